@@ -0,0 +1,2490 @@
+impl windows_core::imp::ProxyVtable for ID3D12CommandAllocator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12CommandAllocator", "Reset", 8usize, |this| ((**(this as *const *const ID3D12CommandAllocator_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        Self { base__: <ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reset }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12CommandAllocator as windows_core::Interface>::IID || <ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12CommandList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void) -> D3D12_COMMAND_LIST_TYPE {
+            windows_core::imp::proxy_call(this, "ID3D12CommandList", "GetType", 8usize, |this| ((**(this as *const *const ID3D12CommandList_Vtbl)).GetType)(this), |_| None)
+        }
+        Self { base__: <ID3D12DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12CommandList as windows_core::Interface>::IID || <ID3D12DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12CommandQueue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UpdateTileMappings(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, numresourceregions: u32, presourceregionstartcoordinates: *const D3D12_TILED_RESOURCE_COORDINATE, presourceregionsizes: *const D3D12_TILE_REGION_SIZE, pheap: *mut core::ffi::c_void, numranges: u32, prangeflags: *const D3D12_TILE_RANGE_FLAGS, pheaprangestartoffsets: *const u32, prangetilecounts: *const u32, flags: D3D12_TILE_MAPPING_FLAGS) {
+            windows_core::imp::proxy_call(this, "ID3D12CommandQueue", "UpdateTileMappings", 8usize, |this| ((**(this as *const *const ID3D12CommandQueue_Vtbl)).UpdateTileMappings)(this, presource, numresourceregions, presourceregionstartcoordinates, presourceregionsizes, pheap, numranges, prangeflags, pheaprangestartoffsets, prangetilecounts, flags), |_| None)
+        }
+        unsafe extern "system" fn CopyTileMappings(this: *mut core::ffi::c_void, pdstresource: *mut core::ffi::c_void, pdstregionstartcoordinate: *const D3D12_TILED_RESOURCE_COORDINATE, psrcresource: *mut core::ffi::c_void, psrcregionstartcoordinate: *const D3D12_TILED_RESOURCE_COORDINATE, pregionsize: *const D3D12_TILE_REGION_SIZE, flags: D3D12_TILE_MAPPING_FLAGS) {
+            windows_core::imp::proxy_call(this, "ID3D12CommandQueue", "CopyTileMappings", 9usize, |this| ((**(this as *const *const ID3D12CommandQueue_Vtbl)).CopyTileMappings)(this, pdstresource, pdstregionstartcoordinate, psrcresource, psrcregionstartcoordinate, pregionsize, flags), |_| None)
+        }
+        unsafe extern "system" fn ExecuteCommandLists(this: *mut core::ffi::c_void, numcommandlists: u32, ppcommandlists: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12CommandQueue", "ExecuteCommandLists", 10usize, |this| ((**(this as *const *const ID3D12CommandQueue_Vtbl)).ExecuteCommandLists)(this, numcommandlists, ppcommandlists), |_| None)
+        }
+        unsafe extern "system" fn SetMarker(this: *mut core::ffi::c_void, metadata: u32, pdata: *const core::ffi::c_void, size: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12CommandQueue", "SetMarker", 11usize, |this| ((**(this as *const *const ID3D12CommandQueue_Vtbl)).SetMarker)(this, metadata, pdata, size), |_| None)
+        }
+        unsafe extern "system" fn BeginEvent(this: *mut core::ffi::c_void, metadata: u32, pdata: *const core::ffi::c_void, size: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12CommandQueue", "BeginEvent", 12usize, |this| ((**(this as *const *const ID3D12CommandQueue_Vtbl)).BeginEvent)(this, metadata, pdata, size), |_| None)
+        }
+        unsafe extern "system" fn EndEvent(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12CommandQueue", "EndEvent", 13usize, |this| ((**(this as *const *const ID3D12CommandQueue_Vtbl)).EndEvent)(this), |_| None)
+        }
+        unsafe extern "system" fn Signal(this: *mut core::ffi::c_void, pfence: *mut core::ffi::c_void, value: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12CommandQueue", "Signal", 14usize, |this| ((**(this as *const *const ID3D12CommandQueue_Vtbl)).Signal)(this, pfence, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Wait(this: *mut core::ffi::c_void, pfence: *mut core::ffi::c_void, value: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12CommandQueue", "Wait", 15usize, |this| ((**(this as *const *const ID3D12CommandQueue_Vtbl)).Wait)(this, pfence, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTimestampFrequency(this: *mut core::ffi::c_void, pfrequency: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12CommandQueue", "GetTimestampFrequency", 16usize, |this| ((**(this as *const *const ID3D12CommandQueue_Vtbl)).GetTimestampFrequency)(this, pfrequency), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClockCalibration(this: *mut core::ffi::c_void, pgputimestamp: *mut u64, pcputimestamp: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12CommandQueue", "GetClockCalibration", 17usize, |this| ((**(this as *const *const ID3D12CommandQueue_Vtbl)).GetClockCalibration)(this, pgputimestamp, pcputimestamp), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, result__: *mut D3D12_COMMAND_QUEUE_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D12CommandQueue", "GetDesc", 18usize, |this| ((**(this as *const *const ID3D12CommandQueue_Vtbl)).GetDesc)(this, result__), |_| None)
+        }
+        Self {
+            base__: <ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            UpdateTileMappings,
+            CopyTileMappings,
+            ExecuteCommandLists,
+            SetMarker,
+            BeginEvent,
+            EndEvent,
+            Signal,
+            Wait,
+            GetTimestampFrequency,
+            GetClockCalibration,
+            GetDesc,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12CommandQueue as windows_core::Interface>::IID || <ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12CommandSignature_Vtbl {
+    const PROXY: Self = { Self { base__: <ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12CommandSignature as windows_core::Interface>::IID || <ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12Debug_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnableDebugLayer(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12Debug", "EnableDebugLayer", 3usize, |this| ((**(this as *const *const ID3D12Debug_Vtbl)).EnableDebugLayer)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnableDebugLayer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Debug as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12Debug1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnableDebugLayer(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12Debug1", "EnableDebugLayer", 3usize, |this| ((**(this as *const *const ID3D12Debug1_Vtbl)).EnableDebugLayer)(this), |_| None)
+        }
+        unsafe extern "system" fn SetEnableGPUBasedValidation(this: *mut core::ffi::c_void, enable: super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID3D12Debug1", "SetEnableGPUBasedValidation", 4usize, |this| ((**(this as *const *const ID3D12Debug1_Vtbl)).SetEnableGPUBasedValidation)(this, enable), |_| None)
+        }
+        unsafe extern "system" fn SetEnableSynchronizedCommandQueueValidation(this: *mut core::ffi::c_void, enable: super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID3D12Debug1", "SetEnableSynchronizedCommandQueueValidation", 5usize, |this| ((**(this as *const *const ID3D12Debug1_Vtbl)).SetEnableSynchronizedCommandQueueValidation)(this, enable), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EnableDebugLayer,
+            SetEnableGPUBasedValidation,
+            SetEnableSynchronizedCommandQueueValidation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Debug1 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12Debug2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetGPUBasedValidationFlags(this: *mut core::ffi::c_void, flags: D3D12_GPU_BASED_VALIDATION_FLAGS) {
+            windows_core::imp::proxy_call(this, "ID3D12Debug2", "SetGPUBasedValidationFlags", 3usize, |this| ((**(this as *const *const ID3D12Debug2_Vtbl)).SetGPUBasedValidationFlags)(this, flags), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetGPUBasedValidationFlags }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Debug2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12Debug3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetEnableGPUBasedValidation(this: *mut core::ffi::c_void, enable: super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID3D12Debug3", "SetEnableGPUBasedValidation", 4usize, |this| ((**(this as *const *const ID3D12Debug3_Vtbl)).SetEnableGPUBasedValidation)(this, enable), |_| None)
+        }
+        unsafe extern "system" fn SetEnableSynchronizedCommandQueueValidation(this: *mut core::ffi::c_void, enable: super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID3D12Debug3", "SetEnableSynchronizedCommandQueueValidation", 5usize, |this| ((**(this as *const *const ID3D12Debug3_Vtbl)).SetEnableSynchronizedCommandQueueValidation)(this, enable), |_| None)
+        }
+        unsafe extern "system" fn SetGPUBasedValidationFlags(this: *mut core::ffi::c_void, flags: D3D12_GPU_BASED_VALIDATION_FLAGS) {
+            windows_core::imp::proxy_call(this, "ID3D12Debug3", "SetGPUBasedValidationFlags", 6usize, |this| ((**(this as *const *const ID3D12Debug3_Vtbl)).SetGPUBasedValidationFlags)(this, flags), |_| None)
+        }
+        Self {
+            base__: <ID3D12Debug_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetEnableGPUBasedValidation,
+            SetEnableSynchronizedCommandQueueValidation,
+            SetGPUBasedValidationFlags,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Debug3 as windows_core::Interface>::IID || <ID3D12Debug_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12Debug4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisableDebugLayer(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12Debug4", "DisableDebugLayer", 7usize, |this| ((**(this as *const *const ID3D12Debug4_Vtbl)).DisableDebugLayer)(this), |_| None)
+        }
+        Self { base__: <ID3D12Debug3_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DisableDebugLayer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Debug4 as windows_core::Interface>::IID || <ID3D12Debug3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12Debug5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetEnableAutoName(this: *mut core::ffi::c_void, enable: super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID3D12Debug5", "SetEnableAutoName", 8usize, |this| ((**(this as *const *const ID3D12Debug5_Vtbl)).SetEnableAutoName)(this, enable), |_| None)
+        }
+        Self { base__: <ID3D12Debug4_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetEnableAutoName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Debug5 as windows_core::Interface>::IID || <ID3D12Debug4_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12Debug6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetForceLegacyBarrierValidation(this: *mut core::ffi::c_void, enable: super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID3D12Debug6", "SetForceLegacyBarrierValidation", 9usize, |this| ((**(this as *const *const ID3D12Debug6_Vtbl)).SetForceLegacyBarrierValidation)(this, enable), |_| None)
+        }
+        Self { base__: <ID3D12Debug5_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetForceLegacyBarrierValidation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Debug6 as windows_core::Interface>::IID || <ID3D12Debug5_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12DebugCommandList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AssertResourceState(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, subresource: u32, state: u32) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D12DebugCommandList", "AssertResourceState", 3usize, |this| ((**(this as *const *const ID3D12DebugCommandList_Vtbl)).AssertResourceState)(this, presource, subresource, state), |_| None)
+        }
+        unsafe extern "system" fn SetFeatureMask(this: *mut core::ffi::c_void, mask: D3D12_DEBUG_FEATURE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12DebugCommandList", "SetFeatureMask", 4usize, |this| ((**(this as *const *const ID3D12DebugCommandList_Vtbl)).SetFeatureMask)(this, mask), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFeatureMask(this: *mut core::ffi::c_void) -> D3D12_DEBUG_FEATURE {
+            windows_core::imp::proxy_call(this, "ID3D12DebugCommandList", "GetFeatureMask", 5usize, |this| ((**(this as *const *const ID3D12DebugCommandList_Vtbl)).GetFeatureMask)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AssertResourceState, SetFeatureMask, GetFeatureMask }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12DebugCommandList as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12DebugCommandList1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AssertResourceState(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, subresource: u32, state: u32) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D12DebugCommandList1", "AssertResourceState", 3usize, |this| ((**(this as *const *const ID3D12DebugCommandList1_Vtbl)).AssertResourceState)(this, presource, subresource, state), |_| None)
+        }
+        unsafe extern "system" fn SetDebugParameter(this: *mut core::ffi::c_void, r#type: D3D12_DEBUG_COMMAND_LIST_PARAMETER_TYPE, pdata: *const core::ffi::c_void, datasize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12DebugCommandList1", "SetDebugParameter", 4usize, |this| ((**(this as *const *const ID3D12DebugCommandList1_Vtbl)).SetDebugParameter)(this, r#type, pdata, datasize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDebugParameter(this: *mut core::ffi::c_void, r#type: D3D12_DEBUG_COMMAND_LIST_PARAMETER_TYPE, pdata: *mut core::ffi::c_void, datasize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12DebugCommandList1", "GetDebugParameter", 5usize, |this| ((**(this as *const *const ID3D12DebugCommandList1_Vtbl)).GetDebugParameter)(this, r#type, pdata, datasize), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AssertResourceState, SetDebugParameter, GetDebugParameter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12DebugCommandList1 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12DebugCommandList2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDebugParameter(this: *mut core::ffi::c_void, r#type: D3D12_DEBUG_COMMAND_LIST_PARAMETER_TYPE, pdata: *const core::ffi::c_void, datasize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12DebugCommandList2", "SetDebugParameter", 6usize, |this| ((**(this as *const *const ID3D12DebugCommandList2_Vtbl)).SetDebugParameter)(this, r#type, pdata, datasize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDebugParameter(this: *mut core::ffi::c_void, r#type: D3D12_DEBUG_COMMAND_LIST_PARAMETER_TYPE, pdata: *mut core::ffi::c_void, datasize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12DebugCommandList2", "GetDebugParameter", 7usize, |this| ((**(this as *const *const ID3D12DebugCommandList2_Vtbl)).GetDebugParameter)(this, r#type, pdata, datasize), |result| Some(*result))
+        }
+        Self { base__: <ID3D12DebugCommandList_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetDebugParameter, GetDebugParameter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12DebugCommandList2 as windows_core::Interface>::IID || <ID3D12DebugCommandList_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12DebugCommandList3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AssertResourceAccess(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, subresource: u32, access: D3D12_BARRIER_ACCESS) {
+            windows_core::imp::proxy_call(this, "ID3D12DebugCommandList3", "AssertResourceAccess", 8usize, |this| ((**(this as *const *const ID3D12DebugCommandList3_Vtbl)).AssertResourceAccess)(this, presource, subresource, access), |_| None)
+        }
+        unsafe extern "system" fn AssertTextureLayout(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, subresource: u32, layout: D3D12_BARRIER_LAYOUT) {
+            windows_core::imp::proxy_call(this, "ID3D12DebugCommandList3", "AssertTextureLayout", 9usize, |this| ((**(this as *const *const ID3D12DebugCommandList3_Vtbl)).AssertTextureLayout)(this, presource, subresource, layout), |_| None)
+        }
+        Self { base__: <ID3D12DebugCommandList2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AssertResourceAccess, AssertTextureLayout }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12DebugCommandList3 as windows_core::Interface>::IID || <ID3D12DebugCommandList2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12DebugCommandQueue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AssertResourceState(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, subresource: u32, state: u32) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D12DebugCommandQueue", "AssertResourceState", 3usize, |this| ((**(this as *const *const ID3D12DebugCommandQueue_Vtbl)).AssertResourceState)(this, presource, subresource, state), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AssertResourceState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12DebugCommandQueue as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12DebugCommandQueue1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AssertResourceAccess(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, subresource: u32, access: D3D12_BARRIER_ACCESS) {
+            windows_core::imp::proxy_call(this, "ID3D12DebugCommandQueue1", "AssertResourceAccess", 4usize, |this| ((**(this as *const *const ID3D12DebugCommandQueue1_Vtbl)).AssertResourceAccess)(this, presource, subresource, access), |_| None)
+        }
+        unsafe extern "system" fn AssertTextureLayout(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, subresource: u32, layout: D3D12_BARRIER_LAYOUT) {
+            windows_core::imp::proxy_call(this, "ID3D12DebugCommandQueue1", "AssertTextureLayout", 5usize, |this| ((**(this as *const *const ID3D12DebugCommandQueue1_Vtbl)).AssertTextureLayout)(this, presource, subresource, layout), |_| None)
+        }
+        Self { base__: <ID3D12DebugCommandQueue_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AssertResourceAccess, AssertTextureLayout }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12DebugCommandQueue1 as windows_core::Interface>::IID || <ID3D12DebugCommandQueue_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12DebugDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetFeatureMask(this: *mut core::ffi::c_void, mask: D3D12_DEBUG_FEATURE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12DebugDevice", "SetFeatureMask", 3usize, |this| ((**(this as *const *const ID3D12DebugDevice_Vtbl)).SetFeatureMask)(this, mask), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFeatureMask(this: *mut core::ffi::c_void) -> D3D12_DEBUG_FEATURE {
+            windows_core::imp::proxy_call(this, "ID3D12DebugDevice", "GetFeatureMask", 4usize, |this| ((**(this as *const *const ID3D12DebugDevice_Vtbl)).GetFeatureMask)(this), |_| None)
+        }
+        unsafe extern "system" fn ReportLiveDeviceObjects(this: *mut core::ffi::c_void, flags: D3D12_RLDO_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12DebugDevice", "ReportLiveDeviceObjects", 5usize, |this| ((**(this as *const *const ID3D12DebugDevice_Vtbl)).ReportLiveDeviceObjects)(this, flags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetFeatureMask, GetFeatureMask, ReportLiveDeviceObjects }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12DebugDevice as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12DebugDevice1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDebugParameter(this: *mut core::ffi::c_void, r#type: D3D12_DEBUG_DEVICE_PARAMETER_TYPE, pdata: *const core::ffi::c_void, datasize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12DebugDevice1", "SetDebugParameter", 3usize, |this| ((**(this as *const *const ID3D12DebugDevice1_Vtbl)).SetDebugParameter)(this, r#type, pdata, datasize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDebugParameter(this: *mut core::ffi::c_void, r#type: D3D12_DEBUG_DEVICE_PARAMETER_TYPE, pdata: *mut core::ffi::c_void, datasize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12DebugDevice1", "GetDebugParameter", 4usize, |this| ((**(this as *const *const ID3D12DebugDevice1_Vtbl)).GetDebugParameter)(this, r#type, pdata, datasize), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportLiveDeviceObjects(this: *mut core::ffi::c_void, flags: D3D12_RLDO_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12DebugDevice1", "ReportLiveDeviceObjects", 5usize, |this| ((**(this as *const *const ID3D12DebugDevice1_Vtbl)).ReportLiveDeviceObjects)(this, flags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetDebugParameter, GetDebugParameter, ReportLiveDeviceObjects }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12DebugDevice1 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12DebugDevice2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDebugParameter(this: *mut core::ffi::c_void, r#type: D3D12_DEBUG_DEVICE_PARAMETER_TYPE, pdata: *const core::ffi::c_void, datasize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12DebugDevice2", "SetDebugParameter", 6usize, |this| ((**(this as *const *const ID3D12DebugDevice2_Vtbl)).SetDebugParameter)(this, r#type, pdata, datasize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDebugParameter(this: *mut core::ffi::c_void, r#type: D3D12_DEBUG_DEVICE_PARAMETER_TYPE, pdata: *mut core::ffi::c_void, datasize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12DebugDevice2", "GetDebugParameter", 7usize, |this| ((**(this as *const *const ID3D12DebugDevice2_Vtbl)).GetDebugParameter)(this, r#type, pdata, datasize), |result| Some(*result))
+        }
+        Self { base__: <ID3D12DebugDevice_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetDebugParameter, GetDebugParameter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12DebugDevice2 as windows_core::Interface>::IID || <ID3D12DebugDevice_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12DescriptorHeap_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, result__: *mut D3D12_DESCRIPTOR_HEAP_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D12DescriptorHeap", "GetDesc", 8usize, |this| ((**(this as *const *const ID3D12DescriptorHeap_Vtbl)).GetDesc)(this, result__), |_| None)
+        }
+        unsafe extern "system" fn GetCPUDescriptorHandleForHeapStart(this: *mut core::ffi::c_void, result__: *mut D3D12_CPU_DESCRIPTOR_HANDLE) {
+            windows_core::imp::proxy_call(this, "ID3D12DescriptorHeap", "GetCPUDescriptorHandleForHeapStart", 9usize, |this| ((**(this as *const *const ID3D12DescriptorHeap_Vtbl)).GetCPUDescriptorHandleForHeapStart)(this, result__), |_| None)
+        }
+        unsafe extern "system" fn GetGPUDescriptorHandleForHeapStart(this: *mut core::ffi::c_void, result__: *mut D3D12_GPU_DESCRIPTOR_HANDLE) {
+            windows_core::imp::proxy_call(this, "ID3D12DescriptorHeap", "GetGPUDescriptorHandleForHeapStart", 10usize, |this| ((**(this as *const *const ID3D12DescriptorHeap_Vtbl)).GetGPUDescriptorHandleForHeapStart)(this, result__), |_| None)
+        }
+        Self {
+            base__: <ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDesc,
+            GetCPUDescriptorHandleForHeapStart,
+            GetGPUDescriptorHandleForHeapStart,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12DescriptorHeap as windows_core::Interface>::IID || <ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl windows_core::imp::ProxyVtable for ID3D12Device_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNodeCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "GetNodeCount", 7usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).GetNodeCount)(this), |_| None)
+        }
+        unsafe extern "system" fn CreateCommandQueue(this: *mut core::ffi::c_void, pdesc: *const D3D12_COMMAND_QUEUE_DESC, riid: *const windows_core::GUID, ppcommandqueue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "CreateCommandQueue", 8usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).CreateCommandQueue)(this, pdesc, riid, ppcommandqueue), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCommandAllocator(this: *mut core::ffi::c_void, r#type: D3D12_COMMAND_LIST_TYPE, riid: *const windows_core::GUID, ppcommandallocator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "CreateCommandAllocator", 9usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).CreateCommandAllocator)(this, r#type, riid, ppcommandallocator), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateGraphicsPipelineState(this: *mut core::ffi::c_void, pdesc: *const D3D12_GRAPHICS_PIPELINE_STATE_DESC, riid: *const windows_core::GUID, pppipelinestate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "CreateGraphicsPipelineState", 10usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).CreateGraphicsPipelineState)(this, pdesc, riid, pppipelinestate), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateComputePipelineState(this: *mut core::ffi::c_void, pdesc: *const D3D12_COMPUTE_PIPELINE_STATE_DESC, riid: *const windows_core::GUID, pppipelinestate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "CreateComputePipelineState", 11usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).CreateComputePipelineState)(this, pdesc, riid, pppipelinestate), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCommandList(this: *mut core::ffi::c_void, nodemask: u32, r#type: D3D12_COMMAND_LIST_TYPE, pcommandallocator: *mut core::ffi::c_void, pinitialstate: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppcommandlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "CreateCommandList", 12usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).CreateCommandList)(this, nodemask, r#type, pcommandallocator, pinitialstate, riid, ppcommandlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckFeatureSupport(this: *mut core::ffi::c_void, feature: D3D12_FEATURE, pfeaturesupportdata: *mut core::ffi::c_void, featuresupportdatasize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "CheckFeatureSupport", 13usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).CheckFeatureSupport)(this, feature, pfeaturesupportdata, featuresupportdatasize), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDescriptorHeap(this: *mut core::ffi::c_void, pdescriptorheapdesc: *const D3D12_DESCRIPTOR_HEAP_DESC, riid: *const windows_core::GUID, ppvheap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "CreateDescriptorHeap", 14usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).CreateDescriptorHeap)(this, pdescriptorheapdesc, riid, ppvheap), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescriptorHandleIncrementSize(this: *mut core::ffi::c_void, descriptorheaptype: D3D12_DESCRIPTOR_HEAP_TYPE) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "GetDescriptorHandleIncrementSize", 15usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).GetDescriptorHandleIncrementSize)(this, descriptorheaptype), |_| None)
+        }
+        unsafe extern "system" fn CreateRootSignature(this: *mut core::ffi::c_void, nodemask: u32, pblobwithrootsignature: *const core::ffi::c_void, bloblengthinbytes: usize, riid: *const windows_core::GUID, ppvrootsignature: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "CreateRootSignature", 16usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).CreateRootSignature)(this, nodemask, pblobwithrootsignature, bloblengthinbytes, riid, ppvrootsignature), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateConstantBufferView(this: *mut core::ffi::c_void, pdesc: *const D3D12_CONSTANT_BUFFER_VIEW_DESC, destdescriptor: D3D12_CPU_DESCRIPTOR_HANDLE) {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "CreateConstantBufferView", 17usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).CreateConstantBufferView)(this, pdesc, destdescriptor), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateShaderResourceView(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pdesc: *const D3D12_SHADER_RESOURCE_VIEW_DESC, destdescriptor: D3D12_CPU_DESCRIPTOR_HANDLE) {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "CreateShaderResourceView", 18usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).CreateShaderResourceView)(this, presource, pdesc, destdescriptor), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateUnorderedAccessView(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pcounterresource: *mut core::ffi::c_void, pdesc: *const D3D12_UNORDERED_ACCESS_VIEW_DESC, destdescriptor: D3D12_CPU_DESCRIPTOR_HANDLE) {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "CreateUnorderedAccessView", 19usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).CreateUnorderedAccessView)(this, presource, pcounterresource, pdesc, destdescriptor), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateRenderTargetView(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pdesc: *const D3D12_RENDER_TARGET_VIEW_DESC, destdescriptor: D3D12_CPU_DESCRIPTOR_HANDLE) {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "CreateRenderTargetView", 20usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).CreateRenderTargetView)(this, presource, pdesc, destdescriptor), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateDepthStencilView(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pdesc: *const D3D12_DEPTH_STENCIL_VIEW_DESC, destdescriptor: D3D12_CPU_DESCRIPTOR_HANDLE) {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "CreateDepthStencilView", 21usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).CreateDepthStencilView)(this, presource, pdesc, destdescriptor), |_| None)
+        }
+        unsafe extern "system" fn CreateSampler(this: *mut core::ffi::c_void, pdesc: *const D3D12_SAMPLER_DESC, destdescriptor: D3D12_CPU_DESCRIPTOR_HANDLE) {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "CreateSampler", 22usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).CreateSampler)(this, pdesc, destdescriptor), |_| None)
+        }
+        unsafe extern "system" fn CopyDescriptors(this: *mut core::ffi::c_void, numdestdescriptorranges: u32, pdestdescriptorrangestarts: *const D3D12_CPU_DESCRIPTOR_HANDLE, pdestdescriptorrangesizes: *const u32, numsrcdescriptorranges: u32, psrcdescriptorrangestarts: *const D3D12_CPU_DESCRIPTOR_HANDLE, psrcdescriptorrangesizes: *const u32, descriptorheapstype: D3D12_DESCRIPTOR_HEAP_TYPE) {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "CopyDescriptors", 23usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).CopyDescriptors)(this, numdestdescriptorranges, pdestdescriptorrangestarts, pdestdescriptorrangesizes, numsrcdescriptorranges, psrcdescriptorrangestarts, psrcdescriptorrangesizes, descriptorheapstype), |_| None)
+        }
+        unsafe extern "system" fn CopyDescriptorsSimple(this: *mut core::ffi::c_void, numdescriptors: u32, destdescriptorrangestart: D3D12_CPU_DESCRIPTOR_HANDLE, srcdescriptorrangestart: D3D12_CPU_DESCRIPTOR_HANDLE, descriptorheapstype: D3D12_DESCRIPTOR_HEAP_TYPE) {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "CopyDescriptorsSimple", 24usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).CopyDescriptorsSimple)(this, numdescriptors, destdescriptorrangestart, srcdescriptorrangestart, descriptorheapstype), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetResourceAllocationInfo(this: *mut core::ffi::c_void, result__: *mut D3D12_RESOURCE_ALLOCATION_INFO, visiblemask: u32, numresourcedescs: u32, presourcedescs: *const D3D12_RESOURCE_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "GetResourceAllocationInfo", 25usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).GetResourceAllocationInfo)(this, result__, visiblemask, numresourcedescs, presourcedescs), |_| None)
+        }
+        unsafe extern "system" fn GetCustomHeapProperties(this: *mut core::ffi::c_void, result__: *mut D3D12_HEAP_PROPERTIES, nodemask: u32, heaptype: D3D12_HEAP_TYPE) {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "GetCustomHeapProperties", 26usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).GetCustomHeapProperties)(this, result__, nodemask, heaptype), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateCommittedResource(this: *mut core::ffi::c_void, pheapproperties: *const D3D12_HEAP_PROPERTIES, heapflags: D3D12_HEAP_FLAGS, pdesc: *const D3D12_RESOURCE_DESC, initialresourcestate: D3D12_RESOURCE_STATES, poptimizedclearvalue: *const D3D12_CLEAR_VALUE, riidresource: *const windows_core::GUID, ppvresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "CreateCommittedResource", 27usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).CreateCommittedResource)(this, pheapproperties, heapflags, pdesc, initialresourcestate, poptimizedclearvalue, riidresource, ppvresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateHeap(this: *mut core::ffi::c_void, pdesc: *const D3D12_HEAP_DESC, riid: *const windows_core::GUID, ppvheap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "CreateHeap", 28usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).CreateHeap)(this, pdesc, riid, ppvheap), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreatePlacedResource(this: *mut core::ffi::c_void, pheap: *mut core::ffi::c_void, heapoffset: u64, pdesc: *const D3D12_RESOURCE_DESC, initialstate: D3D12_RESOURCE_STATES, poptimizedclearvalue: *const D3D12_CLEAR_VALUE, riid: *const windows_core::GUID, ppvresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "CreatePlacedResource", 29usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).CreatePlacedResource)(this, pheap, heapoffset, pdesc, initialstate, poptimizedclearvalue, riid, ppvresource), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateReservedResource(this: *mut core::ffi::c_void, pdesc: *const D3D12_RESOURCE_DESC, initialstate: D3D12_RESOURCE_STATES, poptimizedclearvalue: *const D3D12_CLEAR_VALUE, riid: *const windows_core::GUID, ppvresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "CreateReservedResource", 30usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).CreateReservedResource)(this, pdesc, initialstate, poptimizedclearvalue, riid, ppvresource), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Security")]
+        unsafe extern "system" fn CreateSharedHandle(this: *mut core::ffi::c_void, pobject: *mut core::ffi::c_void, pattributes: *const super::super::Security::SECURITY_ATTRIBUTES, access: u32, name: windows_core::PCWSTR, phandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "CreateSharedHandle", 31usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).CreateSharedHandle)(this, pobject, pattributes, access, name, phandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenSharedHandle(this: *mut core::ffi::c_void, nthandle: super::super::Foundation::HANDLE, riid: *const windows_core::GUID, ppvobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "OpenSharedHandle", 32usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).OpenSharedHandle)(this, nthandle, riid, ppvobj), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenSharedHandleByName(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, access: u32, pnthandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "OpenSharedHandleByName", 33usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).OpenSharedHandleByName)(this, name, access, pnthandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn MakeResident(this: *mut core::ffi::c_void, numobjects: u32, ppobjects: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "MakeResident", 34usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).MakeResident)(this, numobjects, ppobjects), |result| Some(*result))
+        }
+        unsafe extern "system" fn Evict(this: *mut core::ffi::c_void, numobjects: u32, ppobjects: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "Evict", 35usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).Evict)(this, numobjects, ppobjects), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFence(this: *mut core::ffi::c_void, initialvalue: u64, flags: D3D12_FENCE_FLAGS, riid: *const windows_core::GUID, ppfence: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "CreateFence", 36usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).CreateFence)(this, initialvalue, flags, riid, ppfence), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceRemovedReason(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "GetDeviceRemovedReason", 37usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).GetDeviceRemovedReason)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetCopyableFootprints(this: *mut core::ffi::c_void, presourcedesc: *const D3D12_RESOURCE_DESC, firstsubresource: u32, numsubresources: u32, baseoffset: u64, playouts: *mut D3D12_PLACED_SUBRESOURCE_FOOTPRINT, pnumrows: *mut u32, prowsizeinbytes: *mut u64, ptotalbytes: *mut u64) {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "GetCopyableFootprints", 38usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).GetCopyableFootprints)(this, presourcedesc, firstsubresource, numsubresources, baseoffset, playouts, pnumrows, prowsizeinbytes, ptotalbytes), |_| None)
+        }
+        unsafe extern "system" fn CreateQueryHeap(this: *mut core::ffi::c_void, pdesc: *const D3D12_QUERY_HEAP_DESC, riid: *const windows_core::GUID, ppvheap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "CreateQueryHeap", 39usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).CreateQueryHeap)(this, pdesc, riid, ppvheap), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStablePowerState(this: *mut core::ffi::c_void, enable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "SetStablePowerState", 40usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).SetStablePowerState)(this, enable), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCommandSignature(this: *mut core::ffi::c_void, pdesc: *const D3D12_COMMAND_SIGNATURE_DESC, prootsignature: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvcommandsignature: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "CreateCommandSignature", 41usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).CreateCommandSignature)(this, pdesc, prootsignature, riid, ppvcommandsignature), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResourceTiling(this: *mut core::ffi::c_void, ptiledresource: *mut core::ffi::c_void, pnumtilesforentireresource: *mut u32, ppackedmipdesc: *mut D3D12_PACKED_MIP_INFO, pstandardtileshapefornonpackedmips: *mut D3D12_TILE_SHAPE, pnumsubresourcetilings: *mut u32, firstsubresourcetilingtoget: u32, psubresourcetilingsfornonpackedmips: *mut D3D12_SUBRESOURCE_TILING) {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "GetResourceTiling", 42usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).GetResourceTiling)(this, ptiledresource, pnumtilesforentireresource, ppackedmipdesc, pstandardtileshapefornonpackedmips, pnumsubresourcetilings, firstsubresourcetilingtoget, psubresourcetilingsfornonpackedmips), |_| None)
+        }
+        unsafe extern "system" fn GetAdapterLuid(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::LUID) {
+            windows_core::imp::proxy_call(this, "ID3D12Device", "GetAdapterLuid", 43usize, |this| ((**(this as *const *const ID3D12Device_Vtbl)).GetAdapterLuid)(this, result__), |_| None)
+        }
+        Self {
+            base__: <ID3D12Object_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetNodeCount,
+            CreateCommandQueue,
+            CreateCommandAllocator,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateGraphicsPipelineState,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateGraphicsPipelineState: 0,
+            CreateComputePipelineState,
+            CreateCommandList,
+            CheckFeatureSupport,
+            CreateDescriptorHeap,
+            GetDescriptorHandleIncrementSize,
+            CreateRootSignature,
+            CreateConstantBufferView,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateShaderResourceView,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateShaderResourceView: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateUnorderedAccessView,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateUnorderedAccessView: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateRenderTargetView,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateRenderTargetView: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateDepthStencilView,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateDepthStencilView: 0,
+            CreateSampler,
+            CopyDescriptors,
+            CopyDescriptorsSimple,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetResourceAllocationInfo,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetResourceAllocationInfo: 0,
+            GetCustomHeapProperties,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateCommittedResource,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateCommittedResource: 0,
+            CreateHeap,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreatePlacedResource,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreatePlacedResource: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateReservedResource,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateReservedResource: 0,
+            #[cfg(feature = "Win32_Security")]
+            CreateSharedHandle,
+            #[cfg(not(feature = "Win32_Security"))]
+            CreateSharedHandle: 0,
+            OpenSharedHandle,
+            OpenSharedHandleByName,
+            MakeResident,
+            Evict,
+            CreateFence,
+            GetDeviceRemovedReason,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetCopyableFootprints,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetCopyableFootprints: 0,
+            CreateQueryHeap,
+            SetStablePowerState,
+            CreateCommandSignature,
+            GetResourceTiling,
+            GetAdapterLuid,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Device as windows_core::Interface>::IID || <ID3D12Object_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl windows_core::imp::ProxyVtable for ID3D12Device1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreatePipelineLibrary(this: *mut core::ffi::c_void, plibraryblob: *const core::ffi::c_void, bloblength: usize, riid: *const windows_core::GUID, pppipelinelibrary: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device1", "CreatePipelineLibrary", 44usize, |this| ((**(this as *const *const ID3D12Device1_Vtbl)).CreatePipelineLibrary)(this, plibraryblob, bloblength, riid, pppipelinelibrary), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventOnMultipleFenceCompletion(this: *mut core::ffi::c_void, ppfences: *const *mut core::ffi::c_void, pfencevalues: *const u64, numfences: u32, flags: D3D12_MULTIPLE_FENCE_WAIT_FLAGS, hevent: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device1", "SetEventOnMultipleFenceCompletion", 45usize, |this| ((**(this as *const *const ID3D12Device1_Vtbl)).SetEventOnMultipleFenceCompletion)(this, ppfences, pfencevalues, numfences, flags, hevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetResidencyPriority(this: *mut core::ffi::c_void, numobjects: u32, ppobjects: *const *mut core::ffi::c_void, ppriorities: *const D3D12_RESIDENCY_PRIORITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device1", "SetResidencyPriority", 46usize, |this| ((**(this as *const *const ID3D12Device1_Vtbl)).SetResidencyPriority)(this, numobjects, ppobjects, ppriorities), |result| Some(*result))
+        }
+        Self {
+            base__: <ID3D12Device_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreatePipelineLibrary,
+            SetEventOnMultipleFenceCompletion,
+            SetResidencyPriority,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Device1 as windows_core::Interface>::IID || <ID3D12Device_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl windows_core::imp::ProxyVtable for ID3D12Device10_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateCommittedResource3(this: *mut core::ffi::c_void, pheapproperties: *const D3D12_HEAP_PROPERTIES, heapflags: D3D12_HEAP_FLAGS, pdesc: *const D3D12_RESOURCE_DESC1, initiallayout: D3D12_BARRIER_LAYOUT, poptimizedclearvalue: *const D3D12_CLEAR_VALUE, pprotectedsession: *mut core::ffi::c_void, numcastableformats: u32, pcastableformats: *const super::Dxgi::Common::DXGI_FORMAT, riidresource: *const windows_core::GUID, ppvresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device10", "CreateCommittedResource3", 76usize, |this| ((**(this as *const *const ID3D12Device10_Vtbl)).CreateCommittedResource3)(this, pheapproperties, heapflags, pdesc, initiallayout, poptimizedclearvalue, pprotectedsession, numcastableformats, pcastableformats, riidresource, ppvresource), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreatePlacedResource2(this: *mut core::ffi::c_void, pheap: *mut core::ffi::c_void, heapoffset: u64, pdesc: *const D3D12_RESOURCE_DESC1, initiallayout: D3D12_BARRIER_LAYOUT, poptimizedclearvalue: *const D3D12_CLEAR_VALUE, numcastableformats: u32, pcastableformats: *const super::Dxgi::Common::DXGI_FORMAT, riid: *const windows_core::GUID, ppvresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device10", "CreatePlacedResource2", 77usize, |this| ((**(this as *const *const ID3D12Device10_Vtbl)).CreatePlacedResource2)(this, pheap, heapoffset, pdesc, initiallayout, poptimizedclearvalue, numcastableformats, pcastableformats, riid, ppvresource), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateReservedResource2(this: *mut core::ffi::c_void, pdesc: *const D3D12_RESOURCE_DESC, initiallayout: D3D12_BARRIER_LAYOUT, poptimizedclearvalue: *const D3D12_CLEAR_VALUE, pprotectedsession: *mut core::ffi::c_void, numcastableformats: u32, pcastableformats: *const super::Dxgi::Common::DXGI_FORMAT, riid: *const windows_core::GUID, ppvresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device10", "CreateReservedResource2", 78usize, |this| ((**(this as *const *const ID3D12Device10_Vtbl)).CreateReservedResource2)(this, pdesc, initiallayout, poptimizedclearvalue, pprotectedsession, numcastableformats, pcastableformats, riid, ppvresource), |result| Some(*result))
+        }
+        Self {
+            base__: <ID3D12Device9_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateCommittedResource3,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateCommittedResource3: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreatePlacedResource2,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreatePlacedResource2: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateReservedResource2,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateReservedResource2: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Device10 as windows_core::Interface>::IID || <ID3D12Device9_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl windows_core::imp::ProxyVtable for ID3D12Device11_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateSampler2(this: *mut core::ffi::c_void, pdesc: *const D3D12_SAMPLER_DESC2, destdescriptor: D3D12_CPU_DESCRIPTOR_HANDLE) {
+            windows_core::imp::proxy_call(this, "ID3D12Device11", "CreateSampler2", 79usize, |this| ((**(this as *const *const ID3D12Device11_Vtbl)).CreateSampler2)(this, pdesc, destdescriptor), |_| None)
+        }
+        Self { base__: <ID3D12Device10_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateSampler2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Device11 as windows_core::Interface>::IID || <ID3D12Device10_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl windows_core::imp::ProxyVtable for ID3D12Device12_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetResourceAllocationInfo3(this: *mut core::ffi::c_void, result__: *mut D3D12_RESOURCE_ALLOCATION_INFO, visiblemask: u32, numresourcedescs: u32, presourcedescs: *const D3D12_RESOURCE_DESC1, pnumcastableformats: *const u32, ppcastableformats: *const *const super::Dxgi::Common::DXGI_FORMAT, presourceallocationinfo1: *mut D3D12_RESOURCE_ALLOCATION_INFO1) {
+            windows_core::imp::proxy_call(this, "ID3D12Device12", "GetResourceAllocationInfo3", 80usize, |this| ((**(this as *const *const ID3D12Device12_Vtbl)).GetResourceAllocationInfo3)(this, result__, visiblemask, numresourcedescs, presourcedescs, pnumcastableformats, ppcastableformats, presourceallocationinfo1), |_| None)
+        }
+        Self {
+            base__: <ID3D12Device11_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetResourceAllocationInfo3,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetResourceAllocationInfo3: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Device12 as windows_core::Interface>::IID || <ID3D12Device11_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl windows_core::imp::ProxyVtable for ID3D12Device13_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenExistingHeapFromAddress1(this: *mut core::ffi::c_void, paddress: *const core::ffi::c_void, size: usize, riid: *const windows_core::GUID, ppvheap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device13", "OpenExistingHeapFromAddress1", 81usize, |this| ((**(this as *const *const ID3D12Device13_Vtbl)).OpenExistingHeapFromAddress1)(this, paddress, size, riid, ppvheap), |result| Some(*result))
+        }
+        Self { base__: <ID3D12Device12_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OpenExistingHeapFromAddress1 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Device13 as windows_core::Interface>::IID || <ID3D12Device12_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl windows_core::imp::ProxyVtable for ID3D12Device14_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateRootSignatureFromSubobjectInLibrary(this: *mut core::ffi::c_void, nodemask: u32, plibraryblob: *const core::ffi::c_void, bloblengthinbytes: usize, subobjectname: windows_core::PCWSTR, riid: *const windows_core::GUID, ppvrootsignature: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device14", "CreateRootSignatureFromSubobjectInLibrary", 82usize, |this| ((**(this as *const *const ID3D12Device14_Vtbl)).CreateRootSignatureFromSubobjectInLibrary)(this, nodemask, plibraryblob, bloblengthinbytes, subobjectname, riid, ppvrootsignature), |result| Some(*result))
+        }
+        Self { base__: <ID3D12Device13_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateRootSignatureFromSubobjectInLibrary }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Device14 as windows_core::Interface>::IID || <ID3D12Device13_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl windows_core::imp::ProxyVtable for ID3D12Device2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreatePipelineState(this: *mut core::ffi::c_void, pdesc: *const D3D12_PIPELINE_STATE_STREAM_DESC, riid: *const windows_core::GUID, pppipelinestate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device2", "CreatePipelineState", 47usize, |this| ((**(this as *const *const ID3D12Device2_Vtbl)).CreatePipelineState)(this, pdesc, riid, pppipelinestate), |result| Some(*result))
+        }
+        Self { base__: <ID3D12Device1_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreatePipelineState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Device2 as windows_core::Interface>::IID || <ID3D12Device1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl windows_core::imp::ProxyVtable for ID3D12Device3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenExistingHeapFromAddress(this: *mut core::ffi::c_void, paddress: *const core::ffi::c_void, riid: *const windows_core::GUID, ppvheap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device3", "OpenExistingHeapFromAddress", 48usize, |this| ((**(this as *const *const ID3D12Device3_Vtbl)).OpenExistingHeapFromAddress)(this, paddress, riid, ppvheap), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenExistingHeapFromFileMapping(this: *mut core::ffi::c_void, hfilemapping: super::super::Foundation::HANDLE, riid: *const windows_core::GUID, ppvheap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device3", "OpenExistingHeapFromFileMapping", 49usize, |this| ((**(this as *const *const ID3D12Device3_Vtbl)).OpenExistingHeapFromFileMapping)(this, hfilemapping, riid, ppvheap), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnqueueMakeResident(this: *mut core::ffi::c_void, flags: D3D12_RESIDENCY_FLAGS, numobjects: u32, ppobjects: *const *mut core::ffi::c_void, pfencetosignal: *mut core::ffi::c_void, fencevaluetosignal: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device3", "EnqueueMakeResident", 50usize, |this| ((**(this as *const *const ID3D12Device3_Vtbl)).EnqueueMakeResident)(this, flags, numobjects, ppobjects, pfencetosignal, fencevaluetosignal), |result| Some(*result))
+        }
+        Self {
+            base__: <ID3D12Device2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OpenExistingHeapFromAddress,
+            OpenExistingHeapFromFileMapping,
+            EnqueueMakeResident,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Device3 as windows_core::Interface>::IID || <ID3D12Device2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl windows_core::imp::ProxyVtable for ID3D12Device4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateCommandList1(this: *mut core::ffi::c_void, nodemask: u32, r#type: D3D12_COMMAND_LIST_TYPE, flags: D3D12_COMMAND_LIST_FLAGS, riid: *const windows_core::GUID, ppcommandlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device4", "CreateCommandList1", 51usize, |this| ((**(this as *const *const ID3D12Device4_Vtbl)).CreateCommandList1)(this, nodemask, r#type, flags, riid, ppcommandlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateProtectedResourceSession(this: *mut core::ffi::c_void, pdesc: *const D3D12_PROTECTED_RESOURCE_SESSION_DESC, riid: *const windows_core::GUID, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device4", "CreateProtectedResourceSession", 52usize, |this| ((**(this as *const *const ID3D12Device4_Vtbl)).CreateProtectedResourceSession)(this, pdesc, riid, ppsession), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateCommittedResource1(this: *mut core::ffi::c_void, pheapproperties: *const D3D12_HEAP_PROPERTIES, heapflags: D3D12_HEAP_FLAGS, pdesc: *const D3D12_RESOURCE_DESC, initialresourcestate: D3D12_RESOURCE_STATES, poptimizedclearvalue: *const D3D12_CLEAR_VALUE, pprotectedsession: *mut core::ffi::c_void, riidresource: *const windows_core::GUID, ppvresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device4", "CreateCommittedResource1", 53usize, |this| ((**(this as *const *const ID3D12Device4_Vtbl)).CreateCommittedResource1)(this, pheapproperties, heapflags, pdesc, initialresourcestate, poptimizedclearvalue, pprotectedsession, riidresource, ppvresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateHeap1(this: *mut core::ffi::c_void, pdesc: *const D3D12_HEAP_DESC, pprotectedsession: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvheap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device4", "CreateHeap1", 54usize, |this| ((**(this as *const *const ID3D12Device4_Vtbl)).CreateHeap1)(this, pdesc, pprotectedsession, riid, ppvheap), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateReservedResource1(this: *mut core::ffi::c_void, pdesc: *const D3D12_RESOURCE_DESC, initialstate: D3D12_RESOURCE_STATES, poptimizedclearvalue: *const D3D12_CLEAR_VALUE, pprotectedsession: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device4", "CreateReservedResource1", 55usize, |this| ((**(this as *const *const ID3D12Device4_Vtbl)).CreateReservedResource1)(this, pdesc, initialstate, poptimizedclearvalue, pprotectedsession, riid, ppvresource), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetResourceAllocationInfo1(this: *mut core::ffi::c_void, result__: *mut D3D12_RESOURCE_ALLOCATION_INFO, visiblemask: u32, numresourcedescs: u32, presourcedescs: *const D3D12_RESOURCE_DESC, presourceallocationinfo1: *mut D3D12_RESOURCE_ALLOCATION_INFO1) {
+            windows_core::imp::proxy_call(this, "ID3D12Device4", "GetResourceAllocationInfo1", 56usize, |this| ((**(this as *const *const ID3D12Device4_Vtbl)).GetResourceAllocationInfo1)(this, result__, visiblemask, numresourcedescs, presourcedescs, presourceallocationinfo1), |_| None)
+        }
+        Self {
+            base__: <ID3D12Device3_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateCommandList1,
+            CreateProtectedResourceSession,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateCommittedResource1,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateCommittedResource1: 0,
+            CreateHeap1,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateReservedResource1,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateReservedResource1: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetResourceAllocationInfo1,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetResourceAllocationInfo1: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Device4 as windows_core::Interface>::IID || <ID3D12Device3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl windows_core::imp::ProxyVtable for ID3D12Device5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateLifetimeTracker(this: *mut core::ffi::c_void, powner: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvtracker: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device5", "CreateLifetimeTracker", 57usize, |this| ((**(this as *const *const ID3D12Device5_Vtbl)).CreateLifetimeTracker)(this, powner, riid, ppvtracker), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDevice(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12Device5", "RemoveDevice", 58usize, |this| ((**(this as *const *const ID3D12Device5_Vtbl)).RemoveDevice)(this), |_| None)
+        }
+        unsafe extern "system" fn EnumerateMetaCommands(this: *mut core::ffi::c_void, pnummetacommands: *mut u32, pdescs: *mut D3D12_META_COMMAND_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device5", "EnumerateMetaCommands", 59usize, |this| ((**(this as *const *const ID3D12Device5_Vtbl)).EnumerateMetaCommands)(this, pnummetacommands, pdescs), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateMetaCommandParameters(this: *mut core::ffi::c_void, commandid: *const windows_core::GUID, stage: D3D12_META_COMMAND_PARAMETER_STAGE, ptotalstructuresizeinbytes: *mut u32, pparametercount: *mut u32, pparameterdescs: *mut D3D12_META_COMMAND_PARAMETER_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device5", "EnumerateMetaCommandParameters", 60usize, |this| ((**(this as *const *const ID3D12Device5_Vtbl)).EnumerateMetaCommandParameters)(this, commandid, stage, ptotalstructuresizeinbytes, pparametercount, pparameterdescs), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateMetaCommand(this: *mut core::ffi::c_void, commandid: *const windows_core::GUID, nodemask: u32, pcreationparametersdata: *const core::ffi::c_void, creationparametersdatasizeinbytes: usize, riid: *const windows_core::GUID, ppmetacommand: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device5", "CreateMetaCommand", 61usize, |this| ((**(this as *const *const ID3D12Device5_Vtbl)).CreateMetaCommand)(this, commandid, nodemask, pcreationparametersdata, creationparametersdatasizeinbytes, riid, ppmetacommand), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateStateObject(this: *mut core::ffi::c_void, pdesc: *const D3D12_STATE_OBJECT_DESC, riid: *const windows_core::GUID, ppstateobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device5", "CreateStateObject", 62usize, |this| ((**(this as *const *const ID3D12Device5_Vtbl)).CreateStateObject)(this, pdesc, riid, ppstateobject), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetRaytracingAccelerationStructurePrebuildInfo(this: *mut core::ffi::c_void, pdesc: *const D3D12_BUILD_RAYTRACING_ACCELERATION_STRUCTURE_INPUTS, pinfo: *mut D3D12_RAYTRACING_ACCELERATION_STRUCTURE_PREBUILD_INFO) {
+            windows_core::imp::proxy_call(this, "ID3D12Device5", "GetRaytracingAccelerationStructurePrebuildInfo", 63usize, |this| ((**(this as *const *const ID3D12Device5_Vtbl)).GetRaytracingAccelerationStructurePrebuildInfo)(this, pdesc, pinfo), |_| None)
+        }
+        unsafe extern "system" fn CheckDriverMatchingIdentifier(this: *mut core::ffi::c_void, serializeddatatype: D3D12_SERIALIZED_DATA_TYPE, pidentifiertocheck: *const D3D12_SERIALIZED_DATA_DRIVER_MATCHING_IDENTIFIER) -> D3D12_DRIVER_MATCHING_IDENTIFIER_STATUS {
+            windows_core::imp::proxy_call(this, "ID3D12Device5", "CheckDriverMatchingIdentifier", 64usize, |this| ((**(this as *const *const ID3D12Device5_Vtbl)).CheckDriverMatchingIdentifier)(this, serializeddatatype, pidentifiertocheck), |_| None)
+        }
+        Self {
+            base__: <ID3D12Device4_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateLifetimeTracker,
+            RemoveDevice,
+            EnumerateMetaCommands,
+            EnumerateMetaCommandParameters,
+            CreateMetaCommand,
+            CreateStateObject,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetRaytracingAccelerationStructurePrebuildInfo,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetRaytracingAccelerationStructurePrebuildInfo: 0,
+            CheckDriverMatchingIdentifier,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Device5 as windows_core::Interface>::IID || <ID3D12Device4_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl windows_core::imp::ProxyVtable for ID3D12Device6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetBackgroundProcessingMode(this: *mut core::ffi::c_void, mode: D3D12_BACKGROUND_PROCESSING_MODE, measurementsaction: D3D12_MEASUREMENTS_ACTION, heventtosignaluponcompletion: super::super::Foundation::HANDLE, pbfurthermeasurementsdesired: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device6", "SetBackgroundProcessingMode", 65usize, |this| ((**(this as *const *const ID3D12Device6_Vtbl)).SetBackgroundProcessingMode)(this, mode, measurementsaction, heventtosignaluponcompletion, pbfurthermeasurementsdesired), |result| Some(*result))
+        }
+        Self { base__: <ID3D12Device5_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetBackgroundProcessingMode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Device6 as windows_core::Interface>::IID || <ID3D12Device5_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl windows_core::imp::ProxyVtable for ID3D12Device7_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddToStateObject(this: *mut core::ffi::c_void, paddition: *const D3D12_STATE_OBJECT_DESC, pstateobjecttogrowfrom: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppnewstateobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device7", "AddToStateObject", 66usize, |this| ((**(this as *const *const ID3D12Device7_Vtbl)).AddToStateObject)(this, paddition, pstateobjecttogrowfrom, riid, ppnewstateobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateProtectedResourceSession1(this: *mut core::ffi::c_void, pdesc: *const D3D12_PROTECTED_RESOURCE_SESSION_DESC1, riid: *const windows_core::GUID, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device7", "CreateProtectedResourceSession1", 67usize, |this| ((**(this as *const *const ID3D12Device7_Vtbl)).CreateProtectedResourceSession1)(this, pdesc, riid, ppsession), |result| Some(*result))
+        }
+        Self { base__: <ID3D12Device6_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddToStateObject, CreateProtectedResourceSession1 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Device7 as windows_core::Interface>::IID || <ID3D12Device6_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl windows_core::imp::ProxyVtable for ID3D12Device8_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetResourceAllocationInfo2(this: *mut core::ffi::c_void, result__: *mut D3D12_RESOURCE_ALLOCATION_INFO, visiblemask: u32, numresourcedescs: u32, presourcedescs: *const D3D12_RESOURCE_DESC1, presourceallocationinfo1: *mut D3D12_RESOURCE_ALLOCATION_INFO1) {
+            windows_core::imp::proxy_call(this, "ID3D12Device8", "GetResourceAllocationInfo2", 68usize, |this| ((**(this as *const *const ID3D12Device8_Vtbl)).GetResourceAllocationInfo2)(this, result__, visiblemask, numresourcedescs, presourcedescs, presourceallocationinfo1), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateCommittedResource2(this: *mut core::ffi::c_void, pheapproperties: *const D3D12_HEAP_PROPERTIES, heapflags: D3D12_HEAP_FLAGS, pdesc: *const D3D12_RESOURCE_DESC1, initialresourcestate: D3D12_RESOURCE_STATES, poptimizedclearvalue: *const D3D12_CLEAR_VALUE, pprotectedsession: *mut core::ffi::c_void, riidresource: *const windows_core::GUID, ppvresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device8", "CreateCommittedResource2", 69usize, |this| ((**(this as *const *const ID3D12Device8_Vtbl)).CreateCommittedResource2)(this, pheapproperties, heapflags, pdesc, initialresourcestate, poptimizedclearvalue, pprotectedsession, riidresource, ppvresource), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreatePlacedResource1(this: *mut core::ffi::c_void, pheap: *mut core::ffi::c_void, heapoffset: u64, pdesc: *const D3D12_RESOURCE_DESC1, initialstate: D3D12_RESOURCE_STATES, poptimizedclearvalue: *const D3D12_CLEAR_VALUE, riid: *const windows_core::GUID, ppvresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device8", "CreatePlacedResource1", 70usize, |this| ((**(this as *const *const ID3D12Device8_Vtbl)).CreatePlacedResource1)(this, pheap, heapoffset, pdesc, initialstate, poptimizedclearvalue, riid, ppvresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSamplerFeedbackUnorderedAccessView(this: *mut core::ffi::c_void, ptargetedresource: *mut core::ffi::c_void, pfeedbackresource: *mut core::ffi::c_void, destdescriptor: D3D12_CPU_DESCRIPTOR_HANDLE) {
+            windows_core::imp::proxy_call(this, "ID3D12Device8", "CreateSamplerFeedbackUnorderedAccessView", 71usize, |this| ((**(this as *const *const ID3D12Device8_Vtbl)).CreateSamplerFeedbackUnorderedAccessView)(this, ptargetedresource, pfeedbackresource, destdescriptor), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetCopyableFootprints1(this: *mut core::ffi::c_void, presourcedesc: *const D3D12_RESOURCE_DESC1, firstsubresource: u32, numsubresources: u32, baseoffset: u64, playouts: *mut D3D12_PLACED_SUBRESOURCE_FOOTPRINT, pnumrows: *mut u32, prowsizeinbytes: *mut u64, ptotalbytes: *mut u64) {
+            windows_core::imp::proxy_call(this, "ID3D12Device8", "GetCopyableFootprints1", 72usize, |this| ((**(this as *const *const ID3D12Device8_Vtbl)).GetCopyableFootprints1)(this, presourcedesc, firstsubresource, numsubresources, baseoffset, playouts, pnumrows, prowsizeinbytes, ptotalbytes), |_| None)
+        }
+        Self {
+            base__: <ID3D12Device7_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetResourceAllocationInfo2,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetResourceAllocationInfo2: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateCommittedResource2,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateCommittedResource2: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreatePlacedResource1,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreatePlacedResource1: 0,
+            CreateSamplerFeedbackUnorderedAccessView,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetCopyableFootprints1,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetCopyableFootprints1: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Device8 as windows_core::Interface>::IID || <ID3D12Device7_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Security"))]
+impl windows_core::imp::ProxyVtable for ID3D12Device9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateShaderCacheSession(this: *mut core::ffi::c_void, pdesc: *const D3D12_SHADER_CACHE_SESSION_DESC, riid: *const windows_core::GUID, ppvsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device9", "CreateShaderCacheSession", 73usize, |this| ((**(this as *const *const ID3D12Device9_Vtbl)).CreateShaderCacheSession)(this, pdesc, riid, ppvsession), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShaderCacheControl(this: *mut core::ffi::c_void, kinds: D3D12_SHADER_CACHE_KIND_FLAGS, control: D3D12_SHADER_CACHE_CONTROL_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device9", "ShaderCacheControl", 74usize, |this| ((**(this as *const *const ID3D12Device9_Vtbl)).ShaderCacheControl)(this, kinds, control), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCommandQueue1(this: *mut core::ffi::c_void, pdesc: *const D3D12_COMMAND_QUEUE_DESC, creatorid: *const windows_core::GUID, riid: *const windows_core::GUID, ppcommandqueue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Device9", "CreateCommandQueue1", 75usize, |this| ((**(this as *const *const ID3D12Device9_Vtbl)).CreateCommandQueue1)(this, pdesc, creatorid, riid, ppcommandqueue), |result| Some(*result))
+        }
+        Self { base__: <ID3D12Device8_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateShaderCacheSession, ShaderCacheControl, CreateCommandQueue1 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Device9 as windows_core::Interface>::IID || <ID3D12Device8_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12DeviceChild_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDevice(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12DeviceChild", "GetDevice", 7usize, |this| ((**(this as *const *const ID3D12DeviceChild_Vtbl)).GetDevice)(this, riid, ppvdevice), |result| Some(*result))
+        }
+        Self { base__: <ID3D12Object_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDevice }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12DeviceChild as windows_core::Interface>::IID || <ID3D12Object_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D")]
+impl windows_core::imp::ProxyVtable for ID3D12DeviceConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, result__: *mut D3D12_DEVICE_CONFIGURATION_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D12DeviceConfiguration", "GetDesc", 3usize, |this| ((**(this as *const *const ID3D12DeviceConfiguration_Vtbl)).GetDesc)(this, result__), |_| None)
+        }
+        unsafe extern "system" fn GetEnabledExperimentalFeatures(this: *mut core::ffi::c_void, pguids: *mut windows_core::GUID, numguids: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12DeviceConfiguration", "GetEnabledExperimentalFeatures", 4usize, |this| ((**(this as *const *const ID3D12DeviceConfiguration_Vtbl)).GetEnabledExperimentalFeatures)(this, pguids, numguids), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn SerializeVersionedRootSignature(this: *mut core::ffi::c_void, pdesc: *const D3D12_VERSIONED_ROOT_SIGNATURE_DESC, ppresult: *mut *mut core::ffi::c_void, pperror: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12DeviceConfiguration", "SerializeVersionedRootSignature", 5usize, |this| ((**(this as *const *const ID3D12DeviceConfiguration_Vtbl)).SerializeVersionedRootSignature)(this, pdesc, ppresult, pperror), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVersionedRootSignatureDeserializer(this: *mut core::ffi::c_void, pblob: *const core::ffi::c_void, size: usize, riid: *const windows_core::GUID, ppvdeserializer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12DeviceConfiguration", "CreateVersionedRootSignatureDeserializer", 6usize, |this| ((**(this as *const *const ID3D12DeviceConfiguration_Vtbl)).CreateVersionedRootSignatureDeserializer)(this, pblob, size, riid, ppvdeserializer), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDesc,
+            GetEnabledExperimentalFeatures,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            SerializeVersionedRootSignature,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            SerializeVersionedRootSignature: 0,
+            CreateVersionedRootSignatureDeserializer,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12DeviceConfiguration as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D")]
+impl windows_core::imp::ProxyVtable for ID3D12DeviceConfiguration1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateVersionedRootSignatureDeserializerFromSubobjectInLibrary(this: *mut core::ffi::c_void, plibraryblob: *const core::ffi::c_void, size: usize, rootsignaturesubobjectname: windows_core::PCWSTR, riid: *const windows_core::GUID, ppvdeserializer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12DeviceConfiguration1", "CreateVersionedRootSignatureDeserializerFromSubobjectInLibrary", 7usize, |this| ((**(this as *const *const ID3D12DeviceConfiguration1_Vtbl)).CreateVersionedRootSignatureDeserializerFromSubobjectInLibrary)(this, plibraryblob, size, rootsignaturesubobjectname, riid, ppvdeserializer), |result| Some(*result))
+        }
+        Self {
+            base__: <ID3D12DeviceConfiguration_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateVersionedRootSignatureDeserializerFromSubobjectInLibrary,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12DeviceConfiguration1 as windows_core::Interface>::IID || <ID3D12DeviceConfiguration_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D")]
+impl windows_core::imp::ProxyVtable for ID3D12DeviceFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeFromGlobalState(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12DeviceFactory", "InitializeFromGlobalState", 3usize, |this| ((**(this as *const *const ID3D12DeviceFactory_Vtbl)).InitializeFromGlobalState)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplyToGlobalState(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12DeviceFactory", "ApplyToGlobalState", 4usize, |this| ((**(this as *const *const ID3D12DeviceFactory_Vtbl)).ApplyToGlobalState)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFlags(this: *mut core::ffi::c_void, flags: D3D12_DEVICE_FACTORY_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12DeviceFactory", "SetFlags", 5usize, |this| ((**(this as *const *const ID3D12DeviceFactory_Vtbl)).SetFlags)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlags(this: *mut core::ffi::c_void) -> D3D12_DEVICE_FACTORY_FLAGS {
+            windows_core::imp::proxy_call(this, "ID3D12DeviceFactory", "GetFlags", 6usize, |this| ((**(this as *const *const ID3D12DeviceFactory_Vtbl)).GetFlags)(this), |_| None)
+        }
+        unsafe extern "system" fn GetConfigurationInterface(this: *mut core::ffi::c_void, clsid: *const windows_core::GUID, iid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12DeviceFactory", "GetConfigurationInterface", 7usize, |this| ((**(this as *const *const ID3D12DeviceFactory_Vtbl)).GetConfigurationInterface)(this, clsid, iid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableExperimentalFeatures(this: *mut core::ffi::c_void, numfeatures: u32, piids: *const windows_core::GUID, pconfigurationstructs: *const core::ffi::c_void, pconfigurationstructsizes: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12DeviceFactory", "EnableExperimentalFeatures", 8usize, |this| ((**(this as *const *const ID3D12DeviceFactory_Vtbl)).EnableExperimentalFeatures)(this, numfeatures, piids, pconfigurationstructs, pconfigurationstructsizes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn CreateDevice(this: *mut core::ffi::c_void, adapter: *mut core::ffi::c_void, featurelevel: super::Direct3D::D3D_FEATURE_LEVEL, riid: *const windows_core::GUID, ppvdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12DeviceFactory", "CreateDevice", 9usize, |this| ((**(this as *const *const ID3D12DeviceFactory_Vtbl)).CreateDevice)(this, adapter, featurelevel, riid, ppvdevice), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitializeFromGlobalState,
+            ApplyToGlobalState,
+            SetFlags,
+            GetFlags,
+            GetConfigurationInterface,
+            EnableExperimentalFeatures,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            CreateDevice,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            CreateDevice: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12DeviceFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12DeviceRemovedExtendedData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAutoBreadcrumbsOutput(this: *mut core::ffi::c_void, poutput: *mut D3D12_DRED_AUTO_BREADCRUMBS_OUTPUT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12DeviceRemovedExtendedData", "GetAutoBreadcrumbsOutput", 3usize, |this| ((**(this as *const *const ID3D12DeviceRemovedExtendedData_Vtbl)).GetAutoBreadcrumbsOutput)(this, poutput), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPageFaultAllocationOutput(this: *mut core::ffi::c_void, poutput: *mut D3D12_DRED_PAGE_FAULT_OUTPUT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12DeviceRemovedExtendedData", "GetPageFaultAllocationOutput", 4usize, |this| ((**(this as *const *const ID3D12DeviceRemovedExtendedData_Vtbl)).GetPageFaultAllocationOutput)(this, poutput), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAutoBreadcrumbsOutput, GetPageFaultAllocationOutput }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12DeviceRemovedExtendedData as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12DeviceRemovedExtendedData1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAutoBreadcrumbsOutput1(this: *mut core::ffi::c_void, poutput: *mut D3D12_DRED_AUTO_BREADCRUMBS_OUTPUT1) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12DeviceRemovedExtendedData1", "GetAutoBreadcrumbsOutput1", 5usize, |this| ((**(this as *const *const ID3D12DeviceRemovedExtendedData1_Vtbl)).GetAutoBreadcrumbsOutput1)(this, poutput), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPageFaultAllocationOutput1(this: *mut core::ffi::c_void, poutput: *mut D3D12_DRED_PAGE_FAULT_OUTPUT1) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12DeviceRemovedExtendedData1", "GetPageFaultAllocationOutput1", 6usize, |this| ((**(this as *const *const ID3D12DeviceRemovedExtendedData1_Vtbl)).GetPageFaultAllocationOutput1)(this, poutput), |result| Some(*result))
+        }
+        Self {
+            base__: <ID3D12DeviceRemovedExtendedData_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetAutoBreadcrumbsOutput1,
+            GetPageFaultAllocationOutput1,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12DeviceRemovedExtendedData1 as windows_core::Interface>::IID || <ID3D12DeviceRemovedExtendedData_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12DeviceRemovedExtendedData2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPageFaultAllocationOutput2(this: *mut core::ffi::c_void, poutput: *mut D3D12_DRED_PAGE_FAULT_OUTPUT2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12DeviceRemovedExtendedData2", "GetPageFaultAllocationOutput2", 7usize, |this| ((**(this as *const *const ID3D12DeviceRemovedExtendedData2_Vtbl)).GetPageFaultAllocationOutput2)(this, poutput), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceState(this: *mut core::ffi::c_void) -> D3D12_DRED_DEVICE_STATE {
+            windows_core::imp::proxy_call(this, "ID3D12DeviceRemovedExtendedData2", "GetDeviceState", 8usize, |this| ((**(this as *const *const ID3D12DeviceRemovedExtendedData2_Vtbl)).GetDeviceState)(this), |_| None)
+        }
+        Self { base__: <ID3D12DeviceRemovedExtendedData1_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPageFaultAllocationOutput2, GetDeviceState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12DeviceRemovedExtendedData2 as windows_core::Interface>::IID || <ID3D12DeviceRemovedExtendedData1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12DeviceRemovedExtendedDataSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAutoBreadcrumbsEnablement(this: *mut core::ffi::c_void, enablement: D3D12_DRED_ENABLEMENT) {
+            windows_core::imp::proxy_call(this, "ID3D12DeviceRemovedExtendedDataSettings", "SetAutoBreadcrumbsEnablement", 3usize, |this| ((**(this as *const *const ID3D12DeviceRemovedExtendedDataSettings_Vtbl)).SetAutoBreadcrumbsEnablement)(this, enablement), |_| None)
+        }
+        unsafe extern "system" fn SetPageFaultEnablement(this: *mut core::ffi::c_void, enablement: D3D12_DRED_ENABLEMENT) {
+            windows_core::imp::proxy_call(this, "ID3D12DeviceRemovedExtendedDataSettings", "SetPageFaultEnablement", 4usize, |this| ((**(this as *const *const ID3D12DeviceRemovedExtendedDataSettings_Vtbl)).SetPageFaultEnablement)(this, enablement), |_| None)
+        }
+        unsafe extern "system" fn SetWatsonDumpEnablement(this: *mut core::ffi::c_void, enablement: D3D12_DRED_ENABLEMENT) {
+            windows_core::imp::proxy_call(this, "ID3D12DeviceRemovedExtendedDataSettings", "SetWatsonDumpEnablement", 5usize, |this| ((**(this as *const *const ID3D12DeviceRemovedExtendedDataSettings_Vtbl)).SetWatsonDumpEnablement)(this, enablement), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetAutoBreadcrumbsEnablement,
+            SetPageFaultEnablement,
+            SetWatsonDumpEnablement,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12DeviceRemovedExtendedDataSettings as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12DeviceRemovedExtendedDataSettings1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetBreadcrumbContextEnablement(this: *mut core::ffi::c_void, enablement: D3D12_DRED_ENABLEMENT) {
+            windows_core::imp::proxy_call(this, "ID3D12DeviceRemovedExtendedDataSettings1", "SetBreadcrumbContextEnablement", 6usize, |this| ((**(this as *const *const ID3D12DeviceRemovedExtendedDataSettings1_Vtbl)).SetBreadcrumbContextEnablement)(this, enablement), |_| None)
+        }
+        Self { base__: <ID3D12DeviceRemovedExtendedDataSettings_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetBreadcrumbContextEnablement }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12DeviceRemovedExtendedDataSettings1 as windows_core::Interface>::IID || <ID3D12DeviceRemovedExtendedDataSettings_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12DeviceRemovedExtendedDataSettings2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UseMarkersOnlyAutoBreadcrumbs(this: *mut core::ffi::c_void, markersonly: super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID3D12DeviceRemovedExtendedDataSettings2", "UseMarkersOnlyAutoBreadcrumbs", 7usize, |this| ((**(this as *const *const ID3D12DeviceRemovedExtendedDataSettings2_Vtbl)).UseMarkersOnlyAutoBreadcrumbs)(this, markersonly), |_| None)
+        }
+        Self { base__: <ID3D12DeviceRemovedExtendedDataSettings1_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UseMarkersOnlyAutoBreadcrumbs }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12DeviceRemovedExtendedDataSettings2 as windows_core::Interface>::IID || <ID3D12DeviceRemovedExtendedDataSettings1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12Fence_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCompletedValue(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "ID3D12Fence", "GetCompletedValue", 8usize, |this| ((**(this as *const *const ID3D12Fence_Vtbl)).GetCompletedValue)(this), |_| None)
+        }
+        unsafe extern "system" fn SetEventOnCompletion(this: *mut core::ffi::c_void, value: u64, hevent: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Fence", "SetEventOnCompletion", 9usize, |this| ((**(this as *const *const ID3D12Fence_Vtbl)).SetEventOnCompletion)(this, value, hevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn Signal(this: *mut core::ffi::c_void, value: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Fence", "Signal", 10usize, |this| ((**(this as *const *const ID3D12Fence_Vtbl)).Signal)(this, value), |result| Some(*result))
+        }
+        Self { base__: <ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCompletedValue, SetEventOnCompletion, Signal }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Fence as windows_core::Interface>::IID || <ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12Fence1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCreationFlags(this: *mut core::ffi::c_void) -> D3D12_FENCE_FLAGS {
+            windows_core::imp::proxy_call(this, "ID3D12Fence1", "GetCreationFlags", 11usize, |this| ((**(this as *const *const ID3D12Fence1_Vtbl)).GetCreationFlags)(this), |_| None)
+        }
+        Self { base__: <ID3D12Fence_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCreationFlags }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Fence1 as windows_core::Interface>::IID || <ID3D12Fence_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D12GBVDiagnostics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetGBVEntireSubresourceStatesData(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pdata: *mut i32, datasize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12GBVDiagnostics", "GetGBVEntireSubresourceStatesData", 3usize, |this| ((**(this as *const *const ID3D12GBVDiagnostics_Vtbl)).GetGBVEntireSubresourceStatesData)(this, presource, pdata, datasize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGBVSubresourceState(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, subresource: u32, pdata: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12GBVDiagnostics", "GetGBVSubresourceState", 4usize, |this| ((**(this as *const *const ID3D12GBVDiagnostics_Vtbl)).GetGBVSubresourceState)(this, presource, subresource, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGBVResourceUniformState(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pdata: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12GBVDiagnostics", "GetGBVResourceUniformState", 5usize, |this| ((**(this as *const *const ID3D12GBVDiagnostics_Vtbl)).GetGBVResourceUniformState)(this, presource, pdata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetGBVResourceInfo(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, presourcedesc: *const D3D12_RESOURCE_DESC, presourcehash: *const u32, psubresourcestatesbyteoffset: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12GBVDiagnostics", "GetGBVResourceInfo", 6usize, |this| ((**(this as *const *const ID3D12GBVDiagnostics_Vtbl)).GetGBVResourceInfo)(this, presource, presourcedesc, presourcehash, psubresourcestatesbyteoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GBVReserved0(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12GBVDiagnostics", "GBVReserved0", 7usize, |this| ((**(this as *const *const ID3D12GBVDiagnostics_Vtbl)).GBVReserved0)(this), |_| None)
+        }
+        unsafe extern "system" fn GBVReserved1(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12GBVDiagnostics", "GBVReserved1", 8usize, |this| ((**(this as *const *const ID3D12GBVDiagnostics_Vtbl)).GBVReserved1)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetGBVEntireSubresourceStatesData,
+            GetGBVSubresourceState,
+            GetGBVResourceUniformState,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetGBVResourceInfo,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetGBVResourceInfo: 0,
+            GBVReserved0,
+            GBVReserved1,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12GBVDiagnostics as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D12GraphicsCommandList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "Close", 9usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void, pallocator: *mut core::ffi::c_void, pinitialstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "Reset", 10usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).Reset)(this, pallocator, pinitialstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearState(this: *mut core::ffi::c_void, ppipelinestate: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "ClearState", 11usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).ClearState)(this, ppipelinestate), |_| None)
+        }
+        unsafe extern "system" fn DrawInstanced(this: *mut core::ffi::c_void, vertexcountperinstance: u32, instancecount: u32, startvertexlocation: u32, startinstancelocation: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "DrawInstanced", 12usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).DrawInstanced)(this, vertexcountperinstance, instancecount, startvertexlocation, startinstancelocation), |_| None)
+        }
+        unsafe extern "system" fn DrawIndexedInstanced(this: *mut core::ffi::c_void, indexcountperinstance: u32, instancecount: u32, startindexlocation: u32, basevertexlocation: i32, startinstancelocation: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "DrawIndexedInstanced", 13usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).DrawIndexedInstanced)(this, indexcountperinstance, instancecount, startindexlocation, basevertexlocation, startinstancelocation), |_| None)
+        }
+        unsafe extern "system" fn Dispatch(this: *mut core::ffi::c_void, threadgroupcountx: u32, threadgroupcounty: u32, threadgroupcountz: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "Dispatch", 14usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).Dispatch)(this, threadgroupcountx, threadgroupcounty, threadgroupcountz), |_| None)
+        }
+        unsafe extern "system" fn CopyBufferRegion(this: *mut core::ffi::c_void, pdstbuffer: *mut core::ffi::c_void, dstoffset: u64, psrcbuffer: *mut core::ffi::c_void, srcoffset: u64, numbytes: u64) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "CopyBufferRegion", 15usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).CopyBufferRegion)(this, pdstbuffer, dstoffset, psrcbuffer, srcoffset, numbytes), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CopyTextureRegion(this: *mut core::ffi::c_void, pdst: *const D3D12_TEXTURE_COPY_LOCATION, dstx: u32, dsty: u32, dstz: u32, psrc: *const D3D12_TEXTURE_COPY_LOCATION, psrcbox: *const D3D12_BOX) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "CopyTextureRegion", 16usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).CopyTextureRegion)(this, pdst, dstx, dsty, dstz, psrc, psrcbox), |_| None)
+        }
+        unsafe extern "system" fn CopyResource(this: *mut core::ffi::c_void, pdstresource: *mut core::ffi::c_void, psrcresource: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "CopyResource", 17usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).CopyResource)(this, pdstresource, psrcresource), |_| None)
+        }
+        unsafe extern "system" fn CopyTiles(this: *mut core::ffi::c_void, ptiledresource: *mut core::ffi::c_void, ptileregionstartcoordinate: *const D3D12_TILED_RESOURCE_COORDINATE, ptileregionsize: *const D3D12_TILE_REGION_SIZE, pbuffer: *mut core::ffi::c_void, bufferstartoffsetinbytes: u64, flags: D3D12_TILE_COPY_FLAGS) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "CopyTiles", 18usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).CopyTiles)(this, ptiledresource, ptileregionstartcoordinate, ptileregionsize, pbuffer, bufferstartoffsetinbytes, flags), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn ResolveSubresource(this: *mut core::ffi::c_void, pdstresource: *mut core::ffi::c_void, dstsubresource: u32, psrcresource: *mut core::ffi::c_void, srcsubresource: u32, format: super::Dxgi::Common::DXGI_FORMAT) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "ResolveSubresource", 19usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).ResolveSubresource)(this, pdstresource, dstsubresource, psrcresource, srcsubresource, format), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn IASetPrimitiveTopology(this: *mut core::ffi::c_void, primitivetopology: super::Direct3D::D3D_PRIMITIVE_TOPOLOGY) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "IASetPrimitiveTopology", 20usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).IASetPrimitiveTopology)(this, primitivetopology), |_| None)
+        }
+        unsafe extern "system" fn RSSetViewports(this: *mut core::ffi::c_void, numviewports: u32, pviewports: *const D3D12_VIEWPORT) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "RSSetViewports", 21usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).RSSetViewports)(this, numviewports, pviewports), |_| None)
+        }
+        unsafe extern "system" fn RSSetScissorRects(this: *mut core::ffi::c_void, numrects: u32, prects: *const super::super::Foundation::RECT) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "RSSetScissorRects", 22usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).RSSetScissorRects)(this, numrects, prects), |_| None)
+        }
+        unsafe extern "system" fn OMSetBlendFactor(this: *mut core::ffi::c_void, blendfactor: *const f32) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "OMSetBlendFactor", 23usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).OMSetBlendFactor)(this, blendfactor), |_| None)
+        }
+        unsafe extern "system" fn OMSetStencilRef(this: *mut core::ffi::c_void, stencilref: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "OMSetStencilRef", 24usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).OMSetStencilRef)(this, stencilref), |_| None)
+        }
+        unsafe extern "system" fn SetPipelineState(this: *mut core::ffi::c_void, ppipelinestate: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "SetPipelineState", 25usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).SetPipelineState)(this, ppipelinestate), |_| None)
+        }
+        unsafe extern "system" fn ResourceBarrier(this: *mut core::ffi::c_void, numbarriers: u32, pbarriers: *const D3D12_RESOURCE_BARRIER) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "ResourceBarrier", 26usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).ResourceBarrier)(this, numbarriers, pbarriers), |_| None)
+        }
+        unsafe extern "system" fn ExecuteBundle(this: *mut core::ffi::c_void, pcommandlist: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "ExecuteBundle", 27usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).ExecuteBundle)(this, pcommandlist), |_| None)
+        }
+        unsafe extern "system" fn SetDescriptorHeaps(this: *mut core::ffi::c_void, numdescriptorheaps: u32, ppdescriptorheaps: *const *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "SetDescriptorHeaps", 28usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).SetDescriptorHeaps)(this, numdescriptorheaps, ppdescriptorheaps), |_| None)
+        }
+        unsafe extern "system" fn SetComputeRootSignature(this: *mut core::ffi::c_void, prootsignature: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "SetComputeRootSignature", 29usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).SetComputeRootSignature)(this, prootsignature), |_| None)
+        }
+        unsafe extern "system" fn SetGraphicsRootSignature(this: *mut core::ffi::c_void, prootsignature: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "SetGraphicsRootSignature", 30usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).SetGraphicsRootSignature)(this, prootsignature), |_| None)
+        }
+        unsafe extern "system" fn SetComputeRootDescriptorTable(this: *mut core::ffi::c_void, rootparameterindex: u32, basedescriptor: D3D12_GPU_DESCRIPTOR_HANDLE) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "SetComputeRootDescriptorTable", 31usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).SetComputeRootDescriptorTable)(this, rootparameterindex, basedescriptor), |_| None)
+        }
+        unsafe extern "system" fn SetGraphicsRootDescriptorTable(this: *mut core::ffi::c_void, rootparameterindex: u32, basedescriptor: D3D12_GPU_DESCRIPTOR_HANDLE) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "SetGraphicsRootDescriptorTable", 32usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).SetGraphicsRootDescriptorTable)(this, rootparameterindex, basedescriptor), |_| None)
+        }
+        unsafe extern "system" fn SetComputeRoot32BitConstant(this: *mut core::ffi::c_void, rootparameterindex: u32, srcdata: u32, destoffsetin32bitvalues: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "SetComputeRoot32BitConstant", 33usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).SetComputeRoot32BitConstant)(this, rootparameterindex, srcdata, destoffsetin32bitvalues), |_| None)
+        }
+        unsafe extern "system" fn SetGraphicsRoot32BitConstant(this: *mut core::ffi::c_void, rootparameterindex: u32, srcdata: u32, destoffsetin32bitvalues: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "SetGraphicsRoot32BitConstant", 34usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).SetGraphicsRoot32BitConstant)(this, rootparameterindex, srcdata, destoffsetin32bitvalues), |_| None)
+        }
+        unsafe extern "system" fn SetComputeRoot32BitConstants(this: *mut core::ffi::c_void, rootparameterindex: u32, num32bitvaluestoset: u32, psrcdata: *const core::ffi::c_void, destoffsetin32bitvalues: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "SetComputeRoot32BitConstants", 35usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).SetComputeRoot32BitConstants)(this, rootparameterindex, num32bitvaluestoset, psrcdata, destoffsetin32bitvalues), |_| None)
+        }
+        unsafe extern "system" fn SetGraphicsRoot32BitConstants(this: *mut core::ffi::c_void, rootparameterindex: u32, num32bitvaluestoset: u32, psrcdata: *const core::ffi::c_void, destoffsetin32bitvalues: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "SetGraphicsRoot32BitConstants", 36usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).SetGraphicsRoot32BitConstants)(this, rootparameterindex, num32bitvaluestoset, psrcdata, destoffsetin32bitvalues), |_| None)
+        }
+        unsafe extern "system" fn SetComputeRootConstantBufferView(this: *mut core::ffi::c_void, rootparameterindex: u32, bufferlocation: u64) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "SetComputeRootConstantBufferView", 37usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).SetComputeRootConstantBufferView)(this, rootparameterindex, bufferlocation), |_| None)
+        }
+        unsafe extern "system" fn SetGraphicsRootConstantBufferView(this: *mut core::ffi::c_void, rootparameterindex: u32, bufferlocation: u64) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "SetGraphicsRootConstantBufferView", 38usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).SetGraphicsRootConstantBufferView)(this, rootparameterindex, bufferlocation), |_| None)
+        }
+        unsafe extern "system" fn SetComputeRootShaderResourceView(this: *mut core::ffi::c_void, rootparameterindex: u32, bufferlocation: u64) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "SetComputeRootShaderResourceView", 39usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).SetComputeRootShaderResourceView)(this, rootparameterindex, bufferlocation), |_| None)
+        }
+        unsafe extern "system" fn SetGraphicsRootShaderResourceView(this: *mut core::ffi::c_void, rootparameterindex: u32, bufferlocation: u64) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "SetGraphicsRootShaderResourceView", 40usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).SetGraphicsRootShaderResourceView)(this, rootparameterindex, bufferlocation), |_| None)
+        }
+        unsafe extern "system" fn SetComputeRootUnorderedAccessView(this: *mut core::ffi::c_void, rootparameterindex: u32, bufferlocation: u64) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "SetComputeRootUnorderedAccessView", 41usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).SetComputeRootUnorderedAccessView)(this, rootparameterindex, bufferlocation), |_| None)
+        }
+        unsafe extern "system" fn SetGraphicsRootUnorderedAccessView(this: *mut core::ffi::c_void, rootparameterindex: u32, bufferlocation: u64) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "SetGraphicsRootUnorderedAccessView", 42usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).SetGraphicsRootUnorderedAccessView)(this, rootparameterindex, bufferlocation), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn IASetIndexBuffer(this: *mut core::ffi::c_void, pview: *const D3D12_INDEX_BUFFER_VIEW) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "IASetIndexBuffer", 43usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).IASetIndexBuffer)(this, pview), |_| None)
+        }
+        unsafe extern "system" fn IASetVertexBuffers(this: *mut core::ffi::c_void, startslot: u32, numviews: u32, pviews: *const D3D12_VERTEX_BUFFER_VIEW) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "IASetVertexBuffers", 44usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).IASetVertexBuffers)(this, startslot, numviews, pviews), |_| None)
+        }
+        unsafe extern "system" fn SOSetTargets(this: *mut core::ffi::c_void, startslot: u32, numviews: u32, pviews: *const D3D12_STREAM_OUTPUT_BUFFER_VIEW) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "SOSetTargets", 45usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).SOSetTargets)(this, startslot, numviews, pviews), |_| None)
+        }
+        unsafe extern "system" fn OMSetRenderTargets(this: *mut core::ffi::c_void, numrendertargetdescriptors: u32, prendertargetdescriptors: *const D3D12_CPU_DESCRIPTOR_HANDLE, rtssinglehandletodescriptorrange: super::super::Foundation::BOOL, pdepthstencildescriptor: *const D3D12_CPU_DESCRIPTOR_HANDLE) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "OMSetRenderTargets", 46usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).OMSetRenderTargets)(this, numrendertargetdescriptors, prendertargetdescriptors, rtssinglehandletodescriptorrange, pdepthstencildescriptor), |_| None)
+        }
+        unsafe extern "system" fn ClearDepthStencilView(this: *mut core::ffi::c_void, depthstencilview: D3D12_CPU_DESCRIPTOR_HANDLE, clearflags: D3D12_CLEAR_FLAGS, depth: f32, stencil: u8, numrects: u32, prects: *const super::super::Foundation::RECT) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "ClearDepthStencilView", 47usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).ClearDepthStencilView)(this, depthstencilview, clearflags, depth, stencil, numrects, prects), |_| None)
+        }
+        unsafe extern "system" fn ClearRenderTargetView(this: *mut core::ffi::c_void, rendertargetview: D3D12_CPU_DESCRIPTOR_HANDLE, colorrgba: *const f32, numrects: u32, prects: *const super::super::Foundation::RECT) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "ClearRenderTargetView", 48usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).ClearRenderTargetView)(this, rendertargetview, colorrgba, numrects, prects), |_| None)
+        }
+        unsafe extern "system" fn ClearUnorderedAccessViewUint(this: *mut core::ffi::c_void, viewgpuhandleincurrentheap: D3D12_GPU_DESCRIPTOR_HANDLE, viewcpuhandle: D3D12_CPU_DESCRIPTOR_HANDLE, presource: *mut core::ffi::c_void, values: *const u32, numrects: u32, prects: *const super::super::Foundation::RECT) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "ClearUnorderedAccessViewUint", 49usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).ClearUnorderedAccessViewUint)(this, viewgpuhandleincurrentheap, viewcpuhandle, presource, values, numrects, prects), |_| None)
+        }
+        unsafe extern "system" fn ClearUnorderedAccessViewFloat(this: *mut core::ffi::c_void, viewgpuhandleincurrentheap: D3D12_GPU_DESCRIPTOR_HANDLE, viewcpuhandle: D3D12_CPU_DESCRIPTOR_HANDLE, presource: *mut core::ffi::c_void, values: *const f32, numrects: u32, prects: *const super::super::Foundation::RECT) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "ClearUnorderedAccessViewFloat", 50usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).ClearUnorderedAccessViewFloat)(this, viewgpuhandleincurrentheap, viewcpuhandle, presource, values, numrects, prects), |_| None)
+        }
+        unsafe extern "system" fn DiscardResource(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pregion: *const D3D12_DISCARD_REGION) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "DiscardResource", 51usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).DiscardResource)(this, presource, pregion), |_| None)
+        }
+        unsafe extern "system" fn BeginQuery(this: *mut core::ffi::c_void, pqueryheap: *mut core::ffi::c_void, r#type: D3D12_QUERY_TYPE, index: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "BeginQuery", 52usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).BeginQuery)(this, pqueryheap, r#type, index), |_| None)
+        }
+        unsafe extern "system" fn EndQuery(this: *mut core::ffi::c_void, pqueryheap: *mut core::ffi::c_void, r#type: D3D12_QUERY_TYPE, index: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "EndQuery", 53usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).EndQuery)(this, pqueryheap, r#type, index), |_| None)
+        }
+        unsafe extern "system" fn ResolveQueryData(this: *mut core::ffi::c_void, pqueryheap: *mut core::ffi::c_void, r#type: D3D12_QUERY_TYPE, startindex: u32, numqueries: u32, pdestinationbuffer: *mut core::ffi::c_void, aligneddestinationbufferoffset: u64) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "ResolveQueryData", 54usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).ResolveQueryData)(this, pqueryheap, r#type, startindex, numqueries, pdestinationbuffer, aligneddestinationbufferoffset), |_| None)
+        }
+        unsafe extern "system" fn SetPredication(this: *mut core::ffi::c_void, pbuffer: *mut core::ffi::c_void, alignedbufferoffset: u64, operation: D3D12_PREDICATION_OP) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "SetPredication", 55usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).SetPredication)(this, pbuffer, alignedbufferoffset, operation), |_| None)
+        }
+        unsafe extern "system" fn SetMarker(this: *mut core::ffi::c_void, metadata: u32, pdata: *const core::ffi::c_void, size: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "SetMarker", 56usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).SetMarker)(this, metadata, pdata, size), |_| None)
+        }
+        unsafe extern "system" fn BeginEvent(this: *mut core::ffi::c_void, metadata: u32, pdata: *const core::ffi::c_void, size: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "BeginEvent", 57usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).BeginEvent)(this, metadata, pdata, size), |_| None)
+        }
+        unsafe extern "system" fn EndEvent(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "EndEvent", 58usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).EndEvent)(this), |_| None)
+        }
+        unsafe extern "system" fn ExecuteIndirect(this: *mut core::ffi::c_void, pcommandsignature: *mut core::ffi::c_void, maxcommandcount: u32, pargumentbuffer: *mut core::ffi::c_void, argumentbufferoffset: u64, pcountbuffer: *mut core::ffi::c_void, countbufferoffset: u64) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList", "ExecuteIndirect", 59usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList_Vtbl)).ExecuteIndirect)(this, pcommandsignature, maxcommandcount, pargumentbuffer, argumentbufferoffset, pcountbuffer, countbufferoffset), |_| None)
+        }
+        Self {
+            base__: <ID3D12CommandList_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Close,
+            Reset,
+            ClearState,
+            DrawInstanced,
+            DrawIndexedInstanced,
+            Dispatch,
+            CopyBufferRegion,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CopyTextureRegion,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CopyTextureRegion: 0,
+            CopyResource,
+            CopyTiles,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            ResolveSubresource,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            ResolveSubresource: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            IASetPrimitiveTopology,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            IASetPrimitiveTopology: 0,
+            RSSetViewports,
+            RSSetScissorRects,
+            OMSetBlendFactor,
+            OMSetStencilRef,
+            SetPipelineState,
+            ResourceBarrier,
+            ExecuteBundle,
+            SetDescriptorHeaps,
+            SetComputeRootSignature,
+            SetGraphicsRootSignature,
+            SetComputeRootDescriptorTable,
+            SetGraphicsRootDescriptorTable,
+            SetComputeRoot32BitConstant,
+            SetGraphicsRoot32BitConstant,
+            SetComputeRoot32BitConstants,
+            SetGraphicsRoot32BitConstants,
+            SetComputeRootConstantBufferView,
+            SetGraphicsRootConstantBufferView,
+            SetComputeRootShaderResourceView,
+            SetGraphicsRootShaderResourceView,
+            SetComputeRootUnorderedAccessView,
+            SetGraphicsRootUnorderedAccessView,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            IASetIndexBuffer,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            IASetIndexBuffer: 0,
+            IASetVertexBuffers,
+            SOSetTargets,
+            OMSetRenderTargets,
+            ClearDepthStencilView,
+            ClearRenderTargetView,
+            ClearUnorderedAccessViewUint,
+            ClearUnorderedAccessViewFloat,
+            DiscardResource,
+            BeginQuery,
+            EndQuery,
+            ResolveQueryData,
+            SetPredication,
+            SetMarker,
+            BeginEvent,
+            EndEvent,
+            ExecuteIndirect,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12GraphicsCommandList as windows_core::Interface>::IID || <ID3D12CommandList_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D12GraphicsCommandList1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AtomicCopyBufferUINT(this: *mut core::ffi::c_void, pdstbuffer: *mut core::ffi::c_void, dstoffset: u64, psrcbuffer: *mut core::ffi::c_void, srcoffset: u64, dependencies: u32, ppdependentresources: *const *mut core::ffi::c_void, pdependentsubresourceranges: *const D3D12_SUBRESOURCE_RANGE_UINT64) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList1", "AtomicCopyBufferUINT", 60usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList1_Vtbl)).AtomicCopyBufferUINT)(this, pdstbuffer, dstoffset, psrcbuffer, srcoffset, dependencies, ppdependentresources, pdependentsubresourceranges), |_| None)
+        }
+        unsafe extern "system" fn AtomicCopyBufferUINT64(this: *mut core::ffi::c_void, pdstbuffer: *mut core::ffi::c_void, dstoffset: u64, psrcbuffer: *mut core::ffi::c_void, srcoffset: u64, dependencies: u32, ppdependentresources: *const *mut core::ffi::c_void, pdependentsubresourceranges: *const D3D12_SUBRESOURCE_RANGE_UINT64) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList1", "AtomicCopyBufferUINT64", 61usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList1_Vtbl)).AtomicCopyBufferUINT64)(this, pdstbuffer, dstoffset, psrcbuffer, srcoffset, dependencies, ppdependentresources, pdependentsubresourceranges), |_| None)
+        }
+        unsafe extern "system" fn OMSetDepthBounds(this: *mut core::ffi::c_void, min: f32, max: f32) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList1", "OMSetDepthBounds", 62usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList1_Vtbl)).OMSetDepthBounds)(this, min, max), |_| None)
+        }
+        unsafe extern "system" fn SetSamplePositions(this: *mut core::ffi::c_void, numsamplesperpixel: u32, numpixels: u32, psamplepositions: *const D3D12_SAMPLE_POSITION) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList1", "SetSamplePositions", 63usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList1_Vtbl)).SetSamplePositions)(this, numsamplesperpixel, numpixels, psamplepositions), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn ResolveSubresourceRegion(this: *mut core::ffi::c_void, pdstresource: *mut core::ffi::c_void, dstsubresource: u32, dstx: u32, dsty: u32, psrcresource: *mut core::ffi::c_void, srcsubresource: u32, psrcrect: *const super::super::Foundation::RECT, format: super::Dxgi::Common::DXGI_FORMAT, resolvemode: D3D12_RESOLVE_MODE) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList1", "ResolveSubresourceRegion", 64usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList1_Vtbl)).ResolveSubresourceRegion)(this, pdstresource, dstsubresource, dstx, dsty, psrcresource, srcsubresource, psrcrect, format, resolvemode), |_| None)
+        }
+        unsafe extern "system" fn SetViewInstanceMask(this: *mut core::ffi::c_void, mask: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList1", "SetViewInstanceMask", 65usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList1_Vtbl)).SetViewInstanceMask)(this, mask), |_| None)
+        }
+        Self {
+            base__: <ID3D12GraphicsCommandList_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AtomicCopyBufferUINT,
+            AtomicCopyBufferUINT64,
+            OMSetDepthBounds,
+            SetSamplePositions,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            ResolveSubresourceRegion,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            ResolveSubresourceRegion: 0,
+            SetViewInstanceMask,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12GraphicsCommandList1 as windows_core::Interface>::IID || <ID3D12GraphicsCommandList_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D12GraphicsCommandList10_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetProgram(this: *mut core::ffi::c_void, pdesc: *const D3D12_SET_PROGRAM_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList10", "SetProgram", 84usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList10_Vtbl)).SetProgram)(this, pdesc), |_| None)
+        }
+        unsafe extern "system" fn DispatchGraph(this: *mut core::ffi::c_void, pdesc: *const D3D12_DISPATCH_GRAPH_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList10", "DispatchGraph", 85usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList10_Vtbl)).DispatchGraph)(this, pdesc), |_| None)
+        }
+        Self { base__: <ID3D12GraphicsCommandList9_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetProgram, DispatchGraph }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12GraphicsCommandList10 as windows_core::Interface>::IID || <ID3D12GraphicsCommandList9_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D12GraphicsCommandList2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WriteBufferImmediate(this: *mut core::ffi::c_void, count: u32, pparams: *const D3D12_WRITEBUFFERIMMEDIATE_PARAMETER, pmodes: *const D3D12_WRITEBUFFERIMMEDIATE_MODE) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList2", "WriteBufferImmediate", 66usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList2_Vtbl)).WriteBufferImmediate)(this, count, pparams, pmodes), |_| None)
+        }
+        Self { base__: <ID3D12GraphicsCommandList1_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WriteBufferImmediate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12GraphicsCommandList2 as windows_core::Interface>::IID || <ID3D12GraphicsCommandList1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D12GraphicsCommandList3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetProtectedResourceSession(this: *mut core::ffi::c_void, pprotectedresourcesession: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList3", "SetProtectedResourceSession", 67usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList3_Vtbl)).SetProtectedResourceSession)(this, pprotectedresourcesession), |_| None)
+        }
+        Self { base__: <ID3D12GraphicsCommandList2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetProtectedResourceSession }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12GraphicsCommandList3 as windows_core::Interface>::IID || <ID3D12GraphicsCommandList2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D12GraphicsCommandList4_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn BeginRenderPass(this: *mut core::ffi::c_void, numrendertargets: u32, prendertargets: *const D3D12_RENDER_PASS_RENDER_TARGET_DESC, pdepthstencil: *const D3D12_RENDER_PASS_DEPTH_STENCIL_DESC, flags: D3D12_RENDER_PASS_FLAGS) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList4", "BeginRenderPass", 68usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList4_Vtbl)).BeginRenderPass)(this, numrendertargets, prendertargets, pdepthstencil, flags), |_| None)
+        }
+        unsafe extern "system" fn EndRenderPass(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList4", "EndRenderPass", 69usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList4_Vtbl)).EndRenderPass)(this), |_| None)
+        }
+        unsafe extern "system" fn InitializeMetaCommand(this: *mut core::ffi::c_void, pmetacommand: *mut core::ffi::c_void, pinitializationparametersdata: *const core::ffi::c_void, initializationparametersdatasizeinbytes: usize) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList4", "InitializeMetaCommand", 70usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList4_Vtbl)).InitializeMetaCommand)(this, pmetacommand, pinitializationparametersdata, initializationparametersdatasizeinbytes), |_| None)
+        }
+        unsafe extern "system" fn ExecuteMetaCommand(this: *mut core::ffi::c_void, pmetacommand: *mut core::ffi::c_void, pexecutionparametersdata: *const core::ffi::c_void, executionparametersdatasizeinbytes: usize) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList4", "ExecuteMetaCommand", 71usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList4_Vtbl)).ExecuteMetaCommand)(this, pmetacommand, pexecutionparametersdata, executionparametersdatasizeinbytes), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn BuildRaytracingAccelerationStructure(this: *mut core::ffi::c_void, pdesc: *const D3D12_BUILD_RAYTRACING_ACCELERATION_STRUCTURE_DESC, numpostbuildinfodescs: u32, ppostbuildinfodescs: *const D3D12_RAYTRACING_ACCELERATION_STRUCTURE_POSTBUILD_INFO_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList4", "BuildRaytracingAccelerationStructure", 72usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList4_Vtbl)).BuildRaytracingAccelerationStructure)(this, pdesc, numpostbuildinfodescs, ppostbuildinfodescs), |_| None)
+        }
+        unsafe extern "system" fn EmitRaytracingAccelerationStructurePostbuildInfo(this: *mut core::ffi::c_void, pdesc: *const D3D12_RAYTRACING_ACCELERATION_STRUCTURE_POSTBUILD_INFO_DESC, numsourceaccelerationstructures: u32, psourceaccelerationstructuredata: *const u64) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList4", "EmitRaytracingAccelerationStructurePostbuildInfo", 73usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList4_Vtbl)).EmitRaytracingAccelerationStructurePostbuildInfo)(this, pdesc, numsourceaccelerationstructures, psourceaccelerationstructuredata), |_| None)
+        }
+        unsafe extern "system" fn CopyRaytracingAccelerationStructure(this: *mut core::ffi::c_void, destaccelerationstructuredata: u64, sourceaccelerationstructuredata: u64, mode: D3D12_RAYTRACING_ACCELERATION_STRUCTURE_COPY_MODE) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList4", "CopyRaytracingAccelerationStructure", 74usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList4_Vtbl)).CopyRaytracingAccelerationStructure)(this, destaccelerationstructuredata, sourceaccelerationstructuredata, mode), |_| None)
+        }
+        unsafe extern "system" fn SetPipelineState1(this: *mut core::ffi::c_void, pstateobject: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList4", "SetPipelineState1", 75usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList4_Vtbl)).SetPipelineState1)(this, pstateobject), |_| None)
+        }
+        unsafe extern "system" fn DispatchRays(this: *mut core::ffi::c_void, pdesc: *const D3D12_DISPATCH_RAYS_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList4", "DispatchRays", 76usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList4_Vtbl)).DispatchRays)(this, pdesc), |_| None)
+        }
+        Self {
+            base__: <ID3D12GraphicsCommandList3_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            BeginRenderPass,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            BeginRenderPass: 0,
+            EndRenderPass,
+            InitializeMetaCommand,
+            ExecuteMetaCommand,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            BuildRaytracingAccelerationStructure,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            BuildRaytracingAccelerationStructure: 0,
+            EmitRaytracingAccelerationStructurePostbuildInfo,
+            CopyRaytracingAccelerationStructure,
+            SetPipelineState1,
+            DispatchRays,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12GraphicsCommandList4 as windows_core::Interface>::IID || <ID3D12GraphicsCommandList3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D12GraphicsCommandList5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RSSetShadingRate(this: *mut core::ffi::c_void, baseshadingrate: D3D12_SHADING_RATE, combiners: *const D3D12_SHADING_RATE_COMBINER) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList5", "RSSetShadingRate", 77usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList5_Vtbl)).RSSetShadingRate)(this, baseshadingrate, combiners), |_| None)
+        }
+        unsafe extern "system" fn RSSetShadingRateImage(this: *mut core::ffi::c_void, shadingrateimage: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList5", "RSSetShadingRateImage", 78usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList5_Vtbl)).RSSetShadingRateImage)(this, shadingrateimage), |_| None)
+        }
+        Self { base__: <ID3D12GraphicsCommandList4_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RSSetShadingRate, RSSetShadingRateImage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12GraphicsCommandList5 as windows_core::Interface>::IID || <ID3D12GraphicsCommandList4_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D12GraphicsCommandList6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DispatchMesh(this: *mut core::ffi::c_void, threadgroupcountx: u32, threadgroupcounty: u32, threadgroupcountz: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList6", "DispatchMesh", 79usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList6_Vtbl)).DispatchMesh)(this, threadgroupcountx, threadgroupcounty, threadgroupcountz), |_| None)
+        }
+        Self { base__: <ID3D12GraphicsCommandList5_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DispatchMesh }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12GraphicsCommandList6 as windows_core::Interface>::IID || <ID3D12GraphicsCommandList5_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D12GraphicsCommandList7_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Barrier(this: *mut core::ffi::c_void, numbarriergroups: u32, pbarriergroups: *const D3D12_BARRIER_GROUP) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList7", "Barrier", 80usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList7_Vtbl)).Barrier)(this, numbarriergroups, pbarriergroups), |_| None)
+        }
+        Self { base__: <ID3D12GraphicsCommandList6_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Barrier }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12GraphicsCommandList7 as windows_core::Interface>::IID || <ID3D12GraphicsCommandList6_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D12GraphicsCommandList8_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OMSetFrontAndBackStencilRef(this: *mut core::ffi::c_void, frontstencilref: u32, backstencilref: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList8", "OMSetFrontAndBackStencilRef", 81usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList8_Vtbl)).OMSetFrontAndBackStencilRef)(this, frontstencilref, backstencilref), |_| None)
+        }
+        Self { base__: <ID3D12GraphicsCommandList7_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OMSetFrontAndBackStencilRef }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12GraphicsCommandList8 as windows_core::Interface>::IID || <ID3D12GraphicsCommandList7_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D12GraphicsCommandList9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RSSetDepthBias(this: *mut core::ffi::c_void, depthbias: f32, depthbiasclamp: f32, slopescaleddepthbias: f32) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList9", "RSSetDepthBias", 82usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList9_Vtbl)).RSSetDepthBias)(this, depthbias, depthbiasclamp, slopescaleddepthbias), |_| None)
+        }
+        unsafe extern "system" fn IASetIndexBufferStripCutValue(this: *mut core::ffi::c_void, ibstripcutvalue: D3D12_INDEX_BUFFER_STRIP_CUT_VALUE) {
+            windows_core::imp::proxy_call(this, "ID3D12GraphicsCommandList9", "IASetIndexBufferStripCutValue", 83usize, |this| ((**(this as *const *const ID3D12GraphicsCommandList9_Vtbl)).IASetIndexBufferStripCutValue)(this, ibstripcutvalue), |_| None)
+        }
+        Self { base__: <ID3D12GraphicsCommandList8_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RSSetDepthBias, IASetIndexBufferStripCutValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12GraphicsCommandList9 as windows_core::Interface>::IID || <ID3D12GraphicsCommandList8_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12Heap_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, result__: *mut D3D12_HEAP_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D12Heap", "GetDesc", 8usize, |this| ((**(this as *const *const ID3D12Heap_Vtbl)).GetDesc)(this, result__), |_| None)
+        }
+        Self { base__: <ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Heap as windows_core::Interface>::IID || <ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12Heap1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProtectedResourceSession(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppprotectedsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Heap1", "GetProtectedResourceSession", 9usize, |this| ((**(this as *const *const ID3D12Heap1_Vtbl)).GetProtectedResourceSession)(this, riid, ppprotectedsession), |result| Some(*result))
+        }
+        Self { base__: <ID3D12Heap_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProtectedResourceSession }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Heap1 as windows_core::Interface>::IID || <ID3D12Heap_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12InfoQueue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMessageCountLimit(this: *mut core::ffi::c_void, messagecountlimit: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "SetMessageCountLimit", 3usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).SetMessageCountLimit)(this, messagecountlimit), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearStoredMessages(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "ClearStoredMessages", 4usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).ClearStoredMessages)(this), |_| None)
+        }
+        unsafe extern "system" fn GetMessage(this: *mut core::ffi::c_void, messageindex: u64, pmessage: *mut D3D12_MESSAGE, pmessagebytelength: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "GetMessage", 5usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).GetMessage)(this, messageindex, pmessage, pmessagebytelength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumMessagesAllowedByStorageFilter(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "GetNumMessagesAllowedByStorageFilter", 6usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).GetNumMessagesAllowedByStorageFilter)(this), |_| None)
+        }
+        unsafe extern "system" fn GetNumMessagesDeniedByStorageFilter(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "GetNumMessagesDeniedByStorageFilter", 7usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).GetNumMessagesDeniedByStorageFilter)(this), |_| None)
+        }
+        unsafe extern "system" fn GetNumStoredMessages(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "GetNumStoredMessages", 8usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).GetNumStoredMessages)(this), |_| None)
+        }
+        unsafe extern "system" fn GetNumStoredMessagesAllowedByRetrievalFilter(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "GetNumStoredMessagesAllowedByRetrievalFilter", 9usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).GetNumStoredMessagesAllowedByRetrievalFilter)(this), |_| None)
+        }
+        unsafe extern "system" fn GetNumMessagesDiscardedByMessageCountLimit(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "GetNumMessagesDiscardedByMessageCountLimit", 10usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).GetNumMessagesDiscardedByMessageCountLimit)(this), |_| None)
+        }
+        unsafe extern "system" fn GetMessageCountLimit(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "GetMessageCountLimit", 11usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).GetMessageCountLimit)(this), |_| None)
+        }
+        unsafe extern "system" fn AddStorageFilterEntries(this: *mut core::ffi::c_void, pfilter: *const D3D12_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "AddStorageFilterEntries", 12usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).AddStorageFilterEntries)(this, pfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStorageFilter(this: *mut core::ffi::c_void, pfilter: *mut D3D12_INFO_QUEUE_FILTER, pfilterbytelength: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "GetStorageFilter", 13usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).GetStorageFilter)(this, pfilter, pfilterbytelength), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearStorageFilter(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "ClearStorageFilter", 14usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).ClearStorageFilter)(this), |_| None)
+        }
+        unsafe extern "system" fn PushEmptyStorageFilter(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "PushEmptyStorageFilter", 15usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).PushEmptyStorageFilter)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PushCopyOfStorageFilter(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "PushCopyOfStorageFilter", 16usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).PushCopyOfStorageFilter)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PushStorageFilter(this: *mut core::ffi::c_void, pfilter: *const D3D12_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "PushStorageFilter", 17usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).PushStorageFilter)(this, pfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn PopStorageFilter(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "PopStorageFilter", 18usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).PopStorageFilter)(this), |_| None)
+        }
+        unsafe extern "system" fn GetStorageFilterStackSize(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "GetStorageFilterStackSize", 19usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).GetStorageFilterStackSize)(this), |_| None)
+        }
+        unsafe extern "system" fn AddRetrievalFilterEntries(this: *mut core::ffi::c_void, pfilter: *const D3D12_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "AddRetrievalFilterEntries", 20usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).AddRetrievalFilterEntries)(this, pfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRetrievalFilter(this: *mut core::ffi::c_void, pfilter: *mut D3D12_INFO_QUEUE_FILTER, pfilterbytelength: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "GetRetrievalFilter", 21usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).GetRetrievalFilter)(this, pfilter, pfilterbytelength), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearRetrievalFilter(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "ClearRetrievalFilter", 22usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).ClearRetrievalFilter)(this), |_| None)
+        }
+        unsafe extern "system" fn PushEmptyRetrievalFilter(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "PushEmptyRetrievalFilter", 23usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).PushEmptyRetrievalFilter)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PushCopyOfRetrievalFilter(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "PushCopyOfRetrievalFilter", 24usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).PushCopyOfRetrievalFilter)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PushRetrievalFilter(this: *mut core::ffi::c_void, pfilter: *const D3D12_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "PushRetrievalFilter", 25usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).PushRetrievalFilter)(this, pfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn PopRetrievalFilter(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "PopRetrievalFilter", 26usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).PopRetrievalFilter)(this), |_| None)
+        }
+        unsafe extern "system" fn GetRetrievalFilterStackSize(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "GetRetrievalFilterStackSize", 27usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).GetRetrievalFilterStackSize)(this), |_| None)
+        }
+        unsafe extern "system" fn AddMessage(this: *mut core::ffi::c_void, category: D3D12_MESSAGE_CATEGORY, severity: D3D12_MESSAGE_SEVERITY, id: D3D12_MESSAGE_ID, pdescription: windows_core::PCSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "AddMessage", 28usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).AddMessage)(this, category, severity, id, pdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddApplicationMessage(this: *mut core::ffi::c_void, severity: D3D12_MESSAGE_SEVERITY, pdescription: windows_core::PCSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "AddApplicationMessage", 29usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).AddApplicationMessage)(this, severity, pdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBreakOnCategory(this: *mut core::ffi::c_void, category: D3D12_MESSAGE_CATEGORY, benable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "SetBreakOnCategory", 30usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).SetBreakOnCategory)(this, category, benable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBreakOnSeverity(this: *mut core::ffi::c_void, severity: D3D12_MESSAGE_SEVERITY, benable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "SetBreakOnSeverity", 31usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).SetBreakOnSeverity)(this, severity, benable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBreakOnID(this: *mut core::ffi::c_void, id: D3D12_MESSAGE_ID, benable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "SetBreakOnID", 32usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).SetBreakOnID)(this, id, benable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBreakOnCategory(this: *mut core::ffi::c_void, category: D3D12_MESSAGE_CATEGORY) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "GetBreakOnCategory", 33usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).GetBreakOnCategory)(this, category), |_| None)
+        }
+        unsafe extern "system" fn GetBreakOnSeverity(this: *mut core::ffi::c_void, severity: D3D12_MESSAGE_SEVERITY) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "GetBreakOnSeverity", 34usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).GetBreakOnSeverity)(this, severity), |_| None)
+        }
+        unsafe extern "system" fn GetBreakOnID(this: *mut core::ffi::c_void, id: D3D12_MESSAGE_ID) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "GetBreakOnID", 35usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).GetBreakOnID)(this, id), |_| None)
+        }
+        unsafe extern "system" fn SetMuteDebugOutput(this: *mut core::ffi::c_void, bmute: super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "SetMuteDebugOutput", 36usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).SetMuteDebugOutput)(this, bmute), |_| None)
+        }
+        unsafe extern "system" fn GetMuteDebugOutput(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue", "GetMuteDebugOutput", 37usize, |this| ((**(this as *const *const ID3D12InfoQueue_Vtbl)).GetMuteDebugOutput)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetMessageCountLimit,
+            ClearStoredMessages,
+            GetMessage,
+            GetNumMessagesAllowedByStorageFilter,
+            GetNumMessagesDeniedByStorageFilter,
+            GetNumStoredMessages,
+            GetNumStoredMessagesAllowedByRetrievalFilter,
+            GetNumMessagesDiscardedByMessageCountLimit,
+            GetMessageCountLimit,
+            AddStorageFilterEntries,
+            GetStorageFilter,
+            ClearStorageFilter,
+            PushEmptyStorageFilter,
+            PushCopyOfStorageFilter,
+            PushStorageFilter,
+            PopStorageFilter,
+            GetStorageFilterStackSize,
+            AddRetrievalFilterEntries,
+            GetRetrievalFilter,
+            ClearRetrievalFilter,
+            PushEmptyRetrievalFilter,
+            PushCopyOfRetrievalFilter,
+            PushRetrievalFilter,
+            PopRetrievalFilter,
+            GetRetrievalFilterStackSize,
+            AddMessage,
+            AddApplicationMessage,
+            SetBreakOnCategory,
+            SetBreakOnSeverity,
+            SetBreakOnID,
+            GetBreakOnCategory,
+            GetBreakOnSeverity,
+            GetBreakOnID,
+            SetMuteDebugOutput,
+            GetMuteDebugOutput,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12InfoQueue as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12InfoQueue1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterMessageCallback(this: *mut core::ffi::c_void, callbackfunc: D3D12MessageFunc, callbackfilterflags: D3D12_MESSAGE_CALLBACK_FLAGS, pcontext: *mut core::ffi::c_void, pcallbackcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue1", "RegisterMessageCallback", 38usize, |this| ((**(this as *const *const ID3D12InfoQueue1_Vtbl)).RegisterMessageCallback)(this, callbackfunc, callbackfilterflags, pcontext, pcallbackcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterMessageCallback(this: *mut core::ffi::c_void, callbackcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12InfoQueue1", "UnregisterMessageCallback", 39usize, |this| ((**(this as *const *const ID3D12InfoQueue1_Vtbl)).UnregisterMessageCallback)(this, callbackcookie), |result| Some(*result))
+        }
+        Self { base__: <ID3D12InfoQueue_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterMessageCallback, UnregisterMessageCallback }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12InfoQueue1 as windows_core::Interface>::IID || <ID3D12InfoQueue_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12LibraryReflection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D12_LIBRARY_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12LibraryReflection", "GetDesc", 3usize, |this| ((**(this as *const *const ID3D12LibraryReflection_Vtbl)).GetDesc)(this, pdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFunctionByIndex(this: *mut core::ffi::c_void, functionindex: i32) -> Option<ID3D12FunctionReflection> {
+            windows_core::imp::proxy_call(this, "ID3D12LibraryReflection", "GetFunctionByIndex", 4usize, |this| ((**(this as *const *const ID3D12LibraryReflection_Vtbl)).GetFunctionByIndex)(this, functionindex), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc, GetFunctionByIndex }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12LibraryReflection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12LifetimeOwner_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LifetimeStateUpdated(this: *mut core::ffi::c_void, newstate: D3D12_LIFETIME_STATE) {
+            windows_core::imp::proxy_call(this, "ID3D12LifetimeOwner", "LifetimeStateUpdated", 3usize, |this| ((**(this as *const *const ID3D12LifetimeOwner_Vtbl)).LifetimeStateUpdated)(this, newstate), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LifetimeStateUpdated }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12LifetimeOwner as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12LifetimeTracker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DestroyOwnedObject(this: *mut core::ffi::c_void, pobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12LifetimeTracker", "DestroyOwnedObject", 8usize, |this| ((**(this as *const *const ID3D12LifetimeTracker_Vtbl)).DestroyOwnedObject)(this, pobject), |result| Some(*result))
+        }
+        Self { base__: <ID3D12DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DestroyOwnedObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12LifetimeTracker as windows_core::Interface>::IID || <ID3D12DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12ManualWriteTrackingResource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TrackWrite(this: *mut core::ffi::c_void, subresource: u32, pwrittenrange: *const D3D12_RANGE) {
+            windows_core::imp::proxy_call(this, "ID3D12ManualWriteTrackingResource", "TrackWrite", 3usize, |this| ((**(this as *const *const ID3D12ManualWriteTrackingResource_Vtbl)).TrackWrite)(this, subresource, pwrittenrange), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TrackWrite }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12ManualWriteTrackingResource as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12MetaCommand_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRequiredParameterResourceSize(this: *mut core::ffi::c_void, stage: D3D12_META_COMMAND_PARAMETER_STAGE, parameterindex: u32) -> u64 {
+            windows_core::imp::proxy_call(this, "ID3D12MetaCommand", "GetRequiredParameterResourceSize", 8usize, |this| ((**(this as *const *const ID3D12MetaCommand_Vtbl)).GetRequiredParameterResourceSize)(this, stage, parameterindex), |_| None)
+        }
+        Self { base__: <ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRequiredParameterResourceSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12MetaCommand as windows_core::Interface>::IID || <ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12Object_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPrivateData(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pdatasize: *mut u32, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Object", "GetPrivateData", 3usize, |this| ((**(this as *const *const ID3D12Object_Vtbl)).GetPrivateData)(this, guid, pdatasize, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivateData(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, datasize: u32, pdata: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Object", "SetPrivateData", 4usize, |this| ((**(this as *const *const ID3D12Object_Vtbl)).SetPrivateData)(this, guid, datasize, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivateDataInterface(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Object", "SetPrivateDataInterface", 5usize, |this| ((**(this as *const *const ID3D12Object_Vtbl)).SetPrivateDataInterface)(this, guid, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, name: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Object", "SetName", 6usize, |this| ((**(this as *const *const ID3D12Object_Vtbl)).SetName)(this, name), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPrivateData, SetPrivateData, SetPrivateDataInterface, SetName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Object as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12Pageable_Vtbl {
+    const PROXY: Self = { Self { base__: <ID3D12DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Pageable as windows_core::Interface>::IID || <ID3D12DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D12PipelineLibrary_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StorePipeline(this: *mut core::ffi::c_void, pname: windows_core::PCWSTR, ppipeline: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12PipelineLibrary", "StorePipeline", 8usize, |this| ((**(this as *const *const ID3D12PipelineLibrary_Vtbl)).StorePipeline)(this, pname, ppipeline), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn LoadGraphicsPipeline(this: *mut core::ffi::c_void, pname: windows_core::PCWSTR, pdesc: *const D3D12_GRAPHICS_PIPELINE_STATE_DESC, riid: *const windows_core::GUID, pppipelinestate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12PipelineLibrary", "LoadGraphicsPipeline", 9usize, |this| ((**(this as *const *const ID3D12PipelineLibrary_Vtbl)).LoadGraphicsPipeline)(this, pname, pdesc, riid, pppipelinestate), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadComputePipeline(this: *mut core::ffi::c_void, pname: windows_core::PCWSTR, pdesc: *const D3D12_COMPUTE_PIPELINE_STATE_DESC, riid: *const windows_core::GUID, pppipelinestate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12PipelineLibrary", "LoadComputePipeline", 10usize, |this| ((**(this as *const *const ID3D12PipelineLibrary_Vtbl)).LoadComputePipeline)(this, pname, pdesc, riid, pppipelinestate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSerializedSize(this: *mut core::ffi::c_void) -> usize {
+            windows_core::imp::proxy_call(this, "ID3D12PipelineLibrary", "GetSerializedSize", 11usize, |this| ((**(this as *const *const ID3D12PipelineLibrary_Vtbl)).GetSerializedSize)(this), |_| None)
+        }
+        unsafe extern "system" fn Serialize(this: *mut core::ffi::c_void, pdata: *mut core::ffi::c_void, datasizeinbytes: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12PipelineLibrary", "Serialize", 12usize, |this| ((**(this as *const *const ID3D12PipelineLibrary_Vtbl)).Serialize)(this, pdata, datasizeinbytes), |result| Some(*result))
+        }
+        Self {
+            base__: <ID3D12DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StorePipeline,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            LoadGraphicsPipeline,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            LoadGraphicsPipeline: 0,
+            LoadComputePipeline,
+            GetSerializedSize,
+            Serialize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12PipelineLibrary as windows_core::Interface>::IID || <ID3D12DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D12PipelineLibrary1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LoadPipeline(this: *mut core::ffi::c_void, pname: windows_core::PCWSTR, pdesc: *const D3D12_PIPELINE_STATE_STREAM_DESC, riid: *const windows_core::GUID, pppipelinestate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12PipelineLibrary1", "LoadPipeline", 13usize, |this| ((**(this as *const *const ID3D12PipelineLibrary1_Vtbl)).LoadPipeline)(this, pname, pdesc, riid, pppipelinestate), |result| Some(*result))
+        }
+        Self { base__: <ID3D12PipelineLibrary_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LoadPipeline }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12PipelineLibrary1 as windows_core::Interface>::IID || <ID3D12PipelineLibrary_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D")]
+impl windows_core::imp::ProxyVtable for ID3D12PipelineState_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetCachedBlob(this: *mut core::ffi::c_void, ppblob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12PipelineState", "GetCachedBlob", 8usize, |this| ((**(this as *const *const ID3D12PipelineState_Vtbl)).GetCachedBlob)(this, ppblob), |result| Some(*result))
+        }
+        Self {
+            base__: <ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetCachedBlob,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetCachedBlob: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12PipelineState as windows_core::Interface>::IID || <ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12ProtectedResourceSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, result__: *mut D3D12_PROTECTED_RESOURCE_SESSION_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D12ProtectedResourceSession", "GetDesc", 10usize, |this| ((**(this as *const *const ID3D12ProtectedResourceSession_Vtbl)).GetDesc)(this, result__), |_| None)
+        }
+        Self { base__: <ID3D12ProtectedSession_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12ProtectedResourceSession as windows_core::Interface>::IID || <ID3D12ProtectedSession_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12ProtectedResourceSession1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc1(this: *mut core::ffi::c_void, result__: *mut D3D12_PROTECTED_RESOURCE_SESSION_DESC1) {
+            windows_core::imp::proxy_call(this, "ID3D12ProtectedResourceSession1", "GetDesc1", 11usize, |this| ((**(this as *const *const ID3D12ProtectedResourceSession1_Vtbl)).GetDesc1)(this, result__), |_| None)
+        }
+        Self { base__: <ID3D12ProtectedResourceSession_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc1 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12ProtectedResourceSession1 as windows_core::Interface>::IID || <ID3D12ProtectedResourceSession_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12ProtectedSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStatusFence(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppfence: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12ProtectedSession", "GetStatusFence", 8usize, |this| ((**(this as *const *const ID3D12ProtectedSession_Vtbl)).GetStatusFence)(this, riid, ppfence), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSessionStatus(this: *mut core::ffi::c_void) -> D3D12_PROTECTED_SESSION_STATUS {
+            windows_core::imp::proxy_call(this, "ID3D12ProtectedSession", "GetSessionStatus", 9usize, |this| ((**(this as *const *const ID3D12ProtectedSession_Vtbl)).GetSessionStatus)(this), |_| None)
+        }
+        Self { base__: <ID3D12DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStatusFence, GetSessionStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12ProtectedSession as windows_core::Interface>::IID || <ID3D12DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12QueryHeap_Vtbl {
+    const PROXY: Self = { Self { base__: <ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12QueryHeap as windows_core::Interface>::IID || <ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D12Resource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Map(this: *mut core::ffi::c_void, subresource: u32, preadrange: *const D3D12_RANGE, ppdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Resource", "Map", 8usize, |this| ((**(this as *const *const ID3D12Resource_Vtbl)).Map)(this, subresource, preadrange, ppdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unmap(this: *mut core::ffi::c_void, subresource: u32, pwrittenrange: *const D3D12_RANGE) {
+            windows_core::imp::proxy_call(this, "ID3D12Resource", "Unmap", 9usize, |this| ((**(this as *const *const ID3D12Resource_Vtbl)).Unmap)(this, subresource, pwrittenrange), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, result__: *mut D3D12_RESOURCE_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D12Resource", "GetDesc", 10usize, |this| ((**(this as *const *const ID3D12Resource_Vtbl)).GetDesc)(this, result__), |_| None)
+        }
+        unsafe extern "system" fn GetGPUVirtualAddress(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "ID3D12Resource", "GetGPUVirtualAddress", 11usize, |this| ((**(this as *const *const ID3D12Resource_Vtbl)).GetGPUVirtualAddress)(this), |_| None)
+        }
+        unsafe extern "system" fn WriteToSubresource(this: *mut core::ffi::c_void, dstsubresource: u32, pdstbox: *const D3D12_BOX, psrcdata: *const core::ffi::c_void, srcrowpitch: u32, srcdepthpitch: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Resource", "WriteToSubresource", 12usize, |this| ((**(this as *const *const ID3D12Resource_Vtbl)).WriteToSubresource)(this, dstsubresource, pdstbox, psrcdata, srcrowpitch, srcdepthpitch), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadFromSubresource(this: *mut core::ffi::c_void, pdstdata: *mut core::ffi::c_void, dstrowpitch: u32, dstdepthpitch: u32, srcsubresource: u32, psrcbox: *const D3D12_BOX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Resource", "ReadFromSubresource", 13usize, |this| ((**(this as *const *const ID3D12Resource_Vtbl)).ReadFromSubresource)(this, pdstdata, dstrowpitch, dstdepthpitch, srcsubresource, psrcbox), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHeapProperties(this: *mut core::ffi::c_void, pheapproperties: *mut D3D12_HEAP_PROPERTIES, pheapflags: *mut D3D12_HEAP_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Resource", "GetHeapProperties", 14usize, |this| ((**(this as *const *const ID3D12Resource_Vtbl)).GetHeapProperties)(this, pheapproperties, pheapflags), |result| Some(*result))
+        }
+        Self {
+            base__: <ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Map,
+            Unmap,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDesc,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDesc: 0,
+            GetGPUVirtualAddress,
+            WriteToSubresource,
+            ReadFromSubresource,
+            GetHeapProperties,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Resource as windows_core::Interface>::IID || <ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D12Resource1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProtectedResourceSession(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppprotectedsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12Resource1", "GetProtectedResourceSession", 15usize, |this| ((**(this as *const *const ID3D12Resource1_Vtbl)).GetProtectedResourceSession)(this, riid, ppprotectedsession), |result| Some(*result))
+        }
+        Self { base__: <ID3D12Resource_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProtectedResourceSession }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Resource1 as windows_core::Interface>::IID || <ID3D12Resource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D12Resource2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDesc1(this: *mut core::ffi::c_void, result__: *mut D3D12_RESOURCE_DESC1) {
+            windows_core::imp::proxy_call(this, "ID3D12Resource2", "GetDesc1", 16usize, |this| ((**(this as *const *const ID3D12Resource2_Vtbl)).GetDesc1)(this, result__), |_| None)
+        }
+        Self {
+            base__: <ID3D12Resource1_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDesc1,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDesc1: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Resource2 as windows_core::Interface>::IID || <ID3D12Resource1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12RootSignature_Vtbl {
+    const PROXY: Self = { Self { base__: <ID3D12DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12RootSignature as windows_core::Interface>::IID || <ID3D12DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12RootSignatureDeserializer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRootSignatureDesc(this: *mut core::ffi::c_void) -> *mut D3D12_ROOT_SIGNATURE_DESC {
+            windows_core::imp::proxy_call(this, "ID3D12RootSignatureDeserializer", "GetRootSignatureDesc", 3usize, |this| ((**(this as *const *const ID3D12RootSignatureDeserializer_Vtbl)).GetRootSignatureDesc)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRootSignatureDesc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12RootSignatureDeserializer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12SDKConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSDKVersion(this: *mut core::ffi::c_void, sdkversion: u32, sdkpath: windows_core::PCSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12SDKConfiguration", "SetSDKVersion", 3usize, |this| ((**(this as *const *const ID3D12SDKConfiguration_Vtbl)).SetSDKVersion)(this, sdkversion, sdkpath), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetSDKVersion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12SDKConfiguration as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12SDKConfiguration1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateDeviceFactory(this: *mut core::ffi::c_void, sdkversion: u32, sdkpath: windows_core::PCSTR, riid: *const windows_core::GUID, ppvfactory: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12SDKConfiguration1", "CreateDeviceFactory", 4usize, |this| ((**(this as *const *const ID3D12SDKConfiguration1_Vtbl)).CreateDeviceFactory)(this, sdkversion, sdkpath, riid, ppvfactory), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreeUnusedSDKs(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12SDKConfiguration1", "FreeUnusedSDKs", 5usize, |this| ((**(this as *const *const ID3D12SDKConfiguration1_Vtbl)).FreeUnusedSDKs)(this), |_| None)
+        }
+        Self { base__: <ID3D12SDKConfiguration_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateDeviceFactory, FreeUnusedSDKs }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12SDKConfiguration1 as windows_core::Interface>::IID || <ID3D12SDKConfiguration_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12ShaderCacheSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FindValue(this: *mut core::ffi::c_void, pkey: *const core::ffi::c_void, keysize: u32, pvalue: *mut core::ffi::c_void, pvaluesize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12ShaderCacheSession", "FindValue", 8usize, |this| ((**(this as *const *const ID3D12ShaderCacheSession_Vtbl)).FindValue)(this, pkey, keysize, pvalue, pvaluesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn StoreValue(this: *mut core::ffi::c_void, pkey: *const core::ffi::c_void, keysize: u32, pvalue: *const core::ffi::c_void, valuesize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12ShaderCacheSession", "StoreValue", 9usize, |this| ((**(this as *const *const ID3D12ShaderCacheSession_Vtbl)).StoreValue)(this, pkey, keysize, pvalue, valuesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeleteOnDestroy(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12ShaderCacheSession", "SetDeleteOnDestroy", 10usize, |this| ((**(this as *const *const ID3D12ShaderCacheSession_Vtbl)).SetDeleteOnDestroy)(this), |_| None)
+        }
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, result__: *mut D3D12_SHADER_CACHE_SESSION_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D12ShaderCacheSession", "GetDesc", 11usize, |this| ((**(this as *const *const ID3D12ShaderCacheSession_Vtbl)).GetDesc)(this, result__), |_| None)
+        }
+        Self { base__: <ID3D12DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FindValue, StoreValue, SetDeleteOnDestroy, GetDesc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12ShaderCacheSession as windows_core::Interface>::IID || <ID3D12DeviceChild_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D")]
+impl windows_core::imp::ProxyVtable for ID3D12ShaderReflection_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3D12_SHADER_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12ShaderReflection", "GetDesc", 3usize, |this| ((**(this as *const *const ID3D12ShaderReflection_Vtbl)).GetDesc)(this, pdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConstantBufferByIndex(this: *mut core::ffi::c_void, index: u32) -> Option<ID3D12ShaderReflectionConstantBuffer> {
+            windows_core::imp::proxy_call(this, "ID3D12ShaderReflection", "GetConstantBufferByIndex", 4usize, |this| ((**(this as *const *const ID3D12ShaderReflection_Vtbl)).GetConstantBufferByIndex)(this, index), |_| None)
+        }
+        unsafe extern "system" fn GetConstantBufferByName(this: *mut core::ffi::c_void, name: windows_core::PCSTR) -> Option<ID3D12ShaderReflectionConstantBuffer> {
+            windows_core::imp::proxy_call(this, "ID3D12ShaderReflection", "GetConstantBufferByName", 5usize, |this| ((**(this as *const *const ID3D12ShaderReflection_Vtbl)).GetConstantBufferByName)(this, name), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetResourceBindingDesc(this: *mut core::ffi::c_void, resourceindex: u32, pdesc: *mut D3D12_SHADER_INPUT_BIND_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12ShaderReflection", "GetResourceBindingDesc", 6usize, |this| ((**(this as *const *const ID3D12ShaderReflection_Vtbl)).GetResourceBindingDesc)(this, resourceindex, pdesc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetInputParameterDesc(this: *mut core::ffi::c_void, parameterindex: u32, pdesc: *mut D3D12_SIGNATURE_PARAMETER_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12ShaderReflection", "GetInputParameterDesc", 7usize, |this| ((**(this as *const *const ID3D12ShaderReflection_Vtbl)).GetInputParameterDesc)(this, parameterindex, pdesc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetOutputParameterDesc(this: *mut core::ffi::c_void, parameterindex: u32, pdesc: *mut D3D12_SIGNATURE_PARAMETER_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12ShaderReflection", "GetOutputParameterDesc", 8usize, |this| ((**(this as *const *const ID3D12ShaderReflection_Vtbl)).GetOutputParameterDesc)(this, parameterindex, pdesc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetPatchConstantParameterDesc(this: *mut core::ffi::c_void, parameterindex: u32, pdesc: *mut D3D12_SIGNATURE_PARAMETER_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12ShaderReflection", "GetPatchConstantParameterDesc", 9usize, |this| ((**(this as *const *const ID3D12ShaderReflection_Vtbl)).GetPatchConstantParameterDesc)(this, parameterindex, pdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVariableByName(this: *mut core::ffi::c_void, name: windows_core::PCSTR) -> Option<ID3D12ShaderReflectionVariable> {
+            windows_core::imp::proxy_call(this, "ID3D12ShaderReflection", "GetVariableByName", 10usize, |this| ((**(this as *const *const ID3D12ShaderReflection_Vtbl)).GetVariableByName)(this, name), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetResourceBindingDescByName(this: *mut core::ffi::c_void, name: windows_core::PCSTR, pdesc: *mut D3D12_SHADER_INPUT_BIND_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12ShaderReflection", "GetResourceBindingDescByName", 11usize, |this| ((**(this as *const *const ID3D12ShaderReflection_Vtbl)).GetResourceBindingDescByName)(this, name, pdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMovInstructionCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D12ShaderReflection", "GetMovInstructionCount", 12usize, |this| ((**(this as *const *const ID3D12ShaderReflection_Vtbl)).GetMovInstructionCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetMovcInstructionCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D12ShaderReflection", "GetMovcInstructionCount", 13usize, |this| ((**(this as *const *const ID3D12ShaderReflection_Vtbl)).GetMovcInstructionCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetConversionInstructionCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D12ShaderReflection", "GetConversionInstructionCount", 14usize, |this| ((**(this as *const *const ID3D12ShaderReflection_Vtbl)).GetConversionInstructionCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetBitwiseInstructionCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D12ShaderReflection", "GetBitwiseInstructionCount", 15usize, |this| ((**(this as *const *const ID3D12ShaderReflection_Vtbl)).GetBitwiseInstructionCount)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetGSInputPrimitive(this: *mut core::ffi::c_void) -> super::Direct3D::D3D_PRIMITIVE {
+            windows_core::imp::proxy_call(this, "ID3D12ShaderReflection", "GetGSInputPrimitive", 16usize, |this| ((**(this as *const *const ID3D12ShaderReflection_Vtbl)).GetGSInputPrimitive)(this), |_| None)
+        }
+        unsafe extern "system" fn IsSampleFrequencyShader(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D12ShaderReflection", "IsSampleFrequencyShader", 17usize, |this| ((**(this as *const *const ID3D12ShaderReflection_Vtbl)).IsSampleFrequencyShader)(this), |_| None)
+        }
+        unsafe extern "system" fn GetNumInterfaceSlots(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D12ShaderReflection", "GetNumInterfaceSlots", 18usize, |this| ((**(this as *const *const ID3D12ShaderReflection_Vtbl)).GetNumInterfaceSlots)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetMinFeatureLevel(this: *mut core::ffi::c_void, plevel: *mut super::Direct3D::D3D_FEATURE_LEVEL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12ShaderReflection", "GetMinFeatureLevel", 19usize, |this| ((**(this as *const *const ID3D12ShaderReflection_Vtbl)).GetMinFeatureLevel)(this, plevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetThreadGroupSize(this: *mut core::ffi::c_void, psizex: *mut u32, psizey: *mut u32, psizez: *mut u32) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D12ShaderReflection", "GetThreadGroupSize", 20usize, |this| ((**(this as *const *const ID3D12ShaderReflection_Vtbl)).GetThreadGroupSize)(this, psizex, psizey, psizez), |_| None)
+        }
+        unsafe extern "system" fn GetRequiresFlags(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "ID3D12ShaderReflection", "GetRequiresFlags", 21usize, |this| ((**(this as *const *const ID3D12ShaderReflection_Vtbl)).GetRequiresFlags)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetDesc,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetDesc: 0,
+            GetConstantBufferByIndex,
+            GetConstantBufferByName,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetResourceBindingDesc,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetResourceBindingDesc: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetInputParameterDesc,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetInputParameterDesc: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetOutputParameterDesc,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetOutputParameterDesc: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetPatchConstantParameterDesc,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetPatchConstantParameterDesc: 0,
+            GetVariableByName,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetResourceBindingDescByName,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetResourceBindingDescByName: 0,
+            GetMovInstructionCount,
+            GetMovcInstructionCount,
+            GetConversionInstructionCount,
+            GetBitwiseInstructionCount,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetGSInputPrimitive,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetGSInputPrimitive: 0,
+            IsSampleFrequencyShader,
+            GetNumInterfaceSlots,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetMinFeatureLevel,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetMinFeatureLevel: 0,
+            GetThreadGroupSize,
+            GetRequiresFlags,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12ShaderReflection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12SharingContract_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Present(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, subresource: u32, window: super::super::Foundation::HWND) {
+            windows_core::imp::proxy_call(this, "ID3D12SharingContract", "Present", 3usize, |this| ((**(this as *const *const ID3D12SharingContract_Vtbl)).Present)(this, presource, subresource, window), |_| None)
+        }
+        unsafe extern "system" fn SharedFenceSignal(this: *mut core::ffi::c_void, pfence: *mut core::ffi::c_void, fencevalue: u64) {
+            windows_core::imp::proxy_call(this, "ID3D12SharingContract", "SharedFenceSignal", 4usize, |this| ((**(this as *const *const ID3D12SharingContract_Vtbl)).SharedFenceSignal)(this, pfence, fencevalue), |_| None)
+        }
+        unsafe extern "system" fn BeginCapturableWork(this: *mut core::ffi::c_void, guid: *const windows_core::GUID) {
+            windows_core::imp::proxy_call(this, "ID3D12SharingContract", "BeginCapturableWork", 5usize, |this| ((**(this as *const *const ID3D12SharingContract_Vtbl)).BeginCapturableWork)(this, guid), |_| None)
+        }
+        unsafe extern "system" fn EndCapturableWork(this: *mut core::ffi::c_void, guid: *const windows_core::GUID) {
+            windows_core::imp::proxy_call(this, "ID3D12SharingContract", "EndCapturableWork", 6usize, |this| ((**(this as *const *const ID3D12SharingContract_Vtbl)).EndCapturableWork)(this, guid), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Present,
+            SharedFenceSignal,
+            BeginCapturableWork,
+            EndCapturableWork,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12SharingContract as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12StateObject_Vtbl {
+    const PROXY: Self = { Self { base__: <ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12StateObject as windows_core::Interface>::IID || <ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12StateObjectProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetShaderIdentifier(this: *mut core::ffi::c_void, pexportname: windows_core::PCWSTR) -> *mut core::ffi::c_void {
+            windows_core::imp::proxy_call(this, "ID3D12StateObjectProperties", "GetShaderIdentifier", 3usize, |this| ((**(this as *const *const ID3D12StateObjectProperties_Vtbl)).GetShaderIdentifier)(this, pexportname), |_| None)
+        }
+        unsafe extern "system" fn GetShaderStackSize(this: *mut core::ffi::c_void, pexportname: windows_core::PCWSTR) -> u64 {
+            windows_core::imp::proxy_call(this, "ID3D12StateObjectProperties", "GetShaderStackSize", 4usize, |this| ((**(this as *const *const ID3D12StateObjectProperties_Vtbl)).GetShaderStackSize)(this, pexportname), |_| None)
+        }
+        unsafe extern "system" fn GetPipelineStackSize(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "ID3D12StateObjectProperties", "GetPipelineStackSize", 5usize, |this| ((**(this as *const *const ID3D12StateObjectProperties_Vtbl)).GetPipelineStackSize)(this), |_| None)
+        }
+        unsafe extern "system" fn SetPipelineStackSize(this: *mut core::ffi::c_void, pipelinestacksizeinbytes: u64) {
+            windows_core::imp::proxy_call(this, "ID3D12StateObjectProperties", "SetPipelineStackSize", 6usize, |this| ((**(this as *const *const ID3D12StateObjectProperties_Vtbl)).SetPipelineStackSize)(this, pipelinestacksizeinbytes), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetShaderIdentifier,
+            GetShaderStackSize,
+            GetPipelineStackSize,
+            SetPipelineStackSize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12StateObjectProperties as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12StateObjectProperties1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProgramIdentifier(this: *mut core::ffi::c_void, result__: *mut D3D12_PROGRAM_IDENTIFIER, pprogramname: windows_core::PCWSTR) {
+            windows_core::imp::proxy_call(this, "ID3D12StateObjectProperties1", "GetProgramIdentifier", 7usize, |this| ((**(this as *const *const ID3D12StateObjectProperties1_Vtbl)).GetProgramIdentifier)(this, result__, pprogramname), |_| None)
+        }
+        Self { base__: <ID3D12StateObjectProperties_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProgramIdentifier }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12StateObjectProperties1 as windows_core::Interface>::IID || <ID3D12StateObjectProperties_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12SwapChainAssistant_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLUID(this: *mut core::ffi::c_void, result__: *mut super::super::Foundation::LUID) {
+            windows_core::imp::proxy_call(this, "ID3D12SwapChainAssistant", "GetLUID", 3usize, |this| ((**(this as *const *const ID3D12SwapChainAssistant_Vtbl)).GetLUID)(this, result__), |_| None)
+        }
+        unsafe extern "system" fn GetSwapChainObject(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12SwapChainAssistant", "GetSwapChainObject", 4usize, |this| ((**(this as *const *const ID3D12SwapChainAssistant_Vtbl)).GetSwapChainObject)(this, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentResourceAndCommandQueue(this: *mut core::ffi::c_void, riidresource: *const windows_core::GUID, ppvresource: *mut *mut core::ffi::c_void, riidqueue: *const windows_core::GUID, ppvqueue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12SwapChainAssistant", "GetCurrentResourceAndCommandQueue", 5usize, |this| ((**(this as *const *const ID3D12SwapChainAssistant_Vtbl)).GetCurrentResourceAndCommandQueue)(this, riidresource, ppvresource, riidqueue, ppvqueue), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertImplicitSync(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12SwapChainAssistant", "InsertImplicitSync", 6usize, |this| ((**(this as *const *const ID3D12SwapChainAssistant_Vtbl)).InsertImplicitSync)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetLUID,
+            GetSwapChainObject,
+            GetCurrentResourceAndCommandQueue,
+            InsertImplicitSync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12SwapChainAssistant as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12Tools_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnableShaderInstrumentation(this: *mut core::ffi::c_void, benable: super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ID3D12Tools", "EnableShaderInstrumentation", 3usize, |this| ((**(this as *const *const ID3D12Tools_Vtbl)).EnableShaderInstrumentation)(this, benable), |_| None)
+        }
+        unsafe extern "system" fn ShaderInstrumentationEnabled(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ID3D12Tools", "ShaderInstrumentationEnabled", 4usize, |this| ((**(this as *const *const ID3D12Tools_Vtbl)).ShaderInstrumentationEnabled)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnableShaderInstrumentation, ShaderInstrumentationEnabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12Tools as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12VersionedRootSignatureDeserializer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRootSignatureDescAtVersion(this: *mut core::ffi::c_void, converttoversion: D3D_ROOT_SIGNATURE_VERSION, ppdesc: *mut *mut D3D12_VERSIONED_ROOT_SIGNATURE_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VersionedRootSignatureDeserializer", "GetRootSignatureDescAtVersion", 3usize, |this| ((**(this as *const *const ID3D12VersionedRootSignatureDeserializer_Vtbl)).GetRootSignatureDescAtVersion)(this, converttoversion, ppdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUnconvertedRootSignatureDesc(this: *mut core::ffi::c_void) -> *mut D3D12_VERSIONED_ROOT_SIGNATURE_DESC {
+            windows_core::imp::proxy_call(this, "ID3D12VersionedRootSignatureDeserializer", "GetUnconvertedRootSignatureDesc", 4usize, |this| ((**(this as *const *const ID3D12VersionedRootSignatureDeserializer_Vtbl)).GetUnconvertedRootSignatureDesc)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRootSignatureDescAtVersion, GetUnconvertedRootSignatureDesc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VersionedRootSignatureDeserializer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12VirtualizationGuestDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShareWithHost(this: *mut core::ffi::c_void, pobject: *mut core::ffi::c_void, phandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VirtualizationGuestDevice", "ShareWithHost", 3usize, |this| ((**(this as *const *const ID3D12VirtualizationGuestDevice_Vtbl)).ShareWithHost)(this, pobject, phandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFenceFd(this: *mut core::ffi::c_void, pfence: *mut core::ffi::c_void, fencevalue: u64, pfencefd: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VirtualizationGuestDevice", "CreateFenceFd", 4usize, |this| ((**(this as *const *const ID3D12VirtualizationGuestDevice_Vtbl)).CreateFenceFd)(this, pfence, fencevalue, pfencefd), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShareWithHost, CreateFenceFd }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VirtualizationGuestDevice as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ID3D12WorkGraphProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNumWorkGraphs(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D12WorkGraphProperties", "GetNumWorkGraphs", 3usize, |this| ((**(this as *const *const ID3D12WorkGraphProperties_Vtbl)).GetNumWorkGraphs)(this), |_| None)
+        }
+        unsafe extern "system" fn GetProgramName(this: *mut core::ffi::c_void, workgraphindex: u32) -> windows_core::PCWSTR {
+            windows_core::imp::proxy_call(this, "ID3D12WorkGraphProperties", "GetProgramName", 4usize, |this| ((**(this as *const *const ID3D12WorkGraphProperties_Vtbl)).GetProgramName)(this, workgraphindex), |_| None)
+        }
+        unsafe extern "system" fn GetWorkGraphIndex(this: *mut core::ffi::c_void, pprogramname: windows_core::PCWSTR) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D12WorkGraphProperties", "GetWorkGraphIndex", 5usize, |this| ((**(this as *const *const ID3D12WorkGraphProperties_Vtbl)).GetWorkGraphIndex)(this, pprogramname), |_| None)
+        }
+        unsafe extern "system" fn GetNumNodes(this: *mut core::ffi::c_void, workgraphindex: u32) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D12WorkGraphProperties", "GetNumNodes", 6usize, |this| ((**(this as *const *const ID3D12WorkGraphProperties_Vtbl)).GetNumNodes)(this, workgraphindex), |_| None)
+        }
+        unsafe extern "system" fn GetNodeID(this: *mut core::ffi::c_void, result__: *mut D3D12_NODE_ID, workgraphindex: u32, nodeindex: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12WorkGraphProperties", "GetNodeID", 7usize, |this| ((**(this as *const *const ID3D12WorkGraphProperties_Vtbl)).GetNodeID)(this, result__, workgraphindex, nodeindex), |_| None)
+        }
+        unsafe extern "system" fn GetNodeIndex(this: *mut core::ffi::c_void, workgraphindex: u32, nodeid: D3D12_NODE_ID) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D12WorkGraphProperties", "GetNodeIndex", 8usize, |this| ((**(this as *const *const ID3D12WorkGraphProperties_Vtbl)).GetNodeIndex)(this, workgraphindex, nodeid), |_| None)
+        }
+        unsafe extern "system" fn GetNodeLocalRootArgumentsTableIndex(this: *mut core::ffi::c_void, workgraphindex: u32, nodeindex: u32) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D12WorkGraphProperties", "GetNodeLocalRootArgumentsTableIndex", 9usize, |this| ((**(this as *const *const ID3D12WorkGraphProperties_Vtbl)).GetNodeLocalRootArgumentsTableIndex)(this, workgraphindex, nodeindex), |_| None)
+        }
+        unsafe extern "system" fn GetNumEntrypoints(this: *mut core::ffi::c_void, workgraphindex: u32) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D12WorkGraphProperties", "GetNumEntrypoints", 10usize, |this| ((**(this as *const *const ID3D12WorkGraphProperties_Vtbl)).GetNumEntrypoints)(this, workgraphindex), |_| None)
+        }
+        unsafe extern "system" fn GetEntrypointID(this: *mut core::ffi::c_void, result__: *mut D3D12_NODE_ID, workgraphindex: u32, entrypointindex: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12WorkGraphProperties", "GetEntrypointID", 11usize, |this| ((**(this as *const *const ID3D12WorkGraphProperties_Vtbl)).GetEntrypointID)(this, result__, workgraphindex, entrypointindex), |_| None)
+        }
+        unsafe extern "system" fn GetEntrypointIndex(this: *mut core::ffi::c_void, workgraphindex: u32, nodeid: D3D12_NODE_ID) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D12WorkGraphProperties", "GetEntrypointIndex", 12usize, |this| ((**(this as *const *const ID3D12WorkGraphProperties_Vtbl)).GetEntrypointIndex)(this, workgraphindex, nodeid), |_| None)
+        }
+        unsafe extern "system" fn GetEntrypointRecordSizeInBytes(this: *mut core::ffi::c_void, workgraphindex: u32, entrypointindex: u32) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D12WorkGraphProperties", "GetEntrypointRecordSizeInBytes", 13usize, |this| ((**(this as *const *const ID3D12WorkGraphProperties_Vtbl)).GetEntrypointRecordSizeInBytes)(this, workgraphindex, entrypointindex), |_| None)
+        }
+        unsafe extern "system" fn GetWorkGraphMemoryRequirements(this: *mut core::ffi::c_void, workgraphindex: u32, pworkgraphmemoryrequirements: *mut D3D12_WORK_GRAPH_MEMORY_REQUIREMENTS) {
+            windows_core::imp::proxy_call(this, "ID3D12WorkGraphProperties", "GetWorkGraphMemoryRequirements", 14usize, |this| ((**(this as *const *const ID3D12WorkGraphProperties_Vtbl)).GetWorkGraphMemoryRequirements)(this, workgraphindex, pworkgraphmemoryrequirements), |_| None)
+        }
+        unsafe extern "system" fn GetEntrypointRecordAlignmentInBytes(this: *mut core::ffi::c_void, workgraphindex: u32, entrypointindex: u32) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D12WorkGraphProperties", "GetEntrypointRecordAlignmentInBytes", 15usize, |this| ((**(this as *const *const ID3D12WorkGraphProperties_Vtbl)).GetEntrypointRecordAlignmentInBytes)(this, workgraphindex, entrypointindex), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetNumWorkGraphs,
+            GetProgramName,
+            GetWorkGraphIndex,
+            GetNumNodes,
+            GetNodeID,
+            GetNodeIndex,
+            GetNodeLocalRootArgumentsTableIndex,
+            GetNumEntrypoints,
+            GetEntrypointID,
+            GetEntrypointIndex,
+            GetEntrypointRecordSizeInBytes,
+            GetWorkGraphMemoryRequirements,
+            GetEntrypointRecordAlignmentInBytes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12WorkGraphProperties as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -0,0 +1,1118 @@
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IDirect3D9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterSoftwareDevice(this: *mut core::ffi::c_void, pinitializefunction: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D9", "RegisterSoftwareDevice", 3usize, |this| ((**(this as *const *const IDirect3D9_Vtbl)).RegisterSoftwareDevice)(this, pinitializefunction), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAdapterCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDirect3D9", "GetAdapterCount", 4usize, |this| ((**(this as *const *const IDirect3D9_Vtbl)).GetAdapterCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetAdapterIdentifier(this: *mut core::ffi::c_void, adapter: u32, flags: u32, pidentifier: *mut D3DADAPTER_IDENTIFIER9) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D9", "GetAdapterIdentifier", 5usize, |this| ((**(this as *const *const IDirect3D9_Vtbl)).GetAdapterIdentifier)(this, adapter, flags, pidentifier), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAdapterModeCount(this: *mut core::ffi::c_void, adapter: u32, format: D3DFORMAT) -> u32 {
+            windows_core::imp::proxy_call(this, "IDirect3D9", "GetAdapterModeCount", 6usize, |this| ((**(this as *const *const IDirect3D9_Vtbl)).GetAdapterModeCount)(this, adapter, format), |_| None)
+        }
+        unsafe extern "system" fn EnumAdapterModes(this: *mut core::ffi::c_void, adapter: u32, format: D3DFORMAT, mode: u32, pmode: *mut D3DDISPLAYMODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D9", "EnumAdapterModes", 7usize, |this| ((**(this as *const *const IDirect3D9_Vtbl)).EnumAdapterModes)(this, adapter, format, mode, pmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAdapterDisplayMode(this: *mut core::ffi::c_void, adapter: u32, pmode: *mut D3DDISPLAYMODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D9", "GetAdapterDisplayMode", 8usize, |this| ((**(this as *const *const IDirect3D9_Vtbl)).GetAdapterDisplayMode)(this, adapter, pmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckDeviceType(this: *mut core::ffi::c_void, adapter: u32, devtype: D3DDEVTYPE, adapterformat: D3DFORMAT, backbufferformat: D3DFORMAT, bwindowed: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D9", "CheckDeviceType", 9usize, |this| ((**(this as *const *const IDirect3D9_Vtbl)).CheckDeviceType)(this, adapter, devtype, adapterformat, backbufferformat, bwindowed), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckDeviceFormat(this: *mut core::ffi::c_void, adapter: u32, devicetype: D3DDEVTYPE, adapterformat: D3DFORMAT, usage: u32, rtype: D3DRESOURCETYPE, checkformat: D3DFORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D9", "CheckDeviceFormat", 10usize, |this| ((**(this as *const *const IDirect3D9_Vtbl)).CheckDeviceFormat)(this, adapter, devicetype, adapterformat, usage, rtype, checkformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckDeviceMultiSampleType(this: *mut core::ffi::c_void, adapter: u32, devicetype: D3DDEVTYPE, surfaceformat: D3DFORMAT, windowed: super::super::Foundation::BOOL, multisampletype: D3DMULTISAMPLE_TYPE, pqualitylevels: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D9", "CheckDeviceMultiSampleType", 11usize, |this| ((**(this as *const *const IDirect3D9_Vtbl)).CheckDeviceMultiSampleType)(this, adapter, devicetype, surfaceformat, windowed, multisampletype, pqualitylevels), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckDepthStencilMatch(this: *mut core::ffi::c_void, adapter: u32, devicetype: D3DDEVTYPE, adapterformat: D3DFORMAT, rendertargetformat: D3DFORMAT, depthstencilformat: D3DFORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D9", "CheckDepthStencilMatch", 12usize, |this| ((**(this as *const *const IDirect3D9_Vtbl)).CheckDepthStencilMatch)(this, adapter, devicetype, adapterformat, rendertargetformat, depthstencilformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckDeviceFormatConversion(this: *mut core::ffi::c_void, adapter: u32, devicetype: D3DDEVTYPE, sourceformat: D3DFORMAT, targetformat: D3DFORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D9", "CheckDeviceFormatConversion", 13usize, |this| ((**(this as *const *const IDirect3D9_Vtbl)).CheckDeviceFormatConversion)(this, adapter, devicetype, sourceformat, targetformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceCaps(this: *mut core::ffi::c_void, adapter: u32, devicetype: D3DDEVTYPE, pcaps: *mut D3DCAPS9) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D9", "GetDeviceCaps", 14usize, |this| ((**(this as *const *const IDirect3D9_Vtbl)).GetDeviceCaps)(this, adapter, devicetype, pcaps), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetAdapterMonitor(this: *mut core::ffi::c_void, adapter: u32) -> super::Gdi::HMONITOR {
+            windows_core::imp::proxy_call(this, "IDirect3D9", "GetAdapterMonitor", 15usize, |this| ((**(this as *const *const IDirect3D9_Vtbl)).GetAdapterMonitor)(this, adapter), |_| None)
+        }
+        unsafe extern "system" fn CreateDevice(this: *mut core::ffi::c_void, adapter: u32, devicetype: D3DDEVTYPE, hfocuswindow: super::super::Foundation::HWND, behaviorflags: u32, ppresentationparameters: *mut D3DPRESENT_PARAMETERS, ppreturneddeviceinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D9", "CreateDevice", 16usize, |this| ((**(this as *const *const IDirect3D9_Vtbl)).CreateDevice)(this, adapter, devicetype, hfocuswindow, behaviorflags, ppresentationparameters, ppreturneddeviceinterface), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RegisterSoftwareDevice,
+            GetAdapterCount,
+            GetAdapterIdentifier,
+            GetAdapterModeCount,
+            EnumAdapterModes,
+            GetAdapterDisplayMode,
+            CheckDeviceType,
+            CheckDeviceFormat,
+            CheckDeviceMultiSampleType,
+            CheckDepthStencilMatch,
+            CheckDeviceFormatConversion,
+            GetDeviceCaps,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetAdapterMonitor,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetAdapterMonitor: 0,
+            CreateDevice,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3D9 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IDirect3D9Ex_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAdapterModeCountEx(this: *mut core::ffi::c_void, adapter: u32, pfilter: *const D3DDISPLAYMODEFILTER) -> u32 {
+            windows_core::imp::proxy_call(this, "IDirect3D9Ex", "GetAdapterModeCountEx", 17usize, |this| ((**(this as *const *const IDirect3D9Ex_Vtbl)).GetAdapterModeCountEx)(this, adapter, pfilter), |_| None)
+        }
+        unsafe extern "system" fn EnumAdapterModesEx(this: *mut core::ffi::c_void, adapter: u32, pfilter: *const D3DDISPLAYMODEFILTER, mode: u32, pmode: *mut D3DDISPLAYMODEEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D9Ex", "EnumAdapterModesEx", 18usize, |this| ((**(this as *const *const IDirect3D9Ex_Vtbl)).EnumAdapterModesEx)(this, adapter, pfilter, mode, pmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAdapterDisplayModeEx(this: *mut core::ffi::c_void, adapter: u32, pmode: *mut D3DDISPLAYMODEEX, protation: *mut D3DDISPLAYROTATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D9Ex", "GetAdapterDisplayModeEx", 19usize, |this| ((**(this as *const *const IDirect3D9Ex_Vtbl)).GetAdapterDisplayModeEx)(this, adapter, pmode, protation), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDeviceEx(this: *mut core::ffi::c_void, adapter: u32, devicetype: D3DDEVTYPE, hfocuswindow: super::super::Foundation::HWND, behaviorflags: u32, ppresentationparameters: *mut D3DPRESENT_PARAMETERS, pfullscreendisplaymode: *mut D3DDISPLAYMODEEX, ppreturneddeviceinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D9Ex", "CreateDeviceEx", 20usize, |this| ((**(this as *const *const IDirect3D9Ex_Vtbl)).CreateDeviceEx)(this, adapter, devicetype, hfocuswindow, behaviorflags, ppresentationparameters, pfullscreendisplaymode, ppreturneddeviceinterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAdapterLUID(this: *mut core::ffi::c_void, adapter: u32, pluid: *mut super::super::Foundation::LUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D9Ex", "GetAdapterLUID", 21usize, |this| ((**(this as *const *const IDirect3D9Ex_Vtbl)).GetAdapterLUID)(this, adapter, pluid), |result| Some(*result))
+        }
+        Self {
+            base__: <IDirect3D9_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetAdapterModeCountEx,
+            EnumAdapterModesEx,
+            GetAdapterDisplayModeEx,
+            CreateDeviceEx,
+            GetAdapterLUID,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3D9Ex as windows_core::Interface>::IID || <IDirect3D9_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirect3DBaseTexture9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetLOD(this: *mut core::ffi::c_void, lodnew: u32) -> u32 {
+            windows_core::imp::proxy_call(this, "IDirect3DBaseTexture9", "SetLOD", 11usize, |this| ((**(this as *const *const IDirect3DBaseTexture9_Vtbl)).SetLOD)(this, lodnew), |_| None)
+        }
+        unsafe extern "system" fn GetLOD(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDirect3DBaseTexture9", "GetLOD", 12usize, |this| ((**(this as *const *const IDirect3DBaseTexture9_Vtbl)).GetLOD)(this), |_| None)
+        }
+        unsafe extern "system" fn GetLevelCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDirect3DBaseTexture9", "GetLevelCount", 13usize, |this| ((**(this as *const *const IDirect3DBaseTexture9_Vtbl)).GetLevelCount)(this), |_| None)
+        }
+        unsafe extern "system" fn SetAutoGenFilterType(this: *mut core::ffi::c_void, filtertype: D3DTEXTUREFILTERTYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DBaseTexture9", "SetAutoGenFilterType", 14usize, |this| ((**(this as *const *const IDirect3DBaseTexture9_Vtbl)).SetAutoGenFilterType)(this, filtertype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAutoGenFilterType(this: *mut core::ffi::c_void) -> D3DTEXTUREFILTERTYPE {
+            windows_core::imp::proxy_call(this, "IDirect3DBaseTexture9", "GetAutoGenFilterType", 15usize, |this| ((**(this as *const *const IDirect3DBaseTexture9_Vtbl)).GetAutoGenFilterType)(this), |_| None)
+        }
+        unsafe extern "system" fn GenerateMipSubLevels(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IDirect3DBaseTexture9", "GenerateMipSubLevels", 16usize, |this| ((**(this as *const *const IDirect3DBaseTexture9_Vtbl)).GenerateMipSubLevels)(this), |_| None)
+        }
+        Self {
+            base__: <IDirect3DResource9_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetLOD,
+            GetLOD,
+            GetLevelCount,
+            SetAutoGenFilterType,
+            GetAutoGenFilterType,
+            GenerateMipSubLevels,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3DBaseTexture9 as windows_core::Interface>::IID || <IDirect3DResource9_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirect3DCubeTexture9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLevelDesc(this: *mut core::ffi::c_void, level: u32, pdesc: *mut D3DSURFACE_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DCubeTexture9", "GetLevelDesc", 17usize, |this| ((**(this as *const *const IDirect3DCubeTexture9_Vtbl)).GetLevelDesc)(this, level, pdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCubeMapSurface(this: *mut core::ffi::c_void, facetype: D3DCUBEMAP_FACES, level: u32, ppcubemapsurface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DCubeTexture9", "GetCubeMapSurface", 18usize, |this| ((**(this as *const *const IDirect3DCubeTexture9_Vtbl)).GetCubeMapSurface)(this, facetype, level, ppcubemapsurface), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockRect(this: *mut core::ffi::c_void, facetype: D3DCUBEMAP_FACES, level: u32, plockedrect: *mut D3DLOCKED_RECT, prect: *const super::super::Foundation::RECT, flags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DCubeTexture9", "LockRect", 19usize, |this| ((**(this as *const *const IDirect3DCubeTexture9_Vtbl)).LockRect)(this, facetype, level, plockedrect, prect, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockRect(this: *mut core::ffi::c_void, facetype: D3DCUBEMAP_FACES, level: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DCubeTexture9", "UnlockRect", 20usize, |this| ((**(this as *const *const IDirect3DCubeTexture9_Vtbl)).UnlockRect)(this, facetype, level), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDirtyRect(this: *mut core::ffi::c_void, facetype: D3DCUBEMAP_FACES, pdirtyrect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DCubeTexture9", "AddDirtyRect", 21usize, |this| ((**(this as *const *const IDirect3DCubeTexture9_Vtbl)).AddDirtyRect)(this, facetype, pdirtyrect), |result| Some(*result))
+        }
+        Self {
+            base__: <IDirect3DBaseTexture9_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetLevelDesc,
+            GetCubeMapSurface,
+            LockRect,
+            UnlockRect,
+            AddDirtyRect,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3DCubeTexture9 as windows_core::Interface>::IID || <IDirect3DBaseTexture9_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Gdi"))]
+impl windows_core::imp::ProxyVtable for IDirect3DDevice9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TestCooperativeLevel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "TestCooperativeLevel", 3usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).TestCooperativeLevel)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAvailableTextureMem(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetAvailableTextureMem", 4usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetAvailableTextureMem)(this), |_| None)
+        }
+        unsafe extern "system" fn EvictManagedResources(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "EvictManagedResources", 5usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).EvictManagedResources)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDirect3D(this: *mut core::ffi::c_void, ppd3d9: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetDirect3D", 6usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetDirect3D)(this, ppd3d9), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceCaps(this: *mut core::ffi::c_void, pcaps: *mut D3DCAPS9) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetDeviceCaps", 7usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetDeviceCaps)(this, pcaps), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayMode(this: *mut core::ffi::c_void, iswapchain: u32, pmode: *mut D3DDISPLAYMODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetDisplayMode", 8usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetDisplayMode)(this, iswapchain, pmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCreationParameters(this: *mut core::ffi::c_void, pparameters: *mut D3DDEVICE_CREATION_PARAMETERS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetCreationParameters", 9usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetCreationParameters)(this, pparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCursorProperties(this: *mut core::ffi::c_void, xhotspot: u32, yhotspot: u32, pcursorbitmap: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetCursorProperties", 10usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetCursorProperties)(this, xhotspot, yhotspot, pcursorbitmap), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCursorPosition(this: *mut core::ffi::c_void, x: i32, y: i32, flags: u32) {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetCursorPosition", 11usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetCursorPosition)(this, x, y, flags), |_| None)
+        }
+        unsafe extern "system" fn ShowCursor(this: *mut core::ffi::c_void, bshow: super::super::Foundation::BOOL) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "ShowCursor", 12usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).ShowCursor)(this, bshow), |_| None)
+        }
+        unsafe extern "system" fn CreateAdditionalSwapChain(this: *mut core::ffi::c_void, ppresentationparameters: *mut D3DPRESENT_PARAMETERS, pswapchain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "CreateAdditionalSwapChain", 13usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).CreateAdditionalSwapChain)(this, ppresentationparameters, pswapchain), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSwapChain(this: *mut core::ffi::c_void, iswapchain: u32, pswapchain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetSwapChain", 14usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetSwapChain)(this, iswapchain, pswapchain), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumberOfSwapChains(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetNumberOfSwapChains", 15usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetNumberOfSwapChains)(this), |_| None)
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void, ppresentationparameters: *mut D3DPRESENT_PARAMETERS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "Reset", 16usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).Reset)(this, ppresentationparameters), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn Present(this: *mut core::ffi::c_void, psourcerect: *const super::super::Foundation::RECT, pdestrect: *const super::super::Foundation::RECT, hdestwindowoverride: super::super::Foundation::HWND, pdirtyregion: *const super::Gdi::RGNDATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "Present", 17usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).Present)(this, psourcerect, pdestrect, hdestwindowoverride, pdirtyregion), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBackBuffer(this: *mut core::ffi::c_void, iswapchain: u32, ibackbuffer: u32, r#type: D3DBACKBUFFER_TYPE, ppbackbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetBackBuffer", 18usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetBackBuffer)(this, iswapchain, ibackbuffer, r#type, ppbackbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRasterStatus(this: *mut core::ffi::c_void, iswapchain: u32, prasterstatus: *mut D3DRASTER_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetRasterStatus", 19usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetRasterStatus)(this, iswapchain, prasterstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDialogBoxMode(this: *mut core::ffi::c_void, benabledialogs: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetDialogBoxMode", 20usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetDialogBoxMode)(this, benabledialogs), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGammaRamp(this: *mut core::ffi::c_void, iswapchain: u32, flags: u32, pramp: *const D3DGAMMARAMP) {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetGammaRamp", 21usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetGammaRamp)(this, iswapchain, flags, pramp), |_| None)
+        }
+        unsafe extern "system" fn GetGammaRamp(this: *mut core::ffi::c_void, iswapchain: u32, pramp: *mut D3DGAMMARAMP) {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetGammaRamp", 22usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetGammaRamp)(this, iswapchain, pramp), |_| None)
+        }
+        unsafe extern "system" fn CreateTexture(this: *mut core::ffi::c_void, width: u32, height: u32, levels: u32, usage: u32, format: D3DFORMAT, pool: D3DPOOL, pptexture: *mut *mut core::ffi::c_void, psharedhandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "CreateTexture", 23usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).CreateTexture)(this, width, height, levels, usage, format, pool, pptexture, psharedhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVolumeTexture(this: *mut core::ffi::c_void, width: u32, height: u32, depth: u32, levels: u32, usage: u32, format: D3DFORMAT, pool: D3DPOOL, ppvolumetexture: *mut *mut core::ffi::c_void, psharedhandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "CreateVolumeTexture", 24usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).CreateVolumeTexture)(this, width, height, depth, levels, usage, format, pool, ppvolumetexture, psharedhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCubeTexture(this: *mut core::ffi::c_void, edgelength: u32, levels: u32, usage: u32, format: D3DFORMAT, pool: D3DPOOL, ppcubetexture: *mut *mut core::ffi::c_void, psharedhandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "CreateCubeTexture", 25usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).CreateCubeTexture)(this, edgelength, levels, usage, format, pool, ppcubetexture, psharedhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVertexBuffer(this: *mut core::ffi::c_void, length: u32, usage: u32, fvf: u32, pool: D3DPOOL, ppvertexbuffer: *mut *mut core::ffi::c_void, psharedhandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "CreateVertexBuffer", 26usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).CreateVertexBuffer)(this, length, usage, fvf, pool, ppvertexbuffer, psharedhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateIndexBuffer(this: *mut core::ffi::c_void, length: u32, usage: u32, format: D3DFORMAT, pool: D3DPOOL, ppindexbuffer: *mut *mut core::ffi::c_void, psharedhandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "CreateIndexBuffer", 27usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).CreateIndexBuffer)(this, length, usage, format, pool, ppindexbuffer, psharedhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRenderTarget(this: *mut core::ffi::c_void, width: u32, height: u32, format: D3DFORMAT, multisample: D3DMULTISAMPLE_TYPE, multisamplequality: u32, lockable: super::super::Foundation::BOOL, ppsurface: *mut *mut core::ffi::c_void, psharedhandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "CreateRenderTarget", 28usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).CreateRenderTarget)(this, width, height, format, multisample, multisamplequality, lockable, ppsurface, psharedhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDepthStencilSurface(this: *mut core::ffi::c_void, width: u32, height: u32, format: D3DFORMAT, multisample: D3DMULTISAMPLE_TYPE, multisamplequality: u32, discard: super::super::Foundation::BOOL, ppsurface: *mut *mut core::ffi::c_void, psharedhandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "CreateDepthStencilSurface", 29usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).CreateDepthStencilSurface)(this, width, height, format, multisample, multisamplequality, discard, ppsurface, psharedhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateSurface(this: *mut core::ffi::c_void, psourcesurface: *mut core::ffi::c_void, psourcerect: *const super::super::Foundation::RECT, pdestinationsurface: *mut core::ffi::c_void, pdestpoint: *const super::super::Foundation::POINT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "UpdateSurface", 30usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).UpdateSurface)(this, psourcesurface, psourcerect, pdestinationsurface, pdestpoint), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateTexture(this: *mut core::ffi::c_void, psourcetexture: *mut core::ffi::c_void, pdestinationtexture: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "UpdateTexture", 31usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).UpdateTexture)(this, psourcetexture, pdestinationtexture), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRenderTargetData(this: *mut core::ffi::c_void, prendertarget: *mut core::ffi::c_void, pdestsurface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetRenderTargetData", 32usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetRenderTargetData)(this, prendertarget, pdestsurface), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFrontBufferData(this: *mut core::ffi::c_void, iswapchain: u32, pdestsurface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetFrontBufferData", 33usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetFrontBufferData)(this, iswapchain, pdestsurface), |result| Some(*result))
+        }
+        unsafe extern "system" fn StretchRect(this: *mut core::ffi::c_void, psourcesurface: *mut core::ffi::c_void, psourcerect: *const super::super::Foundation::RECT, pdestsurface: *mut core::ffi::c_void, pdestrect: *const super::super::Foundation::RECT, filter: D3DTEXTUREFILTERTYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "StretchRect", 34usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).StretchRect)(this, psourcesurface, psourcerect, pdestsurface, pdestrect, filter), |result| Some(*result))
+        }
+        unsafe extern "system" fn ColorFill(this: *mut core::ffi::c_void, psurface: *mut core::ffi::c_void, prect: *const super::super::Foundation::RECT, color: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "ColorFill", 35usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).ColorFill)(this, psurface, prect, color), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateOffscreenPlainSurface(this: *mut core::ffi::c_void, width: u32, height: u32, format: D3DFORMAT, pool: D3DPOOL, ppsurface: *mut *mut core::ffi::c_void, psharedhandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "CreateOffscreenPlainSurface", 36usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).CreateOffscreenPlainSurface)(this, width, height, format, pool, ppsurface, psharedhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRenderTarget(this: *mut core::ffi::c_void, rendertargetindex: u32, prendertarget: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetRenderTarget", 37usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetRenderTarget)(this, rendertargetindex, prendertarget), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRenderTarget(this: *mut core::ffi::c_void, rendertargetindex: u32, pprendertarget: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetRenderTarget", 38usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetRenderTarget)(this, rendertargetindex, pprendertarget), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDepthStencilSurface(this: *mut core::ffi::c_void, pnewzstencil: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetDepthStencilSurface", 39usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetDepthStencilSurface)(this, pnewzstencil), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDepthStencilSurface(this: *mut core::ffi::c_void, ppzstencilsurface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetDepthStencilSurface", 40usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetDepthStencilSurface)(this, ppzstencilsurface), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginScene(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "BeginScene", 41usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).BeginScene)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndScene(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "EndScene", 42usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).EndScene)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void, count: u32, prects: *const D3DRECT, flags: u32, color: u32, z: f32, stencil: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "Clear", 43usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).Clear)(this, count, prects, flags, color, z, stencil), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetTransform(this: *mut core::ffi::c_void, state: D3DTRANSFORMSTATETYPE, pmatrix: *const super::super::super::Foundation::Numerics::Matrix4x4) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetTransform", 44usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetTransform)(this, state, pmatrix), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn GetTransform(this: *mut core::ffi::c_void, state: D3DTRANSFORMSTATETYPE, pmatrix: *mut super::super::super::Foundation::Numerics::Matrix4x4) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetTransform", 45usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetTransform)(this, state, pmatrix), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn MultiplyTransform(this: *mut core::ffi::c_void, param0: D3DTRANSFORMSTATETYPE, param1: *const super::super::super::Foundation::Numerics::Matrix4x4) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "MultiplyTransform", 46usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).MultiplyTransform)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetViewport(this: *mut core::ffi::c_void, pviewport: *const D3DVIEWPORT9) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetViewport", 47usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetViewport)(this, pviewport), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetViewport(this: *mut core::ffi::c_void, pviewport: *mut D3DVIEWPORT9) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetViewport", 48usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetViewport)(this, pviewport), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaterial(this: *mut core::ffi::c_void, pmaterial: *const D3DMATERIAL9) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetMaterial", 49usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetMaterial)(this, pmaterial), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaterial(this: *mut core::ffi::c_void, pmaterial: *mut D3DMATERIAL9) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetMaterial", 50usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetMaterial)(this, pmaterial), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn SetLight(this: *mut core::ffi::c_void, index: u32, param1: *const D3DLIGHT9) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetLight", 51usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetLight)(this, index, param1), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetLight(this: *mut core::ffi::c_void, index: u32, param1: *mut D3DLIGHT9) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetLight", 52usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetLight)(this, index, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn LightEnable(this: *mut core::ffi::c_void, index: u32, enable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "LightEnable", 53usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).LightEnable)(this, index, enable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLightEnable(this: *mut core::ffi::c_void, index: u32, penable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetLightEnable", 54usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetLightEnable)(this, index, penable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClipPlane(this: *mut core::ffi::c_void, index: u32, pplane: *const f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetClipPlane", 55usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetClipPlane)(this, index, pplane), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClipPlane(this: *mut core::ffi::c_void, index: u32, pplane: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetClipPlane", 56usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetClipPlane)(this, index, pplane), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRenderState(this: *mut core::ffi::c_void, state: D3DRENDERSTATETYPE, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetRenderState", 57usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetRenderState)(this, state, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRenderState(this: *mut core::ffi::c_void, state: D3DRENDERSTATETYPE, pvalue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetRenderState", 58usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetRenderState)(this, state, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateStateBlock(this: *mut core::ffi::c_void, r#type: D3DSTATEBLOCKTYPE, ppsb: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "CreateStateBlock", 59usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).CreateStateBlock)(this, r#type, ppsb), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginStateBlock(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "BeginStateBlock", 60usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).BeginStateBlock)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndStateBlock(this: *mut core::ffi::c_void, ppsb: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "EndStateBlock", 61usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).EndStateBlock)(this, ppsb), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClipStatus(this: *mut core::ffi::c_void, pclipstatus: *const D3DCLIPSTATUS9) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetClipStatus", 62usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetClipStatus)(this, pclipstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClipStatus(this: *mut core::ffi::c_void, pclipstatus: *mut D3DCLIPSTATUS9) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetClipStatus", 63usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetClipStatus)(this, pclipstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTexture(this: *mut core::ffi::c_void, stage: u32, pptexture: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetTexture", 64usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetTexture)(this, stage, pptexture), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTexture(this: *mut core::ffi::c_void, stage: u32, ptexture: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetTexture", 65usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetTexture)(this, stage, ptexture), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTextureStageState(this: *mut core::ffi::c_void, stage: u32, r#type: D3DTEXTURESTAGESTATETYPE, pvalue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetTextureStageState", 66usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetTextureStageState)(this, stage, r#type, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTextureStageState(this: *mut core::ffi::c_void, stage: u32, r#type: D3DTEXTURESTAGESTATETYPE, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetTextureStageState", 67usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetTextureStageState)(this, stage, r#type, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSamplerState(this: *mut core::ffi::c_void, sampler: u32, r#type: D3DSAMPLERSTATETYPE, pvalue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetSamplerState", 68usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetSamplerState)(this, sampler, r#type, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSamplerState(this: *mut core::ffi::c_void, sampler: u32, r#type: D3DSAMPLERSTATETYPE, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetSamplerState", 69usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetSamplerState)(this, sampler, r#type, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValidateDevice(this: *mut core::ffi::c_void, pnumpasses: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "ValidateDevice", 70usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).ValidateDevice)(this, pnumpasses), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn SetPaletteEntries(this: *mut core::ffi::c_void, palettenumber: u32, pentries: *const super::Gdi::PALETTEENTRY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetPaletteEntries", 71usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetPaletteEntries)(this, palettenumber, pentries), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetPaletteEntries(this: *mut core::ffi::c_void, palettenumber: u32, pentries: *mut super::Gdi::PALETTEENTRY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetPaletteEntries", 72usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetPaletteEntries)(this, palettenumber, pentries), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentTexturePalette(this: *mut core::ffi::c_void, palettenumber: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetCurrentTexturePalette", 73usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetCurrentTexturePalette)(this, palettenumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentTexturePalette(this: *mut core::ffi::c_void, palettenumber: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetCurrentTexturePalette", 74usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetCurrentTexturePalette)(this, palettenumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScissorRect(this: *mut core::ffi::c_void, prect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetScissorRect", 75usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetScissorRect)(this, prect), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScissorRect(this: *mut core::ffi::c_void, prect: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetScissorRect", 76usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetScissorRect)(this, prect), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSoftwareVertexProcessing(this: *mut core::ffi::c_void, bsoftware: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetSoftwareVertexProcessing", 77usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetSoftwareVertexProcessing)(this, bsoftware), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSoftwareVertexProcessing(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetSoftwareVertexProcessing", 78usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetSoftwareVertexProcessing)(this), |_| None)
+        }
+        unsafe extern "system" fn SetNPatchMode(this: *mut core::ffi::c_void, nsegments: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetNPatchMode", 79usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetNPatchMode)(this, nsegments), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNPatchMode(this: *mut core::ffi::c_void) -> f32 {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetNPatchMode", 80usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetNPatchMode)(this), |_| None)
+        }
+        unsafe extern "system" fn DrawPrimitive(this: *mut core::ffi::c_void, primitivetype: D3DPRIMITIVETYPE, startvertex: u32, primitivecount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "DrawPrimitive", 81usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).DrawPrimitive)(this, primitivetype, startvertex, primitivecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn DrawIndexedPrimitive(this: *mut core::ffi::c_void, param0: D3DPRIMITIVETYPE, basevertexindex: i32, minvertexindex: u32, numvertices: u32, startindex: u32, primcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "DrawIndexedPrimitive", 82usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).DrawIndexedPrimitive)(this, param0, basevertexindex, minvertexindex, numvertices, startindex, primcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn DrawPrimitiveUP(this: *mut core::ffi::c_void, primitivetype: D3DPRIMITIVETYPE, primitivecount: u32, pvertexstreamzerodata: *const core::ffi::c_void, vertexstreamzerostride: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "DrawPrimitiveUP", 83usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).DrawPrimitiveUP)(this, primitivetype, primitivecount, pvertexstreamzerodata, vertexstreamzerostride), |result| Some(*result))
+        }
+        unsafe extern "system" fn DrawIndexedPrimitiveUP(this: *mut core::ffi::c_void, primitivetype: D3DPRIMITIVETYPE, minvertexindex: u32, numvertices: u32, primitivecount: u32, pindexdata: *const core::ffi::c_void, indexdataformat: D3DFORMAT, pvertexstreamzerodata: *const core::ffi::c_void, vertexstreamzerostride: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "DrawIndexedPrimitiveUP", 84usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).DrawIndexedPrimitiveUP)(this, primitivetype, minvertexindex, numvertices, primitivecount, pindexdata, indexdataformat, pvertexstreamzerodata, vertexstreamzerostride), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessVertices(this: *mut core::ffi::c_void, srcstartindex: u32, destindex: u32, vertexcount: u32, pdestbuffer: *mut core::ffi::c_void, pvertexdecl: *mut core::ffi::c_void, flags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "ProcessVertices", 85usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).ProcessVertices)(this, srcstartindex, destindex, vertexcount, pdestbuffer, pvertexdecl, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVertexDeclaration(this: *mut core::ffi::c_void, pvertexelements: *const D3DVERTEXELEMENT9, ppdecl: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "CreateVertexDeclaration", 86usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).CreateVertexDeclaration)(this, pvertexelements, ppdecl), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVertexDeclaration(this: *mut core::ffi::c_void, pdecl: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetVertexDeclaration", 87usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetVertexDeclaration)(this, pdecl), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVertexDeclaration(this: *mut core::ffi::c_void, ppdecl: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetVertexDeclaration", 88usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetVertexDeclaration)(this, ppdecl), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFVF(this: *mut core::ffi::c_void, fvf: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetFVF", 89usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetFVF)(this, fvf), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFVF(this: *mut core::ffi::c_void, pfvf: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetFVF", 90usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetFVF)(this, pfvf), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVertexShader(this: *mut core::ffi::c_void, pfunction: *const u32, ppshader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "CreateVertexShader", 91usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).CreateVertexShader)(this, pfunction, ppshader), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVertexShader(this: *mut core::ffi::c_void, pshader: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetVertexShader", 92usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetVertexShader)(this, pshader), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVertexShader(this: *mut core::ffi::c_void, ppshader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetVertexShader", 93usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetVertexShader)(this, ppshader), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVertexShaderConstantF(this: *mut core::ffi::c_void, startregister: u32, pconstantdata: *const f32, vector4fcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetVertexShaderConstantF", 94usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetVertexShaderConstantF)(this, startregister, pconstantdata, vector4fcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVertexShaderConstantF(this: *mut core::ffi::c_void, startregister: u32, pconstantdata: *mut f32, vector4fcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetVertexShaderConstantF", 95usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetVertexShaderConstantF)(this, startregister, pconstantdata, vector4fcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVertexShaderConstantI(this: *mut core::ffi::c_void, startregister: u32, pconstantdata: *const i32, vector4icount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetVertexShaderConstantI", 96usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetVertexShaderConstantI)(this, startregister, pconstantdata, vector4icount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVertexShaderConstantI(this: *mut core::ffi::c_void, startregister: u32, pconstantdata: *mut i32, vector4icount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetVertexShaderConstantI", 97usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetVertexShaderConstantI)(this, startregister, pconstantdata, vector4icount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVertexShaderConstantB(this: *mut core::ffi::c_void, startregister: u32, pconstantdata: *const super::super::Foundation::BOOL, boolcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetVertexShaderConstantB", 98usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetVertexShaderConstantB)(this, startregister, pconstantdata, boolcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVertexShaderConstantB(this: *mut core::ffi::c_void, startregister: u32, pconstantdata: *mut super::super::Foundation::BOOL, boolcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetVertexShaderConstantB", 99usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetVertexShaderConstantB)(this, startregister, pconstantdata, boolcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStreamSource(this: *mut core::ffi::c_void, streamnumber: u32, pstreamdata: *mut core::ffi::c_void, offsetinbytes: u32, stride: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetStreamSource", 100usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetStreamSource)(this, streamnumber, pstreamdata, offsetinbytes, stride), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamSource(this: *mut core::ffi::c_void, streamnumber: u32, ppstreamdata: *mut *mut core::ffi::c_void, poffsetinbytes: *mut u32, pstride: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetStreamSource", 101usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetStreamSource)(this, streamnumber, ppstreamdata, poffsetinbytes, pstride), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStreamSourceFreq(this: *mut core::ffi::c_void, streamnumber: u32, setting: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetStreamSourceFreq", 102usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetStreamSourceFreq)(this, streamnumber, setting), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamSourceFreq(this: *mut core::ffi::c_void, streamnumber: u32, psetting: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetStreamSourceFreq", 103usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetStreamSourceFreq)(this, streamnumber, psetting), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIndices(this: *mut core::ffi::c_void, pindexdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetIndices", 104usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetIndices)(this, pindexdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIndices(this: *mut core::ffi::c_void, ppindexdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetIndices", 105usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetIndices)(this, ppindexdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePixelShader(this: *mut core::ffi::c_void, pfunction: *const u32, ppshader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "CreatePixelShader", 106usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).CreatePixelShader)(this, pfunction, ppshader), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPixelShader(this: *mut core::ffi::c_void, pshader: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetPixelShader", 107usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetPixelShader)(this, pshader), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPixelShader(this: *mut core::ffi::c_void, ppshader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetPixelShader", 108usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetPixelShader)(this, ppshader), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPixelShaderConstantF(this: *mut core::ffi::c_void, startregister: u32, pconstantdata: *const f32, vector4fcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetPixelShaderConstantF", 109usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetPixelShaderConstantF)(this, startregister, pconstantdata, vector4fcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPixelShaderConstantF(this: *mut core::ffi::c_void, startregister: u32, pconstantdata: *mut f32, vector4fcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetPixelShaderConstantF", 110usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetPixelShaderConstantF)(this, startregister, pconstantdata, vector4fcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPixelShaderConstantI(this: *mut core::ffi::c_void, startregister: u32, pconstantdata: *const i32, vector4icount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetPixelShaderConstantI", 111usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetPixelShaderConstantI)(this, startregister, pconstantdata, vector4icount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPixelShaderConstantI(this: *mut core::ffi::c_void, startregister: u32, pconstantdata: *mut i32, vector4icount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetPixelShaderConstantI", 112usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetPixelShaderConstantI)(this, startregister, pconstantdata, vector4icount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPixelShaderConstantB(this: *mut core::ffi::c_void, startregister: u32, pconstantdata: *const super::super::Foundation::BOOL, boolcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "SetPixelShaderConstantB", 113usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).SetPixelShaderConstantB)(this, startregister, pconstantdata, boolcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPixelShaderConstantB(this: *mut core::ffi::c_void, startregister: u32, pconstantdata: *mut super::super::Foundation::BOOL, boolcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "GetPixelShaderConstantB", 114usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).GetPixelShaderConstantB)(this, startregister, pconstantdata, boolcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn DrawRectPatch(this: *mut core::ffi::c_void, handle: u32, pnumsegs: *const f32, prectpatchinfo: *const D3DRECTPATCH_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "DrawRectPatch", 115usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).DrawRectPatch)(this, handle, pnumsegs, prectpatchinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn DrawTriPatch(this: *mut core::ffi::c_void, handle: u32, pnumsegs: *const f32, ptripatchinfo: *const D3DTRIPATCH_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "DrawTriPatch", 116usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).DrawTriPatch)(this, handle, pnumsegs, ptripatchinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeletePatch(this: *mut core::ffi::c_void, handle: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "DeletePatch", 117usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).DeletePatch)(this, handle), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateQuery(this: *mut core::ffi::c_void, r#type: D3DQUERYTYPE, ppquery: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9", "CreateQuery", 118usize, |this| ((**(this as *const *const IDirect3DDevice9_Vtbl)).CreateQuery)(this, r#type, ppquery), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TestCooperativeLevel,
+            GetAvailableTextureMem,
+            EvictManagedResources,
+            GetDirect3D,
+            GetDeviceCaps,
+            GetDisplayMode,
+            GetCreationParameters,
+            SetCursorProperties,
+            SetCursorPosition,
+            ShowCursor,
+            CreateAdditionalSwapChain,
+            GetSwapChain,
+            GetNumberOfSwapChains,
+            Reset,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            Present,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            Present: 0,
+            GetBackBuffer,
+            GetRasterStatus,
+            SetDialogBoxMode,
+            SetGammaRamp,
+            GetGammaRamp,
+            CreateTexture,
+            CreateVolumeTexture,
+            CreateCubeTexture,
+            CreateVertexBuffer,
+            CreateIndexBuffer,
+            CreateRenderTarget,
+            CreateDepthStencilSurface,
+            UpdateSurface,
+            UpdateTexture,
+            GetRenderTargetData,
+            GetFrontBufferData,
+            StretchRect,
+            ColorFill,
+            CreateOffscreenPlainSurface,
+            SetRenderTarget,
+            GetRenderTarget,
+            SetDepthStencilSurface,
+            GetDepthStencilSurface,
+            BeginScene,
+            EndScene,
+            Clear,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetTransform,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetTransform: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            GetTransform,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            GetTransform: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            MultiplyTransform,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            MultiplyTransform: 0,
+            SetViewport,
+            GetViewport,
+            SetMaterial,
+            GetMaterial,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            SetLight,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            SetLight: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetLight,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetLight: 0,
+            LightEnable,
+            GetLightEnable,
+            SetClipPlane,
+            GetClipPlane,
+            SetRenderState,
+            GetRenderState,
+            CreateStateBlock,
+            BeginStateBlock,
+            EndStateBlock,
+            SetClipStatus,
+            GetClipStatus,
+            GetTexture,
+            SetTexture,
+            GetTextureStageState,
+            SetTextureStageState,
+            GetSamplerState,
+            SetSamplerState,
+            ValidateDevice,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            SetPaletteEntries,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            SetPaletteEntries: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetPaletteEntries,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetPaletteEntries: 0,
+            SetCurrentTexturePalette,
+            GetCurrentTexturePalette,
+            SetScissorRect,
+            GetScissorRect,
+            SetSoftwareVertexProcessing,
+            GetSoftwareVertexProcessing,
+            SetNPatchMode,
+            GetNPatchMode,
+            DrawPrimitive,
+            DrawIndexedPrimitive,
+            DrawPrimitiveUP,
+            DrawIndexedPrimitiveUP,
+            ProcessVertices,
+            CreateVertexDeclaration,
+            SetVertexDeclaration,
+            GetVertexDeclaration,
+            SetFVF,
+            GetFVF,
+            CreateVertexShader,
+            SetVertexShader,
+            GetVertexShader,
+            SetVertexShaderConstantF,
+            GetVertexShaderConstantF,
+            SetVertexShaderConstantI,
+            GetVertexShaderConstantI,
+            SetVertexShaderConstantB,
+            GetVertexShaderConstantB,
+            SetStreamSource,
+            GetStreamSource,
+            SetStreamSourceFreq,
+            GetStreamSourceFreq,
+            SetIndices,
+            GetIndices,
+            CreatePixelShader,
+            SetPixelShader,
+            GetPixelShader,
+            SetPixelShaderConstantF,
+            GetPixelShaderConstantF,
+            SetPixelShaderConstantI,
+            GetPixelShaderConstantI,
+            SetPixelShaderConstantB,
+            GetPixelShaderConstantB,
+            DrawRectPatch,
+            DrawTriPatch,
+            DeletePatch,
+            CreateQuery,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3DDevice9 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct3D", feature = "Win32_Graphics_Gdi"))]
+impl windows_core::imp::ProxyVtable for IDirect3DDevice9Ex_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetConvolutionMonoKernel(this: *mut core::ffi::c_void, width: u32, height: u32, rows: *mut f32, columns: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9Ex", "SetConvolutionMonoKernel", 119usize, |this| ((**(this as *const *const IDirect3DDevice9Ex_Vtbl)).SetConvolutionMonoKernel)(this, width, height, rows, columns), |result| Some(*result))
+        }
+        unsafe extern "system" fn ComposeRects(this: *mut core::ffi::c_void, psrc: *mut core::ffi::c_void, pdst: *mut core::ffi::c_void, psrcrectdescs: *mut core::ffi::c_void, numrects: u32, pdstrectdescs: *mut core::ffi::c_void, operation: D3DCOMPOSERECTSOP, xoffset: i32, yoffset: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9Ex", "ComposeRects", 120usize, |this| ((**(this as *const *const IDirect3DDevice9Ex_Vtbl)).ComposeRects)(this, psrc, pdst, psrcrectdescs, numrects, pdstrectdescs, operation, xoffset, yoffset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn PresentEx(this: *mut core::ffi::c_void, psourcerect: *const super::super::Foundation::RECT, pdestrect: *const super::super::Foundation::RECT, hdestwindowoverride: super::super::Foundation::HWND, pdirtyregion: *const super::Gdi::RGNDATA, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9Ex", "PresentEx", 121usize, |this| ((**(this as *const *const IDirect3DDevice9Ex_Vtbl)).PresentEx)(this, psourcerect, pdestrect, hdestwindowoverride, pdirtyregion, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGPUThreadPriority(this: *mut core::ffi::c_void, ppriority: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9Ex", "GetGPUThreadPriority", 122usize, |this| ((**(this as *const *const IDirect3DDevice9Ex_Vtbl)).GetGPUThreadPriority)(this, ppriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGPUThreadPriority(this: *mut core::ffi::c_void, priority: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9Ex", "SetGPUThreadPriority", 123usize, |this| ((**(this as *const *const IDirect3DDevice9Ex_Vtbl)).SetGPUThreadPriority)(this, priority), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForVBlank(this: *mut core::ffi::c_void, iswapchain: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9Ex", "WaitForVBlank", 124usize, |this| ((**(this as *const *const IDirect3DDevice9Ex_Vtbl)).WaitForVBlank)(this, iswapchain), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckResourceResidency(this: *mut core::ffi::c_void, presourcearray: *mut *mut core::ffi::c_void, numresources: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9Ex", "CheckResourceResidency", 125usize, |this| ((**(this as *const *const IDirect3DDevice9Ex_Vtbl)).CheckResourceResidency)(this, presourcearray, numresources), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaximumFrameLatency(this: *mut core::ffi::c_void, maxlatency: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9Ex", "SetMaximumFrameLatency", 126usize, |this| ((**(this as *const *const IDirect3DDevice9Ex_Vtbl)).SetMaximumFrameLatency)(this, maxlatency), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaximumFrameLatency(this: *mut core::ffi::c_void, pmaxlatency: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9Ex", "GetMaximumFrameLatency", 127usize, |this| ((**(this as *const *const IDirect3DDevice9Ex_Vtbl)).GetMaximumFrameLatency)(this, pmaxlatency), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckDeviceState(this: *mut core::ffi::c_void, hdestinationwindow: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9Ex", "CheckDeviceState", 128usize, |this| ((**(this as *const *const IDirect3DDevice9Ex_Vtbl)).CheckDeviceState)(this, hdestinationwindow), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRenderTargetEx(this: *mut core::ffi::c_void, width: u32, height: u32, format: D3DFORMAT, multisample: D3DMULTISAMPLE_TYPE, multisamplequality: u32, lockable: super::super::Foundation::BOOL, ppsurface: *mut *mut core::ffi::c_void, psharedhandle: *mut super::super::Foundation::HANDLE, usage: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9Ex", "CreateRenderTargetEx", 129usize, |this| ((**(this as *const *const IDirect3DDevice9Ex_Vtbl)).CreateRenderTargetEx)(this, width, height, format, multisample, multisamplequality, lockable, ppsurface, psharedhandle, usage), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateOffscreenPlainSurfaceEx(this: *mut core::ffi::c_void, width: u32, height: u32, format: D3DFORMAT, pool: D3DPOOL, ppsurface: *mut *mut core::ffi::c_void, psharedhandle: *mut super::super::Foundation::HANDLE, usage: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9Ex", "CreateOffscreenPlainSurfaceEx", 130usize, |this| ((**(this as *const *const IDirect3DDevice9Ex_Vtbl)).CreateOffscreenPlainSurfaceEx)(this, width, height, format, pool, ppsurface, psharedhandle, usage), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDepthStencilSurfaceEx(this: *mut core::ffi::c_void, width: u32, height: u32, format: D3DFORMAT, multisample: D3DMULTISAMPLE_TYPE, multisamplequality: u32, discard: super::super::Foundation::BOOL, ppsurface: *mut *mut core::ffi::c_void, psharedhandle: *mut super::super::Foundation::HANDLE, usage: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9Ex", "CreateDepthStencilSurfaceEx", 131usize, |this| ((**(this as *const *const IDirect3DDevice9Ex_Vtbl)).CreateDepthStencilSurfaceEx)(this, width, height, format, multisample, multisamplequality, discard, ppsurface, psharedhandle, usage), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetEx(this: *mut core::ffi::c_void, ppresentationparameters: *mut D3DPRESENT_PARAMETERS, pfullscreendisplaymode: *mut D3DDISPLAYMODEEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9Ex", "ResetEx", 132usize, |this| ((**(this as *const *const IDirect3DDevice9Ex_Vtbl)).ResetEx)(this, ppresentationparameters, pfullscreendisplaymode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayModeEx(this: *mut core::ffi::c_void, iswapchain: u32, pmode: *mut D3DDISPLAYMODEEX, protation: *mut D3DDISPLAYROTATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9Ex", "GetDisplayModeEx", 133usize, |this| ((**(this as *const *const IDirect3DDevice9Ex_Vtbl)).GetDisplayModeEx)(this, iswapchain, pmode, protation), |result| Some(*result))
+        }
+        Self {
+            base__: <IDirect3DDevice9_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetConvolutionMonoKernel,
+            ComposeRects,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            PresentEx,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            PresentEx: 0,
+            GetGPUThreadPriority,
+            SetGPUThreadPriority,
+            WaitForVBlank,
+            CheckResourceResidency,
+            SetMaximumFrameLatency,
+            GetMaximumFrameLatency,
+            CheckDeviceState,
+            CreateRenderTargetEx,
+            CreateOffscreenPlainSurfaceEx,
+            CreateDepthStencilSurfaceEx,
+            ResetEx,
+            GetDisplayModeEx,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3DDevice9Ex as windows_core::Interface>::IID || <IDirect3DDevice9_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirect3DIndexBuffer9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Lock(this: *mut core::ffi::c_void, offsettolock: u32, sizetolock: u32, ppbdata: *mut *mut core::ffi::c_void, flags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DIndexBuffer9", "Lock", 11usize, |this| ((**(this as *const *const IDirect3DIndexBuffer9_Vtbl)).Lock)(this, offsettolock, sizetolock, ppbdata, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unlock(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DIndexBuffer9", "Unlock", 12usize, |this| ((**(this as *const *const IDirect3DIndexBuffer9_Vtbl)).Unlock)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3DINDEXBUFFER_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DIndexBuffer9", "GetDesc", 13usize, |this| ((**(this as *const *const IDirect3DIndexBuffer9_Vtbl)).GetDesc)(this, pdesc), |result| Some(*result))
+        }
+        Self { base__: <IDirect3DResource9_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Lock, Unlock, GetDesc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3DIndexBuffer9 as windows_core::Interface>::IID || <IDirect3DResource9_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirect3DPixelShader9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDevice(this: *mut core::ffi::c_void, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DPixelShader9", "GetDevice", 3usize, |this| ((**(this as *const *const IDirect3DPixelShader9_Vtbl)).GetDevice)(this, ppdevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFunction(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, psizeofdata: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DPixelShader9", "GetFunction", 4usize, |this| ((**(this as *const *const IDirect3DPixelShader9_Vtbl)).GetFunction)(this, param0, psizeofdata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDevice, GetFunction }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3DPixelShader9 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirect3DQuery9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDevice(this: *mut core::ffi::c_void, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DQuery9", "GetDevice", 3usize, |this| ((**(this as *const *const IDirect3DQuery9_Vtbl)).GetDevice)(this, ppdevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void) -> D3DQUERYTYPE {
+            windows_core::imp::proxy_call(this, "IDirect3DQuery9", "GetType", 4usize, |this| ((**(this as *const *const IDirect3DQuery9_Vtbl)).GetType)(this), |_| None)
+        }
+        unsafe extern "system" fn GetDataSize(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDirect3DQuery9", "GetDataSize", 5usize, |this| ((**(this as *const *const IDirect3DQuery9_Vtbl)).GetDataSize)(this), |_| None)
+        }
+        unsafe extern "system" fn Issue(this: *mut core::ffi::c_void, dwissueflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DQuery9", "Issue", 6usize, |this| ((**(this as *const *const IDirect3DQuery9_Vtbl)).Issue)(this, dwissueflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetData(this: *mut core::ffi::c_void, pdata: *mut core::ffi::c_void, dwsize: u32, dwgetdataflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DQuery9", "GetData", 7usize, |this| ((**(this as *const *const IDirect3DQuery9_Vtbl)).GetData)(this, pdata, dwsize, dwgetdataflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDevice, GetType, GetDataSize, Issue, GetData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3DQuery9 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirect3DResource9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDevice(this: *mut core::ffi::c_void, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DResource9", "GetDevice", 3usize, |this| ((**(this as *const *const IDirect3DResource9_Vtbl)).GetDevice)(this, ppdevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivateData(this: *mut core::ffi::c_void, refguid: *const windows_core::GUID, pdata: *const core::ffi::c_void, sizeofdata: u32, flags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DResource9", "SetPrivateData", 4usize, |this| ((**(this as *const *const IDirect3DResource9_Vtbl)).SetPrivateData)(this, refguid, pdata, sizeofdata, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrivateData(this: *mut core::ffi::c_void, refguid: *const windows_core::GUID, pdata: *mut core::ffi::c_void, psizeofdata: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DResource9", "GetPrivateData", 5usize, |this| ((**(this as *const *const IDirect3DResource9_Vtbl)).GetPrivateData)(this, refguid, pdata, psizeofdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreePrivateData(this: *mut core::ffi::c_void, refguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DResource9", "FreePrivateData", 6usize, |this| ((**(this as *const *const IDirect3DResource9_Vtbl)).FreePrivateData)(this, refguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPriority(this: *mut core::ffi::c_void, prioritynew: u32) -> u32 {
+            windows_core::imp::proxy_call(this, "IDirect3DResource9", "SetPriority", 7usize, |this| ((**(this as *const *const IDirect3DResource9_Vtbl)).SetPriority)(this, prioritynew), |_| None)
+        }
+        unsafe extern "system" fn GetPriority(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDirect3DResource9", "GetPriority", 8usize, |this| ((**(this as *const *const IDirect3DResource9_Vtbl)).GetPriority)(this), |_| None)
+        }
+        unsafe extern "system" fn PreLoad(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IDirect3DResource9", "PreLoad", 9usize, |this| ((**(this as *const *const IDirect3DResource9_Vtbl)).PreLoad)(this), |_| None)
+        }
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void) -> D3DRESOURCETYPE {
+            windows_core::imp::proxy_call(this, "IDirect3DResource9", "GetType", 10usize, |this| ((**(this as *const *const IDirect3DResource9_Vtbl)).GetType)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDevice,
+            SetPrivateData,
+            GetPrivateData,
+            FreePrivateData,
+            SetPriority,
+            GetPriority,
+            PreLoad,
+            GetType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3DResource9 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirect3DStateBlock9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDevice(this: *mut core::ffi::c_void, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DStateBlock9", "GetDevice", 3usize, |this| ((**(this as *const *const IDirect3DStateBlock9_Vtbl)).GetDevice)(this, ppdevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn Capture(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DStateBlock9", "Capture", 4usize, |this| ((**(this as *const *const IDirect3DStateBlock9_Vtbl)).Capture)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Apply(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DStateBlock9", "Apply", 5usize, |this| ((**(this as *const *const IDirect3DStateBlock9_Vtbl)).Apply)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDevice, Capture, Apply }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3DStateBlock9 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IDirect3DSurface9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetContainer(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppcontainer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DSurface9", "GetContainer", 11usize, |this| ((**(this as *const *const IDirect3DSurface9_Vtbl)).GetContainer)(this, riid, ppcontainer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3DSURFACE_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DSurface9", "GetDesc", 12usize, |this| ((**(this as *const *const IDirect3DSurface9_Vtbl)).GetDesc)(this, pdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockRect(this: *mut core::ffi::c_void, plockedrect: *mut D3DLOCKED_RECT, prect: *const super::super::Foundation::RECT, flags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DSurface9", "LockRect", 13usize, |this| ((**(this as *const *const IDirect3DSurface9_Vtbl)).LockRect)(this, plockedrect, prect, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockRect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DSurface9", "UnlockRect", 14usize, |this| ((**(this as *const *const IDirect3DSurface9_Vtbl)).UnlockRect)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetDC(this: *mut core::ffi::c_void, phdc: *mut super::Gdi::HDC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DSurface9", "GetDC", 15usize, |this| ((**(this as *const *const IDirect3DSurface9_Vtbl)).GetDC)(this, phdc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn ReleaseDC(this: *mut core::ffi::c_void, hdc: super::Gdi::HDC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DSurface9", "ReleaseDC", 16usize, |this| ((**(this as *const *const IDirect3DSurface9_Vtbl)).ReleaseDC)(this, hdc), |result| Some(*result))
+        }
+        Self {
+            base__: <IDirect3DResource9_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetContainer,
+            GetDesc,
+            LockRect,
+            UnlockRect,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetDC,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetDC: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            ReleaseDC,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            ReleaseDC: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3DSurface9 as windows_core::Interface>::IID || <IDirect3DResource9_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IDirect3DSwapChain9_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn Present(this: *mut core::ffi::c_void, psourcerect: *const super::super::Foundation::RECT, pdestrect: *const super::super::Foundation::RECT, hdestwindowoverride: super::super::Foundation::HWND, pdirtyregion: *const super::Gdi::RGNDATA, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DSwapChain9", "Present", 3usize, |this| ((**(this as *const *const IDirect3DSwapChain9_Vtbl)).Present)(this, psourcerect, pdestrect, hdestwindowoverride, pdirtyregion, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFrontBufferData(this: *mut core::ffi::c_void, pdestsurface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DSwapChain9", "GetFrontBufferData", 4usize, |this| ((**(this as *const *const IDirect3DSwapChain9_Vtbl)).GetFrontBufferData)(this, pdestsurface), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBackBuffer(this: *mut core::ffi::c_void, ibackbuffer: u32, r#type: D3DBACKBUFFER_TYPE, ppbackbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DSwapChain9", "GetBackBuffer", 5usize, |this| ((**(this as *const *const IDirect3DSwapChain9_Vtbl)).GetBackBuffer)(this, ibackbuffer, r#type, ppbackbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRasterStatus(this: *mut core::ffi::c_void, prasterstatus: *mut D3DRASTER_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DSwapChain9", "GetRasterStatus", 6usize, |this| ((**(this as *const *const IDirect3DSwapChain9_Vtbl)).GetRasterStatus)(this, prasterstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayMode(this: *mut core::ffi::c_void, pmode: *mut D3DDISPLAYMODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DSwapChain9", "GetDisplayMode", 7usize, |this| ((**(this as *const *const IDirect3DSwapChain9_Vtbl)).GetDisplayMode)(this, pmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDevice(this: *mut core::ffi::c_void, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DSwapChain9", "GetDevice", 8usize, |this| ((**(this as *const *const IDirect3DSwapChain9_Vtbl)).GetDevice)(this, ppdevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPresentParameters(this: *mut core::ffi::c_void, ppresentationparameters: *mut D3DPRESENT_PARAMETERS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DSwapChain9", "GetPresentParameters", 9usize, |this| ((**(this as *const *const IDirect3DSwapChain9_Vtbl)).GetPresentParameters)(this, ppresentationparameters), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            Present,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            Present: 0,
+            GetFrontBufferData,
+            GetBackBuffer,
+            GetRasterStatus,
+            GetDisplayMode,
+            GetDevice,
+            GetPresentParameters,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3DSwapChain9 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IDirect3DSwapChain9Ex_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLastPresentCount(this: *mut core::ffi::c_void, plastpresentcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DSwapChain9Ex", "GetLastPresentCount", 10usize, |this| ((**(this as *const *const IDirect3DSwapChain9Ex_Vtbl)).GetLastPresentCount)(this, plastpresentcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPresentStats(this: *mut core::ffi::c_void, ppresentationstatistics: *mut D3DPRESENTSTATS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DSwapChain9Ex", "GetPresentStats", 11usize, |this| ((**(this as *const *const IDirect3DSwapChain9Ex_Vtbl)).GetPresentStats)(this, ppresentationstatistics), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayModeEx(this: *mut core::ffi::c_void, pmode: *mut D3DDISPLAYMODEEX, protation: *mut D3DDISPLAYROTATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DSwapChain9Ex", "GetDisplayModeEx", 12usize, |this| ((**(this as *const *const IDirect3DSwapChain9Ex_Vtbl)).GetDisplayModeEx)(this, pmode, protation), |result| Some(*result))
+        }
+        Self { base__: <IDirect3DSwapChain9_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetLastPresentCount, GetPresentStats, GetDisplayModeEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3DSwapChain9Ex as windows_core::Interface>::IID || <IDirect3DSwapChain9_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirect3DTexture9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLevelDesc(this: *mut core::ffi::c_void, level: u32, pdesc: *mut D3DSURFACE_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DTexture9", "GetLevelDesc", 17usize, |this| ((**(this as *const *const IDirect3DTexture9_Vtbl)).GetLevelDesc)(this, level, pdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSurfaceLevel(this: *mut core::ffi::c_void, level: u32, ppsurfacelevel: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DTexture9", "GetSurfaceLevel", 18usize, |this| ((**(this as *const *const IDirect3DTexture9_Vtbl)).GetSurfaceLevel)(this, level, ppsurfacelevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockRect(this: *mut core::ffi::c_void, level: u32, plockedrect: *mut D3DLOCKED_RECT, prect: *const super::super::Foundation::RECT, flags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DTexture9", "LockRect", 19usize, |this| ((**(this as *const *const IDirect3DTexture9_Vtbl)).LockRect)(this, level, plockedrect, prect, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockRect(this: *mut core::ffi::c_void, level: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DTexture9", "UnlockRect", 20usize, |this| ((**(this as *const *const IDirect3DTexture9_Vtbl)).UnlockRect)(this, level), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDirtyRect(this: *mut core::ffi::c_void, pdirtyrect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DTexture9", "AddDirtyRect", 21usize, |this| ((**(this as *const *const IDirect3DTexture9_Vtbl)).AddDirtyRect)(this, pdirtyrect), |result| Some(*result))
+        }
+        Self { base__: <IDirect3DBaseTexture9_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetLevelDesc, GetSurfaceLevel, LockRect, UnlockRect, AddDirtyRect }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3DTexture9 as windows_core::Interface>::IID || <IDirect3DBaseTexture9_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirect3DVertexBuffer9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Lock(this: *mut core::ffi::c_void, offsettolock: u32, sizetolock: u32, ppbdata: *mut *mut core::ffi::c_void, flags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DVertexBuffer9", "Lock", 11usize, |this| ((**(this as *const *const IDirect3DVertexBuffer9_Vtbl)).Lock)(this, offsettolock, sizetolock, ppbdata, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unlock(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DVertexBuffer9", "Unlock", 12usize, |this| ((**(this as *const *const IDirect3DVertexBuffer9_Vtbl)).Unlock)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3DVERTEXBUFFER_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DVertexBuffer9", "GetDesc", 13usize, |this| ((**(this as *const *const IDirect3DVertexBuffer9_Vtbl)).GetDesc)(this, pdesc), |result| Some(*result))
+        }
+        Self { base__: <IDirect3DResource9_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Lock, Unlock, GetDesc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3DVertexBuffer9 as windows_core::Interface>::IID || <IDirect3DResource9_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirect3DVertexDeclaration9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDevice(this: *mut core::ffi::c_void, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DVertexDeclaration9", "GetDevice", 3usize, |this| ((**(this as *const *const IDirect3DVertexDeclaration9_Vtbl)).GetDevice)(this, ppdevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeclaration(this: *mut core::ffi::c_void, pelement: *mut D3DVERTEXELEMENT9, pnumelements: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DVertexDeclaration9", "GetDeclaration", 4usize, |this| ((**(this as *const *const IDirect3DVertexDeclaration9_Vtbl)).GetDeclaration)(this, pelement, pnumelements), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDevice, GetDeclaration }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3DVertexDeclaration9 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirect3DVertexShader9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDevice(this: *mut core::ffi::c_void, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DVertexShader9", "GetDevice", 3usize, |this| ((**(this as *const *const IDirect3DVertexShader9_Vtbl)).GetDevice)(this, ppdevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFunction(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, psizeofdata: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DVertexShader9", "GetFunction", 4usize, |this| ((**(this as *const *const IDirect3DVertexShader9_Vtbl)).GetFunction)(this, param0, psizeofdata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDevice, GetFunction }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3DVertexShader9 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirect3DVolume9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDevice(this: *mut core::ffi::c_void, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DVolume9", "GetDevice", 3usize, |this| ((**(this as *const *const IDirect3DVolume9_Vtbl)).GetDevice)(this, ppdevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivateData(this: *mut core::ffi::c_void, refguid: *const windows_core::GUID, pdata: *const core::ffi::c_void, sizeofdata: u32, flags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DVolume9", "SetPrivateData", 4usize, |this| ((**(this as *const *const IDirect3DVolume9_Vtbl)).SetPrivateData)(this, refguid, pdata, sizeofdata, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrivateData(this: *mut core::ffi::c_void, refguid: *const windows_core::GUID, pdata: *mut core::ffi::c_void, psizeofdata: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DVolume9", "GetPrivateData", 5usize, |this| ((**(this as *const *const IDirect3DVolume9_Vtbl)).GetPrivateData)(this, refguid, pdata, psizeofdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreePrivateData(this: *mut core::ffi::c_void, refguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DVolume9", "FreePrivateData", 6usize, |this| ((**(this as *const *const IDirect3DVolume9_Vtbl)).FreePrivateData)(this, refguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContainer(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppcontainer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DVolume9", "GetContainer", 7usize, |this| ((**(this as *const *const IDirect3DVolume9_Vtbl)).GetContainer)(this, riid, ppcontainer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut D3DVOLUME_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DVolume9", "GetDesc", 8usize, |this| ((**(this as *const *const IDirect3DVolume9_Vtbl)).GetDesc)(this, pdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockBox(this: *mut core::ffi::c_void, plockedvolume: *mut D3DLOCKED_BOX, pbox: *const D3DBOX, flags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DVolume9", "LockBox", 9usize, |this| ((**(this as *const *const IDirect3DVolume9_Vtbl)).LockBox)(this, plockedvolume, pbox, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockBox(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DVolume9", "UnlockBox", 10usize, |this| ((**(this as *const *const IDirect3DVolume9_Vtbl)).UnlockBox)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDevice,
+            SetPrivateData,
+            GetPrivateData,
+            FreePrivateData,
+            GetContainer,
+            GetDesc,
+            LockBox,
+            UnlockBox,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3DVolume9 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirect3DVolumeTexture9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLevelDesc(this: *mut core::ffi::c_void, level: u32, pdesc: *mut D3DVOLUME_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DVolumeTexture9", "GetLevelDesc", 17usize, |this| ((**(this as *const *const IDirect3DVolumeTexture9_Vtbl)).GetLevelDesc)(this, level, pdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVolumeLevel(this: *mut core::ffi::c_void, level: u32, ppvolumelevel: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DVolumeTexture9", "GetVolumeLevel", 18usize, |this| ((**(this as *const *const IDirect3DVolumeTexture9_Vtbl)).GetVolumeLevel)(this, level, ppvolumelevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockBox(this: *mut core::ffi::c_void, level: u32, plockedvolume: *mut D3DLOCKED_BOX, pbox: *const D3DBOX, flags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DVolumeTexture9", "LockBox", 19usize, |this| ((**(this as *const *const IDirect3DVolumeTexture9_Vtbl)).LockBox)(this, level, plockedvolume, pbox, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockBox(this: *mut core::ffi::c_void, level: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DVolumeTexture9", "UnlockBox", 20usize, |this| ((**(this as *const *const IDirect3DVolumeTexture9_Vtbl)).UnlockBox)(this, level), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDirtyBox(this: *mut core::ffi::c_void, pdirtybox: *const D3DBOX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DVolumeTexture9", "AddDirtyBox", 21usize, |this| ((**(this as *const *const IDirect3DVolumeTexture9_Vtbl)).AddDirtyBox)(this, pdirtybox), |result| Some(*result))
+        }
+        Self { base__: <IDirect3DBaseTexture9_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetLevelDesc, GetVolumeLevel, LockBox, UnlockBox, AddDirtyBox }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3DVolumeTexture9 as windows_core::Interface>::IID || <IDirect3DBaseTexture9_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
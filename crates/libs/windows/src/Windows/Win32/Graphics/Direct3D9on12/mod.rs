@@ -53,7 +53,8 @@ pub struct IDirect3DDevice9On12_Vtbl {
 }
 pub const MAX_D3D9ON12_QUEUES: u32 = 2u32;
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D9ON12_ARGS {
     pub Enable9On12: super::super::Foundation::BOOL,
     pub pD3D12Device: core::mem::ManuallyDrop<Option<windows_core::IUnknown>>,
@@ -80,3 +81,5 @@ pub type PFN_Direct3DCreate9On12 = Option<unsafe extern "system" fn(sdkversion:
 pub type PFN_Direct3DCreate9On12Ex = Option<unsafe extern "system" fn(sdkversion: u32, poverridelist: *mut D3D9ON12_ARGS, numoverrideentries: u32, ppoutputinterface: *mut Option<super::Direct3D9::IDirect3D9Ex>) -> windows_core::HRESULT>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,32 @@
+#[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Direct3D9"))]
+impl windows_core::imp::ProxyVtable for IDirect3DDevice9On12_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetD3D12Device(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9On12", "GetD3D12Device", 3usize, |this| ((**(this as *const *const IDirect3DDevice9On12_Vtbl)).GetD3D12Device)(this, riid, ppvdevice), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Direct3D9"))]
+        unsafe extern "system" fn UnwrapUnderlyingResource(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pcommandqueue: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvresource12: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9On12", "UnwrapUnderlyingResource", 4usize, |this| ((**(this as *const *const IDirect3DDevice9On12_Vtbl)).UnwrapUnderlyingResource)(this, presource, pcommandqueue, riid, ppvresource12), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Direct3D9"))]
+        unsafe extern "system" fn ReturnUnderlyingResource(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, numsync: u32, psignalvalues: *mut u64, ppfences: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9On12", "ReturnUnderlyingResource", 5usize, |this| ((**(this as *const *const IDirect3DDevice9On12_Vtbl)).ReturnUnderlyingResource)(this, presource, numsync, psignalvalues, ppfences), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetD3D12Device,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Direct3D9"))]
+            UnwrapUnderlyingResource,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Direct3D9")))]
+            UnwrapUnderlyingResource: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Direct3D9"))]
+            ReturnUnderlyingResource,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Direct3D9")))]
+            ReturnUnderlyingResource: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3DDevice9On12 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -2565,6 +2565,7 @@ pub struct COMPOSITION_FRAME_ID_TYPE(pub i32);
 impl windows_core::TypeKind for COMPOSITION_FRAME_ID_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMPOSITION_FRAME_ID_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMPOSITION_FRAME_ID_TYPE").field(&self.0).finish()
@@ -2576,6 +2577,7 @@ pub struct DCOMPOSITION_BACKFACE_VISIBILITY(pub i32);
 impl windows_core::TypeKind for DCOMPOSITION_BACKFACE_VISIBILITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DCOMPOSITION_BACKFACE_VISIBILITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DCOMPOSITION_BACKFACE_VISIBILITY").field(&self.0).finish()
@@ -2587,6 +2589,7 @@ pub struct DCOMPOSITION_BITMAP_INTERPOLATION_MODE(pub i32);
 impl windows_core::TypeKind for DCOMPOSITION_BITMAP_INTERPOLATION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DCOMPOSITION_BITMAP_INTERPOLATION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DCOMPOSITION_BITMAP_INTERPOLATION_MODE").field(&self.0).finish()
@@ -2598,6 +2601,7 @@ pub struct DCOMPOSITION_BORDER_MODE(pub i32);
 impl windows_core::TypeKind for DCOMPOSITION_BORDER_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DCOMPOSITION_BORDER_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DCOMPOSITION_BORDER_MODE").field(&self.0).finish()
@@ -2609,6 +2613,7 @@ pub struct DCOMPOSITION_COMPOSITE_MODE(pub i32);
 impl windows_core::TypeKind for DCOMPOSITION_COMPOSITE_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DCOMPOSITION_COMPOSITE_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DCOMPOSITION_COMPOSITE_MODE").field(&self.0).finish()
@@ -2620,6 +2625,7 @@ pub struct DCOMPOSITION_DEPTH_MODE(pub i32);
 impl windows_core::TypeKind for DCOMPOSITION_DEPTH_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DCOMPOSITION_DEPTH_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DCOMPOSITION_DEPTH_MODE").field(&self.0).finish()
@@ -2631,13 +2637,15 @@ pub struct DCOMPOSITION_OPACITY_MODE(pub i32);
 impl windows_core::TypeKind for DCOMPOSITION_OPACITY_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DCOMPOSITION_OPACITY_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DCOMPOSITION_OPACITY_MODE").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COMPOSITION_FRAME_STATS {
     pub startTime: u64,
     pub targetTime: u64,
@@ -2652,7 +2660,8 @@ impl Default for COMPOSITION_FRAME_STATS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COMPOSITION_STATS {
     pub presentCount: u32,
     pub refreshCount: u32,
@@ -2668,7 +2677,8 @@ impl Default for COMPOSITION_STATS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COMPOSITION_TARGET_ID {
     pub displayAdapterLuid: super::super::Foundation::LUID,
     pub renderAdapterLuid: super::super::Foundation::LUID,
@@ -2685,7 +2695,8 @@ impl Default for COMPOSITION_TARGET_ID {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COMPOSITION_TARGET_STATS {
     pub outstandingPresents: u32,
     pub presentTime: u64,
@@ -2703,7 +2714,8 @@ impl Default for COMPOSITION_TARGET_STATS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DCOMPOSITION_FRAME_STATISTICS {
     pub lastFrameTime: i64,
     pub currentCompositionRate: super::Dxgi::Common::DXGI_RATIONAL,
@@ -2722,7 +2734,8 @@ impl Default for DCOMPOSITION_FRAME_STATISTICS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DCompositionInkTrailPoint {
     pub x: f32,
     pub y: f32,
@@ -2738,3 +2751,5 @@ impl Default for DCompositionInkTrailPoint {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
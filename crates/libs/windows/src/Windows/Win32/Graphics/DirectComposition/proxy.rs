@@ -0,0 +1,1852 @@
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+impl windows_core::imp::ProxyVtable for IDCompositionAffineTransform2DEffect_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn SetInterpolationMode(this: *mut core::ffi::c_void, interpolationmode: super::Direct2D::Common::D2D1_2DAFFINETRANSFORM_INTERPOLATION_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionAffineTransform2DEffect", "SetInterpolationMode", 4usize, |this| ((**(this as *const *const IDCompositionAffineTransform2DEffect_Vtbl)).SetInterpolationMode)(this, interpolationmode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn SetBorderMode(this: *mut core::ffi::c_void, bordermode: super::Direct2D::Common::D2D1_BORDER_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionAffineTransform2DEffect", "SetBorderMode", 5usize, |this| ((**(this as *const *const IDCompositionAffineTransform2DEffect_Vtbl)).SetBorderMode)(this, bordermode), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetTransformMatrix(this: *mut core::ffi::c_void, transformmatrix: *const super::super::super::Foundation::Numerics::Matrix3x2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionAffineTransform2DEffect", "SetTransformMatrix", 6usize, |this| ((**(this as *const *const IDCompositionAffineTransform2DEffect_Vtbl)).SetTransformMatrix)(this, transformmatrix), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransformMatrixElement(this: *mut core::ffi::c_void, row: i32, column: i32, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionAffineTransform2DEffect", "SetTransformMatrixElement", 7usize, |this| ((**(this as *const *const IDCompositionAffineTransform2DEffect_Vtbl)).SetTransformMatrixElement)(this, row, column, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransformMatrixElement2(this: *mut core::ffi::c_void, row: i32, column: i32, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionAffineTransform2DEffect", "SetTransformMatrixElement2", 8usize, |this| ((**(this as *const *const IDCompositionAffineTransform2DEffect_Vtbl)).SetTransformMatrixElement2)(this, row, column, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSharpness(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionAffineTransform2DEffect", "SetSharpness", 9usize, |this| ((**(this as *const *const IDCompositionAffineTransform2DEffect_Vtbl)).SetSharpness)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSharpness2(this: *mut core::ffi::c_void, sharpness: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionAffineTransform2DEffect", "SetSharpness2", 10usize, |this| ((**(this as *const *const IDCompositionAffineTransform2DEffect_Vtbl)).SetSharpness2)(this, sharpness), |result| Some(*result))
+        }
+        Self {
+            base__: <IDCompositionFilterEffect_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            SetInterpolationMode,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            SetInterpolationMode: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            SetBorderMode,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            SetBorderMode: 0,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetTransformMatrix,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetTransformMatrix: 0,
+            SetTransformMatrixElement,
+            SetTransformMatrixElement2,
+            SetSharpness,
+            SetSharpness2,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionAffineTransform2DEffect as windows_core::Interface>::IID || <IDCompositionFilterEffect_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDCompositionAnimation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionAnimation", "Reset", 3usize, |this| ((**(this as *const *const IDCompositionAnimation_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAbsoluteBeginTime(this: *mut core::ffi::c_void, begintime: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionAnimation", "SetAbsoluteBeginTime", 4usize, |this| ((**(this as *const *const IDCompositionAnimation_Vtbl)).SetAbsoluteBeginTime)(this, begintime), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddCubic(this: *mut core::ffi::c_void, beginoffset: f64, constantcoefficient: f32, linearcoefficient: f32, quadraticcoefficient: f32, cubiccoefficient: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionAnimation", "AddCubic", 5usize, |this| ((**(this as *const *const IDCompositionAnimation_Vtbl)).AddCubic)(this, beginoffset, constantcoefficient, linearcoefficient, quadraticcoefficient, cubiccoefficient), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddSinusoidal(this: *mut core::ffi::c_void, beginoffset: f64, bias: f32, amplitude: f32, frequency: f32, phase: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionAnimation", "AddSinusoidal", 6usize, |this| ((**(this as *const *const IDCompositionAnimation_Vtbl)).AddSinusoidal)(this, beginoffset, bias, amplitude, frequency, phase), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddRepeat(this: *mut core::ffi::c_void, beginoffset: f64, durationtorepeat: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionAnimation", "AddRepeat", 7usize, |this| ((**(this as *const *const IDCompositionAnimation_Vtbl)).AddRepeat)(this, beginoffset, durationtorepeat), |result| Some(*result))
+        }
+        unsafe extern "system" fn End(this: *mut core::ffi::c_void, endoffset: f64, endvalue: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionAnimation", "End", 8usize, |this| ((**(this as *const *const IDCompositionAnimation_Vtbl)).End)(this, endoffset, endvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Reset,
+            SetAbsoluteBeginTime,
+            AddCubic,
+            AddSinusoidal,
+            AddRepeat,
+            End,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionAnimation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for IDCompositionArithmeticCompositeEffect_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn SetCoefficients(this: *mut core::ffi::c_void, coefficients: *const super::Direct2D::Common::D2D_VECTOR_4F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionArithmeticCompositeEffect", "SetCoefficients", 4usize, |this| ((**(this as *const *const IDCompositionArithmeticCompositeEffect_Vtbl)).SetCoefficients)(this, coefficients), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClampOutput(this: *mut core::ffi::c_void, clampoutput: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionArithmeticCompositeEffect", "SetClampOutput", 5usize, |this| ((**(this as *const *const IDCompositionArithmeticCompositeEffect_Vtbl)).SetClampOutput)(this, clampoutput), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCoefficient1(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionArithmeticCompositeEffect", "SetCoefficient1", 6usize, |this| ((**(this as *const *const IDCompositionArithmeticCompositeEffect_Vtbl)).SetCoefficient1)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCoefficient12(this: *mut core::ffi::c_void, coeffcient1: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionArithmeticCompositeEffect", "SetCoefficient12", 7usize, |this| ((**(this as *const *const IDCompositionArithmeticCompositeEffect_Vtbl)).SetCoefficient12)(this, coeffcient1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCoefficient2(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionArithmeticCompositeEffect", "SetCoefficient2", 8usize, |this| ((**(this as *const *const IDCompositionArithmeticCompositeEffect_Vtbl)).SetCoefficient2)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCoefficient22(this: *mut core::ffi::c_void, coefficient2: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionArithmeticCompositeEffect", "SetCoefficient22", 9usize, |this| ((**(this as *const *const IDCompositionArithmeticCompositeEffect_Vtbl)).SetCoefficient22)(this, coefficient2), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCoefficient3(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionArithmeticCompositeEffect", "SetCoefficient3", 10usize, |this| ((**(this as *const *const IDCompositionArithmeticCompositeEffect_Vtbl)).SetCoefficient3)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCoefficient32(this: *mut core::ffi::c_void, coefficient3: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionArithmeticCompositeEffect", "SetCoefficient32", 11usize, |this| ((**(this as *const *const IDCompositionArithmeticCompositeEffect_Vtbl)).SetCoefficient32)(this, coefficient3), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCoefficient4(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionArithmeticCompositeEffect", "SetCoefficient4", 12usize, |this| ((**(this as *const *const IDCompositionArithmeticCompositeEffect_Vtbl)).SetCoefficient4)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCoefficient42(this: *mut core::ffi::c_void, coefficient4: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionArithmeticCompositeEffect", "SetCoefficient42", 13usize, |this| ((**(this as *const *const IDCompositionArithmeticCompositeEffect_Vtbl)).SetCoefficient42)(this, coefficient4), |result| Some(*result))
+        }
+        Self {
+            base__: <IDCompositionFilterEffect_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            SetCoefficients,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            SetCoefficients: 0,
+            SetClampOutput,
+            SetCoefficient1,
+            SetCoefficient12,
+            SetCoefficient2,
+            SetCoefficient22,
+            SetCoefficient3,
+            SetCoefficient32,
+            SetCoefficient4,
+            SetCoefficient42,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionArithmeticCompositeEffect as windows_core::Interface>::IID || <IDCompositionFilterEffect_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for IDCompositionBlendEffect_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn SetMode(this: *mut core::ffi::c_void, mode: super::Direct2D::Common::D2D1_BLEND_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionBlendEffect", "SetMode", 4usize, |this| ((**(this as *const *const IDCompositionBlendEffect_Vtbl)).SetMode)(this, mode), |result| Some(*result))
+        }
+        Self {
+            base__: <IDCompositionFilterEffect_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            SetMode,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            SetMode: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionBlendEffect as windows_core::Interface>::IID || <IDCompositionFilterEffect_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for IDCompositionBrightnessEffect_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn SetWhitePoint(this: *mut core::ffi::c_void, whitepoint: *const super::Direct2D::Common::D2D_VECTOR_2F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionBrightnessEffect", "SetWhitePoint", 4usize, |this| ((**(this as *const *const IDCompositionBrightnessEffect_Vtbl)).SetWhitePoint)(this, whitepoint), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn SetBlackPoint(this: *mut core::ffi::c_void, blackpoint: *const super::Direct2D::Common::D2D_VECTOR_2F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionBrightnessEffect", "SetBlackPoint", 5usize, |this| ((**(this as *const *const IDCompositionBrightnessEffect_Vtbl)).SetBlackPoint)(this, blackpoint), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWhitePointX(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionBrightnessEffect", "SetWhitePointX", 6usize, |this| ((**(this as *const *const IDCompositionBrightnessEffect_Vtbl)).SetWhitePointX)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWhitePointX2(this: *mut core::ffi::c_void, whitepointx: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionBrightnessEffect", "SetWhitePointX2", 7usize, |this| ((**(this as *const *const IDCompositionBrightnessEffect_Vtbl)).SetWhitePointX2)(this, whitepointx), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWhitePointY(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionBrightnessEffect", "SetWhitePointY", 8usize, |this| ((**(this as *const *const IDCompositionBrightnessEffect_Vtbl)).SetWhitePointY)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWhitePointY2(this: *mut core::ffi::c_void, whitepointy: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionBrightnessEffect", "SetWhitePointY2", 9usize, |this| ((**(this as *const *const IDCompositionBrightnessEffect_Vtbl)).SetWhitePointY2)(this, whitepointy), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlackPointX(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionBrightnessEffect", "SetBlackPointX", 10usize, |this| ((**(this as *const *const IDCompositionBrightnessEffect_Vtbl)).SetBlackPointX)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlackPointX2(this: *mut core::ffi::c_void, blackpointx: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionBrightnessEffect", "SetBlackPointX2", 11usize, |this| ((**(this as *const *const IDCompositionBrightnessEffect_Vtbl)).SetBlackPointX2)(this, blackpointx), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlackPointY(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionBrightnessEffect", "SetBlackPointY", 12usize, |this| ((**(this as *const *const IDCompositionBrightnessEffect_Vtbl)).SetBlackPointY)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlackPointY2(this: *mut core::ffi::c_void, blackpointy: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionBrightnessEffect", "SetBlackPointY2", 13usize, |this| ((**(this as *const *const IDCompositionBrightnessEffect_Vtbl)).SetBlackPointY2)(this, blackpointy), |result| Some(*result))
+        }
+        Self {
+            base__: <IDCompositionFilterEffect_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            SetWhitePoint,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            SetWhitePoint: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            SetBlackPoint,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            SetBlackPoint: 0,
+            SetWhitePointX,
+            SetWhitePointX2,
+            SetWhitePointY,
+            SetWhitePointY2,
+            SetBlackPointX,
+            SetBlackPointX2,
+            SetBlackPointY,
+            SetBlackPointY2,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionBrightnessEffect as windows_core::Interface>::IID || <IDCompositionFilterEffect_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDCompositionClip_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionClip as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for IDCompositionColorMatrixEffect_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn SetMatrix(this: *mut core::ffi::c_void, matrix: *const super::Direct2D::Common::D2D_MATRIX_5X4_F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionColorMatrixEffect", "SetMatrix", 4usize, |this| ((**(this as *const *const IDCompositionColorMatrixEffect_Vtbl)).SetMatrix)(this, matrix), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMatrixElement(this: *mut core::ffi::c_void, row: i32, column: i32, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionColorMatrixEffect", "SetMatrixElement", 5usize, |this| ((**(this as *const *const IDCompositionColorMatrixEffect_Vtbl)).SetMatrixElement)(this, row, column, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMatrixElement2(this: *mut core::ffi::c_void, row: i32, column: i32, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionColorMatrixEffect", "SetMatrixElement2", 6usize, |this| ((**(this as *const *const IDCompositionColorMatrixEffect_Vtbl)).SetMatrixElement2)(this, row, column, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn SetAlphaMode(this: *mut core::ffi::c_void, mode: super::Direct2D::Common::D2D1_COLORMATRIX_ALPHA_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionColorMatrixEffect", "SetAlphaMode", 7usize, |this| ((**(this as *const *const IDCompositionColorMatrixEffect_Vtbl)).SetAlphaMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClampOutput(this: *mut core::ffi::c_void, clamp: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionColorMatrixEffect", "SetClampOutput", 8usize, |this| ((**(this as *const *const IDCompositionColorMatrixEffect_Vtbl)).SetClampOutput)(this, clamp), |result| Some(*result))
+        }
+        Self {
+            base__: <IDCompositionFilterEffect_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            SetMatrix,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            SetMatrix: 0,
+            SetMatrixElement,
+            SetMatrixElement2,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            SetAlphaMode,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            SetAlphaMode: 0,
+            SetClampOutput,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionColorMatrixEffect as windows_core::Interface>::IID || <IDCompositionFilterEffect_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for IDCompositionCompositeEffect_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn SetMode(this: *mut core::ffi::c_void, mode: super::Direct2D::Common::D2D1_COMPOSITE_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionCompositeEffect", "SetMode", 4usize, |this| ((**(this as *const *const IDCompositionCompositeEffect_Vtbl)).SetMode)(this, mode), |result| Some(*result))
+        }
+        Self {
+            base__: <IDCompositionFilterEffect_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            SetMode,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            SetMode: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionCompositeEffect as windows_core::Interface>::IID || <IDCompositionFilterEffect_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for IDCompositionDelegatedInkTrail_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddTrailPoints(this: *mut core::ffi::c_void, inkpoints: *const DCompositionInkTrailPoint, inkpointscount: u32, generationid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDelegatedInkTrail", "AddTrailPoints", 3usize, |this| ((**(this as *const *const IDCompositionDelegatedInkTrail_Vtbl)).AddTrailPoints)(this, inkpoints, inkpointscount, generationid), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTrailPointsWithPrediction(this: *mut core::ffi::c_void, inkpoints: *const DCompositionInkTrailPoint, inkpointscount: u32, predictedinkpoints: *const DCompositionInkTrailPoint, predictedinkpointscount: u32, generationid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDelegatedInkTrail", "AddTrailPointsWithPrediction", 4usize, |this| ((**(this as *const *const IDCompositionDelegatedInkTrail_Vtbl)).AddTrailPointsWithPrediction)(this, inkpoints, inkpointscount, predictedinkpoints, predictedinkpointscount, generationid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTrailPoints(this: *mut core::ffi::c_void, generationid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDelegatedInkTrail", "RemoveTrailPoints", 5usize, |this| ((**(this as *const *const IDCompositionDelegatedInkTrail_Vtbl)).RemoveTrailPoints)(this, generationid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn StartNewTrail(this: *mut core::ffi::c_void, color: *const super::Direct2D::Common::D2D1_COLOR_F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDelegatedInkTrail", "StartNewTrail", 6usize, |this| ((**(this as *const *const IDCompositionDelegatedInkTrail_Vtbl)).StartNewTrail)(this, color), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddTrailPoints,
+            AddTrailPointsWithPrediction,
+            RemoveTrailPoints,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            StartNewTrail,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            StartNewTrail: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionDelegatedInkTrail as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IDCompositionDesktopDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateTargetForHwnd(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, topmost: super::super::Foundation::BOOL, target: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDesktopDevice", "CreateTargetForHwnd", 24usize, |this| ((**(this as *const *const IDCompositionDesktopDevice_Vtbl)).CreateTargetForHwnd)(this, hwnd, topmost, target), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSurfaceFromHandle(this: *mut core::ffi::c_void, handle: super::super::Foundation::HANDLE, surface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDesktopDevice", "CreateSurfaceFromHandle", 25usize, |this| ((**(this as *const *const IDCompositionDesktopDevice_Vtbl)).CreateSurfaceFromHandle)(this, handle, surface), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSurfaceFromHwnd(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, surface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDesktopDevice", "CreateSurfaceFromHwnd", 26usize, |this| ((**(this as *const *const IDCompositionDesktopDevice_Vtbl)).CreateSurfaceFromHwnd)(this, hwnd, surface), |result| Some(*result))
+        }
+        Self {
+            base__: <IDCompositionDevice2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateTargetForHwnd,
+            CreateSurfaceFromHandle,
+            CreateSurfaceFromHwnd,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionDesktopDevice as windows_core::Interface>::IID || <IDCompositionDevice2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IDCompositionDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice", "Commit", 3usize, |this| ((**(this as *const *const IDCompositionDevice_Vtbl)).Commit)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForCommitCompletion(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice", "WaitForCommitCompletion", 4usize, |this| ((**(this as *const *const IDCompositionDevice_Vtbl)).WaitForCommitCompletion)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetFrameStatistics(this: *mut core::ffi::c_void, statistics: *mut DCOMPOSITION_FRAME_STATISTICS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice", "GetFrameStatistics", 5usize, |this| ((**(this as *const *const IDCompositionDevice_Vtbl)).GetFrameStatistics)(this, statistics), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTargetForHwnd(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, topmost: super::super::Foundation::BOOL, target: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice", "CreateTargetForHwnd", 6usize, |this| ((**(this as *const *const IDCompositionDevice_Vtbl)).CreateTargetForHwnd)(this, hwnd, topmost, target), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVisual(this: *mut core::ffi::c_void, visual: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice", "CreateVisual", 7usize, |this| ((**(this as *const *const IDCompositionDevice_Vtbl)).CreateVisual)(this, visual), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateSurface(this: *mut core::ffi::c_void, width: u32, height: u32, pixelformat: super::Dxgi::Common::DXGI_FORMAT, alphamode: super::Dxgi::Common::DXGI_ALPHA_MODE, surface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice", "CreateSurface", 8usize, |this| ((**(this as *const *const IDCompositionDevice_Vtbl)).CreateSurface)(this, width, height, pixelformat, alphamode, surface), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateVirtualSurface(this: *mut core::ffi::c_void, initialwidth: u32, initialheight: u32, pixelformat: super::Dxgi::Common::DXGI_FORMAT, alphamode: super::Dxgi::Common::DXGI_ALPHA_MODE, virtualsurface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice", "CreateVirtualSurface", 9usize, |this| ((**(this as *const *const IDCompositionDevice_Vtbl)).CreateVirtualSurface)(this, initialwidth, initialheight, pixelformat, alphamode, virtualsurface), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSurfaceFromHandle(this: *mut core::ffi::c_void, handle: super::super::Foundation::HANDLE, surface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice", "CreateSurfaceFromHandle", 10usize, |this| ((**(this as *const *const IDCompositionDevice_Vtbl)).CreateSurfaceFromHandle)(this, handle, surface), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSurfaceFromHwnd(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, surface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice", "CreateSurfaceFromHwnd", 11usize, |this| ((**(this as *const *const IDCompositionDevice_Vtbl)).CreateSurfaceFromHwnd)(this, hwnd, surface), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTranslateTransform(this: *mut core::ffi::c_void, translatetransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice", "CreateTranslateTransform", 12usize, |this| ((**(this as *const *const IDCompositionDevice_Vtbl)).CreateTranslateTransform)(this, translatetransform), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateScaleTransform(this: *mut core::ffi::c_void, scaletransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice", "CreateScaleTransform", 13usize, |this| ((**(this as *const *const IDCompositionDevice_Vtbl)).CreateScaleTransform)(this, scaletransform), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRotateTransform(this: *mut core::ffi::c_void, rotatetransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice", "CreateRotateTransform", 14usize, |this| ((**(this as *const *const IDCompositionDevice_Vtbl)).CreateRotateTransform)(this, rotatetransform), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSkewTransform(this: *mut core::ffi::c_void, skewtransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice", "CreateSkewTransform", 15usize, |this| ((**(this as *const *const IDCompositionDevice_Vtbl)).CreateSkewTransform)(this, skewtransform), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateMatrixTransform(this: *mut core::ffi::c_void, matrixtransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice", "CreateMatrixTransform", 16usize, |this| ((**(this as *const *const IDCompositionDevice_Vtbl)).CreateMatrixTransform)(this, matrixtransform), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTransformGroup(this: *mut core::ffi::c_void, transforms: *const *mut core::ffi::c_void, elements: u32, transformgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice", "CreateTransformGroup", 17usize, |this| ((**(this as *const *const IDCompositionDevice_Vtbl)).CreateTransformGroup)(this, transforms, elements, transformgroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTranslateTransform3D(this: *mut core::ffi::c_void, translatetransform3d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice", "CreateTranslateTransform3D", 18usize, |this| ((**(this as *const *const IDCompositionDevice_Vtbl)).CreateTranslateTransform3D)(this, translatetransform3d), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateScaleTransform3D(this: *mut core::ffi::c_void, scaletransform3d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice", "CreateScaleTransform3D", 19usize, |this| ((**(this as *const *const IDCompositionDevice_Vtbl)).CreateScaleTransform3D)(this, scaletransform3d), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRotateTransform3D(this: *mut core::ffi::c_void, rotatetransform3d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice", "CreateRotateTransform3D", 20usize, |this| ((**(this as *const *const IDCompositionDevice_Vtbl)).CreateRotateTransform3D)(this, rotatetransform3d), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateMatrixTransform3D(this: *mut core::ffi::c_void, matrixtransform3d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice", "CreateMatrixTransform3D", 21usize, |this| ((**(this as *const *const IDCompositionDevice_Vtbl)).CreateMatrixTransform3D)(this, matrixtransform3d), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTransform3DGroup(this: *mut core::ffi::c_void, transforms3d: *const *mut core::ffi::c_void, elements: u32, transform3dgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice", "CreateTransform3DGroup", 22usize, |this| ((**(this as *const *const IDCompositionDevice_Vtbl)).CreateTransform3DGroup)(this, transforms3d, elements, transform3dgroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateEffectGroup(this: *mut core::ffi::c_void, effectgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice", "CreateEffectGroup", 23usize, |this| ((**(this as *const *const IDCompositionDevice_Vtbl)).CreateEffectGroup)(this, effectgroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRectangleClip(this: *mut core::ffi::c_void, clip: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice", "CreateRectangleClip", 24usize, |this| ((**(this as *const *const IDCompositionDevice_Vtbl)).CreateRectangleClip)(this, clip), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAnimation(this: *mut core::ffi::c_void, animation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice", "CreateAnimation", 25usize, |this| ((**(this as *const *const IDCompositionDevice_Vtbl)).CreateAnimation)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckDeviceState(this: *mut core::ffi::c_void, pfvalid: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice", "CheckDeviceState", 26usize, |this| ((**(this as *const *const IDCompositionDevice_Vtbl)).CheckDeviceState)(this, pfvalid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Commit,
+            WaitForCommitCompletion,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetFrameStatistics,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetFrameStatistics: 0,
+            CreateTargetForHwnd,
+            CreateVisual,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateSurface,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateSurface: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateVirtualSurface,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateVirtualSurface: 0,
+            CreateSurfaceFromHandle,
+            CreateSurfaceFromHwnd,
+            CreateTranslateTransform,
+            CreateScaleTransform,
+            CreateRotateTransform,
+            CreateSkewTransform,
+            CreateMatrixTransform,
+            CreateTransformGroup,
+            CreateTranslateTransform3D,
+            CreateScaleTransform3D,
+            CreateRotateTransform3D,
+            CreateMatrixTransform3D,
+            CreateTransform3DGroup,
+            CreateEffectGroup,
+            CreateRectangleClip,
+            CreateAnimation,
+            CheckDeviceState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionDevice as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IDCompositionDevice2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice2", "Commit", 3usize, |this| ((**(this as *const *const IDCompositionDevice2_Vtbl)).Commit)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForCommitCompletion(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice2", "WaitForCommitCompletion", 4usize, |this| ((**(this as *const *const IDCompositionDevice2_Vtbl)).WaitForCommitCompletion)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetFrameStatistics(this: *mut core::ffi::c_void, statistics: *mut DCOMPOSITION_FRAME_STATISTICS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice2", "GetFrameStatistics", 5usize, |this| ((**(this as *const *const IDCompositionDevice2_Vtbl)).GetFrameStatistics)(this, statistics), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVisual(this: *mut core::ffi::c_void, visual: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice2", "CreateVisual", 6usize, |this| ((**(this as *const *const IDCompositionDevice2_Vtbl)).CreateVisual)(this, visual), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSurfaceFactory(this: *mut core::ffi::c_void, renderingdevice: *mut core::ffi::c_void, surfacefactory: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice2", "CreateSurfaceFactory", 7usize, |this| ((**(this as *const *const IDCompositionDevice2_Vtbl)).CreateSurfaceFactory)(this, renderingdevice, surfacefactory), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateSurface(this: *mut core::ffi::c_void, width: u32, height: u32, pixelformat: super::Dxgi::Common::DXGI_FORMAT, alphamode: super::Dxgi::Common::DXGI_ALPHA_MODE, surface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice2", "CreateSurface", 8usize, |this| ((**(this as *const *const IDCompositionDevice2_Vtbl)).CreateSurface)(this, width, height, pixelformat, alphamode, surface), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateVirtualSurface(this: *mut core::ffi::c_void, initialwidth: u32, initialheight: u32, pixelformat: super::Dxgi::Common::DXGI_FORMAT, alphamode: super::Dxgi::Common::DXGI_ALPHA_MODE, virtualsurface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice2", "CreateVirtualSurface", 9usize, |this| ((**(this as *const *const IDCompositionDevice2_Vtbl)).CreateVirtualSurface)(this, initialwidth, initialheight, pixelformat, alphamode, virtualsurface), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTranslateTransform(this: *mut core::ffi::c_void, translatetransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice2", "CreateTranslateTransform", 10usize, |this| ((**(this as *const *const IDCompositionDevice2_Vtbl)).CreateTranslateTransform)(this, translatetransform), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateScaleTransform(this: *mut core::ffi::c_void, scaletransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice2", "CreateScaleTransform", 11usize, |this| ((**(this as *const *const IDCompositionDevice2_Vtbl)).CreateScaleTransform)(this, scaletransform), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRotateTransform(this: *mut core::ffi::c_void, rotatetransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice2", "CreateRotateTransform", 12usize, |this| ((**(this as *const *const IDCompositionDevice2_Vtbl)).CreateRotateTransform)(this, rotatetransform), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSkewTransform(this: *mut core::ffi::c_void, skewtransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice2", "CreateSkewTransform", 13usize, |this| ((**(this as *const *const IDCompositionDevice2_Vtbl)).CreateSkewTransform)(this, skewtransform), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateMatrixTransform(this: *mut core::ffi::c_void, matrixtransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice2", "CreateMatrixTransform", 14usize, |this| ((**(this as *const *const IDCompositionDevice2_Vtbl)).CreateMatrixTransform)(this, matrixtransform), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTransformGroup(this: *mut core::ffi::c_void, transforms: *const *mut core::ffi::c_void, elements: u32, transformgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice2", "CreateTransformGroup", 15usize, |this| ((**(this as *const *const IDCompositionDevice2_Vtbl)).CreateTransformGroup)(this, transforms, elements, transformgroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTranslateTransform3D(this: *mut core::ffi::c_void, translatetransform3d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice2", "CreateTranslateTransform3D", 16usize, |this| ((**(this as *const *const IDCompositionDevice2_Vtbl)).CreateTranslateTransform3D)(this, translatetransform3d), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateScaleTransform3D(this: *mut core::ffi::c_void, scaletransform3d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice2", "CreateScaleTransform3D", 17usize, |this| ((**(this as *const *const IDCompositionDevice2_Vtbl)).CreateScaleTransform3D)(this, scaletransform3d), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRotateTransform3D(this: *mut core::ffi::c_void, rotatetransform3d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice2", "CreateRotateTransform3D", 18usize, |this| ((**(this as *const *const IDCompositionDevice2_Vtbl)).CreateRotateTransform3D)(this, rotatetransform3d), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateMatrixTransform3D(this: *mut core::ffi::c_void, matrixtransform3d: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice2", "CreateMatrixTransform3D", 19usize, |this| ((**(this as *const *const IDCompositionDevice2_Vtbl)).CreateMatrixTransform3D)(this, matrixtransform3d), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTransform3DGroup(this: *mut core::ffi::c_void, transforms3d: *const *mut core::ffi::c_void, elements: u32, transform3dgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice2", "CreateTransform3DGroup", 20usize, |this| ((**(this as *const *const IDCompositionDevice2_Vtbl)).CreateTransform3DGroup)(this, transforms3d, elements, transform3dgroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateEffectGroup(this: *mut core::ffi::c_void, effectgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice2", "CreateEffectGroup", 21usize, |this| ((**(this as *const *const IDCompositionDevice2_Vtbl)).CreateEffectGroup)(this, effectgroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRectangleClip(this: *mut core::ffi::c_void, clip: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice2", "CreateRectangleClip", 22usize, |this| ((**(this as *const *const IDCompositionDevice2_Vtbl)).CreateRectangleClip)(this, clip), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAnimation(this: *mut core::ffi::c_void, animation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice2", "CreateAnimation", 23usize, |this| ((**(this as *const *const IDCompositionDevice2_Vtbl)).CreateAnimation)(this, animation), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Commit,
+            WaitForCommitCompletion,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetFrameStatistics,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetFrameStatistics: 0,
+            CreateVisual,
+            CreateSurfaceFactory,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateSurface,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateSurface: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateVirtualSurface,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateVirtualSurface: 0,
+            CreateTranslateTransform,
+            CreateScaleTransform,
+            CreateRotateTransform,
+            CreateSkewTransform,
+            CreateMatrixTransform,
+            CreateTransformGroup,
+            CreateTranslateTransform3D,
+            CreateScaleTransform3D,
+            CreateRotateTransform3D,
+            CreateMatrixTransform3D,
+            CreateTransform3DGroup,
+            CreateEffectGroup,
+            CreateRectangleClip,
+            CreateAnimation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionDevice2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IDCompositionDevice3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateGaussianBlurEffect(this: *mut core::ffi::c_void, gaussianblureffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice3", "CreateGaussianBlurEffect", 24usize, |this| ((**(this as *const *const IDCompositionDevice3_Vtbl)).CreateGaussianBlurEffect)(this, gaussianblureffect), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBrightnessEffect(this: *mut core::ffi::c_void, brightnesseffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice3", "CreateBrightnessEffect", 25usize, |this| ((**(this as *const *const IDCompositionDevice3_Vtbl)).CreateBrightnessEffect)(this, brightnesseffect), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateColorMatrixEffect(this: *mut core::ffi::c_void, colormatrixeffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice3", "CreateColorMatrixEffect", 26usize, |this| ((**(this as *const *const IDCompositionDevice3_Vtbl)).CreateColorMatrixEffect)(this, colormatrixeffect), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateShadowEffect(this: *mut core::ffi::c_void, shadoweffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice3", "CreateShadowEffect", 27usize, |this| ((**(this as *const *const IDCompositionDevice3_Vtbl)).CreateShadowEffect)(this, shadoweffect), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateHueRotationEffect(this: *mut core::ffi::c_void, huerotationeffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice3", "CreateHueRotationEffect", 28usize, |this| ((**(this as *const *const IDCompositionDevice3_Vtbl)).CreateHueRotationEffect)(this, huerotationeffect), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSaturationEffect(this: *mut core::ffi::c_void, saturationeffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice3", "CreateSaturationEffect", 29usize, |this| ((**(this as *const *const IDCompositionDevice3_Vtbl)).CreateSaturationEffect)(this, saturationeffect), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTurbulenceEffect(this: *mut core::ffi::c_void, turbulenceeffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice3", "CreateTurbulenceEffect", 30usize, |this| ((**(this as *const *const IDCompositionDevice3_Vtbl)).CreateTurbulenceEffect)(this, turbulenceeffect), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateLinearTransferEffect(this: *mut core::ffi::c_void, lineartransfereffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice3", "CreateLinearTransferEffect", 31usize, |this| ((**(this as *const *const IDCompositionDevice3_Vtbl)).CreateLinearTransferEffect)(this, lineartransfereffect), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTableTransferEffect(this: *mut core::ffi::c_void, tabletransfereffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice3", "CreateTableTransferEffect", 32usize, |this| ((**(this as *const *const IDCompositionDevice3_Vtbl)).CreateTableTransferEffect)(this, tabletransfereffect), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCompositeEffect(this: *mut core::ffi::c_void, compositeeffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice3", "CreateCompositeEffect", 33usize, |this| ((**(this as *const *const IDCompositionDevice3_Vtbl)).CreateCompositeEffect)(this, compositeeffect), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBlendEffect(this: *mut core::ffi::c_void, blendeffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice3", "CreateBlendEffect", 34usize, |this| ((**(this as *const *const IDCompositionDevice3_Vtbl)).CreateBlendEffect)(this, blendeffect), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateArithmeticCompositeEffect(this: *mut core::ffi::c_void, arithmeticcompositeeffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice3", "CreateArithmeticCompositeEffect", 35usize, |this| ((**(this as *const *const IDCompositionDevice3_Vtbl)).CreateArithmeticCompositeEffect)(this, arithmeticcompositeeffect), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAffineTransform2DEffect(this: *mut core::ffi::c_void, affinetransform2deffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice3", "CreateAffineTransform2DEffect", 36usize, |this| ((**(this as *const *const IDCompositionDevice3_Vtbl)).CreateAffineTransform2DEffect)(this, affinetransform2deffect), |result| Some(*result))
+        }
+        Self {
+            base__: <IDCompositionDevice2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateGaussianBlurEffect,
+            CreateBrightnessEffect,
+            CreateColorMatrixEffect,
+            CreateShadowEffect,
+            CreateHueRotationEffect,
+            CreateSaturationEffect,
+            CreateTurbulenceEffect,
+            CreateLinearTransferEffect,
+            CreateTableTransferEffect,
+            CreateCompositeEffect,
+            CreateBlendEffect,
+            CreateArithmeticCompositeEffect,
+            CreateAffineTransform2DEffect,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionDevice3 as windows_core::Interface>::IID || <IDCompositionDevice2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IDCompositionDevice4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CheckCompositionTextureSupport(this: *mut core::ffi::c_void, renderingdevice: *mut core::ffi::c_void, supportscompositiontextures: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice4", "CheckCompositionTextureSupport", 37usize, |this| ((**(this as *const *const IDCompositionDevice4_Vtbl)).CheckCompositionTextureSupport)(this, renderingdevice, supportscompositiontextures), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCompositionTexture(this: *mut core::ffi::c_void, d3dtexture: *mut core::ffi::c_void, compositiontexture: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDevice4", "CreateCompositionTexture", 38usize, |this| ((**(this as *const *const IDCompositionDevice4_Vtbl)).CreateCompositionTexture)(this, d3dtexture, compositiontexture), |result| Some(*result))
+        }
+        Self { base__: <IDCompositionDevice3_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CheckCompositionTextureSupport, CreateCompositionTexture }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionDevice4 as windows_core::Interface>::IID || <IDCompositionDevice3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDCompositionDeviceDebug_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnableDebugCounters(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDeviceDebug", "EnableDebugCounters", 3usize, |this| ((**(this as *const *const IDCompositionDeviceDebug_Vtbl)).EnableDebugCounters)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableDebugCounters(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionDeviceDebug", "DisableDebugCounters", 4usize, |this| ((**(this as *const *const IDCompositionDeviceDebug_Vtbl)).DisableDebugCounters)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnableDebugCounters, DisableDebugCounters }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionDeviceDebug as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDCompositionEffect_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionEffect as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDCompositionEffectGroup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetOpacity(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionEffectGroup", "SetOpacity", 3usize, |this| ((**(this as *const *const IDCompositionEffectGroup_Vtbl)).SetOpacity)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOpacity2(this: *mut core::ffi::c_void, opacity: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionEffectGroup", "SetOpacity2", 4usize, |this| ((**(this as *const *const IDCompositionEffectGroup_Vtbl)).SetOpacity2)(this, opacity), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransform3D(this: *mut core::ffi::c_void, transform3d: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionEffectGroup", "SetTransform3D", 5usize, |this| ((**(this as *const *const IDCompositionEffectGroup_Vtbl)).SetTransform3D)(this, transform3d), |result| Some(*result))
+        }
+        Self { base__: <IDCompositionEffect_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetOpacity, SetOpacity2, SetTransform3D }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionEffectGroup as windows_core::Interface>::IID || <IDCompositionEffect_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDCompositionFilterEffect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetInput(this: *mut core::ffi::c_void, index: u32, input: *mut core::ffi::c_void, flags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionFilterEffect", "SetInput", 3usize, |this| ((**(this as *const *const IDCompositionFilterEffect_Vtbl)).SetInput)(this, index, input, flags), |result| Some(*result))
+        }
+        Self { base__: <IDCompositionEffect_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetInput }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionFilterEffect as windows_core::Interface>::IID || <IDCompositionEffect_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for IDCompositionGaussianBlurEffect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetStandardDeviation(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionGaussianBlurEffect", "SetStandardDeviation", 4usize, |this| ((**(this as *const *const IDCompositionGaussianBlurEffect_Vtbl)).SetStandardDeviation)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStandardDeviation2(this: *mut core::ffi::c_void, amount: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionGaussianBlurEffect", "SetStandardDeviation2", 5usize, |this| ((**(this as *const *const IDCompositionGaussianBlurEffect_Vtbl)).SetStandardDeviation2)(this, amount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn SetBorderMode(this: *mut core::ffi::c_void, mode: super::Direct2D::Common::D2D1_BORDER_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionGaussianBlurEffect", "SetBorderMode", 6usize, |this| ((**(this as *const *const IDCompositionGaussianBlurEffect_Vtbl)).SetBorderMode)(this, mode), |result| Some(*result))
+        }
+        Self {
+            base__: <IDCompositionFilterEffect_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetStandardDeviation,
+            SetStandardDeviation2,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            SetBorderMode,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            SetBorderMode: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionGaussianBlurEffect as windows_core::Interface>::IID || <IDCompositionFilterEffect_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDCompositionHueRotationEffect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAngle(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionHueRotationEffect", "SetAngle", 4usize, |this| ((**(this as *const *const IDCompositionHueRotationEffect_Vtbl)).SetAngle)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAngle2(this: *mut core::ffi::c_void, amountdegrees: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionHueRotationEffect", "SetAngle2", 5usize, |this| ((**(this as *const *const IDCompositionHueRotationEffect_Vtbl)).SetAngle2)(this, amountdegrees), |result| Some(*result))
+        }
+        Self { base__: <IDCompositionFilterEffect_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAngle, SetAngle2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionHueRotationEffect as windows_core::Interface>::IID || <IDCompositionFilterEffect_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDCompositionInkTrailDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateDelegatedInkTrail(this: *mut core::ffi::c_void, inktrail: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionInkTrailDevice", "CreateDelegatedInkTrail", 3usize, |this| ((**(this as *const *const IDCompositionInkTrailDevice_Vtbl)).CreateDelegatedInkTrail)(this, inktrail), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDelegatedInkTrailForSwapChain(this: *mut core::ffi::c_void, swapchain: *mut core::ffi::c_void, inktrail: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionInkTrailDevice", "CreateDelegatedInkTrailForSwapChain", 4usize, |this| ((**(this as *const *const IDCompositionInkTrailDevice_Vtbl)).CreateDelegatedInkTrailForSwapChain)(this, swapchain, inktrail), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateDelegatedInkTrail, CreateDelegatedInkTrailForSwapChain }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionInkTrailDevice as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDCompositionLinearTransferEffect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetRedYIntercept(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionLinearTransferEffect", "SetRedYIntercept", 4usize, |this| ((**(this as *const *const IDCompositionLinearTransferEffect_Vtbl)).SetRedYIntercept)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRedYIntercept2(this: *mut core::ffi::c_void, redyintercept: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionLinearTransferEffect", "SetRedYIntercept2", 5usize, |this| ((**(this as *const *const IDCompositionLinearTransferEffect_Vtbl)).SetRedYIntercept2)(this, redyintercept), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRedSlope(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionLinearTransferEffect", "SetRedSlope", 6usize, |this| ((**(this as *const *const IDCompositionLinearTransferEffect_Vtbl)).SetRedSlope)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRedSlope2(this: *mut core::ffi::c_void, redslope: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionLinearTransferEffect", "SetRedSlope2", 7usize, |this| ((**(this as *const *const IDCompositionLinearTransferEffect_Vtbl)).SetRedSlope2)(this, redslope), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRedDisable(this: *mut core::ffi::c_void, reddisable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionLinearTransferEffect", "SetRedDisable", 8usize, |this| ((**(this as *const *const IDCompositionLinearTransferEffect_Vtbl)).SetRedDisable)(this, reddisable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGreenYIntercept(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionLinearTransferEffect", "SetGreenYIntercept", 9usize, |this| ((**(this as *const *const IDCompositionLinearTransferEffect_Vtbl)).SetGreenYIntercept)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGreenYIntercept2(this: *mut core::ffi::c_void, greenyintercept: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionLinearTransferEffect", "SetGreenYIntercept2", 10usize, |this| ((**(this as *const *const IDCompositionLinearTransferEffect_Vtbl)).SetGreenYIntercept2)(this, greenyintercept), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGreenSlope(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionLinearTransferEffect", "SetGreenSlope", 11usize, |this| ((**(this as *const *const IDCompositionLinearTransferEffect_Vtbl)).SetGreenSlope)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGreenSlope2(this: *mut core::ffi::c_void, greenslope: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionLinearTransferEffect", "SetGreenSlope2", 12usize, |this| ((**(this as *const *const IDCompositionLinearTransferEffect_Vtbl)).SetGreenSlope2)(this, greenslope), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGreenDisable(this: *mut core::ffi::c_void, greendisable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionLinearTransferEffect", "SetGreenDisable", 13usize, |this| ((**(this as *const *const IDCompositionLinearTransferEffect_Vtbl)).SetGreenDisable)(this, greendisable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlueYIntercept(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionLinearTransferEffect", "SetBlueYIntercept", 14usize, |this| ((**(this as *const *const IDCompositionLinearTransferEffect_Vtbl)).SetBlueYIntercept)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlueYIntercept2(this: *mut core::ffi::c_void, blueyintercept: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionLinearTransferEffect", "SetBlueYIntercept2", 15usize, |this| ((**(this as *const *const IDCompositionLinearTransferEffect_Vtbl)).SetBlueYIntercept2)(this, blueyintercept), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlueSlope(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionLinearTransferEffect", "SetBlueSlope", 16usize, |this| ((**(this as *const *const IDCompositionLinearTransferEffect_Vtbl)).SetBlueSlope)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlueSlope2(this: *mut core::ffi::c_void, blueslope: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionLinearTransferEffect", "SetBlueSlope2", 17usize, |this| ((**(this as *const *const IDCompositionLinearTransferEffect_Vtbl)).SetBlueSlope2)(this, blueslope), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlueDisable(this: *mut core::ffi::c_void, bluedisable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionLinearTransferEffect", "SetBlueDisable", 18usize, |this| ((**(this as *const *const IDCompositionLinearTransferEffect_Vtbl)).SetBlueDisable)(this, bluedisable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlphaYIntercept(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionLinearTransferEffect", "SetAlphaYIntercept", 19usize, |this| ((**(this as *const *const IDCompositionLinearTransferEffect_Vtbl)).SetAlphaYIntercept)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlphaYIntercept2(this: *mut core::ffi::c_void, alphayintercept: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionLinearTransferEffect", "SetAlphaYIntercept2", 20usize, |this| ((**(this as *const *const IDCompositionLinearTransferEffect_Vtbl)).SetAlphaYIntercept2)(this, alphayintercept), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlphaSlope(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionLinearTransferEffect", "SetAlphaSlope", 21usize, |this| ((**(this as *const *const IDCompositionLinearTransferEffect_Vtbl)).SetAlphaSlope)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlphaSlope2(this: *mut core::ffi::c_void, alphaslope: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionLinearTransferEffect", "SetAlphaSlope2", 22usize, |this| ((**(this as *const *const IDCompositionLinearTransferEffect_Vtbl)).SetAlphaSlope2)(this, alphaslope), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlphaDisable(this: *mut core::ffi::c_void, alphadisable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionLinearTransferEffect", "SetAlphaDisable", 23usize, |this| ((**(this as *const *const IDCompositionLinearTransferEffect_Vtbl)).SetAlphaDisable)(this, alphadisable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClampOutput(this: *mut core::ffi::c_void, clampoutput: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionLinearTransferEffect", "SetClampOutput", 24usize, |this| ((**(this as *const *const IDCompositionLinearTransferEffect_Vtbl)).SetClampOutput)(this, clampoutput), |result| Some(*result))
+        }
+        Self {
+            base__: <IDCompositionFilterEffect_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetRedYIntercept,
+            SetRedYIntercept2,
+            SetRedSlope,
+            SetRedSlope2,
+            SetRedDisable,
+            SetGreenYIntercept,
+            SetGreenYIntercept2,
+            SetGreenSlope,
+            SetGreenSlope2,
+            SetGreenDisable,
+            SetBlueYIntercept,
+            SetBlueYIntercept2,
+            SetBlueSlope,
+            SetBlueSlope2,
+            SetBlueDisable,
+            SetAlphaYIntercept,
+            SetAlphaYIntercept2,
+            SetAlphaSlope,
+            SetAlphaSlope2,
+            SetAlphaDisable,
+            SetClampOutput,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionLinearTransferEffect as windows_core::Interface>::IID || <IDCompositionFilterEffect_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IDCompositionMatrixTransform_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetMatrix(this: *mut core::ffi::c_void, matrix: *const super::super::super::Foundation::Numerics::Matrix3x2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionMatrixTransform", "SetMatrix", 3usize, |this| ((**(this as *const *const IDCompositionMatrixTransform_Vtbl)).SetMatrix)(this, matrix), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMatrixElement(this: *mut core::ffi::c_void, row: i32, column: i32, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionMatrixTransform", "SetMatrixElement", 4usize, |this| ((**(this as *const *const IDCompositionMatrixTransform_Vtbl)).SetMatrixElement)(this, row, column, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMatrixElement2(this: *mut core::ffi::c_void, row: i32, column: i32, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionMatrixTransform", "SetMatrixElement2", 5usize, |this| ((**(this as *const *const IDCompositionMatrixTransform_Vtbl)).SetMatrixElement2)(this, row, column, value), |result| Some(*result))
+        }
+        Self {
+            base__: <IDCompositionTransform_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetMatrix,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetMatrix: 0,
+            SetMatrixElement,
+            SetMatrixElement2,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionMatrixTransform as windows_core::Interface>::IID || <IDCompositionTransform_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Foundation_Numerics")]
+impl windows_core::imp::ProxyVtable for IDCompositionMatrixTransform3D_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetMatrix(this: *mut core::ffi::c_void, matrix: *const super::super::super::Foundation::Numerics::Matrix4x4) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionMatrixTransform3D", "SetMatrix", 3usize, |this| ((**(this as *const *const IDCompositionMatrixTransform3D_Vtbl)).SetMatrix)(this, matrix), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMatrixElement(this: *mut core::ffi::c_void, row: i32, column: i32, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionMatrixTransform3D", "SetMatrixElement", 4usize, |this| ((**(this as *const *const IDCompositionMatrixTransform3D_Vtbl)).SetMatrixElement)(this, row, column, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMatrixElement2(this: *mut core::ffi::c_void, row: i32, column: i32, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionMatrixTransform3D", "SetMatrixElement2", 5usize, |this| ((**(this as *const *const IDCompositionMatrixTransform3D_Vtbl)).SetMatrixElement2)(this, row, column, value), |result| Some(*result))
+        }
+        Self {
+            base__: <IDCompositionTransform3D_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetMatrix,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetMatrix: 0,
+            SetMatrixElement,
+            SetMatrixElement2,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionMatrixTransform3D as windows_core::Interface>::IID || <IDCompositionTransform3D_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDCompositionRectangleClip_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetLeft(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRectangleClip", "SetLeft", 3usize, |this| ((**(this as *const *const IDCompositionRectangleClip_Vtbl)).SetLeft)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLeft2(this: *mut core::ffi::c_void, left: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRectangleClip", "SetLeft2", 4usize, |this| ((**(this as *const *const IDCompositionRectangleClip_Vtbl)).SetLeft2)(this, left), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTop(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRectangleClip", "SetTop", 5usize, |this| ((**(this as *const *const IDCompositionRectangleClip_Vtbl)).SetTop)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTop2(this: *mut core::ffi::c_void, top: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRectangleClip", "SetTop2", 6usize, |this| ((**(this as *const *const IDCompositionRectangleClip_Vtbl)).SetTop2)(this, top), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRight(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRectangleClip", "SetRight", 7usize, |this| ((**(this as *const *const IDCompositionRectangleClip_Vtbl)).SetRight)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRight2(this: *mut core::ffi::c_void, right: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRectangleClip", "SetRight2", 8usize, |this| ((**(this as *const *const IDCompositionRectangleClip_Vtbl)).SetRight2)(this, right), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBottom(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRectangleClip", "SetBottom", 9usize, |this| ((**(this as *const *const IDCompositionRectangleClip_Vtbl)).SetBottom)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBottom2(this: *mut core::ffi::c_void, bottom: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRectangleClip", "SetBottom2", 10usize, |this| ((**(this as *const *const IDCompositionRectangleClip_Vtbl)).SetBottom2)(this, bottom), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTopLeftRadiusX(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRectangleClip", "SetTopLeftRadiusX", 11usize, |this| ((**(this as *const *const IDCompositionRectangleClip_Vtbl)).SetTopLeftRadiusX)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTopLeftRadiusX2(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRectangleClip", "SetTopLeftRadiusX2", 12usize, |this| ((**(this as *const *const IDCompositionRectangleClip_Vtbl)).SetTopLeftRadiusX2)(this, radius), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTopLeftRadiusY(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRectangleClip", "SetTopLeftRadiusY", 13usize, |this| ((**(this as *const *const IDCompositionRectangleClip_Vtbl)).SetTopLeftRadiusY)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTopLeftRadiusY2(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRectangleClip", "SetTopLeftRadiusY2", 14usize, |this| ((**(this as *const *const IDCompositionRectangleClip_Vtbl)).SetTopLeftRadiusY2)(this, radius), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTopRightRadiusX(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRectangleClip", "SetTopRightRadiusX", 15usize, |this| ((**(this as *const *const IDCompositionRectangleClip_Vtbl)).SetTopRightRadiusX)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTopRightRadiusX2(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRectangleClip", "SetTopRightRadiusX2", 16usize, |this| ((**(this as *const *const IDCompositionRectangleClip_Vtbl)).SetTopRightRadiusX2)(this, radius), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTopRightRadiusY(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRectangleClip", "SetTopRightRadiusY", 17usize, |this| ((**(this as *const *const IDCompositionRectangleClip_Vtbl)).SetTopRightRadiusY)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTopRightRadiusY2(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRectangleClip", "SetTopRightRadiusY2", 18usize, |this| ((**(this as *const *const IDCompositionRectangleClip_Vtbl)).SetTopRightRadiusY2)(this, radius), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBottomLeftRadiusX(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRectangleClip", "SetBottomLeftRadiusX", 19usize, |this| ((**(this as *const *const IDCompositionRectangleClip_Vtbl)).SetBottomLeftRadiusX)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBottomLeftRadiusX2(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRectangleClip", "SetBottomLeftRadiusX2", 20usize, |this| ((**(this as *const *const IDCompositionRectangleClip_Vtbl)).SetBottomLeftRadiusX2)(this, radius), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBottomLeftRadiusY(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRectangleClip", "SetBottomLeftRadiusY", 21usize, |this| ((**(this as *const *const IDCompositionRectangleClip_Vtbl)).SetBottomLeftRadiusY)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBottomLeftRadiusY2(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRectangleClip", "SetBottomLeftRadiusY2", 22usize, |this| ((**(this as *const *const IDCompositionRectangleClip_Vtbl)).SetBottomLeftRadiusY2)(this, radius), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBottomRightRadiusX(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRectangleClip", "SetBottomRightRadiusX", 23usize, |this| ((**(this as *const *const IDCompositionRectangleClip_Vtbl)).SetBottomRightRadiusX)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBottomRightRadiusX2(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRectangleClip", "SetBottomRightRadiusX2", 24usize, |this| ((**(this as *const *const IDCompositionRectangleClip_Vtbl)).SetBottomRightRadiusX2)(this, radius), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBottomRightRadiusY(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRectangleClip", "SetBottomRightRadiusY", 25usize, |this| ((**(this as *const *const IDCompositionRectangleClip_Vtbl)).SetBottomRightRadiusY)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBottomRightRadiusY2(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRectangleClip", "SetBottomRightRadiusY2", 26usize, |this| ((**(this as *const *const IDCompositionRectangleClip_Vtbl)).SetBottomRightRadiusY2)(this, radius), |result| Some(*result))
+        }
+        Self {
+            base__: <IDCompositionClip_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetLeft,
+            SetLeft2,
+            SetTop,
+            SetTop2,
+            SetRight,
+            SetRight2,
+            SetBottom,
+            SetBottom2,
+            SetTopLeftRadiusX,
+            SetTopLeftRadiusX2,
+            SetTopLeftRadiusY,
+            SetTopLeftRadiusY2,
+            SetTopRightRadiusX,
+            SetTopRightRadiusX2,
+            SetTopRightRadiusY,
+            SetTopRightRadiusY2,
+            SetBottomLeftRadiusX,
+            SetBottomLeftRadiusX2,
+            SetBottomLeftRadiusY,
+            SetBottomLeftRadiusY2,
+            SetBottomRightRadiusX,
+            SetBottomRightRadiusX2,
+            SetBottomRightRadiusY,
+            SetBottomRightRadiusY2,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionRectangleClip as windows_core::Interface>::IID || <IDCompositionClip_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDCompositionRotateTransform_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAngle(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRotateTransform", "SetAngle", 3usize, |this| ((**(this as *const *const IDCompositionRotateTransform_Vtbl)).SetAngle)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAngle2(this: *mut core::ffi::c_void, angle: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRotateTransform", "SetAngle2", 4usize, |this| ((**(this as *const *const IDCompositionRotateTransform_Vtbl)).SetAngle2)(this, angle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCenterX(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRotateTransform", "SetCenterX", 5usize, |this| ((**(this as *const *const IDCompositionRotateTransform_Vtbl)).SetCenterX)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCenterX2(this: *mut core::ffi::c_void, centerx: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRotateTransform", "SetCenterX2", 6usize, |this| ((**(this as *const *const IDCompositionRotateTransform_Vtbl)).SetCenterX2)(this, centerx), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCenterY(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRotateTransform", "SetCenterY", 7usize, |this| ((**(this as *const *const IDCompositionRotateTransform_Vtbl)).SetCenterY)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCenterY2(this: *mut core::ffi::c_void, centery: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRotateTransform", "SetCenterY2", 8usize, |this| ((**(this as *const *const IDCompositionRotateTransform_Vtbl)).SetCenterY2)(this, centery), |result| Some(*result))
+        }
+        Self {
+            base__: <IDCompositionTransform_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetAngle,
+            SetAngle2,
+            SetCenterX,
+            SetCenterX2,
+            SetCenterY,
+            SetCenterY2,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionRotateTransform as windows_core::Interface>::IID || <IDCompositionTransform_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDCompositionRotateTransform3D_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAngle(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRotateTransform3D", "SetAngle", 3usize, |this| ((**(this as *const *const IDCompositionRotateTransform3D_Vtbl)).SetAngle)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAngle2(this: *mut core::ffi::c_void, angle: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRotateTransform3D", "SetAngle2", 4usize, |this| ((**(this as *const *const IDCompositionRotateTransform3D_Vtbl)).SetAngle2)(this, angle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAxisX(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRotateTransform3D", "SetAxisX", 5usize, |this| ((**(this as *const *const IDCompositionRotateTransform3D_Vtbl)).SetAxisX)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAxisX2(this: *mut core::ffi::c_void, axisx: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRotateTransform3D", "SetAxisX2", 6usize, |this| ((**(this as *const *const IDCompositionRotateTransform3D_Vtbl)).SetAxisX2)(this, axisx), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAxisY(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRotateTransform3D", "SetAxisY", 7usize, |this| ((**(this as *const *const IDCompositionRotateTransform3D_Vtbl)).SetAxisY)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAxisY2(this: *mut core::ffi::c_void, axisy: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRotateTransform3D", "SetAxisY2", 8usize, |this| ((**(this as *const *const IDCompositionRotateTransform3D_Vtbl)).SetAxisY2)(this, axisy), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAxisZ(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRotateTransform3D", "SetAxisZ", 9usize, |this| ((**(this as *const *const IDCompositionRotateTransform3D_Vtbl)).SetAxisZ)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAxisZ2(this: *mut core::ffi::c_void, axisz: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRotateTransform3D", "SetAxisZ2", 10usize, |this| ((**(this as *const *const IDCompositionRotateTransform3D_Vtbl)).SetAxisZ2)(this, axisz), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCenterX(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRotateTransform3D", "SetCenterX", 11usize, |this| ((**(this as *const *const IDCompositionRotateTransform3D_Vtbl)).SetCenterX)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCenterX2(this: *mut core::ffi::c_void, centerx: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRotateTransform3D", "SetCenterX2", 12usize, |this| ((**(this as *const *const IDCompositionRotateTransform3D_Vtbl)).SetCenterX2)(this, centerx), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCenterY(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRotateTransform3D", "SetCenterY", 13usize, |this| ((**(this as *const *const IDCompositionRotateTransform3D_Vtbl)).SetCenterY)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCenterY2(this: *mut core::ffi::c_void, centery: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRotateTransform3D", "SetCenterY2", 14usize, |this| ((**(this as *const *const IDCompositionRotateTransform3D_Vtbl)).SetCenterY2)(this, centery), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCenterZ(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRotateTransform3D", "SetCenterZ", 15usize, |this| ((**(this as *const *const IDCompositionRotateTransform3D_Vtbl)).SetCenterZ)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCenterZ2(this: *mut core::ffi::c_void, centerz: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionRotateTransform3D", "SetCenterZ2", 16usize, |this| ((**(this as *const *const IDCompositionRotateTransform3D_Vtbl)).SetCenterZ2)(this, centerz), |result| Some(*result))
+        }
+        Self {
+            base__: <IDCompositionTransform3D_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetAngle,
+            SetAngle2,
+            SetAxisX,
+            SetAxisX2,
+            SetAxisY,
+            SetAxisY2,
+            SetAxisZ,
+            SetAxisZ2,
+            SetCenterX,
+            SetCenterX2,
+            SetCenterY,
+            SetCenterY2,
+            SetCenterZ,
+            SetCenterZ2,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionRotateTransform3D as windows_core::Interface>::IID || <IDCompositionTransform3D_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDCompositionSaturationEffect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSaturation(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionSaturationEffect", "SetSaturation", 4usize, |this| ((**(this as *const *const IDCompositionSaturationEffect_Vtbl)).SetSaturation)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSaturation2(this: *mut core::ffi::c_void, ratio: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionSaturationEffect", "SetSaturation2", 5usize, |this| ((**(this as *const *const IDCompositionSaturationEffect_Vtbl)).SetSaturation2)(this, ratio), |result| Some(*result))
+        }
+        Self { base__: <IDCompositionFilterEffect_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetSaturation, SetSaturation2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionSaturationEffect as windows_core::Interface>::IID || <IDCompositionFilterEffect_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDCompositionScaleTransform_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetScaleX(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionScaleTransform", "SetScaleX", 3usize, |this| ((**(this as *const *const IDCompositionScaleTransform_Vtbl)).SetScaleX)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScaleX2(this: *mut core::ffi::c_void, scalex: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionScaleTransform", "SetScaleX2", 4usize, |this| ((**(this as *const *const IDCompositionScaleTransform_Vtbl)).SetScaleX2)(this, scalex), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScaleY(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionScaleTransform", "SetScaleY", 5usize, |this| ((**(this as *const *const IDCompositionScaleTransform_Vtbl)).SetScaleY)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScaleY2(this: *mut core::ffi::c_void, scaley: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionScaleTransform", "SetScaleY2", 6usize, |this| ((**(this as *const *const IDCompositionScaleTransform_Vtbl)).SetScaleY2)(this, scaley), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCenterX(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionScaleTransform", "SetCenterX", 7usize, |this| ((**(this as *const *const IDCompositionScaleTransform_Vtbl)).SetCenterX)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCenterX2(this: *mut core::ffi::c_void, centerx: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionScaleTransform", "SetCenterX2", 8usize, |this| ((**(this as *const *const IDCompositionScaleTransform_Vtbl)).SetCenterX2)(this, centerx), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCenterY(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionScaleTransform", "SetCenterY", 9usize, |this| ((**(this as *const *const IDCompositionScaleTransform_Vtbl)).SetCenterY)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCenterY2(this: *mut core::ffi::c_void, centery: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionScaleTransform", "SetCenterY2", 10usize, |this| ((**(this as *const *const IDCompositionScaleTransform_Vtbl)).SetCenterY2)(this, centery), |result| Some(*result))
+        }
+        Self {
+            base__: <IDCompositionTransform_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetScaleX,
+            SetScaleX2,
+            SetScaleY,
+            SetScaleY2,
+            SetCenterX,
+            SetCenterX2,
+            SetCenterY,
+            SetCenterY2,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionScaleTransform as windows_core::Interface>::IID || <IDCompositionTransform_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDCompositionScaleTransform3D_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetScaleX(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionScaleTransform3D", "SetScaleX", 3usize, |this| ((**(this as *const *const IDCompositionScaleTransform3D_Vtbl)).SetScaleX)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScaleX2(this: *mut core::ffi::c_void, scalex: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionScaleTransform3D", "SetScaleX2", 4usize, |this| ((**(this as *const *const IDCompositionScaleTransform3D_Vtbl)).SetScaleX2)(this, scalex), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScaleY(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionScaleTransform3D", "SetScaleY", 5usize, |this| ((**(this as *const *const IDCompositionScaleTransform3D_Vtbl)).SetScaleY)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScaleY2(this: *mut core::ffi::c_void, scaley: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionScaleTransform3D", "SetScaleY2", 6usize, |this| ((**(this as *const *const IDCompositionScaleTransform3D_Vtbl)).SetScaleY2)(this, scaley), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScaleZ(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionScaleTransform3D", "SetScaleZ", 7usize, |this| ((**(this as *const *const IDCompositionScaleTransform3D_Vtbl)).SetScaleZ)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScaleZ2(this: *mut core::ffi::c_void, scalez: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionScaleTransform3D", "SetScaleZ2", 8usize, |this| ((**(this as *const *const IDCompositionScaleTransform3D_Vtbl)).SetScaleZ2)(this, scalez), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCenterX(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionScaleTransform3D", "SetCenterX", 9usize, |this| ((**(this as *const *const IDCompositionScaleTransform3D_Vtbl)).SetCenterX)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCenterX2(this: *mut core::ffi::c_void, centerx: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionScaleTransform3D", "SetCenterX2", 10usize, |this| ((**(this as *const *const IDCompositionScaleTransform3D_Vtbl)).SetCenterX2)(this, centerx), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCenterY(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionScaleTransform3D", "SetCenterY", 11usize, |this| ((**(this as *const *const IDCompositionScaleTransform3D_Vtbl)).SetCenterY)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCenterY2(this: *mut core::ffi::c_void, centery: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionScaleTransform3D", "SetCenterY2", 12usize, |this| ((**(this as *const *const IDCompositionScaleTransform3D_Vtbl)).SetCenterY2)(this, centery), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCenterZ(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionScaleTransform3D", "SetCenterZ", 13usize, |this| ((**(this as *const *const IDCompositionScaleTransform3D_Vtbl)).SetCenterZ)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCenterZ2(this: *mut core::ffi::c_void, centerz: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionScaleTransform3D", "SetCenterZ2", 14usize, |this| ((**(this as *const *const IDCompositionScaleTransform3D_Vtbl)).SetCenterZ2)(this, centerz), |result| Some(*result))
+        }
+        Self {
+            base__: <IDCompositionTransform3D_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetScaleX,
+            SetScaleX2,
+            SetScaleY,
+            SetScaleY2,
+            SetScaleZ,
+            SetScaleZ2,
+            SetCenterX,
+            SetCenterX2,
+            SetCenterY,
+            SetCenterY2,
+            SetCenterZ,
+            SetCenterZ2,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionScaleTransform3D as windows_core::Interface>::IID || <IDCompositionTransform3D_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for IDCompositionShadowEffect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetStandardDeviation(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionShadowEffect", "SetStandardDeviation", 4usize, |this| ((**(this as *const *const IDCompositionShadowEffect_Vtbl)).SetStandardDeviation)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStandardDeviation2(this: *mut core::ffi::c_void, amount: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionShadowEffect", "SetStandardDeviation2", 5usize, |this| ((**(this as *const *const IDCompositionShadowEffect_Vtbl)).SetStandardDeviation2)(this, amount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn SetColor(this: *mut core::ffi::c_void, color: *const super::Direct2D::Common::D2D_VECTOR_4F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionShadowEffect", "SetColor", 6usize, |this| ((**(this as *const *const IDCompositionShadowEffect_Vtbl)).SetColor)(this, color), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRed(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionShadowEffect", "SetRed", 7usize, |this| ((**(this as *const *const IDCompositionShadowEffect_Vtbl)).SetRed)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRed2(this: *mut core::ffi::c_void, amount: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionShadowEffect", "SetRed2", 8usize, |this| ((**(this as *const *const IDCompositionShadowEffect_Vtbl)).SetRed2)(this, amount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGreen(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionShadowEffect", "SetGreen", 9usize, |this| ((**(this as *const *const IDCompositionShadowEffect_Vtbl)).SetGreen)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGreen2(this: *mut core::ffi::c_void, amount: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionShadowEffect", "SetGreen2", 10usize, |this| ((**(this as *const *const IDCompositionShadowEffect_Vtbl)).SetGreen2)(this, amount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlue(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionShadowEffect", "SetBlue", 11usize, |this| ((**(this as *const *const IDCompositionShadowEffect_Vtbl)).SetBlue)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlue2(this: *mut core::ffi::c_void, amount: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionShadowEffect", "SetBlue2", 12usize, |this| ((**(this as *const *const IDCompositionShadowEffect_Vtbl)).SetBlue2)(this, amount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlpha(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionShadowEffect", "SetAlpha", 13usize, |this| ((**(this as *const *const IDCompositionShadowEffect_Vtbl)).SetAlpha)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlpha2(this: *mut core::ffi::c_void, amount: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionShadowEffect", "SetAlpha2", 14usize, |this| ((**(this as *const *const IDCompositionShadowEffect_Vtbl)).SetAlpha2)(this, amount), |result| Some(*result))
+        }
+        Self {
+            base__: <IDCompositionFilterEffect_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetStandardDeviation,
+            SetStandardDeviation2,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            SetColor,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            SetColor: 0,
+            SetRed,
+            SetRed2,
+            SetGreen,
+            SetGreen2,
+            SetBlue,
+            SetBlue2,
+            SetAlpha,
+            SetAlpha2,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionShadowEffect as windows_core::Interface>::IID || <IDCompositionFilterEffect_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDCompositionSkewTransform_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAngleX(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionSkewTransform", "SetAngleX", 3usize, |this| ((**(this as *const *const IDCompositionSkewTransform_Vtbl)).SetAngleX)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAngleX2(this: *mut core::ffi::c_void, anglex: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionSkewTransform", "SetAngleX2", 4usize, |this| ((**(this as *const *const IDCompositionSkewTransform_Vtbl)).SetAngleX2)(this, anglex), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAngleY(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionSkewTransform", "SetAngleY", 5usize, |this| ((**(this as *const *const IDCompositionSkewTransform_Vtbl)).SetAngleY)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAngleY2(this: *mut core::ffi::c_void, angley: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionSkewTransform", "SetAngleY2", 6usize, |this| ((**(this as *const *const IDCompositionSkewTransform_Vtbl)).SetAngleY2)(this, angley), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCenterX(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionSkewTransform", "SetCenterX", 7usize, |this| ((**(this as *const *const IDCompositionSkewTransform_Vtbl)).SetCenterX)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCenterX2(this: *mut core::ffi::c_void, centerx: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionSkewTransform", "SetCenterX2", 8usize, |this| ((**(this as *const *const IDCompositionSkewTransform_Vtbl)).SetCenterX2)(this, centerx), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCenterY(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionSkewTransform", "SetCenterY", 9usize, |this| ((**(this as *const *const IDCompositionSkewTransform_Vtbl)).SetCenterY)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCenterY2(this: *mut core::ffi::c_void, centery: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionSkewTransform", "SetCenterY2", 10usize, |this| ((**(this as *const *const IDCompositionSkewTransform_Vtbl)).SetCenterY2)(this, centery), |result| Some(*result))
+        }
+        Self {
+            base__: <IDCompositionTransform_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetAngleX,
+            SetAngleX2,
+            SetAngleY,
+            SetAngleY2,
+            SetCenterX,
+            SetCenterX2,
+            SetCenterY,
+            SetCenterY2,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionSkewTransform as windows_core::Interface>::IID || <IDCompositionTransform_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDCompositionSurface_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BeginDraw(this: *mut core::ffi::c_void, updaterect: *const super::super::Foundation::RECT, iid: *const windows_core::GUID, updateobject: *mut *mut core::ffi::c_void, updateoffset: *mut super::super::Foundation::POINT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionSurface", "BeginDraw", 3usize, |this| ((**(this as *const *const IDCompositionSurface_Vtbl)).BeginDraw)(this, updaterect, iid, updateobject, updateoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndDraw(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionSurface", "EndDraw", 4usize, |this| ((**(this as *const *const IDCompositionSurface_Vtbl)).EndDraw)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuspendDraw(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionSurface", "SuspendDraw", 5usize, |this| ((**(this as *const *const IDCompositionSurface_Vtbl)).SuspendDraw)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResumeDraw(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionSurface", "ResumeDraw", 6usize, |this| ((**(this as *const *const IDCompositionSurface_Vtbl)).ResumeDraw)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Scroll(this: *mut core::ffi::c_void, scrollrect: *const super::super::Foundation::RECT, cliprect: *const super::super::Foundation::RECT, offsetx: i32, offsety: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionSurface", "Scroll", 7usize, |this| ((**(this as *const *const IDCompositionSurface_Vtbl)).Scroll)(this, scrollrect, cliprect, offsetx, offsety), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BeginDraw, EndDraw, SuspendDraw, ResumeDraw, Scroll }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionSurface as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IDCompositionSurfaceFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateSurface(this: *mut core::ffi::c_void, width: u32, height: u32, pixelformat: super::Dxgi::Common::DXGI_FORMAT, alphamode: super::Dxgi::Common::DXGI_ALPHA_MODE, surface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionSurfaceFactory", "CreateSurface", 3usize, |this| ((**(this as *const *const IDCompositionSurfaceFactory_Vtbl)).CreateSurface)(this, width, height, pixelformat, alphamode, surface), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateVirtualSurface(this: *mut core::ffi::c_void, initialwidth: u32, initialheight: u32, pixelformat: super::Dxgi::Common::DXGI_FORMAT, alphamode: super::Dxgi::Common::DXGI_ALPHA_MODE, virtualsurface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionSurfaceFactory", "CreateVirtualSurface", 4usize, |this| ((**(this as *const *const IDCompositionSurfaceFactory_Vtbl)).CreateVirtualSurface)(this, initialwidth, initialheight, pixelformat, alphamode, virtualsurface), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateSurface,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateSurface: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateVirtualSurface,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateVirtualSurface: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionSurfaceFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDCompositionTableTransferEffect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetRedTable(this: *mut core::ffi::c_void, tablevalues: *const f32, count: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTableTransferEffect", "SetRedTable", 4usize, |this| ((**(this as *const *const IDCompositionTableTransferEffect_Vtbl)).SetRedTable)(this, tablevalues, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGreenTable(this: *mut core::ffi::c_void, tablevalues: *const f32, count: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTableTransferEffect", "SetGreenTable", 5usize, |this| ((**(this as *const *const IDCompositionTableTransferEffect_Vtbl)).SetGreenTable)(this, tablevalues, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlueTable(this: *mut core::ffi::c_void, tablevalues: *const f32, count: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTableTransferEffect", "SetBlueTable", 6usize, |this| ((**(this as *const *const IDCompositionTableTransferEffect_Vtbl)).SetBlueTable)(this, tablevalues, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlphaTable(this: *mut core::ffi::c_void, tablevalues: *const f32, count: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTableTransferEffect", "SetAlphaTable", 7usize, |this| ((**(this as *const *const IDCompositionTableTransferEffect_Vtbl)).SetAlphaTable)(this, tablevalues, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRedDisable(this: *mut core::ffi::c_void, reddisable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTableTransferEffect", "SetRedDisable", 8usize, |this| ((**(this as *const *const IDCompositionTableTransferEffect_Vtbl)).SetRedDisable)(this, reddisable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGreenDisable(this: *mut core::ffi::c_void, greendisable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTableTransferEffect", "SetGreenDisable", 9usize, |this| ((**(this as *const *const IDCompositionTableTransferEffect_Vtbl)).SetGreenDisable)(this, greendisable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlueDisable(this: *mut core::ffi::c_void, bluedisable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTableTransferEffect", "SetBlueDisable", 10usize, |this| ((**(this as *const *const IDCompositionTableTransferEffect_Vtbl)).SetBlueDisable)(this, bluedisable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlphaDisable(this: *mut core::ffi::c_void, alphadisable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTableTransferEffect", "SetAlphaDisable", 11usize, |this| ((**(this as *const *const IDCompositionTableTransferEffect_Vtbl)).SetAlphaDisable)(this, alphadisable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClampOutput(this: *mut core::ffi::c_void, clampoutput: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTableTransferEffect", "SetClampOutput", 12usize, |this| ((**(this as *const *const IDCompositionTableTransferEffect_Vtbl)).SetClampOutput)(this, clampoutput), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRedTableValue(this: *mut core::ffi::c_void, index: u32, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTableTransferEffect", "SetRedTableValue", 13usize, |this| ((**(this as *const *const IDCompositionTableTransferEffect_Vtbl)).SetRedTableValue)(this, index, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRedTableValue2(this: *mut core::ffi::c_void, index: u32, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTableTransferEffect", "SetRedTableValue2", 14usize, |this| ((**(this as *const *const IDCompositionTableTransferEffect_Vtbl)).SetRedTableValue2)(this, index, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGreenTableValue(this: *mut core::ffi::c_void, index: u32, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTableTransferEffect", "SetGreenTableValue", 15usize, |this| ((**(this as *const *const IDCompositionTableTransferEffect_Vtbl)).SetGreenTableValue)(this, index, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGreenTableValue2(this: *mut core::ffi::c_void, index: u32, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTableTransferEffect", "SetGreenTableValue2", 16usize, |this| ((**(this as *const *const IDCompositionTableTransferEffect_Vtbl)).SetGreenTableValue2)(this, index, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlueTableValue(this: *mut core::ffi::c_void, index: u32, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTableTransferEffect", "SetBlueTableValue", 17usize, |this| ((**(this as *const *const IDCompositionTableTransferEffect_Vtbl)).SetBlueTableValue)(this, index, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlueTableValue2(this: *mut core::ffi::c_void, index: u32, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTableTransferEffect", "SetBlueTableValue2", 18usize, |this| ((**(this as *const *const IDCompositionTableTransferEffect_Vtbl)).SetBlueTableValue2)(this, index, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlphaTableValue(this: *mut core::ffi::c_void, index: u32, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTableTransferEffect", "SetAlphaTableValue", 19usize, |this| ((**(this as *const *const IDCompositionTableTransferEffect_Vtbl)).SetAlphaTableValue)(this, index, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlphaTableValue2(this: *mut core::ffi::c_void, index: u32, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTableTransferEffect", "SetAlphaTableValue2", 20usize, |this| ((**(this as *const *const IDCompositionTableTransferEffect_Vtbl)).SetAlphaTableValue2)(this, index, value), |result| Some(*result))
+        }
+        Self {
+            base__: <IDCompositionFilterEffect_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetRedTable,
+            SetGreenTable,
+            SetBlueTable,
+            SetAlphaTable,
+            SetRedDisable,
+            SetGreenDisable,
+            SetBlueDisable,
+            SetAlphaDisable,
+            SetClampOutput,
+            SetRedTableValue,
+            SetRedTableValue2,
+            SetGreenTableValue,
+            SetGreenTableValue2,
+            SetBlueTableValue,
+            SetBlueTableValue2,
+            SetAlphaTableValue,
+            SetAlphaTableValue2,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionTableTransferEffect as windows_core::Interface>::IID || <IDCompositionFilterEffect_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDCompositionTarget_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetRoot(this: *mut core::ffi::c_void, visual: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTarget", "SetRoot", 3usize, |this| ((**(this as *const *const IDCompositionTarget_Vtbl)).SetRoot)(this, visual), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetRoot }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionTarget as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for IDCompositionTexture_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn SetSourceRect(this: *mut core::ffi::c_void, sourcerect: *const super::Direct2D::Common::D2D_RECT_U) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTexture", "SetSourceRect", 3usize, |this| ((**(this as *const *const IDCompositionTexture_Vtbl)).SetSourceRect)(this, sourcerect), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn SetColorSpace(this: *mut core::ffi::c_void, colorspace: super::Dxgi::Common::DXGI_COLOR_SPACE_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTexture", "SetColorSpace", 4usize, |this| ((**(this as *const *const IDCompositionTexture_Vtbl)).SetColorSpace)(this, colorspace), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn SetAlphaMode(this: *mut core::ffi::c_void, alphamode: super::Dxgi::Common::DXGI_ALPHA_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTexture", "SetAlphaMode", 5usize, |this| ((**(this as *const *const IDCompositionTexture_Vtbl)).SetAlphaMode)(this, alphamode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAvailableFence(this: *mut core::ffi::c_void, fencevalue: *mut u64, iid: *const windows_core::GUID, availablefence: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTexture", "GetAvailableFence", 6usize, |this| ((**(this as *const *const IDCompositionTexture_Vtbl)).GetAvailableFence)(this, fencevalue, iid, availablefence), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            SetSourceRect,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            SetSourceRect: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            SetColorSpace,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            SetColorSpace: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            SetAlphaMode,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            SetAlphaMode: 0,
+            GetAvailableFence,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionTexture as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDCompositionTransform_Vtbl {
+    const PROXY: Self = { Self { base__: <IDCompositionTransform3D_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionTransform as windows_core::Interface>::IID || <IDCompositionTransform3D_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDCompositionTransform3D_Vtbl {
+    const PROXY: Self = { Self { base__: <IDCompositionEffect_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionTransform3D as windows_core::Interface>::IID || <IDCompositionEffect_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDCompositionTranslateTransform_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetOffsetX(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTranslateTransform", "SetOffsetX", 3usize, |this| ((**(this as *const *const IDCompositionTranslateTransform_Vtbl)).SetOffsetX)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOffsetX2(this: *mut core::ffi::c_void, offsetx: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTranslateTransform", "SetOffsetX2", 4usize, |this| ((**(this as *const *const IDCompositionTranslateTransform_Vtbl)).SetOffsetX2)(this, offsetx), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOffsetY(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTranslateTransform", "SetOffsetY", 5usize, |this| ((**(this as *const *const IDCompositionTranslateTransform_Vtbl)).SetOffsetY)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOffsetY2(this: *mut core::ffi::c_void, offsety: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTranslateTransform", "SetOffsetY2", 6usize, |this| ((**(this as *const *const IDCompositionTranslateTransform_Vtbl)).SetOffsetY2)(this, offsety), |result| Some(*result))
+        }
+        Self { base__: <IDCompositionTransform_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetOffsetX, SetOffsetX2, SetOffsetY, SetOffsetY2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionTranslateTransform as windows_core::Interface>::IID || <IDCompositionTransform_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDCompositionTranslateTransform3D_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetOffsetX(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTranslateTransform3D", "SetOffsetX", 3usize, |this| ((**(this as *const *const IDCompositionTranslateTransform3D_Vtbl)).SetOffsetX)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOffsetX2(this: *mut core::ffi::c_void, offsetx: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTranslateTransform3D", "SetOffsetX2", 4usize, |this| ((**(this as *const *const IDCompositionTranslateTransform3D_Vtbl)).SetOffsetX2)(this, offsetx), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOffsetY(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTranslateTransform3D", "SetOffsetY", 5usize, |this| ((**(this as *const *const IDCompositionTranslateTransform3D_Vtbl)).SetOffsetY)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOffsetY2(this: *mut core::ffi::c_void, offsety: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTranslateTransform3D", "SetOffsetY2", 6usize, |this| ((**(this as *const *const IDCompositionTranslateTransform3D_Vtbl)).SetOffsetY2)(this, offsety), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOffsetZ(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTranslateTransform3D", "SetOffsetZ", 7usize, |this| ((**(this as *const *const IDCompositionTranslateTransform3D_Vtbl)).SetOffsetZ)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOffsetZ2(this: *mut core::ffi::c_void, offsetz: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTranslateTransform3D", "SetOffsetZ2", 8usize, |this| ((**(this as *const *const IDCompositionTranslateTransform3D_Vtbl)).SetOffsetZ2)(this, offsetz), |result| Some(*result))
+        }
+        Self {
+            base__: <IDCompositionTransform3D_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetOffsetX,
+            SetOffsetX2,
+            SetOffsetY,
+            SetOffsetY2,
+            SetOffsetZ,
+            SetOffsetZ2,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionTranslateTransform3D as windows_core::Interface>::IID || <IDCompositionTransform3D_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for IDCompositionTurbulenceEffect_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn SetOffset(this: *mut core::ffi::c_void, offset: *const super::Direct2D::Common::D2D_VECTOR_2F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTurbulenceEffect", "SetOffset", 4usize, |this| ((**(this as *const *const IDCompositionTurbulenceEffect_Vtbl)).SetOffset)(this, offset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn SetBaseFrequency(this: *mut core::ffi::c_void, frequency: *const super::Direct2D::Common::D2D_VECTOR_2F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTurbulenceEffect", "SetBaseFrequency", 5usize, |this| ((**(this as *const *const IDCompositionTurbulenceEffect_Vtbl)).SetBaseFrequency)(this, frequency), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn SetSize(this: *mut core::ffi::c_void, size: *const super::Direct2D::Common::D2D_VECTOR_2F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTurbulenceEffect", "SetSize", 6usize, |this| ((**(this as *const *const IDCompositionTurbulenceEffect_Vtbl)).SetSize)(this, size), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNumOctaves(this: *mut core::ffi::c_void, numoctaves: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTurbulenceEffect", "SetNumOctaves", 7usize, |this| ((**(this as *const *const IDCompositionTurbulenceEffect_Vtbl)).SetNumOctaves)(this, numoctaves), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSeed(this: *mut core::ffi::c_void, seed: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTurbulenceEffect", "SetSeed", 8usize, |this| ((**(this as *const *const IDCompositionTurbulenceEffect_Vtbl)).SetSeed)(this, seed), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn SetNoise(this: *mut core::ffi::c_void, noise: super::Direct2D::Common::D2D1_TURBULENCE_NOISE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTurbulenceEffect", "SetNoise", 9usize, |this| ((**(this as *const *const IDCompositionTurbulenceEffect_Vtbl)).SetNoise)(this, noise), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStitchable(this: *mut core::ffi::c_void, stitchable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionTurbulenceEffect", "SetStitchable", 10usize, |this| ((**(this as *const *const IDCompositionTurbulenceEffect_Vtbl)).SetStitchable)(this, stitchable), |result| Some(*result))
+        }
+        Self {
+            base__: <IDCompositionFilterEffect_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            SetOffset,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            SetOffset: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            SetBaseFrequency,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            SetBaseFrequency: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            SetSize,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            SetSize: 0,
+            SetNumOctaves,
+            SetSeed,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            SetNoise,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            SetNoise: 0,
+            SetStitchable,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionTurbulenceEffect as windows_core::Interface>::IID || <IDCompositionFilterEffect_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDCompositionVirtualSurface_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Resize(this: *mut core::ffi::c_void, width: u32, height: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVirtualSurface", "Resize", 8usize, |this| ((**(this as *const *const IDCompositionVirtualSurface_Vtbl)).Resize)(this, width, height), |result| Some(*result))
+        }
+        unsafe extern "system" fn Trim(this: *mut core::ffi::c_void, rectangles: *const super::super::Foundation::RECT, count: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVirtualSurface", "Trim", 9usize, |this| ((**(this as *const *const IDCompositionVirtualSurface_Vtbl)).Trim)(this, rectangles, count), |result| Some(*result))
+        }
+        Self { base__: <IDCompositionSurface_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Resize, Trim }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionVirtualSurface as windows_core::Interface>::IID || <IDCompositionSurface_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+impl windows_core::imp::ProxyVtable for IDCompositionVisual_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetOffsetX(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisual", "SetOffsetX", 3usize, |this| ((**(this as *const *const IDCompositionVisual_Vtbl)).SetOffsetX)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOffsetX2(this: *mut core::ffi::c_void, offsetx: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisual", "SetOffsetX2", 4usize, |this| ((**(this as *const *const IDCompositionVisual_Vtbl)).SetOffsetX2)(this, offsetx), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOffsetY(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisual", "SetOffsetY", 5usize, |this| ((**(this as *const *const IDCompositionVisual_Vtbl)).SetOffsetY)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOffsetY2(this: *mut core::ffi::c_void, offsety: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisual", "SetOffsetY2", 6usize, |this| ((**(this as *const *const IDCompositionVisual_Vtbl)).SetOffsetY2)(this, offsety), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransform(this: *mut core::ffi::c_void, transform: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisual", "SetTransform", 7usize, |this| ((**(this as *const *const IDCompositionVisual_Vtbl)).SetTransform)(this, transform), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation_Numerics")]
+        unsafe extern "system" fn SetTransform2(this: *mut core::ffi::c_void, matrix: *const super::super::super::Foundation::Numerics::Matrix3x2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisual", "SetTransform2", 8usize, |this| ((**(this as *const *const IDCompositionVisual_Vtbl)).SetTransform2)(this, matrix), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransformParent(this: *mut core::ffi::c_void, visual: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisual", "SetTransformParent", 9usize, |this| ((**(this as *const *const IDCompositionVisual_Vtbl)).SetTransformParent)(this, visual), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEffect(this: *mut core::ffi::c_void, effect: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisual", "SetEffect", 10usize, |this| ((**(this as *const *const IDCompositionVisual_Vtbl)).SetEffect)(this, effect), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBitmapInterpolationMode(this: *mut core::ffi::c_void, interpolationmode: DCOMPOSITION_BITMAP_INTERPOLATION_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisual", "SetBitmapInterpolationMode", 11usize, |this| ((**(this as *const *const IDCompositionVisual_Vtbl)).SetBitmapInterpolationMode)(this, interpolationmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBorderMode(this: *mut core::ffi::c_void, bordermode: DCOMPOSITION_BORDER_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisual", "SetBorderMode", 12usize, |this| ((**(this as *const *const IDCompositionVisual_Vtbl)).SetBorderMode)(this, bordermode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClip(this: *mut core::ffi::c_void, clip: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisual", "SetClip", 13usize, |this| ((**(this as *const *const IDCompositionVisual_Vtbl)).SetClip)(this, clip), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn SetClip2(this: *mut core::ffi::c_void, rect: *const super::Direct2D::Common::D2D_RECT_F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisual", "SetClip2", 14usize, |this| ((**(this as *const *const IDCompositionVisual_Vtbl)).SetClip2)(this, rect), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContent(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisual", "SetContent", 15usize, |this| ((**(this as *const *const IDCompositionVisual_Vtbl)).SetContent)(this, content), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddVisual(this: *mut core::ffi::c_void, visual: *mut core::ffi::c_void, insertabove: super::super::Foundation::BOOL, referencevisual: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisual", "AddVisual", 16usize, |this| ((**(this as *const *const IDCompositionVisual_Vtbl)).AddVisual)(this, visual, insertabove, referencevisual), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveVisual(this: *mut core::ffi::c_void, visual: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisual", "RemoveVisual", 17usize, |this| ((**(this as *const *const IDCompositionVisual_Vtbl)).RemoveVisual)(this, visual), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAllVisuals(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisual", "RemoveAllVisuals", 18usize, |this| ((**(this as *const *const IDCompositionVisual_Vtbl)).RemoveAllVisuals)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompositeMode(this: *mut core::ffi::c_void, compositemode: DCOMPOSITION_COMPOSITE_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisual", "SetCompositeMode", 19usize, |this| ((**(this as *const *const IDCompositionVisual_Vtbl)).SetCompositeMode)(this, compositemode), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetOffsetX,
+            SetOffsetX2,
+            SetOffsetY,
+            SetOffsetY2,
+            SetTransform,
+            #[cfg(feature = "Foundation_Numerics")]
+            SetTransform2,
+            #[cfg(not(feature = "Foundation_Numerics"))]
+            SetTransform2: 0,
+            SetTransformParent,
+            SetEffect,
+            SetBitmapInterpolationMode,
+            SetBorderMode,
+            SetClip,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            SetClip2,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            SetClip2: 0,
+            SetContent,
+            AddVisual,
+            RemoveVisual,
+            RemoveAllVisuals,
+            SetCompositeMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionVisual as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+impl windows_core::imp::ProxyVtable for IDCompositionVisual2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetOpacityMode(this: *mut core::ffi::c_void, mode: DCOMPOSITION_OPACITY_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisual2", "SetOpacityMode", 20usize, |this| ((**(this as *const *const IDCompositionVisual2_Vtbl)).SetOpacityMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackFaceVisibility(this: *mut core::ffi::c_void, visibility: DCOMPOSITION_BACKFACE_VISIBILITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisual2", "SetBackFaceVisibility", 21usize, |this| ((**(this as *const *const IDCompositionVisual2_Vtbl)).SetBackFaceVisibility)(this, visibility), |result| Some(*result))
+        }
+        Self { base__: <IDCompositionVisual_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetOpacityMode, SetBackFaceVisibility }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionVisual2 as windows_core::Interface>::IID || <IDCompositionVisual_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+impl windows_core::imp::ProxyVtable for IDCompositionVisual3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDepthMode(this: *mut core::ffi::c_void, mode: DCOMPOSITION_DEPTH_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisual3", "SetDepthMode", 26usize, |this| ((**(this as *const *const IDCompositionVisual3_Vtbl)).SetDepthMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOffsetZ(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisual3", "SetOffsetZ", 27usize, |this| ((**(this as *const *const IDCompositionVisual3_Vtbl)).SetOffsetZ)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOffsetZ2(this: *mut core::ffi::c_void, offsetz: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisual3", "SetOffsetZ2", 28usize, |this| ((**(this as *const *const IDCompositionVisual3_Vtbl)).SetOffsetZ2)(this, offsetz), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOpacity(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisual3", "SetOpacity", 29usize, |this| ((**(this as *const *const IDCompositionVisual3_Vtbl)).SetOpacity)(this, animation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOpacity2(this: *mut core::ffi::c_void, opacity: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisual3", "SetOpacity2", 30usize, |this| ((**(this as *const *const IDCompositionVisual3_Vtbl)).SetOpacity2)(this, opacity), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransform(this: *mut core::ffi::c_void, transform: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisual3", "SetTransform", 31usize, |this| ((**(this as *const *const IDCompositionVisual3_Vtbl)).SetTransform)(this, transform), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn SetTransform2(this: *mut core::ffi::c_void, matrix: *const super::Direct2D::Common::D2D_MATRIX_4X4_F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisual3", "SetTransform2", 32usize, |this| ((**(this as *const *const IDCompositionVisual3_Vtbl)).SetTransform2)(this, matrix), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVisible(this: *mut core::ffi::c_void, visible: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisual3", "SetVisible", 33usize, |this| ((**(this as *const *const IDCompositionVisual3_Vtbl)).SetVisible)(this, visible), |result| Some(*result))
+        }
+        Self {
+            base__: <IDCompositionVisualDebug_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetDepthMode,
+            SetOffsetZ,
+            SetOffsetZ2,
+            SetOpacity,
+            SetOpacity2,
+            SetTransform,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            SetTransform2,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            SetTransform2: 0,
+            SetVisible,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionVisual3 as windows_core::Interface>::IID || <IDCompositionVisualDebug_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation_Numerics", feature = "Win32_Graphics_Direct2D_Common"))]
+impl windows_core::imp::ProxyVtable for IDCompositionVisualDebug_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn EnableHeatMap(this: *mut core::ffi::c_void, color: *const super::Direct2D::Common::D2D1_COLOR_F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisualDebug", "EnableHeatMap", 22usize, |this| ((**(this as *const *const IDCompositionVisualDebug_Vtbl)).EnableHeatMap)(this, color), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableHeatMap(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisualDebug", "DisableHeatMap", 23usize, |this| ((**(this as *const *const IDCompositionVisualDebug_Vtbl)).DisableHeatMap)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableRedrawRegions(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisualDebug", "EnableRedrawRegions", 24usize, |this| ((**(this as *const *const IDCompositionVisualDebug_Vtbl)).EnableRedrawRegions)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableRedrawRegions(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCompositionVisualDebug", "DisableRedrawRegions", 25usize, |this| ((**(this as *const *const IDCompositionVisualDebug_Vtbl)).DisableRedrawRegions)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IDCompositionVisual2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            EnableHeatMap,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            EnableHeatMap: 0,
+            DisableHeatMap,
+            EnableRedrawRegions,
+            DisableRedrawRegions,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCompositionVisualDebug as windows_core::Interface>::IID || <IDCompositionVisual2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
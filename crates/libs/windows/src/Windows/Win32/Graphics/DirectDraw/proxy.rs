@@ -0,0 +1,1576 @@
+impl windows_core::imp::ProxyVtable for IDDVideoPortContainer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateVideoPort(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDVIDEOPORTDESC, param2: *mut *mut core::ffi::c_void, param3: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDDVideoPortContainer", "CreateVideoPort", 3usize, |this| ((**(this as *const *const IDDVideoPortContainer_Vtbl)).CreateVideoPort)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumVideoPorts(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDVIDEOPORTCAPS, param2: *mut core::ffi::c_void, param3: LPDDENUMVIDEOCALLBACK) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDDVideoPortContainer", "EnumVideoPorts", 4usize, |this| ((**(this as *const *const IDDVideoPortContainer_Vtbl)).EnumVideoPorts)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoPortConnectInfo(this: *mut core::ffi::c_void, param0: u32, pcinfo: *mut u32, param2: *mut DDVIDEOPORTCONNECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDDVideoPortContainer", "GetVideoPortConnectInfo", 5usize, |this| ((**(this as *const *const IDDVideoPortContainer_Vtbl)).GetVideoPortConnectInfo)(this, param0, pcinfo, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryVideoPortStatus(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDVIDEOPORTSTATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDDVideoPortContainer", "QueryVideoPortStatus", 6usize, |this| ((**(this as *const *const IDDVideoPortContainer_Vtbl)).QueryVideoPortStatus)(this, param0, param1), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateVideoPort,
+            EnumVideoPorts,
+            GetVideoPortConnectInfo,
+            QueryVideoPortStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDDVideoPortContainer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IDirectDraw_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Compact(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw", "Compact", 3usize, |this| ((**(this as *const *const IDirectDraw_Vtbl)).Compact)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateClipper(this: *mut core::ffi::c_void, param0: u32, param1: *mut *mut core::ffi::c_void, param2: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw", "CreateClipper", 4usize, |this| ((**(this as *const *const IDirectDraw_Vtbl)).CreateClipper)(this, param0, param1, param2), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn CreatePalette(this: *mut core::ffi::c_void, param0: u32, param1: *mut super::Gdi::PALETTEENTRY, param2: *mut *mut core::ffi::c_void, param3: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw", "CreatePalette", 5usize, |this| ((**(this as *const *const IDirectDraw_Vtbl)).CreatePalette)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSurface(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC, param1: *mut *mut core::ffi::c_void, param2: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw", "CreateSurface", 6usize, |this| ((**(this as *const *const IDirectDraw_Vtbl)).CreateSurface)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn DuplicateSurface(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw", "DuplicateSurface", 7usize, |this| ((**(this as *const *const IDirectDraw_Vtbl)).DuplicateSurface)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumDisplayModes(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDSURFACEDESC, param2: *mut core::ffi::c_void, param3: LPDDENUMMODESCALLBACK) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw", "EnumDisplayModes", 8usize, |this| ((**(this as *const *const IDirectDraw_Vtbl)).EnumDisplayModes)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumSurfaces(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDSURFACEDESC, param2: *mut core::ffi::c_void, param3: LPDDENUMSURFACESCALLBACK) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw", "EnumSurfaces", 9usize, |this| ((**(this as *const *const IDirectDraw_Vtbl)).EnumSurfaces)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlipToGDISurface(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw", "FlipToGDISurface", 10usize, |this| ((**(this as *const *const IDirectDraw_Vtbl)).FlipToGDISurface)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCaps(this: *mut core::ffi::c_void, param0: *mut DDCAPS_DX7, param1: *mut DDCAPS_DX7) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw", "GetCaps", 11usize, |this| ((**(this as *const *const IDirectDraw_Vtbl)).GetCaps)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayMode(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw", "GetDisplayMode", 12usize, |this| ((**(this as *const *const IDirectDraw_Vtbl)).GetDisplayMode)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFourCCCodes(this: *mut core::ffi::c_void, param0: *mut u32, param1: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw", "GetFourCCCodes", 13usize, |this| ((**(this as *const *const IDirectDraw_Vtbl)).GetFourCCCodes)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGDISurface(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw", "GetGDISurface", 14usize, |this| ((**(this as *const *const IDirectDraw_Vtbl)).GetGDISurface)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMonitorFrequency(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw", "GetMonitorFrequency", 15usize, |this| ((**(this as *const *const IDirectDraw_Vtbl)).GetMonitorFrequency)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScanLine(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw", "GetScanLine", 16usize, |this| ((**(this as *const *const IDirectDraw_Vtbl)).GetScanLine)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVerticalBlankStatus(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw", "GetVerticalBlankStatus", 17usize, |this| ((**(this as *const *const IDirectDraw_Vtbl)).GetVerticalBlankStatus)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, param0: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw", "Initialize", 18usize, |this| ((**(this as *const *const IDirectDraw_Vtbl)).Initialize)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestoreDisplayMode(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw", "RestoreDisplayMode", 19usize, |this| ((**(this as *const *const IDirectDraw_Vtbl)).RestoreDisplayMode)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCooperativeLevel(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw", "SetCooperativeLevel", 20usize, |this| ((**(this as *const *const IDirectDraw_Vtbl)).SetCooperativeLevel)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayMode(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw", "SetDisplayMode", 21usize, |this| ((**(this as *const *const IDirectDraw_Vtbl)).SetDisplayMode)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForVerticalBlank(this: *mut core::ffi::c_void, param0: u32, param1: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw", "WaitForVerticalBlank", 22usize, |this| ((**(this as *const *const IDirectDraw_Vtbl)).WaitForVerticalBlank)(this, param0, param1), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Compact,
+            CreateClipper,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            CreatePalette,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            CreatePalette: 0,
+            CreateSurface,
+            DuplicateSurface,
+            EnumDisplayModes,
+            EnumSurfaces,
+            FlipToGDISurface,
+            GetCaps,
+            GetDisplayMode,
+            GetFourCCCodes,
+            GetGDISurface,
+            GetMonitorFrequency,
+            GetScanLine,
+            GetVerticalBlankStatus,
+            Initialize,
+            RestoreDisplayMode,
+            SetCooperativeLevel,
+            SetDisplayMode,
+            WaitForVerticalBlank,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectDraw as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IDirectDraw2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Compact(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw2", "Compact", 3usize, |this| ((**(this as *const *const IDirectDraw2_Vtbl)).Compact)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateClipper(this: *mut core::ffi::c_void, param0: u32, param1: *mut *mut core::ffi::c_void, param2: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw2", "CreateClipper", 4usize, |this| ((**(this as *const *const IDirectDraw2_Vtbl)).CreateClipper)(this, param0, param1, param2), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn CreatePalette(this: *mut core::ffi::c_void, param0: u32, param1: *mut super::Gdi::PALETTEENTRY, param2: *mut *mut core::ffi::c_void, param3: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw2", "CreatePalette", 5usize, |this| ((**(this as *const *const IDirectDraw2_Vtbl)).CreatePalette)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSurface(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC, param1: *mut *mut core::ffi::c_void, param2: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw2", "CreateSurface", 6usize, |this| ((**(this as *const *const IDirectDraw2_Vtbl)).CreateSurface)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn DuplicateSurface(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw2", "DuplicateSurface", 7usize, |this| ((**(this as *const *const IDirectDraw2_Vtbl)).DuplicateSurface)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumDisplayModes(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDSURFACEDESC, param2: *mut core::ffi::c_void, param3: LPDDENUMMODESCALLBACK) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw2", "EnumDisplayModes", 8usize, |this| ((**(this as *const *const IDirectDraw2_Vtbl)).EnumDisplayModes)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumSurfaces(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDSURFACEDESC, param2: *mut core::ffi::c_void, param3: LPDDENUMSURFACESCALLBACK) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw2", "EnumSurfaces", 9usize, |this| ((**(this as *const *const IDirectDraw2_Vtbl)).EnumSurfaces)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlipToGDISurface(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw2", "FlipToGDISurface", 10usize, |this| ((**(this as *const *const IDirectDraw2_Vtbl)).FlipToGDISurface)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCaps(this: *mut core::ffi::c_void, param0: *mut DDCAPS_DX7, param1: *mut DDCAPS_DX7) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw2", "GetCaps", 11usize, |this| ((**(this as *const *const IDirectDraw2_Vtbl)).GetCaps)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayMode(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw2", "GetDisplayMode", 12usize, |this| ((**(this as *const *const IDirectDraw2_Vtbl)).GetDisplayMode)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFourCCCodes(this: *mut core::ffi::c_void, param0: *mut u32, param1: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw2", "GetFourCCCodes", 13usize, |this| ((**(this as *const *const IDirectDraw2_Vtbl)).GetFourCCCodes)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGDISurface(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw2", "GetGDISurface", 14usize, |this| ((**(this as *const *const IDirectDraw2_Vtbl)).GetGDISurface)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMonitorFrequency(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw2", "GetMonitorFrequency", 15usize, |this| ((**(this as *const *const IDirectDraw2_Vtbl)).GetMonitorFrequency)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScanLine(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw2", "GetScanLine", 16usize, |this| ((**(this as *const *const IDirectDraw2_Vtbl)).GetScanLine)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVerticalBlankStatus(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw2", "GetVerticalBlankStatus", 17usize, |this| ((**(this as *const *const IDirectDraw2_Vtbl)).GetVerticalBlankStatus)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, param0: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw2", "Initialize", 18usize, |this| ((**(this as *const *const IDirectDraw2_Vtbl)).Initialize)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestoreDisplayMode(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw2", "RestoreDisplayMode", 19usize, |this| ((**(this as *const *const IDirectDraw2_Vtbl)).RestoreDisplayMode)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCooperativeLevel(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw2", "SetCooperativeLevel", 20usize, |this| ((**(this as *const *const IDirectDraw2_Vtbl)).SetCooperativeLevel)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayMode(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: u32, param3: u32, param4: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw2", "SetDisplayMode", 21usize, |this| ((**(this as *const *const IDirectDraw2_Vtbl)).SetDisplayMode)(this, param0, param1, param2, param3, param4), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForVerticalBlank(this: *mut core::ffi::c_void, param0: u32, param1: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw2", "WaitForVerticalBlank", 22usize, |this| ((**(this as *const *const IDirectDraw2_Vtbl)).WaitForVerticalBlank)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAvailableVidMem(this: *mut core::ffi::c_void, param0: *mut DDSCAPS, param1: *mut u32, param2: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw2", "GetAvailableVidMem", 23usize, |this| ((**(this as *const *const IDirectDraw2_Vtbl)).GetAvailableVidMem)(this, param0, param1, param2), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Compact,
+            CreateClipper,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            CreatePalette,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            CreatePalette: 0,
+            CreateSurface,
+            DuplicateSurface,
+            EnumDisplayModes,
+            EnumSurfaces,
+            FlipToGDISurface,
+            GetCaps,
+            GetDisplayMode,
+            GetFourCCCodes,
+            GetGDISurface,
+            GetMonitorFrequency,
+            GetScanLine,
+            GetVerticalBlankStatus,
+            Initialize,
+            RestoreDisplayMode,
+            SetCooperativeLevel,
+            SetDisplayMode,
+            WaitForVerticalBlank,
+            GetAvailableVidMem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectDraw2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IDirectDraw4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Compact(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw4", "Compact", 3usize, |this| ((**(this as *const *const IDirectDraw4_Vtbl)).Compact)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateClipper(this: *mut core::ffi::c_void, param0: u32, param1: *mut *mut core::ffi::c_void, param2: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw4", "CreateClipper", 4usize, |this| ((**(this as *const *const IDirectDraw4_Vtbl)).CreateClipper)(this, param0, param1, param2), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn CreatePalette(this: *mut core::ffi::c_void, param0: u32, param1: *mut super::Gdi::PALETTEENTRY, param2: *mut *mut core::ffi::c_void, param3: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw4", "CreatePalette", 5usize, |this| ((**(this as *const *const IDirectDraw4_Vtbl)).CreatePalette)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSurface(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC2, param1: *mut *mut core::ffi::c_void, param2: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw4", "CreateSurface", 6usize, |this| ((**(this as *const *const IDirectDraw4_Vtbl)).CreateSurface)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn DuplicateSurface(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw4", "DuplicateSurface", 7usize, |this| ((**(this as *const *const IDirectDraw4_Vtbl)).DuplicateSurface)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumDisplayModes(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDSURFACEDESC2, param2: *mut core::ffi::c_void, param3: LPDDENUMMODESCALLBACK2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw4", "EnumDisplayModes", 8usize, |this| ((**(this as *const *const IDirectDraw4_Vtbl)).EnumDisplayModes)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumSurfaces(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDSURFACEDESC2, param2: *mut core::ffi::c_void, param3: LPDDENUMSURFACESCALLBACK2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw4", "EnumSurfaces", 9usize, |this| ((**(this as *const *const IDirectDraw4_Vtbl)).EnumSurfaces)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlipToGDISurface(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw4", "FlipToGDISurface", 10usize, |this| ((**(this as *const *const IDirectDraw4_Vtbl)).FlipToGDISurface)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCaps(this: *mut core::ffi::c_void, param0: *mut DDCAPS_DX7, param1: *mut DDCAPS_DX7) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw4", "GetCaps", 11usize, |this| ((**(this as *const *const IDirectDraw4_Vtbl)).GetCaps)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayMode(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw4", "GetDisplayMode", 12usize, |this| ((**(this as *const *const IDirectDraw4_Vtbl)).GetDisplayMode)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFourCCCodes(this: *mut core::ffi::c_void, param0: *mut u32, param1: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw4", "GetFourCCCodes", 13usize, |this| ((**(this as *const *const IDirectDraw4_Vtbl)).GetFourCCCodes)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGDISurface(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw4", "GetGDISurface", 14usize, |this| ((**(this as *const *const IDirectDraw4_Vtbl)).GetGDISurface)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMonitorFrequency(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw4", "GetMonitorFrequency", 15usize, |this| ((**(this as *const *const IDirectDraw4_Vtbl)).GetMonitorFrequency)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScanLine(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw4", "GetScanLine", 16usize, |this| ((**(this as *const *const IDirectDraw4_Vtbl)).GetScanLine)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVerticalBlankStatus(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw4", "GetVerticalBlankStatus", 17usize, |this| ((**(this as *const *const IDirectDraw4_Vtbl)).GetVerticalBlankStatus)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, param0: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw4", "Initialize", 18usize, |this| ((**(this as *const *const IDirectDraw4_Vtbl)).Initialize)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestoreDisplayMode(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw4", "RestoreDisplayMode", 19usize, |this| ((**(this as *const *const IDirectDraw4_Vtbl)).RestoreDisplayMode)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCooperativeLevel(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw4", "SetCooperativeLevel", 20usize, |this| ((**(this as *const *const IDirectDraw4_Vtbl)).SetCooperativeLevel)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayMode(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: u32, param3: u32, param4: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw4", "SetDisplayMode", 21usize, |this| ((**(this as *const *const IDirectDraw4_Vtbl)).SetDisplayMode)(this, param0, param1, param2, param3, param4), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForVerticalBlank(this: *mut core::ffi::c_void, param0: u32, param1: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw4", "WaitForVerticalBlank", 22usize, |this| ((**(this as *const *const IDirectDraw4_Vtbl)).WaitForVerticalBlank)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAvailableVidMem(this: *mut core::ffi::c_void, param0: *mut DDSCAPS2, param1: *mut u32, param2: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw4", "GetAvailableVidMem", 23usize, |this| ((**(this as *const *const IDirectDraw4_Vtbl)).GetAvailableVidMem)(this, param0, param1, param2), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetSurfaceFromDC(this: *mut core::ffi::c_void, param0: super::Gdi::HDC, param1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw4", "GetSurfaceFromDC", 24usize, |this| ((**(this as *const *const IDirectDraw4_Vtbl)).GetSurfaceFromDC)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestoreAllSurfaces(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw4", "RestoreAllSurfaces", 25usize, |this| ((**(this as *const *const IDirectDraw4_Vtbl)).RestoreAllSurfaces)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn TestCooperativeLevel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw4", "TestCooperativeLevel", 26usize, |this| ((**(this as *const *const IDirectDraw4_Vtbl)).TestCooperativeLevel)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceIdentifier(this: *mut core::ffi::c_void, param0: *mut DDDEVICEIDENTIFIER, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw4", "GetDeviceIdentifier", 27usize, |this| ((**(this as *const *const IDirectDraw4_Vtbl)).GetDeviceIdentifier)(this, param0, param1), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Compact,
+            CreateClipper,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            CreatePalette,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            CreatePalette: 0,
+            CreateSurface,
+            DuplicateSurface,
+            EnumDisplayModes,
+            EnumSurfaces,
+            FlipToGDISurface,
+            GetCaps,
+            GetDisplayMode,
+            GetFourCCCodes,
+            GetGDISurface,
+            GetMonitorFrequency,
+            GetScanLine,
+            GetVerticalBlankStatus,
+            Initialize,
+            RestoreDisplayMode,
+            SetCooperativeLevel,
+            SetDisplayMode,
+            WaitForVerticalBlank,
+            GetAvailableVidMem,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetSurfaceFromDC,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetSurfaceFromDC: 0,
+            RestoreAllSurfaces,
+            TestCooperativeLevel,
+            GetDeviceIdentifier,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectDraw4 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IDirectDraw7_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Compact(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw7", "Compact", 3usize, |this| ((**(this as *const *const IDirectDraw7_Vtbl)).Compact)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateClipper(this: *mut core::ffi::c_void, param0: u32, param1: *mut *mut core::ffi::c_void, param2: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw7", "CreateClipper", 4usize, |this| ((**(this as *const *const IDirectDraw7_Vtbl)).CreateClipper)(this, param0, param1, param2), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn CreatePalette(this: *mut core::ffi::c_void, param0: u32, param1: *mut super::Gdi::PALETTEENTRY, param2: *mut *mut core::ffi::c_void, param3: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw7", "CreatePalette", 5usize, |this| ((**(this as *const *const IDirectDraw7_Vtbl)).CreatePalette)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSurface(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC2, param1: *mut *mut core::ffi::c_void, param2: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw7", "CreateSurface", 6usize, |this| ((**(this as *const *const IDirectDraw7_Vtbl)).CreateSurface)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn DuplicateSurface(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw7", "DuplicateSurface", 7usize, |this| ((**(this as *const *const IDirectDraw7_Vtbl)).DuplicateSurface)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumDisplayModes(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDSURFACEDESC2, param2: *mut core::ffi::c_void, param3: LPDDENUMMODESCALLBACK2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw7", "EnumDisplayModes", 8usize, |this| ((**(this as *const *const IDirectDraw7_Vtbl)).EnumDisplayModes)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumSurfaces(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDSURFACEDESC2, param2: *mut core::ffi::c_void, param3: LPDDENUMSURFACESCALLBACK7) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw7", "EnumSurfaces", 9usize, |this| ((**(this as *const *const IDirectDraw7_Vtbl)).EnumSurfaces)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlipToGDISurface(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw7", "FlipToGDISurface", 10usize, |this| ((**(this as *const *const IDirectDraw7_Vtbl)).FlipToGDISurface)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCaps(this: *mut core::ffi::c_void, param0: *mut DDCAPS_DX7, param1: *mut DDCAPS_DX7) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw7", "GetCaps", 11usize, |this| ((**(this as *const *const IDirectDraw7_Vtbl)).GetCaps)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayMode(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw7", "GetDisplayMode", 12usize, |this| ((**(this as *const *const IDirectDraw7_Vtbl)).GetDisplayMode)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFourCCCodes(this: *mut core::ffi::c_void, param0: *mut u32, param1: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw7", "GetFourCCCodes", 13usize, |this| ((**(this as *const *const IDirectDraw7_Vtbl)).GetFourCCCodes)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGDISurface(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw7", "GetGDISurface", 14usize, |this| ((**(this as *const *const IDirectDraw7_Vtbl)).GetGDISurface)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMonitorFrequency(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw7", "GetMonitorFrequency", 15usize, |this| ((**(this as *const *const IDirectDraw7_Vtbl)).GetMonitorFrequency)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScanLine(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw7", "GetScanLine", 16usize, |this| ((**(this as *const *const IDirectDraw7_Vtbl)).GetScanLine)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVerticalBlankStatus(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw7", "GetVerticalBlankStatus", 17usize, |this| ((**(this as *const *const IDirectDraw7_Vtbl)).GetVerticalBlankStatus)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, param0: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw7", "Initialize", 18usize, |this| ((**(this as *const *const IDirectDraw7_Vtbl)).Initialize)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestoreDisplayMode(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw7", "RestoreDisplayMode", 19usize, |this| ((**(this as *const *const IDirectDraw7_Vtbl)).RestoreDisplayMode)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCooperativeLevel(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw7", "SetCooperativeLevel", 20usize, |this| ((**(this as *const *const IDirectDraw7_Vtbl)).SetCooperativeLevel)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayMode(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: u32, param3: u32, param4: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw7", "SetDisplayMode", 21usize, |this| ((**(this as *const *const IDirectDraw7_Vtbl)).SetDisplayMode)(this, param0, param1, param2, param3, param4), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForVerticalBlank(this: *mut core::ffi::c_void, param0: u32, param1: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw7", "WaitForVerticalBlank", 22usize, |this| ((**(this as *const *const IDirectDraw7_Vtbl)).WaitForVerticalBlank)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAvailableVidMem(this: *mut core::ffi::c_void, param0: *mut DDSCAPS2, param1: *mut u32, param2: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw7", "GetAvailableVidMem", 23usize, |this| ((**(this as *const *const IDirectDraw7_Vtbl)).GetAvailableVidMem)(this, param0, param1, param2), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetSurfaceFromDC(this: *mut core::ffi::c_void, param0: super::Gdi::HDC, param1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw7", "GetSurfaceFromDC", 24usize, |this| ((**(this as *const *const IDirectDraw7_Vtbl)).GetSurfaceFromDC)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestoreAllSurfaces(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw7", "RestoreAllSurfaces", 25usize, |this| ((**(this as *const *const IDirectDraw7_Vtbl)).RestoreAllSurfaces)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn TestCooperativeLevel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw7", "TestCooperativeLevel", 26usize, |this| ((**(this as *const *const IDirectDraw7_Vtbl)).TestCooperativeLevel)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceIdentifier(this: *mut core::ffi::c_void, param0: *mut DDDEVICEIDENTIFIER2, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw7", "GetDeviceIdentifier", 27usize, |this| ((**(this as *const *const IDirectDraw7_Vtbl)).GetDeviceIdentifier)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartModeTest(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::SIZE, param1: u32, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw7", "StartModeTest", 28usize, |this| ((**(this as *const *const IDirectDraw7_Vtbl)).StartModeTest)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn EvaluateMode(this: *mut core::ffi::c_void, param0: u32, param1: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDraw7", "EvaluateMode", 29usize, |this| ((**(this as *const *const IDirectDraw7_Vtbl)).EvaluateMode)(this, param0, param1), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Compact,
+            CreateClipper,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            CreatePalette,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            CreatePalette: 0,
+            CreateSurface,
+            DuplicateSurface,
+            EnumDisplayModes,
+            EnumSurfaces,
+            FlipToGDISurface,
+            GetCaps,
+            GetDisplayMode,
+            GetFourCCCodes,
+            GetGDISurface,
+            GetMonitorFrequency,
+            GetScanLine,
+            GetVerticalBlankStatus,
+            Initialize,
+            RestoreDisplayMode,
+            SetCooperativeLevel,
+            SetDisplayMode,
+            WaitForVerticalBlank,
+            GetAvailableVidMem,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetSurfaceFromDC,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetSurfaceFromDC: 0,
+            RestoreAllSurfaces,
+            TestCooperativeLevel,
+            GetDeviceIdentifier,
+            StartModeTest,
+            EvaluateMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectDraw7 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IDirectDrawClipper_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetClipList(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut super::Gdi::RGNDATA, param2: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawClipper", "GetClipList", 3usize, |this| ((**(this as *const *const IDirectDrawClipper_Vtbl)).GetClipList)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHWnd(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawClipper", "GetHWnd", 4usize, |this| ((**(this as *const *const IDirectDrawClipper_Vtbl)).GetHWnd)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawClipper", "Initialize", 5usize, |this| ((**(this as *const *const IDirectDrawClipper_Vtbl)).Initialize)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsClipListChanged(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawClipper", "IsClipListChanged", 6usize, |this| ((**(this as *const *const IDirectDrawClipper_Vtbl)).IsClipListChanged)(this, param0), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn SetClipList(this: *mut core::ffi::c_void, param0: *mut super::Gdi::RGNDATA, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawClipper", "SetClipList", 7usize, |this| ((**(this as *const *const IDirectDrawClipper_Vtbl)).SetClipList)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHWnd(this: *mut core::ffi::c_void, param0: u32, param1: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawClipper", "SetHWnd", 8usize, |this| ((**(this as *const *const IDirectDrawClipper_Vtbl)).SetHWnd)(this, param0, param1), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetClipList,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetClipList: 0,
+            GetHWnd,
+            Initialize,
+            IsClipListChanged,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            SetClipList,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            SetClipList: 0,
+            SetHWnd,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectDrawClipper as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectDrawColorControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetColorControls(this: *mut core::ffi::c_void, param0: *mut DDCOLORCONTROL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawColorControl", "GetColorControls", 3usize, |this| ((**(this as *const *const IDirectDrawColorControl_Vtbl)).GetColorControls)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColorControls(this: *mut core::ffi::c_void, param0: *mut DDCOLORCONTROL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawColorControl", "SetColorControls", 4usize, |this| ((**(this as *const *const IDirectDrawColorControl_Vtbl)).SetColorControls)(this, param0), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetColorControls, SetColorControls }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectDrawColorControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectDrawGammaControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetGammaRamp(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDGAMMARAMP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawGammaControl", "GetGammaRamp", 3usize, |this| ((**(this as *const *const IDirectDrawGammaControl_Vtbl)).GetGammaRamp)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGammaRamp(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDGAMMARAMP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawGammaControl", "SetGammaRamp", 4usize, |this| ((**(this as *const *const IDirectDrawGammaControl_Vtbl)).SetGammaRamp)(this, param0, param1), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetGammaRamp, SetGammaRamp }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectDrawGammaControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectDrawKernel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCaps(this: *mut core::ffi::c_void, param0: *mut DDKERNELCAPS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawKernel", "GetCaps", 3usize, |this| ((**(this as *const *const IDirectDrawKernel_Vtbl)).GetCaps)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKernelHandle(this: *mut core::ffi::c_void, param0: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawKernel", "GetKernelHandle", 4usize, |this| ((**(this as *const *const IDirectDrawKernel_Vtbl)).GetKernelHandle)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseKernelHandle(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawKernel", "ReleaseKernelHandle", 5usize, |this| ((**(this as *const *const IDirectDrawKernel_Vtbl)).ReleaseKernelHandle)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCaps, GetKernelHandle, ReleaseKernelHandle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectDrawKernel as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IDirectDrawPalette_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCaps(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawPalette", "GetCaps", 3usize, |this| ((**(this as *const *const IDirectDrawPalette_Vtbl)).GetCaps)(this, param0), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetEntries(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: u32, param3: *mut super::Gdi::PALETTEENTRY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawPalette", "GetEntries", 4usize, |this| ((**(this as *const *const IDirectDrawPalette_Vtbl)).GetEntries)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: u32, param2: *mut super::Gdi::PALETTEENTRY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawPalette", "Initialize", 5usize, |this| ((**(this as *const *const IDirectDrawPalette_Vtbl)).Initialize)(this, param0, param1, param2), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn SetEntries(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: u32, param3: *mut super::Gdi::PALETTEENTRY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawPalette", "SetEntries", 6usize, |this| ((**(this as *const *const IDirectDrawPalette_Vtbl)).SetEntries)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCaps,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetEntries,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetEntries: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            Initialize,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            Initialize: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            SetEntries,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            SetEntries: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectDrawPalette as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IDirectDrawSurface_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddAttachedSurface(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "AddAttachedSurface", 3usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).AddAttachedSurface)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddOverlayDirtyRect(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "AddOverlayDirtyRect", 4usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).AddOverlayDirtyRect)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Blt(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut core::ffi::c_void, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDBLTFX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "Blt", 5usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).Blt)(this, param0, param1, param2, param3, param4), |result| Some(*result))
+        }
+        unsafe extern "system" fn BltBatch(this: *mut core::ffi::c_void, param0: *mut DDBLTBATCH, param1: u32, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "BltBatch", 6usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).BltBatch)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn BltFast(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: *mut core::ffi::c_void, param3: *mut super::super::Foundation::RECT, param4: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "BltFast", 7usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).BltFast)(this, param0, param1, param2, param3, param4), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAttachedSurface(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "DeleteAttachedSurface", 8usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).DeleteAttachedSurface)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumAttachedSurfaces(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: LPDDENUMSURFACESCALLBACK) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "EnumAttachedSurfaces", 9usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).EnumAttachedSurfaces)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumOverlayZOrders(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void, param2: LPDDENUMSURFACESCALLBACK) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "EnumOverlayZOrders", 10usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).EnumOverlayZOrders)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flip(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "Flip", 11usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).Flip)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttachedSurface(this: *mut core::ffi::c_void, param0: *mut DDSCAPS, param1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "GetAttachedSurface", 12usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).GetAttachedSurface)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBltStatus(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "GetBltStatus", 13usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).GetBltStatus)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCaps(this: *mut core::ffi::c_void, param0: *mut DDSCAPS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "GetCaps", 14usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).GetCaps)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClipper(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "GetClipper", 15usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).GetClipper)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColorKey(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDCOLORKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "GetColorKey", 16usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).GetColorKey)(this, param0, param1), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetDC(this: *mut core::ffi::c_void, param0: *mut super::Gdi::HDC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "GetDC", 17usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).GetDC)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlipStatus(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "GetFlipStatus", 18usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).GetFlipStatus)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOverlayPosition(this: *mut core::ffi::c_void, param0: *mut i32, param1: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "GetOverlayPosition", 19usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).GetOverlayPosition)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPalette(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "GetPalette", 20usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).GetPalette)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPixelFormat(this: *mut core::ffi::c_void, param0: *mut DDPIXELFORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "GetPixelFormat", 21usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).GetPixelFormat)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSurfaceDesc(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "GetSurfaceDesc", 22usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).GetSurfaceDesc)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: *mut DDSURFACEDESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "Initialize", 23usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).Initialize)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLost(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "IsLost", 24usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).IsLost)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Lock(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut DDSURFACEDESC, param2: u32, param3: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "Lock", 25usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).Lock)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn ReleaseDC(this: *mut core::ffi::c_void, param0: super::Gdi::HDC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "ReleaseDC", 26usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).ReleaseDC)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Restore(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "Restore", 27usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).Restore)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClipper(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "SetClipper", 28usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).SetClipper)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColorKey(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDCOLORKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "SetColorKey", 29usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).SetColorKey)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOverlayPosition(this: *mut core::ffi::c_void, param0: i32, param1: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "SetOverlayPosition", 30usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).SetOverlayPosition)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPalette(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "SetPalette", 31usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).SetPalette)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unlock(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "Unlock", 32usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).Unlock)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateOverlay(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut core::ffi::c_void, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDOVERLAYFX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "UpdateOverlay", 33usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).UpdateOverlay)(this, param0, param1, param2, param3, param4), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateOverlayDisplay(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "UpdateOverlayDisplay", 34usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).UpdateOverlayDisplay)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateOverlayZOrder(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface", "UpdateOverlayZOrder", 35usize, |this| ((**(this as *const *const IDirectDrawSurface_Vtbl)).UpdateOverlayZOrder)(this, param0, param1), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddAttachedSurface,
+            AddOverlayDirtyRect,
+            Blt,
+            BltBatch,
+            BltFast,
+            DeleteAttachedSurface,
+            EnumAttachedSurfaces,
+            EnumOverlayZOrders,
+            Flip,
+            GetAttachedSurface,
+            GetBltStatus,
+            GetCaps,
+            GetClipper,
+            GetColorKey,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetDC,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetDC: 0,
+            GetFlipStatus,
+            GetOverlayPosition,
+            GetPalette,
+            GetPixelFormat,
+            GetSurfaceDesc,
+            Initialize,
+            IsLost,
+            Lock,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            ReleaseDC,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            ReleaseDC: 0,
+            Restore,
+            SetClipper,
+            SetColorKey,
+            SetOverlayPosition,
+            SetPalette,
+            Unlock,
+            UpdateOverlay,
+            UpdateOverlayDisplay,
+            UpdateOverlayZOrder,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectDrawSurface as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IDirectDrawSurface2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddAttachedSurface(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "AddAttachedSurface", 3usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).AddAttachedSurface)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddOverlayDirtyRect(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "AddOverlayDirtyRect", 4usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).AddOverlayDirtyRect)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Blt(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut core::ffi::c_void, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDBLTFX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "Blt", 5usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).Blt)(this, param0, param1, param2, param3, param4), |result| Some(*result))
+        }
+        unsafe extern "system" fn BltBatch(this: *mut core::ffi::c_void, param0: *mut DDBLTBATCH, param1: u32, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "BltBatch", 6usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).BltBatch)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn BltFast(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: *mut core::ffi::c_void, param3: *mut super::super::Foundation::RECT, param4: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "BltFast", 7usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).BltFast)(this, param0, param1, param2, param3, param4), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAttachedSurface(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "DeleteAttachedSurface", 8usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).DeleteAttachedSurface)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumAttachedSurfaces(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: LPDDENUMSURFACESCALLBACK) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "EnumAttachedSurfaces", 9usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).EnumAttachedSurfaces)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumOverlayZOrders(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void, param2: LPDDENUMSURFACESCALLBACK) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "EnumOverlayZOrders", 10usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).EnumOverlayZOrders)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flip(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "Flip", 11usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).Flip)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttachedSurface(this: *mut core::ffi::c_void, param0: *mut DDSCAPS, param1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "GetAttachedSurface", 12usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).GetAttachedSurface)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBltStatus(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "GetBltStatus", 13usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).GetBltStatus)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCaps(this: *mut core::ffi::c_void, param0: *mut DDSCAPS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "GetCaps", 14usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).GetCaps)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClipper(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "GetClipper", 15usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).GetClipper)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColorKey(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDCOLORKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "GetColorKey", 16usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).GetColorKey)(this, param0, param1), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetDC(this: *mut core::ffi::c_void, param0: *mut super::Gdi::HDC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "GetDC", 17usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).GetDC)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlipStatus(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "GetFlipStatus", 18usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).GetFlipStatus)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOverlayPosition(this: *mut core::ffi::c_void, param0: *mut i32, param1: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "GetOverlayPosition", 19usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).GetOverlayPosition)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPalette(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "GetPalette", 20usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).GetPalette)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPixelFormat(this: *mut core::ffi::c_void, param0: *mut DDPIXELFORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "GetPixelFormat", 21usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).GetPixelFormat)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSurfaceDesc(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "GetSurfaceDesc", 22usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).GetSurfaceDesc)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: *mut DDSURFACEDESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "Initialize", 23usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).Initialize)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLost(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "IsLost", 24usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).IsLost)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Lock(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut DDSURFACEDESC, param2: u32, param3: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "Lock", 25usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).Lock)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn ReleaseDC(this: *mut core::ffi::c_void, param0: super::Gdi::HDC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "ReleaseDC", 26usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).ReleaseDC)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Restore(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "Restore", 27usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).Restore)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClipper(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "SetClipper", 28usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).SetClipper)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColorKey(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDCOLORKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "SetColorKey", 29usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).SetColorKey)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOverlayPosition(this: *mut core::ffi::c_void, param0: i32, param1: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "SetOverlayPosition", 30usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).SetOverlayPosition)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPalette(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "SetPalette", 31usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).SetPalette)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unlock(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "Unlock", 32usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).Unlock)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateOverlay(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut core::ffi::c_void, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDOVERLAYFX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "UpdateOverlay", 33usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).UpdateOverlay)(this, param0, param1, param2, param3, param4), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateOverlayDisplay(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "UpdateOverlayDisplay", 34usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).UpdateOverlayDisplay)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateOverlayZOrder(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "UpdateOverlayZOrder", 35usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).UpdateOverlayZOrder)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDDInterface(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "GetDDInterface", 36usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).GetDDInterface)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageLock(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "PageLock", 37usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).PageLock)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageUnlock(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface2", "PageUnlock", 38usize, |this| ((**(this as *const *const IDirectDrawSurface2_Vtbl)).PageUnlock)(this, param0), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddAttachedSurface,
+            AddOverlayDirtyRect,
+            Blt,
+            BltBatch,
+            BltFast,
+            DeleteAttachedSurface,
+            EnumAttachedSurfaces,
+            EnumOverlayZOrders,
+            Flip,
+            GetAttachedSurface,
+            GetBltStatus,
+            GetCaps,
+            GetClipper,
+            GetColorKey,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetDC,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetDC: 0,
+            GetFlipStatus,
+            GetOverlayPosition,
+            GetPalette,
+            GetPixelFormat,
+            GetSurfaceDesc,
+            Initialize,
+            IsLost,
+            Lock,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            ReleaseDC,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            ReleaseDC: 0,
+            Restore,
+            SetClipper,
+            SetColorKey,
+            SetOverlayPosition,
+            SetPalette,
+            Unlock,
+            UpdateOverlay,
+            UpdateOverlayDisplay,
+            UpdateOverlayZOrder,
+            GetDDInterface,
+            PageLock,
+            PageUnlock,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectDrawSurface2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IDirectDrawSurface3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddAttachedSurface(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "AddAttachedSurface", 3usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).AddAttachedSurface)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddOverlayDirtyRect(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "AddOverlayDirtyRect", 4usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).AddOverlayDirtyRect)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Blt(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut core::ffi::c_void, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDBLTFX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "Blt", 5usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).Blt)(this, param0, param1, param2, param3, param4), |result| Some(*result))
+        }
+        unsafe extern "system" fn BltBatch(this: *mut core::ffi::c_void, param0: *mut DDBLTBATCH, param1: u32, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "BltBatch", 6usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).BltBatch)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn BltFast(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: *mut core::ffi::c_void, param3: *mut super::super::Foundation::RECT, param4: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "BltFast", 7usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).BltFast)(this, param0, param1, param2, param3, param4), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAttachedSurface(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "DeleteAttachedSurface", 8usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).DeleteAttachedSurface)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumAttachedSurfaces(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: LPDDENUMSURFACESCALLBACK) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "EnumAttachedSurfaces", 9usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).EnumAttachedSurfaces)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumOverlayZOrders(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void, param2: LPDDENUMSURFACESCALLBACK) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "EnumOverlayZOrders", 10usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).EnumOverlayZOrders)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flip(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "Flip", 11usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).Flip)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttachedSurface(this: *mut core::ffi::c_void, param0: *mut DDSCAPS, param1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "GetAttachedSurface", 12usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).GetAttachedSurface)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBltStatus(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "GetBltStatus", 13usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).GetBltStatus)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCaps(this: *mut core::ffi::c_void, param0: *mut DDSCAPS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "GetCaps", 14usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).GetCaps)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClipper(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "GetClipper", 15usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).GetClipper)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColorKey(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDCOLORKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "GetColorKey", 16usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).GetColorKey)(this, param0, param1), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetDC(this: *mut core::ffi::c_void, param0: *mut super::Gdi::HDC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "GetDC", 17usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).GetDC)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlipStatus(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "GetFlipStatus", 18usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).GetFlipStatus)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOverlayPosition(this: *mut core::ffi::c_void, param0: *mut i32, param1: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "GetOverlayPosition", 19usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).GetOverlayPosition)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPalette(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "GetPalette", 20usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).GetPalette)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPixelFormat(this: *mut core::ffi::c_void, param0: *mut DDPIXELFORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "GetPixelFormat", 21usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).GetPixelFormat)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSurfaceDesc(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "GetSurfaceDesc", 22usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).GetSurfaceDesc)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: *mut DDSURFACEDESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "Initialize", 23usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).Initialize)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLost(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "IsLost", 24usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).IsLost)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Lock(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut DDSURFACEDESC, param2: u32, param3: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "Lock", 25usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).Lock)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn ReleaseDC(this: *mut core::ffi::c_void, param0: super::Gdi::HDC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "ReleaseDC", 26usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).ReleaseDC)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Restore(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "Restore", 27usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).Restore)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClipper(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "SetClipper", 28usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).SetClipper)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColorKey(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDCOLORKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "SetColorKey", 29usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).SetColorKey)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOverlayPosition(this: *mut core::ffi::c_void, param0: i32, param1: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "SetOverlayPosition", 30usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).SetOverlayPosition)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPalette(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "SetPalette", 31usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).SetPalette)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unlock(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "Unlock", 32usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).Unlock)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateOverlay(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut core::ffi::c_void, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDOVERLAYFX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "UpdateOverlay", 33usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).UpdateOverlay)(this, param0, param1, param2, param3, param4), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateOverlayDisplay(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "UpdateOverlayDisplay", 34usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).UpdateOverlayDisplay)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateOverlayZOrder(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "UpdateOverlayZOrder", 35usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).UpdateOverlayZOrder)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDDInterface(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "GetDDInterface", 36usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).GetDDInterface)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageLock(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "PageLock", 37usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).PageLock)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageUnlock(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "PageUnlock", 38usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).PageUnlock)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSurfaceDesc(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface3", "SetSurfaceDesc", 39usize, |this| ((**(this as *const *const IDirectDrawSurface3_Vtbl)).SetSurfaceDesc)(this, param0, param1), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddAttachedSurface,
+            AddOverlayDirtyRect,
+            Blt,
+            BltBatch,
+            BltFast,
+            DeleteAttachedSurface,
+            EnumAttachedSurfaces,
+            EnumOverlayZOrders,
+            Flip,
+            GetAttachedSurface,
+            GetBltStatus,
+            GetCaps,
+            GetClipper,
+            GetColorKey,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetDC,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetDC: 0,
+            GetFlipStatus,
+            GetOverlayPosition,
+            GetPalette,
+            GetPixelFormat,
+            GetSurfaceDesc,
+            Initialize,
+            IsLost,
+            Lock,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            ReleaseDC,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            ReleaseDC: 0,
+            Restore,
+            SetClipper,
+            SetColorKey,
+            SetOverlayPosition,
+            SetPalette,
+            Unlock,
+            UpdateOverlay,
+            UpdateOverlayDisplay,
+            UpdateOverlayZOrder,
+            GetDDInterface,
+            PageLock,
+            PageUnlock,
+            SetSurfaceDesc,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectDrawSurface3 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IDirectDrawSurface4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddAttachedSurface(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "AddAttachedSurface", 3usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).AddAttachedSurface)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddOverlayDirtyRect(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "AddOverlayDirtyRect", 4usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).AddOverlayDirtyRect)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Blt(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut core::ffi::c_void, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDBLTFX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "Blt", 5usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).Blt)(this, param0, param1, param2, param3, param4), |result| Some(*result))
+        }
+        unsafe extern "system" fn BltBatch(this: *mut core::ffi::c_void, param0: *mut DDBLTBATCH, param1: u32, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "BltBatch", 6usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).BltBatch)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn BltFast(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: *mut core::ffi::c_void, param3: *mut super::super::Foundation::RECT, param4: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "BltFast", 7usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).BltFast)(this, param0, param1, param2, param3, param4), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAttachedSurface(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "DeleteAttachedSurface", 8usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).DeleteAttachedSurface)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumAttachedSurfaces(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: LPDDENUMSURFACESCALLBACK2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "EnumAttachedSurfaces", 9usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).EnumAttachedSurfaces)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumOverlayZOrders(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void, param2: LPDDENUMSURFACESCALLBACK2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "EnumOverlayZOrders", 10usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).EnumOverlayZOrders)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flip(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "Flip", 11usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).Flip)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttachedSurface(this: *mut core::ffi::c_void, param0: *mut DDSCAPS2, param1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "GetAttachedSurface", 12usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).GetAttachedSurface)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBltStatus(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "GetBltStatus", 13usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).GetBltStatus)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCaps(this: *mut core::ffi::c_void, param0: *mut DDSCAPS2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "GetCaps", 14usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).GetCaps)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClipper(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "GetClipper", 15usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).GetClipper)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColorKey(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDCOLORKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "GetColorKey", 16usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).GetColorKey)(this, param0, param1), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetDC(this: *mut core::ffi::c_void, param0: *mut super::Gdi::HDC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "GetDC", 17usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).GetDC)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlipStatus(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "GetFlipStatus", 18usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).GetFlipStatus)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOverlayPosition(this: *mut core::ffi::c_void, param0: *mut i32, param1: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "GetOverlayPosition", 19usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).GetOverlayPosition)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPalette(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "GetPalette", 20usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).GetPalette)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPixelFormat(this: *mut core::ffi::c_void, param0: *mut DDPIXELFORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "GetPixelFormat", 21usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).GetPixelFormat)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSurfaceDesc(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "GetSurfaceDesc", 22usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).GetSurfaceDesc)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: *mut DDSURFACEDESC2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "Initialize", 23usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).Initialize)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLost(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "IsLost", 24usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).IsLost)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Lock(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut DDSURFACEDESC2, param2: u32, param3: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "Lock", 25usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).Lock)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn ReleaseDC(this: *mut core::ffi::c_void, param0: super::Gdi::HDC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "ReleaseDC", 26usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).ReleaseDC)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Restore(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "Restore", 27usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).Restore)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClipper(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "SetClipper", 28usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).SetClipper)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColorKey(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDCOLORKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "SetColorKey", 29usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).SetColorKey)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOverlayPosition(this: *mut core::ffi::c_void, param0: i32, param1: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "SetOverlayPosition", 30usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).SetOverlayPosition)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPalette(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "SetPalette", 31usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).SetPalette)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unlock(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "Unlock", 32usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).Unlock)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateOverlay(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut core::ffi::c_void, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDOVERLAYFX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "UpdateOverlay", 33usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).UpdateOverlay)(this, param0, param1, param2, param3, param4), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateOverlayDisplay(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "UpdateOverlayDisplay", 34usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).UpdateOverlayDisplay)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateOverlayZOrder(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "UpdateOverlayZOrder", 35usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).UpdateOverlayZOrder)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDDInterface(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "GetDDInterface", 36usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).GetDDInterface)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageLock(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "PageLock", 37usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).PageLock)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageUnlock(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "PageUnlock", 38usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).PageUnlock)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSurfaceDesc(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC2, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "SetSurfaceDesc", 39usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).SetSurfaceDesc)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivateData(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut core::ffi::c_void, param2: u32, param3: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "SetPrivateData", 40usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).SetPrivateData)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrivateData(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut core::ffi::c_void, param2: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "GetPrivateData", 41usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).GetPrivateData)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreePrivateData(this: *mut core::ffi::c_void, param0: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "FreePrivateData", 42usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).FreePrivateData)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUniquenessValue(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "GetUniquenessValue", 43usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).GetUniquenessValue)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeUniquenessValue(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface4", "ChangeUniquenessValue", 44usize, |this| ((**(this as *const *const IDirectDrawSurface4_Vtbl)).ChangeUniquenessValue)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddAttachedSurface,
+            AddOverlayDirtyRect,
+            Blt,
+            BltBatch,
+            BltFast,
+            DeleteAttachedSurface,
+            EnumAttachedSurfaces,
+            EnumOverlayZOrders,
+            Flip,
+            GetAttachedSurface,
+            GetBltStatus,
+            GetCaps,
+            GetClipper,
+            GetColorKey,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetDC,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetDC: 0,
+            GetFlipStatus,
+            GetOverlayPosition,
+            GetPalette,
+            GetPixelFormat,
+            GetSurfaceDesc,
+            Initialize,
+            IsLost,
+            Lock,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            ReleaseDC,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            ReleaseDC: 0,
+            Restore,
+            SetClipper,
+            SetColorKey,
+            SetOverlayPosition,
+            SetPalette,
+            Unlock,
+            UpdateOverlay,
+            UpdateOverlayDisplay,
+            UpdateOverlayZOrder,
+            GetDDInterface,
+            PageLock,
+            PageUnlock,
+            SetSurfaceDesc,
+            SetPrivateData,
+            GetPrivateData,
+            FreePrivateData,
+            GetUniquenessValue,
+            ChangeUniquenessValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectDrawSurface4 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IDirectDrawSurface7_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddAttachedSurface(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "AddAttachedSurface", 3usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).AddAttachedSurface)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddOverlayDirtyRect(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "AddOverlayDirtyRect", 4usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).AddOverlayDirtyRect)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Blt(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut core::ffi::c_void, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDBLTFX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "Blt", 5usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).Blt)(this, param0, param1, param2, param3, param4), |result| Some(*result))
+        }
+        unsafe extern "system" fn BltBatch(this: *mut core::ffi::c_void, param0: *mut DDBLTBATCH, param1: u32, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "BltBatch", 6usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).BltBatch)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn BltFast(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: *mut core::ffi::c_void, param3: *mut super::super::Foundation::RECT, param4: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "BltFast", 7usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).BltFast)(this, param0, param1, param2, param3, param4), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAttachedSurface(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "DeleteAttachedSurface", 8usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).DeleteAttachedSurface)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumAttachedSurfaces(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: LPDDENUMSURFACESCALLBACK7) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "EnumAttachedSurfaces", 9usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).EnumAttachedSurfaces)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumOverlayZOrders(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void, param2: LPDDENUMSURFACESCALLBACK7) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "EnumOverlayZOrders", 10usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).EnumOverlayZOrders)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flip(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "Flip", 11usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).Flip)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttachedSurface(this: *mut core::ffi::c_void, param0: *mut DDSCAPS2, param1: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "GetAttachedSurface", 12usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).GetAttachedSurface)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBltStatus(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "GetBltStatus", 13usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).GetBltStatus)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCaps(this: *mut core::ffi::c_void, param0: *mut DDSCAPS2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "GetCaps", 14usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).GetCaps)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClipper(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "GetClipper", 15usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).GetClipper)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColorKey(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDCOLORKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "GetColorKey", 16usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).GetColorKey)(this, param0, param1), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetDC(this: *mut core::ffi::c_void, param0: *mut super::Gdi::HDC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "GetDC", 17usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).GetDC)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlipStatus(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "GetFlipStatus", 18usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).GetFlipStatus)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOverlayPosition(this: *mut core::ffi::c_void, param0: *mut i32, param1: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "GetOverlayPosition", 19usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).GetOverlayPosition)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPalette(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "GetPalette", 20usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).GetPalette)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPixelFormat(this: *mut core::ffi::c_void, param0: *mut DDPIXELFORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "GetPixelFormat", 21usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).GetPixelFormat)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSurfaceDesc(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "GetSurfaceDesc", 22usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).GetSurfaceDesc)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: *mut DDSURFACEDESC2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "Initialize", 23usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).Initialize)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLost(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "IsLost", 24usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).IsLost)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Lock(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut DDSURFACEDESC2, param2: u32, param3: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "Lock", 25usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).Lock)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn ReleaseDC(this: *mut core::ffi::c_void, param0: super::Gdi::HDC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "ReleaseDC", 26usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).ReleaseDC)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Restore(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "Restore", 27usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).Restore)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClipper(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "SetClipper", 28usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).SetClipper)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColorKey(this: *mut core::ffi::c_void, param0: u32, param1: *mut DDCOLORKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "SetColorKey", 29usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).SetColorKey)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOverlayPosition(this: *mut core::ffi::c_void, param0: i32, param1: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "SetOverlayPosition", 30usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).SetOverlayPosition)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPalette(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "SetPalette", 31usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).SetPalette)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unlock(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "Unlock", 32usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).Unlock)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateOverlay(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::RECT, param1: *mut core::ffi::c_void, param2: *mut super::super::Foundation::RECT, param3: u32, param4: *mut DDOVERLAYFX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "UpdateOverlay", 33usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).UpdateOverlay)(this, param0, param1, param2, param3, param4), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateOverlayDisplay(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "UpdateOverlayDisplay", 34usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).UpdateOverlayDisplay)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateOverlayZOrder(this: *mut core::ffi::c_void, param0: u32, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "UpdateOverlayZOrder", 35usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).UpdateOverlayZOrder)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDDInterface(this: *mut core::ffi::c_void, param0: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "GetDDInterface", 36usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).GetDDInterface)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageLock(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "PageLock", 37usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).PageLock)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn PageUnlock(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "PageUnlock", 38usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).PageUnlock)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSurfaceDesc(this: *mut core::ffi::c_void, param0: *mut DDSURFACEDESC2, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "SetSurfaceDesc", 39usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).SetSurfaceDesc)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivateData(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut core::ffi::c_void, param2: u32, param3: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "SetPrivateData", 40usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).SetPrivateData)(this, param0, param1, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrivateData(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: *mut core::ffi::c_void, param2: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "GetPrivateData", 41usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).GetPrivateData)(this, param0, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreePrivateData(this: *mut core::ffi::c_void, param0: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "FreePrivateData", 42usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).FreePrivateData)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUniquenessValue(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "GetUniquenessValue", 43usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).GetUniquenessValue)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeUniquenessValue(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "ChangeUniquenessValue", 44usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).ChangeUniquenessValue)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPriority(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "SetPriority", 45usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).SetPriority)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPriority(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "GetPriority", 46usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).GetPriority)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLOD(this: *mut core::ffi::c_void, param0: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "SetLOD", 47usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).SetLOD)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLOD(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurface7", "GetLOD", 48usize, |this| ((**(this as *const *const IDirectDrawSurface7_Vtbl)).GetLOD)(this, param0), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddAttachedSurface,
+            AddOverlayDirtyRect,
+            Blt,
+            BltBatch,
+            BltFast,
+            DeleteAttachedSurface,
+            EnumAttachedSurfaces,
+            EnumOverlayZOrders,
+            Flip,
+            GetAttachedSurface,
+            GetBltStatus,
+            GetCaps,
+            GetClipper,
+            GetColorKey,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetDC,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetDC: 0,
+            GetFlipStatus,
+            GetOverlayPosition,
+            GetPalette,
+            GetPixelFormat,
+            GetSurfaceDesc,
+            Initialize,
+            IsLost,
+            Lock,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            ReleaseDC,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            ReleaseDC: 0,
+            Restore,
+            SetClipper,
+            SetColorKey,
+            SetOverlayPosition,
+            SetPalette,
+            Unlock,
+            UpdateOverlay,
+            UpdateOverlayDisplay,
+            UpdateOverlayZOrder,
+            GetDDInterface,
+            PageLock,
+            PageUnlock,
+            SetSurfaceDesc,
+            SetPrivateData,
+            GetPrivateData,
+            FreePrivateData,
+            GetUniquenessValue,
+            ChangeUniquenessValue,
+            SetPriority,
+            GetPriority,
+            SetLOD,
+            GetLOD,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectDrawSurface7 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectDrawSurfaceKernel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetKernelHandle(this: *mut core::ffi::c_void, param0: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurfaceKernel", "GetKernelHandle", 3usize, |this| ((**(this as *const *const IDirectDrawSurfaceKernel_Vtbl)).GetKernelHandle)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseKernelHandle(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawSurfaceKernel", "ReleaseKernelHandle", 4usize, |this| ((**(this as *const *const IDirectDrawSurfaceKernel_Vtbl)).ReleaseKernelHandle)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetKernelHandle, ReleaseKernelHandle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectDrawSurfaceKernel as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectDrawVideoPort_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Flip(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideoPort", "Flip", 3usize, |this| ((**(this as *const *const IDirectDrawVideoPort_Vtbl)).Flip)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBandwidthInfo(this: *mut core::ffi::c_void, param0: *mut DDPIXELFORMAT, param1: u32, param2: u32, param3: u32, param4: *mut DDVIDEOPORTBANDWIDTH) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideoPort", "GetBandwidthInfo", 4usize, |this| ((**(this as *const *const IDirectDrawVideoPort_Vtbl)).GetBandwidthInfo)(this, param0, param1, param2, param3, param4), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColorControls(this: *mut core::ffi::c_void, param0: *mut DDCOLORCONTROL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideoPort", "GetColorControls", 5usize, |this| ((**(this as *const *const IDirectDrawVideoPort_Vtbl)).GetColorControls)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputFormats(this: *mut core::ffi::c_void, lpnumformats: *mut u32, param1: *mut DDPIXELFORMAT, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideoPort", "GetInputFormats", 6usize, |this| ((**(this as *const *const IDirectDrawVideoPort_Vtbl)).GetInputFormats)(this, lpnumformats, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputFormats(this: *mut core::ffi::c_void, param0: *mut DDPIXELFORMAT, lpnumformats: *mut u32, param2: *mut DDPIXELFORMAT, param3: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideoPort", "GetOutputFormats", 7usize, |this| ((**(this as *const *const IDirectDrawVideoPort_Vtbl)).GetOutputFormats)(this, param0, lpnumformats, param2, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFieldPolarity(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideoPort", "GetFieldPolarity", 8usize, |this| ((**(this as *const *const IDirectDrawVideoPort_Vtbl)).GetFieldPolarity)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoLine(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideoPort", "GetVideoLine", 9usize, |this| ((**(this as *const *const IDirectDrawVideoPort_Vtbl)).GetVideoLine)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoSignalStatus(this: *mut core::ffi::c_void, param0: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideoPort", "GetVideoSignalStatus", 10usize, |this| ((**(this as *const *const IDirectDrawVideoPort_Vtbl)).GetVideoSignalStatus)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColorControls(this: *mut core::ffi::c_void, param0: *mut DDCOLORCONTROL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideoPort", "SetColorControls", 11usize, |this| ((**(this as *const *const IDirectDrawVideoPort_Vtbl)).SetColorControls)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTargetSurface(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideoPort", "SetTargetSurface", 12usize, |this| ((**(this as *const *const IDirectDrawVideoPort_Vtbl)).SetTargetSurface)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartVideo(this: *mut core::ffi::c_void, param0: *mut DDVIDEOPORTINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideoPort", "StartVideo", 13usize, |this| ((**(this as *const *const IDirectDrawVideoPort_Vtbl)).StartVideo)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopVideo(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideoPort", "StopVideo", 14usize, |this| ((**(this as *const *const IDirectDrawVideoPort_Vtbl)).StopVideo)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateVideo(this: *mut core::ffi::c_void, param0: *mut DDVIDEOPORTINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideoPort", "UpdateVideo", 15usize, |this| ((**(this as *const *const IDirectDrawVideoPort_Vtbl)).UpdateVideo)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForSync(this: *mut core::ffi::c_void, param0: u32, param1: u32, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideoPort", "WaitForSync", 16usize, |this| ((**(this as *const *const IDirectDrawVideoPort_Vtbl)).WaitForSync)(this, param0, param1, param2), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Flip,
+            GetBandwidthInfo,
+            GetColorControls,
+            GetInputFormats,
+            GetOutputFormats,
+            GetFieldPolarity,
+            GetVideoLine,
+            GetVideoSignalStatus,
+            SetColorControls,
+            SetTargetSurface,
+            StartVideo,
+            StopVideo,
+            UpdateVideo,
+            WaitForSync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectDrawVideoPort as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectDrawVideoPortNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AcquireNotification(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::HANDLE, param1: *mut DDVIDEOPORTNOTIFY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideoPortNotify", "AcquireNotification", 3usize, |this| ((**(this as *const *const IDirectDrawVideoPortNotify_Vtbl)).AcquireNotification)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseNotification(this: *mut core::ffi::c_void, param0: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideoPortNotify", "ReleaseNotification", 4usize, |this| ((**(this as *const *const IDirectDrawVideoPortNotify_Vtbl)).ReleaseNotification)(this, param0), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AcquireNotification, ReleaseNotification }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectDrawVideoPortNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
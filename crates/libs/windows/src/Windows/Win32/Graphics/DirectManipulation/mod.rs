@@ -775,6 +775,7 @@ pub struct DIRECTMANIPULATION_AUTOSCROLL_CONFIGURATION(pub i32);
 impl windows_core::TypeKind for DIRECTMANIPULATION_AUTOSCROLL_CONFIGURATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DIRECTMANIPULATION_AUTOSCROLL_CONFIGURATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DIRECTMANIPULATION_AUTOSCROLL_CONFIGURATION").field(&self.0).finish()
@@ -786,6 +787,7 @@ pub struct DIRECTMANIPULATION_CONFIGURATION(pub i32);
 impl windows_core::TypeKind for DIRECTMANIPULATION_CONFIGURATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DIRECTMANIPULATION_CONFIGURATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DIRECTMANIPULATION_CONFIGURATION").field(&self.0).finish()
@@ -830,6 +832,7 @@ pub struct DIRECTMANIPULATION_DRAG_DROP_CONFIGURATION(pub i32);
 impl windows_core::TypeKind for DIRECTMANIPULATION_DRAG_DROP_CONFIGURATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DIRECTMANIPULATION_DRAG_DROP_CONFIGURATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DIRECTMANIPULATION_DRAG_DROP_CONFIGURATION").field(&self.0).finish()
@@ -874,6 +877,7 @@ pub struct DIRECTMANIPULATION_DRAG_DROP_STATUS(pub i32);
 impl windows_core::TypeKind for DIRECTMANIPULATION_DRAG_DROP_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DIRECTMANIPULATION_DRAG_DROP_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DIRECTMANIPULATION_DRAG_DROP_STATUS").field(&self.0).finish()
@@ -885,6 +889,7 @@ pub struct DIRECTMANIPULATION_GESTURE_CONFIGURATION(pub i32);
 impl windows_core::TypeKind for DIRECTMANIPULATION_GESTURE_CONFIGURATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DIRECTMANIPULATION_GESTURE_CONFIGURATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DIRECTMANIPULATION_GESTURE_CONFIGURATION").field(&self.0).finish()
@@ -929,6 +934,7 @@ pub struct DIRECTMANIPULATION_HITTEST_TYPE(pub i32);
 impl windows_core::TypeKind for DIRECTMANIPULATION_HITTEST_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DIRECTMANIPULATION_HITTEST_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DIRECTMANIPULATION_HITTEST_TYPE").field(&self.0).finish()
@@ -973,6 +979,7 @@ pub struct DIRECTMANIPULATION_HORIZONTALALIGNMENT(pub i32);
 impl windows_core::TypeKind for DIRECTMANIPULATION_HORIZONTALALIGNMENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DIRECTMANIPULATION_HORIZONTALALIGNMENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DIRECTMANIPULATION_HORIZONTALALIGNMENT").field(&self.0).finish()
@@ -1017,6 +1024,7 @@ pub struct DIRECTMANIPULATION_INPUT_MODE(pub i32);
 impl windows_core::TypeKind for DIRECTMANIPULATION_INPUT_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DIRECTMANIPULATION_INPUT_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DIRECTMANIPULATION_INPUT_MODE").field(&self.0).finish()
@@ -1028,6 +1036,7 @@ pub struct DIRECTMANIPULATION_INTERACTION_TYPE(pub i32);
 impl windows_core::TypeKind for DIRECTMANIPULATION_INTERACTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DIRECTMANIPULATION_INTERACTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DIRECTMANIPULATION_INTERACTION_TYPE").field(&self.0).finish()
@@ -1039,6 +1048,7 @@ pub struct DIRECTMANIPULATION_MOTION_TYPES(pub i32);
 impl windows_core::TypeKind for DIRECTMANIPULATION_MOTION_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DIRECTMANIPULATION_MOTION_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DIRECTMANIPULATION_MOTION_TYPES").field(&self.0).finish()
@@ -1083,6 +1093,7 @@ pub struct DIRECTMANIPULATION_SNAPPOINT_COORDINATE(pub i32);
 impl windows_core::TypeKind for DIRECTMANIPULATION_SNAPPOINT_COORDINATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DIRECTMANIPULATION_SNAPPOINT_COORDINATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DIRECTMANIPULATION_SNAPPOINT_COORDINATE").field(&self.0).finish()
@@ -1127,6 +1138,7 @@ pub struct DIRECTMANIPULATION_SNAPPOINT_TYPE(pub i32);
 impl windows_core::TypeKind for DIRECTMANIPULATION_SNAPPOINT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DIRECTMANIPULATION_SNAPPOINT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DIRECTMANIPULATION_SNAPPOINT_TYPE").field(&self.0).finish()
@@ -1138,6 +1150,7 @@ pub struct DIRECTMANIPULATION_STATUS(pub i32);
 impl windows_core::TypeKind for DIRECTMANIPULATION_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DIRECTMANIPULATION_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DIRECTMANIPULATION_STATUS").field(&self.0).finish()
@@ -1149,6 +1162,7 @@ pub struct DIRECTMANIPULATION_VERTICALALIGNMENT(pub i32);
 impl windows_core::TypeKind for DIRECTMANIPULATION_VERTICALALIGNMENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DIRECTMANIPULATION_VERTICALALIGNMENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DIRECTMANIPULATION_VERTICALALIGNMENT").field(&self.0).finish()
@@ -1193,6 +1207,7 @@ pub struct DIRECTMANIPULATION_VIEWPORT_OPTIONS(pub i32);
 impl windows_core::TypeKind for DIRECTMANIPULATION_VIEWPORT_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DIRECTMANIPULATION_VIEWPORT_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DIRECTMANIPULATION_VIEWPORT_OPTIONS").field(&self.0).finish()
@@ -1239,3 +1254,5 @@ pub const DirectManipulationUpdateManager: windows_core::GUID = windows_core::GU
 pub const DirectManipulationViewport: windows_core::GUID = windows_core::GUID::from_u128(0x34e211b6_3650_4f75_8334_fa359598e1c5);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,463 @@
+impl windows_core::imp::ProxyVtable for IDirectManipulationAutoScrollBehavior_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetConfiguration(this: *mut core::ffi::c_void, motiontypes: DIRECTMANIPULATION_MOTION_TYPES, scrollmotion: DIRECTMANIPULATION_AUTOSCROLL_CONFIGURATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationAutoScrollBehavior", "SetConfiguration", 3usize, |this| ((**(this as *const *const IDirectManipulationAutoScrollBehavior_Vtbl)).SetConfiguration)(this, motiontypes, scrollmotion), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetConfiguration }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectManipulationAutoScrollBehavior as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectManipulationCompositor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddContent(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void, device: *mut core::ffi::c_void, parentvisual: *mut core::ffi::c_void, childvisual: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationCompositor", "AddContent", 3usize, |this| ((**(this as *const *const IDirectManipulationCompositor_Vtbl)).AddContent)(this, content, device, parentvisual, childvisual), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveContent(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationCompositor", "RemoveContent", 4usize, |this| ((**(this as *const *const IDirectManipulationCompositor_Vtbl)).RemoveContent)(this, content), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUpdateManager(this: *mut core::ffi::c_void, updatemanager: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationCompositor", "SetUpdateManager", 5usize, |this| ((**(this as *const *const IDirectManipulationCompositor_Vtbl)).SetUpdateManager)(this, updatemanager), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flush(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationCompositor", "Flush", 6usize, |this| ((**(this as *const *const IDirectManipulationCompositor_Vtbl)).Flush)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddContent, RemoveContent, SetUpdateManager, Flush }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectManipulationCompositor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectManipulationCompositor2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddContentWithCrossProcessChaining(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void, device: *mut core::ffi::c_void, parentvisual: *mut core::ffi::c_void, childvisual: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationCompositor2", "AddContentWithCrossProcessChaining", 7usize, |this| ((**(this as *const *const IDirectManipulationCompositor2_Vtbl)).AddContentWithCrossProcessChaining)(this, content, device, parentvisual, childvisual), |result| Some(*result))
+        }
+        Self { base__: <IDirectManipulationCompositor_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddContentWithCrossProcessChaining }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectManipulationCompositor2 as windows_core::Interface>::IID || <IDirectManipulationCompositor_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectManipulationContent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetContentRect(this: *mut core::ffi::c_void, contentsize: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationContent", "GetContentRect", 3usize, |this| ((**(this as *const *const IDirectManipulationContent_Vtbl)).GetContentRect)(this, contentsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContentRect(this: *mut core::ffi::c_void, contentsize: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationContent", "SetContentRect", 4usize, |this| ((**(this as *const *const IDirectManipulationContent_Vtbl)).SetContentRect)(this, contentsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetViewport(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, object: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationContent", "GetViewport", 5usize, |this| ((**(this as *const *const IDirectManipulationContent_Vtbl)).GetViewport)(this, riid, object), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, object: *mut *mut core::ffi::c_void, id: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationContent", "GetTag", 6usize, |this| ((**(this as *const *const IDirectManipulationContent_Vtbl)).GetTag)(this, riid, object, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTag(this: *mut core::ffi::c_void, object: *mut core::ffi::c_void, id: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationContent", "SetTag", 7usize, |this| ((**(this as *const *const IDirectManipulationContent_Vtbl)).SetTag)(this, object, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputTransform(this: *mut core::ffi::c_void, matrix: *mut f32, pointcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationContent", "GetOutputTransform", 8usize, |this| ((**(this as *const *const IDirectManipulationContent_Vtbl)).GetOutputTransform)(this, matrix, pointcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContentTransform(this: *mut core::ffi::c_void, matrix: *mut f32, pointcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationContent", "GetContentTransform", 9usize, |this| ((**(this as *const *const IDirectManipulationContent_Vtbl)).GetContentTransform)(this, matrix, pointcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncContentTransform(this: *mut core::ffi::c_void, matrix: *const f32, pointcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationContent", "SyncContentTransform", 10usize, |this| ((**(this as *const *const IDirectManipulationContent_Vtbl)).SyncContentTransform)(this, matrix, pointcount), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetContentRect,
+            SetContentRect,
+            GetViewport,
+            GetTag,
+            SetTag,
+            GetOutputTransform,
+            GetContentTransform,
+            SyncContentTransform,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectManipulationContent as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectManipulationDeferContactService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeferContact(this: *mut core::ffi::c_void, pointerid: u32, timeout: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationDeferContactService", "DeferContact", 3usize, |this| ((**(this as *const *const IDirectManipulationDeferContactService_Vtbl)).DeferContact)(this, pointerid, timeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelContact(this: *mut core::ffi::c_void, pointerid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationDeferContactService", "CancelContact", 4usize, |this| ((**(this as *const *const IDirectManipulationDeferContactService_Vtbl)).CancelContact)(this, pointerid), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelDeferral(this: *mut core::ffi::c_void, pointerid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationDeferContactService", "CancelDeferral", 5usize, |this| ((**(this as *const *const IDirectManipulationDeferContactService_Vtbl)).CancelDeferral)(this, pointerid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeferContact, CancelContact, CancelDeferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectManipulationDeferContactService as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectManipulationDragDropBehavior_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetConfiguration(this: *mut core::ffi::c_void, configuration: DIRECTMANIPULATION_DRAG_DROP_CONFIGURATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationDragDropBehavior", "SetConfiguration", 3usize, |this| ((**(this as *const *const IDirectManipulationDragDropBehavior_Vtbl)).SetConfiguration)(this, configuration), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, status: *mut DIRECTMANIPULATION_DRAG_DROP_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationDragDropBehavior", "GetStatus", 4usize, |this| ((**(this as *const *const IDirectManipulationDragDropBehavior_Vtbl)).GetStatus)(this, status), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetConfiguration, GetStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectManipulationDragDropBehavior as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectManipulationDragDropEventHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnDragDropStatusChange(this: *mut core::ffi::c_void, viewport: *mut core::ffi::c_void, current: DIRECTMANIPULATION_DRAG_DROP_STATUS, previous: DIRECTMANIPULATION_DRAG_DROP_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationDragDropEventHandler", "OnDragDropStatusChange", 3usize, |this| ((**(this as *const *const IDirectManipulationDragDropEventHandler_Vtbl)).OnDragDropStatusChange)(this, viewport, current, previous), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnDragDropStatusChange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectManipulationDragDropEventHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectManipulationFrameInfoProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNextFrameInfo(this: *mut core::ffi::c_void, time: *mut u64, processtime: *mut u64, compositiontime: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationFrameInfoProvider", "GetNextFrameInfo", 3usize, |this| ((**(this as *const *const IDirectManipulationFrameInfoProvider_Vtbl)).GetNextFrameInfo)(this, time, processtime, compositiontime), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetNextFrameInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectManipulationFrameInfoProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectManipulationInteractionEventHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnInteraction(this: *mut core::ffi::c_void, viewport: *mut core::ffi::c_void, interaction: DIRECTMANIPULATION_INTERACTION_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationInteractionEventHandler", "OnInteraction", 3usize, |this| ((**(this as *const *const IDirectManipulationInteractionEventHandler_Vtbl)).OnInteraction)(this, viewport, interaction), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnInteraction }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectManipulationInteractionEventHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl windows_core::imp::ProxyVtable for IDirectManipulationManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Activate(this: *mut core::ffi::c_void, window: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationManager", "Activate", 3usize, |this| ((**(this as *const *const IDirectManipulationManager_Vtbl)).Activate)(this, window), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deactivate(this: *mut core::ffi::c_void, window: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationManager", "Deactivate", 4usize, |this| ((**(this as *const *const IDirectManipulationManager_Vtbl)).Deactivate)(this, window), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterHitTestTarget(this: *mut core::ffi::c_void, window: super::super::Foundation::HWND, hittestwindow: super::super::Foundation::HWND, r#type: DIRECTMANIPULATION_HITTEST_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationManager", "RegisterHitTestTarget", 5usize, |this| ((**(this as *const *const IDirectManipulationManager_Vtbl)).RegisterHitTestTarget)(this, window, hittestwindow, r#type), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn ProcessInput(this: *mut core::ffi::c_void, message: *const super::super::UI::WindowsAndMessaging::MSG, handled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationManager", "ProcessInput", 6usize, |this| ((**(this as *const *const IDirectManipulationManager_Vtbl)).ProcessInput)(this, message, handled), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUpdateManager(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, object: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationManager", "GetUpdateManager", 7usize, |this| ((**(this as *const *const IDirectManipulationManager_Vtbl)).GetUpdateManager)(this, riid, object), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateViewport(this: *mut core::ffi::c_void, frameinfo: *mut core::ffi::c_void, window: super::super::Foundation::HWND, riid: *const windows_core::GUID, object: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationManager", "CreateViewport", 8usize, |this| ((**(this as *const *const IDirectManipulationManager_Vtbl)).CreateViewport)(this, frameinfo, window, riid, object), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateContent(this: *mut core::ffi::c_void, frameinfo: *mut core::ffi::c_void, clsid: *const windows_core::GUID, riid: *const windows_core::GUID, object: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationManager", "CreateContent", 9usize, |this| ((**(this as *const *const IDirectManipulationManager_Vtbl)).CreateContent)(this, frameinfo, clsid, riid, object), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Activate,
+            Deactivate,
+            RegisterHitTestTarget,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            ProcessInput,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            ProcessInput: 0,
+            GetUpdateManager,
+            CreateViewport,
+            CreateContent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectManipulationManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl windows_core::imp::ProxyVtable for IDirectManipulationManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateBehavior(this: *mut core::ffi::c_void, clsid: *const windows_core::GUID, riid: *const windows_core::GUID, object: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationManager2", "CreateBehavior", 10usize, |this| ((**(this as *const *const IDirectManipulationManager2_Vtbl)).CreateBehavior)(this, clsid, riid, object), |result| Some(*result))
+        }
+        Self { base__: <IDirectManipulationManager_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateBehavior }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectManipulationManager2 as windows_core::Interface>::IID || <IDirectManipulationManager_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl windows_core::imp::ProxyVtable for IDirectManipulationManager3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetService(this: *mut core::ffi::c_void, clsid: *const windows_core::GUID, riid: *const windows_core::GUID, object: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationManager3", "GetService", 11usize, |this| ((**(this as *const *const IDirectManipulationManager3_Vtbl)).GetService)(this, clsid, riid, object), |result| Some(*result))
+        }
+        Self { base__: <IDirectManipulationManager2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetService }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectManipulationManager3 as windows_core::Interface>::IID || <IDirectManipulationManager2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectManipulationPrimaryContent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSnapInterval(this: *mut core::ffi::c_void, motion: DIRECTMANIPULATION_MOTION_TYPES, interval: f32, offset: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationPrimaryContent", "SetSnapInterval", 3usize, |this| ((**(this as *const *const IDirectManipulationPrimaryContent_Vtbl)).SetSnapInterval)(this, motion, interval, offset), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSnapPoints(this: *mut core::ffi::c_void, motion: DIRECTMANIPULATION_MOTION_TYPES, points: *const f32, pointcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationPrimaryContent", "SetSnapPoints", 4usize, |this| ((**(this as *const *const IDirectManipulationPrimaryContent_Vtbl)).SetSnapPoints)(this, motion, points, pointcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSnapType(this: *mut core::ffi::c_void, motion: DIRECTMANIPULATION_MOTION_TYPES, r#type: DIRECTMANIPULATION_SNAPPOINT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationPrimaryContent", "SetSnapType", 5usize, |this| ((**(this as *const *const IDirectManipulationPrimaryContent_Vtbl)).SetSnapType)(this, motion, r#type), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSnapCoordinate(this: *mut core::ffi::c_void, motion: DIRECTMANIPULATION_MOTION_TYPES, coordinate: DIRECTMANIPULATION_SNAPPOINT_COORDINATE, origin: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationPrimaryContent", "SetSnapCoordinate", 6usize, |this| ((**(this as *const *const IDirectManipulationPrimaryContent_Vtbl)).SetSnapCoordinate)(this, motion, coordinate, origin), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetZoomBoundaries(this: *mut core::ffi::c_void, zoomminimum: f32, zoommaximum: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationPrimaryContent", "SetZoomBoundaries", 7usize, |this| ((**(this as *const *const IDirectManipulationPrimaryContent_Vtbl)).SetZoomBoundaries)(this, zoomminimum, zoommaximum), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHorizontalAlignment(this: *mut core::ffi::c_void, alignment: DIRECTMANIPULATION_HORIZONTALALIGNMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationPrimaryContent", "SetHorizontalAlignment", 8usize, |this| ((**(this as *const *const IDirectManipulationPrimaryContent_Vtbl)).SetHorizontalAlignment)(this, alignment), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVerticalAlignment(this: *mut core::ffi::c_void, alignment: DIRECTMANIPULATION_VERTICALALIGNMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationPrimaryContent", "SetVerticalAlignment", 9usize, |this| ((**(this as *const *const IDirectManipulationPrimaryContent_Vtbl)).SetVerticalAlignment)(this, alignment), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInertiaEndTransform(this: *mut core::ffi::c_void, matrix: *mut f32, pointcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationPrimaryContent", "GetInertiaEndTransform", 10usize, |this| ((**(this as *const *const IDirectManipulationPrimaryContent_Vtbl)).GetInertiaEndTransform)(this, matrix, pointcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCenterPoint(this: *mut core::ffi::c_void, centerx: *mut f32, centery: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationPrimaryContent", "GetCenterPoint", 11usize, |this| ((**(this as *const *const IDirectManipulationPrimaryContent_Vtbl)).GetCenterPoint)(this, centerx, centery), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetSnapInterval,
+            SetSnapPoints,
+            SetSnapType,
+            SetSnapCoordinate,
+            SetZoomBoundaries,
+            SetHorizontalAlignment,
+            SetVerticalAlignment,
+            GetInertiaEndTransform,
+            GetCenterPoint,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectManipulationPrimaryContent as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectManipulationUpdateHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationUpdateHandler", "Update", 3usize, |this| ((**(this as *const *const IDirectManipulationUpdateHandler_Vtbl)).Update)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Update }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectManipulationUpdateHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectManipulationUpdateManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterWaitHandleCallback(this: *mut core::ffi::c_void, handle: super::super::Foundation::HANDLE, eventhandler: *mut core::ffi::c_void, cookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationUpdateManager", "RegisterWaitHandleCallback", 3usize, |this| ((**(this as *const *const IDirectManipulationUpdateManager_Vtbl)).RegisterWaitHandleCallback)(this, handle, eventhandler, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterWaitHandleCallback(this: *mut core::ffi::c_void, cookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationUpdateManager", "UnregisterWaitHandleCallback", 4usize, |this| ((**(this as *const *const IDirectManipulationUpdateManager_Vtbl)).UnregisterWaitHandleCallback)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void, frameinfo: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationUpdateManager", "Update", 5usize, |this| ((**(this as *const *const IDirectManipulationUpdateManager_Vtbl)).Update)(this, frameinfo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterWaitHandleCallback, UnregisterWaitHandleCallback, Update }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectManipulationUpdateManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectManipulationViewport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Enable(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport", "Enable", 3usize, |this| ((**(this as *const *const IDirectManipulationViewport_Vtbl)).Enable)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disable(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport", "Disable", 4usize, |this| ((**(this as *const *const IDirectManipulationViewport_Vtbl)).Disable)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContact(this: *mut core::ffi::c_void, pointerid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport", "SetContact", 5usize, |this| ((**(this as *const *const IDirectManipulationViewport_Vtbl)).SetContact)(this, pointerid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseContact(this: *mut core::ffi::c_void, pointerid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport", "ReleaseContact", 6usize, |this| ((**(this as *const *const IDirectManipulationViewport_Vtbl)).ReleaseContact)(this, pointerid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseAllContacts(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport", "ReleaseAllContacts", 7usize, |this| ((**(this as *const *const IDirectManipulationViewport_Vtbl)).ReleaseAllContacts)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, status: *mut DIRECTMANIPULATION_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport", "GetStatus", 8usize, |this| ((**(this as *const *const IDirectManipulationViewport_Vtbl)).GetStatus)(this, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, object: *mut *mut core::ffi::c_void, id: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport", "GetTag", 9usize, |this| ((**(this as *const *const IDirectManipulationViewport_Vtbl)).GetTag)(this, riid, object, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTag(this: *mut core::ffi::c_void, object: *mut core::ffi::c_void, id: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport", "SetTag", 10usize, |this| ((**(this as *const *const IDirectManipulationViewport_Vtbl)).SetTag)(this, object, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetViewportRect(this: *mut core::ffi::c_void, viewport: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport", "GetViewportRect", 11usize, |this| ((**(this as *const *const IDirectManipulationViewport_Vtbl)).GetViewportRect)(this, viewport), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetViewportRect(this: *mut core::ffi::c_void, viewport: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport", "SetViewportRect", 12usize, |this| ((**(this as *const *const IDirectManipulationViewport_Vtbl)).SetViewportRect)(this, viewport), |result| Some(*result))
+        }
+        unsafe extern "system" fn ZoomToRect(this: *mut core::ffi::c_void, left: f32, top: f32, right: f32, bottom: f32, animate: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport", "ZoomToRect", 13usize, |this| ((**(this as *const *const IDirectManipulationViewport_Vtbl)).ZoomToRect)(this, left, top, right, bottom, animate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetViewportTransform(this: *mut core::ffi::c_void, matrix: *const f32, pointcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport", "SetViewportTransform", 14usize, |this| ((**(this as *const *const IDirectManipulationViewport_Vtbl)).SetViewportTransform)(this, matrix, pointcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncDisplayTransform(this: *mut core::ffi::c_void, matrix: *const f32, pointcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport", "SyncDisplayTransform", 15usize, |this| ((**(this as *const *const IDirectManipulationViewport_Vtbl)).SyncDisplayTransform)(this, matrix, pointcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrimaryContent(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, object: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport", "GetPrimaryContent", 16usize, |this| ((**(this as *const *const IDirectManipulationViewport_Vtbl)).GetPrimaryContent)(this, riid, object), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddContent(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport", "AddContent", 17usize, |this| ((**(this as *const *const IDirectManipulationViewport_Vtbl)).AddContent)(this, content), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveContent(this: *mut core::ffi::c_void, content: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport", "RemoveContent", 18usize, |this| ((**(this as *const *const IDirectManipulationViewport_Vtbl)).RemoveContent)(this, content), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetViewportOptions(this: *mut core::ffi::c_void, options: DIRECTMANIPULATION_VIEWPORT_OPTIONS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport", "SetViewportOptions", 19usize, |this| ((**(this as *const *const IDirectManipulationViewport_Vtbl)).SetViewportOptions)(this, options), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddConfiguration(this: *mut core::ffi::c_void, configuration: DIRECTMANIPULATION_CONFIGURATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport", "AddConfiguration", 20usize, |this| ((**(this as *const *const IDirectManipulationViewport_Vtbl)).AddConfiguration)(this, configuration), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveConfiguration(this: *mut core::ffi::c_void, configuration: DIRECTMANIPULATION_CONFIGURATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport", "RemoveConfiguration", 21usize, |this| ((**(this as *const *const IDirectManipulationViewport_Vtbl)).RemoveConfiguration)(this, configuration), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivateConfiguration(this: *mut core::ffi::c_void, configuration: DIRECTMANIPULATION_CONFIGURATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport", "ActivateConfiguration", 22usize, |this| ((**(this as *const *const IDirectManipulationViewport_Vtbl)).ActivateConfiguration)(this, configuration), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetManualGesture(this: *mut core::ffi::c_void, configuration: DIRECTMANIPULATION_GESTURE_CONFIGURATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport", "SetManualGesture", 23usize, |this| ((**(this as *const *const IDirectManipulationViewport_Vtbl)).SetManualGesture)(this, configuration), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetChaining(this: *mut core::ffi::c_void, enabledtypes: DIRECTMANIPULATION_MOTION_TYPES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport", "SetChaining", 24usize, |this| ((**(this as *const *const IDirectManipulationViewport_Vtbl)).SetChaining)(this, enabledtypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddEventHandler(this: *mut core::ffi::c_void, window: super::super::Foundation::HWND, eventhandler: *mut core::ffi::c_void, cookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport", "AddEventHandler", 25usize, |this| ((**(this as *const *const IDirectManipulationViewport_Vtbl)).AddEventHandler)(this, window, eventhandler, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEventHandler(this: *mut core::ffi::c_void, cookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport", "RemoveEventHandler", 26usize, |this| ((**(this as *const *const IDirectManipulationViewport_Vtbl)).RemoveEventHandler)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInputMode(this: *mut core::ffi::c_void, mode: DIRECTMANIPULATION_INPUT_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport", "SetInputMode", 27usize, |this| ((**(this as *const *const IDirectManipulationViewport_Vtbl)).SetInputMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUpdateMode(this: *mut core::ffi::c_void, mode: DIRECTMANIPULATION_INPUT_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport", "SetUpdateMode", 28usize, |this| ((**(this as *const *const IDirectManipulationViewport_Vtbl)).SetUpdateMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport", "Stop", 29usize, |this| ((**(this as *const *const IDirectManipulationViewport_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Abandon(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport", "Abandon", 30usize, |this| ((**(this as *const *const IDirectManipulationViewport_Vtbl)).Abandon)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Enable,
+            Disable,
+            SetContact,
+            ReleaseContact,
+            ReleaseAllContacts,
+            GetStatus,
+            GetTag,
+            SetTag,
+            GetViewportRect,
+            SetViewportRect,
+            ZoomToRect,
+            SetViewportTransform,
+            SyncDisplayTransform,
+            GetPrimaryContent,
+            AddContent,
+            RemoveContent,
+            SetViewportOptions,
+            AddConfiguration,
+            RemoveConfiguration,
+            ActivateConfiguration,
+            SetManualGesture,
+            SetChaining,
+            AddEventHandler,
+            RemoveEventHandler,
+            SetInputMode,
+            SetUpdateMode,
+            Stop,
+            Abandon,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectManipulationViewport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectManipulationViewport2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddBehavior(this: *mut core::ffi::c_void, behavior: *mut core::ffi::c_void, cookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport2", "AddBehavior", 31usize, |this| ((**(this as *const *const IDirectManipulationViewport2_Vtbl)).AddBehavior)(this, behavior, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBehavior(this: *mut core::ffi::c_void, cookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport2", "RemoveBehavior", 32usize, |this| ((**(this as *const *const IDirectManipulationViewport2_Vtbl)).RemoveBehavior)(this, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAllBehaviors(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewport2", "RemoveAllBehaviors", 33usize, |this| ((**(this as *const *const IDirectManipulationViewport2_Vtbl)).RemoveAllBehaviors)(this), |result| Some(*result))
+        }
+        Self { base__: <IDirectManipulationViewport_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddBehavior, RemoveBehavior, RemoveAllBehaviors }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectManipulationViewport2 as windows_core::Interface>::IID || <IDirectManipulationViewport_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectManipulationViewportEventHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnViewportStatusChanged(this: *mut core::ffi::c_void, viewport: *mut core::ffi::c_void, current: DIRECTMANIPULATION_STATUS, previous: DIRECTMANIPULATION_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewportEventHandler", "OnViewportStatusChanged", 3usize, |this| ((**(this as *const *const IDirectManipulationViewportEventHandler_Vtbl)).OnViewportStatusChanged)(this, viewport, current, previous), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnViewportUpdated(this: *mut core::ffi::c_void, viewport: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewportEventHandler", "OnViewportUpdated", 4usize, |this| ((**(this as *const *const IDirectManipulationViewportEventHandler_Vtbl)).OnViewportUpdated)(this, viewport), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnContentUpdated(this: *mut core::ffi::c_void, viewport: *mut core::ffi::c_void, content: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectManipulationViewportEventHandler", "OnContentUpdated", 5usize, |this| ((**(this as *const *const IDirectManipulationViewportEventHandler_Vtbl)).OnContentUpdated)(this, viewport, content), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnViewportStatusChanged, OnViewportUpdated, OnContentUpdated }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectManipulationViewportEventHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
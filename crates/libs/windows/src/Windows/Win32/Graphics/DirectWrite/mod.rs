@@ -4755,6 +4755,7 @@ pub struct DWRITE_AUTOMATIC_FONT_AXES(pub i32);
 impl windows_core::TypeKind for DWRITE_AUTOMATIC_FONT_AXES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_AUTOMATIC_FONT_AXES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_AUTOMATIC_FONT_AXES").field(&self.0).finish()
@@ -4799,6 +4800,7 @@ pub struct DWRITE_BASELINE(pub i32);
 impl windows_core::TypeKind for DWRITE_BASELINE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_BASELINE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_BASELINE").field(&self.0).finish()
@@ -4810,6 +4812,7 @@ pub struct DWRITE_BREAK_CONDITION(pub i32);
 impl windows_core::TypeKind for DWRITE_BREAK_CONDITION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_BREAK_CONDITION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_BREAK_CONDITION").field(&self.0).finish()
@@ -4821,6 +4824,7 @@ pub struct DWRITE_COLOR_COMPOSITE_MODE(pub i32);
 impl windows_core::TypeKind for DWRITE_COLOR_COMPOSITE_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_COLOR_COMPOSITE_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_COLOR_COMPOSITE_MODE").field(&self.0).finish()
@@ -4832,6 +4836,7 @@ pub struct DWRITE_CONTAINER_TYPE(pub i32);
 impl windows_core::TypeKind for DWRITE_CONTAINER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_CONTAINER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_CONTAINER_TYPE").field(&self.0).finish()
@@ -4843,6 +4848,7 @@ pub struct DWRITE_FACTORY_TYPE(pub i32);
 impl windows_core::TypeKind for DWRITE_FACTORY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_FACTORY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_FACTORY_TYPE").field(&self.0).finish()
@@ -4854,6 +4860,7 @@ pub struct DWRITE_FLOW_DIRECTION(pub i32);
 impl windows_core::TypeKind for DWRITE_FLOW_DIRECTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_FLOW_DIRECTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_FLOW_DIRECTION").field(&self.0).finish()
@@ -4865,6 +4872,7 @@ pub struct DWRITE_FONT_AXIS_ATTRIBUTES(pub i32);
 impl windows_core::TypeKind for DWRITE_FONT_AXIS_ATTRIBUTES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_FONT_AXIS_ATTRIBUTES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_FONT_AXIS_ATTRIBUTES").field(&self.0).finish()
@@ -4909,6 +4917,7 @@ pub struct DWRITE_FONT_AXIS_TAG(pub u32);
 impl windows_core::TypeKind for DWRITE_FONT_AXIS_TAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_FONT_AXIS_TAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_FONT_AXIS_TAG").field(&self.0).finish()
@@ -4920,6 +4929,7 @@ pub struct DWRITE_FONT_FACE_TYPE(pub i32);
 impl windows_core::TypeKind for DWRITE_FONT_FACE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_FONT_FACE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_FONT_FACE_TYPE").field(&self.0).finish()
@@ -4931,6 +4941,7 @@ pub struct DWRITE_FONT_FAMILY_MODEL(pub i32);
 impl windows_core::TypeKind for DWRITE_FONT_FAMILY_MODEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_FONT_FAMILY_MODEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_FONT_FAMILY_MODEL").field(&self.0).finish()
@@ -4942,6 +4953,7 @@ pub struct DWRITE_FONT_FEATURE_TAG(pub u32);
 impl windows_core::TypeKind for DWRITE_FONT_FEATURE_TAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_FONT_FEATURE_TAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_FONT_FEATURE_TAG").field(&self.0).finish()
@@ -4953,6 +4965,7 @@ pub struct DWRITE_FONT_FILE_TYPE(pub i32);
 impl windows_core::TypeKind for DWRITE_FONT_FILE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_FONT_FILE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_FONT_FILE_TYPE").field(&self.0).finish()
@@ -4964,6 +4977,7 @@ pub struct DWRITE_FONT_LINE_GAP_USAGE(pub i32);
 impl windows_core::TypeKind for DWRITE_FONT_LINE_GAP_USAGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_FONT_LINE_GAP_USAGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_FONT_LINE_GAP_USAGE").field(&self.0).finish()
@@ -4975,6 +4989,7 @@ pub struct DWRITE_FONT_PROPERTY_ID(pub i32);
 impl windows_core::TypeKind for DWRITE_FONT_PROPERTY_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_FONT_PROPERTY_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_FONT_PROPERTY_ID").field(&self.0).finish()
@@ -4986,6 +5001,7 @@ pub struct DWRITE_FONT_SIMULATIONS(pub i32);
 impl windows_core::TypeKind for DWRITE_FONT_SIMULATIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_FONT_SIMULATIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_FONT_SIMULATIONS").field(&self.0).finish()
@@ -5030,6 +5046,7 @@ pub struct DWRITE_FONT_SOURCE_TYPE(pub i32);
 impl windows_core::TypeKind for DWRITE_FONT_SOURCE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_FONT_SOURCE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_FONT_SOURCE_TYPE").field(&self.0).finish()
@@ -5041,6 +5058,7 @@ pub struct DWRITE_FONT_STRETCH(pub i32);
 impl windows_core::TypeKind for DWRITE_FONT_STRETCH {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_FONT_STRETCH {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_FONT_STRETCH").field(&self.0).finish()
@@ -5052,6 +5070,7 @@ pub struct DWRITE_FONT_STYLE(pub i32);
 impl windows_core::TypeKind for DWRITE_FONT_STYLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_FONT_STYLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_FONT_STYLE").field(&self.0).finish()
@@ -5063,6 +5082,7 @@ pub struct DWRITE_FONT_WEIGHT(pub i32);
 impl windows_core::TypeKind for DWRITE_FONT_WEIGHT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_FONT_WEIGHT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_FONT_WEIGHT").field(&self.0).finish()
@@ -5074,6 +5094,7 @@ pub struct DWRITE_GLYPH_IMAGE_FORMATS(pub i32);
 impl windows_core::TypeKind for DWRITE_GLYPH_IMAGE_FORMATS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_GLYPH_IMAGE_FORMATS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_GLYPH_IMAGE_FORMATS").field(&self.0).finish()
@@ -5118,6 +5139,7 @@ pub struct DWRITE_GLYPH_ORIENTATION_ANGLE(pub i32);
 impl windows_core::TypeKind for DWRITE_GLYPH_ORIENTATION_ANGLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_GLYPH_ORIENTATION_ANGLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_GLYPH_ORIENTATION_ANGLE").field(&self.0).finish()
@@ -5129,6 +5151,7 @@ pub struct DWRITE_GRID_FIT_MODE(pub i32);
 impl windows_core::TypeKind for DWRITE_GRID_FIT_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_GRID_FIT_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_GRID_FIT_MODE").field(&self.0).finish()
@@ -5140,6 +5163,7 @@ pub struct DWRITE_INFORMATIONAL_STRING_ID(pub i32);
 impl windows_core::TypeKind for DWRITE_INFORMATIONAL_STRING_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_INFORMATIONAL_STRING_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_INFORMATIONAL_STRING_ID").field(&self.0).finish()
@@ -5151,6 +5175,7 @@ pub struct DWRITE_LINE_SPACING_METHOD(pub i32);
 impl windows_core::TypeKind for DWRITE_LINE_SPACING_METHOD {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_LINE_SPACING_METHOD {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_LINE_SPACING_METHOD").field(&self.0).finish()
@@ -5162,6 +5187,7 @@ pub struct DWRITE_LOCALITY(pub i32);
 impl windows_core::TypeKind for DWRITE_LOCALITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_LOCALITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_LOCALITY").field(&self.0).finish()
@@ -5173,6 +5199,7 @@ pub struct DWRITE_MEASURING_MODE(pub i32);
 impl windows_core::TypeKind for DWRITE_MEASURING_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_MEASURING_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_MEASURING_MODE").field(&self.0).finish()
@@ -5184,6 +5211,7 @@ pub struct DWRITE_NUMBER_SUBSTITUTION_METHOD(pub i32);
 impl windows_core::TypeKind for DWRITE_NUMBER_SUBSTITUTION_METHOD {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_NUMBER_SUBSTITUTION_METHOD {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_NUMBER_SUBSTITUTION_METHOD").field(&self.0).finish()
@@ -5195,6 +5223,7 @@ pub struct DWRITE_OPTICAL_ALIGNMENT(pub i32);
 impl windows_core::TypeKind for DWRITE_OPTICAL_ALIGNMENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_OPTICAL_ALIGNMENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_OPTICAL_ALIGNMENT").field(&self.0).finish()
@@ -5206,6 +5235,7 @@ pub struct DWRITE_OUTLINE_THRESHOLD(pub i32);
 impl windows_core::TypeKind for DWRITE_OUTLINE_THRESHOLD {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_OUTLINE_THRESHOLD {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_OUTLINE_THRESHOLD").field(&self.0).finish()
@@ -5217,6 +5247,7 @@ pub struct DWRITE_PAINT_ATTRIBUTES(pub i32);
 impl windows_core::TypeKind for DWRITE_PAINT_ATTRIBUTES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PAINT_ATTRIBUTES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PAINT_ATTRIBUTES").field(&self.0).finish()
@@ -5261,6 +5292,7 @@ pub struct DWRITE_PAINT_FEATURE_LEVEL(pub i32);
 impl windows_core::TypeKind for DWRITE_PAINT_FEATURE_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PAINT_FEATURE_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PAINT_FEATURE_LEVEL").field(&self.0).finish()
@@ -5272,6 +5304,7 @@ pub struct DWRITE_PAINT_TYPE(pub i32);
 impl windows_core::TypeKind for DWRITE_PAINT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PAINT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PAINT_TYPE").field(&self.0).finish()
@@ -5283,6 +5316,7 @@ pub struct DWRITE_PANOSE_ARM_STYLE(pub i32);
 impl windows_core::TypeKind for DWRITE_PANOSE_ARM_STYLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PANOSE_ARM_STYLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PANOSE_ARM_STYLE").field(&self.0).finish()
@@ -5294,6 +5328,7 @@ pub struct DWRITE_PANOSE_ASPECT(pub i32);
 impl windows_core::TypeKind for DWRITE_PANOSE_ASPECT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PANOSE_ASPECT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PANOSE_ASPECT").field(&self.0).finish()
@@ -5305,6 +5340,7 @@ pub struct DWRITE_PANOSE_ASPECT_RATIO(pub i32);
 impl windows_core::TypeKind for DWRITE_PANOSE_ASPECT_RATIO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PANOSE_ASPECT_RATIO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PANOSE_ASPECT_RATIO").field(&self.0).finish()
@@ -5316,6 +5352,7 @@ pub struct DWRITE_PANOSE_CHARACTER_RANGES(pub i32);
 impl windows_core::TypeKind for DWRITE_PANOSE_CHARACTER_RANGES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PANOSE_CHARACTER_RANGES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PANOSE_CHARACTER_RANGES").field(&self.0).finish()
@@ -5327,6 +5364,7 @@ pub struct DWRITE_PANOSE_CONTRAST(pub i32);
 impl windows_core::TypeKind for DWRITE_PANOSE_CONTRAST {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PANOSE_CONTRAST {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PANOSE_CONTRAST").field(&self.0).finish()
@@ -5338,6 +5376,7 @@ pub struct DWRITE_PANOSE_DECORATIVE_CLASS(pub i32);
 impl windows_core::TypeKind for DWRITE_PANOSE_DECORATIVE_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PANOSE_DECORATIVE_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PANOSE_DECORATIVE_CLASS").field(&self.0).finish()
@@ -5349,6 +5388,7 @@ pub struct DWRITE_PANOSE_DECORATIVE_TOPOLOGY(pub i32);
 impl windows_core::TypeKind for DWRITE_PANOSE_DECORATIVE_TOPOLOGY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PANOSE_DECORATIVE_TOPOLOGY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PANOSE_DECORATIVE_TOPOLOGY").field(&self.0).finish()
@@ -5360,6 +5400,7 @@ pub struct DWRITE_PANOSE_FAMILY(pub i32);
 impl windows_core::TypeKind for DWRITE_PANOSE_FAMILY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PANOSE_FAMILY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PANOSE_FAMILY").field(&self.0).finish()
@@ -5371,6 +5412,7 @@ pub struct DWRITE_PANOSE_FILL(pub i32);
 impl windows_core::TypeKind for DWRITE_PANOSE_FILL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PANOSE_FILL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PANOSE_FILL").field(&self.0).finish()
@@ -5382,6 +5424,7 @@ pub struct DWRITE_PANOSE_FINIALS(pub i32);
 impl windows_core::TypeKind for DWRITE_PANOSE_FINIALS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PANOSE_FINIALS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PANOSE_FINIALS").field(&self.0).finish()
@@ -5393,6 +5436,7 @@ pub struct DWRITE_PANOSE_LETTERFORM(pub i32);
 impl windows_core::TypeKind for DWRITE_PANOSE_LETTERFORM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PANOSE_LETTERFORM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PANOSE_LETTERFORM").field(&self.0).finish()
@@ -5404,6 +5448,7 @@ pub struct DWRITE_PANOSE_LINING(pub i32);
 impl windows_core::TypeKind for DWRITE_PANOSE_LINING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PANOSE_LINING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PANOSE_LINING").field(&self.0).finish()
@@ -5415,6 +5460,7 @@ pub struct DWRITE_PANOSE_MIDLINE(pub i32);
 impl windows_core::TypeKind for DWRITE_PANOSE_MIDLINE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PANOSE_MIDLINE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PANOSE_MIDLINE").field(&self.0).finish()
@@ -5426,6 +5472,7 @@ pub struct DWRITE_PANOSE_PROPORTION(pub i32);
 impl windows_core::TypeKind for DWRITE_PANOSE_PROPORTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PANOSE_PROPORTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PANOSE_PROPORTION").field(&self.0).finish()
@@ -5437,6 +5484,7 @@ pub struct DWRITE_PANOSE_SCRIPT_FORM(pub i32);
 impl windows_core::TypeKind for DWRITE_PANOSE_SCRIPT_FORM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PANOSE_SCRIPT_FORM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PANOSE_SCRIPT_FORM").field(&self.0).finish()
@@ -5448,6 +5496,7 @@ pub struct DWRITE_PANOSE_SCRIPT_TOPOLOGY(pub i32);
 impl windows_core::TypeKind for DWRITE_PANOSE_SCRIPT_TOPOLOGY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PANOSE_SCRIPT_TOPOLOGY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PANOSE_SCRIPT_TOPOLOGY").field(&self.0).finish()
@@ -5459,6 +5508,7 @@ pub struct DWRITE_PANOSE_SERIF_STYLE(pub i32);
 impl windows_core::TypeKind for DWRITE_PANOSE_SERIF_STYLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PANOSE_SERIF_STYLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PANOSE_SERIF_STYLE").field(&self.0).finish()
@@ -5470,6 +5520,7 @@ pub struct DWRITE_PANOSE_SPACING(pub i32);
 impl windows_core::TypeKind for DWRITE_PANOSE_SPACING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PANOSE_SPACING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PANOSE_SPACING").field(&self.0).finish()
@@ -5481,6 +5532,7 @@ pub struct DWRITE_PANOSE_STROKE_VARIATION(pub i32);
 impl windows_core::TypeKind for DWRITE_PANOSE_STROKE_VARIATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PANOSE_STROKE_VARIATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PANOSE_STROKE_VARIATION").field(&self.0).finish()
@@ -5492,6 +5544,7 @@ pub struct DWRITE_PANOSE_SYMBOL_ASPECT_RATIO(pub i32);
 impl windows_core::TypeKind for DWRITE_PANOSE_SYMBOL_ASPECT_RATIO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PANOSE_SYMBOL_ASPECT_RATIO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PANOSE_SYMBOL_ASPECT_RATIO").field(&self.0).finish()
@@ -5503,6 +5556,7 @@ pub struct DWRITE_PANOSE_SYMBOL_KIND(pub i32);
 impl windows_core::TypeKind for DWRITE_PANOSE_SYMBOL_KIND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PANOSE_SYMBOL_KIND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PANOSE_SYMBOL_KIND").field(&self.0).finish()
@@ -5514,6 +5568,7 @@ pub struct DWRITE_PANOSE_TOOL_KIND(pub i32);
 impl windows_core::TypeKind for DWRITE_PANOSE_TOOL_KIND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PANOSE_TOOL_KIND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PANOSE_TOOL_KIND").field(&self.0).finish()
@@ -5525,6 +5580,7 @@ pub struct DWRITE_PANOSE_WEIGHT(pub i32);
 impl windows_core::TypeKind for DWRITE_PANOSE_WEIGHT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PANOSE_WEIGHT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PANOSE_WEIGHT").field(&self.0).finish()
@@ -5536,6 +5592,7 @@ pub struct DWRITE_PANOSE_XASCENT(pub i32);
 impl windows_core::TypeKind for DWRITE_PANOSE_XASCENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PANOSE_XASCENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PANOSE_XASCENT").field(&self.0).finish()
@@ -5547,6 +5604,7 @@ pub struct DWRITE_PANOSE_XHEIGHT(pub i32);
 impl windows_core::TypeKind for DWRITE_PANOSE_XHEIGHT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PANOSE_XHEIGHT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PANOSE_XHEIGHT").field(&self.0).finish()
@@ -5558,6 +5616,7 @@ pub struct DWRITE_PARAGRAPH_ALIGNMENT(pub i32);
 impl windows_core::TypeKind for DWRITE_PARAGRAPH_ALIGNMENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PARAGRAPH_ALIGNMENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PARAGRAPH_ALIGNMENT").field(&self.0).finish()
@@ -5569,6 +5628,7 @@ pub struct DWRITE_PIXEL_GEOMETRY(pub i32);
 impl windows_core::TypeKind for DWRITE_PIXEL_GEOMETRY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_PIXEL_GEOMETRY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_PIXEL_GEOMETRY").field(&self.0).finish()
@@ -5580,6 +5640,7 @@ pub struct DWRITE_READING_DIRECTION(pub i32);
 impl windows_core::TypeKind for DWRITE_READING_DIRECTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_READING_DIRECTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_READING_DIRECTION").field(&self.0).finish()
@@ -5591,6 +5652,7 @@ pub struct DWRITE_RENDERING_MODE(pub i32);
 impl windows_core::TypeKind for DWRITE_RENDERING_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_RENDERING_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_RENDERING_MODE").field(&self.0).finish()
@@ -5602,6 +5664,7 @@ pub struct DWRITE_RENDERING_MODE1(pub i32);
 impl windows_core::TypeKind for DWRITE_RENDERING_MODE1 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_RENDERING_MODE1 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_RENDERING_MODE1").field(&self.0).finish()
@@ -5613,6 +5676,7 @@ pub struct DWRITE_SCRIPT_SHAPES(pub i32);
 impl windows_core::TypeKind for DWRITE_SCRIPT_SHAPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_SCRIPT_SHAPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_SCRIPT_SHAPES").field(&self.0).finish()
@@ -5657,6 +5721,7 @@ pub struct DWRITE_TEXTURE_TYPE(pub i32);
 impl windows_core::TypeKind for DWRITE_TEXTURE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_TEXTURE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_TEXTURE_TYPE").field(&self.0).finish()
@@ -5668,6 +5733,7 @@ pub struct DWRITE_TEXT_ALIGNMENT(pub i32);
 impl windows_core::TypeKind for DWRITE_TEXT_ALIGNMENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_TEXT_ALIGNMENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_TEXT_ALIGNMENT").field(&self.0).finish()
@@ -5679,6 +5745,7 @@ pub struct DWRITE_TEXT_ANTIALIAS_MODE(pub i32);
 impl windows_core::TypeKind for DWRITE_TEXT_ANTIALIAS_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_TEXT_ANTIALIAS_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_TEXT_ANTIALIAS_MODE").field(&self.0).finish()
@@ -5690,6 +5757,7 @@ pub struct DWRITE_TRIMMING_GRANULARITY(pub i32);
 impl windows_core::TypeKind for DWRITE_TRIMMING_GRANULARITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_TRIMMING_GRANULARITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_TRIMMING_GRANULARITY").field(&self.0).finish()
@@ -5701,6 +5769,7 @@ pub struct DWRITE_VERTICAL_GLYPH_ORIENTATION(pub i32);
 impl windows_core::TypeKind for DWRITE_VERTICAL_GLYPH_ORIENTATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_VERTICAL_GLYPH_ORIENTATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_VERTICAL_GLYPH_ORIENTATION").field(&self.0).finish()
@@ -5712,13 +5781,15 @@ pub struct DWRITE_WORD_WRAPPING(pub i32);
 impl windows_core::TypeKind for DWRITE_WORD_WRAPPING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWRITE_WORD_WRAPPING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWRITE_WORD_WRAPPING").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_BITMAP_DATA_BGRA32 {
     pub width: u32,
     pub height: u32,
@@ -5733,7 +5804,8 @@ impl Default for DWRITE_BITMAP_DATA_BGRA32 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_CARET_METRICS {
     pub slopeRise: i16,
     pub slopeRun: i16,
@@ -5748,12 +5820,51 @@ impl Default for DWRITE_CARET_METRICS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_CLUSTER_METRICS {
     pub width: f32,
     pub length: u16,
     pub _bitfield: u16,
 }
+impl DWRITE_CLUSTER_METRICS {
+    pub const fn canWrapLineAfter(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_canWrapLineAfter(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn isWhitespace(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_isWhitespace(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn isNewline(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_isNewline(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn isSoftHyphen(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_isSoftHyphen(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn isRightToLeft(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_isRightToLeft(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn padding(&self) -> u16 {
+        (self._bitfield >> 5) & 2047
+    }
+    pub fn set_padding(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(2047 << 5)) | ((value & 2047) << 5);
+    }
+}
 impl windows_core::TypeKind for DWRITE_CLUSTER_METRICS {
     type TypeKind = windows_core::CopyType;
 }
@@ -5763,7 +5874,8 @@ impl Default for DWRITE_CLUSTER_METRICS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_COLOR_F {
     pub r: f32,
     pub g: f32,
@@ -5779,7 +5891,8 @@ impl Default for DWRITE_COLOR_F {
     }
 }
 #[repr(C)]
-#[derive(Debug, PartialEq)]
+#[derive(PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_COLOR_GLYPH_RUN {
     pub glyphRun: DWRITE_GLYPH_RUN,
     pub glyphRunDescription: *mut DWRITE_GLYPH_RUN_DESCRIPTION,
@@ -5802,7 +5915,8 @@ impl Default for DWRITE_COLOR_GLYPH_RUN {
     }
 }
 #[repr(C)]
-#[derive(Debug, PartialEq)]
+#[derive(PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_COLOR_GLYPH_RUN1 {
     pub Base: DWRITE_COLOR_GLYPH_RUN,
     pub glyphImageFormat: DWRITE_GLYPH_IMAGE_FORMATS,
@@ -5822,7 +5936,8 @@ impl Default for DWRITE_COLOR_GLYPH_RUN1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_FILE_FRAGMENT {
     pub fileOffset: u64,
     pub fragmentSize: u64,
@@ -5836,7 +5951,8 @@ impl Default for DWRITE_FILE_FRAGMENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_FONT_AXIS_RANGE {
     pub axisTag: DWRITE_FONT_AXIS_TAG,
     pub minValue: f32,
@@ -5851,7 +5967,8 @@ impl Default for DWRITE_FONT_AXIS_RANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_FONT_AXIS_VALUE {
     pub axisTag: DWRITE_FONT_AXIS_TAG,
     pub value: f32,
@@ -5865,7 +5982,8 @@ impl Default for DWRITE_FONT_AXIS_VALUE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_FONT_FEATURE {
     pub nameTag: DWRITE_FONT_FEATURE_TAG,
     pub parameter: u32,
@@ -5879,7 +5997,8 @@ impl Default for DWRITE_FONT_FEATURE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_FONT_METRICS {
     pub designUnitsPerEm: u16,
     pub ascent: u16,
@@ -5901,7 +6020,8 @@ impl Default for DWRITE_FONT_METRICS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_FONT_METRICS1 {
     pub Base: DWRITE_FONT_METRICS,
     pub glyphBoxLeft: i16,
@@ -5927,7 +6047,8 @@ impl Default for DWRITE_FONT_METRICS1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_FONT_PROPERTY {
     pub propertyId: DWRITE_FONT_PROPERTY_ID,
     pub propertyValue: windows_core::PCWSTR,
@@ -5943,7 +6064,8 @@ impl Default for DWRITE_FONT_PROPERTY {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_GLYPH_IMAGE_DATA {
     pub imageData: *const core::ffi::c_void,
     pub imageDataSize: u32,
@@ -5966,7 +6088,8 @@ impl Default for DWRITE_GLYPH_IMAGE_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_GLYPH_METRICS {
     pub leftSideBearing: i32,
     pub advanceWidth: u32,
@@ -5985,7 +6108,8 @@ impl Default for DWRITE_GLYPH_METRICS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_GLYPH_OFFSET {
     pub advanceOffset: f32,
     pub ascenderOffset: f32,
@@ -5999,7 +6123,8 @@ impl Default for DWRITE_GLYPH_OFFSET {
     }
 }
 #[repr(C)]
-#[derive(Debug, PartialEq)]
+#[derive(PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_GLYPH_RUN {
     pub fontFace: core::mem::ManuallyDrop<Option<IDWriteFontFace>>,
     pub fontEmSize: f32,
@@ -6024,7 +6149,8 @@ impl Default for DWRITE_GLYPH_RUN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_GLYPH_RUN_DESCRIPTION {
     pub localeName: windows_core::PCWSTR,
     pub string: windows_core::PCWSTR,
@@ -6041,7 +6167,8 @@ impl Default for DWRITE_GLYPH_RUN_DESCRIPTION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_HIT_TEST_METRICS {
     pub textPosition: u32,
     pub length: u32,
@@ -6062,7 +6189,8 @@ impl Default for DWRITE_HIT_TEST_METRICS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_INLINE_OBJECT_METRICS {
     pub width: f32,
     pub height: f32,
@@ -6078,13 +6206,58 @@ impl Default for DWRITE_INLINE_OBJECT_METRICS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_JUSTIFICATION_OPPORTUNITY {
     pub expansionMinimum: f32,
     pub expansionMaximum: f32,
     pub compressionMaximum: f32,
     pub _bitfield: u32,
 }
+impl DWRITE_JUSTIFICATION_OPPORTUNITY {
+    pub const fn expansionPriority(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_expansionPriority(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn compressionPriority(&self) -> u32 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_compressionPriority(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn allowResidualExpansion(&self) -> u32 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_allowResidualExpansion(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn allowResidualCompression(&self) -> u32 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_allowResidualCompression(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn applyToLeadingEdge(&self) -> u32 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_applyToLeadingEdge(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn applyToTrailingEdge(&self) -> u32 {
+        (self._bitfield >> 19) & 1
+    }
+    pub fn set_applyToTrailingEdge(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 19)) | ((value & 1) << 19);
+    }
+    pub const fn reserved(&self) -> u32 {
+        (self._bitfield >> 20) & 4095
+    }
+    pub fn set_reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(4095 << 20)) | ((value & 4095) << 20);
+    }
+}
 impl windows_core::TypeKind for DWRITE_JUSTIFICATION_OPPORTUNITY {
     type TypeKind = windows_core::CopyType;
 }
@@ -6094,10 +6267,43 @@ impl Default for DWRITE_JUSTIFICATION_OPPORTUNITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_LINE_BREAKPOINT {
     pub _bitfield: u8,
 }
+impl DWRITE_LINE_BREAKPOINT {
+    pub const fn breakConditionBefore(&self) -> u8 {
+        self._bitfield & 3
+    }
+    pub fn set_breakConditionBefore(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn breakConditionAfter(&self) -> u8 {
+        (self._bitfield >> 2) & 3
+    }
+    pub fn set_breakConditionAfter(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(3 << 2)) | ((value & 3) << 2);
+    }
+    pub const fn isWhitespace(&self) -> u8 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_isWhitespace(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn isSoftHyphen(&self) -> u8 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_isSoftHyphen(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn padding(&self) -> u8 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_padding(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+}
 impl windows_core::TypeKind for DWRITE_LINE_BREAKPOINT {
     type TypeKind = windows_core::CopyType;
 }
@@ -6107,7 +6313,8 @@ impl Default for DWRITE_LINE_BREAKPOINT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_LINE_METRICS {
     pub length: u32,
     pub trailingWhitespaceLength: u32,
@@ -6125,7 +6332,8 @@ impl Default for DWRITE_LINE_METRICS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_LINE_METRICS1 {
     pub Base: DWRITE_LINE_METRICS,
     pub leadingBefore: f32,
@@ -6140,7 +6348,8 @@ impl Default for DWRITE_LINE_METRICS1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_LINE_SPACING {
     pub method: DWRITE_LINE_SPACING_METHOD,
     pub height: f32,
@@ -6157,7 +6366,8 @@ impl Default for DWRITE_LINE_SPACING {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_MATRIX {
     pub m11: f32,
     pub m12: f32,
@@ -6175,7 +6385,8 @@ impl Default for DWRITE_MATRIX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_OVERHANG_METRICS {
     pub left: f32,
     pub top: f32,
@@ -6191,7 +6402,8 @@ impl Default for DWRITE_OVERHANG_METRICS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_PAINT_COLOR {
     pub value: DWRITE_COLOR_F,
     pub paletteEntryIndex: u16,
@@ -6250,7 +6462,8 @@ impl Default for DWRITE_PAINT_ELEMENT_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_PAINT_ELEMENT_0_0 {
     pub glyphIndex: u32,
     pub clipBox: super::Direct2D::Common::D2D_RECT_F,
@@ -6267,7 +6480,8 @@ impl Default for DWRITE_PAINT_ELEMENT_0_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_PAINT_ELEMENT_0_1 {
     pub mode: DWRITE_COLOR_COMPOSITE_MODE,
 }
@@ -6283,7 +6497,8 @@ impl Default for DWRITE_PAINT_ELEMENT_0_1 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_PAINT_ELEMENT_0_2 {
     pub glyphIndex: u32,
 }
@@ -6299,7 +6514,8 @@ impl Default for DWRITE_PAINT_ELEMENT_0_2 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_PAINT_ELEMENT_0_3 {
     pub childCount: u32,
 }
@@ -6315,7 +6531,8 @@ impl Default for DWRITE_PAINT_ELEMENT_0_3 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_PAINT_ELEMENT_0_4 {
     pub extendMode: u32,
     pub gradientStopCount: u32,
@@ -6338,7 +6555,8 @@ impl Default for DWRITE_PAINT_ELEMENT_0_4 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_PAINT_ELEMENT_0_5 {
     pub extendMode: u32,
     pub gradientStopCount: u32,
@@ -6361,7 +6579,8 @@ impl Default for DWRITE_PAINT_ELEMENT_0_5 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_PAINT_ELEMENT_0_6 {
     pub glyphIndex: u32,
     pub color: DWRITE_PAINT_COLOR,
@@ -6378,7 +6597,8 @@ impl Default for DWRITE_PAINT_ELEMENT_0_6 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_PAINT_ELEMENT_0_7 {
     pub extendMode: u32,
     pub gradientStopCount: u32,
@@ -6416,7 +6636,8 @@ impl Default for DWRITE_PANOSE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_PANOSE_0 {
     pub familyKind: u8,
     pub decorativeClass: u8,
@@ -6438,7 +6659,8 @@ impl Default for DWRITE_PANOSE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_PANOSE_1 {
     pub familyKind: u8,
     pub toolKind: u8,
@@ -6460,7 +6682,8 @@ impl Default for DWRITE_PANOSE_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_PANOSE_2 {
     pub familyKind: u8,
     pub symbolKind: u8,
@@ -6482,7 +6705,8 @@ impl Default for DWRITE_PANOSE_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_PANOSE_3 {
     pub familyKind: u8,
     pub serifStyle: u8,
@@ -6504,7 +6728,8 @@ impl Default for DWRITE_PANOSE_3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_SCRIPT_ANALYSIS {
     pub script: u16,
     pub shapes: DWRITE_SCRIPT_SHAPES,
@@ -6518,7 +6743,8 @@ impl Default for DWRITE_SCRIPT_ANALYSIS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_SCRIPT_PROPERTIES {
     pub isoScriptCode: u32,
     pub isoScriptNumber: u32,
@@ -6526,6 +6752,56 @@ pub struct DWRITE_SCRIPT_PROPERTIES {
     pub justificationCharacter: u32,
     pub _bitfield: u32,
 }
+impl DWRITE_SCRIPT_PROPERTIES {
+    pub const fn restrictCaretToClusters(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_restrictCaretToClusters(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn usesWordDividers(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_usesWordDividers(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn isDiscreteWriting(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_isDiscreteWriting(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn isBlockWriting(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_isBlockWriting(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn isDistributedWithinCluster(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_isDistributedWithinCluster(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn isConnectedWriting(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_isConnectedWriting(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn isCursiveWriting(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_isCursiveWriting(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn reserved(&self) -> u32 {
+        (self._bitfield >> 7) & 33554431
+    }
+    pub fn set_reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(33554431 << 7)) | ((value & 33554431) << 7);
+    }
+}
 impl windows_core::TypeKind for DWRITE_SCRIPT_PROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
@@ -6535,10 +6811,43 @@ impl Default for DWRITE_SCRIPT_PROPERTIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_SHAPING_GLYPH_PROPERTIES {
     pub _bitfield: u16,
 }
+impl DWRITE_SHAPING_GLYPH_PROPERTIES {
+    pub const fn justification(&self) -> u16 {
+        self._bitfield & 15
+    }
+    pub fn set_justification(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn isClusterStart(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_isClusterStart(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn isDiacritic(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_isDiacritic(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn isZeroWidthSpace(&self) -> u16 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_isZeroWidthSpace(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn reserved(&self) -> u16 {
+        (self._bitfield >> 7) & 511
+    }
+    pub fn set_reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(511 << 7)) | ((value & 511) << 7);
+    }
+}
 impl windows_core::TypeKind for DWRITE_SHAPING_GLYPH_PROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
@@ -6548,10 +6857,37 @@ impl Default for DWRITE_SHAPING_GLYPH_PROPERTIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_SHAPING_TEXT_PROPERTIES {
     pub _bitfield: u16,
 }
+impl DWRITE_SHAPING_TEXT_PROPERTIES {
+    pub const fn isShapedAlone(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_isShapedAlone(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn reserved1(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_reserved1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn canBreakShapingAfter(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_canBreakShapingAfter(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn reserved(&self) -> u16 {
+        (self._bitfield >> 3) & 8191
+    }
+    pub fn set_reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(8191 << 3)) | ((value & 8191) << 3);
+    }
+}
 impl windows_core::TypeKind for DWRITE_SHAPING_TEXT_PROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
@@ -6561,7 +6897,8 @@ impl Default for DWRITE_SHAPING_TEXT_PROPERTIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_STRIKETHROUGH {
     pub width: f32,
     pub thickness: f32,
@@ -6580,7 +6917,8 @@ impl Default for DWRITE_STRIKETHROUGH {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_TEXT_METRICS {
     pub left: f32,
     pub top: f32,
@@ -6601,7 +6939,8 @@ impl Default for DWRITE_TEXT_METRICS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_TEXT_METRICS1 {
     pub Base: DWRITE_TEXT_METRICS,
     pub heightIncludingTrailingWhitespace: f32,
@@ -6615,7 +6954,8 @@ impl Default for DWRITE_TEXT_METRICS1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_TEXT_RANGE {
     pub startPosition: u32,
     pub length: u32,
@@ -6629,7 +6969,8 @@ impl Default for DWRITE_TEXT_RANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_TRIMMING {
     pub granularity: DWRITE_TRIMMING_GRANULARITY,
     pub delimiter: u32,
@@ -6644,7 +6985,8 @@ impl Default for DWRITE_TRIMMING {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_TYPOGRAPHIC_FEATURES {
     pub features: *mut DWRITE_FONT_FEATURE,
     pub featureCount: u32,
@@ -6658,7 +7000,8 @@ impl Default for DWRITE_TYPOGRAPHIC_FEATURES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_UNDERLINE {
     pub width: f32,
     pub thickness: f32,
@@ -6678,7 +7021,8 @@ impl Default for DWRITE_UNDERLINE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWRITE_UNICODE_RANGE {
     pub first: u32,
     pub last: u32,
@@ -6693,3 +7037,5 @@ impl Default for DWRITE_UNICODE_RANGE {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
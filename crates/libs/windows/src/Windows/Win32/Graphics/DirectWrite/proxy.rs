@@ -0,0 +1,2843 @@
+impl windows_core::imp::ProxyVtable for IDWriteAsyncResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetWaitHandle(this: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            windows_core::imp::proxy_call(this, "IDWriteAsyncResult", "GetWaitHandle", 3usize, |this| ((**(this as *const *const IDWriteAsyncResult_Vtbl)).GetWaitHandle)(this), |_| None)
+        }
+        unsafe extern "system" fn GetResult(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteAsyncResult", "GetResult", 4usize, |this| ((**(this as *const *const IDWriteAsyncResult_Vtbl)).GetResult)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetWaitHandle, GetResult }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteAsyncResult as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IDWriteBitmapRenderTarget_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DrawGlyphRun(this: *mut core::ffi::c_void, baselineoriginx: f32, baselineoriginy: f32, measuringmode: DWRITE_MEASURING_MODE, glyphrun: *const DWRITE_GLYPH_RUN, renderingparams: *mut core::ffi::c_void, textcolor: super::super::Foundation::COLORREF, blackboxrect: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteBitmapRenderTarget", "DrawGlyphRun", 3usize, |this| ((**(this as *const *const IDWriteBitmapRenderTarget_Vtbl)).DrawGlyphRun)(this, baselineoriginx, baselineoriginy, measuringmode, glyphrun, renderingparams, textcolor, blackboxrect), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetMemoryDC(this: *mut core::ffi::c_void) -> super::Gdi::HDC {
+            windows_core::imp::proxy_call(this, "IDWriteBitmapRenderTarget", "GetMemoryDC", 4usize, |this| ((**(this as *const *const IDWriteBitmapRenderTarget_Vtbl)).GetMemoryDC)(this), |_| None)
+        }
+        unsafe extern "system" fn GetPixelsPerDip(this: *mut core::ffi::c_void) -> f32 {
+            windows_core::imp::proxy_call(this, "IDWriteBitmapRenderTarget", "GetPixelsPerDip", 5usize, |this| ((**(this as *const *const IDWriteBitmapRenderTarget_Vtbl)).GetPixelsPerDip)(this), |_| None)
+        }
+        unsafe extern "system" fn SetPixelsPerDip(this: *mut core::ffi::c_void, pixelsperdip: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteBitmapRenderTarget", "SetPixelsPerDip", 6usize, |this| ((**(this as *const *const IDWriteBitmapRenderTarget_Vtbl)).SetPixelsPerDip)(this, pixelsperdip), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentTransform(this: *mut core::ffi::c_void, transform: *mut DWRITE_MATRIX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteBitmapRenderTarget", "GetCurrentTransform", 7usize, |this| ((**(this as *const *const IDWriteBitmapRenderTarget_Vtbl)).GetCurrentTransform)(this, transform), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentTransform(this: *mut core::ffi::c_void, transform: *const DWRITE_MATRIX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteBitmapRenderTarget", "SetCurrentTransform", 8usize, |this| ((**(this as *const *const IDWriteBitmapRenderTarget_Vtbl)).SetCurrentTransform)(this, transform), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSize(this: *mut core::ffi::c_void, size: *mut super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteBitmapRenderTarget", "GetSize", 9usize, |this| ((**(this as *const *const IDWriteBitmapRenderTarget_Vtbl)).GetSize)(this, size), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resize(this: *mut core::ffi::c_void, width: u32, height: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteBitmapRenderTarget", "Resize", 10usize, |this| ((**(this as *const *const IDWriteBitmapRenderTarget_Vtbl)).Resize)(this, width, height), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DrawGlyphRun,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetMemoryDC,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetMemoryDC: 0,
+            GetPixelsPerDip,
+            SetPixelsPerDip,
+            GetCurrentTransform,
+            SetCurrentTransform,
+            GetSize,
+            Resize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteBitmapRenderTarget as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IDWriteBitmapRenderTarget1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTextAntialiasMode(this: *mut core::ffi::c_void) -> DWRITE_TEXT_ANTIALIAS_MODE {
+            windows_core::imp::proxy_call(this, "IDWriteBitmapRenderTarget1", "GetTextAntialiasMode", 11usize, |this| ((**(this as *const *const IDWriteBitmapRenderTarget1_Vtbl)).GetTextAntialiasMode)(this), |_| None)
+        }
+        unsafe extern "system" fn SetTextAntialiasMode(this: *mut core::ffi::c_void, antialiasmode: DWRITE_TEXT_ANTIALIAS_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteBitmapRenderTarget1", "SetTextAntialiasMode", 12usize, |this| ((**(this as *const *const IDWriteBitmapRenderTarget1_Vtbl)).SetTextAntialiasMode)(this, antialiasmode), |result| Some(*result))
+        }
+        Self { base__: <IDWriteBitmapRenderTarget_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetTextAntialiasMode, SetTextAntialiasMode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteBitmapRenderTarget1 as windows_core::Interface>::IID || <IDWriteBitmapRenderTarget_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IDWriteBitmapRenderTarget2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBitmapData(this: *mut core::ffi::c_void, bitmapdata: *mut DWRITE_BITMAP_DATA_BGRA32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteBitmapRenderTarget2", "GetBitmapData", 13usize, |this| ((**(this as *const *const IDWriteBitmapRenderTarget2_Vtbl)).GetBitmapData)(this, bitmapdata), |result| Some(*result))
+        }
+        Self { base__: <IDWriteBitmapRenderTarget1_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetBitmapData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteBitmapRenderTarget2 as windows_core::Interface>::IID || <IDWriteBitmapRenderTarget1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IDWriteBitmapRenderTarget3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPaintFeatureLevel(this: *mut core::ffi::c_void) -> DWRITE_PAINT_FEATURE_LEVEL {
+            windows_core::imp::proxy_call(this, "IDWriteBitmapRenderTarget3", "GetPaintFeatureLevel", 14usize, |this| ((**(this as *const *const IDWriteBitmapRenderTarget3_Vtbl)).GetPaintFeatureLevel)(this), |_| None)
+        }
+        unsafe extern "system" fn DrawPaintGlyphRun(this: *mut core::ffi::c_void, baselineoriginx: f32, baselineoriginy: f32, measuringmode: DWRITE_MEASURING_MODE, glyphrun: *const DWRITE_GLYPH_RUN, glyphimageformat: DWRITE_GLYPH_IMAGE_FORMATS, textcolor: super::super::Foundation::COLORREF, colorpaletteindex: u32, blackboxrect: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteBitmapRenderTarget3", "DrawPaintGlyphRun", 15usize, |this| ((**(this as *const *const IDWriteBitmapRenderTarget3_Vtbl)).DrawPaintGlyphRun)(this, baselineoriginx, baselineoriginy, measuringmode, glyphrun, glyphimageformat, textcolor, colorpaletteindex, blackboxrect), |result| Some(*result))
+        }
+        unsafe extern "system" fn DrawGlyphRunWithColorSupport(this: *mut core::ffi::c_void, baselineoriginx: f32, baselineoriginy: f32, measuringmode: DWRITE_MEASURING_MODE, glyphrun: *const DWRITE_GLYPH_RUN, renderingparams: *mut core::ffi::c_void, textcolor: super::super::Foundation::COLORREF, colorpaletteindex: u32, blackboxrect: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteBitmapRenderTarget3", "DrawGlyphRunWithColorSupport", 16usize, |this| ((**(this as *const *const IDWriteBitmapRenderTarget3_Vtbl)).DrawGlyphRunWithColorSupport)(this, baselineoriginx, baselineoriginy, measuringmode, glyphrun, renderingparams, textcolor, colorpaletteindex, blackboxrect), |result| Some(*result))
+        }
+        Self {
+            base__: <IDWriteBitmapRenderTarget2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPaintFeatureLevel,
+            DrawPaintGlyphRun,
+            DrawGlyphRunWithColorSupport,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteBitmapRenderTarget3 as windows_core::Interface>::IID || <IDWriteBitmapRenderTarget2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteColorGlyphRunEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hasrun: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteColorGlyphRunEnumerator", "MoveNext", 3usize, |this| ((**(this as *const *const IDWriteColorGlyphRunEnumerator_Vtbl)).MoveNext)(this, hasrun), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentRun(this: *mut core::ffi::c_void, colorglyphrun: *mut *mut DWRITE_COLOR_GLYPH_RUN) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteColorGlyphRunEnumerator", "GetCurrentRun", 4usize, |this| ((**(this as *const *const IDWriteColorGlyphRunEnumerator_Vtbl)).GetCurrentRun)(this, colorglyphrun), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MoveNext, GetCurrentRun }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteColorGlyphRunEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteColorGlyphRunEnumerator1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentRun(this: *mut core::ffi::c_void, colorglyphrun: *mut *mut DWRITE_COLOR_GLYPH_RUN1) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteColorGlyphRunEnumerator1", "GetCurrentRun", 5usize, |this| ((**(this as *const *const IDWriteColorGlyphRunEnumerator1_Vtbl)).GetCurrentRun)(this, colorglyphrun), |result| Some(*result))
+        }
+        Self { base__: <IDWriteColorGlyphRunEnumerator_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrentRun }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteColorGlyphRunEnumerator1 as windows_core::Interface>::IID || <IDWriteColorGlyphRunEnumerator_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IDWriteFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSystemFontCollection(this: *mut core::ffi::c_void, fontcollection: *mut *mut core::ffi::c_void, checkforupdates: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory", "GetSystemFontCollection", 3usize, |this| ((**(this as *const *const IDWriteFactory_Vtbl)).GetSystemFontCollection)(this, fontcollection, checkforupdates), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCustomFontCollection(this: *mut core::ffi::c_void, collectionloader: *mut core::ffi::c_void, collectionkey: *const core::ffi::c_void, collectionkeysize: u32, fontcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory", "CreateCustomFontCollection", 4usize, |this| ((**(this as *const *const IDWriteFactory_Vtbl)).CreateCustomFontCollection)(this, collectionloader, collectionkey, collectionkeysize, fontcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterFontCollectionLoader(this: *mut core::ffi::c_void, fontcollectionloader: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory", "RegisterFontCollectionLoader", 5usize, |this| ((**(this as *const *const IDWriteFactory_Vtbl)).RegisterFontCollectionLoader)(this, fontcollectionloader), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterFontCollectionLoader(this: *mut core::ffi::c_void, fontcollectionloader: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory", "UnregisterFontCollectionLoader", 6usize, |this| ((**(this as *const *const IDWriteFactory_Vtbl)).UnregisterFontCollectionLoader)(this, fontcollectionloader), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFontFileReference(this: *mut core::ffi::c_void, filepath: windows_core::PCWSTR, lastwritetime: *const super::super::Foundation::FILETIME, fontfile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory", "CreateFontFileReference", 7usize, |this| ((**(this as *const *const IDWriteFactory_Vtbl)).CreateFontFileReference)(this, filepath, lastwritetime, fontfile), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCustomFontFileReference(this: *mut core::ffi::c_void, fontfilereferencekey: *const core::ffi::c_void, fontfilereferencekeysize: u32, fontfileloader: *mut core::ffi::c_void, fontfile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory", "CreateCustomFontFileReference", 8usize, |this| ((**(this as *const *const IDWriteFactory_Vtbl)).CreateCustomFontFileReference)(this, fontfilereferencekey, fontfilereferencekeysize, fontfileloader, fontfile), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFontFace(this: *mut core::ffi::c_void, fontfacetype: DWRITE_FONT_FACE_TYPE, numberoffiles: u32, fontfiles: *const *mut core::ffi::c_void, faceindex: u32, fontfacesimulationflags: DWRITE_FONT_SIMULATIONS, fontface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory", "CreateFontFace", 9usize, |this| ((**(this as *const *const IDWriteFactory_Vtbl)).CreateFontFace)(this, fontfacetype, numberoffiles, fontfiles, faceindex, fontfacesimulationflags, fontface), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRenderingParams(this: *mut core::ffi::c_void, renderingparams: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory", "CreateRenderingParams", 10usize, |this| ((**(this as *const *const IDWriteFactory_Vtbl)).CreateRenderingParams)(this, renderingparams), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn CreateMonitorRenderingParams(this: *mut core::ffi::c_void, monitor: super::Gdi::HMONITOR, renderingparams: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory", "CreateMonitorRenderingParams", 11usize, |this| ((**(this as *const *const IDWriteFactory_Vtbl)).CreateMonitorRenderingParams)(this, monitor, renderingparams), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCustomRenderingParams(this: *mut core::ffi::c_void, gamma: f32, enhancedcontrast: f32, cleartypelevel: f32, pixelgeometry: DWRITE_PIXEL_GEOMETRY, renderingmode: DWRITE_RENDERING_MODE, renderingparams: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory", "CreateCustomRenderingParams", 12usize, |this| ((**(this as *const *const IDWriteFactory_Vtbl)).CreateCustomRenderingParams)(this, gamma, enhancedcontrast, cleartypelevel, pixelgeometry, renderingmode, renderingparams), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterFontFileLoader(this: *mut core::ffi::c_void, fontfileloader: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory", "RegisterFontFileLoader", 13usize, |this| ((**(this as *const *const IDWriteFactory_Vtbl)).RegisterFontFileLoader)(this, fontfileloader), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterFontFileLoader(this: *mut core::ffi::c_void, fontfileloader: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory", "UnregisterFontFileLoader", 14usize, |this| ((**(this as *const *const IDWriteFactory_Vtbl)).UnregisterFontFileLoader)(this, fontfileloader), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTextFormat(this: *mut core::ffi::c_void, fontfamilyname: windows_core::PCWSTR, fontcollection: *mut core::ffi::c_void, fontweight: DWRITE_FONT_WEIGHT, fontstyle: DWRITE_FONT_STYLE, fontstretch: DWRITE_FONT_STRETCH, fontsize: f32, localename: windows_core::PCWSTR, textformat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory", "CreateTextFormat", 15usize, |this| ((**(this as *const *const IDWriteFactory_Vtbl)).CreateTextFormat)(this, fontfamilyname, fontcollection, fontweight, fontstyle, fontstretch, fontsize, localename, textformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTypography(this: *mut core::ffi::c_void, typography: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory", "CreateTypography", 16usize, |this| ((**(this as *const *const IDWriteFactory_Vtbl)).CreateTypography)(this, typography), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGdiInterop(this: *mut core::ffi::c_void, gdiinterop: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory", "GetGdiInterop", 17usize, |this| ((**(this as *const *const IDWriteFactory_Vtbl)).GetGdiInterop)(this, gdiinterop), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTextLayout(this: *mut core::ffi::c_void, string: windows_core::PCWSTR, stringlength: u32, textformat: *mut core::ffi::c_void, maxwidth: f32, maxheight: f32, textlayout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory", "CreateTextLayout", 18usize, |this| ((**(this as *const *const IDWriteFactory_Vtbl)).CreateTextLayout)(this, string, stringlength, textformat, maxwidth, maxheight, textlayout), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateGdiCompatibleTextLayout(this: *mut core::ffi::c_void, string: windows_core::PCWSTR, stringlength: u32, textformat: *mut core::ffi::c_void, layoutwidth: f32, layoutheight: f32, pixelsperdip: f32, transform: *const DWRITE_MATRIX, usegdinatural: super::super::Foundation::BOOL, textlayout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory", "CreateGdiCompatibleTextLayout", 19usize, |this| ((**(this as *const *const IDWriteFactory_Vtbl)).CreateGdiCompatibleTextLayout)(this, string, stringlength, textformat, layoutwidth, layoutheight, pixelsperdip, transform, usegdinatural, textlayout), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateEllipsisTrimmingSign(this: *mut core::ffi::c_void, textformat: *mut core::ffi::c_void, trimmingsign: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory", "CreateEllipsisTrimmingSign", 20usize, |this| ((**(this as *const *const IDWriteFactory_Vtbl)).CreateEllipsisTrimmingSign)(this, textformat, trimmingsign), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTextAnalyzer(this: *mut core::ffi::c_void, textanalyzer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory", "CreateTextAnalyzer", 21usize, |this| ((**(this as *const *const IDWriteFactory_Vtbl)).CreateTextAnalyzer)(this, textanalyzer), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateNumberSubstitution(this: *mut core::ffi::c_void, substitutionmethod: DWRITE_NUMBER_SUBSTITUTION_METHOD, localename: windows_core::PCWSTR, ignoreuseroverride: super::super::Foundation::BOOL, numbersubstitution: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory", "CreateNumberSubstitution", 22usize, |this| ((**(this as *const *const IDWriteFactory_Vtbl)).CreateNumberSubstitution)(this, substitutionmethod, localename, ignoreuseroverride, numbersubstitution), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateGlyphRunAnalysis(this: *mut core::ffi::c_void, glyphrun: *const DWRITE_GLYPH_RUN, pixelsperdip: f32, transform: *const DWRITE_MATRIX, renderingmode: DWRITE_RENDERING_MODE, measuringmode: DWRITE_MEASURING_MODE, baselineoriginx: f32, baselineoriginy: f32, glyphrunanalysis: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory", "CreateGlyphRunAnalysis", 23usize, |this| ((**(this as *const *const IDWriteFactory_Vtbl)).CreateGlyphRunAnalysis)(this, glyphrun, pixelsperdip, transform, renderingmode, measuringmode, baselineoriginx, baselineoriginy, glyphrunanalysis), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSystemFontCollection,
+            CreateCustomFontCollection,
+            RegisterFontCollectionLoader,
+            UnregisterFontCollectionLoader,
+            CreateFontFileReference,
+            CreateCustomFontFileReference,
+            CreateFontFace,
+            CreateRenderingParams,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            CreateMonitorRenderingParams,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            CreateMonitorRenderingParams: 0,
+            CreateCustomRenderingParams,
+            RegisterFontFileLoader,
+            UnregisterFontFileLoader,
+            CreateTextFormat,
+            CreateTypography,
+            GetGdiInterop,
+            CreateTextLayout,
+            CreateGdiCompatibleTextLayout,
+            CreateEllipsisTrimmingSign,
+            CreateTextAnalyzer,
+            CreateNumberSubstitution,
+            CreateGlyphRunAnalysis,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IDWriteFactory1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEudcFontCollection(this: *mut core::ffi::c_void, fontcollection: *mut *mut core::ffi::c_void, checkforupdates: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory1", "GetEudcFontCollection", 24usize, |this| ((**(this as *const *const IDWriteFactory1_Vtbl)).GetEudcFontCollection)(this, fontcollection, checkforupdates), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCustomRenderingParams(this: *mut core::ffi::c_void, gamma: f32, enhancedcontrast: f32, enhancedcontrastgrayscale: f32, cleartypelevel: f32, pixelgeometry: DWRITE_PIXEL_GEOMETRY, renderingmode: DWRITE_RENDERING_MODE, renderingparams: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory1", "CreateCustomRenderingParams", 25usize, |this| ((**(this as *const *const IDWriteFactory1_Vtbl)).CreateCustomRenderingParams)(this, gamma, enhancedcontrast, enhancedcontrastgrayscale, cleartypelevel, pixelgeometry, renderingmode, renderingparams), |result| Some(*result))
+        }
+        Self { base__: <IDWriteFactory_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetEudcFontCollection, CreateCustomRenderingParams }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFactory1 as windows_core::Interface>::IID || <IDWriteFactory_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IDWriteFactory2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSystemFontFallback(this: *mut core::ffi::c_void, fontfallback: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory2", "GetSystemFontFallback", 26usize, |this| ((**(this as *const *const IDWriteFactory2_Vtbl)).GetSystemFontFallback)(this, fontfallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFontFallbackBuilder(this: *mut core::ffi::c_void, fontfallbackbuilder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory2", "CreateFontFallbackBuilder", 27usize, |this| ((**(this as *const *const IDWriteFactory2_Vtbl)).CreateFontFallbackBuilder)(this, fontfallbackbuilder), |result| Some(*result))
+        }
+        unsafe extern "system" fn TranslateColorGlyphRun(this: *mut core::ffi::c_void, baselineoriginx: f32, baselineoriginy: f32, glyphrun: *const DWRITE_GLYPH_RUN, glyphrundescription: *const DWRITE_GLYPH_RUN_DESCRIPTION, measuringmode: DWRITE_MEASURING_MODE, worldtodevicetransform: *const DWRITE_MATRIX, colorpaletteindex: u32, colorlayers: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory2", "TranslateColorGlyphRun", 28usize, |this| ((**(this as *const *const IDWriteFactory2_Vtbl)).TranslateColorGlyphRun)(this, baselineoriginx, baselineoriginy, glyphrun, glyphrundescription, measuringmode, worldtodevicetransform, colorpaletteindex, colorlayers), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCustomRenderingParams(this: *mut core::ffi::c_void, gamma: f32, enhancedcontrast: f32, grayscaleenhancedcontrast: f32, cleartypelevel: f32, pixelgeometry: DWRITE_PIXEL_GEOMETRY, renderingmode: DWRITE_RENDERING_MODE, gridfitmode: DWRITE_GRID_FIT_MODE, renderingparams: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory2", "CreateCustomRenderingParams", 29usize, |this| ((**(this as *const *const IDWriteFactory2_Vtbl)).CreateCustomRenderingParams)(this, gamma, enhancedcontrast, grayscaleenhancedcontrast, cleartypelevel, pixelgeometry, renderingmode, gridfitmode, renderingparams), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateGlyphRunAnalysis(this: *mut core::ffi::c_void, glyphrun: *const DWRITE_GLYPH_RUN, transform: *const DWRITE_MATRIX, renderingmode: DWRITE_RENDERING_MODE, measuringmode: DWRITE_MEASURING_MODE, gridfitmode: DWRITE_GRID_FIT_MODE, antialiasmode: DWRITE_TEXT_ANTIALIAS_MODE, baselineoriginx: f32, baselineoriginy: f32, glyphrunanalysis: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory2", "CreateGlyphRunAnalysis", 30usize, |this| ((**(this as *const *const IDWriteFactory2_Vtbl)).CreateGlyphRunAnalysis)(this, glyphrun, transform, renderingmode, measuringmode, gridfitmode, antialiasmode, baselineoriginx, baselineoriginy, glyphrunanalysis), |result| Some(*result))
+        }
+        Self {
+            base__: <IDWriteFactory1_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSystemFontFallback,
+            CreateFontFallbackBuilder,
+            TranslateColorGlyphRun,
+            CreateCustomRenderingParams,
+            CreateGlyphRunAnalysis,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFactory2 as windows_core::Interface>::IID || <IDWriteFactory1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IDWriteFactory3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateGlyphRunAnalysis(this: *mut core::ffi::c_void, glyphrun: *const DWRITE_GLYPH_RUN, transform: *const DWRITE_MATRIX, renderingmode: DWRITE_RENDERING_MODE1, measuringmode: DWRITE_MEASURING_MODE, gridfitmode: DWRITE_GRID_FIT_MODE, antialiasmode: DWRITE_TEXT_ANTIALIAS_MODE, baselineoriginx: f32, baselineoriginy: f32, glyphrunanalysis: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory3", "CreateGlyphRunAnalysis", 31usize, |this| ((**(this as *const *const IDWriteFactory3_Vtbl)).CreateGlyphRunAnalysis)(this, glyphrun, transform, renderingmode, measuringmode, gridfitmode, antialiasmode, baselineoriginx, baselineoriginy, glyphrunanalysis), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCustomRenderingParams(this: *mut core::ffi::c_void, gamma: f32, enhancedcontrast: f32, grayscaleenhancedcontrast: f32, cleartypelevel: f32, pixelgeometry: DWRITE_PIXEL_GEOMETRY, renderingmode: DWRITE_RENDERING_MODE1, gridfitmode: DWRITE_GRID_FIT_MODE, renderingparams: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory3", "CreateCustomRenderingParams", 32usize, |this| ((**(this as *const *const IDWriteFactory3_Vtbl)).CreateCustomRenderingParams)(this, gamma, enhancedcontrast, grayscaleenhancedcontrast, cleartypelevel, pixelgeometry, renderingmode, gridfitmode, renderingparams), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFontFaceReference(this: *mut core::ffi::c_void, fontfile: *mut core::ffi::c_void, faceindex: u32, fontsimulations: DWRITE_FONT_SIMULATIONS, fontfacereference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory3", "CreateFontFaceReference", 33usize, |this| ((**(this as *const *const IDWriteFactory3_Vtbl)).CreateFontFaceReference)(this, fontfile, faceindex, fontsimulations, fontfacereference), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFontFaceReference2(this: *mut core::ffi::c_void, filepath: windows_core::PCWSTR, lastwritetime: *const super::super::Foundation::FILETIME, faceindex: u32, fontsimulations: DWRITE_FONT_SIMULATIONS, fontfacereference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory3", "CreateFontFaceReference2", 34usize, |this| ((**(this as *const *const IDWriteFactory3_Vtbl)).CreateFontFaceReference2)(this, filepath, lastwritetime, faceindex, fontsimulations, fontfacereference), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSystemFontSet(this: *mut core::ffi::c_void, fontset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory3", "GetSystemFontSet", 35usize, |this| ((**(this as *const *const IDWriteFactory3_Vtbl)).GetSystemFontSet)(this, fontset), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFontSetBuilder(this: *mut core::ffi::c_void, fontsetbuilder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory3", "CreateFontSetBuilder", 36usize, |this| ((**(this as *const *const IDWriteFactory3_Vtbl)).CreateFontSetBuilder)(this, fontsetbuilder), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFontCollectionFromFontSet(this: *mut core::ffi::c_void, fontset: *mut core::ffi::c_void, fontcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory3", "CreateFontCollectionFromFontSet", 37usize, |this| ((**(this as *const *const IDWriteFactory3_Vtbl)).CreateFontCollectionFromFontSet)(this, fontset, fontcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSystemFontCollection(this: *mut core::ffi::c_void, includedownloadablefonts: super::super::Foundation::BOOL, fontcollection: *mut *mut core::ffi::c_void, checkforupdates: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory3", "GetSystemFontCollection", 38usize, |this| ((**(this as *const *const IDWriteFactory3_Vtbl)).GetSystemFontCollection)(this, includedownloadablefonts, fontcollection, checkforupdates), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontDownloadQueue(this: *mut core::ffi::c_void, fontdownloadqueue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory3", "GetFontDownloadQueue", 39usize, |this| ((**(this as *const *const IDWriteFactory3_Vtbl)).GetFontDownloadQueue)(this, fontdownloadqueue), |result| Some(*result))
+        }
+        Self {
+            base__: <IDWriteFactory2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateGlyphRunAnalysis,
+            CreateCustomRenderingParams,
+            CreateFontFaceReference,
+            CreateFontFaceReference2,
+            GetSystemFontSet,
+            CreateFontSetBuilder,
+            CreateFontCollectionFromFontSet,
+            GetSystemFontCollection,
+            GetFontDownloadQueue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFactory3 as windows_core::Interface>::IID || <IDWriteFactory2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Gdi"))]
+impl windows_core::imp::ProxyVtable for IDWriteFactory4_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn TranslateColorGlyphRun(this: *mut core::ffi::c_void, baselineorigin: super::Direct2D::Common::D2D_POINT_2F, glyphrun: *const DWRITE_GLYPH_RUN, glyphrundescription: *const DWRITE_GLYPH_RUN_DESCRIPTION, desiredglyphimageformats: DWRITE_GLYPH_IMAGE_FORMATS, measuringmode: DWRITE_MEASURING_MODE, worldanddpitransform: *const DWRITE_MATRIX, colorpaletteindex: u32, colorlayers: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory4", "TranslateColorGlyphRun", 40usize, |this| ((**(this as *const *const IDWriteFactory4_Vtbl)).TranslateColorGlyphRun)(this, baselineorigin, glyphrun, glyphrundescription, desiredglyphimageformats, measuringmode, worldanddpitransform, colorpaletteindex, colorlayers), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn ComputeGlyphOrigins(this: *mut core::ffi::c_void, glyphrun: *const DWRITE_GLYPH_RUN, baselineorigin: super::Direct2D::Common::D2D_POINT_2F, glyphorigins: *mut super::Direct2D::Common::D2D_POINT_2F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory4", "ComputeGlyphOrigins", 41usize, |this| ((**(this as *const *const IDWriteFactory4_Vtbl)).ComputeGlyphOrigins)(this, glyphrun, baselineorigin, glyphorigins), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn ComputeGlyphOrigins2(this: *mut core::ffi::c_void, glyphrun: *const DWRITE_GLYPH_RUN, measuringmode: DWRITE_MEASURING_MODE, baselineorigin: super::Direct2D::Common::D2D_POINT_2F, worldanddpitransform: *const DWRITE_MATRIX, glyphorigins: *mut super::Direct2D::Common::D2D_POINT_2F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory4", "ComputeGlyphOrigins2", 42usize, |this| ((**(this as *const *const IDWriteFactory4_Vtbl)).ComputeGlyphOrigins2)(this, glyphrun, measuringmode, baselineorigin, worldanddpitransform, glyphorigins), |result| Some(*result))
+        }
+        Self {
+            base__: <IDWriteFactory3_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            TranslateColorGlyphRun,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            TranslateColorGlyphRun: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            ComputeGlyphOrigins,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            ComputeGlyphOrigins: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            ComputeGlyphOrigins2,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            ComputeGlyphOrigins2: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFactory4 as windows_core::Interface>::IID || <IDWriteFactory3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Gdi"))]
+impl windows_core::imp::ProxyVtable for IDWriteFactory5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFontSetBuilder(this: *mut core::ffi::c_void, fontsetbuilder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory5", "CreateFontSetBuilder", 43usize, |this| ((**(this as *const *const IDWriteFactory5_Vtbl)).CreateFontSetBuilder)(this, fontsetbuilder), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInMemoryFontFileLoader(this: *mut core::ffi::c_void, newloader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory5", "CreateInMemoryFontFileLoader", 44usize, |this| ((**(this as *const *const IDWriteFactory5_Vtbl)).CreateInMemoryFontFileLoader)(this, newloader), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateHttpFontFileLoader(this: *mut core::ffi::c_void, referrerurl: windows_core::PCWSTR, extraheaders: windows_core::PCWSTR, newloader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory5", "CreateHttpFontFileLoader", 45usize, |this| ((**(this as *const *const IDWriteFactory5_Vtbl)).CreateHttpFontFileLoader)(this, referrerurl, extraheaders, newloader), |result| Some(*result))
+        }
+        unsafe extern "system" fn AnalyzeContainerType(this: *mut core::ffi::c_void, filedata: *const core::ffi::c_void, filedatasize: u32) -> DWRITE_CONTAINER_TYPE {
+            windows_core::imp::proxy_call(this, "IDWriteFactory5", "AnalyzeContainerType", 46usize, |this| ((**(this as *const *const IDWriteFactory5_Vtbl)).AnalyzeContainerType)(this, filedata, filedatasize), |_| None)
+        }
+        unsafe extern "system" fn UnpackFontFile(this: *mut core::ffi::c_void, containertype: DWRITE_CONTAINER_TYPE, filedata: *const core::ffi::c_void, filedatasize: u32, unpackedfontstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory5", "UnpackFontFile", 47usize, |this| ((**(this as *const *const IDWriteFactory5_Vtbl)).UnpackFontFile)(this, containertype, filedata, filedatasize, unpackedfontstream), |result| Some(*result))
+        }
+        Self {
+            base__: <IDWriteFactory4_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateFontSetBuilder,
+            CreateInMemoryFontFileLoader,
+            CreateHttpFontFileLoader,
+            AnalyzeContainerType,
+            UnpackFontFile,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFactory5 as windows_core::Interface>::IID || <IDWriteFactory4_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Gdi"))]
+impl windows_core::imp::ProxyVtable for IDWriteFactory6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFontFaceReference(this: *mut core::ffi::c_void, fontfile: *mut core::ffi::c_void, faceindex: u32, fontsimulations: DWRITE_FONT_SIMULATIONS, fontaxisvalues: *const DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32, fontfacereference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory6", "CreateFontFaceReference", 48usize, |this| ((**(this as *const *const IDWriteFactory6_Vtbl)).CreateFontFaceReference)(this, fontfile, faceindex, fontsimulations, fontaxisvalues, fontaxisvaluecount, fontfacereference), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFontResource(this: *mut core::ffi::c_void, fontfile: *mut core::ffi::c_void, faceindex: u32, fontresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory6", "CreateFontResource", 49usize, |this| ((**(this as *const *const IDWriteFactory6_Vtbl)).CreateFontResource)(this, fontfile, faceindex, fontresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSystemFontSet(this: *mut core::ffi::c_void, includedownloadablefonts: super::super::Foundation::BOOL, fontset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory6", "GetSystemFontSet", 50usize, |this| ((**(this as *const *const IDWriteFactory6_Vtbl)).GetSystemFontSet)(this, includedownloadablefonts, fontset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSystemFontCollection(this: *mut core::ffi::c_void, includedownloadablefonts: super::super::Foundation::BOOL, fontfamilymodel: DWRITE_FONT_FAMILY_MODEL, fontcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory6", "GetSystemFontCollection", 51usize, |this| ((**(this as *const *const IDWriteFactory6_Vtbl)).GetSystemFontCollection)(this, includedownloadablefonts, fontfamilymodel, fontcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFontCollectionFromFontSet(this: *mut core::ffi::c_void, fontset: *mut core::ffi::c_void, fontfamilymodel: DWRITE_FONT_FAMILY_MODEL, fontcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory6", "CreateFontCollectionFromFontSet", 52usize, |this| ((**(this as *const *const IDWriteFactory6_Vtbl)).CreateFontCollectionFromFontSet)(this, fontset, fontfamilymodel, fontcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFontSetBuilder(this: *mut core::ffi::c_void, fontsetbuilder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory6", "CreateFontSetBuilder", 53usize, |this| ((**(this as *const *const IDWriteFactory6_Vtbl)).CreateFontSetBuilder)(this, fontsetbuilder), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTextFormat(this: *mut core::ffi::c_void, fontfamilyname: windows_core::PCWSTR, fontcollection: *mut core::ffi::c_void, fontaxisvalues: *const DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32, fontsize: f32, localename: windows_core::PCWSTR, textformat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory6", "CreateTextFormat", 54usize, |this| ((**(this as *const *const IDWriteFactory6_Vtbl)).CreateTextFormat)(this, fontfamilyname, fontcollection, fontaxisvalues, fontaxisvaluecount, fontsize, localename, textformat), |result| Some(*result))
+        }
+        Self {
+            base__: <IDWriteFactory5_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateFontFaceReference,
+            CreateFontResource,
+            GetSystemFontSet,
+            GetSystemFontCollection,
+            CreateFontCollectionFromFontSet,
+            CreateFontSetBuilder,
+            CreateTextFormat,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFactory6 as windows_core::Interface>::IID || <IDWriteFactory5_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Gdi"))]
+impl windows_core::imp::ProxyVtable for IDWriteFactory7_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSystemFontSet(this: *mut core::ffi::c_void, includedownloadablefonts: super::super::Foundation::BOOL, fontset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory7", "GetSystemFontSet", 55usize, |this| ((**(this as *const *const IDWriteFactory7_Vtbl)).GetSystemFontSet)(this, includedownloadablefonts, fontset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSystemFontCollection(this: *mut core::ffi::c_void, includedownloadablefonts: super::super::Foundation::BOOL, fontfamilymodel: DWRITE_FONT_FAMILY_MODEL, fontcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory7", "GetSystemFontCollection", 56usize, |this| ((**(this as *const *const IDWriteFactory7_Vtbl)).GetSystemFontCollection)(this, includedownloadablefonts, fontfamilymodel, fontcollection), |result| Some(*result))
+        }
+        Self { base__: <IDWriteFactory6_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSystemFontSet, GetSystemFontCollection }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFactory7 as windows_core::Interface>::IID || <IDWriteFactory6_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Gdi"))]
+impl windows_core::imp::ProxyVtable for IDWriteFactory8_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn TranslateColorGlyphRun(this: *mut core::ffi::c_void, baselineorigin: super::Direct2D::Common::D2D_POINT_2F, glyphrun: *const DWRITE_GLYPH_RUN, glyphrundescription: *const DWRITE_GLYPH_RUN_DESCRIPTION, desiredglyphimageformats: DWRITE_GLYPH_IMAGE_FORMATS, paintfeaturelevel: DWRITE_PAINT_FEATURE_LEVEL, measuringmode: DWRITE_MEASURING_MODE, worldanddpitransform: *const DWRITE_MATRIX, colorpaletteindex: u32, colorenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFactory8", "TranslateColorGlyphRun", 57usize, |this| ((**(this as *const *const IDWriteFactory8_Vtbl)).TranslateColorGlyphRun)(this, baselineorigin, glyphrun, glyphrundescription, desiredglyphimageformats, paintfeaturelevel, measuringmode, worldanddpitransform, colorpaletteindex, colorenumerator), |result| Some(*result))
+        }
+        Self {
+            base__: <IDWriteFactory7_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            TranslateColorGlyphRun,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            TranslateColorGlyphRun: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFactory8 as windows_core::Interface>::IID || <IDWriteFactory7_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFont_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFontFamily(this: *mut core::ffi::c_void, fontfamily: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFont", "GetFontFamily", 3usize, |this| ((**(this as *const *const IDWriteFont_Vtbl)).GetFontFamily)(this, fontfamily), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWeight(this: *mut core::ffi::c_void) -> DWRITE_FONT_WEIGHT {
+            windows_core::imp::proxy_call(this, "IDWriteFont", "GetWeight", 4usize, |this| ((**(this as *const *const IDWriteFont_Vtbl)).GetWeight)(this), |_| None)
+        }
+        unsafe extern "system" fn GetStretch(this: *mut core::ffi::c_void) -> DWRITE_FONT_STRETCH {
+            windows_core::imp::proxy_call(this, "IDWriteFont", "GetStretch", 5usize, |this| ((**(this as *const *const IDWriteFont_Vtbl)).GetStretch)(this), |_| None)
+        }
+        unsafe extern "system" fn GetStyle(this: *mut core::ffi::c_void) -> DWRITE_FONT_STYLE {
+            windows_core::imp::proxy_call(this, "IDWriteFont", "GetStyle", 6usize, |this| ((**(this as *const *const IDWriteFont_Vtbl)).GetStyle)(this), |_| None)
+        }
+        unsafe extern "system" fn IsSymbolFont(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDWriteFont", "IsSymbolFont", 7usize, |this| ((**(this as *const *const IDWriteFont_Vtbl)).IsSymbolFont)(this), |_| None)
+        }
+        unsafe extern "system" fn GetFaceNames(this: *mut core::ffi::c_void, names: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFont", "GetFaceNames", 8usize, |this| ((**(this as *const *const IDWriteFont_Vtbl)).GetFaceNames)(this, names), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInformationalStrings(this: *mut core::ffi::c_void, informationalstringid: DWRITE_INFORMATIONAL_STRING_ID, informationalstrings: *mut *mut core::ffi::c_void, exists: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFont", "GetInformationalStrings", 9usize, |this| ((**(this as *const *const IDWriteFont_Vtbl)).GetInformationalStrings)(this, informationalstringid, informationalstrings, exists), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSimulations(this: *mut core::ffi::c_void) -> DWRITE_FONT_SIMULATIONS {
+            windows_core::imp::proxy_call(this, "IDWriteFont", "GetSimulations", 10usize, |this| ((**(this as *const *const IDWriteFont_Vtbl)).GetSimulations)(this), |_| None)
+        }
+        unsafe extern "system" fn GetMetrics(this: *mut core::ffi::c_void, fontmetrics: *mut DWRITE_FONT_METRICS) {
+            windows_core::imp::proxy_call(this, "IDWriteFont", "GetMetrics", 11usize, |this| ((**(this as *const *const IDWriteFont_Vtbl)).GetMetrics)(this, fontmetrics), |_| None)
+        }
+        unsafe extern "system" fn HasCharacter(this: *mut core::ffi::c_void, unicodevalue: u32, exists: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFont", "HasCharacter", 12usize, |this| ((**(this as *const *const IDWriteFont_Vtbl)).HasCharacter)(this, unicodevalue, exists), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFontFace(this: *mut core::ffi::c_void, fontface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFont", "CreateFontFace", 13usize, |this| ((**(this as *const *const IDWriteFont_Vtbl)).CreateFontFace)(this, fontface), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetFontFamily,
+            GetWeight,
+            GetStretch,
+            GetStyle,
+            IsSymbolFont,
+            GetFaceNames,
+            GetInformationalStrings,
+            GetSimulations,
+            GetMetrics,
+            HasCharacter,
+            CreateFontFace,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFont as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFont1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMetrics(this: *mut core::ffi::c_void, fontmetrics: *mut DWRITE_FONT_METRICS1) {
+            windows_core::imp::proxy_call(this, "IDWriteFont1", "GetMetrics", 14usize, |this| ((**(this as *const *const IDWriteFont1_Vtbl)).GetMetrics)(this, fontmetrics), |_| None)
+        }
+        unsafe extern "system" fn GetPanose(this: *mut core::ffi::c_void, panose: *mut DWRITE_PANOSE) {
+            windows_core::imp::proxy_call(this, "IDWriteFont1", "GetPanose", 15usize, |this| ((**(this as *const *const IDWriteFont1_Vtbl)).GetPanose)(this, panose), |_| None)
+        }
+        unsafe extern "system" fn GetUnicodeRanges(this: *mut core::ffi::c_void, maxrangecount: u32, unicoderanges: *mut DWRITE_UNICODE_RANGE, actualrangecount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFont1", "GetUnicodeRanges", 16usize, |this| ((**(this as *const *const IDWriteFont1_Vtbl)).GetUnicodeRanges)(this, maxrangecount, unicoderanges, actualrangecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMonospacedFont(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDWriteFont1", "IsMonospacedFont", 17usize, |this| ((**(this as *const *const IDWriteFont1_Vtbl)).IsMonospacedFont)(this), |_| None)
+        }
+        Self { base__: <IDWriteFont_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMetrics, GetPanose, GetUnicodeRanges, IsMonospacedFont }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFont1 as windows_core::Interface>::IID || <IDWriteFont_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFont2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsColorFont(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDWriteFont2", "IsColorFont", 18usize, |this| ((**(this as *const *const IDWriteFont2_Vtbl)).IsColorFont)(this), |_| None)
+        }
+        Self { base__: <IDWriteFont1_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsColorFont }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFont2 as windows_core::Interface>::IID || <IDWriteFont1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFont3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFontFace(this: *mut core::ffi::c_void, fontface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFont3", "CreateFontFace", 19usize, |this| ((**(this as *const *const IDWriteFont3_Vtbl)).CreateFontFace)(this, fontface), |result| Some(*result))
+        }
+        unsafe extern "system" fn Equals(this: *mut core::ffi::c_void, font: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDWriteFont3", "Equals", 20usize, |this| ((**(this as *const *const IDWriteFont3_Vtbl)).Equals)(this, font), |_| None)
+        }
+        unsafe extern "system" fn GetFontFaceReference(this: *mut core::ffi::c_void, fontfacereference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFont3", "GetFontFaceReference", 21usize, |this| ((**(this as *const *const IDWriteFont3_Vtbl)).GetFontFaceReference)(this, fontfacereference), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasCharacter(this: *mut core::ffi::c_void, unicodevalue: u32) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDWriteFont3", "HasCharacter", 22usize, |this| ((**(this as *const *const IDWriteFont3_Vtbl)).HasCharacter)(this, unicodevalue), |_| None)
+        }
+        unsafe extern "system" fn GetLocality(this: *mut core::ffi::c_void) -> DWRITE_LOCALITY {
+            windows_core::imp::proxy_call(this, "IDWriteFont3", "GetLocality", 23usize, |this| ((**(this as *const *const IDWriteFont3_Vtbl)).GetLocality)(this), |_| None)
+        }
+        Self { base__: <IDWriteFont2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFontFace, Equals, GetFontFaceReference, HasCharacter, GetLocality }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFont3 as windows_core::Interface>::IID || <IDWriteFont2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFontFamilyCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDWriteFontCollection", "GetFontFamilyCount", 3usize, |this| ((**(this as *const *const IDWriteFontCollection_Vtbl)).GetFontFamilyCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetFontFamily(this: *mut core::ffi::c_void, index: u32, fontfamily: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontCollection", "GetFontFamily", 4usize, |this| ((**(this as *const *const IDWriteFontCollection_Vtbl)).GetFontFamily)(this, index, fontfamily), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindFamilyName(this: *mut core::ffi::c_void, familyname: windows_core::PCWSTR, index: *mut u32, exists: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontCollection", "FindFamilyName", 5usize, |this| ((**(this as *const *const IDWriteFontCollection_Vtbl)).FindFamilyName)(this, familyname, index, exists), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontFromFontFace(this: *mut core::ffi::c_void, fontface: *mut core::ffi::c_void, font: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontCollection", "GetFontFromFontFace", 6usize, |this| ((**(this as *const *const IDWriteFontCollection_Vtbl)).GetFontFromFontFace)(this, fontface, font), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetFontFamilyCount,
+            GetFontFamily,
+            FindFamilyName,
+            GetFontFromFontFace,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontCollection1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFontSet(this: *mut core::ffi::c_void, fontset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontCollection1", "GetFontSet", 7usize, |this| ((**(this as *const *const IDWriteFontCollection1_Vtbl)).GetFontSet)(this, fontset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontFamily(this: *mut core::ffi::c_void, index: u32, fontfamily: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontCollection1", "GetFontFamily", 8usize, |this| ((**(this as *const *const IDWriteFontCollection1_Vtbl)).GetFontFamily)(this, index, fontfamily), |result| Some(*result))
+        }
+        Self { base__: <IDWriteFontCollection_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFontSet, GetFontFamily }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontCollection1 as windows_core::Interface>::IID || <IDWriteFontCollection_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontCollection2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFontFamily(this: *mut core::ffi::c_void, index: u32, fontfamily: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontCollection2", "GetFontFamily", 9usize, |this| ((**(this as *const *const IDWriteFontCollection2_Vtbl)).GetFontFamily)(this, index, fontfamily), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMatchingFonts(this: *mut core::ffi::c_void, familyname: windows_core::PCWSTR, fontaxisvalues: *const DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32, fontlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontCollection2", "GetMatchingFonts", 10usize, |this| ((**(this as *const *const IDWriteFontCollection2_Vtbl)).GetMatchingFonts)(this, familyname, fontaxisvalues, fontaxisvaluecount, fontlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontFamilyModel(this: *mut core::ffi::c_void) -> DWRITE_FONT_FAMILY_MODEL {
+            windows_core::imp::proxy_call(this, "IDWriteFontCollection2", "GetFontFamilyModel", 11usize, |this| ((**(this as *const *const IDWriteFontCollection2_Vtbl)).GetFontFamilyModel)(this), |_| None)
+        }
+        unsafe extern "system" fn GetFontSet(this: *mut core::ffi::c_void, fontset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontCollection2", "GetFontSet", 12usize, |this| ((**(this as *const *const IDWriteFontCollection2_Vtbl)).GetFontSet)(this, fontset), |result| Some(*result))
+        }
+        Self { base__: <IDWriteFontCollection1_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFontFamily, GetMatchingFonts, GetFontFamilyModel, GetFontSet }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontCollection2 as windows_core::Interface>::IID || <IDWriteFontCollection1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontCollection3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetExpirationEvent(this: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            windows_core::imp::proxy_call(this, "IDWriteFontCollection3", "GetExpirationEvent", 13usize, |this| ((**(this as *const *const IDWriteFontCollection3_Vtbl)).GetExpirationEvent)(this), |_| None)
+        }
+        Self { base__: <IDWriteFontCollection2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetExpirationEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontCollection3 as windows_core::Interface>::IID || <IDWriteFontCollection2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontCollectionLoader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateEnumeratorFromKey(this: *mut core::ffi::c_void, factory: *mut core::ffi::c_void, collectionkey: *const core::ffi::c_void, collectionkeysize: u32, fontfileenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontCollectionLoader", "CreateEnumeratorFromKey", 3usize, |this| ((**(this as *const *const IDWriteFontCollectionLoader_Vtbl)).CreateEnumeratorFromKey)(this, factory, collectionkey, collectionkeysize, fontfileenumerator), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateEnumeratorFromKey }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontCollectionLoader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontDownloadListener_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DownloadCompleted(this: *mut core::ffi::c_void, downloadqueue: *mut core::ffi::c_void, context: *mut core::ffi::c_void, downloadresult: windows_core::HRESULT) {
+            windows_core::imp::proxy_call(this, "IDWriteFontDownloadListener", "DownloadCompleted", 3usize, |this| ((**(this as *const *const IDWriteFontDownloadListener_Vtbl)).DownloadCompleted)(this, downloadqueue, context, downloadresult), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DownloadCompleted }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontDownloadListener as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontDownloadQueue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddListener(this: *mut core::ffi::c_void, listener: *mut core::ffi::c_void, token: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontDownloadQueue", "AddListener", 3usize, |this| ((**(this as *const *const IDWriteFontDownloadQueue_Vtbl)).AddListener)(this, listener, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveListener(this: *mut core::ffi::c_void, token: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontDownloadQueue", "RemoveListener", 4usize, |this| ((**(this as *const *const IDWriteFontDownloadQueue_Vtbl)).RemoveListener)(this, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEmpty(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDWriteFontDownloadQueue", "IsEmpty", 5usize, |this| ((**(this as *const *const IDWriteFontDownloadQueue_Vtbl)).IsEmpty)(this), |_| None)
+        }
+        unsafe extern "system" fn BeginDownload(this: *mut core::ffi::c_void, context: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontDownloadQueue", "BeginDownload", 6usize, |this| ((**(this as *const *const IDWriteFontDownloadQueue_Vtbl)).BeginDownload)(this, context), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelDownload(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontDownloadQueue", "CancelDownload", 7usize, |this| ((**(this as *const *const IDWriteFontDownloadQueue_Vtbl)).CancelDownload)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGenerationCount(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "IDWriteFontDownloadQueue", "GetGenerationCount", 8usize, |this| ((**(this as *const *const IDWriteFontDownloadQueue_Vtbl)).GetGenerationCount)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddListener,
+            RemoveListener,
+            IsEmpty,
+            BeginDownload,
+            CancelDownload,
+            GetGenerationCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontDownloadQueue as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for IDWriteFontFace_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void) -> DWRITE_FONT_FACE_TYPE {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace", "GetType", 3usize, |this| ((**(this as *const *const IDWriteFontFace_Vtbl)).GetType)(this), |_| None)
+        }
+        unsafe extern "system" fn GetFiles(this: *mut core::ffi::c_void, numberoffiles: *mut u32, fontfiles: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace", "GetFiles", 4usize, |this| ((**(this as *const *const IDWriteFontFace_Vtbl)).GetFiles)(this, numberoffiles, fontfiles), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIndex(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace", "GetIndex", 5usize, |this| ((**(this as *const *const IDWriteFontFace_Vtbl)).GetIndex)(this), |_| None)
+        }
+        unsafe extern "system" fn GetSimulations(this: *mut core::ffi::c_void) -> DWRITE_FONT_SIMULATIONS {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace", "GetSimulations", 6usize, |this| ((**(this as *const *const IDWriteFontFace_Vtbl)).GetSimulations)(this), |_| None)
+        }
+        unsafe extern "system" fn IsSymbolFont(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace", "IsSymbolFont", 7usize, |this| ((**(this as *const *const IDWriteFontFace_Vtbl)).IsSymbolFont)(this), |_| None)
+        }
+        unsafe extern "system" fn GetMetrics(this: *mut core::ffi::c_void, fontfacemetrics: *mut DWRITE_FONT_METRICS) {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace", "GetMetrics", 8usize, |this| ((**(this as *const *const IDWriteFontFace_Vtbl)).GetMetrics)(this, fontfacemetrics), |_| None)
+        }
+        unsafe extern "system" fn GetGlyphCount(this: *mut core::ffi::c_void) -> u16 {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace", "GetGlyphCount", 9usize, |this| ((**(this as *const *const IDWriteFontFace_Vtbl)).GetGlyphCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetDesignGlyphMetrics(this: *mut core::ffi::c_void, glyphindices: *const u16, glyphcount: u32, glyphmetrics: *mut DWRITE_GLYPH_METRICS, issideways: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace", "GetDesignGlyphMetrics", 10usize, |this| ((**(this as *const *const IDWriteFontFace_Vtbl)).GetDesignGlyphMetrics)(this, glyphindices, glyphcount, glyphmetrics, issideways), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGlyphIndices(this: *mut core::ffi::c_void, codepoints: *const u32, codepointcount: u32, glyphindices: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace", "GetGlyphIndices", 11usize, |this| ((**(this as *const *const IDWriteFontFace_Vtbl)).GetGlyphIndices)(this, codepoints, codepointcount, glyphindices), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryGetFontTable(this: *mut core::ffi::c_void, opentypetabletag: u32, tabledata: *mut *mut core::ffi::c_void, tablesize: *mut u32, tablecontext: *mut *mut core::ffi::c_void, exists: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace", "TryGetFontTable", 12usize, |this| ((**(this as *const *const IDWriteFontFace_Vtbl)).TryGetFontTable)(this, opentypetabletag, tabledata, tablesize, tablecontext, exists), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseFontTable(this: *mut core::ffi::c_void, tablecontext: *const core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace", "ReleaseFontTable", 13usize, |this| ((**(this as *const *const IDWriteFontFace_Vtbl)).ReleaseFontTable)(this, tablecontext), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetGlyphRunOutline(this: *mut core::ffi::c_void, emsize: f32, glyphindices: *const u16, glyphadvances: *const f32, glyphoffsets: *const DWRITE_GLYPH_OFFSET, glyphcount: u32, issideways: super::super::Foundation::BOOL, isrighttoleft: super::super::Foundation::BOOL, geometrysink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace", "GetGlyphRunOutline", 14usize, |this| ((**(this as *const *const IDWriteFontFace_Vtbl)).GetGlyphRunOutline)(this, emsize, glyphindices, glyphadvances, glyphoffsets, glyphcount, issideways, isrighttoleft, geometrysink), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecommendedRenderingMode(this: *mut core::ffi::c_void, emsize: f32, pixelsperdip: f32, measuringmode: DWRITE_MEASURING_MODE, renderingparams: *mut core::ffi::c_void, renderingmode: *mut DWRITE_RENDERING_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace", "GetRecommendedRenderingMode", 15usize, |this| ((**(this as *const *const IDWriteFontFace_Vtbl)).GetRecommendedRenderingMode)(this, emsize, pixelsperdip, measuringmode, renderingparams, renderingmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGdiCompatibleMetrics(this: *mut core::ffi::c_void, emsize: f32, pixelsperdip: f32, transform: *const DWRITE_MATRIX, fontfacemetrics: *mut DWRITE_FONT_METRICS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace", "GetGdiCompatibleMetrics", 16usize, |this| ((**(this as *const *const IDWriteFontFace_Vtbl)).GetGdiCompatibleMetrics)(this, emsize, pixelsperdip, transform, fontfacemetrics), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGdiCompatibleGlyphMetrics(this: *mut core::ffi::c_void, emsize: f32, pixelsperdip: f32, transform: *const DWRITE_MATRIX, usegdinatural: super::super::Foundation::BOOL, glyphindices: *const u16, glyphcount: u32, glyphmetrics: *mut DWRITE_GLYPH_METRICS, issideways: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace", "GetGdiCompatibleGlyphMetrics", 17usize, |this| ((**(this as *const *const IDWriteFontFace_Vtbl)).GetGdiCompatibleGlyphMetrics)(this, emsize, pixelsperdip, transform, usegdinatural, glyphindices, glyphcount, glyphmetrics, issideways), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetType,
+            GetFiles,
+            GetIndex,
+            GetSimulations,
+            IsSymbolFont,
+            GetMetrics,
+            GetGlyphCount,
+            GetDesignGlyphMetrics,
+            GetGlyphIndices,
+            TryGetFontTable,
+            ReleaseFontTable,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetGlyphRunOutline,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetGlyphRunOutline: 0,
+            GetRecommendedRenderingMode,
+            GetGdiCompatibleMetrics,
+            GetGdiCompatibleGlyphMetrics,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontFace as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for IDWriteFontFace1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMetrics(this: *mut core::ffi::c_void, fontmetrics: *mut DWRITE_FONT_METRICS1) {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace1", "GetMetrics", 18usize, |this| ((**(this as *const *const IDWriteFontFace1_Vtbl)).GetMetrics)(this, fontmetrics), |_| None)
+        }
+        unsafe extern "system" fn GetGdiCompatibleMetrics(this: *mut core::ffi::c_void, emsize: f32, pixelsperdip: f32, transform: *const DWRITE_MATRIX, fontmetrics: *mut DWRITE_FONT_METRICS1) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace1", "GetGdiCompatibleMetrics", 19usize, |this| ((**(this as *const *const IDWriteFontFace1_Vtbl)).GetGdiCompatibleMetrics)(this, emsize, pixelsperdip, transform, fontmetrics), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCaretMetrics(this: *mut core::ffi::c_void, caretmetrics: *mut DWRITE_CARET_METRICS) {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace1", "GetCaretMetrics", 20usize, |this| ((**(this as *const *const IDWriteFontFace1_Vtbl)).GetCaretMetrics)(this, caretmetrics), |_| None)
+        }
+        unsafe extern "system" fn GetUnicodeRanges(this: *mut core::ffi::c_void, maxrangecount: u32, unicoderanges: *mut DWRITE_UNICODE_RANGE, actualrangecount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace1", "GetUnicodeRanges", 21usize, |this| ((**(this as *const *const IDWriteFontFace1_Vtbl)).GetUnicodeRanges)(this, maxrangecount, unicoderanges, actualrangecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMonospacedFont(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace1", "IsMonospacedFont", 22usize, |this| ((**(this as *const *const IDWriteFontFace1_Vtbl)).IsMonospacedFont)(this), |_| None)
+        }
+        unsafe extern "system" fn GetDesignGlyphAdvances(this: *mut core::ffi::c_void, glyphcount: u32, glyphindices: *const u16, glyphadvances: *mut i32, issideways: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace1", "GetDesignGlyphAdvances", 23usize, |this| ((**(this as *const *const IDWriteFontFace1_Vtbl)).GetDesignGlyphAdvances)(this, glyphcount, glyphindices, glyphadvances, issideways), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGdiCompatibleGlyphAdvances(this: *mut core::ffi::c_void, emsize: f32, pixelsperdip: f32, transform: *const DWRITE_MATRIX, usegdinatural: super::super::Foundation::BOOL, issideways: super::super::Foundation::BOOL, glyphcount: u32, glyphindices: *const u16, glyphadvances: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace1", "GetGdiCompatibleGlyphAdvances", 24usize, |this| ((**(this as *const *const IDWriteFontFace1_Vtbl)).GetGdiCompatibleGlyphAdvances)(this, emsize, pixelsperdip, transform, usegdinatural, issideways, glyphcount, glyphindices, glyphadvances), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKerningPairAdjustments(this: *mut core::ffi::c_void, glyphcount: u32, glyphindices: *const u16, glyphadvanceadjustments: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace1", "GetKerningPairAdjustments", 25usize, |this| ((**(this as *const *const IDWriteFontFace1_Vtbl)).GetKerningPairAdjustments)(this, glyphcount, glyphindices, glyphadvanceadjustments), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasKerningPairs(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace1", "HasKerningPairs", 26usize, |this| ((**(this as *const *const IDWriteFontFace1_Vtbl)).HasKerningPairs)(this), |_| None)
+        }
+        unsafe extern "system" fn GetRecommendedRenderingMode(this: *mut core::ffi::c_void, fontemsize: f32, dpix: f32, dpiy: f32, transform: *const DWRITE_MATRIX, issideways: super::super::Foundation::BOOL, outlinethreshold: DWRITE_OUTLINE_THRESHOLD, measuringmode: DWRITE_MEASURING_MODE, renderingmode: *mut DWRITE_RENDERING_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace1", "GetRecommendedRenderingMode", 27usize, |this| ((**(this as *const *const IDWriteFontFace1_Vtbl)).GetRecommendedRenderingMode)(this, fontemsize, dpix, dpiy, transform, issideways, outlinethreshold, measuringmode, renderingmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVerticalGlyphVariants(this: *mut core::ffi::c_void, glyphcount: u32, nominalglyphindices: *const u16, verticalglyphindices: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace1", "GetVerticalGlyphVariants", 28usize, |this| ((**(this as *const *const IDWriteFontFace1_Vtbl)).GetVerticalGlyphVariants)(this, glyphcount, nominalglyphindices, verticalglyphindices), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasVerticalGlyphVariants(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace1", "HasVerticalGlyphVariants", 29usize, |this| ((**(this as *const *const IDWriteFontFace1_Vtbl)).HasVerticalGlyphVariants)(this), |_| None)
+        }
+        Self {
+            base__: <IDWriteFontFace_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetMetrics,
+            GetGdiCompatibleMetrics,
+            GetCaretMetrics,
+            GetUnicodeRanges,
+            IsMonospacedFont,
+            GetDesignGlyphAdvances,
+            GetGdiCompatibleGlyphAdvances,
+            GetKerningPairAdjustments,
+            HasKerningPairs,
+            GetRecommendedRenderingMode,
+            GetVerticalGlyphVariants,
+            HasVerticalGlyphVariants,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontFace1 as windows_core::Interface>::IID || <IDWriteFontFace_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for IDWriteFontFace2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsColorFont(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace2", "IsColorFont", 30usize, |this| ((**(this as *const *const IDWriteFontFace2_Vtbl)).IsColorFont)(this), |_| None)
+        }
+        unsafe extern "system" fn GetColorPaletteCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace2", "GetColorPaletteCount", 31usize, |this| ((**(this as *const *const IDWriteFontFace2_Vtbl)).GetColorPaletteCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetPaletteEntryCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace2", "GetPaletteEntryCount", 32usize, |this| ((**(this as *const *const IDWriteFontFace2_Vtbl)).GetPaletteEntryCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetPaletteEntries(this: *mut core::ffi::c_void, colorpaletteindex: u32, firstentryindex: u32, entrycount: u32, paletteentries: *mut DWRITE_COLOR_F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace2", "GetPaletteEntries", 33usize, |this| ((**(this as *const *const IDWriteFontFace2_Vtbl)).GetPaletteEntries)(this, colorpaletteindex, firstentryindex, entrycount, paletteentries), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecommendedRenderingMode(this: *mut core::ffi::c_void, fontemsize: f32, dpix: f32, dpiy: f32, transform: *const DWRITE_MATRIX, issideways: super::super::Foundation::BOOL, outlinethreshold: DWRITE_OUTLINE_THRESHOLD, measuringmode: DWRITE_MEASURING_MODE, renderingparams: *mut core::ffi::c_void, renderingmode: *mut DWRITE_RENDERING_MODE, gridfitmode: *mut DWRITE_GRID_FIT_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace2", "GetRecommendedRenderingMode", 34usize, |this| ((**(this as *const *const IDWriteFontFace2_Vtbl)).GetRecommendedRenderingMode)(this, fontemsize, dpix, dpiy, transform, issideways, outlinethreshold, measuringmode, renderingparams, renderingmode, gridfitmode), |result| Some(*result))
+        }
+        Self {
+            base__: <IDWriteFontFace1_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsColorFont,
+            GetColorPaletteCount,
+            GetPaletteEntryCount,
+            GetPaletteEntries,
+            GetRecommendedRenderingMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontFace2 as windows_core::Interface>::IID || <IDWriteFontFace1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for IDWriteFontFace3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFontFaceReference(this: *mut core::ffi::c_void, fontfacereference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace3", "GetFontFaceReference", 35usize, |this| ((**(this as *const *const IDWriteFontFace3_Vtbl)).GetFontFaceReference)(this, fontfacereference), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPanose(this: *mut core::ffi::c_void, panose: *mut DWRITE_PANOSE) {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace3", "GetPanose", 36usize, |this| ((**(this as *const *const IDWriteFontFace3_Vtbl)).GetPanose)(this, panose), |_| None)
+        }
+        unsafe extern "system" fn GetWeight(this: *mut core::ffi::c_void) -> DWRITE_FONT_WEIGHT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace3", "GetWeight", 37usize, |this| ((**(this as *const *const IDWriteFontFace3_Vtbl)).GetWeight)(this), |_| None)
+        }
+        unsafe extern "system" fn GetStretch(this: *mut core::ffi::c_void) -> DWRITE_FONT_STRETCH {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace3", "GetStretch", 38usize, |this| ((**(this as *const *const IDWriteFontFace3_Vtbl)).GetStretch)(this), |_| None)
+        }
+        unsafe extern "system" fn GetStyle(this: *mut core::ffi::c_void) -> DWRITE_FONT_STYLE {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace3", "GetStyle", 39usize, |this| ((**(this as *const *const IDWriteFontFace3_Vtbl)).GetStyle)(this), |_| None)
+        }
+        unsafe extern "system" fn GetFamilyNames(this: *mut core::ffi::c_void, names: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace3", "GetFamilyNames", 40usize, |this| ((**(this as *const *const IDWriteFontFace3_Vtbl)).GetFamilyNames)(this, names), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFaceNames(this: *mut core::ffi::c_void, names: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace3", "GetFaceNames", 41usize, |this| ((**(this as *const *const IDWriteFontFace3_Vtbl)).GetFaceNames)(this, names), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInformationalStrings(this: *mut core::ffi::c_void, informationalstringid: DWRITE_INFORMATIONAL_STRING_ID, informationalstrings: *mut *mut core::ffi::c_void, exists: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace3", "GetInformationalStrings", 42usize, |this| ((**(this as *const *const IDWriteFontFace3_Vtbl)).GetInformationalStrings)(this, informationalstringid, informationalstrings, exists), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasCharacter(this: *mut core::ffi::c_void, unicodevalue: u32) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace3", "HasCharacter", 43usize, |this| ((**(this as *const *const IDWriteFontFace3_Vtbl)).HasCharacter)(this, unicodevalue), |_| None)
+        }
+        unsafe extern "system" fn GetRecommendedRenderingMode(this: *mut core::ffi::c_void, fontemsize: f32, dpix: f32, dpiy: f32, transform: *const DWRITE_MATRIX, issideways: super::super::Foundation::BOOL, outlinethreshold: DWRITE_OUTLINE_THRESHOLD, measuringmode: DWRITE_MEASURING_MODE, renderingparams: *mut core::ffi::c_void, renderingmode: *mut DWRITE_RENDERING_MODE1, gridfitmode: *mut DWRITE_GRID_FIT_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace3", "GetRecommendedRenderingMode", 44usize, |this| ((**(this as *const *const IDWriteFontFace3_Vtbl)).GetRecommendedRenderingMode)(this, fontemsize, dpix, dpiy, transform, issideways, outlinethreshold, measuringmode, renderingparams, renderingmode, gridfitmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCharacterLocal(this: *mut core::ffi::c_void, unicodevalue: u32) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace3", "IsCharacterLocal", 45usize, |this| ((**(this as *const *const IDWriteFontFace3_Vtbl)).IsCharacterLocal)(this, unicodevalue), |_| None)
+        }
+        unsafe extern "system" fn IsGlyphLocal(this: *mut core::ffi::c_void, glyphid: u16) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace3", "IsGlyphLocal", 46usize, |this| ((**(this as *const *const IDWriteFontFace3_Vtbl)).IsGlyphLocal)(this, glyphid), |_| None)
+        }
+        unsafe extern "system" fn AreCharactersLocal(this: *mut core::ffi::c_void, characters: windows_core::PCWSTR, charactercount: u32, enqueueifnotlocal: super::super::Foundation::BOOL, islocal: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace3", "AreCharactersLocal", 47usize, |this| ((**(this as *const *const IDWriteFontFace3_Vtbl)).AreCharactersLocal)(this, characters, charactercount, enqueueifnotlocal, islocal), |result| Some(*result))
+        }
+        unsafe extern "system" fn AreGlyphsLocal(this: *mut core::ffi::c_void, glyphindices: *const u16, glyphcount: u32, enqueueifnotlocal: super::super::Foundation::BOOL, islocal: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace3", "AreGlyphsLocal", 48usize, |this| ((**(this as *const *const IDWriteFontFace3_Vtbl)).AreGlyphsLocal)(this, glyphindices, glyphcount, enqueueifnotlocal, islocal), |result| Some(*result))
+        }
+        Self {
+            base__: <IDWriteFontFace2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetFontFaceReference,
+            GetPanose,
+            GetWeight,
+            GetStretch,
+            GetStyle,
+            GetFamilyNames,
+            GetFaceNames,
+            GetInformationalStrings,
+            HasCharacter,
+            GetRecommendedRenderingMode,
+            IsCharacterLocal,
+            IsGlyphLocal,
+            AreCharactersLocal,
+            AreGlyphsLocal,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontFace3 as windows_core::Interface>::IID || <IDWriteFontFace2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for IDWriteFontFace4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetGlyphImageFormats(this: *mut core::ffi::c_void, glyphid: u16, pixelsperemfirst: u32, pixelsperemlast: u32, glyphimageformats: *mut DWRITE_GLYPH_IMAGE_FORMATS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace4", "GetGlyphImageFormats", 49usize, |this| ((**(this as *const *const IDWriteFontFace4_Vtbl)).GetGlyphImageFormats)(this, glyphid, pixelsperemfirst, pixelsperemlast, glyphimageformats), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGlyphImageFormats2(this: *mut core::ffi::c_void) -> DWRITE_GLYPH_IMAGE_FORMATS {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace4", "GetGlyphImageFormats2", 50usize, |this| ((**(this as *const *const IDWriteFontFace4_Vtbl)).GetGlyphImageFormats2)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetGlyphImageData(this: *mut core::ffi::c_void, glyphid: u16, pixelsperem: u32, glyphimageformat: DWRITE_GLYPH_IMAGE_FORMATS, glyphdata: *mut DWRITE_GLYPH_IMAGE_DATA, glyphdatacontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace4", "GetGlyphImageData", 51usize, |this| ((**(this as *const *const IDWriteFontFace4_Vtbl)).GetGlyphImageData)(this, glyphid, pixelsperem, glyphimageformat, glyphdata, glyphdatacontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseGlyphImageData(this: *mut core::ffi::c_void, glyphdatacontext: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace4", "ReleaseGlyphImageData", 52usize, |this| ((**(this as *const *const IDWriteFontFace4_Vtbl)).ReleaseGlyphImageData)(this, glyphdatacontext), |_| None)
+        }
+        Self {
+            base__: <IDWriteFontFace3_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetGlyphImageFormats,
+            GetGlyphImageFormats2,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetGlyphImageData,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetGlyphImageData: 0,
+            ReleaseGlyphImageData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontFace4 as windows_core::Interface>::IID || <IDWriteFontFace3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for IDWriteFontFace5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFontAxisValueCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace5", "GetFontAxisValueCount", 53usize, |this| ((**(this as *const *const IDWriteFontFace5_Vtbl)).GetFontAxisValueCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetFontAxisValues(this: *mut core::ffi::c_void, fontaxisvalues: *mut DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace5", "GetFontAxisValues", 54usize, |this| ((**(this as *const *const IDWriteFontFace5_Vtbl)).GetFontAxisValues)(this, fontaxisvalues, fontaxisvaluecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasVariations(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace5", "HasVariations", 55usize, |this| ((**(this as *const *const IDWriteFontFace5_Vtbl)).HasVariations)(this), |_| None)
+        }
+        unsafe extern "system" fn GetFontResource(this: *mut core::ffi::c_void, fontresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace5", "GetFontResource", 56usize, |this| ((**(this as *const *const IDWriteFontFace5_Vtbl)).GetFontResource)(this, fontresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn Equals(this: *mut core::ffi::c_void, fontface: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace5", "Equals", 57usize, |this| ((**(this as *const *const IDWriteFontFace5_Vtbl)).Equals)(this, fontface), |_| None)
+        }
+        Self {
+            base__: <IDWriteFontFace4_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetFontAxisValueCount,
+            GetFontAxisValues,
+            HasVariations,
+            GetFontResource,
+            Equals,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontFace5 as windows_core::Interface>::IID || <IDWriteFontFace4_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for IDWriteFontFace6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFamilyNames(this: *mut core::ffi::c_void, fontfamilymodel: DWRITE_FONT_FAMILY_MODEL, names: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace6", "GetFamilyNames", 58usize, |this| ((**(this as *const *const IDWriteFontFace6_Vtbl)).GetFamilyNames)(this, fontfamilymodel, names), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFaceNames(this: *mut core::ffi::c_void, fontfamilymodel: DWRITE_FONT_FAMILY_MODEL, names: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace6", "GetFaceNames", 59usize, |this| ((**(this as *const *const IDWriteFontFace6_Vtbl)).GetFaceNames)(this, fontfamilymodel, names), |result| Some(*result))
+        }
+        Self { base__: <IDWriteFontFace5_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFamilyNames, GetFaceNames }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontFace6 as windows_core::Interface>::IID || <IDWriteFontFace5_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for IDWriteFontFace7_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPaintFeatureLevel(this: *mut core::ffi::c_void, glyphimageformat: DWRITE_GLYPH_IMAGE_FORMATS) -> DWRITE_PAINT_FEATURE_LEVEL {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace7", "GetPaintFeatureLevel", 60usize, |this| ((**(this as *const *const IDWriteFontFace7_Vtbl)).GetPaintFeatureLevel)(this, glyphimageformat), |_| None)
+        }
+        unsafe extern "system" fn CreatePaintReader(this: *mut core::ffi::c_void, glyphimageformat: DWRITE_GLYPH_IMAGE_FORMATS, paintfeaturelevel: DWRITE_PAINT_FEATURE_LEVEL, paintreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFace7", "CreatePaintReader", 61usize, |this| ((**(this as *const *const IDWriteFontFace7_Vtbl)).CreatePaintReader)(this, glyphimageformat, paintfeaturelevel, paintreader), |result| Some(*result))
+        }
+        Self { base__: <IDWriteFontFace6_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPaintFeatureLevel, CreatePaintReader }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontFace7 as windows_core::Interface>::IID || <IDWriteFontFace6_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontFaceReference_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFontFace(this: *mut core::ffi::c_void, fontface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFaceReference", "CreateFontFace", 3usize, |this| ((**(this as *const *const IDWriteFontFaceReference_Vtbl)).CreateFontFace)(this, fontface), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFontFaceWithSimulations(this: *mut core::ffi::c_void, fontfacesimulationflags: DWRITE_FONT_SIMULATIONS, fontface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFaceReference", "CreateFontFaceWithSimulations", 4usize, |this| ((**(this as *const *const IDWriteFontFaceReference_Vtbl)).CreateFontFaceWithSimulations)(this, fontfacesimulationflags, fontface), |result| Some(*result))
+        }
+        unsafe extern "system" fn Equals(this: *mut core::ffi::c_void, fontfacereference: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDWriteFontFaceReference", "Equals", 5usize, |this| ((**(this as *const *const IDWriteFontFaceReference_Vtbl)).Equals)(this, fontfacereference), |_| None)
+        }
+        unsafe extern "system" fn GetFontFaceIndex(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDWriteFontFaceReference", "GetFontFaceIndex", 6usize, |this| ((**(this as *const *const IDWriteFontFaceReference_Vtbl)).GetFontFaceIndex)(this), |_| None)
+        }
+        unsafe extern "system" fn GetSimulations(this: *mut core::ffi::c_void) -> DWRITE_FONT_SIMULATIONS {
+            windows_core::imp::proxy_call(this, "IDWriteFontFaceReference", "GetSimulations", 7usize, |this| ((**(this as *const *const IDWriteFontFaceReference_Vtbl)).GetSimulations)(this), |_| None)
+        }
+        unsafe extern "system" fn GetFontFile(this: *mut core::ffi::c_void, fontfile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFaceReference", "GetFontFile", 8usize, |this| ((**(this as *const *const IDWriteFontFaceReference_Vtbl)).GetFontFile)(this, fontfile), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocalFileSize(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "IDWriteFontFaceReference", "GetLocalFileSize", 9usize, |this| ((**(this as *const *const IDWriteFontFaceReference_Vtbl)).GetLocalFileSize)(this), |_| None)
+        }
+        unsafe extern "system" fn GetFileSize(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "IDWriteFontFaceReference", "GetFileSize", 10usize, |this| ((**(this as *const *const IDWriteFontFaceReference_Vtbl)).GetFileSize)(this), |_| None)
+        }
+        unsafe extern "system" fn GetFileTime(this: *mut core::ffi::c_void, lastwritetime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFaceReference", "GetFileTime", 11usize, |this| ((**(this as *const *const IDWriteFontFaceReference_Vtbl)).GetFileTime)(this, lastwritetime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocality(this: *mut core::ffi::c_void) -> DWRITE_LOCALITY {
+            windows_core::imp::proxy_call(this, "IDWriteFontFaceReference", "GetLocality", 12usize, |this| ((**(this as *const *const IDWriteFontFaceReference_Vtbl)).GetLocality)(this), |_| None)
+        }
+        unsafe extern "system" fn EnqueueFontDownloadRequest(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFaceReference", "EnqueueFontDownloadRequest", 13usize, |this| ((**(this as *const *const IDWriteFontFaceReference_Vtbl)).EnqueueFontDownloadRequest)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnqueueCharacterDownloadRequest(this: *mut core::ffi::c_void, characters: windows_core::PCWSTR, charactercount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFaceReference", "EnqueueCharacterDownloadRequest", 14usize, |this| ((**(this as *const *const IDWriteFontFaceReference_Vtbl)).EnqueueCharacterDownloadRequest)(this, characters, charactercount), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnqueueGlyphDownloadRequest(this: *mut core::ffi::c_void, glyphindices: *const u16, glyphcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFaceReference", "EnqueueGlyphDownloadRequest", 15usize, |this| ((**(this as *const *const IDWriteFontFaceReference_Vtbl)).EnqueueGlyphDownloadRequest)(this, glyphindices, glyphcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnqueueFileFragmentDownloadRequest(this: *mut core::ffi::c_void, fileoffset: u64, fragmentsize: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFaceReference", "EnqueueFileFragmentDownloadRequest", 16usize, |this| ((**(this as *const *const IDWriteFontFaceReference_Vtbl)).EnqueueFileFragmentDownloadRequest)(this, fileoffset, fragmentsize), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateFontFace,
+            CreateFontFaceWithSimulations,
+            Equals,
+            GetFontFaceIndex,
+            GetSimulations,
+            GetFontFile,
+            GetLocalFileSize,
+            GetFileSize,
+            GetFileTime,
+            GetLocality,
+            EnqueueFontDownloadRequest,
+            EnqueueCharacterDownloadRequest,
+            EnqueueGlyphDownloadRequest,
+            EnqueueFileFragmentDownloadRequest,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontFaceReference as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontFaceReference1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFontFace(this: *mut core::ffi::c_void, fontface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFaceReference1", "CreateFontFace", 17usize, |this| ((**(this as *const *const IDWriteFontFaceReference1_Vtbl)).CreateFontFace)(this, fontface), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontAxisValueCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDWriteFontFaceReference1", "GetFontAxisValueCount", 18usize, |this| ((**(this as *const *const IDWriteFontFaceReference1_Vtbl)).GetFontAxisValueCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetFontAxisValues(this: *mut core::ffi::c_void, fontaxisvalues: *mut DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFaceReference1", "GetFontAxisValues", 19usize, |this| ((**(this as *const *const IDWriteFontFaceReference1_Vtbl)).GetFontAxisValues)(this, fontaxisvalues, fontaxisvaluecount), |result| Some(*result))
+        }
+        Self { base__: <IDWriteFontFaceReference_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFontFace, GetFontAxisValueCount, GetFontAxisValues }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontFaceReference1 as windows_core::Interface>::IID || <IDWriteFontFaceReference_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontFallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MapCharacters(this: *mut core::ffi::c_void, analysissource: *mut core::ffi::c_void, textposition: u32, textlength: u32, basefontcollection: *mut core::ffi::c_void, basefamilyname: windows_core::PCWSTR, baseweight: DWRITE_FONT_WEIGHT, basestyle: DWRITE_FONT_STYLE, basestretch: DWRITE_FONT_STRETCH, mappedlength: *mut u32, mappedfont: *mut *mut core::ffi::c_void, scale: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFallback", "MapCharacters", 3usize, |this| ((**(this as *const *const IDWriteFontFallback_Vtbl)).MapCharacters)(this, analysissource, textposition, textlength, basefontcollection, basefamilyname, baseweight, basestyle, basestretch, mappedlength, mappedfont, scale), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MapCharacters }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontFallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontFallback1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MapCharacters(this: *mut core::ffi::c_void, analysissource: *mut core::ffi::c_void, textposition: u32, textlength: u32, basefontcollection: *mut core::ffi::c_void, basefamilyname: windows_core::PCWSTR, fontaxisvalues: *const DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32, mappedlength: *mut u32, scale: *mut f32, mappedfontface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFallback1", "MapCharacters", 4usize, |this| ((**(this as *const *const IDWriteFontFallback1_Vtbl)).MapCharacters)(this, analysissource, textposition, textlength, basefontcollection, basefamilyname, fontaxisvalues, fontaxisvaluecount, mappedlength, scale, mappedfontface), |result| Some(*result))
+        }
+        Self { base__: <IDWriteFontFallback_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MapCharacters }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontFallback1 as windows_core::Interface>::IID || <IDWriteFontFallback_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontFallbackBuilder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddMapping(this: *mut core::ffi::c_void, ranges: *const DWRITE_UNICODE_RANGE, rangescount: u32, targetfamilynames: *const *const u16, targetfamilynamescount: u32, fontcollection: *mut core::ffi::c_void, localename: windows_core::PCWSTR, basefamilyname: windows_core::PCWSTR, scale: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFallbackBuilder", "AddMapping", 3usize, |this| ((**(this as *const *const IDWriteFontFallbackBuilder_Vtbl)).AddMapping)(this, ranges, rangescount, targetfamilynames, targetfamilynamescount, fontcollection, localename, basefamilyname, scale), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddMappings(this: *mut core::ffi::c_void, fontfallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFallbackBuilder", "AddMappings", 4usize, |this| ((**(this as *const *const IDWriteFontFallbackBuilder_Vtbl)).AddMappings)(this, fontfallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFontFallback(this: *mut core::ffi::c_void, fontfallback: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFallbackBuilder", "CreateFontFallback", 5usize, |this| ((**(this as *const *const IDWriteFontFallbackBuilder_Vtbl)).CreateFontFallback)(this, fontfallback), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddMapping, AddMappings, CreateFontFallback }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontFallbackBuilder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontFamily_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFamilyNames(this: *mut core::ffi::c_void, names: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFamily", "GetFamilyNames", 6usize, |this| ((**(this as *const *const IDWriteFontFamily_Vtbl)).GetFamilyNames)(this, names), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFirstMatchingFont(this: *mut core::ffi::c_void, weight: DWRITE_FONT_WEIGHT, stretch: DWRITE_FONT_STRETCH, style: DWRITE_FONT_STYLE, matchingfont: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFamily", "GetFirstMatchingFont", 7usize, |this| ((**(this as *const *const IDWriteFontFamily_Vtbl)).GetFirstMatchingFont)(this, weight, stretch, style, matchingfont), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMatchingFonts(this: *mut core::ffi::c_void, weight: DWRITE_FONT_WEIGHT, stretch: DWRITE_FONT_STRETCH, style: DWRITE_FONT_STYLE, matchingfonts: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFamily", "GetMatchingFonts", 8usize, |this| ((**(this as *const *const IDWriteFontFamily_Vtbl)).GetMatchingFonts)(this, weight, stretch, style, matchingfonts), |result| Some(*result))
+        }
+        Self { base__: <IDWriteFontList_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFamilyNames, GetFirstMatchingFont, GetMatchingFonts }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontFamily as windows_core::Interface>::IID || <IDWriteFontList_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontFamily1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFontLocality(this: *mut core::ffi::c_void, listindex: u32) -> DWRITE_LOCALITY {
+            windows_core::imp::proxy_call(this, "IDWriteFontFamily1", "GetFontLocality", 9usize, |this| ((**(this as *const *const IDWriteFontFamily1_Vtbl)).GetFontLocality)(this, listindex), |_| None)
+        }
+        unsafe extern "system" fn GetFont(this: *mut core::ffi::c_void, listindex: u32, font: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFamily1", "GetFont", 10usize, |this| ((**(this as *const *const IDWriteFontFamily1_Vtbl)).GetFont)(this, listindex, font), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontFaceReference(this: *mut core::ffi::c_void, listindex: u32, fontfacereference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFamily1", "GetFontFaceReference", 11usize, |this| ((**(this as *const *const IDWriteFontFamily1_Vtbl)).GetFontFaceReference)(this, listindex, fontfacereference), |result| Some(*result))
+        }
+        Self { base__: <IDWriteFontFamily_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFontLocality, GetFont, GetFontFaceReference }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontFamily1 as windows_core::Interface>::IID || <IDWriteFontFamily_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontFamily2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMatchingFonts(this: *mut core::ffi::c_void, fontaxisvalues: *const DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32, matchingfonts: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFamily2", "GetMatchingFonts", 12usize, |this| ((**(this as *const *const IDWriteFontFamily2_Vtbl)).GetMatchingFonts)(this, fontaxisvalues, fontaxisvaluecount, matchingfonts), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontSet(this: *mut core::ffi::c_void, fontset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFamily2", "GetFontSet", 13usize, |this| ((**(this as *const *const IDWriteFontFamily2_Vtbl)).GetFontSet)(this, fontset), |result| Some(*result))
+        }
+        Self { base__: <IDWriteFontFamily1_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMatchingFonts, GetFontSet }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontFamily2 as windows_core::Interface>::IID || <IDWriteFontFamily1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontFile_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetReferenceKey(this: *mut core::ffi::c_void, fontfilereferencekey: *mut *mut core::ffi::c_void, fontfilereferencekeysize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFile", "GetReferenceKey", 3usize, |this| ((**(this as *const *const IDWriteFontFile_Vtbl)).GetReferenceKey)(this, fontfilereferencekey, fontfilereferencekeysize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLoader(this: *mut core::ffi::c_void, fontfileloader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFile", "GetLoader", 4usize, |this| ((**(this as *const *const IDWriteFontFile_Vtbl)).GetLoader)(this, fontfileloader), |result| Some(*result))
+        }
+        unsafe extern "system" fn Analyze(this: *mut core::ffi::c_void, issupportedfonttype: *mut super::super::Foundation::BOOL, fontfiletype: *mut DWRITE_FONT_FILE_TYPE, fontfacetype: *mut DWRITE_FONT_FACE_TYPE, numberoffaces: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFile", "Analyze", 5usize, |this| ((**(this as *const *const IDWriteFontFile_Vtbl)).Analyze)(this, issupportedfonttype, fontfiletype, fontfacetype, numberoffaces), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetReferenceKey, GetLoader, Analyze }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontFile as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontFileEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hascurrentfile: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFileEnumerator", "MoveNext", 3usize, |this| ((**(this as *const *const IDWriteFontFileEnumerator_Vtbl)).MoveNext)(this, hascurrentfile), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentFontFile(this: *mut core::ffi::c_void, fontfile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFileEnumerator", "GetCurrentFontFile", 4usize, |this| ((**(this as *const *const IDWriteFontFileEnumerator_Vtbl)).GetCurrentFontFile)(this, fontfile), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MoveNext, GetCurrentFontFile }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontFileEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontFileLoader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateStreamFromKey(this: *mut core::ffi::c_void, fontfilereferencekey: *const core::ffi::c_void, fontfilereferencekeysize: u32, fontfilestream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFileLoader", "CreateStreamFromKey", 3usize, |this| ((**(this as *const *const IDWriteFontFileLoader_Vtbl)).CreateStreamFromKey)(this, fontfilereferencekey, fontfilereferencekeysize, fontfilestream), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateStreamFromKey }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontFileLoader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontFileStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReadFileFragment(this: *mut core::ffi::c_void, fragmentstart: *mut *mut core::ffi::c_void, fileoffset: u64, fragmentsize: u64, fragmentcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFileStream", "ReadFileFragment", 3usize, |this| ((**(this as *const *const IDWriteFontFileStream_Vtbl)).ReadFileFragment)(this, fragmentstart, fileoffset, fragmentsize, fragmentcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseFileFragment(this: *mut core::ffi::c_void, fragmentcontext: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IDWriteFontFileStream", "ReleaseFileFragment", 4usize, |this| ((**(this as *const *const IDWriteFontFileStream_Vtbl)).ReleaseFileFragment)(this, fragmentcontext), |_| None)
+        }
+        unsafe extern "system" fn GetFileSize(this: *mut core::ffi::c_void, filesize: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFileStream", "GetFileSize", 5usize, |this| ((**(this as *const *const IDWriteFontFileStream_Vtbl)).GetFileSize)(this, filesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastWriteTime(this: *mut core::ffi::c_void, lastwritetime: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontFileStream", "GetLastWriteTime", 6usize, |this| ((**(this as *const *const IDWriteFontFileStream_Vtbl)).GetLastWriteTime)(this, lastwritetime), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReadFileFragment,
+            ReleaseFileFragment,
+            GetFileSize,
+            GetLastWriteTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontFileStream as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFontCollection(this: *mut core::ffi::c_void, fontcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontList", "GetFontCollection", 3usize, |this| ((**(this as *const *const IDWriteFontList_Vtbl)).GetFontCollection)(this, fontcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDWriteFontList", "GetFontCount", 4usize, |this| ((**(this as *const *const IDWriteFontList_Vtbl)).GetFontCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetFont(this: *mut core::ffi::c_void, index: u32, font: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontList", "GetFont", 5usize, |this| ((**(this as *const *const IDWriteFontList_Vtbl)).GetFont)(this, index, font), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFontCollection, GetFontCount, GetFont }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontList as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontList1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFontLocality(this: *mut core::ffi::c_void, listindex: u32) -> DWRITE_LOCALITY {
+            windows_core::imp::proxy_call(this, "IDWriteFontList1", "GetFontLocality", 6usize, |this| ((**(this as *const *const IDWriteFontList1_Vtbl)).GetFontLocality)(this, listindex), |_| None)
+        }
+        unsafe extern "system" fn GetFont(this: *mut core::ffi::c_void, listindex: u32, font: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontList1", "GetFont", 7usize, |this| ((**(this as *const *const IDWriteFontList1_Vtbl)).GetFont)(this, listindex, font), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontFaceReference(this: *mut core::ffi::c_void, listindex: u32, fontfacereference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontList1", "GetFontFaceReference", 8usize, |this| ((**(this as *const *const IDWriteFontList1_Vtbl)).GetFontFaceReference)(this, listindex, fontfacereference), |result| Some(*result))
+        }
+        Self { base__: <IDWriteFontList_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFontLocality, GetFont, GetFontFaceReference }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontList1 as windows_core::Interface>::IID || <IDWriteFontList_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontList2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFontSet(this: *mut core::ffi::c_void, fontset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontList2", "GetFontSet", 9usize, |this| ((**(this as *const *const IDWriteFontList2_Vtbl)).GetFontSet)(this, fontset), |result| Some(*result))
+        }
+        Self { base__: <IDWriteFontList1_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFontSet }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontList2 as windows_core::Interface>::IID || <IDWriteFontList1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontResource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFontFile(this: *mut core::ffi::c_void, fontfile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontResource", "GetFontFile", 3usize, |this| ((**(this as *const *const IDWriteFontResource_Vtbl)).GetFontFile)(this, fontfile), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontFaceIndex(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDWriteFontResource", "GetFontFaceIndex", 4usize, |this| ((**(this as *const *const IDWriteFontResource_Vtbl)).GetFontFaceIndex)(this), |_| None)
+        }
+        unsafe extern "system" fn GetFontAxisCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDWriteFontResource", "GetFontAxisCount", 5usize, |this| ((**(this as *const *const IDWriteFontResource_Vtbl)).GetFontAxisCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetDefaultFontAxisValues(this: *mut core::ffi::c_void, fontaxisvalues: *mut DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontResource", "GetDefaultFontAxisValues", 6usize, |this| ((**(this as *const *const IDWriteFontResource_Vtbl)).GetDefaultFontAxisValues)(this, fontaxisvalues, fontaxisvaluecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontAxisRanges(this: *mut core::ffi::c_void, fontaxisranges: *mut DWRITE_FONT_AXIS_RANGE, fontaxisrangecount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontResource", "GetFontAxisRanges", 7usize, |this| ((**(this as *const *const IDWriteFontResource_Vtbl)).GetFontAxisRanges)(this, fontaxisranges, fontaxisrangecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontAxisAttributes(this: *mut core::ffi::c_void, axisindex: u32) -> DWRITE_FONT_AXIS_ATTRIBUTES {
+            windows_core::imp::proxy_call(this, "IDWriteFontResource", "GetFontAxisAttributes", 8usize, |this| ((**(this as *const *const IDWriteFontResource_Vtbl)).GetFontAxisAttributes)(this, axisindex), |_| None)
+        }
+        unsafe extern "system" fn GetAxisNames(this: *mut core::ffi::c_void, axisindex: u32, names: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontResource", "GetAxisNames", 9usize, |this| ((**(this as *const *const IDWriteFontResource_Vtbl)).GetAxisNames)(this, axisindex, names), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAxisValueNameCount(this: *mut core::ffi::c_void, axisindex: u32) -> u32 {
+            windows_core::imp::proxy_call(this, "IDWriteFontResource", "GetAxisValueNameCount", 10usize, |this| ((**(this as *const *const IDWriteFontResource_Vtbl)).GetAxisValueNameCount)(this, axisindex), |_| None)
+        }
+        unsafe extern "system" fn GetAxisValueNames(this: *mut core::ffi::c_void, axisindex: u32, axisvalueindex: u32, fontaxisrange: *mut DWRITE_FONT_AXIS_RANGE, names: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontResource", "GetAxisValueNames", 11usize, |this| ((**(this as *const *const IDWriteFontResource_Vtbl)).GetAxisValueNames)(this, axisindex, axisvalueindex, fontaxisrange, names), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasVariations(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDWriteFontResource", "HasVariations", 12usize, |this| ((**(this as *const *const IDWriteFontResource_Vtbl)).HasVariations)(this), |_| None)
+        }
+        unsafe extern "system" fn CreateFontFace(this: *mut core::ffi::c_void, fontsimulations: DWRITE_FONT_SIMULATIONS, fontaxisvalues: *const DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32, fontface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontResource", "CreateFontFace", 13usize, |this| ((**(this as *const *const IDWriteFontResource_Vtbl)).CreateFontFace)(this, fontsimulations, fontaxisvalues, fontaxisvaluecount, fontface), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFontFaceReference(this: *mut core::ffi::c_void, fontsimulations: DWRITE_FONT_SIMULATIONS, fontaxisvalues: *const DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32, fontfacereference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontResource", "CreateFontFaceReference", 14usize, |this| ((**(this as *const *const IDWriteFontResource_Vtbl)).CreateFontFaceReference)(this, fontsimulations, fontaxisvalues, fontaxisvaluecount, fontfacereference), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetFontFile,
+            GetFontFaceIndex,
+            GetFontAxisCount,
+            GetDefaultFontAxisValues,
+            GetFontAxisRanges,
+            GetFontAxisAttributes,
+            GetAxisNames,
+            GetAxisValueNameCount,
+            GetAxisValueNames,
+            HasVariations,
+            CreateFontFace,
+            CreateFontFaceReference,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontResource as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontSet_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFontCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet", "GetFontCount", 3usize, |this| ((**(this as *const *const IDWriteFontSet_Vtbl)).GetFontCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetFontFaceReference(this: *mut core::ffi::c_void, listindex: u32, fontfacereference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet", "GetFontFaceReference", 4usize, |this| ((**(this as *const *const IDWriteFontSet_Vtbl)).GetFontFaceReference)(this, listindex, fontfacereference), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindFontFaceReference(this: *mut core::ffi::c_void, fontfacereference: *mut core::ffi::c_void, listindex: *mut u32, exists: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet", "FindFontFaceReference", 5usize, |this| ((**(this as *const *const IDWriteFontSet_Vtbl)).FindFontFaceReference)(this, fontfacereference, listindex, exists), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindFontFace(this: *mut core::ffi::c_void, fontface: *mut core::ffi::c_void, listindex: *mut u32, exists: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet", "FindFontFace", 6usize, |this| ((**(this as *const *const IDWriteFontSet_Vtbl)).FindFontFace)(this, fontface, listindex, exists), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyValues(this: *mut core::ffi::c_void, propertyid: DWRITE_FONT_PROPERTY_ID, values: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet", "GetPropertyValues", 7usize, |this| ((**(this as *const *const IDWriteFontSet_Vtbl)).GetPropertyValues)(this, propertyid, values), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyValues2(this: *mut core::ffi::c_void, propertyid: DWRITE_FONT_PROPERTY_ID, preferredlocalenames: windows_core::PCWSTR, values: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet", "GetPropertyValues2", 8usize, |this| ((**(this as *const *const IDWriteFontSet_Vtbl)).GetPropertyValues2)(this, propertyid, preferredlocalenames, values), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyValues3(this: *mut core::ffi::c_void, listindex: u32, propertyid: DWRITE_FONT_PROPERTY_ID, exists: *mut super::super::Foundation::BOOL, values: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet", "GetPropertyValues3", 9usize, |this| ((**(this as *const *const IDWriteFontSet_Vtbl)).GetPropertyValues3)(this, listindex, propertyid, exists, values), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyOccurrenceCount(this: *mut core::ffi::c_void, property: *const DWRITE_FONT_PROPERTY, propertyoccurrencecount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet", "GetPropertyOccurrenceCount", 10usize, |this| ((**(this as *const *const IDWriteFontSet_Vtbl)).GetPropertyOccurrenceCount)(this, property, propertyoccurrencecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMatchingFonts(this: *mut core::ffi::c_void, familyname: windows_core::PCWSTR, fontweight: DWRITE_FONT_WEIGHT, fontstretch: DWRITE_FONT_STRETCH, fontstyle: DWRITE_FONT_STYLE, filteredset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet", "GetMatchingFonts", 11usize, |this| ((**(this as *const *const IDWriteFontSet_Vtbl)).GetMatchingFonts)(this, familyname, fontweight, fontstretch, fontstyle, filteredset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMatchingFonts2(this: *mut core::ffi::c_void, properties: *const DWRITE_FONT_PROPERTY, propertycount: u32, filteredset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet", "GetMatchingFonts2", 12usize, |this| ((**(this as *const *const IDWriteFontSet_Vtbl)).GetMatchingFonts2)(this, properties, propertycount, filteredset), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetFontCount,
+            GetFontFaceReference,
+            FindFontFaceReference,
+            FindFontFace,
+            GetPropertyValues,
+            GetPropertyValues2,
+            GetPropertyValues3,
+            GetPropertyOccurrenceCount,
+            GetMatchingFonts,
+            GetMatchingFonts2,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontSet as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontSet1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMatchingFonts(this: *mut core::ffi::c_void, fontproperty: *const DWRITE_FONT_PROPERTY, fontaxisvalues: *const DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32, matchingfonts: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet1", "GetMatchingFonts", 13usize, |this| ((**(this as *const *const IDWriteFontSet1_Vtbl)).GetMatchingFonts)(this, fontproperty, fontaxisvalues, fontaxisvaluecount, matchingfonts), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFirstFontResources(this: *mut core::ffi::c_void, filteredfontset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet1", "GetFirstFontResources", 14usize, |this| ((**(this as *const *const IDWriteFontSet1_Vtbl)).GetFirstFontResources)(this, filteredfontset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFilteredFonts(this: *mut core::ffi::c_void, indices: *const u32, indexcount: u32, filteredfontset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet1", "GetFilteredFonts", 15usize, |this| ((**(this as *const *const IDWriteFontSet1_Vtbl)).GetFilteredFonts)(this, indices, indexcount, filteredfontset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFilteredFonts2(this: *mut core::ffi::c_void, fontaxisranges: *const DWRITE_FONT_AXIS_RANGE, fontaxisrangecount: u32, selectanyrange: super::super::Foundation::BOOL, filteredfontset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet1", "GetFilteredFonts2", 16usize, |this| ((**(this as *const *const IDWriteFontSet1_Vtbl)).GetFilteredFonts2)(this, fontaxisranges, fontaxisrangecount, selectanyrange, filteredfontset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFilteredFonts3(this: *mut core::ffi::c_void, properties: *const DWRITE_FONT_PROPERTY, propertycount: u32, selectanyproperty: super::super::Foundation::BOOL, filteredfontset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet1", "GetFilteredFonts3", 17usize, |this| ((**(this as *const *const IDWriteFontSet1_Vtbl)).GetFilteredFonts3)(this, properties, propertycount, selectanyproperty, filteredfontset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFilteredFontIndices(this: *mut core::ffi::c_void, fontaxisranges: *const DWRITE_FONT_AXIS_RANGE, fontaxisrangecount: u32, selectanyrange: super::super::Foundation::BOOL, indices: *mut u32, maxindexcount: u32, actualindexcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet1", "GetFilteredFontIndices", 18usize, |this| ((**(this as *const *const IDWriteFontSet1_Vtbl)).GetFilteredFontIndices)(this, fontaxisranges, fontaxisrangecount, selectanyrange, indices, maxindexcount, actualindexcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFilteredFontIndices2(this: *mut core::ffi::c_void, properties: *const DWRITE_FONT_PROPERTY, propertycount: u32, selectanyproperty: super::super::Foundation::BOOL, indices: *mut u32, maxindexcount: u32, actualindexcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet1", "GetFilteredFontIndices2", 19usize, |this| ((**(this as *const *const IDWriteFontSet1_Vtbl)).GetFilteredFontIndices2)(this, properties, propertycount, selectanyproperty, indices, maxindexcount, actualindexcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontAxisRanges(this: *mut core::ffi::c_void, listindex: u32, fontaxisranges: *mut DWRITE_FONT_AXIS_RANGE, maxfontaxisrangecount: u32, actualfontaxisrangecount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet1", "GetFontAxisRanges", 20usize, |this| ((**(this as *const *const IDWriteFontSet1_Vtbl)).GetFontAxisRanges)(this, listindex, fontaxisranges, maxfontaxisrangecount, actualfontaxisrangecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontAxisRanges2(this: *mut core::ffi::c_void, fontaxisranges: *mut DWRITE_FONT_AXIS_RANGE, maxfontaxisrangecount: u32, actualfontaxisrangecount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet1", "GetFontAxisRanges2", 21usize, |this| ((**(this as *const *const IDWriteFontSet1_Vtbl)).GetFontAxisRanges2)(this, fontaxisranges, maxfontaxisrangecount, actualfontaxisrangecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontFaceReference(this: *mut core::ffi::c_void, listindex: u32, fontfacereference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet1", "GetFontFaceReference", 22usize, |this| ((**(this as *const *const IDWriteFontSet1_Vtbl)).GetFontFaceReference)(this, listindex, fontfacereference), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFontResource(this: *mut core::ffi::c_void, listindex: u32, fontresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet1", "CreateFontResource", 23usize, |this| ((**(this as *const *const IDWriteFontSet1_Vtbl)).CreateFontResource)(this, listindex, fontresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFontFace(this: *mut core::ffi::c_void, listindex: u32, fontface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet1", "CreateFontFace", 24usize, |this| ((**(this as *const *const IDWriteFontSet1_Vtbl)).CreateFontFace)(this, listindex, fontface), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontLocality(this: *mut core::ffi::c_void, listindex: u32) -> DWRITE_LOCALITY {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet1", "GetFontLocality", 25usize, |this| ((**(this as *const *const IDWriteFontSet1_Vtbl)).GetFontLocality)(this, listindex), |_| None)
+        }
+        Self {
+            base__: <IDWriteFontSet_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetMatchingFonts,
+            GetFirstFontResources,
+            GetFilteredFonts,
+            GetFilteredFonts2,
+            GetFilteredFonts3,
+            GetFilteredFontIndices,
+            GetFilteredFontIndices2,
+            GetFontAxisRanges,
+            GetFontAxisRanges2,
+            GetFontFaceReference,
+            CreateFontResource,
+            CreateFontFace,
+            GetFontLocality,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontSet1 as windows_core::Interface>::IID || <IDWriteFontSet_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontSet2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetExpirationEvent(this: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet2", "GetExpirationEvent", 26usize, |this| ((**(this as *const *const IDWriteFontSet2_Vtbl)).GetExpirationEvent)(this), |_| None)
+        }
+        Self { base__: <IDWriteFontSet1_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetExpirationEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontSet2 as windows_core::Interface>::IID || <IDWriteFontSet1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontSet3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFontSourceType(this: *mut core::ffi::c_void, fontindex: u32) -> DWRITE_FONT_SOURCE_TYPE {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet3", "GetFontSourceType", 27usize, |this| ((**(this as *const *const IDWriteFontSet3_Vtbl)).GetFontSourceType)(this, fontindex), |_| None)
+        }
+        unsafe extern "system" fn GetFontSourceNameLength(this: *mut core::ffi::c_void, listindex: u32) -> u32 {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet3", "GetFontSourceNameLength", 28usize, |this| ((**(this as *const *const IDWriteFontSet3_Vtbl)).GetFontSourceNameLength)(this, listindex), |_| None)
+        }
+        unsafe extern "system" fn GetFontSourceName(this: *mut core::ffi::c_void, listindex: u32, stringbuffer: windows_core::PWSTR, stringbuffersize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet3", "GetFontSourceName", 29usize, |this| ((**(this as *const *const IDWriteFontSet3_Vtbl)).GetFontSourceName)(this, listindex, stringbuffer, stringbuffersize), |result| Some(*result))
+        }
+        Self { base__: <IDWriteFontSet2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFontSourceType, GetFontSourceNameLength, GetFontSourceName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontSet3 as windows_core::Interface>::IID || <IDWriteFontSet2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontSet4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConvertWeightStretchStyleToFontAxisValues(this: *mut core::ffi::c_void, inputaxisvalues: *const DWRITE_FONT_AXIS_VALUE, inputaxiscount: u32, fontweight: DWRITE_FONT_WEIGHT, fontstretch: DWRITE_FONT_STRETCH, fontstyle: DWRITE_FONT_STYLE, fontsize: f32, outputaxisvalues: *mut DWRITE_FONT_AXIS_VALUE) -> u32 {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet4", "ConvertWeightStretchStyleToFontAxisValues", 30usize, |this| ((**(this as *const *const IDWriteFontSet4_Vtbl)).ConvertWeightStretchStyleToFontAxisValues)(this, inputaxisvalues, inputaxiscount, fontweight, fontstretch, fontstyle, fontsize, outputaxisvalues), |_| None)
+        }
+        unsafe extern "system" fn GetMatchingFonts(this: *mut core::ffi::c_void, familyname: windows_core::PCWSTR, fontaxisvalues: *const DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32, allowedsimulations: DWRITE_FONT_SIMULATIONS, matchingfonts: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSet4", "GetMatchingFonts", 31usize, |this| ((**(this as *const *const IDWriteFontSet4_Vtbl)).GetMatchingFonts)(this, familyname, fontaxisvalues, fontaxisvaluecount, allowedsimulations, matchingfonts), |result| Some(*result))
+        }
+        Self { base__: <IDWriteFontSet3_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ConvertWeightStretchStyleToFontAxisValues, GetMatchingFonts }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontSet4 as windows_core::Interface>::IID || <IDWriteFontSet3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontSetBuilder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddFontFaceReference(this: *mut core::ffi::c_void, fontfacereference: *mut core::ffi::c_void, properties: *const DWRITE_FONT_PROPERTY, propertycount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSetBuilder", "AddFontFaceReference", 3usize, |this| ((**(this as *const *const IDWriteFontSetBuilder_Vtbl)).AddFontFaceReference)(this, fontfacereference, properties, propertycount), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddFontFaceReference2(this: *mut core::ffi::c_void, fontfacereference: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSetBuilder", "AddFontFaceReference2", 4usize, |this| ((**(this as *const *const IDWriteFontSetBuilder_Vtbl)).AddFontFaceReference2)(this, fontfacereference), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddFontSet(this: *mut core::ffi::c_void, fontset: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSetBuilder", "AddFontSet", 5usize, |this| ((**(this as *const *const IDWriteFontSetBuilder_Vtbl)).AddFontSet)(this, fontset), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFontSet(this: *mut core::ffi::c_void, fontset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSetBuilder", "CreateFontSet", 6usize, |this| ((**(this as *const *const IDWriteFontSetBuilder_Vtbl)).CreateFontSet)(this, fontset), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddFontFaceReference,
+            AddFontFaceReference2,
+            AddFontSet,
+            CreateFontSet,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontSetBuilder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontSetBuilder1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddFontFile(this: *mut core::ffi::c_void, fontfile: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSetBuilder1", "AddFontFile", 7usize, |this| ((**(this as *const *const IDWriteFontSetBuilder1_Vtbl)).AddFontFile)(this, fontfile), |result| Some(*result))
+        }
+        Self { base__: <IDWriteFontSetBuilder_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddFontFile }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontSetBuilder1 as windows_core::Interface>::IID || <IDWriteFontSetBuilder_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteFontSetBuilder2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddFont(this: *mut core::ffi::c_void, fontfile: *mut core::ffi::c_void, fontfaceindex: u32, fontsimulations: DWRITE_FONT_SIMULATIONS, fontaxisvalues: *const DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32, fontaxisranges: *const DWRITE_FONT_AXIS_RANGE, fontaxisrangecount: u32, properties: *const DWRITE_FONT_PROPERTY, propertycount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSetBuilder2", "AddFont", 8usize, |this| ((**(this as *const *const IDWriteFontSetBuilder2_Vtbl)).AddFont)(this, fontfile, fontfaceindex, fontsimulations, fontaxisvalues, fontaxisvaluecount, fontaxisranges, fontaxisrangecount, properties, propertycount), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddFontFile(this: *mut core::ffi::c_void, filepath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteFontSetBuilder2", "AddFontFile", 9usize, |this| ((**(this as *const *const IDWriteFontSetBuilder2_Vtbl)).AddFontFile)(this, filepath), |result| Some(*result))
+        }
+        Self { base__: <IDWriteFontSetBuilder1_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddFont, AddFontFile }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteFontSetBuilder2 as windows_core::Interface>::IID || <IDWriteFontSetBuilder1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IDWriteGdiInterop_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn CreateFontFromLOGFONT(this: *mut core::ffi::c_void, logfont: *const super::Gdi::LOGFONTW, font: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteGdiInterop", "CreateFontFromLOGFONT", 3usize, |this| ((**(this as *const *const IDWriteGdiInterop_Vtbl)).CreateFontFromLOGFONT)(this, logfont, font), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn ConvertFontToLOGFONT(this: *mut core::ffi::c_void, font: *mut core::ffi::c_void, logfont: *mut super::Gdi::LOGFONTW, issystemfont: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteGdiInterop", "ConvertFontToLOGFONT", 4usize, |this| ((**(this as *const *const IDWriteGdiInterop_Vtbl)).ConvertFontToLOGFONT)(this, font, logfont, issystemfont), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn ConvertFontFaceToLOGFONT(this: *mut core::ffi::c_void, font: *mut core::ffi::c_void, logfont: *mut super::Gdi::LOGFONTW) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteGdiInterop", "ConvertFontFaceToLOGFONT", 5usize, |this| ((**(this as *const *const IDWriteGdiInterop_Vtbl)).ConvertFontFaceToLOGFONT)(this, font, logfont), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn CreateFontFaceFromHdc(this: *mut core::ffi::c_void, hdc: super::Gdi::HDC, fontface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteGdiInterop", "CreateFontFaceFromHdc", 6usize, |this| ((**(this as *const *const IDWriteGdiInterop_Vtbl)).CreateFontFaceFromHdc)(this, hdc, fontface), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn CreateBitmapRenderTarget(this: *mut core::ffi::c_void, hdc: super::Gdi::HDC, width: u32, height: u32, rendertarget: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteGdiInterop", "CreateBitmapRenderTarget", 7usize, |this| ((**(this as *const *const IDWriteGdiInterop_Vtbl)).CreateBitmapRenderTarget)(this, hdc, width, height, rendertarget), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            CreateFontFromLOGFONT,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            CreateFontFromLOGFONT: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            ConvertFontToLOGFONT,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            ConvertFontToLOGFONT: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            ConvertFontFaceToLOGFONT,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            ConvertFontFaceToLOGFONT: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            CreateFontFaceFromHdc,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            CreateFontFaceFromHdc: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            CreateBitmapRenderTarget,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            CreateBitmapRenderTarget: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteGdiInterop as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Globalization", feature = "Win32_Graphics_Gdi"))]
+impl windows_core::imp::ProxyVtable for IDWriteGdiInterop1_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn CreateFontFromLOGFONT(this: *mut core::ffi::c_void, logfont: *const super::Gdi::LOGFONTW, fontcollection: *mut core::ffi::c_void, font: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteGdiInterop1", "CreateFontFromLOGFONT", 8usize, |this| ((**(this as *const *const IDWriteGdiInterop1_Vtbl)).CreateFontFromLOGFONT)(this, logfont, fontcollection, font), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Globalization")]
+        unsafe extern "system" fn GetFontSignature(this: *mut core::ffi::c_void, fontface: *mut core::ffi::c_void, fontsignature: *mut super::super::Globalization::FONTSIGNATURE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteGdiInterop1", "GetFontSignature", 9usize, |this| ((**(this as *const *const IDWriteGdiInterop1_Vtbl)).GetFontSignature)(this, fontface, fontsignature), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Globalization")]
+        unsafe extern "system" fn GetFontSignature2(this: *mut core::ffi::c_void, font: *mut core::ffi::c_void, fontsignature: *mut super::super::Globalization::FONTSIGNATURE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteGdiInterop1", "GetFontSignature2", 10usize, |this| ((**(this as *const *const IDWriteGdiInterop1_Vtbl)).GetFontSignature2)(this, font, fontsignature), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetMatchingFontsByLOGFONT(this: *mut core::ffi::c_void, logfont: *const super::Gdi::LOGFONTA, fontset: *mut core::ffi::c_void, filteredset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteGdiInterop1", "GetMatchingFontsByLOGFONT", 11usize, |this| ((**(this as *const *const IDWriteGdiInterop1_Vtbl)).GetMatchingFontsByLOGFONT)(this, logfont, fontset, filteredset), |result| Some(*result))
+        }
+        Self {
+            base__: <IDWriteGdiInterop_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            CreateFontFromLOGFONT,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            CreateFontFromLOGFONT: 0,
+            #[cfg(feature = "Win32_Globalization")]
+            GetFontSignature,
+            #[cfg(not(feature = "Win32_Globalization"))]
+            GetFontSignature: 0,
+            #[cfg(feature = "Win32_Globalization")]
+            GetFontSignature2,
+            #[cfg(not(feature = "Win32_Globalization"))]
+            GetFontSignature2: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetMatchingFontsByLOGFONT,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetMatchingFontsByLOGFONT: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteGdiInterop1 as windows_core::Interface>::IID || <IDWriteGdiInterop_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteGlyphRunAnalysis_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAlphaTextureBounds(this: *mut core::ffi::c_void, texturetype: DWRITE_TEXTURE_TYPE, texturebounds: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteGlyphRunAnalysis", "GetAlphaTextureBounds", 3usize, |this| ((**(this as *const *const IDWriteGlyphRunAnalysis_Vtbl)).GetAlphaTextureBounds)(this, texturetype, texturebounds), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAlphaTexture(this: *mut core::ffi::c_void, texturetype: DWRITE_TEXTURE_TYPE, texturebounds: *const super::super::Foundation::RECT, alphavalues: *mut u8, buffersize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteGlyphRunAnalysis", "CreateAlphaTexture", 4usize, |this| ((**(this as *const *const IDWriteGlyphRunAnalysis_Vtbl)).CreateAlphaTexture)(this, texturetype, texturebounds, alphavalues, buffersize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAlphaBlendParams(this: *mut core::ffi::c_void, renderingparams: *mut core::ffi::c_void, blendgamma: *mut f32, blendenhancedcontrast: *mut f32, blendcleartypelevel: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteGlyphRunAnalysis", "GetAlphaBlendParams", 5usize, |this| ((**(this as *const *const IDWriteGlyphRunAnalysis_Vtbl)).GetAlphaBlendParams)(this, renderingparams, blendgamma, blendenhancedcontrast, blendcleartypelevel), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAlphaTextureBounds, CreateAlphaTexture, GetAlphaBlendParams }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteGlyphRunAnalysis as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteInMemoryFontFileLoader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInMemoryFontFileReference(this: *mut core::ffi::c_void, factory: *mut core::ffi::c_void, fontdata: *const core::ffi::c_void, fontdatasize: u32, ownerobject: *mut core::ffi::c_void, fontfile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteInMemoryFontFileLoader", "CreateInMemoryFontFileReference", 4usize, |this| ((**(this as *const *const IDWriteInMemoryFontFileLoader_Vtbl)).CreateInMemoryFontFileReference)(this, factory, fontdata, fontdatasize, ownerobject, fontfile), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDWriteInMemoryFontFileLoader", "GetFileCount", 5usize, |this| ((**(this as *const *const IDWriteInMemoryFontFileLoader_Vtbl)).GetFileCount)(this), |_| None)
+        }
+        Self { base__: <IDWriteFontFileLoader_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInMemoryFontFileReference, GetFileCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteInMemoryFontFileLoader as windows_core::Interface>::IID || <IDWriteFontFileLoader_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteInlineObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Draw(this: *mut core::ffi::c_void, clientdrawingcontext: *const core::ffi::c_void, renderer: *mut core::ffi::c_void, originx: f32, originy: f32, issideways: super::super::Foundation::BOOL, isrighttoleft: super::super::Foundation::BOOL, clientdrawingeffect: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteInlineObject", "Draw", 3usize, |this| ((**(this as *const *const IDWriteInlineObject_Vtbl)).Draw)(this, clientdrawingcontext, renderer, originx, originy, issideways, isrighttoleft, clientdrawingeffect), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMetrics(this: *mut core::ffi::c_void, metrics: *mut DWRITE_INLINE_OBJECT_METRICS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteInlineObject", "GetMetrics", 4usize, |this| ((**(this as *const *const IDWriteInlineObject_Vtbl)).GetMetrics)(this, metrics), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOverhangMetrics(this: *mut core::ffi::c_void, overhangs: *mut DWRITE_OVERHANG_METRICS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteInlineObject", "GetOverhangMetrics", 5usize, |this| ((**(this as *const *const IDWriteInlineObject_Vtbl)).GetOverhangMetrics)(this, overhangs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBreakConditions(this: *mut core::ffi::c_void, breakconditionbefore: *mut DWRITE_BREAK_CONDITION, breakconditionafter: *mut DWRITE_BREAK_CONDITION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteInlineObject", "GetBreakConditions", 6usize, |this| ((**(this as *const *const IDWriteInlineObject_Vtbl)).GetBreakConditions)(this, breakconditionbefore, breakconditionafter), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Draw, GetMetrics, GetOverhangMetrics, GetBreakConditions }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteInlineObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteLocalFontFileLoader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFilePathLengthFromKey(this: *mut core::ffi::c_void, fontfilereferencekey: *const core::ffi::c_void, fontfilereferencekeysize: u32, filepathlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteLocalFontFileLoader", "GetFilePathLengthFromKey", 4usize, |this| ((**(this as *const *const IDWriteLocalFontFileLoader_Vtbl)).GetFilePathLengthFromKey)(this, fontfilereferencekey, fontfilereferencekeysize, filepathlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFilePathFromKey(this: *mut core::ffi::c_void, fontfilereferencekey: *const core::ffi::c_void, fontfilereferencekeysize: u32, filepath: windows_core::PWSTR, filepathsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteLocalFontFileLoader", "GetFilePathFromKey", 5usize, |this| ((**(this as *const *const IDWriteLocalFontFileLoader_Vtbl)).GetFilePathFromKey)(this, fontfilereferencekey, fontfilereferencekeysize, filepath, filepathsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastWriteTimeFromKey(this: *mut core::ffi::c_void, fontfilereferencekey: *const core::ffi::c_void, fontfilereferencekeysize: u32, lastwritetime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteLocalFontFileLoader", "GetLastWriteTimeFromKey", 6usize, |this| ((**(this as *const *const IDWriteLocalFontFileLoader_Vtbl)).GetLastWriteTimeFromKey)(this, fontfilereferencekey, fontfilereferencekeysize, lastwritetime), |result| Some(*result))
+        }
+        Self {
+            base__: <IDWriteFontFileLoader_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetFilePathLengthFromKey,
+            GetFilePathFromKey,
+            GetLastWriteTimeFromKey,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteLocalFontFileLoader as windows_core::Interface>::IID || <IDWriteFontFileLoader_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteLocalizedStrings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDWriteLocalizedStrings", "GetCount", 3usize, |this| ((**(this as *const *const IDWriteLocalizedStrings_Vtbl)).GetCount)(this), |_| None)
+        }
+        unsafe extern "system" fn FindLocaleName(this: *mut core::ffi::c_void, localename: windows_core::PCWSTR, index: *mut u32, exists: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteLocalizedStrings", "FindLocaleName", 4usize, |this| ((**(this as *const *const IDWriteLocalizedStrings_Vtbl)).FindLocaleName)(this, localename, index, exists), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocaleNameLength(this: *mut core::ffi::c_void, index: u32, length: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteLocalizedStrings", "GetLocaleNameLength", 5usize, |this| ((**(this as *const *const IDWriteLocalizedStrings_Vtbl)).GetLocaleNameLength)(this, index, length), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocaleName(this: *mut core::ffi::c_void, index: u32, localename: windows_core::PWSTR, size: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteLocalizedStrings", "GetLocaleName", 6usize, |this| ((**(this as *const *const IDWriteLocalizedStrings_Vtbl)).GetLocaleName)(this, index, localename, size), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStringLength(this: *mut core::ffi::c_void, index: u32, length: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteLocalizedStrings", "GetStringLength", 7usize, |this| ((**(this as *const *const IDWriteLocalizedStrings_Vtbl)).GetStringLength)(this, index, length), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetString(this: *mut core::ffi::c_void, index: u32, stringbuffer: windows_core::PWSTR, size: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteLocalizedStrings", "GetString", 8usize, |this| ((**(this as *const *const IDWriteLocalizedStrings_Vtbl)).GetString)(this, index, stringbuffer, size), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCount,
+            FindLocaleName,
+            GetLocaleNameLength,
+            GetLocaleName,
+            GetStringLength,
+            GetString,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteLocalizedStrings as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteNumberSubstitution_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteNumberSubstitution as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+impl windows_core::imp::ProxyVtable for IDWritePaintReader_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn SetCurrentGlyph(this: *mut core::ffi::c_void, glyphindex: u32, paintelement: *mut DWRITE_PAINT_ELEMENT, structsize: u32, clipbox: *mut super::Direct2D::Common::D2D_RECT_F, glyphattributes: *mut DWRITE_PAINT_ATTRIBUTES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWritePaintReader", "SetCurrentGlyph", 3usize, |this| ((**(this as *const *const IDWritePaintReader_Vtbl)).SetCurrentGlyph)(this, glyphindex, paintelement, structsize, clipbox, glyphattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTextColor(this: *mut core::ffi::c_void, textcolor: *const DWRITE_COLOR_F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWritePaintReader", "SetTextColor", 4usize, |this| ((**(this as *const *const IDWritePaintReader_Vtbl)).SetTextColor)(this, textcolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColorPaletteIndex(this: *mut core::ffi::c_void, colorpaletteindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWritePaintReader", "SetColorPaletteIndex", 5usize, |this| ((**(this as *const *const IDWritePaintReader_Vtbl)).SetColorPaletteIndex)(this, colorpaletteindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCustomColorPalette(this: *mut core::ffi::c_void, paletteentries: *const DWRITE_COLOR_F, paletteentrycount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWritePaintReader", "SetCustomColorPalette", 6usize, |this| ((**(this as *const *const IDWritePaintReader_Vtbl)).SetCustomColorPalette)(this, paletteentries, paletteentrycount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn MoveToFirstChild(this: *mut core::ffi::c_void, paintelement: *mut DWRITE_PAINT_ELEMENT, structsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWritePaintReader", "MoveToFirstChild", 7usize, |this| ((**(this as *const *const IDWritePaintReader_Vtbl)).MoveToFirstChild)(this, paintelement, structsize), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn MoveToNextSibling(this: *mut core::ffi::c_void, paintelement: *mut DWRITE_PAINT_ELEMENT, structsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWritePaintReader", "MoveToNextSibling", 8usize, |this| ((**(this as *const *const IDWritePaintReader_Vtbl)).MoveToNextSibling)(this, paintelement, structsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveToParent(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWritePaintReader", "MoveToParent", 9usize, |this| ((**(this as *const *const IDWritePaintReader_Vtbl)).MoveToParent)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn GetGradientStops(this: *mut core::ffi::c_void, firstgradientstopindex: u32, gradientstopcount: u32, gradientstops: *mut super::Direct2D::Common::D2D1_GRADIENT_STOP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWritePaintReader", "GetGradientStops", 10usize, |this| ((**(this as *const *const IDWritePaintReader_Vtbl)).GetGradientStops)(this, firstgradientstopindex, gradientstopcount, gradientstops), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGradientStopColors(this: *mut core::ffi::c_void, firstgradientstopindex: u32, gradientstopcount: u32, gradientstopcolors: *mut DWRITE_PAINT_COLOR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWritePaintReader", "GetGradientStopColors", 11usize, |this| ((**(this as *const *const IDWritePaintReader_Vtbl)).GetGradientStopColors)(this, firstgradientstopindex, gradientstopcount, gradientstopcolors), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            SetCurrentGlyph,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            SetCurrentGlyph: 0,
+            SetTextColor,
+            SetColorPaletteIndex,
+            SetCustomColorPalette,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            MoveToFirstChild,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            MoveToFirstChild: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            MoveToNextSibling,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            MoveToNextSibling: 0,
+            MoveToParent,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            GetGradientStops,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            GetGradientStops: 0,
+            GetGradientStopColors,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWritePaintReader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWritePixelSnapping_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsPixelSnappingDisabled(this: *mut core::ffi::c_void, clientdrawingcontext: *const core::ffi::c_void, isdisabled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWritePixelSnapping", "IsPixelSnappingDisabled", 3usize, |this| ((**(this as *const *const IDWritePixelSnapping_Vtbl)).IsPixelSnappingDisabled)(this, clientdrawingcontext, isdisabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentTransform(this: *mut core::ffi::c_void, clientdrawingcontext: *const core::ffi::c_void, transform: *mut DWRITE_MATRIX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWritePixelSnapping", "GetCurrentTransform", 4usize, |this| ((**(this as *const *const IDWritePixelSnapping_Vtbl)).GetCurrentTransform)(this, clientdrawingcontext, transform), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPixelsPerDip(this: *mut core::ffi::c_void, clientdrawingcontext: *const core::ffi::c_void, pixelsperdip: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWritePixelSnapping", "GetPixelsPerDip", 5usize, |this| ((**(this as *const *const IDWritePixelSnapping_Vtbl)).GetPixelsPerDip)(this, clientdrawingcontext, pixelsperdip), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsPixelSnappingDisabled, GetCurrentTransform, GetPixelsPerDip }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWritePixelSnapping as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteRemoteFontFileLoader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateRemoteStreamFromKey(this: *mut core::ffi::c_void, fontfilereferencekey: *const core::ffi::c_void, fontfilereferencekeysize: u32, fontfilestream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteRemoteFontFileLoader", "CreateRemoteStreamFromKey", 4usize, |this| ((**(this as *const *const IDWriteRemoteFontFileLoader_Vtbl)).CreateRemoteStreamFromKey)(this, fontfilereferencekey, fontfilereferencekeysize, fontfilestream), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocalityFromKey(this: *mut core::ffi::c_void, fontfilereferencekey: *const core::ffi::c_void, fontfilereferencekeysize: u32, locality: *mut DWRITE_LOCALITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteRemoteFontFileLoader", "GetLocalityFromKey", 5usize, |this| ((**(this as *const *const IDWriteRemoteFontFileLoader_Vtbl)).GetLocalityFromKey)(this, fontfilereferencekey, fontfilereferencekeysize, locality), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFontFileReferenceFromUrl(this: *mut core::ffi::c_void, factory: *mut core::ffi::c_void, baseurl: windows_core::PCWSTR, fontfileurl: windows_core::PCWSTR, fontfile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteRemoteFontFileLoader", "CreateFontFileReferenceFromUrl", 6usize, |this| ((**(this as *const *const IDWriteRemoteFontFileLoader_Vtbl)).CreateFontFileReferenceFromUrl)(this, factory, baseurl, fontfileurl, fontfile), |result| Some(*result))
+        }
+        Self {
+            base__: <IDWriteFontFileLoader_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateRemoteStreamFromKey,
+            GetLocalityFromKey,
+            CreateFontFileReferenceFromUrl,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteRemoteFontFileLoader as windows_core::Interface>::IID || <IDWriteFontFileLoader_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteRemoteFontFileStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLocalFileSize(this: *mut core::ffi::c_void, localfilesize: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteRemoteFontFileStream", "GetLocalFileSize", 7usize, |this| ((**(this as *const *const IDWriteRemoteFontFileStream_Vtbl)).GetLocalFileSize)(this, localfilesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileFragmentLocality(this: *mut core::ffi::c_void, fileoffset: u64, fragmentsize: u64, islocal: *mut super::super::Foundation::BOOL, partialsize: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteRemoteFontFileStream", "GetFileFragmentLocality", 8usize, |this| ((**(this as *const *const IDWriteRemoteFontFileStream_Vtbl)).GetFileFragmentLocality)(this, fileoffset, fragmentsize, islocal, partialsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocality(this: *mut core::ffi::c_void) -> DWRITE_LOCALITY {
+            windows_core::imp::proxy_call(this, "IDWriteRemoteFontFileStream", "GetLocality", 9usize, |this| ((**(this as *const *const IDWriteRemoteFontFileStream_Vtbl)).GetLocality)(this), |_| None)
+        }
+        unsafe extern "system" fn BeginDownload(this: *mut core::ffi::c_void, downloadoperationid: *const windows_core::GUID, filefragments: *const DWRITE_FILE_FRAGMENT, fragmentcount: u32, asyncresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteRemoteFontFileStream", "BeginDownload", 10usize, |this| ((**(this as *const *const IDWriteRemoteFontFileStream_Vtbl)).BeginDownload)(this, downloadoperationid, filefragments, fragmentcount, asyncresult), |result| Some(*result))
+        }
+        Self {
+            base__: <IDWriteFontFileStream_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetLocalFileSize,
+            GetFileFragmentLocality,
+            GetLocality,
+            BeginDownload,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteRemoteFontFileStream as windows_core::Interface>::IID || <IDWriteFontFileStream_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteRenderingParams_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetGamma(this: *mut core::ffi::c_void) -> f32 {
+            windows_core::imp::proxy_call(this, "IDWriteRenderingParams", "GetGamma", 3usize, |this| ((**(this as *const *const IDWriteRenderingParams_Vtbl)).GetGamma)(this), |_| None)
+        }
+        unsafe extern "system" fn GetEnhancedContrast(this: *mut core::ffi::c_void) -> f32 {
+            windows_core::imp::proxy_call(this, "IDWriteRenderingParams", "GetEnhancedContrast", 4usize, |this| ((**(this as *const *const IDWriteRenderingParams_Vtbl)).GetEnhancedContrast)(this), |_| None)
+        }
+        unsafe extern "system" fn GetClearTypeLevel(this: *mut core::ffi::c_void) -> f32 {
+            windows_core::imp::proxy_call(this, "IDWriteRenderingParams", "GetClearTypeLevel", 5usize, |this| ((**(this as *const *const IDWriteRenderingParams_Vtbl)).GetClearTypeLevel)(this), |_| None)
+        }
+        unsafe extern "system" fn GetPixelGeometry(this: *mut core::ffi::c_void) -> DWRITE_PIXEL_GEOMETRY {
+            windows_core::imp::proxy_call(this, "IDWriteRenderingParams", "GetPixelGeometry", 6usize, |this| ((**(this as *const *const IDWriteRenderingParams_Vtbl)).GetPixelGeometry)(this), |_| None)
+        }
+        unsafe extern "system" fn GetRenderingMode(this: *mut core::ffi::c_void) -> DWRITE_RENDERING_MODE {
+            windows_core::imp::proxy_call(this, "IDWriteRenderingParams", "GetRenderingMode", 7usize, |this| ((**(this as *const *const IDWriteRenderingParams_Vtbl)).GetRenderingMode)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetGamma,
+            GetEnhancedContrast,
+            GetClearTypeLevel,
+            GetPixelGeometry,
+            GetRenderingMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteRenderingParams as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteRenderingParams1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetGrayscaleEnhancedContrast(this: *mut core::ffi::c_void) -> f32 {
+            windows_core::imp::proxy_call(this, "IDWriteRenderingParams1", "GetGrayscaleEnhancedContrast", 8usize, |this| ((**(this as *const *const IDWriteRenderingParams1_Vtbl)).GetGrayscaleEnhancedContrast)(this), |_| None)
+        }
+        Self { base__: <IDWriteRenderingParams_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetGrayscaleEnhancedContrast }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteRenderingParams1 as windows_core::Interface>::IID || <IDWriteRenderingParams_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteRenderingParams2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetGridFitMode(this: *mut core::ffi::c_void) -> DWRITE_GRID_FIT_MODE {
+            windows_core::imp::proxy_call(this, "IDWriteRenderingParams2", "GetGridFitMode", 9usize, |this| ((**(this as *const *const IDWriteRenderingParams2_Vtbl)).GetGridFitMode)(this), |_| None)
+        }
+        Self { base__: <IDWriteRenderingParams1_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetGridFitMode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteRenderingParams2 as windows_core::Interface>::IID || <IDWriteRenderingParams1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteRenderingParams3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRenderingMode1(this: *mut core::ffi::c_void) -> DWRITE_RENDERING_MODE1 {
+            windows_core::imp::proxy_call(this, "IDWriteRenderingParams3", "GetRenderingMode1", 10usize, |this| ((**(this as *const *const IDWriteRenderingParams3_Vtbl)).GetRenderingMode1)(this), |_| None)
+        }
+        Self { base__: <IDWriteRenderingParams2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRenderingMode1 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteRenderingParams3 as windows_core::Interface>::IID || <IDWriteRenderingParams2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteStringList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDWriteStringList", "GetCount", 3usize, |this| ((**(this as *const *const IDWriteStringList_Vtbl)).GetCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetLocaleNameLength(this: *mut core::ffi::c_void, listindex: u32, length: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteStringList", "GetLocaleNameLength", 4usize, |this| ((**(this as *const *const IDWriteStringList_Vtbl)).GetLocaleNameLength)(this, listindex, length), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocaleName(this: *mut core::ffi::c_void, listindex: u32, localename: windows_core::PWSTR, size: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteStringList", "GetLocaleName", 5usize, |this| ((**(this as *const *const IDWriteStringList_Vtbl)).GetLocaleName)(this, listindex, localename, size), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStringLength(this: *mut core::ffi::c_void, listindex: u32, length: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteStringList", "GetStringLength", 6usize, |this| ((**(this as *const *const IDWriteStringList_Vtbl)).GetStringLength)(this, listindex, length), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetString(this: *mut core::ffi::c_void, listindex: u32, stringbuffer: windows_core::PWSTR, stringbuffersize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteStringList", "GetString", 7usize, |this| ((**(this as *const *const IDWriteStringList_Vtbl)).GetString)(this, listindex, stringbuffer, stringbuffersize), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCount,
+            GetLocaleNameLength,
+            GetLocaleName,
+            GetStringLength,
+            GetString,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteStringList as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteTextAnalysisSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetScriptAnalysis(this: *mut core::ffi::c_void, textposition: u32, textlength: u32, scriptanalysis: *const DWRITE_SCRIPT_ANALYSIS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalysisSink", "SetScriptAnalysis", 3usize, |this| ((**(this as *const *const IDWriteTextAnalysisSink_Vtbl)).SetScriptAnalysis)(this, textposition, textlength, scriptanalysis), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLineBreakpoints(this: *mut core::ffi::c_void, textposition: u32, textlength: u32, linebreakpoints: *const DWRITE_LINE_BREAKPOINT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalysisSink", "SetLineBreakpoints", 4usize, |this| ((**(this as *const *const IDWriteTextAnalysisSink_Vtbl)).SetLineBreakpoints)(this, textposition, textlength, linebreakpoints), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBidiLevel(this: *mut core::ffi::c_void, textposition: u32, textlength: u32, explicitlevel: u8, resolvedlevel: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalysisSink", "SetBidiLevel", 5usize, |this| ((**(this as *const *const IDWriteTextAnalysisSink_Vtbl)).SetBidiLevel)(this, textposition, textlength, explicitlevel, resolvedlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNumberSubstitution(this: *mut core::ffi::c_void, textposition: u32, textlength: u32, numbersubstitution: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalysisSink", "SetNumberSubstitution", 6usize, |this| ((**(this as *const *const IDWriteTextAnalysisSink_Vtbl)).SetNumberSubstitution)(this, textposition, textlength, numbersubstitution), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetScriptAnalysis,
+            SetLineBreakpoints,
+            SetBidiLevel,
+            SetNumberSubstitution,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteTextAnalysisSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteTextAnalysisSink1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetGlyphOrientation(this: *mut core::ffi::c_void, textposition: u32, textlength: u32, glyphorientationangle: DWRITE_GLYPH_ORIENTATION_ANGLE, adjustedbidilevel: u8, issideways: super::super::Foundation::BOOL, isrighttoleft: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalysisSink1", "SetGlyphOrientation", 7usize, |this| ((**(this as *const *const IDWriteTextAnalysisSink1_Vtbl)).SetGlyphOrientation)(this, textposition, textlength, glyphorientationangle, adjustedbidilevel, issideways, isrighttoleft), |result| Some(*result))
+        }
+        Self { base__: <IDWriteTextAnalysisSink_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetGlyphOrientation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteTextAnalysisSink1 as windows_core::Interface>::IID || <IDWriteTextAnalysisSink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteTextAnalysisSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTextAtPosition(this: *mut core::ffi::c_void, textposition: u32, textstring: *mut *mut u16, textlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalysisSource", "GetTextAtPosition", 3usize, |this| ((**(this as *const *const IDWriteTextAnalysisSource_Vtbl)).GetTextAtPosition)(this, textposition, textstring, textlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTextBeforePosition(this: *mut core::ffi::c_void, textposition: u32, textstring: *mut *mut u16, textlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalysisSource", "GetTextBeforePosition", 4usize, |this| ((**(this as *const *const IDWriteTextAnalysisSource_Vtbl)).GetTextBeforePosition)(this, textposition, textstring, textlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParagraphReadingDirection(this: *mut core::ffi::c_void) -> DWRITE_READING_DIRECTION {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalysisSource", "GetParagraphReadingDirection", 5usize, |this| ((**(this as *const *const IDWriteTextAnalysisSource_Vtbl)).GetParagraphReadingDirection)(this), |_| None)
+        }
+        unsafe extern "system" fn GetLocaleName(this: *mut core::ffi::c_void, textposition: u32, textlength: *mut u32, localename: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalysisSource", "GetLocaleName", 6usize, |this| ((**(this as *const *const IDWriteTextAnalysisSource_Vtbl)).GetLocaleName)(this, textposition, textlength, localename), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumberSubstitution(this: *mut core::ffi::c_void, textposition: u32, textlength: *mut u32, numbersubstitution: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalysisSource", "GetNumberSubstitution", 7usize, |this| ((**(this as *const *const IDWriteTextAnalysisSource_Vtbl)).GetNumberSubstitution)(this, textposition, textlength, numbersubstitution), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTextAtPosition,
+            GetTextBeforePosition,
+            GetParagraphReadingDirection,
+            GetLocaleName,
+            GetNumberSubstitution,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteTextAnalysisSource as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteTextAnalysisSource1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetVerticalGlyphOrientation(this: *mut core::ffi::c_void, textposition: u32, textlength: *mut u32, glyphorientation: *mut DWRITE_VERTICAL_GLYPH_ORIENTATION, bidilevel: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalysisSource1", "GetVerticalGlyphOrientation", 8usize, |this| ((**(this as *const *const IDWriteTextAnalysisSource1_Vtbl)).GetVerticalGlyphOrientation)(this, textposition, textlength, glyphorientation, bidilevel), |result| Some(*result))
+        }
+        Self { base__: <IDWriteTextAnalysisSource_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetVerticalGlyphOrientation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteTextAnalysisSource1 as windows_core::Interface>::IID || <IDWriteTextAnalysisSource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteTextAnalyzer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AnalyzeScript(this: *mut core::ffi::c_void, analysissource: *mut core::ffi::c_void, textposition: u32, textlength: u32, analysissink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalyzer", "AnalyzeScript", 3usize, |this| ((**(this as *const *const IDWriteTextAnalyzer_Vtbl)).AnalyzeScript)(this, analysissource, textposition, textlength, analysissink), |result| Some(*result))
+        }
+        unsafe extern "system" fn AnalyzeBidi(this: *mut core::ffi::c_void, analysissource: *mut core::ffi::c_void, textposition: u32, textlength: u32, analysissink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalyzer", "AnalyzeBidi", 4usize, |this| ((**(this as *const *const IDWriteTextAnalyzer_Vtbl)).AnalyzeBidi)(this, analysissource, textposition, textlength, analysissink), |result| Some(*result))
+        }
+        unsafe extern "system" fn AnalyzeNumberSubstitution(this: *mut core::ffi::c_void, analysissource: *mut core::ffi::c_void, textposition: u32, textlength: u32, analysissink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalyzer", "AnalyzeNumberSubstitution", 5usize, |this| ((**(this as *const *const IDWriteTextAnalyzer_Vtbl)).AnalyzeNumberSubstitution)(this, analysissource, textposition, textlength, analysissink), |result| Some(*result))
+        }
+        unsafe extern "system" fn AnalyzeLineBreakpoints(this: *mut core::ffi::c_void, analysissource: *mut core::ffi::c_void, textposition: u32, textlength: u32, analysissink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalyzer", "AnalyzeLineBreakpoints", 6usize, |this| ((**(this as *const *const IDWriteTextAnalyzer_Vtbl)).AnalyzeLineBreakpoints)(this, analysissource, textposition, textlength, analysissink), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGlyphs(this: *mut core::ffi::c_void, textstring: windows_core::PCWSTR, textlength: u32, fontface: *mut core::ffi::c_void, issideways: super::super::Foundation::BOOL, isrighttoleft: super::super::Foundation::BOOL, scriptanalysis: *const DWRITE_SCRIPT_ANALYSIS, localename: windows_core::PCWSTR, numbersubstitution: *mut core::ffi::c_void, features: *const *const DWRITE_TYPOGRAPHIC_FEATURES, featurerangelengths: *const u32, featureranges: u32, maxglyphcount: u32, clustermap: *mut u16, textprops: *mut DWRITE_SHAPING_TEXT_PROPERTIES, glyphindices: *mut u16, glyphprops: *mut DWRITE_SHAPING_GLYPH_PROPERTIES, actualglyphcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalyzer", "GetGlyphs", 7usize, |this| ((**(this as *const *const IDWriteTextAnalyzer_Vtbl)).GetGlyphs)(this, textstring, textlength, fontface, issideways, isrighttoleft, scriptanalysis, localename, numbersubstitution, features, featurerangelengths, featureranges, maxglyphcount, clustermap, textprops, glyphindices, glyphprops, actualglyphcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGlyphPlacements(this: *mut core::ffi::c_void, textstring: windows_core::PCWSTR, clustermap: *const u16, textprops: *mut DWRITE_SHAPING_TEXT_PROPERTIES, textlength: u32, glyphindices: *const u16, glyphprops: *const DWRITE_SHAPING_GLYPH_PROPERTIES, glyphcount: u32, fontface: *mut core::ffi::c_void, fontemsize: f32, issideways: super::super::Foundation::BOOL, isrighttoleft: super::super::Foundation::BOOL, scriptanalysis: *const DWRITE_SCRIPT_ANALYSIS, localename: windows_core::PCWSTR, features: *const *const DWRITE_TYPOGRAPHIC_FEATURES, featurerangelengths: *const u32, featureranges: u32, glyphadvances: *mut f32, glyphoffsets: *mut DWRITE_GLYPH_OFFSET) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalyzer", "GetGlyphPlacements", 8usize, |this| ((**(this as *const *const IDWriteTextAnalyzer_Vtbl)).GetGlyphPlacements)(this, textstring, clustermap, textprops, textlength, glyphindices, glyphprops, glyphcount, fontface, fontemsize, issideways, isrighttoleft, scriptanalysis, localename, features, featurerangelengths, featureranges, glyphadvances, glyphoffsets), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGdiCompatibleGlyphPlacements(
+            this: *mut core::ffi::c_void,
+            textstring: windows_core::PCWSTR,
+            clustermap: *const u16,
+            textprops: *const DWRITE_SHAPING_TEXT_PROPERTIES,
+            textlength: u32,
+            glyphindices: *const u16,
+            glyphprops: *const DWRITE_SHAPING_GLYPH_PROPERTIES,
+            glyphcount: u32,
+            fontface: *mut core::ffi::c_void,
+            fontemsize: f32,
+            pixelsperdip: f32,
+            transform: *const DWRITE_MATRIX,
+            usegdinatural: super::super::Foundation::BOOL,
+            issideways: super::super::Foundation::BOOL,
+            isrighttoleft: super::super::Foundation::BOOL,
+            scriptanalysis: *const DWRITE_SCRIPT_ANALYSIS,
+            localename: windows_core::PCWSTR,
+            features: *const *const DWRITE_TYPOGRAPHIC_FEATURES,
+            featurerangelengths: *const u32,
+            featureranges: u32,
+            glyphadvances: *mut f32,
+            glyphoffsets: *mut DWRITE_GLYPH_OFFSET,
+        ) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalyzer", "GetGdiCompatibleGlyphPlacements", 9usize, |this| ((**(this as *const *const IDWriteTextAnalyzer_Vtbl)).GetGdiCompatibleGlyphPlacements)(this, textstring, clustermap, textprops, textlength, glyphindices, glyphprops, glyphcount, fontface, fontemsize, pixelsperdip, transform, usegdinatural, issideways, isrighttoleft, scriptanalysis, localename, features, featurerangelengths, featureranges, glyphadvances, glyphoffsets), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AnalyzeScript,
+            AnalyzeBidi,
+            AnalyzeNumberSubstitution,
+            AnalyzeLineBreakpoints,
+            GetGlyphs,
+            GetGlyphPlacements,
+            GetGdiCompatibleGlyphPlacements,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteTextAnalyzer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteTextAnalyzer1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ApplyCharacterSpacing(this: *mut core::ffi::c_void, leadingspacing: f32, trailingspacing: f32, minimumadvancewidth: f32, textlength: u32, glyphcount: u32, clustermap: *const u16, glyphadvances: *const f32, glyphoffsets: *const DWRITE_GLYPH_OFFSET, glyphproperties: *const DWRITE_SHAPING_GLYPH_PROPERTIES, modifiedglyphadvances: *mut f32, modifiedglyphoffsets: *mut DWRITE_GLYPH_OFFSET) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalyzer1", "ApplyCharacterSpacing", 10usize, |this| ((**(this as *const *const IDWriteTextAnalyzer1_Vtbl)).ApplyCharacterSpacing)(this, leadingspacing, trailingspacing, minimumadvancewidth, textlength, glyphcount, clustermap, glyphadvances, glyphoffsets, glyphproperties, modifiedglyphadvances, modifiedglyphoffsets), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBaseline(this: *mut core::ffi::c_void, fontface: *mut core::ffi::c_void, baseline: DWRITE_BASELINE, isvertical: super::super::Foundation::BOOL, issimulationallowed: super::super::Foundation::BOOL, scriptanalysis: DWRITE_SCRIPT_ANALYSIS, localename: windows_core::PCWSTR, baselinecoordinate: *mut i32, exists: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalyzer1", "GetBaseline", 11usize, |this| ((**(this as *const *const IDWriteTextAnalyzer1_Vtbl)).GetBaseline)(this, fontface, baseline, isvertical, issimulationallowed, scriptanalysis, localename, baselinecoordinate, exists), |result| Some(*result))
+        }
+        unsafe extern "system" fn AnalyzeVerticalGlyphOrientation(this: *mut core::ffi::c_void, analysissource: *mut core::ffi::c_void, textposition: u32, textlength: u32, analysissink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalyzer1", "AnalyzeVerticalGlyphOrientation", 12usize, |this| ((**(this as *const *const IDWriteTextAnalyzer1_Vtbl)).AnalyzeVerticalGlyphOrientation)(this, analysissource, textposition, textlength, analysissink), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGlyphOrientationTransform(this: *mut core::ffi::c_void, glyphorientationangle: DWRITE_GLYPH_ORIENTATION_ANGLE, issideways: super::super::Foundation::BOOL, transform: *mut DWRITE_MATRIX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalyzer1", "GetGlyphOrientationTransform", 13usize, |this| ((**(this as *const *const IDWriteTextAnalyzer1_Vtbl)).GetGlyphOrientationTransform)(this, glyphorientationangle, issideways, transform), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScriptProperties(this: *mut core::ffi::c_void, scriptanalysis: DWRITE_SCRIPT_ANALYSIS, scriptproperties: *mut DWRITE_SCRIPT_PROPERTIES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalyzer1", "GetScriptProperties", 14usize, |this| ((**(this as *const *const IDWriteTextAnalyzer1_Vtbl)).GetScriptProperties)(this, scriptanalysis, scriptproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTextComplexity(this: *mut core::ffi::c_void, textstring: windows_core::PCWSTR, textlength: u32, fontface: *mut core::ffi::c_void, istextsimple: *mut super::super::Foundation::BOOL, textlengthread: *mut u32, glyphindices: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalyzer1", "GetTextComplexity", 15usize, |this| ((**(this as *const *const IDWriteTextAnalyzer1_Vtbl)).GetTextComplexity)(this, textstring, textlength, fontface, istextsimple, textlengthread, glyphindices), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetJustificationOpportunities(this: *mut core::ffi::c_void, fontface: *mut core::ffi::c_void, fontemsize: f32, scriptanalysis: DWRITE_SCRIPT_ANALYSIS, textlength: u32, glyphcount: u32, textstring: windows_core::PCWSTR, clustermap: *const u16, glyphproperties: *const DWRITE_SHAPING_GLYPH_PROPERTIES, justificationopportunities: *mut DWRITE_JUSTIFICATION_OPPORTUNITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalyzer1", "GetJustificationOpportunities", 16usize, |this| ((**(this as *const *const IDWriteTextAnalyzer1_Vtbl)).GetJustificationOpportunities)(this, fontface, fontemsize, scriptanalysis, textlength, glyphcount, textstring, clustermap, glyphproperties, justificationopportunities), |result| Some(*result))
+        }
+        unsafe extern "system" fn JustifyGlyphAdvances(this: *mut core::ffi::c_void, linewidth: f32, glyphcount: u32, justificationopportunities: *const DWRITE_JUSTIFICATION_OPPORTUNITY, glyphadvances: *const f32, glyphoffsets: *const DWRITE_GLYPH_OFFSET, justifiedglyphadvances: *mut f32, justifiedglyphoffsets: *mut DWRITE_GLYPH_OFFSET) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalyzer1", "JustifyGlyphAdvances", 17usize, |this| ((**(this as *const *const IDWriteTextAnalyzer1_Vtbl)).JustifyGlyphAdvances)(this, linewidth, glyphcount, justificationopportunities, glyphadvances, glyphoffsets, justifiedglyphadvances, justifiedglyphoffsets), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetJustifiedGlyphs(this: *mut core::ffi::c_void, fontface: *mut core::ffi::c_void, fontemsize: f32, scriptanalysis: DWRITE_SCRIPT_ANALYSIS, textlength: u32, glyphcount: u32, maxglyphcount: u32, clustermap: *const u16, glyphindices: *const u16, glyphadvances: *const f32, justifiedglyphadvances: *const f32, justifiedglyphoffsets: *const DWRITE_GLYPH_OFFSET, glyphproperties: *const DWRITE_SHAPING_GLYPH_PROPERTIES, actualglyphcount: *mut u32, modifiedclustermap: *mut u16, modifiedglyphindices: *mut u16, modifiedglyphadvances: *mut f32, modifiedglyphoffsets: *mut DWRITE_GLYPH_OFFSET) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalyzer1", "GetJustifiedGlyphs", 18usize, |this| ((**(this as *const *const IDWriteTextAnalyzer1_Vtbl)).GetJustifiedGlyphs)(this, fontface, fontemsize, scriptanalysis, textlength, glyphcount, maxglyphcount, clustermap, glyphindices, glyphadvances, justifiedglyphadvances, justifiedglyphoffsets, glyphproperties, actualglyphcount, modifiedclustermap, modifiedglyphindices, modifiedglyphadvances, modifiedglyphoffsets), |result| Some(*result))
+        }
+        Self {
+            base__: <IDWriteTextAnalyzer_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ApplyCharacterSpacing,
+            GetBaseline,
+            AnalyzeVerticalGlyphOrientation,
+            GetGlyphOrientationTransform,
+            GetScriptProperties,
+            GetTextComplexity,
+            GetJustificationOpportunities,
+            JustifyGlyphAdvances,
+            GetJustifiedGlyphs,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteTextAnalyzer1 as windows_core::Interface>::IID || <IDWriteTextAnalyzer_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteTextAnalyzer2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetGlyphOrientationTransform(this: *mut core::ffi::c_void, glyphorientationangle: DWRITE_GLYPH_ORIENTATION_ANGLE, issideways: super::super::Foundation::BOOL, originx: f32, originy: f32, transform: *mut DWRITE_MATRIX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalyzer2", "GetGlyphOrientationTransform", 19usize, |this| ((**(this as *const *const IDWriteTextAnalyzer2_Vtbl)).GetGlyphOrientationTransform)(this, glyphorientationangle, issideways, originx, originy, transform), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTypographicFeatures(this: *mut core::ffi::c_void, fontface: *mut core::ffi::c_void, scriptanalysis: DWRITE_SCRIPT_ANALYSIS, localename: windows_core::PCWSTR, maxtagcount: u32, actualtagcount: *mut u32, tags: *mut DWRITE_FONT_FEATURE_TAG) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalyzer2", "GetTypographicFeatures", 20usize, |this| ((**(this as *const *const IDWriteTextAnalyzer2_Vtbl)).GetTypographicFeatures)(this, fontface, scriptanalysis, localename, maxtagcount, actualtagcount, tags), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckTypographicFeature(this: *mut core::ffi::c_void, fontface: *mut core::ffi::c_void, scriptanalysis: DWRITE_SCRIPT_ANALYSIS, localename: windows_core::PCWSTR, featuretag: DWRITE_FONT_FEATURE_TAG, glyphcount: u32, glyphindices: *const u16, featureapplies: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextAnalyzer2", "CheckTypographicFeature", 21usize, |this| ((**(this as *const *const IDWriteTextAnalyzer2_Vtbl)).CheckTypographicFeature)(this, fontface, scriptanalysis, localename, featuretag, glyphcount, glyphindices, featureapplies), |result| Some(*result))
+        }
+        Self {
+            base__: <IDWriteTextAnalyzer1_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetGlyphOrientationTransform,
+            GetTypographicFeatures,
+            CheckTypographicFeature,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteTextAnalyzer2 as windows_core::Interface>::IID || <IDWriteTextAnalyzer1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteTextFormat_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetTextAlignment(this: *mut core::ffi::c_void, textalignment: DWRITE_TEXT_ALIGNMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat", "SetTextAlignment", 3usize, |this| ((**(this as *const *const IDWriteTextFormat_Vtbl)).SetTextAlignment)(this, textalignment), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParagraphAlignment(this: *mut core::ffi::c_void, paragraphalignment: DWRITE_PARAGRAPH_ALIGNMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat", "SetParagraphAlignment", 4usize, |this| ((**(this as *const *const IDWriteTextFormat_Vtbl)).SetParagraphAlignment)(this, paragraphalignment), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWordWrapping(this: *mut core::ffi::c_void, wordwrapping: DWRITE_WORD_WRAPPING) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat", "SetWordWrapping", 5usize, |this| ((**(this as *const *const IDWriteTextFormat_Vtbl)).SetWordWrapping)(this, wordwrapping), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReadingDirection(this: *mut core::ffi::c_void, readingdirection: DWRITE_READING_DIRECTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat", "SetReadingDirection", 6usize, |this| ((**(this as *const *const IDWriteTextFormat_Vtbl)).SetReadingDirection)(this, readingdirection), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFlowDirection(this: *mut core::ffi::c_void, flowdirection: DWRITE_FLOW_DIRECTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat", "SetFlowDirection", 7usize, |this| ((**(this as *const *const IDWriteTextFormat_Vtbl)).SetFlowDirection)(this, flowdirection), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIncrementalTabStop(this: *mut core::ffi::c_void, incrementaltabstop: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat", "SetIncrementalTabStop", 8usize, |this| ((**(this as *const *const IDWriteTextFormat_Vtbl)).SetIncrementalTabStop)(this, incrementaltabstop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTrimming(this: *mut core::ffi::c_void, trimmingoptions: *const DWRITE_TRIMMING, trimmingsign: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat", "SetTrimming", 9usize, |this| ((**(this as *const *const IDWriteTextFormat_Vtbl)).SetTrimming)(this, trimmingoptions, trimmingsign), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLineSpacing(this: *mut core::ffi::c_void, linespacingmethod: DWRITE_LINE_SPACING_METHOD, linespacing: f32, baseline: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat", "SetLineSpacing", 10usize, |this| ((**(this as *const *const IDWriteTextFormat_Vtbl)).SetLineSpacing)(this, linespacingmethod, linespacing, baseline), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTextAlignment(this: *mut core::ffi::c_void) -> DWRITE_TEXT_ALIGNMENT {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat", "GetTextAlignment", 11usize, |this| ((**(this as *const *const IDWriteTextFormat_Vtbl)).GetTextAlignment)(this), |_| None)
+        }
+        unsafe extern "system" fn GetParagraphAlignment(this: *mut core::ffi::c_void) -> DWRITE_PARAGRAPH_ALIGNMENT {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat", "GetParagraphAlignment", 12usize, |this| ((**(this as *const *const IDWriteTextFormat_Vtbl)).GetParagraphAlignment)(this), |_| None)
+        }
+        unsafe extern "system" fn GetWordWrapping(this: *mut core::ffi::c_void) -> DWRITE_WORD_WRAPPING {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat", "GetWordWrapping", 13usize, |this| ((**(this as *const *const IDWriteTextFormat_Vtbl)).GetWordWrapping)(this), |_| None)
+        }
+        unsafe extern "system" fn GetReadingDirection(this: *mut core::ffi::c_void) -> DWRITE_READING_DIRECTION {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat", "GetReadingDirection", 14usize, |this| ((**(this as *const *const IDWriteTextFormat_Vtbl)).GetReadingDirection)(this), |_| None)
+        }
+        unsafe extern "system" fn GetFlowDirection(this: *mut core::ffi::c_void) -> DWRITE_FLOW_DIRECTION {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat", "GetFlowDirection", 15usize, |this| ((**(this as *const *const IDWriteTextFormat_Vtbl)).GetFlowDirection)(this), |_| None)
+        }
+        unsafe extern "system" fn GetIncrementalTabStop(this: *mut core::ffi::c_void) -> f32 {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat", "GetIncrementalTabStop", 16usize, |this| ((**(this as *const *const IDWriteTextFormat_Vtbl)).GetIncrementalTabStop)(this), |_| None)
+        }
+        unsafe extern "system" fn GetTrimming(this: *mut core::ffi::c_void, trimmingoptions: *mut DWRITE_TRIMMING, trimmingsign: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat", "GetTrimming", 17usize, |this| ((**(this as *const *const IDWriteTextFormat_Vtbl)).GetTrimming)(this, trimmingoptions, trimmingsign), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLineSpacing(this: *mut core::ffi::c_void, linespacingmethod: *mut DWRITE_LINE_SPACING_METHOD, linespacing: *mut f32, baseline: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat", "GetLineSpacing", 18usize, |this| ((**(this as *const *const IDWriteTextFormat_Vtbl)).GetLineSpacing)(this, linespacingmethod, linespacing, baseline), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontCollection(this: *mut core::ffi::c_void, fontcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat", "GetFontCollection", 19usize, |this| ((**(this as *const *const IDWriteTextFormat_Vtbl)).GetFontCollection)(this, fontcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontFamilyNameLength(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat", "GetFontFamilyNameLength", 20usize, |this| ((**(this as *const *const IDWriteTextFormat_Vtbl)).GetFontFamilyNameLength)(this), |_| None)
+        }
+        unsafe extern "system" fn GetFontFamilyName(this: *mut core::ffi::c_void, fontfamilyname: windows_core::PWSTR, namesize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat", "GetFontFamilyName", 21usize, |this| ((**(this as *const *const IDWriteTextFormat_Vtbl)).GetFontFamilyName)(this, fontfamilyname, namesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontWeight(this: *mut core::ffi::c_void) -> DWRITE_FONT_WEIGHT {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat", "GetFontWeight", 22usize, |this| ((**(this as *const *const IDWriteTextFormat_Vtbl)).GetFontWeight)(this), |_| None)
+        }
+        unsafe extern "system" fn GetFontStyle(this: *mut core::ffi::c_void) -> DWRITE_FONT_STYLE {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat", "GetFontStyle", 23usize, |this| ((**(this as *const *const IDWriteTextFormat_Vtbl)).GetFontStyle)(this), |_| None)
+        }
+        unsafe extern "system" fn GetFontStretch(this: *mut core::ffi::c_void) -> DWRITE_FONT_STRETCH {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat", "GetFontStretch", 24usize, |this| ((**(this as *const *const IDWriteTextFormat_Vtbl)).GetFontStretch)(this), |_| None)
+        }
+        unsafe extern "system" fn GetFontSize(this: *mut core::ffi::c_void) -> f32 {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat", "GetFontSize", 25usize, |this| ((**(this as *const *const IDWriteTextFormat_Vtbl)).GetFontSize)(this), |_| None)
+        }
+        unsafe extern "system" fn GetLocaleNameLength(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat", "GetLocaleNameLength", 26usize, |this| ((**(this as *const *const IDWriteTextFormat_Vtbl)).GetLocaleNameLength)(this), |_| None)
+        }
+        unsafe extern "system" fn GetLocaleName(this: *mut core::ffi::c_void, localename: windows_core::PWSTR, namesize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat", "GetLocaleName", 27usize, |this| ((**(this as *const *const IDWriteTextFormat_Vtbl)).GetLocaleName)(this, localename, namesize), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetTextAlignment,
+            SetParagraphAlignment,
+            SetWordWrapping,
+            SetReadingDirection,
+            SetFlowDirection,
+            SetIncrementalTabStop,
+            SetTrimming,
+            SetLineSpacing,
+            GetTextAlignment,
+            GetParagraphAlignment,
+            GetWordWrapping,
+            GetReadingDirection,
+            GetFlowDirection,
+            GetIncrementalTabStop,
+            GetTrimming,
+            GetLineSpacing,
+            GetFontCollection,
+            GetFontFamilyNameLength,
+            GetFontFamilyName,
+            GetFontWeight,
+            GetFontStyle,
+            GetFontStretch,
+            GetFontSize,
+            GetLocaleNameLength,
+            GetLocaleName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteTextFormat as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteTextFormat1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetVerticalGlyphOrientation(this: *mut core::ffi::c_void, glyphorientation: DWRITE_VERTICAL_GLYPH_ORIENTATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat1", "SetVerticalGlyphOrientation", 28usize, |this| ((**(this as *const *const IDWriteTextFormat1_Vtbl)).SetVerticalGlyphOrientation)(this, glyphorientation), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVerticalGlyphOrientation(this: *mut core::ffi::c_void) -> DWRITE_VERTICAL_GLYPH_ORIENTATION {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat1", "GetVerticalGlyphOrientation", 29usize, |this| ((**(this as *const *const IDWriteTextFormat1_Vtbl)).GetVerticalGlyphOrientation)(this), |_| None)
+        }
+        unsafe extern "system" fn SetLastLineWrapping(this: *mut core::ffi::c_void, islastlinewrappingenabled: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat1", "SetLastLineWrapping", 30usize, |this| ((**(this as *const *const IDWriteTextFormat1_Vtbl)).SetLastLineWrapping)(this, islastlinewrappingenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastLineWrapping(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat1", "GetLastLineWrapping", 31usize, |this| ((**(this as *const *const IDWriteTextFormat1_Vtbl)).GetLastLineWrapping)(this), |_| None)
+        }
+        unsafe extern "system" fn SetOpticalAlignment(this: *mut core::ffi::c_void, opticalalignment: DWRITE_OPTICAL_ALIGNMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat1", "SetOpticalAlignment", 32usize, |this| ((**(this as *const *const IDWriteTextFormat1_Vtbl)).SetOpticalAlignment)(this, opticalalignment), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOpticalAlignment(this: *mut core::ffi::c_void) -> DWRITE_OPTICAL_ALIGNMENT {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat1", "GetOpticalAlignment", 33usize, |this| ((**(this as *const *const IDWriteTextFormat1_Vtbl)).GetOpticalAlignment)(this), |_| None)
+        }
+        unsafe extern "system" fn SetFontFallback(this: *mut core::ffi::c_void, fontfallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat1", "SetFontFallback", 34usize, |this| ((**(this as *const *const IDWriteTextFormat1_Vtbl)).SetFontFallback)(this, fontfallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontFallback(this: *mut core::ffi::c_void, fontfallback: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat1", "GetFontFallback", 35usize, |this| ((**(this as *const *const IDWriteTextFormat1_Vtbl)).GetFontFallback)(this, fontfallback), |result| Some(*result))
+        }
+        Self {
+            base__: <IDWriteTextFormat_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetVerticalGlyphOrientation,
+            GetVerticalGlyphOrientation,
+            SetLastLineWrapping,
+            GetLastLineWrapping,
+            SetOpticalAlignment,
+            GetOpticalAlignment,
+            SetFontFallback,
+            GetFontFallback,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteTextFormat1 as windows_core::Interface>::IID || <IDWriteTextFormat_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteTextFormat2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetLineSpacing(this: *mut core::ffi::c_void, linespacingoptions: *const DWRITE_LINE_SPACING) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat2", "SetLineSpacing", 36usize, |this| ((**(this as *const *const IDWriteTextFormat2_Vtbl)).SetLineSpacing)(this, linespacingoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLineSpacing(this: *mut core::ffi::c_void, linespacingoptions: *mut DWRITE_LINE_SPACING) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat2", "GetLineSpacing", 37usize, |this| ((**(this as *const *const IDWriteTextFormat2_Vtbl)).GetLineSpacing)(this, linespacingoptions), |result| Some(*result))
+        }
+        Self { base__: <IDWriteTextFormat1_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetLineSpacing, GetLineSpacing }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteTextFormat2 as windows_core::Interface>::IID || <IDWriteTextFormat1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteTextFormat3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetFontAxisValues(this: *mut core::ffi::c_void, fontaxisvalues: *const DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat3", "SetFontAxisValues", 38usize, |this| ((**(this as *const *const IDWriteTextFormat3_Vtbl)).SetFontAxisValues)(this, fontaxisvalues, fontaxisvaluecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontAxisValueCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat3", "GetFontAxisValueCount", 39usize, |this| ((**(this as *const *const IDWriteTextFormat3_Vtbl)).GetFontAxisValueCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetFontAxisValues(this: *mut core::ffi::c_void, fontaxisvalues: *mut DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat3", "GetFontAxisValues", 40usize, |this| ((**(this as *const *const IDWriteTextFormat3_Vtbl)).GetFontAxisValues)(this, fontaxisvalues, fontaxisvaluecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAutomaticFontAxes(this: *mut core::ffi::c_void) -> DWRITE_AUTOMATIC_FONT_AXES {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat3", "GetAutomaticFontAxes", 41usize, |this| ((**(this as *const *const IDWriteTextFormat3_Vtbl)).GetAutomaticFontAxes)(this), |_| None)
+        }
+        unsafe extern "system" fn SetAutomaticFontAxes(this: *mut core::ffi::c_void, automaticfontaxes: DWRITE_AUTOMATIC_FONT_AXES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextFormat3", "SetAutomaticFontAxes", 42usize, |this| ((**(this as *const *const IDWriteTextFormat3_Vtbl)).SetAutomaticFontAxes)(this, automaticfontaxes), |result| Some(*result))
+        }
+        Self {
+            base__: <IDWriteTextFormat2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetFontAxisValues,
+            GetFontAxisValueCount,
+            GetFontAxisValues,
+            GetAutomaticFontAxes,
+            SetAutomaticFontAxes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteTextFormat3 as windows_core::Interface>::IID || <IDWriteTextFormat2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteTextLayout_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMaxWidth(this: *mut core::ffi::c_void, maxwidth: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "SetMaxWidth", 28usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).SetMaxWidth)(this, maxwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxHeight(this: *mut core::ffi::c_void, maxheight: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "SetMaxHeight", 29usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).SetMaxHeight)(this, maxheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFontCollection(this: *mut core::ffi::c_void, fontcollection: *mut core::ffi::c_void, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "SetFontCollection", 30usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).SetFontCollection)(this, fontcollection, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFontFamilyName(this: *mut core::ffi::c_void, fontfamilyname: windows_core::PCWSTR, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "SetFontFamilyName", 31usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).SetFontFamilyName)(this, fontfamilyname, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFontWeight(this: *mut core::ffi::c_void, fontweight: DWRITE_FONT_WEIGHT, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "SetFontWeight", 32usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).SetFontWeight)(this, fontweight, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFontStyle(this: *mut core::ffi::c_void, fontstyle: DWRITE_FONT_STYLE, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "SetFontStyle", 33usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).SetFontStyle)(this, fontstyle, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFontStretch(this: *mut core::ffi::c_void, fontstretch: DWRITE_FONT_STRETCH, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "SetFontStretch", 34usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).SetFontStretch)(this, fontstretch, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFontSize(this: *mut core::ffi::c_void, fontsize: f32, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "SetFontSize", 35usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).SetFontSize)(this, fontsize, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUnderline(this: *mut core::ffi::c_void, hasunderline: super::super::Foundation::BOOL, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "SetUnderline", 36usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).SetUnderline)(this, hasunderline, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrikethrough(this: *mut core::ffi::c_void, hasstrikethrough: super::super::Foundation::BOOL, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "SetStrikethrough", 37usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).SetStrikethrough)(this, hasstrikethrough, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDrawingEffect(this: *mut core::ffi::c_void, drawingeffect: *mut core::ffi::c_void, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "SetDrawingEffect", 38usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).SetDrawingEffect)(this, drawingeffect, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInlineObject(this: *mut core::ffi::c_void, inlineobject: *mut core::ffi::c_void, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "SetInlineObject", 39usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).SetInlineObject)(this, inlineobject, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTypography(this: *mut core::ffi::c_void, typography: *mut core::ffi::c_void, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "SetTypography", 40usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).SetTypography)(this, typography, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocaleName(this: *mut core::ffi::c_void, localename: windows_core::PCWSTR, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "SetLocaleName", 41usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).SetLocaleName)(this, localename, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxWidth(this: *mut core::ffi::c_void) -> f32 {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "GetMaxWidth", 42usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).GetMaxWidth)(this), |_| None)
+        }
+        unsafe extern "system" fn GetMaxHeight(this: *mut core::ffi::c_void) -> f32 {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "GetMaxHeight", 43usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).GetMaxHeight)(this), |_| None)
+        }
+        unsafe extern "system" fn GetFontCollection(this: *mut core::ffi::c_void, currentposition: u32, fontcollection: *mut *mut core::ffi::c_void, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "GetFontCollection", 44usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).GetFontCollection)(this, currentposition, fontcollection, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontFamilyNameLength(this: *mut core::ffi::c_void, currentposition: u32, namelength: *mut u32, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "GetFontFamilyNameLength", 45usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).GetFontFamilyNameLength)(this, currentposition, namelength, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontFamilyName(this: *mut core::ffi::c_void, currentposition: u32, fontfamilyname: windows_core::PWSTR, namesize: u32, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "GetFontFamilyName", 46usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).GetFontFamilyName)(this, currentposition, fontfamilyname, namesize, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontWeight(this: *mut core::ffi::c_void, currentposition: u32, fontweight: *mut DWRITE_FONT_WEIGHT, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "GetFontWeight", 47usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).GetFontWeight)(this, currentposition, fontweight, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontStyle(this: *mut core::ffi::c_void, currentposition: u32, fontstyle: *mut DWRITE_FONT_STYLE, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "GetFontStyle", 48usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).GetFontStyle)(this, currentposition, fontstyle, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontStretch(this: *mut core::ffi::c_void, currentposition: u32, fontstretch: *mut DWRITE_FONT_STRETCH, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "GetFontStretch", 49usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).GetFontStretch)(this, currentposition, fontstretch, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontSize(this: *mut core::ffi::c_void, currentposition: u32, fontsize: *mut f32, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "GetFontSize", 50usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).GetFontSize)(this, currentposition, fontsize, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUnderline(this: *mut core::ffi::c_void, currentposition: u32, hasunderline: *mut super::super::Foundation::BOOL, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "GetUnderline", 51usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).GetUnderline)(this, currentposition, hasunderline, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStrikethrough(this: *mut core::ffi::c_void, currentposition: u32, hasstrikethrough: *mut super::super::Foundation::BOOL, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "GetStrikethrough", 52usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).GetStrikethrough)(this, currentposition, hasstrikethrough, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDrawingEffect(this: *mut core::ffi::c_void, currentposition: u32, drawingeffect: *mut *mut core::ffi::c_void, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "GetDrawingEffect", 53usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).GetDrawingEffect)(this, currentposition, drawingeffect, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInlineObject(this: *mut core::ffi::c_void, currentposition: u32, inlineobject: *mut *mut core::ffi::c_void, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "GetInlineObject", 54usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).GetInlineObject)(this, currentposition, inlineobject, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTypography(this: *mut core::ffi::c_void, currentposition: u32, typography: *mut *mut core::ffi::c_void, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "GetTypography", 55usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).GetTypography)(this, currentposition, typography, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocaleNameLength(this: *mut core::ffi::c_void, currentposition: u32, namelength: *mut u32, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "GetLocaleNameLength", 56usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).GetLocaleNameLength)(this, currentposition, namelength, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocaleName(this: *mut core::ffi::c_void, currentposition: u32, localename: windows_core::PWSTR, namesize: u32, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "GetLocaleName", 57usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).GetLocaleName)(this, currentposition, localename, namesize, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn Draw(this: *mut core::ffi::c_void, clientdrawingcontext: *const core::ffi::c_void, renderer: *mut core::ffi::c_void, originx: f32, originy: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "Draw", 58usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).Draw)(this, clientdrawingcontext, renderer, originx, originy), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLineMetrics(this: *mut core::ffi::c_void, linemetrics: *mut DWRITE_LINE_METRICS, maxlinecount: u32, actuallinecount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "GetLineMetrics", 59usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).GetLineMetrics)(this, linemetrics, maxlinecount, actuallinecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMetrics(this: *mut core::ffi::c_void, textmetrics: *mut DWRITE_TEXT_METRICS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "GetMetrics", 60usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).GetMetrics)(this, textmetrics), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOverhangMetrics(this: *mut core::ffi::c_void, overhangs: *mut DWRITE_OVERHANG_METRICS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "GetOverhangMetrics", 61usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).GetOverhangMetrics)(this, overhangs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClusterMetrics(this: *mut core::ffi::c_void, clustermetrics: *mut DWRITE_CLUSTER_METRICS, maxclustercount: u32, actualclustercount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "GetClusterMetrics", 62usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).GetClusterMetrics)(this, clustermetrics, maxclustercount, actualclustercount), |result| Some(*result))
+        }
+        unsafe extern "system" fn DetermineMinWidth(this: *mut core::ffi::c_void, minwidth: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "DetermineMinWidth", 63usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).DetermineMinWidth)(this, minwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn HitTestPoint(this: *mut core::ffi::c_void, pointx: f32, pointy: f32, istrailinghit: *mut super::super::Foundation::BOOL, isinside: *mut super::super::Foundation::BOOL, hittestmetrics: *mut DWRITE_HIT_TEST_METRICS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "HitTestPoint", 64usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).HitTestPoint)(this, pointx, pointy, istrailinghit, isinside, hittestmetrics), |result| Some(*result))
+        }
+        unsafe extern "system" fn HitTestTextPosition(this: *mut core::ffi::c_void, textposition: u32, istrailinghit: super::super::Foundation::BOOL, pointx: *mut f32, pointy: *mut f32, hittestmetrics: *mut DWRITE_HIT_TEST_METRICS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "HitTestTextPosition", 65usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).HitTestTextPosition)(this, textposition, istrailinghit, pointx, pointy, hittestmetrics), |result| Some(*result))
+        }
+        unsafe extern "system" fn HitTestTextRange(this: *mut core::ffi::c_void, textposition: u32, textlength: u32, originx: f32, originy: f32, hittestmetrics: *mut DWRITE_HIT_TEST_METRICS, maxhittestmetricscount: u32, actualhittestmetricscount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout", "HitTestTextRange", 66usize, |this| ((**(this as *const *const IDWriteTextLayout_Vtbl)).HitTestTextRange)(this, textposition, textlength, originx, originy, hittestmetrics, maxhittestmetricscount, actualhittestmetricscount), |result| Some(*result))
+        }
+        Self {
+            base__: <IDWriteTextFormat_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetMaxWidth,
+            SetMaxHeight,
+            SetFontCollection,
+            SetFontFamilyName,
+            SetFontWeight,
+            SetFontStyle,
+            SetFontStretch,
+            SetFontSize,
+            SetUnderline,
+            SetStrikethrough,
+            SetDrawingEffect,
+            SetInlineObject,
+            SetTypography,
+            SetLocaleName,
+            GetMaxWidth,
+            GetMaxHeight,
+            GetFontCollection,
+            GetFontFamilyNameLength,
+            GetFontFamilyName,
+            GetFontWeight,
+            GetFontStyle,
+            GetFontStretch,
+            GetFontSize,
+            GetUnderline,
+            GetStrikethrough,
+            GetDrawingEffect,
+            GetInlineObject,
+            GetTypography,
+            GetLocaleNameLength,
+            GetLocaleName,
+            Draw,
+            GetLineMetrics,
+            GetMetrics,
+            GetOverhangMetrics,
+            GetClusterMetrics,
+            DetermineMinWidth,
+            HitTestPoint,
+            HitTestTextPosition,
+            HitTestTextRange,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteTextLayout as windows_core::Interface>::IID || <IDWriteTextFormat_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteTextLayout1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPairKerning(this: *mut core::ffi::c_void, ispairkerningenabled: super::super::Foundation::BOOL, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout1", "SetPairKerning", 67usize, |this| ((**(this as *const *const IDWriteTextLayout1_Vtbl)).SetPairKerning)(this, ispairkerningenabled, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPairKerning(this: *mut core::ffi::c_void, currentposition: u32, ispairkerningenabled: *mut super::super::Foundation::BOOL, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout1", "GetPairKerning", 68usize, |this| ((**(this as *const *const IDWriteTextLayout1_Vtbl)).GetPairKerning)(this, currentposition, ispairkerningenabled, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCharacterSpacing(this: *mut core::ffi::c_void, leadingspacing: f32, trailingspacing: f32, minimumadvancewidth: f32, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout1", "SetCharacterSpacing", 69usize, |this| ((**(this as *const *const IDWriteTextLayout1_Vtbl)).SetCharacterSpacing)(this, leadingspacing, trailingspacing, minimumadvancewidth, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCharacterSpacing(this: *mut core::ffi::c_void, currentposition: u32, leadingspacing: *mut f32, trailingspacing: *mut f32, minimumadvancewidth: *mut f32, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout1", "GetCharacterSpacing", 70usize, |this| ((**(this as *const *const IDWriteTextLayout1_Vtbl)).GetCharacterSpacing)(this, currentposition, leadingspacing, trailingspacing, minimumadvancewidth, textrange), |result| Some(*result))
+        }
+        Self {
+            base__: <IDWriteTextLayout_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetPairKerning,
+            GetPairKerning,
+            SetCharacterSpacing,
+            GetCharacterSpacing,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteTextLayout1 as windows_core::Interface>::IID || <IDWriteTextLayout_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteTextLayout2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMetrics(this: *mut core::ffi::c_void, textmetrics: *mut DWRITE_TEXT_METRICS1) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout2", "GetMetrics", 71usize, |this| ((**(this as *const *const IDWriteTextLayout2_Vtbl)).GetMetrics)(this, textmetrics), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVerticalGlyphOrientation(this: *mut core::ffi::c_void, glyphorientation: DWRITE_VERTICAL_GLYPH_ORIENTATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout2", "SetVerticalGlyphOrientation", 72usize, |this| ((**(this as *const *const IDWriteTextLayout2_Vtbl)).SetVerticalGlyphOrientation)(this, glyphorientation), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVerticalGlyphOrientation(this: *mut core::ffi::c_void) -> DWRITE_VERTICAL_GLYPH_ORIENTATION {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout2", "GetVerticalGlyphOrientation", 73usize, |this| ((**(this as *const *const IDWriteTextLayout2_Vtbl)).GetVerticalGlyphOrientation)(this), |_| None)
+        }
+        unsafe extern "system" fn SetLastLineWrapping(this: *mut core::ffi::c_void, islastlinewrappingenabled: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout2", "SetLastLineWrapping", 74usize, |this| ((**(this as *const *const IDWriteTextLayout2_Vtbl)).SetLastLineWrapping)(this, islastlinewrappingenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastLineWrapping(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout2", "GetLastLineWrapping", 75usize, |this| ((**(this as *const *const IDWriteTextLayout2_Vtbl)).GetLastLineWrapping)(this), |_| None)
+        }
+        unsafe extern "system" fn SetOpticalAlignment(this: *mut core::ffi::c_void, opticalalignment: DWRITE_OPTICAL_ALIGNMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout2", "SetOpticalAlignment", 76usize, |this| ((**(this as *const *const IDWriteTextLayout2_Vtbl)).SetOpticalAlignment)(this, opticalalignment), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOpticalAlignment(this: *mut core::ffi::c_void) -> DWRITE_OPTICAL_ALIGNMENT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout2", "GetOpticalAlignment", 77usize, |this| ((**(this as *const *const IDWriteTextLayout2_Vtbl)).GetOpticalAlignment)(this), |_| None)
+        }
+        unsafe extern "system" fn SetFontFallback(this: *mut core::ffi::c_void, fontfallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout2", "SetFontFallback", 78usize, |this| ((**(this as *const *const IDWriteTextLayout2_Vtbl)).SetFontFallback)(this, fontfallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontFallback(this: *mut core::ffi::c_void, fontfallback: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout2", "GetFontFallback", 79usize, |this| ((**(this as *const *const IDWriteTextLayout2_Vtbl)).GetFontFallback)(this, fontfallback), |result| Some(*result))
+        }
+        Self {
+            base__: <IDWriteTextLayout1_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetMetrics,
+            SetVerticalGlyphOrientation,
+            GetVerticalGlyphOrientation,
+            SetLastLineWrapping,
+            GetLastLineWrapping,
+            SetOpticalAlignment,
+            GetOpticalAlignment,
+            SetFontFallback,
+            GetFontFallback,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteTextLayout2 as windows_core::Interface>::IID || <IDWriteTextLayout1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteTextLayout3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InvalidateLayout(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout3", "InvalidateLayout", 80usize, |this| ((**(this as *const *const IDWriteTextLayout3_Vtbl)).InvalidateLayout)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLineSpacing(this: *mut core::ffi::c_void, linespacingoptions: *const DWRITE_LINE_SPACING) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout3", "SetLineSpacing", 81usize, |this| ((**(this as *const *const IDWriteTextLayout3_Vtbl)).SetLineSpacing)(this, linespacingoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLineSpacing(this: *mut core::ffi::c_void, linespacingoptions: *mut DWRITE_LINE_SPACING) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout3", "GetLineSpacing", 82usize, |this| ((**(this as *const *const IDWriteTextLayout3_Vtbl)).GetLineSpacing)(this, linespacingoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLineMetrics(this: *mut core::ffi::c_void, linemetrics: *mut DWRITE_LINE_METRICS1, maxlinecount: u32, actuallinecount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout3", "GetLineMetrics", 83usize, |this| ((**(this as *const *const IDWriteTextLayout3_Vtbl)).GetLineMetrics)(this, linemetrics, maxlinecount, actuallinecount), |result| Some(*result))
+        }
+        Self { base__: <IDWriteTextLayout2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InvalidateLayout, SetLineSpacing, GetLineSpacing, GetLineMetrics }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteTextLayout3 as windows_core::Interface>::IID || <IDWriteTextLayout2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteTextLayout4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetFontAxisValues(this: *mut core::ffi::c_void, fontaxisvalues: *const DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32, textrange: DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout4", "SetFontAxisValues", 84usize, |this| ((**(this as *const *const IDWriteTextLayout4_Vtbl)).SetFontAxisValues)(this, fontaxisvalues, fontaxisvaluecount, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontAxisValueCount(this: *mut core::ffi::c_void, currentposition: u32) -> u32 {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout4", "GetFontAxisValueCount", 85usize, |this| ((**(this as *const *const IDWriteTextLayout4_Vtbl)).GetFontAxisValueCount)(this, currentposition), |_| None)
+        }
+        unsafe extern "system" fn GetFontAxisValues(this: *mut core::ffi::c_void, currentposition: u32, fontaxisvalues: *mut DWRITE_FONT_AXIS_VALUE, fontaxisvaluecount: u32, textrange: *mut DWRITE_TEXT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout4", "GetFontAxisValues", 86usize, |this| ((**(this as *const *const IDWriteTextLayout4_Vtbl)).GetFontAxisValues)(this, currentposition, fontaxisvalues, fontaxisvaluecount, textrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAutomaticFontAxes(this: *mut core::ffi::c_void) -> DWRITE_AUTOMATIC_FONT_AXES {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout4", "GetAutomaticFontAxes", 87usize, |this| ((**(this as *const *const IDWriteTextLayout4_Vtbl)).GetAutomaticFontAxes)(this), |_| None)
+        }
+        unsafe extern "system" fn SetAutomaticFontAxes(this: *mut core::ffi::c_void, automaticfontaxes: DWRITE_AUTOMATIC_FONT_AXES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextLayout4", "SetAutomaticFontAxes", 88usize, |this| ((**(this as *const *const IDWriteTextLayout4_Vtbl)).SetAutomaticFontAxes)(this, automaticfontaxes), |result| Some(*result))
+        }
+        Self {
+            base__: <IDWriteTextLayout3_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetFontAxisValues,
+            GetFontAxisValueCount,
+            GetFontAxisValues,
+            GetAutomaticFontAxes,
+            SetAutomaticFontAxes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteTextLayout4 as windows_core::Interface>::IID || <IDWriteTextLayout3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteTextRenderer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DrawGlyphRun(this: *mut core::ffi::c_void, clientdrawingcontext: *const core::ffi::c_void, baselineoriginx: f32, baselineoriginy: f32, measuringmode: DWRITE_MEASURING_MODE, glyphrun: *const DWRITE_GLYPH_RUN, glyphrundescription: *const DWRITE_GLYPH_RUN_DESCRIPTION, clientdrawingeffect: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextRenderer", "DrawGlyphRun", 6usize, |this| ((**(this as *const *const IDWriteTextRenderer_Vtbl)).DrawGlyphRun)(this, clientdrawingcontext, baselineoriginx, baselineoriginy, measuringmode, glyphrun, glyphrundescription, clientdrawingeffect), |result| Some(*result))
+        }
+        unsafe extern "system" fn DrawUnderline(this: *mut core::ffi::c_void, clientdrawingcontext: *const core::ffi::c_void, baselineoriginx: f32, baselineoriginy: f32, underline: *const DWRITE_UNDERLINE, clientdrawingeffect: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextRenderer", "DrawUnderline", 7usize, |this| ((**(this as *const *const IDWriteTextRenderer_Vtbl)).DrawUnderline)(this, clientdrawingcontext, baselineoriginx, baselineoriginy, underline, clientdrawingeffect), |result| Some(*result))
+        }
+        unsafe extern "system" fn DrawStrikethrough(this: *mut core::ffi::c_void, clientdrawingcontext: *const core::ffi::c_void, baselineoriginx: f32, baselineoriginy: f32, strikethrough: *const DWRITE_STRIKETHROUGH, clientdrawingeffect: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextRenderer", "DrawStrikethrough", 8usize, |this| ((**(this as *const *const IDWriteTextRenderer_Vtbl)).DrawStrikethrough)(this, clientdrawingcontext, baselineoriginx, baselineoriginy, strikethrough, clientdrawingeffect), |result| Some(*result))
+        }
+        unsafe extern "system" fn DrawInlineObject(this: *mut core::ffi::c_void, clientdrawingcontext: *const core::ffi::c_void, originx: f32, originy: f32, inlineobject: *mut core::ffi::c_void, issideways: super::super::Foundation::BOOL, isrighttoleft: super::super::Foundation::BOOL, clientdrawingeffect: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextRenderer", "DrawInlineObject", 9usize, |this| ((**(this as *const *const IDWriteTextRenderer_Vtbl)).DrawInlineObject)(this, clientdrawingcontext, originx, originy, inlineobject, issideways, isrighttoleft, clientdrawingeffect), |result| Some(*result))
+        }
+        Self { base__: <IDWritePixelSnapping_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DrawGlyphRun, DrawUnderline, DrawStrikethrough, DrawInlineObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteTextRenderer as windows_core::Interface>::IID || <IDWritePixelSnapping_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteTextRenderer1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DrawGlyphRun(this: *mut core::ffi::c_void, clientdrawingcontext: *const core::ffi::c_void, baselineoriginx: f32, baselineoriginy: f32, orientationangle: DWRITE_GLYPH_ORIENTATION_ANGLE, measuringmode: DWRITE_MEASURING_MODE, glyphrun: *const DWRITE_GLYPH_RUN, glyphrundescription: *const DWRITE_GLYPH_RUN_DESCRIPTION, clientdrawingeffect: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextRenderer1", "DrawGlyphRun", 10usize, |this| ((**(this as *const *const IDWriteTextRenderer1_Vtbl)).DrawGlyphRun)(this, clientdrawingcontext, baselineoriginx, baselineoriginy, orientationangle, measuringmode, glyphrun, glyphrundescription, clientdrawingeffect), |result| Some(*result))
+        }
+        unsafe extern "system" fn DrawUnderline(this: *mut core::ffi::c_void, clientdrawingcontext: *const core::ffi::c_void, baselineoriginx: f32, baselineoriginy: f32, orientationangle: DWRITE_GLYPH_ORIENTATION_ANGLE, underline: *const DWRITE_UNDERLINE, clientdrawingeffect: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextRenderer1", "DrawUnderline", 11usize, |this| ((**(this as *const *const IDWriteTextRenderer1_Vtbl)).DrawUnderline)(this, clientdrawingcontext, baselineoriginx, baselineoriginy, orientationangle, underline, clientdrawingeffect), |result| Some(*result))
+        }
+        unsafe extern "system" fn DrawStrikethrough(this: *mut core::ffi::c_void, clientdrawingcontext: *const core::ffi::c_void, baselineoriginx: f32, baselineoriginy: f32, orientationangle: DWRITE_GLYPH_ORIENTATION_ANGLE, strikethrough: *const DWRITE_STRIKETHROUGH, clientdrawingeffect: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextRenderer1", "DrawStrikethrough", 12usize, |this| ((**(this as *const *const IDWriteTextRenderer1_Vtbl)).DrawStrikethrough)(this, clientdrawingcontext, baselineoriginx, baselineoriginy, orientationangle, strikethrough, clientdrawingeffect), |result| Some(*result))
+        }
+        unsafe extern "system" fn DrawInlineObject(this: *mut core::ffi::c_void, clientdrawingcontext: *const core::ffi::c_void, originx: f32, originy: f32, orientationangle: DWRITE_GLYPH_ORIENTATION_ANGLE, inlineobject: *mut core::ffi::c_void, issideways: super::super::Foundation::BOOL, isrighttoleft: super::super::Foundation::BOOL, clientdrawingeffect: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTextRenderer1", "DrawInlineObject", 13usize, |this| ((**(this as *const *const IDWriteTextRenderer1_Vtbl)).DrawInlineObject)(this, clientdrawingcontext, originx, originy, orientationangle, inlineobject, issideways, isrighttoleft, clientdrawingeffect), |result| Some(*result))
+        }
+        Self { base__: <IDWriteTextRenderer_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DrawGlyphRun, DrawUnderline, DrawStrikethrough, DrawInlineObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteTextRenderer1 as windows_core::Interface>::IID || <IDWriteTextRenderer_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDWriteTypography_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddFontFeature(this: *mut core::ffi::c_void, fontfeature: DWRITE_FONT_FEATURE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTypography", "AddFontFeature", 3usize, |this| ((**(this as *const *const IDWriteTypography_Vtbl)).AddFontFeature)(this, fontfeature), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontFeatureCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDWriteTypography", "GetFontFeatureCount", 4usize, |this| ((**(this as *const *const IDWriteTypography_Vtbl)).GetFontFeatureCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetFontFeature(this: *mut core::ffi::c_void, fontfeatureindex: u32, fontfeature: *mut DWRITE_FONT_FEATURE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDWriteTypography", "GetFontFeature", 5usize, |this| ((**(this as *const *const IDWriteTypography_Vtbl)).GetFontFeature)(this, fontfeatureindex, fontfeature), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddFontFeature, GetFontFeatureCount, GetFontFeature }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDWriteTypography as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -327,6 +327,7 @@ pub struct DWMFLIP3DWINDOWPOLICY(pub i32);
 impl windows_core::TypeKind for DWMFLIP3DWINDOWPOLICY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWMFLIP3DWINDOWPOLICY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWMFLIP3DWINDOWPOLICY").field(&self.0).finish()
@@ -338,6 +339,7 @@ pub struct DWMNCRENDERINGPOLICY(pub i32);
 impl windows_core::TypeKind for DWMNCRENDERINGPOLICY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWMNCRENDERINGPOLICY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWMNCRENDERINGPOLICY").field(&self.0).finish()
@@ -349,6 +351,7 @@ pub struct DWMTRANSITION_OWNEDWINDOW_TARGET(pub i32);
 impl windows_core::TypeKind for DWMTRANSITION_OWNEDWINDOW_TARGET {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWMTRANSITION_OWNEDWINDOW_TARGET {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWMTRANSITION_OWNEDWINDOW_TARGET").field(&self.0).finish()
@@ -360,6 +363,7 @@ pub struct DWMWINDOWATTRIBUTE(pub i32);
 impl windows_core::TypeKind for DWMWINDOWATTRIBUTE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWMWINDOWATTRIBUTE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWMWINDOWATTRIBUTE").field(&self.0).finish()
@@ -371,6 +375,7 @@ pub struct DWM_SHOWCONTACT(pub u32);
 impl windows_core::TypeKind for DWM_SHOWCONTACT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWM_SHOWCONTACT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWM_SHOWCONTACT").field(&self.0).finish()
@@ -415,6 +420,7 @@ pub struct DWM_SOURCE_FRAME_SAMPLING(pub i32);
 impl windows_core::TypeKind for DWM_SOURCE_FRAME_SAMPLING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWM_SOURCE_FRAME_SAMPLING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWM_SOURCE_FRAME_SAMPLING").field(&self.0).finish()
@@ -426,6 +432,7 @@ pub struct DWM_SYSTEMBACKDROP_TYPE(pub i32);
 impl windows_core::TypeKind for DWM_SYSTEMBACKDROP_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWM_SYSTEMBACKDROP_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWM_SYSTEMBACKDROP_TYPE").field(&self.0).finish()
@@ -437,6 +444,7 @@ pub struct DWM_TAB_WINDOW_REQUIREMENTS(pub i32);
 impl windows_core::TypeKind for DWM_TAB_WINDOW_REQUIREMENTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWM_TAB_WINDOW_REQUIREMENTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWM_TAB_WINDOW_REQUIREMENTS").field(&self.0).finish()
@@ -481,6 +489,7 @@ pub struct DWM_WINDOW_CORNER_PREFERENCE(pub i32);
 impl windows_core::TypeKind for DWM_WINDOW_CORNER_PREFERENCE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DWM_WINDOW_CORNER_PREFERENCE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DWM_WINDOW_CORNER_PREFERENCE").field(&self.0).finish()
@@ -492,6 +501,7 @@ pub struct GESTURE_TYPE(pub i32);
 impl windows_core::TypeKind for GESTURE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GESTURE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GESTURE_TYPE").field(&self.0).finish()
@@ -177,6 +177,7 @@ pub struct DXGI_ALPHA_MODE(pub i32);
 impl windows_core::TypeKind for DXGI_ALPHA_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_ALPHA_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_ALPHA_MODE").field(&self.0).finish()
@@ -188,6 +189,7 @@ pub struct DXGI_COLOR_SPACE_TYPE(pub i32);
 impl windows_core::TypeKind for DXGI_COLOR_SPACE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_COLOR_SPACE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_COLOR_SPACE_TYPE").field(&self.0).finish()
@@ -199,6 +201,7 @@ pub struct DXGI_FORMAT(pub i32);
 impl windows_core::TypeKind for DXGI_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_FORMAT").field(&self.0).finish()
@@ -210,6 +213,7 @@ pub struct DXGI_MODE_ROTATION(pub i32);
 impl windows_core::TypeKind for DXGI_MODE_ROTATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_MODE_ROTATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_MODE_ROTATION").field(&self.0).finish()
@@ -221,6 +225,7 @@ pub struct DXGI_MODE_SCALING(pub i32);
 impl windows_core::TypeKind for DXGI_MODE_SCALING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_MODE_SCALING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_MODE_SCALING").field(&self.0).finish()
@@ -232,13 +237,15 @@ pub struct DXGI_MODE_SCANLINE_ORDER(pub i32);
 impl windows_core::TypeKind for DXGI_MODE_SCANLINE_ORDER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_MODE_SCANLINE_ORDER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_MODE_SCANLINE_ORDER").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_GAMMA_CONTROL {
     pub Scale: DXGI_RGB,
     pub Offset: DXGI_RGB,
@@ -253,7 +260,8 @@ impl Default for DXGI_GAMMA_CONTROL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_GAMMA_CONTROL_CAPABILITIES {
     pub ScaleAndOffsetSupported: super::super::super::Foundation::BOOL,
     pub MaxConvertedValue: f32,
@@ -270,7 +278,8 @@ impl Default for DXGI_GAMMA_CONTROL_CAPABILITIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_JPEG_AC_HUFFMAN_TABLE {
     pub CodeCounts: [u8; 16],
     pub CodeValues: [u8; 162],
@@ -284,7 +293,8 @@ impl Default for DXGI_JPEG_AC_HUFFMAN_TABLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_JPEG_DC_HUFFMAN_TABLE {
     pub CodeCounts: [u8; 12],
     pub CodeValues: [u8; 12],
@@ -298,7 +308,8 @@ impl Default for DXGI_JPEG_DC_HUFFMAN_TABLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_JPEG_QUANTIZATION_TABLE {
     pub Elements: [u8; 64],
 }
@@ -311,7 +322,8 @@ impl Default for DXGI_JPEG_QUANTIZATION_TABLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_MODE_DESC {
     pub Width: u32,
     pub Height: u32,
@@ -329,7 +341,8 @@ impl Default for DXGI_MODE_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_RATIONAL {
     pub Numerator: u32,
     pub Denominator: u32,
@@ -343,7 +356,8 @@ impl Default for DXGI_RATIONAL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_RGB {
     pub Red: f32,
     pub Green: f32,
@@ -358,7 +372,8 @@ impl Default for DXGI_RGB {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_SAMPLE_DESC {
     pub Count: u32,
     pub Quality: u32,
@@ -2413,6 +2413,7 @@ pub struct DXGI_ADAPTER_FLAG(pub i32);
 impl windows_core::TypeKind for DXGI_ADAPTER_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_ADAPTER_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_ADAPTER_FLAG").field(&self.0).finish()
@@ -2457,6 +2458,7 @@ pub struct DXGI_ADAPTER_FLAG3(pub i32);
 impl windows_core::TypeKind for DXGI_ADAPTER_FLAG3 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_ADAPTER_FLAG3 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_ADAPTER_FLAG3").field(&self.0).finish()
@@ -2501,6 +2503,7 @@ pub struct DXGI_COMPUTE_PREEMPTION_GRANULARITY(pub i32);
 impl windows_core::TypeKind for DXGI_COMPUTE_PREEMPTION_GRANULARITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_COMPUTE_PREEMPTION_GRANULARITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_COMPUTE_PREEMPTION_GRANULARITY").field(&self.0).finish()
@@ -2512,6 +2515,7 @@ pub struct DXGI_CREATE_FACTORY_FLAGS(pub u32);
 impl windows_core::TypeKind for DXGI_CREATE_FACTORY_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_CREATE_FACTORY_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_CREATE_FACTORY_FLAGS").field(&self.0).finish()
@@ -2556,6 +2560,7 @@ pub struct DXGI_DEBUG_RLO_FLAGS(pub i32);
 impl windows_core::TypeKind for DXGI_DEBUG_RLO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_DEBUG_RLO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_DEBUG_RLO_FLAGS").field(&self.0).finish()
@@ -2600,6 +2605,7 @@ pub struct DXGI_ENUM_MODES(pub u32);
 impl windows_core::TypeKind for DXGI_ENUM_MODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_ENUM_MODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_ENUM_MODES").field(&self.0).finish()
@@ -2644,6 +2650,7 @@ pub struct DXGI_FEATURE(pub i32);
 impl windows_core::TypeKind for DXGI_FEATURE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_FEATURE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_FEATURE").field(&self.0).finish()
@@ -2655,6 +2662,7 @@ pub struct DXGI_FRAME_PRESENTATION_MODE(pub i32);
 impl windows_core::TypeKind for DXGI_FRAME_PRESENTATION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_FRAME_PRESENTATION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_FRAME_PRESENTATION_MODE").field(&self.0).finish()
@@ -2666,6 +2674,7 @@ pub struct DXGI_GPU_PREFERENCE(pub i32);
 impl windows_core::TypeKind for DXGI_GPU_PREFERENCE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_GPU_PREFERENCE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_GPU_PREFERENCE").field(&self.0).finish()
@@ -2677,6 +2686,7 @@ pub struct DXGI_GRAPHICS_PREEMPTION_GRANULARITY(pub i32);
 impl windows_core::TypeKind for DXGI_GRAPHICS_PREEMPTION_GRANULARITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_GRAPHICS_PREEMPTION_GRANULARITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_GRAPHICS_PREEMPTION_GRANULARITY").field(&self.0).finish()
@@ -2688,6 +2698,7 @@ pub struct DXGI_HARDWARE_COMPOSITION_SUPPORT_FLAGS(pub i32);
 impl windows_core::TypeKind for DXGI_HARDWARE_COMPOSITION_SUPPORT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_HARDWARE_COMPOSITION_SUPPORT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_HARDWARE_COMPOSITION_SUPPORT_FLAGS").field(&self.0).finish()
@@ -2732,6 +2743,7 @@ pub struct DXGI_HDR_METADATA_TYPE(pub i32);
 impl windows_core::TypeKind for DXGI_HDR_METADATA_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_HDR_METADATA_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_HDR_METADATA_TYPE").field(&self.0).finish()
@@ -2743,6 +2755,7 @@ pub struct DXGI_INFO_QUEUE_MESSAGE_CATEGORY(pub i32);
 impl windows_core::TypeKind for DXGI_INFO_QUEUE_MESSAGE_CATEGORY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_INFO_QUEUE_MESSAGE_CATEGORY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_INFO_QUEUE_MESSAGE_CATEGORY").field(&self.0).finish()
@@ -2754,6 +2767,7 @@ pub struct DXGI_INFO_QUEUE_MESSAGE_SEVERITY(pub i32);
 impl windows_core::TypeKind for DXGI_INFO_QUEUE_MESSAGE_SEVERITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_INFO_QUEUE_MESSAGE_SEVERITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_INFO_QUEUE_MESSAGE_SEVERITY").field(&self.0).finish()
@@ -2765,6 +2779,7 @@ pub struct DXGI_MAP_FLAGS(pub u32);
 impl windows_core::TypeKind for DXGI_MAP_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_MAP_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_MAP_FLAGS").field(&self.0).finish()
@@ -2809,6 +2824,7 @@ pub struct DXGI_MEMORY_SEGMENT_GROUP(pub i32);
 impl windows_core::TypeKind for DXGI_MEMORY_SEGMENT_GROUP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_MEMORY_SEGMENT_GROUP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_MEMORY_SEGMENT_GROUP").field(&self.0).finish()
@@ -2820,6 +2836,7 @@ pub struct DXGI_MULTIPLANE_OVERLAY_YCbCr_FLAGS(pub i32);
 impl windows_core::TypeKind for DXGI_MULTIPLANE_OVERLAY_YCbCr_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_MULTIPLANE_OVERLAY_YCbCr_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_MULTIPLANE_OVERLAY_YCbCr_FLAGS").field(&self.0).finish()
@@ -2864,6 +2881,7 @@ pub struct DXGI_MWA_FLAGS(pub u32);
 impl windows_core::TypeKind for DXGI_MWA_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_MWA_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_MWA_FLAGS").field(&self.0).finish()
@@ -2908,6 +2926,7 @@ pub struct DXGI_Message_Id(pub i32);
 impl windows_core::TypeKind for DXGI_Message_Id {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_Message_Id {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_Message_Id").field(&self.0).finish()
@@ -2919,6 +2938,7 @@ pub struct DXGI_OFFER_RESOURCE_FLAGS(pub i32);
 impl windows_core::TypeKind for DXGI_OFFER_RESOURCE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_OFFER_RESOURCE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_OFFER_RESOURCE_FLAGS").field(&self.0).finish()
@@ -2963,6 +2983,7 @@ pub struct DXGI_OFFER_RESOURCE_PRIORITY(pub i32);
 impl windows_core::TypeKind for DXGI_OFFER_RESOURCE_PRIORITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_OFFER_RESOURCE_PRIORITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_OFFER_RESOURCE_PRIORITY").field(&self.0).finish()
@@ -2974,6 +2995,7 @@ pub struct DXGI_OUTDUPL_FLAG(pub i32);
 impl windows_core::TypeKind for DXGI_OUTDUPL_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_OUTDUPL_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_OUTDUPL_FLAG").field(&self.0).finish()
@@ -3018,6 +3040,7 @@ pub struct DXGI_OUTDUPL_POINTER_SHAPE_TYPE(pub i32);
 impl windows_core::TypeKind for DXGI_OUTDUPL_POINTER_SHAPE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_OUTDUPL_POINTER_SHAPE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_OUTDUPL_POINTER_SHAPE_TYPE").field(&self.0).finish()
@@ -3029,6 +3052,7 @@ pub struct DXGI_OVERLAY_COLOR_SPACE_SUPPORT_FLAG(pub i32);
 impl windows_core::TypeKind for DXGI_OVERLAY_COLOR_SPACE_SUPPORT_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_OVERLAY_COLOR_SPACE_SUPPORT_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_OVERLAY_COLOR_SPACE_SUPPORT_FLAG").field(&self.0).finish()
@@ -3073,6 +3097,7 @@ pub struct DXGI_OVERLAY_SUPPORT_FLAG(pub i32);
 impl windows_core::TypeKind for DXGI_OVERLAY_SUPPORT_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_OVERLAY_SUPPORT_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_OVERLAY_SUPPORT_FLAG").field(&self.0).finish()
@@ -3117,6 +3142,7 @@ pub struct DXGI_PRESENT(pub u32);
 impl windows_core::TypeKind for DXGI_PRESENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_PRESENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_PRESENT").field(&self.0).finish()
@@ -3161,6 +3187,7 @@ pub struct DXGI_RECLAIM_RESOURCE_RESULTS(pub i32);
 impl windows_core::TypeKind for DXGI_RECLAIM_RESOURCE_RESULTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_RECLAIM_RESOURCE_RESULTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_RECLAIM_RESOURCE_RESULTS").field(&self.0).finish()
@@ -3172,6 +3199,7 @@ pub struct DXGI_RESIDENCY(pub i32);
 impl windows_core::TypeKind for DXGI_RESIDENCY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_RESIDENCY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_RESIDENCY").field(&self.0).finish()
@@ -3183,6 +3211,7 @@ pub struct DXGI_RESOURCE_PRIORITY(pub u32);
 impl windows_core::TypeKind for DXGI_RESOURCE_PRIORITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_RESOURCE_PRIORITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_RESOURCE_PRIORITY").field(&self.0).finish()
@@ -3194,6 +3223,7 @@ pub struct DXGI_SCALING(pub i32);
 impl windows_core::TypeKind for DXGI_SCALING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_SCALING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_SCALING").field(&self.0).finish()
@@ -3205,6 +3235,7 @@ pub struct DXGI_SHARED_RESOURCE_RW(pub u32);
 impl windows_core::TypeKind for DXGI_SHARED_RESOURCE_RW {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_SHARED_RESOURCE_RW {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_SHARED_RESOURCE_RW").field(&self.0).finish()
@@ -3249,6 +3280,7 @@ pub struct DXGI_SWAP_CHAIN_COLOR_SPACE_SUPPORT_FLAG(pub i32);
 impl windows_core::TypeKind for DXGI_SWAP_CHAIN_COLOR_SPACE_SUPPORT_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_SWAP_CHAIN_COLOR_SPACE_SUPPORT_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_SWAP_CHAIN_COLOR_SPACE_SUPPORT_FLAG").field(&self.0).finish()
@@ -3293,6 +3325,7 @@ pub struct DXGI_SWAP_CHAIN_FLAG(pub i32);
 impl windows_core::TypeKind for DXGI_SWAP_CHAIN_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_SWAP_CHAIN_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_SWAP_CHAIN_FLAG").field(&self.0).finish()
@@ -3337,6 +3370,7 @@ pub struct DXGI_SWAP_EFFECT(pub i32);
 impl windows_core::TypeKind for DXGI_SWAP_EFFECT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_SWAP_EFFECT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_SWAP_EFFECT").field(&self.0).finish()
@@ -3348,6 +3382,7 @@ pub struct DXGI_USAGE(pub u32);
 impl windows_core::TypeKind for DXGI_USAGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXGI_USAGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXGI_USAGE").field(&self.0).finish()
@@ -3387,7 +3422,8 @@ impl core::ops::Not for DXGI_USAGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_ADAPTER_DESC {
     pub Description: [u16; 128],
     pub VendorId: u32,
@@ -3408,7 +3444,8 @@ impl Default for DXGI_ADAPTER_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_ADAPTER_DESC1 {
     pub Description: [u16; 128],
     pub VendorId: u32,
@@ -3430,7 +3467,8 @@ impl Default for DXGI_ADAPTER_DESC1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_ADAPTER_DESC2 {
     pub Description: [u16; 128],
     pub VendorId: u32,
@@ -3454,7 +3492,8 @@ impl Default for DXGI_ADAPTER_DESC2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_ADAPTER_DESC3 {
     pub Description: [u16; 128],
     pub VendorId: u32,
@@ -3478,7 +3517,8 @@ impl Default for DXGI_ADAPTER_DESC3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_DECODE_SWAP_CHAIN_DESC {
     pub Flags: u32,
 }
@@ -3491,7 +3531,8 @@ impl Default for DXGI_DECODE_SWAP_CHAIN_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_DISPLAY_COLOR_SPACE {
     pub PrimaryCoordinates: [f32; 16],
     pub WhitePoints: [f32; 32],
@@ -3505,7 +3546,8 @@ impl Default for DXGI_DISPLAY_COLOR_SPACE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_FRAME_STATISTICS {
     pub PresentCount: u32,
     pub PresentRefreshCount: u32,
@@ -3522,7 +3564,8 @@ impl Default for DXGI_FRAME_STATISTICS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_FRAME_STATISTICS_MEDIA {
     pub PresentCount: u32,
     pub PresentRefreshCount: u32,
@@ -3541,7 +3584,8 @@ impl Default for DXGI_FRAME_STATISTICS_MEDIA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_HDR_METADATA_HDR10 {
     pub RedPrimary: [u16; 2],
     pub GreenPrimary: [u16; 2],
@@ -3561,7 +3605,8 @@ impl Default for DXGI_HDR_METADATA_HDR10 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_HDR_METADATA_HDR10PLUS {
     pub Data: [u8; 72],
 }
@@ -3574,7 +3619,8 @@ impl Default for DXGI_HDR_METADATA_HDR10PLUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_INFO_QUEUE_FILTER {
     pub AllowList: DXGI_INFO_QUEUE_FILTER_DESC,
     pub DenyList: DXGI_INFO_QUEUE_FILTER_DESC,
@@ -3588,7 +3634,8 @@ impl Default for DXGI_INFO_QUEUE_FILTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_INFO_QUEUE_FILTER_DESC {
     pub NumCategories: u32,
     pub pCategoryList: *mut DXGI_INFO_QUEUE_MESSAGE_CATEGORY,
@@ -3606,7 +3653,8 @@ impl Default for DXGI_INFO_QUEUE_FILTER_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_INFO_QUEUE_MESSAGE {
     pub Producer: windows_core::GUID,
     pub Category: DXGI_INFO_QUEUE_MESSAGE_CATEGORY,
@@ -3624,7 +3672,8 @@ impl Default for DXGI_INFO_QUEUE_MESSAGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_MAPPED_RECT {
     pub Pitch: i32,
     pub pBits: *mut u8,
@@ -3638,7 +3687,8 @@ impl Default for DXGI_MAPPED_RECT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_MATRIX_3X2_F {
     pub _11: f32,
     pub _12: f32,
@@ -3657,7 +3707,8 @@ impl Default for DXGI_MATRIX_3X2_F {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_MODE_DESC1 {
     pub Width: u32,
     pub Height: u32,
@@ -3679,7 +3730,8 @@ impl Default for DXGI_MODE_DESC1 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_OUTDUPL_DESC {
     pub ModeDesc: Common::DXGI_MODE_DESC,
     pub Rotation: Common::DXGI_MODE_ROTATION,
@@ -3696,7 +3748,8 @@ impl Default for DXGI_OUTDUPL_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_OUTDUPL_FRAME_INFO {
     pub LastPresentTime: i64,
     pub LastMouseUpdateTime: i64,
@@ -3716,7 +3769,8 @@ impl Default for DXGI_OUTDUPL_FRAME_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_OUTDUPL_MOVE_RECT {
     pub SourcePoint: super::super::Foundation::POINT,
     pub DestinationRect: super::super::Foundation::RECT,
@@ -3730,7 +3784,8 @@ impl Default for DXGI_OUTDUPL_MOVE_RECT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_OUTDUPL_POINTER_POSITION {
     pub Position: super::super::Foundation::POINT,
     pub Visible: super::super::Foundation::BOOL,
@@ -3744,7 +3799,8 @@ impl Default for DXGI_OUTDUPL_POINTER_POSITION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_OUTDUPL_POINTER_SHAPE_INFO {
     pub Type: u32,
     pub Width: u32,
@@ -3762,7 +3818,8 @@ impl Default for DXGI_OUTDUPL_POINTER_SHAPE_INFO {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_OUTPUT_DESC {
     pub DeviceName: [u16; 32],
     pub DesktopCoordinates: super::super::Foundation::RECT,
@@ -3782,7 +3839,8 @@ impl Default for DXGI_OUTPUT_DESC {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_OUTPUT_DESC1 {
     pub DeviceName: [u16; 32],
     pub DesktopCoordinates: super::super::Foundation::RECT,
@@ -3810,7 +3868,8 @@ impl Default for DXGI_OUTPUT_DESC1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_PRESENT_PARAMETERS {
     pub DirtyRectsCount: u32,
     pub pDirtyRects: *mut super::super::Foundation::RECT,
@@ -3826,7 +3885,8 @@ impl Default for DXGI_PRESENT_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_QUERY_VIDEO_MEMORY_INFO {
     pub Budget: u64,
     pub CurrentUsage: u64,
@@ -3842,7 +3902,8 @@ impl Default for DXGI_QUERY_VIDEO_MEMORY_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_RGBA {
     pub r: f32,
     pub g: f32,
@@ -3858,7 +3919,8 @@ impl Default for DXGI_RGBA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_SHARED_RESOURCE {
     pub Handle: super::super::Foundation::HANDLE,
 }
@@ -3872,7 +3934,8 @@ impl Default for DXGI_SHARED_RESOURCE {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_SURFACE_DESC {
     pub Width: u32,
     pub Height: u32,
@@ -3891,7 +3954,8 @@ impl Default for DXGI_SURFACE_DESC {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_SWAP_CHAIN_DESC {
     pub BufferDesc: Common::DXGI_MODE_DESC,
     pub SampleDesc: Common::DXGI_SAMPLE_DESC,
@@ -3914,7 +3978,8 @@ impl Default for DXGI_SWAP_CHAIN_DESC {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_SWAP_CHAIN_DESC1 {
     pub Width: u32,
     pub Height: u32,
@@ -3940,7 +4005,8 @@ impl Default for DXGI_SWAP_CHAIN_DESC1 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXGI_SWAP_CHAIN_FULLSCREEN_DESC {
     pub RefreshRate: Common::DXGI_RATIONAL,
     pub ScanlineOrdering: Common::DXGI_MODE_SCANLINE_ORDER,
@@ -3959,3 +4025,5 @@ impl Default for DXGI_SWAP_CHAIN_FULLSCREEN_DESC {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1362 @@
+impl windows_core::imp::ProxyVtable for IDXGIAdapter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumOutputs(this: *mut core::ffi::c_void, output: u32, ppoutput: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIAdapter", "EnumOutputs", 7usize, |this| ((**(this as *const *const IDXGIAdapter_Vtbl)).EnumOutputs)(this, output, ppoutput), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut DXGI_ADAPTER_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIAdapter", "GetDesc", 8usize, |this| ((**(this as *const *const IDXGIAdapter_Vtbl)).GetDesc)(this, pdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckInterfaceSupport(this: *mut core::ffi::c_void, interfacename: *const windows_core::GUID, pumdversion: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIAdapter", "CheckInterfaceSupport", 9usize, |this| ((**(this as *const *const IDXGIAdapter_Vtbl)).CheckInterfaceSupport)(this, interfacename, pumdversion), |result| Some(*result))
+        }
+        Self { base__: <IDXGIObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnumOutputs, GetDesc, CheckInterfaceSupport }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIAdapter as windows_core::Interface>::IID || <IDXGIObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDXGIAdapter1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc1(this: *mut core::ffi::c_void, pdesc: *mut DXGI_ADAPTER_DESC1) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIAdapter1", "GetDesc1", 10usize, |this| ((**(this as *const *const IDXGIAdapter1_Vtbl)).GetDesc1)(this, pdesc), |result| Some(*result))
+        }
+        Self { base__: <IDXGIAdapter_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc1 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIAdapter1 as windows_core::Interface>::IID || <IDXGIAdapter_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDXGIAdapter2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc2(this: *mut core::ffi::c_void, pdesc: *mut DXGI_ADAPTER_DESC2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIAdapter2", "GetDesc2", 11usize, |this| ((**(this as *const *const IDXGIAdapter2_Vtbl)).GetDesc2)(this, pdesc), |result| Some(*result))
+        }
+        Self { base__: <IDXGIAdapter1_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIAdapter2 as windows_core::Interface>::IID || <IDXGIAdapter1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDXGIAdapter3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterHardwareContentProtectionTeardownStatusEvent(this: *mut core::ffi::c_void, hevent: super::super::Foundation::HANDLE, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIAdapter3", "RegisterHardwareContentProtectionTeardownStatusEvent", 12usize, |this| ((**(this as *const *const IDXGIAdapter3_Vtbl)).RegisterHardwareContentProtectionTeardownStatusEvent)(this, hevent, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterHardwareContentProtectionTeardownStatus(this: *mut core::ffi::c_void, dwcookie: u32) {
+            windows_core::imp::proxy_call(this, "IDXGIAdapter3", "UnregisterHardwareContentProtectionTeardownStatus", 13usize, |this| ((**(this as *const *const IDXGIAdapter3_Vtbl)).UnregisterHardwareContentProtectionTeardownStatus)(this, dwcookie), |_| None)
+        }
+        unsafe extern "system" fn QueryVideoMemoryInfo(this: *mut core::ffi::c_void, nodeindex: u32, memorysegmentgroup: DXGI_MEMORY_SEGMENT_GROUP, pvideomemoryinfo: *mut DXGI_QUERY_VIDEO_MEMORY_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIAdapter3", "QueryVideoMemoryInfo", 14usize, |this| ((**(this as *const *const IDXGIAdapter3_Vtbl)).QueryVideoMemoryInfo)(this, nodeindex, memorysegmentgroup, pvideomemoryinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVideoMemoryReservation(this: *mut core::ffi::c_void, nodeindex: u32, memorysegmentgroup: DXGI_MEMORY_SEGMENT_GROUP, reservation: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIAdapter3", "SetVideoMemoryReservation", 15usize, |this| ((**(this as *const *const IDXGIAdapter3_Vtbl)).SetVideoMemoryReservation)(this, nodeindex, memorysegmentgroup, reservation), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterVideoMemoryBudgetChangeNotificationEvent(this: *mut core::ffi::c_void, hevent: super::super::Foundation::HANDLE, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIAdapter3", "RegisterVideoMemoryBudgetChangeNotificationEvent", 16usize, |this| ((**(this as *const *const IDXGIAdapter3_Vtbl)).RegisterVideoMemoryBudgetChangeNotificationEvent)(this, hevent, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterVideoMemoryBudgetChangeNotification(this: *mut core::ffi::c_void, dwcookie: u32) {
+            windows_core::imp::proxy_call(this, "IDXGIAdapter3", "UnregisterVideoMemoryBudgetChangeNotification", 17usize, |this| ((**(this as *const *const IDXGIAdapter3_Vtbl)).UnregisterVideoMemoryBudgetChangeNotification)(this, dwcookie), |_| None)
+        }
+        Self {
+            base__: <IDXGIAdapter2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RegisterHardwareContentProtectionTeardownStatusEvent,
+            UnregisterHardwareContentProtectionTeardownStatus,
+            QueryVideoMemoryInfo,
+            SetVideoMemoryReservation,
+            RegisterVideoMemoryBudgetChangeNotificationEvent,
+            UnregisterVideoMemoryBudgetChangeNotification,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIAdapter3 as windows_core::Interface>::IID || <IDXGIAdapter2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDXGIAdapter4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc3(this: *mut core::ffi::c_void, pdesc: *mut DXGI_ADAPTER_DESC3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIAdapter4", "GetDesc3", 18usize, |this| ((**(this as *const *const IDXGIAdapter4_Vtbl)).GetDesc3)(this, pdesc), |result| Some(*result))
+        }
+        Self { base__: <IDXGIAdapter3_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc3 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIAdapter4 as windows_core::Interface>::IID || <IDXGIAdapter3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDXGIDebug_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportLiveObjects(this: *mut core::ffi::c_void, apiid: windows_core::GUID, flags: DXGI_DEBUG_RLO_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIDebug", "ReportLiveObjects", 3usize, |this| ((**(this as *const *const IDXGIDebug_Vtbl)).ReportLiveObjects)(this, apiid, flags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReportLiveObjects }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIDebug as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDXGIDebug1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnableLeakTrackingForThread(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IDXGIDebug1", "EnableLeakTrackingForThread", 4usize, |this| ((**(this as *const *const IDXGIDebug1_Vtbl)).EnableLeakTrackingForThread)(this), |_| None)
+        }
+        unsafe extern "system" fn DisableLeakTrackingForThread(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IDXGIDebug1", "DisableLeakTrackingForThread", 5usize, |this| ((**(this as *const *const IDXGIDebug1_Vtbl)).DisableLeakTrackingForThread)(this), |_| None)
+        }
+        unsafe extern "system" fn IsLeakTrackingEnabledForThread(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDXGIDebug1", "IsLeakTrackingEnabledForThread", 6usize, |this| ((**(this as *const *const IDXGIDebug1_Vtbl)).IsLeakTrackingEnabledForThread)(this), |_| None)
+        }
+        Self {
+            base__: <IDXGIDebug_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EnableLeakTrackingForThread,
+            DisableLeakTrackingForThread,
+            IsLeakTrackingEnabledForThread,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIDebug1 as windows_core::Interface>::IID || <IDXGIDebug_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDXGIDecodeSwapChain_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PresentBuffer(this: *mut core::ffi::c_void, buffertopresent: u32, syncinterval: u32, flags: DXGI_PRESENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIDecodeSwapChain", "PresentBuffer", 3usize, |this| ((**(this as *const *const IDXGIDecodeSwapChain_Vtbl)).PresentBuffer)(this, buffertopresent, syncinterval, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSourceRect(this: *mut core::ffi::c_void, prect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIDecodeSwapChain", "SetSourceRect", 4usize, |this| ((**(this as *const *const IDXGIDecodeSwapChain_Vtbl)).SetSourceRect)(this, prect), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTargetRect(this: *mut core::ffi::c_void, prect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIDecodeSwapChain", "SetTargetRect", 5usize, |this| ((**(this as *const *const IDXGIDecodeSwapChain_Vtbl)).SetTargetRect)(this, prect), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDestSize(this: *mut core::ffi::c_void, width: u32, height: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIDecodeSwapChain", "SetDestSize", 6usize, |this| ((**(this as *const *const IDXGIDecodeSwapChain_Vtbl)).SetDestSize)(this, width, height), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourceRect(this: *mut core::ffi::c_void, prect: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIDecodeSwapChain", "GetSourceRect", 7usize, |this| ((**(this as *const *const IDXGIDecodeSwapChain_Vtbl)).GetSourceRect)(this, prect), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTargetRect(this: *mut core::ffi::c_void, prect: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIDecodeSwapChain", "GetTargetRect", 8usize, |this| ((**(this as *const *const IDXGIDecodeSwapChain_Vtbl)).GetTargetRect)(this, prect), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDestSize(this: *mut core::ffi::c_void, pwidth: *mut u32, pheight: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIDecodeSwapChain", "GetDestSize", 9usize, |this| ((**(this as *const *const IDXGIDecodeSwapChain_Vtbl)).GetDestSize)(this, pwidth, pheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColorSpace(this: *mut core::ffi::c_void, colorspace: DXGI_MULTIPLANE_OVERLAY_YCbCr_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIDecodeSwapChain", "SetColorSpace", 10usize, |this| ((**(this as *const *const IDXGIDecodeSwapChain_Vtbl)).SetColorSpace)(this, colorspace), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColorSpace(this: *mut core::ffi::c_void) -> DXGI_MULTIPLANE_OVERLAY_YCbCr_FLAGS {
+            windows_core::imp::proxy_call(this, "IDXGIDecodeSwapChain", "GetColorSpace", 11usize, |this| ((**(this as *const *const IDXGIDecodeSwapChain_Vtbl)).GetColorSpace)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PresentBuffer,
+            SetSourceRect,
+            SetTargetRect,
+            SetDestSize,
+            GetSourceRect,
+            GetTargetRect,
+            GetDestSize,
+            SetColorSpace,
+            GetColorSpace,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIDecodeSwapChain as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IDXGIDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAdapter(this: *mut core::ffi::c_void, padapter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIDevice", "GetAdapter", 7usize, |this| ((**(this as *const *const IDXGIDevice_Vtbl)).GetAdapter)(this, padapter), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateSurface(this: *mut core::ffi::c_void, pdesc: *const DXGI_SURFACE_DESC, numsurfaces: u32, usage: DXGI_USAGE, psharedresource: *const DXGI_SHARED_RESOURCE, ppsurface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIDevice", "CreateSurface", 8usize, |this| ((**(this as *const *const IDXGIDevice_Vtbl)).CreateSurface)(this, pdesc, numsurfaces, usage, psharedresource, ppsurface), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryResourceResidency(this: *mut core::ffi::c_void, ppresources: *const *mut core::ffi::c_void, presidencystatus: *mut DXGI_RESIDENCY, numresources: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIDevice", "QueryResourceResidency", 9usize, |this| ((**(this as *const *const IDXGIDevice_Vtbl)).QueryResourceResidency)(this, ppresources, presidencystatus, numresources), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGPUThreadPriority(this: *mut core::ffi::c_void, priority: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIDevice", "SetGPUThreadPriority", 10usize, |this| ((**(this as *const *const IDXGIDevice_Vtbl)).SetGPUThreadPriority)(this, priority), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGPUThreadPriority(this: *mut core::ffi::c_void, ppriority: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIDevice", "GetGPUThreadPriority", 11usize, |this| ((**(this as *const *const IDXGIDevice_Vtbl)).GetGPUThreadPriority)(this, ppriority), |result| Some(*result))
+        }
+        Self {
+            base__: <IDXGIObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetAdapter,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateSurface,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateSurface: 0,
+            QueryResourceResidency,
+            SetGPUThreadPriority,
+            GetGPUThreadPriority,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIDevice as windows_core::Interface>::IID || <IDXGIObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IDXGIDevice1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMaximumFrameLatency(this: *mut core::ffi::c_void, maxlatency: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIDevice1", "SetMaximumFrameLatency", 12usize, |this| ((**(this as *const *const IDXGIDevice1_Vtbl)).SetMaximumFrameLatency)(this, maxlatency), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaximumFrameLatency(this: *mut core::ffi::c_void, pmaxlatency: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIDevice1", "GetMaximumFrameLatency", 13usize, |this| ((**(this as *const *const IDXGIDevice1_Vtbl)).GetMaximumFrameLatency)(this, pmaxlatency), |result| Some(*result))
+        }
+        Self { base__: <IDXGIDevice_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetMaximumFrameLatency, GetMaximumFrameLatency }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIDevice1 as windows_core::Interface>::IID || <IDXGIDevice_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IDXGIDevice2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OfferResources(this: *mut core::ffi::c_void, numresources: u32, ppresources: *const *mut core::ffi::c_void, priority: DXGI_OFFER_RESOURCE_PRIORITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIDevice2", "OfferResources", 14usize, |this| ((**(this as *const *const IDXGIDevice2_Vtbl)).OfferResources)(this, numresources, ppresources, priority), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReclaimResources(this: *mut core::ffi::c_void, numresources: u32, ppresources: *const *mut core::ffi::c_void, pdiscarded: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIDevice2", "ReclaimResources", 15usize, |this| ((**(this as *const *const IDXGIDevice2_Vtbl)).ReclaimResources)(this, numresources, ppresources, pdiscarded), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnqueueSetEvent(this: *mut core::ffi::c_void, hevent: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIDevice2", "EnqueueSetEvent", 16usize, |this| ((**(this as *const *const IDXGIDevice2_Vtbl)).EnqueueSetEvent)(this, hevent), |result| Some(*result))
+        }
+        Self { base__: <IDXGIDevice1_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OfferResources, ReclaimResources, EnqueueSetEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIDevice2 as windows_core::Interface>::IID || <IDXGIDevice1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IDXGIDevice3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Trim(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IDXGIDevice3", "Trim", 17usize, |this| ((**(this as *const *const IDXGIDevice3_Vtbl)).Trim)(this), |_| None)
+        }
+        Self { base__: <IDXGIDevice2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Trim }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIDevice3 as windows_core::Interface>::IID || <IDXGIDevice2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IDXGIDevice4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OfferResources1(this: *mut core::ffi::c_void, numresources: u32, ppresources: *const *mut core::ffi::c_void, priority: DXGI_OFFER_RESOURCE_PRIORITY, flags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIDevice4", "OfferResources1", 18usize, |this| ((**(this as *const *const IDXGIDevice4_Vtbl)).OfferResources1)(this, numresources, ppresources, priority, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReclaimResources1(this: *mut core::ffi::c_void, numresources: u32, ppresources: *const *mut core::ffi::c_void, presults: *mut DXGI_RECLAIM_RESOURCE_RESULTS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIDevice4", "ReclaimResources1", 19usize, |this| ((**(this as *const *const IDXGIDevice4_Vtbl)).ReclaimResources1)(this, numresources, ppresources, presults), |result| Some(*result))
+        }
+        Self { base__: <IDXGIDevice3_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OfferResources1, ReclaimResources1 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIDevice4 as windows_core::Interface>::IID || <IDXGIDevice3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDXGIDeviceSubObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDevice(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIDeviceSubObject", "GetDevice", 7usize, |this| ((**(this as *const *const IDXGIDeviceSubObject_Vtbl)).GetDevice)(this, riid, ppdevice), |result| Some(*result))
+        }
+        Self { base__: <IDXGIObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDevice }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIDeviceSubObject as windows_core::Interface>::IID || <IDXGIObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDXGIDisplayControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsStereoEnabled(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDXGIDisplayControl", "IsStereoEnabled", 3usize, |this| ((**(this as *const *const IDXGIDisplayControl_Vtbl)).IsStereoEnabled)(this), |_| None)
+        }
+        unsafe extern "system" fn SetStereoEnabled(this: *mut core::ffi::c_void, enabled: super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "IDXGIDisplayControl", "SetStereoEnabled", 4usize, |this| ((**(this as *const *const IDXGIDisplayControl_Vtbl)).SetStereoEnabled)(this, enabled), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsStereoEnabled, SetStereoEnabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIDisplayControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IDXGIFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumAdapters(this: *mut core::ffi::c_void, adapter: u32, ppadapter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIFactory", "EnumAdapters", 7usize, |this| ((**(this as *const *const IDXGIFactory_Vtbl)).EnumAdapters)(this, adapter, ppadapter), |result| Some(*result))
+        }
+        unsafe extern "system" fn MakeWindowAssociation(this: *mut core::ffi::c_void, windowhandle: super::super::Foundation::HWND, flags: DXGI_MWA_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIFactory", "MakeWindowAssociation", 8usize, |this| ((**(this as *const *const IDXGIFactory_Vtbl)).MakeWindowAssociation)(this, windowhandle, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWindowAssociation(this: *mut core::ffi::c_void, pwindowhandle: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIFactory", "GetWindowAssociation", 9usize, |this| ((**(this as *const *const IDXGIFactory_Vtbl)).GetWindowAssociation)(this, pwindowhandle), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateSwapChain(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, pdesc: *const DXGI_SWAP_CHAIN_DESC, ppswapchain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIFactory", "CreateSwapChain", 10usize, |this| ((**(this as *const *const IDXGIFactory_Vtbl)).CreateSwapChain)(this, pdevice, pdesc, ppswapchain), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSoftwareAdapter(this: *mut core::ffi::c_void, module: super::super::Foundation::HMODULE, ppadapter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIFactory", "CreateSoftwareAdapter", 11usize, |this| ((**(this as *const *const IDXGIFactory_Vtbl)).CreateSoftwareAdapter)(this, module, ppadapter), |result| Some(*result))
+        }
+        Self {
+            base__: <IDXGIObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EnumAdapters,
+            MakeWindowAssociation,
+            GetWindowAssociation,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateSwapChain,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateSwapChain: 0,
+            CreateSoftwareAdapter,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIFactory as windows_core::Interface>::IID || <IDXGIObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IDXGIFactory1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumAdapters1(this: *mut core::ffi::c_void, adapter: u32, ppadapter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIFactory1", "EnumAdapters1", 12usize, |this| ((**(this as *const *const IDXGIFactory1_Vtbl)).EnumAdapters1)(this, adapter, ppadapter), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCurrent(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDXGIFactory1", "IsCurrent", 13usize, |this| ((**(this as *const *const IDXGIFactory1_Vtbl)).IsCurrent)(this), |_| None)
+        }
+        Self { base__: <IDXGIFactory_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnumAdapters1, IsCurrent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIFactory1 as windows_core::Interface>::IID || <IDXGIFactory_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IDXGIFactory2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsWindowedStereoEnabled(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDXGIFactory2", "IsWindowedStereoEnabled", 14usize, |this| ((**(this as *const *const IDXGIFactory2_Vtbl)).IsWindowedStereoEnabled)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateSwapChainForHwnd(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, pdesc: *const DXGI_SWAP_CHAIN_DESC1, pfullscreendesc: *const DXGI_SWAP_CHAIN_FULLSCREEN_DESC, prestricttooutput: *mut core::ffi::c_void, ppswapchain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIFactory2", "CreateSwapChainForHwnd", 15usize, |this| ((**(this as *const *const IDXGIFactory2_Vtbl)).CreateSwapChainForHwnd)(this, pdevice, hwnd, pdesc, pfullscreendesc, prestricttooutput, ppswapchain), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateSwapChainForCoreWindow(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, pwindow: *mut core::ffi::c_void, pdesc: *const DXGI_SWAP_CHAIN_DESC1, prestricttooutput: *mut core::ffi::c_void, ppswapchain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIFactory2", "CreateSwapChainForCoreWindow", 16usize, |this| ((**(this as *const *const IDXGIFactory2_Vtbl)).CreateSwapChainForCoreWindow)(this, pdevice, pwindow, pdesc, prestricttooutput, ppswapchain), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSharedResourceAdapterLuid(this: *mut core::ffi::c_void, hresource: super::super::Foundation::HANDLE, pluid: *mut super::super::Foundation::LUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIFactory2", "GetSharedResourceAdapterLuid", 17usize, |this| ((**(this as *const *const IDXGIFactory2_Vtbl)).GetSharedResourceAdapterLuid)(this, hresource, pluid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterStereoStatusWindow(this: *mut core::ffi::c_void, windowhandle: super::super::Foundation::HWND, wmsg: u32, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIFactory2", "RegisterStereoStatusWindow", 18usize, |this| ((**(this as *const *const IDXGIFactory2_Vtbl)).RegisterStereoStatusWindow)(this, windowhandle, wmsg, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterStereoStatusEvent(this: *mut core::ffi::c_void, hevent: super::super::Foundation::HANDLE, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIFactory2", "RegisterStereoStatusEvent", 19usize, |this| ((**(this as *const *const IDXGIFactory2_Vtbl)).RegisterStereoStatusEvent)(this, hevent, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterStereoStatus(this: *mut core::ffi::c_void, dwcookie: u32) {
+            windows_core::imp::proxy_call(this, "IDXGIFactory2", "UnregisterStereoStatus", 20usize, |this| ((**(this as *const *const IDXGIFactory2_Vtbl)).UnregisterStereoStatus)(this, dwcookie), |_| None)
+        }
+        unsafe extern "system" fn RegisterOcclusionStatusWindow(this: *mut core::ffi::c_void, windowhandle: super::super::Foundation::HWND, wmsg: u32, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIFactory2", "RegisterOcclusionStatusWindow", 21usize, |this| ((**(this as *const *const IDXGIFactory2_Vtbl)).RegisterOcclusionStatusWindow)(this, windowhandle, wmsg, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterOcclusionStatusEvent(this: *mut core::ffi::c_void, hevent: super::super::Foundation::HANDLE, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIFactory2", "RegisterOcclusionStatusEvent", 22usize, |this| ((**(this as *const *const IDXGIFactory2_Vtbl)).RegisterOcclusionStatusEvent)(this, hevent, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterOcclusionStatus(this: *mut core::ffi::c_void, dwcookie: u32) {
+            windows_core::imp::proxy_call(this, "IDXGIFactory2", "UnregisterOcclusionStatus", 23usize, |this| ((**(this as *const *const IDXGIFactory2_Vtbl)).UnregisterOcclusionStatus)(this, dwcookie), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateSwapChainForComposition(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, pdesc: *const DXGI_SWAP_CHAIN_DESC1, prestricttooutput: *mut core::ffi::c_void, ppswapchain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIFactory2", "CreateSwapChainForComposition", 24usize, |this| ((**(this as *const *const IDXGIFactory2_Vtbl)).CreateSwapChainForComposition)(this, pdevice, pdesc, prestricttooutput, ppswapchain), |result| Some(*result))
+        }
+        Self {
+            base__: <IDXGIFactory1_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsWindowedStereoEnabled,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateSwapChainForHwnd,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateSwapChainForHwnd: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateSwapChainForCoreWindow,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateSwapChainForCoreWindow: 0,
+            GetSharedResourceAdapterLuid,
+            RegisterStereoStatusWindow,
+            RegisterStereoStatusEvent,
+            UnregisterStereoStatus,
+            RegisterOcclusionStatusWindow,
+            RegisterOcclusionStatusEvent,
+            UnregisterOcclusionStatus,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateSwapChainForComposition,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateSwapChainForComposition: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIFactory2 as windows_core::Interface>::IID || <IDXGIFactory1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IDXGIFactory3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCreationFlags(this: *mut core::ffi::c_void) -> DXGI_CREATE_FACTORY_FLAGS {
+            windows_core::imp::proxy_call(this, "IDXGIFactory3", "GetCreationFlags", 25usize, |this| ((**(this as *const *const IDXGIFactory3_Vtbl)).GetCreationFlags)(this), |_| None)
+        }
+        Self { base__: <IDXGIFactory2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCreationFlags }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIFactory3 as windows_core::Interface>::IID || <IDXGIFactory2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IDXGIFactory4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumAdapterByLuid(this: *mut core::ffi::c_void, adapterluid: super::super::Foundation::LUID, riid: *const windows_core::GUID, ppvadapter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIFactory4", "EnumAdapterByLuid", 26usize, |this| ((**(this as *const *const IDXGIFactory4_Vtbl)).EnumAdapterByLuid)(this, adapterluid, riid, ppvadapter), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumWarpAdapter(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvadapter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIFactory4", "EnumWarpAdapter", 27usize, |this| ((**(this as *const *const IDXGIFactory4_Vtbl)).EnumWarpAdapter)(this, riid, ppvadapter), |result| Some(*result))
+        }
+        Self { base__: <IDXGIFactory3_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnumAdapterByLuid, EnumWarpAdapter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIFactory4 as windows_core::Interface>::IID || <IDXGIFactory3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IDXGIFactory5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CheckFeatureSupport(this: *mut core::ffi::c_void, feature: DXGI_FEATURE, pfeaturesupportdata: *mut core::ffi::c_void, featuresupportdatasize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIFactory5", "CheckFeatureSupport", 28usize, |this| ((**(this as *const *const IDXGIFactory5_Vtbl)).CheckFeatureSupport)(this, feature, pfeaturesupportdata, featuresupportdatasize), |result| Some(*result))
+        }
+        Self { base__: <IDXGIFactory4_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CheckFeatureSupport }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIFactory5 as windows_core::Interface>::IID || <IDXGIFactory4_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IDXGIFactory6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumAdapterByGpuPreference(this: *mut core::ffi::c_void, adapter: u32, gpupreference: DXGI_GPU_PREFERENCE, riid: *const windows_core::GUID, ppvadapter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIFactory6", "EnumAdapterByGpuPreference", 29usize, |this| ((**(this as *const *const IDXGIFactory6_Vtbl)).EnumAdapterByGpuPreference)(this, adapter, gpupreference, riid, ppvadapter), |result| Some(*result))
+        }
+        Self { base__: <IDXGIFactory5_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnumAdapterByGpuPreference }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIFactory6 as windows_core::Interface>::IID || <IDXGIFactory5_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IDXGIFactory7_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterAdaptersChangedEvent(this: *mut core::ffi::c_void, hevent: super::super::Foundation::HANDLE, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIFactory7", "RegisterAdaptersChangedEvent", 30usize, |this| ((**(this as *const *const IDXGIFactory7_Vtbl)).RegisterAdaptersChangedEvent)(this, hevent, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterAdaptersChangedEvent(this: *mut core::ffi::c_void, dwcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIFactory7", "UnregisterAdaptersChangedEvent", 31usize, |this| ((**(this as *const *const IDXGIFactory7_Vtbl)).UnregisterAdaptersChangedEvent)(this, dwcookie), |result| Some(*result))
+        }
+        Self { base__: <IDXGIFactory6_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterAdaptersChangedEvent, UnregisterAdaptersChangedEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIFactory7 as windows_core::Interface>::IID || <IDXGIFactory6_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IDXGIFactoryMedia_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateSwapChainForCompositionSurfaceHandle(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, hsurface: super::super::Foundation::HANDLE, pdesc: *const DXGI_SWAP_CHAIN_DESC1, prestricttooutput: *mut core::ffi::c_void, ppswapchain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIFactoryMedia", "CreateSwapChainForCompositionSurfaceHandle", 3usize, |this| ((**(this as *const *const IDXGIFactoryMedia_Vtbl)).CreateSwapChainForCompositionSurfaceHandle)(this, pdevice, hsurface, pdesc, prestricttooutput, ppswapchain), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDecodeSwapChainForCompositionSurfaceHandle(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, hsurface: super::super::Foundation::HANDLE, pdesc: *const DXGI_DECODE_SWAP_CHAIN_DESC, pyuvdecodebuffers: *mut core::ffi::c_void, prestricttooutput: *mut core::ffi::c_void, ppswapchain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIFactoryMedia", "CreateDecodeSwapChainForCompositionSurfaceHandle", 4usize, |this| ((**(this as *const *const IDXGIFactoryMedia_Vtbl)).CreateDecodeSwapChainForCompositionSurfaceHandle)(this, pdevice, hsurface, pdesc, pyuvdecodebuffers, prestricttooutput, ppswapchain), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateSwapChainForCompositionSurfaceHandle,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateSwapChainForCompositionSurfaceHandle: 0,
+            CreateDecodeSwapChainForCompositionSurfaceHandle,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIFactoryMedia as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDXGIInfoQueue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMessageCountLimit(this: *mut core::ffi::c_void, producer: windows_core::GUID, messagecountlimit: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "SetMessageCountLimit", 3usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).SetMessageCountLimit)(this, producer, messagecountlimit), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearStoredMessages(this: *mut core::ffi::c_void, producer: windows_core::GUID) {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "ClearStoredMessages", 4usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).ClearStoredMessages)(this, producer), |_| None)
+        }
+        unsafe extern "system" fn GetMessage(this: *mut core::ffi::c_void, producer: windows_core::GUID, messageindex: u64, pmessage: *mut DXGI_INFO_QUEUE_MESSAGE, pmessagebytelength: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "GetMessage", 5usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).GetMessage)(this, producer, messageindex, pmessage, pmessagebytelength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumStoredMessagesAllowedByRetrievalFilters(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> u64 {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "GetNumStoredMessagesAllowedByRetrievalFilters", 6usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).GetNumStoredMessagesAllowedByRetrievalFilters)(this, producer), |_| None)
+        }
+        unsafe extern "system" fn GetNumStoredMessages(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> u64 {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "GetNumStoredMessages", 7usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).GetNumStoredMessages)(this, producer), |_| None)
+        }
+        unsafe extern "system" fn GetNumMessagesDiscardedByMessageCountLimit(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> u64 {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "GetNumMessagesDiscardedByMessageCountLimit", 8usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).GetNumMessagesDiscardedByMessageCountLimit)(this, producer), |_| None)
+        }
+        unsafe extern "system" fn GetMessageCountLimit(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> u64 {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "GetMessageCountLimit", 9usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).GetMessageCountLimit)(this, producer), |_| None)
+        }
+        unsafe extern "system" fn GetNumMessagesAllowedByStorageFilter(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> u64 {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "GetNumMessagesAllowedByStorageFilter", 10usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).GetNumMessagesAllowedByStorageFilter)(this, producer), |_| None)
+        }
+        unsafe extern "system" fn GetNumMessagesDeniedByStorageFilter(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> u64 {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "GetNumMessagesDeniedByStorageFilter", 11usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).GetNumMessagesDeniedByStorageFilter)(this, producer), |_| None)
+        }
+        unsafe extern "system" fn AddStorageFilterEntries(this: *mut core::ffi::c_void, producer: windows_core::GUID, pfilter: *const DXGI_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "AddStorageFilterEntries", 12usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).AddStorageFilterEntries)(this, producer, pfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStorageFilter(this: *mut core::ffi::c_void, producer: windows_core::GUID, pfilter: *mut DXGI_INFO_QUEUE_FILTER, pfilterbytelength: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "GetStorageFilter", 13usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).GetStorageFilter)(this, producer, pfilter, pfilterbytelength), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearStorageFilter(this: *mut core::ffi::c_void, producer: windows_core::GUID) {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "ClearStorageFilter", 14usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).ClearStorageFilter)(this, producer), |_| None)
+        }
+        unsafe extern "system" fn PushEmptyStorageFilter(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "PushEmptyStorageFilter", 15usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).PushEmptyStorageFilter)(this, producer), |result| Some(*result))
+        }
+        unsafe extern "system" fn PushDenyAllStorageFilter(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "PushDenyAllStorageFilter", 16usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).PushDenyAllStorageFilter)(this, producer), |result| Some(*result))
+        }
+        unsafe extern "system" fn PushCopyOfStorageFilter(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "PushCopyOfStorageFilter", 17usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).PushCopyOfStorageFilter)(this, producer), |result| Some(*result))
+        }
+        unsafe extern "system" fn PushStorageFilter(this: *mut core::ffi::c_void, producer: windows_core::GUID, pfilter: *const DXGI_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "PushStorageFilter", 18usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).PushStorageFilter)(this, producer, pfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn PopStorageFilter(this: *mut core::ffi::c_void, producer: windows_core::GUID) {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "PopStorageFilter", 19usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).PopStorageFilter)(this, producer), |_| None)
+        }
+        unsafe extern "system" fn GetStorageFilterStackSize(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> u32 {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "GetStorageFilterStackSize", 20usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).GetStorageFilterStackSize)(this, producer), |_| None)
+        }
+        unsafe extern "system" fn AddRetrievalFilterEntries(this: *mut core::ffi::c_void, producer: windows_core::GUID, pfilter: *const DXGI_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "AddRetrievalFilterEntries", 21usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).AddRetrievalFilterEntries)(this, producer, pfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRetrievalFilter(this: *mut core::ffi::c_void, producer: windows_core::GUID, pfilter: *mut DXGI_INFO_QUEUE_FILTER, pfilterbytelength: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "GetRetrievalFilter", 22usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).GetRetrievalFilter)(this, producer, pfilter, pfilterbytelength), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearRetrievalFilter(this: *mut core::ffi::c_void, producer: windows_core::GUID) {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "ClearRetrievalFilter", 23usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).ClearRetrievalFilter)(this, producer), |_| None)
+        }
+        unsafe extern "system" fn PushEmptyRetrievalFilter(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "PushEmptyRetrievalFilter", 24usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).PushEmptyRetrievalFilter)(this, producer), |result| Some(*result))
+        }
+        unsafe extern "system" fn PushDenyAllRetrievalFilter(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "PushDenyAllRetrievalFilter", 25usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).PushDenyAllRetrievalFilter)(this, producer), |result| Some(*result))
+        }
+        unsafe extern "system" fn PushCopyOfRetrievalFilter(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "PushCopyOfRetrievalFilter", 26usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).PushCopyOfRetrievalFilter)(this, producer), |result| Some(*result))
+        }
+        unsafe extern "system" fn PushRetrievalFilter(this: *mut core::ffi::c_void, producer: windows_core::GUID, pfilter: *const DXGI_INFO_QUEUE_FILTER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "PushRetrievalFilter", 27usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).PushRetrievalFilter)(this, producer, pfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn PopRetrievalFilter(this: *mut core::ffi::c_void, producer: windows_core::GUID) {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "PopRetrievalFilter", 28usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).PopRetrievalFilter)(this, producer), |_| None)
+        }
+        unsafe extern "system" fn GetRetrievalFilterStackSize(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> u32 {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "GetRetrievalFilterStackSize", 29usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).GetRetrievalFilterStackSize)(this, producer), |_| None)
+        }
+        unsafe extern "system" fn AddMessage(this: *mut core::ffi::c_void, producer: windows_core::GUID, category: DXGI_INFO_QUEUE_MESSAGE_CATEGORY, severity: DXGI_INFO_QUEUE_MESSAGE_SEVERITY, id: i32, pdescription: windows_core::PCSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "AddMessage", 30usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).AddMessage)(this, producer, category, severity, id, pdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddApplicationMessage(this: *mut core::ffi::c_void, severity: DXGI_INFO_QUEUE_MESSAGE_SEVERITY, pdescription: windows_core::PCSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "AddApplicationMessage", 31usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).AddApplicationMessage)(this, severity, pdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBreakOnCategory(this: *mut core::ffi::c_void, producer: windows_core::GUID, category: DXGI_INFO_QUEUE_MESSAGE_CATEGORY, benable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "SetBreakOnCategory", 32usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).SetBreakOnCategory)(this, producer, category, benable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBreakOnSeverity(this: *mut core::ffi::c_void, producer: windows_core::GUID, severity: DXGI_INFO_QUEUE_MESSAGE_SEVERITY, benable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "SetBreakOnSeverity", 33usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).SetBreakOnSeverity)(this, producer, severity, benable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBreakOnID(this: *mut core::ffi::c_void, producer: windows_core::GUID, id: i32, benable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "SetBreakOnID", 34usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).SetBreakOnID)(this, producer, id, benable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBreakOnCategory(this: *mut core::ffi::c_void, producer: windows_core::GUID, category: DXGI_INFO_QUEUE_MESSAGE_CATEGORY) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "GetBreakOnCategory", 35usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).GetBreakOnCategory)(this, producer, category), |_| None)
+        }
+        unsafe extern "system" fn GetBreakOnSeverity(this: *mut core::ffi::c_void, producer: windows_core::GUID, severity: DXGI_INFO_QUEUE_MESSAGE_SEVERITY) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "GetBreakOnSeverity", 36usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).GetBreakOnSeverity)(this, producer, severity), |_| None)
+        }
+        unsafe extern "system" fn GetBreakOnID(this: *mut core::ffi::c_void, producer: windows_core::GUID, id: i32) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "GetBreakOnID", 37usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).GetBreakOnID)(this, producer, id), |_| None)
+        }
+        unsafe extern "system" fn SetMuteDebugOutput(this: *mut core::ffi::c_void, producer: windows_core::GUID, bmute: super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "SetMuteDebugOutput", 38usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).SetMuteDebugOutput)(this, producer, bmute), |_| None)
+        }
+        unsafe extern "system" fn GetMuteDebugOutput(this: *mut core::ffi::c_void, producer: windows_core::GUID) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDXGIInfoQueue", "GetMuteDebugOutput", 39usize, |this| ((**(this as *const *const IDXGIInfoQueue_Vtbl)).GetMuteDebugOutput)(this, producer), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetMessageCountLimit,
+            ClearStoredMessages,
+            GetMessage,
+            GetNumStoredMessagesAllowedByRetrievalFilters,
+            GetNumStoredMessages,
+            GetNumMessagesDiscardedByMessageCountLimit,
+            GetMessageCountLimit,
+            GetNumMessagesAllowedByStorageFilter,
+            GetNumMessagesDeniedByStorageFilter,
+            AddStorageFilterEntries,
+            GetStorageFilter,
+            ClearStorageFilter,
+            PushEmptyStorageFilter,
+            PushDenyAllStorageFilter,
+            PushCopyOfStorageFilter,
+            PushStorageFilter,
+            PopStorageFilter,
+            GetStorageFilterStackSize,
+            AddRetrievalFilterEntries,
+            GetRetrievalFilter,
+            ClearRetrievalFilter,
+            PushEmptyRetrievalFilter,
+            PushDenyAllRetrievalFilter,
+            PushCopyOfRetrievalFilter,
+            PushRetrievalFilter,
+            PopRetrievalFilter,
+            GetRetrievalFilterStackSize,
+            AddMessage,
+            AddApplicationMessage,
+            SetBreakOnCategory,
+            SetBreakOnSeverity,
+            SetBreakOnID,
+            GetBreakOnCategory,
+            GetBreakOnSeverity,
+            GetBreakOnID,
+            SetMuteDebugOutput,
+            GetMuteDebugOutput,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIInfoQueue as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDXGIKeyedMutex_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AcquireSync(this: *mut core::ffi::c_void, key: u64, dwmilliseconds: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIKeyedMutex", "AcquireSync", 8usize, |this| ((**(this as *const *const IDXGIKeyedMutex_Vtbl)).AcquireSync)(this, key, dwmilliseconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseSync(this: *mut core::ffi::c_void, key: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIKeyedMutex", "ReleaseSync", 9usize, |this| ((**(this as *const *const IDXGIKeyedMutex_Vtbl)).ReleaseSync)(this, key), |result| Some(*result))
+        }
+        Self { base__: <IDXGIDeviceSubObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AcquireSync, ReleaseSync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIKeyedMutex as windows_core::Interface>::IID || <IDXGIDeviceSubObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDXGIObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPrivateData(this: *mut core::ffi::c_void, name: *const windows_core::GUID, datasize: u32, pdata: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIObject", "SetPrivateData", 3usize, |this| ((**(this as *const *const IDXGIObject_Vtbl)).SetPrivateData)(this, name, datasize, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivateDataInterface(this: *mut core::ffi::c_void, name: *const windows_core::GUID, punknown: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIObject", "SetPrivateDataInterface", 4usize, |this| ((**(this as *const *const IDXGIObject_Vtbl)).SetPrivateDataInterface)(this, name, punknown), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrivateData(this: *mut core::ffi::c_void, name: *const windows_core::GUID, pdatasize: *mut u32, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIObject", "GetPrivateData", 5usize, |this| ((**(this as *const *const IDXGIObject_Vtbl)).GetPrivateData)(this, name, pdatasize, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParent(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppparent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIObject", "GetParent", 6usize, |this| ((**(this as *const *const IDXGIObject_Vtbl)).GetParent)(this, riid, ppparent), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetPrivateData,
+            SetPrivateDataInterface,
+            GetPrivateData,
+            GetParent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
+impl windows_core::imp::ProxyVtable for IDXGIOutput_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut DXGI_OUTPUT_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIOutput", "GetDesc", 7usize, |this| ((**(this as *const *const IDXGIOutput_Vtbl)).GetDesc)(this, pdesc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDisplayModeList(this: *mut core::ffi::c_void, enumformat: Common::DXGI_FORMAT, flags: DXGI_ENUM_MODES, pnummodes: *mut u32, pdesc: *mut Common::DXGI_MODE_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIOutput", "GetDisplayModeList", 8usize, |this| ((**(this as *const *const IDXGIOutput_Vtbl)).GetDisplayModeList)(this, enumformat, flags, pnummodes, pdesc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn FindClosestMatchingMode(this: *mut core::ffi::c_void, pmodetomatch: *const Common::DXGI_MODE_DESC, pclosestmatch: *mut Common::DXGI_MODE_DESC, pconcerneddevice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIOutput", "FindClosestMatchingMode", 9usize, |this| ((**(this as *const *const IDXGIOutput_Vtbl)).FindClosestMatchingMode)(this, pmodetomatch, pclosestmatch, pconcerneddevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForVBlank(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIOutput", "WaitForVBlank", 10usize, |this| ((**(this as *const *const IDXGIOutput_Vtbl)).WaitForVBlank)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn TakeOwnership(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, exclusive: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIOutput", "TakeOwnership", 11usize, |this| ((**(this as *const *const IDXGIOutput_Vtbl)).TakeOwnership)(this, pdevice, exclusive), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseOwnership(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IDXGIOutput", "ReleaseOwnership", 12usize, |this| ((**(this as *const *const IDXGIOutput_Vtbl)).ReleaseOwnership)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetGammaControlCapabilities(this: *mut core::ffi::c_void, pgammacaps: *mut Common::DXGI_GAMMA_CONTROL_CAPABILITIES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIOutput", "GetGammaControlCapabilities", 13usize, |this| ((**(this as *const *const IDXGIOutput_Vtbl)).GetGammaControlCapabilities)(this, pgammacaps), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn SetGammaControl(this: *mut core::ffi::c_void, parray: *const Common::DXGI_GAMMA_CONTROL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIOutput", "SetGammaControl", 14usize, |this| ((**(this as *const *const IDXGIOutput_Vtbl)).SetGammaControl)(this, parray), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetGammaControl(this: *mut core::ffi::c_void, parray: *mut Common::DXGI_GAMMA_CONTROL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIOutput", "GetGammaControl", 15usize, |this| ((**(this as *const *const IDXGIOutput_Vtbl)).GetGammaControl)(this, parray), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplaySurface(this: *mut core::ffi::c_void, pscanoutsurface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIOutput", "SetDisplaySurface", 16usize, |this| ((**(this as *const *const IDXGIOutput_Vtbl)).SetDisplaySurface)(this, pscanoutsurface), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplaySurfaceData(this: *mut core::ffi::c_void, pdestination: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIOutput", "GetDisplaySurfaceData", 17usize, |this| ((**(this as *const *const IDXGIOutput_Vtbl)).GetDisplaySurfaceData)(this, pdestination), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFrameStatistics(this: *mut core::ffi::c_void, pstats: *mut DXGI_FRAME_STATISTICS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIOutput", "GetFrameStatistics", 18usize, |this| ((**(this as *const *const IDXGIOutput_Vtbl)).GetFrameStatistics)(this, pstats), |result| Some(*result))
+        }
+        Self {
+            base__: <IDXGIObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
+            GetDesc,
+            #[cfg(not(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi")))]
+            GetDesc: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDisplayModeList,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDisplayModeList: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            FindClosestMatchingMode,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            FindClosestMatchingMode: 0,
+            WaitForVBlank,
+            TakeOwnership,
+            ReleaseOwnership,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetGammaControlCapabilities,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetGammaControlCapabilities: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            SetGammaControl,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            SetGammaControl: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetGammaControl,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetGammaControl: 0,
+            SetDisplaySurface,
+            GetDisplaySurfaceData,
+            GetFrameStatistics,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIOutput as windows_core::Interface>::IID || <IDXGIObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
+impl windows_core::imp::ProxyVtable for IDXGIOutput1_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDisplayModeList1(this: *mut core::ffi::c_void, enumformat: Common::DXGI_FORMAT, flags: DXGI_ENUM_MODES, pnummodes: *mut u32, pdesc: *mut DXGI_MODE_DESC1) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIOutput1", "GetDisplayModeList1", 19usize, |this| ((**(this as *const *const IDXGIOutput1_Vtbl)).GetDisplayModeList1)(this, enumformat, flags, pnummodes, pdesc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn FindClosestMatchingMode1(this: *mut core::ffi::c_void, pmodetomatch: *const DXGI_MODE_DESC1, pclosestmatch: *mut DXGI_MODE_DESC1, pconcerneddevice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIOutput1", "FindClosestMatchingMode1", 20usize, |this| ((**(this as *const *const IDXGIOutput1_Vtbl)).FindClosestMatchingMode1)(this, pmodetomatch, pclosestmatch, pconcerneddevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplaySurfaceData1(this: *mut core::ffi::c_void, pdestination: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIOutput1", "GetDisplaySurfaceData1", 21usize, |this| ((**(this as *const *const IDXGIOutput1_Vtbl)).GetDisplaySurfaceData1)(this, pdestination), |result| Some(*result))
+        }
+        unsafe extern "system" fn DuplicateOutput(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, ppoutputduplication: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIOutput1", "DuplicateOutput", 22usize, |this| ((**(this as *const *const IDXGIOutput1_Vtbl)).DuplicateOutput)(this, pdevice, ppoutputduplication), |result| Some(*result))
+        }
+        Self {
+            base__: <IDXGIOutput_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDisplayModeList1,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDisplayModeList1: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            FindClosestMatchingMode1,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            FindClosestMatchingMode1: 0,
+            GetDisplaySurfaceData1,
+            DuplicateOutput,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIOutput1 as windows_core::Interface>::IID || <IDXGIOutput_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
+impl windows_core::imp::ProxyVtable for IDXGIOutput2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SupportsOverlays(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDXGIOutput2", "SupportsOverlays", 23usize, |this| ((**(this as *const *const IDXGIOutput2_Vtbl)).SupportsOverlays)(this), |_| None)
+        }
+        Self { base__: <IDXGIOutput1_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SupportsOverlays }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIOutput2 as windows_core::Interface>::IID || <IDXGIOutput1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
+impl windows_core::imp::ProxyVtable for IDXGIOutput3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CheckOverlaySupport(this: *mut core::ffi::c_void, enumformat: Common::DXGI_FORMAT, pconcerneddevice: *mut core::ffi::c_void, pflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIOutput3", "CheckOverlaySupport", 24usize, |this| ((**(this as *const *const IDXGIOutput3_Vtbl)).CheckOverlaySupport)(this, enumformat, pconcerneddevice, pflags), |result| Some(*result))
+        }
+        Self {
+            base__: <IDXGIOutput2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CheckOverlaySupport,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CheckOverlaySupport: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIOutput3 as windows_core::Interface>::IID || <IDXGIOutput2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
+impl windows_core::imp::ProxyVtable for IDXGIOutput4_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CheckOverlayColorSpaceSupport(this: *mut core::ffi::c_void, format: Common::DXGI_FORMAT, colorspace: Common::DXGI_COLOR_SPACE_TYPE, pconcerneddevice: *mut core::ffi::c_void, pflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIOutput4", "CheckOverlayColorSpaceSupport", 25usize, |this| ((**(this as *const *const IDXGIOutput4_Vtbl)).CheckOverlayColorSpaceSupport)(this, format, colorspace, pconcerneddevice, pflags), |result| Some(*result))
+        }
+        Self {
+            base__: <IDXGIOutput3_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CheckOverlayColorSpaceSupport,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CheckOverlayColorSpaceSupport: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIOutput4 as windows_core::Interface>::IID || <IDXGIOutput3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
+impl windows_core::imp::ProxyVtable for IDXGIOutput5_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn DuplicateOutput1(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, flags: u32, supportedformatscount: u32, psupportedformats: *const Common::DXGI_FORMAT, ppoutputduplication: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIOutput5", "DuplicateOutput1", 26usize, |this| ((**(this as *const *const IDXGIOutput5_Vtbl)).DuplicateOutput1)(this, pdevice, flags, supportedformatscount, psupportedformats, ppoutputduplication), |result| Some(*result))
+        }
+        Self {
+            base__: <IDXGIOutput4_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            DuplicateOutput1,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            DuplicateOutput1: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIOutput5 as windows_core::Interface>::IID || <IDXGIOutput4_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
+impl windows_core::imp::ProxyVtable for IDXGIOutput6_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
+        unsafe extern "system" fn GetDesc1(this: *mut core::ffi::c_void, pdesc: *mut DXGI_OUTPUT_DESC1) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIOutput6", "GetDesc1", 27usize, |this| ((**(this as *const *const IDXGIOutput6_Vtbl)).GetDesc1)(this, pdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckHardwareCompositionSupport(this: *mut core::ffi::c_void, pflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIOutput6", "CheckHardwareCompositionSupport", 28usize, |this| ((**(this as *const *const IDXGIOutput6_Vtbl)).CheckHardwareCompositionSupport)(this, pflags), |result| Some(*result))
+        }
+        Self {
+            base__: <IDXGIOutput5_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
+            GetDesc1,
+            #[cfg(not(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi")))]
+            GetDesc1: 0,
+            CheckHardwareCompositionSupport,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIOutput6 as windows_core::Interface>::IID || <IDXGIOutput5_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IDXGIOutputDuplication_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut DXGI_OUTDUPL_DESC) {
+            windows_core::imp::proxy_call(this, "IDXGIOutputDuplication", "GetDesc", 7usize, |this| ((**(this as *const *const IDXGIOutputDuplication_Vtbl)).GetDesc)(this, pdesc), |_| None)
+        }
+        unsafe extern "system" fn AcquireNextFrame(this: *mut core::ffi::c_void, timeoutinmilliseconds: u32, pframeinfo: *mut DXGI_OUTDUPL_FRAME_INFO, ppdesktopresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIOutputDuplication", "AcquireNextFrame", 8usize, |this| ((**(this as *const *const IDXGIOutputDuplication_Vtbl)).AcquireNextFrame)(this, timeoutinmilliseconds, pframeinfo, ppdesktopresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFrameDirtyRects(this: *mut core::ffi::c_void, dirtyrectsbuffersize: u32, pdirtyrectsbuffer: *mut super::super::Foundation::RECT, pdirtyrectsbuffersizerequired: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIOutputDuplication", "GetFrameDirtyRects", 9usize, |this| ((**(this as *const *const IDXGIOutputDuplication_Vtbl)).GetFrameDirtyRects)(this, dirtyrectsbuffersize, pdirtyrectsbuffer, pdirtyrectsbuffersizerequired), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFrameMoveRects(this: *mut core::ffi::c_void, moverectsbuffersize: u32, pmoverectbuffer: *mut DXGI_OUTDUPL_MOVE_RECT, pmoverectsbuffersizerequired: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIOutputDuplication", "GetFrameMoveRects", 10usize, |this| ((**(this as *const *const IDXGIOutputDuplication_Vtbl)).GetFrameMoveRects)(this, moverectsbuffersize, pmoverectbuffer, pmoverectsbuffersizerequired), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFramePointerShape(this: *mut core::ffi::c_void, pointershapebuffersize: u32, ppointershapebuffer: *mut core::ffi::c_void, ppointershapebuffersizerequired: *mut u32, ppointershapeinfo: *mut DXGI_OUTDUPL_POINTER_SHAPE_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIOutputDuplication", "GetFramePointerShape", 11usize, |this| ((**(this as *const *const IDXGIOutputDuplication_Vtbl)).GetFramePointerShape)(this, pointershapebuffersize, ppointershapebuffer, ppointershapebuffersizerequired, ppointershapeinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn MapDesktopSurface(this: *mut core::ffi::c_void, plockedrect: *mut DXGI_MAPPED_RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIOutputDuplication", "MapDesktopSurface", 12usize, |this| ((**(this as *const *const IDXGIOutputDuplication_Vtbl)).MapDesktopSurface)(this, plockedrect), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnMapDesktopSurface(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIOutputDuplication", "UnMapDesktopSurface", 13usize, |this| ((**(this as *const *const IDXGIOutputDuplication_Vtbl)).UnMapDesktopSurface)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseFrame(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIOutputDuplication", "ReleaseFrame", 14usize, |this| ((**(this as *const *const IDXGIOutputDuplication_Vtbl)).ReleaseFrame)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IDXGIObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDesc,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDesc: 0,
+            AcquireNextFrame,
+            GetFrameDirtyRects,
+            GetFrameMoveRects,
+            GetFramePointerShape,
+            MapDesktopSurface,
+            UnMapDesktopSurface,
+            ReleaseFrame,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIOutputDuplication as windows_core::Interface>::IID || <IDXGIObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDXGIResource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSharedHandle(this: *mut core::ffi::c_void, psharedhandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIResource", "GetSharedHandle", 8usize, |this| ((**(this as *const *const IDXGIResource_Vtbl)).GetSharedHandle)(this, psharedhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUsage(this: *mut core::ffi::c_void, pusage: *mut DXGI_USAGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIResource", "GetUsage", 9usize, |this| ((**(this as *const *const IDXGIResource_Vtbl)).GetUsage)(this, pusage), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEvictionPriority(this: *mut core::ffi::c_void, evictionpriority: DXGI_RESOURCE_PRIORITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIResource", "SetEvictionPriority", 10usize, |this| ((**(this as *const *const IDXGIResource_Vtbl)).SetEvictionPriority)(this, evictionpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEvictionPriority(this: *mut core::ffi::c_void, pevictionpriority: *mut DXGI_RESOURCE_PRIORITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIResource", "GetEvictionPriority", 11usize, |this| ((**(this as *const *const IDXGIResource_Vtbl)).GetEvictionPriority)(this, pevictionpriority), |result| Some(*result))
+        }
+        Self {
+            base__: <IDXGIDeviceSubObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSharedHandle,
+            GetUsage,
+            SetEvictionPriority,
+            GetEvictionPriority,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIResource as windows_core::Interface>::IID || <IDXGIDeviceSubObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Security")]
+impl windows_core::imp::ProxyVtable for IDXGIResource1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateSubresourceSurface(this: *mut core::ffi::c_void, index: u32, ppsurface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIResource1", "CreateSubresourceSurface", 12usize, |this| ((**(this as *const *const IDXGIResource1_Vtbl)).CreateSubresourceSurface)(this, index, ppsurface), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Security")]
+        unsafe extern "system" fn CreateSharedHandle(this: *mut core::ffi::c_void, pattributes: *const super::super::Security::SECURITY_ATTRIBUTES, dwaccess: u32, lpname: windows_core::PCWSTR, phandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGIResource1", "CreateSharedHandle", 13usize, |this| ((**(this as *const *const IDXGIResource1_Vtbl)).CreateSharedHandle)(this, pattributes, dwaccess, lpname, phandle), |result| Some(*result))
+        }
+        Self {
+            base__: <IDXGIResource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateSubresourceSurface,
+            #[cfg(feature = "Win32_Security")]
+            CreateSharedHandle,
+            #[cfg(not(feature = "Win32_Security"))]
+            CreateSharedHandle: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGIResource1 as windows_core::Interface>::IID || <IDXGIResource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IDXGISurface_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut DXGI_SURFACE_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISurface", "GetDesc", 8usize, |this| ((**(this as *const *const IDXGISurface_Vtbl)).GetDesc)(this, pdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn Map(this: *mut core::ffi::c_void, plockedrect: *mut DXGI_MAPPED_RECT, mapflags: DXGI_MAP_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISurface", "Map", 9usize, |this| ((**(this as *const *const IDXGISurface_Vtbl)).Map)(this, plockedrect, mapflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unmap(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISurface", "Unmap", 10usize, |this| ((**(this as *const *const IDXGISurface_Vtbl)).Unmap)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IDXGIDeviceSubObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDesc,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDesc: 0,
+            Map,
+            Unmap,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGISurface as windows_core::Interface>::IID || <IDXGIDeviceSubObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
+impl windows_core::imp::ProxyVtable for IDXGISurface1_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetDC(this: *mut core::ffi::c_void, discard: super::super::Foundation::BOOL, phdc: *mut super::Gdi::HDC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISurface1", "GetDC", 11usize, |this| ((**(this as *const *const IDXGISurface1_Vtbl)).GetDC)(this, discard, phdc), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseDC(this: *mut core::ffi::c_void, pdirtyrect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISurface1", "ReleaseDC", 12usize, |this| ((**(this as *const *const IDXGISurface1_Vtbl)).ReleaseDC)(this, pdirtyrect), |result| Some(*result))
+        }
+        Self {
+            base__: <IDXGISurface_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetDC,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetDC: 0,
+            ReleaseDC,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGISurface1 as windows_core::Interface>::IID || <IDXGISurface_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dxgi_Common", feature = "Win32_Graphics_Gdi"))]
+impl windows_core::imp::ProxyVtable for IDXGISurface2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetResource(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppparentresource: *mut *mut core::ffi::c_void, psubresourceindex: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISurface2", "GetResource", 13usize, |this| ((**(this as *const *const IDXGISurface2_Vtbl)).GetResource)(this, riid, ppparentresource, psubresourceindex), |result| Some(*result))
+        }
+        Self { base__: <IDXGISurface1_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetResource }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGISurface2 as windows_core::Interface>::IID || <IDXGISurface1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IDXGISwapChain_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Present(this: *mut core::ffi::c_void, syncinterval: u32, flags: DXGI_PRESENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain", "Present", 8usize, |this| ((**(this as *const *const IDXGISwapChain_Vtbl)).Present)(this, syncinterval, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBuffer(this: *mut core::ffi::c_void, buffer: u32, riid: *const windows_core::GUID, ppsurface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain", "GetBuffer", 9usize, |this| ((**(this as *const *const IDXGISwapChain_Vtbl)).GetBuffer)(this, buffer, riid, ppsurface), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFullscreenState(this: *mut core::ffi::c_void, fullscreen: super::super::Foundation::BOOL, ptarget: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain", "SetFullscreenState", 10usize, |this| ((**(this as *const *const IDXGISwapChain_Vtbl)).SetFullscreenState)(this, fullscreen, ptarget), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFullscreenState(this: *mut core::ffi::c_void, pfullscreen: *mut super::super::Foundation::BOOL, pptarget: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain", "GetFullscreenState", 11usize, |this| ((**(this as *const *const IDXGISwapChain_Vtbl)).GetFullscreenState)(this, pfullscreen, pptarget), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, pdesc: *mut DXGI_SWAP_CHAIN_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain", "GetDesc", 12usize, |this| ((**(this as *const *const IDXGISwapChain_Vtbl)).GetDesc)(this, pdesc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn ResizeBuffers(this: *mut core::ffi::c_void, buffercount: u32, width: u32, height: u32, newformat: Common::DXGI_FORMAT, swapchainflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain", "ResizeBuffers", 13usize, |this| ((**(this as *const *const IDXGISwapChain_Vtbl)).ResizeBuffers)(this, buffercount, width, height, newformat, swapchainflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn ResizeTarget(this: *mut core::ffi::c_void, pnewtargetparameters: *const Common::DXGI_MODE_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain", "ResizeTarget", 14usize, |this| ((**(this as *const *const IDXGISwapChain_Vtbl)).ResizeTarget)(this, pnewtargetparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContainingOutput(this: *mut core::ffi::c_void, ppoutput: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain", "GetContainingOutput", 15usize, |this| ((**(this as *const *const IDXGISwapChain_Vtbl)).GetContainingOutput)(this, ppoutput), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFrameStatistics(this: *mut core::ffi::c_void, pstats: *mut DXGI_FRAME_STATISTICS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain", "GetFrameStatistics", 16usize, |this| ((**(this as *const *const IDXGISwapChain_Vtbl)).GetFrameStatistics)(this, pstats), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastPresentCount(this: *mut core::ffi::c_void, plastpresentcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain", "GetLastPresentCount", 17usize, |this| ((**(this as *const *const IDXGISwapChain_Vtbl)).GetLastPresentCount)(this, plastpresentcount), |result| Some(*result))
+        }
+        Self {
+            base__: <IDXGIDeviceSubObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Present,
+            GetBuffer,
+            SetFullscreenState,
+            GetFullscreenState,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDesc,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDesc: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            ResizeBuffers,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            ResizeBuffers: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            ResizeTarget,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            ResizeTarget: 0,
+            GetContainingOutput,
+            GetFrameStatistics,
+            GetLastPresentCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGISwapChain as windows_core::Interface>::IID || <IDXGIDeviceSubObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IDXGISwapChain1_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDesc1(this: *mut core::ffi::c_void, pdesc: *mut DXGI_SWAP_CHAIN_DESC1) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain1", "GetDesc1", 18usize, |this| ((**(this as *const *const IDXGISwapChain1_Vtbl)).GetDesc1)(this, pdesc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetFullscreenDesc(this: *mut core::ffi::c_void, pdesc: *mut DXGI_SWAP_CHAIN_FULLSCREEN_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain1", "GetFullscreenDesc", 19usize, |this| ((**(this as *const *const IDXGISwapChain1_Vtbl)).GetFullscreenDesc)(this, pdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHwnd(this: *mut core::ffi::c_void, phwnd: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain1", "GetHwnd", 20usize, |this| ((**(this as *const *const IDXGISwapChain1_Vtbl)).GetHwnd)(this, phwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCoreWindow(this: *mut core::ffi::c_void, refiid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain1", "GetCoreWindow", 21usize, |this| ((**(this as *const *const IDXGISwapChain1_Vtbl)).GetCoreWindow)(this, refiid, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn Present1(this: *mut core::ffi::c_void, syncinterval: u32, presentflags: DXGI_PRESENT, ppresentparameters: *const DXGI_PRESENT_PARAMETERS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain1", "Present1", 22usize, |this| ((**(this as *const *const IDXGISwapChain1_Vtbl)).Present1)(this, syncinterval, presentflags, ppresentparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTemporaryMonoSupported(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain1", "IsTemporaryMonoSupported", 23usize, |this| ((**(this as *const *const IDXGISwapChain1_Vtbl)).IsTemporaryMonoSupported)(this), |_| None)
+        }
+        unsafe extern "system" fn GetRestrictToOutput(this: *mut core::ffi::c_void, pprestricttooutput: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain1", "GetRestrictToOutput", 24usize, |this| ((**(this as *const *const IDXGISwapChain1_Vtbl)).GetRestrictToOutput)(this, pprestricttooutput), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackgroundColor(this: *mut core::ffi::c_void, pcolor: *const DXGI_RGBA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain1", "SetBackgroundColor", 25usize, |this| ((**(this as *const *const IDXGISwapChain1_Vtbl)).SetBackgroundColor)(this, pcolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBackgroundColor(this: *mut core::ffi::c_void, pcolor: *mut DXGI_RGBA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain1", "GetBackgroundColor", 26usize, |this| ((**(this as *const *const IDXGISwapChain1_Vtbl)).GetBackgroundColor)(this, pcolor), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn SetRotation(this: *mut core::ffi::c_void, rotation: Common::DXGI_MODE_ROTATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain1", "SetRotation", 27usize, |this| ((**(this as *const *const IDXGISwapChain1_Vtbl)).SetRotation)(this, rotation), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetRotation(this: *mut core::ffi::c_void, protation: *mut Common::DXGI_MODE_ROTATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain1", "GetRotation", 28usize, |this| ((**(this as *const *const IDXGISwapChain1_Vtbl)).GetRotation)(this, protation), |result| Some(*result))
+        }
+        Self {
+            base__: <IDXGISwapChain_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDesc1,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDesc1: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetFullscreenDesc,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetFullscreenDesc: 0,
+            GetHwnd,
+            GetCoreWindow,
+            Present1,
+            IsTemporaryMonoSupported,
+            GetRestrictToOutput,
+            SetBackgroundColor,
+            GetBackgroundColor,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            SetRotation,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            SetRotation: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetRotation,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetRotation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGISwapChain1 as windows_core::Interface>::IID || <IDXGISwapChain_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IDXGISwapChain2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSourceSize(this: *mut core::ffi::c_void, width: u32, height: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain2", "SetSourceSize", 29usize, |this| ((**(this as *const *const IDXGISwapChain2_Vtbl)).SetSourceSize)(this, width, height), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourceSize(this: *mut core::ffi::c_void, pwidth: *mut u32, pheight: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain2", "GetSourceSize", 30usize, |this| ((**(this as *const *const IDXGISwapChain2_Vtbl)).GetSourceSize)(this, pwidth, pheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaximumFrameLatency(this: *mut core::ffi::c_void, maxlatency: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain2", "SetMaximumFrameLatency", 31usize, |this| ((**(this as *const *const IDXGISwapChain2_Vtbl)).SetMaximumFrameLatency)(this, maxlatency), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaximumFrameLatency(this: *mut core::ffi::c_void, pmaxlatency: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain2", "GetMaximumFrameLatency", 32usize, |this| ((**(this as *const *const IDXGISwapChain2_Vtbl)).GetMaximumFrameLatency)(this, pmaxlatency), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFrameLatencyWaitableObject(this: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain2", "GetFrameLatencyWaitableObject", 33usize, |this| ((**(this as *const *const IDXGISwapChain2_Vtbl)).GetFrameLatencyWaitableObject)(this), |_| None)
+        }
+        unsafe extern "system" fn SetMatrixTransform(this: *mut core::ffi::c_void, pmatrix: *const DXGI_MATRIX_3X2_F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain2", "SetMatrixTransform", 34usize, |this| ((**(this as *const *const IDXGISwapChain2_Vtbl)).SetMatrixTransform)(this, pmatrix), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMatrixTransform(this: *mut core::ffi::c_void, pmatrix: *mut DXGI_MATRIX_3X2_F) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain2", "GetMatrixTransform", 35usize, |this| ((**(this as *const *const IDXGISwapChain2_Vtbl)).GetMatrixTransform)(this, pmatrix), |result| Some(*result))
+        }
+        Self {
+            base__: <IDXGISwapChain1_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetSourceSize,
+            GetSourceSize,
+            SetMaximumFrameLatency,
+            GetMaximumFrameLatency,
+            GetFrameLatencyWaitableObject,
+            SetMatrixTransform,
+            GetMatrixTransform,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGISwapChain2 as windows_core::Interface>::IID || <IDXGISwapChain1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IDXGISwapChain3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentBackBufferIndex(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain3", "GetCurrentBackBufferIndex", 36usize, |this| ((**(this as *const *const IDXGISwapChain3_Vtbl)).GetCurrentBackBufferIndex)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CheckColorSpaceSupport(this: *mut core::ffi::c_void, colorspace: Common::DXGI_COLOR_SPACE_TYPE, pcolorspacesupport: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain3", "CheckColorSpaceSupport", 37usize, |this| ((**(this as *const *const IDXGISwapChain3_Vtbl)).CheckColorSpaceSupport)(this, colorspace, pcolorspacesupport), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn SetColorSpace1(this: *mut core::ffi::c_void, colorspace: Common::DXGI_COLOR_SPACE_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain3", "SetColorSpace1", 38usize, |this| ((**(this as *const *const IDXGISwapChain3_Vtbl)).SetColorSpace1)(this, colorspace), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn ResizeBuffers1(this: *mut core::ffi::c_void, buffercount: u32, width: u32, height: u32, format: Common::DXGI_FORMAT, swapchainflags: u32, pcreationnodemask: *const u32, pppresentqueue: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain3", "ResizeBuffers1", 39usize, |this| ((**(this as *const *const IDXGISwapChain3_Vtbl)).ResizeBuffers1)(this, buffercount, width, height, format, swapchainflags, pcreationnodemask, pppresentqueue), |result| Some(*result))
+        }
+        Self {
+            base__: <IDXGISwapChain2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCurrentBackBufferIndex,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CheckColorSpaceSupport,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CheckColorSpaceSupport: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            SetColorSpace1,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            SetColorSpace1: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            ResizeBuffers1,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            ResizeBuffers1: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGISwapChain3 as windows_core::Interface>::IID || <IDXGISwapChain2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IDXGISwapChain4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetHDRMetaData(this: *mut core::ffi::c_void, r#type: DXGI_HDR_METADATA_TYPE, size: u32, pmetadata: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChain4", "SetHDRMetaData", 40usize, |this| ((**(this as *const *const IDXGISwapChain4_Vtbl)).SetHDRMetaData)(this, r#type, size, pmetadata), |result| Some(*result))
+        }
+        Self { base__: <IDXGISwapChain3_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetHDRMetaData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGISwapChain4 as windows_core::Interface>::IID || <IDXGISwapChain3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDXGISwapChainMedia_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFrameStatisticsMedia(this: *mut core::ffi::c_void, pstats: *mut DXGI_FRAME_STATISTICS_MEDIA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChainMedia", "GetFrameStatisticsMedia", 3usize, |this| ((**(this as *const *const IDXGISwapChainMedia_Vtbl)).GetFrameStatisticsMedia)(this, pstats), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPresentDuration(this: *mut core::ffi::c_void, duration: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChainMedia", "SetPresentDuration", 4usize, |this| ((**(this as *const *const IDXGISwapChainMedia_Vtbl)).SetPresentDuration)(this, duration), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckPresentDurationSupport(this: *mut core::ffi::c_void, desiredpresentduration: u32, pclosestsmallerpresentduration: *mut u32, pclosestlargerpresentduration: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXGISwapChainMedia", "CheckPresentDurationSupport", 5usize, |this| ((**(this as *const *const IDXGISwapChainMedia_Vtbl)).CheckPresentDurationSupport)(this, desiredpresentduration, pclosestsmallerpresentduration, pclosestlargerpresentduration), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetFrameStatisticsMedia,
+            SetPresentDuration,
+            CheckPresentDurationSupport,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGISwapChainMedia as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDXGraphicsAnalysis_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BeginCapture(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IDXGraphicsAnalysis", "BeginCapture", 3usize, |this| ((**(this as *const *const IDXGraphicsAnalysis_Vtbl)).BeginCapture)(this), |_| None)
+        }
+        unsafe extern "system" fn EndCapture(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IDXGraphicsAnalysis", "EndCapture", 4usize, |this| ((**(this as *const *const IDXGraphicsAnalysis_Vtbl)).EndCapture)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BeginCapture, EndCapture }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXGraphicsAnalysis as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
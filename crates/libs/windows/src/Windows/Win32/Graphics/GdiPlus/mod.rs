@@ -4460,6 +4460,7 @@ pub struct BrushType(pub i32);
 impl windows_core::TypeKind for BrushType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BrushType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BrushType").field(&self.0).finish()
@@ -4471,6 +4472,7 @@ pub struct ColorAdjustType(pub i32);
 impl windows_core::TypeKind for ColorAdjustType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ColorAdjustType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ColorAdjustType").field(&self.0).finish()
@@ -4482,6 +4484,7 @@ pub struct ColorChannelFlags(pub i32);
 impl windows_core::TypeKind for ColorChannelFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ColorChannelFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ColorChannelFlags").field(&self.0).finish()
@@ -4493,6 +4496,7 @@ pub struct ColorMatrixFlags(pub i32);
 impl windows_core::TypeKind for ColorMatrixFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ColorMatrixFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ColorMatrixFlags").field(&self.0).finish()
@@ -4504,6 +4508,7 @@ pub struct ColorMode(pub i32);
 impl windows_core::TypeKind for ColorMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ColorMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ColorMode").field(&self.0).finish()
@@ -4515,6 +4520,7 @@ pub struct CombineMode(pub i32);
 impl windows_core::TypeKind for CombineMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CombineMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CombineMode").field(&self.0).finish()
@@ -4526,6 +4532,7 @@ pub struct CompositingMode(pub i32);
 impl windows_core::TypeKind for CompositingMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CompositingMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CompositingMode").field(&self.0).finish()
@@ -4537,6 +4544,7 @@ pub struct CompositingQuality(pub i32);
 impl windows_core::TypeKind for CompositingQuality {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CompositingQuality {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CompositingQuality").field(&self.0).finish()
@@ -4548,6 +4556,7 @@ pub struct ConvertToEmfPlusFlags(pub i32);
 impl windows_core::TypeKind for ConvertToEmfPlusFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ConvertToEmfPlusFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ConvertToEmfPlusFlags").field(&self.0).finish()
@@ -4559,6 +4568,7 @@ pub struct CoordinateSpace(pub i32);
 impl windows_core::TypeKind for CoordinateSpace {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CoordinateSpace {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CoordinateSpace").field(&self.0).finish()
@@ -4570,6 +4580,7 @@ pub struct CurveAdjustments(pub i32);
 impl windows_core::TypeKind for CurveAdjustments {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CurveAdjustments {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CurveAdjustments").field(&self.0).finish()
@@ -4581,6 +4592,7 @@ pub struct CurveChannel(pub i32);
 impl windows_core::TypeKind for CurveChannel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CurveChannel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CurveChannel").field(&self.0).finish()
@@ -4592,6 +4604,7 @@ pub struct CustomLineCapType(pub i32);
 impl windows_core::TypeKind for CustomLineCapType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CustomLineCapType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CustomLineCapType").field(&self.0).finish()
@@ -4603,6 +4616,7 @@ pub struct DashCap(pub i32);
 impl windows_core::TypeKind for DashCap {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DashCap {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DashCap").field(&self.0).finish()
@@ -4614,6 +4628,7 @@ pub struct DashStyle(pub i32);
 impl windows_core::TypeKind for DashStyle {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DashStyle {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DashStyle").field(&self.0).finish()
@@ -4625,6 +4640,7 @@ pub struct DebugEventLevel(pub i32);
 impl windows_core::TypeKind for DebugEventLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DebugEventLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DebugEventLevel").field(&self.0).finish()
@@ -4636,6 +4652,7 @@ pub struct DitherType(pub i32);
 impl windows_core::TypeKind for DitherType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DitherType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DitherType").field(&self.0).finish()
@@ -4647,6 +4664,7 @@ pub struct DriverStringOptions(pub i32);
 impl windows_core::TypeKind for DriverStringOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DriverStringOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DriverStringOptions").field(&self.0).finish()
@@ -4658,6 +4676,7 @@ pub struct EmfPlusRecordType(pub i32);
 impl windows_core::TypeKind for EmfPlusRecordType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmfPlusRecordType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmfPlusRecordType").field(&self.0).finish()
@@ -4669,6 +4688,7 @@ pub struct EmfToWmfBitsFlags(pub i32);
 impl windows_core::TypeKind for EmfToWmfBitsFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmfToWmfBitsFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmfToWmfBitsFlags").field(&self.0).finish()
@@ -4680,6 +4700,7 @@ pub struct EmfType(pub i32);
 impl windows_core::TypeKind for EmfType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmfType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmfType").field(&self.0).finish()
@@ -4691,6 +4712,7 @@ pub struct EncoderParameterValueType(pub i32);
 impl windows_core::TypeKind for EncoderParameterValueType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EncoderParameterValueType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EncoderParameterValueType").field(&self.0).finish()
@@ -4702,6 +4724,7 @@ pub struct EncoderValue(pub i32);
 impl windows_core::TypeKind for EncoderValue {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EncoderValue {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EncoderValue").field(&self.0).finish()
@@ -4713,6 +4736,7 @@ pub struct FillMode(pub i32);
 impl windows_core::TypeKind for FillMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FillMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FillMode").field(&self.0).finish()
@@ -4724,6 +4748,7 @@ pub struct FlushIntention(pub i32);
 impl windows_core::TypeKind for FlushIntention {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FlushIntention {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FlushIntention").field(&self.0).finish()
@@ -4735,6 +4760,7 @@ pub struct FontStyle(pub i32);
 impl windows_core::TypeKind for FontStyle {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FontStyle {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FontStyle").field(&self.0).finish()
@@ -4746,6 +4772,7 @@ pub struct GdiplusStartupParams(pub i32);
 impl windows_core::TypeKind for GdiplusStartupParams {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GdiplusStartupParams {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GdiplusStartupParams").field(&self.0).finish()
@@ -4757,6 +4784,7 @@ pub struct GenericFontFamily(pub i32);
 impl windows_core::TypeKind for GenericFontFamily {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GenericFontFamily {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GenericFontFamily").field(&self.0).finish()
@@ -4768,6 +4796,7 @@ pub struct GpTestControlEnum(pub i32);
 impl windows_core::TypeKind for GpTestControlEnum {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GpTestControlEnum {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GpTestControlEnum").field(&self.0).finish()
@@ -4779,6 +4808,7 @@ pub struct HatchStyle(pub i32);
 impl windows_core::TypeKind for HatchStyle {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HatchStyle {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HatchStyle").field(&self.0).finish()
@@ -4790,6 +4820,7 @@ pub struct HistogramFormat(pub i32);
 impl windows_core::TypeKind for HistogramFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HistogramFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HistogramFormat").field(&self.0).finish()
@@ -4801,6 +4832,7 @@ pub struct HotkeyPrefix(pub i32);
 impl windows_core::TypeKind for HotkeyPrefix {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HotkeyPrefix {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HotkeyPrefix").field(&self.0).finish()
@@ -4812,6 +4844,7 @@ pub struct ImageCodecFlags(pub i32);
 impl windows_core::TypeKind for ImageCodecFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ImageCodecFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ImageCodecFlags").field(&self.0).finish()
@@ -4823,6 +4856,7 @@ pub struct ImageFlags(pub i32);
 impl windows_core::TypeKind for ImageFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ImageFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ImageFlags").field(&self.0).finish()
@@ -4834,6 +4868,7 @@ pub struct ImageLockMode(pub i32);
 impl windows_core::TypeKind for ImageLockMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ImageLockMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ImageLockMode").field(&self.0).finish()
@@ -4845,6 +4880,7 @@ pub struct ImageType(pub i32);
 impl windows_core::TypeKind for ImageType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ImageType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ImageType").field(&self.0).finish()
@@ -4856,6 +4892,7 @@ pub struct InterpolationMode(pub i32);
 impl windows_core::TypeKind for InterpolationMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InterpolationMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InterpolationMode").field(&self.0).finish()
@@ -4867,6 +4904,7 @@ pub struct ItemDataPosition(pub i32);
 impl windows_core::TypeKind for ItemDataPosition {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ItemDataPosition {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ItemDataPosition").field(&self.0).finish()
@@ -4878,6 +4916,7 @@ pub struct LineCap(pub i32);
 impl windows_core::TypeKind for LineCap {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LineCap {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LineCap").field(&self.0).finish()
@@ -4889,6 +4928,7 @@ pub struct LineJoin(pub i32);
 impl windows_core::TypeKind for LineJoin {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LineJoin {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LineJoin").field(&self.0).finish()
@@ -4900,6 +4940,7 @@ pub struct LinearGradientMode(pub i32);
 impl windows_core::TypeKind for LinearGradientMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LinearGradientMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LinearGradientMode").field(&self.0).finish()
@@ -4911,6 +4952,7 @@ pub struct MatrixOrder(pub i32);
 impl windows_core::TypeKind for MatrixOrder {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MatrixOrder {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MatrixOrder").field(&self.0).finish()
@@ -4922,6 +4964,7 @@ pub struct MetafileFrameUnit(pub i32);
 impl windows_core::TypeKind for MetafileFrameUnit {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MetafileFrameUnit {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MetafileFrameUnit").field(&self.0).finish()
@@ -4933,6 +4976,7 @@ pub struct MetafileType(pub i32);
 impl windows_core::TypeKind for MetafileType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MetafileType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MetafileType").field(&self.0).finish()
@@ -4944,6 +4988,7 @@ pub struct ObjectType(pub i32);
 impl windows_core::TypeKind for ObjectType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ObjectType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ObjectType").field(&self.0).finish()
@@ -4955,6 +5000,7 @@ pub struct PaletteFlags(pub i32);
 impl windows_core::TypeKind for PaletteFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PaletteFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PaletteFlags").field(&self.0).finish()
@@ -4966,6 +5012,7 @@ pub struct PaletteType(pub i32);
 impl windows_core::TypeKind for PaletteType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PaletteType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PaletteType").field(&self.0).finish()
@@ -4977,6 +5024,7 @@ pub struct PathPointType(pub i32);
 impl windows_core::TypeKind for PathPointType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PathPointType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PathPointType").field(&self.0).finish()
@@ -4988,6 +5036,7 @@ pub struct PenAlignment(pub i32);
 impl windows_core::TypeKind for PenAlignment {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PenAlignment {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PenAlignment").field(&self.0).finish()
@@ -4999,6 +5048,7 @@ pub struct PenType(pub i32);
 impl windows_core::TypeKind for PenType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PenType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PenType").field(&self.0).finish()
@@ -5010,6 +5060,7 @@ pub struct PixelOffsetMode(pub i32);
 impl windows_core::TypeKind for PixelOffsetMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PixelOffsetMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PixelOffsetMode").field(&self.0).finish()
@@ -5021,6 +5072,7 @@ pub struct QualityMode(pub i32);
 impl windows_core::TypeKind for QualityMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for QualityMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("QualityMode").field(&self.0).finish()
@@ -5032,6 +5084,7 @@ pub struct RotateFlipType(pub i32);
 impl windows_core::TypeKind for RotateFlipType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RotateFlipType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RotateFlipType").field(&self.0).finish()
@@ -5043,6 +5096,7 @@ pub struct SmoothingMode(pub i32);
 impl windows_core::TypeKind for SmoothingMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmoothingMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmoothingMode").field(&self.0).finish()
@@ -5054,6 +5108,7 @@ pub struct Status(pub i32);
 impl windows_core::TypeKind for Status {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for Status {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Status").field(&self.0).finish()
@@ -5065,6 +5120,7 @@ pub struct StringAlignment(pub i32);
 impl windows_core::TypeKind for StringAlignment {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StringAlignment {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StringAlignment").field(&self.0).finish()
@@ -5076,6 +5132,7 @@ pub struct StringDigitSubstitute(pub i32);
 impl windows_core::TypeKind for StringDigitSubstitute {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StringDigitSubstitute {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StringDigitSubstitute").field(&self.0).finish()
@@ -5087,6 +5144,7 @@ pub struct StringFormatFlags(pub i32);
 impl windows_core::TypeKind for StringFormatFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StringFormatFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StringFormatFlags").field(&self.0).finish()
@@ -5098,6 +5156,7 @@ pub struct StringTrimming(pub i32);
 impl windows_core::TypeKind for StringTrimming {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StringTrimming {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StringTrimming").field(&self.0).finish()
@@ -5109,6 +5168,7 @@ pub struct TextRenderingHint(pub i32);
 impl windows_core::TypeKind for TextRenderingHint {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TextRenderingHint {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TextRenderingHint").field(&self.0).finish()
@@ -5120,6 +5180,7 @@ pub struct Unit(pub i32);
 impl windows_core::TypeKind for Unit {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for Unit {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Unit").field(&self.0).finish()
@@ -5131,6 +5192,7 @@ pub struct WarpMode(pub i32);
 impl windows_core::TypeKind for WarpMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WarpMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WarpMode").field(&self.0).finish()
@@ -5142,13 +5204,15 @@ pub struct WrapMode(pub i32);
 impl windows_core::TypeKind for WrapMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WrapMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WrapMode").field(&self.0).finish()
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Bitmap(pub isize);
 impl Default for Bitmap {
     fn default() -> Self {
@@ -5159,7 +5223,8 @@ impl windows_core::TypeKind for Bitmap {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BitmapData {
     pub Width: u32,
     pub Height: u32,
@@ -5177,7 +5242,8 @@ impl Default for BitmapData {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Blur {
     pub Base: Effect,
 }
@@ -5190,7 +5256,8 @@ impl Default for Blur {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BlurParams {
     pub radius: f32,
     pub expandEdge: super::super::Foundation::BOOL,
@@ -5204,7 +5271,8 @@ impl Default for BlurParams {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BrightnessContrast {
     pub Base: Effect,
 }
@@ -5217,7 +5285,8 @@ impl Default for BrightnessContrast {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BrightnessContrastParams {
     pub brightnessLevel: i32,
     pub contrastLevel: i32,
@@ -5231,7 +5300,8 @@ impl Default for BrightnessContrastParams {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CGpEffect(pub isize);
 impl Default for CGpEffect {
     fn default() -> Self {
@@ -5242,7 +5312,8 @@ impl windows_core::TypeKind for CGpEffect {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CachedBitmap(pub isize);
 impl Default for CachedBitmap {
     fn default() -> Self {
@@ -5253,7 +5324,8 @@ impl windows_core::TypeKind for CachedBitmap {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CharacterRange {
     pub First: i32,
     pub Length: i32,
@@ -5267,7 +5339,8 @@ impl Default for CharacterRange {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Color {
     pub Argb: u32,
 }
@@ -5431,7 +5504,8 @@ impl Default for Color {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ColorBalance {
     pub Base: Effect,
 }
@@ -5444,7 +5518,8 @@ impl Default for ColorBalance {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ColorBalanceParams {
     pub cyanRed: i32,
     pub magentaGreen: i32,
@@ -5459,7 +5534,8 @@ impl Default for ColorBalanceParams {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ColorCurve {
     pub Base: Effect,
 }
@@ -5472,7 +5548,8 @@ impl Default for ColorCurve {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ColorCurveParams {
     pub adjustment: CurveAdjustments,
     pub channel: CurveChannel,
@@ -5487,7 +5564,8 @@ impl Default for ColorCurveParams {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ColorLUT {
     pub Base: Effect,
 }
@@ -5500,7 +5578,8 @@ impl Default for ColorLUT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ColorLUTParams {
     pub lutB: [u8; 256],
     pub lutG: [u8; 256],
@@ -5516,7 +5595,8 @@ impl Default for ColorLUTParams {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ColorMap {
     pub oldColor: Color,
     pub newColor: Color,
@@ -5530,7 +5610,8 @@ impl Default for ColorMap {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ColorMatrix {
     pub m: [f32; 25],
 }
@@ -5543,7 +5624,8 @@ impl Default for ColorMatrix {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ColorMatrixEffect {
     pub Base: Effect,
 }
@@ -5556,7 +5638,8 @@ impl Default for ColorMatrixEffect {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ColorPalette {
     pub Flags: u32,
     pub Count: u32,
@@ -5571,7 +5654,8 @@ impl Default for ColorPalette {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CustomLineCap(pub isize);
 impl Default for CustomLineCap {
     fn default() -> Self {
@@ -5582,7 +5666,8 @@ impl windows_core::TypeKind for CustomLineCap {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ENHMETAHEADER3 {
     pub iType: u32,
     pub nSize: u32,
@@ -5609,7 +5694,8 @@ impl Default for ENHMETAHEADER3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Effect {
     pub lpVtbl: *mut *mut core::ffi::c_void,
     pub nativeEffect: *mut CGpEffect,
@@ -5626,7 +5712,8 @@ impl Default for Effect {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EncoderParameter {
     pub Guid: windows_core::GUID,
     pub NumberOfValues: u32,
@@ -5642,7 +5729,8 @@ impl Default for EncoderParameter {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EncoderParameters {
     pub Count: u32,
     pub Parameter: [EncoderParameter; 1],
@@ -5656,7 +5744,8 @@ impl Default for EncoderParameters {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Font(pub isize);
 impl Default for Font {
     fn default() -> Self {
@@ -5667,7 +5756,8 @@ impl windows_core::TypeKind for Font {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FontCollection(pub isize);
 impl Default for FontCollection {
     fn default() -> Self {
@@ -5678,7 +5768,8 @@ impl windows_core::TypeKind for FontCollection {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FontFamily(pub isize);
 impl Default for FontFamily {
     fn default() -> Self {
@@ -5689,7 +5780,8 @@ impl windows_core::TypeKind for FontFamily {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GdiplusStartupInput {
     pub GdiplusVersion: u32,
     pub DebugEventCallback: isize,
@@ -5705,7 +5797,8 @@ impl Default for GdiplusStartupInput {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GdiplusStartupInputEx {
     pub Base: GdiplusStartupInput,
     pub StartupParameters: i32,
@@ -5719,7 +5812,8 @@ impl Default for GdiplusStartupInputEx {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GdiplusStartupOutput {
     pub NotificationHook: isize,
     pub NotificationUnhook: isize,
@@ -5733,7 +5827,8 @@ impl Default for GdiplusStartupOutput {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GpAdjustableArrowCap(pub u8);
 impl windows_core::TypeKind for GpAdjustableArrowCap {
     type TypeKind = windows_core::CopyType;
@@ -5744,7 +5839,8 @@ impl Default for GpAdjustableArrowCap {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GpBitmap(pub u8);
 impl windows_core::TypeKind for GpBitmap {
     type TypeKind = windows_core::CopyType;
@@ -5755,7 +5851,8 @@ impl Default for GpBitmap {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GpBrush(pub u8);
 impl windows_core::TypeKind for GpBrush {
     type TypeKind = windows_core::CopyType;
@@ -5766,7 +5863,8 @@ impl Default for GpBrush {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GpCachedBitmap(pub u8);
 impl windows_core::TypeKind for GpCachedBitmap {
     type TypeKind = windows_core::CopyType;
@@ -5777,7 +5875,8 @@ impl Default for GpCachedBitmap {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GpCustomLineCap(pub u8);
 impl windows_core::TypeKind for GpCustomLineCap {
     type TypeKind = windows_core::CopyType;
@@ -5788,7 +5887,8 @@ impl Default for GpCustomLineCap {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GpFont(pub u8);
 impl windows_core::TypeKind for GpFont {
     type TypeKind = windows_core::CopyType;
@@ -5799,7 +5899,8 @@ impl Default for GpFont {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GpFontCollection(pub u8);
 impl windows_core::TypeKind for GpFontCollection {
     type TypeKind = windows_core::CopyType;
@@ -5810,7 +5911,8 @@ impl Default for GpFontCollection {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GpFontFamily(pub u8);
 impl windows_core::TypeKind for GpFontFamily {
     type TypeKind = windows_core::CopyType;
@@ -5821,7 +5923,8 @@ impl Default for GpFontFamily {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GpGraphics(pub u8);
 impl windows_core::TypeKind for GpGraphics {
     type TypeKind = windows_core::CopyType;
@@ -5832,7 +5935,8 @@ impl Default for GpGraphics {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GpHatch(pub u8);
 impl windows_core::TypeKind for GpHatch {
     type TypeKind = windows_core::CopyType;
@@ -5843,7 +5947,8 @@ impl Default for GpHatch {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GpImage(pub u8);
 impl windows_core::TypeKind for GpImage {
     type TypeKind = windows_core::CopyType;
@@ -5854,7 +5959,8 @@ impl Default for GpImage {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GpImageAttributes(pub u8);
 impl windows_core::TypeKind for GpImageAttributes {
     type TypeKind = windows_core::CopyType;
@@ -5865,7 +5971,8 @@ impl Default for GpImageAttributes {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GpInstalledFontCollection(pub u8);
 impl windows_core::TypeKind for GpInstalledFontCollection {
     type TypeKind = windows_core::CopyType;
@@ -5876,7 +5983,8 @@ impl Default for GpInstalledFontCollection {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GpLineGradient(pub u8);
 impl windows_core::TypeKind for GpLineGradient {
     type TypeKind = windows_core::CopyType;
@@ -5887,7 +5995,8 @@ impl Default for GpLineGradient {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GpMetafile(pub u8);
 impl windows_core::TypeKind for GpMetafile {
     type TypeKind = windows_core::CopyType;
@@ -5898,7 +6007,8 @@ impl Default for GpMetafile {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GpPath(pub u8);
 impl windows_core::TypeKind for GpPath {
     type TypeKind = windows_core::CopyType;
@@ -5909,7 +6019,8 @@ impl Default for GpPath {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GpPathGradient(pub u8);
 impl windows_core::TypeKind for GpPathGradient {
     type TypeKind = windows_core::CopyType;
@@ -5920,7 +6031,8 @@ impl Default for GpPathGradient {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GpPathIterator(pub u8);
 impl windows_core::TypeKind for GpPathIterator {
     type TypeKind = windows_core::CopyType;
@@ -5931,7 +6043,8 @@ impl Default for GpPathIterator {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GpPen(pub u8);
 impl windows_core::TypeKind for GpPen {
     type TypeKind = windows_core::CopyType;
@@ -5942,7 +6055,8 @@ impl Default for GpPen {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GpPrivateFontCollection(pub u8);
 impl windows_core::TypeKind for GpPrivateFontCollection {
     type TypeKind = windows_core::CopyType;
@@ -5953,7 +6067,8 @@ impl Default for GpPrivateFontCollection {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GpRegion(pub u8);
 impl windows_core::TypeKind for GpRegion {
     type TypeKind = windows_core::CopyType;
@@ -5964,7 +6079,8 @@ impl Default for GpRegion {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GpSolidFill(pub u8);
 impl windows_core::TypeKind for GpSolidFill {
     type TypeKind = windows_core::CopyType;
@@ -5975,7 +6091,8 @@ impl Default for GpSolidFill {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GpStringFormat(pub u8);
 impl windows_core::TypeKind for GpStringFormat {
     type TypeKind = windows_core::CopyType;
@@ -5986,7 +6103,8 @@ impl Default for GpStringFormat {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GpTexture(pub u8);
 impl windows_core::TypeKind for GpTexture {
     type TypeKind = windows_core::CopyType;
@@ -5997,7 +6115,8 @@ impl Default for GpTexture {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HueSaturationLightness {
     pub Base: Effect,
 }
@@ -6010,7 +6129,8 @@ impl Default for HueSaturationLightness {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HueSaturationLightnessParams {
     pub hueLevel: i32,
     pub saturationLevel: i32,
@@ -6025,7 +6145,8 @@ impl Default for HueSaturationLightnessParams {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Image(pub isize);
 impl Default for Image {
     fn default() -> Self {
@@ -6036,7 +6157,8 @@ impl windows_core::TypeKind for Image {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ImageCodecInfo {
     pub Clsid: windows_core::GUID,
     pub FormatID: windows_core::GUID,
@@ -6061,7 +6183,8 @@ impl Default for ImageCodecInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ImageItemData {
     pub Size: u32,
     pub Position: u32,
@@ -6080,7 +6203,8 @@ impl Default for ImageItemData {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct InstalledFontCollection(pub isize);
 impl Default for InstalledFontCollection {
     fn default() -> Self {
@@ -6091,7 +6215,8 @@ impl windows_core::TypeKind for InstalledFontCollection {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Levels {
     pub Base: Effect,
 }
@@ -6104,7 +6229,8 @@ impl Default for Levels {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LevelsParams {
     pub highlight: i32,
     pub midtone: i32,
@@ -6119,7 +6245,8 @@ impl Default for LevelsParams {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Matrix(pub isize);
 impl Default for Matrix {
     fn default() -> Self {
@@ -6130,7 +6257,8 @@ impl windows_core::TypeKind for Matrix {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Metafile(pub isize);
 impl Default for Metafile {
     fn default() -> Self {
@@ -6187,7 +6315,8 @@ impl Default for MetafileHeader_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PWMFRect16 {
     pub Left: i16,
     pub Top: i16,
@@ -6203,7 +6332,8 @@ impl Default for PWMFRect16 {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PathData(pub isize);
 impl Default for PathData {
     fn default() -> Self {
@@ -6214,7 +6344,8 @@ impl windows_core::TypeKind for PathData {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Point {
     pub X: i32,
     pub Y: i32,
@@ -6228,7 +6359,8 @@ impl Default for Point {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PointF {
     pub X: f32,
     pub Y: f32,
@@ -6242,7 +6374,8 @@ impl Default for PointF {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PrivateFontCollection(pub isize);
 impl Default for PrivateFontCollection {
     fn default() -> Self {
@@ -6253,7 +6386,8 @@ impl windows_core::TypeKind for PrivateFontCollection {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PropertyItem {
     pub id: u32,
     pub length: u32,
@@ -6269,7 +6403,8 @@ impl Default for PropertyItem {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Rect {
     pub X: i32,
     pub Y: i32,
@@ -6285,7 +6420,8 @@ impl Default for Rect {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RectF {
     pub X: f32,
     pub Y: f32,
@@ -6301,7 +6437,8 @@ impl Default for RectF {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RedEyeCorrection {
     pub Base: Effect,
 }
@@ -6314,7 +6451,8 @@ impl Default for RedEyeCorrection {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RedEyeCorrectionParams {
     pub numberOfAreas: u32,
     pub areas: *mut super::super::Foundation::RECT,
@@ -6328,7 +6466,8 @@ impl Default for RedEyeCorrectionParams {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Region(pub isize);
 impl Default for Region {
     fn default() -> Self {
@@ -6339,7 +6478,8 @@ impl windows_core::TypeKind for Region {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Sharpen {
     pub Base: Effect,
 }
@@ -6352,7 +6492,8 @@ impl Default for Sharpen {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SharpenParams {
     pub radius: f32,
     pub amount: f32,
@@ -6366,7 +6507,8 @@ impl Default for SharpenParams {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Size {
     pub Width: i32,
     pub Height: i32,
@@ -6380,7 +6522,8 @@ impl Default for Size {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SizeF {
     pub Width: f32,
     pub Height: f32,
@@ -6394,7 +6537,8 @@ impl Default for SizeF {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Tint {
     pub Base: Effect,
 }
@@ -6407,7 +6551,8 @@ impl Default for Tint {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TintParams {
     pub hue: i32,
     pub amount: i32,
@@ -6447,3 +6592,5 @@ pub type NotificationHookProc = Option<unsafe extern "system" fn(token: *mut usi
 pub type NotificationUnhookProc = Option<unsafe extern "system" fn(token: usize)>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
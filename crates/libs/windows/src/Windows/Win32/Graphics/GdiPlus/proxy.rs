@@ -0,0 +1,18 @@
+impl windows_core::imp::ProxyVtable for IImageBytes_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CountBytes(this: *mut core::ffi::c_void, pcb: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageBytes", "CountBytes", 3usize, |this| ((**(this as *const *const IImageBytes_Vtbl)).CountBytes)(this, pcb), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockBytes(this: *mut core::ffi::c_void, cb: u32, uloffset: u32, ppvbytes: *const *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageBytes", "LockBytes", 4usize, |this| ((**(this as *const *const IImageBytes_Vtbl)).LockBytes)(this, cb, uloffset, ppvbytes), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockBytes(this: *mut core::ffi::c_void, pvbytes: *const core::ffi::c_void, cb: u32, uloffset: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageBytes", "UnlockBytes", 5usize, |this| ((**(this as *const *const IImageBytes_Vtbl)).UnlockBytes)(this, pvbytes, cb, uloffset), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CountBytes, LockBytes, UnlockBytes }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImageBytes as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
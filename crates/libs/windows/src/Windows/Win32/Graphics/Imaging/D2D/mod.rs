@@ -76,3 +76,5 @@ pub struct IWICImagingFactory2_Vtbl {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
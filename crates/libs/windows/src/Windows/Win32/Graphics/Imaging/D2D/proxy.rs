@@ -0,0 +1,56 @@
+#[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for IWICImageEncoder_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn WriteFrame(this: *mut core::ffi::c_void, pimage: *mut core::ffi::c_void, pframeencode: *mut core::ffi::c_void, pimageparameters: *const super::WICImageParameters) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImageEncoder", "WriteFrame", 3usize, |this| ((**(this as *const *const IWICImageEncoder_Vtbl)).WriteFrame)(this, pimage, pframeencode, pimageparameters), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn WriteFrameThumbnail(this: *mut core::ffi::c_void, pimage: *mut core::ffi::c_void, pframeencode: *mut core::ffi::c_void, pimageparameters: *const super::WICImageParameters) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImageEncoder", "WriteFrameThumbnail", 4usize, |this| ((**(this as *const *const IWICImageEncoder_Vtbl)).WriteFrameThumbnail)(this, pimage, pframeencode, pimageparameters), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn WriteThumbnail(this: *mut core::ffi::c_void, pimage: *mut core::ffi::c_void, pencoder: *mut core::ffi::c_void, pimageparameters: *const super::WICImageParameters) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImageEncoder", "WriteThumbnail", 5usize, |this| ((**(this as *const *const IWICImageEncoder_Vtbl)).WriteThumbnail)(this, pimage, pencoder, pimageparameters), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+            WriteFrame,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common")))]
+            WriteFrame: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+            WriteFrameThumbnail,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common")))]
+            WriteFrameThumbnail: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
+            WriteThumbnail,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common")))]
+            WriteThumbnail: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICImageEncoder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct2D", feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com", feature = "Win32_UI_WindowsAndMessaging"))]
+impl windows_core::imp::ProxyVtable for IWICImagingFactory2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D")]
+        unsafe extern "system" fn CreateImageEncoder(this: *mut core::ffi::c_void, pd2ddevice: *mut core::ffi::c_void, ppwicimageencoder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImagingFactory2", "CreateImageEncoder", 28usize, |this| ((**(this as *const *const IWICImagingFactory2_Vtbl)).CreateImageEncoder)(this, pd2ddevice, ppwicimageencoder), |result| Some(*result))
+        }
+        Self {
+            base__: <super::IWICImagingFactory_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D")]
+            CreateImageEncoder,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D"))]
+            CreateImageEncoder: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICImagingFactory2 as windows_core::Interface>::IID || <super::IWICImagingFactory_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
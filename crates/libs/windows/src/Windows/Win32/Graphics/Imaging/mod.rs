@@ -2786,6 +2786,7 @@ pub struct WIC8BIMIptcDigestProperties(pub i32);
 impl windows_core::TypeKind for WIC8BIMIptcDigestProperties {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WIC8BIMIptcDigestProperties {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WIC8BIMIptcDigestProperties").field(&self.0).finish()
@@ -2797,6 +2798,7 @@ pub struct WIC8BIMIptcProperties(pub i32);
 impl windows_core::TypeKind for WIC8BIMIptcProperties {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WIC8BIMIptcProperties {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WIC8BIMIptcProperties").field(&self.0).finish()
@@ -2808,6 +2810,7 @@ pub struct WIC8BIMResolutionInfoProperties(pub i32);
 impl windows_core::TypeKind for WIC8BIMResolutionInfoProperties {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WIC8BIMResolutionInfoProperties {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WIC8BIMResolutionInfoProperties").field(&self.0).finish()
@@ -2819,6 +2822,7 @@ pub struct WICBitmapAlphaChannelOption(pub i32);
 impl windows_core::TypeKind for WICBitmapAlphaChannelOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICBitmapAlphaChannelOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICBitmapAlphaChannelOption").field(&self.0).finish()
@@ -2830,6 +2834,7 @@ pub struct WICBitmapCreateCacheOption(pub i32);
 impl windows_core::TypeKind for WICBitmapCreateCacheOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICBitmapCreateCacheOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICBitmapCreateCacheOption").field(&self.0).finish()
@@ -2841,6 +2846,7 @@ pub struct WICBitmapDecoderCapabilities(pub i32);
 impl windows_core::TypeKind for WICBitmapDecoderCapabilities {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICBitmapDecoderCapabilities {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICBitmapDecoderCapabilities").field(&self.0).finish()
@@ -2852,6 +2858,7 @@ pub struct WICBitmapDitherType(pub i32);
 impl windows_core::TypeKind for WICBitmapDitherType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICBitmapDitherType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICBitmapDitherType").field(&self.0).finish()
@@ -2863,6 +2870,7 @@ pub struct WICBitmapEncoderCacheOption(pub i32);
 impl windows_core::TypeKind for WICBitmapEncoderCacheOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICBitmapEncoderCacheOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICBitmapEncoderCacheOption").field(&self.0).finish()
@@ -2874,6 +2882,7 @@ pub struct WICBitmapInterpolationMode(pub i32);
 impl windows_core::TypeKind for WICBitmapInterpolationMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICBitmapInterpolationMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICBitmapInterpolationMode").field(&self.0).finish()
@@ -2885,6 +2894,7 @@ pub struct WICBitmapLockFlags(pub i32);
 impl windows_core::TypeKind for WICBitmapLockFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICBitmapLockFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICBitmapLockFlags").field(&self.0).finish()
@@ -2896,6 +2906,7 @@ pub struct WICBitmapPaletteType(pub i32);
 impl windows_core::TypeKind for WICBitmapPaletteType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICBitmapPaletteType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICBitmapPaletteType").field(&self.0).finish()
@@ -2907,6 +2918,7 @@ pub struct WICBitmapTransformOptions(pub i32);
 impl windows_core::TypeKind for WICBitmapTransformOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICBitmapTransformOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICBitmapTransformOptions").field(&self.0).finish()
@@ -2918,6 +2930,7 @@ pub struct WICColorContextType(pub i32);
 impl windows_core::TypeKind for WICColorContextType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICColorContextType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICColorContextType").field(&self.0).finish()
@@ -2929,6 +2942,7 @@ pub struct WICComponentEnumerateOptions(pub i32);
 impl windows_core::TypeKind for WICComponentEnumerateOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICComponentEnumerateOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICComponentEnumerateOptions").field(&self.0).finish()
@@ -2940,6 +2954,7 @@ pub struct WICComponentSigning(pub i32);
 impl windows_core::TypeKind for WICComponentSigning {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICComponentSigning {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICComponentSigning").field(&self.0).finish()
@@ -2951,6 +2966,7 @@ pub struct WICComponentType(pub i32);
 impl windows_core::TypeKind for WICComponentType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICComponentType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICComponentType").field(&self.0).finish()
@@ -2962,6 +2978,7 @@ pub struct WICDdsAlphaMode(pub i32);
 impl windows_core::TypeKind for WICDdsAlphaMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICDdsAlphaMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICDdsAlphaMode").field(&self.0).finish()
@@ -2973,6 +2990,7 @@ pub struct WICDdsDimension(pub i32);
 impl windows_core::TypeKind for WICDdsDimension {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICDdsDimension {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICDdsDimension").field(&self.0).finish()
@@ -2984,6 +3002,7 @@ pub struct WICDecodeOptions(pub i32);
 impl windows_core::TypeKind for WICDecodeOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICDecodeOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICDecodeOptions").field(&self.0).finish()
@@ -2995,6 +3014,7 @@ pub struct WICGifApplicationExtensionProperties(pub i32);
 impl windows_core::TypeKind for WICGifApplicationExtensionProperties {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICGifApplicationExtensionProperties {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICGifApplicationExtensionProperties").field(&self.0).finish()
@@ -3006,6 +3026,7 @@ pub struct WICGifCommentExtensionProperties(pub i32);
 impl windows_core::TypeKind for WICGifCommentExtensionProperties {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICGifCommentExtensionProperties {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICGifCommentExtensionProperties").field(&self.0).finish()
@@ -3017,6 +3038,7 @@ pub struct WICGifGraphicControlExtensionProperties(pub i32);
 impl windows_core::TypeKind for WICGifGraphicControlExtensionProperties {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICGifGraphicControlExtensionProperties {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICGifGraphicControlExtensionProperties").field(&self.0).finish()
@@ -3028,6 +3050,7 @@ pub struct WICGifImageDescriptorProperties(pub i32);
 impl windows_core::TypeKind for WICGifImageDescriptorProperties {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICGifImageDescriptorProperties {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICGifImageDescriptorProperties").field(&self.0).finish()
@@ -3039,6 +3062,7 @@ pub struct WICGifLogicalScreenDescriptorProperties(pub i32);
 impl windows_core::TypeKind for WICGifLogicalScreenDescriptorProperties {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICGifLogicalScreenDescriptorProperties {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICGifLogicalScreenDescriptorProperties").field(&self.0).finish()
@@ -3050,6 +3074,7 @@ pub struct WICHeifHdrProperties(pub i32);
 impl windows_core::TypeKind for WICHeifHdrProperties {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICHeifHdrProperties {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICHeifHdrProperties").field(&self.0).finish()
@@ -3061,6 +3086,7 @@ pub struct WICHeifProperties(pub i32);
 impl windows_core::TypeKind for WICHeifProperties {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICHeifProperties {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICHeifProperties").field(&self.0).finish()
@@ -3072,6 +3098,7 @@ pub struct WICJpegChrominanceProperties(pub i32);
 impl windows_core::TypeKind for WICJpegChrominanceProperties {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICJpegChrominanceProperties {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICJpegChrominanceProperties").field(&self.0).finish()
@@ -3083,6 +3110,7 @@ pub struct WICJpegCommentProperties(pub i32);
 impl windows_core::TypeKind for WICJpegCommentProperties {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICJpegCommentProperties {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICJpegCommentProperties").field(&self.0).finish()
@@ -3094,6 +3122,7 @@ pub struct WICJpegIndexingOptions(pub i32);
 impl windows_core::TypeKind for WICJpegIndexingOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICJpegIndexingOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICJpegIndexingOptions").field(&self.0).finish()
@@ -3105,6 +3134,7 @@ pub struct WICJpegLuminanceProperties(pub i32);
 impl windows_core::TypeKind for WICJpegLuminanceProperties {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICJpegLuminanceProperties {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICJpegLuminanceProperties").field(&self.0).finish()
@@ -3116,6 +3146,7 @@ pub struct WICJpegScanType(pub i32);
 impl windows_core::TypeKind for WICJpegScanType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICJpegScanType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICJpegScanType").field(&self.0).finish()
@@ -3127,6 +3158,7 @@ pub struct WICJpegTransferMatrix(pub i32);
 impl windows_core::TypeKind for WICJpegTransferMatrix {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICJpegTransferMatrix {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICJpegTransferMatrix").field(&self.0).finish()
@@ -3138,6 +3170,7 @@ pub struct WICJpegYCrCbSubsamplingOption(pub i32);
 impl windows_core::TypeKind for WICJpegYCrCbSubsamplingOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICJpegYCrCbSubsamplingOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICJpegYCrCbSubsamplingOption").field(&self.0).finish()
@@ -3149,6 +3182,7 @@ pub struct WICMetadataCreationOptions(pub i32);
 impl windows_core::TypeKind for WICMetadataCreationOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICMetadataCreationOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICMetadataCreationOptions").field(&self.0).finish()
@@ -3160,6 +3194,7 @@ pub struct WICNamedWhitePoint(pub i32);
 impl windows_core::TypeKind for WICNamedWhitePoint {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICNamedWhitePoint {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICNamedWhitePoint").field(&self.0).finish()
@@ -3171,6 +3206,7 @@ pub struct WICPersistOptions(pub i32);
 impl windows_core::TypeKind for WICPersistOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICPersistOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICPersistOptions").field(&self.0).finish()
@@ -3182,6 +3218,7 @@ pub struct WICPixelFormatNumericRepresentation(pub i32);
 impl windows_core::TypeKind for WICPixelFormatNumericRepresentation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICPixelFormatNumericRepresentation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICPixelFormatNumericRepresentation").field(&self.0).finish()
@@ -3193,6 +3230,7 @@ pub struct WICPlanarOptions(pub i32);
 impl windows_core::TypeKind for WICPlanarOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICPlanarOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICPlanarOptions").field(&self.0).finish()
@@ -3204,6 +3242,7 @@ pub struct WICPngBkgdProperties(pub i32);
 impl windows_core::TypeKind for WICPngBkgdProperties {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICPngBkgdProperties {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICPngBkgdProperties").field(&self.0).finish()
@@ -3215,6 +3254,7 @@ pub struct WICPngChrmProperties(pub i32);
 impl windows_core::TypeKind for WICPngChrmProperties {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICPngChrmProperties {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICPngChrmProperties").field(&self.0).finish()
@@ -3226,6 +3266,7 @@ pub struct WICPngFilterOption(pub i32);
 impl windows_core::TypeKind for WICPngFilterOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICPngFilterOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICPngFilterOption").field(&self.0).finish()
@@ -3237,6 +3278,7 @@ pub struct WICPngGamaProperties(pub i32);
 impl windows_core::TypeKind for WICPngGamaProperties {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICPngGamaProperties {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICPngGamaProperties").field(&self.0).finish()
@@ -3248,6 +3290,7 @@ pub struct WICPngHistProperties(pub i32);
 impl windows_core::TypeKind for WICPngHistProperties {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICPngHistProperties {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICPngHistProperties").field(&self.0).finish()
@@ -3259,6 +3302,7 @@ pub struct WICPngIccpProperties(pub i32);
 impl windows_core::TypeKind for WICPngIccpProperties {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICPngIccpProperties {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICPngIccpProperties").field(&self.0).finish()
@@ -3270,6 +3314,7 @@ pub struct WICPngItxtProperties(pub i32);
 impl windows_core::TypeKind for WICPngItxtProperties {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICPngItxtProperties {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICPngItxtProperties").field(&self.0).finish()
@@ -3281,6 +3326,7 @@ pub struct WICPngSrgbProperties(pub i32);
 impl windows_core::TypeKind for WICPngSrgbProperties {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICPngSrgbProperties {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICPngSrgbProperties").field(&self.0).finish()
@@ -3292,6 +3338,7 @@ pub struct WICPngTimeProperties(pub i32);
 impl windows_core::TypeKind for WICPngTimeProperties {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICPngTimeProperties {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICPngTimeProperties").field(&self.0).finish()
@@ -3303,6 +3350,7 @@ pub struct WICProgressNotification(pub i32);
 impl windows_core::TypeKind for WICProgressNotification {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICProgressNotification {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICProgressNotification").field(&self.0).finish()
@@ -3314,6 +3362,7 @@ pub struct WICProgressOperation(pub i32);
 impl windows_core::TypeKind for WICProgressOperation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICProgressOperation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICProgressOperation").field(&self.0).finish()
@@ -3325,6 +3374,7 @@ pub struct WICRawCapabilities(pub i32);
 impl windows_core::TypeKind for WICRawCapabilities {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICRawCapabilities {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICRawCapabilities").field(&self.0).finish()
@@ -3336,6 +3386,7 @@ pub struct WICRawParameterSet(pub i32);
 impl windows_core::TypeKind for WICRawParameterSet {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICRawParameterSet {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICRawParameterSet").field(&self.0).finish()
@@ -3347,6 +3398,7 @@ pub struct WICRawRenderMode(pub i32);
 impl windows_core::TypeKind for WICRawRenderMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICRawRenderMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICRawRenderMode").field(&self.0).finish()
@@ -3358,6 +3410,7 @@ pub struct WICRawRotationCapabilities(pub i32);
 impl windows_core::TypeKind for WICRawRotationCapabilities {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICRawRotationCapabilities {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICRawRotationCapabilities").field(&self.0).finish()
@@ -3369,6 +3422,7 @@ pub struct WICSectionAccessLevel(pub i32);
 impl windows_core::TypeKind for WICSectionAccessLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICSectionAccessLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICSectionAccessLevel").field(&self.0).finish()
@@ -3380,6 +3434,7 @@ pub struct WICTiffCompressionOption(pub i32);
 impl windows_core::TypeKind for WICTiffCompressionOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICTiffCompressionOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICTiffCompressionOption").field(&self.0).finish()
@@ -3391,6 +3446,7 @@ pub struct WICWebpAnimProperties(pub i32);
 impl windows_core::TypeKind for WICWebpAnimProperties {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICWebpAnimProperties {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICWebpAnimProperties").field(&self.0).finish()
@@ -3402,13 +3458,15 @@ pub struct WICWebpAnmfProperties(pub i32);
 impl windows_core::TypeKind for WICWebpAnmfProperties {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WICWebpAnmfProperties {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WICWebpAnmfProperties").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WICBitmapPattern {
     pub Position: u64,
     pub Length: u32,
@@ -3425,7 +3483,8 @@ impl Default for WICBitmapPattern {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WICBitmapPlane {
     pub Format: windows_core::GUID,
     pub pbBuffer: *mut u8,
@@ -3441,7 +3500,8 @@ impl Default for WICBitmapPlane {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WICBitmapPlaneDescription {
     pub Format: windows_core::GUID,
     pub Width: u32,
@@ -3457,7 +3517,8 @@ impl Default for WICBitmapPlaneDescription {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WICDdsFormatInfo {
     pub DxgiFormat: super::Dxgi::Common::DXGI_FORMAT,
     pub BytesPerBlock: u32,
@@ -3476,7 +3537,8 @@ impl Default for WICDdsFormatInfo {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WICDdsParameters {
     pub Width: u32,
     pub Height: u32,
@@ -3499,7 +3561,8 @@ impl Default for WICDdsParameters {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi_Common"))]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WICImageParameters {
     pub PixelFormat: super::Direct2D::Common::D2D1_PIXEL_FORMAT,
     pub DpiX: f32,
@@ -3520,7 +3583,8 @@ impl Default for WICImageParameters {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WICJpegFrameHeader {
     pub Width: u32,
     pub Height: u32,
@@ -3540,7 +3604,8 @@ impl Default for WICJpegFrameHeader {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WICJpegScanHeader {
     pub cComponents: u32,
     pub RestartInterval: u32,
@@ -3560,7 +3625,8 @@ impl Default for WICJpegScanHeader {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WICMetadataHeader {
     pub Position: u64,
     pub Length: u32,
@@ -3576,7 +3642,8 @@ impl Default for WICMetadataHeader {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WICMetadataPattern {
     pub Position: u64,
     pub Length: u32,
@@ -3593,7 +3660,8 @@ impl Default for WICMetadataPattern {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WICRawCapabilitiesInfo {
     pub cbSize: u32,
     pub CodecMajorVersion: u32,
@@ -3623,7 +3691,8 @@ impl Default for WICRawCapabilitiesInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WICRawToneCurve {
     pub cPoints: u32,
     pub aPoints: [WICRawToneCurvePoint; 1],
@@ -3637,7 +3706,8 @@ impl Default for WICRawToneCurve {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WICRawToneCurvePoint {
     pub Input: f64,
     pub Output: f64,
@@ -3651,7 +3721,8 @@ impl Default for WICRawToneCurvePoint {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WICRect {
     pub X: i32,
     pub Y: i32,
@@ -3669,3 +3740,5 @@ impl Default for WICRect {
 pub type PFNProgressNotification = Option<unsafe extern "system" fn(pvdata: *const core::ffi::c_void, uframenum: u32, operation: WICProgressOperation, dblprogress: f64) -> windows_core::HRESULT>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1641 @@
+impl windows_core::imp::ProxyVtable for IWICBitmap_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Lock(this: *mut core::ffi::c_void, prclock: *const WICRect, flags: u32, ppilock: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmap", "Lock", 8usize, |this| ((**(this as *const *const IWICBitmap_Vtbl)).Lock)(this, prclock, flags, ppilock), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPalette(this: *mut core::ffi::c_void, pipalette: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmap", "SetPalette", 9usize, |this| ((**(this as *const *const IWICBitmap_Vtbl)).SetPalette)(this, pipalette), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetResolution(this: *mut core::ffi::c_void, dpix: f64, dpiy: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmap", "SetResolution", 10usize, |this| ((**(this as *const *const IWICBitmap_Vtbl)).SetResolution)(this, dpix, dpiy), |result| Some(*result))
+        }
+        Self { base__: <IWICBitmapSource_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Lock, SetPalette, SetResolution }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICBitmap as windows_core::Interface>::IID || <IWICBitmapSource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICBitmapClipper_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pisource: *mut core::ffi::c_void, prc: *const WICRect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapClipper", "Initialize", 8usize, |this| ((**(this as *const *const IWICBitmapClipper_Vtbl)).Initialize)(this, pisource, prc), |result| Some(*result))
+        }
+        Self { base__: <IWICBitmapSource_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICBitmapClipper as windows_core::Interface>::IID || <IWICBitmapSource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICBitmapCodecInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetContainerFormat(this: *mut core::ffi::c_void, pguidcontainerformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapCodecInfo", "GetContainerFormat", 11usize, |this| ((**(this as *const *const IWICBitmapCodecInfo_Vtbl)).GetContainerFormat)(this, pguidcontainerformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPixelFormats(this: *mut core::ffi::c_void, cformats: u32, pguidpixelformats: *mut windows_core::GUID, pcactual: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapCodecInfo", "GetPixelFormats", 12usize, |this| ((**(this as *const *const IWICBitmapCodecInfo_Vtbl)).GetPixelFormats)(this, cformats, pguidpixelformats, pcactual), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColorManagementVersion(this: *mut core::ffi::c_void, cchcolormanagementversion: u32, wzcolormanagementversion: windows_core::PWSTR, pcchactual: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapCodecInfo", "GetColorManagementVersion", 13usize, |this| ((**(this as *const *const IWICBitmapCodecInfo_Vtbl)).GetColorManagementVersion)(this, cchcolormanagementversion, wzcolormanagementversion, pcchactual), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceManufacturer(this: *mut core::ffi::c_void, cchdevicemanufacturer: u32, wzdevicemanufacturer: windows_core::PWSTR, pcchactual: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapCodecInfo", "GetDeviceManufacturer", 14usize, |this| ((**(this as *const *const IWICBitmapCodecInfo_Vtbl)).GetDeviceManufacturer)(this, cchdevicemanufacturer, wzdevicemanufacturer, pcchactual), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceModels(this: *mut core::ffi::c_void, cchdevicemodels: u32, wzdevicemodels: windows_core::PWSTR, pcchactual: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapCodecInfo", "GetDeviceModels", 15usize, |this| ((**(this as *const *const IWICBitmapCodecInfo_Vtbl)).GetDeviceModels)(this, cchdevicemodels, wzdevicemodels, pcchactual), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMimeTypes(this: *mut core::ffi::c_void, cchmimetypes: u32, wzmimetypes: windows_core::PWSTR, pcchactual: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapCodecInfo", "GetMimeTypes", 16usize, |this| ((**(this as *const *const IWICBitmapCodecInfo_Vtbl)).GetMimeTypes)(this, cchmimetypes, wzmimetypes, pcchactual), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileExtensions(this: *mut core::ffi::c_void, cchfileextensions: u32, wzfileextensions: windows_core::PWSTR, pcchactual: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapCodecInfo", "GetFileExtensions", 17usize, |this| ((**(this as *const *const IWICBitmapCodecInfo_Vtbl)).GetFileExtensions)(this, cchfileextensions, wzfileextensions, pcchactual), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoesSupportAnimation(this: *mut core::ffi::c_void, pfsupportanimation: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapCodecInfo", "DoesSupportAnimation", 18usize, |this| ((**(this as *const *const IWICBitmapCodecInfo_Vtbl)).DoesSupportAnimation)(this, pfsupportanimation), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoesSupportChromakey(this: *mut core::ffi::c_void, pfsupportchromakey: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapCodecInfo", "DoesSupportChromakey", 19usize, |this| ((**(this as *const *const IWICBitmapCodecInfo_Vtbl)).DoesSupportChromakey)(this, pfsupportchromakey), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoesSupportLossless(this: *mut core::ffi::c_void, pfsupportlossless: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapCodecInfo", "DoesSupportLossless", 20usize, |this| ((**(this as *const *const IWICBitmapCodecInfo_Vtbl)).DoesSupportLossless)(this, pfsupportlossless), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoesSupportMultiframe(this: *mut core::ffi::c_void, pfsupportmultiframe: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapCodecInfo", "DoesSupportMultiframe", 21usize, |this| ((**(this as *const *const IWICBitmapCodecInfo_Vtbl)).DoesSupportMultiframe)(this, pfsupportmultiframe), |result| Some(*result))
+        }
+        unsafe extern "system" fn MatchesMimeType(this: *mut core::ffi::c_void, wzmimetype: windows_core::PCWSTR, pfmatches: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapCodecInfo", "MatchesMimeType", 22usize, |this| ((**(this as *const *const IWICBitmapCodecInfo_Vtbl)).MatchesMimeType)(this, wzmimetype, pfmatches), |result| Some(*result))
+        }
+        Self {
+            base__: <IWICComponentInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetContainerFormat,
+            GetPixelFormats,
+            GetColorManagementVersion,
+            GetDeviceManufacturer,
+            GetDeviceModels,
+            GetMimeTypes,
+            GetFileExtensions,
+            DoesSupportAnimation,
+            DoesSupportChromakey,
+            DoesSupportLossless,
+            DoesSupportMultiframe,
+            MatchesMimeType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICBitmapCodecInfo as windows_core::Interface>::IID || <IWICComponentInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICBitmapCodecProgressNotification_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterProgressNotification(this: *mut core::ffi::c_void, pfnprogressnotification: PFNProgressNotification, pvdata: *const core::ffi::c_void, dwprogressflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapCodecProgressNotification", "RegisterProgressNotification", 3usize, |this| ((**(this as *const *const IWICBitmapCodecProgressNotification_Vtbl)).RegisterProgressNotification)(this, pfnprogressnotification, pvdata, dwprogressflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterProgressNotification }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICBitmapCodecProgressNotification as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWICBitmapDecoder_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn QueryCapability(this: *mut core::ffi::c_void, pistream: *mut core::ffi::c_void, pdwcapability: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapDecoder", "QueryCapability", 3usize, |this| ((**(this as *const *const IWICBitmapDecoder_Vtbl)).QueryCapability)(this, pistream, pdwcapability), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pistream: *mut core::ffi::c_void, cacheoptions: WICDecodeOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapDecoder", "Initialize", 4usize, |this| ((**(this as *const *const IWICBitmapDecoder_Vtbl)).Initialize)(this, pistream, cacheoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContainerFormat(this: *mut core::ffi::c_void, pguidcontainerformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapDecoder", "GetContainerFormat", 5usize, |this| ((**(this as *const *const IWICBitmapDecoder_Vtbl)).GetContainerFormat)(this, pguidcontainerformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDecoderInfo(this: *mut core::ffi::c_void, ppidecoderinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapDecoder", "GetDecoderInfo", 6usize, |this| ((**(this as *const *const IWICBitmapDecoder_Vtbl)).GetDecoderInfo)(this, ppidecoderinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyPalette(this: *mut core::ffi::c_void, pipalette: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapDecoder", "CopyPalette", 7usize, |this| ((**(this as *const *const IWICBitmapDecoder_Vtbl)).CopyPalette)(this, pipalette), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMetadataQueryReader(this: *mut core::ffi::c_void, ppimetadataqueryreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapDecoder", "GetMetadataQueryReader", 8usize, |this| ((**(this as *const *const IWICBitmapDecoder_Vtbl)).GetMetadataQueryReader)(this, ppimetadataqueryreader), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreview(this: *mut core::ffi::c_void, ppibitmapsource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapDecoder", "GetPreview", 9usize, |this| ((**(this as *const *const IWICBitmapDecoder_Vtbl)).GetPreview)(this, ppibitmapsource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColorContexts(this: *mut core::ffi::c_void, ccount: u32, ppicolorcontexts: *mut *mut core::ffi::c_void, pcactualcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapDecoder", "GetColorContexts", 10usize, |this| ((**(this as *const *const IWICBitmapDecoder_Vtbl)).GetColorContexts)(this, ccount, ppicolorcontexts, pcactualcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetThumbnail(this: *mut core::ffi::c_void, ppithumbnail: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapDecoder", "GetThumbnail", 11usize, |this| ((**(this as *const *const IWICBitmapDecoder_Vtbl)).GetThumbnail)(this, ppithumbnail), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFrameCount(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapDecoder", "GetFrameCount", 12usize, |this| ((**(this as *const *const IWICBitmapDecoder_Vtbl)).GetFrameCount)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFrame(this: *mut core::ffi::c_void, index: u32, ppibitmapframe: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapDecoder", "GetFrame", 13usize, |this| ((**(this as *const *const IWICBitmapDecoder_Vtbl)).GetFrame)(this, index, ppibitmapframe), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            QueryCapability,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            QueryCapability: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Initialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Initialize: 0,
+            GetContainerFormat,
+            GetDecoderInfo,
+            CopyPalette,
+            GetMetadataQueryReader,
+            GetPreview,
+            GetColorContexts,
+            GetThumbnail,
+            GetFrameCount,
+            GetFrame,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICBitmapDecoder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWICBitmapDecoderInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPatterns(this: *mut core::ffi::c_void, cbsizepatterns: u32, ppatterns: *mut WICBitmapPattern, pcpatterns: *mut u32, pcbpatternsactual: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapDecoderInfo", "GetPatterns", 23usize, |this| ((**(this as *const *const IWICBitmapDecoderInfo_Vtbl)).GetPatterns)(this, cbsizepatterns, ppatterns, pcpatterns, pcbpatternsactual), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn MatchesPattern(this: *mut core::ffi::c_void, pistream: *mut core::ffi::c_void, pfmatches: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapDecoderInfo", "MatchesPattern", 24usize, |this| ((**(this as *const *const IWICBitmapDecoderInfo_Vtbl)).MatchesPattern)(this, pistream, pfmatches), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, ppibitmapdecoder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapDecoderInfo", "CreateInstance", 25usize, |this| ((**(this as *const *const IWICBitmapDecoderInfo_Vtbl)).CreateInstance)(this, ppibitmapdecoder), |result| Some(*result))
+        }
+        Self {
+            base__: <IWICBitmapCodecInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPatterns,
+            #[cfg(feature = "Win32_System_Com")]
+            MatchesPattern,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            MatchesPattern: 0,
+            CreateInstance,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICBitmapDecoderInfo as windows_core::Interface>::IID || <IWICBitmapCodecInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for IWICBitmapEncoder_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pistream: *mut core::ffi::c_void, cacheoption: WICBitmapEncoderCacheOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapEncoder", "Initialize", 3usize, |this| ((**(this as *const *const IWICBitmapEncoder_Vtbl)).Initialize)(this, pistream, cacheoption), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContainerFormat(this: *mut core::ffi::c_void, pguidcontainerformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapEncoder", "GetContainerFormat", 4usize, |this| ((**(this as *const *const IWICBitmapEncoder_Vtbl)).GetContainerFormat)(this, pguidcontainerformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEncoderInfo(this: *mut core::ffi::c_void, ppiencoderinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapEncoder", "GetEncoderInfo", 5usize, |this| ((**(this as *const *const IWICBitmapEncoder_Vtbl)).GetEncoderInfo)(this, ppiencoderinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColorContexts(this: *mut core::ffi::c_void, ccount: u32, ppicolorcontext: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapEncoder", "SetColorContexts", 6usize, |this| ((**(this as *const *const IWICBitmapEncoder_Vtbl)).SetColorContexts)(this, ccount, ppicolorcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPalette(this: *mut core::ffi::c_void, pipalette: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapEncoder", "SetPalette", 7usize, |this| ((**(this as *const *const IWICBitmapEncoder_Vtbl)).SetPalette)(this, pipalette), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetThumbnail(this: *mut core::ffi::c_void, pithumbnail: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapEncoder", "SetThumbnail", 8usize, |this| ((**(this as *const *const IWICBitmapEncoder_Vtbl)).SetThumbnail)(this, pithumbnail), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreview(this: *mut core::ffi::c_void, pipreview: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapEncoder", "SetPreview", 9usize, |this| ((**(this as *const *const IWICBitmapEncoder_Vtbl)).SetPreview)(this, pipreview), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn CreateNewFrame(this: *mut core::ffi::c_void, ppiframeencode: *mut *mut core::ffi::c_void, ppiencoderoptions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapEncoder", "CreateNewFrame", 10usize, |this| ((**(this as *const *const IWICBitmapEncoder_Vtbl)).CreateNewFrame)(this, ppiframeencode, ppiencoderoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapEncoder", "Commit", 11usize, |this| ((**(this as *const *const IWICBitmapEncoder_Vtbl)).Commit)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMetadataQueryWriter(this: *mut core::ffi::c_void, ppimetadataquerywriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapEncoder", "GetMetadataQueryWriter", 12usize, |this| ((**(this as *const *const IWICBitmapEncoder_Vtbl)).GetMetadataQueryWriter)(this, ppimetadataquerywriter), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Initialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Initialize: 0,
+            GetContainerFormat,
+            GetEncoderInfo,
+            SetColorContexts,
+            SetPalette,
+            SetThumbnail,
+            SetPreview,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            CreateNewFrame,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            CreateNewFrame: 0,
+            Commit,
+            GetMetadataQueryWriter,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICBitmapEncoder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICBitmapEncoderInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, ppibitmapencoder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapEncoderInfo", "CreateInstance", 23usize, |this| ((**(this as *const *const IWICBitmapEncoderInfo_Vtbl)).CreateInstance)(this, ppibitmapencoder), |result| Some(*result))
+        }
+        Self { base__: <IWICBitmapCodecInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICBitmapEncoderInfo as windows_core::Interface>::IID || <IWICBitmapCodecInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICBitmapFlipRotator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pisource: *mut core::ffi::c_void, options: WICBitmapTransformOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapFlipRotator", "Initialize", 8usize, |this| ((**(this as *const *const IWICBitmapFlipRotator_Vtbl)).Initialize)(this, pisource, options), |result| Some(*result))
+        }
+        Self { base__: <IWICBitmapSource_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICBitmapFlipRotator as windows_core::Interface>::IID || <IWICBitmapSource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICBitmapFrameDecode_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMetadataQueryReader(this: *mut core::ffi::c_void, ppimetadataqueryreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapFrameDecode", "GetMetadataQueryReader", 8usize, |this| ((**(this as *const *const IWICBitmapFrameDecode_Vtbl)).GetMetadataQueryReader)(this, ppimetadataqueryreader), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColorContexts(this: *mut core::ffi::c_void, ccount: u32, ppicolorcontexts: *mut *mut core::ffi::c_void, pcactualcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapFrameDecode", "GetColorContexts", 9usize, |this| ((**(this as *const *const IWICBitmapFrameDecode_Vtbl)).GetColorContexts)(this, ccount, ppicolorcontexts, pcactualcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetThumbnail(this: *mut core::ffi::c_void, ppithumbnail: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapFrameDecode", "GetThumbnail", 10usize, |this| ((**(this as *const *const IWICBitmapFrameDecode_Vtbl)).GetThumbnail)(this, ppithumbnail), |result| Some(*result))
+        }
+        Self { base__: <IWICBitmapSource_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMetadataQueryReader, GetColorContexts, GetThumbnail }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICBitmapFrameDecode as windows_core::Interface>::IID || <IWICBitmapSource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for IWICBitmapFrameEncode_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, piencoderoptions: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapFrameEncode", "Initialize", 3usize, |this| ((**(this as *const *const IWICBitmapFrameEncode_Vtbl)).Initialize)(this, piencoderoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSize(this: *mut core::ffi::c_void, uiwidth: u32, uiheight: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapFrameEncode", "SetSize", 4usize, |this| ((**(this as *const *const IWICBitmapFrameEncode_Vtbl)).SetSize)(this, uiwidth, uiheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetResolution(this: *mut core::ffi::c_void, dpix: f64, dpiy: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapFrameEncode", "SetResolution", 5usize, |this| ((**(this as *const *const IWICBitmapFrameEncode_Vtbl)).SetResolution)(this, dpix, dpiy), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPixelFormat(this: *mut core::ffi::c_void, ppixelformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapFrameEncode", "SetPixelFormat", 6usize, |this| ((**(this as *const *const IWICBitmapFrameEncode_Vtbl)).SetPixelFormat)(this, ppixelformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColorContexts(this: *mut core::ffi::c_void, ccount: u32, ppicolorcontext: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapFrameEncode", "SetColorContexts", 7usize, |this| ((**(this as *const *const IWICBitmapFrameEncode_Vtbl)).SetColorContexts)(this, ccount, ppicolorcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPalette(this: *mut core::ffi::c_void, pipalette: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapFrameEncode", "SetPalette", 8usize, |this| ((**(this as *const *const IWICBitmapFrameEncode_Vtbl)).SetPalette)(this, pipalette), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetThumbnail(this: *mut core::ffi::c_void, pithumbnail: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapFrameEncode", "SetThumbnail", 9usize, |this| ((**(this as *const *const IWICBitmapFrameEncode_Vtbl)).SetThumbnail)(this, pithumbnail), |result| Some(*result))
+        }
+        unsafe extern "system" fn WritePixels(this: *mut core::ffi::c_void, linecount: u32, cbstride: u32, cbbuffersize: u32, pbpixels: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapFrameEncode", "WritePixels", 10usize, |this| ((**(this as *const *const IWICBitmapFrameEncode_Vtbl)).WritePixels)(this, linecount, cbstride, cbbuffersize, pbpixels), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteSource(this: *mut core::ffi::c_void, pibitmapsource: *mut core::ffi::c_void, prc: *const WICRect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapFrameEncode", "WriteSource", 11usize, |this| ((**(this as *const *const IWICBitmapFrameEncode_Vtbl)).WriteSource)(this, pibitmapsource, prc), |result| Some(*result))
+        }
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapFrameEncode", "Commit", 12usize, |this| ((**(this as *const *const IWICBitmapFrameEncode_Vtbl)).Commit)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMetadataQueryWriter(this: *mut core::ffi::c_void, ppimetadataquerywriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapFrameEncode", "GetMetadataQueryWriter", 13usize, |this| ((**(this as *const *const IWICBitmapFrameEncode_Vtbl)).GetMetadataQueryWriter)(this, ppimetadataquerywriter), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            Initialize,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            Initialize: 0,
+            SetSize,
+            SetResolution,
+            SetPixelFormat,
+            SetColorContexts,
+            SetPalette,
+            SetThumbnail,
+            WritePixels,
+            WriteSource,
+            Commit,
+            GetMetadataQueryWriter,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICBitmapFrameEncode as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICBitmapLock_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSize(this: *mut core::ffi::c_void, puiwidth: *mut u32, puiheight: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapLock", "GetSize", 3usize, |this| ((**(this as *const *const IWICBitmapLock_Vtbl)).GetSize)(this, puiwidth, puiheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStride(this: *mut core::ffi::c_void, pcbstride: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapLock", "GetStride", 4usize, |this| ((**(this as *const *const IWICBitmapLock_Vtbl)).GetStride)(this, pcbstride), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataPointer(this: *mut core::ffi::c_void, pcbbuffersize: *mut u32, ppbdata: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapLock", "GetDataPointer", 5usize, |this| ((**(this as *const *const IWICBitmapLock_Vtbl)).GetDataPointer)(this, pcbbuffersize, ppbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPixelFormat(this: *mut core::ffi::c_void, ppixelformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapLock", "GetPixelFormat", 6usize, |this| ((**(this as *const *const IWICBitmapLock_Vtbl)).GetPixelFormat)(this, ppixelformat), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSize, GetStride, GetDataPointer, GetPixelFormat }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICBitmapLock as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICBitmapScaler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pisource: *mut core::ffi::c_void, uiwidth: u32, uiheight: u32, mode: WICBitmapInterpolationMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapScaler", "Initialize", 8usize, |this| ((**(this as *const *const IWICBitmapScaler_Vtbl)).Initialize)(this, pisource, uiwidth, uiheight, mode), |result| Some(*result))
+        }
+        Self { base__: <IWICBitmapSource_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICBitmapScaler as windows_core::Interface>::IID || <IWICBitmapSource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICBitmapSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSize(this: *mut core::ffi::c_void, puiwidth: *mut u32, puiheight: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapSource", "GetSize", 3usize, |this| ((**(this as *const *const IWICBitmapSource_Vtbl)).GetSize)(this, puiwidth, puiheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPixelFormat(this: *mut core::ffi::c_void, ppixelformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapSource", "GetPixelFormat", 4usize, |this| ((**(this as *const *const IWICBitmapSource_Vtbl)).GetPixelFormat)(this, ppixelformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResolution(this: *mut core::ffi::c_void, pdpix: *mut f64, pdpiy: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapSource", "GetResolution", 5usize, |this| ((**(this as *const *const IWICBitmapSource_Vtbl)).GetResolution)(this, pdpix, pdpiy), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyPalette(this: *mut core::ffi::c_void, pipalette: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapSource", "CopyPalette", 6usize, |this| ((**(this as *const *const IWICBitmapSource_Vtbl)).CopyPalette)(this, pipalette), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyPixels(this: *mut core::ffi::c_void, prc: *const WICRect, cbstride: u32, cbbuffersize: u32, pbbuffer: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapSource", "CopyPixels", 7usize, |this| ((**(this as *const *const IWICBitmapSource_Vtbl)).CopyPixels)(this, prc, cbstride, cbbuffersize, pbbuffer), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSize, GetPixelFormat, GetResolution, CopyPalette, CopyPixels }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICBitmapSource as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICBitmapSourceTransform_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CopyPixels(this: *mut core::ffi::c_void, prc: *const WICRect, uiwidth: u32, uiheight: u32, pguiddstformat: *const windows_core::GUID, dsttransform: WICBitmapTransformOptions, nstride: u32, cbbuffersize: u32, pbbuffer: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapSourceTransform", "CopyPixels", 3usize, |this| ((**(this as *const *const IWICBitmapSourceTransform_Vtbl)).CopyPixels)(this, prc, uiwidth, uiheight, pguiddstformat, dsttransform, nstride, cbbuffersize, pbbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClosestSize(this: *mut core::ffi::c_void, puiwidth: *mut u32, puiheight: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapSourceTransform", "GetClosestSize", 4usize, |this| ((**(this as *const *const IWICBitmapSourceTransform_Vtbl)).GetClosestSize)(this, puiwidth, puiheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClosestPixelFormat(this: *mut core::ffi::c_void, pguiddstformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapSourceTransform", "GetClosestPixelFormat", 5usize, |this| ((**(this as *const *const IWICBitmapSourceTransform_Vtbl)).GetClosestPixelFormat)(this, pguiddstformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoesSupportTransform(this: *mut core::ffi::c_void, dsttransform: WICBitmapTransformOptions, pfissupported: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICBitmapSourceTransform", "DoesSupportTransform", 6usize, |this| ((**(this as *const *const IWICBitmapSourceTransform_Vtbl)).DoesSupportTransform)(this, dsttransform, pfissupported), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CopyPixels,
+            GetClosestSize,
+            GetClosestPixelFormat,
+            DoesSupportTransform,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICBitmapSourceTransform as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICColorContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeFromFilename(this: *mut core::ffi::c_void, wzfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICColorContext", "InitializeFromFilename", 3usize, |this| ((**(this as *const *const IWICColorContext_Vtbl)).InitializeFromFilename)(this, wzfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeFromMemory(this: *mut core::ffi::c_void, pbbuffer: *const u8, cbbuffersize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICColorContext", "InitializeFromMemory", 4usize, |this| ((**(this as *const *const IWICColorContext_Vtbl)).InitializeFromMemory)(this, pbbuffer, cbbuffersize), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeFromExifColorSpace(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICColorContext", "InitializeFromExifColorSpace", 5usize, |this| ((**(this as *const *const IWICColorContext_Vtbl)).InitializeFromExifColorSpace)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, ptype: *mut WICColorContextType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICColorContext", "GetType", 6usize, |this| ((**(this as *const *const IWICColorContext_Vtbl)).GetType)(this, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProfileBytes(this: *mut core::ffi::c_void, cbbuffer: u32, pbbuffer: *mut u8, pcbactual: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICColorContext", "GetProfileBytes", 7usize, |this| ((**(this as *const *const IWICColorContext_Vtbl)).GetProfileBytes)(this, cbbuffer, pbbuffer, pcbactual), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExifColorSpace(this: *mut core::ffi::c_void, pvalue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICColorContext", "GetExifColorSpace", 8usize, |this| ((**(this as *const *const IWICColorContext_Vtbl)).GetExifColorSpace)(this, pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitializeFromFilename,
+            InitializeFromMemory,
+            InitializeFromExifColorSpace,
+            GetType,
+            GetProfileBytes,
+            GetExifColorSpace,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICColorContext as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICColorTransform_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pibitmapsource: *mut core::ffi::c_void, picontextsource: *mut core::ffi::c_void, picontextdest: *mut core::ffi::c_void, pixelfmtdest: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICColorTransform", "Initialize", 8usize, |this| ((**(this as *const *const IWICColorTransform_Vtbl)).Initialize)(this, pibitmapsource, picontextsource, picontextdest, pixelfmtdest), |result| Some(*result))
+        }
+        Self { base__: <IWICBitmapSource_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICColorTransform as windows_core::Interface>::IID || <IWICBitmapSource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant", feature = "Win32_UI_WindowsAndMessaging"))]
+impl windows_core::imp::ProxyVtable for IWICComponentFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateMetadataReader(this: *mut core::ffi::c_void, guidmetadataformat: *const windows_core::GUID, pguidvendor: *const windows_core::GUID, dwoptions: u32, pistream: *mut core::ffi::c_void, ppireader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICComponentFactory", "CreateMetadataReader", 28usize, |this| ((**(this as *const *const IWICComponentFactory_Vtbl)).CreateMetadataReader)(this, guidmetadataformat, pguidvendor, dwoptions, pistream, ppireader), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateMetadataReaderFromContainer(this: *mut core::ffi::c_void, guidcontainerformat: *const windows_core::GUID, pguidvendor: *const windows_core::GUID, dwoptions: u32, pistream: *mut core::ffi::c_void, ppireader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICComponentFactory", "CreateMetadataReaderFromContainer", 29usize, |this| ((**(this as *const *const IWICComponentFactory_Vtbl)).CreateMetadataReaderFromContainer)(this, guidcontainerformat, pguidvendor, dwoptions, pistream, ppireader), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateMetadataWriter(this: *mut core::ffi::c_void, guidmetadataformat: *const windows_core::GUID, pguidvendor: *const windows_core::GUID, dwmetadataoptions: u32, ppiwriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICComponentFactory", "CreateMetadataWriter", 30usize, |this| ((**(this as *const *const IWICComponentFactory_Vtbl)).CreateMetadataWriter)(this, guidmetadataformat, pguidvendor, dwmetadataoptions, ppiwriter), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateMetadataWriterFromReader(this: *mut core::ffi::c_void, pireader: *mut core::ffi::c_void, pguidvendor: *const windows_core::GUID, ppiwriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICComponentFactory", "CreateMetadataWriterFromReader", 31usize, |this| ((**(this as *const *const IWICComponentFactory_Vtbl)).CreateMetadataWriterFromReader)(this, pireader, pguidvendor, ppiwriter), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateQueryReaderFromBlockReader(this: *mut core::ffi::c_void, piblockreader: *mut core::ffi::c_void, ppiqueryreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICComponentFactory", "CreateQueryReaderFromBlockReader", 32usize, |this| ((**(this as *const *const IWICComponentFactory_Vtbl)).CreateQueryReaderFromBlockReader)(this, piblockreader, ppiqueryreader), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateQueryWriterFromBlockWriter(this: *mut core::ffi::c_void, piblockwriter: *mut core::ffi::c_void, ppiquerywriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICComponentFactory", "CreateQueryWriterFromBlockWriter", 33usize, |this| ((**(this as *const *const IWICComponentFactory_Vtbl)).CreateQueryWriterFromBlockWriter)(this, piblockwriter, ppiquerywriter), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
+        unsafe extern "system" fn CreateEncoderPropertyBag(this: *mut core::ffi::c_void, ppropoptions: *const super::super::System::Com::StructuredStorage::PROPBAG2, ccount: u32, ppipropertybag: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICComponentFactory", "CreateEncoderPropertyBag", 34usize, |this| ((**(this as *const *const IWICComponentFactory_Vtbl)).CreateEncoderPropertyBag)(this, ppropoptions, ccount, ppipropertybag), |result| Some(*result))
+        }
+        Self {
+            base__: <IWICImagingFactory_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateMetadataReader,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateMetadataReader: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateMetadataReaderFromContainer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateMetadataReaderFromContainer: 0,
+            CreateMetadataWriter,
+            CreateMetadataWriterFromReader,
+            CreateQueryReaderFromBlockReader,
+            CreateQueryWriterFromBlockWriter,
+            #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant"))]
+            CreateEncoderPropertyBag,
+            #[cfg(not(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Variant")))]
+            CreateEncoderPropertyBag: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICComponentFactory as windows_core::Interface>::IID || <IWICImagingFactory_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICComponentInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetComponentType(this: *mut core::ffi::c_void, ptype: *mut WICComponentType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICComponentInfo", "GetComponentType", 3usize, |this| ((**(this as *const *const IWICComponentInfo_Vtbl)).GetComponentType)(this, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCLSID(this: *mut core::ffi::c_void, pclsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICComponentInfo", "GetCLSID", 4usize, |this| ((**(this as *const *const IWICComponentInfo_Vtbl)).GetCLSID)(this, pclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSigningStatus(this: *mut core::ffi::c_void, pstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICComponentInfo", "GetSigningStatus", 5usize, |this| ((**(this as *const *const IWICComponentInfo_Vtbl)).GetSigningStatus)(this, pstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAuthor(this: *mut core::ffi::c_void, cchauthor: u32, wzauthor: windows_core::PWSTR, pcchactual: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICComponentInfo", "GetAuthor", 6usize, |this| ((**(this as *const *const IWICComponentInfo_Vtbl)).GetAuthor)(this, cchauthor, wzauthor, pcchactual), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVendorGUID(this: *mut core::ffi::c_void, pguidvendor: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICComponentInfo", "GetVendorGUID", 7usize, |this| ((**(this as *const *const IWICComponentInfo_Vtbl)).GetVendorGUID)(this, pguidvendor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersion(this: *mut core::ffi::c_void, cchversion: u32, wzversion: windows_core::PWSTR, pcchactual: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICComponentInfo", "GetVersion", 8usize, |this| ((**(this as *const *const IWICComponentInfo_Vtbl)).GetVersion)(this, cchversion, wzversion, pcchactual), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSpecVersion(this: *mut core::ffi::c_void, cchspecversion: u32, wzspecversion: windows_core::PWSTR, pcchactual: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICComponentInfo", "GetSpecVersion", 9usize, |this| ((**(this as *const *const IWICComponentInfo_Vtbl)).GetSpecVersion)(this, cchspecversion, wzspecversion, pcchactual), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFriendlyName(this: *mut core::ffi::c_void, cchfriendlyname: u32, wzfriendlyname: windows_core::PWSTR, pcchactual: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICComponentInfo", "GetFriendlyName", 10usize, |this| ((**(this as *const *const IWICComponentInfo_Vtbl)).GetFriendlyName)(this, cchfriendlyname, wzfriendlyname, pcchactual), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetComponentType,
+            GetCLSID,
+            GetSigningStatus,
+            GetAuthor,
+            GetVendorGUID,
+            GetVersion,
+            GetSpecVersion,
+            GetFriendlyName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICComponentInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IWICDdsDecoder_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetParameters(this: *mut core::ffi::c_void, pparameters: *mut WICDdsParameters) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDdsDecoder", "GetParameters", 3usize, |this| ((**(this as *const *const IWICDdsDecoder_Vtbl)).GetParameters)(this, pparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFrame(this: *mut core::ffi::c_void, arrayindex: u32, miplevel: u32, sliceindex: u32, ppibitmapframe: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDdsDecoder", "GetFrame", 4usize, |this| ((**(this as *const *const IWICDdsDecoder_Vtbl)).GetFrame)(this, arrayindex, miplevel, sliceindex, ppibitmapframe), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetParameters,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetParameters: 0,
+            GetFrame,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICDdsDecoder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IWICDdsEncoder_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn SetParameters(this: *mut core::ffi::c_void, pparameters: *const WICDdsParameters) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDdsEncoder", "SetParameters", 3usize, |this| ((**(this as *const *const IWICDdsEncoder_Vtbl)).SetParameters)(this, pparameters), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetParameters(this: *mut core::ffi::c_void, pparameters: *mut WICDdsParameters) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDdsEncoder", "GetParameters", 4usize, |this| ((**(this as *const *const IWICDdsEncoder_Vtbl)).GetParameters)(this, pparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateNewFrame(this: *mut core::ffi::c_void, ppiframeencode: *mut *mut core::ffi::c_void, parrayindex: *mut u32, pmiplevel: *mut u32, psliceindex: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDdsEncoder", "CreateNewFrame", 5usize, |this| ((**(this as *const *const IWICDdsEncoder_Vtbl)).CreateNewFrame)(this, ppiframeencode, parrayindex, pmiplevel, psliceindex), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            SetParameters,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            SetParameters: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetParameters,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetParameters: 0,
+            CreateNewFrame,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICDdsEncoder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IWICDdsFrameDecode_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSizeInBlocks(this: *mut core::ffi::c_void, pwidthinblocks: *mut u32, pheightinblocks: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDdsFrameDecode", "GetSizeInBlocks", 3usize, |this| ((**(this as *const *const IWICDdsFrameDecode_Vtbl)).GetSizeInBlocks)(this, pwidthinblocks, pheightinblocks), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetFormatInfo(this: *mut core::ffi::c_void, pformatinfo: *mut WICDdsFormatInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDdsFrameDecode", "GetFormatInfo", 4usize, |this| ((**(this as *const *const IWICDdsFrameDecode_Vtbl)).GetFormatInfo)(this, pformatinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyBlocks(this: *mut core::ffi::c_void, prcboundsinblocks: *const WICRect, cbstride: u32, cbbuffersize: u32, pbbuffer: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDdsFrameDecode", "CopyBlocks", 5usize, |this| ((**(this as *const *const IWICDdsFrameDecode_Vtbl)).CopyBlocks)(this, prcboundsinblocks, cbstride, cbbuffersize, pbbuffer), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSizeInBlocks,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetFormatInfo,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetFormatInfo: 0,
+            CopyBlocks,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICDdsFrameDecode as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for IWICDevelopRaw_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryRawCapabilitiesInfo(this: *mut core::ffi::c_void, pinfo: *mut WICRawCapabilitiesInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "QueryRawCapabilitiesInfo", 11usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).QueryRawCapabilitiesInfo)(this, pinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadParameterSet(this: *mut core::ffi::c_void, parameterset: WICRawParameterSet) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "LoadParameterSet", 12usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).LoadParameterSet)(this, parameterset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn GetCurrentParameterSet(this: *mut core::ffi::c_void, ppcurrentparameterset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "GetCurrentParameterSet", 13usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).GetCurrentParameterSet)(this, ppcurrentparameterset), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExposureCompensation(this: *mut core::ffi::c_void, ev: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "SetExposureCompensation", 14usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).SetExposureCompensation)(this, ev), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExposureCompensation(this: *mut core::ffi::c_void, pev: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "GetExposureCompensation", 15usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).GetExposureCompensation)(this, pev), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWhitePointRGB(this: *mut core::ffi::c_void, red: u32, green: u32, blue: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "SetWhitePointRGB", 16usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).SetWhitePointRGB)(this, red, green, blue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWhitePointRGB(this: *mut core::ffi::c_void, pred: *mut u32, pgreen: *mut u32, pblue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "GetWhitePointRGB", 17usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).GetWhitePointRGB)(this, pred, pgreen, pblue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNamedWhitePoint(this: *mut core::ffi::c_void, whitepoint: WICNamedWhitePoint) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "SetNamedWhitePoint", 18usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).SetNamedWhitePoint)(this, whitepoint), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNamedWhitePoint(this: *mut core::ffi::c_void, pwhitepoint: *mut WICNamedWhitePoint) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "GetNamedWhitePoint", 19usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).GetNamedWhitePoint)(this, pwhitepoint), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWhitePointKelvin(this: *mut core::ffi::c_void, whitepointkelvin: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "SetWhitePointKelvin", 20usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).SetWhitePointKelvin)(this, whitepointkelvin), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWhitePointKelvin(this: *mut core::ffi::c_void, pwhitepointkelvin: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "GetWhitePointKelvin", 21usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).GetWhitePointKelvin)(this, pwhitepointkelvin), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKelvinRangeInfo(this: *mut core::ffi::c_void, pminkelvintemp: *mut u32, pmaxkelvintemp: *mut u32, pkelvintempstepvalue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "GetKelvinRangeInfo", 22usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).GetKelvinRangeInfo)(this, pminkelvintemp, pmaxkelvintemp, pkelvintempstepvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContrast(this: *mut core::ffi::c_void, contrast: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "SetContrast", 23usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).SetContrast)(this, contrast), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContrast(this: *mut core::ffi::c_void, pcontrast: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "GetContrast", 24usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).GetContrast)(this, pcontrast), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGamma(this: *mut core::ffi::c_void, gamma: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "SetGamma", 25usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).SetGamma)(this, gamma), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGamma(this: *mut core::ffi::c_void, pgamma: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "GetGamma", 26usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).GetGamma)(this, pgamma), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSharpness(this: *mut core::ffi::c_void, sharpness: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "SetSharpness", 27usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).SetSharpness)(this, sharpness), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSharpness(this: *mut core::ffi::c_void, psharpness: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "GetSharpness", 28usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).GetSharpness)(this, psharpness), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSaturation(this: *mut core::ffi::c_void, saturation: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "SetSaturation", 29usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).SetSaturation)(this, saturation), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSaturation(this: *mut core::ffi::c_void, psaturation: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "GetSaturation", 30usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).GetSaturation)(this, psaturation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTint(this: *mut core::ffi::c_void, tint: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "SetTint", 31usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).SetTint)(this, tint), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTint(this: *mut core::ffi::c_void, ptint: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "GetTint", 32usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).GetTint)(this, ptint), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNoiseReduction(this: *mut core::ffi::c_void, noisereduction: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "SetNoiseReduction", 33usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).SetNoiseReduction)(this, noisereduction), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNoiseReduction(this: *mut core::ffi::c_void, pnoisereduction: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "GetNoiseReduction", 34usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).GetNoiseReduction)(this, pnoisereduction), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDestinationColorContext(this: *mut core::ffi::c_void, pcolorcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "SetDestinationColorContext", 35usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).SetDestinationColorContext)(this, pcolorcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetToneCurve(this: *mut core::ffi::c_void, cbtonecurvesize: u32, ptonecurve: *const WICRawToneCurve) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "SetToneCurve", 36usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).SetToneCurve)(this, cbtonecurvesize, ptonecurve), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetToneCurve(this: *mut core::ffi::c_void, cbtonecurvebuffersize: u32, ptonecurve: *mut WICRawToneCurve, pcbactualtonecurvebuffersize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "GetToneCurve", 37usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).GetToneCurve)(this, cbtonecurvebuffersize, ptonecurve, pcbactualtonecurvebuffersize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRotation(this: *mut core::ffi::c_void, rotation: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "SetRotation", 38usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).SetRotation)(this, rotation), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRotation(this: *mut core::ffi::c_void, protation: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "GetRotation", 39usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).GetRotation)(this, protation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRenderMode(this: *mut core::ffi::c_void, rendermode: WICRawRenderMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "SetRenderMode", 40usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).SetRenderMode)(this, rendermode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRenderMode(this: *mut core::ffi::c_void, prendermode: *mut WICRawRenderMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "GetRenderMode", 41usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).GetRenderMode)(this, prendermode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNotificationCallback(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRaw", "SetNotificationCallback", 42usize, |this| ((**(this as *const *const IWICDevelopRaw_Vtbl)).SetNotificationCallback)(this, pcallback), |result| Some(*result))
+        }
+        Self {
+            base__: <IWICBitmapFrameDecode_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            QueryRawCapabilitiesInfo,
+            LoadParameterSet,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            GetCurrentParameterSet,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            GetCurrentParameterSet: 0,
+            SetExposureCompensation,
+            GetExposureCompensation,
+            SetWhitePointRGB,
+            GetWhitePointRGB,
+            SetNamedWhitePoint,
+            GetNamedWhitePoint,
+            SetWhitePointKelvin,
+            GetWhitePointKelvin,
+            GetKelvinRangeInfo,
+            SetContrast,
+            GetContrast,
+            SetGamma,
+            GetGamma,
+            SetSharpness,
+            GetSharpness,
+            SetSaturation,
+            GetSaturation,
+            SetTint,
+            GetTint,
+            SetNoiseReduction,
+            GetNoiseReduction,
+            SetDestinationColorContext,
+            SetToneCurve,
+            GetToneCurve,
+            SetRotation,
+            GetRotation,
+            SetRenderMode,
+            GetRenderMode,
+            SetNotificationCallback,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICDevelopRaw as windows_core::Interface>::IID || <IWICBitmapFrameDecode_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICDevelopRawNotificationCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Notify(this: *mut core::ffi::c_void, notificationmask: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICDevelopRawNotificationCallback", "Notify", 3usize, |this| ((**(this as *const *const IWICDevelopRawNotificationCallback_Vtbl)).Notify)(this, notificationmask), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Notify }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICDevelopRawNotificationCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICEnumMetadataItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgeltschema: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>, rgeltid: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>, rgeltvalue: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICEnumMetadataItem", "Next", 3usize, |this| ((**(this as *const *const IWICEnumMetadataItem_Vtbl)).Next)(this, celt, rgeltschema, rgeltid, rgeltvalue, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICEnumMetadataItem", "Skip", 4usize, |this| ((**(this as *const *const IWICEnumMetadataItem_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICEnumMetadataItem", "Reset", 5usize, |this| ((**(this as *const *const IWICEnumMetadataItem_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppienummetadataitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICEnumMetadataItem", "Clone", 6usize, |this| ((**(this as *const *const IWICEnumMetadataItem_Vtbl)).Clone)(this, ppienummetadataitem), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICEnumMetadataItem as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICFastMetadataEncoder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICFastMetadataEncoder", "Commit", 3usize, |this| ((**(this as *const *const IWICFastMetadataEncoder_Vtbl)).Commit)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMetadataQueryWriter(this: *mut core::ffi::c_void, ppimetadataquerywriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICFastMetadataEncoder", "GetMetadataQueryWriter", 4usize, |this| ((**(this as *const *const IWICFastMetadataEncoder_Vtbl)).GetMetadataQueryWriter)(this, ppimetadataquerywriter), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Commit, GetMetadataQueryWriter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICFastMetadataEncoder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICFormatConverter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pisource: *mut core::ffi::c_void, dstformat: *const windows_core::GUID, dither: WICBitmapDitherType, pipalette: *mut core::ffi::c_void, alphathresholdpercent: f64, palettetranslate: WICBitmapPaletteType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICFormatConverter", "Initialize", 8usize, |this| ((**(this as *const *const IWICFormatConverter_Vtbl)).Initialize)(this, pisource, dstformat, dither, pipalette, alphathresholdpercent, palettetranslate), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanConvert(this: *mut core::ffi::c_void, srcpixelformat: *const windows_core::GUID, dstpixelformat: *const windows_core::GUID, pfcanconvert: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICFormatConverter", "CanConvert", 9usize, |this| ((**(this as *const *const IWICFormatConverter_Vtbl)).CanConvert)(this, srcpixelformat, dstpixelformat, pfcanconvert), |result| Some(*result))
+        }
+        Self { base__: <IWICBitmapSource_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, CanConvert }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICFormatConverter as windows_core::Interface>::IID || <IWICBitmapSource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICFormatConverterInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPixelFormats(this: *mut core::ffi::c_void, cformats: u32, ppixelformatguids: *mut windows_core::GUID, pcactual: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICFormatConverterInfo", "GetPixelFormats", 11usize, |this| ((**(this as *const *const IWICFormatConverterInfo_Vtbl)).GetPixelFormats)(this, cformats, ppixelformatguids, pcactual), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, ppiconverter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICFormatConverterInfo", "CreateInstance", 12usize, |this| ((**(this as *const *const IWICFormatConverterInfo_Vtbl)).CreateInstance)(this, ppiconverter), |result| Some(*result))
+        }
+        Self { base__: <IWICComponentInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPixelFormats, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICFormatConverterInfo as windows_core::Interface>::IID || <IWICComponentInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com", feature = "Win32_UI_WindowsAndMessaging"))]
+impl windows_core::imp::ProxyVtable for IWICImagingFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateDecoderFromFilename(this: *mut core::ffi::c_void, wzfilename: windows_core::PCWSTR, pguidvendor: *const windows_core::GUID, dwdesiredaccess: super::super::Foundation::GENERIC_ACCESS_RIGHTS, metadataoptions: WICDecodeOptions, ppidecoder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImagingFactory", "CreateDecoderFromFilename", 3usize, |this| ((**(this as *const *const IWICImagingFactory_Vtbl)).CreateDecoderFromFilename)(this, wzfilename, pguidvendor, dwdesiredaccess, metadataoptions, ppidecoder), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateDecoderFromStream(this: *mut core::ffi::c_void, pistream: *mut core::ffi::c_void, pguidvendor: *const windows_core::GUID, metadataoptions: WICDecodeOptions, ppidecoder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImagingFactory", "CreateDecoderFromStream", 4usize, |this| ((**(this as *const *const IWICImagingFactory_Vtbl)).CreateDecoderFromStream)(this, pistream, pguidvendor, metadataoptions, ppidecoder), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDecoderFromFileHandle(this: *mut core::ffi::c_void, hfile: usize, pguidvendor: *const windows_core::GUID, metadataoptions: WICDecodeOptions, ppidecoder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImagingFactory", "CreateDecoderFromFileHandle", 5usize, |this| ((**(this as *const *const IWICImagingFactory_Vtbl)).CreateDecoderFromFileHandle)(this, hfile, pguidvendor, metadataoptions, ppidecoder), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateComponentInfo(this: *mut core::ffi::c_void, clsidcomponent: *const windows_core::GUID, ppiinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImagingFactory", "CreateComponentInfo", 6usize, |this| ((**(this as *const *const IWICImagingFactory_Vtbl)).CreateComponentInfo)(this, clsidcomponent, ppiinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDecoder(this: *mut core::ffi::c_void, guidcontainerformat: *const windows_core::GUID, pguidvendor: *const windows_core::GUID, ppidecoder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImagingFactory", "CreateDecoder", 7usize, |this| ((**(this as *const *const IWICImagingFactory_Vtbl)).CreateDecoder)(this, guidcontainerformat, pguidvendor, ppidecoder), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateEncoder(this: *mut core::ffi::c_void, guidcontainerformat: *const windows_core::GUID, pguidvendor: *const windows_core::GUID, ppiencoder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImagingFactory", "CreateEncoder", 8usize, |this| ((**(this as *const *const IWICImagingFactory_Vtbl)).CreateEncoder)(this, guidcontainerformat, pguidvendor, ppiencoder), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePalette(this: *mut core::ffi::c_void, ppipalette: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImagingFactory", "CreatePalette", 9usize, |this| ((**(this as *const *const IWICImagingFactory_Vtbl)).CreatePalette)(this, ppipalette), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFormatConverter(this: *mut core::ffi::c_void, ppiformatconverter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImagingFactory", "CreateFormatConverter", 10usize, |this| ((**(this as *const *const IWICImagingFactory_Vtbl)).CreateFormatConverter)(this, ppiformatconverter), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBitmapScaler(this: *mut core::ffi::c_void, ppibitmapscaler: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImagingFactory", "CreateBitmapScaler", 11usize, |this| ((**(this as *const *const IWICImagingFactory_Vtbl)).CreateBitmapScaler)(this, ppibitmapscaler), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBitmapClipper(this: *mut core::ffi::c_void, ppibitmapclipper: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImagingFactory", "CreateBitmapClipper", 12usize, |this| ((**(this as *const *const IWICImagingFactory_Vtbl)).CreateBitmapClipper)(this, ppibitmapclipper), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBitmapFlipRotator(this: *mut core::ffi::c_void, ppibitmapfliprotator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImagingFactory", "CreateBitmapFlipRotator", 13usize, |this| ((**(this as *const *const IWICImagingFactory_Vtbl)).CreateBitmapFlipRotator)(this, ppibitmapfliprotator), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateStream(this: *mut core::ffi::c_void, ppiwicstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImagingFactory", "CreateStream", 14usize, |this| ((**(this as *const *const IWICImagingFactory_Vtbl)).CreateStream)(this, ppiwicstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateColorContext(this: *mut core::ffi::c_void, ppiwiccolorcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImagingFactory", "CreateColorContext", 15usize, |this| ((**(this as *const *const IWICImagingFactory_Vtbl)).CreateColorContext)(this, ppiwiccolorcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateColorTransformer(this: *mut core::ffi::c_void, ppiwiccolortransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImagingFactory", "CreateColorTransformer", 16usize, |this| ((**(this as *const *const IWICImagingFactory_Vtbl)).CreateColorTransformer)(this, ppiwiccolortransform), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBitmap(this: *mut core::ffi::c_void, uiwidth: u32, uiheight: u32, pixelformat: *const windows_core::GUID, option: WICBitmapCreateCacheOption, ppibitmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImagingFactory", "CreateBitmap", 17usize, |this| ((**(this as *const *const IWICImagingFactory_Vtbl)).CreateBitmap)(this, uiwidth, uiheight, pixelformat, option, ppibitmap), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBitmapFromSource(this: *mut core::ffi::c_void, pibitmapsource: *mut core::ffi::c_void, option: WICBitmapCreateCacheOption, ppibitmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImagingFactory", "CreateBitmapFromSource", 18usize, |this| ((**(this as *const *const IWICImagingFactory_Vtbl)).CreateBitmapFromSource)(this, pibitmapsource, option, ppibitmap), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBitmapFromSourceRect(this: *mut core::ffi::c_void, pibitmapsource: *mut core::ffi::c_void, x: u32, y: u32, width: u32, height: u32, ppibitmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImagingFactory", "CreateBitmapFromSourceRect", 19usize, |this| ((**(this as *const *const IWICImagingFactory_Vtbl)).CreateBitmapFromSourceRect)(this, pibitmapsource, x, y, width, height, ppibitmap), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBitmapFromMemory(this: *mut core::ffi::c_void, uiwidth: u32, uiheight: u32, pixelformat: *const windows_core::GUID, cbstride: u32, cbbuffersize: u32, pbbuffer: *const u8, ppibitmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImagingFactory", "CreateBitmapFromMemory", 20usize, |this| ((**(this as *const *const IWICImagingFactory_Vtbl)).CreateBitmapFromMemory)(this, uiwidth, uiheight, pixelformat, cbstride, cbbuffersize, pbbuffer, ppibitmap), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn CreateBitmapFromHBITMAP(this: *mut core::ffi::c_void, hbitmap: super::Gdi::HBITMAP, hpalette: super::Gdi::HPALETTE, options: WICBitmapAlphaChannelOption, ppibitmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImagingFactory", "CreateBitmapFromHBITMAP", 21usize, |this| ((**(this as *const *const IWICImagingFactory_Vtbl)).CreateBitmapFromHBITMAP)(this, hbitmap, hpalette, options, ppibitmap), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn CreateBitmapFromHICON(this: *mut core::ffi::c_void, hicon: super::super::UI::WindowsAndMessaging::HICON, ppibitmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImagingFactory", "CreateBitmapFromHICON", 22usize, |this| ((**(this as *const *const IWICImagingFactory_Vtbl)).CreateBitmapFromHICON)(this, hicon, ppibitmap), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateComponentEnumerator(this: *mut core::ffi::c_void, componenttypes: u32, options: u32, ppienumunknown: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImagingFactory", "CreateComponentEnumerator", 23usize, |this| ((**(this as *const *const IWICImagingFactory_Vtbl)).CreateComponentEnumerator)(this, componenttypes, options, ppienumunknown), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFastMetadataEncoderFromDecoder(this: *mut core::ffi::c_void, pidecoder: *mut core::ffi::c_void, ppifastencoder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImagingFactory", "CreateFastMetadataEncoderFromDecoder", 24usize, |this| ((**(this as *const *const IWICImagingFactory_Vtbl)).CreateFastMetadataEncoderFromDecoder)(this, pidecoder, ppifastencoder), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFastMetadataEncoderFromFrameDecode(this: *mut core::ffi::c_void, piframedecoder: *mut core::ffi::c_void, ppifastencoder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImagingFactory", "CreateFastMetadataEncoderFromFrameDecode", 25usize, |this| ((**(this as *const *const IWICImagingFactory_Vtbl)).CreateFastMetadataEncoderFromFrameDecode)(this, piframedecoder, ppifastencoder), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateQueryWriter(this: *mut core::ffi::c_void, guidmetadataformat: *const windows_core::GUID, pguidvendor: *const windows_core::GUID, ppiquerywriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImagingFactory", "CreateQueryWriter", 26usize, |this| ((**(this as *const *const IWICImagingFactory_Vtbl)).CreateQueryWriter)(this, guidmetadataformat, pguidvendor, ppiquerywriter), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateQueryWriterFromReader(this: *mut core::ffi::c_void, piqueryreader: *mut core::ffi::c_void, pguidvendor: *const windows_core::GUID, ppiquerywriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICImagingFactory", "CreateQueryWriterFromReader", 27usize, |this| ((**(this as *const *const IWICImagingFactory_Vtbl)).CreateQueryWriterFromReader)(this, piqueryreader, pguidvendor, ppiquerywriter), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateDecoderFromFilename,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateDecoderFromStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateDecoderFromStream: 0,
+            CreateDecoderFromFileHandle,
+            CreateComponentInfo,
+            CreateDecoder,
+            CreateEncoder,
+            CreatePalette,
+            CreateFormatConverter,
+            CreateBitmapScaler,
+            CreateBitmapClipper,
+            CreateBitmapFlipRotator,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateStream: 0,
+            CreateColorContext,
+            CreateColorTransformer,
+            CreateBitmap,
+            CreateBitmapFromSource,
+            CreateBitmapFromSourceRect,
+            CreateBitmapFromMemory,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            CreateBitmapFromHBITMAP,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            CreateBitmapFromHBITMAP: 0,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            CreateBitmapFromHICON,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            CreateBitmapFromHICON: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateComponentEnumerator,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateComponentEnumerator: 0,
+            CreateFastMetadataEncoderFromDecoder,
+            CreateFastMetadataEncoderFromFrameDecode,
+            CreateQueryWriter,
+            CreateQueryWriterFromReader,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICImagingFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IWICJpegFrameDecode_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DoesSupportIndexing(this: *mut core::ffi::c_void, pfindexingsupported: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICJpegFrameDecode", "DoesSupportIndexing", 3usize, |this| ((**(this as *const *const IWICJpegFrameDecode_Vtbl)).DoesSupportIndexing)(this, pfindexingsupported), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIndexing(this: *mut core::ffi::c_void, options: WICJpegIndexingOptions, horizontalintervalsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICJpegFrameDecode", "SetIndexing", 4usize, |this| ((**(this as *const *const IWICJpegFrameDecode_Vtbl)).SetIndexing)(this, options, horizontalintervalsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearIndexing(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICJpegFrameDecode", "ClearIndexing", 5usize, |this| ((**(this as *const *const IWICJpegFrameDecode_Vtbl)).ClearIndexing)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetAcHuffmanTable(this: *mut core::ffi::c_void, scanindex: u32, tableindex: u32, pachuffmantable: *mut super::Dxgi::Common::DXGI_JPEG_AC_HUFFMAN_TABLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICJpegFrameDecode", "GetAcHuffmanTable", 6usize, |this| ((**(this as *const *const IWICJpegFrameDecode_Vtbl)).GetAcHuffmanTable)(this, scanindex, tableindex, pachuffmantable), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDcHuffmanTable(this: *mut core::ffi::c_void, scanindex: u32, tableindex: u32, pdchuffmantable: *mut super::Dxgi::Common::DXGI_JPEG_DC_HUFFMAN_TABLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICJpegFrameDecode", "GetDcHuffmanTable", 7usize, |this| ((**(this as *const *const IWICJpegFrameDecode_Vtbl)).GetDcHuffmanTable)(this, scanindex, tableindex, pdchuffmantable), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetQuantizationTable(this: *mut core::ffi::c_void, scanindex: u32, tableindex: u32, pquantizationtable: *mut super::Dxgi::Common::DXGI_JPEG_QUANTIZATION_TABLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICJpegFrameDecode", "GetQuantizationTable", 8usize, |this| ((**(this as *const *const IWICJpegFrameDecode_Vtbl)).GetQuantizationTable)(this, scanindex, tableindex, pquantizationtable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFrameHeader(this: *mut core::ffi::c_void, pframeheader: *mut WICJpegFrameHeader) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICJpegFrameDecode", "GetFrameHeader", 9usize, |this| ((**(this as *const *const IWICJpegFrameDecode_Vtbl)).GetFrameHeader)(this, pframeheader), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScanHeader(this: *mut core::ffi::c_void, scanindex: u32, pscanheader: *mut WICJpegScanHeader) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICJpegFrameDecode", "GetScanHeader", 10usize, |this| ((**(this as *const *const IWICJpegFrameDecode_Vtbl)).GetScanHeader)(this, scanindex, pscanheader), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyScan(this: *mut core::ffi::c_void, scanindex: u32, scanoffset: u32, cbscandata: u32, pbscandata: *mut u8, pcbscandataactual: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICJpegFrameDecode", "CopyScan", 11usize, |this| ((**(this as *const *const IWICJpegFrameDecode_Vtbl)).CopyScan)(this, scanindex, scanoffset, cbscandata, pbscandata, pcbscandataactual), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyMinimalStream(this: *mut core::ffi::c_void, streamoffset: u32, cbstreamdata: u32, pbstreamdata: *mut u8, pcbstreamdataactual: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICJpegFrameDecode", "CopyMinimalStream", 12usize, |this| ((**(this as *const *const IWICJpegFrameDecode_Vtbl)).CopyMinimalStream)(this, streamoffset, cbstreamdata, pbstreamdata, pcbstreamdataactual), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DoesSupportIndexing,
+            SetIndexing,
+            ClearIndexing,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetAcHuffmanTable,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetAcHuffmanTable: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDcHuffmanTable,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDcHuffmanTable: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetQuantizationTable,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetQuantizationTable: 0,
+            GetFrameHeader,
+            GetScanHeader,
+            CopyScan,
+            CopyMinimalStream,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICJpegFrameDecode as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IWICJpegFrameEncode_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetAcHuffmanTable(this: *mut core::ffi::c_void, scanindex: u32, tableindex: u32, pachuffmantable: *mut super::Dxgi::Common::DXGI_JPEG_AC_HUFFMAN_TABLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICJpegFrameEncode", "GetAcHuffmanTable", 3usize, |this| ((**(this as *const *const IWICJpegFrameEncode_Vtbl)).GetAcHuffmanTable)(this, scanindex, tableindex, pachuffmantable), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDcHuffmanTable(this: *mut core::ffi::c_void, scanindex: u32, tableindex: u32, pdchuffmantable: *mut super::Dxgi::Common::DXGI_JPEG_DC_HUFFMAN_TABLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICJpegFrameEncode", "GetDcHuffmanTable", 4usize, |this| ((**(this as *const *const IWICJpegFrameEncode_Vtbl)).GetDcHuffmanTable)(this, scanindex, tableindex, pdchuffmantable), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetQuantizationTable(this: *mut core::ffi::c_void, scanindex: u32, tableindex: u32, pquantizationtable: *mut super::Dxgi::Common::DXGI_JPEG_QUANTIZATION_TABLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICJpegFrameEncode", "GetQuantizationTable", 5usize, |this| ((**(this as *const *const IWICJpegFrameEncode_Vtbl)).GetQuantizationTable)(this, scanindex, tableindex, pquantizationtable), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteScan(this: *mut core::ffi::c_void, cbscandata: u32, pbscandata: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICJpegFrameEncode", "WriteScan", 6usize, |this| ((**(this as *const *const IWICJpegFrameEncode_Vtbl)).WriteScan)(this, cbscandata, pbscandata), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetAcHuffmanTable,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetAcHuffmanTable: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDcHuffmanTable,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDcHuffmanTable: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetQuantizationTable,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetQuantizationTable: 0,
+            WriteScan,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICJpegFrameEncode as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWICMetadataBlockReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetContainerFormat(this: *mut core::ffi::c_void, pguidcontainerformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataBlockReader", "GetContainerFormat", 3usize, |this| ((**(this as *const *const IWICMetadataBlockReader_Vtbl)).GetContainerFormat)(this, pguidcontainerformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pccount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataBlockReader", "GetCount", 4usize, |this| ((**(this as *const *const IWICMetadataBlockReader_Vtbl)).GetCount)(this, pccount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReaderByIndex(this: *mut core::ffi::c_void, nindex: u32, ppimetadatareader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataBlockReader", "GetReaderByIndex", 5usize, |this| ((**(this as *const *const IWICMetadataBlockReader_Vtbl)).GetReaderByIndex)(this, nindex, ppimetadatareader), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetEnumerator(this: *mut core::ffi::c_void, ppienummetadata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataBlockReader", "GetEnumerator", 6usize, |this| ((**(this as *const *const IWICMetadataBlockReader_Vtbl)).GetEnumerator)(this, ppienummetadata), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetContainerFormat,
+            GetCount,
+            GetReaderByIndex,
+            #[cfg(feature = "Win32_System_Com")]
+            GetEnumerator,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetEnumerator: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICMetadataBlockReader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWICMetadataBlockWriter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeFromBlockReader(this: *mut core::ffi::c_void, pimdblockreader: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataBlockWriter", "InitializeFromBlockReader", 7usize, |this| ((**(this as *const *const IWICMetadataBlockWriter_Vtbl)).InitializeFromBlockReader)(this, pimdblockreader), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWriterByIndex(this: *mut core::ffi::c_void, nindex: u32, ppimetadatawriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataBlockWriter", "GetWriterByIndex", 8usize, |this| ((**(this as *const *const IWICMetadataBlockWriter_Vtbl)).GetWriterByIndex)(this, nindex, ppimetadatawriter), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddWriter(this: *mut core::ffi::c_void, pimetadatawriter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataBlockWriter", "AddWriter", 9usize, |this| ((**(this as *const *const IWICMetadataBlockWriter_Vtbl)).AddWriter)(this, pimetadatawriter), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWriterByIndex(this: *mut core::ffi::c_void, nindex: u32, pimetadatawriter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataBlockWriter", "SetWriterByIndex", 10usize, |this| ((**(this as *const *const IWICMetadataBlockWriter_Vtbl)).SetWriterByIndex)(this, nindex, pimetadatawriter), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveWriterByIndex(this: *mut core::ffi::c_void, nindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataBlockWriter", "RemoveWriterByIndex", 11usize, |this| ((**(this as *const *const IWICMetadataBlockWriter_Vtbl)).RemoveWriterByIndex)(this, nindex), |result| Some(*result))
+        }
+        Self {
+            base__: <IWICMetadataBlockReader_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitializeFromBlockReader,
+            GetWriterByIndex,
+            AddWriter,
+            SetWriterByIndex,
+            RemoveWriterByIndex,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICMetadataBlockWriter as windows_core::Interface>::IID || <IWICMetadataBlockReader_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICMetadataHandlerInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMetadataFormat(this: *mut core::ffi::c_void, pguidmetadataformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataHandlerInfo", "GetMetadataFormat", 11usize, |this| ((**(this as *const *const IWICMetadataHandlerInfo_Vtbl)).GetMetadataFormat)(this, pguidmetadataformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContainerFormats(this: *mut core::ffi::c_void, ccontainerformats: u32, pguidcontainerformats: *mut windows_core::GUID, pcchactual: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataHandlerInfo", "GetContainerFormats", 12usize, |this| ((**(this as *const *const IWICMetadataHandlerInfo_Vtbl)).GetContainerFormats)(this, ccontainerformats, pguidcontainerformats, pcchactual), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceManufacturer(this: *mut core::ffi::c_void, cchdevicemanufacturer: u32, wzdevicemanufacturer: windows_core::PWSTR, pcchactual: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataHandlerInfo", "GetDeviceManufacturer", 13usize, |this| ((**(this as *const *const IWICMetadataHandlerInfo_Vtbl)).GetDeviceManufacturer)(this, cchdevicemanufacturer, wzdevicemanufacturer, pcchactual), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceModels(this: *mut core::ffi::c_void, cchdevicemodels: u32, wzdevicemodels: windows_core::PWSTR, pcchactual: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataHandlerInfo", "GetDeviceModels", 14usize, |this| ((**(this as *const *const IWICMetadataHandlerInfo_Vtbl)).GetDeviceModels)(this, cchdevicemodels, wzdevicemodels, pcchactual), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoesRequireFullStream(this: *mut core::ffi::c_void, pfrequiresfullstream: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataHandlerInfo", "DoesRequireFullStream", 15usize, |this| ((**(this as *const *const IWICMetadataHandlerInfo_Vtbl)).DoesRequireFullStream)(this, pfrequiresfullstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoesSupportPadding(this: *mut core::ffi::c_void, pfsupportspadding: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataHandlerInfo", "DoesSupportPadding", 16usize, |this| ((**(this as *const *const IWICMetadataHandlerInfo_Vtbl)).DoesSupportPadding)(this, pfsupportspadding), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoesRequireFixedSize(this: *mut core::ffi::c_void, pffixedsize: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataHandlerInfo", "DoesRequireFixedSize", 17usize, |this| ((**(this as *const *const IWICMetadataHandlerInfo_Vtbl)).DoesRequireFixedSize)(this, pffixedsize), |result| Some(*result))
+        }
+        Self {
+            base__: <IWICComponentInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetMetadataFormat,
+            GetContainerFormats,
+            GetDeviceManufacturer,
+            GetDeviceModels,
+            DoesRequireFullStream,
+            DoesSupportPadding,
+            DoesRequireFixedSize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICMetadataHandlerInfo as windows_core::Interface>::IID || <IWICComponentInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWICMetadataQueryReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetContainerFormat(this: *mut core::ffi::c_void, pguidcontainerformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataQueryReader", "GetContainerFormat", 3usize, |this| ((**(this as *const *const IWICMetadataQueryReader_Vtbl)).GetContainerFormat)(this, pguidcontainerformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocation(this: *mut core::ffi::c_void, cchmaxlength: u32, wznamespace: windows_core::PWSTR, pcchactuallength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataQueryReader", "GetLocation", 4usize, |this| ((**(this as *const *const IWICMetadataQueryReader_Vtbl)).GetLocation)(this, cchmaxlength, wznamespace, pcchactuallength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMetadataByName(this: *mut core::ffi::c_void, wzname: windows_core::PCWSTR, pvarvalue: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataQueryReader", "GetMetadataByName", 5usize, |this| ((**(this as *const *const IWICMetadataQueryReader_Vtbl)).GetMetadataByName)(this, wzname, pvarvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetEnumerator(this: *mut core::ffi::c_void, ppienumstring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataQueryReader", "GetEnumerator", 6usize, |this| ((**(this as *const *const IWICMetadataQueryReader_Vtbl)).GetEnumerator)(this, ppienumstring), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetContainerFormat,
+            GetLocation,
+            GetMetadataByName,
+            #[cfg(feature = "Win32_System_Com")]
+            GetEnumerator,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetEnumerator: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICMetadataQueryReader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWICMetadataQueryWriter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMetadataByName(this: *mut core::ffi::c_void, wzname: windows_core::PCWSTR, pvarvalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataQueryWriter", "SetMetadataByName", 7usize, |this| ((**(this as *const *const IWICMetadataQueryWriter_Vtbl)).SetMetadataByName)(this, wzname, pvarvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMetadataByName(this: *mut core::ffi::c_void, wzname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataQueryWriter", "RemoveMetadataByName", 8usize, |this| ((**(this as *const *const IWICMetadataQueryWriter_Vtbl)).RemoveMetadataByName)(this, wzname), |result| Some(*result))
+        }
+        Self { base__: <IWICMetadataQueryReader_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetMetadataByName, RemoveMetadataByName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICMetadataQueryWriter as windows_core::Interface>::IID || <IWICMetadataQueryReader_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICMetadataReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMetadataFormat(this: *mut core::ffi::c_void, pguidmetadataformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataReader", "GetMetadataFormat", 3usize, |this| ((**(this as *const *const IWICMetadataReader_Vtbl)).GetMetadataFormat)(this, pguidmetadataformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMetadataHandlerInfo(this: *mut core::ffi::c_void, ppihandler: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataReader", "GetMetadataHandlerInfo", 4usize, |this| ((**(this as *const *const IWICMetadataReader_Vtbl)).GetMetadataHandlerInfo)(this, ppihandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pccount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataReader", "GetCount", 5usize, |this| ((**(this as *const *const IWICMetadataReader_Vtbl)).GetCount)(this, pccount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValueByIndex(this: *mut core::ffi::c_void, nindex: u32, pvarschema: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>, pvarid: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>, pvarvalue: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataReader", "GetValueByIndex", 6usize, |this| ((**(this as *const *const IWICMetadataReader_Vtbl)).GetValueByIndex)(this, nindex, pvarschema, pvarid, pvarvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, pvarschema: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, pvarid: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, pvarvalue: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataReader", "GetValue", 7usize, |this| ((**(this as *const *const IWICMetadataReader_Vtbl)).GetValue)(this, pvarschema, pvarid, pvarvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnumerator(this: *mut core::ffi::c_void, ppienummetadata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataReader", "GetEnumerator", 8usize, |this| ((**(this as *const *const IWICMetadataReader_Vtbl)).GetEnumerator)(this, ppienummetadata), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetMetadataFormat,
+            GetMetadataHandlerInfo,
+            GetCount,
+            GetValueByIndex,
+            GetValue,
+            GetEnumerator,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICMetadataReader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWICMetadataReaderInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPatterns(this: *mut core::ffi::c_void, guidcontainerformat: *const windows_core::GUID, cbsize: u32, ppattern: *mut WICMetadataPattern, pccount: *mut u32, pcbactual: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataReaderInfo", "GetPatterns", 18usize, |this| ((**(this as *const *const IWICMetadataReaderInfo_Vtbl)).GetPatterns)(this, guidcontainerformat, cbsize, ppattern, pccount, pcbactual), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn MatchesPattern(this: *mut core::ffi::c_void, guidcontainerformat: *const windows_core::GUID, pistream: *mut core::ffi::c_void, pfmatches: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataReaderInfo", "MatchesPattern", 19usize, |this| ((**(this as *const *const IWICMetadataReaderInfo_Vtbl)).MatchesPattern)(this, guidcontainerformat, pistream, pfmatches), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, ppireader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataReaderInfo", "CreateInstance", 20usize, |this| ((**(this as *const *const IWICMetadataReaderInfo_Vtbl)).CreateInstance)(this, ppireader), |result| Some(*result))
+        }
+        Self {
+            base__: <IWICMetadataHandlerInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPatterns,
+            #[cfg(feature = "Win32_System_Com")]
+            MatchesPattern,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            MatchesPattern: 0,
+            CreateInstance,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICMetadataReaderInfo as windows_core::Interface>::IID || <IWICMetadataHandlerInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICMetadataWriter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, pvarschema: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, pvarid: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, pvarvalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataWriter", "SetValue", 9usize, |this| ((**(this as *const *const IWICMetadataWriter_Vtbl)).SetValue)(this, pvarschema, pvarid, pvarvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValueByIndex(this: *mut core::ffi::c_void, nindex: u32, pvarschema: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, pvarid: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, pvarvalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataWriter", "SetValueByIndex", 10usize, |this| ((**(this as *const *const IWICMetadataWriter_Vtbl)).SetValueByIndex)(this, nindex, pvarschema, pvarid, pvarvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveValue(this: *mut core::ffi::c_void, pvarschema: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, pvarid: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataWriter", "RemoveValue", 11usize, |this| ((**(this as *const *const IWICMetadataWriter_Vtbl)).RemoveValue)(this, pvarschema, pvarid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveValueByIndex(this: *mut core::ffi::c_void, nindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataWriter", "RemoveValueByIndex", 12usize, |this| ((**(this as *const *const IWICMetadataWriter_Vtbl)).RemoveValueByIndex)(this, nindex), |result| Some(*result))
+        }
+        Self { base__: <IWICMetadataReader_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetValue, SetValueByIndex, RemoveValue, RemoveValueByIndex }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICMetadataWriter as windows_core::Interface>::IID || <IWICMetadataReader_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICMetadataWriterInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetHeader(this: *mut core::ffi::c_void, guidcontainerformat: *const windows_core::GUID, cbsize: u32, pheader: *mut WICMetadataHeader, pcbactual: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataWriterInfo", "GetHeader", 18usize, |this| ((**(this as *const *const IWICMetadataWriterInfo_Vtbl)).GetHeader)(this, guidcontainerformat, cbsize, pheader, pcbactual), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, ppiwriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICMetadataWriterInfo", "CreateInstance", 19usize, |this| ((**(this as *const *const IWICMetadataWriterInfo_Vtbl)).CreateInstance)(this, ppiwriter), |result| Some(*result))
+        }
+        Self { base__: <IWICMetadataHandlerInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetHeader, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICMetadataWriterInfo as windows_core::Interface>::IID || <IWICMetadataHandlerInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICPalette_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializePredefined(this: *mut core::ffi::c_void, epalettetype: WICBitmapPaletteType, faddtransparentcolor: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICPalette", "InitializePredefined", 3usize, |this| ((**(this as *const *const IWICPalette_Vtbl)).InitializePredefined)(this, epalettetype, faddtransparentcolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeCustom(this: *mut core::ffi::c_void, pcolors: *const u32, ccount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICPalette", "InitializeCustom", 4usize, |this| ((**(this as *const *const IWICPalette_Vtbl)).InitializeCustom)(this, pcolors, ccount), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeFromBitmap(this: *mut core::ffi::c_void, pisurface: *mut core::ffi::c_void, ccount: u32, faddtransparentcolor: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICPalette", "InitializeFromBitmap", 5usize, |this| ((**(this as *const *const IWICPalette_Vtbl)).InitializeFromBitmap)(this, pisurface, ccount, faddtransparentcolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeFromPalette(this: *mut core::ffi::c_void, pipalette: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICPalette", "InitializeFromPalette", 6usize, |this| ((**(this as *const *const IWICPalette_Vtbl)).InitializeFromPalette)(this, pipalette), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, pepalettetype: *mut WICBitmapPaletteType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICPalette", "GetType", 7usize, |this| ((**(this as *const *const IWICPalette_Vtbl)).GetType)(this, pepalettetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColorCount(this: *mut core::ffi::c_void, pccount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICPalette", "GetColorCount", 8usize, |this| ((**(this as *const *const IWICPalette_Vtbl)).GetColorCount)(this, pccount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColors(this: *mut core::ffi::c_void, ccount: u32, pcolors: *mut u32, pcactualcolors: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICPalette", "GetColors", 9usize, |this| ((**(this as *const *const IWICPalette_Vtbl)).GetColors)(this, ccount, pcolors, pcactualcolors), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsBlackWhite(this: *mut core::ffi::c_void, pfisblackwhite: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICPalette", "IsBlackWhite", 10usize, |this| ((**(this as *const *const IWICPalette_Vtbl)).IsBlackWhite)(this, pfisblackwhite), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsGrayscale(this: *mut core::ffi::c_void, pfisgrayscale: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICPalette", "IsGrayscale", 11usize, |this| ((**(this as *const *const IWICPalette_Vtbl)).IsGrayscale)(this, pfisgrayscale), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasAlpha(this: *mut core::ffi::c_void, pfhasalpha: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICPalette", "HasAlpha", 12usize, |this| ((**(this as *const *const IWICPalette_Vtbl)).HasAlpha)(this, pfhasalpha), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitializePredefined,
+            InitializeCustom,
+            InitializeFromBitmap,
+            InitializeFromPalette,
+            GetType,
+            GetColorCount,
+            GetColors,
+            IsBlackWhite,
+            IsGrayscale,
+            HasAlpha,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICPalette as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWICPersistStream_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn LoadEx(this: *mut core::ffi::c_void, pistream: *mut core::ffi::c_void, pguidpreferredvendor: *const windows_core::GUID, dwpersistoptions: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICPersistStream", "LoadEx", 8usize, |this| ((**(this as *const *const IWICPersistStream_Vtbl)).LoadEx)(this, pistream, pguidpreferredvendor, dwpersistoptions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SaveEx(this: *mut core::ffi::c_void, pistream: *mut core::ffi::c_void, dwpersistoptions: u32, fcleardirty: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICPersistStream", "SaveEx", 9usize, |this| ((**(this as *const *const IWICPersistStream_Vtbl)).SaveEx)(this, pistream, dwpersistoptions, fcleardirty), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IPersistStream_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            LoadEx,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            LoadEx: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SaveEx,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SaveEx: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICPersistStream as windows_core::Interface>::IID || <super::super::System::Com::IPersistStream_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICPixelFormatInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFormatGUID(this: *mut core::ffi::c_void, pformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICPixelFormatInfo", "GetFormatGUID", 11usize, |this| ((**(this as *const *const IWICPixelFormatInfo_Vtbl)).GetFormatGUID)(this, pformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColorContext(this: *mut core::ffi::c_void, ppicolorcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICPixelFormatInfo", "GetColorContext", 12usize, |this| ((**(this as *const *const IWICPixelFormatInfo_Vtbl)).GetColorContext)(this, ppicolorcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBitsPerPixel(this: *mut core::ffi::c_void, puibitsperpixel: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICPixelFormatInfo", "GetBitsPerPixel", 13usize, |this| ((**(this as *const *const IWICPixelFormatInfo_Vtbl)).GetBitsPerPixel)(this, puibitsperpixel), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChannelCount(this: *mut core::ffi::c_void, puichannelcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICPixelFormatInfo", "GetChannelCount", 14usize, |this| ((**(this as *const *const IWICPixelFormatInfo_Vtbl)).GetChannelCount)(this, puichannelcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChannelMask(this: *mut core::ffi::c_void, uichannelindex: u32, cbmaskbuffer: u32, pbmaskbuffer: *mut u8, pcbactual: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICPixelFormatInfo", "GetChannelMask", 15usize, |this| ((**(this as *const *const IWICPixelFormatInfo_Vtbl)).GetChannelMask)(this, uichannelindex, cbmaskbuffer, pbmaskbuffer, pcbactual), |result| Some(*result))
+        }
+        Self {
+            base__: <IWICComponentInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetFormatGUID,
+            GetColorContext,
+            GetBitsPerPixel,
+            GetChannelCount,
+            GetChannelMask,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICPixelFormatInfo as windows_core::Interface>::IID || <IWICComponentInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICPixelFormatInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SupportsTransparency(this: *mut core::ffi::c_void, pfsupportstransparency: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICPixelFormatInfo2", "SupportsTransparency", 16usize, |this| ((**(this as *const *const IWICPixelFormatInfo2_Vtbl)).SupportsTransparency)(this, pfsupportstransparency), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumericRepresentation(this: *mut core::ffi::c_void, pnumericrepresentation: *mut WICPixelFormatNumericRepresentation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICPixelFormatInfo2", "GetNumericRepresentation", 17usize, |this| ((**(this as *const *const IWICPixelFormatInfo2_Vtbl)).GetNumericRepresentation)(this, pnumericrepresentation), |result| Some(*result))
+        }
+        Self { base__: <IWICPixelFormatInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SupportsTransparency, GetNumericRepresentation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICPixelFormatInfo2 as windows_core::Interface>::IID || <IWICPixelFormatInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICPlanarBitmapFrameEncode_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WritePixels(this: *mut core::ffi::c_void, linecount: u32, pplanes: *const WICBitmapPlane, cplanes: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICPlanarBitmapFrameEncode", "WritePixels", 3usize, |this| ((**(this as *const *const IWICPlanarBitmapFrameEncode_Vtbl)).WritePixels)(this, linecount, pplanes, cplanes), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteSource(this: *mut core::ffi::c_void, ppplanes: *const *mut core::ffi::c_void, cplanes: u32, prcsource: *const WICRect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICPlanarBitmapFrameEncode", "WriteSource", 4usize, |this| ((**(this as *const *const IWICPlanarBitmapFrameEncode_Vtbl)).WriteSource)(this, ppplanes, cplanes, prcsource), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WritePixels, WriteSource }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICPlanarBitmapFrameEncode as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICPlanarBitmapSourceTransform_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DoesSupportTransform(this: *mut core::ffi::c_void, puiwidth: *mut u32, puiheight: *mut u32, dsttransform: WICBitmapTransformOptions, dstplanaroptions: WICPlanarOptions, pguiddstformats: *const windows_core::GUID, pplanedescriptions: *mut WICBitmapPlaneDescription, cplanes: u32, pfissupported: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICPlanarBitmapSourceTransform", "DoesSupportTransform", 3usize, |this| ((**(this as *const *const IWICPlanarBitmapSourceTransform_Vtbl)).DoesSupportTransform)(this, puiwidth, puiheight, dsttransform, dstplanaroptions, pguiddstformats, pplanedescriptions, cplanes, pfissupported), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyPixels(this: *mut core::ffi::c_void, prcsource: *const WICRect, uiwidth: u32, uiheight: u32, dsttransform: WICBitmapTransformOptions, dstplanaroptions: WICPlanarOptions, pdstplanes: *const WICBitmapPlane, cplanes: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICPlanarBitmapSourceTransform", "CopyPixels", 4usize, |this| ((**(this as *const *const IWICPlanarBitmapSourceTransform_Vtbl)).CopyPixels)(this, prcsource, uiwidth, uiheight, dsttransform, dstplanaroptions, pdstplanes, cplanes), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DoesSupportTransform, CopyPixels }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICPlanarBitmapSourceTransform as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICPlanarFormatConverter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, ppplanes: *const *mut core::ffi::c_void, cplanes: u32, dstformat: *const windows_core::GUID, dither: WICBitmapDitherType, pipalette: *mut core::ffi::c_void, alphathresholdpercent: f64, palettetranslate: WICBitmapPaletteType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICPlanarFormatConverter", "Initialize", 8usize, |this| ((**(this as *const *const IWICPlanarFormatConverter_Vtbl)).Initialize)(this, ppplanes, cplanes, dstformat, dither, pipalette, alphathresholdpercent, palettetranslate), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanConvert(this: *mut core::ffi::c_void, psrcpixelformats: *const windows_core::GUID, csrcplanes: u32, dstpixelformat: *const windows_core::GUID, pfcanconvert: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICPlanarFormatConverter", "CanConvert", 9usize, |this| ((**(this as *const *const IWICPlanarFormatConverter_Vtbl)).CanConvert)(this, psrcpixelformats, csrcplanes, dstpixelformat, pfcanconvert), |result| Some(*result))
+        }
+        Self { base__: <IWICBitmapSource_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, CanConvert }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICPlanarFormatConverter as windows_core::Interface>::IID || <IWICBitmapSource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICProgressCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Notify(this: *mut core::ffi::c_void, uframenum: u32, operation: WICProgressOperation, dblprogress: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICProgressCallback", "Notify", 3usize, |this| ((**(this as *const *const IWICProgressCallback_Vtbl)).Notify)(this, uframenum, operation, dblprogress), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Notify }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICProgressCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWICProgressiveLevelControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLevelCount(this: *mut core::ffi::c_void, pclevels: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICProgressiveLevelControl", "GetLevelCount", 3usize, |this| ((**(this as *const *const IWICProgressiveLevelControl_Vtbl)).GetLevelCount)(this, pclevels), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentLevel(this: *mut core::ffi::c_void, pnlevel: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICProgressiveLevelControl", "GetCurrentLevel", 4usize, |this| ((**(this as *const *const IWICProgressiveLevelControl_Vtbl)).GetCurrentLevel)(this, pnlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentLevel(this: *mut core::ffi::c_void, nlevel: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICProgressiveLevelControl", "SetCurrentLevel", 5usize, |this| ((**(this as *const *const IWICProgressiveLevelControl_Vtbl)).SetCurrentLevel)(this, nlevel), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetLevelCount, GetCurrentLevel, SetCurrentLevel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICProgressiveLevelControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWICStream_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeFromIStream(this: *mut core::ffi::c_void, pistream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICStream", "InitializeFromIStream", 14usize, |this| ((**(this as *const *const IWICStream_Vtbl)).InitializeFromIStream)(this, pistream), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeFromFilename(this: *mut core::ffi::c_void, wzfilename: windows_core::PCWSTR, dwdesiredaccess: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICStream", "InitializeFromFilename", 15usize, |this| ((**(this as *const *const IWICStream_Vtbl)).InitializeFromFilename)(this, wzfilename, dwdesiredaccess), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeFromMemory(this: *mut core::ffi::c_void, pbbuffer: *const u8, cbbuffersize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICStream", "InitializeFromMemory", 16usize, |this| ((**(this as *const *const IWICStream_Vtbl)).InitializeFromMemory)(this, pbbuffer, cbbuffersize), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeFromIStreamRegion(this: *mut core::ffi::c_void, pistream: *mut core::ffi::c_void, uloffset: u64, ulmaxsize: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICStream", "InitializeFromIStreamRegion", 17usize, |this| ((**(this as *const *const IWICStream_Vtbl)).InitializeFromIStreamRegion)(this, pistream, uloffset, ulmaxsize), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IStream_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeFromIStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeFromIStream: 0,
+            InitializeFromFilename,
+            InitializeFromMemory,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeFromIStreamRegion,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeFromIStreamRegion: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICStream as windows_core::Interface>::IID || <super::super::System::Com::IStream_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWICStreamProvider_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetStream(this: *mut core::ffi::c_void, ppistream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICStreamProvider", "GetStream", 3usize, |this| ((**(this as *const *const IWICStreamProvider_Vtbl)).GetStream)(this, ppistream), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPersistOptions(this: *mut core::ffi::c_void, pdwpersistoptions: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICStreamProvider", "GetPersistOptions", 4usize, |this| ((**(this as *const *const IWICStreamProvider_Vtbl)).GetPersistOptions)(this, pdwpersistoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreferredVendorGUID(this: *mut core::ffi::c_void, pguidpreferredvendor: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICStreamProvider", "GetPreferredVendorGUID", 5usize, |this| ((**(this as *const *const IWICStreamProvider_Vtbl)).GetPreferredVendorGUID)(this, pguidpreferredvendor), |result| Some(*result))
+        }
+        unsafe extern "system" fn RefreshStream(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWICStreamProvider", "RefreshStream", 6usize, |this| ((**(this as *const *const IWICStreamProvider_Vtbl)).RefreshStream)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetStream: 0,
+            GetPersistOptions,
+            GetPreferredVendorGUID,
+            RefreshStream,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWICStreamProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
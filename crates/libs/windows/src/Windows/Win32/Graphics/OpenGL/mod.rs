@@ -2882,6 +2882,7 @@ pub struct PFD_FLAGS(pub u32);
 impl windows_core::TypeKind for PFD_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PFD_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PFD_FLAGS").field(&self.0).finish()
@@ -2926,6 +2927,7 @@ pub struct PFD_LAYER_TYPE(pub i8);
 impl windows_core::TypeKind for PFD_LAYER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PFD_LAYER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PFD_LAYER_TYPE").field(&self.0).finish()
@@ -2937,6 +2939,7 @@ pub struct PFD_PIXEL_TYPE(pub u8);
 impl windows_core::TypeKind for PFD_PIXEL_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PFD_PIXEL_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PFD_PIXEL_TYPE").field(&self.0).finish()
@@ -2944,7 +2947,8 @@ impl core::fmt::Debug for PFD_PIXEL_TYPE {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EMRPIXELFORMAT {
     pub emr: super::Gdi::EMR,
     pub pfd: PIXELFORMATDESCRIPTOR,
@@ -2960,7 +2964,8 @@ impl Default for EMRPIXELFORMAT {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GLUnurbs(pub isize);
 impl Default for GLUnurbs {
     fn default() -> Self {
@@ -2971,7 +2976,8 @@ impl windows_core::TypeKind for GLUnurbs {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GLUquadric(pub isize);
 impl Default for GLUquadric {
     fn default() -> Self {
@@ -2982,7 +2988,8 @@ impl windows_core::TypeKind for GLUquadric {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GLUtesselator(pub isize);
 impl Default for GLUtesselator {
     fn default() -> Self {
@@ -2993,7 +3000,8 @@ impl windows_core::TypeKind for GLUtesselator {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GLYPHMETRICSFLOAT {
     pub gmfBlackBoxX: f32,
     pub gmfBlackBoxY: f32,
@@ -3010,7 +3018,8 @@ impl Default for GLYPHMETRICSFLOAT {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HGLRC(pub *mut core::ffi::c_void);
 impl HGLRC {
     pub fn is_invalid(&self) -> bool {
@@ -3034,7 +3043,8 @@ impl windows_core::TypeKind for HGLRC {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LAYERPLANEDESCRIPTOR {
     pub nSize: u16,
     pub nVersion: u16,
@@ -3070,7 +3080,8 @@ impl Default for LAYERPLANEDESCRIPTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PIXELFORMATDESCRIPTOR {
     pub nSize: u16,
     pub nVersion: u16,
@@ -3108,7 +3119,8 @@ impl Default for PIXELFORMATDESCRIPTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POINTFLOAT {
     pub x: f32,
     pub y: f32,
@@ -121,6 +121,7 @@ pub struct EDefaultDevmodeType(pub i32);
 impl windows_core::TypeKind for EDefaultDevmodeType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EDefaultDevmodeType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EDefaultDevmodeType").field(&self.0).finish()
@@ -132,13 +133,15 @@ pub struct EPrintTicketScope(pub i32);
 impl windows_core::TypeKind for EPrintTicketScope {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EPrintTicketScope {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EPrintTicketScope").field(&self.0).finish()
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HPTPROVIDER(pub *mut core::ffi::c_void);
 impl HPTPROVIDER {
     pub fn is_invalid(&self) -> bool {
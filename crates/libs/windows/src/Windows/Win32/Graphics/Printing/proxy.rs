@@ -0,0 +1,2405 @@
+impl windows_core::imp::ProxyVtable for IAsyncGetSendNotificationCookie_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FinishAsyncCallWithData(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncGetSendNotificationCookie", "FinishAsyncCallWithData", 5usize, |this| ((**(this as *const *const IAsyncGetSendNotificationCookie_Vtbl)).FinishAsyncCallWithData)(this, param0, param1), |result| Some(*result))
+        }
+        Self { base__: <IPrintAsyncCookie_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FinishAsyncCallWithData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAsyncGetSendNotificationCookie as windows_core::Interface>::IID || <IPrintAsyncCookie_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAsyncGetSrvReferralCookie_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FinishAsyncCall(this: *mut core::ffi::c_void, param0: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncGetSrvReferralCookie", "FinishAsyncCall", 3usize, |this| ((**(this as *const *const IAsyncGetSrvReferralCookie_Vtbl)).FinishAsyncCall)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelAsyncCall(this: *mut core::ffi::c_void, param0: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncGetSrvReferralCookie", "CancelAsyncCall", 4usize, |this| ((**(this as *const *const IAsyncGetSrvReferralCookie_Vtbl)).CancelAsyncCall)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn FinishAsyncCallWithData(this: *mut core::ffi::c_void, param0: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncGetSrvReferralCookie", "FinishAsyncCallWithData", 5usize, |this| ((**(this as *const *const IAsyncGetSrvReferralCookie_Vtbl)).FinishAsyncCallWithData)(this, param0), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FinishAsyncCall, CancelAsyncCall, FinishAsyncCallWithData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAsyncGetSrvReferralCookie as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBidiAsyncNotifyChannel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateNotificationChannel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBidiAsyncNotifyChannel", "CreateNotificationChannel", 5usize, |this| ((**(this as *const *const IBidiAsyncNotifyChannel_Vtbl)).CreateNotificationChannel)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrintName(this: *mut core::ffi::c_void, param0: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBidiAsyncNotifyChannel", "GetPrintName", 6usize, |this| ((**(this as *const *const IBidiAsyncNotifyChannel_Vtbl)).GetPrintName)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChannelNotificationType(this: *mut core::ffi::c_void, param0: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBidiAsyncNotifyChannel", "GetChannelNotificationType", 7usize, |this| ((**(this as *const *const IBidiAsyncNotifyChannel_Vtbl)).GetChannelNotificationType)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn AsyncGetNotificationSendResponse(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBidiAsyncNotifyChannel", "AsyncGetNotificationSendResponse", 8usize, |this| ((**(this as *const *const IBidiAsyncNotifyChannel_Vtbl)).AsyncGetNotificationSendResponse)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn AsyncCloseChannel(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void, param1: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBidiAsyncNotifyChannel", "AsyncCloseChannel", 9usize, |this| ((**(this as *const *const IBidiAsyncNotifyChannel_Vtbl)).AsyncCloseChannel)(this, param0, param1), |result| Some(*result))
+        }
+        Self {
+            base__: <IPrintAsyncNotifyChannel_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateNotificationChannel,
+            GetPrintName,
+            GetChannelNotificationType,
+            AsyncGetNotificationSendResponse,
+            AsyncCloseChannel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBidiAsyncNotifyChannel as windows_core::Interface>::IID || <IPrintAsyncNotifyChannel_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBidiRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSchema(this: *mut core::ffi::c_void, pszschema: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBidiRequest", "SetSchema", 3usize, |this| ((**(this as *const *const IBidiRequest_Vtbl)).SetSchema)(this, pszschema), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInputData(this: *mut core::ffi::c_void, dwtype: u32, pdata: *const u8, usize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBidiRequest", "SetInputData", 4usize, |this| ((**(this as *const *const IBidiRequest_Vtbl)).SetInputData)(this, dwtype, pdata, usize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResult(this: *mut core::ffi::c_void, phr: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBidiRequest", "GetResult", 5usize, |this| ((**(this as *const *const IBidiRequest_Vtbl)).GetResult)(this, phr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputData(this: *mut core::ffi::c_void, dwindex: u32, ppszschema: *mut windows_core::PWSTR, pdwtype: *mut u32, ppdata: *mut *mut u8, usize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBidiRequest", "GetOutputData", 6usize, |this| ((**(this as *const *const IBidiRequest_Vtbl)).GetOutputData)(this, dwindex, ppszschema, pdwtype, ppdata, usize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnumCount(this: *mut core::ffi::c_void, pdwtotal: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBidiRequest", "GetEnumCount", 7usize, |this| ((**(this as *const *const IBidiRequest_Vtbl)).GetEnumCount)(this, pdwtotal), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetSchema, SetInputData, GetResult, GetOutputData, GetEnumCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBidiRequest as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IBidiRequestContainer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddRequest(this: *mut core::ffi::c_void, prequest: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBidiRequestContainer", "AddRequest", 3usize, |this| ((**(this as *const *const IBidiRequestContainer_Vtbl)).AddRequest)(this, prequest), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetEnumObject(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBidiRequestContainer", "GetEnumObject", 4usize, |this| ((**(this as *const *const IBidiRequestContainer_Vtbl)).GetEnumObject)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRequestCount(this: *mut core::ffi::c_void, pucount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBidiRequestContainer", "GetRequestCount", 5usize, |this| ((**(this as *const *const IBidiRequestContainer_Vtbl)).GetRequestCount)(this, pucount), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddRequest,
+            #[cfg(feature = "Win32_System_Com")]
+            GetEnumObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetEnumObject: 0,
+            GetRequestCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBidiRequestContainer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBidiSpl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BindDevice(this: *mut core::ffi::c_void, pszdevicename: windows_core::PCWSTR, dwaccess: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBidiSpl", "BindDevice", 3usize, |this| ((**(this as *const *const IBidiSpl_Vtbl)).BindDevice)(this, pszdevicename, dwaccess), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnbindDevice(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBidiSpl", "UnbindDevice", 4usize, |this| ((**(this as *const *const IBidiSpl_Vtbl)).UnbindDevice)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendRecv(this: *mut core::ffi::c_void, pszaction: windows_core::PCWSTR, prequest: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBidiSpl", "SendRecv", 5usize, |this| ((**(this as *const *const IBidiSpl_Vtbl)).SendRecv)(this, pszaction, prequest), |result| Some(*result))
+        }
+        unsafe extern "system" fn MultiSendRecv(this: *mut core::ffi::c_void, pszaction: windows_core::PCWSTR, prequestcontainer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBidiSpl", "MultiSendRecv", 6usize, |this| ((**(this as *const *const IBidiSpl_Vtbl)).MultiSendRecv)(this, pszaction, prequestcontainer), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BindDevice, UnbindDevice, SendRecv, MultiSendRecv }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBidiSpl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IBidiSpl2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BindDevice(this: *mut core::ffi::c_void, pszdevicename: windows_core::PCWSTR, dwaccess: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBidiSpl2", "BindDevice", 3usize, |this| ((**(this as *const *const IBidiSpl2_Vtbl)).BindDevice)(this, pszdevicename, dwaccess), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnbindDevice(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBidiSpl2", "UnbindDevice", 4usize, |this| ((**(this as *const *const IBidiSpl2_Vtbl)).UnbindDevice)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendRecvXMLString(this: *mut core::ffi::c_void, bstrrequest: core::mem::MaybeUninit<windows_core::BSTR>, pbstrresponse: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBidiSpl2", "SendRecvXMLString", 5usize, |this| ((**(this as *const *const IBidiSpl2_Vtbl)).SendRecvXMLString)(this, bstrrequest, pbstrresponse), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SendRecvXMLStream(this: *mut core::ffi::c_void, psrequest: *mut core::ffi::c_void, ppsresponse: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBidiSpl2", "SendRecvXMLStream", 6usize, |this| ((**(this as *const *const IBidiSpl2_Vtbl)).SendRecvXMLStream)(this, psrequest, ppsresponse), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BindDevice,
+            UnbindDevice,
+            SendRecvXMLString,
+            #[cfg(feature = "Win32_System_Com")]
+            SendRecvXMLStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SendRecvXMLStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBidiSpl2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFixedDocument_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetUri(this: *mut core::ffi::c_void, uri: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFixedDocument", "GetUri", 3usize, |this| ((**(this as *const *const IFixedDocument_Vtbl)).GetUri)(this, uri), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrintTicket(this: *mut core::ffi::c_void, ppprintticket: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFixedDocument", "GetPrintTicket", 4usize, |this| ((**(this as *const *const IFixedDocument_Vtbl)).GetPrintTicket)(this, ppprintticket), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrintTicket(this: *mut core::ffi::c_void, pprintticket: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFixedDocument", "SetPrintTicket", 5usize, |this| ((**(this as *const *const IFixedDocument_Vtbl)).SetPrintTicket)(this, pprintticket), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetUri, GetPrintTicket, SetPrintTicket }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFixedDocument as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFixedDocumentSequence_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetUri(this: *mut core::ffi::c_void, uri: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFixedDocumentSequence", "GetUri", 3usize, |this| ((**(this as *const *const IFixedDocumentSequence_Vtbl)).GetUri)(this, uri), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrintTicket(this: *mut core::ffi::c_void, ppprintticket: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFixedDocumentSequence", "GetPrintTicket", 4usize, |this| ((**(this as *const *const IFixedDocumentSequence_Vtbl)).GetPrintTicket)(this, ppprintticket), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrintTicket(this: *mut core::ffi::c_void, pprintticket: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFixedDocumentSequence", "SetPrintTicket", 5usize, |this| ((**(this as *const *const IFixedDocumentSequence_Vtbl)).SetPrintTicket)(this, pprintticket), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetUri, GetPrintTicket, SetPrintTicket }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFixedDocumentSequence as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFixedPage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPrintTicket(this: *mut core::ffi::c_void, ppprintticket: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFixedPage", "GetPrintTicket", 7usize, |this| ((**(this as *const *const IFixedPage_Vtbl)).GetPrintTicket)(this, ppprintticket), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPagePart(this: *mut core::ffi::c_void, uri: windows_core::PCWSTR, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFixedPage", "GetPagePart", 8usize, |this| ((**(this as *const *const IFixedPage_Vtbl)).GetPagePart)(this, uri, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWriteStream(this: *mut core::ffi::c_void, ppwritestream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFixedPage", "GetWriteStream", 9usize, |this| ((**(this as *const *const IFixedPage_Vtbl)).GetWriteStream)(this, ppwritestream), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrintTicket(this: *mut core::ffi::c_void, ppprintticket: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFixedPage", "SetPrintTicket", 10usize, |this| ((**(this as *const *const IFixedPage_Vtbl)).SetPrintTicket)(this, ppprintticket), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPagePart(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFixedPage", "SetPagePart", 11usize, |this| ((**(this as *const *const IFixedPage_Vtbl)).SetPagePart)(this, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteResource(this: *mut core::ffi::c_void, uri: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFixedPage", "DeleteResource", 12usize, |this| ((**(this as *const *const IFixedPage_Vtbl)).DeleteResource)(this, uri), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetXpsPartIterator(this: *mut core::ffi::c_void, pxpspartit: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFixedPage", "GetXpsPartIterator", 13usize, |this| ((**(this as *const *const IFixedPage_Vtbl)).GetXpsPartIterator)(this, pxpspartit), |result| Some(*result))
+        }
+        Self {
+            base__: <IPartBase_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPrintTicket,
+            GetPagePart,
+            GetWriteStream,
+            SetPrintTicket,
+            SetPagePart,
+            DeleteResource,
+            GetXpsPartIterator,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFixedPage as windows_core::Interface>::IID || <IPartBase_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Ole")]
+impl windows_core::imp::ProxyVtable for IImgCreateErrorInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AttachToErrorInfo(this: *mut core::ffi::c_void, perrorinfo: *mut ImgErrorInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImgCreateErrorInfo", "AttachToErrorInfo", 8usize, |this| ((**(this as *const *const IImgCreateErrorInfo_Vtbl)).AttachToErrorInfo)(this, perrorinfo), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Ole::ICreateErrorInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AttachToErrorInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImgCreateErrorInfo as windows_core::Interface>::IID || <super::super::System::Ole::ICreateErrorInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IImgErrorInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeveloperDescription(this: *mut core::ffi::c_void, pbstrdevdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImgErrorInfo", "GetDeveloperDescription", 8usize, |this| ((**(this as *const *const IImgErrorInfo_Vtbl)).GetDeveloperDescription)(this, pbstrdevdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUserErrorId(this: *mut core::ffi::c_void, perrorid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImgErrorInfo", "GetUserErrorId", 9usize, |this| ((**(this as *const *const IImgErrorInfo_Vtbl)).GetUserErrorId)(this, perrorid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUserParameterCount(this: *mut core::ffi::c_void, pcuserparams: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImgErrorInfo", "GetUserParameterCount", 10usize, |this| ((**(this as *const *const IImgErrorInfo_Vtbl)).GetUserParameterCount)(this, pcuserparams), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUserParameter(this: *mut core::ffi::c_void, cparam: u32, pbstrparam: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImgErrorInfo", "GetUserParameter", 11usize, |this| ((**(this as *const *const IImgErrorInfo_Vtbl)).GetUserParameter)(this, cparam, pbstrparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUserFallback(this: *mut core::ffi::c_void, pbstrfallback: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImgErrorInfo", "GetUserFallback", 12usize, |this| ((**(this as *const *const IImgErrorInfo_Vtbl)).GetUserFallback)(this, pbstrfallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExceptionId(this: *mut core::ffi::c_void, pexceptionid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImgErrorInfo", "GetExceptionId", 13usize, |this| ((**(this as *const *const IImgErrorInfo_Vtbl)).GetExceptionId)(this, pexceptionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn DetachErrorInfo(this: *mut core::ffi::c_void, perrorinfo: *mut ImgErrorInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImgErrorInfo", "DetachErrorInfo", 14usize, |this| ((**(this as *const *const IImgErrorInfo_Vtbl)).DetachErrorInfo)(this, perrorinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IErrorInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDeveloperDescription,
+            GetUserErrorId,
+            GetUserParameterCount,
+            GetUserParameter,
+            GetUserFallback,
+            GetExceptionId,
+            DetachErrorInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImgErrorInfo as windows_core::Interface>::IID || <super::super::System::Com::IErrorInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInterFilterCommunicator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestReader(this: *mut core::ffi::c_void, ppireader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInterFilterCommunicator", "RequestReader", 3usize, |this| ((**(this as *const *const IInterFilterCommunicator_Vtbl)).RequestReader)(this, ppireader), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestWriter(this: *mut core::ffi::c_void, ppiwriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInterFilterCommunicator", "RequestWriter", 4usize, |this| ((**(this as *const *const IInterFilterCommunicator_Vtbl)).RequestWriter)(this, ppiwriter), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestReader, RequestWriter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInterFilterCommunicator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPartBase_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetUri(this: *mut core::ffi::c_void, uri: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPartBase", "GetUri", 3usize, |this| ((**(this as *const *const IPartBase_Vtbl)).GetUri)(this, uri), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStream(this: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPartBase", "GetStream", 4usize, |this| ((**(this as *const *const IPartBase_Vtbl)).GetStream)(this, ppstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPartCompression(this: *mut core::ffi::c_void, pcompression: *mut EXpsCompressionOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPartBase", "GetPartCompression", 5usize, |this| ((**(this as *const *const IPartBase_Vtbl)).GetPartCompression)(this, pcompression), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPartCompression(this: *mut core::ffi::c_void, compression: EXpsCompressionOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPartBase", "SetPartCompression", 6usize, |this| ((**(this as *const *const IPartBase_Vtbl)).SetPartCompression)(this, compression), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetUri, GetStream, GetPartCompression, SetPartCompression }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPartBase as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPartColorProfile_Vtbl {
+    const PROXY: Self = { Self { base__: <IPartBase_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPartColorProfile as windows_core::Interface>::IID || <IPartBase_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPartDiscardControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDiscardProperties(this: *mut core::ffi::c_void, urisentinelpage: *mut core::mem::MaybeUninit<windows_core::BSTR>, uriparttodiscard: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPartDiscardControl", "GetDiscardProperties", 3usize, |this| ((**(this as *const *const IPartDiscardControl_Vtbl)).GetDiscardProperties)(this, urisentinelpage, uriparttodiscard), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDiscardProperties }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPartDiscardControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPartFont_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFontProperties(this: *mut core::ffi::c_void, pcontenttype: *mut core::mem::MaybeUninit<windows_core::BSTR>, pfontoptions: *mut EXpsFontOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPartFont", "GetFontProperties", 7usize, |this| ((**(this as *const *const IPartFont_Vtbl)).GetFontProperties)(this, pcontenttype, pfontoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFontContent(this: *mut core::ffi::c_void, pcontenttype: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPartFont", "SetFontContent", 8usize, |this| ((**(this as *const *const IPartFont_Vtbl)).SetFontContent)(this, pcontenttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFontOptions(this: *mut core::ffi::c_void, options: EXpsFontOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPartFont", "SetFontOptions", 9usize, |this| ((**(this as *const *const IPartFont_Vtbl)).SetFontOptions)(this, options), |result| Some(*result))
+        }
+        Self { base__: <IPartBase_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFontProperties, SetFontContent, SetFontOptions }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPartFont as windows_core::Interface>::IID || <IPartBase_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPartFont2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFontRestriction(this: *mut core::ffi::c_void, prestriction: *mut EXpsFontRestriction) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPartFont2", "GetFontRestriction", 10usize, |this| ((**(this as *const *const IPartFont2_Vtbl)).GetFontRestriction)(this, prestriction), |result| Some(*result))
+        }
+        Self { base__: <IPartFont_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFontRestriction }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPartFont2 as windows_core::Interface>::IID || <IPartFont_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPartImage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetImageProperties(this: *mut core::ffi::c_void, pcontenttype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPartImage", "GetImageProperties", 7usize, |this| ((**(this as *const *const IPartImage_Vtbl)).GetImageProperties)(this, pcontenttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetImageContent(this: *mut core::ffi::c_void, pcontenttype: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPartImage", "SetImageContent", 8usize, |this| ((**(this as *const *const IPartImage_Vtbl)).SetImageContent)(this, pcontenttype), |result| Some(*result))
+        }
+        Self { base__: <IPartBase_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetImageProperties, SetImageContent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPartImage as windows_core::Interface>::IID || <IPartBase_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPartPrintTicket_Vtbl {
+    const PROXY: Self = { Self { base__: <IPartBase_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPartPrintTicket as windows_core::Interface>::IID || <IPartBase_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPartResourceDictionary_Vtbl {
+    const PROXY: Self = { Self { base__: <IPartBase_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPartResourceDictionary as windows_core::Interface>::IID || <IPartBase_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPartThumbnail_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetThumbnailProperties(this: *mut core::ffi::c_void, pcontenttype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPartThumbnail", "GetThumbnailProperties", 7usize, |this| ((**(this as *const *const IPartThumbnail_Vtbl)).GetThumbnailProperties)(this, pcontenttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetThumbnailContent(this: *mut core::ffi::c_void, pcontenttype: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPartThumbnail", "SetThumbnailContent", 8usize, |this| ((**(this as *const *const IPartThumbnail_Vtbl)).SetThumbnailContent)(this, pcontenttype), |result| Some(*result))
+        }
+        Self { base__: <IPartBase_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetThumbnailProperties, SetThumbnailContent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPartThumbnail as windows_core::Interface>::IID || <IPartBase_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintAsyncCookie_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FinishAsyncCall(this: *mut core::ffi::c_void, param0: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintAsyncCookie", "FinishAsyncCall", 3usize, |this| ((**(this as *const *const IPrintAsyncCookie_Vtbl)).FinishAsyncCall)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelAsyncCall(this: *mut core::ffi::c_void, param0: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintAsyncCookie", "CancelAsyncCall", 4usize, |this| ((**(this as *const *const IPrintAsyncCookie_Vtbl)).CancelAsyncCall)(this, param0), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FinishAsyncCall, CancelAsyncCall }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintAsyncCookie as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintAsyncNewChannelCookie_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FinishAsyncCallWithData(this: *mut core::ffi::c_void, param0: *const *mut core::ffi::c_void, param1: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintAsyncNewChannelCookie", "FinishAsyncCallWithData", 5usize, |this| ((**(this as *const *const IPrintAsyncNewChannelCookie_Vtbl)).FinishAsyncCallWithData)(this, param0, param1), |result| Some(*result))
+        }
+        Self { base__: <IPrintAsyncCookie_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FinishAsyncCallWithData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintAsyncNewChannelCookie as windows_core::Interface>::IID || <IPrintAsyncCookie_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintAsyncNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreatePrintAsyncNotifyChannel(this: *mut core::ffi::c_void, param0: u32, param1: *const windows_core::GUID, param2: PrintAsyncNotifyUserFilter, param3: PrintAsyncNotifyConversationStyle, param4: *mut core::ffi::c_void, param5: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintAsyncNotify", "CreatePrintAsyncNotifyChannel", 3usize, |this| ((**(this as *const *const IPrintAsyncNotify_Vtbl)).CreatePrintAsyncNotifyChannel)(this, param0, param1, param2, param3, param4, param5), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePrintAsyncNotifyRegistration(this: *mut core::ffi::c_void, param0: *const windows_core::GUID, param1: PrintAsyncNotifyUserFilter, param2: PrintAsyncNotifyConversationStyle, param3: *mut core::ffi::c_void, param4: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintAsyncNotify", "CreatePrintAsyncNotifyRegistration", 4usize, |this| ((**(this as *const *const IPrintAsyncNotify_Vtbl)).CreatePrintAsyncNotifyRegistration)(this, param0, param1, param2, param3, param4), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreatePrintAsyncNotifyChannel,
+            CreatePrintAsyncNotifyRegistration,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintAsyncNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintAsyncNotifyCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnEventNotify(this: *mut core::ffi::c_void, pchannel: *mut core::ffi::c_void, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintAsyncNotifyCallback", "OnEventNotify", 3usize, |this| ((**(this as *const *const IPrintAsyncNotifyCallback_Vtbl)).OnEventNotify)(this, pchannel, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChannelClosed(this: *mut core::ffi::c_void, pchannel: *mut core::ffi::c_void, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintAsyncNotifyCallback", "ChannelClosed", 4usize, |this| ((**(this as *const *const IPrintAsyncNotifyCallback_Vtbl)).ChannelClosed)(this, pchannel, pdata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnEventNotify, ChannelClosed }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintAsyncNotifyCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintAsyncNotifyChannel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SendNotification(this: *mut core::ffi::c_void, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintAsyncNotifyChannel", "SendNotification", 3usize, |this| ((**(this as *const *const IPrintAsyncNotifyChannel_Vtbl)).SendNotification)(this, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseChannel(this: *mut core::ffi::c_void, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintAsyncNotifyChannel", "CloseChannel", 4usize, |this| ((**(this as *const *const IPrintAsyncNotifyChannel_Vtbl)).CloseChannel)(this, pdata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SendNotification, CloseChannel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintAsyncNotifyChannel as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintAsyncNotifyDataObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AcquireData(this: *mut core::ffi::c_void, ppnotificationdata: *mut *mut u8, psize: *mut u32, ppschema: *mut *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintAsyncNotifyDataObject", "AcquireData", 3usize, |this| ((**(this as *const *const IPrintAsyncNotifyDataObject_Vtbl)).AcquireData)(this, ppnotificationdata, psize, ppschema), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseData(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintAsyncNotifyDataObject", "ReleaseData", 4usize, |this| ((**(this as *const *const IPrintAsyncNotifyDataObject_Vtbl)).ReleaseData)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AcquireData, ReleaseData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintAsyncNotifyDataObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintAsyncNotifyRegistration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterForNotifications(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintAsyncNotifyRegistration", "RegisterForNotifications", 3usize, |this| ((**(this as *const *const IPrintAsyncNotifyRegistration_Vtbl)).RegisterForNotifications)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterForNotifications(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintAsyncNotifyRegistration", "UnregisterForNotifications", 4usize, |this| ((**(this as *const *const IPrintAsyncNotifyRegistration_Vtbl)).UnregisterForNotifications)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterForNotifications, UnregisterForNotifications }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintAsyncNotifyRegistration as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintAsyncNotifyServerReferral_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetServerReferral(this: *mut core::ffi::c_void, param0: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintAsyncNotifyServerReferral", "GetServerReferral", 3usize, |this| ((**(this as *const *const IPrintAsyncNotifyServerReferral_Vtbl)).GetServerReferral)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn AsyncGetServerReferral(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintAsyncNotifyServerReferral", "AsyncGetServerReferral", 4usize, |this| ((**(this as *const *const IPrintAsyncNotifyServerReferral_Vtbl)).AsyncGetServerReferral)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServerReferral(this: *mut core::ffi::c_void, prmtserverreferral: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintAsyncNotifyServerReferral", "SetServerReferral", 5usize, |this| ((**(this as *const *const IPrintAsyncNotifyServerReferral_Vtbl)).SetServerReferral)(this, prmtserverreferral), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetServerReferral, AsyncGetServerReferral, SetServerReferral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintAsyncNotifyServerReferral as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintBidiAsyncNotifyRegistration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AsyncGetNewChannel(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintBidiAsyncNotifyRegistration", "AsyncGetNewChannel", 5usize, |this| ((**(this as *const *const IPrintBidiAsyncNotifyRegistration_Vtbl)).AsyncGetNewChannel)(this, param0), |result| Some(*result))
+        }
+        Self { base__: <IPrintAsyncNotifyRegistration_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AsyncGetNewChannel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintBidiAsyncNotifyRegistration as windows_core::Interface>::IID || <IPrintAsyncNotifyRegistration_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintClassObjectFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPrintClassObject(this: *mut core::ffi::c_void, pszprintername: windows_core::PCWSTR, riid: *const windows_core::GUID, ppnewobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintClassObjectFactory", "GetPrintClassObject", 3usize, |this| ((**(this as *const *const IPrintClassObjectFactory_Vtbl)).GetPrintClassObject)(this, pszprintername, riid, ppnewobject), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPrintClassObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintClassObjectFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IPrintCoreHelper_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetOption(this: *mut core::ffi::c_void, pdevmode: *const super::Gdi::DEVMODEA, cbsize: u32, pszfeaturerequested: windows_core::PCSTR, ppszoption: *mut windows_core::PCSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCoreHelper", "GetOption", 3usize, |this| ((**(this as *const *const IPrintCoreHelper_Vtbl)).GetOption)(this, pdevmode, cbsize, pszfeaturerequested, ppszoption), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn SetOptions(this: *mut core::ffi::c_void, pdevmode: *mut super::Gdi::DEVMODEA, cbsize: u32, bresolveconflicts: super::super::Foundation::BOOL, pfopairs: *const PRINT_FEATURE_OPTION, cpairs: u32, pcpairswritten: *mut u32, pdwresult: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCoreHelper", "SetOptions", 4usize, |this| ((**(this as *const *const IPrintCoreHelper_Vtbl)).SetOptions)(this, pdevmode, cbsize, bresolveconflicts, pfopairs, cpairs, pcpairswritten, pdwresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn EnumConstrainedOptions(this: *mut core::ffi::c_void, pdevmode: *const super::Gdi::DEVMODEA, cbsize: u32, pszfeaturekeyword: windows_core::PCSTR, pconstrainedoptionlist: *const *const *const windows_core::PCSTR, pdwnumoptions: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCoreHelper", "EnumConstrainedOptions", 5usize, |this| ((**(this as *const *const IPrintCoreHelper_Vtbl)).EnumConstrainedOptions)(this, pdevmode, cbsize, pszfeaturekeyword, pconstrainedoptionlist, pdwnumoptions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn WhyConstrained(this: *mut core::ffi::c_void, pdevmode: *const super::Gdi::DEVMODEA, cbsize: u32, pszfeaturekeyword: windows_core::PCSTR, pszoptionkeyword: windows_core::PCSTR, ppfoconstraints: *mut *mut PRINT_FEATURE_OPTION, pdwnumoptions: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCoreHelper", "WhyConstrained", 6usize, |this| ((**(this as *const *const IPrintCoreHelper_Vtbl)).WhyConstrained)(this, pdevmode, cbsize, pszfeaturekeyword, pszoptionkeyword, ppfoconstraints, pdwnumoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumFeatures(this: *mut core::ffi::c_void, pfeaturelist: *mut *mut *mut windows_core::PCSTR, pdwnumfeatures: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCoreHelper", "EnumFeatures", 7usize, |this| ((**(this as *const *const IPrintCoreHelper_Vtbl)).EnumFeatures)(this, pfeaturelist, pdwnumfeatures), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumOptions(this: *mut core::ffi::c_void, pszfeaturekeyword: windows_core::PCSTR, poptionlist: *mut *mut *mut windows_core::PCSTR, pdwnumoptions: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCoreHelper", "EnumOptions", 8usize, |this| ((**(this as *const *const IPrintCoreHelper_Vtbl)).EnumOptions)(this, pszfeaturekeyword, poptionlist, pdwnumoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontSubstitution(this: *mut core::ffi::c_void, psztruetypefontname: windows_core::PCWSTR, ppszdevfontname: *const windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCoreHelper", "GetFontSubstitution", 9usize, |this| ((**(this as *const *const IPrintCoreHelper_Vtbl)).GetFontSubstitution)(this, psztruetypefontname, ppszdevfontname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFontSubstitution(this: *mut core::ffi::c_void, psztruetypefontname: windows_core::PCWSTR, pszdevfontname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCoreHelper", "SetFontSubstitution", 10usize, |this| ((**(this as *const *const IPrintCoreHelper_Vtbl)).SetFontSubstitution)(this, psztruetypefontname, pszdevfontname), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInstanceOfMSXMLObject(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, punkouter: *mut core::ffi::c_void, dwclscontext: u32, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCoreHelper", "CreateInstanceOfMSXMLObject", 11usize, |this| ((**(this as *const *const IPrintCoreHelper_Vtbl)).CreateInstanceOfMSXMLObject)(this, rclsid, punkouter, dwclscontext, riid, ppv), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetOption,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetOption: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            SetOptions,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            SetOptions: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            EnumConstrainedOptions,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            EnumConstrainedOptions: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            WhyConstrained,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            WhyConstrained: 0,
+            EnumFeatures,
+            EnumOptions,
+            GetFontSubstitution,
+            SetFontSubstitution,
+            CreateInstanceOfMSXMLObject,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintCoreHelper as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IPrintCoreHelperPS_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetGlobalAttribute(this: *mut core::ffi::c_void, pszattribute: windows_core::PCSTR, pdwdatatype: *mut u32, ppbdata: *mut *mut u8, pcbsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCoreHelperPS", "GetGlobalAttribute", 12usize, |this| ((**(this as *const *const IPrintCoreHelperPS_Vtbl)).GetGlobalAttribute)(this, pszattribute, pdwdatatype, ppbdata, pcbsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFeatureAttribute(this: *mut core::ffi::c_void, pszfeaturekeyword: windows_core::PCSTR, pszattribute: windows_core::PCSTR, pdwdatatype: *mut u32, ppbdata: *mut *mut u8, pcbsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCoreHelperPS", "GetFeatureAttribute", 13usize, |this| ((**(this as *const *const IPrintCoreHelperPS_Vtbl)).GetFeatureAttribute)(this, pszfeaturekeyword, pszattribute, pdwdatatype, ppbdata, pcbsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOptionAttribute(this: *mut core::ffi::c_void, pszfeaturekeyword: windows_core::PCSTR, pszoptionkeyword: windows_core::PCSTR, pszattribute: windows_core::PCSTR, pdwdatatype: *mut u32, ppbdata: *mut *mut u8, pcbsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCoreHelperPS", "GetOptionAttribute", 14usize, |this| ((**(this as *const *const IPrintCoreHelperPS_Vtbl)).GetOptionAttribute)(this, pszfeaturekeyword, pszoptionkeyword, pszattribute, pdwdatatype, ppbdata, pcbsize), |result| Some(*result))
+        }
+        Self { base__: <IPrintCoreHelper_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetGlobalAttribute, GetFeatureAttribute, GetOptionAttribute }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintCoreHelperPS as windows_core::Interface>::IID || <IPrintCoreHelper_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IPrintCoreHelperUni_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn CreateGDLSnapshot(this: *mut core::ffi::c_void, pdevmode: *mut super::Gdi::DEVMODEA, cbsize: u32, dwflags: u32, ppsnapshotstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCoreHelperUni", "CreateGDLSnapshot", 12usize, |this| ((**(this as *const *const IPrintCoreHelperUni_Vtbl)).CreateGDLSnapshot)(this, pdevmode, cbsize, dwflags, ppsnapshotstream), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateDefaultGDLSnapshot(this: *mut core::ffi::c_void, dwflags: u32, ppsnapshotstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCoreHelperUni", "CreateDefaultGDLSnapshot", 13usize, |this| ((**(this as *const *const IPrintCoreHelperUni_Vtbl)).CreateDefaultGDLSnapshot)(this, dwflags, ppsnapshotstream), |result| Some(*result))
+        }
+        Self {
+            base__: <IPrintCoreHelper_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+            CreateGDLSnapshot,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com")))]
+            CreateGDLSnapshot: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateDefaultGDLSnapshot,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateDefaultGDLSnapshot: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintCoreHelperUni as windows_core::Interface>::IID || <IPrintCoreHelper_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IPrintCoreHelperUni2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetNamedCommand(this: *mut core::ffi::c_void, pdevmode: *const super::Gdi::DEVMODEA, cbsize: u32, pszcommandname: windows_core::PCWSTR, ppcommandbytes: *mut *mut u8, pcbcommandsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCoreHelperUni2", "GetNamedCommand", 14usize, |this| ((**(this as *const *const IPrintCoreHelperUni2_Vtbl)).GetNamedCommand)(this, pdevmode, cbsize, pszcommandname, ppcommandbytes, pcbcommandsize), |result| Some(*result))
+        }
+        Self {
+            base__: <IPrintCoreHelperUni_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetNamedCommand,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetNamedCommand: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintCoreHelperUni2 as windows_core::Interface>::IID || <IPrintCoreHelperUni_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintCoreUI2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOptions(this: *mut core::ffi::c_void, poemuiobj: *const OEMUIOBJ, dwflags: u32, pmszfeaturesrequested: *const i8, cbin: u32, pmszfeatureoptionbuf: windows_core::PSTR, cbsize: u32, pcbneeded: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCoreUI2", "GetOptions", 6usize, |this| ((**(this as *const *const IPrintCoreUI2_Vtbl)).GetOptions)(this, poemuiobj, dwflags, pmszfeaturesrequested, cbin, pmszfeatureoptionbuf, cbsize, pcbneeded), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOptions(this: *mut core::ffi::c_void, poemuiobj: *const OEMUIOBJ, dwflags: u32, pmszfeatureoptionbuf: *const i8, cbin: u32, pdwresult: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCoreUI2", "SetOptions", 7usize, |this| ((**(this as *const *const IPrintCoreUI2_Vtbl)).SetOptions)(this, poemuiobj, dwflags, pmszfeatureoptionbuf, cbin, pdwresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumConstrainedOptions(this: *mut core::ffi::c_void, poemuiobj: *const OEMUIOBJ, dwflags: u32, pszfeaturekeyword: windows_core::PCSTR, pmszconstrainedoptionlist: windows_core::PSTR, cbsize: u32, pcbneeded: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCoreUI2", "EnumConstrainedOptions", 8usize, |this| ((**(this as *const *const IPrintCoreUI2_Vtbl)).EnumConstrainedOptions)(this, poemuiobj, dwflags, pszfeaturekeyword, pmszconstrainedoptionlist, cbsize, pcbneeded), |result| Some(*result))
+        }
+        unsafe extern "system" fn WhyConstrained(this: *mut core::ffi::c_void, poemuiobj: *const OEMUIOBJ, dwflags: u32, pszfeaturekeyword: windows_core::PCSTR, pszoptionkeyword: windows_core::PCSTR, pmszreasonlist: windows_core::PSTR, cbsize: u32, pcbneeded: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCoreUI2", "WhyConstrained", 9usize, |this| ((**(this as *const *const IPrintCoreUI2_Vtbl)).WhyConstrained)(this, poemuiobj, dwflags, pszfeaturekeyword, pszoptionkeyword, pmszreasonlist, cbsize, pcbneeded), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGlobalAttribute(this: *mut core::ffi::c_void, poemuiobj: *const OEMUIOBJ, dwflags: u32, pszattribute: windows_core::PCSTR, pdwdatatype: *mut u32, pbdata: *mut u8, cbsize: u32, pcbneeded: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCoreUI2", "GetGlobalAttribute", 10usize, |this| ((**(this as *const *const IPrintCoreUI2_Vtbl)).GetGlobalAttribute)(this, poemuiobj, dwflags, pszattribute, pdwdatatype, pbdata, cbsize, pcbneeded), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFeatureAttribute(this: *mut core::ffi::c_void, poemuiobj: *const OEMUIOBJ, dwflags: u32, pszfeaturekeyword: windows_core::PCSTR, pszattribute: windows_core::PCSTR, pdwdatatype: *mut u32, pbdata: *mut u8, cbsize: u32, pcbneeded: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCoreUI2", "GetFeatureAttribute", 11usize, |this| ((**(this as *const *const IPrintCoreUI2_Vtbl)).GetFeatureAttribute)(this, poemuiobj, dwflags, pszfeaturekeyword, pszattribute, pdwdatatype, pbdata, cbsize, pcbneeded), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOptionAttribute(this: *mut core::ffi::c_void, poemuiobj: *const OEMUIOBJ, dwflags: u32, pszfeaturekeyword: windows_core::PCSTR, pszoptionkeyword: windows_core::PCSTR, pszattribute: windows_core::PCSTR, pdwdatatype: *mut u32, pbdata: *mut u8, cbsize: u32, pcbneeded: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCoreUI2", "GetOptionAttribute", 12usize, |this| ((**(this as *const *const IPrintCoreUI2_Vtbl)).GetOptionAttribute)(this, poemuiobj, dwflags, pszfeaturekeyword, pszoptionkeyword, pszattribute, pdwdatatype, pbdata, cbsize, pcbneeded), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumFeatures(this: *mut core::ffi::c_void, poemuiobj: *const OEMUIOBJ, dwflags: u32, pmszfeaturelist: windows_core::PSTR, cbsize: u32, pcbneeded: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCoreUI2", "EnumFeatures", 13usize, |this| ((**(this as *const *const IPrintCoreUI2_Vtbl)).EnumFeatures)(this, poemuiobj, dwflags, pmszfeaturelist, cbsize, pcbneeded), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumOptions(this: *mut core::ffi::c_void, poemuiobj: *const OEMUIOBJ, dwflags: u32, pszfeaturekeyword: windows_core::PCSTR, pmszoptionlist: windows_core::PSTR, cbsize: u32, pcbneeded: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCoreUI2", "EnumOptions", 14usize, |this| ((**(this as *const *const IPrintCoreUI2_Vtbl)).EnumOptions)(this, poemuiobj, dwflags, pszfeaturekeyword, pmszoptionlist, cbsize, pcbneeded), |result| Some(*result))
+        }
+        unsafe extern "system" fn QuerySimulationSupport(this: *mut core::ffi::c_void, hprinter: super::super::Foundation::HANDLE, dwlevel: u32, pcaps: *mut u8, cbsize: u32, pcbneeded: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintCoreUI2", "QuerySimulationSupport", 15usize, |this| ((**(this as *const *const IPrintCoreUI2_Vtbl)).QuerySimulationSupport)(this, hprinter, dwlevel, pcaps, cbsize, pcbneeded), |result| Some(*result))
+        }
+        Self {
+            base__: <IPrintOemDriverUI_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetOptions,
+            SetOptions,
+            EnumConstrainedOptions,
+            WhyConstrained,
+            GetGlobalAttribute,
+            GetFeatureAttribute,
+            GetOptionAttribute,
+            EnumFeatures,
+            EnumOptions,
+            QuerySimulationSupport,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintCoreUI2 as windows_core::Interface>::IID || <IPrintOemDriverUI_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintJob_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintJob", "Name", 3usize, |this| ((**(this as *const *const IPrintJob_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, pulid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintJob", "Id", 4usize, |this| ((**(this as *const *const IPrintJob_Vtbl)).Id)(this, pulid), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrintedPages(this: *mut core::ffi::c_void, pulpages: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintJob", "PrintedPages", 5usize, |this| ((**(this as *const *const IPrintJob_Vtbl)).PrintedPages)(this, pulpages), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalPages(this: *mut core::ffi::c_void, pulpages: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintJob", "TotalPages", 6usize, |this| ((**(this as *const *const IPrintJob_Vtbl)).TotalPages)(this, pulpages), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, pstatus: *mut PrintJobStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintJob", "Status", 7usize, |this| ((**(this as *const *const IPrintJob_Vtbl)).Status)(this, pstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubmissionTime(this: *mut core::ffi::c_void, psubmissiontime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintJob", "SubmissionTime", 8usize, |this| ((**(this as *const *const IPrintJob_Vtbl)).SubmissionTime)(this, psubmissiontime), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestCancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintJob", "RequestCancel", 9usize, |this| ((**(this as *const *const IPrintJob_Vtbl)).RequestCancel)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            Id,
+            PrintedPages,
+            TotalPages,
+            Status,
+            SubmissionTime,
+            RequestCancel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintJob as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrintJobCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pulcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintJobCollection", "Count", 7usize, |this| ((**(this as *const *const IPrintJobCollection_Vtbl)).Count)(this, pulcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, ulindex: u32, ppjob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintJobCollection", "GetAt", 8usize, |this| ((**(this as *const *const IPrintJobCollection_Vtbl)).GetAt)(this, ulindex, ppjob), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintJobCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const IPrintJobCollection_Vtbl))._NewEnum)(this, ppunk), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Count, GetAt, _NewEnum }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintJobCollection as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IPrintOemCommon_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInfo(this: *mut core::ffi::c_void, dwmode: u32, pbuffer: *mut core::ffi::c_void, cbsize: u32, pcbneeded: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOemCommon", "GetInfo", 3usize, |this| ((**(this as *const *const IPrintOemCommon_Vtbl)).GetInfo)(this, dwmode, pbuffer, cbsize, pcbneeded), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn DevMode(this: *mut core::ffi::c_void, dwmode: u32, poemdmparam: *mut OEMDMPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOemCommon", "DevMode", 4usize, |this| ((**(this as *const *const IPrintOemCommon_Vtbl)).DevMode)(this, dwmode, poemdmparam), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetInfo,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            DevMode,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            DevMode: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintOemCommon as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintOemDriverUI_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DrvGetDriverSetting(this: *mut core::ffi::c_void, pci: *mut core::ffi::c_void, feature: windows_core::PCSTR, poutput: *mut core::ffi::c_void, cbsize: u32, pcbneeded: *mut u32, pdwoptionsreturned: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOemDriverUI", "DrvGetDriverSetting", 3usize, |this| ((**(this as *const *const IPrintOemDriverUI_Vtbl)).DrvGetDriverSetting)(this, pci, feature, poutput, cbsize, pcbneeded, pdwoptionsreturned), |result| Some(*result))
+        }
+        unsafe extern "system" fn DrvUpgradeRegistrySetting(this: *mut core::ffi::c_void, hprinter: super::super::Foundation::HANDLE, pfeature: windows_core::PCSTR, poption: windows_core::PCSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOemDriverUI", "DrvUpgradeRegistrySetting", 4usize, |this| ((**(this as *const *const IPrintOemDriverUI_Vtbl)).DrvUpgradeRegistrySetting)(this, hprinter, pfeature, poption), |result| Some(*result))
+        }
+        unsafe extern "system" fn DrvUpdateUISetting(this: *mut core::ffi::c_void, pci: *mut core::ffi::c_void, poptitem: *mut core::ffi::c_void, dwpreviousselection: u32, dwmode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOemDriverUI", "DrvUpdateUISetting", 5usize, |this| ((**(this as *const *const IPrintOemDriverUI_Vtbl)).DrvUpdateUISetting)(this, pci, poptitem, dwpreviousselection, dwmode), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DrvGetDriverSetting,
+            DrvUpgradeRegistrySetting,
+            DrvUpdateUISetting,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintOemDriverUI as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+impl windows_core::imp::ProxyVtable for IPrintOemUI_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PublishDriverInterface(this: *mut core::ffi::c_void, piunknown: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOemUI", "PublishDriverInterface", 5usize, |this| ((**(this as *const *const IPrintOemUI_Vtbl)).PublishDriverInterface)(this, piunknown), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+        unsafe extern "system" fn CommonUIProp(this: *mut core::ffi::c_void, dwmode: u32, poemcuipparam: *const OEMCUIPPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOemUI", "CommonUIProp", 6usize, |this| ((**(this as *const *const IPrintOemUI_Vtbl)).CommonUIProp)(this, dwmode, poemcuipparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn DocumentPropertySheets(this: *mut core::ffi::c_void, ppsuiinfo: *mut PROPSHEETUI_INFO, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOemUI", "DocumentPropertySheets", 7usize, |this| ((**(this as *const *const IPrintOemUI_Vtbl)).DocumentPropertySheets)(this, ppsuiinfo, lparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn DevicePropertySheets(this: *mut core::ffi::c_void, ppsuiinfo: *const PROPSHEETUI_INFO, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOemUI", "DevicePropertySheets", 8usize, |this| ((**(this as *const *const IPrintOemUI_Vtbl)).DevicePropertySheets)(this, ppsuiinfo, lparam), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn DevQueryPrintEx(this: *mut core::ffi::c_void, poemuiobj: *const OEMUIOBJ, pdqpinfo: *const DEVQUERYPRINT_INFO, ppublicdm: *const super::Gdi::DEVMODEA, poemdm: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOemUI", "DevQueryPrintEx", 9usize, |this| ((**(this as *const *const IPrintOemUI_Vtbl)).DevQueryPrintEx)(this, poemuiobj, pdqpinfo, ppublicdm, poemdm), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn DeviceCapabilitiesA(this: *mut core::ffi::c_void, poemuiobj: *mut OEMUIOBJ, hprinter: super::super::Foundation::HANDLE, pdevicename: windows_core::PCWSTR, wcapability: u16, poutput: *mut core::ffi::c_void, ppublicdm: *const super::Gdi::DEVMODEA, poemdm: *const core::ffi::c_void, dwold: u32, dwresult: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOemUI", "DeviceCapabilitiesA", 10usize, |this| ((**(this as *const *const IPrintOemUI_Vtbl)).DeviceCapabilitiesA)(this, poemuiobj, hprinter, pdevicename, wcapability, poutput, ppublicdm, poemdm, dwold, dwresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpgradePrinter(this: *mut core::ffi::c_void, dwlevel: u32, pdriverupgradeinfo: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOemUI", "UpgradePrinter", 11usize, |this| ((**(this as *const *const IPrintOemUI_Vtbl)).UpgradePrinter)(this, dwlevel, pdriverupgradeinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrinterEvent(this: *mut core::ffi::c_void, pprintername: windows_core::PCWSTR, idriverevent: i32, dwflags: u32, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOemUI", "PrinterEvent", 12usize, |this| ((**(this as *const *const IPrintOemUI_Vtbl)).PrinterEvent)(this, pprintername, idriverevent, dwflags, lparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn DriverEvent(this: *mut core::ffi::c_void, dwdriverevent: u32, dwlevel: u32, pdriverinfo: *const u8, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOemUI", "DriverEvent", 13usize, |this| ((**(this as *const *const IPrintOemUI_Vtbl)).DriverEvent)(this, dwdriverevent, dwlevel, pdriverinfo, lparam), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn QueryColorProfile(this: *mut core::ffi::c_void, hprinter: super::super::Foundation::HANDLE, poemuiobj: *const OEMUIOBJ, ppublicdm: *const super::Gdi::DEVMODEA, poemdm: *const core::ffi::c_void, ulquerymode: u32, pvprofiledata: *mut core::ffi::c_void, pcbprofiledata: *mut u32, pflprofiledata: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOemUI", "QueryColorProfile", 14usize, |this| ((**(this as *const *const IPrintOemUI_Vtbl)).QueryColorProfile)(this, hprinter, poemuiobj, ppublicdm, poemdm, ulquerymode, pvprofiledata, pcbprofiledata, pflprofiledata), |result| Some(*result))
+        }
+        unsafe extern "system" fn FontInstallerDlgProc(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, usmsg: u32, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOemUI", "FontInstallerDlgProc", 15usize, |this| ((**(this as *const *const IPrintOemUI_Vtbl)).FontInstallerDlgProc)(this, hwnd, usmsg, wparam, lparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateExternalFonts(this: *mut core::ffi::c_void, hprinter: super::super::Foundation::HANDLE, hheap: super::super::Foundation::HANDLE, pwstrcartridges: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOemUI", "UpdateExternalFonts", 16usize, |this| ((**(this as *const *const IPrintOemUI_Vtbl)).UpdateExternalFonts)(this, hprinter, hheap, pwstrcartridges), |result| Some(*result))
+        }
+        Self {
+            base__: <IPrintOemCommon_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PublishDriverInterface,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+            CommonUIProp,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging")))]
+            CommonUIProp: 0,
+            DocumentPropertySheets,
+            DevicePropertySheets,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            DevQueryPrintEx,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            DevQueryPrintEx: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            DeviceCapabilitiesA,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            DeviceCapabilitiesA: 0,
+            UpgradePrinter,
+            PrinterEvent,
+            DriverEvent,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            QueryColorProfile,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            QueryColorProfile: 0,
+            FontInstallerDlgProc,
+            UpdateExternalFonts,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintOemUI as windows_core::Interface>::IID || <IPrintOemCommon_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+impl windows_core::imp::ProxyVtable for IPrintOemUI2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn QueryJobAttributes(this: *mut core::ffi::c_void, hprinter: super::super::Foundation::HANDLE, pdevmode: *const super::Gdi::DEVMODEA, dwlevel: u32, lpattributeinfo: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOemUI2", "QueryJobAttributes", 17usize, |this| ((**(this as *const *const IPrintOemUI2_Vtbl)).QueryJobAttributes)(this, hprinter, pdevmode, dwlevel, lpattributeinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn HideStandardUI(this: *mut core::ffi::c_void, dwmode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOemUI2", "HideStandardUI", 18usize, |this| ((**(this as *const *const IPrintOemUI2_Vtbl)).HideStandardUI)(this, dwmode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn DocumentEvent(this: *mut core::ffi::c_void, hprinter: super::super::Foundation::HANDLE, hdc: super::Gdi::HDC, iesc: i32, cbin: u32, pvin: *mut core::ffi::c_void, cbout: u32, pvout: *mut core::ffi::c_void, piresult: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOemUI2", "DocumentEvent", 19usize, |this| ((**(this as *const *const IPrintOemUI2_Vtbl)).DocumentEvent)(this, hprinter, hdc, iesc, cbin, pvin, cbout, pvout, piresult), |result| Some(*result))
+        }
+        Self {
+            base__: <IPrintOemUI_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            QueryJobAttributes,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            QueryJobAttributes: 0,
+            HideStandardUI,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            DocumentEvent,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            DocumentEvent: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintOemUI2 as windows_core::Interface>::IID || <IPrintOemUI_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IPrintOemUIMXDC_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn AdjustImageableArea(this: *mut core::ffi::c_void, hprinter: super::super::Foundation::HANDLE, cbdevmode: u32, pdevmode: *const super::Gdi::DEVMODEA, cboemdm: u32, poemdm: *const core::ffi::c_void, prclimageablearea: *mut super::super::Foundation::RECTL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOemUIMXDC", "AdjustImageableArea", 3usize, |this| ((**(this as *const *const IPrintOemUIMXDC_Vtbl)).AdjustImageableArea)(this, hprinter, cbdevmode, pdevmode, cboemdm, poemdm, prclimageablearea), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn AdjustImageCompression(this: *mut core::ffi::c_void, hprinter: super::super::Foundation::HANDLE, cbdevmode: u32, pdevmode: *const super::Gdi::DEVMODEA, cboemdm: u32, poemdm: *const core::ffi::c_void, pcompressionmode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOemUIMXDC", "AdjustImageCompression", 4usize, |this| ((**(this as *const *const IPrintOemUIMXDC_Vtbl)).AdjustImageCompression)(this, hprinter, cbdevmode, pdevmode, cboemdm, poemdm, pcompressionmode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn AdjustDPI(this: *mut core::ffi::c_void, hprinter: super::super::Foundation::HANDLE, cbdevmode: u32, pdevmode: *const super::Gdi::DEVMODEA, cboemdm: u32, poemdm: *const core::ffi::c_void, pdpi: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintOemUIMXDC", "AdjustDPI", 5usize, |this| ((**(this as *const *const IPrintOemUIMXDC_Vtbl)).AdjustDPI)(this, hprinter, cbdevmode, pdevmode, cboemdm, poemdm, pdpi), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            AdjustImageableArea,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            AdjustImageableArea: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            AdjustImageCompression,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            AdjustImageCompression: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            AdjustDPI,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            AdjustDPI: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintOemUIMXDC as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintPipelineFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeFilter(this: *mut core::ffi::c_void, pinegotiation: *mut core::ffi::c_void, pipropertybag: *mut core::ffi::c_void, pipipelinecontrol: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPipelineFilter", "InitializeFilter", 3usize, |this| ((**(this as *const *const IPrintPipelineFilter_Vtbl)).InitializeFilter)(this, pinegotiation, pipropertybag, pipipelinecontrol), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShutdownOperation(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPipelineFilter", "ShutdownOperation", 4usize, |this| ((**(this as *const *const IPrintPipelineFilter_Vtbl)).ShutdownOperation)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartOperation(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPipelineFilter", "StartOperation", 5usize, |this| ((**(this as *const *const IPrintPipelineFilter_Vtbl)).StartOperation)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InitializeFilter, ShutdownOperation, StartOperation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintPipelineFilter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrintPipelineManagerControl_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RequestShutdown(this: *mut core::ffi::c_void, hrreason: windows_core::HRESULT, preason: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPipelineManagerControl", "RequestShutdown", 3usize, |this| ((**(this as *const *const IPrintPipelineManagerControl_Vtbl)).RequestShutdown)(this, hrreason, preason), |result| Some(*result))
+        }
+        unsafe extern "system" fn FilterFinished(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPipelineManagerControl", "FilterFinished", 4usize, |this| ((**(this as *const *const IPrintPipelineManagerControl_Vtbl)).FilterFinished)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            RequestShutdown,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RequestShutdown: 0,
+            FilterFinished,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintPipelineManagerControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintPipelineProgressReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportProgress(this: *mut core::ffi::c_void, update: EXpsJobConsumption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPipelineProgressReport", "ReportProgress", 3usize, |this| ((**(this as *const *const IPrintPipelineProgressReport_Vtbl)).ReportProgress)(this, update), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReportProgress }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintPipelineProgressReport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintPipelinePropertyBag_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddProperty(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, pvar: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPipelinePropertyBag", "AddProperty", 3usize, |this| ((**(this as *const *const IPrintPipelinePropertyBag_Vtbl)).AddProperty)(this, pszname, pvar), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, pvar: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPipelinePropertyBag", "GetProperty", 4usize, |this| ((**(this as *const *const IPrintPipelinePropertyBag_Vtbl)).GetProperty)(this, pszname, pvar), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteProperty(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IPrintPipelinePropertyBag", "DeleteProperty", 5usize, |this| ((**(this as *const *const IPrintPipelinePropertyBag_Vtbl)).DeleteProperty)(this, pszname), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddProperty, GetProperty, DeleteProperty }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintPipelinePropertyBag as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi")]
+impl windows_core::imp::ProxyVtable for IPrintPreviewDxgiPackageTarget_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetJobPageCount(this: *mut core::ffi::c_void, counttype: PageCountType, count: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPreviewDxgiPackageTarget", "SetJobPageCount", 3usize, |this| ((**(this as *const *const IPrintPreviewDxgiPackageTarget_Vtbl)).SetJobPageCount)(this, counttype, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi")]
+        unsafe extern "system" fn DrawPage(this: *mut core::ffi::c_void, jobpagenumber: u32, pageimage: *mut core::ffi::c_void, dpix: f32, dpiy: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPreviewDxgiPackageTarget", "DrawPage", 4usize, |this| ((**(this as *const *const IPrintPreviewDxgiPackageTarget_Vtbl)).DrawPage)(this, jobpagenumber, pageimage, dpix, dpiy), |result| Some(*result))
+        }
+        unsafe extern "system" fn InvalidatePreview(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPreviewDxgiPackageTarget", "InvalidatePreview", 5usize, |this| ((**(this as *const *const IPrintPreviewDxgiPackageTarget_Vtbl)).InvalidatePreview)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetJobPageCount,
+            #[cfg(feature = "Win32_Graphics_Dxgi")]
+            DrawPage,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi"))]
+            DrawPage: 0,
+            InvalidatePreview,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintPreviewDxgiPackageTarget as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintReadStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Seek(this: *mut core::ffi::c_void, dlibmove: i64, dworigin: u32, plibnewposition: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintReadStream", "Seek", 3usize, |this| ((**(this as *const *const IPrintReadStream_Vtbl)).Seek)(this, dlibmove, dworigin, plibnewposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadBytes(this: *mut core::ffi::c_void, pvbuffer: *mut core::ffi::c_void, cbrequested: u32, pcbread: *mut u32, pbendoffile: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintReadStream", "ReadBytes", 4usize, |this| ((**(this as *const *const IPrintReadStream_Vtbl)).ReadBytes)(this, pvbuffer, cbrequested, pcbread, pbendoffile), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Seek, ReadBytes }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintReadStream as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintReadStreamFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStream(this: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintReadStreamFactory", "GetStream", 3usize, |this| ((**(this as *const *const IPrintReadStreamFactory_Vtbl)).GetStream)(this, ppstream), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStream }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintReadStreamFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrintSchemaAsyncOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaAsyncOperation", "Start", 7usize, |this| ((**(this as *const *const IPrintSchemaAsyncOperation_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaAsyncOperation", "Cancel", 8usize, |this| ((**(this as *const *const IPrintSchemaAsyncOperation_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Start, Cancel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSchemaAsyncOperation as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrintSchemaAsyncOperationEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Completed(this: *mut core::ffi::c_void, pticket: *mut core::ffi::c_void, hroperation: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaAsyncOperationEvent", "Completed", 7usize, |this| ((**(this as *const *const IPrintSchemaAsyncOperationEvent_Vtbl)).Completed)(this, pticket, hroperation), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Completed,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Completed: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSchemaAsyncOperationEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrintSchemaCapabilities_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFeatureByKeyName(this: *mut core::ffi::c_void, bstrkeyname: core::mem::MaybeUninit<windows_core::BSTR>, ppfeature: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaCapabilities", "GetFeatureByKeyName", 10usize, |this| ((**(this as *const *const IPrintSchemaCapabilities_Vtbl)).GetFeatureByKeyName)(this, bstrkeyname, ppfeature), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFeature(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, bstrnamespaceuri: core::mem::MaybeUninit<windows_core::BSTR>, ppfeature: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaCapabilities", "GetFeature", 11usize, |this| ((**(this as *const *const IPrintSchemaCapabilities_Vtbl)).GetFeature)(this, bstrname, bstrnamespaceuri, ppfeature), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PageImageableSize(this: *mut core::ffi::c_void, pppageimageablesize: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaCapabilities", "PageImageableSize", 12usize, |this| ((**(this as *const *const IPrintSchemaCapabilities_Vtbl)).PageImageableSize)(this, pppageimageablesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn JobCopiesAllDocumentsMinValue(this: *mut core::ffi::c_void, puljobcopiesalldocumentsminvalue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaCapabilities", "JobCopiesAllDocumentsMinValue", 13usize, |this| ((**(this as *const *const IPrintSchemaCapabilities_Vtbl)).JobCopiesAllDocumentsMinValue)(this, puljobcopiesalldocumentsminvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn JobCopiesAllDocumentsMaxValue(this: *mut core::ffi::c_void, puljobcopiesalldocumentsmaxvalue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaCapabilities", "JobCopiesAllDocumentsMaxValue", 14usize, |this| ((**(this as *const *const IPrintSchemaCapabilities_Vtbl)).JobCopiesAllDocumentsMaxValue)(this, puljobcopiesalldocumentsmaxvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSelectedOptionInPrintTicket(this: *mut core::ffi::c_void, pfeature: *mut core::ffi::c_void, ppoption: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaCapabilities", "GetSelectedOptionInPrintTicket", 15usize, |this| ((**(this as *const *const IPrintSchemaCapabilities_Vtbl)).GetSelectedOptionInPrintTicket)(this, pfeature, ppoption), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetOptions(this: *mut core::ffi::c_void, pfeature: *mut core::ffi::c_void, ppoptioncollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaCapabilities", "GetOptions", 16usize, |this| ((**(this as *const *const IPrintSchemaCapabilities_Vtbl)).GetOptions)(this, pfeature, ppoptioncollection), |result| Some(*result))
+        }
+        Self {
+            base__: <IPrintSchemaElement_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFeatureByKeyName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFeatureByKeyName: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFeature,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFeature: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PageImageableSize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PageImageableSize: 0,
+            JobCopiesAllDocumentsMinValue,
+            JobCopiesAllDocumentsMaxValue,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSelectedOptionInPrintTicket,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSelectedOptionInPrintTicket: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetOptions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetOptions: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSchemaCapabilities as windows_core::Interface>::IID || <IPrintSchemaElement_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrintSchemaCapabilities2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetParameterDefinition(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, bstrnamespaceuri: core::mem::MaybeUninit<windows_core::BSTR>, ppparameterdefinition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaCapabilities2", "GetParameterDefinition", 17usize, |this| ((**(this as *const *const IPrintSchemaCapabilities2_Vtbl)).GetParameterDefinition)(this, bstrname, bstrnamespaceuri, ppparameterdefinition), |result| Some(*result))
+        }
+        Self {
+            base__: <IPrintSchemaCapabilities_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetParameterDefinition,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetParameterDefinition: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSchemaCapabilities2 as windows_core::Interface>::IID || <IPrintSchemaCapabilities_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrintSchemaDisplayableElement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, pbstrdisplayname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaDisplayableElement", "DisplayName", 10usize, |this| ((**(this as *const *const IPrintSchemaDisplayableElement_Vtbl)).DisplayName)(this, pbstrdisplayname), |result| Some(*result))
+        }
+        Self { base__: <IPrintSchemaElement_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DisplayName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSchemaDisplayableElement as windows_core::Interface>::IID || <IPrintSchemaElement_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrintSchemaElement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn XmlNode(this: *mut core::ffi::c_void, ppxmlnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaElement", "XmlNode", 7usize, |this| ((**(this as *const *const IPrintSchemaElement_Vtbl)).XmlNode)(this, ppxmlnode), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaElement", "Name", 8usize, |this| ((**(this as *const *const IPrintSchemaElement_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn NamespaceUri(this: *mut core::ffi::c_void, pbstrnamespaceuri: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaElement", "NamespaceUri", 9usize, |this| ((**(this as *const *const IPrintSchemaElement_Vtbl)).NamespaceUri)(this, pbstrnamespaceuri), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, XmlNode, Name, NamespaceUri }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSchemaElement as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrintSchemaFeature_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SelectedOption(this: *mut core::ffi::c_void, ppoption: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaFeature", "SelectedOption", 11usize, |this| ((**(this as *const *const IPrintSchemaFeature_Vtbl)).SelectedOption)(this, ppoption), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSelectedOption(this: *mut core::ffi::c_void, poption: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaFeature", "SetSelectedOption", 12usize, |this| ((**(this as *const *const IPrintSchemaFeature_Vtbl)).SetSelectedOption)(this, poption), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectionType(this: *mut core::ffi::c_void, pselectiontype: *mut PrintSchemaSelectionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaFeature", "SelectionType", 13usize, |this| ((**(this as *const *const IPrintSchemaFeature_Vtbl)).SelectionType)(this, pselectiontype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetOption(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, bstrnamespaceuri: core::mem::MaybeUninit<windows_core::BSTR>, ppoption: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaFeature", "GetOption", 14usize, |this| ((**(this as *const *const IPrintSchemaFeature_Vtbl)).GetOption)(this, bstrname, bstrnamespaceuri, ppoption), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayUI(this: *mut core::ffi::c_void, pbshow: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaFeature", "DisplayUI", 15usize, |this| ((**(this as *const *const IPrintSchemaFeature_Vtbl)).DisplayUI)(this, pbshow), |result| Some(*result))
+        }
+        Self {
+            base__: <IPrintSchemaDisplayableElement_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            SelectedOption,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SelectedOption: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSelectedOption,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSelectedOption: 0,
+            SelectionType,
+            #[cfg(feature = "Win32_System_Com")]
+            GetOption,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetOption: 0,
+            DisplayUI,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSchemaFeature as windows_core::Interface>::IID || <IPrintSchemaDisplayableElement_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrintSchemaNUpOption_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PagesPerSheet(this: *mut core::ffi::c_void, pulpagespersheet: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaNUpOption", "PagesPerSheet", 14usize, |this| ((**(this as *const *const IPrintSchemaNUpOption_Vtbl)).PagesPerSheet)(this, pulpagespersheet), |result| Some(*result))
+        }
+        Self { base__: <IPrintSchemaOption_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PagesPerSheet }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSchemaNUpOption as windows_core::Interface>::IID || <IPrintSchemaOption_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrintSchemaOption_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Selected(this: *mut core::ffi::c_void, pbisselected: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaOption", "Selected", 11usize, |this| ((**(this as *const *const IPrintSchemaOption_Vtbl)).Selected)(this, pbisselected), |result| Some(*result))
+        }
+        unsafe extern "system" fn Constrained(this: *mut core::ffi::c_void, psetting: *mut PrintSchemaConstrainedSetting) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaOption", "Constrained", 12usize, |this| ((**(this as *const *const IPrintSchemaOption_Vtbl)).Constrained)(this, psetting), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyValue(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, bstrnamespaceuri: core::mem::MaybeUninit<windows_core::BSTR>, ppxmlvaluenode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaOption", "GetPropertyValue", 13usize, |this| ((**(this as *const *const IPrintSchemaOption_Vtbl)).GetPropertyValue)(this, bstrname, bstrnamespaceuri, ppxmlvaluenode), |result| Some(*result))
+        }
+        Self { base__: <IPrintSchemaDisplayableElement_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Selected, Constrained, GetPropertyValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSchemaOption as windows_core::Interface>::IID || <IPrintSchemaDisplayableElement_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrintSchemaOptionCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pulcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaOptionCollection", "Count", 7usize, |this| ((**(this as *const *const IPrintSchemaOptionCollection_Vtbl)).Count)(this, pulcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, ulindex: u32, ppoption: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaOptionCollection", "GetAt", 8usize, |this| ((**(this as *const *const IPrintSchemaOptionCollection_Vtbl)).GetAt)(this, ulindex, ppoption), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaOptionCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const IPrintSchemaOptionCollection_Vtbl))._NewEnum)(this, ppunk), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            GetAt,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetAt: 0,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSchemaOptionCollection as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrintSchemaPageImageableSize_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ImageableSizeWidthInMicrons(this: *mut core::ffi::c_void, pulimageablesizewidth: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaPageImageableSize", "ImageableSizeWidthInMicrons", 10usize, |this| ((**(this as *const *const IPrintSchemaPageImageableSize_Vtbl)).ImageableSizeWidthInMicrons)(this, pulimageablesizewidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImageableSizeHeightInMicrons(this: *mut core::ffi::c_void, pulimageablesizeheight: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaPageImageableSize", "ImageableSizeHeightInMicrons", 11usize, |this| ((**(this as *const *const IPrintSchemaPageImageableSize_Vtbl)).ImageableSizeHeightInMicrons)(this, pulimageablesizeheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn OriginWidthInMicrons(this: *mut core::ffi::c_void, puloriginwidth: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaPageImageableSize", "OriginWidthInMicrons", 12usize, |this| ((**(this as *const *const IPrintSchemaPageImageableSize_Vtbl)).OriginWidthInMicrons)(this, puloriginwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn OriginHeightInMicrons(this: *mut core::ffi::c_void, puloriginheight: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaPageImageableSize", "OriginHeightInMicrons", 13usize, |this| ((**(this as *const *const IPrintSchemaPageImageableSize_Vtbl)).OriginHeightInMicrons)(this, puloriginheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtentWidthInMicrons(this: *mut core::ffi::c_void, pulextentwidth: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaPageImageableSize", "ExtentWidthInMicrons", 14usize, |this| ((**(this as *const *const IPrintSchemaPageImageableSize_Vtbl)).ExtentWidthInMicrons)(this, pulextentwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtentHeightInMicrons(this: *mut core::ffi::c_void, pulextentheight: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaPageImageableSize", "ExtentHeightInMicrons", 15usize, |this| ((**(this as *const *const IPrintSchemaPageImageableSize_Vtbl)).ExtentHeightInMicrons)(this, pulextentheight), |result| Some(*result))
+        }
+        Self {
+            base__: <IPrintSchemaElement_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ImageableSizeWidthInMicrons,
+            ImageableSizeHeightInMicrons,
+            OriginWidthInMicrons,
+            OriginHeightInMicrons,
+            ExtentWidthInMicrons,
+            ExtentHeightInMicrons,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSchemaPageImageableSize as windows_core::Interface>::IID || <IPrintSchemaElement_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrintSchemaPageMediaSizeOption_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WidthInMicrons(this: *mut core::ffi::c_void, pulwidth: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaPageMediaSizeOption", "WidthInMicrons", 14usize, |this| ((**(this as *const *const IPrintSchemaPageMediaSizeOption_Vtbl)).WidthInMicrons)(this, pulwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn HeightInMicrons(this: *mut core::ffi::c_void, pulheight: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaPageMediaSizeOption", "HeightInMicrons", 15usize, |this| ((**(this as *const *const IPrintSchemaPageMediaSizeOption_Vtbl)).HeightInMicrons)(this, pulheight), |result| Some(*result))
+        }
+        Self { base__: <IPrintSchemaOption_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WidthInMicrons, HeightInMicrons }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSchemaPageMediaSizeOption as windows_core::Interface>::IID || <IPrintSchemaOption_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrintSchemaParameterDefinition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UserInputRequired(this: *mut core::ffi::c_void, pbisrequired: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaParameterDefinition", "UserInputRequired", 11usize, |this| ((**(this as *const *const IPrintSchemaParameterDefinition_Vtbl)).UserInputRequired)(this, pbisrequired), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnitType(this: *mut core::ffi::c_void, pbstrunittype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaParameterDefinition", "UnitType", 12usize, |this| ((**(this as *const *const IPrintSchemaParameterDefinition_Vtbl)).UnitType)(this, pbstrunittype), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataType(this: *mut core::ffi::c_void, pdatatype: *mut PrintSchemaParameterDataType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaParameterDefinition", "DataType", 13usize, |this| ((**(this as *const *const IPrintSchemaParameterDefinition_Vtbl)).DataType)(this, pdatatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn RangeMin(this: *mut core::ffi::c_void, prangemin: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaParameterDefinition", "RangeMin", 14usize, |this| ((**(this as *const *const IPrintSchemaParameterDefinition_Vtbl)).RangeMin)(this, prangemin), |result| Some(*result))
+        }
+        unsafe extern "system" fn RangeMax(this: *mut core::ffi::c_void, prangemax: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaParameterDefinition", "RangeMax", 15usize, |this| ((**(this as *const *const IPrintSchemaParameterDefinition_Vtbl)).RangeMax)(this, prangemax), |result| Some(*result))
+        }
+        Self {
+            base__: <IPrintSchemaDisplayableElement_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            UserInputRequired,
+            UnitType,
+            DataType,
+            RangeMin,
+            RangeMax,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSchemaParameterDefinition as windows_core::Interface>::IID || <IPrintSchemaDisplayableElement_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrintSchemaParameterInitializer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, pvar: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaParameterInitializer", "Value", 10usize, |this| ((**(this as *const *const IPrintSchemaParameterInitializer_Vtbl)).Value)(this, pvar), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, pvar: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaParameterInitializer", "SetValue", 11usize, |this| ((**(this as *const *const IPrintSchemaParameterInitializer_Vtbl)).SetValue)(this, pvar), |result| Some(*result))
+        }
+        Self { base__: <IPrintSchemaElement_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Value, SetValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSchemaParameterInitializer as windows_core::Interface>::IID || <IPrintSchemaElement_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrintSchemaTicket_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFeatureByKeyName(this: *mut core::ffi::c_void, bstrkeyname: core::mem::MaybeUninit<windows_core::BSTR>, ppfeature: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaTicket", "GetFeatureByKeyName", 10usize, |this| ((**(this as *const *const IPrintSchemaTicket_Vtbl)).GetFeatureByKeyName)(this, bstrkeyname, ppfeature), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFeature(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, bstrnamespaceuri: core::mem::MaybeUninit<windows_core::BSTR>, ppfeature: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaTicket", "GetFeature", 11usize, |this| ((**(this as *const *const IPrintSchemaTicket_Vtbl)).GetFeature)(this, bstrname, bstrnamespaceuri, ppfeature), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ValidateAsync(this: *mut core::ffi::c_void, ppasyncoperation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaTicket", "ValidateAsync", 12usize, |this| ((**(this as *const *const IPrintSchemaTicket_Vtbl)).ValidateAsync)(this, ppasyncoperation), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CommitAsync(this: *mut core::ffi::c_void, pprintticketcommit: *mut core::ffi::c_void, ppasyncoperation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaTicket", "CommitAsync", 13usize, |this| ((**(this as *const *const IPrintSchemaTicket_Vtbl)).CommitAsync)(this, pprintticketcommit, ppasyncoperation), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyXmlChanged(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaTicket", "NotifyXmlChanged", 14usize, |this| ((**(this as *const *const IPrintSchemaTicket_Vtbl)).NotifyXmlChanged)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetCapabilities(this: *mut core::ffi::c_void, ppcapabilities: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaTicket", "GetCapabilities", 15usize, |this| ((**(this as *const *const IPrintSchemaTicket_Vtbl)).GetCapabilities)(this, ppcapabilities), |result| Some(*result))
+        }
+        unsafe extern "system" fn JobCopiesAllDocuments(this: *mut core::ffi::c_void, puljobcopiesalldocuments: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaTicket", "JobCopiesAllDocuments", 16usize, |this| ((**(this as *const *const IPrintSchemaTicket_Vtbl)).JobCopiesAllDocuments)(this, puljobcopiesalldocuments), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetJobCopiesAllDocuments(this: *mut core::ffi::c_void, uljobcopiesalldocuments: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaTicket", "SetJobCopiesAllDocuments", 17usize, |this| ((**(this as *const *const IPrintSchemaTicket_Vtbl)).SetJobCopiesAllDocuments)(this, uljobcopiesalldocuments), |result| Some(*result))
+        }
+        Self {
+            base__: <IPrintSchemaElement_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFeatureByKeyName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFeatureByKeyName: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFeature,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFeature: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ValidateAsync,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ValidateAsync: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CommitAsync,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CommitAsync: 0,
+            NotifyXmlChanged,
+            #[cfg(feature = "Win32_System_Com")]
+            GetCapabilities,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetCapabilities: 0,
+            JobCopiesAllDocuments,
+            SetJobCopiesAllDocuments,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSchemaTicket as windows_core::Interface>::IID || <IPrintSchemaElement_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrintSchemaTicket2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetParameterInitializer(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, bstrnamespaceuri: core::mem::MaybeUninit<windows_core::BSTR>, ppparameterinitializer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintSchemaTicket2", "GetParameterInitializer", 18usize, |this| ((**(this as *const *const IPrintSchemaTicket2_Vtbl)).GetParameterInitializer)(this, bstrname, bstrnamespaceuri, ppparameterinitializer), |result| Some(*result))
+        }
+        Self {
+            base__: <IPrintSchemaTicket_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetParameterInitializer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetParameterInitializer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintSchemaTicket2 as windows_core::Interface>::IID || <IPrintSchemaTicket_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IPrintTicketProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSupportedVersions(this: *mut core::ffi::c_void, hprinter: super::super::Foundation::HANDLE, ppversions: *mut *mut i32, cversions: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketProvider", "GetSupportedVersions", 3usize, |this| ((**(this as *const *const IPrintTicketProvider_Vtbl)).GetSupportedVersions)(this, hprinter, ppversions, cversions), |result| Some(*result))
+        }
+        unsafe extern "system" fn BindPrinter(this: *mut core::ffi::c_void, hprinter: super::super::Foundation::HANDLE, version: i32, poptions: *mut SHIMOPTS, pdevmodeflags: *mut u32, cnamespaces: *mut i32, ppnamespaces: *mut *mut windows_core::BSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketProvider", "BindPrinter", 4usize, |this| ((**(this as *const *const IPrintTicketProvider_Vtbl)).BindPrinter)(this, hprinter, version, poptions, pdevmodeflags, cnamespaces, ppnamespaces), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryDeviceNamespace(this: *mut core::ffi::c_void, pdefaultnamespace: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketProvider", "QueryDeviceNamespace", 5usize, |this| ((**(this as *const *const IPrintTicketProvider_Vtbl)).QueryDeviceNamespace)(this, pdefaultnamespace), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn ConvertPrintTicketToDevMode(this: *mut core::ffi::c_void, pprintticket: *mut core::ffi::c_void, cbdevmodein: u32, pdevmodein: *mut super::Gdi::DEVMODEA, pcbdevmodeout: *mut u32, ppdevmodeout: *mut *mut super::Gdi::DEVMODEA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketProvider", "ConvertPrintTicketToDevMode", 6usize, |this| ((**(this as *const *const IPrintTicketProvider_Vtbl)).ConvertPrintTicketToDevMode)(this, pprintticket, cbdevmodein, pdevmodein, pcbdevmodeout, ppdevmodeout), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn ConvertDevModeToPrintTicket(this: *mut core::ffi::c_void, cbdevmode: u32, pdevmode: *mut super::Gdi::DEVMODEA, pprintticket: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketProvider", "ConvertDevModeToPrintTicket", 7usize, |this| ((**(this as *const *const IPrintTicketProvider_Vtbl)).ConvertDevModeToPrintTicket)(this, cbdevmode, pdevmode, pprintticket), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn GetPrintCapabilities(this: *mut core::ffi::c_void, pprintticket: *mut core::ffi::c_void, ppcapabilities: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketProvider", "GetPrintCapabilities", 8usize, |this| ((**(this as *const *const IPrintTicketProvider_Vtbl)).GetPrintCapabilities)(this, pprintticket, ppcapabilities), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn ValidatePrintTicket(this: *mut core::ffi::c_void, pbaseticket: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketProvider", "ValidatePrintTicket", 9usize, |this| ((**(this as *const *const IPrintTicketProvider_Vtbl)).ValidatePrintTicket)(this, pbaseticket), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSupportedVersions,
+            BindPrinter,
+            QueryDeviceNamespace,
+            #[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+            ConvertPrintTicketToDevMode,
+            #[cfg(not(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com")))]
+            ConvertPrintTicketToDevMode: 0,
+            #[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+            ConvertDevModeToPrintTicket,
+            #[cfg(not(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com")))]
+            ConvertDevModeToPrintTicket: 0,
+            #[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com"))]
+            GetPrintCapabilities,
+            #[cfg(not(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com")))]
+            GetPrintCapabilities: 0,
+            #[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com"))]
+            ValidatePrintTicket,
+            #[cfg(not(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com")))]
+            ValidatePrintTicket: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTicketProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IPrintTicketProvider2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn GetPrintDeviceCapabilities(this: *mut core::ffi::c_void, pprintticket: *mut core::ffi::c_void, ppdevicecapabilities: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketProvider2", "GetPrintDeviceCapabilities", 10usize, |this| ((**(this as *const *const IPrintTicketProvider2_Vtbl)).GetPrintDeviceCapabilities)(this, pprintticket, ppdevicecapabilities), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn GetPrintDeviceResources(this: *mut core::ffi::c_void, pszlocalename: windows_core::PCWSTR, pprintticket: *mut core::ffi::c_void, ppdeviceresources: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTicketProvider2", "GetPrintDeviceResources", 11usize, |this| ((**(this as *const *const IPrintTicketProvider2_Vtbl)).GetPrintDeviceResources)(this, pszlocalename, pprintticket, ppdeviceresources), |result| Some(*result))
+        }
+        Self {
+            base__: <IPrintTicketProvider_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com"))]
+            GetPrintDeviceCapabilities,
+            #[cfg(not(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com")))]
+            GetPrintDeviceCapabilities: 0,
+            #[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com"))]
+            GetPrintDeviceResources,
+            #[cfg(not(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com")))]
+            GetPrintDeviceResources: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTicketProvider2 as windows_core::Interface>::IID || <IPrintTicketProvider_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintUnidiAsyncNotifyRegistration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AsyncGetNotification(this: *mut core::ffi::c_void, param0: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintUnidiAsyncNotifyRegistration", "AsyncGetNotification", 5usize, |this| ((**(this as *const *const IPrintUnidiAsyncNotifyRegistration_Vtbl)).AsyncGetNotification)(this, param0), |result| Some(*result))
+        }
+        Self { base__: <IPrintAsyncNotifyRegistration_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AsyncGetNotification }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintUnidiAsyncNotifyRegistration as windows_core::Interface>::IID || <IPrintAsyncNotifyRegistration_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintWriteStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WriteBytes(this: *mut core::ffi::c_void, pvbuffer: *const core::ffi::c_void, cbbuffer: u32, pcbwritten: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWriteStream", "WriteBytes", 3usize, |this| ((**(this as *const *const IPrintWriteStream_Vtbl)).WriteBytes)(this, pvbuffer, cbbuffer, pcbwritten), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IPrintWriteStream", "Close", 4usize, |this| ((**(this as *const *const IPrintWriteStream_Vtbl)).Close)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WriteBytes, Close }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWriteStream as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintWriteStreamFlush_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FlushData(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWriteStreamFlush", "FlushData", 3usize, |this| ((**(this as *const *const IPrintWriteStreamFlush_Vtbl)).FlushData)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FlushData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWriteStreamFlush as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrinterBidiSetRequestCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Completed(this: *mut core::ffi::c_void, bstrresponse: core::mem::MaybeUninit<windows_core::BSTR>, hrstatus: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterBidiSetRequestCallback", "Completed", 3usize, |this| ((**(this as *const *const IPrinterBidiSetRequestCallback_Vtbl)).Completed)(this, bstrresponse, hrstatus), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Completed }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinterBidiSetRequestCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrinterExtensionAsyncOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterExtensionAsyncOperation", "Cancel", 3usize, |this| ((**(this as *const *const IPrinterExtensionAsyncOperation_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Cancel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinterExtensionAsyncOperation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrinterExtensionContext_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PrinterQueue(this: *mut core::ffi::c_void, ppqueue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterExtensionContext", "PrinterQueue", 7usize, |this| ((**(this as *const *const IPrinterExtensionContext_Vtbl)).PrinterQueue)(this, ppqueue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PrintSchemaTicket(this: *mut core::ffi::c_void, ppticket: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterExtensionContext", "PrintSchemaTicket", 8usize, |this| ((**(this as *const *const IPrinterExtensionContext_Vtbl)).PrintSchemaTicket)(this, ppticket), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DriverProperties(this: *mut core::ffi::c_void, pppropertybag: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterExtensionContext", "DriverProperties", 9usize, |this| ((**(this as *const *const IPrinterExtensionContext_Vtbl)).DriverProperties)(this, pppropertybag), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn UserProperties(this: *mut core::ffi::c_void, pppropertybag: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterExtensionContext", "UserProperties", 10usize, |this| ((**(this as *const *const IPrinterExtensionContext_Vtbl)).UserProperties)(this, pppropertybag), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            PrinterQueue,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PrinterQueue: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PrintSchemaTicket,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PrintSchemaTicket: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            DriverProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DriverProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            UserProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            UserProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinterExtensionContext as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrinterExtensionContextCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pulcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterExtensionContextCollection", "Count", 7usize, |this| ((**(this as *const *const IPrinterExtensionContextCollection_Vtbl)).Count)(this, pulcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, ulindex: u32, ppcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterExtensionContextCollection", "GetAt", 8usize, |this| ((**(this as *const *const IPrinterExtensionContextCollection_Vtbl)).GetAt)(this, ulindex, ppcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterExtensionContextCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const IPrinterExtensionContextCollection_Vtbl))._NewEnum)(this, ppunk), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            GetAt,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetAt: 0,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinterExtensionContextCollection as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrinterExtensionEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnDriverEvent(this: *mut core::ffi::c_void, peventargs: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterExtensionEvent", "OnDriverEvent", 7usize, |this| ((**(this as *const *const IPrinterExtensionEvent_Vtbl)).OnDriverEvent)(this, peventargs), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnPrinterQueuesEnumerated(this: *mut core::ffi::c_void, pcontextcollection: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterExtensionEvent", "OnPrinterQueuesEnumerated", 8usize, |this| ((**(this as *const *const IPrinterExtensionEvent_Vtbl)).OnPrinterQueuesEnumerated)(this, pcontextcollection), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            OnDriverEvent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnDriverEvent: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnPrinterQueuesEnumerated,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnPrinterQueuesEnumerated: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinterExtensionEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrinterExtensionEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BidiNotification(this: *mut core::ffi::c_void, pbstrbidinotification: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterExtensionEventArgs", "BidiNotification", 11usize, |this| ((**(this as *const *const IPrinterExtensionEventArgs_Vtbl)).BidiNotification)(this, pbstrbidinotification), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReasonId(this: *mut core::ffi::c_void, preasonid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterExtensionEventArgs", "ReasonId", 12usize, |this| ((**(this as *const *const IPrinterExtensionEventArgs_Vtbl)).ReasonId)(this, preasonid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, pprequest: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterExtensionEventArgs", "Request", 13usize, |this| ((**(this as *const *const IPrinterExtensionEventArgs_Vtbl)).Request)(this, pprequest), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceApplication(this: *mut core::ffi::c_void, pbstrapplication: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterExtensionEventArgs", "SourceApplication", 14usize, |this| ((**(this as *const *const IPrinterExtensionEventArgs_Vtbl)).SourceApplication)(this, pbstrapplication), |result| Some(*result))
+        }
+        unsafe extern "system" fn DetailedReasonId(this: *mut core::ffi::c_void, pdetailedreasonid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterExtensionEventArgs", "DetailedReasonId", 15usize, |this| ((**(this as *const *const IPrinterExtensionEventArgs_Vtbl)).DetailedReasonId)(this, pdetailedreasonid), |result| Some(*result))
+        }
+        unsafe extern "system" fn WindowModal(this: *mut core::ffi::c_void, pbmodal: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterExtensionEventArgs", "WindowModal", 16usize, |this| ((**(this as *const *const IPrinterExtensionEventArgs_Vtbl)).WindowModal)(this, pbmodal), |result| Some(*result))
+        }
+        unsafe extern "system" fn WindowParent(this: *mut core::ffi::c_void, phwndparent: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterExtensionEventArgs", "WindowParent", 17usize, |this| ((**(this as *const *const IPrinterExtensionEventArgs_Vtbl)).WindowParent)(this, phwndparent), |result| Some(*result))
+        }
+        Self {
+            base__: <IPrinterExtensionContext_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BidiNotification,
+            ReasonId,
+            #[cfg(feature = "Win32_System_Com")]
+            Request,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Request: 0,
+            SourceApplication,
+            DetailedReasonId,
+            WindowModal,
+            WindowParent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinterExtensionEventArgs as windows_core::Interface>::IID || <IPrinterExtensionContext_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrinterExtensionManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnableEvents(this: *mut core::ffi::c_void, printerdriverid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterExtensionManager", "EnableEvents", 3usize, |this| ((**(this as *const *const IPrinterExtensionManager_Vtbl)).EnableEvents)(this, printerdriverid), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableEvents(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterExtensionManager", "DisableEvents", 4usize, |this| ((**(this as *const *const IPrinterExtensionManager_Vtbl)).DisableEvents)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnableEvents, DisableEvents }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinterExtensionManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrinterExtensionRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void, hrstatus: windows_core::HRESULT, bstrlogmessage: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterExtensionRequest", "Cancel", 7usize, |this| ((**(this as *const *const IPrinterExtensionRequest_Vtbl)).Cancel)(this, hrstatus, bstrlogmessage), |result| Some(*result))
+        }
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterExtensionRequest", "Complete", 8usize, |this| ((**(this as *const *const IPrinterExtensionRequest_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Cancel, Complete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinterExtensionRequest as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrinterPropertyBag_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBool(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, pbvalue: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterPropertyBag", "GetBool", 7usize, |this| ((**(this as *const *const IPrinterPropertyBag_Vtbl)).GetBool)(this, bstrname, pbvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBool(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, bvalue: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterPropertyBag", "SetBool", 8usize, |this| ((**(this as *const *const IPrinterPropertyBag_Vtbl)).SetBool)(this, bstrname, bvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInt32(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, pnvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterPropertyBag", "GetInt32", 9usize, |this| ((**(this as *const *const IPrinterPropertyBag_Vtbl)).GetInt32)(this, bstrname, pnvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInt32(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, nvalue: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterPropertyBag", "SetInt32", 10usize, |this| ((**(this as *const *const IPrinterPropertyBag_Vtbl)).SetInt32)(this, bstrname, nvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetString(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, pbstrvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterPropertyBag", "GetString", 11usize, |this| ((**(this as *const *const IPrinterPropertyBag_Vtbl)).GetString)(this, bstrname, pbstrvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetString(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, bstrvalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterPropertyBag", "SetString", 12usize, |this| ((**(this as *const *const IPrinterPropertyBag_Vtbl)).SetString)(this, bstrname, bstrvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBytes(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, pcbvalue: *mut u32, ppvalue: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterPropertyBag", "GetBytes", 13usize, |this| ((**(this as *const *const IPrinterPropertyBag_Vtbl)).GetBytes)(this, bstrname, pcbvalue, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBytes(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, cbvalue: u32, pvalue: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterPropertyBag", "SetBytes", 14usize, |this| ((**(this as *const *const IPrinterPropertyBag_Vtbl)).SetBytes)(this, bstrname, cbvalue, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetReadStream(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterPropertyBag", "GetReadStream", 15usize, |this| ((**(this as *const *const IPrinterPropertyBag_Vtbl)).GetReadStream)(this, bstrname, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetWriteStream(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterPropertyBag", "GetWriteStream", 16usize, |this| ((**(this as *const *const IPrinterPropertyBag_Vtbl)).GetWriteStream)(this, bstrname, ppvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetBool,
+            SetBool,
+            GetInt32,
+            SetInt32,
+            GetString,
+            SetString,
+            GetBytes,
+            SetBytes,
+            #[cfg(feature = "Win32_System_Com")]
+            GetReadStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetReadStream: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetWriteStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetWriteStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinterPropertyBag as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrinterQueue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handle(this: *mut core::ffi::c_void, phprinter: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterQueue", "Handle", 7usize, |this| ((**(this as *const *const IPrinterQueue_Vtbl)).Handle)(this, phprinter), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterQueue", "Name", 8usize, |this| ((**(this as *const *const IPrinterQueue_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendBidiQuery(this: *mut core::ffi::c_void, bstrbidiquery: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterQueue", "SendBidiQuery", 9usize, |this| ((**(this as *const *const IPrinterQueue_Vtbl)).SendBidiQuery)(this, bstrbidiquery), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, pppropertybag: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterQueue", "GetProperties", 10usize, |this| ((**(this as *const *const IPrinterQueue_Vtbl)).GetProperties)(this, pppropertybag), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Handle,
+            Name,
+            SendBidiQuery,
+            #[cfg(feature = "Win32_System_Com")]
+            GetProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinterQueue as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrinterQueue2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SendBidiSetRequestAsync(this: *mut core::ffi::c_void, bstrbidirequest: core::mem::MaybeUninit<windows_core::BSTR>, pcallback: *mut core::ffi::c_void, ppasyncoperation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterQueue2", "SendBidiSetRequestAsync", 11usize, |this| ((**(this as *const *const IPrinterQueue2_Vtbl)).SendBidiSetRequestAsync)(this, bstrbidirequest, pcallback, ppasyncoperation), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetPrinterQueueView(this: *mut core::ffi::c_void, ulviewoffset: u32, ulviewsize: u32, ppjobview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterQueue2", "GetPrinterQueueView", 12usize, |this| ((**(this as *const *const IPrinterQueue2_Vtbl)).GetPrinterQueueView)(this, ulviewoffset, ulviewsize, ppjobview), |result| Some(*result))
+        }
+        Self {
+            base__: <IPrinterQueue_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SendBidiSetRequestAsync,
+            #[cfg(feature = "Win32_System_Com")]
+            GetPrinterQueueView,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetPrinterQueueView: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinterQueue2 as windows_core::Interface>::IID || <IPrinterQueue_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrinterQueueEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnBidiResponseReceived(this: *mut core::ffi::c_void, bstrresponse: core::mem::MaybeUninit<windows_core::BSTR>, hrstatus: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterQueueEvent", "OnBidiResponseReceived", 7usize, |this| ((**(this as *const *const IPrinterQueueEvent_Vtbl)).OnBidiResponseReceived)(this, bstrresponse, hrstatus), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnBidiResponseReceived }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinterQueueEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrinterQueueView_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetViewRange(this: *mut core::ffi::c_void, ulviewoffset: u32, ulviewsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterQueueView", "SetViewRange", 7usize, |this| ((**(this as *const *const IPrinterQueueView_Vtbl)).SetViewRange)(this, ulviewoffset, ulviewsize), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetViewRange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinterQueueView as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrinterQueueViewEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnChanged(this: *mut core::ffi::c_void, pcollection: *mut core::ffi::c_void, ulviewoffset: u32, ulviewsize: u32, ulcountjobsinprintqueue: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterQueueViewEvent", "OnChanged", 7usize, |this| ((**(this as *const *const IPrinterQueueViewEvent_Vtbl)).OnChanged)(this, pcollection, ulviewoffset, ulviewsize, ulcountjobsinprintqueue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            OnChanged,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnChanged: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinterQueueViewEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrinterScriptContext_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DriverProperties(this: *mut core::ffi::c_void, pppropertybag: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterScriptContext", "DriverProperties", 7usize, |this| ((**(this as *const *const IPrinterScriptContext_Vtbl)).DriverProperties)(this, pppropertybag), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn QueueProperties(this: *mut core::ffi::c_void, pppropertybag: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterScriptContext", "QueueProperties", 8usize, |this| ((**(this as *const *const IPrinterScriptContext_Vtbl)).QueueProperties)(this, pppropertybag), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn UserProperties(this: *mut core::ffi::c_void, pppropertybag: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterScriptContext", "UserProperties", 9usize, |this| ((**(this as *const *const IPrinterScriptContext_Vtbl)).UserProperties)(this, pppropertybag), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            DriverProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DriverProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            QueueProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            QueueProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            UserProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            UserProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinterScriptContext as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrinterScriptablePropertyBag_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBool(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, pbvalue: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterScriptablePropertyBag", "GetBool", 7usize, |this| ((**(this as *const *const IPrinterScriptablePropertyBag_Vtbl)).GetBool)(this, bstrname, pbvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBool(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, bvalue: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterScriptablePropertyBag", "SetBool", 8usize, |this| ((**(this as *const *const IPrinterScriptablePropertyBag_Vtbl)).SetBool)(this, bstrname, bvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInt32(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, pnvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterScriptablePropertyBag", "GetInt32", 9usize, |this| ((**(this as *const *const IPrinterScriptablePropertyBag_Vtbl)).GetInt32)(this, bstrname, pnvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInt32(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, nvalue: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterScriptablePropertyBag", "SetInt32", 10usize, |this| ((**(this as *const *const IPrinterScriptablePropertyBag_Vtbl)).SetInt32)(this, bstrname, nvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetString(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, pbstrvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterScriptablePropertyBag", "GetString", 11usize, |this| ((**(this as *const *const IPrinterScriptablePropertyBag_Vtbl)).GetString)(this, bstrname, pbstrvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetString(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, bstrvalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterScriptablePropertyBag", "SetString", 12usize, |this| ((**(this as *const *const IPrinterScriptablePropertyBag_Vtbl)).SetString)(this, bstrname, bstrvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetBytes(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, pparray: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterScriptablePropertyBag", "GetBytes", 13usize, |this| ((**(this as *const *const IPrinterScriptablePropertyBag_Vtbl)).GetBytes)(this, bstrname, pparray), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetBytes(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, parray: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterScriptablePropertyBag", "SetBytes", 14usize, |this| ((**(this as *const *const IPrinterScriptablePropertyBag_Vtbl)).SetBytes)(this, bstrname, parray), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetReadStream(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterScriptablePropertyBag", "GetReadStream", 15usize, |this| ((**(this as *const *const IPrinterScriptablePropertyBag_Vtbl)).GetReadStream)(this, bstrname, ppstream), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetWriteStream(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterScriptablePropertyBag", "GetWriteStream", 16usize, |this| ((**(this as *const *const IPrinterScriptablePropertyBag_Vtbl)).GetWriteStream)(this, bstrname, ppstream), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetBool,
+            SetBool,
+            GetInt32,
+            SetInt32,
+            GetString,
+            SetString,
+            #[cfg(feature = "Win32_System_Com")]
+            GetBytes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetBytes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetBytes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetBytes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetReadStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetReadStream: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetWriteStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetWriteStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinterScriptablePropertyBag as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrinterScriptablePropertyBag2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetReadStreamAsXML(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, ppxmlnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterScriptablePropertyBag2", "GetReadStreamAsXML", 17usize, |this| ((**(this as *const *const IPrinterScriptablePropertyBag2_Vtbl)).GetReadStreamAsXML)(this, bstrname, ppxmlnode), |result| Some(*result))
+        }
+        Self { base__: <IPrinterScriptablePropertyBag_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetReadStreamAsXML }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinterScriptablePropertyBag2 as windows_core::Interface>::IID || <IPrinterScriptablePropertyBag_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrinterScriptableSequentialStream_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Read(this: *mut core::ffi::c_void, cbread: i32, pparray: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterScriptableSequentialStream", "Read", 7usize, |this| ((**(this as *const *const IPrinterScriptableSequentialStream_Vtbl)).Read)(this, cbread, pparray), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Write(this: *mut core::ffi::c_void, parray: *mut core::ffi::c_void, pcbwritten: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterScriptableSequentialStream", "Write", 8usize, |this| ((**(this as *const *const IPrinterScriptableSequentialStream_Vtbl)).Write)(this, parray, pcbwritten), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Read,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Read: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Write,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Write: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinterScriptableSequentialStream as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrinterScriptableStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterScriptableStream", "Commit", 9usize, |this| ((**(this as *const *const IPrinterScriptableStream_Vtbl)).Commit)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Seek(this: *mut core::ffi::c_void, loffset: i32, streamseek: super::super::System::Com::STREAM_SEEK, plposition: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterScriptableStream", "Seek", 10usize, |this| ((**(this as *const *const IPrinterScriptableStream_Vtbl)).Seek)(this, loffset, streamseek, plposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSize(this: *mut core::ffi::c_void, lsize: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinterScriptableStream", "SetSize", 11usize, |this| ((**(this as *const *const IPrinterScriptableStream_Vtbl)).SetSize)(this, lsize), |result| Some(*result))
+        }
+        Self {
+            base__: <IPrinterScriptableSequentialStream_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Commit,
+            #[cfg(feature = "Win32_System_Com")]
+            Seek,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Seek: 0,
+            SetSize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinterScriptableStream as windows_core::Interface>::IID || <IPrinterScriptableSequentialStream_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsDocument_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetThumbnail(this: *mut core::ffi::c_void, ppthumbnail: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsDocument", "GetThumbnail", 3usize, |this| ((**(this as *const *const IXpsDocument_Vtbl)).GetThumbnail)(this, ppthumbnail), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetThumbnail(this: *mut core::ffi::c_void, pthumbnail: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsDocument", "SetThumbnail", 4usize, |this| ((**(this as *const *const IXpsDocument_Vtbl)).SetThumbnail)(this, pthumbnail), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetThumbnail, SetThumbnail }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsDocument as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsDocumentConsumer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SendXpsUnknown(this: *mut core::ffi::c_void, punknown: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsDocumentConsumer", "SendXpsUnknown", 3usize, |this| ((**(this as *const *const IXpsDocumentConsumer_Vtbl)).SendXpsUnknown)(this, punknown), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendXpsDocument(this: *mut core::ffi::c_void, pixpsdocument: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsDocumentConsumer", "SendXpsDocument", 4usize, |this| ((**(this as *const *const IXpsDocumentConsumer_Vtbl)).SendXpsDocument)(this, pixpsdocument), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendFixedDocumentSequence(this: *mut core::ffi::c_void, pifixeddocumentsequence: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsDocumentConsumer", "SendFixedDocumentSequence", 5usize, |this| ((**(this as *const *const IXpsDocumentConsumer_Vtbl)).SendFixedDocumentSequence)(this, pifixeddocumentsequence), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendFixedDocument(this: *mut core::ffi::c_void, pifixeddocument: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsDocumentConsumer", "SendFixedDocument", 6usize, |this| ((**(this as *const *const IXpsDocumentConsumer_Vtbl)).SendFixedDocument)(this, pifixeddocument), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendFixedPage(this: *mut core::ffi::c_void, pifixedpage: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsDocumentConsumer", "SendFixedPage", 7usize, |this| ((**(this as *const *const IXpsDocumentConsumer_Vtbl)).SendFixedPage)(this, pifixedpage), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseSender(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsDocumentConsumer", "CloseSender", 8usize, |this| ((**(this as *const *const IXpsDocumentConsumer_Vtbl)).CloseSender)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNewEmptyPart(this: *mut core::ffi::c_void, uri: windows_core::PCWSTR, riid: *const windows_core::GUID, ppnewobject: *mut *mut core::ffi::c_void, ppwritestream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsDocumentConsumer", "GetNewEmptyPart", 9usize, |this| ((**(this as *const *const IXpsDocumentConsumer_Vtbl)).GetNewEmptyPart)(this, uri, riid, ppnewobject, ppwritestream), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SendXpsUnknown,
+            SendXpsDocument,
+            SendFixedDocumentSequence,
+            SendFixedDocument,
+            SendFixedPage,
+            CloseSender,
+            GetNewEmptyPart,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsDocumentConsumer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsDocumentProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetXpsPart(this: *mut core::ffi::c_void, ppixpspart: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsDocumentProvider", "GetXpsPart", 3usize, |this| ((**(this as *const *const IXpsDocumentProvider_Vtbl)).GetXpsPart)(this, ppixpspart), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetXpsPart }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsDocumentProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsPartIterator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IXpsPartIterator", "Reset", 3usize, |this| ((**(this as *const *const IXpsPartIterator_Vtbl)).Reset)(this), |_| None)
+        }
+        unsafe extern "system" fn Current(this: *mut core::ffi::c_void, puri: *mut core::mem::MaybeUninit<windows_core::BSTR>, ppxpspart: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsPartIterator", "Current", 4usize, |this| ((**(this as *const *const IXpsPartIterator_Vtbl)).Current)(this, puri, ppxpspart), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDone(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IXpsPartIterator", "IsDone", 5usize, |this| ((**(this as *const *const IXpsPartIterator_Vtbl)).IsDone)(this), |_| None)
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IXpsPartIterator", "Next", 6usize, |this| ((**(this as *const *const IXpsPartIterator_Vtbl)).Next)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reset, Current, IsDone, Next }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsPartIterator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_Xps")]
+impl windows_core::imp::ProxyVtable for IXpsRasterizationFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_Xps")]
+        unsafe extern "system" fn CreateRasterizer(this: *mut core::ffi::c_void, xpspage: *mut core::ffi::c_void, dpi: f32, nontextrenderingmode: XPSRAS_RENDERING_MODE, textrenderingmode: XPSRAS_RENDERING_MODE, ppixpsrasterizer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsRasterizationFactory", "CreateRasterizer", 3usize, |this| ((**(this as *const *const IXpsRasterizationFactory_Vtbl)).CreateRasterizer)(this, xpspage, dpi, nontextrenderingmode, textrenderingmode, ppixpsrasterizer), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_Xps")]
+            CreateRasterizer,
+            #[cfg(not(feature = "Win32_Storage_Xps"))]
+            CreateRasterizer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsRasterizationFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_Xps")]
+impl windows_core::imp::ProxyVtable for IXpsRasterizationFactory1_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_Xps")]
+        unsafe extern "system" fn CreateRasterizer(this: *mut core::ffi::c_void, xpspage: *mut core::ffi::c_void, dpi: f32, nontextrenderingmode: XPSRAS_RENDERING_MODE, textrenderingmode: XPSRAS_RENDERING_MODE, pixelformat: XPSRAS_PIXEL_FORMAT, ppixpsrasterizer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsRasterizationFactory1", "CreateRasterizer", 3usize, |this| ((**(this as *const *const IXpsRasterizationFactory1_Vtbl)).CreateRasterizer)(this, xpspage, dpi, nontextrenderingmode, textrenderingmode, pixelformat, ppixpsrasterizer), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_Xps")]
+            CreateRasterizer,
+            #[cfg(not(feature = "Win32_Storage_Xps"))]
+            CreateRasterizer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsRasterizationFactory1 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_Xps")]
+impl windows_core::imp::ProxyVtable for IXpsRasterizationFactory2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_Xps")]
+        unsafe extern "system" fn CreateRasterizer(this: *mut core::ffi::c_void, xpspage: *mut core::ffi::c_void, dpix: f32, dpiy: f32, nontextrenderingmode: XPSRAS_RENDERING_MODE, textrenderingmode: XPSRAS_RENDERING_MODE, pixelformat: XPSRAS_PIXEL_FORMAT, backgroundcolor: XPSRAS_BACKGROUND_COLOR, ppixpsrasterizer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsRasterizationFactory2", "CreateRasterizer", 3usize, |this| ((**(this as *const *const IXpsRasterizationFactory2_Vtbl)).CreateRasterizer)(this, xpspage, dpix, dpiy, nontextrenderingmode, textrenderingmode, pixelformat, backgroundcolor, ppixpsrasterizer), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_Xps")]
+            CreateRasterizer,
+            #[cfg(not(feature = "Win32_Storage_Xps"))]
+            CreateRasterizer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsRasterizationFactory2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Imaging")]
+impl windows_core::imp::ProxyVtable for IXpsRasterizer_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Imaging")]
+        unsafe extern "system" fn RasterizeRect(this: *mut core::ffi::c_void, x: i32, y: i32, width: i32, height: i32, notificationcallback: *mut core::ffi::c_void, bitmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsRasterizer", "RasterizeRect", 3usize, |this| ((**(this as *const *const IXpsRasterizer_Vtbl)).RasterizeRect)(this, x, y, width, height, notificationcallback, bitmap), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinimalLineWidth(this: *mut core::ffi::c_void, width: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsRasterizer", "SetMinimalLineWidth", 4usize, |this| ((**(this as *const *const IXpsRasterizer_Vtbl)).SetMinimalLineWidth)(this, width), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Imaging")]
+            RasterizeRect,
+            #[cfg(not(feature = "Win32_Graphics_Imaging"))]
+            RasterizeRect: 0,
+            SetMinimalLineWidth,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsRasterizer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsRasterizerNotificationCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Continue(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsRasterizerNotificationCallback", "Continue", 3usize, |this| ((**(this as *const *const IXpsRasterizerNotificationCallback_Vtbl)).Continue)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Continue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsRasterizerNotificationCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
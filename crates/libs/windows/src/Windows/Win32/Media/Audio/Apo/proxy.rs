@@ -0,0 +1,276 @@
+impl windows_core::imp::ProxyVtable for IApoAcousticEchoCancellation_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApoAcousticEchoCancellation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApoAuxiliaryInputConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddAuxiliaryInput(this: *mut core::ffi::c_void, dwinputid: u32, cbdatasize: u32, pbydata: *const u8, pinputconnection: *const APO_CONNECTION_DESCRIPTOR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApoAuxiliaryInputConfiguration", "AddAuxiliaryInput", 3usize, |this| ((**(this as *const *const IApoAuxiliaryInputConfiguration_Vtbl)).AddAuxiliaryInput)(this, dwinputid, cbdatasize, pbydata, pinputconnection), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAuxiliaryInput(this: *mut core::ffi::c_void, dwinputid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApoAuxiliaryInputConfiguration", "RemoveAuxiliaryInput", 4usize, |this| ((**(this as *const *const IApoAuxiliaryInputConfiguration_Vtbl)).RemoveAuxiliaryInput)(this, dwinputid), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInputFormatSupported(this: *mut core::ffi::c_void, prequestedinputformat: *mut core::ffi::c_void, ppsupportedinputformat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApoAuxiliaryInputConfiguration", "IsInputFormatSupported", 5usize, |this| ((**(this as *const *const IApoAuxiliaryInputConfiguration_Vtbl)).IsInputFormatSupported)(this, prequestedinputformat, ppsupportedinputformat), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddAuxiliaryInput, RemoveAuxiliaryInput, IsInputFormatSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApoAuxiliaryInputConfiguration as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApoAuxiliaryInputRT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AcceptInput(this: *mut core::ffi::c_void, dwinputid: u32, pinputconnection: *const APO_CONNECTION_PROPERTY) {
+            windows_core::imp::proxy_call(this, "IApoAuxiliaryInputRT", "AcceptInput", 3usize, |this| ((**(this as *const *const IApoAuxiliaryInputRT_Vtbl)).AcceptInput)(this, dwinputid, pinputconnection), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AcceptInput }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApoAuxiliaryInputRT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioDeviceModulesClient_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAudioDeviceModulesManager(this: *mut core::ffi::c_void, paudiodevicemodulesmanager: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioDeviceModulesClient", "SetAudioDeviceModulesManager", 3usize, |this| ((**(this as *const *const IAudioDeviceModulesClient_Vtbl)).SetAudioDeviceModulesManager)(this, paudiodevicemodulesmanager), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAudioDeviceModulesManager }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioDeviceModulesClient as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioMediaType_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsCompressedFormat(this: *mut core::ffi::c_void, pfcompressed: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioMediaType", "IsCompressedFormat", 3usize, |this| ((**(this as *const *const IAudioMediaType_Vtbl)).IsCompressedFormat)(this, pfcompressed), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEqual(this: *mut core::ffi::c_void, piaudiotype: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioMediaType", "IsEqual", 4usize, |this| ((**(this as *const *const IAudioMediaType_Vtbl)).IsEqual)(this, piaudiotype, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAudioFormat(this: *mut core::ffi::c_void) -> *mut super::WAVEFORMATEX {
+            windows_core::imp::proxy_call(this, "IAudioMediaType", "GetAudioFormat", 5usize, |this| ((**(this as *const *const IAudioMediaType_Vtbl)).GetAudioFormat)(this), |_| None)
+        }
+        unsafe extern "system" fn GetUncompressedAudioFormat(this: *mut core::ffi::c_void, puncompressedaudioformat: *mut UNCOMPRESSEDAUDIOFORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioMediaType", "GetUncompressedAudioFormat", 6usize, |this| ((**(this as *const *const IAudioMediaType_Vtbl)).GetUncompressedAudioFormat)(this, puncompressedaudioformat), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsCompressedFormat,
+            IsEqual,
+            GetAudioFormat,
+            GetUncompressedAudioFormat,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioMediaType as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioProcessingObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioProcessingObject", "Reset", 3usize, |this| ((**(this as *const *const IAudioProcessingObject_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLatency(this: *mut core::ffi::c_void, ptime: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioProcessingObject", "GetLatency", 4usize, |this| ((**(this as *const *const IAudioProcessingObject_Vtbl)).GetLatency)(this, ptime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRegistrationProperties(this: *mut core::ffi::c_void, ppregprops: *mut *mut APO_REG_PROPERTIES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioProcessingObject", "GetRegistrationProperties", 5usize, |this| ((**(this as *const *const IAudioProcessingObject_Vtbl)).GetRegistrationProperties)(this, ppregprops), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, cbdatasize: u32, pbydata: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioProcessingObject", "Initialize", 6usize, |this| ((**(this as *const *const IAudioProcessingObject_Vtbl)).Initialize)(this, cbdatasize, pbydata), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInputFormatSupported(this: *mut core::ffi::c_void, poppositeformat: *mut core::ffi::c_void, prequestedinputformat: *mut core::ffi::c_void, ppsupportedinputformat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioProcessingObject", "IsInputFormatSupported", 7usize, |this| ((**(this as *const *const IAudioProcessingObject_Vtbl)).IsInputFormatSupported)(this, poppositeformat, prequestedinputformat, ppsupportedinputformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOutputFormatSupported(this: *mut core::ffi::c_void, poppositeformat: *mut core::ffi::c_void, prequestedoutputformat: *mut core::ffi::c_void, ppsupportedoutputformat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioProcessingObject", "IsOutputFormatSupported", 8usize, |this| ((**(this as *const *const IAudioProcessingObject_Vtbl)).IsOutputFormatSupported)(this, poppositeformat, prequestedoutputformat, ppsupportedoutputformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputChannelCount(this: *mut core::ffi::c_void, pu32channelcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioProcessingObject", "GetInputChannelCount", 9usize, |this| ((**(this as *const *const IAudioProcessingObject_Vtbl)).GetInputChannelCount)(this, pu32channelcount), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Reset,
+            GetLatency,
+            GetRegistrationProperties,
+            Initialize,
+            IsInputFormatSupported,
+            IsOutputFormatSupported,
+            GetInputChannelCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioProcessingObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioProcessingObjectConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LockForProcess(this: *mut core::ffi::c_void, u32numinputconnections: u32, ppinputconnections: *const *const APO_CONNECTION_DESCRIPTOR, u32numoutputconnections: u32, ppoutputconnections: *const *const APO_CONNECTION_DESCRIPTOR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioProcessingObjectConfiguration", "LockForProcess", 3usize, |this| ((**(this as *const *const IAudioProcessingObjectConfiguration_Vtbl)).LockForProcess)(this, u32numinputconnections, ppinputconnections, u32numoutputconnections, ppoutputconnections), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockForProcess(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioProcessingObjectConfiguration", "UnlockForProcess", 4usize, |this| ((**(this as *const *const IAudioProcessingObjectConfiguration_Vtbl)).UnlockForProcess)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LockForProcess, UnlockForProcess }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioProcessingObjectConfiguration as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioProcessingObjectLoggingService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ApoLog(this: *mut core::ffi::c_void, level: APO_LOG_LEVEL, format: windows_core::PCWSTR) {
+            windows_core::imp::proxy_call(this, "IAudioProcessingObjectLoggingService", "ApoLog", 3usize, |this| ((**(this as *const *const IAudioProcessingObjectLoggingService_Vtbl)).ApoLog)(this, level, format), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ApoLog }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioProcessingObjectLoggingService as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IAudioProcessingObjectNotifications_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetApoNotificationRegistrationInfo(this: *mut core::ffi::c_void, aponotifications: *mut *mut APO_NOTIFICATION_DESCRIPTOR, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioProcessingObjectNotifications", "GetApoNotificationRegistrationInfo", 3usize, |this| ((**(this as *const *const IAudioProcessingObjectNotifications_Vtbl)).GetApoNotificationRegistrationInfo)(this, aponotifications, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn HandleNotification(this: *mut core::ffi::c_void, aponotification: *const APO_NOTIFICATION) {
+            windows_core::imp::proxy_call(this, "IAudioProcessingObjectNotifications", "HandleNotification", 4usize, |this| ((**(this as *const *const IAudioProcessingObjectNotifications_Vtbl)).HandleNotification)(this, aponotification), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetApoNotificationRegistrationInfo,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            HandleNotification,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            HandleNotification: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioProcessingObjectNotifications as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IAudioProcessingObjectNotifications2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetApoNotificationRegistrationInfo2(this: *mut core::ffi::c_void, maxaponotificationtypesupported: APO_NOTIFICATION_TYPE, aponotifications: *mut *mut APO_NOTIFICATION_DESCRIPTOR, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioProcessingObjectNotifications2", "GetApoNotificationRegistrationInfo2", 5usize, |this| ((**(this as *const *const IAudioProcessingObjectNotifications2_Vtbl)).GetApoNotificationRegistrationInfo2)(this, maxaponotificationtypesupported, aponotifications, count), |result| Some(*result))
+        }
+        Self { base__: <IAudioProcessingObjectNotifications_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetApoNotificationRegistrationInfo2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioProcessingObjectNotifications2 as windows_core::Interface>::IID || <IAudioProcessingObjectNotifications_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioProcessingObjectRT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn APOProcess(this: *mut core::ffi::c_void, u32numinputconnections: u32, ppinputconnections: *const *const APO_CONNECTION_PROPERTY, u32numoutputconnections: u32, ppoutputconnections: *mut *mut APO_CONNECTION_PROPERTY) {
+            windows_core::imp::proxy_call(this, "IAudioProcessingObjectRT", "APOProcess", 3usize, |this| ((**(this as *const *const IAudioProcessingObjectRT_Vtbl)).APOProcess)(this, u32numinputconnections, ppinputconnections, u32numoutputconnections, ppoutputconnections), |_| None)
+        }
+        unsafe extern "system" fn CalcInputFrames(this: *mut core::ffi::c_void, u32outputframecount: u32) -> u32 {
+            windows_core::imp::proxy_call(this, "IAudioProcessingObjectRT", "CalcInputFrames", 4usize, |this| ((**(this as *const *const IAudioProcessingObjectRT_Vtbl)).CalcInputFrames)(this, u32outputframecount), |_| None)
+        }
+        unsafe extern "system" fn CalcOutputFrames(this: *mut core::ffi::c_void, u32inputframecount: u32) -> u32 {
+            windows_core::imp::proxy_call(this, "IAudioProcessingObjectRT", "CalcOutputFrames", 5usize, |this| ((**(this as *const *const IAudioProcessingObjectRT_Vtbl)).CalcOutputFrames)(this, u32inputframecount), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, APOProcess, CalcInputFrames, CalcOutputFrames }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioProcessingObjectRT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioProcessingObjectRTQueueService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRealTimeWorkQueue(this: *mut core::ffi::c_void, workqueueid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioProcessingObjectRTQueueService", "GetRealTimeWorkQueue", 3usize, |this| ((**(this as *const *const IAudioProcessingObjectRTQueueService_Vtbl)).GetRealTimeWorkQueue)(this, workqueueid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRealTimeWorkQueue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioProcessingObjectRTQueueService as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioProcessingObjectVBR_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CalcMaxInputFrames(this: *mut core::ffi::c_void, u32maxoutputframecount: u32, pu32inputframecount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioProcessingObjectVBR", "CalcMaxInputFrames", 3usize, |this| ((**(this as *const *const IAudioProcessingObjectVBR_Vtbl)).CalcMaxInputFrames)(this, u32maxoutputframecount, pu32inputframecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn CalcMaxOutputFrames(this: *mut core::ffi::c_void, u32maxinputframecount: u32, pu32outputframecount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioProcessingObjectVBR", "CalcMaxOutputFrames", 4usize, |this| ((**(this as *const *const IAudioProcessingObjectVBR_Vtbl)).CalcMaxOutputFrames)(this, u32maxinputframecount, pu32outputframecount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CalcMaxInputFrames, CalcMaxOutputFrames }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioProcessingObjectVBR as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioSystemEffects_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioSystemEffects as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioSystemEffects2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEffectsList(this: *mut core::ffi::c_void, ppeffectsids: *mut *mut windows_core::GUID, pceffects: *mut u32, event: super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSystemEffects2", "GetEffectsList", 3usize, |this| ((**(this as *const *const IAudioSystemEffects2_Vtbl)).GetEffectsList)(this, ppeffectsids, pceffects, event), |result| Some(*result))
+        }
+        Self { base__: <IAudioSystemEffects_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetEffectsList }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioSystemEffects2 as windows_core::Interface>::IID || <IAudioSystemEffects_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioSystemEffects3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetControllableSystemEffectsList(this: *mut core::ffi::c_void, effects: *mut *mut AUDIO_SYSTEMEFFECT, numeffects: *mut u32, event: super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSystemEffects3", "GetControllableSystemEffectsList", 4usize, |this| ((**(this as *const *const IAudioSystemEffects3_Vtbl)).GetControllableSystemEffectsList)(this, effects, numeffects, event), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAudioSystemEffectState(this: *mut core::ffi::c_void, effectid: windows_core::GUID, state: AUDIO_SYSTEMEFFECT_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSystemEffects3", "SetAudioSystemEffectState", 5usize, |this| ((**(this as *const *const IAudioSystemEffects3_Vtbl)).SetAudioSystemEffectState)(this, effectid, state), |result| Some(*result))
+        }
+        Self { base__: <IAudioSystemEffects2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetControllableSystemEffectsList, SetAudioSystemEffectState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioSystemEffects3 as windows_core::Interface>::IID || <IAudioSystemEffects2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioSystemEffectsCustomFormats_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFormatCount(this: *mut core::ffi::c_void, pcformats: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSystemEffectsCustomFormats", "GetFormatCount", 3usize, |this| ((**(this as *const *const IAudioSystemEffectsCustomFormats_Vtbl)).GetFormatCount)(this, pcformats), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFormat(this: *mut core::ffi::c_void, nformat: u32, ppformat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSystemEffectsCustomFormats", "GetFormat", 4usize, |this| ((**(this as *const *const IAudioSystemEffectsCustomFormats_Vtbl)).GetFormat)(this, nformat, ppformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFormatRepresentation(this: *mut core::ffi::c_void, nformat: u32, ppwstrformatrep: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSystemEffectsCustomFormats", "GetFormatRepresentation", 5usize, |this| ((**(this as *const *const IAudioSystemEffectsCustomFormats_Vtbl)).GetFormatRepresentation)(this, nformat, ppwstrformatrep), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFormatCount, GetFormat, GetFormatRepresentation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioSystemEffectsCustomFormats as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
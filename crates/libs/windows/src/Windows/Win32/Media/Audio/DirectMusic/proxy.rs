@@ -0,0 +1,455 @@
+#[cfg(feature = "Win32_Media_Audio_DirectSound")]
+impl windows_core::imp::ProxyVtable for IDirectMusic_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumPort(this: *mut core::ffi::c_void, dwindex: u32, pportcaps: *mut DMUS_PORTCAPS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusic", "EnumPort", 3usize, |this| ((**(this as *const *const IDirectMusic_Vtbl)).EnumPort)(this, dwindex, pportcaps), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateMusicBuffer(this: *mut core::ffi::c_void, pbufferdesc: *mut DMUS_BUFFERDESC, ppbuffer: *mut *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusic", "CreateMusicBuffer", 4usize, |this| ((**(this as *const *const IDirectMusic_Vtbl)).CreateMusicBuffer)(this, pbufferdesc, ppbuffer, punkouter), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePort(this: *mut core::ffi::c_void, rclsidport: *const windows_core::GUID, pportparams: *mut DMUS_PORTPARAMS8, ppport: *mut *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusic", "CreatePort", 5usize, |this| ((**(this as *const *const IDirectMusic_Vtbl)).CreatePort)(this, rclsidport, pportparams, ppport, punkouter), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumMasterClock(this: *mut core::ffi::c_void, dwindex: u32, lpclockinfo: *mut DMUS_CLOCKINFO8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusic", "EnumMasterClock", 6usize, |this| ((**(this as *const *const IDirectMusic_Vtbl)).EnumMasterClock)(this, dwindex, lpclockinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMasterClock(this: *mut core::ffi::c_void, pguidclock: *mut windows_core::GUID, ppreferenceclock: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusic", "GetMasterClock", 7usize, |this| ((**(this as *const *const IDirectMusic_Vtbl)).GetMasterClock)(this, pguidclock, ppreferenceclock), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMasterClock(this: *mut core::ffi::c_void, rguidclock: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusic", "SetMasterClock", 8usize, |this| ((**(this as *const *const IDirectMusic_Vtbl)).SetMasterClock)(this, rguidclock), |result| Some(*result))
+        }
+        unsafe extern "system" fn Activate(this: *mut core::ffi::c_void, fenable: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusic", "Activate", 9usize, |this| ((**(this as *const *const IDirectMusic_Vtbl)).Activate)(this, fenable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultPort(this: *mut core::ffi::c_void, pguidport: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusic", "GetDefaultPort", 10usize, |this| ((**(this as *const *const IDirectMusic_Vtbl)).GetDefaultPort)(this, pguidport), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio_DirectSound")]
+        unsafe extern "system" fn SetDirectSound(this: *mut core::ffi::c_void, pdirectsound: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusic", "SetDirectSound", 11usize, |this| ((**(this as *const *const IDirectMusic_Vtbl)).SetDirectSound)(this, pdirectsound, hwnd), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EnumPort,
+            CreateMusicBuffer,
+            CreatePort,
+            EnumMasterClock,
+            GetMasterClock,
+            SetMasterClock,
+            Activate,
+            GetDefaultPort,
+            #[cfg(feature = "Win32_Media_Audio_DirectSound")]
+            SetDirectSound,
+            #[cfg(not(feature = "Win32_Media_Audio_DirectSound"))]
+            SetDirectSound: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectMusic as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_Audio_DirectSound")]
+impl windows_core::imp::ProxyVtable for IDirectMusic8_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetExternalMasterClock(this: *mut core::ffi::c_void, pclock: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusic8", "SetExternalMasterClock", 12usize, |this| ((**(this as *const *const IDirectMusic8_Vtbl)).SetExternalMasterClock)(this, pclock), |result| Some(*result))
+        }
+        Self { base__: <IDirectMusic_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetExternalMasterClock }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectMusic8 as windows_core::Interface>::IID || <IDirectMusic_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectMusicBuffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Flush(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicBuffer", "Flush", 3usize, |this| ((**(this as *const *const IDirectMusicBuffer_Vtbl)).Flush)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalTime(this: *mut core::ffi::c_void, prttime: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicBuffer", "TotalTime", 4usize, |this| ((**(this as *const *const IDirectMusicBuffer_Vtbl)).TotalTime)(this, prttime), |result| Some(*result))
+        }
+        unsafe extern "system" fn PackStructured(this: *mut core::ffi::c_void, rt: i64, dwchannelgroup: u32, dwchannelmessage: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicBuffer", "PackStructured", 5usize, |this| ((**(this as *const *const IDirectMusicBuffer_Vtbl)).PackStructured)(this, rt, dwchannelgroup, dwchannelmessage), |result| Some(*result))
+        }
+        unsafe extern "system" fn PackUnstructured(this: *mut core::ffi::c_void, rt: i64, dwchannelgroup: u32, cb: u32, lpb: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicBuffer", "PackUnstructured", 6usize, |this| ((**(this as *const *const IDirectMusicBuffer_Vtbl)).PackUnstructured)(this, rt, dwchannelgroup, cb, lpb), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetReadPtr(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicBuffer", "ResetReadPtr", 7usize, |this| ((**(this as *const *const IDirectMusicBuffer_Vtbl)).ResetReadPtr)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextEvent(this: *mut core::ffi::c_void, prt: *mut i64, pdwchannelgroup: *mut u32, pdwlength: *mut u32, ppdata: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicBuffer", "GetNextEvent", 8usize, |this| ((**(this as *const *const IDirectMusicBuffer_Vtbl)).GetNextEvent)(this, prt, pdwchannelgroup, pdwlength, ppdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRawBufferPtr(this: *mut core::ffi::c_void, ppdata: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicBuffer", "GetRawBufferPtr", 9usize, |this| ((**(this as *const *const IDirectMusicBuffer_Vtbl)).GetRawBufferPtr)(this, ppdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStartTime(this: *mut core::ffi::c_void, prt: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicBuffer", "GetStartTime", 10usize, |this| ((**(this as *const *const IDirectMusicBuffer_Vtbl)).GetStartTime)(this, prt), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUsedBytes(this: *mut core::ffi::c_void, pcb: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicBuffer", "GetUsedBytes", 11usize, |this| ((**(this as *const *const IDirectMusicBuffer_Vtbl)).GetUsedBytes)(this, pcb), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxBytes(this: *mut core::ffi::c_void, pcb: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicBuffer", "GetMaxBytes", 12usize, |this| ((**(this as *const *const IDirectMusicBuffer_Vtbl)).GetMaxBytes)(this, pcb), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBufferFormat(this: *mut core::ffi::c_void, pguidformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicBuffer", "GetBufferFormat", 13usize, |this| ((**(this as *const *const IDirectMusicBuffer_Vtbl)).GetBufferFormat)(this, pguidformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartTime(this: *mut core::ffi::c_void, rt: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicBuffer", "SetStartTime", 14usize, |this| ((**(this as *const *const IDirectMusicBuffer_Vtbl)).SetStartTime)(this, rt), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUsedBytes(this: *mut core::ffi::c_void, cb: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicBuffer", "SetUsedBytes", 15usize, |this| ((**(this as *const *const IDirectMusicBuffer_Vtbl)).SetUsedBytes)(this, cb), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Flush,
+            TotalTime,
+            PackStructured,
+            PackUnstructured,
+            ResetReadPtr,
+            GetNextEvent,
+            GetRawBufferPtr,
+            GetStartTime,
+            GetUsedBytes,
+            GetMaxBytes,
+            GetBufferFormat,
+            SetStartTime,
+            SetUsedBytes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectMusicBuffer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectMusicCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInstrument(this: *mut core::ffi::c_void, dwpatch: u32, ppinstrument: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicCollection", "GetInstrument", 3usize, |this| ((**(this as *const *const IDirectMusicCollection_Vtbl)).GetInstrument)(this, dwpatch, ppinstrument), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumInstrument(this: *mut core::ffi::c_void, dwindex: u32, pdwpatch: *mut u32, pwszname: windows_core::PCWSTR, dwnamelen: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicCollection", "EnumInstrument", 4usize, |this| ((**(this as *const *const IDirectMusicCollection_Vtbl)).EnumInstrument)(this, dwindex, pdwpatch, pwszname, dwnamelen), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetInstrument, EnumInstrument }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectMusicCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectMusicDownload_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBuffer(this: *mut core::ffi::c_void, ppvbuffer: *mut *mut core::ffi::c_void, pdwsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicDownload", "GetBuffer", 3usize, |this| ((**(this as *const *const IDirectMusicDownload_Vtbl)).GetBuffer)(this, ppvbuffer, pdwsize), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetBuffer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectMusicDownload as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectMusicDownloadedInstrument_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectMusicDownloadedInstrument as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectMusicInstrument_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPatch(this: *mut core::ffi::c_void, pdwpatch: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicInstrument", "GetPatch", 3usize, |this| ((**(this as *const *const IDirectMusicInstrument_Vtbl)).GetPatch)(this, pdwpatch), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPatch(this: *mut core::ffi::c_void, dwpatch: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicInstrument", "SetPatch", 4usize, |this| ((**(this as *const *const IDirectMusicInstrument_Vtbl)).SetPatch)(this, dwpatch), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPatch, SetPatch }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectMusicInstrument as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Media_Audio_DirectSound", feature = "Win32_System_IO"))]
+impl windows_core::imp::ProxyVtable for IDirectMusicPort_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PlayBuffer(this: *mut core::ffi::c_void, pbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicPort", "PlayBuffer", 3usize, |this| ((**(this as *const *const IDirectMusicPort_Vtbl)).PlayBuffer)(this, pbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReadNotificationHandle(this: *mut core::ffi::c_void, hevent: super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicPort", "SetReadNotificationHandle", 4usize, |this| ((**(this as *const *const IDirectMusicPort_Vtbl)).SetReadNotificationHandle)(this, hevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn Read(this: *mut core::ffi::c_void, pbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicPort", "Read", 5usize, |this| ((**(this as *const *const IDirectMusicPort_Vtbl)).Read)(this, pbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadInstrument(this: *mut core::ffi::c_void, pinstrument: *mut core::ffi::c_void, ppdownloadedinstrument: *mut *mut core::ffi::c_void, pnoteranges: *mut DMUS_NOTERANGE, dwnumnoteranges: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicPort", "DownloadInstrument", 6usize, |this| ((**(this as *const *const IDirectMusicPort_Vtbl)).DownloadInstrument)(this, pinstrument, ppdownloadedinstrument, pnoteranges, dwnumnoteranges), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnloadInstrument(this: *mut core::ffi::c_void, pdownloadedinstrument: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicPort", "UnloadInstrument", 7usize, |this| ((**(this as *const *const IDirectMusicPort_Vtbl)).UnloadInstrument)(this, pdownloadedinstrument), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLatencyClock(this: *mut core::ffi::c_void, ppclock: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicPort", "GetLatencyClock", 8usize, |this| ((**(this as *const *const IDirectMusicPort_Vtbl)).GetLatencyClock)(this, ppclock), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRunningStats(this: *mut core::ffi::c_void, pstats: *mut DMUS_SYNTHSTATS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicPort", "GetRunningStats", 9usize, |this| ((**(this as *const *const IDirectMusicPort_Vtbl)).GetRunningStats)(this, pstats), |result| Some(*result))
+        }
+        unsafe extern "system" fn Compact(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicPort", "Compact", 10usize, |this| ((**(this as *const *const IDirectMusicPort_Vtbl)).Compact)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCaps(this: *mut core::ffi::c_void, pportcaps: *mut DMUS_PORTCAPS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicPort", "GetCaps", 11usize, |this| ((**(this as *const *const IDirectMusicPort_Vtbl)).GetCaps)(this, pportcaps), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_IO")]
+        unsafe extern "system" fn DeviceIoControl(this: *mut core::ffi::c_void, dwiocontrolcode: u32, lpinbuffer: *mut core::ffi::c_void, ninbuffersize: u32, lpoutbuffer: *mut core::ffi::c_void, noutbuffersize: u32, lpbytesreturned: *mut u32, lpoverlapped: *mut super::super::super::System::IO::OVERLAPPED) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicPort", "DeviceIoControl", 12usize, |this| ((**(this as *const *const IDirectMusicPort_Vtbl)).DeviceIoControl)(this, dwiocontrolcode, lpinbuffer, ninbuffersize, lpoutbuffer, noutbuffersize, lpbytesreturned, lpoverlapped), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNumChannelGroups(this: *mut core::ffi::c_void, dwchannelgroups: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicPort", "SetNumChannelGroups", 13usize, |this| ((**(this as *const *const IDirectMusicPort_Vtbl)).SetNumChannelGroups)(this, dwchannelgroups), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumChannelGroups(this: *mut core::ffi::c_void, pdwchannelgroups: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicPort", "GetNumChannelGroups", 14usize, |this| ((**(this as *const *const IDirectMusicPort_Vtbl)).GetNumChannelGroups)(this, pdwchannelgroups), |result| Some(*result))
+        }
+        unsafe extern "system" fn Activate(this: *mut core::ffi::c_void, factive: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicPort", "Activate", 15usize, |this| ((**(this as *const *const IDirectMusicPort_Vtbl)).Activate)(this, factive), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetChannelPriority(this: *mut core::ffi::c_void, dwchannelgroup: u32, dwchannel: u32, dwpriority: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicPort", "SetChannelPriority", 16usize, |this| ((**(this as *const *const IDirectMusicPort_Vtbl)).SetChannelPriority)(this, dwchannelgroup, dwchannel, dwpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChannelPriority(this: *mut core::ffi::c_void, dwchannelgroup: u32, dwchannel: u32, pdwpriority: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicPort", "GetChannelPriority", 17usize, |this| ((**(this as *const *const IDirectMusicPort_Vtbl)).GetChannelPriority)(this, dwchannelgroup, dwchannel, pdwpriority), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio_DirectSound")]
+        unsafe extern "system" fn SetDirectSound(this: *mut core::ffi::c_void, pdirectsound: *mut core::ffi::c_void, pdirectsoundbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicPort", "SetDirectSound", 18usize, |this| ((**(this as *const *const IDirectMusicPort_Vtbl)).SetDirectSound)(this, pdirectsound, pdirectsoundbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFormat(this: *mut core::ffi::c_void, pwaveformatex: *mut super::WAVEFORMATEX, pdwwaveformatexsize: *mut u32, pdwbuffersize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicPort", "GetFormat", 19usize, |this| ((**(this as *const *const IDirectMusicPort_Vtbl)).GetFormat)(this, pwaveformatex, pdwwaveformatexsize, pdwbuffersize), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PlayBuffer,
+            SetReadNotificationHandle,
+            Read,
+            DownloadInstrument,
+            UnloadInstrument,
+            GetLatencyClock,
+            GetRunningStats,
+            Compact,
+            GetCaps,
+            #[cfg(feature = "Win32_System_IO")]
+            DeviceIoControl,
+            #[cfg(not(feature = "Win32_System_IO"))]
+            DeviceIoControl: 0,
+            SetNumChannelGroups,
+            GetNumChannelGroups,
+            Activate,
+            SetChannelPriority,
+            GetChannelPriority,
+            #[cfg(feature = "Win32_Media_Audio_DirectSound")]
+            SetDirectSound,
+            #[cfg(not(feature = "Win32_Media_Audio_DirectSound"))]
+            SetDirectSound: 0,
+            GetFormat,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectMusicPort as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectMusicPortDownload_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBuffer(this: *mut core::ffi::c_void, dwdlid: u32, ppidmdownload: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicPortDownload", "GetBuffer", 3usize, |this| ((**(this as *const *const IDirectMusicPortDownload_Vtbl)).GetBuffer)(this, dwdlid, ppidmdownload), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllocateBuffer(this: *mut core::ffi::c_void, dwsize: u32, ppidmdownload: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicPortDownload", "AllocateBuffer", 4usize, |this| ((**(this as *const *const IDirectMusicPortDownload_Vtbl)).AllocateBuffer)(this, dwsize, ppidmdownload), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDLId(this: *mut core::ffi::c_void, pdwstartdlid: *mut u32, dwcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicPortDownload", "GetDLId", 5usize, |this| ((**(this as *const *const IDirectMusicPortDownload_Vtbl)).GetDLId)(this, pdwstartdlid, dwcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAppend(this: *mut core::ffi::c_void, pdwappend: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicPortDownload", "GetAppend", 6usize, |this| ((**(this as *const *const IDirectMusicPortDownload_Vtbl)).GetAppend)(this, pdwappend), |result| Some(*result))
+        }
+        unsafe extern "system" fn Download(this: *mut core::ffi::c_void, pidmdownload: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicPortDownload", "Download", 7usize, |this| ((**(this as *const *const IDirectMusicPortDownload_Vtbl)).Download)(this, pidmdownload), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unload(this: *mut core::ffi::c_void, pidmdownload: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicPortDownload", "Unload", 8usize, |this| ((**(this as *const *const IDirectMusicPortDownload_Vtbl)).Unload)(this, pidmdownload), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetBuffer, AllocateBuffer, GetDLId, GetAppend, Download, Unload }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectMusicPortDownload as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectMusicSynth_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, pportparams: *mut DMUS_PORTPARAMS8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynth", "Open", 3usize, |this| ((**(this as *const *const IDirectMusicSynth_Vtbl)).Open)(this, pportparams), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynth", "Close", 4usize, |this| ((**(this as *const *const IDirectMusicSynth_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNumChannelGroups(this: *mut core::ffi::c_void, dwgroups: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynth", "SetNumChannelGroups", 5usize, |this| ((**(this as *const *const IDirectMusicSynth_Vtbl)).SetNumChannelGroups)(this, dwgroups), |result| Some(*result))
+        }
+        unsafe extern "system" fn Download(this: *mut core::ffi::c_void, phdownload: *mut super::super::super::Foundation::HANDLE, pvdata: *mut core::ffi::c_void, pbfree: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynth", "Download", 6usize, |this| ((**(this as *const *const IDirectMusicSynth_Vtbl)).Download)(this, phdownload, pvdata, pbfree), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unload(this: *mut core::ffi::c_void, hdownload: super::super::super::Foundation::HANDLE, lpfreehandle: isize, huserdata: super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynth", "Unload", 7usize, |this| ((**(this as *const *const IDirectMusicSynth_Vtbl)).Unload)(this, hdownload, lpfreehandle, huserdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayBuffer(this: *mut core::ffi::c_void, rt: i64, pbbuffer: *mut u8, cbbuffer: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynth", "PlayBuffer", 8usize, |this| ((**(this as *const *const IDirectMusicSynth_Vtbl)).PlayBuffer)(this, rt, pbbuffer, cbbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRunningStats(this: *mut core::ffi::c_void, pstats: *mut DMUS_SYNTHSTATS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynth", "GetRunningStats", 9usize, |this| ((**(this as *const *const IDirectMusicSynth_Vtbl)).GetRunningStats)(this, pstats), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPortCaps(this: *mut core::ffi::c_void, pcaps: *mut DMUS_PORTCAPS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynth", "GetPortCaps", 10usize, |this| ((**(this as *const *const IDirectMusicSynth_Vtbl)).GetPortCaps)(this, pcaps), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMasterClock(this: *mut core::ffi::c_void, pclock: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynth", "SetMasterClock", 11usize, |this| ((**(this as *const *const IDirectMusicSynth_Vtbl)).SetMasterClock)(this, pclock), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLatencyClock(this: *mut core::ffi::c_void, ppclock: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynth", "GetLatencyClock", 12usize, |this| ((**(this as *const *const IDirectMusicSynth_Vtbl)).GetLatencyClock)(this, ppclock), |result| Some(*result))
+        }
+        unsafe extern "system" fn Activate(this: *mut core::ffi::c_void, fenable: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynth", "Activate", 13usize, |this| ((**(this as *const *const IDirectMusicSynth_Vtbl)).Activate)(this, fenable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSynthSink(this: *mut core::ffi::c_void, psynthsink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynth", "SetSynthSink", 14usize, |this| ((**(this as *const *const IDirectMusicSynth_Vtbl)).SetSynthSink)(this, psynthsink), |result| Some(*result))
+        }
+        unsafe extern "system" fn Render(this: *mut core::ffi::c_void, pbuffer: *mut i16, dwlength: u32, llposition: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynth", "Render", 15usize, |this| ((**(this as *const *const IDirectMusicSynth_Vtbl)).Render)(this, pbuffer, dwlength, llposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetChannelPriority(this: *mut core::ffi::c_void, dwchannelgroup: u32, dwchannel: u32, dwpriority: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynth", "SetChannelPriority", 16usize, |this| ((**(this as *const *const IDirectMusicSynth_Vtbl)).SetChannelPriority)(this, dwchannelgroup, dwchannel, dwpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChannelPriority(this: *mut core::ffi::c_void, dwchannelgroup: u32, dwchannel: u32, pdwpriority: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynth", "GetChannelPriority", 17usize, |this| ((**(this as *const *const IDirectMusicSynth_Vtbl)).GetChannelPriority)(this, dwchannelgroup, dwchannel, pdwpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFormat(this: *mut core::ffi::c_void, pwaveformatex: *mut super::WAVEFORMATEX, pdwwaveformatexsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynth", "GetFormat", 18usize, |this| ((**(this as *const *const IDirectMusicSynth_Vtbl)).GetFormat)(this, pwaveformatex, pdwwaveformatexsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAppend(this: *mut core::ffi::c_void, pdwappend: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynth", "GetAppend", 19usize, |this| ((**(this as *const *const IDirectMusicSynth_Vtbl)).GetAppend)(this, pdwappend), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Open,
+            Close,
+            SetNumChannelGroups,
+            Download,
+            Unload,
+            PlayBuffer,
+            GetRunningStats,
+            GetPortCaps,
+            SetMasterClock,
+            GetLatencyClock,
+            Activate,
+            SetSynthSink,
+            Render,
+            SetChannelPriority,
+            GetChannelPriority,
+            GetFormat,
+            GetAppend,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectMusicSynth as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectMusicSynth8_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PlayVoice(this: *mut core::ffi::c_void, rt: i64, dwvoiceid: u32, dwchannelgroup: u32, dwchannel: u32, dwdlid: u32, prpitch: i32, vrvolume: i32, stvoicestart: u64, stloopstart: u64, stloopend: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynth8", "PlayVoice", 20usize, |this| ((**(this as *const *const IDirectMusicSynth8_Vtbl)).PlayVoice)(this, rt, dwvoiceid, dwchannelgroup, dwchannel, dwdlid, prpitch, vrvolume, stvoicestart, stloopstart, stloopend), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopVoice(this: *mut core::ffi::c_void, rt: i64, dwvoiceid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynth8", "StopVoice", 21usize, |this| ((**(this as *const *const IDirectMusicSynth8_Vtbl)).StopVoice)(this, rt, dwvoiceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVoiceState(this: *mut core::ffi::c_void, dwvoice: *mut u32, cbvoice: u32, dwvoicestate: *mut DMUS_VOICE_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynth8", "GetVoiceState", 22usize, |this| ((**(this as *const *const IDirectMusicSynth8_Vtbl)).GetVoiceState)(this, dwvoice, cbvoice, dwvoicestate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void, dwdownloadid: u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynth8", "Refresh", 23usize, |this| ((**(this as *const *const IDirectMusicSynth8_Vtbl)).Refresh)(this, dwdownloadid, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AssignChannelToBuses(this: *mut core::ffi::c_void, dwchannelgroup: u32, dwchannel: u32, pdwbuses: *mut u32, cbuses: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynth8", "AssignChannelToBuses", 24usize, |this| ((**(this as *const *const IDirectMusicSynth8_Vtbl)).AssignChannelToBuses)(this, dwchannelgroup, dwchannel, pdwbuses, cbuses), |result| Some(*result))
+        }
+        Self { base__: <IDirectMusicSynth_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PlayVoice, StopVoice, GetVoiceState, Refresh, AssignChannelToBuses }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectMusicSynth8 as windows_core::Interface>::IID || <IDirectMusicSynth_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_Audio_DirectSound")]
+impl windows_core::imp::ProxyVtable for IDirectMusicSynthSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Init(this: *mut core::ffi::c_void, psynth: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynthSink", "Init", 3usize, |this| ((**(this as *const *const IDirectMusicSynthSink_Vtbl)).Init)(this, psynth), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMasterClock(this: *mut core::ffi::c_void, pclock: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynthSink", "SetMasterClock", 4usize, |this| ((**(this as *const *const IDirectMusicSynthSink_Vtbl)).SetMasterClock)(this, pclock), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLatencyClock(this: *mut core::ffi::c_void, ppclock: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynthSink", "GetLatencyClock", 5usize, |this| ((**(this as *const *const IDirectMusicSynthSink_Vtbl)).GetLatencyClock)(this, ppclock), |result| Some(*result))
+        }
+        unsafe extern "system" fn Activate(this: *mut core::ffi::c_void, fenable: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynthSink", "Activate", 6usize, |this| ((**(this as *const *const IDirectMusicSynthSink_Vtbl)).Activate)(this, fenable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SampleToRefTime(this: *mut core::ffi::c_void, llsampletime: i64, prftime: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynthSink", "SampleToRefTime", 7usize, |this| ((**(this as *const *const IDirectMusicSynthSink_Vtbl)).SampleToRefTime)(this, llsampletime, prftime), |result| Some(*result))
+        }
+        unsafe extern "system" fn RefTimeToSample(this: *mut core::ffi::c_void, rftime: i64, pllsampletime: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynthSink", "RefTimeToSample", 8usize, |this| ((**(this as *const *const IDirectMusicSynthSink_Vtbl)).RefTimeToSample)(this, rftime, pllsampletime), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio_DirectSound")]
+        unsafe extern "system" fn SetDirectSound(this: *mut core::ffi::c_void, pdirectsound: *mut core::ffi::c_void, pdirectsoundbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynthSink", "SetDirectSound", 9usize, |this| ((**(this as *const *const IDirectMusicSynthSink_Vtbl)).SetDirectSound)(this, pdirectsound, pdirectsoundbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDesiredBufferSize(this: *mut core::ffi::c_void, pdwbuffersizeinsamples: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicSynthSink", "GetDesiredBufferSize", 10usize, |this| ((**(this as *const *const IDirectMusicSynthSink_Vtbl)).GetDesiredBufferSize)(this, pdwbuffersizeinsamples), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Init,
+            SetMasterClock,
+            GetLatencyClock,
+            Activate,
+            SampleToRefTime,
+            RefTimeToSample,
+            #[cfg(feature = "Win32_Media_Audio_DirectSound")]
+            SetDirectSound,
+            #[cfg(not(feature = "Win32_Media_Audio_DirectSound"))]
+            SetDirectSound: 0,
+            GetDesiredBufferSize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectMusicSynthSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectMusicThru_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ThruChannel(this: *mut core::ffi::c_void, dwsourcechannelgroup: u32, dwsourcechannel: u32, dwdestinationchannelgroup: u32, dwdestinationchannel: u32, pdestinationport: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectMusicThru", "ThruChannel", 3usize, |this| ((**(this as *const *const IDirectMusicThru_Vtbl)).ThruChannel)(this, dwsourcechannelgroup, dwsourcechannel, dwdestinationchannelgroup, dwdestinationchannel, pdestinationport), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ThruChannel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectMusicThru as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -0,0 +1,650 @@
+impl windows_core::imp::ProxyVtable for IDirectSound_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateSoundBuffer(this: *mut core::ffi::c_void, pcdsbufferdesc: *const DSBUFFERDESC, ppdsbuffer: *mut *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound", "CreateSoundBuffer", 3usize, |this| ((**(this as *const *const IDirectSound_Vtbl)).CreateSoundBuffer)(this, pcdsbufferdesc, ppdsbuffer, punkouter), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCaps(this: *mut core::ffi::c_void, pdscaps: *mut DSCAPS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound", "GetCaps", 4usize, |this| ((**(this as *const *const IDirectSound_Vtbl)).GetCaps)(this, pdscaps), |result| Some(*result))
+        }
+        unsafe extern "system" fn DuplicateSoundBuffer(this: *mut core::ffi::c_void, pdsbufferoriginal: *mut core::ffi::c_void, ppdsbufferduplicate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound", "DuplicateSoundBuffer", 5usize, |this| ((**(this as *const *const IDirectSound_Vtbl)).DuplicateSoundBuffer)(this, pdsbufferoriginal, ppdsbufferduplicate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCooperativeLevel(this: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND, dwlevel: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound", "SetCooperativeLevel", 6usize, |this| ((**(this as *const *const IDirectSound_Vtbl)).SetCooperativeLevel)(this, hwnd, dwlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn Compact(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound", "Compact", 7usize, |this| ((**(this as *const *const IDirectSound_Vtbl)).Compact)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSpeakerConfig(this: *mut core::ffi::c_void, pdwspeakerconfig: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound", "GetSpeakerConfig", 8usize, |this| ((**(this as *const *const IDirectSound_Vtbl)).GetSpeakerConfig)(this, pdwspeakerconfig), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSpeakerConfig(this: *mut core::ffi::c_void, dwspeakerconfig: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound", "SetSpeakerConfig", 9usize, |this| ((**(this as *const *const IDirectSound_Vtbl)).SetSpeakerConfig)(this, dwspeakerconfig), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pcguiddevice: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound", "Initialize", 10usize, |this| ((**(this as *const *const IDirectSound_Vtbl)).Initialize)(this, pcguiddevice), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateSoundBuffer,
+            GetCaps,
+            DuplicateSoundBuffer,
+            SetCooperativeLevel,
+            Compact,
+            GetSpeakerConfig,
+            SetSpeakerConfig,
+            Initialize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectSound as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D")]
+impl windows_core::imp::ProxyVtable for IDirectSound3DBuffer_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetAllParameters(this: *mut core::ffi::c_void, pds3dbuffer: *mut DS3DBUFFER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DBuffer", "GetAllParameters", 3usize, |this| ((**(this as *const *const IDirectSound3DBuffer_Vtbl)).GetAllParameters)(this, pds3dbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConeAngles(this: *mut core::ffi::c_void, pdwinsideconeangle: *mut u32, pdwoutsideconeangle: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DBuffer", "GetConeAngles", 4usize, |this| ((**(this as *const *const IDirectSound3DBuffer_Vtbl)).GetConeAngles)(this, pdwinsideconeangle, pdwoutsideconeangle), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetConeOrientation(this: *mut core::ffi::c_void, pvorientation: *mut super::super::super::Graphics::Direct3D::D3DVECTOR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DBuffer", "GetConeOrientation", 5usize, |this| ((**(this as *const *const IDirectSound3DBuffer_Vtbl)).GetConeOrientation)(this, pvorientation), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConeOutsideVolume(this: *mut core::ffi::c_void, plconeoutsidevolume: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DBuffer", "GetConeOutsideVolume", 6usize, |this| ((**(this as *const *const IDirectSound3DBuffer_Vtbl)).GetConeOutsideVolume)(this, plconeoutsidevolume), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxDistance(this: *mut core::ffi::c_void, pflmaxdistance: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DBuffer", "GetMaxDistance", 7usize, |this| ((**(this as *const *const IDirectSound3DBuffer_Vtbl)).GetMaxDistance)(this, pflmaxdistance), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMinDistance(this: *mut core::ffi::c_void, pflmindistance: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DBuffer", "GetMinDistance", 8usize, |this| ((**(this as *const *const IDirectSound3DBuffer_Vtbl)).GetMinDistance)(this, pflmindistance), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMode(this: *mut core::ffi::c_void, pdwmode: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DBuffer", "GetMode", 9usize, |this| ((**(this as *const *const IDirectSound3DBuffer_Vtbl)).GetMode)(this, pdwmode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetPosition(this: *mut core::ffi::c_void, pvposition: *mut super::super::super::Graphics::Direct3D::D3DVECTOR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DBuffer", "GetPosition", 10usize, |this| ((**(this as *const *const IDirectSound3DBuffer_Vtbl)).GetPosition)(this, pvposition), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetVelocity(this: *mut core::ffi::c_void, pvvelocity: *mut super::super::super::Graphics::Direct3D::D3DVECTOR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DBuffer", "GetVelocity", 11usize, |this| ((**(this as *const *const IDirectSound3DBuffer_Vtbl)).GetVelocity)(this, pvvelocity), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn SetAllParameters(this: *mut core::ffi::c_void, pcds3dbuffer: *const DS3DBUFFER, dwapply: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DBuffer", "SetAllParameters", 12usize, |this| ((**(this as *const *const IDirectSound3DBuffer_Vtbl)).SetAllParameters)(this, pcds3dbuffer, dwapply), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConeAngles(this: *mut core::ffi::c_void, dwinsideconeangle: u32, dwoutsideconeangle: u32, dwapply: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DBuffer", "SetConeAngles", 13usize, |this| ((**(this as *const *const IDirectSound3DBuffer_Vtbl)).SetConeAngles)(this, dwinsideconeangle, dwoutsideconeangle, dwapply), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConeOrientation(this: *mut core::ffi::c_void, x: f32, y: f32, z: f32, dwapply: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DBuffer", "SetConeOrientation", 14usize, |this| ((**(this as *const *const IDirectSound3DBuffer_Vtbl)).SetConeOrientation)(this, x, y, z, dwapply), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConeOutsideVolume(this: *mut core::ffi::c_void, lconeoutsidevolume: i32, dwapply: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DBuffer", "SetConeOutsideVolume", 15usize, |this| ((**(this as *const *const IDirectSound3DBuffer_Vtbl)).SetConeOutsideVolume)(this, lconeoutsidevolume, dwapply), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxDistance(this: *mut core::ffi::c_void, flmaxdistance: f32, dwapply: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DBuffer", "SetMaxDistance", 16usize, |this| ((**(this as *const *const IDirectSound3DBuffer_Vtbl)).SetMaxDistance)(this, flmaxdistance, dwapply), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinDistance(this: *mut core::ffi::c_void, flmindistance: f32, dwapply: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DBuffer", "SetMinDistance", 17usize, |this| ((**(this as *const *const IDirectSound3DBuffer_Vtbl)).SetMinDistance)(this, flmindistance, dwapply), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMode(this: *mut core::ffi::c_void, dwmode: u32, dwapply: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DBuffer", "SetMode", 18usize, |this| ((**(this as *const *const IDirectSound3DBuffer_Vtbl)).SetMode)(this, dwmode, dwapply), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPosition(this: *mut core::ffi::c_void, x: f32, y: f32, z: f32, dwapply: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DBuffer", "SetPosition", 19usize, |this| ((**(this as *const *const IDirectSound3DBuffer_Vtbl)).SetPosition)(this, x, y, z, dwapply), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVelocity(this: *mut core::ffi::c_void, x: f32, y: f32, z: f32, dwapply: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DBuffer", "SetVelocity", 20usize, |this| ((**(this as *const *const IDirectSound3DBuffer_Vtbl)).SetVelocity)(this, x, y, z, dwapply), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetAllParameters,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetAllParameters: 0,
+            GetConeAngles,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetConeOrientation,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetConeOrientation: 0,
+            GetConeOutsideVolume,
+            GetMaxDistance,
+            GetMinDistance,
+            GetMode,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetPosition,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetPosition: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetVelocity,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetVelocity: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            SetAllParameters,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            SetAllParameters: 0,
+            SetConeAngles,
+            SetConeOrientation,
+            SetConeOutsideVolume,
+            SetMaxDistance,
+            SetMinDistance,
+            SetMode,
+            SetPosition,
+            SetVelocity,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectSound3DBuffer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D")]
+impl windows_core::imp::ProxyVtable for IDirectSound3DListener_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetAllParameters(this: *mut core::ffi::c_void, plistener: *mut DS3DLISTENER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DListener", "GetAllParameters", 3usize, |this| ((**(this as *const *const IDirectSound3DListener_Vtbl)).GetAllParameters)(this, plistener), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDistanceFactor(this: *mut core::ffi::c_void, pfldistancefactor: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DListener", "GetDistanceFactor", 4usize, |this| ((**(this as *const *const IDirectSound3DListener_Vtbl)).GetDistanceFactor)(this, pfldistancefactor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDopplerFactor(this: *mut core::ffi::c_void, pfldopplerfactor: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DListener", "GetDopplerFactor", 5usize, |this| ((**(this as *const *const IDirectSound3DListener_Vtbl)).GetDopplerFactor)(this, pfldopplerfactor), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetOrientation(this: *mut core::ffi::c_void, pvorientfront: *mut super::super::super::Graphics::Direct3D::D3DVECTOR, pvorienttop: *mut super::super::super::Graphics::Direct3D::D3DVECTOR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DListener", "GetOrientation", 6usize, |this| ((**(this as *const *const IDirectSound3DListener_Vtbl)).GetOrientation)(this, pvorientfront, pvorienttop), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetPosition(this: *mut core::ffi::c_void, pvposition: *mut super::super::super::Graphics::Direct3D::D3DVECTOR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DListener", "GetPosition", 7usize, |this| ((**(this as *const *const IDirectSound3DListener_Vtbl)).GetPosition)(this, pvposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRolloffFactor(this: *mut core::ffi::c_void, pflrollofffactor: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DListener", "GetRolloffFactor", 8usize, |this| ((**(this as *const *const IDirectSound3DListener_Vtbl)).GetRolloffFactor)(this, pflrollofffactor), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn GetVelocity(this: *mut core::ffi::c_void, pvvelocity: *mut super::super::super::Graphics::Direct3D::D3DVECTOR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DListener", "GetVelocity", 9usize, |this| ((**(this as *const *const IDirectSound3DListener_Vtbl)).GetVelocity)(this, pvvelocity), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D")]
+        unsafe extern "system" fn SetAllParameters(this: *mut core::ffi::c_void, pclistener: *const DS3DLISTENER, dwapply: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DListener", "SetAllParameters", 10usize, |this| ((**(this as *const *const IDirectSound3DListener_Vtbl)).SetAllParameters)(this, pclistener, dwapply), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDistanceFactor(this: *mut core::ffi::c_void, fldistancefactor: f32, dwapply: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DListener", "SetDistanceFactor", 11usize, |this| ((**(this as *const *const IDirectSound3DListener_Vtbl)).SetDistanceFactor)(this, fldistancefactor, dwapply), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDopplerFactor(this: *mut core::ffi::c_void, fldopplerfactor: f32, dwapply: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DListener", "SetDopplerFactor", 12usize, |this| ((**(this as *const *const IDirectSound3DListener_Vtbl)).SetDopplerFactor)(this, fldopplerfactor, dwapply), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOrientation(this: *mut core::ffi::c_void, xfront: f32, yfront: f32, zfront: f32, xtop: f32, ytop: f32, ztop: f32, dwapply: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DListener", "SetOrientation", 13usize, |this| ((**(this as *const *const IDirectSound3DListener_Vtbl)).SetOrientation)(this, xfront, yfront, zfront, xtop, ytop, ztop, dwapply), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPosition(this: *mut core::ffi::c_void, x: f32, y: f32, z: f32, dwapply: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DListener", "SetPosition", 14usize, |this| ((**(this as *const *const IDirectSound3DListener_Vtbl)).SetPosition)(this, x, y, z, dwapply), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRolloffFactor(this: *mut core::ffi::c_void, flrollofffactor: f32, dwapply: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DListener", "SetRolloffFactor", 15usize, |this| ((**(this as *const *const IDirectSound3DListener_Vtbl)).SetRolloffFactor)(this, flrollofffactor, dwapply), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVelocity(this: *mut core::ffi::c_void, x: f32, y: f32, z: f32, dwapply: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DListener", "SetVelocity", 16usize, |this| ((**(this as *const *const IDirectSound3DListener_Vtbl)).SetVelocity)(this, x, y, z, dwapply), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommitDeferredSettings(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound3DListener", "CommitDeferredSettings", 17usize, |this| ((**(this as *const *const IDirectSound3DListener_Vtbl)).CommitDeferredSettings)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetAllParameters,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetAllParameters: 0,
+            GetDistanceFactor,
+            GetDopplerFactor,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetOrientation,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetOrientation: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetPosition,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetPosition: 0,
+            GetRolloffFactor,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            GetVelocity,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            GetVelocity: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D")]
+            SetAllParameters,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D"))]
+            SetAllParameters: 0,
+            SetDistanceFactor,
+            SetDopplerFactor,
+            SetOrientation,
+            SetPosition,
+            SetRolloffFactor,
+            SetVelocity,
+            CommitDeferredSettings,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectSound3DListener as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectSound8_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VerifyCertification(this: *mut core::ffi::c_void, pdwcertified: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSound8", "VerifyCertification", 11usize, |this| ((**(this as *const *const IDirectSound8_Vtbl)).VerifyCertification)(this, pdwcertified), |result| Some(*result))
+        }
+        Self { base__: <IDirectSound_Vtbl as windows_core::imp::ProxyVtable>::PROXY, VerifyCertification }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectSound8 as windows_core::Interface>::IID || <IDirectSound_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectSoundBuffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCaps(this: *mut core::ffi::c_void, pdsbuffercaps: *mut DSBCAPS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundBuffer", "GetCaps", 3usize, |this| ((**(this as *const *const IDirectSoundBuffer_Vtbl)).GetCaps)(this, pdsbuffercaps), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentPosition(this: *mut core::ffi::c_void, pdwcurrentplaycursor: *mut u32, pdwcurrentwritecursor: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundBuffer", "GetCurrentPosition", 4usize, |this| ((**(this as *const *const IDirectSoundBuffer_Vtbl)).GetCurrentPosition)(this, pdwcurrentplaycursor, pdwcurrentwritecursor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFormat(this: *mut core::ffi::c_void, pwfxformat: *mut super::WAVEFORMATEX, dwsizeallocated: u32, pdwsizewritten: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundBuffer", "GetFormat", 5usize, |this| ((**(this as *const *const IDirectSoundBuffer_Vtbl)).GetFormat)(this, pwfxformat, dwsizeallocated, pdwsizewritten), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVolume(this: *mut core::ffi::c_void, plvolume: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundBuffer", "GetVolume", 6usize, |this| ((**(this as *const *const IDirectSoundBuffer_Vtbl)).GetVolume)(this, plvolume), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPan(this: *mut core::ffi::c_void, plpan: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundBuffer", "GetPan", 7usize, |this| ((**(this as *const *const IDirectSoundBuffer_Vtbl)).GetPan)(this, plpan), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFrequency(this: *mut core::ffi::c_void, pdwfrequency: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundBuffer", "GetFrequency", 8usize, |this| ((**(this as *const *const IDirectSoundBuffer_Vtbl)).GetFrequency)(this, pdwfrequency), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundBuffer", "GetStatus", 9usize, |this| ((**(this as *const *const IDirectSoundBuffer_Vtbl)).GetStatus)(this, pdwstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pdirectsound: *mut core::ffi::c_void, pcdsbufferdesc: *const DSBUFFERDESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundBuffer", "Initialize", 10usize, |this| ((**(this as *const *const IDirectSoundBuffer_Vtbl)).Initialize)(this, pdirectsound, pcdsbufferdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn Lock(this: *mut core::ffi::c_void, dwoffset: u32, dwbytes: u32, ppvaudioptr1: *mut *mut core::ffi::c_void, pdwaudiobytes1: *mut u32, ppvaudioptr2: *mut *mut core::ffi::c_void, pdwaudiobytes2: *mut u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundBuffer", "Lock", 11usize, |this| ((**(this as *const *const IDirectSoundBuffer_Vtbl)).Lock)(this, dwoffset, dwbytes, ppvaudioptr1, pdwaudiobytes1, ppvaudioptr2, pdwaudiobytes2, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Play(this: *mut core::ffi::c_void, dwreserved1: u32, dwpriority: u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundBuffer", "Play", 12usize, |this| ((**(this as *const *const IDirectSoundBuffer_Vtbl)).Play)(this, dwreserved1, dwpriority, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentPosition(this: *mut core::ffi::c_void, dwnewposition: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundBuffer", "SetCurrentPosition", 13usize, |this| ((**(this as *const *const IDirectSoundBuffer_Vtbl)).SetCurrentPosition)(this, dwnewposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFormat(this: *mut core::ffi::c_void, pcfxformat: *const super::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundBuffer", "SetFormat", 14usize, |this| ((**(this as *const *const IDirectSoundBuffer_Vtbl)).SetFormat)(this, pcfxformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVolume(this: *mut core::ffi::c_void, lvolume: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundBuffer", "SetVolume", 15usize, |this| ((**(this as *const *const IDirectSoundBuffer_Vtbl)).SetVolume)(this, lvolume), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPan(this: *mut core::ffi::c_void, lpan: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundBuffer", "SetPan", 16usize, |this| ((**(this as *const *const IDirectSoundBuffer_Vtbl)).SetPan)(this, lpan), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFrequency(this: *mut core::ffi::c_void, dwfrequency: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundBuffer", "SetFrequency", 17usize, |this| ((**(this as *const *const IDirectSoundBuffer_Vtbl)).SetFrequency)(this, dwfrequency), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundBuffer", "Stop", 18usize, |this| ((**(this as *const *const IDirectSoundBuffer_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unlock(this: *mut core::ffi::c_void, pvaudioptr1: *const core::ffi::c_void, dwaudiobytes1: u32, pvaudioptr2: *const core::ffi::c_void, dwaudiobytes2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundBuffer", "Unlock", 19usize, |this| ((**(this as *const *const IDirectSoundBuffer_Vtbl)).Unlock)(this, pvaudioptr1, dwaudiobytes1, pvaudioptr2, dwaudiobytes2), |result| Some(*result))
+        }
+        unsafe extern "system" fn Restore(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundBuffer", "Restore", 20usize, |this| ((**(this as *const *const IDirectSoundBuffer_Vtbl)).Restore)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCaps,
+            GetCurrentPosition,
+            GetFormat,
+            GetVolume,
+            GetPan,
+            GetFrequency,
+            GetStatus,
+            Initialize,
+            Lock,
+            Play,
+            SetCurrentPosition,
+            SetFormat,
+            SetVolume,
+            SetPan,
+            SetFrequency,
+            Stop,
+            Unlock,
+            Restore,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectSoundBuffer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectSoundBuffer8_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetFX(this: *mut core::ffi::c_void, dweffectscount: u32, pdsfxdesc: *const DSEFFECTDESC, pdwresultcodes: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundBuffer8", "SetFX", 21usize, |this| ((**(this as *const *const IDirectSoundBuffer8_Vtbl)).SetFX)(this, dweffectscount, pdsfxdesc, pdwresultcodes), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcquireResources(this: *mut core::ffi::c_void, dwflags: u32, dweffectscount: u32, pdwresultcodes: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundBuffer8", "AcquireResources", 22usize, |this| ((**(this as *const *const IDirectSoundBuffer8_Vtbl)).AcquireResources)(this, dwflags, dweffectscount, pdwresultcodes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObjectInPath(this: *mut core::ffi::c_void, rguidobject: *const windows_core::GUID, dwindex: u32, rguidinterface: *const windows_core::GUID, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundBuffer8", "GetObjectInPath", 23usize, |this| ((**(this as *const *const IDirectSoundBuffer8_Vtbl)).GetObjectInPath)(this, rguidobject, dwindex, rguidinterface, ppobject), |result| Some(*result))
+        }
+        Self { base__: <IDirectSoundBuffer_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetFX, AcquireResources, GetObjectInPath }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectSoundBuffer8 as windows_core::Interface>::IID || <IDirectSoundBuffer_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectSoundCapture_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateCaptureBuffer(this: *mut core::ffi::c_void, pcdscbufferdesc: *const DSCBUFFERDESC, ppdscbuffer: *mut *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundCapture", "CreateCaptureBuffer", 3usize, |this| ((**(this as *const *const IDirectSoundCapture_Vtbl)).CreateCaptureBuffer)(this, pcdscbufferdesc, ppdscbuffer, punkouter), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCaps(this: *mut core::ffi::c_void, pdsccaps: *mut DSCCAPS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundCapture", "GetCaps", 4usize, |this| ((**(this as *const *const IDirectSoundCapture_Vtbl)).GetCaps)(this, pdsccaps), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pcguiddevice: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundCapture", "Initialize", 5usize, |this| ((**(this as *const *const IDirectSoundCapture_Vtbl)).Initialize)(this, pcguiddevice), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateCaptureBuffer, GetCaps, Initialize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectSoundCapture as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectSoundCaptureBuffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCaps(this: *mut core::ffi::c_void, pdscbcaps: *mut DSCBCAPS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundCaptureBuffer", "GetCaps", 3usize, |this| ((**(this as *const *const IDirectSoundCaptureBuffer_Vtbl)).GetCaps)(this, pdscbcaps), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentPosition(this: *mut core::ffi::c_void, pdwcaptureposition: *mut u32, pdwreadposition: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundCaptureBuffer", "GetCurrentPosition", 4usize, |this| ((**(this as *const *const IDirectSoundCaptureBuffer_Vtbl)).GetCurrentPosition)(this, pdwcaptureposition, pdwreadposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFormat(this: *mut core::ffi::c_void, pwfxformat: *mut super::WAVEFORMATEX, dwsizeallocated: u32, pdwsizewritten: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundCaptureBuffer", "GetFormat", 5usize, |this| ((**(this as *const *const IDirectSoundCaptureBuffer_Vtbl)).GetFormat)(this, pwfxformat, dwsizeallocated, pdwsizewritten), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundCaptureBuffer", "GetStatus", 6usize, |this| ((**(this as *const *const IDirectSoundCaptureBuffer_Vtbl)).GetStatus)(this, pdwstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pdirectsoundcapture: *mut core::ffi::c_void, pcdscbufferdesc: *const DSCBUFFERDESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundCaptureBuffer", "Initialize", 7usize, |this| ((**(this as *const *const IDirectSoundCaptureBuffer_Vtbl)).Initialize)(this, pdirectsoundcapture, pcdscbufferdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn Lock(this: *mut core::ffi::c_void, dwoffset: u32, dwbytes: u32, ppvaudioptr1: *mut *mut core::ffi::c_void, pdwaudiobytes1: *mut u32, ppvaudioptr2: *mut *mut core::ffi::c_void, pdwaudiobytes2: *mut u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundCaptureBuffer", "Lock", 8usize, |this| ((**(this as *const *const IDirectSoundCaptureBuffer_Vtbl)).Lock)(this, dwoffset, dwbytes, ppvaudioptr1, pdwaudiobytes1, ppvaudioptr2, pdwaudiobytes2, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundCaptureBuffer", "Start", 9usize, |this| ((**(this as *const *const IDirectSoundCaptureBuffer_Vtbl)).Start)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundCaptureBuffer", "Stop", 10usize, |this| ((**(this as *const *const IDirectSoundCaptureBuffer_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unlock(this: *mut core::ffi::c_void, pvaudioptr1: *const core::ffi::c_void, dwaudiobytes1: u32, pvaudioptr2: *const core::ffi::c_void, dwaudiobytes2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundCaptureBuffer", "Unlock", 11usize, |this| ((**(this as *const *const IDirectSoundCaptureBuffer_Vtbl)).Unlock)(this, pvaudioptr1, dwaudiobytes1, pvaudioptr2, dwaudiobytes2), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCaps,
+            GetCurrentPosition,
+            GetFormat,
+            GetStatus,
+            Initialize,
+            Lock,
+            Start,
+            Stop,
+            Unlock,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectSoundCaptureBuffer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectSoundCaptureBuffer8_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetObjectInPath(this: *mut core::ffi::c_void, rguidobject: *const windows_core::GUID, dwindex: u32, rguidinterface: *const windows_core::GUID, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundCaptureBuffer8", "GetObjectInPath", 12usize, |this| ((**(this as *const *const IDirectSoundCaptureBuffer8_Vtbl)).GetObjectInPath)(this, rguidobject, dwindex, rguidinterface, ppobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFXStatus(this: *mut core::ffi::c_void, dweffectscount: u32, pdwfxstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundCaptureBuffer8", "GetFXStatus", 13usize, |this| ((**(this as *const *const IDirectSoundCaptureBuffer8_Vtbl)).GetFXStatus)(this, dweffectscount, pdwfxstatus), |result| Some(*result))
+        }
+        Self { base__: <IDirectSoundCaptureBuffer_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetObjectInPath, GetFXStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectSoundCaptureBuffer8 as windows_core::Interface>::IID || <IDirectSoundCaptureBuffer_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectSoundCaptureFXAec_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAllParameters(this: *mut core::ffi::c_void, pdscfxaec: *const DSCFXAec) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundCaptureFXAec", "SetAllParameters", 3usize, |this| ((**(this as *const *const IDirectSoundCaptureFXAec_Vtbl)).SetAllParameters)(this, pdscfxaec), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllParameters(this: *mut core::ffi::c_void, pdscfxaec: *mut DSCFXAec) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundCaptureFXAec", "GetAllParameters", 4usize, |this| ((**(this as *const *const IDirectSoundCaptureFXAec_Vtbl)).GetAllParameters)(this, pdscfxaec), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundCaptureFXAec", "GetStatus", 5usize, |this| ((**(this as *const *const IDirectSoundCaptureFXAec_Vtbl)).GetStatus)(this, pdwstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundCaptureFXAec", "Reset", 6usize, |this| ((**(this as *const *const IDirectSoundCaptureFXAec_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAllParameters, GetAllParameters, GetStatus, Reset }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectSoundCaptureFXAec as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectSoundCaptureFXNoiseSuppress_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAllParameters(this: *mut core::ffi::c_void, pcdscfxnoisesuppress: *const DSCFXNoiseSuppress) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundCaptureFXNoiseSuppress", "SetAllParameters", 3usize, |this| ((**(this as *const *const IDirectSoundCaptureFXNoiseSuppress_Vtbl)).SetAllParameters)(this, pcdscfxnoisesuppress), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllParameters(this: *mut core::ffi::c_void, pdscfxnoisesuppress: *mut DSCFXNoiseSuppress) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundCaptureFXNoiseSuppress", "GetAllParameters", 4usize, |this| ((**(this as *const *const IDirectSoundCaptureFXNoiseSuppress_Vtbl)).GetAllParameters)(this, pdscfxnoisesuppress), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundCaptureFXNoiseSuppress", "Reset", 5usize, |this| ((**(this as *const *const IDirectSoundCaptureFXNoiseSuppress_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAllParameters, GetAllParameters, Reset }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectSoundCaptureFXNoiseSuppress as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectSoundFXChorus_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAllParameters(this: *mut core::ffi::c_void, pcdsfxchorus: *const DSFXChorus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundFXChorus", "SetAllParameters", 3usize, |this| ((**(this as *const *const IDirectSoundFXChorus_Vtbl)).SetAllParameters)(this, pcdsfxchorus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllParameters(this: *mut core::ffi::c_void, pdsfxchorus: *mut DSFXChorus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundFXChorus", "GetAllParameters", 4usize, |this| ((**(this as *const *const IDirectSoundFXChorus_Vtbl)).GetAllParameters)(this, pdsfxchorus), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAllParameters, GetAllParameters }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectSoundFXChorus as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectSoundFXCompressor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAllParameters(this: *mut core::ffi::c_void, pcdsfxcompressor: *const DSFXCompressor) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundFXCompressor", "SetAllParameters", 3usize, |this| ((**(this as *const *const IDirectSoundFXCompressor_Vtbl)).SetAllParameters)(this, pcdsfxcompressor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllParameters(this: *mut core::ffi::c_void, pdsfxcompressor: *mut DSFXCompressor) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundFXCompressor", "GetAllParameters", 4usize, |this| ((**(this as *const *const IDirectSoundFXCompressor_Vtbl)).GetAllParameters)(this, pdsfxcompressor), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAllParameters, GetAllParameters }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectSoundFXCompressor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectSoundFXDistortion_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAllParameters(this: *mut core::ffi::c_void, pcdsfxdistortion: *const DSFXDistortion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundFXDistortion", "SetAllParameters", 3usize, |this| ((**(this as *const *const IDirectSoundFXDistortion_Vtbl)).SetAllParameters)(this, pcdsfxdistortion), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllParameters(this: *mut core::ffi::c_void, pdsfxdistortion: *mut DSFXDistortion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundFXDistortion", "GetAllParameters", 4usize, |this| ((**(this as *const *const IDirectSoundFXDistortion_Vtbl)).GetAllParameters)(this, pdsfxdistortion), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAllParameters, GetAllParameters }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectSoundFXDistortion as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectSoundFXEcho_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAllParameters(this: *mut core::ffi::c_void, pcdsfxecho: *const DSFXEcho) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundFXEcho", "SetAllParameters", 3usize, |this| ((**(this as *const *const IDirectSoundFXEcho_Vtbl)).SetAllParameters)(this, pcdsfxecho), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllParameters(this: *mut core::ffi::c_void, pdsfxecho: *mut DSFXEcho) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundFXEcho", "GetAllParameters", 4usize, |this| ((**(this as *const *const IDirectSoundFXEcho_Vtbl)).GetAllParameters)(this, pdsfxecho), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAllParameters, GetAllParameters }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectSoundFXEcho as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectSoundFXFlanger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAllParameters(this: *mut core::ffi::c_void, pcdsfxflanger: *const DSFXFlanger) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundFXFlanger", "SetAllParameters", 3usize, |this| ((**(this as *const *const IDirectSoundFXFlanger_Vtbl)).SetAllParameters)(this, pcdsfxflanger), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllParameters(this: *mut core::ffi::c_void, pdsfxflanger: *mut DSFXFlanger) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundFXFlanger", "GetAllParameters", 4usize, |this| ((**(this as *const *const IDirectSoundFXFlanger_Vtbl)).GetAllParameters)(this, pdsfxflanger), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAllParameters, GetAllParameters }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectSoundFXFlanger as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectSoundFXGargle_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAllParameters(this: *mut core::ffi::c_void, pcdsfxgargle: *const DSFXGargle) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundFXGargle", "SetAllParameters", 3usize, |this| ((**(this as *const *const IDirectSoundFXGargle_Vtbl)).SetAllParameters)(this, pcdsfxgargle), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllParameters(this: *mut core::ffi::c_void, pdsfxgargle: *mut DSFXGargle) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundFXGargle", "GetAllParameters", 4usize, |this| ((**(this as *const *const IDirectSoundFXGargle_Vtbl)).GetAllParameters)(this, pdsfxgargle), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAllParameters, GetAllParameters }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectSoundFXGargle as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectSoundFXI3DL2Reverb_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAllParameters(this: *mut core::ffi::c_void, pcdsfxi3dl2reverb: *const DSFXI3DL2Reverb) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundFXI3DL2Reverb", "SetAllParameters", 3usize, |this| ((**(this as *const *const IDirectSoundFXI3DL2Reverb_Vtbl)).SetAllParameters)(this, pcdsfxi3dl2reverb), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllParameters(this: *mut core::ffi::c_void, pdsfxi3dl2reverb: *mut DSFXI3DL2Reverb) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundFXI3DL2Reverb", "GetAllParameters", 4usize, |this| ((**(this as *const *const IDirectSoundFXI3DL2Reverb_Vtbl)).GetAllParameters)(this, pdsfxi3dl2reverb), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreset(this: *mut core::ffi::c_void, dwpreset: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundFXI3DL2Reverb", "SetPreset", 5usize, |this| ((**(this as *const *const IDirectSoundFXI3DL2Reverb_Vtbl)).SetPreset)(this, dwpreset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreset(this: *mut core::ffi::c_void, pdwpreset: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundFXI3DL2Reverb", "GetPreset", 6usize, |this| ((**(this as *const *const IDirectSoundFXI3DL2Reverb_Vtbl)).GetPreset)(this, pdwpreset), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQuality(this: *mut core::ffi::c_void, lquality: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundFXI3DL2Reverb", "SetQuality", 7usize, |this| ((**(this as *const *const IDirectSoundFXI3DL2Reverb_Vtbl)).SetQuality)(this, lquality), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetQuality(this: *mut core::ffi::c_void, plquality: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundFXI3DL2Reverb", "GetQuality", 8usize, |this| ((**(this as *const *const IDirectSoundFXI3DL2Reverb_Vtbl)).GetQuality)(this, plquality), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetAllParameters,
+            GetAllParameters,
+            SetPreset,
+            GetPreset,
+            SetQuality,
+            GetQuality,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectSoundFXI3DL2Reverb as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectSoundFXParamEq_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAllParameters(this: *mut core::ffi::c_void, pcdsfxparameq: *const DSFXParamEq) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundFXParamEq", "SetAllParameters", 3usize, |this| ((**(this as *const *const IDirectSoundFXParamEq_Vtbl)).SetAllParameters)(this, pcdsfxparameq), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllParameters(this: *mut core::ffi::c_void, pdsfxparameq: *mut DSFXParamEq) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundFXParamEq", "GetAllParameters", 4usize, |this| ((**(this as *const *const IDirectSoundFXParamEq_Vtbl)).GetAllParameters)(this, pdsfxparameq), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAllParameters, GetAllParameters }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectSoundFXParamEq as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectSoundFXWavesReverb_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAllParameters(this: *mut core::ffi::c_void, pcdsfxwavesreverb: *const DSFXWavesReverb) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundFXWavesReverb", "SetAllParameters", 3usize, |this| ((**(this as *const *const IDirectSoundFXWavesReverb_Vtbl)).SetAllParameters)(this, pcdsfxwavesreverb), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllParameters(this: *mut core::ffi::c_void, pdsfxwavesreverb: *mut DSFXWavesReverb) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundFXWavesReverb", "GetAllParameters", 4usize, |this| ((**(this as *const *const IDirectSoundFXWavesReverb_Vtbl)).GetAllParameters)(this, pdsfxwavesreverb), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAllParameters, GetAllParameters }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectSoundFXWavesReverb as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectSoundFullDuplex_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pcaptureguid: *const windows_core::GUID, prenderguid: *const windows_core::GUID, lpdscbufferdesc: *const DSCBUFFERDESC, lpdsbufferdesc: *const DSBUFFERDESC, hwnd: super::super::super::Foundation::HWND, dwlevel: u32, lplpdirectsoundcapturebuffer8: *mut *mut core::ffi::c_void, lplpdirectsoundbuffer8: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundFullDuplex", "Initialize", 3usize, |this| ((**(this as *const *const IDirectSoundFullDuplex_Vtbl)).Initialize)(this, pcaptureguid, prenderguid, lpdscbufferdesc, lpdsbufferdesc, hwnd, dwlevel, lplpdirectsoundcapturebuffer8, lplpdirectsoundbuffer8), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectSoundFullDuplex as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectSoundNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetNotificationPositions(this: *mut core::ffi::c_void, dwpositionnotifies: u32, pcpositionnotifies: *const DSBPOSITIONNOTIFY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectSoundNotify", "SetNotificationPositions", 3usize, |this| ((**(this as *const *const IDirectSoundNotify_Vtbl)).SetNotificationPositions)(this, dwpositionnotifies, pcpositionnotifies), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetNotificationPositions }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectSoundNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
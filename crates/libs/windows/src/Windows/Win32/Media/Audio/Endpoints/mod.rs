@@ -390,6 +390,7 @@ pub struct EndpointConnectorType(pub i32);
 impl windows_core::TypeKind for EndpointConnectorType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EndpointConnectorType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EndpointConnectorType").field(&self.0).finish()
@@ -414,3 +415,5 @@ impl Default for AUDIO_ENDPOINT_SHARED_CREATE_PARAMS {
 pub const DEVINTERFACE_AUDIOENDPOINTPLUGIN: windows_core::GUID = windows_core::GUID::from_u128(0x9f2f7b66_65ac_4fa6_8ae4_123c78b89313);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
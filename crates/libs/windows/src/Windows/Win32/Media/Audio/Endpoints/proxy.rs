@@ -0,0 +1,274 @@
+impl windows_core::imp::ProxyVtable for IAudioEndpointFormatControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResetToDefault(this: *mut core::ffi::c_void, resetflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointFormatControl", "ResetToDefault", 3usize, |this| ((**(this as *const *const IAudioEndpointFormatControl_Vtbl)).ResetToDefault)(this, resetflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ResetToDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioEndpointFormatControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_Audio_Apo")]
+impl windows_core::imp::ProxyVtable for IAudioEndpointLastBufferControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsLastBufferControlSupported(this: *mut core::ffi::c_void) -> super::super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IAudioEndpointLastBufferControl", "IsLastBufferControlSupported", 3usize, |this| ((**(this as *const *const IAudioEndpointLastBufferControl_Vtbl)).IsLastBufferControlSupported)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Media_Audio_Apo")]
+        unsafe extern "system" fn ReleaseOutputDataPointerForLastBuffer(this: *mut core::ffi::c_void, pconnectionproperty: *const super::Apo::APO_CONNECTION_PROPERTY) {
+            windows_core::imp::proxy_call(this, "IAudioEndpointLastBufferControl", "ReleaseOutputDataPointerForLastBuffer", 4usize, |this| ((**(this as *const *const IAudioEndpointLastBufferControl_Vtbl)).ReleaseOutputDataPointerForLastBuffer)(this, pconnectionproperty), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsLastBufferControlSupported,
+            #[cfg(feature = "Win32_Media_Audio_Apo")]
+            ReleaseOutputDataPointerForLastBuffer,
+            #[cfg(not(feature = "Win32_Media_Audio_Apo"))]
+            ReleaseOutputDataPointerForLastBuffer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioEndpointLastBufferControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioEndpointOffloadStreamMeter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMeterChannelCount(this: *mut core::ffi::c_void, pu32channelcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointOffloadStreamMeter", "GetMeterChannelCount", 3usize, |this| ((**(this as *const *const IAudioEndpointOffloadStreamMeter_Vtbl)).GetMeterChannelCount)(this, pu32channelcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMeteringData(this: *mut core::ffi::c_void, u32channelcount: u32, pf32peakvalues: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointOffloadStreamMeter", "GetMeteringData", 4usize, |this| ((**(this as *const *const IAudioEndpointOffloadStreamMeter_Vtbl)).GetMeteringData)(this, u32channelcount, pf32peakvalues), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMeterChannelCount, GetMeteringData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioEndpointOffloadStreamMeter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioEndpointOffloadStreamMute_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMute(this: *mut core::ffi::c_void, bmuted: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointOffloadStreamMute", "SetMute", 3usize, |this| ((**(this as *const *const IAudioEndpointOffloadStreamMute_Vtbl)).SetMute)(this, bmuted), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMute(this: *mut core::ffi::c_void, pbmuted: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointOffloadStreamMute", "GetMute", 4usize, |this| ((**(this as *const *const IAudioEndpointOffloadStreamMute_Vtbl)).GetMute)(this, pbmuted), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetMute, GetMute }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioEndpointOffloadStreamMute as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_KernelStreaming")]
+impl windows_core::imp::ProxyVtable for IAudioEndpointOffloadStreamVolume_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetVolumeChannelCount(this: *mut core::ffi::c_void, pu32channelcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointOffloadStreamVolume", "GetVolumeChannelCount", 3usize, |this| ((**(this as *const *const IAudioEndpointOffloadStreamVolume_Vtbl)).GetVolumeChannelCount)(this, pu32channelcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_KernelStreaming")]
+        unsafe extern "system" fn SetChannelVolumes(this: *mut core::ffi::c_void, u32channelcount: u32, pf32volumes: *const f32, u32curvetype: super::super::KernelStreaming::AUDIO_CURVE_TYPE, pcurveduration: *const i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointOffloadStreamVolume", "SetChannelVolumes", 4usize, |this| ((**(this as *const *const IAudioEndpointOffloadStreamVolume_Vtbl)).SetChannelVolumes)(this, u32channelcount, pf32volumes, u32curvetype, pcurveduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChannelVolumes(this: *mut core::ffi::c_void, u32channelcount: u32, pf32volumes: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointOffloadStreamVolume", "GetChannelVolumes", 5usize, |this| ((**(this as *const *const IAudioEndpointOffloadStreamVolume_Vtbl)).GetChannelVolumes)(this, u32channelcount, pf32volumes), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetVolumeChannelCount,
+            #[cfg(feature = "Win32_Media_KernelStreaming")]
+            SetChannelVolumes,
+            #[cfg(not(feature = "Win32_Media_KernelStreaming"))]
+            SetChannelVolumes: 0,
+            GetChannelVolumes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioEndpointOffloadStreamVolume as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioEndpointVolume_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterControlChangeNotify(this: *mut core::ffi::c_void, pnotify: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointVolume", "RegisterControlChangeNotify", 3usize, |this| ((**(this as *const *const IAudioEndpointVolume_Vtbl)).RegisterControlChangeNotify)(this, pnotify), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterControlChangeNotify(this: *mut core::ffi::c_void, pnotify: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointVolume", "UnregisterControlChangeNotify", 4usize, |this| ((**(this as *const *const IAudioEndpointVolume_Vtbl)).UnregisterControlChangeNotify)(this, pnotify), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChannelCount(this: *mut core::ffi::c_void, pnchannelcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointVolume", "GetChannelCount", 5usize, |this| ((**(this as *const *const IAudioEndpointVolume_Vtbl)).GetChannelCount)(this, pnchannelcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMasterVolumeLevel(this: *mut core::ffi::c_void, fleveldb: f32, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointVolume", "SetMasterVolumeLevel", 6usize, |this| ((**(this as *const *const IAudioEndpointVolume_Vtbl)).SetMasterVolumeLevel)(this, fleveldb, pguideventcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMasterVolumeLevelScalar(this: *mut core::ffi::c_void, flevel: f32, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointVolume", "SetMasterVolumeLevelScalar", 7usize, |this| ((**(this as *const *const IAudioEndpointVolume_Vtbl)).SetMasterVolumeLevelScalar)(this, flevel, pguideventcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMasterVolumeLevel(this: *mut core::ffi::c_void, pfleveldb: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointVolume", "GetMasterVolumeLevel", 8usize, |this| ((**(this as *const *const IAudioEndpointVolume_Vtbl)).GetMasterVolumeLevel)(this, pfleveldb), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMasterVolumeLevelScalar(this: *mut core::ffi::c_void, pflevel: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointVolume", "GetMasterVolumeLevelScalar", 9usize, |this| ((**(this as *const *const IAudioEndpointVolume_Vtbl)).GetMasterVolumeLevelScalar)(this, pflevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetChannelVolumeLevel(this: *mut core::ffi::c_void, nchannel: u32, fleveldb: f32, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointVolume", "SetChannelVolumeLevel", 10usize, |this| ((**(this as *const *const IAudioEndpointVolume_Vtbl)).SetChannelVolumeLevel)(this, nchannel, fleveldb, pguideventcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetChannelVolumeLevelScalar(this: *mut core::ffi::c_void, nchannel: u32, flevel: f32, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointVolume", "SetChannelVolumeLevelScalar", 11usize, |this| ((**(this as *const *const IAudioEndpointVolume_Vtbl)).SetChannelVolumeLevelScalar)(this, nchannel, flevel, pguideventcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChannelVolumeLevel(this: *mut core::ffi::c_void, nchannel: u32, pfleveldb: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointVolume", "GetChannelVolumeLevel", 12usize, |this| ((**(this as *const *const IAudioEndpointVolume_Vtbl)).GetChannelVolumeLevel)(this, nchannel, pfleveldb), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChannelVolumeLevelScalar(this: *mut core::ffi::c_void, nchannel: u32, pflevel: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointVolume", "GetChannelVolumeLevelScalar", 13usize, |this| ((**(this as *const *const IAudioEndpointVolume_Vtbl)).GetChannelVolumeLevelScalar)(this, nchannel, pflevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMute(this: *mut core::ffi::c_void, bmute: super::super::super::Foundation::BOOL, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointVolume", "SetMute", 14usize, |this| ((**(this as *const *const IAudioEndpointVolume_Vtbl)).SetMute)(this, bmute, pguideventcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMute(this: *mut core::ffi::c_void, pbmute: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointVolume", "GetMute", 15usize, |this| ((**(this as *const *const IAudioEndpointVolume_Vtbl)).GetMute)(this, pbmute), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVolumeStepInfo(this: *mut core::ffi::c_void, pnstep: *mut u32, pnstepcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointVolume", "GetVolumeStepInfo", 16usize, |this| ((**(this as *const *const IAudioEndpointVolume_Vtbl)).GetVolumeStepInfo)(this, pnstep, pnstepcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn VolumeStepUp(this: *mut core::ffi::c_void, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointVolume", "VolumeStepUp", 17usize, |this| ((**(this as *const *const IAudioEndpointVolume_Vtbl)).VolumeStepUp)(this, pguideventcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn VolumeStepDown(this: *mut core::ffi::c_void, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointVolume", "VolumeStepDown", 18usize, |this| ((**(this as *const *const IAudioEndpointVolume_Vtbl)).VolumeStepDown)(this, pguideventcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryHardwareSupport(this: *mut core::ffi::c_void, pdwhardwaresupportmask: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointVolume", "QueryHardwareSupport", 19usize, |this| ((**(this as *const *const IAudioEndpointVolume_Vtbl)).QueryHardwareSupport)(this, pdwhardwaresupportmask), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVolumeRange(this: *mut core::ffi::c_void, pflvolumemindb: *mut f32, pflvolumemaxdb: *mut f32, pflvolumeincrementdb: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointVolume", "GetVolumeRange", 20usize, |this| ((**(this as *const *const IAudioEndpointVolume_Vtbl)).GetVolumeRange)(this, pflvolumemindb, pflvolumemaxdb, pflvolumeincrementdb), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RegisterControlChangeNotify,
+            UnregisterControlChangeNotify,
+            GetChannelCount,
+            SetMasterVolumeLevel,
+            SetMasterVolumeLevelScalar,
+            GetMasterVolumeLevel,
+            GetMasterVolumeLevelScalar,
+            SetChannelVolumeLevel,
+            SetChannelVolumeLevelScalar,
+            GetChannelVolumeLevel,
+            GetChannelVolumeLevelScalar,
+            SetMute,
+            GetMute,
+            GetVolumeStepInfo,
+            VolumeStepUp,
+            VolumeStepDown,
+            QueryHardwareSupport,
+            GetVolumeRange,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioEndpointVolume as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioEndpointVolumeCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnNotify(this: *mut core::ffi::c_void, pnotify: *mut super::AUDIO_VOLUME_NOTIFICATION_DATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointVolumeCallback", "OnNotify", 3usize, |this| ((**(this as *const *const IAudioEndpointVolumeCallback_Vtbl)).OnNotify)(this, pnotify), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnNotify }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioEndpointVolumeCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioEndpointVolumeEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetVolumeRangeChannel(this: *mut core::ffi::c_void, ichannel: u32, pflvolumemindb: *mut f32, pflvolumemaxdb: *mut f32, pflvolumeincrementdb: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointVolumeEx", "GetVolumeRangeChannel", 21usize, |this| ((**(this as *const *const IAudioEndpointVolumeEx_Vtbl)).GetVolumeRangeChannel)(this, ichannel, pflvolumemindb, pflvolumemaxdb, pflvolumeincrementdb), |result| Some(*result))
+        }
+        Self { base__: <IAudioEndpointVolume_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetVolumeRangeChannel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioEndpointVolumeEx as windows_core::Interface>::IID || <IAudioEndpointVolume_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioLfxControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetLocalEffectsState(this: *mut core::ffi::c_void, benabled: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioLfxControl", "SetLocalEffectsState", 3usize, |this| ((**(this as *const *const IAudioLfxControl_Vtbl)).SetLocalEffectsState)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocalEffectsState(this: *mut core::ffi::c_void, pbenabled: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioLfxControl", "GetLocalEffectsState", 4usize, |this| ((**(this as *const *const IAudioLfxControl_Vtbl)).GetLocalEffectsState)(this, pbenabled), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetLocalEffectsState, GetLocalEffectsState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioLfxControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioMeterInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPeakValue(this: *mut core::ffi::c_void, pfpeak: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioMeterInformation", "GetPeakValue", 3usize, |this| ((**(this as *const *const IAudioMeterInformation_Vtbl)).GetPeakValue)(this, pfpeak), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMeteringChannelCount(this: *mut core::ffi::c_void, pnchannelcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioMeterInformation", "GetMeteringChannelCount", 4usize, |this| ((**(this as *const *const IAudioMeterInformation_Vtbl)).GetMeteringChannelCount)(this, pnchannelcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChannelsPeakValues(this: *mut core::ffi::c_void, u32channelcount: u32, afpeakvalues: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioMeterInformation", "GetChannelsPeakValues", 5usize, |this| ((**(this as *const *const IAudioMeterInformation_Vtbl)).GetChannelsPeakValues)(this, u32channelcount, afpeakvalues), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryHardwareSupport(this: *mut core::ffi::c_void, pdwhardwaresupportmask: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioMeterInformation", "QueryHardwareSupport", 6usize, |this| ((**(this as *const *const IAudioMeterInformation_Vtbl)).QueryHardwareSupport)(this, pdwhardwaresupportmask), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPeakValue,
+            GetMeteringChannelCount,
+            GetChannelsPeakValues,
+            QueryHardwareSupport,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioMeterInformation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHardwareAudioEngineBase_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAvailableOffloadConnectorCount(this: *mut core::ffi::c_void, _pwstrdeviceid: windows_core::PCWSTR, _uconnectorid: u32, _pavailableconnectorinstancecount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHardwareAudioEngineBase", "GetAvailableOffloadConnectorCount", 3usize, |this| ((**(this as *const *const IHardwareAudioEngineBase_Vtbl)).GetAvailableOffloadConnectorCount)(this, _pwstrdeviceid, _uconnectorid, _pavailableconnectorinstancecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEngineFormat(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, _brequestdeviceformat: super::super::super::Foundation::BOOL, _ppwfxformat: *mut *mut super::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHardwareAudioEngineBase", "GetEngineFormat", 4usize, |this| ((**(this as *const *const IHardwareAudioEngineBase_Vtbl)).GetEngineFormat)(this, pdevice, _brequestdeviceformat, _ppwfxformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEngineDeviceFormat(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, _pwfxformat: *mut super::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHardwareAudioEngineBase", "SetEngineDeviceFormat", 5usize, |this| ((**(this as *const *const IHardwareAudioEngineBase_Vtbl)).SetEngineDeviceFormat)(this, pdevice, _pwfxformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGfxState(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, _benable: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHardwareAudioEngineBase", "SetGfxState", 6usize, |this| ((**(this as *const *const IHardwareAudioEngineBase_Vtbl)).SetGfxState)(this, pdevice, _benable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGfxState(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, _pbenable: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHardwareAudioEngineBase", "GetGfxState", 7usize, |this| ((**(this as *const *const IHardwareAudioEngineBase_Vtbl)).GetGfxState)(this, pdevice, _pbenable), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetAvailableOffloadConnectorCount,
+            GetEngineFormat,
+            SetEngineDeviceFormat,
+            SetGfxState,
+            GetGfxState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHardwareAudioEngineBase as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -717,6 +717,7 @@ pub struct HrtfDirectivityType(pub i32);
 impl windows_core::TypeKind for HrtfDirectivityType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HrtfDirectivityType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HrtfDirectivityType").field(&self.0).finish()
@@ -728,6 +729,7 @@ pub struct HrtfDistanceDecayType(pub i32);
 impl windows_core::TypeKind for HrtfDistanceDecayType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HrtfDistanceDecayType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HrtfDistanceDecayType").field(&self.0).finish()
@@ -739,6 +741,7 @@ pub struct HrtfEnvironment(pub i32);
 impl windows_core::TypeKind for HrtfEnvironment {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HrtfEnvironment {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HrtfEnvironment").field(&self.0).finish()
@@ -750,6 +753,7 @@ pub struct XAPO_BUFFER_FLAGS(pub i32);
 impl windows_core::TypeKind for XAPO_BUFFER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XAPO_BUFFER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XAPO_BUFFER_FLAGS").field(&self.0).finish()
@@ -761,6 +765,7 @@ pub struct XAUDIO2_FILTER_TYPE(pub i32);
 impl windows_core::TypeKind for XAUDIO2_FILTER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XAUDIO2_FILTER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XAUDIO2_FILTER_TYPE").field(&self.0).finish()
@@ -853,7 +858,8 @@ impl Default for FXREVERB_PARAMETERS {
 }
 pub const FXReverb: windows_core::GUID = windows_core::GUID::from_u128(0x7d9aca56_cb68_4807_b632_b137352e8596);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HrtfApoInit {
     pub distanceDecay: *mut HrtfDistanceDecay,
     pub directivity: *mut HrtfDirectivity,
@@ -867,7 +873,8 @@ impl Default for HrtfApoInit {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HrtfDirectivity {
     pub r#type: HrtfDirectivityType,
     pub scaling: f32,
@@ -881,7 +888,8 @@ impl Default for HrtfDirectivity {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HrtfDirectivityCardioid {
     pub directivity: HrtfDirectivity,
     pub order: f32,
@@ -895,7 +903,8 @@ impl Default for HrtfDirectivityCardioid {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HrtfDirectivityCone {
     pub directivity: HrtfDirectivity,
     pub innerAngle: f32,
@@ -910,7 +919,8 @@ impl Default for HrtfDirectivityCone {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HrtfDistanceDecay {
     pub r#type: HrtfDistanceDecayType,
     pub maxGain: f32,
@@ -927,7 +937,8 @@ impl Default for HrtfDistanceDecay {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HrtfOrientation {
     pub element: [f32; 9],
 }
@@ -940,7 +951,8 @@ impl Default for HrtfOrientation {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HrtfPosition {
     pub x: f32,
     pub y: f32,
@@ -1263,3 +1275,5 @@ impl Default for XAUDIO2_VOICE_STATE {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,159 @@
+impl windows_core::imp::ProxyVtable for IXAPO_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRegistrationProperties(this: *mut core::ffi::c_void, ppregistrationproperties: *mut *mut XAPO_REGISTRATION_PROPERTIES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXAPO", "GetRegistrationProperties", 3usize, |this| ((**(this as *const *const IXAPO_Vtbl)).GetRegistrationProperties)(this, ppregistrationproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInputFormatSupported(this: *mut core::ffi::c_void, poutputformat: *const super::WAVEFORMATEX, prequestedinputformat: *const super::WAVEFORMATEX, ppsupportedinputformat: *mut *mut super::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXAPO", "IsInputFormatSupported", 4usize, |this| ((**(this as *const *const IXAPO_Vtbl)).IsInputFormatSupported)(this, poutputformat, prequestedinputformat, ppsupportedinputformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOutputFormatSupported(this: *mut core::ffi::c_void, pinputformat: *const super::WAVEFORMATEX, prequestedoutputformat: *const super::WAVEFORMATEX, ppsupportedoutputformat: *mut *mut super::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXAPO", "IsOutputFormatSupported", 5usize, |this| ((**(this as *const *const IXAPO_Vtbl)).IsOutputFormatSupported)(this, pinputformat, prequestedoutputformat, ppsupportedoutputformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pdata: *const core::ffi::c_void, databytesize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXAPO", "Initialize", 6usize, |this| ((**(this as *const *const IXAPO_Vtbl)).Initialize)(this, pdata, databytesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IXAPO", "Reset", 7usize, |this| ((**(this as *const *const IXAPO_Vtbl)).Reset)(this), |_| None)
+        }
+        unsafe extern "system" fn LockForProcess(this: *mut core::ffi::c_void, inputlockedparametercount: u32, pinputlockedparameters: *const XAPO_LOCKFORPROCESS_PARAMETERS, outputlockedparametercount: u32, poutputlockedparameters: *const XAPO_LOCKFORPROCESS_PARAMETERS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXAPO", "LockForProcess", 8usize, |this| ((**(this as *const *const IXAPO_Vtbl)).LockForProcess)(this, inputlockedparametercount, pinputlockedparameters, outputlockedparametercount, poutputlockedparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockForProcess(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IXAPO", "UnlockForProcess", 9usize, |this| ((**(this as *const *const IXAPO_Vtbl)).UnlockForProcess)(this), |_| None)
+        }
+        unsafe extern "system" fn Process(this: *mut core::ffi::c_void, inputprocessparametercount: u32, pinputprocessparameters: *const XAPO_PROCESS_BUFFER_PARAMETERS, outputprocessparametercount: u32, poutputprocessparameters: *mut XAPO_PROCESS_BUFFER_PARAMETERS, isenabled: super::super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "IXAPO", "Process", 10usize, |this| ((**(this as *const *const IXAPO_Vtbl)).Process)(this, inputprocessparametercount, pinputprocessparameters, outputprocessparametercount, poutputprocessparameters, isenabled), |_| None)
+        }
+        unsafe extern "system" fn CalcInputFrames(this: *mut core::ffi::c_void, outputframecount: u32) -> u32 {
+            windows_core::imp::proxy_call(this, "IXAPO", "CalcInputFrames", 11usize, |this| ((**(this as *const *const IXAPO_Vtbl)).CalcInputFrames)(this, outputframecount), |_| None)
+        }
+        unsafe extern "system" fn CalcOutputFrames(this: *mut core::ffi::c_void, inputframecount: u32) -> u32 {
+            windows_core::imp::proxy_call(this, "IXAPO", "CalcOutputFrames", 12usize, |this| ((**(this as *const *const IXAPO_Vtbl)).CalcOutputFrames)(this, inputframecount), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetRegistrationProperties,
+            IsInputFormatSupported,
+            IsOutputFormatSupported,
+            Initialize,
+            Reset,
+            LockForProcess,
+            UnlockForProcess,
+            Process,
+            CalcInputFrames,
+            CalcOutputFrames,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXAPO as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXAPOHrtfParameters_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSourcePosition(this: *mut core::ffi::c_void, position: *const HrtfPosition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXAPOHrtfParameters", "SetSourcePosition", 3usize, |this| ((**(this as *const *const IXAPOHrtfParameters_Vtbl)).SetSourcePosition)(this, position), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSourceOrientation(this: *mut core::ffi::c_void, orientation: *const HrtfOrientation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXAPOHrtfParameters", "SetSourceOrientation", 4usize, |this| ((**(this as *const *const IXAPOHrtfParameters_Vtbl)).SetSourceOrientation)(this, orientation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSourceGain(this: *mut core::ffi::c_void, gain: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXAPOHrtfParameters", "SetSourceGain", 5usize, |this| ((**(this as *const *const IXAPOHrtfParameters_Vtbl)).SetSourceGain)(this, gain), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnvironment(this: *mut core::ffi::c_void, environment: HrtfEnvironment) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXAPOHrtfParameters", "SetEnvironment", 6usize, |this| ((**(this as *const *const IXAPOHrtfParameters_Vtbl)).SetEnvironment)(this, environment), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetSourcePosition,
+            SetSourceOrientation,
+            SetSourceGain,
+            SetEnvironment,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXAPOHrtfParameters as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXAPOParameters_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetParameters(this: *mut core::ffi::c_void, pparameters: *const core::ffi::c_void, parameterbytesize: u32) {
+            windows_core::imp::proxy_call(this, "IXAPOParameters", "SetParameters", 3usize, |this| ((**(this as *const *const IXAPOParameters_Vtbl)).SetParameters)(this, pparameters, parameterbytesize), |_| None)
+        }
+        unsafe extern "system" fn GetParameters(this: *mut core::ffi::c_void, pparameters: *mut core::ffi::c_void, parameterbytesize: u32) {
+            windows_core::imp::proxy_call(this, "IXAPOParameters", "GetParameters", 4usize, |this| ((**(this as *const *const IXAPOParameters_Vtbl)).GetParameters)(this, pparameters, parameterbytesize), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetParameters, GetParameters }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXAPOParameters as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXAudio2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterForCallbacks(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXAudio2", "RegisterForCallbacks", 3usize, |this| ((**(this as *const *const IXAudio2_Vtbl)).RegisterForCallbacks)(this, pcallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterForCallbacks(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IXAudio2", "UnregisterForCallbacks", 4usize, |this| ((**(this as *const *const IXAudio2_Vtbl)).UnregisterForCallbacks)(this, pcallback), |_| None)
+        }
+        unsafe extern "system" fn CreateSourceVoice(this: *mut core::ffi::c_void, ppsourcevoice: *mut *mut core::ffi::c_void, psourceformat: *const super::WAVEFORMATEX, flags: u32, maxfrequencyratio: f32, pcallback: *mut core::ffi::c_void, psendlist: *const XAUDIO2_VOICE_SENDS, peffectchain: *const XAUDIO2_EFFECT_CHAIN) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXAudio2", "CreateSourceVoice", 5usize, |this| ((**(this as *const *const IXAudio2_Vtbl)).CreateSourceVoice)(this, ppsourcevoice, psourceformat, flags, maxfrequencyratio, pcallback, psendlist, peffectchain), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSubmixVoice(this: *mut core::ffi::c_void, ppsubmixvoice: *mut *mut core::ffi::c_void, inputchannels: u32, inputsamplerate: u32, flags: u32, processingstage: u32, psendlist: *const XAUDIO2_VOICE_SENDS, peffectchain: *const XAUDIO2_EFFECT_CHAIN) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXAudio2", "CreateSubmixVoice", 6usize, |this| ((**(this as *const *const IXAudio2_Vtbl)).CreateSubmixVoice)(this, ppsubmixvoice, inputchannels, inputsamplerate, flags, processingstage, psendlist, peffectchain), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateMasteringVoice(this: *mut core::ffi::c_void, ppmasteringvoice: *mut *mut core::ffi::c_void, inputchannels: u32, inputsamplerate: u32, flags: u32, szdeviceid: windows_core::PCWSTR, peffectchain: *const XAUDIO2_EFFECT_CHAIN, streamcategory: super::AUDIO_STREAM_CATEGORY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXAudio2", "CreateMasteringVoice", 7usize, |this| ((**(this as *const *const IXAudio2_Vtbl)).CreateMasteringVoice)(this, ppmasteringvoice, inputchannels, inputsamplerate, flags, szdeviceid, peffectchain, streamcategory), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartEngine(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXAudio2", "StartEngine", 8usize, |this| ((**(this as *const *const IXAudio2_Vtbl)).StartEngine)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopEngine(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IXAudio2", "StopEngine", 9usize, |this| ((**(this as *const *const IXAudio2_Vtbl)).StopEngine)(this), |_| None)
+        }
+        unsafe extern "system" fn CommitChanges(this: *mut core::ffi::c_void, operationset: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXAudio2", "CommitChanges", 10usize, |this| ((**(this as *const *const IXAudio2_Vtbl)).CommitChanges)(this, operationset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPerformanceData(this: *mut core::ffi::c_void, pperfdata: *mut XAUDIO2_PERFORMANCE_DATA) {
+            windows_core::imp::proxy_call(this, "IXAudio2", "GetPerformanceData", 11usize, |this| ((**(this as *const *const IXAudio2_Vtbl)).GetPerformanceData)(this, pperfdata), |_| None)
+        }
+        unsafe extern "system" fn SetDebugConfiguration(this: *mut core::ffi::c_void, pdebugconfiguration: *const XAUDIO2_DEBUG_CONFIGURATION, preserved: *const core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IXAudio2", "SetDebugConfiguration", 12usize, |this| ((**(this as *const *const IXAudio2_Vtbl)).SetDebugConfiguration)(this, pdebugconfiguration, preserved), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RegisterForCallbacks,
+            UnregisterForCallbacks,
+            CreateSourceVoice,
+            CreateSubmixVoice,
+            CreateMasteringVoice,
+            StartEngine,
+            StopEngine,
+            CommitChanges,
+            GetPerformanceData,
+            SetDebugConfiguration,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXAudio2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXAudio2Extension_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProcessingQuantum(this: *mut core::ffi::c_void, quantumnumerator: *mut u32, quantumdenominator: *mut u32) {
+            windows_core::imp::proxy_call(this, "IXAudio2Extension", "GetProcessingQuantum", 3usize, |this| ((**(this as *const *const IXAudio2Extension_Vtbl)).GetProcessingQuantum)(this, quantumnumerator, quantumdenominator), |_| None)
+        }
+        unsafe extern "system" fn GetProcessor(this: *mut core::ffi::c_void, processor: *mut u32) {
+            windows_core::imp::proxy_call(this, "IXAudio2Extension", "GetProcessor", 4usize, |this| ((**(this as *const *const IXAudio2Extension_Vtbl)).GetProcessor)(this, processor), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProcessingQuantum, GetProcessor }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXAudio2Extension as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
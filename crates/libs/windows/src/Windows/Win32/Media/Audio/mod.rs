@@ -4141,6 +4141,7 @@ pub struct AMBISONICS_CHANNEL_ORDERING(pub i32);
 impl windows_core::TypeKind for AMBISONICS_CHANNEL_ORDERING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AMBISONICS_CHANNEL_ORDERING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AMBISONICS_CHANNEL_ORDERING").field(&self.0).finish()
@@ -4152,6 +4153,7 @@ pub struct AMBISONICS_NORMALIZATION(pub i32);
 impl windows_core::TypeKind for AMBISONICS_NORMALIZATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AMBISONICS_NORMALIZATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AMBISONICS_NORMALIZATION").field(&self.0).finish()
@@ -4163,6 +4165,7 @@ pub struct AMBISONICS_TYPE(pub i32);
 impl windows_core::TypeKind for AMBISONICS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AMBISONICS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AMBISONICS_TYPE").field(&self.0).finish()
@@ -4174,6 +4177,7 @@ pub struct AUDCLNT_SHAREMODE(pub i32);
 impl windows_core::TypeKind for AUDCLNT_SHAREMODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AUDCLNT_SHAREMODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AUDCLNT_SHAREMODE").field(&self.0).finish()
@@ -4185,6 +4189,7 @@ pub struct AUDCLNT_STREAMOPTIONS(pub i32);
 impl windows_core::TypeKind for AUDCLNT_STREAMOPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AUDCLNT_STREAMOPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AUDCLNT_STREAMOPTIONS").field(&self.0).finish()
@@ -4229,6 +4234,7 @@ pub struct AUDIOCLIENT_ACTIVATION_TYPE(pub i32);
 impl windows_core::TypeKind for AUDIOCLIENT_ACTIVATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AUDIOCLIENT_ACTIVATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AUDIOCLIENT_ACTIVATION_TYPE").field(&self.0).finish()
@@ -4240,6 +4246,7 @@ pub struct AUDIO_DUCKING_OPTIONS(pub i32);
 impl windows_core::TypeKind for AUDIO_DUCKING_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AUDIO_DUCKING_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AUDIO_DUCKING_OPTIONS").field(&self.0).finish()
@@ -4284,6 +4291,7 @@ pub struct AUDIO_EFFECT_STATE(pub i32);
 impl windows_core::TypeKind for AUDIO_EFFECT_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AUDIO_EFFECT_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AUDIO_EFFECT_STATE").field(&self.0).finish()
@@ -4295,6 +4303,7 @@ pub struct AUDIO_STREAM_CATEGORY(pub i32);
 impl windows_core::TypeKind for AUDIO_STREAM_CATEGORY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AUDIO_STREAM_CATEGORY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AUDIO_STREAM_CATEGORY").field(&self.0).finish()
@@ -4306,6 +4315,7 @@ pub struct AUDIO_SYSTEMEFFECTS_PROPERTYSTORE_TYPE(pub i32);
 impl windows_core::TypeKind for AUDIO_SYSTEMEFFECTS_PROPERTYSTORE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AUDIO_SYSTEMEFFECTS_PROPERTYSTORE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AUDIO_SYSTEMEFFECTS_PROPERTYSTORE_TYPE").field(&self.0).finish()
@@ -4317,6 +4327,7 @@ pub struct AudioObjectType(pub i32);
 impl windows_core::TypeKind for AudioObjectType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AudioObjectType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AudioObjectType").field(&self.0).finish()
@@ -4361,6 +4372,7 @@ pub struct AudioSessionDisconnectReason(pub i32);
 impl windows_core::TypeKind for AudioSessionDisconnectReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AudioSessionDisconnectReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AudioSessionDisconnectReason").field(&self.0).finish()
@@ -4372,6 +4384,7 @@ pub struct AudioSessionState(pub i32);
 impl windows_core::TypeKind for AudioSessionState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AudioSessionState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AudioSessionState").field(&self.0).finish()
@@ -4383,6 +4396,7 @@ pub struct AudioStateMonitorSoundLevel(pub i32);
 impl windows_core::TypeKind for AudioStateMonitorSoundLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AudioStateMonitorSoundLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AudioStateMonitorSoundLevel").field(&self.0).finish()
@@ -4402,6 +4416,7 @@ impl ConnectorType {
 impl windows_core::TypeKind for ConnectorType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ConnectorType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ConnectorType").field(&self.0).finish()
@@ -4413,6 +4428,7 @@ pub struct DEVICE_STATE(pub u32);
 impl windows_core::TypeKind for DEVICE_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEVICE_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEVICE_STATE").field(&self.0).finish()
@@ -4424,6 +4440,7 @@ pub struct DataFlow(pub i32);
 impl windows_core::TypeKind for DataFlow {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DataFlow {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DataFlow").field(&self.0).finish()
@@ -4435,6 +4452,7 @@ pub struct EDataFlow(pub i32);
 impl windows_core::TypeKind for EDataFlow {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EDataFlow {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EDataFlow").field(&self.0).finish()
@@ -4446,6 +4464,7 @@ pub struct ERole(pub i32);
 impl windows_core::TypeKind for ERole {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ERole {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ERole").field(&self.0).finish()
@@ -4457,6 +4476,7 @@ pub struct EndpointFormFactor(pub i32);
 impl windows_core::TypeKind for EndpointFormFactor {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EndpointFormFactor {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EndpointFormFactor").field(&self.0).finish()
@@ -4468,6 +4488,7 @@ pub struct MIDI_WAVE_OPEN_TYPE(pub u32);
 impl windows_core::TypeKind for MIDI_WAVE_OPEN_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MIDI_WAVE_OPEN_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MIDI_WAVE_OPEN_TYPE").field(&self.0).finish()
@@ -4512,6 +4533,7 @@ pub struct MIXERLINE_COMPONENTTYPE(pub u32);
 impl windows_core::TypeKind for MIXERLINE_COMPONENTTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MIXERLINE_COMPONENTTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MIXERLINE_COMPONENTTYPE").field(&self.0).finish()
@@ -4523,6 +4545,7 @@ pub struct PROCESS_LOOPBACK_MODE(pub i32);
 impl windows_core::TypeKind for PROCESS_LOOPBACK_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROCESS_LOOPBACK_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROCESS_LOOPBACK_MODE").field(&self.0).finish()
@@ -4534,6 +4557,7 @@ pub struct PartType(pub i32);
 impl windows_core::TypeKind for PartType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PartType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PartType").field(&self.0).finish()
@@ -4545,6 +4569,7 @@ pub struct SND_FLAGS(pub u32);
 impl windows_core::TypeKind for SND_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SND_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SND_FLAGS").field(&self.0).finish()
@@ -4589,6 +4614,7 @@ pub struct SPATIAL_AUDIO_STREAM_OPTIONS(pub i32);
 impl windows_core::TypeKind for SPATIAL_AUDIO_STREAM_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPATIAL_AUDIO_STREAM_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPATIAL_AUDIO_STREAM_OPTIONS").field(&self.0).finish()
@@ -4633,6 +4659,7 @@ pub struct SpatialAudioHrtfDirectivityType(pub i32);
 impl windows_core::TypeKind for SpatialAudioHrtfDirectivityType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpatialAudioHrtfDirectivityType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpatialAudioHrtfDirectivityType").field(&self.0).finish()
@@ -4644,6 +4671,7 @@ pub struct SpatialAudioHrtfDistanceDecayType(pub i32);
 impl windows_core::TypeKind for SpatialAudioHrtfDistanceDecayType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpatialAudioHrtfDistanceDecayType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpatialAudioHrtfDistanceDecayType").field(&self.0).finish()
@@ -4655,6 +4683,7 @@ pub struct SpatialAudioHrtfEnvironmentType(pub i32);
 impl windows_core::TypeKind for SpatialAudioHrtfEnvironmentType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpatialAudioHrtfEnvironmentType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpatialAudioHrtfEnvironmentType").field(&self.0).finish()
@@ -4666,6 +4695,7 @@ pub struct SpatialAudioMetadataCopyMode(pub i32);
 impl windows_core::TypeKind for SpatialAudioMetadataCopyMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpatialAudioMetadataCopyMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpatialAudioMetadataCopyMode").field(&self.0).finish()
@@ -4677,6 +4707,7 @@ pub struct SpatialAudioMetadataWriterOverflowMode(pub i32);
 impl windows_core::TypeKind for SpatialAudioMetadataWriterOverflowMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpatialAudioMetadataWriterOverflowMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpatialAudioMetadataWriterOverflowMode").field(&self.0).finish()
@@ -4688,6 +4719,7 @@ pub struct _AUDCLNT_BUFFERFLAGS(pub i32);
 impl windows_core::TypeKind for _AUDCLNT_BUFFERFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _AUDCLNT_BUFFERFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_AUDCLNT_BUFFERFLAGS").field(&self.0).finish()
@@ -5186,7 +5218,8 @@ impl Default for ACMSTREAMHEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AMBISONICS_PARAMS {
     pub u32Size: u32,
     pub u32Version: u32,
@@ -5233,7 +5266,8 @@ impl Default for AUDIOCLIENT_ACTIVATION_PARAMS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AUDIOCLIENT_PROCESS_LOOPBACK_PARAMS {
     pub TargetProcessId: u32,
     pub ProcessLoopbackMode: PROCESS_LOOPBACK_MODE,
@@ -5247,7 +5281,8 @@ impl Default for AUDIOCLIENT_PROCESS_LOOPBACK_PARAMS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AUDIO_EFFECT {
     pub id: windows_core::GUID,
     pub canSetState: super::super::Foundation::BOOL,
@@ -5262,7 +5297,8 @@ impl Default for AUDIO_EFFECT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AUDIO_VOLUME_NOTIFICATION_DATA {
     pub guidEventContext: windows_core::GUID,
     pub bMuted: super::super::Foundation::BOOL,
@@ -5361,7 +5397,8 @@ impl Default for AUXCAPSW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AudioClient3ActivationParams {
     pub tracingContextId: windows_core::GUID,
 }
@@ -5374,7 +5411,8 @@ impl Default for AudioClient3ActivationParams {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AudioClientProperties {
     pub cbSize: u32,
     pub bIsOffload: super::super::Foundation::BOOL,
@@ -5390,7 +5428,8 @@ impl Default for AudioClientProperties {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AudioExtensionParams {
     pub AddPageParam: super::super::Foundation::LPARAM,
     pub pEndpoint: core::mem::ManuallyDrop<Option<IMMDevice>>,
@@ -5411,7 +5450,8 @@ impl Default for AudioExtensionParams {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DIRECTX_AUDIO_ACTIVATION_PARAMS {
     pub cbDirectXAudioActivationParams: u32,
     pub guidAudioSession: windows_core::GUID,
@@ -5442,7 +5482,8 @@ impl Default for ECHOWAVEFILTER {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HACMDRIVER(pub *mut core::ffi::c_void);
 impl HACMDRIVER {
     pub fn is_invalid(&self) -> bool {
@@ -5466,7 +5507,8 @@ impl windows_core::TypeKind for HACMDRIVER {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HACMDRIVERID(pub *mut core::ffi::c_void);
 impl HACMDRIVERID {
     pub fn is_invalid(&self) -> bool {
@@ -5482,7 +5524,8 @@ impl windows_core::TypeKind for HACMDRIVERID {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HACMOBJ(pub *mut core::ffi::c_void);
 impl HACMOBJ {
     pub fn is_invalid(&self) -> bool {
@@ -5498,7 +5541,8 @@ impl windows_core::TypeKind for HACMOBJ {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HACMSTREAM(pub *mut core::ffi::c_void);
 impl HACMSTREAM {
     pub fn is_invalid(&self) -> bool {
@@ -5522,7 +5566,8 @@ impl windows_core::TypeKind for HACMSTREAM {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HMIDI(pub *mut core::ffi::c_void);
 impl HMIDI {
     pub fn is_invalid(&self) -> bool {
@@ -5538,7 +5583,8 @@ impl windows_core::TypeKind for HMIDI {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HMIDIIN(pub *mut core::ffi::c_void);
 impl HMIDIIN {
     pub fn is_invalid(&self) -> bool {
@@ -5562,7 +5608,8 @@ impl windows_core::TypeKind for HMIDIIN {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HMIDIOUT(pub *mut core::ffi::c_void);
 impl HMIDIOUT {
     pub fn is_invalid(&self) -> bool {
@@ -5586,7 +5633,8 @@ impl windows_core::TypeKind for HMIDIOUT {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HMIDISTRM(pub *mut core::ffi::c_void);
 impl HMIDISTRM {
     pub fn is_invalid(&self) -> bool {
@@ -5610,7 +5658,8 @@ impl windows_core::TypeKind for HMIDISTRM {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HMIXER(pub *mut core::ffi::c_void);
 impl HMIXER {
     pub fn is_invalid(&self) -> bool {
@@ -5634,7 +5683,8 @@ impl windows_core::TypeKind for HMIXER {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HMIXEROBJ(pub *mut core::ffi::c_void);
 impl HMIXEROBJ {
     pub fn is_invalid(&self) -> bool {
@@ -5650,7 +5700,8 @@ impl windows_core::TypeKind for HMIXEROBJ {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HWAVE(pub *mut core::ffi::c_void);
 impl HWAVE {
     pub fn is_invalid(&self) -> bool {
@@ -5666,7 +5717,8 @@ impl windows_core::TypeKind for HWAVE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HWAVEIN(pub *mut core::ffi::c_void);
 impl HWAVEIN {
     pub fn is_invalid(&self) -> bool {
@@ -5690,7 +5742,8 @@ impl windows_core::TypeKind for HWAVEIN {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HWAVEOUT(pub *mut core::ffi::c_void);
 impl HWAVEOUT {
     pub fn is_invalid(&self) -> bool {
@@ -6460,7 +6513,8 @@ impl Default for PCMWAVEFORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SpatialAudioClientActivationParams {
     pub tracingContextId: windows_core::GUID,
     pub appId: windows_core::GUID,
@@ -7018,3 +7072,5 @@ pub type LPWAVECALLBACK = Option<unsafe extern "system" fn(hdrvr: super::Multime
 pub type PAudioStateMonitorCallback = Option<unsafe extern "system" fn(audiostatemonitor: Option<IAudioStateMonitor>, context: *const core::ffi::c_void)>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1628 @@
+impl windows_core::imp::ProxyVtable for IAcousticEchoCancellationControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetEchoCancellationRenderEndpoint(this: *mut core::ffi::c_void, endpointid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAcousticEchoCancellationControl", "SetEchoCancellationRenderEndpoint", 3usize, |this| ((**(this as *const *const IAcousticEchoCancellationControl_Vtbl)).SetEchoCancellationRenderEndpoint)(this, endpointid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetEchoCancellationRenderEndpoint }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAcousticEchoCancellationControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActivateAudioInterfaceAsyncOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetActivateResult(this: *mut core::ffi::c_void, activateresult: *mut windows_core::HRESULT, activatedinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivateAudioInterfaceAsyncOperation", "GetActivateResult", 3usize, |this| ((**(this as *const *const IActivateAudioInterfaceAsyncOperation_Vtbl)).GetActivateResult)(this, activateresult, activatedinterface), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetActivateResult }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActivateAudioInterfaceAsyncOperation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActivateAudioInterfaceCompletionHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ActivateCompleted(this: *mut core::ffi::c_void, activateoperation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivateAudioInterfaceCompletionHandler", "ActivateCompleted", 3usize, |this| ((**(this as *const *const IActivateAudioInterfaceCompletionHandler_Vtbl)).ActivateCompleted)(this, activateoperation), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ActivateCompleted }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActivateAudioInterfaceCompletionHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioAmbisonicsControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, pambisonicsparams: *const AMBISONICS_PARAMS, cbambisonicsparams: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioAmbisonicsControl", "SetData", 3usize, |this| ((**(this as *const *const IAudioAmbisonicsControl_Vtbl)).SetData)(this, pambisonicsparams, cbambisonicsparams), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHeadTracking(this: *mut core::ffi::c_void, benableheadtracking: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioAmbisonicsControl", "SetHeadTracking", 4usize, |this| ((**(this as *const *const IAudioAmbisonicsControl_Vtbl)).SetHeadTracking)(this, benableheadtracking), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHeadTracking(this: *mut core::ffi::c_void, pbenableheadtracking: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioAmbisonicsControl", "GetHeadTracking", 5usize, |this| ((**(this as *const *const IAudioAmbisonicsControl_Vtbl)).GetHeadTracking)(this, pbenableheadtracking), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRotation(this: *mut core::ffi::c_void, x: f32, y: f32, z: f32, w: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioAmbisonicsControl", "SetRotation", 6usize, |this| ((**(this as *const *const IAudioAmbisonicsControl_Vtbl)).SetRotation)(this, x, y, z, w), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetData, SetHeadTracking, GetHeadTracking, SetRotation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioAmbisonicsControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioAutoGainControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEnabled(this: *mut core::ffi::c_void, pbenabled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioAutoGainControl", "GetEnabled", 3usize, |this| ((**(this as *const *const IAudioAutoGainControl_Vtbl)).GetEnabled)(this, pbenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, benable: super::super::Foundation::BOOL, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioAutoGainControl", "SetEnabled", 4usize, |this| ((**(this as *const *const IAudioAutoGainControl_Vtbl)).SetEnabled)(this, benable, pguideventcontext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetEnabled, SetEnabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioAutoGainControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioBass_Vtbl {
+    const PROXY: Self = { Self { base__: <IPerChannelDbLevel_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioBass as windows_core::Interface>::IID || <IPerChannelDbLevel_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioCaptureClient_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBuffer(this: *mut core::ffi::c_void, ppdata: *mut *mut u8, pnumframestoread: *mut u32, pdwflags: *mut u32, pu64deviceposition: *mut u64, pu64qpcposition: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioCaptureClient", "GetBuffer", 3usize, |this| ((**(this as *const *const IAudioCaptureClient_Vtbl)).GetBuffer)(this, ppdata, pnumframestoread, pdwflags, pu64deviceposition, pu64qpcposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseBuffer(this: *mut core::ffi::c_void, numframesread: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioCaptureClient", "ReleaseBuffer", 4usize, |this| ((**(this as *const *const IAudioCaptureClient_Vtbl)).ReleaseBuffer)(this, numframesread), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextPacketSize(this: *mut core::ffi::c_void, pnumframesinnextpacket: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioCaptureClient", "GetNextPacketSize", 5usize, |this| ((**(this as *const *const IAudioCaptureClient_Vtbl)).GetNextPacketSize)(this, pnumframesinnextpacket), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetBuffer, ReleaseBuffer, GetNextPacketSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioCaptureClient as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioChannelConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetChannelConfig(this: *mut core::ffi::c_void, dwconfig: u32, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioChannelConfig", "SetChannelConfig", 3usize, |this| ((**(this as *const *const IAudioChannelConfig_Vtbl)).SetChannelConfig)(this, dwconfig, pguideventcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChannelConfig(this: *mut core::ffi::c_void, pdwconfig: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioChannelConfig", "GetChannelConfig", 4usize, |this| ((**(this as *const *const IAudioChannelConfig_Vtbl)).GetChannelConfig)(this, pdwconfig), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetChannelConfig, GetChannelConfig }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioChannelConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioClient_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, sharemode: AUDCLNT_SHAREMODE, streamflags: u32, hnsbufferduration: i64, hnsperiodicity: i64, pformat: *const WAVEFORMATEX, audiosessionguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioClient", "Initialize", 3usize, |this| ((**(this as *const *const IAudioClient_Vtbl)).Initialize)(this, sharemode, streamflags, hnsbufferduration, hnsperiodicity, pformat, audiosessionguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBufferSize(this: *mut core::ffi::c_void, pnumbufferframes: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioClient", "GetBufferSize", 4usize, |this| ((**(this as *const *const IAudioClient_Vtbl)).GetBufferSize)(this, pnumbufferframes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamLatency(this: *mut core::ffi::c_void, phnslatency: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioClient", "GetStreamLatency", 5usize, |this| ((**(this as *const *const IAudioClient_Vtbl)).GetStreamLatency)(this, phnslatency), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentPadding(this: *mut core::ffi::c_void, pnumpaddingframes: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioClient", "GetCurrentPadding", 6usize, |this| ((**(this as *const *const IAudioClient_Vtbl)).GetCurrentPadding)(this, pnumpaddingframes), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFormatSupported(this: *mut core::ffi::c_void, sharemode: AUDCLNT_SHAREMODE, pformat: *const WAVEFORMATEX, ppclosestmatch: *mut *mut WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioClient", "IsFormatSupported", 7usize, |this| ((**(this as *const *const IAudioClient_Vtbl)).IsFormatSupported)(this, sharemode, pformat, ppclosestmatch), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMixFormat(this: *mut core::ffi::c_void, ppdeviceformat: *mut *mut WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioClient", "GetMixFormat", 8usize, |this| ((**(this as *const *const IAudioClient_Vtbl)).GetMixFormat)(this, ppdeviceformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDevicePeriod(this: *mut core::ffi::c_void, phnsdefaultdeviceperiod: *mut i64, phnsminimumdeviceperiod: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioClient", "GetDevicePeriod", 9usize, |this| ((**(this as *const *const IAudioClient_Vtbl)).GetDevicePeriod)(this, phnsdefaultdeviceperiod, phnsminimumdeviceperiod), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioClient", "Start", 10usize, |this| ((**(this as *const *const IAudioClient_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioClient", "Stop", 11usize, |this| ((**(this as *const *const IAudioClient_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioClient", "Reset", 12usize, |this| ((**(this as *const *const IAudioClient_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventHandle(this: *mut core::ffi::c_void, eventhandle: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioClient", "SetEventHandle", 13usize, |this| ((**(this as *const *const IAudioClient_Vtbl)).SetEventHandle)(this, eventhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetService(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioClient", "GetService", 14usize, |this| ((**(this as *const *const IAudioClient_Vtbl)).GetService)(this, riid, ppv), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetBufferSize,
+            GetStreamLatency,
+            GetCurrentPadding,
+            IsFormatSupported,
+            GetMixFormat,
+            GetDevicePeriod,
+            Start,
+            Stop,
+            Reset,
+            SetEventHandle,
+            GetService,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioClient as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioClient2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsOffloadCapable(this: *mut core::ffi::c_void, category: AUDIO_STREAM_CATEGORY, pboffloadcapable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioClient2", "IsOffloadCapable", 15usize, |this| ((**(this as *const *const IAudioClient2_Vtbl)).IsOffloadCapable)(this, category, pboffloadcapable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClientProperties(this: *mut core::ffi::c_void, pproperties: *const AudioClientProperties) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioClient2", "SetClientProperties", 16usize, |this| ((**(this as *const *const IAudioClient2_Vtbl)).SetClientProperties)(this, pproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBufferSizeLimits(this: *mut core::ffi::c_void, pformat: *const WAVEFORMATEX, beventdriven: super::super::Foundation::BOOL, phnsminbufferduration: *mut i64, phnsmaxbufferduration: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioClient2", "GetBufferSizeLimits", 17usize, |this| ((**(this as *const *const IAudioClient2_Vtbl)).GetBufferSizeLimits)(this, pformat, beventdriven, phnsminbufferduration, phnsmaxbufferduration), |result| Some(*result))
+        }
+        Self { base__: <IAudioClient_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsOffloadCapable, SetClientProperties, GetBufferSizeLimits }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioClient2 as windows_core::Interface>::IID || <IAudioClient_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioClient3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSharedModeEnginePeriod(this: *mut core::ffi::c_void, pformat: *const WAVEFORMATEX, pdefaultperiodinframes: *mut u32, pfundamentalperiodinframes: *mut u32, pminperiodinframes: *mut u32, pmaxperiodinframes: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioClient3", "GetSharedModeEnginePeriod", 18usize, |this| ((**(this as *const *const IAudioClient3_Vtbl)).GetSharedModeEnginePeriod)(this, pformat, pdefaultperiodinframes, pfundamentalperiodinframes, pminperiodinframes, pmaxperiodinframes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentSharedModeEnginePeriod(this: *mut core::ffi::c_void, ppformat: *mut *mut WAVEFORMATEX, pcurrentperiodinframes: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioClient3", "GetCurrentSharedModeEnginePeriod", 19usize, |this| ((**(this as *const *const IAudioClient3_Vtbl)).GetCurrentSharedModeEnginePeriod)(this, ppformat, pcurrentperiodinframes), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeSharedAudioStream(this: *mut core::ffi::c_void, streamflags: u32, periodinframes: u32, pformat: *const WAVEFORMATEX, audiosessionguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioClient3", "InitializeSharedAudioStream", 20usize, |this| ((**(this as *const *const IAudioClient3_Vtbl)).InitializeSharedAudioStream)(this, streamflags, periodinframes, pformat, audiosessionguid), |result| Some(*result))
+        }
+        Self {
+            base__: <IAudioClient2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSharedModeEnginePeriod,
+            GetCurrentSharedModeEnginePeriod,
+            InitializeSharedAudioStream,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioClient3 as windows_core::Interface>::IID || <IAudioClient2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioClientDuckingControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDuckingOptionsForCurrentStream(this: *mut core::ffi::c_void, options: AUDIO_DUCKING_OPTIONS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioClientDuckingControl", "SetDuckingOptionsForCurrentStream", 3usize, |this| ((**(this as *const *const IAudioClientDuckingControl_Vtbl)).SetDuckingOptionsForCurrentStream)(this, options), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetDuckingOptionsForCurrentStream }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioClientDuckingControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioClock_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFrequency(this: *mut core::ffi::c_void, pu64frequency: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioClock", "GetFrequency", 3usize, |this| ((**(this as *const *const IAudioClock_Vtbl)).GetFrequency)(this, pu64frequency), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPosition(this: *mut core::ffi::c_void, pu64position: *mut u64, pu64qpcposition: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioClock", "GetPosition", 4usize, |this| ((**(this as *const *const IAudioClock_Vtbl)).GetPosition)(this, pu64position, pu64qpcposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCharacteristics(this: *mut core::ffi::c_void, pdwcharacteristics: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioClock", "GetCharacteristics", 5usize, |this| ((**(this as *const *const IAudioClock_Vtbl)).GetCharacteristics)(this, pdwcharacteristics), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFrequency, GetPosition, GetCharacteristics }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioClock as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioClock2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDevicePosition(this: *mut core::ffi::c_void, deviceposition: *mut u64, qpcposition: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioClock2", "GetDevicePosition", 3usize, |this| ((**(this as *const *const IAudioClock2_Vtbl)).GetDevicePosition)(this, deviceposition, qpcposition), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDevicePosition }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioClock2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioClockAdjustment_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSampleRate(this: *mut core::ffi::c_void, flsamplerate: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioClockAdjustment", "SetSampleRate", 3usize, |this| ((**(this as *const *const IAudioClockAdjustment_Vtbl)).SetSampleRate)(this, flsamplerate), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetSampleRate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioClockAdjustment as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioEffectsChangedNotificationClient_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnAudioEffectsChanged(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEffectsChangedNotificationClient", "OnAudioEffectsChanged", 3usize, |this| ((**(this as *const *const IAudioEffectsChangedNotificationClient_Vtbl)).OnAudioEffectsChanged)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnAudioEffectsChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioEffectsChangedNotificationClient as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioEffectsManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterAudioEffectsChangedNotificationCallback(this: *mut core::ffi::c_void, client: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEffectsManager", "RegisterAudioEffectsChangedNotificationCallback", 3usize, |this| ((**(this as *const *const IAudioEffectsManager_Vtbl)).RegisterAudioEffectsChangedNotificationCallback)(this, client), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterAudioEffectsChangedNotificationCallback(this: *mut core::ffi::c_void, client: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEffectsManager", "UnregisterAudioEffectsChangedNotificationCallback", 4usize, |this| ((**(this as *const *const IAudioEffectsManager_Vtbl)).UnregisterAudioEffectsChangedNotificationCallback)(this, client), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAudioEffects(this: *mut core::ffi::c_void, effects: *mut *mut AUDIO_EFFECT, numeffects: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEffectsManager", "GetAudioEffects", 5usize, |this| ((**(this as *const *const IAudioEffectsManager_Vtbl)).GetAudioEffects)(this, effects, numeffects), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAudioEffectState(this: *mut core::ffi::c_void, effectid: windows_core::GUID, state: AUDIO_EFFECT_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEffectsManager", "SetAudioEffectState", 6usize, |this| ((**(this as *const *const IAudioEffectsManager_Vtbl)).SetAudioEffectState)(this, effectid, state), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RegisterAudioEffectsChangedNotificationCallback,
+            UnregisterAudioEffectsChangedNotificationCallback,
+            GetAudioEffects,
+            SetAudioEffectState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioEffectsManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioFormatEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFormatEnumerator", "GetCount", 3usize, |this| ((**(this as *const *const IAudioFormatEnumerator_Vtbl)).GetCount)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFormat(this: *mut core::ffi::c_void, index: u32, format: *mut *mut WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioFormatEnumerator", "GetFormat", 4usize, |this| ((**(this as *const *const IAudioFormatEnumerator_Vtbl)).GetFormat)(this, index, format), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCount, GetFormat }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioFormatEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioInputSelector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSelection(this: *mut core::ffi::c_void, pnidselected: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioInputSelector", "GetSelection", 3usize, |this| ((**(this as *const *const IAudioInputSelector_Vtbl)).GetSelection)(this, pnidselected), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelection(this: *mut core::ffi::c_void, nidselect: u32, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioInputSelector", "SetSelection", 4usize, |this| ((**(this as *const *const IAudioInputSelector_Vtbl)).SetSelection)(this, nidselect, pguideventcontext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSelection, SetSelection }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioInputSelector as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioLoudness_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEnabled(this: *mut core::ffi::c_void, pbenabled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioLoudness", "GetEnabled", 3usize, |this| ((**(this as *const *const IAudioLoudness_Vtbl)).GetEnabled)(this, pbenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, benable: super::super::Foundation::BOOL, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioLoudness", "SetEnabled", 4usize, |this| ((**(this as *const *const IAudioLoudness_Vtbl)).SetEnabled)(this, benable, pguideventcontext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetEnabled, SetEnabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioLoudness as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioMidrange_Vtbl {
+    const PROXY: Self = { Self { base__: <IPerChannelDbLevel_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioMidrange as windows_core::Interface>::IID || <IPerChannelDbLevel_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioMute_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMute(this: *mut core::ffi::c_void, bmuted: super::super::Foundation::BOOL, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioMute", "SetMute", 3usize, |this| ((**(this as *const *const IAudioMute_Vtbl)).SetMute)(this, bmuted, pguideventcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMute(this: *mut core::ffi::c_void, pbmuted: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioMute", "GetMute", 4usize, |this| ((**(this as *const *const IAudioMute_Vtbl)).GetMute)(this, pbmuted), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetMute, GetMute }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioMute as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioOutputSelector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSelection(this: *mut core::ffi::c_void, pnidselected: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioOutputSelector", "GetSelection", 3usize, |this| ((**(this as *const *const IAudioOutputSelector_Vtbl)).GetSelection)(this, pnidselected), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelection(this: *mut core::ffi::c_void, nidselect: u32, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioOutputSelector", "SetSelection", 4usize, |this| ((**(this as *const *const IAudioOutputSelector_Vtbl)).SetSelection)(this, nidselect, pguideventcontext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSelection, SetSelection }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioOutputSelector as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioPeakMeter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetChannelCount(this: *mut core::ffi::c_void, pcchannels: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioPeakMeter", "GetChannelCount", 3usize, |this| ((**(this as *const *const IAudioPeakMeter_Vtbl)).GetChannelCount)(this, pcchannels), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLevel(this: *mut core::ffi::c_void, nchannel: u32, pflevel: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioPeakMeter", "GetLevel", 4usize, |this| ((**(this as *const *const IAudioPeakMeter_Vtbl)).GetLevel)(this, nchannel, pflevel), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetChannelCount, GetLevel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioPeakMeter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioRenderClient_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBuffer(this: *mut core::ffi::c_void, numframesrequested: u32, ppdata: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioRenderClient", "GetBuffer", 3usize, |this| ((**(this as *const *const IAudioRenderClient_Vtbl)).GetBuffer)(this, numframesrequested, ppdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseBuffer(this: *mut core::ffi::c_void, numframeswritten: u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioRenderClient", "ReleaseBuffer", 4usize, |this| ((**(this as *const *const IAudioRenderClient_Vtbl)).ReleaseBuffer)(this, numframeswritten, dwflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetBuffer, ReleaseBuffer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioRenderClient as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioSessionControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetState(this: *mut core::ffi::c_void, pretval: *mut AudioSessionState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionControl", "GetState", 3usize, |this| ((**(this as *const *const IAudioSessionControl_Vtbl)).GetState)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayName(this: *mut core::ffi::c_void, pretval: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionControl", "GetDisplayName", 4usize, |this| ((**(this as *const *const IAudioSessionControl_Vtbl)).GetDisplayName)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, value: windows_core::PCWSTR, eventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionControl", "SetDisplayName", 5usize, |this| ((**(this as *const *const IAudioSessionControl_Vtbl)).SetDisplayName)(this, value, eventcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIconPath(this: *mut core::ffi::c_void, pretval: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionControl", "GetIconPath", 6usize, |this| ((**(this as *const *const IAudioSessionControl_Vtbl)).GetIconPath)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIconPath(this: *mut core::ffi::c_void, value: windows_core::PCWSTR, eventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionControl", "SetIconPath", 7usize, |this| ((**(this as *const *const IAudioSessionControl_Vtbl)).SetIconPath)(this, value, eventcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGroupingParam(this: *mut core::ffi::c_void, pretval: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionControl", "GetGroupingParam", 8usize, |this| ((**(this as *const *const IAudioSessionControl_Vtbl)).GetGroupingParam)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGroupingParam(this: *mut core::ffi::c_void, r#override: *const windows_core::GUID, eventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionControl", "SetGroupingParam", 9usize, |this| ((**(this as *const *const IAudioSessionControl_Vtbl)).SetGroupingParam)(this, r#override, eventcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterAudioSessionNotification(this: *mut core::ffi::c_void, newnotifications: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionControl", "RegisterAudioSessionNotification", 10usize, |this| ((**(this as *const *const IAudioSessionControl_Vtbl)).RegisterAudioSessionNotification)(this, newnotifications), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterAudioSessionNotification(this: *mut core::ffi::c_void, newnotifications: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionControl", "UnregisterAudioSessionNotification", 11usize, |this| ((**(this as *const *const IAudioSessionControl_Vtbl)).UnregisterAudioSessionNotification)(this, newnotifications), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetState,
+            GetDisplayName,
+            SetDisplayName,
+            GetIconPath,
+            SetIconPath,
+            GetGroupingParam,
+            SetGroupingParam,
+            RegisterAudioSessionNotification,
+            UnregisterAudioSessionNotification,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioSessionControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioSessionControl2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSessionIdentifier(this: *mut core::ffi::c_void, pretval: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionControl2", "GetSessionIdentifier", 12usize, |this| ((**(this as *const *const IAudioSessionControl2_Vtbl)).GetSessionIdentifier)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSessionInstanceIdentifier(this: *mut core::ffi::c_void, pretval: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionControl2", "GetSessionInstanceIdentifier", 13usize, |this| ((**(this as *const *const IAudioSessionControl2_Vtbl)).GetSessionInstanceIdentifier)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProcessId(this: *mut core::ffi::c_void, pretval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionControl2", "GetProcessId", 14usize, |this| ((**(this as *const *const IAudioSessionControl2_Vtbl)).GetProcessId)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSystemSoundsSession(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionControl2", "IsSystemSoundsSession", 15usize, |this| ((**(this as *const *const IAudioSessionControl2_Vtbl)).IsSystemSoundsSession)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDuckingPreference(this: *mut core::ffi::c_void, optout: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionControl2", "SetDuckingPreference", 16usize, |this| ((**(this as *const *const IAudioSessionControl2_Vtbl)).SetDuckingPreference)(this, optout), |result| Some(*result))
+        }
+        Self {
+            base__: <IAudioSessionControl_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSessionIdentifier,
+            GetSessionInstanceIdentifier,
+            GetProcessId,
+            IsSystemSoundsSession,
+            SetDuckingPreference,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioSessionControl2 as windows_core::Interface>::IID || <IAudioSessionControl_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioSessionEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, sessioncount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionEnumerator", "GetCount", 3usize, |this| ((**(this as *const *const IAudioSessionEnumerator_Vtbl)).GetCount)(this, sessioncount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSession(this: *mut core::ffi::c_void, sessioncount: i32, session: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionEnumerator", "GetSession", 4usize, |this| ((**(this as *const *const IAudioSessionEnumerator_Vtbl)).GetSession)(this, sessioncount, session), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCount, GetSession }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioSessionEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioSessionEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnDisplayNameChanged(this: *mut core::ffi::c_void, newdisplayname: windows_core::PCWSTR, eventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionEvents", "OnDisplayNameChanged", 3usize, |this| ((**(this as *const *const IAudioSessionEvents_Vtbl)).OnDisplayNameChanged)(this, newdisplayname, eventcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnIconPathChanged(this: *mut core::ffi::c_void, newiconpath: windows_core::PCWSTR, eventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionEvents", "OnIconPathChanged", 4usize, |this| ((**(this as *const *const IAudioSessionEvents_Vtbl)).OnIconPathChanged)(this, newiconpath, eventcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnSimpleVolumeChanged(this: *mut core::ffi::c_void, newvolume: f32, newmute: super::super::Foundation::BOOL, eventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionEvents", "OnSimpleVolumeChanged", 5usize, |this| ((**(this as *const *const IAudioSessionEvents_Vtbl)).OnSimpleVolumeChanged)(this, newvolume, newmute, eventcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnChannelVolumeChanged(this: *mut core::ffi::c_void, channelcount: u32, newchannelvolumearray: *const f32, changedchannel: u32, eventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionEvents", "OnChannelVolumeChanged", 6usize, |this| ((**(this as *const *const IAudioSessionEvents_Vtbl)).OnChannelVolumeChanged)(this, channelcount, newchannelvolumearray, changedchannel, eventcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnGroupingParamChanged(this: *mut core::ffi::c_void, newgroupingparam: *const windows_core::GUID, eventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionEvents", "OnGroupingParamChanged", 7usize, |this| ((**(this as *const *const IAudioSessionEvents_Vtbl)).OnGroupingParamChanged)(this, newgroupingparam, eventcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnStateChanged(this: *mut core::ffi::c_void, newstate: AudioSessionState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionEvents", "OnStateChanged", 8usize, |this| ((**(this as *const *const IAudioSessionEvents_Vtbl)).OnStateChanged)(this, newstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnSessionDisconnected(this: *mut core::ffi::c_void, disconnectreason: AudioSessionDisconnectReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionEvents", "OnSessionDisconnected", 9usize, |this| ((**(this as *const *const IAudioSessionEvents_Vtbl)).OnSessionDisconnected)(this, disconnectreason), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnDisplayNameChanged,
+            OnIconPathChanged,
+            OnSimpleVolumeChanged,
+            OnChannelVolumeChanged,
+            OnGroupingParamChanged,
+            OnStateChanged,
+            OnSessionDisconnected,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioSessionEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioSessionManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAudioSessionControl(this: *mut core::ffi::c_void, audiosessionguid: *const windows_core::GUID, streamflags: u32, sessioncontrol: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionManager", "GetAudioSessionControl", 3usize, |this| ((**(this as *const *const IAudioSessionManager_Vtbl)).GetAudioSessionControl)(this, audiosessionguid, streamflags, sessioncontrol), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSimpleAudioVolume(this: *mut core::ffi::c_void, audiosessionguid: *const windows_core::GUID, streamflags: u32, audiovolume: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionManager", "GetSimpleAudioVolume", 4usize, |this| ((**(this as *const *const IAudioSessionManager_Vtbl)).GetSimpleAudioVolume)(this, audiosessionguid, streamflags, audiovolume), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAudioSessionControl, GetSimpleAudioVolume }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioSessionManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioSessionManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSessionEnumerator(this: *mut core::ffi::c_void, sessionenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionManager2", "GetSessionEnumerator", 5usize, |this| ((**(this as *const *const IAudioSessionManager2_Vtbl)).GetSessionEnumerator)(this, sessionenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterSessionNotification(this: *mut core::ffi::c_void, sessionnotification: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionManager2", "RegisterSessionNotification", 6usize, |this| ((**(this as *const *const IAudioSessionManager2_Vtbl)).RegisterSessionNotification)(this, sessionnotification), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterSessionNotification(this: *mut core::ffi::c_void, sessionnotification: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionManager2", "UnregisterSessionNotification", 7usize, |this| ((**(this as *const *const IAudioSessionManager2_Vtbl)).UnregisterSessionNotification)(this, sessionnotification), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterDuckNotification(this: *mut core::ffi::c_void, sessionid: windows_core::PCWSTR, ducknotification: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionManager2", "RegisterDuckNotification", 8usize, |this| ((**(this as *const *const IAudioSessionManager2_Vtbl)).RegisterDuckNotification)(this, sessionid, ducknotification), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterDuckNotification(this: *mut core::ffi::c_void, ducknotification: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionManager2", "UnregisterDuckNotification", 9usize, |this| ((**(this as *const *const IAudioSessionManager2_Vtbl)).UnregisterDuckNotification)(this, ducknotification), |result| Some(*result))
+        }
+        Self {
+            base__: <IAudioSessionManager_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSessionEnumerator,
+            RegisterSessionNotification,
+            UnregisterSessionNotification,
+            RegisterDuckNotification,
+            UnregisterDuckNotification,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioSessionManager2 as windows_core::Interface>::IID || <IAudioSessionManager_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioSessionNotification_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnSessionCreated(this: *mut core::ffi::c_void, newsession: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionNotification", "OnSessionCreated", 3usize, |this| ((**(this as *const *const IAudioSessionNotification_Vtbl)).OnSessionCreated)(this, newsession), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnSessionCreated }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioSessionNotification as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioStateMonitor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterCallback(this: *mut core::ffi::c_void, callback: PAudioStateMonitorCallback, context: *const core::ffi::c_void, registration: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioStateMonitor", "RegisterCallback", 3usize, |this| ((**(this as *const *const IAudioStateMonitor_Vtbl)).RegisterCallback)(this, callback, context, registration), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterCallback(this: *mut core::ffi::c_void, registration: i64) {
+            windows_core::imp::proxy_call(this, "IAudioStateMonitor", "UnregisterCallback", 4usize, |this| ((**(this as *const *const IAudioStateMonitor_Vtbl)).UnregisterCallback)(this, registration), |_| None)
+        }
+        unsafe extern "system" fn GetSoundLevel(this: *mut core::ffi::c_void) -> AudioStateMonitorSoundLevel {
+            windows_core::imp::proxy_call(this, "IAudioStateMonitor", "GetSoundLevel", 5usize, |this| ((**(this as *const *const IAudioStateMonitor_Vtbl)).GetSoundLevel)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterCallback, UnregisterCallback, GetSoundLevel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioStateMonitor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioStreamVolume_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetChannelCount(this: *mut core::ffi::c_void, pdwcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioStreamVolume", "GetChannelCount", 3usize, |this| ((**(this as *const *const IAudioStreamVolume_Vtbl)).GetChannelCount)(this, pdwcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetChannelVolume(this: *mut core::ffi::c_void, dwindex: u32, flevel: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioStreamVolume", "SetChannelVolume", 4usize, |this| ((**(this as *const *const IAudioStreamVolume_Vtbl)).SetChannelVolume)(this, dwindex, flevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChannelVolume(this: *mut core::ffi::c_void, dwindex: u32, pflevel: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioStreamVolume", "GetChannelVolume", 5usize, |this| ((**(this as *const *const IAudioStreamVolume_Vtbl)).GetChannelVolume)(this, dwindex, pflevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllVolumes(this: *mut core::ffi::c_void, dwcount: u32, pfvolumes: *const f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioStreamVolume", "SetAllVolumes", 6usize, |this| ((**(this as *const *const IAudioStreamVolume_Vtbl)).SetAllVolumes)(this, dwcount, pfvolumes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllVolumes(this: *mut core::ffi::c_void, dwcount: u32, pfvolumes: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioStreamVolume", "GetAllVolumes", 7usize, |this| ((**(this as *const *const IAudioStreamVolume_Vtbl)).GetAllVolumes)(this, dwcount, pfvolumes), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetChannelCount,
+            SetChannelVolume,
+            GetChannelVolume,
+            SetAllVolumes,
+            GetAllVolumes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioStreamVolume as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IAudioSystemEffectsPropertyChangeNotificationClient_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn OnPropertyChanged(this: *mut core::ffi::c_void, r#type: AUDIO_SYSTEMEFFECTS_PROPERTYSTORE_TYPE, key: super::super::UI::Shell::PropertiesSystem::PROPERTYKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSystemEffectsPropertyChangeNotificationClient", "OnPropertyChanged", 3usize, |this| ((**(this as *const *const IAudioSystemEffectsPropertyChangeNotificationClient_Vtbl)).OnPropertyChanged)(this, r#type, key), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            OnPropertyChanged,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            OnPropertyChanged: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioSystemEffectsPropertyChangeNotificationClient as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IAudioSystemEffectsPropertyStore_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn OpenDefaultPropertyStore(this: *mut core::ffi::c_void, stgmaccess: u32, propstore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSystemEffectsPropertyStore", "OpenDefaultPropertyStore", 3usize, |this| ((**(this as *const *const IAudioSystemEffectsPropertyStore_Vtbl)).OpenDefaultPropertyStore)(this, stgmaccess, propstore), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn OpenUserPropertyStore(this: *mut core::ffi::c_void, stgmaccess: u32, propstore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSystemEffectsPropertyStore", "OpenUserPropertyStore", 4usize, |this| ((**(this as *const *const IAudioSystemEffectsPropertyStore_Vtbl)).OpenUserPropertyStore)(this, stgmaccess, propstore), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn OpenVolatilePropertyStore(this: *mut core::ffi::c_void, stgmaccess: u32, propstore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSystemEffectsPropertyStore", "OpenVolatilePropertyStore", 5usize, |this| ((**(this as *const *const IAudioSystemEffectsPropertyStore_Vtbl)).OpenVolatilePropertyStore)(this, stgmaccess, propstore), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetUserPropertyStore(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSystemEffectsPropertyStore", "ResetUserPropertyStore", 6usize, |this| ((**(this as *const *const IAudioSystemEffectsPropertyStore_Vtbl)).ResetUserPropertyStore)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetVolatilePropertyStore(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSystemEffectsPropertyStore", "ResetVolatilePropertyStore", 7usize, |this| ((**(this as *const *const IAudioSystemEffectsPropertyStore_Vtbl)).ResetVolatilePropertyStore)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterPropertyChangeNotification(this: *mut core::ffi::c_void, callback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSystemEffectsPropertyStore", "RegisterPropertyChangeNotification", 8usize, |this| ((**(this as *const *const IAudioSystemEffectsPropertyStore_Vtbl)).RegisterPropertyChangeNotification)(this, callback), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterPropertyChangeNotification(this: *mut core::ffi::c_void, callback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSystemEffectsPropertyStore", "UnregisterPropertyChangeNotification", 9usize, |this| ((**(this as *const *const IAudioSystemEffectsPropertyStore_Vtbl)).UnregisterPropertyChangeNotification)(this, callback), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            OpenDefaultPropertyStore,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            OpenDefaultPropertyStore: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            OpenUserPropertyStore,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            OpenUserPropertyStore: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            OpenVolatilePropertyStore,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            OpenVolatilePropertyStore: 0,
+            ResetUserPropertyStore,
+            ResetVolatilePropertyStore,
+            RegisterPropertyChangeNotification,
+            UnregisterPropertyChangeNotification,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioSystemEffectsPropertyStore as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioTreble_Vtbl {
+    const PROXY: Self = { Self { base__: <IPerChannelDbLevel_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioTreble as windows_core::Interface>::IID || <IPerChannelDbLevel_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioViewManagerService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAudioStreamWindow(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioViewManagerService", "SetAudioStreamWindow", 3usize, |this| ((**(this as *const *const IAudioViewManagerService_Vtbl)).SetAudioStreamWindow)(this, hwnd), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAudioStreamWindow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioViewManagerService as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioVolumeDuckNotification_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnVolumeDuckNotification(this: *mut core::ffi::c_void, sessionid: windows_core::PCWSTR, countcommunicationsessions: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioVolumeDuckNotification", "OnVolumeDuckNotification", 3usize, |this| ((**(this as *const *const IAudioVolumeDuckNotification_Vtbl)).OnVolumeDuckNotification)(this, sessionid, countcommunicationsessions), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnVolumeUnduckNotification(this: *mut core::ffi::c_void, sessionid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioVolumeDuckNotification", "OnVolumeUnduckNotification", 4usize, |this| ((**(this as *const *const IAudioVolumeDuckNotification_Vtbl)).OnVolumeUnduckNotification)(this, sessionid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnVolumeDuckNotification, OnVolumeUnduckNotification }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioVolumeDuckNotification as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioVolumeLevel_Vtbl {
+    const PROXY: Self = { Self { base__: <IPerChannelDbLevel_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioVolumeLevel as windows_core::Interface>::IID || <IPerChannelDbLevel_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChannelAudioVolume_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetChannelCount(this: *mut core::ffi::c_void, pdwcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChannelAudioVolume", "GetChannelCount", 3usize, |this| ((**(this as *const *const IChannelAudioVolume_Vtbl)).GetChannelCount)(this, pdwcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetChannelVolume(this: *mut core::ffi::c_void, dwindex: u32, flevel: f32, eventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChannelAudioVolume", "SetChannelVolume", 4usize, |this| ((**(this as *const *const IChannelAudioVolume_Vtbl)).SetChannelVolume)(this, dwindex, flevel, eventcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChannelVolume(this: *mut core::ffi::c_void, dwindex: u32, pflevel: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChannelAudioVolume", "GetChannelVolume", 5usize, |this| ((**(this as *const *const IChannelAudioVolume_Vtbl)).GetChannelVolume)(this, dwindex, pflevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllVolumes(this: *mut core::ffi::c_void, dwcount: u32, pfvolumes: *const f32, eventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChannelAudioVolume", "SetAllVolumes", 6usize, |this| ((**(this as *const *const IChannelAudioVolume_Vtbl)).SetAllVolumes)(this, dwcount, pfvolumes, eventcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllVolumes(this: *mut core::ffi::c_void, dwcount: u32, pfvolumes: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChannelAudioVolume", "GetAllVolumes", 7usize, |this| ((**(this as *const *const IChannelAudioVolume_Vtbl)).GetAllVolumes)(this, dwcount, pfvolumes), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetChannelCount,
+            SetChannelVolume,
+            GetChannelVolume,
+            SetAllVolumes,
+            GetAllVolumes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChannelAudioVolume as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConnector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, ptype: *mut ConnectorType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnector", "GetType", 3usize, |this| ((**(this as *const *const IConnector_Vtbl)).GetType)(this, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataFlow(this: *mut core::ffi::c_void, pflow: *mut DataFlow) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnector", "GetDataFlow", 4usize, |this| ((**(this as *const *const IConnector_Vtbl)).GetDataFlow)(this, pflow), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectTo(this: *mut core::ffi::c_void, pconnectto: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnector", "ConnectTo", 5usize, |this| ((**(this as *const *const IConnector_Vtbl)).ConnectTo)(this, pconnectto), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnector", "Disconnect", 6usize, |this| ((**(this as *const *const IConnector_Vtbl)).Disconnect)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsConnected(this: *mut core::ffi::c_void, pbconnected: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnector", "IsConnected", 7usize, |this| ((**(this as *const *const IConnector_Vtbl)).IsConnected)(this, pbconnected), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConnectedTo(this: *mut core::ffi::c_void, ppconto: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnector", "GetConnectedTo", 8usize, |this| ((**(this as *const *const IConnector_Vtbl)).GetConnectedTo)(this, ppconto), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConnectorIdConnectedTo(this: *mut core::ffi::c_void, ppwstrconnectorid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnector", "GetConnectorIdConnectedTo", 9usize, |this| ((**(this as *const *const IConnector_Vtbl)).GetConnectorIdConnectedTo)(this, ppwstrconnectorid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceIdConnectedTo(this: *mut core::ffi::c_void, ppwstrdeviceid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnector", "GetDeviceIdConnectedTo", 10usize, |this| ((**(this as *const *const IConnector_Vtbl)).GetDeviceIdConnectedTo)(this, ppwstrdeviceid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetType,
+            GetDataFlow,
+            ConnectTo,
+            Disconnect,
+            IsConnected,
+            GetConnectedTo,
+            GetConnectorIdConnectedTo,
+            GetDeviceIdConnectedTo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConnector as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IControlChangeNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnNotify(this: *mut core::ffi::c_void, dwsenderprocessid: u32, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IControlChangeNotify", "OnNotify", 3usize, |this| ((**(this as *const *const IControlChangeNotify_Vtbl)).OnNotify)(this, dwsenderprocessid, pguideventcontext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnNotify }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IControlChangeNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IControlInterface_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, ppwstrname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IControlInterface", "GetName", 3usize, |this| ((**(this as *const *const IControlInterface_Vtbl)).GetName)(this, ppwstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIID(this: *mut core::ffi::c_void, piid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IControlInterface", "GetIID", 4usize, |this| ((**(this as *const *const IControlInterface_Vtbl)).GetIID)(this, piid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetName, GetIID }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IControlInterface as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceSpecificProperty_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, pvtype: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceSpecificProperty", "GetType", 3usize, |this| ((**(this as *const *const IDeviceSpecificProperty_Vtbl)).GetType)(this, pvtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, pvvalue: *mut core::ffi::c_void, pcbvalue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceSpecificProperty", "GetValue", 4usize, |this| ((**(this as *const *const IDeviceSpecificProperty_Vtbl)).GetValue)(this, pvvalue, pcbvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, pvvalue: *const core::ffi::c_void, cbvalue: u32, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceSpecificProperty", "SetValue", 5usize, |this| ((**(this as *const *const IDeviceSpecificProperty_Vtbl)).SetValue)(this, pvvalue, cbvalue, pguideventcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn Get4BRange(this: *mut core::ffi::c_void, plmin: *mut i32, plmax: *mut i32, plstepping: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceSpecificProperty", "Get4BRange", 6usize, |this| ((**(this as *const *const IDeviceSpecificProperty_Vtbl)).Get4BRange)(this, plmin, plmax, plstepping), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetType, GetValue, SetValue, Get4BRange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceSpecificProperty as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeviceTopology_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetConnectorCount(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceTopology", "GetConnectorCount", 3usize, |this| ((**(this as *const *const IDeviceTopology_Vtbl)).GetConnectorCount)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConnector(this: *mut core::ffi::c_void, nindex: u32, ppconnector: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceTopology", "GetConnector", 4usize, |this| ((**(this as *const *const IDeviceTopology_Vtbl)).GetConnector)(this, nindex, ppconnector), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubunitCount(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceTopology", "GetSubunitCount", 5usize, |this| ((**(this as *const *const IDeviceTopology_Vtbl)).GetSubunitCount)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubunit(this: *mut core::ffi::c_void, nindex: u32, ppsubunit: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceTopology", "GetSubunit", 6usize, |this| ((**(this as *const *const IDeviceTopology_Vtbl)).GetSubunit)(this, nindex, ppsubunit), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPartById(this: *mut core::ffi::c_void, nid: u32, pppart: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceTopology", "GetPartById", 7usize, |this| ((**(this as *const *const IDeviceTopology_Vtbl)).GetPartById)(this, nid, pppart), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceId(this: *mut core::ffi::c_void, ppwstrdeviceid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceTopology", "GetDeviceId", 8usize, |this| ((**(this as *const *const IDeviceTopology_Vtbl)).GetDeviceId)(this, ppwstrdeviceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignalPath(this: *mut core::ffi::c_void, pipartfrom: *mut core::ffi::c_void, pipartto: *mut core::ffi::c_void, brejectmixedpaths: super::super::Foundation::BOOL, ppparts: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceTopology", "GetSignalPath", 9usize, |this| ((**(this as *const *const IDeviceTopology_Vtbl)).GetSignalPath)(this, pipartfrom, pipartto, brejectmixedpaths, ppparts), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetConnectorCount,
+            GetConnector,
+            GetSubunitCount,
+            GetSubunit,
+            GetPartById,
+            GetDeviceId,
+            GetSignalPath,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceTopology as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+impl windows_core::imp::ProxyVtable for IMMDevice_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Activate(this: *mut core::ffi::c_void, iid: *const windows_core::GUID, dwclsctx: super::super::System::Com::CLSCTX, pactivationparams: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, ppinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMMDevice", "Activate", 3usize, |this| ((**(this as *const *const IMMDevice_Vtbl)).Activate)(this, iid, dwclsctx, pactivationparams, ppinterface), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+        unsafe extern "system" fn OpenPropertyStore(this: *mut core::ffi::c_void, stgmaccess: super::super::System::Com::STGM, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMMDevice", "OpenPropertyStore", 4usize, |this| ((**(this as *const *const IMMDevice_Vtbl)).OpenPropertyStore)(this, stgmaccess, ppproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetId(this: *mut core::ffi::c_void, ppstrid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMMDevice", "GetId", 5usize, |this| ((**(this as *const *const IMMDevice_Vtbl)).GetId)(this, ppstrid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetState(this: *mut core::ffi::c_void, pdwstate: *mut DEVICE_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMMDevice", "GetState", 6usize, |this| ((**(this as *const *const IMMDevice_Vtbl)).GetState)(this, pdwstate), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Activate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Activate: 0,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+            OpenPropertyStore,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem")))]
+            OpenPropertyStore: 0,
+            GetId,
+            GetState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMMDevice as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMMDeviceActivator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Activate(this: *mut core::ffi::c_void, iid: *const windows_core::GUID, pdevice: *mut core::ffi::c_void, pactivationparams: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, ppinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMMDeviceActivator", "Activate", 3usize, |this| ((**(this as *const *const IMMDeviceActivator_Vtbl)).Activate)(this, iid, pdevice, pactivationparams, ppinterface), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Activate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMMDeviceActivator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMMDeviceCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcdevices: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMMDeviceCollection", "GetCount", 3usize, |this| ((**(this as *const *const IMMDeviceCollection_Vtbl)).GetCount)(this, pcdevices), |result| Some(*result))
+        }
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, ndevice: u32, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMMDeviceCollection", "Item", 4usize, |this| ((**(this as *const *const IMMDeviceCollection_Vtbl)).Item)(this, ndevice, ppdevice), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCount, Item }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMMDeviceCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMMDeviceEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumAudioEndpoints(this: *mut core::ffi::c_void, dataflow: EDataFlow, dwstatemask: DEVICE_STATE, ppdevices: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMMDeviceEnumerator", "EnumAudioEndpoints", 3usize, |this| ((**(this as *const *const IMMDeviceEnumerator_Vtbl)).EnumAudioEndpoints)(this, dataflow, dwstatemask, ppdevices), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultAudioEndpoint(this: *mut core::ffi::c_void, dataflow: EDataFlow, role: ERole, ppendpoint: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMMDeviceEnumerator", "GetDefaultAudioEndpoint", 4usize, |this| ((**(this as *const *const IMMDeviceEnumerator_Vtbl)).GetDefaultAudioEndpoint)(this, dataflow, role, ppendpoint), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDevice(this: *mut core::ffi::c_void, pwstrid: windows_core::PCWSTR, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMMDeviceEnumerator", "GetDevice", 5usize, |this| ((**(this as *const *const IMMDeviceEnumerator_Vtbl)).GetDevice)(this, pwstrid, ppdevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterEndpointNotificationCallback(this: *mut core::ffi::c_void, pclient: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMMDeviceEnumerator", "RegisterEndpointNotificationCallback", 6usize, |this| ((**(this as *const *const IMMDeviceEnumerator_Vtbl)).RegisterEndpointNotificationCallback)(this, pclient), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterEndpointNotificationCallback(this: *mut core::ffi::c_void, pclient: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMMDeviceEnumerator", "UnregisterEndpointNotificationCallback", 7usize, |this| ((**(this as *const *const IMMDeviceEnumerator_Vtbl)).UnregisterEndpointNotificationCallback)(this, pclient), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EnumAudioEndpoints,
+            GetDefaultAudioEndpoint,
+            GetDevice,
+            RegisterEndpointNotificationCallback,
+            UnregisterEndpointNotificationCallback,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMMDeviceEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMMEndpoint_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDataFlow(this: *mut core::ffi::c_void, pdataflow: *mut EDataFlow) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMMEndpoint", "GetDataFlow", 3usize, |this| ((**(this as *const *const IMMEndpoint_Vtbl)).GetDataFlow)(this, pdataflow), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDataFlow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMMEndpoint as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IMMNotificationClient_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnDeviceStateChanged(this: *mut core::ffi::c_void, pwstrdeviceid: windows_core::PCWSTR, dwnewstate: DEVICE_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMMNotificationClient", "OnDeviceStateChanged", 3usize, |this| ((**(this as *const *const IMMNotificationClient_Vtbl)).OnDeviceStateChanged)(this, pwstrdeviceid, dwnewstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDeviceAdded(this: *mut core::ffi::c_void, pwstrdeviceid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMMNotificationClient", "OnDeviceAdded", 4usize, |this| ((**(this as *const *const IMMNotificationClient_Vtbl)).OnDeviceAdded)(this, pwstrdeviceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDeviceRemoved(this: *mut core::ffi::c_void, pwstrdeviceid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMMNotificationClient", "OnDeviceRemoved", 5usize, |this| ((**(this as *const *const IMMNotificationClient_Vtbl)).OnDeviceRemoved)(this, pwstrdeviceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDefaultDeviceChanged(this: *mut core::ffi::c_void, flow: EDataFlow, role: ERole, pwstrdefaultdeviceid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMMNotificationClient", "OnDefaultDeviceChanged", 6usize, |this| ((**(this as *const *const IMMNotificationClient_Vtbl)).OnDefaultDeviceChanged)(this, flow, role, pwstrdefaultdeviceid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn OnPropertyValueChanged(this: *mut core::ffi::c_void, pwstrdeviceid: windows_core::PCWSTR, key: super::super::UI::Shell::PropertiesSystem::PROPERTYKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMMNotificationClient", "OnPropertyValueChanged", 7usize, |this| ((**(this as *const *const IMMNotificationClient_Vtbl)).OnPropertyValueChanged)(this, pwstrdeviceid, key), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnDeviceStateChanged,
+            OnDeviceAdded,
+            OnDeviceRemoved,
+            OnDefaultDeviceChanged,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            OnPropertyValueChanged,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            OnPropertyValueChanged: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMMNotificationClient as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMessageFilter_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn HandleInComingCall(this: *mut core::ffi::c_void, dwcalltype: u32, htaskcaller: super::HTASK, dwtickcount: u32, lpinterfaceinfo: *const super::super::System::Com::INTERFACEINFO) -> u32 {
+            windows_core::imp::proxy_call(this, "IMessageFilter", "HandleInComingCall", 3usize, |this| ((**(this as *const *const IMessageFilter_Vtbl)).HandleInComingCall)(this, dwcalltype, htaskcaller, dwtickcount, lpinterfaceinfo), |_| None)
+        }
+        unsafe extern "system" fn RetryRejectedCall(this: *mut core::ffi::c_void, htaskcallee: super::HTASK, dwtickcount: u32, dwrejecttype: u32) -> u32 {
+            windows_core::imp::proxy_call(this, "IMessageFilter", "RetryRejectedCall", 4usize, |this| ((**(this as *const *const IMessageFilter_Vtbl)).RetryRejectedCall)(this, htaskcallee, dwtickcount, dwrejecttype), |_| None)
+        }
+        unsafe extern "system" fn MessagePending(this: *mut core::ffi::c_void, htaskcallee: super::HTASK, dwtickcount: u32, dwpendingtype: u32) -> u32 {
+            windows_core::imp::proxy_call(this, "IMessageFilter", "MessagePending", 5usize, |this| ((**(this as *const *const IMessageFilter_Vtbl)).MessagePending)(this, htaskcallee, dwtickcount, dwpendingtype), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            HandleInComingCall,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            HandleInComingCall: 0,
+            RetryRejectedCall,
+            MessagePending,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMessageFilter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPart_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, ppwstrname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPart", "GetName", 3usize, |this| ((**(this as *const *const IPart_Vtbl)).GetName)(this, ppwstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocalId(this: *mut core::ffi::c_void, pnid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPart", "GetLocalId", 4usize, |this| ((**(this as *const *const IPart_Vtbl)).GetLocalId)(this, pnid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGlobalId(this: *mut core::ffi::c_void, ppwstrglobalid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPart", "GetGlobalId", 5usize, |this| ((**(this as *const *const IPart_Vtbl)).GetGlobalId)(this, ppwstrglobalid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPartType(this: *mut core::ffi::c_void, pparttype: *mut PartType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPart", "GetPartType", 6usize, |this| ((**(this as *const *const IPart_Vtbl)).GetPartType)(this, pparttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubType(this: *mut core::ffi::c_void, psubtype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPart", "GetSubType", 7usize, |this| ((**(this as *const *const IPart_Vtbl)).GetSubType)(this, psubtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetControlInterfaceCount(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPart", "GetControlInterfaceCount", 8usize, |this| ((**(this as *const *const IPart_Vtbl)).GetControlInterfaceCount)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetControlInterface(this: *mut core::ffi::c_void, nindex: u32, ppinterfacedesc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPart", "GetControlInterface", 9usize, |this| ((**(this as *const *const IPart_Vtbl)).GetControlInterface)(this, nindex, ppinterfacedesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumPartsIncoming(this: *mut core::ffi::c_void, ppparts: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPart", "EnumPartsIncoming", 10usize, |this| ((**(this as *const *const IPart_Vtbl)).EnumPartsIncoming)(this, ppparts), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumPartsOutgoing(this: *mut core::ffi::c_void, ppparts: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPart", "EnumPartsOutgoing", 11usize, |this| ((**(this as *const *const IPart_Vtbl)).EnumPartsOutgoing)(this, ppparts), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTopologyObject(this: *mut core::ffi::c_void, pptopology: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPart", "GetTopologyObject", 12usize, |this| ((**(this as *const *const IPart_Vtbl)).GetTopologyObject)(this, pptopology), |result| Some(*result))
+        }
+        unsafe extern "system" fn Activate(this: *mut core::ffi::c_void, dwclscontext: u32, refiid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPart", "Activate", 13usize, |this| ((**(this as *const *const IPart_Vtbl)).Activate)(this, dwclscontext, refiid, ppvobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterControlChangeCallback(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, pnotify: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPart", "RegisterControlChangeCallback", 14usize, |this| ((**(this as *const *const IPart_Vtbl)).RegisterControlChangeCallback)(this, riid, pnotify), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterControlChangeCallback(this: *mut core::ffi::c_void, pnotify: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPart", "UnregisterControlChangeCallback", 15usize, |this| ((**(this as *const *const IPart_Vtbl)).UnregisterControlChangeCallback)(this, pnotify), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetName,
+            GetLocalId,
+            GetGlobalId,
+            GetPartType,
+            GetSubType,
+            GetControlInterfaceCount,
+            GetControlInterface,
+            EnumPartsIncoming,
+            EnumPartsOutgoing,
+            GetTopologyObject,
+            Activate,
+            RegisterControlChangeCallback,
+            UnregisterControlChangeCallback,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPart as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPartsList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPartsList", "GetCount", 3usize, |this| ((**(this as *const *const IPartsList_Vtbl)).GetCount)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPart(this: *mut core::ffi::c_void, nindex: u32, pppart: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPartsList", "GetPart", 4usize, |this| ((**(this as *const *const IPartsList_Vtbl)).GetPart)(this, nindex, pppart), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCount, GetPart }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPartsList as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPerChannelDbLevel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetChannelCount(this: *mut core::ffi::c_void, pcchannels: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerChannelDbLevel", "GetChannelCount", 3usize, |this| ((**(this as *const *const IPerChannelDbLevel_Vtbl)).GetChannelCount)(this, pcchannels), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLevelRange(this: *mut core::ffi::c_void, nchannel: u32, pfminleveldb: *mut f32, pfmaxleveldb: *mut f32, pfstepping: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerChannelDbLevel", "GetLevelRange", 4usize, |this| ((**(this as *const *const IPerChannelDbLevel_Vtbl)).GetLevelRange)(this, nchannel, pfminleveldb, pfmaxleveldb, pfstepping), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLevel(this: *mut core::ffi::c_void, nchannel: u32, pfleveldb: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerChannelDbLevel", "GetLevel", 5usize, |this| ((**(this as *const *const IPerChannelDbLevel_Vtbl)).GetLevel)(this, nchannel, pfleveldb), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLevel(this: *mut core::ffi::c_void, nchannel: u32, fleveldb: f32, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerChannelDbLevel", "SetLevel", 6usize, |this| ((**(this as *const *const IPerChannelDbLevel_Vtbl)).SetLevel)(this, nchannel, fleveldb, pguideventcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLevelUniform(this: *mut core::ffi::c_void, fleveldb: f32, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerChannelDbLevel", "SetLevelUniform", 7usize, |this| ((**(this as *const *const IPerChannelDbLevel_Vtbl)).SetLevelUniform)(this, fleveldb, pguideventcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLevelAllChannels(this: *mut core::ffi::c_void, alevelsdb: *const f32, cchannels: u32, pguideventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerChannelDbLevel", "SetLevelAllChannels", 8usize, |this| ((**(this as *const *const IPerChannelDbLevel_Vtbl)).SetLevelAllChannels)(this, alevelsdb, cchannels, pguideventcontext), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetChannelCount,
+            GetLevelRange,
+            GetLevel,
+            SetLevel,
+            SetLevelUniform,
+            SetLevelAllChannels,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPerChannelDbLevel as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISimpleAudioVolume_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMasterVolume(this: *mut core::ffi::c_void, flevel: f32, eventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleAudioVolume", "SetMasterVolume", 3usize, |this| ((**(this as *const *const ISimpleAudioVolume_Vtbl)).SetMasterVolume)(this, flevel, eventcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMasterVolume(this: *mut core::ffi::c_void, pflevel: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleAudioVolume", "GetMasterVolume", 4usize, |this| ((**(this as *const *const ISimpleAudioVolume_Vtbl)).GetMasterVolume)(this, pflevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMute(this: *mut core::ffi::c_void, bmute: super::super::Foundation::BOOL, eventcontext: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleAudioVolume", "SetMute", 5usize, |this| ((**(this as *const *const ISimpleAudioVolume_Vtbl)).SetMute)(this, bmute, eventcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMute(this: *mut core::ffi::c_void, pbmute: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleAudioVolume", "GetMute", 6usize, |this| ((**(this as *const *const ISimpleAudioVolume_Vtbl)).GetMute)(this, pbmute), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetMasterVolume, GetMasterVolume, SetMute, GetMute }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISimpleAudioVolume as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialAudioClient_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStaticObjectPosition(this: *mut core::ffi::c_void, r#type: AudioObjectType, x: *mut f32, y: *mut f32, z: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioClient", "GetStaticObjectPosition", 3usize, |this| ((**(this as *const *const ISpatialAudioClient_Vtbl)).GetStaticObjectPosition)(this, r#type, x, y, z), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNativeStaticObjectTypeMask(this: *mut core::ffi::c_void, mask: *mut AudioObjectType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioClient", "GetNativeStaticObjectTypeMask", 4usize, |this| ((**(this as *const *const ISpatialAudioClient_Vtbl)).GetNativeStaticObjectTypeMask)(this, mask), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxDynamicObjectCount(this: *mut core::ffi::c_void, value: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioClient", "GetMaxDynamicObjectCount", 5usize, |this| ((**(this as *const *const ISpatialAudioClient_Vtbl)).GetMaxDynamicObjectCount)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSupportedAudioObjectFormatEnumerator(this: *mut core::ffi::c_void, enumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioClient", "GetSupportedAudioObjectFormatEnumerator", 6usize, |this| ((**(this as *const *const ISpatialAudioClient_Vtbl)).GetSupportedAudioObjectFormatEnumerator)(this, enumerator), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxFrameCount(this: *mut core::ffi::c_void, objectformat: *const WAVEFORMATEX, framecountperbuffer: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioClient", "GetMaxFrameCount", 7usize, |this| ((**(this as *const *const ISpatialAudioClient_Vtbl)).GetMaxFrameCount)(this, objectformat, framecountperbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAudioObjectFormatSupported(this: *mut core::ffi::c_void, objectformat: *const WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioClient", "IsAudioObjectFormatSupported", 8usize, |this| ((**(this as *const *const ISpatialAudioClient_Vtbl)).IsAudioObjectFormatSupported)(this, objectformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSpatialAudioStreamAvailable(this: *mut core::ffi::c_void, streamuuid: *const windows_core::GUID, auxiliaryinfo: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioClient", "IsSpatialAudioStreamAvailable", 9usize, |this| ((**(this as *const *const ISpatialAudioClient_Vtbl)).IsSpatialAudioStreamAvailable)(this, streamuuid, auxiliaryinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivateSpatialAudioStream(this: *mut core::ffi::c_void, activationparams: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, riid: *const windows_core::GUID, stream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioClient", "ActivateSpatialAudioStream", 10usize, |this| ((**(this as *const *const ISpatialAudioClient_Vtbl)).ActivateSpatialAudioStream)(this, activationparams, riid, stream), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetStaticObjectPosition,
+            GetNativeStaticObjectTypeMask,
+            GetMaxDynamicObjectCount,
+            GetSupportedAudioObjectFormatEnumerator,
+            GetMaxFrameCount,
+            IsAudioObjectFormatSupported,
+            IsSpatialAudioStreamAvailable,
+            ActivateSpatialAudioStream,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAudioClient as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialAudioClient2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsOffloadCapable(this: *mut core::ffi::c_void, category: AUDIO_STREAM_CATEGORY, isoffloadcapable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioClient2", "IsOffloadCapable", 11usize, |this| ((**(this as *const *const ISpatialAudioClient2_Vtbl)).IsOffloadCapable)(this, category, isoffloadcapable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxFrameCountForCategory(this: *mut core::ffi::c_void, category: AUDIO_STREAM_CATEGORY, offloadenabled: super::super::Foundation::BOOL, objectformat: *const WAVEFORMATEX, framecountperbuffer: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioClient2", "GetMaxFrameCountForCategory", 12usize, |this| ((**(this as *const *const ISpatialAudioClient2_Vtbl)).GetMaxFrameCountForCategory)(this, category, offloadenabled, objectformat, framecountperbuffer), |result| Some(*result))
+        }
+        Self { base__: <ISpatialAudioClient_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsOffloadCapable, GetMaxFrameCountForCategory }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAudioClient2 as windows_core::Interface>::IID || <ISpatialAudioClient_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialAudioMetadataClient_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ActivateSpatialAudioMetadataItems(this: *mut core::ffi::c_void, maxitemcount: u16, framecount: u16, metadataitemsbuffer: *mut *mut core::ffi::c_void, metadataitems: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioMetadataClient", "ActivateSpatialAudioMetadataItems", 3usize, |this| ((**(this as *const *const ISpatialAudioMetadataClient_Vtbl)).ActivateSpatialAudioMetadataItems)(this, maxitemcount, framecount, metadataitemsbuffer, metadataitems), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSpatialAudioMetadataItemsBufferLength(this: *mut core::ffi::c_void, maxitemcount: u16, bufferlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioMetadataClient", "GetSpatialAudioMetadataItemsBufferLength", 4usize, |this| ((**(this as *const *const ISpatialAudioMetadataClient_Vtbl)).GetSpatialAudioMetadataItemsBufferLength)(this, maxitemcount, bufferlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivateSpatialAudioMetadataWriter(this: *mut core::ffi::c_void, overflowmode: SpatialAudioMetadataWriterOverflowMode, metadatawriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioMetadataClient", "ActivateSpatialAudioMetadataWriter", 5usize, |this| ((**(this as *const *const ISpatialAudioMetadataClient_Vtbl)).ActivateSpatialAudioMetadataWriter)(this, overflowmode, metadatawriter), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivateSpatialAudioMetadataCopier(this: *mut core::ffi::c_void, metadatacopier: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioMetadataClient", "ActivateSpatialAudioMetadataCopier", 6usize, |this| ((**(this as *const *const ISpatialAudioMetadataClient_Vtbl)).ActivateSpatialAudioMetadataCopier)(this, metadatacopier), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivateSpatialAudioMetadataReader(this: *mut core::ffi::c_void, metadatareader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioMetadataClient", "ActivateSpatialAudioMetadataReader", 7usize, |this| ((**(this as *const *const ISpatialAudioMetadataClient_Vtbl)).ActivateSpatialAudioMetadataReader)(this, metadatareader), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ActivateSpatialAudioMetadataItems,
+            GetSpatialAudioMetadataItemsBufferLength,
+            ActivateSpatialAudioMetadataWriter,
+            ActivateSpatialAudioMetadataCopier,
+            ActivateSpatialAudioMetadataReader,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAudioMetadataClient as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialAudioMetadataCopier_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, metadataitems: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioMetadataCopier", "Open", 3usize, |this| ((**(this as *const *const ISpatialAudioMetadataCopier_Vtbl)).Open)(this, metadataitems), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyMetadataForFrames(this: *mut core::ffi::c_void, copyframecount: u16, copymode: SpatialAudioMetadataCopyMode, dstmetadataitems: *mut core::ffi::c_void, itemscopied: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioMetadataCopier", "CopyMetadataForFrames", 4usize, |this| ((**(this as *const *const ISpatialAudioMetadataCopier_Vtbl)).CopyMetadataForFrames)(this, copyframecount, copymode, dstmetadataitems, itemscopied), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioMetadataCopier", "Close", 5usize, |this| ((**(this as *const *const ISpatialAudioMetadataCopier_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Open, CopyMetadataForFrames, Close }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAudioMetadataCopier as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialAudioMetadataItems_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFrameCount(this: *mut core::ffi::c_void, framecount: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioMetadataItems", "GetFrameCount", 3usize, |this| ((**(this as *const *const ISpatialAudioMetadataItems_Vtbl)).GetFrameCount)(this, framecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItemCount(this: *mut core::ffi::c_void, itemcount: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioMetadataItems", "GetItemCount", 4usize, |this| ((**(this as *const *const ISpatialAudioMetadataItems_Vtbl)).GetItemCount)(this, itemcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxItemCount(this: *mut core::ffi::c_void, maxitemcount: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioMetadataItems", "GetMaxItemCount", 5usize, |this| ((**(this as *const *const ISpatialAudioMetadataItems_Vtbl)).GetMaxItemCount)(this, maxitemcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxValueBufferLength(this: *mut core::ffi::c_void, maxvaluebufferlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioMetadataItems", "GetMaxValueBufferLength", 6usize, |this| ((**(this as *const *const ISpatialAudioMetadataItems_Vtbl)).GetMaxValueBufferLength)(this, maxvaluebufferlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInfo(this: *mut core::ffi::c_void, info: *mut SpatialAudioMetadataItemsInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioMetadataItems", "GetInfo", 7usize, |this| ((**(this as *const *const ISpatialAudioMetadataItems_Vtbl)).GetInfo)(this, info), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetFrameCount,
+            GetItemCount,
+            GetMaxItemCount,
+            GetMaxValueBufferLength,
+            GetInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAudioMetadataItems as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialAudioMetadataItemsBuffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AttachToBuffer(this: *mut core::ffi::c_void, buffer: *mut u8, bufferlength: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioMetadataItemsBuffer", "AttachToBuffer", 3usize, |this| ((**(this as *const *const ISpatialAudioMetadataItemsBuffer_Vtbl)).AttachToBuffer)(this, buffer, bufferlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttachToPopulatedBuffer(this: *mut core::ffi::c_void, buffer: *mut u8, bufferlength: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioMetadataItemsBuffer", "AttachToPopulatedBuffer", 4usize, |this| ((**(this as *const *const ISpatialAudioMetadataItemsBuffer_Vtbl)).AttachToPopulatedBuffer)(this, buffer, bufferlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn DetachBuffer(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioMetadataItemsBuffer", "DetachBuffer", 5usize, |this| ((**(this as *const *const ISpatialAudioMetadataItemsBuffer_Vtbl)).DetachBuffer)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AttachToBuffer, AttachToPopulatedBuffer, DetachBuffer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAudioMetadataItemsBuffer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialAudioMetadataReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, metadataitems: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioMetadataReader", "Open", 3usize, |this| ((**(this as *const *const ISpatialAudioMetadataReader_Vtbl)).Open)(this, metadataitems), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadNextItem(this: *mut core::ffi::c_void, commandcount: *mut u8, frameoffset: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioMetadataReader", "ReadNextItem", 4usize, |this| ((**(this as *const *const ISpatialAudioMetadataReader_Vtbl)).ReadNextItem)(this, commandcount, frameoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadNextItemCommand(this: *mut core::ffi::c_void, commandid: *mut u8, valuebuffer: *mut core::ffi::c_void, maxvaluebufferlength: u32, valuebufferlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioMetadataReader", "ReadNextItemCommand", 5usize, |this| ((**(this as *const *const ISpatialAudioMetadataReader_Vtbl)).ReadNextItemCommand)(this, commandid, valuebuffer, maxvaluebufferlength, valuebufferlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioMetadataReader", "Close", 6usize, |this| ((**(this as *const *const ISpatialAudioMetadataReader_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Open, ReadNextItem, ReadNextItemCommand, Close }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAudioMetadataReader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialAudioMetadataWriter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, metadataitems: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioMetadataWriter", "Open", 3usize, |this| ((**(this as *const *const ISpatialAudioMetadataWriter_Vtbl)).Open)(this, metadataitems), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteNextItem(this: *mut core::ffi::c_void, frameoffset: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioMetadataWriter", "WriteNextItem", 4usize, |this| ((**(this as *const *const ISpatialAudioMetadataWriter_Vtbl)).WriteNextItem)(this, frameoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteNextItemCommand(this: *mut core::ffi::c_void, commandid: u8, valuebuffer: *const core::ffi::c_void, valuebufferlength: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioMetadataWriter", "WriteNextItemCommand", 5usize, |this| ((**(this as *const *const ISpatialAudioMetadataWriter_Vtbl)).WriteNextItemCommand)(this, commandid, valuebuffer, valuebufferlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioMetadataWriter", "Close", 6usize, |this| ((**(this as *const *const ISpatialAudioMetadataWriter_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Open, WriteNextItem, WriteNextItemCommand, Close }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAudioMetadataWriter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialAudioObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPosition(this: *mut core::ffi::c_void, x: f32, y: f32, z: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioObject", "SetPosition", 7usize, |this| ((**(this as *const *const ISpatialAudioObject_Vtbl)).SetPosition)(this, x, y, z), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVolume(this: *mut core::ffi::c_void, volume: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioObject", "SetVolume", 8usize, |this| ((**(this as *const *const ISpatialAudioObject_Vtbl)).SetVolume)(this, volume), |result| Some(*result))
+        }
+        Self { base__: <ISpatialAudioObjectBase_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetPosition, SetVolume }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAudioObject as windows_core::Interface>::IID || <ISpatialAudioObjectBase_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialAudioObjectBase_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBuffer(this: *mut core::ffi::c_void, buffer: *mut *mut u8, bufferlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioObjectBase", "GetBuffer", 3usize, |this| ((**(this as *const *const ISpatialAudioObjectBase_Vtbl)).GetBuffer)(this, buffer, bufferlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEndOfStream(this: *mut core::ffi::c_void, framecount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioObjectBase", "SetEndOfStream", 4usize, |this| ((**(this as *const *const ISpatialAudioObjectBase_Vtbl)).SetEndOfStream)(this, framecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsActive(this: *mut core::ffi::c_void, isactive: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioObjectBase", "IsActive", 5usize, |this| ((**(this as *const *const ISpatialAudioObjectBase_Vtbl)).IsActive)(this, isactive), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAudioObjectType(this: *mut core::ffi::c_void, audioobjecttype: *mut AudioObjectType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioObjectBase", "GetAudioObjectType", 6usize, |this| ((**(this as *const *const ISpatialAudioObjectBase_Vtbl)).GetAudioObjectType)(this, audioobjecttype), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetBuffer, SetEndOfStream, IsActive, GetAudioObjectType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAudioObjectBase as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialAudioObjectForHrtf_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPosition(this: *mut core::ffi::c_void, x: f32, y: f32, z: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioObjectForHrtf", "SetPosition", 7usize, |this| ((**(this as *const *const ISpatialAudioObjectForHrtf_Vtbl)).SetPosition)(this, x, y, z), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGain(this: *mut core::ffi::c_void, gain: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioObjectForHrtf", "SetGain", 8usize, |this| ((**(this as *const *const ISpatialAudioObjectForHrtf_Vtbl)).SetGain)(this, gain), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOrientation(this: *mut core::ffi::c_void, orientation: *const *const f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioObjectForHrtf", "SetOrientation", 9usize, |this| ((**(this as *const *const ISpatialAudioObjectForHrtf_Vtbl)).SetOrientation)(this, orientation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnvironment(this: *mut core::ffi::c_void, environment: SpatialAudioHrtfEnvironmentType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioObjectForHrtf", "SetEnvironment", 10usize, |this| ((**(this as *const *const ISpatialAudioObjectForHrtf_Vtbl)).SetEnvironment)(this, environment), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDistanceDecay(this: *mut core::ffi::c_void, distancedecay: *const SpatialAudioHrtfDistanceDecay) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioObjectForHrtf", "SetDistanceDecay", 11usize, |this| ((**(this as *const *const ISpatialAudioObjectForHrtf_Vtbl)).SetDistanceDecay)(this, distancedecay), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDirectivity(this: *mut core::ffi::c_void, directivity: *const SpatialAudioHrtfDirectivityUnion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioObjectForHrtf", "SetDirectivity", 12usize, |this| ((**(this as *const *const ISpatialAudioObjectForHrtf_Vtbl)).SetDirectivity)(this, directivity), |result| Some(*result))
+        }
+        Self {
+            base__: <ISpatialAudioObjectBase_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetPosition,
+            SetGain,
+            SetOrientation,
+            SetEnvironment,
+            SetDistanceDecay,
+            SetDirectivity,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAudioObjectForHrtf as windows_core::Interface>::IID || <ISpatialAudioObjectBase_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialAudioObjectForMetadataCommands_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WriteNextMetadataCommand(this: *mut core::ffi::c_void, commandid: u8, valuebuffer: *const core::ffi::c_void, valuebufferlength: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioObjectForMetadataCommands", "WriteNextMetadataCommand", 7usize, |this| ((**(this as *const *const ISpatialAudioObjectForMetadataCommands_Vtbl)).WriteNextMetadataCommand)(this, commandid, valuebuffer, valuebufferlength), |result| Some(*result))
+        }
+        Self { base__: <ISpatialAudioObjectBase_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WriteNextMetadataCommand }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAudioObjectForMetadataCommands as windows_core::Interface>::IID || <ISpatialAudioObjectBase_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialAudioObjectForMetadataItems_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSpatialAudioMetadataItems(this: *mut core::ffi::c_void, metadataitems: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioObjectForMetadataItems", "GetSpatialAudioMetadataItems", 7usize, |this| ((**(this as *const *const ISpatialAudioObjectForMetadataItems_Vtbl)).GetSpatialAudioMetadataItems)(this, metadataitems), |result| Some(*result))
+        }
+        Self { base__: <ISpatialAudioObjectBase_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSpatialAudioMetadataItems }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAudioObjectForMetadataItems as windows_core::Interface>::IID || <ISpatialAudioObjectBase_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialAudioObjectRenderStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ActivateSpatialAudioObject(this: *mut core::ffi::c_void, r#type: AudioObjectType, audioobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioObjectRenderStream", "ActivateSpatialAudioObject", 10usize, |this| ((**(this as *const *const ISpatialAudioObjectRenderStream_Vtbl)).ActivateSpatialAudioObject)(this, r#type, audioobject), |result| Some(*result))
+        }
+        Self { base__: <ISpatialAudioObjectRenderStreamBase_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ActivateSpatialAudioObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAudioObjectRenderStream as windows_core::Interface>::IID || <ISpatialAudioObjectRenderStreamBase_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialAudioObjectRenderStreamBase_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAvailableDynamicObjectCount(this: *mut core::ffi::c_void, value: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioObjectRenderStreamBase", "GetAvailableDynamicObjectCount", 3usize, |this| ((**(this as *const *const ISpatialAudioObjectRenderStreamBase_Vtbl)).GetAvailableDynamicObjectCount)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetService(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, service: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioObjectRenderStreamBase", "GetService", 4usize, |this| ((**(this as *const *const ISpatialAudioObjectRenderStreamBase_Vtbl)).GetService)(this, riid, service), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioObjectRenderStreamBase", "Start", 5usize, |this| ((**(this as *const *const ISpatialAudioObjectRenderStreamBase_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioObjectRenderStreamBase", "Stop", 6usize, |this| ((**(this as *const *const ISpatialAudioObjectRenderStreamBase_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioObjectRenderStreamBase", "Reset", 7usize, |this| ((**(this as *const *const ISpatialAudioObjectRenderStreamBase_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginUpdatingAudioObjects(this: *mut core::ffi::c_void, availabledynamicobjectcount: *mut u32, framecountperbuffer: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioObjectRenderStreamBase", "BeginUpdatingAudioObjects", 8usize, |this| ((**(this as *const *const ISpatialAudioObjectRenderStreamBase_Vtbl)).BeginUpdatingAudioObjects)(this, availabledynamicobjectcount, framecountperbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndUpdatingAudioObjects(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioObjectRenderStreamBase", "EndUpdatingAudioObjects", 9usize, |this| ((**(this as *const *const ISpatialAudioObjectRenderStreamBase_Vtbl)).EndUpdatingAudioObjects)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetAvailableDynamicObjectCount,
+            GetService,
+            Start,
+            Stop,
+            Reset,
+            BeginUpdatingAudioObjects,
+            EndUpdatingAudioObjects,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAudioObjectRenderStreamBase as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialAudioObjectRenderStreamForHrtf_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ActivateSpatialAudioObjectForHrtf(this: *mut core::ffi::c_void, r#type: AudioObjectType, audioobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioObjectRenderStreamForHrtf", "ActivateSpatialAudioObjectForHrtf", 10usize, |this| ((**(this as *const *const ISpatialAudioObjectRenderStreamForHrtf_Vtbl)).ActivateSpatialAudioObjectForHrtf)(this, r#type, audioobject), |result| Some(*result))
+        }
+        Self { base__: <ISpatialAudioObjectRenderStreamBase_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ActivateSpatialAudioObjectForHrtf }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAudioObjectRenderStreamForHrtf as windows_core::Interface>::IID || <ISpatialAudioObjectRenderStreamBase_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialAudioObjectRenderStreamForMetadata_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ActivateSpatialAudioObjectForMetadataCommands(this: *mut core::ffi::c_void, r#type: AudioObjectType, audioobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioObjectRenderStreamForMetadata", "ActivateSpatialAudioObjectForMetadataCommands", 10usize, |this| ((**(this as *const *const ISpatialAudioObjectRenderStreamForMetadata_Vtbl)).ActivateSpatialAudioObjectForMetadataCommands)(this, r#type, audioobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivateSpatialAudioObjectForMetadataItems(this: *mut core::ffi::c_void, r#type: AudioObjectType, audioobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioObjectRenderStreamForMetadata", "ActivateSpatialAudioObjectForMetadataItems", 11usize, |this| ((**(this as *const *const ISpatialAudioObjectRenderStreamForMetadata_Vtbl)).ActivateSpatialAudioObjectForMetadataItems)(this, r#type, audioobject), |result| Some(*result))
+        }
+        Self {
+            base__: <ISpatialAudioObjectRenderStreamBase_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ActivateSpatialAudioObjectForMetadataCommands,
+            ActivateSpatialAudioObjectForMetadataItems,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAudioObjectRenderStreamForMetadata as windows_core::Interface>::IID || <ISpatialAudioObjectRenderStreamBase_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialAudioObjectRenderStreamNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnAvailableDynamicObjectCountChange(this: *mut core::ffi::c_void, sender: *mut core::ffi::c_void, hnscompliancedeadlinetime: i64, availabledynamicobjectcountchange: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialAudioObjectRenderStreamNotify", "OnAvailableDynamicObjectCountChange", 3usize, |this| ((**(this as *const *const ISpatialAudioObjectRenderStreamNotify_Vtbl)).OnAvailableDynamicObjectCountChange)(this, sender, hnscompliancedeadlinetime, availabledynamicobjectcountchange), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnAvailableDynamicObjectCountChange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialAudioObjectRenderStreamNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISubunit_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISubunit as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
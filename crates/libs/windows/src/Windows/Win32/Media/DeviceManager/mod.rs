@@ -2549,6 +2549,7 @@ pub struct WMDMMessage(pub i32);
 impl windows_core::TypeKind for WMDMMessage {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMDMMessage {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMDMMessage").field(&self.0).finish()
@@ -2560,6 +2561,7 @@ pub struct WMDM_ENUM_PROP_VALID_VALUES_FORM(pub i32);
 impl windows_core::TypeKind for WMDM_ENUM_PROP_VALID_VALUES_FORM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMDM_ENUM_PROP_VALID_VALUES_FORM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMDM_ENUM_PROP_VALID_VALUES_FORM").field(&self.0).finish()
@@ -2571,6 +2573,7 @@ pub struct WMDM_FIND_SCOPE(pub i32);
 impl windows_core::TypeKind for WMDM_FIND_SCOPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMDM_FIND_SCOPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMDM_FIND_SCOPE").field(&self.0).finish()
@@ -2582,6 +2585,7 @@ pub struct WMDM_FORMATCODE(pub i32);
 impl windows_core::TypeKind for WMDM_FORMATCODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMDM_FORMATCODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMDM_FORMATCODE").field(&self.0).finish()
@@ -2593,6 +2597,7 @@ pub struct WMDM_SESSION_TYPE(pub i32);
 impl windows_core::TypeKind for WMDM_SESSION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMDM_SESSION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMDM_SESSION_TYPE").field(&self.0).finish()
@@ -2604,6 +2609,7 @@ pub struct WMDM_STORAGE_ENUM_MODE(pub i32);
 impl windows_core::TypeKind for WMDM_STORAGE_ENUM_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMDM_STORAGE_ENUM_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMDM_STORAGE_ENUM_MODE").field(&self.0).finish()
@@ -2615,13 +2621,15 @@ pub struct WMDM_TAG_DATATYPE(pub i32);
 impl windows_core::TypeKind for WMDM_TAG_DATATYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMDM_TAG_DATATYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMDM_TAG_DATATYPE").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MACINFO {
     pub fUsed: super::super::Foundation::BOOL,
     pub abMacState: [u8; 36],
@@ -2672,7 +2680,8 @@ impl Default for MTP_COMMAND_DATA_OUT {
 pub const MediaDevMgr: windows_core::GUID = windows_core::GUID::from_u128(0x25baad81_3560_11d3_8471_00c04f79dbc0);
 pub const MediaDevMgrClassFactory: windows_core::GUID = windows_core::GUID::from_u128(0x50040c1d_bdbf_4924_b873_f14d6c5bfd66);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OPAQUECOMMAND {
     pub guidCommand: windows_core::GUID,
     pub dwDataLen: u32,
@@ -2688,7 +2697,8 @@ impl Default for OPAQUECOMMAND {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WMDMDATETIME {
     pub wYear: u16,
     pub wMonth: u16,
@@ -2802,7 +2812,8 @@ impl Default for WMDMDetermineMaxPropStringLen {
 pub const WMDMDevice: windows_core::GUID = windows_core::GUID::from_u128(0x807b3cdf_357a_11d3_8471_00c04f79dbc0);
 pub const WMDMDeviceEnum: windows_core::GUID = windows_core::GUID::from_u128(0x430e35af_3971_11d3_8474_00c04f79dbc0);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WMDMID {
     pub cbSize: u32,
     pub dwVendorID: u32,
@@ -2819,7 +2830,8 @@ impl Default for WMDMID {
 }
 pub const WMDMLogger: windows_core::GUID = windows_core::GUID::from_u128(0x110a3202_5a79_11d3_8d78_444553540000);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WMDMMetadataView {
     pub pwszViewName: windows_core::PWSTR,
     pub nDepth: u32,
@@ -2834,7 +2846,8 @@ impl Default for WMDMMetadataView {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WMDMRIGHTS {
     pub cbSize: u32,
     pub dwContentType: u32,
@@ -2856,7 +2869,8 @@ pub const WMDMStorage: windows_core::GUID = windows_core::GUID::from_u128(0x807b
 pub const WMDMStorageEnum: windows_core::GUID = windows_core::GUID::from_u128(0xeb401a3b_3af7_11d3_8474_00c04f79dbc0);
 pub const WMDMStorageGlobal: windows_core::GUID = windows_core::GUID::from_u128(0x807b3ce1_357a_11d3_8471_00c04f79dbc0);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WMDM_FORMAT_CAPABILITY {
     pub nPropConfig: u32,
     pub pConfigs: *mut WMDM_PROP_CONFIG,
@@ -2870,7 +2884,8 @@ impl Default for WMDM_FORMAT_CAPABILITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WMDM_PROP_CONFIG {
     pub nPreference: u32,
     pub nPropDesc: u32,
@@ -2922,7 +2937,8 @@ impl Default for WMDM_PROP_DESC_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WMDM_PROP_VALUES_ENUM {
     pub cEnumValues: u32,
     pub pValues: *mut windows_core::PROPVARIANT,
@@ -2936,7 +2952,8 @@ impl Default for WMDM_PROP_VALUES_ENUM {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WMDM_PROP_VALUES_RANGE {
     pub rangeMin: core::mem::ManuallyDrop<windows_core::PROPVARIANT>,
     pub rangeMax: core::mem::ManuallyDrop<windows_core::PROPVARIANT>,
@@ -2956,7 +2973,8 @@ impl Default for WMDM_PROP_VALUES_RANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WMFILECAPABILITIES {
     pub pwszMimeType: windows_core::PWSTR,
     pub dwReserved: u32,
@@ -2971,3 +2989,5 @@ impl Default for WMFILECAPABILITIES {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
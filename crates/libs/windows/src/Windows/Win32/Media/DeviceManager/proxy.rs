@@ -0,0 +1,1523 @@
+impl windows_core::imp::ProxyVtable for IComponentAuthenticate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SACAuth(this: *mut core::ffi::c_void, dwprotocolid: u32, dwpass: u32, pbdatain: *const u8, dwdatainlen: u32, ppbdataout: *mut *mut u8, pdwdataoutlen: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentAuthenticate", "SACAuth", 3usize, |this| ((**(this as *const *const IComponentAuthenticate_Vtbl)).SACAuth)(this, dwprotocolid, dwpass, pbdatain, dwdatainlen, ppbdataout, pdwdataoutlen), |result| Some(*result))
+        }
+        unsafe extern "system" fn SACGetProtocols(this: *mut core::ffi::c_void, ppdwprotocols: *mut *mut u32, pdwprotocolcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentAuthenticate", "SACGetProtocols", 4usize, |this| ((**(this as *const *const IComponentAuthenticate_Vtbl)).SACGetProtocols)(this, ppdwprotocols, pdwprotocolcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SACAuth, SACGetProtocols }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComponentAuthenticate as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_Audio")]
+impl windows_core::imp::ProxyVtable for IMDSPDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, pwszname: windows_core::PWSTR, nmaxchars: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDevice", "GetName", 3usize, |this| ((**(this as *const *const IMDSPDevice_Vtbl)).GetName)(this, pwszname, nmaxchars), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetManufacturer(this: *mut core::ffi::c_void, pwszname: windows_core::PWSTR, nmaxchars: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDevice", "GetManufacturer", 4usize, |this| ((**(this as *const *const IMDSPDevice_Vtbl)).GetManufacturer)(this, pwszname, nmaxchars), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersion(this: *mut core::ffi::c_void, pdwversion: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDevice", "GetVersion", 5usize, |this| ((**(this as *const *const IMDSPDevice_Vtbl)).GetVersion)(this, pdwversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, pdwtype: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDevice", "GetType", 6usize, |this| ((**(this as *const *const IMDSPDevice_Vtbl)).GetType)(this, pdwtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSerialNumber(this: *mut core::ffi::c_void, pserialnumber: *mut WMDMID, abmac: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDevice", "GetSerialNumber", 7usize, |this| ((**(this as *const *const IMDSPDevice_Vtbl)).GetSerialNumber)(this, pserialnumber, abmac), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPowerSource(this: *mut core::ffi::c_void, pdwpowersource: *mut u32, pdwpercentremaining: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDevice", "GetPowerSource", 8usize, |this| ((**(this as *const *const IMDSPDevice_Vtbl)).GetPowerSource)(this, pdwpowersource, pdwpercentremaining), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDevice", "GetStatus", 9usize, |this| ((**(this as *const *const IMDSPDevice_Vtbl)).GetStatus)(this, pdwstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceIcon(this: *mut core::ffi::c_void, hicon: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDevice", "GetDeviceIcon", 10usize, |this| ((**(this as *const *const IMDSPDevice_Vtbl)).GetDeviceIcon)(this, hicon), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumStorage(this: *mut core::ffi::c_void, ppenumstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDevice", "EnumStorage", 11usize, |this| ((**(this as *const *const IMDSPDevice_Vtbl)).EnumStorage)(this, ppenumstorage), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn GetFormatSupport(this: *mut core::ffi::c_void, pformatex: *mut *mut super::Audio::WAVEFORMATEX, pnformatcount: *mut u32, pppwszmimetype: *mut *mut windows_core::PWSTR, pnmimetypecount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDevice", "GetFormatSupport", 12usize, |this| ((**(this as *const *const IMDSPDevice_Vtbl)).GetFormatSupport)(this, pformatex, pnformatcount, pppwszmimetype, pnmimetypecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendOpaqueCommand(this: *mut core::ffi::c_void, pcommand: *mut OPAQUECOMMAND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDevice", "SendOpaqueCommand", 13usize, |this| ((**(this as *const *const IMDSPDevice_Vtbl)).SendOpaqueCommand)(this, pcommand), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetName,
+            GetManufacturer,
+            GetVersion,
+            GetType,
+            GetSerialNumber,
+            GetPowerSource,
+            GetStatus,
+            GetDeviceIcon,
+            EnumStorage,
+            #[cfg(feature = "Win32_Media_Audio")]
+            GetFormatSupport,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            GetFormatSupport: 0,
+            SendOpaqueCommand,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMDSPDevice as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IMDSPDevice2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStorage(this: *mut core::ffi::c_void, pszstoragename: windows_core::PCWSTR, ppstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDevice2", "GetStorage", 14usize, |this| ((**(this as *const *const IMDSPDevice2_Vtbl)).GetStorage)(this, pszstoragename, ppstorage), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+        unsafe extern "system" fn GetFormatSupport2(this: *mut core::ffi::c_void, dwflags: u32, ppaudioformatex: *mut *mut super::Audio::WAVEFORMATEX, pnaudioformatcount: *mut u32, ppvideoformatex: *mut *mut super::MediaFoundation::VIDEOINFOHEADER, pnvideoformatcount: *mut u32, ppfiletype: *mut *mut WMFILECAPABILITIES, pnfiletypecount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDevice2", "GetFormatSupport2", 15usize, |this| ((**(this as *const *const IMDSPDevice2_Vtbl)).GetFormatSupport2)(this, dwflags, ppaudioformatex, pnaudioformatcount, ppvideoformatex, pnvideoformatcount, ppfiletype, pnfiletypecount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn GetSpecifyPropertyPages(this: *mut core::ffi::c_void, ppspecifyproppages: *mut *mut core::ffi::c_void, pppunknowns: *mut *mut Option<windows_core::IUnknown>, pcunks: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDevice2", "GetSpecifyPropertyPages", 16usize, |this| ((**(this as *const *const IMDSPDevice2_Vtbl)).GetSpecifyPropertyPages)(this, ppspecifyproppages, pppunknowns, pcunks), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCanonicalName(this: *mut core::ffi::c_void, pwszpnpname: windows_core::PWSTR, nmaxchars: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDevice2", "GetCanonicalName", 17usize, |this| ((**(this as *const *const IMDSPDevice2_Vtbl)).GetCanonicalName)(this, pwszpnpname, nmaxchars), |result| Some(*result))
+        }
+        Self {
+            base__: <IMDSPDevice_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetStorage,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+            GetFormatSupport2,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation")))]
+            GetFormatSupport2: 0,
+            #[cfg(feature = "Win32_System_Ole")]
+            GetSpecifyPropertyPages,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            GetSpecifyPropertyPages: 0,
+            GetCanonicalName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMDSPDevice2 as windows_core::Interface>::IID || <IMDSPDevice_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IMDSPDevice3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, pwszpropname: windows_core::PCWSTR, pvalue: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDevice3", "GetProperty", 18usize, |this| ((**(this as *const *const IMDSPDevice3_Vtbl)).GetProperty)(this, pwszpropname, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, pwszpropname: windows_core::PCWSTR, pvalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDevice3", "SetProperty", 19usize, |this| ((**(this as *const *const IMDSPDevice3_Vtbl)).SetProperty)(this, pwszpropname, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFormatCapability(this: *mut core::ffi::c_void, format: WMDM_FORMATCODE, pformatsupport: *mut WMDM_FORMAT_CAPABILITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDevice3", "GetFormatCapability", 20usize, |this| ((**(this as *const *const IMDSPDevice3_Vtbl)).GetFormatCapability)(this, format, pformatsupport), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceIoControl(this: *mut core::ffi::c_void, dwiocontrolcode: u32, lpinbuffer: *const u8, ninbuffersize: u32, lpoutbuffer: *mut u8, pnoutbuffersize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDevice3", "DeviceIoControl", 21usize, |this| ((**(this as *const *const IMDSPDevice3_Vtbl)).DeviceIoControl)(this, dwiocontrolcode, lpinbuffer, ninbuffersize, lpoutbuffer, pnoutbuffersize), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindStorage(this: *mut core::ffi::c_void, findscope: WMDM_FIND_SCOPE, pwszuniqueid: windows_core::PCWSTR, ppstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDevice3", "FindStorage", 22usize, |this| ((**(this as *const *const IMDSPDevice3_Vtbl)).FindStorage)(this, findscope, pwszuniqueid, ppstorage), |result| Some(*result))
+        }
+        Self {
+            base__: <IMDSPDevice2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetProperty,
+            SetProperty,
+            GetFormatCapability,
+            DeviceIoControl,
+            FindStorage,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMDSPDevice3 as windows_core::Interface>::IID || <IMDSPDevice2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_Audio")]
+impl windows_core::imp::ProxyVtable for IMDSPDeviceControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDCStatus(this: *mut core::ffi::c_void, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDeviceControl", "GetDCStatus", 3usize, |this| ((**(this as *const *const IMDSPDeviceControl_Vtbl)).GetDCStatus)(this, pdwstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCapabilities(this: *mut core::ffi::c_void, pdwcapabilitiesmask: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDeviceControl", "GetCapabilities", 4usize, |this| ((**(this as *const *const IMDSPDeviceControl_Vtbl)).GetCapabilities)(this, pdwcapabilitiesmask), |result| Some(*result))
+        }
+        unsafe extern "system" fn Play(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDeviceControl", "Play", 5usize, |this| ((**(this as *const *const IMDSPDeviceControl_Vtbl)).Play)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn Record(this: *mut core::ffi::c_void, pformat: *const super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDeviceControl", "Record", 6usize, |this| ((**(this as *const *const IMDSPDeviceControl_Vtbl)).Record)(this, pformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDeviceControl", "Pause", 7usize, |this| ((**(this as *const *const IMDSPDeviceControl_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDeviceControl", "Resume", 8usize, |this| ((**(this as *const *const IMDSPDeviceControl_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDeviceControl", "Stop", 9usize, |this| ((**(this as *const *const IMDSPDeviceControl_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Seek(this: *mut core::ffi::c_void, fumode: u32, noffset: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDeviceControl", "Seek", 10usize, |this| ((**(this as *const *const IMDSPDeviceControl_Vtbl)).Seek)(this, fumode, noffset), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDCStatus,
+            GetCapabilities,
+            Play,
+            #[cfg(feature = "Win32_Media_Audio")]
+            Record,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            Record: 0,
+            Pause,
+            Resume,
+            Stop,
+            Seek,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMDSPDeviceControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMDSPDirectTransfer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TransferToDevice(this: *mut core::ffi::c_void, pwszsourcefilepath: windows_core::PCWSTR, psourceoperation: *mut core::ffi::c_void, fuflags: u32, pwszdestinationname: windows_core::PCWSTR, psourcemetadata: *mut core::ffi::c_void, ptransferprogress: *mut core::ffi::c_void, ppnewobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPDirectTransfer", "TransferToDevice", 3usize, |this| ((**(this as *const *const IMDSPDirectTransfer_Vtbl)).TransferToDevice)(this, pwszsourcefilepath, psourceoperation, fuflags, pwszdestinationname, psourcemetadata, ptransferprogress, ppnewobject), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TransferToDevice }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMDSPDirectTransfer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMDSPEnumDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppdevice: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPEnumDevice", "Next", 3usize, |this| ((**(this as *const *const IMDSPEnumDevice_Vtbl)).Next)(this, celt, ppdevice, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPEnumDevice", "Skip", 4usize, |this| ((**(this as *const *const IMDSPEnumDevice_Vtbl)).Skip)(this, celt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPEnumDevice", "Reset", 5usize, |this| ((**(this as *const *const IMDSPEnumDevice_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenumdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPEnumDevice", "Clone", 6usize, |this| ((**(this as *const *const IMDSPEnumDevice_Vtbl)).Clone)(this, ppenumdevice), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMDSPEnumDevice as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMDSPEnumStorage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppstorage: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPEnumStorage", "Next", 3usize, |this| ((**(this as *const *const IMDSPEnumStorage_Vtbl)).Next)(this, celt, ppstorage, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPEnumStorage", "Skip", 4usize, |this| ((**(this as *const *const IMDSPEnumStorage_Vtbl)).Skip)(this, celt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPEnumStorage", "Reset", 5usize, |this| ((**(this as *const *const IMDSPEnumStorage_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenumstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPEnumStorage", "Clone", 6usize, |this| ((**(this as *const *const IMDSPEnumStorage_Vtbl)).Clone)(this, ppenumstorage), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMDSPEnumStorage as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMDSPObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, fumode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPObject", "Open", 3usize, |this| ((**(this as *const *const IMDSPObject_Vtbl)).Open)(this, fumode), |result| Some(*result))
+        }
+        unsafe extern "system" fn Read(this: *mut core::ffi::c_void, pdata: *mut u8, pdwsize: *mut u32, abmac: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPObject", "Read", 4usize, |this| ((**(this as *const *const IMDSPObject_Vtbl)).Read)(this, pdata, pdwsize, abmac), |result| Some(*result))
+        }
+        unsafe extern "system" fn Write(this: *mut core::ffi::c_void, pdata: *const u8, pdwsize: *mut u32, abmac: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPObject", "Write", 5usize, |this| ((**(this as *const *const IMDSPObject_Vtbl)).Write)(this, pdata, pdwsize, abmac), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, fumode: u32, pprogress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPObject", "Delete", 6usize, |this| ((**(this as *const *const IMDSPObject_Vtbl)).Delete)(this, fumode, pprogress), |result| Some(*result))
+        }
+        unsafe extern "system" fn Seek(this: *mut core::ffi::c_void, fuflags: u32, dwoffset: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPObject", "Seek", 7usize, |this| ((**(this as *const *const IMDSPObject_Vtbl)).Seek)(this, fuflags, dwoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rename(this: *mut core::ffi::c_void, pwsznewname: windows_core::PCWSTR, pprogress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPObject", "Rename", 8usize, |this| ((**(this as *const *const IMDSPObject_Vtbl)).Rename)(this, pwsznewname, pprogress), |result| Some(*result))
+        }
+        unsafe extern "system" fn Move(this: *mut core::ffi::c_void, fumode: u32, pprogress: *mut core::ffi::c_void, ptarget: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPObject", "Move", 9usize, |this| ((**(this as *const *const IMDSPObject_Vtbl)).Move)(this, fumode, pprogress, ptarget), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPObject", "Close", 10usize, |this| ((**(this as *const *const IMDSPObject_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Open, Read, Write, Delete, Seek, Rename, Move, Close }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMDSPObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMDSPObject2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReadOnClearChannel(this: *mut core::ffi::c_void, pdata: *mut u8, pdwsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPObject2", "ReadOnClearChannel", 11usize, |this| ((**(this as *const *const IMDSPObject2_Vtbl)).ReadOnClearChannel)(this, pdata, pdwsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteOnClearChannel(this: *mut core::ffi::c_void, pdata: *const u8, pdwsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPObject2", "WriteOnClearChannel", 12usize, |this| ((**(this as *const *const IMDSPObject2_Vtbl)).WriteOnClearChannel)(this, pdata, pdwsize), |result| Some(*result))
+        }
+        Self { base__: <IMDSPObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReadOnClearChannel, WriteOnClearChannel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMDSPObject2 as windows_core::Interface>::IID || <IMDSPObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMDSPObjectInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPlayLength(this: *mut core::ffi::c_void, pdwlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPObjectInfo", "GetPlayLength", 3usize, |this| ((**(this as *const *const IMDSPObjectInfo_Vtbl)).GetPlayLength)(this, pdwlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPlayLength(this: *mut core::ffi::c_void, dwlength: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPObjectInfo", "SetPlayLength", 4usize, |this| ((**(this as *const *const IMDSPObjectInfo_Vtbl)).SetPlayLength)(this, dwlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPlayOffset(this: *mut core::ffi::c_void, pdwoffset: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPObjectInfo", "GetPlayOffset", 5usize, |this| ((**(this as *const *const IMDSPObjectInfo_Vtbl)).GetPlayOffset)(this, pdwoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPlayOffset(this: *mut core::ffi::c_void, dwoffset: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPObjectInfo", "SetPlayOffset", 6usize, |this| ((**(this as *const *const IMDSPObjectInfo_Vtbl)).SetPlayOffset)(this, dwoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTotalLength(this: *mut core::ffi::c_void, pdwlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPObjectInfo", "GetTotalLength", 7usize, |this| ((**(this as *const *const IMDSPObjectInfo_Vtbl)).GetTotalLength)(this, pdwlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastPlayPosition(this: *mut core::ffi::c_void, pdwlastpos: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPObjectInfo", "GetLastPlayPosition", 8usize, |this| ((**(this as *const *const IMDSPObjectInfo_Vtbl)).GetLastPlayPosition)(this, pdwlastpos), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLongestPlayPosition(this: *mut core::ffi::c_void, pdwlongestpos: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPObjectInfo", "GetLongestPlayPosition", 9usize, |this| ((**(this as *const *const IMDSPObjectInfo_Vtbl)).GetLongestPlayPosition)(this, pdwlongestpos), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPlayLength,
+            SetPlayLength,
+            GetPlayOffset,
+            SetPlayOffset,
+            GetTotalLength,
+            GetLastPlayPosition,
+            GetLongestPlayPosition,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMDSPObjectInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMDSPRevoked_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRevocationURL(this: *mut core::ffi::c_void, ppwszrevocationurl: *mut windows_core::PWSTR, pdwbufferlen: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPRevoked", "GetRevocationURL", 3usize, |this| ((**(this as *const *const IMDSPRevoked_Vtbl)).GetRevocationURL)(this, ppwszrevocationurl, pdwbufferlen), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRevocationURL }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMDSPRevoked as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_Audio")]
+impl windows_core::imp::ProxyVtable for IMDSPStorage_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn SetAttributes(this: *mut core::ffi::c_void, dwattributes: u32, pformat: *const super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorage", "SetAttributes", 3usize, |this| ((**(this as *const *const IMDSPStorage_Vtbl)).SetAttributes)(this, dwattributes, pformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStorageGlobals(this: *mut core::ffi::c_void, ppstorageglobals: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorage", "GetStorageGlobals", 4usize, |this| ((**(this as *const *const IMDSPStorage_Vtbl)).GetStorageGlobals)(this, ppstorageglobals), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn GetAttributes(this: *mut core::ffi::c_void, pdwattributes: *mut u32, pformat: *mut super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorage", "GetAttributes", 5usize, |this| ((**(this as *const *const IMDSPStorage_Vtbl)).GetAttributes)(this, pdwattributes, pformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, pwszname: windows_core::PWSTR, nmaxchars: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorage", "GetName", 6usize, |this| ((**(this as *const *const IMDSPStorage_Vtbl)).GetName)(this, pwszname, nmaxchars), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDate(this: *mut core::ffi::c_void, pdatetimeutc: *mut WMDMDATETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorage", "GetDate", 7usize, |this| ((**(this as *const *const IMDSPStorage_Vtbl)).GetDate)(this, pdatetimeutc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSize(this: *mut core::ffi::c_void, pdwsizelow: *mut u32, pdwsizehigh: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorage", "GetSize", 8usize, |this| ((**(this as *const *const IMDSPStorage_Vtbl)).GetSize)(this, pdwsizelow, pdwsizehigh), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRights(this: *mut core::ffi::c_void, pprights: *mut *mut WMDMRIGHTS, pnrightscount: *mut u32, abmac: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorage", "GetRights", 9usize, |this| ((**(this as *const *const IMDSPStorage_Vtbl)).GetRights)(this, pprights, pnrightscount, abmac), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn CreateStorage(this: *mut core::ffi::c_void, dwattributes: u32, pformat: *const super::Audio::WAVEFORMATEX, pwszname: windows_core::PCWSTR, ppnewstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorage", "CreateStorage", 10usize, |this| ((**(this as *const *const IMDSPStorage_Vtbl)).CreateStorage)(this, dwattributes, pformat, pwszname, ppnewstorage), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumStorage(this: *mut core::ffi::c_void, ppenumstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorage", "EnumStorage", 11usize, |this| ((**(this as *const *const IMDSPStorage_Vtbl)).EnumStorage)(this, ppenumstorage), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendOpaqueCommand(this: *mut core::ffi::c_void, pcommand: *mut OPAQUECOMMAND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorage", "SendOpaqueCommand", 12usize, |this| ((**(this as *const *const IMDSPStorage_Vtbl)).SendOpaqueCommand)(this, pcommand), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_Audio")]
+            SetAttributes,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            SetAttributes: 0,
+            GetStorageGlobals,
+            #[cfg(feature = "Win32_Media_Audio")]
+            GetAttributes,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            GetAttributes: 0,
+            GetName,
+            GetDate,
+            GetSize,
+            GetRights,
+            #[cfg(feature = "Win32_Media_Audio")]
+            CreateStorage,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            CreateStorage: 0,
+            EnumStorage,
+            SendOpaqueCommand,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMDSPStorage as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+impl windows_core::imp::ProxyVtable for IMDSPStorage2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStorage(this: *mut core::ffi::c_void, pszstoragename: windows_core::PCWSTR, ppstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorage2", "GetStorage", 13usize, |this| ((**(this as *const *const IMDSPStorage2_Vtbl)).GetStorage)(this, pszstoragename, ppstorage), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+        unsafe extern "system" fn CreateStorage2(this: *mut core::ffi::c_void, dwattributes: u32, dwattributesex: u32, paudioformat: *const super::Audio::WAVEFORMATEX, pvideoformat: *const super::MediaFoundation::VIDEOINFOHEADER, pwszname: windows_core::PCWSTR, qwfilesize: u64, ppnewstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorage2", "CreateStorage2", 14usize, |this| ((**(this as *const *const IMDSPStorage2_Vtbl)).CreateStorage2)(this, dwattributes, dwattributesex, paudioformat, pvideoformat, pwszname, qwfilesize, ppnewstorage), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+        unsafe extern "system" fn SetAttributes2(this: *mut core::ffi::c_void, dwattributes: u32, dwattributesex: u32, paudioformat: *const super::Audio::WAVEFORMATEX, pvideoformat: *const super::MediaFoundation::VIDEOINFOHEADER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorage2", "SetAttributes2", 15usize, |this| ((**(this as *const *const IMDSPStorage2_Vtbl)).SetAttributes2)(this, dwattributes, dwattributesex, paudioformat, pvideoformat), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+        unsafe extern "system" fn GetAttributes2(this: *mut core::ffi::c_void, pdwattributes: *mut u32, pdwattributesex: *mut u32, paudioformat: *mut super::Audio::WAVEFORMATEX, pvideoformat: *mut super::MediaFoundation::VIDEOINFOHEADER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorage2", "GetAttributes2", 16usize, |this| ((**(this as *const *const IMDSPStorage2_Vtbl)).GetAttributes2)(this, pdwattributes, pdwattributesex, paudioformat, pvideoformat), |result| Some(*result))
+        }
+        Self {
+            base__: <IMDSPStorage_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetStorage,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+            CreateStorage2,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation")))]
+            CreateStorage2: 0,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+            SetAttributes2,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation")))]
+            SetAttributes2: 0,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+            GetAttributes2,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation")))]
+            GetAttributes2: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMDSPStorage2 as windows_core::Interface>::IID || <IMDSPStorage_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+impl windows_core::imp::ProxyVtable for IMDSPStorage3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMetadata(this: *mut core::ffi::c_void, pmetadata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorage3", "GetMetadata", 17usize, |this| ((**(this as *const *const IMDSPStorage3_Vtbl)).GetMetadata)(this, pmetadata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMetadata(this: *mut core::ffi::c_void, pmetadata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorage3", "SetMetadata", 18usize, |this| ((**(this as *const *const IMDSPStorage3_Vtbl)).SetMetadata)(this, pmetadata), |result| Some(*result))
+        }
+        Self { base__: <IMDSPStorage2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMetadata, SetMetadata }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMDSPStorage3 as windows_core::Interface>::IID || <IMDSPStorage2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+impl windows_core::imp::ProxyVtable for IMDSPStorage4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetReferences(this: *mut core::ffi::c_void, dwrefs: u32, ppispstorage: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorage4", "SetReferences", 19usize, |this| ((**(this as *const *const IMDSPStorage4_Vtbl)).SetReferences)(this, dwrefs, ppispstorage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReferences(this: *mut core::ffi::c_void, pdwrefs: *mut u32, pppispstorage: *mut *mut Option<IMDSPStorage>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorage4", "GetReferences", 20usize, |this| ((**(this as *const *const IMDSPStorage4_Vtbl)).GetReferences)(this, pdwrefs, pppispstorage), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateStorageWithMetadata(this: *mut core::ffi::c_void, dwattributes: u32, pwszname: windows_core::PCWSTR, pmetadata: *mut core::ffi::c_void, qwfilesize: u64, ppnewstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorage4", "CreateStorageWithMetadata", 21usize, |this| ((**(this as *const *const IMDSPStorage4_Vtbl)).CreateStorageWithMetadata)(this, dwattributes, pwszname, pmetadata, qwfilesize, ppnewstorage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSpecifiedMetadata(this: *mut core::ffi::c_void, cproperties: u32, ppwszpropnames: *const windows_core::PCWSTR, pmetadata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorage4", "GetSpecifiedMetadata", 22usize, |this| ((**(this as *const *const IMDSPStorage4_Vtbl)).GetSpecifiedMetadata)(this, cproperties, ppwszpropnames, pmetadata), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindStorage(this: *mut core::ffi::c_void, findscope: WMDM_FIND_SCOPE, pwszuniqueid: windows_core::PCWSTR, ppstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorage4", "FindStorage", 23usize, |this| ((**(this as *const *const IMDSPStorage4_Vtbl)).FindStorage)(this, findscope, pwszuniqueid, ppstorage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParent(this: *mut core::ffi::c_void, ppstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorage4", "GetParent", 24usize, |this| ((**(this as *const *const IMDSPStorage4_Vtbl)).GetParent)(this, ppstorage), |result| Some(*result))
+        }
+        Self {
+            base__: <IMDSPStorage3_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetReferences,
+            GetReferences,
+            CreateStorageWithMetadata,
+            GetSpecifiedMetadata,
+            FindStorage,
+            GetParent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMDSPStorage4 as windows_core::Interface>::IID || <IMDSPStorage3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMDSPStorageGlobals_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCapabilities(this: *mut core::ffi::c_void, pdwcapabilities: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorageGlobals", "GetCapabilities", 3usize, |this| ((**(this as *const *const IMDSPStorageGlobals_Vtbl)).GetCapabilities)(this, pdwcapabilities), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSerialNumber(this: *mut core::ffi::c_void, pserialnum: *mut WMDMID, abmac: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorageGlobals", "GetSerialNumber", 4usize, |this| ((**(this as *const *const IMDSPStorageGlobals_Vtbl)).GetSerialNumber)(this, pserialnum, abmac), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTotalSize(this: *mut core::ffi::c_void, pdwtotalsizelow: *mut u32, pdwtotalsizehigh: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorageGlobals", "GetTotalSize", 5usize, |this| ((**(this as *const *const IMDSPStorageGlobals_Vtbl)).GetTotalSize)(this, pdwtotalsizelow, pdwtotalsizehigh), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTotalFree(this: *mut core::ffi::c_void, pdwfreelow: *mut u32, pdwfreehigh: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorageGlobals", "GetTotalFree", 6usize, |this| ((**(this as *const *const IMDSPStorageGlobals_Vtbl)).GetTotalFree)(this, pdwfreelow, pdwfreehigh), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTotalBad(this: *mut core::ffi::c_void, pdwbadlow: *mut u32, pdwbadhigh: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorageGlobals", "GetTotalBad", 7usize, |this| ((**(this as *const *const IMDSPStorageGlobals_Vtbl)).GetTotalBad)(this, pdwbadlow, pdwbadhigh), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorageGlobals", "GetStatus", 8usize, |this| ((**(this as *const *const IMDSPStorageGlobals_Vtbl)).GetStatus)(this, pdwstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, fumode: u32, pprogress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorageGlobals", "Initialize", 9usize, |this| ((**(this as *const *const IMDSPStorageGlobals_Vtbl)).Initialize)(this, fumode, pprogress), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDevice(this: *mut core::ffi::c_void, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorageGlobals", "GetDevice", 10usize, |this| ((**(this as *const *const IMDSPStorageGlobals_Vtbl)).GetDevice)(this, ppdevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRootStorage(this: *mut core::ffi::c_void, pproot: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDSPStorageGlobals", "GetRootStorage", 11usize, |this| ((**(this as *const *const IMDSPStorageGlobals_Vtbl)).GetRootStorage)(this, pproot), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCapabilities,
+            GetSerialNumber,
+            GetTotalSize,
+            GetTotalFree,
+            GetTotalBad,
+            GetStatus,
+            Initialize,
+            GetDevice,
+            GetRootStorage,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMDSPStorageGlobals as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMDServiceProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceCount(this: *mut core::ffi::c_void, pdwcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDServiceProvider", "GetDeviceCount", 3usize, |this| ((**(this as *const *const IMDServiceProvider_Vtbl)).GetDeviceCount)(this, pdwcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumDevices(this: *mut core::ffi::c_void, ppenumdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDServiceProvider", "EnumDevices", 4usize, |this| ((**(this as *const *const IMDServiceProvider_Vtbl)).EnumDevices)(this, ppenumdevice), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceCount, EnumDevices }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMDServiceProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMDServiceProvider2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateDevice(this: *mut core::ffi::c_void, pwszdevicepath: windows_core::PCWSTR, pdwcount: *mut u32, pppdevicearray: *mut *mut Option<IMDSPDevice>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDServiceProvider2", "CreateDevice", 5usize, |this| ((**(this as *const *const IMDServiceProvider2_Vtbl)).CreateDevice)(this, pwszdevicepath, pdwcount, pppdevicearray), |result| Some(*result))
+        }
+        Self { base__: <IMDServiceProvider_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateDevice }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMDServiceProvider2 as windows_core::Interface>::IID || <IMDServiceProvider_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMDServiceProvider3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDeviceEnumPreference(this: *mut core::ffi::c_void, dwenumpref: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDServiceProvider3", "SetDeviceEnumPreference", 6usize, |this| ((**(this as *const *const IMDServiceProvider3_Vtbl)).SetDeviceEnumPreference)(this, dwenumpref), |result| Some(*result))
+        }
+        Self { base__: <IMDServiceProvider2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetDeviceEnumPreference }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMDServiceProvider3 as windows_core::Interface>::IID || <IMDServiceProvider2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISCPSecureAuthenticate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSecureQuery(this: *mut core::ffi::c_void, ppsecurequery: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCPSecureAuthenticate", "GetSecureQuery", 3usize, |this| ((**(this as *const *const ISCPSecureAuthenticate_Vtbl)).GetSecureQuery)(this, ppsecurequery), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSecureQuery }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISCPSecureAuthenticate as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISCPSecureAuthenticate2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSCPSession(this: *mut core::ffi::c_void, ppscpsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCPSecureAuthenticate2", "GetSCPSession", 4usize, |this| ((**(this as *const *const ISCPSecureAuthenticate2_Vtbl)).GetSCPSession)(this, ppscpsession), |result| Some(*result))
+        }
+        Self { base__: <ISCPSecureAuthenticate_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSCPSession }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISCPSecureAuthenticate2 as windows_core::Interface>::IID || <ISCPSecureAuthenticate_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISCPSecureExchange_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TransferContainerData(this: *mut core::ffi::c_void, pdata: *const u8, dwsize: u32, pfureadyflags: *mut u32, abmac: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCPSecureExchange", "TransferContainerData", 3usize, |this| ((**(this as *const *const ISCPSecureExchange_Vtbl)).TransferContainerData)(this, pdata, dwsize, pfureadyflags, abmac), |result| Some(*result))
+        }
+        unsafe extern "system" fn ObjectData(this: *mut core::ffi::c_void, pdata: *mut u8, pdwsize: *mut u32, abmac: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCPSecureExchange", "ObjectData", 4usize, |this| ((**(this as *const *const ISCPSecureExchange_Vtbl)).ObjectData)(this, pdata, pdwsize, abmac), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransferComplete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCPSecureExchange", "TransferComplete", 5usize, |this| ((**(this as *const *const ISCPSecureExchange_Vtbl)).TransferComplete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TransferContainerData, ObjectData, TransferComplete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISCPSecureExchange as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISCPSecureExchange2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TransferContainerData2(this: *mut core::ffi::c_void, pdata: *const u8, dwsize: u32, pprogresscallback: *mut core::ffi::c_void, pfureadyflags: *mut u32, abmac: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCPSecureExchange2", "TransferContainerData2", 6usize, |this| ((**(this as *const *const ISCPSecureExchange2_Vtbl)).TransferContainerData2)(this, pdata, dwsize, pprogresscallback, pfureadyflags, abmac), |result| Some(*result))
+        }
+        Self { base__: <ISCPSecureExchange_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TransferContainerData2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISCPSecureExchange2 as windows_core::Interface>::IID || <ISCPSecureExchange_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISCPSecureExchange3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TransferContainerDataOnClearChannel(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, pdata: *const u8, dwsize: u32, pprogresscallback: *mut core::ffi::c_void, pfureadyflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCPSecureExchange3", "TransferContainerDataOnClearChannel", 7usize, |this| ((**(this as *const *const ISCPSecureExchange3_Vtbl)).TransferContainerDataOnClearChannel)(this, pdevice, pdata, dwsize, pprogresscallback, pfureadyflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObjectDataOnClearChannel(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, pdata: *mut u8, pdwsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCPSecureExchange3", "GetObjectDataOnClearChannel", 8usize, |this| ((**(this as *const *const ISCPSecureExchange3_Vtbl)).GetObjectDataOnClearChannel)(this, pdevice, pdata, pdwsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransferCompleteForDevice(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCPSecureExchange3", "TransferCompleteForDevice", 9usize, |this| ((**(this as *const *const ISCPSecureExchange3_Vtbl)).TransferCompleteForDevice)(this, pdevice), |result| Some(*result))
+        }
+        Self {
+            base__: <ISCPSecureExchange2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TransferContainerDataOnClearChannel,
+            GetObjectDataOnClearChannel,
+            TransferCompleteForDevice,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISCPSecureExchange3 as windows_core::Interface>::IID || <ISCPSecureExchange2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISCPSecureQuery_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDataDemands(this: *mut core::ffi::c_void, pfuflags: *mut u32, pdwminrightsdata: *mut u32, pdwminexaminedata: *mut u32, pdwmindecidedata: *mut u32, abmac: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCPSecureQuery", "GetDataDemands", 3usize, |this| ((**(this as *const *const ISCPSecureQuery_Vtbl)).GetDataDemands)(this, pfuflags, pdwminrightsdata, pdwminexaminedata, pdwmindecidedata, abmac), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExamineData(this: *mut core::ffi::c_void, fuflags: u32, pwszextension: windows_core::PCWSTR, pdata: *const u8, dwsize: u32, abmac: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCPSecureQuery", "ExamineData", 4usize, |this| ((**(this as *const *const ISCPSecureQuery_Vtbl)).ExamineData)(this, fuflags, pwszextension, pdata, dwsize, abmac), |result| Some(*result))
+        }
+        unsafe extern "system" fn MakeDecision(this: *mut core::ffi::c_void, fuflags: u32, pdata: *const u8, dwsize: u32, dwappsec: u32, pbspsessionkey: *const u8, dwsessionkeylen: u32, pstorageglobals: *mut core::ffi::c_void, ppexchange: *mut *mut core::ffi::c_void, abmac: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCPSecureQuery", "MakeDecision", 5usize, |this| ((**(this as *const *const ISCPSecureQuery_Vtbl)).MakeDecision)(this, fuflags, pdata, dwsize, dwappsec, pbspsessionkey, dwsessionkeylen, pstorageglobals, ppexchange, abmac), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRights(this: *mut core::ffi::c_void, pdata: *const u8, dwsize: u32, pbspsessionkey: *const u8, dwsessionkeylen: u32, pstgglobals: *mut core::ffi::c_void, pprights: *mut *mut WMDMRIGHTS, pnrightscount: *mut u32, abmac: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCPSecureQuery", "GetRights", 6usize, |this| ((**(this as *const *const ISCPSecureQuery_Vtbl)).GetRights)(this, pdata, dwsize, pbspsessionkey, dwsessionkeylen, pstgglobals, pprights, pnrightscount, abmac), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDataDemands, ExamineData, MakeDecision, GetRights }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISCPSecureQuery as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISCPSecureQuery2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MakeDecision2(this: *mut core::ffi::c_void, fuflags: u32, pdata: *const u8, dwsize: u32, dwappsec: u32, pbspsessionkey: *const u8, dwsessionkeylen: u32, pstorageglobals: *mut core::ffi::c_void, pappcertapp: *const u8, dwappcertapplen: u32, pappcertsp: *const u8, dwappcertsplen: u32, pszrevocationurl: *mut windows_core::PWSTR, pdwrevocationurllen: *mut u32, pdwrevocationbitflag: *mut u32, pqwfilesize: *mut u64, punknown: *mut core::ffi::c_void, ppexchange: *mut *mut core::ffi::c_void, abmac: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCPSecureQuery2", "MakeDecision2", 7usize, |this| ((**(this as *const *const ISCPSecureQuery2_Vtbl)).MakeDecision2)(this, fuflags, pdata, dwsize, dwappsec, pbspsessionkey, dwsessionkeylen, pstorageglobals, pappcertapp, dwappcertapplen, pappcertsp, dwappcertsplen, pszrevocationurl, pdwrevocationurllen, pdwrevocationbitflag, pqwfilesize, punknown, ppexchange, abmac), |result| Some(*result))
+        }
+        Self { base__: <ISCPSecureQuery_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MakeDecision2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISCPSecureQuery2 as windows_core::Interface>::IID || <ISCPSecureQuery_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISCPSecureQuery3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRightsOnClearChannel(this: *mut core::ffi::c_void, pdata: *const u8, dwsize: u32, pbspsessionkey: *const u8, dwsessionkeylen: u32, pstgglobals: *mut core::ffi::c_void, pprogresscallback: *mut core::ffi::c_void, pprights: *mut *mut WMDMRIGHTS, pnrightscount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCPSecureQuery3", "GetRightsOnClearChannel", 8usize, |this| ((**(this as *const *const ISCPSecureQuery3_Vtbl)).GetRightsOnClearChannel)(this, pdata, dwsize, pbspsessionkey, dwsessionkeylen, pstgglobals, pprogresscallback, pprights, pnrightscount), |result| Some(*result))
+        }
+        unsafe extern "system" fn MakeDecisionOnClearChannel(this: *mut core::ffi::c_void, fuflags: u32, pdata: *const u8, dwsize: u32, dwappsec: u32, pbspsessionkey: *const u8, dwsessionkeylen: u32, pstorageglobals: *mut core::ffi::c_void, pprogresscallback: *mut core::ffi::c_void, pappcertapp: *const u8, dwappcertapplen: u32, pappcertsp: *const u8, dwappcertsplen: u32, pszrevocationurl: *mut windows_core::PWSTR, pdwrevocationurllen: *mut u32, pdwrevocationbitflag: *mut u32, pqwfilesize: *mut u64, punknown: *mut core::ffi::c_void, ppexchange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCPSecureQuery3", "MakeDecisionOnClearChannel", 9usize, |this| ((**(this as *const *const ISCPSecureQuery3_Vtbl)).MakeDecisionOnClearChannel)(this, fuflags, pdata, dwsize, dwappsec, pbspsessionkey, dwsessionkeylen, pstorageglobals, pprogresscallback, pappcertapp, dwappcertapplen, pappcertsp, dwappcertsplen, pszrevocationurl, pdwrevocationurllen, pdwrevocationbitflag, pqwfilesize, punknown, ppexchange), |result| Some(*result))
+        }
+        Self { base__: <ISCPSecureQuery2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRightsOnClearChannel, MakeDecisionOnClearChannel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISCPSecureQuery3 as windows_core::Interface>::IID || <ISCPSecureQuery2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISCPSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BeginSession(this: *mut core::ffi::c_void, pidevice: *mut core::ffi::c_void, pctx: *const u8, dwsizectx: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCPSession", "BeginSession", 3usize, |this| ((**(this as *const *const ISCPSession_Vtbl)).BeginSession)(this, pidevice, pctx, dwsizectx), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndSession(this: *mut core::ffi::c_void, pctx: *const u8, dwsizectx: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCPSession", "EndSession", 4usize, |this| ((**(this as *const *const ISCPSession_Vtbl)).EndSession)(this, pctx, dwsizectx), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSecureQuery(this: *mut core::ffi::c_void, ppsecurequery: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCPSession", "GetSecureQuery", 5usize, |this| ((**(this as *const *const ISCPSession_Vtbl)).GetSecureQuery)(this, ppsecurequery), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BeginSession, EndSession, GetSecureQuery }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISCPSession as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_Audio")]
+impl windows_core::imp::ProxyVtable for IWMDMDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, pwszname: windows_core::PWSTR, nmaxchars: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDevice", "GetName", 3usize, |this| ((**(this as *const *const IWMDMDevice_Vtbl)).GetName)(this, pwszname, nmaxchars), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetManufacturer(this: *mut core::ffi::c_void, pwszname: windows_core::PWSTR, nmaxchars: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDevice", "GetManufacturer", 4usize, |this| ((**(this as *const *const IWMDMDevice_Vtbl)).GetManufacturer)(this, pwszname, nmaxchars), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersion(this: *mut core::ffi::c_void, pdwversion: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDevice", "GetVersion", 5usize, |this| ((**(this as *const *const IWMDMDevice_Vtbl)).GetVersion)(this, pdwversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, pdwtype: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDevice", "GetType", 6usize, |this| ((**(this as *const *const IWMDMDevice_Vtbl)).GetType)(this, pdwtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSerialNumber(this: *mut core::ffi::c_void, pserialnumber: *mut WMDMID, abmac: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDevice", "GetSerialNumber", 7usize, |this| ((**(this as *const *const IWMDMDevice_Vtbl)).GetSerialNumber)(this, pserialnumber, abmac), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPowerSource(this: *mut core::ffi::c_void, pdwpowersource: *mut u32, pdwpercentremaining: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDevice", "GetPowerSource", 8usize, |this| ((**(this as *const *const IWMDMDevice_Vtbl)).GetPowerSource)(this, pdwpowersource, pdwpercentremaining), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDevice", "GetStatus", 9usize, |this| ((**(this as *const *const IWMDMDevice_Vtbl)).GetStatus)(this, pdwstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceIcon(this: *mut core::ffi::c_void, hicon: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDevice", "GetDeviceIcon", 10usize, |this| ((**(this as *const *const IWMDMDevice_Vtbl)).GetDeviceIcon)(this, hicon), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumStorage(this: *mut core::ffi::c_void, ppenumstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDevice", "EnumStorage", 11usize, |this| ((**(this as *const *const IWMDMDevice_Vtbl)).EnumStorage)(this, ppenumstorage), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn GetFormatSupport(this: *mut core::ffi::c_void, ppformatex: *mut *mut super::Audio::WAVEFORMATEX, pnformatcount: *mut u32, pppwszmimetype: *mut *mut windows_core::PWSTR, pnmimetypecount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDevice", "GetFormatSupport", 12usize, |this| ((**(this as *const *const IWMDMDevice_Vtbl)).GetFormatSupport)(this, ppformatex, pnformatcount, pppwszmimetype, pnmimetypecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendOpaqueCommand(this: *mut core::ffi::c_void, pcommand: *mut OPAQUECOMMAND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDevice", "SendOpaqueCommand", 13usize, |this| ((**(this as *const *const IWMDMDevice_Vtbl)).SendOpaqueCommand)(this, pcommand), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetName,
+            GetManufacturer,
+            GetVersion,
+            GetType,
+            GetSerialNumber,
+            GetPowerSource,
+            GetStatus,
+            GetDeviceIcon,
+            EnumStorage,
+            #[cfg(feature = "Win32_Media_Audio")]
+            GetFormatSupport,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            GetFormatSupport: 0,
+            SendOpaqueCommand,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMDevice as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IWMDMDevice2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStorage(this: *mut core::ffi::c_void, pszstoragename: windows_core::PCWSTR, ppstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDevice2", "GetStorage", 14usize, |this| ((**(this as *const *const IWMDMDevice2_Vtbl)).GetStorage)(this, pszstoragename, ppstorage), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+        unsafe extern "system" fn GetFormatSupport2(this: *mut core::ffi::c_void, dwflags: u32, ppaudioformatex: *mut *mut super::Audio::WAVEFORMATEX, pnaudioformatcount: *mut u32, ppvideoformatex: *mut *mut super::MediaFoundation::VIDEOINFOHEADER, pnvideoformatcount: *mut u32, ppfiletype: *mut *mut WMFILECAPABILITIES, pnfiletypecount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDevice2", "GetFormatSupport2", 15usize, |this| ((**(this as *const *const IWMDMDevice2_Vtbl)).GetFormatSupport2)(this, dwflags, ppaudioformatex, pnaudioformatcount, ppvideoformatex, pnvideoformatcount, ppfiletype, pnfiletypecount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn GetSpecifyPropertyPages(this: *mut core::ffi::c_void, ppspecifyproppages: *mut *mut core::ffi::c_void, pppunknowns: *mut *mut Option<windows_core::IUnknown>, pcunks: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDevice2", "GetSpecifyPropertyPages", 16usize, |this| ((**(this as *const *const IWMDMDevice2_Vtbl)).GetSpecifyPropertyPages)(this, ppspecifyproppages, pppunknowns, pcunks), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCanonicalName(this: *mut core::ffi::c_void, pwszpnpname: windows_core::PWSTR, nmaxchars: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDevice2", "GetCanonicalName", 17usize, |this| ((**(this as *const *const IWMDMDevice2_Vtbl)).GetCanonicalName)(this, pwszpnpname, nmaxchars), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMDMDevice_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetStorage,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+            GetFormatSupport2,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation")))]
+            GetFormatSupport2: 0,
+            #[cfg(feature = "Win32_System_Ole")]
+            GetSpecifyPropertyPages,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            GetSpecifyPropertyPages: 0,
+            GetCanonicalName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMDevice2 as windows_core::Interface>::IID || <IWMDMDevice_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IWMDMDevice3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, pwszpropname: windows_core::PCWSTR, pvalue: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDevice3", "GetProperty", 18usize, |this| ((**(this as *const *const IWMDMDevice3_Vtbl)).GetProperty)(this, pwszpropname, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, pwszpropname: windows_core::PCWSTR, pvalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDevice3", "SetProperty", 19usize, |this| ((**(this as *const *const IWMDMDevice3_Vtbl)).SetProperty)(this, pwszpropname, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFormatCapability(this: *mut core::ffi::c_void, format: WMDM_FORMATCODE, pformatsupport: *mut WMDM_FORMAT_CAPABILITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDevice3", "GetFormatCapability", 20usize, |this| ((**(this as *const *const IWMDMDevice3_Vtbl)).GetFormatCapability)(this, format, pformatsupport), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceIoControl(this: *mut core::ffi::c_void, dwiocontrolcode: u32, lpinbuffer: *const u8, ninbuffersize: u32, lpoutbuffer: *mut u8, pnoutbuffersize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDevice3", "DeviceIoControl", 21usize, |this| ((**(this as *const *const IWMDMDevice3_Vtbl)).DeviceIoControl)(this, dwiocontrolcode, lpinbuffer, ninbuffersize, lpoutbuffer, pnoutbuffersize), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindStorage(this: *mut core::ffi::c_void, findscope: WMDM_FIND_SCOPE, pwszuniqueid: windows_core::PCWSTR, ppstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDevice3", "FindStorage", 22usize, |this| ((**(this as *const *const IWMDMDevice3_Vtbl)).FindStorage)(this, findscope, pwszuniqueid, ppstorage), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMDMDevice2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetProperty,
+            SetProperty,
+            GetFormatCapability,
+            DeviceIoControl,
+            FindStorage,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMDevice3 as windows_core::Interface>::IID || <IWMDMDevice2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_Audio")]
+impl windows_core::imp::ProxyVtable for IWMDMDeviceControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDeviceControl", "GetStatus", 3usize, |this| ((**(this as *const *const IWMDMDeviceControl_Vtbl)).GetStatus)(this, pdwstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCapabilities(this: *mut core::ffi::c_void, pdwcapabilitiesmask: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDeviceControl", "GetCapabilities", 4usize, |this| ((**(this as *const *const IWMDMDeviceControl_Vtbl)).GetCapabilities)(this, pdwcapabilitiesmask), |result| Some(*result))
+        }
+        unsafe extern "system" fn Play(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDeviceControl", "Play", 5usize, |this| ((**(this as *const *const IWMDMDeviceControl_Vtbl)).Play)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn Record(this: *mut core::ffi::c_void, pformat: *const super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDeviceControl", "Record", 6usize, |this| ((**(this as *const *const IWMDMDeviceControl_Vtbl)).Record)(this, pformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDeviceControl", "Pause", 7usize, |this| ((**(this as *const *const IWMDMDeviceControl_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDeviceControl", "Resume", 8usize, |this| ((**(this as *const *const IWMDMDeviceControl_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDeviceControl", "Stop", 9usize, |this| ((**(this as *const *const IWMDMDeviceControl_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Seek(this: *mut core::ffi::c_void, fumode: u32, noffset: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDeviceControl", "Seek", 10usize, |this| ((**(this as *const *const IWMDMDeviceControl_Vtbl)).Seek)(this, fumode, noffset), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetStatus,
+            GetCapabilities,
+            Play,
+            #[cfg(feature = "Win32_Media_Audio")]
+            Record,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            Record: 0,
+            Pause,
+            Resume,
+            Stop,
+            Seek,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMDeviceControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDMDeviceSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BeginSession(this: *mut core::ffi::c_void, r#type: WMDM_SESSION_TYPE, pctx: *const u8, dwsizectx: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDeviceSession", "BeginSession", 3usize, |this| ((**(this as *const *const IWMDMDeviceSession_Vtbl)).BeginSession)(this, r#type, pctx, dwsizectx), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndSession(this: *mut core::ffi::c_void, r#type: WMDM_SESSION_TYPE, pctx: *const u8, dwsizectx: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMDeviceSession", "EndSession", 4usize, |this| ((**(this as *const *const IWMDMDeviceSession_Vtbl)).EndSession)(this, r#type, pctx, dwsizectx), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BeginSession, EndSession }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMDeviceSession as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDMEnumDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppdevice: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMEnumDevice", "Next", 3usize, |this| ((**(this as *const *const IWMDMEnumDevice_Vtbl)).Next)(this, celt, ppdevice, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMEnumDevice", "Skip", 4usize, |this| ((**(this as *const *const IWMDMEnumDevice_Vtbl)).Skip)(this, celt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMEnumDevice", "Reset", 5usize, |this| ((**(this as *const *const IWMDMEnumDevice_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenumdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMEnumDevice", "Clone", 6usize, |this| ((**(this as *const *const IWMDMEnumDevice_Vtbl)).Clone)(this, ppenumdevice), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMEnumDevice as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDMEnumStorage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppstorage: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMEnumStorage", "Next", 3usize, |this| ((**(this as *const *const IWMDMEnumStorage_Vtbl)).Next)(this, celt, ppstorage, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMEnumStorage", "Skip", 4usize, |this| ((**(this as *const *const IWMDMEnumStorage_Vtbl)).Skip)(this, celt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMEnumStorage", "Reset", 5usize, |this| ((**(this as *const *const IWMDMEnumStorage_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenumstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMEnumStorage", "Clone", 6usize, |this| ((**(this as *const *const IWMDMEnumStorage_Vtbl)).Clone)(this, ppenumstorage), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMEnumStorage as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDMLogger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, pfenabled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMLogger", "IsEnabled", 3usize, |this| ((**(this as *const *const IWMDMLogger_Vtbl)).IsEnabled)(this, pfenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enable(this: *mut core::ffi::c_void, fenable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMLogger", "Enable", 4usize, |this| ((**(this as *const *const IWMDMLogger_Vtbl)).Enable)(this, fenable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLogFileName(this: *mut core::ffi::c_void, pszfilename: windows_core::PSTR, nmaxchars: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMLogger", "GetLogFileName", 5usize, |this| ((**(this as *const *const IWMDMLogger_Vtbl)).GetLogFileName)(this, pszfilename, nmaxchars), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLogFileName(this: *mut core::ffi::c_void, pszfilename: windows_core::PCSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMLogger", "SetLogFileName", 6usize, |this| ((**(this as *const *const IWMDMLogger_Vtbl)).SetLogFileName)(this, pszfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogString(this: *mut core::ffi::c_void, dwflags: u32, pszsrcname: windows_core::PCSTR, pszlog: windows_core::PCSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMLogger", "LogString", 7usize, |this| ((**(this as *const *const IWMDMLogger_Vtbl)).LogString)(this, dwflags, pszsrcname, pszlog), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogDword(this: *mut core::ffi::c_void, dwflags: u32, pszsrcname: windows_core::PCSTR, pszlogformat: windows_core::PCSTR, dwlog: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMLogger", "LogDword", 8usize, |this| ((**(this as *const *const IWMDMLogger_Vtbl)).LogDword)(this, dwflags, pszsrcname, pszlogformat, dwlog), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMLogger", "Reset", 9usize, |this| ((**(this as *const *const IWMDMLogger_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSizeParams(this: *mut core::ffi::c_void, pdwmaxsize: *mut u32, pdwshrinktosize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMLogger", "GetSizeParams", 10usize, |this| ((**(this as *const *const IWMDMLogger_Vtbl)).GetSizeParams)(this, pdwmaxsize, pdwshrinktosize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSizeParams(this: *mut core::ffi::c_void, dwmaxsize: u32, dwshrinktosize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMLogger", "SetSizeParams", 11usize, |this| ((**(this as *const *const IWMDMLogger_Vtbl)).SetSizeParams)(this, dwmaxsize, dwshrinktosize), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsEnabled,
+            Enable,
+            GetLogFileName,
+            SetLogFileName,
+            LogString,
+            LogDword,
+            Reset,
+            GetSizeParams,
+            SetSizeParams,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMLogger as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDMMetaData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddItem(this: *mut core::ffi::c_void, r#type: WMDM_TAG_DATATYPE, pwsztagname: windows_core::PCWSTR, pvalue: *const u8, ilength: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMMetaData", "AddItem", 3usize, |this| ((**(this as *const *const IWMDMMetaData_Vtbl)).AddItem)(this, r#type, pwsztagname, pvalue, ilength), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryByName(this: *mut core::ffi::c_void, pwsztagname: windows_core::PCWSTR, ptype: *mut WMDM_TAG_DATATYPE, pvalue: *mut *mut u8, pcblength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMMetaData", "QueryByName", 4usize, |this| ((**(this as *const *const IWMDMMetaData_Vtbl)).QueryByName)(this, pwsztagname, ptype, pvalue, pcblength), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryByIndex(this: *mut core::ffi::c_void, iindex: u32, ppwszname: *mut *mut u16, ptype: *mut WMDM_TAG_DATATYPE, ppvalue: *mut *mut u8, pcblength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMMetaData", "QueryByIndex", 5usize, |this| ((**(this as *const *const IWMDMMetaData_Vtbl)).QueryByIndex)(this, iindex, ppwszname, ptype, ppvalue, pcblength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItemCount(this: *mut core::ffi::c_void, icount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMMetaData", "GetItemCount", 6usize, |this| ((**(this as *const *const IWMDMMetaData_Vtbl)).GetItemCount)(this, icount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddItem, QueryByName, QueryByIndex, GetItemCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMMetaData as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDMNotification_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WMDMMessage(this: *mut core::ffi::c_void, dwmessagetype: u32, pwszcanonicalname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMNotification", "WMDMMessage", 3usize, |this| ((**(this as *const *const IWMDMNotification_Vtbl)).WMDMMessage)(this, dwmessagetype, pwszcanonicalname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WMDMMessage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMNotification as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDMObjectInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPlayLength(this: *mut core::ffi::c_void, pdwlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMObjectInfo", "GetPlayLength", 3usize, |this| ((**(this as *const *const IWMDMObjectInfo_Vtbl)).GetPlayLength)(this, pdwlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPlayLength(this: *mut core::ffi::c_void, dwlength: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMObjectInfo", "SetPlayLength", 4usize, |this| ((**(this as *const *const IWMDMObjectInfo_Vtbl)).SetPlayLength)(this, dwlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPlayOffset(this: *mut core::ffi::c_void, pdwoffset: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMObjectInfo", "GetPlayOffset", 5usize, |this| ((**(this as *const *const IWMDMObjectInfo_Vtbl)).GetPlayOffset)(this, pdwoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPlayOffset(this: *mut core::ffi::c_void, dwoffset: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMObjectInfo", "SetPlayOffset", 6usize, |this| ((**(this as *const *const IWMDMObjectInfo_Vtbl)).SetPlayOffset)(this, dwoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTotalLength(this: *mut core::ffi::c_void, pdwlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMObjectInfo", "GetTotalLength", 7usize, |this| ((**(this as *const *const IWMDMObjectInfo_Vtbl)).GetTotalLength)(this, pdwlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastPlayPosition(this: *mut core::ffi::c_void, pdwlastpos: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMObjectInfo", "GetLastPlayPosition", 8usize, |this| ((**(this as *const *const IWMDMObjectInfo_Vtbl)).GetLastPlayPosition)(this, pdwlastpos), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLongestPlayPosition(this: *mut core::ffi::c_void, pdwlongestpos: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMObjectInfo", "GetLongestPlayPosition", 9usize, |this| ((**(this as *const *const IWMDMObjectInfo_Vtbl)).GetLongestPlayPosition)(this, pdwlongestpos), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPlayLength,
+            SetPlayLength,
+            GetPlayOffset,
+            SetPlayOffset,
+            GetTotalLength,
+            GetLastPlayPosition,
+            GetLongestPlayPosition,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMObjectInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_Audio")]
+impl windows_core::imp::ProxyVtable for IWMDMOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BeginRead(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMOperation", "BeginRead", 3usize, |this| ((**(this as *const *const IWMDMOperation_Vtbl)).BeginRead)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginWrite(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMOperation", "BeginWrite", 4usize, |this| ((**(this as *const *const IWMDMOperation_Vtbl)).BeginWrite)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObjectName(this: *mut core::ffi::c_void, pwszname: windows_core::PWSTR, nmaxchars: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMOperation", "GetObjectName", 5usize, |this| ((**(this as *const *const IWMDMOperation_Vtbl)).GetObjectName)(this, pwszname, nmaxchars), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetObjectName(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR, nmaxchars: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMOperation", "SetObjectName", 6usize, |this| ((**(this as *const *const IWMDMOperation_Vtbl)).SetObjectName)(this, pwszname, nmaxchars), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn GetObjectAttributes(this: *mut core::ffi::c_void, pdwattributes: *mut u32, pformat: *mut super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMOperation", "GetObjectAttributes", 7usize, |this| ((**(this as *const *const IWMDMOperation_Vtbl)).GetObjectAttributes)(this, pdwattributes, pformat), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn SetObjectAttributes(this: *mut core::ffi::c_void, dwattributes: u32, pformat: *const super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMOperation", "SetObjectAttributes", 8usize, |this| ((**(this as *const *const IWMDMOperation_Vtbl)).SetObjectAttributes)(this, dwattributes, pformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObjectTotalSize(this: *mut core::ffi::c_void, pdwsize: *mut u32, pdwsizehigh: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMOperation", "GetObjectTotalSize", 9usize, |this| ((**(this as *const *const IWMDMOperation_Vtbl)).GetObjectTotalSize)(this, pdwsize, pdwsizehigh), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetObjectTotalSize(this: *mut core::ffi::c_void, dwsize: u32, dwsizehigh: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMOperation", "SetObjectTotalSize", 10usize, |this| ((**(this as *const *const IWMDMOperation_Vtbl)).SetObjectTotalSize)(this, dwsize, dwsizehigh), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransferObjectData(this: *mut core::ffi::c_void, pdata: *mut u8, pdwsize: *mut u32, abmac: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMOperation", "TransferObjectData", 11usize, |this| ((**(this as *const *const IWMDMOperation_Vtbl)).TransferObjectData)(this, pdata, pdwsize, abmac), |result| Some(*result))
+        }
+        unsafe extern "system" fn End(this: *mut core::ffi::c_void, phcompletioncode: *const windows_core::HRESULT, pnewobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMOperation", "End", 12usize, |this| ((**(this as *const *const IWMDMOperation_Vtbl)).End)(this, phcompletioncode, pnewobject), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BeginRead,
+            BeginWrite,
+            GetObjectName,
+            SetObjectName,
+            #[cfg(feature = "Win32_Media_Audio")]
+            GetObjectAttributes,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            GetObjectAttributes: 0,
+            #[cfg(feature = "Win32_Media_Audio")]
+            SetObjectAttributes,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            SetObjectAttributes: 0,
+            GetObjectTotalSize,
+            SetObjectTotalSize,
+            TransferObjectData,
+            End,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMOperation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+impl windows_core::imp::ProxyVtable for IWMDMOperation2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+        unsafe extern "system" fn SetObjectAttributes2(this: *mut core::ffi::c_void, dwattributes: u32, dwattributesex: u32, pformat: *const super::Audio::WAVEFORMATEX, pvideoformat: *const super::MediaFoundation::VIDEOINFOHEADER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMOperation2", "SetObjectAttributes2", 13usize, |this| ((**(this as *const *const IWMDMOperation2_Vtbl)).SetObjectAttributes2)(this, dwattributes, dwattributesex, pformat, pvideoformat), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+        unsafe extern "system" fn GetObjectAttributes2(this: *mut core::ffi::c_void, pdwattributes: *mut u32, pdwattributesex: *mut u32, paudioformat: *mut super::Audio::WAVEFORMATEX, pvideoformat: *mut super::MediaFoundation::VIDEOINFOHEADER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMOperation2", "GetObjectAttributes2", 14usize, |this| ((**(this as *const *const IWMDMOperation2_Vtbl)).GetObjectAttributes2)(this, pdwattributes, pdwattributesex, paudioformat, pvideoformat), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMDMOperation_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+            SetObjectAttributes2,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation")))]
+            SetObjectAttributes2: 0,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+            GetObjectAttributes2,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation")))]
+            GetObjectAttributes2: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMOperation2 as windows_core::Interface>::IID || <IWMDMOperation_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_Audio")]
+impl windows_core::imp::ProxyVtable for IWMDMOperation3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TransferObjectDataOnClearChannel(this: *mut core::ffi::c_void, pdata: *mut u8, pdwsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMOperation3", "TransferObjectDataOnClearChannel", 13usize, |this| ((**(this as *const *const IWMDMOperation3_Vtbl)).TransferObjectDataOnClearChannel)(this, pdata, pdwsize), |result| Some(*result))
+        }
+        Self { base__: <IWMDMOperation_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TransferObjectDataOnClearChannel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMOperation3 as windows_core::Interface>::IID || <IWMDMOperation_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDMProgress_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Begin(this: *mut core::ffi::c_void, dwestimatedticks: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMProgress", "Begin", 3usize, |this| ((**(this as *const *const IWMDMProgress_Vtbl)).Begin)(this, dwestimatedticks), |result| Some(*result))
+        }
+        unsafe extern "system" fn Progress(this: *mut core::ffi::c_void, dwtranspiredticks: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMProgress", "Progress", 4usize, |this| ((**(this as *const *const IWMDMProgress_Vtbl)).Progress)(this, dwtranspiredticks), |result| Some(*result))
+        }
+        unsafe extern "system" fn End(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMProgress", "End", 5usize, |this| ((**(this as *const *const IWMDMProgress_Vtbl)).End)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Begin, Progress, End }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMProgress as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDMProgress2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn End2(this: *mut core::ffi::c_void, hrcompletioncode: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMProgress2", "End2", 6usize, |this| ((**(this as *const *const IWMDMProgress2_Vtbl)).End2)(this, hrcompletioncode), |result| Some(*result))
+        }
+        Self { base__: <IWMDMProgress_Vtbl as windows_core::imp::ProxyVtable>::PROXY, End2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMProgress2 as windows_core::Interface>::IID || <IWMDMProgress_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDMProgress3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Begin3(this: *mut core::ffi::c_void, eventid: windows_core::GUID, dwestimatedticks: u32, pcontext: *mut OPAQUECOMMAND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMProgress3", "Begin3", 7usize, |this| ((**(this as *const *const IWMDMProgress3_Vtbl)).Begin3)(this, eventid, dwestimatedticks, pcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn Progress3(this: *mut core::ffi::c_void, eventid: windows_core::GUID, dwtranspiredticks: u32, pcontext: *mut OPAQUECOMMAND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMProgress3", "Progress3", 8usize, |this| ((**(this as *const *const IWMDMProgress3_Vtbl)).Progress3)(this, eventid, dwtranspiredticks, pcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn End3(this: *mut core::ffi::c_void, eventid: windows_core::GUID, hrcompletioncode: windows_core::HRESULT, pcontext: *mut OPAQUECOMMAND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMProgress3", "End3", 9usize, |this| ((**(this as *const *const IWMDMProgress3_Vtbl)).End3)(this, eventid, hrcompletioncode, pcontext), |result| Some(*result))
+        }
+        Self { base__: <IWMDMProgress2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Begin3, Progress3, End3 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMProgress3 as windows_core::Interface>::IID || <IWMDMProgress2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDMRevoked_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRevocationURL(this: *mut core::ffi::c_void, ppwszrevocationurl: *mut windows_core::PWSTR, pdwbufferlen: *mut u32, pdwrevokedbitflag: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMRevoked", "GetRevocationURL", 3usize, |this| ((**(this as *const *const IWMDMRevoked_Vtbl)).GetRevocationURL)(this, ppwszrevocationurl, pdwbufferlen, pdwrevokedbitflag), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRevocationURL }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMRevoked as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_Audio")]
+impl windows_core::imp::ProxyVtable for IWMDMStorage_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn SetAttributes(this: *mut core::ffi::c_void, dwattributes: u32, pformat: *const super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorage", "SetAttributes", 3usize, |this| ((**(this as *const *const IWMDMStorage_Vtbl)).SetAttributes)(this, dwattributes, pformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStorageGlobals(this: *mut core::ffi::c_void, ppstorageglobals: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorage", "GetStorageGlobals", 4usize, |this| ((**(this as *const *const IWMDMStorage_Vtbl)).GetStorageGlobals)(this, ppstorageglobals), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn GetAttributes(this: *mut core::ffi::c_void, pdwattributes: *mut u32, pformat: *mut super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorage", "GetAttributes", 5usize, |this| ((**(this as *const *const IWMDMStorage_Vtbl)).GetAttributes)(this, pdwattributes, pformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, pwszname: windows_core::PWSTR, nmaxchars: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorage", "GetName", 6usize, |this| ((**(this as *const *const IWMDMStorage_Vtbl)).GetName)(this, pwszname, nmaxchars), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDate(this: *mut core::ffi::c_void, pdatetimeutc: *mut WMDMDATETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorage", "GetDate", 7usize, |this| ((**(this as *const *const IWMDMStorage_Vtbl)).GetDate)(this, pdatetimeutc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSize(this: *mut core::ffi::c_void, pdwsizelow: *mut u32, pdwsizehigh: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorage", "GetSize", 8usize, |this| ((**(this as *const *const IWMDMStorage_Vtbl)).GetSize)(this, pdwsizelow, pdwsizehigh), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRights(this: *mut core::ffi::c_void, pprights: *mut *mut WMDMRIGHTS, pnrightscount: *mut u32, abmac: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorage", "GetRights", 9usize, |this| ((**(this as *const *const IWMDMStorage_Vtbl)).GetRights)(this, pprights, pnrightscount, abmac), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumStorage(this: *mut core::ffi::c_void, penumstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorage", "EnumStorage", 10usize, |this| ((**(this as *const *const IWMDMStorage_Vtbl)).EnumStorage)(this, penumstorage), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendOpaqueCommand(this: *mut core::ffi::c_void, pcommand: *mut OPAQUECOMMAND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorage", "SendOpaqueCommand", 11usize, |this| ((**(this as *const *const IWMDMStorage_Vtbl)).SendOpaqueCommand)(this, pcommand), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_Audio")]
+            SetAttributes,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            SetAttributes: 0,
+            GetStorageGlobals,
+            #[cfg(feature = "Win32_Media_Audio")]
+            GetAttributes,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            GetAttributes: 0,
+            GetName,
+            GetDate,
+            GetSize,
+            GetRights,
+            EnumStorage,
+            SendOpaqueCommand,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMStorage as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+impl windows_core::imp::ProxyVtable for IWMDMStorage2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStorage(this: *mut core::ffi::c_void, pszstoragename: windows_core::PCWSTR, ppstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorage2", "GetStorage", 12usize, |this| ((**(this as *const *const IWMDMStorage2_Vtbl)).GetStorage)(this, pszstoragename, ppstorage), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+        unsafe extern "system" fn SetAttributes2(this: *mut core::ffi::c_void, dwattributes: u32, dwattributesex: u32, pformat: *const super::Audio::WAVEFORMATEX, pvideoformat: *const super::MediaFoundation::VIDEOINFOHEADER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorage2", "SetAttributes2", 13usize, |this| ((**(this as *const *const IWMDMStorage2_Vtbl)).SetAttributes2)(this, dwattributes, dwattributesex, pformat, pvideoformat), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+        unsafe extern "system" fn GetAttributes2(this: *mut core::ffi::c_void, pdwattributes: *mut u32, pdwattributesex: *mut u32, paudioformat: *mut super::Audio::WAVEFORMATEX, pvideoformat: *mut super::MediaFoundation::VIDEOINFOHEADER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorage2", "GetAttributes2", 14usize, |this| ((**(this as *const *const IWMDMStorage2_Vtbl)).GetAttributes2)(this, pdwattributes, pdwattributesex, paudioformat, pvideoformat), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMDMStorage_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetStorage,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+            SetAttributes2,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation")))]
+            SetAttributes2: 0,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+            GetAttributes2,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation")))]
+            GetAttributes2: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMStorage2 as windows_core::Interface>::IID || <IWMDMStorage_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+impl windows_core::imp::ProxyVtable for IWMDMStorage3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMetadata(this: *mut core::ffi::c_void, ppmetadata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorage3", "GetMetadata", 15usize, |this| ((**(this as *const *const IWMDMStorage3_Vtbl)).GetMetadata)(this, ppmetadata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMetadata(this: *mut core::ffi::c_void, pmetadata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorage3", "SetMetadata", 16usize, |this| ((**(this as *const *const IWMDMStorage3_Vtbl)).SetMetadata)(this, pmetadata), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateEmptyMetadataObject(this: *mut core::ffi::c_void, ppmetadata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorage3", "CreateEmptyMetadataObject", 17usize, |this| ((**(this as *const *const IWMDMStorage3_Vtbl)).CreateEmptyMetadataObject)(this, ppmetadata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnumPreference(this: *mut core::ffi::c_void, pmode: *mut WMDM_STORAGE_ENUM_MODE, nviews: u32, pviews: *const WMDMMetadataView) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorage3", "SetEnumPreference", 18usize, |this| ((**(this as *const *const IWMDMStorage3_Vtbl)).SetEnumPreference)(this, pmode, nviews, pviews), |result| Some(*result))
+        }
+        Self { base__: <IWMDMStorage2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMetadata, SetMetadata, CreateEmptyMetadataObject, SetEnumPreference }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMStorage3 as windows_core::Interface>::IID || <IWMDMStorage2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Media_Audio", feature = "Win32_Media_MediaFoundation"))]
+impl windows_core::imp::ProxyVtable for IWMDMStorage4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetReferences(this: *mut core::ffi::c_void, dwrefs: u32, ppiwmdmstorage: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorage4", "SetReferences", 19usize, |this| ((**(this as *const *const IWMDMStorage4_Vtbl)).SetReferences)(this, dwrefs, ppiwmdmstorage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReferences(this: *mut core::ffi::c_void, pdwrefs: *mut u32, pppiwmdmstorage: *mut *mut Option<IWMDMStorage>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorage4", "GetReferences", 20usize, |this| ((**(this as *const *const IWMDMStorage4_Vtbl)).GetReferences)(this, pdwrefs, pppiwmdmstorage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRightsWithProgress(this: *mut core::ffi::c_void, piprogresscallback: *mut core::ffi::c_void, pprights: *mut *mut WMDMRIGHTS, pnrightscount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorage4", "GetRightsWithProgress", 21usize, |this| ((**(this as *const *const IWMDMStorage4_Vtbl)).GetRightsWithProgress)(this, piprogresscallback, pprights, pnrightscount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSpecifiedMetadata(this: *mut core::ffi::c_void, cproperties: u32, ppwszpropnames: *const windows_core::PCWSTR, ppmetadata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorage4", "GetSpecifiedMetadata", 22usize, |this| ((**(this as *const *const IWMDMStorage4_Vtbl)).GetSpecifiedMetadata)(this, cproperties, ppwszpropnames, ppmetadata), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindStorage(this: *mut core::ffi::c_void, findscope: WMDM_FIND_SCOPE, pwszuniqueid: windows_core::PCWSTR, ppstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorage4", "FindStorage", 23usize, |this| ((**(this as *const *const IWMDMStorage4_Vtbl)).FindStorage)(this, findscope, pwszuniqueid, ppstorage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParent(this: *mut core::ffi::c_void, ppstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorage4", "GetParent", 24usize, |this| ((**(this as *const *const IWMDMStorage4_Vtbl)).GetParent)(this, ppstorage), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMDMStorage3_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetReferences,
+            GetReferences,
+            GetRightsWithProgress,
+            GetSpecifiedMetadata,
+            FindStorage,
+            GetParent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMStorage4 as windows_core::Interface>::IID || <IWMDMStorage3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDMStorageControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Insert(this: *mut core::ffi::c_void, fumode: u32, pwszfile: windows_core::PCWSTR, poperation: *mut core::ffi::c_void, pprogress: *mut core::ffi::c_void, ppnewobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorageControl", "Insert", 3usize, |this| ((**(this as *const *const IWMDMStorageControl_Vtbl)).Insert)(this, fumode, pwszfile, poperation, pprogress, ppnewobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, fumode: u32, pprogress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorageControl", "Delete", 4usize, |this| ((**(this as *const *const IWMDMStorageControl_Vtbl)).Delete)(this, fumode, pprogress), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rename(this: *mut core::ffi::c_void, fumode: u32, pwsznewname: windows_core::PCWSTR, pprogress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorageControl", "Rename", 5usize, |this| ((**(this as *const *const IWMDMStorageControl_Vtbl)).Rename)(this, fumode, pwsznewname, pprogress), |result| Some(*result))
+        }
+        unsafe extern "system" fn Read(this: *mut core::ffi::c_void, fumode: u32, pwszfile: windows_core::PCWSTR, pprogress: *mut core::ffi::c_void, poperation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorageControl", "Read", 6usize, |this| ((**(this as *const *const IWMDMStorageControl_Vtbl)).Read)(this, fumode, pwszfile, pprogress, poperation), |result| Some(*result))
+        }
+        unsafe extern "system" fn Move(this: *mut core::ffi::c_void, fumode: u32, ptargetobject: *mut core::ffi::c_void, pprogress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorageControl", "Move", 7usize, |this| ((**(this as *const *const IWMDMStorageControl_Vtbl)).Move)(this, fumode, ptargetobject, pprogress), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Insert, Delete, Rename, Read, Move }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMStorageControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDMStorageControl2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Insert2(this: *mut core::ffi::c_void, fumode: u32, pwszfilesource: windows_core::PCWSTR, pwszfiledest: windows_core::PCWSTR, poperation: *mut core::ffi::c_void, pprogress: *mut core::ffi::c_void, punknown: *mut core::ffi::c_void, ppnewobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorageControl2", "Insert2", 8usize, |this| ((**(this as *const *const IWMDMStorageControl2_Vtbl)).Insert2)(this, fumode, pwszfilesource, pwszfiledest, poperation, pprogress, punknown, ppnewobject), |result| Some(*result))
+        }
+        Self { base__: <IWMDMStorageControl_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Insert2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMStorageControl2 as windows_core::Interface>::IID || <IWMDMStorageControl_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDMStorageControl3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Insert3(this: *mut core::ffi::c_void, fumode: u32, futype: u32, pwszfilesource: windows_core::PCWSTR, pwszfiledest: windows_core::PCWSTR, poperation: *mut core::ffi::c_void, pprogress: *mut core::ffi::c_void, pmetadata: *mut core::ffi::c_void, punknown: *mut core::ffi::c_void, ppnewobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorageControl3", "Insert3", 9usize, |this| ((**(this as *const *const IWMDMStorageControl3_Vtbl)).Insert3)(this, fumode, futype, pwszfilesource, pwszfiledest, poperation, pprogress, pmetadata, punknown, ppnewobject), |result| Some(*result))
+        }
+        Self { base__: <IWMDMStorageControl2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Insert3 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMStorageControl3 as windows_core::Interface>::IID || <IWMDMStorageControl2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDMStorageGlobals_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCapabilities(this: *mut core::ffi::c_void, pdwcapabilities: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorageGlobals", "GetCapabilities", 3usize, |this| ((**(this as *const *const IWMDMStorageGlobals_Vtbl)).GetCapabilities)(this, pdwcapabilities), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSerialNumber(this: *mut core::ffi::c_void, pserialnum: *mut WMDMID, abmac: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorageGlobals", "GetSerialNumber", 4usize, |this| ((**(this as *const *const IWMDMStorageGlobals_Vtbl)).GetSerialNumber)(this, pserialnum, abmac), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTotalSize(this: *mut core::ffi::c_void, pdwtotalsizelow: *mut u32, pdwtotalsizehigh: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorageGlobals", "GetTotalSize", 5usize, |this| ((**(this as *const *const IWMDMStorageGlobals_Vtbl)).GetTotalSize)(this, pdwtotalsizelow, pdwtotalsizehigh), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTotalFree(this: *mut core::ffi::c_void, pdwfreelow: *mut u32, pdwfreehigh: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorageGlobals", "GetTotalFree", 6usize, |this| ((**(this as *const *const IWMDMStorageGlobals_Vtbl)).GetTotalFree)(this, pdwfreelow, pdwfreehigh), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTotalBad(this: *mut core::ffi::c_void, pdwbadlow: *mut u32, pdwbadhigh: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorageGlobals", "GetTotalBad", 7usize, |this| ((**(this as *const *const IWMDMStorageGlobals_Vtbl)).GetTotalBad)(this, pdwbadlow, pdwbadhigh), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorageGlobals", "GetStatus", 8usize, |this| ((**(this as *const *const IWMDMStorageGlobals_Vtbl)).GetStatus)(this, pdwstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, fumode: u32, pprogress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDMStorageGlobals", "Initialize", 9usize, |this| ((**(this as *const *const IWMDMStorageGlobals_Vtbl)).Initialize)(this, fumode, pprogress), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCapabilities,
+            GetSerialNumber,
+            GetTotalSize,
+            GetTotalFree,
+            GetTotalBad,
+            GetStatus,
+            Initialize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDMStorageGlobals as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDeviceManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRevision(this: *mut core::ffi::c_void, pdwrevision: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDeviceManager", "GetRevision", 3usize, |this| ((**(this as *const *const IWMDeviceManager_Vtbl)).GetRevision)(this, pdwrevision), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceCount(this: *mut core::ffi::c_void, pdwcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDeviceManager", "GetDeviceCount", 4usize, |this| ((**(this as *const *const IWMDeviceManager_Vtbl)).GetDeviceCount)(this, pdwcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumDevices(this: *mut core::ffi::c_void, ppenumdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDeviceManager", "EnumDevices", 5usize, |this| ((**(this as *const *const IWMDeviceManager_Vtbl)).EnumDevices)(this, ppenumdevice), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRevision, GetDeviceCount, EnumDevices }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDeviceManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDeviceManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceFromCanonicalName(this: *mut core::ffi::c_void, pwszcanonicalname: windows_core::PCWSTR, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDeviceManager2", "GetDeviceFromCanonicalName", 6usize, |this| ((**(this as *const *const IWMDeviceManager2_Vtbl)).GetDeviceFromCanonicalName)(this, pwszcanonicalname, ppdevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumDevices2(this: *mut core::ffi::c_void, ppenumdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDeviceManager2", "EnumDevices2", 7usize, |this| ((**(this as *const *const IWMDeviceManager2_Vtbl)).EnumDevices2)(this, ppenumdevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reinitialize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDeviceManager2", "Reinitialize", 8usize, |this| ((**(this as *const *const IWMDeviceManager2_Vtbl)).Reinitialize)(this), |result| Some(*result))
+        }
+        Self { base__: <IWMDeviceManager_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceFromCanonicalName, EnumDevices2, Reinitialize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDeviceManager2 as windows_core::Interface>::IID || <IWMDeviceManager_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDeviceManager3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDeviceEnumPreference(this: *mut core::ffi::c_void, dwenumpref: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDeviceManager3", "SetDeviceEnumPreference", 9usize, |this| ((**(this as *const *const IWMDeviceManager3_Vtbl)).SetDeviceEnumPreference)(this, dwenumpref), |result| Some(*result))
+        }
+        Self { base__: <IWMDeviceManager2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetDeviceEnumPreference }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDeviceManager3 as windows_core::Interface>::IID || <IWMDeviceManager2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
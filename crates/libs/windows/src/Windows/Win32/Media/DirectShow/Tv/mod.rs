@@ -15015,6 +15015,7 @@ pub struct BDA_DigitalSignalStandard(pub i32);
 impl windows_core::TypeKind for BDA_DigitalSignalStandard {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BDA_DigitalSignalStandard {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BDA_DigitalSignalStandard").field(&self.0).finish()
@@ -15026,6 +15027,7 @@ pub struct BDA_LockType(pub i32);
 impl windows_core::TypeKind for BDA_LockType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BDA_LockType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BDA_LockType").field(&self.0).finish()
@@ -15037,6 +15039,7 @@ pub struct BDA_SignalType(pub i32);
 impl windows_core::TypeKind for BDA_SignalType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BDA_SignalType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BDA_SignalType").field(&self.0).finish()
@@ -15048,6 +15051,7 @@ pub struct BfEnTvRat_Attributes_CAE_TV(pub i32);
 impl windows_core::TypeKind for BfEnTvRat_Attributes_CAE_TV {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BfEnTvRat_Attributes_CAE_TV {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BfEnTvRat_Attributes_CAE_TV").field(&self.0).finish()
@@ -15059,6 +15063,7 @@ pub struct BfEnTvRat_Attributes_CAF_TV(pub i32);
 impl windows_core::TypeKind for BfEnTvRat_Attributes_CAF_TV {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BfEnTvRat_Attributes_CAF_TV {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BfEnTvRat_Attributes_CAF_TV").field(&self.0).finish()
@@ -15070,6 +15075,7 @@ pub struct BfEnTvRat_Attributes_MPAA(pub i32);
 impl windows_core::TypeKind for BfEnTvRat_Attributes_MPAA {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BfEnTvRat_Attributes_MPAA {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BfEnTvRat_Attributes_MPAA").field(&self.0).finish()
@@ -15081,6 +15087,7 @@ pub struct BfEnTvRat_Attributes_US_TV(pub i32);
 impl windows_core::TypeKind for BfEnTvRat_Attributes_US_TV {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BfEnTvRat_Attributes_US_TV {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BfEnTvRat_Attributes_US_TV").field(&self.0).finish()
@@ -15092,6 +15099,7 @@ pub struct BfEnTvRat_GenericAttributes(pub i32);
 impl windows_core::TypeKind for BfEnTvRat_GenericAttributes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BfEnTvRat_GenericAttributes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BfEnTvRat_GenericAttributes").field(&self.0).finish()
@@ -15103,6 +15111,7 @@ pub struct COPPEventBlockReason(pub i32);
 impl windows_core::TypeKind for COPPEventBlockReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COPPEventBlockReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COPPEventBlockReason").field(&self.0).finish()
@@ -15114,6 +15123,7 @@ pub struct CPEventBitShift(pub i32);
 impl windows_core::TypeKind for CPEventBitShift {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CPEventBitShift {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CPEventBitShift").field(&self.0).finish()
@@ -15125,6 +15135,7 @@ pub struct CPEvents(pub i32);
 impl windows_core::TypeKind for CPEvents {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CPEvents {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CPEvents").field(&self.0).finish()
@@ -15136,6 +15147,7 @@ pub struct CPRecordingStatus(pub i32);
 impl windows_core::TypeKind for CPRecordingStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CPRecordingStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CPRecordingStatus").field(&self.0).finish()
@@ -15147,6 +15159,7 @@ pub struct CRID_LOCATION(pub i32);
 impl windows_core::TypeKind for CRID_LOCATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CRID_LOCATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CRID_LOCATION").field(&self.0).finish()
@@ -15158,6 +15171,7 @@ pub struct CROSSBAR_DEFAULT_FLAGS(pub i32);
 impl windows_core::TypeKind for CROSSBAR_DEFAULT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CROSSBAR_DEFAULT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CROSSBAR_DEFAULT_FLAGS").field(&self.0).finish()
@@ -15169,6 +15183,7 @@ pub struct ChannelChangeSpanningEvent_State(pub i32);
 impl windows_core::TypeKind for ChannelChangeSpanningEvent_State {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ChannelChangeSpanningEvent_State {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ChannelChangeSpanningEvent_State").field(&self.0).finish()
@@ -15180,6 +15195,7 @@ pub struct ChannelType(pub i32);
 impl windows_core::TypeKind for ChannelType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ChannelType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ChannelType").field(&self.0).finish()
@@ -15191,6 +15207,7 @@ pub struct DESC_LINKAGE_TYPE(pub i32);
 impl windows_core::TypeKind for DESC_LINKAGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DESC_LINKAGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DESC_LINKAGE_TYPE").field(&self.0).finish()
@@ -15202,6 +15219,7 @@ pub struct DISPID_TUNER(pub i32);
 impl windows_core::TypeKind for DISPID_TUNER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_TUNER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_TUNER").field(&self.0).finish()
@@ -15213,6 +15231,7 @@ pub struct DVB_STRCONV_MODE(pub i32);
 impl windows_core::TypeKind for DVB_STRCONV_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVB_STRCONV_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVB_STRCONV_MODE").field(&self.0).finish()
@@ -15224,6 +15243,7 @@ pub struct DVDFilterState(pub i32);
 impl windows_core::TypeKind for DVDFilterState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVDFilterState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVDFilterState").field(&self.0).finish()
@@ -15235,6 +15255,7 @@ pub struct DVDMenuIDConstants(pub i32);
 impl windows_core::TypeKind for DVDMenuIDConstants {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVDMenuIDConstants {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVDMenuIDConstants").field(&self.0).finish()
@@ -15246,6 +15267,7 @@ pub struct DVDSPExt(pub i32);
 impl windows_core::TypeKind for DVDSPExt {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVDSPExt {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVDSPExt").field(&self.0).finish()
@@ -15257,6 +15279,7 @@ pub struct DVDTextStringType(pub i32);
 impl windows_core::TypeKind for DVDTextStringType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVDTextStringType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVDTextStringType").field(&self.0).finish()
@@ -15268,6 +15291,7 @@ pub struct DisplaySizeList(pub i32);
 impl windows_core::TypeKind for DisplaySizeList {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplaySizeList {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplaySizeList").field(&self.0).finish()
@@ -15279,6 +15303,7 @@ pub struct DownResEventParam(pub i32);
 impl windows_core::TypeKind for DownResEventParam {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DownResEventParam {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DownResEventParam").field(&self.0).finish()
@@ -15290,6 +15315,7 @@ pub struct EnTag_Mode(pub i32);
 impl windows_core::TypeKind for EnTag_Mode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EnTag_Mode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EnTag_Mode").field(&self.0).finish()
@@ -15301,6 +15327,7 @@ pub struct EnTvRat_CAE_TV(pub i32);
 impl windows_core::TypeKind for EnTvRat_CAE_TV {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EnTvRat_CAE_TV {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EnTvRat_CAE_TV").field(&self.0).finish()
@@ -15312,6 +15339,7 @@ pub struct EnTvRat_CAF_TV(pub i32);
 impl windows_core::TypeKind for EnTvRat_CAF_TV {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EnTvRat_CAF_TV {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EnTvRat_CAF_TV").field(&self.0).finish()
@@ -15323,6 +15351,7 @@ pub struct EnTvRat_GenericLevel(pub i32);
 impl windows_core::TypeKind for EnTvRat_GenericLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EnTvRat_GenericLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EnTvRat_GenericLevel").field(&self.0).finish()
@@ -15334,6 +15363,7 @@ pub struct EnTvRat_MPAA(pub i32);
 impl windows_core::TypeKind for EnTvRat_MPAA {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EnTvRat_MPAA {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EnTvRat_MPAA").field(&self.0).finish()
@@ -15345,6 +15375,7 @@ pub struct EnTvRat_System(pub i32);
 impl windows_core::TypeKind for EnTvRat_System {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EnTvRat_System {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EnTvRat_System").field(&self.0).finish()
@@ -15356,6 +15387,7 @@ pub struct EnTvRat_US_TV(pub i32);
 impl windows_core::TypeKind for EnTvRat_US_TV {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EnTvRat_US_TV {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EnTvRat_US_TV").field(&self.0).finish()
@@ -15367,6 +15399,7 @@ pub struct EncDecEvents(pub i32);
 impl windows_core::TypeKind for EncDecEvents {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EncDecEvents {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EncDecEvents").field(&self.0).finish()
@@ -15378,6 +15411,7 @@ pub struct FormatNotSupportedEvents(pub i32);
 impl windows_core::TypeKind for FormatNotSupportedEvents {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FormatNotSupportedEvents {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FormatNotSupportedEvents").field(&self.0).finish()
@@ -15389,6 +15423,7 @@ pub struct KSEVENT_BDA_EVENT_TYPE(pub i32);
 impl windows_core::TypeKind for KSEVENT_BDA_EVENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSEVENT_BDA_EVENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSEVENT_BDA_EVENT_TYPE").field(&self.0).finish()
@@ -15400,6 +15435,7 @@ pub struct KSEVENT_BDA_TUNER(pub i32);
 impl windows_core::TypeKind for KSEVENT_BDA_TUNER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSEVENT_BDA_TUNER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSEVENT_BDA_TUNER").field(&self.0).finish()
@@ -15411,6 +15447,7 @@ pub struct KSMETHOD_BDA_CAS_SERVICE(pub i32);
 impl windows_core::TypeKind for KSMETHOD_BDA_CAS_SERVICE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSMETHOD_BDA_CAS_SERVICE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSMETHOD_BDA_CAS_SERVICE").field(&self.0).finish()
@@ -15422,6 +15459,7 @@ pub struct KSMETHOD_BDA_CHANGE_SYNC(pub i32);
 impl windows_core::TypeKind for KSMETHOD_BDA_CHANGE_SYNC {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSMETHOD_BDA_CHANGE_SYNC {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSMETHOD_BDA_CHANGE_SYNC").field(&self.0).finish()
@@ -15433,6 +15471,7 @@ pub struct KSMETHOD_BDA_DEBUG_SERVICE(pub i32);
 impl windows_core::TypeKind for KSMETHOD_BDA_DEBUG_SERVICE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSMETHOD_BDA_DEBUG_SERVICE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSMETHOD_BDA_DEBUG_SERVICE").field(&self.0).finish()
@@ -15444,6 +15483,7 @@ pub struct KSMETHOD_BDA_DEVICE_CONFIGURATION(pub i32);
 impl windows_core::TypeKind for KSMETHOD_BDA_DEVICE_CONFIGURATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSMETHOD_BDA_DEVICE_CONFIGURATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSMETHOD_BDA_DEVICE_CONFIGURATION").field(&self.0).finish()
@@ -15455,6 +15495,7 @@ pub struct KSMETHOD_BDA_DRM(pub i32);
 impl windows_core::TypeKind for KSMETHOD_BDA_DRM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSMETHOD_BDA_DRM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSMETHOD_BDA_DRM").field(&self.0).finish()
@@ -15466,6 +15507,7 @@ pub struct KSMETHOD_BDA_EVENTING_SERVICE(pub i32);
 impl windows_core::TypeKind for KSMETHOD_BDA_EVENTING_SERVICE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSMETHOD_BDA_EVENTING_SERVICE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSMETHOD_BDA_EVENTING_SERVICE").field(&self.0).finish()
@@ -15477,6 +15519,7 @@ pub struct KSMETHOD_BDA_GDDS_SERVICE(pub i32);
 impl windows_core::TypeKind for KSMETHOD_BDA_GDDS_SERVICE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSMETHOD_BDA_GDDS_SERVICE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSMETHOD_BDA_GDDS_SERVICE").field(&self.0).finish()
@@ -15488,6 +15531,7 @@ pub struct KSMETHOD_BDA_GPNV_SERVICE(pub i32);
 impl windows_core::TypeKind for KSMETHOD_BDA_GPNV_SERVICE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSMETHOD_BDA_GPNV_SERVICE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSMETHOD_BDA_GPNV_SERVICE").field(&self.0).finish()
@@ -15499,6 +15543,7 @@ pub struct KSMETHOD_BDA_ISDB_CAS(pub i32);
 impl windows_core::TypeKind for KSMETHOD_BDA_ISDB_CAS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSMETHOD_BDA_ISDB_CAS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSMETHOD_BDA_ISDB_CAS").field(&self.0).finish()
@@ -15510,6 +15555,7 @@ pub struct KSMETHOD_BDA_MUX_SERVICE(pub i32);
 impl windows_core::TypeKind for KSMETHOD_BDA_MUX_SERVICE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSMETHOD_BDA_MUX_SERVICE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSMETHOD_BDA_MUX_SERVICE").field(&self.0).finish()
@@ -15521,6 +15567,7 @@ pub struct KSMETHOD_BDA_SCAN_SERVICE(pub i32);
 impl windows_core::TypeKind for KSMETHOD_BDA_SCAN_SERVICE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSMETHOD_BDA_SCAN_SERVICE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSMETHOD_BDA_SCAN_SERVICE").field(&self.0).finish()
@@ -15532,6 +15579,7 @@ pub struct KSMETHOD_BDA_TS_SELECTOR(pub i32);
 impl windows_core::TypeKind for KSMETHOD_BDA_TS_SELECTOR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSMETHOD_BDA_TS_SELECTOR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSMETHOD_BDA_TS_SELECTOR").field(&self.0).finish()
@@ -15543,6 +15591,7 @@ pub struct KSMETHOD_BDA_TUNER_SERVICE(pub i32);
 impl windows_core::TypeKind for KSMETHOD_BDA_TUNER_SERVICE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSMETHOD_BDA_TUNER_SERVICE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSMETHOD_BDA_TUNER_SERVICE").field(&self.0).finish()
@@ -15554,6 +15603,7 @@ pub struct KSMETHOD_BDA_USERACTIVITY_SERVICE(pub i32);
 impl windows_core::TypeKind for KSMETHOD_BDA_USERACTIVITY_SERVICE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSMETHOD_BDA_USERACTIVITY_SERVICE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSMETHOD_BDA_USERACTIVITY_SERVICE").field(&self.0).finish()
@@ -15565,6 +15615,7 @@ pub struct KSMETHOD_BDA_WMDRM(pub i32);
 impl windows_core::TypeKind for KSMETHOD_BDA_WMDRM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSMETHOD_BDA_WMDRM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSMETHOD_BDA_WMDRM").field(&self.0).finish()
@@ -15576,6 +15627,7 @@ pub struct KSMETHOD_BDA_WMDRM_TUNER(pub i32);
 impl windows_core::TypeKind for KSMETHOD_BDA_WMDRM_TUNER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSMETHOD_BDA_WMDRM_TUNER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSMETHOD_BDA_WMDRM_TUNER").field(&self.0).finish()
@@ -15587,6 +15639,7 @@ pub struct KSPROPERTY_BDA_AUTODEMODULATE(pub i32);
 impl windows_core::TypeKind for KSPROPERTY_BDA_AUTODEMODULATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSPROPERTY_BDA_AUTODEMODULATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSPROPERTY_BDA_AUTODEMODULATE").field(&self.0).finish()
@@ -15598,6 +15651,7 @@ pub struct KSPROPERTY_BDA_CA(pub i32);
 impl windows_core::TypeKind for KSPROPERTY_BDA_CA {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSPROPERTY_BDA_CA {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSPROPERTY_BDA_CA").field(&self.0).finish()
@@ -15609,6 +15663,7 @@ pub struct KSPROPERTY_BDA_CA_EVENT(pub i32);
 impl windows_core::TypeKind for KSPROPERTY_BDA_CA_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSPROPERTY_BDA_CA_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSPROPERTY_BDA_CA_EVENT").field(&self.0).finish()
@@ -15620,6 +15675,7 @@ pub struct KSPROPERTY_BDA_DIGITAL_DEMODULATOR(pub i32);
 impl windows_core::TypeKind for KSPROPERTY_BDA_DIGITAL_DEMODULATOR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSPROPERTY_BDA_DIGITAL_DEMODULATOR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSPROPERTY_BDA_DIGITAL_DEMODULATOR").field(&self.0).finish()
@@ -15631,6 +15687,7 @@ pub struct KSPROPERTY_BDA_DISEQC_COMMAND(pub i32);
 impl windows_core::TypeKind for KSPROPERTY_BDA_DISEQC_COMMAND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSPROPERTY_BDA_DISEQC_COMMAND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSPROPERTY_BDA_DISEQC_COMMAND").field(&self.0).finish()
@@ -15642,6 +15699,7 @@ pub struct KSPROPERTY_BDA_DISEQC_EVENT(pub i32);
 impl windows_core::TypeKind for KSPROPERTY_BDA_DISEQC_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSPROPERTY_BDA_DISEQC_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSPROPERTY_BDA_DISEQC_EVENT").field(&self.0).finish()
@@ -15653,6 +15711,7 @@ pub struct KSPROPERTY_BDA_ETHERNET_FILTER(pub i32);
 impl windows_core::TypeKind for KSPROPERTY_BDA_ETHERNET_FILTER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSPROPERTY_BDA_ETHERNET_FILTER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSPROPERTY_BDA_ETHERNET_FILTER").field(&self.0).finish()
@@ -15664,6 +15723,7 @@ pub struct KSPROPERTY_BDA_FREQUENCY_FILTER(pub i32);
 impl windows_core::TypeKind for KSPROPERTY_BDA_FREQUENCY_FILTER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSPROPERTY_BDA_FREQUENCY_FILTER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSPROPERTY_BDA_FREQUENCY_FILTER").field(&self.0).finish()
@@ -15675,6 +15735,7 @@ pub struct KSPROPERTY_BDA_IPv4_FILTER(pub i32);
 impl windows_core::TypeKind for KSPROPERTY_BDA_IPv4_FILTER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSPROPERTY_BDA_IPv4_FILTER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSPROPERTY_BDA_IPv4_FILTER").field(&self.0).finish()
@@ -15686,6 +15747,7 @@ pub struct KSPROPERTY_BDA_IPv6_FILTER(pub i32);
 impl windows_core::TypeKind for KSPROPERTY_BDA_IPv6_FILTER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSPROPERTY_BDA_IPv6_FILTER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSPROPERTY_BDA_IPv6_FILTER").field(&self.0).finish()
@@ -15697,6 +15759,7 @@ pub struct KSPROPERTY_BDA_LNB_INFO(pub i32);
 impl windows_core::TypeKind for KSPROPERTY_BDA_LNB_INFO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSPROPERTY_BDA_LNB_INFO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSPROPERTY_BDA_LNB_INFO").field(&self.0).finish()
@@ -15708,6 +15771,7 @@ pub struct KSPROPERTY_BDA_NULL_TRANSFORM(pub i32);
 impl windows_core::TypeKind for KSPROPERTY_BDA_NULL_TRANSFORM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSPROPERTY_BDA_NULL_TRANSFORM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSPROPERTY_BDA_NULL_TRANSFORM").field(&self.0).finish()
@@ -15719,6 +15783,7 @@ pub struct KSPROPERTY_BDA_PIDFILTER(pub i32);
 impl windows_core::TypeKind for KSPROPERTY_BDA_PIDFILTER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSPROPERTY_BDA_PIDFILTER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSPROPERTY_BDA_PIDFILTER").field(&self.0).finish()
@@ -15730,6 +15795,7 @@ pub struct KSPROPERTY_BDA_PIN_CONTROL(pub i32);
 impl windows_core::TypeKind for KSPROPERTY_BDA_PIN_CONTROL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSPROPERTY_BDA_PIN_CONTROL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSPROPERTY_BDA_PIN_CONTROL").field(&self.0).finish()
@@ -15741,6 +15807,7 @@ pub struct KSPROPERTY_BDA_PIN_EVENT(pub i32);
 impl windows_core::TypeKind for KSPROPERTY_BDA_PIN_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSPROPERTY_BDA_PIN_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSPROPERTY_BDA_PIN_EVENT").field(&self.0).finish()
@@ -15752,6 +15819,7 @@ pub struct KSPROPERTY_BDA_SIGNAL_STATS(pub i32);
 impl windows_core::TypeKind for KSPROPERTY_BDA_SIGNAL_STATS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSPROPERTY_BDA_SIGNAL_STATS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSPROPERTY_BDA_SIGNAL_STATS").field(&self.0).finish()
@@ -15763,6 +15831,7 @@ pub struct KSPROPERTY_BDA_TOPOLOGY(pub i32);
 impl windows_core::TypeKind for KSPROPERTY_BDA_TOPOLOGY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSPROPERTY_BDA_TOPOLOGY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSPROPERTY_BDA_TOPOLOGY").field(&self.0).finish()
@@ -15774,6 +15843,7 @@ pub struct KSPROPERTY_BDA_VOID_TRANSFORM(pub i32);
 impl windows_core::TypeKind for KSPROPERTY_BDA_VOID_TRANSFORM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSPROPERTY_BDA_VOID_TRANSFORM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSPROPERTY_BDA_VOID_TRANSFORM").field(&self.0).finish()
@@ -15785,6 +15855,7 @@ pub struct KSPROPERTY_IDS_BDA_TABLE(pub i32);
 impl windows_core::TypeKind for KSPROPERTY_IDS_BDA_TABLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSPROPERTY_IDS_BDA_TABLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSPROPERTY_IDS_BDA_TABLE").field(&self.0).finish()
@@ -15796,6 +15867,7 @@ pub struct LicenseEventBlockReason(pub i32);
 impl windows_core::TypeKind for LicenseEventBlockReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LicenseEventBlockReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LicenseEventBlockReason").field(&self.0).finish()
@@ -15807,6 +15879,7 @@ pub struct MPEG_CONTEXT_TYPE(pub i32);
 impl windows_core::TypeKind for MPEG_CONTEXT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MPEG_CONTEXT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MPEG_CONTEXT_TYPE").field(&self.0).finish()
@@ -15818,6 +15891,7 @@ pub struct MPEG_CURRENT_NEXT_BIT(pub i32);
 impl windows_core::TypeKind for MPEG_CURRENT_NEXT_BIT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MPEG_CURRENT_NEXT_BIT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MPEG_CURRENT_NEXT_BIT").field(&self.0).finish()
@@ -15829,6 +15903,7 @@ pub struct MPEG_REQUEST_TYPE(pub i32);
 impl windows_core::TypeKind for MPEG_REQUEST_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MPEG_REQUEST_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MPEG_REQUEST_TYPE").field(&self.0).finish()
@@ -15848,6 +15923,7 @@ impl MSVidCCService {
 impl windows_core::TypeKind for MSVidCCService {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSVidCCService {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSVidCCService").field(&self.0).finish()
@@ -15859,6 +15935,7 @@ pub struct MSVidCtlButtonstate(pub i32);
 impl windows_core::TypeKind for MSVidCtlButtonstate {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSVidCtlButtonstate {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSVidCtlButtonstate").field(&self.0).finish()
@@ -15870,6 +15947,7 @@ pub struct MSVidCtlStateList(pub i32);
 impl windows_core::TypeKind for MSVidCtlStateList {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSVidCtlStateList {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSVidCtlStateList").field(&self.0).finish()
@@ -15881,6 +15959,7 @@ pub struct MSVidSegmentType(pub i32);
 impl windows_core::TypeKind for MSVidSegmentType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSVidSegmentType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSVidSegmentType").field(&self.0).finish()
@@ -15892,6 +15971,7 @@ pub struct MSVidSinkStreams(pub i32);
 impl windows_core::TypeKind for MSVidSinkStreams {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSVidSinkStreams {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSVidSinkStreams").field(&self.0).finish()
@@ -15903,6 +15983,7 @@ pub struct MSViddispidList(pub i32);
 impl windows_core::TypeKind for MSViddispidList {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSViddispidList {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSViddispidList").field(&self.0).finish()
@@ -15914,6 +15995,7 @@ pub struct PositionModeList(pub i32);
 impl windows_core::TypeKind for PositionModeList {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PositionModeList {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PositionModeList").field(&self.0).finish()
@@ -15925,6 +16007,7 @@ pub struct ProtType(pub i32);
 impl windows_core::TypeKind for ProtType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ProtType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ProtType").field(&self.0).finish()
@@ -15936,6 +16019,7 @@ pub struct RECORDING_TYPE(pub i32);
 impl windows_core::TypeKind for RECORDING_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RECORDING_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RECORDING_TYPE").field(&self.0).finish()
@@ -15947,6 +16031,7 @@ pub struct RecordingType(pub i32);
 impl windows_core::TypeKind for RecordingType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RecordingType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RecordingType").field(&self.0).finish()
@@ -15958,6 +16043,7 @@ pub struct RevokedComponent(pub i32);
 impl windows_core::TypeKind for RevokedComponent {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RevokedComponent {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RevokedComponent").field(&self.0).finish()
@@ -15969,6 +16055,7 @@ pub struct STREAMBUFFER_ATTR_DATATYPE(pub i32);
 impl windows_core::TypeKind for STREAMBUFFER_ATTR_DATATYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STREAMBUFFER_ATTR_DATATYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STREAMBUFFER_ATTR_DATATYPE").field(&self.0).finish()
@@ -15980,6 +16067,7 @@ pub struct SegDispidList(pub i32);
 impl windows_core::TypeKind for SegDispidList {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SegDispidList {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SegDispidList").field(&self.0).finish()
@@ -15991,6 +16079,7 @@ pub struct SegEventidList(pub i32);
 impl windows_core::TypeKind for SegEventidList {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SegEventidList {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SegEventidList").field(&self.0).finish()
@@ -16002,6 +16091,7 @@ pub struct SignalAndServiceStatusSpanningEvent_State(pub i32);
 impl windows_core::TypeKind for SignalAndServiceStatusSpanningEvent_State {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SignalAndServiceStatusSpanningEvent_State {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SignalAndServiceStatusSpanningEvent_State").field(&self.0).finish()
@@ -16013,6 +16103,7 @@ pub struct SourceSizeList(pub i32);
 impl windows_core::TypeKind for SourceSizeList {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SourceSizeList {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SourceSizeList").field(&self.0).finish()
@@ -16024,6 +16115,7 @@ pub struct VA_COLOR_PRIMARIES(pub i32);
 impl windows_core::TypeKind for VA_COLOR_PRIMARIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VA_COLOR_PRIMARIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VA_COLOR_PRIMARIES").field(&self.0).finish()
@@ -16035,6 +16127,7 @@ pub struct VA_MATRIX_COEFFICIENTS(pub i32);
 impl windows_core::TypeKind for VA_MATRIX_COEFFICIENTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VA_MATRIX_COEFFICIENTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VA_MATRIX_COEFFICIENTS").field(&self.0).finish()
@@ -16046,6 +16139,7 @@ pub struct VA_TRANSFER_CHARACTERISTICS(pub i32);
 impl windows_core::TypeKind for VA_TRANSFER_CHARACTERISTICS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VA_TRANSFER_CHARACTERISTICS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VA_TRANSFER_CHARACTERISTICS").field(&self.0).finish()
@@ -16057,6 +16151,7 @@ pub struct VA_VIDEO_FORMAT(pub i32);
 impl windows_core::TypeKind for VA_VIDEO_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VA_VIDEO_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VA_VIDEO_FORMAT").field(&self.0).finish()
@@ -16091,7 +16186,8 @@ pub const AnalogTVTuningSpace: windows_core::GUID = windows_core::GUID::from_u12
 pub const AuxInTuningSpace: windows_core::GUID = windows_core::GUID::from_u128(0xf9769a06_7aca_4e39_9cfb_97bb35f0e77e);
 pub const BDANETWORKTYPE_ATSC: windows_core::GUID = windows_core::GUID::from_u128(0x71985f51_1ca1_11d3_9cc8_00c04f7971e0);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_DEBUG_DATA {
     pub lResult: i32,
     pub uuidDebugDataType: windows_core::GUID,
@@ -16109,7 +16205,8 @@ impl Default for BDA_DEBUG_DATA {
 pub const BDA_DEBUG_DATA_AVAILABLE: windows_core::GUID = windows_core::GUID::from_u128(0x69c24f54_9983_497e_b415_282be4c555fb);
 pub const BDA_DEBUG_DATA_TYPE_STRING: windows_core::GUID = windows_core::GUID::from_u128(0xa806e767_de5c_430c_80bf_a21ebe06c748);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_EVENT_DATA {
     pub lResult: i32,
     pub ulEventID: u32,
@@ -16126,7 +16223,8 @@ impl Default for BDA_EVENT_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_TRANSPORT_INFO {
     pub ulcbPhyiscalPacket: u32,
     pub ulcbPhyiscalFrame: u32,
@@ -16157,7 +16255,8 @@ impl Default for BadSampleInfo {
 }
 pub const BroadcastEventService: windows_core::GUID = windows_core::GUID::from_u128(0x0b3ffb92_0919_4934_9d5b_619c719d0202);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CAPTURE_STREAMTIME {
     pub StreamTime: i64,
 }
@@ -16171,7 +16270,8 @@ impl Default for CAPTURE_STREAMTIME {
 }
 pub const CXDSData: windows_core::GUID = windows_core::GUID::from_u128(0xc4c4c4f4_0049_4e2b_98fb_9537f6ce516d);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ChannelChangeInfo {
     pub state: ChannelChangeSpanningEvent_State,
     pub TimeStamp: u64,
@@ -16216,7 +16316,8 @@ impl Default for ChannelInfo_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ChannelInfo_0_0 {
     pub lProgNumber: i32,
 }
@@ -16229,7 +16330,8 @@ impl Default for ChannelInfo_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ChannelInfo_0_1 {
     pub lProgNumber: i32,
 }
@@ -16242,7 +16344,8 @@ impl Default for ChannelInfo_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ChannelInfo_0_2 {
     pub lONID: i32,
     pub lTSID: i32,
@@ -16258,7 +16361,8 @@ impl Default for ChannelInfo_0_2 {
 }
 pub const ChannelTuneRequest: windows_core::GUID = windows_core::GUID::from_u128(0x0369b4e5_45b6_11d3_b650_00c04f79498e);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ChannelTypeInfo {
     pub channelType: ChannelType,
     pub timeStamp: u64,
@@ -16279,7 +16383,8 @@ pub const CreatePropBagOnRegKey: windows_core::GUID = windows_core::GUID::from_u
 pub const DIGITAL_CABLE_NETWORK_TYPE: windows_core::GUID = windows_core::GUID::from_u128(0x143827ab_f77b_498d_81ca_5a007aec28bf);
 pub const DIRECT_TV_SATELLITE_TV_NETWORK_TYPE: windows_core::GUID = windows_core::GUID::from_u128(0x93b66fb5_93d4_4323_921c_c1f52df61d3f);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DSHOW_STREAM_DESC {
     pub VersionNo: u32,
     pub StreamId: u32,
@@ -16398,7 +16503,8 @@ pub const DVBCLocator: windows_core::GUID = windows_core::GUID::from_u128(0xc531
 pub const DVBSLocator: windows_core::GUID = windows_core::GUID::from_u128(0x1df7d126_4050_47f0_a7cf_4c4ca9241333);
 pub const DVBSTuningSpace: windows_core::GUID = windows_core::GUID::from_u128(0xb64016f3_c9a2_4066_96f0_bd9563314726);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DVBScramblingControlSpanningEvent {
     pub ulPID: u32,
     pub fScrambled: super::super::super::Foundation::BOOL,
@@ -16434,7 +16540,8 @@ pub const DVB_SATELLITE_TV_NETWORK_TYPE: windows_core::GUID = windows_core::GUID
 pub const DVB_TERRESTRIAL_TV_NETWORK_TYPE: windows_core::GUID = windows_core::GUID::from_u128(0x216c62df_6d7f_4e9a_8571_05f14edb766a);
 #[repr(C)]
 #[cfg(feature = "Win32_Media_MediaFoundation")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DVR_STREAM_DESC {
     pub Version: u32,
     pub StreamId: u32,
@@ -16466,7 +16573,8 @@ pub const DigitalCableTuneRequest: windows_core::GUID = windows_core::GUID::from
 pub const DigitalCableTuningSpace: windows_core::GUID = windows_core::GUID::from_u128(0xd9bb4cee_b87a_47f1_ac92_b08d9c7813fc);
 pub const DigitalLocator: windows_core::GUID = windows_core::GUID::from_u128(0x6e50cc0d_c19b_4bf6_810b_5bd60761f5cc);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DualMonoInfo {
     pub LangID1: u16,
     pub LangID2: u16,
@@ -16482,7 +16590,8 @@ impl Default for DualMonoInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DvbParentalRatingDescriptor {
     pub ulNumParams: u32,
     pub pParams: [DvbParentalRatingParam; 1],
@@ -16496,7 +16605,8 @@ impl Default for DvbParentalRatingDescriptor {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DvbParentalRatingParam {
     pub szCountryCode: [i8; 4],
     pub bRating: u8,
@@ -17447,7 +17557,8 @@ impl Default for LONG_SECTION_1 {
 }
 pub const LanguageComponentType: windows_core::GUID = windows_core::GUID::from_u128(0x1be49f30_0e1b_11d3_9d8e_00c04f72d980);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LanguageInfo {
     pub LangID: u16,
     pub lISOLangCode: i32,
@@ -17627,6 +17738,32 @@ impl Default for MPEG_DATE_AND_TIME {
 pub struct MPEG_HEADER_BITS {
     pub _bitfield: u16,
 }
+impl MPEG_HEADER_BITS {
+    pub const fn SectionLength(&self) -> u16 {
+        self._bitfield & 4095
+    }
+    pub fn set_SectionLength(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !4095) | (value & 4095);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 12) & 3
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 12)) | ((value & 3) << 12);
+    }
+    pub const fn PrivateIndicator(&self) -> u16 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_PrivateIndicator(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn SectionSyntaxIndicator(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_SectionSyntaxIndicator(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for MPEG_HEADER_BITS {
     type TypeKind = windows_core::CopyType;
 }
@@ -17649,10 +17786,31 @@ impl Default for MPEG_HEADER_BITS_MIDL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MPEG_HEADER_VERSION_BITS {
     pub _bitfield: u8,
 }
+impl MPEG_HEADER_VERSION_BITS {
+    pub const fn CurrentNextIndicator(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_CurrentNextIndicator(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn VersionNumber(&self) -> u8 {
+        (self._bitfield >> 1) & 31
+    }
+    pub fn set_VersionNumber(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(31 << 1)) | ((value & 31) << 1);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+}
 impl windows_core::TypeKind for MPEG_HEADER_VERSION_BITS {
     type TypeKind = windows_core::CopyType;
 }
@@ -17662,7 +17820,8 @@ impl Default for MPEG_HEADER_VERSION_BITS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MPEG_HEADER_VERSION_BITS_MIDL {
     pub Bits: u8,
 }
@@ -17907,7 +18066,8 @@ impl Default for PBDAParentalControl {
 }
 pub const PBDA_ALWAYS_TUNE_IN_MUX: windows_core::GUID = windows_core::GUID::from_u128(0x1e1d7141_583f_4ac2_b019_1f430eda0f4c);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PBDA_TAG_ATTRIBUTE {
     pub TableUUId: windows_core::GUID,
     pub TableId: u8,
@@ -17924,10 +18084,49 @@ impl Default for PBDA_TAG_ATTRIBUTE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PIC_SEQ_SAMPLE {
     pub _bitfield: u32,
 }
+impl PIC_SEQ_SAMPLE {
+    pub const fn Content(&self) -> u32 {
+        self._bitfield & 7
+    }
+    pub fn set_Content(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn Start(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_Start(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn FrameRate(&self) -> u32 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_FrameRate(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+    pub const fn InvalidVA(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_InvalidVA(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 9) & 524287
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(524287 << 9)) | ((value & 524287) << 9);
+    }
+    pub const fn Version(&self) -> u32 {
+        (self._bitfield >> 28) & 15
+    }
+    pub fn set_Version(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 28)) | ((value & 15) << 28);
+    }
+}
 impl windows_core::TypeKind for PIC_SEQ_SAMPLE {
     type TypeKind = windows_core::CopyType;
 }
@@ -17937,7 +18136,8 @@ impl Default for PIC_SEQ_SAMPLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PIDListSpanningEvent {
     pub wPIDCount: u16,
     pub pulPIDs: [u32; 1],
@@ -17955,6 +18155,20 @@ impl Default for PIDListSpanningEvent {
 pub struct PID_BITS {
     pub _bitfield: u16,
 }
+impl PID_BITS {
+    pub const fn Reserved(&self) -> u16 {
+        self._bitfield & 7
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn ProgramId(&self) -> u16 {
+        (self._bitfield >> 3) & 8191
+    }
+    pub fn set_ProgramId(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(8191 << 3)) | ((value & 8191) << 3);
+    }
+}
 impl windows_core::TypeKind for PID_BITS {
     type TypeKind = windows_core::CopyType;
 }
@@ -17986,7 +18200,8 @@ pub const PINNAME_IPSINK_INPUT: windows_core::GUID = windows_core::GUID::from_u1
 pub const PINNAME_MPE: windows_core::GUID = windows_core::GUID::from_u128(0xc1b06d73_1dbb_11d3_8f46_00c04f7971e2);
 pub const PersistTuneXmlUtility: windows_core::GUID = windows_core::GUID::from_u128(0xe77026b0_b97f_4cbb_b7fb_f4f03ad69f11);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ProgramElement {
     pub wProgramNumber: u16,
     pub wProgramMapPID: u16,
@@ -18036,6 +18251,20 @@ pub struct RATING_SYSTEM {
     pub rating_attribute_count: u32,
     pub lpratingattrib: *mut RATING_ATTRIBUTE,
 }
+impl RATING_SYSTEM {
+    pub const fn rating_system_is_age_type(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_rating_system_is_age_type(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn reserved(&self) -> u8 {
+        (self._bitfield >> 1) & 127
+    }
+    pub fn set_reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(127 << 1)) | ((value & 127) << 1);
+    }
+}
 impl windows_core::TypeKind for RATING_SYSTEM {
     type TypeKind = windows_core::CopyType;
 }
@@ -18045,7 +18274,8 @@ impl Default for RATING_SYSTEM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SAMPLE_LIVE_STREAM_TIME {
     pub qwStreamTime: u64,
     pub qwLiveTime: u64,
@@ -18059,10 +18289,31 @@ impl Default for SAMPLE_LIVE_STREAM_TIME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SAMPLE_SEQ_OFFSET {
     pub _bitfield: u32,
 }
+impl SAMPLE_SEQ_OFFSET {
+    pub const fn Type(&self) -> u32 {
+        self._bitfield & 15
+    }
+    pub fn set_Type(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn Offset(&self) -> u32 {
+        (self._bitfield >> 4) & 1048575
+    }
+    pub fn set_Offset(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1048575 << 4)) | ((value & 1048575) << 4);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for SAMPLE_SEQ_OFFSET {
     type TypeKind = windows_core::CopyType;
 }
@@ -18072,7 +18323,8 @@ impl Default for SAMPLE_SEQ_OFFSET {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SBE2_STREAM_DESC {
     pub Version: u32,
     pub StreamId: u32,
@@ -18088,7 +18340,8 @@ impl Default for SBE2_STREAM_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SBE_PIN_DATA {
     pub cDataBytes: u64,
     pub cSamplesProcessed: u64,
@@ -18134,7 +18387,8 @@ impl Default for SECTION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STREAMBUFFER_ATTRIBUTE {
     pub pszName: windows_core::PWSTR,
     pub StreamBufferAttributeType: STREAMBUFFER_ATTR_DATATYPE,
@@ -18151,7 +18405,8 @@ impl Default for STREAMBUFFER_ATTRIBUTE {
 }
 pub const SectionList: windows_core::GUID = windows_core::GUID::from_u128(0x73da5d04_4347_45d3_a9dc_fae9ddbe558d);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SpanningEventDescriptor {
     pub wDataLen: u16,
     pub wProgNumber: u16,
@@ -18167,7 +18422,8 @@ impl Default for SpanningEventDescriptor {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SpanningEventEmmMessage {
     pub bCAbroadcasterGroupId: u8,
     pub bMessageControl: u8,
@@ -18237,10 +18493,25 @@ impl Default for TRANSPORT_PROPERTIES_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TRANSPORT_PROPERTIES_0_0 {
     pub _bitfield: i64,
 }
+impl TRANSPORT_PROPERTIES_0_0 {
+    pub const fn TransportScramblingControl(&self) -> i64 {
+        self._bitfield & 255
+    }
+    pub fn set_TransportScramblingControl(&mut self, value: i64) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn Reserved(&self) -> i64 {
+        (self._bitfield >> 8) & 72057594037927935
+    }
+    pub fn set_Reserved(&mut self, value: i64) {
+        self._bitfield = (self._bitfield & !(72057594037927935 << 8)) | ((value & 72057594037927935) << 8);
+    }
+}
 impl windows_core::TypeKind for TRANSPORT_PROPERTIES_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -18253,7 +18524,8 @@ pub const TuneRequest: windows_core::GUID = windows_core::GUID::from_u128(0xb46e
 pub const TunerMarshaler: windows_core::GUID = windows_core::GUID::from_u128(0x6438570b_0c08_4a25_9504_8012bb4d50cf);
 pub const TuningSpace: windows_core::GUID = windows_core::GUID::from_u128(0x5ffdc5e6_b83a_4b55_b6e8_c69e765fe9db);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UDCR_TAG {
     pub bVersion: u8,
     pub KID: [u8; 25],
@@ -18273,7 +18545,8 @@ impl Default for UDCR_TAG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VA_OPTIONAL_VIDEO_PROPERTIES {
     pub dwPictureHeight: u16,
     pub dwPictureWidth: u16,
@@ -18312,3 +18585,5 @@ pub const XDSCodec: windows_core::GUID = windows_core::GUID::from_u128(0xc4c4c4f
 pub const XDSToRat: windows_core::GUID = windows_core::GUID::from_u128(0xc5c5c5f0_3abc_11d6_b25b_00c04fa0c026);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
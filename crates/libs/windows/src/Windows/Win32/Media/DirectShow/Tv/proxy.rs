@@ -0,0 +1,10118 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IATSCChannelTuneRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MinorChannel(this: *mut core::ffi::c_void, minorchannel: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSCChannelTuneRequest", "MinorChannel", 14usize, |this| ((**(this as *const *const IATSCChannelTuneRequest_Vtbl)).MinorChannel)(this, minorchannel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinorChannel(this: *mut core::ffi::c_void, minorchannel: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSCChannelTuneRequest", "SetMinorChannel", 15usize, |this| ((**(this as *const *const IATSCChannelTuneRequest_Vtbl)).SetMinorChannel)(this, minorchannel), |result| Some(*result))
+        }
+        Self { base__: <IChannelTuneRequest_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MinorChannel, SetMinorChannel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IATSCChannelTuneRequest as windows_core::Interface>::IID || <IChannelTuneRequest_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Media_MediaFoundation", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IATSCComponentType_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Flags(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSCComponentType", "Flags", 28usize, |this| ((**(this as *const *const IATSCComponentType_Vtbl)).Flags)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFlags(this: *mut core::ffi::c_void, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSCComponentType", "SetFlags", 29usize, |this| ((**(this as *const *const IATSCComponentType_Vtbl)).SetFlags)(this, flags), |result| Some(*result))
+        }
+        Self { base__: <IMPEG2ComponentType_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Flags, SetFlags }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IATSCComponentType as windows_core::Interface>::IID || <IMPEG2ComponentType_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IATSCLocator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PhysicalChannel(this: *mut core::ffi::c_void, physicalchannel: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSCLocator", "PhysicalChannel", 22usize, |this| ((**(this as *const *const IATSCLocator_Vtbl)).PhysicalChannel)(this, physicalchannel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPhysicalChannel(this: *mut core::ffi::c_void, physicalchannel: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSCLocator", "SetPhysicalChannel", 23usize, |this| ((**(this as *const *const IATSCLocator_Vtbl)).SetPhysicalChannel)(this, physicalchannel), |result| Some(*result))
+        }
+        unsafe extern "system" fn TSID(this: *mut core::ffi::c_void, tsid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSCLocator", "TSID", 24usize, |this| ((**(this as *const *const IATSCLocator_Vtbl)).TSID)(this, tsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTSID(this: *mut core::ffi::c_void, tsid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSCLocator", "SetTSID", 25usize, |this| ((**(this as *const *const IATSCLocator_Vtbl)).SetTSID)(this, tsid), |result| Some(*result))
+        }
+        Self { base__: <IDigitalLocator_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PhysicalChannel, SetPhysicalChannel, TSID, SetTSID }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IATSCLocator as windows_core::Interface>::IID || <IDigitalLocator_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IATSCLocator2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProgramNumber(this: *mut core::ffi::c_void, programnumber: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSCLocator2", "ProgramNumber", 26usize, |this| ((**(this as *const *const IATSCLocator2_Vtbl)).ProgramNumber)(this, programnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProgramNumber(this: *mut core::ffi::c_void, programnumber: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSCLocator2", "SetProgramNumber", 27usize, |this| ((**(this as *const *const IATSCLocator2_Vtbl)).SetProgramNumber)(this, programnumber), |result| Some(*result))
+        }
+        Self { base__: <IATSCLocator_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ProgramNumber, SetProgramNumber }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IATSCLocator2 as windows_core::Interface>::IID || <IATSCLocator_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IATSCTuningSpace_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MinMinorChannel(this: *mut core::ffi::c_void, minminorchannelval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSCTuningSpace", "MinMinorChannel", 34usize, |this| ((**(this as *const *const IATSCTuningSpace_Vtbl)).MinMinorChannel)(this, minminorchannelval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinMinorChannel(this: *mut core::ffi::c_void, newminminorchannelval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSCTuningSpace", "SetMinMinorChannel", 35usize, |this| ((**(this as *const *const IATSCTuningSpace_Vtbl)).SetMinMinorChannel)(this, newminminorchannelval), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxMinorChannel(this: *mut core::ffi::c_void, maxminorchannelval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSCTuningSpace", "MaxMinorChannel", 36usize, |this| ((**(this as *const *const IATSCTuningSpace_Vtbl)).MaxMinorChannel)(this, maxminorchannelval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxMinorChannel(this: *mut core::ffi::c_void, newmaxminorchannelval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSCTuningSpace", "SetMaxMinorChannel", 37usize, |this| ((**(this as *const *const IATSCTuningSpace_Vtbl)).SetMaxMinorChannel)(this, newmaxminorchannelval), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinPhysicalChannel(this: *mut core::ffi::c_void, minphysicalchannelval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSCTuningSpace", "MinPhysicalChannel", 38usize, |this| ((**(this as *const *const IATSCTuningSpace_Vtbl)).MinPhysicalChannel)(this, minphysicalchannelval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinPhysicalChannel(this: *mut core::ffi::c_void, newminphysicalchannelval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSCTuningSpace", "SetMinPhysicalChannel", 39usize, |this| ((**(this as *const *const IATSCTuningSpace_Vtbl)).SetMinPhysicalChannel)(this, newminphysicalchannelval), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxPhysicalChannel(this: *mut core::ffi::c_void, maxphysicalchannelval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSCTuningSpace", "MaxPhysicalChannel", 40usize, |this| ((**(this as *const *const IATSCTuningSpace_Vtbl)).MaxPhysicalChannel)(this, maxphysicalchannelval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxPhysicalChannel(this: *mut core::ffi::c_void, newmaxphysicalchannelval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSCTuningSpace", "SetMaxPhysicalChannel", 41usize, |this| ((**(this as *const *const IATSCTuningSpace_Vtbl)).SetMaxPhysicalChannel)(this, newmaxphysicalchannelval), |result| Some(*result))
+        }
+        Self {
+            base__: <IAnalogTVTuningSpace_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MinMinorChannel,
+            SetMinMinorChannel,
+            MaxMinorChannel,
+            SetMaxMinorChannel,
+            MinPhysicalChannel,
+            SetMinPhysicalChannel,
+            MaxPhysicalChannel,
+            SetMaxPhysicalChannel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IATSCTuningSpace as windows_core::Interface>::IID || <IAnalogTVTuningSpace_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IATSC_EIT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psectionlist: *mut core::ffi::c_void, pmpegdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_EIT", "Initialize", 3usize, |this| ((**(this as *const *const IATSC_EIT_Vtbl)).Initialize)(this, psectionlist, pmpegdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionNumber(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_EIT", "GetVersionNumber", 4usize, |this| ((**(this as *const *const IATSC_EIT_Vtbl)).GetVersionNumber)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourceId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_EIT", "GetSourceId", 5usize, |this| ((**(this as *const *const IATSC_EIT_Vtbl)).GetSourceId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProtocolVersion(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_EIT", "GetProtocolVersion", 6usize, |this| ((**(this as *const *const IATSC_EIT_Vtbl)).GetProtocolVersion)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_EIT", "GetCountOfRecords", 7usize, |this| ((**(this as *const *const IATSC_EIT_Vtbl)).GetCountOfRecords)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordEventId(this: *mut core::ffi::c_void, dwrecordindex: u32, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_EIT", "GetRecordEventId", 8usize, |this| ((**(this as *const *const IATSC_EIT_Vtbl)).GetRecordEventId)(this, dwrecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordStartTime(this: *mut core::ffi::c_void, dwrecordindex: u32, pmdtval: *mut MPEG_DATE_AND_TIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_EIT", "GetRecordStartTime", 9usize, |this| ((**(this as *const *const IATSC_EIT_Vtbl)).GetRecordStartTime)(this, dwrecordindex, pmdtval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordEtmLocation(this: *mut core::ffi::c_void, dwrecordindex: u32, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_EIT", "GetRecordEtmLocation", 10usize, |this| ((**(this as *const *const IATSC_EIT_Vtbl)).GetRecordEtmLocation)(this, dwrecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDuration(this: *mut core::ffi::c_void, dwrecordindex: u32, pmdval: *mut MPEG_TIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_EIT", "GetRecordDuration", 11usize, |this| ((**(this as *const *const IATSC_EIT_Vtbl)).GetRecordDuration)(this, dwrecordindex, pmdval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordTitleText(this: *mut core::ffi::c_void, dwrecordindex: u32, pdwlength: *mut u32, pptext: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_EIT", "GetRecordTitleText", 12usize, |this| ((**(this as *const *const IATSC_EIT_Vtbl)).GetRecordTitleText)(this, dwrecordindex, pdwlength, pptext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordCountOfDescriptors(this: *mut core::ffi::c_void, dwrecordindex: u32, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_EIT", "GetRecordCountOfDescriptors", 13usize, |this| ((**(this as *const *const IATSC_EIT_Vtbl)).GetRecordCountOfDescriptors)(this, dwrecordindex, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptorByIndex(this: *mut core::ffi::c_void, dwrecordindex: u32, dwindex: u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_EIT", "GetRecordDescriptorByIndex", 14usize, |this| ((**(this as *const *const IATSC_EIT_Vtbl)).GetRecordDescriptorByIndex)(this, dwrecordindex, dwindex, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptorByTag(this: *mut core::ffi::c_void, dwrecordindex: u32, btag: u8, pdwcookie: *mut u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_EIT", "GetRecordDescriptorByTag", 15usize, |this| ((**(this as *const *const IATSC_EIT_Vtbl)).GetRecordDescriptorByTag)(this, dwrecordindex, btag, pdwcookie, ppdescriptor), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetVersionNumber,
+            GetSourceId,
+            GetProtocolVersion,
+            GetCountOfRecords,
+            GetRecordEventId,
+            GetRecordStartTime,
+            GetRecordEtmLocation,
+            GetRecordDuration,
+            GetRecordTitleText,
+            GetRecordCountOfDescriptors,
+            GetRecordDescriptorByIndex,
+            GetRecordDescriptorByTag,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IATSC_EIT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IATSC_ETT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psectionlist: *mut core::ffi::c_void, pmpegdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_ETT", "Initialize", 3usize, |this| ((**(this as *const *const IATSC_ETT_Vtbl)).Initialize)(this, psectionlist, pmpegdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionNumber(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_ETT", "GetVersionNumber", 4usize, |this| ((**(this as *const *const IATSC_ETT_Vtbl)).GetVersionNumber)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProtocolVersion(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_ETT", "GetProtocolVersion", 5usize, |this| ((**(this as *const *const IATSC_ETT_Vtbl)).GetProtocolVersion)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEtmId(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_ETT", "GetEtmId", 6usize, |this| ((**(this as *const *const IATSC_ETT_Vtbl)).GetEtmId)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExtendedMessageText(this: *mut core::ffi::c_void, pdwlength: *mut u32, pptext: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_ETT", "GetExtendedMessageText", 7usize, |this| ((**(this as *const *const IATSC_ETT_Vtbl)).GetExtendedMessageText)(this, pdwlength, pptext), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetVersionNumber,
+            GetProtocolVersion,
+            GetEtmId,
+            GetExtendedMessageText,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IATSC_ETT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IATSC_MGT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psectionlist: *mut core::ffi::c_void, pmpegdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_MGT", "Initialize", 3usize, |this| ((**(this as *const *const IATSC_MGT_Vtbl)).Initialize)(this, psectionlist, pmpegdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionNumber(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_MGT", "GetVersionNumber", 4usize, |this| ((**(this as *const *const IATSC_MGT_Vtbl)).GetVersionNumber)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProtocolVersion(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_MGT", "GetProtocolVersion", 5usize, |this| ((**(this as *const *const IATSC_MGT_Vtbl)).GetProtocolVersion)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_MGT", "GetCountOfRecords", 6usize, |this| ((**(this as *const *const IATSC_MGT_Vtbl)).GetCountOfRecords)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordType(this: *mut core::ffi::c_void, dwrecordindex: u32, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_MGT", "GetRecordType", 7usize, |this| ((**(this as *const *const IATSC_MGT_Vtbl)).GetRecordType)(this, dwrecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordTypePid(this: *mut core::ffi::c_void, dwrecordindex: u32, ppidval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_MGT", "GetRecordTypePid", 8usize, |this| ((**(this as *const *const IATSC_MGT_Vtbl)).GetRecordTypePid)(this, dwrecordindex, ppidval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordVersionNumber(this: *mut core::ffi::c_void, dwrecordindex: u32, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_MGT", "GetRecordVersionNumber", 9usize, |this| ((**(this as *const *const IATSC_MGT_Vtbl)).GetRecordVersionNumber)(this, dwrecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordCountOfDescriptors(this: *mut core::ffi::c_void, dwrecordindex: u32, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_MGT", "GetRecordCountOfDescriptors", 10usize, |this| ((**(this as *const *const IATSC_MGT_Vtbl)).GetRecordCountOfDescriptors)(this, dwrecordindex, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptorByIndex(this: *mut core::ffi::c_void, dwrecordindex: u32, dwindex: u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_MGT", "GetRecordDescriptorByIndex", 11usize, |this| ((**(this as *const *const IATSC_MGT_Vtbl)).GetRecordDescriptorByIndex)(this, dwrecordindex, dwindex, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptorByTag(this: *mut core::ffi::c_void, dwrecordindex: u32, btag: u8, pdwcookie: *mut u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_MGT", "GetRecordDescriptorByTag", 12usize, |this| ((**(this as *const *const IATSC_MGT_Vtbl)).GetRecordDescriptorByTag)(this, dwrecordindex, btag, pdwcookie, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfTableDescriptors(this: *mut core::ffi::c_void, pdwval: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_MGT", "GetCountOfTableDescriptors", 13usize, |this| ((**(this as *const *const IATSC_MGT_Vtbl)).GetCountOfTableDescriptors)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableDescriptorByIndex(this: *mut core::ffi::c_void, dwindex: u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_MGT", "GetTableDescriptorByIndex", 14usize, |this| ((**(this as *const *const IATSC_MGT_Vtbl)).GetTableDescriptorByIndex)(this, dwindex, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableDescriptorByTag(this: *mut core::ffi::c_void, btag: u8, pdwcookie: *mut u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_MGT", "GetTableDescriptorByTag", 15usize, |this| ((**(this as *const *const IATSC_MGT_Vtbl)).GetTableDescriptorByTag)(this, btag, pdwcookie, ppdescriptor), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetVersionNumber,
+            GetProtocolVersion,
+            GetCountOfRecords,
+            GetRecordType,
+            GetRecordTypePid,
+            GetRecordVersionNumber,
+            GetRecordCountOfDescriptors,
+            GetRecordDescriptorByIndex,
+            GetRecordDescriptorByTag,
+            GetCountOfTableDescriptors,
+            GetTableDescriptorByIndex,
+            GetTableDescriptorByTag,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IATSC_MGT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IATSC_STT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psectionlist: *mut core::ffi::c_void, pmpegdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_STT", "Initialize", 3usize, |this| ((**(this as *const *const IATSC_STT_Vtbl)).Initialize)(this, psectionlist, pmpegdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProtocolVersion(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_STT", "GetProtocolVersion", 4usize, |this| ((**(this as *const *const IATSC_STT_Vtbl)).GetProtocolVersion)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSystemTime(this: *mut core::ffi::c_void, pmdtsystemtime: *mut MPEG_DATE_AND_TIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_STT", "GetSystemTime", 5usize, |this| ((**(this as *const *const IATSC_STT_Vtbl)).GetSystemTime)(this, pmdtsystemtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGpsUtcOffset(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_STT", "GetGpsUtcOffset", 6usize, |this| ((**(this as *const *const IATSC_STT_Vtbl)).GetGpsUtcOffset)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDaylightSavings(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_STT", "GetDaylightSavings", 7usize, |this| ((**(this as *const *const IATSC_STT_Vtbl)).GetDaylightSavings)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfTableDescriptors(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_STT", "GetCountOfTableDescriptors", 8usize, |this| ((**(this as *const *const IATSC_STT_Vtbl)).GetCountOfTableDescriptors)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableDescriptorByIndex(this: *mut core::ffi::c_void, dwindex: u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_STT", "GetTableDescriptorByIndex", 9usize, |this| ((**(this as *const *const IATSC_STT_Vtbl)).GetTableDescriptorByIndex)(this, dwindex, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableDescriptorByTag(this: *mut core::ffi::c_void, btag: u8, pdwcookie: *mut u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_STT", "GetTableDescriptorByTag", 10usize, |this| ((**(this as *const *const IATSC_STT_Vtbl)).GetTableDescriptorByTag)(this, btag, pdwcookie, ppdescriptor), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetProtocolVersion,
+            GetSystemTime,
+            GetGpsUtcOffset,
+            GetDaylightSavings,
+            GetCountOfTableDescriptors,
+            GetTableDescriptorByIndex,
+            GetTableDescriptorByTag,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IATSC_STT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IATSC_VCT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psectionlist: *mut core::ffi::c_void, pmpegdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_VCT", "Initialize", 3usize, |this| ((**(this as *const *const IATSC_VCT_Vtbl)).Initialize)(this, psectionlist, pmpegdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionNumber(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_VCT", "GetVersionNumber", 4usize, |this| ((**(this as *const *const IATSC_VCT_Vtbl)).GetVersionNumber)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransportStreamId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_VCT", "GetTransportStreamId", 5usize, |this| ((**(this as *const *const IATSC_VCT_Vtbl)).GetTransportStreamId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProtocolVersion(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_VCT", "GetProtocolVersion", 6usize, |this| ((**(this as *const *const IATSC_VCT_Vtbl)).GetProtocolVersion)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_VCT", "GetCountOfRecords", 7usize, |this| ((**(this as *const *const IATSC_VCT_Vtbl)).GetCountOfRecords)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordName(this: *mut core::ffi::c_void, dwrecordindex: u32, pwsname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_VCT", "GetRecordName", 8usize, |this| ((**(this as *const *const IATSC_VCT_Vtbl)).GetRecordName)(this, dwrecordindex, pwsname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordMajorChannelNumber(this: *mut core::ffi::c_void, dwrecordindex: u32, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_VCT", "GetRecordMajorChannelNumber", 9usize, |this| ((**(this as *const *const IATSC_VCT_Vtbl)).GetRecordMajorChannelNumber)(this, dwrecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordMinorChannelNumber(this: *mut core::ffi::c_void, dwrecordindex: u32, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_VCT", "GetRecordMinorChannelNumber", 10usize, |this| ((**(this as *const *const IATSC_VCT_Vtbl)).GetRecordMinorChannelNumber)(this, dwrecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordModulationMode(this: *mut core::ffi::c_void, dwrecordindex: u32, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_VCT", "GetRecordModulationMode", 11usize, |this| ((**(this as *const *const IATSC_VCT_Vtbl)).GetRecordModulationMode)(this, dwrecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordCarrierFrequency(this: *mut core::ffi::c_void, dwrecordindex: u32, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_VCT", "GetRecordCarrierFrequency", 12usize, |this| ((**(this as *const *const IATSC_VCT_Vtbl)).GetRecordCarrierFrequency)(this, dwrecordindex, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordTransportStreamId(this: *mut core::ffi::c_void, dwrecordindex: u32, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_VCT", "GetRecordTransportStreamId", 13usize, |this| ((**(this as *const *const IATSC_VCT_Vtbl)).GetRecordTransportStreamId)(this, dwrecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordProgramNumber(this: *mut core::ffi::c_void, dwrecordindex: u32, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_VCT", "GetRecordProgramNumber", 14usize, |this| ((**(this as *const *const IATSC_VCT_Vtbl)).GetRecordProgramNumber)(this, dwrecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordEtmLocation(this: *mut core::ffi::c_void, dwrecordindex: u32, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_VCT", "GetRecordEtmLocation", 15usize, |this| ((**(this as *const *const IATSC_VCT_Vtbl)).GetRecordEtmLocation)(this, dwrecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordIsAccessControlledBitSet(this: *mut core::ffi::c_void, dwrecordindex: u32, pfval: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_VCT", "GetRecordIsAccessControlledBitSet", 16usize, |this| ((**(this as *const *const IATSC_VCT_Vtbl)).GetRecordIsAccessControlledBitSet)(this, dwrecordindex, pfval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordIsHiddenBitSet(this: *mut core::ffi::c_void, dwrecordindex: u32, pfval: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_VCT", "GetRecordIsHiddenBitSet", 17usize, |this| ((**(this as *const *const IATSC_VCT_Vtbl)).GetRecordIsHiddenBitSet)(this, dwrecordindex, pfval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordIsPathSelectBitSet(this: *mut core::ffi::c_void, dwrecordindex: u32, pfval: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_VCT", "GetRecordIsPathSelectBitSet", 18usize, |this| ((**(this as *const *const IATSC_VCT_Vtbl)).GetRecordIsPathSelectBitSet)(this, dwrecordindex, pfval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordIsOutOfBandBitSet(this: *mut core::ffi::c_void, dwrecordindex: u32, pfval: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_VCT", "GetRecordIsOutOfBandBitSet", 19usize, |this| ((**(this as *const *const IATSC_VCT_Vtbl)).GetRecordIsOutOfBandBitSet)(this, dwrecordindex, pfval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordIsHideGuideBitSet(this: *mut core::ffi::c_void, dwrecordindex: u32, pfval: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_VCT", "GetRecordIsHideGuideBitSet", 20usize, |this| ((**(this as *const *const IATSC_VCT_Vtbl)).GetRecordIsHideGuideBitSet)(this, dwrecordindex, pfval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordServiceType(this: *mut core::ffi::c_void, dwrecordindex: u32, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_VCT", "GetRecordServiceType", 21usize, |this| ((**(this as *const *const IATSC_VCT_Vtbl)).GetRecordServiceType)(this, dwrecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordSourceId(this: *mut core::ffi::c_void, dwrecordindex: u32, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_VCT", "GetRecordSourceId", 22usize, |this| ((**(this as *const *const IATSC_VCT_Vtbl)).GetRecordSourceId)(this, dwrecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordCountOfDescriptors(this: *mut core::ffi::c_void, dwrecordindex: u32, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_VCT", "GetRecordCountOfDescriptors", 23usize, |this| ((**(this as *const *const IATSC_VCT_Vtbl)).GetRecordCountOfDescriptors)(this, dwrecordindex, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptorByIndex(this: *mut core::ffi::c_void, dwrecordindex: u32, dwindex: u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_VCT", "GetRecordDescriptorByIndex", 24usize, |this| ((**(this as *const *const IATSC_VCT_Vtbl)).GetRecordDescriptorByIndex)(this, dwrecordindex, dwindex, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptorByTag(this: *mut core::ffi::c_void, dwrecordindex: u32, btag: u8, pdwcookie: *mut u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_VCT", "GetRecordDescriptorByTag", 25usize, |this| ((**(this as *const *const IATSC_VCT_Vtbl)).GetRecordDescriptorByTag)(this, dwrecordindex, btag, pdwcookie, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfTableDescriptors(this: *mut core::ffi::c_void, pdwval: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_VCT", "GetCountOfTableDescriptors", 26usize, |this| ((**(this as *const *const IATSC_VCT_Vtbl)).GetCountOfTableDescriptors)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableDescriptorByIndex(this: *mut core::ffi::c_void, dwindex: u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_VCT", "GetTableDescriptorByIndex", 27usize, |this| ((**(this as *const *const IATSC_VCT_Vtbl)).GetTableDescriptorByIndex)(this, dwindex, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableDescriptorByTag(this: *mut core::ffi::c_void, btag: u8, pdwcookie: *mut u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IATSC_VCT", "GetTableDescriptorByTag", 28usize, |this| ((**(this as *const *const IATSC_VCT_Vtbl)).GetTableDescriptorByTag)(this, btag, pdwcookie, ppdescriptor), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetVersionNumber,
+            GetTransportStreamId,
+            GetProtocolVersion,
+            GetCountOfRecords,
+            GetRecordName,
+            GetRecordMajorChannelNumber,
+            GetRecordMinorChannelNumber,
+            GetRecordModulationMode,
+            GetRecordCarrierFrequency,
+            GetRecordTransportStreamId,
+            GetRecordProgramNumber,
+            GetRecordEtmLocation,
+            GetRecordIsAccessControlledBitSet,
+            GetRecordIsHiddenBitSet,
+            GetRecordIsPathSelectBitSet,
+            GetRecordIsOutOfBandBitSet,
+            GetRecordIsHideGuideBitSet,
+            GetRecordServiceType,
+            GetRecordSourceId,
+            GetRecordCountOfDescriptors,
+            GetRecordDescriptorByIndex,
+            GetRecordDescriptorByTag,
+            GetCountOfTableDescriptors,
+            GetTableDescriptorByIndex,
+            GetTableDescriptorByTag,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IATSC_VCT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Media_MediaFoundation", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IAnalogAudioComponentType_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AnalogAudioMode(this: *mut core::ffi::c_void, mode: *mut super::TVAudioMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnalogAudioComponentType", "AnalogAudioMode", 24usize, |this| ((**(this as *const *const IAnalogAudioComponentType_Vtbl)).AnalogAudioMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAnalogAudioMode(this: *mut core::ffi::c_void, mode: super::TVAudioMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnalogAudioComponentType", "SetAnalogAudioMode", 25usize, |this| ((**(this as *const *const IAnalogAudioComponentType_Vtbl)).SetAnalogAudioMode)(this, mode), |result| Some(*result))
+        }
+        Self { base__: <IComponentType_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AnalogAudioMode, SetAnalogAudioMode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAnalogAudioComponentType as windows_core::Interface>::IID || <IComponentType_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAnalogLocator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VideoStandard(this: *mut core::ffi::c_void, avs: *mut super::AnalogVideoStandard) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnalogLocator", "VideoStandard", 22usize, |this| ((**(this as *const *const IAnalogLocator_Vtbl)).VideoStandard)(this, avs), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVideoStandard(this: *mut core::ffi::c_void, avs: super::AnalogVideoStandard) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnalogLocator", "SetVideoStandard", 23usize, |this| ((**(this as *const *const IAnalogLocator_Vtbl)).SetVideoStandard)(this, avs), |result| Some(*result))
+        }
+        Self { base__: <ILocator_Vtbl as windows_core::imp::ProxyVtable>::PROXY, VideoStandard, SetVideoStandard }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAnalogLocator as windows_core::Interface>::IID || <ILocator_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAnalogRadioTuningSpace_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MinFrequency(this: *mut core::ffi::c_void, minfrequencyval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnalogRadioTuningSpace", "MinFrequency", 26usize, |this| ((**(this as *const *const IAnalogRadioTuningSpace_Vtbl)).MinFrequency)(this, minfrequencyval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinFrequency(this: *mut core::ffi::c_void, newminfrequencyval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnalogRadioTuningSpace", "SetMinFrequency", 27usize, |this| ((**(this as *const *const IAnalogRadioTuningSpace_Vtbl)).SetMinFrequency)(this, newminfrequencyval), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxFrequency(this: *mut core::ffi::c_void, maxfrequencyval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnalogRadioTuningSpace", "MaxFrequency", 28usize, |this| ((**(this as *const *const IAnalogRadioTuningSpace_Vtbl)).MaxFrequency)(this, maxfrequencyval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxFrequency(this: *mut core::ffi::c_void, newmaxfrequencyval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnalogRadioTuningSpace", "SetMaxFrequency", 29usize, |this| ((**(this as *const *const IAnalogRadioTuningSpace_Vtbl)).SetMaxFrequency)(this, newmaxfrequencyval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Step(this: *mut core::ffi::c_void, stepval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnalogRadioTuningSpace", "Step", 30usize, |this| ((**(this as *const *const IAnalogRadioTuningSpace_Vtbl)).Step)(this, stepval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStep(this: *mut core::ffi::c_void, newstepval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnalogRadioTuningSpace", "SetStep", 31usize, |this| ((**(this as *const *const IAnalogRadioTuningSpace_Vtbl)).SetStep)(this, newstepval), |result| Some(*result))
+        }
+        Self {
+            base__: <ITuningSpace_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MinFrequency,
+            SetMinFrequency,
+            MaxFrequency,
+            SetMaxFrequency,
+            Step,
+            SetStep,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAnalogRadioTuningSpace as windows_core::Interface>::IID || <ITuningSpace_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAnalogRadioTuningSpace2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CountryCode(this: *mut core::ffi::c_void, countrycodeval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnalogRadioTuningSpace2", "CountryCode", 32usize, |this| ((**(this as *const *const IAnalogRadioTuningSpace2_Vtbl)).CountryCode)(this, countrycodeval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCountryCode(this: *mut core::ffi::c_void, newcountrycodeval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnalogRadioTuningSpace2", "SetCountryCode", 33usize, |this| ((**(this as *const *const IAnalogRadioTuningSpace2_Vtbl)).SetCountryCode)(this, newcountrycodeval), |result| Some(*result))
+        }
+        Self { base__: <IAnalogRadioTuningSpace_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CountryCode, SetCountryCode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAnalogRadioTuningSpace2 as windows_core::Interface>::IID || <IAnalogRadioTuningSpace_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAnalogTVTuningSpace_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MinChannel(this: *mut core::ffi::c_void, minchannelval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnalogTVTuningSpace", "MinChannel", 26usize, |this| ((**(this as *const *const IAnalogTVTuningSpace_Vtbl)).MinChannel)(this, minchannelval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinChannel(this: *mut core::ffi::c_void, newminchannelval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnalogTVTuningSpace", "SetMinChannel", 27usize, |this| ((**(this as *const *const IAnalogTVTuningSpace_Vtbl)).SetMinChannel)(this, newminchannelval), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxChannel(this: *mut core::ffi::c_void, maxchannelval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnalogTVTuningSpace", "MaxChannel", 28usize, |this| ((**(this as *const *const IAnalogTVTuningSpace_Vtbl)).MaxChannel)(this, maxchannelval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxChannel(this: *mut core::ffi::c_void, newmaxchannelval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnalogTVTuningSpace", "SetMaxChannel", 29usize, |this| ((**(this as *const *const IAnalogTVTuningSpace_Vtbl)).SetMaxChannel)(this, newmaxchannelval), |result| Some(*result))
+        }
+        unsafe extern "system" fn InputType(this: *mut core::ffi::c_void, inputtypeval: *mut super::TunerInputType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnalogTVTuningSpace", "InputType", 30usize, |this| ((**(this as *const *const IAnalogTVTuningSpace_Vtbl)).InputType)(this, inputtypeval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInputType(this: *mut core::ffi::c_void, newinputtypeval: super::TunerInputType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnalogTVTuningSpace", "SetInputType", 31usize, |this| ((**(this as *const *const IAnalogTVTuningSpace_Vtbl)).SetInputType)(this, newinputtypeval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CountryCode(this: *mut core::ffi::c_void, countrycodeval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnalogTVTuningSpace", "CountryCode", 32usize, |this| ((**(this as *const *const IAnalogTVTuningSpace_Vtbl)).CountryCode)(this, countrycodeval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCountryCode(this: *mut core::ffi::c_void, newcountrycodeval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnalogTVTuningSpace", "SetCountryCode", 33usize, |this| ((**(this as *const *const IAnalogTVTuningSpace_Vtbl)).SetCountryCode)(this, newcountrycodeval), |result| Some(*result))
+        }
+        Self {
+            base__: <ITuningSpace_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MinChannel,
+            SetMinChannel,
+            MaxChannel,
+            SetMaxChannel,
+            InputType,
+            SetInputType,
+            CountryCode,
+            SetCountryCode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAnalogTVTuningSpace as windows_core::Interface>::IID || <ITuningSpace_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAtscContentAdvisoryDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtscContentAdvisoryDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IAtscContentAdvisoryDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtscContentAdvisoryDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IAtscContentAdvisoryDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRatingRegionCount(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtscContentAdvisoryDescriptor", "GetRatingRegionCount", 5usize, |this| ((**(this as *const *const IAtscContentAdvisoryDescriptor_Vtbl)).GetRatingRegionCount)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordRatingRegion(this: *mut core::ffi::c_void, bindex: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtscContentAdvisoryDescriptor", "GetRecordRatingRegion", 6usize, |this| ((**(this as *const *const IAtscContentAdvisoryDescriptor_Vtbl)).GetRecordRatingRegion)(this, bindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordRatedDimensions(this: *mut core::ffi::c_void, bindex: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtscContentAdvisoryDescriptor", "GetRecordRatedDimensions", 7usize, |this| ((**(this as *const *const IAtscContentAdvisoryDescriptor_Vtbl)).GetRecordRatedDimensions)(this, bindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordRatingDimension(this: *mut core::ffi::c_void, bindexouter: u8, bindexinner: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtscContentAdvisoryDescriptor", "GetRecordRatingDimension", 8usize, |this| ((**(this as *const *const IAtscContentAdvisoryDescriptor_Vtbl)).GetRecordRatingDimension)(this, bindexouter, bindexinner, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordRatingValue(this: *mut core::ffi::c_void, bindexouter: u8, bindexinner: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtscContentAdvisoryDescriptor", "GetRecordRatingValue", 9usize, |this| ((**(this as *const *const IAtscContentAdvisoryDescriptor_Vtbl)).GetRecordRatingValue)(this, bindexouter, bindexinner, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordRatingDescriptionText(this: *mut core::ffi::c_void, bindex: u8, pblength: *mut u8, pptext: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtscContentAdvisoryDescriptor", "GetRecordRatingDescriptionText", 10usize, |this| ((**(this as *const *const IAtscContentAdvisoryDescriptor_Vtbl)).GetRecordRatingDescriptionText)(this, bindex, pblength, pptext), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetRatingRegionCount,
+            GetRecordRatingRegion,
+            GetRecordRatedDimensions,
+            GetRecordRatingDimension,
+            GetRecordRatingValue,
+            GetRecordRatingDescriptionText,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAtscContentAdvisoryDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAtscPsipParser_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, punkmpeg2data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtscPsipParser", "Initialize", 3usize, |this| ((**(this as *const *const IAtscPsipParser_Vtbl)).Initialize)(this, punkmpeg2data), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPAT(this: *mut core::ffi::c_void, pppat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtscPsipParser", "GetPAT", 4usize, |this| ((**(this as *const *const IAtscPsipParser_Vtbl)).GetPAT)(this, pppat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCAT(this: *mut core::ffi::c_void, dwtimeout: u32, ppcat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtscPsipParser", "GetCAT", 5usize, |this| ((**(this as *const *const IAtscPsipParser_Vtbl)).GetCAT)(this, dwtimeout, ppcat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPMT(this: *mut core::ffi::c_void, pid: u16, pwprogramnumber: *const u16, pppmt: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtscPsipParser", "GetPMT", 6usize, |this| ((**(this as *const *const IAtscPsipParser_Vtbl)).GetPMT)(this, pid, pwprogramnumber, pppmt), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTSDT(this: *mut core::ffi::c_void, pptsdt: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtscPsipParser", "GetTSDT", 7usize, |this| ((**(this as *const *const IAtscPsipParser_Vtbl)).GetTSDT)(this, pptsdt), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMGT(this: *mut core::ffi::c_void, ppmgt: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtscPsipParser", "GetMGT", 8usize, |this| ((**(this as *const *const IAtscPsipParser_Vtbl)).GetMGT)(this, ppmgt), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVCT(this: *mut core::ffi::c_void, tableid: u8, fgetnexttable: super::super::super::Foundation::BOOL, ppvct: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtscPsipParser", "GetVCT", 9usize, |this| ((**(this as *const *const IAtscPsipParser_Vtbl)).GetVCT)(this, tableid, fgetnexttable, ppvct), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEIT(this: *mut core::ffi::c_void, pid: u16, pwsourceid: *const u16, dwtimeout: u32, ppeit: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtscPsipParser", "GetEIT", 10usize, |this| ((**(this as *const *const IAtscPsipParser_Vtbl)).GetEIT)(this, pid, pwsourceid, dwtimeout, ppeit), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetETT(this: *mut core::ffi::c_void, pid: u16, wsourceid: *const u16, pweventid: *const u16, ppett: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtscPsipParser", "GetETT", 11usize, |this| ((**(this as *const *const IAtscPsipParser_Vtbl)).GetETT)(this, pid, wsourceid, pweventid, ppett), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSTT(this: *mut core::ffi::c_void, ppstt: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtscPsipParser", "GetSTT", 12usize, |this| ((**(this as *const *const IAtscPsipParser_Vtbl)).GetSTT)(this, ppstt), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEAS(this: *mut core::ffi::c_void, pid: u16, ppeas: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAtscPsipParser", "GetEAS", 13usize, |this| ((**(this as *const *const IAtscPsipParser_Vtbl)).GetEAS)(this, pid, ppeas), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetPAT,
+            GetCAT,
+            GetPMT,
+            GetTSDT,
+            GetMGT,
+            GetVCT,
+            GetEIT,
+            GetETT,
+            GetSTT,
+            GetEAS,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAtscPsipParser as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAttributeGet_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAttributeGet", "GetCount", 3usize, |this| ((**(this as *const *const IAttributeGet_Vtbl)).GetCount)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttribIndexed(this: *mut core::ffi::c_void, lindex: i32, pguidattribute: *mut windows_core::GUID, pbattribute: *mut u8, pdwattributelength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAttributeGet", "GetAttribIndexed", 4usize, |this| ((**(this as *const *const IAttributeGet_Vtbl)).GetAttribIndexed)(this, lindex, pguidattribute, pbattribute, pdwattributelength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttrib(this: *mut core::ffi::c_void, guidattribute: windows_core::GUID, pbattribute: *mut u8, pdwattributelength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAttributeGet", "GetAttrib", 5usize, |this| ((**(this as *const *const IAttributeGet_Vtbl)).GetAttrib)(this, guidattribute, pbattribute, pdwattributelength), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCount, GetAttribIndexed, GetAttrib }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAttributeGet as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAttributeSet_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAttrib(this: *mut core::ffi::c_void, guidattribute: windows_core::GUID, pbattribute: *const u8, dwattributelength: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAttributeSet", "SetAttrib", 3usize, |this| ((**(this as *const *const IAttributeSet_Vtbl)).SetAttrib)(this, guidattribute, pbattribute, dwattributelength), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAttrib }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAttributeSet as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAuxInTuningSpace_Vtbl {
+    const PROXY: Self = { Self { base__: <ITuningSpace_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAuxInTuningSpace as windows_core::Interface>::IID || <ITuningSpace_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAuxInTuningSpace2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CountryCode(this: *mut core::ffi::c_void, countrycodeval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAuxInTuningSpace2", "CountryCode", 26usize, |this| ((**(this as *const *const IAuxInTuningSpace2_Vtbl)).CountryCode)(this, countrycodeval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCountryCode(this: *mut core::ffi::c_void, newcountrycodeval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAuxInTuningSpace2", "SetCountryCode", 27usize, |this| ((**(this as *const *const IAuxInTuningSpace2_Vtbl)).SetCountryCode)(this, newcountrycodeval), |result| Some(*result))
+        }
+        Self { base__: <IAuxInTuningSpace_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CountryCode, SetCountryCode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAuxInTuningSpace2 as windows_core::Interface>::IID || <IAuxInTuningSpace_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IBDAComparable_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CompareExact(this: *mut core::ffi::c_void, compareto: *mut core::ffi::c_void, result: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDAComparable", "CompareExact", 3usize, |this| ((**(this as *const *const IBDAComparable_Vtbl)).CompareExact)(this, compareto, result), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CompareEquivalent(this: *mut core::ffi::c_void, compareto: *mut core::ffi::c_void, dwflags: u32, result: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDAComparable", "CompareEquivalent", 4usize, |this| ((**(this as *const *const IBDAComparable_Vtbl)).CompareEquivalent)(this, compareto, dwflags, result), |result| Some(*result))
+        }
+        unsafe extern "system" fn HashExact(this: *mut core::ffi::c_void, result: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDAComparable", "HashExact", 5usize, |this| ((**(this as *const *const IBDAComparable_Vtbl)).HashExact)(this, result), |result| Some(*result))
+        }
+        unsafe extern "system" fn HashExactIncremental(this: *mut core::ffi::c_void, partialresult: i64, result: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDAComparable", "HashExactIncremental", 6usize, |this| ((**(this as *const *const IBDAComparable_Vtbl)).HashExactIncremental)(this, partialresult, result), |result| Some(*result))
+        }
+        unsafe extern "system" fn HashEquivalent(this: *mut core::ffi::c_void, dwflags: u32, result: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDAComparable", "HashEquivalent", 7usize, |this| ((**(this as *const *const IBDAComparable_Vtbl)).HashEquivalent)(this, dwflags, result), |result| Some(*result))
+        }
+        unsafe extern "system" fn HashEquivalentIncremental(this: *mut core::ffi::c_void, partialresult: i64, dwflags: u32, result: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDAComparable", "HashEquivalentIncremental", 8usize, |this| ((**(this as *const *const IBDAComparable_Vtbl)).HashEquivalentIncremental)(this, partialresult, dwflags, result), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CompareExact,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CompareExact: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CompareEquivalent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CompareEquivalent: 0,
+            HashExact,
+            HashExactIncremental,
+            HashEquivalent,
+            HashEquivalentIncremental,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDAComparable as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IBDACreateTuneRequestEx_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateTuneRequestEx(this: *mut core::ffi::c_void, tunerequestiid: *const windows_core::GUID, tunerequest: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDACreateTuneRequestEx", "CreateTuneRequestEx", 3usize, |this| ((**(this as *const *const IBDACreateTuneRequestEx_Vtbl)).CreateTuneRequestEx)(this, tunerequestiid, tunerequest), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateTuneRequestEx,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateTuneRequestEx: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDACreateTuneRequestEx as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_TIF_REGISTRATION_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterTIFEx(this: *mut core::ffi::c_void, ptifinputpin: *mut core::ffi::c_void, ppvregistrationcontext: *mut u32, ppmpeg2datacontrol: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_TIF_REGISTRATION", "RegisterTIFEx", 3usize, |this| ((**(this as *const *const IBDA_TIF_REGISTRATION_Vtbl)).RegisterTIFEx)(this, ptifinputpin, ppvregistrationcontext, ppmpeg2datacontrol), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterTIF(this: *mut core::ffi::c_void, pvregistrationcontext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_TIF_REGISTRATION", "UnregisterTIF", 4usize, |this| ((**(this as *const *const IBDA_TIF_REGISTRATION_Vtbl)).UnregisterTIF)(this, pvregistrationcontext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterTIFEx, UnregisterTIF }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_TIF_REGISTRATION as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICAT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psectionlist: *mut core::ffi::c_void, pmpegdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICAT", "Initialize", 3usize, |this| ((**(this as *const *const ICAT_Vtbl)).Initialize)(this, psectionlist, pmpegdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionNumber(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICAT", "GetVersionNumber", 4usize, |this| ((**(this as *const *const ICAT_Vtbl)).GetVersionNumber)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfTableDescriptors(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICAT", "GetCountOfTableDescriptors", 5usize, |this| ((**(this as *const *const ICAT_Vtbl)).GetCountOfTableDescriptors)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableDescriptorByIndex(this: *mut core::ffi::c_void, dwindex: u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICAT", "GetTableDescriptorByIndex", 6usize, |this| ((**(this as *const *const ICAT_Vtbl)).GetTableDescriptorByIndex)(this, dwindex, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableDescriptorByTag(this: *mut core::ffi::c_void, btag: u8, pdwcookie: *mut u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICAT", "GetTableDescriptorByTag", 7usize, |this| ((**(this as *const *const ICAT_Vtbl)).GetTableDescriptorByTag)(this, btag, pdwcookie, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterForNextTable(this: *mut core::ffi::c_void, hnexttableavailable: super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICAT", "RegisterForNextTable", 8usize, |this| ((**(this as *const *const ICAT_Vtbl)).RegisterForNextTable)(this, hnexttableavailable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextTable(this: *mut core::ffi::c_void, dwtimeout: u32, ppcat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICAT", "GetNextTable", 9usize, |this| ((**(this as *const *const ICAT_Vtbl)).GetNextTable)(this, dwtimeout, ppcat), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterForWhenCurrent(this: *mut core::ffi::c_void, hnexttableiscurrent: super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICAT", "RegisterForWhenCurrent", 10usize, |this| ((**(this as *const *const ICAT_Vtbl)).RegisterForWhenCurrent)(this, hnexttableiscurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertNextToCurrent(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICAT", "ConvertNextToCurrent", 11usize, |this| ((**(this as *const *const ICAT_Vtbl)).ConvertNextToCurrent)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetVersionNumber,
+            GetCountOfTableDescriptors,
+            GetTableDescriptorByIndex,
+            GetTableDescriptorByTag,
+            RegisterForNextTable,
+            GetNextTable,
+            RegisterForWhenCurrent,
+            ConvertNextToCurrent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICAT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICaptionServiceDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNumberOfServices(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICaptionServiceDescriptor", "GetNumberOfServices", 3usize, |this| ((**(this as *const *const ICaptionServiceDescriptor_Vtbl)).GetNumberOfServices)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLanguageCode(this: *mut core::ffi::c_void, bindex: u8, langcode: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICaptionServiceDescriptor", "GetLanguageCode", 4usize, |this| ((**(this as *const *const ICaptionServiceDescriptor_Vtbl)).GetLanguageCode)(this, bindex, langcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCaptionServiceNumber(this: *mut core::ffi::c_void, bindex: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICaptionServiceDescriptor", "GetCaptionServiceNumber", 5usize, |this| ((**(this as *const *const ICaptionServiceDescriptor_Vtbl)).GetCaptionServiceNumber)(this, bindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCCType(this: *mut core::ffi::c_void, bindex: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICaptionServiceDescriptor", "GetCCType", 6usize, |this| ((**(this as *const *const ICaptionServiceDescriptor_Vtbl)).GetCCType)(this, bindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEasyReader(this: *mut core::ffi::c_void, bindex: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICaptionServiceDescriptor", "GetEasyReader", 7usize, |this| ((**(this as *const *const ICaptionServiceDescriptor_Vtbl)).GetEasyReader)(this, bindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWideAspectRatio(this: *mut core::ffi::c_void, bindex: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICaptionServiceDescriptor", "GetWideAspectRatio", 8usize, |this| ((**(this as *const *const ICaptionServiceDescriptor_Vtbl)).GetWideAspectRatio)(this, bindex, pbval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetNumberOfServices,
+            GetLanguageCode,
+            GetCaptionServiceNumber,
+            GetCCType,
+            GetEasyReader,
+            GetWideAspectRatio,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICaptionServiceDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IChannelIDTuneRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChannelID(this: *mut core::ffi::c_void, channelid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChannelIDTuneRequest", "ChannelID", 12usize, |this| ((**(this as *const *const IChannelIDTuneRequest_Vtbl)).ChannelID)(this, channelid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetChannelID(this: *mut core::ffi::c_void, channelid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChannelIDTuneRequest", "SetChannelID", 13usize, |this| ((**(this as *const *const IChannelIDTuneRequest_Vtbl)).SetChannelID)(this, channelid), |result| Some(*result))
+        }
+        Self { base__: <ITuneRequest_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ChannelID, SetChannelID }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChannelIDTuneRequest as windows_core::Interface>::IID || <ITuneRequest_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IChannelTuneRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Channel(this: *mut core::ffi::c_void, channel: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChannelTuneRequest", "Channel", 12usize, |this| ((**(this as *const *const IChannelTuneRequest_Vtbl)).Channel)(this, channel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetChannel(this: *mut core::ffi::c_void, channel: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChannelTuneRequest", "SetChannel", 13usize, |this| ((**(this as *const *const IChannelTuneRequest_Vtbl)).SetChannel)(this, channel), |result| Some(*result))
+        }
+        Self { base__: <ITuneRequest_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Channel, SetChannel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChannelTuneRequest as windows_core::Interface>::IID || <ITuneRequest_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IComponent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, ct: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponent", "Type", 7usize, |this| ((**(this as *const *const IComponent_Vtbl)).Type)(this, ct), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetType(this: *mut core::ffi::c_void, ct: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponent", "SetType", 8usize, |this| ((**(this as *const *const IComponent_Vtbl)).SetType)(this, ct), |result| Some(*result))
+        }
+        unsafe extern "system" fn DescLangID(this: *mut core::ffi::c_void, langid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponent", "DescLangID", 9usize, |this| ((**(this as *const *const IComponent_Vtbl)).DescLangID)(this, langid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescLangID(this: *mut core::ffi::c_void, langid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponent", "SetDescLangID", 10usize, |this| ((**(this as *const *const IComponent_Vtbl)).SetDescLangID)(this, langid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, status: *mut super::ComponentStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponent", "Status", 11usize, |this| ((**(this as *const *const IComponent_Vtbl)).Status)(this, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatus(this: *mut core::ffi::c_void, status: super::ComponentStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponent", "SetStatus", 12usize, |this| ((**(this as *const *const IComponent_Vtbl)).SetStatus)(this, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, description: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponent", "Description", 13usize, |this| ((**(this as *const *const IComponent_Vtbl)).Description)(this, description), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, description: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponent", "SetDescription", 14usize, |this| ((**(this as *const *const IComponent_Vtbl)).SetDescription)(this, description), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, newcomponent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponent", "Clone", 15usize, |this| ((**(this as *const *const IComponent_Vtbl)).Clone)(this, newcomponent), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Type,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Type: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetType,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetType: 0,
+            DescLangID,
+            SetDescLangID,
+            Status,
+            SetStatus,
+            Description,
+            SetDescription,
+            #[cfg(feature = "Win32_System_Com")]
+            Clone,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Clone: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComponent as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Media_MediaFoundation", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IComponentType_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Category(this: *mut core::ffi::c_void, category: *mut super::ComponentCategory) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentType", "Category", 7usize, |this| ((**(this as *const *const IComponentType_Vtbl)).Category)(this, category), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCategory(this: *mut core::ffi::c_void, category: super::ComponentCategory) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentType", "SetCategory", 8usize, |this| ((**(this as *const *const IComponentType_Vtbl)).SetCategory)(this, category), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaMajorType(this: *mut core::ffi::c_void, mediamajortype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentType", "MediaMajorType", 9usize, |this| ((**(this as *const *const IComponentType_Vtbl)).MediaMajorType)(this, mediamajortype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMediaMajorType(this: *mut core::ffi::c_void, mediamajortype: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentType", "SetMediaMajorType", 10usize, |this| ((**(this as *const *const IComponentType_Vtbl)).SetMediaMajorType)(this, mediamajortype), |result| Some(*result))
+        }
+        unsafe extern "system" fn _MediaMajorType(this: *mut core::ffi::c_void, mediamajortypeguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentType", "_MediaMajorType", 11usize, |this| ((**(this as *const *const IComponentType_Vtbl))._MediaMajorType)(this, mediamajortypeguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Set_MediaMajorType(this: *mut core::ffi::c_void, mediamajortypeguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentType", "Set_MediaMajorType", 12usize, |this| ((**(this as *const *const IComponentType_Vtbl)).Set_MediaMajorType)(this, mediamajortypeguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaSubType(this: *mut core::ffi::c_void, mediasubtype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentType", "MediaSubType", 13usize, |this| ((**(this as *const *const IComponentType_Vtbl)).MediaSubType)(this, mediasubtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMediaSubType(this: *mut core::ffi::c_void, mediasubtype: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentType", "SetMediaSubType", 14usize, |this| ((**(this as *const *const IComponentType_Vtbl)).SetMediaSubType)(this, mediasubtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn _MediaSubType(this: *mut core::ffi::c_void, mediasubtypeguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentType", "_MediaSubType", 15usize, |this| ((**(this as *const *const IComponentType_Vtbl))._MediaSubType)(this, mediasubtypeguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Set_MediaSubType(this: *mut core::ffi::c_void, mediasubtypeguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentType", "Set_MediaSubType", 16usize, |this| ((**(this as *const *const IComponentType_Vtbl)).Set_MediaSubType)(this, mediasubtypeguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaFormatType(this: *mut core::ffi::c_void, mediaformattype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentType", "MediaFormatType", 17usize, |this| ((**(this as *const *const IComponentType_Vtbl)).MediaFormatType)(this, mediaformattype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMediaFormatType(this: *mut core::ffi::c_void, mediaformattype: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentType", "SetMediaFormatType", 18usize, |this| ((**(this as *const *const IComponentType_Vtbl)).SetMediaFormatType)(this, mediaformattype), |result| Some(*result))
+        }
+        unsafe extern "system" fn _MediaFormatType(this: *mut core::ffi::c_void, mediaformattypeguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentType", "_MediaFormatType", 19usize, |this| ((**(this as *const *const IComponentType_Vtbl))._MediaFormatType)(this, mediaformattypeguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Set_MediaFormatType(this: *mut core::ffi::c_void, mediaformattypeguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentType", "Set_MediaFormatType", 20usize, |this| ((**(this as *const *const IComponentType_Vtbl)).Set_MediaFormatType)(this, mediaformattypeguid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn MediaType(this: *mut core::ffi::c_void, mediatype: *mut super::super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentType", "MediaType", 21usize, |this| ((**(this as *const *const IComponentType_Vtbl)).MediaType)(this, mediatype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn SetMediaType(this: *mut core::ffi::c_void, mediatype: *const super::super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentType", "SetMediaType", 22usize, |this| ((**(this as *const *const IComponentType_Vtbl)).SetMediaType)(this, mediatype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, newct: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentType", "Clone", 23usize, |this| ((**(this as *const *const IComponentType_Vtbl)).Clone)(this, newct), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Category,
+            SetCategory,
+            MediaMajorType,
+            SetMediaMajorType,
+            _MediaMajorType,
+            Set_MediaMajorType,
+            MediaSubType,
+            SetMediaSubType,
+            _MediaSubType,
+            Set_MediaSubType,
+            MediaFormatType,
+            SetMediaFormatType,
+            _MediaFormatType,
+            Set_MediaFormatType,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            MediaType,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            MediaType: 0,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            SetMediaType,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            SetMediaType: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Clone,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Clone: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComponentType as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IComponentTypes_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentTypes", "Count", 7usize, |this| ((**(this as *const *const IComponentTypes_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppnewenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentTypes", "_NewEnum", 8usize, |this| ((**(this as *const *const IComponentTypes_Vtbl))._NewEnum)(this, ppnewenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumComponentTypes(this: *mut core::ffi::c_void, ppnewenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentTypes", "EnumComponentTypes", 9usize, |this| ((**(this as *const *const IComponentTypes_Vtbl)).EnumComponentTypes)(this, ppnewenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>, componenttype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentTypes", "get_Item", 10usize, |this| ((**(this as *const *const IComponentTypes_Vtbl)).get_Item)(this, index, componenttype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn put_Item(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>, componenttype: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentTypes", "put_Item", 11usize, |this| ((**(this as *const *const IComponentTypes_Vtbl)).put_Item)(this, index, componenttype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, componenttype: *mut core::ffi::c_void, newindex: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentTypes", "Add", 12usize, |this| ((**(this as *const *const IComponentTypes_Vtbl)).Add)(this, componenttype, newindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentTypes", "Remove", 13usize, |this| ((**(this as *const *const IComponentTypes_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, newlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentTypes", "Clone", 14usize, |this| ((**(this as *const *const IComponentTypes_Vtbl)).Clone)(this, newlist), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Ole")]
+            _NewEnum,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            _NewEnum: 0,
+            EnumComponentTypes,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            put_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            put_Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            #[cfg(feature = "Win32_System_Com")]
+            Clone,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Clone: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComponentTypes as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IComponents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponents", "Count", 7usize, |this| ((**(this as *const *const IComponents_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppnewenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponents", "_NewEnum", 8usize, |this| ((**(this as *const *const IComponents_Vtbl))._NewEnum)(this, ppnewenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumComponents(this: *mut core::ffi::c_void, ppnewenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponents", "EnumComponents", 9usize, |this| ((**(this as *const *const IComponents_Vtbl)).EnumComponents)(this, ppnewenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>, ppcomponent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponents", "get_Item", 10usize, |this| ((**(this as *const *const IComponents_Vtbl)).get_Item)(this, index, ppcomponent), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, component: *mut core::ffi::c_void, newindex: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponents", "Add", 11usize, |this| ((**(this as *const *const IComponents_Vtbl)).Add)(this, component, newindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponents", "Remove", 12usize, |this| ((**(this as *const *const IComponents_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, newlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponents", "Clone", 13usize, |this| ((**(this as *const *const IComponents_Vtbl)).Clone)(this, newlist), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn put_Item(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>, ppcomponent: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponents", "put_Item", 14usize, |this| ((**(this as *const *const IComponents_Vtbl)).put_Item)(this, index, ppcomponent), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Ole")]
+            _NewEnum,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            _NewEnum: 0,
+            EnumComponents,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            #[cfg(feature = "Win32_System_Com")]
+            Clone,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Clone: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            put_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            put_Item: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComponents as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IComponentsOld_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentsOld", "Count", 7usize, |this| ((**(this as *const *const IComponentsOld_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppnewenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentsOld", "_NewEnum", 8usize, |this| ((**(this as *const *const IComponentsOld_Vtbl))._NewEnum)(this, ppnewenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumComponents(this: *mut core::ffi::c_void, ppnewenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentsOld", "EnumComponents", 9usize, |this| ((**(this as *const *const IComponentsOld_Vtbl)).EnumComponents)(this, ppnewenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>, ppcomponent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentsOld", "get_Item", 10usize, |this| ((**(this as *const *const IComponentsOld_Vtbl)).get_Item)(this, index, ppcomponent), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, component: *mut core::ffi::c_void, newindex: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentsOld", "Add", 11usize, |this| ((**(this as *const *const IComponentsOld_Vtbl)).Add)(this, component, newindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentsOld", "Remove", 12usize, |this| ((**(this as *const *const IComponentsOld_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, newlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentsOld", "Clone", 13usize, |this| ((**(this as *const *const IComponentsOld_Vtbl)).Clone)(this, newlist), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Ole")]
+            _NewEnum,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            _NewEnum: 0,
+            EnumComponents,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            #[cfg(feature = "Win32_System_Com")]
+            Clone,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Clone: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComponentsOld as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Registry")]
+impl windows_core::imp::ProxyVtable for ICreatePropBagOnRegKey_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Registry")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, hkey: super::super::super::System::Registry::HKEY, subkey: windows_core::PCWSTR, uloptions: u32, samdesired: u32, iid: *const windows_core::GUID, ppbag: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreatePropBagOnRegKey", "Create", 3usize, |this| ((**(this as *const *const ICreatePropBagOnRegKey_Vtbl)).Create)(this, hkey, subkey, uloptions, samdesired, iid, ppbag), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Registry")]
+            Create,
+            #[cfg(not(feature = "Win32_System_Registry"))]
+            Create: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICreatePropBagOnRegKey as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDTFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EvalRatObjOK(this: *mut core::ffi::c_void, phrcocreateretval: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDTFilter", "EvalRatObjOK", 3usize, |this| ((**(this as *const *const IDTFilter_Vtbl)).EvalRatObjOK)(this, phrcocreateretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrRating(this: *mut core::ffi::c_void, pensystem: *mut EnTvRat_System, penrating: *mut EnTvRat_GenericLevel, plbfenattr: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDTFilter", "GetCurrRating", 4usize, |this| ((**(this as *const *const IDTFilter_Vtbl)).GetCurrRating)(this, pensystem, penrating, plbfenattr), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_BlockedRatingAttributes(this: *mut core::ffi::c_void, ensystem: EnTvRat_System, enlevel: EnTvRat_GenericLevel, plbfenattr: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDTFilter", "get_BlockedRatingAttributes", 5usize, |this| ((**(this as *const *const IDTFilter_Vtbl)).get_BlockedRatingAttributes)(this, ensystem, enlevel, plbfenattr), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_BlockedRatingAttributes(this: *mut core::ffi::c_void, ensystem: EnTvRat_System, enlevel: EnTvRat_GenericLevel, lbfattrs: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDTFilter", "put_BlockedRatingAttributes", 6usize, |this| ((**(this as *const *const IDTFilter_Vtbl)).put_BlockedRatingAttributes)(this, ensystem, enlevel, lbfattrs), |result| Some(*result))
+        }
+        unsafe extern "system" fn BlockUnRated(this: *mut core::ffi::c_void, pfblockunratedshows: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDTFilter", "BlockUnRated", 7usize, |this| ((**(this as *const *const IDTFilter_Vtbl)).BlockUnRated)(this, pfblockunratedshows), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlockUnRated(this: *mut core::ffi::c_void, fblockunratedshows: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDTFilter", "SetBlockUnRated", 8usize, |this| ((**(this as *const *const IDTFilter_Vtbl)).SetBlockUnRated)(this, fblockunratedshows), |result| Some(*result))
+        }
+        unsafe extern "system" fn BlockUnRatedDelay(this: *mut core::ffi::c_void, pmsecsdelaybeforeblock: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDTFilter", "BlockUnRatedDelay", 9usize, |this| ((**(this as *const *const IDTFilter_Vtbl)).BlockUnRatedDelay)(this, pmsecsdelaybeforeblock), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlockUnRatedDelay(this: *mut core::ffi::c_void, msecsdelaybeforeblock: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDTFilter", "SetBlockUnRatedDelay", 10usize, |this| ((**(this as *const *const IDTFilter_Vtbl)).SetBlockUnRatedDelay)(this, msecsdelaybeforeblock), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EvalRatObjOK,
+            GetCurrRating,
+            get_BlockedRatingAttributes,
+            put_BlockedRatingAttributes,
+            BlockUnRated,
+            SetBlockUnRated,
+            BlockUnRatedDelay,
+            SetBlockUnRatedDelay,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDTFilter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDTFilter2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChallengeUrl(this: *mut core::ffi::c_void, pbstrchallengeurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDTFilter2", "ChallengeUrl", 11usize, |this| ((**(this as *const *const IDTFilter2_Vtbl)).ChallengeUrl)(this, pbstrchallengeurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrLicenseExpDate(this: *mut core::ffi::c_void, prottype: *const ProtType, lpdatetime: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDTFilter2", "GetCurrLicenseExpDate", 12usize, |this| ((**(this as *const *const IDTFilter2_Vtbl)).GetCurrLicenseExpDate)(this, prottype, lpdatetime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastErrorCode(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDTFilter2", "GetLastErrorCode", 13usize, |this| ((**(this as *const *const IDTFilter2_Vtbl)).GetLastErrorCode)(this), |result| Some(*result))
+        }
+        Self { base__: <IDTFilter_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ChallengeUrl, GetCurrLicenseExpDate, GetLastErrorCode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDTFilter2 as windows_core::Interface>::IID || <IDTFilter_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDTFilter3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProtectionType(this: *mut core::ffi::c_void, pprotectiontype: *mut ProtType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDTFilter3", "GetProtectionType", 14usize, |this| ((**(this as *const *const IDTFilter3_Vtbl)).GetProtectionType)(this, pprotectiontype), |result| Some(*result))
+        }
+        unsafe extern "system" fn LicenseHasExpirationDate(this: *mut core::ffi::c_void, pflicensehasexpirationdate: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDTFilter3", "LicenseHasExpirationDate", 15usize, |this| ((**(this as *const *const IDTFilter3_Vtbl)).LicenseHasExpirationDate)(this, pflicensehasexpirationdate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRights(this: *mut core::ffi::c_void, bstrrights: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDTFilter3", "SetRights", 16usize, |this| ((**(this as *const *const IDTFilter3_Vtbl)).SetRights)(this, bstrrights), |result| Some(*result))
+        }
+        Self { base__: <IDTFilter2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProtectionType, LicenseHasExpirationDate, SetRights }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDTFilter3 as windows_core::Interface>::IID || <IDTFilter2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDTFilterConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSecureChannelObject(this: *mut core::ffi::c_void, ppunkdrmsecurechannel: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDTFilterConfig", "GetSecureChannelObject", 3usize, |this| ((**(this as *const *const IDTFilterConfig_Vtbl)).GetSecureChannelObject)(this, ppunkdrmsecurechannel), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSecureChannelObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDTFilterConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDTFilterEvents_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDTFilterEvents as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDTFilterLicenseRenewal_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLicenseRenewalData(this: *mut core::ffi::c_void, ppwszfilename: *mut windows_core::PWSTR, ppwszexpiredkid: *mut windows_core::PWSTR, ppwsztunerid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDTFilterLicenseRenewal", "GetLicenseRenewalData", 3usize, |this| ((**(this as *const *const IDTFilterLicenseRenewal_Vtbl)).GetLicenseRenewalData)(this, ppwszfilename, ppwszexpiredkid, ppwsztunerid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetLicenseRenewalData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDTFilterLicenseRenewal as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDVBCLocator_Vtbl {
+    const PROXY: Self = { Self { base__: <IDigitalLocator_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDVBCLocator as windows_core::Interface>::IID || <IDigitalLocator_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDVBSLocator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SignalPolarisation(this: *mut core::ffi::c_void, polarisationval: *mut super::Polarisation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSLocator", "SignalPolarisation", 22usize, |this| ((**(this as *const *const IDVBSLocator_Vtbl)).SignalPolarisation)(this, polarisationval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSignalPolarisation(this: *mut core::ffi::c_void, polarisationval: super::Polarisation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSLocator", "SetSignalPolarisation", 23usize, |this| ((**(this as *const *const IDVBSLocator_Vtbl)).SetSignalPolarisation)(this, polarisationval), |result| Some(*result))
+        }
+        unsafe extern "system" fn WestPosition(this: *mut core::ffi::c_void, westlongitude: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSLocator", "WestPosition", 24usize, |this| ((**(this as *const *const IDVBSLocator_Vtbl)).WestPosition)(this, westlongitude), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWestPosition(this: *mut core::ffi::c_void, westlongitude: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSLocator", "SetWestPosition", 25usize, |this| ((**(this as *const *const IDVBSLocator_Vtbl)).SetWestPosition)(this, westlongitude), |result| Some(*result))
+        }
+        unsafe extern "system" fn OrbitalPosition(this: *mut core::ffi::c_void, longitude: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSLocator", "OrbitalPosition", 26usize, |this| ((**(this as *const *const IDVBSLocator_Vtbl)).OrbitalPosition)(this, longitude), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOrbitalPosition(this: *mut core::ffi::c_void, longitude: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSLocator", "SetOrbitalPosition", 27usize, |this| ((**(this as *const *const IDVBSLocator_Vtbl)).SetOrbitalPosition)(this, longitude), |result| Some(*result))
+        }
+        unsafe extern "system" fn Azimuth(this: *mut core::ffi::c_void, azimuth: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSLocator", "Azimuth", 28usize, |this| ((**(this as *const *const IDVBSLocator_Vtbl)).Azimuth)(this, azimuth), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAzimuth(this: *mut core::ffi::c_void, azimuth: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSLocator", "SetAzimuth", 29usize, |this| ((**(this as *const *const IDVBSLocator_Vtbl)).SetAzimuth)(this, azimuth), |result| Some(*result))
+        }
+        unsafe extern "system" fn Elevation(this: *mut core::ffi::c_void, elevation: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSLocator", "Elevation", 30usize, |this| ((**(this as *const *const IDVBSLocator_Vtbl)).Elevation)(this, elevation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetElevation(this: *mut core::ffi::c_void, elevation: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSLocator", "SetElevation", 31usize, |this| ((**(this as *const *const IDVBSLocator_Vtbl)).SetElevation)(this, elevation), |result| Some(*result))
+        }
+        Self {
+            base__: <IDigitalLocator_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SignalPolarisation,
+            SetSignalPolarisation,
+            WestPosition,
+            SetWestPosition,
+            OrbitalPosition,
+            SetOrbitalPosition,
+            Azimuth,
+            SetAzimuth,
+            Elevation,
+            SetElevation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDVBSLocator as windows_core::Interface>::IID || <IDigitalLocator_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDVBSLocator2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DiseqLNBSource(this: *mut core::ffi::c_void, diseqlnbsourceval: *mut super::LNB_Source) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSLocator2", "DiseqLNBSource", 32usize, |this| ((**(this as *const *const IDVBSLocator2_Vtbl)).DiseqLNBSource)(this, diseqlnbsourceval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDiseqLNBSource(this: *mut core::ffi::c_void, diseqlnbsourceval: super::LNB_Source) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSLocator2", "SetDiseqLNBSource", 33usize, |this| ((**(this as *const *const IDVBSLocator2_Vtbl)).SetDiseqLNBSource)(this, diseqlnbsourceval), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalOscillatorOverrideLow(this: *mut core::ffi::c_void, localoscillatoroverridelowval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSLocator2", "LocalOscillatorOverrideLow", 34usize, |this| ((**(this as *const *const IDVBSLocator2_Vtbl)).LocalOscillatorOverrideLow)(this, localoscillatoroverridelowval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocalOscillatorOverrideLow(this: *mut core::ffi::c_void, localoscillatoroverridelowval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSLocator2", "SetLocalOscillatorOverrideLow", 35usize, |this| ((**(this as *const *const IDVBSLocator2_Vtbl)).SetLocalOscillatorOverrideLow)(this, localoscillatoroverridelowval), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalOscillatorOverrideHigh(this: *mut core::ffi::c_void, localoscillatoroverridehighval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSLocator2", "LocalOscillatorOverrideHigh", 36usize, |this| ((**(this as *const *const IDVBSLocator2_Vtbl)).LocalOscillatorOverrideHigh)(this, localoscillatoroverridehighval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocalOscillatorOverrideHigh(this: *mut core::ffi::c_void, localoscillatoroverridehighval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSLocator2", "SetLocalOscillatorOverrideHigh", 37usize, |this| ((**(this as *const *const IDVBSLocator2_Vtbl)).SetLocalOscillatorOverrideHigh)(this, localoscillatoroverridehighval), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalLNBSwitchOverride(this: *mut core::ffi::c_void, locallnbswitchoverrideval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSLocator2", "LocalLNBSwitchOverride", 38usize, |this| ((**(this as *const *const IDVBSLocator2_Vtbl)).LocalLNBSwitchOverride)(this, locallnbswitchoverrideval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocalLNBSwitchOverride(this: *mut core::ffi::c_void, locallnbswitchoverrideval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSLocator2", "SetLocalLNBSwitchOverride", 39usize, |this| ((**(this as *const *const IDVBSLocator2_Vtbl)).SetLocalLNBSwitchOverride)(this, locallnbswitchoverrideval), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalSpectralInversionOverride(this: *mut core::ffi::c_void, localspectralinversionoverrideval: *mut super::SpectralInversion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSLocator2", "LocalSpectralInversionOverride", 40usize, |this| ((**(this as *const *const IDVBSLocator2_Vtbl)).LocalSpectralInversionOverride)(this, localspectralinversionoverrideval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocalSpectralInversionOverride(this: *mut core::ffi::c_void, localspectralinversionoverrideval: super::SpectralInversion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSLocator2", "SetLocalSpectralInversionOverride", 41usize, |this| ((**(this as *const *const IDVBSLocator2_Vtbl)).SetLocalSpectralInversionOverride)(this, localspectralinversionoverrideval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignalRollOff(this: *mut core::ffi::c_void, rolloffval: *mut super::RollOff) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSLocator2", "SignalRollOff", 42usize, |this| ((**(this as *const *const IDVBSLocator2_Vtbl)).SignalRollOff)(this, rolloffval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSignalRollOff(this: *mut core::ffi::c_void, rolloffval: super::RollOff) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSLocator2", "SetSignalRollOff", 43usize, |this| ((**(this as *const *const IDVBSLocator2_Vtbl)).SetSignalRollOff)(this, rolloffval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignalPilot(this: *mut core::ffi::c_void, pilotval: *mut super::Pilot) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSLocator2", "SignalPilot", 44usize, |this| ((**(this as *const *const IDVBSLocator2_Vtbl)).SignalPilot)(this, pilotval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSignalPilot(this: *mut core::ffi::c_void, pilotval: super::Pilot) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSLocator2", "SetSignalPilot", 45usize, |this| ((**(this as *const *const IDVBSLocator2_Vtbl)).SetSignalPilot)(this, pilotval), |result| Some(*result))
+        }
+        Self {
+            base__: <IDVBSLocator_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DiseqLNBSource,
+            SetDiseqLNBSource,
+            LocalOscillatorOverrideLow,
+            SetLocalOscillatorOverrideLow,
+            LocalOscillatorOverrideHigh,
+            SetLocalOscillatorOverrideHigh,
+            LocalLNBSwitchOverride,
+            SetLocalLNBSwitchOverride,
+            LocalSpectralInversionOverride,
+            SetLocalSpectralInversionOverride,
+            SignalRollOff,
+            SetSignalRollOff,
+            SignalPilot,
+            SetSignalPilot,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDVBSLocator2 as windows_core::Interface>::IID || <IDVBSLocator_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDVBSTuningSpace_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LowOscillator(this: *mut core::ffi::c_void, lowoscillator: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSTuningSpace", "LowOscillator", 30usize, |this| ((**(this as *const *const IDVBSTuningSpace_Vtbl)).LowOscillator)(this, lowoscillator), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLowOscillator(this: *mut core::ffi::c_void, lowoscillator: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSTuningSpace", "SetLowOscillator", 31usize, |this| ((**(this as *const *const IDVBSTuningSpace_Vtbl)).SetLowOscillator)(this, lowoscillator), |result| Some(*result))
+        }
+        unsafe extern "system" fn HighOscillator(this: *mut core::ffi::c_void, highoscillator: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSTuningSpace", "HighOscillator", 32usize, |this| ((**(this as *const *const IDVBSTuningSpace_Vtbl)).HighOscillator)(this, highoscillator), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHighOscillator(this: *mut core::ffi::c_void, highoscillator: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSTuningSpace", "SetHighOscillator", 33usize, |this| ((**(this as *const *const IDVBSTuningSpace_Vtbl)).SetHighOscillator)(this, highoscillator), |result| Some(*result))
+        }
+        unsafe extern "system" fn LNBSwitch(this: *mut core::ffi::c_void, lnbswitch: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSTuningSpace", "LNBSwitch", 34usize, |this| ((**(this as *const *const IDVBSTuningSpace_Vtbl)).LNBSwitch)(this, lnbswitch), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLNBSwitch(this: *mut core::ffi::c_void, lnbswitch: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSTuningSpace", "SetLNBSwitch", 35usize, |this| ((**(this as *const *const IDVBSTuningSpace_Vtbl)).SetLNBSwitch)(this, lnbswitch), |result| Some(*result))
+        }
+        unsafe extern "system" fn InputRange(this: *mut core::ffi::c_void, inputrange: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSTuningSpace", "InputRange", 36usize, |this| ((**(this as *const *const IDVBSTuningSpace_Vtbl)).InputRange)(this, inputrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInputRange(this: *mut core::ffi::c_void, inputrange: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSTuningSpace", "SetInputRange", 37usize, |this| ((**(this as *const *const IDVBSTuningSpace_Vtbl)).SetInputRange)(this, inputrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn SpectralInversion(this: *mut core::ffi::c_void, spectralinversionval: *mut super::SpectralInversion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSTuningSpace", "SpectralInversion", 38usize, |this| ((**(this as *const *const IDVBSTuningSpace_Vtbl)).SpectralInversion)(this, spectralinversionval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSpectralInversion(this: *mut core::ffi::c_void, spectralinversionval: super::SpectralInversion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBSTuningSpace", "SetSpectralInversion", 39usize, |this| ((**(this as *const *const IDVBSTuningSpace_Vtbl)).SetSpectralInversion)(this, spectralinversionval), |result| Some(*result))
+        }
+        Self {
+            base__: <IDVBTuningSpace2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LowOscillator,
+            SetLowOscillator,
+            HighOscillator,
+            SetHighOscillator,
+            LNBSwitch,
+            SetLNBSwitch,
+            InputRange,
+            SetInputRange,
+            SpectralInversion,
+            SetSpectralInversion,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDVBSTuningSpace as windows_core::Interface>::IID || <IDVBTuningSpace2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDVBTLocator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Bandwidth(this: *mut core::ffi::c_void, bandwidthval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBTLocator", "Bandwidth", 22usize, |this| ((**(this as *const *const IDVBTLocator_Vtbl)).Bandwidth)(this, bandwidthval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBandwidth(this: *mut core::ffi::c_void, bandwidthval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBTLocator", "SetBandwidth", 23usize, |this| ((**(this as *const *const IDVBTLocator_Vtbl)).SetBandwidth)(this, bandwidthval), |result| Some(*result))
+        }
+        unsafe extern "system" fn LPInnerFEC(this: *mut core::ffi::c_void, fec: *mut super::FECMethod) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBTLocator", "LPInnerFEC", 24usize, |this| ((**(this as *const *const IDVBTLocator_Vtbl)).LPInnerFEC)(this, fec), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLPInnerFEC(this: *mut core::ffi::c_void, fec: super::FECMethod) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBTLocator", "SetLPInnerFEC", 25usize, |this| ((**(this as *const *const IDVBTLocator_Vtbl)).SetLPInnerFEC)(this, fec), |result| Some(*result))
+        }
+        unsafe extern "system" fn LPInnerFECRate(this: *mut core::ffi::c_void, fec: *mut super::BinaryConvolutionCodeRate) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBTLocator", "LPInnerFECRate", 26usize, |this| ((**(this as *const *const IDVBTLocator_Vtbl)).LPInnerFECRate)(this, fec), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLPInnerFECRate(this: *mut core::ffi::c_void, fec: super::BinaryConvolutionCodeRate) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBTLocator", "SetLPInnerFECRate", 27usize, |this| ((**(this as *const *const IDVBTLocator_Vtbl)).SetLPInnerFECRate)(this, fec), |result| Some(*result))
+        }
+        unsafe extern "system" fn HAlpha(this: *mut core::ffi::c_void, alpha: *mut super::HierarchyAlpha) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBTLocator", "HAlpha", 28usize, |this| ((**(this as *const *const IDVBTLocator_Vtbl)).HAlpha)(this, alpha), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHAlpha(this: *mut core::ffi::c_void, alpha: super::HierarchyAlpha) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBTLocator", "SetHAlpha", 29usize, |this| ((**(this as *const *const IDVBTLocator_Vtbl)).SetHAlpha)(this, alpha), |result| Some(*result))
+        }
+        unsafe extern "system" fn Guard(this: *mut core::ffi::c_void, gi: *mut super::GuardInterval) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBTLocator", "Guard", 30usize, |this| ((**(this as *const *const IDVBTLocator_Vtbl)).Guard)(this, gi), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGuard(this: *mut core::ffi::c_void, gi: super::GuardInterval) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBTLocator", "SetGuard", 31usize, |this| ((**(this as *const *const IDVBTLocator_Vtbl)).SetGuard)(this, gi), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, mode: *mut super::TransmissionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBTLocator", "Mode", 32usize, |this| ((**(this as *const *const IDVBTLocator_Vtbl)).Mode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMode(this: *mut core::ffi::c_void, mode: super::TransmissionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBTLocator", "SetMode", 33usize, |this| ((**(this as *const *const IDVBTLocator_Vtbl)).SetMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn OtherFrequencyInUse(this: *mut core::ffi::c_void, otherfrequencyinuseval: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBTLocator", "OtherFrequencyInUse", 34usize, |this| ((**(this as *const *const IDVBTLocator_Vtbl)).OtherFrequencyInUse)(this, otherfrequencyinuseval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOtherFrequencyInUse(this: *mut core::ffi::c_void, otherfrequencyinuseval: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBTLocator", "SetOtherFrequencyInUse", 35usize, |this| ((**(this as *const *const IDVBTLocator_Vtbl)).SetOtherFrequencyInUse)(this, otherfrequencyinuseval), |result| Some(*result))
+        }
+        Self {
+            base__: <IDigitalLocator_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Bandwidth,
+            SetBandwidth,
+            LPInnerFEC,
+            SetLPInnerFEC,
+            LPInnerFECRate,
+            SetLPInnerFECRate,
+            HAlpha,
+            SetHAlpha,
+            Guard,
+            SetGuard,
+            Mode,
+            SetMode,
+            OtherFrequencyInUse,
+            SetOtherFrequencyInUse,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDVBTLocator as windows_core::Interface>::IID || <IDigitalLocator_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDVBTLocator2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PhysicalLayerPipeId(this: *mut core::ffi::c_void, physicallayerpipeidval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBTLocator2", "PhysicalLayerPipeId", 36usize, |this| ((**(this as *const *const IDVBTLocator2_Vtbl)).PhysicalLayerPipeId)(this, physicallayerpipeidval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPhysicalLayerPipeId(this: *mut core::ffi::c_void, physicallayerpipeidval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBTLocator2", "SetPhysicalLayerPipeId", 37usize, |this| ((**(this as *const *const IDVBTLocator2_Vtbl)).SetPhysicalLayerPipeId)(this, physicallayerpipeidval), |result| Some(*result))
+        }
+        Self { base__: <IDVBTLocator_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PhysicalLayerPipeId, SetPhysicalLayerPipeId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDVBTLocator2 as windows_core::Interface>::IID || <IDVBTLocator_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDVBTuneRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ONID(this: *mut core::ffi::c_void, onid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBTuneRequest", "ONID", 12usize, |this| ((**(this as *const *const IDVBTuneRequest_Vtbl)).ONID)(this, onid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetONID(this: *mut core::ffi::c_void, onid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBTuneRequest", "SetONID", 13usize, |this| ((**(this as *const *const IDVBTuneRequest_Vtbl)).SetONID)(this, onid), |result| Some(*result))
+        }
+        unsafe extern "system" fn TSID(this: *mut core::ffi::c_void, tsid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBTuneRequest", "TSID", 14usize, |this| ((**(this as *const *const IDVBTuneRequest_Vtbl)).TSID)(this, tsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTSID(this: *mut core::ffi::c_void, tsid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBTuneRequest", "SetTSID", 15usize, |this| ((**(this as *const *const IDVBTuneRequest_Vtbl)).SetTSID)(this, tsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SID(this: *mut core::ffi::c_void, sid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBTuneRequest", "SID", 16usize, |this| ((**(this as *const *const IDVBTuneRequest_Vtbl)).SID)(this, sid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSID(this: *mut core::ffi::c_void, sid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBTuneRequest", "SetSID", 17usize, |this| ((**(this as *const *const IDVBTuneRequest_Vtbl)).SetSID)(this, sid), |result| Some(*result))
+        }
+        Self { base__: <ITuneRequest_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ONID, SetONID, TSID, SetTSID, SID, SetSID }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDVBTuneRequest as windows_core::Interface>::IID || <ITuneRequest_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDVBTuningSpace_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SystemType(this: *mut core::ffi::c_void, systype: *mut super::DVBSystemType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBTuningSpace", "SystemType", 26usize, |this| ((**(this as *const *const IDVBTuningSpace_Vtbl)).SystemType)(this, systype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSystemType(this: *mut core::ffi::c_void, systype: super::DVBSystemType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBTuningSpace", "SetSystemType", 27usize, |this| ((**(this as *const *const IDVBTuningSpace_Vtbl)).SetSystemType)(this, systype), |result| Some(*result))
+        }
+        Self { base__: <ITuningSpace_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SystemType, SetSystemType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDVBTuningSpace as windows_core::Interface>::IID || <ITuningSpace_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDVBTuningSpace2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NetworkID(this: *mut core::ffi::c_void, networkid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBTuningSpace2", "NetworkID", 28usize, |this| ((**(this as *const *const IDVBTuningSpace2_Vtbl)).NetworkID)(this, networkid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNetworkID(this: *mut core::ffi::c_void, networkid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVBTuningSpace2", "SetNetworkID", 29usize, |this| ((**(this as *const *const IDVBTuningSpace2_Vtbl)).SetNetworkID)(this, networkid), |result| Some(*result))
+        }
+        Self { base__: <IDVBTuningSpace_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NetworkID, SetNetworkID }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDVBTuningSpace2 as windows_core::Interface>::IID || <IDVBTuningSpace_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDVB_BAT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psectionlist: *mut core::ffi::c_void, pmpegdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_BAT", "Initialize", 3usize, |this| ((**(this as *const *const IDVB_BAT_Vtbl)).Initialize)(this, psectionlist, pmpegdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionNumber(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_BAT", "GetVersionNumber", 4usize, |this| ((**(this as *const *const IDVB_BAT_Vtbl)).GetVersionNumber)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBouquetId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_BAT", "GetBouquetId", 5usize, |this| ((**(this as *const *const IDVB_BAT_Vtbl)).GetBouquetId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfTableDescriptors(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_BAT", "GetCountOfTableDescriptors", 6usize, |this| ((**(this as *const *const IDVB_BAT_Vtbl)).GetCountOfTableDescriptors)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableDescriptorByIndex(this: *mut core::ffi::c_void, dwindex: u32, ppdescriptor: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_BAT", "GetTableDescriptorByIndex", 7usize, |this| ((**(this as *const *const IDVB_BAT_Vtbl)).GetTableDescriptorByIndex)(this, dwindex, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableDescriptorByTag(this: *mut core::ffi::c_void, btag: u8, pdwcookie: *mut u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_BAT", "GetTableDescriptorByTag", 8usize, |this| ((**(this as *const *const IDVB_BAT_Vtbl)).GetTableDescriptorByTag)(this, btag, pdwcookie, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_BAT", "GetCountOfRecords", 9usize, |this| ((**(this as *const *const IDVB_BAT_Vtbl)).GetCountOfRecords)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordTransportStreamId(this: *mut core::ffi::c_void, dwrecordindex: u32, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_BAT", "GetRecordTransportStreamId", 10usize, |this| ((**(this as *const *const IDVB_BAT_Vtbl)).GetRecordTransportStreamId)(this, dwrecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordOriginalNetworkId(this: *mut core::ffi::c_void, dwrecordindex: u32, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_BAT", "GetRecordOriginalNetworkId", 11usize, |this| ((**(this as *const *const IDVB_BAT_Vtbl)).GetRecordOriginalNetworkId)(this, dwrecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordCountOfDescriptors(this: *mut core::ffi::c_void, dwrecordindex: u32, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_BAT", "GetRecordCountOfDescriptors", 12usize, |this| ((**(this as *const *const IDVB_BAT_Vtbl)).GetRecordCountOfDescriptors)(this, dwrecordindex, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptorByIndex(this: *mut core::ffi::c_void, dwrecordindex: u32, dwindex: u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_BAT", "GetRecordDescriptorByIndex", 13usize, |this| ((**(this as *const *const IDVB_BAT_Vtbl)).GetRecordDescriptorByIndex)(this, dwrecordindex, dwindex, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptorByTag(this: *mut core::ffi::c_void, dwrecordindex: u32, btag: u8, pdwcookie: *mut u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_BAT", "GetRecordDescriptorByTag", 14usize, |this| ((**(this as *const *const IDVB_BAT_Vtbl)).GetRecordDescriptorByTag)(this, dwrecordindex, btag, pdwcookie, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterForNextTable(this: *mut core::ffi::c_void, hnexttableavailable: super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_BAT", "RegisterForNextTable", 15usize, |this| ((**(this as *const *const IDVB_BAT_Vtbl)).RegisterForNextTable)(this, hnexttableavailable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextTable(this: *mut core::ffi::c_void, ppbat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_BAT", "GetNextTable", 16usize, |this| ((**(this as *const *const IDVB_BAT_Vtbl)).GetNextTable)(this, ppbat), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterForWhenCurrent(this: *mut core::ffi::c_void, hnexttableiscurrent: super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_BAT", "RegisterForWhenCurrent", 17usize, |this| ((**(this as *const *const IDVB_BAT_Vtbl)).RegisterForWhenCurrent)(this, hnexttableiscurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertNextToCurrent(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_BAT", "ConvertNextToCurrent", 18usize, |this| ((**(this as *const *const IDVB_BAT_Vtbl)).ConvertNextToCurrent)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetVersionNumber,
+            GetBouquetId,
+            GetCountOfTableDescriptors,
+            GetTableDescriptorByIndex,
+            GetTableDescriptorByTag,
+            GetCountOfRecords,
+            GetRecordTransportStreamId,
+            GetRecordOriginalNetworkId,
+            GetRecordCountOfDescriptors,
+            GetRecordDescriptorByIndex,
+            GetRecordDescriptorByTag,
+            RegisterForNextTable,
+            GetNextTable,
+            RegisterForWhenCurrent,
+            ConvertNextToCurrent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDVB_BAT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDVB_DIT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psectionlist: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_DIT", "Initialize", 3usize, |this| ((**(this as *const *const IDVB_DIT_Vtbl)).Initialize)(this, psectionlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransitionFlag(this: *mut core::ffi::c_void, pfval: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_DIT", "GetTransitionFlag", 4usize, |this| ((**(this as *const *const IDVB_DIT_Vtbl)).GetTransitionFlag)(this, pfval), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, GetTransitionFlag }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDVB_DIT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDVB_EIT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psectionlist: *mut core::ffi::c_void, pmpegdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_EIT", "Initialize", 3usize, |this| ((**(this as *const *const IDVB_EIT_Vtbl)).Initialize)(this, psectionlist, pmpegdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionNumber(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_EIT", "GetVersionNumber", 4usize, |this| ((**(this as *const *const IDVB_EIT_Vtbl)).GetVersionNumber)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServiceId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_EIT", "GetServiceId", 5usize, |this| ((**(this as *const *const IDVB_EIT_Vtbl)).GetServiceId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransportStreamId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_EIT", "GetTransportStreamId", 6usize, |this| ((**(this as *const *const IDVB_EIT_Vtbl)).GetTransportStreamId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOriginalNetworkId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_EIT", "GetOriginalNetworkId", 7usize, |this| ((**(this as *const *const IDVB_EIT_Vtbl)).GetOriginalNetworkId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSegmentLastSectionNumber(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_EIT", "GetSegmentLastSectionNumber", 8usize, |this| ((**(this as *const *const IDVB_EIT_Vtbl)).GetSegmentLastSectionNumber)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastTableId(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_EIT", "GetLastTableId", 9usize, |this| ((**(this as *const *const IDVB_EIT_Vtbl)).GetLastTableId)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_EIT", "GetCountOfRecords", 10usize, |this| ((**(this as *const *const IDVB_EIT_Vtbl)).GetCountOfRecords)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordEventId(this: *mut core::ffi::c_void, dwrecordindex: u32, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_EIT", "GetRecordEventId", 11usize, |this| ((**(this as *const *const IDVB_EIT_Vtbl)).GetRecordEventId)(this, dwrecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordStartTime(this: *mut core::ffi::c_void, dwrecordindex: u32, pmdtval: *mut MPEG_DATE_AND_TIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_EIT", "GetRecordStartTime", 12usize, |this| ((**(this as *const *const IDVB_EIT_Vtbl)).GetRecordStartTime)(this, dwrecordindex, pmdtval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDuration(this: *mut core::ffi::c_void, dwrecordindex: u32, pmdval: *mut MPEG_TIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_EIT", "GetRecordDuration", 13usize, |this| ((**(this as *const *const IDVB_EIT_Vtbl)).GetRecordDuration)(this, dwrecordindex, pmdval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordRunningStatus(this: *mut core::ffi::c_void, dwrecordindex: u32, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_EIT", "GetRecordRunningStatus", 14usize, |this| ((**(this as *const *const IDVB_EIT_Vtbl)).GetRecordRunningStatus)(this, dwrecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordFreeCAMode(this: *mut core::ffi::c_void, dwrecordindex: u32, pfval: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_EIT", "GetRecordFreeCAMode", 15usize, |this| ((**(this as *const *const IDVB_EIT_Vtbl)).GetRecordFreeCAMode)(this, dwrecordindex, pfval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordCountOfDescriptors(this: *mut core::ffi::c_void, dwrecordindex: u32, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_EIT", "GetRecordCountOfDescriptors", 16usize, |this| ((**(this as *const *const IDVB_EIT_Vtbl)).GetRecordCountOfDescriptors)(this, dwrecordindex, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptorByIndex(this: *mut core::ffi::c_void, dwrecordindex: u32, dwindex: u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_EIT", "GetRecordDescriptorByIndex", 17usize, |this| ((**(this as *const *const IDVB_EIT_Vtbl)).GetRecordDescriptorByIndex)(this, dwrecordindex, dwindex, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptorByTag(this: *mut core::ffi::c_void, dwrecordindex: u32, btag: u8, pdwcookie: *mut u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_EIT", "GetRecordDescriptorByTag", 18usize, |this| ((**(this as *const *const IDVB_EIT_Vtbl)).GetRecordDescriptorByTag)(this, dwrecordindex, btag, pdwcookie, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterForNextTable(this: *mut core::ffi::c_void, hnexttableavailable: super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_EIT", "RegisterForNextTable", 19usize, |this| ((**(this as *const *const IDVB_EIT_Vtbl)).RegisterForNextTable)(this, hnexttableavailable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextTable(this: *mut core::ffi::c_void, ppeit: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_EIT", "GetNextTable", 20usize, |this| ((**(this as *const *const IDVB_EIT_Vtbl)).GetNextTable)(this, ppeit), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterForWhenCurrent(this: *mut core::ffi::c_void, hnexttableiscurrent: super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_EIT", "RegisterForWhenCurrent", 21usize, |this| ((**(this as *const *const IDVB_EIT_Vtbl)).RegisterForWhenCurrent)(this, hnexttableiscurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertNextToCurrent(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_EIT", "ConvertNextToCurrent", 22usize, |this| ((**(this as *const *const IDVB_EIT_Vtbl)).ConvertNextToCurrent)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionHash(this: *mut core::ffi::c_void, pdwversionhash: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_EIT", "GetVersionHash", 23usize, |this| ((**(this as *const *const IDVB_EIT_Vtbl)).GetVersionHash)(this, pdwversionhash), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetVersionNumber,
+            GetServiceId,
+            GetTransportStreamId,
+            GetOriginalNetworkId,
+            GetSegmentLastSectionNumber,
+            GetLastTableId,
+            GetCountOfRecords,
+            GetRecordEventId,
+            GetRecordStartTime,
+            GetRecordDuration,
+            GetRecordRunningStatus,
+            GetRecordFreeCAMode,
+            GetRecordCountOfDescriptors,
+            GetRecordDescriptorByIndex,
+            GetRecordDescriptorByTag,
+            RegisterForNextTable,
+            GetNextTable,
+            RegisterForWhenCurrent,
+            ConvertNextToCurrent,
+            GetVersionHash,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDVB_EIT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDVB_EIT2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSegmentInfo(this: *mut core::ffi::c_void, pbtid: *mut u8, pbsegment: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_EIT2", "GetSegmentInfo", 24usize, |this| ((**(this as *const *const IDVB_EIT2_Vtbl)).GetSegmentInfo)(this, pbtid, pbsegment), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordSection(this: *mut core::ffi::c_void, dwrecordindex: u32, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_EIT2", "GetRecordSection", 25usize, |this| ((**(this as *const *const IDVB_EIT2_Vtbl)).GetRecordSection)(this, dwrecordindex, pbval), |result| Some(*result))
+        }
+        Self { base__: <IDVB_EIT_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSegmentInfo, GetRecordSection }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDVB_EIT2 as windows_core::Interface>::IID || <IDVB_EIT_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDVB_NIT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psectionlist: *mut core::ffi::c_void, pmpegdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_NIT", "Initialize", 3usize, |this| ((**(this as *const *const IDVB_NIT_Vtbl)).Initialize)(this, psectionlist, pmpegdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionNumber(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_NIT", "GetVersionNumber", 4usize, |this| ((**(this as *const *const IDVB_NIT_Vtbl)).GetVersionNumber)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNetworkId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_NIT", "GetNetworkId", 5usize, |this| ((**(this as *const *const IDVB_NIT_Vtbl)).GetNetworkId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfTableDescriptors(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_NIT", "GetCountOfTableDescriptors", 6usize, |this| ((**(this as *const *const IDVB_NIT_Vtbl)).GetCountOfTableDescriptors)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableDescriptorByIndex(this: *mut core::ffi::c_void, dwindex: u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_NIT", "GetTableDescriptorByIndex", 7usize, |this| ((**(this as *const *const IDVB_NIT_Vtbl)).GetTableDescriptorByIndex)(this, dwindex, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableDescriptorByTag(this: *mut core::ffi::c_void, btag: u8, pdwcookie: *mut u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_NIT", "GetTableDescriptorByTag", 8usize, |this| ((**(this as *const *const IDVB_NIT_Vtbl)).GetTableDescriptorByTag)(this, btag, pdwcookie, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_NIT", "GetCountOfRecords", 9usize, |this| ((**(this as *const *const IDVB_NIT_Vtbl)).GetCountOfRecords)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordTransportStreamId(this: *mut core::ffi::c_void, dwrecordindex: u32, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_NIT", "GetRecordTransportStreamId", 10usize, |this| ((**(this as *const *const IDVB_NIT_Vtbl)).GetRecordTransportStreamId)(this, dwrecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordOriginalNetworkId(this: *mut core::ffi::c_void, dwrecordindex: u32, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_NIT", "GetRecordOriginalNetworkId", 11usize, |this| ((**(this as *const *const IDVB_NIT_Vtbl)).GetRecordOriginalNetworkId)(this, dwrecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordCountOfDescriptors(this: *mut core::ffi::c_void, dwrecordindex: u32, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_NIT", "GetRecordCountOfDescriptors", 12usize, |this| ((**(this as *const *const IDVB_NIT_Vtbl)).GetRecordCountOfDescriptors)(this, dwrecordindex, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptorByIndex(this: *mut core::ffi::c_void, dwrecordindex: u32, dwindex: u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_NIT", "GetRecordDescriptorByIndex", 13usize, |this| ((**(this as *const *const IDVB_NIT_Vtbl)).GetRecordDescriptorByIndex)(this, dwrecordindex, dwindex, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptorByTag(this: *mut core::ffi::c_void, dwrecordindex: u32, btag: u8, pdwcookie: *mut u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_NIT", "GetRecordDescriptorByTag", 14usize, |this| ((**(this as *const *const IDVB_NIT_Vtbl)).GetRecordDescriptorByTag)(this, dwrecordindex, btag, pdwcookie, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterForNextTable(this: *mut core::ffi::c_void, hnexttableavailable: super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_NIT", "RegisterForNextTable", 15usize, |this| ((**(this as *const *const IDVB_NIT_Vtbl)).RegisterForNextTable)(this, hnexttableavailable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextTable(this: *mut core::ffi::c_void, ppnit: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_NIT", "GetNextTable", 16usize, |this| ((**(this as *const *const IDVB_NIT_Vtbl)).GetNextTable)(this, ppnit), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterForWhenCurrent(this: *mut core::ffi::c_void, hnexttableiscurrent: super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_NIT", "RegisterForWhenCurrent", 17usize, |this| ((**(this as *const *const IDVB_NIT_Vtbl)).RegisterForWhenCurrent)(this, hnexttableiscurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertNextToCurrent(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_NIT", "ConvertNextToCurrent", 18usize, |this| ((**(this as *const *const IDVB_NIT_Vtbl)).ConvertNextToCurrent)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionHash(this: *mut core::ffi::c_void, pdwversionhash: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_NIT", "GetVersionHash", 19usize, |this| ((**(this as *const *const IDVB_NIT_Vtbl)).GetVersionHash)(this, pdwversionhash), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetVersionNumber,
+            GetNetworkId,
+            GetCountOfTableDescriptors,
+            GetTableDescriptorByIndex,
+            GetTableDescriptorByTag,
+            GetCountOfRecords,
+            GetRecordTransportStreamId,
+            GetRecordOriginalNetworkId,
+            GetRecordCountOfDescriptors,
+            GetRecordDescriptorByIndex,
+            GetRecordDescriptorByTag,
+            RegisterForNextTable,
+            GetNextTable,
+            RegisterForWhenCurrent,
+            ConvertNextToCurrent,
+            GetVersionHash,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDVB_NIT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDVB_RST_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psectionlist: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_RST", "Initialize", 3usize, |this| ((**(this as *const *const IDVB_RST_Vtbl)).Initialize)(this, psectionlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_RST", "GetCountOfRecords", 4usize, |this| ((**(this as *const *const IDVB_RST_Vtbl)).GetCountOfRecords)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordTransportStreamId(this: *mut core::ffi::c_void, dwrecordindex: u32, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_RST", "GetRecordTransportStreamId", 5usize, |this| ((**(this as *const *const IDVB_RST_Vtbl)).GetRecordTransportStreamId)(this, dwrecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordOriginalNetworkId(this: *mut core::ffi::c_void, dwrecordindex: u32, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_RST", "GetRecordOriginalNetworkId", 6usize, |this| ((**(this as *const *const IDVB_RST_Vtbl)).GetRecordOriginalNetworkId)(this, dwrecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordServiceId(this: *mut core::ffi::c_void, dwrecordindex: u32, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_RST", "GetRecordServiceId", 7usize, |this| ((**(this as *const *const IDVB_RST_Vtbl)).GetRecordServiceId)(this, dwrecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordEventId(this: *mut core::ffi::c_void, dwrecordindex: u32, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_RST", "GetRecordEventId", 8usize, |this| ((**(this as *const *const IDVB_RST_Vtbl)).GetRecordEventId)(this, dwrecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordRunningStatus(this: *mut core::ffi::c_void, dwrecordindex: u32, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_RST", "GetRecordRunningStatus", 9usize, |this| ((**(this as *const *const IDVB_RST_Vtbl)).GetRecordRunningStatus)(this, dwrecordindex, pbval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetCountOfRecords,
+            GetRecordTransportStreamId,
+            GetRecordOriginalNetworkId,
+            GetRecordServiceId,
+            GetRecordEventId,
+            GetRecordRunningStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDVB_RST as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDVB_SDT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psectionlist: *mut core::ffi::c_void, pmpegdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SDT", "Initialize", 3usize, |this| ((**(this as *const *const IDVB_SDT_Vtbl)).Initialize)(this, psectionlist, pmpegdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionNumber(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SDT", "GetVersionNumber", 4usize, |this| ((**(this as *const *const IDVB_SDT_Vtbl)).GetVersionNumber)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransportStreamId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SDT", "GetTransportStreamId", 5usize, |this| ((**(this as *const *const IDVB_SDT_Vtbl)).GetTransportStreamId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOriginalNetworkId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SDT", "GetOriginalNetworkId", 6usize, |this| ((**(this as *const *const IDVB_SDT_Vtbl)).GetOriginalNetworkId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SDT", "GetCountOfRecords", 7usize, |this| ((**(this as *const *const IDVB_SDT_Vtbl)).GetCountOfRecords)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordServiceId(this: *mut core::ffi::c_void, dwrecordindex: u32, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SDT", "GetRecordServiceId", 8usize, |this| ((**(this as *const *const IDVB_SDT_Vtbl)).GetRecordServiceId)(this, dwrecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordEITScheduleFlag(this: *mut core::ffi::c_void, dwrecordindex: u32, pfval: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SDT", "GetRecordEITScheduleFlag", 9usize, |this| ((**(this as *const *const IDVB_SDT_Vtbl)).GetRecordEITScheduleFlag)(this, dwrecordindex, pfval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordEITPresentFollowingFlag(this: *mut core::ffi::c_void, dwrecordindex: u32, pfval: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SDT", "GetRecordEITPresentFollowingFlag", 10usize, |this| ((**(this as *const *const IDVB_SDT_Vtbl)).GetRecordEITPresentFollowingFlag)(this, dwrecordindex, pfval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordRunningStatus(this: *mut core::ffi::c_void, dwrecordindex: u32, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SDT", "GetRecordRunningStatus", 11usize, |this| ((**(this as *const *const IDVB_SDT_Vtbl)).GetRecordRunningStatus)(this, dwrecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordFreeCAMode(this: *mut core::ffi::c_void, dwrecordindex: u32, pfval: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SDT", "GetRecordFreeCAMode", 12usize, |this| ((**(this as *const *const IDVB_SDT_Vtbl)).GetRecordFreeCAMode)(this, dwrecordindex, pfval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordCountOfDescriptors(this: *mut core::ffi::c_void, dwrecordindex: u32, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SDT", "GetRecordCountOfDescriptors", 13usize, |this| ((**(this as *const *const IDVB_SDT_Vtbl)).GetRecordCountOfDescriptors)(this, dwrecordindex, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptorByIndex(this: *mut core::ffi::c_void, dwrecordindex: u32, dwindex: u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SDT", "GetRecordDescriptorByIndex", 14usize, |this| ((**(this as *const *const IDVB_SDT_Vtbl)).GetRecordDescriptorByIndex)(this, dwrecordindex, dwindex, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptorByTag(this: *mut core::ffi::c_void, dwrecordindex: u32, btag: u8, pdwcookie: *mut u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SDT", "GetRecordDescriptorByTag", 15usize, |this| ((**(this as *const *const IDVB_SDT_Vtbl)).GetRecordDescriptorByTag)(this, dwrecordindex, btag, pdwcookie, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterForNextTable(this: *mut core::ffi::c_void, hnexttableavailable: super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SDT", "RegisterForNextTable", 16usize, |this| ((**(this as *const *const IDVB_SDT_Vtbl)).RegisterForNextTable)(this, hnexttableavailable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextTable(this: *mut core::ffi::c_void, ppsdt: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SDT", "GetNextTable", 17usize, |this| ((**(this as *const *const IDVB_SDT_Vtbl)).GetNextTable)(this, ppsdt), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterForWhenCurrent(this: *mut core::ffi::c_void, hnexttableiscurrent: super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SDT", "RegisterForWhenCurrent", 18usize, |this| ((**(this as *const *const IDVB_SDT_Vtbl)).RegisterForWhenCurrent)(this, hnexttableiscurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertNextToCurrent(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SDT", "ConvertNextToCurrent", 19usize, |this| ((**(this as *const *const IDVB_SDT_Vtbl)).ConvertNextToCurrent)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionHash(this: *mut core::ffi::c_void, pdwversionhash: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SDT", "GetVersionHash", 20usize, |this| ((**(this as *const *const IDVB_SDT_Vtbl)).GetVersionHash)(this, pdwversionhash), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetVersionNumber,
+            GetTransportStreamId,
+            GetOriginalNetworkId,
+            GetCountOfRecords,
+            GetRecordServiceId,
+            GetRecordEITScheduleFlag,
+            GetRecordEITPresentFollowingFlag,
+            GetRecordRunningStatus,
+            GetRecordFreeCAMode,
+            GetRecordCountOfDescriptors,
+            GetRecordDescriptorByIndex,
+            GetRecordDescriptorByTag,
+            RegisterForNextTable,
+            GetNextTable,
+            RegisterForWhenCurrent,
+            ConvertNextToCurrent,
+            GetVersionHash,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDVB_SDT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDVB_SIT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psectionlist: *mut core::ffi::c_void, pmpegdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SIT", "Initialize", 3usize, |this| ((**(this as *const *const IDVB_SIT_Vtbl)).Initialize)(this, psectionlist, pmpegdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionNumber(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SIT", "GetVersionNumber", 4usize, |this| ((**(this as *const *const IDVB_SIT_Vtbl)).GetVersionNumber)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfTableDescriptors(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SIT", "GetCountOfTableDescriptors", 5usize, |this| ((**(this as *const *const IDVB_SIT_Vtbl)).GetCountOfTableDescriptors)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableDescriptorByIndex(this: *mut core::ffi::c_void, dwindex: u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SIT", "GetTableDescriptorByIndex", 6usize, |this| ((**(this as *const *const IDVB_SIT_Vtbl)).GetTableDescriptorByIndex)(this, dwindex, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableDescriptorByTag(this: *mut core::ffi::c_void, btag: u8, pdwcookie: *mut u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SIT", "GetTableDescriptorByTag", 7usize, |this| ((**(this as *const *const IDVB_SIT_Vtbl)).GetTableDescriptorByTag)(this, btag, pdwcookie, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SIT", "GetCountOfRecords", 8usize, |this| ((**(this as *const *const IDVB_SIT_Vtbl)).GetCountOfRecords)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordServiceId(this: *mut core::ffi::c_void, dwrecordindex: u32, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SIT", "GetRecordServiceId", 9usize, |this| ((**(this as *const *const IDVB_SIT_Vtbl)).GetRecordServiceId)(this, dwrecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordRunningStatus(this: *mut core::ffi::c_void, dwrecordindex: u32, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SIT", "GetRecordRunningStatus", 10usize, |this| ((**(this as *const *const IDVB_SIT_Vtbl)).GetRecordRunningStatus)(this, dwrecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordCountOfDescriptors(this: *mut core::ffi::c_void, dwrecordindex: u32, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SIT", "GetRecordCountOfDescriptors", 11usize, |this| ((**(this as *const *const IDVB_SIT_Vtbl)).GetRecordCountOfDescriptors)(this, dwrecordindex, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptorByIndex(this: *mut core::ffi::c_void, dwrecordindex: u32, dwindex: u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SIT", "GetRecordDescriptorByIndex", 12usize, |this| ((**(this as *const *const IDVB_SIT_Vtbl)).GetRecordDescriptorByIndex)(this, dwrecordindex, dwindex, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptorByTag(this: *mut core::ffi::c_void, dwrecordindex: u32, btag: u8, pdwcookie: *mut u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SIT", "GetRecordDescriptorByTag", 13usize, |this| ((**(this as *const *const IDVB_SIT_Vtbl)).GetRecordDescriptorByTag)(this, dwrecordindex, btag, pdwcookie, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterForNextTable(this: *mut core::ffi::c_void, hnexttableavailable: super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SIT", "RegisterForNextTable", 14usize, |this| ((**(this as *const *const IDVB_SIT_Vtbl)).RegisterForNextTable)(this, hnexttableavailable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextTable(this: *mut core::ffi::c_void, dwtimeout: u32, ppsit: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SIT", "GetNextTable", 15usize, |this| ((**(this as *const *const IDVB_SIT_Vtbl)).GetNextTable)(this, dwtimeout, ppsit), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterForWhenCurrent(this: *mut core::ffi::c_void, hnexttableiscurrent: super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SIT", "RegisterForWhenCurrent", 16usize, |this| ((**(this as *const *const IDVB_SIT_Vtbl)).RegisterForWhenCurrent)(this, hnexttableiscurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertNextToCurrent(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_SIT", "ConvertNextToCurrent", 17usize, |this| ((**(this as *const *const IDVB_SIT_Vtbl)).ConvertNextToCurrent)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetVersionNumber,
+            GetCountOfTableDescriptors,
+            GetTableDescriptorByIndex,
+            GetTableDescriptorByTag,
+            GetCountOfRecords,
+            GetRecordServiceId,
+            GetRecordRunningStatus,
+            GetRecordCountOfDescriptors,
+            GetRecordDescriptorByIndex,
+            GetRecordDescriptorByTag,
+            RegisterForNextTable,
+            GetNextTable,
+            RegisterForWhenCurrent,
+            ConvertNextToCurrent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDVB_SIT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDVB_ST_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psectionlist: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_ST", "Initialize", 3usize, |this| ((**(this as *const *const IDVB_ST_Vtbl)).Initialize)(this, psectionlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataLength(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_ST", "GetDataLength", 4usize, |this| ((**(this as *const *const IDVB_ST_Vtbl)).GetDataLength)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetData(this: *mut core::ffi::c_void, ppdata: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_ST", "GetData", 5usize, |this| ((**(this as *const *const IDVB_ST_Vtbl)).GetData)(this, ppdata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, GetDataLength, GetData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDVB_ST as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDVB_TDT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psectionlist: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_TDT", "Initialize", 3usize, |this| ((**(this as *const *const IDVB_TDT_Vtbl)).Initialize)(this, psectionlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUTCTime(this: *mut core::ffi::c_void, pmdtval: *mut MPEG_DATE_AND_TIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_TDT", "GetUTCTime", 4usize, |this| ((**(this as *const *const IDVB_TDT_Vtbl)).GetUTCTime)(this, pmdtval), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, GetUTCTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDVB_TDT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDVB_TOT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psectionlist: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_TOT", "Initialize", 3usize, |this| ((**(this as *const *const IDVB_TOT_Vtbl)).Initialize)(this, psectionlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUTCTime(this: *mut core::ffi::c_void, pmdtval: *mut MPEG_DATE_AND_TIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_TOT", "GetUTCTime", 4usize, |this| ((**(this as *const *const IDVB_TOT_Vtbl)).GetUTCTime)(this, pmdtval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfTableDescriptors(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_TOT", "GetCountOfTableDescriptors", 5usize, |this| ((**(this as *const *const IDVB_TOT_Vtbl)).GetCountOfTableDescriptors)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableDescriptorByIndex(this: *mut core::ffi::c_void, dwindex: u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_TOT", "GetTableDescriptorByIndex", 6usize, |this| ((**(this as *const *const IDVB_TOT_Vtbl)).GetTableDescriptorByIndex)(this, dwindex, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableDescriptorByTag(this: *mut core::ffi::c_void, btag: u8, pdwcookie: *mut u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVB_TOT", "GetTableDescriptorByTag", 7usize, |this| ((**(this as *const *const IDVB_TOT_Vtbl)).GetTableDescriptorByTag)(this, btag, pdwcookie, ppdescriptor), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetUTCTime,
+            GetCountOfTableDescriptors,
+            GetTableDescriptorByIndex,
+            GetTableDescriptorByTag,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDVB_TOT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDigitalCableLocator_Vtbl {
+    const PROXY: Self = { Self { base__: <IATSCLocator2_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDigitalCableLocator as windows_core::Interface>::IID || <IATSCLocator2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDigitalCableTuneRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MajorChannel(this: *mut core::ffi::c_void, pmajorchannel: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalCableTuneRequest", "MajorChannel", 16usize, |this| ((**(this as *const *const IDigitalCableTuneRequest_Vtbl)).MajorChannel)(this, pmajorchannel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMajorChannel(this: *mut core::ffi::c_void, majorchannel: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalCableTuneRequest", "SetMajorChannel", 17usize, |this| ((**(this as *const *const IDigitalCableTuneRequest_Vtbl)).SetMajorChannel)(this, majorchannel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceID(this: *mut core::ffi::c_void, psourceid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalCableTuneRequest", "SourceID", 18usize, |this| ((**(this as *const *const IDigitalCableTuneRequest_Vtbl)).SourceID)(this, psourceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSourceID(this: *mut core::ffi::c_void, sourceid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalCableTuneRequest", "SetSourceID", 19usize, |this| ((**(this as *const *const IDigitalCableTuneRequest_Vtbl)).SetSourceID)(this, sourceid), |result| Some(*result))
+        }
+        Self { base__: <IATSCChannelTuneRequest_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MajorChannel, SetMajorChannel, SourceID, SetSourceID }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDigitalCableTuneRequest as windows_core::Interface>::IID || <IATSCChannelTuneRequest_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDigitalCableTuningSpace_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MinMajorChannel(this: *mut core::ffi::c_void, minmajorchannelval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalCableTuningSpace", "MinMajorChannel", 42usize, |this| ((**(this as *const *const IDigitalCableTuningSpace_Vtbl)).MinMajorChannel)(this, minmajorchannelval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinMajorChannel(this: *mut core::ffi::c_void, newminmajorchannelval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalCableTuningSpace", "SetMinMajorChannel", 43usize, |this| ((**(this as *const *const IDigitalCableTuningSpace_Vtbl)).SetMinMajorChannel)(this, newminmajorchannelval), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxMajorChannel(this: *mut core::ffi::c_void, maxmajorchannelval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalCableTuningSpace", "MaxMajorChannel", 44usize, |this| ((**(this as *const *const IDigitalCableTuningSpace_Vtbl)).MaxMajorChannel)(this, maxmajorchannelval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxMajorChannel(this: *mut core::ffi::c_void, newmaxmajorchannelval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalCableTuningSpace", "SetMaxMajorChannel", 45usize, |this| ((**(this as *const *const IDigitalCableTuningSpace_Vtbl)).SetMaxMajorChannel)(this, newmaxmajorchannelval), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinSourceID(this: *mut core::ffi::c_void, minsourceidval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalCableTuningSpace", "MinSourceID", 46usize, |this| ((**(this as *const *const IDigitalCableTuningSpace_Vtbl)).MinSourceID)(this, minsourceidval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinSourceID(this: *mut core::ffi::c_void, newminsourceidval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalCableTuningSpace", "SetMinSourceID", 47usize, |this| ((**(this as *const *const IDigitalCableTuningSpace_Vtbl)).SetMinSourceID)(this, newminsourceidval), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxSourceID(this: *mut core::ffi::c_void, maxsourceidval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalCableTuningSpace", "MaxSourceID", 48usize, |this| ((**(this as *const *const IDigitalCableTuningSpace_Vtbl)).MaxSourceID)(this, maxsourceidval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxSourceID(this: *mut core::ffi::c_void, newmaxsourceidval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDigitalCableTuningSpace", "SetMaxSourceID", 49usize, |this| ((**(this as *const *const IDigitalCableTuningSpace_Vtbl)).SetMaxSourceID)(this, newmaxsourceidval), |result| Some(*result))
+        }
+        Self {
+            base__: <IATSCTuningSpace_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MinMajorChannel,
+            SetMinMajorChannel,
+            MaxMajorChannel,
+            SetMaxMajorChannel,
+            MinSourceID,
+            SetMinSourceID,
+            MaxSourceID,
+            SetMaxSourceID,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDigitalCableTuningSpace as windows_core::Interface>::IID || <IATSCTuningSpace_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDigitalLocator_Vtbl {
+    const PROXY: Self = { Self { base__: <ILocator_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDigitalLocator as windows_core::Interface>::IID || <ILocator_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbCableDeliverySystemDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbCableDeliverySystemDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IDvbCableDeliverySystemDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbCableDeliverySystemDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IDvbCableDeliverySystemDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFrequency(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbCableDeliverySystemDescriptor", "GetFrequency", 5usize, |this| ((**(this as *const *const IDvbCableDeliverySystemDescriptor_Vtbl)).GetFrequency)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFECOuter(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbCableDeliverySystemDescriptor", "GetFECOuter", 6usize, |this| ((**(this as *const *const IDvbCableDeliverySystemDescriptor_Vtbl)).GetFECOuter)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetModulation(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbCableDeliverySystemDescriptor", "GetModulation", 7usize, |this| ((**(this as *const *const IDvbCableDeliverySystemDescriptor_Vtbl)).GetModulation)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSymbolRate(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbCableDeliverySystemDescriptor", "GetSymbolRate", 8usize, |this| ((**(this as *const *const IDvbCableDeliverySystemDescriptor_Vtbl)).GetSymbolRate)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFECInner(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbCableDeliverySystemDescriptor", "GetFECInner", 9usize, |this| ((**(this as *const *const IDvbCableDeliverySystemDescriptor_Vtbl)).GetFECInner)(this, pbval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetFrequency,
+            GetFECOuter,
+            GetModulation,
+            GetSymbolRate,
+            GetFECInner,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbCableDeliverySystemDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbComponentDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbComponentDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IDvbComponentDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbComponentDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IDvbComponentDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamContent(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbComponentDescriptor", "GetStreamContent", 5usize, |this| ((**(this as *const *const IDvbComponentDescriptor_Vtbl)).GetStreamContent)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetComponentType(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbComponentDescriptor", "GetComponentType", 6usize, |this| ((**(this as *const *const IDvbComponentDescriptor_Vtbl)).GetComponentType)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetComponentTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbComponentDescriptor", "GetComponentTag", 7usize, |this| ((**(this as *const *const IDvbComponentDescriptor_Vtbl)).GetComponentTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLanguageCode(this: *mut core::ffi::c_void, pszcode: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbComponentDescriptor", "GetLanguageCode", 8usize, |this| ((**(this as *const *const IDvbComponentDescriptor_Vtbl)).GetLanguageCode)(this, pszcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTextW(this: *mut core::ffi::c_void, convmode: DVB_STRCONV_MODE, pbstrtext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbComponentDescriptor", "GetTextW", 9usize, |this| ((**(this as *const *const IDvbComponentDescriptor_Vtbl)).GetTextW)(this, convmode, pbstrtext), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetStreamContent,
+            GetComponentType,
+            GetComponentTag,
+            GetLanguageCode,
+            GetTextW,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbComponentDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbContentDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbContentDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IDvbContentDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbContentDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IDvbContentDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbContentDescriptor", "GetCountOfRecords", 5usize, |this| ((**(this as *const *const IDvbContentDescriptor_Vtbl)).GetCountOfRecords)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordContentNibbles(this: *mut core::ffi::c_void, brecordindex: u8, pbvallevel1: *mut u8, pbvallevel2: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbContentDescriptor", "GetRecordContentNibbles", 6usize, |this| ((**(this as *const *const IDvbContentDescriptor_Vtbl)).GetRecordContentNibbles)(this, brecordindex, pbvallevel1, pbvallevel2), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordUserNibbles(this: *mut core::ffi::c_void, brecordindex: u8, pbval1: *mut u8, pbval2: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbContentDescriptor", "GetRecordUserNibbles", 7usize, |this| ((**(this as *const *const IDvbContentDescriptor_Vtbl)).GetRecordUserNibbles)(this, brecordindex, pbval1, pbval2), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetCountOfRecords,
+            GetRecordContentNibbles,
+            GetRecordUserNibbles,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbContentDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbContentIdentifierDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbContentIdentifierDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IDvbContentIdentifierDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbContentIdentifierDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IDvbContentIdentifierDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbContentIdentifierDescriptor", "GetCountOfRecords", 5usize, |this| ((**(this as *const *const IDvbContentIdentifierDescriptor_Vtbl)).GetCountOfRecords)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordCrid(this: *mut core::ffi::c_void, brecordindex: u8, pbtype: *mut u8, pblocation: *mut u8, pblength: *mut u8, ppbbytes: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbContentIdentifierDescriptor", "GetRecordCrid", 6usize, |this| ((**(this as *const *const IDvbContentIdentifierDescriptor_Vtbl)).GetRecordCrid)(this, brecordindex, pbtype, pblocation, pblength, ppbbytes), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetTag, GetLength, GetCountOfRecords, GetRecordCrid }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbContentIdentifierDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbDataBroadcastDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbDataBroadcastDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IDvbDataBroadcastDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbDataBroadcastDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IDvbDataBroadcastDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataBroadcastID(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbDataBroadcastDescriptor", "GetDataBroadcastID", 5usize, |this| ((**(this as *const *const IDvbDataBroadcastDescriptor_Vtbl)).GetDataBroadcastID)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetComponentTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbDataBroadcastDescriptor", "GetComponentTag", 6usize, |this| ((**(this as *const *const IDvbDataBroadcastDescriptor_Vtbl)).GetComponentTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSelectorLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbDataBroadcastDescriptor", "GetSelectorLength", 7usize, |this| ((**(this as *const *const IDvbDataBroadcastDescriptor_Vtbl)).GetSelectorLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSelectorBytes(this: *mut core::ffi::c_void, pblen: *mut u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbDataBroadcastDescriptor", "GetSelectorBytes", 8usize, |this| ((**(this as *const *const IDvbDataBroadcastDescriptor_Vtbl)).GetSelectorBytes)(this, pblen, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLangID(this: *mut core::ffi::c_void, pulval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbDataBroadcastDescriptor", "GetLangID", 9usize, |this| ((**(this as *const *const IDvbDataBroadcastDescriptor_Vtbl)).GetLangID)(this, pulval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTextLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbDataBroadcastDescriptor", "GetTextLength", 10usize, |this| ((**(this as *const *const IDvbDataBroadcastDescriptor_Vtbl)).GetTextLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetText(this: *mut core::ffi::c_void, pblen: *mut u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbDataBroadcastDescriptor", "GetText", 11usize, |this| ((**(this as *const *const IDvbDataBroadcastDescriptor_Vtbl)).GetText)(this, pblen, pbval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetDataBroadcastID,
+            GetComponentTag,
+            GetSelectorLength,
+            GetSelectorBytes,
+            GetLangID,
+            GetTextLength,
+            GetText,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbDataBroadcastDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbDataBroadcastIDDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbDataBroadcastIDDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IDvbDataBroadcastIDDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbDataBroadcastIDDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IDvbDataBroadcastIDDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataBroadcastID(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbDataBroadcastIDDescriptor", "GetDataBroadcastID", 5usize, |this| ((**(this as *const *const IDvbDataBroadcastIDDescriptor_Vtbl)).GetDataBroadcastID)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIDSelectorBytes(this: *mut core::ffi::c_void, pblen: *mut u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbDataBroadcastIDDescriptor", "GetIDSelectorBytes", 6usize, |this| ((**(this as *const *const IDvbDataBroadcastIDDescriptor_Vtbl)).GetIDSelectorBytes)(this, pblen, pbval), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetTag, GetLength, GetDataBroadcastID, GetIDSelectorBytes }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbDataBroadcastIDDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbDefaultAuthorityDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbDefaultAuthorityDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IDvbDefaultAuthorityDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbDefaultAuthorityDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IDvbDefaultAuthorityDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultAuthority(this: *mut core::ffi::c_void, pblength: *mut u8, ppbbytes: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbDefaultAuthorityDescriptor", "GetDefaultAuthority", 5usize, |this| ((**(this as *const *const IDvbDefaultAuthorityDescriptor_Vtbl)).GetDefaultAuthority)(this, pblength, ppbbytes), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetTag, GetLength, GetDefaultAuthority }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbDefaultAuthorityDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbExtendedEventDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbExtendedEventDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IDvbExtendedEventDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbExtendedEventDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IDvbExtendedEventDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescriptorNumber(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbExtendedEventDescriptor", "GetDescriptorNumber", 5usize, |this| ((**(this as *const *const IDvbExtendedEventDescriptor_Vtbl)).GetDescriptorNumber)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastDescriptorNumber(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbExtendedEventDescriptor", "GetLastDescriptorNumber", 6usize, |this| ((**(this as *const *const IDvbExtendedEventDescriptor_Vtbl)).GetLastDescriptorNumber)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLanguageCode(this: *mut core::ffi::c_void, pszcode: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbExtendedEventDescriptor", "GetLanguageCode", 7usize, |this| ((**(this as *const *const IDvbExtendedEventDescriptor_Vtbl)).GetLanguageCode)(this, pszcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbExtendedEventDescriptor", "GetCountOfRecords", 8usize, |this| ((**(this as *const *const IDvbExtendedEventDescriptor_Vtbl)).GetCountOfRecords)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordItemW(this: *mut core::ffi::c_void, brecordindex: u8, convmode: DVB_STRCONV_MODE, pbstrdesc: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstritem: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbExtendedEventDescriptor", "GetRecordItemW", 9usize, |this| ((**(this as *const *const IDvbExtendedEventDescriptor_Vtbl)).GetRecordItemW)(this, brecordindex, convmode, pbstrdesc, pbstritem), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConcatenatedItemW(this: *mut core::ffi::c_void, pfollowingdescriptor: *mut core::ffi::c_void, convmode: DVB_STRCONV_MODE, pbstrdesc: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstritem: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbExtendedEventDescriptor", "GetConcatenatedItemW", 10usize, |this| ((**(this as *const *const IDvbExtendedEventDescriptor_Vtbl)).GetConcatenatedItemW)(this, pfollowingdescriptor, convmode, pbstrdesc, pbstritem), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTextW(this: *mut core::ffi::c_void, convmode: DVB_STRCONV_MODE, pbstrtext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbExtendedEventDescriptor", "GetTextW", 11usize, |this| ((**(this as *const *const IDvbExtendedEventDescriptor_Vtbl)).GetTextW)(this, convmode, pbstrtext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConcatenatedTextW(this: *mut core::ffi::c_void, followingdescriptor: *mut core::ffi::c_void, convmode: DVB_STRCONV_MODE, pbstrtext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbExtendedEventDescriptor", "GetConcatenatedTextW", 12usize, |this| ((**(this as *const *const IDvbExtendedEventDescriptor_Vtbl)).GetConcatenatedTextW)(this, followingdescriptor, convmode, pbstrtext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordItemRawBytes(this: *mut core::ffi::c_void, brecordindex: u8, ppbrawitem: *mut *mut u8, pbitemlength: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbExtendedEventDescriptor", "GetRecordItemRawBytes", 13usize, |this| ((**(this as *const *const IDvbExtendedEventDescriptor_Vtbl)).GetRecordItemRawBytes)(this, brecordindex, ppbrawitem, pbitemlength), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetDescriptorNumber,
+            GetLastDescriptorNumber,
+            GetLanguageCode,
+            GetCountOfRecords,
+            GetRecordItemW,
+            GetConcatenatedItemW,
+            GetTextW,
+            GetConcatenatedTextW,
+            GetRecordItemRawBytes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbExtendedEventDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbFrequencyListDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbFrequencyListDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IDvbFrequencyListDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbFrequencyListDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IDvbFrequencyListDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCodingType(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbFrequencyListDescriptor", "GetCodingType", 5usize, |this| ((**(this as *const *const IDvbFrequencyListDescriptor_Vtbl)).GetCodingType)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbFrequencyListDescriptor", "GetCountOfRecords", 6usize, |this| ((**(this as *const *const IDvbFrequencyListDescriptor_Vtbl)).GetCountOfRecords)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordCentreFrequency(this: *mut core::ffi::c_void, brecordindex: u8, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbFrequencyListDescriptor", "GetRecordCentreFrequency", 7usize, |this| ((**(this as *const *const IDvbFrequencyListDescriptor_Vtbl)).GetRecordCentreFrequency)(this, brecordindex, pdwval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetCodingType,
+            GetCountOfRecords,
+            GetRecordCentreFrequency,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbFrequencyListDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbHDSimulcastLogicalChannelDescriptor_Vtbl {
+    const PROXY: Self = { Self { base__: <IDvbLogicalChannelDescriptor2_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbHDSimulcastLogicalChannelDescriptor as windows_core::Interface>::IID || <IDvbLogicalChannelDescriptor2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbLinkageDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbLinkageDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IDvbLinkageDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbLinkageDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IDvbLinkageDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTSId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbLinkageDescriptor", "GetTSId", 5usize, |this| ((**(this as *const *const IDvbLinkageDescriptor_Vtbl)).GetTSId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetONId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbLinkageDescriptor", "GetONId", 6usize, |this| ((**(this as *const *const IDvbLinkageDescriptor_Vtbl)).GetONId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServiceId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbLinkageDescriptor", "GetServiceId", 7usize, |this| ((**(this as *const *const IDvbLinkageDescriptor_Vtbl)).GetServiceId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLinkageType(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbLinkageDescriptor", "GetLinkageType", 8usize, |this| ((**(this as *const *const IDvbLinkageDescriptor_Vtbl)).GetLinkageType)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrivateDataLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbLinkageDescriptor", "GetPrivateDataLength", 9usize, |this| ((**(this as *const *const IDvbLinkageDescriptor_Vtbl)).GetPrivateDataLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrivateData(this: *mut core::ffi::c_void, pblen: *mut u8, pbdata: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbLinkageDescriptor", "GetPrivateData", 10usize, |this| ((**(this as *const *const IDvbLinkageDescriptor_Vtbl)).GetPrivateData)(this, pblen, pbdata), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetTSId,
+            GetONId,
+            GetServiceId,
+            GetLinkageType,
+            GetPrivateDataLength,
+            GetPrivateData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbLinkageDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbLogicalChannel2Descriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCountOfLists(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbLogicalChannel2Descriptor", "GetCountOfLists", 9usize, |this| ((**(this as *const *const IDvbLogicalChannel2Descriptor_Vtbl)).GetCountOfLists)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetListId(this: *mut core::ffi::c_void, blistindex: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbLogicalChannel2Descriptor", "GetListId", 10usize, |this| ((**(this as *const *const IDvbLogicalChannel2Descriptor_Vtbl)).GetListId)(this, blistindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetListNameW(this: *mut core::ffi::c_void, blistindex: u8, convmode: DVB_STRCONV_MODE, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbLogicalChannel2Descriptor", "GetListNameW", 11usize, |this| ((**(this as *const *const IDvbLogicalChannel2Descriptor_Vtbl)).GetListNameW)(this, blistindex, convmode, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetListCountryCode(this: *mut core::ffi::c_void, blistindex: u8, pszcode: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbLogicalChannel2Descriptor", "GetListCountryCode", 12usize, |this| ((**(this as *const *const IDvbLogicalChannel2Descriptor_Vtbl)).GetListCountryCode)(this, blistindex, pszcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetListCountOfRecords(this: *mut core::ffi::c_void, bchannellistindex: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbLogicalChannel2Descriptor", "GetListCountOfRecords", 13usize, |this| ((**(this as *const *const IDvbLogicalChannel2Descriptor_Vtbl)).GetListCountOfRecords)(this, bchannellistindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetListRecordServiceId(this: *mut core::ffi::c_void, blistindex: u8, brecordindex: u8, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbLogicalChannel2Descriptor", "GetListRecordServiceId", 14usize, |this| ((**(this as *const *const IDvbLogicalChannel2Descriptor_Vtbl)).GetListRecordServiceId)(this, blistindex, brecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetListRecordLogicalChannelNumber(this: *mut core::ffi::c_void, blistindex: u8, brecordindex: u8, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbLogicalChannel2Descriptor", "GetListRecordLogicalChannelNumber", 15usize, |this| ((**(this as *const *const IDvbLogicalChannel2Descriptor_Vtbl)).GetListRecordLogicalChannelNumber)(this, blistindex, brecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetListRecordLogicalChannelAndVisibility(this: *mut core::ffi::c_void, blistindex: u8, brecordindex: u8, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbLogicalChannel2Descriptor", "GetListRecordLogicalChannelAndVisibility", 16usize, |this| ((**(this as *const *const IDvbLogicalChannel2Descriptor_Vtbl)).GetListRecordLogicalChannelAndVisibility)(this, blistindex, brecordindex, pwval), |result| Some(*result))
+        }
+        Self {
+            base__: <IDvbLogicalChannelDescriptor2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCountOfLists,
+            GetListId,
+            GetListNameW,
+            GetListCountryCode,
+            GetListCountOfRecords,
+            GetListRecordServiceId,
+            GetListRecordLogicalChannelNumber,
+            GetListRecordLogicalChannelAndVisibility,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbLogicalChannel2Descriptor as windows_core::Interface>::IID || <IDvbLogicalChannelDescriptor2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbLogicalChannelDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbLogicalChannelDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IDvbLogicalChannelDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbLogicalChannelDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IDvbLogicalChannelDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbLogicalChannelDescriptor", "GetCountOfRecords", 5usize, |this| ((**(this as *const *const IDvbLogicalChannelDescriptor_Vtbl)).GetCountOfRecords)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordServiceId(this: *mut core::ffi::c_void, brecordindex: u8, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbLogicalChannelDescriptor", "GetRecordServiceId", 6usize, |this| ((**(this as *const *const IDvbLogicalChannelDescriptor_Vtbl)).GetRecordServiceId)(this, brecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordLogicalChannelNumber(this: *mut core::ffi::c_void, brecordindex: u8, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbLogicalChannelDescriptor", "GetRecordLogicalChannelNumber", 7usize, |this| ((**(this as *const *const IDvbLogicalChannelDescriptor_Vtbl)).GetRecordLogicalChannelNumber)(this, brecordindex, pwval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetCountOfRecords,
+            GetRecordServiceId,
+            GetRecordLogicalChannelNumber,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbLogicalChannelDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbLogicalChannelDescriptor2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRecordLogicalChannelAndVisibility(this: *mut core::ffi::c_void, brecordindex: u8, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbLogicalChannelDescriptor2", "GetRecordLogicalChannelAndVisibility", 8usize, |this| ((**(this as *const *const IDvbLogicalChannelDescriptor2_Vtbl)).GetRecordLogicalChannelAndVisibility)(this, brecordindex, pwval), |result| Some(*result))
+        }
+        Self { base__: <IDvbLogicalChannelDescriptor_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRecordLogicalChannelAndVisibility }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbLogicalChannelDescriptor2 as windows_core::Interface>::IID || <IDvbLogicalChannelDescriptor_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbMultilingualServiceNameDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbMultilingualServiceNameDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IDvbMultilingualServiceNameDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbMultilingualServiceNameDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IDvbMultilingualServiceNameDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbMultilingualServiceNameDescriptor", "GetCountOfRecords", 5usize, |this| ((**(this as *const *const IDvbMultilingualServiceNameDescriptor_Vtbl)).GetCountOfRecords)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordLangId(this: *mut core::ffi::c_void, brecordindex: u8, ulval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbMultilingualServiceNameDescriptor", "GetRecordLangId", 6usize, |this| ((**(this as *const *const IDvbMultilingualServiceNameDescriptor_Vtbl)).GetRecordLangId)(this, brecordindex, ulval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordServiceProviderNameW(this: *mut core::ffi::c_void, brecordindex: u8, convmode: DVB_STRCONV_MODE, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbMultilingualServiceNameDescriptor", "GetRecordServiceProviderNameW", 7usize, |this| ((**(this as *const *const IDvbMultilingualServiceNameDescriptor_Vtbl)).GetRecordServiceProviderNameW)(this, brecordindex, convmode, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordServiceNameW(this: *mut core::ffi::c_void, brecordindex: u8, convmode: DVB_STRCONV_MODE, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbMultilingualServiceNameDescriptor", "GetRecordServiceNameW", 8usize, |this| ((**(this as *const *const IDvbMultilingualServiceNameDescriptor_Vtbl)).GetRecordServiceNameW)(this, brecordindex, convmode, pbstrname), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetCountOfRecords,
+            GetRecordLangId,
+            GetRecordServiceProviderNameW,
+            GetRecordServiceNameW,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbMultilingualServiceNameDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbNetworkNameDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbNetworkNameDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IDvbNetworkNameDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbNetworkNameDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IDvbNetworkNameDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNetworkName(this: *mut core::ffi::c_void, pszname: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbNetworkNameDescriptor", "GetNetworkName", 5usize, |this| ((**(this as *const *const IDvbNetworkNameDescriptor_Vtbl)).GetNetworkName)(this, pszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNetworkNameW(this: *mut core::ffi::c_void, convmode: DVB_STRCONV_MODE, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbNetworkNameDescriptor", "GetNetworkNameW", 6usize, |this| ((**(this as *const *const IDvbNetworkNameDescriptor_Vtbl)).GetNetworkNameW)(this, convmode, pbstrname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetTag, GetLength, GetNetworkName, GetNetworkNameW }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbNetworkNameDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbParentalRatingDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbParentalRatingDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IDvbParentalRatingDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbParentalRatingDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IDvbParentalRatingDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbParentalRatingDescriptor", "GetCountOfRecords", 5usize, |this| ((**(this as *const *const IDvbParentalRatingDescriptor_Vtbl)).GetCountOfRecords)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordRating(this: *mut core::ffi::c_void, brecordindex: u8, pszcountrycode: *mut u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbParentalRatingDescriptor", "GetRecordRating", 6usize, |this| ((**(this as *const *const IDvbParentalRatingDescriptor_Vtbl)).GetRecordRating)(this, brecordindex, pszcountrycode, pbval), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetTag, GetLength, GetCountOfRecords, GetRecordRating }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbParentalRatingDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbPrivateDataSpecifierDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbPrivateDataSpecifierDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IDvbPrivateDataSpecifierDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbPrivateDataSpecifierDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IDvbPrivateDataSpecifierDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrivateDataSpecifier(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbPrivateDataSpecifierDescriptor", "GetPrivateDataSpecifier", 5usize, |this| ((**(this as *const *const IDvbPrivateDataSpecifierDescriptor_Vtbl)).GetPrivateDataSpecifier)(this, pdwval), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetTag, GetLength, GetPrivateDataSpecifier }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbPrivateDataSpecifierDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbSatelliteDeliverySystemDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSatelliteDeliverySystemDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IDvbSatelliteDeliverySystemDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSatelliteDeliverySystemDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IDvbSatelliteDeliverySystemDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFrequency(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSatelliteDeliverySystemDescriptor", "GetFrequency", 5usize, |this| ((**(this as *const *const IDvbSatelliteDeliverySystemDescriptor_Vtbl)).GetFrequency)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOrbitalPosition(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSatelliteDeliverySystemDescriptor", "GetOrbitalPosition", 6usize, |this| ((**(this as *const *const IDvbSatelliteDeliverySystemDescriptor_Vtbl)).GetOrbitalPosition)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWestEastFlag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSatelliteDeliverySystemDescriptor", "GetWestEastFlag", 7usize, |this| ((**(this as *const *const IDvbSatelliteDeliverySystemDescriptor_Vtbl)).GetWestEastFlag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPolarization(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSatelliteDeliverySystemDescriptor", "GetPolarization", 8usize, |this| ((**(this as *const *const IDvbSatelliteDeliverySystemDescriptor_Vtbl)).GetPolarization)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetModulation(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSatelliteDeliverySystemDescriptor", "GetModulation", 9usize, |this| ((**(this as *const *const IDvbSatelliteDeliverySystemDescriptor_Vtbl)).GetModulation)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSymbolRate(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSatelliteDeliverySystemDescriptor", "GetSymbolRate", 10usize, |this| ((**(this as *const *const IDvbSatelliteDeliverySystemDescriptor_Vtbl)).GetSymbolRate)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFECInner(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSatelliteDeliverySystemDescriptor", "GetFECInner", 11usize, |this| ((**(this as *const *const IDvbSatelliteDeliverySystemDescriptor_Vtbl)).GetFECInner)(this, pbval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetFrequency,
+            GetOrbitalPosition,
+            GetWestEastFlag,
+            GetPolarization,
+            GetModulation,
+            GetSymbolRate,
+            GetFECInner,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbSatelliteDeliverySystemDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbServiceAttributeDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbServiceAttributeDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IDvbServiceAttributeDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbServiceAttributeDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IDvbServiceAttributeDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbServiceAttributeDescriptor", "GetCountOfRecords", 5usize, |this| ((**(this as *const *const IDvbServiceAttributeDescriptor_Vtbl)).GetCountOfRecords)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordServiceId(this: *mut core::ffi::c_void, brecordindex: u8, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbServiceAttributeDescriptor", "GetRecordServiceId", 6usize, |this| ((**(this as *const *const IDvbServiceAttributeDescriptor_Vtbl)).GetRecordServiceId)(this, brecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordNumericSelectionFlag(this: *mut core::ffi::c_void, brecordindex: u8, pfval: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbServiceAttributeDescriptor", "GetRecordNumericSelectionFlag", 7usize, |this| ((**(this as *const *const IDvbServiceAttributeDescriptor_Vtbl)).GetRecordNumericSelectionFlag)(this, brecordindex, pfval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordVisibleServiceFlag(this: *mut core::ffi::c_void, brecordindex: u8, pfval: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbServiceAttributeDescriptor", "GetRecordVisibleServiceFlag", 8usize, |this| ((**(this as *const *const IDvbServiceAttributeDescriptor_Vtbl)).GetRecordVisibleServiceFlag)(this, brecordindex, pfval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetCountOfRecords,
+            GetRecordServiceId,
+            GetRecordNumericSelectionFlag,
+            GetRecordVisibleServiceFlag,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbServiceAttributeDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbServiceDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbServiceDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IDvbServiceDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbServiceDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IDvbServiceDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServiceType(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbServiceDescriptor", "GetServiceType", 5usize, |this| ((**(this as *const *const IDvbServiceDescriptor_Vtbl)).GetServiceType)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServiceProviderName(this: *mut core::ffi::c_void, pszname: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbServiceDescriptor", "GetServiceProviderName", 6usize, |this| ((**(this as *const *const IDvbServiceDescriptor_Vtbl)).GetServiceProviderName)(this, pszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServiceProviderNameW(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbServiceDescriptor", "GetServiceProviderNameW", 7usize, |this| ((**(this as *const *const IDvbServiceDescriptor_Vtbl)).GetServiceProviderNameW)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServiceName(this: *mut core::ffi::c_void, pszname: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbServiceDescriptor", "GetServiceName", 8usize, |this| ((**(this as *const *const IDvbServiceDescriptor_Vtbl)).GetServiceName)(this, pszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProcessedServiceName(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbServiceDescriptor", "GetProcessedServiceName", 9usize, |this| ((**(this as *const *const IDvbServiceDescriptor_Vtbl)).GetProcessedServiceName)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServiceNameEmphasized(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbServiceDescriptor", "GetServiceNameEmphasized", 10usize, |this| ((**(this as *const *const IDvbServiceDescriptor_Vtbl)).GetServiceNameEmphasized)(this, pbstrname), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetServiceType,
+            GetServiceProviderName,
+            GetServiceProviderNameW,
+            GetServiceName,
+            GetProcessedServiceName,
+            GetServiceNameEmphasized,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbServiceDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbServiceDescriptor2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetServiceProviderNameW(this: *mut core::ffi::c_void, convmode: DVB_STRCONV_MODE, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbServiceDescriptor2", "GetServiceProviderNameW", 11usize, |this| ((**(this as *const *const IDvbServiceDescriptor2_Vtbl)).GetServiceProviderNameW)(this, convmode, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServiceNameW(this: *mut core::ffi::c_void, convmode: DVB_STRCONV_MODE, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbServiceDescriptor2", "GetServiceNameW", 12usize, |this| ((**(this as *const *const IDvbServiceDescriptor2_Vtbl)).GetServiceNameW)(this, convmode, pbstrname), |result| Some(*result))
+        }
+        Self { base__: <IDvbServiceDescriptor_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetServiceProviderNameW, GetServiceNameW }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbServiceDescriptor2 as windows_core::Interface>::IID || <IDvbServiceDescriptor_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbServiceListDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbServiceListDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IDvbServiceListDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbServiceListDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IDvbServiceListDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbServiceListDescriptor", "GetCountOfRecords", 5usize, |this| ((**(this as *const *const IDvbServiceListDescriptor_Vtbl)).GetCountOfRecords)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordServiceId(this: *mut core::ffi::c_void, brecordindex: u8, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbServiceListDescriptor", "GetRecordServiceId", 6usize, |this| ((**(this as *const *const IDvbServiceListDescriptor_Vtbl)).GetRecordServiceId)(this, brecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordServiceType(this: *mut core::ffi::c_void, brecordindex: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbServiceListDescriptor", "GetRecordServiceType", 7usize, |this| ((**(this as *const *const IDvbServiceListDescriptor_Vtbl)).GetRecordServiceType)(this, brecordindex, pbval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetCountOfRecords,
+            GetRecordServiceId,
+            GetRecordServiceType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbServiceListDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbShortEventDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbShortEventDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IDvbShortEventDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbShortEventDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IDvbShortEventDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLanguageCode(this: *mut core::ffi::c_void, pszcode: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbShortEventDescriptor", "GetLanguageCode", 5usize, |this| ((**(this as *const *const IDvbShortEventDescriptor_Vtbl)).GetLanguageCode)(this, pszcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEventNameW(this: *mut core::ffi::c_void, convmode: DVB_STRCONV_MODE, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbShortEventDescriptor", "GetEventNameW", 6usize, |this| ((**(this as *const *const IDvbShortEventDescriptor_Vtbl)).GetEventNameW)(this, convmode, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTextW(this: *mut core::ffi::c_void, convmode: DVB_STRCONV_MODE, pbstrtext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbShortEventDescriptor", "GetTextW", 7usize, |this| ((**(this as *const *const IDvbShortEventDescriptor_Vtbl)).GetTextW)(this, convmode, pbstrtext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetTag, GetLength, GetLanguageCode, GetEventNameW, GetTextW }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbShortEventDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbSiParser_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, punkmpeg2data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSiParser", "Initialize", 3usize, |this| ((**(this as *const *const IDvbSiParser_Vtbl)).Initialize)(this, punkmpeg2data), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPAT(this: *mut core::ffi::c_void, pppat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSiParser", "GetPAT", 4usize, |this| ((**(this as *const *const IDvbSiParser_Vtbl)).GetPAT)(this, pppat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCAT(this: *mut core::ffi::c_void, dwtimeout: u32, ppcat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSiParser", "GetCAT", 5usize, |this| ((**(this as *const *const IDvbSiParser_Vtbl)).GetCAT)(this, dwtimeout, ppcat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPMT(this: *mut core::ffi::c_void, pid: u16, pwprogramnumber: *const u16, pppmt: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSiParser", "GetPMT", 6usize, |this| ((**(this as *const *const IDvbSiParser_Vtbl)).GetPMT)(this, pid, pwprogramnumber, pppmt), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTSDT(this: *mut core::ffi::c_void, pptsdt: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSiParser", "GetTSDT", 7usize, |this| ((**(this as *const *const IDvbSiParser_Vtbl)).GetTSDT)(this, pptsdt), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNIT(this: *mut core::ffi::c_void, tableid: u8, pwnetworkid: *const u16, ppnit: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSiParser", "GetNIT", 8usize, |this| ((**(this as *const *const IDvbSiParser_Vtbl)).GetNIT)(this, tableid, pwnetworkid, ppnit), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSDT(this: *mut core::ffi::c_void, tableid: u8, pwtransportstreamid: *const u16, ppsdt: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSiParser", "GetSDT", 9usize, |this| ((**(this as *const *const IDvbSiParser_Vtbl)).GetSDT)(this, tableid, pwtransportstreamid, ppsdt), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEIT(this: *mut core::ffi::c_void, tableid: u8, pwserviceid: *const u16, ppeit: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSiParser", "GetEIT", 10usize, |this| ((**(this as *const *const IDvbSiParser_Vtbl)).GetEIT)(this, tableid, pwserviceid, ppeit), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBAT(this: *mut core::ffi::c_void, pwbouquetid: *const u16, ppbat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSiParser", "GetBAT", 11usize, |this| ((**(this as *const *const IDvbSiParser_Vtbl)).GetBAT)(this, pwbouquetid, ppbat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRST(this: *mut core::ffi::c_void, dwtimeout: u32, pprst: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSiParser", "GetRST", 12usize, |this| ((**(this as *const *const IDvbSiParser_Vtbl)).GetRST)(this, dwtimeout, pprst), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetST(this: *mut core::ffi::c_void, pid: u16, dwtimeout: u32, ppst: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSiParser", "GetST", 13usize, |this| ((**(this as *const *const IDvbSiParser_Vtbl)).GetST)(this, pid, dwtimeout, ppst), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTDT(this: *mut core::ffi::c_void, pptdt: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSiParser", "GetTDT", 14usize, |this| ((**(this as *const *const IDvbSiParser_Vtbl)).GetTDT)(this, pptdt), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTOT(this: *mut core::ffi::c_void, pptot: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSiParser", "GetTOT", 15usize, |this| ((**(this as *const *const IDvbSiParser_Vtbl)).GetTOT)(this, pptot), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDIT(this: *mut core::ffi::c_void, dwtimeout: u32, ppdit: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSiParser", "GetDIT", 16usize, |this| ((**(this as *const *const IDvbSiParser_Vtbl)).GetDIT)(this, dwtimeout, ppdit), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSIT(this: *mut core::ffi::c_void, dwtimeout: u32, ppsit: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSiParser", "GetSIT", 17usize, |this| ((**(this as *const *const IDvbSiParser_Vtbl)).GetSIT)(this, dwtimeout, ppsit), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetPAT,
+            GetCAT,
+            GetPMT,
+            GetTSDT,
+            GetNIT,
+            GetSDT,
+            GetEIT,
+            GetBAT,
+            GetRST,
+            GetST,
+            GetTDT,
+            GetTOT,
+            GetDIT,
+            GetSIT,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbSiParser as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbSiParser2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEIT2(this: *mut core::ffi::c_void, tableid: u8, pwserviceid: *const u16, pbsegment: *const u8, ppeit: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSiParser2", "GetEIT2", 18usize, |this| ((**(this as *const *const IDvbSiParser2_Vtbl)).GetEIT2)(this, tableid, pwserviceid, pbsegment, ppeit), |result| Some(*result))
+        }
+        Self { base__: <IDvbSiParser_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetEIT2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbSiParser2 as windows_core::Interface>::IID || <IDvbSiParser_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbSubtitlingDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSubtitlingDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IDvbSubtitlingDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSubtitlingDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IDvbSubtitlingDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSubtitlingDescriptor", "GetCountOfRecords", 5usize, |this| ((**(this as *const *const IDvbSubtitlingDescriptor_Vtbl)).GetCountOfRecords)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordLangId(this: *mut core::ffi::c_void, brecordindex: u8, pulval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSubtitlingDescriptor", "GetRecordLangId", 6usize, |this| ((**(this as *const *const IDvbSubtitlingDescriptor_Vtbl)).GetRecordLangId)(this, brecordindex, pulval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordSubtitlingType(this: *mut core::ffi::c_void, brecordindex: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSubtitlingDescriptor", "GetRecordSubtitlingType", 7usize, |this| ((**(this as *const *const IDvbSubtitlingDescriptor_Vtbl)).GetRecordSubtitlingType)(this, brecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordCompositionPageID(this: *mut core::ffi::c_void, brecordindex: u8, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSubtitlingDescriptor", "GetRecordCompositionPageID", 8usize, |this| ((**(this as *const *const IDvbSubtitlingDescriptor_Vtbl)).GetRecordCompositionPageID)(this, brecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordAncillaryPageID(this: *mut core::ffi::c_void, brecordindex: u8, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbSubtitlingDescriptor", "GetRecordAncillaryPageID", 9usize, |this| ((**(this as *const *const IDvbSubtitlingDescriptor_Vtbl)).GetRecordAncillaryPageID)(this, brecordindex, pwval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetCountOfRecords,
+            GetRecordLangId,
+            GetRecordSubtitlingType,
+            GetRecordCompositionPageID,
+            GetRecordAncillaryPageID,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbSubtitlingDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbTeletextDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTeletextDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IDvbTeletextDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTeletextDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IDvbTeletextDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTeletextDescriptor", "GetCountOfRecords", 5usize, |this| ((**(this as *const *const IDvbTeletextDescriptor_Vtbl)).GetCountOfRecords)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordLangId(this: *mut core::ffi::c_void, brecordindex: u8, pulval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTeletextDescriptor", "GetRecordLangId", 6usize, |this| ((**(this as *const *const IDvbTeletextDescriptor_Vtbl)).GetRecordLangId)(this, brecordindex, pulval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordTeletextType(this: *mut core::ffi::c_void, brecordindex: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTeletextDescriptor", "GetRecordTeletextType", 7usize, |this| ((**(this as *const *const IDvbTeletextDescriptor_Vtbl)).GetRecordTeletextType)(this, brecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordMagazineNumber(this: *mut core::ffi::c_void, brecordindex: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTeletextDescriptor", "GetRecordMagazineNumber", 8usize, |this| ((**(this as *const *const IDvbTeletextDescriptor_Vtbl)).GetRecordMagazineNumber)(this, brecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordPageNumber(this: *mut core::ffi::c_void, brecordindex: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTeletextDescriptor", "GetRecordPageNumber", 9usize, |this| ((**(this as *const *const IDvbTeletextDescriptor_Vtbl)).GetRecordPageNumber)(this, brecordindex, pbval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetCountOfRecords,
+            GetRecordLangId,
+            GetRecordTeletextType,
+            GetRecordMagazineNumber,
+            GetRecordPageNumber,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbTeletextDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbTerrestrial2DeliverySystemDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTerrestrial2DeliverySystemDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IDvbTerrestrial2DeliverySystemDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTerrestrial2DeliverySystemDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IDvbTerrestrial2DeliverySystemDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTagExtension(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTerrestrial2DeliverySystemDescriptor", "GetTagExtension", 5usize, |this| ((**(this as *const *const IDvbTerrestrial2DeliverySystemDescriptor_Vtbl)).GetTagExtension)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCentreFrequency(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTerrestrial2DeliverySystemDescriptor", "GetCentreFrequency", 6usize, |this| ((**(this as *const *const IDvbTerrestrial2DeliverySystemDescriptor_Vtbl)).GetCentreFrequency)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPLPId(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTerrestrial2DeliverySystemDescriptor", "GetPLPId", 7usize, |this| ((**(this as *const *const IDvbTerrestrial2DeliverySystemDescriptor_Vtbl)).GetPLPId)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetT2SystemId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTerrestrial2DeliverySystemDescriptor", "GetT2SystemId", 8usize, |this| ((**(this as *const *const IDvbTerrestrial2DeliverySystemDescriptor_Vtbl)).GetT2SystemId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMultipleInputMode(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTerrestrial2DeliverySystemDescriptor", "GetMultipleInputMode", 9usize, |this| ((**(this as *const *const IDvbTerrestrial2DeliverySystemDescriptor_Vtbl)).GetMultipleInputMode)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBandwidth(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTerrestrial2DeliverySystemDescriptor", "GetBandwidth", 10usize, |this| ((**(this as *const *const IDvbTerrestrial2DeliverySystemDescriptor_Vtbl)).GetBandwidth)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGuardInterval(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTerrestrial2DeliverySystemDescriptor", "GetGuardInterval", 11usize, |this| ((**(this as *const *const IDvbTerrestrial2DeliverySystemDescriptor_Vtbl)).GetGuardInterval)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransmissionMode(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTerrestrial2DeliverySystemDescriptor", "GetTransmissionMode", 12usize, |this| ((**(this as *const *const IDvbTerrestrial2DeliverySystemDescriptor_Vtbl)).GetTransmissionMode)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCellId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTerrestrial2DeliverySystemDescriptor", "GetCellId", 13usize, |this| ((**(this as *const *const IDvbTerrestrial2DeliverySystemDescriptor_Vtbl)).GetCellId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOtherFrequencyFlag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTerrestrial2DeliverySystemDescriptor", "GetOtherFrequencyFlag", 14usize, |this| ((**(this as *const *const IDvbTerrestrial2DeliverySystemDescriptor_Vtbl)).GetOtherFrequencyFlag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTFSFlag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTerrestrial2DeliverySystemDescriptor", "GetTFSFlag", 15usize, |this| ((**(this as *const *const IDvbTerrestrial2DeliverySystemDescriptor_Vtbl)).GetTFSFlag)(this, pbval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetTagExtension,
+            GetCentreFrequency,
+            GetPLPId,
+            GetT2SystemId,
+            GetMultipleInputMode,
+            GetBandwidth,
+            GetGuardInterval,
+            GetTransmissionMode,
+            GetCellId,
+            GetOtherFrequencyFlag,
+            GetTFSFlag,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbTerrestrial2DeliverySystemDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvbTerrestrialDeliverySystemDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTerrestrialDeliverySystemDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IDvbTerrestrialDeliverySystemDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTerrestrialDeliverySystemDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IDvbTerrestrialDeliverySystemDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCentreFrequency(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTerrestrialDeliverySystemDescriptor", "GetCentreFrequency", 5usize, |this| ((**(this as *const *const IDvbTerrestrialDeliverySystemDescriptor_Vtbl)).GetCentreFrequency)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBandwidth(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTerrestrialDeliverySystemDescriptor", "GetBandwidth", 6usize, |this| ((**(this as *const *const IDvbTerrestrialDeliverySystemDescriptor_Vtbl)).GetBandwidth)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConstellation(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTerrestrialDeliverySystemDescriptor", "GetConstellation", 7usize, |this| ((**(this as *const *const IDvbTerrestrialDeliverySystemDescriptor_Vtbl)).GetConstellation)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHierarchyInformation(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTerrestrialDeliverySystemDescriptor", "GetHierarchyInformation", 8usize, |this| ((**(this as *const *const IDvbTerrestrialDeliverySystemDescriptor_Vtbl)).GetHierarchyInformation)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCodeRateHPStream(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTerrestrialDeliverySystemDescriptor", "GetCodeRateHPStream", 9usize, |this| ((**(this as *const *const IDvbTerrestrialDeliverySystemDescriptor_Vtbl)).GetCodeRateHPStream)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCodeRateLPStream(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTerrestrialDeliverySystemDescriptor", "GetCodeRateLPStream", 10usize, |this| ((**(this as *const *const IDvbTerrestrialDeliverySystemDescriptor_Vtbl)).GetCodeRateLPStream)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGuardInterval(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTerrestrialDeliverySystemDescriptor", "GetGuardInterval", 11usize, |this| ((**(this as *const *const IDvbTerrestrialDeliverySystemDescriptor_Vtbl)).GetGuardInterval)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransmissionMode(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTerrestrialDeliverySystemDescriptor", "GetTransmissionMode", 12usize, |this| ((**(this as *const *const IDvbTerrestrialDeliverySystemDescriptor_Vtbl)).GetTransmissionMode)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOtherFrequencyFlag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvbTerrestrialDeliverySystemDescriptor", "GetOtherFrequencyFlag", 13usize, |this| ((**(this as *const *const IDvbTerrestrialDeliverySystemDescriptor_Vtbl)).GetOtherFrequencyFlag)(this, pbval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetCentreFrequency,
+            GetBandwidth,
+            GetConstellation,
+            GetHierarchyInformation,
+            GetCodeRateHPStream,
+            GetCodeRateLPStream,
+            GetGuardInterval,
+            GetTransmissionMode,
+            GetOtherFrequencyFlag,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvbTerrestrialDeliverySystemDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IESCloseMmiEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDialogNumber(this: *mut core::ffi::c_void, pdialognumber: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESCloseMmiEvent", "GetDialogNumber", 8usize, |this| ((**(this as *const *const IESCloseMmiEvent_Vtbl)).GetDialogNumber)(this, pdialognumber), |result| Some(*result))
+        }
+        Self { base__: <super::IESEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDialogNumber }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESCloseMmiEvent as windows_core::Interface>::IID || <super::IESEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IESEventFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateESEvent(this: *mut core::ffi::c_void, pserviceprovider: *mut core::ffi::c_void, dweventid: u32, guideventtype: windows_core::GUID, dweventdatalength: u32, peventdata: *const u8, bstrbaseurl: core::mem::MaybeUninit<windows_core::BSTR>, pinitcontext: *mut core::ffi::c_void, ppesevent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESEventFactory", "CreateESEvent", 3usize, |this| ((**(this as *const *const IESEventFactory_Vtbl)).CreateESEvent)(this, pserviceprovider, dweventid, guideventtype, dweventdatalength, peventdata, bstrbaseurl, pinitcontext, ppesevent), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateESEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESEventFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IESEventService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FireESEvent(this: *mut core::ffi::c_void, pesevent: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESEventService", "FireESEvent", 3usize, |this| ((**(this as *const *const IESEventService_Vtbl)).FireESEvent)(this, pesevent), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FireESEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESEventService as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IESEventServiceConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetParent(this: *mut core::ffi::c_void, peventservice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESEventServiceConfiguration", "SetParent", 3usize, |this| ((**(this as *const *const IESEventServiceConfiguration_Vtbl)).SetParent)(this, peventservice), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveParent(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESEventServiceConfiguration", "RemoveParent", 4usize, |this| ((**(this as *const *const IESEventServiceConfiguration_Vtbl)).RemoveParent)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOwner(this: *mut core::ffi::c_void, pesevents: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESEventServiceConfiguration", "SetOwner", 5usize, |this| ((**(this as *const *const IESEventServiceConfiguration_Vtbl)).SetOwner)(this, pesevents), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveOwner(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESEventServiceConfiguration", "RemoveOwner", 6usize, |this| ((**(this as *const *const IESEventServiceConfiguration_Vtbl)).RemoveOwner)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGraph(this: *mut core::ffi::c_void, pgraph: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESEventServiceConfiguration", "SetGraph", 7usize, |this| ((**(this as *const *const IESEventServiceConfiguration_Vtbl)).SetGraph)(this, pgraph), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveGraph(this: *mut core::ffi::c_void, pgraph: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESEventServiceConfiguration", "RemoveGraph", 8usize, |this| ((**(this as *const *const IESEventServiceConfiguration_Vtbl)).RemoveGraph)(this, pgraph), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetParent,
+            RemoveParent,
+            SetOwner,
+            RemoveOwner,
+            SetGraph,
+            RemoveGraph,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESEventServiceConfiguration as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IESFileExpiryDateEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTunerId(this: *mut core::ffi::c_void, pguidtunerid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESFileExpiryDateEvent", "GetTunerId", 8usize, |this| ((**(this as *const *const IESFileExpiryDateEvent_Vtbl)).GetTunerId)(this, pguidtunerid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExpiryDate(this: *mut core::ffi::c_void, pqwexpirydate: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESFileExpiryDateEvent", "GetExpiryDate", 9usize, |this| ((**(this as *const *const IESFileExpiryDateEvent_Vtbl)).GetExpiryDate)(this, pqwexpirydate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFinalExpiryDate(this: *mut core::ffi::c_void, pqwexpirydate: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESFileExpiryDateEvent", "GetFinalExpiryDate", 10usize, |this| ((**(this as *const *const IESFileExpiryDateEvent_Vtbl)).GetFinalExpiryDate)(this, pqwexpirydate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxRenewalCount(this: *mut core::ffi::c_void, dwmaxrenewalcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESFileExpiryDateEvent", "GetMaxRenewalCount", 11usize, |this| ((**(this as *const *const IESFileExpiryDateEvent_Vtbl)).GetMaxRenewalCount)(this, dwmaxrenewalcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEntitlementTokenPresent(this: *mut core::ffi::c_void, pfenttokenpresent: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESFileExpiryDateEvent", "IsEntitlementTokenPresent", 12usize, |this| ((**(this as *const *const IESFileExpiryDateEvent_Vtbl)).IsEntitlementTokenPresent)(this, pfenttokenpresent), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoesExpireAfterFirstUse(this: *mut core::ffi::c_void, pfexpireafterfirstuse: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESFileExpiryDateEvent", "DoesExpireAfterFirstUse", 13usize, |this| ((**(this as *const *const IESFileExpiryDateEvent_Vtbl)).DoesExpireAfterFirstUse)(this, pfexpireafterfirstuse), |result| Some(*result))
+        }
+        Self {
+            base__: <super::IESEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTunerId,
+            GetExpiryDate,
+            GetFinalExpiryDate,
+            GetMaxRenewalCount,
+            IsEntitlementTokenPresent,
+            DoesExpireAfterFirstUse,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESFileExpiryDateEvent as windows_core::Interface>::IID || <super::IESEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IESIsdbCasResponseEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRequestId(this: *mut core::ffi::c_void, prequestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESIsdbCasResponseEvent", "GetRequestId", 8usize, |this| ((**(this as *const *const IESIsdbCasResponseEvent_Vtbl)).GetRequestId)(this, prequestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESIsdbCasResponseEvent", "GetStatus", 9usize, |this| ((**(this as *const *const IESIsdbCasResponseEvent_Vtbl)).GetStatus)(this, pstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataLength(this: *mut core::ffi::c_void, prequestlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESIsdbCasResponseEvent", "GetDataLength", 10usize, |this| ((**(this as *const *const IESIsdbCasResponseEvent_Vtbl)).GetDataLength)(this, prequestlength), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetResponseData(this: *mut core::ffi::c_void, pbdata: *mut *mut super::super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESIsdbCasResponseEvent", "GetResponseData", 11usize, |this| ((**(this as *const *const IESIsdbCasResponseEvent_Vtbl)).GetResponseData)(this, pbdata), |result| Some(*result))
+        }
+        Self {
+            base__: <super::IESEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetRequestId,
+            GetStatus,
+            GetDataLength,
+            #[cfg(feature = "Win32_System_Com")]
+            GetResponseData,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetResponseData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESIsdbCasResponseEvent as windows_core::Interface>::IID || <super::IESEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IESLicenseRenewalResultEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCallersId(this: *mut core::ffi::c_void, pdwcallersid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESLicenseRenewalResultEvent", "GetCallersId", 8usize, |this| ((**(this as *const *const IESLicenseRenewalResultEvent_Vtbl)).GetCallersId)(this, pdwcallersid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileName(this: *mut core::ffi::c_void, pbstrfilename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESLicenseRenewalResultEvent", "GetFileName", 9usize, |this| ((**(this as *const *const IESLicenseRenewalResultEvent_Vtbl)).GetFileName)(this, pbstrfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRenewalSuccessful(this: *mut core::ffi::c_void, pfrenewalsuccessful: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESLicenseRenewalResultEvent", "IsRenewalSuccessful", 10usize, |this| ((**(this as *const *const IESLicenseRenewalResultEvent_Vtbl)).IsRenewalSuccessful)(this, pfrenewalsuccessful), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCheckEntitlementCallRequired(this: *mut core::ffi::c_void, pfcheckenttokencallneeded: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESLicenseRenewalResultEvent", "IsCheckEntitlementCallRequired", 11usize, |this| ((**(this as *const *const IESLicenseRenewalResultEvent_Vtbl)).IsCheckEntitlementCallRequired)(this, pfcheckenttokencallneeded), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescrambledStatus(this: *mut core::ffi::c_void, pdescrambledstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESLicenseRenewalResultEvent", "GetDescrambledStatus", 12usize, |this| ((**(this as *const *const IESLicenseRenewalResultEvent_Vtbl)).GetDescrambledStatus)(this, pdescrambledstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRenewalResultCode(this: *mut core::ffi::c_void, pdwrenewalresultcode: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESLicenseRenewalResultEvent", "GetRenewalResultCode", 13usize, |this| ((**(this as *const *const IESLicenseRenewalResultEvent_Vtbl)).GetRenewalResultCode)(this, pdwrenewalresultcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCASFailureCode(this: *mut core::ffi::c_void, pdwcasfailurecode: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESLicenseRenewalResultEvent", "GetCASFailureCode", 14usize, |this| ((**(this as *const *const IESLicenseRenewalResultEvent_Vtbl)).GetCASFailureCode)(this, pdwcasfailurecode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRenewalHResult(this: *mut core::ffi::c_void, phr: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESLicenseRenewalResultEvent", "GetRenewalHResult", 15usize, |this| ((**(this as *const *const IESLicenseRenewalResultEvent_Vtbl)).GetRenewalHResult)(this, phr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEntitlementTokenLength(this: *mut core::ffi::c_void, pdwlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESLicenseRenewalResultEvent", "GetEntitlementTokenLength", 16usize, |this| ((**(this as *const *const IESLicenseRenewalResultEvent_Vtbl)).GetEntitlementTokenLength)(this, pdwlength), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetEntitlementToken(this: *mut core::ffi::c_void, pbdata: *mut *mut super::super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESLicenseRenewalResultEvent", "GetEntitlementToken", 17usize, |this| ((**(this as *const *const IESLicenseRenewalResultEvent_Vtbl)).GetEntitlementToken)(this, pbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExpiryDate(this: *mut core::ffi::c_void, pqwexpirydate: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESLicenseRenewalResultEvent", "GetExpiryDate", 18usize, |this| ((**(this as *const *const IESLicenseRenewalResultEvent_Vtbl)).GetExpiryDate)(this, pqwexpirydate), |result| Some(*result))
+        }
+        Self {
+            base__: <super::IESEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCallersId,
+            GetFileName,
+            IsRenewalSuccessful,
+            IsCheckEntitlementCallRequired,
+            GetDescrambledStatus,
+            GetRenewalResultCode,
+            GetCASFailureCode,
+            GetRenewalHResult,
+            GetEntitlementTokenLength,
+            #[cfg(feature = "Win32_System_Com")]
+            GetEntitlementToken,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetEntitlementToken: 0,
+            GetExpiryDate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESLicenseRenewalResultEvent as windows_core::Interface>::IID || <super::IESEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IESOpenMmiEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDialogNumber(this: *mut core::ffi::c_void, pdialogrequest: *mut u32, pdialognumber: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESOpenMmiEvent", "GetDialogNumber", 8usize, |this| ((**(this as *const *const IESOpenMmiEvent_Vtbl)).GetDialogNumber)(this, pdialogrequest, pdialognumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDialogType(this: *mut core::ffi::c_void, guiddialogtype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESOpenMmiEvent", "GetDialogType", 9usize, |this| ((**(this as *const *const IESOpenMmiEvent_Vtbl)).GetDialogType)(this, guiddialogtype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetDialogData(this: *mut core::ffi::c_void, pbdata: *mut *mut super::super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESOpenMmiEvent", "GetDialogData", 10usize, |this| ((**(this as *const *const IESOpenMmiEvent_Vtbl)).GetDialogData)(this, pbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDialogStringData(this: *mut core::ffi::c_void, pbstrbaseurl: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrdata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESOpenMmiEvent", "GetDialogStringData", 11usize, |this| ((**(this as *const *const IESOpenMmiEvent_Vtbl)).GetDialogStringData)(this, pbstrbaseurl, pbstrdata), |result| Some(*result))
+        }
+        Self {
+            base__: <super::IESEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDialogNumber,
+            GetDialogType,
+            #[cfg(feature = "Win32_System_Com")]
+            GetDialogData,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetDialogData: 0,
+            GetDialogStringData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESOpenMmiEvent as windows_core::Interface>::IID || <super::IESEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IESRequestTunerEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPriority(this: *mut core::ffi::c_void, pbypriority: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESRequestTunerEvent", "GetPriority", 8usize, |this| ((**(this as *const *const IESRequestTunerEvent_Vtbl)).GetPriority)(this, pbypriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReason(this: *mut core::ffi::c_void, pbyreason: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESRequestTunerEvent", "GetReason", 9usize, |this| ((**(this as *const *const IESRequestTunerEvent_Vtbl)).GetReason)(this, pbyreason), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConsequences(this: *mut core::ffi::c_void, pbyconsequences: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESRequestTunerEvent", "GetConsequences", 10usize, |this| ((**(this as *const *const IESRequestTunerEvent_Vtbl)).GetConsequences)(this, pbyconsequences), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEstimatedTime(this: *mut core::ffi::c_void, pdwestimatedtime: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESRequestTunerEvent", "GetEstimatedTime", 11usize, |this| ((**(this as *const *const IESRequestTunerEvent_Vtbl)).GetEstimatedTime)(this, pdwestimatedtime), |result| Some(*result))
+        }
+        Self { base__: <super::IESEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPriority, GetReason, GetConsequences, GetEstimatedTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESRequestTunerEvent as windows_core::Interface>::IID || <super::IESEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IESValueUpdatedEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetValueNames(this: *mut core::ffi::c_void, pbstrnames: *mut *mut super::super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESValueUpdatedEvent", "GetValueNames", 8usize, |this| ((**(this as *const *const IESValueUpdatedEvent_Vtbl)).GetValueNames)(this, pbstrnames), |result| Some(*result))
+        }
+        Self {
+            base__: <super::IESEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetValueNames,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetValueNames: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESValueUpdatedEvent as windows_core::Interface>::IID || <super::IESEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IETFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EvalRatObjOK(this: *mut core::ffi::c_void, phrcocreateretval: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IETFilter", "EvalRatObjOK", 3usize, |this| ((**(this as *const *const IETFilter_Vtbl)).EvalRatObjOK)(this, phrcocreateretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrRating(this: *mut core::ffi::c_void, pensystem: *mut EnTvRat_System, penrating: *mut EnTvRat_GenericLevel, plbfenattr: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IETFilter", "GetCurrRating", 4usize, |this| ((**(this as *const *const IETFilter_Vtbl)).GetCurrRating)(this, pensystem, penrating, plbfenattr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrLicenseExpDate(this: *mut core::ffi::c_void, prottype: *const ProtType, lpdatetime: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IETFilter", "GetCurrLicenseExpDate", 5usize, |this| ((**(this as *const *const IETFilter_Vtbl)).GetCurrLicenseExpDate)(this, prottype, lpdatetime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastErrorCode(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IETFilter", "GetLastErrorCode", 6usize, |this| ((**(this as *const *const IETFilter_Vtbl)).GetLastErrorCode)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRecordingOn(this: *mut core::ffi::c_void, frecstate: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IETFilter", "SetRecordingOn", 7usize, |this| ((**(this as *const *const IETFilter_Vtbl)).SetRecordingOn)(this, frecstate), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EvalRatObjOK,
+            GetCurrRating,
+            GetCurrLicenseExpDate,
+            GetLastErrorCode,
+            SetRecordingOn,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IETFilter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IETFilterConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitLicense(this: *mut core::ffi::c_void, licenseid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IETFilterConfig", "InitLicense", 3usize, |this| ((**(this as *const *const IETFilterConfig_Vtbl)).InitLicense)(this, licenseid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSecureChannelObject(this: *mut core::ffi::c_void, ppunkdrmsecurechannel: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IETFilterConfig", "GetSecureChannelObject", 4usize, |this| ((**(this as *const *const IETFilterConfig_Vtbl)).GetSecureChannelObject)(this, ppunkdrmsecurechannel), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InitLicense, GetSecureChannelObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IETFilterConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IETFilterEvents_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IETFilterEvents as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumComponentTypes_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumComponentTypes", "Next", 3usize, |this| ((**(this as *const *const IEnumComponentTypes_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumComponentTypes", "Skip", 4usize, |this| ((**(this as *const *const IEnumComponentTypes_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumComponentTypes", "Reset", 5usize, |this| ((**(this as *const *const IEnumComponentTypes_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumComponentTypes", "Clone", 6usize, |this| ((**(this as *const *const IEnumComponentTypes_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Skip,
+            Reset,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumComponentTypes as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumComponents_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumComponents", "Next", 3usize, |this| ((**(this as *const *const IEnumComponents_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumComponents", "Skip", 4usize, |this| ((**(this as *const *const IEnumComponents_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumComponents", "Reset", 5usize, |this| ((**(this as *const *const IEnumComponents_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumComponents", "Clone", 6usize, |this| ((**(this as *const *const IEnumComponents_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Skip,
+            Reset,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumComponents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumGuideDataProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppprop: *mut *mut core::ffi::c_void, pcelt: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumGuideDataProperties", "Next", 3usize, |this| ((**(this as *const *const IEnumGuideDataProperties_Vtbl)).Next)(this, celt, ppprop, pcelt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumGuideDataProperties", "Skip", 4usize, |this| ((**(this as *const *const IEnumGuideDataProperties_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumGuideDataProperties", "Reset", 5usize, |this| ((**(this as *const *const IEnumGuideDataProperties_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumGuideDataProperties", "Clone", 6usize, |this| ((**(this as *const *const IEnumGuideDataProperties_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumGuideDataProperties as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumMSVidGraphSegment_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumMSVidGraphSegment", "Next", 3usize, |this| ((**(this as *const *const IEnumMSVidGraphSegment_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumMSVidGraphSegment", "Skip", 4usize, |this| ((**(this as *const *const IEnumMSVidGraphSegment_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumMSVidGraphSegment", "Reset", 5usize, |this| ((**(this as *const *const IEnumMSVidGraphSegment_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumMSVidGraphSegment", "Clone", 6usize, |this| ((**(this as *const *const IEnumMSVidGraphSegment_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Skip,
+            Reset,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumMSVidGraphSegment as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumStreamBufferRecordingAttrib_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, crequest: u32, pstreambufferattribute: *mut STREAMBUFFER_ATTRIBUTE, pcreceived: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumStreamBufferRecordingAttrib", "Next", 3usize, |this| ((**(this as *const *const IEnumStreamBufferRecordingAttrib_Vtbl)).Next)(this, crequest, pstreambufferattribute, pcreceived), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, crecords: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumStreamBufferRecordingAttrib", "Skip", 4usize, |this| ((**(this as *const *const IEnumStreamBufferRecordingAttrib_Vtbl)).Skip)(this, crecords), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumStreamBufferRecordingAttrib", "Reset", 5usize, |this| ((**(this as *const *const IEnumStreamBufferRecordingAttrib_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppienumstreambufferattrib: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumStreamBufferRecordingAttrib", "Clone", 6usize, |this| ((**(this as *const *const IEnumStreamBufferRecordingAttrib_Vtbl)).Clone)(this, ppienumstreambufferattrib), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumStreamBufferRecordingAttrib as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumTuneRequests_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppprop: *mut *mut core::ffi::c_void, pcelt: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTuneRequests", "Next", 3usize, |this| ((**(this as *const *const IEnumTuneRequests_Vtbl)).Next)(this, celt, ppprop, pcelt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTuneRequests", "Skip", 4usize, |this| ((**(this as *const *const IEnumTuneRequests_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTuneRequests", "Reset", 5usize, |this| ((**(this as *const *const IEnumTuneRequests_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTuneRequests", "Clone", 6usize, |this| ((**(this as *const *const IEnumTuneRequests_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Skip,
+            Reset,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumTuneRequests as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumTuningSpaces_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTuningSpaces", "Next", 3usize, |this| ((**(this as *const *const IEnumTuningSpaces_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTuningSpaces", "Skip", 4usize, |this| ((**(this as *const *const IEnumTuningSpaces_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTuningSpaces", "Reset", 5usize, |this| ((**(this as *const *const IEnumTuningSpaces_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTuningSpaces", "Clone", 6usize, |this| ((**(this as *const *const IEnumTuningSpaces_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Skip,
+            Reset,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumTuningSpaces as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEvalRat_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_BlockedRatingAttributes(this: *mut core::ffi::c_void, ensystem: EnTvRat_System, enlevel: EnTvRat_GenericLevel, plbfattrs: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEvalRat", "get_BlockedRatingAttributes", 7usize, |this| ((**(this as *const *const IEvalRat_Vtbl)).get_BlockedRatingAttributes)(this, ensystem, enlevel, plbfattrs), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_BlockedRatingAttributes(this: *mut core::ffi::c_void, ensystem: EnTvRat_System, enlevel: EnTvRat_GenericLevel, lbfattrs: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEvalRat", "put_BlockedRatingAttributes", 8usize, |this| ((**(this as *const *const IEvalRat_Vtbl)).put_BlockedRatingAttributes)(this, ensystem, enlevel, lbfattrs), |result| Some(*result))
+        }
+        unsafe extern "system" fn BlockUnRated(this: *mut core::ffi::c_void, pfblockunratedshows: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEvalRat", "BlockUnRated", 9usize, |this| ((**(this as *const *const IEvalRat_Vtbl)).BlockUnRated)(this, pfblockunratedshows), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlockUnRated(this: *mut core::ffi::c_void, fblockunratedshows: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEvalRat", "SetBlockUnRated", 10usize, |this| ((**(this as *const *const IEvalRat_Vtbl)).SetBlockUnRated)(this, fblockunratedshows), |result| Some(*result))
+        }
+        unsafe extern "system" fn MostRestrictiveRating(this: *mut core::ffi::c_void, ensystem1: EnTvRat_System, enenlevel1: EnTvRat_GenericLevel, lbfenattr1: i32, ensystem2: EnTvRat_System, enenlevel2: EnTvRat_GenericLevel, lbfenattr2: i32, pensystem: *mut EnTvRat_System, penenlevel: *mut EnTvRat_GenericLevel, plbfenattr: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEvalRat", "MostRestrictiveRating", 11usize, |this| ((**(this as *const *const IEvalRat_Vtbl)).MostRestrictiveRating)(this, ensystem1, enenlevel1, lbfenattr1, ensystem2, enenlevel2, lbfenattr2, pensystem, penenlevel, plbfenattr), |result| Some(*result))
+        }
+        unsafe extern "system" fn TestRating(this: *mut core::ffi::c_void, enshowsystem: EnTvRat_System, enshowlevel: EnTvRat_GenericLevel, lbfenshowattributes: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEvalRat", "TestRating", 12usize, |this| ((**(this as *const *const IEvalRat_Vtbl)).TestRating)(this, enshowsystem, enshowlevel, lbfenshowattributes), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            get_BlockedRatingAttributes,
+            put_BlockedRatingAttributes,
+            BlockUnRated,
+            SetBlockUnRated,
+            MostRestrictiveRating,
+            TestRating,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEvalRat as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGenericDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pbdesc: *const u8, bcount: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGenericDescriptor", "Initialize", 3usize, |this| ((**(this as *const *const IGenericDescriptor_Vtbl)).Initialize)(this, pbdesc, bcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGenericDescriptor", "GetTag", 4usize, |this| ((**(this as *const *const IGenericDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGenericDescriptor", "GetLength", 5usize, |this| ((**(this as *const *const IGenericDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBody(this: *mut core::ffi::c_void, ppbval: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGenericDescriptor", "GetBody", 6usize, |this| ((**(this as *const *const IGenericDescriptor_Vtbl)).GetBody)(this, ppbval), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, GetTag, GetLength, GetBody }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGenericDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGenericDescriptor2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pbdesc: *const u8, wcount: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGenericDescriptor2", "Initialize", 7usize, |this| ((**(this as *const *const IGenericDescriptor2_Vtbl)).Initialize)(this, pbdesc, wcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGenericDescriptor2", "GetLength", 8usize, |this| ((**(this as *const *const IGenericDescriptor2_Vtbl)).GetLength)(this, pwval), |result| Some(*result))
+        }
+        Self { base__: <IGenericDescriptor_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, GetLength }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGenericDescriptor2 as windows_core::Interface>::IID || <IGenericDescriptor_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGpnvsCommonBase_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetValueUpdateName(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGpnvsCommonBase", "GetValueUpdateName", 3usize, |this| ((**(this as *const *const IGpnvsCommonBase_Vtbl)).GetValueUpdateName)(this, pbstrname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetValueUpdateName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGpnvsCommonBase as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IGuideData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetServices(this: *mut core::ffi::c_void, ppenumtunerequests: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuideData", "GetServices", 3usize, |this| ((**(this as *const *const IGuideData_Vtbl)).GetServices)(this, ppenumtunerequests), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetServiceProperties(this: *mut core::ffi::c_void, ptunerequest: *mut core::ffi::c_void, ppenumproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuideData", "GetServiceProperties", 4usize, |this| ((**(this as *const *const IGuideData_Vtbl)).GetServiceProperties)(this, ptunerequest, ppenumproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn GetGuideProgramIDs(this: *mut core::ffi::c_void, penumprograms: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuideData", "GetGuideProgramIDs", 5usize, |this| ((**(this as *const *const IGuideData_Vtbl)).GetGuideProgramIDs)(this, penumprograms), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProgramProperties(this: *mut core::ffi::c_void, varprogramdescriptionid: core::mem::MaybeUninit<windows_core::VARIANT>, ppenumproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuideData", "GetProgramProperties", 6usize, |this| ((**(this as *const *const IGuideData_Vtbl)).GetProgramProperties)(this, varprogramdescriptionid, ppenumproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn GetScheduleEntryIDs(this: *mut core::ffi::c_void, penumscheduleentries: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuideData", "GetScheduleEntryIDs", 7usize, |this| ((**(this as *const *const IGuideData_Vtbl)).GetScheduleEntryIDs)(this, penumscheduleentries), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScheduleEntryProperties(this: *mut core::ffi::c_void, varscheduleentrydescriptionid: core::mem::MaybeUninit<windows_core::VARIANT>, ppenumproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuideData", "GetScheduleEntryProperties", 8usize, |this| ((**(this as *const *const IGuideData_Vtbl)).GetScheduleEntryProperties)(this, varscheduleentrydescriptionid, ppenumproperties), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetServices,
+            #[cfg(feature = "Win32_System_Com")]
+            GetServiceProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetServiceProperties: 0,
+            #[cfg(feature = "Win32_System_Ole")]
+            GetGuideProgramIDs,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            GetGuideProgramIDs: 0,
+            GetProgramProperties,
+            #[cfg(feature = "Win32_System_Ole")]
+            GetScheduleEntryIDs,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            GetScheduleEntryIDs: 0,
+            GetScheduleEntryProperties,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGuideData as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGuideDataEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GuideDataAcquired(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuideDataEvent", "GuideDataAcquired", 3usize, |this| ((**(this as *const *const IGuideDataEvent_Vtbl)).GuideDataAcquired)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProgramChanged(this: *mut core::ffi::c_void, varprogramdescriptionid: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuideDataEvent", "ProgramChanged", 4usize, |this| ((**(this as *const *const IGuideDataEvent_Vtbl)).ProgramChanged)(this, varprogramdescriptionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceChanged(this: *mut core::ffi::c_void, varservicedescriptionid: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuideDataEvent", "ServiceChanged", 5usize, |this| ((**(this as *const *const IGuideDataEvent_Vtbl)).ServiceChanged)(this, varservicedescriptionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScheduleEntryChanged(this: *mut core::ffi::c_void, varscheduleentrydescriptionid: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuideDataEvent", "ScheduleEntryChanged", 6usize, |this| ((**(this as *const *const IGuideDataEvent_Vtbl)).ScheduleEntryChanged)(this, varscheduleentrydescriptionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProgramDeleted(this: *mut core::ffi::c_void, varprogramdescriptionid: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuideDataEvent", "ProgramDeleted", 7usize, |this| ((**(this as *const *const IGuideDataEvent_Vtbl)).ProgramDeleted)(this, varprogramdescriptionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceDeleted(this: *mut core::ffi::c_void, varservicedescriptionid: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuideDataEvent", "ServiceDeleted", 8usize, |this| ((**(this as *const *const IGuideDataEvent_Vtbl)).ServiceDeleted)(this, varservicedescriptionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScheduleDeleted(this: *mut core::ffi::c_void, varscheduleentrydescriptionid: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuideDataEvent", "ScheduleDeleted", 9usize, |this| ((**(this as *const *const IGuideDataEvent_Vtbl)).ScheduleDeleted)(this, varscheduleentrydescriptionid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GuideDataAcquired,
+            ProgramChanged,
+            ServiceChanged,
+            ScheduleEntryChanged,
+            ProgramDeleted,
+            ServiceDeleted,
+            ScheduleDeleted,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGuideDataEvent as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGuideDataLoader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Init(this: *mut core::ffi::c_void, pguidestore: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuideDataLoader", "Init", 3usize, |this| ((**(this as *const *const IGuideDataLoader_Vtbl)).Init)(this, pguidestore), |result| Some(*result))
+        }
+        unsafe extern "system" fn Terminate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuideDataLoader", "Terminate", 4usize, |this| ((**(this as *const *const IGuideDataLoader_Vtbl)).Terminate)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Init, Terminate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGuideDataLoader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGuideDataProperty_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuideDataProperty", "Name", 3usize, |this| ((**(this as *const *const IGuideDataProperty_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Language(this: *mut core::ffi::c_void, idlang: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuideDataProperty", "Language", 4usize, |this| ((**(this as *const *const IGuideDataProperty_Vtbl)).Language)(this, idlang), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, pvar: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGuideDataProperty", "Value", 5usize, |this| ((**(this as *const *const IGuideDataProperty_Vtbl)).Value)(this, pvar), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, Language, Value }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGuideDataProperty as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IISDBSLocator_Vtbl {
+    const PROXY: Self = { Self { base__: <IDVBSLocator_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IISDBSLocator as windows_core::Interface>::IID || <IDVBSLocator_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IISDB_BIT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psectionlist: *mut core::ffi::c_void, pmpegdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_BIT", "Initialize", 3usize, |this| ((**(this as *const *const IISDB_BIT_Vtbl)).Initialize)(this, psectionlist, pmpegdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionNumber(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_BIT", "GetVersionNumber", 4usize, |this| ((**(this as *const *const IISDB_BIT_Vtbl)).GetVersionNumber)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOriginalNetworkId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_BIT", "GetOriginalNetworkId", 5usize, |this| ((**(this as *const *const IISDB_BIT_Vtbl)).GetOriginalNetworkId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBroadcastViewPropriety(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_BIT", "GetBroadcastViewPropriety", 6usize, |this| ((**(this as *const *const IISDB_BIT_Vtbl)).GetBroadcastViewPropriety)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfTableDescriptors(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_BIT", "GetCountOfTableDescriptors", 7usize, |this| ((**(this as *const *const IISDB_BIT_Vtbl)).GetCountOfTableDescriptors)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableDescriptorByIndex(this: *mut core::ffi::c_void, dwindex: u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_BIT", "GetTableDescriptorByIndex", 8usize, |this| ((**(this as *const *const IISDB_BIT_Vtbl)).GetTableDescriptorByIndex)(this, dwindex, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableDescriptorByTag(this: *mut core::ffi::c_void, btag: u8, pdwcookie: *mut u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_BIT", "GetTableDescriptorByTag", 9usize, |this| ((**(this as *const *const IISDB_BIT_Vtbl)).GetTableDescriptorByTag)(this, btag, pdwcookie, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_BIT", "GetCountOfRecords", 10usize, |this| ((**(this as *const *const IISDB_BIT_Vtbl)).GetCountOfRecords)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordBroadcasterId(this: *mut core::ffi::c_void, dwrecordindex: u32, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_BIT", "GetRecordBroadcasterId", 11usize, |this| ((**(this as *const *const IISDB_BIT_Vtbl)).GetRecordBroadcasterId)(this, dwrecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordCountOfDescriptors(this: *mut core::ffi::c_void, dwrecordindex: u32, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_BIT", "GetRecordCountOfDescriptors", 12usize, |this| ((**(this as *const *const IISDB_BIT_Vtbl)).GetRecordCountOfDescriptors)(this, dwrecordindex, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptorByIndex(this: *mut core::ffi::c_void, dwrecordindex: u32, dwindex: u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_BIT", "GetRecordDescriptorByIndex", 13usize, |this| ((**(this as *const *const IISDB_BIT_Vtbl)).GetRecordDescriptorByIndex)(this, dwrecordindex, dwindex, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptorByTag(this: *mut core::ffi::c_void, dwrecordindex: u32, btag: u8, pdwcookie: *mut u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_BIT", "GetRecordDescriptorByTag", 14usize, |this| ((**(this as *const *const IISDB_BIT_Vtbl)).GetRecordDescriptorByTag)(this, dwrecordindex, btag, pdwcookie, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionHash(this: *mut core::ffi::c_void, pdwversionhash: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_BIT", "GetVersionHash", 15usize, |this| ((**(this as *const *const IISDB_BIT_Vtbl)).GetVersionHash)(this, pdwversionhash), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetVersionNumber,
+            GetOriginalNetworkId,
+            GetBroadcastViewPropriety,
+            GetCountOfTableDescriptors,
+            GetTableDescriptorByIndex,
+            GetTableDescriptorByTag,
+            GetCountOfRecords,
+            GetRecordBroadcasterId,
+            GetRecordCountOfDescriptors,
+            GetRecordDescriptorByIndex,
+            GetRecordDescriptorByTag,
+            GetVersionHash,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IISDB_BIT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IISDB_CDT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psectionlist: *mut core::ffi::c_void, pmpegdata: *mut core::ffi::c_void, bsectionnumber: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_CDT", "Initialize", 3usize, |this| ((**(this as *const *const IISDB_CDT_Vtbl)).Initialize)(this, psectionlist, pmpegdata, bsectionnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionNumber(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_CDT", "GetVersionNumber", 4usize, |this| ((**(this as *const *const IISDB_CDT_Vtbl)).GetVersionNumber)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDownloadDataId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_CDT", "GetDownloadDataId", 5usize, |this| ((**(this as *const *const IISDB_CDT_Vtbl)).GetDownloadDataId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSectionNumber(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_CDT", "GetSectionNumber", 6usize, |this| ((**(this as *const *const IISDB_CDT_Vtbl)).GetSectionNumber)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOriginalNetworkId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_CDT", "GetOriginalNetworkId", 7usize, |this| ((**(this as *const *const IISDB_CDT_Vtbl)).GetOriginalNetworkId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataType(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_CDT", "GetDataType", 8usize, |this| ((**(this as *const *const IISDB_CDT_Vtbl)).GetDataType)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfTableDescriptors(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_CDT", "GetCountOfTableDescriptors", 9usize, |this| ((**(this as *const *const IISDB_CDT_Vtbl)).GetCountOfTableDescriptors)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableDescriptorByIndex(this: *mut core::ffi::c_void, dwindex: u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_CDT", "GetTableDescriptorByIndex", 10usize, |this| ((**(this as *const *const IISDB_CDT_Vtbl)).GetTableDescriptorByIndex)(this, dwindex, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableDescriptorByTag(this: *mut core::ffi::c_void, btag: u8, pdwcookie: *mut u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_CDT", "GetTableDescriptorByTag", 11usize, |this| ((**(this as *const *const IISDB_CDT_Vtbl)).GetTableDescriptorByTag)(this, btag, pdwcookie, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSizeOfDataModule(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_CDT", "GetSizeOfDataModule", 12usize, |this| ((**(this as *const *const IISDB_CDT_Vtbl)).GetSizeOfDataModule)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataModule(this: *mut core::ffi::c_void, pbdata: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_CDT", "GetDataModule", 13usize, |this| ((**(this as *const *const IISDB_CDT_Vtbl)).GetDataModule)(this, pbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionHash(this: *mut core::ffi::c_void, pdwversionhash: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_CDT", "GetVersionHash", 14usize, |this| ((**(this as *const *const IISDB_CDT_Vtbl)).GetVersionHash)(this, pdwversionhash), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetVersionNumber,
+            GetDownloadDataId,
+            GetSectionNumber,
+            GetOriginalNetworkId,
+            GetDataType,
+            GetCountOfTableDescriptors,
+            GetTableDescriptorByIndex,
+            GetTableDescriptorByTag,
+            GetSizeOfDataModule,
+            GetDataModule,
+            GetVersionHash,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IISDB_CDT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IISDB_EMM_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psectionlist: *mut core::ffi::c_void, pmpegdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_EMM", "Initialize", 3usize, |this| ((**(this as *const *const IISDB_EMM_Vtbl)).Initialize)(this, psectionlist, pmpegdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionNumber(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_EMM", "GetVersionNumber", 4usize, |this| ((**(this as *const *const IISDB_EMM_Vtbl)).GetVersionNumber)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableIdExtension(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_EMM", "GetTableIdExtension", 5usize, |this| ((**(this as *const *const IISDB_EMM_Vtbl)).GetTableIdExtension)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataBytes(this: *mut core::ffi::c_void, pwbufferlength: *mut u16, pbbuffer: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_EMM", "GetDataBytes", 6usize, |this| ((**(this as *const *const IISDB_EMM_Vtbl)).GetDataBytes)(this, pwbufferlength, pbbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSharedEmmMessage(this: *mut core::ffi::c_void, pwlength: *mut u16, ppbmessage: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_EMM", "GetSharedEmmMessage", 7usize, |this| ((**(this as *const *const IISDB_EMM_Vtbl)).GetSharedEmmMessage)(this, pwlength, ppbmessage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIndividualEmmMessage(this: *mut core::ffi::c_void, punknown: *mut core::ffi::c_void, pwlength: *mut u16, ppbmessage: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_EMM", "GetIndividualEmmMessage", 8usize, |this| ((**(this as *const *const IISDB_EMM_Vtbl)).GetIndividualEmmMessage)(this, punknown, pwlength, ppbmessage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionHash(this: *mut core::ffi::c_void, pdwversionhash: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_EMM", "GetVersionHash", 9usize, |this| ((**(this as *const *const IISDB_EMM_Vtbl)).GetVersionHash)(this, pdwversionhash), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetVersionNumber,
+            GetTableIdExtension,
+            GetDataBytes,
+            GetSharedEmmMessage,
+            GetIndividualEmmMessage,
+            GetVersionHash,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IISDB_EMM as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IISDB_LDT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psectionlist: *mut core::ffi::c_void, pmpegdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_LDT", "Initialize", 3usize, |this| ((**(this as *const *const IISDB_LDT_Vtbl)).Initialize)(this, psectionlist, pmpegdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionNumber(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_LDT", "GetVersionNumber", 4usize, |this| ((**(this as *const *const IISDB_LDT_Vtbl)).GetVersionNumber)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOriginalServiceId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_LDT", "GetOriginalServiceId", 5usize, |this| ((**(this as *const *const IISDB_LDT_Vtbl)).GetOriginalServiceId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransportStreamId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_LDT", "GetTransportStreamId", 6usize, |this| ((**(this as *const *const IISDB_LDT_Vtbl)).GetTransportStreamId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOriginalNetworkId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_LDT", "GetOriginalNetworkId", 7usize, |this| ((**(this as *const *const IISDB_LDT_Vtbl)).GetOriginalNetworkId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_LDT", "GetCountOfRecords", 8usize, |this| ((**(this as *const *const IISDB_LDT_Vtbl)).GetCountOfRecords)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptionId(this: *mut core::ffi::c_void, dwrecordindex: u32, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_LDT", "GetRecordDescriptionId", 9usize, |this| ((**(this as *const *const IISDB_LDT_Vtbl)).GetRecordDescriptionId)(this, dwrecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordCountOfDescriptors(this: *mut core::ffi::c_void, dwrecordindex: u32, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_LDT", "GetRecordCountOfDescriptors", 10usize, |this| ((**(this as *const *const IISDB_LDT_Vtbl)).GetRecordCountOfDescriptors)(this, dwrecordindex, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptorByIndex(this: *mut core::ffi::c_void, dwrecordindex: u32, dwindex: u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_LDT", "GetRecordDescriptorByIndex", 11usize, |this| ((**(this as *const *const IISDB_LDT_Vtbl)).GetRecordDescriptorByIndex)(this, dwrecordindex, dwindex, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptorByTag(this: *mut core::ffi::c_void, dwrecordindex: u32, btag: u8, pdwcookie: *mut u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_LDT", "GetRecordDescriptorByTag", 12usize, |this| ((**(this as *const *const IISDB_LDT_Vtbl)).GetRecordDescriptorByTag)(this, dwrecordindex, btag, pdwcookie, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionHash(this: *mut core::ffi::c_void, pdwversionhash: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_LDT", "GetVersionHash", 13usize, |this| ((**(this as *const *const IISDB_LDT_Vtbl)).GetVersionHash)(this, pdwversionhash), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetVersionNumber,
+            GetOriginalServiceId,
+            GetTransportStreamId,
+            GetOriginalNetworkId,
+            GetCountOfRecords,
+            GetRecordDescriptionId,
+            GetRecordCountOfDescriptors,
+            GetRecordDescriptorByIndex,
+            GetRecordDescriptorByTag,
+            GetVersionHash,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IISDB_LDT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IISDB_NBIT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psectionlist: *mut core::ffi::c_void, pmpegdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_NBIT", "Initialize", 3usize, |this| ((**(this as *const *const IISDB_NBIT_Vtbl)).Initialize)(this, psectionlist, pmpegdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionNumber(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_NBIT", "GetVersionNumber", 4usize, |this| ((**(this as *const *const IISDB_NBIT_Vtbl)).GetVersionNumber)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOriginalNetworkId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_NBIT", "GetOriginalNetworkId", 5usize, |this| ((**(this as *const *const IISDB_NBIT_Vtbl)).GetOriginalNetworkId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_NBIT", "GetCountOfRecords", 6usize, |this| ((**(this as *const *const IISDB_NBIT_Vtbl)).GetCountOfRecords)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordInformationId(this: *mut core::ffi::c_void, dwrecordindex: u32, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_NBIT", "GetRecordInformationId", 7usize, |this| ((**(this as *const *const IISDB_NBIT_Vtbl)).GetRecordInformationId)(this, dwrecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordInformationType(this: *mut core::ffi::c_void, dwrecordindex: u32, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_NBIT", "GetRecordInformationType", 8usize, |this| ((**(this as *const *const IISDB_NBIT_Vtbl)).GetRecordInformationType)(this, dwrecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptionBodyLocation(this: *mut core::ffi::c_void, dwrecordindex: u32, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_NBIT", "GetRecordDescriptionBodyLocation", 9usize, |this| ((**(this as *const *const IISDB_NBIT_Vtbl)).GetRecordDescriptionBodyLocation)(this, dwrecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordMessageSectionNumber(this: *mut core::ffi::c_void, dwrecordindex: u32, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_NBIT", "GetRecordMessageSectionNumber", 10usize, |this| ((**(this as *const *const IISDB_NBIT_Vtbl)).GetRecordMessageSectionNumber)(this, dwrecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordUserDefined(this: *mut core::ffi::c_void, dwrecordindex: u32, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_NBIT", "GetRecordUserDefined", 11usize, |this| ((**(this as *const *const IISDB_NBIT_Vtbl)).GetRecordUserDefined)(this, dwrecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordNumberOfKeys(this: *mut core::ffi::c_void, dwrecordindex: u32, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_NBIT", "GetRecordNumberOfKeys", 12usize, |this| ((**(this as *const *const IISDB_NBIT_Vtbl)).GetRecordNumberOfKeys)(this, dwrecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordKeys(this: *mut core::ffi::c_void, dwrecordindex: u32, pbkeys: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_NBIT", "GetRecordKeys", 13usize, |this| ((**(this as *const *const IISDB_NBIT_Vtbl)).GetRecordKeys)(this, dwrecordindex, pbkeys), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordCountOfDescriptors(this: *mut core::ffi::c_void, dwrecordindex: u32, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_NBIT", "GetRecordCountOfDescriptors", 14usize, |this| ((**(this as *const *const IISDB_NBIT_Vtbl)).GetRecordCountOfDescriptors)(this, dwrecordindex, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptorByIndex(this: *mut core::ffi::c_void, dwrecordindex: u32, dwindex: u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_NBIT", "GetRecordDescriptorByIndex", 15usize, |this| ((**(this as *const *const IISDB_NBIT_Vtbl)).GetRecordDescriptorByIndex)(this, dwrecordindex, dwindex, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptorByTag(this: *mut core::ffi::c_void, dwrecordindex: u32, btag: u8, pdwcookie: *mut u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_NBIT", "GetRecordDescriptorByTag", 16usize, |this| ((**(this as *const *const IISDB_NBIT_Vtbl)).GetRecordDescriptorByTag)(this, dwrecordindex, btag, pdwcookie, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionHash(this: *mut core::ffi::c_void, pdwversionhash: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_NBIT", "GetVersionHash", 17usize, |this| ((**(this as *const *const IISDB_NBIT_Vtbl)).GetVersionHash)(this, pdwversionhash), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetVersionNumber,
+            GetOriginalNetworkId,
+            GetCountOfRecords,
+            GetRecordInformationId,
+            GetRecordInformationType,
+            GetRecordDescriptionBodyLocation,
+            GetRecordMessageSectionNumber,
+            GetRecordUserDefined,
+            GetRecordNumberOfKeys,
+            GetRecordKeys,
+            GetRecordCountOfDescriptors,
+            GetRecordDescriptorByIndex,
+            GetRecordDescriptorByTag,
+            GetVersionHash,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IISDB_NBIT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IISDB_SDT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRecordEITUserDefinedFlags(this: *mut core::ffi::c_void, dwrecordindex: u32, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_SDT", "GetRecordEITUserDefinedFlags", 21usize, |this| ((**(this as *const *const IISDB_SDT_Vtbl)).GetRecordEITUserDefinedFlags)(this, dwrecordindex, pbval), |result| Some(*result))
+        }
+        Self { base__: <IDVB_SDT_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRecordEITUserDefinedFlags }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IISDB_SDT as windows_core::Interface>::IID || <IDVB_SDT_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IISDB_SDTT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psectionlist: *mut core::ffi::c_void, pmpegdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_SDTT", "Initialize", 3usize, |this| ((**(this as *const *const IISDB_SDTT_Vtbl)).Initialize)(this, psectionlist, pmpegdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionNumber(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_SDTT", "GetVersionNumber", 4usize, |this| ((**(this as *const *const IISDB_SDTT_Vtbl)).GetVersionNumber)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableIdExt(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_SDTT", "GetTableIdExt", 5usize, |this| ((**(this as *const *const IISDB_SDTT_Vtbl)).GetTableIdExt)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransportStreamId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_SDTT", "GetTransportStreamId", 6usize, |this| ((**(this as *const *const IISDB_SDTT_Vtbl)).GetTransportStreamId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOriginalNetworkId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_SDTT", "GetOriginalNetworkId", 7usize, |this| ((**(this as *const *const IISDB_SDTT_Vtbl)).GetOriginalNetworkId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServiceId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_SDTT", "GetServiceId", 8usize, |this| ((**(this as *const *const IISDB_SDTT_Vtbl)).GetServiceId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_SDTT", "GetCountOfRecords", 9usize, |this| ((**(this as *const *const IISDB_SDTT_Vtbl)).GetCountOfRecords)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordGroup(this: *mut core::ffi::c_void, dwrecordindex: u32, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_SDTT", "GetRecordGroup", 10usize, |this| ((**(this as *const *const IISDB_SDTT_Vtbl)).GetRecordGroup)(this, dwrecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordTargetVersion(this: *mut core::ffi::c_void, dwrecordindex: u32, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_SDTT", "GetRecordTargetVersion", 11usize, |this| ((**(this as *const *const IISDB_SDTT_Vtbl)).GetRecordTargetVersion)(this, dwrecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordNewVersion(this: *mut core::ffi::c_void, dwrecordindex: u32, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_SDTT", "GetRecordNewVersion", 12usize, |this| ((**(this as *const *const IISDB_SDTT_Vtbl)).GetRecordNewVersion)(this, dwrecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDownloadLevel(this: *mut core::ffi::c_void, dwrecordindex: u32, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_SDTT", "GetRecordDownloadLevel", 13usize, |this| ((**(this as *const *const IISDB_SDTT_Vtbl)).GetRecordDownloadLevel)(this, dwrecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordVersionIndicator(this: *mut core::ffi::c_void, dwrecordindex: u32, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_SDTT", "GetRecordVersionIndicator", 14usize, |this| ((**(this as *const *const IISDB_SDTT_Vtbl)).GetRecordVersionIndicator)(this, dwrecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordScheduleTimeShiftInformation(this: *mut core::ffi::c_void, dwrecordindex: u32, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_SDTT", "GetRecordScheduleTimeShiftInformation", 15usize, |this| ((**(this as *const *const IISDB_SDTT_Vtbl)).GetRecordScheduleTimeShiftInformation)(this, dwrecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordCountOfSchedules(this: *mut core::ffi::c_void, dwrecordindex: u32, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_SDTT", "GetRecordCountOfSchedules", 16usize, |this| ((**(this as *const *const IISDB_SDTT_Vtbl)).GetRecordCountOfSchedules)(this, dwrecordindex, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordStartTimeByIndex(this: *mut core::ffi::c_void, dwrecordindex: u32, dwindex: u32, pmdtval: *mut MPEG_DATE_AND_TIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_SDTT", "GetRecordStartTimeByIndex", 17usize, |this| ((**(this as *const *const IISDB_SDTT_Vtbl)).GetRecordStartTimeByIndex)(this, dwrecordindex, dwindex, pmdtval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDurationByIndex(this: *mut core::ffi::c_void, dwrecordindex: u32, dwindex: u32, pmdval: *mut MPEG_TIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_SDTT", "GetRecordDurationByIndex", 18usize, |this| ((**(this as *const *const IISDB_SDTT_Vtbl)).GetRecordDurationByIndex)(this, dwrecordindex, dwindex, pmdval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordCountOfDescriptors(this: *mut core::ffi::c_void, dwrecordindex: u32, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_SDTT", "GetRecordCountOfDescriptors", 19usize, |this| ((**(this as *const *const IISDB_SDTT_Vtbl)).GetRecordCountOfDescriptors)(this, dwrecordindex, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptorByIndex(this: *mut core::ffi::c_void, dwrecordindex: u32, dwindex: u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_SDTT", "GetRecordDescriptorByIndex", 20usize, |this| ((**(this as *const *const IISDB_SDTT_Vtbl)).GetRecordDescriptorByIndex)(this, dwrecordindex, dwindex, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptorByTag(this: *mut core::ffi::c_void, dwrecordindex: u32, btag: u8, pdwcookie: *mut u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_SDTT", "GetRecordDescriptorByTag", 21usize, |this| ((**(this as *const *const IISDB_SDTT_Vtbl)).GetRecordDescriptorByTag)(this, dwrecordindex, btag, pdwcookie, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionHash(this: *mut core::ffi::c_void, pdwversionhash: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IISDB_SDTT", "GetVersionHash", 22usize, |this| ((**(this as *const *const IISDB_SDTT_Vtbl)).GetVersionHash)(this, pdwversionhash), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetVersionNumber,
+            GetTableIdExt,
+            GetTransportStreamId,
+            GetOriginalNetworkId,
+            GetServiceId,
+            GetCountOfRecords,
+            GetRecordGroup,
+            GetRecordTargetVersion,
+            GetRecordNewVersion,
+            GetRecordDownloadLevel,
+            GetRecordVersionIndicator,
+            GetRecordScheduleTimeShiftInformation,
+            GetRecordCountOfSchedules,
+            GetRecordStartTimeByIndex,
+            GetRecordDurationByIndex,
+            GetRecordCountOfDescriptors,
+            GetRecordDescriptorByIndex,
+            GetRecordDescriptorByTag,
+            GetVersionHash,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IISDB_SDTT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIsdbAudioComponentDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbAudioComponentDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IIsdbAudioComponentDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbAudioComponentDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IIsdbAudioComponentDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamContent(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbAudioComponentDescriptor", "GetStreamContent", 5usize, |this| ((**(this as *const *const IIsdbAudioComponentDescriptor_Vtbl)).GetStreamContent)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetComponentType(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbAudioComponentDescriptor", "GetComponentType", 6usize, |this| ((**(this as *const *const IIsdbAudioComponentDescriptor_Vtbl)).GetComponentType)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetComponentTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbAudioComponentDescriptor", "GetComponentTag", 7usize, |this| ((**(this as *const *const IIsdbAudioComponentDescriptor_Vtbl)).GetComponentTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamType(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbAudioComponentDescriptor", "GetStreamType", 8usize, |this| ((**(this as *const *const IIsdbAudioComponentDescriptor_Vtbl)).GetStreamType)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSimulcastGroupTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbAudioComponentDescriptor", "GetSimulcastGroupTag", 9usize, |this| ((**(this as *const *const IIsdbAudioComponentDescriptor_Vtbl)).GetSimulcastGroupTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetESMultiLingualFlag(this: *mut core::ffi::c_void, pfval: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbAudioComponentDescriptor", "GetESMultiLingualFlag", 10usize, |this| ((**(this as *const *const IIsdbAudioComponentDescriptor_Vtbl)).GetESMultiLingualFlag)(this, pfval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMainComponentFlag(this: *mut core::ffi::c_void, pfval: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbAudioComponentDescriptor", "GetMainComponentFlag", 11usize, |this| ((**(this as *const *const IIsdbAudioComponentDescriptor_Vtbl)).GetMainComponentFlag)(this, pfval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetQualityIndicator(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbAudioComponentDescriptor", "GetQualityIndicator", 12usize, |this| ((**(this as *const *const IIsdbAudioComponentDescriptor_Vtbl)).GetQualityIndicator)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSamplingRate(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbAudioComponentDescriptor", "GetSamplingRate", 13usize, |this| ((**(this as *const *const IIsdbAudioComponentDescriptor_Vtbl)).GetSamplingRate)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLanguageCode(this: *mut core::ffi::c_void, pszcode: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbAudioComponentDescriptor", "GetLanguageCode", 14usize, |this| ((**(this as *const *const IIsdbAudioComponentDescriptor_Vtbl)).GetLanguageCode)(this, pszcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLanguageCode2(this: *mut core::ffi::c_void, pszcode: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbAudioComponentDescriptor", "GetLanguageCode2", 15usize, |this| ((**(this as *const *const IIsdbAudioComponentDescriptor_Vtbl)).GetLanguageCode2)(this, pszcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTextW(this: *mut core::ffi::c_void, convmode: DVB_STRCONV_MODE, pbstrtext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbAudioComponentDescriptor", "GetTextW", 16usize, |this| ((**(this as *const *const IIsdbAudioComponentDescriptor_Vtbl)).GetTextW)(this, convmode, pbstrtext), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetStreamContent,
+            GetComponentType,
+            GetComponentTag,
+            GetStreamType,
+            GetSimulcastGroupTag,
+            GetESMultiLingualFlag,
+            GetMainComponentFlag,
+            GetQualityIndicator,
+            GetSamplingRate,
+            GetLanguageCode,
+            GetLanguageCode2,
+            GetTextW,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsdbAudioComponentDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIsdbCAContractInformationDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbCAContractInformationDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IIsdbCAContractInformationDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbCAContractInformationDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IIsdbCAContractInformationDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCASystemId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbCAContractInformationDescriptor", "GetCASystemId", 5usize, |this| ((**(this as *const *const IIsdbCAContractInformationDescriptor_Vtbl)).GetCASystemId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCAUnitId(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbCAContractInformationDescriptor", "GetCAUnitId", 6usize, |this| ((**(this as *const *const IIsdbCAContractInformationDescriptor_Vtbl)).GetCAUnitId)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbCAContractInformationDescriptor", "GetCountOfRecords", 7usize, |this| ((**(this as *const *const IIsdbCAContractInformationDescriptor_Vtbl)).GetCountOfRecords)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordComponentTag(this: *mut core::ffi::c_void, brecordindex: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbCAContractInformationDescriptor", "GetRecordComponentTag", 8usize, |this| ((**(this as *const *const IIsdbCAContractInformationDescriptor_Vtbl)).GetRecordComponentTag)(this, brecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContractVerificationInfoLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbCAContractInformationDescriptor", "GetContractVerificationInfoLength", 9usize, |this| ((**(this as *const *const IIsdbCAContractInformationDescriptor_Vtbl)).GetContractVerificationInfoLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContractVerificationInfo(this: *mut core::ffi::c_void, bbuflength: u8, pbbuf: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbCAContractInformationDescriptor", "GetContractVerificationInfo", 10usize, |this| ((**(this as *const *const IIsdbCAContractInformationDescriptor_Vtbl)).GetContractVerificationInfo)(this, bbuflength, pbbuf), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFeeNameW(this: *mut core::ffi::c_void, convmode: DVB_STRCONV_MODE, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbCAContractInformationDescriptor", "GetFeeNameW", 11usize, |this| ((**(this as *const *const IIsdbCAContractInformationDescriptor_Vtbl)).GetFeeNameW)(this, convmode, pbstrname), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetCASystemId,
+            GetCAUnitId,
+            GetCountOfRecords,
+            GetRecordComponentTag,
+            GetContractVerificationInfoLength,
+            GetContractVerificationInfo,
+            GetFeeNameW,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsdbCAContractInformationDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIsdbCADescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbCADescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IIsdbCADescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbCADescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IIsdbCADescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCASystemId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbCADescriptor", "GetCASystemId", 5usize, |this| ((**(this as *const *const IIsdbCADescriptor_Vtbl)).GetCASystemId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReservedBits(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbCADescriptor", "GetReservedBits", 6usize, |this| ((**(this as *const *const IIsdbCADescriptor_Vtbl)).GetReservedBits)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCAPID(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbCADescriptor", "GetCAPID", 7usize, |this| ((**(this as *const *const IIsdbCADescriptor_Vtbl)).GetCAPID)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrivateDataBytes(this: *mut core::ffi::c_void, pbbufferlength: *mut u8, pbbuffer: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbCADescriptor", "GetPrivateDataBytes", 8usize, |this| ((**(this as *const *const IIsdbCADescriptor_Vtbl)).GetPrivateDataBytes)(this, pbbufferlength, pbbuffer), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetCASystemId,
+            GetReservedBits,
+            GetCAPID,
+            GetPrivateDataBytes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsdbCADescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIsdbCAServiceDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbCAServiceDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IIsdbCAServiceDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbCAServiceDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IIsdbCAServiceDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCASystemId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbCAServiceDescriptor", "GetCASystemId", 5usize, |this| ((**(this as *const *const IIsdbCAServiceDescriptor_Vtbl)).GetCASystemId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCABroadcasterGroupId(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbCAServiceDescriptor", "GetCABroadcasterGroupId", 6usize, |this| ((**(this as *const *const IIsdbCAServiceDescriptor_Vtbl)).GetCABroadcasterGroupId)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMessageControl(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbCAServiceDescriptor", "GetMessageControl", 7usize, |this| ((**(this as *const *const IIsdbCAServiceDescriptor_Vtbl)).GetMessageControl)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServiceIds(this: *mut core::ffi::c_void, pbnumserviceids: *mut u8, pwserviceids: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbCAServiceDescriptor", "GetServiceIds", 8usize, |this| ((**(this as *const *const IIsdbCAServiceDescriptor_Vtbl)).GetServiceIds)(this, pbnumserviceids, pwserviceids), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetCASystemId,
+            GetCABroadcasterGroupId,
+            GetMessageControl,
+            GetServiceIds,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsdbCAServiceDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIsdbComponentGroupDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbComponentGroupDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IIsdbComponentGroupDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbComponentGroupDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IIsdbComponentGroupDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetComponentGroupType(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbComponentGroupDescriptor", "GetComponentGroupType", 5usize, |this| ((**(this as *const *const IIsdbComponentGroupDescriptor_Vtbl)).GetComponentGroupType)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbComponentGroupDescriptor", "GetCountOfRecords", 6usize, |this| ((**(this as *const *const IIsdbComponentGroupDescriptor_Vtbl)).GetCountOfRecords)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordGroupId(this: *mut core::ffi::c_void, brecordindex: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbComponentGroupDescriptor", "GetRecordGroupId", 7usize, |this| ((**(this as *const *const IIsdbComponentGroupDescriptor_Vtbl)).GetRecordGroupId)(this, brecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordNumberOfCAUnit(this: *mut core::ffi::c_void, brecordindex: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbComponentGroupDescriptor", "GetRecordNumberOfCAUnit", 8usize, |this| ((**(this as *const *const IIsdbComponentGroupDescriptor_Vtbl)).GetRecordNumberOfCAUnit)(this, brecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordCAUnitCAUnitId(this: *mut core::ffi::c_void, brecordindex: u8, bcaunitindex: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbComponentGroupDescriptor", "GetRecordCAUnitCAUnitId", 9usize, |this| ((**(this as *const *const IIsdbComponentGroupDescriptor_Vtbl)).GetRecordCAUnitCAUnitId)(this, brecordindex, bcaunitindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordCAUnitNumberOfComponents(this: *mut core::ffi::c_void, brecordindex: u8, bcaunitindex: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbComponentGroupDescriptor", "GetRecordCAUnitNumberOfComponents", 10usize, |this| ((**(this as *const *const IIsdbComponentGroupDescriptor_Vtbl)).GetRecordCAUnitNumberOfComponents)(this, brecordindex, bcaunitindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordCAUnitComponentTag(this: *mut core::ffi::c_void, brecordindex: u8, bcaunitindex: u8, bcomponentindex: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbComponentGroupDescriptor", "GetRecordCAUnitComponentTag", 11usize, |this| ((**(this as *const *const IIsdbComponentGroupDescriptor_Vtbl)).GetRecordCAUnitComponentTag)(this, brecordindex, bcaunitindex, bcomponentindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordTotalBitRate(this: *mut core::ffi::c_void, brecordindex: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbComponentGroupDescriptor", "GetRecordTotalBitRate", 12usize, |this| ((**(this as *const *const IIsdbComponentGroupDescriptor_Vtbl)).GetRecordTotalBitRate)(this, brecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordTextW(this: *mut core::ffi::c_void, brecordindex: u8, convmode: DVB_STRCONV_MODE, pbstrtext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbComponentGroupDescriptor", "GetRecordTextW", 13usize, |this| ((**(this as *const *const IIsdbComponentGroupDescriptor_Vtbl)).GetRecordTextW)(this, brecordindex, convmode, pbstrtext), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetComponentGroupType,
+            GetCountOfRecords,
+            GetRecordGroupId,
+            GetRecordNumberOfCAUnit,
+            GetRecordCAUnitCAUnitId,
+            GetRecordCAUnitNumberOfComponents,
+            GetRecordCAUnitComponentTag,
+            GetRecordTotalBitRate,
+            GetRecordTextW,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsdbComponentGroupDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIsdbDataContentDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDataContentDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IIsdbDataContentDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDataContentDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IIsdbDataContentDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataComponentId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDataContentDescriptor", "GetDataComponentId", 5usize, |this| ((**(this as *const *const IIsdbDataContentDescriptor_Vtbl)).GetDataComponentId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEntryComponent(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDataContentDescriptor", "GetEntryComponent", 6usize, |this| ((**(this as *const *const IIsdbDataContentDescriptor_Vtbl)).GetEntryComponent)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSelectorLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDataContentDescriptor", "GetSelectorLength", 7usize, |this| ((**(this as *const *const IIsdbDataContentDescriptor_Vtbl)).GetSelectorLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSelectorBytes(this: *mut core::ffi::c_void, bbuflength: u8, pbbuf: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDataContentDescriptor", "GetSelectorBytes", 8usize, |this| ((**(this as *const *const IIsdbDataContentDescriptor_Vtbl)).GetSelectorBytes)(this, bbuflength, pbbuf), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDataContentDescriptor", "GetCountOfRecords", 9usize, |this| ((**(this as *const *const IIsdbDataContentDescriptor_Vtbl)).GetCountOfRecords)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordComponentRef(this: *mut core::ffi::c_void, brecordindex: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDataContentDescriptor", "GetRecordComponentRef", 10usize, |this| ((**(this as *const *const IIsdbDataContentDescriptor_Vtbl)).GetRecordComponentRef)(this, brecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLanguageCode(this: *mut core::ffi::c_void, pszcode: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDataContentDescriptor", "GetLanguageCode", 11usize, |this| ((**(this as *const *const IIsdbDataContentDescriptor_Vtbl)).GetLanguageCode)(this, pszcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTextW(this: *mut core::ffi::c_void, convmode: DVB_STRCONV_MODE, pbstrtext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDataContentDescriptor", "GetTextW", 12usize, |this| ((**(this as *const *const IIsdbDataContentDescriptor_Vtbl)).GetTextW)(this, convmode, pbstrtext), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetDataComponentId,
+            GetEntryComponent,
+            GetSelectorLength,
+            GetSelectorBytes,
+            GetCountOfRecords,
+            GetRecordComponentRef,
+            GetLanguageCode,
+            GetTextW,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsdbDataContentDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIsdbDigitalCopyControlDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDigitalCopyControlDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IIsdbDigitalCopyControlDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDigitalCopyControlDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IIsdbDigitalCopyControlDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCopyControl(this: *mut core::ffi::c_void, pbdigitalrecordingcontroldata: *mut u8, pbcopycontroltype: *mut u8, pbapscontroldata: *mut u8, pbmaximumbitrate: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDigitalCopyControlDescriptor", "GetCopyControl", 5usize, |this| ((**(this as *const *const IIsdbDigitalCopyControlDescriptor_Vtbl)).GetCopyControl)(this, pbdigitalrecordingcontroldata, pbcopycontroltype, pbapscontroldata, pbmaximumbitrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDigitalCopyControlDescriptor", "GetCountOfRecords", 6usize, |this| ((**(this as *const *const IIsdbDigitalCopyControlDescriptor_Vtbl)).GetCountOfRecords)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordCopyControl(this: *mut core::ffi::c_void, brecordindex: u8, pbcomponenttag: *mut u8, pbdigitalrecordingcontroldata: *mut u8, pbcopycontroltype: *mut u8, pbapscontroldata: *mut u8, pbmaximumbitrate: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDigitalCopyControlDescriptor", "GetRecordCopyControl", 7usize, |this| ((**(this as *const *const IIsdbDigitalCopyControlDescriptor_Vtbl)).GetRecordCopyControl)(this, brecordindex, pbcomponenttag, pbdigitalrecordingcontroldata, pbcopycontroltype, pbapscontroldata, pbmaximumbitrate), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetCopyControl,
+            GetCountOfRecords,
+            GetRecordCopyControl,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsdbDigitalCopyControlDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIsdbDownloadContentDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDownloadContentDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IIsdbDownloadContentDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDownloadContentDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IIsdbDownloadContentDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlags(this: *mut core::ffi::c_void, pfreboot: *mut super::super::super::Foundation::BOOL, pfaddon: *mut super::super::super::Foundation::BOOL, pfcompatibility: *mut super::super::super::Foundation::BOOL, pfmoduleinfo: *mut super::super::super::Foundation::BOOL, pftextinfo: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDownloadContentDescriptor", "GetFlags", 5usize, |this| ((**(this as *const *const IIsdbDownloadContentDescriptor_Vtbl)).GetFlags)(this, pfreboot, pfaddon, pfcompatibility, pfmoduleinfo, pftextinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetComponentSize(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDownloadContentDescriptor", "GetComponentSize", 6usize, |this| ((**(this as *const *const IIsdbDownloadContentDescriptor_Vtbl)).GetComponentSize)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDownloadId(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDownloadContentDescriptor", "GetDownloadId", 7usize, |this| ((**(this as *const *const IIsdbDownloadContentDescriptor_Vtbl)).GetDownloadId)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTimeOutValueDII(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDownloadContentDescriptor", "GetTimeOutValueDII", 8usize, |this| ((**(this as *const *const IIsdbDownloadContentDescriptor_Vtbl)).GetTimeOutValueDII)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLeakRate(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDownloadContentDescriptor", "GetLeakRate", 9usize, |this| ((**(this as *const *const IIsdbDownloadContentDescriptor_Vtbl)).GetLeakRate)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetComponentTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDownloadContentDescriptor", "GetComponentTag", 10usize, |this| ((**(this as *const *const IIsdbDownloadContentDescriptor_Vtbl)).GetComponentTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCompatiblityDescriptorLength(this: *mut core::ffi::c_void, pwlength: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDownloadContentDescriptor", "GetCompatiblityDescriptorLength", 11usize, |this| ((**(this as *const *const IIsdbDownloadContentDescriptor_Vtbl)).GetCompatiblityDescriptorLength)(this, pwlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCompatiblityDescriptor(this: *mut core::ffi::c_void, ppbdata: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDownloadContentDescriptor", "GetCompatiblityDescriptor", 12usize, |this| ((**(this as *const *const IIsdbDownloadContentDescriptor_Vtbl)).GetCompatiblityDescriptor)(this, ppbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDownloadContentDescriptor", "GetCountOfRecords", 13usize, |this| ((**(this as *const *const IIsdbDownloadContentDescriptor_Vtbl)).GetCountOfRecords)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordModuleId(this: *mut core::ffi::c_void, wrecordindex: u16, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDownloadContentDescriptor", "GetRecordModuleId", 14usize, |this| ((**(this as *const *const IIsdbDownloadContentDescriptor_Vtbl)).GetRecordModuleId)(this, wrecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordModuleSize(this: *mut core::ffi::c_void, wrecordindex: u16, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDownloadContentDescriptor", "GetRecordModuleSize", 15usize, |this| ((**(this as *const *const IIsdbDownloadContentDescriptor_Vtbl)).GetRecordModuleSize)(this, wrecordindex, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordModuleInfoLength(this: *mut core::ffi::c_void, wrecordindex: u16, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDownloadContentDescriptor", "GetRecordModuleInfoLength", 16usize, |this| ((**(this as *const *const IIsdbDownloadContentDescriptor_Vtbl)).GetRecordModuleInfoLength)(this, wrecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordModuleInfo(this: *mut core::ffi::c_void, wrecordindex: u16, ppbdata: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDownloadContentDescriptor", "GetRecordModuleInfo", 17usize, |this| ((**(this as *const *const IIsdbDownloadContentDescriptor_Vtbl)).GetRecordModuleInfo)(this, wrecordindex, ppbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTextLanguageCode(this: *mut core::ffi::c_void, szcode: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDownloadContentDescriptor", "GetTextLanguageCode", 18usize, |this| ((**(this as *const *const IIsdbDownloadContentDescriptor_Vtbl)).GetTextLanguageCode)(this, szcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTextW(this: *mut core::ffi::c_void, convmode: DVB_STRCONV_MODE, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbDownloadContentDescriptor", "GetTextW", 19usize, |this| ((**(this as *const *const IIsdbDownloadContentDescriptor_Vtbl)).GetTextW)(this, convmode, pbstrname), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetFlags,
+            GetComponentSize,
+            GetDownloadId,
+            GetTimeOutValueDII,
+            GetLeakRate,
+            GetComponentTag,
+            GetCompatiblityDescriptorLength,
+            GetCompatiblityDescriptor,
+            GetCountOfRecords,
+            GetRecordModuleId,
+            GetRecordModuleSize,
+            GetRecordModuleInfoLength,
+            GetRecordModuleInfo,
+            GetTextLanguageCode,
+            GetTextW,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsdbDownloadContentDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIsdbEmergencyInformationDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbEmergencyInformationDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IIsdbEmergencyInformationDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbEmergencyInformationDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IIsdbEmergencyInformationDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbEmergencyInformationDescriptor", "GetCountOfRecords", 5usize, |this| ((**(this as *const *const IIsdbEmergencyInformationDescriptor_Vtbl)).GetCountOfRecords)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServiceId(this: *mut core::ffi::c_void, brecordindex: u8, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbEmergencyInformationDescriptor", "GetServiceId", 6usize, |this| ((**(this as *const *const IIsdbEmergencyInformationDescriptor_Vtbl)).GetServiceId)(this, brecordindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStartEndFlag(this: *mut core::ffi::c_void, brecordindex: u8, pval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbEmergencyInformationDescriptor", "GetStartEndFlag", 7usize, |this| ((**(this as *const *const IIsdbEmergencyInformationDescriptor_Vtbl)).GetStartEndFlag)(this, brecordindex, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignalLevel(this: *mut core::ffi::c_void, brecordindex: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbEmergencyInformationDescriptor", "GetSignalLevel", 8usize, |this| ((**(this as *const *const IIsdbEmergencyInformationDescriptor_Vtbl)).GetSignalLevel)(this, brecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAreaCode(this: *mut core::ffi::c_void, brecordindex: u8, ppwval: *mut *mut u16, pbnumareacodes: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbEmergencyInformationDescriptor", "GetAreaCode", 9usize, |this| ((**(this as *const *const IIsdbEmergencyInformationDescriptor_Vtbl)).GetAreaCode)(this, brecordindex, ppwval, pbnumareacodes), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetCountOfRecords,
+            GetServiceId,
+            GetStartEndFlag,
+            GetSignalLevel,
+            GetAreaCode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsdbEmergencyInformationDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIsdbEventGroupDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbEventGroupDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IIsdbEventGroupDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbEventGroupDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IIsdbEventGroupDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGroupType(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbEventGroupDescriptor", "GetGroupType", 5usize, |this| ((**(this as *const *const IIsdbEventGroupDescriptor_Vtbl)).GetGroupType)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbEventGroupDescriptor", "GetCountOfRecords", 6usize, |this| ((**(this as *const *const IIsdbEventGroupDescriptor_Vtbl)).GetCountOfRecords)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordEvent(this: *mut core::ffi::c_void, brecordindex: u8, pwserviceid: *mut u16, pweventid: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbEventGroupDescriptor", "GetRecordEvent", 7usize, |this| ((**(this as *const *const IIsdbEventGroupDescriptor_Vtbl)).GetRecordEvent)(this, brecordindex, pwserviceid, pweventid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRefRecords(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbEventGroupDescriptor", "GetCountOfRefRecords", 8usize, |this| ((**(this as *const *const IIsdbEventGroupDescriptor_Vtbl)).GetCountOfRefRecords)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRefRecordEvent(this: *mut core::ffi::c_void, brecordindex: u8, pworiginalnetworkid: *mut u16, pwtransportstreamid: *mut u16, pwserviceid: *mut u16, pweventid: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbEventGroupDescriptor", "GetRefRecordEvent", 9usize, |this| ((**(this as *const *const IIsdbEventGroupDescriptor_Vtbl)).GetRefRecordEvent)(this, brecordindex, pworiginalnetworkid, pwtransportstreamid, pwserviceid, pweventid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetGroupType,
+            GetCountOfRecords,
+            GetRecordEvent,
+            GetCountOfRefRecords,
+            GetRefRecordEvent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsdbEventGroupDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIsdbHierarchicalTransmissionDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbHierarchicalTransmissionDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IIsdbHierarchicalTransmissionDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbHierarchicalTransmissionDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IIsdbHierarchicalTransmissionDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFutureUse1(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbHierarchicalTransmissionDescriptor", "GetFutureUse1", 5usize, |this| ((**(this as *const *const IIsdbHierarchicalTransmissionDescriptor_Vtbl)).GetFutureUse1)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetQualityLevel(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbHierarchicalTransmissionDescriptor", "GetQualityLevel", 6usize, |this| ((**(this as *const *const IIsdbHierarchicalTransmissionDescriptor_Vtbl)).GetQualityLevel)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFutureUse2(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbHierarchicalTransmissionDescriptor", "GetFutureUse2", 7usize, |this| ((**(this as *const *const IIsdbHierarchicalTransmissionDescriptor_Vtbl)).GetFutureUse2)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReferencePid(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbHierarchicalTransmissionDescriptor", "GetReferencePid", 8usize, |this| ((**(this as *const *const IIsdbHierarchicalTransmissionDescriptor_Vtbl)).GetReferencePid)(this, pwval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetFutureUse1,
+            GetQualityLevel,
+            GetFutureUse2,
+            GetReferencePid,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsdbHierarchicalTransmissionDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIsdbLogoTransmissionDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbLogoTransmissionDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IIsdbLogoTransmissionDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbLogoTransmissionDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IIsdbLogoTransmissionDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLogoTransmissionType(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbLogoTransmissionDescriptor", "GetLogoTransmissionType", 5usize, |this| ((**(this as *const *const IIsdbLogoTransmissionDescriptor_Vtbl)).GetLogoTransmissionType)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLogoId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbLogoTransmissionDescriptor", "GetLogoId", 6usize, |this| ((**(this as *const *const IIsdbLogoTransmissionDescriptor_Vtbl)).GetLogoId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLogoVersion(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbLogoTransmissionDescriptor", "GetLogoVersion", 7usize, |this| ((**(this as *const *const IIsdbLogoTransmissionDescriptor_Vtbl)).GetLogoVersion)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDownloadDataId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbLogoTransmissionDescriptor", "GetDownloadDataId", 8usize, |this| ((**(this as *const *const IIsdbLogoTransmissionDescriptor_Vtbl)).GetDownloadDataId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLogoCharW(this: *mut core::ffi::c_void, convmode: DVB_STRCONV_MODE, pbstrchar: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbLogoTransmissionDescriptor", "GetLogoCharW", 9usize, |this| ((**(this as *const *const IIsdbLogoTransmissionDescriptor_Vtbl)).GetLogoCharW)(this, convmode, pbstrchar), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetLogoTransmissionType,
+            GetLogoId,
+            GetLogoVersion,
+            GetDownloadDataId,
+            GetLogoCharW,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsdbLogoTransmissionDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIsdbSIParameterDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbSIParameterDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IIsdbSIParameterDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbSIParameterDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IIsdbSIParameterDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParameterVersion(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbSIParameterDescriptor", "GetParameterVersion", 5usize, |this| ((**(this as *const *const IIsdbSIParameterDescriptor_Vtbl)).GetParameterVersion)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUpdateTime(this: *mut core::ffi::c_void, pval: *mut MPEG_DATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbSIParameterDescriptor", "GetUpdateTime", 6usize, |this| ((**(this as *const *const IIsdbSIParameterDescriptor_Vtbl)).GetUpdateTime)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordNumberOfTable(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbSIParameterDescriptor", "GetRecordNumberOfTable", 7usize, |this| ((**(this as *const *const IIsdbSIParameterDescriptor_Vtbl)).GetRecordNumberOfTable)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableId(this: *mut core::ffi::c_void, brecordindex: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbSIParameterDescriptor", "GetTableId", 8usize, |this| ((**(this as *const *const IIsdbSIParameterDescriptor_Vtbl)).GetTableId)(this, brecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableDescriptionLength(this: *mut core::ffi::c_void, brecordindex: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbSIParameterDescriptor", "GetTableDescriptionLength", 9usize, |this| ((**(this as *const *const IIsdbSIParameterDescriptor_Vtbl)).GetTableDescriptionLength)(this, brecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableDescriptionBytes(this: *mut core::ffi::c_void, brecordindex: u8, pbbufferlength: *mut u8, pbbuffer: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbSIParameterDescriptor", "GetTableDescriptionBytes", 10usize, |this| ((**(this as *const *const IIsdbSIParameterDescriptor_Vtbl)).GetTableDescriptionBytes)(this, brecordindex, pbbufferlength, pbbuffer), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetParameterVersion,
+            GetUpdateTime,
+            GetRecordNumberOfTable,
+            GetTableId,
+            GetTableDescriptionLength,
+            GetTableDescriptionBytes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsdbSIParameterDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIsdbSeriesDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbSeriesDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IIsdbSeriesDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbSeriesDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IIsdbSeriesDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSeriesId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbSeriesDescriptor", "GetSeriesId", 5usize, |this| ((**(this as *const *const IIsdbSeriesDescriptor_Vtbl)).GetSeriesId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRepeatLabel(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbSeriesDescriptor", "GetRepeatLabel", 6usize, |this| ((**(this as *const *const IIsdbSeriesDescriptor_Vtbl)).GetRepeatLabel)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProgramPattern(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbSeriesDescriptor", "GetProgramPattern", 7usize, |this| ((**(this as *const *const IIsdbSeriesDescriptor_Vtbl)).GetProgramPattern)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExpireDate(this: *mut core::ffi::c_void, pfvalid: *mut super::super::super::Foundation::BOOL, pmdtval: *mut MPEG_DATE_AND_TIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbSeriesDescriptor", "GetExpireDate", 8usize, |this| ((**(this as *const *const IIsdbSeriesDescriptor_Vtbl)).GetExpireDate)(this, pfvalid, pmdtval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEpisodeNumber(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbSeriesDescriptor", "GetEpisodeNumber", 9usize, |this| ((**(this as *const *const IIsdbSeriesDescriptor_Vtbl)).GetEpisodeNumber)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastEpisodeNumber(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbSeriesDescriptor", "GetLastEpisodeNumber", 10usize, |this| ((**(this as *const *const IIsdbSeriesDescriptor_Vtbl)).GetLastEpisodeNumber)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSeriesNameW(this: *mut core::ffi::c_void, convmode: DVB_STRCONV_MODE, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbSeriesDescriptor", "GetSeriesNameW", 11usize, |this| ((**(this as *const *const IIsdbSeriesDescriptor_Vtbl)).GetSeriesNameW)(this, convmode, pbstrname), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetSeriesId,
+            GetRepeatLabel,
+            GetProgramPattern,
+            GetExpireDate,
+            GetEpisodeNumber,
+            GetLastEpisodeNumber,
+            GetSeriesNameW,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsdbSeriesDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIsdbSiParser2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSDT(this: *mut core::ffi::c_void, tableid: u8, pwtransportstreamid: *const u16, ppsdt: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbSiParser2", "GetSDT", 19usize, |this| ((**(this as *const *const IIsdbSiParser2_Vtbl)).GetSDT)(this, tableid, pwtransportstreamid, ppsdt), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBIT(this: *mut core::ffi::c_void, tableid: u8, pworiginalnetworkid: *const u16, ppbit: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbSiParser2", "GetBIT", 20usize, |this| ((**(this as *const *const IIsdbSiParser2_Vtbl)).GetBIT)(this, tableid, pworiginalnetworkid, ppbit), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNBIT(this: *mut core::ffi::c_void, tableid: u8, pworiginalnetworkid: *const u16, ppnbit: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbSiParser2", "GetNBIT", 21usize, |this| ((**(this as *const *const IIsdbSiParser2_Vtbl)).GetNBIT)(this, tableid, pworiginalnetworkid, ppnbit), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLDT(this: *mut core::ffi::c_void, tableid: u8, pworiginalserviceid: *const u16, ppldt: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbSiParser2", "GetLDT", 22usize, |this| ((**(this as *const *const IIsdbSiParser2_Vtbl)).GetLDT)(this, tableid, pworiginalserviceid, ppldt), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSDTT(this: *mut core::ffi::c_void, tableid: u8, pwtableidext: *const u16, ppsdtt: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbSiParser2", "GetSDTT", 23usize, |this| ((**(this as *const *const IIsdbSiParser2_Vtbl)).GetSDTT)(this, tableid, pwtableidext, ppsdtt), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCDT(this: *mut core::ffi::c_void, tableid: u8, bsectionnumber: u8, pwdownloaddataid: *const u16, ppcdt: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbSiParser2", "GetCDT", 24usize, |this| ((**(this as *const *const IIsdbSiParser2_Vtbl)).GetCDT)(this, tableid, bsectionnumber, pwdownloaddataid, ppcdt), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEMM(this: *mut core::ffi::c_void, pid: u16, wtableidext: u16, ppemm: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbSiParser2", "GetEMM", 25usize, |this| ((**(this as *const *const IIsdbSiParser2_Vtbl)).GetEMM)(this, pid, wtableidext, ppemm), |result| Some(*result))
+        }
+        Self { base__: <IDvbSiParser2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSDT, GetBIT, GetNBIT, GetLDT, GetSDTT, GetCDT, GetEMM }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsdbSiParser2 as windows_core::Interface>::IID || <IDvbSiParser2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIsdbTSInformationDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbTSInformationDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IIsdbTSInformationDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbTSInformationDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IIsdbTSInformationDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRemoteControlKeyId(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbTSInformationDescriptor", "GetRemoteControlKeyId", 5usize, |this| ((**(this as *const *const IIsdbTSInformationDescriptor_Vtbl)).GetRemoteControlKeyId)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTSNameW(this: *mut core::ffi::c_void, convmode: DVB_STRCONV_MODE, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbTSInformationDescriptor", "GetTSNameW", 6usize, |this| ((**(this as *const *const IIsdbTSInformationDescriptor_Vtbl)).GetTSNameW)(this, convmode, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbTSInformationDescriptor", "GetCountOfRecords", 7usize, |this| ((**(this as *const *const IIsdbTSInformationDescriptor_Vtbl)).GetCountOfRecords)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordTransmissionTypeInfo(this: *mut core::ffi::c_void, brecordindex: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbTSInformationDescriptor", "GetRecordTransmissionTypeInfo", 8usize, |this| ((**(this as *const *const IIsdbTSInformationDescriptor_Vtbl)).GetRecordTransmissionTypeInfo)(this, brecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordNumberOfServices(this: *mut core::ffi::c_void, brecordindex: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbTSInformationDescriptor", "GetRecordNumberOfServices", 9usize, |this| ((**(this as *const *const IIsdbTSInformationDescriptor_Vtbl)).GetRecordNumberOfServices)(this, brecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordServiceIdByIndex(this: *mut core::ffi::c_void, brecordindex: u8, bserviceindex: u8, pdwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbTSInformationDescriptor", "GetRecordServiceIdByIndex", 10usize, |this| ((**(this as *const *const IIsdbTSInformationDescriptor_Vtbl)).GetRecordServiceIdByIndex)(this, brecordindex, bserviceindex, pdwval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetRemoteControlKeyId,
+            GetTSNameW,
+            GetCountOfRecords,
+            GetRecordTransmissionTypeInfo,
+            GetRecordNumberOfServices,
+            GetRecordServiceIdByIndex,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsdbTSInformationDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIsdbTerrestrialDeliverySystemDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbTerrestrialDeliverySystemDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IIsdbTerrestrialDeliverySystemDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbTerrestrialDeliverySystemDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IIsdbTerrestrialDeliverySystemDescriptor_Vtbl)).GetLength)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAreaCode(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbTerrestrialDeliverySystemDescriptor", "GetAreaCode", 5usize, |this| ((**(this as *const *const IIsdbTerrestrialDeliverySystemDescriptor_Vtbl)).GetAreaCode)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGuardInterval(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbTerrestrialDeliverySystemDescriptor", "GetGuardInterval", 6usize, |this| ((**(this as *const *const IIsdbTerrestrialDeliverySystemDescriptor_Vtbl)).GetGuardInterval)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransmissionMode(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbTerrestrialDeliverySystemDescriptor", "GetTransmissionMode", 7usize, |this| ((**(this as *const *const IIsdbTerrestrialDeliverySystemDescriptor_Vtbl)).GetTransmissionMode)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbTerrestrialDeliverySystemDescriptor", "GetCountOfRecords", 8usize, |this| ((**(this as *const *const IIsdbTerrestrialDeliverySystemDescriptor_Vtbl)).GetCountOfRecords)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordFrequency(this: *mut core::ffi::c_void, brecordindex: u8, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsdbTerrestrialDeliverySystemDescriptor", "GetRecordFrequency", 9usize, |this| ((**(this as *const *const IIsdbTerrestrialDeliverySystemDescriptor_Vtbl)).GetRecordFrequency)(this, brecordindex, pdwval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTag,
+            GetLength,
+            GetAreaCode,
+            GetGuardInterval,
+            GetTransmissionMode,
+            GetCountOfRecords,
+            GetRecordFrequency,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsdbTerrestrialDeliverySystemDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Media_MediaFoundation", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ILanguageComponentType_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LangID(this: *mut core::ffi::c_void, langid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageComponentType", "LangID", 24usize, |this| ((**(this as *const *const ILanguageComponentType_Vtbl)).LangID)(this, langid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLangID(this: *mut core::ffi::c_void, langid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageComponentType", "SetLangID", 25usize, |this| ((**(this as *const *const ILanguageComponentType_Vtbl)).SetLangID)(this, langid), |result| Some(*result))
+        }
+        Self { base__: <IComponentType_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LangID, SetLangID }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILanguageComponentType as windows_core::Interface>::IID || <IComponentType_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ILocator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CarrierFrequency(this: *mut core::ffi::c_void, frequency: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocator", "CarrierFrequency", 7usize, |this| ((**(this as *const *const ILocator_Vtbl)).CarrierFrequency)(this, frequency), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCarrierFrequency(this: *mut core::ffi::c_void, frequency: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocator", "SetCarrierFrequency", 8usize, |this| ((**(this as *const *const ILocator_Vtbl)).SetCarrierFrequency)(this, frequency), |result| Some(*result))
+        }
+        unsafe extern "system" fn InnerFEC(this: *mut core::ffi::c_void, fec: *mut super::FECMethod) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocator", "InnerFEC", 9usize, |this| ((**(this as *const *const ILocator_Vtbl)).InnerFEC)(this, fec), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInnerFEC(this: *mut core::ffi::c_void, fec: super::FECMethod) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocator", "SetInnerFEC", 10usize, |this| ((**(this as *const *const ILocator_Vtbl)).SetInnerFEC)(this, fec), |result| Some(*result))
+        }
+        unsafe extern "system" fn InnerFECRate(this: *mut core::ffi::c_void, fec: *mut super::BinaryConvolutionCodeRate) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocator", "InnerFECRate", 11usize, |this| ((**(this as *const *const ILocator_Vtbl)).InnerFECRate)(this, fec), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInnerFECRate(this: *mut core::ffi::c_void, fec: super::BinaryConvolutionCodeRate) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocator", "SetInnerFECRate", 12usize, |this| ((**(this as *const *const ILocator_Vtbl)).SetInnerFECRate)(this, fec), |result| Some(*result))
+        }
+        unsafe extern "system" fn OuterFEC(this: *mut core::ffi::c_void, fec: *mut super::FECMethod) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocator", "OuterFEC", 13usize, |this| ((**(this as *const *const ILocator_Vtbl)).OuterFEC)(this, fec), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOuterFEC(this: *mut core::ffi::c_void, fec: super::FECMethod) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocator", "SetOuterFEC", 14usize, |this| ((**(this as *const *const ILocator_Vtbl)).SetOuterFEC)(this, fec), |result| Some(*result))
+        }
+        unsafe extern "system" fn OuterFECRate(this: *mut core::ffi::c_void, fec: *mut super::BinaryConvolutionCodeRate) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocator", "OuterFECRate", 15usize, |this| ((**(this as *const *const ILocator_Vtbl)).OuterFECRate)(this, fec), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOuterFECRate(this: *mut core::ffi::c_void, fec: super::BinaryConvolutionCodeRate) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocator", "SetOuterFECRate", 16usize, |this| ((**(this as *const *const ILocator_Vtbl)).SetOuterFECRate)(this, fec), |result| Some(*result))
+        }
+        unsafe extern "system" fn Modulation(this: *mut core::ffi::c_void, modulation: *mut super::ModulationType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocator", "Modulation", 17usize, |this| ((**(this as *const *const ILocator_Vtbl)).Modulation)(this, modulation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetModulation(this: *mut core::ffi::c_void, modulation: super::ModulationType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocator", "SetModulation", 18usize, |this| ((**(this as *const *const ILocator_Vtbl)).SetModulation)(this, modulation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SymbolRate(this: *mut core::ffi::c_void, rate: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocator", "SymbolRate", 19usize, |this| ((**(this as *const *const ILocator_Vtbl)).SymbolRate)(this, rate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSymbolRate(this: *mut core::ffi::c_void, rate: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocator", "SetSymbolRate", 20usize, |this| ((**(this as *const *const ILocator_Vtbl)).SetSymbolRate)(this, rate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, newlocator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILocator", "Clone", 21usize, |this| ((**(this as *const *const ILocator_Vtbl)).Clone)(this, newlocator), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CarrierFrequency,
+            SetCarrierFrequency,
+            InnerFEC,
+            SetInnerFEC,
+            InnerFECRate,
+            SetInnerFECRate,
+            OuterFEC,
+            SetOuterFEC,
+            OuterFECRate,
+            SetOuterFECRate,
+            Modulation,
+            SetModulation,
+            SymbolRate,
+            SetSymbolRate,
+            #[cfg(feature = "Win32_System_Com")]
+            Clone,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Clone: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILocator as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMPEG2Component_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PID(this: *mut core::ffi::c_void, pid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMPEG2Component", "PID", 16usize, |this| ((**(this as *const *const IMPEG2Component_Vtbl)).PID)(this, pid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPID(this: *mut core::ffi::c_void, pid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMPEG2Component", "SetPID", 17usize, |this| ((**(this as *const *const IMPEG2Component_Vtbl)).SetPID)(this, pid), |result| Some(*result))
+        }
+        unsafe extern "system" fn PCRPID(this: *mut core::ffi::c_void, pcrpid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMPEG2Component", "PCRPID", 18usize, |this| ((**(this as *const *const IMPEG2Component_Vtbl)).PCRPID)(this, pcrpid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPCRPID(this: *mut core::ffi::c_void, pcrpid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMPEG2Component", "SetPCRPID", 19usize, |this| ((**(this as *const *const IMPEG2Component_Vtbl)).SetPCRPID)(this, pcrpid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProgramNumber(this: *mut core::ffi::c_void, programnumber: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMPEG2Component", "ProgramNumber", 20usize, |this| ((**(this as *const *const IMPEG2Component_Vtbl)).ProgramNumber)(this, programnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProgramNumber(this: *mut core::ffi::c_void, programnumber: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMPEG2Component", "SetProgramNumber", 21usize, |this| ((**(this as *const *const IMPEG2Component_Vtbl)).SetProgramNumber)(this, programnumber), |result| Some(*result))
+        }
+        Self { base__: <IComponent_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PID, SetPID, PCRPID, SetPCRPID, ProgramNumber, SetProgramNumber }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMPEG2Component as windows_core::Interface>::IID || <IComponent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Media_MediaFoundation", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IMPEG2ComponentType_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StreamType(this: *mut core::ffi::c_void, mp2streamtype: *mut super::MPEG2StreamType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMPEG2ComponentType", "StreamType", 26usize, |this| ((**(this as *const *const IMPEG2ComponentType_Vtbl)).StreamType)(this, mp2streamtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStreamType(this: *mut core::ffi::c_void, mp2streamtype: super::MPEG2StreamType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMPEG2ComponentType", "SetStreamType", 27usize, |this| ((**(this as *const *const IMPEG2ComponentType_Vtbl)).SetStreamType)(this, mp2streamtype), |result| Some(*result))
+        }
+        Self { base__: <ILanguageComponentType_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StreamType, SetStreamType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMPEG2ComponentType as windows_core::Interface>::IID || <ILanguageComponentType_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMPEG2TuneRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TSID(this: *mut core::ffi::c_void, tsid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMPEG2TuneRequest", "TSID", 12usize, |this| ((**(this as *const *const IMPEG2TuneRequest_Vtbl)).TSID)(this, tsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTSID(this: *mut core::ffi::c_void, tsid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMPEG2TuneRequest", "SetTSID", 13usize, |this| ((**(this as *const *const IMPEG2TuneRequest_Vtbl)).SetTSID)(this, tsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProgNo(this: *mut core::ffi::c_void, progno: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMPEG2TuneRequest", "ProgNo", 14usize, |this| ((**(this as *const *const IMPEG2TuneRequest_Vtbl)).ProgNo)(this, progno), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProgNo(this: *mut core::ffi::c_void, progno: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMPEG2TuneRequest", "SetProgNo", 15usize, |this| ((**(this as *const *const IMPEG2TuneRequest_Vtbl)).SetProgNo)(this, progno), |result| Some(*result))
+        }
+        Self { base__: <ITuneRequest_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TSID, SetTSID, ProgNo, SetProgNo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMPEG2TuneRequest as windows_core::Interface>::IID || <ITuneRequest_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMPEG2TuneRequestFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateTuneRequest(this: *mut core::ffi::c_void, tuningspace: *mut core::ffi::c_void, tunerequest: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMPEG2TuneRequestFactory", "CreateTuneRequest", 7usize, |this| ((**(this as *const *const IMPEG2TuneRequestFactory_Vtbl)).CreateTuneRequest)(this, tuningspace, tunerequest), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateTuneRequest,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateTuneRequest: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMPEG2TuneRequestFactory as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMPEG2TuneRequestSupport_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMPEG2TuneRequestSupport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMPEG2_TIF_CONTROL_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterTIF(this: *mut core::ffi::c_void, punktif: *mut core::ffi::c_void, ppvregistrationcontext: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMPEG2_TIF_CONTROL", "RegisterTIF", 3usize, |this| ((**(this as *const *const IMPEG2_TIF_CONTROL_Vtbl)).RegisterTIF)(this, punktif, ppvregistrationcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterTIF(this: *mut core::ffi::c_void, pvregistrationcontext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMPEG2_TIF_CONTROL", "UnregisterTIF", 4usize, |this| ((**(this as *const *const IMPEG2_TIF_CONTROL_Vtbl)).UnregisterTIF)(this, pvregistrationcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPIDs(this: *mut core::ffi::c_void, ulcpids: u32, pulpids: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMPEG2_TIF_CONTROL", "AddPIDs", 5usize, |this| ((**(this as *const *const IMPEG2_TIF_CONTROL_Vtbl)).AddPIDs)(this, ulcpids, pulpids), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeletePIDs(this: *mut core::ffi::c_void, ulcpids: u32, pulpids: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMPEG2_TIF_CONTROL", "DeletePIDs", 6usize, |this| ((**(this as *const *const IMPEG2_TIF_CONTROL_Vtbl)).DeletePIDs)(this, ulcpids, pulpids), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPIDCount(this: *mut core::ffi::c_void, pulcpids: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMPEG2_TIF_CONTROL", "GetPIDCount", 7usize, |this| ((**(this as *const *const IMPEG2_TIF_CONTROL_Vtbl)).GetPIDCount)(this, pulcpids), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPIDs(this: *mut core::ffi::c_void, pulcpids: *mut u32, pulpids: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMPEG2_TIF_CONTROL", "GetPIDs", 8usize, |this| ((**(this as *const *const IMPEG2_TIF_CONTROL_Vtbl)).GetPIDs)(this, pulcpids, pulpids), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RegisterTIF,
+            UnregisterTIF,
+            AddPIDs,
+            DeletePIDs,
+            GetPIDCount,
+            GetPIDs,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMPEG2_TIF_CONTROL as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSEventBinder_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Bind(this: *mut core::ffi::c_void, peventobject: *mut core::ffi::c_void, eventname: core::mem::MaybeUninit<windows_core::BSTR>, eventhandler: core::mem::MaybeUninit<windows_core::BSTR>, cancelid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSEventBinder", "Bind", 7usize, |this| ((**(this as *const *const IMSEventBinder_Vtbl)).Bind)(this, peventobject, eventname, eventhandler, cancelid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unbind(this: *mut core::ffi::c_void, cancelcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSEventBinder", "Unbind", 8usize, |this| ((**(this as *const *const IMSEventBinder_Vtbl)).Unbind)(this, cancelcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Bind,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Bind: 0,
+            Unbind,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSEventBinder as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidAnalogTuner_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Channel(this: *mut core::ffi::c_void, channel: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAnalogTuner", "Channel", 22usize, |this| ((**(this as *const *const IMSVidAnalogTuner_Vtbl)).Channel)(this, channel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetChannel(this: *mut core::ffi::c_void, channel: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAnalogTuner", "SetChannel", 23usize, |this| ((**(this as *const *const IMSVidAnalogTuner_Vtbl)).SetChannel)(this, channel), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFrequency(this: *mut core::ffi::c_void, lcc: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAnalogTuner", "VideoFrequency", 24usize, |this| ((**(this as *const *const IMSVidAnalogTuner_Vtbl)).VideoFrequency)(this, lcc), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioFrequency(this: *mut core::ffi::c_void, lcc: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAnalogTuner", "AudioFrequency", 25usize, |this| ((**(this as *const *const IMSVidAnalogTuner_Vtbl)).AudioFrequency)(this, lcc), |result| Some(*result))
+        }
+        unsafe extern "system" fn CountryCode(this: *mut core::ffi::c_void, lcc: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAnalogTuner", "CountryCode", 26usize, |this| ((**(this as *const *const IMSVidAnalogTuner_Vtbl)).CountryCode)(this, lcc), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCountryCode(this: *mut core::ffi::c_void, lcc: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAnalogTuner", "SetCountryCode", 27usize, |this| ((**(this as *const *const IMSVidAnalogTuner_Vtbl)).SetCountryCode)(this, lcc), |result| Some(*result))
+        }
+        unsafe extern "system" fn SAP(this: *mut core::ffi::c_void, pfsapon: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAnalogTuner", "SAP", 28usize, |this| ((**(this as *const *const IMSVidAnalogTuner_Vtbl)).SAP)(this, pfsapon), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSAP(this: *mut core::ffi::c_void, fsapon: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAnalogTuner", "SetSAP", 29usize, |this| ((**(this as *const *const IMSVidAnalogTuner_Vtbl)).SetSAP)(this, fsapon), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChannelAvailable(this: *mut core::ffi::c_void, nchannel: i32, signalstrength: *mut i32, fsignalpresent: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAnalogTuner", "ChannelAvailable", 30usize, |this| ((**(this as *const *const IMSVidAnalogTuner_Vtbl)).ChannelAvailable)(this, nchannel, signalstrength, fsignalpresent), |result| Some(*result))
+        }
+        Self {
+            base__: <IMSVidTuner_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Channel,
+            SetChannel,
+            VideoFrequency,
+            AudioFrequency,
+            CountryCode,
+            SetCountryCode,
+            SAP,
+            SetSAP,
+            ChannelAvailable,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidAnalogTuner as windows_core::Interface>::IID || <IMSVidTuner_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidAnalogTuner2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TVFormats(this: *mut core::ffi::c_void, formats: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAnalogTuner2", "TVFormats", 31usize, |this| ((**(this as *const *const IMSVidAnalogTuner2_Vtbl)).TVFormats)(this, formats), |result| Some(*result))
+        }
+        unsafe extern "system" fn TunerModes(this: *mut core::ffi::c_void, modes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAnalogTuner2", "TunerModes", 32usize, |this| ((**(this as *const *const IMSVidAnalogTuner2_Vtbl)).TunerModes)(this, modes), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumAuxInputs(this: *mut core::ffi::c_void, inputs: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAnalogTuner2", "NumAuxInputs", 33usize, |this| ((**(this as *const *const IMSVidAnalogTuner2_Vtbl)).NumAuxInputs)(this, inputs), |result| Some(*result))
+        }
+        Self { base__: <IMSVidAnalogTuner_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TVFormats, TunerModes, NumAuxInputs }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidAnalogTuner2 as windows_core::Interface>::IID || <IMSVidAnalogTuner_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidAnalogTunerEvent_Vtbl {
+    const PROXY: Self = { Self { base__: <IMSVidTunerEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidAnalogTunerEvent as windows_core::Interface>::IID || <IMSVidTunerEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidAudioRenderer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetVolume(this: *mut core::ffi::c_void, lvol: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAudioRenderer", "SetVolume", 16usize, |this| ((**(this as *const *const IMSVidAudioRenderer_Vtbl)).SetVolume)(this, lvol), |result| Some(*result))
+        }
+        unsafe extern "system" fn Volume(this: *mut core::ffi::c_void, lvol: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAudioRenderer", "Volume", 17usize, |this| ((**(this as *const *const IMSVidAudioRenderer_Vtbl)).Volume)(this, lvol), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBalance(this: *mut core::ffi::c_void, lbal: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAudioRenderer", "SetBalance", 18usize, |this| ((**(this as *const *const IMSVidAudioRenderer_Vtbl)).SetBalance)(this, lbal), |result| Some(*result))
+        }
+        unsafe extern "system" fn Balance(this: *mut core::ffi::c_void, lbal: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAudioRenderer", "Balance", 19usize, |this| ((**(this as *const *const IMSVidAudioRenderer_Vtbl)).Balance)(this, lbal), |result| Some(*result))
+        }
+        Self { base__: <IMSVidOutputDevice_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetVolume, Volume, SetBalance, Balance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidAudioRenderer as windows_core::Interface>::IID || <IMSVidOutputDevice_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IMSVidAudioRendererDevices_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, lcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAudioRendererDevices", "Count", 7usize, |this| ((**(this as *const *const IMSVidAudioRendererDevices_Vtbl)).Count)(this, lcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAudioRendererDevices", "_NewEnum", 8usize, |this| ((**(this as *const *const IMSVidAudioRendererDevices_Vtbl))._NewEnum)(this, pd), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, v: core::mem::MaybeUninit<windows_core::VARIANT>, pdb: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAudioRendererDevices", "get_Item", 9usize, |this| ((**(this as *const *const IMSVidAudioRendererDevices_Vtbl)).get_Item)(this, v, pdb), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pdb: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAudioRendererDevices", "Add", 10usize, |this| ((**(this as *const *const IMSVidAudioRendererDevices_Vtbl)).Add)(this, pdb), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, v: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAudioRendererDevices", "Remove", 11usize, |this| ((**(this as *const *const IMSVidAudioRendererDevices_Vtbl)).Remove)(this, v), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Ole")]
+            _NewEnum,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            _NewEnum: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidAudioRendererDevices as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidAudioRendererEvent_Vtbl {
+    const PROXY: Self = { Self { base__: <IMSVidOutputDeviceEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidAudioRendererEvent as windows_core::Interface>::IID || <IMSVidOutputDeviceEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidAudioRendererEvent2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AVDecAudioDualMono(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAudioRendererEvent2", "AVDecAudioDualMono", 8usize, |this| ((**(this as *const *const IMSVidAudioRendererEvent2_Vtbl)).AVDecAudioDualMono)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AVAudioSampleRate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAudioRendererEvent2", "AVAudioSampleRate", 9usize, |this| ((**(this as *const *const IMSVidAudioRendererEvent2_Vtbl)).AVAudioSampleRate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AVAudioChannelConfig(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAudioRendererEvent2", "AVAudioChannelConfig", 10usize, |this| ((**(this as *const *const IMSVidAudioRendererEvent2_Vtbl)).AVAudioChannelConfig)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AVAudioChannelCount(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAudioRendererEvent2", "AVAudioChannelCount", 11usize, |this| ((**(this as *const *const IMSVidAudioRendererEvent2_Vtbl)).AVAudioChannelCount)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AVDecCommonMeanBitRate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAudioRendererEvent2", "AVDecCommonMeanBitRate", 12usize, |this| ((**(this as *const *const IMSVidAudioRendererEvent2_Vtbl)).AVDecCommonMeanBitRate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AVDDSurroundMode(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAudioRendererEvent2", "AVDDSurroundMode", 13usize, |this| ((**(this as *const *const IMSVidAudioRendererEvent2_Vtbl)).AVDDSurroundMode)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AVDecCommonInputFormat(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAudioRendererEvent2", "AVDecCommonInputFormat", 14usize, |this| ((**(this as *const *const IMSVidAudioRendererEvent2_Vtbl)).AVDecCommonInputFormat)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AVDecCommonOutputFormat(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidAudioRendererEvent2", "AVDecCommonOutputFormat", 15usize, |this| ((**(this as *const *const IMSVidAudioRendererEvent2_Vtbl)).AVDecCommonOutputFormat)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IMSVidAudioRendererEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AVDecAudioDualMono,
+            AVAudioSampleRate,
+            AVAudioChannelConfig,
+            AVAudioChannelCount,
+            AVDecCommonMeanBitRate,
+            AVDDSurroundMode,
+            AVDecCommonInputFormat,
+            AVDecCommonOutputFormat,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidAudioRendererEvent2 as windows_core::Interface>::IID || <IMSVidAudioRendererEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidClosedCaptioning_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Enable(this: *mut core::ffi::c_void, on: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidClosedCaptioning", "Enable", 16usize, |this| ((**(this as *const *const IMSVidClosedCaptioning_Vtbl)).Enable)(this, on), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnable(this: *mut core::ffi::c_void, on: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidClosedCaptioning", "SetEnable", 17usize, |this| ((**(this as *const *const IMSVidClosedCaptioning_Vtbl)).SetEnable)(this, on), |result| Some(*result))
+        }
+        Self { base__: <IMSVidFeature_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Enable, SetEnable }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidClosedCaptioning as windows_core::Interface>::IID || <IMSVidFeature_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidClosedCaptioning2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Service(this: *mut core::ffi::c_void, on: *mut MSVidCCService) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidClosedCaptioning2", "Service", 18usize, |this| ((**(this as *const *const IMSVidClosedCaptioning2_Vtbl)).Service)(this, on), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetService(this: *mut core::ffi::c_void, on: MSVidCCService) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidClosedCaptioning2", "SetService", 19usize, |this| ((**(this as *const *const IMSVidClosedCaptioning2_Vtbl)).SetService)(this, on), |result| Some(*result))
+        }
+        Self { base__: <IMSVidClosedCaptioning_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Service, SetService }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidClosedCaptioning2 as windows_core::Interface>::IID || <IMSVidClosedCaptioning_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidClosedCaptioning3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TeleTextFilter(this: *mut core::ffi::c_void, punkttfilter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidClosedCaptioning3", "TeleTextFilter", 20usize, |this| ((**(this as *const *const IMSVidClosedCaptioning3_Vtbl)).TeleTextFilter)(this, punkttfilter), |result| Some(*result))
+        }
+        Self { base__: <IMSVidClosedCaptioning2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TeleTextFilter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidClosedCaptioning3 as windows_core::Interface>::IID || <IMSVidClosedCaptioning2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidCompositionSegment_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Compose(this: *mut core::ffi::c_void, upstream: *mut core::ffi::c_void, downstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCompositionSegment", "Compose", 19usize, |this| ((**(this as *const *const IMSVidCompositionSegment_Vtbl)).Compose)(this, upstream, downstream), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Up(this: *mut core::ffi::c_void, upstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCompositionSegment", "Up", 20usize, |this| ((**(this as *const *const IMSVidCompositionSegment_Vtbl)).Up)(this, upstream), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Down(this: *mut core::ffi::c_void, downstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCompositionSegment", "Down", 21usize, |this| ((**(this as *const *const IMSVidCompositionSegment_Vtbl)).Down)(this, downstream), |result| Some(*result))
+        }
+        Self {
+            base__: <IMSVidGraphSegment_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Compose,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Compose: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Up,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Up: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Down,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Down: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidCompositionSegment as windows_core::Interface>::IID || <IMSVidGraphSegment_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidCtl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AutoSize(this: *mut core::ffi::c_void, pbool: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "AutoSize", 7usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).AutoSize)(this, pbool), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoSize(this: *mut core::ffi::c_void, vbool: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "SetAutoSize", 8usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).SetAutoSize)(this, vbool), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackColor(this: *mut core::ffi::c_void, backcolor: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "BackColor", 9usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).BackColor)(this, backcolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackColor(this: *mut core::ffi::c_void, backcolor: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "SetBackColor", 10usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).SetBackColor)(this, backcolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, pbool: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "Enabled", 11usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).Enabled)(this, pbool), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, vbool: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "SetEnabled", 12usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).SetEnabled)(this, vbool), |result| Some(*result))
+        }
+        unsafe extern "system" fn TabStop(this: *mut core::ffi::c_void, pbool: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "TabStop", 13usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).TabStop)(this, pbool), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTabStop(this: *mut core::ffi::c_void, vbool: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "SetTabStop", 14usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).SetTabStop)(this, vbool), |result| Some(*result))
+        }
+        unsafe extern "system" fn Window(this: *mut core::ffi::c_void, phwnd: *mut super::super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "Window", 15usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).Window)(this, phwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "Refresh", 16usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplaySize(this: *mut core::ffi::c_void, currentvalue: *mut DisplaySizeList) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "DisplaySize", 17usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).DisplaySize)(this, currentvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplaySize(this: *mut core::ffi::c_void, newvalue: DisplaySizeList) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "SetDisplaySize", 18usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).SetDisplaySize)(this, newvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaintainAspectRatio(this: *mut core::ffi::c_void, currentvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "MaintainAspectRatio", 19usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).MaintainAspectRatio)(this, currentvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaintainAspectRatio(this: *mut core::ffi::c_void, newvalue: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "SetMaintainAspectRatio", 20usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).SetMaintainAspectRatio)(this, newvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ColorKey(this: *mut core::ffi::c_void, currentvalue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "ColorKey", 21usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).ColorKey)(this, currentvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColorKey(this: *mut core::ffi::c_void, newvalue: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "SetColorKey", 22usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).SetColorKey)(this, newvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_InputsAvailable(this: *mut core::ffi::c_void, categoryguid: core::mem::MaybeUninit<windows_core::BSTR>, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "get_InputsAvailable", 23usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).get_InputsAvailable)(this, categoryguid, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_OutputsAvailable(this: *mut core::ffi::c_void, categoryguid: core::mem::MaybeUninit<windows_core::BSTR>, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "get_OutputsAvailable", 24usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).get_OutputsAvailable)(this, categoryguid, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get__InputsAvailable(this: *mut core::ffi::c_void, categoryguid: *const windows_core::GUID, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "get__InputsAvailable", 25usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).get__InputsAvailable)(this, categoryguid, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get__OutputsAvailable(this: *mut core::ffi::c_void, categoryguid: *const windows_core::GUID, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "get__OutputsAvailable", 26usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).get__OutputsAvailable)(this, categoryguid, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn VideoRenderersAvailable(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "VideoRenderersAvailable", 27usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).VideoRenderersAvailable)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AudioRenderersAvailable(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "AudioRenderersAvailable", 28usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).AudioRenderersAvailable)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn FeaturesAvailable(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "FeaturesAvailable", 29usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).FeaturesAvailable)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InputActive(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "InputActive", 30usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).InputActive)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetInputActive(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "SetInputActive", 31usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).SetInputActive)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OutputsActive(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "OutputsActive", 32usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).OutputsActive)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetOutputsActive(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "SetOutputsActive", 33usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).SetOutputsActive)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn VideoRendererActive(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "VideoRendererActive", 34usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).VideoRendererActive)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetVideoRendererActive(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "SetVideoRendererActive", 35usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).SetVideoRendererActive)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AudioRendererActive(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "AudioRendererActive", 36usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).AudioRendererActive)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetAudioRendererActive(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "SetAudioRendererActive", 37usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).SetAudioRendererActive)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn FeaturesActive(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "FeaturesActive", 38usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).FeaturesActive)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetFeaturesActive(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "SetFeaturesActive", 39usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).SetFeaturesActive)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, lstate: *mut MSVidCtlStateList) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "State", 40usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).State)(this, lstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn View(this: *mut core::ffi::c_void, v: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "View", 41usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).View)(this, v), |result| Some(*result))
+        }
+        unsafe extern "system" fn Build(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "Build", 42usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).Build)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "Pause", 43usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Run(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "Run", 44usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).Run)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "Stop", 45usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Decompose(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "Decompose", 46usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).Decompose)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableVideo(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "DisableVideo", 47usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).DisableVideo)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableAudio(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "DisableAudio", 48usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).DisableAudio)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ViewNext(this: *mut core::ffi::c_void, v: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidCtl", "ViewNext", 49usize, |this| ((**(this as *const *const IMSVidCtl_Vtbl)).ViewNext)(this, v), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AutoSize,
+            SetAutoSize,
+            BackColor,
+            SetBackColor,
+            Enabled,
+            SetEnabled,
+            TabStop,
+            SetTabStop,
+            Window,
+            Refresh,
+            DisplaySize,
+            SetDisplaySize,
+            MaintainAspectRatio,
+            SetMaintainAspectRatio,
+            ColorKey,
+            SetColorKey,
+            #[cfg(feature = "Win32_System_Com")]
+            get_InputsAvailable,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_InputsAvailable: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            get_OutputsAvailable,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_OutputsAvailable: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            get__InputsAvailable,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get__InputsAvailable: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            get__OutputsAvailable,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get__OutputsAvailable: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            VideoRenderersAvailable,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            VideoRenderersAvailable: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AudioRenderersAvailable,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AudioRenderersAvailable: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            FeaturesAvailable,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            FeaturesAvailable: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            InputActive,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InputActive: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetInputActive,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetInputActive: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OutputsActive,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OutputsActive: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetOutputsActive,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetOutputsActive: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            VideoRendererActive,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            VideoRendererActive: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetVideoRendererActive,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetVideoRendererActive: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AudioRendererActive,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AudioRendererActive: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetAudioRendererActive,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetAudioRendererActive: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            FeaturesActive,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            FeaturesActive: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetFeaturesActive,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetFeaturesActive: 0,
+            State,
+            View,
+            Build,
+            Pause,
+            Run,
+            Stop,
+            Decompose,
+            DisableVideo,
+            DisableAudio,
+            ViewNext,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidCtl as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidDataServices_Vtbl {
+    const PROXY: Self = { Self { base__: <IMSVidFeature_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidDataServices as windows_core::Interface>::IID || <IMSVidFeature_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidDataServicesEvent_Vtbl {
+    const PROXY: Self = { Self { base__: <IMSVidDeviceEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidDataServicesEvent as windows_core::Interface>::IID || <IMSVidDeviceEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidDevice", "Name", 7usize, |this| ((**(this as *const *const IMSVidDevice_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, status: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidDevice", "Status", 8usize, |this| ((**(this as *const *const IMSVidDevice_Vtbl)).Status)(this, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPower(this: *mut core::ffi::c_void, power: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidDevice", "SetPower", 9usize, |this| ((**(this as *const *const IMSVidDevice_Vtbl)).SetPower)(this, power), |result| Some(*result))
+        }
+        unsafe extern "system" fn Power(this: *mut core::ffi::c_void, power: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidDevice", "Power", 10usize, |this| ((**(this as *const *const IMSVidDevice_Vtbl)).Power)(this, power), |result| Some(*result))
+        }
+        unsafe extern "system" fn Category(this: *mut core::ffi::c_void, guid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidDevice", "Category", 11usize, |this| ((**(this as *const *const IMSVidDevice_Vtbl)).Category)(this, guid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClassID(this: *mut core::ffi::c_void, clsid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidDevice", "ClassID", 12usize, |this| ((**(this as *const *const IMSVidDevice_Vtbl)).ClassID)(this, clsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn _Category(this: *mut core::ffi::c_void, guid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidDevice", "_Category", 13usize, |this| ((**(this as *const *const IMSVidDevice_Vtbl))._Category)(this, guid), |result| Some(*result))
+        }
+        unsafe extern "system" fn _ClassID(this: *mut core::ffi::c_void, clsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidDevice", "_ClassID", 14usize, |this| ((**(this as *const *const IMSVidDevice_Vtbl))._ClassID)(this, clsid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn IsEqualDevice(this: *mut core::ffi::c_void, device: *mut core::ffi::c_void, isequal: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidDevice", "IsEqualDevice", 15usize, |this| ((**(this as *const *const IMSVidDevice_Vtbl)).IsEqualDevice)(this, device, isequal), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            Status,
+            SetPower,
+            Power,
+            Category,
+            ClassID,
+            _Category,
+            _ClassID,
+            #[cfg(feature = "Win32_System_Com")]
+            IsEqualDevice,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            IsEqualDevice: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidDevice as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMSVidDevice2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DevicePath(this: *mut core::ffi::c_void, devpath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidDevice2", "DevicePath", 3usize, |this| ((**(this as *const *const IMSVidDevice2_Vtbl)).DevicePath)(this, devpath), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DevicePath }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidDevice2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidDeviceEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn StateChange(this: *mut core::ffi::c_void, lpd: *mut core::ffi::c_void, oldstate: i32, newstate: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidDeviceEvent", "StateChange", 7usize, |this| ((**(this as *const *const IMSVidDeviceEvent_Vtbl)).StateChange)(this, lpd, oldstate, newstate), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            StateChange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            StateChange: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidDeviceEvent as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi", feature = "Win32_Media_MediaFoundation", feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IMSVidEVR_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn Presenter(this: *mut core::ffi::c_void, ppallocpresent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidEVR", "Presenter", 46usize, |this| ((**(this as *const *const IMSVidEVR_Vtbl)).Presenter)(this, ppallocpresent), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn SetPresenter(this: *mut core::ffi::c_void, pallocpresent: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidEVR", "SetPresenter", 47usize, |this| ((**(this as *const *const IMSVidEVR_Vtbl)).SetPresenter)(this, pallocpresent), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSuppressEffects(this: *mut core::ffi::c_void, bsuppress: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidEVR", "SetSuppressEffects", 48usize, |this| ((**(this as *const *const IMSVidEVR_Vtbl)).SetSuppressEffects)(this, bsuppress), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuppressEffects(this: *mut core::ffi::c_void, bsuppress: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidEVR", "SuppressEffects", 49usize, |this| ((**(this as *const *const IMSVidEVR_Vtbl)).SuppressEffects)(this, bsuppress), |result| Some(*result))
+        }
+        Self {
+            base__: <IMSVidVideoRenderer_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            Presenter,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            Presenter: 0,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            SetPresenter,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            SetPresenter: 0,
+            SetSuppressEffects,
+            SuppressEffects,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidEVR as windows_core::Interface>::IID || <IMSVidVideoRenderer_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidEVREvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnUserEvent(this: *mut core::ffi::c_void, leventcode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidEVREvent", "OnUserEvent", 8usize, |this| ((**(this as *const *const IMSVidEVREvent_Vtbl)).OnUserEvent)(this, leventcode), |result| Some(*result))
+        }
+        Self { base__: <IMSVidOutputDeviceEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnUserEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidEVREvent as windows_core::Interface>::IID || <IMSVidOutputDeviceEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidEncoder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VideoEncoderInterface(this: *mut core::ffi::c_void, ppencint: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidEncoder", "VideoEncoderInterface", 16usize, |this| ((**(this as *const *const IMSVidEncoder_Vtbl)).VideoEncoderInterface)(this, ppencint), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioEncoderInterface(this: *mut core::ffi::c_void, ppencint: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidEncoder", "AudioEncoderInterface", 17usize, |this| ((**(this as *const *const IMSVidEncoder_Vtbl)).AudioEncoderInterface)(this, ppencint), |result| Some(*result))
+        }
+        Self { base__: <IMSVidFeature_Vtbl as windows_core::imp::ProxyVtable>::PROXY, VideoEncoderInterface, AudioEncoderInterface }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidEncoder as windows_core::Interface>::IID || <IMSVidFeature_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidFeature_Vtbl {
+    const PROXY: Self = { Self { base__: <IMSVidDevice_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidFeature as windows_core::Interface>::IID || <IMSVidDevice_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidFeatureEvent_Vtbl {
+    const PROXY: Self = { Self { base__: <IMSVidDeviceEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidFeatureEvent as windows_core::Interface>::IID || <IMSVidDeviceEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IMSVidFeatures_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, lcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidFeatures", "Count", 7usize, |this| ((**(this as *const *const IMSVidFeatures_Vtbl)).Count)(this, lcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidFeatures", "_NewEnum", 8usize, |this| ((**(this as *const *const IMSVidFeatures_Vtbl))._NewEnum)(this, pd), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, v: core::mem::MaybeUninit<windows_core::VARIANT>, pdb: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidFeatures", "get_Item", 9usize, |this| ((**(this as *const *const IMSVidFeatures_Vtbl)).get_Item)(this, v, pdb), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pdb: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidFeatures", "Add", 10usize, |this| ((**(this as *const *const IMSVidFeatures_Vtbl)).Add)(this, pdb), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, v: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidFeatures", "Remove", 11usize, |this| ((**(this as *const *const IMSVidFeatures_Vtbl)).Remove)(this, v), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Ole")]
+            _NewEnum,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            _NewEnum: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidFeatures as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidFilePlayback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FileName(this: *mut core::ffi::c_void, filename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidFilePlayback", "FileName", 32usize, |this| ((**(this as *const *const IMSVidFilePlayback_Vtbl)).FileName)(this, filename), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFileName(this: *mut core::ffi::c_void, filename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidFilePlayback", "SetFileName", 33usize, |this| ((**(this as *const *const IMSVidFilePlayback_Vtbl)).SetFileName)(this, filename), |result| Some(*result))
+        }
+        Self { base__: <IMSVidPlayback_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FileName, SetFileName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidFilePlayback as windows_core::Interface>::IID || <IMSVidPlayback_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidFilePlayback2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Set_SourceFilter(this: *mut core::ffi::c_void, filename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidFilePlayback2", "Set_SourceFilter", 34usize, |this| ((**(this as *const *const IMSVidFilePlayback2_Vtbl)).Set_SourceFilter)(this, filename), |result| Some(*result))
+        }
+        unsafe extern "system" fn Set__SourceFilter(this: *mut core::ffi::c_void, filename: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidFilePlayback2", "Set__SourceFilter", 35usize, |this| ((**(this as *const *const IMSVidFilePlayback2_Vtbl)).Set__SourceFilter)(this, filename), |result| Some(*result))
+        }
+        Self { base__: <IMSVidFilePlayback_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Set_SourceFilter, Set__SourceFilter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidFilePlayback2 as windows_core::Interface>::IID || <IMSVidFilePlayback_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidFilePlaybackEvent_Vtbl {
+    const PROXY: Self = { Self { base__: <IMSVidPlaybackEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidFilePlaybackEvent as windows_core::Interface>::IID || <IMSVidPlaybackEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidGenericSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSinkFilter(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGenericSink", "SetSinkFilter", 16usize, |this| ((**(this as *const *const IMSVidGenericSink_Vtbl)).SetSinkFilter)(this, bstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SinkStreams(this: *mut core::ffi::c_void, pstreams: *mut MSVidSinkStreams) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGenericSink", "SinkStreams", 17usize, |this| ((**(this as *const *const IMSVidGenericSink_Vtbl)).SinkStreams)(this, pstreams), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSinkStreams(this: *mut core::ffi::c_void, streams: MSVidSinkStreams) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGenericSink", "SetSinkStreams", 18usize, |this| ((**(this as *const *const IMSVidGenericSink_Vtbl)).SetSinkStreams)(this, streams), |result| Some(*result))
+        }
+        Self { base__: <IMSVidOutputDevice_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetSinkFilter, SinkStreams, SetSinkStreams }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidGenericSink as windows_core::Interface>::IID || <IMSVidOutputDevice_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidGenericSink2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddFilter(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGenericSink2", "AddFilter", 19usize, |this| ((**(this as *const *const IMSVidGenericSink2_Vtbl)).AddFilter)(this, bstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetFilterList(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGenericSink2", "ResetFilterList", 20usize, |this| ((**(this as *const *const IMSVidGenericSink2_Vtbl)).ResetFilterList)(this), |result| Some(*result))
+        }
+        Self { base__: <IMSVidGenericSink_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddFilter, ResetFilterList }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidGenericSink2 as windows_core::Interface>::IID || <IMSVidGenericSink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidGraphSegment_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Init(this: *mut core::ffi::c_void, pinit: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegment", "Init", 4usize, |this| ((**(this as *const *const IMSVidGraphSegment_Vtbl)).Init)(this, pinit), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInit(this: *mut core::ffi::c_void, pinit: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegment", "SetInit", 5usize, |this| ((**(this as *const *const IMSVidGraphSegment_Vtbl)).SetInit)(this, pinit), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumFilters(this: *mut core::ffi::c_void, pnewenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegment", "EnumFilters", 6usize, |this| ((**(this as *const *const IMSVidGraphSegment_Vtbl)).EnumFilters)(this, pnewenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Container(this: *mut core::ffi::c_void, ppctl: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegment", "Container", 7usize, |this| ((**(this as *const *const IMSVidGraphSegment_Vtbl)).Container)(this, ppctl), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContainer(this: *mut core::ffi::c_void, pctl: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegment", "SetContainer", 8usize, |this| ((**(this as *const *const IMSVidGraphSegment_Vtbl)).SetContainer)(this, pctl), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, ptype: *mut MSVidSegmentType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegment", "Type", 9usize, |this| ((**(this as *const *const IMSVidGraphSegment_Vtbl)).Type)(this, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Category(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegment", "Category", 10usize, |this| ((**(this as *const *const IMSVidGraphSegment_Vtbl)).Category)(this, pguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Build(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegment", "Build", 11usize, |this| ((**(this as *const *const IMSVidGraphSegment_Vtbl)).Build)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostBuild(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegment", "PostBuild", 12usize, |this| ((**(this as *const *const IMSVidGraphSegment_Vtbl)).PostBuild)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreRun(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegment", "PreRun", 13usize, |this| ((**(this as *const *const IMSVidGraphSegment_Vtbl)).PreRun)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostRun(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegment", "PostRun", 14usize, |this| ((**(this as *const *const IMSVidGraphSegment_Vtbl)).PostRun)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreStop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegment", "PreStop", 15usize, |this| ((**(this as *const *const IMSVidGraphSegment_Vtbl)).PreStop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostStop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegment", "PostStop", 16usize, |this| ((**(this as *const *const IMSVidGraphSegment_Vtbl)).PostStop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnEventNotify(this: *mut core::ffi::c_void, leventcode: i32, leventparm1: isize, leventparm2: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegment", "OnEventNotify", 17usize, |this| ((**(this as *const *const IMSVidGraphSegment_Vtbl)).OnEventNotify)(this, leventcode, leventparm1, leventparm2), |result| Some(*result))
+        }
+        unsafe extern "system" fn Decompose(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegment", "Decompose", 18usize, |this| ((**(this as *const *const IMSVidGraphSegment_Vtbl)).Decompose)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IPersist_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Init,
+            SetInit,
+            EnumFilters,
+            Container,
+            SetContainer,
+            Type,
+            Category,
+            Build,
+            PostBuild,
+            PreRun,
+            PostRun,
+            PreStop,
+            PostStop,
+            OnEventNotify,
+            Decompose,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidGraphSegment as windows_core::Interface>::IID || <super::super::super::System::Com::IPersist_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidGraphSegmentContainer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Graph(this: *mut core::ffi::c_void, ppgraph: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegmentContainer", "Graph", 3usize, |this| ((**(this as *const *const IMSVidGraphSegmentContainer_Vtbl)).Graph)(this, ppgraph), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Input(this: *mut core::ffi::c_void, ppinput: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegmentContainer", "Input", 4usize, |this| ((**(this as *const *const IMSVidGraphSegmentContainer_Vtbl)).Input)(this, ppinput), |result| Some(*result))
+        }
+        unsafe extern "system" fn Outputs(this: *mut core::ffi::c_void, ppoutputs: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegmentContainer", "Outputs", 5usize, |this| ((**(this as *const *const IMSVidGraphSegmentContainer_Vtbl)).Outputs)(this, ppoutputs), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn VideoRenderer(this: *mut core::ffi::c_void, ppvr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegmentContainer", "VideoRenderer", 6usize, |this| ((**(this as *const *const IMSVidGraphSegmentContainer_Vtbl)).VideoRenderer)(this, ppvr), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AudioRenderer(this: *mut core::ffi::c_void, ppar: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegmentContainer", "AudioRenderer", 7usize, |this| ((**(this as *const *const IMSVidGraphSegmentContainer_Vtbl)).AudioRenderer)(this, ppar), |result| Some(*result))
+        }
+        unsafe extern "system" fn Features(this: *mut core::ffi::c_void, ppfeatures: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegmentContainer", "Features", 8usize, |this| ((**(this as *const *const IMSVidGraphSegmentContainer_Vtbl)).Features)(this, ppfeatures), |result| Some(*result))
+        }
+        unsafe extern "system" fn Composites(this: *mut core::ffi::c_void, ppcomposites: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegmentContainer", "Composites", 9usize, |this| ((**(this as *const *const IMSVidGraphSegmentContainer_Vtbl)).Composites)(this, ppcomposites), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParentContainer(this: *mut core::ffi::c_void, ppcontainer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegmentContainer", "ParentContainer", 10usize, |this| ((**(this as *const *const IMSVidGraphSegmentContainer_Vtbl)).ParentContainer)(this, ppcontainer), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Decompose(this: *mut core::ffi::c_void, psegment: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegmentContainer", "Decompose", 11usize, |this| ((**(this as *const *const IMSVidGraphSegmentContainer_Vtbl)).Decompose)(this, psegment), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsWindowless(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegmentContainer", "IsWindowless", 12usize, |this| ((**(this as *const *const IMSVidGraphSegmentContainer_Vtbl)).IsWindowless)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFocus(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegmentContainer", "GetFocus", 13usize, |this| ((**(this as *const *const IMSVidGraphSegmentContainer_Vtbl)).GetFocus)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Graph,
+            #[cfg(feature = "Win32_System_Com")]
+            Input,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Input: 0,
+            Outputs,
+            #[cfg(feature = "Win32_System_Com")]
+            VideoRenderer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            VideoRenderer: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AudioRenderer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AudioRenderer: 0,
+            Features,
+            Composites,
+            ParentContainer,
+            #[cfg(feature = "Win32_System_Com")]
+            Decompose,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Decompose: 0,
+            IsWindowless,
+            GetFocus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidGraphSegmentContainer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMSVidGraphSegmentUserInput_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Click(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegmentUserInput", "Click", 3usize, |this| ((**(this as *const *const IMSVidGraphSegmentUserInput_Vtbl)).Click)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DblClick(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegmentUserInput", "DblClick", 4usize, |this| ((**(this as *const *const IMSVidGraphSegmentUserInput_Vtbl)).DblClick)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyDown(this: *mut core::ffi::c_void, keycode: *const i16, shiftstate: i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegmentUserInput", "KeyDown", 5usize, |this| ((**(this as *const *const IMSVidGraphSegmentUserInput_Vtbl)).KeyDown)(this, keycode, shiftstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyPress(this: *mut core::ffi::c_void, keyascii: *const i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegmentUserInput", "KeyPress", 6usize, |this| ((**(this as *const *const IMSVidGraphSegmentUserInput_Vtbl)).KeyPress)(this, keyascii), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyUp(this: *mut core::ffi::c_void, keycode: *const i16, shiftstate: i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegmentUserInput", "KeyUp", 7usize, |this| ((**(this as *const *const IMSVidGraphSegmentUserInput_Vtbl)).KeyUp)(this, keycode, shiftstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn MouseDown(this: *mut core::ffi::c_void, buttonstate: i16, shiftstate: i16, x: i32, y: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegmentUserInput", "MouseDown", 8usize, |this| ((**(this as *const *const IMSVidGraphSegmentUserInput_Vtbl)).MouseDown)(this, buttonstate, shiftstate, x, y), |result| Some(*result))
+        }
+        unsafe extern "system" fn MouseMove(this: *mut core::ffi::c_void, buttonstate: i16, shiftstate: i16, x: i32, y: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegmentUserInput", "MouseMove", 9usize, |this| ((**(this as *const *const IMSVidGraphSegmentUserInput_Vtbl)).MouseMove)(this, buttonstate, shiftstate, x, y), |result| Some(*result))
+        }
+        unsafe extern "system" fn MouseUp(this: *mut core::ffi::c_void, buttonstate: i16, shiftstate: i16, x: i32, y: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidGraphSegmentUserInput", "MouseUp", 10usize, |this| ((**(this as *const *const IMSVidGraphSegmentUserInput_Vtbl)).MouseUp)(this, buttonstate, shiftstate, x, y), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Click,
+            DblClick,
+            KeyDown,
+            KeyPress,
+            KeyUp,
+            MouseDown,
+            MouseMove,
+            MouseUp,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidGraphSegmentUserInput as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidInputDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsViewable(this: *mut core::ffi::c_void, v: *const core::mem::MaybeUninit<windows_core::VARIANT>, pfviewable: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidInputDevice", "IsViewable", 16usize, |this| ((**(this as *const *const IMSVidInputDevice_Vtbl)).IsViewable)(this, v, pfviewable), |result| Some(*result))
+        }
+        unsafe extern "system" fn View(this: *mut core::ffi::c_void, v: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidInputDevice", "View", 17usize, |this| ((**(this as *const *const IMSVidInputDevice_Vtbl)).View)(this, v), |result| Some(*result))
+        }
+        Self { base__: <IMSVidDevice_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsViewable, View }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidInputDevice as windows_core::Interface>::IID || <IMSVidDevice_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidInputDeviceEvent_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidInputDeviceEvent as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IMSVidInputDevices_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, lcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidInputDevices", "Count", 7usize, |this| ((**(this as *const *const IMSVidInputDevices_Vtbl)).Count)(this, lcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidInputDevices", "_NewEnum", 8usize, |this| ((**(this as *const *const IMSVidInputDevices_Vtbl))._NewEnum)(this, pd), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, v: core::mem::MaybeUninit<windows_core::VARIANT>, pdb: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidInputDevices", "get_Item", 9usize, |this| ((**(this as *const *const IMSVidInputDevices_Vtbl)).get_Item)(this, v, pdb), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pdb: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidInputDevices", "Add", 10usize, |this| ((**(this as *const *const IMSVidInputDevices_Vtbl)).Add)(this, pdb), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, v: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidInputDevices", "Remove", 11usize, |this| ((**(this as *const *const IMSVidInputDevices_Vtbl)).Remove)(this, v), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Ole")]
+            _NewEnum,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            _NewEnum: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidInputDevices as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidOutputDevice_Vtbl {
+    const PROXY: Self = { Self { base__: <IMSVidDevice_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidOutputDevice as windows_core::Interface>::IID || <IMSVidDevice_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidOutputDeviceEvent_Vtbl {
+    const PROXY: Self = { Self { base__: <IMSVidDeviceEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidOutputDeviceEvent as windows_core::Interface>::IID || <IMSVidDeviceEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IMSVidOutputDevices_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, lcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidOutputDevices", "Count", 7usize, |this| ((**(this as *const *const IMSVidOutputDevices_Vtbl)).Count)(this, lcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidOutputDevices", "_NewEnum", 8usize, |this| ((**(this as *const *const IMSVidOutputDevices_Vtbl))._NewEnum)(this, pd), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, v: core::mem::MaybeUninit<windows_core::VARIANT>, pdb: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidOutputDevices", "get_Item", 9usize, |this| ((**(this as *const *const IMSVidOutputDevices_Vtbl)).get_Item)(this, v, pdb), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pdb: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidOutputDevices", "Add", 10usize, |this| ((**(this as *const *const IMSVidOutputDevices_Vtbl)).Add)(this, pdb), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, v: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidOutputDevices", "Remove", 11usize, |this| ((**(this as *const *const IMSVidOutputDevices_Vtbl)).Remove)(this, v), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Ole")]
+            _NewEnum,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            _NewEnum: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidOutputDevices as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidPlayback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnableResetOnStop(this: *mut core::ffi::c_void, pval: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidPlayback", "EnableResetOnStop", 18usize, |this| ((**(this as *const *const IMSVidPlayback_Vtbl)).EnableResetOnStop)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnableResetOnStop(this: *mut core::ffi::c_void, newval: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidPlayback", "SetEnableResetOnStop", 19usize, |this| ((**(this as *const *const IMSVidPlayback_Vtbl)).SetEnableResetOnStop)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Run(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidPlayback", "Run", 20usize, |this| ((**(this as *const *const IMSVidPlayback_Vtbl)).Run)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidPlayback", "Pause", 21usize, |this| ((**(this as *const *const IMSVidPlayback_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidPlayback", "Stop", 22usize, |this| ((**(this as *const *const IMSVidPlayback_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_CanStep(this: *mut core::ffi::c_void, fbackwards: super::super::super::Foundation::VARIANT_BOOL, pfcan: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidPlayback", "get_CanStep", 23usize, |this| ((**(this as *const *const IMSVidPlayback_Vtbl)).get_CanStep)(this, fbackwards, pfcan), |result| Some(*result))
+        }
+        unsafe extern "system" fn Step(this: *mut core::ffi::c_void, lstep: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidPlayback", "Step", 24usize, |this| ((**(this as *const *const IMSVidPlayback_Vtbl)).Step)(this, lstep), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRate(this: *mut core::ffi::c_void, plrate: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidPlayback", "SetRate", 25usize, |this| ((**(this as *const *const IMSVidPlayback_Vtbl)).SetRate)(this, plrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rate(this: *mut core::ffi::c_void, plrate: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidPlayback", "Rate", 26usize, |this| ((**(this as *const *const IMSVidPlayback_Vtbl)).Rate)(this, plrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentPosition(this: *mut core::ffi::c_void, lposition: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidPlayback", "SetCurrentPosition", 27usize, |this| ((**(this as *const *const IMSVidPlayback_Vtbl)).SetCurrentPosition)(this, lposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentPosition(this: *mut core::ffi::c_void, lposition: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidPlayback", "CurrentPosition", 28usize, |this| ((**(this as *const *const IMSVidPlayback_Vtbl)).CurrentPosition)(this, lposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPositionMode(this: *mut core::ffi::c_void, lpositionmode: PositionModeList) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidPlayback", "SetPositionMode", 29usize, |this| ((**(this as *const *const IMSVidPlayback_Vtbl)).SetPositionMode)(this, lpositionmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn PositionMode(this: *mut core::ffi::c_void, lpositionmode: *mut PositionModeList) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidPlayback", "PositionMode", 30usize, |this| ((**(this as *const *const IMSVidPlayback_Vtbl)).PositionMode)(this, lpositionmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, llength: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidPlayback", "Length", 31usize, |this| ((**(this as *const *const IMSVidPlayback_Vtbl)).Length)(this, llength), |result| Some(*result))
+        }
+        Self {
+            base__: <IMSVidInputDevice_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EnableResetOnStop,
+            SetEnableResetOnStop,
+            Run,
+            Pause,
+            Stop,
+            get_CanStep,
+            Step,
+            SetRate,
+            Rate,
+            SetCurrentPosition,
+            CurrentPosition,
+            SetPositionMode,
+            PositionMode,
+            Length,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidPlayback as windows_core::Interface>::IID || <IMSVidInputDevice_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidPlaybackEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EndOfMedia(this: *mut core::ffi::c_void, lpd: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidPlaybackEvent", "EndOfMedia", 7usize, |this| ((**(this as *const *const IMSVidPlaybackEvent_Vtbl)).EndOfMedia)(this, lpd), |result| Some(*result))
+        }
+        Self {
+            base__: <IMSVidInputDeviceEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            EndOfMedia,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EndOfMedia: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidPlaybackEvent as windows_core::Interface>::IID || <IMSVidInputDeviceEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidRect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Top(this: *mut core::ffi::c_void, topval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidRect", "Top", 7usize, |this| ((**(this as *const *const IMSVidRect_Vtbl)).Top)(this, topval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTop(this: *mut core::ffi::c_void, topval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidRect", "SetTop", 8usize, |this| ((**(this as *const *const IMSVidRect_Vtbl)).SetTop)(this, topval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Left(this: *mut core::ffi::c_void, leftval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidRect", "Left", 9usize, |this| ((**(this as *const *const IMSVidRect_Vtbl)).Left)(this, leftval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLeft(this: *mut core::ffi::c_void, leftval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidRect", "SetLeft", 10usize, |this| ((**(this as *const *const IMSVidRect_Vtbl)).SetLeft)(this, leftval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Width(this: *mut core::ffi::c_void, widthval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidRect", "Width", 11usize, |this| ((**(this as *const *const IMSVidRect_Vtbl)).Width)(this, widthval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWidth(this: *mut core::ffi::c_void, widthval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidRect", "SetWidth", 12usize, |this| ((**(this as *const *const IMSVidRect_Vtbl)).SetWidth)(this, widthval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Height(this: *mut core::ffi::c_void, heightval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidRect", "Height", 13usize, |this| ((**(this as *const *const IMSVidRect_Vtbl)).Height)(this, heightval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHeight(this: *mut core::ffi::c_void, heightval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidRect", "SetHeight", 14usize, |this| ((**(this as *const *const IMSVidRect_Vtbl)).SetHeight)(this, heightval), |result| Some(*result))
+        }
+        unsafe extern "system" fn HWnd(this: *mut core::ffi::c_void, hwndval: *mut super::super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidRect", "HWnd", 15usize, |this| ((**(this as *const *const IMSVidRect_Vtbl)).HWnd)(this, hwndval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHWnd(this: *mut core::ffi::c_void, hwndval: super::super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidRect", "SetHWnd", 16usize, |this| ((**(this as *const *const IMSVidRect_Vtbl)).SetHWnd)(this, hwndval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetRect(this: *mut core::ffi::c_void, rectval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidRect", "SetRect", 17usize, |this| ((**(this as *const *const IMSVidRect_Vtbl)).SetRect)(this, rectval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Top,
+            SetTop,
+            Left,
+            SetLeft,
+            Width,
+            SetWidth,
+            Height,
+            SetHeight,
+            HWnd,
+            SetHWnd,
+            #[cfg(feature = "Win32_System_Com")]
+            SetRect,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetRect: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidRect as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidStreamBufferRecordingControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartTime(this: *mut core::ffi::c_void, rtstart: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferRecordingControl", "StartTime", 7usize, |this| ((**(this as *const *const IMSVidStreamBufferRecordingControl_Vtbl)).StartTime)(this, rtstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartTime(this: *mut core::ffi::c_void, rtstart: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferRecordingControl", "SetStartTime", 8usize, |this| ((**(this as *const *const IMSVidStreamBufferRecordingControl_Vtbl)).SetStartTime)(this, rtstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopTime(this: *mut core::ffi::c_void, rtstop: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferRecordingControl", "StopTime", 9usize, |this| ((**(this as *const *const IMSVidStreamBufferRecordingControl_Vtbl)).StopTime)(this, rtstop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStopTime(this: *mut core::ffi::c_void, rtstop: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferRecordingControl", "SetStopTime", 10usize, |this| ((**(this as *const *const IMSVidStreamBufferRecordingControl_Vtbl)).SetStopTime)(this, rtstop), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecordingStopped(this: *mut core::ffi::c_void, phresult: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferRecordingControl", "RecordingStopped", 11usize, |this| ((**(this as *const *const IMSVidStreamBufferRecordingControl_Vtbl)).RecordingStopped)(this, phresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecordingStarted(this: *mut core::ffi::c_void, phresult: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferRecordingControl", "RecordingStarted", 12usize, |this| ((**(this as *const *const IMSVidStreamBufferRecordingControl_Vtbl)).RecordingStarted)(this, phresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecordingType(this: *mut core::ffi::c_void, dwtype: *mut RecordingType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferRecordingControl", "RecordingType", 13usize, |this| ((**(this as *const *const IMSVidStreamBufferRecordingControl_Vtbl)).RecordingType)(this, dwtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecordingAttribute(this: *mut core::ffi::c_void, precordingattribute: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferRecordingControl", "RecordingAttribute", 14usize, |this| ((**(this as *const *const IMSVidStreamBufferRecordingControl_Vtbl)).RecordingAttribute)(this, precordingattribute), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartTime,
+            SetStartTime,
+            StopTime,
+            SetStopTime,
+            RecordingStopped,
+            RecordingStarted,
+            RecordingType,
+            RecordingAttribute,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidStreamBufferRecordingControl as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidStreamBufferSink_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ContentRecorder(this: *mut core::ffi::c_void, pszfilename: core::mem::MaybeUninit<windows_core::BSTR>, precordingiunknown: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSink", "get_ContentRecorder", 16usize, |this| ((**(this as *const *const IMSVidStreamBufferSink_Vtbl)).get_ContentRecorder)(this, pszfilename, precordingiunknown), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ReferenceRecorder(this: *mut core::ffi::c_void, pszfilename: core::mem::MaybeUninit<windows_core::BSTR>, precordingiunknown: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSink", "get_ReferenceRecorder", 17usize, |this| ((**(this as *const *const IMSVidStreamBufferSink_Vtbl)).get_ReferenceRecorder)(this, pszfilename, precordingiunknown), |result| Some(*result))
+        }
+        unsafe extern "system" fn SinkName(this: *mut core::ffi::c_void, pname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSink", "SinkName", 18usize, |this| ((**(this as *const *const IMSVidStreamBufferSink_Vtbl)).SinkName)(this, pname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSinkName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSink", "SetSinkName", 19usize, |this| ((**(this as *const *const IMSVidStreamBufferSink_Vtbl)).SetSinkName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn NameSetLock(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSink", "NameSetLock", 20usize, |this| ((**(this as *const *const IMSVidStreamBufferSink_Vtbl)).NameSetLock)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SBESink(this: *mut core::ffi::c_void, sbeconfig: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSink", "SBESink", 21usize, |this| ((**(this as *const *const IMSVidStreamBufferSink_Vtbl)).SBESink)(this, sbeconfig), |result| Some(*result))
+        }
+        Self {
+            base__: <IMSVidOutputDevice_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ContentRecorder,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ContentRecorder: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ReferenceRecorder,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ReferenceRecorder: 0,
+            SinkName,
+            SetSinkName,
+            NameSetLock,
+            SBESink,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidStreamBufferSink as windows_core::Interface>::IID || <IMSVidOutputDevice_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidStreamBufferSink2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UnlockProfile(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSink2", "UnlockProfile", 22usize, |this| ((**(this as *const *const IMSVidStreamBufferSink2_Vtbl)).UnlockProfile)(this), |result| Some(*result))
+        }
+        Self { base__: <IMSVidStreamBufferSink_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UnlockProfile }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidStreamBufferSink2 as windows_core::Interface>::IID || <IMSVidStreamBufferSink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidStreamBufferSink3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMinSeek(this: *mut core::ffi::c_void, pdwmin: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSink3", "SetMinSeek", 23usize, |this| ((**(this as *const *const IMSVidStreamBufferSink3_Vtbl)).SetMinSeek)(this, pdwmin), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioCounter(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSink3", "AudioCounter", 24usize, |this| ((**(this as *const *const IMSVidStreamBufferSink3_Vtbl)).AudioCounter)(this, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoCounter(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSink3", "VideoCounter", 25usize, |this| ((**(this as *const *const IMSVidStreamBufferSink3_Vtbl)).VideoCounter)(this, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn CCCounter(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSink3", "CCCounter", 26usize, |this| ((**(this as *const *const IMSVidStreamBufferSink3_Vtbl)).CCCounter)(this, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn WSTCounter(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSink3", "WSTCounter", 27usize, |this| ((**(this as *const *const IMSVidStreamBufferSink3_Vtbl)).WSTCounter)(this, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAudioAnalysisFilter(this: *mut core::ffi::c_void, szclsid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSink3", "SetAudioAnalysisFilter", 28usize, |this| ((**(this as *const *const IMSVidStreamBufferSink3_Vtbl)).SetAudioAnalysisFilter)(this, szclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioAnalysisFilter(this: *mut core::ffi::c_void, pszclsid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSink3", "AudioAnalysisFilter", 29usize, |this| ((**(this as *const *const IMSVidStreamBufferSink3_Vtbl)).AudioAnalysisFilter)(this, pszclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Set_AudioAnalysisFilter(this: *mut core::ffi::c_void, guid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSink3", "Set_AudioAnalysisFilter", 30usize, |this| ((**(this as *const *const IMSVidStreamBufferSink3_Vtbl)).Set_AudioAnalysisFilter)(this, guid), |result| Some(*result))
+        }
+        unsafe extern "system" fn _AudioAnalysisFilter(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSink3", "_AudioAnalysisFilter", 31usize, |this| ((**(this as *const *const IMSVidStreamBufferSink3_Vtbl))._AudioAnalysisFilter)(this, pguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVideoAnalysisFilter(this: *mut core::ffi::c_void, szclsid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSink3", "SetVideoAnalysisFilter", 32usize, |this| ((**(this as *const *const IMSVidStreamBufferSink3_Vtbl)).SetVideoAnalysisFilter)(this, szclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoAnalysisFilter(this: *mut core::ffi::c_void, pszclsid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSink3", "VideoAnalysisFilter", 33usize, |this| ((**(this as *const *const IMSVidStreamBufferSink3_Vtbl)).VideoAnalysisFilter)(this, pszclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Set_VideoAnalysisFilter(this: *mut core::ffi::c_void, guid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSink3", "Set_VideoAnalysisFilter", 34usize, |this| ((**(this as *const *const IMSVidStreamBufferSink3_Vtbl)).Set_VideoAnalysisFilter)(this, guid), |result| Some(*result))
+        }
+        unsafe extern "system" fn _VideoAnalysisFilter(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSink3", "_VideoAnalysisFilter", 35usize, |this| ((**(this as *const *const IMSVidStreamBufferSink3_Vtbl))._VideoAnalysisFilter)(this, pguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDataAnalysisFilter(this: *mut core::ffi::c_void, szclsid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSink3", "SetDataAnalysisFilter", 36usize, |this| ((**(this as *const *const IMSVidStreamBufferSink3_Vtbl)).SetDataAnalysisFilter)(this, szclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataAnalysisFilter(this: *mut core::ffi::c_void, pszclsid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSink3", "DataAnalysisFilter", 37usize, |this| ((**(this as *const *const IMSVidStreamBufferSink3_Vtbl)).DataAnalysisFilter)(this, pszclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Set_DataAnalysisFilter(this: *mut core::ffi::c_void, guid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSink3", "Set_DataAnalysisFilter", 38usize, |this| ((**(this as *const *const IMSVidStreamBufferSink3_Vtbl)).Set_DataAnalysisFilter)(this, guid), |result| Some(*result))
+        }
+        unsafe extern "system" fn _DataAnalysisFilter(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSink3", "_DataAnalysisFilter", 39usize, |this| ((**(this as *const *const IMSVidStreamBufferSink3_Vtbl))._DataAnalysisFilter)(this, pguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn LicenseErrorCode(this: *mut core::ffi::c_void, hres: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSink3", "LicenseErrorCode", 40usize, |this| ((**(this as *const *const IMSVidStreamBufferSink3_Vtbl)).LicenseErrorCode)(this, hres), |result| Some(*result))
+        }
+        Self {
+            base__: <IMSVidStreamBufferSink2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetMinSeek,
+            AudioCounter,
+            VideoCounter,
+            CCCounter,
+            WSTCounter,
+            SetAudioAnalysisFilter,
+            AudioAnalysisFilter,
+            Set_AudioAnalysisFilter,
+            _AudioAnalysisFilter,
+            SetVideoAnalysisFilter,
+            VideoAnalysisFilter,
+            Set_VideoAnalysisFilter,
+            _VideoAnalysisFilter,
+            SetDataAnalysisFilter,
+            DataAnalysisFilter,
+            Set_DataAnalysisFilter,
+            _DataAnalysisFilter,
+            LicenseErrorCode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidStreamBufferSink3 as windows_core::Interface>::IID || <IMSVidStreamBufferSink2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidStreamBufferSinkEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CertificateFailure(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSinkEvent", "CertificateFailure", 8usize, |this| ((**(this as *const *const IMSVidStreamBufferSinkEvent_Vtbl)).CertificateFailure)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CertificateSuccess(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSinkEvent", "CertificateSuccess", 9usize, |this| ((**(this as *const *const IMSVidStreamBufferSinkEvent_Vtbl)).CertificateSuccess)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteFailure(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSinkEvent", "WriteFailure", 10usize, |this| ((**(this as *const *const IMSVidStreamBufferSinkEvent_Vtbl)).WriteFailure)(this), |result| Some(*result))
+        }
+        Self { base__: <IMSVidOutputDeviceEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CertificateFailure, CertificateSuccess, WriteFailure }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidStreamBufferSinkEvent as windows_core::Interface>::IID || <IMSVidOutputDeviceEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidStreamBufferSinkEvent2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EncryptionOn(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSinkEvent2", "EncryptionOn", 11usize, |this| ((**(this as *const *const IMSVidStreamBufferSinkEvent2_Vtbl)).EncryptionOn)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EncryptionOff(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSinkEvent2", "EncryptionOff", 12usize, |this| ((**(this as *const *const IMSVidStreamBufferSinkEvent2_Vtbl)).EncryptionOff)(this), |result| Some(*result))
+        }
+        Self { base__: <IMSVidStreamBufferSinkEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EncryptionOn, EncryptionOff }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidStreamBufferSinkEvent2 as windows_core::Interface>::IID || <IMSVidStreamBufferSinkEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidStreamBufferSinkEvent3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LicenseChange(this: *mut core::ffi::c_void, dwprot: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSinkEvent3", "LicenseChange", 13usize, |this| ((**(this as *const *const IMSVidStreamBufferSinkEvent3_Vtbl)).LicenseChange)(this, dwprot), |result| Some(*result))
+        }
+        Self { base__: <IMSVidStreamBufferSinkEvent2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LicenseChange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidStreamBufferSinkEvent3 as windows_core::Interface>::IID || <IMSVidStreamBufferSinkEvent2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidStreamBufferSinkEvent4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WriteFailureClear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSinkEvent4", "WriteFailureClear", 14usize, |this| ((**(this as *const *const IMSVidStreamBufferSinkEvent4_Vtbl)).WriteFailureClear)(this), |result| Some(*result))
+        }
+        Self { base__: <IMSVidStreamBufferSinkEvent3_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WriteFailureClear }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidStreamBufferSinkEvent4 as windows_core::Interface>::IID || <IMSVidStreamBufferSinkEvent3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidStreamBufferSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void, lstart: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSource", "Start", 34usize, |this| ((**(this as *const *const IMSVidStreamBufferSource_Vtbl)).Start)(this, lstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecordingAttribute(this: *mut core::ffi::c_void, precordingattribute: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSource", "RecordingAttribute", 35usize, |this| ((**(this as *const *const IMSVidStreamBufferSource_Vtbl)).RecordingAttribute)(this, precordingattribute), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentRatings(this: *mut core::ffi::c_void, pensystem: *mut EnTvRat_System, penrating: *mut EnTvRat_GenericLevel, pbfenattr: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSource", "CurrentRatings", 36usize, |this| ((**(this as *const *const IMSVidStreamBufferSource_Vtbl)).CurrentRatings)(this, pensystem, penrating, pbfenattr), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxRatingsLevel(this: *mut core::ffi::c_void, ensystem: EnTvRat_System, enrating: EnTvRat_GenericLevel, lbfenattr: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSource", "MaxRatingsLevel", 37usize, |this| ((**(this as *const *const IMSVidStreamBufferSource_Vtbl)).MaxRatingsLevel)(this, ensystem, enrating, lbfenattr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlockUnrated(this: *mut core::ffi::c_void, bblock: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSource", "SetBlockUnrated", 38usize, |this| ((**(this as *const *const IMSVidStreamBufferSource_Vtbl)).SetBlockUnrated)(this, bblock), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUnratedDelay(this: *mut core::ffi::c_void, dwdelay: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSource", "SetUnratedDelay", 39usize, |this| ((**(this as *const *const IMSVidStreamBufferSource_Vtbl)).SetUnratedDelay)(this, dwdelay), |result| Some(*result))
+        }
+        unsafe extern "system" fn SBESource(this: *mut core::ffi::c_void, sbefilter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSource", "SBESource", 40usize, |this| ((**(this as *const *const IMSVidStreamBufferSource_Vtbl)).SBESource)(this, sbefilter), |result| Some(*result))
+        }
+        Self {
+            base__: <IMSVidFilePlayback_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Start,
+            RecordingAttribute,
+            CurrentRatings,
+            MaxRatingsLevel,
+            SetBlockUnrated,
+            SetUnratedDelay,
+            SBESource,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidStreamBufferSource as windows_core::Interface>::IID || <IMSVidFilePlayback_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidStreamBufferSource2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn put_RateEx(this: *mut core::ffi::c_void, dwrate: f64, dwframespersecond: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSource2", "put_RateEx", 41usize, |this| ((**(this as *const *const IMSVidStreamBufferSource2_Vtbl)).put_RateEx)(this, dwrate, dwframespersecond), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioCounter(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSource2", "AudioCounter", 42usize, |this| ((**(this as *const *const IMSVidStreamBufferSource2_Vtbl)).AudioCounter)(this, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoCounter(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSource2", "VideoCounter", 43usize, |this| ((**(this as *const *const IMSVidStreamBufferSource2_Vtbl)).VideoCounter)(this, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn CCCounter(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSource2", "CCCounter", 44usize, |this| ((**(this as *const *const IMSVidStreamBufferSource2_Vtbl)).CCCounter)(this, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn WSTCounter(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSource2", "WSTCounter", 45usize, |this| ((**(this as *const *const IMSVidStreamBufferSource2_Vtbl)).WSTCounter)(this, ppunk), |result| Some(*result))
+        }
+        Self { base__: <IMSVidStreamBufferSource_Vtbl as windows_core::imp::ProxyVtable>::PROXY, put_RateEx, AudioCounter, VideoCounter, CCCounter, WSTCounter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidStreamBufferSource2 as windows_core::Interface>::IID || <IMSVidStreamBufferSource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidStreamBufferSourceEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CertificateFailure(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSourceEvent", "CertificateFailure", 8usize, |this| ((**(this as *const *const IMSVidStreamBufferSourceEvent_Vtbl)).CertificateFailure)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CertificateSuccess(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSourceEvent", "CertificateSuccess", 9usize, |this| ((**(this as *const *const IMSVidStreamBufferSourceEvent_Vtbl)).CertificateSuccess)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RatingsBlocked(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSourceEvent", "RatingsBlocked", 10usize, |this| ((**(this as *const *const IMSVidStreamBufferSourceEvent_Vtbl)).RatingsBlocked)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RatingsUnblocked(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSourceEvent", "RatingsUnblocked", 11usize, |this| ((**(this as *const *const IMSVidStreamBufferSourceEvent_Vtbl)).RatingsUnblocked)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RatingsChanged(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSourceEvent", "RatingsChanged", 12usize, |this| ((**(this as *const *const IMSVidStreamBufferSourceEvent_Vtbl)).RatingsChanged)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimeHole(this: *mut core::ffi::c_void, streamoffsetms: i32, sizems: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSourceEvent", "TimeHole", 13usize, |this| ((**(this as *const *const IMSVidStreamBufferSourceEvent_Vtbl)).TimeHole)(this, streamoffsetms, sizems), |result| Some(*result))
+        }
+        unsafe extern "system" fn StaleDataRead(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSourceEvent", "StaleDataRead", 14usize, |this| ((**(this as *const *const IMSVidStreamBufferSourceEvent_Vtbl)).StaleDataRead)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentBecomingStale(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSourceEvent", "ContentBecomingStale", 15usize, |this| ((**(this as *const *const IMSVidStreamBufferSourceEvent_Vtbl)).ContentBecomingStale)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StaleFileDeleted(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSourceEvent", "StaleFileDeleted", 16usize, |this| ((**(this as *const *const IMSVidStreamBufferSourceEvent_Vtbl)).StaleFileDeleted)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IMSVidFilePlaybackEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CertificateFailure,
+            CertificateSuccess,
+            RatingsBlocked,
+            RatingsUnblocked,
+            RatingsChanged,
+            TimeHole,
+            StaleDataRead,
+            ContentBecomingStale,
+            StaleFileDeleted,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidStreamBufferSourceEvent as windows_core::Interface>::IID || <IMSVidFilePlaybackEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidStreamBufferSourceEvent2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RateChange(this: *mut core::ffi::c_void, qwnewrate: f64, qwoldrate: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSourceEvent2", "RateChange", 17usize, |this| ((**(this as *const *const IMSVidStreamBufferSourceEvent2_Vtbl)).RateChange)(this, qwnewrate, qwoldrate), |result| Some(*result))
+        }
+        Self { base__: <IMSVidStreamBufferSourceEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RateChange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidStreamBufferSourceEvent2 as windows_core::Interface>::IID || <IMSVidStreamBufferSourceEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidStreamBufferSourceEvent3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BroadcastEvent(this: *mut core::ffi::c_void, guid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSourceEvent3", "BroadcastEvent", 18usize, |this| ((**(this as *const *const IMSVidStreamBufferSourceEvent3_Vtbl)).BroadcastEvent)(this, guid), |result| Some(*result))
+        }
+        unsafe extern "system" fn BroadcastEventEx(this: *mut core::ffi::c_void, guid: core::mem::MaybeUninit<windows_core::BSTR>, param1: u32, param2: u32, param3: u32, param4: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSourceEvent3", "BroadcastEventEx", 19usize, |this| ((**(this as *const *const IMSVidStreamBufferSourceEvent3_Vtbl)).BroadcastEventEx)(this, guid, param1, param2, param3, param4), |result| Some(*result))
+        }
+        unsafe extern "system" fn COPPBlocked(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSourceEvent3", "COPPBlocked", 20usize, |this| ((**(this as *const *const IMSVidStreamBufferSourceEvent3_Vtbl)).COPPBlocked)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn COPPUnblocked(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSourceEvent3", "COPPUnblocked", 21usize, |this| ((**(this as *const *const IMSVidStreamBufferSourceEvent3_Vtbl)).COPPUnblocked)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentPrimarilyAudio(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferSourceEvent3", "ContentPrimarilyAudio", 22usize, |this| ((**(this as *const *const IMSVidStreamBufferSourceEvent3_Vtbl)).ContentPrimarilyAudio)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IMSVidStreamBufferSourceEvent2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BroadcastEvent,
+            BroadcastEventEx,
+            COPPBlocked,
+            COPPUnblocked,
+            ContentPrimarilyAudio,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidStreamBufferSourceEvent3 as windows_core::Interface>::IID || <IMSVidStreamBufferSourceEvent2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidStreamBufferV2SourceEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RatingsChanged(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferV2SourceEvent", "RatingsChanged", 8usize, |this| ((**(this as *const *const IMSVidStreamBufferV2SourceEvent_Vtbl)).RatingsChanged)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimeHole(this: *mut core::ffi::c_void, streamoffsetms: i32, sizems: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferV2SourceEvent", "TimeHole", 9usize, |this| ((**(this as *const *const IMSVidStreamBufferV2SourceEvent_Vtbl)).TimeHole)(this, streamoffsetms, sizems), |result| Some(*result))
+        }
+        unsafe extern "system" fn StaleDataRead(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferV2SourceEvent", "StaleDataRead", 10usize, |this| ((**(this as *const *const IMSVidStreamBufferV2SourceEvent_Vtbl)).StaleDataRead)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentBecomingStale(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferV2SourceEvent", "ContentBecomingStale", 11usize, |this| ((**(this as *const *const IMSVidStreamBufferV2SourceEvent_Vtbl)).ContentBecomingStale)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StaleFileDeleted(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferV2SourceEvent", "StaleFileDeleted", 12usize, |this| ((**(this as *const *const IMSVidStreamBufferV2SourceEvent_Vtbl)).StaleFileDeleted)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RateChange(this: *mut core::ffi::c_void, qwnewrate: f64, qwoldrate: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferV2SourceEvent", "RateChange", 13usize, |this| ((**(this as *const *const IMSVidStreamBufferV2SourceEvent_Vtbl)).RateChange)(this, qwnewrate, qwoldrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn BroadcastEvent(this: *mut core::ffi::c_void, guid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferV2SourceEvent", "BroadcastEvent", 14usize, |this| ((**(this as *const *const IMSVidStreamBufferV2SourceEvent_Vtbl)).BroadcastEvent)(this, guid), |result| Some(*result))
+        }
+        unsafe extern "system" fn BroadcastEventEx(this: *mut core::ffi::c_void, guid: core::mem::MaybeUninit<windows_core::BSTR>, param1: u32, param2: u32, param3: u32, param4: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferV2SourceEvent", "BroadcastEventEx", 15usize, |this| ((**(this as *const *const IMSVidStreamBufferV2SourceEvent_Vtbl)).BroadcastEventEx)(this, guid, param1, param2, param3, param4), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentPrimarilyAudio(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidStreamBufferV2SourceEvent", "ContentPrimarilyAudio", 16usize, |this| ((**(this as *const *const IMSVidStreamBufferV2SourceEvent_Vtbl)).ContentPrimarilyAudio)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IMSVidFilePlaybackEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RatingsChanged,
+            TimeHole,
+            StaleDataRead,
+            ContentBecomingStale,
+            StaleFileDeleted,
+            RateChange,
+            BroadcastEvent,
+            BroadcastEventEx,
+            ContentPrimarilyAudio,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidStreamBufferV2SourceEvent as windows_core::Interface>::IID || <IMSVidFilePlaybackEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidTuner_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Tune(this: *mut core::ffi::c_void, pptr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidTuner", "Tune", 18usize, |this| ((**(this as *const *const IMSVidTuner_Vtbl)).Tune)(this, pptr), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetTune(this: *mut core::ffi::c_void, ptr: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidTuner", "SetTune", 19usize, |this| ((**(this as *const *const IMSVidTuner_Vtbl)).SetTune)(this, ptr), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn TuningSpace(this: *mut core::ffi::c_void, plts: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidTuner", "TuningSpace", 20usize, |this| ((**(this as *const *const IMSVidTuner_Vtbl)).TuningSpace)(this, plts), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetTuningSpace(this: *mut core::ffi::c_void, plts: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidTuner", "SetTuningSpace", 21usize, |this| ((**(this as *const *const IMSVidTuner_Vtbl)).SetTuningSpace)(this, plts), |result| Some(*result))
+        }
+        Self {
+            base__: <IMSVidVideoInputDevice_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Tune,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Tune: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetTune,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetTune: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            TuningSpace,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            TuningSpace: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetTuningSpace,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetTuningSpace: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidTuner as windows_core::Interface>::IID || <IMSVidVideoInputDevice_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidTunerEvent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn TuneChanged(this: *mut core::ffi::c_void, lpd: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidTunerEvent", "TuneChanged", 7usize, |this| ((**(this as *const *const IMSVidTunerEvent_Vtbl)).TuneChanged)(this, lpd), |result| Some(*result))
+        }
+        Self {
+            base__: <IMSVidInputDeviceEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            TuneChanged,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            TuneChanged: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidTunerEvent as windows_core::Interface>::IID || <IMSVidInputDeviceEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IMSVidVMR9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Allocator_ID(this: *mut core::ffi::c_void, id: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVMR9", "Allocator_ID", 46usize, |this| ((**(this as *const *const IMSVidVMR9_Vtbl)).Allocator_ID)(this, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllocator(this: *mut core::ffi::c_void, allocpresent: *mut core::ffi::c_void, id: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVMR9", "SetAllocator", 47usize, |this| ((**(this as *const *const IMSVidVMR9_Vtbl)).SetAllocator)(this, allocpresent, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSuppressEffects(this: *mut core::ffi::c_void, bsuppress: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVMR9", "SetSuppressEffects", 48usize, |this| ((**(this as *const *const IMSVidVMR9_Vtbl)).SetSuppressEffects)(this, bsuppress), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuppressEffects(this: *mut core::ffi::c_void, bsuppress: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVMR9", "SuppressEffects", 49usize, |this| ((**(this as *const *const IMSVidVMR9_Vtbl)).SuppressEffects)(this, bsuppress), |result| Some(*result))
+        }
+        unsafe extern "system" fn Allocator(this: *mut core::ffi::c_void, allocpresent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVMR9", "Allocator", 50usize, |this| ((**(this as *const *const IMSVidVMR9_Vtbl)).Allocator)(this, allocpresent), |result| Some(*result))
+        }
+        Self {
+            base__: <IMSVidVideoRenderer_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Allocator_ID,
+            SetAllocator,
+            SetSuppressEffects,
+            SuppressEffects,
+            Allocator,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidVMR9 as windows_core::Interface>::IID || <IMSVidVideoRenderer_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IMSVidVRGraphSegment_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Set_VMRendererMode(this: *mut core::ffi::c_void, dwmode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVRGraphSegment", "Set_VMRendererMode", 19usize, |this| ((**(this as *const *const IMSVidVRGraphSegment_Vtbl)).Set_VMRendererMode)(this, dwmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOwner(this: *mut core::ffi::c_void, window: super::super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVRGraphSegment", "SetOwner", 20usize, |this| ((**(this as *const *const IMSVidVRGraphSegment_Vtbl)).SetOwner)(this, window), |result| Some(*result))
+        }
+        unsafe extern "system" fn Owner(this: *mut core::ffi::c_void, window: *mut super::super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVRGraphSegment", "Owner", 21usize, |this| ((**(this as *const *const IMSVidVRGraphSegment_Vtbl)).Owner)(this, window), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseOverlay(this: *mut core::ffi::c_void, useoverlayval: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVRGraphSegment", "UseOverlay", 22usize, |this| ((**(this as *const *const IMSVidVRGraphSegment_Vtbl)).UseOverlay)(this, useoverlayval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUseOverlay(this: *mut core::ffi::c_void, useoverlayval: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVRGraphSegment", "SetUseOverlay", 23usize, |this| ((**(this as *const *const IMSVidVRGraphSegment_Vtbl)).SetUseOverlay)(this, useoverlayval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Visible(this: *mut core::ffi::c_void, visible: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVRGraphSegment", "Visible", 24usize, |this| ((**(this as *const *const IMSVidVRGraphSegment_Vtbl)).Visible)(this, visible), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVisible(this: *mut core::ffi::c_void, visible: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVRGraphSegment", "SetVisible", 25usize, |this| ((**(this as *const *const IMSVidVRGraphSegment_Vtbl)).SetVisible)(this, visible), |result| Some(*result))
+        }
+        unsafe extern "system" fn ColorKey(this: *mut core::ffi::c_void, colorkey: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVRGraphSegment", "ColorKey", 26usize, |this| ((**(this as *const *const IMSVidVRGraphSegment_Vtbl)).ColorKey)(this, colorkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColorKey(this: *mut core::ffi::c_void, colorkey: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVRGraphSegment", "SetColorKey", 27usize, |this| ((**(this as *const *const IMSVidVRGraphSegment_Vtbl)).SetColorKey)(this, colorkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn Source(this: *mut core::ffi::c_void, r: *mut super::super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVRGraphSegment", "Source", 28usize, |this| ((**(this as *const *const IMSVidVRGraphSegment_Vtbl)).Source)(this, r), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSource(this: *mut core::ffi::c_void, r: super::super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVRGraphSegment", "SetSource", 29usize, |this| ((**(this as *const *const IMSVidVRGraphSegment_Vtbl)).SetSource)(this, r), |result| Some(*result))
+        }
+        unsafe extern "system" fn Destination(this: *mut core::ffi::c_void, r: *mut super::super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVRGraphSegment", "Destination", 30usize, |this| ((**(this as *const *const IMSVidVRGraphSegment_Vtbl)).Destination)(this, r), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDestination(this: *mut core::ffi::c_void, r: super::super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVRGraphSegment", "SetDestination", 31usize, |this| ((**(this as *const *const IMSVidVRGraphSegment_Vtbl)).SetDestination)(this, r), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_NativeSize(this: *mut core::ffi::c_void, sizeval: *mut super::super::super::Foundation::SIZE, aspectratio: *mut super::super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVRGraphSegment", "get_NativeSize", 32usize, |this| ((**(this as *const *const IMSVidVRGraphSegment_Vtbl)).get_NativeSize)(this, sizeval, aspectratio), |result| Some(*result))
+        }
+        unsafe extern "system" fn BorderColor(this: *mut core::ffi::c_void, color: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVRGraphSegment", "BorderColor", 33usize, |this| ((**(this as *const *const IMSVidVRGraphSegment_Vtbl)).BorderColor)(this, color), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBorderColor(this: *mut core::ffi::c_void, color: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVRGraphSegment", "SetBorderColor", 34usize, |this| ((**(this as *const *const IMSVidVRGraphSegment_Vtbl)).SetBorderColor)(this, color), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaintainAspectRatio(this: *mut core::ffi::c_void, fmaintain: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVRGraphSegment", "MaintainAspectRatio", 35usize, |this| ((**(this as *const *const IMSVidVRGraphSegment_Vtbl)).MaintainAspectRatio)(this, fmaintain), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaintainAspectRatio(this: *mut core::ffi::c_void, fmaintain: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVRGraphSegment", "SetMaintainAspectRatio", 36usize, |this| ((**(this as *const *const IMSVidVRGraphSegment_Vtbl)).SetMaintainAspectRatio)(this, fmaintain), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVRGraphSegment", "Refresh", 37usize, |this| ((**(this as *const *const IMSVidVRGraphSegment_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayChange(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVRGraphSegment", "DisplayChange", 38usize, |this| ((**(this as *const *const IMSVidVRGraphSegment_Vtbl)).DisplayChange)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn RePaint(this: *mut core::ffi::c_void, hdc: super::super::super::Graphics::Gdi::HDC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVRGraphSegment", "RePaint", 39usize, |this| ((**(this as *const *const IMSVidVRGraphSegment_Vtbl)).RePaint)(this, hdc), |result| Some(*result))
+        }
+        Self {
+            base__: <IMSVidGraphSegment_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Set_VMRendererMode,
+            SetOwner,
+            Owner,
+            UseOverlay,
+            SetUseOverlay,
+            Visible,
+            SetVisible,
+            ColorKey,
+            SetColorKey,
+            Source,
+            SetSource,
+            Destination,
+            SetDestination,
+            get_NativeSize,
+            BorderColor,
+            SetBorderColor,
+            MaintainAspectRatio,
+            SetMaintainAspectRatio,
+            Refresh,
+            DisplayChange,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            RePaint,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            RePaint: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidVRGraphSegment as windows_core::Interface>::IID || <IMSVidGraphSegment_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidVideoInputDevice_Vtbl {
+    const PROXY: Self = { Self { base__: <IMSVidInputDevice_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidVideoInputDevice as windows_core::Interface>::IID || <IMSVidInputDevice_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IMSVidVideoRenderer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CustomCompositorClass(this: *mut core::ffi::c_void, compositorclsid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "CustomCompositorClass", 16usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl)).CustomCompositorClass)(this, compositorclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCustomCompositorClass(this: *mut core::ffi::c_void, compositorclsid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "SetCustomCompositorClass", 17usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl)).SetCustomCompositorClass)(this, compositorclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn _CustomCompositorClass(this: *mut core::ffi::c_void, compositorclsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "_CustomCompositorClass", 18usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl))._CustomCompositorClass)(this, compositorclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Set_CustomCompositorClass(this: *mut core::ffi::c_void, compositorclsid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "Set_CustomCompositorClass", 19usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl)).Set_CustomCompositorClass)(this, compositorclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn _CustomCompositor(this: *mut core::ffi::c_void, compositor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "_CustomCompositor", 20usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl))._CustomCompositor)(this, compositor), |result| Some(*result))
+        }
+        unsafe extern "system" fn Set_CustomCompositor(this: *mut core::ffi::c_void, compositor: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "Set_CustomCompositor", 21usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl)).Set_CustomCompositor)(this, compositor), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+        unsafe extern "system" fn MixerBitmap(this: *mut core::ffi::c_void, mixerpicturedisp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "MixerBitmap", 22usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl)).MixerBitmap)(this, mixerpicturedisp), |result| Some(*result))
+        }
+        unsafe extern "system" fn _MixerBitmap(this: *mut core::ffi::c_void, mixerpicture: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "_MixerBitmap", 23usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl))._MixerBitmap)(this, mixerpicture), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+        unsafe extern "system" fn SetMixerBitmap(this: *mut core::ffi::c_void, mixerpicturedisp: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "SetMixerBitmap", 24usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl)).SetMixerBitmap)(this, mixerpicturedisp), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi"))]
+        unsafe extern "system" fn Set_MixerBitmap(this: *mut core::ffi::c_void, mixerpicture: *const super::VMRALPHABITMAP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "Set_MixerBitmap", 25usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl)).Set_MixerBitmap)(this, mixerpicture), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn MixerBitmapPositionRect(this: *mut core::ffi::c_void, rdest: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "MixerBitmapPositionRect", 26usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl)).MixerBitmapPositionRect)(this, rdest), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetMixerBitmapPositionRect(this: *mut core::ffi::c_void, rdest: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "SetMixerBitmapPositionRect", 27usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl)).SetMixerBitmapPositionRect)(this, rdest), |result| Some(*result))
+        }
+        unsafe extern "system" fn MixerBitmapOpacity(this: *mut core::ffi::c_void, opacity: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "MixerBitmapOpacity", 28usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl)).MixerBitmapOpacity)(this, opacity), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMixerBitmapOpacity(this: *mut core::ffi::c_void, opacity: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "SetMixerBitmapOpacity", 29usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl)).SetMixerBitmapOpacity)(this, opacity), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+        unsafe extern "system" fn SetupMixerBitmap(this: *mut core::ffi::c_void, mixerpicturedisp: *mut core::ffi::c_void, opacity: i32, rdest: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "SetupMixerBitmap", 30usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl)).SetupMixerBitmap)(this, mixerpicturedisp, opacity, rdest), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceSize(this: *mut core::ffi::c_void, currentsize: *mut SourceSizeList) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "SourceSize", 31usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl)).SourceSize)(this, currentsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSourceSize(this: *mut core::ffi::c_void, newsize: SourceSizeList) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "SetSourceSize", 32usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl)).SetSourceSize)(this, newsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn OverScan(this: *mut core::ffi::c_void, plpercent: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "OverScan", 33usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl)).OverScan)(this, plpercent), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOverScan(this: *mut core::ffi::c_void, lpercent: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "SetOverScan", 34usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl)).SetOverScan)(this, lpercent), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AvailableSourceRect(this: *mut core::ffi::c_void, prect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "AvailableSourceRect", 35usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl)).AvailableSourceRect)(this, prect), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn MaxVidRect(this: *mut core::ffi::c_void, ppvidrect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "MaxVidRect", 36usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl)).MaxVidRect)(this, ppvidrect), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn MinVidRect(this: *mut core::ffi::c_void, ppvidrect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "MinVidRect", 37usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl)).MinVidRect)(this, ppvidrect), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ClippedSourceRect(this: *mut core::ffi::c_void, prect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "ClippedSourceRect", 38usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl)).ClippedSourceRect)(this, prect), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetClippedSourceRect(this: *mut core::ffi::c_void, prect: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "SetClippedSourceRect", 39usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl)).SetClippedSourceRect)(this, prect), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsingOverlay(this: *mut core::ffi::c_void, useoverlayval: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "UsingOverlay", 40usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl)).UsingOverlay)(this, useoverlayval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUsingOverlay(this: *mut core::ffi::c_void, useoverlayval: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "SetUsingOverlay", 41usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl)).SetUsingOverlay)(this, useoverlayval), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+        unsafe extern "system" fn Capture(this: *mut core::ffi::c_void, currentimage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "Capture", 42usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl)).Capture)(this, currentimage), |result| Some(*result))
+        }
+        unsafe extern "system" fn FramesPerSecond(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "FramesPerSecond", 43usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl)).FramesPerSecond)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DecimateInput(this: *mut core::ffi::c_void, pdeci: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "DecimateInput", 44usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl)).DecimateInput)(this, pdeci), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDecimateInput(this: *mut core::ffi::c_void, pdeci: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer", "SetDecimateInput", 45usize, |this| ((**(this as *const *const IMSVidVideoRenderer_Vtbl)).SetDecimateInput)(this, pdeci), |result| Some(*result))
+        }
+        Self {
+            base__: <IMSVidOutputDevice_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CustomCompositorClass,
+            SetCustomCompositorClass,
+            _CustomCompositorClass,
+            Set_CustomCompositorClass,
+            _CustomCompositor,
+            Set_CustomCompositor,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+            MixerBitmap,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Ole")))]
+            MixerBitmap: 0,
+            _MixerBitmap,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+            SetMixerBitmap,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Ole")))]
+            SetMixerBitmap: 0,
+            #[cfg(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi"))]
+            Set_MixerBitmap,
+            #[cfg(not(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi")))]
+            Set_MixerBitmap: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            MixerBitmapPositionRect,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            MixerBitmapPositionRect: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetMixerBitmapPositionRect,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetMixerBitmapPositionRect: 0,
+            MixerBitmapOpacity,
+            SetMixerBitmapOpacity,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+            SetupMixerBitmap,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Ole")))]
+            SetupMixerBitmap: 0,
+            SourceSize,
+            SetSourceSize,
+            OverScan,
+            SetOverScan,
+            #[cfg(feature = "Win32_System_Com")]
+            AvailableSourceRect,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AvailableSourceRect: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            MaxVidRect,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            MaxVidRect: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            MinVidRect,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            MinVidRect: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ClippedSourceRect,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ClippedSourceRect: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetClippedSourceRect,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetClippedSourceRect: 0,
+            UsingOverlay,
+            SetUsingOverlay,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+            Capture,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Ole")))]
+            Capture: 0,
+            FramesPerSecond,
+            DecimateInput,
+            SetDecimateInput,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidVideoRenderer as windows_core::Interface>::IID || <IMSVidOutputDevice_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IMSVidVideoRenderer2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Allocator(this: *mut core::ffi::c_void, allocpresent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer2", "Allocator", 46usize, |this| ((**(this as *const *const IMSVidVideoRenderer2_Vtbl)).Allocator)(this, allocpresent), |result| Some(*result))
+        }
+        unsafe extern "system" fn _Allocator(this: *mut core::ffi::c_void, allocpresent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer2", "_Allocator", 47usize, |this| ((**(this as *const *const IMSVidVideoRenderer2_Vtbl))._Allocator)(this, allocpresent), |result| Some(*result))
+        }
+        unsafe extern "system" fn Allocator_ID(this: *mut core::ffi::c_void, id: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer2", "Allocator_ID", 48usize, |this| ((**(this as *const *const IMSVidVideoRenderer2_Vtbl)).Allocator_ID)(this, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllocator(this: *mut core::ffi::c_void, allocpresent: *mut core::ffi::c_void, id: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer2", "SetAllocator", 49usize, |this| ((**(this as *const *const IMSVidVideoRenderer2_Vtbl)).SetAllocator)(this, allocpresent, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn _SetAllocator2(this: *mut core::ffi::c_void, allocpresent: *mut core::ffi::c_void, id: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer2", "_SetAllocator2", 50usize, |this| ((**(this as *const *const IMSVidVideoRenderer2_Vtbl))._SetAllocator2)(this, allocpresent, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSuppressEffects(this: *mut core::ffi::c_void, bsuppress: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer2", "SetSuppressEffects", 51usize, |this| ((**(this as *const *const IMSVidVideoRenderer2_Vtbl)).SetSuppressEffects)(this, bsuppress), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuppressEffects(this: *mut core::ffi::c_void, bsuppress: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRenderer2", "SuppressEffects", 52usize, |this| ((**(this as *const *const IMSVidVideoRenderer2_Vtbl)).SuppressEffects)(this, bsuppress), |result| Some(*result))
+        }
+        Self {
+            base__: <IMSVidVideoRenderer_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Allocator,
+            _Allocator,
+            Allocator_ID,
+            SetAllocator,
+            _SetAllocator2,
+            SetSuppressEffects,
+            SuppressEffects,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidVideoRenderer2 as windows_core::Interface>::IID || <IMSVidVideoRenderer_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IMSVidVideoRendererDevices_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, lcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRendererDevices", "Count", 7usize, |this| ((**(this as *const *const IMSVidVideoRendererDevices_Vtbl)).Count)(this, lcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRendererDevices", "_NewEnum", 8usize, |this| ((**(this as *const *const IMSVidVideoRendererDevices_Vtbl))._NewEnum)(this, pd), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, v: core::mem::MaybeUninit<windows_core::VARIANT>, pdb: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRendererDevices", "get_Item", 9usize, |this| ((**(this as *const *const IMSVidVideoRendererDevices_Vtbl)).get_Item)(this, v, pdb), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pdb: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRendererDevices", "Add", 10usize, |this| ((**(this as *const *const IMSVidVideoRendererDevices_Vtbl)).Add)(this, pdb), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, v: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRendererDevices", "Remove", 11usize, |this| ((**(this as *const *const IMSVidVideoRendererDevices_Vtbl)).Remove)(this, v), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Ole")]
+            _NewEnum,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            _NewEnum: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidVideoRendererDevices as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidVideoRendererEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OverlayUnavailable(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRendererEvent", "OverlayUnavailable", 8usize, |this| ((**(this as *const *const IMSVidVideoRendererEvent_Vtbl)).OverlayUnavailable)(this), |result| Some(*result))
+        }
+        Self { base__: <IMSVidOutputDeviceEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OverlayUnavailable }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidVideoRendererEvent as windows_core::Interface>::IID || <IMSVidOutputDeviceEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidVideoRendererEvent2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OverlayUnavailable(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidVideoRendererEvent2", "OverlayUnavailable", 8usize, |this| ((**(this as *const *const IMSVidVideoRendererEvent2_Vtbl)).OverlayUnavailable)(this), |result| Some(*result))
+        }
+        Self { base__: <IMSVidOutputDeviceEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OverlayUnavailable }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidVideoRendererEvent2 as windows_core::Interface>::IID || <IMSVidOutputDeviceEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidWebDVD_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnDVDEvent(this: *mut core::ffi::c_void, levent: i32, lparam1: isize, lparam2: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "OnDVDEvent", 32usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).OnDVDEvent)(this, levent, lparam1, lparam2), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayTitle(this: *mut core::ffi::c_void, ltitle: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "PlayTitle", 33usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).PlayTitle)(this, ltitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayChapterInTitle(this: *mut core::ffi::c_void, ltitle: i32, lchapter: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "PlayChapterInTitle", 34usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).PlayChapterInTitle)(this, ltitle, lchapter), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayChapter(this: *mut core::ffi::c_void, lchapter: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "PlayChapter", 35usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).PlayChapter)(this, lchapter), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayChaptersAutoStop(this: *mut core::ffi::c_void, ltitle: i32, lstrchapter: i32, lchaptercount: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "PlayChaptersAutoStop", 36usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).PlayChaptersAutoStop)(this, ltitle, lstrchapter, lchaptercount), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayAtTime(this: *mut core::ffi::c_void, strtime: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "PlayAtTime", 37usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).PlayAtTime)(this, strtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayAtTimeInTitle(this: *mut core::ffi::c_void, ltitle: i32, strtime: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "PlayAtTimeInTitle", 38usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).PlayAtTimeInTitle)(this, ltitle, strtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayPeriodInTitleAutoStop(this: *mut core::ffi::c_void, ltitle: i32, strstarttime: core::mem::MaybeUninit<windows_core::BSTR>, strendtime: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "PlayPeriodInTitleAutoStop", 39usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).PlayPeriodInTitleAutoStop)(this, ltitle, strstarttime, strendtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReplayChapter(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "ReplayChapter", 40usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).ReplayChapter)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayPrevChapter(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "PlayPrevChapter", 41usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).PlayPrevChapter)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayNextChapter(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "PlayNextChapter", 42usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).PlayNextChapter)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StillOff(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "StillOff", 43usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).StillOff)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_AudioLanguage(this: *mut core::ffi::c_void, lstream: i32, fformat: super::super::super::Foundation::VARIANT_BOOL, straudiolang: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "get_AudioLanguage", 44usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).get_AudioLanguage)(this, lstream, fformat, straudiolang), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowMenu(this: *mut core::ffi::c_void, menuid: DVDMenuIDConstants) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "ShowMenu", 45usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).ShowMenu)(this, menuid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "Resume", 46usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReturnFromSubmenu(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "ReturnFromSubmenu", 47usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).ReturnFromSubmenu)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ButtonsAvailable(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "ButtonsAvailable", 48usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).ButtonsAvailable)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentButton(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "CurrentButton", 49usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).CurrentButton)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectAndActivateButton(this: *mut core::ffi::c_void, lbutton: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "SelectAndActivateButton", 50usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).SelectAndActivateButton)(this, lbutton), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivateButton(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "ActivateButton", 51usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).ActivateButton)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectRightButton(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "SelectRightButton", 52usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).SelectRightButton)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectLeftButton(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "SelectLeftButton", 53usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).SelectLeftButton)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectLowerButton(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "SelectLowerButton", 54usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).SelectLowerButton)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectUpperButton(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "SelectUpperButton", 55usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).SelectUpperButton)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivateAtPosition(this: *mut core::ffi::c_void, xpos: i32, ypos: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "ActivateAtPosition", 56usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).ActivateAtPosition)(this, xpos, ypos), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectAtPosition(this: *mut core::ffi::c_void, xpos: i32, ypos: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "SelectAtPosition", 57usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).SelectAtPosition)(this, xpos, ypos), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_ButtonAtPosition(this: *mut core::ffi::c_void, xpos: i32, ypos: i32, plbutton: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "get_ButtonAtPosition", 58usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).get_ButtonAtPosition)(this, xpos, ypos, plbutton), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_NumberOfChapters(this: *mut core::ffi::c_void, ltitle: i32, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "get_NumberOfChapters", 59usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).get_NumberOfChapters)(this, ltitle, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalTitleTime(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "TotalTitleTime", 60usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).TotalTitleTime)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn TitlesAvailable(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "TitlesAvailable", 61usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).TitlesAvailable)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn VolumesAvailable(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "VolumesAvailable", 62usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).VolumesAvailable)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentVolume(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "CurrentVolume", 63usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).CurrentVolume)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentDiscSide(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "CurrentDiscSide", 64usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).CurrentDiscSide)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentDomain(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "CurrentDomain", 65usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).CurrentDomain)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentChapter(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "CurrentChapter", 66usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).CurrentChapter)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentTitle(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "CurrentTitle", 67usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).CurrentTitle)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentTime(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "CurrentTime", 68usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).CurrentTime)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DVDTimeCode2bstr(this: *mut core::ffi::c_void, timecode: i32, ptimestr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "DVDTimeCode2bstr", 69usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).DVDTimeCode2bstr)(this, timecode, ptimestr), |result| Some(*result))
+        }
+        unsafe extern "system" fn DVDDirectory(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "DVDDirectory", 70usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).DVDDirectory)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDVDDirectory(this: *mut core::ffi::c_void, newval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "SetDVDDirectory", 71usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).SetDVDDirectory)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSubpictureStreamEnabled(this: *mut core::ffi::c_void, lstream: i32, fenabled: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "IsSubpictureStreamEnabled", 72usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).IsSubpictureStreamEnabled)(this, lstream, fenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAudioStreamEnabled(this: *mut core::ffi::c_void, lstream: i32, fenabled: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "IsAudioStreamEnabled", 73usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).IsAudioStreamEnabled)(this, lstream, fenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentSubpictureStream(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "CurrentSubpictureStream", 74usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).CurrentSubpictureStream)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentSubpictureStream(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "SetCurrentSubpictureStream", 75usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).SetCurrentSubpictureStream)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_SubpictureLanguage(this: *mut core::ffi::c_void, lstream: i32, strlanguage: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "get_SubpictureLanguage", 76usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).get_SubpictureLanguage)(this, lstream, strlanguage), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentAudioStream(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "CurrentAudioStream", 77usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).CurrentAudioStream)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentAudioStream(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "SetCurrentAudioStream", 78usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).SetCurrentAudioStream)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioStreamsAvailable(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "AudioStreamsAvailable", 79usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).AudioStreamsAvailable)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn AnglesAvailable(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "AnglesAvailable", 80usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).AnglesAvailable)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentAngle(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "CurrentAngle", 81usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).CurrentAngle)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentAngle(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "SetCurrentAngle", 82usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).SetCurrentAngle)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubpictureStreamsAvailable(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "SubpictureStreamsAvailable", 83usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).SubpictureStreamsAvailable)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubpictureOn(this: *mut core::ffi::c_void, pval: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "SubpictureOn", 84usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).SubpictureOn)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubpictureOn(this: *mut core::ffi::c_void, newval: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "SetSubpictureOn", 85usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).SetSubpictureOn)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DVDUniqueID(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "DVDUniqueID", 86usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).DVDUniqueID)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcceptParentalLevelChange(this: *mut core::ffi::c_void, faccept: super::super::super::Foundation::VARIANT_BOOL, strusername: core::mem::MaybeUninit<windows_core::BSTR>, strpassword: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "AcceptParentalLevelChange", 87usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).AcceptParentalLevelChange)(this, faccept, strusername, strpassword), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyParentalLevelChange(this: *mut core::ffi::c_void, newval: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "NotifyParentalLevelChange", 88usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).NotifyParentalLevelChange)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectParentalCountry(this: *mut core::ffi::c_void, lcountry: i32, strusername: core::mem::MaybeUninit<windows_core::BSTR>, strpassword: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "SelectParentalCountry", 89usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).SelectParentalCountry)(this, lcountry, strusername, strpassword), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectParentalLevel(this: *mut core::ffi::c_void, lparentallevel: i32, strusername: core::mem::MaybeUninit<windows_core::BSTR>, strpassword: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "SelectParentalLevel", 90usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).SelectParentalLevel)(this, lparentallevel, strusername, strpassword), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_TitleParentalLevels(this: *mut core::ffi::c_void, ltitle: i32, plparentallevels: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "get_TitleParentalLevels", 91usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).get_TitleParentalLevels)(this, ltitle, plparentallevels), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayerParentalCountry(this: *mut core::ffi::c_void, plcountrycode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "PlayerParentalCountry", 92usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).PlayerParentalCountry)(this, plcountrycode), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayerParentalLevel(this: *mut core::ffi::c_void, plparentallevel: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "PlayerParentalLevel", 93usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).PlayerParentalLevel)(this, plparentallevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn Eject(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "Eject", 94usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).Eject)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn UOPValid(this: *mut core::ffi::c_void, luop: i32, pfvalid: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "UOPValid", 95usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).UOPValid)(this, luop, pfvalid), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_SPRM(this: *mut core::ffi::c_void, lindex: i32, pssprm: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "get_SPRM", 96usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).get_SPRM)(this, lindex, pssprm), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_GPRM(this: *mut core::ffi::c_void, lindex: i32, pssprm: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "get_GPRM", 97usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).get_GPRM)(this, lindex, pssprm), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_GPRM(this: *mut core::ffi::c_void, lindex: i32, svalue: i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "put_GPRM", 98usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).put_GPRM)(this, lindex, svalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_DVDTextStringType(this: *mut core::ffi::c_void, llangindex: i32, lstringindex: i32, ptype: *mut DVDTextStringType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "get_DVDTextStringType", 99usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).get_DVDTextStringType)(this, llangindex, lstringindex, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_DVDTextString(this: *mut core::ffi::c_void, llangindex: i32, lstringindex: i32, pstrtext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "get_DVDTextString", 100usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).get_DVDTextString)(this, llangindex, lstringindex, pstrtext), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_DVDTextNumberOfStrings(this: *mut core::ffi::c_void, llangindex: i32, plnumofstrings: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "get_DVDTextNumberOfStrings", 101usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).get_DVDTextNumberOfStrings)(this, llangindex, plnumofstrings), |result| Some(*result))
+        }
+        unsafe extern "system" fn DVDTextNumberOfLanguages(this: *mut core::ffi::c_void, plnumoflangs: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "DVDTextNumberOfLanguages", 102usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).DVDTextNumberOfLanguages)(this, plnumoflangs), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_DVDTextLanguageLCID(this: *mut core::ffi::c_void, llangindex: i32, lcid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "get_DVDTextLanguageLCID", 103usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).get_DVDTextLanguageLCID)(this, llangindex, lcid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegionChange(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "RegionChange", 104usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).RegionChange)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DVDAdm(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "DVDAdm", 105usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).DVDAdm)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteBookmark(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "DeleteBookmark", 106usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).DeleteBookmark)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestoreBookmark(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "RestoreBookmark", 107usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).RestoreBookmark)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveBookmark(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "SaveBookmark", 108usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).SaveBookmark)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectDefaultAudioLanguage(this: *mut core::ffi::c_void, lang: i32, ext: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "SelectDefaultAudioLanguage", 109usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).SelectDefaultAudioLanguage)(this, lang, ext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectDefaultSubpictureLanguage(this: *mut core::ffi::c_void, lang: i32, ext: DVDSPExt) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "SelectDefaultSubpictureLanguage", 110usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).SelectDefaultSubpictureLanguage)(this, lang, ext), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreferredSubpictureStream(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "PreferredSubpictureStream", 111usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).PreferredSubpictureStream)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultMenuLanguage(this: *mut core::ffi::c_void, lang: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "DefaultMenuLanguage", 112usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).DefaultMenuLanguage)(this, lang), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultMenuLanguage(this: *mut core::ffi::c_void, lang: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "SetDefaultMenuLanguage", 113usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).SetDefaultMenuLanguage)(this, lang), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultSubpictureLanguage(this: *mut core::ffi::c_void, lang: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "DefaultSubpictureLanguage", 114usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).DefaultSubpictureLanguage)(this, lang), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultAudioLanguage(this: *mut core::ffi::c_void, lang: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "DefaultAudioLanguage", 115usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).DefaultAudioLanguage)(this, lang), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultSubpictureLanguageExt(this: *mut core::ffi::c_void, ext: *mut DVDSPExt) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "DefaultSubpictureLanguageExt", 116usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).DefaultSubpictureLanguageExt)(this, ext), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultAudioLanguageExt(this: *mut core::ffi::c_void, ext: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "DefaultAudioLanguageExt", 117usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).DefaultAudioLanguageExt)(this, ext), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_LanguageFromLCID(this: *mut core::ffi::c_void, lcid: i32, lang: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "get_LanguageFromLCID", 118usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).get_LanguageFromLCID)(this, lcid, lang), |result| Some(*result))
+        }
+        unsafe extern "system" fn KaraokeAudioPresentationMode(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "KaraokeAudioPresentationMode", 119usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).KaraokeAudioPresentationMode)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKaraokeAudioPresentationMode(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "SetKaraokeAudioPresentationMode", 120usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).SetKaraokeAudioPresentationMode)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_KaraokeChannelContent(this: *mut core::ffi::c_void, lstream: i32, lchan: i32, lcontent: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "get_KaraokeChannelContent", 121usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).get_KaraokeChannelContent)(this, lstream, lchan, lcontent), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_KaraokeChannelAssignment(this: *mut core::ffi::c_void, lstream: i32, lchannelassignment: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "get_KaraokeChannelAssignment", 122usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).get_KaraokeChannelAssignment)(this, lstream, lchannelassignment), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestorePreferredSettings(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "RestorePreferredSettings", 123usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).RestorePreferredSettings)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ButtonRect(this: *mut core::ffi::c_void, lbutton: i32, prect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "get_ButtonRect", 124usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).get_ButtonRect)(this, lbutton, prect), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DVDScreenInMouseCoordinates(this: *mut core::ffi::c_void, pprect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "DVDScreenInMouseCoordinates", 125usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).DVDScreenInMouseCoordinates)(this, pprect), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetDVDScreenInMouseCoordinates(this: *mut core::ffi::c_void, prect: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD", "SetDVDScreenInMouseCoordinates", 126usize, |this| ((**(this as *const *const IMSVidWebDVD_Vtbl)).SetDVDScreenInMouseCoordinates)(this, prect), |result| Some(*result))
+        }
+        Self {
+            base__: <IMSVidPlayback_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnDVDEvent,
+            PlayTitle,
+            PlayChapterInTitle,
+            PlayChapter,
+            PlayChaptersAutoStop,
+            PlayAtTime,
+            PlayAtTimeInTitle,
+            PlayPeriodInTitleAutoStop,
+            ReplayChapter,
+            PlayPrevChapter,
+            PlayNextChapter,
+            StillOff,
+            get_AudioLanguage,
+            ShowMenu,
+            Resume,
+            ReturnFromSubmenu,
+            ButtonsAvailable,
+            CurrentButton,
+            SelectAndActivateButton,
+            ActivateButton,
+            SelectRightButton,
+            SelectLeftButton,
+            SelectLowerButton,
+            SelectUpperButton,
+            ActivateAtPosition,
+            SelectAtPosition,
+            get_ButtonAtPosition,
+            get_NumberOfChapters,
+            TotalTitleTime,
+            TitlesAvailable,
+            VolumesAvailable,
+            CurrentVolume,
+            CurrentDiscSide,
+            CurrentDomain,
+            CurrentChapter,
+            CurrentTitle,
+            CurrentTime,
+            DVDTimeCode2bstr,
+            DVDDirectory,
+            SetDVDDirectory,
+            IsSubpictureStreamEnabled,
+            IsAudioStreamEnabled,
+            CurrentSubpictureStream,
+            SetCurrentSubpictureStream,
+            get_SubpictureLanguage,
+            CurrentAudioStream,
+            SetCurrentAudioStream,
+            AudioStreamsAvailable,
+            AnglesAvailable,
+            CurrentAngle,
+            SetCurrentAngle,
+            SubpictureStreamsAvailable,
+            SubpictureOn,
+            SetSubpictureOn,
+            DVDUniqueID,
+            AcceptParentalLevelChange,
+            NotifyParentalLevelChange,
+            SelectParentalCountry,
+            SelectParentalLevel,
+            get_TitleParentalLevels,
+            PlayerParentalCountry,
+            PlayerParentalLevel,
+            Eject,
+            UOPValid,
+            get_SPRM,
+            get_GPRM,
+            put_GPRM,
+            get_DVDTextStringType,
+            get_DVDTextString,
+            get_DVDTextNumberOfStrings,
+            DVDTextNumberOfLanguages,
+            get_DVDTextLanguageLCID,
+            RegionChange,
+            #[cfg(feature = "Win32_System_Com")]
+            DVDAdm,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DVDAdm: 0,
+            DeleteBookmark,
+            RestoreBookmark,
+            SaveBookmark,
+            SelectDefaultAudioLanguage,
+            SelectDefaultSubpictureLanguage,
+            PreferredSubpictureStream,
+            DefaultMenuLanguage,
+            SetDefaultMenuLanguage,
+            DefaultSubpictureLanguage,
+            DefaultAudioLanguage,
+            DefaultSubpictureLanguageExt,
+            DefaultAudioLanguageExt,
+            get_LanguageFromLCID,
+            KaraokeAudioPresentationMode,
+            SetKaraokeAudioPresentationMode,
+            get_KaraokeChannelContent,
+            get_KaraokeChannelAssignment,
+            RestorePreferredSettings,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ButtonRect,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ButtonRect: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            DVDScreenInMouseCoordinates,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DVDScreenInMouseCoordinates: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetDVDScreenInMouseCoordinates,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetDVDScreenInMouseCoordinates: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidWebDVD as windows_core::Interface>::IID || <IMSVidPlayback_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidWebDVD2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_Bookmark(this: *mut core::ffi::c_void, ppdata: *mut *mut u8, pdatalength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD2", "get_Bookmark", 127usize, |this| ((**(this as *const *const IMSVidWebDVD2_Vtbl)).get_Bookmark)(this, ppdata, pdatalength), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_Bookmark(this: *mut core::ffi::c_void, pdata: *const u8, dwdatalength: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVD2", "put_Bookmark", 128usize, |this| ((**(this as *const *const IMSVidWebDVD2_Vtbl)).put_Bookmark)(this, pdata, dwdatalength), |result| Some(*result))
+        }
+        Self { base__: <IMSVidWebDVD_Vtbl as windows_core::imp::ProxyVtable>::PROXY, get_Bookmark, put_Bookmark }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidWebDVD2 as windows_core::Interface>::IID || <IMSVidWebDVD_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidWebDVDAdm_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChangePassword(this: *mut core::ffi::c_void, strusername: core::mem::MaybeUninit<windows_core::BSTR>, strold: core::mem::MaybeUninit<windows_core::BSTR>, strnew: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDAdm", "ChangePassword", 7usize, |this| ((**(this as *const *const IMSVidWebDVDAdm_Vtbl)).ChangePassword)(this, strusername, strold, strnew), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveParentalLevel(this: *mut core::ffi::c_void, level: i32, strusername: core::mem::MaybeUninit<windows_core::BSTR>, strpassword: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDAdm", "SaveParentalLevel", 8usize, |this| ((**(this as *const *const IMSVidWebDVDAdm_Vtbl)).SaveParentalLevel)(this, level, strusername, strpassword), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveParentalCountry(this: *mut core::ffi::c_void, country: i32, strusername: core::mem::MaybeUninit<windows_core::BSTR>, strpassword: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDAdm", "SaveParentalCountry", 9usize, |this| ((**(this as *const *const IMSVidWebDVDAdm_Vtbl)).SaveParentalCountry)(this, country, strusername, strpassword), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConfirmPassword(this: *mut core::ffi::c_void, strusername: core::mem::MaybeUninit<windows_core::BSTR>, strpassword: core::mem::MaybeUninit<windows_core::BSTR>, pval: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDAdm", "ConfirmPassword", 10usize, |this| ((**(this as *const *const IMSVidWebDVDAdm_Vtbl)).ConfirmPassword)(this, strusername, strpassword, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParentalLevel(this: *mut core::ffi::c_void, llevel: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDAdm", "GetParentalLevel", 11usize, |this| ((**(this as *const *const IMSVidWebDVDAdm_Vtbl)).GetParentalLevel)(this, llevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParentalCountry(this: *mut core::ffi::c_void, lcountry: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDAdm", "GetParentalCountry", 12usize, |this| ((**(this as *const *const IMSVidWebDVDAdm_Vtbl)).GetParentalCountry)(this, lcountry), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultAudioLCID(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDAdm", "DefaultAudioLCID", 13usize, |this| ((**(this as *const *const IMSVidWebDVDAdm_Vtbl)).DefaultAudioLCID)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultAudioLCID(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDAdm", "SetDefaultAudioLCID", 14usize, |this| ((**(this as *const *const IMSVidWebDVDAdm_Vtbl)).SetDefaultAudioLCID)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultSubpictureLCID(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDAdm", "DefaultSubpictureLCID", 15usize, |this| ((**(this as *const *const IMSVidWebDVDAdm_Vtbl)).DefaultSubpictureLCID)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultSubpictureLCID(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDAdm", "SetDefaultSubpictureLCID", 16usize, |this| ((**(this as *const *const IMSVidWebDVDAdm_Vtbl)).SetDefaultSubpictureLCID)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultMenuLCID(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDAdm", "DefaultMenuLCID", 17usize, |this| ((**(this as *const *const IMSVidWebDVDAdm_Vtbl)).DefaultMenuLCID)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultMenuLCID(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDAdm", "SetDefaultMenuLCID", 18usize, |this| ((**(this as *const *const IMSVidWebDVDAdm_Vtbl)).SetDefaultMenuLCID)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn BookmarkOnStop(this: *mut core::ffi::c_void, pval: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDAdm", "BookmarkOnStop", 19usize, |this| ((**(this as *const *const IMSVidWebDVDAdm_Vtbl)).BookmarkOnStop)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBookmarkOnStop(this: *mut core::ffi::c_void, newval: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDAdm", "SetBookmarkOnStop", 20usize, |this| ((**(this as *const *const IMSVidWebDVDAdm_Vtbl)).SetBookmarkOnStop)(this, newval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ChangePassword,
+            SaveParentalLevel,
+            SaveParentalCountry,
+            ConfirmPassword,
+            GetParentalLevel,
+            GetParentalCountry,
+            DefaultAudioLCID,
+            SetDefaultAudioLCID,
+            DefaultSubpictureLCID,
+            SetDefaultSubpictureLCID,
+            DefaultMenuLCID,
+            SetDefaultMenuLCID,
+            BookmarkOnStop,
+            SetBookmarkOnStop,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidWebDVDAdm as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidWebDVDEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DVDNotify(this: *mut core::ffi::c_void, leventcode: i32, lparam1: core::mem::MaybeUninit<windows_core::VARIANT>, lparam2: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDEvent", "DVDNotify", 8usize, |this| ((**(this as *const *const IMSVidWebDVDEvent_Vtbl)).DVDNotify)(this, leventcode, lparam1, lparam2), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayForwards(this: *mut core::ffi::c_void, benabled: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDEvent", "PlayForwards", 9usize, |this| ((**(this as *const *const IMSVidWebDVDEvent_Vtbl)).PlayForwards)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayBackwards(this: *mut core::ffi::c_void, benabled: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDEvent", "PlayBackwards", 10usize, |this| ((**(this as *const *const IMSVidWebDVDEvent_Vtbl)).PlayBackwards)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowMenu(this: *mut core::ffi::c_void, menuid: DVDMenuIDConstants, benabled: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDEvent", "ShowMenu", 11usize, |this| ((**(this as *const *const IMSVidWebDVDEvent_Vtbl)).ShowMenu)(this, menuid, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void, benabled: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDEvent", "Resume", 12usize, |this| ((**(this as *const *const IMSVidWebDVDEvent_Vtbl)).Resume)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectOrActivateButton(this: *mut core::ffi::c_void, benabled: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDEvent", "SelectOrActivateButton", 13usize, |this| ((**(this as *const *const IMSVidWebDVDEvent_Vtbl)).SelectOrActivateButton)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn StillOff(this: *mut core::ffi::c_void, benabled: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDEvent", "StillOff", 14usize, |this| ((**(this as *const *const IMSVidWebDVDEvent_Vtbl)).StillOff)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn PauseOn(this: *mut core::ffi::c_void, benabled: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDEvent", "PauseOn", 15usize, |this| ((**(this as *const *const IMSVidWebDVDEvent_Vtbl)).PauseOn)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeCurrentAudioStream(this: *mut core::ffi::c_void, benabled: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDEvent", "ChangeCurrentAudioStream", 16usize, |this| ((**(this as *const *const IMSVidWebDVDEvent_Vtbl)).ChangeCurrentAudioStream)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeCurrentSubpictureStream(this: *mut core::ffi::c_void, benabled: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDEvent", "ChangeCurrentSubpictureStream", 17usize, |this| ((**(this as *const *const IMSVidWebDVDEvent_Vtbl)).ChangeCurrentSubpictureStream)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeCurrentAngle(this: *mut core::ffi::c_void, benabled: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDEvent", "ChangeCurrentAngle", 18usize, |this| ((**(this as *const *const IMSVidWebDVDEvent_Vtbl)).ChangeCurrentAngle)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayAtTimeInTitle(this: *mut core::ffi::c_void, benabled: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDEvent", "PlayAtTimeInTitle", 19usize, |this| ((**(this as *const *const IMSVidWebDVDEvent_Vtbl)).PlayAtTimeInTitle)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayAtTime(this: *mut core::ffi::c_void, benabled: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDEvent", "PlayAtTime", 20usize, |this| ((**(this as *const *const IMSVidWebDVDEvent_Vtbl)).PlayAtTime)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayChapterInTitle(this: *mut core::ffi::c_void, benabled: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDEvent", "PlayChapterInTitle", 21usize, |this| ((**(this as *const *const IMSVidWebDVDEvent_Vtbl)).PlayChapterInTitle)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayChapter(this: *mut core::ffi::c_void, benabled: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDEvent", "PlayChapter", 22usize, |this| ((**(this as *const *const IMSVidWebDVDEvent_Vtbl)).PlayChapter)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReplayChapter(this: *mut core::ffi::c_void, benabled: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDEvent", "ReplayChapter", 23usize, |this| ((**(this as *const *const IMSVidWebDVDEvent_Vtbl)).ReplayChapter)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayNextChapter(this: *mut core::ffi::c_void, benabled: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDEvent", "PlayNextChapter", 24usize, |this| ((**(this as *const *const IMSVidWebDVDEvent_Vtbl)).PlayNextChapter)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void, benabled: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDEvent", "Stop", 25usize, |this| ((**(this as *const *const IMSVidWebDVDEvent_Vtbl)).Stop)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReturnFromSubmenu(this: *mut core::ffi::c_void, benabled: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDEvent", "ReturnFromSubmenu", 26usize, |this| ((**(this as *const *const IMSVidWebDVDEvent_Vtbl)).ReturnFromSubmenu)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayTitle(this: *mut core::ffi::c_void, benabled: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDEvent", "PlayTitle", 27usize, |this| ((**(this as *const *const IMSVidWebDVDEvent_Vtbl)).PlayTitle)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayPrevChapter(this: *mut core::ffi::c_void, benabled: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDEvent", "PlayPrevChapter", 28usize, |this| ((**(this as *const *const IMSVidWebDVDEvent_Vtbl)).PlayPrevChapter)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeKaraokePresMode(this: *mut core::ffi::c_void, benabled: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDEvent", "ChangeKaraokePresMode", 29usize, |this| ((**(this as *const *const IMSVidWebDVDEvent_Vtbl)).ChangeKaraokePresMode)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeVideoPresMode(this: *mut core::ffi::c_void, benabled: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidWebDVDEvent", "ChangeVideoPresMode", 30usize, |this| ((**(this as *const *const IMSVidWebDVDEvent_Vtbl)).ChangeVideoPresMode)(this, benabled), |result| Some(*result))
+        }
+        Self {
+            base__: <IMSVidPlaybackEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DVDNotify,
+            PlayForwards,
+            PlayBackwards,
+            ShowMenu,
+            Resume,
+            SelectOrActivateButton,
+            StillOff,
+            PauseOn,
+            ChangeCurrentAudioStream,
+            ChangeCurrentSubpictureStream,
+            ChangeCurrentAngle,
+            PlayAtTimeInTitle,
+            PlayAtTime,
+            PlayChapterInTitle,
+            PlayChapter,
+            ReplayChapter,
+            PlayNextChapter,
+            Stop,
+            ReturnFromSubmenu,
+            PlayTitle,
+            PlayPrevChapter,
+            ChangeKaraokePresMode,
+            ChangeVideoPresMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidWebDVDEvent as windows_core::Interface>::IID || <IMSVidPlaybackEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidXDS_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChannelChangeInterface(this: *mut core::ffi::c_void, punkcc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidXDS", "ChannelChangeInterface", 16usize, |this| ((**(this as *const *const IMSVidXDS_Vtbl)).ChannelChangeInterface)(this, punkcc), |result| Some(*result))
+        }
+        Self { base__: <IMSVidFeature_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ChannelChangeInterface }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidXDS as windows_core::Interface>::IID || <IMSVidFeature_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSVidXDSEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RatingChange(this: *mut core::ffi::c_void, prevratingsystem: EnTvRat_System, prevlevel: EnTvRat_GenericLevel, prevattributes: BfEnTvRat_GenericAttributes, newratingsystem: EnTvRat_System, newlevel: EnTvRat_GenericLevel, newattributes: BfEnTvRat_GenericAttributes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSVidXDSEvent", "RatingChange", 8usize, |this| ((**(this as *const *const IMSVidXDSEvent_Vtbl)).RatingChange)(this, prevratingsystem, prevlevel, prevattributes, newratingsystem, newlevel, newattributes), |result| Some(*result))
+        }
+        Self { base__: <IMSVidFeatureEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RatingChange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSVidXDSEvent as windows_core::Interface>::IID || <IMSVidFeatureEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMceBurnerControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBurnerNoDecryption(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMceBurnerControl", "GetBurnerNoDecryption", 3usize, |this| ((**(this as *const *const IMceBurnerControl_Vtbl)).GetBurnerNoDecryption)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetBurnerNoDecryption }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMceBurnerControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMpeg2Data_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSection(this: *mut core::ffi::c_void, pid: u16, tid: u8, pfilter: *const MPEG2_FILTER, dwtimeout: u32, ppsectionlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpeg2Data", "GetSection", 3usize, |this| ((**(this as *const *const IMpeg2Data_Vtbl)).GetSection)(this, pid, tid, pfilter, dwtimeout, ppsectionlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTable(this: *mut core::ffi::c_void, pid: u16, tid: u8, pfilter: *const MPEG2_FILTER, dwtimeout: u32, ppsectionlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpeg2Data", "GetTable", 4usize, |this| ((**(this as *const *const IMpeg2Data_Vtbl)).GetTable)(this, pid, tid, pfilter, dwtimeout, ppsectionlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamOfSections(this: *mut core::ffi::c_void, pid: u16, tid: u8, pfilter: *const MPEG2_FILTER, hdatareadyevent: super::super::super::Foundation::HANDLE, ppmpegstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpeg2Data", "GetStreamOfSections", 5usize, |this| ((**(this as *const *const IMpeg2Data_Vtbl)).GetStreamOfSections)(this, pid, tid, pfilter, hdatareadyevent, ppmpegstream), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSection, GetTable, GetStreamOfSections }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMpeg2Data as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMpeg2Stream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, requesttype: MPEG_REQUEST_TYPE, pmpeg2data: *mut core::ffi::c_void, pcontext: *const MPEG_CONTEXT, pid: u16, tid: u8, pfilter: *const MPEG2_FILTER, hdatareadyevent: super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpeg2Stream", "Initialize", 3usize, |this| ((**(this as *const *const IMpeg2Stream_Vtbl)).Initialize)(this, requesttype, pmpeg2data, pcontext, pid, tid, pfilter, hdatareadyevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupplyDataBuffer(this: *mut core::ffi::c_void, pstreambuffer: *const MPEG_STREAM_BUFFER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpeg2Stream", "SupplyDataBuffer", 4usize, |this| ((**(this as *const *const IMpeg2Stream_Vtbl)).SupplyDataBuffer)(this, pstreambuffer), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, SupplyDataBuffer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMpeg2Stream as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMpeg2TableFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddPID(this: *mut core::ffi::c_void, p: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpeg2TableFilter", "AddPID", 3usize, |this| ((**(this as *const *const IMpeg2TableFilter_Vtbl)).AddPID)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTable(this: *mut core::ffi::c_void, p: u16, t: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpeg2TableFilter", "AddTable", 4usize, |this| ((**(this as *const *const IMpeg2TableFilter_Vtbl)).AddTable)(this, p, t), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddExtension(this: *mut core::ffi::c_void, p: u16, t: u8, e: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpeg2TableFilter", "AddExtension", 5usize, |this| ((**(this as *const *const IMpeg2TableFilter_Vtbl)).AddExtension)(this, p, t, e), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePID(this: *mut core::ffi::c_void, p: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpeg2TableFilter", "RemovePID", 6usize, |this| ((**(this as *const *const IMpeg2TableFilter_Vtbl)).RemovePID)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTable(this: *mut core::ffi::c_void, p: u16, t: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpeg2TableFilter", "RemoveTable", 7usize, |this| ((**(this as *const *const IMpeg2TableFilter_Vtbl)).RemoveTable)(this, p, t), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveExtension(this: *mut core::ffi::c_void, p: u16, t: u8, e: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpeg2TableFilter", "RemoveExtension", 8usize, |this| ((**(this as *const *const IMpeg2TableFilter_Vtbl)).RemoveExtension)(this, p, t, e), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddPID,
+            AddTable,
+            AddExtension,
+            RemovePID,
+            RemoveTable,
+            RemoveExtension,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMpeg2TableFilter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPAT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psectionlist: *mut core::ffi::c_void, pmpegdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPAT", "Initialize", 3usize, |this| ((**(this as *const *const IPAT_Vtbl)).Initialize)(this, psectionlist, pmpegdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransportStreamId(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPAT", "GetTransportStreamId", 4usize, |this| ((**(this as *const *const IPAT_Vtbl)).GetTransportStreamId)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionNumber(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPAT", "GetVersionNumber", 5usize, |this| ((**(this as *const *const IPAT_Vtbl)).GetVersionNumber)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPAT", "GetCountOfRecords", 6usize, |this| ((**(this as *const *const IPAT_Vtbl)).GetCountOfRecords)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordProgramNumber(this: *mut core::ffi::c_void, dwindex: u32, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPAT", "GetRecordProgramNumber", 7usize, |this| ((**(this as *const *const IPAT_Vtbl)).GetRecordProgramNumber)(this, dwindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordProgramMapPid(this: *mut core::ffi::c_void, dwindex: u32, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPAT", "GetRecordProgramMapPid", 8usize, |this| ((**(this as *const *const IPAT_Vtbl)).GetRecordProgramMapPid)(this, dwindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindRecordProgramMapPid(this: *mut core::ffi::c_void, wprogramnumber: u16, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPAT", "FindRecordProgramMapPid", 9usize, |this| ((**(this as *const *const IPAT_Vtbl)).FindRecordProgramMapPid)(this, wprogramnumber, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterForNextTable(this: *mut core::ffi::c_void, hnexttableavailable: super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPAT", "RegisterForNextTable", 10usize, |this| ((**(this as *const *const IPAT_Vtbl)).RegisterForNextTable)(this, hnexttableavailable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextTable(this: *mut core::ffi::c_void, pppat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPAT", "GetNextTable", 11usize, |this| ((**(this as *const *const IPAT_Vtbl)).GetNextTable)(this, pppat), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterForWhenCurrent(this: *mut core::ffi::c_void, hnexttableiscurrent: super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPAT", "RegisterForWhenCurrent", 12usize, |this| ((**(this as *const *const IPAT_Vtbl)).RegisterForWhenCurrent)(this, hnexttableiscurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertNextToCurrent(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPAT", "ConvertNextToCurrent", 13usize, |this| ((**(this as *const *const IPAT_Vtbl)).ConvertNextToCurrent)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetTransportStreamId,
+            GetVersionNumber,
+            GetCountOfRecords,
+            GetRecordProgramNumber,
+            GetRecordProgramMapPid,
+            FindRecordProgramMapPid,
+            RegisterForNextTable,
+            GetNextTable,
+            RegisterForWhenCurrent,
+            ConvertNextToCurrent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPAT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPBDAAttributesDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPBDAAttributesDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IPBDAAttributesDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPBDAAttributesDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IPBDAAttributesDescriptor_Vtbl)).GetLength)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributePayload(this: *mut core::ffi::c_void, ppbattributebuffer: *mut *mut u8, pdwattributelength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPBDAAttributesDescriptor", "GetAttributePayload", 5usize, |this| ((**(this as *const *const IPBDAAttributesDescriptor_Vtbl)).GetAttributePayload)(this, ppbattributebuffer, pdwattributelength), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetTag, GetLength, GetAttributePayload }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPBDAAttributesDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPBDAEntitlementDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPBDAEntitlementDescriptor", "GetTag", 3usize, |this| ((**(this as *const *const IPBDAEntitlementDescriptor_Vtbl)).GetTag)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPBDAEntitlementDescriptor", "GetLength", 4usize, |this| ((**(this as *const *const IPBDAEntitlementDescriptor_Vtbl)).GetLength)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetToken(this: *mut core::ffi::c_void, ppbtokenbuffer: *mut *mut u8, pdwtokenlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPBDAEntitlementDescriptor", "GetToken", 5usize, |this| ((**(this as *const *const IPBDAEntitlementDescriptor_Vtbl)).GetToken)(this, ppbtokenbuffer, pdwtokenlength), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetTag, GetLength, GetToken }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPBDAEntitlementDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPBDASiParser_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPBDASiParser", "Initialize", 3usize, |this| ((**(this as *const *const IPBDASiParser_Vtbl)).Initialize)(this, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEIT(this: *mut core::ffi::c_void, dwsize: u32, pbuffer: *const u8, ppeit: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPBDASiParser", "GetEIT", 4usize, |this| ((**(this as *const *const IPBDASiParser_Vtbl)).GetEIT)(this, dwsize, pbuffer, ppeit), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServices(this: *mut core::ffi::c_void, dwsize: u32, pbuffer: *const u8, ppservices: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPBDASiParser", "GetServices", 5usize, |this| ((**(this as *const *const IPBDASiParser_Vtbl)).GetServices)(this, dwsize, pbuffer, ppservices), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, GetEIT, GetServices }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPBDASiParser as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPBDA_EIT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, size: u32, pbuffer: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPBDA_EIT", "Initialize", 3usize, |this| ((**(this as *const *const IPBDA_EIT_Vtbl)).Initialize)(this, size, pbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableId(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPBDA_EIT", "GetTableId", 4usize, |this| ((**(this as *const *const IPBDA_EIT_Vtbl)).GetTableId)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionNumber(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPBDA_EIT", "GetVersionNumber", 5usize, |this| ((**(this as *const *const IPBDA_EIT_Vtbl)).GetVersionNumber)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServiceIdx(this: *mut core::ffi::c_void, plwval: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPBDA_EIT", "GetServiceIdx", 6usize, |this| ((**(this as *const *const IPBDA_EIT_Vtbl)).GetServiceIdx)(this, plwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPBDA_EIT", "GetCountOfRecords", 7usize, |this| ((**(this as *const *const IPBDA_EIT_Vtbl)).GetCountOfRecords)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordEventId(this: *mut core::ffi::c_void, dwrecordindex: u32, plwval: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPBDA_EIT", "GetRecordEventId", 8usize, |this| ((**(this as *const *const IPBDA_EIT_Vtbl)).GetRecordEventId)(this, dwrecordindex, plwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordStartTime(this: *mut core::ffi::c_void, dwrecordindex: u32, pmdtval: *mut MPEG_DATE_AND_TIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPBDA_EIT", "GetRecordStartTime", 9usize, |this| ((**(this as *const *const IPBDA_EIT_Vtbl)).GetRecordStartTime)(this, dwrecordindex, pmdtval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDuration(this: *mut core::ffi::c_void, dwrecordindex: u32, pmdval: *mut MPEG_TIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPBDA_EIT", "GetRecordDuration", 10usize, |this| ((**(this as *const *const IPBDA_EIT_Vtbl)).GetRecordDuration)(this, dwrecordindex, pmdval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordCountOfDescriptors(this: *mut core::ffi::c_void, dwrecordindex: u32, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPBDA_EIT", "GetRecordCountOfDescriptors", 11usize, |this| ((**(this as *const *const IPBDA_EIT_Vtbl)).GetRecordCountOfDescriptors)(this, dwrecordindex, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptorByIndex(this: *mut core::ffi::c_void, dwrecordindex: u32, dwindex: u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPBDA_EIT", "GetRecordDescriptorByIndex", 12usize, |this| ((**(this as *const *const IPBDA_EIT_Vtbl)).GetRecordDescriptorByIndex)(this, dwrecordindex, dwindex, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptorByTag(this: *mut core::ffi::c_void, dwrecordindex: u32, btag: u8, pdwcookie: *mut u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPBDA_EIT", "GetRecordDescriptorByTag", 13usize, |this| ((**(this as *const *const IPBDA_EIT_Vtbl)).GetRecordDescriptorByTag)(this, dwrecordindex, btag, pdwcookie, ppdescriptor), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetTableId,
+            GetVersionNumber,
+            GetServiceIdx,
+            GetCountOfRecords,
+            GetRecordEventId,
+            GetRecordStartTime,
+            GetRecordDuration,
+            GetRecordCountOfDescriptors,
+            GetRecordDescriptorByIndex,
+            GetRecordDescriptorByTag,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPBDA_EIT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPBDA_Services_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, size: u32, pbuffer: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPBDA_Services", "Initialize", 3usize, |this| ((**(this as *const *const IPBDA_Services_Vtbl)).Initialize)(this, size, pbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPBDA_Services", "GetCountOfRecords", 4usize, |this| ((**(this as *const *const IPBDA_Services_Vtbl)).GetCountOfRecords)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordByIndex(this: *mut core::ffi::c_void, dwrecordindex: u32, pul64serviceidx: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPBDA_Services", "GetRecordByIndex", 5usize, |this| ((**(this as *const *const IPBDA_Services_Vtbl)).GetRecordByIndex)(this, dwrecordindex, pul64serviceidx), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, GetCountOfRecords, GetRecordByIndex }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPBDA_Services as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPMT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psectionlist: *mut core::ffi::c_void, pmpegdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMT", "Initialize", 3usize, |this| ((**(this as *const *const IPMT_Vtbl)).Initialize)(this, psectionlist, pmpegdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProgramNumber(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMT", "GetProgramNumber", 4usize, |this| ((**(this as *const *const IPMT_Vtbl)).GetProgramNumber)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionNumber(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMT", "GetVersionNumber", 5usize, |this| ((**(this as *const *const IPMT_Vtbl)).GetVersionNumber)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPcrPid(this: *mut core::ffi::c_void, ppidval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMT", "GetPcrPid", 6usize, |this| ((**(this as *const *const IPMT_Vtbl)).GetPcrPid)(this, ppidval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfTableDescriptors(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMT", "GetCountOfTableDescriptors", 7usize, |this| ((**(this as *const *const IPMT_Vtbl)).GetCountOfTableDescriptors)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableDescriptorByIndex(this: *mut core::ffi::c_void, dwindex: u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMT", "GetTableDescriptorByIndex", 8usize, |this| ((**(this as *const *const IPMT_Vtbl)).GetTableDescriptorByIndex)(this, dwindex, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableDescriptorByTag(this: *mut core::ffi::c_void, btag: u8, pdwcookie: *mut u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMT", "GetTableDescriptorByTag", 9usize, |this| ((**(this as *const *const IPMT_Vtbl)).GetTableDescriptorByTag)(this, btag, pdwcookie, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfRecords(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMT", "GetCountOfRecords", 10usize, |this| ((**(this as *const *const IPMT_Vtbl)).GetCountOfRecords)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordStreamType(this: *mut core::ffi::c_void, dwrecordindex: u32, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMT", "GetRecordStreamType", 11usize, |this| ((**(this as *const *const IPMT_Vtbl)).GetRecordStreamType)(this, dwrecordindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordElementaryPid(this: *mut core::ffi::c_void, dwrecordindex: u32, ppidval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMT", "GetRecordElementaryPid", 12usize, |this| ((**(this as *const *const IPMT_Vtbl)).GetRecordElementaryPid)(this, dwrecordindex, ppidval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordCountOfDescriptors(this: *mut core::ffi::c_void, dwrecordindex: u32, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMT", "GetRecordCountOfDescriptors", 13usize, |this| ((**(this as *const *const IPMT_Vtbl)).GetRecordCountOfDescriptors)(this, dwrecordindex, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptorByIndex(this: *mut core::ffi::c_void, dwrecordindex: u32, dwdescindex: u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMT", "GetRecordDescriptorByIndex", 14usize, |this| ((**(this as *const *const IPMT_Vtbl)).GetRecordDescriptorByIndex)(this, dwrecordindex, dwdescindex, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordDescriptorByTag(this: *mut core::ffi::c_void, dwrecordindex: u32, btag: u8, pdwcookie: *mut u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMT", "GetRecordDescriptorByTag", 15usize, |this| ((**(this as *const *const IPMT_Vtbl)).GetRecordDescriptorByTag)(this, dwrecordindex, btag, pdwcookie, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryServiceGatewayInfo(this: *mut core::ffi::c_void, ppdsmcclist: *mut *mut DSMCC_ELEMENT, puicount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMT", "QueryServiceGatewayInfo", 16usize, |this| ((**(this as *const *const IPMT_Vtbl)).QueryServiceGatewayInfo)(this, ppdsmcclist, puicount), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryMPEInfo(this: *mut core::ffi::c_void, ppmpelist: *mut *mut MPE_ELEMENT, puicount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMT", "QueryMPEInfo", 17usize, |this| ((**(this as *const *const IPMT_Vtbl)).QueryMPEInfo)(this, ppmpelist, puicount), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterForNextTable(this: *mut core::ffi::c_void, hnexttableavailable: super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMT", "RegisterForNextTable", 18usize, |this| ((**(this as *const *const IPMT_Vtbl)).RegisterForNextTable)(this, hnexttableavailable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextTable(this: *mut core::ffi::c_void, pppmt: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMT", "GetNextTable", 19usize, |this| ((**(this as *const *const IPMT_Vtbl)).GetNextTable)(this, pppmt), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterForWhenCurrent(this: *mut core::ffi::c_void, hnexttableiscurrent: super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMT", "RegisterForWhenCurrent", 20usize, |this| ((**(this as *const *const IPMT_Vtbl)).RegisterForWhenCurrent)(this, hnexttableiscurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertNextToCurrent(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMT", "ConvertNextToCurrent", 21usize, |this| ((**(this as *const *const IPMT_Vtbl)).ConvertNextToCurrent)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetProgramNumber,
+            GetVersionNumber,
+            GetPcrPid,
+            GetCountOfTableDescriptors,
+            GetTableDescriptorByIndex,
+            GetTableDescriptorByTag,
+            GetCountOfRecords,
+            GetRecordStreamType,
+            GetRecordElementaryPid,
+            GetRecordCountOfDescriptors,
+            GetRecordDescriptorByIndex,
+            GetRecordDescriptorByTag,
+            QueryServiceGatewayInfo,
+            QueryMPEInfo,
+            RegisterForNextTable,
+            GetNextTable,
+            RegisterForWhenCurrent,
+            ConvertNextToCurrent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPMT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPSITables_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTable(this: *mut core::ffi::c_void, dwtsid: u32, dwtid_pid: u32, dwhashedver: u32, dwpara4: u32, ppiunknown: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPSITables", "GetTable", 3usize, |this| ((**(this as *const *const IPSITables_Vtbl)).GetTable)(this, dwtsid, dwtid_pid, dwhashedver, dwpara4, ppiunknown), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetTable }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPSITables as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPTFilterLicenseRenewal_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RenewLicenses(this: *mut core::ffi::c_void, wszfilename: windows_core::PCWSTR, wszexpiredkid: windows_core::PCWSTR, dwcallersid: u32, bhighpriority: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPTFilterLicenseRenewal", "RenewLicenses", 3usize, |this| ((**(this as *const *const IPTFilterLicenseRenewal_Vtbl)).RenewLicenses)(this, wszfilename, wszexpiredkid, dwcallersid, bhighpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelLicenseRenewal(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPTFilterLicenseRenewal", "CancelLicenseRenewal", 4usize, |this| ((**(this as *const *const IPTFilterLicenseRenewal_Vtbl)).CancelLicenseRenewal)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RenewLicenses, CancelLicenseRenewal }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPTFilterLicenseRenewal as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPersistTuneXml_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitNew(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistTuneXml", "InitNew", 4usize, |this| ((**(this as *const *const IPersistTuneXml_Vtbl)).InitNew)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Load(this: *mut core::ffi::c_void, varvalue: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistTuneXml", "Load", 5usize, |this| ((**(this as *const *const IPersistTuneXml_Vtbl)).Load)(this, varvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void, pvarfragment: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistTuneXml", "Save", 6usize, |this| ((**(this as *const *const IPersistTuneXml_Vtbl)).Save)(this, pvarfragment), |result| Some(*result))
+        }
+        Self { base__: <super::super::super::System::Com::IPersist_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InitNew, Load, Save }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPersistTuneXml as windows_core::Interface>::IID || <super::super::super::System::Com::IPersist_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPersistTuneXmlUtility_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Deserialize(this: *mut core::ffi::c_void, varvalue: core::mem::MaybeUninit<windows_core::VARIANT>, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistTuneXmlUtility", "Deserialize", 3usize, |this| ((**(this as *const *const IPersistTuneXmlUtility_Vtbl)).Deserialize)(this, varvalue, ppobject), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Deserialize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPersistTuneXmlUtility as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPersistTuneXmlUtility2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Serialize(this: *mut core::ffi::c_void, pitunerequest: *mut core::ffi::c_void, pstring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistTuneXmlUtility2", "Serialize", 4usize, |this| ((**(this as *const *const IPersistTuneXmlUtility2_Vtbl)).Serialize)(this, pitunerequest, pstring), |result| Some(*result))
+        }
+        Self {
+            base__: <IPersistTuneXmlUtility_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Serialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Serialize: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPersistTuneXmlUtility2 as windows_core::Interface>::IID || <IPersistTuneXmlUtility_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRegisterTuner_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Register(this: *mut core::ffi::c_void, ptuner: *mut core::ffi::c_void, pgraph: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterTuner", "Register", 3usize, |this| ((**(this as *const *const IRegisterTuner_Vtbl)).Register)(this, ptuner, pgraph), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unregister(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterTuner", "Unregister", 4usize, |this| ((**(this as *const *const IRegisterTuner_Vtbl)).Unregister)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Register, Unregister }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRegisterTuner as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISBE2Crossbar_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnableDefaultMode(this: *mut core::ffi::c_void, defaultflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISBE2Crossbar", "EnableDefaultMode", 3usize, |this| ((**(this as *const *const ISBE2Crossbar_Vtbl)).EnableDefaultMode)(this, defaultflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInitialProfile(this: *mut core::ffi::c_void, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISBE2Crossbar", "GetInitialProfile", 4usize, |this| ((**(this as *const *const ISBE2Crossbar_Vtbl)).GetInitialProfile)(this, ppprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputProfile(this: *mut core::ffi::c_void, pprofile: *mut core::ffi::c_void, pcoutputpins: *mut u32, ppoutputpins: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISBE2Crossbar", "SetOutputProfile", 5usize, |this| ((**(this as *const *const ISBE2Crossbar_Vtbl)).SetOutputProfile)(this, pprofile, pcoutputpins, ppoutputpins), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumStreams(this: *mut core::ffi::c_void, ppstreams: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISBE2Crossbar", "EnumStreams", 6usize, |this| ((**(this as *const *const ISBE2Crossbar_Vtbl)).EnumStreams)(this, ppstreams), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EnableDefaultMode,
+            GetInitialProfile,
+            SetOutputProfile,
+            EnumStreams,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISBE2Crossbar as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISBE2EnumStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, crequest: u32, pstreamdesc: *mut SBE2_STREAM_DESC, pcreceived: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISBE2EnumStream", "Next", 3usize, |this| ((**(this as *const *const ISBE2EnumStream_Vtbl)).Next)(this, crequest, pstreamdesc, pcreceived), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, crecords: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISBE2EnumStream", "Skip", 4usize, |this| ((**(this as *const *const ISBE2EnumStream_Vtbl)).Skip)(this, crecords), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISBE2EnumStream", "Reset", 5usize, |this| ((**(this as *const *const ISBE2EnumStream_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppienumstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISBE2EnumStream", "Clone", 6usize, |this| ((**(this as *const *const ISBE2EnumStream_Vtbl)).Clone)(this, ppienumstream), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISBE2EnumStream as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISBE2FileScan_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RepairFile(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISBE2FileScan", "RepairFile", 3usize, |this| ((**(this as *const *const ISBE2FileScan_Vtbl)).RepairFile)(this, filename), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RepairFile }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISBE2FileScan as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISBE2GlobalEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEvent(this: *mut core::ffi::c_void, idevt: *const windows_core::GUID, param1: u32, param2: u32, param3: u32, param4: u32, pspanning: *mut super::super::super::Foundation::BOOL, pcb: *mut u32, pb: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISBE2GlobalEvent", "GetEvent", 3usize, |this| ((**(this as *const *const ISBE2GlobalEvent_Vtbl)).GetEvent)(this, idevt, param1, param2, param3, param4, pspanning, pcb, pb), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISBE2GlobalEvent as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISBE2GlobalEvent2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEventEx(this: *mut core::ffi::c_void, idevt: *const windows_core::GUID, param1: u32, param2: u32, param3: u32, param4: u32, pspanning: *mut super::super::super::Foundation::BOOL, pcb: *mut u32, pb: *mut u8, pstreamtime: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISBE2GlobalEvent2", "GetEventEx", 4usize, |this| ((**(this as *const *const ISBE2GlobalEvent2_Vtbl)).GetEventEx)(this, idevt, param1, param2, param3, param4, pspanning, pcb, pb, pstreamtime), |result| Some(*result))
+        }
+        Self { base__: <ISBE2GlobalEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetEventEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISBE2GlobalEvent2 as windows_core::Interface>::IID || <ISBE2GlobalEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_MediaFoundation")]
+impl windows_core::imp::ProxyVtable for ISBE2MediaTypeProfile_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStreamCount(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISBE2MediaTypeProfile", "GetStreamCount", 3usize, |this| ((**(this as *const *const ISBE2MediaTypeProfile_Vtbl)).GetStreamCount)(this, pcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn GetStream(this: *mut core::ffi::c_void, index: u32, ppmediatype: *mut *mut super::super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISBE2MediaTypeProfile", "GetStream", 4usize, |this| ((**(this as *const *const ISBE2MediaTypeProfile_Vtbl)).GetStream)(this, index, ppmediatype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn AddStream(this: *mut core::ffi::c_void, pmediatype: *const super::super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISBE2MediaTypeProfile", "AddStream", 5usize, |this| ((**(this as *const *const ISBE2MediaTypeProfile_Vtbl)).AddStream)(this, pmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteStream(this: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISBE2MediaTypeProfile", "DeleteStream", 6usize, |this| ((**(this as *const *const ISBE2MediaTypeProfile_Vtbl)).DeleteStream)(this, index), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetStreamCount,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            GetStream,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            GetStream: 0,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            AddStream,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            AddStream: 0,
+            DeleteStream,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISBE2MediaTypeProfile as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISBE2SpanningEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEvent(this: *mut core::ffi::c_void, idevt: *const windows_core::GUID, streamid: u32, pcb: *mut u32, pb: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISBE2SpanningEvent", "GetEvent", 3usize, |this| ((**(this as *const *const ISBE2SpanningEvent_Vtbl)).GetEvent)(this, idevt, streamid, pcb, pb), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISBE2SpanningEvent as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISBE2StreamMap_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MapStream(this: *mut core::ffi::c_void, stream: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISBE2StreamMap", "MapStream", 3usize, |this| ((**(this as *const *const ISBE2StreamMap_Vtbl)).MapStream)(this, stream), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnmapStream(this: *mut core::ffi::c_void, stream: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISBE2StreamMap", "UnmapStream", 4usize, |this| ((**(this as *const *const ISBE2StreamMap_Vtbl)).UnmapStream)(this, stream), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumMappedStreams(this: *mut core::ffi::c_void, ppstreams: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISBE2StreamMap", "EnumMappedStreams", 5usize, |this| ((**(this as *const *const ISBE2StreamMap_Vtbl)).EnumMappedStreams)(this, ppstreams), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MapStream, UnmapStream, EnumMappedStreams }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISBE2StreamMap as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISCTE_EAS_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psectionlist: *mut core::ffi::c_void, pmpegdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "Initialize", 3usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).Initialize)(this, psectionlist, pmpegdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionNumber(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "GetVersionNumber", 4usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).GetVersionNumber)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSequencyNumber(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "GetSequencyNumber", 5usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).GetSequencyNumber)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProtocolVersion(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "GetProtocolVersion", 6usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).GetProtocolVersion)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEASEventID(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "GetEASEventID", 7usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).GetEASEventID)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOriginatorCode(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "GetOriginatorCode", 8usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).GetOriginatorCode)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEASEventCodeLen(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "GetEASEventCodeLen", 9usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).GetEASEventCodeLen)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEASEventCode(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "GetEASEventCode", 10usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).GetEASEventCode)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRawNatureOfActivationTextLen(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "GetRawNatureOfActivationTextLen", 11usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).GetRawNatureOfActivationTextLen)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRawNatureOfActivationText(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "GetRawNatureOfActivationText", 12usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).GetRawNatureOfActivationText)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNatureOfActivationText(this: *mut core::ffi::c_void, bstris0639code: core::mem::MaybeUninit<windows_core::BSTR>, pbstrstring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "GetNatureOfActivationText", 13usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).GetNatureOfActivationText)(this, bstris0639code, pbstrstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTimeRemaining(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "GetTimeRemaining", 14usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).GetTimeRemaining)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStartTime(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "GetStartTime", 15usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).GetStartTime)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDuration(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "GetDuration", 16usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).GetDuration)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAlertPriority(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "GetAlertPriority", 17usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).GetAlertPriority)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDetailsOOBSourceID(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "GetDetailsOOBSourceID", 18usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).GetDetailsOOBSourceID)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDetailsMajor(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "GetDetailsMajor", 19usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).GetDetailsMajor)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDetailsMinor(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "GetDetailsMinor", 20usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).GetDetailsMinor)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDetailsAudioOOBSourceID(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "GetDetailsAudioOOBSourceID", 21usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).GetDetailsAudioOOBSourceID)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAlertText(this: *mut core::ffi::c_void, bstris0639code: core::mem::MaybeUninit<windows_core::BSTR>, pbstrstring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "GetAlertText", 22usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).GetAlertText)(this, bstris0639code, pbstrstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRawAlertTextLen(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "GetRawAlertTextLen", 23usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).GetRawAlertTextLen)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRawAlertText(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "GetRawAlertText", 24usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).GetRawAlertText)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocationCount(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "GetLocationCount", 25usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).GetLocationCount)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocationCodes(this: *mut core::ffi::c_void, bindex: u8, pbstate: *mut u8, pbcountysubdivision: *mut u8, pwcounty: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "GetLocationCodes", 26usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).GetLocationCodes)(this, bindex, pbstate, pbcountysubdivision, pwcounty), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExceptionCount(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "GetExceptionCount", 27usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).GetExceptionCount)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExceptionService(this: *mut core::ffi::c_void, bindex: u8, pbibref: *mut u8, pwfirst: *mut u16, pwsecond: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "GetExceptionService", 28usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).GetExceptionService)(this, bindex, pbibref, pwfirst, pwsecond), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfTableDescriptors(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "GetCountOfTableDescriptors", 29usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).GetCountOfTableDescriptors)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableDescriptorByIndex(this: *mut core::ffi::c_void, dwindex: u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "GetTableDescriptorByIndex", 30usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).GetTableDescriptorByIndex)(this, dwindex, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableDescriptorByTag(this: *mut core::ffi::c_void, btag: u8, pdwcookie: *mut u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCTE_EAS", "GetTableDescriptorByTag", 31usize, |this| ((**(this as *const *const ISCTE_EAS_Vtbl)).GetTableDescriptorByTag)(this, btag, pdwcookie, ppdescriptor), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetVersionNumber,
+            GetSequencyNumber,
+            GetProtocolVersion,
+            GetEASEventID,
+            GetOriginatorCode,
+            GetEASEventCodeLen,
+            GetEASEventCode,
+            GetRawNatureOfActivationTextLen,
+            GetRawNatureOfActivationText,
+            GetNatureOfActivationText,
+            GetTimeRemaining,
+            GetStartTime,
+            GetDuration,
+            GetAlertPriority,
+            GetDetailsOOBSourceID,
+            GetDetailsMajor,
+            GetDetailsMinor,
+            GetDetailsAudioOOBSourceID,
+            GetAlertText,
+            GetRawAlertTextLen,
+            GetRawAlertText,
+            GetLocationCount,
+            GetLocationCodes,
+            GetExceptionCount,
+            GetExceptionService,
+            GetCountOfTableDescriptors,
+            GetTableDescriptorByIndex,
+            GetTableDescriptorByTag,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISCTE_EAS as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISIInbandEPG_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartSIEPGScan(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISIInbandEPG", "StartSIEPGScan", 3usize, |this| ((**(this as *const *const ISIInbandEPG_Vtbl)).StartSIEPGScan)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopSIEPGScan(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISIInbandEPG", "StopSIEPGScan", 4usize, |this| ((**(this as *const *const ISIInbandEPG_Vtbl)).StopSIEPGScan)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSIEPGScanRunning(this: *mut core::ffi::c_void, brunning: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISIInbandEPG", "IsSIEPGScanRunning", 5usize, |this| ((**(this as *const *const ISIInbandEPG_Vtbl)).IsSIEPGScanRunning)(this, brunning), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartSIEPGScan, StopSIEPGScan, IsSIEPGScanRunning }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISIInbandEPG as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISIInbandEPGEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SIObjectEvent(this: *mut core::ffi::c_void, pidvb_eit: *mut core::ffi::c_void, dwtable_id: u32, dwservice_id: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISIInbandEPGEvent", "SIObjectEvent", 3usize, |this| ((**(this as *const *const ISIInbandEPGEvent_Vtbl)).SIObjectEvent)(this, pidvb_eit, dwtable_id, dwservice_id), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SIObjectEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISIInbandEPGEvent as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IScanningTuner_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SeekUp(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScanningTuner", "SeekUp", 13usize, |this| ((**(this as *const *const IScanningTuner_Vtbl)).SeekUp)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SeekDown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScanningTuner", "SeekDown", 14usize, |this| ((**(this as *const *const IScanningTuner_Vtbl)).SeekDown)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScanUp(this: *mut core::ffi::c_void, millisecondspause: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScanningTuner", "ScanUp", 15usize, |this| ((**(this as *const *const IScanningTuner_Vtbl)).ScanUp)(this, millisecondspause), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScanDown(this: *mut core::ffi::c_void, millisecondspause: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScanningTuner", "ScanDown", 16usize, |this| ((**(this as *const *const IScanningTuner_Vtbl)).ScanDown)(this, millisecondspause), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoProgram(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScanningTuner", "AutoProgram", 17usize, |this| ((**(this as *const *const IScanningTuner_Vtbl)).AutoProgram)(this), |result| Some(*result))
+        }
+        Self { base__: <ITuner_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SeekUp, SeekDown, ScanUp, ScanDown, AutoProgram }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScanningTuner as windows_core::Interface>::IID || <ITuner_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IScanningTunerEx_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetCurrentLocator(this: *mut core::ffi::c_void, pilocator: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScanningTunerEx", "GetCurrentLocator", 18usize, |this| ((**(this as *const *const IScanningTunerEx_Vtbl)).GetCurrentLocator)(this, pilocator), |result| Some(*result))
+        }
+        unsafe extern "system" fn PerformExhaustiveScan(this: *mut core::ffi::c_void, dwlowerfreq: i32, dwhigherfreq: i32, bfinetune: super::super::super::Foundation::VARIANT_BOOL, hevent: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScanningTunerEx", "PerformExhaustiveScan", 19usize, |this| ((**(this as *const *const IScanningTunerEx_Vtbl)).PerformExhaustiveScan)(this, dwlowerfreq, dwhigherfreq, bfinetune, hevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn TerminateCurrentScan(this: *mut core::ffi::c_void, pcurrentfreq: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScanningTunerEx", "TerminateCurrentScan", 20usize, |this| ((**(this as *const *const IScanningTunerEx_Vtbl)).TerminateCurrentScan)(this, pcurrentfreq), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResumeCurrentScan(this: *mut core::ffi::c_void, hevent: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScanningTunerEx", "ResumeCurrentScan", 21usize, |this| ((**(this as *const *const IScanningTunerEx_Vtbl)).ResumeCurrentScan)(this, hevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTunerScanningCapability(this: *mut core::ffi::c_void, hardwareassistedscanning: *mut i32, numstandardssupported: *mut i32, broadcaststandards: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScanningTunerEx", "GetTunerScanningCapability", 22usize, |this| ((**(this as *const *const IScanningTunerEx_Vtbl)).GetTunerScanningCapability)(this, hardwareassistedscanning, numstandardssupported, broadcaststandards), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTunerStatus(this: *mut core::ffi::c_void, secondsleft: *mut i32, currentlocktype: *mut i32, autodetect: *mut i32, currentfreq: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScanningTunerEx", "GetTunerStatus", 23usize, |this| ((**(this as *const *const IScanningTunerEx_Vtbl)).GetTunerStatus)(this, secondsleft, currentlocktype, autodetect, currentfreq), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentTunerStandardCapability(this: *mut core::ffi::c_void, currentbroadcaststandard: windows_core::GUID, settlingtime: *mut i32, tvstandardssupported: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScanningTunerEx", "GetCurrentTunerStandardCapability", 24usize, |this| ((**(this as *const *const IScanningTunerEx_Vtbl)).GetCurrentTunerStandardCapability)(this, currentbroadcaststandard, settlingtime, tvstandardssupported), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScanSignalTypeFilter(this: *mut core::ffi::c_void, scanmodulationtypes: i32, analogvideostandard: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScanningTunerEx", "SetScanSignalTypeFilter", 25usize, |this| ((**(this as *const *const IScanningTunerEx_Vtbl)).SetScanSignalTypeFilter)(this, scanmodulationtypes, analogvideostandard), |result| Some(*result))
+        }
+        Self {
+            base__: <IScanningTuner_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetCurrentLocator,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetCurrentLocator: 0,
+            PerformExhaustiveScan,
+            TerminateCurrentScan,
+            ResumeCurrentScan,
+            GetTunerScanningCapability,
+            GetTunerStatus,
+            GetCurrentTunerStandardCapability,
+            SetScanSignalTypeFilter,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScanningTunerEx as windows_core::Interface>::IID || <IScanningTuner_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISectionList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, requesttype: MPEG_REQUEST_TYPE, pmpeg2data: *mut core::ffi::c_void, pcontext: *const MPEG_CONTEXT, pid: u16, tid: u8, pfilter: *const MPEG2_FILTER, timeout: u32, hdoneevent: super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISectionList", "Initialize", 3usize, |this| ((**(this as *const *const ISectionList_Vtbl)).Initialize)(this, requesttype, pmpeg2data, pcontext, pid, tid, pfilter, timeout, hdoneevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeWithRawSections(this: *mut core::ffi::c_void, pmplsections: *const MPEG_PACKET_LIST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISectionList", "InitializeWithRawSections", 4usize, |this| ((**(this as *const *const ISectionList_Vtbl)).InitializeWithRawSections)(this, pmplsections), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelPendingRequest(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISectionList", "CancelPendingRequest", 5usize, |this| ((**(this as *const *const ISectionList_Vtbl)).CancelPendingRequest)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumberOfSections(this: *mut core::ffi::c_void, pcount: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISectionList", "GetNumberOfSections", 6usize, |this| ((**(this as *const *const ISectionList_Vtbl)).GetNumberOfSections)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSectionData(this: *mut core::ffi::c_void, sectionnumber: u16, pdwrawpacketlength: *mut u32, ppsection: *mut *mut SECTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISectionList", "GetSectionData", 7usize, |this| ((**(this as *const *const ISectionList_Vtbl)).GetSectionData)(this, sectionnumber, pdwrawpacketlength, ppsection), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProgramIdentifier(this: *mut core::ffi::c_void, ppid: *const u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISectionList", "GetProgramIdentifier", 8usize, |this| ((**(this as *const *const ISectionList_Vtbl)).GetProgramIdentifier)(this, ppid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableIdentifier(this: *mut core::ffi::c_void, ptableid: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISectionList", "GetTableIdentifier", 9usize, |this| ((**(this as *const *const ISectionList_Vtbl)).GetTableIdentifier)(this, ptableid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            InitializeWithRawSections,
+            CancelPendingRequest,
+            GetNumberOfSections,
+            GetSectionData,
+            GetProgramIdentifier,
+            GetTableIdentifier,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISectionList as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IServiceLocationDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPCR_PID(this: *mut core::ffi::c_void, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceLocationDescriptor", "GetPCR_PID", 3usize, |this| ((**(this as *const *const IServiceLocationDescriptor_Vtbl)).GetPCR_PID)(this, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumberOfElements(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceLocationDescriptor", "GetNumberOfElements", 4usize, |this| ((**(this as *const *const IServiceLocationDescriptor_Vtbl)).GetNumberOfElements)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetElementStreamType(this: *mut core::ffi::c_void, bindex: u8, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceLocationDescriptor", "GetElementStreamType", 5usize, |this| ((**(this as *const *const IServiceLocationDescriptor_Vtbl)).GetElementStreamType)(this, bindex, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetElementPID(this: *mut core::ffi::c_void, bindex: u8, pwval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceLocationDescriptor", "GetElementPID", 6usize, |this| ((**(this as *const *const IServiceLocationDescriptor_Vtbl)).GetElementPID)(this, bindex, pwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetElementLanguageCode(this: *mut core::ffi::c_void, bindex: u8, langcode: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceLocationDescriptor", "GetElementLanguageCode", 7usize, |this| ((**(this as *const *const IServiceLocationDescriptor_Vtbl)).GetElementLanguageCode)(this, bindex, langcode), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPCR_PID,
+            GetNumberOfElements,
+            GetElementStreamType,
+            GetElementPID,
+            GetElementLanguageCode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServiceLocationDescriptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStreamBufferConfigure_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDirectory(this: *mut core::ffi::c_void, pszdirectoryname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferConfigure", "SetDirectory", 3usize, |this| ((**(this as *const *const IStreamBufferConfigure_Vtbl)).SetDirectory)(this, pszdirectoryname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDirectory(this: *mut core::ffi::c_void, ppszdirectoryname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferConfigure", "GetDirectory", 4usize, |this| ((**(this as *const *const IStreamBufferConfigure_Vtbl)).GetDirectory)(this, ppszdirectoryname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackingFileCount(this: *mut core::ffi::c_void, dwmin: u32, dwmax: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferConfigure", "SetBackingFileCount", 5usize, |this| ((**(this as *const *const IStreamBufferConfigure_Vtbl)).SetBackingFileCount)(this, dwmin, dwmax), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBackingFileCount(this: *mut core::ffi::c_void, pdwmin: *mut u32, pdwmax: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferConfigure", "GetBackingFileCount", 6usize, |this| ((**(this as *const *const IStreamBufferConfigure_Vtbl)).GetBackingFileCount)(this, pdwmin, pdwmax), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackingFileDuration(this: *mut core::ffi::c_void, dwseconds: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferConfigure", "SetBackingFileDuration", 7usize, |this| ((**(this as *const *const IStreamBufferConfigure_Vtbl)).SetBackingFileDuration)(this, dwseconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBackingFileDuration(this: *mut core::ffi::c_void, pdwseconds: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferConfigure", "GetBackingFileDuration", 8usize, |this| ((**(this as *const *const IStreamBufferConfigure_Vtbl)).GetBackingFileDuration)(this, pdwseconds), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetDirectory,
+            GetDirectory,
+            SetBackingFileCount,
+            GetBackingFileCount,
+            SetBackingFileDuration,
+            GetBackingFileDuration,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamBufferConfigure as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStreamBufferConfigure2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMultiplexedPacketSize(this: *mut core::ffi::c_void, cbbytesperpacket: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferConfigure2", "SetMultiplexedPacketSize", 9usize, |this| ((**(this as *const *const IStreamBufferConfigure2_Vtbl)).SetMultiplexedPacketSize)(this, cbbytesperpacket), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMultiplexedPacketSize(this: *mut core::ffi::c_void, pcbbytesperpacket: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferConfigure2", "GetMultiplexedPacketSize", 10usize, |this| ((**(this as *const *const IStreamBufferConfigure2_Vtbl)).GetMultiplexedPacketSize)(this, pcbbytesperpacket), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFFTransitionRates(this: *mut core::ffi::c_void, dwmaxfullframerate: u32, dwmaxnonskippingrate: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferConfigure2", "SetFFTransitionRates", 11usize, |this| ((**(this as *const *const IStreamBufferConfigure2_Vtbl)).SetFFTransitionRates)(this, dwmaxfullframerate, dwmaxnonskippingrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFFTransitionRates(this: *mut core::ffi::c_void, pdwmaxfullframerate: *mut u32, pdwmaxnonskippingrate: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferConfigure2", "GetFFTransitionRates", 12usize, |this| ((**(this as *const *const IStreamBufferConfigure2_Vtbl)).GetFFTransitionRates)(this, pdwmaxfullframerate, pdwmaxnonskippingrate), |result| Some(*result))
+        }
+        Self {
+            base__: <IStreamBufferConfigure_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetMultiplexedPacketSize,
+            GetMultiplexedPacketSize,
+            SetFFTransitionRates,
+            GetFFTransitionRates,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamBufferConfigure2 as windows_core::Interface>::IID || <IStreamBufferConfigure_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStreamBufferConfigure3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetStartRecConfig(this: *mut core::ffi::c_void, fstartstopscur: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferConfigure3", "SetStartRecConfig", 13usize, |this| ((**(this as *const *const IStreamBufferConfigure3_Vtbl)).SetStartRecConfig)(this, fstartstopscur), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStartRecConfig(this: *mut core::ffi::c_void, pfstartstopscur: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferConfigure3", "GetStartRecConfig", 14usize, |this| ((**(this as *const *const IStreamBufferConfigure3_Vtbl)).GetStartRecConfig)(this, pfstartstopscur), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNamespace(this: *mut core::ffi::c_void, psznamespace: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferConfigure3", "SetNamespace", 15usize, |this| ((**(this as *const *const IStreamBufferConfigure3_Vtbl)).SetNamespace)(this, psznamespace), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNamespace(this: *mut core::ffi::c_void, ppsznamespace: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferConfigure3", "GetNamespace", 16usize, |this| ((**(this as *const *const IStreamBufferConfigure3_Vtbl)).GetNamespace)(this, ppsznamespace), |result| Some(*result))
+        }
+        Self {
+            base__: <IStreamBufferConfigure2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetStartRecConfig,
+            GetStartRecConfig,
+            SetNamespace,
+            GetNamespace,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamBufferConfigure3 as windows_core::Interface>::IID || <IStreamBufferConfigure2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStreamBufferDataCounters_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetData(this: *mut core::ffi::c_void, ppindata: *mut SBE_PIN_DATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferDataCounters", "GetData", 3usize, |this| ((**(this as *const *const IStreamBufferDataCounters_Vtbl)).GetData)(this, ppindata), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetData(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferDataCounters", "ResetData", 4usize, |this| ((**(this as *const *const IStreamBufferDataCounters_Vtbl)).ResetData)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetData, ResetData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamBufferDataCounters as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Security", feature = "Win32_System_Registry"))]
+impl windows_core::imp::ProxyVtable for IStreamBufferInitialize_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Registry")]
+        unsafe extern "system" fn SetHKEY(this: *mut core::ffi::c_void, hkeyroot: super::super::super::System::Registry::HKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferInitialize", "SetHKEY", 3usize, |this| ((**(this as *const *const IStreamBufferInitialize_Vtbl)).SetHKEY)(this, hkeyroot), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Security")]
+        unsafe extern "system" fn SetSIDs(this: *mut core::ffi::c_void, csids: u32, ppsid: *mut super::super::super::Security::PSID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferInitialize", "SetSIDs", 4usize, |this| ((**(this as *const *const IStreamBufferInitialize_Vtbl)).SetSIDs)(this, csids, ppsid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Registry")]
+            SetHKEY,
+            #[cfg(not(feature = "Win32_System_Registry"))]
+            SetHKEY: 0,
+            #[cfg(feature = "Win32_Security")]
+            SetSIDs,
+            #[cfg(not(feature = "Win32_Security"))]
+            SetSIDs: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamBufferInitialize as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStreamBufferMediaSeeking_Vtbl {
+    const PROXY: Self = { Self { base__: <super::IMediaSeeking_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamBufferMediaSeeking as windows_core::Interface>::IID || <super::IMediaSeeking_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStreamBufferMediaSeeking2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetRateEx(this: *mut core::ffi::c_void, drate: f64, dwframespersec: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferMediaSeeking2", "SetRateEx", 20usize, |this| ((**(this as *const *const IStreamBufferMediaSeeking2_Vtbl)).SetRateEx)(this, drate, dwframespersec), |result| Some(*result))
+        }
+        Self { base__: <IStreamBufferMediaSeeking_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetRateEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamBufferMediaSeeking2 as windows_core::Interface>::IID || <IStreamBufferMediaSeeking_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStreamBufferRecComp_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psztargetfilename: windows_core::PCWSTR, pszsbrecprofileref: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferRecComp", "Initialize", 3usize, |this| ((**(this as *const *const IStreamBufferRecComp_Vtbl)).Initialize)(this, psztargetfilename, pszsbrecprofileref), |result| Some(*result))
+        }
+        unsafe extern "system" fn Append(this: *mut core::ffi::c_void, pszsbrecording: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferRecComp", "Append", 4usize, |this| ((**(this as *const *const IStreamBufferRecComp_Vtbl)).Append)(this, pszsbrecording), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppendEx(this: *mut core::ffi::c_void, pszsbrecording: windows_core::PCWSTR, rtstart: i64, rtstop: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferRecComp", "AppendEx", 5usize, |this| ((**(this as *const *const IStreamBufferRecComp_Vtbl)).AppendEx)(this, pszsbrecording, rtstart, rtstop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentLength(this: *mut core::ffi::c_void, pcseconds: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferRecComp", "GetCurrentLength", 6usize, |this| ((**(this as *const *const IStreamBufferRecComp_Vtbl)).GetCurrentLength)(this, pcseconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferRecComp", "Close", 7usize, |this| ((**(this as *const *const IStreamBufferRecComp_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferRecComp", "Cancel", 8usize, |this| ((**(this as *const *const IStreamBufferRecComp_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, Append, AppendEx, GetCurrentLength, Close, Cancel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamBufferRecComp as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStreamBufferRecordControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void, prtstart: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferRecordControl", "Start", 3usize, |this| ((**(this as *const *const IStreamBufferRecordControl_Vtbl)).Start)(this, prtstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void, rtstop: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferRecordControl", "Stop", 4usize, |this| ((**(this as *const *const IStreamBufferRecordControl_Vtbl)).Stop)(this, rtstop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordingStatus(this: *mut core::ffi::c_void, phresult: *mut windows_core::HRESULT, pbstarted: *mut super::super::super::Foundation::BOOL, pbstopped: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferRecordControl", "GetRecordingStatus", 5usize, |this| ((**(this as *const *const IStreamBufferRecordControl_Vtbl)).GetRecordingStatus)(this, phresult, pbstarted, pbstopped), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Start, Stop, GetRecordingStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamBufferRecordControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStreamBufferRecordingAttribute_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAttribute(this: *mut core::ffi::c_void, ulreserved: u32, pszattributename: windows_core::PCWSTR, streambufferattributetype: STREAMBUFFER_ATTR_DATATYPE, pbattribute: *const u8, cbattributelength: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferRecordingAttribute", "SetAttribute", 3usize, |this| ((**(this as *const *const IStreamBufferRecordingAttribute_Vtbl)).SetAttribute)(this, ulreserved, pszattributename, streambufferattributetype, pbattribute, cbattributelength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributeCount(this: *mut core::ffi::c_void, ulreserved: u32, pcattributes: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferRecordingAttribute", "GetAttributeCount", 4usize, |this| ((**(this as *const *const IStreamBufferRecordingAttribute_Vtbl)).GetAttributeCount)(this, ulreserved, pcattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributeByName(this: *mut core::ffi::c_void, pszattributename: windows_core::PCWSTR, pulreserved: *const u32, pstreambufferattributetype: *mut STREAMBUFFER_ATTR_DATATYPE, pbattribute: *mut u8, pcblength: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferRecordingAttribute", "GetAttributeByName", 5usize, |this| ((**(this as *const *const IStreamBufferRecordingAttribute_Vtbl)).GetAttributeByName)(this, pszattributename, pulreserved, pstreambufferattributetype, pbattribute, pcblength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributeByIndex(this: *mut core::ffi::c_void, windex: u16, pulreserved: *const u32, pszattributename: windows_core::PWSTR, pcchnamelength: *mut u16, pstreambufferattributetype: *mut STREAMBUFFER_ATTR_DATATYPE, pbattribute: *mut u8, pcblength: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferRecordingAttribute", "GetAttributeByIndex", 6usize, |this| ((**(this as *const *const IStreamBufferRecordingAttribute_Vtbl)).GetAttributeByIndex)(this, windex, pulreserved, pszattributename, pcchnamelength, pstreambufferattributetype, pbattribute, pcblength), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumAttributes(this: *mut core::ffi::c_void, ppienumstreambufferattrib: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferRecordingAttribute", "EnumAttributes", 7usize, |this| ((**(this as *const *const IStreamBufferRecordingAttribute_Vtbl)).EnumAttributes)(this, ppienumstreambufferattrib), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetAttribute,
+            GetAttributeCount,
+            GetAttributeByName,
+            GetAttributeByIndex,
+            EnumAttributes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamBufferRecordingAttribute as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStreamBufferSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LockProfile(this: *mut core::ffi::c_void, pszstreambufferfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferSink", "LockProfile", 3usize, |this| ((**(this as *const *const IStreamBufferSink_Vtbl)).LockProfile)(this, pszstreambufferfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRecorder(this: *mut core::ffi::c_void, pszfilename: windows_core::PCWSTR, dwrecordtype: u32, precordingiunknown: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferSink", "CreateRecorder", 4usize, |this| ((**(this as *const *const IStreamBufferSink_Vtbl)).CreateRecorder)(this, pszfilename, dwrecordtype, precordingiunknown), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsProfileLocked(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferSink", "IsProfileLocked", 5usize, |this| ((**(this as *const *const IStreamBufferSink_Vtbl)).IsProfileLocked)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LockProfile, CreateRecorder, IsProfileLocked }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamBufferSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStreamBufferSink2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UnlockProfile(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferSink2", "UnlockProfile", 6usize, |this| ((**(this as *const *const IStreamBufferSink2_Vtbl)).UnlockProfile)(this), |result| Some(*result))
+        }
+        Self { base__: <IStreamBufferSink_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UnlockProfile }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamBufferSink2 as windows_core::Interface>::IID || <IStreamBufferSink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStreamBufferSink3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAvailableFilter(this: *mut core::ffi::c_void, prtmin: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferSink3", "SetAvailableFilter", 7usize, |this| ((**(this as *const *const IStreamBufferSink3_Vtbl)).SetAvailableFilter)(this, prtmin), |result| Some(*result))
+        }
+        Self { base__: <IStreamBufferSink2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAvailableFilter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamBufferSink3 as windows_core::Interface>::IID || <IStreamBufferSink2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStreamBufferSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetStreamSink(this: *mut core::ffi::c_void, pistreambuffersink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBufferSource", "SetStreamSink", 3usize, |this| ((**(this as *const *const IStreamBufferSource_Vtbl)).SetStreamSink)(this, pistreambuffersink), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetStreamSink }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamBufferSource as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITSDT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psectionlist: *mut core::ffi::c_void, pmpegdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSDT", "Initialize", 3usize, |this| ((**(this as *const *const ITSDT_Vtbl)).Initialize)(this, psectionlist, pmpegdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionNumber(this: *mut core::ffi::c_void, pbval: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSDT", "GetVersionNumber", 4usize, |this| ((**(this as *const *const ITSDT_Vtbl)).GetVersionNumber)(this, pbval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCountOfTableDescriptors(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSDT", "GetCountOfTableDescriptors", 5usize, |this| ((**(this as *const *const ITSDT_Vtbl)).GetCountOfTableDescriptors)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableDescriptorByIndex(this: *mut core::ffi::c_void, dwindex: u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSDT", "GetTableDescriptorByIndex", 6usize, |this| ((**(this as *const *const ITSDT_Vtbl)).GetTableDescriptorByIndex)(this, dwindex, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableDescriptorByTag(this: *mut core::ffi::c_void, btag: u8, pdwcookie: *mut u32, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSDT", "GetTableDescriptorByTag", 7usize, |this| ((**(this as *const *const ITSDT_Vtbl)).GetTableDescriptorByTag)(this, btag, pdwcookie, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterForNextTable(this: *mut core::ffi::c_void, hnexttableavailable: super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSDT", "RegisterForNextTable", 8usize, |this| ((**(this as *const *const ITSDT_Vtbl)).RegisterForNextTable)(this, hnexttableavailable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextTable(this: *mut core::ffi::c_void, pptsdt: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSDT", "GetNextTable", 9usize, |this| ((**(this as *const *const ITSDT_Vtbl)).GetNextTable)(this, pptsdt), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterForWhenCurrent(this: *mut core::ffi::c_void, hnexttableiscurrent: super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSDT", "RegisterForWhenCurrent", 10usize, |this| ((**(this as *const *const ITSDT_Vtbl)).RegisterForWhenCurrent)(this, hnexttableiscurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertNextToCurrent(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSDT", "ConvertNextToCurrent", 11usize, |this| ((**(this as *const *const ITSDT_Vtbl)).ConvertNextToCurrent)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetVersionNumber,
+            GetCountOfTableDescriptors,
+            GetTableDescriptorByIndex,
+            GetTableDescriptorByTag,
+            RegisterForNextTable,
+            GetNextTable,
+            RegisterForWhenCurrent,
+            ConvertNextToCurrent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITSDT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITuneRequest_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn TuningSpace(this: *mut core::ffi::c_void, tuningspace: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuneRequest", "TuningSpace", 7usize, |this| ((**(this as *const *const ITuneRequest_Vtbl)).TuningSpace)(this, tuningspace), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Components(this: *mut core::ffi::c_void, components: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuneRequest", "Components", 8usize, |this| ((**(this as *const *const ITuneRequest_Vtbl)).Components)(this, components), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, newtunerequest: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuneRequest", "Clone", 9usize, |this| ((**(this as *const *const ITuneRequest_Vtbl)).Clone)(this, newtunerequest), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Locator(this: *mut core::ffi::c_void, locator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuneRequest", "Locator", 10usize, |this| ((**(this as *const *const ITuneRequest_Vtbl)).Locator)(this, locator), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetLocator(this: *mut core::ffi::c_void, locator: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuneRequest", "SetLocator", 11usize, |this| ((**(this as *const *const ITuneRequest_Vtbl)).SetLocator)(this, locator), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            TuningSpace,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            TuningSpace: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Components,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Components: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Clone,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Clone: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Locator,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Locator: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetLocator,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetLocator: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITuneRequest as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITuneRequestInfo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetLocatorData(this: *mut core::ffi::c_void, request: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuneRequestInfo", "GetLocatorData", 3usize, |this| ((**(this as *const *const ITuneRequestInfo_Vtbl)).GetLocatorData)(this, request), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetComponentData(this: *mut core::ffi::c_void, currentrequest: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuneRequestInfo", "GetComponentData", 4usize, |this| ((**(this as *const *const ITuneRequestInfo_Vtbl)).GetComponentData)(this, currentrequest), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateComponentList(this: *mut core::ffi::c_void, currentrequest: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuneRequestInfo", "CreateComponentList", 5usize, |this| ((**(this as *const *const ITuneRequestInfo_Vtbl)).CreateComponentList)(this, currentrequest), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetNextProgram(this: *mut core::ffi::c_void, currentrequest: *mut core::ffi::c_void, tunerequest: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuneRequestInfo", "GetNextProgram", 6usize, |this| ((**(this as *const *const ITuneRequestInfo_Vtbl)).GetNextProgram)(this, currentrequest, tunerequest), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetPreviousProgram(this: *mut core::ffi::c_void, currentrequest: *mut core::ffi::c_void, tunerequest: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuneRequestInfo", "GetPreviousProgram", 7usize, |this| ((**(this as *const *const ITuneRequestInfo_Vtbl)).GetPreviousProgram)(this, currentrequest, tunerequest), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetNextLocator(this: *mut core::ffi::c_void, currentrequest: *mut core::ffi::c_void, tunerequest: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuneRequestInfo", "GetNextLocator", 8usize, |this| ((**(this as *const *const ITuneRequestInfo_Vtbl)).GetNextLocator)(this, currentrequest, tunerequest), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetPreviousLocator(this: *mut core::ffi::c_void, currentrequest: *mut core::ffi::c_void, tunerequest: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuneRequestInfo", "GetPreviousLocator", 9usize, |this| ((**(this as *const *const ITuneRequestInfo_Vtbl)).GetPreviousLocator)(this, currentrequest, tunerequest), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetLocatorData,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetLocatorData: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetComponentData,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetComponentData: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateComponentList,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateComponentList: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetNextProgram,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetNextProgram: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetPreviousProgram,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetPreviousProgram: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetNextLocator,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetNextLocator: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetPreviousLocator,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetPreviousLocator: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITuneRequestInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITuneRequestInfoEx_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateComponentListEx(this: *mut core::ffi::c_void, currentrequest: *mut core::ffi::c_void, ppcurpmt: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuneRequestInfoEx", "CreateComponentListEx", 10usize, |this| ((**(this as *const *const ITuneRequestInfoEx_Vtbl)).CreateComponentListEx)(this, currentrequest, ppcurpmt), |result| Some(*result))
+        }
+        Self {
+            base__: <ITuneRequestInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateComponentListEx,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateComponentListEx: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITuneRequestInfoEx as windows_core::Interface>::IID || <ITuneRequestInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITuner_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn TuningSpace(this: *mut core::ffi::c_void, tuningspace: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuner", "TuningSpace", 3usize, |this| ((**(this as *const *const ITuner_Vtbl)).TuningSpace)(this, tuningspace), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetTuningSpace(this: *mut core::ffi::c_void, tuningspace: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuner", "SetTuningSpace", 4usize, |this| ((**(this as *const *const ITuner_Vtbl)).SetTuningSpace)(this, tuningspace), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumTuningSpaces(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuner", "EnumTuningSpaces", 5usize, |this| ((**(this as *const *const ITuner_Vtbl)).EnumTuningSpaces)(this, ppenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn TuneRequest(this: *mut core::ffi::c_void, tunerequest: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuner", "TuneRequest", 6usize, |this| ((**(this as *const *const ITuner_Vtbl)).TuneRequest)(this, tunerequest), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetTuneRequest(this: *mut core::ffi::c_void, tunerequest: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuner", "SetTuneRequest", 7usize, |this| ((**(this as *const *const ITuner_Vtbl)).SetTuneRequest)(this, tunerequest), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Validate(this: *mut core::ffi::c_void, tunerequest: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuner", "Validate", 8usize, |this| ((**(this as *const *const ITuner_Vtbl)).Validate)(this, tunerequest), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PreferredComponentTypes(this: *mut core::ffi::c_void, componenttypes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuner", "PreferredComponentTypes", 9usize, |this| ((**(this as *const *const ITuner_Vtbl)).PreferredComponentTypes)(this, componenttypes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetPreferredComponentTypes(this: *mut core::ffi::c_void, componenttypes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuner", "SetPreferredComponentTypes", 10usize, |this| ((**(this as *const *const ITuner_Vtbl)).SetPreferredComponentTypes)(this, componenttypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignalStrength(this: *mut core::ffi::c_void, strength: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuner", "SignalStrength", 11usize, |this| ((**(this as *const *const ITuner_Vtbl)).SignalStrength)(this, strength), |result| Some(*result))
+        }
+        unsafe extern "system" fn TriggerSignalEvents(this: *mut core::ffi::c_void, interval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuner", "TriggerSignalEvents", 12usize, |this| ((**(this as *const *const ITuner_Vtbl)).TriggerSignalEvents)(this, interval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            TuningSpace,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            TuningSpace: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetTuningSpace,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetTuningSpace: 0,
+            EnumTuningSpaces,
+            #[cfg(feature = "Win32_System_Com")]
+            TuneRequest,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            TuneRequest: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetTuneRequest,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetTuneRequest: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Validate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Validate: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PreferredComponentTypes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PreferredComponentTypes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetPreferredComponentTypes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetPreferredComponentTypes: 0,
+            SignalStrength,
+            TriggerSignalEvents,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITuner as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITunerCap_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_SupportedNetworkTypes(this: *mut core::ffi::c_void, ulcnetworktypesmax: u32, pulcnetworktypes: *mut u32, pguidnetworktypes: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITunerCap", "get_SupportedNetworkTypes", 3usize, |this| ((**(this as *const *const ITunerCap_Vtbl)).get_SupportedNetworkTypes)(this, ulcnetworktypesmax, pulcnetworktypes, pguidnetworktypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_SupportedVideoFormats(this: *mut core::ffi::c_void, pulamtunermodetype: *mut u32, pulanalogvideostandard: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITunerCap", "get_SupportedVideoFormats", 4usize, |this| ((**(this as *const *const ITunerCap_Vtbl)).get_SupportedVideoFormats)(this, pulamtunermodetype, pulanalogvideostandard), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_AuxInputCount(this: *mut core::ffi::c_void, pulcompositecount: *mut u32, pulsvideocount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITunerCap", "get_AuxInputCount", 5usize, |this| ((**(this as *const *const ITunerCap_Vtbl)).get_AuxInputCount)(this, pulcompositecount, pulsvideocount), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            get_SupportedNetworkTypes,
+            get_SupportedVideoFormats,
+            get_AuxInputCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITunerCap as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITunerCapEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Has608_708Caption(this: *mut core::ffi::c_void, pbhascaption: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITunerCapEx", "Has608_708Caption", 3usize, |this| ((**(this as *const *const ITunerCapEx_Vtbl)).Has608_708Caption)(this, pbhascaption), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Has608_708Caption }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITunerCapEx as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITuningSpace_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UniqueName(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpace", "UniqueName", 7usize, |this| ((**(this as *const *const ITuningSpace_Vtbl)).UniqueName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUniqueName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpace", "SetUniqueName", 8usize, |this| ((**(this as *const *const ITuningSpace_Vtbl)).SetUniqueName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn FriendlyName(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpace", "FriendlyName", 9usize, |this| ((**(this as *const *const ITuningSpace_Vtbl)).FriendlyName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFriendlyName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpace", "SetFriendlyName", 10usize, |this| ((**(this as *const *const ITuningSpace_Vtbl)).SetFriendlyName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn CLSID(this: *mut core::ffi::c_void, spaceclsid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpace", "CLSID", 11usize, |this| ((**(this as *const *const ITuningSpace_Vtbl)).CLSID)(this, spaceclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkType(this: *mut core::ffi::c_void, networktypeguid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpace", "NetworkType", 12usize, |this| ((**(this as *const *const ITuningSpace_Vtbl)).NetworkType)(this, networktypeguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNetworkType(this: *mut core::ffi::c_void, networktypeguid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpace", "SetNetworkType", 13usize, |this| ((**(this as *const *const ITuningSpace_Vtbl)).SetNetworkType)(this, networktypeguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NetworkType(this: *mut core::ffi::c_void, networktypeguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpace", "_NetworkType", 14usize, |this| ((**(this as *const *const ITuningSpace_Vtbl))._NetworkType)(this, networktypeguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Set_NetworkType(this: *mut core::ffi::c_void, networktypeguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpace", "Set_NetworkType", 15usize, |this| ((**(this as *const *const ITuningSpace_Vtbl)).Set_NetworkType)(this, networktypeguid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateTuneRequest(this: *mut core::ffi::c_void, tunerequest: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpace", "CreateTuneRequest", 16usize, |this| ((**(this as *const *const ITuningSpace_Vtbl)).CreateTuneRequest)(this, tunerequest), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumCategoryGUIDs(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpace", "EnumCategoryGUIDs", 17usize, |this| ((**(this as *const *const ITuningSpace_Vtbl)).EnumCategoryGUIDs)(this, ppenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumDeviceMonikers(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpace", "EnumDeviceMonikers", 18usize, |this| ((**(this as *const *const ITuningSpace_Vtbl)).EnumDeviceMonikers)(this, ppenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DefaultPreferredComponentTypes(this: *mut core::ffi::c_void, componenttypes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpace", "DefaultPreferredComponentTypes", 19usize, |this| ((**(this as *const *const ITuningSpace_Vtbl)).DefaultPreferredComponentTypes)(this, componenttypes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetDefaultPreferredComponentTypes(this: *mut core::ffi::c_void, newcomponenttypes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpace", "SetDefaultPreferredComponentTypes", 20usize, |this| ((**(this as *const *const ITuningSpace_Vtbl)).SetDefaultPreferredComponentTypes)(this, newcomponenttypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn FrequencyMapping(this: *mut core::ffi::c_void, pmapping: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpace", "FrequencyMapping", 21usize, |this| ((**(this as *const *const ITuningSpace_Vtbl)).FrequencyMapping)(this, pmapping), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFrequencyMapping(this: *mut core::ffi::c_void, mapping: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpace", "SetFrequencyMapping", 22usize, |this| ((**(this as *const *const ITuningSpace_Vtbl)).SetFrequencyMapping)(this, mapping), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DefaultLocator(this: *mut core::ffi::c_void, locatorval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpace", "DefaultLocator", 23usize, |this| ((**(this as *const *const ITuningSpace_Vtbl)).DefaultLocator)(this, locatorval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetDefaultLocator(this: *mut core::ffi::c_void, locatorval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpace", "SetDefaultLocator", 24usize, |this| ((**(this as *const *const ITuningSpace_Vtbl)).SetDefaultLocator)(this, locatorval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, newts: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpace", "Clone", 25usize, |this| ((**(this as *const *const ITuningSpace_Vtbl)).Clone)(this, newts), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            UniqueName,
+            SetUniqueName,
+            FriendlyName,
+            SetFriendlyName,
+            CLSID,
+            NetworkType,
+            SetNetworkType,
+            _NetworkType,
+            Set_NetworkType,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateTuneRequest,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateTuneRequest: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumCategoryGUIDs,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumCategoryGUIDs: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumDeviceMonikers,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumDeviceMonikers: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            DefaultPreferredComponentTypes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DefaultPreferredComponentTypes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetDefaultPreferredComponentTypes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetDefaultPreferredComponentTypes: 0,
+            FrequencyMapping,
+            SetFrequencyMapping,
+            #[cfg(feature = "Win32_System_Com")]
+            DefaultLocator,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DefaultLocator: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetDefaultLocator,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetDefaultLocator: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Clone,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Clone: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITuningSpace as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for ITuningSpaceContainer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpaceContainer", "Count", 7usize, |this| ((**(this as *const *const ITuningSpaceContainer_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, newenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpaceContainer", "_NewEnum", 8usize, |this| ((**(this as *const *const ITuningSpaceContainer_Vtbl))._NewEnum)(this, newenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>, tuningspace: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpaceContainer", "get_Item", 9usize, |this| ((**(this as *const *const ITuningSpaceContainer_Vtbl)).get_Item)(this, varindex, tuningspace), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn put_Item(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>, tuningspace: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpaceContainer", "put_Item", 10usize, |this| ((**(this as *const *const ITuningSpaceContainer_Vtbl)).put_Item)(this, varindex, tuningspace), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn TuningSpacesForCLSID(this: *mut core::ffi::c_void, spaceclsid: core::mem::MaybeUninit<windows_core::BSTR>, newcoll: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpaceContainer", "TuningSpacesForCLSID", 11usize, |this| ((**(this as *const *const ITuningSpaceContainer_Vtbl)).TuningSpacesForCLSID)(this, spaceclsid, newcoll), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn _TuningSpacesForCLSID2(this: *mut core::ffi::c_void, spaceclsid: *const windows_core::GUID, newcoll: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpaceContainer", "_TuningSpacesForCLSID2", 12usize, |this| ((**(this as *const *const ITuningSpaceContainer_Vtbl))._TuningSpacesForCLSID2)(this, spaceclsid, newcoll), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn TuningSpacesForName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, newcoll: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpaceContainer", "TuningSpacesForName", 13usize, |this| ((**(this as *const *const ITuningSpaceContainer_Vtbl)).TuningSpacesForName)(this, name, newcoll), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn FindID(this: *mut core::ffi::c_void, tuningspace: *mut core::ffi::c_void, id: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpaceContainer", "FindID", 14usize, |this| ((**(this as *const *const ITuningSpaceContainer_Vtbl)).FindID)(this, tuningspace, id), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, tuningspace: *mut core::ffi::c_void, newindex: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpaceContainer", "Add", 15usize, |this| ((**(this as *const *const ITuningSpaceContainer_Vtbl)).Add)(this, tuningspace, newindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumTuningSpaces(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpaceContainer", "EnumTuningSpaces", 16usize, |this| ((**(this as *const *const ITuningSpaceContainer_Vtbl)).EnumTuningSpaces)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpaceContainer", "Remove", 17usize, |this| ((**(this as *const *const ITuningSpaceContainer_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxCount(this: *mut core::ffi::c_void, maxcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpaceContainer", "MaxCount", 18usize, |this| ((**(this as *const *const ITuningSpaceContainer_Vtbl)).MaxCount)(this, maxcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxCount(this: *mut core::ffi::c_void, maxcount: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpaceContainer", "SetMaxCount", 19usize, |this| ((**(this as *const *const ITuningSpaceContainer_Vtbl)).SetMaxCount)(this, maxcount), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Ole")]
+            _NewEnum,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            _NewEnum: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            put_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            put_Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            TuningSpacesForCLSID,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            TuningSpacesForCLSID: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            _TuningSpacesForCLSID2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            _TuningSpacesForCLSID2: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            TuningSpacesForName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            TuningSpacesForName: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            FindID,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            FindID: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            EnumTuningSpaces,
+            Remove,
+            MaxCount,
+            SetMaxCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITuningSpaceContainer as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for ITuningSpaces_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpaces", "Count", 7usize, |this| ((**(this as *const *const ITuningSpaces_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, newenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpaces", "_NewEnum", 8usize, |this| ((**(this as *const *const ITuningSpaces_Vtbl))._NewEnum)(this, newenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>, tuningspace: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpaces", "get_Item", 9usize, |this| ((**(this as *const *const ITuningSpaces_Vtbl)).get_Item)(this, varindex, tuningspace), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumTuningSpaces(this: *mut core::ffi::c_void, newenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITuningSpaces", "EnumTuningSpaces", 10usize, |this| ((**(this as *const *const ITuningSpaces_Vtbl)).EnumTuningSpaces)(this, newenum), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Ole")]
+            _NewEnum,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            _NewEnum: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            EnumTuningSpaces,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITuningSpaces as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXDSCodec_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn XDSToRatObjOK(this: *mut core::ffi::c_void, phrcocreateretval: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXDSCodec", "XDSToRatObjOK", 3usize, |this| ((**(this as *const *const IXDSCodec_Vtbl)).XDSToRatObjOK)(this, phrcocreateretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCCSubstreamService(this: *mut core::ffi::c_void, substreammask: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXDSCodec", "SetCCSubstreamService", 4usize, |this| ((**(this as *const *const IXDSCodec_Vtbl)).SetCCSubstreamService)(this, substreammask), |result| Some(*result))
+        }
+        unsafe extern "system" fn CCSubstreamService(this: *mut core::ffi::c_void, psubstreammask: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXDSCodec", "CCSubstreamService", 5usize, |this| ((**(this as *const *const IXDSCodec_Vtbl)).CCSubstreamService)(this, psubstreammask), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContentAdvisoryRating(this: *mut core::ffi::c_void, prat: *mut i32, ppktseqid: *mut i32, pcallseqid: *mut i32, ptimestart: *mut i64, ptimeend: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXDSCodec", "GetContentAdvisoryRating", 6usize, |this| ((**(this as *const *const IXDSCodec_Vtbl)).GetContentAdvisoryRating)(this, prat, ppktseqid, pcallseqid, ptimestart, ptimeend), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetXDSPacket(this: *mut core::ffi::c_void, pxdsclasspkt: *mut i32, pxdstypepkt: *mut i32, pbstrxdspkt: *mut core::mem::MaybeUninit<windows_core::BSTR>, ppktseqid: *mut i32, pcallseqid: *mut i32, ptimestart: *mut i64, ptimeend: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXDSCodec", "GetXDSPacket", 7usize, |this| ((**(this as *const *const IXDSCodec_Vtbl)).GetXDSPacket)(this, pxdsclasspkt, pxdstypepkt, pbstrxdspkt, ppktseqid, pcallseqid, ptimestart, ptimeend), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrLicenseExpDate(this: *mut core::ffi::c_void, prottype: *const ProtType, lpdatetime: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXDSCodec", "GetCurrLicenseExpDate", 8usize, |this| ((**(this as *const *const IXDSCodec_Vtbl)).GetCurrLicenseExpDate)(this, prottype, lpdatetime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastErrorCode(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXDSCodec", "GetLastErrorCode", 9usize, |this| ((**(this as *const *const IXDSCodec_Vtbl)).GetLastErrorCode)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            XDSToRatObjOK,
+            SetCCSubstreamService,
+            CCSubstreamService,
+            GetContentAdvisoryRating,
+            GetXDSPacket,
+            GetCurrLicenseExpDate,
+            GetLastErrorCode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXDSCodec as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXDSCodecConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSecureChannelObject(this: *mut core::ffi::c_void, ppunkdrmsecurechannel: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXDSCodecConfig", "GetSecureChannelObject", 3usize, |this| ((**(this as *const *const IXDSCodecConfig_Vtbl)).GetSecureChannelObject)(this, ppunkdrmsecurechannel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPauseBufferTime(this: *mut core::ffi::c_void, dwpausebuffertime: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXDSCodecConfig", "SetPauseBufferTime", 4usize, |this| ((**(this as *const *const IXDSCodecConfig_Vtbl)).SetPauseBufferTime)(this, dwpausebuffertime), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSecureChannelObject, SetPauseBufferTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXDSCodecConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXDSCodecEvents_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXDSCodecEvents as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXDSToRat_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Init(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXDSToRat", "Init", 7usize, |this| ((**(this as *const *const IXDSToRat_Vtbl)).Init)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParseXDSBytePair(this: *mut core::ffi::c_void, byte1: u8, byte2: u8, pensystem: *mut EnTvRat_System, penlevel: *mut EnTvRat_GenericLevel, plbfenattributes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXDSToRat", "ParseXDSBytePair", 8usize, |this| ((**(this as *const *const IXDSToRat_Vtbl)).ParseXDSBytePair)(this, byte1, byte2, pensystem, penlevel, plbfenattributes), |result| Some(*result))
+        }
+        Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Init, ParseXDSBytePair }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXDSToRat as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for _IMSVidCtlEvents_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<_IMSVidCtlEvents as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
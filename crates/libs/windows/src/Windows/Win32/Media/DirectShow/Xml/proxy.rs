@@ -0,0 +1,28 @@
+#[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXMLGraphBuilder_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn BuildFromXML(this: *mut core::ffi::c_void, pgraph: *mut core::ffi::c_void, pxml: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLGraphBuilder", "BuildFromXML", 3usize, |this| ((**(this as *const *const IXMLGraphBuilder_Vtbl)).BuildFromXML)(this, pgraph, pxml), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveToXML(this: *mut core::ffi::c_void, pgraph: *mut core::ffi::c_void, pbstrxml: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLGraphBuilder", "SaveToXML", 4usize, |this| ((**(this as *const *const IXMLGraphBuilder_Vtbl)).SaveToXML)(this, pgraph, pbstrxml), |result| Some(*result))
+        }
+        unsafe extern "system" fn BuildFromXMLFile(this: *mut core::ffi::c_void, pgraph: *mut core::ffi::c_void, wszfilename: windows_core::PCWSTR, wszbaseurl: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXMLGraphBuilder", "BuildFromXMLFile", 5usize, |this| ((**(this as *const *const IXMLGraphBuilder_Vtbl)).BuildFromXMLFile)(this, pgraph, wszfilename, wszbaseurl), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com"))]
+            BuildFromXML,
+            #[cfg(not(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com")))]
+            BuildFromXML: 0,
+            SaveToXML,
+            BuildFromXMLFile,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXMLGraphBuilder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -14686,6 +14686,7 @@ pub struct ADVISE_TYPE(pub i32);
 impl windows_core::TypeKind for ADVISE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ADVISE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ADVISE_TYPE").field(&self.0).finish()
@@ -14730,6 +14731,7 @@ pub struct AMExtendedSeekingCapabilities(pub i32);
 impl windows_core::TypeKind for AMExtendedSeekingCapabilities {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AMExtendedSeekingCapabilities {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AMExtendedSeekingCapabilities").field(&self.0).finish()
@@ -14741,6 +14743,7 @@ pub struct AMMSF_MMS_INIT_FLAGS(pub i32);
 impl windows_core::TypeKind for AMMSF_MMS_INIT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AMMSF_MMS_INIT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AMMSF_MMS_INIT_FLAGS").field(&self.0).finish()
@@ -14785,6 +14788,7 @@ pub struct AMMSF_MS_FLAGS(pub i32);
 impl windows_core::TypeKind for AMMSF_MS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AMMSF_MS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AMMSF_MS_FLAGS").field(&self.0).finish()
@@ -14829,6 +14833,7 @@ pub struct AMMSF_RENDER_FLAGS(pub i32);
 impl windows_core::TypeKind for AMMSF_RENDER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AMMSF_RENDER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AMMSF_RENDER_FLAGS").field(&self.0).finish()
@@ -14873,6 +14878,7 @@ pub struct AMOVERLAYFX(pub i32);
 impl windows_core::TypeKind for AMOVERLAYFX {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AMOVERLAYFX {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AMOVERLAYFX").field(&self.0).finish()
@@ -14884,6 +14890,7 @@ pub struct AMPROPERTY_PIN(pub i32);
 impl windows_core::TypeKind for AMPROPERTY_PIN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AMPROPERTY_PIN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AMPROPERTY_PIN").field(&self.0).finish()
@@ -14895,6 +14902,7 @@ pub struct AMPlayListEventFlags(pub i32);
 impl windows_core::TypeKind for AMPlayListEventFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AMPlayListEventFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AMPlayListEventFlags").field(&self.0).finish()
@@ -14906,6 +14914,7 @@ pub struct AMPlayListFlags(pub i32);
 impl windows_core::TypeKind for AMPlayListFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AMPlayListFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AMPlayListFlags").field(&self.0).finish()
@@ -14917,6 +14926,7 @@ pub struct AMPlayListItemFlags(pub i32);
 impl windows_core::TypeKind for AMPlayListItemFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AMPlayListItemFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AMPlayListItemFlags").field(&self.0).finish()
@@ -14928,6 +14938,7 @@ pub struct AMTVAudioEventType(pub i32);
 impl windows_core::TypeKind for AMTVAudioEventType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AMTVAudioEventType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AMTVAudioEventType").field(&self.0).finish()
@@ -14939,6 +14950,7 @@ pub struct AMTunerEventType(pub i32);
 impl windows_core::TypeKind for AMTunerEventType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AMTunerEventType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AMTunerEventType").field(&self.0).finish()
@@ -14950,6 +14962,7 @@ pub struct AMTunerModeType(pub i32);
 impl windows_core::TypeKind for AMTunerModeType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AMTunerModeType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AMTunerModeType").field(&self.0).finish()
@@ -14961,6 +14974,7 @@ pub struct AMTunerSignalStrength(pub i32);
 impl windows_core::TypeKind for AMTunerSignalStrength {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AMTunerSignalStrength {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AMTunerSignalStrength").field(&self.0).finish()
@@ -14972,6 +14986,7 @@ pub struct AMTunerSubChannel(pub i32);
 impl windows_core::TypeKind for AMTunerSubChannel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AMTunerSubChannel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AMTunerSubChannel").field(&self.0).finish()
@@ -14983,6 +14998,7 @@ pub struct AMVP_MODE(pub i32);
 impl windows_core::TypeKind for AMVP_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AMVP_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AMVP_MODE").field(&self.0).finish()
@@ -14994,6 +15010,7 @@ pub struct AMVP_SELECT_FORMAT_BY(pub i32);
 impl windows_core::TypeKind for AMVP_SELECT_FORMAT_BY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AMVP_SELECT_FORMAT_BY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AMVP_SELECT_FORMAT_BY").field(&self.0).finish()
@@ -15005,6 +15022,7 @@ pub struct AM_ASPECT_RATIO_MODE(pub i32);
 impl windows_core::TypeKind for AM_ASPECT_RATIO_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_ASPECT_RATIO_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_ASPECT_RATIO_MODE").field(&self.0).finish()
@@ -15016,6 +15034,7 @@ pub struct AM_COPY_MACROVISION_LEVEL(pub i32);
 impl windows_core::TypeKind for AM_COPY_MACROVISION_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_COPY_MACROVISION_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_COPY_MACROVISION_LEVEL").field(&self.0).finish()
@@ -15027,6 +15046,7 @@ pub struct AM_DIGITAL_CP(pub i32);
 impl windows_core::TypeKind for AM_DIGITAL_CP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_DIGITAL_CP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_DIGITAL_CP").field(&self.0).finish()
@@ -15038,6 +15058,7 @@ pub struct AM_DVDCOPYSTATE(pub i32);
 impl windows_core::TypeKind for AM_DVDCOPYSTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_DVDCOPYSTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_DVDCOPYSTATE").field(&self.0).finish()
@@ -15049,6 +15070,7 @@ pub struct AM_DVD_GRAPH_FLAGS(pub i32);
 impl windows_core::TypeKind for AM_DVD_GRAPH_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_DVD_GRAPH_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_DVD_GRAPH_FLAGS").field(&self.0).finish()
@@ -15060,6 +15082,7 @@ pub struct AM_DVD_STREAM_FLAGS(pub i32);
 impl windows_core::TypeKind for AM_DVD_STREAM_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_DVD_STREAM_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_DVD_STREAM_FLAGS").field(&self.0).finish()
@@ -15071,6 +15094,7 @@ pub struct AM_FILESINK_FLAGS(pub i32);
 impl windows_core::TypeKind for AM_FILESINK_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_FILESINK_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_FILESINK_FLAGS").field(&self.0).finish()
@@ -15082,6 +15106,7 @@ pub struct AM_FILTER_FLAGS(pub i32);
 impl windows_core::TypeKind for AM_FILTER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_FILTER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_FILTER_FLAGS").field(&self.0).finish()
@@ -15093,6 +15118,7 @@ pub struct AM_GRAPH_CONFIG_RECONNECT_FLAGS(pub i32);
 impl windows_core::TypeKind for AM_GRAPH_CONFIG_RECONNECT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_GRAPH_CONFIG_RECONNECT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_GRAPH_CONFIG_RECONNECT_FLAGS").field(&self.0).finish()
@@ -15104,6 +15130,7 @@ pub struct AM_LINE21_CCLEVEL(pub i32);
 impl windows_core::TypeKind for AM_LINE21_CCLEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_LINE21_CCLEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_LINE21_CCLEVEL").field(&self.0).finish()
@@ -15115,6 +15142,7 @@ pub struct AM_LINE21_CCSERVICE(pub i32);
 impl windows_core::TypeKind for AM_LINE21_CCSERVICE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_LINE21_CCSERVICE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_LINE21_CCSERVICE").field(&self.0).finish()
@@ -15126,6 +15154,7 @@ pub struct AM_LINE21_CCSTATE(pub i32);
 impl windows_core::TypeKind for AM_LINE21_CCSTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_LINE21_CCSTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_LINE21_CCSTATE").field(&self.0).finish()
@@ -15137,6 +15166,7 @@ pub struct AM_LINE21_CCSTYLE(pub i32);
 impl windows_core::TypeKind for AM_LINE21_CCSTYLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_LINE21_CCSTYLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_LINE21_CCSTYLE").field(&self.0).finish()
@@ -15148,6 +15178,7 @@ pub struct AM_LINE21_DRAWBGMODE(pub i32);
 impl windows_core::TypeKind for AM_LINE21_DRAWBGMODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_LINE21_DRAWBGMODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_LINE21_DRAWBGMODE").field(&self.0).finish()
@@ -15159,6 +15190,7 @@ pub struct AM_MEDIAEVENT_FLAGS(pub i32);
 impl windows_core::TypeKind for AM_MEDIAEVENT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_MEDIAEVENT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_MEDIAEVENT_FLAGS").field(&self.0).finish()
@@ -15170,6 +15202,7 @@ pub struct AM_MPEG2Level(pub i32);
 impl windows_core::TypeKind for AM_MPEG2Level {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_MPEG2Level {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_MPEG2Level").field(&self.0).finish()
@@ -15181,6 +15214,7 @@ pub struct AM_MPEG2Profile(pub i32);
 impl windows_core::TypeKind for AM_MPEG2Profile {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_MPEG2Profile {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_MPEG2Profile").field(&self.0).finish()
@@ -15192,6 +15226,7 @@ pub struct AM_PROPERTY_AC3(pub i32);
 impl windows_core::TypeKind for AM_PROPERTY_AC3 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_PROPERTY_AC3 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_PROPERTY_AC3").field(&self.0).finish()
@@ -15203,6 +15238,7 @@ pub struct AM_PROPERTY_DVDCOPYPROT(pub i32);
 impl windows_core::TypeKind for AM_PROPERTY_DVDCOPYPROT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_PROPERTY_DVDCOPYPROT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_PROPERTY_DVDCOPYPROT").field(&self.0).finish()
@@ -15214,6 +15250,7 @@ pub struct AM_PROPERTY_DVDKARAOKE(pub i32);
 impl windows_core::TypeKind for AM_PROPERTY_DVDKARAOKE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_PROPERTY_DVDKARAOKE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_PROPERTY_DVDKARAOKE").field(&self.0).finish()
@@ -15225,6 +15262,7 @@ pub struct AM_PROPERTY_DVDSUBPIC(pub i32);
 impl windows_core::TypeKind for AM_PROPERTY_DVDSUBPIC {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_PROPERTY_DVDSUBPIC {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_PROPERTY_DVDSUBPIC").field(&self.0).finish()
@@ -15236,6 +15274,7 @@ pub struct AM_PROPERTY_DVD_RATE_CHANGE(pub i32);
 impl windows_core::TypeKind for AM_PROPERTY_DVD_RATE_CHANGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_PROPERTY_DVD_RATE_CHANGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_PROPERTY_DVD_RATE_CHANGE").field(&self.0).finish()
@@ -15247,6 +15286,7 @@ pub struct AM_PROPERTY_FRAMESTEP(pub i32);
 impl windows_core::TypeKind for AM_PROPERTY_FRAMESTEP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_PROPERTY_FRAMESTEP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_PROPERTY_FRAMESTEP").field(&self.0).finish()
@@ -15258,6 +15298,7 @@ pub struct AM_PROPERTY_TS_RATE_CHANGE(pub i32);
 impl windows_core::TypeKind for AM_PROPERTY_TS_RATE_CHANGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_PROPERTY_TS_RATE_CHANGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_PROPERTY_TS_RATE_CHANGE").field(&self.0).finish()
@@ -15269,6 +15310,7 @@ pub struct AM_SAMPLE_PROPERTY_FLAGS(pub i32);
 impl windows_core::TypeKind for AM_SAMPLE_PROPERTY_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_SAMPLE_PROPERTY_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_SAMPLE_PROPERTY_FLAGS").field(&self.0).finish()
@@ -15280,6 +15322,7 @@ pub struct AM_SEEKING_SEEKING_CAPABILITIES(pub i32);
 impl windows_core::TypeKind for AM_SEEKING_SEEKING_CAPABILITIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_SEEKING_SEEKING_CAPABILITIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_SEEKING_SEEKING_CAPABILITIES").field(&self.0).finish()
@@ -15291,6 +15334,7 @@ pub struct AM_SEEKING_SEEKING_FLAGS(pub i32);
 impl windows_core::TypeKind for AM_SEEKING_SEEKING_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_SEEKING_SEEKING_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_SEEKING_SEEKING_FLAGS").field(&self.0).finish()
@@ -15302,6 +15346,7 @@ pub struct AM_STREAM_INFO_FLAGS(pub i32);
 impl windows_core::TypeKind for AM_STREAM_INFO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_STREAM_INFO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_STREAM_INFO_FLAGS").field(&self.0).finish()
@@ -15313,6 +15358,7 @@ pub struct AM_WST_DRAWBGMODE(pub i32);
 impl windows_core::TypeKind for AM_WST_DRAWBGMODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_WST_DRAWBGMODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_WST_DRAWBGMODE").field(&self.0).finish()
@@ -15324,6 +15370,7 @@ pub struct AM_WST_LEVEL(pub i32);
 impl windows_core::TypeKind for AM_WST_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_WST_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_WST_LEVEL").field(&self.0).finish()
@@ -15335,6 +15382,7 @@ pub struct AM_WST_SERVICE(pub i32);
 impl windows_core::TypeKind for AM_WST_SERVICE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_WST_SERVICE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_WST_SERVICE").field(&self.0).finish()
@@ -15346,6 +15394,7 @@ pub struct AM_WST_STATE(pub i32);
 impl windows_core::TypeKind for AM_WST_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_WST_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_WST_STATE").field(&self.0).finish()
@@ -15357,6 +15406,7 @@ pub struct AM_WST_STYLE(pub i32);
 impl windows_core::TypeKind for AM_WST_STYLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AM_WST_STYLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AM_WST_STYLE").field(&self.0).finish()
@@ -15368,6 +15418,7 @@ pub struct ATSCComponentTypeFlags(pub i32);
 impl windows_core::TypeKind for ATSCComponentTypeFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ATSCComponentTypeFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ATSCComponentTypeFlags").field(&self.0).finish()
@@ -15379,6 +15430,7 @@ pub struct AnalogVideoStandard(pub i32);
 impl windows_core::TypeKind for AnalogVideoStandard {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AnalogVideoStandard {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AnalogVideoStandard").field(&self.0).finish()
@@ -15390,6 +15442,7 @@ pub struct ApplicationTypeType(pub i32);
 impl windows_core::TypeKind for ApplicationTypeType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ApplicationTypeType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ApplicationTypeType").field(&self.0).finish()
@@ -15401,6 +15454,7 @@ pub struct BDA_CHANGE_STATE(pub i32);
 impl windows_core::TypeKind for BDA_CHANGE_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BDA_CHANGE_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BDA_CHANGE_STATE").field(&self.0).finish()
@@ -15412,6 +15466,7 @@ pub struct BDA_CONDITIONALACCESS_MMICLOSEREASON(pub i32);
 impl windows_core::TypeKind for BDA_CONDITIONALACCESS_MMICLOSEREASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BDA_CONDITIONALACCESS_MMICLOSEREASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BDA_CONDITIONALACCESS_MMICLOSEREASON").field(&self.0).finish()
@@ -15423,6 +15478,7 @@ pub struct BDA_CONDITIONALACCESS_REQUESTTYPE(pub i32);
 impl windows_core::TypeKind for BDA_CONDITIONALACCESS_REQUESTTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BDA_CONDITIONALACCESS_REQUESTTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BDA_CONDITIONALACCESS_REQUESTTYPE").field(&self.0).finish()
@@ -15434,6 +15490,7 @@ pub struct BDA_CONDITIONALACCESS_SESSION_RESULT(pub i32);
 impl windows_core::TypeKind for BDA_CONDITIONALACCESS_SESSION_RESULT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BDA_CONDITIONALACCESS_SESSION_RESULT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BDA_CONDITIONALACCESS_SESSION_RESULT").field(&self.0).finish()
@@ -15445,6 +15502,7 @@ pub struct BDA_Channel(pub i32);
 impl windows_core::TypeKind for BDA_Channel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BDA_Channel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BDA_Channel").field(&self.0).finish()
@@ -15456,6 +15514,7 @@ pub struct BDA_Channel_Bandwidth(pub i32);
 impl windows_core::TypeKind for BDA_Channel_Bandwidth {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BDA_Channel_Bandwidth {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BDA_Channel_Bandwidth").field(&self.0).finish()
@@ -15467,6 +15526,7 @@ pub struct BDA_Comp_Flags(pub i32);
 impl windows_core::TypeKind for BDA_Comp_Flags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BDA_Comp_Flags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BDA_Comp_Flags").field(&self.0).finish()
@@ -15478,6 +15538,7 @@ pub struct BDA_DISCOVERY_STATE(pub i32);
 impl windows_core::TypeKind for BDA_DISCOVERY_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BDA_DISCOVERY_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BDA_DISCOVERY_STATE").field(&self.0).finish()
@@ -15489,6 +15550,7 @@ pub struct BDA_DrmPairingError(pub i32);
 impl windows_core::TypeKind for BDA_DrmPairingError {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BDA_DrmPairingError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BDA_DrmPairingError").field(&self.0).finish()
@@ -15500,6 +15562,7 @@ pub struct BDA_EVENT_ID(pub i32);
 impl windows_core::TypeKind for BDA_EVENT_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BDA_EVENT_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BDA_EVENT_ID").field(&self.0).finish()
@@ -15511,6 +15574,7 @@ pub struct BDA_Frequency(pub i32);
 impl windows_core::TypeKind for BDA_Frequency {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BDA_Frequency {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BDA_Frequency").field(&self.0).finish()
@@ -15522,6 +15586,7 @@ pub struct BDA_Frequency_Multiplier(pub i32);
 impl windows_core::TypeKind for BDA_Frequency_Multiplier {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BDA_Frequency_Multiplier {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BDA_Frequency_Multiplier").field(&self.0).finish()
@@ -15533,6 +15598,7 @@ pub struct BDA_MULTICAST_MODE(pub i32);
 impl windows_core::TypeKind for BDA_MULTICAST_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BDA_MULTICAST_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BDA_MULTICAST_MODE").field(&self.0).finish()
@@ -15544,6 +15610,7 @@ pub struct BDA_Range(pub i32);
 impl windows_core::TypeKind for BDA_Range {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BDA_Range {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BDA_Range").field(&self.0).finish()
@@ -15555,6 +15622,7 @@ pub struct BDA_SIGNAL_STATE(pub i32);
 impl windows_core::TypeKind for BDA_SIGNAL_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BDA_SIGNAL_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BDA_SIGNAL_STATE").field(&self.0).finish()
@@ -15566,6 +15634,7 @@ pub struct BinaryConvolutionCodeRate(pub i32);
 impl windows_core::TypeKind for BinaryConvolutionCodeRate {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BinaryConvolutionCodeRate {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BinaryConvolutionCodeRate").field(&self.0).finish()
@@ -15577,6 +15646,7 @@ pub struct COLORKEY_TYPE(pub i32);
 impl windows_core::TypeKind for COLORKEY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COLORKEY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COLORKEY_TYPE").field(&self.0).finish()
@@ -15588,6 +15658,7 @@ pub struct COMPLETION_STATUS_FLAGS(pub i32);
 impl windows_core::TypeKind for COMPLETION_STATUS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMPLETION_STATUS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMPLETION_STATUS_FLAGS").field(&self.0).finish()
@@ -15599,6 +15670,7 @@ pub struct COPP_ACP_Protection_Level(pub i32);
 impl windows_core::TypeKind for COPP_ACP_Protection_Level {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COPP_ACP_Protection_Level {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COPP_ACP_Protection_Level").field(&self.0).finish()
@@ -15610,6 +15682,7 @@ pub struct COPP_BusType(pub i32);
 impl windows_core::TypeKind for COPP_BusType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COPP_BusType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COPP_BusType").field(&self.0).finish()
@@ -15621,6 +15694,7 @@ pub struct COPP_CGMSA_Protection_Level(pub i32);
 impl windows_core::TypeKind for COPP_CGMSA_Protection_Level {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COPP_CGMSA_Protection_Level {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COPP_CGMSA_Protection_Level").field(&self.0).finish()
@@ -15632,6 +15706,7 @@ pub struct COPP_ConnectorType(pub i32);
 impl windows_core::TypeKind for COPP_ConnectorType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COPP_ConnectorType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COPP_ConnectorType").field(&self.0).finish()
@@ -15643,6 +15718,7 @@ pub struct COPP_HDCP_Protection_Level(pub i32);
 impl windows_core::TypeKind for COPP_HDCP_Protection_Level {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COPP_HDCP_Protection_Level {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COPP_HDCP_Protection_Level").field(&self.0).finish()
@@ -15654,6 +15730,7 @@ pub struct COPP_ImageAspectRatio_EN300294(pub i32);
 impl windows_core::TypeKind for COPP_ImageAspectRatio_EN300294 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COPP_ImageAspectRatio_EN300294 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COPP_ImageAspectRatio_EN300294").field(&self.0).finish()
@@ -15665,6 +15742,7 @@ pub struct COPP_StatusFlags(pub i32);
 impl windows_core::TypeKind for COPP_StatusFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COPP_StatusFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COPP_StatusFlags").field(&self.0).finish()
@@ -15676,6 +15754,7 @@ pub struct COPP_StatusHDCPFlags(pub i32);
 impl windows_core::TypeKind for COPP_StatusHDCPFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COPP_StatusHDCPFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COPP_StatusHDCPFlags").field(&self.0).finish()
@@ -15687,6 +15766,7 @@ pub struct COPP_TVProtectionStandard(pub i32);
 impl windows_core::TypeKind for COPP_TVProtectionStandard {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COPP_TVProtectionStandard {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COPP_TVProtectionStandard").field(&self.0).finish()
@@ -15698,6 +15778,7 @@ pub struct CameraControlFlags(pub i32);
 impl windows_core::TypeKind for CameraControlFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CameraControlFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CameraControlFlags").field(&self.0).finish()
@@ -15709,6 +15790,7 @@ pub struct CameraControlProperty(pub i32);
 impl windows_core::TypeKind for CameraControlProperty {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CameraControlProperty {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CameraControlProperty").field(&self.0).finish()
@@ -15720,6 +15802,7 @@ pub struct ComponentCategory(pub i32);
 impl windows_core::TypeKind for ComponentCategory {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ComponentCategory {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ComponentCategory").field(&self.0).finish()
@@ -15731,6 +15814,7 @@ pub struct ComponentStatus(pub i32);
 impl windows_core::TypeKind for ComponentStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ComponentStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ComponentStatus").field(&self.0).finish()
@@ -15742,6 +15826,7 @@ pub struct CompressionCaps(pub i32);
 impl windows_core::TypeKind for CompressionCaps {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CompressionCaps {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CompressionCaps").field(&self.0).finish()
@@ -15753,6 +15838,7 @@ pub struct DDSFF_FLAGS(pub i32);
 impl windows_core::TypeKind for DDSFF_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DDSFF_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DDSFF_FLAGS").field(&self.0).finish()
@@ -15797,6 +15883,7 @@ pub struct DECIMATION_USAGE(pub i32);
 impl windows_core::TypeKind for DECIMATION_USAGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DECIMATION_USAGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DECIMATION_USAGE").field(&self.0).finish()
@@ -15808,6 +15895,7 @@ pub struct DVBSystemType(pub i32);
 impl windows_core::TypeKind for DVBSystemType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVBSystemType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVBSystemType").field(&self.0).finish()
@@ -15819,6 +15907,7 @@ pub struct DVD_AUDIO_APPMODE(pub i32);
 impl windows_core::TypeKind for DVD_AUDIO_APPMODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVD_AUDIO_APPMODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVD_AUDIO_APPMODE").field(&self.0).finish()
@@ -15830,6 +15919,7 @@ pub struct DVD_AUDIO_FORMAT(pub i32);
 impl windows_core::TypeKind for DVD_AUDIO_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVD_AUDIO_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVD_AUDIO_FORMAT").field(&self.0).finish()
@@ -15841,6 +15931,7 @@ pub struct DVD_AUDIO_LANG_EXT(pub i32);
 impl windows_core::TypeKind for DVD_AUDIO_LANG_EXT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVD_AUDIO_LANG_EXT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVD_AUDIO_LANG_EXT").field(&self.0).finish()
@@ -15852,6 +15943,7 @@ pub struct DVD_CMD_FLAGS(pub i32);
 impl windows_core::TypeKind for DVD_CMD_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVD_CMD_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVD_CMD_FLAGS").field(&self.0).finish()
@@ -15863,6 +15955,7 @@ pub struct DVD_DISC_SIDE(pub i32);
 impl windows_core::TypeKind for DVD_DISC_SIDE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVD_DISC_SIDE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVD_DISC_SIDE").field(&self.0).finish()
@@ -15874,6 +15967,7 @@ pub struct DVD_DOMAIN(pub i32);
 impl windows_core::TypeKind for DVD_DOMAIN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVD_DOMAIN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVD_DOMAIN").field(&self.0).finish()
@@ -15885,6 +15979,7 @@ pub struct DVD_ERROR(pub i32);
 impl windows_core::TypeKind for DVD_ERROR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVD_ERROR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVD_ERROR").field(&self.0).finish()
@@ -15896,6 +15991,7 @@ pub struct DVD_FRAMERATE(pub i32);
 impl windows_core::TypeKind for DVD_FRAMERATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVD_FRAMERATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVD_FRAMERATE").field(&self.0).finish()
@@ -15907,6 +16003,7 @@ pub struct DVD_KARAOKE_ASSIGNMENT(pub i32);
 impl windows_core::TypeKind for DVD_KARAOKE_ASSIGNMENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVD_KARAOKE_ASSIGNMENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVD_KARAOKE_ASSIGNMENT").field(&self.0).finish()
@@ -15918,6 +16015,7 @@ pub struct DVD_KARAOKE_CONTENTS(pub i32);
 impl windows_core::TypeKind for DVD_KARAOKE_CONTENTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVD_KARAOKE_CONTENTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVD_KARAOKE_CONTENTS").field(&self.0).finish()
@@ -15929,6 +16027,7 @@ pub struct DVD_KARAOKE_DOWNMIX(pub i32);
 impl windows_core::TypeKind for DVD_KARAOKE_DOWNMIX {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVD_KARAOKE_DOWNMIX {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVD_KARAOKE_DOWNMIX").field(&self.0).finish()
@@ -15940,6 +16039,7 @@ pub struct DVD_MENU_ID(pub i32);
 impl windows_core::TypeKind for DVD_MENU_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVD_MENU_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVD_MENU_ID").field(&self.0).finish()
@@ -15951,6 +16051,7 @@ pub struct DVD_NavCmdType(pub i32);
 impl windows_core::TypeKind for DVD_NavCmdType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVD_NavCmdType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVD_NavCmdType").field(&self.0).finish()
@@ -15962,6 +16063,7 @@ pub struct DVD_OPTION_FLAG(pub i32);
 impl windows_core::TypeKind for DVD_OPTION_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVD_OPTION_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVD_OPTION_FLAG").field(&self.0).finish()
@@ -15973,6 +16075,7 @@ pub struct DVD_PARENTAL_LEVEL(pub i32);
 impl windows_core::TypeKind for DVD_PARENTAL_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVD_PARENTAL_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVD_PARENTAL_LEVEL").field(&self.0).finish()
@@ -15984,6 +16087,7 @@ pub struct DVD_PB_STOPPED(pub i32);
 impl windows_core::TypeKind for DVD_PB_STOPPED {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVD_PB_STOPPED {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVD_PB_STOPPED").field(&self.0).finish()
@@ -15995,6 +16099,7 @@ pub struct DVD_PLAY_DIRECTION(pub i32);
 impl windows_core::TypeKind for DVD_PLAY_DIRECTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVD_PLAY_DIRECTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVD_PLAY_DIRECTION").field(&self.0).finish()
@@ -16006,6 +16111,7 @@ pub struct DVD_PREFERRED_DISPLAY_MODE(pub i32);
 impl windows_core::TypeKind for DVD_PREFERRED_DISPLAY_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVD_PREFERRED_DISPLAY_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVD_PREFERRED_DISPLAY_MODE").field(&self.0).finish()
@@ -16017,6 +16123,7 @@ pub struct DVD_RELATIVE_BUTTON(pub i32);
 impl windows_core::TypeKind for DVD_RELATIVE_BUTTON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVD_RELATIVE_BUTTON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVD_RELATIVE_BUTTON").field(&self.0).finish()
@@ -16028,6 +16135,7 @@ pub struct DVD_SUBPICTURE_CODING(pub i32);
 impl windows_core::TypeKind for DVD_SUBPICTURE_CODING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVD_SUBPICTURE_CODING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVD_SUBPICTURE_CODING").field(&self.0).finish()
@@ -16039,6 +16147,7 @@ pub struct DVD_SUBPICTURE_LANG_EXT(pub i32);
 impl windows_core::TypeKind for DVD_SUBPICTURE_LANG_EXT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVD_SUBPICTURE_LANG_EXT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVD_SUBPICTURE_LANG_EXT").field(&self.0).finish()
@@ -16050,6 +16159,7 @@ pub struct DVD_SUBPICTURE_TYPE(pub i32);
 impl windows_core::TypeKind for DVD_SUBPICTURE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVD_SUBPICTURE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVD_SUBPICTURE_TYPE").field(&self.0).finish()
@@ -16061,6 +16171,7 @@ pub struct DVD_TIMECODE_FLAGS(pub i32);
 impl windows_core::TypeKind for DVD_TIMECODE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVD_TIMECODE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVD_TIMECODE_FLAGS").field(&self.0).finish()
@@ -16072,6 +16183,7 @@ pub struct DVD_TITLE_APPMODE(pub i32);
 impl windows_core::TypeKind for DVD_TITLE_APPMODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVD_TITLE_APPMODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVD_TITLE_APPMODE").field(&self.0).finish()
@@ -16083,6 +16195,7 @@ pub struct DVD_TextCharSet(pub i32);
 impl windows_core::TypeKind for DVD_TextCharSet {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVD_TextCharSet {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVD_TextCharSet").field(&self.0).finish()
@@ -16094,6 +16207,7 @@ pub struct DVD_TextStringType(pub i32);
 impl windows_core::TypeKind for DVD_TextStringType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVD_TextStringType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVD_TextStringType").field(&self.0).finish()
@@ -16105,6 +16219,7 @@ pub struct DVD_VIDEO_COMPRESSION(pub i32);
 impl windows_core::TypeKind for DVD_VIDEO_COMPRESSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVD_VIDEO_COMPRESSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVD_VIDEO_COMPRESSION").field(&self.0).finish()
@@ -16116,6 +16231,7 @@ pub struct DVD_WARNING(pub i32);
 impl windows_core::TypeKind for DVD_WARNING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVD_WARNING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVD_WARNING").field(&self.0).finish()
@@ -16127,6 +16243,7 @@ pub struct DXVA2_DestinationFlags(pub i32);
 impl windows_core::TypeKind for DXVA2_DestinationFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA2_DestinationFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA2_DestinationFlags").field(&self.0).finish()
@@ -16138,6 +16255,7 @@ pub struct DXVA2_SampleFlags(pub i32);
 impl windows_core::TypeKind for DXVA2_SampleFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA2_SampleFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA2_SampleFlags").field(&self.0).finish()
@@ -16149,6 +16267,7 @@ pub struct EntitlementType(pub i32);
 impl windows_core::TypeKind for EntitlementType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EntitlementType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EntitlementType").field(&self.0).finish()
@@ -16160,6 +16279,7 @@ pub struct FECMethod(pub i32);
 impl windows_core::TypeKind for FECMethod {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FECMethod {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FECMethod").field(&self.0).finish()
@@ -16171,6 +16291,7 @@ pub struct FILTER_STATE(pub i32);
 impl windows_core::TypeKind for FILTER_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FILTER_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FILTER_STATE").field(&self.0).finish()
@@ -16182,6 +16303,7 @@ pub struct GuardInterval(pub i32);
 impl windows_core::TypeKind for GuardInterval {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GuardInterval {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GuardInterval").field(&self.0).finish()
@@ -16193,6 +16315,7 @@ pub struct HierarchyAlpha(pub i32);
 impl windows_core::TypeKind for HierarchyAlpha {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HierarchyAlpha {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HierarchyAlpha").field(&self.0).finish()
@@ -16204,6 +16327,7 @@ pub struct IFILTERMAPPER_MERIT(pub i32);
 impl windows_core::TypeKind for IFILTERMAPPER_MERIT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IFILTERMAPPER_MERIT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IFILTERMAPPER_MERIT").field(&self.0).finish()
@@ -16215,6 +16339,7 @@ pub struct ISDBCAS_REQUEST_ID(pub i32);
 impl windows_core::TypeKind for ISDBCAS_REQUEST_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ISDBCAS_REQUEST_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ISDBCAS_REQUEST_ID").field(&self.0).finish()
@@ -16226,6 +16351,7 @@ pub struct InterleavingMode(pub i32);
 impl windows_core::TypeKind for InterleavingMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InterleavingMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InterleavingMode").field(&self.0).finish()
@@ -16237,6 +16363,7 @@ pub struct KSPROPERTY_IPSINK(pub i32);
 impl windows_core::TypeKind for KSPROPERTY_IPSINK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSPROPERTY_IPSINK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSPROPERTY_IPSINK").field(&self.0).finish()
@@ -16281,6 +16408,7 @@ pub struct LNB_Source(pub i32);
 impl windows_core::TypeKind for LNB_Source {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LNB_Source {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LNB_Source").field(&self.0).finish()
@@ -16292,6 +16420,7 @@ pub struct LocationCodeSchemeType(pub i32);
 impl windows_core::TypeKind for LocationCodeSchemeType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LocationCodeSchemeType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LocationCodeSchemeType").field(&self.0).finish()
@@ -16303,6 +16432,7 @@ pub struct MEDIA_SAMPLE_CONTENT(pub i32);
 impl windows_core::TypeKind for MEDIA_SAMPLE_CONTENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MEDIA_SAMPLE_CONTENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MEDIA_SAMPLE_CONTENT").field(&self.0).finish()
@@ -16314,6 +16444,7 @@ pub struct MMSSF_GET_INFORMATION_FLAGS(pub i32);
 impl windows_core::TypeKind for MMSSF_GET_INFORMATION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MMSSF_GET_INFORMATION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MMSSF_GET_INFORMATION_FLAGS").field(&self.0).finish()
@@ -16358,6 +16489,7 @@ pub struct MPEG2StreamType(pub i32);
 impl windows_core::TypeKind for MPEG2StreamType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MPEG2StreamType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MPEG2StreamType").field(&self.0).finish()
@@ -16369,6 +16501,7 @@ pub struct MPEGLAYER3WAVEFORMAT_FLAGS(pub u32);
 impl windows_core::TypeKind for MPEGLAYER3WAVEFORMAT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MPEGLAYER3WAVEFORMAT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MPEGLAYER3WAVEFORMAT_FLAGS").field(&self.0).finish()
@@ -16380,6 +16513,7 @@ pub struct MP_CURVE_TYPE(pub i32);
 impl windows_core::TypeKind for MP_CURVE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MP_CURVE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MP_CURVE_TYPE").field(&self.0).finish()
@@ -16391,6 +16525,7 @@ pub struct MP_TYPE(pub i32);
 impl windows_core::TypeKind for MP_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MP_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MP_TYPE").field(&self.0).finish()
@@ -16402,6 +16537,7 @@ pub struct MUX_PID_TYPE(pub i32);
 impl windows_core::TypeKind for MUX_PID_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MUX_PID_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MUX_PID_TYPE").field(&self.0).finish()
@@ -16413,6 +16549,7 @@ pub struct ModulationType(pub i32);
 impl windows_core::TypeKind for ModulationType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ModulationType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ModulationType").field(&self.0).finish()
@@ -16424,6 +16561,7 @@ pub struct OA_BOOL(pub i32);
 impl windows_core::TypeKind for OA_BOOL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OA_BOOL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OA_BOOL").field(&self.0).finish()
@@ -16435,6 +16573,7 @@ pub struct OUTPUT_STATE(pub i32);
 impl windows_core::TypeKind for OUTPUT_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OUTPUT_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OUTPUT_STATE").field(&self.0).finish()
@@ -16479,6 +16618,7 @@ pub struct PIN_DIRECTION(pub i32);
 impl windows_core::TypeKind for PIN_DIRECTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PIN_DIRECTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PIN_DIRECTION").field(&self.0).finish()
@@ -16490,6 +16630,7 @@ pub struct PhysicalConnectorType(pub i32);
 impl windows_core::TypeKind for PhysicalConnectorType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PhysicalConnectorType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PhysicalConnectorType").field(&self.0).finish()
@@ -16501,6 +16642,7 @@ pub struct Pilot(pub i32);
 impl windows_core::TypeKind for Pilot {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for Pilot {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Pilot").field(&self.0).finish()
@@ -16512,6 +16654,7 @@ pub struct Polarisation(pub i32);
 impl windows_core::TypeKind for Polarisation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for Polarisation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Polarisation").field(&self.0).finish()
@@ -16523,6 +16666,7 @@ pub struct QualityMessageType(pub i32);
 impl windows_core::TypeKind for QualityMessageType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for QualityMessageType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("QualityMessageType").field(&self.0).finish()
@@ -16534,6 +16678,7 @@ pub struct REG_PINFLAG(pub i32);
 impl windows_core::TypeKind for REG_PINFLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for REG_PINFLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("REG_PINFLAG").field(&self.0).finish()
@@ -16578,6 +16723,7 @@ pub struct RollOff(pub i32);
 impl windows_core::TypeKind for RollOff {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RollOff {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RollOff").field(&self.0).finish()
@@ -16589,6 +16735,7 @@ pub struct SNDDEV_ERR(pub i32);
 impl windows_core::TypeKind for SNDDEV_ERR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SNDDEV_ERR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SNDDEV_ERR").field(&self.0).finish()
@@ -16600,6 +16747,7 @@ pub struct SSUPDATE_TYPE(pub i32);
 impl windows_core::TypeKind for SSUPDATE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SSUPDATE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SSUPDATE_TYPE").field(&self.0).finish()
@@ -16611,6 +16759,7 @@ pub struct STREAMIF_CONSTANTS(pub i32);
 impl windows_core::TypeKind for STREAMIF_CONSTANTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STREAMIF_CONSTANTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STREAMIF_CONSTANTS").field(&self.0).finish()
@@ -16622,6 +16771,7 @@ pub struct STREAM_STATE(pub i32);
 impl windows_core::TypeKind for STREAM_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STREAM_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STREAM_STATE").field(&self.0).finish()
@@ -16633,6 +16783,7 @@ pub struct STREAM_TYPE(pub i32);
 impl windows_core::TypeKind for STREAM_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STREAM_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STREAM_TYPE").field(&self.0).finish()
@@ -16644,6 +16795,7 @@ pub struct ScanModulationTypes(pub i32);
 impl windows_core::TypeKind for ScanModulationTypes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ScanModulationTypes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ScanModulationTypes").field(&self.0).finish()
@@ -16655,6 +16807,7 @@ pub struct SmartCardAssociationType(pub i32);
 impl windows_core::TypeKind for SmartCardAssociationType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmartCardAssociationType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmartCardAssociationType").field(&self.0).finish()
@@ -16666,6 +16819,7 @@ pub struct SmartCardStatusType(pub i32);
 impl windows_core::TypeKind for SmartCardStatusType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SmartCardStatusType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SmartCardStatusType").field(&self.0).finish()
@@ -16677,6 +16831,7 @@ pub struct SpectralInversion(pub i32);
 impl windows_core::TypeKind for SpectralInversion {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpectralInversion {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpectralInversion").field(&self.0).finish()
@@ -16688,6 +16843,7 @@ pub struct TVAudioMode(pub i32);
 impl windows_core::TypeKind for TVAudioMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TVAudioMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TVAudioMode").field(&self.0).finish()
@@ -16699,6 +16855,7 @@ pub struct TransmissionMode(pub i32);
 impl windows_core::TypeKind for TransmissionMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TransmissionMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TransmissionMode").field(&self.0).finish()
@@ -16710,6 +16867,7 @@ pub struct TunerInputType(pub i32);
 impl windows_core::TypeKind for TunerInputType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TunerInputType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TunerInputType").field(&self.0).finish()
@@ -16721,6 +16879,7 @@ pub struct UICloseReasonType(pub i32);
 impl windows_core::TypeKind for UICloseReasonType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UICloseReasonType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UICloseReasonType").field(&self.0).finish()
@@ -16732,6 +16891,7 @@ pub struct VALID_UOP_FLAG(pub i32);
 impl windows_core::TypeKind for VALID_UOP_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VALID_UOP_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VALID_UOP_FLAG").field(&self.0).finish()
@@ -16743,6 +16903,7 @@ pub struct VIDEOENCODER_BITRATE_MODE(pub i32);
 impl windows_core::TypeKind for VIDEOENCODER_BITRATE_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VIDEOENCODER_BITRATE_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VIDEOENCODER_BITRATE_MODE").field(&self.0).finish()
@@ -16754,6 +16915,7 @@ pub struct VMR9AlphaBitmapFlags(pub i32);
 impl windows_core::TypeKind for VMR9AlphaBitmapFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VMR9AlphaBitmapFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VMR9AlphaBitmapFlags").field(&self.0).finish()
@@ -16765,6 +16927,7 @@ pub struct VMR9AspectRatioMode(pub i32);
 impl windows_core::TypeKind for VMR9AspectRatioMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VMR9AspectRatioMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VMR9AspectRatioMode").field(&self.0).finish()
@@ -16776,6 +16939,7 @@ pub struct VMR9DeinterlacePrefs(pub i32);
 impl windows_core::TypeKind for VMR9DeinterlacePrefs {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VMR9DeinterlacePrefs {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VMR9DeinterlacePrefs").field(&self.0).finish()
@@ -16787,6 +16951,7 @@ pub struct VMR9DeinterlaceTech(pub i32);
 impl windows_core::TypeKind for VMR9DeinterlaceTech {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VMR9DeinterlaceTech {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VMR9DeinterlaceTech").field(&self.0).finish()
@@ -16798,6 +16963,7 @@ pub struct VMR9MixerPrefs(pub i32);
 impl windows_core::TypeKind for VMR9MixerPrefs {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VMR9MixerPrefs {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VMR9MixerPrefs").field(&self.0).finish()
@@ -16809,6 +16975,7 @@ pub struct VMR9Mode(pub i32);
 impl windows_core::TypeKind for VMR9Mode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VMR9Mode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VMR9Mode").field(&self.0).finish()
@@ -16820,6 +16987,7 @@ pub struct VMR9PresentationFlags(pub i32);
 impl windows_core::TypeKind for VMR9PresentationFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VMR9PresentationFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VMR9PresentationFlags").field(&self.0).finish()
@@ -16831,6 +16999,7 @@ pub struct VMR9ProcAmpControlFlags(pub i32);
 impl windows_core::TypeKind for VMR9ProcAmpControlFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VMR9ProcAmpControlFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VMR9ProcAmpControlFlags").field(&self.0).finish()
@@ -16842,6 +17011,7 @@ pub struct VMR9RenderPrefs(pub i32);
 impl windows_core::TypeKind for VMR9RenderPrefs {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VMR9RenderPrefs {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VMR9RenderPrefs").field(&self.0).finish()
@@ -16853,6 +17023,7 @@ pub struct VMR9SurfaceAllocationFlags(pub i32);
 impl windows_core::TypeKind for VMR9SurfaceAllocationFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VMR9SurfaceAllocationFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VMR9SurfaceAllocationFlags").field(&self.0).finish()
@@ -16864,6 +17035,7 @@ pub struct VMR9_SampleFormat(pub i32);
 impl windows_core::TypeKind for VMR9_SampleFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VMR9_SampleFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VMR9_SampleFormat").field(&self.0).finish()
@@ -16875,6 +17047,7 @@ pub struct VMRDeinterlacePrefs(pub i32);
 impl windows_core::TypeKind for VMRDeinterlacePrefs {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VMRDeinterlacePrefs {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VMRDeinterlacePrefs").field(&self.0).finish()
@@ -16886,6 +17059,7 @@ pub struct VMRDeinterlaceTech(pub i32);
 impl windows_core::TypeKind for VMRDeinterlaceTech {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VMRDeinterlaceTech {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VMRDeinterlaceTech").field(&self.0).finish()
@@ -16897,6 +17071,7 @@ pub struct VMRMixerPrefs(pub i32);
 impl windows_core::TypeKind for VMRMixerPrefs {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VMRMixerPrefs {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VMRMixerPrefs").field(&self.0).finish()
@@ -16908,6 +17083,7 @@ pub struct VMRMode(pub i32);
 impl windows_core::TypeKind for VMRMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VMRMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VMRMode").field(&self.0).finish()
@@ -16919,6 +17095,7 @@ pub struct VMRPresentationFlags(pub i32);
 impl windows_core::TypeKind for VMRPresentationFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VMRPresentationFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VMRPresentationFlags").field(&self.0).finish()
@@ -16930,6 +17107,7 @@ pub struct VMRRenderPrefs(pub i32);
 impl windows_core::TypeKind for VMRRenderPrefs {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VMRRenderPrefs {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VMRRenderPrefs").field(&self.0).finish()
@@ -16941,6 +17119,7 @@ pub struct VMRSurfaceAllocationFlags(pub i32);
 impl windows_core::TypeKind for VMRSurfaceAllocationFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VMRSurfaceAllocationFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VMRSurfaceAllocationFlags").field(&self.0).finish()
@@ -16952,6 +17131,7 @@ pub struct VMR_ASPECT_RATIO_MODE(pub i32);
 impl windows_core::TypeKind for VMR_ASPECT_RATIO_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VMR_ASPECT_RATIO_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VMR_ASPECT_RATIO_MODE").field(&self.0).finish()
@@ -16963,6 +17143,7 @@ pub struct VfwCaptureDialogs(pub i32);
 impl windows_core::TypeKind for VfwCaptureDialogs {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VfwCaptureDialogs {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VfwCaptureDialogs").field(&self.0).finish()
@@ -16974,6 +17155,7 @@ pub struct VfwCompressDialogs(pub i32);
 impl windows_core::TypeKind for VfwCompressDialogs {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VfwCompressDialogs {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VfwCompressDialogs").field(&self.0).finish()
@@ -16985,6 +17167,7 @@ pub struct VideoControlFlags(pub i32);
 impl windows_core::TypeKind for VideoControlFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VideoControlFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VideoControlFlags").field(&self.0).finish()
@@ -16996,6 +17179,7 @@ pub struct VideoCopyProtectionType(pub i32);
 impl windows_core::TypeKind for VideoCopyProtectionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VideoCopyProtectionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VideoCopyProtectionType").field(&self.0).finish()
@@ -17007,6 +17191,7 @@ pub struct VideoProcAmpFlags(pub i32);
 impl windows_core::TypeKind for VideoProcAmpFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VideoProcAmpFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VideoProcAmpFlags").field(&self.0).finish()
@@ -17018,6 +17203,7 @@ pub struct VideoProcAmpProperty(pub i32);
 impl windows_core::TypeKind for VideoProcAmpProperty {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VideoProcAmpProperty {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VideoProcAmpProperty").field(&self.0).finish()
@@ -17029,6 +17215,7 @@ pub struct _AMRESCTL_RESERVEFLAGS(pub i32);
 impl windows_core::TypeKind for _AMRESCTL_RESERVEFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _AMRESCTL_RESERVEFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_AMRESCTL_RESERVEFLAGS").field(&self.0).finish()
@@ -17040,6 +17227,7 @@ pub struct _AMSTREAMSELECTENABLEFLAGS(pub i32);
 impl windows_core::TypeKind for _AMSTREAMSELECTENABLEFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _AMSTREAMSELECTENABLEFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_AMSTREAMSELECTENABLEFLAGS").field(&self.0).finish()
@@ -17051,6 +17239,7 @@ pub struct _AMSTREAMSELECTINFOFLAGS(pub i32);
 impl windows_core::TypeKind for _AMSTREAMSELECTINFOFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _AMSTREAMSELECTINFOFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_AMSTREAMSELECTINFOFLAGS").field(&self.0).finish()
@@ -17062,6 +17251,7 @@ pub struct _AM_AUDIO_RENDERER_STAT_PARAM(pub i32);
 impl windows_core::TypeKind for _AM_AUDIO_RENDERER_STAT_PARAM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _AM_AUDIO_RENDERER_STAT_PARAM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_AM_AUDIO_RENDERER_STAT_PARAM").field(&self.0).finish()
@@ -17073,6 +17263,7 @@ pub struct _AM_FILTER_MISC_FLAGS(pub i32);
 impl windows_core::TypeKind for _AM_FILTER_MISC_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _AM_FILTER_MISC_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_AM_FILTER_MISC_FLAGS").field(&self.0).finish()
@@ -17084,6 +17275,7 @@ pub struct _AM_INTF_SEARCH_FLAGS(pub i32);
 impl windows_core::TypeKind for _AM_INTF_SEARCH_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _AM_INTF_SEARCH_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_AM_INTF_SEARCH_FLAGS").field(&self.0).finish()
@@ -17095,6 +17287,7 @@ pub struct _AM_OVERLAY_NOTIFY_FLAGS(pub i32);
 impl windows_core::TypeKind for _AM_OVERLAY_NOTIFY_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _AM_OVERLAY_NOTIFY_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_AM_OVERLAY_NOTIFY_FLAGS").field(&self.0).finish()
@@ -17106,6 +17299,7 @@ pub struct _AM_PIN_FLOW_CONTROL_BLOCK_FLAGS(pub i32);
 impl windows_core::TypeKind for _AM_PIN_FLOW_CONTROL_BLOCK_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _AM_PIN_FLOW_CONTROL_BLOCK_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_AM_PIN_FLOW_CONTROL_BLOCK_FLAGS").field(&self.0).finish()
@@ -17117,6 +17311,7 @@ pub struct _AM_PUSHSOURCE_FLAGS(pub i32);
 impl windows_core::TypeKind for _AM_PUSHSOURCE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _AM_PUSHSOURCE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_AM_PUSHSOURCE_FLAGS").field(&self.0).finish()
@@ -17128,6 +17323,7 @@ pub struct _AM_RENSDEREXFLAGS(pub i32);
 impl windows_core::TypeKind for _AM_RENSDEREXFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _AM_RENSDEREXFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_AM_RENSDEREXFLAGS").field(&self.0).finish()
@@ -17139,6 +17335,7 @@ pub struct _DVDECODERRESOLUTION(pub i32);
 impl windows_core::TypeKind for _DVDECODERRESOLUTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _DVDECODERRESOLUTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_DVDECODERRESOLUTION").field(&self.0).finish()
@@ -17150,6 +17347,7 @@ pub struct _DVENCODERFORMAT(pub i32);
 impl windows_core::TypeKind for _DVENCODERFORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _DVENCODERFORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_DVENCODERFORMAT").field(&self.0).finish()
@@ -17161,6 +17359,7 @@ pub struct _DVENCODERRESOLUTION(pub i32);
 impl windows_core::TypeKind for _DVENCODERRESOLUTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _DVENCODERRESOLUTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_DVENCODERRESOLUTION").field(&self.0).finish()
@@ -17172,6 +17371,7 @@ pub struct _DVENCODERVIDEOFORMAT(pub i32);
 impl windows_core::TypeKind for _DVENCODERVIDEOFORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _DVENCODERVIDEOFORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_DVENCODERVIDEOFORMAT").field(&self.0).finish()
@@ -17183,6 +17383,7 @@ pub struct _DVRESOLUTION(pub i32);
 impl windows_core::TypeKind for _DVRESOLUTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _DVRESOLUTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_DVRESOLUTION").field(&self.0).finish()
@@ -17194,13 +17395,15 @@ pub struct _REM_FILTER_FLAGS(pub i32);
 impl windows_core::TypeKind for _REM_FILTER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _REM_FILTER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_REM_FILTER_FLAGS").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ALLOCATOR_PROPERTIES {
     pub cBuffers: i32,
     pub cbBuffer: i32,
@@ -17216,7 +17419,8 @@ impl Default for ALLOCATOR_PROPERTIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AMCOPPCommand {
     pub macKDI: windows_core::GUID,
     pub guidCommandID: windows_core::GUID,
@@ -17233,7 +17437,8 @@ impl Default for AMCOPPCommand {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AMCOPPSignature {
     pub Signature: [u8; 256],
 }
@@ -17246,7 +17451,8 @@ impl Default for AMCOPPSignature {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AMCOPPStatusInput {
     pub rApp: windows_core::GUID,
     pub guidStatusRequestID: windows_core::GUID,
@@ -17263,7 +17469,8 @@ impl Default for AMCOPPStatusInput {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AMCOPPStatusOutput {
     pub macKDI: windows_core::GUID,
     pub cbSizeData: u32,
@@ -17278,7 +17485,8 @@ impl Default for AMCOPPStatusOutput {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AMVABUFFERINFO {
     pub dwTypeIndex: u32,
     pub dwBufferIndex: u32,
@@ -17294,7 +17502,8 @@ impl Default for AMVABUFFERINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AMVABeginFrameInfo {
     pub dwDestSurfaceIndex: u32,
     pub pInputData: *mut core::ffi::c_void,
@@ -17332,7 +17541,8 @@ impl Default for AMVACompBufferInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AMVAEndFrameInfo {
     pub dwSizeMiscData: u32,
     pub pMiscData: *mut core::ffi::c_void,
@@ -17346,7 +17556,8 @@ impl Default for AMVAEndFrameInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AMVAInternalMemInfo {
     pub dwScratchMemAlloc: u32,
 }
@@ -17395,7 +17606,8 @@ impl Default for AMVAUncompDataInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AMVPDATAINFO {
     pub dwSize: u32,
     pub dwMicrosecondsPerField: u32,
@@ -17420,7 +17632,8 @@ impl Default for AMVPDATAINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AMVPDIMINFO {
     pub dwFieldWidth: u32,
     pub dwFieldHeight: u32,
@@ -17437,7 +17650,8 @@ impl Default for AMVPDIMINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AMVPSIZE {
     pub dwWidth: u32,
     pub dwHeight: u32,
@@ -17451,7 +17665,8 @@ impl Default for AMVPSIZE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_AC3_ALTERNATE_AUDIO {
     pub fStereo: super::super::Foundation::BOOL,
     pub DualMode: u32,
@@ -17465,7 +17680,8 @@ impl Default for AM_AC3_ALTERNATE_AUDIO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_AC3_BIT_STREAM_MODE {
     pub BitStreamMode: i32,
 }
@@ -17478,7 +17694,8 @@ impl Default for AM_AC3_BIT_STREAM_MODE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_AC3_DIALOGUE_LEVEL {
     pub DialogueLevel: u32,
 }
@@ -17491,7 +17708,8 @@ impl Default for AM_AC3_DIALOGUE_LEVEL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_AC3_DOWNMIX {
     pub fDownMix: super::super::Foundation::BOOL,
     pub fDolbySurround: super::super::Foundation::BOOL,
@@ -17505,7 +17723,8 @@ impl Default for AM_AC3_DOWNMIX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_AC3_ERROR_CONCEALMENT {
     pub fRepeatPreviousBlock: super::super::Foundation::BOOL,
     pub fErrorInCurrentBlock: super::super::Foundation::BOOL,
@@ -17519,7 +17738,8 @@ impl Default for AM_AC3_ERROR_CONCEALMENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_AC3_ROOM_TYPE {
     pub fLargeRoom: super::super::Foundation::BOOL,
 }
@@ -17532,13 +17752,64 @@ impl Default for AM_AC3_ROOM_TYPE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_COLCON {
     pub _bitfield1: u8,
     pub _bitfield2: u8,
     pub _bitfield3: u8,
     pub _bitfield4: u8,
 }
+impl AM_COLCON {
+    pub const fn emph1col(&self) -> u8 {
+        self._bitfield1 & 15
+    }
+    pub fn set_emph1col(&mut self, value: u8) {
+        self._bitfield1 = (self._bitfield1 & !15) | (value & 15);
+    }
+    pub const fn emph2col(&self) -> u8 {
+        (self._bitfield1 >> 4) & 15
+    }
+    pub fn set_emph2col(&mut self, value: u8) {
+        self._bitfield1 = (self._bitfield1 & !(15 << 4)) | ((value & 15) << 4);
+    }
+    pub const fn backcol(&self) -> u8 {
+        self._bitfield2 & 15
+    }
+    pub fn set_backcol(&mut self, value: u8) {
+        self._bitfield2 = (self._bitfield2 & !15) | (value & 15);
+    }
+    pub const fn patcol(&self) -> u8 {
+        (self._bitfield2 >> 4) & 15
+    }
+    pub fn set_patcol(&mut self, value: u8) {
+        self._bitfield2 = (self._bitfield2 & !(15 << 4)) | ((value & 15) << 4);
+    }
+    pub const fn emph1con(&self) -> u8 {
+        self._bitfield3 & 15
+    }
+    pub fn set_emph1con(&mut self, value: u8) {
+        self._bitfield3 = (self._bitfield3 & !15) | (value & 15);
+    }
+    pub const fn emph2con(&self) -> u8 {
+        (self._bitfield3 >> 4) & 15
+    }
+    pub fn set_emph2con(&mut self, value: u8) {
+        self._bitfield3 = (self._bitfield3 & !(15 << 4)) | ((value & 15) << 4);
+    }
+    pub const fn backcon(&self) -> u8 {
+        self._bitfield4 & 15
+    }
+    pub fn set_backcon(&mut self, value: u8) {
+        self._bitfield4 = (self._bitfield4 & !15) | (value & 15);
+    }
+    pub const fn patcon(&self) -> u8 {
+        (self._bitfield4 >> 4) & 15
+    }
+    pub fn set_patcon(&mut self, value: u8) {
+        self._bitfield4 = (self._bitfield4 & !(15 << 4)) | ((value & 15) << 4);
+    }
+}
 impl windows_core::TypeKind for AM_COLCON {
     type TypeKind = windows_core::CopyType;
 }
@@ -17548,7 +17819,8 @@ impl Default for AM_COLCON {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_COPY_MACROVISION {
     pub MACROVISIONLevel: u32,
 }
@@ -17561,7 +17833,8 @@ impl Default for AM_COPY_MACROVISION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_DVDCOPY_BUSKEY {
     pub BusKey: [u8; 5],
     pub Reserved: [u8; 1],
@@ -17575,7 +17848,8 @@ impl Default for AM_DVDCOPY_BUSKEY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_DVDCOPY_CHLGKEY {
     pub ChlgKey: [u8; 10],
     pub Reserved: [u8; 2],
@@ -17589,7 +17863,8 @@ impl Default for AM_DVDCOPY_CHLGKEY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_DVDCOPY_DISCKEY {
     pub DiscKey: [u8; 2048],
 }
@@ -17602,7 +17877,8 @@ impl Default for AM_DVDCOPY_DISCKEY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_DVDCOPY_SET_COPY_STATE {
     pub DVDCopyState: u32,
 }
@@ -17615,7 +17891,8 @@ impl Default for AM_DVDCOPY_SET_COPY_STATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_DVDCOPY_TITLEKEY {
     pub KeyFlags: u32,
     pub Reserved1: [u32; 2],
@@ -17631,7 +17908,8 @@ impl Default for AM_DVDCOPY_TITLEKEY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_DVD_ChangeRate {
     pub StartInTime: i64,
     pub StartOutTime: i64,
@@ -17646,7 +17924,8 @@ impl Default for AM_DVD_ChangeRate {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_DVD_RENDERSTATUS {
     pub hrVPEStatus: windows_core::HRESULT,
     pub bDvdVolInvalid: super::super::Foundation::BOOL,
@@ -17666,7 +17945,8 @@ impl Default for AM_DVD_RENDERSTATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_DVD_YUV {
     pub Reserved: u8,
     pub Y: u8,
@@ -17682,7 +17962,8 @@ impl Default for AM_DVD_YUV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_DvdKaraokeData {
     pub dwDownmix: u32,
     pub dwSpeakerAssignment: u32,
@@ -17696,7 +17977,8 @@ impl Default for AM_DvdKaraokeData {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_ExactRateChange {
     pub OutputZeroTime: i64,
     pub Rate: i32,
@@ -17710,7 +17992,8 @@ impl Default for AM_ExactRateChange {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_FRAMESTEP_STEP {
     pub dwFramesToStep: u32,
 }
@@ -17724,7 +18007,8 @@ impl Default for AM_FRAMESTEP_STEP {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Media_MediaFoundation")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_MPEGSTREAMTYPE {
     pub dwStreamId: u32,
     pub dwReserved: u32,
@@ -17749,7 +18033,8 @@ impl Default for AM_MPEGSTREAMTYPE {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Media_MediaFoundation")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_MPEGSYSTEMTYPE {
     pub dwBitRate: u32,
     pub cStreams: u32,
@@ -17772,7 +18057,8 @@ impl Default for AM_MPEGSYSTEMTYPE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_PROPERTY_SPHLI {
     pub HLISS: u16,
     pub Reserved: u16,
@@ -17793,7 +18079,8 @@ impl Default for AM_PROPERTY_SPHLI {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_PROPERTY_SPPAL {
     pub sppal: [AM_DVD_YUV; 16],
 }
@@ -17806,7 +18093,8 @@ impl Default for AM_PROPERTY_SPPAL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_QueryRate {
     pub lMaxForwardFullFrame: i32,
     pub lMaxReverseFullFrame: i32,
@@ -17821,7 +18109,8 @@ impl Default for AM_QueryRate {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Media_MediaFoundation")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_SAMPLE2_PROPERTIES {
     pub cbData: u32,
     pub dwTypeSpecificFlags: u32,
@@ -17845,7 +18134,8 @@ impl Default for AM_SAMPLE2_PROPERTIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_STREAM_INFO {
     pub tStart: i64,
     pub tStop: i64,
@@ -17862,7 +18152,8 @@ impl Default for AM_STREAM_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_SimpleRateChange {
     pub StartTime: i64,
     pub Rate: i32,
@@ -17876,7 +18167,8 @@ impl Default for AM_SimpleRateChange {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_WST_PAGE {
     pub dwPageNr: u32,
     pub dwSubPageNr: u32,
@@ -17891,7 +18183,8 @@ impl Default for AM_WST_PAGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ANALOGVIDEOINFO {
     pub rcSource: super::super::Foundation::RECT,
     pub rcTarget: super::super::Foundation::RECT,
@@ -17908,7 +18201,8 @@ impl Default for ANALOGVIDEOINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AUDIO_STREAM_CONFIG_CAPS {
     pub guid: windows_core::GUID,
     pub MinimumChannels: u32,
@@ -17983,7 +18277,8 @@ impl Default for AVIFIELDINDEX_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AVIINDEXENTRY {
     pub ckid: u32,
     pub dwFlags: u32,
@@ -18077,7 +18372,8 @@ impl Default for AVIOLDINDEX_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AVIPALCHANGE {
     pub bFirstEntry: u8,
     pub bNumEntries: u8,
@@ -18159,7 +18455,8 @@ impl Default for AVISTREAMHEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AVISTREAMHEADER_0 {
     pub left: i16,
     pub top: i16,
@@ -18211,7 +18508,8 @@ impl Default for AVISUPERINDEX_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AVIStreamHeader {
     pub fccType: u32,
     pub fccHandler: u32,
@@ -18335,7 +18633,8 @@ impl Default for AVITIMEDINDEX_ENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDANODE_DESCRIPTOR {
     pub ulBdaNodeType: u32,
     pub guidFunction: windows_core::GUID,
@@ -18350,7 +18649,8 @@ impl Default for BDANODE_DESCRIPTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_BUFFER {
     pub lResult: i32,
     pub ulBufferSize: u32,
@@ -18365,7 +18665,8 @@ impl Default for BDA_BUFFER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_CAS_CHECK_ENTITLEMENTTOKEN {
     pub lResult: i32,
     pub ulDescrambleStatus: u32,
@@ -18379,7 +18680,8 @@ impl Default for BDA_CAS_CHECK_ENTITLEMENTTOKEN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_CAS_CLOSEMMIDATA {
     pub ulDialogNumber: u32,
 }
@@ -18392,7 +18694,8 @@ impl Default for BDA_CAS_CLOSEMMIDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_CAS_CLOSE_MMIDIALOG {
     pub lResult: i32,
     pub SessionResult: u32,
@@ -18406,7 +18709,8 @@ impl Default for BDA_CAS_CLOSE_MMIDIALOG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_CAS_OPENMMIDATA {
     pub ulDialogNumber: u32,
     pub ulDialogRequest: u32,
@@ -18423,7 +18727,8 @@ impl Default for BDA_CAS_OPENMMIDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_CAS_REQUESTTUNERDATA {
     pub ucRequestPriority: u8,
     pub ucRequestReason: u8,
@@ -18439,7 +18744,8 @@ impl Default for BDA_CAS_REQUESTTUNERDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_CA_MODULE_UI {
     pub ulFormat: u32,
     pub ulbcDesc: u32,
@@ -18454,7 +18760,8 @@ impl Default for BDA_CA_MODULE_UI {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_DISEQC_RESPONSE {
     pub ulRequestId: u32,
     pub ulPacketLength: u32,
@@ -18469,7 +18776,8 @@ impl Default for BDA_DISEQC_RESPONSE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_DISEQC_SEND {
     pub ulRequestId: u32,
     pub ulPacketLength: u32,
@@ -18484,7 +18792,8 @@ impl Default for BDA_DISEQC_SEND {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_DRM_DRMSTATUS {
     pub lResult: i32,
     pub DRMuuid: windows_core::GUID,
@@ -18500,7 +18809,8 @@ impl Default for BDA_DRM_DRMSTATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_DVBT2_L1_SIGNALLING_DATA {
     pub L1Pre_TYPE: u8,
     pub L1Pre_BWT_S1_S2: u8,
@@ -18526,7 +18836,8 @@ impl Default for BDA_DVBT2_L1_SIGNALLING_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_ETHERNET_ADDRESS {
     pub rgbAddress: [u8; 6],
 }
@@ -18539,7 +18850,8 @@ impl Default for BDA_ETHERNET_ADDRESS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_ETHERNET_ADDRESS_LIST {
     pub ulcAddresses: u32,
     pub rgAddressl: [BDA_ETHERNET_ADDRESS; 1],
@@ -18553,7 +18865,8 @@ impl Default for BDA_ETHERNET_ADDRESS_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_GDDS_DATA {
     pub lResult: i32,
     pub ulDataLength: u32,
@@ -18569,7 +18882,8 @@ impl Default for BDA_GDDS_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_GDDS_DATATYPE {
     pub lResult: i32,
     pub uuidDataType: windows_core::GUID,
@@ -18583,7 +18897,8 @@ impl Default for BDA_GDDS_DATATYPE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_IPv4_ADDRESS {
     pub rgbAddress: [u8; 4],
 }
@@ -18596,7 +18911,8 @@ impl Default for BDA_IPv4_ADDRESS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_IPv4_ADDRESS_LIST {
     pub ulcAddresses: u32,
     pub rgAddressl: [BDA_IPv4_ADDRESS; 1],
@@ -18610,7 +18926,8 @@ impl Default for BDA_IPv4_ADDRESS_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_IPv6_ADDRESS {
     pub rgbAddress: [u8; 6],
 }
@@ -18623,7 +18940,8 @@ impl Default for BDA_IPv6_ADDRESS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_IPv6_ADDRESS_LIST {
     pub ulcAddresses: u32,
     pub rgAddressl: [BDA_IPv6_ADDRESS; 1],
@@ -18637,7 +18955,8 @@ impl Default for BDA_IPv6_ADDRESS_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_ISDBCAS_EMG_REQ {
     pub bCLA: u8,
     pub bINS: u8,
@@ -18707,7 +19026,8 @@ impl Default for BDA_MUX_PIDLISTITEM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_PID_MAP {
     pub MediaSampleContent: MEDIA_SAMPLE_CONTENT,
     pub ulcPIDs: u32,
@@ -18722,7 +19042,8 @@ impl Default for BDA_PID_MAP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_PID_UNMAP {
     pub ulcPIDs: u32,
     pub aulPIDs: [u32; 1],
@@ -18736,7 +19057,8 @@ impl Default for BDA_PID_UNMAP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_PROGRAM_PID_LIST {
     pub ulProgramNumber: u32,
     pub ulcPIDs: u32,
@@ -18751,7 +19073,8 @@ impl Default for BDA_PROGRAM_PID_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_RATING_PINRESET {
     pub bPinLength: u8,
     pub argbNewPin: [u8; 1],
@@ -18765,7 +19088,8 @@ impl Default for BDA_RATING_PINRESET {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_SCAN_CAPABILTIES {
     pub lResult: i32,
     pub ul64AnalogStandardsSupported: u64,
@@ -18779,7 +19103,8 @@ impl Default for BDA_SCAN_CAPABILTIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_SCAN_START {
     pub lResult: i32,
     pub LowerFrequency: u32,
@@ -18794,7 +19119,8 @@ impl Default for BDA_SCAN_START {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_SCAN_STATE {
     pub lResult: i32,
     pub ulSignalLock: u32,
@@ -18810,7 +19136,8 @@ impl Default for BDA_SCAN_STATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_SIGNAL_TIMEOUTS {
     pub ulCarrierTimeoutMs: u32,
     pub ulScanningTimeoutMs: u32,
@@ -18825,7 +19152,8 @@ impl Default for BDA_SIGNAL_TIMEOUTS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_STRING {
     pub lResult: i32,
     pub ulStringSize: u32,
@@ -18840,7 +19168,8 @@ impl Default for BDA_STRING {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_TABLE_SECTION {
     pub ulPrimarySectionId: u32,
     pub ulSecondarySectionId: u32,
@@ -18856,7 +19185,8 @@ impl Default for BDA_TABLE_SECTION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_TEMPLATE_CONNECTION {
     pub FromNodeType: u32,
     pub FromNodePinType: u32,
@@ -18872,7 +19202,8 @@ impl Default for BDA_TEMPLATE_CONNECTION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_TEMPLATE_PIN_JOINT {
     pub uliTemplateConnection: u32,
     pub ulcInstancesMax: u32,
@@ -18903,7 +19234,8 @@ impl Default for BDA_TS_SELECTORINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_TS_SELECTORINFO_ISDBS_EXT {
     pub bTMCC: [u8; 48],
 }
@@ -18916,7 +19248,8 @@ impl Default for BDA_TS_SELECTORINFO_ISDBS_EXT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_TUNER_DIAGNOSTICS {
     pub lResult: i32,
     pub ulSignalLevel: u32,
@@ -18932,7 +19265,8 @@ impl Default for BDA_TUNER_DIAGNOSTICS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_TUNER_TUNERSTATE {
     pub lResult: i32,
     pub ulTuneLength: u32,
@@ -18947,7 +19281,8 @@ impl Default for BDA_TUNER_TUNERSTATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_USERACTIVITY_INTERVAL {
     pub lResult: i32,
     pub ulActivityInterval: u32,
@@ -18961,7 +19296,8 @@ impl Default for BDA_USERACTIVITY_INTERVAL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_WMDRMTUNER_PIDPROTECTION {
     pub lResult: i32,
     pub uuidKeyID: windows_core::GUID,
@@ -18975,7 +19311,8 @@ impl Default for BDA_WMDRMTUNER_PIDPROTECTION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_WMDRMTUNER_PURCHASEENTITLEMENT {
     pub lResult: i32,
     pub ulDescrambleStatus: u32,
@@ -18991,7 +19328,8 @@ impl Default for BDA_WMDRMTUNER_PURCHASEENTITLEMENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_WMDRM_KEYINFOLIST {
     pub lResult: i32,
     pub ulKeyuuidBufferLen: u32,
@@ -19006,7 +19344,8 @@ impl Default for BDA_WMDRM_KEYINFOLIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_WMDRM_RENEWLICENSE {
     pub lResult: i32,
     pub ulDescrambleStatus: u32,
@@ -19022,7 +19361,8 @@ impl Default for BDA_WMDRM_RENEWLICENSE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BDA_WMDRM_STATUS {
     pub lResult: i32,
     pub ulMaxCaptureTokenSize: u32,
@@ -19044,7 +19384,8 @@ impl Default for BDA_WMDRM_STATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COLORKEY {
     pub KeyType: u32,
     pub PaletteIndex: u32,
@@ -19060,7 +19401,8 @@ impl Default for COLORKEY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DVD_ATR {
     pub ulCAT: u32,
     pub pbATRI: [u8; 768],
@@ -19074,7 +19416,8 @@ impl Default for DVD_ATR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DVD_AudioAttributes {
     pub AppMode: DVD_AUDIO_APPMODE,
     pub AppModeData: u8,
@@ -19096,7 +19439,8 @@ impl Default for DVD_AudioAttributes {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DVD_DECODER_CAPS {
     pub dwSize: u32,
     pub dwAudioCaps: u32,
@@ -19120,7 +19464,8 @@ impl Default for DVD_DECODER_CAPS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DVD_HMSF_TIMECODE {
     pub bHours: u8,
     pub bMinutes: u8,
@@ -19136,7 +19481,8 @@ impl Default for DVD_HMSF_TIMECODE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DVD_KaraokeAttributes {
     pub bVersion: u8,
     pub fMasterOfCeremoniesInGuideVocal1: super::super::Foundation::BOOL,
@@ -19153,7 +19499,8 @@ impl Default for DVD_KaraokeAttributes {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DVD_MUA_Coeff {
     pub log2_alpha: f64,
     pub log2_beta: f64,
@@ -19167,7 +19514,8 @@ impl Default for DVD_MUA_Coeff {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DVD_MUA_MixingInfo {
     pub fMixTo0: super::super::Foundation::BOOL,
     pub fMixTo1: super::super::Foundation::BOOL,
@@ -19184,7 +19532,8 @@ impl Default for DVD_MUA_MixingInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DVD_MenuAttributes {
     pub fCompatibleRegion: [super::super::Foundation::BOOL; 8],
     pub VideoAttributes: DVD_VideoAttributes,
@@ -19202,7 +19551,8 @@ impl Default for DVD_MenuAttributes {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DVD_MultichannelAudioAttributes {
     pub Info: [DVD_MUA_MixingInfo; 8],
     pub Coeff: [DVD_MUA_Coeff; 8],
@@ -19216,7 +19566,8 @@ impl Default for DVD_MultichannelAudioAttributes {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DVD_PLAYBACK_LOCATION {
     pub TitleNum: u32,
     pub ChapterNum: u32,
@@ -19231,7 +19582,8 @@ impl Default for DVD_PLAYBACK_LOCATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DVD_PLAYBACK_LOCATION2 {
     pub TitleNum: u32,
     pub ChapterNum: u32,
@@ -19247,7 +19599,8 @@ impl Default for DVD_PLAYBACK_LOCATION2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DVD_REGION {
     pub CopySystem: u8,
     pub RegionData: u8,
@@ -19263,7 +19616,8 @@ impl Default for DVD_REGION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DVD_SubpictureAttributes {
     pub Type: DVD_SUBPICTURE_TYPE,
     pub CodingMode: DVD_SUBPICTURE_CODING,
@@ -19279,10 +19633,67 @@ impl Default for DVD_SubpictureAttributes {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DVD_TIMECODE {
     pub _bitfield: u32,
 }
+impl DVD_TIMECODE {
+    pub const fn Hours1(&self) -> u32 {
+        self._bitfield & 15
+    }
+    pub fn set_Hours1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn Hours10(&self) -> u32 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_Hours10(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+    pub const fn Minutes1(&self) -> u32 {
+        (self._bitfield >> 8) & 15
+    }
+    pub fn set_Minutes1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 8)) | ((value & 15) << 8);
+    }
+    pub const fn Minutes10(&self) -> u32 {
+        (self._bitfield >> 12) & 15
+    }
+    pub fn set_Minutes10(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 12)) | ((value & 15) << 12);
+    }
+    pub const fn Seconds1(&self) -> u32 {
+        (self._bitfield >> 16) & 15
+    }
+    pub fn set_Seconds1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 16)) | ((value & 15) << 16);
+    }
+    pub const fn Seconds10(&self) -> u32 {
+        (self._bitfield >> 20) & 15
+    }
+    pub fn set_Seconds10(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 20)) | ((value & 15) << 20);
+    }
+    pub const fn Frames1(&self) -> u32 {
+        (self._bitfield >> 24) & 15
+    }
+    pub fn set_Frames1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 24)) | ((value & 15) << 24);
+    }
+    pub const fn Frames10(&self) -> u32 {
+        (self._bitfield >> 28) & 3
+    }
+    pub fn set_Frames10(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 28)) | ((value & 3) << 28);
+    }
+    pub const fn FrameRateCode(&self) -> u32 {
+        (self._bitfield >> 30) & 3
+    }
+    pub fn set_FrameRateCode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 30)) | ((value & 3) << 30);
+    }
+}
 impl windows_core::TypeKind for DVD_TIMECODE {
     type TypeKind = windows_core::CopyType;
 }
@@ -19325,7 +19736,8 @@ impl Default for DVD_TitleAttributes_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DVD_VideoAttributes {
     pub fPanscanPermitted: super::super::Foundation::BOOL,
     pub fLetterboxPermitted: super::super::Foundation::BOOL,
@@ -19350,7 +19762,8 @@ impl Default for DVD_VideoAttributes {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DVINFO {
     pub dwDVAAuxSrc: u32,
     pub dwDVAAuxCtl: u32,
@@ -19370,7 +19783,8 @@ impl Default for DVINFO {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Media_MediaFoundation"))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA2SW_CALLBACKS {
     pub Size: u32,
     pub GetVideoProcessorRenderTargetCount: PDXVA2SW_GETVIDEOPROCESSORRENDERTARGETCOUNT,
@@ -19592,7 +20006,8 @@ impl Default for DXVA2_VIDEOSAMPLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA_COPPSetProtectionLevelCmdData {
     pub ProtType: u32,
     pub ProtLevel: u32,
@@ -19608,7 +20023,8 @@ impl Default for DXVA_COPPSetProtectionLevelCmdData {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA_COPPSetSignalingCmdData {
     pub ActiveTVProtectionStandard: u32,
     pub AspectRatioChangeMask1: u32,
@@ -19630,7 +20046,8 @@ impl Default for DXVA_COPPSetSignalingCmdData {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA_COPPStatusData {
     pub rApp: windows_core::GUID,
     pub dwFlags: u32,
@@ -19647,7 +20064,8 @@ impl Default for DXVA_COPPStatusData {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA_COPPStatusDisplayData {
     pub rApp: windows_core::GUID,
     pub dwFlags: u32,
@@ -19667,7 +20085,8 @@ impl Default for DXVA_COPPStatusDisplayData {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA_COPPStatusHDCPKeyData {
     pub rApp: windows_core::GUID,
     pub dwFlags: u32,
@@ -19685,7 +20104,8 @@ impl Default for DXVA_COPPStatusHDCPKeyData {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA_COPPStatusSignalingCmdData {
     pub rApp: windows_core::GUID,
     pub dwFlags: u32,
@@ -19710,7 +20130,8 @@ impl Default for DXVA_COPPStatusSignalingCmdData {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EALocationCodeType {
     pub LocationCodeScheme: LocationCodeSchemeType,
     pub state_code: u8,
@@ -19726,7 +20147,8 @@ impl Default for EALocationCodeType {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILTER_INFO {
     pub achName: [u16; 128],
     pub pGraph: core::mem::ManuallyDrop<Option<IFilterGraph>>,
@@ -19784,7 +20206,8 @@ impl Default for HEAACWAVEINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KS_BDA_FRAME_INFO {
     pub ExtendedHeaderSize: u32,
     pub dwFrameFlags: u32,
@@ -19826,7 +20249,8 @@ impl Default for MPEG1WAVEFORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MPEG2_TRANSPORT_STRIDE {
     pub dwOffset: u32,
     pub dwPacketLength: u32,
@@ -19862,7 +20286,8 @@ impl Default for MPEGLAYER3WAVEFORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MP_ENVELOPE_SEGMENT {
     pub rtStart: i64,
     pub rtEnd: i64,
@@ -19880,7 +20305,8 @@ impl Default for MP_ENVELOPE_SEGMENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MP_PARAMINFO {
     pub mpType: MP_TYPE,
     pub mopCaps: u32,
@@ -19899,7 +20325,8 @@ impl Default for MP_PARAMINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MainAVIHeader {
     pub dwMicroSecPerFrame: u32,
     pub dwMaxBytesPerSec: u32,
@@ -19922,7 +20349,8 @@ impl Default for MainAVIHeader {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NORMALIZEDRECT {
     pub left: f32,
     pub top: f32,
@@ -19938,7 +20366,8 @@ impl Default for NORMALIZEDRECT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PID_MAP {
     pub ulPID: u32,
     pub MediaSampleContent: MEDIA_SAMPLE_CONTENT,
@@ -19953,7 +20382,8 @@ impl Default for PID_MAP {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PIN_INFO {
     pub pFilter: core::mem::ManuallyDrop<Option<IBaseFilter>>,
     pub dir: PIN_DIRECTION,
@@ -19976,7 +20406,8 @@ impl Default for PIN_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Quality {
     pub Type: QualityMessageType,
     pub Proportion: i32,
@@ -19992,7 +20423,8 @@ impl Default for Quality {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REGFILTER {
     pub Clsid: windows_core::GUID,
     pub Name: windows_core::PWSTR,
@@ -20035,7 +20467,8 @@ impl Default for REGFILTER2_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REGFILTER2_0_0 {
     pub cPins: u32,
     pub rgPins: *const REGFILTERPINS,
@@ -20049,7 +20482,8 @@ impl Default for REGFILTER2_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REGFILTER2_0_1 {
     pub cPins2: u32,
     pub rgPins2: *const REGFILTERPINS2,
@@ -20063,7 +20497,8 @@ impl Default for REGFILTER2_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REGFILTERPINS {
     pub strName: windows_core::PWSTR,
     pub bRendered: super::super::Foundation::BOOL,
@@ -20084,7 +20519,8 @@ impl Default for REGFILTERPINS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REGFILTERPINS2 {
     pub dwFlags: u32,
     pub cInstances: u32,
@@ -20103,7 +20539,8 @@ impl Default for REGFILTERPINS2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REGPINMEDIUM {
     pub clsMedium: windows_core::GUID,
     pub dw1: u32,
@@ -20118,7 +20555,8 @@ impl Default for REGPINMEDIUM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REGPINTYPES {
     pub clsMajorType: *const windows_core::GUID,
     pub clsMinorType: *const windows_core::GUID,
@@ -20161,7 +20599,8 @@ impl Default for RIFFLIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STREAM_ID_MAP {
     pub stream_id: u32,
     pub dwMediaSampleContent: u32,
@@ -20177,7 +20616,8 @@ impl Default for STREAM_ID_MAP {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SmartCardApplication {
     pub ApplicationType: ApplicationTypeType,
     pub ApplicationVersion: u16,
@@ -20214,7 +20654,8 @@ impl Default for TIMECODEDATA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TRUECOLORINFO {
     pub dwBitMasks: [u32; 3],
     pub bmiColors: [super::super::Graphics::Gdi::RGBQUAD; 256],
@@ -20230,7 +20671,8 @@ impl Default for TRUECOLORINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VFW_FILTERLIST {
     pub cFilters: u32,
     pub aClsId: [windows_core::GUID; 1],
@@ -20284,7 +20726,8 @@ impl Default for VIDEOINFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEO_STREAM_CONFIG_CAPS {
     pub guid: windows_core::GUID,
     pub VideoStandard: u32,
@@ -20318,7 +20761,8 @@ impl Default for VIDEO_STREAM_CONFIG_CAPS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VMR9AllocationInfo {
     pub dwFlags: u32,
     pub dwWidth: u32,
@@ -20341,7 +20785,8 @@ impl Default for VMR9AllocationInfo {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_Gdi"))]
-#[derive(Debug, PartialEq)]
+#[derive(PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VMR9AlphaBitmap {
     pub dwFlags: u32,
     pub hdc: super::super::Graphics::Gdi::HDC,
@@ -20369,7 +20814,8 @@ impl Default for VMR9AlphaBitmap {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VMR9DeinterlaceCaps {
     pub dwSize: u32,
     pub dwNumPreviousOutputFrames: u32,
@@ -20386,7 +20832,8 @@ impl Default for VMR9DeinterlaceCaps {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VMR9Frequency {
     pub dwNumerator: u32,
     pub dwDenominator: u32,
@@ -20401,7 +20848,8 @@ impl Default for VMR9Frequency {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VMR9MonitorInfo {
     pub uDevID: u32,
     pub rcMonitor: super::super::Foundation::RECT,
@@ -20426,7 +20874,8 @@ impl Default for VMR9MonitorInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VMR9NormalizedRect {
     pub left: f32,
     pub top: f32,
@@ -20443,7 +20892,8 @@ impl Default for VMR9NormalizedRect {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VMR9PresentationInfo {
     pub dwFlags: u32,
     pub lpSurf: core::mem::ManuallyDrop<Option<super::super::Graphics::Direct3D9::IDirect3DSurface9>>,
@@ -20472,7 +20922,8 @@ impl Default for VMR9PresentationInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VMR9ProcAmpControl {
     pub dwSize: u32,
     pub dwFlags: u32,
@@ -20490,7 +20941,8 @@ impl Default for VMR9ProcAmpControl {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VMR9ProcAmpControlRange {
     pub dwSize: u32,
     pub dwProperty: VMR9ProcAmpControlFlags,
@@ -20508,7 +20960,8 @@ impl Default for VMR9ProcAmpControlRange {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VMR9VideoDesc {
     pub dwSize: u32,
     pub dwSampleWidth: u32,
@@ -20528,7 +20981,8 @@ impl Default for VMR9VideoDesc {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Debug, PartialEq)]
+#[derive(PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VMR9VideoStreamInfo {
     pub pddsVideoSurface: core::mem::ManuallyDrop<Option<super::super::Graphics::Direct3D9::IDirect3DSurface9>>,
     pub dwWidth: u32,
@@ -20558,7 +21012,8 @@ impl Default for VMR9VideoStreamInfo {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VMRALLOCATIONINFO {
     pub dwFlags: u32,
     pub lpHdr: *mut super::super::Graphics::Gdi::BITMAPINFOHEADER,
@@ -20581,7 +21036,8 @@ impl Default for VMRALLOCATIONINFO {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi"))]
-#[derive(Debug, PartialEq)]
+#[derive(PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VMRALPHABITMAP {
     pub dwFlags: u32,
     pub hdc: super::super::Graphics::Gdi::HDC,
@@ -20608,7 +21064,8 @@ impl Default for VMRALPHABITMAP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VMRDeinterlaceCaps {
     pub dwSize: u32,
     pub dwNumPreviousOutputFrames: u32,
@@ -20625,7 +21082,8 @@ impl Default for VMRDeinterlaceCaps {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VMRFrequency {
     pub dwNumerator: u32,
     pub dwDenominator: u32,
@@ -20639,7 +21097,8 @@ impl Default for VMRFrequency {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VMRGUID {
     pub pGUID: *mut windows_core::GUID,
     pub GUID: windows_core::GUID,
@@ -20654,7 +21113,8 @@ impl Default for VMRGUID {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VMRMONITORINFO {
     pub guid: VMRGUID,
     pub rcMonitor: super::super::Foundation::RECT,
@@ -20680,7 +21140,8 @@ impl Default for VMRMONITORINFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_DirectDraw")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VMRPRESENTATIONINFO {
     pub dwFlags: u32,
     pub lpSurf: core::mem::ManuallyDrop<Option<super::super::Graphics::DirectDraw::IDirectDrawSurface7>>,
@@ -20710,7 +21171,8 @@ impl Default for VMRPRESENTATIONINFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_DirectDraw")]
-#[derive(Debug, PartialEq)]
+#[derive(PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VMRVIDEOSTREAMINFO {
     pub pddsVideoSurface: core::mem::ManuallyDrop<Option<super::super::Graphics::DirectDraw::IDirectDrawSurface7>>,
     pub dwWidth: u32,
@@ -20737,7 +21199,8 @@ impl Default for VMRVIDEOSTREAMINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VMRVideoDesc {
     pub dwSize: u32,
     pub dwSampleWidth: u32,
@@ -20782,3 +21245,5 @@ pub type PDXVA2SW_VIDEOPROCESSENDFRAME = Option<unsafe extern "system" fn(hdevic
 pub type PDXVA2SW_VIDEOPROCESSSETRENDERTARGET = Option<unsafe extern "system" fn(hdevice: super::super::Foundation::HANDLE, prendertarget: Option<super::super::Graphics::Direct3D9::IDirect3DSurface9>) -> windows_core::HRESULT>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,9898 @@
+impl windows_core::imp::ProxyVtable for IAMAnalogVideoDecoder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AvailableTVFormats(this: *mut core::ffi::c_void, lanalogvideostandard: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAnalogVideoDecoder", "AvailableTVFormats", 3usize, |this| ((**(this as *const *const IAMAnalogVideoDecoder_Vtbl)).AvailableTVFormats)(this, lanalogvideostandard), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTVFormat(this: *mut core::ffi::c_void, lanalogvideostandard: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAnalogVideoDecoder", "SetTVFormat", 4usize, |this| ((**(this as *const *const IAMAnalogVideoDecoder_Vtbl)).SetTVFormat)(this, lanalogvideostandard), |result| Some(*result))
+        }
+        unsafe extern "system" fn TVFormat(this: *mut core::ffi::c_void, planalogvideostandard: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAnalogVideoDecoder", "TVFormat", 5usize, |this| ((**(this as *const *const IAMAnalogVideoDecoder_Vtbl)).TVFormat)(this, planalogvideostandard), |result| Some(*result))
+        }
+        unsafe extern "system" fn HorizontalLocked(this: *mut core::ffi::c_void, pllocked: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAnalogVideoDecoder", "HorizontalLocked", 6usize, |this| ((**(this as *const *const IAMAnalogVideoDecoder_Vtbl)).HorizontalLocked)(this, pllocked), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVCRHorizontalLocking(this: *mut core::ffi::c_void, lvcrhorizontallocking: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAnalogVideoDecoder", "SetVCRHorizontalLocking", 7usize, |this| ((**(this as *const *const IAMAnalogVideoDecoder_Vtbl)).SetVCRHorizontalLocking)(this, lvcrhorizontallocking), |result| Some(*result))
+        }
+        unsafe extern "system" fn VCRHorizontalLocking(this: *mut core::ffi::c_void, plvcrhorizontallocking: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAnalogVideoDecoder", "VCRHorizontalLocking", 8usize, |this| ((**(this as *const *const IAMAnalogVideoDecoder_Vtbl)).VCRHorizontalLocking)(this, plvcrhorizontallocking), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfLines(this: *mut core::ffi::c_void, plnumberoflines: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAnalogVideoDecoder", "NumberOfLines", 9usize, |this| ((**(this as *const *const IAMAnalogVideoDecoder_Vtbl)).NumberOfLines)(this, plnumberoflines), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputEnable(this: *mut core::ffi::c_void, loutputenable: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAnalogVideoDecoder", "SetOutputEnable", 10usize, |this| ((**(this as *const *const IAMAnalogVideoDecoder_Vtbl)).SetOutputEnable)(this, loutputenable), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutputEnable(this: *mut core::ffi::c_void, ploutputenable: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAnalogVideoDecoder", "OutputEnable", 11usize, |this| ((**(this as *const *const IAMAnalogVideoDecoder_Vtbl)).OutputEnable)(this, ploutputenable), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AvailableTVFormats,
+            SetTVFormat,
+            TVFormat,
+            HorizontalLocked,
+            SetVCRHorizontalLocking,
+            VCRHorizontalLocking,
+            NumberOfLines,
+            SetOutputEnable,
+            OutputEnable,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMAnalogVideoDecoder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMAnalogVideoEncoder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AvailableTVFormats(this: *mut core::ffi::c_void, lanalogvideostandard: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAnalogVideoEncoder", "AvailableTVFormats", 3usize, |this| ((**(this as *const *const IAMAnalogVideoEncoder_Vtbl)).AvailableTVFormats)(this, lanalogvideostandard), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTVFormat(this: *mut core::ffi::c_void, lanalogvideostandard: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAnalogVideoEncoder", "SetTVFormat", 4usize, |this| ((**(this as *const *const IAMAnalogVideoEncoder_Vtbl)).SetTVFormat)(this, lanalogvideostandard), |result| Some(*result))
+        }
+        unsafe extern "system" fn TVFormat(this: *mut core::ffi::c_void, planalogvideostandard: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAnalogVideoEncoder", "TVFormat", 5usize, |this| ((**(this as *const *const IAMAnalogVideoEncoder_Vtbl)).TVFormat)(this, planalogvideostandard), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCopyProtection(this: *mut core::ffi::c_void, lvideocopyprotection: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAnalogVideoEncoder", "SetCopyProtection", 6usize, |this| ((**(this as *const *const IAMAnalogVideoEncoder_Vtbl)).SetCopyProtection)(this, lvideocopyprotection), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyProtection(this: *mut core::ffi::c_void, lvideocopyprotection: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAnalogVideoEncoder", "CopyProtection", 7usize, |this| ((**(this as *const *const IAMAnalogVideoEncoder_Vtbl)).CopyProtection)(this, lvideocopyprotection), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCCEnable(this: *mut core::ffi::c_void, lccenable: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAnalogVideoEncoder", "SetCCEnable", 8usize, |this| ((**(this as *const *const IAMAnalogVideoEncoder_Vtbl)).SetCCEnable)(this, lccenable), |result| Some(*result))
+        }
+        unsafe extern "system" fn CCEnable(this: *mut core::ffi::c_void, lccenable: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAnalogVideoEncoder", "CCEnable", 9usize, |this| ((**(this as *const *const IAMAnalogVideoEncoder_Vtbl)).CCEnable)(this, lccenable), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AvailableTVFormats,
+            SetTVFormat,
+            TVFormat,
+            SetCopyProtection,
+            CopyProtection,
+            SetCCEnable,
+            CCEnable,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMAnalogVideoEncoder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMAsyncReaderTimestampScaling_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTimestampMode(this: *mut core::ffi::c_void, pfraw: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAsyncReaderTimestampScaling", "GetTimestampMode", 3usize, |this| ((**(this as *const *const IAMAsyncReaderTimestampScaling_Vtbl)).GetTimestampMode)(this, pfraw), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTimestampMode(this: *mut core::ffi::c_void, fraw: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAsyncReaderTimestampScaling", "SetTimestampMode", 4usize, |this| ((**(this as *const *const IAMAsyncReaderTimestampScaling_Vtbl)).SetTimestampMode)(this, fraw), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetTimestampMode, SetTimestampMode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMAsyncReaderTimestampScaling as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMAudioInputMixer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetEnable(this: *mut core::ffi::c_void, fenable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAudioInputMixer", "SetEnable", 3usize, |this| ((**(this as *const *const IAMAudioInputMixer_Vtbl)).SetEnable)(this, fenable), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enable(this: *mut core::ffi::c_void, pfenable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAudioInputMixer", "Enable", 4usize, |this| ((**(this as *const *const IAMAudioInputMixer_Vtbl)).Enable)(this, pfenable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMono(this: *mut core::ffi::c_void, fmono: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAudioInputMixer", "SetMono", 5usize, |this| ((**(this as *const *const IAMAudioInputMixer_Vtbl)).SetMono)(this, fmono), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mono(this: *mut core::ffi::c_void, pfmono: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAudioInputMixer", "Mono", 6usize, |this| ((**(this as *const *const IAMAudioInputMixer_Vtbl)).Mono)(this, pfmono), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMixLevel(this: *mut core::ffi::c_void, level: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAudioInputMixer", "SetMixLevel", 7usize, |this| ((**(this as *const *const IAMAudioInputMixer_Vtbl)).SetMixLevel)(this, level), |result| Some(*result))
+        }
+        unsafe extern "system" fn MixLevel(this: *mut core::ffi::c_void, plevel: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAudioInputMixer", "MixLevel", 8usize, |this| ((**(this as *const *const IAMAudioInputMixer_Vtbl)).MixLevel)(this, plevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPan(this: *mut core::ffi::c_void, pan: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAudioInputMixer", "SetPan", 9usize, |this| ((**(this as *const *const IAMAudioInputMixer_Vtbl)).SetPan)(this, pan), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pan(this: *mut core::ffi::c_void, ppan: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAudioInputMixer", "Pan", 10usize, |this| ((**(this as *const *const IAMAudioInputMixer_Vtbl)).Pan)(this, ppan), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLoudness(this: *mut core::ffi::c_void, floudness: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAudioInputMixer", "SetLoudness", 11usize, |this| ((**(this as *const *const IAMAudioInputMixer_Vtbl)).SetLoudness)(this, floudness), |result| Some(*result))
+        }
+        unsafe extern "system" fn Loudness(this: *mut core::ffi::c_void, pfloudness: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAudioInputMixer", "Loudness", 12usize, |this| ((**(this as *const *const IAMAudioInputMixer_Vtbl)).Loudness)(this, pfloudness), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTreble(this: *mut core::ffi::c_void, treble: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAudioInputMixer", "SetTreble", 13usize, |this| ((**(this as *const *const IAMAudioInputMixer_Vtbl)).SetTreble)(this, treble), |result| Some(*result))
+        }
+        unsafe extern "system" fn Treble(this: *mut core::ffi::c_void, ptreble: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAudioInputMixer", "Treble", 14usize, |this| ((**(this as *const *const IAMAudioInputMixer_Vtbl)).Treble)(this, ptreble), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrebleRange(this: *mut core::ffi::c_void, prange: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAudioInputMixer", "TrebleRange", 15usize, |this| ((**(this as *const *const IAMAudioInputMixer_Vtbl)).TrebleRange)(this, prange), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBass(this: *mut core::ffi::c_void, bass: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAudioInputMixer", "SetBass", 16usize, |this| ((**(this as *const *const IAMAudioInputMixer_Vtbl)).SetBass)(this, bass), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bass(this: *mut core::ffi::c_void, pbass: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAudioInputMixer", "Bass", 17usize, |this| ((**(this as *const *const IAMAudioInputMixer_Vtbl)).Bass)(this, pbass), |result| Some(*result))
+        }
+        unsafe extern "system" fn BassRange(this: *mut core::ffi::c_void, prange: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAudioInputMixer", "BassRange", 18usize, |this| ((**(this as *const *const IAMAudioInputMixer_Vtbl)).BassRange)(this, prange), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetEnable,
+            Enable,
+            SetMono,
+            Mono,
+            SetMixLevel,
+            MixLevel,
+            SetPan,
+            Pan,
+            SetLoudness,
+            Loudness,
+            SetTreble,
+            Treble,
+            TrebleRange,
+            SetBass,
+            Bass,
+            BassRange,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMAudioInputMixer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMAudioRendererStats_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStatParam(this: *mut core::ffi::c_void, dwparam: u32, pdwparam1: *mut u32, pdwparam2: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMAudioRendererStats", "GetStatParam", 3usize, |this| ((**(this as *const *const IAMAudioRendererStats_Vtbl)).GetStatParam)(this, dwparam, pdwparam1, pdwparam2), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStatParam }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMAudioRendererStats as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMBufferNegotiation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SuggestAllocatorProperties(this: *mut core::ffi::c_void, pprop: *const ALLOCATOR_PROPERTIES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMBufferNegotiation", "SuggestAllocatorProperties", 3usize, |this| ((**(this as *const *const IAMBufferNegotiation_Vtbl)).SuggestAllocatorProperties)(this, pprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllocatorProperties(this: *mut core::ffi::c_void, pprop: *mut ALLOCATOR_PROPERTIES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMBufferNegotiation", "GetAllocatorProperties", 4usize, |this| ((**(this as *const *const IAMBufferNegotiation_Vtbl)).GetAllocatorProperties)(this, pprop), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SuggestAllocatorProperties, GetAllocatorProperties }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMBufferNegotiation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMCameraControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRange(this: *mut core::ffi::c_void, property: i32, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMCameraControl", "GetRange", 3usize, |this| ((**(this as *const *const IAMCameraControl_Vtbl)).GetRange)(this, property, pmin, pmax, psteppingdelta, pdefault, pcapsflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Set(this: *mut core::ffi::c_void, property: i32, lvalue: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMCameraControl", "Set", 4usize, |this| ((**(this as *const *const IAMCameraControl_Vtbl)).Set)(this, property, lvalue, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Get(this: *mut core::ffi::c_void, property: i32, lvalue: *mut i32, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMCameraControl", "Get", 5usize, |this| ((**(this as *const *const IAMCameraControl_Vtbl)).Get)(this, property, lvalue, flags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRange, Set, Get }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMCameraControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMCertifiedOutputProtection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn KeyExchange(this: *mut core::ffi::c_void, prandom: *mut windows_core::GUID, varlencertgh: *mut *mut u8, pdwlengthcertgh: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMCertifiedOutputProtection", "KeyExchange", 3usize, |this| ((**(this as *const *const IAMCertifiedOutputProtection_Vtbl)).KeyExchange)(this, prandom, varlencertgh, pdwlengthcertgh), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionSequenceStart(this: *mut core::ffi::c_void, psig: *const AMCOPPSignature) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMCertifiedOutputProtection", "SessionSequenceStart", 4usize, |this| ((**(this as *const *const IAMCertifiedOutputProtection_Vtbl)).SessionSequenceStart)(this, psig), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtectionCommand(this: *mut core::ffi::c_void, cmd: *const AMCOPPCommand) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMCertifiedOutputProtection", "ProtectionCommand", 5usize, |this| ((**(this as *const *const IAMCertifiedOutputProtection_Vtbl)).ProtectionCommand)(this, cmd), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtectionStatus(this: *mut core::ffi::c_void, pstatusinput: *const AMCOPPStatusInput, pstatusoutput: *mut AMCOPPStatusOutput) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMCertifiedOutputProtection", "ProtectionStatus", 6usize, |this| ((**(this as *const *const IAMCertifiedOutputProtection_Vtbl)).ProtectionStatus)(this, pstatusinput, pstatusoutput), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            KeyExchange,
+            SessionSequenceStart,
+            ProtectionCommand,
+            ProtectionStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMCertifiedOutputProtection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAMChannelInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChannelName(this: *mut core::ffi::c_void, pbstrchannelname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMChannelInfo", "ChannelName", 7usize, |this| ((**(this as *const *const IAMChannelInfo_Vtbl)).ChannelName)(this, pbstrchannelname), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChannelDescription(this: *mut core::ffi::c_void, pbstrchanneldescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMChannelInfo", "ChannelDescription", 8usize, |this| ((**(this as *const *const IAMChannelInfo_Vtbl)).ChannelDescription)(this, pbstrchanneldescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChannelURL(this: *mut core::ffi::c_void, pbstrchannelurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMChannelInfo", "ChannelURL", 9usize, |this| ((**(this as *const *const IAMChannelInfo_Vtbl)).ChannelURL)(this, pbstrchannelurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContactAddress(this: *mut core::ffi::c_void, pbstrcontactaddress: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMChannelInfo", "ContactAddress", 10usize, |this| ((**(this as *const *const IAMChannelInfo_Vtbl)).ContactAddress)(this, pbstrcontactaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContactPhone(this: *mut core::ffi::c_void, pbstrcontactphone: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMChannelInfo", "ContactPhone", 11usize, |this| ((**(this as *const *const IAMChannelInfo_Vtbl)).ContactPhone)(this, pbstrcontactphone), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContactEmail(this: *mut core::ffi::c_void, pbstrcontactemail: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMChannelInfo", "ContactEmail", 12usize, |this| ((**(this as *const *const IAMChannelInfo_Vtbl)).ContactEmail)(this, pbstrcontactemail), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ChannelName,
+            ChannelDescription,
+            ChannelURL,
+            ContactAddress,
+            ContactPhone,
+            ContactEmail,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMChannelInfo as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMClockAdjust_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetClockDelta(this: *mut core::ffi::c_void, rtdelta: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMClockAdjust", "SetClockDelta", 3usize, |this| ((**(this as *const *const IAMClockAdjust_Vtbl)).SetClockDelta)(this, rtdelta), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetClockDelta }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMClockAdjust as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMClockSlave_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetErrorTolerance(this: *mut core::ffi::c_void, dwtolerance: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMClockSlave", "SetErrorTolerance", 3usize, |this| ((**(this as *const *const IAMClockSlave_Vtbl)).SetErrorTolerance)(this, dwtolerance), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetErrorTolerance(this: *mut core::ffi::c_void, pdwtolerance: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMClockSlave", "GetErrorTolerance", 4usize, |this| ((**(this as *const *const IAMClockSlave_Vtbl)).GetErrorTolerance)(this, pdwtolerance), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetErrorTolerance, GetErrorTolerance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMClockSlave as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAMCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMCollection", "Count", 7usize, |this| ((**(this as *const *const IAMCollection_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, litem: i32, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMCollection", "Item", 8usize, |this| ((**(this as *const *const IAMCollection_Vtbl)).Item)(this, litem, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const IAMCollection_Vtbl))._NewEnum)(this, ppunk), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Count, Item, _NewEnum }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMCollection as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMCopyCaptureFileProgress_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Progress(this: *mut core::ffi::c_void, iprogress: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMCopyCaptureFileProgress", "Progress", 3usize, |this| ((**(this as *const *const IAMCopyCaptureFileProgress_Vtbl)).Progress)(this, iprogress), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Progress }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMCopyCaptureFileProgress as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMCrossbar_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_PinCounts(this: *mut core::ffi::c_void, outputpincount: *mut i32, inputpincount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMCrossbar", "get_PinCounts", 3usize, |this| ((**(this as *const *const IAMCrossbar_Vtbl)).get_PinCounts)(this, outputpincount, inputpincount), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanRoute(this: *mut core::ffi::c_void, outputpinindex: i32, inputpinindex: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMCrossbar", "CanRoute", 4usize, |this| ((**(this as *const *const IAMCrossbar_Vtbl)).CanRoute)(this, outputpinindex, inputpinindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn Route(this: *mut core::ffi::c_void, outputpinindex: i32, inputpinindex: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMCrossbar", "Route", 5usize, |this| ((**(this as *const *const IAMCrossbar_Vtbl)).Route)(this, outputpinindex, inputpinindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_IsRoutedTo(this: *mut core::ffi::c_void, outputpinindex: i32, inputpinindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMCrossbar", "get_IsRoutedTo", 6usize, |this| ((**(this as *const *const IAMCrossbar_Vtbl)).get_IsRoutedTo)(this, outputpinindex, inputpinindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_CrossbarPinInfo(this: *mut core::ffi::c_void, isinputpin: super::super::Foundation::BOOL, pinindex: i32, pinindexrelated: *mut i32, physicaltype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMCrossbar", "get_CrossbarPinInfo", 7usize, |this| ((**(this as *const *const IAMCrossbar_Vtbl)).get_CrossbarPinInfo)(this, isinputpin, pinindex, pinindexrelated, physicaltype), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            get_PinCounts,
+            CanRoute,
+            Route,
+            get_IsRoutedTo,
+            get_CrossbarPinInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMCrossbar as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMDecoderCaps_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDecoderCaps(this: *mut core::ffi::c_void, dwcapindex: u32, lpdwcap: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMDecoderCaps", "GetDecoderCaps", 3usize, |this| ((**(this as *const *const IAMDecoderCaps_Vtbl)).GetDecoderCaps)(this, dwcapindex, lpdwcap), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDecoderCaps }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMDecoderCaps as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMDevMemoryAllocator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInfo(this: *mut core::ffi::c_void, pdwcbtotalfree: *mut u32, pdwcblargestfree: *mut u32, pdwcbtotalmemory: *mut u32, pdwcbminimumchunk: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMDevMemoryAllocator", "GetInfo", 3usize, |this| ((**(this as *const *const IAMDevMemoryAllocator_Vtbl)).GetInfo)(this, pdwcbtotalfree, pdwcblargestfree, pdwcbtotalmemory, pdwcbminimumchunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckMemory(this: *mut core::ffi::c_void, pbuffer: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMDevMemoryAllocator", "CheckMemory", 4usize, |this| ((**(this as *const *const IAMDevMemoryAllocator_Vtbl)).CheckMemory)(this, pbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn Alloc(this: *mut core::ffi::c_void, ppbuffer: *mut *mut u8, pdwcbbuffer: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMDevMemoryAllocator", "Alloc", 5usize, |this| ((**(this as *const *const IAMDevMemoryAllocator_Vtbl)).Alloc)(this, ppbuffer, pdwcbbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn Free(this: *mut core::ffi::c_void, pbuffer: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMDevMemoryAllocator", "Free", 6usize, |this| ((**(this as *const *const IAMDevMemoryAllocator_Vtbl)).Free)(this, pbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDevMemoryObject(this: *mut core::ffi::c_void, ppunkinnner: *mut *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMDevMemoryAllocator", "GetDevMemoryObject", 7usize, |this| ((**(this as *const *const IAMDevMemoryAllocator_Vtbl)).GetDevMemoryObject)(this, ppunkinnner, punkouter), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetInfo, CheckMemory, Alloc, Free, GetDevMemoryObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMDevMemoryAllocator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMDevMemoryControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryWriteSync(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMDevMemoryControl", "QueryWriteSync", 3usize, |this| ((**(this as *const *const IAMDevMemoryControl_Vtbl)).QueryWriteSync)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteSync(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMDevMemoryControl", "WriteSync", 4usize, |this| ((**(this as *const *const IAMDevMemoryControl_Vtbl)).WriteSync)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDevId(this: *mut core::ffi::c_void, pdwdevid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMDevMemoryControl", "GetDevId", 5usize, |this| ((**(this as *const *const IAMDevMemoryControl_Vtbl)).GetDevId)(this, pdwdevid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryWriteSync, WriteSync, GetDevId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMDevMemoryControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMDeviceRemoval_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceInfo(this: *mut core::ffi::c_void, pclsidinterfaceclass: *mut windows_core::GUID, pwszsymboliclink: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMDeviceRemoval", "DeviceInfo", 3usize, |this| ((**(this as *const *const IAMDeviceRemoval_Vtbl)).DeviceInfo)(this, pclsidinterfaceclass, pwszsymboliclink), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reassociate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMDeviceRemoval", "Reassociate", 4usize, |this| ((**(this as *const *const IAMDeviceRemoval_Vtbl)).Reassociate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disassociate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMDeviceRemoval", "Disassociate", 5usize, |this| ((**(this as *const *const IAMDeviceRemoval_Vtbl)).Disassociate)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeviceInfo, Reassociate, Disassociate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMDeviceRemoval as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_Audio_DirectSound")]
+impl windows_core::imp::ProxyVtable for IAMDirectSound_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_Audio_DirectSound")]
+        unsafe extern "system" fn GetDirectSoundInterface(this: *mut core::ffi::c_void, lplpds: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMDirectSound", "GetDirectSoundInterface", 3usize, |this| ((**(this as *const *const IAMDirectSound_Vtbl)).GetDirectSoundInterface)(this, lplpds), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio_DirectSound")]
+        unsafe extern "system" fn GetPrimaryBufferInterface(this: *mut core::ffi::c_void, lplpdsb: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMDirectSound", "GetPrimaryBufferInterface", 4usize, |this| ((**(this as *const *const IAMDirectSound_Vtbl)).GetPrimaryBufferInterface)(this, lplpdsb), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio_DirectSound")]
+        unsafe extern "system" fn GetSecondaryBufferInterface(this: *mut core::ffi::c_void, lplpdsb: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMDirectSound", "GetSecondaryBufferInterface", 5usize, |this| ((**(this as *const *const IAMDirectSound_Vtbl)).GetSecondaryBufferInterface)(this, lplpdsb), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio_DirectSound")]
+        unsafe extern "system" fn ReleaseDirectSoundInterface(this: *mut core::ffi::c_void, lpds: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMDirectSound", "ReleaseDirectSoundInterface", 6usize, |this| ((**(this as *const *const IAMDirectSound_Vtbl)).ReleaseDirectSoundInterface)(this, lpds), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio_DirectSound")]
+        unsafe extern "system" fn ReleasePrimaryBufferInterface(this: *mut core::ffi::c_void, lpdsb: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMDirectSound", "ReleasePrimaryBufferInterface", 7usize, |this| ((**(this as *const *const IAMDirectSound_Vtbl)).ReleasePrimaryBufferInterface)(this, lpdsb), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio_DirectSound")]
+        unsafe extern "system" fn ReleaseSecondaryBufferInterface(this: *mut core::ffi::c_void, lpdsb: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMDirectSound", "ReleaseSecondaryBufferInterface", 8usize, |this| ((**(this as *const *const IAMDirectSound_Vtbl)).ReleaseSecondaryBufferInterface)(this, lpdsb), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFocusWindow(this: *mut core::ffi::c_void, param0: super::super::Foundation::HWND, param1: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMDirectSound", "SetFocusWindow", 9usize, |this| ((**(this as *const *const IAMDirectSound_Vtbl)).SetFocusWindow)(this, param0, param1), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFocusWindow(this: *mut core::ffi::c_void, param0: *mut super::super::Foundation::HWND, param1: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMDirectSound", "GetFocusWindow", 10usize, |this| ((**(this as *const *const IAMDirectSound_Vtbl)).GetFocusWindow)(this, param0, param1), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_Audio_DirectSound")]
+            GetDirectSoundInterface,
+            #[cfg(not(feature = "Win32_Media_Audio_DirectSound"))]
+            GetDirectSoundInterface: 0,
+            #[cfg(feature = "Win32_Media_Audio_DirectSound")]
+            GetPrimaryBufferInterface,
+            #[cfg(not(feature = "Win32_Media_Audio_DirectSound"))]
+            GetPrimaryBufferInterface: 0,
+            #[cfg(feature = "Win32_Media_Audio_DirectSound")]
+            GetSecondaryBufferInterface,
+            #[cfg(not(feature = "Win32_Media_Audio_DirectSound"))]
+            GetSecondaryBufferInterface: 0,
+            #[cfg(feature = "Win32_Media_Audio_DirectSound")]
+            ReleaseDirectSoundInterface,
+            #[cfg(not(feature = "Win32_Media_Audio_DirectSound"))]
+            ReleaseDirectSoundInterface: 0,
+            #[cfg(feature = "Win32_Media_Audio_DirectSound")]
+            ReleasePrimaryBufferInterface,
+            #[cfg(not(feature = "Win32_Media_Audio_DirectSound"))]
+            ReleasePrimaryBufferInterface: 0,
+            #[cfg(feature = "Win32_Media_Audio_DirectSound")]
+            ReleaseSecondaryBufferInterface,
+            #[cfg(not(feature = "Win32_Media_Audio_DirectSound"))]
+            ReleaseSecondaryBufferInterface: 0,
+            SetFocusWindow,
+            GetFocusWindow,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMDirectSound as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMDroppedFrames_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNumDropped(this: *mut core::ffi::c_void, pldropped: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMDroppedFrames", "GetNumDropped", 3usize, |this| ((**(this as *const *const IAMDroppedFrames_Vtbl)).GetNumDropped)(this, pldropped), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumNotDropped(this: *mut core::ffi::c_void, plnotdropped: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMDroppedFrames", "GetNumNotDropped", 4usize, |this| ((**(this as *const *const IAMDroppedFrames_Vtbl)).GetNumNotDropped)(this, plnotdropped), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDroppedInfo(this: *mut core::ffi::c_void, lsize: i32, plarray: *mut i32, plnumcopied: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMDroppedFrames", "GetDroppedInfo", 5usize, |this| ((**(this as *const *const IAMDroppedFrames_Vtbl)).GetDroppedInfo)(this, lsize, plarray, plnumcopied), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAverageFrameSize(this: *mut core::ffi::c_void, plaveragesize: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMDroppedFrames", "GetAverageFrameSize", 6usize, |this| ((**(this as *const *const IAMDroppedFrames_Vtbl)).GetAverageFrameSize)(this, plaveragesize), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetNumDropped,
+            GetNumNotDropped,
+            GetDroppedInfo,
+            GetAverageFrameSize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMDroppedFrames as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMExtDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCapability(this: *mut core::ffi::c_void, capability: i32, pvalue: *mut i32, pdblvalue: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtDevice", "GetCapability", 3usize, |this| ((**(this as *const *const IAMExtDevice_Vtbl)).GetCapability)(this, capability, pvalue, pdblvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExternalDeviceID(this: *mut core::ffi::c_void, ppszdata: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtDevice", "ExternalDeviceID", 4usize, |this| ((**(this as *const *const IAMExtDevice_Vtbl)).ExternalDeviceID)(this, ppszdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExternalDeviceVersion(this: *mut core::ffi::c_void, ppszdata: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtDevice", "ExternalDeviceVersion", 5usize, |this| ((**(this as *const *const IAMExtDevice_Vtbl)).ExternalDeviceVersion)(this, ppszdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDevicePower(this: *mut core::ffi::c_void, powermode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtDevice", "SetDevicePower", 6usize, |this| ((**(this as *const *const IAMExtDevice_Vtbl)).SetDevicePower)(this, powermode), |result| Some(*result))
+        }
+        unsafe extern "system" fn DevicePower(this: *mut core::ffi::c_void, ppowermode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtDevice", "DevicePower", 7usize, |this| ((**(this as *const *const IAMExtDevice_Vtbl)).DevicePower)(this, ppowermode), |result| Some(*result))
+        }
+        unsafe extern "system" fn Calibrate(this: *mut core::ffi::c_void, hevent: usize, mode: i32, pstatus: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtDevice", "Calibrate", 8usize, |this| ((**(this as *const *const IAMExtDevice_Vtbl)).Calibrate)(this, hevent, mode, pstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDevicePort(this: *mut core::ffi::c_void, deviceport: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtDevice", "SetDevicePort", 9usize, |this| ((**(this as *const *const IAMExtDevice_Vtbl)).SetDevicePort)(this, deviceport), |result| Some(*result))
+        }
+        unsafe extern "system" fn DevicePort(this: *mut core::ffi::c_void, pdeviceport: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtDevice", "DevicePort", 10usize, |this| ((**(this as *const *const IAMExtDevice_Vtbl)).DevicePort)(this, pdeviceport), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCapability,
+            ExternalDeviceID,
+            ExternalDeviceVersion,
+            SetDevicePower,
+            DevicePower,
+            Calibrate,
+            SetDevicePort,
+            DevicePort,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMExtDevice as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMExtTransport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCapability(this: *mut core::ffi::c_void, capability: i32, pvalue: *mut i32, pdblvalue: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtTransport", "GetCapability", 3usize, |this| ((**(this as *const *const IAMExtTransport_Vtbl)).GetCapability)(this, capability, pvalue, pdblvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMediaState(this: *mut core::ffi::c_void, state: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtTransport", "SetMediaState", 4usize, |this| ((**(this as *const *const IAMExtTransport_Vtbl)).SetMediaState)(this, state), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaState(this: *mut core::ffi::c_void, pstate: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtTransport", "MediaState", 5usize, |this| ((**(this as *const *const IAMExtTransport_Vtbl)).MediaState)(this, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocalControl(this: *mut core::ffi::c_void, state: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtTransport", "SetLocalControl", 6usize, |this| ((**(this as *const *const IAMExtTransport_Vtbl)).SetLocalControl)(this, state), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalControl(this: *mut core::ffi::c_void, pstate: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtTransport", "LocalControl", 7usize, |this| ((**(this as *const *const IAMExtTransport_Vtbl)).LocalControl)(this, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, statusitem: i32, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtTransport", "GetStatus", 8usize, |this| ((**(this as *const *const IAMExtTransport_Vtbl)).GetStatus)(this, statusitem, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransportBasicParameters(this: *mut core::ffi::c_void, param: i32, pvalue: *mut i32, ppszdata: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtTransport", "GetTransportBasicParameters", 9usize, |this| ((**(this as *const *const IAMExtTransport_Vtbl)).GetTransportBasicParameters)(this, param, pvalue, ppszdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransportBasicParameters(this: *mut core::ffi::c_void, param: i32, value: i32, pszdata: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtTransport", "SetTransportBasicParameters", 10usize, |this| ((**(this as *const *const IAMExtTransport_Vtbl)).SetTransportBasicParameters)(this, param, value, pszdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransportVideoParameters(this: *mut core::ffi::c_void, param: i32, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtTransport", "GetTransportVideoParameters", 11usize, |this| ((**(this as *const *const IAMExtTransport_Vtbl)).GetTransportVideoParameters)(this, param, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransportVideoParameters(this: *mut core::ffi::c_void, param: i32, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtTransport", "SetTransportVideoParameters", 12usize, |this| ((**(this as *const *const IAMExtTransport_Vtbl)).SetTransportVideoParameters)(this, param, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransportAudioParameters(this: *mut core::ffi::c_void, param: i32, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtTransport", "GetTransportAudioParameters", 13usize, |this| ((**(this as *const *const IAMExtTransport_Vtbl)).GetTransportAudioParameters)(this, param, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransportAudioParameters(this: *mut core::ffi::c_void, param: i32, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtTransport", "SetTransportAudioParameters", 14usize, |this| ((**(this as *const *const IAMExtTransport_Vtbl)).SetTransportAudioParameters)(this, param, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMode(this: *mut core::ffi::c_void, mode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtTransport", "SetMode", 15usize, |this| ((**(this as *const *const IAMExtTransport_Vtbl)).SetMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, pmode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtTransport", "Mode", 16usize, |this| ((**(this as *const *const IAMExtTransport_Vtbl)).Mode)(this, pmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRate(this: *mut core::ffi::c_void, dblrate: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtTransport", "SetRate", 17usize, |this| ((**(this as *const *const IAMExtTransport_Vtbl)).SetRate)(this, dblrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rate(this: *mut core::ffi::c_void, pdblrate: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtTransport", "Rate", 18usize, |this| ((**(this as *const *const IAMExtTransport_Vtbl)).Rate)(this, pdblrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChase(this: *mut core::ffi::c_void, penabled: *mut i32, poffset: *mut i32, phevent: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtTransport", "GetChase", 19usize, |this| ((**(this as *const *const IAMExtTransport_Vtbl)).GetChase)(this, penabled, poffset, phevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetChase(this: *mut core::ffi::c_void, enable: i32, offset: i32, hevent: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtTransport", "SetChase", 20usize, |this| ((**(this as *const *const IAMExtTransport_Vtbl)).SetChase)(this, enable, offset, hevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBump(this: *mut core::ffi::c_void, pspeed: *mut i32, pduration: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtTransport", "GetBump", 21usize, |this| ((**(this as *const *const IAMExtTransport_Vtbl)).GetBump)(this, pspeed, pduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBump(this: *mut core::ffi::c_void, speed: i32, duration: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtTransport", "SetBump", 22usize, |this| ((**(this as *const *const IAMExtTransport_Vtbl)).SetBump)(this, speed, duration), |result| Some(*result))
+        }
+        unsafe extern "system" fn AntiClogControl(this: *mut core::ffi::c_void, penabled: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtTransport", "AntiClogControl", 23usize, |this| ((**(this as *const *const IAMExtTransport_Vtbl)).AntiClogControl)(this, penabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAntiClogControl(this: *mut core::ffi::c_void, enable: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtTransport", "SetAntiClogControl", 24usize, |this| ((**(this as *const *const IAMExtTransport_Vtbl)).SetAntiClogControl)(this, enable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEditPropertySet(this: *mut core::ffi::c_void, editid: i32, pstate: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtTransport", "GetEditPropertySet", 25usize, |this| ((**(this as *const *const IAMExtTransport_Vtbl)).GetEditPropertySet)(this, editid, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEditPropertySet(this: *mut core::ffi::c_void, peditid: *mut i32, state: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtTransport", "SetEditPropertySet", 26usize, |this| ((**(this as *const *const IAMExtTransport_Vtbl)).SetEditPropertySet)(this, peditid, state), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEditProperty(this: *mut core::ffi::c_void, editid: i32, param: i32, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtTransport", "GetEditProperty", 27usize, |this| ((**(this as *const *const IAMExtTransport_Vtbl)).GetEditProperty)(this, editid, param, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEditProperty(this: *mut core::ffi::c_void, editid: i32, param: i32, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtTransport", "SetEditProperty", 28usize, |this| ((**(this as *const *const IAMExtTransport_Vtbl)).SetEditProperty)(this, editid, param, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn EditStart(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtTransport", "EditStart", 29usize, |this| ((**(this as *const *const IAMExtTransport_Vtbl)).EditStart)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEditStart(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtTransport", "SetEditStart", 30usize, |this| ((**(this as *const *const IAMExtTransport_Vtbl)).SetEditStart)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCapability,
+            SetMediaState,
+            MediaState,
+            SetLocalControl,
+            LocalControl,
+            GetStatus,
+            GetTransportBasicParameters,
+            SetTransportBasicParameters,
+            GetTransportVideoParameters,
+            SetTransportVideoParameters,
+            GetTransportAudioParameters,
+            SetTransportAudioParameters,
+            SetMode,
+            Mode,
+            SetRate,
+            Rate,
+            GetChase,
+            SetChase,
+            GetBump,
+            SetBump,
+            AntiClogControl,
+            SetAntiClogControl,
+            GetEditPropertySet,
+            SetEditPropertySet,
+            GetEditProperty,
+            SetEditProperty,
+            EditStart,
+            SetEditStart,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMExtTransport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAMExtendedErrorInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HasError(this: *mut core::ffi::c_void, phaserror: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtendedErrorInfo", "HasError", 7usize, |this| ((**(this as *const *const IAMExtendedErrorInfo_Vtbl)).HasError)(this, phaserror), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorDescription(this: *mut core::ffi::c_void, pbstrerrordescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtendedErrorInfo", "ErrorDescription", 8usize, |this| ((**(this as *const *const IAMExtendedErrorInfo_Vtbl)).ErrorDescription)(this, pbstrerrordescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorCode(this: *mut core::ffi::c_void, perrorcode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtendedErrorInfo", "ErrorCode", 9usize, |this| ((**(this as *const *const IAMExtendedErrorInfo_Vtbl)).ErrorCode)(this, perrorcode), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HasError, ErrorDescription, ErrorCode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMExtendedErrorInfo as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAMExtendedSeeking_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExSeekCapabilities(this: *mut core::ffi::c_void, pexcapabilities: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtendedSeeking", "ExSeekCapabilities", 7usize, |this| ((**(this as *const *const IAMExtendedSeeking_Vtbl)).ExSeekCapabilities)(this, pexcapabilities), |result| Some(*result))
+        }
+        unsafe extern "system" fn MarkerCount(this: *mut core::ffi::c_void, pmarkercount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtendedSeeking", "MarkerCount", 8usize, |this| ((**(this as *const *const IAMExtendedSeeking_Vtbl)).MarkerCount)(this, pmarkercount), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentMarker(this: *mut core::ffi::c_void, pcurrentmarker: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtendedSeeking", "CurrentMarker", 9usize, |this| ((**(this as *const *const IAMExtendedSeeking_Vtbl)).CurrentMarker)(this, pcurrentmarker), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMarkerTime(this: *mut core::ffi::c_void, markernum: i32, pmarkertime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtendedSeeking", "GetMarkerTime", 10usize, |this| ((**(this as *const *const IAMExtendedSeeking_Vtbl)).GetMarkerTime)(this, markernum, pmarkertime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMarkerName(this: *mut core::ffi::c_void, markernum: i32, pbstrmarkername: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtendedSeeking", "GetMarkerName", 11usize, |this| ((**(this as *const *const IAMExtendedSeeking_Vtbl)).GetMarkerName)(this, markernum, pbstrmarkername), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPlaybackSpeed(this: *mut core::ffi::c_void, speed: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtendedSeeking", "SetPlaybackSpeed", 12usize, |this| ((**(this as *const *const IAMExtendedSeeking_Vtbl)).SetPlaybackSpeed)(this, speed), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlaybackSpeed(this: *mut core::ffi::c_void, pspeed: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMExtendedSeeking", "PlaybackSpeed", 13usize, |this| ((**(this as *const *const IAMExtendedSeeking_Vtbl)).PlaybackSpeed)(this, pspeed), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ExSeekCapabilities,
+            MarkerCount,
+            CurrentMarker,
+            GetMarkerTime,
+            GetMarkerName,
+            SetPlaybackSpeed,
+            PlaybackSpeed,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMExtendedSeeking as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMFilterGraphCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UnableToRender(this: *mut core::ffi::c_void, ppin: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMFilterGraphCallback", "UnableToRender", 3usize, |this| ((**(this as *const *const IAMFilterGraphCallback_Vtbl)).UnableToRender)(this, ppin), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UnableToRender }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMFilterGraphCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMFilterMiscFlags_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMiscFlags(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IAMFilterMiscFlags", "GetMiscFlags", 3usize, |this| ((**(this as *const *const IAMFilterMiscFlags_Vtbl)).GetMiscFlags)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMiscFlags }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMFilterMiscFlags as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAMGraphBuilderCallback_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SelectedFilter(this: *mut core::ffi::c_void, pmon: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMGraphBuilderCallback", "SelectedFilter", 3usize, |this| ((**(this as *const *const IAMGraphBuilderCallback_Vtbl)).SelectedFilter)(this, pmon), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreatedFilter(this: *mut core::ffi::c_void, pfil: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMGraphBuilderCallback", "CreatedFilter", 4usize, |this| ((**(this as *const *const IAMGraphBuilderCallback_Vtbl)).CreatedFilter)(this, pfil), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            SelectedFilter,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SelectedFilter: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreatedFilter,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreatedFilter: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMGraphBuilderCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMGraphStreams_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FindUpstreamInterface(this: *mut core::ffi::c_void, ppin: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvinterface: *mut *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMGraphStreams", "FindUpstreamInterface", 3usize, |this| ((**(this as *const *const IAMGraphStreams_Vtbl)).FindUpstreamInterface)(this, ppin, riid, ppvinterface, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncUsingStreamOffset(this: *mut core::ffi::c_void, busestreamoffset: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMGraphStreams", "SyncUsingStreamOffset", 4usize, |this| ((**(this as *const *const IAMGraphStreams_Vtbl)).SyncUsingStreamOffset)(this, busestreamoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxGraphLatency(this: *mut core::ffi::c_void, rtmaxgraphlatency: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMGraphStreams", "SetMaxGraphLatency", 5usize, |this| ((**(this as *const *const IAMGraphStreams_Vtbl)).SetMaxGraphLatency)(this, rtmaxgraphlatency), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FindUpstreamInterface, SyncUsingStreamOffset, SetMaxGraphLatency }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMGraphStreams as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMLatency_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLatency(this: *mut core::ffi::c_void, prtlatency: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMLatency", "GetLatency", 3usize, |this| ((**(this as *const *const IAMLatency_Vtbl)).GetLatency)(this, prtlatency), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetLatency }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMLatency as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IAMLine21Decoder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDecoderLevel(this: *mut core::ffi::c_void, lplevel: *mut AM_LINE21_CCLEVEL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMLine21Decoder", "GetDecoderLevel", 3usize, |this| ((**(this as *const *const IAMLine21Decoder_Vtbl)).GetDecoderLevel)(this, lplevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentService(this: *mut core::ffi::c_void, lpservice: *mut AM_LINE21_CCSERVICE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMLine21Decoder", "GetCurrentService", 4usize, |this| ((**(this as *const *const IAMLine21Decoder_Vtbl)).GetCurrentService)(this, lpservice), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentService(this: *mut core::ffi::c_void, service: AM_LINE21_CCSERVICE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMLine21Decoder", "SetCurrentService", 5usize, |this| ((**(this as *const *const IAMLine21Decoder_Vtbl)).SetCurrentService)(this, service), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServiceState(this: *mut core::ffi::c_void, lpstate: *mut AM_LINE21_CCSTATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMLine21Decoder", "GetServiceState", 6usize, |this| ((**(this as *const *const IAMLine21Decoder_Vtbl)).GetServiceState)(this, lpstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServiceState(this: *mut core::ffi::c_void, state: AM_LINE21_CCSTATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMLine21Decoder", "SetServiceState", 7usize, |this| ((**(this as *const *const IAMLine21Decoder_Vtbl)).SetServiceState)(this, state), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetOutputFormat(this: *mut core::ffi::c_void, lpbmih: *mut super::super::Graphics::Gdi::BITMAPINFOHEADER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMLine21Decoder", "GetOutputFormat", 8usize, |this| ((**(this as *const *const IAMLine21Decoder_Vtbl)).GetOutputFormat)(this, lpbmih), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn SetOutputFormat(this: *mut core::ffi::c_void, lpbmi: *mut super::super::Graphics::Gdi::BITMAPINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMLine21Decoder", "SetOutputFormat", 9usize, |this| ((**(this as *const *const IAMLine21Decoder_Vtbl)).SetOutputFormat)(this, lpbmi), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBackgroundColor(this: *mut core::ffi::c_void, pdwphyscolor: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMLine21Decoder", "GetBackgroundColor", 10usize, |this| ((**(this as *const *const IAMLine21Decoder_Vtbl)).GetBackgroundColor)(this, pdwphyscolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackgroundColor(this: *mut core::ffi::c_void, dwphyscolor: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMLine21Decoder", "SetBackgroundColor", 11usize, |this| ((**(this as *const *const IAMLine21Decoder_Vtbl)).SetBackgroundColor)(this, dwphyscolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRedrawAlways(this: *mut core::ffi::c_void, lpboption: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMLine21Decoder", "GetRedrawAlways", 12usize, |this| ((**(this as *const *const IAMLine21Decoder_Vtbl)).GetRedrawAlways)(this, lpboption), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRedrawAlways(this: *mut core::ffi::c_void, boption: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMLine21Decoder", "SetRedrawAlways", 13usize, |this| ((**(this as *const *const IAMLine21Decoder_Vtbl)).SetRedrawAlways)(this, boption), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDrawBackgroundMode(this: *mut core::ffi::c_void, lpmode: *mut AM_LINE21_DRAWBGMODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMLine21Decoder", "GetDrawBackgroundMode", 14usize, |this| ((**(this as *const *const IAMLine21Decoder_Vtbl)).GetDrawBackgroundMode)(this, lpmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDrawBackgroundMode(this: *mut core::ffi::c_void, mode: AM_LINE21_DRAWBGMODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMLine21Decoder", "SetDrawBackgroundMode", 15usize, |this| ((**(this as *const *const IAMLine21Decoder_Vtbl)).SetDrawBackgroundMode)(this, mode), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDecoderLevel,
+            GetCurrentService,
+            SetCurrentService,
+            GetServiceState,
+            SetServiceState,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetOutputFormat,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetOutputFormat: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            SetOutputFormat,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            SetOutputFormat: 0,
+            GetBackgroundColor,
+            SetBackgroundColor,
+            GetRedrawAlways,
+            SetRedrawAlways,
+            GetDrawBackgroundMode,
+            SetDrawBackgroundMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMLine21Decoder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAMMediaContent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AuthorName(this: *mut core::ffi::c_void, pbstrauthorname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaContent", "AuthorName", 7usize, |this| ((**(this as *const *const IAMMediaContent_Vtbl)).AuthorName)(this, pbstrauthorname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, pbstrtitle: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaContent", "Title", 8usize, |this| ((**(this as *const *const IAMMediaContent_Vtbl)).Title)(this, pbstrtitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rating(this: *mut core::ffi::c_void, pbstrrating: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaContent", "Rating", 9usize, |this| ((**(this as *const *const IAMMediaContent_Vtbl)).Rating)(this, pbstrrating), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, pbstrdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaContent", "Description", 10usize, |this| ((**(this as *const *const IAMMediaContent_Vtbl)).Description)(this, pbstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn Copyright(this: *mut core::ffi::c_void, pbstrcopyright: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaContent", "Copyright", 11usize, |this| ((**(this as *const *const IAMMediaContent_Vtbl)).Copyright)(this, pbstrcopyright), |result| Some(*result))
+        }
+        unsafe extern "system" fn BaseURL(this: *mut core::ffi::c_void, pbstrbaseurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaContent", "BaseURL", 12usize, |this| ((**(this as *const *const IAMMediaContent_Vtbl)).BaseURL)(this, pbstrbaseurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogoURL(this: *mut core::ffi::c_void, pbstrlogourl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaContent", "LogoURL", 13usize, |this| ((**(this as *const *const IAMMediaContent_Vtbl)).LogoURL)(this, pbstrlogourl), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogoIconURL(this: *mut core::ffi::c_void, pbstrlogourl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaContent", "LogoIconURL", 14usize, |this| ((**(this as *const *const IAMMediaContent_Vtbl)).LogoIconURL)(this, pbstrlogourl), |result| Some(*result))
+        }
+        unsafe extern "system" fn WatermarkURL(this: *mut core::ffi::c_void, pbstrwatermarkurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaContent", "WatermarkURL", 15usize, |this| ((**(this as *const *const IAMMediaContent_Vtbl)).WatermarkURL)(this, pbstrwatermarkurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoreInfoURL(this: *mut core::ffi::c_void, pbstrmoreinfourl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaContent", "MoreInfoURL", 16usize, |this| ((**(this as *const *const IAMMediaContent_Vtbl)).MoreInfoURL)(this, pbstrmoreinfourl), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoreInfoBannerImage(this: *mut core::ffi::c_void, pbstrmoreinfobannerimage: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaContent", "MoreInfoBannerImage", 17usize, |this| ((**(this as *const *const IAMMediaContent_Vtbl)).MoreInfoBannerImage)(this, pbstrmoreinfobannerimage), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoreInfoBannerURL(this: *mut core::ffi::c_void, pbstrmoreinfobannerurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaContent", "MoreInfoBannerURL", 18usize, |this| ((**(this as *const *const IAMMediaContent_Vtbl)).MoreInfoBannerURL)(this, pbstrmoreinfobannerurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoreInfoText(this: *mut core::ffi::c_void, pbstrmoreinfotext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaContent", "MoreInfoText", 19usize, |this| ((**(this as *const *const IAMMediaContent_Vtbl)).MoreInfoText)(this, pbstrmoreinfotext), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AuthorName,
+            Title,
+            Rating,
+            Description,
+            Copyright,
+            BaseURL,
+            LogoURL,
+            LogoIconURL,
+            WatermarkURL,
+            MoreInfoURL,
+            MoreInfoBannerImage,
+            MoreInfoBannerURL,
+            MoreInfoText,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMMediaContent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAMMediaContent2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_MediaParameter(this: *mut core::ffi::c_void, entrynum: i32, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, pbstrvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaContent2", "get_MediaParameter", 7usize, |this| ((**(this as *const *const IAMMediaContent2_Vtbl)).get_MediaParameter)(this, entrynum, bstrname, pbstrvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_MediaParameterName(this: *mut core::ffi::c_void, entrynum: i32, index: i32, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaContent2", "get_MediaParameterName", 8usize, |this| ((**(this as *const *const IAMMediaContent2_Vtbl)).get_MediaParameterName)(this, entrynum, index, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlaylistCount(this: *mut core::ffi::c_void, pnumberentries: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaContent2", "PlaylistCount", 9usize, |this| ((**(this as *const *const IAMMediaContent2_Vtbl)).PlaylistCount)(this, pnumberentries), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            get_MediaParameter,
+            get_MediaParameterName,
+            PlaylistCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMMediaContent2 as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAMMediaStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psourceobject: *mut core::ffi::c_void, dwflags: u32, purposeid: *const windows_core::GUID, streamtype: STREAM_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaStream", "Initialize", 9usize, |this| ((**(this as *const *const IAMMediaStream_Vtbl)).Initialize)(this, psourceobject, dwflags, purposeid, streamtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetState(this: *mut core::ffi::c_void, state: FILTER_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaStream", "SetState", 10usize, |this| ((**(this as *const *const IAMMediaStream_Vtbl)).SetState)(this, state), |result| Some(*result))
+        }
+        unsafe extern "system" fn JoinAMMultiMediaStream(this: *mut core::ffi::c_void, pammultimediastream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaStream", "JoinAMMultiMediaStream", 11usize, |this| ((**(this as *const *const IAMMediaStream_Vtbl)).JoinAMMultiMediaStream)(this, pammultimediastream), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn JoinFilter(this: *mut core::ffi::c_void, pmediastreamfilter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaStream", "JoinFilter", 12usize, |this| ((**(this as *const *const IAMMediaStream_Vtbl)).JoinFilter)(this, pmediastreamfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn JoinFilterGraph(this: *mut core::ffi::c_void, pfiltergraph: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaStream", "JoinFilterGraph", 13usize, |this| ((**(this as *const *const IAMMediaStream_Vtbl)).JoinFilterGraph)(this, pfiltergraph), |result| Some(*result))
+        }
+        Self {
+            base__: <IMediaStream_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            SetState,
+            JoinAMMultiMediaStream,
+            #[cfg(feature = "Win32_System_Com")]
+            JoinFilter,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            JoinFilter: 0,
+            JoinFilterGraph,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMMediaStream as windows_core::Interface>::IID || <IMediaStream_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_MediaFoundation")]
+impl windows_core::imp::ProxyVtable for IAMMediaTypeSample_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPointer(this: *mut core::ffi::c_void, pbuffer: *const u8, lsize: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaTypeSample", "SetPointer", 8usize, |this| ((**(this as *const *const IAMMediaTypeSample_Vtbl)).SetPointer)(this, pbuffer, lsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPointer(this: *mut core::ffi::c_void, ppbuffer: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaTypeSample", "GetPointer", 9usize, |this| ((**(this as *const *const IAMMediaTypeSample_Vtbl)).GetPointer)(this, ppbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSize(this: *mut core::ffi::c_void) -> i32 {
+            windows_core::imp::proxy_call(this, "IAMMediaTypeSample", "GetSize", 10usize, |this| ((**(this as *const *const IAMMediaTypeSample_Vtbl)).GetSize)(this), |_| None)
+        }
+        unsafe extern "system" fn GetTime(this: *mut core::ffi::c_void, ptimestart: *mut i64, ptimeend: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaTypeSample", "GetTime", 11usize, |this| ((**(this as *const *const IAMMediaTypeSample_Vtbl)).GetTime)(this, ptimestart, ptimeend), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTime(this: *mut core::ffi::c_void, ptimestart: *const i64, ptimeend: *const i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaTypeSample", "SetTime", 12usize, |this| ((**(this as *const *const IAMMediaTypeSample_Vtbl)).SetTime)(this, ptimestart, ptimeend), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSyncPoint(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaTypeSample", "IsSyncPoint", 13usize, |this| ((**(this as *const *const IAMMediaTypeSample_Vtbl)).IsSyncPoint)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSyncPoint(this: *mut core::ffi::c_void, bissyncpoint: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaTypeSample", "SetSyncPoint", 14usize, |this| ((**(this as *const *const IAMMediaTypeSample_Vtbl)).SetSyncPoint)(this, bissyncpoint), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPreroll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaTypeSample", "IsPreroll", 15usize, |this| ((**(this as *const *const IAMMediaTypeSample_Vtbl)).IsPreroll)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreroll(this: *mut core::ffi::c_void, bispreroll: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaTypeSample", "SetPreroll", 16usize, |this| ((**(this as *const *const IAMMediaTypeSample_Vtbl)).SetPreroll)(this, bispreroll), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActualDataLength(this: *mut core::ffi::c_void) -> i32 {
+            windows_core::imp::proxy_call(this, "IAMMediaTypeSample", "GetActualDataLength", 17usize, |this| ((**(this as *const *const IAMMediaTypeSample_Vtbl)).GetActualDataLength)(this), |_| None)
+        }
+        unsafe extern "system" fn SetActualDataLength(this: *mut core::ffi::c_void, __midl__iammediatypesample0000: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaTypeSample", "SetActualDataLength", 18usize, |this| ((**(this as *const *const IAMMediaTypeSample_Vtbl)).SetActualDataLength)(this, __midl__iammediatypesample0000), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn GetMediaType(this: *mut core::ffi::c_void, ppmediatype: *mut *mut super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaTypeSample", "GetMediaType", 19usize, |this| ((**(this as *const *const IAMMediaTypeSample_Vtbl)).GetMediaType)(this, ppmediatype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn SetMediaType(this: *mut core::ffi::c_void, pmediatype: *const super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaTypeSample", "SetMediaType", 20usize, |this| ((**(this as *const *const IAMMediaTypeSample_Vtbl)).SetMediaType)(this, pmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDiscontinuity(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaTypeSample", "IsDiscontinuity", 21usize, |this| ((**(this as *const *const IAMMediaTypeSample_Vtbl)).IsDiscontinuity)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDiscontinuity(this: *mut core::ffi::c_void, bdiscontinuity: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaTypeSample", "SetDiscontinuity", 22usize, |this| ((**(this as *const *const IAMMediaTypeSample_Vtbl)).SetDiscontinuity)(this, bdiscontinuity), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMediaTime(this: *mut core::ffi::c_void, ptimestart: *mut i64, ptimeend: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaTypeSample", "GetMediaTime", 23usize, |this| ((**(this as *const *const IAMMediaTypeSample_Vtbl)).GetMediaTime)(this, ptimestart, ptimeend), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMediaTime(this: *mut core::ffi::c_void, ptimestart: *const i64, ptimeend: *const i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaTypeSample", "SetMediaTime", 24usize, |this| ((**(this as *const *const IAMMediaTypeSample_Vtbl)).SetMediaTime)(this, ptimestart, ptimeend), |result| Some(*result))
+        }
+        Self {
+            base__: <IStreamSample_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetPointer,
+            GetPointer,
+            GetSize,
+            GetTime,
+            SetTime,
+            IsSyncPoint,
+            SetSyncPoint,
+            IsPreroll,
+            SetPreroll,
+            GetActualDataLength,
+            SetActualDataLength,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            GetMediaType,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            GetMediaType: 0,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            SetMediaType,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            SetMediaType: 0,
+            IsDiscontinuity,
+            SetDiscontinuity,
+            GetMediaTime,
+            SetMediaTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMMediaTypeSample as windows_core::Interface>::IID || <IStreamSample_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_MediaFoundation")]
+impl windows_core::imp::ProxyVtable for IAMMediaTypeStream_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn GetFormat(this: *mut core::ffi::c_void, pmediatype: *mut super::MediaFoundation::AM_MEDIA_TYPE, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaTypeStream", "GetFormat", 9usize, |this| ((**(this as *const *const IAMMediaTypeStream_Vtbl)).GetFormat)(this, pmediatype, dwflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn SetFormat(this: *mut core::ffi::c_void, pmediatype: *const super::MediaFoundation::AM_MEDIA_TYPE, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaTypeStream", "SetFormat", 10usize, |this| ((**(this as *const *const IAMMediaTypeStream_Vtbl)).SetFormat)(this, pmediatype, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSample(this: *mut core::ffi::c_void, lsamplesize: i32, pbbuffer: *const u8, dwflags: u32, punkouter: *mut core::ffi::c_void, ppammediatypesample: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaTypeStream", "CreateSample", 11usize, |this| ((**(this as *const *const IAMMediaTypeStream_Vtbl)).CreateSample)(this, lsamplesize, pbbuffer, dwflags, punkouter, ppammediatypesample), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamAllocatorRequirements(this: *mut core::ffi::c_void, pprops: *mut ALLOCATOR_PROPERTIES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaTypeStream", "GetStreamAllocatorRequirements", 12usize, |this| ((**(this as *const *const IAMMediaTypeStream_Vtbl)).GetStreamAllocatorRequirements)(this, pprops), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStreamAllocatorRequirements(this: *mut core::ffi::c_void, pprops: *const ALLOCATOR_PROPERTIES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMediaTypeStream", "SetStreamAllocatorRequirements", 13usize, |this| ((**(this as *const *const IAMMediaTypeStream_Vtbl)).SetStreamAllocatorRequirements)(this, pprops), |result| Some(*result))
+        }
+        Self {
+            base__: <IMediaStream_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            GetFormat,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            GetFormat: 0,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            SetFormat,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            SetFormat: 0,
+            CreateSample,
+            GetStreamAllocatorRequirements,
+            SetStreamAllocatorRequirements,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMMediaTypeStream as windows_core::Interface>::IID || <IMediaStream_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAMMultiMediaStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, streamtype: STREAM_TYPE, dwflags: u32, pfiltergraph: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMultiMediaStream", "Initialize", 12usize, |this| ((**(this as *const *const IAMMultiMediaStream_Vtbl)).Initialize)(this, streamtype, dwflags, pfiltergraph), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFilterGraph(this: *mut core::ffi::c_void, ppgraphbuilder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMultiMediaStream", "GetFilterGraph", 13usize, |this| ((**(this as *const *const IAMMultiMediaStream_Vtbl)).GetFilterGraph)(this, ppgraphbuilder), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFilter(this: *mut core::ffi::c_void, ppfilter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMultiMediaStream", "GetFilter", 14usize, |this| ((**(this as *const *const IAMMultiMediaStream_Vtbl)).GetFilter)(this, ppfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddMediaStream(this: *mut core::ffi::c_void, pstreamobject: *mut core::ffi::c_void, purposeid: *const windows_core::GUID, dwflags: u32, ppnewstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMultiMediaStream", "AddMediaStream", 15usize, |this| ((**(this as *const *const IAMMultiMediaStream_Vtbl)).AddMediaStream)(this, pstreamobject, purposeid, dwflags, ppnewstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenFile(this: *mut core::ffi::c_void, pszfilename: windows_core::PCWSTR, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMultiMediaStream", "OpenFile", 16usize, |this| ((**(this as *const *const IAMMultiMediaStream_Vtbl)).OpenFile)(this, pszfilename, dwflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OpenMoniker(this: *mut core::ffi::c_void, pctx: *mut core::ffi::c_void, pmoniker: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMultiMediaStream", "OpenMoniker", 17usize, |this| ((**(this as *const *const IAMMultiMediaStream_Vtbl)).OpenMoniker)(this, pctx, pmoniker, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Render(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMMultiMediaStream", "Render", 18usize, |this| ((**(this as *const *const IAMMultiMediaStream_Vtbl)).Render)(this, dwflags), |result| Some(*result))
+        }
+        Self {
+            base__: <IMultiMediaStream_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetFilterGraph,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFilter,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFilter: 0,
+            AddMediaStream,
+            OpenFile,
+            #[cfg(feature = "Win32_System_Com")]
+            OpenMoniker,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OpenMoniker: 0,
+            Render,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMMultiMediaStream as windows_core::Interface>::IID || <IMultiMediaStream_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAMNetShowConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BufferingTime(this: *mut core::ffi::c_void, pbufferingtime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowConfig", "BufferingTime", 7usize, |this| ((**(this as *const *const IAMNetShowConfig_Vtbl)).BufferingTime)(this, pbufferingtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBufferingTime(this: *mut core::ffi::c_void, bufferingtime: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowConfig", "SetBufferingTime", 8usize, |this| ((**(this as *const *const IAMNetShowConfig_Vtbl)).SetBufferingTime)(this, bufferingtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseFixedUDPPort(this: *mut core::ffi::c_void, pusefixedudpport: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowConfig", "UseFixedUDPPort", 9usize, |this| ((**(this as *const *const IAMNetShowConfig_Vtbl)).UseFixedUDPPort)(this, pusefixedudpport), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUseFixedUDPPort(this: *mut core::ffi::c_void, usefixedudpport: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowConfig", "SetUseFixedUDPPort", 10usize, |this| ((**(this as *const *const IAMNetShowConfig_Vtbl)).SetUseFixedUDPPort)(this, usefixedudpport), |result| Some(*result))
+        }
+        unsafe extern "system" fn FixedUDPPort(this: *mut core::ffi::c_void, pfixedudpport: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowConfig", "FixedUDPPort", 11usize, |this| ((**(this as *const *const IAMNetShowConfig_Vtbl)).FixedUDPPort)(this, pfixedudpport), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFixedUDPPort(this: *mut core::ffi::c_void, fixedudpport: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowConfig", "SetFixedUDPPort", 12usize, |this| ((**(this as *const *const IAMNetShowConfig_Vtbl)).SetFixedUDPPort)(this, fixedudpport), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseHTTPProxy(this: *mut core::ffi::c_void, pusehttpproxy: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowConfig", "UseHTTPProxy", 13usize, |this| ((**(this as *const *const IAMNetShowConfig_Vtbl)).UseHTTPProxy)(this, pusehttpproxy), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUseHTTPProxy(this: *mut core::ffi::c_void, usehttpproxy: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowConfig", "SetUseHTTPProxy", 14usize, |this| ((**(this as *const *const IAMNetShowConfig_Vtbl)).SetUseHTTPProxy)(this, usehttpproxy), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableAutoProxy(this: *mut core::ffi::c_void, penableautoproxy: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowConfig", "EnableAutoProxy", 15usize, |this| ((**(this as *const *const IAMNetShowConfig_Vtbl)).EnableAutoProxy)(this, penableautoproxy), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnableAutoProxy(this: *mut core::ffi::c_void, enableautoproxy: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowConfig", "SetEnableAutoProxy", 16usize, |this| ((**(this as *const *const IAMNetShowConfig_Vtbl)).SetEnableAutoProxy)(this, enableautoproxy), |result| Some(*result))
+        }
+        unsafe extern "system" fn HTTPProxyHost(this: *mut core::ffi::c_void, pbstrhttpproxyhost: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowConfig", "HTTPProxyHost", 17usize, |this| ((**(this as *const *const IAMNetShowConfig_Vtbl)).HTTPProxyHost)(this, pbstrhttpproxyhost), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHTTPProxyHost(this: *mut core::ffi::c_void, bstrhttpproxyhost: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowConfig", "SetHTTPProxyHost", 18usize, |this| ((**(this as *const *const IAMNetShowConfig_Vtbl)).SetHTTPProxyHost)(this, bstrhttpproxyhost), |result| Some(*result))
+        }
+        unsafe extern "system" fn HTTPProxyPort(this: *mut core::ffi::c_void, phttpproxyport: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowConfig", "HTTPProxyPort", 19usize, |this| ((**(this as *const *const IAMNetShowConfig_Vtbl)).HTTPProxyPort)(this, phttpproxyport), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHTTPProxyPort(this: *mut core::ffi::c_void, httpproxyport: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowConfig", "SetHTTPProxyPort", 20usize, |this| ((**(this as *const *const IAMNetShowConfig_Vtbl)).SetHTTPProxyPort)(this, httpproxyport), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableMulticast(this: *mut core::ffi::c_void, penablemulticast: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowConfig", "EnableMulticast", 21usize, |this| ((**(this as *const *const IAMNetShowConfig_Vtbl)).EnableMulticast)(this, penablemulticast), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnableMulticast(this: *mut core::ffi::c_void, enablemulticast: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowConfig", "SetEnableMulticast", 22usize, |this| ((**(this as *const *const IAMNetShowConfig_Vtbl)).SetEnableMulticast)(this, enablemulticast), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableUDP(this: *mut core::ffi::c_void, penableudp: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowConfig", "EnableUDP", 23usize, |this| ((**(this as *const *const IAMNetShowConfig_Vtbl)).EnableUDP)(this, penableudp), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnableUDP(this: *mut core::ffi::c_void, enableudp: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowConfig", "SetEnableUDP", 24usize, |this| ((**(this as *const *const IAMNetShowConfig_Vtbl)).SetEnableUDP)(this, enableudp), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableTCP(this: *mut core::ffi::c_void, penabletcp: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowConfig", "EnableTCP", 25usize, |this| ((**(this as *const *const IAMNetShowConfig_Vtbl)).EnableTCP)(this, penabletcp), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnableTCP(this: *mut core::ffi::c_void, enabletcp: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowConfig", "SetEnableTCP", 26usize, |this| ((**(this as *const *const IAMNetShowConfig_Vtbl)).SetEnableTCP)(this, enabletcp), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableHTTP(this: *mut core::ffi::c_void, penablehttp: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowConfig", "EnableHTTP", 27usize, |this| ((**(this as *const *const IAMNetShowConfig_Vtbl)).EnableHTTP)(this, penablehttp), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnableHTTP(this: *mut core::ffi::c_void, enablehttp: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowConfig", "SetEnableHTTP", 28usize, |this| ((**(this as *const *const IAMNetShowConfig_Vtbl)).SetEnableHTTP)(this, enablehttp), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BufferingTime,
+            SetBufferingTime,
+            UseFixedUDPPort,
+            SetUseFixedUDPPort,
+            FixedUDPPort,
+            SetFixedUDPPort,
+            UseHTTPProxy,
+            SetUseHTTPProxy,
+            EnableAutoProxy,
+            SetEnableAutoProxy,
+            HTTPProxyHost,
+            SetHTTPProxyHost,
+            HTTPProxyPort,
+            SetHTTPProxyPort,
+            EnableMulticast,
+            SetEnableMulticast,
+            EnableUDP,
+            SetEnableUDP,
+            EnableTCP,
+            SetEnableTCP,
+            EnableHTTP,
+            SetEnableHTTP,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMNetShowConfig as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAMNetShowExProps_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SourceProtocol(this: *mut core::ffi::c_void, psourceprotocol: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowExProps", "SourceProtocol", 7usize, |this| ((**(this as *const *const IAMNetShowExProps_Vtbl)).SourceProtocol)(this, psourceprotocol), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bandwidth(this: *mut core::ffi::c_void, pbandwidth: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowExProps", "Bandwidth", 8usize, |this| ((**(this as *const *const IAMNetShowExProps_Vtbl)).Bandwidth)(this, pbandwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorCorrection(this: *mut core::ffi::c_void, pbstrerrorcorrection: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowExProps", "ErrorCorrection", 9usize, |this| ((**(this as *const *const IAMNetShowExProps_Vtbl)).ErrorCorrection)(this, pbstrerrorcorrection), |result| Some(*result))
+        }
+        unsafe extern "system" fn CodecCount(this: *mut core::ffi::c_void, pcodeccount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowExProps", "CodecCount", 10usize, |this| ((**(this as *const *const IAMNetShowExProps_Vtbl)).CodecCount)(this, pcodeccount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCodecInstalled(this: *mut core::ffi::c_void, codecnum: i32, pcodecinstalled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowExProps", "GetCodecInstalled", 11usize, |this| ((**(this as *const *const IAMNetShowExProps_Vtbl)).GetCodecInstalled)(this, codecnum, pcodecinstalled), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCodecDescription(this: *mut core::ffi::c_void, codecnum: i32, pbstrcodecdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowExProps", "GetCodecDescription", 12usize, |this| ((**(this as *const *const IAMNetShowExProps_Vtbl)).GetCodecDescription)(this, codecnum, pbstrcodecdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCodecURL(this: *mut core::ffi::c_void, codecnum: i32, pbstrcodecurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowExProps", "GetCodecURL", 13usize, |this| ((**(this as *const *const IAMNetShowExProps_Vtbl)).GetCodecURL)(this, codecnum, pbstrcodecurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreationDate(this: *mut core::ffi::c_void, pcreationdate: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowExProps", "CreationDate", 14usize, |this| ((**(this as *const *const IAMNetShowExProps_Vtbl)).CreationDate)(this, pcreationdate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceLink(this: *mut core::ffi::c_void, pbstrsourcelink: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowExProps", "SourceLink", 15usize, |this| ((**(this as *const *const IAMNetShowExProps_Vtbl)).SourceLink)(this, pbstrsourcelink), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SourceProtocol,
+            Bandwidth,
+            ErrorCorrection,
+            CodecCount,
+            GetCodecInstalled,
+            GetCodecDescription,
+            GetCodecURL,
+            CreationDate,
+            SourceLink,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMNetShowExProps as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAMNetShowPreroll_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPreroll(this: *mut core::ffi::c_void, fpreroll: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowPreroll", "SetPreroll", 7usize, |this| ((**(this as *const *const IAMNetShowPreroll_Vtbl)).SetPreroll)(this, fpreroll), |result| Some(*result))
+        }
+        unsafe extern "system" fn Preroll(this: *mut core::ffi::c_void, pfpreroll: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetShowPreroll", "Preroll", 8usize, |this| ((**(this as *const *const IAMNetShowPreroll_Vtbl)).Preroll)(this, pfpreroll), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetPreroll, Preroll }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMNetShowPreroll as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAMNetworkStatus_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReceivedPackets(this: *mut core::ffi::c_void, preceivedpackets: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetworkStatus", "ReceivedPackets", 7usize, |this| ((**(this as *const *const IAMNetworkStatus_Vtbl)).ReceivedPackets)(this, preceivedpackets), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecoveredPackets(this: *mut core::ffi::c_void, precoveredpackets: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetworkStatus", "RecoveredPackets", 8usize, |this| ((**(this as *const *const IAMNetworkStatus_Vtbl)).RecoveredPackets)(this, precoveredpackets), |result| Some(*result))
+        }
+        unsafe extern "system" fn LostPackets(this: *mut core::ffi::c_void, plostpackets: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetworkStatus", "LostPackets", 9usize, |this| ((**(this as *const *const IAMNetworkStatus_Vtbl)).LostPackets)(this, plostpackets), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReceptionQuality(this: *mut core::ffi::c_void, preceptionquality: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetworkStatus", "ReceptionQuality", 10usize, |this| ((**(this as *const *const IAMNetworkStatus_Vtbl)).ReceptionQuality)(this, preceptionquality), |result| Some(*result))
+        }
+        unsafe extern "system" fn BufferingCount(this: *mut core::ffi::c_void, pbufferingcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetworkStatus", "BufferingCount", 11usize, |this| ((**(this as *const *const IAMNetworkStatus_Vtbl)).BufferingCount)(this, pbufferingcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsBroadcast(this: *mut core::ffi::c_void, pisbroadcast: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetworkStatus", "IsBroadcast", 12usize, |this| ((**(this as *const *const IAMNetworkStatus_Vtbl)).IsBroadcast)(this, pisbroadcast), |result| Some(*result))
+        }
+        unsafe extern "system" fn BufferingProgress(this: *mut core::ffi::c_void, pbufferingprogress: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMNetworkStatus", "BufferingProgress", 13usize, |this| ((**(this as *const *const IAMNetworkStatus_Vtbl)).BufferingProgress)(this, pbufferingprogress), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReceivedPackets,
+            RecoveredPackets,
+            LostPackets,
+            ReceptionQuality,
+            BufferingCount,
+            IsBroadcast,
+            BufferingProgress,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMNetworkStatus as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMOpenProgress_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryProgress(this: *mut core::ffi::c_void, plltotal: *mut i64, pllcurrent: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMOpenProgress", "QueryProgress", 3usize, |this| ((**(this as *const *const IAMOpenProgress_Vtbl)).QueryProgress)(this, plltotal, pllcurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn AbortOperation(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMOpenProgress", "AbortOperation", 4usize, |this| ((**(this as *const *const IAMOpenProgress_Vtbl)).AbortOperation)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryProgress, AbortOperation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMOpenProgress as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMOverlayFX_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryOverlayFXCaps(this: *mut core::ffi::c_void, lpdwoverlayfxcaps: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMOverlayFX", "QueryOverlayFXCaps", 3usize, |this| ((**(this as *const *const IAMOverlayFX_Vtbl)).QueryOverlayFXCaps)(this, lpdwoverlayfxcaps), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOverlayFX(this: *mut core::ffi::c_void, dwoverlayfx: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMOverlayFX", "SetOverlayFX", 4usize, |this| ((**(this as *const *const IAMOverlayFX_Vtbl)).SetOverlayFX)(this, dwoverlayfx), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOverlayFX(this: *mut core::ffi::c_void, lpdwoverlayfx: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMOverlayFX", "GetOverlayFX", 5usize, |this| ((**(this as *const *const IAMOverlayFX_Vtbl)).GetOverlayFX)(this, lpdwoverlayfx), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryOverlayFXCaps, SetOverlayFX, GetOverlayFX }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMOverlayFX as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMParse_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetParseTime(this: *mut core::ffi::c_void, prtcurrent: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMParse", "GetParseTime", 3usize, |this| ((**(this as *const *const IAMParse_Vtbl)).GetParseTime)(this, prtcurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParseTime(this: *mut core::ffi::c_void, rtcurrent: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMParse", "SetParseTime", 4usize, |this| ((**(this as *const *const IAMParse_Vtbl)).SetParseTime)(this, rtcurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flush(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMParse", "Flush", 5usize, |this| ((**(this as *const *const IAMParse_Vtbl)).Flush)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetParseTime, SetParseTime, Flush }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMParse as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMPhysicalPinInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPhysicalType(this: *mut core::ffi::c_void, ptype: *mut i32, ppsztype: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPhysicalPinInfo", "GetPhysicalType", 3usize, |this| ((**(this as *const *const IAMPhysicalPinInfo_Vtbl)).GetPhysicalType)(this, ptype, ppsztype), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPhysicalType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMPhysicalPinInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMPlayList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFlags(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPlayList", "GetFlags", 3usize, |this| ((**(this as *const *const IAMPlayList_Vtbl)).GetFlags)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItemCount(this: *mut core::ffi::c_void, pdwitems: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPlayList", "GetItemCount", 4usize, |this| ((**(this as *const *const IAMPlayList_Vtbl)).GetItemCount)(this, pdwitems), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItem(this: *mut core::ffi::c_void, dwitemindex: u32, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPlayList", "GetItem", 5usize, |this| ((**(this as *const *const IAMPlayList_Vtbl)).GetItem)(this, dwitemindex, ppitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNamedEvent(this: *mut core::ffi::c_void, pwszeventname: windows_core::PCWSTR, dwitemindex: u32, ppitem: *mut *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPlayList", "GetNamedEvent", 6usize, |this| ((**(this as *const *const IAMPlayList_Vtbl)).GetNamedEvent)(this, pwszeventname, dwitemindex, ppitem, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRepeatInfo(this: *mut core::ffi::c_void, pdwrepeatcount: *mut u32, pdwrepeatstart: *mut u32, pdwrepeatend: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPlayList", "GetRepeatInfo", 7usize, |this| ((**(this as *const *const IAMPlayList_Vtbl)).GetRepeatInfo)(this, pdwrepeatcount, pdwrepeatstart, pdwrepeatend), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFlags, GetItemCount, GetItem, GetNamedEvent, GetRepeatInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMPlayList as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMPlayListItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFlags(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPlayListItem", "GetFlags", 3usize, |this| ((**(this as *const *const IAMPlayListItem_Vtbl)).GetFlags)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourceCount(this: *mut core::ffi::c_void, pdwsources: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPlayListItem", "GetSourceCount", 4usize, |this| ((**(this as *const *const IAMPlayListItem_Vtbl)).GetSourceCount)(this, pdwsources), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourceURL(this: *mut core::ffi::c_void, dwsourceindex: u32, pbstrurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPlayListItem", "GetSourceURL", 5usize, |this| ((**(this as *const *const IAMPlayListItem_Vtbl)).GetSourceURL)(this, dwsourceindex, pbstrurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourceStart(this: *mut core::ffi::c_void, dwsourceindex: u32, prtstart: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPlayListItem", "GetSourceStart", 6usize, |this| ((**(this as *const *const IAMPlayListItem_Vtbl)).GetSourceStart)(this, dwsourceindex, prtstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourceDuration(this: *mut core::ffi::c_void, dwsourceindex: u32, prtduration: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPlayListItem", "GetSourceDuration", 7usize, |this| ((**(this as *const *const IAMPlayListItem_Vtbl)).GetSourceDuration)(this, dwsourceindex, prtduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourceStartMarker(this: *mut core::ffi::c_void, dwsourceindex: u32, pdwmarker: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPlayListItem", "GetSourceStartMarker", 8usize, |this| ((**(this as *const *const IAMPlayListItem_Vtbl)).GetSourceStartMarker)(this, dwsourceindex, pdwmarker), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourceEndMarker(this: *mut core::ffi::c_void, dwsourceindex: u32, pdwmarker: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPlayListItem", "GetSourceEndMarker", 9usize, |this| ((**(this as *const *const IAMPlayListItem_Vtbl)).GetSourceEndMarker)(this, dwsourceindex, pdwmarker), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourceStartMarkerName(this: *mut core::ffi::c_void, dwsourceindex: u32, pbstrstartmarker: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPlayListItem", "GetSourceStartMarkerName", 10usize, |this| ((**(this as *const *const IAMPlayListItem_Vtbl)).GetSourceStartMarkerName)(this, dwsourceindex, pbstrstartmarker), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourceEndMarkerName(this: *mut core::ffi::c_void, dwsourceindex: u32, pbstrendmarker: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPlayListItem", "GetSourceEndMarkerName", 11usize, |this| ((**(this as *const *const IAMPlayListItem_Vtbl)).GetSourceEndMarkerName)(this, dwsourceindex, pbstrendmarker), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLinkURL(this: *mut core::ffi::c_void, pbstrurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPlayListItem", "GetLinkURL", 12usize, |this| ((**(this as *const *const IAMPlayListItem_Vtbl)).GetLinkURL)(this, pbstrurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScanDuration(this: *mut core::ffi::c_void, dwsourceindex: u32, prtscanduration: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPlayListItem", "GetScanDuration", 13usize, |this| ((**(this as *const *const IAMPlayListItem_Vtbl)).GetScanDuration)(this, dwsourceindex, prtscanduration), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetFlags,
+            GetSourceCount,
+            GetSourceURL,
+            GetSourceStart,
+            GetSourceDuration,
+            GetSourceStartMarker,
+            GetSourceEndMarker,
+            GetSourceStartMarkerName,
+            GetSourceEndMarkerName,
+            GetLinkURL,
+            GetScanDuration,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMPlayListItem as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMPluginControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPreferredClsid(this: *mut core::ffi::c_void, subtype: *const windows_core::GUID, clsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPluginControl", "GetPreferredClsid", 3usize, |this| ((**(this as *const *const IAMPluginControl_Vtbl)).GetPreferredClsid)(this, subtype, clsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreferredClsidByIndex(this: *mut core::ffi::c_void, index: u32, subtype: *mut windows_core::GUID, clsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPluginControl", "GetPreferredClsidByIndex", 4usize, |this| ((**(this as *const *const IAMPluginControl_Vtbl)).GetPreferredClsidByIndex)(this, index, subtype, clsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreferredClsid(this: *mut core::ffi::c_void, subtype: *const windows_core::GUID, clsid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPluginControl", "SetPreferredClsid", 5usize, |this| ((**(this as *const *const IAMPluginControl_Vtbl)).SetPreferredClsid)(this, subtype, clsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDisabled(this: *mut core::ffi::c_void, clsid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPluginControl", "IsDisabled", 6usize, |this| ((**(this as *const *const IAMPluginControl_Vtbl)).IsDisabled)(this, clsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisabledByIndex(this: *mut core::ffi::c_void, index: u32, clsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPluginControl", "GetDisabledByIndex", 7usize, |this| ((**(this as *const *const IAMPluginControl_Vtbl)).GetDisabledByIndex)(this, index, clsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisabled(this: *mut core::ffi::c_void, clsid: *const windows_core::GUID, disabled: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPluginControl", "SetDisabled", 8usize, |this| ((**(this as *const *const IAMPluginControl_Vtbl)).SetDisabled)(this, clsid, disabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLegacyDisabled(this: *mut core::ffi::c_void, dllname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPluginControl", "IsLegacyDisabled", 9usize, |this| ((**(this as *const *const IAMPluginControl_Vtbl)).IsLegacyDisabled)(this, dllname), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPreferredClsid,
+            GetPreferredClsidByIndex,
+            SetPreferredClsid,
+            IsDisabled,
+            GetDisabledByIndex,
+            SetDisabled,
+            IsLegacyDisabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMPluginControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMPushSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPushSourceFlags(this: *mut core::ffi::c_void, pflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPushSource", "GetPushSourceFlags", 4usize, |this| ((**(this as *const *const IAMPushSource_Vtbl)).GetPushSourceFlags)(this, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPushSourceFlags(this: *mut core::ffi::c_void, flags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPushSource", "SetPushSourceFlags", 5usize, |this| ((**(this as *const *const IAMPushSource_Vtbl)).SetPushSourceFlags)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStreamOffset(this: *mut core::ffi::c_void, rtoffset: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPushSource", "SetStreamOffset", 6usize, |this| ((**(this as *const *const IAMPushSource_Vtbl)).SetStreamOffset)(this, rtoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamOffset(this: *mut core::ffi::c_void, prtoffset: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPushSource", "GetStreamOffset", 7usize, |this| ((**(this as *const *const IAMPushSource_Vtbl)).GetStreamOffset)(this, prtoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxStreamOffset(this: *mut core::ffi::c_void, prtmaxoffset: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPushSource", "GetMaxStreamOffset", 8usize, |this| ((**(this as *const *const IAMPushSource_Vtbl)).GetMaxStreamOffset)(this, prtmaxoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxStreamOffset(this: *mut core::ffi::c_void, rtmaxoffset: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMPushSource", "SetMaxStreamOffset", 9usize, |this| ((**(this as *const *const IAMPushSource_Vtbl)).SetMaxStreamOffset)(this, rtmaxoffset), |result| Some(*result))
+        }
+        Self {
+            base__: <IAMLatency_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPushSourceFlags,
+            SetPushSourceFlags,
+            SetStreamOffset,
+            GetStreamOffset,
+            GetMaxStreamOffset,
+            SetMaxStreamOffset,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMPushSource as windows_core::Interface>::IID || <IAMLatency_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMRebuild_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RebuildNow(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMRebuild", "RebuildNow", 3usize, |this| ((**(this as *const *const IAMRebuild_Vtbl)).RebuildNow)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RebuildNow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMRebuild as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMResourceControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reserve(this: *mut core::ffi::c_void, dwflags: u32, pvreserved: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMResourceControl", "Reserve", 3usize, |this| ((**(this as *const *const IAMResourceControl_Vtbl)).Reserve)(this, dwflags, pvreserved), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reserve }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMResourceControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAMStats_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMStats", "Reset", 7usize, |this| ((**(this as *const *const IAMStats_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMStats", "Count", 8usize, |this| ((**(this as *const *const IAMStats_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValueByIndex(this: *mut core::ffi::c_void, lindex: i32, szname: *mut core::mem::MaybeUninit<windows_core::BSTR>, lcount: *mut i32, dlast: *mut f64, daverage: *mut f64, dstddev: *mut f64, dmin: *mut f64, dmax: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMStats", "GetValueByIndex", 9usize, |this| ((**(this as *const *const IAMStats_Vtbl)).GetValueByIndex)(this, lindex, szname, lcount, dlast, daverage, dstddev, dmin, dmax), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValueByName(this: *mut core::ffi::c_void, szname: core::mem::MaybeUninit<windows_core::BSTR>, lindex: *mut i32, lcount: *mut i32, dlast: *mut f64, daverage: *mut f64, dstddev: *mut f64, dmin: *mut f64, dmax: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMStats", "GetValueByName", 10usize, |this| ((**(this as *const *const IAMStats_Vtbl)).GetValueByName)(this, szname, lindex, lcount, dlast, daverage, dstddev, dmin, dmax), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIndex(this: *mut core::ffi::c_void, szname: core::mem::MaybeUninit<windows_core::BSTR>, lcreate: i32, plindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMStats", "GetIndex", 11usize, |this| ((**(this as *const *const IAMStats_Vtbl)).GetIndex)(this, szname, lcreate, plindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddValue(this: *mut core::ffi::c_void, lindex: i32, dvalue: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMStats", "AddValue", 12usize, |this| ((**(this as *const *const IAMStats_Vtbl)).AddValue)(this, lindex, dvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Reset,
+            Count,
+            GetValueByIndex,
+            GetValueByName,
+            GetIndex,
+            AddValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMStats as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_MediaFoundation")]
+impl windows_core::imp::ProxyVtable for IAMStreamConfig_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn SetFormat(this: *mut core::ffi::c_void, pmt: *const super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMStreamConfig", "SetFormat", 3usize, |this| ((**(this as *const *const IAMStreamConfig_Vtbl)).SetFormat)(this, pmt), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn GetFormat(this: *mut core::ffi::c_void, ppmt: *mut *mut super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMStreamConfig", "GetFormat", 4usize, |this| ((**(this as *const *const IAMStreamConfig_Vtbl)).GetFormat)(this, ppmt), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumberOfCapabilities(this: *mut core::ffi::c_void, picount: *mut i32, pisize: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMStreamConfig", "GetNumberOfCapabilities", 5usize, |this| ((**(this as *const *const IAMStreamConfig_Vtbl)).GetNumberOfCapabilities)(this, picount, pisize), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn GetStreamCaps(this: *mut core::ffi::c_void, iindex: i32, ppmt: *mut *mut super::MediaFoundation::AM_MEDIA_TYPE, pscc: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMStreamConfig", "GetStreamCaps", 6usize, |this| ((**(this as *const *const IAMStreamConfig_Vtbl)).GetStreamCaps)(this, iindex, ppmt, pscc), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            SetFormat,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            SetFormat: 0,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            GetFormat,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            GetFormat: 0,
+            GetNumberOfCapabilities,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            GetStreamCaps,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            GetStreamCaps: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMStreamConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMStreamControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartAt(this: *mut core::ffi::c_void, ptstart: *const i64, dwcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMStreamControl", "StartAt", 3usize, |this| ((**(this as *const *const IAMStreamControl_Vtbl)).StartAt)(this, ptstart, dwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopAt(this: *mut core::ffi::c_void, ptstop: *const i64, bsendextra: super::super::Foundation::BOOL, dwcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMStreamControl", "StopAt", 4usize, |this| ((**(this as *const *const IAMStreamControl_Vtbl)).StopAt)(this, ptstop, bsendextra, dwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInfo(this: *mut core::ffi::c_void, pinfo: *mut AM_STREAM_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMStreamControl", "GetInfo", 5usize, |this| ((**(this as *const *const IAMStreamControl_Vtbl)).GetInfo)(this, pinfo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartAt, StopAt, GetInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMStreamControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_MediaFoundation")]
+impl windows_core::imp::ProxyVtable for IAMStreamSelect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pcstreams: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMStreamSelect", "Count", 3usize, |this| ((**(this as *const *const IAMStreamSelect_Vtbl)).Count)(this, pcstreams), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn Info(this: *mut core::ffi::c_void, lindex: i32, ppmt: *mut *mut super::MediaFoundation::AM_MEDIA_TYPE, pdwflags: *mut u32, plcid: *mut u32, pdwgroup: *mut u32, ppszname: *mut windows_core::PWSTR, ppobject: *mut *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMStreamSelect", "Info", 4usize, |this| ((**(this as *const *const IAMStreamSelect_Vtbl)).Info)(this, lindex, ppmt, pdwflags, plcid, pdwgroup, ppszname, ppobject, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enable(this: *mut core::ffi::c_void, lindex: i32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMStreamSelect", "Enable", 5usize, |this| ((**(this as *const *const IAMStreamSelect_Vtbl)).Enable)(this, lindex, dwflags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            Info,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            Info: 0,
+            Enable,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMStreamSelect as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMTVAudio_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetHardwareSupportedTVAudioModes(this: *mut core::ffi::c_void, plmodes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTVAudio", "GetHardwareSupportedTVAudioModes", 3usize, |this| ((**(this as *const *const IAMTVAudio_Vtbl)).GetHardwareSupportedTVAudioModes)(this, plmodes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAvailableTVAudioModes(this: *mut core::ffi::c_void, plmodes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTVAudio", "GetAvailableTVAudioModes", 4usize, |this| ((**(this as *const *const IAMTVAudio_Vtbl)).GetAvailableTVAudioModes)(this, plmodes), |result| Some(*result))
+        }
+        unsafe extern "system" fn TVAudioMode(this: *mut core::ffi::c_void, plmode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTVAudio", "TVAudioMode", 5usize, |this| ((**(this as *const *const IAMTVAudio_Vtbl)).TVAudioMode)(this, plmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTVAudioMode(this: *mut core::ffi::c_void, lmode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTVAudio", "SetTVAudioMode", 6usize, |this| ((**(this as *const *const IAMTVAudio_Vtbl)).SetTVAudioMode)(this, lmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterNotificationCallBack(this: *mut core::ffi::c_void, pnotify: *mut core::ffi::c_void, levents: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTVAudio", "RegisterNotificationCallBack", 7usize, |this| ((**(this as *const *const IAMTVAudio_Vtbl)).RegisterNotificationCallBack)(this, pnotify, levents), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnRegisterNotificationCallBack(this: *mut core::ffi::c_void, pnotify: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTVAudio", "UnRegisterNotificationCallBack", 8usize, |this| ((**(this as *const *const IAMTVAudio_Vtbl)).UnRegisterNotificationCallBack)(this, pnotify), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetHardwareSupportedTVAudioModes,
+            GetAvailableTVAudioModes,
+            TVAudioMode,
+            SetTVAudioMode,
+            RegisterNotificationCallBack,
+            UnRegisterNotificationCallBack,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMTVAudio as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMTVAudioNotification_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnEvent(this: *mut core::ffi::c_void, event: AMTVAudioEventType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTVAudioNotification", "OnEvent", 3usize, |this| ((**(this as *const *const IAMTVAudioNotification_Vtbl)).OnEvent)(this, event), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMTVAudioNotification as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMTVTuner_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AvailableTVFormats(this: *mut core::ffi::c_void, lanalogvideostandard: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTVTuner", "AvailableTVFormats", 18usize, |this| ((**(this as *const *const IAMTVTuner_Vtbl)).AvailableTVFormats)(this, lanalogvideostandard), |result| Some(*result))
+        }
+        unsafe extern "system" fn TVFormat(this: *mut core::ffi::c_void, planalogvideostandard: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTVTuner", "TVFormat", 19usize, |this| ((**(this as *const *const IAMTVTuner_Vtbl)).TVFormat)(this, planalogvideostandard), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoTune(this: *mut core::ffi::c_void, lchannel: i32, plfoundsignal: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTVTuner", "AutoTune", 20usize, |this| ((**(this as *const *const IAMTVTuner_Vtbl)).AutoTune)(this, lchannel, plfoundsignal), |result| Some(*result))
+        }
+        unsafe extern "system" fn StoreAutoTune(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTVTuner", "StoreAutoTune", 21usize, |this| ((**(this as *const *const IAMTVTuner_Vtbl)).StoreAutoTune)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumInputConnections(this: *mut core::ffi::c_void, plnuminputconnections: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTVTuner", "NumInputConnections", 22usize, |this| ((**(this as *const *const IAMTVTuner_Vtbl)).NumInputConnections)(this, plnuminputconnections), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_InputType(this: *mut core::ffi::c_void, lindex: i32, inputtype: TunerInputType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTVTuner", "put_InputType", 23usize, |this| ((**(this as *const *const IAMTVTuner_Vtbl)).put_InputType)(this, lindex, inputtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_InputType(this: *mut core::ffi::c_void, lindex: i32, pinputtype: *mut TunerInputType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTVTuner", "get_InputType", 24usize, |this| ((**(this as *const *const IAMTVTuner_Vtbl)).get_InputType)(this, lindex, pinputtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConnectInput(this: *mut core::ffi::c_void, lindex: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTVTuner", "SetConnectInput", 25usize, |this| ((**(this as *const *const IAMTVTuner_Vtbl)).SetConnectInput)(this, lindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectInput(this: *mut core::ffi::c_void, plindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTVTuner", "ConnectInput", 26usize, |this| ((**(this as *const *const IAMTVTuner_Vtbl)).ConnectInput)(this, plindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoFrequency(this: *mut core::ffi::c_void, lfreq: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTVTuner", "VideoFrequency", 27usize, |this| ((**(this as *const *const IAMTVTuner_Vtbl)).VideoFrequency)(this, lfreq), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioFrequency(this: *mut core::ffi::c_void, lfreq: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTVTuner", "AudioFrequency", 28usize, |this| ((**(this as *const *const IAMTVTuner_Vtbl)).AudioFrequency)(this, lfreq), |result| Some(*result))
+        }
+        Self {
+            base__: <IAMTuner_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AvailableTVFormats,
+            TVFormat,
+            AutoTune,
+            StoreAutoTune,
+            NumInputConnections,
+            put_InputType,
+            get_InputType,
+            SetConnectInput,
+            ConnectInput,
+            VideoFrequency,
+            AudioFrequency,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMTVTuner as windows_core::Interface>::IID || <IAMTuner_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMTimecodeDisplay_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTCDisplayEnable(this: *mut core::ffi::c_void, pstate: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTimecodeDisplay", "GetTCDisplayEnable", 3usize, |this| ((**(this as *const *const IAMTimecodeDisplay_Vtbl)).GetTCDisplayEnable)(this, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTCDisplayEnable(this: *mut core::ffi::c_void, state: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTimecodeDisplay", "SetTCDisplayEnable", 4usize, |this| ((**(this as *const *const IAMTimecodeDisplay_Vtbl)).SetTCDisplayEnable)(this, state), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTCDisplay(this: *mut core::ffi::c_void, param: i32, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTimecodeDisplay", "GetTCDisplay", 5usize, |this| ((**(this as *const *const IAMTimecodeDisplay_Vtbl)).GetTCDisplay)(this, param, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTCDisplay(this: *mut core::ffi::c_void, param: i32, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTimecodeDisplay", "SetTCDisplay", 6usize, |this| ((**(this as *const *const IAMTimecodeDisplay_Vtbl)).SetTCDisplay)(this, param, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTCDisplayEnable,
+            SetTCDisplayEnable,
+            GetTCDisplay,
+            SetTCDisplay,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMTimecodeDisplay as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMTimecodeGenerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTCGMode(this: *mut core::ffi::c_void, param: i32, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTimecodeGenerator", "GetTCGMode", 3usize, |this| ((**(this as *const *const IAMTimecodeGenerator_Vtbl)).GetTCGMode)(this, param, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTCGMode(this: *mut core::ffi::c_void, param: i32, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTimecodeGenerator", "SetTCGMode", 4usize, |this| ((**(this as *const *const IAMTimecodeGenerator_Vtbl)).SetTCGMode)(this, param, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVITCLine(this: *mut core::ffi::c_void, line: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTimecodeGenerator", "SetVITCLine", 5usize, |this| ((**(this as *const *const IAMTimecodeGenerator_Vtbl)).SetVITCLine)(this, line), |result| Some(*result))
+        }
+        unsafe extern "system" fn VITCLine(this: *mut core::ffi::c_void, pline: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTimecodeGenerator", "VITCLine", 6usize, |this| ((**(this as *const *const IAMTimecodeGenerator_Vtbl)).VITCLine)(this, pline), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTimecode(this: *mut core::ffi::c_void, ptimecodesample: *const super::TIMECODE_SAMPLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTimecodeGenerator", "SetTimecode", 7usize, |this| ((**(this as *const *const IAMTimecodeGenerator_Vtbl)).SetTimecode)(this, ptimecodesample), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTimecode(this: *mut core::ffi::c_void, ptimecodesample: *mut super::TIMECODE_SAMPLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTimecodeGenerator", "GetTimecode", 8usize, |this| ((**(this as *const *const IAMTimecodeGenerator_Vtbl)).GetTimecode)(this, ptimecodesample), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTCGMode,
+            SetTCGMode,
+            SetVITCLine,
+            VITCLine,
+            SetTimecode,
+            GetTimecode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMTimecodeGenerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMTimecodeReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTCRMode(this: *mut core::ffi::c_void, param: i32, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTimecodeReader", "GetTCRMode", 3usize, |this| ((**(this as *const *const IAMTimecodeReader_Vtbl)).GetTCRMode)(this, param, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTCRMode(this: *mut core::ffi::c_void, param: i32, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTimecodeReader", "SetTCRMode", 4usize, |this| ((**(this as *const *const IAMTimecodeReader_Vtbl)).SetTCRMode)(this, param, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVITCLine(this: *mut core::ffi::c_void, line: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTimecodeReader", "SetVITCLine", 5usize, |this| ((**(this as *const *const IAMTimecodeReader_Vtbl)).SetVITCLine)(this, line), |result| Some(*result))
+        }
+        unsafe extern "system" fn VITCLine(this: *mut core::ffi::c_void, pline: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTimecodeReader", "VITCLine", 6usize, |this| ((**(this as *const *const IAMTimecodeReader_Vtbl)).VITCLine)(this, pline), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTimecode(this: *mut core::ffi::c_void, ptimecodesample: *mut super::TIMECODE_SAMPLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTimecodeReader", "GetTimecode", 7usize, |this| ((**(this as *const *const IAMTimecodeReader_Vtbl)).GetTimecode)(this, ptimecodesample), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetTCRMode, SetTCRMode, SetVITCLine, VITCLine, GetTimecode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMTimecodeReader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMTuner_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn put_Channel(this: *mut core::ffi::c_void, lchannel: i32, lvideosubchannel: i32, laudiosubchannel: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTuner", "put_Channel", 3usize, |this| ((**(this as *const *const IAMTuner_Vtbl)).put_Channel)(this, lchannel, lvideosubchannel, laudiosubchannel), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Channel(this: *mut core::ffi::c_void, plchannel: *mut i32, plvideosubchannel: *mut i32, plaudiosubchannel: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTuner", "get_Channel", 4usize, |this| ((**(this as *const *const IAMTuner_Vtbl)).get_Channel)(this, plchannel, plvideosubchannel, plaudiosubchannel), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChannelMinMax(this: *mut core::ffi::c_void, lchannelmin: *mut i32, lchannelmax: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTuner", "ChannelMinMax", 5usize, |this| ((**(this as *const *const IAMTuner_Vtbl)).ChannelMinMax)(this, lchannelmin, lchannelmax), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCountryCode(this: *mut core::ffi::c_void, lcountrycode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTuner", "SetCountryCode", 6usize, |this| ((**(this as *const *const IAMTuner_Vtbl)).SetCountryCode)(this, lcountrycode), |result| Some(*result))
+        }
+        unsafe extern "system" fn CountryCode(this: *mut core::ffi::c_void, plcountrycode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTuner", "CountryCode", 7usize, |this| ((**(this as *const *const IAMTuner_Vtbl)).CountryCode)(this, plcountrycode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTuningSpace(this: *mut core::ffi::c_void, ltuningspace: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTuner", "SetTuningSpace", 8usize, |this| ((**(this as *const *const IAMTuner_Vtbl)).SetTuningSpace)(this, ltuningspace), |result| Some(*result))
+        }
+        unsafe extern "system" fn TuningSpace(this: *mut core::ffi::c_void, pltuningspace: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTuner", "TuningSpace", 9usize, |this| ((**(this as *const *const IAMTuner_Vtbl)).TuningSpace)(this, pltuningspace), |result| Some(*result))
+        }
+        unsafe extern "system" fn Logon(this: *mut core::ffi::c_void, hcurrentuser: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTuner", "Logon", 10usize, |this| ((**(this as *const *const IAMTuner_Vtbl)).Logon)(this, hcurrentuser), |result| Some(*result))
+        }
+        unsafe extern "system" fn Logout(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTuner", "Logout", 11usize, |this| ((**(this as *const *const IAMTuner_Vtbl)).Logout)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignalPresent(this: *mut core::ffi::c_void, plsignalstrength: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTuner", "SignalPresent", 12usize, |this| ((**(this as *const *const IAMTuner_Vtbl)).SignalPresent)(this, plsignalstrength), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMode(this: *mut core::ffi::c_void, lmode: AMTunerModeType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTuner", "SetMode", 13usize, |this| ((**(this as *const *const IAMTuner_Vtbl)).SetMode)(this, lmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, plmode: *mut AMTunerModeType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTuner", "Mode", 14usize, |this| ((**(this as *const *const IAMTuner_Vtbl)).Mode)(this, plmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAvailableModes(this: *mut core::ffi::c_void, plmodes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTuner", "GetAvailableModes", 15usize, |this| ((**(this as *const *const IAMTuner_Vtbl)).GetAvailableModes)(this, plmodes), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterNotificationCallBack(this: *mut core::ffi::c_void, pnotify: *mut core::ffi::c_void, levents: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTuner", "RegisterNotificationCallBack", 16usize, |this| ((**(this as *const *const IAMTuner_Vtbl)).RegisterNotificationCallBack)(this, pnotify, levents), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnRegisterNotificationCallBack(this: *mut core::ffi::c_void, pnotify: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTuner", "UnRegisterNotificationCallBack", 17usize, |this| ((**(this as *const *const IAMTuner_Vtbl)).UnRegisterNotificationCallBack)(this, pnotify), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            put_Channel,
+            get_Channel,
+            ChannelMinMax,
+            SetCountryCode,
+            CountryCode,
+            SetTuningSpace,
+            TuningSpace,
+            Logon,
+            Logout,
+            SignalPresent,
+            SetMode,
+            Mode,
+            GetAvailableModes,
+            RegisterNotificationCallBack,
+            UnRegisterNotificationCallBack,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMTuner as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMTunerNotification_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnEvent(this: *mut core::ffi::c_void, event: AMTunerEventType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMTunerNotification", "OnEvent", 3usize, |this| ((**(this as *const *const IAMTunerNotification_Vtbl)).OnEvent)(this, event), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMTunerNotification as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMVfwCaptureDialogs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HasDialog(this: *mut core::ffi::c_void, idialog: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVfwCaptureDialogs", "HasDialog", 3usize, |this| ((**(this as *const *const IAMVfwCaptureDialogs_Vtbl)).HasDialog)(this, idialog), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowDialog(this: *mut core::ffi::c_void, idialog: i32, hwnd: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVfwCaptureDialogs", "ShowDialog", 4usize, |this| ((**(this as *const *const IAMVfwCaptureDialogs_Vtbl)).ShowDialog)(this, idialog, hwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendDriverMessage(this: *mut core::ffi::c_void, idialog: i32, umsg: i32, dw1: i32, dw2: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVfwCaptureDialogs", "SendDriverMessage", 5usize, |this| ((**(this as *const *const IAMVfwCaptureDialogs_Vtbl)).SendDriverMessage)(this, idialog, umsg, dw1, dw2), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HasDialog, ShowDialog, SendDriverMessage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMVfwCaptureDialogs as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMVfwCompressDialogs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowDialog(this: *mut core::ffi::c_void, idialog: i32, hwnd: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVfwCompressDialogs", "ShowDialog", 3usize, |this| ((**(this as *const *const IAMVfwCompressDialogs_Vtbl)).ShowDialog)(this, idialog, hwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetState(this: *mut core::ffi::c_void, pstate: *mut core::ffi::c_void, pcbstate: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVfwCompressDialogs", "GetState", 4usize, |this| ((**(this as *const *const IAMVfwCompressDialogs_Vtbl)).GetState)(this, pstate, pcbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetState(this: *mut core::ffi::c_void, pstate: *const core::ffi::c_void, cbstate: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVfwCompressDialogs", "SetState", 5usize, |this| ((**(this as *const *const IAMVfwCompressDialogs_Vtbl)).SetState)(this, pstate, cbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendDriverMessage(this: *mut core::ffi::c_void, umsg: i32, dw1: i32, dw2: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVfwCompressDialogs", "SendDriverMessage", 6usize, |this| ((**(this as *const *const IAMVfwCompressDialogs_Vtbl)).SendDriverMessage)(this, umsg, dw1, dw2), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShowDialog, GetState, SetState, SendDriverMessage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMVfwCompressDialogs as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_DirectDraw")]
+impl windows_core::imp::ProxyVtable for IAMVideoAccelerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetVideoAcceleratorGUIDs(this: *mut core::ffi::c_void, pdwnumguidssupported: *mut u32, pguidssupported: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoAccelerator", "GetVideoAcceleratorGUIDs", 3usize, |this| ((**(this as *const *const IAMVideoAccelerator_Vtbl)).GetVideoAcceleratorGUIDs)(this, pdwnumguidssupported, pguidssupported), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn GetUncompFormatsSupported(this: *mut core::ffi::c_void, pguid: *const windows_core::GUID, pdwnumformatssupported: *mut u32, pformatssupported: *mut super::super::Graphics::DirectDraw::DDPIXELFORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoAccelerator", "GetUncompFormatsSupported", 4usize, |this| ((**(this as *const *const IAMVideoAccelerator_Vtbl)).GetUncompFormatsSupported)(this, pguid, pdwnumformatssupported, pformatssupported), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn GetInternalMemInfo(this: *mut core::ffi::c_void, pguid: *const windows_core::GUID, pamvauncompdatainfo: *const AMVAUncompDataInfo, pamvainternalmeminfo: *mut AMVAInternalMemInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoAccelerator", "GetInternalMemInfo", 5usize, |this| ((**(this as *const *const IAMVideoAccelerator_Vtbl)).GetInternalMemInfo)(this, pguid, pamvauncompdatainfo, pamvainternalmeminfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn GetCompBufferInfo(this: *mut core::ffi::c_void, pguid: *const windows_core::GUID, pamvauncompdatainfo: *const AMVAUncompDataInfo, pdwnumtypescompbuffers: *mut u32, pamvacompbufferinfo: *mut AMVACompBufferInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoAccelerator", "GetCompBufferInfo", 6usize, |this| ((**(this as *const *const IAMVideoAccelerator_Vtbl)).GetCompBufferInfo)(this, pguid, pamvauncompdatainfo, pdwnumtypescompbuffers, pamvacompbufferinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn GetInternalCompBufferInfo(this: *mut core::ffi::c_void, pdwnumtypescompbuffers: *mut u32, pamvacompbufferinfo: *mut AMVACompBufferInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoAccelerator", "GetInternalCompBufferInfo", 7usize, |this| ((**(this as *const *const IAMVideoAccelerator_Vtbl)).GetInternalCompBufferInfo)(this, pdwnumtypescompbuffers, pamvacompbufferinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginFrame(this: *mut core::ffi::c_void, amvabeginframeinfo: *const AMVABeginFrameInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoAccelerator", "BeginFrame", 8usize, |this| ((**(this as *const *const IAMVideoAccelerator_Vtbl)).BeginFrame)(this, amvabeginframeinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndFrame(this: *mut core::ffi::c_void, pendframeinfo: *const AMVAEndFrameInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoAccelerator", "EndFrame", 9usize, |this| ((**(this as *const *const IAMVideoAccelerator_Vtbl)).EndFrame)(this, pendframeinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBuffer(this: *mut core::ffi::c_void, dwtypeindex: u32, dwbufferindex: u32, breadonly: super::super::Foundation::BOOL, ppbuffer: *mut *mut core::ffi::c_void, lpstride: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoAccelerator", "GetBuffer", 10usize, |this| ((**(this as *const *const IAMVideoAccelerator_Vtbl)).GetBuffer)(this, dwtypeindex, dwbufferindex, breadonly, ppbuffer, lpstride), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseBuffer(this: *mut core::ffi::c_void, dwtypeindex: u32, dwbufferindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoAccelerator", "ReleaseBuffer", 11usize, |this| ((**(this as *const *const IAMVideoAccelerator_Vtbl)).ReleaseBuffer)(this, dwtypeindex, dwbufferindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn Execute(this: *mut core::ffi::c_void, dwfunction: u32, lpprivateinputdata: *const core::ffi::c_void, cbprivateinputdata: u32, lpprivateoutputdat: *const core::ffi::c_void, cbprivateoutputdata: u32, dwnumbuffers: u32, pamvabufferinfo: *const AMVABUFFERINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoAccelerator", "Execute", 12usize, |this| ((**(this as *const *const IAMVideoAccelerator_Vtbl)).Execute)(this, dwfunction, lpprivateinputdata, cbprivateinputdata, lpprivateoutputdat, cbprivateoutputdata, dwnumbuffers, pamvabufferinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryRenderStatus(this: *mut core::ffi::c_void, dwtypeindex: u32, dwbufferindex: u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoAccelerator", "QueryRenderStatus", 13usize, |this| ((**(this as *const *const IAMVideoAccelerator_Vtbl)).QueryRenderStatus)(this, dwtypeindex, dwbufferindex, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayFrame(this: *mut core::ffi::c_void, dwfliptoindex: u32, pmediasample: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoAccelerator", "DisplayFrame", 14usize, |this| ((**(this as *const *const IAMVideoAccelerator_Vtbl)).DisplayFrame)(this, dwfliptoindex, pmediasample), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetVideoAcceleratorGUIDs,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            GetUncompFormatsSupported,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            GetUncompFormatsSupported: 0,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            GetInternalMemInfo,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            GetInternalMemInfo: 0,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            GetCompBufferInfo,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            GetCompBufferInfo: 0,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            GetInternalCompBufferInfo,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            GetInternalCompBufferInfo: 0,
+            BeginFrame,
+            EndFrame,
+            GetBuffer,
+            ReleaseBuffer,
+            Execute,
+            QueryRenderStatus,
+            DisplayFrame,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMVideoAccelerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_DirectDraw")]
+impl windows_core::imp::ProxyVtable for IAMVideoAcceleratorNotify_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn GetUncompSurfacesInfo(this: *mut core::ffi::c_void, pguid: *const windows_core::GUID, puncompbufferinfo: *mut AMVAUncompBufferInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoAcceleratorNotify", "GetUncompSurfacesInfo", 3usize, |this| ((**(this as *const *const IAMVideoAcceleratorNotify_Vtbl)).GetUncompSurfacesInfo)(this, pguid, puncompbufferinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUncompSurfacesInfo(this: *mut core::ffi::c_void, dwactualuncompsurfacesallocated: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoAcceleratorNotify", "SetUncompSurfacesInfo", 4usize, |this| ((**(this as *const *const IAMVideoAcceleratorNotify_Vtbl)).SetUncompSurfacesInfo)(this, dwactualuncompsurfacesallocated), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCreateVideoAcceleratorData(this: *mut core::ffi::c_void, pguid: *const windows_core::GUID, pdwsizemiscdata: *mut u32, ppmiscdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoAcceleratorNotify", "GetCreateVideoAcceleratorData", 5usize, |this| ((**(this as *const *const IAMVideoAcceleratorNotify_Vtbl)).GetCreateVideoAcceleratorData)(this, pguid, pdwsizemiscdata, ppmiscdata), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            GetUncompSurfacesInfo,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            GetUncompSurfacesInfo: 0,
+            SetUncompSurfacesInfo,
+            GetCreateVideoAcceleratorData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMVideoAcceleratorNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMVideoCompression_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetKeyFrameRate(this: *mut core::ffi::c_void, keyframerate: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoCompression", "SetKeyFrameRate", 3usize, |this| ((**(this as *const *const IAMVideoCompression_Vtbl)).SetKeyFrameRate)(this, keyframerate), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyFrameRate(this: *mut core::ffi::c_void, pkeyframerate: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoCompression", "KeyFrameRate", 4usize, |this| ((**(this as *const *const IAMVideoCompression_Vtbl)).KeyFrameRate)(this, pkeyframerate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPFramesPerKeyFrame(this: *mut core::ffi::c_void, pframesperkeyframe: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoCompression", "SetPFramesPerKeyFrame", 5usize, |this| ((**(this as *const *const IAMVideoCompression_Vtbl)).SetPFramesPerKeyFrame)(this, pframesperkeyframe), |result| Some(*result))
+        }
+        unsafe extern "system" fn PFramesPerKeyFrame(this: *mut core::ffi::c_void, ppframesperkeyframe: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoCompression", "PFramesPerKeyFrame", 6usize, |this| ((**(this as *const *const IAMVideoCompression_Vtbl)).PFramesPerKeyFrame)(this, ppframesperkeyframe), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQuality(this: *mut core::ffi::c_void, quality: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoCompression", "SetQuality", 7usize, |this| ((**(this as *const *const IAMVideoCompression_Vtbl)).SetQuality)(this, quality), |result| Some(*result))
+        }
+        unsafe extern "system" fn Quality(this: *mut core::ffi::c_void, pquality: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoCompression", "Quality", 8usize, |this| ((**(this as *const *const IAMVideoCompression_Vtbl)).Quality)(this, pquality), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWindowSize(this: *mut core::ffi::c_void, windowsize: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoCompression", "SetWindowSize", 9usize, |this| ((**(this as *const *const IAMVideoCompression_Vtbl)).SetWindowSize)(this, windowsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn WindowSize(this: *mut core::ffi::c_void, pwindowsize: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoCompression", "WindowSize", 10usize, |this| ((**(this as *const *const IAMVideoCompression_Vtbl)).WindowSize)(this, pwindowsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInfo(this: *mut core::ffi::c_void, pszversion: windows_core::PWSTR, pcbversion: *mut i32, pszdescription: windows_core::PWSTR, pcbdescription: *mut i32, pdefaultkeyframerate: *mut i32, pdefaultpframesperkey: *mut i32, pdefaultquality: *mut f64, pcapabilities: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoCompression", "GetInfo", 11usize, |this| ((**(this as *const *const IAMVideoCompression_Vtbl)).GetInfo)(this, pszversion, pcbversion, pszdescription, pcbdescription, pdefaultkeyframerate, pdefaultpframesperkey, pdefaultquality, pcapabilities), |result| Some(*result))
+        }
+        unsafe extern "system" fn OverrideKeyFrame(this: *mut core::ffi::c_void, framenumber: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoCompression", "OverrideKeyFrame", 12usize, |this| ((**(this as *const *const IAMVideoCompression_Vtbl)).OverrideKeyFrame)(this, framenumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn OverrideFrameSize(this: *mut core::ffi::c_void, framenumber: i32, size: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoCompression", "OverrideFrameSize", 13usize, |this| ((**(this as *const *const IAMVideoCompression_Vtbl)).OverrideFrameSize)(this, framenumber, size), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetKeyFrameRate,
+            KeyFrameRate,
+            SetPFramesPerKeyFrame,
+            PFramesPerKeyFrame,
+            SetQuality,
+            Quality,
+            SetWindowSize,
+            WindowSize,
+            GetInfo,
+            OverrideKeyFrame,
+            OverrideFrameSize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMVideoCompression as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMVideoControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCaps(this: *mut core::ffi::c_void, ppin: *mut core::ffi::c_void, pcapsflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoControl", "GetCaps", 3usize, |this| ((**(this as *const *const IAMVideoControl_Vtbl)).GetCaps)(this, ppin, pcapsflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMode(this: *mut core::ffi::c_void, ppin: *mut core::ffi::c_void, mode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoControl", "SetMode", 4usize, |this| ((**(this as *const *const IAMVideoControl_Vtbl)).SetMode)(this, ppin, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMode(this: *mut core::ffi::c_void, ppin: *mut core::ffi::c_void, mode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoControl", "GetMode", 5usize, |this| ((**(this as *const *const IAMVideoControl_Vtbl)).GetMode)(this, ppin, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentActualFrameRate(this: *mut core::ffi::c_void, ppin: *mut core::ffi::c_void, actualframerate: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoControl", "GetCurrentActualFrameRate", 6usize, |this| ((**(this as *const *const IAMVideoControl_Vtbl)).GetCurrentActualFrameRate)(this, ppin, actualframerate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxAvailableFrameRate(this: *mut core::ffi::c_void, ppin: *mut core::ffi::c_void, iindex: i32, dimensions: super::super::Foundation::SIZE, maxavailableframerate: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoControl", "GetMaxAvailableFrameRate", 7usize, |this| ((**(this as *const *const IAMVideoControl_Vtbl)).GetMaxAvailableFrameRate)(this, ppin, iindex, dimensions, maxavailableframerate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFrameRateList(this: *mut core::ffi::c_void, ppin: *mut core::ffi::c_void, iindex: i32, dimensions: super::super::Foundation::SIZE, listsize: *mut i32, framerates: *mut *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoControl", "GetFrameRateList", 8usize, |this| ((**(this as *const *const IAMVideoControl_Vtbl)).GetFrameRateList)(this, ppin, iindex, dimensions, listsize, framerates), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCaps,
+            SetMode,
+            GetMode,
+            GetCurrentActualFrameRate,
+            GetMaxAvailableFrameRate,
+            GetFrameRateList,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMVideoControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMVideoDecimationProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryDecimationUsage(this: *mut core::ffi::c_void, lpusage: *mut DECIMATION_USAGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoDecimationProperties", "QueryDecimationUsage", 3usize, |this| ((**(this as *const *const IAMVideoDecimationProperties_Vtbl)).QueryDecimationUsage)(this, lpusage), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDecimationUsage(this: *mut core::ffi::c_void, usage: DECIMATION_USAGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoDecimationProperties", "SetDecimationUsage", 4usize, |this| ((**(this as *const *const IAMVideoDecimationProperties_Vtbl)).SetDecimationUsage)(this, usage), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryDecimationUsage, SetDecimationUsage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMVideoDecimationProperties as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMVideoProcAmp_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRange(this: *mut core::ffi::c_void, property: i32, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoProcAmp", "GetRange", 3usize, |this| ((**(this as *const *const IAMVideoProcAmp_Vtbl)).GetRange)(this, property, pmin, pmax, psteppingdelta, pdefault, pcapsflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Set(this: *mut core::ffi::c_void, property: i32, lvalue: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoProcAmp", "Set", 4usize, |this| ((**(this as *const *const IAMVideoProcAmp_Vtbl)).Set)(this, property, lvalue, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Get(this: *mut core::ffi::c_void, property: i32, lvalue: *mut i32, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMVideoProcAmp", "Get", 5usize, |this| ((**(this as *const *const IAMVideoProcAmp_Vtbl)).Get)(this, property, lvalue, flags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRange, Set, Get }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMVideoProcAmp as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMWMBufferPass_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetNotify(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMWMBufferPass", "SetNotify", 3usize, |this| ((**(this as *const *const IAMWMBufferPass_Vtbl)).SetNotify)(this, pcallback), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetNotify }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMWMBufferPass as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_WindowsMediaFormat")]
+impl windows_core::imp::ProxyVtable for IAMWMBufferPassCallback_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_WindowsMediaFormat")]
+        unsafe extern "system" fn Notify(this: *mut core::ffi::c_void, pnssbuffer3: *mut core::ffi::c_void, ppin: *mut core::ffi::c_void, prtstart: *const i64, prtend: *const i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMWMBufferPassCallback", "Notify", 3usize, |this| ((**(this as *const *const IAMWMBufferPassCallback_Vtbl)).Notify)(this, pnssbuffer3, ppin, prtstart, prtend), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_WindowsMediaFormat")]
+            Notify,
+            #[cfg(not(feature = "Win32_Media_WindowsMediaFormat"))]
+            Notify: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMWMBufferPassCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IAMWstDecoder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDecoderLevel(this: *mut core::ffi::c_void, lplevel: *mut AM_WST_LEVEL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMWstDecoder", "GetDecoderLevel", 3usize, |this| ((**(this as *const *const IAMWstDecoder_Vtbl)).GetDecoderLevel)(this, lplevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentService(this: *mut core::ffi::c_void, lpservice: *mut AM_WST_SERVICE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMWstDecoder", "GetCurrentService", 4usize, |this| ((**(this as *const *const IAMWstDecoder_Vtbl)).GetCurrentService)(this, lpservice), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServiceState(this: *mut core::ffi::c_void, lpstate: *mut AM_WST_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMWstDecoder", "GetServiceState", 5usize, |this| ((**(this as *const *const IAMWstDecoder_Vtbl)).GetServiceState)(this, lpstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServiceState(this: *mut core::ffi::c_void, state: AM_WST_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMWstDecoder", "SetServiceState", 6usize, |this| ((**(this as *const *const IAMWstDecoder_Vtbl)).SetServiceState)(this, state), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetOutputFormat(this: *mut core::ffi::c_void, lpbmih: *mut super::super::Graphics::Gdi::BITMAPINFOHEADER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMWstDecoder", "GetOutputFormat", 7usize, |this| ((**(this as *const *const IAMWstDecoder_Vtbl)).GetOutputFormat)(this, lpbmih), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn SetOutputFormat(this: *mut core::ffi::c_void, lpbmi: *mut super::super::Graphics::Gdi::BITMAPINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMWstDecoder", "SetOutputFormat", 8usize, |this| ((**(this as *const *const IAMWstDecoder_Vtbl)).SetOutputFormat)(this, lpbmi), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBackgroundColor(this: *mut core::ffi::c_void, pdwphyscolor: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMWstDecoder", "GetBackgroundColor", 9usize, |this| ((**(this as *const *const IAMWstDecoder_Vtbl)).GetBackgroundColor)(this, pdwphyscolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackgroundColor(this: *mut core::ffi::c_void, dwphyscolor: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMWstDecoder", "SetBackgroundColor", 10usize, |this| ((**(this as *const *const IAMWstDecoder_Vtbl)).SetBackgroundColor)(this, dwphyscolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRedrawAlways(this: *mut core::ffi::c_void, lpboption: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMWstDecoder", "GetRedrawAlways", 11usize, |this| ((**(this as *const *const IAMWstDecoder_Vtbl)).GetRedrawAlways)(this, lpboption), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRedrawAlways(this: *mut core::ffi::c_void, boption: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMWstDecoder", "SetRedrawAlways", 12usize, |this| ((**(this as *const *const IAMWstDecoder_Vtbl)).SetRedrawAlways)(this, boption), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDrawBackgroundMode(this: *mut core::ffi::c_void, lpmode: *mut AM_WST_DRAWBGMODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMWstDecoder", "GetDrawBackgroundMode", 13usize, |this| ((**(this as *const *const IAMWstDecoder_Vtbl)).GetDrawBackgroundMode)(this, lpmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDrawBackgroundMode(this: *mut core::ffi::c_void, mode: AM_WST_DRAWBGMODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMWstDecoder", "SetDrawBackgroundMode", 14usize, |this| ((**(this as *const *const IAMWstDecoder_Vtbl)).SetDrawBackgroundMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAnswerMode(this: *mut core::ffi::c_void, banswer: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMWstDecoder", "SetAnswerMode", 15usize, |this| ((**(this as *const *const IAMWstDecoder_Vtbl)).SetAnswerMode)(this, banswer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAnswerMode(this: *mut core::ffi::c_void, pbanswer: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMWstDecoder", "GetAnswerMode", 16usize, |this| ((**(this as *const *const IAMWstDecoder_Vtbl)).GetAnswerMode)(this, pbanswer), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHoldPage(this: *mut core::ffi::c_void, bholdpage: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMWstDecoder", "SetHoldPage", 17usize, |this| ((**(this as *const *const IAMWstDecoder_Vtbl)).SetHoldPage)(this, bholdpage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHoldPage(this: *mut core::ffi::c_void, pbholdpage: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMWstDecoder", "GetHoldPage", 18usize, |this| ((**(this as *const *const IAMWstDecoder_Vtbl)).GetHoldPage)(this, pbholdpage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentPage(this: *mut core::ffi::c_void, pwstpage: *mut AM_WST_PAGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMWstDecoder", "GetCurrentPage", 19usize, |this| ((**(this as *const *const IAMWstDecoder_Vtbl)).GetCurrentPage)(this, pwstpage), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentPage(this: *mut core::ffi::c_void, wstpage: AM_WST_PAGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMWstDecoder", "SetCurrentPage", 20usize, |this| ((**(this as *const *const IAMWstDecoder_Vtbl)).SetCurrentPage)(this, wstpage), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDecoderLevel,
+            GetCurrentService,
+            GetServiceState,
+            SetServiceState,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetOutputFormat,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetOutputFormat: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            SetOutputFormat,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            SetOutputFormat: 0,
+            GetBackgroundColor,
+            SetBackgroundColor,
+            GetRedrawAlways,
+            SetRedrawAlways,
+            GetDrawBackgroundMode,
+            SetDrawBackgroundMode,
+            SetAnswerMode,
+            GetAnswerMode,
+            SetHoldPage,
+            GetHoldPage,
+            GetCurrentPage,
+            SetCurrentPage,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMWstDecoder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAMovieSetup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Register(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMovieSetup", "Register", 3usize, |this| ((**(this as *const *const IAMovieSetup_Vtbl)).Register)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unregister(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAMovieSetup", "Unregister", 4usize, |this| ((**(this as *const *const IAMovieSetup_Vtbl)).Unregister)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Register, Unregister }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAMovieSetup as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAsyncReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestAllocator(this: *mut core::ffi::c_void, ppreferred: *mut core::ffi::c_void, pprops: *const ALLOCATOR_PROPERTIES, ppactual: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncReader", "RequestAllocator", 3usize, |this| ((**(this as *const *const IAsyncReader_Vtbl)).RequestAllocator)(this, ppreferred, pprops, ppactual), |result| Some(*result))
+        }
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, psample: *mut core::ffi::c_void, dwuser: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncReader", "Request", 4usize, |this| ((**(this as *const *const IAsyncReader_Vtbl)).Request)(this, psample, dwuser), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForNext(this: *mut core::ffi::c_void, dwtimeout: u32, ppsample: *mut *mut core::ffi::c_void, pdwuser: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncReader", "WaitForNext", 5usize, |this| ((**(this as *const *const IAsyncReader_Vtbl)).WaitForNext)(this, dwtimeout, ppsample, pdwuser), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncReadAligned(this: *mut core::ffi::c_void, psample: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncReader", "SyncReadAligned", 6usize, |this| ((**(this as *const *const IAsyncReader_Vtbl)).SyncReadAligned)(this, psample), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncRead(this: *mut core::ffi::c_void, llposition: i64, llength: i32, pbuffer: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncReader", "SyncRead", 7usize, |this| ((**(this as *const *const IAsyncReader_Vtbl)).SyncRead)(this, llposition, llength, pbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, ptotal: *mut i64, pavailable: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncReader", "Length", 8usize, |this| ((**(this as *const *const IAsyncReader_Vtbl)).Length)(this, ptotal, pavailable), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginFlush(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncReader", "BeginFlush", 9usize, |this| ((**(this as *const *const IAsyncReader_Vtbl)).BeginFlush)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndFlush(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncReader", "EndFlush", 10usize, |this| ((**(this as *const *const IAsyncReader_Vtbl)).EndFlush)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestAllocator,
+            Request,
+            WaitForNext,
+            SyncReadAligned,
+            SyncRead,
+            Length,
+            BeginFlush,
+            EndFlush,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAsyncReader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_Audio")]
+impl windows_core::imp::ProxyVtable for IAudioData_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn GetFormat(this: *mut core::ffi::c_void, pwaveformatcurrent: *mut super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioData", "GetFormat", 6usize, |this| ((**(this as *const *const IAudioData_Vtbl)).GetFormat)(this, pwaveformatcurrent), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn SetFormat(this: *mut core::ffi::c_void, lpwaveformat: *const super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioData", "SetFormat", 7usize, |this| ((**(this as *const *const IAudioData_Vtbl)).SetFormat)(this, lpwaveformat), |result| Some(*result))
+        }
+        Self {
+            base__: <IMemoryData_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_Audio")]
+            GetFormat,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            GetFormat: 0,
+            #[cfg(feature = "Win32_Media_Audio")]
+            SetFormat,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            SetFormat: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioData as windows_core::Interface>::IID || <IMemoryData_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_Audio")]
+impl windows_core::imp::ProxyVtable for IAudioMediaStream_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn GetFormat(this: *mut core::ffi::c_void, pwaveformatcurrent: *mut super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioMediaStream", "GetFormat", 9usize, |this| ((**(this as *const *const IAudioMediaStream_Vtbl)).GetFormat)(this, pwaveformatcurrent), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn SetFormat(this: *mut core::ffi::c_void, lpwaveformat: *const super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioMediaStream", "SetFormat", 10usize, |this| ((**(this as *const *const IAudioMediaStream_Vtbl)).SetFormat)(this, lpwaveformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSample(this: *mut core::ffi::c_void, paudiodata: *mut core::ffi::c_void, dwflags: u32, ppsample: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioMediaStream", "CreateSample", 11usize, |this| ((**(this as *const *const IAudioMediaStream_Vtbl)).CreateSample)(this, paudiodata, dwflags, ppsample), |result| Some(*result))
+        }
+        Self {
+            base__: <IMediaStream_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_Audio")]
+            GetFormat,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            GetFormat: 0,
+            #[cfg(feature = "Win32_Media_Audio")]
+            SetFormat,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            SetFormat: 0,
+            CreateSample,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioMediaStream as windows_core::Interface>::IID || <IMediaStream_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioStreamSample_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAudioData(this: *mut core::ffi::c_void, ppaudio: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioStreamSample", "GetAudioData", 8usize, |this| ((**(this as *const *const IAudioStreamSample_Vtbl)).GetAudioData)(this, ppaudio), |result| Some(*result))
+        }
+        Self { base__: <IStreamSample_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAudioData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioStreamSample as windows_core::Interface>::IID || <IStreamSample_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_AUX_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryCapabilities(this: *mut core::ffi::c_void, pdwnumauxinputsbstr: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_AUX", "QueryCapabilities", 3usize, |this| ((**(this as *const *const IBDA_AUX_Vtbl)).QueryCapabilities)(this, pdwnumauxinputsbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumCapability(this: *mut core::ffi::c_void, dwindex: u32, dwinputid: *mut u32, pconnectortype: *mut windows_core::GUID, conntypenum: *mut u32, numvideostds: *mut u32, analogstds: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_AUX", "EnumCapability", 4usize, |this| ((**(this as *const *const IBDA_AUX_Vtbl)).EnumCapability)(this, dwindex, dwinputid, pconnectortype, conntypenum, numvideostds, analogstds), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryCapabilities, EnumCapability }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_AUX as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_AutoDemodulate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn put_AutoDemodulate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_AutoDemodulate", "put_AutoDemodulate", 3usize, |this| ((**(this as *const *const IBDA_AutoDemodulate_Vtbl)).put_AutoDemodulate)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, put_AutoDemodulate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_AutoDemodulate as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_AutoDemodulateEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_SupportedDeviceNodeTypes(this: *mut core::ffi::c_void, ulcdevicenodetypesmax: u32, pulcdevicenodetypes: *mut u32, pguiddevicenodetypes: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_AutoDemodulateEx", "get_SupportedDeviceNodeTypes", 4usize, |this| ((**(this as *const *const IBDA_AutoDemodulateEx_Vtbl)).get_SupportedDeviceNodeTypes)(this, ulcdevicenodetypesmax, pulcdevicenodetypes, pguiddevicenodetypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_SupportedVideoFormats(this: *mut core::ffi::c_void, pulamtunermodetype: *mut u32, pulanalogvideostandard: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_AutoDemodulateEx", "get_SupportedVideoFormats", 5usize, |this| ((**(this as *const *const IBDA_AutoDemodulateEx_Vtbl)).get_SupportedVideoFormats)(this, pulamtunermodetype, pulanalogvideostandard), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_AuxInputCount(this: *mut core::ffi::c_void, pulcompositecount: *mut u32, pulsvideocount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_AutoDemodulateEx", "get_AuxInputCount", 6usize, |this| ((**(this as *const *const IBDA_AutoDemodulateEx_Vtbl)).get_AuxInputCount)(this, pulcompositecount, pulsvideocount), |result| Some(*result))
+        }
+        Self {
+            base__: <IBDA_AutoDemodulate_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            get_SupportedDeviceNodeTypes,
+            get_SupportedVideoFormats,
+            get_AuxInputCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_AutoDemodulateEx as windows_core::Interface>::IID || <IBDA_AutoDemodulate_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_ConditionalAccess_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_SmartCardStatus(this: *mut core::ffi::c_void, pcardstatus: *mut SmartCardStatusType, pcardassociation: *mut SmartCardAssociationType, pbstrcarderror: *mut core::mem::MaybeUninit<windows_core::BSTR>, pfooblocked: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_ConditionalAccess", "get_SmartCardStatus", 3usize, |this| ((**(this as *const *const IBDA_ConditionalAccess_Vtbl)).get_SmartCardStatus)(this, pcardstatus, pcardassociation, pbstrcarderror, pfooblocked), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_SmartCardInfo(this: *mut core::ffi::c_void, pbstrcardname: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrcardmanufacturer: *mut core::mem::MaybeUninit<windows_core::BSTR>, pfdaylightsavings: *mut super::super::Foundation::VARIANT_BOOL, pbyratingregion: *mut u8, pltimezoneoffsetminutes: *mut i32, pbstrlanguage: *mut core::mem::MaybeUninit<windows_core::BSTR>, pealocationcode: *mut EALocationCodeType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_ConditionalAccess", "get_SmartCardInfo", 4usize, |this| ((**(this as *const *const IBDA_ConditionalAccess_Vtbl)).get_SmartCardInfo)(this, pbstrcardname, pbstrcardmanufacturer, pfdaylightsavings, pbyratingregion, pltimezoneoffsetminutes, pbstrlanguage, pealocationcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_SmartCardApplications(this: *mut core::ffi::c_void, pulcapplications: *mut u32, ulcapplicationsmax: u32, rgapplications: *mut SmartCardApplication) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_ConditionalAccess", "get_SmartCardApplications", 5usize, |this| ((**(this as *const *const IBDA_ConditionalAccess_Vtbl)).get_SmartCardApplications)(this, pulcapplications, ulcapplicationsmax, rgapplications), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Entitlement(this: *mut core::ffi::c_void, usvirtualchannel: u16, pentitlement: *mut EntitlementType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_ConditionalAccess", "get_Entitlement", 6usize, |this| ((**(this as *const *const IBDA_ConditionalAccess_Vtbl)).get_Entitlement)(this, usvirtualchannel, pentitlement), |result| Some(*result))
+        }
+        unsafe extern "system" fn TuneByChannel(this: *mut core::ffi::c_void, usvirtualchannel: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_ConditionalAccess", "TuneByChannel", 7usize, |this| ((**(this as *const *const IBDA_ConditionalAccess_Vtbl)).TuneByChannel)(this, usvirtualchannel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProgram(this: *mut core::ffi::c_void, usprogramnumber: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_ConditionalAccess", "SetProgram", 8usize, |this| ((**(this as *const *const IBDA_ConditionalAccess_Vtbl)).SetProgram)(this, usprogramnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddProgram(this: *mut core::ffi::c_void, usprogramnumber: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_ConditionalAccess", "AddProgram", 9usize, |this| ((**(this as *const *const IBDA_ConditionalAccess_Vtbl)).AddProgram)(this, usprogramnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveProgram(this: *mut core::ffi::c_void, usprogramnumber: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_ConditionalAccess", "RemoveProgram", 10usize, |this| ((**(this as *const *const IBDA_ConditionalAccess_Vtbl)).RemoveProgram)(this, usprogramnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetModuleUI(this: *mut core::ffi::c_void, bydialognumber: u8, pbstrurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_ConditionalAccess", "GetModuleUI", 11usize, |this| ((**(this as *const *const IBDA_ConditionalAccess_Vtbl)).GetModuleUI)(this, bydialognumber, pbstrurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn InformUIClosed(this: *mut core::ffi::c_void, bydialognumber: u8, closereason: UICloseReasonType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_ConditionalAccess", "InformUIClosed", 12usize, |this| ((**(this as *const *const IBDA_ConditionalAccess_Vtbl)).InformUIClosed)(this, bydialognumber, closereason), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            get_SmartCardStatus,
+            get_SmartCardInfo,
+            get_SmartCardApplications,
+            get_Entitlement,
+            TuneByChannel,
+            SetProgram,
+            AddProgram,
+            RemoveProgram,
+            GetModuleUI,
+            InformUIClosed,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_ConditionalAccess as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_ConditionalAccessEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CheckEntitlementToken(this: *mut core::ffi::c_void, uldialogrequest: u32, bstrlanguage: core::mem::MaybeUninit<windows_core::BSTR>, requesttype: BDA_CONDITIONALACCESS_REQUESTTYPE, ulcbentitlementtokenlen: u32, pbentitlementtoken: *const u8, puldescramblestatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_ConditionalAccessEx", "CheckEntitlementToken", 3usize, |this| ((**(this as *const *const IBDA_ConditionalAccessEx_Vtbl)).CheckEntitlementToken)(this, uldialogrequest, bstrlanguage, requesttype, ulcbentitlementtokenlen, pbentitlementtoken, puldescramblestatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCaptureToken(this: *mut core::ffi::c_void, ulcbcapturetokenlen: u32, pbcapturetoken: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_ConditionalAccessEx", "SetCaptureToken", 4usize, |this| ((**(this as *const *const IBDA_ConditionalAccessEx_Vtbl)).SetCaptureToken)(this, ulcbcapturetokenlen, pbcapturetoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenBroadcastMmi(this: *mut core::ffi::c_void, uldialogrequest: u32, bstrlanguage: core::mem::MaybeUninit<windows_core::BSTR>, eventid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_ConditionalAccessEx", "OpenBroadcastMmi", 5usize, |this| ((**(this as *const *const IBDA_ConditionalAccessEx_Vtbl)).OpenBroadcastMmi)(this, uldialogrequest, bstrlanguage, eventid), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseMmiDialog(this: *mut core::ffi::c_void, uldialogrequest: u32, bstrlanguage: core::mem::MaybeUninit<windows_core::BSTR>, uldialognumber: u32, reasoncode: BDA_CONDITIONALACCESS_MMICLOSEREASON, pulsessionresult: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_ConditionalAccessEx", "CloseMmiDialog", 6usize, |this| ((**(this as *const *const IBDA_ConditionalAccessEx_Vtbl)).CloseMmiDialog)(this, uldialogrequest, bstrlanguage, uldialognumber, reasoncode, pulsessionresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDialogRequestNumber(this: *mut core::ffi::c_void, puldialogrequestnumber: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_ConditionalAccessEx", "CreateDialogRequestNumber", 7usize, |this| ((**(this as *const *const IBDA_ConditionalAccessEx_Vtbl)).CreateDialogRequestNumber)(this, puldialogrequestnumber), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CheckEntitlementToken,
+            SetCaptureToken,
+            OpenBroadcastMmi,
+            CloseMmiDialog,
+            CreateDialogRequestNumber,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_ConditionalAccessEx as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_DRIDRMService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDRM(this: *mut core::ffi::c_void, bstrnewdrm: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DRIDRMService", "SetDRM", 3usize, |this| ((**(this as *const *const IBDA_DRIDRMService_Vtbl)).SetDRM)(this, bstrnewdrm), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDRMStatus(this: *mut core::ffi::c_void, pbstrdrmuuidlist: *mut core::mem::MaybeUninit<windows_core::BSTR>, drmuuid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DRIDRMService", "GetDRMStatus", 4usize, |this| ((**(this as *const *const IBDA_DRIDRMService_Vtbl)).GetDRMStatus)(this, pbstrdrmuuidlist, drmuuid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPairingStatus(this: *mut core::ffi::c_void, penumpairingstatus: *mut BDA_DrmPairingError) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DRIDRMService", "GetPairingStatus", 5usize, |this| ((**(this as *const *const IBDA_DRIDRMService_Vtbl)).GetPairingStatus)(this, penumpairingstatus), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetDRM, GetDRMStatus, GetPairingStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_DRIDRMService as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_DRIWMDRMSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AcknowledgeLicense(this: *mut core::ffi::c_void, hrlicenseack: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DRIWMDRMSession", "AcknowledgeLicense", 3usize, |this| ((**(this as *const *const IBDA_DRIWMDRMSession_Vtbl)).AcknowledgeLicense)(this, hrlicenseack), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessLicenseChallenge(this: *mut core::ffi::c_void, dwcblicensemessage: u32, pblicensemessage: *const u8, pdwcblicenseresponse: *mut u32, ppblicenseresponse: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DRIWMDRMSession", "ProcessLicenseChallenge", 4usize, |this| ((**(this as *const *const IBDA_DRIWMDRMSession_Vtbl)).ProcessLicenseChallenge)(this, dwcblicensemessage, pblicensemessage, pdwcblicenseresponse, ppblicenseresponse), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessRegistrationChallenge(this: *mut core::ffi::c_void, dwcbregistrationmessage: u32, pbregistrationmessage: *const u8, pdwcbregistrationresponse: *mut u32, ppbregistrationresponse: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DRIWMDRMSession", "ProcessRegistrationChallenge", 5usize, |this| ((**(this as *const *const IBDA_DRIWMDRMSession_Vtbl)).ProcessRegistrationChallenge)(this, dwcbregistrationmessage, pbregistrationmessage, pdwcbregistrationresponse, ppbregistrationresponse), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRevInfo(this: *mut core::ffi::c_void, dwrevinfolen: u32, pbrevinfo: *const u8, pdwresponse: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DRIWMDRMSession", "SetRevInfo", 6usize, |this| ((**(this as *const *const IBDA_DRIWMDRMSession_Vtbl)).SetRevInfo)(this, dwrevinfolen, pbrevinfo, pdwresponse), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCrl(this: *mut core::ffi::c_void, dwcrllen: u32, pbcrllen: *const u8, pdwresponse: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DRIWMDRMSession", "SetCrl", 7usize, |this| ((**(this as *const *const IBDA_DRIWMDRMSession_Vtbl)).SetCrl)(this, dwcrllen, pbcrllen, pdwresponse), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHMSAssociationData(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DRIWMDRMSession", "GetHMSAssociationData", 8usize, |this| ((**(this as *const *const IBDA_DRIWMDRMSession_Vtbl)).GetHMSAssociationData)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastCardeaError(this: *mut core::ffi::c_void, pdwerror: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DRIWMDRMSession", "GetLastCardeaError", 9usize, |this| ((**(this as *const *const IBDA_DRIWMDRMSession_Vtbl)).GetLastCardeaError)(this, pdwerror), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AcknowledgeLicense,
+            ProcessLicenseChallenge,
+            ProcessRegistrationChallenge,
+            SetRevInfo,
+            SetCrl,
+            GetHMSAssociationData,
+            GetLastCardeaError,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_DRIWMDRMSession as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_DRM_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDRMPairingStatus(this: *mut core::ffi::c_void, pdwstatus: *mut u32, pherror: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DRM", "GetDRMPairingStatus", 3usize, |this| ((**(this as *const *const IBDA_DRM_Vtbl)).GetDRMPairingStatus)(this, pdwstatus, pherror), |result| Some(*result))
+        }
+        unsafe extern "system" fn PerformDRMPairing(this: *mut core::ffi::c_void, fsync: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DRM", "PerformDRMPairing", 4usize, |this| ((**(this as *const *const IBDA_DRM_Vtbl)).PerformDRMPairing)(this, fsync), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDRMPairingStatus, PerformDRMPairing }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_DRM as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_DRMService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDRM(this: *mut core::ffi::c_void, puuidnewdrm: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DRMService", "SetDRM", 3usize, |this| ((**(this as *const *const IBDA_DRMService_Vtbl)).SetDRM)(this, puuidnewdrm), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDRMStatus(this: *mut core::ffi::c_void, pbstrdrmuuidlist: *mut core::mem::MaybeUninit<windows_core::BSTR>, drmuuid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DRMService", "GetDRMStatus", 4usize, |this| ((**(this as *const *const IBDA_DRMService_Vtbl)).GetDRMStatus)(this, pbstrdrmuuidlist, drmuuid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetDRM, GetDRMStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_DRMService as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_DeviceControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartChanges(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DeviceControl", "StartChanges", 3usize, |this| ((**(this as *const *const IBDA_DeviceControl_Vtbl)).StartChanges)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckChanges(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DeviceControl", "CheckChanges", 4usize, |this| ((**(this as *const *const IBDA_DeviceControl_Vtbl)).CheckChanges)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommitChanges(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DeviceControl", "CommitChanges", 5usize, |this| ((**(this as *const *const IBDA_DeviceControl_Vtbl)).CommitChanges)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChangeState(this: *mut core::ffi::c_void, pstate: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DeviceControl", "GetChangeState", 6usize, |this| ((**(this as *const *const IBDA_DeviceControl_Vtbl)).GetChangeState)(this, pstate), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartChanges, CheckChanges, CommitChanges, GetChangeState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_DeviceControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for IBDA_DiagnosticProperties_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::System::Com::StructuredStorage::IPropertyBag_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_DiagnosticProperties as windows_core::Interface>::IID || <super::super::System::Com::StructuredStorage::IPropertyBag_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_DigitalDemodulator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetModulationType(this: *mut core::ffi::c_void, pmodulationtype: *const ModulationType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DigitalDemodulator", "SetModulationType", 3usize, |this| ((**(this as *const *const IBDA_DigitalDemodulator_Vtbl)).SetModulationType)(this, pmodulationtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModulationType(this: *mut core::ffi::c_void, pmodulationtype: *mut ModulationType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DigitalDemodulator", "ModulationType", 4usize, |this| ((**(this as *const *const IBDA_DigitalDemodulator_Vtbl)).ModulationType)(this, pmodulationtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInnerFECMethod(this: *mut core::ffi::c_void, pfecmethod: *const FECMethod) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DigitalDemodulator", "SetInnerFECMethod", 5usize, |this| ((**(this as *const *const IBDA_DigitalDemodulator_Vtbl)).SetInnerFECMethod)(this, pfecmethod), |result| Some(*result))
+        }
+        unsafe extern "system" fn InnerFECMethod(this: *mut core::ffi::c_void, pfecmethod: *mut FECMethod) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DigitalDemodulator", "InnerFECMethod", 6usize, |this| ((**(this as *const *const IBDA_DigitalDemodulator_Vtbl)).InnerFECMethod)(this, pfecmethod), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInnerFECRate(this: *mut core::ffi::c_void, pfecrate: *const BinaryConvolutionCodeRate) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DigitalDemodulator", "SetInnerFECRate", 7usize, |this| ((**(this as *const *const IBDA_DigitalDemodulator_Vtbl)).SetInnerFECRate)(this, pfecrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn InnerFECRate(this: *mut core::ffi::c_void, pfecrate: *mut BinaryConvolutionCodeRate) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DigitalDemodulator", "InnerFECRate", 8usize, |this| ((**(this as *const *const IBDA_DigitalDemodulator_Vtbl)).InnerFECRate)(this, pfecrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOuterFECMethod(this: *mut core::ffi::c_void, pfecmethod: *const FECMethod) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DigitalDemodulator", "SetOuterFECMethod", 9usize, |this| ((**(this as *const *const IBDA_DigitalDemodulator_Vtbl)).SetOuterFECMethod)(this, pfecmethod), |result| Some(*result))
+        }
+        unsafe extern "system" fn OuterFECMethod(this: *mut core::ffi::c_void, pfecmethod: *mut FECMethod) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DigitalDemodulator", "OuterFECMethod", 10usize, |this| ((**(this as *const *const IBDA_DigitalDemodulator_Vtbl)).OuterFECMethod)(this, pfecmethod), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOuterFECRate(this: *mut core::ffi::c_void, pfecrate: *const BinaryConvolutionCodeRate) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DigitalDemodulator", "SetOuterFECRate", 11usize, |this| ((**(this as *const *const IBDA_DigitalDemodulator_Vtbl)).SetOuterFECRate)(this, pfecrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn OuterFECRate(this: *mut core::ffi::c_void, pfecrate: *mut BinaryConvolutionCodeRate) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DigitalDemodulator", "OuterFECRate", 12usize, |this| ((**(this as *const *const IBDA_DigitalDemodulator_Vtbl)).OuterFECRate)(this, pfecrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSymbolRate(this: *mut core::ffi::c_void, psymbolrate: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DigitalDemodulator", "SetSymbolRate", 13usize, |this| ((**(this as *const *const IBDA_DigitalDemodulator_Vtbl)).SetSymbolRate)(this, psymbolrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SymbolRate(this: *mut core::ffi::c_void, psymbolrate: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DigitalDemodulator", "SymbolRate", 14usize, |this| ((**(this as *const *const IBDA_DigitalDemodulator_Vtbl)).SymbolRate)(this, psymbolrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSpectralInversion(this: *mut core::ffi::c_void, pspectralinversion: *const SpectralInversion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DigitalDemodulator", "SetSpectralInversion", 15usize, |this| ((**(this as *const *const IBDA_DigitalDemodulator_Vtbl)).SetSpectralInversion)(this, pspectralinversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn SpectralInversion(this: *mut core::ffi::c_void, pspectralinversion: *mut SpectralInversion) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DigitalDemodulator", "SpectralInversion", 16usize, |this| ((**(this as *const *const IBDA_DigitalDemodulator_Vtbl)).SpectralInversion)(this, pspectralinversion), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetModulationType,
+            ModulationType,
+            SetInnerFECMethod,
+            InnerFECMethod,
+            SetInnerFECRate,
+            InnerFECRate,
+            SetOuterFECMethod,
+            OuterFECMethod,
+            SetOuterFECRate,
+            OuterFECRate,
+            SetSymbolRate,
+            SymbolRate,
+            SetSpectralInversion,
+            SpectralInversion,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_DigitalDemodulator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_DigitalDemodulator2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetGuardInterval(this: *mut core::ffi::c_void, pguardinterval: *const GuardInterval) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DigitalDemodulator2", "SetGuardInterval", 17usize, |this| ((**(this as *const *const IBDA_DigitalDemodulator2_Vtbl)).SetGuardInterval)(this, pguardinterval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GuardInterval(this: *mut core::ffi::c_void, pguardinterval: *mut GuardInterval) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DigitalDemodulator2", "GuardInterval", 18usize, |this| ((**(this as *const *const IBDA_DigitalDemodulator2_Vtbl)).GuardInterval)(this, pguardinterval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransmissionMode(this: *mut core::ffi::c_void, ptransmissionmode: *const TransmissionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DigitalDemodulator2", "SetTransmissionMode", 19usize, |this| ((**(this as *const *const IBDA_DigitalDemodulator2_Vtbl)).SetTransmissionMode)(this, ptransmissionmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransmissionMode(this: *mut core::ffi::c_void, ptransmissionmode: *mut TransmissionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DigitalDemodulator2", "TransmissionMode", 20usize, |this| ((**(this as *const *const IBDA_DigitalDemodulator2_Vtbl)).TransmissionMode)(this, ptransmissionmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRollOff(this: *mut core::ffi::c_void, prolloff: *const RollOff) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DigitalDemodulator2", "SetRollOff", 21usize, |this| ((**(this as *const *const IBDA_DigitalDemodulator2_Vtbl)).SetRollOff)(this, prolloff), |result| Some(*result))
+        }
+        unsafe extern "system" fn RollOff(this: *mut core::ffi::c_void, prolloff: *mut RollOff) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DigitalDemodulator2", "RollOff", 22usize, |this| ((**(this as *const *const IBDA_DigitalDemodulator2_Vtbl)).RollOff)(this, prolloff), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPilot(this: *mut core::ffi::c_void, ppilot: *const Pilot) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DigitalDemodulator2", "SetPilot", 23usize, |this| ((**(this as *const *const IBDA_DigitalDemodulator2_Vtbl)).SetPilot)(this, ppilot), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pilot(this: *mut core::ffi::c_void, ppilot: *mut Pilot) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DigitalDemodulator2", "Pilot", 24usize, |this| ((**(this as *const *const IBDA_DigitalDemodulator2_Vtbl)).Pilot)(this, ppilot), |result| Some(*result))
+        }
+        Self {
+            base__: <IBDA_DigitalDemodulator_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetGuardInterval,
+            GuardInterval,
+            SetTransmissionMode,
+            TransmissionMode,
+            SetRollOff,
+            RollOff,
+            SetPilot,
+            Pilot,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_DigitalDemodulator2 as windows_core::Interface>::IID || <IBDA_DigitalDemodulator_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_DigitalDemodulator3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSignalTimeouts(this: *mut core::ffi::c_void, psignaltimeouts: *const BDA_SIGNAL_TIMEOUTS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DigitalDemodulator3", "SetSignalTimeouts", 25usize, |this| ((**(this as *const *const IBDA_DigitalDemodulator3_Vtbl)).SetSignalTimeouts)(this, psignaltimeouts), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignalTimeouts(this: *mut core::ffi::c_void, psignaltimeouts: *mut BDA_SIGNAL_TIMEOUTS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DigitalDemodulator3", "SignalTimeouts", 26usize, |this| ((**(this as *const *const IBDA_DigitalDemodulator3_Vtbl)).SignalTimeouts)(this, psignaltimeouts), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPLPNumber(this: *mut core::ffi::c_void, pplpnumber: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DigitalDemodulator3", "SetPLPNumber", 27usize, |this| ((**(this as *const *const IBDA_DigitalDemodulator3_Vtbl)).SetPLPNumber)(this, pplpnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn PLPNumber(this: *mut core::ffi::c_void, pplpnumber: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DigitalDemodulator3", "PLPNumber", 28usize, |this| ((**(this as *const *const IBDA_DigitalDemodulator3_Vtbl)).PLPNumber)(this, pplpnumber), |result| Some(*result))
+        }
+        Self { base__: <IBDA_DigitalDemodulator2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetSignalTimeouts, SignalTimeouts, SetPLPNumber, PLPNumber }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_DigitalDemodulator3 as windows_core::Interface>::IID || <IBDA_DigitalDemodulator2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_DiseqCommand_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetEnableDiseqCommands(this: *mut core::ffi::c_void, benable: super::super::Foundation::BOOLEAN) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DiseqCommand", "SetEnableDiseqCommands", 3usize, |this| ((**(this as *const *const IBDA_DiseqCommand_Vtbl)).SetEnableDiseqCommands)(this, benable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDiseqLNBSource(this: *mut core::ffi::c_void, ullnbsource: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DiseqCommand", "SetDiseqLNBSource", 4usize, |this| ((**(this as *const *const IBDA_DiseqCommand_Vtbl)).SetDiseqLNBSource)(this, ullnbsource), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDiseqUseToneBurst(this: *mut core::ffi::c_void, busetoneburst: super::super::Foundation::BOOLEAN) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DiseqCommand", "SetDiseqUseToneBurst", 5usize, |this| ((**(this as *const *const IBDA_DiseqCommand_Vtbl)).SetDiseqUseToneBurst)(this, busetoneburst), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDiseqRepeats(this: *mut core::ffi::c_void, ulrepeats: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DiseqCommand", "SetDiseqRepeats", 6usize, |this| ((**(this as *const *const IBDA_DiseqCommand_Vtbl)).SetDiseqRepeats)(this, ulrepeats), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_DiseqSendCommand(this: *mut core::ffi::c_void, ulrequestid: u32, ulcbcommandlen: u32, pbcommand: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DiseqCommand", "put_DiseqSendCommand", 7usize, |this| ((**(this as *const *const IBDA_DiseqCommand_Vtbl)).put_DiseqSendCommand)(this, ulrequestid, ulcbcommandlen, pbcommand), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_DiseqResponse(this: *mut core::ffi::c_void, ulrequestid: u32, pulcbresponselen: *mut u32, pbresponse: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_DiseqCommand", "get_DiseqResponse", 8usize, |this| ((**(this as *const *const IBDA_DiseqCommand_Vtbl)).get_DiseqResponse)(this, ulrequestid, pulcbresponselen, pbresponse), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetEnableDiseqCommands,
+            SetDiseqLNBSource,
+            SetDiseqUseToneBurst,
+            SetDiseqRepeats,
+            put_DiseqSendCommand,
+            get_DiseqResponse,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_DiseqCommand as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_EasMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_EasMessage(this: *mut core::ffi::c_void, uleventid: u32, ppeasobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_EasMessage", "get_EasMessage", 3usize, |this| ((**(this as *const *const IBDA_EasMessage_Vtbl)).get_EasMessage)(this, uleventid, ppeasobject), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, get_EasMessage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_EasMessage as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_Encoder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryCapabilities(this: *mut core::ffi::c_void, numaudiofmts: *mut u32, numvideofmts: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_Encoder", "QueryCapabilities", 3usize, |this| ((**(this as *const *const IBDA_Encoder_Vtbl)).QueryCapabilities)(this, numaudiofmts, numvideofmts), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumAudioCapability(this: *mut core::ffi::c_void, fmtindex: u32, methodid: *mut u32, algorithmtype: *mut u32, samplingrate: *mut u32, bitdepth: *mut u32, numchannels: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_Encoder", "EnumAudioCapability", 4usize, |this| ((**(this as *const *const IBDA_Encoder_Vtbl)).EnumAudioCapability)(this, fmtindex, methodid, algorithmtype, samplingrate, bitdepth, numchannels), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumVideoCapability(this: *mut core::ffi::c_void, fmtindex: u32, methodid: *mut u32, algorithmtype: *mut u32, verticalsize: *mut u32, horizontalsize: *mut u32, aspectratio: *mut u32, frameratecode: *mut u32, progressivesequence: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_Encoder", "EnumVideoCapability", 5usize, |this| ((**(this as *const *const IBDA_Encoder_Vtbl)).EnumVideoCapability)(this, fmtindex, methodid, algorithmtype, verticalsize, horizontalsize, aspectratio, frameratecode, progressivesequence), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParameters(this: *mut core::ffi::c_void, audiobitratemode: u32, audiobitrate: u32, audiomethodid: u32, audioprogram: u32, videobitratemode: u32, videobitrate: u32, videomethodid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_Encoder", "SetParameters", 6usize, |this| ((**(this as *const *const IBDA_Encoder_Vtbl)).SetParameters)(this, audiobitratemode, audiobitrate, audiomethodid, audioprogram, videobitratemode, videobitrate, videomethodid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetState(this: *mut core::ffi::c_void, audiobitratemax: *mut u32, audiobitratemin: *mut u32, audiobitratemode: *mut u32, audiobitratestepping: *mut u32, audiobitrate: *mut u32, audiomethodid: *mut u32, availableaudioprograms: *mut u32, audioprogram: *mut u32, videobitratemax: *mut u32, videobitratemin: *mut u32, videobitratemode: *mut u32, videobitrate: *mut u32, videobitratestepping: *mut u32, videomethodid: *mut u32, signalsourceid: *mut u32, signalformat: *mut u64, signallock: *mut super::super::Foundation::BOOL, signallevel: *mut i32, signaltonoiseratio: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_Encoder", "GetState", 7usize, |this| ((**(this as *const *const IBDA_Encoder_Vtbl)).GetState)(this, audiobitratemax, audiobitratemin, audiobitratemode, audiobitratestepping, audiobitrate, audiomethodid, availableaudioprograms, audioprogram, videobitratemax, videobitratemin, videobitratemode, videobitrate, videobitratestepping, videomethodid, signalsourceid, signalformat, signallock, signallevel, signaltonoiseratio), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            QueryCapabilities,
+            EnumAudioCapability,
+            EnumVideoCapability,
+            SetParameters,
+            GetState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_Encoder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_EthernetFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMulticastListSize(this: *mut core::ffi::c_void, pulcbaddresses: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_EthernetFilter", "GetMulticastListSize", 3usize, |this| ((**(this as *const *const IBDA_EthernetFilter_Vtbl)).GetMulticastListSize)(this, pulcbaddresses), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutMulticastList(this: *mut core::ffi::c_void, ulcbaddresses: u32, paddresslist: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_EthernetFilter", "PutMulticastList", 4usize, |this| ((**(this as *const *const IBDA_EthernetFilter_Vtbl)).PutMulticastList)(this, ulcbaddresses, paddresslist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMulticastList(this: *mut core::ffi::c_void, pulcbaddresses: *mut u32, paddresslist: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_EthernetFilter", "GetMulticastList", 5usize, |this| ((**(this as *const *const IBDA_EthernetFilter_Vtbl)).GetMulticastList)(this, pulcbaddresses, paddresslist), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutMulticastMode(this: *mut core::ffi::c_void, ulmodemask: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_EthernetFilter", "PutMulticastMode", 6usize, |this| ((**(this as *const *const IBDA_EthernetFilter_Vtbl)).PutMulticastMode)(this, ulmodemask), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMulticastMode(this: *mut core::ffi::c_void, pulmodemask: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_EthernetFilter", "GetMulticastMode", 7usize, |this| ((**(this as *const *const IBDA_EthernetFilter_Vtbl)).GetMulticastMode)(this, pulmodemask), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetMulticastListSize,
+            PutMulticastList,
+            GetMulticastList,
+            PutMulticastMode,
+            GetMulticastMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_EthernetFilter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_EventingService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CompleteEvent(this: *mut core::ffi::c_void, uleventid: u32, uleventresult: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_EventingService", "CompleteEvent", 3usize, |this| ((**(this as *const *const IBDA_EventingService_Vtbl)).CompleteEvent)(this, uleventid, uleventresult), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CompleteEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_EventingService as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_FDC_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, currentbitrate: *mut u32, carrierlock: *mut super::super::Foundation::BOOL, currentfrequency: *mut u32, currentspectruminversion: *mut super::super::Foundation::BOOL, currentpidlist: *mut core::mem::MaybeUninit<windows_core::BSTR>, currenttidlist: *mut core::mem::MaybeUninit<windows_core::BSTR>, overflow: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_FDC", "GetStatus", 3usize, |this| ((**(this as *const *const IBDA_FDC_Vtbl)).GetStatus)(this, currentbitrate, carrierlock, currentfrequency, currentspectruminversion, currentpidlist, currenttidlist, overflow), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestTables(this: *mut core::ffi::c_void, tableids: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_FDC", "RequestTables", 4usize, |this| ((**(this as *const *const IBDA_FDC_Vtbl)).RequestTables)(this, tableids), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPid(this: *mut core::ffi::c_void, pidstoadd: core::mem::MaybeUninit<windows_core::BSTR>, remainingfilterentries: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_FDC", "AddPid", 5usize, |this| ((**(this as *const *const IBDA_FDC_Vtbl)).AddPid)(this, pidstoadd, remainingfilterentries), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePid(this: *mut core::ffi::c_void, pidstoremove: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_FDC", "RemovePid", 6usize, |this| ((**(this as *const *const IBDA_FDC_Vtbl)).RemovePid)(this, pidstoremove), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTid(this: *mut core::ffi::c_void, tidstoadd: core::mem::MaybeUninit<windows_core::BSTR>, currenttidlist: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_FDC", "AddTid", 7usize, |this| ((**(this as *const *const IBDA_FDC_Vtbl)).AddTid)(this, tidstoadd, currenttidlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTid(this: *mut core::ffi::c_void, tidstoremove: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_FDC", "RemoveTid", 8usize, |this| ((**(this as *const *const IBDA_FDC_Vtbl)).RemoveTid)(this, tidstoremove), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableSection(this: *mut core::ffi::c_void, pid: *mut u32, maxbuffersize: u32, actualsize: *mut u32, secbuffer: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_FDC", "GetTableSection", 9usize, |this| ((**(this as *const *const IBDA_FDC_Vtbl)).GetTableSection)(this, pid, maxbuffersize, actualsize, secbuffer), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetStatus,
+            RequestTables,
+            AddPid,
+            RemovePid,
+            AddTid,
+            RemoveTid,
+            GetTableSection,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_FDC as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_FrequencyFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAutotune(this: *mut core::ffi::c_void, ultransponder: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_FrequencyFilter", "SetAutotune", 3usize, |this| ((**(this as *const *const IBDA_FrequencyFilter_Vtbl)).SetAutotune)(this, ultransponder), |result| Some(*result))
+        }
+        unsafe extern "system" fn Autotune(this: *mut core::ffi::c_void, pultransponder: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_FrequencyFilter", "Autotune", 4usize, |this| ((**(this as *const *const IBDA_FrequencyFilter_Vtbl)).Autotune)(this, pultransponder), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFrequency(this: *mut core::ffi::c_void, ulfrequency: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_FrequencyFilter", "SetFrequency", 5usize, |this| ((**(this as *const *const IBDA_FrequencyFilter_Vtbl)).SetFrequency)(this, ulfrequency), |result| Some(*result))
+        }
+        unsafe extern "system" fn Frequency(this: *mut core::ffi::c_void, pulfrequency: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_FrequencyFilter", "Frequency", 6usize, |this| ((**(this as *const *const IBDA_FrequencyFilter_Vtbl)).Frequency)(this, pulfrequency), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPolarity(this: *mut core::ffi::c_void, polarity: Polarisation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_FrequencyFilter", "SetPolarity", 7usize, |this| ((**(this as *const *const IBDA_FrequencyFilter_Vtbl)).SetPolarity)(this, polarity), |result| Some(*result))
+        }
+        unsafe extern "system" fn Polarity(this: *mut core::ffi::c_void, ppolarity: *mut Polarisation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_FrequencyFilter", "Polarity", 8usize, |this| ((**(this as *const *const IBDA_FrequencyFilter_Vtbl)).Polarity)(this, ppolarity), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRange(this: *mut core::ffi::c_void, ulrange: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_FrequencyFilter", "SetRange", 9usize, |this| ((**(this as *const *const IBDA_FrequencyFilter_Vtbl)).SetRange)(this, ulrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn Range(this: *mut core::ffi::c_void, pulrange: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_FrequencyFilter", "Range", 10usize, |this| ((**(this as *const *const IBDA_FrequencyFilter_Vtbl)).Range)(this, pulrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBandwidth(this: *mut core::ffi::c_void, ulbandwidth: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_FrequencyFilter", "SetBandwidth", 11usize, |this| ((**(this as *const *const IBDA_FrequencyFilter_Vtbl)).SetBandwidth)(this, ulbandwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bandwidth(this: *mut core::ffi::c_void, pulbandwidth: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_FrequencyFilter", "Bandwidth", 12usize, |this| ((**(this as *const *const IBDA_FrequencyFilter_Vtbl)).Bandwidth)(this, pulbandwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFrequencyMultiplier(this: *mut core::ffi::c_void, ulmultiplier: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_FrequencyFilter", "SetFrequencyMultiplier", 13usize, |this| ((**(this as *const *const IBDA_FrequencyFilter_Vtbl)).SetFrequencyMultiplier)(this, ulmultiplier), |result| Some(*result))
+        }
+        unsafe extern "system" fn FrequencyMultiplier(this: *mut core::ffi::c_void, pulmultiplier: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_FrequencyFilter", "FrequencyMultiplier", 14usize, |this| ((**(this as *const *const IBDA_FrequencyFilter_Vtbl)).FrequencyMultiplier)(this, pulmultiplier), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetAutotune,
+            Autotune,
+            SetFrequency,
+            Frequency,
+            SetPolarity,
+            Polarity,
+            SetRange,
+            Range,
+            SetBandwidth,
+            Bandwidth,
+            SetFrequencyMultiplier,
+            FrequencyMultiplier,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_FrequencyFilter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_GuideDataDeliveryService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetGuideDataType(this: *mut core::ffi::c_void, pguiddatatype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_GuideDataDeliveryService", "GetGuideDataType", 3usize, |this| ((**(this as *const *const IBDA_GuideDataDeliveryService_Vtbl)).GetGuideDataType)(this, pguiddatatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGuideData(this: *mut core::ffi::c_void, pulcbbufferlen: *mut u32, pbbuffer: *mut u8, pulguidedatapercentageprogress: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_GuideDataDeliveryService", "GetGuideData", 4usize, |this| ((**(this as *const *const IBDA_GuideDataDeliveryService_Vtbl)).GetGuideData)(this, pulcbbufferlen, pbbuffer, pulguidedatapercentageprogress), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestGuideDataUpdate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_GuideDataDeliveryService", "RequestGuideDataUpdate", 5usize, |this| ((**(this as *const *const IBDA_GuideDataDeliveryService_Vtbl)).RequestGuideDataUpdate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTuneXmlFromServiceIdx(this: *mut core::ffi::c_void, ul64serviceidx: u64, pbstrtunexml: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_GuideDataDeliveryService", "GetTuneXmlFromServiceIdx", 6usize, |this| ((**(this as *const *const IBDA_GuideDataDeliveryService_Vtbl)).GetTuneXmlFromServiceIdx)(this, ul64serviceidx, pbstrtunexml), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServices(this: *mut core::ffi::c_void, pulcbbufferlen: *mut u32, pbbuffer: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_GuideDataDeliveryService", "GetServices", 7usize, |this| ((**(this as *const *const IBDA_GuideDataDeliveryService_Vtbl)).GetServices)(this, pulcbbufferlen, pbbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServiceInfoFromTuneXml(this: *mut core::ffi::c_void, bstrtunexml: core::mem::MaybeUninit<windows_core::BSTR>, pbstrservicedescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_GuideDataDeliveryService", "GetServiceInfoFromTuneXml", 8usize, |this| ((**(this as *const *const IBDA_GuideDataDeliveryService_Vtbl)).GetServiceInfoFromTuneXml)(this, bstrtunexml, pbstrservicedescription), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetGuideDataType,
+            GetGuideData,
+            RequestGuideDataUpdate,
+            GetTuneXmlFromServiceIdx,
+            GetServices,
+            GetServiceInfoFromTuneXml,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_GuideDataDeliveryService as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_IPSinkControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMulticastList(this: *mut core::ffi::c_void, pulcbsize: *mut u32, pbbuffer: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_IPSinkControl", "GetMulticastList", 3usize, |this| ((**(this as *const *const IBDA_IPSinkControl_Vtbl)).GetMulticastList)(this, pulcbsize, pbbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAdapterIPAddress(this: *mut core::ffi::c_void, pulcbsize: *mut u32, pbbuffer: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_IPSinkControl", "GetAdapterIPAddress", 4usize, |this| ((**(this as *const *const IBDA_IPSinkControl_Vtbl)).GetAdapterIPAddress)(this, pulcbsize, pbbuffer), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMulticastList, GetAdapterIPAddress }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_IPSinkControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_IPSinkInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_MulticastList(this: *mut core::ffi::c_void, pulcbaddresses: *mut u32, ppbaddresslist: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_IPSinkInfo", "get_MulticastList", 3usize, |this| ((**(this as *const *const IBDA_IPSinkInfo_Vtbl)).get_MulticastList)(this, pulcbaddresses, ppbaddresslist), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdapterIPAddress(this: *mut core::ffi::c_void, pbstrbuffer: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_IPSinkInfo", "AdapterIPAddress", 4usize, |this| ((**(this as *const *const IBDA_IPSinkInfo_Vtbl)).AdapterIPAddress)(this, pbstrbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdapterDescription(this: *mut core::ffi::c_void, pbstrbuffer: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_IPSinkInfo", "AdapterDescription", 5usize, |this| ((**(this as *const *const IBDA_IPSinkInfo_Vtbl)).AdapterDescription)(this, pbstrbuffer), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, get_MulticastList, AdapterIPAddress, AdapterDescription }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_IPSinkInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_IPV4Filter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMulticastListSize(this: *mut core::ffi::c_void, pulcbaddresses: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_IPV4Filter", "GetMulticastListSize", 3usize, |this| ((**(this as *const *const IBDA_IPV4Filter_Vtbl)).GetMulticastListSize)(this, pulcbaddresses), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutMulticastList(this: *mut core::ffi::c_void, ulcbaddresses: u32, paddresslist: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_IPV4Filter", "PutMulticastList", 4usize, |this| ((**(this as *const *const IBDA_IPV4Filter_Vtbl)).PutMulticastList)(this, ulcbaddresses, paddresslist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMulticastList(this: *mut core::ffi::c_void, pulcbaddresses: *mut u32, paddresslist: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_IPV4Filter", "GetMulticastList", 5usize, |this| ((**(this as *const *const IBDA_IPV4Filter_Vtbl)).GetMulticastList)(this, pulcbaddresses, paddresslist), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutMulticastMode(this: *mut core::ffi::c_void, ulmodemask: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_IPV4Filter", "PutMulticastMode", 6usize, |this| ((**(this as *const *const IBDA_IPV4Filter_Vtbl)).PutMulticastMode)(this, ulmodemask), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMulticastMode(this: *mut core::ffi::c_void, pulmodemask: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_IPV4Filter", "GetMulticastMode", 7usize, |this| ((**(this as *const *const IBDA_IPV4Filter_Vtbl)).GetMulticastMode)(this, pulmodemask), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetMulticastListSize,
+            PutMulticastList,
+            GetMulticastList,
+            PutMulticastMode,
+            GetMulticastMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_IPV4Filter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_IPV6Filter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMulticastListSize(this: *mut core::ffi::c_void, pulcbaddresses: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_IPV6Filter", "GetMulticastListSize", 3usize, |this| ((**(this as *const *const IBDA_IPV6Filter_Vtbl)).GetMulticastListSize)(this, pulcbaddresses), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutMulticastList(this: *mut core::ffi::c_void, ulcbaddresses: u32, paddresslist: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_IPV6Filter", "PutMulticastList", 4usize, |this| ((**(this as *const *const IBDA_IPV6Filter_Vtbl)).PutMulticastList)(this, ulcbaddresses, paddresslist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMulticastList(this: *mut core::ffi::c_void, pulcbaddresses: *mut u32, paddresslist: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_IPV6Filter", "GetMulticastList", 5usize, |this| ((**(this as *const *const IBDA_IPV6Filter_Vtbl)).GetMulticastList)(this, pulcbaddresses, paddresslist), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutMulticastMode(this: *mut core::ffi::c_void, ulmodemask: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_IPV6Filter", "PutMulticastMode", 6usize, |this| ((**(this as *const *const IBDA_IPV6Filter_Vtbl)).PutMulticastMode)(this, ulmodemask), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMulticastMode(this: *mut core::ffi::c_void, pulmodemask: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_IPV6Filter", "GetMulticastMode", 7usize, |this| ((**(this as *const *const IBDA_IPV6Filter_Vtbl)).GetMulticastMode)(this, pulmodemask), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetMulticastListSize,
+            PutMulticastList,
+            GetMulticastList,
+            PutMulticastMode,
+            GetMulticastMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_IPV6Filter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_ISDBConditionalAccess_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetIsdbCasRequest(this: *mut core::ffi::c_void, ulrequestid: u32, ulcbrequestbufferlen: u32, pbrequestbuffer: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_ISDBConditionalAccess", "SetIsdbCasRequest", 3usize, |this| ((**(this as *const *const IBDA_ISDBConditionalAccess_Vtbl)).SetIsdbCasRequest)(this, ulrequestid, ulcbrequestbufferlen, pbrequestbuffer), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetIsdbCasRequest }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_ISDBConditionalAccess as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_LNBInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetLocalOscilatorFrequencyLowBand(this: *mut core::ffi::c_void, ulloflow: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_LNBInfo", "SetLocalOscilatorFrequencyLowBand", 3usize, |this| ((**(this as *const *const IBDA_LNBInfo_Vtbl)).SetLocalOscilatorFrequencyLowBand)(this, ulloflow), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalOscilatorFrequencyLowBand(this: *mut core::ffi::c_void, pulloflow: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_LNBInfo", "LocalOscilatorFrequencyLowBand", 4usize, |this| ((**(this as *const *const IBDA_LNBInfo_Vtbl)).LocalOscilatorFrequencyLowBand)(this, pulloflow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocalOscilatorFrequencyHighBand(this: *mut core::ffi::c_void, ullofhigh: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_LNBInfo", "SetLocalOscilatorFrequencyHighBand", 5usize, |this| ((**(this as *const *const IBDA_LNBInfo_Vtbl)).SetLocalOscilatorFrequencyHighBand)(this, ullofhigh), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalOscilatorFrequencyHighBand(this: *mut core::ffi::c_void, pullofhigh: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_LNBInfo", "LocalOscilatorFrequencyHighBand", 6usize, |this| ((**(this as *const *const IBDA_LNBInfo_Vtbl)).LocalOscilatorFrequencyHighBand)(this, pullofhigh), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHighLowSwitchFrequency(this: *mut core::ffi::c_void, ulswitchfrequency: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_LNBInfo", "SetHighLowSwitchFrequency", 7usize, |this| ((**(this as *const *const IBDA_LNBInfo_Vtbl)).SetHighLowSwitchFrequency)(this, ulswitchfrequency), |result| Some(*result))
+        }
+        unsafe extern "system" fn HighLowSwitchFrequency(this: *mut core::ffi::c_void, pulswitchfrequency: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_LNBInfo", "HighLowSwitchFrequency", 8usize, |this| ((**(this as *const *const IBDA_LNBInfo_Vtbl)).HighLowSwitchFrequency)(this, pulswitchfrequency), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetLocalOscilatorFrequencyLowBand,
+            LocalOscilatorFrequencyLowBand,
+            SetLocalOscilatorFrequencyHighBand,
+            LocalOscilatorFrequencyHighBand,
+            SetHighLowSwitchFrequency,
+            HighLowSwitchFrequency,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_LNBInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_MUX_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPidList(this: *mut core::ffi::c_void, ulpidlistcount: u32, pbpidlistbuffer: *const BDA_MUX_PIDLISTITEM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_MUX", "SetPidList", 3usize, |this| ((**(this as *const *const IBDA_MUX_Vtbl)).SetPidList)(this, ulpidlistcount, pbpidlistbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPidList(this: *mut core::ffi::c_void, pulpidlistcount: *mut u32, pbpidlistbuffer: *mut BDA_MUX_PIDLISTITEM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_MUX", "GetPidList", 4usize, |this| ((**(this as *const *const IBDA_MUX_Vtbl)).GetPidList)(this, pulpidlistcount, pbpidlistbuffer), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetPidList, GetPidList }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_MUX as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_NameValueService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetValueNameByIndex(this: *mut core::ffi::c_void, ulindex: u32, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_NameValueService", "GetValueNameByIndex", 3usize, |this| ((**(this as *const *const IBDA_NameValueService_Vtbl)).GetValueNameByIndex)(this, ulindex, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, bstrlanguage: core::mem::MaybeUninit<windows_core::BSTR>, pbstrvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_NameValueService", "GetValue", 4usize, |this| ((**(this as *const *const IBDA_NameValueService_Vtbl)).GetValue)(this, bstrname, bstrlanguage, pbstrvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, uldialogrequest: u32, bstrlanguage: core::mem::MaybeUninit<windows_core::BSTR>, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, bstrvalue: core::mem::MaybeUninit<windows_core::BSTR>, ulreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_NameValueService", "SetValue", 5usize, |this| ((**(this as *const *const IBDA_NameValueService_Vtbl)).SetValue)(this, uldialogrequest, bstrlanguage, bstrname, bstrvalue, ulreserved), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetValueNameByIndex, GetValue, SetValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_NameValueService as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_NetworkProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PutSignalSource(this: *mut core::ffi::c_void, ulsignalsource: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_NetworkProvider", "PutSignalSource", 3usize, |this| ((**(this as *const *const IBDA_NetworkProvider_Vtbl)).PutSignalSource)(this, ulsignalsource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignalSource(this: *mut core::ffi::c_void, pulsignalsource: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_NetworkProvider", "GetSignalSource", 4usize, |this| ((**(this as *const *const IBDA_NetworkProvider_Vtbl)).GetSignalSource)(this, pulsignalsource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNetworkType(this: *mut core::ffi::c_void, pguidnetworktype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_NetworkProvider", "GetNetworkType", 5usize, |this| ((**(this as *const *const IBDA_NetworkProvider_Vtbl)).GetNetworkType)(this, pguidnetworktype), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutTuningSpace(this: *mut core::ffi::c_void, guidtuningspace: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_NetworkProvider", "PutTuningSpace", 6usize, |this| ((**(this as *const *const IBDA_NetworkProvider_Vtbl)).PutTuningSpace)(this, guidtuningspace), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTuningSpace(this: *mut core::ffi::c_void, pguidtuingspace: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_NetworkProvider", "GetTuningSpace", 7usize, |this| ((**(this as *const *const IBDA_NetworkProvider_Vtbl)).GetTuningSpace)(this, pguidtuingspace), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterDeviceFilter(this: *mut core::ffi::c_void, punkfiltercontrol: *mut core::ffi::c_void, ppvregisitrationcontext: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_NetworkProvider", "RegisterDeviceFilter", 8usize, |this| ((**(this as *const *const IBDA_NetworkProvider_Vtbl)).RegisterDeviceFilter)(this, punkfiltercontrol, ppvregisitrationcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnRegisterDeviceFilter(this: *mut core::ffi::c_void, pvregistrationcontext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_NetworkProvider", "UnRegisterDeviceFilter", 9usize, |this| ((**(this as *const *const IBDA_NetworkProvider_Vtbl)).UnRegisterDeviceFilter)(this, pvregistrationcontext), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PutSignalSource,
+            GetSignalSource,
+            GetNetworkType,
+            PutTuningSpace,
+            GetTuningSpace,
+            RegisterDeviceFilter,
+            UnRegisterDeviceFilter,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_NetworkProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_NullTransform_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_NullTransform", "Start", 3usize, |this| ((**(this as *const *const IBDA_NullTransform_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_NullTransform", "Stop", 4usize, |this| ((**(this as *const *const IBDA_NullTransform_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Start, Stop }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_NullTransform as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_PinControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPinID(this: *mut core::ffi::c_void, pulpinid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_PinControl", "GetPinID", 3usize, |this| ((**(this as *const *const IBDA_PinControl_Vtbl)).GetPinID)(this, pulpinid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPinType(this: *mut core::ffi::c_void, pulpintype: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_PinControl", "GetPinType", 4usize, |this| ((**(this as *const *const IBDA_PinControl_Vtbl)).GetPinType)(this, pulpintype), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegistrationContext(this: *mut core::ffi::c_void, pulregistrationctx: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_PinControl", "RegistrationContext", 5usize, |this| ((**(this as *const *const IBDA_PinControl_Vtbl)).RegistrationContext)(this, pulregistrationctx), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPinID, GetPinType, RegistrationContext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_PinControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_SignalProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PutNetworkType(this: *mut core::ffi::c_void, guidnetworktype: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_SignalProperties", "PutNetworkType", 3usize, |this| ((**(this as *const *const IBDA_SignalProperties_Vtbl)).PutNetworkType)(this, guidnetworktype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNetworkType(this: *mut core::ffi::c_void, pguidnetworktype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_SignalProperties", "GetNetworkType", 4usize, |this| ((**(this as *const *const IBDA_SignalProperties_Vtbl)).GetNetworkType)(this, pguidnetworktype), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutSignalSource(this: *mut core::ffi::c_void, ulsignalsource: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_SignalProperties", "PutSignalSource", 5usize, |this| ((**(this as *const *const IBDA_SignalProperties_Vtbl)).PutSignalSource)(this, ulsignalsource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignalSource(this: *mut core::ffi::c_void, pulsignalsource: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_SignalProperties", "GetSignalSource", 6usize, |this| ((**(this as *const *const IBDA_SignalProperties_Vtbl)).GetSignalSource)(this, pulsignalsource), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutTuningSpace(this: *mut core::ffi::c_void, guidtuningspace: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_SignalProperties", "PutTuningSpace", 7usize, |this| ((**(this as *const *const IBDA_SignalProperties_Vtbl)).PutTuningSpace)(this, guidtuningspace), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTuningSpace(this: *mut core::ffi::c_void, pguidtuingspace: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_SignalProperties", "GetTuningSpace", 8usize, |this| ((**(this as *const *const IBDA_SignalProperties_Vtbl)).GetTuningSpace)(this, pguidtuingspace), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PutNetworkType,
+            GetNetworkType,
+            PutSignalSource,
+            GetSignalSource,
+            PutTuningSpace,
+            GetTuningSpace,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_SignalProperties as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_SignalStatistics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSignalStrength(this: *mut core::ffi::c_void, ldbstrength: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_SignalStatistics", "SetSignalStrength", 3usize, |this| ((**(this as *const *const IBDA_SignalStatistics_Vtbl)).SetSignalStrength)(this, ldbstrength), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignalStrength(this: *mut core::ffi::c_void, pldbstrength: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_SignalStatistics", "SignalStrength", 4usize, |this| ((**(this as *const *const IBDA_SignalStatistics_Vtbl)).SignalStrength)(this, pldbstrength), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSignalQuality(this: *mut core::ffi::c_void, lpercentquality: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_SignalStatistics", "SetSignalQuality", 5usize, |this| ((**(this as *const *const IBDA_SignalStatistics_Vtbl)).SetSignalQuality)(this, lpercentquality), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignalQuality(this: *mut core::ffi::c_void, plpercentquality: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_SignalStatistics", "SignalQuality", 6usize, |this| ((**(this as *const *const IBDA_SignalStatistics_Vtbl)).SignalQuality)(this, plpercentquality), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSignalPresent(this: *mut core::ffi::c_void, fpresent: super::super::Foundation::BOOLEAN) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_SignalStatistics", "SetSignalPresent", 7usize, |this| ((**(this as *const *const IBDA_SignalStatistics_Vtbl)).SetSignalPresent)(this, fpresent), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignalPresent(this: *mut core::ffi::c_void, pfpresent: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_SignalStatistics", "SignalPresent", 8usize, |this| ((**(this as *const *const IBDA_SignalStatistics_Vtbl)).SignalPresent)(this, pfpresent), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSignalLocked(this: *mut core::ffi::c_void, flocked: super::super::Foundation::BOOLEAN) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_SignalStatistics", "SetSignalLocked", 9usize, |this| ((**(this as *const *const IBDA_SignalStatistics_Vtbl)).SetSignalLocked)(this, flocked), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignalLocked(this: *mut core::ffi::c_void, pflocked: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_SignalStatistics", "SignalLocked", 10usize, |this| ((**(this as *const *const IBDA_SignalStatistics_Vtbl)).SignalLocked)(this, pflocked), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSampleTime(this: *mut core::ffi::c_void, lmssampletime: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_SignalStatistics", "SetSampleTime", 11usize, |this| ((**(this as *const *const IBDA_SignalStatistics_Vtbl)).SetSampleTime)(this, lmssampletime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SampleTime(this: *mut core::ffi::c_void, plmssampletime: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_SignalStatistics", "SampleTime", 12usize, |this| ((**(this as *const *const IBDA_SignalStatistics_Vtbl)).SampleTime)(this, plmssampletime), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetSignalStrength,
+            SignalStrength,
+            SetSignalQuality,
+            SignalQuality,
+            SetSignalPresent,
+            SignalPresent,
+            SetSignalLocked,
+            SignalLocked,
+            SetSampleTime,
+            SampleTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_SignalStatistics as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_MediaFoundation")]
+impl windows_core::imp::ProxyVtable for IBDA_Topology_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNodeTypes(this: *mut core::ffi::c_void, pulcnodetypes: *mut u32, ulcnodetypesmax: u32, rgulnodetypes: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_Topology", "GetNodeTypes", 3usize, |this| ((**(this as *const *const IBDA_Topology_Vtbl)).GetNodeTypes)(this, pulcnodetypes, ulcnodetypesmax, rgulnodetypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNodeDescriptors(this: *mut core::ffi::c_void, ulcnodedescriptors: *mut u32, ulcnodedescriptorsmax: u32, rgnodedescriptors: *mut BDANODE_DESCRIPTOR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_Topology", "GetNodeDescriptors", 4usize, |this| ((**(this as *const *const IBDA_Topology_Vtbl)).GetNodeDescriptors)(this, ulcnodedescriptors, ulcnodedescriptorsmax, rgnodedescriptors), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNodeInterfaces(this: *mut core::ffi::c_void, ulnodetype: u32, pulcinterfaces: *mut u32, ulcinterfacesmax: u32, rgguidinterfaces: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_Topology", "GetNodeInterfaces", 5usize, |this| ((**(this as *const *const IBDA_Topology_Vtbl)).GetNodeInterfaces)(this, ulnodetype, pulcinterfaces, ulcinterfacesmax, rgguidinterfaces), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPinTypes(this: *mut core::ffi::c_void, pulcpintypes: *mut u32, ulcpintypesmax: u32, rgulpintypes: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_Topology", "GetPinTypes", 6usize, |this| ((**(this as *const *const IBDA_Topology_Vtbl)).GetPinTypes)(this, pulcpintypes, ulcpintypesmax, rgulpintypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTemplateConnections(this: *mut core::ffi::c_void, pulcconnections: *mut u32, ulcconnectionsmax: u32, rgconnections: *mut BDA_TEMPLATE_CONNECTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_Topology", "GetTemplateConnections", 7usize, |this| ((**(this as *const *const IBDA_Topology_Vtbl)).GetTemplateConnections)(this, pulcconnections, ulcconnectionsmax, rgconnections), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePin(this: *mut core::ffi::c_void, ulpintype: u32, pulpinid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_Topology", "CreatePin", 8usize, |this| ((**(this as *const *const IBDA_Topology_Vtbl)).CreatePin)(this, ulpintype, pulpinid), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeletePin(this: *mut core::ffi::c_void, ulpinid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_Topology", "DeletePin", 9usize, |this| ((**(this as *const *const IBDA_Topology_Vtbl)).DeletePin)(this, ulpinid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn SetMediaType(this: *mut core::ffi::c_void, ulpinid: u32, pmediatype: *const super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_Topology", "SetMediaType", 10usize, |this| ((**(this as *const *const IBDA_Topology_Vtbl)).SetMediaType)(this, ulpinid, pmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMedium(this: *mut core::ffi::c_void, ulpinid: u32, pmedium: *const REGPINMEDIUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_Topology", "SetMedium", 11usize, |this| ((**(this as *const *const IBDA_Topology_Vtbl)).SetMedium)(this, ulpinid, pmedium), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTopology(this: *mut core::ffi::c_void, ulinputpinid: u32, uloutputpinid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_Topology", "CreateTopology", 12usize, |this| ((**(this as *const *const IBDA_Topology_Vtbl)).CreateTopology)(this, ulinputpinid, uloutputpinid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetControlNode(this: *mut core::ffi::c_void, ulinputpinid: u32, uloutputpinid: u32, ulnodetype: u32, ppcontrolnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_Topology", "GetControlNode", 13usize, |this| ((**(this as *const *const IBDA_Topology_Vtbl)).GetControlNode)(this, ulinputpinid, uloutputpinid, ulnodetype, ppcontrolnode), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetNodeTypes,
+            GetNodeDescriptors,
+            GetNodeInterfaces,
+            GetPinTypes,
+            GetTemplateConnections,
+            CreatePin,
+            DeletePin,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            SetMediaType,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            SetMediaType: 0,
+            SetMedium,
+            CreateTopology,
+            GetControlNode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_Topology as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_TransportStreamInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PatTableTickCount(this: *mut core::ffi::c_void, ppattickcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_TransportStreamInfo", "PatTableTickCount", 3usize, |this| ((**(this as *const *const IBDA_TransportStreamInfo_Vtbl)).PatTableTickCount)(this, ppattickcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PatTableTickCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_TransportStreamInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_TransportStreamSelector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetTSID(this: *mut core::ffi::c_void, ustsid: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_TransportStreamSelector", "SetTSID", 3usize, |this| ((**(this as *const *const IBDA_TransportStreamSelector_Vtbl)).SetTSID)(this, ustsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTSInformation(this: *mut core::ffi::c_void, pultsinformationbufferlen: *mut u32, pbtsinformationbuffer: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_TransportStreamSelector", "GetTSInformation", 4usize, |this| ((**(this as *const *const IBDA_TransportStreamSelector_Vtbl)).GetTSInformation)(this, pultsinformationbufferlen, pbtsinformationbuffer), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetTSID, GetTSInformation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_TransportStreamSelector as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_UserActivityService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetCurrentTunerUseReason(this: *mut core::ffi::c_void, dwusereason: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_UserActivityService", "SetCurrentTunerUseReason", 3usize, |this| ((**(this as *const *const IBDA_UserActivityService_Vtbl)).SetCurrentTunerUseReason)(this, dwusereason), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUserActivityInterval(this: *mut core::ffi::c_void, pdwactivityinterval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_UserActivityService", "GetUserActivityInterval", 4usize, |this| ((**(this as *const *const IBDA_UserActivityService_Vtbl)).GetUserActivityInterval)(this, pdwactivityinterval), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserActivityDetected(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_UserActivityService", "UserActivityDetected", 5usize, |this| ((**(this as *const *const IBDA_UserActivityService_Vtbl)).UserActivityDetected)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetCurrentTunerUseReason,
+            GetUserActivityInterval,
+            UserActivityDetected,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_UserActivityService as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_VoidTransform_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_VoidTransform", "Start", 3usize, |this| ((**(this as *const *const IBDA_VoidTransform_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_VoidTransform", "Stop", 4usize, |this| ((**(this as *const *const IBDA_VoidTransform_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Start, Stop }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_VoidTransform as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_WMDRMSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, maxcapturetoken: *mut u32, maxstreamingpid: *mut u32, maxlicense: *mut u32, minsecuritylevel: *mut u32, revinfosequencenumber: *mut u32, revinfoissuedtime: *mut u64, revinfottl: *mut u32, revlistversion: *mut u32, ulstate: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_WMDRMSession", "GetStatus", 3usize, |this| ((**(this as *const *const IBDA_WMDRMSession_Vtbl)).GetStatus)(this, maxcapturetoken, maxstreamingpid, maxlicense, minsecuritylevel, revinfosequencenumber, revinfoissuedtime, revinfottl, revlistversion, ulstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRevInfo(this: *mut core::ffi::c_void, ulrevinfolen: u32, pbrevinfo: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_WMDRMSession", "SetRevInfo", 4usize, |this| ((**(this as *const *const IBDA_WMDRMSession_Vtbl)).SetRevInfo)(this, ulrevinfolen, pbrevinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCrl(this: *mut core::ffi::c_void, ulcrllen: u32, pbcrllen: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_WMDRMSession", "SetCrl", 5usize, |this| ((**(this as *const *const IBDA_WMDRMSession_Vtbl)).SetCrl)(this, ulcrllen, pbcrllen), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransactMessage(this: *mut core::ffi::c_void, ulcbrequest: u32, pbrequest: *const u8, pulcbresponse: *mut u32, pbresponse: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_WMDRMSession", "TransactMessage", 6usize, |this| ((**(this as *const *const IBDA_WMDRMSession_Vtbl)).TransactMessage)(this, ulcbrequest, pbrequest, pulcbresponse, pbresponse), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLicense(this: *mut core::ffi::c_void, uuidkey: *const windows_core::GUID, pulpackagelen: *mut u32, pbpackage: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_WMDRMSession", "GetLicense", 7usize, |this| ((**(this as *const *const IBDA_WMDRMSession_Vtbl)).GetLicense)(this, uuidkey, pulpackagelen, pbpackage), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReissueLicense(this: *mut core::ffi::c_void, uuidkey: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_WMDRMSession", "ReissueLicense", 8usize, |this| ((**(this as *const *const IBDA_WMDRMSession_Vtbl)).ReissueLicense)(this, uuidkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn RenewLicense(this: *mut core::ffi::c_void, ulinxmrlicenselen: u32, pbinxmrlicense: *const u8, ulentitlementtokenlen: u32, pbentitlementtoken: *const u8, puldescramblestatus: *mut u32, puloutxmrlicenselen: *mut u32, pboutxmrlicense: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_WMDRMSession", "RenewLicense", 9usize, |this| ((**(this as *const *const IBDA_WMDRMSession_Vtbl)).RenewLicense)(this, ulinxmrlicenselen, pbinxmrlicense, ulentitlementtokenlen, pbentitlementtoken, puldescramblestatus, puloutxmrlicenselen, pboutxmrlicense), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKeyInfo(this: *mut core::ffi::c_void, pulkeyinfolen: *mut u32, pbkeyinfo: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_WMDRMSession", "GetKeyInfo", 10usize, |this| ((**(this as *const *const IBDA_WMDRMSession_Vtbl)).GetKeyInfo)(this, pulkeyinfolen, pbkeyinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetStatus,
+            SetRevInfo,
+            SetCrl,
+            TransactMessage,
+            GetLicense,
+            ReissueLicense,
+            RenewLicense,
+            GetKeyInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_WMDRMSession as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBDA_WMDRMTuner_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PurchaseEntitlement(this: *mut core::ffi::c_void, uldialogrequest: u32, bstrlanguage: core::mem::MaybeUninit<windows_core::BSTR>, ulpurchasetokenlen: u32, pbpurchasetoken: *const u8, puldescramblestatus: *mut u32, pulcapturetokenlen: *mut u32, pbcapturetoken: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_WMDRMTuner", "PurchaseEntitlement", 3usize, |this| ((**(this as *const *const IBDA_WMDRMTuner_Vtbl)).PurchaseEntitlement)(this, uldialogrequest, bstrlanguage, ulpurchasetokenlen, pbpurchasetoken, puldescramblestatus, pulcapturetokenlen, pbcapturetoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelCaptureToken(this: *mut core::ffi::c_void, ulcapturetokenlen: u32, pbcapturetoken: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_WMDRMTuner", "CancelCaptureToken", 4usize, |this| ((**(this as *const *const IBDA_WMDRMTuner_Vtbl)).CancelCaptureToken)(this, ulcapturetokenlen, pbcapturetoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPidProtection(this: *mut core::ffi::c_void, ulpid: u32, uuidkey: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_WMDRMTuner", "SetPidProtection", 5usize, |this| ((**(this as *const *const IBDA_WMDRMTuner_Vtbl)).SetPidProtection)(this, ulpid, uuidkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPidProtection(this: *mut core::ffi::c_void, pulpid: u32, uuidkey: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_WMDRMTuner", "GetPidProtection", 6usize, |this| ((**(this as *const *const IBDA_WMDRMTuner_Vtbl)).GetPidProtection)(this, pulpid, uuidkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSyncValue(this: *mut core::ffi::c_void, ulsyncvalue: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_WMDRMTuner", "SetSyncValue", 7usize, |this| ((**(this as *const *const IBDA_WMDRMTuner_Vtbl)).SetSyncValue)(this, ulsyncvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStartCodeProfile(this: *mut core::ffi::c_void, pulstartcodeprofilelen: *mut u32, pbstartcodeprofile: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBDA_WMDRMTuner", "GetStartCodeProfile", 8usize, |this| ((**(this as *const *const IBDA_WMDRMTuner_Vtbl)).GetStartCodeProfile)(this, pulstartcodeprofilelen, pbstartcodeprofile), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PurchaseEntitlement,
+            CancelCaptureToken,
+            SetPidProtection,
+            GetPidProtection,
+            SetSyncValue,
+            GetStartCodeProfile,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBDA_WMDRMTuner as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBPCSatelliteTuner_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_DefaultSubChannelTypes(this: *mut core::ffi::c_void, pldefaultvideotype: *mut i32, pldefaultaudiotype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBPCSatelliteTuner", "get_DefaultSubChannelTypes", 18usize, |this| ((**(this as *const *const IBPCSatelliteTuner_Vtbl)).get_DefaultSubChannelTypes)(this, pldefaultvideotype, pldefaultaudiotype), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_DefaultSubChannelTypes(this: *mut core::ffi::c_void, ldefaultvideotype: i32, ldefaultaudiotype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBPCSatelliteTuner", "put_DefaultSubChannelTypes", 19usize, |this| ((**(this as *const *const IBPCSatelliteTuner_Vtbl)).put_DefaultSubChannelTypes)(this, ldefaultvideotype, ldefaultaudiotype), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTapingPermitted(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBPCSatelliteTuner", "IsTapingPermitted", 20usize, |this| ((**(this as *const *const IBPCSatelliteTuner_Vtbl)).IsTapingPermitted)(this), |result| Some(*result))
+        }
+        Self { base__: <IAMTuner_Vtbl as windows_core::imp::ProxyVtable>::PROXY, get_DefaultSubChannelTypes, put_DefaultSubChannelTypes, IsTapingPermitted }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBPCSatelliteTuner as windows_core::Interface>::IID || <IAMTuner_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IBaseFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumPins(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBaseFilter", "EnumPins", 10usize, |this| ((**(this as *const *const IBaseFilter_Vtbl)).EnumPins)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindPin(this: *mut core::ffi::c_void, id: windows_core::PCWSTR, pppin: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBaseFilter", "FindPin", 11usize, |this| ((**(this as *const *const IBaseFilter_Vtbl)).FindPin)(this, id, pppin), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryFilterInfo(this: *mut core::ffi::c_void, pinfo: *mut FILTER_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBaseFilter", "QueryFilterInfo", 12usize, |this| ((**(this as *const *const IBaseFilter_Vtbl)).QueryFilterInfo)(this, pinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn JoinFilterGraph(this: *mut core::ffi::c_void, pgraph: *mut core::ffi::c_void, pname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBaseFilter", "JoinFilterGraph", 13usize, |this| ((**(this as *const *const IBaseFilter_Vtbl)).JoinFilterGraph)(this, pgraph, pname), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryVendorInfo(this: *mut core::ffi::c_void, pvendorinfo: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBaseFilter", "QueryVendorInfo", 14usize, |this| ((**(this as *const *const IBaseFilter_Vtbl)).QueryVendorInfo)(this, pvendorinfo), |result| Some(*result))
+        }
+        Self { base__: <IMediaFilter_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnumPins, FindPin, QueryFilterInfo, JoinFilterGraph, QueryVendorInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBaseFilter as windows_core::Interface>::IID || <IMediaFilter_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBaseVideoMixer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetLeadPin(this: *mut core::ffi::c_void, ipin: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBaseVideoMixer", "SetLeadPin", 3usize, |this| ((**(this as *const *const IBaseVideoMixer_Vtbl)).SetLeadPin)(this, ipin), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLeadPin(this: *mut core::ffi::c_void, pipin: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBaseVideoMixer", "GetLeadPin", 4usize, |this| ((**(this as *const *const IBaseVideoMixer_Vtbl)).GetLeadPin)(this, pipin), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputPinCount(this: *mut core::ffi::c_void, pipincount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBaseVideoMixer", "GetInputPinCount", 5usize, |this| ((**(this as *const *const IBaseVideoMixer_Vtbl)).GetInputPinCount)(this, pipincount), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUsingClock(this: *mut core::ffi::c_void, pbvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBaseVideoMixer", "IsUsingClock", 6usize, |this| ((**(this as *const *const IBaseVideoMixer_Vtbl)).IsUsingClock)(this, pbvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUsingClock(this: *mut core::ffi::c_void, bvalue: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBaseVideoMixer", "SetUsingClock", 7usize, |this| ((**(this as *const *const IBaseVideoMixer_Vtbl)).SetUsingClock)(this, bvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClockPeriod(this: *mut core::ffi::c_void, pbvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBaseVideoMixer", "GetClockPeriod", 8usize, |this| ((**(this as *const *const IBaseVideoMixer_Vtbl)).GetClockPeriod)(this, pbvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClockPeriod(this: *mut core::ffi::c_void, bvalue: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBaseVideoMixer", "SetClockPeriod", 9usize, |this| ((**(this as *const *const IBaseVideoMixer_Vtbl)).SetClockPeriod)(this, bvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetLeadPin,
+            GetLeadPin,
+            GetInputPinCount,
+            IsUsingClock,
+            SetUsingClock,
+            GetClockPeriod,
+            SetClockPeriod,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBaseVideoMixer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IBasicAudio_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetVolume(this: *mut core::ffi::c_void, lvolume: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicAudio", "SetVolume", 7usize, |this| ((**(this as *const *const IBasicAudio_Vtbl)).SetVolume)(this, lvolume), |result| Some(*result))
+        }
+        unsafe extern "system" fn Volume(this: *mut core::ffi::c_void, plvolume: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicAudio", "Volume", 8usize, |this| ((**(this as *const *const IBasicAudio_Vtbl)).Volume)(this, plvolume), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBalance(this: *mut core::ffi::c_void, lbalance: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicAudio", "SetBalance", 9usize, |this| ((**(this as *const *const IBasicAudio_Vtbl)).SetBalance)(this, lbalance), |result| Some(*result))
+        }
+        unsafe extern "system" fn Balance(this: *mut core::ffi::c_void, plbalance: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicAudio", "Balance", 10usize, |this| ((**(this as *const *const IBasicAudio_Vtbl)).Balance)(this, plbalance), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetVolume, Volume, SetBalance, Balance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBasicAudio as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IBasicVideo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AvgTimePerFrame(this: *mut core::ffi::c_void, pavgtimeperframe: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "AvgTimePerFrame", 7usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).AvgTimePerFrame)(this, pavgtimeperframe), |result| Some(*result))
+        }
+        unsafe extern "system" fn BitRate(this: *mut core::ffi::c_void, pbitrate: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "BitRate", 8usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).BitRate)(this, pbitrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn BitErrorRate(this: *mut core::ffi::c_void, pbiterrorrate: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "BitErrorRate", 9usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).BitErrorRate)(this, pbiterrorrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoWidth(this: *mut core::ffi::c_void, pvideowidth: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "VideoWidth", 10usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).VideoWidth)(this, pvideowidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoHeight(this: *mut core::ffi::c_void, pvideoheight: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "VideoHeight", 11usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).VideoHeight)(this, pvideoheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSourceLeft(this: *mut core::ffi::c_void, sourceleft: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "SetSourceLeft", 12usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).SetSourceLeft)(this, sourceleft), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceLeft(this: *mut core::ffi::c_void, psourceleft: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "SourceLeft", 13usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).SourceLeft)(this, psourceleft), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSourceWidth(this: *mut core::ffi::c_void, sourcewidth: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "SetSourceWidth", 14usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).SetSourceWidth)(this, sourcewidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceWidth(this: *mut core::ffi::c_void, psourcewidth: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "SourceWidth", 15usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).SourceWidth)(this, psourcewidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSourceTop(this: *mut core::ffi::c_void, sourcetop: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "SetSourceTop", 16usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).SetSourceTop)(this, sourcetop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceTop(this: *mut core::ffi::c_void, psourcetop: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "SourceTop", 17usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).SourceTop)(this, psourcetop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSourceHeight(this: *mut core::ffi::c_void, sourceheight: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "SetSourceHeight", 18usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).SetSourceHeight)(this, sourceheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceHeight(this: *mut core::ffi::c_void, psourceheight: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "SourceHeight", 19usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).SourceHeight)(this, psourceheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDestinationLeft(this: *mut core::ffi::c_void, destinationleft: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "SetDestinationLeft", 20usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).SetDestinationLeft)(this, destinationleft), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestinationLeft(this: *mut core::ffi::c_void, pdestinationleft: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "DestinationLeft", 21usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).DestinationLeft)(this, pdestinationleft), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDestinationWidth(this: *mut core::ffi::c_void, destinationwidth: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "SetDestinationWidth", 22usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).SetDestinationWidth)(this, destinationwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestinationWidth(this: *mut core::ffi::c_void, pdestinationwidth: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "DestinationWidth", 23usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).DestinationWidth)(this, pdestinationwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDestinationTop(this: *mut core::ffi::c_void, destinationtop: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "SetDestinationTop", 24usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).SetDestinationTop)(this, destinationtop), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestinationTop(this: *mut core::ffi::c_void, pdestinationtop: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "DestinationTop", 25usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).DestinationTop)(this, pdestinationtop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDestinationHeight(this: *mut core::ffi::c_void, destinationheight: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "SetDestinationHeight", 26usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).SetDestinationHeight)(this, destinationheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestinationHeight(this: *mut core::ffi::c_void, pdestinationheight: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "DestinationHeight", 27usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).DestinationHeight)(this, pdestinationheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSourcePosition(this: *mut core::ffi::c_void, left: i32, top: i32, width: i32, height: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "SetSourcePosition", 28usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).SetSourcePosition)(this, left, top, width, height), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourcePosition(this: *mut core::ffi::c_void, pleft: *mut i32, ptop: *mut i32, pwidth: *mut i32, pheight: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "GetSourcePosition", 29usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).GetSourcePosition)(this, pleft, ptop, pwidth, pheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultSourcePosition(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "SetDefaultSourcePosition", 30usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).SetDefaultSourcePosition)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDestinationPosition(this: *mut core::ffi::c_void, left: i32, top: i32, width: i32, height: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "SetDestinationPosition", 31usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).SetDestinationPosition)(this, left, top, width, height), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDestinationPosition(this: *mut core::ffi::c_void, pleft: *mut i32, ptop: *mut i32, pwidth: *mut i32, pheight: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "GetDestinationPosition", 32usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).GetDestinationPosition)(this, pleft, ptop, pwidth, pheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultDestinationPosition(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "SetDefaultDestinationPosition", 33usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).SetDefaultDestinationPosition)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoSize(this: *mut core::ffi::c_void, pwidth: *mut i32, pheight: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "GetVideoSize", 34usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).GetVideoSize)(this, pwidth, pheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoPaletteEntries(this: *mut core::ffi::c_void, startindex: i32, entries: i32, pretrieved: *mut i32, ppalette: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "GetVideoPaletteEntries", 35usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).GetVideoPaletteEntries)(this, startindex, entries, pretrieved, ppalette), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentImage(this: *mut core::ffi::c_void, pbuffersize: *mut i32, pdibimage: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "GetCurrentImage", 36usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).GetCurrentImage)(this, pbuffersize, pdibimage), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUsingDefaultSource(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "IsUsingDefaultSource", 37usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).IsUsingDefaultSource)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUsingDefaultDestination(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo", "IsUsingDefaultDestination", 38usize, |this| ((**(this as *const *const IBasicVideo_Vtbl)).IsUsingDefaultDestination)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AvgTimePerFrame,
+            BitRate,
+            BitErrorRate,
+            VideoWidth,
+            VideoHeight,
+            SetSourceLeft,
+            SourceLeft,
+            SetSourceWidth,
+            SourceWidth,
+            SetSourceTop,
+            SourceTop,
+            SetSourceHeight,
+            SourceHeight,
+            SetDestinationLeft,
+            DestinationLeft,
+            SetDestinationWidth,
+            DestinationWidth,
+            SetDestinationTop,
+            DestinationTop,
+            SetDestinationHeight,
+            DestinationHeight,
+            SetSourcePosition,
+            GetSourcePosition,
+            SetDefaultSourcePosition,
+            SetDestinationPosition,
+            GetDestinationPosition,
+            SetDefaultDestinationPosition,
+            GetVideoSize,
+            GetVideoPaletteEntries,
+            GetCurrentImage,
+            IsUsingDefaultSource,
+            IsUsingDefaultDestination,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBasicVideo as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IBasicVideo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPreferredAspectRatio(this: *mut core::ffi::c_void, plaspectx: *mut i32, plaspecty: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBasicVideo2", "GetPreferredAspectRatio", 39usize, |this| ((**(this as *const *const IBasicVideo2_Vtbl)).GetPreferredAspectRatio)(this, plaspectx, plaspecty), |result| Some(*result))
+        }
+        Self { base__: <IBasicVideo_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPreferredAspectRatio }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBasicVideo2 as windows_core::Interface>::IID || <IBasicVideo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBroadcastEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Fire(this: *mut core::ffi::c_void, eventid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBroadcastEvent", "Fire", 3usize, |this| ((**(this as *const *const IBroadcastEvent_Vtbl)).Fire)(this, eventid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Fire }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBroadcastEvent as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBroadcastEventEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FireEx(this: *mut core::ffi::c_void, eventid: windows_core::GUID, param1: u32, param2: u32, param3: u32, param4: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBroadcastEventEx", "FireEx", 4usize, |this| ((**(this as *const *const IBroadcastEventEx_Vtbl)).FireEx)(this, eventid, param1, param2, param3, param4), |result| Some(*result))
+        }
+        Self { base__: <IBroadcastEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FireEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBroadcastEventEx as windows_core::Interface>::IID || <IBroadcastEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBufferingTime_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBufferingTime(this: *mut core::ffi::c_void, pdwmilliseconds: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBufferingTime", "GetBufferingTime", 3usize, |this| ((**(this as *const *const IBufferingTime_Vtbl)).GetBufferingTime)(this, pdwmilliseconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBufferingTime(this: *mut core::ffi::c_void, dwmilliseconds: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBufferingTime", "SetBufferingTime", 4usize, |this| ((**(this as *const *const IBufferingTime_Vtbl)).SetBufferingTime)(this, dwmilliseconds), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetBufferingTime, SetBufferingTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBufferingTime as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICCSubStreamFiltering_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SubstreamTypes(this: *mut core::ffi::c_void, ptypes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICCSubStreamFiltering", "SubstreamTypes", 3usize, |this| ((**(this as *const *const ICCSubStreamFiltering_Vtbl)).SubstreamTypes)(this, ptypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubstreamTypes(this: *mut core::ffi::c_void, types: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICCSubStreamFiltering", "SetSubstreamTypes", 4usize, |this| ((**(this as *const *const ICCSubStreamFiltering_Vtbl)).SetSubstreamTypes)(this, types), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SubstreamTypes, SetSubstreamTypes }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICCSubStreamFiltering as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICameraControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_Exposure(this: *mut core::ffi::c_void, pvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "get_Exposure", 3usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).get_Exposure)(this, pvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_Exposure(this: *mut core::ffi::c_void, value: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "put_Exposure", 4usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).put_Exposure)(this, value, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn getRange_Exposure(this: *mut core::ffi::c_void, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflag: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "getRange_Exposure", 5usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).getRange_Exposure)(this, pmin, pmax, psteppingdelta, pdefault, pcapsflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Focus(this: *mut core::ffi::c_void, pvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "get_Focus", 6usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).get_Focus)(this, pvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_Focus(this: *mut core::ffi::c_void, value: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "put_Focus", 7usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).put_Focus)(this, value, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn getRange_Focus(this: *mut core::ffi::c_void, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflag: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "getRange_Focus", 8usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).getRange_Focus)(this, pmin, pmax, psteppingdelta, pdefault, pcapsflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Iris(this: *mut core::ffi::c_void, pvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "get_Iris", 9usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).get_Iris)(this, pvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_Iris(this: *mut core::ffi::c_void, value: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "put_Iris", 10usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).put_Iris)(this, value, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn getRange_Iris(this: *mut core::ffi::c_void, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflag: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "getRange_Iris", 11usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).getRange_Iris)(this, pmin, pmax, psteppingdelta, pdefault, pcapsflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Zoom(this: *mut core::ffi::c_void, pvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "get_Zoom", 12usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).get_Zoom)(this, pvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_Zoom(this: *mut core::ffi::c_void, value: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "put_Zoom", 13usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).put_Zoom)(this, value, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn getRange_Zoom(this: *mut core::ffi::c_void, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflag: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "getRange_Zoom", 14usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).getRange_Zoom)(this, pmin, pmax, psteppingdelta, pdefault, pcapsflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_FocalLengths(this: *mut core::ffi::c_void, plocularfocallength: *mut i32, plobjectivefocallengthmin: *mut i32, plobjectivefocallengthmax: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "get_FocalLengths", 15usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).get_FocalLengths)(this, plocularfocallength, plobjectivefocallengthmin, plobjectivefocallengthmax), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Pan(this: *mut core::ffi::c_void, pvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "get_Pan", 16usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).get_Pan)(this, pvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_Pan(this: *mut core::ffi::c_void, value: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "put_Pan", 17usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).put_Pan)(this, value, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn getRange_Pan(this: *mut core::ffi::c_void, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflag: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "getRange_Pan", 18usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).getRange_Pan)(this, pmin, pmax, psteppingdelta, pdefault, pcapsflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Tilt(this: *mut core::ffi::c_void, pvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "get_Tilt", 19usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).get_Tilt)(this, pvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_Tilt(this: *mut core::ffi::c_void, value: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "put_Tilt", 20usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).put_Tilt)(this, value, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn getRange_Tilt(this: *mut core::ffi::c_void, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflag: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "getRange_Tilt", 21usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).getRange_Tilt)(this, pmin, pmax, psteppingdelta, pdefault, pcapsflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_PanTilt(this: *mut core::ffi::c_void, ppanvalue: *mut i32, ptiltvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "get_PanTilt", 22usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).get_PanTilt)(this, ppanvalue, ptiltvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_PanTilt(this: *mut core::ffi::c_void, panvalue: i32, tiltvalue: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "put_PanTilt", 23usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).put_PanTilt)(this, panvalue, tiltvalue, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Roll(this: *mut core::ffi::c_void, pvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "get_Roll", 24usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).get_Roll)(this, pvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_Roll(this: *mut core::ffi::c_void, value: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "put_Roll", 25usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).put_Roll)(this, value, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn getRange_Roll(this: *mut core::ffi::c_void, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflag: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "getRange_Roll", 26usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).getRange_Roll)(this, pmin, pmax, psteppingdelta, pdefault, pcapsflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_ExposureRelative(this: *mut core::ffi::c_void, pvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "get_ExposureRelative", 27usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).get_ExposureRelative)(this, pvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_ExposureRelative(this: *mut core::ffi::c_void, value: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "put_ExposureRelative", 28usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).put_ExposureRelative)(this, value, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn getRange_ExposureRelative(this: *mut core::ffi::c_void, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflag: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "getRange_ExposureRelative", 29usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).getRange_ExposureRelative)(this, pmin, pmax, psteppingdelta, pdefault, pcapsflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_FocusRelative(this: *mut core::ffi::c_void, pvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "get_FocusRelative", 30usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).get_FocusRelative)(this, pvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_FocusRelative(this: *mut core::ffi::c_void, value: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "put_FocusRelative", 31usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).put_FocusRelative)(this, value, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn getRange_FocusRelative(this: *mut core::ffi::c_void, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflag: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "getRange_FocusRelative", 32usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).getRange_FocusRelative)(this, pmin, pmax, psteppingdelta, pdefault, pcapsflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_IrisRelative(this: *mut core::ffi::c_void, pvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "get_IrisRelative", 33usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).get_IrisRelative)(this, pvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_IrisRelative(this: *mut core::ffi::c_void, value: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "put_IrisRelative", 34usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).put_IrisRelative)(this, value, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn getRange_IrisRelative(this: *mut core::ffi::c_void, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflag: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "getRange_IrisRelative", 35usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).getRange_IrisRelative)(this, pmin, pmax, psteppingdelta, pdefault, pcapsflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_ZoomRelative(this: *mut core::ffi::c_void, pvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "get_ZoomRelative", 36usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).get_ZoomRelative)(this, pvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_ZoomRelative(this: *mut core::ffi::c_void, value: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "put_ZoomRelative", 37usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).put_ZoomRelative)(this, value, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn getRange_ZoomRelative(this: *mut core::ffi::c_void, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflag: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "getRange_ZoomRelative", 38usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).getRange_ZoomRelative)(this, pmin, pmax, psteppingdelta, pdefault, pcapsflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_PanRelative(this: *mut core::ffi::c_void, pvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "get_PanRelative", 39usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).get_PanRelative)(this, pvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_PanRelative(this: *mut core::ffi::c_void, value: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "put_PanRelative", 40usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).put_PanRelative)(this, value, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_TiltRelative(this: *mut core::ffi::c_void, pvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "get_TiltRelative", 41usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).get_TiltRelative)(this, pvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_TiltRelative(this: *mut core::ffi::c_void, value: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "put_TiltRelative", 42usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).put_TiltRelative)(this, value, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn getRange_TiltRelative(this: *mut core::ffi::c_void, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflag: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "getRange_TiltRelative", 43usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).getRange_TiltRelative)(this, pmin, pmax, psteppingdelta, pdefault, pcapsflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_PanTiltRelative(this: *mut core::ffi::c_void, ppanvalue: *mut i32, ptiltvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "get_PanTiltRelative", 44usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).get_PanTiltRelative)(this, ppanvalue, ptiltvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_PanTiltRelative(this: *mut core::ffi::c_void, panvalue: i32, tiltvalue: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "put_PanTiltRelative", 45usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).put_PanTiltRelative)(this, panvalue, tiltvalue, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn getRange_PanRelative(this: *mut core::ffi::c_void, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflag: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "getRange_PanRelative", 46usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).getRange_PanRelative)(this, pmin, pmax, psteppingdelta, pdefault, pcapsflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_RollRelative(this: *mut core::ffi::c_void, pvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "get_RollRelative", 47usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).get_RollRelative)(this, pvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_RollRelative(this: *mut core::ffi::c_void, value: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "put_RollRelative", 48usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).put_RollRelative)(this, value, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn getRange_RollRelative(this: *mut core::ffi::c_void, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflag: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "getRange_RollRelative", 49usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).getRange_RollRelative)(this, pmin, pmax, psteppingdelta, pdefault, pcapsflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_ScanMode(this: *mut core::ffi::c_void, pvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "get_ScanMode", 50usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).get_ScanMode)(this, pvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_ScanMode(this: *mut core::ffi::c_void, value: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "put_ScanMode", 51usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).put_ScanMode)(this, value, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_PrivacyMode(this: *mut core::ffi::c_void, pvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "get_PrivacyMode", 52usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).get_PrivacyMode)(this, pvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_PrivacyMode(this: *mut core::ffi::c_void, value: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraControl", "put_PrivacyMode", 53usize, |this| ((**(this as *const *const ICameraControl_Vtbl)).put_PrivacyMode)(this, value, flags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            get_Exposure,
+            put_Exposure,
+            getRange_Exposure,
+            get_Focus,
+            put_Focus,
+            getRange_Focus,
+            get_Iris,
+            put_Iris,
+            getRange_Iris,
+            get_Zoom,
+            put_Zoom,
+            getRange_Zoom,
+            get_FocalLengths,
+            get_Pan,
+            put_Pan,
+            getRange_Pan,
+            get_Tilt,
+            put_Tilt,
+            getRange_Tilt,
+            get_PanTilt,
+            put_PanTilt,
+            get_Roll,
+            put_Roll,
+            getRange_Roll,
+            get_ExposureRelative,
+            put_ExposureRelative,
+            getRange_ExposureRelative,
+            get_FocusRelative,
+            put_FocusRelative,
+            getRange_FocusRelative,
+            get_IrisRelative,
+            put_IrisRelative,
+            getRange_IrisRelative,
+            get_ZoomRelative,
+            put_ZoomRelative,
+            getRange_ZoomRelative,
+            get_PanRelative,
+            put_PanRelative,
+            get_TiltRelative,
+            put_TiltRelative,
+            getRange_TiltRelative,
+            get_PanTiltRelative,
+            put_PanTiltRelative,
+            getRange_PanRelative,
+            get_RollRelative,
+            put_RollRelative,
+            getRange_RollRelative,
+            get_ScanMode,
+            put_ScanMode,
+            get_PrivacyMode,
+            put_PrivacyMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICameraControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICaptureGraphBuilder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetFiltergraph(this: *mut core::ffi::c_void, pfg: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICaptureGraphBuilder", "SetFiltergraph", 3usize, |this| ((**(this as *const *const ICaptureGraphBuilder_Vtbl)).SetFiltergraph)(this, pfg), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFiltergraph(this: *mut core::ffi::c_void, ppfg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICaptureGraphBuilder", "GetFiltergraph", 4usize, |this| ((**(this as *const *const ICaptureGraphBuilder_Vtbl)).GetFiltergraph)(this, ppfg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetOutputFileName(this: *mut core::ffi::c_void, ptype: *const windows_core::GUID, lpstrfile: windows_core::PCWSTR, ppf: *mut *mut core::ffi::c_void, ppsink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICaptureGraphBuilder", "SetOutputFileName", 5usize, |this| ((**(this as *const *const ICaptureGraphBuilder_Vtbl)).SetOutputFileName)(this, ptype, lpstrfile, ppf, ppsink), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn FindInterface(this: *mut core::ffi::c_void, pcategory: *const windows_core::GUID, pf: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppint: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICaptureGraphBuilder", "FindInterface", 6usize, |this| ((**(this as *const *const ICaptureGraphBuilder_Vtbl)).FindInterface)(this, pcategory, pf, riid, ppint), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RenderStream(this: *mut core::ffi::c_void, pcategory: *const windows_core::GUID, psource: *mut core::ffi::c_void, pfcompressor: *mut core::ffi::c_void, pfrenderer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICaptureGraphBuilder", "RenderStream", 7usize, |this| ((**(this as *const *const ICaptureGraphBuilder_Vtbl)).RenderStream)(this, pcategory, psource, pfcompressor, pfrenderer), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ControlStream(this: *mut core::ffi::c_void, pcategory: *const windows_core::GUID, pfilter: *mut core::ffi::c_void, pstart: *const i64, pstop: *const i64, wstartcookie: u16, wstopcookie: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICaptureGraphBuilder", "ControlStream", 8usize, |this| ((**(this as *const *const ICaptureGraphBuilder_Vtbl)).ControlStream)(this, pcategory, pfilter, pstart, pstop, wstartcookie, wstopcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllocCapFile(this: *mut core::ffi::c_void, lpstr: windows_core::PCWSTR, dwlsize: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICaptureGraphBuilder", "AllocCapFile", 9usize, |this| ((**(this as *const *const ICaptureGraphBuilder_Vtbl)).AllocCapFile)(this, lpstr, dwlsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyCaptureFile(this: *mut core::ffi::c_void, lpwstrold: windows_core::PCWSTR, lpwstrnew: windows_core::PCWSTR, fallowescabort: i32, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICaptureGraphBuilder", "CopyCaptureFile", 10usize, |this| ((**(this as *const *const ICaptureGraphBuilder_Vtbl)).CopyCaptureFile)(this, lpwstrold, lpwstrnew, fallowescabort, pcallback), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetFiltergraph,
+            GetFiltergraph,
+            #[cfg(feature = "Win32_System_Com")]
+            SetOutputFileName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetOutputFileName: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            FindInterface,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            FindInterface: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            RenderStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RenderStream: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ControlStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ControlStream: 0,
+            AllocCapFile,
+            CopyCaptureFile,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICaptureGraphBuilder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICaptureGraphBuilder2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetFiltergraph(this: *mut core::ffi::c_void, pfg: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICaptureGraphBuilder2", "SetFiltergraph", 3usize, |this| ((**(this as *const *const ICaptureGraphBuilder2_Vtbl)).SetFiltergraph)(this, pfg), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFiltergraph(this: *mut core::ffi::c_void, ppfg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICaptureGraphBuilder2", "GetFiltergraph", 4usize, |this| ((**(this as *const *const ICaptureGraphBuilder2_Vtbl)).GetFiltergraph)(this, ppfg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetOutputFileName(this: *mut core::ffi::c_void, ptype: *const windows_core::GUID, lpstrfile: windows_core::PCWSTR, ppf: *mut *mut core::ffi::c_void, ppsink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICaptureGraphBuilder2", "SetOutputFileName", 5usize, |this| ((**(this as *const *const ICaptureGraphBuilder2_Vtbl)).SetOutputFileName)(this, ptype, lpstrfile, ppf, ppsink), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn FindInterface(this: *mut core::ffi::c_void, pcategory: *const windows_core::GUID, ptype: *const windows_core::GUID, pf: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppint: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICaptureGraphBuilder2", "FindInterface", 6usize, |this| ((**(this as *const *const ICaptureGraphBuilder2_Vtbl)).FindInterface)(this, pcategory, ptype, pf, riid, ppint), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RenderStream(this: *mut core::ffi::c_void, pcategory: *const windows_core::GUID, ptype: *const windows_core::GUID, psource: *mut core::ffi::c_void, pfcompressor: *mut core::ffi::c_void, pfrenderer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICaptureGraphBuilder2", "RenderStream", 7usize, |this| ((**(this as *const *const ICaptureGraphBuilder2_Vtbl)).RenderStream)(this, pcategory, ptype, psource, pfcompressor, pfrenderer), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ControlStream(this: *mut core::ffi::c_void, pcategory: *const windows_core::GUID, ptype: *const windows_core::GUID, pfilter: *mut core::ffi::c_void, pstart: *const i64, pstop: *const i64, wstartcookie: u16, wstopcookie: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICaptureGraphBuilder2", "ControlStream", 8usize, |this| ((**(this as *const *const ICaptureGraphBuilder2_Vtbl)).ControlStream)(this, pcategory, ptype, pfilter, pstart, pstop, wstartcookie, wstopcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllocCapFile(this: *mut core::ffi::c_void, lpstr: windows_core::PCWSTR, dwlsize: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICaptureGraphBuilder2", "AllocCapFile", 9usize, |this| ((**(this as *const *const ICaptureGraphBuilder2_Vtbl)).AllocCapFile)(this, lpstr, dwlsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyCaptureFile(this: *mut core::ffi::c_void, lpwstrold: windows_core::PCWSTR, lpwstrnew: windows_core::PCWSTR, fallowescabort: i32, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICaptureGraphBuilder2", "CopyCaptureFile", 10usize, |this| ((**(this as *const *const ICaptureGraphBuilder2_Vtbl)).CopyCaptureFile)(this, lpwstrold, lpwstrnew, fallowescabort, pcallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindPin(this: *mut core::ffi::c_void, psource: *mut core::ffi::c_void, pindir: PIN_DIRECTION, pcategory: *const windows_core::GUID, ptype: *const windows_core::GUID, funconnected: super::super::Foundation::BOOL, num: i32, pppin: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICaptureGraphBuilder2", "FindPin", 11usize, |this| ((**(this as *const *const ICaptureGraphBuilder2_Vtbl)).FindPin)(this, psource, pindir, pcategory, ptype, funconnected, num, pppin), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetFiltergraph,
+            GetFiltergraph,
+            #[cfg(feature = "Win32_System_Com")]
+            SetOutputFileName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetOutputFileName: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            FindInterface,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            FindInterface: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            RenderStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RenderStream: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ControlStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ControlStream: 0,
+            AllocCapFile,
+            CopyCaptureFile,
+            FindPin,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICaptureGraphBuilder2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_WindowsMediaFormat")]
+impl windows_core::imp::ProxyVtable for IConfigAsfWriter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConfigureFilterUsingProfileId(this: *mut core::ffi::c_void, dwprofileid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigAsfWriter", "ConfigureFilterUsingProfileId", 3usize, |this| ((**(this as *const *const IConfigAsfWriter_Vtbl)).ConfigureFilterUsingProfileId)(this, dwprofileid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentProfileId(this: *mut core::ffi::c_void, pdwprofileid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigAsfWriter", "GetCurrentProfileId", 4usize, |this| ((**(this as *const *const IConfigAsfWriter_Vtbl)).GetCurrentProfileId)(this, pdwprofileid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConfigureFilterUsingProfileGuid(this: *mut core::ffi::c_void, guidprofile: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigAsfWriter", "ConfigureFilterUsingProfileGuid", 5usize, |this| ((**(this as *const *const IConfigAsfWriter_Vtbl)).ConfigureFilterUsingProfileGuid)(this, guidprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentProfileGuid(this: *mut core::ffi::c_void, pprofileguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigAsfWriter", "GetCurrentProfileGuid", 6usize, |this| ((**(this as *const *const IConfigAsfWriter_Vtbl)).GetCurrentProfileGuid)(this, pprofileguid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_WindowsMediaFormat")]
+        unsafe extern "system" fn ConfigureFilterUsingProfile(this: *mut core::ffi::c_void, pprofile: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigAsfWriter", "ConfigureFilterUsingProfile", 7usize, |this| ((**(this as *const *const IConfigAsfWriter_Vtbl)).ConfigureFilterUsingProfile)(this, pprofile), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_WindowsMediaFormat")]
+        unsafe extern "system" fn GetCurrentProfile(this: *mut core::ffi::c_void, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigAsfWriter", "GetCurrentProfile", 8usize, |this| ((**(this as *const *const IConfigAsfWriter_Vtbl)).GetCurrentProfile)(this, ppprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIndexMode(this: *mut core::ffi::c_void, bindexfile: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigAsfWriter", "SetIndexMode", 9usize, |this| ((**(this as *const *const IConfigAsfWriter_Vtbl)).SetIndexMode)(this, bindexfile), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIndexMode(this: *mut core::ffi::c_void, pbindexfile: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigAsfWriter", "GetIndexMode", 10usize, |this| ((**(this as *const *const IConfigAsfWriter_Vtbl)).GetIndexMode)(this, pbindexfile), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ConfigureFilterUsingProfileId,
+            GetCurrentProfileId,
+            ConfigureFilterUsingProfileGuid,
+            GetCurrentProfileGuid,
+            #[cfg(feature = "Win32_Media_WindowsMediaFormat")]
+            ConfigureFilterUsingProfile,
+            #[cfg(not(feature = "Win32_Media_WindowsMediaFormat"))]
+            ConfigureFilterUsingProfile: 0,
+            #[cfg(feature = "Win32_Media_WindowsMediaFormat")]
+            GetCurrentProfile,
+            #[cfg(not(feature = "Win32_Media_WindowsMediaFormat"))]
+            GetCurrentProfile: 0,
+            SetIndexMode,
+            GetIndexMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConfigAsfWriter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_WindowsMediaFormat")]
+impl windows_core::imp::ProxyVtable for IConfigAsfWriter2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StreamNumFromPin(this: *mut core::ffi::c_void, ppin: *mut core::ffi::c_void, pwstreamnum: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigAsfWriter2", "StreamNumFromPin", 11usize, |this| ((**(this as *const *const IConfigAsfWriter2_Vtbl)).StreamNumFromPin)(this, ppin, pwstreamnum), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParam(this: *mut core::ffi::c_void, dwparam: u32, dwparam1: u32, dwparam2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigAsfWriter2", "SetParam", 12usize, |this| ((**(this as *const *const IConfigAsfWriter2_Vtbl)).SetParam)(this, dwparam, dwparam1, dwparam2), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParam(this: *mut core::ffi::c_void, dwparam: u32, pdwparam1: *mut u32, pdwparam2: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigAsfWriter2", "GetParam", 13usize, |this| ((**(this as *const *const IConfigAsfWriter2_Vtbl)).GetParam)(this, dwparam, pdwparam1, pdwparam2), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetMultiPassState(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigAsfWriter2", "ResetMultiPassState", 14usize, |this| ((**(this as *const *const IConfigAsfWriter2_Vtbl)).ResetMultiPassState)(this), |result| Some(*result))
+        }
+        Self { base__: <IConfigAsfWriter_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StreamNumFromPin, SetParam, GetParam, ResetMultiPassState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConfigAsfWriter2 as windows_core::Interface>::IID || <IConfigAsfWriter_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConfigAviMux_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMasterStream(this: *mut core::ffi::c_void, istream: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigAviMux", "SetMasterStream", 3usize, |this| ((**(this as *const *const IConfigAviMux_Vtbl)).SetMasterStream)(this, istream), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMasterStream(this: *mut core::ffi::c_void, pstream: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigAviMux", "GetMasterStream", 4usize, |this| ((**(this as *const *const IConfigAviMux_Vtbl)).GetMasterStream)(this, pstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputCompatibilityIndex(this: *mut core::ffi::c_void, foldindex: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigAviMux", "SetOutputCompatibilityIndex", 5usize, |this| ((**(this as *const *const IConfigAviMux_Vtbl)).SetOutputCompatibilityIndex)(this, foldindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputCompatibilityIndex(this: *mut core::ffi::c_void, pfoldindex: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigAviMux", "GetOutputCompatibilityIndex", 6usize, |this| ((**(this as *const *const IConfigAviMux_Vtbl)).GetOutputCompatibilityIndex)(this, pfoldindex), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetMasterStream,
+            GetMasterStream,
+            SetOutputCompatibilityIndex,
+            GetOutputCompatibilityIndex,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConfigAviMux as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConfigInterleaving_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMode(this: *mut core::ffi::c_void, mode: InterleavingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigInterleaving", "SetMode", 3usize, |this| ((**(this as *const *const IConfigInterleaving_Vtbl)).SetMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, pmode: *mut InterleavingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigInterleaving", "Mode", 4usize, |this| ((**(this as *const *const IConfigInterleaving_Vtbl)).Mode)(this, pmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_Interleaving(this: *mut core::ffi::c_void, prtinterleave: *const i64, prtpreroll: *const i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigInterleaving", "put_Interleaving", 5usize, |this| ((**(this as *const *const IConfigInterleaving_Vtbl)).put_Interleaving)(this, prtinterleave, prtpreroll), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Interleaving(this: *mut core::ffi::c_void, prtinterleave: *mut i64, prtpreroll: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigInterleaving", "get_Interleaving", 6usize, |this| ((**(this as *const *const IConfigInterleaving_Vtbl)).get_Interleaving)(this, prtinterleave, prtpreroll), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetMode, Mode, put_Interleaving, get_Interleaving }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConfigInterleaving as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICreateDevEnum_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateClassEnumerator(this: *mut core::ffi::c_void, clsiddeviceclass: *const windows_core::GUID, ppenummoniker: *mut *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateDevEnum", "CreateClassEnumerator", 3usize, |this| ((**(this as *const *const ICreateDevEnum_Vtbl)).CreateClassEnumerator)(this, clsiddeviceclass, ppenummoniker, dwflags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateClassEnumerator,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateClassEnumerator: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICreateDevEnum as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_DirectDraw")]
+impl windows_core::imp::ProxyVtable for IDDrawExclModeVideo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn SetDDrawObject(this: *mut core::ffi::c_void, pddrawobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDDrawExclModeVideo", "SetDDrawObject", 3usize, |this| ((**(this as *const *const IDDrawExclModeVideo_Vtbl)).SetDDrawObject)(this, pddrawobject), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn GetDDrawObject(this: *mut core::ffi::c_void, ppddrawobject: *mut *mut core::ffi::c_void, pbusingexternal: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDDrawExclModeVideo", "GetDDrawObject", 4usize, |this| ((**(this as *const *const IDDrawExclModeVideo_Vtbl)).GetDDrawObject)(this, ppddrawobject, pbusingexternal), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn SetDDrawSurface(this: *mut core::ffi::c_void, pddrawsurface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDDrawExclModeVideo", "SetDDrawSurface", 5usize, |this| ((**(this as *const *const IDDrawExclModeVideo_Vtbl)).SetDDrawSurface)(this, pddrawsurface), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn GetDDrawSurface(this: *mut core::ffi::c_void, ppddrawsurface: *mut *mut core::ffi::c_void, pbusingexternal: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDDrawExclModeVideo", "GetDDrawSurface", 6usize, |this| ((**(this as *const *const IDDrawExclModeVideo_Vtbl)).GetDDrawSurface)(this, ppddrawsurface, pbusingexternal), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDrawParameters(this: *mut core::ffi::c_void, prcsource: *const super::super::Foundation::RECT, prctarget: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDDrawExclModeVideo", "SetDrawParameters", 7usize, |this| ((**(this as *const *const IDDrawExclModeVideo_Vtbl)).SetDrawParameters)(this, prcsource, prctarget), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNativeVideoProps(this: *mut core::ffi::c_void, pdwvideowidth: *mut u32, pdwvideoheight: *mut u32, pdwpictaspectratiox: *mut u32, pdwpictaspectratioy: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDDrawExclModeVideo", "GetNativeVideoProps", 8usize, |this| ((**(this as *const *const IDDrawExclModeVideo_Vtbl)).GetNativeVideoProps)(this, pdwvideowidth, pdwvideoheight, pdwpictaspectratiox, pdwpictaspectratioy), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCallbackInterface(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDDrawExclModeVideo", "SetCallbackInterface", 9usize, |this| ((**(this as *const *const IDDrawExclModeVideo_Vtbl)).SetCallbackInterface)(this, pcallback, dwflags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            SetDDrawObject,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            SetDDrawObject: 0,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            GetDDrawObject,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            GetDDrawObject: 0,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            SetDDrawSurface,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            SetDDrawSurface: 0,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            GetDDrawSurface,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            GetDDrawSurface: 0,
+            SetDrawParameters,
+            GetNativeVideoProps,
+            SetCallbackInterface,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDDrawExclModeVideo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDDrawExclModeVideoCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnUpdateOverlay(this: *mut core::ffi::c_void, bbefore: super::super::Foundation::BOOL, dwflags: u32, boldvisible: super::super::Foundation::BOOL, prcoldsrc: *const super::super::Foundation::RECT, prcolddest: *const super::super::Foundation::RECT, bnewvisible: super::super::Foundation::BOOL, prcnewsrc: *const super::super::Foundation::RECT, prcnewdest: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDDrawExclModeVideoCallback", "OnUpdateOverlay", 3usize, |this| ((**(this as *const *const IDDrawExclModeVideoCallback_Vtbl)).OnUpdateOverlay)(this, bbefore, dwflags, boldvisible, prcoldsrc, prcolddest, bnewvisible, prcnewsrc, prcnewdest), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnUpdateColorKey(this: *mut core::ffi::c_void, pkey: *const COLORKEY, dwcolor: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDDrawExclModeVideoCallback", "OnUpdateColorKey", 4usize, |this| ((**(this as *const *const IDDrawExclModeVideoCallback_Vtbl)).OnUpdateColorKey)(this, pkey, dwcolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnUpdateSize(this: *mut core::ffi::c_void, dwwidth: u32, dwheight: u32, dwarwidth: u32, dwarheight: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDDrawExclModeVideoCallback", "OnUpdateSize", 5usize, |this| ((**(this as *const *const IDDrawExclModeVideoCallback_Vtbl)).OnUpdateSize)(this, dwwidth, dwheight, dwarwidth, dwarheight), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnUpdateOverlay, OnUpdateColorKey, OnUpdateSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDDrawExclModeVideoCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDMOWrapperFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Init(this: *mut core::ffi::c_void, clsiddmo: *const windows_core::GUID, catdmo: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDMOWrapperFilter", "Init", 3usize, |this| ((**(this as *const *const IDMOWrapperFilter_Vtbl)).Init)(this, clsiddmo, catdmo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Init }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDMOWrapperFilter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDShowPlugin_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn URL(this: *mut core::ffi::c_void, purl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDShowPlugin", "URL", 3usize, |this| ((**(this as *const *const IDShowPlugin_Vtbl)).URL)(this, purl), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserAgent(this: *mut core::ffi::c_void, puseragent: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDShowPlugin", "UserAgent", 4usize, |this| ((**(this as *const *const IDShowPlugin_Vtbl)).UserAgent)(this, puseragent), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, URL, UserAgent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDShowPlugin as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDVEnc_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_IFormatResolution(this: *mut core::ffi::c_void, videoformat: *mut i32, dvformat: *mut i32, resolution: *mut i32, fdvinfo: u8, sdvinfo: *mut DVINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVEnc", "get_IFormatResolution", 3usize, |this| ((**(this as *const *const IDVEnc_Vtbl)).get_IFormatResolution)(this, videoformat, dvformat, resolution, fdvinfo, sdvinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_IFormatResolution(this: *mut core::ffi::c_void, videoformat: i32, dvformat: i32, resolution: i32, fdvinfo: u8, sdvinfo: *const DVINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVEnc", "put_IFormatResolution", 4usize, |this| ((**(this as *const *const IDVEnc_Vtbl)).put_IFormatResolution)(this, videoformat, dvformat, resolution, fdvinfo, sdvinfo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, get_IFormatResolution, put_IFormatResolution }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDVEnc as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDVRGB219_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetRGB219(this: *mut core::ffi::c_void, bstate: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVRGB219", "SetRGB219", 3usize, |this| ((**(this as *const *const IDVRGB219_Vtbl)).SetRGB219)(this, bstate), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetRGB219 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDVRGB219 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDVSplitter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DiscardAlternateVideoFrames(this: *mut core::ffi::c_void, ndiscard: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDVSplitter", "DiscardAlternateVideoFrames", 3usize, |this| ((**(this as *const *const IDVSplitter_Vtbl)).DiscardAlternateVideoFrames)(this, ndiscard), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DiscardAlternateVideoFrames }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDVSplitter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDecimateVideoImage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDecimationImageSize(this: *mut core::ffi::c_void, lwidth: i32, lheight: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDecimateVideoImage", "SetDecimationImageSize", 3usize, |this| ((**(this as *const *const IDecimateVideoImage_Vtbl)).SetDecimationImageSize)(this, lwidth, lheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetDecimationImageSize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDecimateVideoImage", "ResetDecimationImageSize", 4usize, |this| ((**(this as *const *const IDecimateVideoImage_Vtbl)).ResetDecimationImageSize)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetDecimationImageSize, ResetDecimationImageSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDecimateVideoImage as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeferredCommand_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeferredCommand", "Cancel", 3usize, |this| ((**(this as *const *const IDeferredCommand_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Confidence(this: *mut core::ffi::c_void, pconfidence: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeferredCommand", "Confidence", 4usize, |this| ((**(this as *const *const IDeferredCommand_Vtbl)).Confidence)(this, pconfidence), |result| Some(*result))
+        }
+        unsafe extern "system" fn Postpone(this: *mut core::ffi::c_void, newtime: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeferredCommand", "Postpone", 5usize, |this| ((**(this as *const *const IDeferredCommand_Vtbl)).Postpone)(this, newtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHResult(this: *mut core::ffi::c_void, phrresult: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeferredCommand", "GetHResult", 6usize, |this| ((**(this as *const *const IDeferredCommand_Vtbl)).GetHResult)(this, phrresult), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Cancel, Confidence, Postpone, GetHResult }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeferredCommand as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_DirectDraw")]
+impl windows_core::imp::ProxyVtable for IDirectDrawMediaSample_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn GetSurfaceAndReleaseLock(this: *mut core::ffi::c_void, ppdirectdrawsurface: *mut *mut core::ffi::c_void, prect: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawMediaSample", "GetSurfaceAndReleaseLock", 3usize, |this| ((**(this as *const *const IDirectDrawMediaSample_Vtbl)).GetSurfaceAndReleaseLock)(this, ppdirectdrawsurface, prect), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockMediaSamplePointer(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawMediaSample", "LockMediaSamplePointer", 4usize, |this| ((**(this as *const *const IDirectDrawMediaSample_Vtbl)).LockMediaSamplePointer)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            GetSurfaceAndReleaseLock,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            GetSurfaceAndReleaseLock: 0,
+            LockMediaSamplePointer,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectDrawMediaSample as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_DirectDraw")]
+impl windows_core::imp::ProxyVtable for IDirectDrawMediaSampleAllocator_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn GetDirectDraw(this: *mut core::ffi::c_void, ppdirectdraw: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawMediaSampleAllocator", "GetDirectDraw", 3usize, |this| ((**(this as *const *const IDirectDrawMediaSampleAllocator_Vtbl)).GetDirectDraw)(this, ppdirectdraw), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            GetDirectDraw,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            GetDirectDraw: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectDrawMediaSampleAllocator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_DirectDraw")]
+impl windows_core::imp::ProxyVtable for IDirectDrawMediaStream_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn GetFormat(this: *mut core::ffi::c_void, pddsdcurrent: *mut super::super::Graphics::DirectDraw::DDSURFACEDESC, ppdirectdrawpalette: *mut *mut core::ffi::c_void, pddsddesired: *mut super::super::Graphics::DirectDraw::DDSURFACEDESC, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawMediaStream", "GetFormat", 9usize, |this| ((**(this as *const *const IDirectDrawMediaStream_Vtbl)).GetFormat)(this, pddsdcurrent, ppdirectdrawpalette, pddsddesired, pdwflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn SetFormat(this: *mut core::ffi::c_void, pddsurfacedesc: *const super::super::Graphics::DirectDraw::DDSURFACEDESC, pdirectdrawpalette: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawMediaStream", "SetFormat", 10usize, |this| ((**(this as *const *const IDirectDrawMediaStream_Vtbl)).SetFormat)(this, pddsurfacedesc, pdirectdrawpalette), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn GetDirectDraw(this: *mut core::ffi::c_void, ppdirectdraw: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawMediaStream", "GetDirectDraw", 11usize, |this| ((**(this as *const *const IDirectDrawMediaStream_Vtbl)).GetDirectDraw)(this, ppdirectdraw), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn SetDirectDraw(this: *mut core::ffi::c_void, pdirectdraw: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawMediaStream", "SetDirectDraw", 12usize, |this| ((**(this as *const *const IDirectDrawMediaStream_Vtbl)).SetDirectDraw)(this, pdirectdraw), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn CreateSample(this: *mut core::ffi::c_void, psurface: *mut core::ffi::c_void, prect: *const super::super::Foundation::RECT, dwflags: u32, ppsample: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawMediaStream", "CreateSample", 13usize, |this| ((**(this as *const *const IDirectDrawMediaStream_Vtbl)).CreateSample)(this, psurface, prect, dwflags, ppsample), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTimePerFrame(this: *mut core::ffi::c_void, pframetime: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawMediaStream", "GetTimePerFrame", 14usize, |this| ((**(this as *const *const IDirectDrawMediaStream_Vtbl)).GetTimePerFrame)(this, pframetime), |result| Some(*result))
+        }
+        Self {
+            base__: <IMediaStream_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            GetFormat,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            GetFormat: 0,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            SetFormat,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            SetFormat: 0,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            GetDirectDraw,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            GetDirectDraw: 0,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            SetDirectDraw,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            SetDirectDraw: 0,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            CreateSample,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            CreateSample: 0,
+            GetTimePerFrame,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectDrawMediaStream as windows_core::Interface>::IID || <IMediaStream_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_DirectDraw")]
+impl windows_core::imp::ProxyVtable for IDirectDrawStreamSample_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn GetSurface(this: *mut core::ffi::c_void, ppdirectdrawsurface: *mut *mut core::ffi::c_void, prect: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawStreamSample", "GetSurface", 8usize, |this| ((**(this as *const *const IDirectDrawStreamSample_Vtbl)).GetSurface)(this, ppdirectdrawsurface, prect), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRect(this: *mut core::ffi::c_void, prect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawStreamSample", "SetRect", 9usize, |this| ((**(this as *const *const IDirectDrawStreamSample_Vtbl)).SetRect)(this, prect), |result| Some(*result))
+        }
+        Self {
+            base__: <IStreamSample_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            GetSurface,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            GetSurface: 0,
+            SetRect,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectDrawStreamSample as windows_core::Interface>::IID || <IStreamSample_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_DirectDraw")]
+impl windows_core::imp::ProxyVtable for IDirectDrawVideo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSwitches(this: *mut core::ffi::c_void, pswitches: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideo", "GetSwitches", 3usize, |this| ((**(this as *const *const IDirectDrawVideo_Vtbl)).GetSwitches)(this, pswitches), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSwitches(this: *mut core::ffi::c_void, switches: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideo", "SetSwitches", 4usize, |this| ((**(this as *const *const IDirectDrawVideo_Vtbl)).SetSwitches)(this, switches), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn GetCaps(this: *mut core::ffi::c_void, pcaps: *mut super::super::Graphics::DirectDraw::DDCAPS_DX7) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideo", "GetCaps", 5usize, |this| ((**(this as *const *const IDirectDrawVideo_Vtbl)).GetCaps)(this, pcaps), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn GetEmulatedCaps(this: *mut core::ffi::c_void, pcaps: *mut super::super::Graphics::DirectDraw::DDCAPS_DX7) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideo", "GetEmulatedCaps", 6usize, |this| ((**(this as *const *const IDirectDrawVideo_Vtbl)).GetEmulatedCaps)(this, pcaps), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn GetSurfaceDesc(this: *mut core::ffi::c_void, psurfacedesc: *mut super::super::Graphics::DirectDraw::DDSURFACEDESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideo", "GetSurfaceDesc", 7usize, |this| ((**(this as *const *const IDirectDrawVideo_Vtbl)).GetSurfaceDesc)(this, psurfacedesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFourCCCodes(this: *mut core::ffi::c_void, pcount: *mut u32, pcodes: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideo", "GetFourCCCodes", 8usize, |this| ((**(this as *const *const IDirectDrawVideo_Vtbl)).GetFourCCCodes)(this, pcount, pcodes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn SetDirectDraw(this: *mut core::ffi::c_void, pdirectdraw: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideo", "SetDirectDraw", 9usize, |this| ((**(this as *const *const IDirectDrawVideo_Vtbl)).SetDirectDraw)(this, pdirectdraw), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn GetDirectDraw(this: *mut core::ffi::c_void, ppdirectdraw: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideo", "GetDirectDraw", 10usize, |this| ((**(this as *const *const IDirectDrawVideo_Vtbl)).GetDirectDraw)(this, ppdirectdraw), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSurfaceType(this: *mut core::ffi::c_void, psurfacetype: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideo", "GetSurfaceType", 11usize, |this| ((**(this as *const *const IDirectDrawVideo_Vtbl)).GetSurfaceType)(this, psurfacetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefault(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideo", "SetDefault", 12usize, |this| ((**(this as *const *const IDirectDrawVideo_Vtbl)).SetDefault)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseScanLine(this: *mut core::ffi::c_void, usescanline: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideo", "UseScanLine", 13usize, |this| ((**(this as *const *const IDirectDrawVideo_Vtbl)).UseScanLine)(this, usescanline), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanUseScanLine(this: *mut core::ffi::c_void, usescanline: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideo", "CanUseScanLine", 14usize, |this| ((**(this as *const *const IDirectDrawVideo_Vtbl)).CanUseScanLine)(this, usescanline), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseOverlayStretch(this: *mut core::ffi::c_void, useoverlaystretch: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideo", "UseOverlayStretch", 15usize, |this| ((**(this as *const *const IDirectDrawVideo_Vtbl)).UseOverlayStretch)(this, useoverlaystretch), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanUseOverlayStretch(this: *mut core::ffi::c_void, useoverlaystretch: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideo", "CanUseOverlayStretch", 16usize, |this| ((**(this as *const *const IDirectDrawVideo_Vtbl)).CanUseOverlayStretch)(this, useoverlaystretch), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseWhenFullScreen(this: *mut core::ffi::c_void, usewhenfullscreen: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideo", "UseWhenFullScreen", 17usize, |this| ((**(this as *const *const IDirectDrawVideo_Vtbl)).UseWhenFullScreen)(this, usewhenfullscreen), |result| Some(*result))
+        }
+        unsafe extern "system" fn WillUseFullScreen(this: *mut core::ffi::c_void, usewhenfullscreen: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectDrawVideo", "WillUseFullScreen", 18usize, |this| ((**(this as *const *const IDirectDrawVideo_Vtbl)).WillUseFullScreen)(this, usewhenfullscreen), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSwitches,
+            SetSwitches,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            GetCaps,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            GetCaps: 0,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            GetEmulatedCaps,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            GetEmulatedCaps: 0,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            GetSurfaceDesc,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            GetSurfaceDesc: 0,
+            GetFourCCCodes,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            SetDirectDraw,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            SetDirectDraw: 0,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            GetDirectDraw,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            GetDirectDraw: 0,
+            GetSurfaceType,
+            SetDefault,
+            UseScanLine,
+            CanUseScanLine,
+            UseOverlayStretch,
+            CanUseOverlayStretch,
+            UseWhenFullScreen,
+            WillUseFullScreen,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectDrawVideo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDistributorNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDistributorNotify", "Stop", 3usize, |this| ((**(this as *const *const IDistributorNotify_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDistributorNotify", "Pause", 4usize, |this| ((**(this as *const *const IDistributorNotify_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Run(this: *mut core::ffi::c_void, tstart: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDistributorNotify", "Run", 5usize, |this| ((**(this as *const *const IDistributorNotify_Vtbl)).Run)(this, tstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSyncSource(this: *mut core::ffi::c_void, pclock: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDistributorNotify", "SetSyncSource", 6usize, |this| ((**(this as *const *const IDistributorNotify_Vtbl)).SetSyncSource)(this, pclock), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyGraphChange(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDistributorNotify", "NotifyGraphChange", 7usize, |this| ((**(this as *const *const IDistributorNotify_Vtbl)).NotifyGraphChange)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Stop, Pause, Run, SetSyncSource, NotifyGraphChange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDistributorNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IDrawVideoImage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DrawVideoImageBegin(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDrawVideoImage", "DrawVideoImageBegin", 3usize, |this| ((**(this as *const *const IDrawVideoImage_Vtbl)).DrawVideoImageBegin)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DrawVideoImageEnd(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDrawVideoImage", "DrawVideoImageEnd", 4usize, |this| ((**(this as *const *const IDrawVideoImage_Vtbl)).DrawVideoImageEnd)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn DrawVideoImageDraw(this: *mut core::ffi::c_void, hdc: super::super::Graphics::Gdi::HDC, lprcsrc: *const super::super::Foundation::RECT, lprcdst: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDrawVideoImage", "DrawVideoImageDraw", 5usize, |this| ((**(this as *const *const IDrawVideoImage_Vtbl)).DrawVideoImageDraw)(this, hdc, lprcsrc, lprcdst), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DrawVideoImageBegin,
+            DrawVideoImageEnd,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            DrawVideoImageDraw,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            DrawVideoImageDraw: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDrawVideoImage as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvdCmd_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WaitForStart(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdCmd", "WaitForStart", 3usize, |this| ((**(this as *const *const IDvdCmd_Vtbl)).WaitForStart)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForEnd(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdCmd", "WaitForEnd", 4usize, |this| ((**(this as *const *const IDvdCmd_Vtbl)).WaitForEnd)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WaitForStart, WaitForEnd }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvdCmd as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvdControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TitlePlay(this: *mut core::ffi::c_void, ultitle: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "TitlePlay", 3usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).TitlePlay)(this, ultitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChapterPlay(this: *mut core::ffi::c_void, ultitle: u32, ulchapter: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "ChapterPlay", 4usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).ChapterPlay)(this, ultitle, ulchapter), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimePlay(this: *mut core::ffi::c_void, ultitle: u32, bcdtime: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "TimePlay", 5usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).TimePlay)(this, ultitle, bcdtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopForResume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "StopForResume", 6usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).StopForResume)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GoUp(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "GoUp", 7usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).GoUp)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimeSearch(this: *mut core::ffi::c_void, bcdtime: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "TimeSearch", 8usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).TimeSearch)(this, bcdtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChapterSearch(this: *mut core::ffi::c_void, ulchapter: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "ChapterSearch", 9usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).ChapterSearch)(this, ulchapter), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrevPGSearch(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "PrevPGSearch", 10usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).PrevPGSearch)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn TopPGSearch(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "TopPGSearch", 11usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).TopPGSearch)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn NextPGSearch(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "NextPGSearch", 12usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).NextPGSearch)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForwardScan(this: *mut core::ffi::c_void, dwspeed: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "ForwardScan", 13usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).ForwardScan)(this, dwspeed), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackwardScan(this: *mut core::ffi::c_void, dwspeed: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "BackwardScan", 14usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).BackwardScan)(this, dwspeed), |result| Some(*result))
+        }
+        unsafe extern "system" fn MenuCall(this: *mut core::ffi::c_void, menuid: DVD_MENU_ID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "MenuCall", 15usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).MenuCall)(this, menuid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "Resume", 16usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpperButtonSelect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "UpperButtonSelect", 17usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).UpperButtonSelect)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn LowerButtonSelect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "LowerButtonSelect", 18usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).LowerButtonSelect)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn LeftButtonSelect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "LeftButtonSelect", 19usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).LeftButtonSelect)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RightButtonSelect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "RightButtonSelect", 20usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).RightButtonSelect)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ButtonActivate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "ButtonActivate", 21usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).ButtonActivate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ButtonSelectAndActivate(this: *mut core::ffi::c_void, ulbutton: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "ButtonSelectAndActivate", 22usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).ButtonSelectAndActivate)(this, ulbutton), |result| Some(*result))
+        }
+        unsafe extern "system" fn StillOff(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "StillOff", 23usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).StillOff)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PauseOn(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "PauseOn", 24usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).PauseOn)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PauseOff(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "PauseOff", 25usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).PauseOff)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn MenuLanguageSelect(this: *mut core::ffi::c_void, language: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "MenuLanguageSelect", 26usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).MenuLanguageSelect)(this, language), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioStreamChange(this: *mut core::ffi::c_void, ulaudio: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "AudioStreamChange", 27usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).AudioStreamChange)(this, ulaudio), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubpictureStreamChange(this: *mut core::ffi::c_void, ulsubpicture: u32, bdisplay: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "SubpictureStreamChange", 28usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).SubpictureStreamChange)(this, ulsubpicture, bdisplay), |result| Some(*result))
+        }
+        unsafe extern "system" fn AngleChange(this: *mut core::ffi::c_void, ulangle: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "AngleChange", 29usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).AngleChange)(this, ulangle), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParentalLevelSelect(this: *mut core::ffi::c_void, ulparentallevel: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "ParentalLevelSelect", 30usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).ParentalLevelSelect)(this, ulparentallevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParentalCountrySelect(this: *mut core::ffi::c_void, wcountry: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "ParentalCountrySelect", 31usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).ParentalCountrySelect)(this, wcountry), |result| Some(*result))
+        }
+        unsafe extern "system" fn KaraokeAudioPresentationModeChange(this: *mut core::ffi::c_void, ulmode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "KaraokeAudioPresentationModeChange", 32usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).KaraokeAudioPresentationModeChange)(this, ulmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn VideoModePreferrence(this: *mut core::ffi::c_void, ulpreferreddisplaymode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "VideoModePreferrence", 33usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).VideoModePreferrence)(this, ulpreferreddisplaymode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRoot(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "SetRoot", 34usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).SetRoot)(this, pszpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn MouseActivate(this: *mut core::ffi::c_void, point: super::super::Foundation::POINT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "MouseActivate", 35usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).MouseActivate)(this, point), |result| Some(*result))
+        }
+        unsafe extern "system" fn MouseSelect(this: *mut core::ffi::c_void, point: super::super::Foundation::POINT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "MouseSelect", 36usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).MouseSelect)(this, point), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChapterPlayAutoStop(this: *mut core::ffi::c_void, ultitle: u32, ulchapter: u32, ulchapterstoplay: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl", "ChapterPlayAutoStop", 37usize, |this| ((**(this as *const *const IDvdControl_Vtbl)).ChapterPlayAutoStop)(this, ultitle, ulchapter, ulchapterstoplay), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TitlePlay,
+            ChapterPlay,
+            TimePlay,
+            StopForResume,
+            GoUp,
+            TimeSearch,
+            ChapterSearch,
+            PrevPGSearch,
+            TopPGSearch,
+            NextPGSearch,
+            ForwardScan,
+            BackwardScan,
+            MenuCall,
+            Resume,
+            UpperButtonSelect,
+            LowerButtonSelect,
+            LeftButtonSelect,
+            RightButtonSelect,
+            ButtonActivate,
+            ButtonSelectAndActivate,
+            StillOff,
+            PauseOn,
+            PauseOff,
+            MenuLanguageSelect,
+            AudioStreamChange,
+            SubpictureStreamChange,
+            AngleChange,
+            ParentalLevelSelect,
+            ParentalCountrySelect,
+            KaraokeAudioPresentationModeChange,
+            VideoModePreferrence,
+            SetRoot,
+            MouseActivate,
+            MouseSelect,
+            ChapterPlayAutoStop,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvdControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvdControl2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PlayTitle(this: *mut core::ffi::c_void, ultitle: u32, dwflags: u32, ppcmd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "PlayTitle", 3usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).PlayTitle)(this, ultitle, dwflags, ppcmd), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayChapterInTitle(this: *mut core::ffi::c_void, ultitle: u32, ulchapter: u32, dwflags: u32, ppcmd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "PlayChapterInTitle", 4usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).PlayChapterInTitle)(this, ultitle, ulchapter, dwflags, ppcmd), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayAtTimeInTitle(this: *mut core::ffi::c_void, ultitle: u32, pstarttime: *const DVD_HMSF_TIMECODE, dwflags: u32, ppcmd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "PlayAtTimeInTitle", 5usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).PlayAtTimeInTitle)(this, ultitle, pstarttime, dwflags, ppcmd), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "Stop", 6usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReturnFromSubmenu(this: *mut core::ffi::c_void, dwflags: u32, ppcmd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "ReturnFromSubmenu", 7usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).ReturnFromSubmenu)(this, dwflags, ppcmd), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayAtTime(this: *mut core::ffi::c_void, ptime: *const DVD_HMSF_TIMECODE, dwflags: u32, ppcmd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "PlayAtTime", 8usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).PlayAtTime)(this, ptime, dwflags, ppcmd), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayChapter(this: *mut core::ffi::c_void, ulchapter: u32, dwflags: u32, ppcmd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "PlayChapter", 9usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).PlayChapter)(this, ulchapter, dwflags, ppcmd), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayPrevChapter(this: *mut core::ffi::c_void, dwflags: u32, ppcmd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "PlayPrevChapter", 10usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).PlayPrevChapter)(this, dwflags, ppcmd), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReplayChapter(this: *mut core::ffi::c_void, dwflags: u32, ppcmd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "ReplayChapter", 11usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).ReplayChapter)(this, dwflags, ppcmd), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayNextChapter(this: *mut core::ffi::c_void, dwflags: u32, ppcmd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "PlayNextChapter", 12usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).PlayNextChapter)(this, dwflags, ppcmd), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayForwards(this: *mut core::ffi::c_void, dspeed: f64, dwflags: u32, ppcmd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "PlayForwards", 13usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).PlayForwards)(this, dspeed, dwflags, ppcmd), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayBackwards(this: *mut core::ffi::c_void, dspeed: f64, dwflags: u32, ppcmd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "PlayBackwards", 14usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).PlayBackwards)(this, dspeed, dwflags, ppcmd), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowMenu(this: *mut core::ffi::c_void, menuid: DVD_MENU_ID, dwflags: u32, ppcmd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "ShowMenu", 15usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).ShowMenu)(this, menuid, dwflags, ppcmd), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void, dwflags: u32, ppcmd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "Resume", 16usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).Resume)(this, dwflags, ppcmd), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectRelativeButton(this: *mut core::ffi::c_void, buttondir: DVD_RELATIVE_BUTTON) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "SelectRelativeButton", 17usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).SelectRelativeButton)(this, buttondir), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivateButton(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "ActivateButton", 18usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).ActivateButton)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectButton(this: *mut core::ffi::c_void, ulbutton: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "SelectButton", 19usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).SelectButton)(this, ulbutton), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectAndActivateButton(this: *mut core::ffi::c_void, ulbutton: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "SelectAndActivateButton", 20usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).SelectAndActivateButton)(this, ulbutton), |result| Some(*result))
+        }
+        unsafe extern "system" fn StillOff(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "StillOff", 21usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).StillOff)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void, bstate: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "Pause", 22usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).Pause)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectAudioStream(this: *mut core::ffi::c_void, ulaudio: u32, dwflags: u32, ppcmd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "SelectAudioStream", 23usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).SelectAudioStream)(this, ulaudio, dwflags, ppcmd), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectSubpictureStream(this: *mut core::ffi::c_void, ulsubpicture: u32, dwflags: u32, ppcmd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "SelectSubpictureStream", 24usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).SelectSubpictureStream)(this, ulsubpicture, dwflags, ppcmd), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubpictureState(this: *mut core::ffi::c_void, bstate: super::super::Foundation::BOOL, dwflags: u32, ppcmd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "SetSubpictureState", 25usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).SetSubpictureState)(this, bstate, dwflags, ppcmd), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectAngle(this: *mut core::ffi::c_void, ulangle: u32, dwflags: u32, ppcmd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "SelectAngle", 26usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).SelectAngle)(this, ulangle, dwflags, ppcmd), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectParentalLevel(this: *mut core::ffi::c_void, ulparentallevel: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "SelectParentalLevel", 27usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).SelectParentalLevel)(this, ulparentallevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectParentalCountry(this: *mut core::ffi::c_void, bcountry: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "SelectParentalCountry", 28usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).SelectParentalCountry)(this, bcountry), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectKaraokeAudioPresentationMode(this: *mut core::ffi::c_void, ulmode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "SelectKaraokeAudioPresentationMode", 29usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).SelectKaraokeAudioPresentationMode)(this, ulmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectVideoModePreference(this: *mut core::ffi::c_void, ulpreferreddisplaymode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "SelectVideoModePreference", 30usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).SelectVideoModePreference)(this, ulpreferreddisplaymode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDVDDirectory(this: *mut core::ffi::c_void, pszwpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "SetDVDDirectory", 31usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).SetDVDDirectory)(this, pszwpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivateAtPosition(this: *mut core::ffi::c_void, point: super::super::Foundation::POINT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "ActivateAtPosition", 32usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).ActivateAtPosition)(this, point), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectAtPosition(this: *mut core::ffi::c_void, point: super::super::Foundation::POINT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "SelectAtPosition", 33usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).SelectAtPosition)(this, point), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayChaptersAutoStop(this: *mut core::ffi::c_void, ultitle: u32, ulchapter: u32, ulchapterstoplay: u32, dwflags: u32, ppcmd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "PlayChaptersAutoStop", 34usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).PlayChaptersAutoStop)(this, ultitle, ulchapter, ulchapterstoplay, dwflags, ppcmd), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcceptParentalLevelChange(this: *mut core::ffi::c_void, baccept: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "AcceptParentalLevelChange", 35usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).AcceptParentalLevelChange)(this, baccept), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOption(this: *mut core::ffi::c_void, flag: DVD_OPTION_FLAG, fstate: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "SetOption", 36usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).SetOption)(this, flag, fstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetState(this: *mut core::ffi::c_void, pstate: *mut core::ffi::c_void, dwflags: u32, ppcmd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "SetState", 37usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).SetState)(this, pstate, dwflags, ppcmd), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayPeriodInTitleAutoStop(this: *mut core::ffi::c_void, ultitle: u32, pstarttime: *const DVD_HMSF_TIMECODE, pendtime: *const DVD_HMSF_TIMECODE, dwflags: u32, ppcmd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "PlayPeriodInTitleAutoStop", 38usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).PlayPeriodInTitleAutoStop)(this, ultitle, pstarttime, pendtime, dwflags, ppcmd), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGPRM(this: *mut core::ffi::c_void, ulindex: u32, wvalue: u16, dwflags: u32, ppcmd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "SetGPRM", 39usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).SetGPRM)(this, ulindex, wvalue, dwflags, ppcmd), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectDefaultMenuLanguage(this: *mut core::ffi::c_void, language: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "SelectDefaultMenuLanguage", 40usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).SelectDefaultMenuLanguage)(this, language), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectDefaultAudioLanguage(this: *mut core::ffi::c_void, language: u32, audioextension: DVD_AUDIO_LANG_EXT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "SelectDefaultAudioLanguage", 41usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).SelectDefaultAudioLanguage)(this, language, audioextension), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectDefaultSubpictureLanguage(this: *mut core::ffi::c_void, language: u32, subpictureextension: DVD_SUBPICTURE_LANG_EXT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdControl2", "SelectDefaultSubpictureLanguage", 42usize, |this| ((**(this as *const *const IDvdControl2_Vtbl)).SelectDefaultSubpictureLanguage)(this, language, subpictureextension), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PlayTitle,
+            PlayChapterInTitle,
+            PlayAtTimeInTitle,
+            Stop,
+            ReturnFromSubmenu,
+            PlayAtTime,
+            PlayChapter,
+            PlayPrevChapter,
+            ReplayChapter,
+            PlayNextChapter,
+            PlayForwards,
+            PlayBackwards,
+            ShowMenu,
+            Resume,
+            SelectRelativeButton,
+            ActivateButton,
+            SelectButton,
+            SelectAndActivateButton,
+            StillOff,
+            Pause,
+            SelectAudioStream,
+            SelectSubpictureStream,
+            SetSubpictureState,
+            SelectAngle,
+            SelectParentalLevel,
+            SelectParentalCountry,
+            SelectKaraokeAudioPresentationMode,
+            SelectVideoModePreference,
+            SetDVDDirectory,
+            ActivateAtPosition,
+            SelectAtPosition,
+            PlayChaptersAutoStop,
+            AcceptParentalLevelChange,
+            SetOption,
+            SetState,
+            PlayPeriodInTitleAutoStop,
+            SetGPRM,
+            SelectDefaultMenuLanguage,
+            SelectDefaultAudioLanguage,
+            SelectDefaultSubpictureLanguage,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvdControl2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvdGraphBuilder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFiltergraph(this: *mut core::ffi::c_void, ppgb: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdGraphBuilder", "GetFiltergraph", 3usize, |this| ((**(this as *const *const IDvdGraphBuilder_Vtbl)).GetFiltergraph)(this, ppgb), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDvdInterface(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvif: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdGraphBuilder", "GetDvdInterface", 4usize, |this| ((**(this as *const *const IDvdGraphBuilder_Vtbl)).GetDvdInterface)(this, riid, ppvif), |result| Some(*result))
+        }
+        unsafe extern "system" fn RenderDvdVideoVolume(this: *mut core::ffi::c_void, lpcwszpathname: windows_core::PCWSTR, dwflags: u32, pstatus: *mut AM_DVD_RENDERSTATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdGraphBuilder", "RenderDvdVideoVolume", 5usize, |this| ((**(this as *const *const IDvdGraphBuilder_Vtbl)).RenderDvdVideoVolume)(this, lpcwszpathname, dwflags, pstatus), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFiltergraph, GetDvdInterface, RenderDvdVideoVolume }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvdGraphBuilder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvdInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentDomain(this: *mut core::ffi::c_void, pdomain: *mut DVD_DOMAIN) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo", "GetCurrentDomain", 3usize, |this| ((**(this as *const *const IDvdInfo_Vtbl)).GetCurrentDomain)(this, pdomain), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentLocation(this: *mut core::ffi::c_void, plocation: *mut DVD_PLAYBACK_LOCATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo", "GetCurrentLocation", 4usize, |this| ((**(this as *const *const IDvdInfo_Vtbl)).GetCurrentLocation)(this, plocation), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTotalTitleTime(this: *mut core::ffi::c_void, pultotaltime: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo", "GetTotalTitleTime", 5usize, |this| ((**(this as *const *const IDvdInfo_Vtbl)).GetTotalTitleTime)(this, pultotaltime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentButton(this: *mut core::ffi::c_void, pulbuttonsavailable: *mut u32, pulcurrentbutton: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo", "GetCurrentButton", 6usize, |this| ((**(this as *const *const IDvdInfo_Vtbl)).GetCurrentButton)(this, pulbuttonsavailable, pulcurrentbutton), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentAngle(this: *mut core::ffi::c_void, pulanglesavailable: *mut u32, pulcurrentangle: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo", "GetCurrentAngle", 7usize, |this| ((**(this as *const *const IDvdInfo_Vtbl)).GetCurrentAngle)(this, pulanglesavailable, pulcurrentangle), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentAudio(this: *mut core::ffi::c_void, pulstreamsavailable: *mut u32, pulcurrentstream: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo", "GetCurrentAudio", 8usize, |this| ((**(this as *const *const IDvdInfo_Vtbl)).GetCurrentAudio)(this, pulstreamsavailable, pulcurrentstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentSubpicture(this: *mut core::ffi::c_void, pulstreamsavailable: *mut u32, pulcurrentstream: *mut u32, pisdisabled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo", "GetCurrentSubpicture", 9usize, |this| ((**(this as *const *const IDvdInfo_Vtbl)).GetCurrentSubpicture)(this, pulstreamsavailable, pulcurrentstream, pisdisabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentUOPS(this: *mut core::ffi::c_void, puop: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo", "GetCurrentUOPS", 10usize, |this| ((**(this as *const *const IDvdInfo_Vtbl)).GetCurrentUOPS)(this, puop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllSPRMs(this: *mut core::ffi::c_void, pregisterarray: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo", "GetAllSPRMs", 11usize, |this| ((**(this as *const *const IDvdInfo_Vtbl)).GetAllSPRMs)(this, pregisterarray), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllGPRMs(this: *mut core::ffi::c_void, pregisterarray: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo", "GetAllGPRMs", 12usize, |this| ((**(this as *const *const IDvdInfo_Vtbl)).GetAllGPRMs)(this, pregisterarray), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAudioLanguage(this: *mut core::ffi::c_void, ulstream: u32, planguage: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo", "GetAudioLanguage", 13usize, |this| ((**(this as *const *const IDvdInfo_Vtbl)).GetAudioLanguage)(this, ulstream, planguage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubpictureLanguage(this: *mut core::ffi::c_void, ulstream: u32, planguage: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo", "GetSubpictureLanguage", 14usize, |this| ((**(this as *const *const IDvdInfo_Vtbl)).GetSubpictureLanguage)(this, ulstream, planguage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTitleAttributes(this: *mut core::ffi::c_void, ultitle: u32, patr: *mut DVD_ATR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo", "GetTitleAttributes", 15usize, |this| ((**(this as *const *const IDvdInfo_Vtbl)).GetTitleAttributes)(this, ultitle, patr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVMGAttributes(this: *mut core::ffi::c_void, patr: *mut DVD_ATR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo", "GetVMGAttributes", 16usize, |this| ((**(this as *const *const IDvdInfo_Vtbl)).GetVMGAttributes)(this, patr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentVideoAttributes(this: *mut core::ffi::c_void, patr: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo", "GetCurrentVideoAttributes", 17usize, |this| ((**(this as *const *const IDvdInfo_Vtbl)).GetCurrentVideoAttributes)(this, patr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentAudioAttributes(this: *mut core::ffi::c_void, patr: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo", "GetCurrentAudioAttributes", 18usize, |this| ((**(this as *const *const IDvdInfo_Vtbl)).GetCurrentAudioAttributes)(this, patr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentSubpictureAttributes(this: *mut core::ffi::c_void, patr: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo", "GetCurrentSubpictureAttributes", 19usize, |this| ((**(this as *const *const IDvdInfo_Vtbl)).GetCurrentSubpictureAttributes)(this, patr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentVolumeInfo(this: *mut core::ffi::c_void, pulnumofvol: *mut u32, pulthisvolnum: *mut u32, pside: *mut DVD_DISC_SIDE, pulnumoftitles: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo", "GetCurrentVolumeInfo", 20usize, |this| ((**(this as *const *const IDvdInfo_Vtbl)).GetCurrentVolumeInfo)(this, pulnumofvol, pulthisvolnum, pside, pulnumoftitles), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDVDTextInfo(this: *mut core::ffi::c_void, ptextmanager: *mut u8, ulbufsize: u32, pulactualsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo", "GetDVDTextInfo", 21usize, |this| ((**(this as *const *const IDvdInfo_Vtbl)).GetDVDTextInfo)(this, ptextmanager, ulbufsize, pulactualsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPlayerParentalLevel(this: *mut core::ffi::c_void, pulparentallevel: *mut u32, pulcountrycode: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo", "GetPlayerParentalLevel", 22usize, |this| ((**(this as *const *const IDvdInfo_Vtbl)).GetPlayerParentalLevel)(this, pulparentallevel, pulcountrycode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumberOfChapters(this: *mut core::ffi::c_void, ultitle: u32, pulnumberofchapters: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo", "GetNumberOfChapters", 23usize, |this| ((**(this as *const *const IDvdInfo_Vtbl)).GetNumberOfChapters)(this, ultitle, pulnumberofchapters), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTitleParentalLevels(this: *mut core::ffi::c_void, ultitle: u32, pulparentallevels: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo", "GetTitleParentalLevels", 24usize, |this| ((**(this as *const *const IDvdInfo_Vtbl)).GetTitleParentalLevels)(this, ultitle, pulparentallevels), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRoot(this: *mut core::ffi::c_void, proot: windows_core::PSTR, ulbufsize: u32, pulactualsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo", "GetRoot", 25usize, |this| ((**(this as *const *const IDvdInfo_Vtbl)).GetRoot)(this, proot, ulbufsize, pulactualsize), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCurrentDomain,
+            GetCurrentLocation,
+            GetTotalTitleTime,
+            GetCurrentButton,
+            GetCurrentAngle,
+            GetCurrentAudio,
+            GetCurrentSubpicture,
+            GetCurrentUOPS,
+            GetAllSPRMs,
+            GetAllGPRMs,
+            GetAudioLanguage,
+            GetSubpictureLanguage,
+            GetTitleAttributes,
+            GetVMGAttributes,
+            GetCurrentVideoAttributes,
+            GetCurrentAudioAttributes,
+            GetCurrentSubpictureAttributes,
+            GetCurrentVolumeInfo,
+            GetDVDTextInfo,
+            GetPlayerParentalLevel,
+            GetNumberOfChapters,
+            GetTitleParentalLevels,
+            GetRoot,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvdInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvdInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentDomain(this: *mut core::ffi::c_void, pdomain: *mut DVD_DOMAIN) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetCurrentDomain", 3usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetCurrentDomain)(this, pdomain), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentLocation(this: *mut core::ffi::c_void, plocation: *mut DVD_PLAYBACK_LOCATION2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetCurrentLocation", 4usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetCurrentLocation)(this, plocation), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTotalTitleTime(this: *mut core::ffi::c_void, ptotaltime: *mut DVD_HMSF_TIMECODE, ultimecodeflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetTotalTitleTime", 5usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetTotalTitleTime)(this, ptotaltime, ultimecodeflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentButton(this: *mut core::ffi::c_void, pulbuttonsavailable: *mut u32, pulcurrentbutton: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetCurrentButton", 6usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetCurrentButton)(this, pulbuttonsavailable, pulcurrentbutton), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentAngle(this: *mut core::ffi::c_void, pulanglesavailable: *mut u32, pulcurrentangle: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetCurrentAngle", 7usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetCurrentAngle)(this, pulanglesavailable, pulcurrentangle), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentAudio(this: *mut core::ffi::c_void, pulstreamsavailable: *mut u32, pulcurrentstream: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetCurrentAudio", 8usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetCurrentAudio)(this, pulstreamsavailable, pulcurrentstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentSubpicture(this: *mut core::ffi::c_void, pulstreamsavailable: *mut u32, pulcurrentstream: *mut u32, pbisdisabled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetCurrentSubpicture", 9usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetCurrentSubpicture)(this, pulstreamsavailable, pulcurrentstream, pbisdisabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentUOPS(this: *mut core::ffi::c_void, puluops: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetCurrentUOPS", 10usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetCurrentUOPS)(this, puluops), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllSPRMs(this: *mut core::ffi::c_void, pregisterarray: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetAllSPRMs", 11usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetAllSPRMs)(this, pregisterarray), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllGPRMs(this: *mut core::ffi::c_void, pregisterarray: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetAllGPRMs", 12usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetAllGPRMs)(this, pregisterarray), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAudioLanguage(this: *mut core::ffi::c_void, ulstream: u32, planguage: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetAudioLanguage", 13usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetAudioLanguage)(this, ulstream, planguage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubpictureLanguage(this: *mut core::ffi::c_void, ulstream: u32, planguage: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetSubpictureLanguage", 14usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetSubpictureLanguage)(this, ulstream, planguage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTitleAttributes(this: *mut core::ffi::c_void, ultitle: u32, pmenu: *mut DVD_MenuAttributes, ptitle: *mut DVD_TitleAttributes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetTitleAttributes", 15usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetTitleAttributes)(this, ultitle, pmenu, ptitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVMGAttributes(this: *mut core::ffi::c_void, patr: *mut DVD_MenuAttributes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetVMGAttributes", 16usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetVMGAttributes)(this, patr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentVideoAttributes(this: *mut core::ffi::c_void, patr: *mut DVD_VideoAttributes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetCurrentVideoAttributes", 17usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetCurrentVideoAttributes)(this, patr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAudioAttributes(this: *mut core::ffi::c_void, ulstream: u32, patr: *mut DVD_AudioAttributes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetAudioAttributes", 18usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetAudioAttributes)(this, ulstream, patr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKaraokeAttributes(this: *mut core::ffi::c_void, ulstream: u32, pattributes: *mut DVD_KaraokeAttributes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetKaraokeAttributes", 19usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetKaraokeAttributes)(this, ulstream, pattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubpictureAttributes(this: *mut core::ffi::c_void, ulstream: u32, patr: *mut DVD_SubpictureAttributes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetSubpictureAttributes", 20usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetSubpictureAttributes)(this, ulstream, patr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDVDVolumeInfo(this: *mut core::ffi::c_void, pulnumofvolumes: *mut u32, pulvolume: *mut u32, pside: *mut DVD_DISC_SIDE, pulnumoftitles: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetDVDVolumeInfo", 21usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetDVDVolumeInfo)(this, pulnumofvolumes, pulvolume, pside, pulnumoftitles), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDVDTextNumberOfLanguages(this: *mut core::ffi::c_void, pulnumoflangs: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetDVDTextNumberOfLanguages", 22usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetDVDTextNumberOfLanguages)(this, pulnumoflangs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDVDTextLanguageInfo(this: *mut core::ffi::c_void, ullangindex: u32, pulnumofstrings: *mut u32, plangcode: *mut u32, pbcharacterset: *mut DVD_TextCharSet) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetDVDTextLanguageInfo", 23usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetDVDTextLanguageInfo)(this, ullangindex, pulnumofstrings, plangcode, pbcharacterset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDVDTextStringAsNative(this: *mut core::ffi::c_void, ullangindex: u32, ulstringindex: u32, pbbuffer: *mut u8, ulmaxbuffersize: u32, pulactualsize: *mut u32, ptype: *mut DVD_TextStringType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetDVDTextStringAsNative", 24usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetDVDTextStringAsNative)(this, ullangindex, ulstringindex, pbbuffer, ulmaxbuffersize, pulactualsize, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDVDTextStringAsUnicode(this: *mut core::ffi::c_void, ullangindex: u32, ulstringindex: u32, pchwbuffer: windows_core::PWSTR, ulmaxbuffersize: u32, pulactualsize: *mut u32, ptype: *mut DVD_TextStringType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetDVDTextStringAsUnicode", 25usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetDVDTextStringAsUnicode)(this, ullangindex, ulstringindex, pchwbuffer, ulmaxbuffersize, pulactualsize, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPlayerParentalLevel(this: *mut core::ffi::c_void, pulparentallevel: *mut u32, pbcountrycode: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetPlayerParentalLevel", 26usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetPlayerParentalLevel)(this, pulparentallevel, pbcountrycode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumberOfChapters(this: *mut core::ffi::c_void, ultitle: u32, pulnumofchapters: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetNumberOfChapters", 27usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetNumberOfChapters)(this, ultitle, pulnumofchapters), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTitleParentalLevels(this: *mut core::ffi::c_void, ultitle: u32, pulparentallevels: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetTitleParentalLevels", 28usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetTitleParentalLevels)(this, ultitle, pulparentallevels), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDVDDirectory(this: *mut core::ffi::c_void, pszwpath: windows_core::PWSTR, ulmaxsize: u32, pulactualsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetDVDDirectory", 29usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetDVDDirectory)(this, pszwpath, ulmaxsize, pulactualsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAudioStreamEnabled(this: *mut core::ffi::c_void, ulstreamnum: u32, pbenabled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "IsAudioStreamEnabled", 30usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).IsAudioStreamEnabled)(this, ulstreamnum, pbenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDiscID(this: *mut core::ffi::c_void, pszwpath: windows_core::PCWSTR, pulldiscid: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetDiscID", 31usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetDiscID)(this, pszwpath, pulldiscid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetState(this: *mut core::ffi::c_void, pstatedata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetState", 32usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetState)(this, pstatedata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMenuLanguages(this: *mut core::ffi::c_void, planguages: *mut u32, ulmaxlanguages: u32, pulactuallanguages: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetMenuLanguages", 33usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetMenuLanguages)(this, planguages, ulmaxlanguages, pulactuallanguages), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetButtonAtPosition(this: *mut core::ffi::c_void, point: super::super::Foundation::POINT, pulbuttonindex: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetButtonAtPosition", 34usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetButtonAtPosition)(this, point, pulbuttonindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCmdFromEvent(this: *mut core::ffi::c_void, lparam1: isize, pcmdobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetCmdFromEvent", 35usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetCmdFromEvent)(this, lparam1, pcmdobj), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultMenuLanguage(this: *mut core::ffi::c_void, planguage: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetDefaultMenuLanguage", 36usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetDefaultMenuLanguage)(this, planguage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultAudioLanguage(this: *mut core::ffi::c_void, planguage: *mut u32, paudioextension: *mut DVD_AUDIO_LANG_EXT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetDefaultAudioLanguage", 37usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetDefaultAudioLanguage)(this, planguage, paudioextension), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultSubpictureLanguage(this: *mut core::ffi::c_void, planguage: *mut u32, psubpictureextension: *mut DVD_SUBPICTURE_LANG_EXT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetDefaultSubpictureLanguage", 38usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetDefaultSubpictureLanguage)(this, planguage, psubpictureextension), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDecoderCaps(this: *mut core::ffi::c_void, pcaps: *mut DVD_DECODER_CAPS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetDecoderCaps", 39usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetDecoderCaps)(this, pcaps), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetButtonRect(this: *mut core::ffi::c_void, ulbutton: u32, prect: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "GetButtonRect", 40usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).GetButtonRect)(this, ulbutton, prect), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSubpictureStreamEnabled(this: *mut core::ffi::c_void, ulstreamnum: u32, pbenabled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdInfo2", "IsSubpictureStreamEnabled", 41usize, |this| ((**(this as *const *const IDvdInfo2_Vtbl)).IsSubpictureStreamEnabled)(this, ulstreamnum, pbenabled), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCurrentDomain,
+            GetCurrentLocation,
+            GetTotalTitleTime,
+            GetCurrentButton,
+            GetCurrentAngle,
+            GetCurrentAudio,
+            GetCurrentSubpicture,
+            GetCurrentUOPS,
+            GetAllSPRMs,
+            GetAllGPRMs,
+            GetAudioLanguage,
+            GetSubpictureLanguage,
+            GetTitleAttributes,
+            GetVMGAttributes,
+            GetCurrentVideoAttributes,
+            GetAudioAttributes,
+            GetKaraokeAttributes,
+            GetSubpictureAttributes,
+            GetDVDVolumeInfo,
+            GetDVDTextNumberOfLanguages,
+            GetDVDTextLanguageInfo,
+            GetDVDTextStringAsNative,
+            GetDVDTextStringAsUnicode,
+            GetPlayerParentalLevel,
+            GetNumberOfChapters,
+            GetTitleParentalLevels,
+            GetDVDDirectory,
+            IsAudioStreamEnabled,
+            GetDiscID,
+            GetState,
+            GetMenuLanguages,
+            GetButtonAtPosition,
+            GetCmdFromEvent,
+            GetDefaultMenuLanguage,
+            GetDefaultAudioLanguage,
+            GetDefaultSubpictureLanguage,
+            GetDecoderCaps,
+            GetButtonRect,
+            IsSubpictureStreamEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvdInfo2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDvdState_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDiscID(this: *mut core::ffi::c_void, pulluniqueid: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdState", "GetDiscID", 3usize, |this| ((**(this as *const *const IDvdState_Vtbl)).GetDiscID)(this, pulluniqueid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParentalLevel(this: *mut core::ffi::c_void, pulparentallevel: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDvdState", "GetParentalLevel", 4usize, |this| ((**(this as *const *const IDvdState_Vtbl)).GetParentalLevel)(this, pulparentallevel), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDiscID, GetParentalLevel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDvdState as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IESEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEventId(this: *mut core::ffi::c_void, pdweventid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESEvent", "GetEventId", 3usize, |this| ((**(this as *const *const IESEvent_Vtbl)).GetEventId)(this, pdweventid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEventType(this: *mut core::ffi::c_void, pguideventtype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESEvent", "GetEventType", 4usize, |this| ((**(this as *const *const IESEvent_Vtbl)).GetEventType)(this, pguideventtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompletionStatus(this: *mut core::ffi::c_void, dwresult: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESEvent", "SetCompletionStatus", 5usize, |this| ((**(this as *const *const IESEvent_Vtbl)).SetCompletionStatus)(this, dwresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetData(this: *mut core::ffi::c_void, pbdata: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESEvent", "GetData", 6usize, |this| ((**(this as *const *const IESEvent_Vtbl)).GetData)(this, pbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStringData(this: *mut core::ffi::c_void, pbstrdata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESEvent", "GetStringData", 7usize, |this| ((**(this as *const *const IESEvent_Vtbl)).GetStringData)(this, pbstrdata), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetEventId,
+            GetEventType,
+            SetCompletionStatus,
+            #[cfg(feature = "Win32_System_Com")]
+            GetData,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetData: 0,
+            GetStringData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESEvent as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IESEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnESEventReceived(this: *mut core::ffi::c_void, guideventtype: windows_core::GUID, pesevent: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IESEvents", "OnESEventReceived", 3usize, |this| ((**(this as *const *const IESEvents_Vtbl)).OnESEventReceived)(this, guideventtype, pesevent), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnESEventReceived }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IESEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEncoderAPI_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, api: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEncoderAPI", "IsSupported", 3usize, |this| ((**(this as *const *const IEncoderAPI_Vtbl)).IsSupported)(this, api), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAvailable(this: *mut core::ffi::c_void, api: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEncoderAPI", "IsAvailable", 4usize, |this| ((**(this as *const *const IEncoderAPI_Vtbl)).IsAvailable)(this, api), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParameterRange(this: *mut core::ffi::c_void, api: *const windows_core::GUID, valuemin: *mut core::mem::MaybeUninit<windows_core::VARIANT>, valuemax: *mut core::mem::MaybeUninit<windows_core::VARIANT>, steppingdelta: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEncoderAPI", "GetParameterRange", 5usize, |this| ((**(this as *const *const IEncoderAPI_Vtbl)).GetParameterRange)(this, api, valuemin, valuemax, steppingdelta), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParameterValues(this: *mut core::ffi::c_void, api: *const windows_core::GUID, values: *mut *mut windows_core::VARIANT, valuescount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEncoderAPI", "GetParameterValues", 6usize, |this| ((**(this as *const *const IEncoderAPI_Vtbl)).GetParameterValues)(this, api, values, valuescount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultValue(this: *mut core::ffi::c_void, api: *const windows_core::GUID, value: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEncoderAPI", "GetDefaultValue", 7usize, |this| ((**(this as *const *const IEncoderAPI_Vtbl)).GetDefaultValue)(this, api, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, api: *const windows_core::GUID, value: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEncoderAPI", "GetValue", 8usize, |this| ((**(this as *const *const IEncoderAPI_Vtbl)).GetValue)(this, api, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, api: *const windows_core::GUID, value: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEncoderAPI", "SetValue", 9usize, |this| ((**(this as *const *const IEncoderAPI_Vtbl)).SetValue)(this, api, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsSupported,
+            IsAvailable,
+            GetParameterRange,
+            GetParameterValues,
+            GetDefaultValue,
+            GetValue,
+            SetValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEncoderAPI as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumFilters_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, cfilters: u32, ppfilter: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumFilters", "Next", 3usize, |this| ((**(this as *const *const IEnumFilters_Vtbl)).Next)(this, cfilters, ppfilter, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, cfilters: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumFilters", "Skip", 4usize, |this| ((**(this as *const *const IEnumFilters_Vtbl)).Skip)(this, cfilters), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumFilters", "Reset", 5usize, |this| ((**(this as *const *const IEnumFilters_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumFilters", "Clone", 6usize, |this| ((**(this as *const *const IEnumFilters_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Skip,
+            Reset,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumFilters as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_MediaFoundation")]
+impl windows_core::imp::ProxyVtable for IEnumMediaTypes_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, cmediatypes: u32, ppmediatypes: *mut *mut super::MediaFoundation::AM_MEDIA_TYPE, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumMediaTypes", "Next", 3usize, |this| ((**(this as *const *const IEnumMediaTypes_Vtbl)).Next)(this, cmediatypes, ppmediatypes, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, cmediatypes: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumMediaTypes", "Skip", 4usize, |this| ((**(this as *const *const IEnumMediaTypes_Vtbl)).Skip)(this, cmediatypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumMediaTypes", "Reset", 5usize, |this| ((**(this as *const *const IEnumMediaTypes_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumMediaTypes", "Clone", 6usize, |this| ((**(this as *const *const IEnumMediaTypes_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            Next,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            Next: 0,
+            Skip,
+            Reset,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumMediaTypes as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumPIDMap_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, crequest: u32, ppidmap: *mut PID_MAP, pcreceived: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPIDMap", "Next", 3usize, |this| ((**(this as *const *const IEnumPIDMap_Vtbl)).Next)(this, crequest, ppidmap, pcreceived), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, crecords: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPIDMap", "Skip", 4usize, |this| ((**(this as *const *const IEnumPIDMap_Vtbl)).Skip)(this, crecords), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPIDMap", "Reset", 5usize, |this| ((**(this as *const *const IEnumPIDMap_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppienumpidmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPIDMap", "Clone", 6usize, |this| ((**(this as *const *const IEnumPIDMap_Vtbl)).Clone)(this, ppienumpidmap), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumPIDMap as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumPins_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, cpins: u32, pppins: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPins", "Next", 3usize, |this| ((**(this as *const *const IEnumPins_Vtbl)).Next)(this, cpins, pppins, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, cpins: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPins", "Skip", 4usize, |this| ((**(this as *const *const IEnumPins_Vtbl)).Skip)(this, cpins), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPins", "Reset", 5usize, |this| ((**(this as *const *const IEnumPins_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumPins", "Clone", 6usize, |this| ((**(this as *const *const IEnumPins_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumPins as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumRegFilters_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, cfilters: u32, apregfilter: *mut *mut REGFILTER, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumRegFilters", "Next", 3usize, |this| ((**(this as *const *const IEnumRegFilters_Vtbl)).Next)(this, cfilters, apregfilter, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, cfilters: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumRegFilters", "Skip", 4usize, |this| ((**(this as *const *const IEnumRegFilters_Vtbl)).Skip)(this, cfilters), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumRegFilters", "Reset", 5usize, |this| ((**(this as *const *const IEnumRegFilters_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumRegFilters", "Clone", 6usize, |this| ((**(this as *const *const IEnumRegFilters_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumRegFilters as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumStreamIdMap_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, crequest: u32, pstreamidmap: *mut STREAM_ID_MAP, pcreceived: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumStreamIdMap", "Next", 3usize, |this| ((**(this as *const *const IEnumStreamIdMap_Vtbl)).Next)(this, crequest, pstreamidmap, pcreceived), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, crecords: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumStreamIdMap", "Skip", 4usize, |this| ((**(this as *const *const IEnumStreamIdMap_Vtbl)).Skip)(this, crecords), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumStreamIdMap", "Reset", 5usize, |this| ((**(this as *const *const IEnumStreamIdMap_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppienumstreamidmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumStreamIdMap", "Clone", 6usize, |this| ((**(this as *const *const IEnumStreamIdMap_Vtbl)).Clone)(this, ppienumstreamidmap), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumStreamIdMap as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_MediaFoundation")]
+impl windows_core::imp::ProxyVtable for IFileSinkFilter_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn SetFileName(this: *mut core::ffi::c_void, pszfilename: windows_core::PCWSTR, pmt: *const super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSinkFilter", "SetFileName", 3usize, |this| ((**(this as *const *const IFileSinkFilter_Vtbl)).SetFileName)(this, pszfilename, pmt), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn GetCurFile(this: *mut core::ffi::c_void, ppszfilename: *mut windows_core::PWSTR, pmt: *mut super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSinkFilter", "GetCurFile", 4usize, |this| ((**(this as *const *const IFileSinkFilter_Vtbl)).GetCurFile)(this, ppszfilename, pmt), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            SetFileName,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            SetFileName: 0,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            GetCurFile,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            GetCurFile: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileSinkFilter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_MediaFoundation")]
+impl windows_core::imp::ProxyVtable for IFileSinkFilter2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMode(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSinkFilter2", "SetMode", 5usize, |this| ((**(this as *const *const IFileSinkFilter2_Vtbl)).SetMode)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMode(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSinkFilter2", "GetMode", 6usize, |this| ((**(this as *const *const IFileSinkFilter2_Vtbl)).GetMode)(this, pdwflags), |result| Some(*result))
+        }
+        Self { base__: <IFileSinkFilter_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetMode, GetMode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileSinkFilter2 as windows_core::Interface>::IID || <IFileSinkFilter_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_MediaFoundation")]
+impl windows_core::imp::ProxyVtable for IFileSourceFilter_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn Load(this: *mut core::ffi::c_void, pszfilename: windows_core::PCWSTR, pmt: *const super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSourceFilter", "Load", 3usize, |this| ((**(this as *const *const IFileSourceFilter_Vtbl)).Load)(this, pszfilename, pmt), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn GetCurFile(this: *mut core::ffi::c_void, ppszfilename: *mut windows_core::PWSTR, pmt: *mut super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSourceFilter", "GetCurFile", 4usize, |this| ((**(this as *const *const IFileSourceFilter_Vtbl)).GetCurFile)(this, ppszfilename, pmt), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            Load,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            Load: 0,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            GetCurFile,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            GetCurFile: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileSourceFilter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFilterChain_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn StartChain(this: *mut core::ffi::c_void, pstartfilter: *mut core::ffi::c_void, pendfilter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterChain", "StartChain", 3usize, |this| ((**(this as *const *const IFilterChain_Vtbl)).StartChain)(this, pstartfilter, pendfilter), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PauseChain(this: *mut core::ffi::c_void, pstartfilter: *mut core::ffi::c_void, pendfilter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterChain", "PauseChain", 4usize, |this| ((**(this as *const *const IFilterChain_Vtbl)).PauseChain)(this, pstartfilter, pendfilter), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn StopChain(this: *mut core::ffi::c_void, pstartfilter: *mut core::ffi::c_void, pendfilter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterChain", "StopChain", 5usize, |this| ((**(this as *const *const IFilterChain_Vtbl)).StopChain)(this, pstartfilter, pendfilter), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RemoveChain(this: *mut core::ffi::c_void, pstartfilter: *mut core::ffi::c_void, pendfilter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterChain", "RemoveChain", 6usize, |this| ((**(this as *const *const IFilterChain_Vtbl)).RemoveChain)(this, pstartfilter, pendfilter), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            StartChain,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            StartChain: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PauseChain,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PauseChain: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            StopChain,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            StopChain: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            RemoveChain,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RemoveChain: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFilterChain as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Media_MediaFoundation", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IFilterGraph_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddFilter(this: *mut core::ffi::c_void, pfilter: *mut core::ffi::c_void, pname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterGraph", "AddFilter", 3usize, |this| ((**(this as *const *const IFilterGraph_Vtbl)).AddFilter)(this, pfilter, pname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RemoveFilter(this: *mut core::ffi::c_void, pfilter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterGraph", "RemoveFilter", 4usize, |this| ((**(this as *const *const IFilterGraph_Vtbl)).RemoveFilter)(this, pfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumFilters(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterGraph", "EnumFilters", 5usize, |this| ((**(this as *const *const IFilterGraph_Vtbl)).EnumFilters)(this, ppenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn FindFilterByName(this: *mut core::ffi::c_void, pname: windows_core::PCWSTR, ppfilter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterGraph", "FindFilterByName", 6usize, |this| ((**(this as *const *const IFilterGraph_Vtbl)).FindFilterByName)(this, pname, ppfilter), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn ConnectDirect(this: *mut core::ffi::c_void, ppinout: *mut core::ffi::c_void, ppinin: *mut core::ffi::c_void, pmt: *const super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterGraph", "ConnectDirect", 7usize, |this| ((**(this as *const *const IFilterGraph_Vtbl)).ConnectDirect)(this, ppinout, ppinin, pmt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reconnect(this: *mut core::ffi::c_void, ppin: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterGraph", "Reconnect", 8usize, |this| ((**(this as *const *const IFilterGraph_Vtbl)).Reconnect)(this, ppin), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnect(this: *mut core::ffi::c_void, ppin: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterGraph", "Disconnect", 9usize, |this| ((**(this as *const *const IFilterGraph_Vtbl)).Disconnect)(this, ppin), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultSyncSource(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterGraph", "SetDefaultSyncSource", 10usize, |this| ((**(this as *const *const IFilterGraph_Vtbl)).SetDefaultSyncSource)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            AddFilter,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddFilter: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            RemoveFilter,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RemoveFilter: 0,
+            EnumFilters,
+            #[cfg(feature = "Win32_System_Com")]
+            FindFilterByName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            FindFilterByName: 0,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            ConnectDirect,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            ConnectDirect: 0,
+            Reconnect,
+            Disconnect,
+            SetDefaultSyncSource,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFilterGraph as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Media_MediaFoundation", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IFilterGraph2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddSourceFilterForMoniker(this: *mut core::ffi::c_void, pmoniker: *mut core::ffi::c_void, pctx: *mut core::ffi::c_void, lpcwstrfiltername: windows_core::PCWSTR, ppfilter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterGraph2", "AddSourceFilterForMoniker", 18usize, |this| ((**(this as *const *const IFilterGraph2_Vtbl)).AddSourceFilterForMoniker)(this, pmoniker, pctx, lpcwstrfiltername, ppfilter), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn ReconnectEx(this: *mut core::ffi::c_void, ppin: *mut core::ffi::c_void, pmt: *const super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterGraph2", "ReconnectEx", 19usize, |this| ((**(this as *const *const IFilterGraph2_Vtbl)).ReconnectEx)(this, ppin, pmt), |result| Some(*result))
+        }
+        unsafe extern "system" fn RenderEx(this: *mut core::ffi::c_void, ppinout: *mut core::ffi::c_void, dwflags: u32, pvcontext: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterGraph2", "RenderEx", 20usize, |this| ((**(this as *const *const IFilterGraph2_Vtbl)).RenderEx)(this, ppinout, dwflags, pvcontext), |result| Some(*result))
+        }
+        Self {
+            base__: <IGraphBuilder_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            AddSourceFilterForMoniker,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddSourceFilterForMoniker: 0,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            ReconnectEx,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            ReconnectEx: 0,
+            RenderEx,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFilterGraph2 as windows_core::Interface>::IID || <IGraphBuilder_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Media_MediaFoundation", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IFilterGraph3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSyncSourceEx(this: *mut core::ffi::c_void, pclockformostoffiltergraph: *mut core::ffi::c_void, pclockforfilter: *mut core::ffi::c_void, pfilter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterGraph3", "SetSyncSourceEx", 21usize, |this| ((**(this as *const *const IFilterGraph3_Vtbl)).SetSyncSourceEx)(this, pclockformostoffiltergraph, pclockforfilter, pfilter), |result| Some(*result))
+        }
+        Self {
+            base__: <IFilterGraph2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSyncSourceEx,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSyncSourceEx: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFilterGraph3 as windows_core::Interface>::IID || <IFilterGraph2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFilterInfo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn FindPin(this: *mut core::ffi::c_void, strpinid: core::mem::MaybeUninit<windows_core::BSTR>, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterInfo", "FindPin", 7usize, |this| ((**(this as *const *const IFilterInfo_Vtbl)).FindPin)(this, strpinid, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, strname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterInfo", "Name", 8usize, |this| ((**(this as *const *const IFilterInfo_Vtbl)).Name)(this, strname), |result| Some(*result))
+        }
+        unsafe extern "system" fn VendorInfo(this: *mut core::ffi::c_void, strvendorinfo: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterInfo", "VendorInfo", 9usize, |this| ((**(this as *const *const IFilterInfo_Vtbl)).VendorInfo)(this, strvendorinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn Filter(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterInfo", "Filter", 10usize, |this| ((**(this as *const *const IFilterInfo_Vtbl)).Filter)(this, ppunk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Pins(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterInfo", "Pins", 11usize, |this| ((**(this as *const *const IFilterInfo_Vtbl)).Pins)(this, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFileSource(this: *mut core::ffi::c_void, pbissource: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterInfo", "IsFileSource", 12usize, |this| ((**(this as *const *const IFilterInfo_Vtbl)).IsFileSource)(this, pbissource), |result| Some(*result))
+        }
+        unsafe extern "system" fn Filename(this: *mut core::ffi::c_void, pstrfilename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterInfo", "Filename", 13usize, |this| ((**(this as *const *const IFilterInfo_Vtbl)).Filename)(this, pstrfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFilename(this: *mut core::ffi::c_void, strfilename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterInfo", "SetFilename", 14usize, |this| ((**(this as *const *const IFilterInfo_Vtbl)).SetFilename)(this, strfilename), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            FindPin,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            FindPin: 0,
+            Name,
+            VendorInfo,
+            Filter,
+            #[cfg(feature = "Win32_System_Com")]
+            Pins,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Pins: 0,
+            IsFileSource,
+            Filename,
+            SetFilename,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFilterInfo as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFilterMapper_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterFilter(this: *mut core::ffi::c_void, clsid: windows_core::GUID, name: windows_core::PCWSTR, dwmerit: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterMapper", "RegisterFilter", 3usize, |this| ((**(this as *const *const IFilterMapper_Vtbl)).RegisterFilter)(this, clsid, name, dwmerit), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterFilterInstance(this: *mut core::ffi::c_void, clsid: windows_core::GUID, name: windows_core::PCWSTR, mrid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterMapper", "RegisterFilterInstance", 4usize, |this| ((**(this as *const *const IFilterMapper_Vtbl)).RegisterFilterInstance)(this, clsid, name, mrid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterPin(this: *mut core::ffi::c_void, filter: windows_core::GUID, name: windows_core::PCWSTR, brendered: super::super::Foundation::BOOL, boutput: super::super::Foundation::BOOL, bzero: super::super::Foundation::BOOL, bmany: super::super::Foundation::BOOL, connectstofilter: windows_core::GUID, connectstopin: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterMapper", "RegisterPin", 5usize, |this| ((**(this as *const *const IFilterMapper_Vtbl)).RegisterPin)(this, filter, name, brendered, boutput, bzero, bmany, connectstofilter, connectstopin), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterPinType(this: *mut core::ffi::c_void, clsfilter: windows_core::GUID, strname: windows_core::PCWSTR, clsmajortype: windows_core::GUID, clssubtype: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterMapper", "RegisterPinType", 6usize, |this| ((**(this as *const *const IFilterMapper_Vtbl)).RegisterPinType)(this, clsfilter, strname, clsmajortype, clssubtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterFilter(this: *mut core::ffi::c_void, filter: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterMapper", "UnregisterFilter", 7usize, |this| ((**(this as *const *const IFilterMapper_Vtbl)).UnregisterFilter)(this, filter), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterFilterInstance(this: *mut core::ffi::c_void, mrid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterMapper", "UnregisterFilterInstance", 8usize, |this| ((**(this as *const *const IFilterMapper_Vtbl)).UnregisterFilterInstance)(this, mrid), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterPin(this: *mut core::ffi::c_void, filter: windows_core::GUID, name: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterMapper", "UnregisterPin", 9usize, |this| ((**(this as *const *const IFilterMapper_Vtbl)).UnregisterPin)(this, filter, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumMatchingFilters(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void, dwmerit: u32, binputneeded: super::super::Foundation::BOOL, clsinmaj: windows_core::GUID, clsinsub: windows_core::GUID, brender: super::super::Foundation::BOOL, boututneeded: super::super::Foundation::BOOL, clsoutmaj: windows_core::GUID, clsoutsub: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterMapper", "EnumMatchingFilters", 10usize, |this| ((**(this as *const *const IFilterMapper_Vtbl)).EnumMatchingFilters)(this, ppenum, dwmerit, binputneeded, clsinmaj, clsinsub, brender, boututneeded, clsoutmaj, clsoutsub), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RegisterFilter,
+            RegisterFilterInstance,
+            RegisterPin,
+            RegisterPinType,
+            UnregisterFilter,
+            UnregisterFilterInstance,
+            UnregisterPin,
+            EnumMatchingFilters,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFilterMapper as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFilterMapper2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateCategory(this: *mut core::ffi::c_void, clsidcategory: *const windows_core::GUID, dwcategorymerit: u32, description: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterMapper2", "CreateCategory", 3usize, |this| ((**(this as *const *const IFilterMapper2_Vtbl)).CreateCategory)(this, clsidcategory, dwcategorymerit, description), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterFilter(this: *mut core::ffi::c_void, pclsidcategory: *const windows_core::GUID, szinstance: windows_core::PCWSTR, filter: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterMapper2", "UnregisterFilter", 4usize, |this| ((**(this as *const *const IFilterMapper2_Vtbl)).UnregisterFilter)(this, pclsidcategory, szinstance, filter), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RegisterFilter(this: *mut core::ffi::c_void, clsidfilter: *const windows_core::GUID, name: windows_core::PCWSTR, ppmoniker: *mut *mut core::ffi::c_void, pclsidcategory: *const windows_core::GUID, szinstance: windows_core::PCWSTR, prf2: *const REGFILTER2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterMapper2", "RegisterFilter", 5usize, |this| ((**(this as *const *const IFilterMapper2_Vtbl)).RegisterFilter)(this, clsidfilter, name, ppmoniker, pclsidcategory, szinstance, prf2), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumMatchingFilters(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void, dwflags: u32, bexactmatch: super::super::Foundation::BOOL, dwmerit: u32, binputneeded: super::super::Foundation::BOOL, cinputtypes: u32, pinputtypes: *const windows_core::GUID, pmedin: *const REGPINMEDIUM, ppincategoryin: *const windows_core::GUID, brender: super::super::Foundation::BOOL, boutputneeded: super::super::Foundation::BOOL, coutputtypes: u32, poutputtypes: *const windows_core::GUID, pmedout: *const REGPINMEDIUM, ppincategoryout: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterMapper2", "EnumMatchingFilters", 6usize, |this| ((**(this as *const *const IFilterMapper2_Vtbl)).EnumMatchingFilters)(this, ppenum, dwflags, bexactmatch, dwmerit, binputneeded, cinputtypes, pinputtypes, pmedin, ppincategoryin, brender, boutputneeded, coutputtypes, poutputtypes, pmedout, ppincategoryout), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateCategory,
+            UnregisterFilter,
+            #[cfg(feature = "Win32_System_Com")]
+            RegisterFilter,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RegisterFilter: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumMatchingFilters,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumMatchingFilters: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFilterMapper2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFilterMapper3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetICreateDevEnum(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterMapper3", "GetICreateDevEnum", 7usize, |this| ((**(this as *const *const IFilterMapper3_Vtbl)).GetICreateDevEnum)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <IFilterMapper2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetICreateDevEnum }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFilterMapper3 as windows_core::Interface>::IID || <IFilterMapper2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFrequencyMap_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_FrequencyMapping(this: *mut core::ffi::c_void, ulcount: *mut u32, ppullist: *mut *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrequencyMap", "get_FrequencyMapping", 3usize, |this| ((**(this as *const *const IFrequencyMap_Vtbl)).get_FrequencyMapping)(this, ulcount, ppullist), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_FrequencyMapping(this: *mut core::ffi::c_void, ulcount: u32, plist: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrequencyMap", "put_FrequencyMapping", 4usize, |this| ((**(this as *const *const IFrequencyMap_Vtbl)).put_FrequencyMapping)(this, ulcount, plist), |result| Some(*result))
+        }
+        unsafe extern "system" fn CountryCode(this: *mut core::ffi::c_void, pulcountrycode: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrequencyMap", "CountryCode", 5usize, |this| ((**(this as *const *const IFrequencyMap_Vtbl)).CountryCode)(this, pulcountrycode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCountryCode(this: *mut core::ffi::c_void, ulcountrycode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrequencyMap", "SetCountryCode", 6usize, |this| ((**(this as *const *const IFrequencyMap_Vtbl)).SetCountryCode)(this, ulcountrycode), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_DefaultFrequencyMapping(this: *mut core::ffi::c_void, ulcountrycode: u32, pulcount: *mut u32, ppullist: *mut *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrequencyMap", "get_DefaultFrequencyMapping", 7usize, |this| ((**(this as *const *const IFrequencyMap_Vtbl)).get_DefaultFrequencyMapping)(this, ulcountrycode, pulcount, ppullist), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_CountryCodeList(this: *mut core::ffi::c_void, pulcount: *mut u32, ppullist: *mut *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFrequencyMap", "get_CountryCodeList", 8usize, |this| ((**(this as *const *const IFrequencyMap_Vtbl)).get_CountryCodeList)(this, pulcount, ppullist), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            get_FrequencyMapping,
+            put_FrequencyMapping,
+            CountryCode,
+            SetCountryCode,
+            get_DefaultFrequencyMapping,
+            get_CountryCodeList,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFrequencyMap as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFullScreenVideo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CountModes(this: *mut core::ffi::c_void, pmodes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullScreenVideo", "CountModes", 3usize, |this| ((**(this as *const *const IFullScreenVideo_Vtbl)).CountModes)(this, pmodes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetModeInfo(this: *mut core::ffi::c_void, mode: i32, pwidth: *mut i32, pheight: *mut i32, pdepth: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullScreenVideo", "GetModeInfo", 4usize, |this| ((**(this as *const *const IFullScreenVideo_Vtbl)).GetModeInfo)(this, mode, pwidth, pheight, pdepth), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentMode(this: *mut core::ffi::c_void, pmode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullScreenVideo", "GetCurrentMode", 5usize, |this| ((**(this as *const *const IFullScreenVideo_Vtbl)).GetCurrentMode)(this, pmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsModeAvailable(this: *mut core::ffi::c_void, mode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullScreenVideo", "IsModeAvailable", 6usize, |this| ((**(this as *const *const IFullScreenVideo_Vtbl)).IsModeAvailable)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsModeEnabled(this: *mut core::ffi::c_void, mode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullScreenVideo", "IsModeEnabled", 7usize, |this| ((**(this as *const *const IFullScreenVideo_Vtbl)).IsModeEnabled)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, mode: i32, benabled: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullScreenVideo", "SetEnabled", 8usize, |this| ((**(this as *const *const IFullScreenVideo_Vtbl)).SetEnabled)(this, mode, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClipFactor(this: *mut core::ffi::c_void, pclipfactor: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullScreenVideo", "GetClipFactor", 9usize, |this| ((**(this as *const *const IFullScreenVideo_Vtbl)).GetClipFactor)(this, pclipfactor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClipFactor(this: *mut core::ffi::c_void, clipfactor: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullScreenVideo", "SetClipFactor", 10usize, |this| ((**(this as *const *const IFullScreenVideo_Vtbl)).SetClipFactor)(this, clipfactor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMessageDrain(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullScreenVideo", "SetMessageDrain", 11usize, |this| ((**(this as *const *const IFullScreenVideo_Vtbl)).SetMessageDrain)(this, hwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMessageDrain(this: *mut core::ffi::c_void, hwnd: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullScreenVideo", "GetMessageDrain", 12usize, |this| ((**(this as *const *const IFullScreenVideo_Vtbl)).GetMessageDrain)(this, hwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMonitor(this: *mut core::ffi::c_void, monitor: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullScreenVideo", "SetMonitor", 13usize, |this| ((**(this as *const *const IFullScreenVideo_Vtbl)).SetMonitor)(this, monitor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMonitor(this: *mut core::ffi::c_void, monitor: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullScreenVideo", "GetMonitor", 14usize, |this| ((**(this as *const *const IFullScreenVideo_Vtbl)).GetMonitor)(this, monitor), |result| Some(*result))
+        }
+        unsafe extern "system" fn HideOnDeactivate(this: *mut core::ffi::c_void, hide: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullScreenVideo", "HideOnDeactivate", 15usize, |this| ((**(this as *const *const IFullScreenVideo_Vtbl)).HideOnDeactivate)(this, hide), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsHideOnDeactivate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullScreenVideo", "IsHideOnDeactivate", 16usize, |this| ((**(this as *const *const IFullScreenVideo_Vtbl)).IsHideOnDeactivate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCaption(this: *mut core::ffi::c_void, strcaption: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullScreenVideo", "SetCaption", 17usize, |this| ((**(this as *const *const IFullScreenVideo_Vtbl)).SetCaption)(this, strcaption), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCaption(this: *mut core::ffi::c_void, pstrcaption: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullScreenVideo", "GetCaption", 18usize, |this| ((**(this as *const *const IFullScreenVideo_Vtbl)).GetCaption)(this, pstrcaption), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefault(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullScreenVideo", "SetDefault", 19usize, |this| ((**(this as *const *const IFullScreenVideo_Vtbl)).SetDefault)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CountModes,
+            GetModeInfo,
+            GetCurrentMode,
+            IsModeAvailable,
+            IsModeEnabled,
+            SetEnabled,
+            GetClipFactor,
+            SetClipFactor,
+            SetMessageDrain,
+            GetMessageDrain,
+            SetMonitor,
+            GetMonitor,
+            HideOnDeactivate,
+            IsHideOnDeactivate,
+            SetCaption,
+            GetCaption,
+            SetDefault,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFullScreenVideo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl windows_core::imp::ProxyVtable for IFullScreenVideoEx_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn SetAcceleratorTable(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, haccel: super::super::UI::WindowsAndMessaging::HACCEL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullScreenVideoEx", "SetAcceleratorTable", 20usize, |this| ((**(this as *const *const IFullScreenVideoEx_Vtbl)).SetAcceleratorTable)(this, hwnd, haccel), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn GetAcceleratorTable(this: *mut core::ffi::c_void, phwnd: *mut super::super::Foundation::HWND, phaccel: *mut super::super::UI::WindowsAndMessaging::HACCEL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullScreenVideoEx", "GetAcceleratorTable", 21usize, |this| ((**(this as *const *const IFullScreenVideoEx_Vtbl)).GetAcceleratorTable)(this, phwnd, phaccel), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeepPixelAspectRatio(this: *mut core::ffi::c_void, keepaspect: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullScreenVideoEx", "KeepPixelAspectRatio", 22usize, |this| ((**(this as *const *const IFullScreenVideoEx_Vtbl)).KeepPixelAspectRatio)(this, keepaspect), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsKeepPixelAspectRatio(this: *mut core::ffi::c_void, pkeepaspect: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFullScreenVideoEx", "IsKeepPixelAspectRatio", 23usize, |this| ((**(this as *const *const IFullScreenVideoEx_Vtbl)).IsKeepPixelAspectRatio)(this, pkeepaspect), |result| Some(*result))
+        }
+        Self {
+            base__: <IFullScreenVideo_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            SetAcceleratorTable,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            SetAcceleratorTable: 0,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            GetAcceleratorTable,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            GetAcceleratorTable: 0,
+            KeepPixelAspectRatio,
+            IsKeepPixelAspectRatio,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFullScreenVideoEx as windows_core::Interface>::IID || <IFullScreenVideo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Registry")]
+impl windows_core::imp::ProxyVtable for IGetCapabilitiesKey_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Registry")]
+        unsafe extern "system" fn GetCapabilitiesKey(this: *mut core::ffi::c_void, phkey: *mut super::super::System::Registry::HKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetCapabilitiesKey", "GetCapabilitiesKey", 3usize, |this| ((**(this as *const *const IGetCapabilitiesKey_Vtbl)).GetCapabilitiesKey)(this, phkey), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Registry")]
+            GetCapabilitiesKey,
+            #[cfg(not(feature = "Win32_System_Registry"))]
+            GetCapabilitiesKey: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGetCapabilitiesKey as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Media_MediaFoundation", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IGraphBuilder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Connect(this: *mut core::ffi::c_void, ppinout: *mut core::ffi::c_void, ppinin: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphBuilder", "Connect", 11usize, |this| ((**(this as *const *const IGraphBuilder_Vtbl)).Connect)(this, ppinout, ppinin), |result| Some(*result))
+        }
+        unsafe extern "system" fn Render(this: *mut core::ffi::c_void, ppinout: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphBuilder", "Render", 12usize, |this| ((**(this as *const *const IGraphBuilder_Vtbl)).Render)(this, ppinout), |result| Some(*result))
+        }
+        unsafe extern "system" fn RenderFile(this: *mut core::ffi::c_void, lpcwstrfile: windows_core::PCWSTR, lpcwstrplaylist: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphBuilder", "RenderFile", 13usize, |this| ((**(this as *const *const IGraphBuilder_Vtbl)).RenderFile)(this, lpcwstrfile, lpcwstrplaylist), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddSourceFilter(this: *mut core::ffi::c_void, lpcwstrfilename: windows_core::PCWSTR, lpcwstrfiltername: windows_core::PCWSTR, ppfilter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphBuilder", "AddSourceFilter", 14usize, |this| ((**(this as *const *const IGraphBuilder_Vtbl)).AddSourceFilter)(this, lpcwstrfilename, lpcwstrfiltername, ppfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLogFile(this: *mut core::ffi::c_void, hfile: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphBuilder", "SetLogFile", 15usize, |this| ((**(this as *const *const IGraphBuilder_Vtbl)).SetLogFile)(this, hfile), |result| Some(*result))
+        }
+        unsafe extern "system" fn Abort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphBuilder", "Abort", 16usize, |this| ((**(this as *const *const IGraphBuilder_Vtbl)).Abort)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShouldOperationContinue(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphBuilder", "ShouldOperationContinue", 17usize, |this| ((**(this as *const *const IGraphBuilder_Vtbl)).ShouldOperationContinue)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IFilterGraph_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Connect,
+            Render,
+            RenderFile,
+            #[cfg(feature = "Win32_System_Com")]
+            AddSourceFilter,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddSourceFilter: 0,
+            SetLogFile,
+            Abort,
+            ShouldOperationContinue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGraphBuilder as windows_core::Interface>::IID || <IFilterGraph_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Media_MediaFoundation", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IGraphConfig_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Media_MediaFoundation", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn Reconnect(this: *mut core::ffi::c_void, poutputpin: *mut core::ffi::c_void, pinputpin: *mut core::ffi::c_void, pmtfirstconnection: *const super::MediaFoundation::AM_MEDIA_TYPE, pusingfilter: *mut core::ffi::c_void, habortevent: super::super::Foundation::HANDLE, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphConfig", "Reconnect", 3usize, |this| ((**(this as *const *const IGraphConfig_Vtbl)).Reconnect)(this, poutputpin, pinputpin, pmtfirstconnection, pusingfilter, habortevent, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reconfigure(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void, dwflags: u32, habortevent: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphConfig", "Reconfigure", 4usize, |this| ((**(this as *const *const IGraphConfig_Vtbl)).Reconfigure)(this, pcallback, pvcontext, dwflags, habortevent), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddFilterToCache(this: *mut core::ffi::c_void, pfilter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphConfig", "AddFilterToCache", 5usize, |this| ((**(this as *const *const IGraphConfig_Vtbl)).AddFilterToCache)(this, pfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumCacheFilter(this: *mut core::ffi::c_void, penum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphConfig", "EnumCacheFilter", 6usize, |this| ((**(this as *const *const IGraphConfig_Vtbl)).EnumCacheFilter)(this, penum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RemoveFilterFromCache(this: *mut core::ffi::c_void, pfilter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphConfig", "RemoveFilterFromCache", 7usize, |this| ((**(this as *const *const IGraphConfig_Vtbl)).RemoveFilterFromCache)(this, pfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStartTime(this: *mut core::ffi::c_void, prtstart: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphConfig", "GetStartTime", 8usize, |this| ((**(this as *const *const IGraphConfig_Vtbl)).GetStartTime)(this, prtstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn PushThroughData(this: *mut core::ffi::c_void, poutputpin: *mut core::ffi::c_void, pconnection: *mut core::ffi::c_void, heventabort: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphConfig", "PushThroughData", 9usize, |this| ((**(this as *const *const IGraphConfig_Vtbl)).PushThroughData)(this, poutputpin, pconnection, heventabort), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetFilterFlags(this: *mut core::ffi::c_void, pfilter: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphConfig", "SetFilterFlags", 10usize, |this| ((**(this as *const *const IGraphConfig_Vtbl)).SetFilterFlags)(this, pfilter, dwflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFilterFlags(this: *mut core::ffi::c_void, pfilter: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphConfig", "GetFilterFlags", 11usize, |this| ((**(this as *const *const IGraphConfig_Vtbl)).GetFilterFlags)(this, pfilter, pdwflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RemoveFilterEx(this: *mut core::ffi::c_void, pfilter: *mut core::ffi::c_void, flags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphConfig", "RemoveFilterEx", 12usize, |this| ((**(this as *const *const IGraphConfig_Vtbl)).RemoveFilterEx)(this, pfilter, flags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Media_MediaFoundation", feature = "Win32_System_Com"))]
+            Reconnect,
+            #[cfg(not(all(feature = "Win32_Media_MediaFoundation", feature = "Win32_System_Com")))]
+            Reconnect: 0,
+            Reconfigure,
+            #[cfg(feature = "Win32_System_Com")]
+            AddFilterToCache,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddFilterToCache: 0,
+            EnumCacheFilter,
+            #[cfg(feature = "Win32_System_Com")]
+            RemoveFilterFromCache,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RemoveFilterFromCache: 0,
+            GetStartTime,
+            PushThroughData,
+            #[cfg(feature = "Win32_System_Com")]
+            SetFilterFlags,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetFilterFlags: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFilterFlags,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFilterFlags: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            RemoveFilterEx,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RemoveFilterEx: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGraphConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGraphConfigCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reconfigure(this: *mut core::ffi::c_void, pvcontext: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphConfigCallback", "Reconfigure", 3usize, |this| ((**(this as *const *const IGraphConfigCallback_Vtbl)).Reconfigure)(this, pvcontext, dwflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reconfigure }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGraphConfigCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGraphVersion_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryVersion(this: *mut core::ffi::c_void, pversion: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphVersion", "QueryVersion", 3usize, |this| ((**(this as *const *const IGraphVersion_Vtbl)).QueryVersion)(this, pversion), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryVersion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGraphVersion as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIPDVDec_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IPDisplay(this: *mut core::ffi::c_void, displaypix: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIPDVDec", "IPDisplay", 3usize, |this| ((**(this as *const *const IIPDVDec_Vtbl)).IPDisplay)(this, displaypix), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIPDisplay(this: *mut core::ffi::c_void, displaypix: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIPDVDec", "SetIPDisplay", 4usize, |this| ((**(this as *const *const IIPDVDec_Vtbl)).SetIPDisplay)(this, displaypix), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IPDisplay, SetIPDisplay }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIPDVDec as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMPEG2PIDMap_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MapPID(this: *mut core::ffi::c_void, culpid: u32, pulpid: *const u32, mediasamplecontent: MEDIA_SAMPLE_CONTENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMPEG2PIDMap", "MapPID", 3usize, |this| ((**(this as *const *const IMPEG2PIDMap_Vtbl)).MapPID)(this, culpid, pulpid, mediasamplecontent), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnmapPID(this: *mut core::ffi::c_void, culpid: u32, pulpid: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMPEG2PIDMap", "UnmapPID", 4usize, |this| ((**(this as *const *const IMPEG2PIDMap_Vtbl)).UnmapPID)(this, culpid, pulpid), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumPIDMap(this: *mut core::ffi::c_void, pienumpidmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMPEG2PIDMap", "EnumPIDMap", 5usize, |this| ((**(this as *const *const IMPEG2PIDMap_Vtbl)).EnumPIDMap)(this, pienumpidmap), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MapPID, UnmapPID, EnumPIDMap }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMPEG2PIDMap as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMPEG2StreamIdMap_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MapStreamId(this: *mut core::ffi::c_void, ulstreamid: u32, mediasamplecontent: u32, ulsubstreamfiltervalue: u32, idataoffset: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMPEG2StreamIdMap", "MapStreamId", 3usize, |this| ((**(this as *const *const IMPEG2StreamIdMap_Vtbl)).MapStreamId)(this, ulstreamid, mediasamplecontent, ulsubstreamfiltervalue, idataoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnmapStreamId(this: *mut core::ffi::c_void, culstreamid: u32, pulstreamid: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMPEG2StreamIdMap", "UnmapStreamId", 4usize, |this| ((**(this as *const *const IMPEG2StreamIdMap_Vtbl)).UnmapStreamId)(this, culstreamid, pulstreamid), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumStreamIdMap(this: *mut core::ffi::c_void, ppienumstreamidmap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMPEG2StreamIdMap", "EnumStreamIdMap", 5usize, |this| ((**(this as *const *const IMPEG2StreamIdMap_Vtbl)).EnumStreamIdMap)(this, ppienumstreamidmap), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MapStreamId, UnmapStreamId, EnumStreamIdMap }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMPEG2StreamIdMap as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMediaControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Run(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "Run", 7usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).Run)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "Pause", 8usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "Stop", 9usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetState(this: *mut core::ffi::c_void, mstimeout: i32, pfs: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "GetState", 10usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).GetState)(this, mstimeout, pfs), |result| Some(*result))
+        }
+        unsafe extern "system" fn RenderFile(this: *mut core::ffi::c_void, strfilename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "RenderFile", 11usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).RenderFile)(this, strfilename), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddSourceFilter(this: *mut core::ffi::c_void, strfilename: core::mem::MaybeUninit<windows_core::BSTR>, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "AddSourceFilter", 12usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).AddSourceFilter)(this, strfilename, ppunk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn FilterCollection(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "FilterCollection", 13usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).FilterCollection)(this, ppunk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RegFilterCollection(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "RegFilterCollection", 14usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).RegFilterCollection)(this, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopWhenReady(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaControl", "StopWhenReady", 15usize, |this| ((**(this as *const *const IMediaControl_Vtbl)).StopWhenReady)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Run,
+            Pause,
+            Stop,
+            GetState,
+            RenderFile,
+            #[cfg(feature = "Win32_System_Com")]
+            AddSourceFilter,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddSourceFilter: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            FilterCollection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            FilterCollection: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            RegFilterCollection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RegFilterCollection: 0,
+            StopWhenReady,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaControl as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMediaEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEventHandle(this: *mut core::ffi::c_void, hevent: *mut isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEvent", "GetEventHandle", 7usize, |this| ((**(this as *const *const IMediaEvent_Vtbl)).GetEventHandle)(this, hevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEvent(this: *mut core::ffi::c_void, leventcode: *mut i32, lparam1: *mut isize, lparam2: *mut isize, mstimeout: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEvent", "GetEvent", 8usize, |this| ((**(this as *const *const IMediaEvent_Vtbl)).GetEvent)(this, leventcode, lparam1, lparam2, mstimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForCompletion(this: *mut core::ffi::c_void, mstimeout: i32, pevcode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEvent", "WaitForCompletion", 9usize, |this| ((**(this as *const *const IMediaEvent_Vtbl)).WaitForCompletion)(this, mstimeout, pevcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelDefaultHandling(this: *mut core::ffi::c_void, levcode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEvent", "CancelDefaultHandling", 10usize, |this| ((**(this as *const *const IMediaEvent_Vtbl)).CancelDefaultHandling)(this, levcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestoreDefaultHandling(this: *mut core::ffi::c_void, levcode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEvent", "RestoreDefaultHandling", 11usize, |this| ((**(this as *const *const IMediaEvent_Vtbl)).RestoreDefaultHandling)(this, levcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreeEventParams(this: *mut core::ffi::c_void, levcode: i32, lparam1: isize, lparam2: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEvent", "FreeEventParams", 12usize, |this| ((**(this as *const *const IMediaEvent_Vtbl)).FreeEventParams)(this, levcode, lparam1, lparam2), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetEventHandle,
+            GetEvent,
+            WaitForCompletion,
+            CancelDefaultHandling,
+            RestoreDefaultHandling,
+            FreeEventParams,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaEvent as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMediaEventEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetNotifyWindow(this: *mut core::ffi::c_void, hwnd: isize, lmsg: i32, linstancedata: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEventEx", "SetNotifyWindow", 13usize, |this| ((**(this as *const *const IMediaEventEx_Vtbl)).SetNotifyWindow)(this, hwnd, lmsg, linstancedata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNotifyFlags(this: *mut core::ffi::c_void, lnonotifyflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEventEx", "SetNotifyFlags", 14usize, |this| ((**(this as *const *const IMediaEventEx_Vtbl)).SetNotifyFlags)(this, lnonotifyflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNotifyFlags(this: *mut core::ffi::c_void, lplnonotifyflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEventEx", "GetNotifyFlags", 15usize, |this| ((**(this as *const *const IMediaEventEx_Vtbl)).GetNotifyFlags)(this, lplnonotifyflags), |result| Some(*result))
+        }
+        Self { base__: <IMediaEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetNotifyWindow, SetNotifyFlags, GetNotifyFlags }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaEventEx as windows_core::Interface>::IID || <IMediaEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaEventSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Notify(this: *mut core::ffi::c_void, eventcode: i32, eventparam1: isize, eventparam2: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaEventSink", "Notify", 3usize, |this| ((**(this as *const *const IMediaEventSink_Vtbl)).Notify)(this, eventcode, eventparam1, eventparam2), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Notify }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaEventSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMediaFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFilter", "Stop", 4usize, |this| ((**(this as *const *const IMediaFilter_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFilter", "Pause", 5usize, |this| ((**(this as *const *const IMediaFilter_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Run(this: *mut core::ffi::c_void, tstart: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFilter", "Run", 6usize, |this| ((**(this as *const *const IMediaFilter_Vtbl)).Run)(this, tstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetState(this: *mut core::ffi::c_void, dwmillisecstimeout: u32, state: *mut FILTER_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFilter", "GetState", 7usize, |this| ((**(this as *const *const IMediaFilter_Vtbl)).GetState)(this, dwmillisecstimeout, state), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSyncSource(this: *mut core::ffi::c_void, pclock: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFilter", "SetSyncSource", 8usize, |this| ((**(this as *const *const IMediaFilter_Vtbl)).SetSyncSource)(this, pclock), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSyncSource(this: *mut core::ffi::c_void, pclock: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaFilter", "GetSyncSource", 9usize, |this| ((**(this as *const *const IMediaFilter_Vtbl)).GetSyncSource)(this, pclock), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IPersist_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Stop,
+            Pause,
+            Run,
+            GetState,
+            SetSyncSource,
+            GetSyncSource,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaFilter as windows_core::Interface>::IID || <super::super::System::Com::IPersist_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaParamInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetParamCount(this: *mut core::ffi::c_void, pdwparams: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaParamInfo", "GetParamCount", 3usize, |this| ((**(this as *const *const IMediaParamInfo_Vtbl)).GetParamCount)(this, pdwparams), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParamInfo(this: *mut core::ffi::c_void, dwparamindex: u32, pinfo: *mut MP_PARAMINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaParamInfo", "GetParamInfo", 4usize, |this| ((**(this as *const *const IMediaParamInfo_Vtbl)).GetParamInfo)(this, dwparamindex, pinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParamText(this: *mut core::ffi::c_void, dwparamindex: u32, ppwchtext: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaParamInfo", "GetParamText", 5usize, |this| ((**(this as *const *const IMediaParamInfo_Vtbl)).GetParamText)(this, dwparamindex, ppwchtext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumTimeFormats(this: *mut core::ffi::c_void, pdwnumtimeformats: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaParamInfo", "GetNumTimeFormats", 6usize, |this| ((**(this as *const *const IMediaParamInfo_Vtbl)).GetNumTimeFormats)(this, pdwnumtimeformats), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSupportedTimeFormat(this: *mut core::ffi::c_void, dwformatindex: u32, pguidtimeformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaParamInfo", "GetSupportedTimeFormat", 7usize, |this| ((**(this as *const *const IMediaParamInfo_Vtbl)).GetSupportedTimeFormat)(this, dwformatindex, pguidtimeformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentTimeFormat(this: *mut core::ffi::c_void, pguidtimeformat: *mut windows_core::GUID, ptimedata: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaParamInfo", "GetCurrentTimeFormat", 8usize, |this| ((**(this as *const *const IMediaParamInfo_Vtbl)).GetCurrentTimeFormat)(this, pguidtimeformat, ptimedata), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetParamCount,
+            GetParamInfo,
+            GetParamText,
+            GetNumTimeFormats,
+            GetSupportedTimeFormat,
+            GetCurrentTimeFormat,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaParamInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaParams_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetParam(this: *mut core::ffi::c_void, dwparamindex: u32, pvalue: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaParams", "GetParam", 3usize, |this| ((**(this as *const *const IMediaParams_Vtbl)).GetParam)(this, dwparamindex, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParam(this: *mut core::ffi::c_void, dwparamindex: u32, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaParams", "SetParam", 4usize, |this| ((**(this as *const *const IMediaParams_Vtbl)).SetParam)(this, dwparamindex, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddEnvelope(this: *mut core::ffi::c_void, dwparamindex: u32, csegments: u32, penvelopesegments: *const MP_ENVELOPE_SEGMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaParams", "AddEnvelope", 5usize, |this| ((**(this as *const *const IMediaParams_Vtbl)).AddEnvelope)(this, dwparamindex, csegments, penvelopesegments), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlushEnvelope(this: *mut core::ffi::c_void, dwparamindex: u32, reftimestart: i64, reftimeend: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaParams", "FlushEnvelope", 6usize, |this| ((**(this as *const *const IMediaParams_Vtbl)).FlushEnvelope)(this, dwparamindex, reftimestart, reftimeend), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTimeFormat(this: *mut core::ffi::c_void, guidtimeformat: windows_core::GUID, mptimedata: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaParams", "SetTimeFormat", 7usize, |this| ((**(this as *const *const IMediaParams_Vtbl)).SetTimeFormat)(this, guidtimeformat, mptimedata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetParam, SetParam, AddEnvelope, FlushEnvelope, SetTimeFormat }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaParams as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMediaPosition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, plength: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPosition", "Duration", 7usize, |this| ((**(this as *const *const IMediaPosition_Vtbl)).Duration)(this, plength), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentPosition(this: *mut core::ffi::c_void, lltime: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPosition", "SetCurrentPosition", 8usize, |this| ((**(this as *const *const IMediaPosition_Vtbl)).SetCurrentPosition)(this, lltime), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentPosition(this: *mut core::ffi::c_void, plltime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPosition", "CurrentPosition", 9usize, |this| ((**(this as *const *const IMediaPosition_Vtbl)).CurrentPosition)(this, plltime), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopTime(this: *mut core::ffi::c_void, plltime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPosition", "StopTime", 10usize, |this| ((**(this as *const *const IMediaPosition_Vtbl)).StopTime)(this, plltime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStopTime(this: *mut core::ffi::c_void, lltime: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPosition", "SetStopTime", 11usize, |this| ((**(this as *const *const IMediaPosition_Vtbl)).SetStopTime)(this, lltime), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrerollTime(this: *mut core::ffi::c_void, plltime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPosition", "PrerollTime", 12usize, |this| ((**(this as *const *const IMediaPosition_Vtbl)).PrerollTime)(this, plltime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrerollTime(this: *mut core::ffi::c_void, lltime: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPosition", "SetPrerollTime", 13usize, |this| ((**(this as *const *const IMediaPosition_Vtbl)).SetPrerollTime)(this, lltime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRate(this: *mut core::ffi::c_void, drate: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPosition", "SetRate", 14usize, |this| ((**(this as *const *const IMediaPosition_Vtbl)).SetRate)(this, drate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rate(this: *mut core::ffi::c_void, pdrate: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPosition", "Rate", 15usize, |this| ((**(this as *const *const IMediaPosition_Vtbl)).Rate)(this, pdrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanSeekForward(this: *mut core::ffi::c_void, pcanseekforward: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPosition", "CanSeekForward", 16usize, |this| ((**(this as *const *const IMediaPosition_Vtbl)).CanSeekForward)(this, pcanseekforward), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanSeekBackward(this: *mut core::ffi::c_void, pcanseekbackward: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPosition", "CanSeekBackward", 17usize, |this| ((**(this as *const *const IMediaPosition_Vtbl)).CanSeekBackward)(this, pcanseekbackward), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Duration,
+            SetCurrentPosition,
+            CurrentPosition,
+            StopTime,
+            SetStopTime,
+            PrerollTime,
+            SetPrerollTime,
+            SetRate,
+            Rate,
+            CanSeekForward,
+            CanSeekBackward,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPosition as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for IMediaPropertyBag_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumProperty(this: *mut core::ffi::c_void, iproperty: u32, pvarpropertyname: *mut core::mem::MaybeUninit<windows_core::VARIANT>, pvarpropertyvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaPropertyBag", "EnumProperty", 5usize, |this| ((**(this as *const *const IMediaPropertyBag_Vtbl)).EnumProperty)(this, iproperty, pvarpropertyname, pvarpropertyvalue), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::StructuredStorage::IPropertyBag_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnumProperty }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaPropertyBag as windows_core::Interface>::IID || <super::super::System::Com::StructuredStorage::IPropertyBag_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_MediaFoundation")]
+impl windows_core::imp::ProxyVtable for IMediaSample_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPointer(this: *mut core::ffi::c_void, ppbuffer: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSample", "GetPointer", 3usize, |this| ((**(this as *const *const IMediaSample_Vtbl)).GetPointer)(this, ppbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSize(this: *mut core::ffi::c_void) -> i32 {
+            windows_core::imp::proxy_call(this, "IMediaSample", "GetSize", 4usize, |this| ((**(this as *const *const IMediaSample_Vtbl)).GetSize)(this), |_| None)
+        }
+        unsafe extern "system" fn GetTime(this: *mut core::ffi::c_void, ptimestart: *mut i64, ptimeend: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSample", "GetTime", 5usize, |this| ((**(this as *const *const IMediaSample_Vtbl)).GetTime)(this, ptimestart, ptimeend), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTime(this: *mut core::ffi::c_void, ptimestart: *const i64, ptimeend: *const i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSample", "SetTime", 6usize, |this| ((**(this as *const *const IMediaSample_Vtbl)).SetTime)(this, ptimestart, ptimeend), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSyncPoint(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSample", "IsSyncPoint", 7usize, |this| ((**(this as *const *const IMediaSample_Vtbl)).IsSyncPoint)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSyncPoint(this: *mut core::ffi::c_void, bissyncpoint: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSample", "SetSyncPoint", 8usize, |this| ((**(this as *const *const IMediaSample_Vtbl)).SetSyncPoint)(this, bissyncpoint), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPreroll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSample", "IsPreroll", 9usize, |this| ((**(this as *const *const IMediaSample_Vtbl)).IsPreroll)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreroll(this: *mut core::ffi::c_void, bispreroll: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSample", "SetPreroll", 10usize, |this| ((**(this as *const *const IMediaSample_Vtbl)).SetPreroll)(this, bispreroll), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActualDataLength(this: *mut core::ffi::c_void) -> i32 {
+            windows_core::imp::proxy_call(this, "IMediaSample", "GetActualDataLength", 11usize, |this| ((**(this as *const *const IMediaSample_Vtbl)).GetActualDataLength)(this), |_| None)
+        }
+        unsafe extern "system" fn SetActualDataLength(this: *mut core::ffi::c_void, __midl__imediasample0000: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSample", "SetActualDataLength", 12usize, |this| ((**(this as *const *const IMediaSample_Vtbl)).SetActualDataLength)(this, __midl__imediasample0000), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn GetMediaType(this: *mut core::ffi::c_void, ppmediatype: *mut *mut super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSample", "GetMediaType", 13usize, |this| ((**(this as *const *const IMediaSample_Vtbl)).GetMediaType)(this, ppmediatype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn SetMediaType(this: *mut core::ffi::c_void, pmediatype: *const super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSample", "SetMediaType", 14usize, |this| ((**(this as *const *const IMediaSample_Vtbl)).SetMediaType)(this, pmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDiscontinuity(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSample", "IsDiscontinuity", 15usize, |this| ((**(this as *const *const IMediaSample_Vtbl)).IsDiscontinuity)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDiscontinuity(this: *mut core::ffi::c_void, bdiscontinuity: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSample", "SetDiscontinuity", 16usize, |this| ((**(this as *const *const IMediaSample_Vtbl)).SetDiscontinuity)(this, bdiscontinuity), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMediaTime(this: *mut core::ffi::c_void, ptimestart: *mut i64, ptimeend: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSample", "GetMediaTime", 17usize, |this| ((**(this as *const *const IMediaSample_Vtbl)).GetMediaTime)(this, ptimestart, ptimeend), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMediaTime(this: *mut core::ffi::c_void, ptimestart: *const i64, ptimeend: *const i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSample", "SetMediaTime", 18usize, |this| ((**(this as *const *const IMediaSample_Vtbl)).SetMediaTime)(this, ptimestart, ptimeend), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPointer,
+            GetSize,
+            GetTime,
+            SetTime,
+            IsSyncPoint,
+            SetSyncPoint,
+            IsPreroll,
+            SetPreroll,
+            GetActualDataLength,
+            SetActualDataLength,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            GetMediaType,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            GetMediaType: 0,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            SetMediaType,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            SetMediaType: 0,
+            IsDiscontinuity,
+            SetDiscontinuity,
+            GetMediaTime,
+            SetMediaTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaSample as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_MediaFoundation")]
+impl windows_core::imp::ProxyVtable for IMediaSample2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, cbproperties: u32, pbproperties: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSample2", "GetProperties", 19usize, |this| ((**(this as *const *const IMediaSample2_Vtbl)).GetProperties)(this, cbproperties, pbproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperties(this: *mut core::ffi::c_void, cbproperties: u32, pbproperties: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSample2", "SetProperties", 20usize, |this| ((**(this as *const *const IMediaSample2_Vtbl)).SetProperties)(this, cbproperties, pbproperties), |result| Some(*result))
+        }
+        Self { base__: <IMediaSample_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProperties, SetProperties }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaSample2 as windows_core::Interface>::IID || <IMediaSample_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaSample2Config_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSurface(this: *mut core::ffi::c_void, ppdirect3dsurface9: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSample2Config", "GetSurface", 3usize, |this| ((**(this as *const *const IMediaSample2Config_Vtbl)).GetSurface)(this, ppdirect3dsurface9), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSurface }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaSample2Config as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaSeeking_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCapabilities(this: *mut core::ffi::c_void, pcapabilities: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSeeking", "GetCapabilities", 3usize, |this| ((**(this as *const *const IMediaSeeking_Vtbl)).GetCapabilities)(this, pcapabilities), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckCapabilities(this: *mut core::ffi::c_void, pcapabilities: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSeeking", "CheckCapabilities", 4usize, |this| ((**(this as *const *const IMediaSeeking_Vtbl)).CheckCapabilities)(this, pcapabilities), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFormatSupported(this: *mut core::ffi::c_void, pformat: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSeeking", "IsFormatSupported", 5usize, |this| ((**(this as *const *const IMediaSeeking_Vtbl)).IsFormatSupported)(this, pformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryPreferredFormat(this: *mut core::ffi::c_void, pformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSeeking", "QueryPreferredFormat", 6usize, |this| ((**(this as *const *const IMediaSeeking_Vtbl)).QueryPreferredFormat)(this, pformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTimeFormat(this: *mut core::ffi::c_void, pformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSeeking", "GetTimeFormat", 7usize, |this| ((**(this as *const *const IMediaSeeking_Vtbl)).GetTimeFormat)(this, pformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUsingTimeFormat(this: *mut core::ffi::c_void, pformat: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSeeking", "IsUsingTimeFormat", 8usize, |this| ((**(this as *const *const IMediaSeeking_Vtbl)).IsUsingTimeFormat)(this, pformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTimeFormat(this: *mut core::ffi::c_void, pformat: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSeeking", "SetTimeFormat", 9usize, |this| ((**(this as *const *const IMediaSeeking_Vtbl)).SetTimeFormat)(this, pformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDuration(this: *mut core::ffi::c_void, pduration: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSeeking", "GetDuration", 10usize, |this| ((**(this as *const *const IMediaSeeking_Vtbl)).GetDuration)(this, pduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStopPosition(this: *mut core::ffi::c_void, pstop: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSeeking", "GetStopPosition", 11usize, |this| ((**(this as *const *const IMediaSeeking_Vtbl)).GetStopPosition)(this, pstop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentPosition(this: *mut core::ffi::c_void, pcurrent: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSeeking", "GetCurrentPosition", 12usize, |this| ((**(this as *const *const IMediaSeeking_Vtbl)).GetCurrentPosition)(this, pcurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertTimeFormat(this: *mut core::ffi::c_void, ptarget: *mut i64, ptargetformat: *const windows_core::GUID, source: i64, psourceformat: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSeeking", "ConvertTimeFormat", 13usize, |this| ((**(this as *const *const IMediaSeeking_Vtbl)).ConvertTimeFormat)(this, ptarget, ptargetformat, source, psourceformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPositions(this: *mut core::ffi::c_void, pcurrent: *mut i64, dwcurrentflags: u32, pstop: *mut i64, dwstopflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSeeking", "SetPositions", 14usize, |this| ((**(this as *const *const IMediaSeeking_Vtbl)).SetPositions)(this, pcurrent, dwcurrentflags, pstop, dwstopflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPositions(this: *mut core::ffi::c_void, pcurrent: *mut i64, pstop: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSeeking", "GetPositions", 15usize, |this| ((**(this as *const *const IMediaSeeking_Vtbl)).GetPositions)(this, pcurrent, pstop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAvailable(this: *mut core::ffi::c_void, pearliest: *mut i64, platest: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSeeking", "GetAvailable", 16usize, |this| ((**(this as *const *const IMediaSeeking_Vtbl)).GetAvailable)(this, pearliest, platest), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRate(this: *mut core::ffi::c_void, drate: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSeeking", "SetRate", 17usize, |this| ((**(this as *const *const IMediaSeeking_Vtbl)).SetRate)(this, drate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRate(this: *mut core::ffi::c_void, pdrate: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSeeking", "GetRate", 18usize, |this| ((**(this as *const *const IMediaSeeking_Vtbl)).GetRate)(this, pdrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreroll(this: *mut core::ffi::c_void, pllpreroll: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaSeeking", "GetPreroll", 19usize, |this| ((**(this as *const *const IMediaSeeking_Vtbl)).GetPreroll)(this, pllpreroll), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCapabilities,
+            CheckCapabilities,
+            IsFormatSupported,
+            QueryPreferredFormat,
+            GetTimeFormat,
+            IsUsingTimeFormat,
+            SetTimeFormat,
+            GetDuration,
+            GetStopPosition,
+            GetCurrentPosition,
+            ConvertTimeFormat,
+            SetPositions,
+            GetPositions,
+            GetAvailable,
+            SetRate,
+            GetRate,
+            GetPreroll,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaSeeking as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMultiMediaStream(this: *mut core::ffi::c_void, ppmultimediastream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStream", "GetMultiMediaStream", 3usize, |this| ((**(this as *const *const IMediaStream_Vtbl)).GetMultiMediaStream)(this, ppmultimediastream), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInformation(this: *mut core::ffi::c_void, ppurposeid: *mut windows_core::GUID, ptype: *mut STREAM_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStream", "GetInformation", 4usize, |this| ((**(this as *const *const IMediaStream_Vtbl)).GetInformation)(this, ppurposeid, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSameFormat(this: *mut core::ffi::c_void, pstreamthathasdesiredformat: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStream", "SetSameFormat", 5usize, |this| ((**(this as *const *const IMediaStream_Vtbl)).SetSameFormat)(this, pstreamthathasdesiredformat, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllocateSample(this: *mut core::ffi::c_void, dwflags: u32, ppsample: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStream", "AllocateSample", 6usize, |this| ((**(this as *const *const IMediaStream_Vtbl)).AllocateSample)(this, dwflags, ppsample), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSharedSample(this: *mut core::ffi::c_void, pexistingsample: *mut core::ffi::c_void, dwflags: u32, ppnewsample: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStream", "CreateSharedSample", 7usize, |this| ((**(this as *const *const IMediaStream_Vtbl)).CreateSharedSample)(this, pexistingsample, dwflags, ppnewsample), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendEndOfStream(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStream", "SendEndOfStream", 8usize, |this| ((**(this as *const *const IMediaStream_Vtbl)).SendEndOfStream)(this, dwflags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetMultiMediaStream,
+            GetInformation,
+            SetSameFormat,
+            AllocateSample,
+            CreateSharedSample,
+            SendEndOfStream,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaStream as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMediaStreamFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddMediaStream(this: *mut core::ffi::c_void, pammediastream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamFilter", "AddMediaStream", 15usize, |this| ((**(this as *const *const IMediaStreamFilter_Vtbl)).AddMediaStream)(this, pammediastream), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMediaStream(this: *mut core::ffi::c_void, idpurpose: *const windows_core::GUID, ppmediastream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamFilter", "GetMediaStream", 16usize, |this| ((**(this as *const *const IMediaStreamFilter_Vtbl)).GetMediaStream)(this, idpurpose, ppmediastream), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumMediaStreams(this: *mut core::ffi::c_void, index: i32, ppmediastream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamFilter", "EnumMediaStreams", 17usize, |this| ((**(this as *const *const IMediaStreamFilter_Vtbl)).EnumMediaStreams)(this, index, ppmediastream), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportSeeking(this: *mut core::ffi::c_void, brenderer: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamFilter", "SupportSeeking", 18usize, |this| ((**(this as *const *const IMediaStreamFilter_Vtbl)).SupportSeeking)(this, brenderer), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReferenceTimeToStreamTime(this: *mut core::ffi::c_void, ptime: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamFilter", "ReferenceTimeToStreamTime", 19usize, |this| ((**(this as *const *const IMediaStreamFilter_Vtbl)).ReferenceTimeToStreamTime)(this, ptime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentStreamTime(this: *mut core::ffi::c_void, pcurrentstreamtime: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamFilter", "GetCurrentStreamTime", 20usize, |this| ((**(this as *const *const IMediaStreamFilter_Vtbl)).GetCurrentStreamTime)(this, pcurrentstreamtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitUntil(this: *mut core::ffi::c_void, waitstreamtime: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamFilter", "WaitUntil", 21usize, |this| ((**(this as *const *const IMediaStreamFilter_Vtbl)).WaitUntil)(this, waitstreamtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flush(this: *mut core::ffi::c_void, bcanceleos: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamFilter", "Flush", 22usize, |this| ((**(this as *const *const IMediaStreamFilter_Vtbl)).Flush)(this, bcanceleos), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndOfStream(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaStreamFilter", "EndOfStream", 23usize, |this| ((**(this as *const *const IMediaStreamFilter_Vtbl)).EndOfStream)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IBaseFilter_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddMediaStream,
+            GetMediaStream,
+            EnumMediaStreams,
+            SupportSeeking,
+            ReferenceTimeToStreamTime,
+            GetCurrentStreamTime,
+            WaitUntil,
+            Flush,
+            EndOfStream,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaStreamFilter as windows_core::Interface>::IID || <IBaseFilter_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMediaTypeInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, strtype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTypeInfo", "Type", 7usize, |this| ((**(this as *const *const IMediaTypeInfo_Vtbl)).Type)(this, strtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subtype(this: *mut core::ffi::c_void, strtype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaTypeInfo", "Subtype", 8usize, |this| ((**(this as *const *const IMediaTypeInfo_Vtbl)).Subtype)(this, strtype), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Type, Subtype }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaTypeInfo as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMemAllocator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetProperties(this: *mut core::ffi::c_void, prequest: *const ALLOCATOR_PROPERTIES, pactual: *mut ALLOCATOR_PROPERTIES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemAllocator", "SetProperties", 3usize, |this| ((**(this as *const *const IMemAllocator_Vtbl)).SetProperties)(this, prequest, pactual), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, pprops: *mut ALLOCATOR_PROPERTIES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemAllocator", "GetProperties", 4usize, |this| ((**(this as *const *const IMemAllocator_Vtbl)).GetProperties)(this, pprops), |result| Some(*result))
+        }
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemAllocator", "Commit", 5usize, |this| ((**(this as *const *const IMemAllocator_Vtbl)).Commit)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Decommit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemAllocator", "Decommit", 6usize, |this| ((**(this as *const *const IMemAllocator_Vtbl)).Decommit)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBuffer(this: *mut core::ffi::c_void, ppbuffer: *mut *mut core::ffi::c_void, pstarttime: *const i64, pendtime: *const i64, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemAllocator", "GetBuffer", 7usize, |this| ((**(this as *const *const IMemAllocator_Vtbl)).GetBuffer)(this, ppbuffer, pstarttime, pendtime, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseBuffer(this: *mut core::ffi::c_void, pbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemAllocator", "ReleaseBuffer", 8usize, |this| ((**(this as *const *const IMemAllocator_Vtbl)).ReleaseBuffer)(this, pbuffer), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetProperties,
+            GetProperties,
+            Commit,
+            Decommit,
+            GetBuffer,
+            ReleaseBuffer,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMemAllocator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMemAllocatorCallbackTemp_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetNotify(this: *mut core::ffi::c_void, pnotify: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemAllocatorCallbackTemp", "SetNotify", 9usize, |this| ((**(this as *const *const IMemAllocatorCallbackTemp_Vtbl)).SetNotify)(this, pnotify), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFreeCount(this: *mut core::ffi::c_void, plbuffersfree: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemAllocatorCallbackTemp", "GetFreeCount", 10usize, |this| ((**(this as *const *const IMemAllocatorCallbackTemp_Vtbl)).GetFreeCount)(this, plbuffersfree), |result| Some(*result))
+        }
+        Self { base__: <IMemAllocator_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetNotify, GetFreeCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMemAllocatorCallbackTemp as windows_core::Interface>::IID || <IMemAllocator_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMemAllocatorNotifyCallbackTemp_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NotifyRelease(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemAllocatorNotifyCallbackTemp", "NotifyRelease", 3usize, |this| ((**(this as *const *const IMemAllocatorNotifyCallbackTemp_Vtbl)).NotifyRelease)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NotifyRelease }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMemAllocatorNotifyCallbackTemp as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMemInputPin_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAllocator(this: *mut core::ffi::c_void, ppallocator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemInputPin", "GetAllocator", 3usize, |this| ((**(this as *const *const IMemInputPin_Vtbl)).GetAllocator)(this, ppallocator), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyAllocator(this: *mut core::ffi::c_void, pallocator: *mut core::ffi::c_void, breadonly: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemInputPin", "NotifyAllocator", 4usize, |this| ((**(this as *const *const IMemInputPin_Vtbl)).NotifyAllocator)(this, pallocator, breadonly), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllocatorRequirements(this: *mut core::ffi::c_void, pprops: *mut ALLOCATOR_PROPERTIES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemInputPin", "GetAllocatorRequirements", 5usize, |this| ((**(this as *const *const IMemInputPin_Vtbl)).GetAllocatorRequirements)(this, pprops), |result| Some(*result))
+        }
+        unsafe extern "system" fn Receive(this: *mut core::ffi::c_void, psample: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemInputPin", "Receive", 6usize, |this| ((**(this as *const *const IMemInputPin_Vtbl)).Receive)(this, psample), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReceiveMultiple(this: *mut core::ffi::c_void, psamples: *const *mut core::ffi::c_void, nsamples: i32, nsamplesprocessed: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemInputPin", "ReceiveMultiple", 7usize, |this| ((**(this as *const *const IMemInputPin_Vtbl)).ReceiveMultiple)(this, psamples, nsamples, nsamplesprocessed), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReceiveCanBlock(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemInputPin", "ReceiveCanBlock", 8usize, |this| ((**(this as *const *const IMemInputPin_Vtbl)).ReceiveCanBlock)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetAllocator,
+            NotifyAllocator,
+            GetAllocatorRequirements,
+            Receive,
+            ReceiveMultiple,
+            ReceiveCanBlock,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMemInputPin as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMemoryData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetBuffer(this: *mut core::ffi::c_void, cbsize: u32, pbdata: *const u8, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemoryData", "SetBuffer", 3usize, |this| ((**(this as *const *const IMemoryData_Vtbl)).SetBuffer)(this, cbsize, pbdata, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInfo(this: *mut core::ffi::c_void, pdwlength: *mut u32, ppbdata: *mut *mut u8, pcbactualdata: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemoryData", "GetInfo", 4usize, |this| ((**(this as *const *const IMemoryData_Vtbl)).GetInfo)(this, pdwlength, ppbdata, pcbactualdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActual(this: *mut core::ffi::c_void, cbdatavalid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemoryData", "SetActual", 5usize, |this| ((**(this as *const *const IMemoryData_Vtbl)).SetActual)(this, cbdatavalid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetBuffer, GetInfo, SetActual }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMemoryData as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IMixerOCX_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnDisplayChange(this: *mut core::ffi::c_void, ulbitsperpixel: u32, ulscreenwidth: u32, ulscreenheight: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMixerOCX", "OnDisplayChange", 3usize, |this| ((**(this as *const *const IMixerOCX_Vtbl)).OnDisplayChange)(this, ulbitsperpixel, ulscreenwidth, ulscreenheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAspectRatio(this: *mut core::ffi::c_void, pdwpictaspectratiox: *mut u32, pdwpictaspectratioy: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMixerOCX", "GetAspectRatio", 4usize, |this| ((**(this as *const *const IMixerOCX_Vtbl)).GetAspectRatio)(this, pdwpictaspectratiox, pdwpictaspectratioy), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoSize(this: *mut core::ffi::c_void, pdwvideowidth: *mut u32, pdwvideoheight: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMixerOCX", "GetVideoSize", 5usize, |this| ((**(this as *const *const IMixerOCX_Vtbl)).GetVideoSize)(this, pdwvideowidth, pdwvideoheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pdwstatus: *mut *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMixerOCX", "GetStatus", 6usize, |this| ((**(this as *const *const IMixerOCX_Vtbl)).GetStatus)(this, pdwstatus), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn OnDraw(this: *mut core::ffi::c_void, hdcdraw: super::super::Graphics::Gdi::HDC, prcdraw: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMixerOCX", "OnDraw", 7usize, |this| ((**(this as *const *const IMixerOCX_Vtbl)).OnDraw)(this, hdcdraw, prcdraw), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDrawRegion(this: *mut core::ffi::c_void, lppttopleftsc: *const super::super::Foundation::POINT, prcdrawcc: *const super::super::Foundation::RECT, lprcclip: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMixerOCX", "SetDrawRegion", 8usize, |this| ((**(this as *const *const IMixerOCX_Vtbl)).SetDrawRegion)(this, lppttopleftsc, prcdrawcc, lprcclip), |result| Some(*result))
+        }
+        unsafe extern "system" fn Advise(this: *mut core::ffi::c_void, pmdns: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMixerOCX", "Advise", 9usize, |this| ((**(this as *const *const IMixerOCX_Vtbl)).Advise)(this, pmdns), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnAdvise(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMixerOCX", "UnAdvise", 10usize, |this| ((**(this as *const *const IMixerOCX_Vtbl)).UnAdvise)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnDisplayChange,
+            GetAspectRatio,
+            GetVideoSize,
+            GetStatus,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            OnDraw,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            OnDraw: 0,
+            SetDrawRegion,
+            Advise,
+            UnAdvise,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMixerOCX as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMixerOCXNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnInvalidateRect(this: *mut core::ffi::c_void, lpcrect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMixerOCXNotify", "OnInvalidateRect", 3usize, |this| ((**(this as *const *const IMixerOCXNotify_Vtbl)).OnInvalidateRect)(this, lpcrect), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnStatusChange(this: *mut core::ffi::c_void, ulstatusflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMixerOCXNotify", "OnStatusChange", 4usize, |this| ((**(this as *const *const IMixerOCXNotify_Vtbl)).OnStatusChange)(this, ulstatusflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDataChange(this: *mut core::ffi::c_void, uldataflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMixerOCXNotify", "OnDataChange", 5usize, |this| ((**(this as *const *const IMixerOCXNotify_Vtbl)).OnDataChange)(this, uldataflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnInvalidateRect, OnStatusChange, OnDataChange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMixerOCXNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMixerPinConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetRelativePosition(this: *mut core::ffi::c_void, dwleft: u32, dwtop: u32, dwright: u32, dwbottom: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMixerPinConfig", "SetRelativePosition", 3usize, |this| ((**(this as *const *const IMixerPinConfig_Vtbl)).SetRelativePosition)(this, dwleft, dwtop, dwright, dwbottom), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRelativePosition(this: *mut core::ffi::c_void, pdwleft: *mut u32, pdwtop: *mut u32, pdwright: *mut u32, pdwbottom: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMixerPinConfig", "GetRelativePosition", 4usize, |this| ((**(this as *const *const IMixerPinConfig_Vtbl)).GetRelativePosition)(this, pdwleft, pdwtop, pdwright, pdwbottom), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetZOrder(this: *mut core::ffi::c_void, dwzorder: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMixerPinConfig", "SetZOrder", 5usize, |this| ((**(this as *const *const IMixerPinConfig_Vtbl)).SetZOrder)(this, dwzorder), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetZOrder(this: *mut core::ffi::c_void, pdwzorder: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMixerPinConfig", "GetZOrder", 6usize, |this| ((**(this as *const *const IMixerPinConfig_Vtbl)).GetZOrder)(this, pdwzorder), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColorKey(this: *mut core::ffi::c_void, pcolorkey: *mut COLORKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMixerPinConfig", "SetColorKey", 7usize, |this| ((**(this as *const *const IMixerPinConfig_Vtbl)).SetColorKey)(this, pcolorkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColorKey(this: *mut core::ffi::c_void, pcolorkey: *mut COLORKEY, pcolor: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMixerPinConfig", "GetColorKey", 8usize, |this| ((**(this as *const *const IMixerPinConfig_Vtbl)).GetColorKey)(this, pcolorkey, pcolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlendingParameter(this: *mut core::ffi::c_void, dwblendingparameter: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMixerPinConfig", "SetBlendingParameter", 9usize, |this| ((**(this as *const *const IMixerPinConfig_Vtbl)).SetBlendingParameter)(this, dwblendingparameter), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBlendingParameter(this: *mut core::ffi::c_void, pdwblendingparameter: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMixerPinConfig", "GetBlendingParameter", 10usize, |this| ((**(this as *const *const IMixerPinConfig_Vtbl)).GetBlendingParameter)(this, pdwblendingparameter), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAspectRatioMode(this: *mut core::ffi::c_void, amaspectratiomode: AM_ASPECT_RATIO_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMixerPinConfig", "SetAspectRatioMode", 11usize, |this| ((**(this as *const *const IMixerPinConfig_Vtbl)).SetAspectRatioMode)(this, amaspectratiomode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAspectRatioMode(this: *mut core::ffi::c_void, pamaspectratiomode: *mut AM_ASPECT_RATIO_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMixerPinConfig", "GetAspectRatioMode", 12usize, |this| ((**(this as *const *const IMixerPinConfig_Vtbl)).GetAspectRatioMode)(this, pamaspectratiomode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStreamTransparent(this: *mut core::ffi::c_void, bstreamtransparent: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMixerPinConfig", "SetStreamTransparent", 13usize, |this| ((**(this as *const *const IMixerPinConfig_Vtbl)).SetStreamTransparent)(this, bstreamtransparent), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamTransparent(this: *mut core::ffi::c_void, pbstreamtransparent: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMixerPinConfig", "GetStreamTransparent", 14usize, |this| ((**(this as *const *const IMixerPinConfig_Vtbl)).GetStreamTransparent)(this, pbstreamtransparent), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetRelativePosition,
+            GetRelativePosition,
+            SetZOrder,
+            GetZOrder,
+            SetColorKey,
+            GetColorKey,
+            SetBlendingParameter,
+            GetBlendingParameter,
+            SetAspectRatioMode,
+            GetAspectRatioMode,
+            SetStreamTransparent,
+            GetStreamTransparent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMixerPinConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_DirectDraw")]
+impl windows_core::imp::ProxyVtable for IMixerPinConfig2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn SetOverlaySurfaceColorControls(this: *mut core::ffi::c_void, pcolorcontrol: *mut super::super::Graphics::DirectDraw::DDCOLORCONTROL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMixerPinConfig2", "SetOverlaySurfaceColorControls", 15usize, |this| ((**(this as *const *const IMixerPinConfig2_Vtbl)).SetOverlaySurfaceColorControls)(this, pcolorcontrol), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn GetOverlaySurfaceColorControls(this: *mut core::ffi::c_void, pcolorcontrol: *mut super::super::Graphics::DirectDraw::DDCOLORCONTROL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMixerPinConfig2", "GetOverlaySurfaceColorControls", 16usize, |this| ((**(this as *const *const IMixerPinConfig2_Vtbl)).GetOverlaySurfaceColorControls)(this, pcolorcontrol), |result| Some(*result))
+        }
+        Self {
+            base__: <IMixerPinConfig_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            SetOverlaySurfaceColorControls,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            SetOverlaySurfaceColorControls: 0,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            GetOverlaySurfaceColorControls,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            GetOverlaySurfaceColorControls: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMixerPinConfig2 as windows_core::Interface>::IID || <IMixerPinConfig_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_MediaFoundation")]
+impl windows_core::imp::ProxyVtable for IMpeg2Demultiplexer_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn CreateOutputPin(this: *mut core::ffi::c_void, pmediatype: *const super::MediaFoundation::AM_MEDIA_TYPE, pszpinname: windows_core::PCWSTR, ppipin: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpeg2Demultiplexer", "CreateOutputPin", 3usize, |this| ((**(this as *const *const IMpeg2Demultiplexer_Vtbl)).CreateOutputPin)(this, pmediatype, pszpinname, ppipin), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn SetOutputPinMediaType(this: *mut core::ffi::c_void, pszpinname: windows_core::PCWSTR, pmediatype: *const super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpeg2Demultiplexer", "SetOutputPinMediaType", 4usize, |this| ((**(this as *const *const IMpeg2Demultiplexer_Vtbl)).SetOutputPinMediaType)(this, pszpinname, pmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteOutputPin(this: *mut core::ffi::c_void, pszpinname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpeg2Demultiplexer", "DeleteOutputPin", 5usize, |this| ((**(this as *const *const IMpeg2Demultiplexer_Vtbl)).DeleteOutputPin)(this, pszpinname), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            CreateOutputPin,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            CreateOutputPin: 0,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            SetOutputPinMediaType,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            SetOutputPinMediaType: 0,
+            DeleteOutputPin,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMpeg2Demultiplexer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_Audio")]
+impl windows_core::imp::ProxyVtable for IMpegAudioDecoder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FrequencyDivider(this: *mut core::ffi::c_void, pdivider: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpegAudioDecoder", "FrequencyDivider", 3usize, |this| ((**(this as *const *const IMpegAudioDecoder_Vtbl)).FrequencyDivider)(this, pdivider), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFrequencyDivider(this: *mut core::ffi::c_void, divider: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpegAudioDecoder", "SetFrequencyDivider", 4usize, |this| ((**(this as *const *const IMpegAudioDecoder_Vtbl)).SetFrequencyDivider)(this, divider), |result| Some(*result))
+        }
+        unsafe extern "system" fn DecoderAccuracy(this: *mut core::ffi::c_void, paccuracy: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpegAudioDecoder", "DecoderAccuracy", 5usize, |this| ((**(this as *const *const IMpegAudioDecoder_Vtbl)).DecoderAccuracy)(this, paccuracy), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDecoderAccuracy(this: *mut core::ffi::c_void, accuracy: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpegAudioDecoder", "SetDecoderAccuracy", 6usize, |this| ((**(this as *const *const IMpegAudioDecoder_Vtbl)).SetDecoderAccuracy)(this, accuracy), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stereo(this: *mut core::ffi::c_void, pstereo: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpegAudioDecoder", "Stereo", 7usize, |this| ((**(this as *const *const IMpegAudioDecoder_Vtbl)).Stereo)(this, pstereo), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStereo(this: *mut core::ffi::c_void, stereo: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpegAudioDecoder", "SetStereo", 8usize, |this| ((**(this as *const *const IMpegAudioDecoder_Vtbl)).SetStereo)(this, stereo), |result| Some(*result))
+        }
+        unsafe extern "system" fn DecoderWordSize(this: *mut core::ffi::c_void, pwordsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpegAudioDecoder", "DecoderWordSize", 9usize, |this| ((**(this as *const *const IMpegAudioDecoder_Vtbl)).DecoderWordSize)(this, pwordsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDecoderWordSize(this: *mut core::ffi::c_void, wordsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpegAudioDecoder", "SetDecoderWordSize", 10usize, |this| ((**(this as *const *const IMpegAudioDecoder_Vtbl)).SetDecoderWordSize)(this, wordsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn IntegerDecode(this: *mut core::ffi::c_void, pintdecode: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpegAudioDecoder", "IntegerDecode", 11usize, |this| ((**(this as *const *const IMpegAudioDecoder_Vtbl)).IntegerDecode)(this, pintdecode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIntegerDecode(this: *mut core::ffi::c_void, intdecode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpegAudioDecoder", "SetIntegerDecode", 12usize, |this| ((**(this as *const *const IMpegAudioDecoder_Vtbl)).SetIntegerDecode)(this, intdecode), |result| Some(*result))
+        }
+        unsafe extern "system" fn DualMode(this: *mut core::ffi::c_void, pintdecode: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpegAudioDecoder", "DualMode", 13usize, |this| ((**(this as *const *const IMpegAudioDecoder_Vtbl)).DualMode)(this, pintdecode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDualMode(this: *mut core::ffi::c_void, intdecode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpegAudioDecoder", "SetDualMode", 14usize, |this| ((**(this as *const *const IMpegAudioDecoder_Vtbl)).SetDualMode)(this, intdecode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn AudioFormat(this: *mut core::ffi::c_void, lpfmt: *mut MPEG1WAVEFORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMpegAudioDecoder", "AudioFormat", 15usize, |this| ((**(this as *const *const IMpegAudioDecoder_Vtbl)).AudioFormat)(this, lpfmt), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FrequencyDivider,
+            SetFrequencyDivider,
+            DecoderAccuracy,
+            SetDecoderAccuracy,
+            Stereo,
+            SetStereo,
+            DecoderWordSize,
+            SetDecoderWordSize,
+            IntegerDecode,
+            SetIntegerDecode,
+            DualMode,
+            SetDualMode,
+            #[cfg(feature = "Win32_Media_Audio")]
+            AudioFormat,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            AudioFormat: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMpegAudioDecoder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMultiMediaStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInformation(this: *mut core::ffi::c_void, pdwflags: *mut MMSSF_GET_INFORMATION_FLAGS, pstreamtype: *mut STREAM_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiMediaStream", "GetInformation", 3usize, |this| ((**(this as *const *const IMultiMediaStream_Vtbl)).GetInformation)(this, pdwflags, pstreamtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMediaStream(this: *mut core::ffi::c_void, idpurpose: *const windows_core::GUID, ppmediastream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiMediaStream", "GetMediaStream", 4usize, |this| ((**(this as *const *const IMultiMediaStream_Vtbl)).GetMediaStream)(this, idpurpose, ppmediastream), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumMediaStreams(this: *mut core::ffi::c_void, index: i32, ppmediastream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiMediaStream", "EnumMediaStreams", 5usize, |this| ((**(this as *const *const IMultiMediaStream_Vtbl)).EnumMediaStreams)(this, index, ppmediastream), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetState(this: *mut core::ffi::c_void, pcurrentstate: *mut STREAM_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiMediaStream", "GetState", 6usize, |this| ((**(this as *const *const IMultiMediaStream_Vtbl)).GetState)(this, pcurrentstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetState(this: *mut core::ffi::c_void, newstate: STREAM_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiMediaStream", "SetState", 7usize, |this| ((**(this as *const *const IMultiMediaStream_Vtbl)).SetState)(this, newstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTime(this: *mut core::ffi::c_void, pcurrenttime: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiMediaStream", "GetTime", 8usize, |this| ((**(this as *const *const IMultiMediaStream_Vtbl)).GetTime)(this, pcurrenttime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDuration(this: *mut core::ffi::c_void, pduration: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiMediaStream", "GetDuration", 9usize, |this| ((**(this as *const *const IMultiMediaStream_Vtbl)).GetDuration)(this, pduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn Seek(this: *mut core::ffi::c_void, seektime: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiMediaStream", "Seek", 10usize, |this| ((**(this as *const *const IMultiMediaStream_Vtbl)).Seek)(this, seektime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEndOfStreamEventHandle(this: *mut core::ffi::c_void, pheos: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiMediaStream", "GetEndOfStreamEventHandle", 11usize, |this| ((**(this as *const *const IMultiMediaStream_Vtbl)).GetEndOfStreamEventHandle)(this, pheos), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetInformation,
+            GetMediaStream,
+            EnumMediaStreams,
+            GetState,
+            SetState,
+            GetTime,
+            GetDuration,
+            Seek,
+            GetEndOfStreamEventHandle,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMultiMediaStream as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IOverlay_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetPalette(this: *mut core::ffi::c_void, pdwcolors: *mut u32, pppalette: *mut *mut super::super::Graphics::Gdi::PALETTEENTRY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOverlay", "GetPalette", 3usize, |this| ((**(this as *const *const IOverlay_Vtbl)).GetPalette)(this, pdwcolors, pppalette), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn SetPalette(this: *mut core::ffi::c_void, dwcolors: u32, ppalette: *const super::super::Graphics::Gdi::PALETTEENTRY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOverlay", "SetPalette", 4usize, |this| ((**(this as *const *const IOverlay_Vtbl)).SetPalette)(this, dwcolors, ppalette), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultColorKey(this: *mut core::ffi::c_void, pcolorkey: *mut COLORKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOverlay", "GetDefaultColorKey", 5usize, |this| ((**(this as *const *const IOverlay_Vtbl)).GetDefaultColorKey)(this, pcolorkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColorKey(this: *mut core::ffi::c_void, pcolorkey: *mut COLORKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOverlay", "GetColorKey", 6usize, |this| ((**(this as *const *const IOverlay_Vtbl)).GetColorKey)(this, pcolorkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColorKey(this: *mut core::ffi::c_void, pcolorkey: *mut COLORKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOverlay", "SetColorKey", 7usize, |this| ((**(this as *const *const IOverlay_Vtbl)).SetColorKey)(this, pcolorkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWindowHandle(this: *mut core::ffi::c_void, phwnd: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOverlay", "GetWindowHandle", 8usize, |this| ((**(this as *const *const IOverlay_Vtbl)).GetWindowHandle)(this, phwnd), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetClipList(this: *mut core::ffi::c_void, psourcerect: *mut super::super::Foundation::RECT, pdestinationrect: *mut super::super::Foundation::RECT, pprgndata: *mut *mut super::super::Graphics::Gdi::RGNDATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOverlay", "GetClipList", 9usize, |this| ((**(this as *const *const IOverlay_Vtbl)).GetClipList)(this, psourcerect, pdestinationrect, pprgndata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoPosition(this: *mut core::ffi::c_void, psourcerect: *mut super::super::Foundation::RECT, pdestinationrect: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOverlay", "GetVideoPosition", 10usize, |this| ((**(this as *const *const IOverlay_Vtbl)).GetVideoPosition)(this, psourcerect, pdestinationrect), |result| Some(*result))
+        }
+        unsafe extern "system" fn Advise(this: *mut core::ffi::c_void, poverlaynotify: *mut core::ffi::c_void, dwinterests: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOverlay", "Advise", 11usize, |this| ((**(this as *const *const IOverlay_Vtbl)).Advise)(this, poverlaynotify, dwinterests), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unadvise(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOverlay", "Unadvise", 12usize, |this| ((**(this as *const *const IOverlay_Vtbl)).Unadvise)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetPalette,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetPalette: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            SetPalette,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            SetPalette: 0,
+            GetDefaultColorKey,
+            GetColorKey,
+            SetColorKey,
+            GetWindowHandle,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetClipList,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetClipList: 0,
+            GetVideoPosition,
+            Advise,
+            Unadvise,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOverlay as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IOverlayNotify_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn OnPaletteChange(this: *mut core::ffi::c_void, dwcolors: u32, ppalette: *const super::super::Graphics::Gdi::PALETTEENTRY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOverlayNotify", "OnPaletteChange", 3usize, |this| ((**(this as *const *const IOverlayNotify_Vtbl)).OnPaletteChange)(this, dwcolors, ppalette), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn OnClipChange(this: *mut core::ffi::c_void, psourcerect: *const super::super::Foundation::RECT, pdestinationrect: *const super::super::Foundation::RECT, prgndata: *const super::super::Graphics::Gdi::RGNDATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOverlayNotify", "OnClipChange", 4usize, |this| ((**(this as *const *const IOverlayNotify_Vtbl)).OnClipChange)(this, psourcerect, pdestinationrect, prgndata), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnColorKeyChange(this: *mut core::ffi::c_void, pcolorkey: *const COLORKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOverlayNotify", "OnColorKeyChange", 5usize, |this| ((**(this as *const *const IOverlayNotify_Vtbl)).OnColorKeyChange)(this, pcolorkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnPositionChange(this: *mut core::ffi::c_void, psourcerect: *const super::super::Foundation::RECT, pdestinationrect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOverlayNotify", "OnPositionChange", 6usize, |this| ((**(this as *const *const IOverlayNotify_Vtbl)).OnPositionChange)(this, psourcerect, pdestinationrect), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            OnPaletteChange,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            OnPaletteChange: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            OnClipChange,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            OnClipChange: 0,
+            OnColorKeyChange,
+            OnPositionChange,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOverlayNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IOverlayNotify2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn OnDisplayChange(this: *mut core::ffi::c_void, hmonitor: super::super::Graphics::Gdi::HMONITOR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOverlayNotify2", "OnDisplayChange", 7usize, |this| ((**(this as *const *const IOverlayNotify2_Vtbl)).OnDisplayChange)(this, hmonitor), |result| Some(*result))
+        }
+        Self {
+            base__: <IOverlayNotify_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            OnDisplayChange,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            OnDisplayChange: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOverlayNotify2 as windows_core::Interface>::IID || <IOverlayNotify_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for IPersistMediaPropertyBag_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitNew(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistMediaPropertyBag", "InitNew", 4usize, |this| ((**(this as *const *const IPersistMediaPropertyBag_Vtbl)).InitNew)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn Load(this: *mut core::ffi::c_void, ppropbag: *mut core::ffi::c_void, perrorlog: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistMediaPropertyBag", "Load", 5usize, |this| ((**(this as *const *const IPersistMediaPropertyBag_Vtbl)).Load)(this, ppropbag, perrorlog), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void, ppropbag: *mut core::ffi::c_void, fcleardirty: super::super::Foundation::BOOL, fsaveallproperties: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistMediaPropertyBag", "Save", 6usize, |this| ((**(this as *const *const IPersistMediaPropertyBag_Vtbl)).Save)(this, ppropbag, fcleardirty, fsaveallproperties), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IPersist_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitNew,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            Load,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            Load: 0,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            Save,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            Save: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPersistMediaPropertyBag as windows_core::Interface>::IID || <super::super::System::Com::IPersist_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Media_MediaFoundation", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IPin_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn Connect(this: *mut core::ffi::c_void, preceivepin: *mut core::ffi::c_void, pmt: *const super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPin", "Connect", 3usize, |this| ((**(this as *const *const IPin_Vtbl)).Connect)(this, preceivepin, pmt), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn ReceiveConnection(this: *mut core::ffi::c_void, pconnector: *mut core::ffi::c_void, pmt: *const super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPin", "ReceiveConnection", 4usize, |this| ((**(this as *const *const IPin_Vtbl)).ReceiveConnection)(this, pconnector, pmt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPin", "Disconnect", 5usize, |this| ((**(this as *const *const IPin_Vtbl)).Disconnect)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectedTo(this: *mut core::ffi::c_void, ppin: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPin", "ConnectedTo", 6usize, |this| ((**(this as *const *const IPin_Vtbl)).ConnectedTo)(this, ppin), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn ConnectionMediaType(this: *mut core::ffi::c_void, pmt: *mut super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPin", "ConnectionMediaType", 7usize, |this| ((**(this as *const *const IPin_Vtbl)).ConnectionMediaType)(this, pmt), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn QueryPinInfo(this: *mut core::ffi::c_void, pinfo: *mut PIN_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPin", "QueryPinInfo", 8usize, |this| ((**(this as *const *const IPin_Vtbl)).QueryPinInfo)(this, pinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryDirection(this: *mut core::ffi::c_void, ppindir: *mut PIN_DIRECTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPin", "QueryDirection", 9usize, |this| ((**(this as *const *const IPin_Vtbl)).QueryDirection)(this, ppindir), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryId(this: *mut core::ffi::c_void, id: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPin", "QueryId", 10usize, |this| ((**(this as *const *const IPin_Vtbl)).QueryId)(this, id), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn QueryAccept(this: *mut core::ffi::c_void, pmt: *const super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPin", "QueryAccept", 11usize, |this| ((**(this as *const *const IPin_Vtbl)).QueryAccept)(this, pmt), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumMediaTypes(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPin", "EnumMediaTypes", 12usize, |this| ((**(this as *const *const IPin_Vtbl)).EnumMediaTypes)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryInternalConnections(this: *mut core::ffi::c_void, appin: *mut *mut core::ffi::c_void, npin: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPin", "QueryInternalConnections", 13usize, |this| ((**(this as *const *const IPin_Vtbl)).QueryInternalConnections)(this, appin, npin), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndOfStream(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPin", "EndOfStream", 14usize, |this| ((**(this as *const *const IPin_Vtbl)).EndOfStream)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginFlush(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPin", "BeginFlush", 15usize, |this| ((**(this as *const *const IPin_Vtbl)).BeginFlush)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndFlush(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPin", "EndFlush", 16usize, |this| ((**(this as *const *const IPin_Vtbl)).EndFlush)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn NewSegment(this: *mut core::ffi::c_void, tstart: i64, tstop: i64, drate: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPin", "NewSegment", 17usize, |this| ((**(this as *const *const IPin_Vtbl)).NewSegment)(this, tstart, tstop, drate), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            Connect,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            Connect: 0,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            ReceiveConnection,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            ReceiveConnection: 0,
+            Disconnect,
+            ConnectedTo,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            ConnectionMediaType,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            ConnectionMediaType: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            QueryPinInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            QueryPinInfo: 0,
+            QueryDirection,
+            QueryId,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            QueryAccept,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            QueryAccept: 0,
+            EnumMediaTypes,
+            QueryInternalConnections,
+            EndOfStream,
+            BeginFlush,
+            EndFlush,
+            NewSegment,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPin as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_MediaFoundation")]
+impl windows_core::imp::ProxyVtable for IPinConnection_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn DynamicQueryAccept(this: *mut core::ffi::c_void, pmt: *const super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinConnection", "DynamicQueryAccept", 3usize, |this| ((**(this as *const *const IPinConnection_Vtbl)).DynamicQueryAccept)(this, pmt), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyEndOfStream(this: *mut core::ffi::c_void, hnotifyevent: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinConnection", "NotifyEndOfStream", 4usize, |this| ((**(this as *const *const IPinConnection_Vtbl)).NotifyEndOfStream)(this, hnotifyevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEndPin(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinConnection", "IsEndPin", 5usize, |this| ((**(this as *const *const IPinConnection_Vtbl)).IsEndPin)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DynamicDisconnect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinConnection", "DynamicDisconnect", 6usize, |this| ((**(this as *const *const IPinConnection_Vtbl)).DynamicDisconnect)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            DynamicQueryAccept,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            DynamicQueryAccept: 0,
+            NotifyEndOfStream,
+            IsEndPin,
+            DynamicDisconnect,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPinConnection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPinFlowControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Block(this: *mut core::ffi::c_void, dwblockflags: u32, hevent: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinFlowControl", "Block", 3usize, |this| ((**(this as *const *const IPinFlowControl_Vtbl)).Block)(this, dwblockflags, hevent), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Block }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPinFlowControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPinInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Pin(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinInfo", "Pin", 7usize, |this| ((**(this as *const *const IPinInfo_Vtbl)).Pin)(this, ppunk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ConnectedTo(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinInfo", "ConnectedTo", 8usize, |this| ((**(this as *const *const IPinInfo_Vtbl)).ConnectedTo)(this, ppunk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ConnectionMediaType(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinInfo", "ConnectionMediaType", 9usize, |this| ((**(this as *const *const IPinInfo_Vtbl)).ConnectionMediaType)(this, ppunk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn FilterInfo(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinInfo", "FilterInfo", 10usize, |this| ((**(this as *const *const IPinInfo_Vtbl)).FilterInfo)(this, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, ppunk: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinInfo", "Name", 11usize, |this| ((**(this as *const *const IPinInfo_Vtbl)).Name)(this, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn Direction(this: *mut core::ffi::c_void, ppdirection: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinInfo", "Direction", 12usize, |this| ((**(this as *const *const IPinInfo_Vtbl)).Direction)(this, ppdirection), |result| Some(*result))
+        }
+        unsafe extern "system" fn PinID(this: *mut core::ffi::c_void, strpinid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinInfo", "PinID", 13usize, |this| ((**(this as *const *const IPinInfo_Vtbl)).PinID)(this, strpinid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn MediaTypes(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinInfo", "MediaTypes", 14usize, |this| ((**(this as *const *const IPinInfo_Vtbl)).MediaTypes)(this, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn Connect(this: *mut core::ffi::c_void, ppin: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinInfo", "Connect", 15usize, |this| ((**(this as *const *const IPinInfo_Vtbl)).Connect)(this, ppin), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectDirect(this: *mut core::ffi::c_void, ppin: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinInfo", "ConnectDirect", 16usize, |this| ((**(this as *const *const IPinInfo_Vtbl)).ConnectDirect)(this, ppin), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ConnectWithType(this: *mut core::ffi::c_void, ppin: *mut core::ffi::c_void, pmediatype: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinInfo", "ConnectWithType", 17usize, |this| ((**(this as *const *const IPinInfo_Vtbl)).ConnectWithType)(this, ppin, pmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinInfo", "Disconnect", 18usize, |this| ((**(this as *const *const IPinInfo_Vtbl)).Disconnect)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Render(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPinInfo", "Render", 19usize, |this| ((**(this as *const *const IPinInfo_Vtbl)).Render)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Pin,
+            #[cfg(feature = "Win32_System_Com")]
+            ConnectedTo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ConnectedTo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ConnectionMediaType,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ConnectionMediaType: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            FilterInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            FilterInfo: 0,
+            Name,
+            Direction,
+            PinID,
+            #[cfg(feature = "Win32_System_Com")]
+            MediaTypes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            MediaTypes: 0,
+            Connect,
+            ConnectDirect,
+            #[cfg(feature = "Win32_System_Com")]
+            ConnectWithType,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ConnectWithType: 0,
+            Disconnect,
+            Render,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPinInfo as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IQualProp_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FramesDroppedInRenderer(this: *mut core::ffi::c_void, pcframes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQualProp", "FramesDroppedInRenderer", 3usize, |this| ((**(this as *const *const IQualProp_Vtbl)).FramesDroppedInRenderer)(this, pcframes), |result| Some(*result))
+        }
+        unsafe extern "system" fn FramesDrawn(this: *mut core::ffi::c_void, pcframesdrawn: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQualProp", "FramesDrawn", 4usize, |this| ((**(this as *const *const IQualProp_Vtbl)).FramesDrawn)(this, pcframesdrawn), |result| Some(*result))
+        }
+        unsafe extern "system" fn AvgFrameRate(this: *mut core::ffi::c_void, piavgframerate: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQualProp", "AvgFrameRate", 5usize, |this| ((**(this as *const *const IQualProp_Vtbl)).AvgFrameRate)(this, piavgframerate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Jitter(this: *mut core::ffi::c_void, ijitter: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQualProp", "Jitter", 6usize, |this| ((**(this as *const *const IQualProp_Vtbl)).Jitter)(this, ijitter), |result| Some(*result))
+        }
+        unsafe extern "system" fn AvgSyncOffset(this: *mut core::ffi::c_void, piavg: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQualProp", "AvgSyncOffset", 7usize, |this| ((**(this as *const *const IQualProp_Vtbl)).AvgSyncOffset)(this, piavg), |result| Some(*result))
+        }
+        unsafe extern "system" fn DevSyncOffset(this: *mut core::ffi::c_void, pidev: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQualProp", "DevSyncOffset", 8usize, |this| ((**(this as *const *const IQualProp_Vtbl)).DevSyncOffset)(this, pidev), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FramesDroppedInRenderer,
+            FramesDrawn,
+            AvgFrameRate,
+            Jitter,
+            AvgSyncOffset,
+            DevSyncOffset,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IQualProp as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IQualityControl_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Notify(this: *mut core::ffi::c_void, pself: *mut core::ffi::c_void, q: Quality) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQualityControl", "Notify", 3usize, |this| ((**(this as *const *const IQualityControl_Vtbl)).Notify)(this, pself, q), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSink(this: *mut core::ffi::c_void, piqc: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQualityControl", "SetSink", 4usize, |this| ((**(this as *const *const IQualityControl_Vtbl)).SetSink)(this, piqc), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Notify,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Notify: 0,
+            SetSink,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IQualityControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IQueueCommand_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InvokeAtStreamTime(this: *mut core::ffi::c_void, pcmd: *mut *mut core::ffi::c_void, time: f64, iid: *const windows_core::GUID, dispidmethod: i32, wflags: i16, cargs: i32, pdispparams: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvarresult: *mut core::mem::MaybeUninit<windows_core::VARIANT>, puargerr: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueueCommand", "InvokeAtStreamTime", 3usize, |this| ((**(this as *const *const IQueueCommand_Vtbl)).InvokeAtStreamTime)(this, pcmd, time, iid, dispidmethod, wflags, cargs, pdispparams, pvarresult, puargerr), |result| Some(*result))
+        }
+        unsafe extern "system" fn InvokeAtPresentationTime(this: *mut core::ffi::c_void, pcmd: *mut *mut core::ffi::c_void, time: f64, iid: *const windows_core::GUID, dispidmethod: i32, wflags: i16, cargs: i32, pdispparams: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvarresult: *mut core::mem::MaybeUninit<windows_core::VARIANT>, puargerr: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueueCommand", "InvokeAtPresentationTime", 4usize, |this| ((**(this as *const *const IQueueCommand_Vtbl)).InvokeAtPresentationTime)(this, pcmd, time, iid, dispidmethod, wflags, cargs, pdispparams, pvarresult, puargerr), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InvokeAtStreamTime, InvokeAtPresentationTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IQueueCommand as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRegFilterInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, strname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegFilterInfo", "Name", 7usize, |this| ((**(this as *const *const IRegFilterInfo_Vtbl)).Name)(this, strname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Filter(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegFilterInfo", "Filter", 8usize, |this| ((**(this as *const *const IRegFilterInfo_Vtbl)).Filter)(this, ppunk), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            #[cfg(feature = "Win32_System_Com")]
+            Filter,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Filter: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRegFilterInfo as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRegisterServiceProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterService(this: *mut core::ffi::c_void, guidservice: *const windows_core::GUID, punkobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterServiceProvider", "RegisterService", 3usize, |this| ((**(this as *const *const IRegisterServiceProvider_Vtbl)).RegisterService)(this, guidservice, punkobject), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterService }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRegisterServiceProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IResourceConsumer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AcquireResource(this: *mut core::ffi::c_void, idresource: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceConsumer", "AcquireResource", 3usize, |this| ((**(this as *const *const IResourceConsumer_Vtbl)).AcquireResource)(this, idresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseResource(this: *mut core::ffi::c_void, idresource: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceConsumer", "ReleaseResource", 4usize, |this| ((**(this as *const *const IResourceConsumer_Vtbl)).ReleaseResource)(this, idresource), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AcquireResource, ReleaseResource }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceConsumer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IResourceManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Register(this: *mut core::ffi::c_void, pname: windows_core::PCWSTR, cresource: i32, pltoken: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceManager", "Register", 3usize, |this| ((**(this as *const *const IResourceManager_Vtbl)).Register)(this, pname, cresource, pltoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterGroup(this: *mut core::ffi::c_void, pname: windows_core::PCWSTR, cresource: i32, paltokens: *const i32, pltoken: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceManager", "RegisterGroup", 4usize, |this| ((**(this as *const *const IResourceManager_Vtbl)).RegisterGroup)(this, pname, cresource, paltokens, pltoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestResource(this: *mut core::ffi::c_void, idresource: i32, pfocusobject: *mut core::ffi::c_void, pconsumer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceManager", "RequestResource", 5usize, |this| ((**(this as *const *const IResourceManager_Vtbl)).RequestResource)(this, idresource, pfocusobject, pconsumer), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyAcquire(this: *mut core::ffi::c_void, idresource: i32, pconsumer: *mut core::ffi::c_void, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceManager", "NotifyAcquire", 6usize, |this| ((**(this as *const *const IResourceManager_Vtbl)).NotifyAcquire)(this, idresource, pconsumer, hr), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyRelease(this: *mut core::ffi::c_void, idresource: i32, pconsumer: *mut core::ffi::c_void, bstillwant: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceManager", "NotifyRelease", 7usize, |this| ((**(this as *const *const IResourceManager_Vtbl)).NotifyRelease)(this, idresource, pconsumer, bstillwant), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelRequest(this: *mut core::ffi::c_void, idresource: i32, pconsumer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceManager", "CancelRequest", 8usize, |this| ((**(this as *const *const IResourceManager_Vtbl)).CancelRequest)(this, idresource, pconsumer), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFocus(this: *mut core::ffi::c_void, pfocusobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceManager", "SetFocus", 9usize, |this| ((**(this as *const *const IResourceManager_Vtbl)).SetFocus)(this, pfocusobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseFocus(this: *mut core::ffi::c_void, pfocusobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceManager", "ReleaseFocus", 10usize, |this| ((**(this as *const *const IResourceManager_Vtbl)).ReleaseFocus)(this, pfocusobject), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Register,
+            RegisterGroup,
+            RequestResource,
+            NotifyAcquire,
+            NotifyRelease,
+            CancelRequest,
+            SetFocus,
+            ReleaseFocus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISeekingPassThru_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Init(this: *mut core::ffi::c_void, bsupportrendering: super::super::Foundation::BOOL, ppin: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISeekingPassThru", "Init", 3usize, |this| ((**(this as *const *const ISeekingPassThru_Vtbl)).Init)(this, bsupportrendering, ppin), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Init }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISeekingPassThru as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISelector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NumSources(this: *mut core::ffi::c_void, pdwnumsources: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISelector", "NumSources", 3usize, |this| ((**(this as *const *const ISelector_Vtbl)).NumSources)(this, pdwnumsources), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceNodeId(this: *mut core::ffi::c_void, pdwpinid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISelector", "SourceNodeId", 4usize, |this| ((**(this as *const *const ISelector_Vtbl)).SourceNodeId)(this, pdwpinid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSourceNodeId(this: *mut core::ffi::c_void, dwpinid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISelector", "SetSourceNodeId", 5usize, |this| ((**(this as *const *const ISelector_Vtbl)).SetSourceNodeId)(this, dwpinid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NumSources, SourceNodeId, SetSourceNodeId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISelector as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Ole")]
+impl windows_core::imp::ProxyVtable for ISpecifyParticularPages_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn GetPages(this: *mut core::ffi::c_void, guidwhatpages: *const windows_core::GUID, ppages: *mut super::super::System::Ole::CAUUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpecifyParticularPages", "GetPages", 3usize, |this| ((**(this as *const *const ISpecifyParticularPages_Vtbl)).GetPages)(this, guidwhatpages, ppages), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Ole")]
+            GetPages,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            GetPages: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpecifyParticularPages as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStreamBuilder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Render(this: *mut core::ffi::c_void, ppinout: *mut core::ffi::c_void, pgraph: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBuilder", "Render", 3usize, |this| ((**(this as *const *const IStreamBuilder_Vtbl)).Render)(this, ppinout, pgraph), |result| Some(*result))
+        }
+        unsafe extern "system" fn Backout(this: *mut core::ffi::c_void, ppinout: *mut core::ffi::c_void, pgraph: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamBuilder", "Backout", 4usize, |this| ((**(this as *const *const IStreamBuilder_Vtbl)).Backout)(this, ppinout, pgraph), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Render, Backout }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamBuilder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStreamSample_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMediaStream(this: *mut core::ffi::c_void, ppmediastream: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSample", "GetMediaStream", 3usize, |this| ((**(this as *const *const IStreamSample_Vtbl)).GetMediaStream)(this, ppmediastream), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSampleTimes(this: *mut core::ffi::c_void, pstarttime: *mut i64, pendtime: *mut i64, pcurrenttime: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSample", "GetSampleTimes", 4usize, |this| ((**(this as *const *const IStreamSample_Vtbl)).GetSampleTimes)(this, pstarttime, pendtime, pcurrenttime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSampleTimes(this: *mut core::ffi::c_void, pstarttime: *const i64, pendtime: *const i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSample", "SetSampleTimes", 5usize, |this| ((**(this as *const *const IStreamSample_Vtbl)).SetSampleTimes)(this, pstarttime, pendtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void, dwflags: u32, hevent: super::super::Foundation::HANDLE, pfnapc: super::super::Foundation::PAPCFUNC, dwapcdata: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSample", "Update", 6usize, |this| ((**(this as *const *const IStreamSample_Vtbl)).Update)(this, dwflags, hevent, pfnapc, dwapcdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompletionStatus(this: *mut core::ffi::c_void, dwflags: u32, dwmilliseconds: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamSample", "CompletionStatus", 7usize, |this| ((**(this as *const *const IStreamSample_Vtbl)).CompletionStatus)(this, dwflags, dwmilliseconds), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetMediaStream,
+            GetSampleTimes,
+            SetSampleTimes,
+            Update,
+            CompletionStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamSample as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVMRAspectRatioControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAspectRatioMode(this: *mut core::ffi::c_void, lpdwarmode: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRAspectRatioControl", "GetAspectRatioMode", 3usize, |this| ((**(this as *const *const IVMRAspectRatioControl_Vtbl)).GetAspectRatioMode)(this, lpdwarmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAspectRatioMode(this: *mut core::ffi::c_void, dwarmode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRAspectRatioControl", "SetAspectRatioMode", 4usize, |this| ((**(this as *const *const IVMRAspectRatioControl_Vtbl)).SetAspectRatioMode)(this, dwarmode), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAspectRatioMode, SetAspectRatioMode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRAspectRatioControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVMRAspectRatioControl9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAspectRatioMode(this: *mut core::ffi::c_void, lpdwarmode: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRAspectRatioControl9", "GetAspectRatioMode", 3usize, |this| ((**(this as *const *const IVMRAspectRatioControl9_Vtbl)).GetAspectRatioMode)(this, lpdwarmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAspectRatioMode(this: *mut core::ffi::c_void, dwarmode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRAspectRatioControl9", "SetAspectRatioMode", 4usize, |this| ((**(this as *const *const IVMRAspectRatioControl9_Vtbl)).SetAspectRatioMode)(this, dwarmode), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAspectRatioMode, SetAspectRatioMode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRAspectRatioControl9 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVMRDeinterlaceControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNumberOfDeinterlaceModes(this: *mut core::ffi::c_void, lpvideodescription: *const VMRVideoDesc, lpdwnumdeinterlacemodes: *mut u32, lpdeinterlacemodes: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRDeinterlaceControl", "GetNumberOfDeinterlaceModes", 3usize, |this| ((**(this as *const *const IVMRDeinterlaceControl_Vtbl)).GetNumberOfDeinterlaceModes)(this, lpvideodescription, lpdwnumdeinterlacemodes, lpdeinterlacemodes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeinterlaceModeCaps(this: *mut core::ffi::c_void, lpdeinterlacemode: *const windows_core::GUID, lpvideodescription: *const VMRVideoDesc, lpdeinterlacecaps: *mut VMRDeinterlaceCaps) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRDeinterlaceControl", "GetDeinterlaceModeCaps", 4usize, |this| ((**(this as *const *const IVMRDeinterlaceControl_Vtbl)).GetDeinterlaceModeCaps)(this, lpdeinterlacemode, lpvideodescription, lpdeinterlacecaps), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeinterlaceMode(this: *mut core::ffi::c_void, dwstreamid: u32, lpdeinterlacemode: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRDeinterlaceControl", "GetDeinterlaceMode", 5usize, |this| ((**(this as *const *const IVMRDeinterlaceControl_Vtbl)).GetDeinterlaceMode)(this, dwstreamid, lpdeinterlacemode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeinterlaceMode(this: *mut core::ffi::c_void, dwstreamid: u32, lpdeinterlacemode: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRDeinterlaceControl", "SetDeinterlaceMode", 6usize, |this| ((**(this as *const *const IVMRDeinterlaceControl_Vtbl)).SetDeinterlaceMode)(this, dwstreamid, lpdeinterlacemode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeinterlacePrefs(this: *mut core::ffi::c_void, lpdwdeinterlaceprefs: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRDeinterlaceControl", "GetDeinterlacePrefs", 7usize, |this| ((**(this as *const *const IVMRDeinterlaceControl_Vtbl)).GetDeinterlacePrefs)(this, lpdwdeinterlaceprefs), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeinterlacePrefs(this: *mut core::ffi::c_void, dwdeinterlaceprefs: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRDeinterlaceControl", "SetDeinterlacePrefs", 8usize, |this| ((**(this as *const *const IVMRDeinterlaceControl_Vtbl)).SetDeinterlacePrefs)(this, dwdeinterlaceprefs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActualDeinterlaceMode(this: *mut core::ffi::c_void, dwstreamid: u32, lpdeinterlacemode: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRDeinterlaceControl", "GetActualDeinterlaceMode", 9usize, |this| ((**(this as *const *const IVMRDeinterlaceControl_Vtbl)).GetActualDeinterlaceMode)(this, dwstreamid, lpdeinterlacemode), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetNumberOfDeinterlaceModes,
+            GetDeinterlaceModeCaps,
+            GetDeinterlaceMode,
+            SetDeinterlaceMode,
+            GetDeinterlacePrefs,
+            SetDeinterlacePrefs,
+            GetActualDeinterlaceMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRDeinterlaceControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVMRDeinterlaceControl9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNumberOfDeinterlaceModes(this: *mut core::ffi::c_void, lpvideodescription: *const VMR9VideoDesc, lpdwnumdeinterlacemodes: *mut u32, lpdeinterlacemodes: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRDeinterlaceControl9", "GetNumberOfDeinterlaceModes", 3usize, |this| ((**(this as *const *const IVMRDeinterlaceControl9_Vtbl)).GetNumberOfDeinterlaceModes)(this, lpvideodescription, lpdwnumdeinterlacemodes, lpdeinterlacemodes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeinterlaceModeCaps(this: *mut core::ffi::c_void, lpdeinterlacemode: *const windows_core::GUID, lpvideodescription: *const VMR9VideoDesc, lpdeinterlacecaps: *mut VMR9DeinterlaceCaps) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRDeinterlaceControl9", "GetDeinterlaceModeCaps", 4usize, |this| ((**(this as *const *const IVMRDeinterlaceControl9_Vtbl)).GetDeinterlaceModeCaps)(this, lpdeinterlacemode, lpvideodescription, lpdeinterlacecaps), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeinterlaceMode(this: *mut core::ffi::c_void, dwstreamid: u32, lpdeinterlacemode: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRDeinterlaceControl9", "GetDeinterlaceMode", 5usize, |this| ((**(this as *const *const IVMRDeinterlaceControl9_Vtbl)).GetDeinterlaceMode)(this, dwstreamid, lpdeinterlacemode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeinterlaceMode(this: *mut core::ffi::c_void, dwstreamid: u32, lpdeinterlacemode: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRDeinterlaceControl9", "SetDeinterlaceMode", 6usize, |this| ((**(this as *const *const IVMRDeinterlaceControl9_Vtbl)).SetDeinterlaceMode)(this, dwstreamid, lpdeinterlacemode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeinterlacePrefs(this: *mut core::ffi::c_void, lpdwdeinterlaceprefs: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRDeinterlaceControl9", "GetDeinterlacePrefs", 7usize, |this| ((**(this as *const *const IVMRDeinterlaceControl9_Vtbl)).GetDeinterlacePrefs)(this, lpdwdeinterlaceprefs), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeinterlacePrefs(this: *mut core::ffi::c_void, dwdeinterlaceprefs: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRDeinterlaceControl9", "SetDeinterlacePrefs", 8usize, |this| ((**(this as *const *const IVMRDeinterlaceControl9_Vtbl)).SetDeinterlacePrefs)(this, dwdeinterlaceprefs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActualDeinterlaceMode(this: *mut core::ffi::c_void, dwstreamid: u32, lpdeinterlacemode: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRDeinterlaceControl9", "GetActualDeinterlaceMode", 9usize, |this| ((**(this as *const *const IVMRDeinterlaceControl9_Vtbl)).GetActualDeinterlaceMode)(this, dwstreamid, lpdeinterlacemode), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetNumberOfDeinterlaceModes,
+            GetDeinterlaceModeCaps,
+            GetDeinterlaceMode,
+            SetDeinterlaceMode,
+            GetDeinterlacePrefs,
+            SetDeinterlacePrefs,
+            GetActualDeinterlaceMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRDeinterlaceControl9 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVMRFilterConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetImageCompositor(this: *mut core::ffi::c_void, lpvmrimgcompositor: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRFilterConfig", "SetImageCompositor", 3usize, |this| ((**(this as *const *const IVMRFilterConfig_Vtbl)).SetImageCompositor)(this, lpvmrimgcompositor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNumberOfStreams(this: *mut core::ffi::c_void, dwmaxstreams: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRFilterConfig", "SetNumberOfStreams", 4usize, |this| ((**(this as *const *const IVMRFilterConfig_Vtbl)).SetNumberOfStreams)(this, dwmaxstreams), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumberOfStreams(this: *mut core::ffi::c_void, pdwmaxstreams: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRFilterConfig", "GetNumberOfStreams", 5usize, |this| ((**(this as *const *const IVMRFilterConfig_Vtbl)).GetNumberOfStreams)(this, pdwmaxstreams), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRenderingPrefs(this: *mut core::ffi::c_void, dwrenderflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRFilterConfig", "SetRenderingPrefs", 6usize, |this| ((**(this as *const *const IVMRFilterConfig_Vtbl)).SetRenderingPrefs)(this, dwrenderflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRenderingPrefs(this: *mut core::ffi::c_void, pdwrenderflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRFilterConfig", "GetRenderingPrefs", 7usize, |this| ((**(this as *const *const IVMRFilterConfig_Vtbl)).GetRenderingPrefs)(this, pdwrenderflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRenderingMode(this: *mut core::ffi::c_void, mode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRFilterConfig", "SetRenderingMode", 8usize, |this| ((**(this as *const *const IVMRFilterConfig_Vtbl)).SetRenderingMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRenderingMode(this: *mut core::ffi::c_void, pmode: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRFilterConfig", "GetRenderingMode", 9usize, |this| ((**(this as *const *const IVMRFilterConfig_Vtbl)).GetRenderingMode)(this, pmode), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetImageCompositor,
+            SetNumberOfStreams,
+            GetNumberOfStreams,
+            SetRenderingPrefs,
+            GetRenderingPrefs,
+            SetRenderingMode,
+            GetRenderingMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRFilterConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVMRFilterConfig9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetImageCompositor(this: *mut core::ffi::c_void, lpvmrimgcompositor: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRFilterConfig9", "SetImageCompositor", 3usize, |this| ((**(this as *const *const IVMRFilterConfig9_Vtbl)).SetImageCompositor)(this, lpvmrimgcompositor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNumberOfStreams(this: *mut core::ffi::c_void, dwmaxstreams: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRFilterConfig9", "SetNumberOfStreams", 4usize, |this| ((**(this as *const *const IVMRFilterConfig9_Vtbl)).SetNumberOfStreams)(this, dwmaxstreams), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumberOfStreams(this: *mut core::ffi::c_void, pdwmaxstreams: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRFilterConfig9", "GetNumberOfStreams", 5usize, |this| ((**(this as *const *const IVMRFilterConfig9_Vtbl)).GetNumberOfStreams)(this, pdwmaxstreams), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRenderingPrefs(this: *mut core::ffi::c_void, dwrenderflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRFilterConfig9", "SetRenderingPrefs", 6usize, |this| ((**(this as *const *const IVMRFilterConfig9_Vtbl)).SetRenderingPrefs)(this, dwrenderflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRenderingPrefs(this: *mut core::ffi::c_void, pdwrenderflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRFilterConfig9", "GetRenderingPrefs", 7usize, |this| ((**(this as *const *const IVMRFilterConfig9_Vtbl)).GetRenderingPrefs)(this, pdwrenderflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRenderingMode(this: *mut core::ffi::c_void, mode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRFilterConfig9", "SetRenderingMode", 8usize, |this| ((**(this as *const *const IVMRFilterConfig9_Vtbl)).SetRenderingMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRenderingMode(this: *mut core::ffi::c_void, pmode: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRFilterConfig9", "GetRenderingMode", 9usize, |this| ((**(this as *const *const IVMRFilterConfig9_Vtbl)).GetRenderingMode)(this, pmode), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetImageCompositor,
+            SetNumberOfStreams,
+            GetNumberOfStreams,
+            SetRenderingPrefs,
+            GetRenderingPrefs,
+            SetRenderingMode,
+            GetRenderingMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRFilterConfig9 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Media_MediaFoundation"))]
+impl windows_core::imp::ProxyVtable for IVMRImageCompositor_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn InitCompositionTarget(this: *mut core::ffi::c_void, pd3ddevice: *mut core::ffi::c_void, pddsrendertarget: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRImageCompositor", "InitCompositionTarget", 3usize, |this| ((**(this as *const *const IVMRImageCompositor_Vtbl)).InitCompositionTarget)(this, pd3ddevice, pddsrendertarget), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn TermCompositionTarget(this: *mut core::ffi::c_void, pd3ddevice: *mut core::ffi::c_void, pddsrendertarget: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRImageCompositor", "TermCompositionTarget", 4usize, |this| ((**(this as *const *const IVMRImageCompositor_Vtbl)).TermCompositionTarget)(this, pd3ddevice, pddsrendertarget), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn SetStreamMediaType(this: *mut core::ffi::c_void, dwstrmid: u32, pmt: *const super::MediaFoundation::AM_MEDIA_TYPE, ftexture: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRImageCompositor", "SetStreamMediaType", 5usize, |this| ((**(this as *const *const IVMRImageCompositor_Vtbl)).SetStreamMediaType)(this, dwstrmid, pmt, ftexture), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Media_MediaFoundation"))]
+        unsafe extern "system" fn CompositeImage(this: *mut core::ffi::c_void, pd3ddevice: *mut core::ffi::c_void, pddsrendertarget: *mut core::ffi::c_void, pmtrendertarget: *const super::MediaFoundation::AM_MEDIA_TYPE, rtstart: i64, rtend: i64, dwclrbkgnd: u32, pvideostreaminfo: *const VMRVIDEOSTREAMINFO, cstreams: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRImageCompositor", "CompositeImage", 6usize, |this| ((**(this as *const *const IVMRImageCompositor_Vtbl)).CompositeImage)(this, pd3ddevice, pddsrendertarget, pmtrendertarget, rtstart, rtend, dwclrbkgnd, pvideostreaminfo, cstreams), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            InitCompositionTarget,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            InitCompositionTarget: 0,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            TermCompositionTarget,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            TermCompositionTarget: 0,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            SetStreamMediaType,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            SetStreamMediaType: 0,
+            #[cfg(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Media_MediaFoundation"))]
+            CompositeImage,
+            #[cfg(not(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Media_MediaFoundation")))]
+            CompositeImage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRImageCompositor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Media_MediaFoundation"))]
+impl windows_core::imp::ProxyVtable for IVMRImageCompositor9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitCompositionDevice(this: *mut core::ffi::c_void, pd3ddevice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRImageCompositor9", "InitCompositionDevice", 3usize, |this| ((**(this as *const *const IVMRImageCompositor9_Vtbl)).InitCompositionDevice)(this, pd3ddevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn TermCompositionDevice(this: *mut core::ffi::c_void, pd3ddevice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRImageCompositor9", "TermCompositionDevice", 4usize, |this| ((**(this as *const *const IVMRImageCompositor9_Vtbl)).TermCompositionDevice)(this, pd3ddevice), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn SetStreamMediaType(this: *mut core::ffi::c_void, dwstrmid: u32, pmt: *const super::MediaFoundation::AM_MEDIA_TYPE, ftexture: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRImageCompositor9", "SetStreamMediaType", 5usize, |this| ((**(this as *const *const IVMRImageCompositor9_Vtbl)).SetStreamMediaType)(this, dwstrmid, pmt, ftexture), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Media_MediaFoundation"))]
+        unsafe extern "system" fn CompositeImage(this: *mut core::ffi::c_void, pd3ddevice: *mut core::ffi::c_void, pddsrendertarget: *mut core::ffi::c_void, pmtrendertarget: *const super::MediaFoundation::AM_MEDIA_TYPE, rtstart: i64, rtend: i64, dwclrbkgnd: u32, pvideostreaminfo: *const VMR9VideoStreamInfo, cstreams: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRImageCompositor9", "CompositeImage", 6usize, |this| ((**(this as *const *const IVMRImageCompositor9_Vtbl)).CompositeImage)(this, pd3ddevice, pddsrendertarget, pmtrendertarget, rtstart, rtend, dwclrbkgnd, pvideostreaminfo, cstreams), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitCompositionDevice,
+            TermCompositionDevice,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            SetStreamMediaType,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            SetStreamMediaType: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Media_MediaFoundation"))]
+            CompositeImage,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Media_MediaFoundation")))]
+            CompositeImage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRImageCompositor9 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_DirectDraw")]
+impl windows_core::imp::ProxyVtable for IVMRImagePresenter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartPresenting(this: *mut core::ffi::c_void, dwuserid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRImagePresenter", "StartPresenting", 3usize, |this| ((**(this as *const *const IVMRImagePresenter_Vtbl)).StartPresenting)(this, dwuserid), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopPresenting(this: *mut core::ffi::c_void, dwuserid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRImagePresenter", "StopPresenting", 4usize, |this| ((**(this as *const *const IVMRImagePresenter_Vtbl)).StopPresenting)(this, dwuserid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn PresentImage(this: *mut core::ffi::c_void, dwuserid: usize, lppresinfo: *const VMRPRESENTATIONINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRImagePresenter", "PresentImage", 5usize, |this| ((**(this as *const *const IVMRImagePresenter_Vtbl)).PresentImage)(this, dwuserid, lppresinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartPresenting,
+            StopPresenting,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            PresentImage,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            PresentImage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRImagePresenter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D9")]
+impl windows_core::imp::ProxyVtable for IVMRImagePresenter9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartPresenting(this: *mut core::ffi::c_void, dwuserid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRImagePresenter9", "StartPresenting", 3usize, |this| ((**(this as *const *const IVMRImagePresenter9_Vtbl)).StartPresenting)(this, dwuserid), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopPresenting(this: *mut core::ffi::c_void, dwuserid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRImagePresenter9", "StopPresenting", 4usize, |this| ((**(this as *const *const IVMRImagePresenter9_Vtbl)).StopPresenting)(this, dwuserid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn PresentImage(this: *mut core::ffi::c_void, dwuserid: usize, lppresinfo: *const VMR9PresentationInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRImagePresenter9", "PresentImage", 5usize, |this| ((**(this as *const *const IVMRImagePresenter9_Vtbl)).PresentImage)(this, dwuserid, lppresinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartPresenting,
+            StopPresenting,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            PresentImage,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            PresentImage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRImagePresenter9 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVMRImagePresenterConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetRenderingPrefs(this: *mut core::ffi::c_void, dwrenderflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRImagePresenterConfig", "SetRenderingPrefs", 3usize, |this| ((**(this as *const *const IVMRImagePresenterConfig_Vtbl)).SetRenderingPrefs)(this, dwrenderflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRenderingPrefs(this: *mut core::ffi::c_void, dwrenderflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRImagePresenterConfig", "GetRenderingPrefs", 4usize, |this| ((**(this as *const *const IVMRImagePresenterConfig_Vtbl)).GetRenderingPrefs)(this, dwrenderflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetRenderingPrefs, GetRenderingPrefs }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRImagePresenterConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVMRImagePresenterConfig9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetRenderingPrefs(this: *mut core::ffi::c_void, dwrenderflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRImagePresenterConfig9", "SetRenderingPrefs", 3usize, |this| ((**(this as *const *const IVMRImagePresenterConfig9_Vtbl)).SetRenderingPrefs)(this, dwrenderflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRenderingPrefs(this: *mut core::ffi::c_void, dwrenderflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRImagePresenterConfig9", "GetRenderingPrefs", 4usize, |this| ((**(this as *const *const IVMRImagePresenterConfig9_Vtbl)).GetRenderingPrefs)(this, dwrenderflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetRenderingPrefs, GetRenderingPrefs }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRImagePresenterConfig9 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_DirectDraw")]
+impl windows_core::imp::ProxyVtable for IVMRImagePresenterExclModeConfig_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn SetXlcModeDDObjAndPrimarySurface(this: *mut core::ffi::c_void, lpddobj: *mut core::ffi::c_void, lpprimarysurf: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRImagePresenterExclModeConfig", "SetXlcModeDDObjAndPrimarySurface", 5usize, |this| ((**(this as *const *const IVMRImagePresenterExclModeConfig_Vtbl)).SetXlcModeDDObjAndPrimarySurface)(this, lpddobj, lpprimarysurf), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn GetXlcModeDDObjAndPrimarySurface(this: *mut core::ffi::c_void, lpddobj: *mut *mut core::ffi::c_void, lpprimarysurf: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRImagePresenterExclModeConfig", "GetXlcModeDDObjAndPrimarySurface", 6usize, |this| ((**(this as *const *const IVMRImagePresenterExclModeConfig_Vtbl)).GetXlcModeDDObjAndPrimarySurface)(this, lpddobj, lpprimarysurf), |result| Some(*result))
+        }
+        Self {
+            base__: <IVMRImagePresenterConfig_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            SetXlcModeDDObjAndPrimarySurface,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            SetXlcModeDDObjAndPrimarySurface: 0,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            GetXlcModeDDObjAndPrimarySurface,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            GetXlcModeDDObjAndPrimarySurface: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRImagePresenterExclModeConfig as windows_core::Interface>::IID || <IVMRImagePresenterConfig_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi"))]
+impl windows_core::imp::ProxyVtable for IVMRMixerBitmap_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi"))]
+        unsafe extern "system" fn SetAlphaBitmap(this: *mut core::ffi::c_void, pbmpparms: *const VMRALPHABITMAP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerBitmap", "SetAlphaBitmap", 3usize, |this| ((**(this as *const *const IVMRMixerBitmap_Vtbl)).SetAlphaBitmap)(this, pbmpparms), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi"))]
+        unsafe extern "system" fn UpdateAlphaBitmapParameters(this: *mut core::ffi::c_void, pbmpparms: *const VMRALPHABITMAP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerBitmap", "UpdateAlphaBitmapParameters", 4usize, |this| ((**(this as *const *const IVMRMixerBitmap_Vtbl)).UpdateAlphaBitmapParameters)(this, pbmpparms), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi"))]
+        unsafe extern "system" fn GetAlphaBitmapParameters(this: *mut core::ffi::c_void, pbmpparms: *mut VMRALPHABITMAP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerBitmap", "GetAlphaBitmapParameters", 5usize, |this| ((**(this as *const *const IVMRMixerBitmap_Vtbl)).GetAlphaBitmapParameters)(this, pbmpparms), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi"))]
+            SetAlphaBitmap,
+            #[cfg(not(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi")))]
+            SetAlphaBitmap: 0,
+            #[cfg(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi"))]
+            UpdateAlphaBitmapParameters,
+            #[cfg(not(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi")))]
+            UpdateAlphaBitmapParameters: 0,
+            #[cfg(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi"))]
+            GetAlphaBitmapParameters,
+            #[cfg(not(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi")))]
+            GetAlphaBitmapParameters: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRMixerBitmap as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_Gdi"))]
+impl windows_core::imp::ProxyVtable for IVMRMixerBitmap9_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_Gdi"))]
+        unsafe extern "system" fn SetAlphaBitmap(this: *mut core::ffi::c_void, pbmpparms: *const VMR9AlphaBitmap) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerBitmap9", "SetAlphaBitmap", 3usize, |this| ((**(this as *const *const IVMRMixerBitmap9_Vtbl)).SetAlphaBitmap)(this, pbmpparms), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_Gdi"))]
+        unsafe extern "system" fn UpdateAlphaBitmapParameters(this: *mut core::ffi::c_void, pbmpparms: *const VMR9AlphaBitmap) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerBitmap9", "UpdateAlphaBitmapParameters", 4usize, |this| ((**(this as *const *const IVMRMixerBitmap9_Vtbl)).UpdateAlphaBitmapParameters)(this, pbmpparms), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_Gdi"))]
+        unsafe extern "system" fn GetAlphaBitmapParameters(this: *mut core::ffi::c_void, pbmpparms: *mut VMR9AlphaBitmap) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerBitmap9", "GetAlphaBitmapParameters", 5usize, |this| ((**(this as *const *const IVMRMixerBitmap9_Vtbl)).GetAlphaBitmapParameters)(this, pbmpparms), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_Gdi"))]
+            SetAlphaBitmap,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_Gdi")))]
+            SetAlphaBitmap: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_Gdi"))]
+            UpdateAlphaBitmapParameters,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_Gdi")))]
+            UpdateAlphaBitmapParameters: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_Gdi"))]
+            GetAlphaBitmapParameters,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_Gdi")))]
+            GetAlphaBitmapParameters: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRMixerBitmap9 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVMRMixerControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAlpha(this: *mut core::ffi::c_void, dwstreamid: u32, alpha: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerControl", "SetAlpha", 3usize, |this| ((**(this as *const *const IVMRMixerControl_Vtbl)).SetAlpha)(this, dwstreamid, alpha), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAlpha(this: *mut core::ffi::c_void, dwstreamid: u32, palpha: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerControl", "GetAlpha", 4usize, |this| ((**(this as *const *const IVMRMixerControl_Vtbl)).GetAlpha)(this, dwstreamid, palpha), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetZOrder(this: *mut core::ffi::c_void, dwstreamid: u32, dwz: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerControl", "SetZOrder", 5usize, |this| ((**(this as *const *const IVMRMixerControl_Vtbl)).SetZOrder)(this, dwstreamid, dwz), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetZOrder(this: *mut core::ffi::c_void, dwstreamid: u32, pz: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerControl", "GetZOrder", 6usize, |this| ((**(this as *const *const IVMRMixerControl_Vtbl)).GetZOrder)(this, dwstreamid, pz), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputRect(this: *mut core::ffi::c_void, dwstreamid: u32, prect: *const NORMALIZEDRECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerControl", "SetOutputRect", 7usize, |this| ((**(this as *const *const IVMRMixerControl_Vtbl)).SetOutputRect)(this, dwstreamid, prect), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputRect(this: *mut core::ffi::c_void, dwstreamid: u32, prect: *mut NORMALIZEDRECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerControl", "GetOutputRect", 8usize, |this| ((**(this as *const *const IVMRMixerControl_Vtbl)).GetOutputRect)(this, dwstreamid, prect), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackgroundClr(this: *mut core::ffi::c_void, clrbkg: super::super::Foundation::COLORREF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerControl", "SetBackgroundClr", 9usize, |this| ((**(this as *const *const IVMRMixerControl_Vtbl)).SetBackgroundClr)(this, clrbkg), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBackgroundClr(this: *mut core::ffi::c_void, lpclrbkg: *const super::super::Foundation::COLORREF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerControl", "GetBackgroundClr", 10usize, |this| ((**(this as *const *const IVMRMixerControl_Vtbl)).GetBackgroundClr)(this, lpclrbkg), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMixingPrefs(this: *mut core::ffi::c_void, dwmixerprefs: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerControl", "SetMixingPrefs", 11usize, |this| ((**(this as *const *const IVMRMixerControl_Vtbl)).SetMixingPrefs)(this, dwmixerprefs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMixingPrefs(this: *mut core::ffi::c_void, pdwmixerprefs: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerControl", "GetMixingPrefs", 12usize, |this| ((**(this as *const *const IVMRMixerControl_Vtbl)).GetMixingPrefs)(this, pdwmixerprefs), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetAlpha,
+            GetAlpha,
+            SetZOrder,
+            GetZOrder,
+            SetOutputRect,
+            GetOutputRect,
+            SetBackgroundClr,
+            GetBackgroundClr,
+            SetMixingPrefs,
+            GetMixingPrefs,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRMixerControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVMRMixerControl9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAlpha(this: *mut core::ffi::c_void, dwstreamid: u32, alpha: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerControl9", "SetAlpha", 3usize, |this| ((**(this as *const *const IVMRMixerControl9_Vtbl)).SetAlpha)(this, dwstreamid, alpha), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAlpha(this: *mut core::ffi::c_void, dwstreamid: u32, palpha: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerControl9", "GetAlpha", 4usize, |this| ((**(this as *const *const IVMRMixerControl9_Vtbl)).GetAlpha)(this, dwstreamid, palpha), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetZOrder(this: *mut core::ffi::c_void, dwstreamid: u32, dwz: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerControl9", "SetZOrder", 5usize, |this| ((**(this as *const *const IVMRMixerControl9_Vtbl)).SetZOrder)(this, dwstreamid, dwz), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetZOrder(this: *mut core::ffi::c_void, dwstreamid: u32, pz: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerControl9", "GetZOrder", 6usize, |this| ((**(this as *const *const IVMRMixerControl9_Vtbl)).GetZOrder)(this, dwstreamid, pz), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputRect(this: *mut core::ffi::c_void, dwstreamid: u32, prect: *const VMR9NormalizedRect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerControl9", "SetOutputRect", 7usize, |this| ((**(this as *const *const IVMRMixerControl9_Vtbl)).SetOutputRect)(this, dwstreamid, prect), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputRect(this: *mut core::ffi::c_void, dwstreamid: u32, prect: *mut VMR9NormalizedRect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerControl9", "GetOutputRect", 8usize, |this| ((**(this as *const *const IVMRMixerControl9_Vtbl)).GetOutputRect)(this, dwstreamid, prect), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackgroundClr(this: *mut core::ffi::c_void, clrbkg: super::super::Foundation::COLORREF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerControl9", "SetBackgroundClr", 9usize, |this| ((**(this as *const *const IVMRMixerControl9_Vtbl)).SetBackgroundClr)(this, clrbkg), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBackgroundClr(this: *mut core::ffi::c_void, lpclrbkg: *const super::super::Foundation::COLORREF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerControl9", "GetBackgroundClr", 10usize, |this| ((**(this as *const *const IVMRMixerControl9_Vtbl)).GetBackgroundClr)(this, lpclrbkg), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMixingPrefs(this: *mut core::ffi::c_void, dwmixerprefs: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerControl9", "SetMixingPrefs", 11usize, |this| ((**(this as *const *const IVMRMixerControl9_Vtbl)).SetMixingPrefs)(this, dwmixerprefs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMixingPrefs(this: *mut core::ffi::c_void, pdwmixerprefs: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerControl9", "GetMixingPrefs", 12usize, |this| ((**(this as *const *const IVMRMixerControl9_Vtbl)).GetMixingPrefs)(this, pdwmixerprefs), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProcAmpControl(this: *mut core::ffi::c_void, dwstreamid: u32, lpclrcontrol: *const VMR9ProcAmpControl) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerControl9", "SetProcAmpControl", 13usize, |this| ((**(this as *const *const IVMRMixerControl9_Vtbl)).SetProcAmpControl)(this, dwstreamid, lpclrcontrol), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProcAmpControl(this: *mut core::ffi::c_void, dwstreamid: u32, lpclrcontrol: *mut VMR9ProcAmpControl) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerControl9", "GetProcAmpControl", 14usize, |this| ((**(this as *const *const IVMRMixerControl9_Vtbl)).GetProcAmpControl)(this, dwstreamid, lpclrcontrol), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProcAmpControlRange(this: *mut core::ffi::c_void, dwstreamid: u32, lpclrcontrol: *mut VMR9ProcAmpControlRange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMixerControl9", "GetProcAmpControlRange", 15usize, |this| ((**(this as *const *const IVMRMixerControl9_Vtbl)).GetProcAmpControlRange)(this, dwstreamid, lpclrcontrol), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetAlpha,
+            GetAlpha,
+            SetZOrder,
+            GetZOrder,
+            SetOutputRect,
+            GetOutputRect,
+            SetBackgroundClr,
+            GetBackgroundClr,
+            SetMixingPrefs,
+            GetMixingPrefs,
+            SetProcAmpControl,
+            GetProcAmpControl,
+            GetProcAmpControlRange,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRMixerControl9 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IVMRMonitorConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMonitor(this: *mut core::ffi::c_void, pguid: *const VMRGUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMonitorConfig", "SetMonitor", 3usize, |this| ((**(this as *const *const IVMRMonitorConfig_Vtbl)).SetMonitor)(this, pguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMonitor(this: *mut core::ffi::c_void, pguid: *mut VMRGUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMonitorConfig", "GetMonitor", 4usize, |this| ((**(this as *const *const IVMRMonitorConfig_Vtbl)).GetMonitor)(this, pguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultMonitor(this: *mut core::ffi::c_void, pguid: *const VMRGUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMonitorConfig", "SetDefaultMonitor", 5usize, |this| ((**(this as *const *const IVMRMonitorConfig_Vtbl)).SetDefaultMonitor)(this, pguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultMonitor(this: *mut core::ffi::c_void, pguid: *mut VMRGUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMonitorConfig", "GetDefaultMonitor", 6usize, |this| ((**(this as *const *const IVMRMonitorConfig_Vtbl)).GetDefaultMonitor)(this, pguid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetAvailableMonitors(this: *mut core::ffi::c_void, pinfo: *mut VMRMONITORINFO, dwmaxinfoarraysize: u32, pdwnumdevices: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMonitorConfig", "GetAvailableMonitors", 7usize, |this| ((**(this as *const *const IVMRMonitorConfig_Vtbl)).GetAvailableMonitors)(this, pinfo, dwmaxinfoarraysize, pdwnumdevices), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetMonitor,
+            GetMonitor,
+            SetDefaultMonitor,
+            GetDefaultMonitor,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetAvailableMonitors,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetAvailableMonitors: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRMonitorConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IVMRMonitorConfig9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMonitor(this: *mut core::ffi::c_void, udev: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMonitorConfig9", "SetMonitor", 3usize, |this| ((**(this as *const *const IVMRMonitorConfig9_Vtbl)).SetMonitor)(this, udev), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMonitor(this: *mut core::ffi::c_void, pudev: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMonitorConfig9", "GetMonitor", 4usize, |this| ((**(this as *const *const IVMRMonitorConfig9_Vtbl)).GetMonitor)(this, pudev), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultMonitor(this: *mut core::ffi::c_void, udev: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMonitorConfig9", "SetDefaultMonitor", 5usize, |this| ((**(this as *const *const IVMRMonitorConfig9_Vtbl)).SetDefaultMonitor)(this, udev), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultMonitor(this: *mut core::ffi::c_void, pudev: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMonitorConfig9", "GetDefaultMonitor", 6usize, |this| ((**(this as *const *const IVMRMonitorConfig9_Vtbl)).GetDefaultMonitor)(this, pudev), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetAvailableMonitors(this: *mut core::ffi::c_void, pinfo: *mut VMR9MonitorInfo, dwmaxinfoarraysize: u32, pdwnumdevices: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRMonitorConfig9", "GetAvailableMonitors", 7usize, |this| ((**(this as *const *const IVMRMonitorConfig9_Vtbl)).GetAvailableMonitors)(this, pinfo, dwmaxinfoarraysize, pdwnumdevices), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetMonitor,
+            GetMonitor,
+            SetDefaultMonitor,
+            GetDefaultMonitor,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetAvailableMonitors,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetAvailableMonitors: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRMonitorConfig9 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_DirectDraw")]
+impl windows_core::imp::ProxyVtable for IVMRSurface_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSurfaceLocked(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRSurface", "IsSurfaceLocked", 3usize, |this| ((**(this as *const *const IVMRSurface_Vtbl)).IsSurfaceLocked)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockSurface(this: *mut core::ffi::c_void, lpsurface: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRSurface", "LockSurface", 4usize, |this| ((**(this as *const *const IVMRSurface_Vtbl)).LockSurface)(this, lpsurface), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockSurface(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRSurface", "UnlockSurface", 5usize, |this| ((**(this as *const *const IVMRSurface_Vtbl)).UnlockSurface)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn GetSurface(this: *mut core::ffi::c_void, lplpsurface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRSurface", "GetSurface", 6usize, |this| ((**(this as *const *const IVMRSurface_Vtbl)).GetSurface)(this, lplpsurface), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsSurfaceLocked,
+            LockSurface,
+            UnlockSurface,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            GetSurface,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            GetSurface: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRSurface as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D9")]
+impl windows_core::imp::ProxyVtable for IVMRSurface9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSurfaceLocked(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRSurface9", "IsSurfaceLocked", 3usize, |this| ((**(this as *const *const IVMRSurface9_Vtbl)).IsSurfaceLocked)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockSurface(this: *mut core::ffi::c_void, lpsurface: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRSurface9", "LockSurface", 4usize, |this| ((**(this as *const *const IVMRSurface9_Vtbl)).LockSurface)(this, lpsurface), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockSurface(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRSurface9", "UnlockSurface", 5usize, |this| ((**(this as *const *const IVMRSurface9_Vtbl)).UnlockSurface)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn GetSurface(this: *mut core::ffi::c_void, lplpsurface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRSurface9", "GetSurface", 6usize, |this| ((**(this as *const *const IVMRSurface9_Vtbl)).GetSurface)(this, lplpsurface), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsSurfaceLocked,
+            LockSurface,
+            UnlockSurface,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            GetSurface,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            GetSurface: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRSurface9 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi"))]
+impl windows_core::imp::ProxyVtable for IVMRSurfaceAllocator_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi"))]
+        unsafe extern "system" fn AllocateSurface(this: *mut core::ffi::c_void, dwuserid: usize, lpallocinfo: *const VMRALLOCATIONINFO, lpdwactualbuffers: *mut u32, lplpsurface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRSurfaceAllocator", "AllocateSurface", 3usize, |this| ((**(this as *const *const IVMRSurfaceAllocator_Vtbl)).AllocateSurface)(this, dwuserid, lpallocinfo, lpdwactualbuffers, lplpsurface), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreeSurface(this: *mut core::ffi::c_void, dwid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRSurfaceAllocator", "FreeSurface", 4usize, |this| ((**(this as *const *const IVMRSurfaceAllocator_Vtbl)).FreeSurface)(this, dwid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn PrepareSurface(this: *mut core::ffi::c_void, dwuserid: usize, lpsurface: *mut core::ffi::c_void, dwsurfaceflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRSurfaceAllocator", "PrepareSurface", 5usize, |this| ((**(this as *const *const IVMRSurfaceAllocator_Vtbl)).PrepareSurface)(this, dwuserid, lpsurface, dwsurfaceflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdviseNotify(this: *mut core::ffi::c_void, lpivmrsurfallocnotify: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRSurfaceAllocator", "AdviseNotify", 6usize, |this| ((**(this as *const *const IVMRSurfaceAllocator_Vtbl)).AdviseNotify)(this, lpivmrsurfallocnotify), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi"))]
+            AllocateSurface,
+            #[cfg(not(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi")))]
+            AllocateSurface: 0,
+            FreeSurface,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            PrepareSurface,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            PrepareSurface: 0,
+            AdviseNotify,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRSurfaceAllocator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D9")]
+impl windows_core::imp::ProxyVtable for IVMRSurfaceAllocator9_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn InitializeDevice(this: *mut core::ffi::c_void, dwuserid: usize, lpallocinfo: *const VMR9AllocationInfo, lpnumbuffers: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRSurfaceAllocator9", "InitializeDevice", 3usize, |this| ((**(this as *const *const IVMRSurfaceAllocator9_Vtbl)).InitializeDevice)(this, dwuserid, lpallocinfo, lpnumbuffers), |result| Some(*result))
+        }
+        unsafe extern "system" fn TerminateDevice(this: *mut core::ffi::c_void, dwid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRSurfaceAllocator9", "TerminateDevice", 4usize, |this| ((**(this as *const *const IVMRSurfaceAllocator9_Vtbl)).TerminateDevice)(this, dwid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn GetSurface(this: *mut core::ffi::c_void, dwuserid: usize, surfaceindex: u32, surfaceflags: u32, lplpsurface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRSurfaceAllocator9", "GetSurface", 5usize, |this| ((**(this as *const *const IVMRSurfaceAllocator9_Vtbl)).GetSurface)(this, dwuserid, surfaceindex, surfaceflags, lplpsurface), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdviseNotify(this: *mut core::ffi::c_void, lpivmrsurfallocnotify: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRSurfaceAllocator9", "AdviseNotify", 6usize, |this| ((**(this as *const *const IVMRSurfaceAllocator9_Vtbl)).AdviseNotify)(this, lpivmrsurfallocnotify), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            InitializeDevice,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            InitializeDevice: 0,
+            TerminateDevice,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            GetSurface,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            GetSurface: 0,
+            AdviseNotify,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRSurfaceAllocator9 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D9")]
+impl windows_core::imp::ProxyVtable for IVMRSurfaceAllocatorEx9_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn GetSurfaceEx(this: *mut core::ffi::c_void, dwuserid: usize, surfaceindex: u32, surfaceflags: u32, lplpsurface: *mut *mut core::ffi::c_void, lprcdst: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRSurfaceAllocatorEx9", "GetSurfaceEx", 7usize, |this| ((**(this as *const *const IVMRSurfaceAllocatorEx9_Vtbl)).GetSurfaceEx)(this, dwuserid, surfaceindex, surfaceflags, lplpsurface, lprcdst), |result| Some(*result))
+        }
+        Self {
+            base__: <IVMRSurfaceAllocator9_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            GetSurfaceEx,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            GetSurfaceEx: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRSurfaceAllocatorEx9 as windows_core::Interface>::IID || <IVMRSurfaceAllocator9_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi"))]
+impl windows_core::imp::ProxyVtable for IVMRSurfaceAllocatorNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AdviseSurfaceAllocator(this: *mut core::ffi::c_void, dwuserid: usize, lpivrmsurfaceallocator: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRSurfaceAllocatorNotify", "AdviseSurfaceAllocator", 3usize, |this| ((**(this as *const *const IVMRSurfaceAllocatorNotify_Vtbl)).AdviseSurfaceAllocator)(this, dwuserid, lpivrmsurfaceallocator), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi"))]
+        unsafe extern "system" fn SetDDrawDevice(this: *mut core::ffi::c_void, lpddrawdevice: *mut core::ffi::c_void, hmonitor: super::super::Graphics::Gdi::HMONITOR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRSurfaceAllocatorNotify", "SetDDrawDevice", 4usize, |this| ((**(this as *const *const IVMRSurfaceAllocatorNotify_Vtbl)).SetDDrawDevice)(this, lpddrawdevice, hmonitor), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi"))]
+        unsafe extern "system" fn ChangeDDrawDevice(this: *mut core::ffi::c_void, lpddrawdevice: *mut core::ffi::c_void, hmonitor: super::super::Graphics::Gdi::HMONITOR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRSurfaceAllocatorNotify", "ChangeDDrawDevice", 5usize, |this| ((**(this as *const *const IVMRSurfaceAllocatorNotify_Vtbl)).ChangeDDrawDevice)(this, lpddrawdevice, hmonitor), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestoreDDrawSurfaces(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRSurfaceAllocatorNotify", "RestoreDDrawSurfaces", 6usize, |this| ((**(this as *const *const IVMRSurfaceAllocatorNotify_Vtbl)).RestoreDDrawSurfaces)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyEvent(this: *mut core::ffi::c_void, eventcode: i32, param1: isize, param2: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRSurfaceAllocatorNotify", "NotifyEvent", 7usize, |this| ((**(this as *const *const IVMRSurfaceAllocatorNotify_Vtbl)).NotifyEvent)(this, eventcode, param1, param2), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBorderColor(this: *mut core::ffi::c_void, clrborder: super::super::Foundation::COLORREF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRSurfaceAllocatorNotify", "SetBorderColor", 8usize, |this| ((**(this as *const *const IVMRSurfaceAllocatorNotify_Vtbl)).SetBorderColor)(this, clrborder), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AdviseSurfaceAllocator,
+            #[cfg(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi"))]
+            SetDDrawDevice,
+            #[cfg(not(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi")))]
+            SetDDrawDevice: 0,
+            #[cfg(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi"))]
+            ChangeDDrawDevice,
+            #[cfg(not(all(feature = "Win32_Graphics_DirectDraw", feature = "Win32_Graphics_Gdi")))]
+            ChangeDDrawDevice: 0,
+            RestoreDDrawSurfaces,
+            NotifyEvent,
+            SetBorderColor,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRSurfaceAllocatorNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_Gdi"))]
+impl windows_core::imp::ProxyVtable for IVMRSurfaceAllocatorNotify9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AdviseSurfaceAllocator(this: *mut core::ffi::c_void, dwuserid: usize, lpivrmsurfaceallocator: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRSurfaceAllocatorNotify9", "AdviseSurfaceAllocator", 3usize, |this| ((**(this as *const *const IVMRSurfaceAllocatorNotify9_Vtbl)).AdviseSurfaceAllocator)(this, dwuserid, lpivrmsurfaceallocator), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_Gdi"))]
+        unsafe extern "system" fn SetD3DDevice(this: *mut core::ffi::c_void, lpd3ddevice: *mut core::ffi::c_void, hmonitor: super::super::Graphics::Gdi::HMONITOR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRSurfaceAllocatorNotify9", "SetD3DDevice", 4usize, |this| ((**(this as *const *const IVMRSurfaceAllocatorNotify9_Vtbl)).SetD3DDevice)(this, lpd3ddevice, hmonitor), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_Gdi"))]
+        unsafe extern "system" fn ChangeD3DDevice(this: *mut core::ffi::c_void, lpd3ddevice: *mut core::ffi::c_void, hmonitor: super::super::Graphics::Gdi::HMONITOR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRSurfaceAllocatorNotify9", "ChangeD3DDevice", 5usize, |this| ((**(this as *const *const IVMRSurfaceAllocatorNotify9_Vtbl)).ChangeD3DDevice)(this, lpd3ddevice, hmonitor), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn AllocateSurfaceHelper(this: *mut core::ffi::c_void, lpallocinfo: *const VMR9AllocationInfo, lpnumbuffers: *mut u32, lplpsurface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRSurfaceAllocatorNotify9", "AllocateSurfaceHelper", 6usize, |this| ((**(this as *const *const IVMRSurfaceAllocatorNotify9_Vtbl)).AllocateSurfaceHelper)(this, lpallocinfo, lpnumbuffers, lplpsurface), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyEvent(this: *mut core::ffi::c_void, eventcode: i32, param1: isize, param2: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRSurfaceAllocatorNotify9", "NotifyEvent", 7usize, |this| ((**(this as *const *const IVMRSurfaceAllocatorNotify9_Vtbl)).NotifyEvent)(this, eventcode, param1, param2), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AdviseSurfaceAllocator,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_Gdi"))]
+            SetD3DDevice,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_Gdi")))]
+            SetD3DDevice: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_Gdi"))]
+            ChangeD3DDevice,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_Gdi")))]
+            ChangeD3DDevice: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            AllocateSurfaceHelper,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            AllocateSurfaceHelper: 0,
+            NotifyEvent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRSurfaceAllocatorNotify9 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_DirectDraw")]
+impl windows_core::imp::ProxyVtable for IVMRVideoStreamControl_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn SetColorKey(this: *mut core::ffi::c_void, lpclrkey: *const super::super::Graphics::DirectDraw::DDCOLORKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRVideoStreamControl", "SetColorKey", 3usize, |this| ((**(this as *const *const IVMRVideoStreamControl_Vtbl)).SetColorKey)(this, lpclrkey), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn GetColorKey(this: *mut core::ffi::c_void, lpclrkey: *mut super::super::Graphics::DirectDraw::DDCOLORKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRVideoStreamControl", "GetColorKey", 4usize, |this| ((**(this as *const *const IVMRVideoStreamControl_Vtbl)).GetColorKey)(this, lpclrkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStreamActiveState(this: *mut core::ffi::c_void, factive: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRVideoStreamControl", "SetStreamActiveState", 5usize, |this| ((**(this as *const *const IVMRVideoStreamControl_Vtbl)).SetStreamActiveState)(this, factive), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamActiveState(this: *mut core::ffi::c_void, lpfactive: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRVideoStreamControl", "GetStreamActiveState", 6usize, |this| ((**(this as *const *const IVMRVideoStreamControl_Vtbl)).GetStreamActiveState)(this, lpfactive), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            SetColorKey,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            SetColorKey: 0,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            GetColorKey,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            GetColorKey: 0,
+            SetStreamActiveState,
+            GetStreamActiveState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRVideoStreamControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVMRVideoStreamControl9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetStreamActiveState(this: *mut core::ffi::c_void, factive: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRVideoStreamControl9", "SetStreamActiveState", 3usize, |this| ((**(this as *const *const IVMRVideoStreamControl9_Vtbl)).SetStreamActiveState)(this, factive), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamActiveState(this: *mut core::ffi::c_void, lpfactive: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRVideoStreamControl9", "GetStreamActiveState", 4usize, |this| ((**(this as *const *const IVMRVideoStreamControl9_Vtbl)).GetStreamActiveState)(this, lpfactive), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetStreamActiveState, GetStreamActiveState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRVideoStreamControl9 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IVMRWindowlessControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNativeVideoSize(this: *mut core::ffi::c_void, lpwidth: *mut i32, lpheight: *mut i32, lparwidth: *mut i32, lparheight: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRWindowlessControl", "GetNativeVideoSize", 3usize, |this| ((**(this as *const *const IVMRWindowlessControl_Vtbl)).GetNativeVideoSize)(this, lpwidth, lpheight, lparwidth, lparheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMinIdealVideoSize(this: *mut core::ffi::c_void, lpwidth: *mut i32, lpheight: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRWindowlessControl", "GetMinIdealVideoSize", 4usize, |this| ((**(this as *const *const IVMRWindowlessControl_Vtbl)).GetMinIdealVideoSize)(this, lpwidth, lpheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxIdealVideoSize(this: *mut core::ffi::c_void, lpwidth: *mut i32, lpheight: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRWindowlessControl", "GetMaxIdealVideoSize", 5usize, |this| ((**(this as *const *const IVMRWindowlessControl_Vtbl)).GetMaxIdealVideoSize)(this, lpwidth, lpheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVideoPosition(this: *mut core::ffi::c_void, lpsrcrect: *const super::super::Foundation::RECT, lpdstrect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRWindowlessControl", "SetVideoPosition", 6usize, |this| ((**(this as *const *const IVMRWindowlessControl_Vtbl)).SetVideoPosition)(this, lpsrcrect, lpdstrect), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoPosition(this: *mut core::ffi::c_void, lpsrcrect: *mut super::super::Foundation::RECT, lpdstrect: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRWindowlessControl", "GetVideoPosition", 7usize, |this| ((**(this as *const *const IVMRWindowlessControl_Vtbl)).GetVideoPosition)(this, lpsrcrect, lpdstrect), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAspectRatioMode(this: *mut core::ffi::c_void, lpaspectratiomode: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRWindowlessControl", "GetAspectRatioMode", 8usize, |this| ((**(this as *const *const IVMRWindowlessControl_Vtbl)).GetAspectRatioMode)(this, lpaspectratiomode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAspectRatioMode(this: *mut core::ffi::c_void, aspectratiomode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRWindowlessControl", "SetAspectRatioMode", 9usize, |this| ((**(this as *const *const IVMRWindowlessControl_Vtbl)).SetAspectRatioMode)(this, aspectratiomode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVideoClippingWindow(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRWindowlessControl", "SetVideoClippingWindow", 10usize, |this| ((**(this as *const *const IVMRWindowlessControl_Vtbl)).SetVideoClippingWindow)(this, hwnd), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn RepaintVideo(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, hdc: super::super::Graphics::Gdi::HDC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRWindowlessControl", "RepaintVideo", 11usize, |this| ((**(this as *const *const IVMRWindowlessControl_Vtbl)).RepaintVideo)(this, hwnd, hdc), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayModeChanged(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRWindowlessControl", "DisplayModeChanged", 12usize, |this| ((**(this as *const *const IVMRWindowlessControl_Vtbl)).DisplayModeChanged)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentImage(this: *mut core::ffi::c_void, lpdib: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRWindowlessControl", "GetCurrentImage", 13usize, |this| ((**(this as *const *const IVMRWindowlessControl_Vtbl)).GetCurrentImage)(this, lpdib), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBorderColor(this: *mut core::ffi::c_void, clr: super::super::Foundation::COLORREF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRWindowlessControl", "SetBorderColor", 14usize, |this| ((**(this as *const *const IVMRWindowlessControl_Vtbl)).SetBorderColor)(this, clr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBorderColor(this: *mut core::ffi::c_void, lpclr: *mut super::super::Foundation::COLORREF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRWindowlessControl", "GetBorderColor", 15usize, |this| ((**(this as *const *const IVMRWindowlessControl_Vtbl)).GetBorderColor)(this, lpclr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColorKey(this: *mut core::ffi::c_void, clr: super::super::Foundation::COLORREF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRWindowlessControl", "SetColorKey", 16usize, |this| ((**(this as *const *const IVMRWindowlessControl_Vtbl)).SetColorKey)(this, clr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColorKey(this: *mut core::ffi::c_void, lpclr: *mut super::super::Foundation::COLORREF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRWindowlessControl", "GetColorKey", 17usize, |this| ((**(this as *const *const IVMRWindowlessControl_Vtbl)).GetColorKey)(this, lpclr), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetNativeVideoSize,
+            GetMinIdealVideoSize,
+            GetMaxIdealVideoSize,
+            SetVideoPosition,
+            GetVideoPosition,
+            GetAspectRatioMode,
+            SetAspectRatioMode,
+            SetVideoClippingWindow,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            RepaintVideo,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            RepaintVideo: 0,
+            DisplayModeChanged,
+            GetCurrentImage,
+            SetBorderColor,
+            GetBorderColor,
+            SetColorKey,
+            GetColorKey,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRWindowlessControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IVMRWindowlessControl9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNativeVideoSize(this: *mut core::ffi::c_void, lpwidth: *mut i32, lpheight: *mut i32, lparwidth: *mut i32, lparheight: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRWindowlessControl9", "GetNativeVideoSize", 3usize, |this| ((**(this as *const *const IVMRWindowlessControl9_Vtbl)).GetNativeVideoSize)(this, lpwidth, lpheight, lparwidth, lparheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMinIdealVideoSize(this: *mut core::ffi::c_void, lpwidth: *mut i32, lpheight: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRWindowlessControl9", "GetMinIdealVideoSize", 4usize, |this| ((**(this as *const *const IVMRWindowlessControl9_Vtbl)).GetMinIdealVideoSize)(this, lpwidth, lpheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxIdealVideoSize(this: *mut core::ffi::c_void, lpwidth: *mut i32, lpheight: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRWindowlessControl9", "GetMaxIdealVideoSize", 5usize, |this| ((**(this as *const *const IVMRWindowlessControl9_Vtbl)).GetMaxIdealVideoSize)(this, lpwidth, lpheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVideoPosition(this: *mut core::ffi::c_void, lpsrcrect: *const super::super::Foundation::RECT, lpdstrect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRWindowlessControl9", "SetVideoPosition", 6usize, |this| ((**(this as *const *const IVMRWindowlessControl9_Vtbl)).SetVideoPosition)(this, lpsrcrect, lpdstrect), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoPosition(this: *mut core::ffi::c_void, lpsrcrect: *mut super::super::Foundation::RECT, lpdstrect: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRWindowlessControl9", "GetVideoPosition", 7usize, |this| ((**(this as *const *const IVMRWindowlessControl9_Vtbl)).GetVideoPosition)(this, lpsrcrect, lpdstrect), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAspectRatioMode(this: *mut core::ffi::c_void, lpaspectratiomode: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRWindowlessControl9", "GetAspectRatioMode", 8usize, |this| ((**(this as *const *const IVMRWindowlessControl9_Vtbl)).GetAspectRatioMode)(this, lpaspectratiomode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAspectRatioMode(this: *mut core::ffi::c_void, aspectratiomode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRWindowlessControl9", "SetAspectRatioMode", 9usize, |this| ((**(this as *const *const IVMRWindowlessControl9_Vtbl)).SetAspectRatioMode)(this, aspectratiomode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVideoClippingWindow(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRWindowlessControl9", "SetVideoClippingWindow", 10usize, |this| ((**(this as *const *const IVMRWindowlessControl9_Vtbl)).SetVideoClippingWindow)(this, hwnd), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn RepaintVideo(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, hdc: super::super::Graphics::Gdi::HDC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRWindowlessControl9", "RepaintVideo", 11usize, |this| ((**(this as *const *const IVMRWindowlessControl9_Vtbl)).RepaintVideo)(this, hwnd, hdc), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayModeChanged(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRWindowlessControl9", "DisplayModeChanged", 12usize, |this| ((**(this as *const *const IVMRWindowlessControl9_Vtbl)).DisplayModeChanged)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentImage(this: *mut core::ffi::c_void, lpdib: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRWindowlessControl9", "GetCurrentImage", 13usize, |this| ((**(this as *const *const IVMRWindowlessControl9_Vtbl)).GetCurrentImage)(this, lpdib), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBorderColor(this: *mut core::ffi::c_void, clr: super::super::Foundation::COLORREF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRWindowlessControl9", "SetBorderColor", 14usize, |this| ((**(this as *const *const IVMRWindowlessControl9_Vtbl)).SetBorderColor)(this, clr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBorderColor(this: *mut core::ffi::c_void, lpclr: *mut super::super::Foundation::COLORREF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVMRWindowlessControl9", "GetBorderColor", 15usize, |this| ((**(this as *const *const IVMRWindowlessControl9_Vtbl)).GetBorderColor)(this, lpclr), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetNativeVideoSize,
+            GetMinIdealVideoSize,
+            GetMaxIdealVideoSize,
+            SetVideoPosition,
+            GetVideoPosition,
+            GetAspectRatioMode,
+            SetAspectRatioMode,
+            SetVideoClippingWindow,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            RepaintVideo,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            RepaintVideo: 0,
+            DisplayModeChanged,
+            GetCurrentImage,
+            SetBorderColor,
+            GetBorderColor,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVMRWindowlessControl9 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_DirectDraw")]
+impl windows_core::imp::ProxyVtable for IVPBaseConfig_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn GetConnectInfo(this: *mut core::ffi::c_void, pdwnumconnectinfo: *mut u32, pddvpconnectinfo: *mut super::super::Graphics::DirectDraw::DDVIDEOPORTCONNECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVPBaseConfig", "GetConnectInfo", 3usize, |this| ((**(this as *const *const IVPBaseConfig_Vtbl)).GetConnectInfo)(this, pdwnumconnectinfo, pddvpconnectinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConnectInfo(this: *mut core::ffi::c_void, dwchosenentry: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVPBaseConfig", "SetConnectInfo", 4usize, |this| ((**(this as *const *const IVPBaseConfig_Vtbl)).SetConnectInfo)(this, dwchosenentry), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVPDataInfo(this: *mut core::ffi::c_void, pamvpdatainfo: *mut AMVPDATAINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVPBaseConfig", "GetVPDataInfo", 5usize, |this| ((**(this as *const *const IVPBaseConfig_Vtbl)).GetVPDataInfo)(this, pamvpdatainfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxPixelRate(this: *mut core::ffi::c_void, pamvpsize: *mut AMVPSIZE, pdwmaxpixelspersecond: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVPBaseConfig", "GetMaxPixelRate", 6usize, |this| ((**(this as *const *const IVPBaseConfig_Vtbl)).GetMaxPixelRate)(this, pamvpsize, pdwmaxpixelspersecond), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn InformVPInputFormats(this: *mut core::ffi::c_void, dwnumformats: u32, pddpixelformats: *mut super::super::Graphics::DirectDraw::DDPIXELFORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVPBaseConfig", "InformVPInputFormats", 7usize, |this| ((**(this as *const *const IVPBaseConfig_Vtbl)).InformVPInputFormats)(this, dwnumformats, pddpixelformats), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn GetVideoFormats(this: *mut core::ffi::c_void, pdwnumformats: *mut u32, pddpixelformats: *mut super::super::Graphics::DirectDraw::DDPIXELFORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVPBaseConfig", "GetVideoFormats", 8usize, |this| ((**(this as *const *const IVPBaseConfig_Vtbl)).GetVideoFormats)(this, pdwnumformats, pddpixelformats), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVideoFormat(this: *mut core::ffi::c_void, dwchosenentry: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVPBaseConfig", "SetVideoFormat", 9usize, |this| ((**(this as *const *const IVPBaseConfig_Vtbl)).SetVideoFormat)(this, dwchosenentry), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInvertPolarity(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVPBaseConfig", "SetInvertPolarity", 10usize, |this| ((**(this as *const *const IVPBaseConfig_Vtbl)).SetInvertPolarity)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectDraw")]
+        unsafe extern "system" fn GetOverlaySurface(this: *mut core::ffi::c_void, ppddoverlaysurface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVPBaseConfig", "GetOverlaySurface", 11usize, |this| ((**(this as *const *const IVPBaseConfig_Vtbl)).GetOverlaySurface)(this, ppddoverlaysurface), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDirectDrawKernelHandle(this: *mut core::ffi::c_void, dwddkernelhandle: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVPBaseConfig", "SetDirectDrawKernelHandle", 12usize, |this| ((**(this as *const *const IVPBaseConfig_Vtbl)).SetDirectDrawKernelHandle)(this, dwddkernelhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVideoPortID(this: *mut core::ffi::c_void, dwvideoportid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVPBaseConfig", "SetVideoPortID", 13usize, |this| ((**(this as *const *const IVPBaseConfig_Vtbl)).SetVideoPortID)(this, dwvideoportid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDDSurfaceKernelHandles(this: *mut core::ffi::c_void, chandles: u32, rgddkernelhandles: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVPBaseConfig", "SetDDSurfaceKernelHandles", 14usize, |this| ((**(this as *const *const IVPBaseConfig_Vtbl)).SetDDSurfaceKernelHandles)(this, chandles, rgddkernelhandles), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSurfaceParameters(this: *mut core::ffi::c_void, dwpitch: u32, dwxorigin: u32, dwyorigin: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVPBaseConfig", "SetSurfaceParameters", 15usize, |this| ((**(this as *const *const IVPBaseConfig_Vtbl)).SetSurfaceParameters)(this, dwpitch, dwxorigin, dwyorigin), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            GetConnectInfo,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            GetConnectInfo: 0,
+            SetConnectInfo,
+            GetVPDataInfo,
+            GetMaxPixelRate,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            InformVPInputFormats,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            InformVPInputFormats: 0,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            GetVideoFormats,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            GetVideoFormats: 0,
+            SetVideoFormat,
+            SetInvertPolarity,
+            #[cfg(feature = "Win32_Graphics_DirectDraw")]
+            GetOverlaySurface,
+            #[cfg(not(feature = "Win32_Graphics_DirectDraw"))]
+            GetOverlaySurface: 0,
+            SetDirectDrawKernelHandle,
+            SetVideoPortID,
+            SetDDSurfaceKernelHandles,
+            SetSurfaceParameters,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVPBaseConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVPBaseNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RenegotiateVPParameters(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVPBaseNotify", "RenegotiateVPParameters", 3usize, |this| ((**(this as *const *const IVPBaseNotify_Vtbl)).RenegotiateVPParameters)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RenegotiateVPParameters }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVPBaseNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_DirectDraw")]
+impl windows_core::imp::ProxyVtable for IVPConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsVPDecimationAllowed(this: *mut core::ffi::c_void, pbisdecimationallowed: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVPConfig", "IsVPDecimationAllowed", 16usize, |this| ((**(this as *const *const IVPConfig_Vtbl)).IsVPDecimationAllowed)(this, pbisdecimationallowed), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScalingFactors(this: *mut core::ffi::c_void, pamvpsize: *mut AMVPSIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVPConfig", "SetScalingFactors", 17usize, |this| ((**(this as *const *const IVPConfig_Vtbl)).SetScalingFactors)(this, pamvpsize), |result| Some(*result))
+        }
+        Self { base__: <IVPBaseConfig_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsVPDecimationAllowed, SetScalingFactors }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVPConfig as windows_core::Interface>::IID || <IVPBaseConfig_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVPManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetVideoPortIndex(this: *mut core::ffi::c_void, dwvideoportindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVPManager", "SetVideoPortIndex", 3usize, |this| ((**(this as *const *const IVPManager_Vtbl)).SetVideoPortIndex)(this, dwvideoportindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoPortIndex(this: *mut core::ffi::c_void, pdwvideoportindex: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVPManager", "GetVideoPortIndex", 4usize, |this| ((**(this as *const *const IVPManager_Vtbl)).GetVideoPortIndex)(this, pdwvideoportindex), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetVideoPortIndex, GetVideoPortIndex }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVPManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVPNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDeinterlaceMode(this: *mut core::ffi::c_void, mode: AMVP_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVPNotify", "SetDeinterlaceMode", 4usize, |this| ((**(this as *const *const IVPNotify_Vtbl)).SetDeinterlaceMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeinterlaceMode(this: *mut core::ffi::c_void, pmode: *mut AMVP_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVPNotify", "GetDeinterlaceMode", 5usize, |this| ((**(this as *const *const IVPNotify_Vtbl)).GetDeinterlaceMode)(this, pmode), |result| Some(*result))
+        }
+        Self { base__: <IVPBaseNotify_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetDeinterlaceMode, GetDeinterlaceMode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVPNotify as windows_core::Interface>::IID || <IVPBaseNotify_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVPNotify2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetVPSyncMaster(this: *mut core::ffi::c_void, bvpsyncmaster: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVPNotify2", "SetVPSyncMaster", 6usize, |this| ((**(this as *const *const IVPNotify2_Vtbl)).SetVPSyncMaster)(this, bvpsyncmaster), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVPSyncMaster(this: *mut core::ffi::c_void, pbvpsyncmaster: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVPNotify2", "GetVPSyncMaster", 7usize, |this| ((**(this as *const *const IVPNotify2_Vtbl)).GetVPSyncMaster)(this, pbvpsyncmaster), |result| Some(*result))
+        }
+        Self { base__: <IVPNotify_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetVPSyncMaster, GetVPSyncMaster }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVPNotify2 as windows_core::Interface>::IID || <IVPNotify_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_DirectDraw")]
+impl windows_core::imp::ProxyVtable for IVPVBIConfig_Vtbl {
+    const PROXY: Self = { Self { base__: <IVPBaseConfig_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVPVBIConfig as windows_core::Interface>::IID || <IVPBaseConfig_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVPVBINotify_Vtbl {
+    const PROXY: Self = { Self { base__: <IVPBaseNotify_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVPVBINotify as windows_core::Interface>::IID || <IVPBaseNotify_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVideoEncoder_Vtbl {
+    const PROXY: Self = { Self { base__: <IEncoderAPI_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoEncoder as windows_core::Interface>::IID || <IEncoderAPI_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVideoFrameStep_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Step(this: *mut core::ffi::c_void, dwframes: u32, pstepobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoFrameStep", "Step", 3usize, |this| ((**(this as *const *const IVideoFrameStep_Vtbl)).Step)(this, dwframes, pstepobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanStep(this: *mut core::ffi::c_void, bmultiple: i32, pstepobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoFrameStep", "CanStep", 4usize, |this| ((**(this as *const *const IVideoFrameStep_Vtbl)).CanStep)(this, bmultiple, pstepobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelStep(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoFrameStep", "CancelStep", 5usize, |this| ((**(this as *const *const IVideoFrameStep_Vtbl)).CancelStep)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Step, CanStep, CancelStep }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoFrameStep as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVideoProcAmp_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_BacklightCompensation(this: *mut core::ffi::c_void, pvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "get_BacklightCompensation", 3usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).get_BacklightCompensation)(this, pvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_BacklightCompensation(this: *mut core::ffi::c_void, value: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "put_BacklightCompensation", 4usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).put_BacklightCompensation)(this, value, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn getRange_BacklightCompensation(this: *mut core::ffi::c_void, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflag: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "getRange_BacklightCompensation", 5usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).getRange_BacklightCompensation)(this, pmin, pmax, psteppingdelta, pdefault, pcapsflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Brightness(this: *mut core::ffi::c_void, pvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "get_Brightness", 6usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).get_Brightness)(this, pvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_Brightness(this: *mut core::ffi::c_void, value: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "put_Brightness", 7usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).put_Brightness)(this, value, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn getRange_Brightness(this: *mut core::ffi::c_void, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflag: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "getRange_Brightness", 8usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).getRange_Brightness)(this, pmin, pmax, psteppingdelta, pdefault, pcapsflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_ColorEnable(this: *mut core::ffi::c_void, pvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "get_ColorEnable", 9usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).get_ColorEnable)(this, pvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_ColorEnable(this: *mut core::ffi::c_void, value: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "put_ColorEnable", 10usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).put_ColorEnable)(this, value, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn getRange_ColorEnable(this: *mut core::ffi::c_void, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflag: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "getRange_ColorEnable", 11usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).getRange_ColorEnable)(this, pmin, pmax, psteppingdelta, pdefault, pcapsflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Contrast(this: *mut core::ffi::c_void, pvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "get_Contrast", 12usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).get_Contrast)(this, pvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_Contrast(this: *mut core::ffi::c_void, value: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "put_Contrast", 13usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).put_Contrast)(this, value, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn getRange_Contrast(this: *mut core::ffi::c_void, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflag: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "getRange_Contrast", 14usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).getRange_Contrast)(this, pmin, pmax, psteppingdelta, pdefault, pcapsflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Gamma(this: *mut core::ffi::c_void, pvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "get_Gamma", 15usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).get_Gamma)(this, pvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_Gamma(this: *mut core::ffi::c_void, value: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "put_Gamma", 16usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).put_Gamma)(this, value, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn getRange_Gamma(this: *mut core::ffi::c_void, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflag: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "getRange_Gamma", 17usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).getRange_Gamma)(this, pmin, pmax, psteppingdelta, pdefault, pcapsflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Saturation(this: *mut core::ffi::c_void, pvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "get_Saturation", 18usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).get_Saturation)(this, pvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_Saturation(this: *mut core::ffi::c_void, value: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "put_Saturation", 19usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).put_Saturation)(this, value, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn getRange_Saturation(this: *mut core::ffi::c_void, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflag: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "getRange_Saturation", 20usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).getRange_Saturation)(this, pmin, pmax, psteppingdelta, pdefault, pcapsflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Sharpness(this: *mut core::ffi::c_void, pvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "get_Sharpness", 21usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).get_Sharpness)(this, pvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_Sharpness(this: *mut core::ffi::c_void, value: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "put_Sharpness", 22usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).put_Sharpness)(this, value, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn getRange_Sharpness(this: *mut core::ffi::c_void, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflag: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "getRange_Sharpness", 23usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).getRange_Sharpness)(this, pmin, pmax, psteppingdelta, pdefault, pcapsflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_WhiteBalance(this: *mut core::ffi::c_void, pvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "get_WhiteBalance", 24usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).get_WhiteBalance)(this, pvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_WhiteBalance(this: *mut core::ffi::c_void, value: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "put_WhiteBalance", 25usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).put_WhiteBalance)(this, value, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn getRange_WhiteBalance(this: *mut core::ffi::c_void, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflag: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "getRange_WhiteBalance", 26usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).getRange_WhiteBalance)(this, pmin, pmax, psteppingdelta, pdefault, pcapsflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Gain(this: *mut core::ffi::c_void, pvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "get_Gain", 27usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).get_Gain)(this, pvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_Gain(this: *mut core::ffi::c_void, value: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "put_Gain", 28usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).put_Gain)(this, value, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn getRange_Gain(this: *mut core::ffi::c_void, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflag: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "getRange_Gain", 29usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).getRange_Gain)(this, pmin, pmax, psteppingdelta, pdefault, pcapsflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Hue(this: *mut core::ffi::c_void, pvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "get_Hue", 30usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).get_Hue)(this, pvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_Hue(this: *mut core::ffi::c_void, value: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "put_Hue", 31usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).put_Hue)(this, value, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn getRange_Hue(this: *mut core::ffi::c_void, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflag: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "getRange_Hue", 32usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).getRange_Hue)(this, pmin, pmax, psteppingdelta, pdefault, pcapsflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_DigitalMultiplier(this: *mut core::ffi::c_void, pvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "get_DigitalMultiplier", 33usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).get_DigitalMultiplier)(this, pvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_DigitalMultiplier(this: *mut core::ffi::c_void, value: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "put_DigitalMultiplier", 34usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).put_DigitalMultiplier)(this, value, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn getRange_DigitalMultiplier(this: *mut core::ffi::c_void, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflag: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "getRange_DigitalMultiplier", 35usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).getRange_DigitalMultiplier)(this, pmin, pmax, psteppingdelta, pdefault, pcapsflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_PowerlineFrequency(this: *mut core::ffi::c_void, pvalue: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "get_PowerlineFrequency", 36usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).get_PowerlineFrequency)(this, pvalue, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_PowerlineFrequency(this: *mut core::ffi::c_void, value: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "put_PowerlineFrequency", 37usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).put_PowerlineFrequency)(this, value, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn getRange_PowerlineFrequency(this: *mut core::ffi::c_void, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflag: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "getRange_PowerlineFrequency", 38usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).getRange_PowerlineFrequency)(this, pmin, pmax, psteppingdelta, pdefault, pcapsflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_WhiteBalanceComponent(this: *mut core::ffi::c_void, pvalue1: *mut i32, pvalue2: *mut i32, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "get_WhiteBalanceComponent", 39usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).get_WhiteBalanceComponent)(this, pvalue1, pvalue2, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_WhiteBalanceComponent(this: *mut core::ffi::c_void, value1: i32, value2: i32, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "put_WhiteBalanceComponent", 40usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).put_WhiteBalanceComponent)(this, value1, value2, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn getRange_WhiteBalanceComponent(this: *mut core::ffi::c_void, pmin: *mut i32, pmax: *mut i32, psteppingdelta: *mut i32, pdefault: *mut i32, pcapsflag: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoProcAmp", "getRange_WhiteBalanceComponent", 41usize, |this| ((**(this as *const *const IVideoProcAmp_Vtbl)).getRange_WhiteBalanceComponent)(this, pmin, pmax, psteppingdelta, pdefault, pcapsflag), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            get_BacklightCompensation,
+            put_BacklightCompensation,
+            getRange_BacklightCompensation,
+            get_Brightness,
+            put_Brightness,
+            getRange_Brightness,
+            get_ColorEnable,
+            put_ColorEnable,
+            getRange_ColorEnable,
+            get_Contrast,
+            put_Contrast,
+            getRange_Contrast,
+            get_Gamma,
+            put_Gamma,
+            getRange_Gamma,
+            get_Saturation,
+            put_Saturation,
+            getRange_Saturation,
+            get_Sharpness,
+            put_Sharpness,
+            getRange_Sharpness,
+            get_WhiteBalance,
+            put_WhiteBalance,
+            getRange_WhiteBalance,
+            get_Gain,
+            put_Gain,
+            getRange_Gain,
+            get_Hue,
+            put_Hue,
+            getRange_Hue,
+            get_DigitalMultiplier,
+            put_DigitalMultiplier,
+            getRange_DigitalMultiplier,
+            get_PowerlineFrequency,
+            put_PowerlineFrequency,
+            getRange_PowerlineFrequency,
+            get_WhiteBalanceComponent,
+            put_WhiteBalanceComponent,
+            getRange_WhiteBalanceComponent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoProcAmp as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_WindowsAndMessaging"))]
+impl windows_core::imp::ProxyVtable for IVideoWindow_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetCaption(this: *mut core::ffi::c_void, strcaption: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "SetCaption", 7usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).SetCaption)(this, strcaption), |result| Some(*result))
+        }
+        unsafe extern "system" fn Caption(this: *mut core::ffi::c_void, strcaption: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "Caption", 8usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).Caption)(this, strcaption), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWindowStyle(this: *mut core::ffi::c_void, windowstyle: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "SetWindowStyle", 9usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).SetWindowStyle)(this, windowstyle), |result| Some(*result))
+        }
+        unsafe extern "system" fn WindowStyle(this: *mut core::ffi::c_void, windowstyle: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "WindowStyle", 10usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).WindowStyle)(this, windowstyle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWindowStyleEx(this: *mut core::ffi::c_void, windowstyleex: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "SetWindowStyleEx", 11usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).SetWindowStyleEx)(this, windowstyleex), |result| Some(*result))
+        }
+        unsafe extern "system" fn WindowStyleEx(this: *mut core::ffi::c_void, windowstyleex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "WindowStyleEx", 12usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).WindowStyleEx)(this, windowstyleex), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoShow(this: *mut core::ffi::c_void, autoshow: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "SetAutoShow", 13usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).SetAutoShow)(this, autoshow), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoShow(this: *mut core::ffi::c_void, autoshow: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "AutoShow", 14usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).AutoShow)(this, autoshow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWindowState(this: *mut core::ffi::c_void, windowstate: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "SetWindowState", 15usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).SetWindowState)(this, windowstate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn WindowState(this: *mut core::ffi::c_void, windowstate: *mut super::super::UI::WindowsAndMessaging::SHOW_WINDOW_CMD) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "WindowState", 16usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).WindowState)(this, windowstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackgroundPalette(this: *mut core::ffi::c_void, backgroundpalette: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "SetBackgroundPalette", 17usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).SetBackgroundPalette)(this, backgroundpalette), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackgroundPalette(this: *mut core::ffi::c_void, pbackgroundpalette: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "BackgroundPalette", 18usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).BackgroundPalette)(this, pbackgroundpalette), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVisible(this: *mut core::ffi::c_void, visible: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "SetVisible", 19usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).SetVisible)(this, visible), |result| Some(*result))
+        }
+        unsafe extern "system" fn Visible(this: *mut core::ffi::c_void, pvisible: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "Visible", 20usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).Visible)(this, pvisible), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLeft(this: *mut core::ffi::c_void, left: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "SetLeft", 21usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).SetLeft)(this, left), |result| Some(*result))
+        }
+        unsafe extern "system" fn Left(this: *mut core::ffi::c_void, pleft: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "Left", 22usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).Left)(this, pleft), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWidth(this: *mut core::ffi::c_void, width: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "SetWidth", 23usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).SetWidth)(this, width), |result| Some(*result))
+        }
+        unsafe extern "system" fn Width(this: *mut core::ffi::c_void, pwidth: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "Width", 24usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).Width)(this, pwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTop(this: *mut core::ffi::c_void, top: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "SetTop", 25usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).SetTop)(this, top), |result| Some(*result))
+        }
+        unsafe extern "system" fn Top(this: *mut core::ffi::c_void, ptop: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "Top", 26usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).Top)(this, ptop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHeight(this: *mut core::ffi::c_void, height: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "SetHeight", 27usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).SetHeight)(this, height), |result| Some(*result))
+        }
+        unsafe extern "system" fn Height(this: *mut core::ffi::c_void, pheight: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "Height", 28usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).Height)(this, pheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOwner(this: *mut core::ffi::c_void, owner: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "SetOwner", 29usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).SetOwner)(this, owner), |result| Some(*result))
+        }
+        unsafe extern "system" fn Owner(this: *mut core::ffi::c_void, owner: *mut isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "Owner", 30usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).Owner)(this, owner), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMessageDrain(this: *mut core::ffi::c_void, drain: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "SetMessageDrain", 31usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).SetMessageDrain)(this, drain), |result| Some(*result))
+        }
+        unsafe extern "system" fn MessageDrain(this: *mut core::ffi::c_void, drain: *mut isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "MessageDrain", 32usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).MessageDrain)(this, drain), |result| Some(*result))
+        }
+        unsafe extern "system" fn BorderColor(this: *mut core::ffi::c_void, color: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "BorderColor", 33usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).BorderColor)(this, color), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBorderColor(this: *mut core::ffi::c_void, color: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "SetBorderColor", 34usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).SetBorderColor)(this, color), |result| Some(*result))
+        }
+        unsafe extern "system" fn FullScreenMode(this: *mut core::ffi::c_void, fullscreenmode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "FullScreenMode", 35usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).FullScreenMode)(this, fullscreenmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFullScreenMode(this: *mut core::ffi::c_void, fullscreenmode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "SetFullScreenMode", 36usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).SetFullScreenMode)(this, fullscreenmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWindowForeground(this: *mut core::ffi::c_void, focus: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "SetWindowForeground", 37usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).SetWindowForeground)(this, focus), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyOwnerMessage(this: *mut core::ffi::c_void, hwnd: isize, umsg: i32, wparam: isize, lparam: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "NotifyOwnerMessage", 38usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).NotifyOwnerMessage)(this, hwnd, umsg, wparam, lparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWindowPosition(this: *mut core::ffi::c_void, left: i32, top: i32, width: i32, height: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "SetWindowPosition", 39usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).SetWindowPosition)(this, left, top, width, height), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWindowPosition(this: *mut core::ffi::c_void, pleft: *mut i32, ptop: *mut i32, pwidth: *mut i32, pheight: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "GetWindowPosition", 40usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).GetWindowPosition)(this, pleft, ptop, pwidth, pheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMinIdealImageSize(this: *mut core::ffi::c_void, pwidth: *mut i32, pheight: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "GetMinIdealImageSize", 41usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).GetMinIdealImageSize)(this, pwidth, pheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxIdealImageSize(this: *mut core::ffi::c_void, pwidth: *mut i32, pheight: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "GetMaxIdealImageSize", 42usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).GetMaxIdealImageSize)(this, pwidth, pheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRestorePosition(this: *mut core::ffi::c_void, pleft: *mut i32, ptop: *mut i32, pwidth: *mut i32, pheight: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "GetRestorePosition", 43usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).GetRestorePosition)(this, pleft, ptop, pwidth, pheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn HideCursor(this: *mut core::ffi::c_void, hidecursor: OA_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "HideCursor", 44usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).HideCursor)(this, hidecursor), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCursorHidden(this: *mut core::ffi::c_void, cursorhidden: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVideoWindow", "IsCursorHidden", 45usize, |this| ((**(this as *const *const IVideoWindow_Vtbl)).IsCursorHidden)(this, cursorhidden), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetCaption,
+            Caption,
+            SetWindowStyle,
+            WindowStyle,
+            SetWindowStyleEx,
+            WindowStyleEx,
+            SetAutoShow,
+            AutoShow,
+            SetWindowState,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            WindowState,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            WindowState: 0,
+            SetBackgroundPalette,
+            BackgroundPalette,
+            SetVisible,
+            Visible,
+            SetLeft,
+            Left,
+            SetWidth,
+            Width,
+            SetTop,
+            Top,
+            SetHeight,
+            Height,
+            SetOwner,
+            Owner,
+            SetMessageDrain,
+            MessageDrain,
+            BorderColor,
+            SetBorderColor,
+            FullScreenMode,
+            SetFullScreenMode,
+            SetWindowForeground,
+            NotifyOwnerMessage,
+            SetWindowPosition,
+            GetWindowPosition,
+            GetMinIdealImageSize,
+            GetMaxIdealImageSize,
+            GetRestorePosition,
+            HideCursor,
+            IsCursorHidden,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVideoWindow as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Media_MediaFoundation", feature = "Win32_Media_WindowsMediaFormat"))]
+impl windows_core::imp::ProxyVtable for IWMCodecAMVideoAccelerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAcceleratorInterface(this: *mut core::ffi::c_void, piamva: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMCodecAMVideoAccelerator", "SetAcceleratorInterface", 3usize, |this| ((**(this as *const *const IWMCodecAMVideoAccelerator_Vtbl)).SetAcceleratorInterface)(this, piamva), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn NegotiateConnection(this: *mut core::ffi::c_void, pmediatype: *const super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMCodecAMVideoAccelerator", "NegotiateConnection", 4usize, |this| ((**(this as *const *const IWMCodecAMVideoAccelerator_Vtbl)).NegotiateConnection)(this, pmediatype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_WindowsMediaFormat")]
+        unsafe extern "system" fn SetPlayerNotify(this: *mut core::ffi::c_void, phook: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMCodecAMVideoAccelerator", "SetPlayerNotify", 5usize, |this| ((**(this as *const *const IWMCodecAMVideoAccelerator_Vtbl)).SetPlayerNotify)(this, phook), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetAcceleratorInterface,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            NegotiateConnection,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            NegotiateConnection: 0,
+            #[cfg(feature = "Win32_Media_WindowsMediaFormat")]
+            SetPlayerNotify,
+            #[cfg(not(feature = "Win32_Media_WindowsMediaFormat"))]
+            SetPlayerNotify: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMCodecAMVideoAccelerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Media_MediaFoundation", feature = "Win32_Media_WindowsMediaFormat"))]
+impl windows_core::imp::ProxyVtable for IWMCodecVideoAccelerator_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn NegotiateConnection(this: *mut core::ffi::c_void, piamva: *mut core::ffi::c_void, pmediatype: *const super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMCodecVideoAccelerator", "NegotiateConnection", 3usize, |this| ((**(this as *const *const IWMCodecVideoAccelerator_Vtbl)).NegotiateConnection)(this, piamva, pmediatype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_WindowsMediaFormat")]
+        unsafe extern "system" fn SetPlayerNotify(this: *mut core::ffi::c_void, phook: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMCodecVideoAccelerator", "SetPlayerNotify", 4usize, |this| ((**(this as *const *const IWMCodecVideoAccelerator_Vtbl)).SetPlayerNotify)(this, phook), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            NegotiateConnection,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            NegotiateConnection: 0,
+            #[cfg(feature = "Win32_Media_WindowsMediaFormat")]
+            SetPlayerNotify,
+            #[cfg(not(feature = "Win32_Media_WindowsMediaFormat"))]
+            SetPlayerNotify: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMCodecVideoAccelerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -358,6 +358,7 @@ pub struct DMO_ENUM_FLAGS(pub i32);
 impl windows_core::TypeKind for DMO_ENUM_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DMO_ENUM_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DMO_ENUM_FLAGS").field(&self.0).finish()
@@ -369,6 +370,7 @@ pub struct DMO_REGISTER_FLAGS(pub i32);
 impl windows_core::TypeKind for DMO_REGISTER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DMO_REGISTER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DMO_REGISTER_FLAGS").field(&self.0).finish()
@@ -380,6 +382,7 @@ pub struct _DMO_INPLACE_PROCESS_FLAGS(pub i32);
 impl windows_core::TypeKind for _DMO_INPLACE_PROCESS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _DMO_INPLACE_PROCESS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_DMO_INPLACE_PROCESS_FLAGS").field(&self.0).finish()
@@ -391,6 +394,7 @@ pub struct _DMO_INPUT_DATA_BUFFER_FLAGS(pub i32);
 impl windows_core::TypeKind for _DMO_INPUT_DATA_BUFFER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _DMO_INPUT_DATA_BUFFER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_DMO_INPUT_DATA_BUFFER_FLAGS").field(&self.0).finish()
@@ -402,6 +406,7 @@ pub struct _DMO_INPUT_STATUS_FLAGS(pub i32);
 impl windows_core::TypeKind for _DMO_INPUT_STATUS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _DMO_INPUT_STATUS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_DMO_INPUT_STATUS_FLAGS").field(&self.0).finish()
@@ -413,6 +418,7 @@ pub struct _DMO_INPUT_STREAM_INFO_FLAGS(pub i32);
 impl windows_core::TypeKind for _DMO_INPUT_STREAM_INFO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _DMO_INPUT_STREAM_INFO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_DMO_INPUT_STREAM_INFO_FLAGS").field(&self.0).finish()
@@ -424,6 +430,7 @@ pub struct _DMO_OUTPUT_DATA_BUFFER_FLAGS(pub i32);
 impl windows_core::TypeKind for _DMO_OUTPUT_DATA_BUFFER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _DMO_OUTPUT_DATA_BUFFER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_DMO_OUTPUT_DATA_BUFFER_FLAGS").field(&self.0).finish()
@@ -435,6 +442,7 @@ pub struct _DMO_OUTPUT_STREAM_INFO_FLAGS(pub i32);
 impl windows_core::TypeKind for _DMO_OUTPUT_STREAM_INFO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _DMO_OUTPUT_STREAM_INFO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_DMO_OUTPUT_STREAM_INFO_FLAGS").field(&self.0).finish()
@@ -446,6 +454,7 @@ pub struct _DMO_PROCESS_OUTPUT_FLAGS(pub i32);
 impl windows_core::TypeKind for _DMO_PROCESS_OUTPUT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _DMO_PROCESS_OUTPUT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_DMO_PROCESS_OUTPUT_FLAGS").field(&self.0).finish()
@@ -457,6 +466,7 @@ pub struct _DMO_QUALITY_STATUS_FLAGS(pub i32);
 impl windows_core::TypeKind for _DMO_QUALITY_STATUS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _DMO_QUALITY_STATUS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_DMO_QUALITY_STATUS_FLAGS").field(&self.0).finish()
@@ -468,6 +478,7 @@ pub struct _DMO_SET_TYPE_FLAGS(pub i32);
 impl windows_core::TypeKind for _DMO_SET_TYPE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _DMO_SET_TYPE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_DMO_SET_TYPE_FLAGS").field(&self.0).finish()
@@ -479,13 +490,15 @@ pub struct _DMO_VIDEO_OUTPUT_STREAM_FLAGS(pub i32);
 impl windows_core::TypeKind for _DMO_VIDEO_OUTPUT_STREAM_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _DMO_VIDEO_OUTPUT_STREAM_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_DMO_VIDEO_OUTPUT_STREAM_FLAGS").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DMO_MEDIA_TYPE {
     pub majortype: windows_core::GUID,
     pub subtype: windows_core::GUID,
@@ -511,7 +524,8 @@ impl Default for DMO_MEDIA_TYPE {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DMO_OUTPUT_DATA_BUFFER {
     pub pBuffer: core::mem::ManuallyDrop<Option<IMediaBuffer>>,
     pub dwStatus: u32,
@@ -532,7 +546,8 @@ impl Default for DMO_OUTPUT_DATA_BUFFER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DMO_PARTIAL_MEDIATYPE {
     pub r#type: windows_core::GUID,
     pub subtype: windows_core::GUID,
@@ -547,3 +562,5 @@ impl Default for DMO_PARTIAL_MEDIATYPE {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,197 @@
+impl windows_core::imp::ProxyVtable for IDMOQualityControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetNow(this: *mut core::ffi::c_void, rtnow: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDMOQualityControl", "SetNow", 3usize, |this| ((**(this as *const *const IDMOQualityControl_Vtbl)).SetNow)(this, rtnow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatus(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDMOQualityControl", "SetStatus", 4usize, |this| ((**(this as *const *const IDMOQualityControl_Vtbl)).SetStatus)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDMOQualityControl", "GetStatus", 5usize, |this| ((**(this as *const *const IDMOQualityControl_Vtbl)).GetStatus)(this, pdwflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetNow, SetStatus, GetStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDMOQualityControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDMOVideoOutputOptimizations_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryOperationModePreferences(this: *mut core::ffi::c_void, uloutputstreamindex: u32, pdwrequestedcapabilities: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDMOVideoOutputOptimizations", "QueryOperationModePreferences", 3usize, |this| ((**(this as *const *const IDMOVideoOutputOptimizations_Vtbl)).QueryOperationModePreferences)(this, uloutputstreamindex, pdwrequestedcapabilities), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOperationMode(this: *mut core::ffi::c_void, uloutputstreamindex: u32, dwenabledfeatures: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDMOVideoOutputOptimizations", "SetOperationMode", 4usize, |this| ((**(this as *const *const IDMOVideoOutputOptimizations_Vtbl)).SetOperationMode)(this, uloutputstreamindex, dwenabledfeatures), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentOperationMode(this: *mut core::ffi::c_void, uloutputstreamindex: u32, pdwenabledfeatures: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDMOVideoOutputOptimizations", "GetCurrentOperationMode", 5usize, |this| ((**(this as *const *const IDMOVideoOutputOptimizations_Vtbl)).GetCurrentOperationMode)(this, uloutputstreamindex, pdwenabledfeatures), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentSampleRequirements(this: *mut core::ffi::c_void, uloutputstreamindex: u32, pdwrequestedfeatures: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDMOVideoOutputOptimizations", "GetCurrentSampleRequirements", 6usize, |this| ((**(this as *const *const IDMOVideoOutputOptimizations_Vtbl)).GetCurrentSampleRequirements)(this, uloutputstreamindex, pdwrequestedfeatures), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            QueryOperationModePreferences,
+            SetOperationMode,
+            GetCurrentOperationMode,
+            GetCurrentSampleRequirements,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDMOVideoOutputOptimizations as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumDMO_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, citemstofetch: u32, pclsid: *mut windows_core::GUID, names: *mut windows_core::PWSTR, pcitemsfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDMO", "Next", 3usize, |this| ((**(this as *const *const IEnumDMO_Vtbl)).Next)(this, citemstofetch, pclsid, names, pcitemsfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, citemstoskip: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDMO", "Skip", 4usize, |this| ((**(this as *const *const IEnumDMO_Vtbl)).Skip)(this, citemstoskip), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDMO", "Reset", 5usize, |this| ((**(this as *const *const IEnumDMO_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDMO", "Clone", 6usize, |this| ((**(this as *const *const IEnumDMO_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumDMO as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaBuffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetLength(this: *mut core::ffi::c_void, cblength: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBuffer", "SetLength", 3usize, |this| ((**(this as *const *const IMediaBuffer_Vtbl)).SetLength)(this, cblength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxLength(this: *mut core::ffi::c_void, pcbmaxlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBuffer", "GetMaxLength", 4usize, |this| ((**(this as *const *const IMediaBuffer_Vtbl)).GetMaxLength)(this, pcbmaxlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBufferAndLength(this: *mut core::ffi::c_void, ppbuffer: *mut *mut u8, pcblength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaBuffer", "GetBufferAndLength", 5usize, |this| ((**(this as *const *const IMediaBuffer_Vtbl)).GetBufferAndLength)(this, ppbuffer, pcblength), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetLength, GetMaxLength, GetBufferAndLength }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaBuffer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStreamCount(this: *mut core::ffi::c_void, pcinputstreams: *mut u32, pcoutputstreams: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaObject", "GetStreamCount", 3usize, |this| ((**(this as *const *const IMediaObject_Vtbl)).GetStreamCount)(this, pcinputstreams, pcoutputstreams), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputStreamInfo(this: *mut core::ffi::c_void, dwinputstreamindex: u32, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaObject", "GetInputStreamInfo", 4usize, |this| ((**(this as *const *const IMediaObject_Vtbl)).GetInputStreamInfo)(this, dwinputstreamindex, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputStreamInfo(this: *mut core::ffi::c_void, dwoutputstreamindex: u32, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaObject", "GetOutputStreamInfo", 5usize, |this| ((**(this as *const *const IMediaObject_Vtbl)).GetOutputStreamInfo)(this, dwoutputstreamindex, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputType(this: *mut core::ffi::c_void, dwinputstreamindex: u32, dwtypeindex: u32, pmt: *mut DMO_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaObject", "GetInputType", 6usize, |this| ((**(this as *const *const IMediaObject_Vtbl)).GetInputType)(this, dwinputstreamindex, dwtypeindex, pmt), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputType(this: *mut core::ffi::c_void, dwoutputstreamindex: u32, dwtypeindex: u32, pmt: *mut DMO_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaObject", "GetOutputType", 7usize, |this| ((**(this as *const *const IMediaObject_Vtbl)).GetOutputType)(this, dwoutputstreamindex, dwtypeindex, pmt), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInputType(this: *mut core::ffi::c_void, dwinputstreamindex: u32, pmt: *const DMO_MEDIA_TYPE, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaObject", "SetInputType", 8usize, |this| ((**(this as *const *const IMediaObject_Vtbl)).SetInputType)(this, dwinputstreamindex, pmt, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputType(this: *mut core::ffi::c_void, dwoutputstreamindex: u32, pmt: *const DMO_MEDIA_TYPE, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaObject", "SetOutputType", 9usize, |this| ((**(this as *const *const IMediaObject_Vtbl)).SetOutputType)(this, dwoutputstreamindex, pmt, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputCurrentType(this: *mut core::ffi::c_void, dwinputstreamindex: u32, pmt: *mut DMO_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaObject", "GetInputCurrentType", 10usize, |this| ((**(this as *const *const IMediaObject_Vtbl)).GetInputCurrentType)(this, dwinputstreamindex, pmt), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputCurrentType(this: *mut core::ffi::c_void, dwoutputstreamindex: u32, pmt: *mut DMO_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaObject", "GetOutputCurrentType", 11usize, |this| ((**(this as *const *const IMediaObject_Vtbl)).GetOutputCurrentType)(this, dwoutputstreamindex, pmt), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputSizeInfo(this: *mut core::ffi::c_void, dwinputstreamindex: u32, pcbsize: *mut u32, pcbmaxlookahead: *mut u32, pcbalignment: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaObject", "GetInputSizeInfo", 12usize, |this| ((**(this as *const *const IMediaObject_Vtbl)).GetInputSizeInfo)(this, dwinputstreamindex, pcbsize, pcbmaxlookahead, pcbalignment), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputSizeInfo(this: *mut core::ffi::c_void, dwoutputstreamindex: u32, pcbsize: *mut u32, pcbalignment: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaObject", "GetOutputSizeInfo", 13usize, |this| ((**(this as *const *const IMediaObject_Vtbl)).GetOutputSizeInfo)(this, dwoutputstreamindex, pcbsize, pcbalignment), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputMaxLatency(this: *mut core::ffi::c_void, dwinputstreamindex: u32, prtmaxlatency: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaObject", "GetInputMaxLatency", 14usize, |this| ((**(this as *const *const IMediaObject_Vtbl)).GetInputMaxLatency)(this, dwinputstreamindex, prtmaxlatency), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInputMaxLatency(this: *mut core::ffi::c_void, dwinputstreamindex: u32, rtmaxlatency: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaObject", "SetInputMaxLatency", 15usize, |this| ((**(this as *const *const IMediaObject_Vtbl)).SetInputMaxLatency)(this, dwinputstreamindex, rtmaxlatency), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flush(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaObject", "Flush", 16usize, |this| ((**(this as *const *const IMediaObject_Vtbl)).Flush)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Discontinuity(this: *mut core::ffi::c_void, dwinputstreamindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaObject", "Discontinuity", 17usize, |this| ((**(this as *const *const IMediaObject_Vtbl)).Discontinuity)(this, dwinputstreamindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllocateStreamingResources(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaObject", "AllocateStreamingResources", 18usize, |this| ((**(this as *const *const IMediaObject_Vtbl)).AllocateStreamingResources)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreeStreamingResources(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaObject", "FreeStreamingResources", 19usize, |this| ((**(this as *const *const IMediaObject_Vtbl)).FreeStreamingResources)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputStatus(this: *mut core::ffi::c_void, dwinputstreamindex: u32, dwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaObject", "GetInputStatus", 20usize, |this| ((**(this as *const *const IMediaObject_Vtbl)).GetInputStatus)(this, dwinputstreamindex, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessInput(this: *mut core::ffi::c_void, dwinputstreamindex: u32, pbuffer: *mut core::ffi::c_void, dwflags: u32, rttimestamp: i64, rttimelength: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaObject", "ProcessInput", 21usize, |this| ((**(this as *const *const IMediaObject_Vtbl)).ProcessInput)(this, dwinputstreamindex, pbuffer, dwflags, rttimestamp, rttimelength), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessOutput(this: *mut core::ffi::c_void, dwflags: u32, coutputbuffercount: u32, poutputbuffers: *mut DMO_OUTPUT_DATA_BUFFER, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaObject", "ProcessOutput", 22usize, |this| ((**(this as *const *const IMediaObject_Vtbl)).ProcessOutput)(this, dwflags, coutputbuffercount, poutputbuffers, pdwstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn Lock(this: *mut core::ffi::c_void, block: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaObject", "Lock", 23usize, |this| ((**(this as *const *const IMediaObject_Vtbl)).Lock)(this, block), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetStreamCount,
+            GetInputStreamInfo,
+            GetOutputStreamInfo,
+            GetInputType,
+            GetOutputType,
+            SetInputType,
+            SetOutputType,
+            GetInputCurrentType,
+            GetOutputCurrentType,
+            GetInputSizeInfo,
+            GetOutputSizeInfo,
+            GetInputMaxLatency,
+            SetInputMaxLatency,
+            Flush,
+            Discontinuity,
+            AllocateStreamingResources,
+            FreeStreamingResources,
+            GetInputStatus,
+            ProcessInput,
+            ProcessOutput,
+            Lock,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaObjectInPlace_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Process(this: *mut core::ffi::c_void, ulsize: u32, pdata: *mut u8, reftimestart: i64, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaObjectInPlace", "Process", 3usize, |this| ((**(this as *const *const IMediaObjectInPlace_Vtbl)).Process)(this, ulsize, pdata, reftimestart, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppmediaobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaObjectInPlace", "Clone", 4usize, |this| ((**(this as *const *const IMediaObjectInPlace_Vtbl)).Clone)(this, ppmediaobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLatency(this: *mut core::ffi::c_void, platencytime: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaObjectInPlace", "GetLatency", 5usize, |this| ((**(this as *const *const IMediaObjectInPlace_Vtbl)).GetLatency)(this, platencytime), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Process, Clone, GetLatency }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaObjectInPlace as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -0,0 +1,606 @@
+impl windows_core::imp::ProxyVtable for IKsAggregateControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn KsAddAggregate(this: *mut core::ffi::c_void, aggregateclass: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsAggregateControl", "KsAddAggregate", 3usize, |this| ((**(this as *const *const IKsAggregateControl_Vtbl)).KsAddAggregate)(this, aggregateclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn KsRemoveAggregate(this: *mut core::ffi::c_void, aggregateclass: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsAggregateControl", "KsRemoveAggregate", 4usize, |this| ((**(this as *const *const IKsAggregateControl_Vtbl)).KsRemoveAggregate)(this, aggregateclass), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, KsAddAggregate, KsRemoveAggregate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKsAggregateControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKsAllocator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn KsGetAllocatorHandle(this: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            windows_core::imp::proxy_call(this, "IKsAllocator", "KsGetAllocatorHandle", 3usize, |this| ((**(this as *const *const IKsAllocator_Vtbl)).KsGetAllocatorHandle)(this), |_| None)
+        }
+        unsafe extern "system" fn KsGetAllocatorMode(this: *mut core::ffi::c_void) -> KSALLOCATORMODE {
+            windows_core::imp::proxy_call(this, "IKsAllocator", "KsGetAllocatorMode", 4usize, |this| ((**(this as *const *const IKsAllocator_Vtbl)).KsGetAllocatorMode)(this), |_| None)
+        }
+        unsafe extern "system" fn KsGetAllocatorStatus(this: *mut core::ffi::c_void, allocatorstatus: *mut KSSTREAMALLOCATOR_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsAllocator", "KsGetAllocatorStatus", 5usize, |this| ((**(this as *const *const IKsAllocator_Vtbl)).KsGetAllocatorStatus)(this, allocatorstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn KsSetAllocatorMode(this: *mut core::ffi::c_void, mode: KSALLOCATORMODE) {
+            windows_core::imp::proxy_call(this, "IKsAllocator", "KsSetAllocatorMode", 6usize, |this| ((**(this as *const *const IKsAllocator_Vtbl)).KsSetAllocatorMode)(this, mode), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            KsGetAllocatorHandle,
+            KsGetAllocatorMode,
+            KsGetAllocatorStatus,
+            KsSetAllocatorMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKsAllocator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKsAllocatorEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn KsGetProperties(this: *mut core::ffi::c_void) -> *mut ALLOCATOR_PROPERTIES_EX {
+            windows_core::imp::proxy_call(this, "IKsAllocatorEx", "KsGetProperties", 7usize, |this| ((**(this as *const *const IKsAllocatorEx_Vtbl)).KsGetProperties)(this), |_| None)
+        }
+        unsafe extern "system" fn KsSetProperties(this: *mut core::ffi::c_void, param0: *const ALLOCATOR_PROPERTIES_EX) {
+            windows_core::imp::proxy_call(this, "IKsAllocatorEx", "KsSetProperties", 8usize, |this| ((**(this as *const *const IKsAllocatorEx_Vtbl)).KsSetProperties)(this, param0), |_| None)
+        }
+        unsafe extern "system" fn KsSetAllocatorHandle(this: *mut core::ffi::c_void, allocatorhandle: super::super::Foundation::HANDLE) {
+            windows_core::imp::proxy_call(this, "IKsAllocatorEx", "KsSetAllocatorHandle", 9usize, |this| ((**(this as *const *const IKsAllocatorEx_Vtbl)).KsSetAllocatorHandle)(this, allocatorhandle), |_| None)
+        }
+        unsafe extern "system" fn KsCreateAllocatorAndGetHandle(this: *mut core::ffi::c_void, kspin: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            windows_core::imp::proxy_call(this, "IKsAllocatorEx", "KsCreateAllocatorAndGetHandle", 10usize, |this| ((**(this as *const *const IKsAllocatorEx_Vtbl)).KsCreateAllocatorAndGetHandle)(this, kspin), |_| None)
+        }
+        Self {
+            base__: <IKsAllocator_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            KsGetProperties,
+            KsSetProperties,
+            KsSetAllocatorHandle,
+            KsCreateAllocatorAndGetHandle,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKsAllocatorEx as windows_core::Interface>::IID || <IKsAllocator_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKsClockPropertySet_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn KsGetTime(this: *mut core::ffi::c_void, time: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsClockPropertySet", "KsGetTime", 3usize, |this| ((**(this as *const *const IKsClockPropertySet_Vtbl)).KsGetTime)(this, time), |result| Some(*result))
+        }
+        unsafe extern "system" fn KsSetTime(this: *mut core::ffi::c_void, time: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsClockPropertySet", "KsSetTime", 4usize, |this| ((**(this as *const *const IKsClockPropertySet_Vtbl)).KsSetTime)(this, time), |result| Some(*result))
+        }
+        unsafe extern "system" fn KsGetPhysicalTime(this: *mut core::ffi::c_void, time: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsClockPropertySet", "KsGetPhysicalTime", 5usize, |this| ((**(this as *const *const IKsClockPropertySet_Vtbl)).KsGetPhysicalTime)(this, time), |result| Some(*result))
+        }
+        unsafe extern "system" fn KsSetPhysicalTime(this: *mut core::ffi::c_void, time: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsClockPropertySet", "KsSetPhysicalTime", 6usize, |this| ((**(this as *const *const IKsClockPropertySet_Vtbl)).KsSetPhysicalTime)(this, time), |result| Some(*result))
+        }
+        unsafe extern "system" fn KsGetCorrelatedTime(this: *mut core::ffi::c_void, correlatedtime: *mut KSCORRELATED_TIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsClockPropertySet", "KsGetCorrelatedTime", 7usize, |this| ((**(this as *const *const IKsClockPropertySet_Vtbl)).KsGetCorrelatedTime)(this, correlatedtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn KsSetCorrelatedTime(this: *mut core::ffi::c_void, correlatedtime: *const KSCORRELATED_TIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsClockPropertySet", "KsSetCorrelatedTime", 8usize, |this| ((**(this as *const *const IKsClockPropertySet_Vtbl)).KsSetCorrelatedTime)(this, correlatedtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn KsGetCorrelatedPhysicalTime(this: *mut core::ffi::c_void, correlatedtime: *mut KSCORRELATED_TIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsClockPropertySet", "KsGetCorrelatedPhysicalTime", 9usize, |this| ((**(this as *const *const IKsClockPropertySet_Vtbl)).KsGetCorrelatedPhysicalTime)(this, correlatedtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn KsSetCorrelatedPhysicalTime(this: *mut core::ffi::c_void, correlatedtime: *const KSCORRELATED_TIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsClockPropertySet", "KsSetCorrelatedPhysicalTime", 10usize, |this| ((**(this as *const *const IKsClockPropertySet_Vtbl)).KsSetCorrelatedPhysicalTime)(this, correlatedtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn KsGetResolution(this: *mut core::ffi::c_void, resolution: *mut KSRESOLUTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsClockPropertySet", "KsGetResolution", 11usize, |this| ((**(this as *const *const IKsClockPropertySet_Vtbl)).KsGetResolution)(this, resolution), |result| Some(*result))
+        }
+        unsafe extern "system" fn KsGetState(this: *mut core::ffi::c_void, state: *mut KSSTATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsClockPropertySet", "KsGetState", 12usize, |this| ((**(this as *const *const IKsClockPropertySet_Vtbl)).KsGetState)(this, state), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            KsGetTime,
+            KsSetTime,
+            KsGetPhysicalTime,
+            KsSetPhysicalTime,
+            KsGetCorrelatedTime,
+            KsSetCorrelatedTime,
+            KsGetCorrelatedPhysicalTime,
+            KsSetCorrelatedPhysicalTime,
+            KsGetResolution,
+            KsGetState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKsClockPropertySet as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKsControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn KsProperty(this: *mut core::ffi::c_void, property: *const KSIDENTIFIER, propertylength: u32, propertydata: *mut core::ffi::c_void, datalength: u32, bytesreturned: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsControl", "KsProperty", 3usize, |this| ((**(this as *const *const IKsControl_Vtbl)).KsProperty)(this, property, propertylength, propertydata, datalength, bytesreturned), |result| Some(*result))
+        }
+        unsafe extern "system" fn KsMethod(this: *mut core::ffi::c_void, method: *const KSIDENTIFIER, methodlength: u32, methoddata: *mut core::ffi::c_void, datalength: u32, bytesreturned: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsControl", "KsMethod", 4usize, |this| ((**(this as *const *const IKsControl_Vtbl)).KsMethod)(this, method, methodlength, methoddata, datalength, bytesreturned), |result| Some(*result))
+        }
+        unsafe extern "system" fn KsEvent(this: *mut core::ffi::c_void, event: *const KSIDENTIFIER, eventlength: u32, eventdata: *mut core::ffi::c_void, datalength: u32, bytesreturned: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsControl", "KsEvent", 5usize, |this| ((**(this as *const *const IKsControl_Vtbl)).KsEvent)(this, event, eventlength, eventdata, datalength, bytesreturned), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, KsProperty, KsMethod, KsEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKsControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_MediaFoundation")]
+impl windows_core::imp::ProxyVtable for IKsDataTypeCompletion_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn KsCompleteMediaType(this: *mut core::ffi::c_void, filterhandle: super::super::Foundation::HANDLE, pinfactoryid: u32, ammediatype: *mut super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsDataTypeCompletion", "KsCompleteMediaType", 3usize, |this| ((**(this as *const *const IKsDataTypeCompletion_Vtbl)).KsCompleteMediaType)(this, filterhandle, pinfactoryid, ammediatype), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            KsCompleteMediaType,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            KsCompleteMediaType: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKsDataTypeCompletion as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Media_DirectShow", feature = "Win32_Media_MediaFoundation"))]
+impl windows_core::imp::ProxyVtable for IKsDataTypeHandler_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_DirectShow")]
+        unsafe extern "system" fn KsCompleteIoOperation(this: *mut core::ffi::c_void, sample: *mut core::ffi::c_void, streamheader: *mut core::ffi::c_void, iooperation: KSIOOPERATION, cancelled: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsDataTypeHandler", "KsCompleteIoOperation", 3usize, |this| ((**(this as *const *const IKsDataTypeHandler_Vtbl)).KsCompleteIoOperation)(this, sample, streamheader, iooperation, cancelled), |result| Some(*result))
+        }
+        unsafe extern "system" fn KsIsMediaTypeInRanges(this: *mut core::ffi::c_void, dataranges: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsDataTypeHandler", "KsIsMediaTypeInRanges", 4usize, |this| ((**(this as *const *const IKsDataTypeHandler_Vtbl)).KsIsMediaTypeInRanges)(this, dataranges), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_DirectShow")]
+        unsafe extern "system" fn KsPrepareIoOperation(this: *mut core::ffi::c_void, sample: *mut core::ffi::c_void, streamheader: *mut core::ffi::c_void, iooperation: KSIOOPERATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsDataTypeHandler", "KsPrepareIoOperation", 5usize, |this| ((**(this as *const *const IKsDataTypeHandler_Vtbl)).KsPrepareIoOperation)(this, sample, streamheader, iooperation), |result| Some(*result))
+        }
+        unsafe extern "system" fn KsQueryExtendedSize(this: *mut core::ffi::c_void, extendedsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsDataTypeHandler", "KsQueryExtendedSize", 6usize, |this| ((**(this as *const *const IKsDataTypeHandler_Vtbl)).KsQueryExtendedSize)(this, extendedsize), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn KsSetMediaType(this: *mut core::ffi::c_void, ammediatype: *const super::MediaFoundation::AM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsDataTypeHandler", "KsSetMediaType", 7usize, |this| ((**(this as *const *const IKsDataTypeHandler_Vtbl)).KsSetMediaType)(this, ammediatype), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_DirectShow")]
+            KsCompleteIoOperation,
+            #[cfg(not(feature = "Win32_Media_DirectShow"))]
+            KsCompleteIoOperation: 0,
+            KsIsMediaTypeInRanges,
+            #[cfg(feature = "Win32_Media_DirectShow")]
+            KsPrepareIoOperation,
+            #[cfg(not(feature = "Win32_Media_DirectShow"))]
+            KsPrepareIoOperation: 0,
+            KsQueryExtendedSize,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            KsSetMediaType,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            KsSetMediaType: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKsDataTypeHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKsFormatSupport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsFormatSupported(this: *mut core::ffi::c_void, pksformat: *mut KSDATAFORMAT, cbformat: u32, pbsupported: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsFormatSupport", "IsFormatSupported", 3usize, |this| ((**(this as *const *const IKsFormatSupport_Vtbl)).IsFormatSupported)(this, pksformat, cbformat, pbsupported), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDevicePreferredFormat(this: *mut core::ffi::c_void, ppksformat: *mut *mut KSDATAFORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsFormatSupport", "GetDevicePreferredFormat", 4usize, |this| ((**(this as *const *const IKsFormatSupport_Vtbl)).GetDevicePreferredFormat)(this, ppksformat), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsFormatSupported, GetDevicePreferredFormat }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKsFormatSupport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_DirectShow")]
+impl windows_core::imp::ProxyVtable for IKsInterfaceHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn KsSetPin(this: *mut core::ffi::c_void, kspin: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsInterfaceHandler", "KsSetPin", 3usize, |this| ((**(this as *const *const IKsInterfaceHandler_Vtbl)).KsSetPin)(this, kspin), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_DirectShow")]
+        unsafe extern "system" fn KsProcessMediaSamples(this: *mut core::ffi::c_void, ksdatatypehandler: *mut core::ffi::c_void, samplelist: *const *mut core::ffi::c_void, samplecount: *mut i32, iooperation: KSIOOPERATION, streamsegment: *mut *mut KSSTREAM_SEGMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsInterfaceHandler", "KsProcessMediaSamples", 4usize, |this| ((**(this as *const *const IKsInterfaceHandler_Vtbl)).KsProcessMediaSamples)(this, ksdatatypehandler, samplelist, samplecount, iooperation, streamsegment), |result| Some(*result))
+        }
+        unsafe extern "system" fn KsCompleteIo(this: *mut core::ffi::c_void, streamsegment: *mut KSSTREAM_SEGMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsInterfaceHandler", "KsCompleteIo", 5usize, |this| ((**(this as *const *const IKsInterfaceHandler_Vtbl)).KsCompleteIo)(this, streamsegment), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            KsSetPin,
+            #[cfg(feature = "Win32_Media_DirectShow")]
+            KsProcessMediaSamples,
+            #[cfg(not(feature = "Win32_Media_DirectShow"))]
+            KsProcessMediaSamples: 0,
+            KsCompleteIo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKsInterfaceHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKsJackContainerId_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetJackContainerId(this: *mut core::ffi::c_void, pjackcontainerid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsJackContainerId", "GetJackContainerId", 3usize, |this| ((**(this as *const *const IKsJackContainerId_Vtbl)).GetJackContainerId)(this, pjackcontainerid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetJackContainerId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKsJackContainerId as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKsJackDescription_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetJackCount(this: *mut core::ffi::c_void, pcjacks: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsJackDescription", "GetJackCount", 3usize, |this| ((**(this as *const *const IKsJackDescription_Vtbl)).GetJackCount)(this, pcjacks), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetJackDescription(this: *mut core::ffi::c_void, njack: u32, pdescription: *mut KSJACK_DESCRIPTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsJackDescription", "GetJackDescription", 4usize, |this| ((**(this as *const *const IKsJackDescription_Vtbl)).GetJackDescription)(this, njack, pdescription), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetJackCount, GetJackDescription }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKsJackDescription as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKsJackDescription2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetJackCount(this: *mut core::ffi::c_void, pcjacks: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsJackDescription2", "GetJackCount", 3usize, |this| ((**(this as *const *const IKsJackDescription2_Vtbl)).GetJackCount)(this, pcjacks), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetJackDescription2(this: *mut core::ffi::c_void, njack: u32, pdescription2: *mut KSJACK_DESCRIPTION2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsJackDescription2", "GetJackDescription2", 4usize, |this| ((**(this as *const *const IKsJackDescription2_Vtbl)).GetJackDescription2)(this, njack, pdescription2), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetJackCount, GetJackDescription2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKsJackDescription2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKsJackDescription3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetJackCount(this: *mut core::ffi::c_void, pcjacks: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsJackDescription3", "GetJackCount", 3usize, |this| ((**(this as *const *const IKsJackDescription3_Vtbl)).GetJackCount)(this, pcjacks), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetJackDescription3(this: *mut core::ffi::c_void, njack: u32, pdescription3: *mut KSJACK_DESCRIPTION3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsJackDescription3", "GetJackDescription3", 4usize, |this| ((**(this as *const *const IKsJackDescription3_Vtbl)).GetJackDescription3)(this, njack, pdescription3), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetJackCount, GetJackDescription3 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKsJackDescription3 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKsJackSinkInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetJackSinkInformation(this: *mut core::ffi::c_void, pjacksinkinformation: *mut KSJACK_SINK_INFORMATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsJackSinkInformation", "GetJackSinkInformation", 3usize, |this| ((**(this as *const *const IKsJackSinkInformation_Vtbl)).GetJackSinkInformation)(this, pjacksinkinformation), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetJackSinkInformation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKsJackSinkInformation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKsNodeControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetNodeId(this: *mut core::ffi::c_void, dwnodeid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsNodeControl", "SetNodeId", 3usize, |this| ((**(this as *const *const IKsNodeControl_Vtbl)).SetNodeId)(this, dwnodeid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKsControl(this: *mut core::ffi::c_void, pkscontrol: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsNodeControl", "SetKsControl", 4usize, |this| ((**(this as *const *const IKsNodeControl_Vtbl)).SetKsControl)(this, pkscontrol), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetNodeId, SetKsControl }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKsNodeControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKsNotifyEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn KsNotifyEvent(this: *mut core::ffi::c_void, event: u32, lparam1: usize, lparam2: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsNotifyEvent", "KsNotifyEvent", 3usize, |this| ((**(this as *const *const IKsNotifyEvent_Vtbl)).KsNotifyEvent)(this, event, lparam1, lparam2), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, KsNotifyEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKsNotifyEvent as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKsObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn KsGetObjectHandle(this: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            windows_core::imp::proxy_call(this, "IKsObject", "KsGetObjectHandle", 3usize, |this| ((**(this as *const *const IKsObject_Vtbl)).KsGetObjectHandle)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, KsGetObjectHandle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKsObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_DirectShow")]
+impl windows_core::imp::ProxyVtable for IKsPin_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn KsQueryMediums(this: *mut core::ffi::c_void, mediumlist: *mut *mut KSMULTIPLE_ITEM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsPin", "KsQueryMediums", 3usize, |this| ((**(this as *const *const IKsPin_Vtbl)).KsQueryMediums)(this, mediumlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn KsQueryInterfaces(this: *mut core::ffi::c_void, interfacelist: *mut *mut KSMULTIPLE_ITEM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsPin", "KsQueryInterfaces", 4usize, |this| ((**(this as *const *const IKsPin_Vtbl)).KsQueryInterfaces)(this, interfacelist), |result| Some(*result))
+        }
+        unsafe extern "system" fn KsCreateSinkPinHandle(this: *mut core::ffi::c_void, interface: *const KSIDENTIFIER, medium: *const KSIDENTIFIER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsPin", "KsCreateSinkPinHandle", 5usize, |this| ((**(this as *const *const IKsPin_Vtbl)).KsCreateSinkPinHandle)(this, interface, medium), |result| Some(*result))
+        }
+        unsafe extern "system" fn KsGetCurrentCommunication(this: *mut core::ffi::c_void, communication: *mut KSPIN_COMMUNICATION, interface: *mut KSIDENTIFIER, medium: *mut KSIDENTIFIER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsPin", "KsGetCurrentCommunication", 6usize, |this| ((**(this as *const *const IKsPin_Vtbl)).KsGetCurrentCommunication)(this, communication, interface, medium), |result| Some(*result))
+        }
+        unsafe extern "system" fn KsPropagateAcquire(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsPin", "KsPropagateAcquire", 7usize, |this| ((**(this as *const *const IKsPin_Vtbl)).KsPropagateAcquire)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_DirectShow")]
+        unsafe extern "system" fn KsDeliver(this: *mut core::ffi::c_void, sample: *mut core::ffi::c_void, flags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsPin", "KsDeliver", 8usize, |this| ((**(this as *const *const IKsPin_Vtbl)).KsDeliver)(this, sample, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn KsMediaSamplesCompleted(this: *mut core::ffi::c_void, streamsegment: *const KSSTREAM_SEGMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsPin", "KsMediaSamplesCompleted", 9usize, |this| ((**(this as *const *const IKsPin_Vtbl)).KsMediaSamplesCompleted)(this, streamsegment), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_DirectShow")]
+        unsafe extern "system" fn KsPeekAllocator(this: *mut core::ffi::c_void, operation: KSPEEKOPERATION) -> Option<super::DirectShow::IMemAllocator> {
+            windows_core::imp::proxy_call(this, "IKsPin", "KsPeekAllocator", 10usize, |this| ((**(this as *const *const IKsPin_Vtbl)).KsPeekAllocator)(this, operation), |_| None)
+        }
+        #[cfg(feature = "Win32_Media_DirectShow")]
+        unsafe extern "system" fn KsReceiveAllocator(this: *mut core::ffi::c_void, memallocator: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsPin", "KsReceiveAllocator", 11usize, |this| ((**(this as *const *const IKsPin_Vtbl)).KsReceiveAllocator)(this, memallocator), |result| Some(*result))
+        }
+        unsafe extern "system" fn KsRenegotiateAllocator(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsPin", "KsRenegotiateAllocator", 12usize, |this| ((**(this as *const *const IKsPin_Vtbl)).KsRenegotiateAllocator)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn KsIncrementPendingIoCount(this: *mut core::ffi::c_void) -> i32 {
+            windows_core::imp::proxy_call(this, "IKsPin", "KsIncrementPendingIoCount", 13usize, |this| ((**(this as *const *const IKsPin_Vtbl)).KsIncrementPendingIoCount)(this), |_| None)
+        }
+        unsafe extern "system" fn KsDecrementPendingIoCount(this: *mut core::ffi::c_void) -> i32 {
+            windows_core::imp::proxy_call(this, "IKsPin", "KsDecrementPendingIoCount", 14usize, |this| ((**(this as *const *const IKsPin_Vtbl)).KsDecrementPendingIoCount)(this), |_| None)
+        }
+        unsafe extern "system" fn KsQualityNotify(this: *mut core::ffi::c_void, proportion: u32, timedelta: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsPin", "KsQualityNotify", 15usize, |this| ((**(this as *const *const IKsPin_Vtbl)).KsQualityNotify)(this, proportion, timedelta), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            KsQueryMediums,
+            KsQueryInterfaces,
+            KsCreateSinkPinHandle,
+            KsGetCurrentCommunication,
+            KsPropagateAcquire,
+            #[cfg(feature = "Win32_Media_DirectShow")]
+            KsDeliver,
+            #[cfg(not(feature = "Win32_Media_DirectShow"))]
+            KsDeliver: 0,
+            KsMediaSamplesCompleted,
+            #[cfg(feature = "Win32_Media_DirectShow")]
+            KsPeekAllocator,
+            #[cfg(not(feature = "Win32_Media_DirectShow"))]
+            KsPeekAllocator: 0,
+            #[cfg(feature = "Win32_Media_DirectShow")]
+            KsReceiveAllocator,
+            #[cfg(not(feature = "Win32_Media_DirectShow"))]
+            KsReceiveAllocator: 0,
+            KsRenegotiateAllocator,
+            KsIncrementPendingIoCount,
+            KsDecrementPendingIoCount,
+            KsQualityNotify,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKsPin as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_DirectShow")]
+impl windows_core::imp::ProxyVtable for IKsPinEx_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_DirectShow")]
+        unsafe extern "system" fn KsNotifyError(this: *mut core::ffi::c_void, sample: *mut core::ffi::c_void, hr: windows_core::HRESULT) {
+            windows_core::imp::proxy_call(this, "IKsPinEx", "KsNotifyError", 16usize, |this| ((**(this as *const *const IKsPinEx_Vtbl)).KsNotifyError)(this, sample, hr), |_| None)
+        }
+        Self {
+            base__: <IKsPin_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_DirectShow")]
+            KsNotifyError,
+            #[cfg(not(feature = "Win32_Media_DirectShow"))]
+            KsNotifyError: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKsPinEx as windows_core::Interface>::IID || <IKsPin_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKsPinFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn KsPinFactory(this: *mut core::ffi::c_void, pinfactory: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsPinFactory", "KsPinFactory", 3usize, |this| ((**(this as *const *const IKsPinFactory_Vtbl)).KsPinFactory)(this, pinfactory), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, KsPinFactory }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKsPinFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_DirectShow")]
+impl windows_core::imp::ProxyVtable for IKsPinPipe_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn KsGetPinFramingCache(this: *mut core::ffi::c_void, framingex: *mut *mut KSALLOCATOR_FRAMING_EX, framingprop: *mut FRAMING_PROP, option: FRAMING_CACHE_OPS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsPinPipe", "KsGetPinFramingCache", 3usize, |this| ((**(this as *const *const IKsPinPipe_Vtbl)).KsGetPinFramingCache)(this, framingex, framingprop, option), |result| Some(*result))
+        }
+        unsafe extern "system" fn KsSetPinFramingCache(this: *mut core::ffi::c_void, framingex: *const KSALLOCATOR_FRAMING_EX, framingprop: *const FRAMING_PROP, option: FRAMING_CACHE_OPS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsPinPipe", "KsSetPinFramingCache", 4usize, |this| ((**(this as *const *const IKsPinPipe_Vtbl)).KsSetPinFramingCache)(this, framingex, framingprop, option), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_DirectShow")]
+        unsafe extern "system" fn KsGetConnectedPin(this: *mut core::ffi::c_void) -> Option<super::DirectShow::IPin> {
+            windows_core::imp::proxy_call(this, "IKsPinPipe", "KsGetConnectedPin", 5usize, |this| ((**(this as *const *const IKsPinPipe_Vtbl)).KsGetConnectedPin)(this), |_| None)
+        }
+        unsafe extern "system" fn KsGetPipe(this: *mut core::ffi::c_void, operation: KSPEEKOPERATION) -> Option<IKsAllocatorEx> {
+            windows_core::imp::proxy_call(this, "IKsPinPipe", "KsGetPipe", 6usize, |this| ((**(this as *const *const IKsPinPipe_Vtbl)).KsGetPipe)(this, operation), |_| None)
+        }
+        unsafe extern "system" fn KsSetPipe(this: *mut core::ffi::c_void, ksallocator: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsPinPipe", "KsSetPipe", 7usize, |this| ((**(this as *const *const IKsPinPipe_Vtbl)).KsSetPipe)(this, ksallocator), |result| Some(*result))
+        }
+        unsafe extern "system" fn KsGetPipeAllocatorFlag(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IKsPinPipe", "KsGetPipeAllocatorFlag", 8usize, |this| ((**(this as *const *const IKsPinPipe_Vtbl)).KsGetPipeAllocatorFlag)(this), |_| None)
+        }
+        unsafe extern "system" fn KsSetPipeAllocatorFlag(this: *mut core::ffi::c_void, flag: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsPinPipe", "KsSetPipeAllocatorFlag", 9usize, |this| ((**(this as *const *const IKsPinPipe_Vtbl)).KsSetPipeAllocatorFlag)(this, flag), |result| Some(*result))
+        }
+        unsafe extern "system" fn KsGetPinBusCache(this: *mut core::ffi::c_void, result__: *mut windows_core::GUID) {
+            windows_core::imp::proxy_call(this, "IKsPinPipe", "KsGetPinBusCache", 10usize, |this| ((**(this as *const *const IKsPinPipe_Vtbl)).KsGetPinBusCache)(this, result__), |_| None)
+        }
+        unsafe extern "system" fn KsSetPinBusCache(this: *mut core::ffi::c_void, bus: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsPinPipe", "KsSetPinBusCache", 11usize, |this| ((**(this as *const *const IKsPinPipe_Vtbl)).KsSetPinBusCache)(this, bus), |result| Some(*result))
+        }
+        unsafe extern "system" fn KsGetPinName(this: *mut core::ffi::c_void) -> windows_core::PWSTR {
+            windows_core::imp::proxy_call(this, "IKsPinPipe", "KsGetPinName", 12usize, |this| ((**(this as *const *const IKsPinPipe_Vtbl)).KsGetPinName)(this), |_| None)
+        }
+        unsafe extern "system" fn KsGetFilterName(this: *mut core::ffi::c_void) -> windows_core::PWSTR {
+            windows_core::imp::proxy_call(this, "IKsPinPipe", "KsGetFilterName", 13usize, |this| ((**(this as *const *const IKsPinPipe_Vtbl)).KsGetFilterName)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            KsGetPinFramingCache,
+            KsSetPinFramingCache,
+            #[cfg(feature = "Win32_Media_DirectShow")]
+            KsGetConnectedPin,
+            #[cfg(not(feature = "Win32_Media_DirectShow"))]
+            KsGetConnectedPin: 0,
+            KsGetPipe,
+            KsSetPipe,
+            KsGetPipeAllocatorFlag,
+            KsSetPipeAllocatorFlag,
+            KsGetPinBusCache,
+            KsSetPinBusCache,
+            KsGetPinName,
+            KsGetFilterName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKsPinPipe as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKsPropertySet_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Set(this: *mut core::ffi::c_void, guidpropset: *const windows_core::GUID, dwpropid: u32, pinstancedata: *const core::ffi::c_void, cbinstancedata: u32, ppropdata: *const core::ffi::c_void, cbpropdata: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsPropertySet", "Set", 3usize, |this| ((**(this as *const *const IKsPropertySet_Vtbl)).Set)(this, guidpropset, dwpropid, pinstancedata, cbinstancedata, ppropdata, cbpropdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn Get(this: *mut core::ffi::c_void, guidpropset: *const windows_core::GUID, dwpropid: u32, pinstancedata: *const core::ffi::c_void, cbinstancedata: u32, ppropdata: *mut core::ffi::c_void, cbpropdata: u32, pcbreturned: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsPropertySet", "Get", 4usize, |this| ((**(this as *const *const IKsPropertySet_Vtbl)).Get)(this, guidpropset, dwpropid, pinstancedata, cbinstancedata, ppropdata, cbpropdata, pcbreturned), |result| Some(*result))
+        }
+        unsafe extern "system" fn QuerySupported(this: *mut core::ffi::c_void, guidpropset: *const windows_core::GUID, dwpropid: u32, ptypesupport: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsPropertySet", "QuerySupported", 5usize, |this| ((**(this as *const *const IKsPropertySet_Vtbl)).QuerySupported)(this, guidpropset, dwpropid, ptypesupport), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Set, Get, QuerySupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKsPropertySet as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKsQualityForwarder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn KsFlushClient(this: *mut core::ffi::c_void, pin: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IKsQualityForwarder", "KsFlushClient", 4usize, |this| ((**(this as *const *const IKsQualityForwarder_Vtbl)).KsFlushClient)(this, pin), |_| None)
+        }
+        Self { base__: <IKsObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY, KsFlushClient }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKsQualityForwarder as windows_core::Interface>::IID || <IKsObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKsTopology_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateNodeInstance(this: *mut core::ffi::c_void, nodeid: u32, flags: u32, desiredaccess: u32, unkouter: *mut core::ffi::c_void, interfaceid: *const windows_core::GUID, interface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsTopology", "CreateNodeInstance", 3usize, |this| ((**(this as *const *const IKsTopology_Vtbl)).CreateNodeInstance)(this, nodeid, flags, desiredaccess, unkouter, interfaceid, interface), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateNodeInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKsTopology as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKsTopologyInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NumCategories(this: *mut core::ffi::c_void, pdwnumcategories: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsTopologyInfo", "NumCategories", 3usize, |this| ((**(this as *const *const IKsTopologyInfo_Vtbl)).NumCategories)(this, pdwnumcategories), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Category(this: *mut core::ffi::c_void, dwindex: u32, pcategory: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsTopologyInfo", "get_Category", 4usize, |this| ((**(this as *const *const IKsTopologyInfo_Vtbl)).get_Category)(this, dwindex, pcategory), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumConnections(this: *mut core::ffi::c_void, pdwnumconnections: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsTopologyInfo", "NumConnections", 5usize, |this| ((**(this as *const *const IKsTopologyInfo_Vtbl)).NumConnections)(this, pdwnumconnections), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_ConnectionInfo(this: *mut core::ffi::c_void, dwindex: u32, pconnectioninfo: *mut KSTOPOLOGY_CONNECTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsTopologyInfo", "get_ConnectionInfo", 6usize, |this| ((**(this as *const *const IKsTopologyInfo_Vtbl)).get_ConnectionInfo)(this, dwindex, pconnectioninfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_NodeName(this: *mut core::ffi::c_void, dwnodeid: u32, pwchnodename: windows_core::PWSTR, dwbufsize: u32, pdwnamelen: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsTopologyInfo", "get_NodeName", 7usize, |this| ((**(this as *const *const IKsTopologyInfo_Vtbl)).get_NodeName)(this, dwnodeid, pwchnodename, dwbufsize, pdwnamelen), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumNodes(this: *mut core::ffi::c_void, pdwnumnodes: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsTopologyInfo", "NumNodes", 8usize, |this| ((**(this as *const *const IKsTopologyInfo_Vtbl)).NumNodes)(this, pdwnumnodes), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_NodeType(this: *mut core::ffi::c_void, dwnodeid: u32, pnodetype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsTopologyInfo", "get_NodeType", 9usize, |this| ((**(this as *const *const IKsTopologyInfo_Vtbl)).get_NodeType)(this, dwnodeid, pnodetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateNodeInstance(this: *mut core::ffi::c_void, dwnodeid: u32, iid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKsTopologyInfo", "CreateNodeInstance", 10usize, |this| ((**(this as *const *const IKsTopologyInfo_Vtbl)).CreateNodeInstance)(this, dwnodeid, iid, ppvobject), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            NumCategories,
+            get_Category,
+            NumConnections,
+            get_ConnectionInfo,
+            get_NodeName,
+            NumNodes,
+            get_NodeType,
+            CreateNodeInstance,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKsTopologyInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
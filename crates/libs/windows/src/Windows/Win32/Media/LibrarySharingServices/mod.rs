@@ -309,6 +309,7 @@ pub struct WindowsMediaLibrarySharingDeviceAuthorizationStatus(pub i32);
 impl windows_core::TypeKind for WindowsMediaLibrarySharingDeviceAuthorizationStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WindowsMediaLibrarySharingDeviceAuthorizationStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WindowsMediaLibrarySharingDeviceAuthorizationStatus").field(&self.0).finish()
@@ -317,3 +318,5 @@ impl core::fmt::Debug for WindowsMediaLibrarySharingDeviceAuthorizationStatus {
 pub const WindowsMediaLibrarySharingServices: windows_core::GUID = windows_core::GUID::from_u128(0xad581b00_7b64_4e59_a38d_d2c5bf51ddb3);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,204 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWindowsMediaLibrarySharingDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceID(this: *mut core::ffi::c_void, deviceid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingDevice", "DeviceID", 7usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingDevice_Vtbl)).DeviceID)(this, deviceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Authorization(this: *mut core::ffi::c_void, authorization: *mut WindowsMediaLibrarySharingDeviceAuthorizationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingDevice", "Authorization", 8usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingDevice_Vtbl)).Authorization)(this, authorization), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthorization(this: *mut core::ffi::c_void, authorization: WindowsMediaLibrarySharingDeviceAuthorizationStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingDevice", "SetAuthorization", 9usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingDevice_Vtbl)).SetAuthorization)(this, authorization), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, deviceproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingDevice", "Properties", 10usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingDevice_Vtbl)).Properties)(this, deviceproperties), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceID,
+            Authorization,
+            SetAuthorization,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsMediaLibrarySharingDevice as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWindowsMediaLibrarySharingDeviceProperties_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, property: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingDeviceProperties", "get_Item", 7usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingDeviceProperties_Vtbl)).get_Item)(this, index, property), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingDeviceProperties", "Count", 8usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingDeviceProperties_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, property: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingDeviceProperties", "GetProperty", 9usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingDeviceProperties_Vtbl)).GetProperty)(this, name, property), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            GetProperty,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetProperty: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsMediaLibrarySharingDeviceProperties as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWindowsMediaLibrarySharingDeviceProperty_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingDeviceProperty", "Name", 7usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingDeviceProperty_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingDeviceProperty", "Value", 8usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingDeviceProperty_Vtbl)).Value)(this, value), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, Value }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsMediaLibrarySharingDeviceProperty as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWindowsMediaLibrarySharingDevices_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, device: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingDevices", "get_Item", 7usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingDevices_Vtbl)).get_Item)(this, index, device), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingDevices", "Count", 8usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingDevices_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetDevice(this: *mut core::ffi::c_void, deviceid: core::mem::MaybeUninit<windows_core::BSTR>, device: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingDevices", "GetDevice", 9usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingDevices_Vtbl)).GetDevice)(this, deviceid, device), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            GetDevice,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetDevice: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsMediaLibrarySharingDevices as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWindowsMediaLibrarySharingServices_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn showShareMediaCPL(this: *mut core::ffi::c_void, device: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingServices", "showShareMediaCPL", 7usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingServices_Vtbl)).showShareMediaCPL)(this, device), |result| Some(*result))
+        }
+        unsafe extern "system" fn userHomeMediaSharingState(this: *mut core::ffi::c_void, sharingenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingServices", "userHomeMediaSharingState", 8usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingServices_Vtbl)).userHomeMediaSharingState)(this, sharingenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetuserHomeMediaSharingState(this: *mut core::ffi::c_void, sharingenabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingServices", "SetuserHomeMediaSharingState", 9usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingServices_Vtbl)).SetuserHomeMediaSharingState)(this, sharingenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn userHomeMediaSharingLibraryName(this: *mut core::ffi::c_void, libraryname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingServices", "userHomeMediaSharingLibraryName", 10usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingServices_Vtbl)).userHomeMediaSharingLibraryName)(this, libraryname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetuserHomeMediaSharingLibraryName(this: *mut core::ffi::c_void, libraryname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingServices", "SetuserHomeMediaSharingLibraryName", 11usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingServices_Vtbl)).SetuserHomeMediaSharingLibraryName)(this, libraryname), |result| Some(*result))
+        }
+        unsafe extern "system" fn computerHomeMediaSharingAllowedState(this: *mut core::ffi::c_void, sharingallowed: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingServices", "computerHomeMediaSharingAllowedState", 12usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingServices_Vtbl)).computerHomeMediaSharingAllowedState)(this, sharingallowed), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetcomputerHomeMediaSharingAllowedState(this: *mut core::ffi::c_void, sharingallowed: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingServices", "SetcomputerHomeMediaSharingAllowedState", 13usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingServices_Vtbl)).SetcomputerHomeMediaSharingAllowedState)(this, sharingallowed), |result| Some(*result))
+        }
+        unsafe extern "system" fn userInternetMediaSharingState(this: *mut core::ffi::c_void, sharingenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingServices", "userInternetMediaSharingState", 14usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingServices_Vtbl)).userInternetMediaSharingState)(this, sharingenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetuserInternetMediaSharingState(this: *mut core::ffi::c_void, sharingenabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingServices", "SetuserInternetMediaSharingState", 15usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingServices_Vtbl)).SetuserInternetMediaSharingState)(this, sharingenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn computerInternetMediaSharingAllowedState(this: *mut core::ffi::c_void, sharingallowed: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingServices", "computerInternetMediaSharingAllowedState", 16usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingServices_Vtbl)).computerInternetMediaSharingAllowedState)(this, sharingallowed), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetcomputerInternetMediaSharingAllowedState(this: *mut core::ffi::c_void, sharingallowed: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingServices", "SetcomputerInternetMediaSharingAllowedState", 17usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingServices_Vtbl)).SetcomputerInternetMediaSharingAllowedState)(this, sharingallowed), |result| Some(*result))
+        }
+        unsafe extern "system" fn internetMediaSharingSecurityGroup(this: *mut core::ffi::c_void, securitygroup: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingServices", "internetMediaSharingSecurityGroup", 18usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingServices_Vtbl)).internetMediaSharingSecurityGroup)(this, securitygroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetinternetMediaSharingSecurityGroup(this: *mut core::ffi::c_void, securitygroup: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingServices", "SetinternetMediaSharingSecurityGroup", 19usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingServices_Vtbl)).SetinternetMediaSharingSecurityGroup)(this, securitygroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn allowSharingToAllDevices(this: *mut core::ffi::c_void, sharingenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingServices", "allowSharingToAllDevices", 20usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingServices_Vtbl)).allowSharingToAllDevices)(this, sharingenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetallowSharingToAllDevices(this: *mut core::ffi::c_void, sharingenabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingServices", "SetallowSharingToAllDevices", 21usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingServices_Vtbl)).SetallowSharingToAllDevices)(this, sharingenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn setDefaultAuthorization(this: *mut core::ffi::c_void, macaddresses: core::mem::MaybeUninit<windows_core::BSTR>, friendlyname: core::mem::MaybeUninit<windows_core::BSTR>, authorization: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingServices", "setDefaultAuthorization", 22usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingServices_Vtbl)).setDefaultAuthorization)(this, macaddresses, friendlyname, authorization), |result| Some(*result))
+        }
+        unsafe extern "system" fn setAuthorizationState(this: *mut core::ffi::c_void, macaddress: core::mem::MaybeUninit<windows_core::BSTR>, authorizationstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingServices", "setAuthorizationState", 23usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingServices_Vtbl)).setAuthorizationState)(this, macaddress, authorizationstate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn getAllDevices(this: *mut core::ffi::c_void, devices: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingServices", "getAllDevices", 24usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingServices_Vtbl)).getAllDevices)(this, devices), |result| Some(*result))
+        }
+        unsafe extern "system" fn customSettingsApplied(this: *mut core::ffi::c_void, customsettingsapplied: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsMediaLibrarySharingServices", "customSettingsApplied", 25usize, |this| ((**(this as *const *const IWindowsMediaLibrarySharingServices_Vtbl)).customSettingsApplied)(this, customsettingsapplied), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            showShareMediaCPL,
+            userHomeMediaSharingState,
+            SetuserHomeMediaSharingState,
+            userHomeMediaSharingLibraryName,
+            SetuserHomeMediaSharingLibraryName,
+            computerHomeMediaSharingAllowedState,
+            SetcomputerHomeMediaSharingAllowedState,
+            userInternetMediaSharingState,
+            SetuserInternetMediaSharingState,
+            computerInternetMediaSharingAllowedState,
+            SetcomputerInternetMediaSharingAllowedState,
+            internetMediaSharingSecurityGroup,
+            SetinternetMediaSharingSecurityGroup,
+            allowSharingToAllDevices,
+            SetallowSharingToAllDevices,
+            setDefaultAuthorization,
+            setAuthorizationState,
+            #[cfg(feature = "Win32_System_Com")]
+            getAllDevices,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            getAllDevices: 0,
+            customSettingsApplied,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsMediaLibrarySharingServices as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
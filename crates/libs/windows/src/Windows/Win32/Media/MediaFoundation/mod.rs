@@ -20644,6 +20644,7 @@ pub struct AEC_INPUT_STREAM(pub i32);
 impl windows_core::TypeKind for AEC_INPUT_STREAM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AEC_INPUT_STREAM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AEC_INPUT_STREAM").field(&self.0).finish()
@@ -20655,6 +20656,7 @@ pub struct AEC_SYSTEM_MODE(pub i32);
 impl windows_core::TypeKind for AEC_SYSTEM_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AEC_SYSTEM_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AEC_SYSTEM_MODE").field(&self.0).finish()
@@ -20666,6 +20668,7 @@ pub struct AEC_VAD_MODE(pub i32);
 impl windows_core::TypeKind for AEC_VAD_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AEC_VAD_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AEC_VAD_MODE").field(&self.0).finish()
@@ -20677,6 +20680,7 @@ pub struct ASF_SELECTION_STATUS(pub i32);
 impl windows_core::TypeKind for ASF_SELECTION_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ASF_SELECTION_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ASF_SELECTION_STATUS").field(&self.0).finish()
@@ -20688,6 +20692,7 @@ pub struct ASF_STATUSFLAGS(pub i32);
 impl windows_core::TypeKind for ASF_STATUSFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ASF_STATUSFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ASF_STATUSFLAGS").field(&self.0).finish()
@@ -20699,6 +20704,7 @@ pub struct D3D12_BITSTREAM_ENCRYPTION_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_BITSTREAM_ENCRYPTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_BITSTREAM_ENCRYPTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_BITSTREAM_ENCRYPTION_TYPE").field(&self.0).finish()
@@ -20710,6 +20716,7 @@ pub struct D3D12_FEATURE_VIDEO(pub i32);
 impl windows_core::TypeKind for D3D12_FEATURE_VIDEO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_FEATURE_VIDEO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_FEATURE_VIDEO").field(&self.0).finish()
@@ -20721,6 +20728,7 @@ pub struct D3D12_VIDEO_DECODE_ARGUMENT_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_DECODE_ARGUMENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_DECODE_ARGUMENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_DECODE_ARGUMENT_TYPE").field(&self.0).finish()
@@ -20732,6 +20740,7 @@ pub struct D3D12_VIDEO_DECODE_CONFIGURATION_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_DECODE_CONFIGURATION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_DECODE_CONFIGURATION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_DECODE_CONFIGURATION_FLAGS").field(&self.0).finish()
@@ -20776,6 +20785,7 @@ pub struct D3D12_VIDEO_DECODE_CONVERSION_SUPPORT_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_DECODE_CONVERSION_SUPPORT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_DECODE_CONVERSION_SUPPORT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_DECODE_CONVERSION_SUPPORT_FLAGS").field(&self.0).finish()
@@ -20820,6 +20830,7 @@ pub struct D3D12_VIDEO_DECODE_HISTOGRAM_COMPONENT(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_DECODE_HISTOGRAM_COMPONENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_DECODE_HISTOGRAM_COMPONENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_DECODE_HISTOGRAM_COMPONENT").field(&self.0).finish()
@@ -20831,6 +20842,7 @@ pub struct D3D12_VIDEO_DECODE_HISTOGRAM_COMPONENT_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_DECODE_HISTOGRAM_COMPONENT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_DECODE_HISTOGRAM_COMPONENT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_DECODE_HISTOGRAM_COMPONENT_FLAGS").field(&self.0).finish()
@@ -20875,6 +20887,7 @@ pub struct D3D12_VIDEO_DECODE_STATUS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_DECODE_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_DECODE_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_DECODE_STATUS").field(&self.0).finish()
@@ -20886,6 +20899,7 @@ pub struct D3D12_VIDEO_DECODE_SUPPORT_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_DECODE_SUPPORT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_DECODE_SUPPORT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_DECODE_SUPPORT_FLAGS").field(&self.0).finish()
@@ -20930,6 +20944,7 @@ pub struct D3D12_VIDEO_DECODE_TIER(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_DECODE_TIER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_DECODE_TIER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_DECODE_TIER").field(&self.0).finish()
@@ -20941,6 +20956,7 @@ pub struct D3D12_VIDEO_ENCODER_AV1_COMP_PREDICTION_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_AV1_COMP_PREDICTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_AV1_COMP_PREDICTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_AV1_COMP_PREDICTION_TYPE").field(&self.0).finish()
@@ -20952,6 +20968,7 @@ pub struct D3D12_VIDEO_ENCODER_AV1_FEATURE_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_AV1_FEATURE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_AV1_FEATURE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_AV1_FEATURE_FLAGS").field(&self.0).finish()
@@ -20996,6 +21013,7 @@ pub struct D3D12_VIDEO_ENCODER_AV1_FRAME_SUBREGION_LAYOUT_CONFIG_VALIDATION_FLAG
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_AV1_FRAME_SUBREGION_LAYOUT_CONFIG_VALIDATION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_AV1_FRAME_SUBREGION_LAYOUT_CONFIG_VALIDATION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_AV1_FRAME_SUBREGION_LAYOUT_CONFIG_VALIDATION_FLAGS").field(&self.0).finish()
@@ -21040,6 +21058,7 @@ pub struct D3D12_VIDEO_ENCODER_AV1_FRAME_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_AV1_FRAME_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_AV1_FRAME_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_AV1_FRAME_TYPE").field(&self.0).finish()
@@ -21051,6 +21070,7 @@ pub struct D3D12_VIDEO_ENCODER_AV1_FRAME_TYPE_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_AV1_FRAME_TYPE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_AV1_FRAME_TYPE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_AV1_FRAME_TYPE_FLAGS").field(&self.0).finish()
@@ -21095,6 +21115,7 @@ pub struct D3D12_VIDEO_ENCODER_AV1_INTERPOLATION_FILTERS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_AV1_INTERPOLATION_FILTERS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_AV1_INTERPOLATION_FILTERS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_AV1_INTERPOLATION_FILTERS").field(&self.0).finish()
@@ -21106,6 +21127,7 @@ pub struct D3D12_VIDEO_ENCODER_AV1_INTERPOLATION_FILTERS_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_AV1_INTERPOLATION_FILTERS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_AV1_INTERPOLATION_FILTERS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_AV1_INTERPOLATION_FILTERS_FLAGS").field(&self.0).finish()
@@ -21150,6 +21172,7 @@ pub struct D3D12_VIDEO_ENCODER_AV1_LEVELS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_AV1_LEVELS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_AV1_LEVELS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_AV1_LEVELS").field(&self.0).finish()
@@ -21161,6 +21184,7 @@ pub struct D3D12_VIDEO_ENCODER_AV1_PICTURE_CONTROL_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_AV1_PICTURE_CONTROL_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_AV1_PICTURE_CONTROL_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_AV1_PICTURE_CONTROL_FLAGS").field(&self.0).finish()
@@ -21205,6 +21229,7 @@ pub struct D3D12_VIDEO_ENCODER_AV1_POST_ENCODE_VALUES_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_AV1_POST_ENCODE_VALUES_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_AV1_POST_ENCODE_VALUES_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_AV1_POST_ENCODE_VALUES_FLAGS").field(&self.0).finish()
@@ -21249,6 +21274,7 @@ pub struct D3D12_VIDEO_ENCODER_AV1_PROFILE(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_AV1_PROFILE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_AV1_PROFILE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_AV1_PROFILE").field(&self.0).finish()
@@ -21260,6 +21286,7 @@ pub struct D3D12_VIDEO_ENCODER_AV1_REFERENCE_WARPED_MOTION_TRANSFORMATION(pub i3
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_AV1_REFERENCE_WARPED_MOTION_TRANSFORMATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_AV1_REFERENCE_WARPED_MOTION_TRANSFORMATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_AV1_REFERENCE_WARPED_MOTION_TRANSFORMATION").field(&self.0).finish()
@@ -21271,6 +21298,7 @@ pub struct D3D12_VIDEO_ENCODER_AV1_REFERENCE_WARPED_MOTION_TRANSFORMATION_FLAGS(
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_AV1_REFERENCE_WARPED_MOTION_TRANSFORMATION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_AV1_REFERENCE_WARPED_MOTION_TRANSFORMATION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_AV1_REFERENCE_WARPED_MOTION_TRANSFORMATION_FLAGS").field(&self.0).finish()
@@ -21315,6 +21343,7 @@ pub struct D3D12_VIDEO_ENCODER_AV1_RESTORATION_SUPPORT_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_AV1_RESTORATION_SUPPORT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_AV1_RESTORATION_SUPPORT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_AV1_RESTORATION_SUPPORT_FLAGS").field(&self.0).finish()
@@ -21359,6 +21388,7 @@ pub struct D3D12_VIDEO_ENCODER_AV1_RESTORATION_TILESIZE(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_AV1_RESTORATION_TILESIZE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_AV1_RESTORATION_TILESIZE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_AV1_RESTORATION_TILESIZE").field(&self.0).finish()
@@ -21370,6 +21400,7 @@ pub struct D3D12_VIDEO_ENCODER_AV1_RESTORATION_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_AV1_RESTORATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_AV1_RESTORATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_AV1_RESTORATION_TYPE").field(&self.0).finish()
@@ -21381,6 +21412,7 @@ pub struct D3D12_VIDEO_ENCODER_AV1_SEGMENTATION_BLOCK_SIZE(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_AV1_SEGMENTATION_BLOCK_SIZE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_AV1_SEGMENTATION_BLOCK_SIZE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_AV1_SEGMENTATION_BLOCK_SIZE").field(&self.0).finish()
@@ -21392,6 +21424,7 @@ pub struct D3D12_VIDEO_ENCODER_AV1_SEGMENTATION_MODE(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_AV1_SEGMENTATION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_AV1_SEGMENTATION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_AV1_SEGMENTATION_MODE").field(&self.0).finish()
@@ -21403,6 +21436,7 @@ pub struct D3D12_VIDEO_ENCODER_AV1_SEGMENTATION_MODE_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_AV1_SEGMENTATION_MODE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_AV1_SEGMENTATION_MODE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_AV1_SEGMENTATION_MODE_FLAGS").field(&self.0).finish()
@@ -21447,6 +21481,7 @@ pub struct D3D12_VIDEO_ENCODER_AV1_TIER(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_AV1_TIER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_AV1_TIER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_AV1_TIER").field(&self.0).finish()
@@ -21458,6 +21493,7 @@ pub struct D3D12_VIDEO_ENCODER_AV1_TX_MODE(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_AV1_TX_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_AV1_TX_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_AV1_TX_MODE").field(&self.0).finish()
@@ -21469,6 +21505,7 @@ pub struct D3D12_VIDEO_ENCODER_AV1_TX_MODE_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_AV1_TX_MODE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_AV1_TX_MODE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_AV1_TX_MODE_FLAGS").field(&self.0).finish()
@@ -21513,6 +21550,7 @@ pub struct D3D12_VIDEO_ENCODER_CODEC(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_CODEC {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_CODEC {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_CODEC").field(&self.0).finish()
@@ -21524,6 +21562,7 @@ pub struct D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_H264_DIRECT_MODES(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_H264_DIRECT_MODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_H264_DIRECT_MODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_H264_DIRECT_MODES").field(&self.0).finish()
@@ -21535,6 +21574,7 @@ pub struct D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_H264_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_H264_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_H264_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_H264_FLAGS").field(&self.0).finish()
@@ -21579,6 +21619,7 @@ pub struct D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_H264_SLICES_DEBLOCKING_MODES(
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_H264_SLICES_DEBLOCKING_MODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_H264_SLICES_DEBLOCKING_MODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_H264_SLICES_DEBLOCKING_MODES").field(&self.0).finish()
@@ -21590,6 +21631,7 @@ pub struct D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_H264_SLICES_DEBLOCKING_MODE_F
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_H264_SLICES_DEBLOCKING_MODE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_H264_SLICES_DEBLOCKING_MODE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_H264_SLICES_DEBLOCKING_MODE_FLAGS").field(&self.0).finish()
@@ -21634,6 +21676,7 @@ pub struct D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_HEVC_CUSIZE(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_HEVC_CUSIZE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_HEVC_CUSIZE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_HEVC_CUSIZE").field(&self.0).finish()
@@ -21645,6 +21688,7 @@ pub struct D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_HEVC_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_HEVC_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_HEVC_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_HEVC_FLAGS").field(&self.0).finish()
@@ -21689,6 +21733,7 @@ pub struct D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_HEVC_TUSIZE(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_HEVC_TUSIZE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_HEVC_TUSIZE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_HEVC_TUSIZE").field(&self.0).finish()
@@ -21700,6 +21745,7 @@ pub struct D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_SUPPORT_H264_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_SUPPORT_H264_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_SUPPORT_H264_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_SUPPORT_H264_FLAGS").field(&self.0).finish()
@@ -21744,6 +21790,7 @@ pub struct D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_SUPPORT_HEVC_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_SUPPORT_HEVC_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_SUPPORT_HEVC_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_SUPPORT_HEVC_FLAGS").field(&self.0).finish()
@@ -21788,6 +21835,7 @@ pub struct D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_SUPPORT_HEVC_FLAGS1(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_SUPPORT_HEVC_FLAGS1 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_SUPPORT_HEVC_FLAGS1 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_SUPPORT_HEVC_FLAGS1").field(&self.0).finish()
@@ -21832,6 +21880,7 @@ pub struct D3D12_VIDEO_ENCODER_ENCODE_ERROR_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_ENCODE_ERROR_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_ENCODE_ERROR_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_ENCODE_ERROR_FLAGS").field(&self.0).finish()
@@ -21876,6 +21925,7 @@ pub struct D3D12_VIDEO_ENCODER_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_FLAGS").field(&self.0).finish()
@@ -21920,6 +21970,7 @@ pub struct D3D12_VIDEO_ENCODER_FRAME_SUBREGION_LAYOUT_MODE(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_FRAME_SUBREGION_LAYOUT_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_FRAME_SUBREGION_LAYOUT_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_FRAME_SUBREGION_LAYOUT_MODE").field(&self.0).finish()
@@ -21931,6 +21982,7 @@ pub struct D3D12_VIDEO_ENCODER_FRAME_TYPE_H264(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_FRAME_TYPE_H264 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_FRAME_TYPE_H264 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_FRAME_TYPE_H264").field(&self.0).finish()
@@ -21942,6 +21994,7 @@ pub struct D3D12_VIDEO_ENCODER_FRAME_TYPE_HEVC(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_FRAME_TYPE_HEVC {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_FRAME_TYPE_HEVC {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_FRAME_TYPE_HEVC").field(&self.0).finish()
@@ -21953,6 +22006,7 @@ pub struct D3D12_VIDEO_ENCODER_HEAP_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_HEAP_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_HEAP_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_HEAP_FLAGS").field(&self.0).finish()
@@ -21997,6 +22051,7 @@ pub struct D3D12_VIDEO_ENCODER_INTRA_REFRESH_MODE(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_INTRA_REFRESH_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_INTRA_REFRESH_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_INTRA_REFRESH_MODE").field(&self.0).finish()
@@ -22008,6 +22063,7 @@ pub struct D3D12_VIDEO_ENCODER_LEVELS_H264(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_LEVELS_H264 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_LEVELS_H264 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_LEVELS_H264").field(&self.0).finish()
@@ -22019,6 +22075,7 @@ pub struct D3D12_VIDEO_ENCODER_LEVELS_HEVC(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_LEVELS_HEVC {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_LEVELS_HEVC {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_LEVELS_HEVC").field(&self.0).finish()
@@ -22030,6 +22087,7 @@ pub struct D3D12_VIDEO_ENCODER_MOTION_ESTIMATION_PRECISION_MODE(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_MOTION_ESTIMATION_PRECISION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_MOTION_ESTIMATION_PRECISION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_MOTION_ESTIMATION_PRECISION_MODE").field(&self.0).finish()
@@ -22041,6 +22099,7 @@ pub struct D3D12_VIDEO_ENCODER_PICTURE_CONTROL_CODEC_DATA_H264_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_PICTURE_CONTROL_CODEC_DATA_H264_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_PICTURE_CONTROL_CODEC_DATA_H264_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_PICTURE_CONTROL_CODEC_DATA_H264_FLAGS").field(&self.0).finish()
@@ -22085,6 +22144,7 @@ pub struct D3D12_VIDEO_ENCODER_PICTURE_CONTROL_CODEC_DATA_HEVC_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_PICTURE_CONTROL_CODEC_DATA_HEVC_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_PICTURE_CONTROL_CODEC_DATA_HEVC_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_PICTURE_CONTROL_CODEC_DATA_HEVC_FLAGS").field(&self.0).finish()
@@ -22129,6 +22189,7 @@ pub struct D3D12_VIDEO_ENCODER_PICTURE_CONTROL_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_PICTURE_CONTROL_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_PICTURE_CONTROL_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_PICTURE_CONTROL_FLAGS").field(&self.0).finish()
@@ -22173,6 +22234,7 @@ pub struct D3D12_VIDEO_ENCODER_PROFILE_H264(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_PROFILE_H264 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_PROFILE_H264 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_PROFILE_H264").field(&self.0).finish()
@@ -22184,6 +22246,7 @@ pub struct D3D12_VIDEO_ENCODER_PROFILE_HEVC(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_PROFILE_HEVC {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_PROFILE_HEVC {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_PROFILE_HEVC").field(&self.0).finish()
@@ -22195,6 +22258,7 @@ pub struct D3D12_VIDEO_ENCODER_RATE_CONTROL_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_RATE_CONTROL_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_RATE_CONTROL_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_RATE_CONTROL_FLAGS").field(&self.0).finish()
@@ -22239,6 +22303,7 @@ pub struct D3D12_VIDEO_ENCODER_RATE_CONTROL_MODE(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_RATE_CONTROL_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_RATE_CONTROL_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_RATE_CONTROL_MODE").field(&self.0).finish()
@@ -22250,6 +22315,7 @@ pub struct D3D12_VIDEO_ENCODER_SEQUENCE_CONTROL_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_SEQUENCE_CONTROL_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_SEQUENCE_CONTROL_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_SEQUENCE_CONTROL_FLAGS").field(&self.0).finish()
@@ -22294,6 +22360,7 @@ pub struct D3D12_VIDEO_ENCODER_SUPPORT_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_SUPPORT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_SUPPORT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_SUPPORT_FLAGS").field(&self.0).finish()
@@ -22338,6 +22405,7 @@ pub struct D3D12_VIDEO_ENCODER_TIER_HEVC(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_TIER_HEVC {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_TIER_HEVC {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_TIER_HEVC").field(&self.0).finish()
@@ -22349,6 +22417,7 @@ pub struct D3D12_VIDEO_ENCODER_VALIDATION_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_ENCODER_VALIDATION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_ENCODER_VALIDATION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_ENCODER_VALIDATION_FLAGS").field(&self.0).finish()
@@ -22393,6 +22462,7 @@ pub struct D3D12_VIDEO_EXTENSION_COMMAND_PARAMETER_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_EXTENSION_COMMAND_PARAMETER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_EXTENSION_COMMAND_PARAMETER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_EXTENSION_COMMAND_PARAMETER_FLAGS").field(&self.0).finish()
@@ -22437,6 +22507,7 @@ pub struct D3D12_VIDEO_EXTENSION_COMMAND_PARAMETER_STAGE(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_EXTENSION_COMMAND_PARAMETER_STAGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_EXTENSION_COMMAND_PARAMETER_STAGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_EXTENSION_COMMAND_PARAMETER_STAGE").field(&self.0).finish()
@@ -22448,6 +22519,7 @@ pub struct D3D12_VIDEO_EXTENSION_COMMAND_PARAMETER_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_EXTENSION_COMMAND_PARAMETER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_EXTENSION_COMMAND_PARAMETER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_EXTENSION_COMMAND_PARAMETER_TYPE").field(&self.0).finish()
@@ -22459,6 +22531,7 @@ pub struct D3D12_VIDEO_FIELD_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_FIELD_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_FIELD_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_FIELD_TYPE").field(&self.0).finish()
@@ -22470,6 +22543,7 @@ pub struct D3D12_VIDEO_FRAME_CODED_INTERLACE_TYPE(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_FRAME_CODED_INTERLACE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_FRAME_CODED_INTERLACE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_FRAME_CODED_INTERLACE_TYPE").field(&self.0).finish()
@@ -22481,6 +22555,7 @@ pub struct D3D12_VIDEO_FRAME_STEREO_FORMAT(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_FRAME_STEREO_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_FRAME_STEREO_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_FRAME_STEREO_FORMAT").field(&self.0).finish()
@@ -22492,6 +22567,7 @@ pub struct D3D12_VIDEO_MOTION_ESTIMATOR_SEARCH_BLOCK_SIZE(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_MOTION_ESTIMATOR_SEARCH_BLOCK_SIZE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_MOTION_ESTIMATOR_SEARCH_BLOCK_SIZE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_MOTION_ESTIMATOR_SEARCH_BLOCK_SIZE").field(&self.0).finish()
@@ -22503,6 +22579,7 @@ pub struct D3D12_VIDEO_MOTION_ESTIMATOR_SEARCH_BLOCK_SIZE_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_MOTION_ESTIMATOR_SEARCH_BLOCK_SIZE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_MOTION_ESTIMATOR_SEARCH_BLOCK_SIZE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_MOTION_ESTIMATOR_SEARCH_BLOCK_SIZE_FLAGS").field(&self.0).finish()
@@ -22547,6 +22624,7 @@ pub struct D3D12_VIDEO_MOTION_ESTIMATOR_VECTOR_PRECISION(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_MOTION_ESTIMATOR_VECTOR_PRECISION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_MOTION_ESTIMATOR_VECTOR_PRECISION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_MOTION_ESTIMATOR_VECTOR_PRECISION").field(&self.0).finish()
@@ -22558,6 +22636,7 @@ pub struct D3D12_VIDEO_MOTION_ESTIMATOR_VECTOR_PRECISION_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_MOTION_ESTIMATOR_VECTOR_PRECISION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_MOTION_ESTIMATOR_VECTOR_PRECISION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_MOTION_ESTIMATOR_VECTOR_PRECISION_FLAGS").field(&self.0).finish()
@@ -22602,6 +22681,7 @@ pub struct D3D12_VIDEO_PROCESS_ALPHA_FILL_MODE(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_PROCESS_ALPHA_FILL_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_PROCESS_ALPHA_FILL_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_PROCESS_ALPHA_FILL_MODE").field(&self.0).finish()
@@ -22613,6 +22693,7 @@ pub struct D3D12_VIDEO_PROCESS_AUTO_PROCESSING_FLAGS(pub u32);
 impl windows_core::TypeKind for D3D12_VIDEO_PROCESS_AUTO_PROCESSING_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_PROCESS_AUTO_PROCESSING_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_PROCESS_AUTO_PROCESSING_FLAGS").field(&self.0).finish()
@@ -22657,6 +22738,7 @@ pub struct D3D12_VIDEO_PROCESS_DEINTERLACE_FLAGS(pub u32);
 impl windows_core::TypeKind for D3D12_VIDEO_PROCESS_DEINTERLACE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_PROCESS_DEINTERLACE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_PROCESS_DEINTERLACE_FLAGS").field(&self.0).finish()
@@ -22701,6 +22783,7 @@ pub struct D3D12_VIDEO_PROCESS_FEATURE_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_PROCESS_FEATURE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_PROCESS_FEATURE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_PROCESS_FEATURE_FLAGS").field(&self.0).finish()
@@ -22745,6 +22828,7 @@ pub struct D3D12_VIDEO_PROCESS_FILTER(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_PROCESS_FILTER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_PROCESS_FILTER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_PROCESS_FILTER").field(&self.0).finish()
@@ -22756,6 +22840,7 @@ pub struct D3D12_VIDEO_PROCESS_FILTER_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_PROCESS_FILTER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_PROCESS_FILTER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_PROCESS_FILTER_FLAGS").field(&self.0).finish()
@@ -22800,6 +22885,7 @@ pub struct D3D12_VIDEO_PROCESS_INPUT_STREAM_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_PROCESS_INPUT_STREAM_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_PROCESS_INPUT_STREAM_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_PROCESS_INPUT_STREAM_FLAGS").field(&self.0).finish()
@@ -22844,6 +22930,7 @@ pub struct D3D12_VIDEO_PROCESS_ORIENTATION(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_PROCESS_ORIENTATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_PROCESS_ORIENTATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_PROCESS_ORIENTATION").field(&self.0).finish()
@@ -22855,6 +22942,7 @@ pub struct D3D12_VIDEO_PROCESS_SUPPORT_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_PROCESS_SUPPORT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_PROCESS_SUPPORT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_PROCESS_SUPPORT_FLAGS").field(&self.0).finish()
@@ -22899,6 +22987,7 @@ pub struct D3D12_VIDEO_PROTECTED_RESOURCE_SUPPORT_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_PROTECTED_RESOURCE_SUPPORT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_PROTECTED_RESOURCE_SUPPORT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_PROTECTED_RESOURCE_SUPPORT_FLAGS").field(&self.0).finish()
@@ -22943,6 +23032,7 @@ pub struct D3D12_VIDEO_SCALE_SUPPORT_FLAGS(pub i32);
 impl windows_core::TypeKind for D3D12_VIDEO_SCALE_SUPPORT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for D3D12_VIDEO_SCALE_SUPPORT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("D3D12_VIDEO_SCALE_SUPPORT_FLAGS").field(&self.0).finish()
@@ -22987,6 +23077,7 @@ pub struct DXVA2_BufferfType(pub i32);
 impl windows_core::TypeKind for DXVA2_BufferfType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA2_BufferfType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA2_BufferfType").field(&self.0).finish()
@@ -22998,6 +23089,7 @@ pub struct DXVA2_DeinterlaceTech(pub i32);
 impl windows_core::TypeKind for DXVA2_DeinterlaceTech {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA2_DeinterlaceTech {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA2_DeinterlaceTech").field(&self.0).finish()
@@ -23009,6 +23101,7 @@ pub struct DXVA2_DestData(pub i32);
 impl windows_core::TypeKind for DXVA2_DestData {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA2_DestData {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA2_DestData").field(&self.0).finish()
@@ -23020,6 +23113,7 @@ pub struct DXVA2_DetailFilterTech(pub i32);
 impl windows_core::TypeKind for DXVA2_DetailFilterTech {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA2_DetailFilterTech {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA2_DetailFilterTech").field(&self.0).finish()
@@ -23031,6 +23125,7 @@ pub struct DXVA2_FilterType(pub i32);
 impl windows_core::TypeKind for DXVA2_FilterType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA2_FilterType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA2_FilterType").field(&self.0).finish()
@@ -23042,6 +23137,7 @@ pub struct DXVA2_NoiseFilterTech(pub i32);
 impl windows_core::TypeKind for DXVA2_NoiseFilterTech {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA2_NoiseFilterTech {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA2_NoiseFilterTech").field(&self.0).finish()
@@ -23053,6 +23149,7 @@ pub struct DXVA2_NominalRange(pub i32);
 impl windows_core::TypeKind for DXVA2_NominalRange {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA2_NominalRange {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA2_NominalRange").field(&self.0).finish()
@@ -23064,6 +23161,7 @@ pub struct DXVA2_ProcAmp(pub i32);
 impl windows_core::TypeKind for DXVA2_ProcAmp {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA2_ProcAmp {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA2_ProcAmp").field(&self.0).finish()
@@ -23075,6 +23173,7 @@ pub struct DXVA2_SampleData(pub i32);
 impl windows_core::TypeKind for DXVA2_SampleData {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA2_SampleData {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA2_SampleData").field(&self.0).finish()
@@ -23086,6 +23185,7 @@ pub struct DXVA2_SampleFormat(pub i32);
 impl windows_core::TypeKind for DXVA2_SampleFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA2_SampleFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA2_SampleFormat").field(&self.0).finish()
@@ -23097,6 +23197,7 @@ pub struct DXVA2_SurfaceType(pub i32);
 impl windows_core::TypeKind for DXVA2_SurfaceType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA2_SurfaceType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA2_SurfaceType").field(&self.0).finish()
@@ -23108,6 +23209,7 @@ pub struct DXVA2_VPDev(pub i32);
 impl windows_core::TypeKind for DXVA2_VPDev {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA2_VPDev {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA2_VPDev").field(&self.0).finish()
@@ -23119,6 +23221,7 @@ pub struct DXVA2_VideoChromaSubSampling(pub i32);
 impl windows_core::TypeKind for DXVA2_VideoChromaSubSampling {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA2_VideoChromaSubSampling {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA2_VideoChromaSubSampling").field(&self.0).finish()
@@ -23130,6 +23233,7 @@ pub struct DXVA2_VideoLighting(pub i32);
 impl windows_core::TypeKind for DXVA2_VideoLighting {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA2_VideoLighting {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA2_VideoLighting").field(&self.0).finish()
@@ -23141,6 +23245,7 @@ pub struct DXVA2_VideoPrimaries(pub i32);
 impl windows_core::TypeKind for DXVA2_VideoPrimaries {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA2_VideoPrimaries {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA2_VideoPrimaries").field(&self.0).finish()
@@ -23152,6 +23257,7 @@ pub struct DXVA2_VideoProcess(pub i32);
 impl windows_core::TypeKind for DXVA2_VideoProcess {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA2_VideoProcess {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA2_VideoProcess").field(&self.0).finish()
@@ -23163,6 +23269,7 @@ pub struct DXVA2_VideoRenderTargetType(pub i32);
 impl windows_core::TypeKind for DXVA2_VideoRenderTargetType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA2_VideoRenderTargetType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA2_VideoRenderTargetType").field(&self.0).finish()
@@ -23174,6 +23281,7 @@ pub struct DXVA2_VideoTransferFunction(pub i32);
 impl windows_core::TypeKind for DXVA2_VideoTransferFunction {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA2_VideoTransferFunction {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA2_VideoTransferFunction").field(&self.0).finish()
@@ -23185,6 +23293,7 @@ pub struct DXVA2_VideoTransferMatrix(pub i32);
 impl windows_core::TypeKind for DXVA2_VideoTransferMatrix {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA2_VideoTransferMatrix {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA2_VideoTransferMatrix").field(&self.0).finish()
@@ -23196,6 +23305,7 @@ pub struct DXVAHD_ALPHA_FILL_MODE(pub i32);
 impl windows_core::TypeKind for DXVAHD_ALPHA_FILL_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVAHD_ALPHA_FILL_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVAHD_ALPHA_FILL_MODE").field(&self.0).finish()
@@ -23207,6 +23317,7 @@ pub struct DXVAHD_BLT_STATE(pub i32);
 impl windows_core::TypeKind for DXVAHD_BLT_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVAHD_BLT_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVAHD_BLT_STATE").field(&self.0).finish()
@@ -23218,6 +23329,7 @@ pub struct DXVAHD_DEVICE_CAPS(pub i32);
 impl windows_core::TypeKind for DXVAHD_DEVICE_CAPS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVAHD_DEVICE_CAPS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVAHD_DEVICE_CAPS").field(&self.0).finish()
@@ -23229,6 +23341,7 @@ pub struct DXVAHD_DEVICE_TYPE(pub i32);
 impl windows_core::TypeKind for DXVAHD_DEVICE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVAHD_DEVICE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVAHD_DEVICE_TYPE").field(&self.0).finish()
@@ -23240,6 +23353,7 @@ pub struct DXVAHD_DEVICE_USAGE(pub i32);
 impl windows_core::TypeKind for DXVAHD_DEVICE_USAGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVAHD_DEVICE_USAGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVAHD_DEVICE_USAGE").field(&self.0).finish()
@@ -23251,6 +23365,7 @@ pub struct DXVAHD_FEATURE_CAPS(pub i32);
 impl windows_core::TypeKind for DXVAHD_FEATURE_CAPS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVAHD_FEATURE_CAPS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVAHD_FEATURE_CAPS").field(&self.0).finish()
@@ -23262,6 +23377,7 @@ pub struct DXVAHD_FILTER(pub i32);
 impl windows_core::TypeKind for DXVAHD_FILTER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVAHD_FILTER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVAHD_FILTER").field(&self.0).finish()
@@ -23273,6 +23389,7 @@ pub struct DXVAHD_FILTER_CAPS(pub i32);
 impl windows_core::TypeKind for DXVAHD_FILTER_CAPS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVAHD_FILTER_CAPS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVAHD_FILTER_CAPS").field(&self.0).finish()
@@ -23284,6 +23401,7 @@ pub struct DXVAHD_FRAME_FORMAT(pub i32);
 impl windows_core::TypeKind for DXVAHD_FRAME_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVAHD_FRAME_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVAHD_FRAME_FORMAT").field(&self.0).finish()
@@ -23295,6 +23413,7 @@ pub struct DXVAHD_INPUT_FORMAT_CAPS(pub i32);
 impl windows_core::TypeKind for DXVAHD_INPUT_FORMAT_CAPS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVAHD_INPUT_FORMAT_CAPS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVAHD_INPUT_FORMAT_CAPS").field(&self.0).finish()
@@ -23306,6 +23425,7 @@ pub struct DXVAHD_ITELECINE_CAPS(pub i32);
 impl windows_core::TypeKind for DXVAHD_ITELECINE_CAPS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVAHD_ITELECINE_CAPS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVAHD_ITELECINE_CAPS").field(&self.0).finish()
@@ -23317,6 +23437,7 @@ pub struct DXVAHD_OUTPUT_RATE(pub i32);
 impl windows_core::TypeKind for DXVAHD_OUTPUT_RATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVAHD_OUTPUT_RATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVAHD_OUTPUT_RATE").field(&self.0).finish()
@@ -23328,6 +23449,7 @@ pub struct DXVAHD_PROCESSOR_CAPS(pub i32);
 impl windows_core::TypeKind for DXVAHD_PROCESSOR_CAPS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVAHD_PROCESSOR_CAPS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVAHD_PROCESSOR_CAPS").field(&self.0).finish()
@@ -23339,6 +23461,7 @@ pub struct DXVAHD_STREAM_STATE(pub i32);
 impl windows_core::TypeKind for DXVAHD_STREAM_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVAHD_STREAM_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVAHD_STREAM_STATE").field(&self.0).finish()
@@ -23350,6 +23473,7 @@ pub struct DXVAHD_SURFACE_TYPE(pub i32);
 impl windows_core::TypeKind for DXVAHD_SURFACE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVAHD_SURFACE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVAHD_SURFACE_TYPE").field(&self.0).finish()
@@ -23361,6 +23485,7 @@ pub struct DXVA_DeinterlaceTech(pub i32);
 impl windows_core::TypeKind for DXVA_DeinterlaceTech {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA_DeinterlaceTech {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA_DeinterlaceTech").field(&self.0).finish()
@@ -23372,6 +23497,7 @@ pub struct DXVA_DestinationFlags(pub i32);
 impl windows_core::TypeKind for DXVA_DestinationFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA_DestinationFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA_DestinationFlags").field(&self.0).finish()
@@ -23383,6 +23509,7 @@ pub struct DXVA_NominalRange(pub i32);
 impl windows_core::TypeKind for DXVA_NominalRange {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA_NominalRange {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA_NominalRange").field(&self.0).finish()
@@ -23394,6 +23521,7 @@ pub struct DXVA_ProcAmpControlProp(pub i32);
 impl windows_core::TypeKind for DXVA_ProcAmpControlProp {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA_ProcAmpControlProp {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA_ProcAmpControlProp").field(&self.0).finish()
@@ -23405,6 +23533,7 @@ pub struct DXVA_SampleFlags(pub i32);
 impl windows_core::TypeKind for DXVA_SampleFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA_SampleFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA_SampleFlags").field(&self.0).finish()
@@ -23416,6 +23545,7 @@ pub struct DXVA_SampleFormat(pub i32);
 impl windows_core::TypeKind for DXVA_SampleFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA_SampleFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA_SampleFormat").field(&self.0).finish()
@@ -23427,6 +23557,7 @@ pub struct DXVA_VideoChromaSubsampling(pub i32);
 impl windows_core::TypeKind for DXVA_VideoChromaSubsampling {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA_VideoChromaSubsampling {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA_VideoChromaSubsampling").field(&self.0).finish()
@@ -23438,6 +23569,7 @@ pub struct DXVA_VideoLighting(pub i32);
 impl windows_core::TypeKind for DXVA_VideoLighting {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA_VideoLighting {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA_VideoLighting").field(&self.0).finish()
@@ -23449,6 +23581,7 @@ pub struct DXVA_VideoPrimaries(pub i32);
 impl windows_core::TypeKind for DXVA_VideoPrimaries {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA_VideoPrimaries {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA_VideoPrimaries").field(&self.0).finish()
@@ -23460,6 +23593,7 @@ pub struct DXVA_VideoProcessCaps(pub i32);
 impl windows_core::TypeKind for DXVA_VideoProcessCaps {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA_VideoProcessCaps {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA_VideoProcessCaps").field(&self.0).finish()
@@ -23471,6 +23605,7 @@ pub struct DXVA_VideoTransferFunction(pub i32);
 impl windows_core::TypeKind for DXVA_VideoTransferFunction {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA_VideoTransferFunction {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA_VideoTransferFunction").field(&self.0).finish()
@@ -23482,6 +23617,7 @@ pub struct DXVA_VideoTransferMatrix(pub i32);
 impl windows_core::TypeKind for DXVA_VideoTransferMatrix {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DXVA_VideoTransferMatrix {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DXVA_VideoTransferMatrix").field(&self.0).finish()
@@ -23493,6 +23629,7 @@ pub struct DeviceStreamState(pub i32);
 impl windows_core::TypeKind for DeviceStreamState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DeviceStreamState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DeviceStreamState").field(&self.0).finish()
@@ -23504,6 +23641,7 @@ pub struct EAllocationType(pub i32);
 impl windows_core::TypeKind for EAllocationType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EAllocationType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EAllocationType").field(&self.0).finish()
@@ -23515,6 +23653,7 @@ pub struct EVRFilterConfigPrefs(pub i32);
 impl windows_core::TypeKind for EVRFilterConfigPrefs {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EVRFilterConfigPrefs {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EVRFilterConfigPrefs").field(&self.0).finish()
@@ -23526,6 +23665,7 @@ pub struct FILE_ACCESSMODE(pub i32);
 impl windows_core::TypeKind for FILE_ACCESSMODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FILE_ACCESSMODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FILE_ACCESSMODE").field(&self.0).finish()
@@ -23537,6 +23677,7 @@ pub struct FILE_OPENMODE(pub i32);
 impl windows_core::TypeKind for FILE_OPENMODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FILE_OPENMODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FILE_OPENMODE").field(&self.0).finish()
@@ -23548,6 +23689,7 @@ pub struct KSMETHOD_OPMVIDEOOUTPUT(pub i32);
 impl windows_core::TypeKind for KSMETHOD_OPMVIDEOOUTPUT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KSMETHOD_OPMVIDEOOUTPUT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KSMETHOD_OPMVIDEOOUTPUT").field(&self.0).finish()
@@ -23559,6 +23701,7 @@ pub struct MEDIA_EVENT_GENERATOR_GET_EVENT_FLAGS(pub u32);
 impl windows_core::TypeKind for MEDIA_EVENT_GENERATOR_GET_EVENT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MEDIA_EVENT_GENERATOR_GET_EVENT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MEDIA_EVENT_GENERATOR_GET_EVENT_FLAGS").field(&self.0).finish()
@@ -23570,6 +23713,7 @@ pub struct MF2DBuffer_LockFlags(pub i32);
 impl windows_core::TypeKind for MF2DBuffer_LockFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF2DBuffer_LockFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF2DBuffer_LockFlags").field(&self.0).finish()
@@ -23581,6 +23725,7 @@ pub struct MF3DVideoOutputType(pub i32);
 impl windows_core::TypeKind for MF3DVideoOutputType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF3DVideoOutputType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF3DVideoOutputType").field(&self.0).finish()
@@ -23592,6 +23737,7 @@ pub struct MFASF_INDEXER_FLAGS(pub i32);
 impl windows_core::TypeKind for MFASF_INDEXER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFASF_INDEXER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFASF_INDEXER_FLAGS").field(&self.0).finish()
@@ -23603,6 +23749,7 @@ pub struct MFASF_MULTIPLEXERFLAGS(pub i32);
 impl windows_core::TypeKind for MFASF_MULTIPLEXERFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFASF_MULTIPLEXERFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFASF_MULTIPLEXERFLAGS").field(&self.0).finish()
@@ -23614,6 +23761,7 @@ pub struct MFASF_SPLITTERFLAGS(pub i32);
 impl windows_core::TypeKind for MFASF_SPLITTERFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFASF_SPLITTERFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFASF_SPLITTERFLAGS").field(&self.0).finish()
@@ -23625,6 +23773,7 @@ pub struct MFASF_STREAMSELECTOR_FLAGS(pub i32);
 impl windows_core::TypeKind for MFASF_STREAMSELECTOR_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFASF_STREAMSELECTOR_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFASF_STREAMSELECTOR_FLAGS").field(&self.0).finish()
@@ -23636,6 +23785,7 @@ pub struct MFASYNC_WORKQUEUE_TYPE(pub i32);
 impl windows_core::TypeKind for MFASYNC_WORKQUEUE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFASYNC_WORKQUEUE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFASYNC_WORKQUEUE_TYPE").field(&self.0).finish()
@@ -23647,6 +23797,7 @@ pub struct MFAudioConstriction(pub i32);
 impl windows_core::TypeKind for MFAudioConstriction {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFAudioConstriction {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFAudioConstriction").field(&self.0).finish()
@@ -23658,6 +23809,7 @@ pub struct MFBYTESTREAM_SEEK_ORIGIN(pub i32);
 impl windows_core::TypeKind for MFBYTESTREAM_SEEK_ORIGIN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFBYTESTREAM_SEEK_ORIGIN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFBYTESTREAM_SEEK_ORIGIN").field(&self.0).finish()
@@ -23669,6 +23821,7 @@ pub struct MFCLOCK_CHARACTERISTICS_FLAGS(pub i32);
 impl windows_core::TypeKind for MFCLOCK_CHARACTERISTICS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFCLOCK_CHARACTERISTICS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFCLOCK_CHARACTERISTICS_FLAGS").field(&self.0).finish()
@@ -23680,6 +23833,7 @@ pub struct MFCLOCK_RELATIONAL_FLAGS(pub i32);
 impl windows_core::TypeKind for MFCLOCK_RELATIONAL_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFCLOCK_RELATIONAL_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFCLOCK_RELATIONAL_FLAGS").field(&self.0).finish()
@@ -23691,6 +23845,7 @@ pub struct MFCLOCK_STATE(pub i32);
 impl windows_core::TypeKind for MFCLOCK_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFCLOCK_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFCLOCK_STATE").field(&self.0).finish()
@@ -23702,6 +23857,7 @@ pub struct MFCameraIntrinsic_DistortionModelType(pub i32);
 impl windows_core::TypeKind for MFCameraIntrinsic_DistortionModelType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFCameraIntrinsic_DistortionModelType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFCameraIntrinsic_DistortionModelType").field(&self.0).finish()
@@ -23713,6 +23869,7 @@ pub struct MFCameraOcclusionState(pub i32);
 impl windows_core::TypeKind for MFCameraOcclusionState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFCameraOcclusionState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFCameraOcclusionState").field(&self.0).finish()
@@ -23757,6 +23914,7 @@ pub struct MFDepthMeasurement(pub i32);
 impl windows_core::TypeKind for MFDepthMeasurement {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFDepthMeasurement {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFDepthMeasurement").field(&self.0).finish()
@@ -23768,6 +23926,7 @@ pub struct MFFrameSourceTypes(pub i32);
 impl windows_core::TypeKind for MFFrameSourceTypes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFFrameSourceTypes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFFrameSourceTypes").field(&self.0).finish()
@@ -23779,6 +23938,7 @@ pub struct MFMEDIASOURCE_CHARACTERISTICS(pub i32);
 impl windows_core::TypeKind for MFMEDIASOURCE_CHARACTERISTICS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFMEDIASOURCE_CHARACTERISTICS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFMEDIASOURCE_CHARACTERISTICS").field(&self.0).finish()
@@ -23790,6 +23950,7 @@ pub struct MFNETSOURCE_CACHE_STATE(pub i32);
 impl windows_core::TypeKind for MFNETSOURCE_CACHE_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFNETSOURCE_CACHE_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFNETSOURCE_CACHE_STATE").field(&self.0).finish()
@@ -23801,6 +23962,7 @@ pub struct MFNETSOURCE_PROTOCOL_TYPE(pub i32);
 impl windows_core::TypeKind for MFNETSOURCE_PROTOCOL_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFNETSOURCE_PROTOCOL_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFNETSOURCE_PROTOCOL_TYPE").field(&self.0).finish()
@@ -23812,6 +23974,7 @@ pub struct MFNETSOURCE_STATISTICS_IDS(pub i32);
 impl windows_core::TypeKind for MFNETSOURCE_STATISTICS_IDS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFNETSOURCE_STATISTICS_IDS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFNETSOURCE_STATISTICS_IDS").field(&self.0).finish()
@@ -23823,6 +23986,7 @@ pub struct MFNETSOURCE_TRANSPORT_TYPE(pub i32);
 impl windows_core::TypeKind for MFNETSOURCE_TRANSPORT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFNETSOURCE_TRANSPORT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFNETSOURCE_TRANSPORT_TYPE").field(&self.0).finish()
@@ -23834,6 +23998,7 @@ pub struct MFNET_PROXYSETTINGS(pub i32);
 impl windows_core::TypeKind for MFNET_PROXYSETTINGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFNET_PROXYSETTINGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFNET_PROXYSETTINGS").field(&self.0).finish()
@@ -23845,6 +24010,7 @@ pub struct MFNetAuthenticationFlags(pub i32);
 impl windows_core::TypeKind for MFNetAuthenticationFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFNetAuthenticationFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFNetAuthenticationFlags").field(&self.0).finish()
@@ -23856,6 +24022,7 @@ pub struct MFNetCredentialOptions(pub i32);
 impl windows_core::TypeKind for MFNetCredentialOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFNetCredentialOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFNetCredentialOptions").field(&self.0).finish()
@@ -23867,6 +24034,7 @@ pub struct MFNetCredentialRequirements(pub i32);
 impl windows_core::TypeKind for MFNetCredentialRequirements {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFNetCredentialRequirements {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFNetCredentialRequirements").field(&self.0).finish()
@@ -23878,6 +24046,7 @@ pub struct MFNominalRange(pub i32);
 impl windows_core::TypeKind for MFNominalRange {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFNominalRange {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFNominalRange").field(&self.0).finish()
@@ -23889,6 +24058,7 @@ pub struct MFPMPSESSION_CREATION_FLAGS(pub i32);
 impl windows_core::TypeKind for MFPMPSESSION_CREATION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFPMPSESSION_CREATION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFPMPSESSION_CREATION_FLAGS").field(&self.0).finish()
@@ -23900,6 +24070,7 @@ pub struct MFPOLICYMANAGER_ACTION(pub i32);
 impl windows_core::TypeKind for MFPOLICYMANAGER_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFPOLICYMANAGER_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFPOLICYMANAGER_ACTION").field(&self.0).finish()
@@ -23911,6 +24082,7 @@ pub struct MFP_CREATION_OPTIONS(pub i32);
 impl windows_core::TypeKind for MFP_CREATION_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFP_CREATION_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFP_CREATION_OPTIONS").field(&self.0).finish()
@@ -23922,6 +24094,7 @@ pub struct MFP_EVENT_TYPE(pub i32);
 impl windows_core::TypeKind for MFP_EVENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFP_EVENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFP_EVENT_TYPE").field(&self.0).finish()
@@ -23933,6 +24106,7 @@ pub struct MFP_MEDIAPLAYER_STATE(pub i32);
 impl windows_core::TypeKind for MFP_MEDIAPLAYER_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFP_MEDIAPLAYER_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFP_MEDIAPLAYER_STATE").field(&self.0).finish()
@@ -23944,6 +24118,7 @@ pub struct MFRATE_DIRECTION(pub i32);
 impl windows_core::TypeKind for MFRATE_DIRECTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFRATE_DIRECTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFRATE_DIRECTION").field(&self.0).finish()
@@ -23955,6 +24130,7 @@ pub struct MFSESSION_GETFULLTOPOLOGY_FLAGS(pub i32);
 impl windows_core::TypeKind for MFSESSION_GETFULLTOPOLOGY_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFSESSION_GETFULLTOPOLOGY_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFSESSION_GETFULLTOPOLOGY_FLAGS").field(&self.0).finish()
@@ -23966,6 +24142,7 @@ pub struct MFSESSION_SETTOPOLOGY_FLAGS(pub i32);
 impl windows_core::TypeKind for MFSESSION_SETTOPOLOGY_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFSESSION_SETTOPOLOGY_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFSESSION_SETTOPOLOGY_FLAGS").field(&self.0).finish()
@@ -23977,6 +24154,7 @@ pub struct MFSHUTDOWN_STATUS(pub i32);
 impl windows_core::TypeKind for MFSHUTDOWN_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFSHUTDOWN_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFSHUTDOWN_STATUS").field(&self.0).finish()
@@ -23988,6 +24166,7 @@ pub struct MFSINK_WMDRMACTION(pub i32);
 impl windows_core::TypeKind for MFSINK_WMDRMACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFSINK_WMDRMACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFSINK_WMDRMACTION").field(&self.0).finish()
@@ -23999,6 +24178,7 @@ pub struct MFSTREAMSINK_MARKER_TYPE(pub i32);
 impl windows_core::TypeKind for MFSTREAMSINK_MARKER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFSTREAMSINK_MARKER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFSTREAMSINK_MARKER_TYPE").field(&self.0).finish()
@@ -24010,6 +24190,7 @@ pub struct MFSampleAllocatorUsage(pub i32);
 impl windows_core::TypeKind for MFSampleAllocatorUsage {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFSampleAllocatorUsage {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFSampleAllocatorUsage").field(&self.0).finish()
@@ -24021,6 +24202,7 @@ pub struct MFSampleEncryptionProtectionScheme(pub i32);
 impl windows_core::TypeKind for MFSampleEncryptionProtectionScheme {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFSampleEncryptionProtectionScheme {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFSampleEncryptionProtectionScheme").field(&self.0).finish()
@@ -24032,6 +24214,7 @@ pub struct MFSensorDeviceMode(pub i32);
 impl windows_core::TypeKind for MFSensorDeviceMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFSensorDeviceMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFSensorDeviceMode").field(&self.0).finish()
@@ -24043,6 +24226,7 @@ pub struct MFSensorDeviceType(pub i32);
 impl windows_core::TypeKind for MFSensorDeviceType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFSensorDeviceType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFSensorDeviceType").field(&self.0).finish()
@@ -24054,6 +24238,7 @@ pub struct MFSensorStreamType(pub i32);
 impl windows_core::TypeKind for MFSensorStreamType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFSensorStreamType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFSensorStreamType").field(&self.0).finish()
@@ -24065,6 +24250,7 @@ pub struct MFSequencerTopologyFlags(pub i32);
 impl windows_core::TypeKind for MFSequencerTopologyFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFSequencerTopologyFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFSequencerTopologyFlags").field(&self.0).finish()
@@ -24076,6 +24262,7 @@ pub struct MFStandardVideoFormat(pub i32);
 impl windows_core::TypeKind for MFStandardVideoFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFStandardVideoFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFStandardVideoFormat").field(&self.0).finish()
@@ -24087,6 +24274,7 @@ pub struct MFTIMER_FLAGS(pub i32);
 impl windows_core::TypeKind for MFTIMER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFTIMER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFTIMER_FLAGS").field(&self.0).finish()
@@ -24098,6 +24286,7 @@ pub struct MFTOPOLOGY_DXVA_MODE(pub i32);
 impl windows_core::TypeKind for MFTOPOLOGY_DXVA_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFTOPOLOGY_DXVA_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFTOPOLOGY_DXVA_MODE").field(&self.0).finish()
@@ -24109,6 +24298,7 @@ pub struct MFTOPOLOGY_HARDWARE_MODE(pub i32);
 impl windows_core::TypeKind for MFTOPOLOGY_HARDWARE_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFTOPOLOGY_HARDWARE_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFTOPOLOGY_HARDWARE_MODE").field(&self.0).finish()
@@ -24120,6 +24310,7 @@ pub struct MFT_AUDIO_DECODER_DEGRADATION_REASON(pub i32);
 impl windows_core::TypeKind for MFT_AUDIO_DECODER_DEGRADATION_REASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFT_AUDIO_DECODER_DEGRADATION_REASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFT_AUDIO_DECODER_DEGRADATION_REASON").field(&self.0).finish()
@@ -24131,6 +24322,7 @@ pub struct MFT_AUDIO_DECODER_DEGRADATION_TYPE(pub i32);
 impl windows_core::TypeKind for MFT_AUDIO_DECODER_DEGRADATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFT_AUDIO_DECODER_DEGRADATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFT_AUDIO_DECODER_DEGRADATION_TYPE").field(&self.0).finish()
@@ -24142,6 +24334,7 @@ pub struct MFT_DRAIN_TYPE(pub i32);
 impl windows_core::TypeKind for MFT_DRAIN_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFT_DRAIN_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFT_DRAIN_TYPE").field(&self.0).finish()
@@ -24153,6 +24346,7 @@ pub struct MFT_ENUM_FLAG(pub i32);
 impl windows_core::TypeKind for MFT_ENUM_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFT_ENUM_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFT_ENUM_FLAG").field(&self.0).finish()
@@ -24197,6 +24391,7 @@ pub struct MFT_MESSAGE_TYPE(pub i32);
 impl windows_core::TypeKind for MFT_MESSAGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFT_MESSAGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFT_MESSAGE_TYPE").field(&self.0).finish()
@@ -24208,6 +24403,7 @@ pub struct MFVP_MESSAGE_TYPE(pub i32);
 impl windows_core::TypeKind for MFVP_MESSAGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFVP_MESSAGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFVP_MESSAGE_TYPE").field(&self.0).finish()
@@ -24219,6 +24415,7 @@ pub struct MFVideo3DFormat(pub i32);
 impl windows_core::TypeKind for MFVideo3DFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFVideo3DFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFVideo3DFormat").field(&self.0).finish()
@@ -24230,6 +24427,7 @@ pub struct MFVideo3DSampleFormat(pub i32);
 impl windows_core::TypeKind for MFVideo3DSampleFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFVideo3DSampleFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFVideo3DSampleFormat").field(&self.0).finish()
@@ -24241,6 +24439,7 @@ pub struct MFVideoAlphaBitmapFlags(pub i32);
 impl windows_core::TypeKind for MFVideoAlphaBitmapFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFVideoAlphaBitmapFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFVideoAlphaBitmapFlags").field(&self.0).finish()
@@ -24252,6 +24451,7 @@ pub struct MFVideoAspectRatioMode(pub i32);
 impl windows_core::TypeKind for MFVideoAspectRatioMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFVideoAspectRatioMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFVideoAspectRatioMode").field(&self.0).finish()
@@ -24263,6 +24463,7 @@ pub struct MFVideoChromaSubsampling(pub i32);
 impl windows_core::TypeKind for MFVideoChromaSubsampling {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFVideoChromaSubsampling {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFVideoChromaSubsampling").field(&self.0).finish()
@@ -24274,6 +24475,7 @@ pub struct MFVideoDRMFlags(pub i32);
 impl windows_core::TypeKind for MFVideoDRMFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFVideoDRMFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFVideoDRMFlags").field(&self.0).finish()
@@ -24285,6 +24487,7 @@ pub struct MFVideoDSPMode(pub i32);
 impl windows_core::TypeKind for MFVideoDSPMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFVideoDSPMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFVideoDSPMode").field(&self.0).finish()
@@ -24296,6 +24499,7 @@ pub struct MFVideoFlags(pub i32);
 impl windows_core::TypeKind for MFVideoFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFVideoFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFVideoFlags").field(&self.0).finish()
@@ -24307,6 +24511,7 @@ pub struct MFVideoInterlaceMode(pub i32);
 impl windows_core::TypeKind for MFVideoInterlaceMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFVideoInterlaceMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFVideoInterlaceMode").field(&self.0).finish()
@@ -24318,6 +24523,7 @@ pub struct MFVideoLighting(pub i32);
 impl windows_core::TypeKind for MFVideoLighting {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFVideoLighting {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFVideoLighting").field(&self.0).finish()
@@ -24329,6 +24535,7 @@ pub struct MFVideoMixPrefs(pub i32);
 impl windows_core::TypeKind for MFVideoMixPrefs {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFVideoMixPrefs {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFVideoMixPrefs").field(&self.0).finish()
@@ -24340,6 +24547,7 @@ pub struct MFVideoPadFlags(pub i32);
 impl windows_core::TypeKind for MFVideoPadFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFVideoPadFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFVideoPadFlags").field(&self.0).finish()
@@ -24351,6 +24559,7 @@ pub struct MFVideoPrimaries(pub i32);
 impl windows_core::TypeKind for MFVideoPrimaries {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFVideoPrimaries {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFVideoPrimaries").field(&self.0).finish()
@@ -24362,6 +24571,7 @@ pub struct MFVideoRenderPrefs(pub i32);
 impl windows_core::TypeKind for MFVideoRenderPrefs {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFVideoRenderPrefs {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFVideoRenderPrefs").field(&self.0).finish()
@@ -24373,6 +24583,7 @@ pub struct MFVideoRotationFormat(pub i32);
 impl windows_core::TypeKind for MFVideoRotationFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFVideoRotationFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFVideoRotationFormat").field(&self.0).finish()
@@ -24384,6 +24595,7 @@ pub struct MFVideoSphericalFormat(pub i32);
 impl windows_core::TypeKind for MFVideoSphericalFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFVideoSphericalFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFVideoSphericalFormat").field(&self.0).finish()
@@ -24395,6 +24607,7 @@ pub struct MFVideoSphericalProjectionMode(pub i32);
 impl windows_core::TypeKind for MFVideoSphericalProjectionMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFVideoSphericalProjectionMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFVideoSphericalProjectionMode").field(&self.0).finish()
@@ -24406,6 +24619,7 @@ pub struct MFVideoSrcContentHintFlags(pub i32);
 impl windows_core::TypeKind for MFVideoSrcContentHintFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFVideoSrcContentHintFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFVideoSrcContentHintFlags").field(&self.0).finish()
@@ -24417,6 +24631,7 @@ pub struct MFVideoTransferFunction(pub i32);
 impl windows_core::TypeKind for MFVideoTransferFunction {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFVideoTransferFunction {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFVideoTransferFunction").field(&self.0).finish()
@@ -24428,6 +24643,7 @@ pub struct MFVideoTransferMatrix(pub i32);
 impl windows_core::TypeKind for MFVideoTransferMatrix {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFVideoTransferMatrix {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFVideoTransferMatrix").field(&self.0).finish()
@@ -24439,6 +24655,7 @@ pub struct MFVirtualCameraAccess(pub i32);
 impl windows_core::TypeKind for MFVirtualCameraAccess {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFVirtualCameraAccess {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFVirtualCameraAccess").field(&self.0).finish()
@@ -24450,6 +24667,7 @@ pub struct MFVirtualCameraLifetime(pub i32);
 impl windows_core::TypeKind for MFVirtualCameraLifetime {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFVirtualCameraLifetime {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFVirtualCameraLifetime").field(&self.0).finish()
@@ -24461,6 +24679,7 @@ pub struct MFVirtualCameraType(pub i32);
 impl windows_core::TypeKind for MFVirtualCameraType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFVirtualCameraType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFVirtualCameraType").field(&self.0).finish()
@@ -24472,6 +24691,7 @@ pub struct MFWaveFormatExConvertFlags(pub i32);
 impl windows_core::TypeKind for MFWaveFormatExConvertFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MFWaveFormatExConvertFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MFWaveFormatExConvertFlags").field(&self.0).finish()
@@ -24483,6 +24703,7 @@ pub struct MF_ACTIVATE_CUSTOM_MIXER(pub i32);
 impl windows_core::TypeKind for MF_ACTIVATE_CUSTOM_MIXER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_ACTIVATE_CUSTOM_MIXER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_ACTIVATE_CUSTOM_MIXER").field(&self.0).finish()
@@ -24494,6 +24715,7 @@ pub struct MF_ACTIVATE_CUSTOM_PRESENTER(pub i32);
 impl windows_core::TypeKind for MF_ACTIVATE_CUSTOM_PRESENTER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_ACTIVATE_CUSTOM_PRESENTER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_ACTIVATE_CUSTOM_PRESENTER").field(&self.0).finish()
@@ -24505,6 +24727,7 @@ pub struct MF_ATTRIBUTES_MATCH_TYPE(pub i32);
 impl windows_core::TypeKind for MF_ATTRIBUTES_MATCH_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_ATTRIBUTES_MATCH_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_ATTRIBUTES_MATCH_TYPE").field(&self.0).finish()
@@ -24516,6 +24739,7 @@ pub struct MF_ATTRIBUTE_SERIALIZE_OPTIONS(pub i32);
 impl windows_core::TypeKind for MF_ATTRIBUTE_SERIALIZE_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_ATTRIBUTE_SERIALIZE_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_ATTRIBUTE_SERIALIZE_OPTIONS").field(&self.0).finish()
@@ -24527,6 +24751,7 @@ pub struct MF_ATTRIBUTE_TYPE(pub i32);
 impl windows_core::TypeKind for MF_ATTRIBUTE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_ATTRIBUTE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_ATTRIBUTE_TYPE").field(&self.0).finish()
@@ -24538,6 +24763,7 @@ pub struct MF_AUVRHP_ROOMMODEL(pub i32);
 impl windows_core::TypeKind for MF_AUVRHP_ROOMMODEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_AUVRHP_ROOMMODEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_AUVRHP_ROOMMODEL").field(&self.0).finish()
@@ -24549,6 +24775,7 @@ pub struct MF_CAMERA_CONTROL_CONFIGURATION_TYPE(pub i32);
 impl windows_core::TypeKind for MF_CAMERA_CONTROL_CONFIGURATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_CAMERA_CONTROL_CONFIGURATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_CAMERA_CONTROL_CONFIGURATION_TYPE").field(&self.0).finish()
@@ -24560,6 +24787,7 @@ pub struct MF_CAPTURE_ENGINE_AUDIO_PROCESSING_MODE(pub i32);
 impl windows_core::TypeKind for MF_CAPTURE_ENGINE_AUDIO_PROCESSING_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_CAPTURE_ENGINE_AUDIO_PROCESSING_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_CAPTURE_ENGINE_AUDIO_PROCESSING_MODE").field(&self.0).finish()
@@ -24571,6 +24799,7 @@ pub struct MF_CAPTURE_ENGINE_DEVICE_TYPE(pub i32);
 impl windows_core::TypeKind for MF_CAPTURE_ENGINE_DEVICE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_CAPTURE_ENGINE_DEVICE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_CAPTURE_ENGINE_DEVICE_TYPE").field(&self.0).finish()
@@ -24582,6 +24811,7 @@ pub struct MF_CAPTURE_ENGINE_MEDIA_CATEGORY_TYPE(pub i32);
 impl windows_core::TypeKind for MF_CAPTURE_ENGINE_MEDIA_CATEGORY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_CAPTURE_ENGINE_MEDIA_CATEGORY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_CAPTURE_ENGINE_MEDIA_CATEGORY_TYPE").field(&self.0).finish()
@@ -24593,6 +24823,7 @@ pub struct MF_CAPTURE_ENGINE_SINK_TYPE(pub i32);
 impl windows_core::TypeKind for MF_CAPTURE_ENGINE_SINK_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_CAPTURE_ENGINE_SINK_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_CAPTURE_ENGINE_SINK_TYPE").field(&self.0).finish()
@@ -24604,6 +24835,7 @@ pub struct MF_CAPTURE_ENGINE_SOURCE(pub u32);
 impl windows_core::TypeKind for MF_CAPTURE_ENGINE_SOURCE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_CAPTURE_ENGINE_SOURCE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_CAPTURE_ENGINE_SOURCE").field(&self.0).finish()
@@ -24615,6 +24847,7 @@ pub struct MF_CAPTURE_ENGINE_STREAM_CATEGORY(pub i32);
 impl windows_core::TypeKind for MF_CAPTURE_ENGINE_STREAM_CATEGORY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_CAPTURE_ENGINE_STREAM_CATEGORY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_CAPTURE_ENGINE_STREAM_CATEGORY").field(&self.0).finish()
@@ -24626,6 +24859,7 @@ pub struct MF_CONNECT_METHOD(pub i32);
 impl windows_core::TypeKind for MF_CONNECT_METHOD {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_CONNECT_METHOD {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_CONNECT_METHOD").field(&self.0).finish()
@@ -24637,6 +24871,7 @@ pub struct MF_CROSS_ORIGIN_POLICY(pub i32);
 impl windows_core::TypeKind for MF_CROSS_ORIGIN_POLICY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_CROSS_ORIGIN_POLICY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_CROSS_ORIGIN_POLICY").field(&self.0).finish()
@@ -24648,6 +24883,7 @@ pub struct MF_CUSTOM_DECODE_UNIT_TYPE(pub i32);
 impl windows_core::TypeKind for MF_CUSTOM_DECODE_UNIT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_CUSTOM_DECODE_UNIT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_CUSTOM_DECODE_UNIT_TYPE").field(&self.0).finish()
@@ -24659,6 +24895,7 @@ pub struct MF_EVENT_TYPE(pub i32);
 impl windows_core::TypeKind for MF_EVENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_EVENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_EVENT_TYPE").field(&self.0).finish()
@@ -24670,6 +24907,7 @@ pub struct MF_FILE_ACCESSMODE(pub i32);
 impl windows_core::TypeKind for MF_FILE_ACCESSMODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_FILE_ACCESSMODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_FILE_ACCESSMODE").field(&self.0).finish()
@@ -24681,6 +24919,7 @@ pub struct MF_FILE_FLAGS(pub i32);
 impl windows_core::TypeKind for MF_FILE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_FILE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_FILE_FLAGS").field(&self.0).finish()
@@ -24692,6 +24931,7 @@ pub struct MF_FILE_OPENMODE(pub i32);
 impl windows_core::TypeKind for MF_FILE_OPENMODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_FILE_OPENMODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_FILE_OPENMODE").field(&self.0).finish()
@@ -24703,6 +24943,7 @@ pub struct MF_HDCP_STATUS(pub i32);
 impl windows_core::TypeKind for MF_HDCP_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_HDCP_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_HDCP_STATUS").field(&self.0).finish()
@@ -24714,6 +24955,7 @@ pub struct MF_MEDIAKEYSESSION_MESSAGETYPE(pub i32);
 impl windows_core::TypeKind for MF_MEDIAKEYSESSION_MESSAGETYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_MEDIAKEYSESSION_MESSAGETYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_MEDIAKEYSESSION_MESSAGETYPE").field(&self.0).finish()
@@ -24725,6 +24967,7 @@ pub struct MF_MEDIAKEYSESSION_TYPE(pub i32);
 impl windows_core::TypeKind for MF_MEDIAKEYSESSION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_MEDIAKEYSESSION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_MEDIAKEYSESSION_TYPE").field(&self.0).finish()
@@ -24736,6 +24979,7 @@ pub struct MF_MEDIAKEYS_REQUIREMENT(pub i32);
 impl windows_core::TypeKind for MF_MEDIAKEYS_REQUIREMENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_MEDIAKEYS_REQUIREMENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_MEDIAKEYS_REQUIREMENT").field(&self.0).finish()
@@ -24747,6 +24991,7 @@ pub struct MF_MEDIAKEY_STATUS(pub i32);
 impl windows_core::TypeKind for MF_MEDIAKEY_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_MEDIAKEY_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_MEDIAKEY_STATUS").field(&self.0).finish()
@@ -24758,6 +25003,7 @@ pub struct MF_MEDIA_ENGINE_CANPLAY(pub i32);
 impl windows_core::TypeKind for MF_MEDIA_ENGINE_CANPLAY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_MEDIA_ENGINE_CANPLAY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_MEDIA_ENGINE_CANPLAY").field(&self.0).finish()
@@ -24769,6 +25015,7 @@ pub struct MF_MEDIA_ENGINE_CREATEFLAGS(pub i32);
 impl windows_core::TypeKind for MF_MEDIA_ENGINE_CREATEFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_MEDIA_ENGINE_CREATEFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_MEDIA_ENGINE_CREATEFLAGS").field(&self.0).finish()
@@ -24780,6 +25027,7 @@ pub struct MF_MEDIA_ENGINE_ERR(pub i32);
 impl windows_core::TypeKind for MF_MEDIA_ENGINE_ERR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_MEDIA_ENGINE_ERR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_MEDIA_ENGINE_ERR").field(&self.0).finish()
@@ -24791,6 +25039,7 @@ pub struct MF_MEDIA_ENGINE_EVENT(pub i32);
 impl windows_core::TypeKind for MF_MEDIA_ENGINE_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_MEDIA_ENGINE_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_MEDIA_ENGINE_EVENT").field(&self.0).finish()
@@ -24802,6 +25051,7 @@ pub struct MF_MEDIA_ENGINE_EXTENSION_TYPE(pub i32);
 impl windows_core::TypeKind for MF_MEDIA_ENGINE_EXTENSION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_MEDIA_ENGINE_EXTENSION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_MEDIA_ENGINE_EXTENSION_TYPE").field(&self.0).finish()
@@ -24813,6 +25063,7 @@ pub struct MF_MEDIA_ENGINE_FRAME_PROTECTION_FLAGS(pub i32);
 impl windows_core::TypeKind for MF_MEDIA_ENGINE_FRAME_PROTECTION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_MEDIA_ENGINE_FRAME_PROTECTION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_MEDIA_ENGINE_FRAME_PROTECTION_FLAGS").field(&self.0).finish()
@@ -24824,6 +25075,7 @@ pub struct MF_MEDIA_ENGINE_KEYERR(pub i32);
 impl windows_core::TypeKind for MF_MEDIA_ENGINE_KEYERR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_MEDIA_ENGINE_KEYERR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_MEDIA_ENGINE_KEYERR").field(&self.0).finish()
@@ -24835,6 +25087,7 @@ pub struct MF_MEDIA_ENGINE_NETWORK(pub i32);
 impl windows_core::TypeKind for MF_MEDIA_ENGINE_NETWORK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_MEDIA_ENGINE_NETWORK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_MEDIA_ENGINE_NETWORK").field(&self.0).finish()
@@ -24846,6 +25099,7 @@ pub struct MF_MEDIA_ENGINE_OPM_STATUS(pub i32);
 impl windows_core::TypeKind for MF_MEDIA_ENGINE_OPM_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_MEDIA_ENGINE_OPM_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_MEDIA_ENGINE_OPM_STATUS").field(&self.0).finish()
@@ -24857,6 +25111,7 @@ pub struct MF_MEDIA_ENGINE_PRELOAD(pub i32);
 impl windows_core::TypeKind for MF_MEDIA_ENGINE_PRELOAD {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_MEDIA_ENGINE_PRELOAD {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_MEDIA_ENGINE_PRELOAD").field(&self.0).finish()
@@ -24868,6 +25123,7 @@ pub struct MF_MEDIA_ENGINE_PROTECTION_FLAGS(pub i32);
 impl windows_core::TypeKind for MF_MEDIA_ENGINE_PROTECTION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_MEDIA_ENGINE_PROTECTION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_MEDIA_ENGINE_PROTECTION_FLAGS").field(&self.0).finish()
@@ -24879,6 +25135,7 @@ pub struct MF_MEDIA_ENGINE_READY(pub i32);
 impl windows_core::TypeKind for MF_MEDIA_ENGINE_READY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_MEDIA_ENGINE_READY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_MEDIA_ENGINE_READY").field(&self.0).finish()
@@ -24890,6 +25147,7 @@ pub struct MF_MEDIA_ENGINE_S3D_PACKING_MODE(pub i32);
 impl windows_core::TypeKind for MF_MEDIA_ENGINE_S3D_PACKING_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_MEDIA_ENGINE_S3D_PACKING_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_MEDIA_ENGINE_S3D_PACKING_MODE").field(&self.0).finish()
@@ -24901,6 +25159,7 @@ pub struct MF_MEDIA_ENGINE_SEEK_MODE(pub i32);
 impl windows_core::TypeKind for MF_MEDIA_ENGINE_SEEK_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_MEDIA_ENGINE_SEEK_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_MEDIA_ENGINE_SEEK_MODE").field(&self.0).finish()
@@ -24912,6 +25171,7 @@ pub struct MF_MEDIA_ENGINE_STATISTIC(pub i32);
 impl windows_core::TypeKind for MF_MEDIA_ENGINE_STATISTIC {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_MEDIA_ENGINE_STATISTIC {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_MEDIA_ENGINE_STATISTIC").field(&self.0).finish()
@@ -24923,6 +25183,7 @@ pub struct MF_MEDIA_ENGINE_STREAMTYPE_FAILED(pub i32);
 impl windows_core::TypeKind for MF_MEDIA_ENGINE_STREAMTYPE_FAILED {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_MEDIA_ENGINE_STREAMTYPE_FAILED {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_MEDIA_ENGINE_STREAMTYPE_FAILED").field(&self.0).finish()
@@ -24934,6 +25195,7 @@ pub struct MF_MEDIA_SHARING_ENGINE_EVENT(pub i32);
 impl windows_core::TypeKind for MF_MEDIA_SHARING_ENGINE_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_MEDIA_SHARING_ENGINE_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_MEDIA_SHARING_ENGINE_EVENT").field(&self.0).finish()
@@ -24945,6 +25207,7 @@ pub struct MF_MSE_APPEND_MODE(pub i32);
 impl windows_core::TypeKind for MF_MSE_APPEND_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_MSE_APPEND_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_MSE_APPEND_MODE").field(&self.0).finish()
@@ -24956,6 +25219,7 @@ pub struct MF_MSE_ERROR(pub i32);
 impl windows_core::TypeKind for MF_MSE_ERROR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_MSE_ERROR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_MSE_ERROR").field(&self.0).finish()
@@ -24967,6 +25231,7 @@ pub struct MF_MSE_OPUS_SUPPORT_TYPE(pub i32);
 impl windows_core::TypeKind for MF_MSE_OPUS_SUPPORT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_MSE_OPUS_SUPPORT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_MSE_OPUS_SUPPORT_TYPE").field(&self.0).finish()
@@ -24978,6 +25243,7 @@ pub struct MF_MSE_READY(pub i32);
 impl windows_core::TypeKind for MF_MSE_READY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_MSE_READY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_MSE_READY").field(&self.0).finish()
@@ -24989,6 +25255,7 @@ pub struct MF_MSE_VP9_SUPPORT_TYPE(pub i32);
 impl windows_core::TypeKind for MF_MSE_VP9_SUPPORT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_MSE_VP9_SUPPORT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_MSE_VP9_SUPPORT_TYPE").field(&self.0).finish()
@@ -25000,6 +25267,7 @@ pub struct MF_MT_D3D_RESOURCE_VERSION_ENUM(pub i32);
 impl windows_core::TypeKind for MF_MT_D3D_RESOURCE_VERSION_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_MT_D3D_RESOURCE_VERSION_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_MT_D3D_RESOURCE_VERSION_ENUM").field(&self.0).finish()
@@ -25011,6 +25279,7 @@ pub struct MF_OBJECT_TYPE(pub i32);
 impl windows_core::TypeKind for MF_OBJECT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_OBJECT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_OBJECT_TYPE").field(&self.0).finish()
@@ -25022,6 +25291,7 @@ pub struct MF_OPM_ACP_PROTECTION_LEVEL(pub i32);
 impl windows_core::TypeKind for MF_OPM_ACP_PROTECTION_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_OPM_ACP_PROTECTION_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_OPM_ACP_PROTECTION_LEVEL").field(&self.0).finish()
@@ -25033,6 +25303,7 @@ pub struct MF_OPM_CGMSA_PROTECTION_LEVEL(pub i32);
 impl windows_core::TypeKind for MF_OPM_CGMSA_PROTECTION_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_OPM_CGMSA_PROTECTION_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_OPM_CGMSA_PROTECTION_LEVEL").field(&self.0).finish()
@@ -25044,6 +25315,7 @@ pub struct MF_PLUGIN_CONTROL_POLICY(pub i32);
 impl windows_core::TypeKind for MF_PLUGIN_CONTROL_POLICY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_PLUGIN_CONTROL_POLICY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_PLUGIN_CONTROL_POLICY").field(&self.0).finish()
@@ -25055,6 +25327,7 @@ pub struct MF_Plugin_Type(pub i32);
 impl windows_core::TypeKind for MF_Plugin_Type {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_Plugin_Type {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_Plugin_Type").field(&self.0).finish()
@@ -25066,6 +25339,7 @@ pub struct MF_QUALITY_ADVISE_FLAGS(pub i32);
 impl windows_core::TypeKind for MF_QUALITY_ADVISE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_QUALITY_ADVISE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_QUALITY_ADVISE_FLAGS").field(&self.0).finish()
@@ -25077,6 +25351,7 @@ pub struct MF_QUALITY_DROP_MODE(pub i32);
 impl windows_core::TypeKind for MF_QUALITY_DROP_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_QUALITY_DROP_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_QUALITY_DROP_MODE").field(&self.0).finish()
@@ -25088,6 +25363,7 @@ pub struct MF_QUALITY_LEVEL(pub i32);
 impl windows_core::TypeKind for MF_QUALITY_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_QUALITY_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_QUALITY_LEVEL").field(&self.0).finish()
@@ -25099,6 +25375,7 @@ pub struct MF_RESOLUTION_FLAGS(pub i32);
 impl windows_core::TypeKind for MF_RESOLUTION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_RESOLUTION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_RESOLUTION_FLAGS").field(&self.0).finish()
@@ -25143,6 +25420,7 @@ pub struct MF_SERVICE_LOOKUP_TYPE(pub i32);
 impl windows_core::TypeKind for MF_SERVICE_LOOKUP_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_SERVICE_LOOKUP_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_SERVICE_LOOKUP_TYPE").field(&self.0).finish()
@@ -25154,6 +25432,7 @@ pub struct MF_SHARING_ENGINE_EVENT(pub i32);
 impl windows_core::TypeKind for MF_SHARING_ENGINE_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_SHARING_ENGINE_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_SHARING_ENGINE_EVENT").field(&self.0).finish()
@@ -25165,6 +25444,7 @@ pub struct MF_SINK_WRITER_CONSTANTS(pub u32);
 impl windows_core::TypeKind for MF_SINK_WRITER_CONSTANTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_SINK_WRITER_CONSTANTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_SINK_WRITER_CONSTANTS").field(&self.0).finish()
@@ -25176,6 +25456,7 @@ pub struct MF_SOURCE_READER_CONSTANTS(pub i32);
 impl windows_core::TypeKind for MF_SOURCE_READER_CONSTANTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_SOURCE_READER_CONSTANTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_SOURCE_READER_CONSTANTS").field(&self.0).finish()
@@ -25187,6 +25468,7 @@ pub struct MF_SOURCE_READER_CONTROL_FLAG(pub i32);
 impl windows_core::TypeKind for MF_SOURCE_READER_CONTROL_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_SOURCE_READER_CONTROL_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_SOURCE_READER_CONTROL_FLAG").field(&self.0).finish()
@@ -25231,6 +25513,7 @@ pub struct MF_SOURCE_READER_CURRENT_TYPE_CONSTANTS(pub i32);
 impl windows_core::TypeKind for MF_SOURCE_READER_CURRENT_TYPE_CONSTANTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_SOURCE_READER_CURRENT_TYPE_CONSTANTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_SOURCE_READER_CURRENT_TYPE_CONSTANTS").field(&self.0).finish()
@@ -25242,6 +25525,7 @@ pub struct MF_SOURCE_READER_FLAG(pub i32);
 impl windows_core::TypeKind for MF_SOURCE_READER_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_SOURCE_READER_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_SOURCE_READER_FLAG").field(&self.0).finish()
@@ -25286,6 +25570,7 @@ pub struct MF_STREAM_STATE(pub i32);
 impl windows_core::TypeKind for MF_STREAM_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_STREAM_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_STREAM_STATE").field(&self.0).finish()
@@ -25297,6 +25582,7 @@ pub struct MF_TIMED_TEXT_ALIGNMENT(pub i32);
 impl windows_core::TypeKind for MF_TIMED_TEXT_ALIGNMENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_TIMED_TEXT_ALIGNMENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_TIMED_TEXT_ALIGNMENT").field(&self.0).finish()
@@ -25308,6 +25594,7 @@ pub struct MF_TIMED_TEXT_BOUTEN_POSITION(pub i32);
 impl windows_core::TypeKind for MF_TIMED_TEXT_BOUTEN_POSITION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_TIMED_TEXT_BOUTEN_POSITION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_TIMED_TEXT_BOUTEN_POSITION").field(&self.0).finish()
@@ -25319,6 +25606,7 @@ pub struct MF_TIMED_TEXT_BOUTEN_TYPE(pub i32);
 impl windows_core::TypeKind for MF_TIMED_TEXT_BOUTEN_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_TIMED_TEXT_BOUTEN_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_TIMED_TEXT_BOUTEN_TYPE").field(&self.0).finish()
@@ -25330,6 +25618,7 @@ pub struct MF_TIMED_TEXT_CUE_EVENT(pub i32);
 impl windows_core::TypeKind for MF_TIMED_TEXT_CUE_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_TIMED_TEXT_CUE_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_TIMED_TEXT_CUE_EVENT").field(&self.0).finish()
@@ -25341,6 +25630,7 @@ pub struct MF_TIMED_TEXT_DECORATION(pub i32);
 impl windows_core::TypeKind for MF_TIMED_TEXT_DECORATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_TIMED_TEXT_DECORATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_TIMED_TEXT_DECORATION").field(&self.0).finish()
@@ -25352,6 +25642,7 @@ pub struct MF_TIMED_TEXT_DISPLAY_ALIGNMENT(pub i32);
 impl windows_core::TypeKind for MF_TIMED_TEXT_DISPLAY_ALIGNMENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_TIMED_TEXT_DISPLAY_ALIGNMENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_TIMED_TEXT_DISPLAY_ALIGNMENT").field(&self.0).finish()
@@ -25363,6 +25654,7 @@ pub struct MF_TIMED_TEXT_ERROR_CODE(pub i32);
 impl windows_core::TypeKind for MF_TIMED_TEXT_ERROR_CODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_TIMED_TEXT_ERROR_CODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_TIMED_TEXT_ERROR_CODE").field(&self.0).finish()
@@ -25374,6 +25666,7 @@ pub struct MF_TIMED_TEXT_FONT_STYLE(pub i32);
 impl windows_core::TypeKind for MF_TIMED_TEXT_FONT_STYLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_TIMED_TEXT_FONT_STYLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_TIMED_TEXT_FONT_STYLE").field(&self.0).finish()
@@ -25385,6 +25678,7 @@ pub struct MF_TIMED_TEXT_RUBY_ALIGN(pub i32);
 impl windows_core::TypeKind for MF_TIMED_TEXT_RUBY_ALIGN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_TIMED_TEXT_RUBY_ALIGN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_TIMED_TEXT_RUBY_ALIGN").field(&self.0).finish()
@@ -25396,6 +25690,7 @@ pub struct MF_TIMED_TEXT_RUBY_POSITION(pub i32);
 impl windows_core::TypeKind for MF_TIMED_TEXT_RUBY_POSITION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_TIMED_TEXT_RUBY_POSITION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_TIMED_TEXT_RUBY_POSITION").field(&self.0).finish()
@@ -25407,6 +25702,7 @@ pub struct MF_TIMED_TEXT_RUBY_RESERVE(pub i32);
 impl windows_core::TypeKind for MF_TIMED_TEXT_RUBY_RESERVE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_TIMED_TEXT_RUBY_RESERVE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_TIMED_TEXT_RUBY_RESERVE").field(&self.0).finish()
@@ -25418,6 +25714,7 @@ pub struct MF_TIMED_TEXT_SCROLL_MODE(pub i32);
 impl windows_core::TypeKind for MF_TIMED_TEXT_SCROLL_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_TIMED_TEXT_SCROLL_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_TIMED_TEXT_SCROLL_MODE").field(&self.0).finish()
@@ -25429,6 +25726,7 @@ pub struct MF_TIMED_TEXT_TRACK_KIND(pub i32);
 impl windows_core::TypeKind for MF_TIMED_TEXT_TRACK_KIND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_TIMED_TEXT_TRACK_KIND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_TIMED_TEXT_TRACK_KIND").field(&self.0).finish()
@@ -25440,6 +25738,7 @@ pub struct MF_TIMED_TEXT_TRACK_READY_STATE(pub i32);
 impl windows_core::TypeKind for MF_TIMED_TEXT_TRACK_READY_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_TIMED_TEXT_TRACK_READY_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_TIMED_TEXT_TRACK_READY_STATE").field(&self.0).finish()
@@ -25451,6 +25750,7 @@ pub struct MF_TIMED_TEXT_UNIT_TYPE(pub i32);
 impl windows_core::TypeKind for MF_TIMED_TEXT_UNIT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_TIMED_TEXT_UNIT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_TIMED_TEXT_UNIT_TYPE").field(&self.0).finish()
@@ -25462,6 +25762,7 @@ pub struct MF_TIMED_TEXT_WRITING_MODE(pub i32);
 impl windows_core::TypeKind for MF_TIMED_TEXT_WRITING_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_TIMED_TEXT_WRITING_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_TIMED_TEXT_WRITING_MODE").field(&self.0).finish()
@@ -25473,6 +25774,7 @@ pub struct MF_TOPOLOGY_RESOLUTION_STATUS_FLAGS(pub i32);
 impl windows_core::TypeKind for MF_TOPOLOGY_RESOLUTION_STATUS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_TOPOLOGY_RESOLUTION_STATUS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_TOPOLOGY_RESOLUTION_STATUS_FLAGS").field(&self.0).finish()
@@ -25484,6 +25786,7 @@ pub struct MF_TOPOLOGY_TYPE(pub i32);
 impl windows_core::TypeKind for MF_TOPOLOGY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_TOPOLOGY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_TOPOLOGY_TYPE").field(&self.0).finish()
@@ -25495,6 +25798,7 @@ pub struct MF_TOPONODE_DRAIN_MODE(pub i32);
 impl windows_core::TypeKind for MF_TOPONODE_DRAIN_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_TOPONODE_DRAIN_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_TOPONODE_DRAIN_MODE").field(&self.0).finish()
@@ -25506,6 +25810,7 @@ pub struct MF_TOPONODE_FLUSH_MODE(pub i32);
 impl windows_core::TypeKind for MF_TOPONODE_FLUSH_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_TOPONODE_FLUSH_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_TOPONODE_FLUSH_MODE").field(&self.0).finish()
@@ -25517,6 +25822,7 @@ pub struct MF_TOPOSTATUS(pub i32);
 impl windows_core::TypeKind for MF_TOPOSTATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_TOPOSTATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_TOPOSTATUS").field(&self.0).finish()
@@ -25528,6 +25834,7 @@ pub struct MF_TRANSCODE_ADJUST_PROFILE_FLAGS(pub i32);
 impl windows_core::TypeKind for MF_TRANSCODE_ADJUST_PROFILE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_TRANSCODE_ADJUST_PROFILE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_TRANSCODE_ADJUST_PROFILE_FLAGS").field(&self.0).finish()
@@ -25539,6 +25846,7 @@ pub struct MF_TRANSCODE_TOPOLOGYMODE_FLAGS(pub i32);
 impl windows_core::TypeKind for MF_TRANSCODE_TOPOLOGYMODE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_TRANSCODE_TOPOLOGYMODE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_TRANSCODE_TOPOLOGYMODE_FLAGS").field(&self.0).finish()
@@ -25550,6 +25858,7 @@ pub struct MF_URL_TRUST_STATUS(pub i32);
 impl windows_core::TypeKind for MF_URL_TRUST_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_URL_TRUST_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_URL_TRUST_STATUS").field(&self.0).finish()
@@ -25561,6 +25870,7 @@ pub struct MF_VIDEO_PROCESSOR_ALGORITHM_TYPE(pub i32);
 impl windows_core::TypeKind for MF_VIDEO_PROCESSOR_ALGORITHM_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_VIDEO_PROCESSOR_ALGORITHM_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_VIDEO_PROCESSOR_ALGORITHM_TYPE").field(&self.0).finish()
@@ -25572,6 +25882,7 @@ pub struct MF_VIDEO_PROCESSOR_MIRROR(pub i32);
 impl windows_core::TypeKind for MF_VIDEO_PROCESSOR_MIRROR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_VIDEO_PROCESSOR_MIRROR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_VIDEO_PROCESSOR_MIRROR").field(&self.0).finish()
@@ -25583,6 +25894,7 @@ pub struct MF_VIDEO_PROCESSOR_ROTATION(pub i32);
 impl windows_core::TypeKind for MF_VIDEO_PROCESSOR_ROTATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_VIDEO_PROCESSOR_ROTATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_VIDEO_PROCESSOR_ROTATION").field(&self.0).finish()
@@ -25594,6 +25906,7 @@ pub struct MIC_ARRAY_MODE(pub i32);
 impl windows_core::TypeKind for MIC_ARRAY_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MIC_ARRAY_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MIC_ARRAY_MODE").field(&self.0).finish()
@@ -25605,6 +25918,7 @@ pub struct MPEG2VIDEOINFO_FLAGS(pub u32);
 impl windows_core::TypeKind for MPEG2VIDEOINFO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MPEG2VIDEOINFO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MPEG2VIDEOINFO_FLAGS").field(&self.0).finish()
@@ -25649,6 +25963,7 @@ pub struct OPM_ACP_PROTECTION_LEVEL(pub i32);
 impl windows_core::TypeKind for OPM_ACP_PROTECTION_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPM_ACP_PROTECTION_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPM_ACP_PROTECTION_LEVEL").field(&self.0).finish()
@@ -25660,6 +25975,7 @@ pub struct OPM_BUS_TYPE(pub i32);
 impl windows_core::TypeKind for OPM_BUS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPM_BUS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPM_BUS_TYPE").field(&self.0).finish()
@@ -25671,6 +25987,7 @@ pub struct OPM_CGMSA(pub i32);
 impl windows_core::TypeKind for OPM_CGMSA {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPM_CGMSA {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPM_CGMSA").field(&self.0).finish()
@@ -25682,6 +25999,7 @@ pub struct OPM_CONNECTOR_TYPE(pub i32);
 impl windows_core::TypeKind for OPM_CONNECTOR_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPM_CONNECTOR_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPM_CONNECTOR_TYPE").field(&self.0).finish()
@@ -25693,6 +26011,7 @@ pub struct OPM_DPCP_PROTECTION_LEVEL(pub i32);
 impl windows_core::TypeKind for OPM_DPCP_PROTECTION_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPM_DPCP_PROTECTION_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPM_DPCP_PROTECTION_LEVEL").field(&self.0).finish()
@@ -25704,6 +26023,7 @@ pub struct OPM_DVI_CHARACTERISTIC(pub i32);
 impl windows_core::TypeKind for OPM_DVI_CHARACTERISTIC {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPM_DVI_CHARACTERISTIC {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPM_DVI_CHARACTERISTIC").field(&self.0).finish()
@@ -25715,6 +26035,7 @@ pub struct OPM_HDCP_FLAGS(pub i32);
 impl windows_core::TypeKind for OPM_HDCP_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPM_HDCP_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPM_HDCP_FLAGS").field(&self.0).finish()
@@ -25759,6 +26080,7 @@ pub struct OPM_HDCP_PROTECTION_LEVEL(pub i32);
 impl windows_core::TypeKind for OPM_HDCP_PROTECTION_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPM_HDCP_PROTECTION_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPM_HDCP_PROTECTION_LEVEL").field(&self.0).finish()
@@ -25770,6 +26092,7 @@ pub struct OPM_HDCP_STATUS(pub i32);
 impl windows_core::TypeKind for OPM_HDCP_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPM_HDCP_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPM_HDCP_STATUS").field(&self.0).finish()
@@ -25781,6 +26104,7 @@ pub struct OPM_HDCP_TYPE(pub i32);
 impl windows_core::TypeKind for OPM_HDCP_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPM_HDCP_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPM_HDCP_TYPE").field(&self.0).finish()
@@ -25792,6 +26116,7 @@ pub struct OPM_IMAGE_ASPECT_RATIO_EN300294(pub i32);
 impl windows_core::TypeKind for OPM_IMAGE_ASPECT_RATIO_EN300294 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPM_IMAGE_ASPECT_RATIO_EN300294 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPM_IMAGE_ASPECT_RATIO_EN300294").field(&self.0).finish()
@@ -25803,6 +26128,7 @@ pub struct OPM_OUTPUT_HARDWARE_PROTECTION(pub i32);
 impl windows_core::TypeKind for OPM_OUTPUT_HARDWARE_PROTECTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPM_OUTPUT_HARDWARE_PROTECTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPM_OUTPUT_HARDWARE_PROTECTION").field(&self.0).finish()
@@ -25814,6 +26140,7 @@ pub struct OPM_PROTECTION_STANDARD_TYPE(pub u32);
 impl windows_core::TypeKind for OPM_PROTECTION_STANDARD_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPM_PROTECTION_STANDARD_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPM_PROTECTION_STANDARD_TYPE").field(&self.0).finish()
@@ -25825,6 +26152,7 @@ pub struct OPM_PROTECTION_TYPE(pub i32);
 impl windows_core::TypeKind for OPM_PROTECTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPM_PROTECTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPM_PROTECTION_TYPE").field(&self.0).finish()
@@ -25836,6 +26164,7 @@ pub struct OPM_STATUS(pub i32);
 impl windows_core::TypeKind for OPM_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPM_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPM_STATUS").field(&self.0).finish()
@@ -25847,6 +26176,7 @@ pub struct OPM_TYPE(pub i32);
 impl windows_core::TypeKind for OPM_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPM_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPM_TYPE").field(&self.0).finish()
@@ -25858,6 +26188,7 @@ pub struct OPM_TYPE_ENFORCEMENT_HDCP_PROTECTION_LEVEL(pub i32);
 impl windows_core::TypeKind for OPM_TYPE_ENFORCEMENT_HDCP_PROTECTION_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPM_TYPE_ENFORCEMENT_HDCP_PROTECTION_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPM_TYPE_ENFORCEMENT_HDCP_PROTECTION_LEVEL").field(&self.0).finish()
@@ -25869,6 +26200,7 @@ pub struct OPM_VIDEO_OUTPUT_SEMANTICS(pub i32);
 impl windows_core::TypeKind for OPM_VIDEO_OUTPUT_SEMANTICS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPM_VIDEO_OUTPUT_SEMANTICS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPM_VIDEO_OUTPUT_SEMANTICS").field(&self.0).finish()
@@ -25880,6 +26212,7 @@ pub struct PLAYTO_SOURCE_CREATEFLAGS(pub i32);
 impl windows_core::TypeKind for PLAYTO_SOURCE_CREATEFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PLAYTO_SOURCE_CREATEFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PLAYTO_SOURCE_CREATEFLAGS").field(&self.0).finish()
@@ -25924,6 +26257,7 @@ pub struct SAMPLE_PROTECTION_VERSION(pub i32);
 impl windows_core::TypeKind for SAMPLE_PROTECTION_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SAMPLE_PROTECTION_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SAMPLE_PROTECTION_VERSION").field(&self.0).finish()
@@ -25935,6 +26269,7 @@ pub struct SEEK_ORIGIN(pub i32);
 impl windows_core::TypeKind for SEEK_ORIGIN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SEEK_ORIGIN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SEEK_ORIGIN").field(&self.0).finish()
@@ -25946,6 +26281,7 @@ pub struct TOC_POS_TYPE(pub i32);
 impl windows_core::TypeKind for TOC_POS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TOC_POS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TOC_POS_TYPE").field(&self.0).finish()
@@ -25957,6 +26293,7 @@ pub struct WMT_PROP_DATATYPE(pub i32);
 impl windows_core::TypeKind for WMT_PROP_DATATYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMT_PROP_DATATYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMT_PROP_DATATYPE").field(&self.0).finish()
@@ -25968,6 +26305,7 @@ pub struct WMV_DYNAMIC_FLAGS(pub i32);
 impl windows_core::TypeKind for WMV_DYNAMIC_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMV_DYNAMIC_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMV_DYNAMIC_FLAGS").field(&self.0).finish()
@@ -25979,6 +26317,7 @@ pub struct _MFP_CREDENTIAL_FLAGS(pub i32);
 impl windows_core::TypeKind for _MFP_CREDENTIAL_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _MFP_CREDENTIAL_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_MFP_CREDENTIAL_FLAGS").field(&self.0).finish()
@@ -25990,6 +26329,7 @@ pub struct _MFP_MEDIAITEM_CHARACTERISTICS(pub i32);
 impl windows_core::TypeKind for _MFP_MEDIAITEM_CHARACTERISTICS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _MFP_MEDIAITEM_CHARACTERISTICS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_MFP_MEDIAITEM_CHARACTERISTICS").field(&self.0).finish()
@@ -26001,6 +26341,7 @@ pub struct _MFT_INPUT_DATA_BUFFER_FLAGS(pub i32);
 impl windows_core::TypeKind for _MFT_INPUT_DATA_BUFFER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _MFT_INPUT_DATA_BUFFER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_MFT_INPUT_DATA_BUFFER_FLAGS").field(&self.0).finish()
@@ -26012,6 +26353,7 @@ pub struct _MFT_INPUT_STATUS_FLAGS(pub i32);
 impl windows_core::TypeKind for _MFT_INPUT_STATUS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _MFT_INPUT_STATUS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_MFT_INPUT_STATUS_FLAGS").field(&self.0).finish()
@@ -26023,6 +26365,7 @@ pub struct _MFT_INPUT_STREAM_INFO_FLAGS(pub i32);
 impl windows_core::TypeKind for _MFT_INPUT_STREAM_INFO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _MFT_INPUT_STREAM_INFO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_MFT_INPUT_STREAM_INFO_FLAGS").field(&self.0).finish()
@@ -26034,6 +26377,7 @@ pub struct _MFT_OUTPUT_DATA_BUFFER_FLAGS(pub i32);
 impl windows_core::TypeKind for _MFT_OUTPUT_DATA_BUFFER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _MFT_OUTPUT_DATA_BUFFER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_MFT_OUTPUT_DATA_BUFFER_FLAGS").field(&self.0).finish()
@@ -26045,6 +26389,7 @@ pub struct _MFT_OUTPUT_STATUS_FLAGS(pub i32);
 impl windows_core::TypeKind for _MFT_OUTPUT_STATUS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _MFT_OUTPUT_STATUS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_MFT_OUTPUT_STATUS_FLAGS").field(&self.0).finish()
@@ -26056,6 +26401,7 @@ pub struct _MFT_OUTPUT_STREAM_INFO_FLAGS(pub i32);
 impl windows_core::TypeKind for _MFT_OUTPUT_STREAM_INFO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _MFT_OUTPUT_STREAM_INFO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_MFT_OUTPUT_STREAM_INFO_FLAGS").field(&self.0).finish()
@@ -26067,6 +26413,7 @@ pub struct _MFT_PROCESS_OUTPUT_FLAGS(pub i32);
 impl windows_core::TypeKind for _MFT_PROCESS_OUTPUT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _MFT_PROCESS_OUTPUT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_MFT_PROCESS_OUTPUT_FLAGS").field(&self.0).finish()
@@ -26078,6 +26425,7 @@ pub struct _MFT_PROCESS_OUTPUT_STATUS(pub i32);
 impl windows_core::TypeKind for _MFT_PROCESS_OUTPUT_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _MFT_PROCESS_OUTPUT_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_MFT_PROCESS_OUTPUT_STATUS").field(&self.0).finish()
@@ -26089,6 +26437,7 @@ pub struct _MFT_SET_TYPE_FLAGS(pub i32);
 impl windows_core::TypeKind for _MFT_SET_TYPE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _MFT_SET_TYPE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_MFT_SET_TYPE_FLAGS").field(&self.0).finish()
@@ -26100,6 +26449,7 @@ pub struct eAVAudioChannelConfig(pub i32);
 impl windows_core::TypeKind for eAVAudioChannelConfig {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVAudioChannelConfig {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVAudioChannelConfig").field(&self.0).finish()
@@ -26111,6 +26461,7 @@ pub struct eAVDDSurroundMode(pub i32);
 impl windows_core::TypeKind for eAVDDSurroundMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVDDSurroundMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVDDSurroundMode").field(&self.0).finish()
@@ -26122,6 +26473,7 @@ pub struct eAVDSPLoudnessEqualization(pub i32);
 impl windows_core::TypeKind for eAVDSPLoudnessEqualization {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVDSPLoudnessEqualization {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVDSPLoudnessEqualization").field(&self.0).finish()
@@ -26133,6 +26485,7 @@ pub struct eAVDSPSpeakerFill(pub i32);
 impl windows_core::TypeKind for eAVDSPSpeakerFill {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVDSPSpeakerFill {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVDSPSpeakerFill").field(&self.0).finish()
@@ -26144,6 +26497,7 @@ pub struct eAVDecAACDownmixMode(pub i32);
 impl windows_core::TypeKind for eAVDecAACDownmixMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVDecAACDownmixMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVDecAACDownmixMode").field(&self.0).finish()
@@ -26155,6 +26509,7 @@ pub struct eAVDecAudioDualMono(pub i32);
 impl windows_core::TypeKind for eAVDecAudioDualMono {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVDecAudioDualMono {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVDecAudioDualMono").field(&self.0).finish()
@@ -26166,6 +26521,7 @@ pub struct eAVDecAudioDualMonoReproMode(pub i32);
 impl windows_core::TypeKind for eAVDecAudioDualMonoReproMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVDecAudioDualMonoReproMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVDecAudioDualMonoReproMode").field(&self.0).finish()
@@ -26177,6 +26533,7 @@ pub struct eAVDecDDMatrixDecodingMode(pub i32);
 impl windows_core::TypeKind for eAVDecDDMatrixDecodingMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVDecDDMatrixDecodingMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVDecDDMatrixDecodingMode").field(&self.0).finish()
@@ -26188,6 +26545,7 @@ pub struct eAVDecDDOperationalMode(pub i32);
 impl windows_core::TypeKind for eAVDecDDOperationalMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVDecDDOperationalMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVDecDDOperationalMode").field(&self.0).finish()
@@ -26199,6 +26557,7 @@ pub struct eAVDecDDStereoDownMixMode(pub i32);
 impl windows_core::TypeKind for eAVDecDDStereoDownMixMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVDecDDStereoDownMixMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVDecDDStereoDownMixMode").field(&self.0).finish()
@@ -26210,6 +26569,7 @@ pub struct eAVDecHEAACDynamicRangeControl(pub i32);
 impl windows_core::TypeKind for eAVDecHEAACDynamicRangeControl {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVDecHEAACDynamicRangeControl {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVDecHEAACDynamicRangeControl").field(&self.0).finish()
@@ -26221,6 +26581,7 @@ pub struct eAVDecVideoCodecType(pub i32);
 impl windows_core::TypeKind for eAVDecVideoCodecType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVDecVideoCodecType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVDecVideoCodecType").field(&self.0).finish()
@@ -26232,6 +26593,7 @@ pub struct eAVDecVideoDXVABusEncryption(pub i32);
 impl windows_core::TypeKind for eAVDecVideoDXVABusEncryption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVDecVideoDXVABusEncryption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVDecVideoDXVABusEncryption").field(&self.0).finish()
@@ -26243,6 +26605,7 @@ pub struct eAVDecVideoDXVAMode(pub i32);
 impl windows_core::TypeKind for eAVDecVideoDXVAMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVDecVideoDXVAMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVDecVideoDXVAMode").field(&self.0).finish()
@@ -26254,6 +26617,7 @@ pub struct eAVDecVideoH264ErrorConcealment(pub i32);
 impl windows_core::TypeKind for eAVDecVideoH264ErrorConcealment {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVDecVideoH264ErrorConcealment {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVDecVideoH264ErrorConcealment").field(&self.0).finish()
@@ -26265,6 +26629,7 @@ pub struct eAVDecVideoInputScanType(pub i32);
 impl windows_core::TypeKind for eAVDecVideoInputScanType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVDecVideoInputScanType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVDecVideoInputScanType").field(&self.0).finish()
@@ -26276,6 +26641,7 @@ pub struct eAVDecVideoMPEG2ErrorConcealment(pub i32);
 impl windows_core::TypeKind for eAVDecVideoMPEG2ErrorConcealment {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVDecVideoMPEG2ErrorConcealment {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVDecVideoMPEG2ErrorConcealment").field(&self.0).finish()
@@ -26287,6 +26653,7 @@ pub struct eAVDecVideoSWPowerLevel(pub i32);
 impl windows_core::TypeKind for eAVDecVideoSWPowerLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVDecVideoSWPowerLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVDecVideoSWPowerLevel").field(&self.0).finish()
@@ -26298,6 +26665,7 @@ pub struct eAVDecVideoSoftwareDeinterlaceMode(pub i32);
 impl windows_core::TypeKind for eAVDecVideoSoftwareDeinterlaceMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVDecVideoSoftwareDeinterlaceMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVDecVideoSoftwareDeinterlaceMode").field(&self.0).finish()
@@ -26309,6 +26677,7 @@ pub struct eAVEncAV1PictureType(pub i32);
 impl windows_core::TypeKind for eAVEncAV1PictureType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncAV1PictureType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncAV1PictureType").field(&self.0).finish()
@@ -26320,6 +26689,7 @@ pub struct eAVEncAV1VLevel(pub i32);
 impl windows_core::TypeKind for eAVEncAV1VLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncAV1VLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncAV1VLevel").field(&self.0).finish()
@@ -26331,6 +26701,7 @@ pub struct eAVEncAV1VProfile(pub i32);
 impl windows_core::TypeKind for eAVEncAV1VProfile {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncAV1VProfile {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncAV1VProfile").field(&self.0).finish()
@@ -26342,6 +26713,7 @@ pub struct eAVEncAdaptiveMode(pub i32);
 impl windows_core::TypeKind for eAVEncAdaptiveMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncAdaptiveMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncAdaptiveMode").field(&self.0).finish()
@@ -26353,6 +26725,7 @@ pub struct eAVEncAudioDualMono(pub i32);
 impl windows_core::TypeKind for eAVEncAudioDualMono {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncAudioDualMono {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncAudioDualMono").field(&self.0).finish()
@@ -26364,6 +26737,7 @@ pub struct eAVEncAudioInputContent(pub i32);
 impl windows_core::TypeKind for eAVEncAudioInputContent {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncAudioInputContent {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncAudioInputContent").field(&self.0).finish()
@@ -26375,6 +26749,7 @@ pub struct eAVEncChromaEncodeMode(pub i32);
 impl windows_core::TypeKind for eAVEncChromaEncodeMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncChromaEncodeMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncChromaEncodeMode").field(&self.0).finish()
@@ -26386,6 +26761,7 @@ pub struct eAVEncCommonRateControlMode(pub i32);
 impl windows_core::TypeKind for eAVEncCommonRateControlMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncCommonRateControlMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncCommonRateControlMode").field(&self.0).finish()
@@ -26397,6 +26773,7 @@ pub struct eAVEncCommonStreamEndHandling(pub i32);
 impl windows_core::TypeKind for eAVEncCommonStreamEndHandling {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncCommonStreamEndHandling {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncCommonStreamEndHandling").field(&self.0).finish()
@@ -26408,6 +26785,7 @@ pub struct eAVEncDDAtoDConverterType(pub i32);
 impl windows_core::TypeKind for eAVEncDDAtoDConverterType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncDDAtoDConverterType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncDDAtoDConverterType").field(&self.0).finish()
@@ -26419,6 +26797,7 @@ pub struct eAVEncDDDynamicRangeCompressionControl(pub i32);
 impl windows_core::TypeKind for eAVEncDDDynamicRangeCompressionControl {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncDDDynamicRangeCompressionControl {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncDDDynamicRangeCompressionControl").field(&self.0).finish()
@@ -26430,6 +26809,7 @@ pub struct eAVEncDDHeadphoneMode(pub i32);
 impl windows_core::TypeKind for eAVEncDDHeadphoneMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncDDHeadphoneMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncDDHeadphoneMode").field(&self.0).finish()
@@ -26441,6 +26821,7 @@ pub struct eAVEncDDPreferredStereoDownMixMode(pub i32);
 impl windows_core::TypeKind for eAVEncDDPreferredStereoDownMixMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncDDPreferredStereoDownMixMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncDDPreferredStereoDownMixMode").field(&self.0).finish()
@@ -26452,6 +26833,7 @@ pub struct eAVEncDDProductionRoomType(pub i32);
 impl windows_core::TypeKind for eAVEncDDProductionRoomType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncDDProductionRoomType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncDDProductionRoomType").field(&self.0).finish()
@@ -26463,6 +26845,7 @@ pub struct eAVEncDDService(pub i32);
 impl windows_core::TypeKind for eAVEncDDService {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncDDService {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncDDService").field(&self.0).finish()
@@ -26474,6 +26857,7 @@ pub struct eAVEncDDSurroundExMode(pub i32);
 impl windows_core::TypeKind for eAVEncDDSurroundExMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncDDSurroundExMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncDDSurroundExMode").field(&self.0).finish()
@@ -26485,6 +26869,7 @@ pub struct eAVEncH263PictureType(pub i32);
 impl windows_core::TypeKind for eAVEncH263PictureType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncH263PictureType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncH263PictureType").field(&self.0).finish()
@@ -26496,6 +26881,7 @@ pub struct eAVEncH263VLevel(pub i32);
 impl windows_core::TypeKind for eAVEncH263VLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncH263VLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncH263VLevel").field(&self.0).finish()
@@ -26507,6 +26893,7 @@ pub struct eAVEncH263VProfile(pub i32);
 impl windows_core::TypeKind for eAVEncH263VProfile {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncH263VProfile {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncH263VProfile").field(&self.0).finish()
@@ -26518,6 +26905,7 @@ pub struct eAVEncH264PictureType(pub i32);
 impl windows_core::TypeKind for eAVEncH264PictureType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncH264PictureType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncH264PictureType").field(&self.0).finish()
@@ -26529,6 +26917,7 @@ pub struct eAVEncH264VLevel(pub i32);
 impl windows_core::TypeKind for eAVEncH264VLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncH264VLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncH264VLevel").field(&self.0).finish()
@@ -26540,6 +26929,7 @@ pub struct eAVEncH264VProfile(pub i32);
 impl windows_core::TypeKind for eAVEncH264VProfile {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncH264VProfile {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncH264VProfile").field(&self.0).finish()
@@ -26551,6 +26941,7 @@ pub struct eAVEncH265VLevel(pub i32);
 impl windows_core::TypeKind for eAVEncH265VLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncH265VLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncH265VLevel").field(&self.0).finish()
@@ -26562,6 +26953,7 @@ pub struct eAVEncH265VProfile(pub i32);
 impl windows_core::TypeKind for eAVEncH265VProfile {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncH265VProfile {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncH265VProfile").field(&self.0).finish()
@@ -26573,6 +26965,7 @@ pub struct eAVEncInputVideoSystem(pub i32);
 impl windows_core::TypeKind for eAVEncInputVideoSystem {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncInputVideoSystem {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncInputVideoSystem").field(&self.0).finish()
@@ -26584,6 +26977,7 @@ pub struct eAVEncMPACodingMode(pub i32);
 impl windows_core::TypeKind for eAVEncMPACodingMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncMPACodingMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncMPACodingMode").field(&self.0).finish()
@@ -26595,6 +26989,7 @@ pub struct eAVEncMPAEmphasisType(pub i32);
 impl windows_core::TypeKind for eAVEncMPAEmphasisType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncMPAEmphasisType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncMPAEmphasisType").field(&self.0).finish()
@@ -26606,6 +27001,7 @@ pub struct eAVEncMPALayer(pub i32);
 impl windows_core::TypeKind for eAVEncMPALayer {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncMPALayer {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncMPALayer").field(&self.0).finish()
@@ -26617,6 +27013,7 @@ pub struct eAVEncMPVFrameFieldMode(pub i32);
 impl windows_core::TypeKind for eAVEncMPVFrameFieldMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncMPVFrameFieldMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncMPVFrameFieldMode").field(&self.0).finish()
@@ -26628,6 +27025,7 @@ pub struct eAVEncMPVIntraVLCTable(pub i32);
 impl windows_core::TypeKind for eAVEncMPVIntraVLCTable {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncMPVIntraVLCTable {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncMPVIntraVLCTable").field(&self.0).finish()
@@ -26639,6 +27037,7 @@ pub struct eAVEncMPVLevel(pub i32);
 impl windows_core::TypeKind for eAVEncMPVLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncMPVLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncMPVLevel").field(&self.0).finish()
@@ -26650,6 +27049,7 @@ pub struct eAVEncMPVProfile(pub i32);
 impl windows_core::TypeKind for eAVEncMPVProfile {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncMPVProfile {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncMPVProfile").field(&self.0).finish()
@@ -26661,6 +27061,7 @@ pub struct eAVEncMPVQScaleType(pub i32);
 impl windows_core::TypeKind for eAVEncMPVQScaleType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncMPVQScaleType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncMPVQScaleType").field(&self.0).finish()
@@ -26672,6 +27073,7 @@ pub struct eAVEncMPVScanPattern(pub i32);
 impl windows_core::TypeKind for eAVEncMPVScanPattern {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncMPVScanPattern {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncMPVScanPattern").field(&self.0).finish()
@@ -26683,6 +27085,7 @@ pub struct eAVEncMPVSceneDetection(pub i32);
 impl windows_core::TypeKind for eAVEncMPVSceneDetection {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncMPVSceneDetection {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncMPVSceneDetection").field(&self.0).finish()
@@ -26694,6 +27097,7 @@ pub struct eAVEncMuxOutput(pub i32);
 impl windows_core::TypeKind for eAVEncMuxOutput {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncMuxOutput {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncMuxOutput").field(&self.0).finish()
@@ -26705,6 +27109,7 @@ pub struct eAVEncVP9VProfile(pub i32);
 impl windows_core::TypeKind for eAVEncVP9VProfile {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncVP9VProfile {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncVP9VProfile").field(&self.0).finish()
@@ -26716,6 +27121,7 @@ pub struct eAVEncVideoChromaResolution(pub i32);
 impl windows_core::TypeKind for eAVEncVideoChromaResolution {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncVideoChromaResolution {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncVideoChromaResolution").field(&self.0).finish()
@@ -26727,6 +27133,7 @@ pub struct eAVEncVideoChromaSubsampling(pub i32);
 impl windows_core::TypeKind for eAVEncVideoChromaSubsampling {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncVideoChromaSubsampling {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncVideoChromaSubsampling").field(&self.0).finish()
@@ -26738,6 +27145,7 @@ pub struct eAVEncVideoColorLighting(pub i32);
 impl windows_core::TypeKind for eAVEncVideoColorLighting {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncVideoColorLighting {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncVideoColorLighting").field(&self.0).finish()
@@ -26749,6 +27157,7 @@ pub struct eAVEncVideoColorNominalRange(pub i32);
 impl windows_core::TypeKind for eAVEncVideoColorNominalRange {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncVideoColorNominalRange {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncVideoColorNominalRange").field(&self.0).finish()
@@ -26760,6 +27169,7 @@ pub struct eAVEncVideoColorPrimaries(pub i32);
 impl windows_core::TypeKind for eAVEncVideoColorPrimaries {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncVideoColorPrimaries {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncVideoColorPrimaries").field(&self.0).finish()
@@ -26771,6 +27181,7 @@ pub struct eAVEncVideoColorTransferFunction(pub i32);
 impl windows_core::TypeKind for eAVEncVideoColorTransferFunction {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncVideoColorTransferFunction {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncVideoColorTransferFunction").field(&self.0).finish()
@@ -26782,6 +27193,7 @@ pub struct eAVEncVideoColorTransferMatrix(pub i32);
 impl windows_core::TypeKind for eAVEncVideoColorTransferMatrix {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncVideoColorTransferMatrix {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncVideoColorTransferMatrix").field(&self.0).finish()
@@ -26793,6 +27205,7 @@ pub struct eAVEncVideoContentType(pub i32);
 impl windows_core::TypeKind for eAVEncVideoContentType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncVideoContentType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncVideoContentType").field(&self.0).finish()
@@ -26804,6 +27217,7 @@ pub struct eAVEncVideoFilmContent(pub i32);
 impl windows_core::TypeKind for eAVEncVideoFilmContent {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncVideoFilmContent {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncVideoFilmContent").field(&self.0).finish()
@@ -26815,6 +27229,7 @@ pub struct eAVEncVideoOutputFrameRateConversion(pub i32);
 impl windows_core::TypeKind for eAVEncVideoOutputFrameRateConversion {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncVideoOutputFrameRateConversion {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncVideoOutputFrameRateConversion").field(&self.0).finish()
@@ -26826,6 +27241,7 @@ pub struct eAVEncVideoOutputScanType(pub i32);
 impl windows_core::TypeKind for eAVEncVideoOutputScanType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncVideoOutputScanType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncVideoOutputScanType").field(&self.0).finish()
@@ -26837,6 +27253,7 @@ pub struct eAVEncVideoSourceScanType(pub i32);
 impl windows_core::TypeKind for eAVEncVideoSourceScanType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVEncVideoSourceScanType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVEncVideoSourceScanType").field(&self.0).finish()
@@ -26848,6 +27265,7 @@ pub struct eAVFastDecodeMode(pub i32);
 impl windows_core::TypeKind for eAVFastDecodeMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVFastDecodeMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVFastDecodeMode").field(&self.0).finish()
@@ -26859,6 +27277,7 @@ pub struct eAVScenarioInfo(pub i32);
 impl windows_core::TypeKind for eAVScenarioInfo {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAVScenarioInfo {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAVScenarioInfo").field(&self.0).finish()
@@ -26870,6 +27289,7 @@ pub struct eVideoEncoderDisplayContentType(pub i32);
 impl windows_core::TypeKind for eVideoEncoderDisplayContentType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eVideoEncoderDisplayContentType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eVideoEncoderDisplayContentType").field(&self.0).finish()
@@ -26878,7 +27298,8 @@ impl core::fmt::Debug for eVideoEncoderDisplayContentType {
 pub const AACMFTEncoder: windows_core::GUID = windows_core::GUID::from_u128(0x93af0c51_2275_45d2_a35b_f2ba21caed00);
 pub const ALawCodecWrapper: windows_core::GUID = windows_core::GUID::from_u128(0x36cb6e0c_78c1_42b2_9943_846262f31786);
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_MEDIA_TYPE {
     pub majortype: windows_core::GUID,
     pub subtype: windows_core::GUID,
@@ -26933,7 +27354,8 @@ impl Default for ASF_FLAT_SYNCHRONISED_LYRICS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ASF_INDEX_DESCRIPTOR {
     pub Identifier: ASF_INDEX_IDENTIFIER,
     pub cPerEntryBytes: u16,
@@ -26949,7 +27371,8 @@ impl Default for ASF_INDEX_DESCRIPTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ASF_INDEX_IDENTIFIER {
     pub guidIndexType: windows_core::GUID,
     pub wStreamNumber: u16,
@@ -26963,7 +27386,8 @@ impl Default for ASF_INDEX_IDENTIFIER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ASF_MUX_STATISTICS {
     pub cFramesWritten: u32,
     pub cFramesDropped: u32,
@@ -26977,7 +27401,8 @@ impl Default for ASF_MUX_STATISTICS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AecQualityMetrics_Struct {
     pub i64Timestamp: i64,
     pub ConvergenceFlag: u8,
@@ -27410,7 +27835,8 @@ pub const CWVC1EncMediaObject: windows_core::GUID = windows_core::GUID::from_u12
 pub const CZuneAACCCDecMediaObject: windows_core::GUID = windows_core::GUID::from_u128(0xa74e98f2_52d6_4b4e_885b_e0a6ca4f187a);
 pub const CZuneM4S2DecMediaObject: windows_core::GUID = windows_core::GUID::from_u128(0xc56fc25c_0fc6_404a_9503_b10bf51a8ab9);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CodecAPIEventData {
     pub guid: windows_core::GUID,
     pub dataLength: u32,
@@ -27425,7 +27851,8 @@ impl Default for CodecAPIEventData {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_ARCHITECTURE {
     pub IOCoherent: super::super::Foundation::BOOL,
 }
@@ -27439,7 +27866,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_ARCHITECTURE {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_DECODER_HEAP_SIZE {
     pub VideoDecoderHeapDesc: D3D12_VIDEO_DECODER_HEAP_DESC,
     pub MemoryPoolL0Size: u64,
@@ -27457,7 +27885,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_DECODER_HEAP_SIZE {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_DECODER_HEAP_SIZE1 {
     pub VideoDecoderHeapDesc: D3D12_VIDEO_DECODER_HEAP_DESC,
     pub Protected: super::super::Foundation::BOOL,
@@ -27476,7 +27905,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_DECODER_HEAP_SIZE1 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_DECODE_CONVERSION_SUPPORT {
     pub NodeIndex: u32,
     pub Configuration: D3D12_VIDEO_DECODE_CONFIGURATION,
@@ -27499,7 +27929,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_DECODE_CONVERSION_SUPPORT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_DECODE_FORMATS {
     pub NodeIndex: u32,
     pub Configuration: D3D12_VIDEO_DECODE_CONFIGURATION,
@@ -27517,7 +27948,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_DECODE_FORMATS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_DECODE_FORMAT_COUNT {
     pub NodeIndex: u32,
     pub Configuration: D3D12_VIDEO_DECODE_CONFIGURATION,
@@ -27533,7 +27965,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_DECODE_FORMAT_COUNT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_DECODE_HISTOGRAM {
     pub NodeIndex: u32,
     pub DecodeProfile: windows_core::GUID,
@@ -27555,7 +27988,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_DECODE_HISTOGRAM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_DECODE_PROFILES {
     pub NodeIndex: u32,
     pub ProfileCount: u32,
@@ -27570,7 +28004,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_DECODE_PROFILES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_DECODE_PROFILE_COUNT {
     pub NodeIndex: u32,
     pub ProfileCount: u32,
@@ -27584,7 +28019,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_DECODE_PROFILE_COUNT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_DECODE_PROTECTED_RESOURCES {
     pub NodeIndex: u32,
     pub Configuration: D3D12_VIDEO_DECODE_CONFIGURATION,
@@ -27600,7 +28036,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_DECODE_PROTECTED_RESOURCES {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_DECODE_SUPPORT {
     pub NodeIndex: u32,
     pub Configuration: D3D12_VIDEO_DECODE_CONFIGURATION,
@@ -27624,7 +28061,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_DECODE_SUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_ENCODER_CODEC {
     pub NodeIndex: u32,
     pub Codec: D3D12_VIDEO_ENCODER_CODEC,
@@ -27765,7 +28203,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_ENCODER_INTRA_REFRESH_MODE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_ENCODER_OUTPUT_RESOLUTION {
     pub NodeIndex: u32,
     pub Codec: D3D12_VIDEO_ENCODER_CODEC,
@@ -27786,7 +28225,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_ENCODER_OUTPUT_RESOLUTION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_ENCODER_OUTPUT_RESOLUTION_RATIOS_COUNT {
     pub NodeIndex: u32,
     pub Codec: D3D12_VIDEO_ENCODER_CODEC,
@@ -27819,7 +28259,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_ENCODER_PROFILE_LEVEL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_ENCODER_RATE_CONTROL_MODE {
     pub NodeIndex: u32,
     pub Codec: D3D12_VIDEO_ENCODER_CODEC,
@@ -27835,7 +28276,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_ENCODER_RATE_CONTROL_MODE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_ENCODER_RESOLUTION_SUPPORT_LIMITS {
     pub MaxSubregionsNumber: u32,
     pub MaxIntraRefreshFrameDuration: u32,
@@ -27940,7 +28382,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_ENCODER_SUPPORT1 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_EXTENSION_COMMANDS {
     pub NodeIndex: u32,
     pub CommandCount: u32,
@@ -27957,7 +28400,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_EXTENSION_COMMANDS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_EXTENSION_COMMAND_COUNT {
     pub NodeIndex: u32,
     pub CommandCount: u32,
@@ -27971,7 +28415,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_EXTENSION_COMMAND_COUNT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_EXTENSION_COMMAND_PARAMETERS {
     pub CommandId: windows_core::GUID,
     pub Stage: D3D12_VIDEO_EXTENSION_COMMAND_PARAMETER_STAGE,
@@ -27987,7 +28432,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_EXTENSION_COMMAND_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_EXTENSION_COMMAND_PARAMETER_COUNT {
     pub CommandId: windows_core::GUID,
     pub Stage: D3D12_VIDEO_EXTENSION_COMMAND_PARAMETER_STAGE,
@@ -28003,7 +28449,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_EXTENSION_COMMAND_PARAMETER_COUNT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_EXTENSION_COMMAND_SIZE {
     pub NodeIndex: u32,
     pub CommandId: windows_core::GUID,
@@ -28021,7 +28468,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_EXTENSION_COMMAND_SIZE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_EXTENSION_COMMAND_SUPPORT {
     pub NodeIndex: u32,
     pub CommandId: windows_core::GUID,
@@ -28039,7 +28487,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_EXTENSION_COMMAND_SUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_FEATURE_AREA_SUPPORT {
     pub NodeIndex: u32,
     pub VideoDecodeSupport: super::super::Foundation::BOOL,
@@ -28056,7 +28505,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_FEATURE_AREA_SUPPORT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_MOTION_ESTIMATOR {
     pub NodeIndex: u32,
     pub InputFormat: super::super::Graphics::Dxgi::Common::DXGI_FORMAT,
@@ -28075,7 +28525,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_MOTION_ESTIMATOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_MOTION_ESTIMATOR_PROTECTED_RESOURCES {
     pub NodeIndex: u32,
     pub SupportFlags: D3D12_VIDEO_PROTECTED_RESOURCE_SUPPORT_FLAGS,
@@ -28090,7 +28541,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_MOTION_ESTIMATOR_PROTECTED_RESOURCES {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_MOTION_ESTIMATOR_SIZE {
     pub NodeIndex: u32,
     pub InputFormat: super::super::Graphics::Dxgi::Common::DXGI_FORMAT,
@@ -28115,7 +28567,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_MOTION_ESTIMATOR_SIZE {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_PROCESSOR_SIZE {
     pub NodeMask: u32,
     pub pOutputStreamDesc: *const D3D12_VIDEO_PROCESS_OUTPUT_STREAM_DESC,
@@ -28136,7 +28589,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_PROCESSOR_SIZE {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_PROCESSOR_SIZE1 {
     pub NodeMask: u32,
     pub pOutputStreamDesc: *const D3D12_VIDEO_PROCESS_OUTPUT_STREAM_DESC,
@@ -28157,7 +28611,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_PROCESSOR_SIZE1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_PROCESS_MAX_INPUT_STREAMS {
     pub NodeIndex: u32,
     pub MaxInputStreams: u32,
@@ -28171,7 +28626,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_PROCESS_MAX_INPUT_STREAMS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_PROCESS_PROTECTED_RESOURCES {
     pub NodeIndex: u32,
     pub SupportFlags: D3D12_VIDEO_PROTECTED_RESOURCE_SUPPORT_FLAGS,
@@ -28186,7 +28642,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_PROCESS_PROTECTED_RESOURCES {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_PROCESS_REFERENCE_INFO {
     pub NodeIndex: u32,
     pub DeinterlaceMode: D3D12_VIDEO_PROCESS_DEINTERLACE_FLAGS,
@@ -28210,7 +28667,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_PROCESS_REFERENCE_INFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_FEATURE_DATA_VIDEO_PROCESS_SUPPORT {
     pub NodeIndex: u32,
     pub InputSample: D3D12_VIDEO_SAMPLE,
@@ -28240,7 +28698,8 @@ impl Default for D3D12_FEATURE_DATA_VIDEO_PROCESS_SUPPORT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_QUERY_DATA_VIDEO_DECODE_STATISTICS {
     pub Status: u64,
     pub NumMacroblocksAffected: u64,
@@ -28259,7 +28718,8 @@ impl Default for D3D12_QUERY_DATA_VIDEO_DECODE_STATISTICS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RESOLVE_VIDEO_MOTION_VECTOR_HEAP_INPUT {
     pub pMotionVectorHeap: core::mem::ManuallyDrop<Option<ID3D12VideoMotionVectorHeap>>,
     pub PixelWidth: u32,
@@ -28283,7 +28743,8 @@ impl Default for D3D12_RESOLVE_VIDEO_MOTION_VECTOR_HEAP_INPUT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RESOLVE_VIDEO_MOTION_VECTOR_HEAP_OUTPUT {
     pub pMotionVectorTexture2D: core::mem::ManuallyDrop<Option<super::super::Graphics::Direct3D12::ID3D12Resource>>,
     pub MotionVectorCoordinate: D3D12_RESOURCE_COORDINATE,
@@ -28305,7 +28766,8 @@ impl Default for D3D12_RESOLVE_VIDEO_MOTION_VECTOR_HEAP_OUTPUT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_RESOURCE_COORDINATE {
     pub X: u64,
     pub Y: u32,
@@ -28321,7 +28783,8 @@ impl Default for D3D12_RESOURCE_COORDINATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_DECODER_DESC {
     pub NodeMask: u32,
     pub Configuration: D3D12_VIDEO_DECODE_CONFIGURATION,
@@ -28336,7 +28799,8 @@ impl Default for D3D12_VIDEO_DECODER_DESC {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_DECODER_HEAP_DESC {
     pub NodeMask: u32,
     pub Configuration: D3D12_VIDEO_DECODE_CONFIGURATION,
@@ -28359,7 +28823,8 @@ impl Default for D3D12_VIDEO_DECODER_HEAP_DESC {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_DECODE_COMPRESSED_BITSTREAM {
     pub pBuffer: core::mem::ManuallyDrop<Option<super::super::Graphics::Direct3D12::ID3D12Resource>>,
     pub Offset: u64,
@@ -28382,7 +28847,8 @@ impl Default for D3D12_VIDEO_DECODE_COMPRESSED_BITSTREAM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_DECODE_CONFIGURATION {
     pub DecodeProfile: windows_core::GUID,
     pub BitstreamEncryption: D3D12_BITSTREAM_ENCRYPTION_TYPE,
@@ -28398,7 +28864,8 @@ impl Default for D3D12_VIDEO_DECODE_CONFIGURATION {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_DECODE_CONVERSION_ARGUMENTS {
     pub Enable: super::super::Foundation::BOOL,
     pub pReferenceTexture2D: core::mem::ManuallyDrop<Option<super::super::Graphics::Direct3D12::ID3D12Resource>>,
@@ -28424,7 +28891,8 @@ impl Default for D3D12_VIDEO_DECODE_CONVERSION_ARGUMENTS {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_DECODE_CONVERSION_ARGUMENTS1 {
     pub Enable: super::super::Foundation::BOOL,
     pub pReferenceTexture2D: core::mem::ManuallyDrop<Option<super::super::Graphics::Direct3D12::ID3D12Resource>>,
@@ -28451,7 +28919,8 @@ impl Default for D3D12_VIDEO_DECODE_CONVERSION_ARGUMENTS1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_DECODE_FRAME_ARGUMENT {
     pub Type: D3D12_VIDEO_DECODE_ARGUMENT_TYPE,
     pub Size: u32,
@@ -28467,7 +28936,8 @@ impl Default for D3D12_VIDEO_DECODE_FRAME_ARGUMENT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_DECODE_INPUT_STREAM_ARGUMENTS {
     pub NumFrameArguments: u32,
     pub FrameArguments: [D3D12_VIDEO_DECODE_FRAME_ARGUMENT; 10],
@@ -28493,7 +28963,8 @@ impl Default for D3D12_VIDEO_DECODE_INPUT_STREAM_ARGUMENTS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_DECODE_OUTPUT_HISTOGRAM {
     pub Offset: u64,
     pub pBuffer: core::mem::ManuallyDrop<Option<super::super::Graphics::Direct3D12::ID3D12Resource>>,
@@ -28516,7 +28987,8 @@ impl Default for D3D12_VIDEO_DECODE_OUTPUT_HISTOGRAM {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_DECODE_OUTPUT_STREAM_ARGUMENTS {
     pub pOutputTexture2D: core::mem::ManuallyDrop<Option<super::super::Graphics::Direct3D12::ID3D12Resource>>,
     pub OutputSubresource: u32,
@@ -28540,7 +29012,8 @@ impl Default for D3D12_VIDEO_DECODE_OUTPUT_STREAM_ARGUMENTS {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_DECODE_OUTPUT_STREAM_ARGUMENTS1 {
     pub pOutputTexture2D: core::mem::ManuallyDrop<Option<super::super::Graphics::Direct3D12::ID3D12Resource>>,
     pub OutputSubresource: u32,
@@ -28565,7 +29038,8 @@ impl Default for D3D12_VIDEO_DECODE_OUTPUT_STREAM_ARGUMENTS1 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_DECODE_REFERENCE_FRAMES {
     pub NumTexture2Ds: u32,
     pub ppTexture2Ds: *mut Option<super::super::Graphics::Direct3D12::ID3D12Resource>,
@@ -28583,7 +29057,8 @@ impl Default for D3D12_VIDEO_DECODE_REFERENCE_FRAMES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_AV1_CDEF_CONFIG {
     pub CdefBits: u64,
     pub CdefDampingMinus3: u64,
@@ -28601,7 +29076,8 @@ impl Default for D3D12_VIDEO_ENCODER_AV1_CDEF_CONFIG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_AV1_CODEC_CONFIGURATION {
     pub FeatureFlags: D3D12_VIDEO_ENCODER_AV1_FEATURE_FLAGS,
     pub OrderHintBitsMinus1: u32,
@@ -28615,7 +29091,8 @@ impl Default for D3D12_VIDEO_ENCODER_AV1_CODEC_CONFIGURATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_AV1_CODEC_CONFIGURATION_SUPPORT {
     pub SupportedFeatureFlags: D3D12_VIDEO_ENCODER_AV1_FEATURE_FLAGS,
     pub RequiredFeatureFlags: D3D12_VIDEO_ENCODER_AV1_FEATURE_FLAGS,
@@ -28637,7 +29114,8 @@ impl Default for D3D12_VIDEO_ENCODER_AV1_CODEC_CONFIGURATION_SUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_AV1_FRAME_SUBREGION_LAYOUT_CONFIG_SUPPORT {
     pub Use128SuperBlocks: super::super::Foundation::BOOL,
     pub TilesConfiguration: D3D12_VIDEO_ENCODER_AV1_PICTURE_CONTROL_SUBREGIONS_LAYOUT_DATA_TILES,
@@ -28661,7 +29139,8 @@ impl Default for D3D12_VIDEO_ENCODER_AV1_FRAME_SUBREGION_LAYOUT_CONFIG_SUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_AV1_LEVEL_TIER_CONSTRAINTS {
     pub Level: D3D12_VIDEO_ENCODER_AV1_LEVELS,
     pub Tier: D3D12_VIDEO_ENCODER_AV1_TIER,
@@ -28675,7 +29154,8 @@ impl Default for D3D12_VIDEO_ENCODER_AV1_LEVEL_TIER_CONSTRAINTS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_AV1_PICTURE_CONTROL_CODEC_DATA {
     pub Flags: D3D12_VIDEO_ENCODER_AV1_PICTURE_CONTROL_FLAGS,
     pub FrameType: D3D12_VIDEO_ENCODER_AV1_FRAME_TYPE,
@@ -28711,7 +29191,8 @@ impl Default for D3D12_VIDEO_ENCODER_AV1_PICTURE_CONTROL_CODEC_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_AV1_PICTURE_CONTROL_SUBREGIONS_LAYOUT_DATA_TILES {
     pub RowCount: u64,
     pub ColCount: u64,
@@ -28728,7 +29209,8 @@ impl Default for D3D12_VIDEO_ENCODER_AV1_PICTURE_CONTROL_SUBREGIONS_LAYOUT_DATA_
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_AV1_POST_ENCODE_VALUES {
     pub CompoundPredictionType: u64,
     pub LoopFilter: D3D12_VIDEO_ENCODER_CODEC_AV1_LOOP_FILTER_CONFIG,
@@ -28749,7 +29231,8 @@ impl Default for D3D12_VIDEO_ENCODER_AV1_POST_ENCODE_VALUES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_AV1_REFERENCE_PICTURE_DESCRIPTOR {
     pub ReconstructedPictureResourceIndex: u32,
     pub TemporalLayerIndexPlus1: u32,
@@ -28768,7 +29251,8 @@ impl Default for D3D12_VIDEO_ENCODER_AV1_REFERENCE_PICTURE_DESCRIPTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_AV1_REFERENCE_PICTURE_WARPED_MOTION_INFO {
     pub TransformationType: D3D12_VIDEO_ENCODER_AV1_REFERENCE_WARPED_MOTION_TRANSFORMATION,
     pub TransformationMatrix: [i32; 8],
@@ -28783,7 +29267,8 @@ impl Default for D3D12_VIDEO_ENCODER_AV1_REFERENCE_PICTURE_WARPED_MOTION_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_AV1_RESTORATION_CONFIG {
     pub FrameRestorationType: [D3D12_VIDEO_ENCODER_AV1_RESTORATION_TYPE; 3],
     pub LoopRestorationPixelSize: [D3D12_VIDEO_ENCODER_AV1_RESTORATION_TILESIZE; 3],
@@ -28797,7 +29282,8 @@ impl Default for D3D12_VIDEO_ENCODER_AV1_RESTORATION_CONFIG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_AV1_SEGMENTATION_CONFIG {
     pub UpdateMap: u64,
     pub TemporalUpdate: u64,
@@ -28814,7 +29300,8 @@ impl Default for D3D12_VIDEO_ENCODER_AV1_SEGMENTATION_CONFIG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_AV1_SEGMENTATION_MAP {
     pub SegmentsMapByteSize: u32,
     pub pSegmentsMap: *mut u8,
@@ -28828,7 +29315,8 @@ impl Default for D3D12_VIDEO_ENCODER_AV1_SEGMENTATION_MAP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_AV1_SEGMENT_DATA {
     pub EnabledFeatures: u64,
     pub FeatureValue: [i64; 8],
@@ -28842,7 +29330,8 @@ impl Default for D3D12_VIDEO_ENCODER_AV1_SEGMENT_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_AV1_SEQUENCE_STRUCTURE {
     pub IntraDistance: u32,
     pub InterFramePeriod: u32,
@@ -28856,7 +29345,8 @@ impl Default for D3D12_VIDEO_ENCODER_AV1_SEQUENCE_STRUCTURE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_CODEC_AV1_LOOP_FILTER_CONFIG {
     pub LoopFilterLevel: [u64; 2],
     pub LoopFilterLevelU: u64,
@@ -28877,7 +29367,8 @@ impl Default for D3D12_VIDEO_ENCODER_CODEC_AV1_LOOP_FILTER_CONFIG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_CODEC_AV1_LOOP_FILTER_DELTA_CONFIG {
     pub DeltaLFPresent: u64,
     pub DeltaLFMulti: u64,
@@ -28892,7 +29383,8 @@ impl Default for D3D12_VIDEO_ENCODER_CODEC_AV1_LOOP_FILTER_DELTA_CONFIG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_CODEC_AV1_PICTURE_CONTROL_SUPPORT {
     pub PredictionMode: D3D12_VIDEO_ENCODER_AV1_COMP_PREDICTION_TYPE,
     pub MaxUniqueReferencesPerFrame: u32,
@@ -28908,7 +29400,8 @@ impl Default for D3D12_VIDEO_ENCODER_CODEC_AV1_PICTURE_CONTROL_SUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_CODEC_AV1_QUANTIZATION_CONFIG {
     pub BaseQIndex: u64,
     pub YDCDeltaQ: i64,
@@ -28930,7 +29423,8 @@ impl Default for D3D12_VIDEO_ENCODER_CODEC_AV1_QUANTIZATION_CONFIG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_CODEC_AV1_QUANTIZATION_DELTA_CONFIG {
     pub DeltaQPresent: u64,
     pub DeltaQRes: u64,
@@ -28973,7 +29467,8 @@ impl Default for D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_H264 {
     pub ConfigurationFlags: D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_H264_FLAGS,
     pub DirectModeConfig: D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_H264_DIRECT_MODES,
@@ -28988,7 +29483,8 @@ impl Default for D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_H264 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_HEVC {
     pub ConfigurationFlags: D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_HEVC_FLAGS,
     pub MinLumaCodingUnitSize: D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_HEVC_CUSIZE,
@@ -29037,7 +29533,8 @@ impl Default for D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_SUPPORT_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_SUPPORT_H264 {
     pub SupportFlags: D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_SUPPORT_H264_FLAGS,
     pub DisableDeblockingFilterSupportedModes: D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_H264_SLICES_DEBLOCKING_MODE_FLAGS,
@@ -29051,7 +29548,8 @@ impl Default for D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_SUPPORT_H264 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_SUPPORT_HEVC {
     pub SupportFlags: D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_SUPPORT_HEVC_FLAGS,
     pub MinLumaCodingUnitSize: D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_HEVC_CUSIZE,
@@ -29070,7 +29568,8 @@ impl Default for D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_SUPPORT_HEVC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_SUPPORT_HEVC1 {
     pub SupportFlags: D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_SUPPORT_HEVC_FLAGS,
     pub MinLumaCodingUnitSize: D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION_HEVC_CUSIZE,
@@ -29126,7 +29625,8 @@ impl Default for D3D12_VIDEO_ENCODER_CODEC_PICTURE_CONTROL_SUPPORT_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_CODEC_PICTURE_CONTROL_SUPPORT_H264 {
     pub MaxL0ReferencesForP: u32,
     pub MaxL0ReferencesForB: u32,
@@ -29143,7 +29643,8 @@ impl Default for D3D12_VIDEO_ENCODER_CODEC_PICTURE_CONTROL_SUPPORT_H264 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_CODEC_PICTURE_CONTROL_SUPPORT_HEVC {
     pub MaxL0ReferencesForP: u32,
     pub MaxL0ReferencesForB: u32,
@@ -29161,7 +29662,8 @@ impl Default for D3D12_VIDEO_ENCODER_CODEC_PICTURE_CONTROL_SUPPORT_HEVC {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_COMPRESSED_BITSTREAM {
     pub pBuffer: core::mem::ManuallyDrop<Option<super::super::Graphics::Direct3D12::ID3D12Resource>>,
     pub FrameStartOffset: u64,
@@ -29231,7 +29733,8 @@ impl Default for D3D12_VIDEO_ENCODER_ENCODEFRAME_INPUT_ARGUMENTS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_ENCODEFRAME_OUTPUT_ARGUMENTS {
     pub Bitstream: D3D12_VIDEO_ENCODER_COMPRESSED_BITSTREAM,
     pub ReconstructedPicture: D3D12_VIDEO_ENCODER_RECONSTRUCTED_PICTURE,
@@ -29255,7 +29758,8 @@ impl Default for D3D12_VIDEO_ENCODER_ENCODEFRAME_OUTPUT_ARGUMENTS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_ENCODE_OPERATION_METADATA_BUFFER {
     pub pBuffer: core::mem::ManuallyDrop<Option<super::super::Graphics::Direct3D12::ID3D12Resource>>,
     pub Offset: u64,
@@ -29304,7 +29808,8 @@ impl Default for D3D12_VIDEO_ENCODER_FRAME_SUBREGION_LAYOUT_CONFIG_SUPPORT_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_FRAME_SUBREGION_METADATA {
     pub bSize: u64,
     pub bStartOffset: u64,
@@ -29338,7 +29843,8 @@ impl Default for D3D12_VIDEO_ENCODER_HEAP_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_INTRA_REFRESH {
     pub Mode: D3D12_VIDEO_ENCODER_INTRA_REFRESH_MODE,
     pub IntraRefreshDuration: u32,
@@ -29381,7 +29887,8 @@ impl Default for D3D12_VIDEO_ENCODER_LEVEL_SETTING_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_LEVEL_TIER_CONSTRAINTS_HEVC {
     pub Level: D3D12_VIDEO_ENCODER_LEVELS_HEVC,
     pub Tier: D3D12_VIDEO_ENCODER_TIER_HEVC,
@@ -29395,7 +29902,8 @@ impl Default for D3D12_VIDEO_ENCODER_LEVEL_TIER_CONSTRAINTS_HEVC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_OUTPUT_METADATA {
     pub EncodeErrorFlags: u64,
     pub EncodeStats: D3D12_VIDEO_ENCODER_OUTPUT_METADATA_STATISTICS,
@@ -29411,7 +29919,8 @@ impl Default for D3D12_VIDEO_ENCODER_OUTPUT_METADATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_OUTPUT_METADATA_STATISTICS {
     pub AverageQP: u64,
     pub IntraCodingUnitsCount: u64,
@@ -29459,7 +29968,8 @@ impl Default for D3D12_VIDEO_ENCODER_PICTURE_CONTROL_CODEC_DATA_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_PICTURE_CONTROL_CODEC_DATA_H264 {
     pub Flags: D3D12_VIDEO_ENCODER_PICTURE_CONTROL_CODEC_DATA_H264_FLAGS,
     pub FrameType: D3D12_VIDEO_ENCODER_FRAME_TYPE_H264,
@@ -29493,7 +30003,8 @@ impl Default for D3D12_VIDEO_ENCODER_PICTURE_CONTROL_CODEC_DATA_H264 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_PICTURE_CONTROL_CODEC_DATA_H264_REFERENCE_PICTURE_LIST_MODIFICATION_OPERATION {
     pub modification_of_pic_nums_idc: u8,
     pub abs_diff_pic_num_minus1: u32,
@@ -29508,7 +30019,8 @@ impl Default for D3D12_VIDEO_ENCODER_PICTURE_CONTROL_CODEC_DATA_H264_REFERENCE_P
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_PICTURE_CONTROL_CODEC_DATA_H264_REFERENCE_PICTURE_MARKING_OPERATION {
     pub memory_management_control_operation: u8,
     pub difference_of_pic_nums_minus1: u32,
@@ -29525,7 +30037,8 @@ impl Default for D3D12_VIDEO_ENCODER_PICTURE_CONTROL_CODEC_DATA_H264_REFERENCE_P
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_PICTURE_CONTROL_CODEC_DATA_HEVC {
     pub Flags: D3D12_VIDEO_ENCODER_PICTURE_CONTROL_CODEC_DATA_HEVC_FLAGS,
     pub FrameType: D3D12_VIDEO_ENCODER_FRAME_TYPE_HEVC,
@@ -29554,7 +30067,8 @@ impl Default for D3D12_VIDEO_ENCODER_PICTURE_CONTROL_CODEC_DATA_HEVC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_PICTURE_CONTROL_CODEC_DATA_HEVC1 {
     pub Flags: D3D12_VIDEO_ENCODER_PICTURE_CONTROL_CODEC_DATA_HEVC_FLAGS,
     pub FrameType: D3D12_VIDEO_ENCODER_FRAME_TYPE_HEVC,
@@ -29667,7 +30181,8 @@ impl Default for D3D12_VIDEO_ENCODER_PICTURE_CONTROL_SUBREGIONS_LAYOUT_DATA_SLIC
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_PICTURE_RESOLUTION_DESC {
     pub Width: u32,
     pub Height: u32,
@@ -29681,7 +30196,8 @@ impl Default for D3D12_VIDEO_ENCODER_PICTURE_RESOLUTION_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_PICTURE_RESOLUTION_RATIO_DESC {
     pub WidthRatio: u32,
     pub HeightRatio: u32,
@@ -29743,7 +30259,8 @@ impl Default for D3D12_VIDEO_ENCODER_RATE_CONTROL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_RATE_CONTROL_ABSOLUTE_QP_MAP {
     pub QualityVsSpeed: u32,
 }
@@ -29756,7 +30273,8 @@ impl Default for D3D12_VIDEO_ENCODER_RATE_CONTROL_ABSOLUTE_QP_MAP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_RATE_CONTROL_CBR {
     pub InitialQP: u32,
     pub MinQP: u32,
@@ -29775,7 +30293,8 @@ impl Default for D3D12_VIDEO_ENCODER_RATE_CONTROL_CBR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_RATE_CONTROL_CBR1 {
     pub InitialQP: u32,
     pub MinQP: u32,
@@ -29830,7 +30349,8 @@ impl Default for D3D12_VIDEO_ENCODER_RATE_CONTROL_CONFIGURATION_PARAMS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_RATE_CONTROL_CQP {
     pub ConstantQP_FullIntracodedFrame: u32,
     pub ConstantQP_InterPredictedFrame_PrevRefOnly: u32,
@@ -29845,7 +30365,8 @@ impl Default for D3D12_VIDEO_ENCODER_RATE_CONTROL_CQP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_RATE_CONTROL_CQP1 {
     pub ConstantQP_FullIntracodedFrame: u32,
     pub ConstantQP_InterPredictedFrame_PrevRefOnly: u32,
@@ -29861,7 +30382,8 @@ impl Default for D3D12_VIDEO_ENCODER_RATE_CONTROL_CQP1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_RATE_CONTROL_QVBR {
     pub InitialQP: u32,
     pub MinQP: u32,
@@ -29880,7 +30402,8 @@ impl Default for D3D12_VIDEO_ENCODER_RATE_CONTROL_QVBR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_RATE_CONTROL_QVBR1 {
     pub InitialQP: u32,
     pub MinQP: u32,
@@ -29902,7 +30425,8 @@ impl Default for D3D12_VIDEO_ENCODER_RATE_CONTROL_QVBR1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_RATE_CONTROL_VBR {
     pub InitialQP: u32,
     pub MinQP: u32,
@@ -29922,7 +30446,8 @@ impl Default for D3D12_VIDEO_ENCODER_RATE_CONTROL_VBR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_RATE_CONTROL_VBR1 {
     pub InitialQP: u32,
     pub MinQP: u32,
@@ -29944,7 +30469,8 @@ impl Default for D3D12_VIDEO_ENCODER_RATE_CONTROL_VBR1 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_RECONSTRUCTED_PICTURE {
     pub pReconstructedPicture: core::mem::ManuallyDrop<Option<super::super::Graphics::Direct3D12::ID3D12Resource>>,
     pub ReconstructedPictureSubresource: u32,
@@ -29966,7 +30492,8 @@ impl Default for D3D12_VIDEO_ENCODER_RECONSTRUCTED_PICTURE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_REFERENCE_PICTURE_DESCRIPTOR_H264 {
     pub ReconstructedPictureResourceIndex: u32,
     pub IsLongTermReference: super::super::Foundation::BOOL,
@@ -29984,7 +30511,8 @@ impl Default for D3D12_VIDEO_ENCODER_REFERENCE_PICTURE_DESCRIPTOR_H264 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_REFERENCE_PICTURE_DESCRIPTOR_HEVC {
     pub ReconstructedPictureResourceIndex: u32,
     pub IsRefUsedByCurrentPic: super::super::Foundation::BOOL,
@@ -30027,7 +30555,8 @@ impl Default for D3D12_VIDEO_ENCODER_RESOLVE_METADATA_INPUT_ARGUMENTS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_RESOLVE_METADATA_OUTPUT_ARGUMENTS {
     pub ResolvedLayoutMetadata: D3D12_VIDEO_ENCODER_ENCODE_OPERATION_METADATA_BUFFER,
 }
@@ -30099,7 +30628,8 @@ impl Default for D3D12_VIDEO_ENCODER_SEQUENCE_GOP_STRUCTURE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_SEQUENCE_GOP_STRUCTURE_H264 {
     pub GOPLength: u32,
     pub PPicturePeriod: u32,
@@ -30116,7 +30646,8 @@ impl Default for D3D12_VIDEO_ENCODER_SEQUENCE_GOP_STRUCTURE_H264 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODER_SEQUENCE_GOP_STRUCTURE_HEVC {
     pub GOPLength: u32,
     pub PPicturePeriod: u32,
@@ -30132,7 +30663,8 @@ impl Default for D3D12_VIDEO_ENCODER_SEQUENCE_GOP_STRUCTURE_HEVC {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_ENCODE_REFERENCE_FRAMES {
     pub NumTexture2Ds: u32,
     pub ppTexture2Ds: *mut Option<super::super::Graphics::Direct3D12::ID3D12Resource>,
@@ -30149,7 +30681,8 @@ impl Default for D3D12_VIDEO_ENCODE_REFERENCE_FRAMES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_EXTENSION_COMMAND_DESC {
     pub NodeMask: u32,
     pub CommandId: windows_core::GUID,
@@ -30164,7 +30697,8 @@ impl Default for D3D12_VIDEO_EXTENSION_COMMAND_DESC {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_EXTENSION_COMMAND_INFO {
     pub CommandId: windows_core::GUID,
     pub Name: windows_core::PCWSTR,
@@ -30181,7 +30715,8 @@ impl Default for D3D12_VIDEO_EXTENSION_COMMAND_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_EXTENSION_COMMAND_PARAMETER_INFO {
     pub Name: windows_core::PCWSTR,
     pub Type: D3D12_VIDEO_EXTENSION_COMMAND_PARAMETER_TYPE,
@@ -30197,7 +30732,8 @@ impl Default for D3D12_VIDEO_EXTENSION_COMMAND_PARAMETER_INFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_FORMAT {
     pub Format: super::super::Graphics::Dxgi::Common::DXGI_FORMAT,
     pub ColorSpace: super::super::Graphics::Dxgi::Common::DXGI_COLOR_SPACE_TYPE,
@@ -30214,7 +30750,8 @@ impl Default for D3D12_VIDEO_FORMAT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_MOTION_ESTIMATOR_DESC {
     pub NodeMask: u32,
     pub InputFormat: super::super::Graphics::Dxgi::Common::DXGI_FORMAT,
@@ -30234,7 +30771,8 @@ impl Default for D3D12_VIDEO_MOTION_ESTIMATOR_DESC {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_MOTION_ESTIMATOR_INPUT {
     pub pInputTexture2D: core::mem::ManuallyDrop<Option<super::super::Graphics::Direct3D12::ID3D12Resource>>,
     pub InputSubresourceIndex: u32,
@@ -30260,7 +30798,8 @@ impl Default for D3D12_VIDEO_MOTION_ESTIMATOR_INPUT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_MOTION_ESTIMATOR_OUTPUT {
     pub pMotionVectorHeap: core::mem::ManuallyDrop<Option<ID3D12VideoMotionVectorHeap>>,
 }
@@ -30282,7 +30821,8 @@ impl Default for D3D12_VIDEO_MOTION_ESTIMATOR_OUTPUT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_MOTION_VECTOR_HEAP_DESC {
     pub NodeMask: u32,
     pub InputFormat: super::super::Graphics::Dxgi::Common::DXGI_FORMAT,
@@ -30301,7 +30841,8 @@ impl Default for D3D12_VIDEO_MOTION_VECTOR_HEAP_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_PROCESS_ALPHA_BLENDING {
     pub Enable: super::super::Foundation::BOOL,
     pub Alpha: f32,
@@ -30315,7 +30856,8 @@ impl Default for D3D12_VIDEO_PROCESS_ALPHA_BLENDING {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_PROCESS_FILTER_RANGE {
     pub Minimum: i32,
     pub Maximum: i32,
@@ -30332,7 +30874,8 @@ impl Default for D3D12_VIDEO_PROCESS_FILTER_RANGE {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_PROCESS_INPUT_STREAM {
     pub pTexture2D: core::mem::ManuallyDrop<Option<super::super::Graphics::Direct3D12::ID3D12Resource>>,
     pub Subresource: u32,
@@ -30356,7 +30899,8 @@ impl Default for D3D12_VIDEO_PROCESS_INPUT_STREAM {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
-#[derive(Debug, PartialEq)]
+#[derive(PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_PROCESS_INPUT_STREAM_ARGUMENTS {
     pub InputStream: [D3D12_VIDEO_PROCESS_INPUT_STREAM; 2],
     pub Transform: D3D12_VIDEO_PROCESS_TRANSFORM,
@@ -30383,7 +30927,8 @@ impl Default for D3D12_VIDEO_PROCESS_INPUT_STREAM_ARGUMENTS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
-#[derive(Debug, PartialEq)]
+#[derive(PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_PROCESS_INPUT_STREAM_ARGUMENTS1 {
     pub InputStream: [D3D12_VIDEO_PROCESS_INPUT_STREAM; 2],
     pub Transform: D3D12_VIDEO_PROCESS_TRANSFORM,
@@ -30411,7 +30956,8 @@ impl Default for D3D12_VIDEO_PROCESS_INPUT_STREAM_ARGUMENTS1 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_PROCESS_INPUT_STREAM_DESC {
     pub Format: super::super::Graphics::Dxgi::Common::DXGI_FORMAT,
     pub ColorSpace: super::super::Graphics::Dxgi::Common::DXGI_COLOR_SPACE_TYPE,
@@ -30442,7 +30988,8 @@ impl Default for D3D12_VIDEO_PROCESS_INPUT_STREAM_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_PROCESS_INPUT_STREAM_RATE {
     pub OutputIndex: u32,
     pub InputFrameOrField: u32,
@@ -30456,7 +31003,8 @@ impl Default for D3D12_VIDEO_PROCESS_INPUT_STREAM_RATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_PROCESS_LUMA_KEY {
     pub Enable: super::super::Foundation::BOOL,
     pub Lower: f32,
@@ -30472,7 +31020,8 @@ impl Default for D3D12_VIDEO_PROCESS_LUMA_KEY {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_PROCESS_OUTPUT_STREAM {
     pub pTexture2D: core::mem::ManuallyDrop<Option<super::super::Graphics::Direct3D12::ID3D12Resource>>,
     pub Subresource: u32,
@@ -30495,7 +31044,8 @@ impl Default for D3D12_VIDEO_PROCESS_OUTPUT_STREAM {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_PROCESS_OUTPUT_STREAM_ARGUMENTS {
     pub OutputStream: [D3D12_VIDEO_PROCESS_OUTPUT_STREAM; 2],
     pub TargetRectangle: super::super::Foundation::RECT,
@@ -30518,7 +31068,8 @@ impl Default for D3D12_VIDEO_PROCESS_OUTPUT_STREAM_ARGUMENTS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_PROCESS_OUTPUT_STREAM_DESC {
     pub Format: super::super::Graphics::Dxgi::Common::DXGI_FORMAT,
     pub ColorSpace: super::super::Graphics::Dxgi::Common::DXGI_COLOR_SPACE_TYPE,
@@ -30540,7 +31091,8 @@ impl Default for D3D12_VIDEO_PROCESS_OUTPUT_STREAM_DESC {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D12")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_PROCESS_REFERENCE_SET {
     pub NumPastFrames: u32,
     pub ppPastFrames: *mut Option<super::super::Graphics::Direct3D12::ID3D12Resource>,
@@ -30560,7 +31112,8 @@ impl Default for D3D12_VIDEO_PROCESS_REFERENCE_SET {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_PROCESS_TRANSFORM {
     pub SourceRectangle: super::super::Foundation::RECT,
     pub DestinationRectangle: super::super::Foundation::RECT,
@@ -30576,7 +31129,8 @@ impl Default for D3D12_VIDEO_PROCESS_TRANSFORM {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_SAMPLE {
     pub Width: u32,
     pub Height: u32,
@@ -30593,7 +31147,8 @@ impl Default for D3D12_VIDEO_SAMPLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_SCALE_SUPPORT {
     pub OutputSizeRange: D3D12_VIDEO_SIZE_RANGE,
     pub Flags: D3D12_VIDEO_SCALE_SUPPORT_FLAGS,
@@ -30607,7 +31162,8 @@ impl Default for D3D12_VIDEO_SCALE_SUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3D12_VIDEO_SIZE_RANGE {
     pub MaxWidth: u32,
     pub MaxHeight: u32,
@@ -30663,7 +31219,8 @@ impl Default for D3DCONTENTPROTECTIONCAPS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct D3DOVERLAYCAPS {
     pub Caps: u32,
     pub MaxOverlayDisplayWidth: u32,
@@ -30678,7 +31235,8 @@ impl Default for D3DOVERLAYCAPS {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DEVICE_INFO {
     pub pFriendlyDeviceName: core::mem::ManuallyDrop<windows_core::BSTR>,
     pub pUniqueDeviceName: core::mem::ManuallyDrop<windows_core::BSTR>,
@@ -30700,7 +31258,8 @@ impl Default for DEVICE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DIRTYRECT_INFO {
     pub FrameNumber: u32,
     pub NumDirtyRects: u32,
@@ -30715,7 +31274,8 @@ impl Default for DIRTYRECT_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA2_AES_CTR_IV {
     pub IV: u64,
     pub Count: u64,
@@ -30729,7 +31289,8 @@ impl Default for DXVA2_AES_CTR_IV {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA2_AYUVSample16 {
     pub Cr: u16,
     pub Cb: u16,
@@ -30745,7 +31306,8 @@ impl Default for DXVA2_AYUVSample16 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA2_AYUVSample8 {
     pub Cr: u8,
     pub Cb: u8,
@@ -30761,7 +31323,8 @@ impl Default for DXVA2_AYUVSample8 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA2_ConfigPictureDecode {
     pub guidConfigBitstreamEncryption: windows_core::GUID,
     pub guidConfigMBcontrolEncryption: windows_core::GUID,
@@ -30790,7 +31353,8 @@ impl Default for DXVA2_ConfigPictureDecode {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA2_DecodeBufferDesc {
     pub CompressedBufferType: u32,
     pub BufferIndex: u32,
@@ -30813,7 +31377,8 @@ impl Default for DXVA2_DecodeBufferDesc {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA2_DecodeExecuteParams {
     pub NumCompBuffers: u32,
     pub pCompressedBuffers: *mut DXVA2_DecodeBufferDesc,
@@ -30828,7 +31393,8 @@ impl Default for DXVA2_DecodeExecuteParams {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA2_DecodeExtensionData {
     pub Function: u32,
     pub pPrivateInputData: *mut core::ffi::c_void,
@@ -30872,10 +31438,55 @@ impl Default for DXVA2_ExtendedFormat_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA2_ExtendedFormat_0_0 {
     pub _bitfield: u32,
 }
+impl DXVA2_ExtendedFormat_0_0 {
+    pub const fn SampleFormat(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_SampleFormat(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn VideoChromaSubsampling(&self) -> u32 {
+        (self._bitfield >> 8) & 15
+    }
+    pub fn set_VideoChromaSubsampling(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 8)) | ((value & 15) << 8);
+    }
+    pub const fn NominalRange(&self) -> u32 {
+        (self._bitfield >> 12) & 7
+    }
+    pub fn set_NominalRange(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 12)) | ((value & 7) << 12);
+    }
+    pub const fn VideoTransferMatrix(&self) -> u32 {
+        (self._bitfield >> 15) & 7
+    }
+    pub fn set_VideoTransferMatrix(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 15)) | ((value & 7) << 15);
+    }
+    pub const fn VideoLighting(&self) -> u32 {
+        (self._bitfield >> 18) & 15
+    }
+    pub fn set_VideoLighting(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 18)) | ((value & 15) << 18);
+    }
+    pub const fn VideoPrimaries(&self) -> u32 {
+        (self._bitfield >> 22) & 31
+    }
+    pub fn set_VideoPrimaries(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 22)) | ((value & 31) << 22);
+    }
+    pub const fn VideoTransferFunction(&self) -> u32 {
+        (self._bitfield >> 27) & 31
+    }
+    pub fn set_VideoTransferFunction(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 27)) | ((value & 31) << 27);
+    }
+}
 impl windows_core::TypeKind for DXVA2_ExtendedFormat_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -30927,7 +31538,8 @@ impl Default for DXVA2_Fixed32_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA2_Fixed32_0_0 {
     pub Fraction: u16,
     pub Value: i16,
@@ -30941,7 +31553,8 @@ impl Default for DXVA2_Fixed32_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA2_Frequency {
     pub Numerator: u32,
     pub Denominator: u32,
@@ -31036,7 +31649,8 @@ impl Default for DXVA2_VideoProcessBltParams {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA2_VideoProcessorCaps {
     pub DeviceCaps: u32,
     pub InputPool: super::super::Graphics::Direct3D9::D3DPOOL,
@@ -31089,7 +31703,8 @@ impl Default for DXVA2_VideoSample {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVABufferInfo {
     pub pCompSurface: *mut core::ffi::c_void,
     pub DataOffset: u32,
@@ -31105,7 +31720,8 @@ impl Default for DXVABufferInfo {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVACompBufferInfo {
     pub NumCompBuffers: u32,
     pub WidthToCreate: u32,
@@ -31126,7 +31742,8 @@ impl Default for DXVACompBufferInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHDETW_CREATEVIDEOPROCESSOR {
     pub pObject: u64,
     pub pD3D9Ex: u64,
@@ -31141,7 +31758,8 @@ impl Default for DXVAHDETW_CREATEVIDEOPROCESSOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHDETW_DESTROYVIDEOPROCESSOR {
     pub pObject: u64,
 }
@@ -31155,7 +31773,8 @@ impl Default for DXVAHDETW_DESTROYVIDEOPROCESSOR {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHDETW_VIDEOPROCESSBLTHD {
     pub pObject: u64,
     pub pOutputSurface: u64,
@@ -31178,7 +31797,8 @@ impl Default for DXVAHDETW_VIDEOPROCESSBLTHD {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHDETW_VIDEOPROCESSBLTHD_STREAM {
     pub pObject: u64,
     pub pInputSurface: u64,
@@ -31204,7 +31824,8 @@ impl Default for DXVAHDETW_VIDEOPROCESSBLTHD_STREAM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHDETW_VIDEOPROCESSBLTSTATE {
     pub pObject: u64,
     pub State: DXVAHD_BLT_STATE,
@@ -31220,7 +31841,8 @@ impl Default for DXVAHDETW_VIDEOPROCESSBLTSTATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHDETW_VIDEOPROCESSSTREAMSTATE {
     pub pObject: u64,
     pub StreamNumber: u32,
@@ -31238,7 +31860,8 @@ impl Default for DXVAHDETW_VIDEOPROCESSSTREAMSTATE {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHDSW_CALLBACKS {
     pub CreateDevice: PDXVAHDSW_CreateDevice,
     pub ProposeVideoPrivateFormat: PDXVAHDSW_ProposeVideoPrivateFormat,
@@ -31268,7 +31891,8 @@ impl Default for DXVAHDSW_CALLBACKS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHD_BLT_STATE_ALPHA_FILL_DATA {
     pub Mode: DXVAHD_ALPHA_FILL_MODE,
     pub StreamNumber: u32,
@@ -31296,7 +31920,8 @@ impl Default for DXVAHD_BLT_STATE_BACKGROUND_COLOR_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHD_BLT_STATE_CONSTRICTION_DATA {
     pub Enable: super::super::Foundation::BOOL,
     pub Size: super::super::Foundation::SIZE,
@@ -31337,10 +31962,43 @@ impl Default for DXVAHD_BLT_STATE_OUTPUT_COLOR_SPACE_DATA_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHD_BLT_STATE_OUTPUT_COLOR_SPACE_DATA_0_0 {
     pub _bitfield: u32,
 }
+impl DXVAHD_BLT_STATE_OUTPUT_COLOR_SPACE_DATA_0_0 {
+    pub const fn Usage(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Usage(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn RGB_Range(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_RGB_Range(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn YCbCr_Matrix(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_YCbCr_Matrix(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn YCbCr_xvYCC(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_YCbCr_xvYCC(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 4) & 268435455
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(268435455 << 4)) | ((value & 268435455) << 4);
+    }
+}
 impl windows_core::TypeKind for DXVAHD_BLT_STATE_OUTPUT_COLOR_SPACE_DATA_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -31350,7 +32008,8 @@ impl Default for DXVAHD_BLT_STATE_OUTPUT_COLOR_SPACE_DATA_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHD_BLT_STATE_PRIVATE_DATA {
     pub Guid: windows_core::GUID,
     pub DataSize: u32,
@@ -31365,7 +32024,8 @@ impl Default for DXVAHD_BLT_STATE_PRIVATE_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHD_BLT_STATE_TARGET_RECT_DATA {
     pub Enable: super::super::Foundation::BOOL,
     pub TargetRect: super::super::Foundation::RECT,
@@ -31393,7 +32053,8 @@ impl Default for DXVAHD_COLOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHD_COLOR_RGBA {
     pub R: f32,
     pub G: f32,
@@ -31409,7 +32070,8 @@ impl Default for DXVAHD_COLOR_RGBA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHD_COLOR_YCbCrA {
     pub Y: f32,
     pub Cb: f32,
@@ -31425,7 +32087,8 @@ impl Default for DXVAHD_COLOR_YCbCrA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHD_CONTENT_DESC {
     pub InputFrameFormat: DXVAHD_FRAME_FORMAT,
     pub InputFrameRate: DXVAHD_RATIONAL,
@@ -31444,7 +32107,8 @@ impl Default for DXVAHD_CONTENT_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHD_CUSTOM_RATE_DATA {
     pub CustomRate: DXVAHD_RATIONAL,
     pub OutputFrames: u32,
@@ -31460,7 +32124,8 @@ impl Default for DXVAHD_CUSTOM_RATE_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHD_FILTER_RANGE_DATA {
     pub Minimum: i32,
     pub Maximum: i32,
@@ -31476,7 +32141,8 @@ impl Default for DXVAHD_FILTER_RANGE_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHD_RATIONAL {
     pub Numerator: u32,
     pub Denominator: u32,
@@ -31491,7 +32157,8 @@ impl Default for DXVAHD_RATIONAL {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHD_STREAM_DATA {
     pub Enable: super::super::Foundation::BOOL,
     pub OutputIndex: u32,
@@ -31519,7 +32186,8 @@ impl Default for DXVAHD_STREAM_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHD_STREAM_STATE_ALPHA_DATA {
     pub Enable: super::super::Foundation::BOOL,
     pub Alpha: f32,
@@ -31533,7 +32201,8 @@ impl Default for DXVAHD_STREAM_STATE_ALPHA_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHD_STREAM_STATE_ASPECT_RATIO_DATA {
     pub Enable: super::super::Foundation::BOOL,
     pub SourceAspectRatio: DXVAHD_RATIONAL,
@@ -31549,7 +32218,8 @@ impl Default for DXVAHD_STREAM_STATE_ASPECT_RATIO_DATA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHD_STREAM_STATE_D3DFORMAT_DATA {
     pub Format: super::super::Graphics::Direct3D9::D3DFORMAT,
 }
@@ -31564,7 +32234,8 @@ impl Default for DXVAHD_STREAM_STATE_D3DFORMAT_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHD_STREAM_STATE_DESTINATION_RECT_DATA {
     pub Enable: super::super::Foundation::BOOL,
     pub DestinationRect: super::super::Foundation::RECT,
@@ -31578,7 +32249,8 @@ impl Default for DXVAHD_STREAM_STATE_DESTINATION_RECT_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHD_STREAM_STATE_FILTER_DATA {
     pub Enable: super::super::Foundation::BOOL,
     pub Level: i32,
@@ -31592,7 +32264,8 @@ impl Default for DXVAHD_STREAM_STATE_FILTER_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHD_STREAM_STATE_FRAME_FORMAT_DATA {
     pub FrameFormat: DXVAHD_FRAME_FORMAT,
 }
@@ -31632,10 +32305,43 @@ impl Default for DXVAHD_STREAM_STATE_INPUT_COLOR_SPACE_DATA_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHD_STREAM_STATE_INPUT_COLOR_SPACE_DATA_0_0 {
     pub _bitfield: u32,
 }
+impl DXVAHD_STREAM_STATE_INPUT_COLOR_SPACE_DATA_0_0 {
+    pub const fn Type(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Type(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn RGB_Range(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_RGB_Range(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn YCbCr_Matrix(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_YCbCr_Matrix(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn YCbCr_xvYCC(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_YCbCr_xvYCC(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 4) & 268435455
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(268435455 << 4)) | ((value & 268435455) << 4);
+    }
+}
 impl windows_core::TypeKind for DXVAHD_STREAM_STATE_INPUT_COLOR_SPACE_DATA_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -31645,7 +32351,8 @@ impl Default for DXVAHD_STREAM_STATE_INPUT_COLOR_SPACE_DATA_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHD_STREAM_STATE_LUMA_KEY_DATA {
     pub Enable: super::super::Foundation::BOOL,
     pub Lower: f32,
@@ -31660,7 +32367,8 @@ impl Default for DXVAHD_STREAM_STATE_LUMA_KEY_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHD_STREAM_STATE_OUTPUT_RATE_DATA {
     pub RepeatFrame: super::super::Foundation::BOOL,
     pub OutputRate: DXVAHD_OUTPUT_RATE,
@@ -31675,7 +32383,8 @@ impl Default for DXVAHD_STREAM_STATE_OUTPUT_RATE_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHD_STREAM_STATE_PALETTE_DATA {
     pub Count: u32,
     pub pEntries: *mut u32,
@@ -31689,7 +32398,8 @@ impl Default for DXVAHD_STREAM_STATE_PALETTE_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHD_STREAM_STATE_PRIVATE_DATA {
     pub Guid: windows_core::GUID,
     pub DataSize: u32,
@@ -31704,7 +32414,8 @@ impl Default for DXVAHD_STREAM_STATE_PRIVATE_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHD_STREAM_STATE_PRIVATE_IVTC_DATA {
     pub Enable: super::super::Foundation::BOOL,
     pub ITelecineFlags: u32,
@@ -31720,7 +32431,8 @@ impl Default for DXVAHD_STREAM_STATE_PRIVATE_IVTC_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHD_STREAM_STATE_SOURCE_RECT_DATA {
     pub Enable: super::super::Foundation::BOOL,
     pub SourceRect: super::super::Foundation::RECT,
@@ -31734,7 +32446,8 @@ impl Default for DXVAHD_STREAM_STATE_SOURCE_RECT_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHD_VPCAPS {
     pub VPGuid: windows_core::GUID,
     pub PastFrames: u32,
@@ -31753,7 +32466,8 @@ impl Default for DXVAHD_VPCAPS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAHD_VPDEVCAPS {
     pub DeviceType: DXVAHD_DEVICE_TYPE,
     pub DeviceCaps: u32,
@@ -31779,7 +32493,8 @@ impl Default for DXVAHD_VPDEVCAPS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVAUncompDataInfo {
     pub UncompWidth: u32,
     pub UncompHeight: u32,
@@ -31796,7 +32511,8 @@ impl Default for DXVAUncompDataInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA_AYUVsample2 {
     pub bCrValue: u8,
     pub bCbValue: u8,
@@ -31834,7 +32550,8 @@ impl Default for DXVA_BufferDescription {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA_COPPCommand {
     pub macKDI: windows_core::GUID,
     pub guidCommandID: windows_core::GUID,
@@ -31851,7 +32568,8 @@ impl Default for DXVA_COPPCommand {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA_COPPSignature {
     pub Signature: [u8; 256],
 }
@@ -31864,7 +32582,8 @@ impl Default for DXVA_COPPSignature {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA_COPPStatusInput {
     pub rApp: windows_core::GUID,
     pub guidStatusRequestID: windows_core::GUID,
@@ -31881,7 +32600,8 @@ impl Default for DXVA_COPPStatusInput {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA_COPPStatusOutput {
     pub macKDI: windows_core::GUID,
     pub cbSizeData: u32,
@@ -31925,7 +32645,8 @@ impl Default for DXVA_ConfigPictureDecode {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA_DeinterlaceBlt {
     pub Size: u32,
     pub Reserved: u32,
@@ -31945,7 +32666,8 @@ impl Default for DXVA_DeinterlaceBlt {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA_DeinterlaceBltEx {
     pub Size: u32,
     pub BackgroundColor: DXVA_AYUVsample2,
@@ -31967,7 +32689,8 @@ impl Default for DXVA_DeinterlaceBltEx {
 }
 #[repr(C)]
 #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec", target_arch = "x86_64"))]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA_DeinterlaceBltEx32 {
     pub Size: u32,
     pub BackgroundColor: DXVA_AYUVsample2,
@@ -31991,7 +32714,8 @@ impl Default for DXVA_DeinterlaceBltEx32 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA_DeinterlaceCaps {
     pub Size: u32,
     pub NumPreviousOutputFrames: u32,
@@ -32013,7 +32737,8 @@ impl Default for DXVA_DeinterlaceCaps {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA_DeinterlaceQueryAvailableModes {
     pub Size: u32,
     pub NumGuids: u32,
@@ -32029,7 +32754,8 @@ impl Default for DXVA_DeinterlaceQueryAvailableModes {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA_DeinterlaceQueryModeCaps {
     pub Size: u32,
     pub Guid: windows_core::GUID,
@@ -32046,10 +32772,55 @@ impl Default for DXVA_DeinterlaceQueryModeCaps {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA_ExtendedFormat {
     pub _bitfield: u32,
 }
+impl DXVA_ExtendedFormat {
+    pub const fn SampleFormat(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_SampleFormat(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn VideoChromaSubsampling(&self) -> u32 {
+        (self._bitfield >> 8) & 15
+    }
+    pub fn set_VideoChromaSubsampling(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 8)) | ((value & 15) << 8);
+    }
+    pub const fn NominalRange(&self) -> u32 {
+        (self._bitfield >> 12) & 7
+    }
+    pub fn set_NominalRange(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 12)) | ((value & 7) << 12);
+    }
+    pub const fn VideoTransferMatrix(&self) -> u32 {
+        (self._bitfield >> 15) & 7
+    }
+    pub fn set_VideoTransferMatrix(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 15)) | ((value & 7) << 15);
+    }
+    pub const fn VideoLighting(&self) -> u32 {
+        (self._bitfield >> 18) & 15
+    }
+    pub fn set_VideoLighting(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 18)) | ((value & 15) << 18);
+    }
+    pub const fn VideoPrimaries(&self) -> u32 {
+        (self._bitfield >> 22) & 31
+    }
+    pub fn set_VideoPrimaries(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 22)) | ((value & 31) << 22);
+    }
+    pub const fn VideoTransferFunction(&self) -> u32 {
+        (self._bitfield >> 27) & 31
+    }
+    pub fn set_VideoTransferFunction(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 27)) | ((value & 31) << 27);
+    }
+}
 impl windows_core::TypeKind for DXVA_ExtendedFormat {
     type TypeKind = windows_core::CopyType;
 }
@@ -32059,7 +32830,8 @@ impl Default for DXVA_ExtendedFormat {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA_Frequency {
     pub Numerator: u32,
     pub Denominator: u32,
@@ -32121,7 +32893,8 @@ impl Default for DXVA_PictureParameters {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA_ProcAmpControlBlt {
     pub Size: u32,
     pub DstRect: super::super::Foundation::RECT,
@@ -32142,7 +32915,8 @@ impl Default for DXVA_ProcAmpControlBlt {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA_ProcAmpControlCaps {
     pub Size: u32,
     pub InputPool: u32,
@@ -32162,7 +32936,8 @@ impl Default for DXVA_ProcAmpControlCaps {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA_ProcAmpControlQueryRange {
     pub Size: u32,
     pub ProcAmpControlProp: DXVA_ProcAmpControlProp,
@@ -32180,7 +32955,8 @@ impl Default for DXVA_ProcAmpControlQueryRange {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Direct3D9")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA_VideoDesc {
     pub Size: u32,
     pub SampleWidth: u32,
@@ -32201,7 +32977,8 @@ impl Default for DXVA_VideoDesc {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA_VideoPropertyRange {
     pub MinValue: f32,
     pub MaxValue: f32,
@@ -32217,7 +32994,8 @@ impl Default for DXVA_VideoPropertyRange {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA_VideoSample {
     pub rtStart: i64,
     pub rtEnd: i64,
@@ -32234,7 +33012,8 @@ impl Default for DXVA_VideoSample {
 }
 #[repr(C)]
 #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec", target_arch = "x86_64"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA_VideoSample2 {
     pub Size: u32,
     pub Reserved: u32,
@@ -32259,7 +33038,8 @@ impl Default for DXVA_VideoSample2 {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA_VideoSample2 {
     pub rtStart: i64,
     pub rtEnd: i64,
@@ -32282,7 +33062,8 @@ impl Default for DXVA_VideoSample2 {
 }
 #[repr(C)]
 #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec", target_arch = "x86_64"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DXVA_VideoSample32 {
     pub rtStart: i64,
     pub rtEnd: i64,
@@ -32304,7 +33085,8 @@ impl Default for DXVA_VideoSample32 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DigitalWindowSetting {
     pub OriginX: f64,
     pub OriginY: f64,
@@ -32320,7 +33102,8 @@ impl Default for DigitalWindowSetting {
 }
 pub const KSPROPSETID_OPMVideoOutput: windows_core::GUID = windows_core::GUID::from_u128(0x06f414bb_f43a_4fe2_a566_774b4c81f0db);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MACROBLOCK_DATA {
     pub flags: u32,
     pub motionVectorX: i16,
@@ -32338,7 +33121,8 @@ impl Default for MACROBLOCK_DATA {
 pub const MFAMRNBByteStreamHandler: windows_core::GUID = windows_core::GUID::from_u128(0xefe6208a_0a2c_49fa_8a01_3768b559b6da);
 pub const MFAMRNBSinkClassFactory: windows_core::GUID = windows_core::GUID::from_u128(0xb0271158_70d2_4c5b_9f94_76f549d90fdf);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFARGB {
     pub rgbBlue: u8,
     pub rgbGreen: u8,
@@ -32354,7 +33138,8 @@ impl Default for MFARGB {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFAYUVSample {
     pub bCrValue: u8,
     pub bCbValue: u8,
@@ -32370,7 +33155,8 @@ impl Default for MFAYUVSample {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFAudioDecoderDegradationInfo {
     pub eDegradationReason: MFT_AUDIO_DECODER_DEGRADATION_REASON,
     pub eType: MFT_AUDIO_DECODER_DEGRADATION_TYPE,
@@ -32384,7 +33170,8 @@ impl Default for MFAudioDecoderDegradationInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFBYTESTREAM_BUFFERING_PARAMS {
     pub cbTotalFileSize: u64,
     pub cbPlayableDataSize: u64,
@@ -32404,7 +33191,8 @@ impl Default for MFBYTESTREAM_BUFFERING_PARAMS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFCLOCK_PROPERTIES {
     pub qwCorrelationRate: u64,
     pub guidClockId: windows_core::GUID,
@@ -32422,7 +33210,8 @@ impl Default for MFCLOCK_PROPERTIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFCONTENTPROTECTIONDEVICE_INPUT_DATA {
     pub HWProtectionFunctionID: u32,
     pub PrivateDataByteCount: u32,
@@ -32439,7 +33228,8 @@ impl Default for MFCONTENTPROTECTIONDEVICE_INPUT_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFCONTENTPROTECTIONDEVICE_OUTPUT_DATA {
     pub PrivateDataByteCount: u32,
     pub MaxHWProtectionDataByteCount: u32,
@@ -32458,7 +33248,8 @@ impl Default for MFCONTENTPROTECTIONDEVICE_OUTPUT_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFCONTENTPROTECTIONDEVICE_REALTIMECLIENT_DATA {
     pub TaskIndex: u32,
     pub ClassName: [u16; 260],
@@ -32473,7 +33264,8 @@ impl Default for MFCONTENTPROTECTIONDEVICE_REALTIMECLIENT_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFCameraExtrinsic_CalibratedTransform {
     pub CalibrationId: windows_core::GUID,
     pub Position: MF_FLOAT3,
@@ -32488,7 +33280,8 @@ impl Default for MFCameraExtrinsic_CalibratedTransform {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFCameraExtrinsics {
     pub TransformCount: u32,
     pub CalibratedTransforms: [MFCameraExtrinsic_CalibratedTransform; 1],
@@ -32502,7 +33295,8 @@ impl Default for MFCameraExtrinsics {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFCameraIntrinsic_CameraModel {
     pub FocalLength_x: f32,
     pub FocalLength_y: f32,
@@ -32518,7 +33312,8 @@ impl Default for MFCameraIntrinsic_CameraModel {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFCameraIntrinsic_DistortionModel {
     pub Radial_k1: f32,
     pub Radial_k2: f32,
@@ -32535,7 +33330,8 @@ impl Default for MFCameraIntrinsic_DistortionModel {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFCameraIntrinsic_DistortionModel6KT {
     pub Radial_k1: f32,
     pub Radial_k2: f32,
@@ -32555,7 +33351,8 @@ impl Default for MFCameraIntrinsic_DistortionModel6KT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFCameraIntrinsic_DistortionModelArcTan {
     pub Radial_k0: f32,
     pub DistortionCenter_x: f32,
@@ -32572,7 +33369,8 @@ impl Default for MFCameraIntrinsic_DistortionModelArcTan {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFCameraIntrinsic_PinholeCameraModel {
     pub FocalLength: MF_FLOAT2,
     pub PrincipalPoint: MF_FLOAT2,
@@ -32586,7 +33384,8 @@ impl Default for MFCameraIntrinsic_PinholeCameraModel {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFExtendedCameraIntrinsic_IntrinsicModel {
     pub Width: u32,
     pub Height: u32,
@@ -32604,7 +33403,8 @@ impl Default for MFExtendedCameraIntrinsic_IntrinsicModel {
 pub const MFFLACBytestreamHandler: windows_core::GUID = windows_core::GUID::from_u128(0x0e41cfb8_0506_40f4_a516_77cc23642d91);
 pub const MFFLACSinkClassFactory: windows_core::GUID = windows_core::GUID::from_u128(0x7d39c56f_6075_47c9_9bae_8cf9e531b5f5);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFFOLDDOWN_MATRIX {
     pub cbSize: u32,
     pub cSrcChannels: u32,
@@ -32621,7 +33421,8 @@ impl Default for MFFOLDDOWN_MATRIX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFINPUTTRUSTAUTHORITY_ACCESS_ACTION {
     pub Action: MFPOLICYMANAGER_ACTION,
     pub pbTicket: *mut u8,
@@ -32636,7 +33437,8 @@ impl Default for MFINPUTTRUSTAUTHORITY_ACCESS_ACTION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFINPUTTRUSTAUTHORITY_ACCESS_PARAMS {
     pub dwSize: u32,
     pub dwVer: u32,
@@ -32656,7 +33458,8 @@ impl Default for MFINPUTTRUSTAUTHORITY_ACCESS_PARAMS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFMPEG2DLNASINKSTATS {
     pub cBytesWritten: u64,
     pub fPAL: super::super::Foundation::BOOL,
@@ -32682,7 +33485,8 @@ impl Default for MFMPEG2DLNASINKSTATS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFMediaKeyStatus {
     pub pbKeyId: *mut u8,
     pub cbKeyId: u32,
@@ -32697,7 +33501,8 @@ impl Default for MFMediaKeyStatus {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFNetCredentialManagerGetParam {
     pub hrOp: windows_core::HRESULT,
     pub fAllowLoggedOnUser: super::super::Foundation::BOOL,
@@ -32717,7 +33522,8 @@ impl Default for MFNetCredentialManagerGetParam {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFOffset {
     pub fract: u16,
     pub value: i16,
@@ -32732,7 +33538,8 @@ impl Default for MFOffset {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFP_ACQUIRE_USER_CREDENTIAL_EVENT {
     pub header: MFP_EVENT_HEADER,
     pub dwUserData: usize,
@@ -32764,7 +33571,8 @@ impl Default for MFP_ACQUIRE_USER_CREDENTIAL_EVENT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFP_ERROR_EVENT {
     pub header: MFP_EVENT_HEADER,
 }
@@ -32786,7 +33594,8 @@ impl Default for MFP_ERROR_EVENT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFP_EVENT_HEADER {
     pub eEventType: MFP_EVENT_TYPE,
     pub hrEvent: windows_core::HRESULT,
@@ -32812,7 +33621,8 @@ impl Default for MFP_EVENT_HEADER {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFP_FRAME_STEP_EVENT {
     pub header: MFP_EVENT_HEADER,
     pub pMediaItem: core::mem::ManuallyDrop<Option<IMFPMediaItem>>,
@@ -32835,7 +33645,8 @@ impl Default for MFP_FRAME_STEP_EVENT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFP_MEDIAITEM_CLEARED_EVENT {
     pub header: MFP_EVENT_HEADER,
     pub pMediaItem: core::mem::ManuallyDrop<Option<IMFPMediaItem>>,
@@ -32858,7 +33669,8 @@ impl Default for MFP_MEDIAITEM_CLEARED_EVENT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFP_MEDIAITEM_CREATED_EVENT {
     pub header: MFP_EVENT_HEADER,
     pub pMediaItem: core::mem::ManuallyDrop<Option<IMFPMediaItem>>,
@@ -32882,7 +33694,8 @@ impl Default for MFP_MEDIAITEM_CREATED_EVENT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFP_MEDIAITEM_SET_EVENT {
     pub header: MFP_EVENT_HEADER,
     pub pMediaItem: core::mem::ManuallyDrop<Option<IMFPMediaItem>>,
@@ -32905,7 +33718,8 @@ impl Default for MFP_MEDIAITEM_SET_EVENT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFP_MF_EVENT {
     pub header: MFP_EVENT_HEADER,
     pub MFEventType: u32,
@@ -32930,7 +33744,8 @@ impl Default for MFP_MF_EVENT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFP_PAUSE_EVENT {
     pub header: MFP_EVENT_HEADER,
     pub pMediaItem: core::mem::ManuallyDrop<Option<IMFPMediaItem>>,
@@ -32953,7 +33768,8 @@ impl Default for MFP_PAUSE_EVENT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFP_PLAYBACK_ENDED_EVENT {
     pub header: MFP_EVENT_HEADER,
     pub pMediaItem: core::mem::ManuallyDrop<Option<IMFPMediaItem>>,
@@ -32976,7 +33792,8 @@ impl Default for MFP_PLAYBACK_ENDED_EVENT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFP_PLAY_EVENT {
     pub header: MFP_EVENT_HEADER,
     pub pMediaItem: core::mem::ManuallyDrop<Option<IMFPMediaItem>>,
@@ -32999,7 +33816,8 @@ impl Default for MFP_PLAY_EVENT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFP_POSITION_SET_EVENT {
     pub header: MFP_EVENT_HEADER,
     pub pMediaItem: core::mem::ManuallyDrop<Option<IMFPMediaItem>>,
@@ -33022,7 +33840,8 @@ impl Default for MFP_POSITION_SET_EVENT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
-#[derive(Debug, PartialEq)]
+#[derive(PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFP_RATE_SET_EVENT {
     pub header: MFP_EVENT_HEADER,
     pub pMediaItem: core::mem::ManuallyDrop<Option<IMFPMediaItem>>,
@@ -33046,7 +33865,8 @@ impl Default for MFP_RATE_SET_EVENT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFP_STOP_EVENT {
     pub header: MFP_EVENT_HEADER,
     pub pMediaItem: core::mem::ManuallyDrop<Option<IMFPMediaItem>>,
@@ -33082,7 +33902,8 @@ impl Default for MFPaletteEntry {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFPinholeCameraIntrinsic_IntrinsicModel {
     pub Width: u32,
     pub Height: u32,
@@ -33098,7 +33919,8 @@ impl Default for MFPinholeCameraIntrinsic_IntrinsicModel {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFPinholeCameraIntrinsics {
     pub IntrinsicModelCount: u32,
     pub IntrinsicModels: [MFPinholeCameraIntrinsic_IntrinsicModel; 1],
@@ -33112,7 +33934,8 @@ impl Default for MFPinholeCameraIntrinsics {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFRR_COMPONENTS {
     pub dwRRInfoVersion: u32,
     pub dwRRComponents: u32,
@@ -33127,7 +33950,8 @@ impl Default for MFRR_COMPONENTS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFRR_COMPONENT_HASH_INFO {
     pub ulReason: u32,
     pub rgHeaderHash: [u16; 43],
@@ -33143,7 +33967,8 @@ impl Default for MFRR_COMPONENT_HASH_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFRatio {
     pub Numerator: u32,
     pub Denominator: u32,
@@ -33188,7 +34013,8 @@ impl Default for MFTOPONODE_ATTRIBUTE_UPDATE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFT_INPUT_STREAM_INFO {
     pub hnsMaxLatency: i64,
     pub dwFlags: u32,
@@ -33205,7 +34031,8 @@ impl Default for MFT_INPUT_STREAM_INFO {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFT_OUTPUT_DATA_BUFFER {
     pub dwStreamID: u32,
     pub pSample: core::mem::ManuallyDrop<Option<IMFSample>>,
@@ -33226,7 +34053,8 @@ impl Default for MFT_OUTPUT_DATA_BUFFER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFT_OUTPUT_STREAM_INFO {
     pub dwFlags: u32,
     pub cbSize: u32,
@@ -33241,7 +34069,8 @@ impl Default for MFT_OUTPUT_STREAM_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFT_REGISTER_TYPE_INFO {
     pub guidMajorType: windows_core::GUID,
     pub guidSubtype: windows_core::GUID,
@@ -33255,7 +34084,8 @@ impl Default for MFT_REGISTER_TYPE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFT_REGISTRATION_INFO {
     pub clsid: windows_core::GUID,
     pub guidCategory: windows_core::GUID,
@@ -33275,7 +34105,8 @@ impl Default for MFT_REGISTRATION_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFT_STREAM_STATE_PARAM {
     pub StreamId: u32,
     pub State: MF_STREAM_STATE,
@@ -33351,7 +34182,8 @@ impl Default for MFVideoAlphaBitmap_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFVideoAlphaBitmapParams {
     pub dwFlags: u32,
     pub clrSrcKey: super::super::Foundation::COLORREF,
@@ -33369,7 +34201,8 @@ impl Default for MFVideoAlphaBitmapParams {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFVideoArea {
     pub OffsetX: MFOffset,
     pub OffsetY: MFOffset,
@@ -33384,7 +34217,8 @@ impl Default for MFVideoArea {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFVideoCompressedInfo {
     pub AvgBitrate: i64,
     pub AvgBitErrorRate: i64,
@@ -33399,7 +34233,8 @@ impl Default for MFVideoCompressedInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFVideoInfo {
     pub dwWidth: u32,
     pub dwHeight: u32,
@@ -33426,7 +34261,8 @@ impl Default for MFVideoInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MFVideoNormalizedRect {
     pub left: f32,
     pub top: f32,
@@ -33457,7 +34293,8 @@ impl Default for MFVideoSurfaceInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MF_BYTE_STREAM_CACHE_RANGE {
     pub qwStartOffset: u64,
     pub qwEndOffset: u64,
@@ -33471,7 +34308,8 @@ impl Default for MF_BYTE_STREAM_CACHE_RANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MF_CAMERA_CONTROL_RANGE_INFO {
     pub minValue: i32,
     pub maxValue: i32,
@@ -33487,7 +34325,8 @@ impl Default for MF_CAMERA_CONTROL_RANGE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MF_FLOAT2 {
     pub x: f32,
     pub y: f32,
@@ -33501,7 +34340,8 @@ impl Default for MF_FLOAT2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MF_FLOAT3 {
     pub x: f32,
     pub y: f32,
@@ -33516,7 +34356,8 @@ impl Default for MF_FLOAT3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MF_LEAKY_BUCKET_PAIR {
     pub dwBitrate: u32,
     pub msBufferWindow: u32,
@@ -33530,7 +34371,8 @@ impl Default for MF_LEAKY_BUCKET_PAIR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MF_QUATERNION {
     pub x: f32,
     pub y: f32,
@@ -33546,7 +34388,8 @@ impl Default for MF_QUATERNION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MF_SINK_WRITER_STATISTICS {
     pub cb: u32,
     pub llLastTimestampReceived: i64,
@@ -33574,7 +34417,8 @@ impl Default for MF_SINK_WRITER_STATISTICS {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MF_TRANSCODE_SINK_INFO {
     pub dwVideoStreamID: u32,
     pub pVideoMediaType: core::mem::ManuallyDrop<Option<IMFMediaType>>,
@@ -33595,7 +34439,8 @@ impl Default for MF_TRANSCODE_SINK_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MF_VIDEO_SPHERICAL_VIEWDIRECTION {
     pub iHeading: i32,
     pub iPitch: i32,
@@ -33610,7 +34455,8 @@ impl Default for MF_VIDEO_SPHERICAL_VIEWDIRECTION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MOVEREGION_INFO {
     pub FrameNumber: u32,
     pub NumMoveRegions: u32,
@@ -33625,7 +34471,8 @@ impl Default for MOVEREGION_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MOVE_RECT {
     pub SourcePoint: super::super::Foundation::POINT,
     pub DestRect: super::super::Foundation::RECT,
@@ -33641,7 +34488,8 @@ impl Default for MOVE_RECT {
 pub const MP3ACMCodecWrapper: windows_core::GUID = windows_core::GUID::from_u128(0x11103421_354c_4cca_a7a3_1aff9a5b6701);
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MPEG1VIDEOINFO {
     pub hdr: VIDEOINFOHEADER,
     pub dwStartTimeCode: u32,
@@ -33683,7 +34531,8 @@ impl Default for MPEG2VIDEOINFO {
 pub const MSAMRNBDecoder: windows_core::GUID = windows_core::GUID::from_u128(0x265011ae_5481_4f77_a295_abb6ffe8d63e);
 pub const MSAMRNBEncoder: windows_core::GUID = windows_core::GUID::from_u128(0x2fae8afe_04a3_423a_a814_85db454712b0);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MT_ARBITRARY_HEADER {
     pub majortype: windows_core::GUID,
     pub subtype: windows_core::GUID,
@@ -33701,7 +34550,8 @@ impl Default for MT_ARBITRARY_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MT_CUSTOM_VIDEO_PRIMARIES {
     pub fRx: f32,
     pub fRy: f32,
@@ -33823,7 +34673,8 @@ impl Default for OPM_COPP_COMPATIBLE_GET_INFO_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OPM_ENCRYPTED_INITIALIZATION_PARAMETERS {
     pub abEncryptedInitializationParameters: [u8; 256],
 }
@@ -33882,7 +34733,8 @@ impl Default for OPM_GET_INFO_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OPM_HDCP_KEY_SELECTION_VECTOR {
     pub abKeySelectionVector: [u8; 5],
 }
@@ -33895,7 +34747,8 @@ impl Default for OPM_HDCP_KEY_SELECTION_VECTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OPM_OMAC {
     pub abOMAC: [u8; 16],
 }
@@ -33923,7 +34776,8 @@ impl Default for OPM_OUTPUT_ID_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OPM_RANDOM_NUMBER {
     pub abRandomNumber: [u8; 16],
 }
@@ -34019,7 +34873,8 @@ impl Default for OPM_STANDARD_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ROI_AREA {
     pub rect: super::super::Foundation::RECT,
     pub QPDelta: i32,
@@ -34033,7 +34888,8 @@ impl Default for ROI_AREA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SENSORPROFILEID {
     pub Type: windows_core::GUID,
     pub Index: u32,
@@ -34048,7 +34904,8 @@ impl Default for SENSORPROFILEID {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STREAM_MEDIUM {
     pub gidMedium: windows_core::GUID,
     pub unMediumInstance: u32,
@@ -34062,7 +34919,8 @@ impl Default for STREAM_MEDIUM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TOC_DESCRIPTOR {
     pub guidID: windows_core::GUID,
     pub wStreamNumber: u16,
@@ -34078,7 +34936,8 @@ impl Default for TOC_DESCRIPTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TOC_ENTRY_DESCRIPTOR {
     pub qwStartTime: u64,
     pub qwEndTime: u64,
@@ -34096,7 +34955,8 @@ impl Default for TOC_ENTRY_DESCRIPTOR {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIDEOINFOHEADER {
     pub rcSource: super::super::Foundation::RECT,
     pub rcTarget: super::super::Foundation::RECT,
@@ -34188,3 +35048,5 @@ pub type PDXVAHDSW_VideoProcessBltHD = Option<unsafe extern "system" fn(hvideopr
 pub type PDXVAHD_CreateDevice = Option<unsafe extern "system" fn(pd3ddevice: Option<super::super::Graphics::Direct3D9::IDirect3DDevice9Ex>, pcontentdesc: *const DXVAHD_CONTENT_DESC, usage: DXVAHD_DEVICE_USAGE, pplugin: PDXVAHDSW_Plugin, ppdevice: *mut Option<IDXVAHD_Device>) -> windows_core::HRESULT>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,10152 @@
+impl windows_core::imp::ProxyVtable for IAdvancedMediaCapture_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAdvancedMediaCaptureSettings(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedMediaCapture", "GetAdvancedMediaCaptureSettings", 3usize, |this| ((**(this as *const *const IAdvancedMediaCapture_Vtbl)).GetAdvancedMediaCaptureSettings)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAdvancedMediaCaptureSettings }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdvancedMediaCapture as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdvancedMediaCaptureInitializationSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDirectxDeviceManager(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedMediaCaptureInitializationSettings", "SetDirectxDeviceManager", 3usize, |this| ((**(this as *const *const IAdvancedMediaCaptureInitializationSettings_Vtbl)).SetDirectxDeviceManager)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetDirectxDeviceManager }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdvancedMediaCaptureInitializationSettings as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAdvancedMediaCaptureSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDirectxDeviceManager(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAdvancedMediaCaptureSettings", "GetDirectxDeviceManager", 3usize, |this| ((**(this as *const *const IAdvancedMediaCaptureSettings_Vtbl)).GetDirectxDeviceManager)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDirectxDeviceManager }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdvancedMediaCaptureSettings as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioSourceProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProvideInput(this: *mut core::ffi::c_void, dwsamplecount: u32, pdwchannelcount: *mut u32, pinterleavedaudiodata: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSourceProvider", "ProvideInput", 3usize, |this| ((**(this as *const *const IAudioSourceProvider_Vtbl)).ProvideInput)(this, dwsamplecount, pdwchannelcount, pinterleavedaudiodata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ProvideInput }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioSourceProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClusterDetector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, wbaseentrylevel: u16, wclusterentrylevel: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClusterDetector", "Initialize", 3usize, |this| ((**(this as *const *const IClusterDetector_Vtbl)).Initialize)(this, wbaseentrylevel, wclusterentrylevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn Detect(this: *mut core::ffi::c_void, dwmaxnumclusters: u32, fminclusterduration: f32, fmaxclusterduration: f32, psrctoc: *mut core::ffi::c_void, ppdsttoc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClusterDetector", "Detect", 4usize, |this| ((**(this as *const *const IClusterDetector_Vtbl)).Detect)(this, dwmaxnumclusters, fminclusterduration, fmaxclusterduration, psrctoc, ppdsttoc), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, Detect }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClusterDetector as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICodecAPI_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSupported(this: *mut core::ffi::c_void, api: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecAPI", "IsSupported", 3usize, |this| ((**(this as *const *const ICodecAPI_Vtbl)).IsSupported)(this, api), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsModifiable(this: *mut core::ffi::c_void, api: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecAPI", "IsModifiable", 4usize, |this| ((**(this as *const *const ICodecAPI_Vtbl)).IsModifiable)(this, api), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParameterRange(this: *mut core::ffi::c_void, api: *const windows_core::GUID, valuemin: *mut core::mem::MaybeUninit<windows_core::VARIANT>, valuemax: *mut core::mem::MaybeUninit<windows_core::VARIANT>, steppingdelta: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecAPI", "GetParameterRange", 5usize, |this| ((**(this as *const *const ICodecAPI_Vtbl)).GetParameterRange)(this, api, valuemin, valuemax, steppingdelta), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParameterValues(this: *mut core::ffi::c_void, api: *const windows_core::GUID, values: *mut *mut windows_core::VARIANT, valuescount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecAPI", "GetParameterValues", 6usize, |this| ((**(this as *const *const ICodecAPI_Vtbl)).GetParameterValues)(this, api, values, valuescount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultValue(this: *mut core::ffi::c_void, api: *const windows_core::GUID, value: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecAPI", "GetDefaultValue", 7usize, |this| ((**(this as *const *const ICodecAPI_Vtbl)).GetDefaultValue)(this, api, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, api: *const windows_core::GUID, value: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecAPI", "GetValue", 8usize, |this| ((**(this as *const *const ICodecAPI_Vtbl)).GetValue)(this, api, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, api: *const windows_core::GUID, value: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecAPI", "SetValue", 9usize, |this| ((**(this as *const *const ICodecAPI_Vtbl)).SetValue)(this, api, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterForEvent(this: *mut core::ffi::c_void, api: *const windows_core::GUID, userdata: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecAPI", "RegisterForEvent", 10usize, |this| ((**(this as *const *const ICodecAPI_Vtbl)).RegisterForEvent)(this, api, userdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterForEvent(this: *mut core::ffi::c_void, api: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecAPI", "UnregisterForEvent", 11usize, |this| ((**(this as *const *const ICodecAPI_Vtbl)).UnregisterForEvent)(this, api), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllDefaults(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecAPI", "SetAllDefaults", 12usize, |this| ((**(this as *const *const ICodecAPI_Vtbl)).SetAllDefaults)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValueWithNotify(this: *mut core::ffi::c_void, api: *const windows_core::GUID, value: *const core::mem::MaybeUninit<windows_core::VARIANT>, changedparam: *mut *mut windows_core::GUID, changedparamcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecAPI", "SetValueWithNotify", 13usize, |this| ((**(this as *const *const ICodecAPI_Vtbl)).SetValueWithNotify)(this, api, value, changedparam, changedparamcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllDefaultsWithNotify(this: *mut core::ffi::c_void, changedparam: *mut *mut windows_core::GUID, changedparamcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecAPI", "SetAllDefaultsWithNotify", 14usize, |this| ((**(this as *const *const ICodecAPI_Vtbl)).SetAllDefaultsWithNotify)(this, changedparam, changedparamcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetAllSettings(this: *mut core::ffi::c_void, __midl__icodecapi0000: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecAPI", "GetAllSettings", 15usize, |this| ((**(this as *const *const ICodecAPI_Vtbl)).GetAllSettings)(this, __midl__icodecapi0000), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetAllSettings(this: *mut core::ffi::c_void, __midl__icodecapi0001: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecAPI", "SetAllSettings", 16usize, |this| ((**(this as *const *const ICodecAPI_Vtbl)).SetAllSettings)(this, __midl__icodecapi0001), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetAllSettingsWithNotify(this: *mut core::ffi::c_void, __midl__icodecapi0002: *mut core::ffi::c_void, changedparam: *mut *mut windows_core::GUID, changedparamcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodecAPI", "SetAllSettingsWithNotify", 17usize, |this| ((**(this as *const *const ICodecAPI_Vtbl)).SetAllSettingsWithNotify)(this, __midl__icodecapi0002, changedparam, changedparamcount), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsSupported,
+            IsModifiable,
+            GetParameterRange,
+            GetParameterValues,
+            GetDefaultValue,
+            GetValue,
+            SetValue,
+            RegisterForEvent,
+            UnregisterForEvent,
+            SetAllDefaults,
+            SetValueWithNotify,
+            SetAllDefaultsWithNotify,
+            #[cfg(feature = "Win32_System_Com")]
+            GetAllSettings,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetAllSettings: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetAllSettings,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetAllSettings: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetAllSettingsWithNotify,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetAllSettingsWithNotify: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICodecAPI as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D12VideoDecodeCommandList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDecodeCommandList", "Close", 9usize, |this| ((**(this as *const *const ID3D12VideoDecodeCommandList_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void, pallocator: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDecodeCommandList", "Reset", 10usize, |this| ((**(this as *const *const ID3D12VideoDecodeCommandList_Vtbl)).Reset)(this, pallocator), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearState(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDecodeCommandList", "ClearState", 11usize, |this| ((**(this as *const *const ID3D12VideoDecodeCommandList_Vtbl)).ClearState)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn ResourceBarrier(this: *mut core::ffi::c_void, numbarriers: u32, pbarriers: *const super::super::Graphics::Direct3D12::D3D12_RESOURCE_BARRIER) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDecodeCommandList", "ResourceBarrier", 12usize, |this| ((**(this as *const *const ID3D12VideoDecodeCommandList_Vtbl)).ResourceBarrier)(this, numbarriers, pbarriers), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn DiscardResource(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pregion: *const super::super::Graphics::Direct3D12::D3D12_DISCARD_REGION) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDecodeCommandList", "DiscardResource", 13usize, |this| ((**(this as *const *const ID3D12VideoDecodeCommandList_Vtbl)).DiscardResource)(this, presource, pregion), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn BeginQuery(this: *mut core::ffi::c_void, pqueryheap: *mut core::ffi::c_void, r#type: super::super::Graphics::Direct3D12::D3D12_QUERY_TYPE, index: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDecodeCommandList", "BeginQuery", 14usize, |this| ((**(this as *const *const ID3D12VideoDecodeCommandList_Vtbl)).BeginQuery)(this, pqueryheap, r#type, index), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn EndQuery(this: *mut core::ffi::c_void, pqueryheap: *mut core::ffi::c_void, r#type: super::super::Graphics::Direct3D12::D3D12_QUERY_TYPE, index: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDecodeCommandList", "EndQuery", 15usize, |this| ((**(this as *const *const ID3D12VideoDecodeCommandList_Vtbl)).EndQuery)(this, pqueryheap, r#type, index), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn ResolveQueryData(this: *mut core::ffi::c_void, pqueryheap: *mut core::ffi::c_void, r#type: super::super::Graphics::Direct3D12::D3D12_QUERY_TYPE, startindex: u32, numqueries: u32, pdestinationbuffer: *mut core::ffi::c_void, aligneddestinationbufferoffset: u64) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDecodeCommandList", "ResolveQueryData", 16usize, |this| ((**(this as *const *const ID3D12VideoDecodeCommandList_Vtbl)).ResolveQueryData)(this, pqueryheap, r#type, startindex, numqueries, pdestinationbuffer, aligneddestinationbufferoffset), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn SetPredication(this: *mut core::ffi::c_void, pbuffer: *mut core::ffi::c_void, alignedbufferoffset: u64, operation: super::super::Graphics::Direct3D12::D3D12_PREDICATION_OP) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDecodeCommandList", "SetPredication", 17usize, |this| ((**(this as *const *const ID3D12VideoDecodeCommandList_Vtbl)).SetPredication)(this, pbuffer, alignedbufferoffset, operation), |_| None)
+        }
+        unsafe extern "system" fn SetMarker(this: *mut core::ffi::c_void, metadata: u32, pdata: *const core::ffi::c_void, size: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDecodeCommandList", "SetMarker", 18usize, |this| ((**(this as *const *const ID3D12VideoDecodeCommandList_Vtbl)).SetMarker)(this, metadata, pdata, size), |_| None)
+        }
+        unsafe extern "system" fn BeginEvent(this: *mut core::ffi::c_void, metadata: u32, pdata: *const core::ffi::c_void, size: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDecodeCommandList", "BeginEvent", 19usize, |this| ((**(this as *const *const ID3D12VideoDecodeCommandList_Vtbl)).BeginEvent)(this, metadata, pdata, size), |_| None)
+        }
+        unsafe extern "system" fn EndEvent(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDecodeCommandList", "EndEvent", 20usize, |this| ((**(this as *const *const ID3D12VideoDecodeCommandList_Vtbl)).EndEvent)(this), |_| None)
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn DecodeFrame(this: *mut core::ffi::c_void, pdecoder: *mut core::ffi::c_void, poutputarguments: *const D3D12_VIDEO_DECODE_OUTPUT_STREAM_ARGUMENTS, pinputarguments: *const D3D12_VIDEO_DECODE_INPUT_STREAM_ARGUMENTS) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDecodeCommandList", "DecodeFrame", 21usize, |this| ((**(this as *const *const ID3D12VideoDecodeCommandList_Vtbl)).DecodeFrame)(this, pdecoder, poutputarguments, pinputarguments), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn WriteBufferImmediate(this: *mut core::ffi::c_void, count: u32, pparams: *const super::super::Graphics::Direct3D12::D3D12_WRITEBUFFERIMMEDIATE_PARAMETER, pmodes: *const super::super::Graphics::Direct3D12::D3D12_WRITEBUFFERIMMEDIATE_MODE) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDecodeCommandList", "WriteBufferImmediate", 22usize, |this| ((**(this as *const *const ID3D12VideoDecodeCommandList_Vtbl)).WriteBufferImmediate)(this, count, pparams, pmodes), |_| None)
+        }
+        Self {
+            base__: <super::super::Graphics::Direct3D12::ID3D12CommandList_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Close,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            Reset,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            Reset: 0,
+            ClearState,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            ResourceBarrier,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            ResourceBarrier: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            DiscardResource,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            DiscardResource: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            BeginQuery,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            BeginQuery: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            EndQuery,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            EndQuery: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            ResolveQueryData,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            ResolveQueryData: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            SetPredication,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            SetPredication: 0,
+            SetMarker,
+            BeginEvent,
+            EndEvent,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+            DecodeFrame,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common")))]
+            DecodeFrame: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            WriteBufferImmediate,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            WriteBufferImmediate: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VideoDecodeCommandList as windows_core::Interface>::IID || <super::super::Graphics::Direct3D12::ID3D12CommandList_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D12VideoDecodeCommandList1_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn DecodeFrame1(this: *mut core::ffi::c_void, pdecoder: *mut core::ffi::c_void, poutputarguments: *const D3D12_VIDEO_DECODE_OUTPUT_STREAM_ARGUMENTS1, pinputarguments: *const D3D12_VIDEO_DECODE_INPUT_STREAM_ARGUMENTS) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDecodeCommandList1", "DecodeFrame1", 23usize, |this| ((**(this as *const *const ID3D12VideoDecodeCommandList1_Vtbl)).DecodeFrame1)(this, pdecoder, poutputarguments, pinputarguments), |_| None)
+        }
+        Self {
+            base__: <ID3D12VideoDecodeCommandList_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+            DecodeFrame1,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common")))]
+            DecodeFrame1: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VideoDecodeCommandList1 as windows_core::Interface>::IID || <ID3D12VideoDecodeCommandList_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D12VideoDecodeCommandList2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn SetProtectedResourceSession(this: *mut core::ffi::c_void, pprotectedresourcesession: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDecodeCommandList2", "SetProtectedResourceSession", 24usize, |this| ((**(this as *const *const ID3D12VideoDecodeCommandList2_Vtbl)).SetProtectedResourceSession)(this, pprotectedresourcesession), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn InitializeExtensionCommand(this: *mut core::ffi::c_void, pextensioncommand: *mut core::ffi::c_void, pinitializationparameters: *const core::ffi::c_void, initializationparameterssizeinbytes: usize) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDecodeCommandList2", "InitializeExtensionCommand", 25usize, |this| ((**(this as *const *const ID3D12VideoDecodeCommandList2_Vtbl)).InitializeExtensionCommand)(this, pextensioncommand, pinitializationparameters, initializationparameterssizeinbytes), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn ExecuteExtensionCommand(this: *mut core::ffi::c_void, pextensioncommand: *mut core::ffi::c_void, pexecutionparameters: *const core::ffi::c_void, executionparameterssizeinbytes: usize) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDecodeCommandList2", "ExecuteExtensionCommand", 26usize, |this| ((**(this as *const *const ID3D12VideoDecodeCommandList2_Vtbl)).ExecuteExtensionCommand)(this, pextensioncommand, pexecutionparameters, executionparameterssizeinbytes), |_| None)
+        }
+        Self {
+            base__: <ID3D12VideoDecodeCommandList1_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            SetProtectedResourceSession,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            SetProtectedResourceSession: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            InitializeExtensionCommand,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            InitializeExtensionCommand: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            ExecuteExtensionCommand,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            ExecuteExtensionCommand: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VideoDecodeCommandList2 as windows_core::Interface>::IID || <ID3D12VideoDecodeCommandList1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D12VideoDecodeCommandList3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn Barrier(this: *mut core::ffi::c_void, numbarriergroups: u32, pbarriergroups: *const super::super::Graphics::Direct3D12::D3D12_BARRIER_GROUP) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDecodeCommandList3", "Barrier", 27usize, |this| ((**(this as *const *const ID3D12VideoDecodeCommandList3_Vtbl)).Barrier)(this, numbarriergroups, pbarriergroups), |_| None)
+        }
+        Self {
+            base__: <ID3D12VideoDecodeCommandList2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            Barrier,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            Barrier: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VideoDecodeCommandList3 as windows_core::Interface>::IID || <ID3D12VideoDecodeCommandList2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D12")]
+impl windows_core::imp::ProxyVtable for ID3D12VideoDecoder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, result__: *mut D3D12_VIDEO_DECODER_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDecoder", "GetDesc", 8usize, |this| ((**(this as *const *const ID3D12VideoDecoder_Vtbl)).GetDesc)(this, result__), |_| None)
+        }
+        Self { base__: <super::super::Graphics::Direct3D12::ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VideoDecoder as windows_core::Interface>::IID || <super::super::Graphics::Direct3D12::ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D12")]
+impl windows_core::imp::ProxyVtable for ID3D12VideoDecoder1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProtectedResourceSession(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppprotectedsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDecoder1", "GetProtectedResourceSession", 9usize, |this| ((**(this as *const *const ID3D12VideoDecoder1_Vtbl)).GetProtectedResourceSession)(this, riid, ppprotectedsession), |result| Some(*result))
+        }
+        Self { base__: <ID3D12VideoDecoder_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProtectedResourceSession }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VideoDecoder1 as windows_core::Interface>::IID || <ID3D12VideoDecoder_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D12VideoDecoderHeap_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, result__: *mut D3D12_VIDEO_DECODER_HEAP_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDecoderHeap", "GetDesc", 8usize, |this| ((**(this as *const *const ID3D12VideoDecoderHeap_Vtbl)).GetDesc)(this, result__), |_| None)
+        }
+        Self {
+            base__: <super::super::Graphics::Direct3D12::ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDesc,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDesc: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VideoDecoderHeap as windows_core::Interface>::IID || <super::super::Graphics::Direct3D12::ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D12VideoDecoderHeap1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProtectedResourceSession(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppprotectedsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDecoderHeap1", "GetProtectedResourceSession", 9usize, |this| ((**(this as *const *const ID3D12VideoDecoderHeap1_Vtbl)).GetProtectedResourceSession)(this, riid, ppprotectedsession), |result| Some(*result))
+        }
+        Self { base__: <ID3D12VideoDecoderHeap_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProtectedResourceSession }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VideoDecoderHeap1 as windows_core::Interface>::IID || <ID3D12VideoDecoderHeap_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ID3D12VideoDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CheckFeatureSupport(this: *mut core::ffi::c_void, featurevideo: D3D12_FEATURE_VIDEO, pfeaturesupportdata: *mut core::ffi::c_void, featuresupportdatasize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDevice", "CheckFeatureSupport", 3usize, |this| ((**(this as *const *const ID3D12VideoDevice_Vtbl)).CheckFeatureSupport)(this, featurevideo, pfeaturesupportdata, featuresupportdatasize), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVideoDecoder(this: *mut core::ffi::c_void, pdesc: *const D3D12_VIDEO_DECODER_DESC, riid: *const windows_core::GUID, ppvideodecoder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDevice", "CreateVideoDecoder", 4usize, |this| ((**(this as *const *const ID3D12VideoDevice_Vtbl)).CreateVideoDecoder)(this, pdesc, riid, ppvideodecoder), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateVideoDecoderHeap(this: *mut core::ffi::c_void, pvideodecoderheapdesc: *const D3D12_VIDEO_DECODER_HEAP_DESC, riid: *const windows_core::GUID, ppvideodecoderheap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDevice", "CreateVideoDecoderHeap", 5usize, |this| ((**(this as *const *const ID3D12VideoDevice_Vtbl)).CreateVideoDecoderHeap)(this, pvideodecoderheapdesc, riid, ppvideodecoderheap), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateVideoProcessor(this: *mut core::ffi::c_void, nodemask: u32, poutputstreamdesc: *const D3D12_VIDEO_PROCESS_OUTPUT_STREAM_DESC, numinputstreamdescs: u32, pinputstreamdescs: *const D3D12_VIDEO_PROCESS_INPUT_STREAM_DESC, riid: *const windows_core::GUID, ppvideoprocessor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDevice", "CreateVideoProcessor", 6usize, |this| ((**(this as *const *const ID3D12VideoDevice_Vtbl)).CreateVideoProcessor)(this, nodemask, poutputstreamdesc, numinputstreamdescs, pinputstreamdescs, riid, ppvideoprocessor), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CheckFeatureSupport,
+            CreateVideoDecoder,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateVideoDecoderHeap,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateVideoDecoderHeap: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateVideoProcessor,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateVideoProcessor: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VideoDevice as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D12VideoDevice1_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn CreateVideoMotionEstimator(this: *mut core::ffi::c_void, pdesc: *const D3D12_VIDEO_MOTION_ESTIMATOR_DESC, pprotectedresourcesession: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvideomotionestimator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDevice1", "CreateVideoMotionEstimator", 7usize, |this| ((**(this as *const *const ID3D12VideoDevice1_Vtbl)).CreateVideoMotionEstimator)(this, pdesc, pprotectedresourcesession, riid, ppvideomotionestimator), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn CreateVideoMotionVectorHeap(this: *mut core::ffi::c_void, pdesc: *const D3D12_VIDEO_MOTION_VECTOR_HEAP_DESC, pprotectedresourcesession: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvideomotionvectorheap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDevice1", "CreateVideoMotionVectorHeap", 8usize, |this| ((**(this as *const *const ID3D12VideoDevice1_Vtbl)).CreateVideoMotionVectorHeap)(this, pdesc, pprotectedresourcesession, riid, ppvideomotionvectorheap), |result| Some(*result))
+        }
+        Self {
+            base__: <ID3D12VideoDevice_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateVideoMotionEstimator,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common")))]
+            CreateVideoMotionEstimator: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateVideoMotionVectorHeap,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common")))]
+            CreateVideoMotionVectorHeap: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VideoDevice1 as windows_core::Interface>::IID || <ID3D12VideoDevice_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D12VideoDevice2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn CreateVideoDecoder1(this: *mut core::ffi::c_void, pdesc: *const D3D12_VIDEO_DECODER_DESC, pprotectedresourcesession: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvideodecoder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDevice2", "CreateVideoDecoder1", 9usize, |this| ((**(this as *const *const ID3D12VideoDevice2_Vtbl)).CreateVideoDecoder1)(this, pdesc, pprotectedresourcesession, riid, ppvideodecoder), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn CreateVideoDecoderHeap1(this: *mut core::ffi::c_void, pvideodecoderheapdesc: *const D3D12_VIDEO_DECODER_HEAP_DESC, pprotectedresourcesession: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvideodecoderheap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDevice2", "CreateVideoDecoderHeap1", 10usize, |this| ((**(this as *const *const ID3D12VideoDevice2_Vtbl)).CreateVideoDecoderHeap1)(this, pvideodecoderheapdesc, pprotectedresourcesession, riid, ppvideodecoderheap), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn CreateVideoProcessor1(this: *mut core::ffi::c_void, nodemask: u32, poutputstreamdesc: *const D3D12_VIDEO_PROCESS_OUTPUT_STREAM_DESC, numinputstreamdescs: u32, pinputstreamdescs: *const D3D12_VIDEO_PROCESS_INPUT_STREAM_DESC, pprotectedresourcesession: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvideoprocessor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDevice2", "CreateVideoProcessor1", 11usize, |this| ((**(this as *const *const ID3D12VideoDevice2_Vtbl)).CreateVideoProcessor1)(this, nodemask, poutputstreamdesc, numinputstreamdescs, pinputstreamdescs, pprotectedresourcesession, riid, ppvideoprocessor), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn CreateVideoExtensionCommand(this: *mut core::ffi::c_void, pdesc: *const D3D12_VIDEO_EXTENSION_COMMAND_DESC, pcreationparameters: *const core::ffi::c_void, creationparametersdatasizeinbytes: usize, pprotectedresourcesession: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvideoextensioncommand: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDevice2", "CreateVideoExtensionCommand", 12usize, |this| ((**(this as *const *const ID3D12VideoDevice2_Vtbl)).CreateVideoExtensionCommand)(this, pdesc, pcreationparameters, creationparametersdatasizeinbytes, pprotectedresourcesession, riid, ppvideoextensioncommand), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn ExecuteExtensionCommand(this: *mut core::ffi::c_void, pextensioncommand: *mut core::ffi::c_void, pexecutionparameters: *const core::ffi::c_void, executionparameterssizeinbytes: usize, poutputdata: *mut core::ffi::c_void, outputdatasizeinbytes: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDevice2", "ExecuteExtensionCommand", 13usize, |this| ((**(this as *const *const ID3D12VideoDevice2_Vtbl)).ExecuteExtensionCommand)(this, pextensioncommand, pexecutionparameters, executionparameterssizeinbytes, poutputdata, outputdatasizeinbytes), |result| Some(*result))
+        }
+        Self {
+            base__: <ID3D12VideoDevice1_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            CreateVideoDecoder1,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            CreateVideoDecoder1: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateVideoDecoderHeap1,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common")))]
+            CreateVideoDecoderHeap1: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateVideoProcessor1,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common")))]
+            CreateVideoProcessor1: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            CreateVideoExtensionCommand,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            CreateVideoExtensionCommand: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            ExecuteExtensionCommand,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            ExecuteExtensionCommand: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VideoDevice2 as windows_core::Interface>::IID || <ID3D12VideoDevice1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D12VideoDevice3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn CreateVideoEncoder(this: *mut core::ffi::c_void, pdesc: *const D3D12_VIDEO_ENCODER_DESC, riid: *const windows_core::GUID, ppvideoencoder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDevice3", "CreateVideoEncoder", 14usize, |this| ((**(this as *const *const ID3D12VideoDevice3_Vtbl)).CreateVideoEncoder)(this, pdesc, riid, ppvideoencoder), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVideoEncoderHeap(this: *mut core::ffi::c_void, pdesc: *const D3D12_VIDEO_ENCODER_HEAP_DESC, riid: *const windows_core::GUID, ppvideoencoderheap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoDevice3", "CreateVideoEncoderHeap", 15usize, |this| ((**(this as *const *const ID3D12VideoDevice3_Vtbl)).CreateVideoEncoderHeap)(this, pdesc, riid, ppvideoencoderheap), |result| Some(*result))
+        }
+        Self {
+            base__: <ID3D12VideoDevice2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            CreateVideoEncoder,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateVideoEncoder: 0,
+            CreateVideoEncoderHeap,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VideoDevice3 as windows_core::Interface>::IID || <ID3D12VideoDevice2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D12")]
+impl windows_core::imp::ProxyVtable for ID3D12VideoEncodeCommandList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncodeCommandList", "Close", 9usize, |this| ((**(this as *const *const ID3D12VideoEncodeCommandList_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void, pallocator: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncodeCommandList", "Reset", 10usize, |this| ((**(this as *const *const ID3D12VideoEncodeCommandList_Vtbl)).Reset)(this, pallocator), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearState(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncodeCommandList", "ClearState", 11usize, |this| ((**(this as *const *const ID3D12VideoEncodeCommandList_Vtbl)).ClearState)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn ResourceBarrier(this: *mut core::ffi::c_void, numbarriers: u32, pbarriers: *const super::super::Graphics::Direct3D12::D3D12_RESOURCE_BARRIER) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncodeCommandList", "ResourceBarrier", 12usize, |this| ((**(this as *const *const ID3D12VideoEncodeCommandList_Vtbl)).ResourceBarrier)(this, numbarriers, pbarriers), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn DiscardResource(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pregion: *const super::super::Graphics::Direct3D12::D3D12_DISCARD_REGION) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncodeCommandList", "DiscardResource", 13usize, |this| ((**(this as *const *const ID3D12VideoEncodeCommandList_Vtbl)).DiscardResource)(this, presource, pregion), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn BeginQuery(this: *mut core::ffi::c_void, pqueryheap: *mut core::ffi::c_void, r#type: super::super::Graphics::Direct3D12::D3D12_QUERY_TYPE, index: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncodeCommandList", "BeginQuery", 14usize, |this| ((**(this as *const *const ID3D12VideoEncodeCommandList_Vtbl)).BeginQuery)(this, pqueryheap, r#type, index), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn EndQuery(this: *mut core::ffi::c_void, pqueryheap: *mut core::ffi::c_void, r#type: super::super::Graphics::Direct3D12::D3D12_QUERY_TYPE, index: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncodeCommandList", "EndQuery", 15usize, |this| ((**(this as *const *const ID3D12VideoEncodeCommandList_Vtbl)).EndQuery)(this, pqueryheap, r#type, index), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn ResolveQueryData(this: *mut core::ffi::c_void, pqueryheap: *mut core::ffi::c_void, r#type: super::super::Graphics::Direct3D12::D3D12_QUERY_TYPE, startindex: u32, numqueries: u32, pdestinationbuffer: *mut core::ffi::c_void, aligneddestinationbufferoffset: u64) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncodeCommandList", "ResolveQueryData", 16usize, |this| ((**(this as *const *const ID3D12VideoEncodeCommandList_Vtbl)).ResolveQueryData)(this, pqueryheap, r#type, startindex, numqueries, pdestinationbuffer, aligneddestinationbufferoffset), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn SetPredication(this: *mut core::ffi::c_void, pbuffer: *mut core::ffi::c_void, alignedbufferoffset: u64, operation: super::super::Graphics::Direct3D12::D3D12_PREDICATION_OP) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncodeCommandList", "SetPredication", 17usize, |this| ((**(this as *const *const ID3D12VideoEncodeCommandList_Vtbl)).SetPredication)(this, pbuffer, alignedbufferoffset, operation), |_| None)
+        }
+        unsafe extern "system" fn SetMarker(this: *mut core::ffi::c_void, metadata: u32, pdata: *const core::ffi::c_void, size: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncodeCommandList", "SetMarker", 18usize, |this| ((**(this as *const *const ID3D12VideoEncodeCommandList_Vtbl)).SetMarker)(this, metadata, pdata, size), |_| None)
+        }
+        unsafe extern "system" fn BeginEvent(this: *mut core::ffi::c_void, metadata: u32, pdata: *const core::ffi::c_void, size: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncodeCommandList", "BeginEvent", 19usize, |this| ((**(this as *const *const ID3D12VideoEncodeCommandList_Vtbl)).BeginEvent)(this, metadata, pdata, size), |_| None)
+        }
+        unsafe extern "system" fn EndEvent(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncodeCommandList", "EndEvent", 20usize, |this| ((**(this as *const *const ID3D12VideoEncodeCommandList_Vtbl)).EndEvent)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn EstimateMotion(this: *mut core::ffi::c_void, pmotionestimator: *mut core::ffi::c_void, poutputarguments: *const D3D12_VIDEO_MOTION_ESTIMATOR_OUTPUT, pinputarguments: *const D3D12_VIDEO_MOTION_ESTIMATOR_INPUT) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncodeCommandList", "EstimateMotion", 21usize, |this| ((**(this as *const *const ID3D12VideoEncodeCommandList_Vtbl)).EstimateMotion)(this, pmotionestimator, poutputarguments, pinputarguments), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn ResolveMotionVectorHeap(this: *mut core::ffi::c_void, poutputarguments: *const D3D12_RESOLVE_VIDEO_MOTION_VECTOR_HEAP_OUTPUT, pinputarguments: *const D3D12_RESOLVE_VIDEO_MOTION_VECTOR_HEAP_INPUT) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncodeCommandList", "ResolveMotionVectorHeap", 22usize, |this| ((**(this as *const *const ID3D12VideoEncodeCommandList_Vtbl)).ResolveMotionVectorHeap)(this, poutputarguments, pinputarguments), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn WriteBufferImmediate(this: *mut core::ffi::c_void, count: u32, pparams: *const super::super::Graphics::Direct3D12::D3D12_WRITEBUFFERIMMEDIATE_PARAMETER, pmodes: *const super::super::Graphics::Direct3D12::D3D12_WRITEBUFFERIMMEDIATE_MODE) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncodeCommandList", "WriteBufferImmediate", 23usize, |this| ((**(this as *const *const ID3D12VideoEncodeCommandList_Vtbl)).WriteBufferImmediate)(this, count, pparams, pmodes), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn SetProtectedResourceSession(this: *mut core::ffi::c_void, pprotectedresourcesession: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncodeCommandList", "SetProtectedResourceSession", 24usize, |this| ((**(this as *const *const ID3D12VideoEncodeCommandList_Vtbl)).SetProtectedResourceSession)(this, pprotectedresourcesession), |_| None)
+        }
+        Self {
+            base__: <super::super::Graphics::Direct3D12::ID3D12CommandList_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Close,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            Reset,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            Reset: 0,
+            ClearState,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            ResourceBarrier,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            ResourceBarrier: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            DiscardResource,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            DiscardResource: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            BeginQuery,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            BeginQuery: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            EndQuery,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            EndQuery: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            ResolveQueryData,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            ResolveQueryData: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            SetPredication,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            SetPredication: 0,
+            SetMarker,
+            BeginEvent,
+            EndEvent,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            EstimateMotion,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            EstimateMotion: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            ResolveMotionVectorHeap,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            ResolveMotionVectorHeap: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            WriteBufferImmediate,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            WriteBufferImmediate: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            SetProtectedResourceSession,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            SetProtectedResourceSession: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VideoEncodeCommandList as windows_core::Interface>::IID || <super::super::Graphics::Direct3D12::ID3D12CommandList_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D12")]
+impl windows_core::imp::ProxyVtable for ID3D12VideoEncodeCommandList1_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn InitializeExtensionCommand(this: *mut core::ffi::c_void, pextensioncommand: *mut core::ffi::c_void, pinitializationparameters: *const core::ffi::c_void, initializationparameterssizeinbytes: usize) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncodeCommandList1", "InitializeExtensionCommand", 25usize, |this| ((**(this as *const *const ID3D12VideoEncodeCommandList1_Vtbl)).InitializeExtensionCommand)(this, pextensioncommand, pinitializationparameters, initializationparameterssizeinbytes), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn ExecuteExtensionCommand(this: *mut core::ffi::c_void, pextensioncommand: *mut core::ffi::c_void, pexecutionparameters: *const core::ffi::c_void, executionparameterssizeinbytes: usize) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncodeCommandList1", "ExecuteExtensionCommand", 26usize, |this| ((**(this as *const *const ID3D12VideoEncodeCommandList1_Vtbl)).ExecuteExtensionCommand)(this, pextensioncommand, pexecutionparameters, executionparameterssizeinbytes), |_| None)
+        }
+        Self {
+            base__: <ID3D12VideoEncodeCommandList_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            InitializeExtensionCommand,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            InitializeExtensionCommand: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            ExecuteExtensionCommand,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            ExecuteExtensionCommand: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VideoEncodeCommandList1 as windows_core::Interface>::IID || <ID3D12VideoEncodeCommandList_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D12VideoEncodeCommandList2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn EncodeFrame(this: *mut core::ffi::c_void, pencoder: *mut core::ffi::c_void, pheap: *mut core::ffi::c_void, pinputarguments: *const D3D12_VIDEO_ENCODER_ENCODEFRAME_INPUT_ARGUMENTS, poutputarguments: *const D3D12_VIDEO_ENCODER_ENCODEFRAME_OUTPUT_ARGUMENTS) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncodeCommandList2", "EncodeFrame", 27usize, |this| ((**(this as *const *const ID3D12VideoEncodeCommandList2_Vtbl)).EncodeFrame)(this, pencoder, pheap, pinputarguments, poutputarguments), |_| None)
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn ResolveEncoderOutputMetadata(this: *mut core::ffi::c_void, pinputarguments: *const D3D12_VIDEO_ENCODER_RESOLVE_METADATA_INPUT_ARGUMENTS, poutputarguments: *const D3D12_VIDEO_ENCODER_RESOLVE_METADATA_OUTPUT_ARGUMENTS) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncodeCommandList2", "ResolveEncoderOutputMetadata", 28usize, |this| ((**(this as *const *const ID3D12VideoEncodeCommandList2_Vtbl)).ResolveEncoderOutputMetadata)(this, pinputarguments, poutputarguments), |_| None)
+        }
+        Self {
+            base__: <ID3D12VideoEncodeCommandList1_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+            EncodeFrame,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common")))]
+            EncodeFrame: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+            ResolveEncoderOutputMetadata,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common")))]
+            ResolveEncoderOutputMetadata: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VideoEncodeCommandList2 as windows_core::Interface>::IID || <ID3D12VideoEncodeCommandList1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D12VideoEncodeCommandList3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn Barrier(this: *mut core::ffi::c_void, numbarriergroups: u32, pbarriergroups: *const super::super::Graphics::Direct3D12::D3D12_BARRIER_GROUP) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncodeCommandList3", "Barrier", 29usize, |this| ((**(this as *const *const ID3D12VideoEncodeCommandList3_Vtbl)).Barrier)(this, numbarriergroups, pbarriergroups), |_| None)
+        }
+        Self {
+            base__: <ID3D12VideoEncodeCommandList2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            Barrier,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            Barrier: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VideoEncodeCommandList3 as windows_core::Interface>::IID || <ID3D12VideoEncodeCommandList2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D12VideoEncoder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNodeMask(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncoder", "GetNodeMask", 8usize, |this| ((**(this as *const *const ID3D12VideoEncoder_Vtbl)).GetNodeMask)(this), |_| None)
+        }
+        unsafe extern "system" fn GetEncoderFlags(this: *mut core::ffi::c_void) -> D3D12_VIDEO_ENCODER_FLAGS {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncoder", "GetEncoderFlags", 9usize, |this| ((**(this as *const *const ID3D12VideoEncoder_Vtbl)).GetEncoderFlags)(this), |_| None)
+        }
+        unsafe extern "system" fn GetCodec(this: *mut core::ffi::c_void) -> D3D12_VIDEO_ENCODER_CODEC {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncoder", "GetCodec", 10usize, |this| ((**(this as *const *const ID3D12VideoEncoder_Vtbl)).GetCodec)(this), |_| None)
+        }
+        unsafe extern "system" fn GetCodecProfile(this: *mut core::ffi::c_void, dstprofile: D3D12_VIDEO_ENCODER_PROFILE_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncoder", "GetCodecProfile", 11usize, |this| ((**(this as *const *const ID3D12VideoEncoder_Vtbl)).GetCodecProfile)(this, dstprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCodecConfiguration(this: *mut core::ffi::c_void, dstcodecconfig: D3D12_VIDEO_ENCODER_CODEC_CONFIGURATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncoder", "GetCodecConfiguration", 12usize, |this| ((**(this as *const *const ID3D12VideoEncoder_Vtbl)).GetCodecConfiguration)(this, dstcodecconfig), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetInputFormat(this: *mut core::ffi::c_void) -> super::super::Graphics::Dxgi::Common::DXGI_FORMAT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncoder", "GetInputFormat", 13usize, |this| ((**(this as *const *const ID3D12VideoEncoder_Vtbl)).GetInputFormat)(this), |_| None)
+        }
+        unsafe extern "system" fn GetMaxMotionEstimationPrecision(this: *mut core::ffi::c_void) -> D3D12_VIDEO_ENCODER_MOTION_ESTIMATION_PRECISION_MODE {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncoder", "GetMaxMotionEstimationPrecision", 14usize, |this| ((**(this as *const *const ID3D12VideoEncoder_Vtbl)).GetMaxMotionEstimationPrecision)(this), |_| None)
+        }
+        Self {
+            base__: <super::super::Graphics::Direct3D12::ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetNodeMask,
+            GetEncoderFlags,
+            GetCodec,
+            GetCodecProfile,
+            GetCodecConfiguration,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetInputFormat,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetInputFormat: 0,
+            GetMaxMotionEstimationPrecision,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VideoEncoder as windows_core::Interface>::IID || <super::super::Graphics::Direct3D12::ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D12")]
+impl windows_core::imp::ProxyVtable for ID3D12VideoEncoderHeap_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNodeMask(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncoderHeap", "GetNodeMask", 8usize, |this| ((**(this as *const *const ID3D12VideoEncoderHeap_Vtbl)).GetNodeMask)(this), |_| None)
+        }
+        unsafe extern "system" fn GetEncoderHeapFlags(this: *mut core::ffi::c_void) -> D3D12_VIDEO_ENCODER_HEAP_FLAGS {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncoderHeap", "GetEncoderHeapFlags", 9usize, |this| ((**(this as *const *const ID3D12VideoEncoderHeap_Vtbl)).GetEncoderHeapFlags)(this), |_| None)
+        }
+        unsafe extern "system" fn GetCodec(this: *mut core::ffi::c_void) -> D3D12_VIDEO_ENCODER_CODEC {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncoderHeap", "GetCodec", 10usize, |this| ((**(this as *const *const ID3D12VideoEncoderHeap_Vtbl)).GetCodec)(this), |_| None)
+        }
+        unsafe extern "system" fn GetCodecProfile(this: *mut core::ffi::c_void, dstprofile: D3D12_VIDEO_ENCODER_PROFILE_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncoderHeap", "GetCodecProfile", 11usize, |this| ((**(this as *const *const ID3D12VideoEncoderHeap_Vtbl)).GetCodecProfile)(this, dstprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCodecLevel(this: *mut core::ffi::c_void, dstlevel: D3D12_VIDEO_ENCODER_LEVEL_SETTING) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncoderHeap", "GetCodecLevel", 12usize, |this| ((**(this as *const *const ID3D12VideoEncoderHeap_Vtbl)).GetCodecLevel)(this, dstlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResolutionListCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncoderHeap", "GetResolutionListCount", 13usize, |this| ((**(this as *const *const ID3D12VideoEncoderHeap_Vtbl)).GetResolutionListCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetResolutionList(this: *mut core::ffi::c_void, resolutionslistcount: u32, presolutionlist: *mut D3D12_VIDEO_ENCODER_PICTURE_RESOLUTION_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoEncoderHeap", "GetResolutionList", 14usize, |this| ((**(this as *const *const ID3D12VideoEncoderHeap_Vtbl)).GetResolutionList)(this, resolutionslistcount, presolutionlist), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::Graphics::Direct3D12::ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetNodeMask,
+            GetEncoderHeapFlags,
+            GetCodec,
+            GetCodecProfile,
+            GetCodecLevel,
+            GetResolutionListCount,
+            GetResolutionList,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VideoEncoderHeap as windows_core::Interface>::IID || <super::super::Graphics::Direct3D12::ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D12")]
+impl windows_core::imp::ProxyVtable for ID3D12VideoExtensionCommand_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, result__: *mut D3D12_VIDEO_EXTENSION_COMMAND_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoExtensionCommand", "GetDesc", 8usize, |this| ((**(this as *const *const ID3D12VideoExtensionCommand_Vtbl)).GetDesc)(this, result__), |_| None)
+        }
+        unsafe extern "system" fn GetProtectedResourceSession(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppprotectedsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoExtensionCommand", "GetProtectedResourceSession", 9usize, |this| ((**(this as *const *const ID3D12VideoExtensionCommand_Vtbl)).GetProtectedResourceSession)(this, riid, ppprotectedsession), |result| Some(*result))
+        }
+        Self { base__: <super::super::Graphics::Direct3D12::ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDesc, GetProtectedResourceSession }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VideoExtensionCommand as windows_core::Interface>::IID || <super::super::Graphics::Direct3D12::ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D12VideoMotionEstimator_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, result__: *mut D3D12_VIDEO_MOTION_ESTIMATOR_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoMotionEstimator", "GetDesc", 8usize, |this| ((**(this as *const *const ID3D12VideoMotionEstimator_Vtbl)).GetDesc)(this, result__), |_| None)
+        }
+        unsafe extern "system" fn GetProtectedResourceSession(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppprotectedsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoMotionEstimator", "GetProtectedResourceSession", 9usize, |this| ((**(this as *const *const ID3D12VideoMotionEstimator_Vtbl)).GetProtectedResourceSession)(this, riid, ppprotectedsession), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::Graphics::Direct3D12::ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDesc,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDesc: 0,
+            GetProtectedResourceSession,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VideoMotionEstimator as windows_core::Interface>::IID || <super::super::Graphics::Direct3D12::ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D12VideoMotionVectorHeap_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetDesc(this: *mut core::ffi::c_void, result__: *mut D3D12_VIDEO_MOTION_VECTOR_HEAP_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoMotionVectorHeap", "GetDesc", 8usize, |this| ((**(this as *const *const ID3D12VideoMotionVectorHeap_Vtbl)).GetDesc)(this, result__), |_| None)
+        }
+        unsafe extern "system" fn GetProtectedResourceSession(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppprotectedsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoMotionVectorHeap", "GetProtectedResourceSession", 9usize, |this| ((**(this as *const *const ID3D12VideoMotionVectorHeap_Vtbl)).GetProtectedResourceSession)(this, riid, ppprotectedsession), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::Graphics::Direct3D12::ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetDesc,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetDesc: 0,
+            GetProtectedResourceSession,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VideoMotionVectorHeap as windows_core::Interface>::IID || <super::super::Graphics::Direct3D12::ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D12")]
+impl windows_core::imp::ProxyVtable for ID3D12VideoProcessCommandList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoProcessCommandList", "Close", 9usize, |this| ((**(this as *const *const ID3D12VideoProcessCommandList_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void, pallocator: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoProcessCommandList", "Reset", 10usize, |this| ((**(this as *const *const ID3D12VideoProcessCommandList_Vtbl)).Reset)(this, pallocator), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearState(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoProcessCommandList", "ClearState", 11usize, |this| ((**(this as *const *const ID3D12VideoProcessCommandList_Vtbl)).ClearState)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn ResourceBarrier(this: *mut core::ffi::c_void, numbarriers: u32, pbarriers: *const super::super::Graphics::Direct3D12::D3D12_RESOURCE_BARRIER) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoProcessCommandList", "ResourceBarrier", 12usize, |this| ((**(this as *const *const ID3D12VideoProcessCommandList_Vtbl)).ResourceBarrier)(this, numbarriers, pbarriers), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn DiscardResource(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pregion: *const super::super::Graphics::Direct3D12::D3D12_DISCARD_REGION) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoProcessCommandList", "DiscardResource", 13usize, |this| ((**(this as *const *const ID3D12VideoProcessCommandList_Vtbl)).DiscardResource)(this, presource, pregion), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn BeginQuery(this: *mut core::ffi::c_void, pqueryheap: *mut core::ffi::c_void, r#type: super::super::Graphics::Direct3D12::D3D12_QUERY_TYPE, index: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoProcessCommandList", "BeginQuery", 14usize, |this| ((**(this as *const *const ID3D12VideoProcessCommandList_Vtbl)).BeginQuery)(this, pqueryheap, r#type, index), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn EndQuery(this: *mut core::ffi::c_void, pqueryheap: *mut core::ffi::c_void, r#type: super::super::Graphics::Direct3D12::D3D12_QUERY_TYPE, index: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoProcessCommandList", "EndQuery", 15usize, |this| ((**(this as *const *const ID3D12VideoProcessCommandList_Vtbl)).EndQuery)(this, pqueryheap, r#type, index), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn ResolveQueryData(this: *mut core::ffi::c_void, pqueryheap: *mut core::ffi::c_void, r#type: super::super::Graphics::Direct3D12::D3D12_QUERY_TYPE, startindex: u32, numqueries: u32, pdestinationbuffer: *mut core::ffi::c_void, aligneddestinationbufferoffset: u64) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoProcessCommandList", "ResolveQueryData", 16usize, |this| ((**(this as *const *const ID3D12VideoProcessCommandList_Vtbl)).ResolveQueryData)(this, pqueryheap, r#type, startindex, numqueries, pdestinationbuffer, aligneddestinationbufferoffset), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn SetPredication(this: *mut core::ffi::c_void, pbuffer: *mut core::ffi::c_void, alignedbufferoffset: u64, operation: super::super::Graphics::Direct3D12::D3D12_PREDICATION_OP) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoProcessCommandList", "SetPredication", 17usize, |this| ((**(this as *const *const ID3D12VideoProcessCommandList_Vtbl)).SetPredication)(this, pbuffer, alignedbufferoffset, operation), |_| None)
+        }
+        unsafe extern "system" fn SetMarker(this: *mut core::ffi::c_void, metadata: u32, pdata: *const core::ffi::c_void, size: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoProcessCommandList", "SetMarker", 18usize, |this| ((**(this as *const *const ID3D12VideoProcessCommandList_Vtbl)).SetMarker)(this, metadata, pdata, size), |_| None)
+        }
+        unsafe extern "system" fn BeginEvent(this: *mut core::ffi::c_void, metadata: u32, pdata: *const core::ffi::c_void, size: u32) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoProcessCommandList", "BeginEvent", 19usize, |this| ((**(this as *const *const ID3D12VideoProcessCommandList_Vtbl)).BeginEvent)(this, metadata, pdata, size), |_| None)
+        }
+        unsafe extern "system" fn EndEvent(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoProcessCommandList", "EndEvent", 20usize, |this| ((**(this as *const *const ID3D12VideoProcessCommandList_Vtbl)).EndEvent)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn ProcessFrames(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, poutputarguments: *const D3D12_VIDEO_PROCESS_OUTPUT_STREAM_ARGUMENTS, numinputstreams: u32, pinputarguments: *const D3D12_VIDEO_PROCESS_INPUT_STREAM_ARGUMENTS) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoProcessCommandList", "ProcessFrames", 21usize, |this| ((**(this as *const *const ID3D12VideoProcessCommandList_Vtbl)).ProcessFrames)(this, pvideoprocessor, poutputarguments, numinputstreams, pinputarguments), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn WriteBufferImmediate(this: *mut core::ffi::c_void, count: u32, pparams: *const super::super::Graphics::Direct3D12::D3D12_WRITEBUFFERIMMEDIATE_PARAMETER, pmodes: *const super::super::Graphics::Direct3D12::D3D12_WRITEBUFFERIMMEDIATE_MODE) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoProcessCommandList", "WriteBufferImmediate", 22usize, |this| ((**(this as *const *const ID3D12VideoProcessCommandList_Vtbl)).WriteBufferImmediate)(this, count, pparams, pmodes), |_| None)
+        }
+        Self {
+            base__: <super::super::Graphics::Direct3D12::ID3D12CommandList_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Close,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            Reset,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            Reset: 0,
+            ClearState,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            ResourceBarrier,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            ResourceBarrier: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            DiscardResource,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            DiscardResource: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            BeginQuery,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            BeginQuery: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            EndQuery,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            EndQuery: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            ResolveQueryData,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            ResolveQueryData: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            SetPredication,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            SetPredication: 0,
+            SetMarker,
+            BeginEvent,
+            EndEvent,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            ProcessFrames,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            ProcessFrames: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            WriteBufferImmediate,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            WriteBufferImmediate: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VideoProcessCommandList as windows_core::Interface>::IID || <super::super::Graphics::Direct3D12::ID3D12CommandList_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D12")]
+impl windows_core::imp::ProxyVtable for ID3D12VideoProcessCommandList1_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn ProcessFrames1(this: *mut core::ffi::c_void, pvideoprocessor: *mut core::ffi::c_void, poutputarguments: *const D3D12_VIDEO_PROCESS_OUTPUT_STREAM_ARGUMENTS, numinputstreams: u32, pinputarguments: *const D3D12_VIDEO_PROCESS_INPUT_STREAM_ARGUMENTS1) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoProcessCommandList1", "ProcessFrames1", 23usize, |this| ((**(this as *const *const ID3D12VideoProcessCommandList1_Vtbl)).ProcessFrames1)(this, pvideoprocessor, poutputarguments, numinputstreams, pinputarguments), |_| None)
+        }
+        Self {
+            base__: <ID3D12VideoProcessCommandList_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            ProcessFrames1,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            ProcessFrames1: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VideoProcessCommandList1 as windows_core::Interface>::IID || <ID3D12VideoProcessCommandList_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D12")]
+impl windows_core::imp::ProxyVtable for ID3D12VideoProcessCommandList2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn SetProtectedResourceSession(this: *mut core::ffi::c_void, pprotectedresourcesession: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoProcessCommandList2", "SetProtectedResourceSession", 24usize, |this| ((**(this as *const *const ID3D12VideoProcessCommandList2_Vtbl)).SetProtectedResourceSession)(this, pprotectedresourcesession), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn InitializeExtensionCommand(this: *mut core::ffi::c_void, pextensioncommand: *mut core::ffi::c_void, pinitializationparameters: *const core::ffi::c_void, initializationparameterssizeinbytes: usize) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoProcessCommandList2", "InitializeExtensionCommand", 25usize, |this| ((**(this as *const *const ID3D12VideoProcessCommandList2_Vtbl)).InitializeExtensionCommand)(this, pextensioncommand, pinitializationparameters, initializationparameterssizeinbytes), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn ExecuteExtensionCommand(this: *mut core::ffi::c_void, pextensioncommand: *mut core::ffi::c_void, pexecutionparameters: *const core::ffi::c_void, executionparameterssizeinbytes: usize) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoProcessCommandList2", "ExecuteExtensionCommand", 26usize, |this| ((**(this as *const *const ID3D12VideoProcessCommandList2_Vtbl)).ExecuteExtensionCommand)(this, pextensioncommand, pexecutionparameters, executionparameterssizeinbytes), |_| None)
+        }
+        Self {
+            base__: <ID3D12VideoProcessCommandList1_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            SetProtectedResourceSession,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            SetProtectedResourceSession: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            InitializeExtensionCommand,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            InitializeExtensionCommand: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            ExecuteExtensionCommand,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            ExecuteExtensionCommand: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VideoProcessCommandList2 as windows_core::Interface>::IID || <ID3D12VideoProcessCommandList1_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D12")]
+impl windows_core::imp::ProxyVtable for ID3D12VideoProcessCommandList3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn Barrier(this: *mut core::ffi::c_void, numbarriergroups: u32, pbarriergroups: *const super::super::Graphics::Direct3D12::D3D12_BARRIER_GROUP) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoProcessCommandList3", "Barrier", 27usize, |this| ((**(this as *const *const ID3D12VideoProcessCommandList3_Vtbl)).Barrier)(this, numbarriergroups, pbarriergroups), |_| None)
+        }
+        Self {
+            base__: <ID3D12VideoProcessCommandList2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            Barrier,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            Barrier: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VideoProcessCommandList3 as windows_core::Interface>::IID || <ID3D12VideoProcessCommandList2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D12VideoProcessor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNodeMask(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D12VideoProcessor", "GetNodeMask", 8usize, |this| ((**(this as *const *const ID3D12VideoProcessor_Vtbl)).GetNodeMask)(this), |_| None)
+        }
+        unsafe extern "system" fn GetNumInputStreamDescs(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ID3D12VideoProcessor", "GetNumInputStreamDescs", 9usize, |this| ((**(this as *const *const ID3D12VideoProcessor_Vtbl)).GetNumInputStreamDescs)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetInputStreamDescs(this: *mut core::ffi::c_void, numinputstreamdescs: u32, pinputstreamdescs: *mut D3D12_VIDEO_PROCESS_INPUT_STREAM_DESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoProcessor", "GetInputStreamDescs", 10usize, |this| ((**(this as *const *const ID3D12VideoProcessor_Vtbl)).GetInputStreamDescs)(this, numinputstreamdescs, pinputstreamdescs), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetOutputStreamDesc(this: *mut core::ffi::c_void, result__: *mut D3D12_VIDEO_PROCESS_OUTPUT_STREAM_DESC) {
+            windows_core::imp::proxy_call(this, "ID3D12VideoProcessor", "GetOutputStreamDesc", 11usize, |this| ((**(this as *const *const ID3D12VideoProcessor_Vtbl)).GetOutputStreamDesc)(this, result__), |_| None)
+        }
+        Self {
+            base__: <super::super::Graphics::Direct3D12::ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetNodeMask,
+            GetNumInputStreamDescs,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetInputStreamDescs,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetInputStreamDescs: 0,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetOutputStreamDesc,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetOutputStreamDesc: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VideoProcessor as windows_core::Interface>::IID || <super::super::Graphics::Direct3D12::ID3D12Pageable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for ID3D12VideoProcessor1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProtectedResourceSession(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppprotectedsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ID3D12VideoProcessor1", "GetProtectedResourceSession", 12usize, |this| ((**(this as *const *const ID3D12VideoProcessor1_Vtbl)).GetProtectedResourceSession)(this, riid, ppprotectedsession), |result| Some(*result))
+        }
+        Self { base__: <ID3D12VideoProcessor_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProtectedResourceSession }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ID3D12VideoProcessor1 as windows_core::Interface>::IID || <ID3D12VideoProcessor_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D9")]
+impl windows_core::imp::ProxyVtable for IDXVAHD_Device_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn CreateVideoSurface(this: *mut core::ffi::c_void, width: u32, height: u32, format: super::super::Graphics::Direct3D9::D3DFORMAT, pool: super::super::Graphics::Direct3D9::D3DPOOL, usage: u32, r#type: DXVAHD_SURFACE_TYPE, numsurfaces: u32, ppsurfaces: *mut *mut core::ffi::c_void, psharedhandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXVAHD_Device", "CreateVideoSurface", 3usize, |this| ((**(this as *const *const IDXVAHD_Device_Vtbl)).CreateVideoSurface)(this, width, height, format, pool, usage, r#type, numsurfaces, ppsurfaces, psharedhandle), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn GetVideoProcessorDeviceCaps(this: *mut core::ffi::c_void, pcaps: *mut DXVAHD_VPDEVCAPS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXVAHD_Device", "GetVideoProcessorDeviceCaps", 4usize, |this| ((**(this as *const *const IDXVAHD_Device_Vtbl)).GetVideoProcessorDeviceCaps)(this, pcaps), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn GetVideoProcessorOutputFormats(this: *mut core::ffi::c_void, count: u32, pformats: *mut super::super::Graphics::Direct3D9::D3DFORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXVAHD_Device", "GetVideoProcessorOutputFormats", 5usize, |this| ((**(this as *const *const IDXVAHD_Device_Vtbl)).GetVideoProcessorOutputFormats)(this, count, pformats), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn GetVideoProcessorInputFormats(this: *mut core::ffi::c_void, count: u32, pformats: *mut super::super::Graphics::Direct3D9::D3DFORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXVAHD_Device", "GetVideoProcessorInputFormats", 6usize, |this| ((**(this as *const *const IDXVAHD_Device_Vtbl)).GetVideoProcessorInputFormats)(this, count, pformats), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoProcessorCaps(this: *mut core::ffi::c_void, count: u32, pcaps: *mut DXVAHD_VPCAPS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXVAHD_Device", "GetVideoProcessorCaps", 7usize, |this| ((**(this as *const *const IDXVAHD_Device_Vtbl)).GetVideoProcessorCaps)(this, count, pcaps), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoProcessorCustomRates(this: *mut core::ffi::c_void, pvpguid: *const windows_core::GUID, count: u32, prates: *mut DXVAHD_CUSTOM_RATE_DATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXVAHD_Device", "GetVideoProcessorCustomRates", 8usize, |this| ((**(this as *const *const IDXVAHD_Device_Vtbl)).GetVideoProcessorCustomRates)(this, pvpguid, count, prates), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoProcessorFilterRange(this: *mut core::ffi::c_void, filter: DXVAHD_FILTER, prange: *mut DXVAHD_FILTER_RANGE_DATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXVAHD_Device", "GetVideoProcessorFilterRange", 9usize, |this| ((**(this as *const *const IDXVAHD_Device_Vtbl)).GetVideoProcessorFilterRange)(this, filter, prange), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVideoProcessor(this: *mut core::ffi::c_void, pvpguid: *const windows_core::GUID, ppvideoprocessor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXVAHD_Device", "CreateVideoProcessor", 10usize, |this| ((**(this as *const *const IDXVAHD_Device_Vtbl)).CreateVideoProcessor)(this, pvpguid, ppvideoprocessor), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            CreateVideoSurface,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            CreateVideoSurface: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            GetVideoProcessorDeviceCaps,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            GetVideoProcessorDeviceCaps: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            GetVideoProcessorOutputFormats,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            GetVideoProcessorOutputFormats: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            GetVideoProcessorInputFormats,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            GetVideoProcessorInputFormats: 0,
+            GetVideoProcessorCaps,
+            GetVideoProcessorCustomRates,
+            GetVideoProcessorFilterRange,
+            CreateVideoProcessor,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXVAHD_Device as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D9")]
+impl windows_core::imp::ProxyVtable for IDXVAHD_VideoProcessor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetVideoProcessBltState(this: *mut core::ffi::c_void, state: DXVAHD_BLT_STATE, datasize: u32, pdata: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXVAHD_VideoProcessor", "SetVideoProcessBltState", 3usize, |this| ((**(this as *const *const IDXVAHD_VideoProcessor_Vtbl)).SetVideoProcessBltState)(this, state, datasize, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoProcessBltState(this: *mut core::ffi::c_void, state: DXVAHD_BLT_STATE, datasize: u32, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXVAHD_VideoProcessor", "GetVideoProcessBltState", 4usize, |this| ((**(this as *const *const IDXVAHD_VideoProcessor_Vtbl)).GetVideoProcessBltState)(this, state, datasize, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVideoProcessStreamState(this: *mut core::ffi::c_void, streamnumber: u32, state: DXVAHD_STREAM_STATE, datasize: u32, pdata: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXVAHD_VideoProcessor", "SetVideoProcessStreamState", 5usize, |this| ((**(this as *const *const IDXVAHD_VideoProcessor_Vtbl)).SetVideoProcessStreamState)(this, streamnumber, state, datasize, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoProcessStreamState(this: *mut core::ffi::c_void, streamnumber: u32, state: DXVAHD_STREAM_STATE, datasize: u32, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXVAHD_VideoProcessor", "GetVideoProcessStreamState", 6usize, |this| ((**(this as *const *const IDXVAHD_VideoProcessor_Vtbl)).GetVideoProcessStreamState)(this, streamnumber, state, datasize, pdata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn VideoProcessBltHD(this: *mut core::ffi::c_void, poutputsurface: *mut core::ffi::c_void, outputframe: u32, streamcount: u32, pstreams: *const DXVAHD_STREAM_DATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDXVAHD_VideoProcessor", "VideoProcessBltHD", 7usize, |this| ((**(this as *const *const IDXVAHD_VideoProcessor_Vtbl)).VideoProcessBltHD)(this, poutputsurface, outputframe, streamcount, pstreams), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetVideoProcessBltState,
+            GetVideoProcessBltState,
+            SetVideoProcessStreamState,
+            GetVideoProcessStreamState,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            VideoProcessBltHD,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            VideoProcessBltHD: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDXVAHD_VideoProcessor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D9")]
+impl windows_core::imp::ProxyVtable for IDirect3D9ExOverlayExtension_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn CheckDeviceOverlayType(this: *mut core::ffi::c_void, adapter: u32, devtype: super::super::Graphics::Direct3D9::D3DDEVTYPE, overlaywidth: u32, overlayheight: u32, overlayformat: super::super::Graphics::Direct3D9::D3DFORMAT, pdisplaymode: *mut super::super::Graphics::Direct3D9::D3DDISPLAYMODEEX, displayrotation: super::super::Graphics::Direct3D9::D3DDISPLAYROTATION, poverlaycaps: *mut D3DOVERLAYCAPS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3D9ExOverlayExtension", "CheckDeviceOverlayType", 3usize, |this| ((**(this as *const *const IDirect3D9ExOverlayExtension_Vtbl)).CheckDeviceOverlayType)(this, adapter, devtype, overlaywidth, overlayheight, overlayformat, pdisplaymode, displayrotation, poverlaycaps), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            CheckDeviceOverlayType,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            CheckDeviceOverlayType: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3D9ExOverlayExtension as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D9")]
+impl windows_core::imp::ProxyVtable for IDirect3DAuthenticatedChannel9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCertificateSize(this: *mut core::ffi::c_void, pcertificatesize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DAuthenticatedChannel9", "GetCertificateSize", 3usize, |this| ((**(this as *const *const IDirect3DAuthenticatedChannel9_Vtbl)).GetCertificateSize)(this, pcertificatesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCertificate(this: *mut core::ffi::c_void, certifactesize: u32, ppcertificate: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DAuthenticatedChannel9", "GetCertificate", 4usize, |this| ((**(this as *const *const IDirect3DAuthenticatedChannel9_Vtbl)).GetCertificate)(this, certifactesize, ppcertificate), |result| Some(*result))
+        }
+        unsafe extern "system" fn NegotiateKeyExchange(this: *mut core::ffi::c_void, datasize: u32, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DAuthenticatedChannel9", "NegotiateKeyExchange", 5usize, |this| ((**(this as *const *const IDirect3DAuthenticatedChannel9_Vtbl)).NegotiateKeyExchange)(this, datasize, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn Query(this: *mut core::ffi::c_void, inputsize: u32, pinput: *const core::ffi::c_void, outputsize: u32, poutput: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DAuthenticatedChannel9", "Query", 6usize, |this| ((**(this as *const *const IDirect3DAuthenticatedChannel9_Vtbl)).Query)(this, inputsize, pinput, outputsize, poutput), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn Configure(this: *mut core::ffi::c_void, inputsize: u32, pinput: *const core::ffi::c_void, poutput: *mut super::super::Graphics::Direct3D9::D3DAUTHENTICATEDCHANNEL_CONFIGURE_OUTPUT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DAuthenticatedChannel9", "Configure", 7usize, |this| ((**(this as *const *const IDirect3DAuthenticatedChannel9_Vtbl)).Configure)(this, inputsize, pinput, poutput), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCertificateSize,
+            GetCertificate,
+            NegotiateKeyExchange,
+            Query,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            Configure,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            Configure: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3DAuthenticatedChannel9 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D9")]
+impl windows_core::imp::ProxyVtable for IDirect3DCryptoSession9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCertificateSize(this: *mut core::ffi::c_void, pcertificatesize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DCryptoSession9", "GetCertificateSize", 3usize, |this| ((**(this as *const *const IDirect3DCryptoSession9_Vtbl)).GetCertificateSize)(this, pcertificatesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCertificate(this: *mut core::ffi::c_void, certifactesize: u32, ppcertificate: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DCryptoSession9", "GetCertificate", 4usize, |this| ((**(this as *const *const IDirect3DCryptoSession9_Vtbl)).GetCertificate)(this, certifactesize, ppcertificate), |result| Some(*result))
+        }
+        unsafe extern "system" fn NegotiateKeyExchange(this: *mut core::ffi::c_void, datasize: u32, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DCryptoSession9", "NegotiateKeyExchange", 5usize, |this| ((**(this as *const *const IDirect3DCryptoSession9_Vtbl)).NegotiateKeyExchange)(this, datasize, pdata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn EncryptionBlt(this: *mut core::ffi::c_void, psrcsurface: *mut core::ffi::c_void, pdstsurface: *mut core::ffi::c_void, dstsurfacesize: u32, piv: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DCryptoSession9", "EncryptionBlt", 6usize, |this| ((**(this as *const *const IDirect3DCryptoSession9_Vtbl)).EncryptionBlt)(this, psrcsurface, pdstsurface, dstsurfacesize, piv), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn DecryptionBlt(this: *mut core::ffi::c_void, psrcsurface: *mut core::ffi::c_void, pdstsurface: *mut core::ffi::c_void, srcsurfacesize: u32, pencryptedblockinfo: *mut super::super::Graphics::Direct3D9::D3DENCRYPTED_BLOCK_INFO, pcontentkey: *mut core::ffi::c_void, piv: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DCryptoSession9", "DecryptionBlt", 7usize, |this| ((**(this as *const *const IDirect3DCryptoSession9_Vtbl)).DecryptionBlt)(this, psrcsurface, pdstsurface, srcsurfacesize, pencryptedblockinfo, pcontentkey, piv), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn GetSurfacePitch(this: *mut core::ffi::c_void, psrcsurface: *mut core::ffi::c_void, psurfacepitch: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DCryptoSession9", "GetSurfacePitch", 8usize, |this| ((**(this as *const *const IDirect3DCryptoSession9_Vtbl)).GetSurfacePitch)(this, psrcsurface, psurfacepitch), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartSessionKeyRefresh(this: *mut core::ffi::c_void, prandomnumber: *mut core::ffi::c_void, randomnumbersize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DCryptoSession9", "StartSessionKeyRefresh", 9usize, |this| ((**(this as *const *const IDirect3DCryptoSession9_Vtbl)).StartSessionKeyRefresh)(this, prandomnumber, randomnumbersize), |result| Some(*result))
+        }
+        unsafe extern "system" fn FinishSessionKeyRefresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DCryptoSession9", "FinishSessionKeyRefresh", 10usize, |this| ((**(this as *const *const IDirect3DCryptoSession9_Vtbl)).FinishSessionKeyRefresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEncryptionBltKey(this: *mut core::ffi::c_void, preadbackkey: *mut core::ffi::c_void, keysize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DCryptoSession9", "GetEncryptionBltKey", 11usize, |this| ((**(this as *const *const IDirect3DCryptoSession9_Vtbl)).GetEncryptionBltKey)(this, preadbackkey, keysize), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCertificateSize,
+            GetCertificate,
+            NegotiateKeyExchange,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            EncryptionBlt,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            EncryptionBlt: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            DecryptionBlt,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            DecryptionBlt: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            GetSurfacePitch,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            GetSurfacePitch: 0,
+            StartSessionKeyRefresh,
+            FinishSessionKeyRefresh,
+            GetEncryptionBltKey,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3DCryptoSession9 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D9")]
+impl windows_core::imp::ProxyVtable for IDirect3DDevice9Video_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetContentProtectionCaps(this: *mut core::ffi::c_void, pcryptotype: *const windows_core::GUID, pdecodeprofile: *const windows_core::GUID, pcaps: *mut D3DCONTENTPROTECTIONCAPS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9Video", "GetContentProtectionCaps", 3usize, |this| ((**(this as *const *const IDirect3DDevice9Video_Vtbl)).GetContentProtectionCaps)(this, pcryptotype, pdecodeprofile, pcaps), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn CreateAuthenticatedChannel(this: *mut core::ffi::c_void, channeltype: super::super::Graphics::Direct3D9::D3DAUTHENTICATEDCHANNELTYPE, ppauthenticatedchannel: *mut *mut core::ffi::c_void, pchannelhandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9Video", "CreateAuthenticatedChannel", 4usize, |this| ((**(this as *const *const IDirect3DDevice9Video_Vtbl)).CreateAuthenticatedChannel)(this, channeltype, ppauthenticatedchannel, pchannelhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCryptoSession(this: *mut core::ffi::c_void, pcryptotype: *const windows_core::GUID, pdecodeprofile: *const windows_core::GUID, ppcryptosession: *mut *mut core::ffi::c_void, pcryptohandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDevice9Video", "CreateCryptoSession", 5usize, |this| ((**(this as *const *const IDirect3DDevice9Video_Vtbl)).CreateCryptoSession)(this, pcryptotype, pdecodeprofile, ppcryptosession, pcryptohandle), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetContentProtectionCaps,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            CreateAuthenticatedChannel,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            CreateAuthenticatedChannel: 0,
+            CreateCryptoSession,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3DDevice9Video as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D9")]
+impl windows_core::imp::ProxyVtable for IDirect3DDeviceManager9_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn ResetDevice(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, resettoken: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDeviceManager9", "ResetDevice", 3usize, |this| ((**(this as *const *const IDirect3DDeviceManager9_Vtbl)).ResetDevice)(this, pdevice, resettoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenDeviceHandle(this: *mut core::ffi::c_void, phdevice: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDeviceManager9", "OpenDeviceHandle", 4usize, |this| ((**(this as *const *const IDirect3DDeviceManager9_Vtbl)).OpenDeviceHandle)(this, phdevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseDeviceHandle(this: *mut core::ffi::c_void, hdevice: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDeviceManager9", "CloseDeviceHandle", 5usize, |this| ((**(this as *const *const IDirect3DDeviceManager9_Vtbl)).CloseDeviceHandle)(this, hdevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn TestDevice(this: *mut core::ffi::c_void, hdevice: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDeviceManager9", "TestDevice", 6usize, |this| ((**(this as *const *const IDirect3DDeviceManager9_Vtbl)).TestDevice)(this, hdevice), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn LockDevice(this: *mut core::ffi::c_void, hdevice: super::super::Foundation::HANDLE, ppdevice: *mut *mut core::ffi::c_void, fblock: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDeviceManager9", "LockDevice", 7usize, |this| ((**(this as *const *const IDirect3DDeviceManager9_Vtbl)).LockDevice)(this, hdevice, ppdevice, fblock), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockDevice(this: *mut core::ffi::c_void, hdevice: super::super::Foundation::HANDLE, fsavestate: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDeviceManager9", "UnlockDevice", 8usize, |this| ((**(this as *const *const IDirect3DDeviceManager9_Vtbl)).UnlockDevice)(this, hdevice, fsavestate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoService(this: *mut core::ffi::c_void, hdevice: super::super::Foundation::HANDLE, riid: *const windows_core::GUID, ppservice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDeviceManager9", "GetVideoService", 9usize, |this| ((**(this as *const *const IDirect3DDeviceManager9_Vtbl)).GetVideoService)(this, hdevice, riid, ppservice), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            ResetDevice,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            ResetDevice: 0,
+            OpenDeviceHandle,
+            CloseDeviceHandle,
+            TestDevice,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            LockDevice,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            LockDevice: 0,
+            UnlockDevice,
+            GetVideoService,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3DDeviceManager9 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D9")]
+impl windows_core::imp::ProxyVtable for IDirectXVideoAccelerationService_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn CreateSurface(this: *mut core::ffi::c_void, width: u32, height: u32, backbuffers: u32, format: super::super::Graphics::Direct3D9::D3DFORMAT, pool: super::super::Graphics::Direct3D9::D3DPOOL, usage: u32, dxvatype: u32, ppsurface: *mut *mut core::ffi::c_void, psharedhandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectXVideoAccelerationService", "CreateSurface", 3usize, |this| ((**(this as *const *const IDirectXVideoAccelerationService_Vtbl)).CreateSurface)(this, width, height, backbuffers, format, pool, usage, dxvatype, ppsurface, psharedhandle), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            CreateSurface,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            CreateSurface: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectXVideoAccelerationService as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D9")]
+impl windows_core::imp::ProxyVtable for IDirectXVideoDecoder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetVideoDecoderService(this: *mut core::ffi::c_void, ppservice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectXVideoDecoder", "GetVideoDecoderService", 3usize, |this| ((**(this as *const *const IDirectXVideoDecoder_Vtbl)).GetVideoDecoderService)(this, ppservice), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn GetCreationParameters(this: *mut core::ffi::c_void, pdeviceguid: *mut windows_core::GUID, pvideodesc: *mut DXVA2_VideoDesc, pconfig: *mut DXVA2_ConfigPictureDecode, pdecoderrendertargets: *mut *mut Option<super::super::Graphics::Direct3D9::IDirect3DSurface9>, pnumsurfaces: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectXVideoDecoder", "GetCreationParameters", 4usize, |this| ((**(this as *const *const IDirectXVideoDecoder_Vtbl)).GetCreationParameters)(this, pdeviceguid, pvideodesc, pconfig, pdecoderrendertargets, pnumsurfaces), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBuffer(this: *mut core::ffi::c_void, buffertype: u32, ppbuffer: *mut *mut core::ffi::c_void, pbuffersize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectXVideoDecoder", "GetBuffer", 5usize, |this| ((**(this as *const *const IDirectXVideoDecoder_Vtbl)).GetBuffer)(this, buffertype, ppbuffer, pbuffersize), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseBuffer(this: *mut core::ffi::c_void, buffertype: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectXVideoDecoder", "ReleaseBuffer", 6usize, |this| ((**(this as *const *const IDirectXVideoDecoder_Vtbl)).ReleaseBuffer)(this, buffertype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn BeginFrame(this: *mut core::ffi::c_void, prendertarget: *mut core::ffi::c_void, pvpvpdata: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectXVideoDecoder", "BeginFrame", 7usize, |this| ((**(this as *const *const IDirectXVideoDecoder_Vtbl)).BeginFrame)(this, prendertarget, pvpvpdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndFrame(this: *mut core::ffi::c_void, phandlecomplete: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectXVideoDecoder", "EndFrame", 8usize, |this| ((**(this as *const *const IDirectXVideoDecoder_Vtbl)).EndFrame)(this, phandlecomplete), |result| Some(*result))
+        }
+        unsafe extern "system" fn Execute(this: *mut core::ffi::c_void, pexecuteparams: *const DXVA2_DecodeExecuteParams) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectXVideoDecoder", "Execute", 9usize, |this| ((**(this as *const *const IDirectXVideoDecoder_Vtbl)).Execute)(this, pexecuteparams), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetVideoDecoderService,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            GetCreationParameters,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            GetCreationParameters: 0,
+            GetBuffer,
+            ReleaseBuffer,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            BeginFrame,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            BeginFrame: 0,
+            EndFrame,
+            Execute,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectXVideoDecoder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D9")]
+impl windows_core::imp::ProxyVtable for IDirectXVideoDecoderService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDecoderDeviceGuids(this: *mut core::ffi::c_void, pcount: *mut u32, pguids: *mut *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectXVideoDecoderService", "GetDecoderDeviceGuids", 4usize, |this| ((**(this as *const *const IDirectXVideoDecoderService_Vtbl)).GetDecoderDeviceGuids)(this, pcount, pguids), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn GetDecoderRenderTargets(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pcount: *mut u32, pformats: *mut *mut super::super::Graphics::Direct3D9::D3DFORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectXVideoDecoderService", "GetDecoderRenderTargets", 5usize, |this| ((**(this as *const *const IDirectXVideoDecoderService_Vtbl)).GetDecoderRenderTargets)(this, guid, pcount, pformats), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn GetDecoderConfigurations(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pvideodesc: *const DXVA2_VideoDesc, preserved: *const core::ffi::c_void, pcount: *mut u32, ppconfigs: *mut *mut DXVA2_ConfigPictureDecode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectXVideoDecoderService", "GetDecoderConfigurations", 6usize, |this| ((**(this as *const *const IDirectXVideoDecoderService_Vtbl)).GetDecoderConfigurations)(this, guid, pvideodesc, preserved, pcount, ppconfigs), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn CreateVideoDecoder(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pvideodesc: *const DXVA2_VideoDesc, pconfig: *const DXVA2_ConfigPictureDecode, ppdecoderrendertargets: *const *mut core::ffi::c_void, numrendertargets: u32, ppdecode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectXVideoDecoderService", "CreateVideoDecoder", 7usize, |this| ((**(this as *const *const IDirectXVideoDecoderService_Vtbl)).CreateVideoDecoder)(this, guid, pvideodesc, pconfig, ppdecoderrendertargets, numrendertargets, ppdecode), |result| Some(*result))
+        }
+        Self {
+            base__: <IDirectXVideoAccelerationService_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDecoderDeviceGuids,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            GetDecoderRenderTargets,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            GetDecoderRenderTargets: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            GetDecoderConfigurations,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            GetDecoderConfigurations: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            CreateVideoDecoder,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            CreateVideoDecoder: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectXVideoDecoderService as windows_core::Interface>::IID || <IDirectXVideoAccelerationService_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectXVideoMemoryConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAvailableSurfaceTypeByIndex(this: *mut core::ffi::c_void, dwtypeindex: u32, pdwtype: *mut DXVA2_SurfaceType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectXVideoMemoryConfiguration", "GetAvailableSurfaceTypeByIndex", 3usize, |this| ((**(this as *const *const IDirectXVideoMemoryConfiguration_Vtbl)).GetAvailableSurfaceTypeByIndex)(this, dwtypeindex, pdwtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSurfaceType(this: *mut core::ffi::c_void, dwtype: DXVA2_SurfaceType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectXVideoMemoryConfiguration", "SetSurfaceType", 4usize, |this| ((**(this as *const *const IDirectXVideoMemoryConfiguration_Vtbl)).SetSurfaceType)(this, dwtype), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAvailableSurfaceTypeByIndex, SetSurfaceType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectXVideoMemoryConfiguration as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D9")]
+impl windows_core::imp::ProxyVtable for IDirectXVideoProcessor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetVideoProcessorService(this: *mut core::ffi::c_void, ppservice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectXVideoProcessor", "GetVideoProcessorService", 3usize, |this| ((**(this as *const *const IDirectXVideoProcessor_Vtbl)).GetVideoProcessorService)(this, ppservice), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn GetCreationParameters(this: *mut core::ffi::c_void, pdeviceguid: *mut windows_core::GUID, pvideodesc: *mut DXVA2_VideoDesc, prendertargetformat: *mut super::super::Graphics::Direct3D9::D3DFORMAT, pmaxnumsubstreams: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectXVideoProcessor", "GetCreationParameters", 4usize, |this| ((**(this as *const *const IDirectXVideoProcessor_Vtbl)).GetCreationParameters)(this, pdeviceguid, pvideodesc, prendertargetformat, pmaxnumsubstreams), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn GetVideoProcessorCaps(this: *mut core::ffi::c_void, pcaps: *mut DXVA2_VideoProcessorCaps) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectXVideoProcessor", "GetVideoProcessorCaps", 5usize, |this| ((**(this as *const *const IDirectXVideoProcessor_Vtbl)).GetVideoProcessorCaps)(this, pcaps), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProcAmpRange(this: *mut core::ffi::c_void, procampcap: u32, prange: *mut DXVA2_ValueRange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectXVideoProcessor", "GetProcAmpRange", 6usize, |this| ((**(this as *const *const IDirectXVideoProcessor_Vtbl)).GetProcAmpRange)(this, procampcap, prange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFilterPropertyRange(this: *mut core::ffi::c_void, filtersetting: u32, prange: *mut DXVA2_ValueRange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectXVideoProcessor", "GetFilterPropertyRange", 7usize, |this| ((**(this as *const *const IDirectXVideoProcessor_Vtbl)).GetFilterPropertyRange)(this, filtersetting, prange), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn VideoProcessBlt(this: *mut core::ffi::c_void, prendertarget: *mut core::ffi::c_void, pbltparams: *const DXVA2_VideoProcessBltParams, psamples: *const DXVA2_VideoSample, numsamples: u32, phandlecomplete: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectXVideoProcessor", "VideoProcessBlt", 8usize, |this| ((**(this as *const *const IDirectXVideoProcessor_Vtbl)).VideoProcessBlt)(this, prendertarget, pbltparams, psamples, numsamples, phandlecomplete), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetVideoProcessorService,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            GetCreationParameters,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            GetCreationParameters: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            GetVideoProcessorCaps,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            GetVideoProcessorCaps: 0,
+            GetProcAmpRange,
+            GetFilterPropertyRange,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            VideoProcessBlt,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            VideoProcessBlt: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectXVideoProcessor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D9")]
+impl windows_core::imp::ProxyVtable for IDirectXVideoProcessorService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterVideoProcessorSoftwareDevice(this: *mut core::ffi::c_void, pcallbacks: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectXVideoProcessorService", "RegisterVideoProcessorSoftwareDevice", 4usize, |this| ((**(this as *const *const IDirectXVideoProcessorService_Vtbl)).RegisterVideoProcessorSoftwareDevice)(this, pcallbacks), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn GetVideoProcessorDeviceGuids(this: *mut core::ffi::c_void, pvideodesc: *const DXVA2_VideoDesc, pcount: *mut u32, pguids: *mut *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectXVideoProcessorService", "GetVideoProcessorDeviceGuids", 5usize, |this| ((**(this as *const *const IDirectXVideoProcessorService_Vtbl)).GetVideoProcessorDeviceGuids)(this, pvideodesc, pcount, pguids), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn GetVideoProcessorRenderTargets(this: *mut core::ffi::c_void, videoprocdeviceguid: *const windows_core::GUID, pvideodesc: *const DXVA2_VideoDesc, pcount: *mut u32, pformats: *mut *mut super::super::Graphics::Direct3D9::D3DFORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectXVideoProcessorService", "GetVideoProcessorRenderTargets", 6usize, |this| ((**(this as *const *const IDirectXVideoProcessorService_Vtbl)).GetVideoProcessorRenderTargets)(this, videoprocdeviceguid, pvideodesc, pcount, pformats), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn GetVideoProcessorSubStreamFormats(this: *mut core::ffi::c_void, videoprocdeviceguid: *const windows_core::GUID, pvideodesc: *const DXVA2_VideoDesc, rendertargetformat: super::super::Graphics::Direct3D9::D3DFORMAT, pcount: *mut u32, pformats: *mut *mut super::super::Graphics::Direct3D9::D3DFORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectXVideoProcessorService", "GetVideoProcessorSubStreamFormats", 7usize, |this| ((**(this as *const *const IDirectXVideoProcessorService_Vtbl)).GetVideoProcessorSubStreamFormats)(this, videoprocdeviceguid, pvideodesc, rendertargetformat, pcount, pformats), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn GetVideoProcessorCaps(this: *mut core::ffi::c_void, videoprocdeviceguid: *const windows_core::GUID, pvideodesc: *const DXVA2_VideoDesc, rendertargetformat: super::super::Graphics::Direct3D9::D3DFORMAT, pcaps: *mut DXVA2_VideoProcessorCaps) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectXVideoProcessorService", "GetVideoProcessorCaps", 8usize, |this| ((**(this as *const *const IDirectXVideoProcessorService_Vtbl)).GetVideoProcessorCaps)(this, videoprocdeviceguid, pvideodesc, rendertargetformat, pcaps), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn GetProcAmpRange(this: *mut core::ffi::c_void, videoprocdeviceguid: *const windows_core::GUID, pvideodesc: *const DXVA2_VideoDesc, rendertargetformat: super::super::Graphics::Direct3D9::D3DFORMAT, procampcap: u32, prange: *mut DXVA2_ValueRange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectXVideoProcessorService", "GetProcAmpRange", 9usize, |this| ((**(this as *const *const IDirectXVideoProcessorService_Vtbl)).GetProcAmpRange)(this, videoprocdeviceguid, pvideodesc, rendertargetformat, procampcap, prange), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn GetFilterPropertyRange(this: *mut core::ffi::c_void, videoprocdeviceguid: *const windows_core::GUID, pvideodesc: *const DXVA2_VideoDesc, rendertargetformat: super::super::Graphics::Direct3D9::D3DFORMAT, filtersetting: u32, prange: *mut DXVA2_ValueRange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectXVideoProcessorService", "GetFilterPropertyRange", 10usize, |this| ((**(this as *const *const IDirectXVideoProcessorService_Vtbl)).GetFilterPropertyRange)(this, videoprocdeviceguid, pvideodesc, rendertargetformat, filtersetting, prange), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn CreateVideoProcessor(this: *mut core::ffi::c_void, videoprocdeviceguid: *const windows_core::GUID, pvideodesc: *const DXVA2_VideoDesc, rendertargetformat: super::super::Graphics::Direct3D9::D3DFORMAT, maxnumsubstreams: u32, ppvidprocess: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectXVideoProcessorService", "CreateVideoProcessor", 11usize, |this| ((**(this as *const *const IDirectXVideoProcessorService_Vtbl)).CreateVideoProcessor)(this, videoprocdeviceguid, pvideodesc, rendertargetformat, maxnumsubstreams, ppvidprocess), |result| Some(*result))
+        }
+        Self {
+            base__: <IDirectXVideoAccelerationService_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RegisterVideoProcessorSoftwareDevice,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            GetVideoProcessorDeviceGuids,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            GetVideoProcessorDeviceGuids: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            GetVideoProcessorRenderTargets,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            GetVideoProcessorRenderTargets: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            GetVideoProcessorSubStreamFormats,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            GetVideoProcessorSubStreamFormats: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            GetVideoProcessorCaps,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            GetVideoProcessorCaps: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            GetProcAmpRange,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            GetProcAmpRange: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            GetFilterPropertyRange,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            GetFilterPropertyRange: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            CreateVideoProcessor,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            CreateVideoProcessor: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectXVideoProcessorService as windows_core::Interface>::IID || <IDirectXVideoAccelerationService_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEVRFilterConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetNumberOfStreams(this: *mut core::ffi::c_void, dwmaxstreams: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEVRFilterConfig", "SetNumberOfStreams", 3usize, |this| ((**(this as *const *const IEVRFilterConfig_Vtbl)).SetNumberOfStreams)(this, dwmaxstreams), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumberOfStreams(this: *mut core::ffi::c_void, pdwmaxstreams: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEVRFilterConfig", "GetNumberOfStreams", 4usize, |this| ((**(this as *const *const IEVRFilterConfig_Vtbl)).GetNumberOfStreams)(this, pdwmaxstreams), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetNumberOfStreams, GetNumberOfStreams }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEVRFilterConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEVRFilterConfigEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetConfigPrefs(this: *mut core::ffi::c_void, dwconfigflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEVRFilterConfigEx", "SetConfigPrefs", 5usize, |this| ((**(this as *const *const IEVRFilterConfigEx_Vtbl)).SetConfigPrefs)(this, dwconfigflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConfigPrefs(this: *mut core::ffi::c_void, pdwconfigflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEVRFilterConfigEx", "GetConfigPrefs", 6usize, |this| ((**(this as *const *const IEVRFilterConfigEx_Vtbl)).GetConfigPrefs)(this, pdwconfigflags), |result| Some(*result))
+        }
+        Self { base__: <IEVRFilterConfig_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetConfigPrefs, GetConfigPrefs }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEVRFilterConfigEx as windows_core::Interface>::IID || <IEVRFilterConfig_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEVRTrustedVideoPlugin_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsInTrustedVideoMode(this: *mut core::ffi::c_void, pyes: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEVRTrustedVideoPlugin", "IsInTrustedVideoMode", 3usize, |this| ((**(this as *const *const IEVRTrustedVideoPlugin_Vtbl)).IsInTrustedVideoMode)(this, pyes), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanConstrict(this: *mut core::ffi::c_void, pyes: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEVRTrustedVideoPlugin", "CanConstrict", 4usize, |this| ((**(this as *const *const IEVRTrustedVideoPlugin_Vtbl)).CanConstrict)(this, pyes), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConstriction(this: *mut core::ffi::c_void, dwkpix: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEVRTrustedVideoPlugin", "SetConstriction", 5usize, |this| ((**(this as *const *const IEVRTrustedVideoPlugin_Vtbl)).SetConstriction)(this, dwkpix), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableImageExport(this: *mut core::ffi::c_void, bdisable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEVRTrustedVideoPlugin", "DisableImageExport", 6usize, |this| ((**(this as *const *const IEVRTrustedVideoPlugin_Vtbl)).DisableImageExport)(this, bdisable), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsInTrustedVideoMode,
+            CanConstrict,
+            SetConstriction,
+            DisableImageExport,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEVRTrustedVideoPlugin as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEVRVideoStreamControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetStreamActiveState(this: *mut core::ffi::c_void, factive: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEVRVideoStreamControl", "SetStreamActiveState", 3usize, |this| ((**(this as *const *const IEVRVideoStreamControl_Vtbl)).SetStreamActiveState)(this, factive), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamActiveState(this: *mut core::ffi::c_void, lpfactive: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEVRVideoStreamControl", "GetStreamActiveState", 4usize, |this| ((**(this as *const *const IEVRVideoStreamControl_Vtbl)).GetStreamActiveState)(this, lpfactive), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetStreamActiveState, GetStreamActiveState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEVRVideoStreamControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFileClient_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetObjectDiskSize(this: *mut core::ffi::c_void, pqwsize: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileClient", "GetObjectDiskSize", 3usize, |this| ((**(this as *const *const IFileClient_Vtbl)).GetObjectDiskSize)(this, pqwsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn Write(this: *mut core::ffi::c_void, pfio: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileClient", "Write", 4usize, |this| ((**(this as *const *const IFileClient_Vtbl)).Write)(this, pfio), |result| Some(*result))
+        }
+        unsafe extern "system" fn Read(this: *mut core::ffi::c_void, pfio: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileClient", "Read", 5usize, |this| ((**(this as *const *const IFileClient_Vtbl)).Read)(this, pfio), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetObjectDiskSize, Write, Read }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileClient as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFileIo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, eaccessmode: FILE_ACCESSMODE, eopenmode: FILE_OPENMODE, pwszfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileIo", "Initialize", 3usize, |this| ((**(this as *const *const IFileIo_Vtbl)).Initialize)(this, eaccessmode, eopenmode, pwszfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pqwlength: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileIo", "GetLength", 4usize, |this| ((**(this as *const *const IFileIo_Vtbl)).GetLength)(this, pqwlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLength(this: *mut core::ffi::c_void, qwlength: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileIo", "SetLength", 5usize, |this| ((**(this as *const *const IFileIo_Vtbl)).SetLength)(this, qwlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentPosition(this: *mut core::ffi::c_void, pqwposition: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileIo", "GetCurrentPosition", 6usize, |this| ((**(this as *const *const IFileIo_Vtbl)).GetCurrentPosition)(this, pqwposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentPosition(this: *mut core::ffi::c_void, qwposition: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileIo", "SetCurrentPosition", 7usize, |this| ((**(this as *const *const IFileIo_Vtbl)).SetCurrentPosition)(this, qwposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEndOfStream(this: *mut core::ffi::c_void, pbendofstream: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileIo", "IsEndOfStream", 8usize, |this| ((**(this as *const *const IFileIo_Vtbl)).IsEndOfStream)(this, pbendofstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn Read(this: *mut core::ffi::c_void, pbt: *mut u8, ul: u32, pulread: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileIo", "Read", 9usize, |this| ((**(this as *const *const IFileIo_Vtbl)).Read)(this, pbt, ul, pulread), |result| Some(*result))
+        }
+        unsafe extern "system" fn Write(this: *mut core::ffi::c_void, pbt: *mut u8, ul: u32, pulwritten: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileIo", "Write", 10usize, |this| ((**(this as *const *const IFileIo_Vtbl)).Write)(this, pbt, ul, pulwritten), |result| Some(*result))
+        }
+        unsafe extern "system" fn Seek(this: *mut core::ffi::c_void, eseekorigin: SEEK_ORIGIN, qwseekoffset: u64, dwseekflags: u32, pqwcurrentposition: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileIo", "Seek", 11usize, |this| ((**(this as *const *const IFileIo_Vtbl)).Seek)(this, eseekorigin, qwseekoffset, dwseekflags, pqwcurrentposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileIo", "Close", 12usize, |this| ((**(this as *const *const IFileIo_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetLength,
+            SetLength,
+            GetCurrentPosition,
+            SetCurrentPosition,
+            IsEndOfStream,
+            Read,
+            Write,
+            Seek,
+            Close,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileIo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMF2DBuffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Lock2D(this: *mut core::ffi::c_void, ppbscanline0: *mut *mut u8, plpitch: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMF2DBuffer", "Lock2D", 3usize, |this| ((**(this as *const *const IMF2DBuffer_Vtbl)).Lock2D)(this, ppbscanline0, plpitch), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unlock2D(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMF2DBuffer", "Unlock2D", 4usize, |this| ((**(this as *const *const IMF2DBuffer_Vtbl)).Unlock2D)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScanline0AndPitch(this: *mut core::ffi::c_void, pbscanline0: *mut *mut u8, plpitch: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMF2DBuffer", "GetScanline0AndPitch", 5usize, |this| ((**(this as *const *const IMF2DBuffer_Vtbl)).GetScanline0AndPitch)(this, pbscanline0, plpitch), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsContiguousFormat(this: *mut core::ffi::c_void, pfiscontiguous: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMF2DBuffer", "IsContiguousFormat", 6usize, |this| ((**(this as *const *const IMF2DBuffer_Vtbl)).IsContiguousFormat)(this, pfiscontiguous), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContiguousLength(this: *mut core::ffi::c_void, pcblength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMF2DBuffer", "GetContiguousLength", 7usize, |this| ((**(this as *const *const IMF2DBuffer_Vtbl)).GetContiguousLength)(this, pcblength), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContiguousCopyTo(this: *mut core::ffi::c_void, pbdestbuffer: *mut u8, cbdestbuffer: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMF2DBuffer", "ContiguousCopyTo", 8usize, |this| ((**(this as *const *const IMF2DBuffer_Vtbl)).ContiguousCopyTo)(this, pbdestbuffer, cbdestbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContiguousCopyFrom(this: *mut core::ffi::c_void, pbsrcbuffer: *const u8, cbsrcbuffer: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMF2DBuffer", "ContiguousCopyFrom", 9usize, |this| ((**(this as *const *const IMF2DBuffer_Vtbl)).ContiguousCopyFrom)(this, pbsrcbuffer, cbsrcbuffer), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Lock2D,
+            Unlock2D,
+            GetScanline0AndPitch,
+            IsContiguousFormat,
+            GetContiguousLength,
+            ContiguousCopyTo,
+            ContiguousCopyFrom,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMF2DBuffer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMF2DBuffer2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Lock2DSize(this: *mut core::ffi::c_void, lockflags: MF2DBuffer_LockFlags, ppbscanline0: *mut *mut u8, plpitch: *mut i32, ppbbufferstart: *mut *mut u8, pcbbufferlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMF2DBuffer2", "Lock2DSize", 10usize, |this| ((**(this as *const *const IMF2DBuffer2_Vtbl)).Lock2DSize)(this, lockflags, ppbscanline0, plpitch, ppbbufferstart, pcbbufferlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn Copy2DTo(this: *mut core::ffi::c_void, pdestbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMF2DBuffer2", "Copy2DTo", 11usize, |this| ((**(this as *const *const IMF2DBuffer2_Vtbl)).Copy2DTo)(this, pdestbuffer), |result| Some(*result))
+        }
+        Self { base__: <IMF2DBuffer_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Lock2DSize, Copy2DTo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMF2DBuffer2 as windows_core::Interface>::IID || <IMF2DBuffer_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IMFASFContentInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetHeaderSize(this: *mut core::ffi::c_void, pistartofcontent: *mut core::ffi::c_void, cbheadersize: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFContentInfo", "GetHeaderSize", 3usize, |this| ((**(this as *const *const IMFASFContentInfo_Vtbl)).GetHeaderSize)(this, pistartofcontent, cbheadersize), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParseHeader(this: *mut core::ffi::c_void, piheaderbuffer: *mut core::ffi::c_void, cboffsetwithinheader: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFContentInfo", "ParseHeader", 4usize, |this| ((**(this as *const *const IMFASFContentInfo_Vtbl)).ParseHeader)(this, piheaderbuffer, cboffsetwithinheader), |result| Some(*result))
+        }
+        unsafe extern "system" fn GenerateHeader(this: *mut core::ffi::c_void, piheader: *mut core::ffi::c_void, pcbheader: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFContentInfo", "GenerateHeader", 5usize, |this| ((**(this as *const *const IMFASFContentInfo_Vtbl)).GenerateHeader)(this, piheader, pcbheader), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProfile(this: *mut core::ffi::c_void, ppiprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFContentInfo", "GetProfile", 6usize, |this| ((**(this as *const *const IMFASFContentInfo_Vtbl)).GetProfile)(this, ppiprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProfile(this: *mut core::ffi::c_void, piprofile: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFContentInfo", "SetProfile", 7usize, |this| ((**(this as *const *const IMFASFContentInfo_Vtbl)).SetProfile)(this, piprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn GeneratePresentationDescriptor(this: *mut core::ffi::c_void, ppipresentationdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFContentInfo", "GeneratePresentationDescriptor", 8usize, |this| ((**(this as *const *const IMFASFContentInfo_Vtbl)).GeneratePresentationDescriptor)(this, ppipresentationdescriptor), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetEncodingConfigurationPropertyStore(this: *mut core::ffi::c_void, wstreamnumber: u16, ppistore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFContentInfo", "GetEncodingConfigurationPropertyStore", 9usize, |this| ((**(this as *const *const IMFASFContentInfo_Vtbl)).GetEncodingConfigurationPropertyStore)(this, wstreamnumber, ppistore), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetHeaderSize,
+            ParseHeader,
+            GenerateHeader,
+            GetProfile,
+            SetProfile,
+            GeneratePresentationDescriptor,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetEncodingConfigurationPropertyStore,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetEncodingConfigurationPropertyStore: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFASFContentInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFASFIndexer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetFlags(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFIndexer", "SetFlags", 3usize, |this| ((**(this as *const *const IMFASFIndexer_Vtbl)).SetFlags)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlags(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFIndexer", "GetFlags", 4usize, |this| ((**(this as *const *const IMFASFIndexer_Vtbl)).GetFlags)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, picontentinfo: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFIndexer", "Initialize", 5usize, |this| ((**(this as *const *const IMFASFIndexer_Vtbl)).Initialize)(this, picontentinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIndexPosition(this: *mut core::ffi::c_void, picontentinfo: *mut core::ffi::c_void, pcbindexoffset: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFIndexer", "GetIndexPosition", 6usize, |this| ((**(this as *const *const IMFASFIndexer_Vtbl)).GetIndexPosition)(this, picontentinfo, pcbindexoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIndexByteStreams(this: *mut core::ffi::c_void, ppibytestreams: *const *mut core::ffi::c_void, cbytestreams: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFIndexer", "SetIndexByteStreams", 7usize, |this| ((**(this as *const *const IMFASFIndexer_Vtbl)).SetIndexByteStreams)(this, ppibytestreams, cbytestreams), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIndexByteStreamCount(this: *mut core::ffi::c_void, pcbytestreams: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFIndexer", "GetIndexByteStreamCount", 8usize, |this| ((**(this as *const *const IMFASFIndexer_Vtbl)).GetIndexByteStreamCount)(this, pcbytestreams), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIndexStatus(this: *mut core::ffi::c_void, pindexidentifier: *const ASF_INDEX_IDENTIFIER, pfisindexed: *mut super::super::Foundation::BOOL, pbindexdescriptor: *mut u8, pcbindexdescriptor: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFIndexer", "GetIndexStatus", 9usize, |this| ((**(this as *const *const IMFASFIndexer_Vtbl)).GetIndexStatus)(this, pindexidentifier, pfisindexed, pbindexdescriptor, pcbindexdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIndexStatus(this: *mut core::ffi::c_void, pbindexdescriptor: *const u8, cbindexdescriptor: u32, fgenerateindex: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFIndexer", "SetIndexStatus", 10usize, |this| ((**(this as *const *const IMFASFIndexer_Vtbl)).SetIndexStatus)(this, pbindexdescriptor, cbindexdescriptor, fgenerateindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSeekPositionForValue(this: *mut core::ffi::c_void, pvarvalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, pindexidentifier: *const ASF_INDEX_IDENTIFIER, pcboffsetwithindata: *mut u64, phnsapproxtime: *mut i64, pdwpayloadnumberofstreamwithinpacket: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFIndexer", "GetSeekPositionForValue", 11usize, |this| ((**(this as *const *const IMFASFIndexer_Vtbl)).GetSeekPositionForValue)(this, pvarvalue, pindexidentifier, pcboffsetwithindata, phnsapproxtime, pdwpayloadnumberofstreamwithinpacket), |result| Some(*result))
+        }
+        unsafe extern "system" fn GenerateIndexEntries(this: *mut core::ffi::c_void, piasfpacketsample: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFIndexer", "GenerateIndexEntries", 12usize, |this| ((**(this as *const *const IMFASFIndexer_Vtbl)).GenerateIndexEntries)(this, piasfpacketsample), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommitIndex(this: *mut core::ffi::c_void, picontentinfo: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFIndexer", "CommitIndex", 13usize, |this| ((**(this as *const *const IMFASFIndexer_Vtbl)).CommitIndex)(this, picontentinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIndexWriteSpace(this: *mut core::ffi::c_void, pcbindexwritespace: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFIndexer", "GetIndexWriteSpace", 14usize, |this| ((**(this as *const *const IMFASFIndexer_Vtbl)).GetIndexWriteSpace)(this, pcbindexwritespace), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCompletedIndex(this: *mut core::ffi::c_void, piindexbuffer: *mut core::ffi::c_void, cboffsetwithinindex: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFIndexer", "GetCompletedIndex", 15usize, |this| ((**(this as *const *const IMFASFIndexer_Vtbl)).GetCompletedIndex)(this, piindexbuffer, cboffsetwithinindex), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetFlags,
+            GetFlags,
+            Initialize,
+            GetIndexPosition,
+            SetIndexByteStreams,
+            GetIndexByteStreamCount,
+            GetIndexStatus,
+            SetIndexStatus,
+            GetSeekPositionForValue,
+            GenerateIndexEntries,
+            CommitIndex,
+            GetIndexWriteSpace,
+            GetCompletedIndex,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFASFIndexer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFASFMultiplexer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, picontentinfo: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFMultiplexer", "Initialize", 3usize, |this| ((**(this as *const *const IMFASFMultiplexer_Vtbl)).Initialize)(this, picontentinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFlags(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFMultiplexer", "SetFlags", 4usize, |this| ((**(this as *const *const IMFASFMultiplexer_Vtbl)).SetFlags)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlags(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFMultiplexer", "GetFlags", 5usize, |this| ((**(this as *const *const IMFASFMultiplexer_Vtbl)).GetFlags)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessSample(this: *mut core::ffi::c_void, wstreamnumber: u16, pisample: *mut core::ffi::c_void, hnstimestampadjust: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFMultiplexer", "ProcessSample", 6usize, |this| ((**(this as *const *const IMFASFMultiplexer_Vtbl)).ProcessSample)(this, wstreamnumber, pisample, hnstimestampadjust), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextPacket(this: *mut core::ffi::c_void, pdwstatusflags: *mut u32, ppipacket: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFMultiplexer", "GetNextPacket", 7usize, |this| ((**(this as *const *const IMFASFMultiplexer_Vtbl)).GetNextPacket)(this, pdwstatusflags, ppipacket), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flush(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFMultiplexer", "Flush", 8usize, |this| ((**(this as *const *const IMFASFMultiplexer_Vtbl)).Flush)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn End(this: *mut core::ffi::c_void, picontentinfo: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFMultiplexer", "End", 9usize, |this| ((**(this as *const *const IMFASFMultiplexer_Vtbl)).End)(this, picontentinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatistics(this: *mut core::ffi::c_void, wstreamnumber: u16, pmuxstats: *mut ASF_MUX_STATISTICS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFMultiplexer", "GetStatistics", 10usize, |this| ((**(this as *const *const IMFASFMultiplexer_Vtbl)).GetStatistics)(this, wstreamnumber, pmuxstats), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSyncTolerance(this: *mut core::ffi::c_void, mssynctolerance: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFMultiplexer", "SetSyncTolerance", 11usize, |this| ((**(this as *const *const IMFASFMultiplexer_Vtbl)).SetSyncTolerance)(this, mssynctolerance), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            SetFlags,
+            GetFlags,
+            ProcessSample,
+            GetNextPacket,
+            Flush,
+            End,
+            GetStatistics,
+            SetSyncTolerance,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFASFMultiplexer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFASFMutualExclusion_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, pguidtype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFMutualExclusion", "GetType", 3usize, |this| ((**(this as *const *const IMFASFMutualExclusion_Vtbl)).GetType)(this, pguidtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetType(this: *mut core::ffi::c_void, guidtype: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFMutualExclusion", "SetType", 4usize, |this| ((**(this as *const *const IMFASFMutualExclusion_Vtbl)).SetType)(this, guidtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordCount(this: *mut core::ffi::c_void, pdwrecordcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFMutualExclusion", "GetRecordCount", 5usize, |this| ((**(this as *const *const IMFASFMutualExclusion_Vtbl)).GetRecordCount)(this, pdwrecordcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamsForRecord(this: *mut core::ffi::c_void, dwrecordnumber: u32, pwstreamnumarray: *mut u16, pcstreams: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFMutualExclusion", "GetStreamsForRecord", 6usize, |this| ((**(this as *const *const IMFASFMutualExclusion_Vtbl)).GetStreamsForRecord)(this, dwrecordnumber, pwstreamnumarray, pcstreams), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddStreamForRecord(this: *mut core::ffi::c_void, dwrecordnumber: u32, wstreamnumber: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFMutualExclusion", "AddStreamForRecord", 7usize, |this| ((**(this as *const *const IMFASFMutualExclusion_Vtbl)).AddStreamForRecord)(this, dwrecordnumber, wstreamnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStreamFromRecord(this: *mut core::ffi::c_void, dwrecordnumber: u32, wstreamnumber: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFMutualExclusion", "RemoveStreamFromRecord", 8usize, |this| ((**(this as *const *const IMFASFMutualExclusion_Vtbl)).RemoveStreamFromRecord)(this, dwrecordnumber, wstreamnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRecord(this: *mut core::ffi::c_void, dwrecordnumber: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFMutualExclusion", "RemoveRecord", 9usize, |this| ((**(this as *const *const IMFASFMutualExclusion_Vtbl)).RemoveRecord)(this, dwrecordnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddRecord(this: *mut core::ffi::c_void, pdwrecordnumber: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFMutualExclusion", "AddRecord", 10usize, |this| ((**(this as *const *const IMFASFMutualExclusion_Vtbl)).AddRecord)(this, pdwrecordnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppimutex: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFMutualExclusion", "Clone", 11usize, |this| ((**(this as *const *const IMFASFMutualExclusion_Vtbl)).Clone)(this, ppimutex), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetType,
+            SetType,
+            GetRecordCount,
+            GetStreamsForRecord,
+            AddStreamForRecord,
+            RemoveStreamFromRecord,
+            RemoveRecord,
+            AddRecord,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFASFMutualExclusion as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFASFProfile_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStreamCount(this: *mut core::ffi::c_void, pcstreams: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFProfile", "GetStreamCount", 33usize, |this| ((**(this as *const *const IMFASFProfile_Vtbl)).GetStreamCount)(this, pcstreams), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStream(this: *mut core::ffi::c_void, dwstreamindex: u32, pwstreamnumber: *mut u16, ppistream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFProfile", "GetStream", 34usize, |this| ((**(this as *const *const IMFASFProfile_Vtbl)).GetStream)(this, dwstreamindex, pwstreamnumber, ppistream), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamByNumber(this: *mut core::ffi::c_void, wstreamnumber: u16, ppistream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFProfile", "GetStreamByNumber", 35usize, |this| ((**(this as *const *const IMFASFProfile_Vtbl)).GetStreamByNumber)(this, wstreamnumber, ppistream), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStream(this: *mut core::ffi::c_void, pistream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFProfile", "SetStream", 36usize, |this| ((**(this as *const *const IMFASFProfile_Vtbl)).SetStream)(this, pistream), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStream(this: *mut core::ffi::c_void, wstreamnumber: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFProfile", "RemoveStream", 37usize, |this| ((**(this as *const *const IMFASFProfile_Vtbl)).RemoveStream)(this, wstreamnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateStream(this: *mut core::ffi::c_void, pimediatype: *mut core::ffi::c_void, ppistream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFProfile", "CreateStream", 38usize, |this| ((**(this as *const *const IMFASFProfile_Vtbl)).CreateStream)(this, pimediatype, ppistream), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMutualExclusionCount(this: *mut core::ffi::c_void, pcmutexs: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFProfile", "GetMutualExclusionCount", 39usize, |this| ((**(this as *const *const IMFASFProfile_Vtbl)).GetMutualExclusionCount)(this, pcmutexs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMutualExclusion(this: *mut core::ffi::c_void, dwmutexindex: u32, ppimutex: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFProfile", "GetMutualExclusion", 40usize, |this| ((**(this as *const *const IMFASFProfile_Vtbl)).GetMutualExclusion)(this, dwmutexindex, ppimutex), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddMutualExclusion(this: *mut core::ffi::c_void, pimutex: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFProfile", "AddMutualExclusion", 41usize, |this| ((**(this as *const *const IMFASFProfile_Vtbl)).AddMutualExclusion)(this, pimutex), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMutualExclusion(this: *mut core::ffi::c_void, dwmutexindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFProfile", "RemoveMutualExclusion", 42usize, |this| ((**(this as *const *const IMFASFProfile_Vtbl)).RemoveMutualExclusion)(this, dwmutexindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateMutualExclusion(this: *mut core::ffi::c_void, ppimutex: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFProfile", "CreateMutualExclusion", 43usize, |this| ((**(this as *const *const IMFASFProfile_Vtbl)).CreateMutualExclusion)(this, ppimutex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamPrioritization(this: *mut core::ffi::c_void, ppistreamprioritization: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFProfile", "GetStreamPrioritization", 44usize, |this| ((**(this as *const *const IMFASFProfile_Vtbl)).GetStreamPrioritization)(this, ppistreamprioritization), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddStreamPrioritization(this: *mut core::ffi::c_void, pistreamprioritization: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFProfile", "AddStreamPrioritization", 45usize, |this| ((**(this as *const *const IMFASFProfile_Vtbl)).AddStreamPrioritization)(this, pistreamprioritization), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStreamPrioritization(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFProfile", "RemoveStreamPrioritization", 46usize, |this| ((**(this as *const *const IMFASFProfile_Vtbl)).RemoveStreamPrioritization)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateStreamPrioritization(this: *mut core::ffi::c_void, ppistreamprioritization: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFProfile", "CreateStreamPrioritization", 47usize, |this| ((**(this as *const *const IMFASFProfile_Vtbl)).CreateStreamPrioritization)(this, ppistreamprioritization), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppiprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFProfile", "Clone", 48usize, |this| ((**(this as *const *const IMFASFProfile_Vtbl)).Clone)(this, ppiprofile), |result| Some(*result))
+        }
+        Self {
+            base__: <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetStreamCount,
+            GetStream,
+            GetStreamByNumber,
+            SetStream,
+            RemoveStream,
+            CreateStream,
+            GetMutualExclusionCount,
+            GetMutualExclusion,
+            AddMutualExclusion,
+            RemoveMutualExclusion,
+            CreateMutualExclusion,
+            GetStreamPrioritization,
+            AddStreamPrioritization,
+            RemoveStreamPrioritization,
+            CreateStreamPrioritization,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFASFProfile as windows_core::Interface>::IID || <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFASFSplitter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, picontentinfo: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFSplitter", "Initialize", 3usize, |this| ((**(this as *const *const IMFASFSplitter_Vtbl)).Initialize)(this, picontentinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFlags(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFSplitter", "SetFlags", 4usize, |this| ((**(this as *const *const IMFASFSplitter_Vtbl)).SetFlags)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlags(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFSplitter", "GetFlags", 5usize, |this| ((**(this as *const *const IMFASFSplitter_Vtbl)).GetFlags)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectStreams(this: *mut core::ffi::c_void, pwstreamnumbers: *const u16, wnumstreams: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFSplitter", "SelectStreams", 6usize, |this| ((**(this as *const *const IMFASFSplitter_Vtbl)).SelectStreams)(this, pwstreamnumbers, wnumstreams), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSelectedStreams(this: *mut core::ffi::c_void, pwstreamnumbers: *mut u16, pwnumstreams: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFSplitter", "GetSelectedStreams", 7usize, |this| ((**(this as *const *const IMFASFSplitter_Vtbl)).GetSelectedStreams)(this, pwstreamnumbers, pwnumstreams), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParseData(this: *mut core::ffi::c_void, pibuffer: *mut core::ffi::c_void, cbbufferoffset: u32, cblength: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFSplitter", "ParseData", 8usize, |this| ((**(this as *const *const IMFASFSplitter_Vtbl)).ParseData)(this, pibuffer, cbbufferoffset, cblength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextSample(this: *mut core::ffi::c_void, pdwstatusflags: *mut ASF_STATUSFLAGS, pwstreamnumber: *mut u16, ppisample: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFSplitter", "GetNextSample", 9usize, |this| ((**(this as *const *const IMFASFSplitter_Vtbl)).GetNextSample)(this, pdwstatusflags, pwstreamnumber, ppisample), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flush(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFSplitter", "Flush", 10usize, |this| ((**(this as *const *const IMFASFSplitter_Vtbl)).Flush)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastSendTime(this: *mut core::ffi::c_void, pdwlastsendtime: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFSplitter", "GetLastSendTime", 11usize, |this| ((**(this as *const *const IMFASFSplitter_Vtbl)).GetLastSendTime)(this, pdwlastsendtime), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            SetFlags,
+            GetFlags,
+            SelectStreams,
+            GetSelectedStreams,
+            ParseData,
+            GetNextSample,
+            Flush,
+            GetLastSendTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFASFSplitter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFASFStreamConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStreamType(this: *mut core::ffi::c_void, pguidstreamtype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFStreamConfig", "GetStreamType", 33usize, |this| ((**(this as *const *const IMFASFStreamConfig_Vtbl)).GetStreamType)(this, pguidstreamtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamNumber(this: *mut core::ffi::c_void) -> u16 {
+            windows_core::imp::proxy_call(this, "IMFASFStreamConfig", "GetStreamNumber", 34usize, |this| ((**(this as *const *const IMFASFStreamConfig_Vtbl)).GetStreamNumber)(this), |_| None)
+        }
+        unsafe extern "system" fn SetStreamNumber(this: *mut core::ffi::c_void, wstreamnum: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFStreamConfig", "SetStreamNumber", 35usize, |this| ((**(this as *const *const IMFASFStreamConfig_Vtbl)).SetStreamNumber)(this, wstreamnum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMediaType(this: *mut core::ffi::c_void, ppimediatype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFStreamConfig", "GetMediaType", 36usize, |this| ((**(this as *const *const IMFASFStreamConfig_Vtbl)).GetMediaType)(this, ppimediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMediaType(this: *mut core::ffi::c_void, pimediatype: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFStreamConfig", "SetMediaType", 37usize, |this| ((**(this as *const *const IMFASFStreamConfig_Vtbl)).SetMediaType)(this, pimediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPayloadExtensionCount(this: *mut core::ffi::c_void, pcpayloadextensions: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFStreamConfig", "GetPayloadExtensionCount", 38usize, |this| ((**(this as *const *const IMFASFStreamConfig_Vtbl)).GetPayloadExtensionCount)(this, pcpayloadextensions), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPayloadExtension(this: *mut core::ffi::c_void, wpayloadextensionnumber: u16, pguidextensionsystemid: *mut windows_core::GUID, pcbextensiondatasize: *mut u16, pbextensionsysteminfo: *mut u8, pcbextensionsysteminfo: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFStreamConfig", "GetPayloadExtension", 39usize, |this| ((**(this as *const *const IMFASFStreamConfig_Vtbl)).GetPayloadExtension)(this, wpayloadextensionnumber, pguidextensionsystemid, pcbextensiondatasize, pbextensionsysteminfo, pcbextensionsysteminfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPayloadExtension(this: *mut core::ffi::c_void, guidextensionsystemid: windows_core::GUID, cbextensiondatasize: u16, pbextensionsysteminfo: *const u8, cbextensionsysteminfo: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFStreamConfig", "AddPayloadExtension", 40usize, |this| ((**(this as *const *const IMFASFStreamConfig_Vtbl)).AddPayloadExtension)(this, guidextensionsystemid, cbextensiondatasize, pbextensionsysteminfo, cbextensionsysteminfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAllPayloadExtensions(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFStreamConfig", "RemoveAllPayloadExtensions", 41usize, |this| ((**(this as *const *const IMFASFStreamConfig_Vtbl)).RemoveAllPayloadExtensions)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppistreamconfig: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFStreamConfig", "Clone", 42usize, |this| ((**(this as *const *const IMFASFStreamConfig_Vtbl)).Clone)(this, ppistreamconfig), |result| Some(*result))
+        }
+        Self {
+            base__: <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetStreamType,
+            GetStreamNumber,
+            SetStreamNumber,
+            GetMediaType,
+            SetMediaType,
+            GetPayloadExtensionCount,
+            GetPayloadExtension,
+            AddPayloadExtension,
+            RemoveAllPayloadExtensions,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFASFStreamConfig as windows_core::Interface>::IID || <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFASFStreamPrioritization_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStreamCount(this: *mut core::ffi::c_void, pdwstreamcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFStreamPrioritization", "GetStreamCount", 3usize, |this| ((**(this as *const *const IMFASFStreamPrioritization_Vtbl)).GetStreamCount)(this, pdwstreamcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStream(this: *mut core::ffi::c_void, dwstreamindex: u32, pwstreamnumber: *mut u16, pwstreamflags: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFStreamPrioritization", "GetStream", 4usize, |this| ((**(this as *const *const IMFASFStreamPrioritization_Vtbl)).GetStream)(this, dwstreamindex, pwstreamnumber, pwstreamflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddStream(this: *mut core::ffi::c_void, wstreamnumber: u16, wstreamflags: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFStreamPrioritization", "AddStream", 5usize, |this| ((**(this as *const *const IMFASFStreamPrioritization_Vtbl)).AddStream)(this, wstreamnumber, wstreamflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStream(this: *mut core::ffi::c_void, dwstreamindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFStreamPrioritization", "RemoveStream", 6usize, |this| ((**(this as *const *const IMFASFStreamPrioritization_Vtbl)).RemoveStream)(this, dwstreamindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppistreamprioritization: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFStreamPrioritization", "Clone", 7usize, |this| ((**(this as *const *const IMFASFStreamPrioritization_Vtbl)).Clone)(this, ppistreamprioritization), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStreamCount, GetStream, AddStream, RemoveStream, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFASFStreamPrioritization as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFASFStreamSelector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStreamCount(this: *mut core::ffi::c_void, pcstreams: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFStreamSelector", "GetStreamCount", 3usize, |this| ((**(this as *const *const IMFASFStreamSelector_Vtbl)).GetStreamCount)(this, pcstreams), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputCount(this: *mut core::ffi::c_void, pcoutputs: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFStreamSelector", "GetOutputCount", 4usize, |this| ((**(this as *const *const IMFASFStreamSelector_Vtbl)).GetOutputCount)(this, pcoutputs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputStreamCount(this: *mut core::ffi::c_void, dwoutputnum: u32, pcstreams: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFStreamSelector", "GetOutputStreamCount", 5usize, |this| ((**(this as *const *const IMFASFStreamSelector_Vtbl)).GetOutputStreamCount)(this, dwoutputnum, pcstreams), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputStreamNumbers(this: *mut core::ffi::c_void, dwoutputnum: u32, rgwstreamnumbers: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFStreamSelector", "GetOutputStreamNumbers", 6usize, |this| ((**(this as *const *const IMFASFStreamSelector_Vtbl)).GetOutputStreamNumbers)(this, dwoutputnum, rgwstreamnumbers), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputFromStream(this: *mut core::ffi::c_void, wstreamnum: u16, pdwoutput: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFStreamSelector", "GetOutputFromStream", 7usize, |this| ((**(this as *const *const IMFASFStreamSelector_Vtbl)).GetOutputFromStream)(this, wstreamnum, pdwoutput), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputOverride(this: *mut core::ffi::c_void, dwoutputnum: u32, pselection: *mut ASF_SELECTION_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFStreamSelector", "GetOutputOverride", 8usize, |this| ((**(this as *const *const IMFASFStreamSelector_Vtbl)).GetOutputOverride)(this, dwoutputnum, pselection), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputOverride(this: *mut core::ffi::c_void, dwoutputnum: u32, selection: ASF_SELECTION_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFStreamSelector", "SetOutputOverride", 9usize, |this| ((**(this as *const *const IMFASFStreamSelector_Vtbl)).SetOutputOverride)(this, dwoutputnum, selection), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputMutexCount(this: *mut core::ffi::c_void, dwoutputnum: u32, pcmutexes: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFStreamSelector", "GetOutputMutexCount", 10usize, |this| ((**(this as *const *const IMFASFStreamSelector_Vtbl)).GetOutputMutexCount)(this, dwoutputnum, pcmutexes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputMutex(this: *mut core::ffi::c_void, dwoutputnum: u32, dwmutexnum: u32, ppmutex: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFStreamSelector", "GetOutputMutex", 11usize, |this| ((**(this as *const *const IMFASFStreamSelector_Vtbl)).GetOutputMutex)(this, dwoutputnum, dwmutexnum, ppmutex), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputMutexSelection(this: *mut core::ffi::c_void, dwoutputnum: u32, dwmutexnum: u32, wselectedrecord: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFStreamSelector", "SetOutputMutexSelection", 12usize, |this| ((**(this as *const *const IMFASFStreamSelector_Vtbl)).SetOutputMutexSelection)(this, dwoutputnum, dwmutexnum, wselectedrecord), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBandwidthStepCount(this: *mut core::ffi::c_void, pcstepcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFStreamSelector", "GetBandwidthStepCount", 13usize, |this| ((**(this as *const *const IMFASFStreamSelector_Vtbl)).GetBandwidthStepCount)(this, pcstepcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBandwidthStep(this: *mut core::ffi::c_void, dwstepnum: u32, pdwbitrate: *mut u32, rgwstreamnumbers: *mut u16, rgselections: *mut ASF_SELECTION_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFStreamSelector", "GetBandwidthStep", 14usize, |this| ((**(this as *const *const IMFASFStreamSelector_Vtbl)).GetBandwidthStep)(this, dwstepnum, pdwbitrate, rgwstreamnumbers, rgselections), |result| Some(*result))
+        }
+        unsafe extern "system" fn BitrateToStepNumber(this: *mut core::ffi::c_void, dwbitrate: u32, pdwstepnum: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFStreamSelector", "BitrateToStepNumber", 15usize, |this| ((**(this as *const *const IMFASFStreamSelector_Vtbl)).BitrateToStepNumber)(this, dwbitrate, pdwstepnum), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStreamSelectorFlags(this: *mut core::ffi::c_void, dwstreamselectorflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFASFStreamSelector", "SetStreamSelectorFlags", 16usize, |this| ((**(this as *const *const IMFASFStreamSelector_Vtbl)).SetStreamSelectorFlags)(this, dwstreamselectorflags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetStreamCount,
+            GetOutputCount,
+            GetOutputStreamCount,
+            GetOutputStreamNumbers,
+            GetOutputFromStream,
+            GetOutputOverride,
+            SetOutputOverride,
+            GetOutputMutexCount,
+            GetOutputMutex,
+            SetOutputMutexSelection,
+            GetBandwidthStepCount,
+            GetBandwidthStep,
+            BitrateToStepNumber,
+            SetStreamSelectorFlags,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFASFStreamSelector as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFActivate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ActivateObject(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFActivate", "ActivateObject", 33usize, |this| ((**(this as *const *const IMFActivate_Vtbl)).ActivateObject)(this, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShutdownObject(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFActivate", "ShutdownObject", 34usize, |this| ((**(this as *const *const IMFActivate_Vtbl)).ShutdownObject)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DetachObject(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFActivate", "DetachObject", 35usize, |this| ((**(this as *const *const IMFActivate_Vtbl)).DetachObject)(this), |result| Some(*result))
+        }
+        Self { base__: <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ActivateObject, ShutdownObject, DetachObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFActivate as windows_core::Interface>::IID || <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFAsyncCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetParameters(this: *mut core::ffi::c_void, pdwflags: *mut u32, pdwqueue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAsyncCallback", "GetParameters", 3usize, |this| ((**(this as *const *const IMFAsyncCallback_Vtbl)).GetParameters)(this, pdwflags, pdwqueue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Invoke(this: *mut core::ffi::c_void, pasyncresult: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAsyncCallback", "Invoke", 4usize, |this| ((**(this as *const *const IMFAsyncCallback_Vtbl)).Invoke)(this, pasyncresult), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetParameters, Invoke }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFAsyncCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFAsyncCallbackLogging_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetObjectPointer(this: *mut core::ffi::c_void) -> *mut core::ffi::c_void {
+            windows_core::imp::proxy_call(this, "IMFAsyncCallbackLogging", "GetObjectPointer", 5usize, |this| ((**(this as *const *const IMFAsyncCallbackLogging_Vtbl)).GetObjectPointer)(this), |_| None)
+        }
+        unsafe extern "system" fn GetObjectTag(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IMFAsyncCallbackLogging", "GetObjectTag", 6usize, |this| ((**(this as *const *const IMFAsyncCallbackLogging_Vtbl)).GetObjectTag)(this), |_| None)
+        }
+        Self { base__: <IMFAsyncCallback_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetObjectPointer, GetObjectTag }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFAsyncCallbackLogging as windows_core::Interface>::IID || <IMFAsyncCallback_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFAsyncResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetState(this: *mut core::ffi::c_void, ppunkstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAsyncResult", "GetState", 3usize, |this| ((**(this as *const *const IMFAsyncResult_Vtbl)).GetState)(this, ppunkstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAsyncResult", "GetStatus", 4usize, |this| ((**(this as *const *const IMFAsyncResult_Vtbl)).GetStatus)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatus(this: *mut core::ffi::c_void, hrstatus: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAsyncResult", "SetStatus", 5usize, |this| ((**(this as *const *const IMFAsyncResult_Vtbl)).SetStatus)(this, hrstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObject(this: *mut core::ffi::c_void, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAsyncResult", "GetObject", 6usize, |this| ((**(this as *const *const IMFAsyncResult_Vtbl)).GetObject)(this, ppobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStateNoAddRef(this: *mut core::ffi::c_void) -> Option<windows_core::IUnknown> {
+            windows_core::imp::proxy_call(this, "IMFAsyncResult", "GetStateNoAddRef", 7usize, |this| ((**(this as *const *const IMFAsyncResult_Vtbl)).GetStateNoAddRef)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetState, GetStatus, SetStatus, GetObject, GetStateNoAddRef }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFAsyncResult as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFAttributes_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetItem(this: *mut core::ffi::c_void, guidkey: *const windows_core::GUID, pvalue: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "GetItem", 3usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).GetItem)(this, guidkey, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItemType(this: *mut core::ffi::c_void, guidkey: *const windows_core::GUID, ptype: *mut MF_ATTRIBUTE_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "GetItemType", 4usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).GetItemType)(this, guidkey, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompareItem(this: *mut core::ffi::c_void, guidkey: *const windows_core::GUID, value: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, pbresult: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "CompareItem", 5usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).CompareItem)(this, guidkey, value, pbresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn Compare(this: *mut core::ffi::c_void, ptheirs: *mut core::ffi::c_void, matchtype: MF_ATTRIBUTES_MATCH_TYPE, pbresult: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "Compare", 6usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).Compare)(this, ptheirs, matchtype, pbresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUINT32(this: *mut core::ffi::c_void, guidkey: *const windows_core::GUID, punvalue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "GetUINT32", 7usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).GetUINT32)(this, guidkey, punvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUINT64(this: *mut core::ffi::c_void, guidkey: *const windows_core::GUID, punvalue: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "GetUINT64", 8usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).GetUINT64)(this, guidkey, punvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDouble(this: *mut core::ffi::c_void, guidkey: *const windows_core::GUID, pfvalue: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "GetDouble", 9usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).GetDouble)(this, guidkey, pfvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGUID(this: *mut core::ffi::c_void, guidkey: *const windows_core::GUID, pguidvalue: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "GetGUID", 10usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).GetGUID)(this, guidkey, pguidvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStringLength(this: *mut core::ffi::c_void, guidkey: *const windows_core::GUID, pcchlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "GetStringLength", 11usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).GetStringLength)(this, guidkey, pcchlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetString(this: *mut core::ffi::c_void, guidkey: *const windows_core::GUID, pwszvalue: windows_core::PWSTR, cchbufsize: u32, pcchlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "GetString", 12usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).GetString)(this, guidkey, pwszvalue, cchbufsize, pcchlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllocatedString(this: *mut core::ffi::c_void, guidkey: *const windows_core::GUID, ppwszvalue: *mut windows_core::PWSTR, pcchlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "GetAllocatedString", 13usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).GetAllocatedString)(this, guidkey, ppwszvalue, pcchlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBlobSize(this: *mut core::ffi::c_void, guidkey: *const windows_core::GUID, pcbblobsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "GetBlobSize", 14usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).GetBlobSize)(this, guidkey, pcbblobsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBlob(this: *mut core::ffi::c_void, guidkey: *const windows_core::GUID, pbuf: *mut u8, cbbufsize: u32, pcbblobsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "GetBlob", 15usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).GetBlob)(this, guidkey, pbuf, cbbufsize, pcbblobsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllocatedBlob(this: *mut core::ffi::c_void, guidkey: *const windows_core::GUID, ppbuf: *mut *mut u8, pcbsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "GetAllocatedBlob", 16usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).GetAllocatedBlob)(this, guidkey, ppbuf, pcbsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUnknown(this: *mut core::ffi::c_void, guidkey: *const windows_core::GUID, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "GetUnknown", 17usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).GetUnknown)(this, guidkey, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetItem(this: *mut core::ffi::c_void, guidkey: *const windows_core::GUID, value: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "SetItem", 18usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).SetItem)(this, guidkey, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteItem(this: *mut core::ffi::c_void, guidkey: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "DeleteItem", 19usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).DeleteItem)(this, guidkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAllItems(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "DeleteAllItems", 20usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).DeleteAllItems)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUINT32(this: *mut core::ffi::c_void, guidkey: *const windows_core::GUID, unvalue: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "SetUINT32", 21usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).SetUINT32)(this, guidkey, unvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUINT64(this: *mut core::ffi::c_void, guidkey: *const windows_core::GUID, unvalue: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "SetUINT64", 22usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).SetUINT64)(this, guidkey, unvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDouble(this: *mut core::ffi::c_void, guidkey: *const windows_core::GUID, fvalue: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "SetDouble", 23usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).SetDouble)(this, guidkey, fvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGUID(this: *mut core::ffi::c_void, guidkey: *const windows_core::GUID, guidvalue: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "SetGUID", 24usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).SetGUID)(this, guidkey, guidvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetString(this: *mut core::ffi::c_void, guidkey: *const windows_core::GUID, wszvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "SetString", 25usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).SetString)(this, guidkey, wszvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlob(this: *mut core::ffi::c_void, guidkey: *const windows_core::GUID, pbuf: *const u8, cbbufsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "SetBlob", 26usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).SetBlob)(this, guidkey, pbuf, cbbufsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUnknown(this: *mut core::ffi::c_void, guidkey: *const windows_core::GUID, punknown: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "SetUnknown", 27usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).SetUnknown)(this, guidkey, punknown), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockStore(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "LockStore", 28usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).LockStore)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockStore(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "UnlockStore", 29usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).UnlockStore)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcitems: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "GetCount", 30usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).GetCount)(this, pcitems), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItemByIndex(this: *mut core::ffi::c_void, unindex: u32, pguidkey: *mut windows_core::GUID, pvalue: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "GetItemByIndex", 31usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).GetItemByIndex)(this, unindex, pguidkey, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyAllItems(this: *mut core::ffi::c_void, pdest: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAttributes", "CopyAllItems", 32usize, |this| ((**(this as *const *const IMFAttributes_Vtbl)).CopyAllItems)(this, pdest), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetItem,
+            GetItemType,
+            CompareItem,
+            Compare,
+            GetUINT32,
+            GetUINT64,
+            GetDouble,
+            GetGUID,
+            GetStringLength,
+            GetString,
+            GetAllocatedString,
+            GetBlobSize,
+            GetBlob,
+            GetAllocatedBlob,
+            GetUnknown,
+            SetItem,
+            DeleteItem,
+            DeleteAllItems,
+            SetUINT32,
+            SetUINT64,
+            SetDouble,
+            SetGUID,
+            SetString,
+            SetBlob,
+            SetUnknown,
+            LockStore,
+            UnlockStore,
+            GetCount,
+            GetItemByIndex,
+            CopyAllItems,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFAttributes as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_Audio")]
+impl windows_core::imp::ProxyVtable for IMFAudioMediaType_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn GetAudioFormat(this: *mut core::ffi::c_void) -> *mut super::Audio::WAVEFORMATEX {
+            windows_core::imp::proxy_call(this, "IMFAudioMediaType", "GetAudioFormat", 38usize, |this| ((**(this as *const *const IMFAudioMediaType_Vtbl)).GetAudioFormat)(this), |_| None)
+        }
+        Self {
+            base__: <IMFMediaType_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_Audio")]
+            GetAudioFormat,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            GetAudioFormat: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFAudioMediaType as windows_core::Interface>::IID || <IMFMediaType_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFAudioPolicy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetGroupingParam(this: *mut core::ffi::c_void, rguidclass: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAudioPolicy", "SetGroupingParam", 3usize, |this| ((**(this as *const *const IMFAudioPolicy_Vtbl)).SetGroupingParam)(this, rguidclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGroupingParam(this: *mut core::ffi::c_void, pguidclass: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAudioPolicy", "GetGroupingParam", 4usize, |this| ((**(this as *const *const IMFAudioPolicy_Vtbl)).GetGroupingParam)(this, pguidclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAudioPolicy", "SetDisplayName", 5usize, |this| ((**(this as *const *const IMFAudioPolicy_Vtbl)).SetDisplayName)(this, pszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayName(this: *mut core::ffi::c_void, pszname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAudioPolicy", "GetDisplayName", 6usize, |this| ((**(this as *const *const IMFAudioPolicy_Vtbl)).GetDisplayName)(this, pszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIconPath(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAudioPolicy", "SetIconPath", 7usize, |this| ((**(this as *const *const IMFAudioPolicy_Vtbl)).SetIconPath)(this, pszpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIconPath(this: *mut core::ffi::c_void, pszpath: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAudioPolicy", "GetIconPath", 8usize, |this| ((**(this as *const *const IMFAudioPolicy_Vtbl)).GetIconPath)(this, pszpath), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetGroupingParam,
+            GetGroupingParam,
+            SetDisplayName,
+            GetDisplayName,
+            SetIconPath,
+            GetIconPath,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFAudioPolicy as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFAudioStreamVolume_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetChannelCount(this: *mut core::ffi::c_void, pdwcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAudioStreamVolume", "GetChannelCount", 3usize, |this| ((**(this as *const *const IMFAudioStreamVolume_Vtbl)).GetChannelCount)(this, pdwcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetChannelVolume(this: *mut core::ffi::c_void, dwindex: u32, flevel: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAudioStreamVolume", "SetChannelVolume", 4usize, |this| ((**(this as *const *const IMFAudioStreamVolume_Vtbl)).SetChannelVolume)(this, dwindex, flevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChannelVolume(this: *mut core::ffi::c_void, dwindex: u32, pflevel: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAudioStreamVolume", "GetChannelVolume", 5usize, |this| ((**(this as *const *const IMFAudioStreamVolume_Vtbl)).GetChannelVolume)(this, dwindex, pflevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllVolumes(this: *mut core::ffi::c_void, dwcount: u32, pfvolumes: *const f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAudioStreamVolume", "SetAllVolumes", 6usize, |this| ((**(this as *const *const IMFAudioStreamVolume_Vtbl)).SetAllVolumes)(this, dwcount, pfvolumes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllVolumes(this: *mut core::ffi::c_void, dwcount: u32, pfvolumes: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFAudioStreamVolume", "GetAllVolumes", 7usize, |this| ((**(this as *const *const IMFAudioStreamVolume_Vtbl)).GetAllVolumes)(this, dwcount, pfvolumes), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetChannelCount,
+            SetChannelVolume,
+            GetChannelVolume,
+            SetAllVolumes,
+            GetAllVolumes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFAudioStreamVolume as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFBufferListNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnAddSourceBuffer(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IMFBufferListNotify", "OnAddSourceBuffer", 3usize, |this| ((**(this as *const *const IMFBufferListNotify_Vtbl)).OnAddSourceBuffer)(this), |_| None)
+        }
+        unsafe extern "system" fn OnRemoveSourceBuffer(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IMFBufferListNotify", "OnRemoveSourceBuffer", 4usize, |this| ((**(this as *const *const IMFBufferListNotify_Vtbl)).OnRemoveSourceBuffer)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnAddSourceBuffer, OnRemoveSourceBuffer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFBufferListNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFByteStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCapabilities(this: *mut core::ffi::c_void, pdwcapabilities: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStream", "GetCapabilities", 3usize, |this| ((**(this as *const *const IMFByteStream_Vtbl)).GetCapabilities)(this, pdwcapabilities), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pqwlength: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStream", "GetLength", 4usize, |this| ((**(this as *const *const IMFByteStream_Vtbl)).GetLength)(this, pqwlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLength(this: *mut core::ffi::c_void, qwlength: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStream", "SetLength", 5usize, |this| ((**(this as *const *const IMFByteStream_Vtbl)).SetLength)(this, qwlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentPosition(this: *mut core::ffi::c_void, pqwposition: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStream", "GetCurrentPosition", 6usize, |this| ((**(this as *const *const IMFByteStream_Vtbl)).GetCurrentPosition)(this, pqwposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentPosition(this: *mut core::ffi::c_void, qwposition: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStream", "SetCurrentPosition", 7usize, |this| ((**(this as *const *const IMFByteStream_Vtbl)).SetCurrentPosition)(this, qwposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEndOfStream(this: *mut core::ffi::c_void, pfendofstream: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStream", "IsEndOfStream", 8usize, |this| ((**(this as *const *const IMFByteStream_Vtbl)).IsEndOfStream)(this, pfendofstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn Read(this: *mut core::ffi::c_void, pb: *mut u8, cb: u32, pcbread: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStream", "Read", 9usize, |this| ((**(this as *const *const IMFByteStream_Vtbl)).Read)(this, pb, cb, pcbread), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginRead(this: *mut core::ffi::c_void, pb: *mut u8, cb: u32, pcallback: *mut core::ffi::c_void, punkstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStream", "BeginRead", 10usize, |this| ((**(this as *const *const IMFByteStream_Vtbl)).BeginRead)(this, pb, cb, pcallback, punkstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndRead(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void, pcbread: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStream", "EndRead", 11usize, |this| ((**(this as *const *const IMFByteStream_Vtbl)).EndRead)(this, presult, pcbread), |result| Some(*result))
+        }
+        unsafe extern "system" fn Write(this: *mut core::ffi::c_void, pb: *const u8, cb: u32, pcbwritten: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStream", "Write", 12usize, |this| ((**(this as *const *const IMFByteStream_Vtbl)).Write)(this, pb, cb, pcbwritten), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginWrite(this: *mut core::ffi::c_void, pb: *const u8, cb: u32, pcallback: *mut core::ffi::c_void, punkstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStream", "BeginWrite", 13usize, |this| ((**(this as *const *const IMFByteStream_Vtbl)).BeginWrite)(this, pb, cb, pcallback, punkstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndWrite(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void, pcbwritten: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStream", "EndWrite", 14usize, |this| ((**(this as *const *const IMFByteStream_Vtbl)).EndWrite)(this, presult, pcbwritten), |result| Some(*result))
+        }
+        unsafe extern "system" fn Seek(this: *mut core::ffi::c_void, seekorigin: MFBYTESTREAM_SEEK_ORIGIN, llseekoffset: i64, dwseekflags: u32, pqwcurrentposition: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStream", "Seek", 15usize, |this| ((**(this as *const *const IMFByteStream_Vtbl)).Seek)(this, seekorigin, llseekoffset, dwseekflags, pqwcurrentposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flush(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStream", "Flush", 16usize, |this| ((**(this as *const *const IMFByteStream_Vtbl)).Flush)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStream", "Close", 17usize, |this| ((**(this as *const *const IMFByteStream_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCapabilities,
+            GetLength,
+            SetLength,
+            GetCurrentPosition,
+            SetCurrentPosition,
+            IsEndOfStream,
+            Read,
+            BeginRead,
+            EndRead,
+            Write,
+            BeginWrite,
+            EndWrite,
+            Seek,
+            Flush,
+            Close,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFByteStream as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFByteStreamBuffering_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetBufferingParams(this: *mut core::ffi::c_void, pparams: *const MFBYTESTREAM_BUFFERING_PARAMS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStreamBuffering", "SetBufferingParams", 3usize, |this| ((**(this as *const *const IMFByteStreamBuffering_Vtbl)).SetBufferingParams)(this, pparams), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableBuffering(this: *mut core::ffi::c_void, fenable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStreamBuffering", "EnableBuffering", 4usize, |this| ((**(this as *const *const IMFByteStreamBuffering_Vtbl)).EnableBuffering)(this, fenable), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopBuffering(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStreamBuffering", "StopBuffering", 5usize, |this| ((**(this as *const *const IMFByteStreamBuffering_Vtbl)).StopBuffering)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetBufferingParams, EnableBuffering, StopBuffering }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFByteStreamBuffering as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFByteStreamCacheControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StopBackgroundTransfer(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStreamCacheControl", "StopBackgroundTransfer", 3usize, |this| ((**(this as *const *const IMFByteStreamCacheControl_Vtbl)).StopBackgroundTransfer)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StopBackgroundTransfer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFByteStreamCacheControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFByteStreamCacheControl2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetByteRanges(this: *mut core::ffi::c_void, pcranges: *mut u32, ppranges: *mut *mut MF_BYTE_STREAM_CACHE_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStreamCacheControl2", "GetByteRanges", 4usize, |this| ((**(this as *const *const IMFByteStreamCacheControl2_Vtbl)).GetByteRanges)(this, pcranges, ppranges), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCacheLimit(this: *mut core::ffi::c_void, qwbytes: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStreamCacheControl2", "SetCacheLimit", 5usize, |this| ((**(this as *const *const IMFByteStreamCacheControl2_Vtbl)).SetCacheLimit)(this, qwbytes), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsBackgroundTransferActive(this: *mut core::ffi::c_void, pfactive: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStreamCacheControl2", "IsBackgroundTransferActive", 6usize, |this| ((**(this as *const *const IMFByteStreamCacheControl2_Vtbl)).IsBackgroundTransferActive)(this, pfactive), |result| Some(*result))
+        }
+        Self { base__: <IMFByteStreamCacheControl_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetByteRanges, SetCacheLimit, IsBackgroundTransferActive }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFByteStreamCacheControl2 as windows_core::Interface>::IID || <IMFByteStreamCacheControl_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IMFByteStreamHandler_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn BeginCreateObject(this: *mut core::ffi::c_void, pbytestream: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, dwflags: u32, pprops: *mut core::ffi::c_void, ppiunknowncancelcookie: *mut *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, punkstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStreamHandler", "BeginCreateObject", 3usize, |this| ((**(this as *const *const IMFByteStreamHandler_Vtbl)).BeginCreateObject)(this, pbytestream, pwszurl, dwflags, pprops, ppiunknowncancelcookie, pcallback, punkstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndCreateObject(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void, pobjecttype: *mut MF_OBJECT_TYPE, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStreamHandler", "EndCreateObject", 4usize, |this| ((**(this as *const *const IMFByteStreamHandler_Vtbl)).EndCreateObject)(this, presult, pobjecttype, ppobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelObjectCreation(this: *mut core::ffi::c_void, piunknowncancelcookie: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStreamHandler", "CancelObjectCreation", 5usize, |this| ((**(this as *const *const IMFByteStreamHandler_Vtbl)).CancelObjectCreation)(this, piunknowncancelcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxNumberOfBytesRequiredForResolution(this: *mut core::ffi::c_void, pqwbytes: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStreamHandler", "GetMaxNumberOfBytesRequiredForResolution", 6usize, |this| ((**(this as *const *const IMFByteStreamHandler_Vtbl)).GetMaxNumberOfBytesRequiredForResolution)(this, pqwbytes), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            BeginCreateObject,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            BeginCreateObject: 0,
+            EndCreateObject,
+            CancelObjectCreation,
+            GetMaxNumberOfBytesRequiredForResolution,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFByteStreamHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFByteStreamProxyClassFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateByteStreamProxy(this: *mut core::ffi::c_void, pbytestream: *mut core::ffi::c_void, pattributes: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStreamProxyClassFactory", "CreateByteStreamProxy", 3usize, |this| ((**(this as *const *const IMFByteStreamProxyClassFactory_Vtbl)).CreateByteStreamProxy)(this, pbytestream, pattributes, riid, ppvobject), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateByteStreamProxy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFByteStreamProxyClassFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFByteStreamTimeSeek_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsTimeSeekSupported(this: *mut core::ffi::c_void, pftimeseekissupported: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStreamTimeSeek", "IsTimeSeekSupported", 3usize, |this| ((**(this as *const *const IMFByteStreamTimeSeek_Vtbl)).IsTimeSeekSupported)(this, pftimeseekissupported), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimeSeek(this: *mut core::ffi::c_void, qwtimeposition: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStreamTimeSeek", "TimeSeek", 4usize, |this| ((**(this as *const *const IMFByteStreamTimeSeek_Vtbl)).TimeSeek)(this, qwtimeposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTimeSeekResult(this: *mut core::ffi::c_void, pqwstarttime: *mut u64, pqwstoptime: *mut u64, pqwduration: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFByteStreamTimeSeek", "GetTimeSeekResult", 5usize, |this| ((**(this as *const *const IMFByteStreamTimeSeek_Vtbl)).GetTimeSeekResult)(this, pqwstarttime, pqwstoptime, pqwduration), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsTimeSeekSupported, TimeSeek, GetTimeSeekResult }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFByteStreamTimeSeek as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFCameraConfigurationManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LoadDefaults(this: *mut core::ffi::c_void, cameraattributes: *mut core::ffi::c_void, configurations: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCameraConfigurationManager", "LoadDefaults", 3usize, |this| ((**(this as *const *const IMFCameraConfigurationManager_Vtbl)).LoadDefaults)(this, cameraattributes, configurations), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveDefaults(this: *mut core::ffi::c_void, configurations: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCameraConfigurationManager", "SaveDefaults", 4usize, |this| ((**(this as *const *const IMFCameraConfigurationManager_Vtbl)).SaveDefaults)(this, configurations), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IMFCameraConfigurationManager", "Shutdown", 5usize, |this| ((**(this as *const *const IMFCameraConfigurationManager_Vtbl)).Shutdown)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LoadDefaults, SaveDefaults, Shutdown }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFCameraConfigurationManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFCameraControlDefaults_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void) -> MF_CAMERA_CONTROL_CONFIGURATION_TYPE {
+            windows_core::imp::proxy_call(this, "IMFCameraControlDefaults", "GetType", 3usize, |this| ((**(this as *const *const IMFCameraControlDefaults_Vtbl)).GetType)(this), |_| None)
+        }
+        unsafe extern "system" fn GetRangeInfo(this: *mut core::ffi::c_void, rangeinfo: *mut MF_CAMERA_CONTROL_RANGE_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCameraControlDefaults", "GetRangeInfo", 4usize, |this| ((**(this as *const *const IMFCameraControlDefaults_Vtbl)).GetRangeInfo)(this, rangeinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockControlData(this: *mut core::ffi::c_void, control: *mut *mut core::ffi::c_void, controlsize: *mut u32, data: *mut *mut core::ffi::c_void, datasize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCameraControlDefaults", "LockControlData", 5usize, |this| ((**(this as *const *const IMFCameraControlDefaults_Vtbl)).LockControlData)(this, control, controlsize, data, datasize), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockControlData(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCameraControlDefaults", "UnlockControlData", 6usize, |this| ((**(this as *const *const IMFCameraControlDefaults_Vtbl)).UnlockControlData)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetType, GetRangeInfo, LockControlData, UnlockControlData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFCameraControlDefaults as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFCameraControlDefaultsCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetControlCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IMFCameraControlDefaultsCollection", "GetControlCount", 33usize, |this| ((**(this as *const *const IMFCameraControlDefaultsCollection_Vtbl)).GetControlCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetControl(this: *mut core::ffi::c_void, index: u32, configuration: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCameraControlDefaultsCollection", "GetControl", 34usize, |this| ((**(this as *const *const IMFCameraControlDefaultsCollection_Vtbl)).GetControl)(this, index, configuration), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOrAddExtendedControl(this: *mut core::ffi::c_void, configtype: MF_CAMERA_CONTROL_CONFIGURATION_TYPE, constrolid: u32, streamid: u32, datasize: u32, defaults: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCameraControlDefaultsCollection", "GetOrAddExtendedControl", 35usize, |this| ((**(this as *const *const IMFCameraControlDefaultsCollection_Vtbl)).GetOrAddExtendedControl)(this, configtype, constrolid, streamid, datasize, defaults), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOrAddControl(this: *mut core::ffi::c_void, configtype: MF_CAMERA_CONTROL_CONFIGURATION_TYPE, controlset: *const windows_core::GUID, constrolid: u32, controlsize: u32, datasize: u32, defaults: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCameraControlDefaultsCollection", "GetOrAddControl", 36usize, |this| ((**(this as *const *const IMFCameraControlDefaultsCollection_Vtbl)).GetOrAddControl)(this, configtype, controlset, constrolid, controlsize, datasize, defaults), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveControl(this: *mut core::ffi::c_void, controlset: *const windows_core::GUID, constrolid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCameraControlDefaultsCollection", "RemoveControl", 37usize, |this| ((**(this as *const *const IMFCameraControlDefaultsCollection_Vtbl)).RemoveControl)(this, controlset, constrolid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAllControls(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCameraControlDefaultsCollection", "RemoveAllControls", 38usize, |this| ((**(this as *const *const IMFCameraControlDefaultsCollection_Vtbl)).RemoveAllControls)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetControlCount,
+            GetControl,
+            GetOrAddExtendedControl,
+            GetOrAddControl,
+            RemoveControl,
+            RemoveAllControls,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFCameraControlDefaultsCollection as windows_core::Interface>::IID || <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFCameraControlMonitor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCameraControlMonitor", "Start", 3usize, |this| ((**(this as *const *const IMFCameraControlMonitor_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCameraControlMonitor", "Stop", 4usize, |this| ((**(this as *const *const IMFCameraControlMonitor_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddControlSubscription(this: *mut core::ffi::c_void, controlset: windows_core::GUID, id: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCameraControlMonitor", "AddControlSubscription", 5usize, |this| ((**(this as *const *const IMFCameraControlMonitor_Vtbl)).AddControlSubscription)(this, controlset, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveControlSubscription(this: *mut core::ffi::c_void, controlset: windows_core::GUID, id: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCameraControlMonitor", "RemoveControlSubscription", 6usize, |this| ((**(this as *const *const IMFCameraControlMonitor_Vtbl)).RemoveControlSubscription)(this, controlset, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IMFCameraControlMonitor", "Shutdown", 7usize, |this| ((**(this as *const *const IMFCameraControlMonitor_Vtbl)).Shutdown)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Start,
+            Stop,
+            AddControlSubscription,
+            RemoveControlSubscription,
+            Shutdown,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFCameraControlMonitor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFCameraControlNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnChange(this: *mut core::ffi::c_void, controlset: *const windows_core::GUID, id: u32) {
+            windows_core::imp::proxy_call(this, "IMFCameraControlNotify", "OnChange", 3usize, |this| ((**(this as *const *const IMFCameraControlNotify_Vtbl)).OnChange)(this, controlset, id), |_| None)
+        }
+        unsafe extern "system" fn OnError(this: *mut core::ffi::c_void, hrstatus: windows_core::HRESULT) {
+            windows_core::imp::proxy_call(this, "IMFCameraControlNotify", "OnError", 4usize, |this| ((**(this as *const *const IMFCameraControlNotify_Vtbl)).OnError)(this, hrstatus), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnChange, OnError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFCameraControlNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFCameraOcclusionStateMonitor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCameraOcclusionStateMonitor", "Start", 3usize, |this| ((**(this as *const *const IMFCameraOcclusionStateMonitor_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCameraOcclusionStateMonitor", "Stop", 4usize, |this| ((**(this as *const *const IMFCameraOcclusionStateMonitor_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSupportedStates(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IMFCameraOcclusionStateMonitor", "GetSupportedStates", 5usize, |this| ((**(this as *const *const IMFCameraOcclusionStateMonitor_Vtbl)).GetSupportedStates)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Start, Stop, GetSupportedStates }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFCameraOcclusionStateMonitor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFCameraOcclusionStateReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOcclusionState(this: *mut core::ffi::c_void, occlusionstate: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCameraOcclusionStateReport", "GetOcclusionState", 3usize, |this| ((**(this as *const *const IMFCameraOcclusionStateReport_Vtbl)).GetOcclusionState)(this, occlusionstate), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetOcclusionState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFCameraOcclusionStateReport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFCameraOcclusionStateReportCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnOcclusionStateReport(this: *mut core::ffi::c_void, occlusionstatereport: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCameraOcclusionStateReportCallback", "OnOcclusionStateReport", 3usize, |this| ((**(this as *const *const IMFCameraOcclusionStateReportCallback_Vtbl)).OnOcclusionStateReport)(this, occlusionstatereport), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnOcclusionStateReport }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFCameraOcclusionStateReportCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFCameraSyncObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WaitOnSignal(this: *mut core::ffi::c_void, timeoutinms: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCameraSyncObject", "WaitOnSignal", 3usize, |this| ((**(this as *const *const IMFCameraSyncObject_Vtbl)).WaitOnSignal)(this, timeoutinms), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IMFCameraSyncObject", "Shutdown", 4usize, |this| ((**(this as *const *const IMFCameraSyncObject_Vtbl)).Shutdown)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WaitOnSignal, Shutdown }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFCameraSyncObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFCaptureEngine_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, peventcallback: *mut core::ffi::c_void, pattributes: *mut core::ffi::c_void, paudiosource: *mut core::ffi::c_void, pvideosource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureEngine", "Initialize", 3usize, |this| ((**(this as *const *const IMFCaptureEngine_Vtbl)).Initialize)(this, peventcallback, pattributes, paudiosource, pvideosource), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartPreview(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureEngine", "StartPreview", 4usize, |this| ((**(this as *const *const IMFCaptureEngine_Vtbl)).StartPreview)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopPreview(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureEngine", "StopPreview", 5usize, |this| ((**(this as *const *const IMFCaptureEngine_Vtbl)).StopPreview)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartRecord(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureEngine", "StartRecord", 6usize, |this| ((**(this as *const *const IMFCaptureEngine_Vtbl)).StartRecord)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopRecord(this: *mut core::ffi::c_void, bfinalize: super::super::Foundation::BOOL, bflushunprocessedsamples: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureEngine", "StopRecord", 7usize, |this| ((**(this as *const *const IMFCaptureEngine_Vtbl)).StopRecord)(this, bfinalize, bflushunprocessedsamples), |result| Some(*result))
+        }
+        unsafe extern "system" fn TakePhoto(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureEngine", "TakePhoto", 8usize, |this| ((**(this as *const *const IMFCaptureEngine_Vtbl)).TakePhoto)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSink(this: *mut core::ffi::c_void, mfcaptureenginesinktype: MF_CAPTURE_ENGINE_SINK_TYPE, ppsink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureEngine", "GetSink", 9usize, |this| ((**(this as *const *const IMFCaptureEngine_Vtbl)).GetSink)(this, mfcaptureenginesinktype, ppsink), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSource(this: *mut core::ffi::c_void, ppsource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureEngine", "GetSource", 10usize, |this| ((**(this as *const *const IMFCaptureEngine_Vtbl)).GetSource)(this, ppsource), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            StartPreview,
+            StopPreview,
+            StartRecord,
+            StopRecord,
+            TakePhoto,
+            GetSink,
+            GetSource,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFCaptureEngine as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFCaptureEngineClassFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, clsid: *const windows_core::GUID, riid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureEngineClassFactory", "CreateInstance", 3usize, |this| ((**(this as *const *const IMFCaptureEngineClassFactory_Vtbl)).CreateInstance)(this, clsid, riid, ppvobject), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFCaptureEngineClassFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFCaptureEngineOnEventCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnEvent(this: *mut core::ffi::c_void, pevent: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureEngineOnEventCallback", "OnEvent", 3usize, |this| ((**(this as *const *const IMFCaptureEngineOnEventCallback_Vtbl)).OnEvent)(this, pevent), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFCaptureEngineOnEventCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFCaptureEngineOnSampleCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnSample(this: *mut core::ffi::c_void, psample: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureEngineOnSampleCallback", "OnSample", 3usize, |this| ((**(this as *const *const IMFCaptureEngineOnSampleCallback_Vtbl)).OnSample)(this, psample), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnSample }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFCaptureEngineOnSampleCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFCaptureEngineOnSampleCallback2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnSynchronizedEvent(this: *mut core::ffi::c_void, pevent: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureEngineOnSampleCallback2", "OnSynchronizedEvent", 4usize, |this| ((**(this as *const *const IMFCaptureEngineOnSampleCallback2_Vtbl)).OnSynchronizedEvent)(this, pevent), |result| Some(*result))
+        }
+        Self { base__: <IMFCaptureEngineOnSampleCallback_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnSynchronizedEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFCaptureEngineOnSampleCallback2 as windows_core::Interface>::IID || <IMFCaptureEngineOnSampleCallback_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFCapturePhotoConfirmation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPhotoConfirmationCallback(this: *mut core::ffi::c_void, pnotificationcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCapturePhotoConfirmation", "SetPhotoConfirmationCallback", 3usize, |this| ((**(this as *const *const IMFCapturePhotoConfirmation_Vtbl)).SetPhotoConfirmationCallback)(this, pnotificationcallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPixelFormat(this: *mut core::ffi::c_void, subtype: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCapturePhotoConfirmation", "SetPixelFormat", 4usize, |this| ((**(this as *const *const IMFCapturePhotoConfirmation_Vtbl)).SetPixelFormat)(this, subtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPixelFormat(this: *mut core::ffi::c_void, subtype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCapturePhotoConfirmation", "GetPixelFormat", 5usize, |this| ((**(this as *const *const IMFCapturePhotoConfirmation_Vtbl)).GetPixelFormat)(this, subtype), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetPhotoConfirmationCallback, SetPixelFormat, GetPixelFormat }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFCapturePhotoConfirmation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFCapturePhotoSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetOutputFileName(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCapturePhotoSink", "SetOutputFileName", 8usize, |this| ((**(this as *const *const IMFCapturePhotoSink_Vtbl)).SetOutputFileName)(this, filename), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSampleCallback(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCapturePhotoSink", "SetSampleCallback", 9usize, |this| ((**(this as *const *const IMFCapturePhotoSink_Vtbl)).SetSampleCallback)(this, pcallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputByteStream(this: *mut core::ffi::c_void, pbytestream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCapturePhotoSink", "SetOutputByteStream", 10usize, |this| ((**(this as *const *const IMFCapturePhotoSink_Vtbl)).SetOutputByteStream)(this, pbytestream), |result| Some(*result))
+        }
+        Self { base__: <IMFCaptureSink_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetOutputFileName, SetSampleCallback, SetOutputByteStream }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFCapturePhotoSink as windows_core::Interface>::IID || <IMFCaptureSink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFCapturePreviewSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetRenderHandle(this: *mut core::ffi::c_void, handle: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCapturePreviewSink", "SetRenderHandle", 8usize, |this| ((**(this as *const *const IMFCapturePreviewSink_Vtbl)).SetRenderHandle)(this, handle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRenderSurface(this: *mut core::ffi::c_void, psurface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCapturePreviewSink", "SetRenderSurface", 9usize, |this| ((**(this as *const *const IMFCapturePreviewSink_Vtbl)).SetRenderSurface)(this, psurface), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateVideo(this: *mut core::ffi::c_void, psrc: *const MFVideoNormalizedRect, pdst: *const super::super::Foundation::RECT, pborderclr: *const super::super::Foundation::COLORREF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCapturePreviewSink", "UpdateVideo", 10usize, |this| ((**(this as *const *const IMFCapturePreviewSink_Vtbl)).UpdateVideo)(this, psrc, pdst, pborderclr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSampleCallback(this: *mut core::ffi::c_void, dwstreamsinkindex: u32, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCapturePreviewSink", "SetSampleCallback", 11usize, |this| ((**(this as *const *const IMFCapturePreviewSink_Vtbl)).SetSampleCallback)(this, dwstreamsinkindex, pcallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMirrorState(this: *mut core::ffi::c_void, pfmirrorstate: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCapturePreviewSink", "GetMirrorState", 12usize, |this| ((**(this as *const *const IMFCapturePreviewSink_Vtbl)).GetMirrorState)(this, pfmirrorstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMirrorState(this: *mut core::ffi::c_void, fmirrorstate: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCapturePreviewSink", "SetMirrorState", 13usize, |this| ((**(this as *const *const IMFCapturePreviewSink_Vtbl)).SetMirrorState)(this, fmirrorstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRotation(this: *mut core::ffi::c_void, dwstreamindex: u32, pdwrotationvalue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCapturePreviewSink", "GetRotation", 14usize, |this| ((**(this as *const *const IMFCapturePreviewSink_Vtbl)).GetRotation)(this, dwstreamindex, pdwrotationvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRotation(this: *mut core::ffi::c_void, dwstreamindex: u32, dwrotationvalue: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCapturePreviewSink", "SetRotation", 15usize, |this| ((**(this as *const *const IMFCapturePreviewSink_Vtbl)).SetRotation)(this, dwstreamindex, dwrotationvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCustomSink(this: *mut core::ffi::c_void, pmediasink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCapturePreviewSink", "SetCustomSink", 16usize, |this| ((**(this as *const *const IMFCapturePreviewSink_Vtbl)).SetCustomSink)(this, pmediasink), |result| Some(*result))
+        }
+        Self {
+            base__: <IMFCaptureSink_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetRenderHandle,
+            SetRenderSurface,
+            UpdateVideo,
+            SetSampleCallback,
+            GetMirrorState,
+            SetMirrorState,
+            GetRotation,
+            SetRotation,
+            SetCustomSink,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFCapturePreviewSink as windows_core::Interface>::IID || <IMFCaptureSink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFCaptureRecordSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetOutputByteStream(this: *mut core::ffi::c_void, pbytestream: *mut core::ffi::c_void, guidcontainertype: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureRecordSink", "SetOutputByteStream", 8usize, |this| ((**(this as *const *const IMFCaptureRecordSink_Vtbl)).SetOutputByteStream)(this, pbytestream, guidcontainertype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputFileName(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureRecordSink", "SetOutputFileName", 9usize, |this| ((**(this as *const *const IMFCaptureRecordSink_Vtbl)).SetOutputFileName)(this, filename), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSampleCallback(this: *mut core::ffi::c_void, dwstreamsinkindex: u32, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureRecordSink", "SetSampleCallback", 10usize, |this| ((**(this as *const *const IMFCaptureRecordSink_Vtbl)).SetSampleCallback)(this, dwstreamsinkindex, pcallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCustomSink(this: *mut core::ffi::c_void, pmediasink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureRecordSink", "SetCustomSink", 11usize, |this| ((**(this as *const *const IMFCaptureRecordSink_Vtbl)).SetCustomSink)(this, pmediasink), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRotation(this: *mut core::ffi::c_void, dwstreamindex: u32, pdwrotationvalue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureRecordSink", "GetRotation", 12usize, |this| ((**(this as *const *const IMFCaptureRecordSink_Vtbl)).GetRotation)(this, dwstreamindex, pdwrotationvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRotation(this: *mut core::ffi::c_void, dwstreamindex: u32, dwrotationvalue: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureRecordSink", "SetRotation", 13usize, |this| ((**(this as *const *const IMFCaptureRecordSink_Vtbl)).SetRotation)(this, dwstreamindex, dwrotationvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <IMFCaptureSink_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetOutputByteStream,
+            SetOutputFileName,
+            SetSampleCallback,
+            SetCustomSink,
+            GetRotation,
+            SetRotation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFCaptureRecordSink as windows_core::Interface>::IID || <IMFCaptureSink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFCaptureSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOutputMediaType(this: *mut core::ffi::c_void, dwsinkstreamindex: u32, ppmediatype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureSink", "GetOutputMediaType", 3usize, |this| ((**(this as *const *const IMFCaptureSink_Vtbl)).GetOutputMediaType)(this, dwsinkstreamindex, ppmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetService(this: *mut core::ffi::c_void, dwsinkstreamindex: u32, rguidservice: *const windows_core::GUID, riid: *const windows_core::GUID, ppunknown: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureSink", "GetService", 4usize, |this| ((**(this as *const *const IMFCaptureSink_Vtbl)).GetService)(this, dwsinkstreamindex, rguidservice, riid, ppunknown), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddStream(this: *mut core::ffi::c_void, dwsourcestreamindex: u32, pmediatype: *mut core::ffi::c_void, pattributes: *mut core::ffi::c_void, pdwsinkstreamindex: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureSink", "AddStream", 5usize, |this| ((**(this as *const *const IMFCaptureSink_Vtbl)).AddStream)(this, dwsourcestreamindex, pmediatype, pattributes, pdwsinkstreamindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn Prepare(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureSink", "Prepare", 6usize, |this| ((**(this as *const *const IMFCaptureSink_Vtbl)).Prepare)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAllStreams(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureSink", "RemoveAllStreams", 7usize, |this| ((**(this as *const *const IMFCaptureSink_Vtbl)).RemoveAllStreams)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetOutputMediaType,
+            GetService,
+            AddStream,
+            Prepare,
+            RemoveAllStreams,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFCaptureSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFCaptureSink2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetOutputMediaType(this: *mut core::ffi::c_void, dwstreamindex: u32, pmediatype: *mut core::ffi::c_void, pencodingattributes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureSink2", "SetOutputMediaType", 8usize, |this| ((**(this as *const *const IMFCaptureSink2_Vtbl)).SetOutputMediaType)(this, dwstreamindex, pmediatype, pencodingattributes), |result| Some(*result))
+        }
+        Self { base__: <IMFCaptureSink_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetOutputMediaType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFCaptureSink2 as windows_core::Interface>::IID || <IMFCaptureSink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFCaptureSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCaptureDeviceSource(this: *mut core::ffi::c_void, mfcaptureenginedevicetype: MF_CAPTURE_ENGINE_DEVICE_TYPE, ppmediasource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureSource", "GetCaptureDeviceSource", 3usize, |this| ((**(this as *const *const IMFCaptureSource_Vtbl)).GetCaptureDeviceSource)(this, mfcaptureenginedevicetype, ppmediasource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCaptureDeviceActivate(this: *mut core::ffi::c_void, mfcaptureenginedevicetype: MF_CAPTURE_ENGINE_DEVICE_TYPE, ppactivate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureSource", "GetCaptureDeviceActivate", 4usize, |this| ((**(this as *const *const IMFCaptureSource_Vtbl)).GetCaptureDeviceActivate)(this, mfcaptureenginedevicetype, ppactivate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetService(this: *mut core::ffi::c_void, rguidservice: *const windows_core::GUID, riid: *const windows_core::GUID, ppunknown: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureSource", "GetService", 5usize, |this| ((**(this as *const *const IMFCaptureSource_Vtbl)).GetService)(this, rguidservice, riid, ppunknown), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddEffect(this: *mut core::ffi::c_void, dwsourcestreamindex: u32, punknown: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureSource", "AddEffect", 6usize, |this| ((**(this as *const *const IMFCaptureSource_Vtbl)).AddEffect)(this, dwsourcestreamindex, punknown), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEffect(this: *mut core::ffi::c_void, dwsourcestreamindex: u32, punknown: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureSource", "RemoveEffect", 7usize, |this| ((**(this as *const *const IMFCaptureSource_Vtbl)).RemoveEffect)(this, dwsourcestreamindex, punknown), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAllEffects(this: *mut core::ffi::c_void, dwsourcestreamindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureSource", "RemoveAllEffects", 8usize, |this| ((**(this as *const *const IMFCaptureSource_Vtbl)).RemoveAllEffects)(this, dwsourcestreamindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAvailableDeviceMediaType(this: *mut core::ffi::c_void, dwsourcestreamindex: u32, dwmediatypeindex: u32, ppmediatype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureSource", "GetAvailableDeviceMediaType", 9usize, |this| ((**(this as *const *const IMFCaptureSource_Vtbl)).GetAvailableDeviceMediaType)(this, dwsourcestreamindex, dwmediatypeindex, ppmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentDeviceMediaType(this: *mut core::ffi::c_void, dwsourcestreamindex: u32, pmediatype: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureSource", "SetCurrentDeviceMediaType", 10usize, |this| ((**(this as *const *const IMFCaptureSource_Vtbl)).SetCurrentDeviceMediaType)(this, dwsourcestreamindex, pmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentDeviceMediaType(this: *mut core::ffi::c_void, dwsourcestreamindex: u32, ppmediatype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureSource", "GetCurrentDeviceMediaType", 11usize, |this| ((**(this as *const *const IMFCaptureSource_Vtbl)).GetCurrentDeviceMediaType)(this, dwsourcestreamindex, ppmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceStreamCount(this: *mut core::ffi::c_void, pdwstreamcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureSource", "GetDeviceStreamCount", 12usize, |this| ((**(this as *const *const IMFCaptureSource_Vtbl)).GetDeviceStreamCount)(this, pdwstreamcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceStreamCategory(this: *mut core::ffi::c_void, dwsourcestreamindex: u32, pstreamcategory: *mut MF_CAPTURE_ENGINE_STREAM_CATEGORY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureSource", "GetDeviceStreamCategory", 13usize, |this| ((**(this as *const *const IMFCaptureSource_Vtbl)).GetDeviceStreamCategory)(this, dwsourcestreamindex, pstreamcategory), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMirrorState(this: *mut core::ffi::c_void, dwstreamindex: u32, pfmirrorstate: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureSource", "GetMirrorState", 14usize, |this| ((**(this as *const *const IMFCaptureSource_Vtbl)).GetMirrorState)(this, dwstreamindex, pfmirrorstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMirrorState(this: *mut core::ffi::c_void, dwstreamindex: u32, fmirrorstate: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureSource", "SetMirrorState", 15usize, |this| ((**(this as *const *const IMFCaptureSource_Vtbl)).SetMirrorState)(this, dwstreamindex, fmirrorstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamIndexFromFriendlyName(this: *mut core::ffi::c_void, uifriendlyname: u32, pdwactualstreamindex: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCaptureSource", "GetStreamIndexFromFriendlyName", 16usize, |this| ((**(this as *const *const IMFCaptureSource_Vtbl)).GetStreamIndexFromFriendlyName)(this, uifriendlyname, pdwactualstreamindex), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCaptureDeviceSource,
+            GetCaptureDeviceActivate,
+            GetService,
+            AddEffect,
+            RemoveEffect,
+            RemoveAllEffects,
+            GetAvailableDeviceMediaType,
+            SetCurrentDeviceMediaType,
+            GetCurrentDeviceMediaType,
+            GetDeviceStreamCount,
+            GetDeviceStreamCategory,
+            GetMirrorState,
+            SetMirrorState,
+            GetStreamIndexFromFriendlyName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFCaptureSource as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFCdmSuspendNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Begin(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCdmSuspendNotify", "Begin", 3usize, |this| ((**(this as *const *const IMFCdmSuspendNotify_Vtbl)).Begin)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn End(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCdmSuspendNotify", "End", 4usize, |this| ((**(this as *const *const IMFCdmSuspendNotify_Vtbl)).End)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Begin, End }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFCdmSuspendNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFClock_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetClockCharacteristics(this: *mut core::ffi::c_void, pdwcharacteristics: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFClock", "GetClockCharacteristics", 3usize, |this| ((**(this as *const *const IMFClock_Vtbl)).GetClockCharacteristics)(this, pdwcharacteristics), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCorrelatedTime(this: *mut core::ffi::c_void, dwreserved: u32, pllclocktime: *mut i64, phnssystemtime: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFClock", "GetCorrelatedTime", 4usize, |this| ((**(this as *const *const IMFClock_Vtbl)).GetCorrelatedTime)(this, dwreserved, pllclocktime, phnssystemtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContinuityKey(this: *mut core::ffi::c_void, pdwcontinuitykey: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFClock", "GetContinuityKey", 5usize, |this| ((**(this as *const *const IMFClock_Vtbl)).GetContinuityKey)(this, pdwcontinuitykey), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetState(this: *mut core::ffi::c_void, dwreserved: u32, peclockstate: *mut MFCLOCK_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFClock", "GetState", 6usize, |this| ((**(this as *const *const IMFClock_Vtbl)).GetState)(this, dwreserved, peclockstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, pclockproperties: *mut MFCLOCK_PROPERTIES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFClock", "GetProperties", 7usize, |this| ((**(this as *const *const IMFClock_Vtbl)).GetProperties)(this, pclockproperties), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetClockCharacteristics,
+            GetCorrelatedTime,
+            GetContinuityKey,
+            GetState,
+            GetProperties,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFClock as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFClockConsumer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPresentationClock(this: *mut core::ffi::c_void, ppresentationclock: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFClockConsumer", "SetPresentationClock", 3usize, |this| ((**(this as *const *const IMFClockConsumer_Vtbl)).SetPresentationClock)(this, ppresentationclock), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPresentationClock(this: *mut core::ffi::c_void, pppresentationclock: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFClockConsumer", "GetPresentationClock", 4usize, |this| ((**(this as *const *const IMFClockConsumer_Vtbl)).GetPresentationClock)(this, pppresentationclock), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetPresentationClock, GetPresentationClock }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFClockConsumer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFClockStateSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnClockStart(this: *mut core::ffi::c_void, hnssystemtime: i64, llclockstartoffset: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFClockStateSink", "OnClockStart", 3usize, |this| ((**(this as *const *const IMFClockStateSink_Vtbl)).OnClockStart)(this, hnssystemtime, llclockstartoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnClockStop(this: *mut core::ffi::c_void, hnssystemtime: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFClockStateSink", "OnClockStop", 4usize, |this| ((**(this as *const *const IMFClockStateSink_Vtbl)).OnClockStop)(this, hnssystemtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnClockPause(this: *mut core::ffi::c_void, hnssystemtime: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFClockStateSink", "OnClockPause", 5usize, |this| ((**(this as *const *const IMFClockStateSink_Vtbl)).OnClockPause)(this, hnssystemtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnClockRestart(this: *mut core::ffi::c_void, hnssystemtime: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFClockStateSink", "OnClockRestart", 6usize, |this| ((**(this as *const *const IMFClockStateSink_Vtbl)).OnClockRestart)(this, hnssystemtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnClockSetRate(this: *mut core::ffi::c_void, hnssystemtime: i64, flrate: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFClockStateSink", "OnClockSetRate", 7usize, |this| ((**(this as *const *const IMFClockStateSink_Vtbl)).OnClockSetRate)(this, hnssystemtime, flrate), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnClockStart,
+            OnClockStop,
+            OnClockPause,
+            OnClockRestart,
+            OnClockSetRate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFClockStateSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetElementCount(this: *mut core::ffi::c_void, pcelements: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCollection", "GetElementCount", 3usize, |this| ((**(this as *const *const IMFCollection_Vtbl)).GetElementCount)(this, pcelements), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetElement(this: *mut core::ffi::c_void, dwelementindex: u32, ppunkelement: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCollection", "GetElement", 4usize, |this| ((**(this as *const *const IMFCollection_Vtbl)).GetElement)(this, dwelementindex, ppunkelement), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddElement(this: *mut core::ffi::c_void, punkelement: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCollection", "AddElement", 5usize, |this| ((**(this as *const *const IMFCollection_Vtbl)).AddElement)(this, punkelement), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveElement(this: *mut core::ffi::c_void, dwelementindex: u32, ppunkelement: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCollection", "RemoveElement", 6usize, |this| ((**(this as *const *const IMFCollection_Vtbl)).RemoveElement)(this, dwelementindex, ppunkelement), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertElementAt(this: *mut core::ffi::c_void, dwindex: u32, punknown: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCollection", "InsertElementAt", 7usize, |this| ((**(this as *const *const IMFCollection_Vtbl)).InsertElementAt)(this, dwindex, punknown), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAllElements(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFCollection", "RemoveAllElements", 8usize, |this| ((**(this as *const *const IMFCollection_Vtbl)).RemoveAllElements)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetElementCount,
+            GetElement,
+            AddElement,
+            RemoveElement,
+            InsertElementAt,
+            RemoveAllElements,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFContentDecryptionModule_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetContentEnabler(this: *mut core::ffi::c_void, contentenabler: *mut core::ffi::c_void, result: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentDecryptionModule", "SetContentEnabler", 3usize, |this| ((**(this as *const *const IMFContentDecryptionModule_Vtbl)).SetContentEnabler)(this, contentenabler, result), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSuspendNotify(this: *mut core::ffi::c_void, notify: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentDecryptionModule", "GetSuspendNotify", 4usize, |this| ((**(this as *const *const IMFContentDecryptionModule_Vtbl)).GetSuspendNotify)(this, notify), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPMPHostApp(this: *mut core::ffi::c_void, pmphostapp: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentDecryptionModule", "SetPMPHostApp", 5usize, |this| ((**(this as *const *const IMFContentDecryptionModule_Vtbl)).SetPMPHostApp)(this, pmphostapp), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSession(this: *mut core::ffi::c_void, sessiontype: MF_MEDIAKEYSESSION_TYPE, callbacks: *mut core::ffi::c_void, session: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentDecryptionModule", "CreateSession", 6usize, |this| ((**(this as *const *const IMFContentDecryptionModule_Vtbl)).CreateSession)(this, sessiontype, callbacks, session), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServerCertificate(this: *mut core::ffi::c_void, certificate: *const u8, certificatesize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentDecryptionModule", "SetServerCertificate", 7usize, |this| ((**(this as *const *const IMFContentDecryptionModule_Vtbl)).SetServerCertificate)(this, certificate, certificatesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTrustedInput(this: *mut core::ffi::c_void, contentinitdata: *const u8, contentinitdatasize: u32, trustedinput: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentDecryptionModule", "CreateTrustedInput", 8usize, |this| ((**(this as *const *const IMFContentDecryptionModule_Vtbl)).CreateTrustedInput)(this, contentinitdata, contentinitdatasize, trustedinput), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProtectionSystemIds(this: *mut core::ffi::c_void, systemids: *mut *mut windows_core::GUID, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentDecryptionModule", "GetProtectionSystemIds", 9usize, |this| ((**(this as *const *const IMFContentDecryptionModule_Vtbl)).GetProtectionSystemIds)(this, systemids, count), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetContentEnabler,
+            GetSuspendNotify,
+            SetPMPHostApp,
+            CreateSession,
+            SetServerCertificate,
+            CreateTrustedInput,
+            GetProtectionSystemIds,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFContentDecryptionModule as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IMFContentDecryptionModuleAccess_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn CreateContentDecryptionModule(this: *mut core::ffi::c_void, contentdecryptionmoduleproperties: *mut core::ffi::c_void, contentdecryptionmodule: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentDecryptionModuleAccess", "CreateContentDecryptionModule", 3usize, |this| ((**(this as *const *const IMFContentDecryptionModuleAccess_Vtbl)).CreateContentDecryptionModule)(this, contentdecryptionmoduleproperties, contentdecryptionmodule), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetConfiguration(this: *mut core::ffi::c_void, configuration: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentDecryptionModuleAccess", "GetConfiguration", 4usize, |this| ((**(this as *const *const IMFContentDecryptionModuleAccess_Vtbl)).GetConfiguration)(this, configuration), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKeySystem(this: *mut core::ffi::c_void, keysystem: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentDecryptionModuleAccess", "GetKeySystem", 5usize, |this| ((**(this as *const *const IMFContentDecryptionModuleAccess_Vtbl)).GetKeySystem)(this, keysystem), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            CreateContentDecryptionModule,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            CreateContentDecryptionModule: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetConfiguration,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetConfiguration: 0,
+            GetKeySystem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFContentDecryptionModuleAccess as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IMFContentDecryptionModuleFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsTypeSupported(this: *mut core::ffi::c_void, keysystem: windows_core::PCWSTR, contenttype: windows_core::PCWSTR) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IMFContentDecryptionModuleFactory", "IsTypeSupported", 3usize, |this| ((**(this as *const *const IMFContentDecryptionModuleFactory_Vtbl)).IsTypeSupported)(this, keysystem, contenttype), |_| None)
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn CreateContentDecryptionModuleAccess(this: *mut core::ffi::c_void, keysystem: windows_core::PCWSTR, configurations: *const *mut core::ffi::c_void, numconfigurations: u32, contentdecryptionmoduleaccess: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentDecryptionModuleFactory", "CreateContentDecryptionModuleAccess", 4usize, |this| ((**(this as *const *const IMFContentDecryptionModuleFactory_Vtbl)).CreateContentDecryptionModuleAccess)(this, keysystem, configurations, numconfigurations, contentdecryptionmoduleaccess), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsTypeSupported,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            CreateContentDecryptionModuleAccess,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            CreateContentDecryptionModuleAccess: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFContentDecryptionModuleFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFContentDecryptionModuleSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSessionId(this: *mut core::ffi::c_void, sessionid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentDecryptionModuleSession", "GetSessionId", 3usize, |this| ((**(this as *const *const IMFContentDecryptionModuleSession_Vtbl)).GetSessionId)(this, sessionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExpiration(this: *mut core::ffi::c_void, expiration: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentDecryptionModuleSession", "GetExpiration", 4usize, |this| ((**(this as *const *const IMFContentDecryptionModuleSession_Vtbl)).GetExpiration)(this, expiration), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKeyStatuses(this: *mut core::ffi::c_void, keystatuses: *mut *mut MFMediaKeyStatus, numkeystatuses: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentDecryptionModuleSession", "GetKeyStatuses", 5usize, |this| ((**(this as *const *const IMFContentDecryptionModuleSession_Vtbl)).GetKeyStatuses)(this, keystatuses, numkeystatuses), |result| Some(*result))
+        }
+        unsafe extern "system" fn Load(this: *mut core::ffi::c_void, sessionid: windows_core::PCWSTR, loaded: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentDecryptionModuleSession", "Load", 6usize, |this| ((**(this as *const *const IMFContentDecryptionModuleSession_Vtbl)).Load)(this, sessionid, loaded), |result| Some(*result))
+        }
+        unsafe extern "system" fn GenerateRequest(this: *mut core::ffi::c_void, initdatatype: windows_core::PCWSTR, initdata: *const u8, initdatasize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentDecryptionModuleSession", "GenerateRequest", 7usize, |this| ((**(this as *const *const IMFContentDecryptionModuleSession_Vtbl)).GenerateRequest)(this, initdatatype, initdata, initdatasize), |result| Some(*result))
+        }
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void, response: *const u8, responsesize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentDecryptionModuleSession", "Update", 8usize, |this| ((**(this as *const *const IMFContentDecryptionModuleSession_Vtbl)).Update)(this, response, responsesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentDecryptionModuleSession", "Close", 9usize, |this| ((**(this as *const *const IMFContentDecryptionModuleSession_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentDecryptionModuleSession", "Remove", 10usize, |this| ((**(this as *const *const IMFContentDecryptionModuleSession_Vtbl)).Remove)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSessionId,
+            GetExpiration,
+            GetKeyStatuses,
+            Load,
+            GenerateRequest,
+            Update,
+            Close,
+            Remove,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFContentDecryptionModuleSession as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFContentDecryptionModuleSessionCallbacks_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn KeyMessage(this: *mut core::ffi::c_void, messagetype: MF_MEDIAKEYSESSION_MESSAGETYPE, message: *const u8, messagesize: u32, destinationurl: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentDecryptionModuleSessionCallbacks", "KeyMessage", 3usize, |this| ((**(this as *const *const IMFContentDecryptionModuleSessionCallbacks_Vtbl)).KeyMessage)(this, messagetype, message, messagesize, destinationurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyStatusChanged(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentDecryptionModuleSessionCallbacks", "KeyStatusChanged", 4usize, |this| ((**(this as *const *const IMFContentDecryptionModuleSessionCallbacks_Vtbl)).KeyStatusChanged)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, KeyMessage, KeyStatusChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFContentDecryptionModuleSessionCallbacks as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFContentDecryptorContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeHardwareKey(this: *mut core::ffi::c_void, inputprivatedatabytecount: u32, inputprivatedata: *const core::ffi::c_void, outputprivatedata: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentDecryptorContext", "InitializeHardwareKey", 3usize, |this| ((**(this as *const *const IMFContentDecryptorContext_Vtbl)).InitializeHardwareKey)(this, inputprivatedatabytecount, inputprivatedata, outputprivatedata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InitializeHardwareKey }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFContentDecryptorContext as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFContentEnabler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEnableType(this: *mut core::ffi::c_void, ptype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentEnabler", "GetEnableType", 3usize, |this| ((**(this as *const *const IMFContentEnabler_Vtbl)).GetEnableType)(this, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnableURL(this: *mut core::ffi::c_void, ppwszurl: *mut windows_core::PWSTR, pcchurl: *mut u32, ptruststatus: *mut MF_URL_TRUST_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentEnabler", "GetEnableURL", 4usize, |this| ((**(this as *const *const IMFContentEnabler_Vtbl)).GetEnableURL)(this, ppwszurl, pcchurl, ptruststatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnableData(this: *mut core::ffi::c_void, ppbdata: *mut *mut u8, pcbdata: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentEnabler", "GetEnableData", 5usize, |this| ((**(this as *const *const IMFContentEnabler_Vtbl)).GetEnableData)(this, ppbdata, pcbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAutomaticSupported(this: *mut core::ffi::c_void, pfautomatic: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentEnabler", "IsAutomaticSupported", 6usize, |this| ((**(this as *const *const IMFContentEnabler_Vtbl)).IsAutomaticSupported)(this, pfautomatic), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutomaticEnable(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentEnabler", "AutomaticEnable", 7usize, |this| ((**(this as *const *const IMFContentEnabler_Vtbl)).AutomaticEnable)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn MonitorEnable(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentEnabler", "MonitorEnable", 8usize, |this| ((**(this as *const *const IMFContentEnabler_Vtbl)).MonitorEnable)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentEnabler", "Cancel", 9usize, |this| ((**(this as *const *const IMFContentEnabler_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetEnableType,
+            GetEnableURL,
+            GetEnableData,
+            IsAutomaticSupported,
+            AutomaticEnable,
+            MonitorEnable,
+            Cancel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFContentEnabler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFContentProtectionDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InvokeFunction(this: *mut core::ffi::c_void, functionid: u32, inputbufferbytecount: u32, inputbuffer: *const u8, outputbufferbytecount: *mut u32, outputbuffer: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentProtectionDevice", "InvokeFunction", 3usize, |this| ((**(this as *const *const IMFContentProtectionDevice_Vtbl)).InvokeFunction)(this, functionid, inputbufferbytecount, inputbuffer, outputbufferbytecount, outputbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrivateDataByteCount(this: *mut core::ffi::c_void, privateinputbytecount: *mut u32, privateoutputbytecount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentProtectionDevice", "GetPrivateDataByteCount", 4usize, |this| ((**(this as *const *const IMFContentProtectionDevice_Vtbl)).GetPrivateDataByteCount)(this, privateinputbytecount, privateoutputbytecount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InvokeFunction, GetPrivateDataByteCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFContentProtectionDevice as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFContentProtectionManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BeginEnableContent(this: *mut core::ffi::c_void, penableractivate: *mut core::ffi::c_void, ptopo: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, punkstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentProtectionManager", "BeginEnableContent", 3usize, |this| ((**(this as *const *const IMFContentProtectionManager_Vtbl)).BeginEnableContent)(this, penableractivate, ptopo, pcallback, punkstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndEnableContent(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFContentProtectionManager", "EndEnableContent", 4usize, |this| ((**(this as *const *const IMFContentProtectionManager_Vtbl)).EndEnableContent)(this, presult), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BeginEnableContent, EndEnableContent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFContentProtectionManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFD3D12SynchronizationObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SignalEventOnFinalResourceRelease(this: *mut core::ffi::c_void, hevent: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFD3D12SynchronizationObject", "SignalEventOnFinalResourceRelease", 3usize, |this| ((**(this as *const *const IMFD3D12SynchronizationObject_Vtbl)).SignalEventOnFinalResourceRelease)(this, hevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFD3D12SynchronizationObject", "Reset", 4usize, |this| ((**(this as *const *const IMFD3D12SynchronizationObject_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SignalEventOnFinalResourceRelease, Reset }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFD3D12SynchronizationObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D12")]
+impl windows_core::imp::ProxyVtable for IMFD3D12SynchronizationObjectCommands_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn EnqueueResourceReady(this: *mut core::ffi::c_void, pproducercommandqueue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFD3D12SynchronizationObjectCommands", "EnqueueResourceReady", 3usize, |this| ((**(this as *const *const IMFD3D12SynchronizationObjectCommands_Vtbl)).EnqueueResourceReady)(this, pproducercommandqueue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn EnqueueResourceReadyWait(this: *mut core::ffi::c_void, pconsumercommandqueue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFD3D12SynchronizationObjectCommands", "EnqueueResourceReadyWait", 4usize, |this| ((**(this as *const *const IMFD3D12SynchronizationObjectCommands_Vtbl)).EnqueueResourceReadyWait)(this, pconsumercommandqueue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignalEventOnResourceReady(this: *mut core::ffi::c_void, hevent: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFD3D12SynchronizationObjectCommands", "SignalEventOnResourceReady", 5usize, |this| ((**(this as *const *const IMFD3D12SynchronizationObjectCommands_Vtbl)).SignalEventOnResourceReady)(this, hevent), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn EnqueueResourceRelease(this: *mut core::ffi::c_void, pconsumercommandqueue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFD3D12SynchronizationObjectCommands", "EnqueueResourceRelease", 6usize, |this| ((**(this as *const *const IMFD3D12SynchronizationObjectCommands_Vtbl)).EnqueueResourceRelease)(this, pconsumercommandqueue), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            EnqueueResourceReady,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            EnqueueResourceReady: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            EnqueueResourceReadyWait,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            EnqueueResourceReadyWait: 0,
+            SignalEventOnResourceReady,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            EnqueueResourceRelease,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            EnqueueResourceRelease: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFD3D12SynchronizationObjectCommands as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFDLNASinkInit_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pbytestream: *mut core::ffi::c_void, fpal: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDLNASinkInit", "Initialize", 3usize, |this| ((**(this as *const *const IMFDLNASinkInit_Vtbl)).Initialize)(this, pbytestream, fpal), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFDLNASinkInit as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFDRMNetHelper_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProcessLicenseRequest(this: *mut core::ffi::c_void, plicenserequest: *const u8, cblicenserequest: u32, pplicenseresponse: *mut *mut u8, pcblicenseresponse: *mut u32, pbstrkid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDRMNetHelper", "ProcessLicenseRequest", 3usize, |this| ((**(this as *const *const IMFDRMNetHelper_Vtbl)).ProcessLicenseRequest)(this, plicenserequest, cblicenserequest, pplicenseresponse, pcblicenseresponse, pbstrkid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChainedLicenseResponse(this: *mut core::ffi::c_void, pplicenseresponse: *mut *mut u8, pcblicenseresponse: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDRMNetHelper", "GetChainedLicenseResponse", 4usize, |this| ((**(this as *const *const IMFDRMNetHelper_Vtbl)).GetChainedLicenseResponse)(this, pplicenseresponse, pcblicenseresponse), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ProcessLicenseRequest, GetChainedLicenseResponse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFDRMNetHelper as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFDXGIBuffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetResource(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDXGIBuffer", "GetResource", 3usize, |this| ((**(this as *const *const IMFDXGIBuffer_Vtbl)).GetResource)(this, riid, ppvobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubresourceIndex(this: *mut core::ffi::c_void, pusubresource: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDXGIBuffer", "GetSubresourceIndex", 4usize, |this| ((**(this as *const *const IMFDXGIBuffer_Vtbl)).GetSubresourceIndex)(this, pusubresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUnknown(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, riid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDXGIBuffer", "GetUnknown", 5usize, |this| ((**(this as *const *const IMFDXGIBuffer_Vtbl)).GetUnknown)(this, guid, riid, ppvobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUnknown(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, punkdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDXGIBuffer", "SetUnknown", 6usize, |this| ((**(this as *const *const IMFDXGIBuffer_Vtbl)).SetUnknown)(this, guid, punkdata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetResource, GetSubresourceIndex, GetUnknown, SetUnknown }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFDXGIBuffer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFDXGIDeviceManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CloseDeviceHandle(this: *mut core::ffi::c_void, hdevice: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDXGIDeviceManager", "CloseDeviceHandle", 3usize, |this| ((**(this as *const *const IMFDXGIDeviceManager_Vtbl)).CloseDeviceHandle)(this, hdevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoService(this: *mut core::ffi::c_void, hdevice: super::super::Foundation::HANDLE, riid: *const windows_core::GUID, ppservice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDXGIDeviceManager", "GetVideoService", 4usize, |this| ((**(this as *const *const IMFDXGIDeviceManager_Vtbl)).GetVideoService)(this, hdevice, riid, ppservice), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockDevice(this: *mut core::ffi::c_void, hdevice: super::super::Foundation::HANDLE, riid: *const windows_core::GUID, ppunkdevice: *mut *mut core::ffi::c_void, fblock: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDXGIDeviceManager", "LockDevice", 5usize, |this| ((**(this as *const *const IMFDXGIDeviceManager_Vtbl)).LockDevice)(this, hdevice, riid, ppunkdevice, fblock), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenDeviceHandle(this: *mut core::ffi::c_void, phdevice: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDXGIDeviceManager", "OpenDeviceHandle", 6usize, |this| ((**(this as *const *const IMFDXGIDeviceManager_Vtbl)).OpenDeviceHandle)(this, phdevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetDevice(this: *mut core::ffi::c_void, punkdevice: *mut core::ffi::c_void, resettoken: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDXGIDeviceManager", "ResetDevice", 7usize, |this| ((**(this as *const *const IMFDXGIDeviceManager_Vtbl)).ResetDevice)(this, punkdevice, resettoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn TestDevice(this: *mut core::ffi::c_void, hdevice: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDXGIDeviceManager", "TestDevice", 8usize, |this| ((**(this as *const *const IMFDXGIDeviceManager_Vtbl)).TestDevice)(this, hdevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockDevice(this: *mut core::ffi::c_void, hdevice: super::super::Foundation::HANDLE, fsavestate: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDXGIDeviceManager", "UnlockDevice", 9usize, |this| ((**(this as *const *const IMFDXGIDeviceManager_Vtbl)).UnlockDevice)(this, hdevice, fsavestate), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CloseDeviceHandle,
+            GetVideoService,
+            LockDevice,
+            OpenDeviceHandle,
+            ResetDevice,
+            TestDevice,
+            UnlockDevice,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFDXGIDeviceManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFDXGIDeviceManagerSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetManager(this: *mut core::ffi::c_void, ppmanager: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDXGIDeviceManagerSource", "GetManager", 3usize, |this| ((**(this as *const *const IMFDXGIDeviceManagerSource_Vtbl)).GetManager)(this, ppmanager), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetManager }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFDXGIDeviceManagerSource as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFDesiredSample_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesiredSampleTimeAndDuration(this: *mut core::ffi::c_void, phnssampletime: *mut i64, phnssampleduration: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDesiredSample", "GetDesiredSampleTimeAndDuration", 3usize, |this| ((**(this as *const *const IMFDesiredSample_Vtbl)).GetDesiredSampleTimeAndDuration)(this, phnssampletime, phnssampleduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredSampleTimeAndDuration(this: *mut core::ffi::c_void, hnssampletime: i64, hnssampleduration: i64) {
+            windows_core::imp::proxy_call(this, "IMFDesiredSample", "SetDesiredSampleTimeAndDuration", 4usize, |this| ((**(this as *const *const IMFDesiredSample_Vtbl)).SetDesiredSampleTimeAndDuration)(this, hnssampletime, hnssampleduration), |_| None)
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IMFDesiredSample", "Clear", 5usize, |this| ((**(this as *const *const IMFDesiredSample_Vtbl)).Clear)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDesiredSampleTimeAndDuration,
+            SetDesiredSampleTimeAndDuration,
+            Clear,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFDesiredSample as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFDeviceTransform_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeTransform(this: *mut core::ffi::c_void, pattributes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDeviceTransform", "InitializeTransform", 3usize, |this| ((**(this as *const *const IMFDeviceTransform_Vtbl)).InitializeTransform)(this, pattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputAvailableType(this: *mut core::ffi::c_void, dwinputstreamid: u32, dwtypeindex: u32, pmediatype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDeviceTransform", "GetInputAvailableType", 4usize, |this| ((**(this as *const *const IMFDeviceTransform_Vtbl)).GetInputAvailableType)(this, dwinputstreamid, dwtypeindex, pmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputCurrentType(this: *mut core::ffi::c_void, dwinputstreamid: u32, pmediatype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDeviceTransform", "GetInputCurrentType", 5usize, |this| ((**(this as *const *const IMFDeviceTransform_Vtbl)).GetInputCurrentType)(this, dwinputstreamid, pmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputStreamAttributes(this: *mut core::ffi::c_void, dwinputstreamid: u32, ppattributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDeviceTransform", "GetInputStreamAttributes", 6usize, |this| ((**(this as *const *const IMFDeviceTransform_Vtbl)).GetInputStreamAttributes)(this, dwinputstreamid, ppattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputAvailableType(this: *mut core::ffi::c_void, dwoutputstreamid: u32, dwtypeindex: u32, pmediatype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDeviceTransform", "GetOutputAvailableType", 7usize, |this| ((**(this as *const *const IMFDeviceTransform_Vtbl)).GetOutputAvailableType)(this, dwoutputstreamid, dwtypeindex, pmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputCurrentType(this: *mut core::ffi::c_void, dwoutputstreamid: u32, pmediatype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDeviceTransform", "GetOutputCurrentType", 8usize, |this| ((**(this as *const *const IMFDeviceTransform_Vtbl)).GetOutputCurrentType)(this, dwoutputstreamid, pmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputStreamAttributes(this: *mut core::ffi::c_void, dwoutputstreamid: u32, ppattributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDeviceTransform", "GetOutputStreamAttributes", 9usize, |this| ((**(this as *const *const IMFDeviceTransform_Vtbl)).GetOutputStreamAttributes)(this, dwoutputstreamid, ppattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamCount(this: *mut core::ffi::c_void, pcinputstreams: *mut u32, pcoutputstreams: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDeviceTransform", "GetStreamCount", 10usize, |this| ((**(this as *const *const IMFDeviceTransform_Vtbl)).GetStreamCount)(this, pcinputstreams, pcoutputstreams), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamIDs(this: *mut core::ffi::c_void, dwinputidarraysize: u32, pdwinputstreamids: *mut u32, dwoutputidarraysize: u32, pdwoutputstreamids: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDeviceTransform", "GetStreamIDs", 11usize, |this| ((**(this as *const *const IMFDeviceTransform_Vtbl)).GetStreamIDs)(this, dwinputidarraysize, pdwinputstreamids, dwoutputidarraysize, pdwoutputstreamids), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessEvent(this: *mut core::ffi::c_void, dwinputstreamid: u32, pevent: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDeviceTransform", "ProcessEvent", 12usize, |this| ((**(this as *const *const IMFDeviceTransform_Vtbl)).ProcessEvent)(this, dwinputstreamid, pevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessInput(this: *mut core::ffi::c_void, dwinputstreamid: u32, psample: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDeviceTransform", "ProcessInput", 13usize, |this| ((**(this as *const *const IMFDeviceTransform_Vtbl)).ProcessInput)(this, dwinputstreamid, psample, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessMessage(this: *mut core::ffi::c_void, emessage: MFT_MESSAGE_TYPE, ulparam: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDeviceTransform", "ProcessMessage", 14usize, |this| ((**(this as *const *const IMFDeviceTransform_Vtbl)).ProcessMessage)(this, emessage, ulparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessOutput(this: *mut core::ffi::c_void, dwflags: u32, coutputbuffercount: u32, poutputsample: *mut MFT_OUTPUT_DATA_BUFFER, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDeviceTransform", "ProcessOutput", 15usize, |this| ((**(this as *const *const IMFDeviceTransform_Vtbl)).ProcessOutput)(this, dwflags, coutputbuffercount, poutputsample, pdwstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInputStreamState(this: *mut core::ffi::c_void, dwstreamid: u32, pmediatype: *mut core::ffi::c_void, value: DeviceStreamState, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDeviceTransform", "SetInputStreamState", 16usize, |this| ((**(this as *const *const IMFDeviceTransform_Vtbl)).SetInputStreamState)(this, dwstreamid, pmediatype, value, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputStreamState(this: *mut core::ffi::c_void, dwstreamid: u32, value: *mut DeviceStreamState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDeviceTransform", "GetInputStreamState", 17usize, |this| ((**(this as *const *const IMFDeviceTransform_Vtbl)).GetInputStreamState)(this, dwstreamid, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputStreamState(this: *mut core::ffi::c_void, dwstreamid: u32, pmediatype: *mut core::ffi::c_void, value: DeviceStreamState, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDeviceTransform", "SetOutputStreamState", 18usize, |this| ((**(this as *const *const IMFDeviceTransform_Vtbl)).SetOutputStreamState)(this, dwstreamid, pmediatype, value, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputStreamState(this: *mut core::ffi::c_void, dwstreamid: u32, value: *mut DeviceStreamState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDeviceTransform", "GetOutputStreamState", 19usize, |this| ((**(this as *const *const IMFDeviceTransform_Vtbl)).GetOutputStreamState)(this, dwstreamid, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputStreamPreferredState(this: *mut core::ffi::c_void, dwstreamid: u32, value: *mut DeviceStreamState, ppmediatype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDeviceTransform", "GetInputStreamPreferredState", 20usize, |this| ((**(this as *const *const IMFDeviceTransform_Vtbl)).GetInputStreamPreferredState)(this, dwstreamid, value, ppmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlushInputStream(this: *mut core::ffi::c_void, dwstreamindex: u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDeviceTransform", "FlushInputStream", 21usize, |this| ((**(this as *const *const IMFDeviceTransform_Vtbl)).FlushInputStream)(this, dwstreamindex, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlushOutputStream(this: *mut core::ffi::c_void, dwstreamindex: u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDeviceTransform", "FlushOutputStream", 22usize, |this| ((**(this as *const *const IMFDeviceTransform_Vtbl)).FlushOutputStream)(this, dwstreamindex, dwflags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitializeTransform,
+            GetInputAvailableType,
+            GetInputCurrentType,
+            GetInputStreamAttributes,
+            GetOutputAvailableType,
+            GetOutputCurrentType,
+            GetOutputStreamAttributes,
+            GetStreamCount,
+            GetStreamIDs,
+            ProcessEvent,
+            ProcessInput,
+            ProcessMessage,
+            ProcessOutput,
+            SetInputStreamState,
+            GetInputStreamState,
+            SetOutputStreamState,
+            GetOutputStreamState,
+            GetInputStreamPreferredState,
+            FlushInputStream,
+            FlushOutputStream,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFDeviceTransform as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFDeviceTransformCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnBufferSent(this: *mut core::ffi::c_void, pcallbackattributes: *mut core::ffi::c_void, pinid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFDeviceTransformCallback", "OnBufferSent", 3usize, |this| ((**(this as *const *const IMFDeviceTransformCallback_Vtbl)).OnBufferSent)(this, pcallbackattributes, pinid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnBufferSent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFDeviceTransformCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFExtendedCameraControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCapabilities(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "IMFExtendedCameraControl", "GetCapabilities", 3usize, |this| ((**(this as *const *const IMFExtendedCameraControl_Vtbl)).GetCapabilities)(this), |_| None)
+        }
+        unsafe extern "system" fn SetFlags(this: *mut core::ffi::c_void, ulflags: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFExtendedCameraControl", "SetFlags", 4usize, |this| ((**(this as *const *const IMFExtendedCameraControl_Vtbl)).SetFlags)(this, ulflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlags(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "IMFExtendedCameraControl", "GetFlags", 5usize, |this| ((**(this as *const *const IMFExtendedCameraControl_Vtbl)).GetFlags)(this), |_| None)
+        }
+        unsafe extern "system" fn LockPayload(this: *mut core::ffi::c_void, pppayload: *mut *mut u8, pulpayload: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFExtendedCameraControl", "LockPayload", 6usize, |this| ((**(this as *const *const IMFExtendedCameraControl_Vtbl)).LockPayload)(this, pppayload, pulpayload), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockPayload(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFExtendedCameraControl", "UnlockPayload", 7usize, |this| ((**(this as *const *const IMFExtendedCameraControl_Vtbl)).UnlockPayload)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommitSettings(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFExtendedCameraControl", "CommitSettings", 8usize, |this| ((**(this as *const *const IMFExtendedCameraControl_Vtbl)).CommitSettings)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCapabilities,
+            SetFlags,
+            GetFlags,
+            LockPayload,
+            UnlockPayload,
+            CommitSettings,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFExtendedCameraControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFExtendedCameraController_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetExtendedCameraControl(this: *mut core::ffi::c_void, dwstreamindex: u32, ulpropertyid: u32, ppcontrol: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFExtendedCameraController", "GetExtendedCameraControl", 3usize, |this| ((**(this as *const *const IMFExtendedCameraController_Vtbl)).GetExtendedCameraControl)(this, dwstreamindex, ulpropertyid, ppcontrol), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetExtendedCameraControl }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFExtendedCameraController as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFExtendedCameraIntrinsicModel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetModel(this: *mut core::ffi::c_void, pintrinsicmodel: *mut MFExtendedCameraIntrinsic_IntrinsicModel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFExtendedCameraIntrinsicModel", "GetModel", 3usize, |this| ((**(this as *const *const IMFExtendedCameraIntrinsicModel_Vtbl)).GetModel)(this, pintrinsicmodel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetModel(this: *mut core::ffi::c_void, pintrinsicmodel: *const MFExtendedCameraIntrinsic_IntrinsicModel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFExtendedCameraIntrinsicModel", "SetModel", 4usize, |this| ((**(this as *const *const IMFExtendedCameraIntrinsicModel_Vtbl)).SetModel)(this, pintrinsicmodel), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDistortionModelType(this: *mut core::ffi::c_void, pdistortionmodeltype: *mut MFCameraIntrinsic_DistortionModelType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFExtendedCameraIntrinsicModel", "GetDistortionModelType", 5usize, |this| ((**(this as *const *const IMFExtendedCameraIntrinsicModel_Vtbl)).GetDistortionModelType)(this, pdistortionmodeltype), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetModel, SetModel, GetDistortionModelType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFExtendedCameraIntrinsicModel as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFExtendedCameraIntrinsics_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeFromBuffer(this: *mut core::ffi::c_void, pbbuffer: *const u8, dwbuffersize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFExtendedCameraIntrinsics", "InitializeFromBuffer", 3usize, |this| ((**(this as *const *const IMFExtendedCameraIntrinsics_Vtbl)).InitializeFromBuffer)(this, pbbuffer, dwbuffersize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBufferSize(this: *mut core::ffi::c_void, pdwbuffersize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFExtendedCameraIntrinsics", "GetBufferSize", 4usize, |this| ((**(this as *const *const IMFExtendedCameraIntrinsics_Vtbl)).GetBufferSize)(this, pdwbuffersize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SerializeToBuffer(this: *mut core::ffi::c_void, pbbuffer: *mut u8, pdwbuffersize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFExtendedCameraIntrinsics", "SerializeToBuffer", 5usize, |this| ((**(this as *const *const IMFExtendedCameraIntrinsics_Vtbl)).SerializeToBuffer)(this, pbbuffer, pdwbuffersize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIntrinsicModelCount(this: *mut core::ffi::c_void, pdwcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFExtendedCameraIntrinsics", "GetIntrinsicModelCount", 6usize, |this| ((**(this as *const *const IMFExtendedCameraIntrinsics_Vtbl)).GetIntrinsicModelCount)(this, pdwcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIntrinsicModelByIndex(this: *mut core::ffi::c_void, dwindex: u32, ppintrinsicmodel: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFExtendedCameraIntrinsics", "GetIntrinsicModelByIndex", 7usize, |this| ((**(this as *const *const IMFExtendedCameraIntrinsics_Vtbl)).GetIntrinsicModelByIndex)(this, dwindex, ppintrinsicmodel), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddIntrinsicModel(this: *mut core::ffi::c_void, pintrinsicmodel: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFExtendedCameraIntrinsics", "AddIntrinsicModel", 8usize, |this| ((**(this as *const *const IMFExtendedCameraIntrinsics_Vtbl)).AddIntrinsicModel)(this, pintrinsicmodel), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitializeFromBuffer,
+            GetBufferSize,
+            SerializeToBuffer,
+            GetIntrinsicModelCount,
+            GetIntrinsicModelByIndex,
+            AddIntrinsicModel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFExtendedCameraIntrinsics as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFExtendedCameraIntrinsicsDistortionModel6KT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDistortionModel(this: *mut core::ffi::c_void, pdistortionmodel: *mut MFCameraIntrinsic_DistortionModel6KT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFExtendedCameraIntrinsicsDistortionModel6KT", "GetDistortionModel", 3usize, |this| ((**(this as *const *const IMFExtendedCameraIntrinsicsDistortionModel6KT_Vtbl)).GetDistortionModel)(this, pdistortionmodel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDistortionModel(this: *mut core::ffi::c_void, pdistortionmodel: *const MFCameraIntrinsic_DistortionModel6KT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFExtendedCameraIntrinsicsDistortionModel6KT", "SetDistortionModel", 4usize, |this| ((**(this as *const *const IMFExtendedCameraIntrinsicsDistortionModel6KT_Vtbl)).SetDistortionModel)(this, pdistortionmodel), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDistortionModel, SetDistortionModel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFExtendedCameraIntrinsicsDistortionModel6KT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFExtendedCameraIntrinsicsDistortionModelArcTan_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDistortionModel(this: *mut core::ffi::c_void, pdistortionmodel: *mut MFCameraIntrinsic_DistortionModelArcTan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFExtendedCameraIntrinsicsDistortionModelArcTan", "GetDistortionModel", 3usize, |this| ((**(this as *const *const IMFExtendedCameraIntrinsicsDistortionModelArcTan_Vtbl)).GetDistortionModel)(this, pdistortionmodel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDistortionModel(this: *mut core::ffi::c_void, pdistortionmodel: *const MFCameraIntrinsic_DistortionModelArcTan) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFExtendedCameraIntrinsicsDistortionModelArcTan", "SetDistortionModel", 4usize, |this| ((**(this as *const *const IMFExtendedCameraIntrinsicsDistortionModelArcTan_Vtbl)).SetDistortionModel)(this, pdistortionmodel), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDistortionModel, SetDistortionModel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFExtendedCameraIntrinsicsDistortionModelArcTan as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFExtendedDRMTypeSupport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsTypeSupportedEx(this: *mut core::ffi::c_void, r#type: core::mem::MaybeUninit<windows_core::BSTR>, keysystem: core::mem::MaybeUninit<windows_core::BSTR>, panswer: *mut MF_MEDIA_ENGINE_CANPLAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFExtendedDRMTypeSupport", "IsTypeSupportedEx", 3usize, |this| ((**(this as *const *const IMFExtendedDRMTypeSupport_Vtbl)).IsTypeSupportedEx)(this, r#type, keysystem, panswer), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsTypeSupportedEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFExtendedDRMTypeSupport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFFieldOfUseMFTUnlock_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Unlock(this: *mut core::ffi::c_void, punkmft: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFFieldOfUseMFTUnlock", "Unlock", 3usize, |this| ((**(this as *const *const IMFFieldOfUseMFTUnlock_Vtbl)).Unlock)(this, punkmft), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Unlock }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFFieldOfUseMFTUnlock as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFFinalizableMediaSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BeginFinalize(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, punkstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFFinalizableMediaSink", "BeginFinalize", 12usize, |this| ((**(this as *const *const IMFFinalizableMediaSink_Vtbl)).BeginFinalize)(this, pcallback, punkstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndFinalize(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFFinalizableMediaSink", "EndFinalize", 13usize, |this| ((**(this as *const *const IMFFinalizableMediaSink_Vtbl)).EndFinalize)(this, presult), |result| Some(*result))
+        }
+        Self { base__: <IMFMediaSink_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BeginFinalize, EndFinalize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFFinalizableMediaSink as windows_core::Interface>::IID || <IMFMediaSink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFGetService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetService(this: *mut core::ffi::c_void, guidservice: *const windows_core::GUID, riid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFGetService", "GetService", 3usize, |this| ((**(this as *const *const IMFGetService_Vtbl)).GetService)(this, guidservice, riid, ppvobject), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetService }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFGetService as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFHDCPStatus_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Query(this: *mut core::ffi::c_void, pstatus: *mut MF_HDCP_STATUS, pfstatus: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFHDCPStatus", "Query", 3usize, |this| ((**(this as *const *const IMFHDCPStatus_Vtbl)).Query)(this, pstatus, pfstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn Set(this: *mut core::ffi::c_void, status: MF_HDCP_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFHDCPStatus", "Set", 4usize, |this| ((**(this as *const *const IMFHDCPStatus_Vtbl)).Set)(this, status), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Query, Set }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFHDCPStatus as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFHttpDownloadRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddHeader(this: *mut core::ffi::c_void, szheader: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFHttpDownloadRequest", "AddHeader", 3usize, |this| ((**(this as *const *const IMFHttpDownloadRequest_Vtbl)).AddHeader)(this, szheader), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginSendRequest(this: *mut core::ffi::c_void, pbpayload: *const u8, cbpayload: u32, pcallback: *mut core::ffi::c_void, punkstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFHttpDownloadRequest", "BeginSendRequest", 4usize, |this| ((**(this as *const *const IMFHttpDownloadRequest_Vtbl)).BeginSendRequest)(this, pbpayload, cbpayload, pcallback, punkstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndSendRequest(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFHttpDownloadRequest", "EndSendRequest", 5usize, |this| ((**(this as *const *const IMFHttpDownloadRequest_Vtbl)).EndSendRequest)(this, presult), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginReceiveResponse(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, punkstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFHttpDownloadRequest", "BeginReceiveResponse", 6usize, |this| ((**(this as *const *const IMFHttpDownloadRequest_Vtbl)).BeginReceiveResponse)(this, pcallback, punkstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndReceiveResponse(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFHttpDownloadRequest", "EndReceiveResponse", 7usize, |this| ((**(this as *const *const IMFHttpDownloadRequest_Vtbl)).EndReceiveResponse)(this, presult), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginReadPayload(this: *mut core::ffi::c_void, pb: *mut u8, cb: u32, pcallback: *mut core::ffi::c_void, punkstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFHttpDownloadRequest", "BeginReadPayload", 8usize, |this| ((**(this as *const *const IMFHttpDownloadRequest_Vtbl)).BeginReadPayload)(this, pb, cb, pcallback, punkstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndReadPayload(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void, pqwoffset: *mut u64, pcbread: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFHttpDownloadRequest", "EndReadPayload", 9usize, |this| ((**(this as *const *const IMFHttpDownloadRequest_Vtbl)).EndReadPayload)(this, presult, pqwoffset, pcbread), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryHeader(this: *mut core::ffi::c_void, szheadername: windows_core::PCWSTR, dwindex: u32, ppszheadervalue: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFHttpDownloadRequest", "QueryHeader", 10usize, |this| ((**(this as *const *const IMFHttpDownloadRequest_Vtbl)).QueryHeader)(this, szheadername, dwindex, ppszheadervalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetURL(this: *mut core::ffi::c_void, ppszurl: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFHttpDownloadRequest", "GetURL", 11usize, |this| ((**(this as *const *const IMFHttpDownloadRequest_Vtbl)).GetURL)(this, ppszurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasNullSourceOrigin(this: *mut core::ffi::c_void, pfnullsourceorigin: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFHttpDownloadRequest", "HasNullSourceOrigin", 12usize, |this| ((**(this as *const *const IMFHttpDownloadRequest_Vtbl)).HasNullSourceOrigin)(this, pfnullsourceorigin), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTimeSeekResult(this: *mut core::ffi::c_void, pqwstarttime: *mut u64, pqwstoptime: *mut u64, pqwduration: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFHttpDownloadRequest", "GetTimeSeekResult", 13usize, |this| ((**(this as *const *const IMFHttpDownloadRequest_Vtbl)).GetTimeSeekResult)(this, pqwstarttime, pqwstoptime, pqwduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHttpStatus(this: *mut core::ffi::c_void, pdwhttpstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFHttpDownloadRequest", "GetHttpStatus", 14usize, |this| ((**(this as *const *const IMFHttpDownloadRequest_Vtbl)).GetHttpStatus)(this, pdwhttpstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAtEndOfPayload(this: *mut core::ffi::c_void, pfatendofpayload: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFHttpDownloadRequest", "GetAtEndOfPayload", 15usize, |this| ((**(this as *const *const IMFHttpDownloadRequest_Vtbl)).GetAtEndOfPayload)(this, pfatendofpayload), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTotalLength(this: *mut core::ffi::c_void, pqwtotallength: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFHttpDownloadRequest", "GetTotalLength", 16usize, |this| ((**(this as *const *const IMFHttpDownloadRequest_Vtbl)).GetTotalLength)(this, pqwtotallength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRangeEndOffset(this: *mut core::ffi::c_void, pqwrangeend: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFHttpDownloadRequest", "GetRangeEndOffset", 17usize, |this| ((**(this as *const *const IMFHttpDownloadRequest_Vtbl)).GetRangeEndOffset)(this, pqwrangeend), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFHttpDownloadRequest", "Close", 18usize, |this| ((**(this as *const *const IMFHttpDownloadRequest_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddHeader,
+            BeginSendRequest,
+            EndSendRequest,
+            BeginReceiveResponse,
+            EndReceiveResponse,
+            BeginReadPayload,
+            EndReadPayload,
+            QueryHeader,
+            GetURL,
+            HasNullSourceOrigin,
+            GetTimeSeekResult,
+            GetHttpStatus,
+            GetAtEndOfPayload,
+            GetTotalLength,
+            GetRangeEndOffset,
+            Close,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFHttpDownloadRequest as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFHttpDownloadSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetServer(this: *mut core::ffi::c_void, szservername: windows_core::PCWSTR, nport: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFHttpDownloadSession", "SetServer", 3usize, |this| ((**(this as *const *const IMFHttpDownloadSession_Vtbl)).SetServer)(this, szservername, nport), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRequest(this: *mut core::ffi::c_void, szobjectname: windows_core::PCWSTR, fbypassproxycache: super::super::Foundation::BOOL, fsecure: super::super::Foundation::BOOL, szverb: windows_core::PCWSTR, szreferrer: windows_core::PCWSTR, pprequest: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFHttpDownloadSession", "CreateRequest", 4usize, |this| ((**(this as *const *const IMFHttpDownloadSession_Vtbl)).CreateRequest)(this, szobjectname, fbypassproxycache, fsecure, szverb, szreferrer, pprequest), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFHttpDownloadSession", "Close", 5usize, |this| ((**(this as *const *const IMFHttpDownloadSession_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetServer, CreateRequest, Close }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFHttpDownloadSession as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFHttpDownloadSessionProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateHttpDownloadSession(this: *mut core::ffi::c_void, wszscheme: windows_core::PCWSTR, ppdownloadsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFHttpDownloadSessionProvider", "CreateHttpDownloadSession", 3usize, |this| ((**(this as *const *const IMFHttpDownloadSessionProvider_Vtbl)).CreateHttpDownloadSession)(this, wszscheme, ppdownloadsession), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateHttpDownloadSession }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFHttpDownloadSessionProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFImageSharingEngine_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSource(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFImageSharingEngine", "SetSource", 3usize, |this| ((**(this as *const *const IMFImageSharingEngine_Vtbl)).SetSource)(this, pstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDevice(this: *mut core::ffi::c_void, pdevice: *mut DEVICE_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFImageSharingEngine", "GetDevice", 4usize, |this| ((**(this as *const *const IMFImageSharingEngine_Vtbl)).GetDevice)(this, pdevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFImageSharingEngine", "Shutdown", 5usize, |this| ((**(this as *const *const IMFImageSharingEngine_Vtbl)).Shutdown)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetSource, GetDevice, Shutdown }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFImageSharingEngine as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFImageSharingEngineClassFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstanceFromUDN(this: *mut core::ffi::c_void, puniquedevicename: core::mem::MaybeUninit<windows_core::BSTR>, ppengine: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFImageSharingEngineClassFactory", "CreateInstanceFromUDN", 3usize, |this| ((**(this as *const *const IMFImageSharingEngineClassFactory_Vtbl)).CreateInstanceFromUDN)(this, puniquedevicename, ppengine), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstanceFromUDN }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFImageSharingEngineClassFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFInputTrustAuthority_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDecrypter(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFInputTrustAuthority", "GetDecrypter", 3usize, |this| ((**(this as *const *const IMFInputTrustAuthority_Vtbl)).GetDecrypter)(this, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAccess(this: *mut core::ffi::c_void, action: MFPOLICYMANAGER_ACTION, ppcontentenableractivate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFInputTrustAuthority", "RequestAccess", 4usize, |this| ((**(this as *const *const IMFInputTrustAuthority_Vtbl)).RequestAccess)(this, action, ppcontentenableractivate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPolicy(this: *mut core::ffi::c_void, action: MFPOLICYMANAGER_ACTION, pppolicy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFInputTrustAuthority", "GetPolicy", 5usize, |this| ((**(this as *const *const IMFInputTrustAuthority_Vtbl)).GetPolicy)(this, action, pppolicy), |result| Some(*result))
+        }
+        unsafe extern "system" fn BindAccess(this: *mut core::ffi::c_void, pparam: *const MFINPUTTRUSTAUTHORITY_ACCESS_PARAMS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFInputTrustAuthority", "BindAccess", 6usize, |this| ((**(this as *const *const IMFInputTrustAuthority_Vtbl)).BindAccess)(this, pparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateAccess(this: *mut core::ffi::c_void, pparam: *const MFINPUTTRUSTAUTHORITY_ACCESS_PARAMS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFInputTrustAuthority", "UpdateAccess", 7usize, |this| ((**(this as *const *const IMFInputTrustAuthority_Vtbl)).UpdateAccess)(this, pparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFInputTrustAuthority", "Reset", 8usize, |this| ((**(this as *const *const IMFInputTrustAuthority_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDecrypter,
+            RequestAccess,
+            GetPolicy,
+            BindAccess,
+            UpdateAccess,
+            Reset,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFInputTrustAuthority as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFLocalMFTRegistration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterMFTs(this: *mut core::ffi::c_void, pmfts: *const MFT_REGISTRATION_INFO, cmfts: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFLocalMFTRegistration", "RegisterMFTs", 3usize, |this| ((**(this as *const *const IMFLocalMFTRegistration_Vtbl)).RegisterMFTs)(this, pmfts, cmfts), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterMFTs }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFLocalMFTRegistration as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaBuffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Lock(this: *mut core::ffi::c_void, ppbbuffer: *mut *mut u8, pcbmaxlength: *mut u32, pcbcurrentlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaBuffer", "Lock", 3usize, |this| ((**(this as *const *const IMFMediaBuffer_Vtbl)).Lock)(this, ppbbuffer, pcbmaxlength, pcbcurrentlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unlock(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaBuffer", "Unlock", 4usize, |this| ((**(this as *const *const IMFMediaBuffer_Vtbl)).Unlock)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentLength(this: *mut core::ffi::c_void, pcbcurrentlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaBuffer", "GetCurrentLength", 5usize, |this| ((**(this as *const *const IMFMediaBuffer_Vtbl)).GetCurrentLength)(this, pcbcurrentlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentLength(this: *mut core::ffi::c_void, cbcurrentlength: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaBuffer", "SetCurrentLength", 6usize, |this| ((**(this as *const *const IMFMediaBuffer_Vtbl)).SetCurrentLength)(this, cbcurrentlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxLength(this: *mut core::ffi::c_void, pcbmaxlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaBuffer", "GetMaxLength", 7usize, |this| ((**(this as *const *const IMFMediaBuffer_Vtbl)).GetMaxLength)(this, pcbmaxlength), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Lock, Unlock, GetCurrentLength, SetCurrentLength, GetMaxLength }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaBuffer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaEngine_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetError(this: *mut core::ffi::c_void, pperror: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "GetError", 3usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).GetError)(this, pperror), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetErrorCode(this: *mut core::ffi::c_void, error: MF_MEDIA_ENGINE_ERR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "SetErrorCode", 4usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).SetErrorCode)(this, error), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSourceElements(this: *mut core::ffi::c_void, psrcelements: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "SetSourceElements", 5usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).SetSourceElements)(this, psrcelements), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSource(this: *mut core::ffi::c_void, purl: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "SetSource", 6usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).SetSource)(this, purl), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentSource(this: *mut core::ffi::c_void, ppurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "GetCurrentSource", 7usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).GetCurrentSource)(this, ppurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNetworkState(this: *mut core::ffi::c_void) -> u16 {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "GetNetworkState", 8usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).GetNetworkState)(this), |_| None)
+        }
+        unsafe extern "system" fn GetPreload(this: *mut core::ffi::c_void) -> MF_MEDIA_ENGINE_PRELOAD {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "GetPreload", 9usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).GetPreload)(this), |_| None)
+        }
+        unsafe extern "system" fn SetPreload(this: *mut core::ffi::c_void, preload: MF_MEDIA_ENGINE_PRELOAD) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "SetPreload", 10usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).SetPreload)(this, preload), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBuffered(this: *mut core::ffi::c_void, ppbuffered: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "GetBuffered", 11usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).GetBuffered)(this, ppbuffered), |result| Some(*result))
+        }
+        unsafe extern "system" fn Load(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "Load", 12usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).Load)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanPlayType(this: *mut core::ffi::c_void, r#type: core::mem::MaybeUninit<windows_core::BSTR>, panswer: *mut MF_MEDIA_ENGINE_CANPLAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "CanPlayType", 13usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).CanPlayType)(this, r#type, panswer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReadyState(this: *mut core::ffi::c_void) -> u16 {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "GetReadyState", 14usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).GetReadyState)(this), |_| None)
+        }
+        unsafe extern "system" fn IsSeeking(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "IsSeeking", 15usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).IsSeeking)(this), |_| None)
+        }
+        unsafe extern "system" fn GetCurrentTime(this: *mut core::ffi::c_void) -> f64 {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "GetCurrentTime", 16usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).GetCurrentTime)(this), |_| None)
+        }
+        unsafe extern "system" fn SetCurrentTime(this: *mut core::ffi::c_void, seektime: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "SetCurrentTime", 17usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).SetCurrentTime)(this, seektime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStartTime(this: *mut core::ffi::c_void) -> f64 {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "GetStartTime", 18usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).GetStartTime)(this), |_| None)
+        }
+        unsafe extern "system" fn GetDuration(this: *mut core::ffi::c_void) -> f64 {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "GetDuration", 19usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).GetDuration)(this), |_| None)
+        }
+        unsafe extern "system" fn IsPaused(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "IsPaused", 20usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).IsPaused)(this), |_| None)
+        }
+        unsafe extern "system" fn GetDefaultPlaybackRate(this: *mut core::ffi::c_void) -> f64 {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "GetDefaultPlaybackRate", 21usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).GetDefaultPlaybackRate)(this), |_| None)
+        }
+        unsafe extern "system" fn SetDefaultPlaybackRate(this: *mut core::ffi::c_void, rate: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "SetDefaultPlaybackRate", 22usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).SetDefaultPlaybackRate)(this, rate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPlaybackRate(this: *mut core::ffi::c_void) -> f64 {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "GetPlaybackRate", 23usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).GetPlaybackRate)(this), |_| None)
+        }
+        unsafe extern "system" fn SetPlaybackRate(this: *mut core::ffi::c_void, rate: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "SetPlaybackRate", 24usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).SetPlaybackRate)(this, rate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPlayed(this: *mut core::ffi::c_void, ppplayed: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "GetPlayed", 25usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).GetPlayed)(this, ppplayed), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSeekable(this: *mut core::ffi::c_void, ppseekable: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "GetSeekable", 26usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).GetSeekable)(this, ppseekable), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEnded(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "IsEnded", 27usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).IsEnded)(this), |_| None)
+        }
+        unsafe extern "system" fn GetAutoPlay(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "GetAutoPlay", 28usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).GetAutoPlay)(this), |_| None)
+        }
+        unsafe extern "system" fn SetAutoPlay(this: *mut core::ffi::c_void, autoplay: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "SetAutoPlay", 29usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).SetAutoPlay)(this, autoplay), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLoop(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "GetLoop", 30usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).GetLoop)(this), |_| None)
+        }
+        unsafe extern "system" fn SetLoop(this: *mut core::ffi::c_void, r#loop: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "SetLoop", 31usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).SetLoop)(this, r#loop), |result| Some(*result))
+        }
+        unsafe extern "system" fn Play(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "Play", 32usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).Play)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "Pause", 33usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMuted(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "GetMuted", 34usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).GetMuted)(this), |_| None)
+        }
+        unsafe extern "system" fn SetMuted(this: *mut core::ffi::c_void, muted: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "SetMuted", 35usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).SetMuted)(this, muted), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVolume(this: *mut core::ffi::c_void) -> f64 {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "GetVolume", 36usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).GetVolume)(this), |_| None)
+        }
+        unsafe extern "system" fn SetVolume(this: *mut core::ffi::c_void, volume: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "SetVolume", 37usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).SetVolume)(this, volume), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasVideo(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "HasVideo", 38usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).HasVideo)(this), |_| None)
+        }
+        unsafe extern "system" fn HasAudio(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "HasAudio", 39usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).HasAudio)(this), |_| None)
+        }
+        unsafe extern "system" fn GetNativeVideoSize(this: *mut core::ffi::c_void, cx: *mut u32, cy: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "GetNativeVideoSize", 40usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).GetNativeVideoSize)(this, cx, cy), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoAspectRatio(this: *mut core::ffi::c_void, cx: *mut u32, cy: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "GetVideoAspectRatio", 41usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).GetVideoAspectRatio)(this, cx, cy), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "Shutdown", 42usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).Shutdown)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransferVideoFrame(this: *mut core::ffi::c_void, pdstsurf: *mut core::ffi::c_void, psrc: *const MFVideoNormalizedRect, pdst: *const super::super::Foundation::RECT, pborderclr: *const MFARGB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "TransferVideoFrame", 43usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).TransferVideoFrame)(this, pdstsurf, psrc, pdst, pborderclr), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnVideoStreamTick(this: *mut core::ffi::c_void, ppts: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngine", "OnVideoStreamTick", 44usize, |this| ((**(this as *const *const IMFMediaEngine_Vtbl)).OnVideoStreamTick)(this, ppts), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetError,
+            SetErrorCode,
+            SetSourceElements,
+            SetSource,
+            GetCurrentSource,
+            GetNetworkState,
+            GetPreload,
+            SetPreload,
+            GetBuffered,
+            Load,
+            CanPlayType,
+            GetReadyState,
+            IsSeeking,
+            GetCurrentTime,
+            SetCurrentTime,
+            GetStartTime,
+            GetDuration,
+            IsPaused,
+            GetDefaultPlaybackRate,
+            SetDefaultPlaybackRate,
+            GetPlaybackRate,
+            SetPlaybackRate,
+            GetPlayed,
+            GetSeekable,
+            IsEnded,
+            GetAutoPlay,
+            SetAutoPlay,
+            GetLoop,
+            SetLoop,
+            Play,
+            Pause,
+            GetMuted,
+            SetMuted,
+            GetVolume,
+            SetVolume,
+            HasVideo,
+            HasAudio,
+            GetNativeVideoSize,
+            GetVideoAspectRatio,
+            Shutdown,
+            TransferVideoFrame,
+            OnVideoStreamTick,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaEngine as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaEngineAudioEndpointId_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAudioEndpointId(this: *mut core::ffi::c_void, pszendpointid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineAudioEndpointId", "SetAudioEndpointId", 3usize, |this| ((**(this as *const *const IMFMediaEngineAudioEndpointId_Vtbl)).SetAudioEndpointId)(this, pszendpointid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAudioEndpointId(this: *mut core::ffi::c_void, ppszendpointid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineAudioEndpointId", "GetAudioEndpointId", 4usize, |this| ((**(this as *const *const IMFMediaEngineAudioEndpointId_Vtbl)).GetAudioEndpointId)(this, ppszendpointid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAudioEndpointId, GetAudioEndpointId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaEngineAudioEndpointId as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaEngineClassFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, dwflags: u32, pattr: *mut core::ffi::c_void, ppplayer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineClassFactory", "CreateInstance", 3usize, |this| ((**(this as *const *const IMFMediaEngineClassFactory_Vtbl)).CreateInstance)(this, dwflags, pattr, ppplayer), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTimeRange(this: *mut core::ffi::c_void, pptimerange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineClassFactory", "CreateTimeRange", 4usize, |this| ((**(this as *const *const IMFMediaEngineClassFactory_Vtbl)).CreateTimeRange)(this, pptimerange), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateError(this: *mut core::ffi::c_void, pperror: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineClassFactory", "CreateError", 5usize, |this| ((**(this as *const *const IMFMediaEngineClassFactory_Vtbl)).CreateError)(this, pperror), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance, CreateTimeRange, CreateError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaEngineClassFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaEngineClassFactory2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateMediaKeys2(this: *mut core::ffi::c_void, keysystem: core::mem::MaybeUninit<windows_core::BSTR>, defaultcdmstorepath: core::mem::MaybeUninit<windows_core::BSTR>, inprivatecdmstorepath: core::mem::MaybeUninit<windows_core::BSTR>, ppkeys: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineClassFactory2", "CreateMediaKeys2", 3usize, |this| ((**(this as *const *const IMFMediaEngineClassFactory2_Vtbl)).CreateMediaKeys2)(this, keysystem, defaultcdmstorepath, inprivatecdmstorepath, ppkeys), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateMediaKeys2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaEngineClassFactory2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IMFMediaEngineClassFactory3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn CreateMediaKeySystemAccess(this: *mut core::ffi::c_void, keysystem: core::mem::MaybeUninit<windows_core::BSTR>, ppsupportedconfigurationsarray: *const *mut core::ffi::c_void, usize: u32, ppkeyaccess: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineClassFactory3", "CreateMediaKeySystemAccess", 3usize, |this| ((**(this as *const *const IMFMediaEngineClassFactory3_Vtbl)).CreateMediaKeySystemAccess)(this, keysystem, ppsupportedconfigurationsarray, usize, ppkeyaccess), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            CreateMediaKeySystemAccess,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            CreateMediaKeySystemAccess: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaEngineClassFactory3 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaEngineClassFactory4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateContentDecryptionModuleFactory(this: *mut core::ffi::c_void, keysystem: windows_core::PCWSTR, riid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineClassFactory4", "CreateContentDecryptionModuleFactory", 3usize, |this| ((**(this as *const *const IMFMediaEngineClassFactory4_Vtbl)).CreateContentDecryptionModuleFactory)(this, keysystem, riid, ppvobject), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateContentDecryptionModuleFactory }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaEngineClassFactory4 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaEngineClassFactoryEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateMediaSourceExtension(this: *mut core::ffi::c_void, dwflags: u32, pattr: *mut core::ffi::c_void, ppmse: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineClassFactoryEx", "CreateMediaSourceExtension", 6usize, |this| ((**(this as *const *const IMFMediaEngineClassFactoryEx_Vtbl)).CreateMediaSourceExtension)(this, dwflags, pattr, ppmse), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateMediaKeys(this: *mut core::ffi::c_void, keysystem: core::mem::MaybeUninit<windows_core::BSTR>, cdmstorepath: core::mem::MaybeUninit<windows_core::BSTR>, ppkeys: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineClassFactoryEx", "CreateMediaKeys", 7usize, |this| ((**(this as *const *const IMFMediaEngineClassFactoryEx_Vtbl)).CreateMediaKeys)(this, keysystem, cdmstorepath, ppkeys), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTypeSupported(this: *mut core::ffi::c_void, r#type: core::mem::MaybeUninit<windows_core::BSTR>, keysystem: core::mem::MaybeUninit<windows_core::BSTR>, issupported: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineClassFactoryEx", "IsTypeSupported", 8usize, |this| ((**(this as *const *const IMFMediaEngineClassFactoryEx_Vtbl)).IsTypeSupported)(this, r#type, keysystem, issupported), |result| Some(*result))
+        }
+        Self { base__: <IMFMediaEngineClassFactory_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateMediaSourceExtension, CreateMediaKeys, IsTypeSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaEngineClassFactoryEx as windows_core::Interface>::IID || <IMFMediaEngineClassFactory_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaEngineEME_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Keys(this: *mut core::ffi::c_void, keys: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEME", "Keys", 3usize, |this| ((**(this as *const *const IMFMediaEngineEME_Vtbl)).Keys)(this, keys), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMediaKeys(this: *mut core::ffi::c_void, keys: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEME", "SetMediaKeys", 4usize, |this| ((**(this as *const *const IMFMediaEngineEME_Vtbl)).SetMediaKeys)(this, keys), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Keys, SetMediaKeys }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaEngineEME as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaEngineEMENotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Encrypted(this: *mut core::ffi::c_void, pbinitdata: *const u8, cb: u32, bstrinitdatatype: core::mem::MaybeUninit<windows_core::BSTR>) {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEMENotify", "Encrypted", 3usize, |this| ((**(this as *const *const IMFMediaEngineEMENotify_Vtbl)).Encrypted)(this, pbinitdata, cb, bstrinitdatatype), |_| None)
+        }
+        unsafe extern "system" fn WaitingForKey(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEMENotify", "WaitingForKey", 4usize, |this| ((**(this as *const *const IMFMediaEngineEMENotify_Vtbl)).WaitingForKey)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Encrypted, WaitingForKey }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaEngineEMENotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaEngineEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSourceFromByteStream(this: *mut core::ffi::c_void, pbytestream: *mut core::ffi::c_void, purl: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "SetSourceFromByteStream", 45usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).SetSourceFromByteStream)(this, pbytestream, purl), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatistics(this: *mut core::ffi::c_void, statisticid: MF_MEDIA_ENGINE_STATISTIC, pstatistic: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "GetStatistics", 46usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).GetStatistics)(this, statisticid, pstatistic), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateVideoStream(this: *mut core::ffi::c_void, psrc: *const MFVideoNormalizedRect, pdst: *const super::super::Foundation::RECT, pborderclr: *const MFARGB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "UpdateVideoStream", 47usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).UpdateVideoStream)(this, psrc, pdst, pborderclr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBalance(this: *mut core::ffi::c_void) -> f64 {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "GetBalance", 48usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).GetBalance)(this), |_| None)
+        }
+        unsafe extern "system" fn SetBalance(this: *mut core::ffi::c_void, balance: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "SetBalance", 49usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).SetBalance)(this, balance), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPlaybackRateSupported(this: *mut core::ffi::c_void, rate: f64) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "IsPlaybackRateSupported", 50usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).IsPlaybackRateSupported)(this, rate), |_| None)
+        }
+        unsafe extern "system" fn FrameStep(this: *mut core::ffi::c_void, forward: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "FrameStep", 51usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).FrameStep)(this, forward), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResourceCharacteristics(this: *mut core::ffi::c_void, pcharacteristics: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "GetResourceCharacteristics", 52usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).GetResourceCharacteristics)(this, pcharacteristics), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPresentationAttribute(this: *mut core::ffi::c_void, guidmfattribute: *const windows_core::GUID, pvvalue: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "GetPresentationAttribute", 53usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).GetPresentationAttribute)(this, guidmfattribute, pvvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumberOfStreams(this: *mut core::ffi::c_void, pdwstreamcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "GetNumberOfStreams", 54usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).GetNumberOfStreams)(this, pdwstreamcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamAttribute(this: *mut core::ffi::c_void, dwstreamindex: u32, guidmfattribute: *const windows_core::GUID, pvvalue: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "GetStreamAttribute", 55usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).GetStreamAttribute)(this, dwstreamindex, guidmfattribute, pvvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamSelection(this: *mut core::ffi::c_void, dwstreamindex: u32, penabled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "GetStreamSelection", 56usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).GetStreamSelection)(this, dwstreamindex, penabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStreamSelection(this: *mut core::ffi::c_void, dwstreamindex: u32, enabled: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "SetStreamSelection", 57usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).SetStreamSelection)(this, dwstreamindex, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplyStreamSelections(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "ApplyStreamSelections", 58usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).ApplyStreamSelections)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsProtected(this: *mut core::ffi::c_void, pprotected: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "IsProtected", 59usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).IsProtected)(this, pprotected), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertVideoEffect(this: *mut core::ffi::c_void, peffect: *mut core::ffi::c_void, foptional: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "InsertVideoEffect", 60usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).InsertVideoEffect)(this, peffect, foptional), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertAudioEffect(this: *mut core::ffi::c_void, peffect: *mut core::ffi::c_void, foptional: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "InsertAudioEffect", 61usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).InsertAudioEffect)(this, peffect, foptional), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAllEffects(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "RemoveAllEffects", 62usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).RemoveAllEffects)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTimelineMarkerTimer(this: *mut core::ffi::c_void, timetofire: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "SetTimelineMarkerTimer", 63usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).SetTimelineMarkerTimer)(this, timetofire), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTimelineMarkerTimer(this: *mut core::ffi::c_void, ptimetofire: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "GetTimelineMarkerTimer", 64usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).GetTimelineMarkerTimer)(this, ptimetofire), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelTimelineMarkerTimer(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "CancelTimelineMarkerTimer", 65usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).CancelTimelineMarkerTimer)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStereo3D(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "IsStereo3D", 66usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).IsStereo3D)(this), |_| None)
+        }
+        unsafe extern "system" fn GetStereo3DFramePackingMode(this: *mut core::ffi::c_void, packmode: *mut MF_MEDIA_ENGINE_S3D_PACKING_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "GetStereo3DFramePackingMode", 67usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).GetStereo3DFramePackingMode)(this, packmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStereo3DFramePackingMode(this: *mut core::ffi::c_void, packmode: MF_MEDIA_ENGINE_S3D_PACKING_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "SetStereo3DFramePackingMode", 68usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).SetStereo3DFramePackingMode)(this, packmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStereo3DRenderMode(this: *mut core::ffi::c_void, outputtype: *mut MF3DVideoOutputType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "GetStereo3DRenderMode", 69usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).GetStereo3DRenderMode)(this, outputtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStereo3DRenderMode(this: *mut core::ffi::c_void, outputtype: MF3DVideoOutputType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "SetStereo3DRenderMode", 70usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).SetStereo3DRenderMode)(this, outputtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableWindowlessSwapchainMode(this: *mut core::ffi::c_void, fenable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "EnableWindowlessSwapchainMode", 71usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).EnableWindowlessSwapchainMode)(this, fenable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoSwapchainHandle(this: *mut core::ffi::c_void, phswapchain: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "GetVideoSwapchainHandle", 72usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).GetVideoSwapchainHandle)(this, phswapchain), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableHorizontalMirrorMode(this: *mut core::ffi::c_void, fenable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "EnableHorizontalMirrorMode", 73usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).EnableHorizontalMirrorMode)(this, fenable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAudioStreamCategory(this: *mut core::ffi::c_void, pcategory: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "GetAudioStreamCategory", 74usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).GetAudioStreamCategory)(this, pcategory), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAudioStreamCategory(this: *mut core::ffi::c_void, category: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "SetAudioStreamCategory", 75usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).SetAudioStreamCategory)(this, category), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAudioEndpointRole(this: *mut core::ffi::c_void, prole: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "GetAudioEndpointRole", 76usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).GetAudioEndpointRole)(this, prole), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAudioEndpointRole(this: *mut core::ffi::c_void, role: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "SetAudioEndpointRole", 77usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).SetAudioEndpointRole)(this, role), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRealTimeMode(this: *mut core::ffi::c_void, pfenabled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "GetRealTimeMode", 78usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).GetRealTimeMode)(this, pfenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRealTimeMode(this: *mut core::ffi::c_void, fenable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "SetRealTimeMode", 79usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).SetRealTimeMode)(this, fenable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentTimeEx(this: *mut core::ffi::c_void, seektime: f64, seekmode: MF_MEDIA_ENGINE_SEEK_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "SetCurrentTimeEx", 80usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).SetCurrentTimeEx)(this, seektime, seekmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableTimeUpdateTimer(this: *mut core::ffi::c_void, fenabletimer: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineEx", "EnableTimeUpdateTimer", 81usize, |this| ((**(this as *const *const IMFMediaEngineEx_Vtbl)).EnableTimeUpdateTimer)(this, fenabletimer), |result| Some(*result))
+        }
+        Self {
+            base__: <IMFMediaEngine_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetSourceFromByteStream,
+            GetStatistics,
+            UpdateVideoStream,
+            GetBalance,
+            SetBalance,
+            IsPlaybackRateSupported,
+            FrameStep,
+            GetResourceCharacteristics,
+            GetPresentationAttribute,
+            GetNumberOfStreams,
+            GetStreamAttribute,
+            GetStreamSelection,
+            SetStreamSelection,
+            ApplyStreamSelections,
+            IsProtected,
+            InsertVideoEffect,
+            InsertAudioEffect,
+            RemoveAllEffects,
+            SetTimelineMarkerTimer,
+            GetTimelineMarkerTimer,
+            CancelTimelineMarkerTimer,
+            IsStereo3D,
+            GetStereo3DFramePackingMode,
+            SetStereo3DFramePackingMode,
+            GetStereo3DRenderMode,
+            SetStereo3DRenderMode,
+            EnableWindowlessSwapchainMode,
+            GetVideoSwapchainHandle,
+            EnableHorizontalMirrorMode,
+            GetAudioStreamCategory,
+            SetAudioStreamCategory,
+            GetAudioEndpointRole,
+            SetAudioEndpointRole,
+            GetRealTimeMode,
+            SetRealTimeMode,
+            SetCurrentTimeEx,
+            EnableTimeUpdateTimer,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaEngineEx as windows_core::Interface>::IID || <IMFMediaEngine_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaEngineExtension_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CanPlayType(this: *mut core::ffi::c_void, audioonly: super::super::Foundation::BOOL, mimetype: core::mem::MaybeUninit<windows_core::BSTR>, panswer: *mut MF_MEDIA_ENGINE_CANPLAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineExtension", "CanPlayType", 3usize, |this| ((**(this as *const *const IMFMediaEngineExtension_Vtbl)).CanPlayType)(this, audioonly, mimetype, panswer), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginCreateObject(this: *mut core::ffi::c_void, bstrurl: core::mem::MaybeUninit<windows_core::BSTR>, pbytestream: *mut core::ffi::c_void, r#type: MF_OBJECT_TYPE, ppiunknowncancelcookie: *mut *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, punkstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineExtension", "BeginCreateObject", 4usize, |this| ((**(this as *const *const IMFMediaEngineExtension_Vtbl)).BeginCreateObject)(this, bstrurl, pbytestream, r#type, ppiunknowncancelcookie, pcallback, punkstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelObjectCreation(this: *mut core::ffi::c_void, piunknowncancelcookie: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineExtension", "CancelObjectCreation", 5usize, |this| ((**(this as *const *const IMFMediaEngineExtension_Vtbl)).CancelObjectCreation)(this, piunknowncancelcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndCreateObject(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineExtension", "EndCreateObject", 6usize, |this| ((**(this as *const *const IMFMediaEngineExtension_Vtbl)).EndCreateObject)(this, presult, ppobject), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CanPlayType,
+            BeginCreateObject,
+            CancelObjectCreation,
+            EndCreateObject,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaEngineExtension as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaEngineNeedKeyNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NeedKey(this: *mut core::ffi::c_void, initdata: *const u8, cb: u32) {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineNeedKeyNotify", "NeedKey", 3usize, |this| ((**(this as *const *const IMFMediaEngineNeedKeyNotify_Vtbl)).NeedKey)(this, initdata, cb), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NeedKey }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaEngineNeedKeyNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaEngineNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EventNotify(this: *mut core::ffi::c_void, event: u32, param1: usize, param2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineNotify", "EventNotify", 3usize, |this| ((**(this as *const *const IMFMediaEngineNotify_Vtbl)).EventNotify)(this, event, param1, param2), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EventNotify }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaEngineNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaEngineOPMInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOPMInfo(this: *mut core::ffi::c_void, pstatus: *mut MF_MEDIA_ENGINE_OPM_STATUS, pconstricted: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineOPMInfo", "GetOPMInfo", 3usize, |this| ((**(this as *const *const IMFMediaEngineOPMInfo_Vtbl)).GetOPMInfo)(this, pstatus, pconstricted), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetOPMInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaEngineOPMInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaEngineProtectedContent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShareResources(this: *mut core::ffi::c_void, punkdevicecontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineProtectedContent", "ShareResources", 3usize, |this| ((**(this as *const *const IMFMediaEngineProtectedContent_Vtbl)).ShareResources)(this, punkdevicecontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRequiredProtections(this: *mut core::ffi::c_void, pframeprotectionflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineProtectedContent", "GetRequiredProtections", 4usize, |this| ((**(this as *const *const IMFMediaEngineProtectedContent_Vtbl)).GetRequiredProtections)(this, pframeprotectionflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOPMWindow(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineProtectedContent", "SetOPMWindow", 5usize, |this| ((**(this as *const *const IMFMediaEngineProtectedContent_Vtbl)).SetOPMWindow)(this, hwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransferVideoFrame(this: *mut core::ffi::c_void, pdstsurf: *mut core::ffi::c_void, psrc: *const MFVideoNormalizedRect, pdst: *const super::super::Foundation::RECT, pborderclr: *const MFARGB, pframeprotectionflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineProtectedContent", "TransferVideoFrame", 6usize, |this| ((**(this as *const *const IMFMediaEngineProtectedContent_Vtbl)).TransferVideoFrame)(this, pdstsurf, psrc, pdst, pborderclr, pframeprotectionflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContentProtectionManager(this: *mut core::ffi::c_void, pcpm: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineProtectedContent", "SetContentProtectionManager", 7usize, |this| ((**(this as *const *const IMFMediaEngineProtectedContent_Vtbl)).SetContentProtectionManager)(this, pcpm), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetApplicationCertificate(this: *mut core::ffi::c_void, pbblob: *const u8, cbblob: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineProtectedContent", "SetApplicationCertificate", 8usize, |this| ((**(this as *const *const IMFMediaEngineProtectedContent_Vtbl)).SetApplicationCertificate)(this, pbblob, cbblob), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ShareResources,
+            GetRequiredProtections,
+            SetOPMWindow,
+            TransferVideoFrame,
+            SetContentProtectionManager,
+            SetApplicationCertificate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaEngineProtectedContent as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaEngineSrcElements_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineSrcElements", "GetLength", 3usize, |this| ((**(this as *const *const IMFMediaEngineSrcElements_Vtbl)).GetLength)(this), |_| None)
+        }
+        unsafe extern "system" fn GetURL(this: *mut core::ffi::c_void, index: u32, purl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineSrcElements", "GetURL", 4usize, |this| ((**(this as *const *const IMFMediaEngineSrcElements_Vtbl)).GetURL)(this, index, purl), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, index: u32, ptype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineSrcElements", "GetType", 5usize, |this| ((**(this as *const *const IMFMediaEngineSrcElements_Vtbl)).GetType)(this, index, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMedia(this: *mut core::ffi::c_void, index: u32, pmedia: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineSrcElements", "GetMedia", 6usize, |this| ((**(this as *const *const IMFMediaEngineSrcElements_Vtbl)).GetMedia)(this, index, pmedia), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddElement(this: *mut core::ffi::c_void, purl: core::mem::MaybeUninit<windows_core::BSTR>, ptype: core::mem::MaybeUninit<windows_core::BSTR>, pmedia: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineSrcElements", "AddElement", 7usize, |this| ((**(this as *const *const IMFMediaEngineSrcElements_Vtbl)).AddElement)(this, purl, ptype, pmedia), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAllElements(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineSrcElements", "RemoveAllElements", 8usize, |this| ((**(this as *const *const IMFMediaEngineSrcElements_Vtbl)).RemoveAllElements)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetLength,
+            GetURL,
+            GetType,
+            GetMedia,
+            AddElement,
+            RemoveAllElements,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaEngineSrcElements as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaEngineSrcElementsEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddElementEx(this: *mut core::ffi::c_void, purl: core::mem::MaybeUninit<windows_core::BSTR>, ptype: core::mem::MaybeUninit<windows_core::BSTR>, pmedia: core::mem::MaybeUninit<windows_core::BSTR>, keysystem: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineSrcElementsEx", "AddElementEx", 9usize, |this| ((**(this as *const *const IMFMediaEngineSrcElementsEx_Vtbl)).AddElementEx)(this, purl, ptype, pmedia, keysystem), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKeySystem(this: *mut core::ffi::c_void, index: u32, ptype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineSrcElementsEx", "GetKeySystem", 10usize, |this| ((**(this as *const *const IMFMediaEngineSrcElementsEx_Vtbl)).GetKeySystem)(this, index, ptype), |result| Some(*result))
+        }
+        Self { base__: <IMFMediaEngineSrcElements_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddElementEx, GetKeySystem }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaEngineSrcElementsEx as windows_core::Interface>::IID || <IMFMediaEngineSrcElements_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaEngineSupportsSourceTransfer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShouldTransferSource(this: *mut core::ffi::c_void, pfshouldtransfer: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineSupportsSourceTransfer", "ShouldTransferSource", 3usize, |this| ((**(this as *const *const IMFMediaEngineSupportsSourceTransfer_Vtbl)).ShouldTransferSource)(this, pfshouldtransfer), |result| Some(*result))
+        }
+        unsafe extern "system" fn DetachMediaSource(this: *mut core::ffi::c_void, ppbytestream: *mut *mut core::ffi::c_void, ppmediasource: *mut *mut core::ffi::c_void, ppmse: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineSupportsSourceTransfer", "DetachMediaSource", 4usize, |this| ((**(this as *const *const IMFMediaEngineSupportsSourceTransfer_Vtbl)).DetachMediaSource)(this, ppbytestream, ppmediasource, ppmse), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttachMediaSource(this: *mut core::ffi::c_void, pbytestream: *mut core::ffi::c_void, pmediasource: *mut core::ffi::c_void, pmse: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineSupportsSourceTransfer", "AttachMediaSource", 5usize, |this| ((**(this as *const *const IMFMediaEngineSupportsSourceTransfer_Vtbl)).AttachMediaSource)(this, pbytestream, pmediasource, pmse), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShouldTransferSource, DetachMediaSource, AttachMediaSource }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaEngineSupportsSourceTransfer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaEngineTransferSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TransferSourceToMediaEngine(this: *mut core::ffi::c_void, destination: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineTransferSource", "TransferSourceToMediaEngine", 3usize, |this| ((**(this as *const *const IMFMediaEngineTransferSource_Vtbl)).TransferSourceToMediaEngine)(this, destination), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TransferSourceToMediaEngine }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaEngineTransferSource as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaEngineWebSupport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShouldDelayTheLoadEvent(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineWebSupport", "ShouldDelayTheLoadEvent", 3usize, |this| ((**(this as *const *const IMFMediaEngineWebSupport_Vtbl)).ShouldDelayTheLoadEvent)(this), |_| None)
+        }
+        unsafe extern "system" fn ConnectWebAudio(this: *mut core::ffi::c_void, dwsamplerate: u32, ppsourceprovider: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineWebSupport", "ConnectWebAudio", 4usize, |this| ((**(this as *const *const IMFMediaEngineWebSupport_Vtbl)).ConnectWebAudio)(this, dwsamplerate, ppsourceprovider), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisconnectWebAudio(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEngineWebSupport", "DisconnectWebAudio", 5usize, |this| ((**(this as *const *const IMFMediaEngineWebSupport_Vtbl)).DisconnectWebAudio)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShouldDelayTheLoadEvent, ConnectWebAudio, DisconnectWebAudio }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaEngineWebSupport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaError_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetErrorCode(this: *mut core::ffi::c_void) -> u16 {
+            windows_core::imp::proxy_call(this, "IMFMediaError", "GetErrorCode", 3usize, |this| ((**(this as *const *const IMFMediaError_Vtbl)).GetErrorCode)(this), |_| None)
+        }
+        unsafe extern "system" fn GetExtendedErrorCode(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaError", "GetExtendedErrorCode", 4usize, |this| ((**(this as *const *const IMFMediaError_Vtbl)).GetExtendedErrorCode)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetErrorCode(this: *mut core::ffi::c_void, error: MF_MEDIA_ENGINE_ERR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaError", "SetErrorCode", 5usize, |this| ((**(this as *const *const IMFMediaError_Vtbl)).SetErrorCode)(this, error), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExtendedErrorCode(this: *mut core::ffi::c_void, error: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaError", "SetExtendedErrorCode", 6usize, |this| ((**(this as *const *const IMFMediaError_Vtbl)).SetExtendedErrorCode)(this, error), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetErrorCode,
+            GetExtendedErrorCode,
+            SetErrorCode,
+            SetExtendedErrorCode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaError as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, pmet: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEvent", "GetType", 33usize, |this| ((**(this as *const *const IMFMediaEvent_Vtbl)).GetType)(this, pmet), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExtendedType(this: *mut core::ffi::c_void, pguidextendedtype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEvent", "GetExtendedType", 34usize, |this| ((**(this as *const *const IMFMediaEvent_Vtbl)).GetExtendedType)(this, pguidextendedtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, phrstatus: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEvent", "GetStatus", 35usize, |this| ((**(this as *const *const IMFMediaEvent_Vtbl)).GetStatus)(this, phrstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, pvvalue: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEvent", "GetValue", 36usize, |this| ((**(this as *const *const IMFMediaEvent_Vtbl)).GetValue)(this, pvvalue), |result| Some(*result))
+        }
+        Self { base__: <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetType, GetExtendedType, GetStatus, GetValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaEvent as windows_core::Interface>::IID || <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaEventGenerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEvent(this: *mut core::ffi::c_void, dwflags: MEDIA_EVENT_GENERATOR_GET_EVENT_FLAGS, ppevent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEventGenerator", "GetEvent", 3usize, |this| ((**(this as *const *const IMFMediaEventGenerator_Vtbl)).GetEvent)(this, dwflags, ppevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginGetEvent(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, punkstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEventGenerator", "BeginGetEvent", 4usize, |this| ((**(this as *const *const IMFMediaEventGenerator_Vtbl)).BeginGetEvent)(this, pcallback, punkstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndGetEvent(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void, ppevent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEventGenerator", "EndGetEvent", 5usize, |this| ((**(this as *const *const IMFMediaEventGenerator_Vtbl)).EndGetEvent)(this, presult, ppevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueueEvent(this: *mut core::ffi::c_void, met: u32, guidextendedtype: *const windows_core::GUID, hrstatus: windows_core::HRESULT, pvvalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEventGenerator", "QueueEvent", 6usize, |this| ((**(this as *const *const IMFMediaEventGenerator_Vtbl)).QueueEvent)(this, met, guidextendedtype, hrstatus, pvvalue), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetEvent, BeginGetEvent, EndGetEvent, QueueEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaEventGenerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaEventQueue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEvent(this: *mut core::ffi::c_void, dwflags: u32, ppevent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEventQueue", "GetEvent", 3usize, |this| ((**(this as *const *const IMFMediaEventQueue_Vtbl)).GetEvent)(this, dwflags, ppevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginGetEvent(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, punkstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEventQueue", "BeginGetEvent", 4usize, |this| ((**(this as *const *const IMFMediaEventQueue_Vtbl)).BeginGetEvent)(this, pcallback, punkstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndGetEvent(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void, ppevent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEventQueue", "EndGetEvent", 5usize, |this| ((**(this as *const *const IMFMediaEventQueue_Vtbl)).EndGetEvent)(this, presult, ppevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueueEvent(this: *mut core::ffi::c_void, pevent: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEventQueue", "QueueEvent", 6usize, |this| ((**(this as *const *const IMFMediaEventQueue_Vtbl)).QueueEvent)(this, pevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueueEventParamVar(this: *mut core::ffi::c_void, met: u32, guidextendedtype: *const windows_core::GUID, hrstatus: windows_core::HRESULT, pvvalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEventQueue", "QueueEventParamVar", 7usize, |this| ((**(this as *const *const IMFMediaEventQueue_Vtbl)).QueueEventParamVar)(this, met, guidextendedtype, hrstatus, pvvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueueEventParamUnk(this: *mut core::ffi::c_void, met: u32, guidextendedtype: *const windows_core::GUID, hrstatus: windows_core::HRESULT, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEventQueue", "QueueEventParamUnk", 8usize, |this| ((**(this as *const *const IMFMediaEventQueue_Vtbl)).QueueEventParamUnk)(this, met, guidextendedtype, hrstatus, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaEventQueue", "Shutdown", 9usize, |this| ((**(this as *const *const IMFMediaEventQueue_Vtbl)).Shutdown)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetEvent,
+            BeginGetEvent,
+            EndGetEvent,
+            QueueEvent,
+            QueueEventParamVar,
+            QueueEventParamUnk,
+            Shutdown,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaEventQueue as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaKeySession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetError(this: *mut core::ffi::c_void, code: *mut u16, systemcode: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaKeySession", "GetError", 3usize, |this| ((**(this as *const *const IMFMediaKeySession_Vtbl)).GetError)(this, code, systemcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeySystem(this: *mut core::ffi::c_void, keysystem: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaKeySession", "KeySystem", 4usize, |this| ((**(this as *const *const IMFMediaKeySession_Vtbl)).KeySystem)(this, keysystem), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionId(this: *mut core::ffi::c_void, sessionid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaKeySession", "SessionId", 5usize, |this| ((**(this as *const *const IMFMediaKeySession_Vtbl)).SessionId)(this, sessionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void, key: *const u8, cb: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaKeySession", "Update", 6usize, |this| ((**(this as *const *const IMFMediaKeySession_Vtbl)).Update)(this, key, cb), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaKeySession", "Close", 7usize, |this| ((**(this as *const *const IMFMediaKeySession_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetError, KeySystem, SessionId, Update, Close }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaKeySession as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaKeySession2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_KeyStatuses(this: *mut core::ffi::c_void, pkeystatusesarray: *mut *mut MFMediaKeyStatus, pusize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaKeySession2", "get_KeyStatuses", 8usize, |this| ((**(this as *const *const IMFMediaKeySession2_Vtbl)).get_KeyStatuses)(this, pkeystatusesarray, pusize), |result| Some(*result))
+        }
+        unsafe extern "system" fn Load(this: *mut core::ffi::c_void, bstrsessionid: core::mem::MaybeUninit<windows_core::BSTR>, pfloaded: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaKeySession2", "Load", 9usize, |this| ((**(this as *const *const IMFMediaKeySession2_Vtbl)).Load)(this, bstrsessionid, pfloaded), |result| Some(*result))
+        }
+        unsafe extern "system" fn GenerateRequest(this: *mut core::ffi::c_void, initdatatype: core::mem::MaybeUninit<windows_core::BSTR>, pbinitdata: *const u8, cb: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaKeySession2", "GenerateRequest", 10usize, |this| ((**(this as *const *const IMFMediaKeySession2_Vtbl)).GenerateRequest)(this, initdatatype, pbinitdata, cb), |result| Some(*result))
+        }
+        unsafe extern "system" fn Expiration(this: *mut core::ffi::c_void, dblexpiration: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaKeySession2", "Expiration", 11usize, |this| ((**(this as *const *const IMFMediaKeySession2_Vtbl)).Expiration)(this, dblexpiration), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaKeySession2", "Remove", 12usize, |this| ((**(this as *const *const IMFMediaKeySession2_Vtbl)).Remove)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaKeySession2", "Shutdown", 13usize, |this| ((**(this as *const *const IMFMediaKeySession2_Vtbl)).Shutdown)(this), |result| Some(*result))
+        }
+        Self { base__: <IMFMediaKeySession_Vtbl as windows_core::imp::ProxyVtable>::PROXY, get_KeyStatuses, Load, GenerateRequest, Expiration, Remove, Shutdown }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaKeySession2 as windows_core::Interface>::IID || <IMFMediaKeySession_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaKeySessionNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn KeyMessage(this: *mut core::ffi::c_void, destinationurl: core::mem::MaybeUninit<windows_core::BSTR>, message: *const u8, cb: u32) {
+            windows_core::imp::proxy_call(this, "IMFMediaKeySessionNotify", "KeyMessage", 3usize, |this| ((**(this as *const *const IMFMediaKeySessionNotify_Vtbl)).KeyMessage)(this, destinationurl, message, cb), |_| None)
+        }
+        unsafe extern "system" fn KeyAdded(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IMFMediaKeySessionNotify", "KeyAdded", 4usize, |this| ((**(this as *const *const IMFMediaKeySessionNotify_Vtbl)).KeyAdded)(this), |_| None)
+        }
+        unsafe extern "system" fn KeyError(this: *mut core::ffi::c_void, code: u16, systemcode: u32) {
+            windows_core::imp::proxy_call(this, "IMFMediaKeySessionNotify", "KeyError", 5usize, |this| ((**(this as *const *const IMFMediaKeySessionNotify_Vtbl)).KeyError)(this, code, systemcode), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, KeyMessage, KeyAdded, KeyError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaKeySessionNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaKeySessionNotify2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn KeyMessage2(this: *mut core::ffi::c_void, emessagetype: MF_MEDIAKEYSESSION_MESSAGETYPE, destinationurl: core::mem::MaybeUninit<windows_core::BSTR>, pbmessage: *const u8, cbmessage: u32) {
+            windows_core::imp::proxy_call(this, "IMFMediaKeySessionNotify2", "KeyMessage2", 6usize, |this| ((**(this as *const *const IMFMediaKeySessionNotify2_Vtbl)).KeyMessage2)(this, emessagetype, destinationurl, pbmessage, cbmessage), |_| None)
+        }
+        unsafe extern "system" fn KeyStatusChange(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IMFMediaKeySessionNotify2", "KeyStatusChange", 7usize, |this| ((**(this as *const *const IMFMediaKeySessionNotify2_Vtbl)).KeyStatusChange)(this), |_| None)
+        }
+        Self { base__: <IMFMediaKeySessionNotify_Vtbl as windows_core::imp::ProxyVtable>::PROXY, KeyMessage2, KeyStatusChange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaKeySessionNotify2 as windows_core::Interface>::IID || <IMFMediaKeySessionNotify_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IMFMediaKeySystemAccess_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn CreateMediaKeys(this: *mut core::ffi::c_void, pcdmcustomconfig: *mut core::ffi::c_void, ppkeys: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaKeySystemAccess", "CreateMediaKeys", 3usize, |this| ((**(this as *const *const IMFMediaKeySystemAccess_Vtbl)).CreateMediaKeys)(this, pcdmcustomconfig, ppkeys), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn SupportedConfiguration(this: *mut core::ffi::c_void, ppsupportedconfiguration: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaKeySystemAccess", "SupportedConfiguration", 4usize, |this| ((**(this as *const *const IMFMediaKeySystemAccess_Vtbl)).SupportedConfiguration)(this, ppsupportedconfiguration), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeySystem(this: *mut core::ffi::c_void, pkeysystem: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaKeySystemAccess", "KeySystem", 5usize, |this| ((**(this as *const *const IMFMediaKeySystemAccess_Vtbl)).KeySystem)(this, pkeysystem), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            CreateMediaKeys,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            CreateMediaKeys: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            SupportedConfiguration,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            SupportedConfiguration: 0,
+            KeySystem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaKeySystemAccess as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaKeys_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateSession(this: *mut core::ffi::c_void, mimetype: core::mem::MaybeUninit<windows_core::BSTR>, initdata: *const u8, cb: u32, customdata: *const u8, cbcustomdata: u32, notify: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaKeys", "CreateSession", 3usize, |this| ((**(this as *const *const IMFMediaKeys_Vtbl)).CreateSession)(this, mimetype, initdata, cb, customdata, cbcustomdata, notify, ppsession), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeySystem(this: *mut core::ffi::c_void, keysystem: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaKeys", "KeySystem", 4usize, |this| ((**(this as *const *const IMFMediaKeys_Vtbl)).KeySystem)(this, keysystem), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaKeys", "Shutdown", 5usize, |this| ((**(this as *const *const IMFMediaKeys_Vtbl)).Shutdown)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSuspendNotify(this: *mut core::ffi::c_void, notify: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaKeys", "GetSuspendNotify", 6usize, |this| ((**(this as *const *const IMFMediaKeys_Vtbl)).GetSuspendNotify)(this, notify), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateSession, KeySystem, Shutdown, GetSuspendNotify }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaKeys as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaKeys2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateSession2(this: *mut core::ffi::c_void, esessiontype: MF_MEDIAKEYSESSION_TYPE, pmfmediakeysessionnotify2: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaKeys2", "CreateSession2", 7usize, |this| ((**(this as *const *const IMFMediaKeys2_Vtbl)).CreateSession2)(this, esessiontype, pmfmediakeysessionnotify2, ppsession), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServerCertificate(this: *mut core::ffi::c_void, pbservercertificate: *const u8, cb: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaKeys2", "SetServerCertificate", 8usize, |this| ((**(this as *const *const IMFMediaKeys2_Vtbl)).SetServerCertificate)(this, pbservercertificate, cb), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDOMException(this: *mut core::ffi::c_void, systemcode: windows_core::HRESULT, code: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaKeys2", "GetDOMException", 9usize, |this| ((**(this as *const *const IMFMediaKeys2_Vtbl)).GetDOMException)(this, systemcode, code), |result| Some(*result))
+        }
+        Self { base__: <IMFMediaKeys_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateSession2, SetServerCertificate, GetDOMException }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaKeys2 as windows_core::Interface>::IID || <IMFMediaKeys_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetTopology(this: *mut core::ffi::c_void, dwsettopologyflags: u32, ptopology: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSession", "SetTopology", 7usize, |this| ((**(this as *const *const IMFMediaSession_Vtbl)).SetTopology)(this, dwsettopologyflags, ptopology), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearTopologies(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSession", "ClearTopologies", 8usize, |this| ((**(this as *const *const IMFMediaSession_Vtbl)).ClearTopologies)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void, pguidtimeformat: *const windows_core::GUID, pvarstartposition: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSession", "Start", 9usize, |this| ((**(this as *const *const IMFMediaSession_Vtbl)).Start)(this, pguidtimeformat, pvarstartposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSession", "Pause", 10usize, |this| ((**(this as *const *const IMFMediaSession_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSession", "Stop", 11usize, |this| ((**(this as *const *const IMFMediaSession_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSession", "Close", 12usize, |this| ((**(this as *const *const IMFMediaSession_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSession", "Shutdown", 13usize, |this| ((**(this as *const *const IMFMediaSession_Vtbl)).Shutdown)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClock(this: *mut core::ffi::c_void, ppclock: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSession", "GetClock", 14usize, |this| ((**(this as *const *const IMFMediaSession_Vtbl)).GetClock)(this, ppclock), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSessionCapabilities(this: *mut core::ffi::c_void, pdwcaps: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSession", "GetSessionCapabilities", 15usize, |this| ((**(this as *const *const IMFMediaSession_Vtbl)).GetSessionCapabilities)(this, pdwcaps), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFullTopology(this: *mut core::ffi::c_void, dwgetfulltopologyflags: u32, topoid: u64, ppfulltopology: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSession", "GetFullTopology", 16usize, |this| ((**(this as *const *const IMFMediaSession_Vtbl)).GetFullTopology)(this, dwgetfulltopologyflags, topoid, ppfulltopology), |result| Some(*result))
+        }
+        Self {
+            base__: <IMFMediaEventGenerator_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetTopology,
+            ClearTopologies,
+            Start,
+            Pause,
+            Stop,
+            Close,
+            Shutdown,
+            GetClock,
+            GetSessionCapabilities,
+            GetFullTopology,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaSession as windows_core::Interface>::IID || <IMFMediaEventGenerator_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaSharingEngine_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDevice(this: *mut core::ffi::c_void, pdevice: *mut DEVICE_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSharingEngine", "GetDevice", 45usize, |this| ((**(this as *const *const IMFMediaSharingEngine_Vtbl)).GetDevice)(this, pdevice), |result| Some(*result))
+        }
+        Self { base__: <IMFMediaEngine_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDevice }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaSharingEngine as windows_core::Interface>::IID || <IMFMediaEngine_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaSharingEngineClassFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, dwflags: u32, pattr: *mut core::ffi::c_void, ppengine: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSharingEngineClassFactory", "CreateInstance", 3usize, |this| ((**(this as *const *const IMFMediaSharingEngineClassFactory_Vtbl)).CreateInstance)(this, dwflags, pattr, ppengine), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaSharingEngineClassFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCharacteristics(this: *mut core::ffi::c_void, pdwcharacteristics: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSink", "GetCharacteristics", 3usize, |this| ((**(this as *const *const IMFMediaSink_Vtbl)).GetCharacteristics)(this, pdwcharacteristics), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddStreamSink(this: *mut core::ffi::c_void, dwstreamsinkidentifier: u32, pmediatype: *mut core::ffi::c_void, ppstreamsink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSink", "AddStreamSink", 4usize, |this| ((**(this as *const *const IMFMediaSink_Vtbl)).AddStreamSink)(this, dwstreamsinkidentifier, pmediatype, ppstreamsink), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStreamSink(this: *mut core::ffi::c_void, dwstreamsinkidentifier: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSink", "RemoveStreamSink", 5usize, |this| ((**(this as *const *const IMFMediaSink_Vtbl)).RemoveStreamSink)(this, dwstreamsinkidentifier), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamSinkCount(this: *mut core::ffi::c_void, pcstreamsinkcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSink", "GetStreamSinkCount", 6usize, |this| ((**(this as *const *const IMFMediaSink_Vtbl)).GetStreamSinkCount)(this, pcstreamsinkcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamSinkByIndex(this: *mut core::ffi::c_void, dwindex: u32, ppstreamsink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSink", "GetStreamSinkByIndex", 7usize, |this| ((**(this as *const *const IMFMediaSink_Vtbl)).GetStreamSinkByIndex)(this, dwindex, ppstreamsink), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamSinkById(this: *mut core::ffi::c_void, dwstreamsinkidentifier: u32, ppstreamsink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSink", "GetStreamSinkById", 8usize, |this| ((**(this as *const *const IMFMediaSink_Vtbl)).GetStreamSinkById)(this, dwstreamsinkidentifier, ppstreamsink), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPresentationClock(this: *mut core::ffi::c_void, ppresentationclock: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSink", "SetPresentationClock", 9usize, |this| ((**(this as *const *const IMFMediaSink_Vtbl)).SetPresentationClock)(this, ppresentationclock), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPresentationClock(this: *mut core::ffi::c_void, pppresentationclock: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSink", "GetPresentationClock", 10usize, |this| ((**(this as *const *const IMFMediaSink_Vtbl)).GetPresentationClock)(this, pppresentationclock), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSink", "Shutdown", 11usize, |this| ((**(this as *const *const IMFMediaSink_Vtbl)).Shutdown)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCharacteristics,
+            AddStreamSink,
+            RemoveStreamSink,
+            GetStreamSinkCount,
+            GetStreamSinkByIndex,
+            GetStreamSinkById,
+            SetPresentationClock,
+            GetPresentationClock,
+            Shutdown,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaSinkPreroll_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NotifyPreroll(this: *mut core::ffi::c_void, hnsupcomingstarttime: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSinkPreroll", "NotifyPreroll", 3usize, |this| ((**(this as *const *const IMFMediaSinkPreroll_Vtbl)).NotifyPreroll)(this, hnsupcomingstarttime), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NotifyPreroll }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaSinkPreroll as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCharacteristics(this: *mut core::ffi::c_void, pdwcharacteristics: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSource", "GetCharacteristics", 7usize, |this| ((**(this as *const *const IMFMediaSource_Vtbl)).GetCharacteristics)(this, pdwcharacteristics), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePresentationDescriptor(this: *mut core::ffi::c_void, pppresentationdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSource", "CreatePresentationDescriptor", 8usize, |this| ((**(this as *const *const IMFMediaSource_Vtbl)).CreatePresentationDescriptor)(this, pppresentationdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void, ppresentationdescriptor: *mut core::ffi::c_void, pguidtimeformat: *const windows_core::GUID, pvarstartposition: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSource", "Start", 9usize, |this| ((**(this as *const *const IMFMediaSource_Vtbl)).Start)(this, ppresentationdescriptor, pguidtimeformat, pvarstartposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSource", "Stop", 10usize, |this| ((**(this as *const *const IMFMediaSource_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSource", "Pause", 11usize, |this| ((**(this as *const *const IMFMediaSource_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSource", "Shutdown", 12usize, |this| ((**(this as *const *const IMFMediaSource_Vtbl)).Shutdown)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IMFMediaEventGenerator_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCharacteristics,
+            CreatePresentationDescriptor,
+            Start,
+            Stop,
+            Pause,
+            Shutdown,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaSource as windows_core::Interface>::IID || <IMFMediaEventGenerator_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaSource2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMediaType(this: *mut core::ffi::c_void, dwstreamid: u32, pmediatype: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSource2", "SetMediaType", 16usize, |this| ((**(this as *const *const IMFMediaSource2_Vtbl)).SetMediaType)(this, dwstreamid, pmediatype), |result| Some(*result))
+        }
+        Self { base__: <IMFMediaSourceEx_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetMediaType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaSource2 as windows_core::Interface>::IID || <IMFMediaSourceEx_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaSourceEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSourceAttributes(this: *mut core::ffi::c_void, ppattributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSourceEx", "GetSourceAttributes", 13usize, |this| ((**(this as *const *const IMFMediaSourceEx_Vtbl)).GetSourceAttributes)(this, ppattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamAttributes(this: *mut core::ffi::c_void, dwstreamidentifier: u32, ppattributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSourceEx", "GetStreamAttributes", 14usize, |this| ((**(this as *const *const IMFMediaSourceEx_Vtbl)).GetStreamAttributes)(this, dwstreamidentifier, ppattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetD3DManager(this: *mut core::ffi::c_void, pmanager: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSourceEx", "SetD3DManager", 15usize, |this| ((**(this as *const *const IMFMediaSourceEx_Vtbl)).SetD3DManager)(this, pmanager), |result| Some(*result))
+        }
+        Self { base__: <IMFMediaSource_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSourceAttributes, GetStreamAttributes, SetD3DManager }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaSourceEx as windows_core::Interface>::IID || <IMFMediaSource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaSourceExtension_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSourceBuffers(this: *mut core::ffi::c_void) -> Option<IMFSourceBufferList> {
+            windows_core::imp::proxy_call(this, "IMFMediaSourceExtension", "GetSourceBuffers", 3usize, |this| ((**(this as *const *const IMFMediaSourceExtension_Vtbl)).GetSourceBuffers)(this), |_| None)
+        }
+        unsafe extern "system" fn GetActiveSourceBuffers(this: *mut core::ffi::c_void) -> Option<IMFSourceBufferList> {
+            windows_core::imp::proxy_call(this, "IMFMediaSourceExtension", "GetActiveSourceBuffers", 4usize, |this| ((**(this as *const *const IMFMediaSourceExtension_Vtbl)).GetActiveSourceBuffers)(this), |_| None)
+        }
+        unsafe extern "system" fn GetReadyState(this: *mut core::ffi::c_void) -> MF_MSE_READY {
+            windows_core::imp::proxy_call(this, "IMFMediaSourceExtension", "GetReadyState", 5usize, |this| ((**(this as *const *const IMFMediaSourceExtension_Vtbl)).GetReadyState)(this), |_| None)
+        }
+        unsafe extern "system" fn GetDuration(this: *mut core::ffi::c_void) -> f64 {
+            windows_core::imp::proxy_call(this, "IMFMediaSourceExtension", "GetDuration", 6usize, |this| ((**(this as *const *const IMFMediaSourceExtension_Vtbl)).GetDuration)(this), |_| None)
+        }
+        unsafe extern "system" fn SetDuration(this: *mut core::ffi::c_void, duration: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSourceExtension", "SetDuration", 7usize, |this| ((**(this as *const *const IMFMediaSourceExtension_Vtbl)).SetDuration)(this, duration), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddSourceBuffer(this: *mut core::ffi::c_void, r#type: core::mem::MaybeUninit<windows_core::BSTR>, pnotify: *mut core::ffi::c_void, ppsourcebuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSourceExtension", "AddSourceBuffer", 8usize, |this| ((**(this as *const *const IMFMediaSourceExtension_Vtbl)).AddSourceBuffer)(this, r#type, pnotify, ppsourcebuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSourceBuffer(this: *mut core::ffi::c_void, psourcebuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSourceExtension", "RemoveSourceBuffer", 9usize, |this| ((**(this as *const *const IMFMediaSourceExtension_Vtbl)).RemoveSourceBuffer)(this, psourcebuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEndOfStream(this: *mut core::ffi::c_void, error: MF_MSE_ERROR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSourceExtension", "SetEndOfStream", 10usize, |this| ((**(this as *const *const IMFMediaSourceExtension_Vtbl)).SetEndOfStream)(this, error), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTypeSupported(this: *mut core::ffi::c_void, r#type: core::mem::MaybeUninit<windows_core::BSTR>) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IMFMediaSourceExtension", "IsTypeSupported", 11usize, |this| ((**(this as *const *const IMFMediaSourceExtension_Vtbl)).IsTypeSupported)(this, r#type), |_| None)
+        }
+        unsafe extern "system" fn GetSourceBuffer(this: *mut core::ffi::c_void, dwstreamindex: u32) -> Option<IMFSourceBuffer> {
+            windows_core::imp::proxy_call(this, "IMFMediaSourceExtension", "GetSourceBuffer", 12usize, |this| ((**(this as *const *const IMFMediaSourceExtension_Vtbl)).GetSourceBuffer)(this, dwstreamindex), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSourceBuffers,
+            GetActiveSourceBuffers,
+            GetReadyState,
+            GetDuration,
+            SetDuration,
+            AddSourceBuffer,
+            RemoveSourceBuffer,
+            SetEndOfStream,
+            IsTypeSupported,
+            GetSourceBuffer,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaSourceExtension as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaSourceExtensionLiveSeekableRange_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetLiveSeekableRange(this: *mut core::ffi::c_void, start: f64, end: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSourceExtensionLiveSeekableRange", "SetLiveSeekableRange", 3usize, |this| ((**(this as *const *const IMFMediaSourceExtensionLiveSeekableRange_Vtbl)).SetLiveSeekableRange)(this, start, end), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearLiveSeekableRange(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSourceExtensionLiveSeekableRange", "ClearLiveSeekableRange", 4usize, |this| ((**(this as *const *const IMFMediaSourceExtensionLiveSeekableRange_Vtbl)).ClearLiveSeekableRange)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetLiveSeekableRange, ClearLiveSeekableRange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaSourceExtensionLiveSeekableRange as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaSourceExtensionNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnSourceOpen(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IMFMediaSourceExtensionNotify", "OnSourceOpen", 3usize, |this| ((**(this as *const *const IMFMediaSourceExtensionNotify_Vtbl)).OnSourceOpen)(this), |_| None)
+        }
+        unsafe extern "system" fn OnSourceEnded(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IMFMediaSourceExtensionNotify", "OnSourceEnded", 4usize, |this| ((**(this as *const *const IMFMediaSourceExtensionNotify_Vtbl)).OnSourceEnded)(this), |_| None)
+        }
+        unsafe extern "system" fn OnSourceClose(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IMFMediaSourceExtensionNotify", "OnSourceClose", 5usize, |this| ((**(this as *const *const IMFMediaSourceExtensionNotify_Vtbl)).OnSourceClose)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnSourceOpen, OnSourceEnded, OnSourceClose }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaSourceExtensionNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaSourcePresentationProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ForceEndOfPresentation(this: *mut core::ffi::c_void, ppresentationdescriptor: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSourcePresentationProvider", "ForceEndOfPresentation", 3usize, |this| ((**(this as *const *const IMFMediaSourcePresentationProvider_Vtbl)).ForceEndOfPresentation)(this, ppresentationdescriptor), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ForceEndOfPresentation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaSourcePresentationProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaSourceTopologyProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMediaSourceTopology(this: *mut core::ffi::c_void, ppresentationdescriptor: *mut core::ffi::c_void, pptopology: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaSourceTopologyProvider", "GetMediaSourceTopology", 3usize, |this| ((**(this as *const *const IMFMediaSourceTopologyProvider_Vtbl)).GetMediaSourceTopology)(this, ppresentationdescriptor, pptopology), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMediaSourceTopology }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaSourceTopologyProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMediaSource(this: *mut core::ffi::c_void, ppmediasource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaStream", "GetMediaSource", 7usize, |this| ((**(this as *const *const IMFMediaStream_Vtbl)).GetMediaSource)(this, ppmediasource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamDescriptor(this: *mut core::ffi::c_void, ppstreamdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaStream", "GetStreamDescriptor", 8usize, |this| ((**(this as *const *const IMFMediaStream_Vtbl)).GetStreamDescriptor)(this, ppstreamdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestSample(this: *mut core::ffi::c_void, ptoken: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaStream", "RequestSample", 9usize, |this| ((**(this as *const *const IMFMediaStream_Vtbl)).RequestSample)(this, ptoken), |result| Some(*result))
+        }
+        Self { base__: <IMFMediaEventGenerator_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMediaSource, GetStreamDescriptor, RequestSample }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaStream as windows_core::Interface>::IID || <IMFMediaEventGenerator_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaStream2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetStreamState(this: *mut core::ffi::c_void, value: MF_STREAM_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaStream2", "SetStreamState", 10usize, |this| ((**(this as *const *const IMFMediaStream2_Vtbl)).SetStreamState)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamState(this: *mut core::ffi::c_void, value: *mut MF_STREAM_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaStream2", "GetStreamState", 11usize, |this| ((**(this as *const *const IMFMediaStream2_Vtbl)).GetStreamState)(this, value), |result| Some(*result))
+        }
+        Self { base__: <IMFMediaStream_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetStreamState, GetStreamState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaStream2 as windows_core::Interface>::IID || <IMFMediaStream_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaStreamSourceSampleRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSample(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaStreamSourceSampleRequest", "SetSample", 3usize, |this| ((**(this as *const *const IMFMediaStreamSourceSampleRequest_Vtbl)).SetSample)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetSample }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaStreamSourceSampleRequest as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaTimeRange_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IMFMediaTimeRange", "GetLength", 3usize, |this| ((**(this as *const *const IMFMediaTimeRange_Vtbl)).GetLength)(this), |_| None)
+        }
+        unsafe extern "system" fn GetStart(this: *mut core::ffi::c_void, index: u32, pstart: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaTimeRange", "GetStart", 4usize, |this| ((**(this as *const *const IMFMediaTimeRange_Vtbl)).GetStart)(this, index, pstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnd(this: *mut core::ffi::c_void, index: u32, pend: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaTimeRange", "GetEnd", 5usize, |this| ((**(this as *const *const IMFMediaTimeRange_Vtbl)).GetEnd)(this, index, pend), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContainsTime(this: *mut core::ffi::c_void, time: f64) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IMFMediaTimeRange", "ContainsTime", 6usize, |this| ((**(this as *const *const IMFMediaTimeRange_Vtbl)).ContainsTime)(this, time), |_| None)
+        }
+        unsafe extern "system" fn AddRange(this: *mut core::ffi::c_void, starttime: f64, endtime: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaTimeRange", "AddRange", 7usize, |this| ((**(this as *const *const IMFMediaTimeRange_Vtbl)).AddRange)(this, starttime, endtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaTimeRange", "Clear", 8usize, |this| ((**(this as *const *const IMFMediaTimeRange_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetLength, GetStart, GetEnd, ContainsTime, AddRange, Clear }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaTimeRange as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaType_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMajorType(this: *mut core::ffi::c_void, pguidmajortype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaType", "GetMajorType", 33usize, |this| ((**(this as *const *const IMFMediaType_Vtbl)).GetMajorType)(this, pguidmajortype), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCompressedFormat(this: *mut core::ffi::c_void, pfcompressed: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaType", "IsCompressedFormat", 34usize, |this| ((**(this as *const *const IMFMediaType_Vtbl)).IsCompressedFormat)(this, pfcompressed), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEqual(this: *mut core::ffi::c_void, pimediatype: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaType", "IsEqual", 35usize, |this| ((**(this as *const *const IMFMediaType_Vtbl)).IsEqual)(this, pimediatype, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRepresentation(this: *mut core::ffi::c_void, guidrepresentation: windows_core::GUID, ppvrepresentation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaType", "GetRepresentation", 36usize, |this| ((**(this as *const *const IMFMediaType_Vtbl)).GetRepresentation)(this, guidrepresentation, ppvrepresentation), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreeRepresentation(this: *mut core::ffi::c_void, guidrepresentation: windows_core::GUID, pvrepresentation: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaType", "FreeRepresentation", 37usize, |this| ((**(this as *const *const IMFMediaType_Vtbl)).FreeRepresentation)(this, guidrepresentation, pvrepresentation), |result| Some(*result))
+        }
+        Self {
+            base__: <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetMajorType,
+            IsCompressedFormat,
+            IsEqual,
+            GetRepresentation,
+            FreeRepresentation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaType as windows_core::Interface>::IID || <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMediaTypeHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsMediaTypeSupported(this: *mut core::ffi::c_void, pmediatype: *mut core::ffi::c_void, ppmediatype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaTypeHandler", "IsMediaTypeSupported", 3usize, |this| ((**(this as *const *const IMFMediaTypeHandler_Vtbl)).IsMediaTypeSupported)(this, pmediatype, ppmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMediaTypeCount(this: *mut core::ffi::c_void, pdwtypecount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaTypeHandler", "GetMediaTypeCount", 4usize, |this| ((**(this as *const *const IMFMediaTypeHandler_Vtbl)).GetMediaTypeCount)(this, pdwtypecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMediaTypeByIndex(this: *mut core::ffi::c_void, dwindex: u32, pptype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaTypeHandler", "GetMediaTypeByIndex", 5usize, |this| ((**(this as *const *const IMFMediaTypeHandler_Vtbl)).GetMediaTypeByIndex)(this, dwindex, pptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentMediaType(this: *mut core::ffi::c_void, pmediatype: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaTypeHandler", "SetCurrentMediaType", 6usize, |this| ((**(this as *const *const IMFMediaTypeHandler_Vtbl)).SetCurrentMediaType)(this, pmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentMediaType(this: *mut core::ffi::c_void, ppmediatype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaTypeHandler", "GetCurrentMediaType", 7usize, |this| ((**(this as *const *const IMFMediaTypeHandler_Vtbl)).GetCurrentMediaType)(this, ppmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMajorType(this: *mut core::ffi::c_void, pguidmajortype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMediaTypeHandler", "GetMajorType", 8usize, |this| ((**(this as *const *const IMFMediaTypeHandler_Vtbl)).GetMajorType)(this, pguidmajortype), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsMediaTypeSupported,
+            GetMediaTypeCount,
+            GetMediaTypeByIndex,
+            SetCurrentMediaType,
+            GetCurrentMediaType,
+            GetMajorType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMediaTypeHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMetadata_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetLanguage(this: *mut core::ffi::c_void, pwszrfc1766: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMetadata", "SetLanguage", 3usize, |this| ((**(this as *const *const IMFMetadata_Vtbl)).SetLanguage)(this, pwszrfc1766), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLanguage(this: *mut core::ffi::c_void, ppwszrfc1766: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMetadata", "GetLanguage", 4usize, |this| ((**(this as *const *const IMFMetadata_Vtbl)).GetLanguage)(this, ppwszrfc1766), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllLanguages(this: *mut core::ffi::c_void, ppvlanguages: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMetadata", "GetAllLanguages", 5usize, |this| ((**(this as *const *const IMFMetadata_Vtbl)).GetAllLanguages)(this, ppvlanguages), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR, ppvvalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMetadata", "SetProperty", 6usize, |this| ((**(this as *const *const IMFMetadata_Vtbl)).SetProperty)(this, pwszname, ppvvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR, ppvvalue: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMetadata", "GetProperty", 7usize, |this| ((**(this as *const *const IMFMetadata_Vtbl)).GetProperty)(this, pwszname, ppvvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteProperty(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMetadata", "DeleteProperty", 8usize, |this| ((**(this as *const *const IMFMetadata_Vtbl)).DeleteProperty)(this, pwszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllPropertyNames(this: *mut core::ffi::c_void, ppvnames: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMetadata", "GetAllPropertyNames", 9usize, |this| ((**(this as *const *const IMFMetadata_Vtbl)).GetAllPropertyNames)(this, ppvnames), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetLanguage,
+            GetLanguage,
+            GetAllLanguages,
+            SetProperty,
+            GetProperty,
+            DeleteProperty,
+            GetAllPropertyNames,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMetadata as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMetadataProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMFMetadata(this: *mut core::ffi::c_void, ppresentationdescriptor: *mut core::ffi::c_void, dwstreamidentifier: u32, dwflags: u32, ppmfmetadata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMetadataProvider", "GetMFMetadata", 3usize, |this| ((**(this as *const *const IMFMetadataProvider_Vtbl)).GetMFMetadata)(this, ppresentationdescriptor, dwstreamidentifier, dwflags, ppmfmetadata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMFMetadata }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMetadataProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMuxStreamAttributesManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStreamCount(this: *mut core::ffi::c_void, pdwmuxstreamcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMuxStreamAttributesManager", "GetStreamCount", 3usize, |this| ((**(this as *const *const IMFMuxStreamAttributesManager_Vtbl)).GetStreamCount)(this, pdwmuxstreamcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributes(this: *mut core::ffi::c_void, dwmuxstreamindex: u32, ppstreamattributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMuxStreamAttributesManager", "GetAttributes", 4usize, |this| ((**(this as *const *const IMFMuxStreamAttributesManager_Vtbl)).GetAttributes)(this, dwmuxstreamindex, ppstreamattributes), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStreamCount, GetAttributes }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMuxStreamAttributesManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMuxStreamMediaTypeManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStreamCount(this: *mut core::ffi::c_void, pdwmuxstreamcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMuxStreamMediaTypeManager", "GetStreamCount", 3usize, |this| ((**(this as *const *const IMFMuxStreamMediaTypeManager_Vtbl)).GetStreamCount)(this, pdwmuxstreamcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMediaType(this: *mut core::ffi::c_void, dwmuxstreamindex: u32, ppmediatype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMuxStreamMediaTypeManager", "GetMediaType", 4usize, |this| ((**(this as *const *const IMFMuxStreamMediaTypeManager_Vtbl)).GetMediaType)(this, dwmuxstreamindex, ppmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamConfigurationCount(this: *mut core::ffi::c_void, pdwcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMuxStreamMediaTypeManager", "GetStreamConfigurationCount", 5usize, |this| ((**(this as *const *const IMFMuxStreamMediaTypeManager_Vtbl)).GetStreamConfigurationCount)(this, pdwcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddStreamConfiguration(this: *mut core::ffi::c_void, ullstreammask: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMuxStreamMediaTypeManager", "AddStreamConfiguration", 6usize, |this| ((**(this as *const *const IMFMuxStreamMediaTypeManager_Vtbl)).AddStreamConfiguration)(this, ullstreammask), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStreamConfiguration(this: *mut core::ffi::c_void, ullstreammask: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMuxStreamMediaTypeManager", "RemoveStreamConfiguration", 7usize, |this| ((**(this as *const *const IMFMuxStreamMediaTypeManager_Vtbl)).RemoveStreamConfiguration)(this, ullstreammask), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamConfiguration(this: *mut core::ffi::c_void, ulindex: u32, pullstreammask: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMuxStreamMediaTypeManager", "GetStreamConfiguration", 8usize, |this| ((**(this as *const *const IMFMuxStreamMediaTypeManager_Vtbl)).GetStreamConfiguration)(this, ulindex, pullstreammask), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetStreamCount,
+            GetMediaType,
+            GetStreamConfigurationCount,
+            AddStreamConfiguration,
+            RemoveStreamConfiguration,
+            GetStreamConfiguration,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMuxStreamMediaTypeManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFMuxStreamSampleManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStreamCount(this: *mut core::ffi::c_void, pdwmuxstreamcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMuxStreamSampleManager", "GetStreamCount", 3usize, |this| ((**(this as *const *const IMFMuxStreamSampleManager_Vtbl)).GetStreamCount)(this, pdwmuxstreamcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSample(this: *mut core::ffi::c_void, dwmuxstreamindex: u32, ppsample: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFMuxStreamSampleManager", "GetSample", 4usize, |this| ((**(this as *const *const IMFMuxStreamSampleManager_Vtbl)).GetSample)(this, dwmuxstreamindex, ppsample), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamConfiguration(this: *mut core::ffi::c_void) -> u64 {
+            windows_core::imp::proxy_call(this, "IMFMuxStreamSampleManager", "GetStreamConfiguration", 5usize, |this| ((**(this as *const *const IMFMuxStreamSampleManager_Vtbl)).GetStreamConfiguration)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStreamCount, GetSample, GetStreamConfiguration }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFMuxStreamSampleManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFNetCredential_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetUser(this: *mut core::ffi::c_void, pbdata: *const u8, cbdata: u32, fdataisencrypted: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFNetCredential", "SetUser", 3usize, |this| ((**(this as *const *const IMFNetCredential_Vtbl)).SetUser)(this, pbdata, cbdata, fdataisencrypted), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPassword(this: *mut core::ffi::c_void, pbdata: *const u8, cbdata: u32, fdataisencrypted: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFNetCredential", "SetPassword", 4usize, |this| ((**(this as *const *const IMFNetCredential_Vtbl)).SetPassword)(this, pbdata, cbdata, fdataisencrypted), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUser(this: *mut core::ffi::c_void, pbdata: *mut u8, pcbdata: *mut u32, fencryptdata: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFNetCredential", "GetUser", 5usize, |this| ((**(this as *const *const IMFNetCredential_Vtbl)).GetUser)(this, pbdata, pcbdata, fencryptdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPassword(this: *mut core::ffi::c_void, pbdata: *mut u8, pcbdata: *mut u32, fencryptdata: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFNetCredential", "GetPassword", 6usize, |this| ((**(this as *const *const IMFNetCredential_Vtbl)).GetPassword)(this, pbdata, pcbdata, fencryptdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoggedOnUser(this: *mut core::ffi::c_void, pfloggedonuser: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFNetCredential", "LoggedOnUser", 7usize, |this| ((**(this as *const *const IMFNetCredential_Vtbl)).LoggedOnUser)(this, pfloggedonuser), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetUser, SetPassword, GetUser, GetPassword, LoggedOnUser }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFNetCredential as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFNetCredentialCache_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCredential(this: *mut core::ffi::c_void, pszurl: windows_core::PCWSTR, pszrealm: windows_core::PCWSTR, dwauthenticationflags: u32, ppcred: *mut *mut core::ffi::c_void, pdwrequirementsflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFNetCredentialCache", "GetCredential", 3usize, |this| ((**(this as *const *const IMFNetCredentialCache_Vtbl)).GetCredential)(this, pszurl, pszrealm, dwauthenticationflags, ppcred, pdwrequirementsflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGood(this: *mut core::ffi::c_void, pcred: *mut core::ffi::c_void, fgood: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFNetCredentialCache", "SetGood", 4usize, |this| ((**(this as *const *const IMFNetCredentialCache_Vtbl)).SetGood)(this, pcred, fgood), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUserOptions(this: *mut core::ffi::c_void, pcred: *mut core::ffi::c_void, dwoptionsflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFNetCredentialCache", "SetUserOptions", 5usize, |this| ((**(this as *const *const IMFNetCredentialCache_Vtbl)).SetUserOptions)(this, pcred, dwoptionsflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCredential, SetGood, SetUserOptions }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFNetCredentialCache as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFNetCredentialManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BeginGetCredentials(this: *mut core::ffi::c_void, pparam: *const MFNetCredentialManagerGetParam, pcallback: *mut core::ffi::c_void, pstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFNetCredentialManager", "BeginGetCredentials", 3usize, |this| ((**(this as *const *const IMFNetCredentialManager_Vtbl)).BeginGetCredentials)(this, pparam, pcallback, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndGetCredentials(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void, ppcred: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFNetCredentialManager", "EndGetCredentials", 4usize, |this| ((**(this as *const *const IMFNetCredentialManager_Vtbl)).EndGetCredentials)(this, presult, ppcred), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGood(this: *mut core::ffi::c_void, pcred: *mut core::ffi::c_void, fgood: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFNetCredentialManager", "SetGood", 5usize, |this| ((**(this as *const *const IMFNetCredentialManager_Vtbl)).SetGood)(this, pcred, fgood), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BeginGetCredentials, EndGetCredentials, SetGood }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFNetCredentialManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFNetCrossOriginSupport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCrossOriginPolicy(this: *mut core::ffi::c_void, ppolicy: *mut MF_CROSS_ORIGIN_POLICY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFNetCrossOriginSupport", "GetCrossOriginPolicy", 3usize, |this| ((**(this as *const *const IMFNetCrossOriginSupport_Vtbl)).GetCrossOriginPolicy)(this, ppolicy), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourceOrigin(this: *mut core::ffi::c_void, wszsourceorigin: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFNetCrossOriginSupport", "GetSourceOrigin", 4usize, |this| ((**(this as *const *const IMFNetCrossOriginSupport_Vtbl)).GetSourceOrigin)(this, wszsourceorigin), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSameOrigin(this: *mut core::ffi::c_void, wszurl: windows_core::PCWSTR, pfissameorigin: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFNetCrossOriginSupport", "IsSameOrigin", 5usize, |this| ((**(this as *const *const IMFNetCrossOriginSupport_Vtbl)).IsSameOrigin)(this, wszurl, pfissameorigin), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCrossOriginPolicy, GetSourceOrigin, IsSameOrigin }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFNetCrossOriginSupport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFNetProxyLocator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FindFirstProxy(this: *mut core::ffi::c_void, pszhost: windows_core::PCWSTR, pszurl: windows_core::PCWSTR, freserved: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFNetProxyLocator", "FindFirstProxy", 3usize, |this| ((**(this as *const *const IMFNetProxyLocator_Vtbl)).FindFirstProxy)(this, pszhost, pszurl, freserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindNextProxy(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFNetProxyLocator", "FindNextProxy", 4usize, |this| ((**(this as *const *const IMFNetProxyLocator_Vtbl)).FindNextProxy)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterProxyResult(this: *mut core::ffi::c_void, hrop: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFNetProxyLocator", "RegisterProxyResult", 5usize, |this| ((**(this as *const *const IMFNetProxyLocator_Vtbl)).RegisterProxyResult)(this, hrop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentProxy(this: *mut core::ffi::c_void, pszstr: windows_core::PWSTR, pcchstr: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFNetProxyLocator", "GetCurrentProxy", 6usize, |this| ((**(this as *const *const IMFNetProxyLocator_Vtbl)).GetCurrentProxy)(this, pszstr, pcchstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppproxylocator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFNetProxyLocator", "Clone", 7usize, |this| ((**(this as *const *const IMFNetProxyLocator_Vtbl)).Clone)(this, ppproxylocator), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FindFirstProxy,
+            FindNextProxy,
+            RegisterProxyResult,
+            GetCurrentProxy,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFNetProxyLocator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFNetProxyLocatorFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateProxyLocator(this: *mut core::ffi::c_void, pszprotocol: windows_core::PCWSTR, ppproxylocator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFNetProxyLocatorFactory", "CreateProxyLocator", 3usize, |this| ((**(this as *const *const IMFNetProxyLocatorFactory_Vtbl)).CreateProxyLocator)(this, pszprotocol, ppproxylocator), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateProxyLocator }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFNetProxyLocatorFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFNetResourceFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnRedirect(this: *mut core::ffi::c_void, pszurl: windows_core::PCWSTR, pvbcancel: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFNetResourceFilter", "OnRedirect", 3usize, |this| ((**(this as *const *const IMFNetResourceFilter_Vtbl)).OnRedirect)(this, pszurl, pvbcancel), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnSendingRequest(this: *mut core::ffi::c_void, pszurl: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFNetResourceFilter", "OnSendingRequest", 4usize, |this| ((**(this as *const *const IMFNetResourceFilter_Vtbl)).OnSendingRequest)(this, pszurl), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnRedirect, OnSendingRequest }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFNetResourceFilter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFNetSchemeHandlerConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNumberOfSupportedProtocols(this: *mut core::ffi::c_void, pcprotocols: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFNetSchemeHandlerConfig", "GetNumberOfSupportedProtocols", 3usize, |this| ((**(this as *const *const IMFNetSchemeHandlerConfig_Vtbl)).GetNumberOfSupportedProtocols)(this, pcprotocols), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSupportedProtocolType(this: *mut core::ffi::c_void, nprotocolindex: u32, pnprotocoltype: *mut MFNETSOURCE_PROTOCOL_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFNetSchemeHandlerConfig", "GetSupportedProtocolType", 4usize, |this| ((**(this as *const *const IMFNetSchemeHandlerConfig_Vtbl)).GetSupportedProtocolType)(this, nprotocolindex, pnprotocoltype), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetProtocolRolloverSettings(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFNetSchemeHandlerConfig", "ResetProtocolRolloverSettings", 5usize, |this| ((**(this as *const *const IMFNetSchemeHandlerConfig_Vtbl)).ResetProtocolRolloverSettings)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetNumberOfSupportedProtocols,
+            GetSupportedProtocolType,
+            ResetProtocolRolloverSettings,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFNetSchemeHandlerConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFObjectReferenceStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SaveReference(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFObjectReferenceStream", "SaveReference", 3usize, |this| ((**(this as *const *const IMFObjectReferenceStream_Vtbl)).SaveReference)(this, riid, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadReference(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFObjectReferenceStream", "LoadReference", 4usize, |this| ((**(this as *const *const IMFObjectReferenceStream_Vtbl)).LoadReference)(this, riid, ppv), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SaveReference, LoadReference }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFObjectReferenceStream as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFOutputPolicy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GenerateRequiredSchemas(this: *mut core::ffi::c_void, dwattributes: u32, guidoutputsubtype: windows_core::GUID, rgguidprotectionschemassupported: *const windows_core::GUID, cprotectionschemassupported: u32, pprequiredprotectionschemas: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFOutputPolicy", "GenerateRequiredSchemas", 33usize, |this| ((**(this as *const *const IMFOutputPolicy_Vtbl)).GenerateRequiredSchemas)(this, dwattributes, guidoutputsubtype, rgguidprotectionschemassupported, cprotectionschemassupported, pprequiredprotectionschemas), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOriginatorID(this: *mut core::ffi::c_void, pguidoriginatorid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFOutputPolicy", "GetOriginatorID", 34usize, |this| ((**(this as *const *const IMFOutputPolicy_Vtbl)).GetOriginatorID)(this, pguidoriginatorid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMinimumGRLVersion(this: *mut core::ffi::c_void, pdwminimumgrlversion: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFOutputPolicy", "GetMinimumGRLVersion", 35usize, |this| ((**(this as *const *const IMFOutputPolicy_Vtbl)).GetMinimumGRLVersion)(this, pdwminimumgrlversion), |result| Some(*result))
+        }
+        Self { base__: <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GenerateRequiredSchemas, GetOriginatorID, GetMinimumGRLVersion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFOutputPolicy as windows_core::Interface>::IID || <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFOutputSchema_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSchemaType(this: *mut core::ffi::c_void, pguidschematype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFOutputSchema", "GetSchemaType", 33usize, |this| ((**(this as *const *const IMFOutputSchema_Vtbl)).GetSchemaType)(this, pguidschematype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConfigurationData(this: *mut core::ffi::c_void, pdwval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFOutputSchema", "GetConfigurationData", 34usize, |this| ((**(this as *const *const IMFOutputSchema_Vtbl)).GetConfigurationData)(this, pdwval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOriginatorID(this: *mut core::ffi::c_void, pguidoriginatorid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFOutputSchema", "GetOriginatorID", 35usize, |this| ((**(this as *const *const IMFOutputSchema_Vtbl)).GetOriginatorID)(this, pguidoriginatorid), |result| Some(*result))
+        }
+        Self { base__: <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSchemaType, GetConfigurationData, GetOriginatorID }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFOutputSchema as windows_core::Interface>::IID || <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFOutputTrustAuthority_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAction(this: *mut core::ffi::c_void, paction: *mut MFPOLICYMANAGER_ACTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFOutputTrustAuthority", "GetAction", 3usize, |this| ((**(this as *const *const IMFOutputTrustAuthority_Vtbl)).GetAction)(this, paction), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPolicy(this: *mut core::ffi::c_void, pppolicy: *const *mut core::ffi::c_void, npolicy: u32, ppbticket: *mut *mut u8, pcbticket: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFOutputTrustAuthority", "SetPolicy", 4usize, |this| ((**(this as *const *const IMFOutputTrustAuthority_Vtbl)).SetPolicy)(this, pppolicy, npolicy, ppbticket, pcbticket), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAction, SetPolicy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFOutputTrustAuthority as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFPMPClient_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPMPHost(this: *mut core::ffi::c_void, ppmphost: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMPClient", "SetPMPHost", 3usize, |this| ((**(this as *const *const IMFPMPClient_Vtbl)).SetPMPHost)(this, ppmphost), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetPMPHost }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFPMPClient as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFPMPClientApp_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPMPHost(this: *mut core::ffi::c_void, ppmphost: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMPClientApp", "SetPMPHost", 3usize, |this| ((**(this as *const *const IMFPMPClientApp_Vtbl)).SetPMPHost)(this, ppmphost), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetPMPHost }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFPMPClientApp as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMFPMPHost_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LockProcess(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMPHost", "LockProcess", 3usize, |this| ((**(this as *const *const IMFPMPHost_Vtbl)).LockProcess)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockProcess(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMPHost", "UnlockProcess", 4usize, |this| ((**(this as *const *const IMFPMPHost_Vtbl)).UnlockProcess)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateObjectByCLSID(this: *mut core::ffi::c_void, clsid: *const windows_core::GUID, pstream: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMPHost", "CreateObjectByCLSID", 5usize, |this| ((**(this as *const *const IMFPMPHost_Vtbl)).CreateObjectByCLSID)(this, clsid, pstream, riid, ppv), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LockProcess,
+            UnlockProcess,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateObjectByCLSID,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateObjectByCLSID: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFPMPHost as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMFPMPHostApp_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LockProcess(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMPHostApp", "LockProcess", 3usize, |this| ((**(this as *const *const IMFPMPHostApp_Vtbl)).LockProcess)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockProcess(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMPHostApp", "UnlockProcess", 4usize, |this| ((**(this as *const *const IMFPMPHostApp_Vtbl)).UnlockProcess)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ActivateClassById(this: *mut core::ffi::c_void, id: windows_core::PCWSTR, pstream: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMPHostApp", "ActivateClassById", 5usize, |this| ((**(this as *const *const IMFPMPHostApp_Vtbl)).ActivateClassById)(this, id, pstream, riid, ppv), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LockProcess,
+            UnlockProcess,
+            #[cfg(feature = "Win32_System_Com")]
+            ActivateClassById,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ActivateClassById: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFPMPHostApp as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFPMPServer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LockProcess(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMPServer", "LockProcess", 3usize, |this| ((**(this as *const *const IMFPMPServer_Vtbl)).LockProcess)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockProcess(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMPServer", "UnlockProcess", 4usize, |this| ((**(this as *const *const IMFPMPServer_Vtbl)).UnlockProcess)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateObjectByCLSID(this: *mut core::ffi::c_void, clsid: *const windows_core::GUID, riid: *const windows_core::GUID, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMPServer", "CreateObjectByCLSID", 5usize, |this| ((**(this as *const *const IMFPMPServer_Vtbl)).CreateObjectByCLSID)(this, clsid, riid, ppobject), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LockProcess, UnlockProcess, CreateObjectByCLSID }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFPMPServer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IMFPMediaItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMediaPlayer(this: *mut core::ffi::c_void, ppmediaplayer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaItem", "GetMediaPlayer", 3usize, |this| ((**(this as *const *const IMFPMediaItem_Vtbl)).GetMediaPlayer)(this, ppmediaplayer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetURL(this: *mut core::ffi::c_void, ppwszurl: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaItem", "GetURL", 4usize, |this| ((**(this as *const *const IMFPMediaItem_Vtbl)).GetURL)(this, ppwszurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObject(this: *mut core::ffi::c_void, ppiunknown: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaItem", "GetObject", 5usize, |this| ((**(this as *const *const IMFPMediaItem_Vtbl)).GetObject)(this, ppiunknown), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUserData(this: *mut core::ffi::c_void, pdwuserdata: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaItem", "GetUserData", 6usize, |this| ((**(this as *const *const IMFPMediaItem_Vtbl)).GetUserData)(this, pdwuserdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUserData(this: *mut core::ffi::c_void, dwuserdata: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaItem", "SetUserData", 7usize, |this| ((**(this as *const *const IMFPMediaItem_Vtbl)).SetUserData)(this, dwuserdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStartStopPosition(this: *mut core::ffi::c_void, pguidstartpositiontype: *mut windows_core::GUID, pvstartvalue: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>, pguidstoppositiontype: *mut windows_core::GUID, pvstopvalue: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaItem", "GetStartStopPosition", 8usize, |this| ((**(this as *const *const IMFPMediaItem_Vtbl)).GetStartStopPosition)(this, pguidstartpositiontype, pvstartvalue, pguidstoppositiontype, pvstopvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartStopPosition(this: *mut core::ffi::c_void, pguidstartpositiontype: *const windows_core::GUID, pvstartvalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, pguidstoppositiontype: *const windows_core::GUID, pvstopvalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaItem", "SetStartStopPosition", 9usize, |this| ((**(this as *const *const IMFPMediaItem_Vtbl)).SetStartStopPosition)(this, pguidstartpositiontype, pvstartvalue, pguidstoppositiontype, pvstopvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasVideo(this: *mut core::ffi::c_void, pfhasvideo: *mut super::super::Foundation::BOOL, pfselected: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaItem", "HasVideo", 10usize, |this| ((**(this as *const *const IMFPMediaItem_Vtbl)).HasVideo)(this, pfhasvideo, pfselected), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasAudio(this: *mut core::ffi::c_void, pfhasaudio: *mut super::super::Foundation::BOOL, pfselected: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaItem", "HasAudio", 11usize, |this| ((**(this as *const *const IMFPMediaItem_Vtbl)).HasAudio)(this, pfhasaudio, pfselected), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsProtected(this: *mut core::ffi::c_void, pfprotected: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaItem", "IsProtected", 12usize, |this| ((**(this as *const *const IMFPMediaItem_Vtbl)).IsProtected)(this, pfprotected), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDuration(this: *mut core::ffi::c_void, guidpositiontype: *const windows_core::GUID, pvdurationvalue: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaItem", "GetDuration", 13usize, |this| ((**(this as *const *const IMFPMediaItem_Vtbl)).GetDuration)(this, guidpositiontype, pvdurationvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumberOfStreams(this: *mut core::ffi::c_void, pdwstreamcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaItem", "GetNumberOfStreams", 14usize, |this| ((**(this as *const *const IMFPMediaItem_Vtbl)).GetNumberOfStreams)(this, pdwstreamcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamSelection(this: *mut core::ffi::c_void, dwstreamindex: u32, pfenabled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaItem", "GetStreamSelection", 15usize, |this| ((**(this as *const *const IMFPMediaItem_Vtbl)).GetStreamSelection)(this, dwstreamindex, pfenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStreamSelection(this: *mut core::ffi::c_void, dwstreamindex: u32, fenabled: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaItem", "SetStreamSelection", 16usize, |this| ((**(this as *const *const IMFPMediaItem_Vtbl)).SetStreamSelection)(this, dwstreamindex, fenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamAttribute(this: *mut core::ffi::c_void, dwstreamindex: u32, guidmfattribute: *const windows_core::GUID, pvvalue: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaItem", "GetStreamAttribute", 17usize, |this| ((**(this as *const *const IMFPMediaItem_Vtbl)).GetStreamAttribute)(this, dwstreamindex, guidmfattribute, pvvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPresentationAttribute(this: *mut core::ffi::c_void, guidmfattribute: *const windows_core::GUID, pvvalue: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaItem", "GetPresentationAttribute", 18usize, |this| ((**(this as *const *const IMFPMediaItem_Vtbl)).GetPresentationAttribute)(this, guidmfattribute, pvvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCharacteristics(this: *mut core::ffi::c_void, pcharacteristics: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaItem", "GetCharacteristics", 19usize, |this| ((**(this as *const *const IMFPMediaItem_Vtbl)).GetCharacteristics)(this, pcharacteristics), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStreamSink(this: *mut core::ffi::c_void, dwstreamindex: u32, pmediasink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaItem", "SetStreamSink", 20usize, |this| ((**(this as *const *const IMFPMediaItem_Vtbl)).SetStreamSink)(this, dwstreamindex, pmediasink), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetMetadata(this: *mut core::ffi::c_void, ppmetadatastore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaItem", "GetMetadata", 21usize, |this| ((**(this as *const *const IMFPMediaItem_Vtbl)).GetMetadata)(this, ppmetadatastore), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetMediaPlayer,
+            GetURL,
+            GetObject,
+            GetUserData,
+            SetUserData,
+            GetStartStopPosition,
+            SetStartStopPosition,
+            HasVideo,
+            HasAudio,
+            IsProtected,
+            GetDuration,
+            GetNumberOfStreams,
+            GetStreamSelection,
+            SetStreamSelection,
+            GetStreamAttribute,
+            GetPresentationAttribute,
+            GetCharacteristics,
+            SetStreamSink,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetMetadata,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetMetadata: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFPMediaItem as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFPMediaPlayer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Play(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "Play", 3usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).Play)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "Pause", 4usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "Stop", 5usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn FrameStep(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "FrameStep", 6usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).FrameStep)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPosition(this: *mut core::ffi::c_void, guidpositiontype: *const windows_core::GUID, pvpositionvalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "SetPosition", 7usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).SetPosition)(this, guidpositiontype, pvpositionvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPosition(this: *mut core::ffi::c_void, guidpositiontype: *const windows_core::GUID, pvpositionvalue: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "GetPosition", 8usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).GetPosition)(this, guidpositiontype, pvpositionvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDuration(this: *mut core::ffi::c_void, guidpositiontype: *const windows_core::GUID, pvdurationvalue: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "GetDuration", 9usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).GetDuration)(this, guidpositiontype, pvdurationvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRate(this: *mut core::ffi::c_void, flrate: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "SetRate", 10usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).SetRate)(this, flrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRate(this: *mut core::ffi::c_void, pflrate: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "GetRate", 11usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).GetRate)(this, pflrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSupportedRates(this: *mut core::ffi::c_void, fforwarddirection: super::super::Foundation::BOOL, pflslowestrate: *mut f32, pflfastestrate: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "GetSupportedRates", 12usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).GetSupportedRates)(this, fforwarddirection, pflslowestrate, pflfastestrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetState(this: *mut core::ffi::c_void, pestate: *mut MFP_MEDIAPLAYER_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "GetState", 13usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).GetState)(this, pestate), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateMediaItemFromURL(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, fsync: super::super::Foundation::BOOL, dwuserdata: usize, ppmediaitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "CreateMediaItemFromURL", 14usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).CreateMediaItemFromURL)(this, pwszurl, fsync, dwuserdata, ppmediaitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateMediaItemFromObject(this: *mut core::ffi::c_void, piunknownobj: *mut core::ffi::c_void, fsync: super::super::Foundation::BOOL, dwuserdata: usize, ppmediaitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "CreateMediaItemFromObject", 15usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).CreateMediaItemFromObject)(this, piunknownobj, fsync, dwuserdata, ppmediaitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMediaItem(this: *mut core::ffi::c_void, pimfpmediaitem: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "SetMediaItem", 16usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).SetMediaItem)(this, pimfpmediaitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearMediaItem(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "ClearMediaItem", 17usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).ClearMediaItem)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMediaItem(this: *mut core::ffi::c_void, ppimfpmediaitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "GetMediaItem", 18usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).GetMediaItem)(this, ppimfpmediaitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVolume(this: *mut core::ffi::c_void, pflvolume: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "GetVolume", 19usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).GetVolume)(this, pflvolume), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVolume(this: *mut core::ffi::c_void, flvolume: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "SetVolume", 20usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).SetVolume)(this, flvolume), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBalance(this: *mut core::ffi::c_void, pflbalance: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "GetBalance", 21usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).GetBalance)(this, pflbalance), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBalance(this: *mut core::ffi::c_void, flbalance: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "SetBalance", 22usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).SetBalance)(this, flbalance), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMute(this: *mut core::ffi::c_void, pfmute: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "GetMute", 23usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).GetMute)(this, pfmute), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMute(this: *mut core::ffi::c_void, fmute: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "SetMute", 24usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).SetMute)(this, fmute), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNativeVideoSize(this: *mut core::ffi::c_void, pszvideo: *mut super::super::Foundation::SIZE, pszarvideo: *mut super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "GetNativeVideoSize", 25usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).GetNativeVideoSize)(this, pszvideo, pszarvideo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIdealVideoSize(this: *mut core::ffi::c_void, pszmin: *mut super::super::Foundation::SIZE, pszmax: *mut super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "GetIdealVideoSize", 26usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).GetIdealVideoSize)(this, pszmin, pszmax), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVideoSourceRect(this: *mut core::ffi::c_void, pnrcsource: *const MFVideoNormalizedRect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "SetVideoSourceRect", 27usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).SetVideoSourceRect)(this, pnrcsource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoSourceRect(this: *mut core::ffi::c_void, pnrcsource: *mut MFVideoNormalizedRect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "GetVideoSourceRect", 28usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).GetVideoSourceRect)(this, pnrcsource), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAspectRatioMode(this: *mut core::ffi::c_void, dwaspectratiomode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "SetAspectRatioMode", 29usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).SetAspectRatioMode)(this, dwaspectratiomode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAspectRatioMode(this: *mut core::ffi::c_void, pdwaspectratiomode: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "GetAspectRatioMode", 30usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).GetAspectRatioMode)(this, pdwaspectratiomode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoWindow(this: *mut core::ffi::c_void, phwndvideo: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "GetVideoWindow", 31usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).GetVideoWindow)(this, phwndvideo), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateVideo(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "UpdateVideo", 32usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).UpdateVideo)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBorderColor(this: *mut core::ffi::c_void, clr: super::super::Foundation::COLORREF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "SetBorderColor", 33usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).SetBorderColor)(this, clr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBorderColor(this: *mut core::ffi::c_void, pclr: *mut super::super::Foundation::COLORREF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "GetBorderColor", 34usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).GetBorderColor)(this, pclr), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertEffect(this: *mut core::ffi::c_void, peffect: *mut core::ffi::c_void, foptional: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "InsertEffect", 35usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).InsertEffect)(this, peffect, foptional), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEffect(this: *mut core::ffi::c_void, peffect: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "RemoveEffect", 36usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).RemoveEffect)(this, peffect), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAllEffects(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "RemoveAllEffects", 37usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).RemoveAllEffects)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayer", "Shutdown", 38usize, |this| ((**(this as *const *const IMFPMediaPlayer_Vtbl)).Shutdown)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Play,
+            Pause,
+            Stop,
+            FrameStep,
+            SetPosition,
+            GetPosition,
+            GetDuration,
+            SetRate,
+            GetRate,
+            GetSupportedRates,
+            GetState,
+            CreateMediaItemFromURL,
+            CreateMediaItemFromObject,
+            SetMediaItem,
+            ClearMediaItem,
+            GetMediaItem,
+            GetVolume,
+            SetVolume,
+            GetBalance,
+            SetBalance,
+            GetMute,
+            SetMute,
+            GetNativeVideoSize,
+            GetIdealVideoSize,
+            SetVideoSourceRect,
+            GetVideoSourceRect,
+            SetAspectRatioMode,
+            GetAspectRatioMode,
+            GetVideoWindow,
+            UpdateVideo,
+            SetBorderColor,
+            GetBorderColor,
+            InsertEffect,
+            RemoveEffect,
+            RemoveAllEffects,
+            Shutdown,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFPMediaPlayer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IMFPMediaPlayerCallback_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn OnMediaPlayerEvent(this: *mut core::ffi::c_void, peventheader: *const MFP_EVENT_HEADER) {
+            windows_core::imp::proxy_call(this, "IMFPMediaPlayerCallback", "OnMediaPlayerEvent", 3usize, |this| ((**(this as *const *const IMFPMediaPlayerCallback_Vtbl)).OnMediaPlayerEvent)(this, peventheader), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            OnMediaPlayerEvent,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            OnMediaPlayerEvent: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFPMediaPlayerCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFPluginControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPreferredClsid(this: *mut core::ffi::c_void, plugintype: u32, selector: windows_core::PCWSTR, clsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPluginControl", "GetPreferredClsid", 3usize, |this| ((**(this as *const *const IMFPluginControl_Vtbl)).GetPreferredClsid)(this, plugintype, selector, clsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreferredClsidByIndex(this: *mut core::ffi::c_void, plugintype: u32, index: u32, selector: *mut windows_core::PWSTR, clsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPluginControl", "GetPreferredClsidByIndex", 4usize, |this| ((**(this as *const *const IMFPluginControl_Vtbl)).GetPreferredClsidByIndex)(this, plugintype, index, selector, clsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreferredClsid(this: *mut core::ffi::c_void, plugintype: u32, selector: windows_core::PCWSTR, clsid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPluginControl", "SetPreferredClsid", 5usize, |this| ((**(this as *const *const IMFPluginControl_Vtbl)).SetPreferredClsid)(this, plugintype, selector, clsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDisabled(this: *mut core::ffi::c_void, plugintype: u32, clsid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPluginControl", "IsDisabled", 6usize, |this| ((**(this as *const *const IMFPluginControl_Vtbl)).IsDisabled)(this, plugintype, clsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisabledByIndex(this: *mut core::ffi::c_void, plugintype: u32, index: u32, clsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPluginControl", "GetDisabledByIndex", 7usize, |this| ((**(this as *const *const IMFPluginControl_Vtbl)).GetDisabledByIndex)(this, plugintype, index, clsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisabled(this: *mut core::ffi::c_void, plugintype: u32, clsid: *const windows_core::GUID, disabled: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPluginControl", "SetDisabled", 8usize, |this| ((**(this as *const *const IMFPluginControl_Vtbl)).SetDisabled)(this, plugintype, clsid, disabled), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPreferredClsid,
+            GetPreferredClsidByIndex,
+            SetPreferredClsid,
+            IsDisabled,
+            GetDisabledByIndex,
+            SetDisabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFPluginControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFPluginControl2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPolicy(this: *mut core::ffi::c_void, policy: MF_PLUGIN_CONTROL_POLICY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPluginControl2", "SetPolicy", 9usize, |this| ((**(this as *const *const IMFPluginControl2_Vtbl)).SetPolicy)(this, policy), |result| Some(*result))
+        }
+        Self { base__: <IMFPluginControl_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetPolicy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFPluginControl2 as windows_core::Interface>::IID || <IMFPluginControl_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFPresentationClock_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetTimeSource(this: *mut core::ffi::c_void, ptimesource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPresentationClock", "SetTimeSource", 8usize, |this| ((**(this as *const *const IMFPresentationClock_Vtbl)).SetTimeSource)(this, ptimesource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTimeSource(this: *mut core::ffi::c_void, pptimesource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPresentationClock", "GetTimeSource", 9usize, |this| ((**(this as *const *const IMFPresentationClock_Vtbl)).GetTimeSource)(this, pptimesource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTime(this: *mut core::ffi::c_void, phnsclocktime: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPresentationClock", "GetTime", 10usize, |this| ((**(this as *const *const IMFPresentationClock_Vtbl)).GetTime)(this, phnsclocktime), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddClockStateSink(this: *mut core::ffi::c_void, pstatesink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPresentationClock", "AddClockStateSink", 11usize, |this| ((**(this as *const *const IMFPresentationClock_Vtbl)).AddClockStateSink)(this, pstatesink), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveClockStateSink(this: *mut core::ffi::c_void, pstatesink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPresentationClock", "RemoveClockStateSink", 12usize, |this| ((**(this as *const *const IMFPresentationClock_Vtbl)).RemoveClockStateSink)(this, pstatesink), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void, llclockstartoffset: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPresentationClock", "Start", 13usize, |this| ((**(this as *const *const IMFPresentationClock_Vtbl)).Start)(this, llclockstartoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPresentationClock", "Stop", 14usize, |this| ((**(this as *const *const IMFPresentationClock_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPresentationClock", "Pause", 15usize, |this| ((**(this as *const *const IMFPresentationClock_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IMFClock_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetTimeSource,
+            GetTimeSource,
+            GetTime,
+            AddClockStateSink,
+            RemoveClockStateSink,
+            Start,
+            Stop,
+            Pause,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFPresentationClock as windows_core::Interface>::IID || <IMFClock_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFPresentationDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStreamDescriptorCount(this: *mut core::ffi::c_void, pdwdescriptorcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPresentationDescriptor", "GetStreamDescriptorCount", 33usize, |this| ((**(this as *const *const IMFPresentationDescriptor_Vtbl)).GetStreamDescriptorCount)(this, pdwdescriptorcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamDescriptorByIndex(this: *mut core::ffi::c_void, dwindex: u32, pfselected: *mut super::super::Foundation::BOOL, ppdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPresentationDescriptor", "GetStreamDescriptorByIndex", 34usize, |this| ((**(this as *const *const IMFPresentationDescriptor_Vtbl)).GetStreamDescriptorByIndex)(this, dwindex, pfselected, ppdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectStream(this: *mut core::ffi::c_void, dwdescriptorindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPresentationDescriptor", "SelectStream", 35usize, |this| ((**(this as *const *const IMFPresentationDescriptor_Vtbl)).SelectStream)(this, dwdescriptorindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeselectStream(this: *mut core::ffi::c_void, dwdescriptorindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPresentationDescriptor", "DeselectStream", 36usize, |this| ((**(this as *const *const IMFPresentationDescriptor_Vtbl)).DeselectStream)(this, dwdescriptorindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, pppresentationdescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPresentationDescriptor", "Clone", 37usize, |this| ((**(this as *const *const IMFPresentationDescriptor_Vtbl)).Clone)(this, pppresentationdescriptor), |result| Some(*result))
+        }
+        Self {
+            base__: <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetStreamDescriptorCount,
+            GetStreamDescriptorByIndex,
+            SelectStream,
+            DeselectStream,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFPresentationDescriptor as windows_core::Interface>::IID || <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFPresentationTimeSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetUnderlyingClock(this: *mut core::ffi::c_void, ppclock: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFPresentationTimeSource", "GetUnderlyingClock", 8usize, |this| ((**(this as *const *const IMFPresentationTimeSource_Vtbl)).GetUnderlyingClock)(this, ppclock), |result| Some(*result))
+        }
+        Self { base__: <IMFClock_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetUnderlyingClock }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFPresentationTimeSource as windows_core::Interface>::IID || <IMFClock_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFProtectedEnvironmentAccess_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Call(this: *mut core::ffi::c_void, inputlength: u32, input: *const u8, outputlength: u32, output: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFProtectedEnvironmentAccess", "Call", 3usize, |this| ((**(this as *const *const IMFProtectedEnvironmentAccess_Vtbl)).Call)(this, inputlength, input, outputlength, output), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadGRL(this: *mut core::ffi::c_void, outputlength: *mut u32, output: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFProtectedEnvironmentAccess", "ReadGRL", 4usize, |this| ((**(this as *const *const IMFProtectedEnvironmentAccess_Vtbl)).ReadGRL)(this, outputlength, output), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Call, ReadGRL }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFProtectedEnvironmentAccess as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFQualityAdvise_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDropMode(this: *mut core::ffi::c_void, edropmode: MF_QUALITY_DROP_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFQualityAdvise", "SetDropMode", 3usize, |this| ((**(this as *const *const IMFQualityAdvise_Vtbl)).SetDropMode)(this, edropmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQualityLevel(this: *mut core::ffi::c_void, equalitylevel: MF_QUALITY_LEVEL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFQualityAdvise", "SetQualityLevel", 4usize, |this| ((**(this as *const *const IMFQualityAdvise_Vtbl)).SetQualityLevel)(this, equalitylevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDropMode(this: *mut core::ffi::c_void, pedropmode: *mut MF_QUALITY_DROP_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFQualityAdvise", "GetDropMode", 5usize, |this| ((**(this as *const *const IMFQualityAdvise_Vtbl)).GetDropMode)(this, pedropmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetQualityLevel(this: *mut core::ffi::c_void, pequalitylevel: *mut MF_QUALITY_LEVEL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFQualityAdvise", "GetQualityLevel", 6usize, |this| ((**(this as *const *const IMFQualityAdvise_Vtbl)).GetQualityLevel)(this, pequalitylevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn DropTime(this: *mut core::ffi::c_void, hnsamounttodrop: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFQualityAdvise", "DropTime", 7usize, |this| ((**(this as *const *const IMFQualityAdvise_Vtbl)).DropTime)(this, hnsamounttodrop), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetDropMode,
+            SetQualityLevel,
+            GetDropMode,
+            GetQualityLevel,
+            DropTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFQualityAdvise as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFQualityAdvise2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NotifyQualityEvent(this: *mut core::ffi::c_void, pevent: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFQualityAdvise2", "NotifyQualityEvent", 8usize, |this| ((**(this as *const *const IMFQualityAdvise2_Vtbl)).NotifyQualityEvent)(this, pevent, pdwflags), |result| Some(*result))
+        }
+        Self { base__: <IMFQualityAdvise_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NotifyQualityEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFQualityAdvise2 as windows_core::Interface>::IID || <IMFQualityAdvise_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFQualityAdviseLimits_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMaximumDropMode(this: *mut core::ffi::c_void, pedropmode: *mut MF_QUALITY_DROP_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFQualityAdviseLimits", "GetMaximumDropMode", 3usize, |this| ((**(this as *const *const IMFQualityAdviseLimits_Vtbl)).GetMaximumDropMode)(this, pedropmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMinimumQualityLevel(this: *mut core::ffi::c_void, pequalitylevel: *mut MF_QUALITY_LEVEL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFQualityAdviseLimits", "GetMinimumQualityLevel", 4usize, |this| ((**(this as *const *const IMFQualityAdviseLimits_Vtbl)).GetMinimumQualityLevel)(this, pequalitylevel), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMaximumDropMode, GetMinimumQualityLevel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFQualityAdviseLimits as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFQualityManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NotifyTopology(this: *mut core::ffi::c_void, ptopology: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFQualityManager", "NotifyTopology", 3usize, |this| ((**(this as *const *const IMFQualityManager_Vtbl)).NotifyTopology)(this, ptopology), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyPresentationClock(this: *mut core::ffi::c_void, pclock: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFQualityManager", "NotifyPresentationClock", 4usize, |this| ((**(this as *const *const IMFQualityManager_Vtbl)).NotifyPresentationClock)(this, pclock), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyProcessInput(this: *mut core::ffi::c_void, pnode: *mut core::ffi::c_void, linputindex: i32, psample: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFQualityManager", "NotifyProcessInput", 5usize, |this| ((**(this as *const *const IMFQualityManager_Vtbl)).NotifyProcessInput)(this, pnode, linputindex, psample), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyProcessOutput(this: *mut core::ffi::c_void, pnode: *mut core::ffi::c_void, loutputindex: i32, psample: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFQualityManager", "NotifyProcessOutput", 6usize, |this| ((**(this as *const *const IMFQualityManager_Vtbl)).NotifyProcessOutput)(this, pnode, loutputindex, psample), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyQualityEvent(this: *mut core::ffi::c_void, pobject: *mut core::ffi::c_void, pevent: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFQualityManager", "NotifyQualityEvent", 7usize, |this| ((**(this as *const *const IMFQualityManager_Vtbl)).NotifyQualityEvent)(this, pobject, pevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFQualityManager", "Shutdown", 8usize, |this| ((**(this as *const *const IMFQualityManager_Vtbl)).Shutdown)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            NotifyTopology,
+            NotifyPresentationClock,
+            NotifyProcessInput,
+            NotifyProcessOutput,
+            NotifyQualityEvent,
+            Shutdown,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFQualityManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFRateControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetRate(this: *mut core::ffi::c_void, fthin: super::super::Foundation::BOOL, flrate: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFRateControl", "SetRate", 3usize, |this| ((**(this as *const *const IMFRateControl_Vtbl)).SetRate)(this, fthin, flrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRate(this: *mut core::ffi::c_void, pfthin: *mut super::super::Foundation::BOOL, pflrate: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFRateControl", "GetRate", 4usize, |this| ((**(this as *const *const IMFRateControl_Vtbl)).GetRate)(this, pfthin, pflrate), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetRate, GetRate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFRateControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFRateSupport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSlowestRate(this: *mut core::ffi::c_void, edirection: MFRATE_DIRECTION, fthin: super::super::Foundation::BOOL, pflrate: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFRateSupport", "GetSlowestRate", 3usize, |this| ((**(this as *const *const IMFRateSupport_Vtbl)).GetSlowestRate)(this, edirection, fthin, pflrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFastestRate(this: *mut core::ffi::c_void, edirection: MFRATE_DIRECTION, fthin: super::super::Foundation::BOOL, pflrate: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFRateSupport", "GetFastestRate", 4usize, |this| ((**(this as *const *const IMFRateSupport_Vtbl)).GetFastestRate)(this, edirection, fthin, pflrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRateSupported(this: *mut core::ffi::c_void, fthin: super::super::Foundation::BOOL, flrate: f32, pflnearestsupportedrate: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFRateSupport", "IsRateSupported", 5usize, |this| ((**(this as *const *const IMFRateSupport_Vtbl)).IsRateSupported)(this, fthin, flrate, pflnearestsupportedrate), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSlowestRate, GetFastestRate, IsRateSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFRateSupport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFReadWriteClassFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstanceFromURL(this: *mut core::ffi::c_void, clsid: *const windows_core::GUID, pwszurl: windows_core::PCWSTR, pattributes: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFReadWriteClassFactory", "CreateInstanceFromURL", 3usize, |this| ((**(this as *const *const IMFReadWriteClassFactory_Vtbl)).CreateInstanceFromURL)(this, clsid, pwszurl, pattributes, riid, ppvobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInstanceFromObject(this: *mut core::ffi::c_void, clsid: *const windows_core::GUID, punkobject: *mut core::ffi::c_void, pattributes: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFReadWriteClassFactory", "CreateInstanceFromObject", 4usize, |this| ((**(this as *const *const IMFReadWriteClassFactory_Vtbl)).CreateInstanceFromObject)(this, clsid, punkobject, pattributes, riid, ppvobject), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstanceFromURL, CreateInstanceFromObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFReadWriteClassFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFRealTimeClient_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterThreads(this: *mut core::ffi::c_void, dwtaskindex: u32, wszclass: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFRealTimeClient", "RegisterThreads", 3usize, |this| ((**(this as *const *const IMFRealTimeClient_Vtbl)).RegisterThreads)(this, dwtaskindex, wszclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterThreads(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFRealTimeClient", "UnregisterThreads", 4usize, |this| ((**(this as *const *const IMFRealTimeClient_Vtbl)).UnregisterThreads)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWorkQueue(this: *mut core::ffi::c_void, dwworkqueueid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFRealTimeClient", "SetWorkQueue", 5usize, |this| ((**(this as *const *const IMFRealTimeClient_Vtbl)).SetWorkQueue)(this, dwworkqueueid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterThreads, UnregisterThreads, SetWorkQueue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFRealTimeClient as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFRealTimeClientEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterThreadsEx(this: *mut core::ffi::c_void, pdwtaskindex: *mut u32, wszclassname: windows_core::PCWSTR, lbasepriority: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFRealTimeClientEx", "RegisterThreadsEx", 3usize, |this| ((**(this as *const *const IMFRealTimeClientEx_Vtbl)).RegisterThreadsEx)(this, pdwtaskindex, wszclassname, lbasepriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterThreads(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFRealTimeClientEx", "UnregisterThreads", 4usize, |this| ((**(this as *const *const IMFRealTimeClientEx_Vtbl)).UnregisterThreads)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWorkQueueEx(this: *mut core::ffi::c_void, dwmultithreadedworkqueueid: u32, lworkitembasepriority: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFRealTimeClientEx", "SetWorkQueueEx", 5usize, |this| ((**(this as *const *const IMFRealTimeClientEx_Vtbl)).SetWorkQueueEx)(this, dwmultithreadedworkqueueid, lworkitembasepriority), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterThreadsEx, UnregisterThreads, SetWorkQueueEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFRealTimeClientEx as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFRelativePanelReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRelativePanel(this: *mut core::ffi::c_void, panel: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFRelativePanelReport", "GetRelativePanel", 3usize, |this| ((**(this as *const *const IMFRelativePanelReport_Vtbl)).GetRelativePanel)(this, panel), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRelativePanel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFRelativePanelReport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFRelativePanelWatcher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BeginGetReport(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, pstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFRelativePanelWatcher", "BeginGetReport", 5usize, |this| ((**(this as *const *const IMFRelativePanelWatcher_Vtbl)).BeginGetReport)(this, pcallback, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndGetReport(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void, pprelativepanelreport: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFRelativePanelWatcher", "EndGetReport", 6usize, |this| ((**(this as *const *const IMFRelativePanelWatcher_Vtbl)).EndGetReport)(this, presult, pprelativepanelreport), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReport(this: *mut core::ffi::c_void, pprelativepanelreport: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFRelativePanelWatcher", "GetReport", 7usize, |this| ((**(this as *const *const IMFRelativePanelWatcher_Vtbl)).GetReport)(this, pprelativepanelreport), |result| Some(*result))
+        }
+        Self { base__: <IMFShutdown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BeginGetReport, EndGetReport, GetReport }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFRelativePanelWatcher as windows_core::Interface>::IID || <IMFShutdown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFRemoteAsyncCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Invoke(this: *mut core::ffi::c_void, hr: windows_core::HRESULT, premoteresult: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFRemoteAsyncCallback", "Invoke", 3usize, |this| ((**(this as *const *const IMFRemoteAsyncCallback_Vtbl)).Invoke)(this, hr, premoteresult), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Invoke }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFRemoteAsyncCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFRemoteDesktopPlugin_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UpdateTopology(this: *mut core::ffi::c_void, ptopology: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFRemoteDesktopPlugin", "UpdateTopology", 3usize, |this| ((**(this as *const *const IMFRemoteDesktopPlugin_Vtbl)).UpdateTopology)(this, ptopology), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UpdateTopology }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFRemoteDesktopPlugin as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFRemoteProxy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRemoteObject(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFRemoteProxy", "GetRemoteObject", 3usize, |this| ((**(this as *const *const IMFRemoteProxy_Vtbl)).GetRemoteObject)(this, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRemoteHost(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFRemoteProxy", "GetRemoteHost", 4usize, |this| ((**(this as *const *const IMFRemoteProxy_Vtbl)).GetRemoteHost)(this, riid, ppv), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRemoteObject, GetRemoteHost }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFRemoteProxy as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSAMIStyle_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStyleCount(this: *mut core::ffi::c_void, pdwcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSAMIStyle", "GetStyleCount", 3usize, |this| ((**(this as *const *const IMFSAMIStyle_Vtbl)).GetStyleCount)(this, pdwcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStyles(this: *mut core::ffi::c_void, ppropvarstylearray: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSAMIStyle", "GetStyles", 4usize, |this| ((**(this as *const *const IMFSAMIStyle_Vtbl)).GetStyles)(this, ppropvarstylearray), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelectedStyle(this: *mut core::ffi::c_void, pwszstyle: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSAMIStyle", "SetSelectedStyle", 5usize, |this| ((**(this as *const *const IMFSAMIStyle_Vtbl)).SetSelectedStyle)(this, pwszstyle), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSelectedStyle(this: *mut core::ffi::c_void, ppwszstyle: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSAMIStyle", "GetSelectedStyle", 6usize, |this| ((**(this as *const *const IMFSAMIStyle_Vtbl)).GetSelectedStyle)(this, ppwszstyle), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStyleCount, GetStyles, SetSelectedStyle, GetSelectedStyle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSAMIStyle as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSSLCertificateManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetClientCertificate(this: *mut core::ffi::c_void, pszurl: windows_core::PCWSTR, ppbdata: *mut *mut u8, pcbdata: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSSLCertificateManager", "GetClientCertificate", 3usize, |this| ((**(this as *const *const IMFSSLCertificateManager_Vtbl)).GetClientCertificate)(this, pszurl, ppbdata, pcbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginGetClientCertificate(this: *mut core::ffi::c_void, pszurl: windows_core::PCWSTR, pcallback: *mut core::ffi::c_void, pstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSSLCertificateManager", "BeginGetClientCertificate", 4usize, |this| ((**(this as *const *const IMFSSLCertificateManager_Vtbl)).BeginGetClientCertificate)(this, pszurl, pcallback, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndGetClientCertificate(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void, ppbdata: *mut *mut u8, pcbdata: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSSLCertificateManager", "EndGetClientCertificate", 5usize, |this| ((**(this as *const *const IMFSSLCertificateManager_Vtbl)).EndGetClientCertificate)(this, presult, ppbdata, pcbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCertificatePolicy(this: *mut core::ffi::c_void, pszurl: windows_core::PCWSTR, pfoverrideautomaticcheck: *mut super::super::Foundation::BOOL, pfclientcertificateavailable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSSLCertificateManager", "GetCertificatePolicy", 6usize, |this| ((**(this as *const *const IMFSSLCertificateManager_Vtbl)).GetCertificatePolicy)(this, pszurl, pfoverrideautomaticcheck, pfclientcertificateavailable), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnServerCertificate(this: *mut core::ffi::c_void, pszurl: windows_core::PCWSTR, pbdata: *const u8, cbdata: u32, pfisgood: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSSLCertificateManager", "OnServerCertificate", 7usize, |this| ((**(this as *const *const IMFSSLCertificateManager_Vtbl)).OnServerCertificate)(this, pszurl, pbdata, cbdata, pfisgood), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetClientCertificate,
+            BeginGetClientCertificate,
+            EndGetClientCertificate,
+            GetCertificatePolicy,
+            OnServerCertificate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSSLCertificateManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSample_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSampleFlags(this: *mut core::ffi::c_void, pdwsampleflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSample", "GetSampleFlags", 33usize, |this| ((**(this as *const *const IMFSample_Vtbl)).GetSampleFlags)(this, pdwsampleflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSampleFlags(this: *mut core::ffi::c_void, dwsampleflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSample", "SetSampleFlags", 34usize, |this| ((**(this as *const *const IMFSample_Vtbl)).SetSampleFlags)(this, dwsampleflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSampleTime(this: *mut core::ffi::c_void, phnssampletime: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSample", "GetSampleTime", 35usize, |this| ((**(this as *const *const IMFSample_Vtbl)).GetSampleTime)(this, phnssampletime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSampleTime(this: *mut core::ffi::c_void, hnssampletime: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSample", "SetSampleTime", 36usize, |this| ((**(this as *const *const IMFSample_Vtbl)).SetSampleTime)(this, hnssampletime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSampleDuration(this: *mut core::ffi::c_void, phnssampleduration: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSample", "GetSampleDuration", 37usize, |this| ((**(this as *const *const IMFSample_Vtbl)).GetSampleDuration)(this, phnssampleduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSampleDuration(this: *mut core::ffi::c_void, hnssampleduration: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSample", "SetSampleDuration", 38usize, |this| ((**(this as *const *const IMFSample_Vtbl)).SetSampleDuration)(this, hnssampleduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBufferCount(this: *mut core::ffi::c_void, pdwbuffercount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSample", "GetBufferCount", 39usize, |this| ((**(this as *const *const IMFSample_Vtbl)).GetBufferCount)(this, pdwbuffercount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBufferByIndex(this: *mut core::ffi::c_void, dwindex: u32, ppbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSample", "GetBufferByIndex", 40usize, |this| ((**(this as *const *const IMFSample_Vtbl)).GetBufferByIndex)(this, dwindex, ppbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertToContiguousBuffer(this: *mut core::ffi::c_void, ppbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSample", "ConvertToContiguousBuffer", 41usize, |this| ((**(this as *const *const IMFSample_Vtbl)).ConvertToContiguousBuffer)(this, ppbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddBuffer(this: *mut core::ffi::c_void, pbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSample", "AddBuffer", 42usize, |this| ((**(this as *const *const IMFSample_Vtbl)).AddBuffer)(this, pbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBufferByIndex(this: *mut core::ffi::c_void, dwindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSample", "RemoveBufferByIndex", 43usize, |this| ((**(this as *const *const IMFSample_Vtbl)).RemoveBufferByIndex)(this, dwindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAllBuffers(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSample", "RemoveAllBuffers", 44usize, |this| ((**(this as *const *const IMFSample_Vtbl)).RemoveAllBuffers)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTotalLength(this: *mut core::ffi::c_void, pcbtotallength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSample", "GetTotalLength", 45usize, |this| ((**(this as *const *const IMFSample_Vtbl)).GetTotalLength)(this, pcbtotallength), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyToBuffer(this: *mut core::ffi::c_void, pbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSample", "CopyToBuffer", 46usize, |this| ((**(this as *const *const IMFSample_Vtbl)).CopyToBuffer)(this, pbuffer), |result| Some(*result))
+        }
+        Self {
+            base__: <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSampleFlags,
+            SetSampleFlags,
+            GetSampleTime,
+            SetSampleTime,
+            GetSampleDuration,
+            SetSampleDuration,
+            GetBufferCount,
+            GetBufferByIndex,
+            ConvertToContiguousBuffer,
+            AddBuffer,
+            RemoveBufferByIndex,
+            RemoveAllBuffers,
+            GetTotalLength,
+            CopyToBuffer,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSample as windows_core::Interface>::IID || <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSampleAllocatorControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDefaultAllocator(this: *mut core::ffi::c_void, dwoutputstreamid: u32, pallocator: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSampleAllocatorControl", "SetDefaultAllocator", 3usize, |this| ((**(this as *const *const IMFSampleAllocatorControl_Vtbl)).SetDefaultAllocator)(this, dwoutputstreamid, pallocator), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllocatorUsage(this: *mut core::ffi::c_void, dwoutputstreamid: u32, pdwinputstreamid: *mut u32, peusage: *mut MFSampleAllocatorUsage) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSampleAllocatorControl", "GetAllocatorUsage", 4usize, |this| ((**(this as *const *const IMFSampleAllocatorControl_Vtbl)).GetAllocatorUsage)(this, dwoutputstreamid, pdwinputstreamid, peusage), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetDefaultAllocator, GetAllocatorUsage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSampleAllocatorControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSampleGrabberSinkCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnSetPresentationClock(this: *mut core::ffi::c_void, ppresentationclock: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSampleGrabberSinkCallback", "OnSetPresentationClock", 8usize, |this| ((**(this as *const *const IMFSampleGrabberSinkCallback_Vtbl)).OnSetPresentationClock)(this, ppresentationclock), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnProcessSample(this: *mut core::ffi::c_void, guidmajormediatype: *const windows_core::GUID, dwsampleflags: u32, llsampletime: i64, llsampleduration: i64, psamplebuffer: *const u8, dwsamplesize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSampleGrabberSinkCallback", "OnProcessSample", 9usize, |this| ((**(this as *const *const IMFSampleGrabberSinkCallback_Vtbl)).OnProcessSample)(this, guidmajormediatype, dwsampleflags, llsampletime, llsampleduration, psamplebuffer, dwsamplesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnShutdown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSampleGrabberSinkCallback", "OnShutdown", 10usize, |this| ((**(this as *const *const IMFSampleGrabberSinkCallback_Vtbl)).OnShutdown)(this), |result| Some(*result))
+        }
+        Self { base__: <IMFClockStateSink_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnSetPresentationClock, OnProcessSample, OnShutdown }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSampleGrabberSinkCallback as windows_core::Interface>::IID || <IMFClockStateSink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSampleGrabberSinkCallback2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnProcessSampleEx(this: *mut core::ffi::c_void, guidmajormediatype: *const windows_core::GUID, dwsampleflags: u32, llsampletime: i64, llsampleduration: i64, psamplebuffer: *const u8, dwsamplesize: u32, pattributes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSampleGrabberSinkCallback2", "OnProcessSampleEx", 11usize, |this| ((**(this as *const *const IMFSampleGrabberSinkCallback2_Vtbl)).OnProcessSampleEx)(this, guidmajormediatype, dwsampleflags, llsampletime, llsampleduration, psamplebuffer, dwsamplesize, pattributes), |result| Some(*result))
+        }
+        Self { base__: <IMFSampleGrabberSinkCallback_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnProcessSampleEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSampleGrabberSinkCallback2 as windows_core::Interface>::IID || <IMFSampleGrabberSinkCallback_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSampleOutputStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BeginWriteSample(this: *mut core::ffi::c_void, psample: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, punkstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSampleOutputStream", "BeginWriteSample", 3usize, |this| ((**(this as *const *const IMFSampleOutputStream_Vtbl)).BeginWriteSample)(this, psample, pcallback, punkstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndWriteSample(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSampleOutputStream", "EndWriteSample", 4usize, |this| ((**(this as *const *const IMFSampleOutputStream_Vtbl)).EndWriteSample)(this, presult), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSampleOutputStream", "Close", 5usize, |this| ((**(this as *const *const IMFSampleOutputStream_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BeginWriteSample, EndWriteSample, Close }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSampleOutputStream as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSampleProtection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInputProtectionVersion(this: *mut core::ffi::c_void, pdwversion: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSampleProtection", "GetInputProtectionVersion", 3usize, |this| ((**(this as *const *const IMFSampleProtection_Vtbl)).GetInputProtectionVersion)(this, pdwversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputProtectionVersion(this: *mut core::ffi::c_void, pdwversion: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSampleProtection", "GetOutputProtectionVersion", 4usize, |this| ((**(this as *const *const IMFSampleProtection_Vtbl)).GetOutputProtectionVersion)(this, pdwversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProtectionCertificate(this: *mut core::ffi::c_void, dwversion: u32, ppcert: *mut *mut u8, pcbcert: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSampleProtection", "GetProtectionCertificate", 5usize, |this| ((**(this as *const *const IMFSampleProtection_Vtbl)).GetProtectionCertificate)(this, dwversion, ppcert, pcbcert), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitOutputProtection(this: *mut core::ffi::c_void, dwversion: u32, dwoutputid: u32, pbcert: *const u8, cbcert: u32, ppbseed: *mut *mut u8, pcbseed: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSampleProtection", "InitOutputProtection", 6usize, |this| ((**(this as *const *const IMFSampleProtection_Vtbl)).InitOutputProtection)(this, dwversion, dwoutputid, pbcert, cbcert, ppbseed, pcbseed), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitInputProtection(this: *mut core::ffi::c_void, dwversion: u32, dwinputid: u32, pbseed: *const u8, cbseed: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSampleProtection", "InitInputProtection", 7usize, |this| ((**(this as *const *const IMFSampleProtection_Vtbl)).InitInputProtection)(this, dwversion, dwinputid, pbseed, cbseed), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetInputProtectionVersion,
+            GetOutputProtectionVersion,
+            GetProtectionCertificate,
+            InitOutputProtection,
+            InitInputProtection,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSampleProtection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSaveJob_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BeginSave(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, pstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSaveJob", "BeginSave", 3usize, |this| ((**(this as *const *const IMFSaveJob_Vtbl)).BeginSave)(this, pstream, pcallback, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndSave(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSaveJob", "EndSave", 4usize, |this| ((**(this as *const *const IMFSaveJob_Vtbl)).EndSave)(this, presult), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelSave(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSaveJob", "CancelSave", 5usize, |this| ((**(this as *const *const IMFSaveJob_Vtbl)).CancelSave)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProgress(this: *mut core::ffi::c_void, pdwpercentcomplete: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSaveJob", "GetProgress", 6usize, |this| ((**(this as *const *const IMFSaveJob_Vtbl)).GetProgress)(this, pdwpercentcomplete), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BeginSave, EndSave, CancelSave, GetProgress }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSaveJob as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IMFSchemeHandler_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn BeginCreateObject(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, dwflags: u32, pprops: *mut core::ffi::c_void, ppiunknowncancelcookie: *mut *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, punkstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSchemeHandler", "BeginCreateObject", 3usize, |this| ((**(this as *const *const IMFSchemeHandler_Vtbl)).BeginCreateObject)(this, pwszurl, dwflags, pprops, ppiunknowncancelcookie, pcallback, punkstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndCreateObject(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void, pobjecttype: *mut MF_OBJECT_TYPE, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSchemeHandler", "EndCreateObject", 4usize, |this| ((**(this as *const *const IMFSchemeHandler_Vtbl)).EndCreateObject)(this, presult, pobjecttype, ppobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelObjectCreation(this: *mut core::ffi::c_void, piunknowncancelcookie: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSchemeHandler", "CancelObjectCreation", 5usize, |this| ((**(this as *const *const IMFSchemeHandler_Vtbl)).CancelObjectCreation)(this, piunknowncancelcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            BeginCreateObject,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            BeginCreateObject: 0,
+            EndCreateObject,
+            CancelObjectCreation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSchemeHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSecureBuffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetIdentifier(this: *mut core::ffi::c_void, pguididentifier: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSecureBuffer", "GetIdentifier", 3usize, |this| ((**(this as *const *const IMFSecureBuffer_Vtbl)).GetIdentifier)(this, pguididentifier), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetIdentifier }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSecureBuffer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSecureChannel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCertificate(this: *mut core::ffi::c_void, ppcert: *mut *mut u8, pcbcert: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSecureChannel", "GetCertificate", 3usize, |this| ((**(this as *const *const IMFSecureChannel_Vtbl)).GetCertificate)(this, ppcert, pcbcert), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetupSession(this: *mut core::ffi::c_void, pbencryptedsessionkey: *const u8, cbsessionkey: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSecureChannel", "SetupSession", 4usize, |this| ((**(this as *const *const IMFSecureChannel_Vtbl)).SetupSession)(this, pbencryptedsessionkey, cbsessionkey), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCertificate, SetupSession }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSecureChannel as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSeekInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNearestKeyFrames(this: *mut core::ffi::c_void, pguidtimeformat: *const windows_core::GUID, pvarstartposition: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, pvarpreviouskeyframe: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>, pvarnextkeyframe: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSeekInfo", "GetNearestKeyFrames", 3usize, |this| ((**(this as *const *const IMFSeekInfo_Vtbl)).GetNearestKeyFrames)(this, pguidtimeformat, pvarstartposition, pvarpreviouskeyframe, pvarnextkeyframe), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetNearestKeyFrames }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSeekInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSensorActivitiesReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pccount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorActivitiesReport", "GetCount", 3usize, |this| ((**(this as *const *const IMFSensorActivitiesReport_Vtbl)).GetCount)(this, pccount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActivityReport(this: *mut core::ffi::c_void, index: u32, sensoractivityreport: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorActivitiesReport", "GetActivityReport", 4usize, |this| ((**(this as *const *const IMFSensorActivitiesReport_Vtbl)).GetActivityReport)(this, index, sensoractivityreport), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActivityReportByDeviceName(this: *mut core::ffi::c_void, symbolicname: windows_core::PCWSTR, sensoractivityreport: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorActivitiesReport", "GetActivityReportByDeviceName", 5usize, |this| ((**(this as *const *const IMFSensorActivitiesReport_Vtbl)).GetActivityReportByDeviceName)(this, symbolicname, sensoractivityreport), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCount, GetActivityReport, GetActivityReportByDeviceName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSensorActivitiesReport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSensorActivitiesReportCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnActivitiesReport(this: *mut core::ffi::c_void, sensoractivitiesreport: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorActivitiesReportCallback", "OnActivitiesReport", 3usize, |this| ((**(this as *const *const IMFSensorActivitiesReportCallback_Vtbl)).OnActivitiesReport)(this, sensoractivitiesreport), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnActivitiesReport }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSensorActivitiesReportCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSensorActivityMonitor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorActivityMonitor", "Start", 3usize, |this| ((**(this as *const *const IMFSensorActivityMonitor_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorActivityMonitor", "Stop", 4usize, |this| ((**(this as *const *const IMFSensorActivityMonitor_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Start, Stop }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSensorActivityMonitor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSensorActivityReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFriendlyName(this: *mut core::ffi::c_void, friendlyname: windows_core::PWSTR, cchfriendlyname: u32, pcchwritten: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorActivityReport", "GetFriendlyName", 3usize, |this| ((**(this as *const *const IMFSensorActivityReport_Vtbl)).GetFriendlyName)(this, friendlyname, cchfriendlyname, pcchwritten), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSymbolicLink(this: *mut core::ffi::c_void, symboliclink: windows_core::PWSTR, cchsymboliclink: u32, pcchwritten: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorActivityReport", "GetSymbolicLink", 4usize, |this| ((**(this as *const *const IMFSensorActivityReport_Vtbl)).GetSymbolicLink)(this, symboliclink, cchsymboliclink, pcchwritten), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProcessCount(this: *mut core::ffi::c_void, pccount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorActivityReport", "GetProcessCount", 5usize, |this| ((**(this as *const *const IMFSensorActivityReport_Vtbl)).GetProcessCount)(this, pccount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProcessActivity(this: *mut core::ffi::c_void, index: u32, ppprocessactivity: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorActivityReport", "GetProcessActivity", 6usize, |this| ((**(this as *const *const IMFSensorActivityReport_Vtbl)).GetProcessActivity)(this, index, ppprocessactivity), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetFriendlyName,
+            GetSymbolicLink,
+            GetProcessCount,
+            GetProcessActivity,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSensorActivityReport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSensorDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceId(this: *mut core::ffi::c_void, pdeviceid: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorDevice", "GetDeviceId", 3usize, |this| ((**(this as *const *const IMFSensorDevice_Vtbl)).GetDeviceId)(this, pdeviceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceType(this: *mut core::ffi::c_void, ptype: *mut MFSensorDeviceType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorDevice", "GetDeviceType", 4usize, |this| ((**(this as *const *const IMFSensorDevice_Vtbl)).GetDeviceType)(this, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlags(this: *mut core::ffi::c_void, pflags: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorDevice", "GetFlags", 5usize, |this| ((**(this as *const *const IMFSensorDevice_Vtbl)).GetFlags)(this, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSymbolicLink(this: *mut core::ffi::c_void, symboliclink: windows_core::PWSTR, cchsymboliclink: i32, pcchwritten: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorDevice", "GetSymbolicLink", 6usize, |this| ((**(this as *const *const IMFSensorDevice_Vtbl)).GetSymbolicLink)(this, symboliclink, cchsymboliclink, pcchwritten), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceAttributes(this: *mut core::ffi::c_void, ppattributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorDevice", "GetDeviceAttributes", 7usize, |this| ((**(this as *const *const IMFSensorDevice_Vtbl)).GetDeviceAttributes)(this, ppattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamAttributesCount(this: *mut core::ffi::c_void, etype: MFSensorStreamType, pdwcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorDevice", "GetStreamAttributesCount", 8usize, |this| ((**(this as *const *const IMFSensorDevice_Vtbl)).GetStreamAttributesCount)(this, etype, pdwcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamAttributes(this: *mut core::ffi::c_void, etype: MFSensorStreamType, dwindex: u32, ppattributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorDevice", "GetStreamAttributes", 9usize, |this| ((**(this as *const *const IMFSensorDevice_Vtbl)).GetStreamAttributes)(this, etype, dwindex, ppattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSensorDeviceMode(this: *mut core::ffi::c_void, emode: MFSensorDeviceMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorDevice", "SetSensorDeviceMode", 10usize, |this| ((**(this as *const *const IMFSensorDevice_Vtbl)).SetSensorDeviceMode)(this, emode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSensorDeviceMode(this: *mut core::ffi::c_void, pemode: *mut MFSensorDeviceMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorDevice", "GetSensorDeviceMode", 11usize, |this| ((**(this as *const *const IMFSensorDevice_Vtbl)).GetSensorDeviceMode)(this, pemode), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDeviceId,
+            GetDeviceType,
+            GetFlags,
+            GetSymbolicLink,
+            GetDeviceAttributes,
+            GetStreamAttributesCount,
+            GetStreamAttributes,
+            SetSensorDeviceMode,
+            GetSensorDeviceMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSensorDevice as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSensorGroup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSymbolicLink(this: *mut core::ffi::c_void, symboliclink: windows_core::PWSTR, cchsymboliclink: i32, pcchwritten: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorGroup", "GetSymbolicLink", 3usize, |this| ((**(this as *const *const IMFSensorGroup_Vtbl)).GetSymbolicLink)(this, symboliclink, cchsymboliclink, pcchwritten), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlags(this: *mut core::ffi::c_void, pflags: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorGroup", "GetFlags", 4usize, |this| ((**(this as *const *const IMFSensorGroup_Vtbl)).GetFlags)(this, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSensorGroupAttributes(this: *mut core::ffi::c_void, ppattributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorGroup", "GetSensorGroupAttributes", 5usize, |this| ((**(this as *const *const IMFSensorGroup_Vtbl)).GetSensorGroupAttributes)(this, ppattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSensorDeviceCount(this: *mut core::ffi::c_void, pdwcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorGroup", "GetSensorDeviceCount", 6usize, |this| ((**(this as *const *const IMFSensorGroup_Vtbl)).GetSensorDeviceCount)(this, pdwcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSensorDevice(this: *mut core::ffi::c_void, dwindex: u32, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorGroup", "GetSensorDevice", 7usize, |this| ((**(this as *const *const IMFSensorGroup_Vtbl)).GetSensorDevice)(this, dwindex, ppdevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultSensorDeviceIndex(this: *mut core::ffi::c_void, dwindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorGroup", "SetDefaultSensorDeviceIndex", 8usize, |this| ((**(this as *const *const IMFSensorGroup_Vtbl)).SetDefaultSensorDeviceIndex)(this, dwindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultSensorDeviceIndex(this: *mut core::ffi::c_void, pdwindex: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorGroup", "GetDefaultSensorDeviceIndex", 9usize, |this| ((**(this as *const *const IMFSensorGroup_Vtbl)).GetDefaultSensorDeviceIndex)(this, pdwindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateMediaSource(this: *mut core::ffi::c_void, ppsource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorGroup", "CreateMediaSource", 10usize, |this| ((**(this as *const *const IMFSensorGroup_Vtbl)).CreateMediaSource)(this, ppsource), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSymbolicLink,
+            GetFlags,
+            GetSensorGroupAttributes,
+            GetSensorDeviceCount,
+            GetSensorDevice,
+            SetDefaultSensorDeviceIndex,
+            GetDefaultSensorDeviceIndex,
+            CreateMediaSource,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSensorGroup as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSensorProcessActivity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProcessId(this: *mut core::ffi::c_void, ppid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorProcessActivity", "GetProcessId", 3usize, |this| ((**(this as *const *const IMFSensorProcessActivity_Vtbl)).GetProcessId)(this, ppid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamingState(this: *mut core::ffi::c_void, pfstreaming: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorProcessActivity", "GetStreamingState", 4usize, |this| ((**(this as *const *const IMFSensorProcessActivity_Vtbl)).GetStreamingState)(this, pfstreaming), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamingMode(this: *mut core::ffi::c_void, pmode: *mut MFSensorDeviceMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorProcessActivity", "GetStreamingMode", 5usize, |this| ((**(this as *const *const IMFSensorProcessActivity_Vtbl)).GetStreamingMode)(this, pmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReportTime(this: *mut core::ffi::c_void, pft: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorProcessActivity", "GetReportTime", 6usize, |this| ((**(this as *const *const IMFSensorProcessActivity_Vtbl)).GetReportTime)(this, pft), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProcessId, GetStreamingState, GetStreamingMode, GetReportTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSensorProcessActivity as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSensorProfile_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProfileId(this: *mut core::ffi::c_void, pid: *mut SENSORPROFILEID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorProfile", "GetProfileId", 3usize, |this| ((**(this as *const *const IMFSensorProfile_Vtbl)).GetProfileId)(this, pid), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddProfileFilter(this: *mut core::ffi::c_void, streamid: u32, wzfiltersetstring: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorProfile", "AddProfileFilter", 4usize, |this| ((**(this as *const *const IMFSensorProfile_Vtbl)).AddProfileFilter)(this, streamid, wzfiltersetstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMediaTypeSupported(this: *mut core::ffi::c_void, streamid: u32, pmediatype: *mut core::ffi::c_void, pfsupported: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorProfile", "IsMediaTypeSupported", 5usize, |this| ((**(this as *const *const IMFSensorProfile_Vtbl)).IsMediaTypeSupported)(this, streamid, pmediatype, pfsupported), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddBlockedControl(this: *mut core::ffi::c_void, wzblockedcontrol: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorProfile", "AddBlockedControl", 6usize, |this| ((**(this as *const *const IMFSensorProfile_Vtbl)).AddBlockedControl)(this, wzblockedcontrol), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetProfileId,
+            AddProfileFilter,
+            IsMediaTypeSupported,
+            AddBlockedControl,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSensorProfile as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSensorProfileCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProfileCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IMFSensorProfileCollection", "GetProfileCount", 3usize, |this| ((**(this as *const *const IMFSensorProfileCollection_Vtbl)).GetProfileCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetProfile(this: *mut core::ffi::c_void, index: u32, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorProfileCollection", "GetProfile", 4usize, |this| ((**(this as *const *const IMFSensorProfileCollection_Vtbl)).GetProfile)(this, index, ppprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddProfile(this: *mut core::ffi::c_void, pprofile: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorProfileCollection", "AddProfile", 5usize, |this| ((**(this as *const *const IMFSensorProfileCollection_Vtbl)).AddProfile)(this, pprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindProfile(this: *mut core::ffi::c_void, profileid: *const SENSORPROFILEID, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorProfileCollection", "FindProfile", 6usize, |this| ((**(this as *const *const IMFSensorProfileCollection_Vtbl)).FindProfile)(this, profileid, ppprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveProfileByIndex(this: *mut core::ffi::c_void, index: u32) {
+            windows_core::imp::proxy_call(this, "IMFSensorProfileCollection", "RemoveProfileByIndex", 7usize, |this| ((**(this as *const *const IMFSensorProfileCollection_Vtbl)).RemoveProfileByIndex)(this, index), |_| None)
+        }
+        unsafe extern "system" fn RemoveProfile(this: *mut core::ffi::c_void, profileid: *const SENSORPROFILEID) {
+            windows_core::imp::proxy_call(this, "IMFSensorProfileCollection", "RemoveProfile", 8usize, |this| ((**(this as *const *const IMFSensorProfileCollection_Vtbl)).RemoveProfile)(this, profileid), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetProfileCount,
+            GetProfile,
+            AddProfile,
+            FindProfile,
+            RemoveProfileByIndex,
+            RemoveProfile,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSensorProfileCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSensorStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMediaTypeCount(this: *mut core::ffi::c_void, pdwcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorStream", "GetMediaTypeCount", 33usize, |this| ((**(this as *const *const IMFSensorStream_Vtbl)).GetMediaTypeCount)(this, pdwcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMediaType(this: *mut core::ffi::c_void, dwindex: u32, ppmediatype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorStream", "GetMediaType", 34usize, |this| ((**(this as *const *const IMFSensorStream_Vtbl)).GetMediaType)(this, dwindex, ppmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloneSensorStream(this: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorStream", "CloneSensorStream", 35usize, |this| ((**(this as *const *const IMFSensorStream_Vtbl)).CloneSensorStream)(this, ppstream), |result| Some(*result))
+        }
+        Self { base__: <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMediaTypeCount, GetMediaType, CloneSensorStream }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSensorStream as windows_core::Interface>::IID || <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSensorTransformFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFactoryAttributes(this: *mut core::ffi::c_void, ppattributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorTransformFactory", "GetFactoryAttributes", 3usize, |this| ((**(this as *const *const IMFSensorTransformFactory_Vtbl)).GetFactoryAttributes)(this, ppattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeFactory(this: *mut core::ffi::c_void, dwmaxtransformcount: u32, psensordevices: *mut core::ffi::c_void, pattributes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorTransformFactory", "InitializeFactory", 4usize, |this| ((**(this as *const *const IMFSensorTransformFactory_Vtbl)).InitializeFactory)(this, dwmaxtransformcount, psensordevices, pattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransformCount(this: *mut core::ffi::c_void, pdwcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorTransformFactory", "GetTransformCount", 5usize, |this| ((**(this as *const *const IMFSensorTransformFactory_Vtbl)).GetTransformCount)(this, pdwcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransformInformation(this: *mut core::ffi::c_void, transformindex: u32, pguidtransformid: *mut windows_core::GUID, ppattributes: *mut *mut core::ffi::c_void, ppstreaminformation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorTransformFactory", "GetTransformInformation", 6usize, |this| ((**(this as *const *const IMFSensorTransformFactory_Vtbl)).GetTransformInformation)(this, transformindex, pguidtransformid, ppattributes, ppstreaminformation), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTransform(this: *mut core::ffi::c_void, guidsensortransformid: *const windows_core::GUID, pattributes: *mut core::ffi::c_void, ppdevicemft: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSensorTransformFactory", "CreateTransform", 7usize, |this| ((**(this as *const *const IMFSensorTransformFactory_Vtbl)).CreateTransform)(this, guidsensortransformid, pattributes, ppdevicemft), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetFactoryAttributes,
+            InitializeFactory,
+            GetTransformCount,
+            GetTransformInformation,
+            CreateTransform,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSensorTransformFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSequencerSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppendTopology(this: *mut core::ffi::c_void, ptopology: *mut core::ffi::c_void, dwflags: u32, pdwid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSequencerSource", "AppendTopology", 3usize, |this| ((**(this as *const *const IMFSequencerSource_Vtbl)).AppendTopology)(this, ptopology, dwflags, pdwid), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteTopology(this: *mut core::ffi::c_void, dwid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSequencerSource", "DeleteTopology", 4usize, |this| ((**(this as *const *const IMFSequencerSource_Vtbl)).DeleteTopology)(this, dwid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPresentationContext(this: *mut core::ffi::c_void, ppd: *mut core::ffi::c_void, pid: *mut u32, pptopology: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSequencerSource", "GetPresentationContext", 5usize, |this| ((**(this as *const *const IMFSequencerSource_Vtbl)).GetPresentationContext)(this, ppd, pid, pptopology), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateTopology(this: *mut core::ffi::c_void, dwid: u32, ptopology: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSequencerSource", "UpdateTopology", 6usize, |this| ((**(this as *const *const IMFSequencerSource_Vtbl)).UpdateTopology)(this, dwid, ptopology), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateTopologyFlags(this: *mut core::ffi::c_void, dwid: u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSequencerSource", "UpdateTopologyFlags", 7usize, |this| ((**(this as *const *const IMFSequencerSource_Vtbl)).UpdateTopologyFlags)(this, dwid, dwflags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AppendTopology,
+            DeleteTopology,
+            GetPresentationContext,
+            UpdateTopology,
+            UpdateTopologyFlags,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSequencerSource as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSharingEngineClassFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, dwflags: u32, pattr: *mut core::ffi::c_void, ppengine: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSharingEngineClassFactory", "CreateInstance", 3usize, |this| ((**(this as *const *const IMFSharingEngineClassFactory_Vtbl)).CreateInstance)(this, dwflags, pattr, ppengine), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSharingEngineClassFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFShutdown_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFShutdown", "Shutdown", 3usize, |this| ((**(this as *const *const IMFShutdown_Vtbl)).Shutdown)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetShutdownStatus(this: *mut core::ffi::c_void, pstatus: *mut MFSHUTDOWN_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFShutdown", "GetShutdownStatus", 4usize, |this| ((**(this as *const *const IMFShutdown_Vtbl)).GetShutdownStatus)(this, pstatus), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Shutdown, GetShutdownStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFShutdown as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSignedLibrary_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProcedureAddress(this: *mut core::ffi::c_void, name: windows_core::PCSTR, address: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSignedLibrary", "GetProcedureAddress", 3usize, |this| ((**(this as *const *const IMFSignedLibrary_Vtbl)).GetProcedureAddress)(this, name, address), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProcedureAddress }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSignedLibrary as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSimpleAudioVolume_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMasterVolume(this: *mut core::ffi::c_void, flevel: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSimpleAudioVolume", "SetMasterVolume", 3usize, |this| ((**(this as *const *const IMFSimpleAudioVolume_Vtbl)).SetMasterVolume)(this, flevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMasterVolume(this: *mut core::ffi::c_void, pflevel: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSimpleAudioVolume", "GetMasterVolume", 4usize, |this| ((**(this as *const *const IMFSimpleAudioVolume_Vtbl)).GetMasterVolume)(this, pflevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMute(this: *mut core::ffi::c_void, bmute: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSimpleAudioVolume", "SetMute", 5usize, |this| ((**(this as *const *const IMFSimpleAudioVolume_Vtbl)).SetMute)(this, bmute), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMute(this: *mut core::ffi::c_void, pbmute: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSimpleAudioVolume", "GetMute", 6usize, |this| ((**(this as *const *const IMFSimpleAudioVolume_Vtbl)).GetMute)(this, pbmute), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetMasterVolume, GetMasterVolume, SetMute, GetMute }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSimpleAudioVolume as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSinkWriter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddStream(this: *mut core::ffi::c_void, ptargetmediatype: *mut core::ffi::c_void, pdwstreamindex: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSinkWriter", "AddStream", 3usize, |this| ((**(this as *const *const IMFSinkWriter_Vtbl)).AddStream)(this, ptargetmediatype, pdwstreamindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInputMediaType(this: *mut core::ffi::c_void, dwstreamindex: u32, pinputmediatype: *mut core::ffi::c_void, pencodingparameters: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSinkWriter", "SetInputMediaType", 4usize, |this| ((**(this as *const *const IMFSinkWriter_Vtbl)).SetInputMediaType)(this, dwstreamindex, pinputmediatype, pencodingparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginWriting(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSinkWriter", "BeginWriting", 5usize, |this| ((**(this as *const *const IMFSinkWriter_Vtbl)).BeginWriting)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteSample(this: *mut core::ffi::c_void, dwstreamindex: u32, psample: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSinkWriter", "WriteSample", 6usize, |this| ((**(this as *const *const IMFSinkWriter_Vtbl)).WriteSample)(this, dwstreamindex, psample), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendStreamTick(this: *mut core::ffi::c_void, dwstreamindex: u32, lltimestamp: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSinkWriter", "SendStreamTick", 7usize, |this| ((**(this as *const *const IMFSinkWriter_Vtbl)).SendStreamTick)(this, dwstreamindex, lltimestamp), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlaceMarker(this: *mut core::ffi::c_void, dwstreamindex: u32, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSinkWriter", "PlaceMarker", 8usize, |this| ((**(this as *const *const IMFSinkWriter_Vtbl)).PlaceMarker)(this, dwstreamindex, pvcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyEndOfSegment(this: *mut core::ffi::c_void, dwstreamindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSinkWriter", "NotifyEndOfSegment", 9usize, |this| ((**(this as *const *const IMFSinkWriter_Vtbl)).NotifyEndOfSegment)(this, dwstreamindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flush(this: *mut core::ffi::c_void, dwstreamindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSinkWriter", "Flush", 10usize, |this| ((**(this as *const *const IMFSinkWriter_Vtbl)).Flush)(this, dwstreamindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finalize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSinkWriter", "Finalize", 11usize, |this| ((**(this as *const *const IMFSinkWriter_Vtbl)).Finalize)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServiceForStream(this: *mut core::ffi::c_void, dwstreamindex: u32, guidservice: *const windows_core::GUID, riid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSinkWriter", "GetServiceForStream", 12usize, |this| ((**(this as *const *const IMFSinkWriter_Vtbl)).GetServiceForStream)(this, dwstreamindex, guidservice, riid, ppvobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatistics(this: *mut core::ffi::c_void, dwstreamindex: u32, pstats: *mut MF_SINK_WRITER_STATISTICS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSinkWriter", "GetStatistics", 13usize, |this| ((**(this as *const *const IMFSinkWriter_Vtbl)).GetStatistics)(this, dwstreamindex, pstats), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddStream,
+            SetInputMediaType,
+            BeginWriting,
+            WriteSample,
+            SendStreamTick,
+            PlaceMarker,
+            NotifyEndOfSegment,
+            Flush,
+            Finalize,
+            GetServiceForStream,
+            GetStatistics,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSinkWriter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSinkWriterCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnFinalize(this: *mut core::ffi::c_void, hrstatus: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSinkWriterCallback", "OnFinalize", 3usize, |this| ((**(this as *const *const IMFSinkWriterCallback_Vtbl)).OnFinalize)(this, hrstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnMarker(this: *mut core::ffi::c_void, dwstreamindex: u32, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSinkWriterCallback", "OnMarker", 4usize, |this| ((**(this as *const *const IMFSinkWriterCallback_Vtbl)).OnMarker)(this, dwstreamindex, pvcontext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnFinalize, OnMarker }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSinkWriterCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSinkWriterCallback2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnTransformChange(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSinkWriterCallback2", "OnTransformChange", 5usize, |this| ((**(this as *const *const IMFSinkWriterCallback2_Vtbl)).OnTransformChange)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnStreamError(this: *mut core::ffi::c_void, dwstreamindex: u32, hrstatus: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSinkWriterCallback2", "OnStreamError", 6usize, |this| ((**(this as *const *const IMFSinkWriterCallback2_Vtbl)).OnStreamError)(this, dwstreamindex, hrstatus), |result| Some(*result))
+        }
+        Self { base__: <IMFSinkWriterCallback_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnTransformChange, OnStreamError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSinkWriterCallback2 as windows_core::Interface>::IID || <IMFSinkWriterCallback_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSinkWriterEncoderConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetTargetMediaType(this: *mut core::ffi::c_void, dwstreamindex: u32, ptargetmediatype: *mut core::ffi::c_void, pencodingparameters: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSinkWriterEncoderConfig", "SetTargetMediaType", 3usize, |this| ((**(this as *const *const IMFSinkWriterEncoderConfig_Vtbl)).SetTargetMediaType)(this, dwstreamindex, ptargetmediatype, pencodingparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlaceEncodingParameters(this: *mut core::ffi::c_void, dwstreamindex: u32, pencodingparameters: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSinkWriterEncoderConfig", "PlaceEncodingParameters", 4usize, |this| ((**(this as *const *const IMFSinkWriterEncoderConfig_Vtbl)).PlaceEncodingParameters)(this, dwstreamindex, pencodingparameters), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetTargetMediaType, PlaceEncodingParameters }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSinkWriterEncoderConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSinkWriterEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTransformForStream(this: *mut core::ffi::c_void, dwstreamindex: u32, dwtransformindex: u32, pguidcategory: *mut windows_core::GUID, pptransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSinkWriterEx", "GetTransformForStream", 14usize, |this| ((**(this as *const *const IMFSinkWriterEx_Vtbl)).GetTransformForStream)(this, dwstreamindex, dwtransformindex, pguidcategory, pptransform), |result| Some(*result))
+        }
+        Self { base__: <IMFSinkWriter_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetTransformForStream }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSinkWriterEx as windows_core::Interface>::IID || <IMFSinkWriter_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSourceBuffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetUpdating(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IMFSourceBuffer", "GetUpdating", 3usize, |this| ((**(this as *const *const IMFSourceBuffer_Vtbl)).GetUpdating)(this), |_| None)
+        }
+        unsafe extern "system" fn GetBuffered(this: *mut core::ffi::c_void, ppbuffered: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceBuffer", "GetBuffered", 4usize, |this| ((**(this as *const *const IMFSourceBuffer_Vtbl)).GetBuffered)(this, ppbuffered), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTimeStampOffset(this: *mut core::ffi::c_void) -> f64 {
+            windows_core::imp::proxy_call(this, "IMFSourceBuffer", "GetTimeStampOffset", 5usize, |this| ((**(this as *const *const IMFSourceBuffer_Vtbl)).GetTimeStampOffset)(this), |_| None)
+        }
+        unsafe extern "system" fn SetTimeStampOffset(this: *mut core::ffi::c_void, offset: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceBuffer", "SetTimeStampOffset", 6usize, |this| ((**(this as *const *const IMFSourceBuffer_Vtbl)).SetTimeStampOffset)(this, offset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAppendWindowStart(this: *mut core::ffi::c_void) -> f64 {
+            windows_core::imp::proxy_call(this, "IMFSourceBuffer", "GetAppendWindowStart", 7usize, |this| ((**(this as *const *const IMFSourceBuffer_Vtbl)).GetAppendWindowStart)(this), |_| None)
+        }
+        unsafe extern "system" fn SetAppendWindowStart(this: *mut core::ffi::c_void, time: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceBuffer", "SetAppendWindowStart", 8usize, |this| ((**(this as *const *const IMFSourceBuffer_Vtbl)).SetAppendWindowStart)(this, time), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAppendWindowEnd(this: *mut core::ffi::c_void) -> f64 {
+            windows_core::imp::proxy_call(this, "IMFSourceBuffer", "GetAppendWindowEnd", 9usize, |this| ((**(this as *const *const IMFSourceBuffer_Vtbl)).GetAppendWindowEnd)(this), |_| None)
+        }
+        unsafe extern "system" fn SetAppendWindowEnd(this: *mut core::ffi::c_void, time: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceBuffer", "SetAppendWindowEnd", 10usize, |this| ((**(this as *const *const IMFSourceBuffer_Vtbl)).SetAppendWindowEnd)(this, time), |result| Some(*result))
+        }
+        unsafe extern "system" fn Append(this: *mut core::ffi::c_void, pdata: *const u8, len: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceBuffer", "Append", 11usize, |this| ((**(this as *const *const IMFSourceBuffer_Vtbl)).Append)(this, pdata, len), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppendByteStream(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, pmaxlen: *const u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceBuffer", "AppendByteStream", 12usize, |this| ((**(this as *const *const IMFSourceBuffer_Vtbl)).AppendByteStream)(this, pstream, pmaxlen), |result| Some(*result))
+        }
+        unsafe extern "system" fn Abort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceBuffer", "Abort", 13usize, |this| ((**(this as *const *const IMFSourceBuffer_Vtbl)).Abort)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, start: f64, end: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceBuffer", "Remove", 14usize, |this| ((**(this as *const *const IMFSourceBuffer_Vtbl)).Remove)(this, start, end), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetUpdating,
+            GetBuffered,
+            GetTimeStampOffset,
+            SetTimeStampOffset,
+            GetAppendWindowStart,
+            SetAppendWindowStart,
+            GetAppendWindowEnd,
+            SetAppendWindowEnd,
+            Append,
+            AppendByteStream,
+            Abort,
+            Remove,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSourceBuffer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSourceBufferAppendMode_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAppendMode(this: *mut core::ffi::c_void) -> MF_MSE_APPEND_MODE {
+            windows_core::imp::proxy_call(this, "IMFSourceBufferAppendMode", "GetAppendMode", 3usize, |this| ((**(this as *const *const IMFSourceBufferAppendMode_Vtbl)).GetAppendMode)(this), |_| None)
+        }
+        unsafe extern "system" fn SetAppendMode(this: *mut core::ffi::c_void, mode: MF_MSE_APPEND_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceBufferAppendMode", "SetAppendMode", 4usize, |this| ((**(this as *const *const IMFSourceBufferAppendMode_Vtbl)).SetAppendMode)(this, mode), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAppendMode, SetAppendMode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSourceBufferAppendMode as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSourceBufferList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IMFSourceBufferList", "GetLength", 3usize, |this| ((**(this as *const *const IMFSourceBufferList_Vtbl)).GetLength)(this), |_| None)
+        }
+        unsafe extern "system" fn GetSourceBuffer(this: *mut core::ffi::c_void, index: u32) -> Option<IMFSourceBuffer> {
+            windows_core::imp::proxy_call(this, "IMFSourceBufferList", "GetSourceBuffer", 4usize, |this| ((**(this as *const *const IMFSourceBufferList_Vtbl)).GetSourceBuffer)(this, index), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetLength, GetSourceBuffer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSourceBufferList as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSourceBufferNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnUpdateStart(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IMFSourceBufferNotify", "OnUpdateStart", 3usize, |this| ((**(this as *const *const IMFSourceBufferNotify_Vtbl)).OnUpdateStart)(this), |_| None)
+        }
+        unsafe extern "system" fn OnAbort(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IMFSourceBufferNotify", "OnAbort", 4usize, |this| ((**(this as *const *const IMFSourceBufferNotify_Vtbl)).OnAbort)(this), |_| None)
+        }
+        unsafe extern "system" fn OnError(this: *mut core::ffi::c_void, hr: windows_core::HRESULT) {
+            windows_core::imp::proxy_call(this, "IMFSourceBufferNotify", "OnError", 5usize, |this| ((**(this as *const *const IMFSourceBufferNotify_Vtbl)).OnError)(this, hr), |_| None)
+        }
+        unsafe extern "system" fn OnUpdate(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IMFSourceBufferNotify", "OnUpdate", 6usize, |this| ((**(this as *const *const IMFSourceBufferNotify_Vtbl)).OnUpdate)(this), |_| None)
+        }
+        unsafe extern "system" fn OnUpdateEnd(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IMFSourceBufferNotify", "OnUpdateEnd", 7usize, |this| ((**(this as *const *const IMFSourceBufferNotify_Vtbl)).OnUpdateEnd)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnUpdateStart, OnAbort, OnError, OnUpdate, OnUpdateEnd }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSourceBufferNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSourceOpenMonitor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnSourceEvent(this: *mut core::ffi::c_void, pevent: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceOpenMonitor", "OnSourceEvent", 3usize, |this| ((**(this as *const *const IMFSourceOpenMonitor_Vtbl)).OnSourceEvent)(this, pevent), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnSourceEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSourceOpenMonitor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSourceReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStreamSelection(this: *mut core::ffi::c_void, dwstreamindex: u32, pfselected: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceReader", "GetStreamSelection", 3usize, |this| ((**(this as *const *const IMFSourceReader_Vtbl)).GetStreamSelection)(this, dwstreamindex, pfselected), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStreamSelection(this: *mut core::ffi::c_void, dwstreamindex: u32, fselected: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceReader", "SetStreamSelection", 4usize, |this| ((**(this as *const *const IMFSourceReader_Vtbl)).SetStreamSelection)(this, dwstreamindex, fselected), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNativeMediaType(this: *mut core::ffi::c_void, dwstreamindex: u32, dwmediatypeindex: u32, ppmediatype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceReader", "GetNativeMediaType", 5usize, |this| ((**(this as *const *const IMFSourceReader_Vtbl)).GetNativeMediaType)(this, dwstreamindex, dwmediatypeindex, ppmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentMediaType(this: *mut core::ffi::c_void, dwstreamindex: u32, ppmediatype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceReader", "GetCurrentMediaType", 6usize, |this| ((**(this as *const *const IMFSourceReader_Vtbl)).GetCurrentMediaType)(this, dwstreamindex, ppmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentMediaType(this: *mut core::ffi::c_void, dwstreamindex: u32, pdwreserved: *const u32, pmediatype: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceReader", "SetCurrentMediaType", 7usize, |this| ((**(this as *const *const IMFSourceReader_Vtbl)).SetCurrentMediaType)(this, dwstreamindex, pdwreserved, pmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentPosition(this: *mut core::ffi::c_void, guidtimeformat: *const windows_core::GUID, varposition: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceReader", "SetCurrentPosition", 8usize, |this| ((**(this as *const *const IMFSourceReader_Vtbl)).SetCurrentPosition)(this, guidtimeformat, varposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadSample(this: *mut core::ffi::c_void, dwstreamindex: u32, dwcontrolflags: u32, pdwactualstreamindex: *mut u32, pdwstreamflags: *mut u32, plltimestamp: *mut i64, ppsample: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceReader", "ReadSample", 9usize, |this| ((**(this as *const *const IMFSourceReader_Vtbl)).ReadSample)(this, dwstreamindex, dwcontrolflags, pdwactualstreamindex, pdwstreamflags, plltimestamp, ppsample), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flush(this: *mut core::ffi::c_void, dwstreamindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceReader", "Flush", 10usize, |this| ((**(this as *const *const IMFSourceReader_Vtbl)).Flush)(this, dwstreamindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServiceForStream(this: *mut core::ffi::c_void, dwstreamindex: u32, guidservice: *const windows_core::GUID, riid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceReader", "GetServiceForStream", 11usize, |this| ((**(this as *const *const IMFSourceReader_Vtbl)).GetServiceForStream)(this, dwstreamindex, guidservice, riid, ppvobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPresentationAttribute(this: *mut core::ffi::c_void, dwstreamindex: u32, guidattribute: *const windows_core::GUID, pvarattribute: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceReader", "GetPresentationAttribute", 12usize, |this| ((**(this as *const *const IMFSourceReader_Vtbl)).GetPresentationAttribute)(this, dwstreamindex, guidattribute, pvarattribute), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetStreamSelection,
+            SetStreamSelection,
+            GetNativeMediaType,
+            GetCurrentMediaType,
+            SetCurrentMediaType,
+            SetCurrentPosition,
+            ReadSample,
+            Flush,
+            GetServiceForStream,
+            GetPresentationAttribute,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSourceReader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSourceReaderCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnReadSample(this: *mut core::ffi::c_void, hrstatus: windows_core::HRESULT, dwstreamindex: u32, dwstreamflags: u32, lltimestamp: i64, psample: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceReaderCallback", "OnReadSample", 3usize, |this| ((**(this as *const *const IMFSourceReaderCallback_Vtbl)).OnReadSample)(this, hrstatus, dwstreamindex, dwstreamflags, lltimestamp, psample), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnFlush(this: *mut core::ffi::c_void, dwstreamindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceReaderCallback", "OnFlush", 4usize, |this| ((**(this as *const *const IMFSourceReaderCallback_Vtbl)).OnFlush)(this, dwstreamindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnEvent(this: *mut core::ffi::c_void, dwstreamindex: u32, pevent: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceReaderCallback", "OnEvent", 5usize, |this| ((**(this as *const *const IMFSourceReaderCallback_Vtbl)).OnEvent)(this, dwstreamindex, pevent), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnReadSample, OnFlush, OnEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSourceReaderCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSourceReaderCallback2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnTransformChange(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceReaderCallback2", "OnTransformChange", 6usize, |this| ((**(this as *const *const IMFSourceReaderCallback2_Vtbl)).OnTransformChange)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnStreamError(this: *mut core::ffi::c_void, dwstreamindex: u32, hrstatus: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceReaderCallback2", "OnStreamError", 7usize, |this| ((**(this as *const *const IMFSourceReaderCallback2_Vtbl)).OnStreamError)(this, dwstreamindex, hrstatus), |result| Some(*result))
+        }
+        Self { base__: <IMFSourceReaderCallback_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnTransformChange, OnStreamError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSourceReaderCallback2 as windows_core::Interface>::IID || <IMFSourceReaderCallback_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSourceReaderEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetNativeMediaType(this: *mut core::ffi::c_void, dwstreamindex: u32, pmediatype: *mut core::ffi::c_void, pdwstreamflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceReaderEx", "SetNativeMediaType", 13usize, |this| ((**(this as *const *const IMFSourceReaderEx_Vtbl)).SetNativeMediaType)(this, dwstreamindex, pmediatype, pdwstreamflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTransformForStream(this: *mut core::ffi::c_void, dwstreamindex: u32, ptransformoractivate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceReaderEx", "AddTransformForStream", 14usize, |this| ((**(this as *const *const IMFSourceReaderEx_Vtbl)).AddTransformForStream)(this, dwstreamindex, ptransformoractivate), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAllTransformsForStream(this: *mut core::ffi::c_void, dwstreamindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceReaderEx", "RemoveAllTransformsForStream", 15usize, |this| ((**(this as *const *const IMFSourceReaderEx_Vtbl)).RemoveAllTransformsForStream)(this, dwstreamindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransformForStream(this: *mut core::ffi::c_void, dwstreamindex: u32, dwtransformindex: u32, pguidcategory: *mut windows_core::GUID, pptransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceReaderEx", "GetTransformForStream", 16usize, |this| ((**(this as *const *const IMFSourceReaderEx_Vtbl)).GetTransformForStream)(this, dwstreamindex, dwtransformindex, pguidcategory, pptransform), |result| Some(*result))
+        }
+        Self {
+            base__: <IMFSourceReader_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetNativeMediaType,
+            AddTransformForStream,
+            RemoveAllTransformsForStream,
+            GetTransformForStream,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSourceReaderEx as windows_core::Interface>::IID || <IMFSourceReader_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IMFSourceResolver_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn CreateObjectFromURL(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, dwflags: u32, pprops: *mut core::ffi::c_void, pobjecttype: *mut MF_OBJECT_TYPE, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceResolver", "CreateObjectFromURL", 3usize, |this| ((**(this as *const *const IMFSourceResolver_Vtbl)).CreateObjectFromURL)(this, pwszurl, dwflags, pprops, pobjecttype, ppobject), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn CreateObjectFromByteStream(this: *mut core::ffi::c_void, pbytestream: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, dwflags: u32, pprops: *mut core::ffi::c_void, pobjecttype: *mut MF_OBJECT_TYPE, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceResolver", "CreateObjectFromByteStream", 4usize, |this| ((**(this as *const *const IMFSourceResolver_Vtbl)).CreateObjectFromByteStream)(this, pbytestream, pwszurl, dwflags, pprops, pobjecttype, ppobject), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn BeginCreateObjectFromURL(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, dwflags: u32, pprops: *mut core::ffi::c_void, ppiunknowncancelcookie: *mut *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, punkstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceResolver", "BeginCreateObjectFromURL", 5usize, |this| ((**(this as *const *const IMFSourceResolver_Vtbl)).BeginCreateObjectFromURL)(this, pwszurl, dwflags, pprops, ppiunknowncancelcookie, pcallback, punkstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndCreateObjectFromURL(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void, pobjecttype: *mut MF_OBJECT_TYPE, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceResolver", "EndCreateObjectFromURL", 6usize, |this| ((**(this as *const *const IMFSourceResolver_Vtbl)).EndCreateObjectFromURL)(this, presult, pobjecttype, ppobject), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn BeginCreateObjectFromByteStream(this: *mut core::ffi::c_void, pbytestream: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, dwflags: u32, pprops: *mut core::ffi::c_void, ppiunknowncancelcookie: *mut *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, punkstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceResolver", "BeginCreateObjectFromByteStream", 7usize, |this| ((**(this as *const *const IMFSourceResolver_Vtbl)).BeginCreateObjectFromByteStream)(this, pbytestream, pwszurl, dwflags, pprops, ppiunknowncancelcookie, pcallback, punkstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndCreateObjectFromByteStream(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void, pobjecttype: *mut MF_OBJECT_TYPE, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceResolver", "EndCreateObjectFromByteStream", 8usize, |this| ((**(this as *const *const IMFSourceResolver_Vtbl)).EndCreateObjectFromByteStream)(this, presult, pobjecttype, ppobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelObjectCreation(this: *mut core::ffi::c_void, piunknowncancelcookie: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSourceResolver", "CancelObjectCreation", 9usize, |this| ((**(this as *const *const IMFSourceResolver_Vtbl)).CancelObjectCreation)(this, piunknowncancelcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            CreateObjectFromURL,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            CreateObjectFromURL: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            CreateObjectFromByteStream,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            CreateObjectFromByteStream: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            BeginCreateObjectFromURL,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            BeginCreateObjectFromURL: 0,
+            EndCreateObjectFromURL,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            BeginCreateObjectFromByteStream,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            BeginCreateObjectFromByteStream: 0,
+            EndCreateObjectFromByteStream,
+            CancelObjectCreation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSourceResolver as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_Audio")]
+impl windows_core::imp::ProxyVtable for IMFSpatialAudioObjectBuffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetID(this: *mut core::ffi::c_void, u32id: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSpatialAudioObjectBuffer", "SetID", 8usize, |this| ((**(this as *const *const IMFSpatialAudioObjectBuffer_Vtbl)).SetID)(this, u32id), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetID(this: *mut core::ffi::c_void, pu32id: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSpatialAudioObjectBuffer", "GetID", 9usize, |this| ((**(this as *const *const IMFSpatialAudioObjectBuffer_Vtbl)).GetID)(this, pu32id), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn SetType(this: *mut core::ffi::c_void, r#type: super::Audio::AudioObjectType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSpatialAudioObjectBuffer", "SetType", 10usize, |this| ((**(this as *const *const IMFSpatialAudioObjectBuffer_Vtbl)).SetType)(this, r#type), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, ptype: *mut super::Audio::AudioObjectType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSpatialAudioObjectBuffer", "GetType", 11usize, |this| ((**(this as *const *const IMFSpatialAudioObjectBuffer_Vtbl)).GetType)(this, ptype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn GetMetadataItems(this: *mut core::ffi::c_void, ppmetadataitems: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSpatialAudioObjectBuffer", "GetMetadataItems", 12usize, |this| ((**(this as *const *const IMFSpatialAudioObjectBuffer_Vtbl)).GetMetadataItems)(this, ppmetadataitems), |result| Some(*result))
+        }
+        Self {
+            base__: <IMFMediaBuffer_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetID,
+            GetID,
+            #[cfg(feature = "Win32_Media_Audio")]
+            SetType,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            SetType: 0,
+            #[cfg(feature = "Win32_Media_Audio")]
+            GetType,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            GetType: 0,
+            #[cfg(feature = "Win32_Media_Audio")]
+            GetMetadataItems,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            GetMetadataItems: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSpatialAudioObjectBuffer as windows_core::Interface>::IID || <IMFMediaBuffer_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSpatialAudioSample_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetObjectCount(this: *mut core::ffi::c_void, pdwobjectcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSpatialAudioSample", "GetObjectCount", 47usize, |this| ((**(this as *const *const IMFSpatialAudioSample_Vtbl)).GetObjectCount)(this, pdwobjectcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddSpatialAudioObject(this: *mut core::ffi::c_void, paudioobjbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSpatialAudioSample", "AddSpatialAudioObject", 48usize, |this| ((**(this as *const *const IMFSpatialAudioSample_Vtbl)).AddSpatialAudioObject)(this, paudioobjbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSpatialAudioObjectByIndex(this: *mut core::ffi::c_void, dwindex: u32, ppaudioobjbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSpatialAudioSample", "GetSpatialAudioObjectByIndex", 49usize, |this| ((**(this as *const *const IMFSpatialAudioSample_Vtbl)).GetSpatialAudioObjectByIndex)(this, dwindex, ppaudioobjbuffer), |result| Some(*result))
+        }
+        Self { base__: <IMFSample_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetObjectCount, AddSpatialAudioObject, GetSpatialAudioObjectByIndex }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSpatialAudioSample as windows_core::Interface>::IID || <IMFSample_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFStreamDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStreamIdentifier(this: *mut core::ffi::c_void, pdwstreamidentifier: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFStreamDescriptor", "GetStreamIdentifier", 33usize, |this| ((**(this as *const *const IMFStreamDescriptor_Vtbl)).GetStreamIdentifier)(this, pdwstreamidentifier), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMediaTypeHandler(this: *mut core::ffi::c_void, ppmediatypehandler: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFStreamDescriptor", "GetMediaTypeHandler", 34usize, |this| ((**(this as *const *const IMFStreamDescriptor_Vtbl)).GetMediaTypeHandler)(this, ppmediatypehandler), |result| Some(*result))
+        }
+        Self { base__: <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStreamIdentifier, GetMediaTypeHandler }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFStreamDescriptor as windows_core::Interface>::IID || <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFStreamSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMediaSink(this: *mut core::ffi::c_void, ppmediasink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFStreamSink", "GetMediaSink", 7usize, |this| ((**(this as *const *const IMFStreamSink_Vtbl)).GetMediaSink)(this, ppmediasink), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIdentifier(this: *mut core::ffi::c_void, pdwidentifier: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFStreamSink", "GetIdentifier", 8usize, |this| ((**(this as *const *const IMFStreamSink_Vtbl)).GetIdentifier)(this, pdwidentifier), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMediaTypeHandler(this: *mut core::ffi::c_void, pphandler: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFStreamSink", "GetMediaTypeHandler", 9usize, |this| ((**(this as *const *const IMFStreamSink_Vtbl)).GetMediaTypeHandler)(this, pphandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessSample(this: *mut core::ffi::c_void, psample: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFStreamSink", "ProcessSample", 10usize, |this| ((**(this as *const *const IMFStreamSink_Vtbl)).ProcessSample)(this, psample), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlaceMarker(this: *mut core::ffi::c_void, emarkertype: MFSTREAMSINK_MARKER_TYPE, pvarmarkervalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, pvarcontextvalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFStreamSink", "PlaceMarker", 11usize, |this| ((**(this as *const *const IMFStreamSink_Vtbl)).PlaceMarker)(this, emarkertype, pvarmarkervalue, pvarcontextvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flush(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFStreamSink", "Flush", 12usize, |this| ((**(this as *const *const IMFStreamSink_Vtbl)).Flush)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IMFMediaEventGenerator_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetMediaSink,
+            GetIdentifier,
+            GetMediaTypeHandler,
+            ProcessSample,
+            PlaceMarker,
+            Flush,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFStreamSink as windows_core::Interface>::IID || <IMFMediaEventGenerator_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFStreamingSinkConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartStreaming(this: *mut core::ffi::c_void, fseekoffsetisbyteoffset: super::super::Foundation::BOOL, qwseekoffset: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFStreamingSinkConfig", "StartStreaming", 3usize, |this| ((**(this as *const *const IMFStreamingSinkConfig_Vtbl)).StartStreaming)(this, fseekoffsetisbyteoffset, qwseekoffset), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartStreaming }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFStreamingSinkConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFSystemId_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetData(this: *mut core::ffi::c_void, size: *mut u32, data: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSystemId", "GetData", 3usize, |this| ((**(this as *const *const IMFSystemId_Vtbl)).GetData)(this, size, data), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setup(this: *mut core::ffi::c_void, stage: u32, cbin: u32, pbin: *const u8, pcbout: *mut u32, ppbout: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFSystemId", "Setup", 4usize, |this| ((**(this as *const *const IMFSystemId_Vtbl)).Setup)(this, stage, cbin, pbin, pcbout, ppbout), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetData, Setup }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFSystemId as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFTimecodeTranslate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BeginConvertTimecodeToHNS(this: *mut core::ffi::c_void, ppropvartimecode: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, pcallback: *mut core::ffi::c_void, punkstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimecodeTranslate", "BeginConvertTimecodeToHNS", 3usize, |this| ((**(this as *const *const IMFTimecodeTranslate_Vtbl)).BeginConvertTimecodeToHNS)(this, ppropvartimecode, pcallback, punkstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndConvertTimecodeToHNS(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void, phnstime: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimecodeTranslate", "EndConvertTimecodeToHNS", 4usize, |this| ((**(this as *const *const IMFTimecodeTranslate_Vtbl)).EndConvertTimecodeToHNS)(this, presult, phnstime), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginConvertHNSToTimecode(this: *mut core::ffi::c_void, hnstime: i64, pcallback: *mut core::ffi::c_void, punkstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimecodeTranslate", "BeginConvertHNSToTimecode", 5usize, |this| ((**(this as *const *const IMFTimecodeTranslate_Vtbl)).BeginConvertHNSToTimecode)(this, hnstime, pcallback, punkstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndConvertHNSToTimecode(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void, ppropvartimecode: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimecodeTranslate", "EndConvertHNSToTimecode", 6usize, |this| ((**(this as *const *const IMFTimecodeTranslate_Vtbl)).EndConvertHNSToTimecode)(this, presult, ppropvartimecode), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BeginConvertTimecodeToHNS,
+            EndConvertTimecodeToHNS,
+            BeginConvertHNSToTimecode,
+            EndConvertHNSToTimecode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFTimecodeTranslate as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFTimedText_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterNotifications(this: *mut core::ffi::c_void, notify: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedText", "RegisterNotifications", 3usize, |this| ((**(this as *const *const IMFTimedText_Vtbl)).RegisterNotifications)(this, notify), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectTrack(this: *mut core::ffi::c_void, trackid: u32, selected: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedText", "SelectTrack", 4usize, |this| ((**(this as *const *const IMFTimedText_Vtbl)).SelectTrack)(this, trackid, selected), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDataSource(this: *mut core::ffi::c_void, bytestream: *mut core::ffi::c_void, label: windows_core::PCWSTR, language: windows_core::PCWSTR, kind: MF_TIMED_TEXT_TRACK_KIND, isdefault: super::super::Foundation::BOOL, trackid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedText", "AddDataSource", 5usize, |this| ((**(this as *const *const IMFTimedText_Vtbl)).AddDataSource)(this, bytestream, label, language, kind, isdefault, trackid), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDataSourceFromUrl(this: *mut core::ffi::c_void, url: windows_core::PCWSTR, label: windows_core::PCWSTR, language: windows_core::PCWSTR, kind: MF_TIMED_TEXT_TRACK_KIND, isdefault: super::super::Foundation::BOOL, trackid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedText", "AddDataSourceFromUrl", 6usize, |this| ((**(this as *const *const IMFTimedText_Vtbl)).AddDataSourceFromUrl)(this, url, label, language, kind, isdefault, trackid), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTrack(this: *mut core::ffi::c_void, label: windows_core::PCWSTR, language: windows_core::PCWSTR, kind: MF_TIMED_TEXT_TRACK_KIND, track: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedText", "AddTrack", 7usize, |this| ((**(this as *const *const IMFTimedText_Vtbl)).AddTrack)(this, label, language, kind, track), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTrack(this: *mut core::ffi::c_void, track: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedText", "RemoveTrack", 8usize, |this| ((**(this as *const *const IMFTimedText_Vtbl)).RemoveTrack)(this, track), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCueTimeOffset(this: *mut core::ffi::c_void, offset: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedText", "GetCueTimeOffset", 9usize, |this| ((**(this as *const *const IMFTimedText_Vtbl)).GetCueTimeOffset)(this, offset), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCueTimeOffset(this: *mut core::ffi::c_void, offset: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedText", "SetCueTimeOffset", 10usize, |this| ((**(this as *const *const IMFTimedText_Vtbl)).SetCueTimeOffset)(this, offset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTracks(this: *mut core::ffi::c_void, tracks: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedText", "GetTracks", 11usize, |this| ((**(this as *const *const IMFTimedText_Vtbl)).GetTracks)(this, tracks), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActiveTracks(this: *mut core::ffi::c_void, activetracks: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedText", "GetActiveTracks", 12usize, |this| ((**(this as *const *const IMFTimedText_Vtbl)).GetActiveTracks)(this, activetracks), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTextTracks(this: *mut core::ffi::c_void, texttracks: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedText", "GetTextTracks", 13usize, |this| ((**(this as *const *const IMFTimedText_Vtbl)).GetTextTracks)(this, texttracks), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMetadataTracks(this: *mut core::ffi::c_void, metadatatracks: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedText", "GetMetadataTracks", 14usize, |this| ((**(this as *const *const IMFTimedText_Vtbl)).GetMetadataTracks)(this, metadatatracks), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInBandEnabled(this: *mut core::ffi::c_void, enabled: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedText", "SetInBandEnabled", 15usize, |this| ((**(this as *const *const IMFTimedText_Vtbl)).SetInBandEnabled)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInBandEnabled(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IMFTimedText", "IsInBandEnabled", 16usize, |this| ((**(this as *const *const IMFTimedText_Vtbl)).IsInBandEnabled)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RegisterNotifications,
+            SelectTrack,
+            AddDataSource,
+            AddDataSourceFromUrl,
+            AddTrack,
+            RemoveTrack,
+            GetCueTimeOffset,
+            SetCueTimeOffset,
+            GetTracks,
+            GetActiveTracks,
+            GetTextTracks,
+            GetMetadataTracks,
+            SetInBandEnabled,
+            IsInBandEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFTimedText as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFTimedTextBinary_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetData(this: *mut core::ffi::c_void, data: *mut *mut u8, length: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextBinary", "GetData", 3usize, |this| ((**(this as *const *const IMFTimedTextBinary_Vtbl)).GetData)(this, data, length), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFTimedTextBinary as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFTimedTextBouten_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBoutenType(this: *mut core::ffi::c_void, value: *mut MF_TIMED_TEXT_BOUTEN_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextBouten", "GetBoutenType", 3usize, |this| ((**(this as *const *const IMFTimedTextBouten_Vtbl)).GetBoutenType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBoutenColor(this: *mut core::ffi::c_void, value: *mut MFARGB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextBouten", "GetBoutenColor", 4usize, |this| ((**(this as *const *const IMFTimedTextBouten_Vtbl)).GetBoutenColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBoutenPosition(this: *mut core::ffi::c_void, value: *mut MF_TIMED_TEXT_BOUTEN_POSITION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextBouten", "GetBoutenPosition", 5usize, |this| ((**(this as *const *const IMFTimedTextBouten_Vtbl)).GetBoutenPosition)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetBoutenType, GetBoutenColor, GetBoutenPosition }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFTimedTextBouten as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFTimedTextCue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetId(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IMFTimedTextCue", "GetId", 3usize, |this| ((**(this as *const *const IMFTimedTextCue_Vtbl)).GetId)(this), |_| None)
+        }
+        unsafe extern "system" fn GetOriginalId(this: *mut core::ffi::c_void, originalid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextCue", "GetOriginalId", 4usize, |this| ((**(this as *const *const IMFTimedTextCue_Vtbl)).GetOriginalId)(this, originalid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCueKind(this: *mut core::ffi::c_void) -> MF_TIMED_TEXT_TRACK_KIND {
+            windows_core::imp::proxy_call(this, "IMFTimedTextCue", "GetCueKind", 5usize, |this| ((**(this as *const *const IMFTimedTextCue_Vtbl)).GetCueKind)(this), |_| None)
+        }
+        unsafe extern "system" fn GetStartTime(this: *mut core::ffi::c_void) -> f64 {
+            windows_core::imp::proxy_call(this, "IMFTimedTextCue", "GetStartTime", 6usize, |this| ((**(this as *const *const IMFTimedTextCue_Vtbl)).GetStartTime)(this), |_| None)
+        }
+        unsafe extern "system" fn GetDuration(this: *mut core::ffi::c_void) -> f64 {
+            windows_core::imp::proxy_call(this, "IMFTimedTextCue", "GetDuration", 7usize, |this| ((**(this as *const *const IMFTimedTextCue_Vtbl)).GetDuration)(this), |_| None)
+        }
+        unsafe extern "system" fn GetTrackId(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IMFTimedTextCue", "GetTrackId", 8usize, |this| ((**(this as *const *const IMFTimedTextCue_Vtbl)).GetTrackId)(this), |_| None)
+        }
+        unsafe extern "system" fn GetData(this: *mut core::ffi::c_void, data: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextCue", "GetData", 9usize, |this| ((**(this as *const *const IMFTimedTextCue_Vtbl)).GetData)(this, data), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRegion(this: *mut core::ffi::c_void, region: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextCue", "GetRegion", 10usize, |this| ((**(this as *const *const IMFTimedTextCue_Vtbl)).GetRegion)(this, region), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStyle(this: *mut core::ffi::c_void, style: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextCue", "GetStyle", 11usize, |this| ((**(this as *const *const IMFTimedTextCue_Vtbl)).GetStyle)(this, style), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLineCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IMFTimedTextCue", "GetLineCount", 12usize, |this| ((**(this as *const *const IMFTimedTextCue_Vtbl)).GetLineCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetLine(this: *mut core::ffi::c_void, index: u32, line: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextCue", "GetLine", 13usize, |this| ((**(this as *const *const IMFTimedTextCue_Vtbl)).GetLine)(this, index, line), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetId,
+            GetOriginalId,
+            GetCueKind,
+            GetStartTime,
+            GetDuration,
+            GetTrackId,
+            GetData,
+            GetRegion,
+            GetStyle,
+            GetLineCount,
+            GetLine,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFTimedTextCue as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFTimedTextCueList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IMFTimedTextCueList", "GetLength", 3usize, |this| ((**(this as *const *const IMFTimedTextCueList_Vtbl)).GetLength)(this), |_| None)
+        }
+        unsafe extern "system" fn GetCueByIndex(this: *mut core::ffi::c_void, index: u32, cue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextCueList", "GetCueByIndex", 4usize, |this| ((**(this as *const *const IMFTimedTextCueList_Vtbl)).GetCueByIndex)(this, index, cue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCueById(this: *mut core::ffi::c_void, id: u32, cue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextCueList", "GetCueById", 5usize, |this| ((**(this as *const *const IMFTimedTextCueList_Vtbl)).GetCueById)(this, id, cue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCueByOriginalId(this: *mut core::ffi::c_void, originalid: windows_core::PCWSTR, cue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextCueList", "GetCueByOriginalId", 6usize, |this| ((**(this as *const *const IMFTimedTextCueList_Vtbl)).GetCueByOriginalId)(this, originalid, cue), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTextCue(this: *mut core::ffi::c_void, start: f64, duration: f64, text: windows_core::PCWSTR, cue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextCueList", "AddTextCue", 7usize, |this| ((**(this as *const *const IMFTimedTextCueList_Vtbl)).AddTextCue)(this, start, duration, text, cue), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDataCue(this: *mut core::ffi::c_void, start: f64, duration: f64, data: *const u8, datasize: u32, cue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextCueList", "AddDataCue", 8usize, |this| ((**(this as *const *const IMFTimedTextCueList_Vtbl)).AddDataCue)(this, start, duration, data, datasize, cue), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCue(this: *mut core::ffi::c_void, cue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextCueList", "RemoveCue", 9usize, |this| ((**(this as *const *const IMFTimedTextCueList_Vtbl)).RemoveCue)(this, cue), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetLength,
+            GetCueByIndex,
+            GetCueById,
+            GetCueByOriginalId,
+            AddTextCue,
+            AddDataCue,
+            RemoveCue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFTimedTextCueList as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFTimedTextFormattedText_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetText(this: *mut core::ffi::c_void, text: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextFormattedText", "GetText", 3usize, |this| ((**(this as *const *const IMFTimedTextFormattedText_Vtbl)).GetText)(this, text), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubformattingCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IMFTimedTextFormattedText", "GetSubformattingCount", 4usize, |this| ((**(this as *const *const IMFTimedTextFormattedText_Vtbl)).GetSubformattingCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetSubformatting(this: *mut core::ffi::c_void, index: u32, firstchar: *mut u32, charlength: *mut u32, style: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextFormattedText", "GetSubformatting", 5usize, |this| ((**(this as *const *const IMFTimedTextFormattedText_Vtbl)).GetSubformatting)(this, index, firstchar, charlength, style), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetText, GetSubformattingCount, GetSubformatting }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFTimedTextFormattedText as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFTimedTextNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TrackAdded(this: *mut core::ffi::c_void, trackid: u32) {
+            windows_core::imp::proxy_call(this, "IMFTimedTextNotify", "TrackAdded", 3usize, |this| ((**(this as *const *const IMFTimedTextNotify_Vtbl)).TrackAdded)(this, trackid), |_| None)
+        }
+        unsafe extern "system" fn TrackRemoved(this: *mut core::ffi::c_void, trackid: u32) {
+            windows_core::imp::proxy_call(this, "IMFTimedTextNotify", "TrackRemoved", 4usize, |this| ((**(this as *const *const IMFTimedTextNotify_Vtbl)).TrackRemoved)(this, trackid), |_| None)
+        }
+        unsafe extern "system" fn TrackSelected(this: *mut core::ffi::c_void, trackid: u32, selected: super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "IMFTimedTextNotify", "TrackSelected", 5usize, |this| ((**(this as *const *const IMFTimedTextNotify_Vtbl)).TrackSelected)(this, trackid, selected), |_| None)
+        }
+        unsafe extern "system" fn TrackReadyStateChanged(this: *mut core::ffi::c_void, trackid: u32) {
+            windows_core::imp::proxy_call(this, "IMFTimedTextNotify", "TrackReadyStateChanged", 6usize, |this| ((**(this as *const *const IMFTimedTextNotify_Vtbl)).TrackReadyStateChanged)(this, trackid), |_| None)
+        }
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, errorcode: MF_TIMED_TEXT_ERROR_CODE, extendederrorcode: windows_core::HRESULT, sourcetrackid: u32) {
+            windows_core::imp::proxy_call(this, "IMFTimedTextNotify", "Error", 7usize, |this| ((**(this as *const *const IMFTimedTextNotify_Vtbl)).Error)(this, errorcode, extendederrorcode, sourcetrackid), |_| None)
+        }
+        unsafe extern "system" fn Cue(this: *mut core::ffi::c_void, cueevent: MF_TIMED_TEXT_CUE_EVENT, currenttime: f64, cue: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IMFTimedTextNotify", "Cue", 8usize, |this| ((**(this as *const *const IMFTimedTextNotify_Vtbl)).Cue)(this, cueevent, currenttime, cue), |_| None)
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IMFTimedTextNotify", "Reset", 9usize, |this| ((**(this as *const *const IMFTimedTextNotify_Vtbl)).Reset)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TrackAdded,
+            TrackRemoved,
+            TrackSelected,
+            TrackReadyStateChanged,
+            Error,
+            Cue,
+            Reset,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFTimedTextNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFTimedTextRegion_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, name: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextRegion", "GetName", 3usize, |this| ((**(this as *const *const IMFTimedTextRegion_Vtbl)).GetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPosition(this: *mut core::ffi::c_void, px: *mut f64, py: *mut f64, unittype: *mut MF_TIMED_TEXT_UNIT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextRegion", "GetPosition", 4usize, |this| ((**(this as *const *const IMFTimedTextRegion_Vtbl)).GetPosition)(this, px, py, unittype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExtent(this: *mut core::ffi::c_void, pwidth: *mut f64, pheight: *mut f64, unittype: *mut MF_TIMED_TEXT_UNIT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextRegion", "GetExtent", 5usize, |this| ((**(this as *const *const IMFTimedTextRegion_Vtbl)).GetExtent)(this, pwidth, pheight, unittype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBackgroundColor(this: *mut core::ffi::c_void, bgcolor: *mut MFARGB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextRegion", "GetBackgroundColor", 6usize, |this| ((**(this as *const *const IMFTimedTextRegion_Vtbl)).GetBackgroundColor)(this, bgcolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWritingMode(this: *mut core::ffi::c_void, writingmode: *mut MF_TIMED_TEXT_WRITING_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextRegion", "GetWritingMode", 7usize, |this| ((**(this as *const *const IMFTimedTextRegion_Vtbl)).GetWritingMode)(this, writingmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayAlignment(this: *mut core::ffi::c_void, displayalign: *mut MF_TIMED_TEXT_DISPLAY_ALIGNMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextRegion", "GetDisplayAlignment", 8usize, |this| ((**(this as *const *const IMFTimedTextRegion_Vtbl)).GetDisplayAlignment)(this, displayalign), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLineHeight(this: *mut core::ffi::c_void, plineheight: *mut f64, unittype: *mut MF_TIMED_TEXT_UNIT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextRegion", "GetLineHeight", 9usize, |this| ((**(this as *const *const IMFTimedTextRegion_Vtbl)).GetLineHeight)(this, plineheight, unittype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClipOverflow(this: *mut core::ffi::c_void, clipoverflow: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextRegion", "GetClipOverflow", 10usize, |this| ((**(this as *const *const IMFTimedTextRegion_Vtbl)).GetClipOverflow)(this, clipoverflow), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPadding(this: *mut core::ffi::c_void, before: *mut f64, start: *mut f64, after: *mut f64, end: *mut f64, unittype: *mut MF_TIMED_TEXT_UNIT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextRegion", "GetPadding", 11usize, |this| ((**(this as *const *const IMFTimedTextRegion_Vtbl)).GetPadding)(this, before, start, after, end, unittype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWrap(this: *mut core::ffi::c_void, wrap: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextRegion", "GetWrap", 12usize, |this| ((**(this as *const *const IMFTimedTextRegion_Vtbl)).GetWrap)(this, wrap), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetZIndex(this: *mut core::ffi::c_void, zindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextRegion", "GetZIndex", 13usize, |this| ((**(this as *const *const IMFTimedTextRegion_Vtbl)).GetZIndex)(this, zindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScrollMode(this: *mut core::ffi::c_void, scrollmode: *mut MF_TIMED_TEXT_SCROLL_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextRegion", "GetScrollMode", 14usize, |this| ((**(this as *const *const IMFTimedTextRegion_Vtbl)).GetScrollMode)(this, scrollmode), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetName,
+            GetPosition,
+            GetExtent,
+            GetBackgroundColor,
+            GetWritingMode,
+            GetDisplayAlignment,
+            GetLineHeight,
+            GetClipOverflow,
+            GetPadding,
+            GetWrap,
+            GetZIndex,
+            GetScrollMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFTimedTextRegion as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFTimedTextRuby_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRubyText(this: *mut core::ffi::c_void, rubytext: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextRuby", "GetRubyText", 3usize, |this| ((**(this as *const *const IMFTimedTextRuby_Vtbl)).GetRubyText)(this, rubytext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRubyPosition(this: *mut core::ffi::c_void, value: *mut MF_TIMED_TEXT_RUBY_POSITION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextRuby", "GetRubyPosition", 4usize, |this| ((**(this as *const *const IMFTimedTextRuby_Vtbl)).GetRubyPosition)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRubyAlign(this: *mut core::ffi::c_void, value: *mut MF_TIMED_TEXT_RUBY_ALIGN) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextRuby", "GetRubyAlign", 5usize, |this| ((**(this as *const *const IMFTimedTextRuby_Vtbl)).GetRubyAlign)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRubyReserve(this: *mut core::ffi::c_void, value: *mut MF_TIMED_TEXT_RUBY_RESERVE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextRuby", "GetRubyReserve", 6usize, |this| ((**(this as *const *const IMFTimedTextRuby_Vtbl)).GetRubyReserve)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRubyText, GetRubyPosition, GetRubyAlign, GetRubyReserve }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFTimedTextRuby as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFTimedTextStyle_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, name: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextStyle", "GetName", 3usize, |this| ((**(this as *const *const IMFTimedTextStyle_Vtbl)).GetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsExternal(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IMFTimedTextStyle", "IsExternal", 4usize, |this| ((**(this as *const *const IMFTimedTextStyle_Vtbl)).IsExternal)(this), |_| None)
+        }
+        unsafe extern "system" fn GetFontFamily(this: *mut core::ffi::c_void, fontfamily: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextStyle", "GetFontFamily", 5usize, |this| ((**(this as *const *const IMFTimedTextStyle_Vtbl)).GetFontFamily)(this, fontfamily), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontSize(this: *mut core::ffi::c_void, fontsize: *mut f64, unittype: *mut MF_TIMED_TEXT_UNIT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextStyle", "GetFontSize", 6usize, |this| ((**(this as *const *const IMFTimedTextStyle_Vtbl)).GetFontSize)(this, fontsize, unittype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColor(this: *mut core::ffi::c_void, color: *mut MFARGB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextStyle", "GetColor", 7usize, |this| ((**(this as *const *const IMFTimedTextStyle_Vtbl)).GetColor)(this, color), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBackgroundColor(this: *mut core::ffi::c_void, bgcolor: *mut MFARGB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextStyle", "GetBackgroundColor", 8usize, |this| ((**(this as *const *const IMFTimedTextStyle_Vtbl)).GetBackgroundColor)(this, bgcolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetShowBackgroundAlways(this: *mut core::ffi::c_void, showbackgroundalways: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextStyle", "GetShowBackgroundAlways", 9usize, |this| ((**(this as *const *const IMFTimedTextStyle_Vtbl)).GetShowBackgroundAlways)(this, showbackgroundalways), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontStyle(this: *mut core::ffi::c_void, fontstyle: *mut MF_TIMED_TEXT_FONT_STYLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextStyle", "GetFontStyle", 10usize, |this| ((**(this as *const *const IMFTimedTextStyle_Vtbl)).GetFontStyle)(this, fontstyle), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBold(this: *mut core::ffi::c_void, bold: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextStyle", "GetBold", 11usize, |this| ((**(this as *const *const IMFTimedTextStyle_Vtbl)).GetBold)(this, bold), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRightToLeft(this: *mut core::ffi::c_void, righttoleft: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextStyle", "GetRightToLeft", 12usize, |this| ((**(this as *const *const IMFTimedTextStyle_Vtbl)).GetRightToLeft)(this, righttoleft), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTextAlignment(this: *mut core::ffi::c_void, textalign: *mut MF_TIMED_TEXT_ALIGNMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextStyle", "GetTextAlignment", 13usize, |this| ((**(this as *const *const IMFTimedTextStyle_Vtbl)).GetTextAlignment)(this, textalign), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTextDecoration(this: *mut core::ffi::c_void, textdecoration: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextStyle", "GetTextDecoration", 14usize, |this| ((**(this as *const *const IMFTimedTextStyle_Vtbl)).GetTextDecoration)(this, textdecoration), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTextOutline(this: *mut core::ffi::c_void, color: *mut MFARGB, thickness: *mut f64, blurradius: *mut f64, unittype: *mut MF_TIMED_TEXT_UNIT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextStyle", "GetTextOutline", 15usize, |this| ((**(this as *const *const IMFTimedTextStyle_Vtbl)).GetTextOutline)(this, color, thickness, blurradius, unittype), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetName,
+            IsExternal,
+            GetFontFamily,
+            GetFontSize,
+            GetColor,
+            GetBackgroundColor,
+            GetShowBackgroundAlways,
+            GetFontStyle,
+            GetBold,
+            GetRightToLeft,
+            GetTextAlignment,
+            GetTextDecoration,
+            GetTextOutline,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFTimedTextStyle as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFTimedTextStyle2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRuby(this: *mut core::ffi::c_void, ruby: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextStyle2", "GetRuby", 3usize, |this| ((**(this as *const *const IMFTimedTextStyle2_Vtbl)).GetRuby)(this, ruby), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBouten(this: *mut core::ffi::c_void, bouten: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextStyle2", "GetBouten", 4usize, |this| ((**(this as *const *const IMFTimedTextStyle2_Vtbl)).GetBouten)(this, bouten), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTextCombined(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextStyle2", "IsTextCombined", 5usize, |this| ((**(this as *const *const IMFTimedTextStyle2_Vtbl)).IsTextCombined)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontAngleInDegrees(this: *mut core::ffi::c_void, value: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextStyle2", "GetFontAngleInDegrees", 6usize, |this| ((**(this as *const *const IMFTimedTextStyle2_Vtbl)).GetFontAngleInDegrees)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRuby, GetBouten, IsTextCombined, GetFontAngleInDegrees }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFTimedTextStyle2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFTimedTextTrack_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetId(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IMFTimedTextTrack", "GetId", 3usize, |this| ((**(this as *const *const IMFTimedTextTrack_Vtbl)).GetId)(this), |_| None)
+        }
+        unsafe extern "system" fn GetLabel(this: *mut core::ffi::c_void, label: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextTrack", "GetLabel", 4usize, |this| ((**(this as *const *const IMFTimedTextTrack_Vtbl)).GetLabel)(this, label), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLabel(this: *mut core::ffi::c_void, label: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextTrack", "SetLabel", 5usize, |this| ((**(this as *const *const IMFTimedTextTrack_Vtbl)).SetLabel)(this, label), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLanguage(this: *mut core::ffi::c_void, language: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextTrack", "GetLanguage", 6usize, |this| ((**(this as *const *const IMFTimedTextTrack_Vtbl)).GetLanguage)(this, language), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTrackKind(this: *mut core::ffi::c_void) -> MF_TIMED_TEXT_TRACK_KIND {
+            windows_core::imp::proxy_call(this, "IMFTimedTextTrack", "GetTrackKind", 7usize, |this| ((**(this as *const *const IMFTimedTextTrack_Vtbl)).GetTrackKind)(this), |_| None)
+        }
+        unsafe extern "system" fn IsInBand(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IMFTimedTextTrack", "IsInBand", 8usize, |this| ((**(this as *const *const IMFTimedTextTrack_Vtbl)).IsInBand)(this), |_| None)
+        }
+        unsafe extern "system" fn GetInBandMetadataTrackDispatchType(this: *mut core::ffi::c_void, dispatchtype: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextTrack", "GetInBandMetadataTrackDispatchType", 9usize, |this| ((**(this as *const *const IMFTimedTextTrack_Vtbl)).GetInBandMetadataTrackDispatchType)(this, dispatchtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsActive(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IMFTimedTextTrack", "IsActive", 10usize, |this| ((**(this as *const *const IMFTimedTextTrack_Vtbl)).IsActive)(this), |_| None)
+        }
+        unsafe extern "system" fn GetErrorCode(this: *mut core::ffi::c_void) -> MF_TIMED_TEXT_ERROR_CODE {
+            windows_core::imp::proxy_call(this, "IMFTimedTextTrack", "GetErrorCode", 11usize, |this| ((**(this as *const *const IMFTimedTextTrack_Vtbl)).GetErrorCode)(this), |_| None)
+        }
+        unsafe extern "system" fn GetExtendedErrorCode(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextTrack", "GetExtendedErrorCode", 12usize, |this| ((**(this as *const *const IMFTimedTextTrack_Vtbl)).GetExtendedErrorCode)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataFormat(this: *mut core::ffi::c_void, format: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextTrack", "GetDataFormat", 13usize, |this| ((**(this as *const *const IMFTimedTextTrack_Vtbl)).GetDataFormat)(this, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReadyState(this: *mut core::ffi::c_void) -> MF_TIMED_TEXT_TRACK_READY_STATE {
+            windows_core::imp::proxy_call(this, "IMFTimedTextTrack", "GetReadyState", 14usize, |this| ((**(this as *const *const IMFTimedTextTrack_Vtbl)).GetReadyState)(this), |_| None)
+        }
+        unsafe extern "system" fn GetCueList(this: *mut core::ffi::c_void, cues: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextTrack", "GetCueList", 15usize, |this| ((**(this as *const *const IMFTimedTextTrack_Vtbl)).GetCueList)(this, cues), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetId,
+            GetLabel,
+            SetLabel,
+            GetLanguage,
+            GetTrackKind,
+            IsInBand,
+            GetInBandMetadataTrackDispatchType,
+            IsActive,
+            GetErrorCode,
+            GetExtendedErrorCode,
+            GetDataFormat,
+            GetReadyState,
+            GetCueList,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFTimedTextTrack as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFTimedTextTrackList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IMFTimedTextTrackList", "GetLength", 3usize, |this| ((**(this as *const *const IMFTimedTextTrackList_Vtbl)).GetLength)(this), |_| None)
+        }
+        unsafe extern "system" fn GetTrack(this: *mut core::ffi::c_void, index: u32, track: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextTrackList", "GetTrack", 4usize, |this| ((**(this as *const *const IMFTimedTextTrackList_Vtbl)).GetTrack)(this, index, track), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTrackById(this: *mut core::ffi::c_void, trackid: u32, track: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimedTextTrackList", "GetTrackById", 5usize, |this| ((**(this as *const *const IMFTimedTextTrackList_Vtbl)).GetTrackById)(this, trackid, track), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetLength, GetTrack, GetTrackById }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFTimedTextTrackList as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFTimer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetTimer(this: *mut core::ffi::c_void, dwflags: u32, llclocktime: i64, pcallback: *mut core::ffi::c_void, punkstate: *mut core::ffi::c_void, ppunkkey: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimer", "SetTimer", 3usize, |this| ((**(this as *const *const IMFTimer_Vtbl)).SetTimer)(this, dwflags, llclocktime, pcallback, punkstate, ppunkkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelTimer(this: *mut core::ffi::c_void, punkkey: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTimer", "CancelTimer", 4usize, |this| ((**(this as *const *const IMFTimer_Vtbl)).CancelTimer)(this, punkkey), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetTimer, CancelTimer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFTimer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFTopoLoader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Load(this: *mut core::ffi::c_void, pinputtopo: *mut core::ffi::c_void, ppoutputtopo: *mut *mut core::ffi::c_void, pcurrenttopo: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopoLoader", "Load", 3usize, |this| ((**(this as *const *const IMFTopoLoader_Vtbl)).Load)(this, pinputtopo, ppoutputtopo, pcurrenttopo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Load }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFTopoLoader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFTopology_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTopologyID(this: *mut core::ffi::c_void, pid: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopology", "GetTopologyID", 33usize, |this| ((**(this as *const *const IMFTopology_Vtbl)).GetTopologyID)(this, pid), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddNode(this: *mut core::ffi::c_void, pnode: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopology", "AddNode", 34usize, |this| ((**(this as *const *const IMFTopology_Vtbl)).AddNode)(this, pnode), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveNode(this: *mut core::ffi::c_void, pnode: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopology", "RemoveNode", 35usize, |this| ((**(this as *const *const IMFTopology_Vtbl)).RemoveNode)(this, pnode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNodeCount(this: *mut core::ffi::c_void, pwnodes: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopology", "GetNodeCount", 36usize, |this| ((**(this as *const *const IMFTopology_Vtbl)).GetNodeCount)(this, pwnodes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNode(this: *mut core::ffi::c_void, windex: u16, ppnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopology", "GetNode", 37usize, |this| ((**(this as *const *const IMFTopology_Vtbl)).GetNode)(this, windex, ppnode), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopology", "Clear", 38usize, |this| ((**(this as *const *const IMFTopology_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloneFrom(this: *mut core::ffi::c_void, ptopology: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopology", "CloneFrom", 39usize, |this| ((**(this as *const *const IMFTopology_Vtbl)).CloneFrom)(this, ptopology), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNodeByID(this: *mut core::ffi::c_void, qwtoponodeid: u64, ppnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopology", "GetNodeByID", 40usize, |this| ((**(this as *const *const IMFTopology_Vtbl)).GetNodeByID)(this, qwtoponodeid, ppnode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourceNodeCollection(this: *mut core::ffi::c_void, ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopology", "GetSourceNodeCollection", 41usize, |this| ((**(this as *const *const IMFTopology_Vtbl)).GetSourceNodeCollection)(this, ppcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputNodeCollection(this: *mut core::ffi::c_void, ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopology", "GetOutputNodeCollection", 42usize, |this| ((**(this as *const *const IMFTopology_Vtbl)).GetOutputNodeCollection)(this, ppcollection), |result| Some(*result))
+        }
+        Self {
+            base__: <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTopologyID,
+            AddNode,
+            RemoveNode,
+            GetNodeCount,
+            GetNode,
+            Clear,
+            CloneFrom,
+            GetNodeByID,
+            GetSourceNodeCollection,
+            GetOutputNodeCollection,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFTopology as windows_core::Interface>::IID || <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFTopologyNode_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetObject(this: *mut core::ffi::c_void, pobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopologyNode", "SetObject", 33usize, |this| ((**(this as *const *const IMFTopologyNode_Vtbl)).SetObject)(this, pobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObject(this: *mut core::ffi::c_void, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopologyNode", "GetObject", 34usize, |this| ((**(this as *const *const IMFTopologyNode_Vtbl)).GetObject)(this, ppobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNodeType(this: *mut core::ffi::c_void, ptype: *mut MF_TOPOLOGY_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopologyNode", "GetNodeType", 35usize, |this| ((**(this as *const *const IMFTopologyNode_Vtbl)).GetNodeType)(this, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTopoNodeID(this: *mut core::ffi::c_void, pid: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopologyNode", "GetTopoNodeID", 36usize, |this| ((**(this as *const *const IMFTopologyNode_Vtbl)).GetTopoNodeID)(this, pid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTopoNodeID(this: *mut core::ffi::c_void, ulltopoid: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopologyNode", "SetTopoNodeID", 37usize, |this| ((**(this as *const *const IMFTopologyNode_Vtbl)).SetTopoNodeID)(this, ulltopoid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputCount(this: *mut core::ffi::c_void, pcinputs: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopologyNode", "GetInputCount", 38usize, |this| ((**(this as *const *const IMFTopologyNode_Vtbl)).GetInputCount)(this, pcinputs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputCount(this: *mut core::ffi::c_void, pcoutputs: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopologyNode", "GetOutputCount", 39usize, |this| ((**(this as *const *const IMFTopologyNode_Vtbl)).GetOutputCount)(this, pcoutputs), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectOutput(this: *mut core::ffi::c_void, dwoutputindex: u32, pdownstreamnode: *mut core::ffi::c_void, dwinputindexondownstreamnode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopologyNode", "ConnectOutput", 40usize, |this| ((**(this as *const *const IMFTopologyNode_Vtbl)).ConnectOutput)(this, dwoutputindex, pdownstreamnode, dwinputindexondownstreamnode), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisconnectOutput(this: *mut core::ffi::c_void, dwoutputindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopologyNode", "DisconnectOutput", 41usize, |this| ((**(this as *const *const IMFTopologyNode_Vtbl)).DisconnectOutput)(this, dwoutputindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInput(this: *mut core::ffi::c_void, dwinputindex: u32, ppupstreamnode: *mut *mut core::ffi::c_void, pdwoutputindexonupstreamnode: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopologyNode", "GetInput", 42usize, |this| ((**(this as *const *const IMFTopologyNode_Vtbl)).GetInput)(this, dwinputindex, ppupstreamnode, pdwoutputindexonupstreamnode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutput(this: *mut core::ffi::c_void, dwoutputindex: u32, ppdownstreamnode: *mut *mut core::ffi::c_void, pdwinputindexondownstreamnode: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopologyNode", "GetOutput", 43usize, |this| ((**(this as *const *const IMFTopologyNode_Vtbl)).GetOutput)(this, dwoutputindex, ppdownstreamnode, pdwinputindexondownstreamnode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputPrefType(this: *mut core::ffi::c_void, dwoutputindex: u32, ptype: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopologyNode", "SetOutputPrefType", 44usize, |this| ((**(this as *const *const IMFTopologyNode_Vtbl)).SetOutputPrefType)(this, dwoutputindex, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputPrefType(this: *mut core::ffi::c_void, dwoutputindex: u32, pptype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopologyNode", "GetOutputPrefType", 45usize, |this| ((**(this as *const *const IMFTopologyNode_Vtbl)).GetOutputPrefType)(this, dwoutputindex, pptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInputPrefType(this: *mut core::ffi::c_void, dwinputindex: u32, ptype: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopologyNode", "SetInputPrefType", 46usize, |this| ((**(this as *const *const IMFTopologyNode_Vtbl)).SetInputPrefType)(this, dwinputindex, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputPrefType(this: *mut core::ffi::c_void, dwinputindex: u32, pptype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopologyNode", "GetInputPrefType", 47usize, |this| ((**(this as *const *const IMFTopologyNode_Vtbl)).GetInputPrefType)(this, dwinputindex, pptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloneFrom(this: *mut core::ffi::c_void, pnode: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopologyNode", "CloneFrom", 48usize, |this| ((**(this as *const *const IMFTopologyNode_Vtbl)).CloneFrom)(this, pnode), |result| Some(*result))
+        }
+        Self {
+            base__: <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetObject,
+            GetObject,
+            GetNodeType,
+            GetTopoNodeID,
+            SetTopoNodeID,
+            GetInputCount,
+            GetOutputCount,
+            ConnectOutput,
+            DisconnectOutput,
+            GetInput,
+            GetOutput,
+            SetOutputPrefType,
+            GetOutputPrefType,
+            SetInputPrefType,
+            GetInputPrefType,
+            CloneFrom,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFTopologyNode as windows_core::Interface>::IID || <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFTopologyNodeAttributeEditor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UpdateNodeAttributes(this: *mut core::ffi::c_void, topoid: u64, cupdates: u32, pupdates: *const MFTOPONODE_ATTRIBUTE_UPDATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopologyNodeAttributeEditor", "UpdateNodeAttributes", 3usize, |this| ((**(this as *const *const IMFTopologyNodeAttributeEditor_Vtbl)).UpdateNodeAttributes)(this, topoid, cupdates, pupdates), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UpdateNodeAttributes }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFTopologyNodeAttributeEditor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFTopologyServiceLookup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LookupService(this: *mut core::ffi::c_void, r#type: MF_SERVICE_LOOKUP_TYPE, dwindex: u32, guidservice: *const windows_core::GUID, riid: *const windows_core::GUID, ppvobjects: *mut *mut core::ffi::c_void, pnobjects: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopologyServiceLookup", "LookupService", 3usize, |this| ((**(this as *const *const IMFTopologyServiceLookup_Vtbl)).LookupService)(this, r#type, dwindex, guidservice, riid, ppvobjects, pnobjects), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LookupService }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFTopologyServiceLookup as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFTopologyServiceLookupClient_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitServicePointers(this: *mut core::ffi::c_void, plookup: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopologyServiceLookupClient", "InitServicePointers", 3usize, |this| ((**(this as *const *const IMFTopologyServiceLookupClient_Vtbl)).InitServicePointers)(this, plookup), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseServicePointers(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTopologyServiceLookupClient", "ReleaseServicePointers", 4usize, |this| ((**(this as *const *const IMFTopologyServiceLookupClient_Vtbl)).ReleaseServicePointers)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InitServicePointers, ReleaseServicePointers }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFTopologyServiceLookupClient as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFTrackedSample_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAllocator(this: *mut core::ffi::c_void, psampleallocator: *mut core::ffi::c_void, punkstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTrackedSample", "SetAllocator", 3usize, |this| ((**(this as *const *const IMFTrackedSample_Vtbl)).SetAllocator)(this, psampleallocator, punkstate), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAllocator }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFTrackedSample as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFTranscodeProfile_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAudioAttributes(this: *mut core::ffi::c_void, pattrs: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTranscodeProfile", "SetAudioAttributes", 3usize, |this| ((**(this as *const *const IMFTranscodeProfile_Vtbl)).SetAudioAttributes)(this, pattrs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAudioAttributes(this: *mut core::ffi::c_void, ppattrs: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTranscodeProfile", "GetAudioAttributes", 4usize, |this| ((**(this as *const *const IMFTranscodeProfile_Vtbl)).GetAudioAttributes)(this, ppattrs), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVideoAttributes(this: *mut core::ffi::c_void, pattrs: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTranscodeProfile", "SetVideoAttributes", 5usize, |this| ((**(this as *const *const IMFTranscodeProfile_Vtbl)).SetVideoAttributes)(this, pattrs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoAttributes(this: *mut core::ffi::c_void, ppattrs: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTranscodeProfile", "GetVideoAttributes", 6usize, |this| ((**(this as *const *const IMFTranscodeProfile_Vtbl)).GetVideoAttributes)(this, ppattrs), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContainerAttributes(this: *mut core::ffi::c_void, pattrs: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTranscodeProfile", "SetContainerAttributes", 7usize, |this| ((**(this as *const *const IMFTranscodeProfile_Vtbl)).SetContainerAttributes)(this, pattrs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContainerAttributes(this: *mut core::ffi::c_void, ppattrs: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTranscodeProfile", "GetContainerAttributes", 8usize, |this| ((**(this as *const *const IMFTranscodeProfile_Vtbl)).GetContainerAttributes)(this, ppattrs), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetAudioAttributes,
+            GetAudioAttributes,
+            SetVideoAttributes,
+            GetVideoAttributes,
+            SetContainerAttributes,
+            GetContainerAttributes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFTranscodeProfile as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFTranscodeSinkInfoProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetOutputFile(this: *mut core::ffi::c_void, pwszfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTranscodeSinkInfoProvider", "SetOutputFile", 3usize, |this| ((**(this as *const *const IMFTranscodeSinkInfoProvider_Vtbl)).SetOutputFile)(this, pwszfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputByteStream(this: *mut core::ffi::c_void, pbytestreamactivate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTranscodeSinkInfoProvider", "SetOutputByteStream", 4usize, |this| ((**(this as *const *const IMFTranscodeSinkInfoProvider_Vtbl)).SetOutputByteStream)(this, pbytestreamactivate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProfile(this: *mut core::ffi::c_void, pprofile: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTranscodeSinkInfoProvider", "SetProfile", 5usize, |this| ((**(this as *const *const IMFTranscodeSinkInfoProvider_Vtbl)).SetProfile)(this, pprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSinkInfo(this: *mut core::ffi::c_void, psinkinfo: *mut MF_TRANSCODE_SINK_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTranscodeSinkInfoProvider", "GetSinkInfo", 6usize, |this| ((**(this as *const *const IMFTranscodeSinkInfoProvider_Vtbl)).GetSinkInfo)(this, psinkinfo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetOutputFile, SetOutputByteStream, SetProfile, GetSinkInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFTranscodeSinkInfoProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFTransform_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStreamLimits(this: *mut core::ffi::c_void, pdwinputminimum: *mut u32, pdwinputmaximum: *mut u32, pdwoutputminimum: *mut u32, pdwoutputmaximum: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTransform", "GetStreamLimits", 3usize, |this| ((**(this as *const *const IMFTransform_Vtbl)).GetStreamLimits)(this, pdwinputminimum, pdwinputmaximum, pdwoutputminimum, pdwoutputmaximum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamCount(this: *mut core::ffi::c_void, pcinputstreams: *mut u32, pcoutputstreams: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTransform", "GetStreamCount", 4usize, |this| ((**(this as *const *const IMFTransform_Vtbl)).GetStreamCount)(this, pcinputstreams, pcoutputstreams), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamIDs(this: *mut core::ffi::c_void, dwinputidarraysize: u32, pdwinputids: *mut u32, dwoutputidarraysize: u32, pdwoutputids: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTransform", "GetStreamIDs", 5usize, |this| ((**(this as *const *const IMFTransform_Vtbl)).GetStreamIDs)(this, dwinputidarraysize, pdwinputids, dwoutputidarraysize, pdwoutputids), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputStreamInfo(this: *mut core::ffi::c_void, dwinputstreamid: u32, pstreaminfo: *mut MFT_INPUT_STREAM_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTransform", "GetInputStreamInfo", 6usize, |this| ((**(this as *const *const IMFTransform_Vtbl)).GetInputStreamInfo)(this, dwinputstreamid, pstreaminfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputStreamInfo(this: *mut core::ffi::c_void, dwoutputstreamid: u32, pstreaminfo: *mut MFT_OUTPUT_STREAM_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTransform", "GetOutputStreamInfo", 7usize, |this| ((**(this as *const *const IMFTransform_Vtbl)).GetOutputStreamInfo)(this, dwoutputstreamid, pstreaminfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributes(this: *mut core::ffi::c_void, pattributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTransform", "GetAttributes", 8usize, |this| ((**(this as *const *const IMFTransform_Vtbl)).GetAttributes)(this, pattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputStreamAttributes(this: *mut core::ffi::c_void, dwinputstreamid: u32, pattributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTransform", "GetInputStreamAttributes", 9usize, |this| ((**(this as *const *const IMFTransform_Vtbl)).GetInputStreamAttributes)(this, dwinputstreamid, pattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputStreamAttributes(this: *mut core::ffi::c_void, dwoutputstreamid: u32, pattributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTransform", "GetOutputStreamAttributes", 10usize, |this| ((**(this as *const *const IMFTransform_Vtbl)).GetOutputStreamAttributes)(this, dwoutputstreamid, pattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteInputStream(this: *mut core::ffi::c_void, dwstreamid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTransform", "DeleteInputStream", 11usize, |this| ((**(this as *const *const IMFTransform_Vtbl)).DeleteInputStream)(this, dwstreamid), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddInputStreams(this: *mut core::ffi::c_void, cstreams: u32, adwstreamids: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTransform", "AddInputStreams", 12usize, |this| ((**(this as *const *const IMFTransform_Vtbl)).AddInputStreams)(this, cstreams, adwstreamids), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputAvailableType(this: *mut core::ffi::c_void, dwinputstreamid: u32, dwtypeindex: u32, pptype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTransform", "GetInputAvailableType", 13usize, |this| ((**(this as *const *const IMFTransform_Vtbl)).GetInputAvailableType)(this, dwinputstreamid, dwtypeindex, pptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputAvailableType(this: *mut core::ffi::c_void, dwoutputstreamid: u32, dwtypeindex: u32, pptype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTransform", "GetOutputAvailableType", 14usize, |this| ((**(this as *const *const IMFTransform_Vtbl)).GetOutputAvailableType)(this, dwoutputstreamid, dwtypeindex, pptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInputType(this: *mut core::ffi::c_void, dwinputstreamid: u32, ptype: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTransform", "SetInputType", 15usize, |this| ((**(this as *const *const IMFTransform_Vtbl)).SetInputType)(this, dwinputstreamid, ptype, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputType(this: *mut core::ffi::c_void, dwoutputstreamid: u32, ptype: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTransform", "SetOutputType", 16usize, |this| ((**(this as *const *const IMFTransform_Vtbl)).SetOutputType)(this, dwoutputstreamid, ptype, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputCurrentType(this: *mut core::ffi::c_void, dwinputstreamid: u32, pptype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTransform", "GetInputCurrentType", 17usize, |this| ((**(this as *const *const IMFTransform_Vtbl)).GetInputCurrentType)(this, dwinputstreamid, pptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputCurrentType(this: *mut core::ffi::c_void, dwoutputstreamid: u32, pptype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTransform", "GetOutputCurrentType", 18usize, |this| ((**(this as *const *const IMFTransform_Vtbl)).GetOutputCurrentType)(this, dwoutputstreamid, pptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputStatus(this: *mut core::ffi::c_void, dwinputstreamid: u32, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTransform", "GetInputStatus", 19usize, |this| ((**(this as *const *const IMFTransform_Vtbl)).GetInputStatus)(this, dwinputstreamid, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputStatus(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTransform", "GetOutputStatus", 20usize, |this| ((**(this as *const *const IMFTransform_Vtbl)).GetOutputStatus)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputBounds(this: *mut core::ffi::c_void, hnslowerbound: i64, hnsupperbound: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTransform", "SetOutputBounds", 21usize, |this| ((**(this as *const *const IMFTransform_Vtbl)).SetOutputBounds)(this, hnslowerbound, hnsupperbound), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessEvent(this: *mut core::ffi::c_void, dwinputstreamid: u32, pevent: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTransform", "ProcessEvent", 22usize, |this| ((**(this as *const *const IMFTransform_Vtbl)).ProcessEvent)(this, dwinputstreamid, pevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessMessage(this: *mut core::ffi::c_void, emessage: MFT_MESSAGE_TYPE, ulparam: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTransform", "ProcessMessage", 23usize, |this| ((**(this as *const *const IMFTransform_Vtbl)).ProcessMessage)(this, emessage, ulparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessInput(this: *mut core::ffi::c_void, dwinputstreamid: u32, psample: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTransform", "ProcessInput", 24usize, |this| ((**(this as *const *const IMFTransform_Vtbl)).ProcessInput)(this, dwinputstreamid, psample, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessOutput(this: *mut core::ffi::c_void, dwflags: u32, coutputbuffercount: u32, poutputsamples: *mut MFT_OUTPUT_DATA_BUFFER, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTransform", "ProcessOutput", 25usize, |this| ((**(this as *const *const IMFTransform_Vtbl)).ProcessOutput)(this, dwflags, coutputbuffercount, poutputsamples, pdwstatus), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetStreamLimits,
+            GetStreamCount,
+            GetStreamIDs,
+            GetInputStreamInfo,
+            GetOutputStreamInfo,
+            GetAttributes,
+            GetInputStreamAttributes,
+            GetOutputStreamAttributes,
+            DeleteInputStream,
+            AddInputStreams,
+            GetInputAvailableType,
+            GetOutputAvailableType,
+            SetInputType,
+            SetOutputType,
+            GetInputCurrentType,
+            GetOutputCurrentType,
+            GetInputStatus,
+            GetOutputStatus,
+            SetOutputBounds,
+            ProcessEvent,
+            ProcessMessage,
+            ProcessInput,
+            ProcessOutput,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFTransform as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFTrustedInput_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInputTrustAuthority(this: *mut core::ffi::c_void, dwstreamid: u32, riid: *const windows_core::GUID, ppunkobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTrustedInput", "GetInputTrustAuthority", 3usize, |this| ((**(this as *const *const IMFTrustedInput_Vtbl)).GetInputTrustAuthority)(this, dwstreamid, riid, ppunkobject), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetInputTrustAuthority }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFTrustedInput as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFTrustedOutput_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOutputTrustAuthorityCount(this: *mut core::ffi::c_void, pcoutputtrustauthorities: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTrustedOutput", "GetOutputTrustAuthorityCount", 3usize, |this| ((**(this as *const *const IMFTrustedOutput_Vtbl)).GetOutputTrustAuthorityCount)(this, pcoutputtrustauthorities), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputTrustAuthorityByIndex(this: *mut core::ffi::c_void, dwindex: u32, ppauthority: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTrustedOutput", "GetOutputTrustAuthorityByIndex", 4usize, |this| ((**(this as *const *const IMFTrustedOutput_Vtbl)).GetOutputTrustAuthorityByIndex)(this, dwindex, ppauthority), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFinal(this: *mut core::ffi::c_void, pfisfinal: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFTrustedOutput", "IsFinal", 5usize, |this| ((**(this as *const *const IMFTrustedOutput_Vtbl)).IsFinal)(this, pfisfinal), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetOutputTrustAuthorityCount,
+            GetOutputTrustAuthorityByIndex,
+            IsFinal,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFTrustedOutput as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFVideoCaptureSampleAllocator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeCaptureSampleAllocator(this: *mut core::ffi::c_void, cbsamplesize: u32, cbcapturemetadatasize: u32, cbalignment: u32, cminimumsamples: u32, pattributes: *mut core::ffi::c_void, pmediatype: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoCaptureSampleAllocator", "InitializeCaptureSampleAllocator", 7usize, |this| ((**(this as *const *const IMFVideoCaptureSampleAllocator_Vtbl)).InitializeCaptureSampleAllocator)(this, cbsamplesize, cbcapturemetadatasize, cbalignment, cminimumsamples, pattributes, pmediatype), |result| Some(*result))
+        }
+        Self { base__: <IMFVideoSampleAllocator_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InitializeCaptureSampleAllocator }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFVideoCaptureSampleAllocator as windows_core::Interface>::IID || <IMFVideoSampleAllocator_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFVideoDeviceID_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceID(this: *mut core::ffi::c_void, pdeviceid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoDeviceID", "GetDeviceID", 3usize, |this| ((**(this as *const *const IMFVideoDeviceID_Vtbl)).GetDeviceID)(this, pdeviceid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceID }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFVideoDeviceID as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IMFVideoDisplayControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNativeVideoSize(this: *mut core::ffi::c_void, pszvideo: *mut super::super::Foundation::SIZE, pszarvideo: *mut super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoDisplayControl", "GetNativeVideoSize", 3usize, |this| ((**(this as *const *const IMFVideoDisplayControl_Vtbl)).GetNativeVideoSize)(this, pszvideo, pszarvideo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIdealVideoSize(this: *mut core::ffi::c_void, pszmin: *mut super::super::Foundation::SIZE, pszmax: *mut super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoDisplayControl", "GetIdealVideoSize", 4usize, |this| ((**(this as *const *const IMFVideoDisplayControl_Vtbl)).GetIdealVideoSize)(this, pszmin, pszmax), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVideoPosition(this: *mut core::ffi::c_void, pnrcsource: *const MFVideoNormalizedRect, prcdest: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoDisplayControl", "SetVideoPosition", 5usize, |this| ((**(this as *const *const IMFVideoDisplayControl_Vtbl)).SetVideoPosition)(this, pnrcsource, prcdest), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoPosition(this: *mut core::ffi::c_void, pnrcsource: *mut MFVideoNormalizedRect, prcdest: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoDisplayControl", "GetVideoPosition", 6usize, |this| ((**(this as *const *const IMFVideoDisplayControl_Vtbl)).GetVideoPosition)(this, pnrcsource, prcdest), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAspectRatioMode(this: *mut core::ffi::c_void, dwaspectratiomode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoDisplayControl", "SetAspectRatioMode", 7usize, |this| ((**(this as *const *const IMFVideoDisplayControl_Vtbl)).SetAspectRatioMode)(this, dwaspectratiomode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAspectRatioMode(this: *mut core::ffi::c_void, pdwaspectratiomode: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoDisplayControl", "GetAspectRatioMode", 8usize, |this| ((**(this as *const *const IMFVideoDisplayControl_Vtbl)).GetAspectRatioMode)(this, pdwaspectratiomode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVideoWindow(this: *mut core::ffi::c_void, hwndvideo: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoDisplayControl", "SetVideoWindow", 9usize, |this| ((**(this as *const *const IMFVideoDisplayControl_Vtbl)).SetVideoWindow)(this, hwndvideo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoWindow(this: *mut core::ffi::c_void, phwndvideo: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoDisplayControl", "GetVideoWindow", 10usize, |this| ((**(this as *const *const IMFVideoDisplayControl_Vtbl)).GetVideoWindow)(this, phwndvideo), |result| Some(*result))
+        }
+        unsafe extern "system" fn RepaintVideo(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoDisplayControl", "RepaintVideo", 11usize, |this| ((**(this as *const *const IMFVideoDisplayControl_Vtbl)).RepaintVideo)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetCurrentImage(this: *mut core::ffi::c_void, pbih: *mut super::super::Graphics::Gdi::BITMAPINFOHEADER, pdib: *mut *mut u8, pcbdib: *mut u32, ptimestamp: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoDisplayControl", "GetCurrentImage", 12usize, |this| ((**(this as *const *const IMFVideoDisplayControl_Vtbl)).GetCurrentImage)(this, pbih, pdib, pcbdib, ptimestamp), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBorderColor(this: *mut core::ffi::c_void, clr: super::super::Foundation::COLORREF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoDisplayControl", "SetBorderColor", 13usize, |this| ((**(this as *const *const IMFVideoDisplayControl_Vtbl)).SetBorderColor)(this, clr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBorderColor(this: *mut core::ffi::c_void, pclr: *mut super::super::Foundation::COLORREF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoDisplayControl", "GetBorderColor", 14usize, |this| ((**(this as *const *const IMFVideoDisplayControl_Vtbl)).GetBorderColor)(this, pclr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRenderingPrefs(this: *mut core::ffi::c_void, dwrenderflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoDisplayControl", "SetRenderingPrefs", 15usize, |this| ((**(this as *const *const IMFVideoDisplayControl_Vtbl)).SetRenderingPrefs)(this, dwrenderflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRenderingPrefs(this: *mut core::ffi::c_void, pdwrenderflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoDisplayControl", "GetRenderingPrefs", 16usize, |this| ((**(this as *const *const IMFVideoDisplayControl_Vtbl)).GetRenderingPrefs)(this, pdwrenderflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFullscreen(this: *mut core::ffi::c_void, ffullscreen: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoDisplayControl", "SetFullscreen", 17usize, |this| ((**(this as *const *const IMFVideoDisplayControl_Vtbl)).SetFullscreen)(this, ffullscreen), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFullscreen(this: *mut core::ffi::c_void, pffullscreen: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoDisplayControl", "GetFullscreen", 18usize, |this| ((**(this as *const *const IMFVideoDisplayControl_Vtbl)).GetFullscreen)(this, pffullscreen), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetNativeVideoSize,
+            GetIdealVideoSize,
+            SetVideoPosition,
+            GetVideoPosition,
+            SetAspectRatioMode,
+            GetAspectRatioMode,
+            SetVideoWindow,
+            GetVideoWindow,
+            RepaintVideo,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetCurrentImage,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetCurrentImage: 0,
+            SetBorderColor,
+            GetBorderColor,
+            SetRenderingPrefs,
+            GetRenderingPrefs,
+            SetFullscreen,
+            GetFullscreen,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFVideoDisplayControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFVideoMediaType_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetVideoFormat(this: *mut core::ffi::c_void) -> *mut MFVIDEOFORMAT {
+            windows_core::imp::proxy_call(this, "IMFVideoMediaType", "GetVideoFormat", 38usize, |this| ((**(this as *const *const IMFVideoMediaType_Vtbl)).GetVideoFormat)(this), |_| None)
+        }
+        unsafe extern "system" fn GetVideoRepresentation(this: *mut core::ffi::c_void, guidrepresentation: windows_core::GUID, ppvrepresentation: *mut *mut core::ffi::c_void, lstride: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoMediaType", "GetVideoRepresentation", 39usize, |this| ((**(this as *const *const IMFVideoMediaType_Vtbl)).GetVideoRepresentation)(this, guidrepresentation, ppvrepresentation, lstride), |result| Some(*result))
+        }
+        Self { base__: <IMFMediaType_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetVideoFormat, GetVideoRepresentation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFVideoMediaType as windows_core::Interface>::IID || <IMFMediaType_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_Gdi"))]
+impl windows_core::imp::ProxyVtable for IMFVideoMixerBitmap_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_Gdi"))]
+        unsafe extern "system" fn SetAlphaBitmap(this: *mut core::ffi::c_void, pbmpparms: *const MFVideoAlphaBitmap) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoMixerBitmap", "SetAlphaBitmap", 3usize, |this| ((**(this as *const *const IMFVideoMixerBitmap_Vtbl)).SetAlphaBitmap)(this, pbmpparms), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearAlphaBitmap(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoMixerBitmap", "ClearAlphaBitmap", 4usize, |this| ((**(this as *const *const IMFVideoMixerBitmap_Vtbl)).ClearAlphaBitmap)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateAlphaBitmapParameters(this: *mut core::ffi::c_void, pbmpparms: *const MFVideoAlphaBitmapParams) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoMixerBitmap", "UpdateAlphaBitmapParameters", 5usize, |this| ((**(this as *const *const IMFVideoMixerBitmap_Vtbl)).UpdateAlphaBitmapParameters)(this, pbmpparms), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAlphaBitmapParameters(this: *mut core::ffi::c_void, pbmpparms: *mut MFVideoAlphaBitmapParams) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoMixerBitmap", "GetAlphaBitmapParameters", 6usize, |this| ((**(this as *const *const IMFVideoMixerBitmap_Vtbl)).GetAlphaBitmapParameters)(this, pbmpparms), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_Gdi"))]
+            SetAlphaBitmap,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D9", feature = "Win32_Graphics_Gdi")))]
+            SetAlphaBitmap: 0,
+            ClearAlphaBitmap,
+            UpdateAlphaBitmapParameters,
+            GetAlphaBitmapParameters,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFVideoMixerBitmap as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFVideoMixerControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetStreamZOrder(this: *mut core::ffi::c_void, dwstreamid: u32, dwz: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoMixerControl", "SetStreamZOrder", 3usize, |this| ((**(this as *const *const IMFVideoMixerControl_Vtbl)).SetStreamZOrder)(this, dwstreamid, dwz), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamZOrder(this: *mut core::ffi::c_void, dwstreamid: u32, pdwz: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoMixerControl", "GetStreamZOrder", 4usize, |this| ((**(this as *const *const IMFVideoMixerControl_Vtbl)).GetStreamZOrder)(this, dwstreamid, pdwz), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStreamOutputRect(this: *mut core::ffi::c_void, dwstreamid: u32, pnrcoutput: *const MFVideoNormalizedRect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoMixerControl", "SetStreamOutputRect", 5usize, |this| ((**(this as *const *const IMFVideoMixerControl_Vtbl)).SetStreamOutputRect)(this, dwstreamid, pnrcoutput), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamOutputRect(this: *mut core::ffi::c_void, dwstreamid: u32, pnrcoutput: *mut MFVideoNormalizedRect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoMixerControl", "GetStreamOutputRect", 6usize, |this| ((**(this as *const *const IMFVideoMixerControl_Vtbl)).GetStreamOutputRect)(this, dwstreamid, pnrcoutput), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetStreamZOrder,
+            GetStreamZOrder,
+            SetStreamOutputRect,
+            GetStreamOutputRect,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFVideoMixerControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFVideoMixerControl2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMixingPrefs(this: *mut core::ffi::c_void, dwmixflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoMixerControl2", "SetMixingPrefs", 7usize, |this| ((**(this as *const *const IMFVideoMixerControl2_Vtbl)).SetMixingPrefs)(this, dwmixflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMixingPrefs(this: *mut core::ffi::c_void, pdwmixflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoMixerControl2", "GetMixingPrefs", 8usize, |this| ((**(this as *const *const IMFVideoMixerControl2_Vtbl)).GetMixingPrefs)(this, pdwmixflags), |result| Some(*result))
+        }
+        Self { base__: <IMFVideoMixerControl_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetMixingPrefs, GetMixingPrefs }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFVideoMixerControl2 as windows_core::Interface>::IID || <IMFVideoMixerControl_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFVideoPositionMapper_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MapOutputCoordinateToInputStream(this: *mut core::ffi::c_void, xout: f32, yout: f32, dwoutputstreamindex: u32, dwinputstreamindex: u32, pxin: *mut f32, pyin: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoPositionMapper", "MapOutputCoordinateToInputStream", 3usize, |this| ((**(this as *const *const IMFVideoPositionMapper_Vtbl)).MapOutputCoordinateToInputStream)(this, xout, yout, dwoutputstreamindex, dwinputstreamindex, pxin, pyin), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MapOutputCoordinateToInputStream }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFVideoPositionMapper as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFVideoPresenter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProcessMessage(this: *mut core::ffi::c_void, emessage: MFVP_MESSAGE_TYPE, ulparam: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoPresenter", "ProcessMessage", 8usize, |this| ((**(this as *const *const IMFVideoPresenter_Vtbl)).ProcessMessage)(this, emessage, ulparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentMediaType(this: *mut core::ffi::c_void, ppmediatype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoPresenter", "GetCurrentMediaType", 9usize, |this| ((**(this as *const *const IMFVideoPresenter_Vtbl)).GetCurrentMediaType)(this, ppmediatype), |result| Some(*result))
+        }
+        Self { base__: <IMFClockStateSink_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ProcessMessage, GetCurrentMediaType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFVideoPresenter as windows_core::Interface>::IID || <IMFClockStateSink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D9")]
+impl windows_core::imp::ProxyVtable for IMFVideoProcessor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAvailableVideoProcessorModes(this: *mut core::ffi::c_void, lpdwnumprocessingmodes: *mut u32, ppvideoprocessingmodes: *mut *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoProcessor", "GetAvailableVideoProcessorModes", 3usize, |this| ((**(this as *const *const IMFVideoProcessor_Vtbl)).GetAvailableVideoProcessorModes)(this, lpdwnumprocessingmodes, ppvideoprocessingmodes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D9")]
+        unsafe extern "system" fn GetVideoProcessorCaps(this: *mut core::ffi::c_void, lpvideoprocessormode: *const windows_core::GUID, lpvideoprocessorcaps: *mut DXVA2_VideoProcessorCaps) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoProcessor", "GetVideoProcessorCaps", 4usize, |this| ((**(this as *const *const IMFVideoProcessor_Vtbl)).GetVideoProcessorCaps)(this, lpvideoprocessormode, lpvideoprocessorcaps), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoProcessorMode(this: *mut core::ffi::c_void, lpmode: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoProcessor", "GetVideoProcessorMode", 5usize, |this| ((**(this as *const *const IMFVideoProcessor_Vtbl)).GetVideoProcessorMode)(this, lpmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVideoProcessorMode(this: *mut core::ffi::c_void, lpmode: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoProcessor", "SetVideoProcessorMode", 6usize, |this| ((**(this as *const *const IMFVideoProcessor_Vtbl)).SetVideoProcessorMode)(this, lpmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProcAmpRange(this: *mut core::ffi::c_void, dwproperty: u32, pproprange: *mut DXVA2_ValueRange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoProcessor", "GetProcAmpRange", 7usize, |this| ((**(this as *const *const IMFVideoProcessor_Vtbl)).GetProcAmpRange)(this, dwproperty, pproprange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProcAmpValues(this: *mut core::ffi::c_void, dwflags: u32, values: *mut DXVA2_ProcAmpValues) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoProcessor", "GetProcAmpValues", 8usize, |this| ((**(this as *const *const IMFVideoProcessor_Vtbl)).GetProcAmpValues)(this, dwflags, values), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProcAmpValues(this: *mut core::ffi::c_void, dwflags: u32, pvalues: *const DXVA2_ProcAmpValues) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoProcessor", "SetProcAmpValues", 9usize, |this| ((**(this as *const *const IMFVideoProcessor_Vtbl)).SetProcAmpValues)(this, dwflags, pvalues), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFilteringRange(this: *mut core::ffi::c_void, dwproperty: u32, pproprange: *mut DXVA2_ValueRange) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoProcessor", "GetFilteringRange", 10usize, |this| ((**(this as *const *const IMFVideoProcessor_Vtbl)).GetFilteringRange)(this, dwproperty, pproprange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFilteringValue(this: *mut core::ffi::c_void, dwproperty: u32, pvalue: *mut DXVA2_Fixed32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoProcessor", "GetFilteringValue", 11usize, |this| ((**(this as *const *const IMFVideoProcessor_Vtbl)).GetFilteringValue)(this, dwproperty, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFilteringValue(this: *mut core::ffi::c_void, dwproperty: u32, pvalue: *const DXVA2_Fixed32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoProcessor", "SetFilteringValue", 12usize, |this| ((**(this as *const *const IMFVideoProcessor_Vtbl)).SetFilteringValue)(this, dwproperty, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBackgroundColor(this: *mut core::ffi::c_void, lpclrbkg: *mut super::super::Foundation::COLORREF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoProcessor", "GetBackgroundColor", 13usize, |this| ((**(this as *const *const IMFVideoProcessor_Vtbl)).GetBackgroundColor)(this, lpclrbkg), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackgroundColor(this: *mut core::ffi::c_void, clrbkg: super::super::Foundation::COLORREF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoProcessor", "SetBackgroundColor", 14usize, |this| ((**(this as *const *const IMFVideoProcessor_Vtbl)).SetBackgroundColor)(this, clrbkg), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetAvailableVideoProcessorModes,
+            #[cfg(feature = "Win32_Graphics_Direct3D9")]
+            GetVideoProcessorCaps,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D9"))]
+            GetVideoProcessorCaps: 0,
+            GetVideoProcessorMode,
+            SetVideoProcessorMode,
+            GetProcAmpRange,
+            GetProcAmpValues,
+            SetProcAmpValues,
+            GetFilteringRange,
+            GetFilteringValue,
+            SetFilteringValue,
+            GetBackgroundColor,
+            SetBackgroundColor,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFVideoProcessor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFVideoProcessorControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetBorderColor(this: *mut core::ffi::c_void, pbordercolor: *const MFARGB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoProcessorControl", "SetBorderColor", 3usize, |this| ((**(this as *const *const IMFVideoProcessorControl_Vtbl)).SetBorderColor)(this, pbordercolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSourceRectangle(this: *mut core::ffi::c_void, psrcrect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoProcessorControl", "SetSourceRectangle", 4usize, |this| ((**(this as *const *const IMFVideoProcessorControl_Vtbl)).SetSourceRectangle)(this, psrcrect), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDestinationRectangle(this: *mut core::ffi::c_void, pdstrect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoProcessorControl", "SetDestinationRectangle", 5usize, |this| ((**(this as *const *const IMFVideoProcessorControl_Vtbl)).SetDestinationRectangle)(this, pdstrect), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMirror(this: *mut core::ffi::c_void, emirror: MF_VIDEO_PROCESSOR_MIRROR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoProcessorControl", "SetMirror", 6usize, |this| ((**(this as *const *const IMFVideoProcessorControl_Vtbl)).SetMirror)(this, emirror), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRotation(this: *mut core::ffi::c_void, erotation: MF_VIDEO_PROCESSOR_ROTATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoProcessorControl", "SetRotation", 7usize, |this| ((**(this as *const *const IMFVideoProcessorControl_Vtbl)).SetRotation)(this, erotation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConstrictionSize(this: *mut core::ffi::c_void, pconstrictionsize: *const super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoProcessorControl", "SetConstrictionSize", 8usize, |this| ((**(this as *const *const IMFVideoProcessorControl_Vtbl)).SetConstrictionSize)(this, pconstrictionsize), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetBorderColor,
+            SetSourceRectangle,
+            SetDestinationRectangle,
+            SetMirror,
+            SetRotation,
+            SetConstrictionSize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFVideoProcessorControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFVideoProcessorControl2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetRotationOverride(this: *mut core::ffi::c_void, uirotation: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoProcessorControl2", "SetRotationOverride", 9usize, |this| ((**(this as *const *const IMFVideoProcessorControl2_Vtbl)).SetRotationOverride)(this, uirotation), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableHardwareEffects(this: *mut core::ffi::c_void, fenabled: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoProcessorControl2", "EnableHardwareEffects", 10usize, |this| ((**(this as *const *const IMFVideoProcessorControl2_Vtbl)).EnableHardwareEffects)(this, fenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSupportedHardwareEffects(this: *mut core::ffi::c_void, puisupport: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoProcessorControl2", "GetSupportedHardwareEffects", 11usize, |this| ((**(this as *const *const IMFVideoProcessorControl2_Vtbl)).GetSupportedHardwareEffects)(this, puisupport), |result| Some(*result))
+        }
+        Self {
+            base__: <IMFVideoProcessorControl_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetRotationOverride,
+            EnableHardwareEffects,
+            GetSupportedHardwareEffects,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFVideoProcessorControl2 as windows_core::Interface>::IID || <IMFVideoProcessorControl_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFVideoProcessorControl3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNaturalOutputType(this: *mut core::ffi::c_void, pptype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoProcessorControl3", "GetNaturalOutputType", 12usize, |this| ((**(this as *const *const IMFVideoProcessorControl3_Vtbl)).GetNaturalOutputType)(this, pptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableSphericalVideoProcessing(this: *mut core::ffi::c_void, fenable: super::super::Foundation::BOOL, eformat: MFVideoSphericalFormat, eprojectionmode: MFVideoSphericalProjectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoProcessorControl3", "EnableSphericalVideoProcessing", 13usize, |this| ((**(this as *const *const IMFVideoProcessorControl3_Vtbl)).EnableSphericalVideoProcessing)(this, fenable, eformat, eprojectionmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSphericalVideoProperties(this: *mut core::ffi::c_void, x: f32, y: f32, z: f32, w: f32, fieldofview: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoProcessorControl3", "SetSphericalVideoProperties", 14usize, |this| ((**(this as *const *const IMFVideoProcessorControl3_Vtbl)).SetSphericalVideoProperties)(this, x, y, z, w, fieldofview), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputDevice(this: *mut core::ffi::c_void, poutputdevice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoProcessorControl3", "SetOutputDevice", 15usize, |this| ((**(this as *const *const IMFVideoProcessorControl3_Vtbl)).SetOutputDevice)(this, poutputdevice), |result| Some(*result))
+        }
+        Self {
+            base__: <IMFVideoProcessorControl2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetNaturalOutputType,
+            EnableSphericalVideoProcessing,
+            SetSphericalVideoProperties,
+            SetOutputDevice,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFVideoProcessorControl3 as windows_core::Interface>::IID || <IMFVideoProcessorControl2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFVideoRenderer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeRenderer(this: *mut core::ffi::c_void, pvideomixer: *mut core::ffi::c_void, pvideopresenter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoRenderer", "InitializeRenderer", 3usize, |this| ((**(this as *const *const IMFVideoRenderer_Vtbl)).InitializeRenderer)(this, pvideomixer, pvideopresenter), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InitializeRenderer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFVideoRenderer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFVideoRendererEffectControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnAppServiceConnectionEstablished(this: *mut core::ffi::c_void, pappserviceconnection: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoRendererEffectControl", "OnAppServiceConnectionEstablished", 3usize, |this| ((**(this as *const *const IMFVideoRendererEffectControl_Vtbl)).OnAppServiceConnectionEstablished)(this, pappserviceconnection), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnAppServiceConnectionEstablished }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFVideoRendererEffectControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFVideoSampleAllocator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDirectXManager(this: *mut core::ffi::c_void, pmanager: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoSampleAllocator", "SetDirectXManager", 3usize, |this| ((**(this as *const *const IMFVideoSampleAllocator_Vtbl)).SetDirectXManager)(this, pmanager), |result| Some(*result))
+        }
+        unsafe extern "system" fn UninitializeSampleAllocator(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoSampleAllocator", "UninitializeSampleAllocator", 4usize, |this| ((**(this as *const *const IMFVideoSampleAllocator_Vtbl)).UninitializeSampleAllocator)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeSampleAllocator(this: *mut core::ffi::c_void, crequestedframes: u32, pmediatype: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoSampleAllocator", "InitializeSampleAllocator", 5usize, |this| ((**(this as *const *const IMFVideoSampleAllocator_Vtbl)).InitializeSampleAllocator)(this, crequestedframes, pmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllocateSample(this: *mut core::ffi::c_void, ppsample: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoSampleAllocator", "AllocateSample", 6usize, |this| ((**(this as *const *const IMFVideoSampleAllocator_Vtbl)).AllocateSample)(this, ppsample), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetDirectXManager,
+            UninitializeSampleAllocator,
+            InitializeSampleAllocator,
+            AllocateSample,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFVideoSampleAllocator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFVideoSampleAllocatorCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetCallback(this: *mut core::ffi::c_void, pnotify: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoSampleAllocatorCallback", "SetCallback", 3usize, |this| ((**(this as *const *const IMFVideoSampleAllocatorCallback_Vtbl)).SetCallback)(this, pnotify), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFreeSampleCount(this: *mut core::ffi::c_void, plsamples: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoSampleAllocatorCallback", "GetFreeSampleCount", 4usize, |this| ((**(this as *const *const IMFVideoSampleAllocatorCallback_Vtbl)).GetFreeSampleCount)(this, plsamples), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetCallback, GetFreeSampleCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFVideoSampleAllocatorCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFVideoSampleAllocatorEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeSampleAllocatorEx(this: *mut core::ffi::c_void, cinitialsamples: u32, cmaximumsamples: u32, pattributes: *mut core::ffi::c_void, pmediatype: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoSampleAllocatorEx", "InitializeSampleAllocatorEx", 7usize, |this| ((**(this as *const *const IMFVideoSampleAllocatorEx_Vtbl)).InitializeSampleAllocatorEx)(this, cinitialsamples, cmaximumsamples, pattributes, pmediatype), |result| Some(*result))
+        }
+        Self { base__: <IMFVideoSampleAllocator_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InitializeSampleAllocatorEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFVideoSampleAllocatorEx as windows_core::Interface>::IID || <IMFVideoSampleAllocator_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFVideoSampleAllocatorNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NotifyRelease(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoSampleAllocatorNotify", "NotifyRelease", 3usize, |this| ((**(this as *const *const IMFVideoSampleAllocatorNotify_Vtbl)).NotifyRelease)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NotifyRelease }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFVideoSampleAllocatorNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFVideoSampleAllocatorNotifyEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NotifyPrune(this: *mut core::ffi::c_void, __midl__imfvideosampleallocatornotifyex0000: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVideoSampleAllocatorNotifyEx", "NotifyPrune", 4usize, |this| ((**(this as *const *const IMFVideoSampleAllocatorNotifyEx_Vtbl)).NotifyPrune)(this, __midl__imfvideosampleallocatornotifyex0000), |result| Some(*result))
+        }
+        Self { base__: <IMFVideoSampleAllocatorNotify_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NotifyPrune }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFVideoSampleAllocatorNotifyEx as windows_core::Interface>::IID || <IMFVideoSampleAllocatorNotify_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Devices_Properties")]
+impl windows_core::imp::ProxyVtable for IMFVirtualCamera_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddDeviceSourceInfo(this: *mut core::ffi::c_void, devicesourceinfo: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVirtualCamera", "AddDeviceSourceInfo", 33usize, |this| ((**(this as *const *const IMFVirtualCamera_Vtbl)).AddDeviceSourceInfo)(this, devicesourceinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Devices_Properties")]
+        unsafe extern "system" fn AddProperty(this: *mut core::ffi::c_void, pkey: *const super::super::Devices::Properties::DEVPROPKEY, r#type: super::super::Devices::Properties::DEVPROPTYPE, pbdata: *const u8, cbdata: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVirtualCamera", "AddProperty", 34usize, |this| ((**(this as *const *const IMFVirtualCamera_Vtbl)).AddProperty)(this, pkey, r#type, pbdata, cbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddRegistryEntry(this: *mut core::ffi::c_void, entryname: windows_core::PCWSTR, subkeypath: windows_core::PCWSTR, dwregtype: u32, pbdata: *const u8, cbdata: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVirtualCamera", "AddRegistryEntry", 35usize, |this| ((**(this as *const *const IMFVirtualCamera_Vtbl)).AddRegistryEntry)(this, entryname, subkeypath, dwregtype, pbdata, cbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVirtualCamera", "Start", 36usize, |this| ((**(this as *const *const IMFVirtualCamera_Vtbl)).Start)(this, pcallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVirtualCamera", "Stop", 37usize, |this| ((**(this as *const *const IMFVirtualCamera_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVirtualCamera", "Remove", 38usize, |this| ((**(this as *const *const IMFVirtualCamera_Vtbl)).Remove)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMediaSource(this: *mut core::ffi::c_void, ppmediasource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVirtualCamera", "GetMediaSource", 39usize, |this| ((**(this as *const *const IMFVirtualCamera_Vtbl)).GetMediaSource)(this, ppmediasource), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendCameraProperty(this: *mut core::ffi::c_void, propertyset: *const windows_core::GUID, propertyid: u32, propertyflags: u32, propertypayload: *mut core::ffi::c_void, propertypayloadlength: u32, data: *mut core::ffi::c_void, datalength: u32, datawritten: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVirtualCamera", "SendCameraProperty", 40usize, |this| ((**(this as *const *const IMFVirtualCamera_Vtbl)).SendCameraProperty)(this, propertyset, propertyid, propertyflags, propertypayload, propertypayloadlength, data, datalength, datawritten), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSyncEvent(this: *mut core::ffi::c_void, kseventset: *const windows_core::GUID, kseventid: u32, kseventflags: u32, eventhandle: super::super::Foundation::HANDLE, camerasyncobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVirtualCamera", "CreateSyncEvent", 41usize, |this| ((**(this as *const *const IMFVirtualCamera_Vtbl)).CreateSyncEvent)(this, kseventset, kseventid, kseventflags, eventhandle, camerasyncobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSyncSemaphore(this: *mut core::ffi::c_void, kseventset: *const windows_core::GUID, kseventid: u32, kseventflags: u32, semaphorehandle: super::super::Foundation::HANDLE, semaphoreadjustment: i32, camerasyncobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVirtualCamera", "CreateSyncSemaphore", 42usize, |this| ((**(this as *const *const IMFVirtualCamera_Vtbl)).CreateSyncSemaphore)(this, kseventset, kseventid, kseventflags, semaphorehandle, semaphoreadjustment, camerasyncobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFVirtualCamera", "Shutdown", 43usize, |this| ((**(this as *const *const IMFVirtualCamera_Vtbl)).Shutdown)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddDeviceSourceInfo,
+            #[cfg(feature = "Win32_Devices_Properties")]
+            AddProperty,
+            #[cfg(not(feature = "Win32_Devices_Properties"))]
+            AddProperty: 0,
+            AddRegistryEntry,
+            Start,
+            Stop,
+            Remove,
+            GetMediaSource,
+            SendCameraProperty,
+            CreateSyncEvent,
+            CreateSyncSemaphore,
+            Shutdown,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFVirtualCamera as windows_core::Interface>::IID || <IMFAttributes_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFWorkQueueServices_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BeginRegisterTopologyWorkQueuesWithMMCSS(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, pstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFWorkQueueServices", "BeginRegisterTopologyWorkQueuesWithMMCSS", 3usize, |this| ((**(this as *const *const IMFWorkQueueServices_Vtbl)).BeginRegisterTopologyWorkQueuesWithMMCSS)(this, pcallback, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndRegisterTopologyWorkQueuesWithMMCSS(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFWorkQueueServices", "EndRegisterTopologyWorkQueuesWithMMCSS", 4usize, |this| ((**(this as *const *const IMFWorkQueueServices_Vtbl)).EndRegisterTopologyWorkQueuesWithMMCSS)(this, presult), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginUnregisterTopologyWorkQueuesWithMMCSS(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, pstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFWorkQueueServices", "BeginUnregisterTopologyWorkQueuesWithMMCSS", 5usize, |this| ((**(this as *const *const IMFWorkQueueServices_Vtbl)).BeginUnregisterTopologyWorkQueuesWithMMCSS)(this, pcallback, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndUnregisterTopologyWorkQueuesWithMMCSS(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFWorkQueueServices", "EndUnregisterTopologyWorkQueuesWithMMCSS", 6usize, |this| ((**(this as *const *const IMFWorkQueueServices_Vtbl)).EndUnregisterTopologyWorkQueuesWithMMCSS)(this, presult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTopologyWorkQueueMMCSSClass(this: *mut core::ffi::c_void, dwtopologyworkqueueid: u32, pwszclass: windows_core::PWSTR, pcchclass: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFWorkQueueServices", "GetTopologyWorkQueueMMCSSClass", 7usize, |this| ((**(this as *const *const IMFWorkQueueServices_Vtbl)).GetTopologyWorkQueueMMCSSClass)(this, dwtopologyworkqueueid, pwszclass, pcchclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTopologyWorkQueueMMCSSTaskId(this: *mut core::ffi::c_void, dwtopologyworkqueueid: u32, pdwtaskid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFWorkQueueServices", "GetTopologyWorkQueueMMCSSTaskId", 8usize, |this| ((**(this as *const *const IMFWorkQueueServices_Vtbl)).GetTopologyWorkQueueMMCSSTaskId)(this, dwtopologyworkqueueid, pdwtaskid), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginRegisterPlatformWorkQueueWithMMCSS(this: *mut core::ffi::c_void, dwplatformworkqueue: u32, wszclass: windows_core::PCWSTR, dwtaskid: u32, pcallback: *mut core::ffi::c_void, pstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFWorkQueueServices", "BeginRegisterPlatformWorkQueueWithMMCSS", 9usize, |this| ((**(this as *const *const IMFWorkQueueServices_Vtbl)).BeginRegisterPlatformWorkQueueWithMMCSS)(this, dwplatformworkqueue, wszclass, dwtaskid, pcallback, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndRegisterPlatformWorkQueueWithMMCSS(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void, pdwtaskid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFWorkQueueServices", "EndRegisterPlatformWorkQueueWithMMCSS", 10usize, |this| ((**(this as *const *const IMFWorkQueueServices_Vtbl)).EndRegisterPlatformWorkQueueWithMMCSS)(this, presult, pdwtaskid), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginUnregisterPlatformWorkQueueWithMMCSS(this: *mut core::ffi::c_void, dwplatformworkqueue: u32, pcallback: *mut core::ffi::c_void, pstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFWorkQueueServices", "BeginUnregisterPlatformWorkQueueWithMMCSS", 11usize, |this| ((**(this as *const *const IMFWorkQueueServices_Vtbl)).BeginUnregisterPlatformWorkQueueWithMMCSS)(this, dwplatformworkqueue, pcallback, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndUnregisterPlatformWorkQueueWithMMCSS(this: *mut core::ffi::c_void, presult: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFWorkQueueServices", "EndUnregisterPlatformWorkQueueWithMMCSS", 12usize, |this| ((**(this as *const *const IMFWorkQueueServices_Vtbl)).EndUnregisterPlatformWorkQueueWithMMCSS)(this, presult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPlaftormWorkQueueMMCSSClass(this: *mut core::ffi::c_void, dwplatformworkqueueid: u32, pwszclass: windows_core::PWSTR, pcchclass: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFWorkQueueServices", "GetPlaftormWorkQueueMMCSSClass", 13usize, |this| ((**(this as *const *const IMFWorkQueueServices_Vtbl)).GetPlaftormWorkQueueMMCSSClass)(this, dwplatformworkqueueid, pwszclass, pcchclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPlatformWorkQueueMMCSSTaskId(this: *mut core::ffi::c_void, dwplatformworkqueueid: u32, pdwtaskid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFWorkQueueServices", "GetPlatformWorkQueueMMCSSTaskId", 14usize, |this| ((**(this as *const *const IMFWorkQueueServices_Vtbl)).GetPlatformWorkQueueMMCSSTaskId)(this, dwplatformworkqueueid, pdwtaskid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BeginRegisterTopologyWorkQueuesWithMMCSS,
+            EndRegisterTopologyWorkQueuesWithMMCSS,
+            BeginUnregisterTopologyWorkQueuesWithMMCSS,
+            EndUnregisterTopologyWorkQueuesWithMMCSS,
+            GetTopologyWorkQueueMMCSSClass,
+            GetTopologyWorkQueueMMCSSTaskId,
+            BeginRegisterPlatformWorkQueueWithMMCSS,
+            EndRegisterPlatformWorkQueueWithMMCSS,
+            BeginUnregisterPlatformWorkQueueWithMMCSS,
+            EndUnregisterPlatformWorkQueueWithMMCSS,
+            GetPlaftormWorkQueueMMCSSClass,
+            GetPlatformWorkQueueMMCSSTaskId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFWorkQueueServices as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMFWorkQueueServicesEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTopologyWorkQueueMMCSSPriority(this: *mut core::ffi::c_void, dwtopologyworkqueueid: u32, plpriority: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFWorkQueueServicesEx", "GetTopologyWorkQueueMMCSSPriority", 15usize, |this| ((**(this as *const *const IMFWorkQueueServicesEx_Vtbl)).GetTopologyWorkQueueMMCSSPriority)(this, dwtopologyworkqueueid, plpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginRegisterPlatformWorkQueueWithMMCSSEx(this: *mut core::ffi::c_void, dwplatformworkqueue: u32, wszclass: windows_core::PCWSTR, dwtaskid: u32, lpriority: i32, pcallback: *mut core::ffi::c_void, pstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFWorkQueueServicesEx", "BeginRegisterPlatformWorkQueueWithMMCSSEx", 16usize, |this| ((**(this as *const *const IMFWorkQueueServicesEx_Vtbl)).BeginRegisterPlatformWorkQueueWithMMCSSEx)(this, dwplatformworkqueue, wszclass, dwtaskid, lpriority, pcallback, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPlatformWorkQueueMMCSSPriority(this: *mut core::ffi::c_void, dwplatformworkqueueid: u32, plpriority: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMFWorkQueueServicesEx", "GetPlatformWorkQueueMMCSSPriority", 17usize, |this| ((**(this as *const *const IMFWorkQueueServicesEx_Vtbl)).GetPlatformWorkQueueMMCSSPriority)(this, dwplatformworkqueueid, plpriority), |result| Some(*result))
+        }
+        Self {
+            base__: <IMFWorkQueueServices_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTopologyWorkQueueMMCSSPriority,
+            BeginRegisterPlatformWorkQueueWithMMCSSEx,
+            GetPlatformWorkQueueMMCSSPriority,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMFWorkQueueServicesEx as windows_core::Interface>::IID || <IMFWorkQueueServices_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOPMVideoOutput_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartInitialization(this: *mut core::ffi::c_void, prnrandomnumber: *mut OPM_RANDOM_NUMBER, ppbcertificate: *mut *mut u8, pulcertificatelength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOPMVideoOutput", "StartInitialization", 3usize, |this| ((**(this as *const *const IOPMVideoOutput_Vtbl)).StartInitialization)(this, prnrandomnumber, ppbcertificate, pulcertificatelength), |result| Some(*result))
+        }
+        unsafe extern "system" fn FinishInitialization(this: *mut core::ffi::c_void, pparameters: *const OPM_ENCRYPTED_INITIALIZATION_PARAMETERS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOPMVideoOutput", "FinishInitialization", 4usize, |this| ((**(this as *const *const IOPMVideoOutput_Vtbl)).FinishInitialization)(this, pparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInformation(this: *mut core::ffi::c_void, pparameters: *const OPM_GET_INFO_PARAMETERS, prequestedinformation: *mut OPM_REQUESTED_INFORMATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOPMVideoOutput", "GetInformation", 5usize, |this| ((**(this as *const *const IOPMVideoOutput_Vtbl)).GetInformation)(this, pparameters, prequestedinformation), |result| Some(*result))
+        }
+        unsafe extern "system" fn COPPCompatibleGetInformation(this: *mut core::ffi::c_void, pparameters: *const OPM_COPP_COMPATIBLE_GET_INFO_PARAMETERS, prequestedinformation: *mut OPM_REQUESTED_INFORMATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOPMVideoOutput", "COPPCompatibleGetInformation", 6usize, |this| ((**(this as *const *const IOPMVideoOutput_Vtbl)).COPPCompatibleGetInformation)(this, pparameters, prequestedinformation), |result| Some(*result))
+        }
+        unsafe extern "system" fn Configure(this: *mut core::ffi::c_void, pparameters: *const OPM_CONFIGURE_PARAMETERS, uladditionalparameterssize: u32, pbadditionalparameters: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOPMVideoOutput", "Configure", 7usize, |this| ((**(this as *const *const IOPMVideoOutput_Vtbl)).Configure)(this, pparameters, uladditionalparameterssize, pbadditionalparameters), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartInitialization,
+            FinishInitialization,
+            GetInformation,
+            COPPCompatibleGetInformation,
+            Configure,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOPMVideoOutput as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayToControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Connect(this: *mut core::ffi::c_void, pfactory: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToControl", "Connect", 3usize, |this| ((**(this as *const *const IPlayToControl_Vtbl)).Connect)(this, pfactory), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToControl", "Disconnect", 4usize, |this| ((**(this as *const *const IPlayToControl_Vtbl)).Disconnect)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Connect, Disconnect }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayToControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayToControlWithCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCapabilities(this: *mut core::ffi::c_void, pcapabilities: *mut PLAYTO_SOURCE_CREATEFLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToControlWithCapabilities", "GetCapabilities", 5usize, |this| ((**(this as *const *const IPlayToControlWithCapabilities_Vtbl)).GetCapabilities)(this, pcapabilities), |result| Some(*result))
+        }
+        Self { base__: <IPlayToControl_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCapabilities }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayToControlWithCapabilities as windows_core::Interface>::IID || <IPlayToControl_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayToSourceClassFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, dwflags: u32, pcontrol: *mut core::ffi::c_void, ppsource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToSourceClassFactory", "CreateInstance", 3usize, |this| ((**(this as *const *const IPlayToSourceClassFactory_Vtbl)).CreateInstance)(this, dwflags, pcontrol, ppsource), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayToSourceClassFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IToc_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDescriptor(this: *mut core::ffi::c_void, pdescriptor: *mut TOC_DESCRIPTOR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToc", "SetDescriptor", 3usize, |this| ((**(this as *const *const IToc_Vtbl)).SetDescriptor)(this, pdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescriptor(this: *mut core::ffi::c_void, pdescriptor: *mut TOC_DESCRIPTOR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToc", "GetDescriptor", 4usize, |this| ((**(this as *const *const IToc_Vtbl)).GetDescriptor)(this, pdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, pwszdescription: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToc", "SetDescription", 5usize, |this| ((**(this as *const *const IToc_Vtbl)).SetDescription)(this, pwszdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescription(this: *mut core::ffi::c_void, pwdescriptionsize: *mut u16, pwszdescription: windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToc", "GetDescription", 6usize, |this| ((**(this as *const *const IToc_Vtbl)).GetDescription)(this, pwdescriptionsize, pwszdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContext(this: *mut core::ffi::c_void, dwcontextsize: u32, pbtcontext: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToc", "SetContext", 7usize, |this| ((**(this as *const *const IToc_Vtbl)).SetContext)(this, dwcontextsize, pbtcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContext(this: *mut core::ffi::c_void, pdwcontextsize: *mut u32, pbtcontext: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToc", "GetContext", 8usize, |this| ((**(this as *const *const IToc_Vtbl)).GetContext)(this, pdwcontextsize, pbtcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEntryListCount(this: *mut core::ffi::c_void, pwcount: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToc", "GetEntryListCount", 9usize, |this| ((**(this as *const *const IToc_Vtbl)).GetEntryListCount)(this, pwcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEntryListByIndex(this: *mut core::ffi::c_void, wentrylistindex: u16, ppentrylist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToc", "GetEntryListByIndex", 10usize, |this| ((**(this as *const *const IToc_Vtbl)).GetEntryListByIndex)(this, wentrylistindex, ppentrylist), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddEntryList(this: *mut core::ffi::c_void, pentrylist: *mut core::ffi::c_void, pwentrylistindex: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToc", "AddEntryList", 11usize, |this| ((**(this as *const *const IToc_Vtbl)).AddEntryList)(this, pentrylist, pwentrylistindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddEntryListByIndex(this: *mut core::ffi::c_void, wentrylistindex: u16, pentrylist: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToc", "AddEntryListByIndex", 12usize, |this| ((**(this as *const *const IToc_Vtbl)).AddEntryListByIndex)(this, wentrylistindex, pentrylist), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEntryListByIndex(this: *mut core::ffi::c_void, wentrylistindex: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToc", "RemoveEntryListByIndex", 13usize, |this| ((**(this as *const *const IToc_Vtbl)).RemoveEntryListByIndex)(this, wentrylistindex), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetDescriptor,
+            GetDescriptor,
+            SetDescription,
+            GetDescription,
+            SetContext,
+            GetContext,
+            GetEntryListCount,
+            GetEntryListByIndex,
+            AddEntryList,
+            AddEntryListByIndex,
+            RemoveEntryListByIndex,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToc as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITocCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEntryCount(this: *mut core::ffi::c_void, pdwentrycount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITocCollection", "GetEntryCount", 3usize, |this| ((**(this as *const *const ITocCollection_Vtbl)).GetEntryCount)(this, pdwentrycount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEntryByIndex(this: *mut core::ffi::c_void, dwentryindex: u32, pptoc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITocCollection", "GetEntryByIndex", 4usize, |this| ((**(this as *const *const ITocCollection_Vtbl)).GetEntryByIndex)(this, dwentryindex, pptoc), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddEntry(this: *mut core::ffi::c_void, ptoc: *mut core::ffi::c_void, pdwentryindex: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITocCollection", "AddEntry", 5usize, |this| ((**(this as *const *const ITocCollection_Vtbl)).AddEntry)(this, ptoc, pdwentryindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddEntryByIndex(this: *mut core::ffi::c_void, dwentryindex: u32, ptoc: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITocCollection", "AddEntryByIndex", 6usize, |this| ((**(this as *const *const ITocCollection_Vtbl)).AddEntryByIndex)(this, dwentryindex, ptoc), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEntryByIndex(this: *mut core::ffi::c_void, dwentryindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITocCollection", "RemoveEntryByIndex", 7usize, |this| ((**(this as *const *const ITocCollection_Vtbl)).RemoveEntryByIndex)(this, dwentryindex), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetEntryCount,
+            GetEntryByIndex,
+            AddEntry,
+            AddEntryByIndex,
+            RemoveEntryByIndex,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITocCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITocEntry_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, pwsztitle: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITocEntry", "SetTitle", 3usize, |this| ((**(this as *const *const ITocEntry_Vtbl)).SetTitle)(this, pwsztitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTitle(this: *mut core::ffi::c_void, pwtitlesize: *mut u16, pwsztitle: windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITocEntry", "GetTitle", 4usize, |this| ((**(this as *const *const ITocEntry_Vtbl)).GetTitle)(this, pwtitlesize, pwsztitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescriptor(this: *mut core::ffi::c_void, pdescriptor: *mut TOC_ENTRY_DESCRIPTOR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITocEntry", "SetDescriptor", 5usize, |this| ((**(this as *const *const ITocEntry_Vtbl)).SetDescriptor)(this, pdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescriptor(this: *mut core::ffi::c_void, pdescriptor: *mut TOC_ENTRY_DESCRIPTOR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITocEntry", "GetDescriptor", 6usize, |this| ((**(this as *const *const ITocEntry_Vtbl)).GetDescriptor)(this, pdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubEntries(this: *mut core::ffi::c_void, dwnumsubentries: u32, pwsubentryindices: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITocEntry", "SetSubEntries", 7usize, |this| ((**(this as *const *const ITocEntry_Vtbl)).SetSubEntries)(this, dwnumsubentries, pwsubentryindices), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubEntries(this: *mut core::ffi::c_void, pdwnumsubentries: *mut u32, pwsubentryindices: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITocEntry", "GetSubEntries", 8usize, |this| ((**(this as *const *const ITocEntry_Vtbl)).GetSubEntries)(this, pdwnumsubentries, pwsubentryindices), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescriptionData(this: *mut core::ffi::c_void, dwdescriptiondatasize: u32, pbtdescriptiondata: *mut u8, pguidtype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITocEntry", "SetDescriptionData", 9usize, |this| ((**(this as *const *const ITocEntry_Vtbl)).SetDescriptionData)(this, dwdescriptiondatasize, pbtdescriptiondata, pguidtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescriptionData(this: *mut core::ffi::c_void, pdwdescriptiondatasize: *mut u32, pbtdescriptiondata: *mut u8, pguidtype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITocEntry", "GetDescriptionData", 10usize, |this| ((**(this as *const *const ITocEntry_Vtbl)).GetDescriptionData)(this, pdwdescriptiondatasize, pbtdescriptiondata, pguidtype), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetTitle,
+            GetTitle,
+            SetDescriptor,
+            GetDescriptor,
+            SetSubEntries,
+            GetSubEntries,
+            SetDescriptionData,
+            GetDescriptionData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITocEntry as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITocEntryList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEntryCount(this: *mut core::ffi::c_void, pdwentrycount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITocEntryList", "GetEntryCount", 3usize, |this| ((**(this as *const *const ITocEntryList_Vtbl)).GetEntryCount)(this, pdwentrycount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEntryByIndex(this: *mut core::ffi::c_void, dwentryindex: u32, ppentry: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITocEntryList", "GetEntryByIndex", 4usize, |this| ((**(this as *const *const ITocEntryList_Vtbl)).GetEntryByIndex)(this, dwentryindex, ppentry), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddEntry(this: *mut core::ffi::c_void, pentry: *mut core::ffi::c_void, pdwentryindex: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITocEntryList", "AddEntry", 5usize, |this| ((**(this as *const *const ITocEntryList_Vtbl)).AddEntry)(this, pentry, pdwentryindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddEntryByIndex(this: *mut core::ffi::c_void, dwentryindex: u32, pentry: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITocEntryList", "AddEntryByIndex", 6usize, |this| ((**(this as *const *const ITocEntryList_Vtbl)).AddEntryByIndex)(this, dwentryindex, pentry), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEntryByIndex(this: *mut core::ffi::c_void, dwentryindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITocEntryList", "RemoveEntryByIndex", 7usize, |this| ((**(this as *const *const ITocEntryList_Vtbl)).RemoveEntryByIndex)(this, dwentryindex), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetEntryCount,
+            GetEntryByIndex,
+            AddEntry,
+            AddEntryByIndex,
+            RemoveEntryByIndex,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITocEntryList as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITocParser_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Init(this: *mut core::ffi::c_void, pwszfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITocParser", "Init", 3usize, |this| ((**(this as *const *const ITocParser_Vtbl)).Init)(this, pwszfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTocCount(this: *mut core::ffi::c_void, enumtocpostype: TOC_POS_TYPE, pdwtoccount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITocParser", "GetTocCount", 4usize, |this| ((**(this as *const *const ITocParser_Vtbl)).GetTocCount)(this, enumtocpostype, pdwtoccount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTocByIndex(this: *mut core::ffi::c_void, enumtocpostype: TOC_POS_TYPE, dwtocindex: u32, pptoc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITocParser", "GetTocByIndex", 5usize, |this| ((**(this as *const *const ITocParser_Vtbl)).GetTocByIndex)(this, enumtocpostype, dwtocindex, pptoc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTocByType(this: *mut core::ffi::c_void, enumtocpostype: TOC_POS_TYPE, guidtoctype: windows_core::GUID, pptocs: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITocParser", "GetTocByType", 6usize, |this| ((**(this as *const *const ITocParser_Vtbl)).GetTocByType)(this, enumtocpostype, guidtoctype, pptocs), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddToc(this: *mut core::ffi::c_void, enumtocpostype: TOC_POS_TYPE, ptoc: *mut core::ffi::c_void, pdwtocindex: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITocParser", "AddToc", 7usize, |this| ((**(this as *const *const ITocParser_Vtbl)).AddToc)(this, enumtocpostype, ptoc, pdwtocindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTocByIndex(this: *mut core::ffi::c_void, enumtocpostype: TOC_POS_TYPE, dwtocindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITocParser", "RemoveTocByIndex", 8usize, |this| ((**(this as *const *const ITocParser_Vtbl)).RemoveTocByIndex)(this, enumtocpostype, dwtocindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTocByType(this: *mut core::ffi::c_void, enumtocpostype: TOC_POS_TYPE, guidtoctype: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITocParser", "RemoveTocByType", 9usize, |this| ((**(this as *const *const ITocParser_Vtbl)).RemoveTocByType)(this, enumtocpostype, guidtoctype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITocParser", "Commit", 10usize, |this| ((**(this as *const *const ITocParser_Vtbl)).Commit)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Init,
+            GetTocCount,
+            GetTocByIndex,
+            GetTocByType,
+            AddToc,
+            RemoveTocByIndex,
+            RemoveTocByType,
+            Commit,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITocParser as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IValidateBinding_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetIdentifier(this: *mut core::ffi::c_void, guidlicensorid: windows_core::GUID, pbephemeron: *const u8, cbephemeron: u32, ppbblobvalidationid: *mut *mut u8, pcbblobsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValidateBinding", "GetIdentifier", 3usize, |this| ((**(this as *const *const IValidateBinding_Vtbl)).GetIdentifier)(this, guidlicensorid, pbephemeron, cbephemeron, ppbblobvalidationid, pcbblobsize), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetIdentifier }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IValidateBinding as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMCodecLeakyBucket_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetBufferSizeBits(this: *mut core::ffi::c_void, ulbuffersize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMCodecLeakyBucket", "SetBufferSizeBits", 3usize, |this| ((**(this as *const *const IWMCodecLeakyBucket_Vtbl)).SetBufferSizeBits)(this, ulbuffersize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBufferSizeBits(this: *mut core::ffi::c_void, pulbuffersize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMCodecLeakyBucket", "GetBufferSizeBits", 4usize, |this| ((**(this as *const *const IWMCodecLeakyBucket_Vtbl)).GetBufferSizeBits)(this, pulbuffersize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBufferFullnessBits(this: *mut core::ffi::c_void, ulbufferfullness: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMCodecLeakyBucket", "SetBufferFullnessBits", 5usize, |this| ((**(this as *const *const IWMCodecLeakyBucket_Vtbl)).SetBufferFullnessBits)(this, ulbufferfullness), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBufferFullnessBits(this: *mut core::ffi::c_void, pulbufferfullness: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMCodecLeakyBucket", "GetBufferFullnessBits", 6usize, |this| ((**(this as *const *const IWMCodecLeakyBucket_Vtbl)).GetBufferFullnessBits)(this, pulbufferfullness), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetBufferSizeBits,
+            GetBufferSizeBits,
+            SetBufferFullnessBits,
+            GetBufferFullnessBits,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMCodecLeakyBucket as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMCodecOutputTimestamp_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNextOutputTime(this: *mut core::ffi::c_void, prttime: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMCodecOutputTimestamp", "GetNextOutputTime", 3usize, |this| ((**(this as *const *const IWMCodecOutputTimestamp_Vtbl)).GetNextOutputTime)(this, prttime), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetNextOutputTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMCodecOutputTimestamp as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_DxMediaObjects")]
+impl windows_core::imp::ProxyVtable for IWMCodecPrivateData_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_DxMediaObjects")]
+        unsafe extern "system" fn SetPartialOutputType(this: *mut core::ffi::c_void, pmt: *mut super::DxMediaObjects::DMO_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMCodecPrivateData", "SetPartialOutputType", 3usize, |this| ((**(this as *const *const IWMCodecPrivateData_Vtbl)).SetPartialOutputType)(this, pmt), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrivateData(this: *mut core::ffi::c_void, pbdata: *mut u8, pcbdata: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMCodecPrivateData", "GetPrivateData", 4usize, |this| ((**(this as *const *const IWMCodecPrivateData_Vtbl)).GetPrivateData)(this, pbdata, pcbdata), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_DxMediaObjects")]
+            SetPartialOutputType,
+            #[cfg(not(feature = "Win32_Media_DxMediaObjects"))]
+            SetPartialOutputType: 0,
+            GetPrivateData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMCodecPrivateData as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_DxMediaObjects")]
+impl windows_core::imp::ProxyVtable for IWMCodecProps_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_DxMediaObjects")]
+        unsafe extern "system" fn GetFormatProp(this: *mut core::ffi::c_void, pmt: *mut super::DxMediaObjects::DMO_MEDIA_TYPE, pszname: windows_core::PCWSTR, ptype: *mut WMT_PROP_DATATYPE, pvalue: *mut u8, pdwsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMCodecProps", "GetFormatProp", 3usize, |this| ((**(this as *const *const IWMCodecProps_Vtbl)).GetFormatProp)(this, pmt, pszname, ptype, pvalue, pdwsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCodecProp(this: *mut core::ffi::c_void, dwformat: u32, pszname: windows_core::PCWSTR, ptype: *mut WMT_PROP_DATATYPE, pvalue: *mut u8, pdwsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMCodecProps", "GetCodecProp", 4usize, |this| ((**(this as *const *const IWMCodecProps_Vtbl)).GetCodecProp)(this, dwformat, pszname, ptype, pvalue, pdwsize), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_DxMediaObjects")]
+            GetFormatProp,
+            #[cfg(not(feature = "Win32_Media_DxMediaObjects"))]
+            GetFormatProp: 0,
+            GetCodecProp,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMCodecProps as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_DxMediaObjects")]
+impl windows_core::imp::ProxyVtable for IWMCodecStrings_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_DxMediaObjects")]
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, pmt: *mut super::DxMediaObjects::DMO_MEDIA_TYPE, cchlength: u32, szname: windows_core::PWSTR, pcchlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMCodecStrings", "GetName", 3usize, |this| ((**(this as *const *const IWMCodecStrings_Vtbl)).GetName)(this, pmt, cchlength, szname, pcchlength), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_DxMediaObjects")]
+        unsafe extern "system" fn GetDescription(this: *mut core::ffi::c_void, pmt: *mut super::DxMediaObjects::DMO_MEDIA_TYPE, cchlength: u32, szdescription: windows_core::PWSTR, pcchlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMCodecStrings", "GetDescription", 4usize, |this| ((**(this as *const *const IWMCodecStrings_Vtbl)).GetDescription)(this, pmt, cchlength, szdescription, pcchlength), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_DxMediaObjects")]
+            GetName,
+            #[cfg(not(feature = "Win32_Media_DxMediaObjects"))]
+            GetName: 0,
+            #[cfg(feature = "Win32_Media_DxMediaObjects")]
+            GetDescription,
+            #[cfg(not(feature = "Win32_Media_DxMediaObjects"))]
+            GetDescription: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMCodecStrings as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMColorConvProps_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMode(this: *mut core::ffi::c_void, lmode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMColorConvProps", "SetMode", 3usize, |this| ((**(this as *const *const IWMColorConvProps_Vtbl)).SetMode)(this, lmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFullCroppingParam(this: *mut core::ffi::c_void, lsrccropleft: i32, lsrccroptop: i32, ldstcropleft: i32, ldstcroptop: i32, lcropwidth: i32, lcropheight: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMColorConvProps", "SetFullCroppingParam", 4usize, |this| ((**(this as *const *const IWMColorConvProps_Vtbl)).SetFullCroppingParam)(this, lsrccropleft, lsrccroptop, ldstcropleft, ldstcroptop, lcropwidth, lcropheight), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetMode, SetFullCroppingParam }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMColorConvProps as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMColorLegalizerProps_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetColorLegalizerQuality(this: *mut core::ffi::c_void, lquality: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMColorLegalizerProps", "SetColorLegalizerQuality", 3usize, |this| ((**(this as *const *const IWMColorLegalizerProps_Vtbl)).SetColorLegalizerQuality)(this, lquality), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetColorLegalizerQuality }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMColorLegalizerProps as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMFrameInterpProps_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetFrameRateIn(this: *mut core::ffi::c_void, lframerate: i32, lscale: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMFrameInterpProps", "SetFrameRateIn", 3usize, |this| ((**(this as *const *const IWMFrameInterpProps_Vtbl)).SetFrameRateIn)(this, lframerate, lscale), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFrameRateOut(this: *mut core::ffi::c_void, lframerate: i32, lscale: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMFrameInterpProps", "SetFrameRateOut", 4usize, |this| ((**(this as *const *const IWMFrameInterpProps_Vtbl)).SetFrameRateOut)(this, lframerate, lscale), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFrameInterpEnabled(this: *mut core::ffi::c_void, bfienabled: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMFrameInterpProps", "SetFrameInterpEnabled", 5usize, |this| ((**(this as *const *const IWMFrameInterpProps_Vtbl)).SetFrameInterpEnabled)(this, bfienabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetComplexityLevel(this: *mut core::ffi::c_void, icomplexity: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMFrameInterpProps", "SetComplexityLevel", 6usize, |this| ((**(this as *const *const IWMFrameInterpProps_Vtbl)).SetComplexityLevel)(this, icomplexity), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetFrameRateIn,
+            SetFrameRateOut,
+            SetFrameInterpEnabled,
+            SetComplexityLevel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMFrameInterpProps as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMInterlaceProps_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetProcessType(this: *mut core::ffi::c_void, iprocesstype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMInterlaceProps", "SetProcessType", 3usize, |this| ((**(this as *const *const IWMInterlaceProps_Vtbl)).SetProcessType)(this, iprocesstype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInitInverseTeleCinePattern(this: *mut core::ffi::c_void, iinitpattern: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMInterlaceProps", "SetInitInverseTeleCinePattern", 4usize, |this| ((**(this as *const *const IWMInterlaceProps_Vtbl)).SetInitInverseTeleCinePattern)(this, iinitpattern), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLastFrame(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMInterlaceProps", "SetLastFrame", 5usize, |this| ((**(this as *const *const IWMInterlaceProps_Vtbl)).SetLastFrame)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetProcessType, SetInitInverseTeleCinePattern, SetLastFrame }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMInterlaceProps as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMResamplerProps_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetHalfFilterLength(this: *mut core::ffi::c_void, lhalffilterlen: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMResamplerProps", "SetHalfFilterLength", 3usize, |this| ((**(this as *const *const IWMResamplerProps_Vtbl)).SetHalfFilterLength)(this, lhalffilterlen), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUserChannelMtx(this: *mut core::ffi::c_void, userchannelmtx: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMResamplerProps", "SetUserChannelMtx", 4usize, |this| ((**(this as *const *const IWMResamplerProps_Vtbl)).SetUserChannelMtx)(this, userchannelmtx), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetHalfFilterLength, SetUserChannelMtx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMResamplerProps as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMResizerProps_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetResizerQuality(this: *mut core::ffi::c_void, lquality: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMResizerProps", "SetResizerQuality", 3usize, |this| ((**(this as *const *const IWMResizerProps_Vtbl)).SetResizerQuality)(this, lquality), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInterlaceMode(this: *mut core::ffi::c_void, lmode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMResizerProps", "SetInterlaceMode", 4usize, |this| ((**(this as *const *const IWMResizerProps_Vtbl)).SetInterlaceMode)(this, lmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClipRegion(this: *mut core::ffi::c_void, lcliporixsrc: i32, lcliporiysrc: i32, lclipwidthsrc: i32, lclipheightsrc: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMResizerProps", "SetClipRegion", 5usize, |this| ((**(this as *const *const IWMResizerProps_Vtbl)).SetClipRegion)(this, lcliporixsrc, lcliporiysrc, lclipwidthsrc, lclipheightsrc), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFullCropRegion(this: *mut core::ffi::c_void, lcliporixsrc: i32, lcliporiysrc: i32, lclipwidthsrc: i32, lclipheightsrc: i32, lcliporixdst: i32, lcliporiydst: i32, lclipwidthdst: i32, lclipheightdst: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMResizerProps", "SetFullCropRegion", 6usize, |this| ((**(this as *const *const IWMResizerProps_Vtbl)).SetFullCropRegion)(this, lcliporixsrc, lcliporiysrc, lclipwidthsrc, lclipheightsrc, lcliporixdst, lcliporiydst, lclipwidthdst, lclipheightdst), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFullCropRegion(this: *mut core::ffi::c_void, lcliporixsrc: *mut i32, lcliporiysrc: *mut i32, lclipwidthsrc: *mut i32, lclipheightsrc: *mut i32, lcliporixdst: *mut i32, lcliporiydst: *mut i32, lclipwidthdst: *mut i32, lclipheightdst: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMResizerProps", "GetFullCropRegion", 7usize, |this| ((**(this as *const *const IWMResizerProps_Vtbl)).GetFullCropRegion)(this, lcliporixsrc, lcliporiysrc, lclipwidthsrc, lclipheightsrc, lcliporixdst, lcliporiydst, lclipwidthdst, lclipheightdst), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetResizerQuality,
+            SetInterlaceMode,
+            SetClipRegion,
+            SetFullCropRegion,
+            GetFullCropRegion,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMResizerProps as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMSampleExtensionSupport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetUseSampleExtensions(this: *mut core::ffi::c_void, fuseextensions: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSampleExtensionSupport", "SetUseSampleExtensions", 3usize, |this| ((**(this as *const *const IWMSampleExtensionSupport_Vtbl)).SetUseSampleExtensions)(this, fuseextensions), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetUseSampleExtensions }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMSampleExtensionSupport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMValidate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetIdentifier(this: *mut core::ffi::c_void, guidvalidationid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMValidate", "SetIdentifier", 3usize, |this| ((**(this as *const *const IWMValidate_Vtbl)).SetIdentifier)(this, guidvalidationid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetIdentifier }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMValidate as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMVideoDecoderHurryup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetHurryup(this: *mut core::ffi::c_void, lhurryup: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMVideoDecoderHurryup", "SetHurryup", 3usize, |this| ((**(this as *const *const IWMVideoDecoderHurryup_Vtbl)).SetHurryup)(this, lhurryup), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHurryup(this: *mut core::ffi::c_void, plhurryup: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMVideoDecoderHurryup", "GetHurryup", 4usize, |this| ((**(this as *const *const IWMVideoDecoderHurryup_Vtbl)).GetHurryup)(this, plhurryup), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetHurryup, GetHurryup }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMVideoDecoderHurryup as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_DxMediaObjects")]
+impl windows_core::imp::ProxyVtable for IWMVideoDecoderReconBuffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetReconstructedVideoFrameSize(this: *mut core::ffi::c_void, pdwsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMVideoDecoderReconBuffer", "GetReconstructedVideoFrameSize", 3usize, |this| ((**(this as *const *const IWMVideoDecoderReconBuffer_Vtbl)).GetReconstructedVideoFrameSize)(this, pdwsize), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_DxMediaObjects")]
+        unsafe extern "system" fn GetReconstructedVideoFrame(this: *mut core::ffi::c_void, pbuf: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMVideoDecoderReconBuffer", "GetReconstructedVideoFrame", 4usize, |this| ((**(this as *const *const IWMVideoDecoderReconBuffer_Vtbl)).GetReconstructedVideoFrame)(this, pbuf), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_DxMediaObjects")]
+        unsafe extern "system" fn SetReconstructedVideoFrame(this: *mut core::ffi::c_void, pbuf: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMVideoDecoderReconBuffer", "SetReconstructedVideoFrame", 5usize, |this| ((**(this as *const *const IWMVideoDecoderReconBuffer_Vtbl)).SetReconstructedVideoFrame)(this, pbuf), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetReconstructedVideoFrameSize,
+            #[cfg(feature = "Win32_Media_DxMediaObjects")]
+            GetReconstructedVideoFrame,
+            #[cfg(not(feature = "Win32_Media_DxMediaObjects"))]
+            GetReconstructedVideoFrame: 0,
+            #[cfg(feature = "Win32_Media_DxMediaObjects")]
+            SetReconstructedVideoFrame,
+            #[cfg(not(feature = "Win32_Media_DxMediaObjects"))]
+            SetReconstructedVideoFrame: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMVideoDecoderReconBuffer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMVideoForceKeyFrame_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetKeyFrame(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMVideoForceKeyFrame", "SetKeyFrame", 3usize, |this| ((**(this as *const *const IWMVideoForceKeyFrame_Vtbl)).SetKeyFrame)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetKeyFrame }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMVideoForceKeyFrame as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for MFASYNCRESULT_Vtbl {
+    const PROXY: Self = { Self { base__: <IMFAsyncResult_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<MFASYNCRESULT as windows_core::Interface>::IID || <IMFAsyncResult_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
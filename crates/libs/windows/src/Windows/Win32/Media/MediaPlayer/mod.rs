@@ -7634,6 +7634,7 @@ pub struct FEEDS_BACKGROUNDSYNC_ACTION(pub i32);
 impl windows_core::TypeKind for FEEDS_BACKGROUNDSYNC_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FEEDS_BACKGROUNDSYNC_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FEEDS_BACKGROUNDSYNC_ACTION").field(&self.0).finish()
@@ -7645,6 +7646,7 @@ pub struct FEEDS_BACKGROUNDSYNC_STATUS(pub i32);
 impl windows_core::TypeKind for FEEDS_BACKGROUNDSYNC_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FEEDS_BACKGROUNDSYNC_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FEEDS_BACKGROUNDSYNC_STATUS").field(&self.0).finish()
@@ -7656,6 +7658,7 @@ pub struct FEEDS_DOWNLOAD_ERROR(pub i32);
 impl windows_core::TypeKind for FEEDS_DOWNLOAD_ERROR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FEEDS_DOWNLOAD_ERROR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FEEDS_DOWNLOAD_ERROR").field(&self.0).finish()
@@ -7667,6 +7670,7 @@ pub struct FEEDS_DOWNLOAD_STATUS(pub i32);
 impl windows_core::TypeKind for FEEDS_DOWNLOAD_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FEEDS_DOWNLOAD_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FEEDS_DOWNLOAD_STATUS").field(&self.0).finish()
@@ -7678,6 +7682,7 @@ pub struct FEEDS_ERROR_CODE(pub i32);
 impl windows_core::TypeKind for FEEDS_ERROR_CODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FEEDS_ERROR_CODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FEEDS_ERROR_CODE").field(&self.0).finish()
@@ -7689,6 +7694,7 @@ pub struct FEEDS_EVENTS_ITEM_COUNT_FLAGS(pub i32);
 impl windows_core::TypeKind for FEEDS_EVENTS_ITEM_COUNT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FEEDS_EVENTS_ITEM_COUNT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FEEDS_EVENTS_ITEM_COUNT_FLAGS").field(&self.0).finish()
@@ -7700,6 +7706,7 @@ pub struct FEEDS_EVENTS_MASK(pub i32);
 impl windows_core::TypeKind for FEEDS_EVENTS_MASK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FEEDS_EVENTS_MASK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FEEDS_EVENTS_MASK").field(&self.0).finish()
@@ -7711,6 +7718,7 @@ pub struct FEEDS_EVENTS_SCOPE(pub i32);
 impl windows_core::TypeKind for FEEDS_EVENTS_SCOPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FEEDS_EVENTS_SCOPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FEEDS_EVENTS_SCOPE").field(&self.0).finish()
@@ -7722,6 +7730,7 @@ pub struct FEEDS_SYNC_SETTING(pub i32);
 impl windows_core::TypeKind for FEEDS_SYNC_SETTING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FEEDS_SYNC_SETTING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FEEDS_SYNC_SETTING").field(&self.0).finish()
@@ -7733,6 +7742,7 @@ pub struct FEEDS_XML_FILTER_FLAGS(pub i32);
 impl windows_core::TypeKind for FEEDS_XML_FILTER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FEEDS_XML_FILTER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FEEDS_XML_FILTER_FLAGS").field(&self.0).finish()
@@ -7744,6 +7754,7 @@ pub struct FEEDS_XML_INCLUDE_FLAGS(pub i32);
 impl windows_core::TypeKind for FEEDS_XML_INCLUDE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FEEDS_XML_INCLUDE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FEEDS_XML_INCLUDE_FLAGS").field(&self.0).finish()
@@ -7755,6 +7766,7 @@ pub struct FEEDS_XML_SORT_ORDER(pub i32);
 impl windows_core::TypeKind for FEEDS_XML_SORT_ORDER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FEEDS_XML_SORT_ORDER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FEEDS_XML_SORT_ORDER").field(&self.0).finish()
@@ -7766,6 +7778,7 @@ pub struct FEEDS_XML_SORT_PROPERTY(pub i32);
 impl windows_core::TypeKind for FEEDS_XML_SORT_PROPERTY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FEEDS_XML_SORT_PROPERTY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FEEDS_XML_SORT_PROPERTY").field(&self.0).finish()
@@ -7777,6 +7790,7 @@ pub struct PlayerState(pub i32);
 impl windows_core::TypeKind for PlayerState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PlayerState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PlayerState").field(&self.0).finish()
@@ -7788,6 +7802,7 @@ pub struct WMPAccountType(pub i32);
 impl windows_core::TypeKind for WMPAccountType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMPAccountType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMPAccountType").field(&self.0).finish()
@@ -7799,6 +7814,7 @@ pub struct WMPBurnFormat(pub i32);
 impl windows_core::TypeKind for WMPBurnFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMPBurnFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMPBurnFormat").field(&self.0).finish()
@@ -7810,6 +7826,7 @@ pub struct WMPBurnState(pub i32);
 impl windows_core::TypeKind for WMPBurnState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMPBurnState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMPBurnState").field(&self.0).finish()
@@ -7821,6 +7838,7 @@ pub struct WMPCallbackNotification(pub i32);
 impl windows_core::TypeKind for WMPCallbackNotification {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMPCallbackNotification {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMPCallbackNotification").field(&self.0).finish()
@@ -7832,6 +7850,7 @@ pub struct WMPDeviceStatus(pub i32);
 impl windows_core::TypeKind for WMPDeviceStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMPDeviceStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMPDeviceStatus").field(&self.0).finish()
@@ -7843,6 +7862,7 @@ pub struct WMPFolderScanState(pub i32);
 impl windows_core::TypeKind for WMPFolderScanState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMPFolderScanState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMPFolderScanState").field(&self.0).finish()
@@ -7854,6 +7874,7 @@ pub struct WMPLibraryType(pub i32);
 impl windows_core::TypeKind for WMPLibraryType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMPLibraryType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMPLibraryType").field(&self.0).finish()
@@ -7865,6 +7886,7 @@ pub struct WMPOpenState(pub i32);
 impl windows_core::TypeKind for WMPOpenState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMPOpenState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMPOpenState").field(&self.0).finish()
@@ -7876,6 +7898,7 @@ pub struct WMPPartnerNotification(pub i32);
 impl windows_core::TypeKind for WMPPartnerNotification {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMPPartnerNotification {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMPPartnerNotification").field(&self.0).finish()
@@ -7887,6 +7910,7 @@ pub struct WMPPlayState(pub i32);
 impl windows_core::TypeKind for WMPPlayState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMPPlayState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMPPlayState").field(&self.0).finish()
@@ -7898,6 +7922,7 @@ pub struct WMPPlaylistChangeEventType(pub i32);
 impl windows_core::TypeKind for WMPPlaylistChangeEventType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMPPlaylistChangeEventType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMPPlaylistChangeEventType").field(&self.0).finish()
@@ -7909,6 +7934,7 @@ pub struct WMPPlugin_Caps(pub i32);
 impl windows_core::TypeKind for WMPPlugin_Caps {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMPPlugin_Caps {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMPPlugin_Caps").field(&self.0).finish()
@@ -7920,6 +7946,7 @@ pub struct WMPRipState(pub i32);
 impl windows_core::TypeKind for WMPRipState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMPRipState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMPRipState").field(&self.0).finish()
@@ -7931,6 +7958,7 @@ pub struct WMPServices_StreamState(pub i32);
 impl windows_core::TypeKind for WMPServices_StreamState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMPServices_StreamState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMPServices_StreamState").field(&self.0).finish()
@@ -7942,6 +7970,7 @@ pub struct WMPStreamingType(pub i32);
 impl windows_core::TypeKind for WMPStreamingType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMPStreamingType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMPStreamingType").field(&self.0).finish()
@@ -7953,6 +7982,7 @@ pub struct WMPStringCollectionChangeEventType(pub i32);
 impl windows_core::TypeKind for WMPStringCollectionChangeEventType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMPStringCollectionChangeEventType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMPStringCollectionChangeEventType").field(&self.0).finish()
@@ -7964,6 +7994,7 @@ pub struct WMPSubscriptionDownloadState(pub i32);
 impl windows_core::TypeKind for WMPSubscriptionDownloadState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMPSubscriptionDownloadState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMPSubscriptionDownloadState").field(&self.0).finish()
@@ -7975,6 +8006,7 @@ pub struct WMPSubscriptionServiceEvent(pub i32);
 impl windows_core::TypeKind for WMPSubscriptionServiceEvent {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMPSubscriptionServiceEvent {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMPSubscriptionServiceEvent").field(&self.0).finish()
@@ -7986,6 +8018,7 @@ pub struct WMPSyncState(pub i32);
 impl windows_core::TypeKind for WMPSyncState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMPSyncState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMPSyncState").field(&self.0).finish()
@@ -7997,6 +8030,7 @@ pub struct WMPTaskType(pub i32);
 impl windows_core::TypeKind for WMPTaskType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMPTaskType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMPTaskType").field(&self.0).finish()
@@ -8008,6 +8042,7 @@ pub struct WMPTemplateSize(pub i32);
 impl windows_core::TypeKind for WMPTemplateSize {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMPTemplateSize {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMPTemplateSize").field(&self.0).finish()
@@ -8019,6 +8054,7 @@ pub struct WMPTransactionType(pub i32);
 impl windows_core::TypeKind for WMPTransactionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMPTransactionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMPTransactionType").field(&self.0).finish()
@@ -8028,7 +8064,8 @@ pub const FeedFolderWatcher: windows_core::GUID = windows_core::GUID::from_u128(
 pub const FeedWatcher: windows_core::GUID = windows_core::GUID::from_u128(0x18a6737b_f433_4687_89bc_a1b4dfb9f123);
 pub const FeedsManager: windows_core::GUID = windows_core::GUID::from_u128(0xfaeb54c4_f66f_4806_83a0_805299f5e3ad);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TimedLevel {
     pub frequency: [u8; 2048],
     pub waveform: [u8; 2048],
@@ -8044,7 +8081,8 @@ impl Default for TimedLevel {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WMPContextMenuInfo {
     pub dwID: u32,
     pub bstrMenuText: core::mem::ManuallyDrop<windows_core::BSTR>,
@@ -8100,3 +8138,5 @@ impl Default for WMP_WMDM_METADATA_ROUND_TRIP_PC2DEVICE {
 pub const WindowsMediaPlayer: windows_core::GUID = windows_core::GUID::from_u128(0x6bf52a52_394a_11d3_b153_00c04f79faa6);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,4805 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFeed_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Xml(this: *mut core::ffi::c_void, count: i32, sortproperty: FEEDS_XML_SORT_PROPERTY, sortorder: FEEDS_XML_SORT_ORDER, filterflags: FEEDS_XML_FILTER_FLAGS, includeflags: FEEDS_XML_INCLUDE_FLAGS, xml: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "Xml", 7usize, |this| ((**(this as *const *const IFeed_Vtbl)).Xml)(this, count, sortproperty, sortorder, filterflags, includeflags, xml), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "Name", 8usize, |this| ((**(this as *const *const IFeed_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rename(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "Rename", 9usize, |this| ((**(this as *const *const IFeed_Vtbl)).Rename)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn Url(this: *mut core::ffi::c_void, feedurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "Url", 10usize, |this| ((**(this as *const *const IFeed_Vtbl)).Url)(this, feedurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUrl(this: *mut core::ffi::c_void, feedurl: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "SetUrl", 11usize, |this| ((**(this as *const *const IFeed_Vtbl)).SetUrl)(this, feedurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalId(this: *mut core::ffi::c_void, feedguid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "LocalId", 12usize, |this| ((**(this as *const *const IFeed_Vtbl)).LocalId)(this, feedguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, path: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "Path", 13usize, |this| ((**(this as *const *const IFeed_Vtbl)).Path)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn Move(this: *mut core::ffi::c_void, newparentpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "Move", 14usize, |this| ((**(this as *const *const IFeed_Vtbl)).Move)(this, newparentpath), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Parent(this: *mut core::ffi::c_void, disp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "Parent", 15usize, |this| ((**(this as *const *const IFeed_Vtbl)).Parent)(this, disp), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastWriteTime(this: *mut core::ffi::c_void, lastwrite: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "LastWriteTime", 16usize, |this| ((**(this as *const *const IFeed_Vtbl)).LastWriteTime)(this, lastwrite), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "Delete", 17usize, |this| ((**(this as *const *const IFeed_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Download(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "Download", 18usize, |this| ((**(this as *const *const IFeed_Vtbl)).Download)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AsyncDownload(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "AsyncDownload", 19usize, |this| ((**(this as *const *const IFeed_Vtbl)).AsyncDownload)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelAsyncDownload(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "CancelAsyncDownload", 20usize, |this| ((**(this as *const *const IFeed_Vtbl)).CancelAsyncDownload)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncSetting(this: *mut core::ffi::c_void, syncsetting: *mut FEEDS_SYNC_SETTING) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "SyncSetting", 21usize, |this| ((**(this as *const *const IFeed_Vtbl)).SyncSetting)(this, syncsetting), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSyncSetting(this: *mut core::ffi::c_void, syncsetting: FEEDS_SYNC_SETTING) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "SetSyncSetting", 22usize, |this| ((**(this as *const *const IFeed_Vtbl)).SetSyncSetting)(this, syncsetting), |result| Some(*result))
+        }
+        unsafe extern "system" fn Interval(this: *mut core::ffi::c_void, minutes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "Interval", 23usize, |this| ((**(this as *const *const IFeed_Vtbl)).Interval)(this, minutes), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInterval(this: *mut core::ffi::c_void, minutes: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "SetInterval", 24usize, |this| ((**(this as *const *const IFeed_Vtbl)).SetInterval)(this, minutes), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastDownloadTime(this: *mut core::ffi::c_void, lastdownload: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "LastDownloadTime", 25usize, |this| ((**(this as *const *const IFeed_Vtbl)).LastDownloadTime)(this, lastdownload), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalEnclosurePath(this: *mut core::ffi::c_void, path: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "LocalEnclosurePath", 26usize, |this| ((**(this as *const *const IFeed_Vtbl)).LocalEnclosurePath)(this, path), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Items(this: *mut core::ffi::c_void, disp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "Items", 27usize, |this| ((**(this as *const *const IFeed_Vtbl)).Items)(this, disp), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetItem(this: *mut core::ffi::c_void, itemid: i32, disp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "GetItem", 28usize, |this| ((**(this as *const *const IFeed_Vtbl)).GetItem)(this, itemid, disp), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, title: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "Title", 29usize, |this| ((**(this as *const *const IFeed_Vtbl)).Title)(this, title), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, description: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "Description", 30usize, |this| ((**(this as *const *const IFeed_Vtbl)).Description)(this, description), |result| Some(*result))
+        }
+        unsafe extern "system" fn Link(this: *mut core::ffi::c_void, homepage: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "Link", 31usize, |this| ((**(this as *const *const IFeed_Vtbl)).Link)(this, homepage), |result| Some(*result))
+        }
+        unsafe extern "system" fn Image(this: *mut core::ffi::c_void, imageurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "Image", 32usize, |this| ((**(this as *const *const IFeed_Vtbl)).Image)(this, imageurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastBuildDate(this: *mut core::ffi::c_void, lastbuilddate: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "LastBuildDate", 33usize, |this| ((**(this as *const *const IFeed_Vtbl)).LastBuildDate)(this, lastbuilddate), |result| Some(*result))
+        }
+        unsafe extern "system" fn PubDate(this: *mut core::ffi::c_void, lastpopulatedate: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "PubDate", 34usize, |this| ((**(this as *const *const IFeed_Vtbl)).PubDate)(this, lastpopulatedate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ttl(this: *mut core::ffi::c_void, ttl: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "Ttl", 35usize, |this| ((**(this as *const *const IFeed_Vtbl)).Ttl)(this, ttl), |result| Some(*result))
+        }
+        unsafe extern "system" fn Language(this: *mut core::ffi::c_void, language: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "Language", 36usize, |this| ((**(this as *const *const IFeed_Vtbl)).Language)(this, language), |result| Some(*result))
+        }
+        unsafe extern "system" fn Copyright(this: *mut core::ffi::c_void, copyright: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "Copyright", 37usize, |this| ((**(this as *const *const IFeed_Vtbl)).Copyright)(this, copyright), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxItemCount(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "MaxItemCount", 38usize, |this| ((**(this as *const *const IFeed_Vtbl)).MaxItemCount)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxItemCount(this: *mut core::ffi::c_void, count: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "SetMaxItemCount", 39usize, |this| ((**(this as *const *const IFeed_Vtbl)).SetMaxItemCount)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadEnclosuresAutomatically(this: *mut core::ffi::c_void, downloadenclosuresautomatically: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "DownloadEnclosuresAutomatically", 40usize, |this| ((**(this as *const *const IFeed_Vtbl)).DownloadEnclosuresAutomatically)(this, downloadenclosuresautomatically), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDownloadEnclosuresAutomatically(this: *mut core::ffi::c_void, downloadenclosuresautomatically: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "SetDownloadEnclosuresAutomatically", 41usize, |this| ((**(this as *const *const IFeed_Vtbl)).SetDownloadEnclosuresAutomatically)(this, downloadenclosuresautomatically), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadStatus(this: *mut core::ffi::c_void, status: *mut FEEDS_DOWNLOAD_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "DownloadStatus", 42usize, |this| ((**(this as *const *const IFeed_Vtbl)).DownloadStatus)(this, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastDownloadError(this: *mut core::ffi::c_void, error: *mut FEEDS_DOWNLOAD_ERROR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "LastDownloadError", 43usize, |this| ((**(this as *const *const IFeed_Vtbl)).LastDownloadError)(this, error), |result| Some(*result))
+        }
+        unsafe extern "system" fn Merge(this: *mut core::ffi::c_void, feedxml: core::mem::MaybeUninit<windows_core::BSTR>, feedurl: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "Merge", 44usize, |this| ((**(this as *const *const IFeed_Vtbl)).Merge)(this, feedxml, feedurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadUrl(this: *mut core::ffi::c_void, feedurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "DownloadUrl", 45usize, |this| ((**(this as *const *const IFeed_Vtbl)).DownloadUrl)(this, feedurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsList(this: *mut core::ffi::c_void, islist: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "IsList", 46usize, |this| ((**(this as *const *const IFeed_Vtbl)).IsList)(this, islist), |result| Some(*result))
+        }
+        unsafe extern "system" fn MarkAllItemsRead(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "MarkAllItemsRead", 47usize, |this| ((**(this as *const *const IFeed_Vtbl)).MarkAllItemsRead)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetWatcher(this: *mut core::ffi::c_void, scope: FEEDS_EVENTS_SCOPE, mask: FEEDS_EVENTS_MASK, disp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "GetWatcher", 48usize, |this| ((**(this as *const *const IFeed_Vtbl)).GetWatcher)(this, scope, mask, disp), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnreadItemCount(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "UnreadItemCount", 49usize, |this| ((**(this as *const *const IFeed_Vtbl)).UnreadItemCount)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItemCount(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed", "ItemCount", 50usize, |this| ((**(this as *const *const IFeed_Vtbl)).ItemCount)(this, count), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Xml,
+            Name,
+            Rename,
+            Url,
+            SetUrl,
+            LocalId,
+            Path,
+            Move,
+            #[cfg(feature = "Win32_System_Com")]
+            Parent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Parent: 0,
+            LastWriteTime,
+            Delete,
+            Download,
+            AsyncDownload,
+            CancelAsyncDownload,
+            SyncSetting,
+            SetSyncSetting,
+            Interval,
+            SetInterval,
+            LastDownloadTime,
+            LocalEnclosurePath,
+            #[cfg(feature = "Win32_System_Com")]
+            Items,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Items: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetItem: 0,
+            Title,
+            Description,
+            Link,
+            Image,
+            LastBuildDate,
+            PubDate,
+            Ttl,
+            Language,
+            Copyright,
+            MaxItemCount,
+            SetMaxItemCount,
+            DownloadEnclosuresAutomatically,
+            SetDownloadEnclosuresAutomatically,
+            DownloadStatus,
+            LastDownloadError,
+            Merge,
+            DownloadUrl,
+            IsList,
+            MarkAllItemsRead,
+            #[cfg(feature = "Win32_System_Com")]
+            GetWatcher,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetWatcher: 0,
+            UnreadItemCount,
+            ItemCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFeed as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFeed2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetItemByEffectiveId(this: *mut core::ffi::c_void, itemeffectiveid: i32, disp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed2", "GetItemByEffectiveId", 51usize, |this| ((**(this as *const *const IFeed2_Vtbl)).GetItemByEffectiveId)(this, itemeffectiveid, disp), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastItemDownloadTime(this: *mut core::ffi::c_void, lastitemdownloadtime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed2", "LastItemDownloadTime", 52usize, |this| ((**(this as *const *const IFeed2_Vtbl)).LastItemDownloadTime)(this, lastitemdownloadtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn Username(this: *mut core::ffi::c_void, username: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed2", "Username", 53usize, |this| ((**(this as *const *const IFeed2_Vtbl)).Username)(this, username), |result| Some(*result))
+        }
+        unsafe extern "system" fn Password(this: *mut core::ffi::c_void, password: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed2", "Password", 54usize, |this| ((**(this as *const *const IFeed2_Vtbl)).Password)(this, password), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCredentials(this: *mut core::ffi::c_void, username: core::mem::MaybeUninit<windows_core::BSTR>, password: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed2", "SetCredentials", 55usize, |this| ((**(this as *const *const IFeed2_Vtbl)).SetCredentials)(this, username, password), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearCredentials(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeed2", "ClearCredentials", 56usize, |this| ((**(this as *const *const IFeed2_Vtbl)).ClearCredentials)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IFeed_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetItemByEffectiveId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetItemByEffectiveId: 0,
+            LastItemDownloadTime,
+            Username,
+            Password,
+            SetCredentials,
+            ClearCredentials,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFeed2 as windows_core::Interface>::IID || <IFeed_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFeedEnclosure_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Url(this: *mut core::ffi::c_void, enclosureurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedEnclosure", "Url", 7usize, |this| ((**(this as *const *const IFeedEnclosure_Vtbl)).Url)(this, enclosureurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, mimetype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedEnclosure", "Type", 8usize, |this| ((**(this as *const *const IFeedEnclosure_Vtbl)).Type)(this, mimetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, length: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedEnclosure", "Length", 9usize, |this| ((**(this as *const *const IFeedEnclosure_Vtbl)).Length)(this, length), |result| Some(*result))
+        }
+        unsafe extern "system" fn AsyncDownload(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedEnclosure", "AsyncDownload", 10usize, |this| ((**(this as *const *const IFeedEnclosure_Vtbl)).AsyncDownload)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelAsyncDownload(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedEnclosure", "CancelAsyncDownload", 11usize, |this| ((**(this as *const *const IFeedEnclosure_Vtbl)).CancelAsyncDownload)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadStatus(this: *mut core::ffi::c_void, status: *mut FEEDS_DOWNLOAD_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedEnclosure", "DownloadStatus", 12usize, |this| ((**(this as *const *const IFeedEnclosure_Vtbl)).DownloadStatus)(this, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastDownloadError(this: *mut core::ffi::c_void, error: *mut FEEDS_DOWNLOAD_ERROR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedEnclosure", "LastDownloadError", 13usize, |this| ((**(this as *const *const IFeedEnclosure_Vtbl)).LastDownloadError)(this, error), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalPath(this: *mut core::ffi::c_void, localpath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedEnclosure", "LocalPath", 14usize, |this| ((**(this as *const *const IFeedEnclosure_Vtbl)).LocalPath)(this, localpath), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Parent(this: *mut core::ffi::c_void, disp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedEnclosure", "Parent", 15usize, |this| ((**(this as *const *const IFeedEnclosure_Vtbl)).Parent)(this, disp), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadUrl(this: *mut core::ffi::c_void, enclosureurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedEnclosure", "DownloadUrl", 16usize, |this| ((**(this as *const *const IFeedEnclosure_Vtbl)).DownloadUrl)(this, enclosureurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadMimeType(this: *mut core::ffi::c_void, mimetype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedEnclosure", "DownloadMimeType", 17usize, |this| ((**(this as *const *const IFeedEnclosure_Vtbl)).DownloadMimeType)(this, mimetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFile(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedEnclosure", "RemoveFile", 18usize, |this| ((**(this as *const *const IFeedEnclosure_Vtbl)).RemoveFile)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFile(this: *mut core::ffi::c_void, downloadurl: core::mem::MaybeUninit<windows_core::BSTR>, downloadfilepath: core::mem::MaybeUninit<windows_core::BSTR>, downloadmimetype: core::mem::MaybeUninit<windows_core::BSTR>, enclosurefilename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedEnclosure", "SetFile", 19usize, |this| ((**(this as *const *const IFeedEnclosure_Vtbl)).SetFile)(this, downloadurl, downloadfilepath, downloadmimetype, enclosurefilename), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Url,
+            Type,
+            Length,
+            AsyncDownload,
+            CancelAsyncDownload,
+            DownloadStatus,
+            LastDownloadError,
+            LocalPath,
+            #[cfg(feature = "Win32_System_Com")]
+            Parent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Parent: 0,
+            DownloadUrl,
+            DownloadMimeType,
+            RemoveFile,
+            SetFile,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFeedEnclosure as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFeedEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedEvents", "Error", 7usize, |this| ((**(this as *const *const IFeedEvents_Vtbl)).Error)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedDeleted(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedEvents", "FeedDeleted", 8usize, |this| ((**(this as *const *const IFeedEvents_Vtbl)).FeedDeleted)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedRenamed(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, oldpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedEvents", "FeedRenamed", 9usize, |this| ((**(this as *const *const IFeedEvents_Vtbl)).FeedRenamed)(this, path, oldpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedUrlChanged(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedEvents", "FeedUrlChanged", 10usize, |this| ((**(this as *const *const IFeedEvents_Vtbl)).FeedUrlChanged)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedMoved(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, oldpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedEvents", "FeedMoved", 11usize, |this| ((**(this as *const *const IFeedEvents_Vtbl)).FeedMoved)(this, path, oldpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedDownloading(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedEvents", "FeedDownloading", 12usize, |this| ((**(this as *const *const IFeedEvents_Vtbl)).FeedDownloading)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedDownloadCompleted(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, error: FEEDS_DOWNLOAD_ERROR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedEvents", "FeedDownloadCompleted", 13usize, |this| ((**(this as *const *const IFeedEvents_Vtbl)).FeedDownloadCompleted)(this, path, error), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedItemCountChanged(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, itemcounttype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedEvents", "FeedItemCountChanged", 14usize, |this| ((**(this as *const *const IFeedEvents_Vtbl)).FeedItemCountChanged)(this, path, itemcounttype), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Error,
+            FeedDeleted,
+            FeedRenamed,
+            FeedUrlChanged,
+            FeedMoved,
+            FeedDownloading,
+            FeedDownloadCompleted,
+            FeedItemCountChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFeedEvents as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFeedFolder_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Feeds(this: *mut core::ffi::c_void, disp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolder", "Feeds", 7usize, |this| ((**(this as *const *const IFeedFolder_Vtbl)).Feeds)(this, disp), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Subfolders(this: *mut core::ffi::c_void, disp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolder", "Subfolders", 8usize, |this| ((**(this as *const *const IFeedFolder_Vtbl)).Subfolders)(this, disp), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateFeed(this: *mut core::ffi::c_void, feedname: core::mem::MaybeUninit<windows_core::BSTR>, feedurl: core::mem::MaybeUninit<windows_core::BSTR>, disp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolder", "CreateFeed", 9usize, |this| ((**(this as *const *const IFeedFolder_Vtbl)).CreateFeed)(this, feedname, feedurl, disp), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateSubfolder(this: *mut core::ffi::c_void, foldername: core::mem::MaybeUninit<windows_core::BSTR>, disp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolder", "CreateSubfolder", 10usize, |this| ((**(this as *const *const IFeedFolder_Vtbl)).CreateSubfolder)(this, foldername, disp), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExistsFeed(this: *mut core::ffi::c_void, feedname: core::mem::MaybeUninit<windows_core::BSTR>, exists: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolder", "ExistsFeed", 11usize, |this| ((**(this as *const *const IFeedFolder_Vtbl)).ExistsFeed)(this, feedname, exists), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFeed(this: *mut core::ffi::c_void, feedname: core::mem::MaybeUninit<windows_core::BSTR>, disp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolder", "GetFeed", 12usize, |this| ((**(this as *const *const IFeedFolder_Vtbl)).GetFeed)(this, feedname, disp), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExistsSubfolder(this: *mut core::ffi::c_void, foldername: core::mem::MaybeUninit<windows_core::BSTR>, exists: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolder", "ExistsSubfolder", 13usize, |this| ((**(this as *const *const IFeedFolder_Vtbl)).ExistsSubfolder)(this, foldername, exists), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSubfolder(this: *mut core::ffi::c_void, foldername: core::mem::MaybeUninit<windows_core::BSTR>, disp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolder", "GetSubfolder", 14usize, |this| ((**(this as *const *const IFeedFolder_Vtbl)).GetSubfolder)(this, foldername, disp), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolder", "Delete", 15usize, |this| ((**(this as *const *const IFeedFolder_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, foldername: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolder", "Name", 16usize, |this| ((**(this as *const *const IFeedFolder_Vtbl)).Name)(this, foldername), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rename(this: *mut core::ffi::c_void, foldername: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolder", "Rename", 17usize, |this| ((**(this as *const *const IFeedFolder_Vtbl)).Rename)(this, foldername), |result| Some(*result))
+        }
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, folderpath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolder", "Path", 18usize, |this| ((**(this as *const *const IFeedFolder_Vtbl)).Path)(this, folderpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn Move(this: *mut core::ffi::c_void, newparentpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolder", "Move", 19usize, |this| ((**(this as *const *const IFeedFolder_Vtbl)).Move)(this, newparentpath), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Parent(this: *mut core::ffi::c_void, disp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolder", "Parent", 20usize, |this| ((**(this as *const *const IFeedFolder_Vtbl)).Parent)(this, disp), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRoot(this: *mut core::ffi::c_void, isroot: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolder", "IsRoot", 21usize, |this| ((**(this as *const *const IFeedFolder_Vtbl)).IsRoot)(this, isroot), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalUnreadItemCount(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolder", "TotalUnreadItemCount", 22usize, |this| ((**(this as *const *const IFeedFolder_Vtbl)).TotalUnreadItemCount)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalItemCount(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolder", "TotalItemCount", 23usize, |this| ((**(this as *const *const IFeedFolder_Vtbl)).TotalItemCount)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetWatcher(this: *mut core::ffi::c_void, scope: FEEDS_EVENTS_SCOPE, mask: FEEDS_EVENTS_MASK, disp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolder", "GetWatcher", 24usize, |this| ((**(this as *const *const IFeedFolder_Vtbl)).GetWatcher)(this, scope, mask, disp), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Feeds,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Feeds: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Subfolders,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Subfolders: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateFeed,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateFeed: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateSubfolder,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateSubfolder: 0,
+            ExistsFeed,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFeed,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFeed: 0,
+            ExistsSubfolder,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSubfolder,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSubfolder: 0,
+            Delete,
+            Name,
+            Rename,
+            Path,
+            Move,
+            #[cfg(feature = "Win32_System_Com")]
+            Parent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Parent: 0,
+            IsRoot,
+            TotalUnreadItemCount,
+            TotalItemCount,
+            #[cfg(feature = "Win32_System_Com")]
+            GetWatcher,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetWatcher: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFeedFolder as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFeedFolderEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolderEvents", "Error", 7usize, |this| ((**(this as *const *const IFeedFolderEvents_Vtbl)).Error)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn FolderAdded(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolderEvents", "FolderAdded", 8usize, |this| ((**(this as *const *const IFeedFolderEvents_Vtbl)).FolderAdded)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn FolderDeleted(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolderEvents", "FolderDeleted", 9usize, |this| ((**(this as *const *const IFeedFolderEvents_Vtbl)).FolderDeleted)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn FolderRenamed(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, oldpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolderEvents", "FolderRenamed", 10usize, |this| ((**(this as *const *const IFeedFolderEvents_Vtbl)).FolderRenamed)(this, path, oldpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn FolderMovedFrom(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, oldpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolderEvents", "FolderMovedFrom", 11usize, |this| ((**(this as *const *const IFeedFolderEvents_Vtbl)).FolderMovedFrom)(this, path, oldpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn FolderMovedTo(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, oldpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolderEvents", "FolderMovedTo", 12usize, |this| ((**(this as *const *const IFeedFolderEvents_Vtbl)).FolderMovedTo)(this, path, oldpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn FolderItemCountChanged(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, itemcounttype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolderEvents", "FolderItemCountChanged", 13usize, |this| ((**(this as *const *const IFeedFolderEvents_Vtbl)).FolderItemCountChanged)(this, path, itemcounttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedAdded(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolderEvents", "FeedAdded", 14usize, |this| ((**(this as *const *const IFeedFolderEvents_Vtbl)).FeedAdded)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedDeleted(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolderEvents", "FeedDeleted", 15usize, |this| ((**(this as *const *const IFeedFolderEvents_Vtbl)).FeedDeleted)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedRenamed(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, oldpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolderEvents", "FeedRenamed", 16usize, |this| ((**(this as *const *const IFeedFolderEvents_Vtbl)).FeedRenamed)(this, path, oldpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedUrlChanged(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolderEvents", "FeedUrlChanged", 17usize, |this| ((**(this as *const *const IFeedFolderEvents_Vtbl)).FeedUrlChanged)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedMovedFrom(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, oldpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolderEvents", "FeedMovedFrom", 18usize, |this| ((**(this as *const *const IFeedFolderEvents_Vtbl)).FeedMovedFrom)(this, path, oldpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedMovedTo(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, oldpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolderEvents", "FeedMovedTo", 19usize, |this| ((**(this as *const *const IFeedFolderEvents_Vtbl)).FeedMovedTo)(this, path, oldpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedDownloading(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolderEvents", "FeedDownloading", 20usize, |this| ((**(this as *const *const IFeedFolderEvents_Vtbl)).FeedDownloading)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedDownloadCompleted(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, error: FEEDS_DOWNLOAD_ERROR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolderEvents", "FeedDownloadCompleted", 21usize, |this| ((**(this as *const *const IFeedFolderEvents_Vtbl)).FeedDownloadCompleted)(this, path, error), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedItemCountChanged(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, itemcounttype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedFolderEvents", "FeedItemCountChanged", 22usize, |this| ((**(this as *const *const IFeedFolderEvents_Vtbl)).FeedItemCountChanged)(this, path, itemcounttype), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Error,
+            FolderAdded,
+            FolderDeleted,
+            FolderRenamed,
+            FolderMovedFrom,
+            FolderMovedTo,
+            FolderItemCountChanged,
+            FeedAdded,
+            FeedDeleted,
+            FeedRenamed,
+            FeedUrlChanged,
+            FeedMovedFrom,
+            FeedMovedTo,
+            FeedDownloading,
+            FeedDownloadCompleted,
+            FeedItemCountChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFeedFolderEvents as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFeedItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Xml(this: *mut core::ffi::c_void, includeflags: FEEDS_XML_INCLUDE_FLAGS, xml: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedItem", "Xml", 7usize, |this| ((**(this as *const *const IFeedItem_Vtbl)).Xml)(this, includeflags, xml), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, title: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedItem", "Title", 8usize, |this| ((**(this as *const *const IFeedItem_Vtbl)).Title)(this, title), |result| Some(*result))
+        }
+        unsafe extern "system" fn Link(this: *mut core::ffi::c_void, linkurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedItem", "Link", 9usize, |this| ((**(this as *const *const IFeedItem_Vtbl)).Link)(this, linkurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn Guid(this: *mut core::ffi::c_void, itemguid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedItem", "Guid", 10usize, |this| ((**(this as *const *const IFeedItem_Vtbl)).Guid)(this, itemguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, description: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedItem", "Description", 11usize, |this| ((**(this as *const *const IFeedItem_Vtbl)).Description)(this, description), |result| Some(*result))
+        }
+        unsafe extern "system" fn PubDate(this: *mut core::ffi::c_void, pubdate: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedItem", "PubDate", 12usize, |this| ((**(this as *const *const IFeedItem_Vtbl)).PubDate)(this, pubdate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Comments(this: *mut core::ffi::c_void, comments: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedItem", "Comments", 13usize, |this| ((**(this as *const *const IFeedItem_Vtbl)).Comments)(this, comments), |result| Some(*result))
+        }
+        unsafe extern "system" fn Author(this: *mut core::ffi::c_void, author: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedItem", "Author", 14usize, |this| ((**(this as *const *const IFeedItem_Vtbl)).Author)(this, author), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Enclosure(this: *mut core::ffi::c_void, disp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedItem", "Enclosure", 15usize, |this| ((**(this as *const *const IFeedItem_Vtbl)).Enclosure)(this, disp), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRead(this: *mut core::ffi::c_void, isread: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedItem", "IsRead", 16usize, |this| ((**(this as *const *const IFeedItem_Vtbl)).IsRead)(this, isread), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsRead(this: *mut core::ffi::c_void, isread: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedItem", "SetIsRead", 17usize, |this| ((**(this as *const *const IFeedItem_Vtbl)).SetIsRead)(this, isread), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalId(this: *mut core::ffi::c_void, itemid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedItem", "LocalId", 18usize, |this| ((**(this as *const *const IFeedItem_Vtbl)).LocalId)(this, itemid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Parent(this: *mut core::ffi::c_void, disp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedItem", "Parent", 19usize, |this| ((**(this as *const *const IFeedItem_Vtbl)).Parent)(this, disp), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedItem", "Delete", 20usize, |this| ((**(this as *const *const IFeedItem_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadUrl(this: *mut core::ffi::c_void, itemurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedItem", "DownloadUrl", 21usize, |this| ((**(this as *const *const IFeedItem_Vtbl)).DownloadUrl)(this, itemurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastDownloadTime(this: *mut core::ffi::c_void, lastdownload: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedItem", "LastDownloadTime", 22usize, |this| ((**(this as *const *const IFeedItem_Vtbl)).LastDownloadTime)(this, lastdownload), |result| Some(*result))
+        }
+        unsafe extern "system" fn Modified(this: *mut core::ffi::c_void, modified: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedItem", "Modified", 23usize, |this| ((**(this as *const *const IFeedItem_Vtbl)).Modified)(this, modified), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Xml,
+            Title,
+            Link,
+            Guid,
+            Description,
+            PubDate,
+            Comments,
+            Author,
+            #[cfg(feature = "Win32_System_Com")]
+            Enclosure,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Enclosure: 0,
+            IsRead,
+            SetIsRead,
+            LocalId,
+            #[cfg(feature = "Win32_System_Com")]
+            Parent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Parent: 0,
+            Delete,
+            DownloadUrl,
+            LastDownloadTime,
+            Modified,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFeedItem as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFeedItem2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EffectiveId(this: *mut core::ffi::c_void, effectiveid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedItem2", "EffectiveId", 24usize, |this| ((**(this as *const *const IFeedItem2_Vtbl)).EffectiveId)(this, effectiveid), |result| Some(*result))
+        }
+        Self { base__: <IFeedItem_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EffectiveId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFeedItem2 as windows_core::Interface>::IID || <IFeedItem_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IFeedsEnum_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedsEnum", "Count", 7usize, |this| ((**(this as *const *const IFeedsEnum_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: i32, disp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedsEnum", "Item", 8usize, |this| ((**(this as *const *const IFeedsEnum_Vtbl)).Item)(this, index, disp), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, enumvar: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedsEnum", "_NewEnum", 9usize, |this| ((**(this as *const *const IFeedsEnum_Vtbl))._NewEnum)(this, enumvar), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            #[cfg(feature = "Win32_System_Ole")]
+            _NewEnum,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            _NewEnum: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFeedsEnum as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFeedsManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RootFolder(this: *mut core::ffi::c_void, disp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedsManager", "RootFolder", 7usize, |this| ((**(this as *const *const IFeedsManager_Vtbl)).RootFolder)(this, disp), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSubscribed(this: *mut core::ffi::c_void, feedurl: core::mem::MaybeUninit<windows_core::BSTR>, subscribed: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedsManager", "IsSubscribed", 8usize, |this| ((**(this as *const *const IFeedsManager_Vtbl)).IsSubscribed)(this, feedurl, subscribed), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExistsFeed(this: *mut core::ffi::c_void, feedpath: core::mem::MaybeUninit<windows_core::BSTR>, exists: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedsManager", "ExistsFeed", 9usize, |this| ((**(this as *const *const IFeedsManager_Vtbl)).ExistsFeed)(this, feedpath, exists), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFeed(this: *mut core::ffi::c_void, feedpath: core::mem::MaybeUninit<windows_core::BSTR>, disp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedsManager", "GetFeed", 10usize, |this| ((**(this as *const *const IFeedsManager_Vtbl)).GetFeed)(this, feedpath, disp), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFeedByUrl(this: *mut core::ffi::c_void, feedurl: core::mem::MaybeUninit<windows_core::BSTR>, disp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedsManager", "GetFeedByUrl", 11usize, |this| ((**(this as *const *const IFeedsManager_Vtbl)).GetFeedByUrl)(this, feedurl, disp), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExistsFolder(this: *mut core::ffi::c_void, folderpath: core::mem::MaybeUninit<windows_core::BSTR>, exists: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedsManager", "ExistsFolder", 12usize, |this| ((**(this as *const *const IFeedsManager_Vtbl)).ExistsFolder)(this, folderpath, exists), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFolder(this: *mut core::ffi::c_void, folderpath: core::mem::MaybeUninit<windows_core::BSTR>, disp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedsManager", "GetFolder", 13usize, |this| ((**(this as *const *const IFeedsManager_Vtbl)).GetFolder)(this, folderpath, disp), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteFeed(this: *mut core::ffi::c_void, feedpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedsManager", "DeleteFeed", 14usize, |this| ((**(this as *const *const IFeedsManager_Vtbl)).DeleteFeed)(this, feedpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteFolder(this: *mut core::ffi::c_void, folderpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedsManager", "DeleteFolder", 15usize, |this| ((**(this as *const *const IFeedsManager_Vtbl)).DeleteFolder)(this, folderpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackgroundSync(this: *mut core::ffi::c_void, action: FEEDS_BACKGROUNDSYNC_ACTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedsManager", "BackgroundSync", 16usize, |this| ((**(this as *const *const IFeedsManager_Vtbl)).BackgroundSync)(this, action), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackgroundSyncStatus(this: *mut core::ffi::c_void, status: *mut FEEDS_BACKGROUNDSYNC_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedsManager", "BackgroundSyncStatus", 17usize, |this| ((**(this as *const *const IFeedsManager_Vtbl)).BackgroundSyncStatus)(this, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultInterval(this: *mut core::ffi::c_void, minutes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedsManager", "DefaultInterval", 18usize, |this| ((**(this as *const *const IFeedsManager_Vtbl)).DefaultInterval)(this, minutes), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultInterval(this: *mut core::ffi::c_void, minutes: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedsManager", "SetDefaultInterval", 19usize, |this| ((**(this as *const *const IFeedsManager_Vtbl)).SetDefaultInterval)(this, minutes), |result| Some(*result))
+        }
+        unsafe extern "system" fn AsyncSyncAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedsManager", "AsyncSyncAll", 20usize, |this| ((**(this as *const *const IFeedsManager_Vtbl)).AsyncSyncAll)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Normalize(this: *mut core::ffi::c_void, feedxmlin: core::mem::MaybeUninit<windows_core::BSTR>, feedxmlout: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedsManager", "Normalize", 21usize, |this| ((**(this as *const *const IFeedsManager_Vtbl)).Normalize)(this, feedxmlin, feedxmlout), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItemCountLimit(this: *mut core::ffi::c_void, itemcountlimit: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedsManager", "ItemCountLimit", 22usize, |this| ((**(this as *const *const IFeedsManager_Vtbl)).ItemCountLimit)(this, itemcountlimit), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            RootFolder,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RootFolder: 0,
+            IsSubscribed,
+            ExistsFeed,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFeed,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFeed: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFeedByUrl,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFeedByUrl: 0,
+            ExistsFolder,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFolder,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFolder: 0,
+            DeleteFeed,
+            DeleteFolder,
+            BackgroundSync,
+            BackgroundSyncStatus,
+            DefaultInterval,
+            SetDefaultInterval,
+            AsyncSyncAll,
+            Normalize,
+            ItemCountLimit,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFeedsManager as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPAudioRenderConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn audioOutputDevice(this: *mut core::ffi::c_void, pbstroutputdevice: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPAudioRenderConfig", "audioOutputDevice", 3usize, |this| ((**(this as *const *const IWMPAudioRenderConfig_Vtbl)).audioOutputDevice)(this, pbstroutputdevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetaudioOutputDevice(this: *mut core::ffi::c_void, bstroutputdevice: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPAudioRenderConfig", "SetaudioOutputDevice", 4usize, |this| ((**(this as *const *const IWMPAudioRenderConfig_Vtbl)).SetaudioOutputDevice)(this, bstroutputdevice), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, audioOutputDevice, SetaudioOutputDevice }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPAudioRenderConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPCdrom_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn driveSpecifier(this: *mut core::ffi::c_void, pbstrdrive: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCdrom", "driveSpecifier", 7usize, |this| ((**(this as *const *const IWMPCdrom_Vtbl)).driveSpecifier)(this, pbstrdrive), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn playlist(this: *mut core::ffi::c_void, ppplaylist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCdrom", "playlist", 8usize, |this| ((**(this as *const *const IWMPCdrom_Vtbl)).playlist)(this, ppplaylist), |result| Some(*result))
+        }
+        unsafe extern "system" fn eject(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCdrom", "eject", 9usize, |this| ((**(this as *const *const IWMPCdrom_Vtbl)).eject)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            driveSpecifier,
+            #[cfg(feature = "Win32_System_Com")]
+            playlist,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            playlist: 0,
+            eject,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPCdrom as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPCdromBurn_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn isAvailable(this: *mut core::ffi::c_void, bstritem: core::mem::MaybeUninit<windows_core::BSTR>, pisavailable: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCdromBurn", "isAvailable", 3usize, |this| ((**(this as *const *const IWMPCdromBurn_Vtbl)).isAvailable)(this, bstritem, pisavailable), |result| Some(*result))
+        }
+        unsafe extern "system" fn getItemInfo(this: *mut core::ffi::c_void, bstritem: core::mem::MaybeUninit<windows_core::BSTR>, pbstrval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCdromBurn", "getItemInfo", 4usize, |this| ((**(this as *const *const IWMPCdromBurn_Vtbl)).getItemInfo)(this, bstritem, pbstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn label(this: *mut core::ffi::c_void, pbstrlabel: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCdromBurn", "label", 5usize, |this| ((**(this as *const *const IWMPCdromBurn_Vtbl)).label)(this, pbstrlabel), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setlabel(this: *mut core::ffi::c_void, bstrlabel: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCdromBurn", "Setlabel", 6usize, |this| ((**(this as *const *const IWMPCdromBurn_Vtbl)).Setlabel)(this, bstrlabel), |result| Some(*result))
+        }
+        unsafe extern "system" fn burnFormat(this: *mut core::ffi::c_void, pwmpbf: *mut WMPBurnFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCdromBurn", "burnFormat", 7usize, |this| ((**(this as *const *const IWMPCdromBurn_Vtbl)).burnFormat)(this, pwmpbf), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetburnFormat(this: *mut core::ffi::c_void, wmpbf: WMPBurnFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCdromBurn", "SetburnFormat", 8usize, |this| ((**(this as *const *const IWMPCdromBurn_Vtbl)).SetburnFormat)(this, wmpbf), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn burnPlaylist(this: *mut core::ffi::c_void, ppplaylist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCdromBurn", "burnPlaylist", 9usize, |this| ((**(this as *const *const IWMPCdromBurn_Vtbl)).burnPlaylist)(this, ppplaylist), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetburnPlaylist(this: *mut core::ffi::c_void, pplaylist: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCdromBurn", "SetburnPlaylist", 10usize, |this| ((**(this as *const *const IWMPCdromBurn_Vtbl)).SetburnPlaylist)(this, pplaylist), |result| Some(*result))
+        }
+        unsafe extern "system" fn refreshStatus(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCdromBurn", "refreshStatus", 11usize, |this| ((**(this as *const *const IWMPCdromBurn_Vtbl)).refreshStatus)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn burnState(this: *mut core::ffi::c_void, pwmpbs: *mut WMPBurnState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCdromBurn", "burnState", 12usize, |this| ((**(this as *const *const IWMPCdromBurn_Vtbl)).burnState)(this, pwmpbs), |result| Some(*result))
+        }
+        unsafe extern "system" fn burnProgress(this: *mut core::ffi::c_void, plprogress: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCdromBurn", "burnProgress", 13usize, |this| ((**(this as *const *const IWMPCdromBurn_Vtbl)).burnProgress)(this, plprogress), |result| Some(*result))
+        }
+        unsafe extern "system" fn startBurn(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCdromBurn", "startBurn", 14usize, |this| ((**(this as *const *const IWMPCdromBurn_Vtbl)).startBurn)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn stopBurn(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCdromBurn", "stopBurn", 15usize, |this| ((**(this as *const *const IWMPCdromBurn_Vtbl)).stopBurn)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn erase(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCdromBurn", "erase", 16usize, |this| ((**(this as *const *const IWMPCdromBurn_Vtbl)).erase)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            isAvailable,
+            getItemInfo,
+            label,
+            Setlabel,
+            burnFormat,
+            SetburnFormat,
+            #[cfg(feature = "Win32_System_Com")]
+            burnPlaylist,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            burnPlaylist: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetburnPlaylist,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetburnPlaylist: 0,
+            refreshStatus,
+            burnState,
+            burnProgress,
+            startBurn,
+            stopBurn,
+            erase,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPCdromBurn as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPCdromCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCdromCollection", "count", 7usize, |this| ((**(this as *const *const IWMPCdromCollection_Vtbl)).count)(this, plcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn item(this: *mut core::ffi::c_void, lindex: i32, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCdromCollection", "item", 8usize, |this| ((**(this as *const *const IWMPCdromCollection_Vtbl)).item)(this, lindex, ppitem), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn getByDriveSpecifier(this: *mut core::ffi::c_void, bstrdrivespecifier: core::mem::MaybeUninit<windows_core::BSTR>, ppcdrom: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCdromCollection", "getByDriveSpecifier", 9usize, |this| ((**(this as *const *const IWMPCdromCollection_Vtbl)).getByDriveSpecifier)(this, bstrdrivespecifier, ppcdrom), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            count,
+            #[cfg(feature = "Win32_System_Com")]
+            item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            getByDriveSpecifier,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            getByDriveSpecifier: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPCdromCollection as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPCdromRip_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ripState(this: *mut core::ffi::c_void, pwmprs: *mut WMPRipState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCdromRip", "ripState", 3usize, |this| ((**(this as *const *const IWMPCdromRip_Vtbl)).ripState)(this, pwmprs), |result| Some(*result))
+        }
+        unsafe extern "system" fn ripProgress(this: *mut core::ffi::c_void, plprogress: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCdromRip", "ripProgress", 4usize, |this| ((**(this as *const *const IWMPCdromRip_Vtbl)).ripProgress)(this, plprogress), |result| Some(*result))
+        }
+        unsafe extern "system" fn startRip(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCdromRip", "startRip", 5usize, |this| ((**(this as *const *const IWMPCdromRip_Vtbl)).startRip)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn stopRip(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCdromRip", "stopRip", 6usize, |this| ((**(this as *const *const IWMPCdromRip_Vtbl)).stopRip)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ripState, ripProgress, startRip, stopRip }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPCdromRip as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPClosedCaption_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SAMIStyle(this: *mut core::ffi::c_void, pbstrsamistyle: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPClosedCaption", "SAMIStyle", 7usize, |this| ((**(this as *const *const IWMPClosedCaption_Vtbl)).SAMIStyle)(this, pbstrsamistyle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSAMIStyle(this: *mut core::ffi::c_void, bstrsamistyle: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPClosedCaption", "SetSAMIStyle", 8usize, |this| ((**(this as *const *const IWMPClosedCaption_Vtbl)).SetSAMIStyle)(this, bstrsamistyle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SAMILang(this: *mut core::ffi::c_void, pbstrsamilang: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPClosedCaption", "SAMILang", 9usize, |this| ((**(this as *const *const IWMPClosedCaption_Vtbl)).SAMILang)(this, pbstrsamilang), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSAMILang(this: *mut core::ffi::c_void, bstrsamilang: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPClosedCaption", "SetSAMILang", 10usize, |this| ((**(this as *const *const IWMPClosedCaption_Vtbl)).SetSAMILang)(this, bstrsamilang), |result| Some(*result))
+        }
+        unsafe extern "system" fn SAMIFileName(this: *mut core::ffi::c_void, pbstrsamifilename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPClosedCaption", "SAMIFileName", 11usize, |this| ((**(this as *const *const IWMPClosedCaption_Vtbl)).SAMIFileName)(this, pbstrsamifilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSAMIFileName(this: *mut core::ffi::c_void, bstrsamifilename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPClosedCaption", "SetSAMIFileName", 12usize, |this| ((**(this as *const *const IWMPClosedCaption_Vtbl)).SetSAMIFileName)(this, bstrsamifilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn captioningId(this: *mut core::ffi::c_void, pbstrcaptioningid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPClosedCaption", "captioningId", 13usize, |this| ((**(this as *const *const IWMPClosedCaption_Vtbl)).captioningId)(this, pbstrcaptioningid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetcaptioningId(this: *mut core::ffi::c_void, bstrcaptioningid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPClosedCaption", "SetcaptioningId", 14usize, |this| ((**(this as *const *const IWMPClosedCaption_Vtbl)).SetcaptioningId)(this, bstrcaptioningid), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SAMIStyle,
+            SetSAMIStyle,
+            SAMILang,
+            SetSAMILang,
+            SAMIFileName,
+            SetSAMIFileName,
+            captioningId,
+            SetcaptioningId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPClosedCaption as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPClosedCaption2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SAMILangCount(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPClosedCaption2", "SAMILangCount", 15usize, |this| ((**(this as *const *const IWMPClosedCaption2_Vtbl)).SAMILangCount)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn getSAMILangName(this: *mut core::ffi::c_void, nindex: i32, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPClosedCaption2", "getSAMILangName", 16usize, |this| ((**(this as *const *const IWMPClosedCaption2_Vtbl)).getSAMILangName)(this, nindex, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn getSAMILangID(this: *mut core::ffi::c_void, nindex: i32, pllangid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPClosedCaption2", "getSAMILangID", 17usize, |this| ((**(this as *const *const IWMPClosedCaption2_Vtbl)).getSAMILangID)(this, nindex, pllangid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SAMIStyleCount(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPClosedCaption2", "SAMIStyleCount", 18usize, |this| ((**(this as *const *const IWMPClosedCaption2_Vtbl)).SAMIStyleCount)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn getSAMIStyleName(this: *mut core::ffi::c_void, nindex: i32, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPClosedCaption2", "getSAMIStyleName", 19usize, |this| ((**(this as *const *const IWMPClosedCaption2_Vtbl)).getSAMIStyleName)(this, nindex, pbstrname), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMPClosedCaption_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SAMILangCount,
+            getSAMILangName,
+            getSAMILangID,
+            SAMIStyleCount,
+            getSAMIStyleName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPClosedCaption2 as windows_core::Interface>::IID || <IWMPClosedCaption_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPContentContainer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetID(this: *mut core::ffi::c_void, pcontentid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentContainer", "GetID", 3usize, |this| ((**(this as *const *const IWMPContentContainer_Vtbl)).GetID)(this, pcontentid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrice(this: *mut core::ffi::c_void, pbstrprice: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentContainer", "GetPrice", 4usize, |this| ((**(this as *const *const IWMPContentContainer_Vtbl)).GetPrice)(this, pbstrprice), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, pbstrtype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentContainer", "GetType", 5usize, |this| ((**(this as *const *const IWMPContentContainer_Vtbl)).GetType)(this, pbstrtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContentCount(this: *mut core::ffi::c_void, pccontent: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentContainer", "GetContentCount", 6usize, |this| ((**(this as *const *const IWMPContentContainer_Vtbl)).GetContentCount)(this, pccontent), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContentPrice(this: *mut core::ffi::c_void, idxcontent: u32, pbstrprice: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentContainer", "GetContentPrice", 7usize, |this| ((**(this as *const *const IWMPContentContainer_Vtbl)).GetContentPrice)(this, idxcontent, pbstrprice), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContentID(this: *mut core::ffi::c_void, idxcontent: u32, pcontentid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentContainer", "GetContentID", 8usize, |this| ((**(this as *const *const IWMPContentContainer_Vtbl)).GetContentID)(this, idxcontent, pcontentid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetID,
+            GetPrice,
+            GetType,
+            GetContentCount,
+            GetContentPrice,
+            GetContentID,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPContentContainer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPContentContainerList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTransactionType(this: *mut core::ffi::c_void, pwmptt: *mut WMPTransactionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentContainerList", "GetTransactionType", 3usize, |this| ((**(this as *const *const IWMPContentContainerList_Vtbl)).GetTransactionType)(this, pwmptt), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContainerCount(this: *mut core::ffi::c_void, pccontainer: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentContainerList", "GetContainerCount", 4usize, |this| ((**(this as *const *const IWMPContentContainerList_Vtbl)).GetContainerCount)(this, pccontainer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContainer(this: *mut core::ffi::c_void, idxcontainer: u32, ppcontent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentContainerList", "GetContainer", 5usize, |this| ((**(this as *const *const IWMPContentContainerList_Vtbl)).GetContainer)(this, idxcontainer, ppcontent), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetTransactionType, GetContainerCount, GetContainer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPContentContainerList as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPContentPartner_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetCallback(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartner", "SetCallback", 3usize, |this| ((**(this as *const *const IWMPContentPartner_Vtbl)).SetCallback)(this, pcallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn Notify(this: *mut core::ffi::c_void, r#type: WMPPartnerNotification, pcontext: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartner", "Notify", 4usize, |this| ((**(this as *const *const IWMPContentPartner_Vtbl)).Notify)(this, r#type, pcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItemInfo(this: *mut core::ffi::c_void, bstrinfoname: core::mem::MaybeUninit<windows_core::BSTR>, pcontext: *const core::mem::MaybeUninit<windows_core::VARIANT>, pdata: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartner", "GetItemInfo", 5usize, |this| ((**(this as *const *const IWMPContentPartner_Vtbl)).GetItemInfo)(this, bstrinfoname, pcontext, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContentPartnerInfo(this: *mut core::ffi::c_void, bstrinfoname: core::mem::MaybeUninit<windows_core::BSTR>, pdata: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartner", "GetContentPartnerInfo", 6usize, |this| ((**(this as *const *const IWMPContentPartner_Vtbl)).GetContentPartnerInfo)(this, bstrinfoname, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCommands(this: *mut core::ffi::c_void, location: core::mem::MaybeUninit<windows_core::BSTR>, plocationcontext: *const core::mem::MaybeUninit<windows_core::VARIANT>, itemlocation: core::mem::MaybeUninit<windows_core::BSTR>, citemids: u32, prgitemids: *const u32, pcitemids: *mut u32, pprgitems: *mut *mut WMPContextMenuInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartner", "GetCommands", 7usize, |this| ((**(this as *const *const IWMPContentPartner_Vtbl)).GetCommands)(this, location, plocationcontext, itemlocation, citemids, prgitemids, pcitemids, pprgitems), |result| Some(*result))
+        }
+        unsafe extern "system" fn InvokeCommand(this: *mut core::ffi::c_void, dwcommandid: u32, location: core::mem::MaybeUninit<windows_core::BSTR>, plocationcontext: *const core::mem::MaybeUninit<windows_core::VARIANT>, itemlocation: core::mem::MaybeUninit<windows_core::BSTR>, citemids: u32, rgitemids: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartner", "InvokeCommand", 8usize, |this| ((**(this as *const *const IWMPContentPartner_Vtbl)).InvokeCommand)(this, dwcommandid, location, plocationcontext, itemlocation, citemids, rgitemids), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanBuySilent(this: *mut core::ffi::c_void, pinfo: *mut core::ffi::c_void, pbstrtotalprice: *mut core::mem::MaybeUninit<windows_core::BSTR>, psilentok: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartner", "CanBuySilent", 9usize, |this| ((**(this as *const *const IWMPContentPartner_Vtbl)).CanBuySilent)(this, pinfo, pbstrtotalprice, psilentok), |result| Some(*result))
+        }
+        unsafe extern "system" fn Buy(this: *mut core::ffi::c_void, pinfo: *mut core::ffi::c_void, cookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartner", "Buy", 10usize, |this| ((**(this as *const *const IWMPContentPartner_Vtbl)).Buy)(this, pinfo, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamingURL(this: *mut core::ffi::c_void, st: WMPStreamingType, pstreamcontext: *const core::mem::MaybeUninit<windows_core::VARIANT>, pbstrurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartner", "GetStreamingURL", 11usize, |this| ((**(this as *const *const IWMPContentPartner_Vtbl)).GetStreamingURL)(this, st, pstreamcontext, pbstrurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn Download(this: *mut core::ffi::c_void, pinfo: *mut core::ffi::c_void, cookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartner", "Download", 12usize, |this| ((**(this as *const *const IWMPContentPartner_Vtbl)).Download)(this, pinfo, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadTrackComplete(this: *mut core::ffi::c_void, hrresult: windows_core::HRESULT, contentid: u32, downloadtrackparam: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartner", "DownloadTrackComplete", 13usize, |this| ((**(this as *const *const IWMPContentPartner_Vtbl)).DownloadTrackComplete)(this, hrresult, contentid, downloadtrackparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn RefreshLicense(this: *mut core::ffi::c_void, dwcookie: u32, flocal: super::super::Foundation::VARIANT_BOOL, bstrurl: core::mem::MaybeUninit<windows_core::BSTR>, r#type: WMPStreamingType, contentid: u32, bstrrefreshreason: core::mem::MaybeUninit<windows_core::BSTR>, preasoncontext: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartner", "RefreshLicense", 14usize, |this| ((**(this as *const *const IWMPContentPartner_Vtbl)).RefreshLicense)(this, dwcookie, flocal, bstrurl, r#type, contentid, bstrrefreshreason, preasoncontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCatalogURL(this: *mut core::ffi::c_void, dwcatalogversion: u32, dwcatalogschemaversion: u32, cataloglcid: u32, pdwnewcatalogversion: *mut u32, pbstrcatalogurl: *mut core::mem::MaybeUninit<windows_core::BSTR>, pexpirationdate: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartner", "GetCatalogURL", 15usize, |this| ((**(this as *const *const IWMPContentPartner_Vtbl)).GetCatalogURL)(this, dwcatalogversion, dwcatalogschemaversion, cataloglcid, pdwnewcatalogversion, pbstrcatalogurl, pexpirationdate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTemplate(this: *mut core::ffi::c_void, task: WMPTaskType, location: core::mem::MaybeUninit<windows_core::BSTR>, pcontext: *const core::mem::MaybeUninit<windows_core::VARIANT>, clicklocation: core::mem::MaybeUninit<windows_core::BSTR>, pclickcontext: *const core::mem::MaybeUninit<windows_core::VARIANT>, bstrfilter: core::mem::MaybeUninit<windows_core::BSTR>, bstrviewparams: core::mem::MaybeUninit<windows_core::BSTR>, pbstrtemplateurl: *mut core::mem::MaybeUninit<windows_core::BSTR>, ptemplatesize: *mut WMPTemplateSize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartner", "GetTemplate", 16usize, |this| ((**(this as *const *const IWMPContentPartner_Vtbl)).GetTemplate)(this, task, location, pcontext, clicklocation, pclickcontext, bstrfilter, bstrviewparams, pbstrtemplateurl, ptemplatesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateDevice(this: *mut core::ffi::c_void, bstrdevicename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartner", "UpdateDevice", 17usize, |this| ((**(this as *const *const IWMPContentPartner_Vtbl)).UpdateDevice)(this, bstrdevicename), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetListContents(this: *mut core::ffi::c_void, location: core::mem::MaybeUninit<windows_core::BSTR>, pcontext: *const core::mem::MaybeUninit<windows_core::VARIANT>, bstrlisttype: core::mem::MaybeUninit<windows_core::BSTR>, bstrparams: core::mem::MaybeUninit<windows_core::BSTR>, dwlistcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartner", "GetListContents", 18usize, |this| ((**(this as *const *const IWMPContentPartner_Vtbl)).GetListContents)(this, location, pcontext, bstrlisttype, bstrparams, dwlistcookie), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Login(this: *mut core::ffi::c_void, userinfo: super::super::System::Com::BLOB, pwdinfo: super::super::System::Com::BLOB, fusedcachedcreds: super::super::Foundation::VARIANT_BOOL, foktocache: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartner", "Login", 19usize, |this| ((**(this as *const *const IWMPContentPartner_Vtbl)).Login)(this, userinfo, pwdinfo, fusedcachedcreds, foktocache), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Authenticate(this: *mut core::ffi::c_void, userinfo: super::super::System::Com::BLOB, pwdinfo: super::super::System::Com::BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartner", "Authenticate", 20usize, |this| ((**(this as *const *const IWMPContentPartner_Vtbl)).Authenticate)(this, userinfo, pwdinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn Logout(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartner", "Logout", 21usize, |this| ((**(this as *const *const IWMPContentPartner_Vtbl)).Logout)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendMessage(this: *mut core::ffi::c_void, bstrmsg: core::mem::MaybeUninit<windows_core::BSTR>, bstrparam: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartner", "SendMessage", 22usize, |this| ((**(this as *const *const IWMPContentPartner_Vtbl)).SendMessage)(this, bstrmsg, bstrparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn StationEvent(this: *mut core::ffi::c_void, bstrstationeventtype: core::mem::MaybeUninit<windows_core::BSTR>, stationid: u32, playlistindex: u32, trackid: u32, trackdata: core::mem::MaybeUninit<windows_core::BSTR>, dwsecondsplayed: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartner", "StationEvent", 23usize, |this| ((**(this as *const *const IWMPContentPartner_Vtbl)).StationEvent)(this, bstrstationeventtype, stationid, playlistindex, trackid, trackdata, dwsecondsplayed), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompareContainerListPrices(this: *mut core::ffi::c_void, plistbase: *mut core::ffi::c_void, plistcompare: *mut core::ffi::c_void, presult: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartner", "CompareContainerListPrices", 24usize, |this| ((**(this as *const *const IWMPContentPartner_Vtbl)).CompareContainerListPrices)(this, plistbase, plistcompare, presult), |result| Some(*result))
+        }
+        unsafe extern "system" fn VerifyPermission(this: *mut core::ffi::c_void, bstrpermission: core::mem::MaybeUninit<windows_core::BSTR>, pcontext: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartner", "VerifyPermission", 25usize, |this| ((**(this as *const *const IWMPContentPartner_Vtbl)).VerifyPermission)(this, bstrpermission, pcontext), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetCallback,
+            Notify,
+            GetItemInfo,
+            GetContentPartnerInfo,
+            GetCommands,
+            InvokeCommand,
+            CanBuySilent,
+            Buy,
+            GetStreamingURL,
+            Download,
+            DownloadTrackComplete,
+            RefreshLicense,
+            GetCatalogURL,
+            GetTemplate,
+            UpdateDevice,
+            GetListContents,
+            #[cfg(feature = "Win32_System_Com")]
+            Login,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Login: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Authenticate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Authenticate: 0,
+            Logout,
+            SendMessage,
+            StationEvent,
+            CompareContainerListPrices,
+            VerifyPermission,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPContentPartner as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPContentPartnerCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Notify(this: *mut core::ffi::c_void, r#type: WMPCallbackNotification, pcontext: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartnerCallback", "Notify", 3usize, |this| ((**(this as *const *const IWMPContentPartnerCallback_Vtbl)).Notify)(this, r#type, pcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn BuyComplete(this: *mut core::ffi::c_void, hrresult: windows_core::HRESULT, dwbuycookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartnerCallback", "BuyComplete", 4usize, |this| ((**(this as *const *const IWMPContentPartnerCallback_Vtbl)).BuyComplete)(this, hrresult, dwbuycookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadTrack(this: *mut core::ffi::c_void, cookie: u32, bstrtrackurl: core::mem::MaybeUninit<windows_core::BSTR>, dwservicetrackid: u32, bstrdownloadparams: core::mem::MaybeUninit<windows_core::BSTR>, hrdownload: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartnerCallback", "DownloadTrack", 5usize, |this| ((**(this as *const *const IWMPContentPartnerCallback_Vtbl)).DownloadTrack)(this, cookie, bstrtrackurl, dwservicetrackid, bstrdownloadparams, hrdownload), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCatalogVersion(this: *mut core::ffi::c_void, pdwversion: *mut u32, pdwschemaversion: *mut u32, plcid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartnerCallback", "GetCatalogVersion", 6usize, |this| ((**(this as *const *const IWMPContentPartnerCallback_Vtbl)).GetCatalogVersion)(this, pdwversion, pdwschemaversion, plcid), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateDeviceComplete(this: *mut core::ffi::c_void, bstrdevicename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartnerCallback", "UpdateDeviceComplete", 7usize, |this| ((**(this as *const *const IWMPContentPartnerCallback_Vtbl)).UpdateDeviceComplete)(this, bstrdevicename), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeView(this: *mut core::ffi::c_void, bstrtype: core::mem::MaybeUninit<windows_core::BSTR>, bstrid: core::mem::MaybeUninit<windows_core::BSTR>, bstrfilter: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartnerCallback", "ChangeView", 8usize, |this| ((**(this as *const *const IWMPContentPartnerCallback_Vtbl)).ChangeView)(this, bstrtype, bstrid, bstrfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddListContents(this: *mut core::ffi::c_void, dwlistcookie: u32, citems: u32, prgitems: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartnerCallback", "AddListContents", 9usize, |this| ((**(this as *const *const IWMPContentPartnerCallback_Vtbl)).AddListContents)(this, dwlistcookie, citems, prgitems), |result| Some(*result))
+        }
+        unsafe extern "system" fn ListContentsComplete(this: *mut core::ffi::c_void, dwlistcookie: u32, hrsuccess: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartnerCallback", "ListContentsComplete", 10usize, |this| ((**(this as *const *const IWMPContentPartnerCallback_Vtbl)).ListContentsComplete)(this, dwlistcookie, hrsuccess), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendMessageComplete(this: *mut core::ffi::c_void, bstrmsg: core::mem::MaybeUninit<windows_core::BSTR>, bstrparam: core::mem::MaybeUninit<windows_core::BSTR>, bstrresult: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartnerCallback", "SendMessageComplete", 11usize, |this| ((**(this as *const *const IWMPContentPartnerCallback_Vtbl)).SendMessageComplete)(this, bstrmsg, bstrparam, bstrresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContentIDsInLibrary(this: *mut core::ffi::c_void, pccontentids: *mut u32, pprgids: *mut *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartnerCallback", "GetContentIDsInLibrary", 12usize, |this| ((**(this as *const *const IWMPContentPartnerCallback_Vtbl)).GetContentIDsInLibrary)(this, pccontentids, pprgids), |result| Some(*result))
+        }
+        unsafe extern "system" fn RefreshLicenseComplete(this: *mut core::ffi::c_void, dwcookie: u32, contentid: u32, hrrefresh: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartnerCallback", "RefreshLicenseComplete", 13usize, |this| ((**(this as *const *const IWMPContentPartnerCallback_Vtbl)).RefreshLicenseComplete)(this, dwcookie, contentid, hrrefresh), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowPopup(this: *mut core::ffi::c_void, lindex: i32, bstrparameters: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartnerCallback", "ShowPopup", 14usize, |this| ((**(this as *const *const IWMPContentPartnerCallback_Vtbl)).ShowPopup)(this, lindex, bstrparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn VerifyPermissionComplete(this: *mut core::ffi::c_void, bstrpermission: core::mem::MaybeUninit<windows_core::BSTR>, pcontext: *const core::mem::MaybeUninit<windows_core::VARIANT>, hrpermission: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPContentPartnerCallback", "VerifyPermissionComplete", 15usize, |this| ((**(this as *const *const IWMPContentPartnerCallback_Vtbl)).VerifyPermissionComplete)(this, bstrpermission, pcontext, hrpermission), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Notify,
+            BuyComplete,
+            DownloadTrack,
+            GetCatalogVersion,
+            UpdateDeviceComplete,
+            ChangeView,
+            AddListContents,
+            ListContentsComplete,
+            SendMessageComplete,
+            GetContentIDsInLibrary,
+            RefreshLicenseComplete,
+            ShowPopup,
+            VerifyPermissionComplete,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPContentPartnerCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPControls_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_isAvailable(this: *mut core::ffi::c_void, bstritem: core::mem::MaybeUninit<windows_core::BSTR>, pisavailable: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPControls", "get_isAvailable", 7usize, |this| ((**(this as *const *const IWMPControls_Vtbl)).get_isAvailable)(this, bstritem, pisavailable), |result| Some(*result))
+        }
+        unsafe extern "system" fn play(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPControls", "play", 8usize, |this| ((**(this as *const *const IWMPControls_Vtbl)).play)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPControls", "stop", 9usize, |this| ((**(this as *const *const IWMPControls_Vtbl)).stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPControls", "pause", 10usize, |this| ((**(this as *const *const IWMPControls_Vtbl)).pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn fastForward(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPControls", "fastForward", 11usize, |this| ((**(this as *const *const IWMPControls_Vtbl)).fastForward)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn fastReverse(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPControls", "fastReverse", 12usize, |this| ((**(this as *const *const IWMPControls_Vtbl)).fastReverse)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn currentPosition(this: *mut core::ffi::c_void, pdcurrentposition: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPControls", "currentPosition", 13usize, |this| ((**(this as *const *const IWMPControls_Vtbl)).currentPosition)(this, pdcurrentposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetcurrentPosition(this: *mut core::ffi::c_void, dcurrentposition: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPControls", "SetcurrentPosition", 14usize, |this| ((**(this as *const *const IWMPControls_Vtbl)).SetcurrentPosition)(this, dcurrentposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn currentPositionString(this: *mut core::ffi::c_void, pbstrcurrentposition: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPControls", "currentPositionString", 15usize, |this| ((**(this as *const *const IWMPControls_Vtbl)).currentPositionString)(this, pbstrcurrentposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn next(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPControls", "next", 16usize, |this| ((**(this as *const *const IWMPControls_Vtbl)).next)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn previous(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPControls", "previous", 17usize, |this| ((**(this as *const *const IWMPControls_Vtbl)).previous)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn currentItem(this: *mut core::ffi::c_void, ppiwmpmedia: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPControls", "currentItem", 18usize, |this| ((**(this as *const *const IWMPControls_Vtbl)).currentItem)(this, ppiwmpmedia), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetcurrentItem(this: *mut core::ffi::c_void, piwmpmedia: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPControls", "SetcurrentItem", 19usize, |this| ((**(this as *const *const IWMPControls_Vtbl)).SetcurrentItem)(this, piwmpmedia), |result| Some(*result))
+        }
+        unsafe extern "system" fn currentMarker(this: *mut core::ffi::c_void, plmarker: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPControls", "currentMarker", 20usize, |this| ((**(this as *const *const IWMPControls_Vtbl)).currentMarker)(this, plmarker), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetcurrentMarker(this: *mut core::ffi::c_void, lmarker: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPControls", "SetcurrentMarker", 21usize, |this| ((**(this as *const *const IWMPControls_Vtbl)).SetcurrentMarker)(this, lmarker), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn playItem(this: *mut core::ffi::c_void, piwmpmedia: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPControls", "playItem", 22usize, |this| ((**(this as *const *const IWMPControls_Vtbl)).playItem)(this, piwmpmedia), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            get_isAvailable,
+            play,
+            stop,
+            pause,
+            fastForward,
+            fastReverse,
+            currentPosition,
+            SetcurrentPosition,
+            currentPositionString,
+            next,
+            previous,
+            #[cfg(feature = "Win32_System_Com")]
+            currentItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            currentItem: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetcurrentItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetcurrentItem: 0,
+            currentMarker,
+            SetcurrentMarker,
+            #[cfg(feature = "Win32_System_Com")]
+            playItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            playItem: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPControls as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPControls2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn step(this: *mut core::ffi::c_void, lstep: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPControls2", "step", 23usize, |this| ((**(this as *const *const IWMPControls2_Vtbl)).step)(this, lstep), |result| Some(*result))
+        }
+        Self { base__: <IWMPControls_Vtbl as windows_core::imp::ProxyVtable>::PROXY, step }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPControls2 as windows_core::Interface>::IID || <IWMPControls_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPControls3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn audioLanguageCount(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPControls3", "audioLanguageCount", 24usize, |this| ((**(this as *const *const IWMPControls3_Vtbl)).audioLanguageCount)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn getAudioLanguageID(this: *mut core::ffi::c_void, lindex: i32, pllangid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPControls3", "getAudioLanguageID", 25usize, |this| ((**(this as *const *const IWMPControls3_Vtbl)).getAudioLanguageID)(this, lindex, pllangid), |result| Some(*result))
+        }
+        unsafe extern "system" fn getAudioLanguageDescription(this: *mut core::ffi::c_void, lindex: i32, pbstrlangdesc: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPControls3", "getAudioLanguageDescription", 26usize, |this| ((**(this as *const *const IWMPControls3_Vtbl)).getAudioLanguageDescription)(this, lindex, pbstrlangdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn currentAudioLanguage(this: *mut core::ffi::c_void, pllangid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPControls3", "currentAudioLanguage", 27usize, |this| ((**(this as *const *const IWMPControls3_Vtbl)).currentAudioLanguage)(this, pllangid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetcurrentAudioLanguage(this: *mut core::ffi::c_void, llangid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPControls3", "SetcurrentAudioLanguage", 28usize, |this| ((**(this as *const *const IWMPControls3_Vtbl)).SetcurrentAudioLanguage)(this, llangid), |result| Some(*result))
+        }
+        unsafe extern "system" fn currentAudioLanguageIndex(this: *mut core::ffi::c_void, plindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPControls3", "currentAudioLanguageIndex", 29usize, |this| ((**(this as *const *const IWMPControls3_Vtbl)).currentAudioLanguageIndex)(this, plindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetcurrentAudioLanguageIndex(this: *mut core::ffi::c_void, lindex: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPControls3", "SetcurrentAudioLanguageIndex", 30usize, |this| ((**(this as *const *const IWMPControls3_Vtbl)).SetcurrentAudioLanguageIndex)(this, lindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn getLanguageName(this: *mut core::ffi::c_void, llangid: i32, pbstrlangname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPControls3", "getLanguageName", 31usize, |this| ((**(this as *const *const IWMPControls3_Vtbl)).getLanguageName)(this, llangid, pbstrlangname), |result| Some(*result))
+        }
+        unsafe extern "system" fn currentPositionTimecode(this: *mut core::ffi::c_void, bstrtimecode: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPControls3", "currentPositionTimecode", 32usize, |this| ((**(this as *const *const IWMPControls3_Vtbl)).currentPositionTimecode)(this, bstrtimecode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetcurrentPositionTimecode(this: *mut core::ffi::c_void, bstrtimecode: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPControls3", "SetcurrentPositionTimecode", 33usize, |this| ((**(this as *const *const IWMPControls3_Vtbl)).SetcurrentPositionTimecode)(this, bstrtimecode), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMPControls2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            audioLanguageCount,
+            getAudioLanguageID,
+            getAudioLanguageDescription,
+            currentAudioLanguage,
+            SetcurrentAudioLanguage,
+            currentAudioLanguageIndex,
+            SetcurrentAudioLanguageIndex,
+            getLanguageName,
+            currentPositionTimecode,
+            SetcurrentPositionTimecode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPControls3 as windows_core::Interface>::IID || <IWMPControls2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPConvert_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConvertFile(this: *mut core::ffi::c_void, bstrinputfile: core::mem::MaybeUninit<windows_core::BSTR>, bstrdestinationfolder: core::mem::MaybeUninit<windows_core::BSTR>, pbstroutputfile: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPConvert", "ConvertFile", 3usize, |this| ((**(this as *const *const IWMPConvert_Vtbl)).ConvertFile)(this, bstrinputfile, bstrdestinationfolder, pbstroutputfile), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetErrorURL(this: *mut core::ffi::c_void, pbstrurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPConvert", "GetErrorURL", 4usize, |this| ((**(this as *const *const IWMPConvert_Vtbl)).GetErrorURL)(this, pbstrurl), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ConvertFile, GetErrorURL }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPConvert as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPCore_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCore", "close", 7usize, |this| ((**(this as *const *const IWMPCore_Vtbl)).close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn URL(this: *mut core::ffi::c_void, pbstrurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCore", "URL", 8usize, |this| ((**(this as *const *const IWMPCore_Vtbl)).URL)(this, pbstrurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetURL(this: *mut core::ffi::c_void, bstrurl: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCore", "SetURL", 9usize, |this| ((**(this as *const *const IWMPCore_Vtbl)).SetURL)(this, bstrurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn openState(this: *mut core::ffi::c_void, pwmpos: *mut WMPOpenState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCore", "openState", 10usize, |this| ((**(this as *const *const IWMPCore_Vtbl)).openState)(this, pwmpos), |result| Some(*result))
+        }
+        unsafe extern "system" fn playState(this: *mut core::ffi::c_void, pwmpps: *mut WMPPlayState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCore", "playState", 11usize, |this| ((**(this as *const *const IWMPCore_Vtbl)).playState)(this, pwmpps), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn controls(this: *mut core::ffi::c_void, ppcontrol: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCore", "controls", 12usize, |this| ((**(this as *const *const IWMPCore_Vtbl)).controls)(this, ppcontrol), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn settings(this: *mut core::ffi::c_void, ppsettings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCore", "settings", 13usize, |this| ((**(this as *const *const IWMPCore_Vtbl)).settings)(this, ppsettings), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn currentMedia(this: *mut core::ffi::c_void, ppmedia: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCore", "currentMedia", 14usize, |this| ((**(this as *const *const IWMPCore_Vtbl)).currentMedia)(this, ppmedia), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetcurrentMedia(this: *mut core::ffi::c_void, pmedia: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCore", "SetcurrentMedia", 15usize, |this| ((**(this as *const *const IWMPCore_Vtbl)).SetcurrentMedia)(this, pmedia), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn mediaCollection(this: *mut core::ffi::c_void, ppmediacollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCore", "mediaCollection", 16usize, |this| ((**(this as *const *const IWMPCore_Vtbl)).mediaCollection)(this, ppmediacollection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn playlistCollection(this: *mut core::ffi::c_void, ppplaylistcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCore", "playlistCollection", 17usize, |this| ((**(this as *const *const IWMPCore_Vtbl)).playlistCollection)(this, ppplaylistcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn versionInfo(this: *mut core::ffi::c_void, pbstrversioninfo: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCore", "versionInfo", 18usize, |this| ((**(this as *const *const IWMPCore_Vtbl)).versionInfo)(this, pbstrversioninfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn launchURL(this: *mut core::ffi::c_void, bstrurl: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCore", "launchURL", 19usize, |this| ((**(this as *const *const IWMPCore_Vtbl)).launchURL)(this, bstrurl), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn network(this: *mut core::ffi::c_void, ppqni: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCore", "network", 20usize, |this| ((**(this as *const *const IWMPCore_Vtbl)).network)(this, ppqni), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn currentPlaylist(this: *mut core::ffi::c_void, pppl: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCore", "currentPlaylist", 21usize, |this| ((**(this as *const *const IWMPCore_Vtbl)).currentPlaylist)(this, pppl), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetcurrentPlaylist(this: *mut core::ffi::c_void, ppl: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCore", "SetcurrentPlaylist", 22usize, |this| ((**(this as *const *const IWMPCore_Vtbl)).SetcurrentPlaylist)(this, ppl), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn cdromCollection(this: *mut core::ffi::c_void, ppcdromcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCore", "cdromCollection", 23usize, |this| ((**(this as *const *const IWMPCore_Vtbl)).cdromCollection)(this, ppcdromcollection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn closedCaption(this: *mut core::ffi::c_void, ppclosedcaption: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCore", "closedCaption", 24usize, |this| ((**(this as *const *const IWMPCore_Vtbl)).closedCaption)(this, ppclosedcaption), |result| Some(*result))
+        }
+        unsafe extern "system" fn isOnline(this: *mut core::ffi::c_void, pfonline: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCore", "isOnline", 25usize, |this| ((**(this as *const *const IWMPCore_Vtbl)).isOnline)(this, pfonline), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn error(this: *mut core::ffi::c_void, pperror: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCore", "error", 26usize, |this| ((**(this as *const *const IWMPCore_Vtbl)).error)(this, pperror), |result| Some(*result))
+        }
+        unsafe extern "system" fn status(this: *mut core::ffi::c_void, pbstrstatus: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCore", "status", 27usize, |this| ((**(this as *const *const IWMPCore_Vtbl)).status)(this, pbstrstatus), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            close,
+            URL,
+            SetURL,
+            openState,
+            playState,
+            #[cfg(feature = "Win32_System_Com")]
+            controls,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            controls: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            settings,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            settings: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            currentMedia,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            currentMedia: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetcurrentMedia,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetcurrentMedia: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            mediaCollection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            mediaCollection: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            playlistCollection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            playlistCollection: 0,
+            versionInfo,
+            launchURL,
+            #[cfg(feature = "Win32_System_Com")]
+            network,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            network: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            currentPlaylist,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            currentPlaylist: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetcurrentPlaylist,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetcurrentPlaylist: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            cdromCollection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            cdromCollection: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            closedCaption,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            closedCaption: 0,
+            isOnline,
+            #[cfg(feature = "Win32_System_Com")]
+            error,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            error: 0,
+            status,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPCore as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPCore2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn dvd(this: *mut core::ffi::c_void, ppdvd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCore2", "dvd", 28usize, |this| ((**(this as *const *const IWMPCore2_Vtbl)).dvd)(this, ppdvd), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMPCore_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            dvd,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            dvd: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPCore2 as windows_core::Interface>::IID || <IWMPCore_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPCore3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn newPlaylist(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, bstrurl: core::mem::MaybeUninit<windows_core::BSTR>, ppplaylist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCore3", "newPlaylist", 29usize, |this| ((**(this as *const *const IWMPCore3_Vtbl)).newPlaylist)(this, bstrname, bstrurl, ppplaylist), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn newMedia(this: *mut core::ffi::c_void, bstrurl: core::mem::MaybeUninit<windows_core::BSTR>, ppmedia: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPCore3", "newMedia", 30usize, |this| ((**(this as *const *const IWMPCore3_Vtbl)).newMedia)(this, bstrurl, ppmedia), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMPCore2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            newPlaylist,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            newPlaylist: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            newMedia,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            newMedia: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPCore3 as windows_core::Interface>::IID || <IWMPCore2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPDVD_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_isAvailable(this: *mut core::ffi::c_void, bstritem: core::mem::MaybeUninit<windows_core::BSTR>, pisavailable: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPDVD", "get_isAvailable", 7usize, |this| ((**(this as *const *const IWMPDVD_Vtbl)).get_isAvailable)(this, bstritem, pisavailable), |result| Some(*result))
+        }
+        unsafe extern "system" fn domain(this: *mut core::ffi::c_void, strdomain: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPDVD", "domain", 8usize, |this| ((**(this as *const *const IWMPDVD_Vtbl)).domain)(this, strdomain), |result| Some(*result))
+        }
+        unsafe extern "system" fn topMenu(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPDVD", "topMenu", 9usize, |this| ((**(this as *const *const IWMPDVD_Vtbl)).topMenu)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn titleMenu(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPDVD", "titleMenu", 10usize, |this| ((**(this as *const *const IWMPDVD_Vtbl)).titleMenu)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn back(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPDVD", "back", 11usize, |this| ((**(this as *const *const IWMPDVD_Vtbl)).back)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPDVD", "resume", 12usize, |this| ((**(this as *const *const IWMPDVD_Vtbl)).resume)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            get_isAvailable,
+            domain,
+            topMenu,
+            titleMenu,
+            back,
+            resume,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPDVD as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPDownloadCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn id(this: *mut core::ffi::c_void, plid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPDownloadCollection", "id", 7usize, |this| ((**(this as *const *const IWMPDownloadCollection_Vtbl)).id)(this, plid), |result| Some(*result))
+        }
+        unsafe extern "system" fn count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPDownloadCollection", "count", 8usize, |this| ((**(this as *const *const IWMPDownloadCollection_Vtbl)).count)(this, plcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn item(this: *mut core::ffi::c_void, litem: i32, ppdownload: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPDownloadCollection", "item", 9usize, |this| ((**(this as *const *const IWMPDownloadCollection_Vtbl)).item)(this, litem, ppdownload), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn startDownload(this: *mut core::ffi::c_void, bstrsourceurl: core::mem::MaybeUninit<windows_core::BSTR>, bstrtype: core::mem::MaybeUninit<windows_core::BSTR>, ppdownload: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPDownloadCollection", "startDownload", 10usize, |this| ((**(this as *const *const IWMPDownloadCollection_Vtbl)).startDownload)(this, bstrsourceurl, bstrtype, ppdownload), |result| Some(*result))
+        }
+        unsafe extern "system" fn removeItem(this: *mut core::ffi::c_void, litem: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPDownloadCollection", "removeItem", 11usize, |this| ((**(this as *const *const IWMPDownloadCollection_Vtbl)).removeItem)(this, litem), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPDownloadCollection", "Clear", 12usize, |this| ((**(this as *const *const IWMPDownloadCollection_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            id,
+            count,
+            #[cfg(feature = "Win32_System_Com")]
+            item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            startDownload,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            startDownload: 0,
+            removeItem,
+            Clear,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPDownloadCollection as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPDownloadItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn sourceURL(this: *mut core::ffi::c_void, pbstrurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPDownloadItem", "sourceURL", 7usize, |this| ((**(this as *const *const IWMPDownloadItem_Vtbl)).sourceURL)(this, pbstrurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn size(this: *mut core::ffi::c_void, plsize: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPDownloadItem", "size", 8usize, |this| ((**(this as *const *const IWMPDownloadItem_Vtbl)).size)(this, plsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn r#type(this: *mut core::ffi::c_void, pbstrtype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPDownloadItem", "r#type", 9usize, |this| ((**(this as *const *const IWMPDownloadItem_Vtbl)).r#type)(this, pbstrtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn progress(this: *mut core::ffi::c_void, plprogress: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPDownloadItem", "progress", 10usize, |this| ((**(this as *const *const IWMPDownloadItem_Vtbl)).progress)(this, plprogress), |result| Some(*result))
+        }
+        unsafe extern "system" fn downloadState(this: *mut core::ffi::c_void, pwmpsdls: *mut WMPSubscriptionDownloadState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPDownloadItem", "downloadState", 11usize, |this| ((**(this as *const *const IWMPDownloadItem_Vtbl)).downloadState)(this, pwmpsdls), |result| Some(*result))
+        }
+        unsafe extern "system" fn pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPDownloadItem", "pause", 12usize, |this| ((**(this as *const *const IWMPDownloadItem_Vtbl)).pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPDownloadItem", "resume", 13usize, |this| ((**(this as *const *const IWMPDownloadItem_Vtbl)).resume)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPDownloadItem", "cancel", 14usize, |this| ((**(this as *const *const IWMPDownloadItem_Vtbl)).cancel)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            sourceURL,
+            size,
+            r#type,
+            progress,
+            downloadState,
+            pause,
+            resume,
+            cancel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPDownloadItem as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPDownloadItem2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn getItemInfo(this: *mut core::ffi::c_void, bstritemname: core::mem::MaybeUninit<windows_core::BSTR>, pbstrval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPDownloadItem2", "getItemInfo", 15usize, |this| ((**(this as *const *const IWMPDownloadItem2_Vtbl)).getItemInfo)(this, bstritemname, pbstrval), |result| Some(*result))
+        }
+        Self { base__: <IWMPDownloadItem_Vtbl as windows_core::imp::ProxyVtable>::PROXY, getItemInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPDownloadItem2 as windows_core::Interface>::IID || <IWMPDownloadItem_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPDownloadManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn getDownloadCollection(this: *mut core::ffi::c_void, lcollectionid: i32, ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPDownloadManager", "getDownloadCollection", 7usize, |this| ((**(this as *const *const IWMPDownloadManager_Vtbl)).getDownloadCollection)(this, lcollectionid, ppcollection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn createDownloadCollection(this: *mut core::ffi::c_void, ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPDownloadManager", "createDownloadCollection", 8usize, |this| ((**(this as *const *const IWMPDownloadManager_Vtbl)).createDownloadCollection)(this, ppcollection), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            getDownloadCollection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            getDownloadCollection: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            createDownloadCollection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            createDownloadCollection: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPDownloadManager as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IWMPEffects_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn Render(this: *mut core::ffi::c_void, plevels: *mut TimedLevel, hdc: super::super::Graphics::Gdi::HDC, prc: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPEffects", "Render", 3usize, |this| ((**(this as *const *const IWMPEffects_Vtbl)).Render)(this, plevels, hdc, prc), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaInfo(this: *mut core::ffi::c_void, lchannelcount: i32, lsamplerate: i32, bstrtitle: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPEffects", "MediaInfo", 4usize, |this| ((**(this as *const *const IWMPEffects_Vtbl)).MediaInfo)(this, lchannelcount, lsamplerate, bstrtitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCapabilities(this: *mut core::ffi::c_void, pdwcapabilities: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPEffects", "GetCapabilities", 5usize, |this| ((**(this as *const *const IWMPEffects_Vtbl)).GetCapabilities)(this, pdwcapabilities), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTitle(this: *mut core::ffi::c_void, bstrtitle: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPEffects", "GetTitle", 6usize, |this| ((**(this as *const *const IWMPEffects_Vtbl)).GetTitle)(this, bstrtitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPresetTitle(this: *mut core::ffi::c_void, npreset: i32, bstrpresettitle: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPEffects", "GetPresetTitle", 7usize, |this| ((**(this as *const *const IWMPEffects_Vtbl)).GetPresetTitle)(this, npreset, bstrpresettitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPresetCount(this: *mut core::ffi::c_void, pnpresetcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPEffects", "GetPresetCount", 8usize, |this| ((**(this as *const *const IWMPEffects_Vtbl)).GetPresetCount)(this, pnpresetcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentPreset(this: *mut core::ffi::c_void, npreset: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPEffects", "SetCurrentPreset", 9usize, |this| ((**(this as *const *const IWMPEffects_Vtbl)).SetCurrentPreset)(this, npreset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentPreset(this: *mut core::ffi::c_void, pnpreset: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPEffects", "GetCurrentPreset", 10usize, |this| ((**(this as *const *const IWMPEffects_Vtbl)).GetCurrentPreset)(this, pnpreset), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayPropertyPage(this: *mut core::ffi::c_void, hwndowner: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPEffects", "DisplayPropertyPage", 11usize, |this| ((**(this as *const *const IWMPEffects_Vtbl)).DisplayPropertyPage)(this, hwndowner), |result| Some(*result))
+        }
+        unsafe extern "system" fn GoFullscreen(this: *mut core::ffi::c_void, ffullscreen: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPEffects", "GoFullscreen", 12usize, |this| ((**(this as *const *const IWMPEffects_Vtbl)).GoFullscreen)(this, ffullscreen), |result| Some(*result))
+        }
+        unsafe extern "system" fn RenderFullScreen(this: *mut core::ffi::c_void, plevels: *mut TimedLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPEffects", "RenderFullScreen", 13usize, |this| ((**(this as *const *const IWMPEffects_Vtbl)).RenderFullScreen)(this, plevels), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            Render,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            Render: 0,
+            MediaInfo,
+            GetCapabilities,
+            GetTitle,
+            GetPresetTitle,
+            GetPresetCount,
+            SetCurrentPreset,
+            GetCurrentPreset,
+            DisplayPropertyPage,
+            GoFullscreen,
+            RenderFullScreen,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPEffects as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IWMPEffects2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetCore(this: *mut core::ffi::c_void, pplayer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPEffects2", "SetCore", 14usize, |this| ((**(this as *const *const IWMPEffects2_Vtbl)).SetCore)(this, pplayer), |result| Some(*result))
+        }
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPEffects2", "Create", 15usize, |this| ((**(this as *const *const IWMPEffects2_Vtbl)).Create)(this, hwndparent), |result| Some(*result))
+        }
+        unsafe extern "system" fn Destroy(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPEffects2", "Destroy", 16usize, |this| ((**(this as *const *const IWMPEffects2_Vtbl)).Destroy)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn NotifyNewMedia(this: *mut core::ffi::c_void, pmedia: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPEffects2", "NotifyNewMedia", 17usize, |this| ((**(this as *const *const IWMPEffects2_Vtbl)).NotifyNewMedia)(this, pmedia), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnWindowMessage(this: *mut core::ffi::c_void, msg: u32, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM, plresultparam: *mut super::super::Foundation::LRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPEffects2", "OnWindowMessage", 18usize, |this| ((**(this as *const *const IWMPEffects2_Vtbl)).OnWindowMessage)(this, msg, wparam, lparam, plresultparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn RenderWindowed(this: *mut core::ffi::c_void, pdata: *mut TimedLevel, frequiredrender: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPEffects2", "RenderWindowed", 19usize, |this| ((**(this as *const *const IWMPEffects2_Vtbl)).RenderWindowed)(this, pdata, frequiredrender), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMPEffects_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            SetCore,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetCore: 0,
+            Create,
+            Destroy,
+            #[cfg(feature = "Win32_System_Com")]
+            NotifyNewMedia,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            NotifyNewMedia: 0,
+            OnWindowMessage,
+            RenderWindowed,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPEffects2 as windows_core::Interface>::IID || <IWMPEffects_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPError_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn clearErrorQueue(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPError", "clearErrorQueue", 7usize, |this| ((**(this as *const *const IWMPError_Vtbl)).clearErrorQueue)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn errorCount(this: *mut core::ffi::c_void, plnumerrors: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPError", "errorCount", 8usize, |this| ((**(this as *const *const IWMPError_Vtbl)).errorCount)(this, plnumerrors), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_item(this: *mut core::ffi::c_void, dwindex: i32, pperroritem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPError", "get_item", 9usize, |this| ((**(this as *const *const IWMPError_Vtbl)).get_item)(this, dwindex, pperroritem), |result| Some(*result))
+        }
+        unsafe extern "system" fn webHelp(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPError", "webHelp", 10usize, |this| ((**(this as *const *const IWMPError_Vtbl)).webHelp)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            clearErrorQueue,
+            errorCount,
+            #[cfg(feature = "Win32_System_Com")]
+            get_item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_item: 0,
+            webHelp,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPError as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPErrorItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn errorCode(this: *mut core::ffi::c_void, phr: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPErrorItem", "errorCode", 7usize, |this| ((**(this as *const *const IWMPErrorItem_Vtbl)).errorCode)(this, phr), |result| Some(*result))
+        }
+        unsafe extern "system" fn errorDescription(this: *mut core::ffi::c_void, pbstrdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPErrorItem", "errorDescription", 8usize, |this| ((**(this as *const *const IWMPErrorItem_Vtbl)).errorDescription)(this, pbstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn errorContext(this: *mut core::ffi::c_void, pvarcontext: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPErrorItem", "errorContext", 9usize, |this| ((**(this as *const *const IWMPErrorItem_Vtbl)).errorContext)(this, pvarcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn remedy(this: *mut core::ffi::c_void, plremedy: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPErrorItem", "remedy", 10usize, |this| ((**(this as *const *const IWMPErrorItem_Vtbl)).remedy)(this, plremedy), |result| Some(*result))
+        }
+        unsafe extern "system" fn customUrl(this: *mut core::ffi::c_void, pbstrcustomurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPErrorItem", "customUrl", 11usize, |this| ((**(this as *const *const IWMPErrorItem_Vtbl)).customUrl)(this, pbstrcustomurl), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            errorCode,
+            errorDescription,
+            errorContext,
+            remedy,
+            customUrl,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPErrorItem as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPErrorItem2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn condition(this: *mut core::ffi::c_void, plcondition: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPErrorItem2", "condition", 12usize, |this| ((**(this as *const *const IWMPErrorItem2_Vtbl)).condition)(this, plcondition), |result| Some(*result))
+        }
+        Self { base__: <IWMPErrorItem_Vtbl as windows_core::imp::ProxyVtable>::PROXY, condition }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPErrorItem2 as windows_core::Interface>::IID || <IWMPErrorItem_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenStateChange(this: *mut core::ffi::c_void, newstate: i32) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "OpenStateChange", 3usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).OpenStateChange)(this, newstate), |_| None)
+        }
+        unsafe extern "system" fn PlayStateChange(this: *mut core::ffi::c_void, newstate: i32) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "PlayStateChange", 4usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).PlayStateChange)(this, newstate), |_| None)
+        }
+        unsafe extern "system" fn AudioLanguageChange(this: *mut core::ffi::c_void, langid: i32) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "AudioLanguageChange", 5usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).AudioLanguageChange)(this, langid), |_| None)
+        }
+        unsafe extern "system" fn StatusChange(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "StatusChange", 6usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).StatusChange)(this), |_| None)
+        }
+        unsafe extern "system" fn ScriptCommand(this: *mut core::ffi::c_void, sctype: core::mem::MaybeUninit<windows_core::BSTR>, param: core::mem::MaybeUninit<windows_core::BSTR>) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "ScriptCommand", 7usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).ScriptCommand)(this, sctype, param), |_| None)
+        }
+        unsafe extern "system" fn NewStream(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "NewStream", 8usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).NewStream)(this), |_| None)
+        }
+        unsafe extern "system" fn Disconnect(this: *mut core::ffi::c_void, result: i32) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "Disconnect", 9usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).Disconnect)(this, result), |_| None)
+        }
+        unsafe extern "system" fn Buffering(this: *mut core::ffi::c_void, start: super::super::Foundation::VARIANT_BOOL) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "Buffering", 10usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).Buffering)(this, start), |_| None)
+        }
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "Error", 11usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).Error)(this), |_| None)
+        }
+        unsafe extern "system" fn Warning(this: *mut core::ffi::c_void, warningtype: i32, param: i32, description: core::mem::MaybeUninit<windows_core::BSTR>) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "Warning", 12usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).Warning)(this, warningtype, param, description), |_| None)
+        }
+        unsafe extern "system" fn EndOfStream(this: *mut core::ffi::c_void, result: i32) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "EndOfStream", 13usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).EndOfStream)(this, result), |_| None)
+        }
+        unsafe extern "system" fn PositionChange(this: *mut core::ffi::c_void, oldposition: f64, newposition: f64) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "PositionChange", 14usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).PositionChange)(this, oldposition, newposition), |_| None)
+        }
+        unsafe extern "system" fn MarkerHit(this: *mut core::ffi::c_void, markernum: i32) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "MarkerHit", 15usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).MarkerHit)(this, markernum), |_| None)
+        }
+        unsafe extern "system" fn DurationUnitChange(this: *mut core::ffi::c_void, newdurationunit: i32) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "DurationUnitChange", 16usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).DurationUnitChange)(this, newdurationunit), |_| None)
+        }
+        unsafe extern "system" fn CdromMediaChange(this: *mut core::ffi::c_void, cdromnum: i32) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "CdromMediaChange", 17usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).CdromMediaChange)(this, cdromnum), |_| None)
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PlaylistChange(this: *mut core::ffi::c_void, playlist: *mut core::ffi::c_void, change: WMPPlaylistChangeEventType) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "PlaylistChange", 18usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).PlaylistChange)(this, playlist, change), |_| None)
+        }
+        unsafe extern "system" fn CurrentPlaylistChange(this: *mut core::ffi::c_void, change: WMPPlaylistChangeEventType) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "CurrentPlaylistChange", 19usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).CurrentPlaylistChange)(this, change), |_| None)
+        }
+        unsafe extern "system" fn CurrentPlaylistItemAvailable(this: *mut core::ffi::c_void, bstritemname: core::mem::MaybeUninit<windows_core::BSTR>) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "CurrentPlaylistItemAvailable", 20usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).CurrentPlaylistItemAvailable)(this, bstritemname), |_| None)
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn MediaChange(this: *mut core::ffi::c_void, item: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "MediaChange", 21usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).MediaChange)(this, item), |_| None)
+        }
+        unsafe extern "system" fn CurrentMediaItemAvailable(this: *mut core::ffi::c_void, bstritemname: core::mem::MaybeUninit<windows_core::BSTR>) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "CurrentMediaItemAvailable", 22usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).CurrentMediaItemAvailable)(this, bstritemname), |_| None)
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CurrentItemChange(this: *mut core::ffi::c_void, pdispmedia: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "CurrentItemChange", 23usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).CurrentItemChange)(this, pdispmedia), |_| None)
+        }
+        unsafe extern "system" fn MediaCollectionChange(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "MediaCollectionChange", 24usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).MediaCollectionChange)(this), |_| None)
+        }
+        unsafe extern "system" fn MediaCollectionAttributeStringAdded(this: *mut core::ffi::c_void, bstrattribname: core::mem::MaybeUninit<windows_core::BSTR>, bstrattribval: core::mem::MaybeUninit<windows_core::BSTR>) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "MediaCollectionAttributeStringAdded", 25usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).MediaCollectionAttributeStringAdded)(this, bstrattribname, bstrattribval), |_| None)
+        }
+        unsafe extern "system" fn MediaCollectionAttributeStringRemoved(this: *mut core::ffi::c_void, bstrattribname: core::mem::MaybeUninit<windows_core::BSTR>, bstrattribval: core::mem::MaybeUninit<windows_core::BSTR>) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "MediaCollectionAttributeStringRemoved", 26usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).MediaCollectionAttributeStringRemoved)(this, bstrattribname, bstrattribval), |_| None)
+        }
+        unsafe extern "system" fn MediaCollectionAttributeStringChanged(this: *mut core::ffi::c_void, bstrattribname: core::mem::MaybeUninit<windows_core::BSTR>, bstroldattribval: core::mem::MaybeUninit<windows_core::BSTR>, bstrnewattribval: core::mem::MaybeUninit<windows_core::BSTR>) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "MediaCollectionAttributeStringChanged", 27usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).MediaCollectionAttributeStringChanged)(this, bstrattribname, bstroldattribval, bstrnewattribval), |_| None)
+        }
+        unsafe extern "system" fn PlaylistCollectionChange(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "PlaylistCollectionChange", 28usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).PlaylistCollectionChange)(this), |_| None)
+        }
+        unsafe extern "system" fn PlaylistCollectionPlaylistAdded(this: *mut core::ffi::c_void, bstrplaylistname: core::mem::MaybeUninit<windows_core::BSTR>) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "PlaylistCollectionPlaylistAdded", 29usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).PlaylistCollectionPlaylistAdded)(this, bstrplaylistname), |_| None)
+        }
+        unsafe extern "system" fn PlaylistCollectionPlaylistRemoved(this: *mut core::ffi::c_void, bstrplaylistname: core::mem::MaybeUninit<windows_core::BSTR>) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "PlaylistCollectionPlaylistRemoved", 30usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).PlaylistCollectionPlaylistRemoved)(this, bstrplaylistname), |_| None)
+        }
+        unsafe extern "system" fn PlaylistCollectionPlaylistSetAsDeleted(this: *mut core::ffi::c_void, bstrplaylistname: core::mem::MaybeUninit<windows_core::BSTR>, varfisdeleted: super::super::Foundation::VARIANT_BOOL) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "PlaylistCollectionPlaylistSetAsDeleted", 31usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).PlaylistCollectionPlaylistSetAsDeleted)(this, bstrplaylistname, varfisdeleted), |_| None)
+        }
+        unsafe extern "system" fn ModeChange(this: *mut core::ffi::c_void, modename: core::mem::MaybeUninit<windows_core::BSTR>, newvalue: super::super::Foundation::VARIANT_BOOL) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "ModeChange", 32usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).ModeChange)(this, modename, newvalue), |_| None)
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn MediaError(this: *mut core::ffi::c_void, pmediaobject: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "MediaError", 33usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).MediaError)(this, pmediaobject), |_| None)
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OpenPlaylistSwitch(this: *mut core::ffi::c_void, pitem: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "OpenPlaylistSwitch", 34usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).OpenPlaylistSwitch)(this, pitem), |_| None)
+        }
+        unsafe extern "system" fn DomainChange(this: *mut core::ffi::c_void, strdomain: core::mem::MaybeUninit<windows_core::BSTR>) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "DomainChange", 35usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).DomainChange)(this, strdomain), |_| None)
+        }
+        unsafe extern "system" fn SwitchedToPlayerApplication(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "SwitchedToPlayerApplication", 36usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).SwitchedToPlayerApplication)(this), |_| None)
+        }
+        unsafe extern "system" fn SwitchedToControl(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "SwitchedToControl", 37usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).SwitchedToControl)(this), |_| None)
+        }
+        unsafe extern "system" fn PlayerDockedStateChange(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "PlayerDockedStateChange", 38usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).PlayerDockedStateChange)(this), |_| None)
+        }
+        unsafe extern "system" fn PlayerReconnect(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "PlayerReconnect", 39usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).PlayerReconnect)(this), |_| None)
+        }
+        unsafe extern "system" fn Click(this: *mut core::ffi::c_void, nbutton: i16, nshiftstate: i16, fx: i32, fy: i32) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "Click", 40usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).Click)(this, nbutton, nshiftstate, fx, fy), |_| None)
+        }
+        unsafe extern "system" fn DoubleClick(this: *mut core::ffi::c_void, nbutton: i16, nshiftstate: i16, fx: i32, fy: i32) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "DoubleClick", 41usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).DoubleClick)(this, nbutton, nshiftstate, fx, fy), |_| None)
+        }
+        unsafe extern "system" fn KeyDown(this: *mut core::ffi::c_void, nkeycode: i16, nshiftstate: i16) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "KeyDown", 42usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).KeyDown)(this, nkeycode, nshiftstate), |_| None)
+        }
+        unsafe extern "system" fn KeyPress(this: *mut core::ffi::c_void, nkeyascii: i16) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "KeyPress", 43usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).KeyPress)(this, nkeyascii), |_| None)
+        }
+        unsafe extern "system" fn KeyUp(this: *mut core::ffi::c_void, nkeycode: i16, nshiftstate: i16) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "KeyUp", 44usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).KeyUp)(this, nkeycode, nshiftstate), |_| None)
+        }
+        unsafe extern "system" fn MouseDown(this: *mut core::ffi::c_void, nbutton: i16, nshiftstate: i16, fx: i32, fy: i32) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "MouseDown", 45usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).MouseDown)(this, nbutton, nshiftstate, fx, fy), |_| None)
+        }
+        unsafe extern "system" fn MouseMove(this: *mut core::ffi::c_void, nbutton: i16, nshiftstate: i16, fx: i32, fy: i32) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "MouseMove", 46usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).MouseMove)(this, nbutton, nshiftstate, fx, fy), |_| None)
+        }
+        unsafe extern "system" fn MouseUp(this: *mut core::ffi::c_void, nbutton: i16, nshiftstate: i16, fx: i32, fy: i32) {
+            windows_core::imp::proxy_call(this, "IWMPEvents", "MouseUp", 47usize, |this| ((**(this as *const *const IWMPEvents_Vtbl)).MouseUp)(this, nbutton, nshiftstate, fx, fy), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OpenStateChange,
+            PlayStateChange,
+            AudioLanguageChange,
+            StatusChange,
+            ScriptCommand,
+            NewStream,
+            Disconnect,
+            Buffering,
+            Error,
+            Warning,
+            EndOfStream,
+            PositionChange,
+            MarkerHit,
+            DurationUnitChange,
+            CdromMediaChange,
+            #[cfg(feature = "Win32_System_Com")]
+            PlaylistChange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PlaylistChange: 0,
+            CurrentPlaylistChange,
+            CurrentPlaylistItemAvailable,
+            #[cfg(feature = "Win32_System_Com")]
+            MediaChange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            MediaChange: 0,
+            CurrentMediaItemAvailable,
+            #[cfg(feature = "Win32_System_Com")]
+            CurrentItemChange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CurrentItemChange: 0,
+            MediaCollectionChange,
+            MediaCollectionAttributeStringAdded,
+            MediaCollectionAttributeStringRemoved,
+            MediaCollectionAttributeStringChanged,
+            PlaylistCollectionChange,
+            PlaylistCollectionPlaylistAdded,
+            PlaylistCollectionPlaylistRemoved,
+            PlaylistCollectionPlaylistSetAsDeleted,
+            ModeChange,
+            #[cfg(feature = "Win32_System_Com")]
+            MediaError,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            MediaError: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OpenPlaylistSwitch,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OpenPlaylistSwitch: 0,
+            DomainChange,
+            SwitchedToPlayerApplication,
+            SwitchedToControl,
+            PlayerDockedStateChange,
+            PlayerReconnect,
+            Click,
+            DoubleClick,
+            KeyDown,
+            KeyPress,
+            KeyUp,
+            MouseDown,
+            MouseMove,
+            MouseUp,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPEvents2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceConnect(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IWMPEvents2", "DeviceConnect", 48usize, |this| ((**(this as *const *const IWMPEvents2_Vtbl)).DeviceConnect)(this, pdevice), |_| None)
+        }
+        unsafe extern "system" fn DeviceDisconnect(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IWMPEvents2", "DeviceDisconnect", 49usize, |this| ((**(this as *const *const IWMPEvents2_Vtbl)).DeviceDisconnect)(this, pdevice), |_| None)
+        }
+        unsafe extern "system" fn DeviceStatusChange(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, newstatus: WMPDeviceStatus) {
+            windows_core::imp::proxy_call(this, "IWMPEvents2", "DeviceStatusChange", 50usize, |this| ((**(this as *const *const IWMPEvents2_Vtbl)).DeviceStatusChange)(this, pdevice, newstatus), |_| None)
+        }
+        unsafe extern "system" fn DeviceSyncStateChange(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, newstate: WMPSyncState) {
+            windows_core::imp::proxy_call(this, "IWMPEvents2", "DeviceSyncStateChange", 51usize, |this| ((**(this as *const *const IWMPEvents2_Vtbl)).DeviceSyncStateChange)(this, pdevice, newstate), |_| None)
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DeviceSyncError(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, pmedia: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IWMPEvents2", "DeviceSyncError", 52usize, |this| ((**(this as *const *const IWMPEvents2_Vtbl)).DeviceSyncError)(this, pdevice, pmedia), |_| None)
+        }
+        unsafe extern "system" fn CreatePartnershipComplete(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, hrresult: windows_core::HRESULT) {
+            windows_core::imp::proxy_call(this, "IWMPEvents2", "CreatePartnershipComplete", 53usize, |this| ((**(this as *const *const IWMPEvents2_Vtbl)).CreatePartnershipComplete)(this, pdevice, hrresult), |_| None)
+        }
+        Self {
+            base__: <IWMPEvents_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeviceConnect,
+            DeviceDisconnect,
+            DeviceStatusChange,
+            DeviceSyncStateChange,
+            #[cfg(feature = "Win32_System_Com")]
+            DeviceSyncError,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DeviceSyncError: 0,
+            CreatePartnershipComplete,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPEvents2 as windows_core::Interface>::IID || <IWMPEvents_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPEvents3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CdromRipStateChange(this: *mut core::ffi::c_void, pcdromrip: *mut core::ffi::c_void, wmprs: WMPRipState) {
+            windows_core::imp::proxy_call(this, "IWMPEvents3", "CdromRipStateChange", 54usize, |this| ((**(this as *const *const IWMPEvents3_Vtbl)).CdromRipStateChange)(this, pcdromrip, wmprs), |_| None)
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CdromRipMediaError(this: *mut core::ffi::c_void, pcdromrip: *mut core::ffi::c_void, pmedia: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IWMPEvents3", "CdromRipMediaError", 55usize, |this| ((**(this as *const *const IWMPEvents3_Vtbl)).CdromRipMediaError)(this, pcdromrip, pmedia), |_| None)
+        }
+        unsafe extern "system" fn CdromBurnStateChange(this: *mut core::ffi::c_void, pcdromburn: *mut core::ffi::c_void, wmpbs: WMPBurnState) {
+            windows_core::imp::proxy_call(this, "IWMPEvents3", "CdromBurnStateChange", 56usize, |this| ((**(this as *const *const IWMPEvents3_Vtbl)).CdromBurnStateChange)(this, pcdromburn, wmpbs), |_| None)
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CdromBurnMediaError(this: *mut core::ffi::c_void, pcdromburn: *mut core::ffi::c_void, pmedia: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IWMPEvents3", "CdromBurnMediaError", 57usize, |this| ((**(this as *const *const IWMPEvents3_Vtbl)).CdromBurnMediaError)(this, pcdromburn, pmedia), |_| None)
+        }
+        unsafe extern "system" fn CdromBurnError(this: *mut core::ffi::c_void, pcdromburn: *mut core::ffi::c_void, hrerror: windows_core::HRESULT) {
+            windows_core::imp::proxy_call(this, "IWMPEvents3", "CdromBurnError", 58usize, |this| ((**(this as *const *const IWMPEvents3_Vtbl)).CdromBurnError)(this, pcdromburn, hrerror), |_| None)
+        }
+        unsafe extern "system" fn LibraryConnect(this: *mut core::ffi::c_void, plibrary: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IWMPEvents3", "LibraryConnect", 59usize, |this| ((**(this as *const *const IWMPEvents3_Vtbl)).LibraryConnect)(this, plibrary), |_| None)
+        }
+        unsafe extern "system" fn LibraryDisconnect(this: *mut core::ffi::c_void, plibrary: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IWMPEvents3", "LibraryDisconnect", 60usize, |this| ((**(this as *const *const IWMPEvents3_Vtbl)).LibraryDisconnect)(this, plibrary), |_| None)
+        }
+        unsafe extern "system" fn FolderScanStateChange(this: *mut core::ffi::c_void, wmpfss: WMPFolderScanState) {
+            windows_core::imp::proxy_call(this, "IWMPEvents3", "FolderScanStateChange", 61usize, |this| ((**(this as *const *const IWMPEvents3_Vtbl)).FolderScanStateChange)(this, wmpfss), |_| None)
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn StringCollectionChange(this: *mut core::ffi::c_void, pdispstringcollection: *mut core::ffi::c_void, change: WMPStringCollectionChangeEventType, lcollectionindex: i32) {
+            windows_core::imp::proxy_call(this, "IWMPEvents3", "StringCollectionChange", 62usize, |this| ((**(this as *const *const IWMPEvents3_Vtbl)).StringCollectionChange)(this, pdispstringcollection, change, lcollectionindex), |_| None)
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn MediaCollectionMediaAdded(this: *mut core::ffi::c_void, pdispmedia: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IWMPEvents3", "MediaCollectionMediaAdded", 63usize, |this| ((**(this as *const *const IWMPEvents3_Vtbl)).MediaCollectionMediaAdded)(this, pdispmedia), |_| None)
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn MediaCollectionMediaRemoved(this: *mut core::ffi::c_void, pdispmedia: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IWMPEvents3", "MediaCollectionMediaRemoved", 64usize, |this| ((**(this as *const *const IWMPEvents3_Vtbl)).MediaCollectionMediaRemoved)(this, pdispmedia), |_| None)
+        }
+        Self {
+            base__: <IWMPEvents2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CdromRipStateChange,
+            #[cfg(feature = "Win32_System_Com")]
+            CdromRipMediaError,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CdromRipMediaError: 0,
+            CdromBurnStateChange,
+            #[cfg(feature = "Win32_System_Com")]
+            CdromBurnMediaError,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CdromBurnMediaError: 0,
+            CdromBurnError,
+            LibraryConnect,
+            LibraryDisconnect,
+            FolderScanStateChange,
+            #[cfg(feature = "Win32_System_Com")]
+            StringCollectionChange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            StringCollectionChange: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            MediaCollectionMediaAdded,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            MediaCollectionMediaAdded: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            MediaCollectionMediaRemoved,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            MediaCollectionMediaRemoved: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPEvents3 as windows_core::Interface>::IID || <IWMPEvents2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPEvents4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceEstimation(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, hrresult: windows_core::HRESULT, qwestimatedusedspace: i64, qwestimatedspace: i64) {
+            windows_core::imp::proxy_call(this, "IWMPEvents4", "DeviceEstimation", 65usize, |this| ((**(this as *const *const IWMPEvents4_Vtbl)).DeviceEstimation)(this, pdevice, hrresult, qwestimatedusedspace, qwestimatedspace), |_| None)
+        }
+        Self { base__: <IWMPEvents3_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeviceEstimation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPEvents4 as windows_core::Interface>::IID || <IWMPEvents3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPFolderMonitorServices_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPFolderMonitorServices", "count", 3usize, |this| ((**(this as *const *const IWMPFolderMonitorServices_Vtbl)).count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn item(this: *mut core::ffi::c_void, lindex: i32, pbstrfolder: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPFolderMonitorServices", "item", 4usize, |this| ((**(this as *const *const IWMPFolderMonitorServices_Vtbl)).item)(this, lindex, pbstrfolder), |result| Some(*result))
+        }
+        unsafe extern "system" fn add(this: *mut core::ffi::c_void, bstrfolder: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPFolderMonitorServices", "add", 5usize, |this| ((**(this as *const *const IWMPFolderMonitorServices_Vtbl)).add)(this, bstrfolder), |result| Some(*result))
+        }
+        unsafe extern "system" fn remove(this: *mut core::ffi::c_void, lindex: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPFolderMonitorServices", "remove", 6usize, |this| ((**(this as *const *const IWMPFolderMonitorServices_Vtbl)).remove)(this, lindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn scanState(this: *mut core::ffi::c_void, pwmpfss: *mut WMPFolderScanState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPFolderMonitorServices", "scanState", 7usize, |this| ((**(this as *const *const IWMPFolderMonitorServices_Vtbl)).scanState)(this, pwmpfss), |result| Some(*result))
+        }
+        unsafe extern "system" fn currentFolder(this: *mut core::ffi::c_void, pbstrfolder: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPFolderMonitorServices", "currentFolder", 8usize, |this| ((**(this as *const *const IWMPFolderMonitorServices_Vtbl)).currentFolder)(this, pbstrfolder), |result| Some(*result))
+        }
+        unsafe extern "system" fn scannedFilesCount(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPFolderMonitorServices", "scannedFilesCount", 9usize, |this| ((**(this as *const *const IWMPFolderMonitorServices_Vtbl)).scannedFilesCount)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn addedFilesCount(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPFolderMonitorServices", "addedFilesCount", 10usize, |this| ((**(this as *const *const IWMPFolderMonitorServices_Vtbl)).addedFilesCount)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn updateProgress(this: *mut core::ffi::c_void, plprogress: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPFolderMonitorServices", "updateProgress", 11usize, |this| ((**(this as *const *const IWMPFolderMonitorServices_Vtbl)).updateProgress)(this, plprogress), |result| Some(*result))
+        }
+        unsafe extern "system" fn startScan(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPFolderMonitorServices", "startScan", 12usize, |this| ((**(this as *const *const IWMPFolderMonitorServices_Vtbl)).startScan)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn stopScan(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPFolderMonitorServices", "stopScan", 13usize, |this| ((**(this as *const *const IWMPFolderMonitorServices_Vtbl)).stopScan)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            count,
+            item,
+            add,
+            remove,
+            scanState,
+            currentFolder,
+            scannedFilesCount,
+            addedFilesCount,
+            updateProgress,
+            startScan,
+            stopScan,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPFolderMonitorServices as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPGraphCreation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GraphCreationPreRender(this: *mut core::ffi::c_void, pfiltergraph: *mut core::ffi::c_void, preserved: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPGraphCreation", "GraphCreationPreRender", 3usize, |this| ((**(this as *const *const IWMPGraphCreation_Vtbl)).GraphCreationPreRender)(this, pfiltergraph, preserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn GraphCreationPostRender(this: *mut core::ffi::c_void, pfiltergraph: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPGraphCreation", "GraphCreationPostRender", 4usize, |this| ((**(this as *const *const IWMPGraphCreation_Vtbl)).GraphCreationPostRender)(this, pfiltergraph), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGraphCreationFlags(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPGraphCreation", "GetGraphCreationFlags", 5usize, |this| ((**(this as *const *const IWMPGraphCreation_Vtbl)).GetGraphCreationFlags)(this, pdwflags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GraphCreationPreRender,
+            GraphCreationPostRender,
+            GetGraphCreationFlags,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPGraphCreation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPLibrary_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPLibrary", "name", 3usize, |this| ((**(this as *const *const IWMPLibrary_Vtbl)).name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn r#type(this: *mut core::ffi::c_void, pwmplt: *mut WMPLibraryType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPLibrary", "r#type", 4usize, |this| ((**(this as *const *const IWMPLibrary_Vtbl)).r#type)(this, pwmplt), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn mediaCollection(this: *mut core::ffi::c_void, ppiwmpmediacollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPLibrary", "mediaCollection", 5usize, |this| ((**(this as *const *const IWMPLibrary_Vtbl)).mediaCollection)(this, ppiwmpmediacollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn isIdentical(this: *mut core::ffi::c_void, piwmplibrary: *mut core::ffi::c_void, pvbool: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPLibrary", "isIdentical", 6usize, |this| ((**(this as *const *const IWMPLibrary_Vtbl)).isIdentical)(this, piwmplibrary, pvbool), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            name,
+            r#type,
+            #[cfg(feature = "Win32_System_Com")]
+            mediaCollection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            mediaCollection: 0,
+            isIdentical,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPLibrary as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPLibrary2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn getItemInfo(this: *mut core::ffi::c_void, bstritemname: core::mem::MaybeUninit<windows_core::BSTR>, pbstrval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPLibrary2", "getItemInfo", 7usize, |this| ((**(this as *const *const IWMPLibrary2_Vtbl)).getItemInfo)(this, bstritemname, pbstrval), |result| Some(*result))
+        }
+        Self { base__: <IWMPLibrary_Vtbl as windows_core::imp::ProxyVtable>::PROXY, getItemInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPLibrary2 as windows_core::Interface>::IID || <IWMPLibrary_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPLibraryServices_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn getCountByType(this: *mut core::ffi::c_void, wmplt: WMPLibraryType, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPLibraryServices", "getCountByType", 3usize, |this| ((**(this as *const *const IWMPLibraryServices_Vtbl)).getCountByType)(this, wmplt, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn getLibraryByType(this: *mut core::ffi::c_void, wmplt: WMPLibraryType, lindex: i32, ppiwmplibrary: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPLibraryServices", "getLibraryByType", 4usize, |this| ((**(this as *const *const IWMPLibraryServices_Vtbl)).getLibraryByType)(this, wmplt, lindex, ppiwmplibrary), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, getCountByType, getLibraryByType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPLibraryServices as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPLibrarySharingServices_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn isLibraryShared(this: *mut core::ffi::c_void, pvbshared: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPLibrarySharingServices", "isLibraryShared", 3usize, |this| ((**(this as *const *const IWMPLibrarySharingServices_Vtbl)).isLibraryShared)(this, pvbshared), |result| Some(*result))
+        }
+        unsafe extern "system" fn isLibrarySharingEnabled(this: *mut core::ffi::c_void, pvbenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPLibrarySharingServices", "isLibrarySharingEnabled", 4usize, |this| ((**(this as *const *const IWMPLibrarySharingServices_Vtbl)).isLibrarySharingEnabled)(this, pvbenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn showLibrarySharing(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPLibrarySharingServices", "showLibrarySharing", 5usize, |this| ((**(this as *const *const IWMPLibrarySharingServices_Vtbl)).showLibrarySharing)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, isLibraryShared, isLibrarySharingEnabled, showLibrarySharing }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPLibrarySharingServices as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPMedia_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_isIdentical(this: *mut core::ffi::c_void, piwmpmedia: *mut core::ffi::c_void, pvbool: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMedia", "get_isIdentical", 7usize, |this| ((**(this as *const *const IWMPMedia_Vtbl)).get_isIdentical)(this, piwmpmedia, pvbool), |result| Some(*result))
+        }
+        unsafe extern "system" fn sourceURL(this: *mut core::ffi::c_void, pbstrsourceurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMedia", "sourceURL", 8usize, |this| ((**(this as *const *const IWMPMedia_Vtbl)).sourceURL)(this, pbstrsourceurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMedia", "name", 9usize, |this| ((**(this as *const *const IWMPMedia_Vtbl)).name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setname(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMedia", "Setname", 10usize, |this| ((**(this as *const *const IWMPMedia_Vtbl)).Setname)(this, bstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn imageSourceWidth(this: *mut core::ffi::c_void, pwidth: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMedia", "imageSourceWidth", 11usize, |this| ((**(this as *const *const IWMPMedia_Vtbl)).imageSourceWidth)(this, pwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn imageSourceHeight(this: *mut core::ffi::c_void, pheight: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMedia", "imageSourceHeight", 12usize, |this| ((**(this as *const *const IWMPMedia_Vtbl)).imageSourceHeight)(this, pheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn markerCount(this: *mut core::ffi::c_void, pmarkercount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMedia", "markerCount", 13usize, |this| ((**(this as *const *const IWMPMedia_Vtbl)).markerCount)(this, pmarkercount), |result| Some(*result))
+        }
+        unsafe extern "system" fn getMarkerTime(this: *mut core::ffi::c_void, markernum: i32, pmarkertime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMedia", "getMarkerTime", 14usize, |this| ((**(this as *const *const IWMPMedia_Vtbl)).getMarkerTime)(this, markernum, pmarkertime), |result| Some(*result))
+        }
+        unsafe extern "system" fn getMarkerName(this: *mut core::ffi::c_void, markernum: i32, pbstrmarkername: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMedia", "getMarkerName", 15usize, |this| ((**(this as *const *const IWMPMedia_Vtbl)).getMarkerName)(this, markernum, pbstrmarkername), |result| Some(*result))
+        }
+        unsafe extern "system" fn duration(this: *mut core::ffi::c_void, pduration: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMedia", "duration", 16usize, |this| ((**(this as *const *const IWMPMedia_Vtbl)).duration)(this, pduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn durationString(this: *mut core::ffi::c_void, pbstrduration: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMedia", "durationString", 17usize, |this| ((**(this as *const *const IWMPMedia_Vtbl)).durationString)(this, pbstrduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn attributeCount(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMedia", "attributeCount", 18usize, |this| ((**(this as *const *const IWMPMedia_Vtbl)).attributeCount)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn getAttributeName(this: *mut core::ffi::c_void, lindex: i32, pbstritemname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMedia", "getAttributeName", 19usize, |this| ((**(this as *const *const IWMPMedia_Vtbl)).getAttributeName)(this, lindex, pbstritemname), |result| Some(*result))
+        }
+        unsafe extern "system" fn getItemInfo(this: *mut core::ffi::c_void, bstritemname: core::mem::MaybeUninit<windows_core::BSTR>, pbstrval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMedia", "getItemInfo", 20usize, |this| ((**(this as *const *const IWMPMedia_Vtbl)).getItemInfo)(this, bstritemname, pbstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn setItemInfo(this: *mut core::ffi::c_void, bstritemname: core::mem::MaybeUninit<windows_core::BSTR>, bstrval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMedia", "setItemInfo", 21usize, |this| ((**(this as *const *const IWMPMedia_Vtbl)).setItemInfo)(this, bstritemname, bstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn getItemInfoByAtom(this: *mut core::ffi::c_void, latom: i32, pbstrval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMedia", "getItemInfoByAtom", 22usize, |this| ((**(this as *const *const IWMPMedia_Vtbl)).getItemInfoByAtom)(this, latom, pbstrval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn isMemberOf(this: *mut core::ffi::c_void, pplaylist: *mut core::ffi::c_void, pvarfismemberof: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMedia", "isMemberOf", 23usize, |this| ((**(this as *const *const IWMPMedia_Vtbl)).isMemberOf)(this, pplaylist, pvarfismemberof), |result| Some(*result))
+        }
+        unsafe extern "system" fn isReadOnlyItem(this: *mut core::ffi::c_void, bstritemname: core::mem::MaybeUninit<windows_core::BSTR>, pvarfisreadonly: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMedia", "isReadOnlyItem", 24usize, |this| ((**(this as *const *const IWMPMedia_Vtbl)).isReadOnlyItem)(this, bstritemname, pvarfisreadonly), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_isIdentical,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_isIdentical: 0,
+            sourceURL,
+            name,
+            Setname,
+            imageSourceWidth,
+            imageSourceHeight,
+            markerCount,
+            getMarkerTime,
+            getMarkerName,
+            duration,
+            durationString,
+            attributeCount,
+            getAttributeName,
+            getItemInfo,
+            setItemInfo,
+            getItemInfoByAtom,
+            #[cfg(feature = "Win32_System_Com")]
+            isMemberOf,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            isMemberOf: 0,
+            isReadOnlyItem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPMedia as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPMedia2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn error(this: *mut core::ffi::c_void, ppiwmperroritem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMedia2", "error", 25usize, |this| ((**(this as *const *const IWMPMedia2_Vtbl)).error)(this, ppiwmperroritem), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMPMedia_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            error,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            error: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPMedia2 as windows_core::Interface>::IID || <IWMPMedia_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPMedia3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn getAttributeCountByType(this: *mut core::ffi::c_void, bstrtype: core::mem::MaybeUninit<windows_core::BSTR>, bstrlanguage: core::mem::MaybeUninit<windows_core::BSTR>, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMedia3", "getAttributeCountByType", 26usize, |this| ((**(this as *const *const IWMPMedia3_Vtbl)).getAttributeCountByType)(this, bstrtype, bstrlanguage, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn getItemInfoByType(this: *mut core::ffi::c_void, bstrtype: core::mem::MaybeUninit<windows_core::BSTR>, bstrlanguage: core::mem::MaybeUninit<windows_core::BSTR>, lindex: i32, pvarvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMedia3", "getItemInfoByType", 27usize, |this| ((**(this as *const *const IWMPMedia3_Vtbl)).getItemInfoByType)(this, bstrtype, bstrlanguage, lindex, pvarvalue), |result| Some(*result))
+        }
+        Self { base__: <IWMPMedia2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, getAttributeCountByType, getItemInfoByType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPMedia3 as windows_core::Interface>::IID || <IWMPMedia2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPMediaCollection_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn add(this: *mut core::ffi::c_void, bstrurl: core::mem::MaybeUninit<windows_core::BSTR>, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMediaCollection", "add", 7usize, |this| ((**(this as *const *const IWMPMediaCollection_Vtbl)).add)(this, bstrurl, ppitem), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn getAll(this: *mut core::ffi::c_void, ppmediaitems: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMediaCollection", "getAll", 8usize, |this| ((**(this as *const *const IWMPMediaCollection_Vtbl)).getAll)(this, ppmediaitems), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn getByName(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, ppmediaitems: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMediaCollection", "getByName", 9usize, |this| ((**(this as *const *const IWMPMediaCollection_Vtbl)).getByName)(this, bstrname, ppmediaitems), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn getByGenre(this: *mut core::ffi::c_void, bstrgenre: core::mem::MaybeUninit<windows_core::BSTR>, ppmediaitems: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMediaCollection", "getByGenre", 10usize, |this| ((**(this as *const *const IWMPMediaCollection_Vtbl)).getByGenre)(this, bstrgenre, ppmediaitems), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn getByAuthor(this: *mut core::ffi::c_void, bstrauthor: core::mem::MaybeUninit<windows_core::BSTR>, ppmediaitems: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMediaCollection", "getByAuthor", 11usize, |this| ((**(this as *const *const IWMPMediaCollection_Vtbl)).getByAuthor)(this, bstrauthor, ppmediaitems), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn getByAlbum(this: *mut core::ffi::c_void, bstralbum: core::mem::MaybeUninit<windows_core::BSTR>, ppmediaitems: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMediaCollection", "getByAlbum", 12usize, |this| ((**(this as *const *const IWMPMediaCollection_Vtbl)).getByAlbum)(this, bstralbum, ppmediaitems), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn getByAttribute(this: *mut core::ffi::c_void, bstrattribute: core::mem::MaybeUninit<windows_core::BSTR>, bstrvalue: core::mem::MaybeUninit<windows_core::BSTR>, ppmediaitems: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMediaCollection", "getByAttribute", 13usize, |this| ((**(this as *const *const IWMPMediaCollection_Vtbl)).getByAttribute)(this, bstrattribute, bstrvalue, ppmediaitems), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn remove(this: *mut core::ffi::c_void, pitem: *mut core::ffi::c_void, varfdeletefile: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMediaCollection", "remove", 14usize, |this| ((**(this as *const *const IWMPMediaCollection_Vtbl)).remove)(this, pitem, varfdeletefile), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn getAttributeStringCollection(this: *mut core::ffi::c_void, bstrattribute: core::mem::MaybeUninit<windows_core::BSTR>, bstrmediatype: core::mem::MaybeUninit<windows_core::BSTR>, ppstringcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMediaCollection", "getAttributeStringCollection", 15usize, |this| ((**(this as *const *const IWMPMediaCollection_Vtbl)).getAttributeStringCollection)(this, bstrattribute, bstrmediatype, ppstringcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn getMediaAtom(this: *mut core::ffi::c_void, bstritemname: core::mem::MaybeUninit<windows_core::BSTR>, platom: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMediaCollection", "getMediaAtom", 16usize, |this| ((**(this as *const *const IWMPMediaCollection_Vtbl)).getMediaAtom)(this, bstritemname, platom), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn setDeleted(this: *mut core::ffi::c_void, pitem: *mut core::ffi::c_void, varfisdeleted: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMediaCollection", "setDeleted", 17usize, |this| ((**(this as *const *const IWMPMediaCollection_Vtbl)).setDeleted)(this, pitem, varfisdeleted), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn isDeleted(this: *mut core::ffi::c_void, pitem: *mut core::ffi::c_void, pvarfisdeleted: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMediaCollection", "isDeleted", 18usize, |this| ((**(this as *const *const IWMPMediaCollection_Vtbl)).isDeleted)(this, pitem, pvarfisdeleted), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            add: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            getAll,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            getAll: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            getByName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            getByName: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            getByGenre,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            getByGenre: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            getByAuthor,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            getByAuthor: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            getByAlbum,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            getByAlbum: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            getByAttribute,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            getByAttribute: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            remove,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            remove: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            getAttributeStringCollection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            getAttributeStringCollection: 0,
+            getMediaAtom,
+            #[cfg(feature = "Win32_System_Com")]
+            setDeleted,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            setDeleted: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            isDeleted,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            isDeleted: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPMediaCollection as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPMediaCollection2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn createQuery(this: *mut core::ffi::c_void, ppquery: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMediaCollection2", "createQuery", 19usize, |this| ((**(this as *const *const IWMPMediaCollection2_Vtbl)).createQuery)(this, ppquery), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn getPlaylistByQuery(this: *mut core::ffi::c_void, pquery: *mut core::ffi::c_void, bstrmediatype: core::mem::MaybeUninit<windows_core::BSTR>, bstrsortattribute: core::mem::MaybeUninit<windows_core::BSTR>, fsortascending: super::super::Foundation::VARIANT_BOOL, ppplaylist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMediaCollection2", "getPlaylistByQuery", 20usize, |this| ((**(this as *const *const IWMPMediaCollection2_Vtbl)).getPlaylistByQuery)(this, pquery, bstrmediatype, bstrsortattribute, fsortascending, ppplaylist), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn getStringCollectionByQuery(this: *mut core::ffi::c_void, bstrattribute: core::mem::MaybeUninit<windows_core::BSTR>, pquery: *mut core::ffi::c_void, bstrmediatype: core::mem::MaybeUninit<windows_core::BSTR>, bstrsortattribute: core::mem::MaybeUninit<windows_core::BSTR>, fsortascending: super::super::Foundation::VARIANT_BOOL, ppstringcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMediaCollection2", "getStringCollectionByQuery", 21usize, |this| ((**(this as *const *const IWMPMediaCollection2_Vtbl)).getStringCollectionByQuery)(this, bstrattribute, pquery, bstrmediatype, bstrsortattribute, fsortascending, ppstringcollection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn getByAttributeAndMediaType(this: *mut core::ffi::c_void, bstrattribute: core::mem::MaybeUninit<windows_core::BSTR>, bstrvalue: core::mem::MaybeUninit<windows_core::BSTR>, bstrmediatype: core::mem::MaybeUninit<windows_core::BSTR>, ppmediaitems: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMediaCollection2", "getByAttributeAndMediaType", 22usize, |this| ((**(this as *const *const IWMPMediaCollection2_Vtbl)).getByAttributeAndMediaType)(this, bstrattribute, bstrvalue, bstrmediatype, ppmediaitems), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMPMediaCollection_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            createQuery,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            createQuery: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            getPlaylistByQuery,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            getPlaylistByQuery: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            getStringCollectionByQuery,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            getStringCollectionByQuery: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            getByAttributeAndMediaType,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            getByAttributeAndMediaType: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPMediaCollection2 as windows_core::Interface>::IID || <IWMPMediaCollection_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPMediaPluginRegistrar_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WMPRegisterPlayerPlugin(this: *mut core::ffi::c_void, pwszfriendlyname: windows_core::PCWSTR, pwszdescription: windows_core::PCWSTR, pwszuninstallstring: windows_core::PCWSTR, dwpriority: u32, guidplugintype: windows_core::GUID, clsid: windows_core::GUID, cmediatypes: u32, pmediatypes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMediaPluginRegistrar", "WMPRegisterPlayerPlugin", 3usize, |this| ((**(this as *const *const IWMPMediaPluginRegistrar_Vtbl)).WMPRegisterPlayerPlugin)(this, pwszfriendlyname, pwszdescription, pwszuninstallstring, dwpriority, guidplugintype, clsid, cmediatypes, pmediatypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn WMPUnRegisterPlayerPlugin(this: *mut core::ffi::c_void, guidplugintype: windows_core::GUID, clsid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMediaPluginRegistrar", "WMPUnRegisterPlayerPlugin", 4usize, |this| ((**(this as *const *const IWMPMediaPluginRegistrar_Vtbl)).WMPUnRegisterPlayerPlugin)(this, guidplugintype, clsid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WMPRegisterPlayerPlugin, WMPUnRegisterPlayerPlugin }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPMediaPluginRegistrar as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPMetadataPicture_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn mimeType(this: *mut core::ffi::c_void, pbstrmimetype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMetadataPicture", "mimeType", 7usize, |this| ((**(this as *const *const IWMPMetadataPicture_Vtbl)).mimeType)(this, pbstrmimetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn pictureType(this: *mut core::ffi::c_void, pbstrpicturetype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMetadataPicture", "pictureType", 8usize, |this| ((**(this as *const *const IWMPMetadataPicture_Vtbl)).pictureType)(this, pbstrpicturetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn description(this: *mut core::ffi::c_void, pbstrdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMetadataPicture", "description", 9usize, |this| ((**(this as *const *const IWMPMetadataPicture_Vtbl)).description)(this, pbstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn URL(this: *mut core::ffi::c_void, pbstrurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMetadataPicture", "URL", 10usize, |this| ((**(this as *const *const IWMPMetadataPicture_Vtbl)).URL)(this, pbstrurl), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, mimeType, pictureType, description, URL }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPMetadataPicture as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPMetadataText_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn description(this: *mut core::ffi::c_void, pbstrdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMetadataText", "description", 7usize, |this| ((**(this as *const *const IWMPMetadataText_Vtbl)).description)(this, pbstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn text(this: *mut core::ffi::c_void, pbstrtext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPMetadataText", "text", 8usize, |this| ((**(this as *const *const IWMPMetadataText_Vtbl)).text)(this, pbstrtext), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, description, text }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPMetadataText as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPNetwork_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn bandWidth(this: *mut core::ffi::c_void, plbandwidth: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNetwork", "bandWidth", 7usize, |this| ((**(this as *const *const IWMPNetwork_Vtbl)).bandWidth)(this, plbandwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn recoveredPackets(this: *mut core::ffi::c_void, plrecoveredpackets: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNetwork", "recoveredPackets", 8usize, |this| ((**(this as *const *const IWMPNetwork_Vtbl)).recoveredPackets)(this, plrecoveredpackets), |result| Some(*result))
+        }
+        unsafe extern "system" fn sourceProtocol(this: *mut core::ffi::c_void, pbstrsourceprotocol: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNetwork", "sourceProtocol", 9usize, |this| ((**(this as *const *const IWMPNetwork_Vtbl)).sourceProtocol)(this, pbstrsourceprotocol), |result| Some(*result))
+        }
+        unsafe extern "system" fn receivedPackets(this: *mut core::ffi::c_void, plreceivedpackets: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNetwork", "receivedPackets", 10usize, |this| ((**(this as *const *const IWMPNetwork_Vtbl)).receivedPackets)(this, plreceivedpackets), |result| Some(*result))
+        }
+        unsafe extern "system" fn lostPackets(this: *mut core::ffi::c_void, pllostpackets: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNetwork", "lostPackets", 11usize, |this| ((**(this as *const *const IWMPNetwork_Vtbl)).lostPackets)(this, pllostpackets), |result| Some(*result))
+        }
+        unsafe extern "system" fn receptionQuality(this: *mut core::ffi::c_void, plreceptionquality: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNetwork", "receptionQuality", 12usize, |this| ((**(this as *const *const IWMPNetwork_Vtbl)).receptionQuality)(this, plreceptionquality), |result| Some(*result))
+        }
+        unsafe extern "system" fn bufferingCount(this: *mut core::ffi::c_void, plbufferingcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNetwork", "bufferingCount", 13usize, |this| ((**(this as *const *const IWMPNetwork_Vtbl)).bufferingCount)(this, plbufferingcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn bufferingProgress(this: *mut core::ffi::c_void, plbufferingprogress: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNetwork", "bufferingProgress", 14usize, |this| ((**(this as *const *const IWMPNetwork_Vtbl)).bufferingProgress)(this, plbufferingprogress), |result| Some(*result))
+        }
+        unsafe extern "system" fn bufferingTime(this: *mut core::ffi::c_void, plbufferingtime: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNetwork", "bufferingTime", 15usize, |this| ((**(this as *const *const IWMPNetwork_Vtbl)).bufferingTime)(this, plbufferingtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetbufferingTime(this: *mut core::ffi::c_void, lbufferingtime: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNetwork", "SetbufferingTime", 16usize, |this| ((**(this as *const *const IWMPNetwork_Vtbl)).SetbufferingTime)(this, lbufferingtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn frameRate(this: *mut core::ffi::c_void, plframerate: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNetwork", "frameRate", 17usize, |this| ((**(this as *const *const IWMPNetwork_Vtbl)).frameRate)(this, plframerate), |result| Some(*result))
+        }
+        unsafe extern "system" fn maxBitRate(this: *mut core::ffi::c_void, plbitrate: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNetwork", "maxBitRate", 18usize, |this| ((**(this as *const *const IWMPNetwork_Vtbl)).maxBitRate)(this, plbitrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn bitRate(this: *mut core::ffi::c_void, plbitrate: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNetwork", "bitRate", 19usize, |this| ((**(this as *const *const IWMPNetwork_Vtbl)).bitRate)(this, plbitrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn getProxySettings(this: *mut core::ffi::c_void, bstrprotocol: core::mem::MaybeUninit<windows_core::BSTR>, plproxysetting: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNetwork", "getProxySettings", 20usize, |this| ((**(this as *const *const IWMPNetwork_Vtbl)).getProxySettings)(this, bstrprotocol, plproxysetting), |result| Some(*result))
+        }
+        unsafe extern "system" fn setProxySettings(this: *mut core::ffi::c_void, bstrprotocol: core::mem::MaybeUninit<windows_core::BSTR>, lproxysetting: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNetwork", "setProxySettings", 21usize, |this| ((**(this as *const *const IWMPNetwork_Vtbl)).setProxySettings)(this, bstrprotocol, lproxysetting), |result| Some(*result))
+        }
+        unsafe extern "system" fn getProxyName(this: *mut core::ffi::c_void, bstrprotocol: core::mem::MaybeUninit<windows_core::BSTR>, pbstrproxyname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNetwork", "getProxyName", 22usize, |this| ((**(this as *const *const IWMPNetwork_Vtbl)).getProxyName)(this, bstrprotocol, pbstrproxyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn setProxyName(this: *mut core::ffi::c_void, bstrprotocol: core::mem::MaybeUninit<windows_core::BSTR>, bstrproxyname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNetwork", "setProxyName", 23usize, |this| ((**(this as *const *const IWMPNetwork_Vtbl)).setProxyName)(this, bstrprotocol, bstrproxyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn getProxyPort(this: *mut core::ffi::c_void, bstrprotocol: core::mem::MaybeUninit<windows_core::BSTR>, lproxyport: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNetwork", "getProxyPort", 24usize, |this| ((**(this as *const *const IWMPNetwork_Vtbl)).getProxyPort)(this, bstrprotocol, lproxyport), |result| Some(*result))
+        }
+        unsafe extern "system" fn setProxyPort(this: *mut core::ffi::c_void, bstrprotocol: core::mem::MaybeUninit<windows_core::BSTR>, lproxyport: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNetwork", "setProxyPort", 25usize, |this| ((**(this as *const *const IWMPNetwork_Vtbl)).setProxyPort)(this, bstrprotocol, lproxyport), |result| Some(*result))
+        }
+        unsafe extern "system" fn getProxyExceptionList(this: *mut core::ffi::c_void, bstrprotocol: core::mem::MaybeUninit<windows_core::BSTR>, pbstrexceptionlist: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNetwork", "getProxyExceptionList", 26usize, |this| ((**(this as *const *const IWMPNetwork_Vtbl)).getProxyExceptionList)(this, bstrprotocol, pbstrexceptionlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn setProxyExceptionList(this: *mut core::ffi::c_void, bstrprotocol: core::mem::MaybeUninit<windows_core::BSTR>, pbstrexceptionlist: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNetwork", "setProxyExceptionList", 27usize, |this| ((**(this as *const *const IWMPNetwork_Vtbl)).setProxyExceptionList)(this, bstrprotocol, pbstrexceptionlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn getProxyBypassForLocal(this: *mut core::ffi::c_void, bstrprotocol: core::mem::MaybeUninit<windows_core::BSTR>, pfbypassforlocal: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNetwork", "getProxyBypassForLocal", 28usize, |this| ((**(this as *const *const IWMPNetwork_Vtbl)).getProxyBypassForLocal)(this, bstrprotocol, pfbypassforlocal), |result| Some(*result))
+        }
+        unsafe extern "system" fn setProxyBypassForLocal(this: *mut core::ffi::c_void, bstrprotocol: core::mem::MaybeUninit<windows_core::BSTR>, fbypassforlocal: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNetwork", "setProxyBypassForLocal", 29usize, |this| ((**(this as *const *const IWMPNetwork_Vtbl)).setProxyBypassForLocal)(this, bstrprotocol, fbypassforlocal), |result| Some(*result))
+        }
+        unsafe extern "system" fn maxBandwidth(this: *mut core::ffi::c_void, lmaxbandwidth: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNetwork", "maxBandwidth", 30usize, |this| ((**(this as *const *const IWMPNetwork_Vtbl)).maxBandwidth)(this, lmaxbandwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetmaxBandwidth(this: *mut core::ffi::c_void, lmaxbandwidth: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNetwork", "SetmaxBandwidth", 31usize, |this| ((**(this as *const *const IWMPNetwork_Vtbl)).SetmaxBandwidth)(this, lmaxbandwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn downloadProgress(this: *mut core::ffi::c_void, pldownloadprogress: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNetwork", "downloadProgress", 32usize, |this| ((**(this as *const *const IWMPNetwork_Vtbl)).downloadProgress)(this, pldownloadprogress), |result| Some(*result))
+        }
+        unsafe extern "system" fn encodedFrameRate(this: *mut core::ffi::c_void, plframerate: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNetwork", "encodedFrameRate", 33usize, |this| ((**(this as *const *const IWMPNetwork_Vtbl)).encodedFrameRate)(this, plframerate), |result| Some(*result))
+        }
+        unsafe extern "system" fn framesSkipped(this: *mut core::ffi::c_void, plframes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNetwork", "framesSkipped", 34usize, |this| ((**(this as *const *const IWMPNetwork_Vtbl)).framesSkipped)(this, plframes), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            bandWidth,
+            recoveredPackets,
+            sourceProtocol,
+            receivedPackets,
+            lostPackets,
+            receptionQuality,
+            bufferingCount,
+            bufferingProgress,
+            bufferingTime,
+            SetbufferingTime,
+            frameRate,
+            maxBitRate,
+            bitRate,
+            getProxySettings,
+            setProxySettings,
+            getProxyName,
+            setProxyName,
+            getProxyPort,
+            setProxyPort,
+            getProxyExceptionList,
+            setProxyExceptionList,
+            getProxyBypassForLocal,
+            setProxyBypassForLocal,
+            maxBandwidth,
+            SetmaxBandwidth,
+            downloadProgress,
+            encodedFrameRate,
+            framesSkipped,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPNetwork as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPNodeRealEstate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDesiredSize(this: *mut core::ffi::c_void, psize: *mut super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNodeRealEstate", "GetDesiredSize", 3usize, |this| ((**(this as *const *const IWMPNodeRealEstate_Vtbl)).GetDesiredSize)(this, psize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRects(this: *mut core::ffi::c_void, psrc: *const super::super::Foundation::RECT, pdest: *const super::super::Foundation::RECT, pclip: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNodeRealEstate", "SetRects", 4usize, |this| ((**(this as *const *const IWMPNodeRealEstate_Vtbl)).SetRects)(this, psrc, pdest, pclip), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRects(this: *mut core::ffi::c_void, psrc: *mut super::super::Foundation::RECT, pdest: *mut super::super::Foundation::RECT, pclip: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNodeRealEstate", "GetRects", 5usize, |this| ((**(this as *const *const IWMPNodeRealEstate_Vtbl)).GetRects)(this, psrc, pdest, pclip), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWindowless(this: *mut core::ffi::c_void, fwindowless: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNodeRealEstate", "SetWindowless", 6usize, |this| ((**(this as *const *const IWMPNodeRealEstate_Vtbl)).SetWindowless)(this, fwindowless), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWindowless(this: *mut core::ffi::c_void, pfwindowless: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNodeRealEstate", "GetWindowless", 7usize, |this| ((**(this as *const *const IWMPNodeRealEstate_Vtbl)).GetWindowless)(this, pfwindowless), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFullScreen(this: *mut core::ffi::c_void, ffullscreen: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNodeRealEstate", "SetFullScreen", 8usize, |this| ((**(this as *const *const IWMPNodeRealEstate_Vtbl)).SetFullScreen)(this, ffullscreen), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFullScreen(this: *mut core::ffi::c_void, pffullscreen: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNodeRealEstate", "GetFullScreen", 9usize, |this| ((**(this as *const *const IWMPNodeRealEstate_Vtbl)).GetFullScreen)(this, pffullscreen), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDesiredSize,
+            SetRects,
+            GetRects,
+            SetWindowless,
+            GetWindowless,
+            SetFullScreen,
+            GetFullScreen,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPNodeRealEstate as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPNodeRealEstateHost_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnDesiredSizeChange(this: *mut core::ffi::c_void, psize: *mut super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNodeRealEstateHost", "OnDesiredSizeChange", 3usize, |this| ((**(this as *const *const IWMPNodeRealEstateHost_Vtbl)).OnDesiredSizeChange)(this, psize), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnFullScreenTransition(this: *mut core::ffi::c_void, ffullscreen: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNodeRealEstateHost", "OnFullScreenTransition", 4usize, |this| ((**(this as *const *const IWMPNodeRealEstateHost_Vtbl)).OnFullScreenTransition)(this, ffullscreen), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnDesiredSizeChange, OnFullScreenTransition }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPNodeRealEstateHost as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPNodeWindowed_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetOwnerWindow(this: *mut core::ffi::c_void, hwnd: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNodeWindowed", "SetOwnerWindow", 3usize, |this| ((**(this as *const *const IWMPNodeWindowed_Vtbl)).SetOwnerWindow)(this, hwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOwnerWindow(this: *mut core::ffi::c_void, phwnd: *mut isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNodeWindowed", "GetOwnerWindow", 4usize, |this| ((**(this as *const *const IWMPNodeWindowed_Vtbl)).GetOwnerWindow)(this, phwnd), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetOwnerWindow, GetOwnerWindow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPNodeWindowed as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPNodeWindowedHost_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnWindowMessageFromRenderer(this: *mut core::ffi::c_void, umsg: u32, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM, plret: *mut super::super::Foundation::LRESULT, pfhandled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNodeWindowedHost", "OnWindowMessageFromRenderer", 3usize, |this| ((**(this as *const *const IWMPNodeWindowedHost_Vtbl)).OnWindowMessageFromRenderer)(this, umsg, wparam, lparam, plret, pfhandled), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnWindowMessageFromRenderer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPNodeWindowedHost as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPNodeWindowless_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnDraw(this: *mut core::ffi::c_void, hdc: isize, prcdraw: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNodeWindowless", "OnDraw", 4usize, |this| ((**(this as *const *const IWMPNodeWindowless_Vtbl)).OnDraw)(this, hdc, prcdraw), |result| Some(*result))
+        }
+        Self { base__: <IWMPWindowMessageSink_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnDraw }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPNodeWindowless as windows_core::Interface>::IID || <IWMPWindowMessageSink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPNodeWindowlessHost_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InvalidateRect(this: *mut core::ffi::c_void, prc: *const super::super::Foundation::RECT, ferase: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPNodeWindowlessHost", "InvalidateRect", 3usize, |this| ((**(this as *const *const IWMPNodeWindowlessHost_Vtbl)).InvalidateRect)(this, prc, ferase), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InvalidateRect }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPNodeWindowlessHost as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPPlayer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn enabled(this: *mut core::ffi::c_void, pbenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer", "enabled", 28usize, |this| ((**(this as *const *const IWMPPlayer_Vtbl)).enabled)(this, pbenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setenabled(this: *mut core::ffi::c_void, benabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer", "Setenabled", 29usize, |this| ((**(this as *const *const IWMPPlayer_Vtbl)).Setenabled)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn fullScreen(this: *mut core::ffi::c_void, pbfullscreen: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer", "fullScreen", 30usize, |this| ((**(this as *const *const IWMPPlayer_Vtbl)).fullScreen)(this, pbfullscreen), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetfullScreen(this: *mut core::ffi::c_void, bfullscreen: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer", "SetfullScreen", 31usize, |this| ((**(this as *const *const IWMPPlayer_Vtbl)).SetfullScreen)(this, bfullscreen), |result| Some(*result))
+        }
+        unsafe extern "system" fn enableContextMenu(this: *mut core::ffi::c_void, pbenablecontextmenu: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer", "enableContextMenu", 32usize, |this| ((**(this as *const *const IWMPPlayer_Vtbl)).enableContextMenu)(this, pbenablecontextmenu), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetenableContextMenu(this: *mut core::ffi::c_void, benablecontextmenu: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer", "SetenableContextMenu", 33usize, |this| ((**(this as *const *const IWMPPlayer_Vtbl)).SetenableContextMenu)(this, benablecontextmenu), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetuiMode(this: *mut core::ffi::c_void, bstrmode: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer", "SetuiMode", 34usize, |this| ((**(this as *const *const IWMPPlayer_Vtbl)).SetuiMode)(this, bstrmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn uiMode(this: *mut core::ffi::c_void, pbstrmode: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer", "uiMode", 35usize, |this| ((**(this as *const *const IWMPPlayer_Vtbl)).uiMode)(this, pbstrmode), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMPCore_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            enabled,
+            Setenabled,
+            fullScreen,
+            SetfullScreen,
+            enableContextMenu,
+            SetenableContextMenu,
+            SetuiMode,
+            uiMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPPlayer as windows_core::Interface>::IID || <IWMPCore_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPPlayer2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn enabled(this: *mut core::ffi::c_void, pbenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer2", "enabled", 28usize, |this| ((**(this as *const *const IWMPPlayer2_Vtbl)).enabled)(this, pbenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setenabled(this: *mut core::ffi::c_void, benabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer2", "Setenabled", 29usize, |this| ((**(this as *const *const IWMPPlayer2_Vtbl)).Setenabled)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn fullScreen(this: *mut core::ffi::c_void, pbfullscreen: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer2", "fullScreen", 30usize, |this| ((**(this as *const *const IWMPPlayer2_Vtbl)).fullScreen)(this, pbfullscreen), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetfullScreen(this: *mut core::ffi::c_void, bfullscreen: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer2", "SetfullScreen", 31usize, |this| ((**(this as *const *const IWMPPlayer2_Vtbl)).SetfullScreen)(this, bfullscreen), |result| Some(*result))
+        }
+        unsafe extern "system" fn enableContextMenu(this: *mut core::ffi::c_void, pbenablecontextmenu: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer2", "enableContextMenu", 32usize, |this| ((**(this as *const *const IWMPPlayer2_Vtbl)).enableContextMenu)(this, pbenablecontextmenu), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetenableContextMenu(this: *mut core::ffi::c_void, benablecontextmenu: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer2", "SetenableContextMenu", 33usize, |this| ((**(this as *const *const IWMPPlayer2_Vtbl)).SetenableContextMenu)(this, benablecontextmenu), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetuiMode(this: *mut core::ffi::c_void, bstrmode: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer2", "SetuiMode", 34usize, |this| ((**(this as *const *const IWMPPlayer2_Vtbl)).SetuiMode)(this, bstrmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn uiMode(this: *mut core::ffi::c_void, pbstrmode: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer2", "uiMode", 35usize, |this| ((**(this as *const *const IWMPPlayer2_Vtbl)).uiMode)(this, pbstrmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn stretchToFit(this: *mut core::ffi::c_void, pbenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer2", "stretchToFit", 36usize, |this| ((**(this as *const *const IWMPPlayer2_Vtbl)).stretchToFit)(this, pbenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetstretchToFit(this: *mut core::ffi::c_void, benabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer2", "SetstretchToFit", 37usize, |this| ((**(this as *const *const IWMPPlayer2_Vtbl)).SetstretchToFit)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn windowlessVideo(this: *mut core::ffi::c_void, pbenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer2", "windowlessVideo", 38usize, |this| ((**(this as *const *const IWMPPlayer2_Vtbl)).windowlessVideo)(this, pbenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetwindowlessVideo(this: *mut core::ffi::c_void, benabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer2", "SetwindowlessVideo", 39usize, |this| ((**(this as *const *const IWMPPlayer2_Vtbl)).SetwindowlessVideo)(this, benabled), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMPCore_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            enabled,
+            Setenabled,
+            fullScreen,
+            SetfullScreen,
+            enableContextMenu,
+            SetenableContextMenu,
+            SetuiMode,
+            uiMode,
+            stretchToFit,
+            SetstretchToFit,
+            windowlessVideo,
+            SetwindowlessVideo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPPlayer2 as windows_core::Interface>::IID || <IWMPCore_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPPlayer3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn enabled(this: *mut core::ffi::c_void, pbenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer3", "enabled", 29usize, |this| ((**(this as *const *const IWMPPlayer3_Vtbl)).enabled)(this, pbenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setenabled(this: *mut core::ffi::c_void, benabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer3", "Setenabled", 30usize, |this| ((**(this as *const *const IWMPPlayer3_Vtbl)).Setenabled)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn fullScreen(this: *mut core::ffi::c_void, pbfullscreen: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer3", "fullScreen", 31usize, |this| ((**(this as *const *const IWMPPlayer3_Vtbl)).fullScreen)(this, pbfullscreen), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetfullScreen(this: *mut core::ffi::c_void, bfullscreen: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer3", "SetfullScreen", 32usize, |this| ((**(this as *const *const IWMPPlayer3_Vtbl)).SetfullScreen)(this, bfullscreen), |result| Some(*result))
+        }
+        unsafe extern "system" fn enableContextMenu(this: *mut core::ffi::c_void, pbenablecontextmenu: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer3", "enableContextMenu", 33usize, |this| ((**(this as *const *const IWMPPlayer3_Vtbl)).enableContextMenu)(this, pbenablecontextmenu), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetenableContextMenu(this: *mut core::ffi::c_void, benablecontextmenu: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer3", "SetenableContextMenu", 34usize, |this| ((**(this as *const *const IWMPPlayer3_Vtbl)).SetenableContextMenu)(this, benablecontextmenu), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetuiMode(this: *mut core::ffi::c_void, bstrmode: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer3", "SetuiMode", 35usize, |this| ((**(this as *const *const IWMPPlayer3_Vtbl)).SetuiMode)(this, bstrmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn uiMode(this: *mut core::ffi::c_void, pbstrmode: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer3", "uiMode", 36usize, |this| ((**(this as *const *const IWMPPlayer3_Vtbl)).uiMode)(this, pbstrmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn stretchToFit(this: *mut core::ffi::c_void, pbenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer3", "stretchToFit", 37usize, |this| ((**(this as *const *const IWMPPlayer3_Vtbl)).stretchToFit)(this, pbenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetstretchToFit(this: *mut core::ffi::c_void, benabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer3", "SetstretchToFit", 38usize, |this| ((**(this as *const *const IWMPPlayer3_Vtbl)).SetstretchToFit)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn windowlessVideo(this: *mut core::ffi::c_void, pbenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer3", "windowlessVideo", 39usize, |this| ((**(this as *const *const IWMPPlayer3_Vtbl)).windowlessVideo)(this, pbenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetwindowlessVideo(this: *mut core::ffi::c_void, benabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer3", "SetwindowlessVideo", 40usize, |this| ((**(this as *const *const IWMPPlayer3_Vtbl)).SetwindowlessVideo)(this, benabled), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMPCore2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            enabled,
+            Setenabled,
+            fullScreen,
+            SetfullScreen,
+            enableContextMenu,
+            SetenableContextMenu,
+            SetuiMode,
+            uiMode,
+            stretchToFit,
+            SetstretchToFit,
+            windowlessVideo,
+            SetwindowlessVideo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPPlayer3 as windows_core::Interface>::IID || <IWMPCore2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPPlayer4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn enabled(this: *mut core::ffi::c_void, pbenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer4", "enabled", 31usize, |this| ((**(this as *const *const IWMPPlayer4_Vtbl)).enabled)(this, pbenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setenabled(this: *mut core::ffi::c_void, benabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer4", "Setenabled", 32usize, |this| ((**(this as *const *const IWMPPlayer4_Vtbl)).Setenabled)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn fullScreen(this: *mut core::ffi::c_void, pbfullscreen: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer4", "fullScreen", 33usize, |this| ((**(this as *const *const IWMPPlayer4_Vtbl)).fullScreen)(this, pbfullscreen), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetfullScreen(this: *mut core::ffi::c_void, bfullscreen: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer4", "SetfullScreen", 34usize, |this| ((**(this as *const *const IWMPPlayer4_Vtbl)).SetfullScreen)(this, bfullscreen), |result| Some(*result))
+        }
+        unsafe extern "system" fn enableContextMenu(this: *mut core::ffi::c_void, pbenablecontextmenu: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer4", "enableContextMenu", 35usize, |this| ((**(this as *const *const IWMPPlayer4_Vtbl)).enableContextMenu)(this, pbenablecontextmenu), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetenableContextMenu(this: *mut core::ffi::c_void, benablecontextmenu: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer4", "SetenableContextMenu", 36usize, |this| ((**(this as *const *const IWMPPlayer4_Vtbl)).SetenableContextMenu)(this, benablecontextmenu), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetuiMode(this: *mut core::ffi::c_void, bstrmode: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer4", "SetuiMode", 37usize, |this| ((**(this as *const *const IWMPPlayer4_Vtbl)).SetuiMode)(this, bstrmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn uiMode(this: *mut core::ffi::c_void, pbstrmode: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer4", "uiMode", 38usize, |this| ((**(this as *const *const IWMPPlayer4_Vtbl)).uiMode)(this, pbstrmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn stretchToFit(this: *mut core::ffi::c_void, pbenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer4", "stretchToFit", 39usize, |this| ((**(this as *const *const IWMPPlayer4_Vtbl)).stretchToFit)(this, pbenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetstretchToFit(this: *mut core::ffi::c_void, benabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer4", "SetstretchToFit", 40usize, |this| ((**(this as *const *const IWMPPlayer4_Vtbl)).SetstretchToFit)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn windowlessVideo(this: *mut core::ffi::c_void, pbenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer4", "windowlessVideo", 41usize, |this| ((**(this as *const *const IWMPPlayer4_Vtbl)).windowlessVideo)(this, pbenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetwindowlessVideo(this: *mut core::ffi::c_void, benabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer4", "SetwindowlessVideo", 42usize, |this| ((**(this as *const *const IWMPPlayer4_Vtbl)).SetwindowlessVideo)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn isRemote(this: *mut core::ffi::c_void, pvarfisremote: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer4", "isRemote", 43usize, |this| ((**(this as *const *const IWMPPlayer4_Vtbl)).isRemote)(this, pvarfisremote), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn playerApplication(this: *mut core::ffi::c_void, ppiwmpplayerapplication: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer4", "playerApplication", 44usize, |this| ((**(this as *const *const IWMPPlayer4_Vtbl)).playerApplication)(this, ppiwmpplayerapplication), |result| Some(*result))
+        }
+        unsafe extern "system" fn openPlayer(this: *mut core::ffi::c_void, bstrurl: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayer4", "openPlayer", 45usize, |this| ((**(this as *const *const IWMPPlayer4_Vtbl)).openPlayer)(this, bstrurl), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMPCore3_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            enabled,
+            Setenabled,
+            fullScreen,
+            SetfullScreen,
+            enableContextMenu,
+            SetenableContextMenu,
+            SetuiMode,
+            uiMode,
+            stretchToFit,
+            SetstretchToFit,
+            windowlessVideo,
+            SetwindowlessVideo,
+            isRemote,
+            #[cfg(feature = "Win32_System_Com")]
+            playerApplication,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            playerApplication: 0,
+            openPlayer,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPPlayer4 as windows_core::Interface>::IID || <IWMPCore3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPPlayerApplication_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn switchToPlayerApplication(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayerApplication", "switchToPlayerApplication", 7usize, |this| ((**(this as *const *const IWMPPlayerApplication_Vtbl)).switchToPlayerApplication)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn switchToControl(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayerApplication", "switchToControl", 8usize, |this| ((**(this as *const *const IWMPPlayerApplication_Vtbl)).switchToControl)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn playerDocked(this: *mut core::ffi::c_void, pbplayerdocked: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayerApplication", "playerDocked", 9usize, |this| ((**(this as *const *const IWMPPlayerApplication_Vtbl)).playerDocked)(this, pbplayerdocked), |result| Some(*result))
+        }
+        unsafe extern "system" fn hasDisplay(this: *mut core::ffi::c_void, pbhasdisplay: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayerApplication", "hasDisplay", 10usize, |this| ((**(this as *const *const IWMPPlayerApplication_Vtbl)).hasDisplay)(this, pbhasdisplay), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            switchToPlayerApplication,
+            switchToControl,
+            playerDocked,
+            hasDisplay,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPPlayerApplication as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPPlayerServices_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn activateUIPlugin(this: *mut core::ffi::c_void, bstrplugin: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayerServices", "activateUIPlugin", 3usize, |this| ((**(this as *const *const IWMPPlayerServices_Vtbl)).activateUIPlugin)(this, bstrplugin), |result| Some(*result))
+        }
+        unsafe extern "system" fn setTaskPane(this: *mut core::ffi::c_void, bstrtaskpane: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayerServices", "setTaskPane", 4usize, |this| ((**(this as *const *const IWMPPlayerServices_Vtbl)).setTaskPane)(this, bstrtaskpane), |result| Some(*result))
+        }
+        unsafe extern "system" fn setTaskPaneURL(this: *mut core::ffi::c_void, bstrtaskpane: core::mem::MaybeUninit<windows_core::BSTR>, bstrurl: core::mem::MaybeUninit<windows_core::BSTR>, bstrfriendlyname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayerServices", "setTaskPaneURL", 5usize, |this| ((**(this as *const *const IWMPPlayerServices_Vtbl)).setTaskPaneURL)(this, bstrtaskpane, bstrurl, bstrfriendlyname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, activateUIPlugin, setTaskPane, setTaskPaneURL }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPPlayerServices as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPPlayerServices2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn setBackgroundProcessingPriority(this: *mut core::ffi::c_void, bstrpriority: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlayerServices2", "setBackgroundProcessingPriority", 6usize, |this| ((**(this as *const *const IWMPPlayerServices2_Vtbl)).setBackgroundProcessingPriority)(this, bstrpriority), |result| Some(*result))
+        }
+        Self { base__: <IWMPPlayerServices_Vtbl as windows_core::imp::ProxyVtable>::PROXY, setBackgroundProcessingPriority }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPPlayerServices2 as windows_core::Interface>::IID || <IWMPPlayerServices_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPPlaylist_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlaylist", "count", 7usize, |this| ((**(this as *const *const IWMPPlaylist_Vtbl)).count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlaylist", "name", 8usize, |this| ((**(this as *const *const IWMPPlaylist_Vtbl)).name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setname(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlaylist", "Setname", 9usize, |this| ((**(this as *const *const IWMPPlaylist_Vtbl)).Setname)(this, bstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn attributeCount(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlaylist", "attributeCount", 10usize, |this| ((**(this as *const *const IWMPPlaylist_Vtbl)).attributeCount)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_attributeName(this: *mut core::ffi::c_void, lindex: i32, pbstrattributename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlaylist", "get_attributeName", 11usize, |this| ((**(this as *const *const IWMPPlaylist_Vtbl)).get_attributeName)(this, lindex, pbstrattributename), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_item(this: *mut core::ffi::c_void, lindex: i32, ppiwmpmedia: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlaylist", "get_item", 12usize, |this| ((**(this as *const *const IWMPPlaylist_Vtbl)).get_item)(this, lindex, ppiwmpmedia), |result| Some(*result))
+        }
+        unsafe extern "system" fn getItemInfo(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, pbstrval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlaylist", "getItemInfo", 13usize, |this| ((**(this as *const *const IWMPPlaylist_Vtbl)).getItemInfo)(this, bstrname, pbstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn setItemInfo(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, bstrvalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlaylist", "setItemInfo", 14usize, |this| ((**(this as *const *const IWMPPlaylist_Vtbl)).setItemInfo)(this, bstrname, bstrvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_isIdentical(this: *mut core::ffi::c_void, piwmpplaylist: *mut core::ffi::c_void, pvbool: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlaylist", "get_isIdentical", 15usize, |this| ((**(this as *const *const IWMPPlaylist_Vtbl)).get_isIdentical)(this, piwmpplaylist, pvbool), |result| Some(*result))
+        }
+        unsafe extern "system" fn clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlaylist", "clear", 16usize, |this| ((**(this as *const *const IWMPPlaylist_Vtbl)).clear)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn insertItem(this: *mut core::ffi::c_void, lindex: i32, piwmpmedia: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlaylist", "insertItem", 17usize, |this| ((**(this as *const *const IWMPPlaylist_Vtbl)).insertItem)(this, lindex, piwmpmedia), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn appendItem(this: *mut core::ffi::c_void, piwmpmedia: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlaylist", "appendItem", 18usize, |this| ((**(this as *const *const IWMPPlaylist_Vtbl)).appendItem)(this, piwmpmedia), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn removeItem(this: *mut core::ffi::c_void, piwmpmedia: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlaylist", "removeItem", 19usize, |this| ((**(this as *const *const IWMPPlaylist_Vtbl)).removeItem)(this, piwmpmedia), |result| Some(*result))
+        }
+        unsafe extern "system" fn moveItem(this: *mut core::ffi::c_void, lindexold: i32, lindexnew: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlaylist", "moveItem", 20usize, |this| ((**(this as *const *const IWMPPlaylist_Vtbl)).moveItem)(this, lindexold, lindexnew), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            count,
+            name,
+            Setname,
+            attributeCount,
+            get_attributeName,
+            #[cfg(feature = "Win32_System_Com")]
+            get_item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_item: 0,
+            getItemInfo,
+            setItemInfo,
+            #[cfg(feature = "Win32_System_Com")]
+            get_isIdentical,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_isIdentical: 0,
+            clear,
+            #[cfg(feature = "Win32_System_Com")]
+            insertItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            insertItem: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            appendItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            appendItem: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            removeItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            removeItem: 0,
+            moveItem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPPlaylist as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPPlaylistArray_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlaylistArray", "count", 7usize, |this| ((**(this as *const *const IWMPPlaylistArray_Vtbl)).count)(this, plcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn item(this: *mut core::ffi::c_void, lindex: i32, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlaylistArray", "item", 8usize, |this| ((**(this as *const *const IWMPPlaylistArray_Vtbl)).item)(this, lindex, ppitem), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            count,
+            #[cfg(feature = "Win32_System_Com")]
+            item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            item: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPPlaylistArray as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPPlaylistCollection_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn newPlaylist(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlaylistCollection", "newPlaylist", 7usize, |this| ((**(this as *const *const IWMPPlaylistCollection_Vtbl)).newPlaylist)(this, bstrname, ppitem), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn getAll(this: *mut core::ffi::c_void, ppplaylistarray: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlaylistCollection", "getAll", 8usize, |this| ((**(this as *const *const IWMPPlaylistCollection_Vtbl)).getAll)(this, ppplaylistarray), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn getByName(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, ppplaylistarray: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlaylistCollection", "getByName", 9usize, |this| ((**(this as *const *const IWMPPlaylistCollection_Vtbl)).getByName)(this, bstrname, ppplaylistarray), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn remove(this: *mut core::ffi::c_void, pitem: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlaylistCollection", "remove", 10usize, |this| ((**(this as *const *const IWMPPlaylistCollection_Vtbl)).remove)(this, pitem), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn setDeleted(this: *mut core::ffi::c_void, pitem: *mut core::ffi::c_void, varfisdeleted: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlaylistCollection", "setDeleted", 11usize, |this| ((**(this as *const *const IWMPPlaylistCollection_Vtbl)).setDeleted)(this, pitem, varfisdeleted), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn isDeleted(this: *mut core::ffi::c_void, pitem: *mut core::ffi::c_void, pvarfisdeleted: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlaylistCollection", "isDeleted", 12usize, |this| ((**(this as *const *const IWMPPlaylistCollection_Vtbl)).isDeleted)(this, pitem, pvarfisdeleted), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn importPlaylist(this: *mut core::ffi::c_void, pitem: *mut core::ffi::c_void, ppimporteditem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlaylistCollection", "importPlaylist", 13usize, |this| ((**(this as *const *const IWMPPlaylistCollection_Vtbl)).importPlaylist)(this, pitem, ppimporteditem), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            newPlaylist,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            newPlaylist: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            getAll,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            getAll: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            getByName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            getByName: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            remove,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            remove: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            setDeleted,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            setDeleted: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            isDeleted,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            isDeleted: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            importPlaylist,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            importPlaylist: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPPlaylistCollection as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPPlugin_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Init(this: *mut core::ffi::c_void, dwplaybackcontext: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlugin", "Init", 3usize, |this| ((**(this as *const *const IWMPPlugin_Vtbl)).Init)(this, dwplaybackcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlugin", "Shutdown", 4usize, |this| ((**(this as *const *const IWMPPlugin_Vtbl)).Shutdown)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetID(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlugin", "GetID", 5usize, |this| ((**(this as *const *const IWMPPlugin_Vtbl)).GetID)(this, pguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCaps(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlugin", "GetCaps", 6usize, |this| ((**(this as *const *const IWMPPlugin_Vtbl)).GetCaps)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdviseWMPServices(this: *mut core::ffi::c_void, pwmpservices: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlugin", "AdviseWMPServices", 7usize, |this| ((**(this as *const *const IWMPPlugin_Vtbl)).AdviseWMPServices)(this, pwmpservices), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnAdviseWMPServices(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPlugin", "UnAdviseWMPServices", 8usize, |this| ((**(this as *const *const IWMPPlugin_Vtbl)).UnAdviseWMPServices)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Init,
+            Shutdown,
+            GetID,
+            GetCaps,
+            AdviseWMPServices,
+            UnAdviseWMPServices,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPPlugin as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPPluginEnable_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetEnable(this: *mut core::ffi::c_void, fenable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPluginEnable", "SetEnable", 3usize, |this| ((**(this as *const *const IWMPPluginEnable_Vtbl)).SetEnable)(this, fenable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnable(this: *mut core::ffi::c_void, pfenable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPluginEnable", "GetEnable", 4usize, |this| ((**(this as *const *const IWMPPluginEnable_Vtbl)).GetEnable)(this, pfenable), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetEnable, GetEnable }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPPluginEnable as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_WindowsAndMessaging"))]
+impl windows_core::imp::ProxyVtable for IWMPPluginUI_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetCore(this: *mut core::ffi::c_void, pcore: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPluginUI", "SetCore", 3usize, |this| ((**(this as *const *const IWMPPluginUI_Vtbl)).SetCore)(this, pcore), |result| Some(*result))
+        }
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, phwndwindow: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPluginUI", "Create", 4usize, |this| ((**(this as *const *const IWMPPluginUI_Vtbl)).Create)(this, hwndparent, phwndwindow), |result| Some(*result))
+        }
+        unsafe extern "system" fn Destroy(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPluginUI", "Destroy", 5usize, |this| ((**(this as *const *const IWMPPluginUI_Vtbl)).Destroy)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayPropertyPage(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPluginUI", "DisplayPropertyPage", 6usize, |this| ((**(this as *const *const IWMPPluginUI_Vtbl)).DisplayPropertyPage)(this, hwndparent), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR, pvarproperty: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPluginUI", "GetProperty", 7usize, |this| ((**(this as *const *const IWMPPluginUI_Vtbl)).GetProperty)(this, pwszname, pvarproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR, pvarproperty: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPluginUI", "SetProperty", 8usize, |this| ((**(this as *const *const IWMPPluginUI_Vtbl)).SetProperty)(this, pwszname, pvarproperty), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn TranslateAccelerator(this: *mut core::ffi::c_void, lpmsg: *mut super::super::UI::WindowsAndMessaging::MSG) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPPluginUI", "TranslateAccelerator", 9usize, |this| ((**(this as *const *const IWMPPluginUI_Vtbl)).TranslateAccelerator)(this, lpmsg), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            SetCore,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetCore: 0,
+            Create,
+            Destroy,
+            DisplayPropertyPage,
+            GetProperty,
+            SetProperty,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            TranslateAccelerator,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            TranslateAccelerator: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPPluginUI as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPQuery_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn addCondition(this: *mut core::ffi::c_void, bstrattribute: core::mem::MaybeUninit<windows_core::BSTR>, bstroperator: core::mem::MaybeUninit<windows_core::BSTR>, bstrvalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPQuery", "addCondition", 7usize, |this| ((**(this as *const *const IWMPQuery_Vtbl)).addCondition)(this, bstrattribute, bstroperator, bstrvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn beginNextGroup(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPQuery", "beginNextGroup", 8usize, |this| ((**(this as *const *const IWMPQuery_Vtbl)).beginNextGroup)(this), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, addCondition, beginNextGroup }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPQuery as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPRemoteMediaServices_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetServiceType(this: *mut core::ffi::c_void, pbstrtype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPRemoteMediaServices", "GetServiceType", 3usize, |this| ((**(this as *const *const IWMPRemoteMediaServices_Vtbl)).GetServiceType)(this, pbstrtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetApplicationName(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPRemoteMediaServices", "GetApplicationName", 4usize, |this| ((**(this as *const *const IWMPRemoteMediaServices_Vtbl)).GetApplicationName)(this, pbstrname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetScriptableObject(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>, ppdispatch: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPRemoteMediaServices", "GetScriptableObject", 5usize, |this| ((**(this as *const *const IWMPRemoteMediaServices_Vtbl)).GetScriptableObject)(this, pbstrname, ppdispatch), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCustomUIMode(this: *mut core::ffi::c_void, pbstrfile: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPRemoteMediaServices", "GetCustomUIMode", 6usize, |this| ((**(this as *const *const IWMPRemoteMediaServices_Vtbl)).GetCustomUIMode)(this, pbstrfile), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetServiceType,
+            GetApplicationName,
+            #[cfg(feature = "Win32_System_Com")]
+            GetScriptableObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetScriptableObject: 0,
+            GetCustomUIMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPRemoteMediaServices as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPRenderConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetinProcOnly(this: *mut core::ffi::c_void, finproc: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPRenderConfig", "SetinProcOnly", 3usize, |this| ((**(this as *const *const IWMPRenderConfig_Vtbl)).SetinProcOnly)(this, finproc), |result| Some(*result))
+        }
+        unsafe extern "system" fn inProcOnly(this: *mut core::ffi::c_void, pfinproc: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPRenderConfig", "inProcOnly", 4usize, |this| ((**(this as *const *const IWMPRenderConfig_Vtbl)).inProcOnly)(this, pfinproc), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetinProcOnly, inProcOnly }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPRenderConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPServices_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStreamTime(this: *mut core::ffi::c_void, prt: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPServices", "GetStreamTime", 3usize, |this| ((**(this as *const *const IWMPServices_Vtbl)).GetStreamTime)(this, prt), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamState(this: *mut core::ffi::c_void, pstate: *mut WMPServices_StreamState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPServices", "GetStreamState", 4usize, |this| ((**(this as *const *const IWMPServices_Vtbl)).GetStreamState)(this, pstate), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStreamTime, GetStreamState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPServices as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_isAvailable(this: *mut core::ffi::c_void, bstritem: core::mem::MaybeUninit<windows_core::BSTR>, pisavailable: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSettings", "get_isAvailable", 7usize, |this| ((**(this as *const *const IWMPSettings_Vtbl)).get_isAvailable)(this, bstritem, pisavailable), |result| Some(*result))
+        }
+        unsafe extern "system" fn autoStart(this: *mut core::ffi::c_void, pfautostart: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSettings", "autoStart", 8usize, |this| ((**(this as *const *const IWMPSettings_Vtbl)).autoStart)(this, pfautostart), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetautoStart(this: *mut core::ffi::c_void, fautostart: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSettings", "SetautoStart", 9usize, |this| ((**(this as *const *const IWMPSettings_Vtbl)).SetautoStart)(this, fautostart), |result| Some(*result))
+        }
+        unsafe extern "system" fn baseURL(this: *mut core::ffi::c_void, pbstrbaseurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSettings", "baseURL", 10usize, |this| ((**(this as *const *const IWMPSettings_Vtbl)).baseURL)(this, pbstrbaseurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetbaseURL(this: *mut core::ffi::c_void, bstrbaseurl: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSettings", "SetbaseURL", 11usize, |this| ((**(this as *const *const IWMPSettings_Vtbl)).SetbaseURL)(this, bstrbaseurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn defaultFrame(this: *mut core::ffi::c_void, pbstrdefaultframe: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSettings", "defaultFrame", 12usize, |this| ((**(this as *const *const IWMPSettings_Vtbl)).defaultFrame)(this, pbstrdefaultframe), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetdefaultFrame(this: *mut core::ffi::c_void, bstrdefaultframe: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSettings", "SetdefaultFrame", 13usize, |this| ((**(this as *const *const IWMPSettings_Vtbl)).SetdefaultFrame)(this, bstrdefaultframe), |result| Some(*result))
+        }
+        unsafe extern "system" fn invokeURLs(this: *mut core::ffi::c_void, pfinvokeurls: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSettings", "invokeURLs", 14usize, |this| ((**(this as *const *const IWMPSettings_Vtbl)).invokeURLs)(this, pfinvokeurls), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetinvokeURLs(this: *mut core::ffi::c_void, finvokeurls: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSettings", "SetinvokeURLs", 15usize, |this| ((**(this as *const *const IWMPSettings_Vtbl)).SetinvokeURLs)(this, finvokeurls), |result| Some(*result))
+        }
+        unsafe extern "system" fn mute(this: *mut core::ffi::c_void, pfmute: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSettings", "mute", 16usize, |this| ((**(this as *const *const IWMPSettings_Vtbl)).mute)(this, pfmute), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setmute(this: *mut core::ffi::c_void, fmute: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSettings", "Setmute", 17usize, |this| ((**(this as *const *const IWMPSettings_Vtbl)).Setmute)(this, fmute), |result| Some(*result))
+        }
+        unsafe extern "system" fn playCount(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSettings", "playCount", 18usize, |this| ((**(this as *const *const IWMPSettings_Vtbl)).playCount)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetplayCount(this: *mut core::ffi::c_void, lcount: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSettings", "SetplayCount", 19usize, |this| ((**(this as *const *const IWMPSettings_Vtbl)).SetplayCount)(this, lcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn rate(this: *mut core::ffi::c_void, pdrate: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSettings", "rate", 20usize, |this| ((**(this as *const *const IWMPSettings_Vtbl)).rate)(this, pdrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setrate(this: *mut core::ffi::c_void, drate: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSettings", "Setrate", 21usize, |this| ((**(this as *const *const IWMPSettings_Vtbl)).Setrate)(this, drate), |result| Some(*result))
+        }
+        unsafe extern "system" fn balance(this: *mut core::ffi::c_void, plbalance: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSettings", "balance", 22usize, |this| ((**(this as *const *const IWMPSettings_Vtbl)).balance)(this, plbalance), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setbalance(this: *mut core::ffi::c_void, lbalance: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSettings", "Setbalance", 23usize, |this| ((**(this as *const *const IWMPSettings_Vtbl)).Setbalance)(this, lbalance), |result| Some(*result))
+        }
+        unsafe extern "system" fn volume(this: *mut core::ffi::c_void, plvolume: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSettings", "volume", 24usize, |this| ((**(this as *const *const IWMPSettings_Vtbl)).volume)(this, plvolume), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setvolume(this: *mut core::ffi::c_void, lvolume: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSettings", "Setvolume", 25usize, |this| ((**(this as *const *const IWMPSettings_Vtbl)).Setvolume)(this, lvolume), |result| Some(*result))
+        }
+        unsafe extern "system" fn getMode(this: *mut core::ffi::c_void, bstrmode: core::mem::MaybeUninit<windows_core::BSTR>, pvarfmode: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSettings", "getMode", 26usize, |this| ((**(this as *const *const IWMPSettings_Vtbl)).getMode)(this, bstrmode, pvarfmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn setMode(this: *mut core::ffi::c_void, bstrmode: core::mem::MaybeUninit<windows_core::BSTR>, varfmode: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSettings", "setMode", 27usize, |this| ((**(this as *const *const IWMPSettings_Vtbl)).setMode)(this, bstrmode, varfmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn enableErrorDialogs(this: *mut core::ffi::c_void, pfenableerrordialogs: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSettings", "enableErrorDialogs", 28usize, |this| ((**(this as *const *const IWMPSettings_Vtbl)).enableErrorDialogs)(this, pfenableerrordialogs), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetenableErrorDialogs(this: *mut core::ffi::c_void, fenableerrordialogs: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSettings", "SetenableErrorDialogs", 29usize, |this| ((**(this as *const *const IWMPSettings_Vtbl)).SetenableErrorDialogs)(this, fenableerrordialogs), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            get_isAvailable,
+            autoStart,
+            SetautoStart,
+            baseURL,
+            SetbaseURL,
+            defaultFrame,
+            SetdefaultFrame,
+            invokeURLs,
+            SetinvokeURLs,
+            mute,
+            Setmute,
+            playCount,
+            SetplayCount,
+            rate,
+            Setrate,
+            balance,
+            Setbalance,
+            volume,
+            Setvolume,
+            getMode,
+            setMode,
+            enableErrorDialogs,
+            SetenableErrorDialogs,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPSettings as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPSettings2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn defaultAudioLanguage(this: *mut core::ffi::c_void, pllangid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSettings2", "defaultAudioLanguage", 30usize, |this| ((**(this as *const *const IWMPSettings2_Vtbl)).defaultAudioLanguage)(this, pllangid), |result| Some(*result))
+        }
+        unsafe extern "system" fn mediaAccessRights(this: *mut core::ffi::c_void, pbstrrights: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSettings2", "mediaAccessRights", 31usize, |this| ((**(this as *const *const IWMPSettings2_Vtbl)).mediaAccessRights)(this, pbstrrights), |result| Some(*result))
+        }
+        unsafe extern "system" fn requestMediaAccessRights(this: *mut core::ffi::c_void, bstrdesiredaccess: core::mem::MaybeUninit<windows_core::BSTR>, pvbaccepted: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSettings2", "requestMediaAccessRights", 32usize, |this| ((**(this as *const *const IWMPSettings2_Vtbl)).requestMediaAccessRights)(this, bstrdesiredaccess, pvbaccepted), |result| Some(*result))
+        }
+        Self { base__: <IWMPSettings_Vtbl as windows_core::imp::ProxyVtable>::PROXY, defaultAudioLanguage, mediaAccessRights, requestMediaAccessRights }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPSettings2 as windows_core::Interface>::IID || <IWMPSettings_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPSkinManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetVisualStyle(this: *mut core::ffi::c_void, bstrpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSkinManager", "SetVisualStyle", 3usize, |this| ((**(this as *const *const IWMPSkinManager_Vtbl)).SetVisualStyle)(this, bstrpath), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetVisualStyle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPSkinManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPStringCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPStringCollection", "count", 7usize, |this| ((**(this as *const *const IWMPStringCollection_Vtbl)).count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn item(this: *mut core::ffi::c_void, lindex: i32, pbstrstring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPStringCollection", "item", 8usize, |this| ((**(this as *const *const IWMPStringCollection_Vtbl)).item)(this, lindex, pbstrstring), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, count, item }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPStringCollection as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPStringCollection2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn isIdentical(this: *mut core::ffi::c_void, piwmpstringcollection2: *mut core::ffi::c_void, pvbool: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPStringCollection2", "isIdentical", 9usize, |this| ((**(this as *const *const IWMPStringCollection2_Vtbl)).isIdentical)(this, piwmpstringcollection2, pvbool), |result| Some(*result))
+        }
+        unsafe extern "system" fn getItemInfo(this: *mut core::ffi::c_void, lcollectionindex: i32, bstritemname: core::mem::MaybeUninit<windows_core::BSTR>, pbstrvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPStringCollection2", "getItemInfo", 10usize, |this| ((**(this as *const *const IWMPStringCollection2_Vtbl)).getItemInfo)(this, lcollectionindex, bstritemname, pbstrvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn getAttributeCountByType(this: *mut core::ffi::c_void, lcollectionindex: i32, bstrtype: core::mem::MaybeUninit<windows_core::BSTR>, bstrlanguage: core::mem::MaybeUninit<windows_core::BSTR>, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPStringCollection2", "getAttributeCountByType", 11usize, |this| ((**(this as *const *const IWMPStringCollection2_Vtbl)).getAttributeCountByType)(this, lcollectionindex, bstrtype, bstrlanguage, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn getItemInfoByType(this: *mut core::ffi::c_void, lcollectionindex: i32, bstrtype: core::mem::MaybeUninit<windows_core::BSTR>, bstrlanguage: core::mem::MaybeUninit<windows_core::BSTR>, lattributeindex: i32, pvarvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPStringCollection2", "getItemInfoByType", 12usize, |this| ((**(this as *const *const IWMPStringCollection2_Vtbl)).getItemInfoByType)(this, lcollectionindex, bstrtype, bstrlanguage, lattributeindex, pvarvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMPStringCollection_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            isIdentical,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            isIdentical: 0,
+            getItemInfo,
+            getAttributeCountByType,
+            getItemInfoByType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPStringCollection2 as windows_core::Interface>::IID || <IWMPStringCollection_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPSubscriptionService_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn allowPlay(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, pmedia: *mut core::ffi::c_void, pfallowplay: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSubscriptionService", "allowPlay", 3usize, |this| ((**(this as *const *const IWMPSubscriptionService_Vtbl)).allowPlay)(this, hwnd, pmedia, pfallowplay), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn allowCDBurn(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, pplaylist: *mut core::ffi::c_void, pfallowburn: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSubscriptionService", "allowCDBurn", 4usize, |this| ((**(this as *const *const IWMPSubscriptionService_Vtbl)).allowCDBurn)(this, hwnd, pplaylist, pfallowburn), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn allowPDATransfer(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, pplaylist: *mut core::ffi::c_void, pfallowtransfer: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSubscriptionService", "allowPDATransfer", 5usize, |this| ((**(this as *const *const IWMPSubscriptionService_Vtbl)).allowPDATransfer)(this, hwnd, pplaylist, pfallowtransfer), |result| Some(*result))
+        }
+        unsafe extern "system" fn startBackgroundProcessing(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSubscriptionService", "startBackgroundProcessing", 6usize, |this| ((**(this as *const *const IWMPSubscriptionService_Vtbl)).startBackgroundProcessing)(this, hwnd), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            allowPlay,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            allowPlay: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            allowCDBurn,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            allowCDBurn: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            allowPDATransfer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            allowPDATransfer: 0,
+            startBackgroundProcessing,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPSubscriptionService as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPSubscriptionService2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn stopBackgroundProcessing(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSubscriptionService2", "stopBackgroundProcessing", 7usize, |this| ((**(this as *const *const IWMPSubscriptionService2_Vtbl)).stopBackgroundProcessing)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn serviceEvent(this: *mut core::ffi::c_void, event: WMPSubscriptionServiceEvent) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSubscriptionService2", "serviceEvent", 8usize, |this| ((**(this as *const *const IWMPSubscriptionService2_Vtbl)).serviceEvent)(this, event), |result| Some(*result))
+        }
+        unsafe extern "system" fn deviceAvailable(this: *mut core::ffi::c_void, bstrdevicename: core::mem::MaybeUninit<windows_core::BSTR>, pcb: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSubscriptionService2", "deviceAvailable", 9usize, |this| ((**(this as *const *const IWMPSubscriptionService2_Vtbl)).deviceAvailable)(this, bstrdevicename, pcb), |result| Some(*result))
+        }
+        unsafe extern "system" fn prepareForSync(this: *mut core::ffi::c_void, bstrfilename: core::mem::MaybeUninit<windows_core::BSTR>, bstrdevicename: core::mem::MaybeUninit<windows_core::BSTR>, pcb: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSubscriptionService2", "prepareForSync", 10usize, |this| ((**(this as *const *const IWMPSubscriptionService2_Vtbl)).prepareForSync)(this, bstrfilename, bstrdevicename, pcb), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMPSubscriptionService_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            stopBackgroundProcessing,
+            serviceEvent,
+            deviceAvailable,
+            prepareForSync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPSubscriptionService2 as windows_core::Interface>::IID || <IWMPSubscriptionService_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPSubscriptionServiceCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn onComplete(this: *mut core::ffi::c_void, hrresult: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSubscriptionServiceCallback", "onComplete", 3usize, |this| ((**(this as *const *const IWMPSubscriptionServiceCallback_Vtbl)).onComplete)(this, hrresult), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, onComplete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPSubscriptionServiceCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPSyncDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn friendlyName(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSyncDevice", "friendlyName", 3usize, |this| ((**(this as *const *const IWMPSyncDevice_Vtbl)).friendlyName)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetfriendlyName(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSyncDevice", "SetfriendlyName", 4usize, |this| ((**(this as *const *const IWMPSyncDevice_Vtbl)).SetfriendlyName)(this, bstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn deviceName(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSyncDevice", "deviceName", 5usize, |this| ((**(this as *const *const IWMPSyncDevice_Vtbl)).deviceName)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn deviceId(this: *mut core::ffi::c_void, pbstrdeviceid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSyncDevice", "deviceId", 6usize, |this| ((**(this as *const *const IWMPSyncDevice_Vtbl)).deviceId)(this, pbstrdeviceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn partnershipIndex(this: *mut core::ffi::c_void, plindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSyncDevice", "partnershipIndex", 7usize, |this| ((**(this as *const *const IWMPSyncDevice_Vtbl)).partnershipIndex)(this, plindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn connected(this: *mut core::ffi::c_void, pvbconnected: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSyncDevice", "connected", 8usize, |this| ((**(this as *const *const IWMPSyncDevice_Vtbl)).connected)(this, pvbconnected), |result| Some(*result))
+        }
+        unsafe extern "system" fn status(this: *mut core::ffi::c_void, pwmpds: *mut WMPDeviceStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSyncDevice", "status", 9usize, |this| ((**(this as *const *const IWMPSyncDevice_Vtbl)).status)(this, pwmpds), |result| Some(*result))
+        }
+        unsafe extern "system" fn syncState(this: *mut core::ffi::c_void, pwmpss: *mut WMPSyncState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSyncDevice", "syncState", 10usize, |this| ((**(this as *const *const IWMPSyncDevice_Vtbl)).syncState)(this, pwmpss), |result| Some(*result))
+        }
+        unsafe extern "system" fn progress(this: *mut core::ffi::c_void, plprogress: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSyncDevice", "progress", 11usize, |this| ((**(this as *const *const IWMPSyncDevice_Vtbl)).progress)(this, plprogress), |result| Some(*result))
+        }
+        unsafe extern "system" fn getItemInfo(this: *mut core::ffi::c_void, bstritemname: core::mem::MaybeUninit<windows_core::BSTR>, pbstrval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSyncDevice", "getItemInfo", 12usize, |this| ((**(this as *const *const IWMPSyncDevice_Vtbl)).getItemInfo)(this, bstritemname, pbstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn createPartnership(this: *mut core::ffi::c_void, vbshowui: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSyncDevice", "createPartnership", 13usize, |this| ((**(this as *const *const IWMPSyncDevice_Vtbl)).createPartnership)(this, vbshowui), |result| Some(*result))
+        }
+        unsafe extern "system" fn deletePartnership(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSyncDevice", "deletePartnership", 14usize, |this| ((**(this as *const *const IWMPSyncDevice_Vtbl)).deletePartnership)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSyncDevice", "start", 15usize, |this| ((**(this as *const *const IWMPSyncDevice_Vtbl)).start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSyncDevice", "stop", 16usize, |this| ((**(this as *const *const IWMPSyncDevice_Vtbl)).stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn showSettings(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSyncDevice", "showSettings", 17usize, |this| ((**(this as *const *const IWMPSyncDevice_Vtbl)).showSettings)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn isIdentical(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, pvbool: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSyncDevice", "isIdentical", 18usize, |this| ((**(this as *const *const IWMPSyncDevice_Vtbl)).isIdentical)(this, pdevice, pvbool), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            friendlyName,
+            SetfriendlyName,
+            deviceName,
+            deviceId,
+            partnershipIndex,
+            connected,
+            status,
+            syncState,
+            progress,
+            getItemInfo,
+            createPartnership,
+            deletePartnership,
+            start,
+            stop,
+            showSettings,
+            isIdentical,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPSyncDevice as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPSyncDevice2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn setItemInfo(this: *mut core::ffi::c_void, bstritemname: core::mem::MaybeUninit<windows_core::BSTR>, bstrval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSyncDevice2", "setItemInfo", 19usize, |this| ((**(this as *const *const IWMPSyncDevice2_Vtbl)).setItemInfo)(this, bstritemname, bstrval), |result| Some(*result))
+        }
+        Self { base__: <IWMPSyncDevice_Vtbl as windows_core::imp::ProxyVtable>::PROXY, setItemInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPSyncDevice2 as windows_core::Interface>::IID || <IWMPSyncDevice_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMPSyncDevice3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn estimateSyncSize(this: *mut core::ffi::c_void, pnonruleplaylist: *mut core::ffi::c_void, prulesplaylist: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSyncDevice3", "estimateSyncSize", 20usize, |this| ((**(this as *const *const IWMPSyncDevice3_Vtbl)).estimateSyncSize)(this, pnonruleplaylist, prulesplaylist), |result| Some(*result))
+        }
+        unsafe extern "system" fn cancelEstimation(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSyncDevice3", "cancelEstimation", 21usize, |this| ((**(this as *const *const IWMPSyncDevice3_Vtbl)).cancelEstimation)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMPSyncDevice2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            estimateSyncSize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            estimateSyncSize: 0,
+            cancelEstimation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPSyncDevice3 as windows_core::Interface>::IID || <IWMPSyncDevice2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPSyncServices_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn deviceCount(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSyncServices", "deviceCount", 3usize, |this| ((**(this as *const *const IWMPSyncServices_Vtbl)).deviceCount)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn getDevice(this: *mut core::ffi::c_void, lindex: i32, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPSyncServices", "getDevice", 4usize, |this| ((**(this as *const *const IWMPSyncServices_Vtbl)).getDevice)(this, lindex, ppdevice), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, deviceCount, getDevice }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPSyncServices as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPTranscodePolicy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn allowTranscode(this: *mut core::ffi::c_void, pvballow: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPTranscodePolicy", "allowTranscode", 3usize, |this| ((**(this as *const *const IWMPTranscodePolicy_Vtbl)).allowTranscode)(this, pvballow), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, allowTranscode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPTranscodePolicy as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPUserEventSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NotifyUserEvent(this: *mut core::ffi::c_void, eventcode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPUserEventSink", "NotifyUserEvent", 3usize, |this| ((**(this as *const *const IWMPUserEventSink_Vtbl)).NotifyUserEvent)(this, eventcode), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NotifyUserEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPUserEventSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_MediaFoundation")]
+impl windows_core::imp::ProxyVtable for IWMPVideoRenderConfig_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn SetpresenterActivate(this: *mut core::ffi::c_void, pactivate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPVideoRenderConfig", "SetpresenterActivate", 3usize, |this| ((**(this as *const *const IWMPVideoRenderConfig_Vtbl)).SetpresenterActivate)(this, pactivate), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            SetpresenterActivate,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            SetpresenterActivate: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPVideoRenderConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPWindowMessageSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnWindowMessage(this: *mut core::ffi::c_void, umsg: u32, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM, plret: *mut super::super::Foundation::LRESULT, pfhandled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPWindowMessageSink", "OnWindowMessage", 3usize, |this| ((**(this as *const *const IWMPWindowMessageSink_Vtbl)).OnWindowMessage)(this, umsg, wparam, lparam, plret, pfhandled), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnWindowMessage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPWindowMessageSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXFeed_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Xml(this: *mut core::ffi::c_void, uiitemcount: u32, sortproperty: FEEDS_XML_SORT_PROPERTY, sortorder: FEEDS_XML_SORT_ORDER, filterflags: FEEDS_XML_FILTER_FLAGS, includeflags: FEEDS_XML_INCLUDE_FLAGS, pps: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "Xml", 3usize, |this| ((**(this as *const *const IXFeed_Vtbl)).Xml)(this, uiitemcount, sortproperty, sortorder, filterflags, includeflags, pps), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, ppszname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "Name", 4usize, |this| ((**(this as *const *const IXFeed_Vtbl)).Name)(this, ppszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rename(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "Rename", 5usize, |this| ((**(this as *const *const IXFeed_Vtbl)).Rename)(this, pszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Url(this: *mut core::ffi::c_void, ppszurl: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "Url", 6usize, |this| ((**(this as *const *const IXFeed_Vtbl)).Url)(this, ppszurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUrl(this: *mut core::ffi::c_void, pszurl: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "SetUrl", 7usize, |this| ((**(this as *const *const IXFeed_Vtbl)).SetUrl)(this, pszurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalId(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "LocalId", 8usize, |this| ((**(this as *const *const IXFeed_Vtbl)).LocalId)(this, pguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, ppszpath: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "Path", 9usize, |this| ((**(this as *const *const IXFeed_Vtbl)).Path)(this, ppszpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn Move(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "Move", 10usize, |this| ((**(this as *const *const IXFeed_Vtbl)).Move)(this, pszpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn Parent(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "Parent", 11usize, |this| ((**(this as *const *const IXFeed_Vtbl)).Parent)(this, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastWriteTime(this: *mut core::ffi::c_void, pstlastwritetime: *mut super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "LastWriteTime", 12usize, |this| ((**(this as *const *const IXFeed_Vtbl)).LastWriteTime)(this, pstlastwritetime), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "Delete", 13usize, |this| ((**(this as *const *const IXFeed_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Download(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "Download", 14usize, |this| ((**(this as *const *const IXFeed_Vtbl)).Download)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AsyncDownload(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "AsyncDownload", 15usize, |this| ((**(this as *const *const IXFeed_Vtbl)).AsyncDownload)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelAsyncDownload(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "CancelAsyncDownload", 16usize, |this| ((**(this as *const *const IXFeed_Vtbl)).CancelAsyncDownload)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncSetting(this: *mut core::ffi::c_void, pfss: *mut FEEDS_SYNC_SETTING) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "SyncSetting", 17usize, |this| ((**(this as *const *const IXFeed_Vtbl)).SyncSetting)(this, pfss), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSyncSetting(this: *mut core::ffi::c_void, fss: FEEDS_SYNC_SETTING) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "SetSyncSetting", 18usize, |this| ((**(this as *const *const IXFeed_Vtbl)).SetSyncSetting)(this, fss), |result| Some(*result))
+        }
+        unsafe extern "system" fn Interval(this: *mut core::ffi::c_void, puiinterval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "Interval", 19usize, |this| ((**(this as *const *const IXFeed_Vtbl)).Interval)(this, puiinterval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInterval(this: *mut core::ffi::c_void, uiinterval: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "SetInterval", 20usize, |this| ((**(this as *const *const IXFeed_Vtbl)).SetInterval)(this, uiinterval), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastDownloadTime(this: *mut core::ffi::c_void, pstlastdownloadtime: *mut super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "LastDownloadTime", 21usize, |this| ((**(this as *const *const IXFeed_Vtbl)).LastDownloadTime)(this, pstlastdownloadtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalEnclosurePath(this: *mut core::ffi::c_void, ppszpath: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "LocalEnclosurePath", 22usize, |this| ((**(this as *const *const IXFeed_Vtbl)).LocalEnclosurePath)(this, ppszpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn Items(this: *mut core::ffi::c_void, ppfe: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "Items", 23usize, |this| ((**(this as *const *const IXFeed_Vtbl)).Items)(this, ppfe), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItem(this: *mut core::ffi::c_void, uiid: u32, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "GetItem", 24usize, |this| ((**(this as *const *const IXFeed_Vtbl)).GetItem)(this, uiid, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn MarkAllItemsRead(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "MarkAllItemsRead", 25usize, |this| ((**(this as *const *const IXFeed_Vtbl)).MarkAllItemsRead)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxItemCount(this: *mut core::ffi::c_void, puimaxitemcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "MaxItemCount", 26usize, |this| ((**(this as *const *const IXFeed_Vtbl)).MaxItemCount)(this, puimaxitemcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxItemCount(this: *mut core::ffi::c_void, uimaxitemcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "SetMaxItemCount", 27usize, |this| ((**(this as *const *const IXFeed_Vtbl)).SetMaxItemCount)(this, uimaxitemcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadEnclosuresAutomatically(this: *mut core::ffi::c_void, pbdownloadenclosuresautomatically: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "DownloadEnclosuresAutomatically", 28usize, |this| ((**(this as *const *const IXFeed_Vtbl)).DownloadEnclosuresAutomatically)(this, pbdownloadenclosuresautomatically), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDownloadEnclosuresAutomatically(this: *mut core::ffi::c_void, bdownloadenclosuresautomatically: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "SetDownloadEnclosuresAutomatically", 29usize, |this| ((**(this as *const *const IXFeed_Vtbl)).SetDownloadEnclosuresAutomatically)(this, bdownloadenclosuresautomatically), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadStatus(this: *mut core::ffi::c_void, pfds: *mut FEEDS_DOWNLOAD_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "DownloadStatus", 30usize, |this| ((**(this as *const *const IXFeed_Vtbl)).DownloadStatus)(this, pfds), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastDownloadError(this: *mut core::ffi::c_void, pfde: *mut FEEDS_DOWNLOAD_ERROR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "LastDownloadError", 31usize, |this| ((**(this as *const *const IXFeed_Vtbl)).LastDownloadError)(this, pfde), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Merge(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, pszurl: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "Merge", 32usize, |this| ((**(this as *const *const IXFeed_Vtbl)).Merge)(this, pstream, pszurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadUrl(this: *mut core::ffi::c_void, ppszurl: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "DownloadUrl", 33usize, |this| ((**(this as *const *const IXFeed_Vtbl)).DownloadUrl)(this, ppszurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, ppsztitle: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "Title", 34usize, |this| ((**(this as *const *const IXFeed_Vtbl)).Title)(this, ppsztitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, ppszdescription: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "Description", 35usize, |this| ((**(this as *const *const IXFeed_Vtbl)).Description)(this, ppszdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn Link(this: *mut core::ffi::c_void, ppszhomepage: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "Link", 36usize, |this| ((**(this as *const *const IXFeed_Vtbl)).Link)(this, ppszhomepage), |result| Some(*result))
+        }
+        unsafe extern "system" fn Image(this: *mut core::ffi::c_void, ppszimageurl: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "Image", 37usize, |this| ((**(this as *const *const IXFeed_Vtbl)).Image)(this, ppszimageurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastBuildDate(this: *mut core::ffi::c_void, pstlastbuilddate: *mut super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "LastBuildDate", 38usize, |this| ((**(this as *const *const IXFeed_Vtbl)).LastBuildDate)(this, pstlastbuilddate), |result| Some(*result))
+        }
+        unsafe extern "system" fn PubDate(this: *mut core::ffi::c_void, pstpubdate: *mut super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "PubDate", 39usize, |this| ((**(this as *const *const IXFeed_Vtbl)).PubDate)(this, pstpubdate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ttl(this: *mut core::ffi::c_void, puittl: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "Ttl", 40usize, |this| ((**(this as *const *const IXFeed_Vtbl)).Ttl)(this, puittl), |result| Some(*result))
+        }
+        unsafe extern "system" fn Language(this: *mut core::ffi::c_void, ppszlanguage: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "Language", 41usize, |this| ((**(this as *const *const IXFeed_Vtbl)).Language)(this, ppszlanguage), |result| Some(*result))
+        }
+        unsafe extern "system" fn Copyright(this: *mut core::ffi::c_void, ppszcopyright: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "Copyright", 42usize, |this| ((**(this as *const *const IXFeed_Vtbl)).Copyright)(this, ppszcopyright), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsList(this: *mut core::ffi::c_void, pbislist: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "IsList", 43usize, |this| ((**(this as *const *const IXFeed_Vtbl)).IsList)(this, pbislist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWatcher(this: *mut core::ffi::c_void, scope: FEEDS_EVENTS_SCOPE, mask: FEEDS_EVENTS_MASK, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "GetWatcher", 44usize, |this| ((**(this as *const *const IXFeed_Vtbl)).GetWatcher)(this, scope, mask, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnreadItemCount(this: *mut core::ffi::c_void, puiunreaditemcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "UnreadItemCount", 45usize, |this| ((**(this as *const *const IXFeed_Vtbl)).UnreadItemCount)(this, puiunreaditemcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItemCount(this: *mut core::ffi::c_void, puiitemcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed", "ItemCount", 46usize, |this| ((**(this as *const *const IXFeed_Vtbl)).ItemCount)(this, puiitemcount), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Xml,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Xml: 0,
+            Name,
+            Rename,
+            Url,
+            SetUrl,
+            LocalId,
+            Path,
+            Move,
+            Parent,
+            LastWriteTime,
+            Delete,
+            Download,
+            AsyncDownload,
+            CancelAsyncDownload,
+            SyncSetting,
+            SetSyncSetting,
+            Interval,
+            SetInterval,
+            LastDownloadTime,
+            LocalEnclosurePath,
+            Items,
+            GetItem,
+            MarkAllItemsRead,
+            MaxItemCount,
+            SetMaxItemCount,
+            DownloadEnclosuresAutomatically,
+            SetDownloadEnclosuresAutomatically,
+            DownloadStatus,
+            LastDownloadError,
+            #[cfg(feature = "Win32_System_Com")]
+            Merge,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Merge: 0,
+            DownloadUrl,
+            Title,
+            Description,
+            Link,
+            Image,
+            LastBuildDate,
+            PubDate,
+            Ttl,
+            Language,
+            Copyright,
+            IsList,
+            GetWatcher,
+            UnreadItemCount,
+            ItemCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXFeed as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXFeed2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetItemByEffectiveId(this: *mut core::ffi::c_void, uieffectiveid: u32, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed2", "GetItemByEffectiveId", 47usize, |this| ((**(this as *const *const IXFeed2_Vtbl)).GetItemByEffectiveId)(this, uieffectiveid, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastItemDownloadTime(this: *mut core::ffi::c_void, pstlastitemdownloadtime: *mut super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed2", "LastItemDownloadTime", 48usize, |this| ((**(this as *const *const IXFeed2_Vtbl)).LastItemDownloadTime)(this, pstlastitemdownloadtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn Username(this: *mut core::ffi::c_void, ppszusername: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed2", "Username", 49usize, |this| ((**(this as *const *const IXFeed2_Vtbl)).Username)(this, ppszusername), |result| Some(*result))
+        }
+        unsafe extern "system" fn Password(this: *mut core::ffi::c_void, ppszpassword: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed2", "Password", 50usize, |this| ((**(this as *const *const IXFeed2_Vtbl)).Password)(this, ppszpassword), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCredentials(this: *mut core::ffi::c_void, pszusername: windows_core::PCWSTR, pszpassword: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed2", "SetCredentials", 51usize, |this| ((**(this as *const *const IXFeed2_Vtbl)).SetCredentials)(this, pszusername, pszpassword), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearCredentials(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeed2", "ClearCredentials", 52usize, |this| ((**(this as *const *const IXFeed2_Vtbl)).ClearCredentials)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IXFeed_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetItemByEffectiveId,
+            LastItemDownloadTime,
+            Username,
+            Password,
+            SetCredentials,
+            ClearCredentials,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXFeed2 as windows_core::Interface>::IID || <IXFeed_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXFeedEnclosure_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Url(this: *mut core::ffi::c_void, ppszurl: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedEnclosure", "Url", 3usize, |this| ((**(this as *const *const IXFeedEnclosure_Vtbl)).Url)(this, ppszurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, ppszmimetype: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedEnclosure", "Type", 4usize, |this| ((**(this as *const *const IXFeedEnclosure_Vtbl)).Type)(this, ppszmimetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, puilength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedEnclosure", "Length", 5usize, |this| ((**(this as *const *const IXFeedEnclosure_Vtbl)).Length)(this, puilength), |result| Some(*result))
+        }
+        unsafe extern "system" fn AsyncDownload(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedEnclosure", "AsyncDownload", 6usize, |this| ((**(this as *const *const IXFeedEnclosure_Vtbl)).AsyncDownload)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelAsyncDownload(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedEnclosure", "CancelAsyncDownload", 7usize, |this| ((**(this as *const *const IXFeedEnclosure_Vtbl)).CancelAsyncDownload)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadStatus(this: *mut core::ffi::c_void, pfds: *mut FEEDS_DOWNLOAD_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedEnclosure", "DownloadStatus", 8usize, |this| ((**(this as *const *const IXFeedEnclosure_Vtbl)).DownloadStatus)(this, pfds), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastDownloadError(this: *mut core::ffi::c_void, pfde: *mut FEEDS_DOWNLOAD_ERROR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedEnclosure", "LastDownloadError", 9usize, |this| ((**(this as *const *const IXFeedEnclosure_Vtbl)).LastDownloadError)(this, pfde), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalPath(this: *mut core::ffi::c_void, ppszpath: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedEnclosure", "LocalPath", 10usize, |this| ((**(this as *const *const IXFeedEnclosure_Vtbl)).LocalPath)(this, ppszpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn Parent(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedEnclosure", "Parent", 11usize, |this| ((**(this as *const *const IXFeedEnclosure_Vtbl)).Parent)(this, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadUrl(this: *mut core::ffi::c_void, ppszurl: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedEnclosure", "DownloadUrl", 12usize, |this| ((**(this as *const *const IXFeedEnclosure_Vtbl)).DownloadUrl)(this, ppszurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadMimeType(this: *mut core::ffi::c_void, ppszmimetype: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedEnclosure", "DownloadMimeType", 13usize, |this| ((**(this as *const *const IXFeedEnclosure_Vtbl)).DownloadMimeType)(this, ppszmimetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFile(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedEnclosure", "RemoveFile", 14usize, |this| ((**(this as *const *const IXFeedEnclosure_Vtbl)).RemoveFile)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFile(this: *mut core::ffi::c_void, pszdownloadurl: windows_core::PCWSTR, pszdownloadfilepath: windows_core::PCWSTR, pszdownloadmimetype: windows_core::PCWSTR, pszenclosurefilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedEnclosure", "SetFile", 15usize, |this| ((**(this as *const *const IXFeedEnclosure_Vtbl)).SetFile)(this, pszdownloadurl, pszdownloadfilepath, pszdownloadmimetype, pszenclosurefilename), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Url,
+            Type,
+            Length,
+            AsyncDownload,
+            CancelAsyncDownload,
+            DownloadStatus,
+            LastDownloadError,
+            LocalPath,
+            Parent,
+            DownloadUrl,
+            DownloadMimeType,
+            RemoveFile,
+            SetFile,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXFeedEnclosure as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXFeedEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedEvents", "Error", 3usize, |this| ((**(this as *const *const IXFeedEvents_Vtbl)).Error)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedDeleted(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedEvents", "FeedDeleted", 4usize, |this| ((**(this as *const *const IXFeedEvents_Vtbl)).FeedDeleted)(this, pszpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedRenamed(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, pszoldpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedEvents", "FeedRenamed", 5usize, |this| ((**(this as *const *const IXFeedEvents_Vtbl)).FeedRenamed)(this, pszpath, pszoldpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedUrlChanged(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedEvents", "FeedUrlChanged", 6usize, |this| ((**(this as *const *const IXFeedEvents_Vtbl)).FeedUrlChanged)(this, pszpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedMoved(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, pszoldpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedEvents", "FeedMoved", 7usize, |this| ((**(this as *const *const IXFeedEvents_Vtbl)).FeedMoved)(this, pszpath, pszoldpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedDownloading(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedEvents", "FeedDownloading", 8usize, |this| ((**(this as *const *const IXFeedEvents_Vtbl)).FeedDownloading)(this, pszpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedDownloadCompleted(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, fde: FEEDS_DOWNLOAD_ERROR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedEvents", "FeedDownloadCompleted", 9usize, |this| ((**(this as *const *const IXFeedEvents_Vtbl)).FeedDownloadCompleted)(this, pszpath, fde), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedItemCountChanged(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, feicfflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedEvents", "FeedItemCountChanged", 10usize, |this| ((**(this as *const *const IXFeedEvents_Vtbl)).FeedItemCountChanged)(this, pszpath, feicfflags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Error,
+            FeedDeleted,
+            FeedRenamed,
+            FeedUrlChanged,
+            FeedMoved,
+            FeedDownloading,
+            FeedDownloadCompleted,
+            FeedItemCountChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXFeedEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXFeedFolder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Feeds(this: *mut core::ffi::c_void, ppfe: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolder", "Feeds", 3usize, |this| ((**(this as *const *const IXFeedFolder_Vtbl)).Feeds)(this, ppfe), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subfolders(this: *mut core::ffi::c_void, ppfe: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolder", "Subfolders", 4usize, |this| ((**(this as *const *const IXFeedFolder_Vtbl)).Subfolders)(this, ppfe), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFeed(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, pszurl: windows_core::PCWSTR, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolder", "CreateFeed", 5usize, |this| ((**(this as *const *const IXFeedFolder_Vtbl)).CreateFeed)(this, pszname, pszurl, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSubfolder(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolder", "CreateSubfolder", 6usize, |this| ((**(this as *const *const IXFeedFolder_Vtbl)).CreateSubfolder)(this, pszname, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExistsFeed(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, pbfeedexists: *const super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolder", "ExistsFeed", 7usize, |this| ((**(this as *const *const IXFeedFolder_Vtbl)).ExistsFeed)(this, pszname, pbfeedexists), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExistsSubfolder(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, pbsubfolderexists: *const super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolder", "ExistsSubfolder", 8usize, |this| ((**(this as *const *const IXFeedFolder_Vtbl)).ExistsSubfolder)(this, pszname, pbsubfolderexists), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFeed(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolder", "GetFeed", 9usize, |this| ((**(this as *const *const IXFeedFolder_Vtbl)).GetFeed)(this, pszname, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubfolder(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolder", "GetSubfolder", 10usize, |this| ((**(this as *const *const IXFeedFolder_Vtbl)).GetSubfolder)(this, pszname, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolder", "Delete", 11usize, |this| ((**(this as *const *const IXFeedFolder_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, ppszname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolder", "Name", 12usize, |this| ((**(this as *const *const IXFeedFolder_Vtbl)).Name)(this, ppszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rename(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolder", "Rename", 13usize, |this| ((**(this as *const *const IXFeedFolder_Vtbl)).Rename)(this, pszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, ppszpath: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolder", "Path", 14usize, |this| ((**(this as *const *const IXFeedFolder_Vtbl)).Path)(this, ppszpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn Move(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolder", "Move", 15usize, |this| ((**(this as *const *const IXFeedFolder_Vtbl)).Move)(this, pszpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn Parent(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolder", "Parent", 16usize, |this| ((**(this as *const *const IXFeedFolder_Vtbl)).Parent)(this, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRoot(this: *mut core::ffi::c_void, pbisrootfeedfolder: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolder", "IsRoot", 17usize, |this| ((**(this as *const *const IXFeedFolder_Vtbl)).IsRoot)(this, pbisrootfeedfolder), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWatcher(this: *mut core::ffi::c_void, scope: FEEDS_EVENTS_SCOPE, mask: FEEDS_EVENTS_MASK, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolder", "GetWatcher", 18usize, |this| ((**(this as *const *const IXFeedFolder_Vtbl)).GetWatcher)(this, scope, mask, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalUnreadItemCount(this: *mut core::ffi::c_void, puitotalunreaditemcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolder", "TotalUnreadItemCount", 19usize, |this| ((**(this as *const *const IXFeedFolder_Vtbl)).TotalUnreadItemCount)(this, puitotalunreaditemcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalItemCount(this: *mut core::ffi::c_void, puitotalitemcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolder", "TotalItemCount", 20usize, |this| ((**(this as *const *const IXFeedFolder_Vtbl)).TotalItemCount)(this, puitotalitemcount), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Feeds,
+            Subfolders,
+            CreateFeed,
+            CreateSubfolder,
+            ExistsFeed,
+            ExistsSubfolder,
+            GetFeed,
+            GetSubfolder,
+            Delete,
+            Name,
+            Rename,
+            Path,
+            Move,
+            Parent,
+            IsRoot,
+            GetWatcher,
+            TotalUnreadItemCount,
+            TotalItemCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXFeedFolder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXFeedFolderEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolderEvents", "Error", 3usize, |this| ((**(this as *const *const IXFeedFolderEvents_Vtbl)).Error)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn FolderAdded(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolderEvents", "FolderAdded", 4usize, |this| ((**(this as *const *const IXFeedFolderEvents_Vtbl)).FolderAdded)(this, pszpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn FolderDeleted(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolderEvents", "FolderDeleted", 5usize, |this| ((**(this as *const *const IXFeedFolderEvents_Vtbl)).FolderDeleted)(this, pszpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn FolderRenamed(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, pszoldpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolderEvents", "FolderRenamed", 6usize, |this| ((**(this as *const *const IXFeedFolderEvents_Vtbl)).FolderRenamed)(this, pszpath, pszoldpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn FolderMovedFrom(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, pszoldpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolderEvents", "FolderMovedFrom", 7usize, |this| ((**(this as *const *const IXFeedFolderEvents_Vtbl)).FolderMovedFrom)(this, pszpath, pszoldpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn FolderMovedTo(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, pszoldpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolderEvents", "FolderMovedTo", 8usize, |this| ((**(this as *const *const IXFeedFolderEvents_Vtbl)).FolderMovedTo)(this, pszpath, pszoldpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn FolderItemCountChanged(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, feicfflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolderEvents", "FolderItemCountChanged", 9usize, |this| ((**(this as *const *const IXFeedFolderEvents_Vtbl)).FolderItemCountChanged)(this, pszpath, feicfflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedAdded(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolderEvents", "FeedAdded", 10usize, |this| ((**(this as *const *const IXFeedFolderEvents_Vtbl)).FeedAdded)(this, pszpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedDeleted(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolderEvents", "FeedDeleted", 11usize, |this| ((**(this as *const *const IXFeedFolderEvents_Vtbl)).FeedDeleted)(this, pszpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedRenamed(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, pszoldpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolderEvents", "FeedRenamed", 12usize, |this| ((**(this as *const *const IXFeedFolderEvents_Vtbl)).FeedRenamed)(this, pszpath, pszoldpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedUrlChanged(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolderEvents", "FeedUrlChanged", 13usize, |this| ((**(this as *const *const IXFeedFolderEvents_Vtbl)).FeedUrlChanged)(this, pszpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedMovedFrom(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, pszoldpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolderEvents", "FeedMovedFrom", 14usize, |this| ((**(this as *const *const IXFeedFolderEvents_Vtbl)).FeedMovedFrom)(this, pszpath, pszoldpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedMovedTo(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, pszoldpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolderEvents", "FeedMovedTo", 15usize, |this| ((**(this as *const *const IXFeedFolderEvents_Vtbl)).FeedMovedTo)(this, pszpath, pszoldpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedDownloading(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolderEvents", "FeedDownloading", 16usize, |this| ((**(this as *const *const IXFeedFolderEvents_Vtbl)).FeedDownloading)(this, pszpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedDownloadCompleted(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, fde: FEEDS_DOWNLOAD_ERROR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolderEvents", "FeedDownloadCompleted", 17usize, |this| ((**(this as *const *const IXFeedFolderEvents_Vtbl)).FeedDownloadCompleted)(this, pszpath, fde), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeedItemCountChanged(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, feicfflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedFolderEvents", "FeedItemCountChanged", 18usize, |this| ((**(this as *const *const IXFeedFolderEvents_Vtbl)).FeedItemCountChanged)(this, pszpath, feicfflags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Error,
+            FolderAdded,
+            FolderDeleted,
+            FolderRenamed,
+            FolderMovedFrom,
+            FolderMovedTo,
+            FolderItemCountChanged,
+            FeedAdded,
+            FeedDeleted,
+            FeedRenamed,
+            FeedUrlChanged,
+            FeedMovedFrom,
+            FeedMovedTo,
+            FeedDownloading,
+            FeedDownloadCompleted,
+            FeedItemCountChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXFeedFolderEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXFeedItem_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Xml(this: *mut core::ffi::c_void, fxif: FEEDS_XML_INCLUDE_FLAGS, pps: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedItem", "Xml", 3usize, |this| ((**(this as *const *const IXFeedItem_Vtbl)).Xml)(this, fxif, pps), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, ppsztitle: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedItem", "Title", 4usize, |this| ((**(this as *const *const IXFeedItem_Vtbl)).Title)(this, ppsztitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn Link(this: *mut core::ffi::c_void, ppszurl: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedItem", "Link", 5usize, |this| ((**(this as *const *const IXFeedItem_Vtbl)).Link)(this, ppszurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn Guid(this: *mut core::ffi::c_void, ppszguid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedItem", "Guid", 6usize, |this| ((**(this as *const *const IXFeedItem_Vtbl)).Guid)(this, ppszguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, ppszdescription: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedItem", "Description", 7usize, |this| ((**(this as *const *const IXFeedItem_Vtbl)).Description)(this, ppszdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn PubDate(this: *mut core::ffi::c_void, pstpubdate: *mut super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedItem", "PubDate", 8usize, |this| ((**(this as *const *const IXFeedItem_Vtbl)).PubDate)(this, pstpubdate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Comments(this: *mut core::ffi::c_void, ppszurl: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedItem", "Comments", 9usize, |this| ((**(this as *const *const IXFeedItem_Vtbl)).Comments)(this, ppszurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn Author(this: *mut core::ffi::c_void, ppszauthor: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedItem", "Author", 10usize, |this| ((**(this as *const *const IXFeedItem_Vtbl)).Author)(this, ppszauthor), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enclosure(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedItem", "Enclosure", 11usize, |this| ((**(this as *const *const IXFeedItem_Vtbl)).Enclosure)(this, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRead(this: *mut core::ffi::c_void, pbisread: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedItem", "IsRead", 12usize, |this| ((**(this as *const *const IXFeedItem_Vtbl)).IsRead)(this, pbisread), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsRead(this: *mut core::ffi::c_void, bisread: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedItem", "SetIsRead", 13usize, |this| ((**(this as *const *const IXFeedItem_Vtbl)).SetIsRead)(this, bisread), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalId(this: *mut core::ffi::c_void, puiid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedItem", "LocalId", 14usize, |this| ((**(this as *const *const IXFeedItem_Vtbl)).LocalId)(this, puiid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Parent(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedItem", "Parent", 15usize, |this| ((**(this as *const *const IXFeedItem_Vtbl)).Parent)(this, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedItem", "Delete", 16usize, |this| ((**(this as *const *const IXFeedItem_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadUrl(this: *mut core::ffi::c_void, ppszurl: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedItem", "DownloadUrl", 17usize, |this| ((**(this as *const *const IXFeedItem_Vtbl)).DownloadUrl)(this, ppszurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastDownloadTime(this: *mut core::ffi::c_void, pstlastdownloadtime: *mut super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedItem", "LastDownloadTime", 18usize, |this| ((**(this as *const *const IXFeedItem_Vtbl)).LastDownloadTime)(this, pstlastdownloadtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn Modified(this: *mut core::ffi::c_void, pstmodifiedtime: *mut super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedItem", "Modified", 19usize, |this| ((**(this as *const *const IXFeedItem_Vtbl)).Modified)(this, pstmodifiedtime), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Xml,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Xml: 0,
+            Title,
+            Link,
+            Guid,
+            Description,
+            PubDate,
+            Comments,
+            Author,
+            Enclosure,
+            IsRead,
+            SetIsRead,
+            LocalId,
+            Parent,
+            Delete,
+            DownloadUrl,
+            LastDownloadTime,
+            Modified,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXFeedItem as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXFeedItem2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EffectiveId(this: *mut core::ffi::c_void, puieffectiveid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedItem2", "EffectiveId", 20usize, |this| ((**(this as *const *const IXFeedItem2_Vtbl)).EffectiveId)(this, puieffectiveid), |result| Some(*result))
+        }
+        Self { base__: <IXFeedItem_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EffectiveId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXFeedItem2 as windows_core::Interface>::IID || <IXFeedItem_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXFeedsEnum_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, puicount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedsEnum", "Count", 3usize, |this| ((**(this as *const *const IXFeedsEnum_Vtbl)).Count)(this, puicount), |result| Some(*result))
+        }
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, uiindex: u32, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedsEnum", "Item", 4usize, |this| ((**(this as *const *const IXFeedsEnum_Vtbl)).Item)(this, uiindex, riid, ppv), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Count, Item }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXFeedsEnum as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXFeedsManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RootFolder(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedsManager", "RootFolder", 3usize, |this| ((**(this as *const *const IXFeedsManager_Vtbl)).RootFolder)(this, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSubscribed(this: *mut core::ffi::c_void, pszurl: windows_core::PCWSTR, pbsubscribed: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedsManager", "IsSubscribed", 4usize, |this| ((**(this as *const *const IXFeedsManager_Vtbl)).IsSubscribed)(this, pszurl, pbsubscribed), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExistsFeed(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, pbfeedexists: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedsManager", "ExistsFeed", 5usize, |this| ((**(this as *const *const IXFeedsManager_Vtbl)).ExistsFeed)(this, pszpath, pbfeedexists), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFeed(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedsManager", "GetFeed", 6usize, |this| ((**(this as *const *const IXFeedsManager_Vtbl)).GetFeed)(this, pszpath, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFeedByUrl(this: *mut core::ffi::c_void, pszurl: windows_core::PCWSTR, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedsManager", "GetFeedByUrl", 7usize, |this| ((**(this as *const *const IXFeedsManager_Vtbl)).GetFeedByUrl)(this, pszurl, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExistsFolder(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, pbfolderexists: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedsManager", "ExistsFolder", 8usize, |this| ((**(this as *const *const IXFeedsManager_Vtbl)).ExistsFolder)(this, pszpath, pbfolderexists), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFolder(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedsManager", "GetFolder", 9usize, |this| ((**(this as *const *const IXFeedsManager_Vtbl)).GetFolder)(this, pszpath, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteFeed(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedsManager", "DeleteFeed", 10usize, |this| ((**(this as *const *const IXFeedsManager_Vtbl)).DeleteFeed)(this, pszpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteFolder(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedsManager", "DeleteFolder", 11usize, |this| ((**(this as *const *const IXFeedsManager_Vtbl)).DeleteFolder)(this, pszpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackgroundSync(this: *mut core::ffi::c_void, fbsa: FEEDS_BACKGROUNDSYNC_ACTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedsManager", "BackgroundSync", 12usize, |this| ((**(this as *const *const IXFeedsManager_Vtbl)).BackgroundSync)(this, fbsa), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackgroundSyncStatus(this: *mut core::ffi::c_void, pfbss: *mut FEEDS_BACKGROUNDSYNC_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedsManager", "BackgroundSyncStatus", 13usize, |this| ((**(this as *const *const IXFeedsManager_Vtbl)).BackgroundSyncStatus)(this, pfbss), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultInterval(this: *mut core::ffi::c_void, puiinterval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedsManager", "DefaultInterval", 14usize, |this| ((**(this as *const *const IXFeedsManager_Vtbl)).DefaultInterval)(this, puiinterval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultInterval(this: *mut core::ffi::c_void, uiinterval: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedsManager", "SetDefaultInterval", 15usize, |this| ((**(this as *const *const IXFeedsManager_Vtbl)).SetDefaultInterval)(this, uiinterval), |result| Some(*result))
+        }
+        unsafe extern "system" fn AsyncSyncAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedsManager", "AsyncSyncAll", 16usize, |this| ((**(this as *const *const IXFeedsManager_Vtbl)).AsyncSyncAll)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Normalize(this: *mut core::ffi::c_void, pstreamin: *mut core::ffi::c_void, ppstreamout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedsManager", "Normalize", 17usize, |this| ((**(this as *const *const IXFeedsManager_Vtbl)).Normalize)(this, pstreamin, ppstreamout), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItemCountLimit(this: *mut core::ffi::c_void, puiitemcountlimit: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXFeedsManager", "ItemCountLimit", 18usize, |this| ((**(this as *const *const IXFeedsManager_Vtbl)).ItemCountLimit)(this, puiitemcountlimit), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RootFolder,
+            IsSubscribed,
+            ExistsFeed,
+            GetFeed,
+            GetFeedByUrl,
+            ExistsFolder,
+            GetFolder,
+            DeleteFeed,
+            DeleteFolder,
+            BackgroundSync,
+            BackgroundSyncStatus,
+            DefaultInterval,
+            SetDefaultInterval,
+            AsyncSyncAll,
+            #[cfg(feature = "Win32_System_Com")]
+            Normalize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Normalize: 0,
+            ItemCountLimit,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXFeedsManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for _WMPOCXEvents_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<_WMPOCXEvents as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
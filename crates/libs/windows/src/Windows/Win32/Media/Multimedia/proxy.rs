@@ -0,0 +1,178 @@
+impl windows_core::imp::ProxyVtable for IAVIEditStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Cut(this: *mut core::ffi::c_void, plstart: *mut i32, pllength: *mut i32, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAVIEditStream", "Cut", 3usize, |this| ((**(this as *const *const IAVIEditStream_Vtbl)).Cut)(this, plstart, pllength, ppresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn Copy(this: *mut core::ffi::c_void, plstart: *mut i32, pllength: *mut i32, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAVIEditStream", "Copy", 4usize, |this| ((**(this as *const *const IAVIEditStream_Vtbl)).Copy)(this, plstart, pllength, ppresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn Paste(this: *mut core::ffi::c_void, plpos: *mut i32, pllength: *mut i32, pstream: *mut core::ffi::c_void, lstart: i32, lend: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAVIEditStream", "Paste", 5usize, |this| ((**(this as *const *const IAVIEditStream_Vtbl)).Paste)(this, plpos, pllength, pstream, lstart, lend), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAVIEditStream", "Clone", 6usize, |this| ((**(this as *const *const IAVIEditStream_Vtbl)).Clone)(this, ppresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInfo(this: *mut core::ffi::c_void, lpinfo: *const AVISTREAMINFOW, cbinfo: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAVIEditStream", "SetInfo", 7usize, |this| ((**(this as *const *const IAVIEditStream_Vtbl)).SetInfo)(this, lpinfo, cbinfo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Cut, Copy, Paste, Clone, SetInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAVIEditStream as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAVIFile_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Info(this: *mut core::ffi::c_void, pfi: *mut AVIFILEINFOW, lsize: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAVIFile", "Info", 3usize, |this| ((**(this as *const *const IAVIFile_Vtbl)).Info)(this, pfi, lsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStream(this: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void, fcctype: u32, lparam: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAVIFile", "GetStream", 4usize, |this| ((**(this as *const *const IAVIFile_Vtbl)).GetStream)(this, ppstream, fcctype, lparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateStream(this: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void, psi: *const AVISTREAMINFOW) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAVIFile", "CreateStream", 5usize, |this| ((**(this as *const *const IAVIFile_Vtbl)).CreateStream)(this, ppstream, psi), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteData(this: *mut core::ffi::c_void, ckid: u32, lpdata: *const core::ffi::c_void, cbdata: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAVIFile", "WriteData", 6usize, |this| ((**(this as *const *const IAVIFile_Vtbl)).WriteData)(this, ckid, lpdata, cbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadData(this: *mut core::ffi::c_void, ckid: u32, lpdata: *mut core::ffi::c_void, lpcbdata: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAVIFile", "ReadData", 7usize, |this| ((**(this as *const *const IAVIFile_Vtbl)).ReadData)(this, ckid, lpdata, lpcbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndRecord(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAVIFile", "EndRecord", 8usize, |this| ((**(this as *const *const IAVIFile_Vtbl)).EndRecord)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteStream(this: *mut core::ffi::c_void, fcctype: u32, lparam: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAVIFile", "DeleteStream", 9usize, |this| ((**(this as *const *const IAVIFile_Vtbl)).DeleteStream)(this, fcctype, lparam), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Info,
+            GetStream,
+            CreateStream,
+            WriteData,
+            ReadData,
+            EndRecord,
+            DeleteStream,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAVIFile as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAVIPersistFile_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reserved1(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAVIPersistFile", "Reserved1", 9usize, |this| ((**(this as *const *const IAVIPersistFile_Vtbl)).Reserved1)(this), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IPersistFile_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reserved1 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAVIPersistFile as windows_core::Interface>::IID || <super::super::System::Com::IPersistFile_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAVIStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, lparam1: super::super::Foundation::LPARAM, lparam2: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAVIStream", "Create", 3usize, |this| ((**(this as *const *const IAVIStream_Vtbl)).Create)(this, lparam1, lparam2), |result| Some(*result))
+        }
+        unsafe extern "system" fn Info(this: *mut core::ffi::c_void, psi: *mut AVISTREAMINFOW, lsize: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAVIStream", "Info", 4usize, |this| ((**(this as *const *const IAVIStream_Vtbl)).Info)(this, psi, lsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindSample(this: *mut core::ffi::c_void, lpos: i32, lflags: i32) -> i32 {
+            windows_core::imp::proxy_call(this, "IAVIStream", "FindSample", 5usize, |this| ((**(this as *const *const IAVIStream_Vtbl)).FindSample)(this, lpos, lflags), |_| None)
+        }
+        unsafe extern "system" fn ReadFormat(this: *mut core::ffi::c_void, lpos: i32, lpformat: *mut core::ffi::c_void, lpcbformat: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAVIStream", "ReadFormat", 6usize, |this| ((**(this as *const *const IAVIStream_Vtbl)).ReadFormat)(this, lpos, lpformat, lpcbformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFormat(this: *mut core::ffi::c_void, lpos: i32, lpformat: *const core::ffi::c_void, cbformat: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAVIStream", "SetFormat", 7usize, |this| ((**(this as *const *const IAVIStream_Vtbl)).SetFormat)(this, lpos, lpformat, cbformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn Read(this: *mut core::ffi::c_void, lstart: i32, lsamples: i32, lpbuffer: *mut core::ffi::c_void, cbbuffer: i32, plbytes: *mut i32, plsamples: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAVIStream", "Read", 8usize, |this| ((**(this as *const *const IAVIStream_Vtbl)).Read)(this, lstart, lsamples, lpbuffer, cbbuffer, plbytes, plsamples), |result| Some(*result))
+        }
+        unsafe extern "system" fn Write(this: *mut core::ffi::c_void, lstart: i32, lsamples: i32, lpbuffer: *const core::ffi::c_void, cbbuffer: i32, dwflags: u32, plsampwritten: *mut i32, plbyteswritten: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAVIStream", "Write", 9usize, |this| ((**(this as *const *const IAVIStream_Vtbl)).Write)(this, lstart, lsamples, lpbuffer, cbbuffer, dwflags, plsampwritten, plbyteswritten), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, lstart: i32, lsamples: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAVIStream", "Delete", 10usize, |this| ((**(this as *const *const IAVIStream_Vtbl)).Delete)(this, lstart, lsamples), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadData(this: *mut core::ffi::c_void, fcc: u32, lp: *mut core::ffi::c_void, lpcb: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAVIStream", "ReadData", 11usize, |this| ((**(this as *const *const IAVIStream_Vtbl)).ReadData)(this, fcc, lp, lpcb), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteData(this: *mut core::ffi::c_void, fcc: u32, lp: *const core::ffi::c_void, cb: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAVIStream", "WriteData", 12usize, |this| ((**(this as *const *const IAVIStream_Vtbl)).WriteData)(this, fcc, lp, cb), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInfo(this: *mut core::ffi::c_void, lpinfo: *const AVISTREAMINFOW, cbinfo: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAVIStream", "SetInfo", 13usize, |this| ((**(this as *const *const IAVIStream_Vtbl)).SetInfo)(this, lpinfo, cbinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Create,
+            Info,
+            FindSample,
+            ReadFormat,
+            SetFormat,
+            Read,
+            Write,
+            Delete,
+            ReadData,
+            WriteData,
+            SetInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAVIStream as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAVIStreaming_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Begin(this: *mut core::ffi::c_void, lstart: i32, lend: i32, lrate: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAVIStreaming", "Begin", 3usize, |this| ((**(this as *const *const IAVIStreaming_Vtbl)).Begin)(this, lstart, lend, lrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn End(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAVIStreaming", "End", 4usize, |this| ((**(this as *const *const IAVIStreaming_Vtbl)).End)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Begin, End }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAVIStreaming as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IGetFrame_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFrame(this: *mut core::ffi::c_void, lpos: i32) -> *mut core::ffi::c_void {
+            windows_core::imp::proxy_call(this, "IGetFrame", "GetFrame", 3usize, |this| ((**(this as *const *const IGetFrame_Vtbl)).GetFrame)(this, lpos), |_| None)
+        }
+        unsafe extern "system" fn Begin(this: *mut core::ffi::c_void, lstart: i32, lend: i32, lrate: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetFrame", "Begin", 4usize, |this| ((**(this as *const *const IGetFrame_Vtbl)).Begin)(this, lstart, lend, lrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn End(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetFrame", "End", 5usize, |this| ((**(this as *const *const IGetFrame_Vtbl)).End)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn SetFormat(this: *mut core::ffi::c_void, lpbi: *const super::super::Graphics::Gdi::BITMAPINFOHEADER, lpbits: *const core::ffi::c_void, x: i32, y: i32, dx: i32, dy: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetFrame", "SetFormat", 6usize, |this| ((**(this as *const *const IGetFrame_Vtbl)).SetFormat)(this, lpbi, lpbits, x, y, dx, dy), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetFrame,
+            Begin,
+            End,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            SetFormat,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            SetFormat: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGetFrame as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
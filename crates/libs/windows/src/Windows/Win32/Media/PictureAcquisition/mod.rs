@@ -810,6 +810,7 @@ pub struct DEVICE_SELECTION_DEVICE_TYPE(pub i32);
 impl windows_core::TypeKind for DEVICE_SELECTION_DEVICE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEVICE_SELECTION_DEVICE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEVICE_SELECTION_DEVICE_TYPE").field(&self.0).finish()
@@ -821,6 +822,7 @@ pub struct ERROR_ADVISE_MESSAGE_TYPE(pub i32);
 impl windows_core::TypeKind for ERROR_ADVISE_MESSAGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ERROR_ADVISE_MESSAGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ERROR_ADVISE_MESSAGE_TYPE").field(&self.0).finish()
@@ -832,6 +834,7 @@ pub struct ERROR_ADVISE_RESULT(pub i32);
 impl windows_core::TypeKind for ERROR_ADVISE_RESULT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ERROR_ADVISE_RESULT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ERROR_ADVISE_RESULT").field(&self.0).finish()
@@ -843,6 +846,7 @@ pub struct PROGRESS_DIALOG_CHECKBOX_ID(pub i32);
 impl windows_core::TypeKind for PROGRESS_DIALOG_CHECKBOX_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROGRESS_DIALOG_CHECKBOX_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROGRESS_DIALOG_CHECKBOX_ID").field(&self.0).finish()
@@ -854,6 +858,7 @@ pub struct PROGRESS_DIALOG_IMAGE_TYPE(pub i32);
 impl windows_core::TypeKind for PROGRESS_DIALOG_IMAGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROGRESS_DIALOG_IMAGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROGRESS_DIALOG_IMAGE_TYPE").field(&self.0).finish()
@@ -865,6 +870,7 @@ pub struct USER_INPUT_STRING_TYPE(pub i32);
 impl windows_core::TypeKind for USER_INPUT_STRING_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USER_INPUT_STRING_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USER_INPUT_STRING_TYPE").field(&self.0).finish()
@@ -878,3 +884,5 @@ pub const PhotoAcquireOptionsDialog: windows_core::GUID = windows_core::GUID::fr
 pub const PhotoProgressDialog: windows_core::GUID = windows_core::GUID::from_u128(0x00f24ca0_748f_4e8a_894f_0e0357c6799f);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,520 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPhotoAcquire_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreatePhotoSource(this: *mut core::ffi::c_void, pszdevice: windows_core::PCWSTR, ppphotoacquiresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquire", "CreatePhotoSource", 3usize, |this| ((**(this as *const *const IPhotoAcquire_Vtbl)).CreatePhotoSource)(this, pszdevice, ppphotoacquiresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn Acquire(this: *mut core::ffi::c_void, pphotoacquiresource: *mut core::ffi::c_void, fshowprogress: super::super::Foundation::BOOL, hwndparent: super::super::Foundation::HWND, pszapplicationname: windows_core::PCWSTR, pphotoacquireprogresscb: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquire", "Acquire", 4usize, |this| ((**(this as *const *const IPhotoAcquire_Vtbl)).Acquire)(this, pphotoacquiresource, fshowprogress, hwndparent, pszapplicationname, pphotoacquireprogresscb), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumResults(this: *mut core::ffi::c_void, ppenumfilepaths: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquire", "EnumResults", 5usize, |this| ((**(this as *const *const IPhotoAcquire_Vtbl)).EnumResults)(this, ppenumfilepaths), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreatePhotoSource,
+            Acquire,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumResults,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumResults: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoAcquire as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhotoAcquireDeviceSelectionDialog_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, psztitle: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireDeviceSelectionDialog", "SetTitle", 3usize, |this| ((**(this as *const *const IPhotoAcquireDeviceSelectionDialog_Vtbl)).SetTitle)(this, psztitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubmitButtonText(this: *mut core::ffi::c_void, pszsubmitbuttontext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireDeviceSelectionDialog", "SetSubmitButtonText", 4usize, |this| ((**(this as *const *const IPhotoAcquireDeviceSelectionDialog_Vtbl)).SetSubmitButtonText)(this, pszsubmitbuttontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoModal(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, dwdeviceflags: u32, pbstrdeviceid: *mut core::mem::MaybeUninit<windows_core::BSTR>, pndevicetype: *mut DEVICE_SELECTION_DEVICE_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireDeviceSelectionDialog", "DoModal", 5usize, |this| ((**(this as *const *const IPhotoAcquireDeviceSelectionDialog_Vtbl)).DoModal)(this, hwndparent, dwdeviceflags, pbstrdeviceid, pndevicetype), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetTitle, SetSubmitButtonText, DoModal }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoAcquireDeviceSelectionDialog as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+impl windows_core::imp::ProxyVtable for IPhotoAcquireItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetItemName(this: *mut core::ffi::c_void, pbstritemname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireItem", "GetItemName", 3usize, |this| ((**(this as *const *const IPhotoAcquireItem_Vtbl)).GetItemName)(this, pbstritemname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetThumbnail(this: *mut core::ffi::c_void, sizethumbnail: super::super::Foundation::SIZE, phbmpthumbnail: *mut super::super::Graphics::Gdi::HBITMAP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireItem", "GetThumbnail", 4usize, |this| ((**(this as *const *const IPhotoAcquireItem_Vtbl)).GetThumbnail)(this, sizethumbnail, phbmpthumbnail), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pv: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireItem", "GetProperty", 5usize, |this| ((**(this as *const *const IPhotoAcquireItem_Vtbl)).GetProperty)(this, key, pv), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pv: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireItem", "SetProperty", 6usize, |this| ((**(this as *const *const IPhotoAcquireItem_Vtbl)).SetProperty)(this, key, pv), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetStream(this: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireItem", "GetStream", 7usize, |this| ((**(this as *const *const IPhotoAcquireItem_Vtbl)).GetStream)(this, ppstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanDelete(this: *mut core::ffi::c_void, pfcandelete: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireItem", "CanDelete", 8usize, |this| ((**(this as *const *const IPhotoAcquireItem_Vtbl)).CanDelete)(this, pfcandelete), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireItem", "Delete", 9usize, |this| ((**(this as *const *const IPhotoAcquireItem_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubItemCount(this: *mut core::ffi::c_void, pncount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireItem", "GetSubItemCount", 10usize, |this| ((**(this as *const *const IPhotoAcquireItem_Vtbl)).GetSubItemCount)(this, pncount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubItemAt(this: *mut core::ffi::c_void, nitemindex: u32, ppphotoacquireitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireItem", "GetSubItemAt", 11usize, |this| ((**(this as *const *const IPhotoAcquireItem_Vtbl)).GetSubItemAt)(this, nitemindex, ppphotoacquireitem), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetItemName,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetThumbnail,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetThumbnail: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetProperty,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetProperty: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            SetProperty,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            SetProperty: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetStream: 0,
+            CanDelete,
+            Delete,
+            GetSubItemCount,
+            GetSubItemAt,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoAcquireItem as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhotoAcquireOptionsDialog_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pszregistryroot: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireOptionsDialog", "Initialize", 3usize, |this| ((**(this as *const *const IPhotoAcquireOptionsDialog_Vtbl)).Initialize)(this, pszregistryroot), |result| Some(*result))
+        }
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, phwnddialog: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireOptionsDialog", "Create", 4usize, |this| ((**(this as *const *const IPhotoAcquireOptionsDialog_Vtbl)).Create)(this, hwndparent, phwnddialog), |result| Some(*result))
+        }
+        unsafe extern "system" fn Destroy(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireOptionsDialog", "Destroy", 5usize, |this| ((**(this as *const *const IPhotoAcquireOptionsDialog_Vtbl)).Destroy)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoModal(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, ppnreturncode: *mut isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireOptionsDialog", "DoModal", 6usize, |this| ((**(this as *const *const IPhotoAcquireOptionsDialog_Vtbl)).DoModal)(this, hwndparent, ppnreturncode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveData(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireOptionsDialog", "SaveData", 7usize, |this| ((**(this as *const *const IPhotoAcquireOptionsDialog_Vtbl)).SaveData)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, Create, Destroy, DoModal, SaveData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoAcquireOptionsDialog as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+impl windows_core::imp::ProxyVtable for IPhotoAcquirePlugin_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pphotoacquiresource: *mut core::ffi::c_void, pphotoacquireprogresscb: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquirePlugin", "Initialize", 3usize, |this| ((**(this as *const *const IPhotoAcquirePlugin_Vtbl)).Initialize)(this, pphotoacquiresource, pphotoacquireprogresscb), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+        unsafe extern "system" fn ProcessItem(this: *mut core::ffi::c_void, dwacquirestage: u32, pphotoacquireitem: *mut core::ffi::c_void, poriginalitemstream: *mut core::ffi::c_void, pszfinalfilename: windows_core::PCWSTR, ppropertystore: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquirePlugin", "ProcessItem", 4usize, |this| ((**(this as *const *const IPhotoAcquirePlugin_Vtbl)).ProcessItem)(this, dwacquirestage, pphotoacquireitem, poriginalitemstream, pszfinalfilename, ppropertystore), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransferComplete(this: *mut core::ffi::c_void, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquirePlugin", "TransferComplete", 5usize, |this| ((**(this as *const *const IPhotoAcquirePlugin_Vtbl)).TransferComplete)(this, hr), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayConfigureDialog(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquirePlugin", "DisplayConfigureDialog", 6usize, |this| ((**(this as *const *const IPhotoAcquirePlugin_Vtbl)).DisplayConfigureDialog)(this, hwndparent), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+            ProcessItem,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem")))]
+            ProcessItem: 0,
+            TransferComplete,
+            DisplayConfigureDialog,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoAcquirePlugin as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhotoAcquireProgressCB_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Cancelled(this: *mut core::ffi::c_void, pfcancelled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireProgressCB", "Cancelled", 3usize, |this| ((**(this as *const *const IPhotoAcquireProgressCB_Vtbl)).Cancelled)(this, pfcancelled), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartEnumeration(this: *mut core::ffi::c_void, pphotoacquiresource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireProgressCB", "StartEnumeration", 4usize, |this| ((**(this as *const *const IPhotoAcquireProgressCB_Vtbl)).StartEnumeration)(this, pphotoacquiresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn FoundItem(this: *mut core::ffi::c_void, pphotoacquireitem: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireProgressCB", "FoundItem", 5usize, |this| ((**(this as *const *const IPhotoAcquireProgressCB_Vtbl)).FoundItem)(this, pphotoacquireitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndEnumeration(this: *mut core::ffi::c_void, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireProgressCB", "EndEnumeration", 6usize, |this| ((**(this as *const *const IPhotoAcquireProgressCB_Vtbl)).EndEnumeration)(this, hr), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartTransfer(this: *mut core::ffi::c_void, pphotoacquiresource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireProgressCB", "StartTransfer", 7usize, |this| ((**(this as *const *const IPhotoAcquireProgressCB_Vtbl)).StartTransfer)(this, pphotoacquiresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartItemTransfer(this: *mut core::ffi::c_void, nitemindex: u32, pphotoacquireitem: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireProgressCB", "StartItemTransfer", 8usize, |this| ((**(this as *const *const IPhotoAcquireProgressCB_Vtbl)).StartItemTransfer)(this, nitemindex, pphotoacquireitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn DirectoryCreated(this: *mut core::ffi::c_void, pszdirectory: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireProgressCB", "DirectoryCreated", 9usize, |this| ((**(this as *const *const IPhotoAcquireProgressCB_Vtbl)).DirectoryCreated)(this, pszdirectory), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateTransferPercent(this: *mut core::ffi::c_void, foverall: super::super::Foundation::BOOL, npercent: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireProgressCB", "UpdateTransferPercent", 10usize, |this| ((**(this as *const *const IPhotoAcquireProgressCB_Vtbl)).UpdateTransferPercent)(this, foverall, npercent), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndItemTransfer(this: *mut core::ffi::c_void, nitemindex: u32, pphotoacquireitem: *mut core::ffi::c_void, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireProgressCB", "EndItemTransfer", 11usize, |this| ((**(this as *const *const IPhotoAcquireProgressCB_Vtbl)).EndItemTransfer)(this, nitemindex, pphotoacquireitem, hr), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndTransfer(this: *mut core::ffi::c_void, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireProgressCB", "EndTransfer", 12usize, |this| ((**(this as *const *const IPhotoAcquireProgressCB_Vtbl)).EndTransfer)(this, hr), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartDelete(this: *mut core::ffi::c_void, pphotoacquiresource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireProgressCB", "StartDelete", 13usize, |this| ((**(this as *const *const IPhotoAcquireProgressCB_Vtbl)).StartDelete)(this, pphotoacquiresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartItemDelete(this: *mut core::ffi::c_void, nitemindex: u32, pphotoacquireitem: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireProgressCB", "StartItemDelete", 14usize, |this| ((**(this as *const *const IPhotoAcquireProgressCB_Vtbl)).StartItemDelete)(this, nitemindex, pphotoacquireitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateDeletePercent(this: *mut core::ffi::c_void, npercent: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireProgressCB", "UpdateDeletePercent", 15usize, |this| ((**(this as *const *const IPhotoAcquireProgressCB_Vtbl)).UpdateDeletePercent)(this, npercent), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndItemDelete(this: *mut core::ffi::c_void, nitemindex: u32, pphotoacquireitem: *mut core::ffi::c_void, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireProgressCB", "EndItemDelete", 16usize, |this| ((**(this as *const *const IPhotoAcquireProgressCB_Vtbl)).EndItemDelete)(this, nitemindex, pphotoacquireitem, hr), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndDelete(this: *mut core::ffi::c_void, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireProgressCB", "EndDelete", 17usize, |this| ((**(this as *const *const IPhotoAcquireProgressCB_Vtbl)).EndDelete)(this, hr), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndSession(this: *mut core::ffi::c_void, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireProgressCB", "EndSession", 18usize, |this| ((**(this as *const *const IPhotoAcquireProgressCB_Vtbl)).EndSession)(this, hr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeleteAfterAcquire(this: *mut core::ffi::c_void, pfdeleteafteracquire: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireProgressCB", "GetDeleteAfterAcquire", 19usize, |this| ((**(this as *const *const IPhotoAcquireProgressCB_Vtbl)).GetDeleteAfterAcquire)(this, pfdeleteafteracquire), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorAdvise(this: *mut core::ffi::c_void, hr: windows_core::HRESULT, pszerrormessage: windows_core::PCWSTR, nmessagetype: ERROR_ADVISE_MESSAGE_TYPE, pnerroradviseresult: *mut ERROR_ADVISE_RESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireProgressCB", "ErrorAdvise", 20usize, |this| ((**(this as *const *const IPhotoAcquireProgressCB_Vtbl)).ErrorAdvise)(this, hr, pszerrormessage, nmessagetype, pnerroradviseresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUserInput(this: *mut core::ffi::c_void, riidtype: *const windows_core::GUID, punknown: *mut core::ffi::c_void, ppropvarresult: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>, ppropvardefault: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireProgressCB", "GetUserInput", 21usize, |this| ((**(this as *const *const IPhotoAcquireProgressCB_Vtbl)).GetUserInput)(this, riidtype, punknown, ppropvarresult, ppropvardefault), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Cancelled,
+            StartEnumeration,
+            FoundItem,
+            EndEnumeration,
+            StartTransfer,
+            StartItemTransfer,
+            DirectoryCreated,
+            UpdateTransferPercent,
+            EndItemTransfer,
+            EndTransfer,
+            StartDelete,
+            StartItemDelete,
+            UpdateDeletePercent,
+            EndItemDelete,
+            EndDelete,
+            EndSession,
+            GetDeleteAfterAcquire,
+            ErrorAdvise,
+            GetUserInput,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoAcquireProgressCB as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhotoAcquireSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeFromRegistry(this: *mut core::ffi::c_void, pszregistrykey: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireSettings", "InitializeFromRegistry", 3usize, |this| ((**(this as *const *const IPhotoAcquireSettings_Vtbl)).InitializeFromRegistry)(this, pszregistrykey), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFlags(this: *mut core::ffi::c_void, dwphotoacquireflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireSettings", "SetFlags", 4usize, |this| ((**(this as *const *const IPhotoAcquireSettings_Vtbl)).SetFlags)(this, dwphotoacquireflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputFilenameTemplate(this: *mut core::ffi::c_void, psztemplate: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireSettings", "SetOutputFilenameTemplate", 5usize, |this| ((**(this as *const *const IPhotoAcquireSettings_Vtbl)).SetOutputFilenameTemplate)(this, psztemplate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSequencePaddingWidth(this: *mut core::ffi::c_void, dwwidth: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireSettings", "SetSequencePaddingWidth", 6usize, |this| ((**(this as *const *const IPhotoAcquireSettings_Vtbl)).SetSequencePaddingWidth)(this, dwwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSequenceZeroPadding(this: *mut core::ffi::c_void, fzeropad: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireSettings", "SetSequenceZeroPadding", 7usize, |this| ((**(this as *const *const IPhotoAcquireSettings_Vtbl)).SetSequenceZeroPadding)(this, fzeropad), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGroupTag(this: *mut core::ffi::c_void, pszgrouptag: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireSettings", "SetGroupTag", 8usize, |this| ((**(this as *const *const IPhotoAcquireSettings_Vtbl)).SetGroupTag)(this, pszgrouptag), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAcquisitionTime(this: *mut core::ffi::c_void, pftacquisitiontime: *const super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireSettings", "SetAcquisitionTime", 9usize, |this| ((**(this as *const *const IPhotoAcquireSettings_Vtbl)).SetAcquisitionTime)(this, pftacquisitiontime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlags(this: *mut core::ffi::c_void, pdwphotoacquireflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireSettings", "GetFlags", 10usize, |this| ((**(this as *const *const IPhotoAcquireSettings_Vtbl)).GetFlags)(this, pdwphotoacquireflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputFilenameTemplate(this: *mut core::ffi::c_void, pbstrtemplate: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireSettings", "GetOutputFilenameTemplate", 11usize, |this| ((**(this as *const *const IPhotoAcquireSettings_Vtbl)).GetOutputFilenameTemplate)(this, pbstrtemplate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSequencePaddingWidth(this: *mut core::ffi::c_void, pdwwidth: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireSettings", "GetSequencePaddingWidth", 12usize, |this| ((**(this as *const *const IPhotoAcquireSettings_Vtbl)).GetSequencePaddingWidth)(this, pdwwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSequenceZeroPadding(this: *mut core::ffi::c_void, pfzeropad: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireSettings", "GetSequenceZeroPadding", 13usize, |this| ((**(this as *const *const IPhotoAcquireSettings_Vtbl)).GetSequenceZeroPadding)(this, pfzeropad), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGroupTag(this: *mut core::ffi::c_void, pbstrgrouptag: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireSettings", "GetGroupTag", 14usize, |this| ((**(this as *const *const IPhotoAcquireSettings_Vtbl)).GetGroupTag)(this, pbstrgrouptag), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAcquisitionTime(this: *mut core::ffi::c_void, pftacquisitiontime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireSettings", "GetAcquisitionTime", 15usize, |this| ((**(this as *const *const IPhotoAcquireSettings_Vtbl)).GetAcquisitionTime)(this, pftacquisitiontime), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitializeFromRegistry,
+            SetFlags,
+            SetOutputFilenameTemplate,
+            SetSequencePaddingWidth,
+            SetSequenceZeroPadding,
+            SetGroupTag,
+            SetAcquisitionTime,
+            GetFlags,
+            GetOutputFilenameTemplate,
+            GetSequencePaddingWidth,
+            GetSequenceZeroPadding,
+            GetGroupTag,
+            GetAcquisitionTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoAcquireSettings as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl windows_core::imp::ProxyVtable for IPhotoAcquireSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFriendlyName(this: *mut core::ffi::c_void, pbstrfriendlyname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireSource", "GetFriendlyName", 3usize, |this| ((**(this as *const *const IPhotoAcquireSource_Vtbl)).GetFriendlyName)(this, pbstrfriendlyname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn GetDeviceIcons(this: *mut core::ffi::c_void, nsize: u32, phlargeicon: *mut super::super::UI::WindowsAndMessaging::HICON, phsmallicon: *mut super::super::UI::WindowsAndMessaging::HICON) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireSource", "GetDeviceIcons", 4usize, |this| ((**(this as *const *const IPhotoAcquireSource_Vtbl)).GetDeviceIcons)(this, nsize, phlargeicon, phsmallicon), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeItemList(this: *mut core::ffi::c_void, fforceenumeration: super::super::Foundation::BOOL, pphotoacquireprogresscb: *mut core::ffi::c_void, pnitemcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireSource", "InitializeItemList", 5usize, |this| ((**(this as *const *const IPhotoAcquireSource_Vtbl)).InitializeItemList)(this, fforceenumeration, pphotoacquireprogresscb, pnitemcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItemCount(this: *mut core::ffi::c_void, pnitemcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireSource", "GetItemCount", 6usize, |this| ((**(this as *const *const IPhotoAcquireSource_Vtbl)).GetItemCount)(this, pnitemcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItemAt(this: *mut core::ffi::c_void, nindex: u32, ppphotoacquireitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireSource", "GetItemAt", 7usize, |this| ((**(this as *const *const IPhotoAcquireSource_Vtbl)).GetItemAt)(this, nindex, ppphotoacquireitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPhotoAcquireSettings(this: *mut core::ffi::c_void, ppphotoacquiresettings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireSource", "GetPhotoAcquireSettings", 8usize, |this| ((**(this as *const *const IPhotoAcquireSource_Vtbl)).GetPhotoAcquireSettings)(this, ppphotoacquiresettings), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceId(this: *mut core::ffi::c_void, pbstrdeviceid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireSource", "GetDeviceId", 9usize, |this| ((**(this as *const *const IPhotoAcquireSource_Vtbl)).GetDeviceId)(this, pbstrdeviceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn BindToObject(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoAcquireSource", "BindToObject", 10usize, |this| ((**(this as *const *const IPhotoAcquireSource_Vtbl)).BindToObject)(this, riid, ppv), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetFriendlyName,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            GetDeviceIcons,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            GetDeviceIcons: 0,
+            InitializeItemList,
+            GetItemCount,
+            GetItemAt,
+            GetPhotoAcquireSettings,
+            GetDeviceId,
+            BindToObject,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoAcquireSource as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhotoProgressActionCB_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DoAction(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoProgressActionCB", "DoAction", 3usize, |this| ((**(this as *const *const IPhotoProgressActionCB_Vtbl)).DoAction)(this, hwndparent), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DoAction }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoProgressActionCB as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+impl windows_core::imp::ProxyVtable for IPhotoProgressDialog_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoProgressDialog", "Create", 3usize, |this| ((**(this as *const *const IPhotoProgressDialog_Vtbl)).Create)(this, hwndparent), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWindow(this: *mut core::ffi::c_void, phwndprogressdialog: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoProgressDialog", "GetWindow", 4usize, |this| ((**(this as *const *const IPhotoProgressDialog_Vtbl)).GetWindow)(this, phwndprogressdialog), |result| Some(*result))
+        }
+        unsafe extern "system" fn Destroy(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoProgressDialog", "Destroy", 5usize, |this| ((**(this as *const *const IPhotoProgressDialog_Vtbl)).Destroy)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, psztitle: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoProgressDialog", "SetTitle", 6usize, |this| ((**(this as *const *const IPhotoProgressDialog_Vtbl)).SetTitle)(this, psztitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowCheckbox(this: *mut core::ffi::c_void, ncheckboxid: PROGRESS_DIALOG_CHECKBOX_ID, fshow: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoProgressDialog", "ShowCheckbox", 7usize, |this| ((**(this as *const *const IPhotoProgressDialog_Vtbl)).ShowCheckbox)(this, ncheckboxid, fshow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCheckboxText(this: *mut core::ffi::c_void, ncheckboxid: PROGRESS_DIALOG_CHECKBOX_ID, pszcheckboxtext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoProgressDialog", "SetCheckboxText", 8usize, |this| ((**(this as *const *const IPhotoProgressDialog_Vtbl)).SetCheckboxText)(this, ncheckboxid, pszcheckboxtext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCheckboxCheck(this: *mut core::ffi::c_void, ncheckboxid: PROGRESS_DIALOG_CHECKBOX_ID, fchecked: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoProgressDialog", "SetCheckboxCheck", 9usize, |this| ((**(this as *const *const IPhotoProgressDialog_Vtbl)).SetCheckboxCheck)(this, ncheckboxid, fchecked), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCheckboxTooltip(this: *mut core::ffi::c_void, ncheckboxid: PROGRESS_DIALOG_CHECKBOX_ID, pszcheckboxtooltiptext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoProgressDialog", "SetCheckboxTooltip", 10usize, |this| ((**(this as *const *const IPhotoProgressDialog_Vtbl)).SetCheckboxTooltip)(this, ncheckboxid, pszcheckboxtooltiptext), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCheckboxChecked(this: *mut core::ffi::c_void, ncheckboxid: PROGRESS_DIALOG_CHECKBOX_ID, pfchecked: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoProgressDialog", "IsCheckboxChecked", 11usize, |this| ((**(this as *const *const IPhotoProgressDialog_Vtbl)).IsCheckboxChecked)(this, ncheckboxid, pfchecked), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCaption(this: *mut core::ffi::c_void, psztitle: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoProgressDialog", "SetCaption", 12usize, |this| ((**(this as *const *const IPhotoProgressDialog_Vtbl)).SetCaption)(this, psztitle), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+        unsafe extern "system" fn SetImage(this: *mut core::ffi::c_void, nimagetype: PROGRESS_DIALOG_IMAGE_TYPE, hicon: super::super::UI::WindowsAndMessaging::HICON, hbitmap: super::super::Graphics::Gdi::HBITMAP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoProgressDialog", "SetImage", 13usize, |this| ((**(this as *const *const IPhotoProgressDialog_Vtbl)).SetImage)(this, nimagetype, hicon, hbitmap), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPercentComplete(this: *mut core::ffi::c_void, npercent: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoProgressDialog", "SetPercentComplete", 14usize, |this| ((**(this as *const *const IPhotoProgressDialog_Vtbl)).SetPercentComplete)(this, npercent), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProgressText(this: *mut core::ffi::c_void, pszprogresstext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoProgressDialog", "SetProgressText", 15usize, |this| ((**(this as *const *const IPhotoProgressDialog_Vtbl)).SetProgressText)(this, pszprogresstext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActionLinkCallback(this: *mut core::ffi::c_void, pphotoprogressactioncb: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoProgressDialog", "SetActionLinkCallback", 16usize, |this| ((**(this as *const *const IPhotoProgressDialog_Vtbl)).SetActionLinkCallback)(this, pphotoprogressactioncb), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActionLinkText(this: *mut core::ffi::c_void, pszcaption: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoProgressDialog", "SetActionLinkText", 17usize, |this| ((**(this as *const *const IPhotoProgressDialog_Vtbl)).SetActionLinkText)(this, pszcaption), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowActionLink(this: *mut core::ffi::c_void, fshow: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoProgressDialog", "ShowActionLink", 18usize, |this| ((**(this as *const *const IPhotoProgressDialog_Vtbl)).ShowActionLink)(this, fshow), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCancelled(this: *mut core::ffi::c_void, pfcancelled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoProgressDialog", "IsCancelled", 19usize, |this| ((**(this as *const *const IPhotoProgressDialog_Vtbl)).IsCancelled)(this, pfcancelled), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUserInput(this: *mut core::ffi::c_void, riidtype: *const windows_core::GUID, punknown: *mut core::ffi::c_void, ppropvarresult: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>, ppropvardefault: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhotoProgressDialog", "GetUserInput", 20usize, |this| ((**(this as *const *const IPhotoProgressDialog_Vtbl)).GetUserInput)(this, riidtype, punknown, ppropvarresult, ppropvardefault), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Create,
+            GetWindow,
+            Destroy,
+            SetTitle,
+            ShowCheckbox,
+            SetCheckboxText,
+            SetCheckboxCheck,
+            SetCheckboxTooltip,
+            IsCheckboxChecked,
+            SetCaption,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+            SetImage,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging")))]
+            SetImage: 0,
+            SetPercentComplete,
+            SetProgressText,
+            SetActionLinkCallback,
+            SetActionLinkText,
+            ShowActionLink,
+            IsCancelled,
+            GetUserInput,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhotoProgressDialog as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+impl windows_core::imp::ProxyVtable for IUserInputString_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSubmitButtonText(this: *mut core::ffi::c_void, pbstrsubmitbuttontext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserInputString", "GetSubmitButtonText", 3usize, |this| ((**(this as *const *const IUserInputString_Vtbl)).GetSubmitButtonText)(this, pbstrsubmitbuttontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrompt(this: *mut core::ffi::c_void, pbstrprompttitle: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserInputString", "GetPrompt", 4usize, |this| ((**(this as *const *const IUserInputString_Vtbl)).GetPrompt)(this, pbstrprompttitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStringId(this: *mut core::ffi::c_void, pbstrstringid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserInputString", "GetStringId", 5usize, |this| ((**(this as *const *const IUserInputString_Vtbl)).GetStringId)(this, pbstrstringid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStringType(this: *mut core::ffi::c_void, pnstringtype: *mut USER_INPUT_STRING_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserInputString", "GetStringType", 6usize, |this| ((**(this as *const *const IUserInputString_Vtbl)).GetStringType)(this, pnstringtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTooltipText(this: *mut core::ffi::c_void, pbstrtooltiptext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserInputString", "GetTooltipText", 7usize, |this| ((**(this as *const *const IUserInputString_Vtbl)).GetTooltipText)(this, pbstrtooltiptext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxLength(this: *mut core::ffi::c_void, pcchmaxlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserInputString", "GetMaxLength", 8usize, |this| ((**(this as *const *const IUserInputString_Vtbl)).GetMaxLength)(this, pcchmaxlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefault(this: *mut core::ffi::c_void, pbstrdefault: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserInputString", "GetDefault", 9usize, |this| ((**(this as *const *const IUserInputString_Vtbl)).GetDefault)(this, pbstrdefault), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMruCount(this: *mut core::ffi::c_void, pnmrucount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserInputString", "GetMruCount", 10usize, |this| ((**(this as *const *const IUserInputString_Vtbl)).GetMruCount)(this, pnmrucount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMruEntryAt(this: *mut core::ffi::c_void, nindex: u32, pbstrmruentry: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserInputString", "GetMruEntryAt", 11usize, |this| ((**(this as *const *const IUserInputString_Vtbl)).GetMruEntryAt)(this, nindex, pbstrmruentry), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+        unsafe extern "system" fn GetImage(this: *mut core::ffi::c_void, nsize: u32, phbitmap: *mut super::super::Graphics::Gdi::HBITMAP, phicon: *mut super::super::UI::WindowsAndMessaging::HICON) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserInputString", "GetImage", 12usize, |this| ((**(this as *const *const IUserInputString_Vtbl)).GetImage)(this, nsize, phbitmap, phicon), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSubmitButtonText,
+            GetPrompt,
+            GetStringId,
+            GetStringType,
+            GetTooltipText,
+            GetMaxLength,
+            GetDefault,
+            GetMruCount,
+            GetMruEntryAt,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+            GetImage,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging")))]
+            GetImage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserInputString as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -7719,6 +7719,7 @@ pub struct DISPIDSPRG(pub i32);
 impl windows_core::TypeKind for DISPIDSPRG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPIDSPRG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPIDSPRG").field(&self.0).finish()
@@ -7730,6 +7731,7 @@ pub struct DISPIDSPTSI(pub i32);
 impl windows_core::TypeKind for DISPIDSPTSI {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPIDSPTSI {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPIDSPTSI").field(&self.0).finish()
@@ -7741,6 +7743,7 @@ pub struct DISPID_SpeechAudio(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechAudio {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechAudio {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechAudio").field(&self.0).finish()
@@ -7752,6 +7755,7 @@ pub struct DISPID_SpeechAudioBufferInfo(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechAudioBufferInfo {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechAudioBufferInfo {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechAudioBufferInfo").field(&self.0).finish()
@@ -7763,6 +7767,7 @@ pub struct DISPID_SpeechAudioFormat(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechAudioFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechAudioFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechAudioFormat").field(&self.0).finish()
@@ -7774,6 +7779,7 @@ pub struct DISPID_SpeechAudioStatus(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechAudioStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechAudioStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechAudioStatus").field(&self.0).finish()
@@ -7785,6 +7791,7 @@ pub struct DISPID_SpeechBaseStream(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechBaseStream {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechBaseStream {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechBaseStream").field(&self.0).finish()
@@ -7796,6 +7803,7 @@ pub struct DISPID_SpeechCustomStream(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechCustomStream {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechCustomStream {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechCustomStream").field(&self.0).finish()
@@ -7807,6 +7815,7 @@ pub struct DISPID_SpeechDataKey(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechDataKey {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechDataKey {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechDataKey").field(&self.0).finish()
@@ -7818,6 +7827,7 @@ pub struct DISPID_SpeechFileStream(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechFileStream {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechFileStream {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechFileStream").field(&self.0).finish()
@@ -7829,6 +7839,7 @@ pub struct DISPID_SpeechGrammarRule(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechGrammarRule {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechGrammarRule {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechGrammarRule").field(&self.0).finish()
@@ -7840,6 +7851,7 @@ pub struct DISPID_SpeechGrammarRuleState(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechGrammarRuleState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechGrammarRuleState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechGrammarRuleState").field(&self.0).finish()
@@ -7851,6 +7863,7 @@ pub struct DISPID_SpeechGrammarRuleStateTransition(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechGrammarRuleStateTransition {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechGrammarRuleStateTransition {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechGrammarRuleStateTransition").field(&self.0).finish()
@@ -7862,6 +7875,7 @@ pub struct DISPID_SpeechGrammarRuleStateTransitions(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechGrammarRuleStateTransitions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechGrammarRuleStateTransitions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechGrammarRuleStateTransitions").field(&self.0).finish()
@@ -7873,6 +7887,7 @@ pub struct DISPID_SpeechGrammarRules(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechGrammarRules {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechGrammarRules {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechGrammarRules").field(&self.0).finish()
@@ -7884,6 +7899,7 @@ pub struct DISPID_SpeechLexicon(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechLexicon {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechLexicon {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechLexicon").field(&self.0).finish()
@@ -7895,6 +7911,7 @@ pub struct DISPID_SpeechLexiconProns(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechLexiconProns {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechLexiconProns {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechLexiconProns").field(&self.0).finish()
@@ -7906,6 +7923,7 @@ pub struct DISPID_SpeechLexiconPronunciation(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechLexiconPronunciation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechLexiconPronunciation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechLexiconPronunciation").field(&self.0).finish()
@@ -7917,6 +7935,7 @@ pub struct DISPID_SpeechLexiconWord(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechLexiconWord {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechLexiconWord {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechLexiconWord").field(&self.0).finish()
@@ -7928,6 +7947,7 @@ pub struct DISPID_SpeechLexiconWords(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechLexiconWords {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechLexiconWords {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechLexiconWords").field(&self.0).finish()
@@ -7939,6 +7959,7 @@ pub struct DISPID_SpeechMMSysAudio(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechMMSysAudio {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechMMSysAudio {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechMMSysAudio").field(&self.0).finish()
@@ -7950,6 +7971,7 @@ pub struct DISPID_SpeechMemoryStream(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechMemoryStream {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechMemoryStream {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechMemoryStream").field(&self.0).finish()
@@ -7961,6 +7983,7 @@ pub struct DISPID_SpeechObjectToken(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechObjectToken {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechObjectToken {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechObjectToken").field(&self.0).finish()
@@ -7972,6 +7995,7 @@ pub struct DISPID_SpeechObjectTokenCategory(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechObjectTokenCategory {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechObjectTokenCategory {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechObjectTokenCategory").field(&self.0).finish()
@@ -7983,6 +8007,7 @@ pub struct DISPID_SpeechObjectTokens(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechObjectTokens {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechObjectTokens {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechObjectTokens").field(&self.0).finish()
@@ -7994,6 +8019,7 @@ pub struct DISPID_SpeechPhoneConverter(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechPhoneConverter {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechPhoneConverter {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechPhoneConverter").field(&self.0).finish()
@@ -8005,6 +8031,7 @@ pub struct DISPID_SpeechPhraseAlternate(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechPhraseAlternate {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechPhraseAlternate {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechPhraseAlternate").field(&self.0).finish()
@@ -8016,6 +8043,7 @@ pub struct DISPID_SpeechPhraseAlternates(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechPhraseAlternates {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechPhraseAlternates {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechPhraseAlternates").field(&self.0).finish()
@@ -8027,6 +8055,7 @@ pub struct DISPID_SpeechPhraseBuilder(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechPhraseBuilder {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechPhraseBuilder {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechPhraseBuilder").field(&self.0).finish()
@@ -8038,6 +8067,7 @@ pub struct DISPID_SpeechPhraseElement(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechPhraseElement {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechPhraseElement {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechPhraseElement").field(&self.0).finish()
@@ -8049,6 +8079,7 @@ pub struct DISPID_SpeechPhraseElements(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechPhraseElements {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechPhraseElements {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechPhraseElements").field(&self.0).finish()
@@ -8060,6 +8091,7 @@ pub struct DISPID_SpeechPhraseInfo(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechPhraseInfo {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechPhraseInfo {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechPhraseInfo").field(&self.0).finish()
@@ -8071,6 +8103,7 @@ pub struct DISPID_SpeechPhraseProperties(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechPhraseProperties {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechPhraseProperties {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechPhraseProperties").field(&self.0).finish()
@@ -8082,6 +8115,7 @@ pub struct DISPID_SpeechPhraseProperty(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechPhraseProperty {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechPhraseProperty {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechPhraseProperty").field(&self.0).finish()
@@ -8093,6 +8127,7 @@ pub struct DISPID_SpeechPhraseReplacement(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechPhraseReplacement {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechPhraseReplacement {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechPhraseReplacement").field(&self.0).finish()
@@ -8104,6 +8139,7 @@ pub struct DISPID_SpeechPhraseReplacements(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechPhraseReplacements {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechPhraseReplacements {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechPhraseReplacements").field(&self.0).finish()
@@ -8115,6 +8151,7 @@ pub struct DISPID_SpeechPhraseRule(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechPhraseRule {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechPhraseRule {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechPhraseRule").field(&self.0).finish()
@@ -8126,6 +8163,7 @@ pub struct DISPID_SpeechPhraseRules(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechPhraseRules {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechPhraseRules {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechPhraseRules").field(&self.0).finish()
@@ -8137,6 +8175,7 @@ pub struct DISPID_SpeechRecoContext(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechRecoContext {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechRecoContext {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechRecoContext").field(&self.0).finish()
@@ -8148,6 +8187,7 @@ pub struct DISPID_SpeechRecoContextEvents(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechRecoContextEvents {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechRecoContextEvents {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechRecoContextEvents").field(&self.0).finish()
@@ -8159,6 +8199,7 @@ pub struct DISPID_SpeechRecoResult(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechRecoResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechRecoResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechRecoResult").field(&self.0).finish()
@@ -8170,6 +8211,7 @@ pub struct DISPID_SpeechRecoResult2(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechRecoResult2 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechRecoResult2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechRecoResult2").field(&self.0).finish()
@@ -8181,6 +8223,7 @@ pub struct DISPID_SpeechRecoResultTimes(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechRecoResultTimes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechRecoResultTimes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechRecoResultTimes").field(&self.0).finish()
@@ -8192,6 +8235,7 @@ pub struct DISPID_SpeechRecognizer(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechRecognizer {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechRecognizer {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechRecognizer").field(&self.0).finish()
@@ -8203,6 +8247,7 @@ pub struct DISPID_SpeechRecognizerStatus(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechRecognizerStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechRecognizerStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechRecognizerStatus").field(&self.0).finish()
@@ -8214,6 +8259,7 @@ pub struct DISPID_SpeechVoice(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechVoice {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechVoice {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechVoice").field(&self.0).finish()
@@ -8225,6 +8271,7 @@ pub struct DISPID_SpeechVoiceEvent(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechVoiceEvent {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechVoiceEvent {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechVoiceEvent").field(&self.0).finish()
@@ -8236,6 +8283,7 @@ pub struct DISPID_SpeechVoiceStatus(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechVoiceStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechVoiceStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechVoiceStatus").field(&self.0).finish()
@@ -8247,6 +8295,7 @@ pub struct DISPID_SpeechWaveFormatEx(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechWaveFormatEx {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechWaveFormatEx {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechWaveFormatEx").field(&self.0).finish()
@@ -8258,6 +8307,7 @@ pub struct DISPID_SpeechXMLRecoResult(pub i32);
 impl windows_core::TypeKind for DISPID_SpeechXMLRecoResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_SpeechXMLRecoResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_SpeechXMLRecoResult").field(&self.0).finish()
@@ -8269,6 +8319,7 @@ pub struct PHONETICALPHABET(pub i32);
 impl windows_core::TypeKind for PHONETICALPHABET {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PHONETICALPHABET {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PHONETICALPHABET").field(&self.0).finish()
@@ -8280,6 +8331,7 @@ pub struct SPADAPTATIONRELEVANCE(pub i32);
 impl windows_core::TypeKind for SPADAPTATIONRELEVANCE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPADAPTATIONRELEVANCE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPADAPTATIONRELEVANCE").field(&self.0).finish()
@@ -8291,6 +8343,7 @@ pub struct SPADAPTATIONSETTINGS(pub i32);
 impl windows_core::TypeKind for SPADAPTATIONSETTINGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPADAPTATIONSETTINGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPADAPTATIONSETTINGS").field(&self.0).finish()
@@ -8302,6 +8355,7 @@ pub struct SPAUDIOOPTIONS(pub i32);
 impl windows_core::TypeKind for SPAUDIOOPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPAUDIOOPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPAUDIOOPTIONS").field(&self.0).finish()
@@ -8313,6 +8367,7 @@ pub struct SPAUDIOSTATE(pub i32);
 impl windows_core::TypeKind for SPAUDIOSTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPAUDIOSTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPAUDIOSTATE").field(&self.0).finish()
@@ -8324,6 +8379,7 @@ pub struct SPBOOKMARKOPTIONS(pub i32);
 impl windows_core::TypeKind for SPBOOKMARKOPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPBOOKMARKOPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPBOOKMARKOPTIONS").field(&self.0).finish()
@@ -8335,6 +8391,7 @@ pub struct SPCFGNOTIFY(pub i32);
 impl windows_core::TypeKind for SPCFGNOTIFY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPCFGNOTIFY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPCFGNOTIFY").field(&self.0).finish()
@@ -8346,6 +8403,7 @@ pub struct SPCFGRULEATTRIBUTES(pub i32);
 impl windows_core::TypeKind for SPCFGRULEATTRIBUTES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPCFGRULEATTRIBUTES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPCFGRULEATTRIBUTES").field(&self.0).finish()
@@ -8357,6 +8415,7 @@ pub struct SPCOMMITFLAGS(pub i32);
 impl windows_core::TypeKind for SPCOMMITFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPCOMMITFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPCOMMITFLAGS").field(&self.0).finish()
@@ -8368,6 +8427,7 @@ pub struct SPCONTEXTSTATE(pub i32);
 impl windows_core::TypeKind for SPCONTEXTSTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPCONTEXTSTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPCONTEXTSTATE").field(&self.0).finish()
@@ -8379,6 +8439,7 @@ pub struct SPDATAKEYLOCATION(pub i32);
 impl windows_core::TypeKind for SPDATAKEYLOCATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPDATAKEYLOCATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPDATAKEYLOCATION").field(&self.0).finish()
@@ -8390,6 +8451,7 @@ pub struct SPDISPLAYATTRIBUTES(pub i32);
 impl windows_core::TypeKind for SPDISPLAYATTRIBUTES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPDISPLAYATTRIBUTES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPDISPLAYATTRIBUTES").field(&self.0).finish()
@@ -8401,6 +8463,7 @@ pub struct SPEAKFLAGS(pub i32);
 impl windows_core::TypeKind for SPEAKFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPEAKFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPEAKFLAGS").field(&self.0).finish()
@@ -8412,6 +8475,7 @@ pub struct SPENDSRSTREAMFLAGS(pub i32);
 impl windows_core::TypeKind for SPENDSRSTREAMFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPENDSRSTREAMFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPENDSRSTREAMFLAGS").field(&self.0).finish()
@@ -8423,6 +8487,7 @@ pub struct SPEVENTENUM(pub i32);
 impl windows_core::TypeKind for SPEVENTENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPEVENTENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPEVENTENUM").field(&self.0).finish()
@@ -8434,6 +8499,7 @@ pub struct SPEVENTLPARAMTYPE(pub i32);
 impl windows_core::TypeKind for SPEVENTLPARAMTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPEVENTLPARAMTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPEVENTLPARAMTYPE").field(&self.0).finish()
@@ -8445,6 +8511,7 @@ pub struct SPFILEMODE(pub i32);
 impl windows_core::TypeKind for SPFILEMODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPFILEMODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPFILEMODE").field(&self.0).finish()
@@ -8456,6 +8523,7 @@ pub struct SPGRAMMAROPTIONS(pub i32);
 impl windows_core::TypeKind for SPGRAMMAROPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPGRAMMAROPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPGRAMMAROPTIONS").field(&self.0).finish()
@@ -8467,6 +8535,7 @@ pub struct SPGRAMMARSTATE(pub i32);
 impl windows_core::TypeKind for SPGRAMMARSTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPGRAMMARSTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPGRAMMARSTATE").field(&self.0).finish()
@@ -8478,6 +8547,7 @@ pub struct SPGRAMMARWORDTYPE(pub i32);
 impl windows_core::TypeKind for SPGRAMMARWORDTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPGRAMMARWORDTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPGRAMMARWORDTYPE").field(&self.0).finish()
@@ -8489,6 +8559,7 @@ pub struct SPINTERFERENCE(pub i32);
 impl windows_core::TypeKind for SPINTERFERENCE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPINTERFERENCE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPINTERFERENCE").field(&self.0).finish()
@@ -8500,6 +8571,7 @@ pub struct SPLEXICONTYPE(pub i32);
 impl windows_core::TypeKind for SPLEXICONTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPLEXICONTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPLEXICONTYPE").field(&self.0).finish()
@@ -8511,6 +8583,7 @@ pub struct SPLOADOPTIONS(pub i32);
 impl windows_core::TypeKind for SPLOADOPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPLOADOPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPLOADOPTIONS").field(&self.0).finish()
@@ -8522,6 +8595,7 @@ pub struct SPMATCHINGMODE(pub i32);
 impl windows_core::TypeKind for SPMATCHINGMODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPMATCHINGMODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPMATCHINGMODE").field(&self.0).finish()
@@ -8533,6 +8607,7 @@ pub struct SPPARTOFSPEECH(pub i32);
 impl windows_core::TypeKind for SPPARTOFSPEECH {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPPARTOFSPEECH {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPPARTOFSPEECH").field(&self.0).finish()
@@ -8544,6 +8619,7 @@ pub struct SPPHRASEPROPERTYUNIONTYPE(pub i32);
 impl windows_core::TypeKind for SPPHRASEPROPERTYUNIONTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPPHRASEPROPERTYUNIONTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPPHRASEPROPERTYUNIONTYPE").field(&self.0).finish()
@@ -8555,6 +8631,7 @@ pub struct SPPHRASERNG(pub i32);
 impl windows_core::TypeKind for SPPHRASERNG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPPHRASERNG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPPHRASERNG").field(&self.0).finish()
@@ -8566,6 +8643,7 @@ pub struct SPPRONUNCIATIONFLAGS(pub i32);
 impl windows_core::TypeKind for SPPRONUNCIATIONFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPPRONUNCIATIONFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPPRONUNCIATIONFLAGS").field(&self.0).finish()
@@ -8577,6 +8655,7 @@ pub struct SPPROPSRC(pub i32);
 impl windows_core::TypeKind for SPPROPSRC {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPPROPSRC {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPPROPSRC").field(&self.0).finish()
@@ -8588,6 +8667,7 @@ pub struct SPRECOEVENTFLAGS(pub i32);
 impl windows_core::TypeKind for SPRECOEVENTFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPRECOEVENTFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPRECOEVENTFLAGS").field(&self.0).finish()
@@ -8599,6 +8679,7 @@ pub struct SPRECOSTATE(pub i32);
 impl windows_core::TypeKind for SPRECOSTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPRECOSTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPRECOSTATE").field(&self.0).finish()
@@ -8610,6 +8691,7 @@ pub struct SPRESULTTYPE(pub i32);
 impl windows_core::TypeKind for SPRESULTTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPRESULTTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPRESULTTYPE").field(&self.0).finish()
@@ -8621,6 +8703,7 @@ pub struct SPRULEINFOOPT(pub i32);
 impl windows_core::TypeKind for SPRULEINFOOPT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPRULEINFOOPT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPRULEINFOOPT").field(&self.0).finish()
@@ -8632,6 +8715,7 @@ pub struct SPRULESTATE(pub i32);
 impl windows_core::TypeKind for SPRULESTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPRULESTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPRULESTATE").field(&self.0).finish()
@@ -8643,6 +8727,7 @@ pub struct SPRUNSTATE(pub i32);
 impl windows_core::TypeKind for SPRUNSTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPRUNSTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPRUNSTATE").field(&self.0).finish()
@@ -8654,6 +8739,7 @@ pub struct SPSEMANTICFORMAT(pub i32);
 impl windows_core::TypeKind for SPSEMANTICFORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPSEMANTICFORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPSEMANTICFORMAT").field(&self.0).finish()
@@ -8665,6 +8751,7 @@ pub struct SPSHORTCUTTYPE(pub i32);
 impl windows_core::TypeKind for SPSHORTCUTTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPSHORTCUTTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPSHORTCUTTYPE").field(&self.0).finish()
@@ -8676,6 +8763,7 @@ pub struct SPSTREAMFORMAT(pub i32);
 impl windows_core::TypeKind for SPSTREAMFORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPSTREAMFORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPSTREAMFORMAT").field(&self.0).finish()
@@ -8687,6 +8775,7 @@ pub struct SPSTREAMFORMATTYPE(pub i32);
 impl windows_core::TypeKind for SPSTREAMFORMATTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPSTREAMFORMATTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPSTREAMFORMATTYPE").field(&self.0).finish()
@@ -8698,6 +8787,7 @@ pub struct SPTRANSITIONTYPE(pub i32);
 impl windows_core::TypeKind for SPTRANSITIONTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPTRANSITIONTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPTRANSITIONTYPE").field(&self.0).finish()
@@ -8709,6 +8799,7 @@ pub struct SPVACTIONS(pub i32);
 impl windows_core::TypeKind for SPVACTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPVACTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPVACTIONS").field(&self.0).finish()
@@ -8720,6 +8811,7 @@ pub struct SPVALUETYPE(pub i32);
 impl windows_core::TypeKind for SPVALUETYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPVALUETYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPVALUETYPE").field(&self.0).finish()
@@ -8731,6 +8823,7 @@ pub struct SPVESACTIONS(pub i32);
 impl windows_core::TypeKind for SPVESACTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPVESACTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPVESACTIONS").field(&self.0).finish()
@@ -8742,6 +8835,7 @@ pub struct SPVFEATURE(pub i32);
 impl windows_core::TypeKind for SPVFEATURE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPVFEATURE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPVFEATURE").field(&self.0).finish()
@@ -8753,6 +8847,7 @@ pub struct SPVISEMES(pub i32);
 impl windows_core::TypeKind for SPVISEMES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPVISEMES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPVISEMES").field(&self.0).finish()
@@ -8764,6 +8859,7 @@ pub struct SPVLIMITS(pub i32);
 impl windows_core::TypeKind for SPVLIMITS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPVLIMITS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPVLIMITS").field(&self.0).finish()
@@ -8775,6 +8871,7 @@ pub struct SPVPRIORITY(pub i32);
 impl windows_core::TypeKind for SPVPRIORITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPVPRIORITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPVPRIORITY").field(&self.0).finish()
@@ -8786,6 +8883,7 @@ pub struct SPVSKIPTYPE(pub i32);
 impl windows_core::TypeKind for SPVSKIPTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPVSKIPTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPVSKIPTYPE").field(&self.0).finish()
@@ -8797,6 +8895,7 @@ pub struct SPWORDINFOOPT(pub i32);
 impl windows_core::TypeKind for SPWORDINFOOPT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPWORDINFOOPT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPWORDINFOOPT").field(&self.0).finish()
@@ -8808,6 +8907,7 @@ pub struct SPWORDPRONOUNCEABLE(pub i32);
 impl windows_core::TypeKind for SPWORDPRONOUNCEABLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPWORDPRONOUNCEABLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPWORDPRONOUNCEABLE").field(&self.0).finish()
@@ -8819,6 +8919,7 @@ pub struct SPWORDTYPE(pub i32);
 impl windows_core::TypeKind for SPWORDTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPWORDTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPWORDTYPE").field(&self.0).finish()
@@ -8830,6 +8931,7 @@ pub struct SPXMLRESULTOPTIONS(pub i32);
 impl windows_core::TypeKind for SPXMLRESULTOPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPXMLRESULTOPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPXMLRESULTOPTIONS").field(&self.0).finish()
@@ -8841,6 +8943,7 @@ pub struct SpeechAudioFormatType(pub i32);
 impl windows_core::TypeKind for SpeechAudioFormatType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechAudioFormatType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechAudioFormatType").field(&self.0).finish()
@@ -8852,6 +8955,7 @@ pub struct SpeechAudioState(pub i32);
 impl windows_core::TypeKind for SpeechAudioState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechAudioState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechAudioState").field(&self.0).finish()
@@ -8863,6 +8967,7 @@ pub struct SpeechBookmarkOptions(pub i32);
 impl windows_core::TypeKind for SpeechBookmarkOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechBookmarkOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechBookmarkOptions").field(&self.0).finish()
@@ -8874,6 +8979,7 @@ pub struct SpeechDataKeyLocation(pub i32);
 impl windows_core::TypeKind for SpeechDataKeyLocation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechDataKeyLocation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechDataKeyLocation").field(&self.0).finish()
@@ -8885,6 +8991,7 @@ pub struct SpeechDiscardType(pub i32);
 impl windows_core::TypeKind for SpeechDiscardType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechDiscardType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechDiscardType").field(&self.0).finish()
@@ -8896,6 +9003,7 @@ pub struct SpeechDisplayAttributes(pub i32);
 impl windows_core::TypeKind for SpeechDisplayAttributes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechDisplayAttributes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechDisplayAttributes").field(&self.0).finish()
@@ -8907,6 +9015,7 @@ pub struct SpeechEmulationCompareFlags(pub i32);
 impl windows_core::TypeKind for SpeechEmulationCompareFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechEmulationCompareFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechEmulationCompareFlags").field(&self.0).finish()
@@ -8918,6 +9027,7 @@ pub struct SpeechEngineConfidence(pub i32);
 impl windows_core::TypeKind for SpeechEngineConfidence {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechEngineConfidence {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechEngineConfidence").field(&self.0).finish()
@@ -8929,6 +9039,7 @@ pub struct SpeechFormatType(pub i32);
 impl windows_core::TypeKind for SpeechFormatType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechFormatType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechFormatType").field(&self.0).finish()
@@ -8940,6 +9051,7 @@ pub struct SpeechGrammarRuleStateTransitionType(pub i32);
 impl windows_core::TypeKind for SpeechGrammarRuleStateTransitionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechGrammarRuleStateTransitionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechGrammarRuleStateTransitionType").field(&self.0).finish()
@@ -8951,6 +9063,7 @@ pub struct SpeechGrammarState(pub i32);
 impl windows_core::TypeKind for SpeechGrammarState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechGrammarState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechGrammarState").field(&self.0).finish()
@@ -8962,6 +9075,7 @@ pub struct SpeechGrammarWordType(pub i32);
 impl windows_core::TypeKind for SpeechGrammarWordType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechGrammarWordType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechGrammarWordType").field(&self.0).finish()
@@ -8973,6 +9087,7 @@ pub struct SpeechInterference(pub i32);
 impl windows_core::TypeKind for SpeechInterference {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechInterference {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechInterference").field(&self.0).finish()
@@ -8984,6 +9099,7 @@ pub struct SpeechLexiconType(pub i32);
 impl windows_core::TypeKind for SpeechLexiconType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechLexiconType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechLexiconType").field(&self.0).finish()
@@ -8995,6 +9111,7 @@ pub struct SpeechLoadOption(pub i32);
 impl windows_core::TypeKind for SpeechLoadOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechLoadOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechLoadOption").field(&self.0).finish()
@@ -9006,6 +9123,7 @@ pub struct SpeechPartOfSpeech(pub i32);
 impl windows_core::TypeKind for SpeechPartOfSpeech {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechPartOfSpeech {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechPartOfSpeech").field(&self.0).finish()
@@ -9017,6 +9135,7 @@ pub struct SpeechRecoContextState(pub i32);
 impl windows_core::TypeKind for SpeechRecoContextState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechRecoContextState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechRecoContextState").field(&self.0).finish()
@@ -9028,6 +9147,7 @@ pub struct SpeechRecoEvents(pub i32);
 impl windows_core::TypeKind for SpeechRecoEvents {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechRecoEvents {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechRecoEvents").field(&self.0).finish()
@@ -9039,6 +9159,7 @@ pub struct SpeechRecognitionType(pub i32);
 impl windows_core::TypeKind for SpeechRecognitionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechRecognitionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechRecognitionType").field(&self.0).finish()
@@ -9050,6 +9171,7 @@ pub struct SpeechRecognizerState(pub i32);
 impl windows_core::TypeKind for SpeechRecognizerState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechRecognizerState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechRecognizerState").field(&self.0).finish()
@@ -9061,6 +9183,7 @@ pub struct SpeechRetainedAudioOptions(pub i32);
 impl windows_core::TypeKind for SpeechRetainedAudioOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechRetainedAudioOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechRetainedAudioOptions").field(&self.0).finish()
@@ -9072,6 +9195,7 @@ pub struct SpeechRuleAttributes(pub i32);
 impl windows_core::TypeKind for SpeechRuleAttributes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechRuleAttributes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechRuleAttributes").field(&self.0).finish()
@@ -9083,6 +9207,7 @@ pub struct SpeechRuleState(pub i32);
 impl windows_core::TypeKind for SpeechRuleState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechRuleState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechRuleState").field(&self.0).finish()
@@ -9094,6 +9219,7 @@ pub struct SpeechRunState(pub i32);
 impl windows_core::TypeKind for SpeechRunState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechRunState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechRunState").field(&self.0).finish()
@@ -9105,6 +9231,7 @@ pub struct SpeechSpecialTransitionType(pub i32);
 impl windows_core::TypeKind for SpeechSpecialTransitionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechSpecialTransitionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechSpecialTransitionType").field(&self.0).finish()
@@ -9116,6 +9243,7 @@ pub struct SpeechStreamFileMode(pub i32);
 impl windows_core::TypeKind for SpeechStreamFileMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechStreamFileMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechStreamFileMode").field(&self.0).finish()
@@ -9127,6 +9255,7 @@ pub struct SpeechStreamSeekPositionType(pub u32);
 impl windows_core::TypeKind for SpeechStreamSeekPositionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechStreamSeekPositionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechStreamSeekPositionType").field(&self.0).finish()
@@ -9138,6 +9267,7 @@ pub struct SpeechTokenContext(pub u32);
 impl windows_core::TypeKind for SpeechTokenContext {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechTokenContext {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechTokenContext").field(&self.0).finish()
@@ -9149,6 +9279,7 @@ pub struct SpeechTokenShellFolder(pub i32);
 impl windows_core::TypeKind for SpeechTokenShellFolder {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechTokenShellFolder {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechTokenShellFolder").field(&self.0).finish()
@@ -9160,6 +9291,7 @@ pub struct SpeechVisemeFeature(pub i32);
 impl windows_core::TypeKind for SpeechVisemeFeature {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechVisemeFeature {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechVisemeFeature").field(&self.0).finish()
@@ -9171,6 +9303,7 @@ pub struct SpeechVisemeType(pub i32);
 impl windows_core::TypeKind for SpeechVisemeType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechVisemeType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechVisemeType").field(&self.0).finish()
@@ -9182,6 +9315,7 @@ pub struct SpeechVoiceEvents(pub i32);
 impl windows_core::TypeKind for SpeechVoiceEvents {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechVoiceEvents {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechVoiceEvents").field(&self.0).finish()
@@ -9193,6 +9327,7 @@ pub struct SpeechVoicePriority(pub i32);
 impl windows_core::TypeKind for SpeechVoicePriority {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechVoicePriority {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechVoicePriority").field(&self.0).finish()
@@ -9204,6 +9339,7 @@ pub struct SpeechVoiceSpeakFlags(pub i32);
 impl windows_core::TypeKind for SpeechVoiceSpeakFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechVoiceSpeakFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechVoiceSpeakFlags").field(&self.0).finish()
@@ -9215,6 +9351,7 @@ pub struct SpeechWordPronounceable(pub i32);
 impl windows_core::TypeKind for SpeechWordPronounceable {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechWordPronounceable {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechWordPronounceable").field(&self.0).finish()
@@ -9226,13 +9363,15 @@ pub struct SpeechWordType(pub i32);
 impl windows_core::TypeKind for SpeechWordType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SpeechWordType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SpeechWordType").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPAUDIOBUFFERINFO {
     pub ulMsMinNotification: u32,
     pub ulMsBufferSize: u32,
@@ -9247,7 +9386,8 @@ impl Default for SPAUDIOBUFFERINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPAUDIOSTATUS {
     pub cbFreeBuffSpace: i32,
     pub cbNonBlockingIO: u32,
@@ -9266,7 +9406,8 @@ impl Default for SPAUDIOSTATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPBINARYGRAMMAR {
     pub ulTotalSerializedSize: u32,
 }
@@ -9279,7 +9420,8 @@ impl Default for SPBINARYGRAMMAR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPDISPLAYPHRASE {
     pub ulNumTokens: u32,
     pub pTokens: *mut SPDISPLAYTOKEN,
@@ -9293,7 +9435,8 @@ impl Default for SPDISPLAYPHRASE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPDISPLAYTOKEN {
     pub pszLexical: windows_core::PCWSTR,
     pub pszDisplay: windows_core::PCWSTR,
@@ -9308,7 +9451,8 @@ impl Default for SPDISPLAYTOKEN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPEVENT {
     pub _bitfield: i32,
     pub ulStreamNum: u32,
@@ -9316,6 +9460,20 @@ pub struct SPEVENT {
     pub wParam: super::super::Foundation::WPARAM,
     pub lParam: super::super::Foundation::LPARAM,
 }
+impl SPEVENT {
+    pub const fn eEventId(&self) -> i32 {
+        self._bitfield & 65535
+    }
+    pub fn set_eEventId(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn elParamType(&self) -> i32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_elParamType(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for SPEVENT {
     type TypeKind = windows_core::CopyType;
 }
@@ -9325,7 +9483,8 @@ impl Default for SPEVENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPEVENTEX {
     pub _bitfield: i32,
     pub ulStreamNum: u32,
@@ -9334,6 +9493,20 @@ pub struct SPEVENTEX {
     pub lParam: super::super::Foundation::LPARAM,
     pub ullAudioTimeOffset: u64,
 }
+impl SPEVENTEX {
+    pub const fn eEventId(&self) -> i32 {
+        self._bitfield & 65535
+    }
+    pub fn set_eEventId(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn elParamType(&self) -> i32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_elParamType(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for SPEVENTEX {
     type TypeKind = windows_core::CopyType;
 }
@@ -9343,7 +9516,8 @@ impl Default for SPEVENTEX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPEVENTSOURCEINFO {
     pub ullEventInterest: u64,
     pub ullQueuedInterest: u64,
@@ -9358,7 +9532,8 @@ impl Default for SPEVENTSOURCEINFO {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPGRAMMARHANDLE(pub *mut core::ffi::c_void);
 impl SPGRAMMARHANDLE {
     pub fn is_invalid(&self) -> bool {
@@ -9374,7 +9549,8 @@ impl windows_core::TypeKind for SPGRAMMARHANDLE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPNORMALIZATIONLIST {
     pub ulSize: u32,
     pub ppszzNormalizedList: *mut *mut u16,
@@ -9388,7 +9564,8 @@ impl Default for SPNORMALIZATIONLIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPPARSEINFO {
     pub cbSize: u32,
     pub hRule: SPRULEHANDLE,
@@ -9410,7 +9587,8 @@ impl Default for SPPARSEINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPPATHENTRY {
     pub hTransition: SPTRANSITIONID,
     pub elem: SPPHRASEELEMENT,
@@ -9424,7 +9602,8 @@ impl Default for SPPATHENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPPHRASE {
     pub Base: SPPHRASE_50,
     pub pSML: windows_core::PWSTR,
@@ -9439,7 +9618,8 @@ impl Default for SPPHRASE {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPPHRASEALT {
     pub pPhrase: core::mem::ManuallyDrop<Option<ISpPhraseBuilder>>,
     pub ulStartElementInParent: u32,
@@ -9462,7 +9642,8 @@ impl Default for SPPHRASEALT {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPPHRASEALTREQUEST {
     pub ulStartElement: u32,
     pub cElements: u32,
@@ -9486,7 +9667,8 @@ impl Default for SPPHRASEALTREQUEST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPPHRASEELEMENT {
     pub ulAudioTimeOffset: u32,
     pub ulAudioSizeTime: u32,
@@ -9552,7 +9734,8 @@ impl Default for SPPHRASEPROPERTY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPPHRASEPROPERTY_0_0 {
     pub bType: u8,
     pub bReserved: u8,
@@ -9567,7 +9750,8 @@ impl Default for SPPHRASEPROPERTY_0_0 {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPPHRASEPROPERTYHANDLE(pub *mut core::ffi::c_void);
 impl SPPHRASEPROPERTYHANDLE {
     pub fn is_invalid(&self) -> bool {
@@ -9583,7 +9767,8 @@ impl windows_core::TypeKind for SPPHRASEPROPERTYHANDLE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPPHRASEREPLACEMENT {
     pub bDisplayAttributes: u8,
     pub pszReplacementText: windows_core::PCWSTR,
@@ -9599,7 +9784,8 @@ impl Default for SPPHRASEREPLACEMENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPPHRASERULE {
     pub pszName: windows_core::PCWSTR,
     pub ulId: u32,
@@ -9619,7 +9805,8 @@ impl Default for SPPHRASERULE {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPPHRASERULEHANDLE(pub *mut core::ffi::c_void);
 impl SPPHRASERULEHANDLE {
     pub fn is_invalid(&self) -> bool {
@@ -9635,7 +9822,8 @@ impl windows_core::TypeKind for SPPHRASERULEHANDLE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPPHRASE_50 {
     pub cbSize: u32,
     pub LangID: u16,
@@ -9664,7 +9852,8 @@ impl Default for SPPHRASE_50 {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPPROPERTYINFO {
     pub pszName: windows_core::PCWSTR,
     pub ulId: u32,
@@ -9685,7 +9874,8 @@ impl Default for SPPROPERTYINFO {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPRECOCONTEXTHANDLE(pub *mut core::ffi::c_void);
 impl SPRECOCONTEXTHANDLE {
     pub fn is_invalid(&self) -> bool {
@@ -9701,7 +9891,8 @@ impl windows_core::TypeKind for SPRECOCONTEXTHANDLE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPRECOCONTEXTSTATUS {
     pub eInterference: SPINTERFERENCE,
     pub szRequestTypeOfUI: [u16; 255],
@@ -9717,7 +9908,8 @@ impl Default for SPRECOCONTEXTSTATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPRECOGNIZERSTATUS {
     pub AudioStatus: SPAUDIOSTATUS,
     pub ullRecognitionStreamPos: u64,
@@ -9737,7 +9929,8 @@ impl Default for SPRECOGNIZERSTATUS {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPRECORESULTINFO {
     pub cbSize: u32,
     pub eResultType: SPRESULTTYPE,
@@ -9766,7 +9959,8 @@ impl Default for SPRECORESULTINFO {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPRECORESULTINFOEX {
     pub Base: SPRECORESULTINFO,
     pub ullStreamTimeStart: u64,
@@ -9786,7 +9980,8 @@ impl Default for SPRECORESULTINFOEX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPRECORESULTTIMES {
     pub ftStreamTime: super::super::Foundation::FILETIME,
     pub ullLength: u64,
@@ -9802,7 +9997,8 @@ impl Default for SPRECORESULTTIMES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPRULE {
     pub pszRuleName: windows_core::PCWSTR,
     pub ulRuleId: u32,
@@ -9817,7 +10013,8 @@ impl Default for SPRULE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPRULEENTRY {
     pub hRule: SPRULEHANDLE,
     pub hInitialState: SPSTATEHANDLE,
@@ -9834,7 +10031,8 @@ impl Default for SPRULEENTRY {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPRULEHANDLE(pub *mut core::ffi::c_void);
 impl SPRULEHANDLE {
     pub fn is_invalid(&self) -> bool {
@@ -9850,7 +10048,8 @@ impl windows_core::TypeKind for SPRULEHANDLE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPSEMANTICERRORINFO {
     pub ulLineNumber: u32,
     pub pszScriptLine: windows_core::PWSTR,
@@ -9867,7 +10066,8 @@ impl Default for SPSEMANTICERRORINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPSERIALIZEDEVENT {
     pub _bitfield: i32,
     pub ulStreamNum: u32,
@@ -9875,6 +10075,20 @@ pub struct SPSERIALIZEDEVENT {
     pub SerializedwParam: u32,
     pub SerializedlParam: i32,
 }
+impl SPSERIALIZEDEVENT {
+    pub const fn eEventId(&self) -> i32 {
+        self._bitfield & 65535
+    }
+    pub fn set_eEventId(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn elParamType(&self) -> i32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_elParamType(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for SPSERIALIZEDEVENT {
     type TypeKind = windows_core::CopyType;
 }
@@ -9884,7 +10098,8 @@ impl Default for SPSERIALIZEDEVENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPSERIALIZEDEVENT64 {
     pub _bitfield: i32,
     pub ulStreamNum: u32,
@@ -9892,6 +10107,20 @@ pub struct SPSERIALIZEDEVENT64 {
     pub SerializedwParam: u64,
     pub SerializedlParam: i64,
 }
+impl SPSERIALIZEDEVENT64 {
+    pub const fn eEventId(&self) -> i32 {
+        self._bitfield & 65535
+    }
+    pub fn set_eEventId(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn elParamType(&self) -> i32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_elParamType(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for SPSERIALIZEDEVENT64 {
     type TypeKind = windows_core::CopyType;
 }
@@ -9901,7 +10130,8 @@ impl Default for SPSERIALIZEDEVENT64 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPSERIALIZEDPHRASE {
     pub ulSerializedSize: u32,
 }
@@ -9914,7 +10144,8 @@ impl Default for SPSERIALIZEDPHRASE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPSERIALIZEDRESULT {
     pub ulSerializedSize: u32,
 }
@@ -9927,7 +10158,8 @@ impl Default for SPSERIALIZEDRESULT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPSHORTCUTPAIR {
     pub pNextSHORTCUTPAIR: *mut SPSHORTCUTPAIR,
     pub LangID: u16,
@@ -9944,7 +10176,8 @@ impl Default for SPSHORTCUTPAIR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPSHORTCUTPAIRLIST {
     pub ulSize: u32,
     pub pvBuffer: *mut u8,
@@ -9959,7 +10192,8 @@ impl Default for SPSHORTCUTPAIRLIST {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPSTATEHANDLE(pub *mut core::ffi::c_void);
 impl SPSTATEHANDLE {
     pub fn is_invalid(&self) -> bool {
@@ -9975,7 +10209,8 @@ impl windows_core::TypeKind for SPSTATEHANDLE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPSTATEINFO {
     pub cAllocatedEntries: u32,
     pub pTransitions: *mut SPTRANSITIONENTRY,
@@ -9993,7 +10228,8 @@ impl Default for SPSTATEINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPTEXTSELECTIONINFO {
     pub ulStartActiveOffset: u32,
     pub cchActiveChars: u32,
@@ -10009,7 +10245,8 @@ impl Default for SPTEXTSELECTIONINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPTMTHREADINFO {
     pub lPoolSize: i32,
     pub lPriority: i32,
@@ -10044,7 +10281,8 @@ impl Default for SPTRANSITIONENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPTRANSITIONENTRY_0 {
     pub fHasProperty: u32,
 }
@@ -10072,7 +10310,8 @@ impl Default for SPTRANSITIONENTRY_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPTRANSITIONENTRY_1_0 {
     pub hRuleInitialState: SPSTATEHANDLE,
     pub hRule: SPRULEHANDLE,
@@ -10087,7 +10326,8 @@ impl Default for SPTRANSITIONENTRY_1_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPTRANSITIONENTRY_1_1 {
     pub hWord: SPWORDHANDLE,
     pub pvClientWordContext: *mut core::ffi::c_void,
@@ -10101,7 +10341,8 @@ impl Default for SPTRANSITIONENTRY_1_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPTRANSITIONENTRY_1_2 {
     pub pvGrammarCookie: *mut core::ffi::c_void,
 }
@@ -10114,7 +10355,8 @@ impl Default for SPTRANSITIONENTRY_1_2 {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPTRANSITIONID(pub *mut core::ffi::c_void);
 impl SPTRANSITIONID {
     pub fn is_invalid(&self) -> bool {
@@ -10130,7 +10372,8 @@ impl windows_core::TypeKind for SPTRANSITIONID {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPTRANSITIONPROPERTY {
     pub pszName: windows_core::PCWSTR,
     pub ulId: u32,
@@ -10151,7 +10394,8 @@ impl Default for SPTRANSITIONPROPERTY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPVCONTEXT {
     pub pCategory: windows_core::PCWSTR,
     pub pBefore: windows_core::PCWSTR,
@@ -10166,7 +10410,8 @@ impl Default for SPVCONTEXT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPVOICESTATUS {
     pub ulCurrentStream: u32,
     pub ulLastStreamQueued: u32,
@@ -10191,7 +10436,8 @@ impl Default for SPVOICESTATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPVPITCH {
     pub MiddleAdj: i32,
     pub RangeAdj: i32,
@@ -10205,7 +10451,8 @@ impl Default for SPVPITCH {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPVSTATE {
     pub eAction: SPVACTIONS,
     pub LangID: u16,
@@ -10228,7 +10475,8 @@ impl Default for SPVSTATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPVTEXTFRAG {
     pub pNext: *mut SPVTEXTFRAG,
     pub State: SPVSTATE,
@@ -10245,7 +10493,8 @@ impl Default for SPVTEXTFRAG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPWORD {
     pub pNextWord: *mut SPWORD,
     pub LangID: u16,
@@ -10263,7 +10512,8 @@ impl Default for SPWORD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPWORDENTRY {
     pub hWord: SPWORDHANDLE,
     pub LangID: u16,
@@ -10281,7 +10531,8 @@ impl Default for SPWORDENTRY {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPWORDHANDLE(pub *mut core::ffi::c_void);
 impl SPWORDHANDLE {
     pub fn is_invalid(&self) -> bool {
@@ -10297,7 +10548,8 @@ impl windows_core::TypeKind for SPWORDHANDLE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPWORDLIST {
     pub ulSize: u32,
     pub pvBuffer: *mut u8,
@@ -10312,7 +10564,8 @@ impl Default for SPWORDLIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPWORDPRONUNCIATION {
     pub pNextWordPronunciation: *mut SPWORDPRONUNCIATION,
     pub eLexiconType: SPLEXICONTYPE,
@@ -10330,7 +10583,8 @@ impl Default for SPWORDPRONUNCIATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPWORDPRONUNCIATIONLIST {
     pub ulSize: u32,
     pub pvBuffer: *mut u8,
@@ -10382,3 +10636,5 @@ pub const SpWaveFormatEx: windows_core::GUID = windows_core::GUID::from_u128(0xc
 pub type SPNOTIFYCALLBACK = Option<unsafe extern "system" fn(wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM)>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
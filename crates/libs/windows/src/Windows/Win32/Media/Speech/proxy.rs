@@ -0,0 +1,4629 @@
+impl windows_core::imp::ProxyVtable for IEnumSpObjectTokens_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, pelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSpObjectTokens", "Next", 3usize, |this| ((**(this as *const *const IEnumSpObjectTokens_Vtbl)).Next)(this, celt, pelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSpObjectTokens", "Skip", 4usize, |this| ((**(this as *const *const IEnumSpObjectTokens_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSpObjectTokens", "Reset", 5usize, |this| ((**(this as *const *const IEnumSpObjectTokens_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSpObjectTokens", "Clone", 6usize, |this| ((**(this as *const *const IEnumSpObjectTokens_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: u32, pptoken: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSpObjectTokens", "Item", 7usize, |this| ((**(this as *const *const IEnumSpObjectTokens_Vtbl)).Item)(this, index, pptoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSpObjectTokens", "GetCount", 8usize, |this| ((**(this as *const *const IEnumSpObjectTokens_Vtbl)).GetCount)(this, pcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone, Item, GetCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumSpObjectTokens as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ISpAudio_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetState(this: *mut core::ffi::c_void, newstate: SPAUDIOSTATE, ullreserved: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpAudio", "SetState", 15usize, |this| ((**(this as *const *const ISpAudio_Vtbl)).SetState)(this, newstate, ullreserved), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn SetFormat(this: *mut core::ffi::c_void, rguidfmtid: *const windows_core::GUID, pwaveformatex: *const super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpAudio", "SetFormat", 16usize, |this| ((**(this as *const *const ISpAudio_Vtbl)).SetFormat)(this, rguidfmtid, pwaveformatex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pstatus: *mut SPAUDIOSTATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpAudio", "GetStatus", 17usize, |this| ((**(this as *const *const ISpAudio_Vtbl)).GetStatus)(this, pstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBufferInfo(this: *mut core::ffi::c_void, pbuffinfo: *const SPAUDIOBUFFERINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpAudio", "SetBufferInfo", 18usize, |this| ((**(this as *const *const ISpAudio_Vtbl)).SetBufferInfo)(this, pbuffinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBufferInfo(this: *mut core::ffi::c_void, pbuffinfo: *mut SPAUDIOBUFFERINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpAudio", "GetBufferInfo", 19usize, |this| ((**(this as *const *const ISpAudio_Vtbl)).GetBufferInfo)(this, pbuffinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn GetDefaultFormat(this: *mut core::ffi::c_void, pformatid: *mut windows_core::GUID, ppcomemwaveformatex: *mut *mut super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpAudio", "GetDefaultFormat", 20usize, |this| ((**(this as *const *const ISpAudio_Vtbl)).GetDefaultFormat)(this, pformatid, ppcomemwaveformatex), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventHandle(this: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            windows_core::imp::proxy_call(this, "ISpAudio", "EventHandle", 21usize, |this| ((**(this as *const *const ISpAudio_Vtbl)).EventHandle)(this), |_| None)
+        }
+        unsafe extern "system" fn GetVolumeLevel(this: *mut core::ffi::c_void, plevel: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpAudio", "GetVolumeLevel", 22usize, |this| ((**(this as *const *const ISpAudio_Vtbl)).GetVolumeLevel)(this, plevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVolumeLevel(this: *mut core::ffi::c_void, level: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpAudio", "SetVolumeLevel", 23usize, |this| ((**(this as *const *const ISpAudio_Vtbl)).SetVolumeLevel)(this, level), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBufferNotifySize(this: *mut core::ffi::c_void, pcbsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpAudio", "GetBufferNotifySize", 24usize, |this| ((**(this as *const *const ISpAudio_Vtbl)).GetBufferNotifySize)(this, pcbsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBufferNotifySize(this: *mut core::ffi::c_void, cbsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpAudio", "SetBufferNotifySize", 25usize, |this| ((**(this as *const *const ISpAudio_Vtbl)).SetBufferNotifySize)(this, cbsize), |result| Some(*result))
+        }
+        Self {
+            base__: <ISpStreamFormat_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetState,
+            #[cfg(feature = "Win32_Media_Audio")]
+            SetFormat,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            SetFormat: 0,
+            GetStatus,
+            SetBufferInfo,
+            GetBufferInfo,
+            #[cfg(feature = "Win32_Media_Audio")]
+            GetDefaultFormat,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            GetDefaultFormat: 0,
+            EventHandle,
+            GetVolumeLevel,
+            SetVolumeLevel,
+            GetBufferNotifySize,
+            SetBufferNotifySize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpAudio as windows_core::Interface>::IID || <ISpStreamFormat_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpCFGInterpreter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitGrammar(this: *mut core::ffi::c_void, pszgrammarname: windows_core::PCWSTR, pvgrammardata: *const *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpCFGInterpreter", "InitGrammar", 3usize, |this| ((**(this as *const *const ISpCFGInterpreter_Vtbl)).InitGrammar)(this, pszgrammarname, pvgrammardata), |result| Some(*result))
+        }
+        unsafe extern "system" fn Interpret(this: *mut core::ffi::c_void, pphrase: *mut core::ffi::c_void, ulfirstelement: u32, ulcountofelements: u32, psite: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpCFGInterpreter", "Interpret", 4usize, |this| ((**(this as *const *const ISpCFGInterpreter_Vtbl)).Interpret)(this, pphrase, ulfirstelement, ulcountofelements, psite), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InitGrammar, Interpret }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpCFGInterpreter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpCFGInterpreterSite_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddTextReplacement(this: *mut core::ffi::c_void, preplace: *const SPPHRASEREPLACEMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpCFGInterpreterSite", "AddTextReplacement", 3usize, |this| ((**(this as *const *const ISpCFGInterpreterSite_Vtbl)).AddTextReplacement)(this, preplace), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddProperty(this: *mut core::ffi::c_void, pproperty: *const SPPHRASEPROPERTY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpCFGInterpreterSite", "AddProperty", 4usize, |this| ((**(this as *const *const ISpCFGInterpreterSite_Vtbl)).AddProperty)(this, pproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResourceValue(this: *mut core::ffi::c_void, pszresourcename: windows_core::PCWSTR, ppcomemresource: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpCFGInterpreterSite", "GetResourceValue", 5usize, |this| ((**(this as *const *const ISpCFGInterpreterSite_Vtbl)).GetResourceValue)(this, pszresourcename, ppcomemresource), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddTextReplacement, AddProperty, GetResourceValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpCFGInterpreterSite as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpContainerLexicon_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddLexicon(this: *mut core::ffi::c_void, paddlexicon: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpContainerLexicon", "AddLexicon", 9usize, |this| ((**(this as *const *const ISpContainerLexicon_Vtbl)).AddLexicon)(this, paddlexicon, dwflags), |result| Some(*result))
+        }
+        Self { base__: <ISpLexicon_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddLexicon }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpContainerLexicon as windows_core::Interface>::IID || <ISpLexicon_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpDataKey_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, pszvaluename: windows_core::PCWSTR, cbdata: u32, pdata: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpDataKey", "SetData", 3usize, |this| ((**(this as *const *const ISpDataKey_Vtbl)).SetData)(this, pszvaluename, cbdata, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetData(this: *mut core::ffi::c_void, pszvaluename: windows_core::PCWSTR, pcbdata: *mut u32, pdata: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpDataKey", "GetData", 4usize, |this| ((**(this as *const *const ISpDataKey_Vtbl)).GetData)(this, pszvaluename, pcbdata, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStringValue(this: *mut core::ffi::c_void, pszvaluename: windows_core::PCWSTR, pszvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpDataKey", "SetStringValue", 5usize, |this| ((**(this as *const *const ISpDataKey_Vtbl)).SetStringValue)(this, pszvaluename, pszvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStringValue(this: *mut core::ffi::c_void, pszvaluename: windows_core::PCWSTR, ppszvalue: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpDataKey", "GetStringValue", 6usize, |this| ((**(this as *const *const ISpDataKey_Vtbl)).GetStringValue)(this, pszvaluename, ppszvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDWORD(this: *mut core::ffi::c_void, pszvaluename: windows_core::PCWSTR, dwvalue: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpDataKey", "SetDWORD", 7usize, |this| ((**(this as *const *const ISpDataKey_Vtbl)).SetDWORD)(this, pszvaluename, dwvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDWORD(this: *mut core::ffi::c_void, pszvaluename: windows_core::PCWSTR, pdwvalue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpDataKey", "GetDWORD", 8usize, |this| ((**(this as *const *const ISpDataKey_Vtbl)).GetDWORD)(this, pszvaluename, pdwvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenKey(this: *mut core::ffi::c_void, pszsubkeyname: windows_core::PCWSTR, ppsubkey: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpDataKey", "OpenKey", 9usize, |this| ((**(this as *const *const ISpDataKey_Vtbl)).OpenKey)(this, pszsubkeyname, ppsubkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateKey(this: *mut core::ffi::c_void, pszsubkey: windows_core::PCWSTR, ppsubkey: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpDataKey", "CreateKey", 10usize, |this| ((**(this as *const *const ISpDataKey_Vtbl)).CreateKey)(this, pszsubkey, ppsubkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteKey(this: *mut core::ffi::c_void, pszsubkey: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpDataKey", "DeleteKey", 11usize, |this| ((**(this as *const *const ISpDataKey_Vtbl)).DeleteKey)(this, pszsubkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteValue(this: *mut core::ffi::c_void, pszvaluename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpDataKey", "DeleteValue", 12usize, |this| ((**(this as *const *const ISpDataKey_Vtbl)).DeleteValue)(this, pszvaluename), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumKeys(this: *mut core::ffi::c_void, index: u32, ppszsubkeyname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpDataKey", "EnumKeys", 13usize, |this| ((**(this as *const *const ISpDataKey_Vtbl)).EnumKeys)(this, index, ppszsubkeyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumValues(this: *mut core::ffi::c_void, index: u32, ppszvaluename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpDataKey", "EnumValues", 14usize, |this| ((**(this as *const *const ISpDataKey_Vtbl)).EnumValues)(this, index, ppszvaluename), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetData,
+            GetData,
+            SetStringValue,
+            GetStringValue,
+            SetDWORD,
+            GetDWORD,
+            OpenKey,
+            CreateKey,
+            DeleteKey,
+            DeleteValue,
+            EnumKeys,
+            EnumValues,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpDataKey as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpDisplayAlternates_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDisplayAlternates(this: *mut core::ffi::c_void, pphrase: *const SPDISPLAYPHRASE, crequestcount: u32, ppcomemphrases: *mut *mut SPDISPLAYPHRASE, pcphrasesreturned: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpDisplayAlternates", "GetDisplayAlternates", 3usize, |this| ((**(this as *const *const ISpDisplayAlternates_Vtbl)).GetDisplayAlternates)(this, pphrase, crequestcount, ppcomemphrases, pcphrasesreturned), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFullStopTrailSpace(this: *mut core::ffi::c_void, ultrailspace: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpDisplayAlternates", "SetFullStopTrailSpace", 4usize, |this| ((**(this as *const *const ISpDisplayAlternates_Vtbl)).SetFullStopTrailSpace)(this, ultrailspace), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDisplayAlternates, SetFullStopTrailSpace }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpDisplayAlternates as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpEnginePronunciation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Normalize(this: *mut core::ffi::c_void, pszword: windows_core::PCWSTR, pszleftcontext: windows_core::PCWSTR, pszrightcontext: windows_core::PCWSTR, langid: u16, pnormalizationlist: *mut SPNORMALIZATIONLIST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpEnginePronunciation", "Normalize", 3usize, |this| ((**(this as *const *const ISpEnginePronunciation_Vtbl)).Normalize)(this, pszword, pszleftcontext, pszrightcontext, langid, pnormalizationlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPronunciations(this: *mut core::ffi::c_void, pszword: windows_core::PCWSTR, pszleftcontext: windows_core::PCWSTR, pszrightcontext: windows_core::PCWSTR, langid: u16, penginepronunciationlist: *mut SPWORDPRONUNCIATIONLIST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpEnginePronunciation", "GetPronunciations", 4usize, |this| ((**(this as *const *const ISpEnginePronunciation_Vtbl)).GetPronunciations)(this, pszword, pszleftcontext, pszrightcontext, langid, penginepronunciationlist), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Normalize, GetPronunciations }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpEnginePronunciation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpErrorLog_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddError(this: *mut core::ffi::c_void, llinenumber: i32, hr: windows_core::HRESULT, pszdescription: windows_core::PCWSTR, pszhelpfile: windows_core::PCWSTR, dwhelpcontext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpErrorLog", "AddError", 3usize, |this| ((**(this as *const *const ISpErrorLog_Vtbl)).AddError)(this, llinenumber, hr, pszdescription, pszhelpfile, dwhelpcontext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpErrorLog as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpEventSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddEvents(this: *mut core::ffi::c_void, peventarray: *const SPEVENT, ulcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpEventSink", "AddEvents", 3usize, |this| ((**(this as *const *const ISpEventSink_Vtbl)).AddEvents)(this, peventarray, ulcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEventInterest(this: *mut core::ffi::c_void, pulleventinterest: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpEventSink", "GetEventInterest", 4usize, |this| ((**(this as *const *const ISpEventSink_Vtbl)).GetEventInterest)(this, pulleventinterest), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddEvents, GetEventInterest }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpEventSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpEventSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetInterest(this: *mut core::ffi::c_void, ulleventinterest: u64, ullqueuedinterest: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpEventSource", "SetInterest", 10usize, |this| ((**(this as *const *const ISpEventSource_Vtbl)).SetInterest)(this, ulleventinterest, ullqueuedinterest), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEvents(this: *mut core::ffi::c_void, ulcount: u32, peventarray: *mut SPEVENT, pulfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpEventSource", "GetEvents", 11usize, |this| ((**(this as *const *const ISpEventSource_Vtbl)).GetEvents)(this, ulcount, peventarray, pulfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInfo(this: *mut core::ffi::c_void, pinfo: *mut SPEVENTSOURCEINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpEventSource", "GetInfo", 12usize, |this| ((**(this as *const *const ISpEventSource_Vtbl)).GetInfo)(this, pinfo), |result| Some(*result))
+        }
+        Self { base__: <ISpNotifySource_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetInterest, GetEvents, GetInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpEventSource as windows_core::Interface>::IID || <ISpNotifySource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpEventSource2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEventsEx(this: *mut core::ffi::c_void, ulcount: u32, peventarray: *mut SPEVENTEX, pulfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpEventSource2", "GetEventsEx", 13usize, |this| ((**(this as *const *const ISpEventSource2_Vtbl)).GetEventsEx)(this, ulcount, peventarray, pulfetched), |result| Some(*result))
+        }
+        Self { base__: <ISpEventSource_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetEventsEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpEventSource2 as windows_core::Interface>::IID || <ISpEventSource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpGramCompBackend_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSaveObjects(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, perrorlog: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpGramCompBackend", "SetSaveObjects", 11usize, |this| ((**(this as *const *const ISpGramCompBackend_Vtbl)).SetSaveObjects)(this, pstream, perrorlog), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitFromBinaryGrammar(this: *mut core::ffi::c_void, pbinarydata: *const SPBINARYGRAMMAR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpGramCompBackend", "InitFromBinaryGrammar", 12usize, |this| ((**(this as *const *const ISpGramCompBackend_Vtbl)).InitFromBinaryGrammar)(this, pbinarydata), |result| Some(*result))
+        }
+        Self {
+            base__: <ISpGrammarBuilder_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSaveObjects,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSaveObjects: 0,
+            InitFromBinaryGrammar,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpGramCompBackend as windows_core::Interface>::IID || <ISpGrammarBuilder_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpGrammarBuilder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResetGrammar(this: *mut core::ffi::c_void, newlanguage: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpGrammarBuilder", "ResetGrammar", 3usize, |this| ((**(this as *const *const ISpGrammarBuilder_Vtbl)).ResetGrammar)(this, newlanguage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRule(this: *mut core::ffi::c_void, pszrulename: windows_core::PCWSTR, dwruleid: u32, dwattributes: u32, fcreateifnotexist: super::super::Foundation::BOOL, phinitialstate: *mut SPSTATEHANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpGrammarBuilder", "GetRule", 4usize, |this| ((**(this as *const *const ISpGrammarBuilder_Vtbl)).GetRule)(this, pszrulename, dwruleid, dwattributes, fcreateifnotexist, phinitialstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearRule(this: *mut core::ffi::c_void, hstate: SPSTATEHANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpGrammarBuilder", "ClearRule", 5usize, |this| ((**(this as *const *const ISpGrammarBuilder_Vtbl)).ClearRule)(this, hstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateNewState(this: *mut core::ffi::c_void, hstate: SPSTATEHANDLE, phstate: *mut SPSTATEHANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpGrammarBuilder", "CreateNewState", 6usize, |this| ((**(this as *const *const ISpGrammarBuilder_Vtbl)).CreateNewState)(this, hstate, phstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddWordTransition(this: *mut core::ffi::c_void, hfromstate: SPSTATEHANDLE, htostate: SPSTATEHANDLE, psz: windows_core::PCWSTR, pszseparators: windows_core::PCWSTR, ewordtype: SPGRAMMARWORDTYPE, weight: f32, ppropinfo: *const SPPROPERTYINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpGrammarBuilder", "AddWordTransition", 7usize, |this| ((**(this as *const *const ISpGrammarBuilder_Vtbl)).AddWordTransition)(this, hfromstate, htostate, psz, pszseparators, ewordtype, weight, ppropinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddRuleTransition(this: *mut core::ffi::c_void, hfromstate: SPSTATEHANDLE, htostate: SPSTATEHANDLE, hrule: SPSTATEHANDLE, weight: f32, ppropinfo: *const SPPROPERTYINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpGrammarBuilder", "AddRuleTransition", 8usize, |this| ((**(this as *const *const ISpGrammarBuilder_Vtbl)).AddRuleTransition)(this, hfromstate, htostate, hrule, weight, ppropinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddResource(this: *mut core::ffi::c_void, hrulestate: SPSTATEHANDLE, pszresourcename: windows_core::PCWSTR, pszresourcevalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpGrammarBuilder", "AddResource", 9usize, |this| ((**(this as *const *const ISpGrammarBuilder_Vtbl)).AddResource)(this, hrulestate, pszresourcename, pszresourcevalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpGrammarBuilder", "Commit", 10usize, |this| ((**(this as *const *const ISpGrammarBuilder_Vtbl)).Commit)(this, dwreserved), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ResetGrammar,
+            GetRule,
+            ClearRule,
+            CreateNewState,
+            AddWordTransition,
+            AddRuleTransition,
+            AddResource,
+            Commit,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpGrammarBuilder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpGrammarBuilder2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddTextSubset(this: *mut core::ffi::c_void, hfromstate: SPSTATEHANDLE, htostate: SPSTATEHANDLE, psz: windows_core::PCWSTR, ematchmode: SPMATCHINGMODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpGrammarBuilder2", "AddTextSubset", 3usize, |this| ((**(this as *const *const ISpGrammarBuilder2_Vtbl)).AddTextSubset)(this, hfromstate, htostate, psz, ematchmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPhoneticAlphabet(this: *mut core::ffi::c_void, phoneticalphabet: PHONETICALPHABET) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpGrammarBuilder2", "SetPhoneticAlphabet", 4usize, |this| ((**(this as *const *const ISpGrammarBuilder2_Vtbl)).SetPhoneticAlphabet)(this, phoneticalphabet), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddTextSubset, SetPhoneticAlphabet }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpGrammarBuilder2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpGrammarCompiler_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CompileStream(this: *mut core::ffi::c_void, psource: *mut core::ffi::c_void, pdest: *mut core::ffi::c_void, pheader: *mut core::ffi::c_void, preserved: *mut core::ffi::c_void, perrorlog: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpGrammarCompiler", "CompileStream", 3usize, |this| ((**(this as *const *const ISpGrammarCompiler_Vtbl)).CompileStream)(this, psource, pdest, pheader, preserved, perrorlog, dwflags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CompileStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CompileStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpGrammarCompiler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpITNProcessor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LoadITNGrammar(this: *mut core::ffi::c_void, pszclsid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpITNProcessor", "LoadITNGrammar", 3usize, |this| ((**(this as *const *const ISpITNProcessor_Vtbl)).LoadITNGrammar)(this, pszclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ITNPhrase(this: *mut core::ffi::c_void, pphrase: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpITNProcessor", "ITNPhrase", 4usize, |this| ((**(this as *const *const ISpITNProcessor_Vtbl)).ITNPhrase)(this, pphrase), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LoadITNGrammar, ITNPhrase }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpITNProcessor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpLexicon_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPronunciations(this: *mut core::ffi::c_void, pszword: windows_core::PCWSTR, langid: u16, dwflags: u32, pwordpronunciationlist: *mut SPWORDPRONUNCIATIONLIST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpLexicon", "GetPronunciations", 3usize, |this| ((**(this as *const *const ISpLexicon_Vtbl)).GetPronunciations)(this, pszword, langid, dwflags, pwordpronunciationlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPronunciation(this: *mut core::ffi::c_void, pszword: windows_core::PCWSTR, langid: u16, epartofspeech: SPPARTOFSPEECH, pszpronunciation: *const u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpLexicon", "AddPronunciation", 4usize, |this| ((**(this as *const *const ISpLexicon_Vtbl)).AddPronunciation)(this, pszword, langid, epartofspeech, pszpronunciation), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePronunciation(this: *mut core::ffi::c_void, pszword: windows_core::PCWSTR, langid: u16, epartofspeech: SPPARTOFSPEECH, pszpronunciation: *const u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpLexicon", "RemovePronunciation", 5usize, |this| ((**(this as *const *const ISpLexicon_Vtbl)).RemovePronunciation)(this, pszword, langid, epartofspeech, pszpronunciation), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGeneration(this: *mut core::ffi::c_void, pdwgeneration: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpLexicon", "GetGeneration", 6usize, |this| ((**(this as *const *const ISpLexicon_Vtbl)).GetGeneration)(this, pdwgeneration), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGenerationChange(this: *mut core::ffi::c_void, dwflags: u32, pdwgeneration: *mut u32, pwordlist: *mut SPWORDLIST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpLexicon", "GetGenerationChange", 7usize, |this| ((**(this as *const *const ISpLexicon_Vtbl)).GetGenerationChange)(this, dwflags, pdwgeneration, pwordlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWords(this: *mut core::ffi::c_void, dwflags: u32, pdwgeneration: *mut u32, pdwcookie: *mut u32, pwordlist: *mut SPWORDLIST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpLexicon", "GetWords", 8usize, |this| ((**(this as *const *const ISpLexicon_Vtbl)).GetWords)(this, dwflags, pdwgeneration, pdwcookie, pwordlist), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPronunciations,
+            AddPronunciation,
+            RemovePronunciation,
+            GetGeneration,
+            GetGenerationChange,
+            GetWords,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpLexicon as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ISpMMSysAudio_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceId(this: *mut core::ffi::c_void, pudeviceid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpMMSysAudio", "GetDeviceId", 26usize, |this| ((**(this as *const *const ISpMMSysAudio_Vtbl)).GetDeviceId)(this, pudeviceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeviceId(this: *mut core::ffi::c_void, udeviceid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpMMSysAudio", "SetDeviceId", 27usize, |this| ((**(this as *const *const ISpMMSysAudio_Vtbl)).SetDeviceId)(this, udeviceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMMHandle(this: *mut core::ffi::c_void, phandle: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpMMSysAudio", "GetMMHandle", 28usize, |this| ((**(this as *const *const ISpMMSysAudio_Vtbl)).GetMMHandle)(this, phandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLineId(this: *mut core::ffi::c_void, pulineid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpMMSysAudio", "GetLineId", 29usize, |this| ((**(this as *const *const ISpMMSysAudio_Vtbl)).GetLineId)(this, pulineid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLineId(this: *mut core::ffi::c_void, ulineid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpMMSysAudio", "SetLineId", 30usize, |this| ((**(this as *const *const ISpMMSysAudio_Vtbl)).SetLineId)(this, ulineid), |result| Some(*result))
+        }
+        Self { base__: <ISpAudio_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceId, SetDeviceId, GetMMHandle, GetLineId, SetLineId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpMMSysAudio as windows_core::Interface>::IID || <ISpAudio_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpNotifySink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Notify(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpNotifySink", "Notify", 3usize, |this| ((**(this as *const *const ISpNotifySink_Vtbl)).Notify)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Notify }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpNotifySink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpNotifySource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetNotifySink(this: *mut core::ffi::c_void, pnotifysink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpNotifySource", "SetNotifySink", 3usize, |this| ((**(this as *const *const ISpNotifySource_Vtbl)).SetNotifySink)(this, pnotifysink), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNotifyWindowMessage(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, msg: u32, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpNotifySource", "SetNotifyWindowMessage", 4usize, |this| ((**(this as *const *const ISpNotifySource_Vtbl)).SetNotifyWindowMessage)(this, hwnd, msg, wparam, lparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNotifyCallbackFunction(this: *mut core::ffi::c_void, pfncallback: *mut SPNOTIFYCALLBACK, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpNotifySource", "SetNotifyCallbackFunction", 5usize, |this| ((**(this as *const *const ISpNotifySource_Vtbl)).SetNotifyCallbackFunction)(this, pfncallback, wparam, lparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNotifyCallbackInterface(this: *mut core::ffi::c_void, pspcallback: *mut core::ffi::c_void, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpNotifySource", "SetNotifyCallbackInterface", 6usize, |this| ((**(this as *const *const ISpNotifySource_Vtbl)).SetNotifyCallbackInterface)(this, pspcallback, wparam, lparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNotifyWin32Event(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpNotifySource", "SetNotifyWin32Event", 7usize, |this| ((**(this as *const *const ISpNotifySource_Vtbl)).SetNotifyWin32Event)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForNotifyEvent(this: *mut core::ffi::c_void, dwmilliseconds: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpNotifySource", "WaitForNotifyEvent", 8usize, |this| ((**(this as *const *const ISpNotifySource_Vtbl)).WaitForNotifyEvent)(this, dwmilliseconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNotifyEventHandle(this: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            windows_core::imp::proxy_call(this, "ISpNotifySource", "GetNotifyEventHandle", 9usize, |this| ((**(this as *const *const ISpNotifySource_Vtbl)).GetNotifyEventHandle)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetNotifySink,
+            SetNotifyWindowMessage,
+            SetNotifyCallbackFunction,
+            SetNotifyCallbackInterface,
+            SetNotifyWin32Event,
+            WaitForNotifyEvent,
+            GetNotifyEventHandle,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpNotifySource as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpNotifyTranslator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitWindowMessage(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, msg: u32, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpNotifyTranslator", "InitWindowMessage", 4usize, |this| ((**(this as *const *const ISpNotifyTranslator_Vtbl)).InitWindowMessage)(this, hwnd, msg, wparam, lparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitCallback(this: *mut core::ffi::c_void, pfncallback: *mut SPNOTIFYCALLBACK, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpNotifyTranslator", "InitCallback", 5usize, |this| ((**(this as *const *const ISpNotifyTranslator_Vtbl)).InitCallback)(this, pfncallback, wparam, lparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitSpNotifyCallback(this: *mut core::ffi::c_void, pspcallback: *mut core::ffi::c_void, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpNotifyTranslator", "InitSpNotifyCallback", 6usize, |this| ((**(this as *const *const ISpNotifyTranslator_Vtbl)).InitSpNotifyCallback)(this, pspcallback, wparam, lparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitWin32Event(this: *mut core::ffi::c_void, hevent: super::super::Foundation::HANDLE, fclosehandleonrelease: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpNotifyTranslator", "InitWin32Event", 7usize, |this| ((**(this as *const *const ISpNotifyTranslator_Vtbl)).InitWin32Event)(this, hevent, fclosehandleonrelease), |result| Some(*result))
+        }
+        unsafe extern "system" fn Wait(this: *mut core::ffi::c_void, dwmilliseconds: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpNotifyTranslator", "Wait", 8usize, |this| ((**(this as *const *const ISpNotifyTranslator_Vtbl)).Wait)(this, dwmilliseconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEventHandle(this: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            windows_core::imp::proxy_call(this, "ISpNotifyTranslator", "GetEventHandle", 9usize, |this| ((**(this as *const *const ISpNotifyTranslator_Vtbl)).GetEventHandle)(this), |_| None)
+        }
+        Self {
+            base__: <ISpNotifySink_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitWindowMessage,
+            InitCallback,
+            InitSpNotifyCallback,
+            InitWin32Event,
+            Wait,
+            GetEventHandle,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpNotifyTranslator as windows_core::Interface>::IID || <ISpNotifySink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpObjectToken_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetId(this: *mut core::ffi::c_void, pszcategoryid: windows_core::PCWSTR, psztokenid: windows_core::PCWSTR, fcreateifnotexist: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpObjectToken", "SetId", 15usize, |this| ((**(this as *const *const ISpObjectToken_Vtbl)).SetId)(this, pszcategoryid, psztokenid, fcreateifnotexist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetId(this: *mut core::ffi::c_void, ppszcomemtokenid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpObjectToken", "GetId", 16usize, |this| ((**(this as *const *const ISpObjectToken_Vtbl)).GetId)(this, ppszcomemtokenid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCategory(this: *mut core::ffi::c_void, pptokencategory: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpObjectToken", "GetCategory", 17usize, |this| ((**(this as *const *const ISpObjectToken_Vtbl)).GetCategory)(this, pptokencategory), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, dwclscontext: u32, riid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpObjectToken", "CreateInstance", 18usize, |this| ((**(this as *const *const ISpObjectToken_Vtbl)).CreateInstance)(this, punkouter, dwclscontext, riid, ppvobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStorageFileName(this: *mut core::ffi::c_void, clsidcaller: *const windows_core::GUID, pszvaluename: windows_core::PCWSTR, pszfilenamespecifier: windows_core::PCWSTR, nfolder: u32, ppszfilepath: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpObjectToken", "GetStorageFileName", 19usize, |this| ((**(this as *const *const ISpObjectToken_Vtbl)).GetStorageFileName)(this, clsidcaller, pszvaluename, pszfilenamespecifier, nfolder, ppszfilepath), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStorageFileName(this: *mut core::ffi::c_void, clsidcaller: *const windows_core::GUID, pszkeyname: windows_core::PCWSTR, fdeletefile: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpObjectToken", "RemoveStorageFileName", 20usize, |this| ((**(this as *const *const ISpObjectToken_Vtbl)).RemoveStorageFileName)(this, clsidcaller, pszkeyname, fdeletefile), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, pclsidcaller: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpObjectToken", "Remove", 21usize, |this| ((**(this as *const *const ISpObjectToken_Vtbl)).Remove)(this, pclsidcaller), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUISupported(this: *mut core::ffi::c_void, psztypeofui: windows_core::PCWSTR, pvextradata: *mut core::ffi::c_void, cbextradata: u32, punkobject: *mut core::ffi::c_void, pfsupported: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpObjectToken", "IsUISupported", 22usize, |this| ((**(this as *const *const ISpObjectToken_Vtbl)).IsUISupported)(this, psztypeofui, pvextradata, cbextradata, punkobject, pfsupported), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayUI(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, psztitle: windows_core::PCWSTR, psztypeofui: windows_core::PCWSTR, pvextradata: *mut core::ffi::c_void, cbextradata: u32, punkobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpObjectToken", "DisplayUI", 23usize, |this| ((**(this as *const *const ISpObjectToken_Vtbl)).DisplayUI)(this, hwndparent, psztitle, psztypeofui, pvextradata, cbextradata, punkobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn MatchesAttributes(this: *mut core::ffi::c_void, pszattributes: windows_core::PCWSTR, pfmatches: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpObjectToken", "MatchesAttributes", 24usize, |this| ((**(this as *const *const ISpObjectToken_Vtbl)).MatchesAttributes)(this, pszattributes, pfmatches), |result| Some(*result))
+        }
+        Self {
+            base__: <ISpDataKey_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetId,
+            GetId,
+            GetCategory,
+            CreateInstance,
+            GetStorageFileName,
+            RemoveStorageFileName,
+            Remove,
+            IsUISupported,
+            DisplayUI,
+            MatchesAttributes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpObjectToken as windows_core::Interface>::IID || <ISpDataKey_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpObjectTokenCategory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetId(this: *mut core::ffi::c_void, pszcategoryid: windows_core::PCWSTR, fcreateifnotexist: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpObjectTokenCategory", "SetId", 15usize, |this| ((**(this as *const *const ISpObjectTokenCategory_Vtbl)).SetId)(this, pszcategoryid, fcreateifnotexist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetId(this: *mut core::ffi::c_void, ppszcomemcategoryid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpObjectTokenCategory", "GetId", 16usize, |this| ((**(this as *const *const ISpObjectTokenCategory_Vtbl)).GetId)(this, ppszcomemcategoryid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataKey(this: *mut core::ffi::c_void, spdkl: SPDATAKEYLOCATION, ppdatakey: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpObjectTokenCategory", "GetDataKey", 17usize, |this| ((**(this as *const *const ISpObjectTokenCategory_Vtbl)).GetDataKey)(this, spdkl, ppdatakey), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumTokens(this: *mut core::ffi::c_void, pzsreqattribs: windows_core::PCWSTR, pszoptattribs: windows_core::PCWSTR, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpObjectTokenCategory", "EnumTokens", 18usize, |this| ((**(this as *const *const ISpObjectTokenCategory_Vtbl)).EnumTokens)(this, pzsreqattribs, pszoptattribs, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultTokenId(this: *mut core::ffi::c_void, psztokenid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpObjectTokenCategory", "SetDefaultTokenId", 19usize, |this| ((**(this as *const *const ISpObjectTokenCategory_Vtbl)).SetDefaultTokenId)(this, psztokenid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultTokenId(this: *mut core::ffi::c_void, ppszcomemtokenid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpObjectTokenCategory", "GetDefaultTokenId", 20usize, |this| ((**(this as *const *const ISpObjectTokenCategory_Vtbl)).GetDefaultTokenId)(this, ppszcomemtokenid), |result| Some(*result))
+        }
+        Self { base__: <ISpDataKey_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetId, GetId, GetDataKey, EnumTokens, SetDefaultTokenId, GetDefaultTokenId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpObjectTokenCategory as windows_core::Interface>::IID || <ISpDataKey_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpObjectTokenEnumBuilder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAttribs(this: *mut core::ffi::c_void, pszreqattribs: windows_core::PCWSTR, pszoptattribs: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpObjectTokenEnumBuilder", "SetAttribs", 9usize, |this| ((**(this as *const *const ISpObjectTokenEnumBuilder_Vtbl)).SetAttribs)(this, pszreqattribs, pszoptattribs), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTokens(this: *mut core::ffi::c_void, ctokens: u32, ptoken: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpObjectTokenEnumBuilder", "AddTokens", 10usize, |this| ((**(this as *const *const ISpObjectTokenEnumBuilder_Vtbl)).AddTokens)(this, ctokens, ptoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTokensFromDataKey(this: *mut core::ffi::c_void, pdatakey: *mut core::ffi::c_void, pszsubkey: windows_core::PCWSTR, pszcategoryid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpObjectTokenEnumBuilder", "AddTokensFromDataKey", 11usize, |this| ((**(this as *const *const ISpObjectTokenEnumBuilder_Vtbl)).AddTokensFromDataKey)(this, pdatakey, pszsubkey, pszcategoryid), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTokensFromTokenEnum(this: *mut core::ffi::c_void, ptokenenum: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpObjectTokenEnumBuilder", "AddTokensFromTokenEnum", 12usize, |this| ((**(this as *const *const ISpObjectTokenEnumBuilder_Vtbl)).AddTokensFromTokenEnum)(this, ptokenenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sort(this: *mut core::ffi::c_void, psztokenidtolistfirst: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpObjectTokenEnumBuilder", "Sort", 13usize, |this| ((**(this as *const *const ISpObjectTokenEnumBuilder_Vtbl)).Sort)(this, psztokenidtolistfirst), |result| Some(*result))
+        }
+        Self {
+            base__: <IEnumSpObjectTokens_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetAttribs,
+            AddTokens,
+            AddTokensFromDataKey,
+            AddTokensFromTokenEnum,
+            Sort,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpObjectTokenEnumBuilder as windows_core::Interface>::IID || <IEnumSpObjectTokens_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpObjectTokenInit_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitFromDataKey(this: *mut core::ffi::c_void, pszcategoryid: windows_core::PCWSTR, psztokenid: windows_core::PCWSTR, pdatakey: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpObjectTokenInit", "InitFromDataKey", 25usize, |this| ((**(this as *const *const ISpObjectTokenInit_Vtbl)).InitFromDataKey)(this, pszcategoryid, psztokenid, pdatakey), |result| Some(*result))
+        }
+        Self { base__: <ISpObjectToken_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InitFromDataKey }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpObjectTokenInit as windows_core::Interface>::IID || <ISpObjectToken_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpObjectWithToken_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetObjectToken(this: *mut core::ffi::c_void, ptoken: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpObjectWithToken", "SetObjectToken", 3usize, |this| ((**(this as *const *const ISpObjectWithToken_Vtbl)).SetObjectToken)(this, ptoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObjectToken(this: *mut core::ffi::c_void, pptoken: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpObjectWithToken", "GetObjectToken", 4usize, |this| ((**(this as *const *const ISpObjectWithToken_Vtbl)).GetObjectToken)(this, pptoken), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetObjectToken, GetObjectToken }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpObjectWithToken as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpPhoneConverter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PhoneToId(this: *mut core::ffi::c_void, pszphone: windows_core::PCWSTR, pid: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpPhoneConverter", "PhoneToId", 5usize, |this| ((**(this as *const *const ISpPhoneConverter_Vtbl)).PhoneToId)(this, pszphone, pid), |result| Some(*result))
+        }
+        unsafe extern "system" fn IdToPhone(this: *mut core::ffi::c_void, pid: *const u16, pszphone: windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpPhoneConverter", "IdToPhone", 6usize, |this| ((**(this as *const *const ISpPhoneConverter_Vtbl)).IdToPhone)(this, pid, pszphone), |result| Some(*result))
+        }
+        Self { base__: <ISpObjectWithToken_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PhoneToId, IdToPhone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpPhoneConverter as windows_core::Interface>::IID || <ISpObjectWithToken_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpPhoneticAlphabetConverter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLangId(this: *mut core::ffi::c_void, plangid: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpPhoneticAlphabetConverter", "GetLangId", 3usize, |this| ((**(this as *const *const ISpPhoneticAlphabetConverter_Vtbl)).GetLangId)(this, plangid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLangId(this: *mut core::ffi::c_void, langid: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpPhoneticAlphabetConverter", "SetLangId", 4usize, |this| ((**(this as *const *const ISpPhoneticAlphabetConverter_Vtbl)).SetLangId)(this, langid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SAPI2UPS(this: *mut core::ffi::c_void, pszsapiid: *const u16, pszupsid: *mut u16, cmaxlength: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpPhoneticAlphabetConverter", "SAPI2UPS", 5usize, |this| ((**(this as *const *const ISpPhoneticAlphabetConverter_Vtbl)).SAPI2UPS)(this, pszsapiid, pszupsid, cmaxlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn UPS2SAPI(this: *mut core::ffi::c_void, pszupsid: *const u16, pszsapiid: *mut u16, cmaxlength: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpPhoneticAlphabetConverter", "UPS2SAPI", 6usize, |this| ((**(this as *const *const ISpPhoneticAlphabetConverter_Vtbl)).UPS2SAPI)(this, pszupsid, pszsapiid, cmaxlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxConvertLength(this: *mut core::ffi::c_void, csrclength: u32, bsapi2ups: super::super::Foundation::BOOL, pcmaxdestlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpPhoneticAlphabetConverter", "GetMaxConvertLength", 7usize, |this| ((**(this as *const *const ISpPhoneticAlphabetConverter_Vtbl)).GetMaxConvertLength)(this, csrclength, bsapi2ups, pcmaxdestlength), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetLangId, SetLangId, SAPI2UPS, UPS2SAPI, GetMaxConvertLength }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpPhoneticAlphabetConverter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpPhoneticAlphabetSelection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsAlphabetUPS(this: *mut core::ffi::c_void, pfisups: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpPhoneticAlphabetSelection", "IsAlphabetUPS", 3usize, |this| ((**(this as *const *const ISpPhoneticAlphabetSelection_Vtbl)).IsAlphabetUPS)(this, pfisups), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlphabetToUPS(this: *mut core::ffi::c_void, fforceups: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpPhoneticAlphabetSelection", "SetAlphabetToUPS", 4usize, |this| ((**(this as *const *const ISpPhoneticAlphabetSelection_Vtbl)).SetAlphabetToUPS)(this, fforceups), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsAlphabetUPS, SetAlphabetToUPS }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpPhoneticAlphabetSelection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpPhrase_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPhrase(this: *mut core::ffi::c_void, ppcomemphrase: *mut *mut SPPHRASE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpPhrase", "GetPhrase", 3usize, |this| ((**(this as *const *const ISpPhrase_Vtbl)).GetPhrase)(this, ppcomemphrase), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSerializedPhrase(this: *mut core::ffi::c_void, ppcomemphrase: *mut *mut SPSERIALIZEDPHRASE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpPhrase", "GetSerializedPhrase", 4usize, |this| ((**(this as *const *const ISpPhrase_Vtbl)).GetSerializedPhrase)(this, ppcomemphrase), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetText(this: *mut core::ffi::c_void, ulstart: u32, ulcount: u32, fusetextreplacements: super::super::Foundation::BOOL, ppszcomemtext: *mut windows_core::PWSTR, pbdisplayattributes: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpPhrase", "GetText", 5usize, |this| ((**(this as *const *const ISpPhrase_Vtbl)).GetText)(this, ulstart, ulcount, fusetextreplacements, ppszcomemtext, pbdisplayattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn Discard(this: *mut core::ffi::c_void, dwvaluetypes: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpPhrase", "Discard", 6usize, |this| ((**(this as *const *const ISpPhrase_Vtbl)).Discard)(this, dwvaluetypes), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPhrase, GetSerializedPhrase, GetText, Discard }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpPhrase as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpPhrase2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetXMLResult(this: *mut core::ffi::c_void, ppszcomemxmlresult: *mut windows_core::PWSTR, options: SPXMLRESULTOPTIONS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpPhrase2", "GetXMLResult", 7usize, |this| ((**(this as *const *const ISpPhrase2_Vtbl)).GetXMLResult)(this, ppszcomemxmlresult, options), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetXMLErrorInfo(this: *mut core::ffi::c_void, psemanticerrorinfo: *mut SPSEMANTICERRORINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpPhrase2", "GetXMLErrorInfo", 8usize, |this| ((**(this as *const *const ISpPhrase2_Vtbl)).GetXMLErrorInfo)(this, psemanticerrorinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetAudio(this: *mut core::ffi::c_void, ulstartelement: u32, celements: u32, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpPhrase2", "GetAudio", 9usize, |this| ((**(this as *const *const ISpPhrase2_Vtbl)).GetAudio)(this, ulstartelement, celements, ppstream), |result| Some(*result))
+        }
+        Self {
+            base__: <ISpPhrase_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetXMLResult,
+            GetXMLErrorInfo,
+            #[cfg(feature = "Win32_System_Com")]
+            GetAudio,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetAudio: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpPhrase2 as windows_core::Interface>::IID || <ISpPhrase_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpPhraseAlt_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAltInfo(this: *mut core::ffi::c_void, ppparent: *mut *mut core::ffi::c_void, pulstartelementinparent: *mut u32, pcelementsinparent: *mut u32, pcelementsinalt: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpPhraseAlt", "GetAltInfo", 7usize, |this| ((**(this as *const *const ISpPhraseAlt_Vtbl)).GetAltInfo)(this, ppparent, pulstartelementinparent, pcelementsinparent, pcelementsinalt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpPhraseAlt", "Commit", 8usize, |this| ((**(this as *const *const ISpPhraseAlt_Vtbl)).Commit)(this), |result| Some(*result))
+        }
+        Self { base__: <ISpPhrase_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAltInfo, Commit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpPhraseAlt as windows_core::Interface>::IID || <ISpPhrase_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpPhraseBuilder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitFromPhrase(this: *mut core::ffi::c_void, pphrase: *const SPPHRASE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpPhraseBuilder", "InitFromPhrase", 7usize, |this| ((**(this as *const *const ISpPhraseBuilder_Vtbl)).InitFromPhrase)(this, pphrase), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitFromSerializedPhrase(this: *mut core::ffi::c_void, pphrase: *const SPSERIALIZEDPHRASE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpPhraseBuilder", "InitFromSerializedPhrase", 8usize, |this| ((**(this as *const *const ISpPhraseBuilder_Vtbl)).InitFromSerializedPhrase)(this, pphrase), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddElements(this: *mut core::ffi::c_void, celements: u32, pelement: *const SPPHRASEELEMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpPhraseBuilder", "AddElements", 9usize, |this| ((**(this as *const *const ISpPhraseBuilder_Vtbl)).AddElements)(this, celements, pelement), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddRules(this: *mut core::ffi::c_void, hparent: SPPHRASERULEHANDLE, prule: *const SPPHRASERULE, phnewrule: *mut SPPHRASERULEHANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpPhraseBuilder", "AddRules", 10usize, |this| ((**(this as *const *const ISpPhraseBuilder_Vtbl)).AddRules)(this, hparent, prule, phnewrule), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddProperties(this: *mut core::ffi::c_void, hparent: SPPHRASEPROPERTYHANDLE, pproperty: *const SPPHRASEPROPERTY, phnewproperty: *mut SPPHRASEPROPERTYHANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpPhraseBuilder", "AddProperties", 11usize, |this| ((**(this as *const *const ISpPhraseBuilder_Vtbl)).AddProperties)(this, hparent, pproperty, phnewproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddReplacements(this: *mut core::ffi::c_void, creplacements: u32, preplacements: *const SPPHRASEREPLACEMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpPhraseBuilder", "AddReplacements", 12usize, |this| ((**(this as *const *const ISpPhraseBuilder_Vtbl)).AddReplacements)(this, creplacements, preplacements), |result| Some(*result))
+        }
+        Self {
+            base__: <ISpPhrase_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitFromPhrase,
+            InitFromSerializedPhrase,
+            AddElements,
+            AddRules,
+            AddProperties,
+            AddReplacements,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpPhraseBuilder as windows_core::Interface>::IID || <ISpPhrase_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpPrivateEngineCallEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CallEngineSynchronize(this: *mut core::ffi::c_void, pinframe: *const core::ffi::c_void, ulinframesize: u32, ppcomemoutframe: *mut *mut core::ffi::c_void, puloutframesize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpPrivateEngineCallEx", "CallEngineSynchronize", 3usize, |this| ((**(this as *const *const ISpPrivateEngineCallEx_Vtbl)).CallEngineSynchronize)(this, pinframe, ulinframesize, ppcomemoutframe, puloutframesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallEngineImmediate(this: *mut core::ffi::c_void, pinframe: *const core::ffi::c_void, ulinframesize: u32, ppcomemoutframe: *mut *mut core::ffi::c_void, puloutframesize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpPrivateEngineCallEx", "CallEngineImmediate", 4usize, |this| ((**(this as *const *const ISpPrivateEngineCallEx_Vtbl)).CallEngineImmediate)(this, pinframe, ulinframesize, ppcomemoutframe, puloutframesize), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CallEngineSynchronize, CallEngineImmediate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpPrivateEngineCallEx as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPropertyNum(this: *mut core::ffi::c_void, pname: windows_core::PCWSTR, lvalue: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpProperties", "SetPropertyNum", 3usize, |this| ((**(this as *const *const ISpProperties_Vtbl)).SetPropertyNum)(this, pname, lvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyNum(this: *mut core::ffi::c_void, pname: windows_core::PCWSTR, plvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpProperties", "GetPropertyNum", 4usize, |this| ((**(this as *const *const ISpProperties_Vtbl)).GetPropertyNum)(this, pname, plvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPropertyString(this: *mut core::ffi::c_void, pname: windows_core::PCWSTR, pvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpProperties", "SetPropertyString", 5usize, |this| ((**(this as *const *const ISpProperties_Vtbl)).SetPropertyString)(this, pname, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyString(this: *mut core::ffi::c_void, pname: windows_core::PCWSTR, ppcomemvalue: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpProperties", "GetPropertyString", 6usize, |this| ((**(this as *const *const ISpProperties_Vtbl)).GetPropertyString)(this, pname, ppcomemvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetPropertyNum,
+            GetPropertyNum,
+            SetPropertyString,
+            GetPropertyString,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpProperties as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_Audio")]
+impl windows_core::imp::ProxyVtable for ISpRecoContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRecognizer(this: *mut core::ffi::c_void, pprecognizer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoContext", "GetRecognizer", 13usize, |this| ((**(this as *const *const ISpRecoContext_Vtbl)).GetRecognizer)(this, pprecognizer), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateGrammar(this: *mut core::ffi::c_void, ullgrammarid: u64, ppgrammar: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoContext", "CreateGrammar", 14usize, |this| ((**(this as *const *const ISpRecoContext_Vtbl)).CreateGrammar)(this, ullgrammarid, ppgrammar), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pstatus: *mut SPRECOCONTEXTSTATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoContext", "GetStatus", 15usize, |this| ((**(this as *const *const ISpRecoContext_Vtbl)).GetStatus)(this, pstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxAlternates(this: *mut core::ffi::c_void, pcalternates: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoContext", "GetMaxAlternates", 16usize, |this| ((**(this as *const *const ISpRecoContext_Vtbl)).GetMaxAlternates)(this, pcalternates), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxAlternates(this: *mut core::ffi::c_void, calternates: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoContext", "SetMaxAlternates", 17usize, |this| ((**(this as *const *const ISpRecoContext_Vtbl)).SetMaxAlternates)(this, calternates), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn SetAudioOptions(this: *mut core::ffi::c_void, options: SPAUDIOOPTIONS, paudioformatid: *const windows_core::GUID, pwaveformatex: *const super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoContext", "SetAudioOptions", 18usize, |this| ((**(this as *const *const ISpRecoContext_Vtbl)).SetAudioOptions)(this, options, paudioformatid, pwaveformatex), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn GetAudioOptions(this: *mut core::ffi::c_void, poptions: *mut SPAUDIOOPTIONS, paudioformatid: *mut windows_core::GUID, ppcomemwfex: *mut *mut super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoContext", "GetAudioOptions", 19usize, |this| ((**(this as *const *const ISpRecoContext_Vtbl)).GetAudioOptions)(this, poptions, paudioformatid, ppcomemwfex), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeserializeResult(this: *mut core::ffi::c_void, pserializedresult: *const SPSERIALIZEDRESULT, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoContext", "DeserializeResult", 20usize, |this| ((**(this as *const *const ISpRecoContext_Vtbl)).DeserializeResult)(this, pserializedresult, ppresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bookmark(this: *mut core::ffi::c_void, options: SPBOOKMARKOPTIONS, ullstreamposition: u64, lparamevent: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoContext", "Bookmark", 21usize, |this| ((**(this as *const *const ISpRecoContext_Vtbl)).Bookmark)(this, options, ullstreamposition, lparamevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAdaptationData(this: *mut core::ffi::c_void, padaptationdata: windows_core::PCWSTR, cch: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoContext", "SetAdaptationData", 22usize, |this| ((**(this as *const *const ISpRecoContext_Vtbl)).SetAdaptationData)(this, padaptationdata, cch), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoContext", "Pause", 23usize, |this| ((**(this as *const *const ISpRecoContext_Vtbl)).Pause)(this, dwreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoContext", "Resume", 24usize, |this| ((**(this as *const *const ISpRecoContext_Vtbl)).Resume)(this, dwreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVoice(this: *mut core::ffi::c_void, pvoice: *mut core::ffi::c_void, fallowformatchanges: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoContext", "SetVoice", 25usize, |this| ((**(this as *const *const ISpRecoContext_Vtbl)).SetVoice)(this, pvoice, fallowformatchanges), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVoice(this: *mut core::ffi::c_void, ppvoice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoContext", "GetVoice", 26usize, |this| ((**(this as *const *const ISpRecoContext_Vtbl)).GetVoice)(this, ppvoice), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVoicePurgeEvent(this: *mut core::ffi::c_void, ulleventinterest: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoContext", "SetVoicePurgeEvent", 27usize, |this| ((**(this as *const *const ISpRecoContext_Vtbl)).SetVoicePurgeEvent)(this, ulleventinterest), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVoicePurgeEvent(this: *mut core::ffi::c_void, pulleventinterest: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoContext", "GetVoicePurgeEvent", 28usize, |this| ((**(this as *const *const ISpRecoContext_Vtbl)).GetVoicePurgeEvent)(this, pulleventinterest), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContextState(this: *mut core::ffi::c_void, econtextstate: SPCONTEXTSTATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoContext", "SetContextState", 29usize, |this| ((**(this as *const *const ISpRecoContext_Vtbl)).SetContextState)(this, econtextstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContextState(this: *mut core::ffi::c_void, pecontextstate: *mut SPCONTEXTSTATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoContext", "GetContextState", 30usize, |this| ((**(this as *const *const ISpRecoContext_Vtbl)).GetContextState)(this, pecontextstate), |result| Some(*result))
+        }
+        Self {
+            base__: <ISpEventSource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetRecognizer,
+            CreateGrammar,
+            GetStatus,
+            GetMaxAlternates,
+            SetMaxAlternates,
+            #[cfg(feature = "Win32_Media_Audio")]
+            SetAudioOptions,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            SetAudioOptions: 0,
+            #[cfg(feature = "Win32_Media_Audio")]
+            GetAudioOptions,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            GetAudioOptions: 0,
+            DeserializeResult,
+            Bookmark,
+            SetAdaptationData,
+            Pause,
+            Resume,
+            SetVoice,
+            GetVoice,
+            SetVoicePurgeEvent,
+            GetVoicePurgeEvent,
+            SetContextState,
+            GetContextState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpRecoContext as windows_core::Interface>::IID || <ISpEventSource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpRecoContext2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetGrammarOptions(this: *mut core::ffi::c_void, egrammaroptions: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoContext2", "SetGrammarOptions", 3usize, |this| ((**(this as *const *const ISpRecoContext2_Vtbl)).SetGrammarOptions)(this, egrammaroptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGrammarOptions(this: *mut core::ffi::c_void, pegrammaroptions: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoContext2", "GetGrammarOptions", 4usize, |this| ((**(this as *const *const ISpRecoContext2_Vtbl)).GetGrammarOptions)(this, pegrammaroptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAdaptationData2(this: *mut core::ffi::c_void, padaptationdata: windows_core::PCWSTR, cch: u32, ptopicname: windows_core::PCWSTR, eadaptationsettings: u32, erelevance: SPADAPTATIONRELEVANCE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoContext2", "SetAdaptationData2", 5usize, |this| ((**(this as *const *const ISpRecoContext2_Vtbl)).SetAdaptationData2)(this, padaptationdata, cch, ptopicname, eadaptationsettings, erelevance), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetGrammarOptions, GetGrammarOptions, SetAdaptationData2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpRecoContext2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpRecoGrammar_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetGrammarId(this: *mut core::ffi::c_void, pullgrammarid: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoGrammar", "GetGrammarId", 11usize, |this| ((**(this as *const *const ISpRecoGrammar_Vtbl)).GetGrammarId)(this, pullgrammarid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecoContext(this: *mut core::ffi::c_void, pprecoctxt: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoGrammar", "GetRecoContext", 12usize, |this| ((**(this as *const *const ISpRecoGrammar_Vtbl)).GetRecoContext)(this, pprecoctxt), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadCmdFromFile(this: *mut core::ffi::c_void, pszfilename: windows_core::PCWSTR, options: SPLOADOPTIONS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoGrammar", "LoadCmdFromFile", 13usize, |this| ((**(this as *const *const ISpRecoGrammar_Vtbl)).LoadCmdFromFile)(this, pszfilename, options), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadCmdFromObject(this: *mut core::ffi::c_void, rcid: *const windows_core::GUID, pszgrammarname: windows_core::PCWSTR, options: SPLOADOPTIONS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoGrammar", "LoadCmdFromObject", 14usize, |this| ((**(this as *const *const ISpRecoGrammar_Vtbl)).LoadCmdFromObject)(this, rcid, pszgrammarname, options), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadCmdFromResource(this: *mut core::ffi::c_void, hmodule: super::super::Foundation::HMODULE, pszresourcename: windows_core::PCWSTR, pszresourcetype: windows_core::PCWSTR, wlanguage: u16, options: SPLOADOPTIONS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoGrammar", "LoadCmdFromResource", 15usize, |this| ((**(this as *const *const ISpRecoGrammar_Vtbl)).LoadCmdFromResource)(this, hmodule, pszresourcename, pszresourcetype, wlanguage, options), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadCmdFromMemory(this: *mut core::ffi::c_void, pgrammar: *const SPBINARYGRAMMAR, options: SPLOADOPTIONS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoGrammar", "LoadCmdFromMemory", 16usize, |this| ((**(this as *const *const ISpRecoGrammar_Vtbl)).LoadCmdFromMemory)(this, pgrammar, options), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadCmdFromProprietaryGrammar(this: *mut core::ffi::c_void, rguidparam: *const windows_core::GUID, pszstringparam: windows_core::PCWSTR, pvdataprarm: *const core::ffi::c_void, cbdatasize: u32, options: SPLOADOPTIONS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoGrammar", "LoadCmdFromProprietaryGrammar", 17usize, |this| ((**(this as *const *const ISpRecoGrammar_Vtbl)).LoadCmdFromProprietaryGrammar)(this, rguidparam, pszstringparam, pvdataprarm, cbdatasize, options), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRuleState(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, preserved: *mut core::ffi::c_void, newstate: SPRULESTATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoGrammar", "SetRuleState", 18usize, |this| ((**(this as *const *const ISpRecoGrammar_Vtbl)).SetRuleState)(this, pszname, preserved, newstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRuleIdState(this: *mut core::ffi::c_void, ulruleid: u32, newstate: SPRULESTATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoGrammar", "SetRuleIdState", 19usize, |this| ((**(this as *const *const ISpRecoGrammar_Vtbl)).SetRuleIdState)(this, ulruleid, newstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadDictation(this: *mut core::ffi::c_void, psztopicname: windows_core::PCWSTR, options: SPLOADOPTIONS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoGrammar", "LoadDictation", 20usize, |this| ((**(this as *const *const ISpRecoGrammar_Vtbl)).LoadDictation)(this, psztopicname, options), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnloadDictation(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoGrammar", "UnloadDictation", 21usize, |this| ((**(this as *const *const ISpRecoGrammar_Vtbl)).UnloadDictation)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDictationState(this: *mut core::ffi::c_void, newstate: SPRULESTATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoGrammar", "SetDictationState", 22usize, |this| ((**(this as *const *const ISpRecoGrammar_Vtbl)).SetDictationState)(this, newstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWordSequenceData(this: *mut core::ffi::c_void, ptext: windows_core::PCWSTR, cchtext: u32, pinfo: *const SPTEXTSELECTIONINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoGrammar", "SetWordSequenceData", 23usize, |this| ((**(this as *const *const ISpRecoGrammar_Vtbl)).SetWordSequenceData)(this, ptext, cchtext, pinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTextSelection(this: *mut core::ffi::c_void, pinfo: *const SPTEXTSELECTIONINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoGrammar", "SetTextSelection", 24usize, |this| ((**(this as *const *const ISpRecoGrammar_Vtbl)).SetTextSelection)(this, pinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPronounceable(this: *mut core::ffi::c_void, pszword: windows_core::PCWSTR, pwordpronounceable: *mut SPWORDPRONOUNCEABLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoGrammar", "IsPronounceable", 25usize, |this| ((**(this as *const *const ISpRecoGrammar_Vtbl)).IsPronounceable)(this, pszword, pwordpronounceable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGrammarState(this: *mut core::ffi::c_void, egrammarstate: SPGRAMMARSTATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoGrammar", "SetGrammarState", 26usize, |this| ((**(this as *const *const ISpRecoGrammar_Vtbl)).SetGrammarState)(this, egrammarstate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SaveCmd(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, ppszcomemerrortext: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoGrammar", "SaveCmd", 27usize, |this| ((**(this as *const *const ISpRecoGrammar_Vtbl)).SaveCmd)(this, pstream, ppszcomemerrortext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGrammarState(this: *mut core::ffi::c_void, pegrammarstate: *mut SPGRAMMARSTATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoGrammar", "GetGrammarState", 28usize, |this| ((**(this as *const *const ISpRecoGrammar_Vtbl)).GetGrammarState)(this, pegrammarstate), |result| Some(*result))
+        }
+        Self {
+            base__: <ISpGrammarBuilder_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetGrammarId,
+            GetRecoContext,
+            LoadCmdFromFile,
+            LoadCmdFromObject,
+            LoadCmdFromResource,
+            LoadCmdFromMemory,
+            LoadCmdFromProprietaryGrammar,
+            SetRuleState,
+            SetRuleIdState,
+            LoadDictation,
+            UnloadDictation,
+            SetDictationState,
+            SetWordSequenceData,
+            SetTextSelection,
+            IsPronounceable,
+            SetGrammarState,
+            #[cfg(feature = "Win32_System_Com")]
+            SaveCmd,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SaveCmd: 0,
+            GetGrammarState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpRecoGrammar as windows_core::Interface>::IID || <ISpGrammarBuilder_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_Urlmon")]
+impl windows_core::imp::ProxyVtable for ISpRecoGrammar2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRules(this: *mut core::ffi::c_void, ppcomemrules: *mut *mut SPRULE, punumrules: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoGrammar2", "GetRules", 3usize, |this| ((**(this as *const *const ISpRecoGrammar2_Vtbl)).GetRules)(this, ppcomemrules, punumrules), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadCmdFromFile2(this: *mut core::ffi::c_void, pszfilename: windows_core::PCWSTR, options: SPLOADOPTIONS, pszsharinguri: windows_core::PCWSTR, pszbaseuri: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoGrammar2", "LoadCmdFromFile2", 4usize, |this| ((**(this as *const *const ISpRecoGrammar2_Vtbl)).LoadCmdFromFile2)(this, pszfilename, options, pszsharinguri, pszbaseuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadCmdFromMemory2(this: *mut core::ffi::c_void, pgrammar: *const SPBINARYGRAMMAR, options: SPLOADOPTIONS, pszsharinguri: windows_core::PCWSTR, pszbaseuri: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoGrammar2", "LoadCmdFromMemory2", 5usize, |this| ((**(this as *const *const ISpRecoGrammar2_Vtbl)).LoadCmdFromMemory2)(this, pgrammar, options, pszsharinguri, pszbaseuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRulePriority(this: *mut core::ffi::c_void, pszrulename: windows_core::PCWSTR, ulruleid: u32, nrulepriority: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoGrammar2", "SetRulePriority", 6usize, |this| ((**(this as *const *const ISpRecoGrammar2_Vtbl)).SetRulePriority)(this, pszrulename, ulruleid, nrulepriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRuleWeight(this: *mut core::ffi::c_void, pszrulename: windows_core::PCWSTR, ulruleid: u32, flweight: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoGrammar2", "SetRuleWeight", 7usize, |this| ((**(this as *const *const ISpRecoGrammar2_Vtbl)).SetRuleWeight)(this, pszrulename, ulruleid, flweight), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDictationWeight(this: *mut core::ffi::c_void, flweight: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoGrammar2", "SetDictationWeight", 8usize, |this| ((**(this as *const *const ISpRecoGrammar2_Vtbl)).SetDictationWeight)(this, flweight), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetGrammarLoader(this: *mut core::ffi::c_void, ploader: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoGrammar2", "SetGrammarLoader", 9usize, |this| ((**(this as *const *const ISpRecoGrammar2_Vtbl)).SetGrammarLoader)(this, ploader), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_Urlmon")]
+        unsafe extern "system" fn SetSMLSecurityManager(this: *mut core::ffi::c_void, psmlsecuritymanager: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoGrammar2", "SetSMLSecurityManager", 10usize, |this| ((**(this as *const *const ISpRecoGrammar2_Vtbl)).SetSMLSecurityManager)(this, psmlsecuritymanager), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetRules,
+            LoadCmdFromFile2,
+            LoadCmdFromMemory2,
+            SetRulePriority,
+            SetRuleWeight,
+            SetDictationWeight,
+            #[cfg(feature = "Win32_System_Com")]
+            SetGrammarLoader,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetGrammarLoader: 0,
+            #[cfg(feature = "Win32_System_Com_Urlmon")]
+            SetSMLSecurityManager,
+            #[cfg(not(feature = "Win32_System_Com_Urlmon"))]
+            SetSMLSecurityManager: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpRecoGrammar2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ISpRecoResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetResultTimes(this: *mut core::ffi::c_void, ptimes: *mut SPRECORESULTTIMES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoResult", "GetResultTimes", 7usize, |this| ((**(this as *const *const ISpRecoResult_Vtbl)).GetResultTimes)(this, ptimes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAlternates(this: *mut core::ffi::c_void, ulstartelement: u32, celements: u32, ulrequestcount: u32, ppphrases: *mut *mut core::ffi::c_void, pcphrasesreturned: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoResult", "GetAlternates", 8usize, |this| ((**(this as *const *const ISpRecoResult_Vtbl)).GetAlternates)(this, ulstartelement, celements, ulrequestcount, ppphrases, pcphrasesreturned), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetAudio(this: *mut core::ffi::c_void, ulstartelement: u32, celements: u32, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoResult", "GetAudio", 9usize, |this| ((**(this as *const *const ISpRecoResult_Vtbl)).GetAudio)(this, ulstartelement, celements, ppstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn SpeakAudio(this: *mut core::ffi::c_void, ulstartelement: u32, celements: u32, dwflags: u32, pulstreamnumber: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoResult", "SpeakAudio", 10usize, |this| ((**(this as *const *const ISpRecoResult_Vtbl)).SpeakAudio)(this, ulstartelement, celements, dwflags, pulstreamnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn Serialize(this: *mut core::ffi::c_void, ppcomemserializedresult: *mut *mut SPSERIALIZEDRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoResult", "Serialize", 11usize, |this| ((**(this as *const *const ISpRecoResult_Vtbl)).Serialize)(this, ppcomemserializedresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn ScaleAudio(this: *mut core::ffi::c_void, paudioformatid: *const windows_core::GUID, pwaveformatex: *const super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoResult", "ScaleAudio", 12usize, |this| ((**(this as *const *const ISpRecoResult_Vtbl)).ScaleAudio)(this, paudioformatid, pwaveformatex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecoContext(this: *mut core::ffi::c_void, pprecocontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoResult", "GetRecoContext", 13usize, |this| ((**(this as *const *const ISpRecoResult_Vtbl)).GetRecoContext)(this, pprecocontext), |result| Some(*result))
+        }
+        Self {
+            base__: <ISpPhrase_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetResultTimes,
+            GetAlternates,
+            #[cfg(feature = "Win32_System_Com")]
+            GetAudio,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetAudio: 0,
+            SpeakAudio,
+            Serialize,
+            #[cfg(feature = "Win32_Media_Audio")]
+            ScaleAudio,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            ScaleAudio: 0,
+            GetRecoContext,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpRecoResult as windows_core::Interface>::IID || <ISpPhrase_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ISpRecoResult2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CommitAlternate(this: *mut core::ffi::c_void, pphrasealt: *mut core::ffi::c_void, ppnewresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoResult2", "CommitAlternate", 14usize, |this| ((**(this as *const *const ISpRecoResult2_Vtbl)).CommitAlternate)(this, pphrasealt, ppnewresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommitText(this: *mut core::ffi::c_void, ulstartelement: u32, celements: u32, pszcorrecteddata: windows_core::PCWSTR, ecommitflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoResult2", "CommitText", 15usize, |this| ((**(this as *const *const ISpRecoResult2_Vtbl)).CommitText)(this, ulstartelement, celements, pszcorrecteddata, ecommitflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTextFeedback(this: *mut core::ffi::c_void, pszfeedback: windows_core::PCWSTR, fsuccessful: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecoResult2", "SetTextFeedback", 16usize, |this| ((**(this as *const *const ISpRecoResult2_Vtbl)).SetTextFeedback)(this, pszfeedback, fsuccessful), |result| Some(*result))
+        }
+        Self { base__: <ISpRecoResult_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CommitAlternate, CommitText, SetTextFeedback }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpRecoResult2 as windows_core::Interface>::IID || <ISpRecoResult_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ISpRecognizer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetRecognizer(this: *mut core::ffi::c_void, precognizer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecognizer", "SetRecognizer", 7usize, |this| ((**(this as *const *const ISpRecognizer_Vtbl)).SetRecognizer)(this, precognizer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecognizer(this: *mut core::ffi::c_void, pprecognizer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecognizer", "GetRecognizer", 8usize, |this| ((**(this as *const *const ISpRecognizer_Vtbl)).GetRecognizer)(this, pprecognizer), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInput(this: *mut core::ffi::c_void, punkinput: *mut core::ffi::c_void, fallowformatchanges: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecognizer", "SetInput", 9usize, |this| ((**(this as *const *const ISpRecognizer_Vtbl)).SetInput)(this, punkinput, fallowformatchanges), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputObjectToken(this: *mut core::ffi::c_void, pptoken: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecognizer", "GetInputObjectToken", 10usize, |this| ((**(this as *const *const ISpRecognizer_Vtbl)).GetInputObjectToken)(this, pptoken), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetInputStream(this: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecognizer", "GetInputStream", 11usize, |this| ((**(this as *const *const ISpRecognizer_Vtbl)).GetInputStream)(this, ppstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRecoContext(this: *mut core::ffi::c_void, ppnewctxt: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecognizer", "CreateRecoContext", 12usize, |this| ((**(this as *const *const ISpRecognizer_Vtbl)).CreateRecoContext)(this, ppnewctxt), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecoProfile(this: *mut core::ffi::c_void, pptoken: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecognizer", "GetRecoProfile", 13usize, |this| ((**(this as *const *const ISpRecognizer_Vtbl)).GetRecoProfile)(this, pptoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRecoProfile(this: *mut core::ffi::c_void, ptoken: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecognizer", "SetRecoProfile", 14usize, |this| ((**(this as *const *const ISpRecognizer_Vtbl)).SetRecoProfile)(this, ptoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSharedInstance(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecognizer", "IsSharedInstance", 15usize, |this| ((**(this as *const *const ISpRecognizer_Vtbl)).IsSharedInstance)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecoState(this: *mut core::ffi::c_void, pstate: *mut SPRECOSTATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecognizer", "GetRecoState", 16usize, |this| ((**(this as *const *const ISpRecognizer_Vtbl)).GetRecoState)(this, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRecoState(this: *mut core::ffi::c_void, newstate: SPRECOSTATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecognizer", "SetRecoState", 17usize, |this| ((**(this as *const *const ISpRecognizer_Vtbl)).SetRecoState)(this, newstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pstatus: *mut SPRECOGNIZERSTATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecognizer", "GetStatus", 18usize, |this| ((**(this as *const *const ISpRecognizer_Vtbl)).GetStatus)(this, pstatus), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn GetFormat(this: *mut core::ffi::c_void, waveformattype: SPSTREAMFORMATTYPE, pformatid: *mut windows_core::GUID, ppcomemwfex: *mut *mut super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecognizer", "GetFormat", 19usize, |this| ((**(this as *const *const ISpRecognizer_Vtbl)).GetFormat)(this, waveformattype, pformatid, ppcomemwfex), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUISupported(this: *mut core::ffi::c_void, psztypeofui: windows_core::PCWSTR, pvextradata: *mut core::ffi::c_void, cbextradata: u32, pfsupported: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecognizer", "IsUISupported", 20usize, |this| ((**(this as *const *const ISpRecognizer_Vtbl)).IsUISupported)(this, psztypeofui, pvextradata, cbextradata, pfsupported), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayUI(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, psztitle: windows_core::PCWSTR, psztypeofui: windows_core::PCWSTR, pvextradata: *mut core::ffi::c_void, cbextradata: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecognizer", "DisplayUI", 21usize, |this| ((**(this as *const *const ISpRecognizer_Vtbl)).DisplayUI)(this, hwndparent, psztitle, psztypeofui, pvextradata, cbextradata), |result| Some(*result))
+        }
+        unsafe extern "system" fn EmulateRecognition(this: *mut core::ffi::c_void, pphrase: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecognizer", "EmulateRecognition", 22usize, |this| ((**(this as *const *const ISpRecognizer_Vtbl)).EmulateRecognition)(this, pphrase), |result| Some(*result))
+        }
+        Self {
+            base__: <ISpProperties_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetRecognizer,
+            GetRecognizer,
+            SetInput,
+            GetInputObjectToken,
+            #[cfg(feature = "Win32_System_Com")]
+            GetInputStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetInputStream: 0,
+            CreateRecoContext,
+            GetRecoProfile,
+            SetRecoProfile,
+            IsSharedInstance,
+            GetRecoState,
+            SetRecoState,
+            GetStatus,
+            #[cfg(feature = "Win32_Media_Audio")]
+            GetFormat,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            GetFormat: 0,
+            IsUISupported,
+            DisplayUI,
+            EmulateRecognition,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpRecognizer as windows_core::Interface>::IID || <ISpProperties_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpRecognizer2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EmulateRecognitionEx(this: *mut core::ffi::c_void, pphrase: *mut core::ffi::c_void, dwcompareflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecognizer2", "EmulateRecognitionEx", 3usize, |this| ((**(this as *const *const ISpRecognizer2_Vtbl)).EmulateRecognitionEx)(this, pphrase, dwcompareflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTrainingState(this: *mut core::ffi::c_void, fdoingtraining: super::super::Foundation::BOOL, fadaptfromtrainingdata: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecognizer2", "SetTrainingState", 4usize, |this| ((**(this as *const *const ISpRecognizer2_Vtbl)).SetTrainingState)(this, fdoingtraining, fadaptfromtrainingdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetAcousticModelAdaptation(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRecognizer2", "ResetAcousticModelAdaptation", 5usize, |this| ((**(this as *const *const ISpRecognizer2_Vtbl)).ResetAcousticModelAdaptation)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EmulateRecognitionEx,
+            SetTrainingState,
+            ResetAcousticModelAdaptation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpRecognizer2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Registry")]
+impl windows_core::imp::ProxyVtable for ISpRegDataKey_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Registry")]
+        unsafe extern "system" fn SetKey(this: *mut core::ffi::c_void, hkey: super::super::System::Registry::HKEY, freadonly: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpRegDataKey", "SetKey", 15usize, |this| ((**(this as *const *const ISpRegDataKey_Vtbl)).SetKey)(this, hkey, freadonly), |result| Some(*result))
+        }
+        Self {
+            base__: <ISpDataKey_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Registry")]
+            SetKey,
+            #[cfg(not(feature = "Win32_System_Registry"))]
+            SetKey: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpRegDataKey as windows_core::Interface>::IID || <ISpDataKey_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpResourceManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetObject(this: *mut core::ffi::c_void, guidserviceid: *const windows_core::GUID, punkobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpResourceManager", "SetObject", 4usize, |this| ((**(this as *const *const ISpResourceManager_Vtbl)).SetObject)(this, guidserviceid, punkobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObject(this: *mut core::ffi::c_void, guidserviceid: *const windows_core::GUID, objectclsid: *const windows_core::GUID, objectiid: *const windows_core::GUID, freleasewhenlastexternalrefreleased: super::super::Foundation::BOOL, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpResourceManager", "GetObject", 5usize, |this| ((**(this as *const *const ISpResourceManager_Vtbl)).GetObject)(this, guidserviceid, objectclsid, objectiid, freleasewhenlastexternalrefreleased, ppobject), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IServiceProvider_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetObject, GetObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpResourceManager as windows_core::Interface>::IID || <super::super::System::Com::IServiceProvider_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpSRAlternates_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAlternates(this: *mut core::ffi::c_void, paltrequest: *const SPPHRASEALTREQUEST, ppalts: *mut *mut SPPHRASEALT, pcalts: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSRAlternates", "GetAlternates", 3usize, |this| ((**(this as *const *const ISpSRAlternates_Vtbl)).GetAlternates)(this, paltrequest, ppalts, pcalts), |result| Some(*result))
+        }
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void, paltrequest: *const SPPHRASEALTREQUEST, palt: *const SPPHRASEALT, ppvresultextra: *mut *mut core::ffi::c_void, pcbresultextra: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSRAlternates", "Commit", 4usize, |this| ((**(this as *const *const ISpSRAlternates_Vtbl)).Commit)(this, paltrequest, palt, ppvresultextra, pcbresultextra), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAlternates, Commit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpSRAlternates as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpSRAlternates2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CommitText(this: *mut core::ffi::c_void, paltrequest: *const SPPHRASEALTREQUEST, pcsznewtext: windows_core::PCWSTR, commitflags: SPCOMMITFLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSRAlternates2", "CommitText", 5usize, |this| ((**(this as *const *const ISpSRAlternates2_Vtbl)).CommitText)(this, paltrequest, pcsznewtext, commitflags), |result| Some(*result))
+        }
+        Self { base__: <ISpSRAlternates_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CommitText }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpSRAlternates2 as windows_core::Interface>::IID || <ISpSRAlternates_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_Audio")]
+impl windows_core::imp::ProxyVtable for ISpSREngine_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSite(this: *mut core::ffi::c_void, psite: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "SetSite", 3usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).SetSite)(this, psite), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn GetInputAudioFormat(this: *mut core::ffi::c_void, pguidsourceformatid: *const windows_core::GUID, psourcewaveformatex: *const super::Audio::WAVEFORMATEX, pguiddesiredformatid: *mut windows_core::GUID, ppcomemdesiredwaveformatex: *mut *mut super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "GetInputAudioFormat", 4usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).GetInputAudioFormat)(this, pguidsourceformatid, psourcewaveformatex, pguiddesiredformatid, ppcomemdesiredwaveformatex), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn RecognizeStream(this: *mut core::ffi::c_void, rguidfmtid: *const windows_core::GUID, pwaveformatex: *const super::Audio::WAVEFORMATEX, hrequestsync: super::super::Foundation::HANDLE, hdataavailable: super::super::Foundation::HANDLE, hexit: super::super::Foundation::HANDLE, fnewaudiostream: super::super::Foundation::BOOL, frealtimeaudio: super::super::Foundation::BOOL, paudioobjecttoken: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "RecognizeStream", 5usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).RecognizeStream)(this, rguidfmtid, pwaveformatex, hrequestsync, hdataavailable, hexit, fnewaudiostream, frealtimeaudio, paudioobjecttoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRecoProfile(this: *mut core::ffi::c_void, pprofile: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "SetRecoProfile", 6usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).SetRecoProfile)(this, pprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnCreateGrammar(this: *mut core::ffi::c_void, pvenginerecocontext: *const core::ffi::c_void, hsapigrammar: SPGRAMMARHANDLE, ppvenginegrammarcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "OnCreateGrammar", 7usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).OnCreateGrammar)(this, pvenginerecocontext, hsapigrammar, ppvenginegrammarcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDeleteGrammar(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "OnDeleteGrammar", 8usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).OnDeleteGrammar)(this, pvenginegrammar), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadProprietaryGrammar(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void, rguidparam: *const windows_core::GUID, pszstringparam: windows_core::PCWSTR, pvdataparam: *const core::ffi::c_void, uldatasize: u32, options: SPLOADOPTIONS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "LoadProprietaryGrammar", 9usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).LoadProprietaryGrammar)(this, pvenginegrammar, rguidparam, pszstringparam, pvdataparam, uldatasize, options), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnloadProprietaryGrammar(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "UnloadProprietaryGrammar", 10usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).UnloadProprietaryGrammar)(this, pvenginegrammar), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProprietaryRuleState(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void, pszname: windows_core::PCWSTR, preserved: *const core::ffi::c_void, newstate: SPRULESTATE, pcruleschanged: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "SetProprietaryRuleState", 11usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).SetProprietaryRuleState)(this, pvenginegrammar, pszname, preserved, newstate, pcruleschanged), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProprietaryRuleIdState(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void, dwruleid: u32, newstate: SPRULESTATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "SetProprietaryRuleIdState", 12usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).SetProprietaryRuleIdState)(this, pvenginegrammar, dwruleid, newstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadSLM(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void, psztopicname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "LoadSLM", 13usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).LoadSLM)(this, pvenginegrammar, psztopicname), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnloadSLM(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "UnloadSLM", 14usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).UnloadSLM)(this, pvenginegrammar), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSLMState(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void, newstate: SPRULESTATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "SetSLMState", 15usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).SetSLMState)(this, pvenginegrammar, newstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWordSequenceData(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void, ptext: windows_core::PCWSTR, cchtext: u32, pinfo: *const SPTEXTSELECTIONINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "SetWordSequenceData", 16usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).SetWordSequenceData)(this, pvenginegrammar, ptext, cchtext, pinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTextSelection(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void, pinfo: *const SPTEXTSELECTIONINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "SetTextSelection", 17usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).SetTextSelection)(this, pvenginegrammar, pinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPronounceable(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void, pszword: windows_core::PCWSTR, pwordpronounceable: *mut SPWORDPRONOUNCEABLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "IsPronounceable", 18usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).IsPronounceable)(this, pvenginegrammar, pszword, pwordpronounceable), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnCreateRecoContext(this: *mut core::ffi::c_void, hsapirecocontext: SPRECOCONTEXTHANDLE, ppvenginecontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "OnCreateRecoContext", 19usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).OnCreateRecoContext)(this, hsapirecocontext, ppvenginecontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDeleteRecoContext(this: *mut core::ffi::c_void, pvenginecontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "OnDeleteRecoContext", 20usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).OnDeleteRecoContext)(this, pvenginecontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrivateCall(this: *mut core::ffi::c_void, pvenginecontext: *const core::ffi::c_void, pcallframe: *mut core::ffi::c_void, ulcallframesize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "PrivateCall", 21usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).PrivateCall)(this, pvenginecontext, pcallframe, ulcallframesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAdaptationData(this: *mut core::ffi::c_void, pvenginecontext: *const core::ffi::c_void, padaptationdata: windows_core::PCWSTR, cch: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "SetAdaptationData", 22usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).SetAdaptationData)(this, pvenginecontext, padaptationdata, cch), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPropertyNum(this: *mut core::ffi::c_void, esrc: SPPROPSRC, pvsrcobj: *const core::ffi::c_void, pname: windows_core::PCWSTR, lvalue: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "SetPropertyNum", 23usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).SetPropertyNum)(this, esrc, pvsrcobj, pname, lvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyNum(this: *mut core::ffi::c_void, esrc: SPPROPSRC, pvsrcobj: *const core::ffi::c_void, pname: windows_core::PCWSTR, lvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "GetPropertyNum", 24usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).GetPropertyNum)(this, esrc, pvsrcobj, pname, lvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPropertyString(this: *mut core::ffi::c_void, esrc: SPPROPSRC, pvsrcobj: *const core::ffi::c_void, pname: windows_core::PCWSTR, pvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "SetPropertyString", 25usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).SetPropertyString)(this, esrc, pvsrcobj, pname, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyString(this: *mut core::ffi::c_void, esrc: SPPROPSRC, pvsrcobj: *const core::ffi::c_void, pname: windows_core::PCWSTR, ppcomemvalue: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "GetPropertyString", 26usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).GetPropertyString)(this, esrc, pvsrcobj, pname, ppcomemvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGrammarState(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void, egrammarstate: SPGRAMMARSTATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "SetGrammarState", 27usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).SetGrammarState)(this, pvenginegrammar, egrammarstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn WordNotify(this: *mut core::ffi::c_void, action: SPCFGNOTIFY, cwords: u32, pwords: *const SPWORDENTRY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "WordNotify", 28usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).WordNotify)(this, action, cwords, pwords), |result| Some(*result))
+        }
+        unsafe extern "system" fn RuleNotify(this: *mut core::ffi::c_void, action: SPCFGNOTIFY, crules: u32, prules: *const SPRULEENTRY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "RuleNotify", 29usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).RuleNotify)(this, action, crules, prules), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrivateCallEx(this: *mut core::ffi::c_void, pvenginecontext: *const core::ffi::c_void, pincallframe: *const core::ffi::c_void, ulincallframesize: u32, ppvcomemresponse: *mut *mut core::ffi::c_void, pulresponsesize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "PrivateCallEx", 30usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).PrivateCallEx)(this, pvenginecontext, pincallframe, ulincallframesize, ppvcomemresponse, pulresponsesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContextState(this: *mut core::ffi::c_void, pvenginecontext: *const core::ffi::c_void, econtextstate: SPCONTEXTSTATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine", "SetContextState", 31usize, |this| ((**(this as *const *const ISpSREngine_Vtbl)).SetContextState)(this, pvenginecontext, econtextstate), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetSite,
+            #[cfg(feature = "Win32_Media_Audio")]
+            GetInputAudioFormat,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            GetInputAudioFormat: 0,
+            #[cfg(feature = "Win32_Media_Audio")]
+            RecognizeStream,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            RecognizeStream: 0,
+            SetRecoProfile,
+            OnCreateGrammar,
+            OnDeleteGrammar,
+            LoadProprietaryGrammar,
+            UnloadProprietaryGrammar,
+            SetProprietaryRuleState,
+            SetProprietaryRuleIdState,
+            LoadSLM,
+            UnloadSLM,
+            SetSLMState,
+            SetWordSequenceData,
+            SetTextSelection,
+            IsPronounceable,
+            OnCreateRecoContext,
+            OnDeleteRecoContext,
+            PrivateCall,
+            SetAdaptationData,
+            SetPropertyNum,
+            GetPropertyNum,
+            SetPropertyString,
+            GetPropertyString,
+            SetGrammarState,
+            WordNotify,
+            RuleNotify,
+            PrivateCallEx,
+            SetContextState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpSREngine as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_Audio")]
+impl windows_core::imp::ProxyVtable for ISpSREngine2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrivateCallImmediate(this: *mut core::ffi::c_void, pvenginecontext: *const core::ffi::c_void, pincallframe: *const core::ffi::c_void, ulincallframesize: u32, ppvcomemresponse: *mut *mut core::ffi::c_void, pulresponsesize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine2", "PrivateCallImmediate", 32usize, |this| ((**(this as *const *const ISpSREngine2_Vtbl)).PrivateCallImmediate)(this, pvenginecontext, pincallframe, ulincallframesize, ppvcomemresponse, pulresponsesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAdaptationData2(this: *mut core::ffi::c_void, pvenginecontext: *const core::ffi::c_void, padaptationdata: windows_core::PCWSTR, cch: u32, ptopicname: windows_core::PCWSTR, esettings: SPADAPTATIONSETTINGS, erelevance: SPADAPTATIONRELEVANCE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine2", "SetAdaptationData2", 33usize, |this| ((**(this as *const *const ISpSREngine2_Vtbl)).SetAdaptationData2)(this, pvenginecontext, padaptationdata, cch, ptopicname, esettings, erelevance), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGrammarPrefix(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void, pszprefix: windows_core::PCWSTR, fisprefixrequired: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine2", "SetGrammarPrefix", 34usize, |this| ((**(this as *const *const ISpSREngine2_Vtbl)).SetGrammarPrefix)(this, pvenginegrammar, pszprefix, fisprefixrequired), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRulePriority(this: *mut core::ffi::c_void, hrule: SPRULEHANDLE, pvclientrulecontext: *const core::ffi::c_void, nrulepriority: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine2", "SetRulePriority", 35usize, |this| ((**(this as *const *const ISpSREngine2_Vtbl)).SetRulePriority)(this, hrule, pvclientrulecontext, nrulepriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn EmulateRecognition(this: *mut core::ffi::c_void, pphrase: *mut core::ffi::c_void, dwcompareflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine2", "EmulateRecognition", 36usize, |this| ((**(this as *const *const ISpSREngine2_Vtbl)).EmulateRecognition)(this, pphrase, dwcompareflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSLMWeight(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void, flweight: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine2", "SetSLMWeight", 37usize, |this| ((**(this as *const *const ISpSREngine2_Vtbl)).SetSLMWeight)(this, pvenginegrammar, flweight), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRuleWeight(this: *mut core::ffi::c_void, hrule: SPRULEHANDLE, pvclientrulecontext: *const core::ffi::c_void, flweight: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine2", "SetRuleWeight", 38usize, |this| ((**(this as *const *const ISpSREngine2_Vtbl)).SetRuleWeight)(this, hrule, pvclientrulecontext, flweight), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTrainingState(this: *mut core::ffi::c_void, fdoingtraining: super::super::Foundation::BOOL, fadaptfromtrainingdata: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine2", "SetTrainingState", 39usize, |this| ((**(this as *const *const ISpSREngine2_Vtbl)).SetTrainingState)(this, fdoingtraining, fadaptfromtrainingdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetAcousticModelAdaptation(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine2", "ResetAcousticModelAdaptation", 40usize, |this| ((**(this as *const *const ISpSREngine2_Vtbl)).ResetAcousticModelAdaptation)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnLoadCFG(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void, pgrammardata: *const SPBINARYGRAMMAR, ulgrammarid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine2", "OnLoadCFG", 41usize, |this| ((**(this as *const *const ISpSREngine2_Vtbl)).OnLoadCFG)(this, pvenginegrammar, pgrammardata, ulgrammarid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnUnloadCFG(this: *mut core::ffi::c_void, pvenginegrammar: *const core::ffi::c_void, ulgrammarid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngine2", "OnUnloadCFG", 42usize, |this| ((**(this as *const *const ISpSREngine2_Vtbl)).OnUnloadCFG)(this, pvenginegrammar, ulgrammarid), |result| Some(*result))
+        }
+        Self {
+            base__: <ISpSREngine_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PrivateCallImmediate,
+            SetAdaptationData2,
+            SetGrammarPrefix,
+            SetRulePriority,
+            EmulateRecognition,
+            SetSLMWeight,
+            SetRuleWeight,
+            SetTrainingState,
+            ResetAcousticModelAdaptation,
+            OnLoadCFG,
+            OnUnloadCFG,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpSREngine2 as windows_core::Interface>::IID || <ISpSREngine_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpSREngineSite_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Read(this: *mut core::ffi::c_void, pv: *const core::ffi::c_void, cb: u32, pcbread: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngineSite", "Read", 3usize, |this| ((**(this as *const *const ISpSREngineSite_Vtbl)).Read)(this, pv, cb, pcbread), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataAvailable(this: *mut core::ffi::c_void, pcb: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngineSite", "DataAvailable", 4usize, |this| ((**(this as *const *const ISpSREngineSite_Vtbl)).DataAvailable)(this, pcb), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBufferNotifySize(this: *mut core::ffi::c_void, cbsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngineSite", "SetBufferNotifySize", 5usize, |this| ((**(this as *const *const ISpSREngineSite_Vtbl)).SetBufferNotifySize)(this, cbsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParseFromTransitions(this: *mut core::ffi::c_void, pparseinfo: *const SPPARSEINFO, ppnewphrase: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngineSite", "ParseFromTransitions", 6usize, |this| ((**(this as *const *const ISpSREngineSite_Vtbl)).ParseFromTransitions)(this, pparseinfo, ppnewphrase), |result| Some(*result))
+        }
+        unsafe extern "system" fn Recognition(this: *mut core::ffi::c_void, presultinfo: *const SPRECORESULTINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngineSite", "Recognition", 7usize, |this| ((**(this as *const *const ISpSREngineSite_Vtbl)).Recognition)(this, presultinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddEvent(this: *mut core::ffi::c_void, pevent: *const SPEVENT, hsapirecocontext: SPRECOCONTEXTHANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngineSite", "AddEvent", 8usize, |this| ((**(this as *const *const ISpSREngineSite_Vtbl)).AddEvent)(this, pevent, hsapirecocontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn Synchronize(this: *mut core::ffi::c_void, ullprocessedthrupos: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngineSite", "Synchronize", 9usize, |this| ((**(this as *const *const ISpSREngineSite_Vtbl)).Synchronize)(this, ullprocessedthrupos), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWordInfo(this: *mut core::ffi::c_void, pwordentry: *mut SPWORDENTRY, options: SPWORDINFOOPT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngineSite", "GetWordInfo", 10usize, |this| ((**(this as *const *const ISpSREngineSite_Vtbl)).GetWordInfo)(this, pwordentry, options), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWordClientContext(this: *mut core::ffi::c_void, hword: SPWORDHANDLE, pvclientcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngineSite", "SetWordClientContext", 11usize, |this| ((**(this as *const *const ISpSREngineSite_Vtbl)).SetWordClientContext)(this, hword, pvclientcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRuleInfo(this: *mut core::ffi::c_void, pruleentry: *mut SPRULEENTRY, options: SPRULEINFOOPT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngineSite", "GetRuleInfo", 12usize, |this| ((**(this as *const *const ISpSREngineSite_Vtbl)).GetRuleInfo)(this, pruleentry, options), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRuleClientContext(this: *mut core::ffi::c_void, hrule: SPRULEHANDLE, pvclientcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngineSite", "SetRuleClientContext", 13usize, |this| ((**(this as *const *const ISpSREngineSite_Vtbl)).SetRuleClientContext)(this, hrule, pvclientcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStateInfo(this: *mut core::ffi::c_void, hstate: SPSTATEHANDLE, pstateinfo: *mut SPSTATEINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngineSite", "GetStateInfo", 14usize, |this| ((**(this as *const *const ISpSREngineSite_Vtbl)).GetStateInfo)(this, hstate, pstateinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResource(this: *mut core::ffi::c_void, hrule: SPRULEHANDLE, pszresourcename: windows_core::PCWSTR, ppcomemresource: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngineSite", "GetResource", 15usize, |this| ((**(this as *const *const ISpSREngineSite_Vtbl)).GetResource)(this, hrule, pszresourcename, ppcomemresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransitionProperty(this: *mut core::ffi::c_void, id: SPTRANSITIONID, ppcomemproperty: *mut *mut SPTRANSITIONPROPERTY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngineSite", "GetTransitionProperty", 16usize, |this| ((**(this as *const *const ISpSREngineSite_Vtbl)).GetTransitionProperty)(this, id, ppcomemproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAlternate(this: *mut core::ffi::c_void, hrule: SPRULEHANDLE, haltrule: SPRULEHANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngineSite", "IsAlternate", 17usize, |this| ((**(this as *const *const ISpSREngineSite_Vtbl)).IsAlternate)(this, hrule, haltrule), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxAlternates(this: *mut core::ffi::c_void, hrule: SPRULEHANDLE, pulnumalts: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngineSite", "GetMaxAlternates", 18usize, |this| ((**(this as *const *const ISpSREngineSite_Vtbl)).GetMaxAlternates)(this, hrule, pulnumalts), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContextMaxAlternates(this: *mut core::ffi::c_void, hcontext: SPRECOCONTEXTHANDLE, pulnumalts: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngineSite", "GetContextMaxAlternates", 19usize, |this| ((**(this as *const *const ISpSREngineSite_Vtbl)).GetContextMaxAlternates)(this, hcontext, pulnumalts), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateRecoPos(this: *mut core::ffi::c_void, ullcurrentrecopos: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngineSite", "UpdateRecoPos", 20usize, |this| ((**(this as *const *const ISpSREngineSite_Vtbl)).UpdateRecoPos)(this, ullcurrentrecopos), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Read,
+            DataAvailable,
+            SetBufferNotifySize,
+            ParseFromTransitions,
+            Recognition,
+            AddEvent,
+            Synchronize,
+            GetWordInfo,
+            SetWordClientContext,
+            GetRuleInfo,
+            SetRuleClientContext,
+            GetStateInfo,
+            GetResource,
+            GetTransitionProperty,
+            IsAlternate,
+            GetMaxAlternates,
+            GetContextMaxAlternates,
+            UpdateRecoPos,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpSREngineSite as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpSREngineSite2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddEventEx(this: *mut core::ffi::c_void, pevent: *const SPEVENTEX, hsapirecocontext: SPRECOCONTEXTHANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngineSite2", "AddEventEx", 21usize, |this| ((**(this as *const *const ISpSREngineSite2_Vtbl)).AddEventEx)(this, pevent, hsapirecocontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateRecoPosEx(this: *mut core::ffi::c_void, ullcurrentrecopos: u64, ullcurrentrecotime: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngineSite2", "UpdateRecoPosEx", 22usize, |this| ((**(this as *const *const ISpSREngineSite2_Vtbl)).UpdateRecoPosEx)(this, ullcurrentrecopos, ullcurrentrecotime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRuleTransition(this: *mut core::ffi::c_void, ulgrammarid: u32, ruleindex: u32, ptrans: *mut SPTRANSITIONENTRY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngineSite2", "GetRuleTransition", 23usize, |this| ((**(this as *const *const ISpSREngineSite2_Vtbl)).GetRuleTransition)(this, ulgrammarid, ruleindex, ptrans), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecognitionEx(this: *mut core::ffi::c_void, presultinfo: *const SPRECORESULTINFOEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSREngineSite2", "RecognitionEx", 24usize, |this| ((**(this as *const *const ISpSREngineSite2_Vtbl)).RecognitionEx)(this, presultinfo), |result| Some(*result))
+        }
+        Self { base__: <ISpSREngineSite_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddEventEx, UpdateRecoPosEx, GetRuleTransition, RecognitionEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpSREngineSite2 as windows_core::Interface>::IID || <ISpSREngineSite_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpSerializeState_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSerializedState(this: *mut core::ffi::c_void, ppbdata: *mut *mut u8, pulsize: *mut u32, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSerializeState", "GetSerializedState", 3usize, |this| ((**(this as *const *const ISpSerializeState_Vtbl)).GetSerializedState)(this, ppbdata, pulsize, dwreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSerializedState(this: *mut core::ffi::c_void, pbdata: *const u8, ulsize: u32, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpSerializeState", "SetSerializedState", 4usize, |this| ((**(this as *const *const ISpSerializeState_Vtbl)).SetSerializedState)(this, pbdata, ulsize, dwreserved), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSerializedState, SetSerializedState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpSerializeState as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpShortcut_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddShortcut(this: *mut core::ffi::c_void, pszdisplay: windows_core::PCWSTR, langid: u16, pszspoken: windows_core::PCWSTR, shtype: SPSHORTCUTTYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpShortcut", "AddShortcut", 3usize, |this| ((**(this as *const *const ISpShortcut_Vtbl)).AddShortcut)(this, pszdisplay, langid, pszspoken, shtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveShortcut(this: *mut core::ffi::c_void, pszdisplay: windows_core::PCWSTR, langid: u16, pszspoken: windows_core::PCWSTR, shtype: SPSHORTCUTTYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpShortcut", "RemoveShortcut", 4usize, |this| ((**(this as *const *const ISpShortcut_Vtbl)).RemoveShortcut)(this, pszdisplay, langid, pszspoken, shtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetShortcuts(this: *mut core::ffi::c_void, langid: u16, pshortcutpairlist: *mut SPSHORTCUTPAIRLIST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpShortcut", "GetShortcuts", 5usize, |this| ((**(this as *const *const ISpShortcut_Vtbl)).GetShortcuts)(this, langid, pshortcutpairlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGeneration(this: *mut core::ffi::c_void, pdwgeneration: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpShortcut", "GetGeneration", 6usize, |this| ((**(this as *const *const ISpShortcut_Vtbl)).GetGeneration)(this, pdwgeneration), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWordsFromGenerationChange(this: *mut core::ffi::c_void, pdwgeneration: *mut u32, pwordlist: *mut SPWORDLIST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpShortcut", "GetWordsFromGenerationChange", 7usize, |this| ((**(this as *const *const ISpShortcut_Vtbl)).GetWordsFromGenerationChange)(this, pdwgeneration, pwordlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWords(this: *mut core::ffi::c_void, pdwgeneration: *mut u32, pdwcookie: *mut u32, pwordlist: *mut SPWORDLIST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpShortcut", "GetWords", 8usize, |this| ((**(this as *const *const ISpShortcut_Vtbl)).GetWords)(this, pdwgeneration, pdwcookie, pwordlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetShortcutsForGeneration(this: *mut core::ffi::c_void, pdwgeneration: *mut u32, pdwcookie: *mut u32, pshortcutpairlist: *mut SPSHORTCUTPAIRLIST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpShortcut", "GetShortcutsForGeneration", 9usize, |this| ((**(this as *const *const ISpShortcut_Vtbl)).GetShortcutsForGeneration)(this, pdwgeneration, pdwcookie, pshortcutpairlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGenerationChange(this: *mut core::ffi::c_void, pdwgeneration: *mut u32, pshortcutpairlist: *mut SPSHORTCUTPAIRLIST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpShortcut", "GetGenerationChange", 10usize, |this| ((**(this as *const *const ISpShortcut_Vtbl)).GetGenerationChange)(this, pdwgeneration, pshortcutpairlist), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddShortcut,
+            RemoveShortcut,
+            GetShortcuts,
+            GetGeneration,
+            GetWordsFromGenerationChange,
+            GetWords,
+            GetShortcutsForGeneration,
+            GetGenerationChange,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpShortcut as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ISpStream_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn SetBaseStream(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, rguidformat: *const windows_core::GUID, pwaveformatex: *const super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpStream", "SetBaseStream", 15usize, |this| ((**(this as *const *const ISpStream_Vtbl)).SetBaseStream)(this, pstream, rguidformat, pwaveformatex), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetBaseStream(this: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpStream", "GetBaseStream", 16usize, |this| ((**(this as *const *const ISpStream_Vtbl)).GetBaseStream)(this, ppstream), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn BindToFile(this: *mut core::ffi::c_void, pszfilename: windows_core::PCWSTR, emode: SPFILEMODE, pformatid: *const windows_core::GUID, pwaveformatex: *const super::Audio::WAVEFORMATEX, ulleventinterest: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpStream", "BindToFile", 17usize, |this| ((**(this as *const *const ISpStream_Vtbl)).BindToFile)(this, pszfilename, emode, pformatid, pwaveformatex, ulleventinterest), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpStream", "Close", 18usize, |this| ((**(this as *const *const ISpStream_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <ISpStreamFormat_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com"))]
+            SetBaseStream,
+            #[cfg(not(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com")))]
+            SetBaseStream: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetBaseStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetBaseStream: 0,
+            #[cfg(feature = "Win32_Media_Audio")]
+            BindToFile,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            BindToFile: 0,
+            Close,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpStream as windows_core::Interface>::IID || <ISpStreamFormat_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ISpStreamFormat_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn GetFormat(this: *mut core::ffi::c_void, pguidformatid: *const windows_core::GUID, ppcomemwaveformatex: *mut *mut super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpStreamFormat", "GetFormat", 14usize, |this| ((**(this as *const *const ISpStreamFormat_Vtbl)).GetFormat)(this, pguidformatid, ppcomemwaveformatex), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IStream_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_Audio")]
+            GetFormat,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            GetFormat: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpStreamFormat as windows_core::Interface>::IID || <super::super::System::Com::IStream_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ISpStreamFormatConverter_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetBaseStream(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, fsetformattobasestreamformat: super::super::Foundation::BOOL, fwritetobasestream: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpStreamFormatConverter", "SetBaseStream", 15usize, |this| ((**(this as *const *const ISpStreamFormatConverter_Vtbl)).SetBaseStream)(this, pstream, fsetformattobasestreamformat, fwritetobasestream), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetBaseStream(this: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpStreamFormatConverter", "GetBaseStream", 16usize, |this| ((**(this as *const *const ISpStreamFormatConverter_Vtbl)).GetBaseStream)(this, ppstream), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn SetFormat(this: *mut core::ffi::c_void, rguidformatidofconvertedstream: *const windows_core::GUID, pwaveformatexofconvertedstream: *const super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpStreamFormatConverter", "SetFormat", 17usize, |this| ((**(this as *const *const ISpStreamFormatConverter_Vtbl)).SetFormat)(this, rguidformatidofconvertedstream, pwaveformatexofconvertedstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetSeekPosition(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpStreamFormatConverter", "ResetSeekPosition", 18usize, |this| ((**(this as *const *const ISpStreamFormatConverter_Vtbl)).ResetSeekPosition)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScaleConvertedToBaseOffset(this: *mut core::ffi::c_void, ulloffsetconvertedstream: u64, pulloffsetbasestream: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpStreamFormatConverter", "ScaleConvertedToBaseOffset", 19usize, |this| ((**(this as *const *const ISpStreamFormatConverter_Vtbl)).ScaleConvertedToBaseOffset)(this, ulloffsetconvertedstream, pulloffsetbasestream), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScaleBaseToConvertedOffset(this: *mut core::ffi::c_void, ulloffsetbasestream: u64, pulloffsetconvertedstream: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpStreamFormatConverter", "ScaleBaseToConvertedOffset", 20usize, |this| ((**(this as *const *const ISpStreamFormatConverter_Vtbl)).ScaleBaseToConvertedOffset)(this, ulloffsetbasestream, pulloffsetconvertedstream), |result| Some(*result))
+        }
+        Self {
+            base__: <ISpStreamFormat_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            SetBaseStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetBaseStream: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetBaseStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetBaseStream: 0,
+            #[cfg(feature = "Win32_Media_Audio")]
+            SetFormat,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            SetFormat: 0,
+            ResetSeekPosition,
+            ScaleConvertedToBaseOffset,
+            ScaleBaseToConvertedOffset,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpStreamFormatConverter as windows_core::Interface>::IID || <ISpStreamFormat_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_Audio")]
+impl windows_core::imp::ProxyVtable for ISpTTSEngine_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn Speak(this: *mut core::ffi::c_void, dwspeakflags: u32, rguidformatid: *const windows_core::GUID, pwaveformatex: *const super::Audio::WAVEFORMATEX, ptextfraglist: *const SPVTEXTFRAG, poutputsite: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpTTSEngine", "Speak", 3usize, |this| ((**(this as *const *const ISpTTSEngine_Vtbl)).Speak)(this, dwspeakflags, rguidformatid, pwaveformatex, ptextfraglist, poutputsite), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn GetOutputFormat(this: *mut core::ffi::c_void, ptargetfmtid: *const windows_core::GUID, ptargetwaveformatex: *const super::Audio::WAVEFORMATEX, poutputformatid: *mut windows_core::GUID, ppcomemoutputwaveformatex: *mut *mut super::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpTTSEngine", "GetOutputFormat", 4usize, |this| ((**(this as *const *const ISpTTSEngine_Vtbl)).GetOutputFormat)(this, ptargetfmtid, ptargetwaveformatex, poutputformatid, ppcomemoutputwaveformatex), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_Audio")]
+            Speak,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            Speak: 0,
+            #[cfg(feature = "Win32_Media_Audio")]
+            GetOutputFormat,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            GetOutputFormat: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpTTSEngine as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpTTSEngineSite_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetActions(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ISpTTSEngineSite", "GetActions", 5usize, |this| ((**(this as *const *const ISpTTSEngineSite_Vtbl)).GetActions)(this), |_| None)
+        }
+        unsafe extern "system" fn Write(this: *mut core::ffi::c_void, pbuff: *const core::ffi::c_void, cb: u32, pcbwritten: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpTTSEngineSite", "Write", 6usize, |this| ((**(this as *const *const ISpTTSEngineSite_Vtbl)).Write)(this, pbuff, cb, pcbwritten), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRate(this: *mut core::ffi::c_void, prateadjust: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpTTSEngineSite", "GetRate", 7usize, |this| ((**(this as *const *const ISpTTSEngineSite_Vtbl)).GetRate)(this, prateadjust), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVolume(this: *mut core::ffi::c_void, pusvolume: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpTTSEngineSite", "GetVolume", 8usize, |this| ((**(this as *const *const ISpTTSEngineSite_Vtbl)).GetVolume)(this, pusvolume), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSkipInfo(this: *mut core::ffi::c_void, petype: *mut SPVSKIPTYPE, plnumitems: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpTTSEngineSite", "GetSkipInfo", 9usize, |this| ((**(this as *const *const ISpTTSEngineSite_Vtbl)).GetSkipInfo)(this, petype, plnumitems), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompleteSkip(this: *mut core::ffi::c_void, ulnumskipped: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpTTSEngineSite", "CompleteSkip", 10usize, |this| ((**(this as *const *const ISpTTSEngineSite_Vtbl)).CompleteSkip)(this, ulnumskipped), |result| Some(*result))
+        }
+        Self { base__: <ISpEventSink_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetActions, Write, GetRate, GetVolume, GetSkipInfo, CompleteSkip }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpTTSEngineSite as windows_core::Interface>::IID || <ISpEventSink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpTaskManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetThreadPoolInfo(this: *mut core::ffi::c_void, ppoolinfo: *const SPTMTHREADINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpTaskManager", "SetThreadPoolInfo", 3usize, |this| ((**(this as *const *const ISpTaskManager_Vtbl)).SetThreadPoolInfo)(this, ppoolinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetThreadPoolInfo(this: *mut core::ffi::c_void, ppoolinfo: *mut SPTMTHREADINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpTaskManager", "GetThreadPoolInfo", 4usize, |this| ((**(this as *const *const ISpTaskManager_Vtbl)).GetThreadPoolInfo)(this, ppoolinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueueTask(this: *mut core::ffi::c_void, ptask: *mut core::ffi::c_void, pvtaskdata: *const core::ffi::c_void, hcompevent: super::super::Foundation::HANDLE, pdwgroupid: *mut u32, ptaskid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpTaskManager", "QueueTask", 5usize, |this| ((**(this as *const *const ISpTaskManager_Vtbl)).QueueTask)(this, ptask, pvtaskdata, hcompevent, pdwgroupid, ptaskid), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateReoccurringTask(this: *mut core::ffi::c_void, ptask: *mut core::ffi::c_void, pvtaskdata: *const core::ffi::c_void, hcompevent: super::super::Foundation::HANDLE, pptaskctrl: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpTaskManager", "CreateReoccurringTask", 6usize, |this| ((**(this as *const *const ISpTaskManager_Vtbl)).CreateReoccurringTask)(this, ptask, pvtaskdata, hcompevent, pptaskctrl), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateThreadControl(this: *mut core::ffi::c_void, ptask: *mut core::ffi::c_void, pvtaskdata: *const core::ffi::c_void, npriority: i32, pptaskctrl: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpTaskManager", "CreateThreadControl", 7usize, |this| ((**(this as *const *const ISpTaskManager_Vtbl)).CreateThreadControl)(this, ptask, pvtaskdata, npriority, pptaskctrl), |result| Some(*result))
+        }
+        unsafe extern "system" fn TerminateTask(this: *mut core::ffi::c_void, dwtaskid: u32, ulwaitperiod: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpTaskManager", "TerminateTask", 8usize, |this| ((**(this as *const *const ISpTaskManager_Vtbl)).TerminateTask)(this, dwtaskid, ulwaitperiod), |result| Some(*result))
+        }
+        unsafe extern "system" fn TerminateTaskGroup(this: *mut core::ffi::c_void, dwgroupid: u32, ulwaitperiod: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpTaskManager", "TerminateTaskGroup", 9usize, |this| ((**(this as *const *const ISpTaskManager_Vtbl)).TerminateTaskGroup)(this, dwgroupid, ulwaitperiod), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetThreadPoolInfo,
+            GetThreadPoolInfo,
+            QueueTask,
+            CreateReoccurringTask,
+            CreateThreadControl,
+            TerminateTask,
+            TerminateTaskGroup,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpTaskManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpThreadControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartThread(this: *mut core::ffi::c_void, dwflags: u32, phwnd: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpThreadControl", "StartThread", 4usize, |this| ((**(this as *const *const ISpThreadControl_Vtbl)).StartThread)(this, dwflags, phwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForThreadDone(this: *mut core::ffi::c_void, fforcestop: super::super::Foundation::BOOL, phrthreadresult: *mut windows_core::HRESULT, mstimeout: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpThreadControl", "WaitForThreadDone", 5usize, |this| ((**(this as *const *const ISpThreadControl_Vtbl)).WaitForThreadDone)(this, fforcestop, phrthreadresult, mstimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn TerminateThread(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpThreadControl", "TerminateThread", 6usize, |this| ((**(this as *const *const ISpThreadControl_Vtbl)).TerminateThread)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ThreadHandle(this: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            windows_core::imp::proxy_call(this, "ISpThreadControl", "ThreadHandle", 7usize, |this| ((**(this as *const *const ISpThreadControl_Vtbl)).ThreadHandle)(this), |_| None)
+        }
+        unsafe extern "system" fn ThreadId(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ISpThreadControl", "ThreadId", 8usize, |this| ((**(this as *const *const ISpThreadControl_Vtbl)).ThreadId)(this), |_| None)
+        }
+        unsafe extern "system" fn NotifyEvent(this: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            windows_core::imp::proxy_call(this, "ISpThreadControl", "NotifyEvent", 9usize, |this| ((**(this as *const *const ISpThreadControl_Vtbl)).NotifyEvent)(this), |_| None)
+        }
+        unsafe extern "system" fn WindowHandle(this: *mut core::ffi::c_void) -> super::super::Foundation::HWND {
+            windows_core::imp::proxy_call(this, "ISpThreadControl", "WindowHandle", 10usize, |this| ((**(this as *const *const ISpThreadControl_Vtbl)).WindowHandle)(this), |_| None)
+        }
+        unsafe extern "system" fn ThreadCompleteEvent(this: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            windows_core::imp::proxy_call(this, "ISpThreadControl", "ThreadCompleteEvent", 11usize, |this| ((**(this as *const *const ISpThreadControl_Vtbl)).ThreadCompleteEvent)(this), |_| None)
+        }
+        unsafe extern "system" fn ExitThreadEvent(this: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            windows_core::imp::proxy_call(this, "ISpThreadControl", "ExitThreadEvent", 12usize, |this| ((**(this as *const *const ISpThreadControl_Vtbl)).ExitThreadEvent)(this), |_| None)
+        }
+        Self {
+            base__: <ISpNotifySink_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartThread,
+            WaitForThreadDone,
+            TerminateThread,
+            ThreadHandle,
+            ThreadId,
+            NotifyEvent,
+            WindowHandle,
+            ThreadCompleteEvent,
+            ExitThreadEvent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpThreadControl as windows_core::Interface>::IID || <ISpNotifySink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpTokenUI_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsUISupported(this: *mut core::ffi::c_void, psztypeofui: windows_core::PCWSTR, pvextradata: *const core::ffi::c_void, cbextradata: u32, punkobject: *mut core::ffi::c_void, pfsupported: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpTokenUI", "IsUISupported", 3usize, |this| ((**(this as *const *const ISpTokenUI_Vtbl)).IsUISupported)(this, psztypeofui, pvextradata, cbextradata, punkobject, pfsupported), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayUI(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, psztitle: windows_core::PCWSTR, psztypeofui: windows_core::PCWSTR, pvextradata: *const core::ffi::c_void, cbextradata: u32, ptoken: *mut core::ffi::c_void, punkobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpTokenUI", "DisplayUI", 4usize, |this| ((**(this as *const *const ISpTokenUI_Vtbl)).DisplayUI)(this, hwndparent, psztitle, psztypeofui, pvextradata, cbextradata, ptoken, punkobject), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsUISupported, DisplayUI }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpTokenUI as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpTranscript_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTranscript(this: *mut core::ffi::c_void, ppsztranscript: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpTranscript", "GetTranscript", 3usize, |this| ((**(this as *const *const ISpTranscript_Vtbl)).GetTranscript)(this, ppsztranscript), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppendTranscript(this: *mut core::ffi::c_void, psztranscript: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpTranscript", "AppendTranscript", 4usize, |this| ((**(this as *const *const ISpTranscript_Vtbl)).AppendTranscript)(this, psztranscript), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetTranscript, AppendTranscript }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpTranscript as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpVoice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetOutput(this: *mut core::ffi::c_void, punkoutput: *mut core::ffi::c_void, fallowformatchanges: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpVoice", "SetOutput", 13usize, |this| ((**(this as *const *const ISpVoice_Vtbl)).SetOutput)(this, punkoutput, fallowformatchanges), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputObjectToken(this: *mut core::ffi::c_void, ppobjecttoken: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpVoice", "GetOutputObjectToken", 14usize, |this| ((**(this as *const *const ISpVoice_Vtbl)).GetOutputObjectToken)(this, ppobjecttoken), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetOutputStream(this: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpVoice", "GetOutputStream", 15usize, |this| ((**(this as *const *const ISpVoice_Vtbl)).GetOutputStream)(this, ppstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpVoice", "Pause", 16usize, |this| ((**(this as *const *const ISpVoice_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpVoice", "Resume", 17usize, |this| ((**(this as *const *const ISpVoice_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVoice(this: *mut core::ffi::c_void, ptoken: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpVoice", "SetVoice", 18usize, |this| ((**(this as *const *const ISpVoice_Vtbl)).SetVoice)(this, ptoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVoice(this: *mut core::ffi::c_void, pptoken: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpVoice", "GetVoice", 19usize, |this| ((**(this as *const *const ISpVoice_Vtbl)).GetVoice)(this, pptoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn Speak(this: *mut core::ffi::c_void, pwcs: windows_core::PCWSTR, dwflags: u32, pulstreamnumber: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpVoice", "Speak", 20usize, |this| ((**(this as *const *const ISpVoice_Vtbl)).Speak)(this, pwcs, dwflags, pulstreamnumber), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SpeakStream(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, dwflags: u32, pulstreamnumber: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpVoice", "SpeakStream", 21usize, |this| ((**(this as *const *const ISpVoice_Vtbl)).SpeakStream)(this, pstream, dwflags, pulstreamnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pstatus: *mut SPVOICESTATUS, ppszlastbookmark: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpVoice", "GetStatus", 22usize, |this| ((**(this as *const *const ISpVoice_Vtbl)).GetStatus)(this, pstatus, ppszlastbookmark), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, pitemtype: windows_core::PCWSTR, lnumitems: i32, pulnumskipped: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpVoice", "Skip", 23usize, |this| ((**(this as *const *const ISpVoice_Vtbl)).Skip)(this, pitemtype, lnumitems, pulnumskipped), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPriority(this: *mut core::ffi::c_void, epriority: SPVPRIORITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpVoice", "SetPriority", 24usize, |this| ((**(this as *const *const ISpVoice_Vtbl)).SetPriority)(this, epriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPriority(this: *mut core::ffi::c_void, pepriority: *mut SPVPRIORITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpVoice", "GetPriority", 25usize, |this| ((**(this as *const *const ISpVoice_Vtbl)).GetPriority)(this, pepriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlertBoundary(this: *mut core::ffi::c_void, eboundary: SPEVENTENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpVoice", "SetAlertBoundary", 26usize, |this| ((**(this as *const *const ISpVoice_Vtbl)).SetAlertBoundary)(this, eboundary), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAlertBoundary(this: *mut core::ffi::c_void, peboundary: *mut SPEVENTENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpVoice", "GetAlertBoundary", 27usize, |this| ((**(this as *const *const ISpVoice_Vtbl)).GetAlertBoundary)(this, peboundary), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRate(this: *mut core::ffi::c_void, rateadjust: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpVoice", "SetRate", 28usize, |this| ((**(this as *const *const ISpVoice_Vtbl)).SetRate)(this, rateadjust), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRate(this: *mut core::ffi::c_void, prateadjust: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpVoice", "GetRate", 29usize, |this| ((**(this as *const *const ISpVoice_Vtbl)).GetRate)(this, prateadjust), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVolume(this: *mut core::ffi::c_void, usvolume: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpVoice", "SetVolume", 30usize, |this| ((**(this as *const *const ISpVoice_Vtbl)).SetVolume)(this, usvolume), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVolume(this: *mut core::ffi::c_void, pusvolume: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpVoice", "GetVolume", 31usize, |this| ((**(this as *const *const ISpVoice_Vtbl)).GetVolume)(this, pusvolume), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitUntilDone(this: *mut core::ffi::c_void, mstimeout: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpVoice", "WaitUntilDone", 32usize, |this| ((**(this as *const *const ISpVoice_Vtbl)).WaitUntilDone)(this, mstimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSyncSpeakTimeout(this: *mut core::ffi::c_void, mstimeout: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpVoice", "SetSyncSpeakTimeout", 33usize, |this| ((**(this as *const *const ISpVoice_Vtbl)).SetSyncSpeakTimeout)(this, mstimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSyncSpeakTimeout(this: *mut core::ffi::c_void, pmstimeout: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpVoice", "GetSyncSpeakTimeout", 34usize, |this| ((**(this as *const *const ISpVoice_Vtbl)).GetSyncSpeakTimeout)(this, pmstimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn SpeakCompleteEvent(this: *mut core::ffi::c_void) -> super::super::Foundation::HANDLE {
+            windows_core::imp::proxy_call(this, "ISpVoice", "SpeakCompleteEvent", 35usize, |this| ((**(this as *const *const ISpVoice_Vtbl)).SpeakCompleteEvent)(this), |_| None)
+        }
+        unsafe extern "system" fn IsUISupported(this: *mut core::ffi::c_void, psztypeofui: windows_core::PCWSTR, pvextradata: *mut core::ffi::c_void, cbextradata: u32, pfsupported: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpVoice", "IsUISupported", 36usize, |this| ((**(this as *const *const ISpVoice_Vtbl)).IsUISupported)(this, psztypeofui, pvextradata, cbextradata, pfsupported), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayUI(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, psztitle: windows_core::PCWSTR, psztypeofui: windows_core::PCWSTR, pvextradata: *mut core::ffi::c_void, cbextradata: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpVoice", "DisplayUI", 37usize, |this| ((**(this as *const *const ISpVoice_Vtbl)).DisplayUI)(this, hwndparent, psztitle, psztypeofui, pvextradata, cbextradata), |result| Some(*result))
+        }
+        Self {
+            base__: <ISpEventSource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetOutput,
+            GetOutputObjectToken,
+            #[cfg(feature = "Win32_System_Com")]
+            GetOutputStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetOutputStream: 0,
+            Pause,
+            Resume,
+            SetVoice,
+            GetVoice,
+            Speak,
+            #[cfg(feature = "Win32_System_Com")]
+            SpeakStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SpeakStream: 0,
+            GetStatus,
+            Skip,
+            SetPriority,
+            GetPriority,
+            SetAlertBoundary,
+            GetAlertBoundary,
+            SetRate,
+            GetRate,
+            SetVolume,
+            GetVolume,
+            WaitUntilDone,
+            SetSyncSpeakTimeout,
+            GetSyncSpeakTimeout,
+            SpeakCompleteEvent,
+            IsUISupported,
+            DisplayUI,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpVoice as windows_core::Interface>::IID || <ISpEventSource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Media_Audio", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ISpXMLRecoResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetXMLResult(this: *mut core::ffi::c_void, ppszcomemxmlresult: *mut windows_core::PWSTR, options: SPXMLRESULTOPTIONS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpXMLRecoResult", "GetXMLResult", 14usize, |this| ((**(this as *const *const ISpXMLRecoResult_Vtbl)).GetXMLResult)(this, ppszcomemxmlresult, options), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetXMLErrorInfo(this: *mut core::ffi::c_void, psemanticerrorinfo: *mut SPSEMANTICERRORINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpXMLRecoResult", "GetXMLErrorInfo", 15usize, |this| ((**(this as *const *const ISpXMLRecoResult_Vtbl)).GetXMLErrorInfo)(this, psemanticerrorinfo), |result| Some(*result))
+        }
+        Self { base__: <ISpRecoResult_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetXMLResult, GetXMLErrorInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpXMLRecoResult as windows_core::Interface>::IID || <ISpRecoResult_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechAudio_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, status: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechAudio", "Status", 12usize, |this| ((**(this as *const *const ISpeechAudio_Vtbl)).Status)(this, status), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn BufferInfo(this: *mut core::ffi::c_void, bufferinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechAudio", "BufferInfo", 13usize, |this| ((**(this as *const *const ISpeechAudio_Vtbl)).BufferInfo)(this, bufferinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DefaultFormat(this: *mut core::ffi::c_void, streamformat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechAudio", "DefaultFormat", 14usize, |this| ((**(this as *const *const ISpeechAudio_Vtbl)).DefaultFormat)(this, streamformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn Volume(this: *mut core::ffi::c_void, volume: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechAudio", "Volume", 15usize, |this| ((**(this as *const *const ISpeechAudio_Vtbl)).Volume)(this, volume), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVolume(this: *mut core::ffi::c_void, volume: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechAudio", "SetVolume", 16usize, |this| ((**(this as *const *const ISpeechAudio_Vtbl)).SetVolume)(this, volume), |result| Some(*result))
+        }
+        unsafe extern "system" fn BufferNotifySize(this: *mut core::ffi::c_void, buffernotifysize: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechAudio", "BufferNotifySize", 17usize, |this| ((**(this as *const *const ISpeechAudio_Vtbl)).BufferNotifySize)(this, buffernotifysize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBufferNotifySize(this: *mut core::ffi::c_void, buffernotifysize: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechAudio", "SetBufferNotifySize", 18usize, |this| ((**(this as *const *const ISpeechAudio_Vtbl)).SetBufferNotifySize)(this, buffernotifysize), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventHandle(this: *mut core::ffi::c_void, eventhandle: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechAudio", "EventHandle", 19usize, |this| ((**(this as *const *const ISpeechAudio_Vtbl)).EventHandle)(this, eventhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetState(this: *mut core::ffi::c_void, state: SpeechAudioState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechAudio", "SetState", 20usize, |this| ((**(this as *const *const ISpeechAudio_Vtbl)).SetState)(this, state), |result| Some(*result))
+        }
+        Self {
+            base__: <ISpeechBaseStream_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Status,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Status: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            BufferInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            BufferInfo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            DefaultFormat,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DefaultFormat: 0,
+            Volume,
+            SetVolume,
+            BufferNotifySize,
+            SetBufferNotifySize,
+            EventHandle,
+            SetState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechAudio as windows_core::Interface>::IID || <ISpeechBaseStream_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechAudioBufferInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MinNotification(this: *mut core::ffi::c_void, minnotification: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechAudioBufferInfo", "MinNotification", 7usize, |this| ((**(this as *const *const ISpeechAudioBufferInfo_Vtbl)).MinNotification)(this, minnotification), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinNotification(this: *mut core::ffi::c_void, minnotification: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechAudioBufferInfo", "SetMinNotification", 8usize, |this| ((**(this as *const *const ISpeechAudioBufferInfo_Vtbl)).SetMinNotification)(this, minnotification), |result| Some(*result))
+        }
+        unsafe extern "system" fn BufferSize(this: *mut core::ffi::c_void, buffersize: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechAudioBufferInfo", "BufferSize", 9usize, |this| ((**(this as *const *const ISpeechAudioBufferInfo_Vtbl)).BufferSize)(this, buffersize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBufferSize(this: *mut core::ffi::c_void, buffersize: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechAudioBufferInfo", "SetBufferSize", 10usize, |this| ((**(this as *const *const ISpeechAudioBufferInfo_Vtbl)).SetBufferSize)(this, buffersize), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventBias(this: *mut core::ffi::c_void, eventbias: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechAudioBufferInfo", "EventBias", 11usize, |this| ((**(this as *const *const ISpeechAudioBufferInfo_Vtbl)).EventBias)(this, eventbias), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventBias(this: *mut core::ffi::c_void, eventbias: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechAudioBufferInfo", "SetEventBias", 12usize, |this| ((**(this as *const *const ISpeechAudioBufferInfo_Vtbl)).SetEventBias)(this, eventbias), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MinNotification,
+            SetMinNotification,
+            BufferSize,
+            SetBufferSize,
+            EventBias,
+            SetEventBias,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechAudioBufferInfo as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechAudioFormat_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, audioformat: *mut SpeechAudioFormatType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechAudioFormat", "Type", 7usize, |this| ((**(this as *const *const ISpeechAudioFormat_Vtbl)).Type)(this, audioformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetType(this: *mut core::ffi::c_void, audioformat: SpeechAudioFormatType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechAudioFormat", "SetType", 8usize, |this| ((**(this as *const *const ISpeechAudioFormat_Vtbl)).SetType)(this, audioformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn Guid(this: *mut core::ffi::c_void, guid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechAudioFormat", "Guid", 9usize, |this| ((**(this as *const *const ISpeechAudioFormat_Vtbl)).Guid)(this, guid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGuid(this: *mut core::ffi::c_void, guid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechAudioFormat", "SetGuid", 10usize, |this| ((**(this as *const *const ISpeechAudioFormat_Vtbl)).SetGuid)(this, guid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetWaveFormatEx(this: *mut core::ffi::c_void, speechwaveformatex: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechAudioFormat", "GetWaveFormatEx", 11usize, |this| ((**(this as *const *const ISpeechAudioFormat_Vtbl)).GetWaveFormatEx)(this, speechwaveformatex), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetWaveFormatEx(this: *mut core::ffi::c_void, speechwaveformatex: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechAudioFormat", "SetWaveFormatEx", 12usize, |this| ((**(this as *const *const ISpeechAudioFormat_Vtbl)).SetWaveFormatEx)(this, speechwaveformatex), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Type,
+            SetType,
+            Guid,
+            SetGuid,
+            #[cfg(feature = "Win32_System_Com")]
+            GetWaveFormatEx,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetWaveFormatEx: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetWaveFormatEx,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetWaveFormatEx: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechAudioFormat as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechAudioStatus_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FreeBufferSpace(this: *mut core::ffi::c_void, freebufferspace: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechAudioStatus", "FreeBufferSpace", 7usize, |this| ((**(this as *const *const ISpeechAudioStatus_Vtbl)).FreeBufferSpace)(this, freebufferspace), |result| Some(*result))
+        }
+        unsafe extern "system" fn NonBlockingIO(this: *mut core::ffi::c_void, nonblockingio: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechAudioStatus", "NonBlockingIO", 8usize, |this| ((**(this as *const *const ISpeechAudioStatus_Vtbl)).NonBlockingIO)(this, nonblockingio), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, state: *mut SpeechAudioState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechAudioStatus", "State", 9usize, |this| ((**(this as *const *const ISpeechAudioStatus_Vtbl)).State)(this, state), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentSeekPosition(this: *mut core::ffi::c_void, currentseekposition: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechAudioStatus", "CurrentSeekPosition", 10usize, |this| ((**(this as *const *const ISpeechAudioStatus_Vtbl)).CurrentSeekPosition)(this, currentseekposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentDevicePosition(this: *mut core::ffi::c_void, currentdeviceposition: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechAudioStatus", "CurrentDevicePosition", 11usize, |this| ((**(this as *const *const ISpeechAudioStatus_Vtbl)).CurrentDevicePosition)(this, currentdeviceposition), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FreeBufferSpace,
+            NonBlockingIO,
+            State,
+            CurrentSeekPosition,
+            CurrentDevicePosition,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechAudioStatus as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechBaseStream_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Format(this: *mut core::ffi::c_void, audioformat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechBaseStream", "Format", 7usize, |this| ((**(this as *const *const ISpeechBaseStream_Vtbl)).Format)(this, audioformat), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_Format(this: *mut core::ffi::c_void, audioformat: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechBaseStream", "putref_Format", 8usize, |this| ((**(this as *const *const ISpeechBaseStream_Vtbl)).putref_Format)(this, audioformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn Read(this: *mut core::ffi::c_void, buffer: *mut core::mem::MaybeUninit<windows_core::VARIANT>, numberofbytes: i32, bytesread: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechBaseStream", "Read", 9usize, |this| ((**(this as *const *const ISpeechBaseStream_Vtbl)).Read)(this, buffer, numberofbytes, bytesread), |result| Some(*result))
+        }
+        unsafe extern "system" fn Write(this: *mut core::ffi::c_void, buffer: core::mem::MaybeUninit<windows_core::VARIANT>, byteswritten: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechBaseStream", "Write", 10usize, |this| ((**(this as *const *const ISpeechBaseStream_Vtbl)).Write)(this, buffer, byteswritten), |result| Some(*result))
+        }
+        unsafe extern "system" fn Seek(this: *mut core::ffi::c_void, position: core::mem::MaybeUninit<windows_core::VARIANT>, origin: SpeechStreamSeekPositionType, newposition: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechBaseStream", "Seek", 11usize, |this| ((**(this as *const *const ISpeechBaseStream_Vtbl)).Seek)(this, position, origin, newposition), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Format,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Format: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_Format,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_Format: 0,
+            Read,
+            Write,
+            Seek,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechBaseStream as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechCustomStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BaseStream(this: *mut core::ffi::c_void, ppunkstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechCustomStream", "BaseStream", 12usize, |this| ((**(this as *const *const ISpeechCustomStream_Vtbl)).BaseStream)(this, ppunkstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn putref_BaseStream(this: *mut core::ffi::c_void, punkstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechCustomStream", "putref_BaseStream", 13usize, |this| ((**(this as *const *const ISpeechCustomStream_Vtbl)).putref_BaseStream)(this, punkstream), |result| Some(*result))
+        }
+        Self { base__: <ISpeechBaseStream_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BaseStream, putref_BaseStream }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechCustomStream as windows_core::Interface>::IID || <ISpeechBaseStream_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechDataKey_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetBinaryValue(this: *mut core::ffi::c_void, valuename: core::mem::MaybeUninit<windows_core::BSTR>, value: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechDataKey", "SetBinaryValue", 7usize, |this| ((**(this as *const *const ISpeechDataKey_Vtbl)).SetBinaryValue)(this, valuename, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBinaryValue(this: *mut core::ffi::c_void, valuename: core::mem::MaybeUninit<windows_core::BSTR>, value: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechDataKey", "GetBinaryValue", 8usize, |this| ((**(this as *const *const ISpeechDataKey_Vtbl)).GetBinaryValue)(this, valuename, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStringValue(this: *mut core::ffi::c_void, valuename: core::mem::MaybeUninit<windows_core::BSTR>, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechDataKey", "SetStringValue", 9usize, |this| ((**(this as *const *const ISpeechDataKey_Vtbl)).SetStringValue)(this, valuename, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStringValue(this: *mut core::ffi::c_void, valuename: core::mem::MaybeUninit<windows_core::BSTR>, value: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechDataKey", "GetStringValue", 10usize, |this| ((**(this as *const *const ISpeechDataKey_Vtbl)).GetStringValue)(this, valuename, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLongValue(this: *mut core::ffi::c_void, valuename: core::mem::MaybeUninit<windows_core::BSTR>, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechDataKey", "SetLongValue", 11usize, |this| ((**(this as *const *const ISpeechDataKey_Vtbl)).SetLongValue)(this, valuename, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLongValue(this: *mut core::ffi::c_void, valuename: core::mem::MaybeUninit<windows_core::BSTR>, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechDataKey", "GetLongValue", 12usize, |this| ((**(this as *const *const ISpeechDataKey_Vtbl)).GetLongValue)(this, valuename, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OpenKey(this: *mut core::ffi::c_void, subkeyname: core::mem::MaybeUninit<windows_core::BSTR>, subkey: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechDataKey", "OpenKey", 13usize, |this| ((**(this as *const *const ISpeechDataKey_Vtbl)).OpenKey)(this, subkeyname, subkey), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateKey(this: *mut core::ffi::c_void, subkeyname: core::mem::MaybeUninit<windows_core::BSTR>, subkey: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechDataKey", "CreateKey", 14usize, |this| ((**(this as *const *const ISpeechDataKey_Vtbl)).CreateKey)(this, subkeyname, subkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteKey(this: *mut core::ffi::c_void, subkeyname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechDataKey", "DeleteKey", 15usize, |this| ((**(this as *const *const ISpeechDataKey_Vtbl)).DeleteKey)(this, subkeyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteValue(this: *mut core::ffi::c_void, valuename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechDataKey", "DeleteValue", 16usize, |this| ((**(this as *const *const ISpeechDataKey_Vtbl)).DeleteValue)(this, valuename), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumKeys(this: *mut core::ffi::c_void, index: i32, subkeyname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechDataKey", "EnumKeys", 17usize, |this| ((**(this as *const *const ISpeechDataKey_Vtbl)).EnumKeys)(this, index, subkeyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumValues(this: *mut core::ffi::c_void, index: i32, valuename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechDataKey", "EnumValues", 18usize, |this| ((**(this as *const *const ISpeechDataKey_Vtbl)).EnumValues)(this, index, valuename), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetBinaryValue,
+            GetBinaryValue,
+            SetStringValue,
+            GetStringValue,
+            SetLongValue,
+            GetLongValue,
+            #[cfg(feature = "Win32_System_Com")]
+            OpenKey,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OpenKey: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateKey,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateKey: 0,
+            DeleteKey,
+            DeleteValue,
+            EnumKeys,
+            EnumValues,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechDataKey as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechFileStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, filename: core::mem::MaybeUninit<windows_core::BSTR>, filemode: SpeechStreamFileMode, doevents: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechFileStream", "Open", 12usize, |this| ((**(this as *const *const ISpeechFileStream_Vtbl)).Open)(this, filename, filemode, doevents), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechFileStream", "Close", 13usize, |this| ((**(this as *const *const ISpeechFileStream_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self { base__: <ISpeechBaseStream_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Open, Close }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechFileStream as windows_core::Interface>::IID || <ISpeechBaseStream_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechGrammarRule_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Attributes(this: *mut core::ffi::c_void, attributes: *mut SpeechRuleAttributes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRule", "Attributes", 7usize, |this| ((**(this as *const *const ISpeechGrammarRule_Vtbl)).Attributes)(this, attributes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitialState(this: *mut core::ffi::c_void, state: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRule", "InitialState", 8usize, |this| ((**(this as *const *const ISpeechGrammarRule_Vtbl)).InitialState)(this, state), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRule", "Name", 9usize, |this| ((**(this as *const *const ISpeechGrammarRule_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, id: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRule", "Id", 10usize, |this| ((**(this as *const *const ISpeechGrammarRule_Vtbl)).Id)(this, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRule", "Clear", 11usize, |this| ((**(this as *const *const ISpeechGrammarRule_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddResource(this: *mut core::ffi::c_void, resourcename: core::mem::MaybeUninit<windows_core::BSTR>, resourcevalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRule", "AddResource", 12usize, |this| ((**(this as *const *const ISpeechGrammarRule_Vtbl)).AddResource)(this, resourcename, resourcevalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddState(this: *mut core::ffi::c_void, state: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRule", "AddState", 13usize, |this| ((**(this as *const *const ISpeechGrammarRule_Vtbl)).AddState)(this, state), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Attributes,
+            #[cfg(feature = "Win32_System_Com")]
+            InitialState,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitialState: 0,
+            Name,
+            Id,
+            Clear,
+            AddResource,
+            #[cfg(feature = "Win32_System_Com")]
+            AddState,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddState: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechGrammarRule as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechGrammarRuleState_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Rule(this: *mut core::ffi::c_void, rule: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRuleState", "Rule", 7usize, |this| ((**(this as *const *const ISpeechGrammarRuleState_Vtbl)).Rule)(this, rule), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Transitions(this: *mut core::ffi::c_void, transitions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRuleState", "Transitions", 8usize, |this| ((**(this as *const *const ISpeechGrammarRuleState_Vtbl)).Transitions)(this, transitions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddWordTransition(this: *mut core::ffi::c_void, deststate: *mut core::ffi::c_void, words: core::mem::MaybeUninit<windows_core::BSTR>, separators: core::mem::MaybeUninit<windows_core::BSTR>, r#type: SpeechGrammarWordType, propertyname: core::mem::MaybeUninit<windows_core::BSTR>, propertyid: i32, propertyvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>, weight: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRuleState", "AddWordTransition", 9usize, |this| ((**(this as *const *const ISpeechGrammarRuleState_Vtbl)).AddWordTransition)(this, deststate, words, separators, r#type, propertyname, propertyid, propertyvalue, weight), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddRuleTransition(this: *mut core::ffi::c_void, destinationstate: *mut core::ffi::c_void, rule: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::BSTR>, propertyid: i32, propertyvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>, weight: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRuleState", "AddRuleTransition", 10usize, |this| ((**(this as *const *const ISpeechGrammarRuleState_Vtbl)).AddRuleTransition)(this, destinationstate, rule, propertyname, propertyid, propertyvalue, weight), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddSpecialTransition(this: *mut core::ffi::c_void, destinationstate: *mut core::ffi::c_void, r#type: SpeechSpecialTransitionType, propertyname: core::mem::MaybeUninit<windows_core::BSTR>, propertyid: i32, propertyvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>, weight: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRuleState", "AddSpecialTransition", 11usize, |this| ((**(this as *const *const ISpeechGrammarRuleState_Vtbl)).AddSpecialTransition)(this, destinationstate, r#type, propertyname, propertyid, propertyvalue, weight), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Rule,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Rule: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Transitions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Transitions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AddWordTransition,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddWordTransition: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AddRuleTransition,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddRuleTransition: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AddSpecialTransition,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddSpecialTransition: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechGrammarRuleState as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechGrammarRuleStateTransition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, r#type: *mut SpeechGrammarRuleStateTransitionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRuleStateTransition", "Type", 7usize, |this| ((**(this as *const *const ISpeechGrammarRuleStateTransition_Vtbl)).Type)(this, r#type), |result| Some(*result))
+        }
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, text: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRuleStateTransition", "Text", 8usize, |this| ((**(this as *const *const ISpeechGrammarRuleStateTransition_Vtbl)).Text)(this, text), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Rule(this: *mut core::ffi::c_void, rule: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRuleStateTransition", "Rule", 9usize, |this| ((**(this as *const *const ISpeechGrammarRuleStateTransition_Vtbl)).Rule)(this, rule), |result| Some(*result))
+        }
+        unsafe extern "system" fn Weight(this: *mut core::ffi::c_void, weight: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRuleStateTransition", "Weight", 10usize, |this| ((**(this as *const *const ISpeechGrammarRuleStateTransition_Vtbl)).Weight)(this, weight), |result| Some(*result))
+        }
+        unsafe extern "system" fn PropertyName(this: *mut core::ffi::c_void, propertyname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRuleStateTransition", "PropertyName", 11usize, |this| ((**(this as *const *const ISpeechGrammarRuleStateTransition_Vtbl)).PropertyName)(this, propertyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn PropertyId(this: *mut core::ffi::c_void, propertyid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRuleStateTransition", "PropertyId", 12usize, |this| ((**(this as *const *const ISpeechGrammarRuleStateTransition_Vtbl)).PropertyId)(this, propertyid), |result| Some(*result))
+        }
+        unsafe extern "system" fn PropertyValue(this: *mut core::ffi::c_void, propertyvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRuleStateTransition", "PropertyValue", 13usize, |this| ((**(this as *const *const ISpeechGrammarRuleStateTransition_Vtbl)).PropertyValue)(this, propertyvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn NextState(this: *mut core::ffi::c_void, nextstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRuleStateTransition", "NextState", 14usize, |this| ((**(this as *const *const ISpeechGrammarRuleStateTransition_Vtbl)).NextState)(this, nextstate), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Type,
+            Text,
+            #[cfg(feature = "Win32_System_Com")]
+            Rule,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Rule: 0,
+            Weight,
+            PropertyName,
+            PropertyId,
+            PropertyValue,
+            #[cfg(feature = "Win32_System_Com")]
+            NextState,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            NextState: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechGrammarRuleStateTransition as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechGrammarRuleStateTransitions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRuleStateTransitions", "Count", 7usize, |this| ((**(this as *const *const ISpeechGrammarRuleStateTransitions_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: i32, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRuleStateTransitions", "Item", 8usize, |this| ((**(this as *const *const ISpeechGrammarRuleStateTransitions_Vtbl)).Item)(this, index, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, enumvariant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRuleStateTransitions", "_NewEnum", 9usize, |this| ((**(this as *const *const ISpeechGrammarRuleStateTransitions_Vtbl))._NewEnum)(this, enumvariant), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechGrammarRuleStateTransitions as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechGrammarRules_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRules", "Count", 7usize, |this| ((**(this as *const *const ISpeechGrammarRules_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn FindRule(this: *mut core::ffi::c_void, rulenameorid: core::mem::MaybeUninit<windows_core::VARIANT>, rule: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRules", "FindRule", 8usize, |this| ((**(this as *const *const ISpeechGrammarRules_Vtbl)).FindRule)(this, rulenameorid, rule), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: i32, rule: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRules", "Item", 9usize, |this| ((**(this as *const *const ISpeechGrammarRules_Vtbl)).Item)(this, index, rule), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, enumvariant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRules", "_NewEnum", 10usize, |this| ((**(this as *const *const ISpeechGrammarRules_Vtbl))._NewEnum)(this, enumvariant), |result| Some(*result))
+        }
+        unsafe extern "system" fn Dynamic(this: *mut core::ffi::c_void, dynamic: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRules", "Dynamic", 11usize, |this| ((**(this as *const *const ISpeechGrammarRules_Vtbl)).Dynamic)(this, dynamic), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, rulename: core::mem::MaybeUninit<windows_core::BSTR>, attributes: SpeechRuleAttributes, ruleid: i32, rule: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRules", "Add", 12usize, |this| ((**(this as *const *const ISpeechGrammarRules_Vtbl)).Add)(this, rulename, attributes, ruleid, rule), |result| Some(*result))
+        }
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRules", "Commit", 13usize, |this| ((**(this as *const *const ISpeechGrammarRules_Vtbl)).Commit)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommitAndSave(this: *mut core::ffi::c_void, errortext: *mut core::mem::MaybeUninit<windows_core::BSTR>, savestream: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechGrammarRules", "CommitAndSave", 14usize, |this| ((**(this as *const *const ISpeechGrammarRules_Vtbl)).CommitAndSave)(this, errortext, savestream), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            FindRule,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            FindRule: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            _NewEnum,
+            Dynamic,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Commit,
+            CommitAndSave,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechGrammarRules as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechLexicon_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GenerationId(this: *mut core::ffi::c_void, generationid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechLexicon", "GenerationId", 7usize, |this| ((**(this as *const *const ISpeechLexicon_Vtbl)).GenerationId)(this, generationid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetWords(this: *mut core::ffi::c_void, flags: SpeechLexiconType, generationid: *mut i32, words: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechLexicon", "GetWords", 8usize, |this| ((**(this as *const *const ISpeechLexicon_Vtbl)).GetWords)(this, flags, generationid, words), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPronunciation(this: *mut core::ffi::c_void, bstrword: core::mem::MaybeUninit<windows_core::BSTR>, langid: i32, partofspeech: SpeechPartOfSpeech, bstrpronunciation: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechLexicon", "AddPronunciation", 9usize, |this| ((**(this as *const *const ISpeechLexicon_Vtbl)).AddPronunciation)(this, bstrword, langid, partofspeech, bstrpronunciation), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPronunciationByPhoneIds(this: *mut core::ffi::c_void, bstrword: core::mem::MaybeUninit<windows_core::BSTR>, langid: i32, partofspeech: SpeechPartOfSpeech, phoneids: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechLexicon", "AddPronunciationByPhoneIds", 10usize, |this| ((**(this as *const *const ISpeechLexicon_Vtbl)).AddPronunciationByPhoneIds)(this, bstrword, langid, partofspeech, phoneids), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePronunciation(this: *mut core::ffi::c_void, bstrword: core::mem::MaybeUninit<windows_core::BSTR>, langid: i32, partofspeech: SpeechPartOfSpeech, bstrpronunciation: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechLexicon", "RemovePronunciation", 11usize, |this| ((**(this as *const *const ISpeechLexicon_Vtbl)).RemovePronunciation)(this, bstrword, langid, partofspeech, bstrpronunciation), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePronunciationByPhoneIds(this: *mut core::ffi::c_void, bstrword: core::mem::MaybeUninit<windows_core::BSTR>, langid: i32, partofspeech: SpeechPartOfSpeech, phoneids: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechLexicon", "RemovePronunciationByPhoneIds", 12usize, |this| ((**(this as *const *const ISpeechLexicon_Vtbl)).RemovePronunciationByPhoneIds)(this, bstrword, langid, partofspeech, phoneids), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetPronunciations(this: *mut core::ffi::c_void, bstrword: core::mem::MaybeUninit<windows_core::BSTR>, langid: i32, typeflags: SpeechLexiconType, pppronunciations: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechLexicon", "GetPronunciations", 13usize, |this| ((**(this as *const *const ISpeechLexicon_Vtbl)).GetPronunciations)(this, bstrword, langid, typeflags, pppronunciations), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetGenerationChange(this: *mut core::ffi::c_void, generationid: *mut i32, ppwords: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechLexicon", "GetGenerationChange", 14usize, |this| ((**(this as *const *const ISpeechLexicon_Vtbl)).GetGenerationChange)(this, generationid, ppwords), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GenerationId,
+            #[cfg(feature = "Win32_System_Com")]
+            GetWords,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetWords: 0,
+            AddPronunciation,
+            AddPronunciationByPhoneIds,
+            RemovePronunciation,
+            RemovePronunciationByPhoneIds,
+            #[cfg(feature = "Win32_System_Com")]
+            GetPronunciations,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetPronunciations: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetGenerationChange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetGenerationChange: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechLexicon as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechLexiconPronunciation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, lexicontype: *mut SpeechLexiconType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechLexiconPronunciation", "Type", 7usize, |this| ((**(this as *const *const ISpeechLexiconPronunciation_Vtbl)).Type)(this, lexicontype), |result| Some(*result))
+        }
+        unsafe extern "system" fn LangId(this: *mut core::ffi::c_void, langid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechLexiconPronunciation", "LangId", 8usize, |this| ((**(this as *const *const ISpeechLexiconPronunciation_Vtbl)).LangId)(this, langid), |result| Some(*result))
+        }
+        unsafe extern "system" fn PartOfSpeech(this: *mut core::ffi::c_void, partofspeech: *mut SpeechPartOfSpeech) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechLexiconPronunciation", "PartOfSpeech", 9usize, |this| ((**(this as *const *const ISpeechLexiconPronunciation_Vtbl)).PartOfSpeech)(this, partofspeech), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhoneIds(this: *mut core::ffi::c_void, phoneids: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechLexiconPronunciation", "PhoneIds", 10usize, |this| ((**(this as *const *const ISpeechLexiconPronunciation_Vtbl)).PhoneIds)(this, phoneids), |result| Some(*result))
+        }
+        unsafe extern "system" fn Symbolic(this: *mut core::ffi::c_void, symbolic: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechLexiconPronunciation", "Symbolic", 11usize, |this| ((**(this as *const *const ISpeechLexiconPronunciation_Vtbl)).Symbolic)(this, symbolic), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Type, LangId, PartOfSpeech, PhoneIds, Symbolic }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechLexiconPronunciation as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechLexiconPronunciations_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechLexiconPronunciations", "Count", 7usize, |this| ((**(this as *const *const ISpeechLexiconPronunciations_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: i32, pronunciation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechLexiconPronunciations", "Item", 8usize, |this| ((**(this as *const *const ISpeechLexiconPronunciations_Vtbl)).Item)(this, index, pronunciation), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, enumvariant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechLexiconPronunciations", "_NewEnum", 9usize, |this| ((**(this as *const *const ISpeechLexiconPronunciations_Vtbl))._NewEnum)(this, enumvariant), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechLexiconPronunciations as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechLexiconWord_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LangId(this: *mut core::ffi::c_void, langid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechLexiconWord", "LangId", 7usize, |this| ((**(this as *const *const ISpeechLexiconWord_Vtbl)).LangId)(this, langid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, wordtype: *mut SpeechWordType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechLexiconWord", "Type", 8usize, |this| ((**(this as *const *const ISpeechLexiconWord_Vtbl)).Type)(this, wordtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Word(this: *mut core::ffi::c_void, word: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechLexiconWord", "Word", 9usize, |this| ((**(this as *const *const ISpeechLexiconWord_Vtbl)).Word)(this, word), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Pronunciations(this: *mut core::ffi::c_void, pronunciations: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechLexiconWord", "Pronunciations", 10usize, |this| ((**(this as *const *const ISpeechLexiconWord_Vtbl)).Pronunciations)(this, pronunciations), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LangId,
+            Type,
+            Word,
+            #[cfg(feature = "Win32_System_Com")]
+            Pronunciations,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Pronunciations: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechLexiconWord as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechLexiconWords_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechLexiconWords", "Count", 7usize, |this| ((**(this as *const *const ISpeechLexiconWords_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: i32, word: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechLexiconWords", "Item", 8usize, |this| ((**(this as *const *const ISpeechLexiconWords_Vtbl)).Item)(this, index, word), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, enumvariant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechLexiconWords", "_NewEnum", 9usize, |this| ((**(this as *const *const ISpeechLexiconWords_Vtbl))._NewEnum)(this, enumvariant), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechLexiconWords as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechMMSysAudio_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceId(this: *mut core::ffi::c_void, deviceid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechMMSysAudio", "DeviceId", 21usize, |this| ((**(this as *const *const ISpeechMMSysAudio_Vtbl)).DeviceId)(this, deviceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeviceId(this: *mut core::ffi::c_void, deviceid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechMMSysAudio", "SetDeviceId", 22usize, |this| ((**(this as *const *const ISpeechMMSysAudio_Vtbl)).SetDeviceId)(this, deviceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn LineId(this: *mut core::ffi::c_void, lineid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechMMSysAudio", "LineId", 23usize, |this| ((**(this as *const *const ISpeechMMSysAudio_Vtbl)).LineId)(this, lineid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLineId(this: *mut core::ffi::c_void, lineid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechMMSysAudio", "SetLineId", 24usize, |this| ((**(this as *const *const ISpeechMMSysAudio_Vtbl)).SetLineId)(this, lineid), |result| Some(*result))
+        }
+        unsafe extern "system" fn MMHandle(this: *mut core::ffi::c_void, handle: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechMMSysAudio", "MMHandle", 25usize, |this| ((**(this as *const *const ISpeechMMSysAudio_Vtbl)).MMHandle)(this, handle), |result| Some(*result))
+        }
+        Self { base__: <ISpeechAudio_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeviceId, SetDeviceId, LineId, SetLineId, MMHandle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechMMSysAudio as windows_core::Interface>::IID || <ISpeechAudio_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechMemoryStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, data: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechMemoryStream", "SetData", 12usize, |this| ((**(this as *const *const ISpeechMemoryStream_Vtbl)).SetData)(this, data), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetData(this: *mut core::ffi::c_void, pdata: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechMemoryStream", "GetData", 13usize, |this| ((**(this as *const *const ISpeechMemoryStream_Vtbl)).GetData)(this, pdata), |result| Some(*result))
+        }
+        Self { base__: <ISpeechBaseStream_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetData, GetData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechMemoryStream as windows_core::Interface>::IID || <ISpeechBaseStream_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechObjectToken_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, objectid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechObjectToken", "Id", 7usize, |this| ((**(this as *const *const ISpeechObjectToken_Vtbl)).Id)(this, objectid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DataKey(this: *mut core::ffi::c_void, datakey: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechObjectToken", "DataKey", 8usize, |this| ((**(this as *const *const ISpeechObjectToken_Vtbl)).DataKey)(this, datakey), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Category(this: *mut core::ffi::c_void, category: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechObjectToken", "Category", 9usize, |this| ((**(this as *const *const ISpeechObjectToken_Vtbl)).Category)(this, category), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescription(this: *mut core::ffi::c_void, locale: i32, description: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechObjectToken", "GetDescription", 10usize, |this| ((**(this as *const *const ISpeechObjectToken_Vtbl)).GetDescription)(this, locale, description), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetId(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::BSTR>, categoryid: core::mem::MaybeUninit<windows_core::BSTR>, createifnotexist: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechObjectToken", "SetId", 11usize, |this| ((**(this as *const *const ISpeechObjectToken_Vtbl)).SetId)(this, id, categoryid, createifnotexist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttribute(this: *mut core::ffi::c_void, attributename: core::mem::MaybeUninit<windows_core::BSTR>, attributevalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechObjectToken", "GetAttribute", 12usize, |this| ((**(this as *const *const ISpeechObjectToken_Vtbl)).GetAttribute)(this, attributename, attributevalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, clscontext: SpeechTokenContext, object: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechObjectToken", "CreateInstance", 13usize, |this| ((**(this as *const *const ISpeechObjectToken_Vtbl)).CreateInstance)(this, punkouter, clscontext, object), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, objectstorageclsid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechObjectToken", "Remove", 14usize, |this| ((**(this as *const *const ISpeechObjectToken_Vtbl)).Remove)(this, objectstorageclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStorageFileName(this: *mut core::ffi::c_void, objectstorageclsid: core::mem::MaybeUninit<windows_core::BSTR>, keyname: core::mem::MaybeUninit<windows_core::BSTR>, filename: core::mem::MaybeUninit<windows_core::BSTR>, folder: SpeechTokenShellFolder, filepath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechObjectToken", "GetStorageFileName", 15usize, |this| ((**(this as *const *const ISpeechObjectToken_Vtbl)).GetStorageFileName)(this, objectstorageclsid, keyname, filename, folder, filepath), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStorageFileName(this: *mut core::ffi::c_void, objectstorageclsid: core::mem::MaybeUninit<windows_core::BSTR>, keyname: core::mem::MaybeUninit<windows_core::BSTR>, deletefile: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechObjectToken", "RemoveStorageFileName", 16usize, |this| ((**(this as *const *const ISpeechObjectToken_Vtbl)).RemoveStorageFileName)(this, objectstorageclsid, keyname, deletefile), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUISupported(this: *mut core::ffi::c_void, typeofui: core::mem::MaybeUninit<windows_core::BSTR>, extradata: *const core::mem::MaybeUninit<windows_core::VARIANT>, object: *mut core::ffi::c_void, supported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechObjectToken", "IsUISupported", 17usize, |this| ((**(this as *const *const ISpeechObjectToken_Vtbl)).IsUISupported)(this, typeofui, extradata, object, supported), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayUI(this: *mut core::ffi::c_void, hwnd: i32, title: core::mem::MaybeUninit<windows_core::BSTR>, typeofui: core::mem::MaybeUninit<windows_core::BSTR>, extradata: *const core::mem::MaybeUninit<windows_core::VARIANT>, object: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechObjectToken", "DisplayUI", 18usize, |this| ((**(this as *const *const ISpeechObjectToken_Vtbl)).DisplayUI)(this, hwnd, title, typeofui, extradata, object), |result| Some(*result))
+        }
+        unsafe extern "system" fn MatchesAttributes(this: *mut core::ffi::c_void, attributes: core::mem::MaybeUninit<windows_core::BSTR>, matches: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechObjectToken", "MatchesAttributes", 19usize, |this| ((**(this as *const *const ISpeechObjectToken_Vtbl)).MatchesAttributes)(this, attributes, matches), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            #[cfg(feature = "Win32_System_Com")]
+            DataKey,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DataKey: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Category,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Category: 0,
+            GetDescription,
+            SetId,
+            GetAttribute,
+            CreateInstance,
+            Remove,
+            GetStorageFileName,
+            RemoveStorageFileName,
+            IsUISupported,
+            DisplayUI,
+            MatchesAttributes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechObjectToken as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechObjectTokenCategory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, id: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechObjectTokenCategory", "Id", 7usize, |this| ((**(this as *const *const ISpeechObjectTokenCategory_Vtbl)).Id)(this, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefault(this: *mut core::ffi::c_void, tokenid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechObjectTokenCategory", "SetDefault", 8usize, |this| ((**(this as *const *const ISpeechObjectTokenCategory_Vtbl)).SetDefault)(this, tokenid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Default(this: *mut core::ffi::c_void, tokenid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechObjectTokenCategory", "Default", 9usize, |this| ((**(this as *const *const ISpeechObjectTokenCategory_Vtbl)).Default)(this, tokenid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetId(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::BSTR>, createifnotexist: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechObjectTokenCategory", "SetId", 10usize, |this| ((**(this as *const *const ISpeechObjectTokenCategory_Vtbl)).SetId)(this, id, createifnotexist), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetDataKey(this: *mut core::ffi::c_void, location: SpeechDataKeyLocation, datakey: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechObjectTokenCategory", "GetDataKey", 11usize, |this| ((**(this as *const *const ISpeechObjectTokenCategory_Vtbl)).GetDataKey)(this, location, datakey), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumerateTokens(this: *mut core::ffi::c_void, requiredattributes: core::mem::MaybeUninit<windows_core::BSTR>, optionalattributes: core::mem::MaybeUninit<windows_core::BSTR>, tokens: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechObjectTokenCategory", "EnumerateTokens", 12usize, |this| ((**(this as *const *const ISpeechObjectTokenCategory_Vtbl)).EnumerateTokens)(this, requiredattributes, optionalattributes, tokens), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            SetDefault,
+            Default,
+            SetId,
+            #[cfg(feature = "Win32_System_Com")]
+            GetDataKey,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetDataKey: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumerateTokens,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumerateTokens: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechObjectTokenCategory as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechObjectTokens_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechObjectTokens", "Count", 7usize, |this| ((**(this as *const *const ISpeechObjectTokens_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: i32, token: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechObjectTokens", "Item", 8usize, |this| ((**(this as *const *const ISpeechObjectTokens_Vtbl)).Item)(this, index, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppenumvariant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechObjectTokens", "_NewEnum", 9usize, |this| ((**(this as *const *const ISpeechObjectTokens_Vtbl))._NewEnum)(this, ppenumvariant), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechObjectTokens as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechPhoneConverter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LanguageId(this: *mut core::ffi::c_void, languageid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhoneConverter", "LanguageId", 7usize, |this| ((**(this as *const *const ISpeechPhoneConverter_Vtbl)).LanguageId)(this, languageid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLanguageId(this: *mut core::ffi::c_void, languageid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhoneConverter", "SetLanguageId", 8usize, |this| ((**(this as *const *const ISpeechPhoneConverter_Vtbl)).SetLanguageId)(this, languageid), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhoneToId(this: *mut core::ffi::c_void, phonemes: core::mem::MaybeUninit<windows_core::BSTR>, idarray: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhoneConverter", "PhoneToId", 9usize, |this| ((**(this as *const *const ISpeechPhoneConverter_Vtbl)).PhoneToId)(this, phonemes, idarray), |result| Some(*result))
+        }
+        unsafe extern "system" fn IdToPhone(this: *mut core::ffi::c_void, idarray: core::mem::MaybeUninit<windows_core::VARIANT>, phonemes: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhoneConverter", "IdToPhone", 10usize, |this| ((**(this as *const *const ISpeechPhoneConverter_Vtbl)).IdToPhone)(this, idarray, phonemes), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LanguageId, SetLanguageId, PhoneToId, IdToPhone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechPhoneConverter as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechPhraseAlternate_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RecoResult(this: *mut core::ffi::c_void, recoresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseAlternate", "RecoResult", 7usize, |this| ((**(this as *const *const ISpeechPhraseAlternate_Vtbl)).RecoResult)(this, recoresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartElementInResult(this: *mut core::ffi::c_void, startelement: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseAlternate", "StartElementInResult", 8usize, |this| ((**(this as *const *const ISpeechPhraseAlternate_Vtbl)).StartElementInResult)(this, startelement), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfElementsInResult(this: *mut core::ffi::c_void, numberofelements: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseAlternate", "NumberOfElementsInResult", 9usize, |this| ((**(this as *const *const ISpeechPhraseAlternate_Vtbl)).NumberOfElementsInResult)(this, numberofelements), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PhraseInfo(this: *mut core::ffi::c_void, phraseinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseAlternate", "PhraseInfo", 10usize, |this| ((**(this as *const *const ISpeechPhraseAlternate_Vtbl)).PhraseInfo)(this, phraseinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseAlternate", "Commit", 11usize, |this| ((**(this as *const *const ISpeechPhraseAlternate_Vtbl)).Commit)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            RecoResult,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RecoResult: 0,
+            StartElementInResult,
+            NumberOfElementsInResult,
+            #[cfg(feature = "Win32_System_Com")]
+            PhraseInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PhraseInfo: 0,
+            Commit,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechPhraseAlternate as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechPhraseAlternates_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseAlternates", "Count", 7usize, |this| ((**(this as *const *const ISpeechPhraseAlternates_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: i32, phrasealternate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseAlternates", "Item", 8usize, |this| ((**(this as *const *const ISpeechPhraseAlternates_Vtbl)).Item)(this, index, phrasealternate), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, enumvariant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseAlternates", "_NewEnum", 9usize, |this| ((**(this as *const *const ISpeechPhraseAlternates_Vtbl))._NewEnum)(this, enumvariant), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechPhraseAlternates as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechPhraseElement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AudioTimeOffset(this: *mut core::ffi::c_void, audiotimeoffset: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseElement", "AudioTimeOffset", 7usize, |this| ((**(this as *const *const ISpeechPhraseElement_Vtbl)).AudioTimeOffset)(this, audiotimeoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioSizeTime(this: *mut core::ffi::c_void, audiosizetime: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseElement", "AudioSizeTime", 8usize, |this| ((**(this as *const *const ISpeechPhraseElement_Vtbl)).AudioSizeTime)(this, audiosizetime), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioStreamOffset(this: *mut core::ffi::c_void, audiostreamoffset: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseElement", "AudioStreamOffset", 9usize, |this| ((**(this as *const *const ISpeechPhraseElement_Vtbl)).AudioStreamOffset)(this, audiostreamoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioSizeBytes(this: *mut core::ffi::c_void, audiosizebytes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseElement", "AudioSizeBytes", 10usize, |this| ((**(this as *const *const ISpeechPhraseElement_Vtbl)).AudioSizeBytes)(this, audiosizebytes), |result| Some(*result))
+        }
+        unsafe extern "system" fn RetainedStreamOffset(this: *mut core::ffi::c_void, retainedstreamoffset: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseElement", "RetainedStreamOffset", 11usize, |this| ((**(this as *const *const ISpeechPhraseElement_Vtbl)).RetainedStreamOffset)(this, retainedstreamoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn RetainedSizeBytes(this: *mut core::ffi::c_void, retainedsizebytes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseElement", "RetainedSizeBytes", 12usize, |this| ((**(this as *const *const ISpeechPhraseElement_Vtbl)).RetainedSizeBytes)(this, retainedsizebytes), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayText(this: *mut core::ffi::c_void, displaytext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseElement", "DisplayText", 13usize, |this| ((**(this as *const *const ISpeechPhraseElement_Vtbl)).DisplayText)(this, displaytext), |result| Some(*result))
+        }
+        unsafe extern "system" fn LexicalForm(this: *mut core::ffi::c_void, lexicalform: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseElement", "LexicalForm", 14usize, |this| ((**(this as *const *const ISpeechPhraseElement_Vtbl)).LexicalForm)(this, lexicalform), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pronunciation(this: *mut core::ffi::c_void, pronunciation: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseElement", "Pronunciation", 15usize, |this| ((**(this as *const *const ISpeechPhraseElement_Vtbl)).Pronunciation)(this, pronunciation), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayAttributes(this: *mut core::ffi::c_void, displayattributes: *mut SpeechDisplayAttributes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseElement", "DisplayAttributes", 16usize, |this| ((**(this as *const *const ISpeechPhraseElement_Vtbl)).DisplayAttributes)(this, displayattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequiredConfidence(this: *mut core::ffi::c_void, requiredconfidence: *mut SpeechEngineConfidence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseElement", "RequiredConfidence", 17usize, |this| ((**(this as *const *const ISpeechPhraseElement_Vtbl)).RequiredConfidence)(this, requiredconfidence), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActualConfidence(this: *mut core::ffi::c_void, actualconfidence: *mut SpeechEngineConfidence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseElement", "ActualConfidence", 18usize, |this| ((**(this as *const *const ISpeechPhraseElement_Vtbl)).ActualConfidence)(this, actualconfidence), |result| Some(*result))
+        }
+        unsafe extern "system" fn EngineConfidence(this: *mut core::ffi::c_void, engineconfidence: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseElement", "EngineConfidence", 19usize, |this| ((**(this as *const *const ISpeechPhraseElement_Vtbl)).EngineConfidence)(this, engineconfidence), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AudioTimeOffset,
+            AudioSizeTime,
+            AudioStreamOffset,
+            AudioSizeBytes,
+            RetainedStreamOffset,
+            RetainedSizeBytes,
+            DisplayText,
+            LexicalForm,
+            Pronunciation,
+            DisplayAttributes,
+            RequiredConfidence,
+            ActualConfidence,
+            EngineConfidence,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechPhraseElement as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechPhraseElements_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseElements", "Count", 7usize, |this| ((**(this as *const *const ISpeechPhraseElements_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: i32, element: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseElements", "Item", 8usize, |this| ((**(this as *const *const ISpeechPhraseElements_Vtbl)).Item)(this, index, element), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, enumvariant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseElements", "_NewEnum", 9usize, |this| ((**(this as *const *const ISpeechPhraseElements_Vtbl))._NewEnum)(this, enumvariant), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechPhraseElements as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechPhraseInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LanguageId(this: *mut core::ffi::c_void, languageid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseInfo", "LanguageId", 7usize, |this| ((**(this as *const *const ISpeechPhraseInfo_Vtbl)).LanguageId)(this, languageid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GrammarId(this: *mut core::ffi::c_void, grammarid: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseInfo", "GrammarId", 8usize, |this| ((**(this as *const *const ISpeechPhraseInfo_Vtbl)).GrammarId)(this, grammarid), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartTime(this: *mut core::ffi::c_void, starttime: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseInfo", "StartTime", 9usize, |this| ((**(this as *const *const ISpeechPhraseInfo_Vtbl)).StartTime)(this, starttime), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioStreamPosition(this: *mut core::ffi::c_void, audiostreamposition: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseInfo", "AudioStreamPosition", 10usize, |this| ((**(this as *const *const ISpeechPhraseInfo_Vtbl)).AudioStreamPosition)(this, audiostreamposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioSizeBytes(this: *mut core::ffi::c_void, paudiosizebytes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseInfo", "AudioSizeBytes", 11usize, |this| ((**(this as *const *const ISpeechPhraseInfo_Vtbl)).AudioSizeBytes)(this, paudiosizebytes), |result| Some(*result))
+        }
+        unsafe extern "system" fn RetainedSizeBytes(this: *mut core::ffi::c_void, retainedsizebytes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseInfo", "RetainedSizeBytes", 12usize, |this| ((**(this as *const *const ISpeechPhraseInfo_Vtbl)).RetainedSizeBytes)(this, retainedsizebytes), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioSizeTime(this: *mut core::ffi::c_void, audiosizetime: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseInfo", "AudioSizeTime", 13usize, |this| ((**(this as *const *const ISpeechPhraseInfo_Vtbl)).AudioSizeTime)(this, audiosizetime), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Rule(this: *mut core::ffi::c_void, rule: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseInfo", "Rule", 14usize, |this| ((**(this as *const *const ISpeechPhraseInfo_Vtbl)).Rule)(this, rule), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, properties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseInfo", "Properties", 15usize, |this| ((**(this as *const *const ISpeechPhraseInfo_Vtbl)).Properties)(this, properties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Elements(this: *mut core::ffi::c_void, elements: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseInfo", "Elements", 16usize, |this| ((**(this as *const *const ISpeechPhraseInfo_Vtbl)).Elements)(this, elements), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Replacements(this: *mut core::ffi::c_void, replacements: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseInfo", "Replacements", 17usize, |this| ((**(this as *const *const ISpeechPhraseInfo_Vtbl)).Replacements)(this, replacements), |result| Some(*result))
+        }
+        unsafe extern "system" fn EngineId(this: *mut core::ffi::c_void, engineidguid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseInfo", "EngineId", 18usize, |this| ((**(this as *const *const ISpeechPhraseInfo_Vtbl)).EngineId)(this, engineidguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnginePrivateData(this: *mut core::ffi::c_void, privatedata: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseInfo", "EnginePrivateData", 19usize, |this| ((**(this as *const *const ISpeechPhraseInfo_Vtbl)).EnginePrivateData)(this, privatedata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveToMemory(this: *mut core::ffi::c_void, phraseblock: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseInfo", "SaveToMemory", 20usize, |this| ((**(this as *const *const ISpeechPhraseInfo_Vtbl)).SaveToMemory)(this, phraseblock), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetText(this: *mut core::ffi::c_void, startelement: i32, elements: i32, usereplacements: super::super::Foundation::VARIANT_BOOL, text: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseInfo", "GetText", 21usize, |this| ((**(this as *const *const ISpeechPhraseInfo_Vtbl)).GetText)(this, startelement, elements, usereplacements, text), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayAttributes(this: *mut core::ffi::c_void, startelement: i32, elements: i32, usereplacements: super::super::Foundation::VARIANT_BOOL, displayattributes: *mut SpeechDisplayAttributes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseInfo", "GetDisplayAttributes", 22usize, |this| ((**(this as *const *const ISpeechPhraseInfo_Vtbl)).GetDisplayAttributes)(this, startelement, elements, usereplacements, displayattributes), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LanguageId,
+            GrammarId,
+            StartTime,
+            AudioStreamPosition,
+            AudioSizeBytes,
+            RetainedSizeBytes,
+            AudioSizeTime,
+            #[cfg(feature = "Win32_System_Com")]
+            Rule,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Rule: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Elements,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Elements: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Replacements,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Replacements: 0,
+            EngineId,
+            EnginePrivateData,
+            SaveToMemory,
+            GetText,
+            GetDisplayAttributes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechPhraseInfo as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechPhraseInfoBuilder_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RestorePhraseFromMemory(this: *mut core::ffi::c_void, phraseinmemory: *const core::mem::MaybeUninit<windows_core::VARIANT>, phraseinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseInfoBuilder", "RestorePhraseFromMemory", 7usize, |this| ((**(this as *const *const ISpeechPhraseInfoBuilder_Vtbl)).RestorePhraseFromMemory)(this, phraseinmemory, phraseinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            RestorePhraseFromMemory,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RestorePhraseFromMemory: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechPhraseInfoBuilder as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechPhraseProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseProperties", "Count", 7usize, |this| ((**(this as *const *const ISpeechPhraseProperties_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: i32, property: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseProperties", "Item", 8usize, |this| ((**(this as *const *const ISpeechPhraseProperties_Vtbl)).Item)(this, index, property), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, enumvariant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseProperties", "_NewEnum", 9usize, |this| ((**(this as *const *const ISpeechPhraseProperties_Vtbl))._NewEnum)(this, enumvariant), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechPhraseProperties as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechPhraseProperty_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseProperty", "Name", 7usize, |this| ((**(this as *const *const ISpeechPhraseProperty_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, id: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseProperty", "Id", 8usize, |this| ((**(this as *const *const ISpeechPhraseProperty_Vtbl)).Id)(this, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseProperty", "Value", 9usize, |this| ((**(this as *const *const ISpeechPhraseProperty_Vtbl)).Value)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FirstElement(this: *mut core::ffi::c_void, firstelement: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseProperty", "FirstElement", 10usize, |this| ((**(this as *const *const ISpeechPhraseProperty_Vtbl)).FirstElement)(this, firstelement), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfElements(this: *mut core::ffi::c_void, numberofelements: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseProperty", "NumberOfElements", 11usize, |this| ((**(this as *const *const ISpeechPhraseProperty_Vtbl)).NumberOfElements)(this, numberofelements), |result| Some(*result))
+        }
+        unsafe extern "system" fn EngineConfidence(this: *mut core::ffi::c_void, confidence: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseProperty", "EngineConfidence", 12usize, |this| ((**(this as *const *const ISpeechPhraseProperty_Vtbl)).EngineConfidence)(this, confidence), |result| Some(*result))
+        }
+        unsafe extern "system" fn Confidence(this: *mut core::ffi::c_void, confidence: *mut SpeechEngineConfidence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseProperty", "Confidence", 13usize, |this| ((**(this as *const *const ISpeechPhraseProperty_Vtbl)).Confidence)(this, confidence), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Parent(this: *mut core::ffi::c_void, parentproperty: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseProperty", "Parent", 14usize, |this| ((**(this as *const *const ISpeechPhraseProperty_Vtbl)).Parent)(this, parentproperty), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Children(this: *mut core::ffi::c_void, children: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseProperty", "Children", 15usize, |this| ((**(this as *const *const ISpeechPhraseProperty_Vtbl)).Children)(this, children), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            Id,
+            Value,
+            FirstElement,
+            NumberOfElements,
+            EngineConfidence,
+            Confidence,
+            #[cfg(feature = "Win32_System_Com")]
+            Parent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Parent: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Children,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Children: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechPhraseProperty as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechPhraseReplacement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayAttributes(this: *mut core::ffi::c_void, displayattributes: *mut SpeechDisplayAttributes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseReplacement", "DisplayAttributes", 7usize, |this| ((**(this as *const *const ISpeechPhraseReplacement_Vtbl)).DisplayAttributes)(this, displayattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, text: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseReplacement", "Text", 8usize, |this| ((**(this as *const *const ISpeechPhraseReplacement_Vtbl)).Text)(this, text), |result| Some(*result))
+        }
+        unsafe extern "system" fn FirstElement(this: *mut core::ffi::c_void, firstelement: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseReplacement", "FirstElement", 9usize, |this| ((**(this as *const *const ISpeechPhraseReplacement_Vtbl)).FirstElement)(this, firstelement), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfElements(this: *mut core::ffi::c_void, numberofelements: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseReplacement", "NumberOfElements", 10usize, |this| ((**(this as *const *const ISpeechPhraseReplacement_Vtbl)).NumberOfElements)(this, numberofelements), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DisplayAttributes,
+            Text,
+            FirstElement,
+            NumberOfElements,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechPhraseReplacement as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechPhraseReplacements_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseReplacements", "Count", 7usize, |this| ((**(this as *const *const ISpeechPhraseReplacements_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: i32, reps: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseReplacements", "Item", 8usize, |this| ((**(this as *const *const ISpeechPhraseReplacements_Vtbl)).Item)(this, index, reps), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, enumvariant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseReplacements", "_NewEnum", 9usize, |this| ((**(this as *const *const ISpeechPhraseReplacements_Vtbl))._NewEnum)(this, enumvariant), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechPhraseReplacements as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechPhraseRule_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseRule", "Name", 7usize, |this| ((**(this as *const *const ISpeechPhraseRule_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, id: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseRule", "Id", 8usize, |this| ((**(this as *const *const ISpeechPhraseRule_Vtbl)).Id)(this, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn FirstElement(this: *mut core::ffi::c_void, firstelement: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseRule", "FirstElement", 9usize, |this| ((**(this as *const *const ISpeechPhraseRule_Vtbl)).FirstElement)(this, firstelement), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfElements(this: *mut core::ffi::c_void, numberofelements: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseRule", "NumberOfElements", 10usize, |this| ((**(this as *const *const ISpeechPhraseRule_Vtbl)).NumberOfElements)(this, numberofelements), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Parent(this: *mut core::ffi::c_void, parent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseRule", "Parent", 11usize, |this| ((**(this as *const *const ISpeechPhraseRule_Vtbl)).Parent)(this, parent), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Children(this: *mut core::ffi::c_void, children: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseRule", "Children", 12usize, |this| ((**(this as *const *const ISpeechPhraseRule_Vtbl)).Children)(this, children), |result| Some(*result))
+        }
+        unsafe extern "system" fn Confidence(this: *mut core::ffi::c_void, actualconfidence: *mut SpeechEngineConfidence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseRule", "Confidence", 13usize, |this| ((**(this as *const *const ISpeechPhraseRule_Vtbl)).Confidence)(this, actualconfidence), |result| Some(*result))
+        }
+        unsafe extern "system" fn EngineConfidence(this: *mut core::ffi::c_void, engineconfidence: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseRule", "EngineConfidence", 14usize, |this| ((**(this as *const *const ISpeechPhraseRule_Vtbl)).EngineConfidence)(this, engineconfidence), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            Id,
+            FirstElement,
+            NumberOfElements,
+            #[cfg(feature = "Win32_System_Com")]
+            Parent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Parent: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Children,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Children: 0,
+            Confidence,
+            EngineConfidence,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechPhraseRule as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechPhraseRules_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseRules", "Count", 7usize, |this| ((**(this as *const *const ISpeechPhraseRules_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: i32, rule: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseRules", "Item", 8usize, |this| ((**(this as *const *const ISpeechPhraseRules_Vtbl)).Item)(this, index, rule), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, enumvariant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechPhraseRules", "_NewEnum", 9usize, |this| ((**(this as *const *const ISpeechPhraseRules_Vtbl))._NewEnum)(this, enumvariant), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechPhraseRules as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechRecoContext_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Recognizer(this: *mut core::ffi::c_void, recognizer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoContext", "Recognizer", 7usize, |this| ((**(this as *const *const ISpeechRecoContext_Vtbl)).Recognizer)(this, recognizer), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioInputInterferenceStatus(this: *mut core::ffi::c_void, interference: *mut SpeechInterference) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoContext", "AudioInputInterferenceStatus", 8usize, |this| ((**(this as *const *const ISpeechRecoContext_Vtbl)).AudioInputInterferenceStatus)(this, interference), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestedUIType(this: *mut core::ffi::c_void, uitype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoContext", "RequestedUIType", 9usize, |this| ((**(this as *const *const ISpeechRecoContext_Vtbl)).RequestedUIType)(this, uitype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_Voice(this: *mut core::ffi::c_void, voice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoContext", "putref_Voice", 10usize, |this| ((**(this as *const *const ISpeechRecoContext_Vtbl)).putref_Voice)(this, voice), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Voice(this: *mut core::ffi::c_void, voice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoContext", "Voice", 11usize, |this| ((**(this as *const *const ISpeechRecoContext_Vtbl)).Voice)(this, voice), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowVoiceFormatMatchingOnNextSet(this: *mut core::ffi::c_void, allow: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoContext", "SetAllowVoiceFormatMatchingOnNextSet", 12usize, |this| ((**(this as *const *const ISpeechRecoContext_Vtbl)).SetAllowVoiceFormatMatchingOnNextSet)(this, allow), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowVoiceFormatMatchingOnNextSet(this: *mut core::ffi::c_void, pallow: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoContext", "AllowVoiceFormatMatchingOnNextSet", 13usize, |this| ((**(this as *const *const ISpeechRecoContext_Vtbl)).AllowVoiceFormatMatchingOnNextSet)(this, pallow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVoicePurgeEvent(this: *mut core::ffi::c_void, eventinterest: SpeechRecoEvents) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoContext", "SetVoicePurgeEvent", 14usize, |this| ((**(this as *const *const ISpeechRecoContext_Vtbl)).SetVoicePurgeEvent)(this, eventinterest), |result| Some(*result))
+        }
+        unsafe extern "system" fn VoicePurgeEvent(this: *mut core::ffi::c_void, eventinterest: *mut SpeechRecoEvents) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoContext", "VoicePurgeEvent", 15usize, |this| ((**(this as *const *const ISpeechRecoContext_Vtbl)).VoicePurgeEvent)(this, eventinterest), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventInterests(this: *mut core::ffi::c_void, eventinterest: SpeechRecoEvents) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoContext", "SetEventInterests", 16usize, |this| ((**(this as *const *const ISpeechRecoContext_Vtbl)).SetEventInterests)(this, eventinterest), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventInterests(this: *mut core::ffi::c_void, eventinterest: *mut SpeechRecoEvents) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoContext", "EventInterests", 17usize, |this| ((**(this as *const *const ISpeechRecoContext_Vtbl)).EventInterests)(this, eventinterest), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCmdMaxAlternates(this: *mut core::ffi::c_void, maxalternates: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoContext", "SetCmdMaxAlternates", 18usize, |this| ((**(this as *const *const ISpeechRecoContext_Vtbl)).SetCmdMaxAlternates)(this, maxalternates), |result| Some(*result))
+        }
+        unsafe extern "system" fn CmdMaxAlternates(this: *mut core::ffi::c_void, maxalternates: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoContext", "CmdMaxAlternates", 19usize, |this| ((**(this as *const *const ISpeechRecoContext_Vtbl)).CmdMaxAlternates)(this, maxalternates), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetState(this: *mut core::ffi::c_void, state: SpeechRecoContextState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoContext", "SetState", 20usize, |this| ((**(this as *const *const ISpeechRecoContext_Vtbl)).SetState)(this, state), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, state: *mut SpeechRecoContextState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoContext", "State", 21usize, |this| ((**(this as *const *const ISpeechRecoContext_Vtbl)).State)(this, state), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRetainedAudio(this: *mut core::ffi::c_void, option: SpeechRetainedAudioOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoContext", "SetRetainedAudio", 22usize, |this| ((**(this as *const *const ISpeechRecoContext_Vtbl)).SetRetainedAudio)(this, option), |result| Some(*result))
+        }
+        unsafe extern "system" fn RetainedAudio(this: *mut core::ffi::c_void, option: *mut SpeechRetainedAudioOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoContext", "RetainedAudio", 23usize, |this| ((**(this as *const *const ISpeechRecoContext_Vtbl)).RetainedAudio)(this, option), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_RetainedAudioFormat(this: *mut core::ffi::c_void, format: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoContext", "putref_RetainedAudioFormat", 24usize, |this| ((**(this as *const *const ISpeechRecoContext_Vtbl)).putref_RetainedAudioFormat)(this, format), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RetainedAudioFormat(this: *mut core::ffi::c_void, format: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoContext", "RetainedAudioFormat", 25usize, |this| ((**(this as *const *const ISpeechRecoContext_Vtbl)).RetainedAudioFormat)(this, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoContext", "Pause", 26usize, |this| ((**(this as *const *const ISpeechRecoContext_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoContext", "Resume", 27usize, |this| ((**(this as *const *const ISpeechRecoContext_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateGrammar(this: *mut core::ffi::c_void, grammarid: core::mem::MaybeUninit<windows_core::VARIANT>, grammar: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoContext", "CreateGrammar", 28usize, |this| ((**(this as *const *const ISpeechRecoContext_Vtbl)).CreateGrammar)(this, grammarid, grammar), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateResultFromMemory(this: *mut core::ffi::c_void, resultblock: *const core::mem::MaybeUninit<windows_core::VARIANT>, result: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoContext", "CreateResultFromMemory", 29usize, |this| ((**(this as *const *const ISpeechRecoContext_Vtbl)).CreateResultFromMemory)(this, resultblock, result), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bookmark(this: *mut core::ffi::c_void, options: SpeechBookmarkOptions, streampos: core::mem::MaybeUninit<windows_core::VARIANT>, bookmarkid: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoContext", "Bookmark", 30usize, |this| ((**(this as *const *const ISpeechRecoContext_Vtbl)).Bookmark)(this, options, streampos, bookmarkid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAdaptationData(this: *mut core::ffi::c_void, adaptationstring: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoContext", "SetAdaptationData", 31usize, |this| ((**(this as *const *const ISpeechRecoContext_Vtbl)).SetAdaptationData)(this, adaptationstring), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Recognizer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Recognizer: 0,
+            AudioInputInterferenceStatus,
+            RequestedUIType,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_Voice,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_Voice: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Voice,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Voice: 0,
+            SetAllowVoiceFormatMatchingOnNextSet,
+            AllowVoiceFormatMatchingOnNextSet,
+            SetVoicePurgeEvent,
+            VoicePurgeEvent,
+            SetEventInterests,
+            EventInterests,
+            SetCmdMaxAlternates,
+            CmdMaxAlternates,
+            SetState,
+            State,
+            SetRetainedAudio,
+            RetainedAudio,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_RetainedAudioFormat,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_RetainedAudioFormat: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            RetainedAudioFormat,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RetainedAudioFormat: 0,
+            Pause,
+            Resume,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateGrammar,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateGrammar: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateResultFromMemory,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateResultFromMemory: 0,
+            Bookmark,
+            SetAdaptationData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecoContext as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechRecoGrammar_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, id: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoGrammar", "Id", 7usize, |this| ((**(this as *const *const ISpeechRecoGrammar_Vtbl)).Id)(this, id), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RecoContext(this: *mut core::ffi::c_void, recocontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoGrammar", "RecoContext", 8usize, |this| ((**(this as *const *const ISpeechRecoGrammar_Vtbl)).RecoContext)(this, recocontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetState(this: *mut core::ffi::c_void, state: SpeechGrammarState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoGrammar", "SetState", 9usize, |this| ((**(this as *const *const ISpeechRecoGrammar_Vtbl)).SetState)(this, state), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, state: *mut SpeechGrammarState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoGrammar", "State", 10usize, |this| ((**(this as *const *const ISpeechRecoGrammar_Vtbl)).State)(this, state), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Rules(this: *mut core::ffi::c_void, rules: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoGrammar", "Rules", 11usize, |this| ((**(this as *const *const ISpeechRecoGrammar_Vtbl)).Rules)(this, rules), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void, newlanguage: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoGrammar", "Reset", 12usize, |this| ((**(this as *const *const ISpeechRecoGrammar_Vtbl)).Reset)(this, newlanguage), |result| Some(*result))
+        }
+        unsafe extern "system" fn CmdLoadFromFile(this: *mut core::ffi::c_void, filename: core::mem::MaybeUninit<windows_core::BSTR>, loadoption: SpeechLoadOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoGrammar", "CmdLoadFromFile", 13usize, |this| ((**(this as *const *const ISpeechRecoGrammar_Vtbl)).CmdLoadFromFile)(this, filename, loadoption), |result| Some(*result))
+        }
+        unsafe extern "system" fn CmdLoadFromObject(this: *mut core::ffi::c_void, classid: core::mem::MaybeUninit<windows_core::BSTR>, grammarname: core::mem::MaybeUninit<windows_core::BSTR>, loadoption: SpeechLoadOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoGrammar", "CmdLoadFromObject", 14usize, |this| ((**(this as *const *const ISpeechRecoGrammar_Vtbl)).CmdLoadFromObject)(this, classid, grammarname, loadoption), |result| Some(*result))
+        }
+        unsafe extern "system" fn CmdLoadFromResource(this: *mut core::ffi::c_void, hmodule: i32, resourcename: core::mem::MaybeUninit<windows_core::VARIANT>, resourcetype: core::mem::MaybeUninit<windows_core::VARIANT>, languageid: i32, loadoption: SpeechLoadOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoGrammar", "CmdLoadFromResource", 15usize, |this| ((**(this as *const *const ISpeechRecoGrammar_Vtbl)).CmdLoadFromResource)(this, hmodule, resourcename, resourcetype, languageid, loadoption), |result| Some(*result))
+        }
+        unsafe extern "system" fn CmdLoadFromMemory(this: *mut core::ffi::c_void, grammardata: core::mem::MaybeUninit<windows_core::VARIANT>, loadoption: SpeechLoadOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoGrammar", "CmdLoadFromMemory", 16usize, |this| ((**(this as *const *const ISpeechRecoGrammar_Vtbl)).CmdLoadFromMemory)(this, grammardata, loadoption), |result| Some(*result))
+        }
+        unsafe extern "system" fn CmdLoadFromProprietaryGrammar(this: *mut core::ffi::c_void, proprietaryguid: core::mem::MaybeUninit<windows_core::BSTR>, proprietarystring: core::mem::MaybeUninit<windows_core::BSTR>, proprietarydata: core::mem::MaybeUninit<windows_core::VARIANT>, loadoption: SpeechLoadOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoGrammar", "CmdLoadFromProprietaryGrammar", 17usize, |this| ((**(this as *const *const ISpeechRecoGrammar_Vtbl)).CmdLoadFromProprietaryGrammar)(this, proprietaryguid, proprietarystring, proprietarydata, loadoption), |result| Some(*result))
+        }
+        unsafe extern "system" fn CmdSetRuleState(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, state: SpeechRuleState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoGrammar", "CmdSetRuleState", 18usize, |this| ((**(this as *const *const ISpeechRecoGrammar_Vtbl)).CmdSetRuleState)(this, name, state), |result| Some(*result))
+        }
+        unsafe extern "system" fn CmdSetRuleIdState(this: *mut core::ffi::c_void, ruleid: i32, state: SpeechRuleState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoGrammar", "CmdSetRuleIdState", 19usize, |this| ((**(this as *const *const ISpeechRecoGrammar_Vtbl)).CmdSetRuleIdState)(this, ruleid, state), |result| Some(*result))
+        }
+        unsafe extern "system" fn DictationLoad(this: *mut core::ffi::c_void, topicname: core::mem::MaybeUninit<windows_core::BSTR>, loadoption: SpeechLoadOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoGrammar", "DictationLoad", 20usize, |this| ((**(this as *const *const ISpeechRecoGrammar_Vtbl)).DictationLoad)(this, topicname, loadoption), |result| Some(*result))
+        }
+        unsafe extern "system" fn DictationUnload(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoGrammar", "DictationUnload", 21usize, |this| ((**(this as *const *const ISpeechRecoGrammar_Vtbl)).DictationUnload)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DictationSetState(this: *mut core::ffi::c_void, state: SpeechRuleState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoGrammar", "DictationSetState", 22usize, |this| ((**(this as *const *const ISpeechRecoGrammar_Vtbl)).DictationSetState)(this, state), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetWordSequenceData(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::BSTR>, textlength: i32, info: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoGrammar", "SetWordSequenceData", 23usize, |this| ((**(this as *const *const ISpeechRecoGrammar_Vtbl)).SetWordSequenceData)(this, text, textlength, info), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetTextSelection(this: *mut core::ffi::c_void, info: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoGrammar", "SetTextSelection", 24usize, |this| ((**(this as *const *const ISpeechRecoGrammar_Vtbl)).SetTextSelection)(this, info), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPronounceable(this: *mut core::ffi::c_void, word: core::mem::MaybeUninit<windows_core::BSTR>, wordpronounceable: *mut SpeechWordPronounceable) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoGrammar", "IsPronounceable", 25usize, |this| ((**(this as *const *const ISpeechRecoGrammar_Vtbl)).IsPronounceable)(this, word, wordpronounceable), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            #[cfg(feature = "Win32_System_Com")]
+            RecoContext,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RecoContext: 0,
+            SetState,
+            State,
+            #[cfg(feature = "Win32_System_Com")]
+            Rules,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Rules: 0,
+            Reset,
+            CmdLoadFromFile,
+            CmdLoadFromObject,
+            CmdLoadFromResource,
+            CmdLoadFromMemory,
+            CmdLoadFromProprietaryGrammar,
+            CmdSetRuleState,
+            CmdSetRuleIdState,
+            DictationLoad,
+            DictationUnload,
+            DictationSetState,
+            #[cfg(feature = "Win32_System_Com")]
+            SetWordSequenceData,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetWordSequenceData: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetTextSelection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetTextSelection: 0,
+            IsPronounceable,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecoGrammar as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechRecoResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RecoContext(this: *mut core::ffi::c_void, recocontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoResult", "RecoContext", 7usize, |this| ((**(this as *const *const ISpeechRecoResult_Vtbl)).RecoContext)(this, recocontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Times(this: *mut core::ffi::c_void, times: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoResult", "Times", 8usize, |this| ((**(this as *const *const ISpeechRecoResult_Vtbl)).Times)(this, times), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_AudioFormat(this: *mut core::ffi::c_void, format: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoResult", "putref_AudioFormat", 9usize, |this| ((**(this as *const *const ISpeechRecoResult_Vtbl)).putref_AudioFormat)(this, format), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AudioFormat(this: *mut core::ffi::c_void, format: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoResult", "AudioFormat", 10usize, |this| ((**(this as *const *const ISpeechRecoResult_Vtbl)).AudioFormat)(this, format), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PhraseInfo(this: *mut core::ffi::c_void, phraseinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoResult", "PhraseInfo", 11usize, |this| ((**(this as *const *const ISpeechRecoResult_Vtbl)).PhraseInfo)(this, phraseinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Alternates(this: *mut core::ffi::c_void, requestcount: i32, startelement: i32, elements: i32, alternates: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoResult", "Alternates", 12usize, |this| ((**(this as *const *const ISpeechRecoResult_Vtbl)).Alternates)(this, requestcount, startelement, elements, alternates), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Audio(this: *mut core::ffi::c_void, startelement: i32, elements: i32, stream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoResult", "Audio", 13usize, |this| ((**(this as *const *const ISpeechRecoResult_Vtbl)).Audio)(this, startelement, elements, stream), |result| Some(*result))
+        }
+        unsafe extern "system" fn SpeakAudio(this: *mut core::ffi::c_void, startelement: i32, elements: i32, flags: SpeechVoiceSpeakFlags, streamnumber: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoResult", "SpeakAudio", 14usize, |this| ((**(this as *const *const ISpeechRecoResult_Vtbl)).SpeakAudio)(this, startelement, elements, flags, streamnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveToMemory(this: *mut core::ffi::c_void, resultblock: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoResult", "SaveToMemory", 15usize, |this| ((**(this as *const *const ISpeechRecoResult_Vtbl)).SaveToMemory)(this, resultblock), |result| Some(*result))
+        }
+        unsafe extern "system" fn DiscardResultInfo(this: *mut core::ffi::c_void, valuetypes: SpeechDiscardType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoResult", "DiscardResultInfo", 16usize, |this| ((**(this as *const *const ISpeechRecoResult_Vtbl)).DiscardResultInfo)(this, valuetypes), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            RecoContext,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RecoContext: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Times,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Times: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_AudioFormat,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_AudioFormat: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AudioFormat,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AudioFormat: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PhraseInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PhraseInfo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Alternates,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Alternates: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Audio,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Audio: 0,
+            SpeakAudio,
+            SaveToMemory,
+            DiscardResultInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecoResult as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechRecoResult2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetTextFeedback(this: *mut core::ffi::c_void, feedback: core::mem::MaybeUninit<windows_core::BSTR>, wassuccessful: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoResult2", "SetTextFeedback", 17usize, |this| ((**(this as *const *const ISpeechRecoResult2_Vtbl)).SetTextFeedback)(this, feedback, wassuccessful), |result| Some(*result))
+        }
+        Self { base__: <ISpeechRecoResult_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetTextFeedback }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecoResult2 as windows_core::Interface>::IID || <ISpeechRecoResult_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechRecoResultDispatch_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RecoContext(this: *mut core::ffi::c_void, recocontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoResultDispatch", "RecoContext", 7usize, |this| ((**(this as *const *const ISpeechRecoResultDispatch_Vtbl)).RecoContext)(this, recocontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Times(this: *mut core::ffi::c_void, times: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoResultDispatch", "Times", 8usize, |this| ((**(this as *const *const ISpeechRecoResultDispatch_Vtbl)).Times)(this, times), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_AudioFormat(this: *mut core::ffi::c_void, format: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoResultDispatch", "putref_AudioFormat", 9usize, |this| ((**(this as *const *const ISpeechRecoResultDispatch_Vtbl)).putref_AudioFormat)(this, format), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AudioFormat(this: *mut core::ffi::c_void, format: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoResultDispatch", "AudioFormat", 10usize, |this| ((**(this as *const *const ISpeechRecoResultDispatch_Vtbl)).AudioFormat)(this, format), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PhraseInfo(this: *mut core::ffi::c_void, phraseinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoResultDispatch", "PhraseInfo", 11usize, |this| ((**(this as *const *const ISpeechRecoResultDispatch_Vtbl)).PhraseInfo)(this, phraseinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Alternates(this: *mut core::ffi::c_void, requestcount: i32, startelement: i32, elements: i32, alternates: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoResultDispatch", "Alternates", 12usize, |this| ((**(this as *const *const ISpeechRecoResultDispatch_Vtbl)).Alternates)(this, requestcount, startelement, elements, alternates), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Audio(this: *mut core::ffi::c_void, startelement: i32, elements: i32, stream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoResultDispatch", "Audio", 13usize, |this| ((**(this as *const *const ISpeechRecoResultDispatch_Vtbl)).Audio)(this, startelement, elements, stream), |result| Some(*result))
+        }
+        unsafe extern "system" fn SpeakAudio(this: *mut core::ffi::c_void, startelement: i32, elements: i32, flags: SpeechVoiceSpeakFlags, streamnumber: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoResultDispatch", "SpeakAudio", 14usize, |this| ((**(this as *const *const ISpeechRecoResultDispatch_Vtbl)).SpeakAudio)(this, startelement, elements, flags, streamnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveToMemory(this: *mut core::ffi::c_void, resultblock: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoResultDispatch", "SaveToMemory", 15usize, |this| ((**(this as *const *const ISpeechRecoResultDispatch_Vtbl)).SaveToMemory)(this, resultblock), |result| Some(*result))
+        }
+        unsafe extern "system" fn DiscardResultInfo(this: *mut core::ffi::c_void, valuetypes: SpeechDiscardType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoResultDispatch", "DiscardResultInfo", 16usize, |this| ((**(this as *const *const ISpeechRecoResultDispatch_Vtbl)).DiscardResultInfo)(this, valuetypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetXMLResult(this: *mut core::ffi::c_void, options: SPXMLRESULTOPTIONS, presult: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoResultDispatch", "GetXMLResult", 17usize, |this| ((**(this as *const *const ISpeechRecoResultDispatch_Vtbl)).GetXMLResult)(this, options, presult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetXMLErrorInfo(this: *mut core::ffi::c_void, linenumber: *mut i32, scriptline: *mut core::mem::MaybeUninit<windows_core::BSTR>, source: *mut core::mem::MaybeUninit<windows_core::BSTR>, description: *mut core::mem::MaybeUninit<windows_core::BSTR>, resultcode: *mut windows_core::HRESULT, iserror: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoResultDispatch", "GetXMLErrorInfo", 18usize, |this| ((**(this as *const *const ISpeechRecoResultDispatch_Vtbl)).GetXMLErrorInfo)(this, linenumber, scriptline, source, description, resultcode, iserror), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTextFeedback(this: *mut core::ffi::c_void, feedback: core::mem::MaybeUninit<windows_core::BSTR>, wassuccessful: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoResultDispatch", "SetTextFeedback", 19usize, |this| ((**(this as *const *const ISpeechRecoResultDispatch_Vtbl)).SetTextFeedback)(this, feedback, wassuccessful), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            RecoContext,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RecoContext: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Times,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Times: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_AudioFormat,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_AudioFormat: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AudioFormat,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AudioFormat: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PhraseInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PhraseInfo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Alternates,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Alternates: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Audio,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Audio: 0,
+            SpeakAudio,
+            SaveToMemory,
+            DiscardResultInfo,
+            GetXMLResult,
+            GetXMLErrorInfo,
+            SetTextFeedback,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecoResultDispatch as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechRecoResultTimes_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StreamTime(this: *mut core::ffi::c_void, time: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoResultTimes", "StreamTime", 7usize, |this| ((**(this as *const *const ISpeechRecoResultTimes_Vtbl)).StreamTime)(this, time), |result| Some(*result))
+        }
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, length: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoResultTimes", "Length", 8usize, |this| ((**(this as *const *const ISpeechRecoResultTimes_Vtbl)).Length)(this, length), |result| Some(*result))
+        }
+        unsafe extern "system" fn TickCount(this: *mut core::ffi::c_void, tickcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoResultTimes", "TickCount", 9usize, |this| ((**(this as *const *const ISpeechRecoResultTimes_Vtbl)).TickCount)(this, tickcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn OffsetFromStart(this: *mut core::ffi::c_void, offsetfromstart: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecoResultTimes", "OffsetFromStart", 10usize, |this| ((**(this as *const *const ISpeechRecoResultTimes_Vtbl)).OffsetFromStart)(this, offsetfromstart), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StreamTime, Length, TickCount, OffsetFromStart }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecoResultTimes as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechRecognizer_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_Recognizer(this: *mut core::ffi::c_void, recognizer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "putref_Recognizer", 7usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).putref_Recognizer)(this, recognizer), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Recognizer(this: *mut core::ffi::c_void, recognizer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "Recognizer", 8usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).Recognizer)(this, recognizer), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowAudioInputFormatChangesOnNextSet(this: *mut core::ffi::c_void, allow: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "SetAllowAudioInputFormatChangesOnNextSet", 9usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).SetAllowAudioInputFormatChangesOnNextSet)(this, allow), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowAudioInputFormatChangesOnNextSet(this: *mut core::ffi::c_void, allow: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "AllowAudioInputFormatChangesOnNextSet", 10usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).AllowAudioInputFormatChangesOnNextSet)(this, allow), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_AudioInput(this: *mut core::ffi::c_void, audioinput: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "putref_AudioInput", 11usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).putref_AudioInput)(this, audioinput), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AudioInput(this: *mut core::ffi::c_void, audioinput: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "AudioInput", 12usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).AudioInput)(this, audioinput), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_AudioInputStream(this: *mut core::ffi::c_void, audioinputstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "putref_AudioInputStream", 13usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).putref_AudioInputStream)(this, audioinputstream), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AudioInputStream(this: *mut core::ffi::c_void, audioinputstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "AudioInputStream", 14usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).AudioInputStream)(this, audioinputstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsShared(this: *mut core::ffi::c_void, shared: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "IsShared", 15usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).IsShared)(this, shared), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetState(this: *mut core::ffi::c_void, state: SpeechRecognizerState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "SetState", 16usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).SetState)(this, state), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, state: *mut SpeechRecognizerState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "State", 17usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).State)(this, state), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, status: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "Status", 18usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).Status)(this, status), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_Profile(this: *mut core::ffi::c_void, profile: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "putref_Profile", 19usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).putref_Profile)(this, profile), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Profile(this: *mut core::ffi::c_void, profile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "Profile", 20usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).Profile)(this, profile), |result| Some(*result))
+        }
+        unsafe extern "system" fn EmulateRecognition(this: *mut core::ffi::c_void, textelements: core::mem::MaybeUninit<windows_core::VARIANT>, elementdisplayattributes: *const core::mem::MaybeUninit<windows_core::VARIANT>, languageid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "EmulateRecognition", 21usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).EmulateRecognition)(this, textelements, elementdisplayattributes, languageid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateRecoContext(this: *mut core::ffi::c_void, newcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "CreateRecoContext", 22usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).CreateRecoContext)(this, newcontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFormat(this: *mut core::ffi::c_void, r#type: SpeechFormatType, format: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "GetFormat", 23usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).GetFormat)(this, r#type, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPropertyNumber(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, value: i32, supported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "SetPropertyNumber", 24usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).SetPropertyNumber)(this, name, value, supported), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyNumber(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, value: *mut i32, supported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "GetPropertyNumber", 25usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).GetPropertyNumber)(this, name, value, supported), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPropertyString(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, value: core::mem::MaybeUninit<windows_core::BSTR>, supported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "SetPropertyString", 26usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).SetPropertyString)(this, name, value, supported), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyString(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, value: *mut core::mem::MaybeUninit<windows_core::BSTR>, supported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "GetPropertyString", 27usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).GetPropertyString)(this, name, value, supported), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUISupported(this: *mut core::ffi::c_void, typeofui: core::mem::MaybeUninit<windows_core::BSTR>, extradata: *const core::mem::MaybeUninit<windows_core::VARIANT>, supported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "IsUISupported", 28usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).IsUISupported)(this, typeofui, extradata, supported), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayUI(this: *mut core::ffi::c_void, hwndparent: i32, title: core::mem::MaybeUninit<windows_core::BSTR>, typeofui: core::mem::MaybeUninit<windows_core::BSTR>, extradata: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "DisplayUI", 29usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).DisplayUI)(this, hwndparent, title, typeofui, extradata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetRecognizers(this: *mut core::ffi::c_void, requiredattributes: core::mem::MaybeUninit<windows_core::BSTR>, optionalattributes: core::mem::MaybeUninit<windows_core::BSTR>, objecttokens: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "GetRecognizers", 30usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).GetRecognizers)(this, requiredattributes, optionalattributes, objecttokens), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetAudioInputs(this: *mut core::ffi::c_void, requiredattributes: core::mem::MaybeUninit<windows_core::BSTR>, optionalattributes: core::mem::MaybeUninit<windows_core::BSTR>, objecttokens: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "GetAudioInputs", 31usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).GetAudioInputs)(this, requiredattributes, optionalattributes, objecttokens), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetProfiles(this: *mut core::ffi::c_void, requiredattributes: core::mem::MaybeUninit<windows_core::BSTR>, optionalattributes: core::mem::MaybeUninit<windows_core::BSTR>, objecttokens: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizer", "GetProfiles", 32usize, |this| ((**(this as *const *const ISpeechRecognizer_Vtbl)).GetProfiles)(this, requiredattributes, optionalattributes, objecttokens), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_Recognizer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_Recognizer: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Recognizer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Recognizer: 0,
+            SetAllowAudioInputFormatChangesOnNextSet,
+            AllowAudioInputFormatChangesOnNextSet,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_AudioInput,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_AudioInput: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AudioInput,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AudioInput: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_AudioInputStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_AudioInputStream: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AudioInputStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AudioInputStream: 0,
+            IsShared,
+            SetState,
+            State,
+            #[cfg(feature = "Win32_System_Com")]
+            Status,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Status: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_Profile,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_Profile: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Profile,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Profile: 0,
+            EmulateRecognition,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateRecoContext,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateRecoContext: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFormat,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFormat: 0,
+            SetPropertyNumber,
+            GetPropertyNumber,
+            SetPropertyString,
+            GetPropertyString,
+            IsUISupported,
+            DisplayUI,
+            #[cfg(feature = "Win32_System_Com")]
+            GetRecognizers,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetRecognizers: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetAudioInputs,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetAudioInputs: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetProfiles,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetProfiles: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecognizer as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechRecognizerStatus_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AudioStatus(this: *mut core::ffi::c_void, audiostatus: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizerStatus", "AudioStatus", 7usize, |this| ((**(this as *const *const ISpeechRecognizerStatus_Vtbl)).AudioStatus)(this, audiostatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentStreamPosition(this: *mut core::ffi::c_void, pcurrentstreampos: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizerStatus", "CurrentStreamPosition", 8usize, |this| ((**(this as *const *const ISpeechRecognizerStatus_Vtbl)).CurrentStreamPosition)(this, pcurrentstreampos), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentStreamNumber(this: *mut core::ffi::c_void, streamnumber: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizerStatus", "CurrentStreamNumber", 9usize, |this| ((**(this as *const *const ISpeechRecognizerStatus_Vtbl)).CurrentStreamNumber)(this, streamnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfActiveRules(this: *mut core::ffi::c_void, numberofactiverules: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizerStatus", "NumberOfActiveRules", 10usize, |this| ((**(this as *const *const ISpeechRecognizerStatus_Vtbl)).NumberOfActiveRules)(this, numberofactiverules), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClsidEngine(this: *mut core::ffi::c_void, clsidengine: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizerStatus", "ClsidEngine", 11usize, |this| ((**(this as *const *const ISpeechRecognizerStatus_Vtbl)).ClsidEngine)(this, clsidengine), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportedLanguages(this: *mut core::ffi::c_void, supportedlanguages: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechRecognizerStatus", "SupportedLanguages", 12usize, |this| ((**(this as *const *const ISpeechRecognizerStatus_Vtbl)).SupportedLanguages)(this, supportedlanguages), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            AudioStatus,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AudioStatus: 0,
+            CurrentStreamPosition,
+            CurrentStreamNumber,
+            NumberOfActiveRules,
+            ClsidEngine,
+            SupportedLanguages,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechRecognizerStatus as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechResourceLoader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LoadResource(this: *mut core::ffi::c_void, bstrresourceuri: core::mem::MaybeUninit<windows_core::BSTR>, falwaysreload: super::super::Foundation::VARIANT_BOOL, pstream: *mut *mut core::ffi::c_void, pbstrmimetype: *mut core::mem::MaybeUninit<windows_core::BSTR>, pfmodified: *mut super::super::Foundation::VARIANT_BOOL, pbstrredirecturl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechResourceLoader", "LoadResource", 7usize, |this| ((**(this as *const *const ISpeechResourceLoader_Vtbl)).LoadResource)(this, bstrresourceuri, falwaysreload, pstream, pbstrmimetype, pfmodified, pbstrredirecturl), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocalCopy(this: *mut core::ffi::c_void, bstrresourceuri: core::mem::MaybeUninit<windows_core::BSTR>, pbstrlocalpath: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrmimetype: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrredirecturl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechResourceLoader", "GetLocalCopy", 8usize, |this| ((**(this as *const *const ISpeechResourceLoader_Vtbl)).GetLocalCopy)(this, bstrresourceuri, pbstrlocalpath, pbstrmimetype, pbstrredirecturl), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseLocalCopy(this: *mut core::ffi::c_void, pbstrlocalpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechResourceLoader", "ReleaseLocalCopy", 9usize, |this| ((**(this as *const *const ISpeechResourceLoader_Vtbl)).ReleaseLocalCopy)(this, pbstrlocalpath), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LoadResource, GetLocalCopy, ReleaseLocalCopy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechResourceLoader as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechTextSelectionInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetActiveOffset(this: *mut core::ffi::c_void, activeoffset: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechTextSelectionInformation", "SetActiveOffset", 7usize, |this| ((**(this as *const *const ISpeechTextSelectionInformation_Vtbl)).SetActiveOffset)(this, activeoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActiveOffset(this: *mut core::ffi::c_void, activeoffset: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechTextSelectionInformation", "ActiveOffset", 8usize, |this| ((**(this as *const *const ISpeechTextSelectionInformation_Vtbl)).ActiveOffset)(this, activeoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActiveLength(this: *mut core::ffi::c_void, activelength: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechTextSelectionInformation", "SetActiveLength", 9usize, |this| ((**(this as *const *const ISpeechTextSelectionInformation_Vtbl)).SetActiveLength)(this, activelength), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActiveLength(this: *mut core::ffi::c_void, activelength: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechTextSelectionInformation", "ActiveLength", 10usize, |this| ((**(this as *const *const ISpeechTextSelectionInformation_Vtbl)).ActiveLength)(this, activelength), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelectionOffset(this: *mut core::ffi::c_void, selectionoffset: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechTextSelectionInformation", "SetSelectionOffset", 11usize, |this| ((**(this as *const *const ISpeechTextSelectionInformation_Vtbl)).SetSelectionOffset)(this, selectionoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectionOffset(this: *mut core::ffi::c_void, selectionoffset: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechTextSelectionInformation", "SelectionOffset", 12usize, |this| ((**(this as *const *const ISpeechTextSelectionInformation_Vtbl)).SelectionOffset)(this, selectionoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelectionLength(this: *mut core::ffi::c_void, selectionlength: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechTextSelectionInformation", "SetSelectionLength", 13usize, |this| ((**(this as *const *const ISpeechTextSelectionInformation_Vtbl)).SetSelectionLength)(this, selectionlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectionLength(this: *mut core::ffi::c_void, selectionlength: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechTextSelectionInformation", "SelectionLength", 14usize, |this| ((**(this as *const *const ISpeechTextSelectionInformation_Vtbl)).SelectionLength)(this, selectionlength), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetActiveOffset,
+            ActiveOffset,
+            SetActiveLength,
+            ActiveLength,
+            SetSelectionOffset,
+            SelectionOffset,
+            SetSelectionLength,
+            SelectionLength,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechTextSelectionInformation as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechVoice_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, status: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "Status", 7usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).Status)(this, status), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Voice(this: *mut core::ffi::c_void, voice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "Voice", 8usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).Voice)(this, voice), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_Voice(this: *mut core::ffi::c_void, voice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "putref_Voice", 9usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).putref_Voice)(this, voice), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AudioOutput(this: *mut core::ffi::c_void, audiooutput: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "AudioOutput", 10usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).AudioOutput)(this, audiooutput), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_AudioOutput(this: *mut core::ffi::c_void, audiooutput: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "putref_AudioOutput", 11usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).putref_AudioOutput)(this, audiooutput), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AudioOutputStream(this: *mut core::ffi::c_void, audiooutputstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "AudioOutputStream", 12usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).AudioOutputStream)(this, audiooutputstream), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_AudioOutputStream(this: *mut core::ffi::c_void, audiooutputstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "putref_AudioOutputStream", 13usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).putref_AudioOutputStream)(this, audiooutputstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rate(this: *mut core::ffi::c_void, rate: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "Rate", 14usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).Rate)(this, rate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRate(this: *mut core::ffi::c_void, rate: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "SetRate", 15usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).SetRate)(this, rate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Volume(this: *mut core::ffi::c_void, volume: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "Volume", 16usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).Volume)(this, volume), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVolume(this: *mut core::ffi::c_void, volume: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "SetVolume", 17usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).SetVolume)(this, volume), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowAudioOutputFormatChangesOnNextSet(this: *mut core::ffi::c_void, allow: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "SetAllowAudioOutputFormatChangesOnNextSet", 18usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).SetAllowAudioOutputFormatChangesOnNextSet)(this, allow), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowAudioOutputFormatChangesOnNextSet(this: *mut core::ffi::c_void, allow: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "AllowAudioOutputFormatChangesOnNextSet", 19usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).AllowAudioOutputFormatChangesOnNextSet)(this, allow), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventInterests(this: *mut core::ffi::c_void, eventinterestflags: *mut SpeechVoiceEvents) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "EventInterests", 20usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).EventInterests)(this, eventinterestflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventInterests(this: *mut core::ffi::c_void, eventinterestflags: SpeechVoiceEvents) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "SetEventInterests", 21usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).SetEventInterests)(this, eventinterestflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPriority(this: *mut core::ffi::c_void, priority: SpeechVoicePriority) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "SetPriority", 22usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).SetPriority)(this, priority), |result| Some(*result))
+        }
+        unsafe extern "system" fn Priority(this: *mut core::ffi::c_void, priority: *mut SpeechVoicePriority) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "Priority", 23usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).Priority)(this, priority), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlertBoundary(this: *mut core::ffi::c_void, boundary: SpeechVoiceEvents) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "SetAlertBoundary", 24usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).SetAlertBoundary)(this, boundary), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlertBoundary(this: *mut core::ffi::c_void, boundary: *mut SpeechVoiceEvents) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "AlertBoundary", 25usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).AlertBoundary)(this, boundary), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSynchronousSpeakTimeout(this: *mut core::ffi::c_void, mstimeout: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "SetSynchronousSpeakTimeout", 26usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).SetSynchronousSpeakTimeout)(this, mstimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn SynchronousSpeakTimeout(this: *mut core::ffi::c_void, mstimeout: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "SynchronousSpeakTimeout", 27usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).SynchronousSpeakTimeout)(this, mstimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn Speak(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::BSTR>, flags: SpeechVoiceSpeakFlags, streamnumber: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "Speak", 28usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).Speak)(this, text, flags, streamnumber), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SpeakStream(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, flags: SpeechVoiceSpeakFlags, streamnumber: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "SpeakStream", 29usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).SpeakStream)(this, stream, flags, streamnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "Pause", 30usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "Resume", 31usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, r#type: core::mem::MaybeUninit<windows_core::BSTR>, numitems: i32, numskipped: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "Skip", 32usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).Skip)(this, r#type, numitems, numskipped), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetVoices(this: *mut core::ffi::c_void, requiredattributes: core::mem::MaybeUninit<windows_core::BSTR>, optionalattributes: core::mem::MaybeUninit<windows_core::BSTR>, objecttokens: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "GetVoices", 33usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).GetVoices)(this, requiredattributes, optionalattributes, objecttokens), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetAudioOutputs(this: *mut core::ffi::c_void, requiredattributes: core::mem::MaybeUninit<windows_core::BSTR>, optionalattributes: core::mem::MaybeUninit<windows_core::BSTR>, objecttokens: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "GetAudioOutputs", 34usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).GetAudioOutputs)(this, requiredattributes, optionalattributes, objecttokens), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitUntilDone(this: *mut core::ffi::c_void, mstimeout: i32, done: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "WaitUntilDone", 35usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).WaitUntilDone)(this, mstimeout, done), |result| Some(*result))
+        }
+        unsafe extern "system" fn SpeakCompleteEvent(this: *mut core::ffi::c_void, handle: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "SpeakCompleteEvent", 36usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).SpeakCompleteEvent)(this, handle), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUISupported(this: *mut core::ffi::c_void, typeofui: core::mem::MaybeUninit<windows_core::BSTR>, extradata: *const core::mem::MaybeUninit<windows_core::VARIANT>, supported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "IsUISupported", 37usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).IsUISupported)(this, typeofui, extradata, supported), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayUI(this: *mut core::ffi::c_void, hwndparent: i32, title: core::mem::MaybeUninit<windows_core::BSTR>, typeofui: core::mem::MaybeUninit<windows_core::BSTR>, extradata: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoice", "DisplayUI", 38usize, |this| ((**(this as *const *const ISpeechVoice_Vtbl)).DisplayUI)(this, hwndparent, title, typeofui, extradata), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Status,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Status: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Voice,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Voice: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_Voice,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_Voice: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AudioOutput,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AudioOutput: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_AudioOutput,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_AudioOutput: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AudioOutputStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AudioOutputStream: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_AudioOutputStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_AudioOutputStream: 0,
+            Rate,
+            SetRate,
+            Volume,
+            SetVolume,
+            SetAllowAudioOutputFormatChangesOnNextSet,
+            AllowAudioOutputFormatChangesOnNextSet,
+            EventInterests,
+            SetEventInterests,
+            SetPriority,
+            Priority,
+            SetAlertBoundary,
+            AlertBoundary,
+            SetSynchronousSpeakTimeout,
+            SynchronousSpeakTimeout,
+            Speak,
+            #[cfg(feature = "Win32_System_Com")]
+            SpeakStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SpeakStream: 0,
+            Pause,
+            Resume,
+            Skip,
+            #[cfg(feature = "Win32_System_Com")]
+            GetVoices,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetVoices: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetAudioOutputs,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetAudioOutputs: 0,
+            WaitUntilDone,
+            SpeakCompleteEvent,
+            IsUISupported,
+            DisplayUI,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechVoice as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechVoiceStatus_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentStreamNumber(this: *mut core::ffi::c_void, streamnumber: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoiceStatus", "CurrentStreamNumber", 7usize, |this| ((**(this as *const *const ISpeechVoiceStatus_Vtbl)).CurrentStreamNumber)(this, streamnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastStreamNumberQueued(this: *mut core::ffi::c_void, streamnumber: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoiceStatus", "LastStreamNumberQueued", 8usize, |this| ((**(this as *const *const ISpeechVoiceStatus_Vtbl)).LastStreamNumberQueued)(this, streamnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastHResult(this: *mut core::ffi::c_void, hresult: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoiceStatus", "LastHResult", 9usize, |this| ((**(this as *const *const ISpeechVoiceStatus_Vtbl)).LastHResult)(this, hresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunningState(this: *mut core::ffi::c_void, state: *mut SpeechRunState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoiceStatus", "RunningState", 10usize, |this| ((**(this as *const *const ISpeechVoiceStatus_Vtbl)).RunningState)(this, state), |result| Some(*result))
+        }
+        unsafe extern "system" fn InputWordPosition(this: *mut core::ffi::c_void, position: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoiceStatus", "InputWordPosition", 11usize, |this| ((**(this as *const *const ISpeechVoiceStatus_Vtbl)).InputWordPosition)(this, position), |result| Some(*result))
+        }
+        unsafe extern "system" fn InputWordLength(this: *mut core::ffi::c_void, length: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoiceStatus", "InputWordLength", 12usize, |this| ((**(this as *const *const ISpeechVoiceStatus_Vtbl)).InputWordLength)(this, length), |result| Some(*result))
+        }
+        unsafe extern "system" fn InputSentencePosition(this: *mut core::ffi::c_void, position: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoiceStatus", "InputSentencePosition", 13usize, |this| ((**(this as *const *const ISpeechVoiceStatus_Vtbl)).InputSentencePosition)(this, position), |result| Some(*result))
+        }
+        unsafe extern "system" fn InputSentenceLength(this: *mut core::ffi::c_void, length: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoiceStatus", "InputSentenceLength", 14usize, |this| ((**(this as *const *const ISpeechVoiceStatus_Vtbl)).InputSentenceLength)(this, length), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastBookmark(this: *mut core::ffi::c_void, bookmark: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoiceStatus", "LastBookmark", 15usize, |this| ((**(this as *const *const ISpeechVoiceStatus_Vtbl)).LastBookmark)(this, bookmark), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastBookmarkId(this: *mut core::ffi::c_void, bookmarkid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoiceStatus", "LastBookmarkId", 16usize, |this| ((**(this as *const *const ISpeechVoiceStatus_Vtbl)).LastBookmarkId)(this, bookmarkid), |result| Some(*result))
+        }
+        unsafe extern "system" fn PhonemeId(this: *mut core::ffi::c_void, phoneid: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoiceStatus", "PhonemeId", 17usize, |this| ((**(this as *const *const ISpeechVoiceStatus_Vtbl)).PhonemeId)(this, phoneid), |result| Some(*result))
+        }
+        unsafe extern "system" fn VisemeId(this: *mut core::ffi::c_void, visemeid: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechVoiceStatus", "VisemeId", 18usize, |this| ((**(this as *const *const ISpeechVoiceStatus_Vtbl)).VisemeId)(this, visemeid), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CurrentStreamNumber,
+            LastStreamNumberQueued,
+            LastHResult,
+            RunningState,
+            InputWordPosition,
+            InputWordLength,
+            InputSentencePosition,
+            InputSentenceLength,
+            LastBookmark,
+            LastBookmarkId,
+            PhonemeId,
+            VisemeId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechVoiceStatus as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechWaveFormatEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FormatTag(this: *mut core::ffi::c_void, formattag: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechWaveFormatEx", "FormatTag", 7usize, |this| ((**(this as *const *const ISpeechWaveFormatEx_Vtbl)).FormatTag)(this, formattag), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFormatTag(this: *mut core::ffi::c_void, formattag: i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechWaveFormatEx", "SetFormatTag", 8usize, |this| ((**(this as *const *const ISpeechWaveFormatEx_Vtbl)).SetFormatTag)(this, formattag), |result| Some(*result))
+        }
+        unsafe extern "system" fn Channels(this: *mut core::ffi::c_void, channels: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechWaveFormatEx", "Channels", 9usize, |this| ((**(this as *const *const ISpeechWaveFormatEx_Vtbl)).Channels)(this, channels), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetChannels(this: *mut core::ffi::c_void, channels: i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechWaveFormatEx", "SetChannels", 10usize, |this| ((**(this as *const *const ISpeechWaveFormatEx_Vtbl)).SetChannels)(this, channels), |result| Some(*result))
+        }
+        unsafe extern "system" fn SamplesPerSec(this: *mut core::ffi::c_void, samplespersec: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechWaveFormatEx", "SamplesPerSec", 11usize, |this| ((**(this as *const *const ISpeechWaveFormatEx_Vtbl)).SamplesPerSec)(this, samplespersec), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSamplesPerSec(this: *mut core::ffi::c_void, samplespersec: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechWaveFormatEx", "SetSamplesPerSec", 12usize, |this| ((**(this as *const *const ISpeechWaveFormatEx_Vtbl)).SetSamplesPerSec)(this, samplespersec), |result| Some(*result))
+        }
+        unsafe extern "system" fn AvgBytesPerSec(this: *mut core::ffi::c_void, avgbytespersec: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechWaveFormatEx", "AvgBytesPerSec", 13usize, |this| ((**(this as *const *const ISpeechWaveFormatEx_Vtbl)).AvgBytesPerSec)(this, avgbytespersec), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAvgBytesPerSec(this: *mut core::ffi::c_void, avgbytespersec: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechWaveFormatEx", "SetAvgBytesPerSec", 14usize, |this| ((**(this as *const *const ISpeechWaveFormatEx_Vtbl)).SetAvgBytesPerSec)(this, avgbytespersec), |result| Some(*result))
+        }
+        unsafe extern "system" fn BlockAlign(this: *mut core::ffi::c_void, blockalign: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechWaveFormatEx", "BlockAlign", 15usize, |this| ((**(this as *const *const ISpeechWaveFormatEx_Vtbl)).BlockAlign)(this, blockalign), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlockAlign(this: *mut core::ffi::c_void, blockalign: i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechWaveFormatEx", "SetBlockAlign", 16usize, |this| ((**(this as *const *const ISpeechWaveFormatEx_Vtbl)).SetBlockAlign)(this, blockalign), |result| Some(*result))
+        }
+        unsafe extern "system" fn BitsPerSample(this: *mut core::ffi::c_void, bitspersample: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechWaveFormatEx", "BitsPerSample", 17usize, |this| ((**(this as *const *const ISpeechWaveFormatEx_Vtbl)).BitsPerSample)(this, bitspersample), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBitsPerSample(this: *mut core::ffi::c_void, bitspersample: i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechWaveFormatEx", "SetBitsPerSample", 18usize, |this| ((**(this as *const *const ISpeechWaveFormatEx_Vtbl)).SetBitsPerSample)(this, bitspersample), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtraData(this: *mut core::ffi::c_void, extradata: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechWaveFormatEx", "ExtraData", 19usize, |this| ((**(this as *const *const ISpeechWaveFormatEx_Vtbl)).ExtraData)(this, extradata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExtraData(this: *mut core::ffi::c_void, extradata: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechWaveFormatEx", "SetExtraData", 20usize, |this| ((**(this as *const *const ISpeechWaveFormatEx_Vtbl)).SetExtraData)(this, extradata), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FormatTag,
+            SetFormatTag,
+            Channels,
+            SetChannels,
+            SamplesPerSec,
+            SetSamplesPerSec,
+            AvgBytesPerSec,
+            SetAvgBytesPerSec,
+            BlockAlign,
+            SetBlockAlign,
+            BitsPerSample,
+            SetBitsPerSample,
+            ExtraData,
+            SetExtraData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechWaveFormatEx as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpeechXMLRecoResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetXMLResult(this: *mut core::ffi::c_void, options: SPXMLRESULTOPTIONS, presult: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechXMLRecoResult", "GetXMLResult", 17usize, |this| ((**(this as *const *const ISpeechXMLRecoResult_Vtbl)).GetXMLResult)(this, options, presult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetXMLErrorInfo(this: *mut core::ffi::c_void, linenumber: *mut i32, scriptline: *mut core::mem::MaybeUninit<windows_core::BSTR>, source: *mut core::mem::MaybeUninit<windows_core::BSTR>, description: *mut core::mem::MaybeUninit<windows_core::BSTR>, resultcode: *mut i32, iserror: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechXMLRecoResult", "GetXMLErrorInfo", 18usize, |this| ((**(this as *const *const ISpeechXMLRecoResult_Vtbl)).GetXMLErrorInfo)(this, linenumber, scriptline, source, description, resultcode, iserror), |result| Some(*result))
+        }
+        Self { base__: <ISpeechRecoResult_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetXMLResult, GetXMLErrorInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechXMLRecoResult as windows_core::Interface>::IID || <ISpeechRecoResult_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for _ISpPrivateEngineCall_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CallEngine(this: *mut core::ffi::c_void, pcallframe: *mut core::ffi::c_void, ulcallframesize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISpPrivateEngineCall", "CallEngine", 3usize, |this| ((**(this as *const *const _ISpPrivateEngineCall_Vtbl)).CallEngine)(this, pcallframe, ulcallframesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallEngineEx(this: *mut core::ffi::c_void, pinframe: *const core::ffi::c_void, ulinframesize: u32, ppcomemoutframe: *mut *mut core::ffi::c_void, puloutframesize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISpPrivateEngineCall", "CallEngineEx", 4usize, |this| ((**(this as *const *const _ISpPrivateEngineCall_Vtbl)).CallEngineEx)(this, pinframe, ulinframesize, ppcomemoutframe, puloutframesize), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CallEngine, CallEngineEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<_ISpPrivateEngineCall as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for _ISpeechRecoContextEvents_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<_ISpeechRecoContextEvents as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for _ISpeechVoiceEvents_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<_ISpeechVoiceEvents as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -38,6 +38,7 @@ pub struct MF_MEDIASOURCE_STATUS_INFO(pub i32);
 impl windows_core::TypeKind for MF_MEDIASOURCE_STATUS_INFO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_MEDIASOURCE_STATUS_INFO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_MEDIASOURCE_STATUS_INFO").field(&self.0).finish()
@@ -49,13 +50,15 @@ pub struct MF_TRANSFER_VIDEO_FRAME_FLAGS(pub i32);
 impl windows_core::TypeKind for MF_TRANSFER_VIDEO_FRAME_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MF_TRANSFER_VIDEO_FRAME_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MF_TRANSFER_VIDEO_FRAME_FLAGS").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CapturedMetadataExposureCompensation {
     pub Flags: u64,
     pub Value: i32,
@@ -69,7 +72,8 @@ impl Default for CapturedMetadataExposureCompensation {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CapturedMetadataISOGains {
     pub AnalogGain: f32,
     pub DigitalGain: f32,
@@ -83,7 +87,8 @@ impl Default for CapturedMetadataISOGains {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CapturedMetadataWhiteBalanceGains {
     pub R: f32,
     pub G: f32,
@@ -98,7 +103,8 @@ impl Default for CapturedMetadataWhiteBalanceGains {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FaceCharacterization {
     pub BlinkScoreLeft: u32,
     pub BlinkScoreRight: u32,
@@ -114,7 +120,8 @@ impl Default for FaceCharacterization {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FaceCharacterizationBlobHeader {
     pub Size: u32,
     pub Count: u32,
@@ -128,7 +135,8 @@ impl Default for FaceCharacterizationBlobHeader {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FaceRectInfo {
     pub Region: super::super::Foundation::RECT,
     pub confidenceLevel: i32,
@@ -142,7 +150,8 @@ impl Default for FaceRectInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FaceRectInfoBlobHeader {
     pub Size: u32,
     pub Count: u32,
@@ -156,7 +165,8 @@ impl Default for FaceRectInfoBlobHeader {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HistogramBlobHeader {
     pub Size: u32,
     pub Histograms: u32,
@@ -170,7 +180,8 @@ impl Default for HistogramBlobHeader {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HistogramDataHeader {
     pub Size: u32,
     pub ChannelMask: u32,
@@ -185,7 +196,8 @@ impl Default for HistogramDataHeader {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HistogramGrid {
     pub Width: u32,
     pub Height: u32,
@@ -200,7 +212,8 @@ impl Default for HistogramGrid {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HistogramHeader {
     pub Size: u32,
     pub Bins: u32,
@@ -217,7 +230,8 @@ impl Default for HistogramHeader {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MetadataTimeStamps {
     pub Flags: u32,
     pub Device: i64,
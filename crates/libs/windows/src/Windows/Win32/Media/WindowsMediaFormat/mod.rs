@@ -4798,6 +4798,7 @@ pub struct NETSOURCE_URLCREDPOLICY_SETTINGS(pub i32);
 impl windows_core::TypeKind for NETSOURCE_URLCREDPOLICY_SETTINGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NETSOURCE_URLCREDPOLICY_SETTINGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NETSOURCE_URLCREDPOLICY_SETTINGS").field(&self.0).finish()
@@ -4809,6 +4810,7 @@ pub struct WEBSTREAM_SAMPLE_TYPE(pub i32);
 impl windows_core::TypeKind for WEBSTREAM_SAMPLE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WEBSTREAM_SAMPLE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WEBSTREAM_SAMPLE_TYPE").field(&self.0).finish()
@@ -4820,6 +4822,7 @@ pub struct WMT_ATTR_DATATYPE(pub i32);
 impl windows_core::TypeKind for WMT_ATTR_DATATYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMT_ATTR_DATATYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMT_ATTR_DATATYPE").field(&self.0).finish()
@@ -4831,6 +4834,7 @@ pub struct WMT_ATTR_IMAGETYPE(pub i32);
 impl windows_core::TypeKind for WMT_ATTR_IMAGETYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMT_ATTR_IMAGETYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMT_ATTR_IMAGETYPE").field(&self.0).finish()
@@ -4842,6 +4846,7 @@ pub struct WMT_CODEC_INFO_TYPE(pub i32);
 impl windows_core::TypeKind for WMT_CODEC_INFO_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMT_CODEC_INFO_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMT_CODEC_INFO_TYPE").field(&self.0).finish()
@@ -4853,6 +4858,7 @@ pub struct WMT_CREDENTIAL_FLAGS(pub i32);
 impl windows_core::TypeKind for WMT_CREDENTIAL_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMT_CREDENTIAL_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMT_CREDENTIAL_FLAGS").field(&self.0).finish()
@@ -4864,6 +4870,7 @@ pub struct WMT_DRMLA_TRUST(pub i32);
 impl windows_core::TypeKind for WMT_DRMLA_TRUST {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMT_DRMLA_TRUST {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMT_DRMLA_TRUST").field(&self.0).finish()
@@ -4875,6 +4882,7 @@ pub struct WMT_FILESINK_MODE(pub i32);
 impl windows_core::TypeKind for WMT_FILESINK_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMT_FILESINK_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMT_FILESINK_MODE").field(&self.0).finish()
@@ -4886,6 +4894,7 @@ pub struct WMT_IMAGE_TYPE(pub i32);
 impl windows_core::TypeKind for WMT_IMAGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMT_IMAGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMT_IMAGE_TYPE").field(&self.0).finish()
@@ -4897,6 +4906,7 @@ pub struct WMT_INDEXER_TYPE(pub i32);
 impl windows_core::TypeKind for WMT_INDEXER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMT_INDEXER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMT_INDEXER_TYPE").field(&self.0).finish()
@@ -4908,6 +4918,7 @@ pub struct WMT_INDEX_TYPE(pub i32);
 impl windows_core::TypeKind for WMT_INDEX_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMT_INDEX_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMT_INDEX_TYPE").field(&self.0).finish()
@@ -4919,6 +4930,7 @@ pub struct WMT_MUSICSPEECH_CLASS_MODE(pub i32);
 impl windows_core::TypeKind for WMT_MUSICSPEECH_CLASS_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMT_MUSICSPEECH_CLASS_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMT_MUSICSPEECH_CLASS_MODE").field(&self.0).finish()
@@ -4930,6 +4942,7 @@ pub struct WMT_NET_PROTOCOL(pub i32);
 impl windows_core::TypeKind for WMT_NET_PROTOCOL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMT_NET_PROTOCOL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMT_NET_PROTOCOL").field(&self.0).finish()
@@ -4941,6 +4954,7 @@ pub struct WMT_OFFSET_FORMAT(pub i32);
 impl windows_core::TypeKind for WMT_OFFSET_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMT_OFFSET_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMT_OFFSET_FORMAT").field(&self.0).finish()
@@ -4952,6 +4966,7 @@ pub struct WMT_PLAY_MODE(pub i32);
 impl windows_core::TypeKind for WMT_PLAY_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMT_PLAY_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMT_PLAY_MODE").field(&self.0).finish()
@@ -4963,6 +4978,7 @@ pub struct WMT_PROXY_SETTINGS(pub i32);
 impl windows_core::TypeKind for WMT_PROXY_SETTINGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMT_PROXY_SETTINGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMT_PROXY_SETTINGS").field(&self.0).finish()
@@ -4974,6 +4990,7 @@ pub struct WMT_RIGHTS(pub i32);
 impl windows_core::TypeKind for WMT_RIGHTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMT_RIGHTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMT_RIGHTS").field(&self.0).finish()
@@ -4985,6 +5002,7 @@ pub struct WMT_STATUS(pub i32);
 impl windows_core::TypeKind for WMT_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMT_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMT_STATUS").field(&self.0).finish()
@@ -4996,6 +5014,7 @@ pub struct WMT_STORAGE_FORMAT(pub i32);
 impl windows_core::TypeKind for WMT_STORAGE_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMT_STORAGE_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMT_STORAGE_FORMAT").field(&self.0).finish()
@@ -5007,6 +5026,7 @@ pub struct WMT_STREAM_SELECTION(pub i32);
 impl windows_core::TypeKind for WMT_STREAM_SELECTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMT_STREAM_SELECTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMT_STREAM_SELECTION").field(&self.0).finish()
@@ -5018,6 +5038,7 @@ pub struct WMT_TIMECODE_FRAMERATE(pub i32);
 impl windows_core::TypeKind for WMT_TIMECODE_FRAMERATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMT_TIMECODE_FRAMERATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMT_TIMECODE_FRAMERATE").field(&self.0).finish()
@@ -5029,6 +5050,7 @@ pub struct WMT_TRANSPORT_TYPE(pub i32);
 impl windows_core::TypeKind for WMT_TRANSPORT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMT_TRANSPORT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMT_TRANSPORT_TYPE").field(&self.0).finish()
@@ -5040,6 +5062,7 @@ pub struct WMT_VERSION(pub i32);
 impl windows_core::TypeKind for WMT_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMT_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMT_VERSION").field(&self.0).finish()
@@ -5051,6 +5074,7 @@ pub struct WMT_WATERMARK_ENTRY_TYPE(pub i32);
 impl windows_core::TypeKind for WMT_WATERMARK_ENTRY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMT_WATERMARK_ENTRY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMT_WATERMARK_ENTRY_TYPE").field(&self.0).finish()
@@ -5062,6 +5086,7 @@ pub struct WM_AETYPE(pub i32);
 impl windows_core::TypeKind for WM_AETYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WM_AETYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WM_AETYPE").field(&self.0).finish()
@@ -5073,6 +5098,7 @@ pub struct WM_DM_INTERLACED_TYPE(pub i32);
 impl windows_core::TypeKind for WM_DM_INTERLACED_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WM_DM_INTERLACED_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WM_DM_INTERLACED_TYPE").field(&self.0).finish()
@@ -5084,6 +5110,7 @@ pub struct WM_DM_IT_FIRST_FRAME_COHERENCY(pub i32);
 impl windows_core::TypeKind for WM_DM_IT_FIRST_FRAME_COHERENCY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WM_DM_IT_FIRST_FRAME_COHERENCY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WM_DM_IT_FIRST_FRAME_COHERENCY").field(&self.0).finish()
@@ -5095,6 +5122,7 @@ pub struct WM_PLAYBACK_DRC_LEVEL(pub i32);
 impl windows_core::TypeKind for WM_PLAYBACK_DRC_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WM_PLAYBACK_DRC_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WM_PLAYBACK_DRC_LEVEL").field(&self.0).finish()
@@ -5106,6 +5134,7 @@ pub struct WM_SFEX_TYPE(pub i32);
 impl windows_core::TypeKind for WM_SFEX_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WM_SFEX_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WM_SFEX_TYPE").field(&self.0).finish()
@@ -5117,6 +5146,7 @@ pub struct WM_SF_TYPE(pub i32);
 impl windows_core::TypeKind for WM_SF_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WM_SF_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WM_SF_TYPE").field(&self.0).finish()
@@ -5128,13 +5158,15 @@ pub struct _AM_ASFWRITERCONFIG_PARAM(pub i32);
 impl windows_core::TypeKind for _AM_ASFWRITERCONFIG_PARAM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _AM_ASFWRITERCONFIG_PARAM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_AM_ASFWRITERCONFIG_PARAM").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AM_WMT_EVENT_DATA {
     pub hrStatus: windows_core::HRESULT,
     pub pData: *mut core::ffi::c_void,
@@ -5148,7 +5180,8 @@ impl Default for AM_WMT_EVENT_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRM_COPY_OPL {
     pub wMinimumCopyLevel: u16,
     pub oplIdIncludes: DRM_OPL_OUTPUT_IDS,
@@ -5163,7 +5196,8 @@ impl Default for DRM_COPY_OPL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRM_MINIMUM_OUTPUT_PROTECTION_LEVELS {
     pub wCompressedDigitalVideo: u16,
     pub wUncompressedDigitalVideo: u16,
@@ -5180,7 +5214,8 @@ impl Default for DRM_MINIMUM_OUTPUT_PROTECTION_LEVELS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRM_OPL_OUTPUT_IDS {
     pub cIds: u16,
     pub rgIds: *mut windows_core::GUID,
@@ -5194,7 +5229,8 @@ impl Default for DRM_OPL_OUTPUT_IDS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRM_OUTPUT_PROTECTION {
     pub guidId: windows_core::GUID,
     pub bConfigData: u8,
@@ -5208,7 +5244,8 @@ impl Default for DRM_OUTPUT_PROTECTION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRM_PLAY_OPL {
     pub minOPL: DRM_MINIMUM_OUTPUT_PROTECTION_LEVELS,
     pub oplIdReserved: DRM_OPL_OUTPUT_IDS,
@@ -5223,7 +5260,8 @@ impl Default for DRM_PLAY_OPL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRM_VAL16 {
     pub val: [u8; 16],
 }
@@ -5236,7 +5274,8 @@ impl Default for DRM_VAL16 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRM_VIDEO_OUTPUT_PROTECTION_IDS {
     pub cEntries: u16,
     pub rgVop: *mut DRM_OUTPUT_PROTECTION,
@@ -5250,7 +5289,8 @@ impl Default for DRM_VIDEO_OUTPUT_PROTECTION_IDS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WMDRM_IMPORT_INIT_STRUCT {
     pub dwVersion: u32,
     pub cbEncryptedSessionKeyMessage: u32,
@@ -5268,7 +5308,8 @@ impl Default for WMDRM_IMPORT_INIT_STRUCT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WMMPEG2VIDEOINFO {
     pub hdr: WMVIDEOINFOHEADER2,
     pub dwStartTimeCode: u32,
@@ -5289,7 +5330,8 @@ impl Default for WMMPEG2VIDEOINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WMSCRIPTFORMAT {
     pub scriptType: windows_core::GUID,
 }
@@ -5302,7 +5344,8 @@ impl Default for WMSCRIPTFORMAT {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WMT_BUFFER_SEGMENT {
     pub pBuffer: core::mem::ManuallyDrop<Option<INSSBuffer>>,
     pub cbOffset: u32,
@@ -5322,7 +5365,8 @@ impl Default for WMT_BUFFER_SEGMENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WMT_COLORSPACEINFO_EXTENSION_DATA {
     pub ucColorPrimaries: u8,
     pub ucColorTransferChar: u8,
@@ -5337,7 +5381,8 @@ impl Default for WMT_COLORSPACEINFO_EXTENSION_DATA {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WMT_FILESINK_DATA_UNIT {
     pub packetHeaderBuffer: WMT_BUFFER_SEGMENT,
     pub cPayloads: u32,
@@ -5359,7 +5404,8 @@ impl Default for WMT_FILESINK_DATA_UNIT {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WMT_PAYLOAD_FRAGMENT {
     pub dwPayloadIndex: u32,
     pub segmentData: WMT_BUFFER_SEGMENT,
@@ -5394,7 +5440,8 @@ impl Default for WMT_TIMECODE_EXTENSION_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WMT_VIDEOIMAGE_SAMPLE {
     pub dwMagic: u32,
     pub cbStruct: u32,
@@ -5427,7 +5474,8 @@ impl Default for WMT_VIDEOIMAGE_SAMPLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WMT_VIDEOIMAGE_SAMPLE2 {
     pub dwMagic: u32,
     pub dwStructSize: u32,
@@ -5466,7 +5514,8 @@ impl Default for WMT_VIDEOIMAGE_SAMPLE2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WMT_WATERMARK_ENTRY {
     pub wmetType: WMT_WATERMARK_ENTRY_TYPE,
     pub clsid: windows_core::GUID,
@@ -5482,7 +5531,8 @@ impl Default for WMT_WATERMARK_ENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WMT_WEBSTREAM_FORMAT {
     pub cbSize: u16,
     pub cbSampleHeaderFixedData: u16,
@@ -5498,7 +5548,8 @@ impl Default for WMT_WEBSTREAM_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WMT_WEBSTREAM_SAMPLE_HEADER {
     pub cbLength: u16,
     pub wPart: u16,
@@ -5516,7 +5567,8 @@ impl Default for WMT_WEBSTREAM_SAMPLE_HEADER {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WMVIDEOINFOHEADER {
     pub rcSource: super::super::Foundation::RECT,
     pub rcTarget: super::super::Foundation::RECT,
@@ -5537,7 +5589,8 @@ impl Default for WMVIDEOINFOHEADER {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WMVIDEOINFOHEADER2 {
     pub rcSource: super::super::Foundation::RECT,
     pub rcTarget: super::super::Foundation::RECT,
@@ -5563,7 +5616,8 @@ impl Default for WMVIDEOINFOHEADER2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WM_ADDRESS_ACCESSENTRY {
     pub dwIPAddress: u32,
     pub dwMask: u32,
@@ -5577,7 +5631,8 @@ impl Default for WM_ADDRESS_ACCESSENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WM_CLIENT_PROPERTIES {
     pub dwIPAddress: u32,
     pub dwPort: u32,
@@ -5591,7 +5646,8 @@ impl Default for WM_CLIENT_PROPERTIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WM_CLIENT_PROPERTIES_EX {
     pub cbSize: u32,
     pub pwszIPAddress: windows_core::PCWSTR,
@@ -5621,7 +5677,8 @@ impl Default for WM_LEAKY_BUCKET_PAIR {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WM_MEDIA_TYPE {
     pub majortype: windows_core::GUID,
     pub subtype: windows_core::GUID,
@@ -5664,7 +5721,8 @@ impl Default for WM_PICTURE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WM_PORT_NUMBER_RANGE {
     pub wPortBegin: u16,
     pub wPortEnd: u16,
@@ -5678,7 +5736,8 @@ impl Default for WM_PORT_NUMBER_RANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WM_READER_CLIENTINFO {
     pub cbSize: u32,
     pub wszLang: windows_core::PWSTR,
@@ -5699,7 +5758,8 @@ impl Default for WM_READER_CLIENTINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WM_READER_STATISTICS {
     pub cbSize: u32,
     pub dwBandwidth: u32,
@@ -5790,7 +5850,8 @@ impl Default for WM_USER_WEB_URL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WM_WRITER_STATISTICS {
     pub qwSampleCount: u64,
     pub qwByteCount: u64,
@@ -5812,7 +5873,8 @@ impl Default for WM_WRITER_STATISTICS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WM_WRITER_STATISTICS_EX {
     pub dwBitratePlusOverhead: u32,
     pub dwCurrentSampleDropRateInQueue: u32,
@@ -5832,3 +5894,5 @@ impl Default for WM_WRITER_STATISTICS_EX {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
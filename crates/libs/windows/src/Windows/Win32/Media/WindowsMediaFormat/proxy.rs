@@ -0,0 +1,2956 @@
+impl windows_core::imp::ProxyVtable for INSNetSourceCreator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INSNetSourceCreator", "Initialize", 3usize, |this| ((**(this as *const *const INSNetSourceCreator_Vtbl)).Initialize)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateNetSource(this: *mut core::ffi::c_void, pszstreamname: windows_core::PCWSTR, pmonitor: *mut core::ffi::c_void, pdata: *const u8, pusercontext: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, qwcontext: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INSNetSourceCreator", "CreateNetSource", 4usize, |this| ((**(this as *const *const INSNetSourceCreator_Vtbl)).CreateNetSource)(this, pszstreamname, pmonitor, pdata, pusercontext, pcallback, qwcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNetSourceProperties(this: *mut core::ffi::c_void, pszstreamname: windows_core::PCWSTR, pppropertiesnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INSNetSourceCreator", "GetNetSourceProperties", 5usize, |this| ((**(this as *const *const INSNetSourceCreator_Vtbl)).GetNetSourceProperties)(this, pszstreamname, pppropertiesnode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNetSourceSharedNamespace(this: *mut core::ffi::c_void, ppsharednamespace: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INSNetSourceCreator", "GetNetSourceSharedNamespace", 6usize, |this| ((**(this as *const *const INSNetSourceCreator_Vtbl)).GetNetSourceSharedNamespace)(this, ppsharednamespace), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNetSourceAdminInterface(this: *mut core::ffi::c_void, pszstreamname: windows_core::PCWSTR, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INSNetSourceCreator", "GetNetSourceAdminInterface", 7usize, |this| ((**(this as *const *const INSNetSourceCreator_Vtbl)).GetNetSourceAdminInterface)(this, pszstreamname, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumProtocolsSupported(this: *mut core::ffi::c_void, pcprotocols: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INSNetSourceCreator", "GetNumProtocolsSupported", 8usize, |this| ((**(this as *const *const INSNetSourceCreator_Vtbl)).GetNumProtocolsSupported)(this, pcprotocols), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProtocolName(this: *mut core::ffi::c_void, dwprotocolnum: u32, pwszprotocolname: windows_core::PWSTR, pcchprotocolname: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INSNetSourceCreator", "GetProtocolName", 9usize, |this| ((**(this as *const *const INSNetSourceCreator_Vtbl)).GetProtocolName)(this, dwprotocolnum, pwszprotocolname, pcchprotocolname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INSNetSourceCreator", "Shutdown", 10usize, |this| ((**(this as *const *const INSNetSourceCreator_Vtbl)).Shutdown)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            CreateNetSource,
+            GetNetSourceProperties,
+            GetNetSourceSharedNamespace,
+            GetNetSourceAdminInterface,
+            GetNumProtocolsSupported,
+            GetProtocolName,
+            Shutdown,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INSNetSourceCreator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INSSBuffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, pdwlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INSSBuffer", "GetLength", 3usize, |this| ((**(this as *const *const INSSBuffer_Vtbl)).GetLength)(this, pdwlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLength(this: *mut core::ffi::c_void, dwlength: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INSSBuffer", "SetLength", 4usize, |this| ((**(this as *const *const INSSBuffer_Vtbl)).SetLength)(this, dwlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxLength(this: *mut core::ffi::c_void, pdwlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INSSBuffer", "GetMaxLength", 5usize, |this| ((**(this as *const *const INSSBuffer_Vtbl)).GetMaxLength)(this, pdwlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBuffer(this: *mut core::ffi::c_void, ppdwbuffer: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INSSBuffer", "GetBuffer", 6usize, |this| ((**(this as *const *const INSSBuffer_Vtbl)).GetBuffer)(this, ppdwbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBufferAndLength(this: *mut core::ffi::c_void, ppdwbuffer: *mut *mut u8, pdwlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INSSBuffer", "GetBufferAndLength", 7usize, |this| ((**(this as *const *const INSSBuffer_Vtbl)).GetBufferAndLength)(this, ppdwbuffer, pdwlength), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetLength,
+            SetLength,
+            GetMaxLength,
+            GetBuffer,
+            GetBufferAndLength,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INSSBuffer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INSSBuffer2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSampleProperties(this: *mut core::ffi::c_void, cbproperties: u32, pbproperties: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INSSBuffer2", "GetSampleProperties", 8usize, |this| ((**(this as *const *const INSSBuffer2_Vtbl)).GetSampleProperties)(this, cbproperties, pbproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSampleProperties(this: *mut core::ffi::c_void, cbproperties: u32, pbproperties: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INSSBuffer2", "SetSampleProperties", 9usize, |this| ((**(this as *const *const INSSBuffer2_Vtbl)).SetSampleProperties)(this, cbproperties, pbproperties), |result| Some(*result))
+        }
+        Self { base__: <INSSBuffer_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSampleProperties, SetSampleProperties }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INSSBuffer2 as windows_core::Interface>::IID || <INSSBuffer_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INSSBuffer3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, guidbufferproperty: windows_core::GUID, pvbufferproperty: *const core::ffi::c_void, dwbufferpropertysize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INSSBuffer3", "SetProperty", 10usize, |this| ((**(this as *const *const INSSBuffer3_Vtbl)).SetProperty)(this, guidbufferproperty, pvbufferproperty, dwbufferpropertysize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, guidbufferproperty: windows_core::GUID, pvbufferproperty: *mut core::ffi::c_void, pdwbufferpropertysize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INSSBuffer3", "GetProperty", 11usize, |this| ((**(this as *const *const INSSBuffer3_Vtbl)).GetProperty)(this, guidbufferproperty, pvbufferproperty, pdwbufferpropertysize), |result| Some(*result))
+        }
+        Self { base__: <INSSBuffer2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetProperty, GetProperty }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INSSBuffer3 as windows_core::Interface>::IID || <INSSBuffer2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INSSBuffer4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPropertyCount(this: *mut core::ffi::c_void, pcbufferproperties: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INSSBuffer4", "GetPropertyCount", 12usize, |this| ((**(this as *const *const INSSBuffer4_Vtbl)).GetPropertyCount)(this, pcbufferproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyByIndex(this: *mut core::ffi::c_void, dwbufferpropertyindex: u32, pguidbufferproperty: *mut windows_core::GUID, pvbufferproperty: *mut core::ffi::c_void, pdwbufferpropertysize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INSSBuffer4", "GetPropertyByIndex", 13usize, |this| ((**(this as *const *const INSSBuffer4_Vtbl)).GetPropertyByIndex)(this, dwbufferpropertyindex, pguidbufferproperty, pvbufferproperty, pdwbufferpropertysize), |result| Some(*result))
+        }
+        Self { base__: <INSSBuffer3_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPropertyCount, GetPropertyByIndex }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INSSBuffer4 as windows_core::Interface>::IID || <INSSBuffer3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMAddressAccess_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAccessEntryCount(this: *mut core::ffi::c_void, aetype: WM_AETYPE, pcentries: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMAddressAccess", "GetAccessEntryCount", 3usize, |this| ((**(this as *const *const IWMAddressAccess_Vtbl)).GetAccessEntryCount)(this, aetype, pcentries), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAccessEntry(this: *mut core::ffi::c_void, aetype: WM_AETYPE, dwentrynum: u32, paddraccessentry: *mut WM_ADDRESS_ACCESSENTRY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMAddressAccess", "GetAccessEntry", 4usize, |this| ((**(this as *const *const IWMAddressAccess_Vtbl)).GetAccessEntry)(this, aetype, dwentrynum, paddraccessentry), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddAccessEntry(this: *mut core::ffi::c_void, aetype: WM_AETYPE, paddraccessentry: *const WM_ADDRESS_ACCESSENTRY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMAddressAccess", "AddAccessEntry", 5usize, |this| ((**(this as *const *const IWMAddressAccess_Vtbl)).AddAccessEntry)(this, aetype, paddraccessentry), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAccessEntry(this: *mut core::ffi::c_void, aetype: WM_AETYPE, dwentrynum: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMAddressAccess", "RemoveAccessEntry", 6usize, |this| ((**(this as *const *const IWMAddressAccess_Vtbl)).RemoveAccessEntry)(this, aetype, dwentrynum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetAccessEntryCount,
+            GetAccessEntry,
+            AddAccessEntry,
+            RemoveAccessEntry,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMAddressAccess as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMAddressAccess2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAccessEntryEx(this: *mut core::ffi::c_void, aetype: WM_AETYPE, dwentrynum: u32, pbstraddress: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrmask: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMAddressAccess2", "GetAccessEntryEx", 7usize, |this| ((**(this as *const *const IWMAddressAccess2_Vtbl)).GetAccessEntryEx)(this, aetype, dwentrynum, pbstraddress, pbstrmask), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddAccessEntryEx(this: *mut core::ffi::c_void, aetype: WM_AETYPE, bstraddress: core::mem::MaybeUninit<windows_core::BSTR>, bstrmask: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMAddressAccess2", "AddAccessEntryEx", 8usize, |this| ((**(this as *const *const IWMAddressAccess2_Vtbl)).AddAccessEntryEx)(this, aetype, bstraddress, bstrmask), |result| Some(*result))
+        }
+        Self { base__: <IWMAddressAccess_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAccessEntryEx, AddAccessEntryEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMAddressAccess2 as windows_core::Interface>::IID || <IWMAddressAccess_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMAuthorizer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCertCount(this: *mut core::ffi::c_void, pccerts: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMAuthorizer", "GetCertCount", 3usize, |this| ((**(this as *const *const IWMAuthorizer_Vtbl)).GetCertCount)(this, pccerts), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCert(this: *mut core::ffi::c_void, dwindex: u32, ppbcertdata: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMAuthorizer", "GetCert", 4usize, |this| ((**(this as *const *const IWMAuthorizer_Vtbl)).GetCert)(this, dwindex, ppbcertdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSharedData(this: *mut core::ffi::c_void, dwcertindex: u32, pbshareddata: *const u8, pbcert: *const u8, ppbshareddata: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMAuthorizer", "GetSharedData", 5usize, |this| ((**(this as *const *const IWMAuthorizer_Vtbl)).GetSharedData)(this, dwcertindex, pbshareddata, pbcert, ppbshareddata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCertCount, GetCert, GetSharedData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMAuthorizer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMBackupRestoreProps_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPropCount(this: *mut core::ffi::c_void, pcprops: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMBackupRestoreProps", "GetPropCount", 3usize, |this| ((**(this as *const *const IWMBackupRestoreProps_Vtbl)).GetPropCount)(this, pcprops), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropByIndex(this: *mut core::ffi::c_void, windex: u16, pwszname: windows_core::PWSTR, pcchnamelen: *mut u16, ptype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pcblength: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMBackupRestoreProps", "GetPropByIndex", 4usize, |this| ((**(this as *const *const IWMBackupRestoreProps_Vtbl)).GetPropByIndex)(this, windex, pwszname, pcchnamelen, ptype, pvalue, pcblength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropByName(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, ptype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pcblength: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMBackupRestoreProps", "GetPropByName", 5usize, |this| ((**(this as *const *const IWMBackupRestoreProps_Vtbl)).GetPropByName)(this, pszname, ptype, pvalue, pcblength), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProp(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, r#type: WMT_ATTR_DATATYPE, pvalue: *const u8, cblength: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMBackupRestoreProps", "SetProp", 6usize, |this| ((**(this as *const *const IWMBackupRestoreProps_Vtbl)).SetProp)(this, pszname, r#type, pvalue, cblength), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveProp(this: *mut core::ffi::c_void, pcwszname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMBackupRestoreProps", "RemoveProp", 7usize, |this| ((**(this as *const *const IWMBackupRestoreProps_Vtbl)).RemoveProp)(this, pcwszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAllProps(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMBackupRestoreProps", "RemoveAllProps", 8usize, |this| ((**(this as *const *const IWMBackupRestoreProps_Vtbl)).RemoveAllProps)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPropCount,
+            GetPropByIndex,
+            GetPropByName,
+            SetProp,
+            RemoveProp,
+            RemoveAllProps,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMBackupRestoreProps as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMBandwidthSharing_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, pguidtype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMBandwidthSharing", "GetType", 6usize, |this| ((**(this as *const *const IWMBandwidthSharing_Vtbl)).GetType)(this, pguidtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetType(this: *mut core::ffi::c_void, guidtype: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMBandwidthSharing", "SetType", 7usize, |this| ((**(this as *const *const IWMBandwidthSharing_Vtbl)).SetType)(this, guidtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBandwidth(this: *mut core::ffi::c_void, pdwbitrate: *mut u32, pmsbufferwindow: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMBandwidthSharing", "GetBandwidth", 8usize, |this| ((**(this as *const *const IWMBandwidthSharing_Vtbl)).GetBandwidth)(this, pdwbitrate, pmsbufferwindow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBandwidth(this: *mut core::ffi::c_void, dwbitrate: u32, msbufferwindow: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMBandwidthSharing", "SetBandwidth", 9usize, |this| ((**(this as *const *const IWMBandwidthSharing_Vtbl)).SetBandwidth)(this, dwbitrate, msbufferwindow), |result| Some(*result))
+        }
+        Self { base__: <IWMStreamList_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetType, SetType, GetBandwidth, SetBandwidth }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMBandwidthSharing as windows_core::Interface>::IID || <IWMStreamList_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMClientConnections_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetClientCount(this: *mut core::ffi::c_void, pcclients: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMClientConnections", "GetClientCount", 3usize, |this| ((**(this as *const *const IWMClientConnections_Vtbl)).GetClientCount)(this, pcclients), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClientProperties(this: *mut core::ffi::c_void, dwclientnum: u32, pclientproperties: *mut WM_CLIENT_PROPERTIES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMClientConnections", "GetClientProperties", 4usize, |this| ((**(this as *const *const IWMClientConnections_Vtbl)).GetClientProperties)(this, dwclientnum, pclientproperties), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetClientCount, GetClientProperties }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMClientConnections as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMClientConnections2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetClientInfo(this: *mut core::ffi::c_void, dwclientnum: u32, pwsznetworkaddress: windows_core::PWSTR, pcchnetworkaddress: *mut u32, pwszport: windows_core::PWSTR, pcchport: *mut u32, pwszdnsname: windows_core::PWSTR, pcchdnsname: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMClientConnections2", "GetClientInfo", 5usize, |this| ((**(this as *const *const IWMClientConnections2_Vtbl)).GetClientInfo)(this, dwclientnum, pwsznetworkaddress, pcchnetworkaddress, pwszport, pcchport, pwszdnsname, pcchdnsname), |result| Some(*result))
+        }
+        Self { base__: <IWMClientConnections_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetClientInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMClientConnections2 as windows_core::Interface>::IID || <IWMClientConnections_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMCodecInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCodecInfoCount(this: *mut core::ffi::c_void, guidtype: *const windows_core::GUID, pccodecs: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMCodecInfo", "GetCodecInfoCount", 3usize, |this| ((**(this as *const *const IWMCodecInfo_Vtbl)).GetCodecInfoCount)(this, guidtype, pccodecs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCodecFormatCount(this: *mut core::ffi::c_void, guidtype: *const windows_core::GUID, dwcodecindex: u32, pcformat: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMCodecInfo", "GetCodecFormatCount", 4usize, |this| ((**(this as *const *const IWMCodecInfo_Vtbl)).GetCodecFormatCount)(this, guidtype, dwcodecindex, pcformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCodecFormat(this: *mut core::ffi::c_void, guidtype: *const windows_core::GUID, dwcodecindex: u32, dwformatindex: u32, ppistreamconfig: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMCodecInfo", "GetCodecFormat", 5usize, |this| ((**(this as *const *const IWMCodecInfo_Vtbl)).GetCodecFormat)(this, guidtype, dwcodecindex, dwformatindex, ppistreamconfig), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCodecInfoCount, GetCodecFormatCount, GetCodecFormat }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMCodecInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMCodecInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCodecName(this: *mut core::ffi::c_void, guidtype: *const windows_core::GUID, dwcodecindex: u32, wszname: windows_core::PWSTR, pcchname: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMCodecInfo2", "GetCodecName", 6usize, |this| ((**(this as *const *const IWMCodecInfo2_Vtbl)).GetCodecName)(this, guidtype, dwcodecindex, wszname, pcchname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCodecFormatDesc(this: *mut core::ffi::c_void, guidtype: *const windows_core::GUID, dwcodecindex: u32, dwformatindex: u32, ppistreamconfig: *mut *mut core::ffi::c_void, wszdesc: windows_core::PWSTR, pcchdesc: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMCodecInfo2", "GetCodecFormatDesc", 7usize, |this| ((**(this as *const *const IWMCodecInfo2_Vtbl)).GetCodecFormatDesc)(this, guidtype, dwcodecindex, dwformatindex, ppistreamconfig, wszdesc, pcchdesc), |result| Some(*result))
+        }
+        Self { base__: <IWMCodecInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCodecName, GetCodecFormatDesc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMCodecInfo2 as windows_core::Interface>::IID || <IWMCodecInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMCodecInfo3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCodecFormatProp(this: *mut core::ffi::c_void, guidtype: *const windows_core::GUID, dwcodecindex: u32, dwformatindex: u32, pszname: windows_core::PCWSTR, ptype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pdwsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMCodecInfo3", "GetCodecFormatProp", 8usize, |this| ((**(this as *const *const IWMCodecInfo3_Vtbl)).GetCodecFormatProp)(this, guidtype, dwcodecindex, dwformatindex, pszname, ptype, pvalue, pdwsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCodecProp(this: *mut core::ffi::c_void, guidtype: *const windows_core::GUID, dwcodecindex: u32, pszname: windows_core::PCWSTR, ptype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pdwsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMCodecInfo3", "GetCodecProp", 9usize, |this| ((**(this as *const *const IWMCodecInfo3_Vtbl)).GetCodecProp)(this, guidtype, dwcodecindex, pszname, ptype, pvalue, pdwsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCodecEnumerationSetting(this: *mut core::ffi::c_void, guidtype: *const windows_core::GUID, dwcodecindex: u32, pszname: windows_core::PCWSTR, r#type: WMT_ATTR_DATATYPE, pvalue: *const u8, dwsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMCodecInfo3", "SetCodecEnumerationSetting", 10usize, |this| ((**(this as *const *const IWMCodecInfo3_Vtbl)).SetCodecEnumerationSetting)(this, guidtype, dwcodecindex, pszname, r#type, pvalue, dwsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCodecEnumerationSetting(this: *mut core::ffi::c_void, guidtype: *const windows_core::GUID, dwcodecindex: u32, pszname: windows_core::PCWSTR, ptype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pdwsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMCodecInfo3", "GetCodecEnumerationSetting", 11usize, |this| ((**(this as *const *const IWMCodecInfo3_Vtbl)).GetCodecEnumerationSetting)(this, guidtype, dwcodecindex, pszname, ptype, pvalue, pdwsize), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMCodecInfo2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCodecFormatProp,
+            GetCodecProp,
+            SetCodecEnumerationSetting,
+            GetCodecEnumerationSetting,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMCodecInfo3 as windows_core::Interface>::IID || <IWMCodecInfo2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMCredentialCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AcquireCredentials(this: *mut core::ffi::c_void, pwszrealm: windows_core::PCWSTR, pwszsite: windows_core::PCWSTR, pwszuser: windows_core::PWSTR, cchuser: u32, pwszpassword: windows_core::PWSTR, cchpassword: u32, hrstatus: windows_core::HRESULT, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMCredentialCallback", "AcquireCredentials", 3usize, |this| ((**(this as *const *const IWMCredentialCallback_Vtbl)).AcquireCredentials)(this, pwszrealm, pwszsite, pwszuser, cchuser, pwszpassword, cchpassword, hrstatus, pdwflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AcquireCredentials }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMCredentialCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDRMEditor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDRMProperty(this: *mut core::ffi::c_void, pwstrname: windows_core::PCWSTR, pdwtype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pcblength: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMEditor", "GetDRMProperty", 3usize, |this| ((**(this as *const *const IWMDRMEditor_Vtbl)).GetDRMProperty)(this, pwstrname, pdwtype, pvalue, pcblength), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDRMProperty }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDRMEditor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDRMMessageParser_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ParseRegistrationReqMsg(this: *mut core::ffi::c_void, pbregistrationreqmsg: *const u8, cbregistrationreqmsg: u32, ppdevicecert: *mut *mut core::ffi::c_void, pdeviceserialnumber: *mut DRM_VAL16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMMessageParser", "ParseRegistrationReqMsg", 3usize, |this| ((**(this as *const *const IWMDRMMessageParser_Vtbl)).ParseRegistrationReqMsg)(this, pbregistrationreqmsg, cbregistrationreqmsg, ppdevicecert, pdeviceserialnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParseLicenseRequestMsg(this: *mut core::ffi::c_void, pblicenserequestmsg: *const u8, cblicenserequestmsg: u32, ppdevicecert: *mut *mut core::ffi::c_void, pdeviceserialnumber: *mut DRM_VAL16, pbstraction: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMMessageParser", "ParseLicenseRequestMsg", 4usize, |this| ((**(this as *const *const IWMDRMMessageParser_Vtbl)).ParseLicenseRequestMsg)(this, pblicenserequestmsg, cblicenserequestmsg, ppdevicecert, pdeviceserialnumber, pbstraction), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ParseRegistrationReqMsg, ParseLicenseRequestMsg }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDRMMessageParser as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDRMReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AcquireLicense(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMReader", "AcquireLicense", 3usize, |this| ((**(this as *const *const IWMDRMReader_Vtbl)).AcquireLicense)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelLicenseAcquisition(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMReader", "CancelLicenseAcquisition", 4usize, |this| ((**(this as *const *const IWMDRMReader_Vtbl)).CancelLicenseAcquisition)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Individualize(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMReader", "Individualize", 5usize, |this| ((**(this as *const *const IWMDRMReader_Vtbl)).Individualize)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelIndividualization(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMReader", "CancelIndividualization", 6usize, |this| ((**(this as *const *const IWMDRMReader_Vtbl)).CancelIndividualization)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn MonitorLicenseAcquisition(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMReader", "MonitorLicenseAcquisition", 7usize, |this| ((**(this as *const *const IWMDRMReader_Vtbl)).MonitorLicenseAcquisition)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelMonitorLicenseAcquisition(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMReader", "CancelMonitorLicenseAcquisition", 8usize, |this| ((**(this as *const *const IWMDRMReader_Vtbl)).CancelMonitorLicenseAcquisition)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDRMProperty(this: *mut core::ffi::c_void, pwstrname: windows_core::PCWSTR, dwtype: WMT_ATTR_DATATYPE, pvalue: *const u8, cblength: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMReader", "SetDRMProperty", 9usize, |this| ((**(this as *const *const IWMDRMReader_Vtbl)).SetDRMProperty)(this, pwstrname, dwtype, pvalue, cblength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDRMProperty(this: *mut core::ffi::c_void, pwstrname: windows_core::PCWSTR, pdwtype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pcblength: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMReader", "GetDRMProperty", 10usize, |this| ((**(this as *const *const IWMDRMReader_Vtbl)).GetDRMProperty)(this, pwstrname, pdwtype, pvalue, pcblength), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AcquireLicense,
+            CancelLicenseAcquisition,
+            Individualize,
+            CancelIndividualization,
+            MonitorLicenseAcquisition,
+            CancelMonitorLicenseAcquisition,
+            SetDRMProperty,
+            GetDRMProperty,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDRMReader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDRMReader2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetEvaluateOutputLevelLicenses(this: *mut core::ffi::c_void, fevaluate: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMReader2", "SetEvaluateOutputLevelLicenses", 11usize, |this| ((**(this as *const *const IWMDRMReader2_Vtbl)).SetEvaluateOutputLevelLicenses)(this, fevaluate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPlayOutputLevels(this: *mut core::ffi::c_void, pplayopl: *mut DRM_PLAY_OPL, pcblength: *mut u32, pdwminappcompliancelevel: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMReader2", "GetPlayOutputLevels", 12usize, |this| ((**(this as *const *const IWMDRMReader2_Vtbl)).GetPlayOutputLevels)(this, pplayopl, pcblength, pdwminappcompliancelevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCopyOutputLevels(this: *mut core::ffi::c_void, pcopyopl: *mut DRM_COPY_OPL, pcblength: *mut u32, pdwminappcompliancelevel: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMReader2", "GetCopyOutputLevels", 13usize, |this| ((**(this as *const *const IWMDRMReader2_Vtbl)).GetCopyOutputLevels)(this, pcopyopl, pcblength, pdwminappcompliancelevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryNextLicense(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMReader2", "TryNextLicense", 14usize, |this| ((**(this as *const *const IWMDRMReader2_Vtbl)).TryNextLicense)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMDRMReader_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetEvaluateOutputLevelLicenses,
+            GetPlayOutputLevels,
+            GetCopyOutputLevels,
+            TryNextLicense,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDRMReader2 as windows_core::Interface>::IID || <IWMDRMReader_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDRMReader3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInclusionList(this: *mut core::ffi::c_void, ppguids: *mut *mut windows_core::GUID, pcguids: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMReader3", "GetInclusionList", 15usize, |this| ((**(this as *const *const IWMDRMReader3_Vtbl)).GetInclusionList)(this, ppguids, pcguids), |result| Some(*result))
+        }
+        Self { base__: <IWMDRMReader2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetInclusionList }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDRMReader3 as windows_core::Interface>::IID || <IWMDRMReader2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDRMTranscryptionManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateTranscryptor(this: *mut core::ffi::c_void, pptranscryptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMTranscryptionManager", "CreateTranscryptor", 3usize, |this| ((**(this as *const *const IWMDRMTranscryptionManager_Vtbl)).CreateTranscryptor)(this, pptranscryptor), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateTranscryptor }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDRMTranscryptionManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDRMTranscryptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, bstrfilename: core::mem::MaybeUninit<windows_core::BSTR>, pblicenserequestmsg: *mut u8, cblicenserequestmsg: u32, pplicenseresponsemsg: *mut *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMTranscryptor", "Initialize", 3usize, |this| ((**(this as *const *const IWMDRMTranscryptor_Vtbl)).Initialize)(this, bstrfilename, pblicenserequestmsg, cblicenserequestmsg, pplicenseresponsemsg, pcallback, pvcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn Seek(this: *mut core::ffi::c_void, hnstime: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMTranscryptor", "Seek", 4usize, |this| ((**(this as *const *const IWMDRMTranscryptor_Vtbl)).Seek)(this, hnstime), |result| Some(*result))
+        }
+        unsafe extern "system" fn Read(this: *mut core::ffi::c_void, pbdata: *const u8, pcbdata: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMTranscryptor", "Read", 5usize, |this| ((**(this as *const *const IWMDRMTranscryptor_Vtbl)).Read)(this, pbdata, pcbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMTranscryptor", "Close", 6usize, |this| ((**(this as *const *const IWMDRMTranscryptor_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, Seek, Read, Close }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDRMTranscryptor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDRMTranscryptor2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SeekEx(this: *mut core::ffi::c_void, cnsstarttime: u64, cnsduration: u64, flrate: f32, fincludefileheader: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMTranscryptor2", "SeekEx", 7usize, |this| ((**(this as *const *const IWMDRMTranscryptor2_Vtbl)).SeekEx)(this, cnsstarttime, cnsduration, flrate, fincludefileheader), |result| Some(*result))
+        }
+        unsafe extern "system" fn ZeroAdjustTimestamps(this: *mut core::ffi::c_void, fenable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMTranscryptor2", "ZeroAdjustTimestamps", 8usize, |this| ((**(this as *const *const IWMDRMTranscryptor2_Vtbl)).ZeroAdjustTimestamps)(this, fenable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSeekStartTime(this: *mut core::ffi::c_void, pcnstime: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMTranscryptor2", "GetSeekStartTime", 9usize, |this| ((**(this as *const *const IWMDRMTranscryptor2_Vtbl)).GetSeekStartTime)(this, pcnstime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDuration(this: *mut core::ffi::c_void, pcnsduration: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMTranscryptor2", "GetDuration", 10usize, |this| ((**(this as *const *const IWMDRMTranscryptor2_Vtbl)).GetDuration)(this, pcnsduration), |result| Some(*result))
+        }
+        Self { base__: <IWMDRMTranscryptor_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SeekEx, ZeroAdjustTimestamps, GetSeekStartTime, GetDuration }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDRMTranscryptor2 as windows_core::Interface>::IID || <IWMDRMTranscryptor_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDRMWriter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GenerateKeySeed(this: *mut core::ffi::c_void, pwszkeyseed: windows_core::PWSTR, pcwchlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMWriter", "GenerateKeySeed", 3usize, |this| ((**(this as *const *const IWMDRMWriter_Vtbl)).GenerateKeySeed)(this, pwszkeyseed, pcwchlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GenerateKeyID(this: *mut core::ffi::c_void, pwszkeyid: windows_core::PWSTR, pcwchlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMWriter", "GenerateKeyID", 4usize, |this| ((**(this as *const *const IWMDRMWriter_Vtbl)).GenerateKeyID)(this, pwszkeyid, pcwchlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GenerateSigningKeyPair(this: *mut core::ffi::c_void, pwszprivkey: windows_core::PWSTR, pcwchprivkeylength: *mut u32, pwszpubkey: windows_core::PWSTR, pcwchpubkeylength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMWriter", "GenerateSigningKeyPair", 5usize, |this| ((**(this as *const *const IWMDRMWriter_Vtbl)).GenerateSigningKeyPair)(this, pwszprivkey, pcwchprivkeylength, pwszpubkey, pcwchpubkeylength), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDRMAttribute(this: *mut core::ffi::c_void, wstreamnum: u16, pszname: windows_core::PCWSTR, r#type: WMT_ATTR_DATATYPE, pvalue: *const u8, cblength: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMWriter", "SetDRMAttribute", 6usize, |this| ((**(this as *const *const IWMDRMWriter_Vtbl)).SetDRMAttribute)(this, wstreamnum, pszname, r#type, pvalue, cblength), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GenerateKeySeed,
+            GenerateKeyID,
+            GenerateSigningKeyPair,
+            SetDRMAttribute,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDRMWriter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDRMWriter2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetWMDRMNetEncryption(this: *mut core::ffi::c_void, fsamplesencrypted: super::super::Foundation::BOOL, pbkeyid: *const u8, cbkeyid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMWriter2", "SetWMDRMNetEncryption", 7usize, |this| ((**(this as *const *const IWMDRMWriter2_Vtbl)).SetWMDRMNetEncryption)(this, fsamplesencrypted, pbkeyid, cbkeyid), |result| Some(*result))
+        }
+        Self { base__: <IWMDRMWriter_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetWMDRMNetEncryption }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDRMWriter2 as windows_core::Interface>::IID || <IWMDRMWriter_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDRMWriter3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetProtectStreamSamples(this: *mut core::ffi::c_void, pimportinitstruct: *const WMDRM_IMPORT_INIT_STRUCT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDRMWriter3", "SetProtectStreamSamples", 8usize, |this| ((**(this as *const *const IWMDRMWriter3_Vtbl)).SetProtectStreamSamples)(this, pimportinitstruct), |result| Some(*result))
+        }
+        Self { base__: <IWMDRMWriter2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetProtectStreamSamples }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDRMWriter3 as windows_core::Interface>::IID || <IWMDRMWriter2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMDeviceRegistration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterDevice(this: *mut core::ffi::c_void, dwregistertype: u32, pbcertificate: *const u8, cbcertificate: u32, serialnumber: DRM_VAL16, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDeviceRegistration", "RegisterDevice", 3usize, |this| ((**(this as *const *const IWMDeviceRegistration_Vtbl)).RegisterDevice)(this, dwregistertype, pbcertificate, cbcertificate, serialnumber, ppdevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterDevice(this: *mut core::ffi::c_void, dwregistertype: u32, pbcertificate: *const u8, cbcertificate: u32, serialnumber: DRM_VAL16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDeviceRegistration", "UnregisterDevice", 4usize, |this| ((**(this as *const *const IWMDeviceRegistration_Vtbl)).UnregisterDevice)(this, dwregistertype, pbcertificate, cbcertificate, serialnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRegistrationStats(this: *mut core::ffi::c_void, dwregistertype: u32, pcregistereddevices: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDeviceRegistration", "GetRegistrationStats", 5usize, |this| ((**(this as *const *const IWMDeviceRegistration_Vtbl)).GetRegistrationStats)(this, dwregistertype, pcregistereddevices), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFirstRegisteredDevice(this: *mut core::ffi::c_void, dwregistertype: u32, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDeviceRegistration", "GetFirstRegisteredDevice", 6usize, |this| ((**(this as *const *const IWMDeviceRegistration_Vtbl)).GetFirstRegisteredDevice)(this, dwregistertype, ppdevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextRegisteredDevice(this: *mut core::ffi::c_void, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDeviceRegistration", "GetNextRegisteredDevice", 7usize, |this| ((**(this as *const *const IWMDeviceRegistration_Vtbl)).GetNextRegisteredDevice)(this, ppdevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRegisteredDeviceByID(this: *mut core::ffi::c_void, dwregistertype: u32, pbcertificate: *const u8, cbcertificate: u32, serialnumber: DRM_VAL16, ppdevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMDeviceRegistration", "GetRegisteredDeviceByID", 8usize, |this| ((**(this as *const *const IWMDeviceRegistration_Vtbl)).GetRegisteredDeviceByID)(this, dwregistertype, pbcertificate, cbcertificate, serialnumber, ppdevice), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RegisterDevice,
+            UnregisterDevice,
+            GetRegistrationStats,
+            GetFirstRegisteredDevice,
+            GetNextRegisteredDevice,
+            GetRegisteredDeviceByID,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMDeviceRegistration as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMGetSecureChannel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPeerSecureChannelInterface(this: *mut core::ffi::c_void, pppeer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMGetSecureChannel", "GetPeerSecureChannelInterface", 3usize, |this| ((**(this as *const *const IWMGetSecureChannel_Vtbl)).GetPeerSecureChannelInterface)(this, pppeer), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPeerSecureChannelInterface }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMGetSecureChannel as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMHeaderInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAttributeCount(this: *mut core::ffi::c_void, wstreamnum: u16, pcattributes: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMHeaderInfo", "GetAttributeCount", 3usize, |this| ((**(this as *const *const IWMHeaderInfo_Vtbl)).GetAttributeCount)(this, wstreamnum, pcattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributeByIndex(this: *mut core::ffi::c_void, windex: u16, pwstreamnum: *mut u16, pwszname: windows_core::PWSTR, pcchnamelen: *mut u16, ptype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pcblength: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMHeaderInfo", "GetAttributeByIndex", 4usize, |this| ((**(this as *const *const IWMHeaderInfo_Vtbl)).GetAttributeByIndex)(this, windex, pwstreamnum, pwszname, pcchnamelen, ptype, pvalue, pcblength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributeByName(this: *mut core::ffi::c_void, pwstreamnum: *mut u16, pszname: windows_core::PCWSTR, ptype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pcblength: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMHeaderInfo", "GetAttributeByName", 5usize, |this| ((**(this as *const *const IWMHeaderInfo_Vtbl)).GetAttributeByName)(this, pwstreamnum, pszname, ptype, pvalue, pcblength), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAttribute(this: *mut core::ffi::c_void, wstreamnum: u16, pszname: windows_core::PCWSTR, r#type: WMT_ATTR_DATATYPE, pvalue: *const u8, cblength: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMHeaderInfo", "SetAttribute", 6usize, |this| ((**(this as *const *const IWMHeaderInfo_Vtbl)).SetAttribute)(this, wstreamnum, pszname, r#type, pvalue, cblength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMarkerCount(this: *mut core::ffi::c_void, pcmarkers: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMHeaderInfo", "GetMarkerCount", 7usize, |this| ((**(this as *const *const IWMHeaderInfo_Vtbl)).GetMarkerCount)(this, pcmarkers), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMarker(this: *mut core::ffi::c_void, windex: u16, pwszmarkername: windows_core::PWSTR, pcchmarkernamelen: *mut u16, pcnsmarkertime: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMHeaderInfo", "GetMarker", 8usize, |this| ((**(this as *const *const IWMHeaderInfo_Vtbl)).GetMarker)(this, windex, pwszmarkername, pcchmarkernamelen, pcnsmarkertime), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddMarker(this: *mut core::ffi::c_void, pwszmarkername: windows_core::PCWSTR, cnsmarkertime: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMHeaderInfo", "AddMarker", 9usize, |this| ((**(this as *const *const IWMHeaderInfo_Vtbl)).AddMarker)(this, pwszmarkername, cnsmarkertime), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMarker(this: *mut core::ffi::c_void, windex: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMHeaderInfo", "RemoveMarker", 10usize, |this| ((**(this as *const *const IWMHeaderInfo_Vtbl)).RemoveMarker)(this, windex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScriptCount(this: *mut core::ffi::c_void, pcscripts: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMHeaderInfo", "GetScriptCount", 11usize, |this| ((**(this as *const *const IWMHeaderInfo_Vtbl)).GetScriptCount)(this, pcscripts), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScript(this: *mut core::ffi::c_void, windex: u16, pwsztype: windows_core::PWSTR, pcchtypelen: *mut u16, pwszcommand: windows_core::PWSTR, pcchcommandlen: *mut u16, pcnsscripttime: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMHeaderInfo", "GetScript", 12usize, |this| ((**(this as *const *const IWMHeaderInfo_Vtbl)).GetScript)(this, windex, pwsztype, pcchtypelen, pwszcommand, pcchcommandlen, pcnsscripttime), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddScript(this: *mut core::ffi::c_void, pwsztype: windows_core::PCWSTR, pwszcommand: windows_core::PCWSTR, cnsscripttime: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMHeaderInfo", "AddScript", 13usize, |this| ((**(this as *const *const IWMHeaderInfo_Vtbl)).AddScript)(this, pwsztype, pwszcommand, cnsscripttime), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveScript(this: *mut core::ffi::c_void, windex: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMHeaderInfo", "RemoveScript", 14usize, |this| ((**(this as *const *const IWMHeaderInfo_Vtbl)).RemoveScript)(this, windex), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetAttributeCount,
+            GetAttributeByIndex,
+            GetAttributeByName,
+            SetAttribute,
+            GetMarkerCount,
+            GetMarker,
+            AddMarker,
+            RemoveMarker,
+            GetScriptCount,
+            GetScript,
+            AddScript,
+            RemoveScript,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMHeaderInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMHeaderInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCodecInfoCount(this: *mut core::ffi::c_void, pccodecinfos: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMHeaderInfo2", "GetCodecInfoCount", 15usize, |this| ((**(this as *const *const IWMHeaderInfo2_Vtbl)).GetCodecInfoCount)(this, pccodecinfos), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCodecInfo(this: *mut core::ffi::c_void, windex: u32, pcchname: *mut u16, pwszname: windows_core::PWSTR, pcchdescription: *mut u16, pwszdescription: windows_core::PWSTR, pcodectype: *mut WMT_CODEC_INFO_TYPE, pcbcodecinfo: *mut u16, pbcodecinfo: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMHeaderInfo2", "GetCodecInfo", 16usize, |this| ((**(this as *const *const IWMHeaderInfo2_Vtbl)).GetCodecInfo)(this, windex, pcchname, pwszname, pcchdescription, pwszdescription, pcodectype, pcbcodecinfo, pbcodecinfo), |result| Some(*result))
+        }
+        Self { base__: <IWMHeaderInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCodecInfoCount, GetCodecInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMHeaderInfo2 as windows_core::Interface>::IID || <IWMHeaderInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMHeaderInfo3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAttributeCountEx(this: *mut core::ffi::c_void, wstreamnum: u16, pcattributes: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMHeaderInfo3", "GetAttributeCountEx", 17usize, |this| ((**(this as *const *const IWMHeaderInfo3_Vtbl)).GetAttributeCountEx)(this, wstreamnum, pcattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributeIndices(this: *mut core::ffi::c_void, wstreamnum: u16, pwszname: windows_core::PCWSTR, pwlangindex: *const u16, pwindices: *mut u16, pwcount: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMHeaderInfo3", "GetAttributeIndices", 18usize, |this| ((**(this as *const *const IWMHeaderInfo3_Vtbl)).GetAttributeIndices)(this, wstreamnum, pwszname, pwlangindex, pwindices, pwcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributeByIndexEx(this: *mut core::ffi::c_void, wstreamnum: u16, windex: u16, pwszname: windows_core::PWSTR, pwnamelen: *mut u16, ptype: *mut WMT_ATTR_DATATYPE, pwlangindex: *mut u16, pvalue: *mut u8, pdwdatalength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMHeaderInfo3", "GetAttributeByIndexEx", 19usize, |this| ((**(this as *const *const IWMHeaderInfo3_Vtbl)).GetAttributeByIndexEx)(this, wstreamnum, windex, pwszname, pwnamelen, ptype, pwlangindex, pvalue, pdwdatalength), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModifyAttribute(this: *mut core::ffi::c_void, wstreamnum: u16, windex: u16, r#type: WMT_ATTR_DATATYPE, wlangindex: u16, pvalue: *const u8, dwlength: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMHeaderInfo3", "ModifyAttribute", 20usize, |this| ((**(this as *const *const IWMHeaderInfo3_Vtbl)).ModifyAttribute)(this, wstreamnum, windex, r#type, wlangindex, pvalue, dwlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddAttribute(this: *mut core::ffi::c_void, wstreamnum: u16, pszname: windows_core::PCWSTR, pwindex: *mut u16, r#type: WMT_ATTR_DATATYPE, wlangindex: u16, pvalue: *const u8, dwlength: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMHeaderInfo3", "AddAttribute", 21usize, |this| ((**(this as *const *const IWMHeaderInfo3_Vtbl)).AddAttribute)(this, wstreamnum, pszname, pwindex, r#type, wlangindex, pvalue, dwlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAttribute(this: *mut core::ffi::c_void, wstreamnum: u16, windex: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMHeaderInfo3", "DeleteAttribute", 22usize, |this| ((**(this as *const *const IWMHeaderInfo3_Vtbl)).DeleteAttribute)(this, wstreamnum, windex), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddCodecInfo(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR, pwszdescription: windows_core::PCWSTR, codectype: WMT_CODEC_INFO_TYPE, cbcodecinfo: u16, pbcodecinfo: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMHeaderInfo3", "AddCodecInfo", 23usize, |this| ((**(this as *const *const IWMHeaderInfo3_Vtbl)).AddCodecInfo)(this, pwszname, pwszdescription, codectype, cbcodecinfo, pbcodecinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMHeaderInfo2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetAttributeCountEx,
+            GetAttributeIndices,
+            GetAttributeByIndexEx,
+            ModifyAttribute,
+            AddAttribute,
+            DeleteAttribute,
+            AddCodecInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMHeaderInfo3 as windows_core::Interface>::IID || <IWMHeaderInfo2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMIStreamProps_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, ptype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pdwsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMIStreamProps", "GetProperty", 3usize, |this| ((**(this as *const *const IWMIStreamProps_Vtbl)).GetProperty)(this, pszname, ptype, pvalue, pdwsize), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProperty }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMIStreamProps as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMImageInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetImageCount(this: *mut core::ffi::c_void, pcimages: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMImageInfo", "GetImageCount", 3usize, |this| ((**(this as *const *const IWMImageInfo_Vtbl)).GetImageCount)(this, pcimages), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetImage(this: *mut core::ffi::c_void, windex: u32, pcchmimetype: *mut u16, pwszmimetype: windows_core::PWSTR, pcchdescription: *mut u16, pwszdescription: windows_core::PWSTR, pimagetype: *mut u16, pcbimagedata: *mut u32, pbimagedata: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMImageInfo", "GetImage", 4usize, |this| ((**(this as *const *const IWMImageInfo_Vtbl)).GetImage)(this, windex, pcchmimetype, pwszmimetype, pcchdescription, pwszdescription, pimagetype, pcbimagedata, pbimagedata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetImageCount, GetImage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMImageInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMIndexer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartIndexing(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, pcallback: *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMIndexer", "StartIndexing", 3usize, |this| ((**(this as *const *const IWMIndexer_Vtbl)).StartIndexing)(this, pwszurl, pcallback, pvcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMIndexer", "Cancel", 4usize, |this| ((**(this as *const *const IWMIndexer_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartIndexing, Cancel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMIndexer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMIndexer2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Configure(this: *mut core::ffi::c_void, wstreamnum: u16, nindexertype: WMT_INDEXER_TYPE, pvinterval: *const core::ffi::c_void, pvindextype: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMIndexer2", "Configure", 5usize, |this| ((**(this as *const *const IWMIndexer2_Vtbl)).Configure)(this, wstreamnum, nindexertype, pvinterval, pvindextype), |result| Some(*result))
+        }
+        Self { base__: <IWMIndexer_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Configure }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMIndexer2 as windows_core::Interface>::IID || <IWMIndexer_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMInputMediaProps_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetConnectionName(this: *mut core::ffi::c_void, pwszname: windows_core::PWSTR, pcchname: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMInputMediaProps", "GetConnectionName", 6usize, |this| ((**(this as *const *const IWMInputMediaProps_Vtbl)).GetConnectionName)(this, pwszname, pcchname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGroupName(this: *mut core::ffi::c_void, pwszname: windows_core::PWSTR, pcchname: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMInputMediaProps", "GetGroupName", 7usize, |this| ((**(this as *const *const IWMInputMediaProps_Vtbl)).GetGroupName)(this, pwszname, pcchname), |result| Some(*result))
+        }
+        Self { base__: <IWMMediaProps_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetConnectionName, GetGroupName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMInputMediaProps as windows_core::Interface>::IID || <IWMMediaProps_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMLanguageList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLanguageCount(this: *mut core::ffi::c_void, pwcount: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMLanguageList", "GetLanguageCount", 3usize, |this| ((**(this as *const *const IWMLanguageList_Vtbl)).GetLanguageCount)(this, pwcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLanguageDetails(this: *mut core::ffi::c_void, windex: u16, pwszlanguagestring: windows_core::PWSTR, pcchlanguagestringlength: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMLanguageList", "GetLanguageDetails", 4usize, |this| ((**(this as *const *const IWMLanguageList_Vtbl)).GetLanguageDetails)(this, windex, pwszlanguagestring, pcchlanguagestringlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddLanguageByRFC1766String(this: *mut core::ffi::c_void, pwszlanguagestring: windows_core::PCWSTR, pwindex: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMLanguageList", "AddLanguageByRFC1766String", 5usize, |this| ((**(this as *const *const IWMLanguageList_Vtbl)).AddLanguageByRFC1766String)(this, pwszlanguagestring, pwindex), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetLanguageCount, GetLanguageDetails, AddLanguageByRFC1766String }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMLanguageList as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMLicenseBackup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BackupLicenses(this: *mut core::ffi::c_void, dwflags: u32, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMLicenseBackup", "BackupLicenses", 3usize, |this| ((**(this as *const *const IWMLicenseBackup_Vtbl)).BackupLicenses)(this, dwflags, pcallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelLicenseBackup(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMLicenseBackup", "CancelLicenseBackup", 4usize, |this| ((**(this as *const *const IWMLicenseBackup_Vtbl)).CancelLicenseBackup)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BackupLicenses, CancelLicenseBackup }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMLicenseBackup as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMLicenseRestore_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RestoreLicenses(this: *mut core::ffi::c_void, dwflags: u32, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMLicenseRestore", "RestoreLicenses", 3usize, |this| ((**(this as *const *const IWMLicenseRestore_Vtbl)).RestoreLicenses)(this, dwflags, pcallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelLicenseRestore(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMLicenseRestore", "CancelLicenseRestore", 4usize, |this| ((**(this as *const *const IWMLicenseRestore_Vtbl)).CancelLicenseRestore)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RestoreLicenses, CancelLicenseRestore }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMLicenseRestore as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMLicenseRevocationAgent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLRBChallenge(this: *mut core::ffi::c_void, pmachineid: *const u8, dwmachineidlength: u32, pchallenge: *const u8, dwchallengelength: u32, pchallengeoutput: *mut u8, pdwchallengeoutputlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMLicenseRevocationAgent", "GetLRBChallenge", 3usize, |this| ((**(this as *const *const IWMLicenseRevocationAgent_Vtbl)).GetLRBChallenge)(this, pmachineid, dwmachineidlength, pchallenge, dwchallengelength, pchallengeoutput, pdwchallengeoutputlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessLRB(this: *mut core::ffi::c_void, psignedlrb: *const u8, dwsignedlrblength: u32, psignedack: *mut u8, pdwsignedacklength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMLicenseRevocationAgent", "ProcessLRB", 4usize, |this| ((**(this as *const *const IWMLicenseRevocationAgent_Vtbl)).ProcessLRB)(this, psignedlrb, dwsignedlrblength, psignedack, pdwsignedacklength), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetLRBChallenge, ProcessLRB }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMLicenseRevocationAgent as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMMediaProps_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, pguidtype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMMediaProps", "GetType", 3usize, |this| ((**(this as *const *const IWMMediaProps_Vtbl)).GetType)(this, pguidtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMediaType(this: *mut core::ffi::c_void, ptype: *mut WM_MEDIA_TYPE, pcbtype: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMMediaProps", "GetMediaType", 4usize, |this| ((**(this as *const *const IWMMediaProps_Vtbl)).GetMediaType)(this, ptype, pcbtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMediaType(this: *mut core::ffi::c_void, ptype: *const WM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMMediaProps", "SetMediaType", 5usize, |this| ((**(this as *const *const IWMMediaProps_Vtbl)).SetMediaType)(this, ptype), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetType, GetMediaType, SetMediaType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMMediaProps as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMMetadataEditor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, pwszfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMMetadataEditor", "Open", 3usize, |this| ((**(this as *const *const IWMMetadataEditor_Vtbl)).Open)(this, pwszfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMMetadataEditor", "Close", 4usize, |this| ((**(this as *const *const IWMMetadataEditor_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flush(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMMetadataEditor", "Flush", 5usize, |this| ((**(this as *const *const IWMMetadataEditor_Vtbl)).Flush)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Open, Close, Flush }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMMetadataEditor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMMetadataEditor2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenEx(this: *mut core::ffi::c_void, pwszfilename: windows_core::PCWSTR, dwdesiredaccess: u32, dwsharemode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMMetadataEditor2", "OpenEx", 6usize, |this| ((**(this as *const *const IWMMetadataEditor2_Vtbl)).OpenEx)(this, pwszfilename, dwdesiredaccess, dwsharemode), |result| Some(*result))
+        }
+        Self { base__: <IWMMetadataEditor_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OpenEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMMetadataEditor2 as windows_core::Interface>::IID || <IWMMetadataEditor_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMMutualExclusion_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, pguidtype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMMutualExclusion", "GetType", 6usize, |this| ((**(this as *const *const IWMMutualExclusion_Vtbl)).GetType)(this, pguidtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetType(this: *mut core::ffi::c_void, guidtype: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMMutualExclusion", "SetType", 7usize, |this| ((**(this as *const *const IWMMutualExclusion_Vtbl)).SetType)(this, guidtype), |result| Some(*result))
+        }
+        Self { base__: <IWMStreamList_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetType, SetType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMMutualExclusion as windows_core::Interface>::IID || <IWMStreamList_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMMutualExclusion2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, pwszname: windows_core::PWSTR, pcchname: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMMutualExclusion2", "GetName", 8usize, |this| ((**(this as *const *const IWMMutualExclusion2_Vtbl)).GetName)(this, pwszname, pcchname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMMutualExclusion2", "SetName", 9usize, |this| ((**(this as *const *const IWMMutualExclusion2_Vtbl)).SetName)(this, pwszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordCount(this: *mut core::ffi::c_void, pwrecordcount: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMMutualExclusion2", "GetRecordCount", 10usize, |this| ((**(this as *const *const IWMMutualExclusion2_Vtbl)).GetRecordCount)(this, pwrecordcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddRecord(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMMutualExclusion2", "AddRecord", 11usize, |this| ((**(this as *const *const IWMMutualExclusion2_Vtbl)).AddRecord)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRecord(this: *mut core::ffi::c_void, wrecordnumber: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMMutualExclusion2", "RemoveRecord", 12usize, |this| ((**(this as *const *const IWMMutualExclusion2_Vtbl)).RemoveRecord)(this, wrecordnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordName(this: *mut core::ffi::c_void, wrecordnumber: u16, pwszrecordname: windows_core::PWSTR, pcchrecordname: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMMutualExclusion2", "GetRecordName", 13usize, |this| ((**(this as *const *const IWMMutualExclusion2_Vtbl)).GetRecordName)(this, wrecordnumber, pwszrecordname, pcchrecordname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRecordName(this: *mut core::ffi::c_void, wrecordnumber: u16, pwszrecordname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMMutualExclusion2", "SetRecordName", 14usize, |this| ((**(this as *const *const IWMMutualExclusion2_Vtbl)).SetRecordName)(this, wrecordnumber, pwszrecordname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamsForRecord(this: *mut core::ffi::c_void, wrecordnumber: u16, pwstreamnumarray: *mut u16, pcstreams: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMMutualExclusion2", "GetStreamsForRecord", 15usize, |this| ((**(this as *const *const IWMMutualExclusion2_Vtbl)).GetStreamsForRecord)(this, wrecordnumber, pwstreamnumarray, pcstreams), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddStreamForRecord(this: *mut core::ffi::c_void, wrecordnumber: u16, wstreamnumber: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMMutualExclusion2", "AddStreamForRecord", 16usize, |this| ((**(this as *const *const IWMMutualExclusion2_Vtbl)).AddStreamForRecord)(this, wrecordnumber, wstreamnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStreamForRecord(this: *mut core::ffi::c_void, wrecordnumber: u16, wstreamnumber: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMMutualExclusion2", "RemoveStreamForRecord", 17usize, |this| ((**(this as *const *const IWMMutualExclusion2_Vtbl)).RemoveStreamForRecord)(this, wrecordnumber, wstreamnumber), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMMutualExclusion_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetName,
+            SetName,
+            GetRecordCount,
+            AddRecord,
+            RemoveRecord,
+            GetRecordName,
+            SetRecordName,
+            GetStreamsForRecord,
+            AddStreamForRecord,
+            RemoveStreamForRecord,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMMutualExclusion2 as windows_core::Interface>::IID || <IWMMutualExclusion_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMOutputMediaProps_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStreamGroupName(this: *mut core::ffi::c_void, pwszname: windows_core::PWSTR, pcchname: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMOutputMediaProps", "GetStreamGroupName", 6usize, |this| ((**(this as *const *const IWMOutputMediaProps_Vtbl)).GetStreamGroupName)(this, pwszname, pcchname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConnectionName(this: *mut core::ffi::c_void, pwszname: windows_core::PWSTR, pcchname: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMOutputMediaProps", "GetConnectionName", 7usize, |this| ((**(this as *const *const IWMOutputMediaProps_Vtbl)).GetConnectionName)(this, pwszname, pcchname), |result| Some(*result))
+        }
+        Self { base__: <IWMMediaProps_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStreamGroupName, GetConnectionName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMOutputMediaProps as windows_core::Interface>::IID || <IWMMediaProps_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPacketSize_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMaxPacketSize(this: *mut core::ffi::c_void, pdwmaxpacketsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPacketSize", "GetMaxPacketSize", 3usize, |this| ((**(this as *const *const IWMPacketSize_Vtbl)).GetMaxPacketSize)(this, pdwmaxpacketsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxPacketSize(this: *mut core::ffi::c_void, dwmaxpacketsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPacketSize", "SetMaxPacketSize", 4usize, |this| ((**(this as *const *const IWMPacketSize_Vtbl)).SetMaxPacketSize)(this, dwmaxpacketsize), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMaxPacketSize, SetMaxPacketSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPacketSize as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPacketSize2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMinPacketSize(this: *mut core::ffi::c_void, pdwminpacketsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPacketSize2", "GetMinPacketSize", 5usize, |this| ((**(this as *const *const IWMPacketSize2_Vtbl)).GetMinPacketSize)(this, pdwminpacketsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinPacketSize(this: *mut core::ffi::c_void, dwminpacketsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPacketSize2", "SetMinPacketSize", 6usize, |this| ((**(this as *const *const IWMPacketSize2_Vtbl)).SetMinPacketSize)(this, dwminpacketsize), |result| Some(*result))
+        }
+        Self { base__: <IWMPacketSize_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMinPacketSize, SetMinPacketSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPacketSize2 as windows_core::Interface>::IID || <IWMPacketSize_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPlayerHook_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PreDecode(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPlayerHook", "PreDecode", 3usize, |this| ((**(this as *const *const IWMPlayerHook_Vtbl)).PreDecode)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PreDecode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPlayerHook as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPlayerTimestampHook_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MapTimestamp(this: *mut core::ffi::c_void, rtin: i64, prtout: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPlayerTimestampHook", "MapTimestamp", 3usize, |this| ((**(this as *const *const IWMPlayerTimestampHook_Vtbl)).MapTimestamp)(this, rtin, prtout), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MapTimestamp }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPlayerTimestampHook as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMProfile_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetVersion(this: *mut core::ffi::c_void, pdwversion: *mut WMT_VERSION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile", "GetVersion", 3usize, |this| ((**(this as *const *const IWMProfile_Vtbl)).GetVersion)(this, pdwversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, pwszname: windows_core::PWSTR, pcchname: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile", "GetName", 4usize, |this| ((**(this as *const *const IWMProfile_Vtbl)).GetName)(this, pwszname, pcchname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile", "SetName", 5usize, |this| ((**(this as *const *const IWMProfile_Vtbl)).SetName)(this, pwszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescription(this: *mut core::ffi::c_void, pwszdescription: windows_core::PWSTR, pcchdescription: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile", "GetDescription", 6usize, |this| ((**(this as *const *const IWMProfile_Vtbl)).GetDescription)(this, pwszdescription, pcchdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, pwszdescription: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile", "SetDescription", 7usize, |this| ((**(this as *const *const IWMProfile_Vtbl)).SetDescription)(this, pwszdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamCount(this: *mut core::ffi::c_void, pcstreams: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile", "GetStreamCount", 8usize, |this| ((**(this as *const *const IWMProfile_Vtbl)).GetStreamCount)(this, pcstreams), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStream(this: *mut core::ffi::c_void, dwstreamindex: u32, ppconfig: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile", "GetStream", 9usize, |this| ((**(this as *const *const IWMProfile_Vtbl)).GetStream)(this, dwstreamindex, ppconfig), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamByNumber(this: *mut core::ffi::c_void, wstreamnum: u16, ppconfig: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile", "GetStreamByNumber", 10usize, |this| ((**(this as *const *const IWMProfile_Vtbl)).GetStreamByNumber)(this, wstreamnum, ppconfig), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStream(this: *mut core::ffi::c_void, pconfig: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile", "RemoveStream", 11usize, |this| ((**(this as *const *const IWMProfile_Vtbl)).RemoveStream)(this, pconfig), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStreamByNumber(this: *mut core::ffi::c_void, wstreamnum: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile", "RemoveStreamByNumber", 12usize, |this| ((**(this as *const *const IWMProfile_Vtbl)).RemoveStreamByNumber)(this, wstreamnum), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddStream(this: *mut core::ffi::c_void, pconfig: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile", "AddStream", 13usize, |this| ((**(this as *const *const IWMProfile_Vtbl)).AddStream)(this, pconfig), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReconfigStream(this: *mut core::ffi::c_void, pconfig: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile", "ReconfigStream", 14usize, |this| ((**(this as *const *const IWMProfile_Vtbl)).ReconfigStream)(this, pconfig), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateNewStream(this: *mut core::ffi::c_void, guidstreamtype: *const windows_core::GUID, ppconfig: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile", "CreateNewStream", 15usize, |this| ((**(this as *const *const IWMProfile_Vtbl)).CreateNewStream)(this, guidstreamtype, ppconfig), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMutualExclusionCount(this: *mut core::ffi::c_void, pcme: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile", "GetMutualExclusionCount", 16usize, |this| ((**(this as *const *const IWMProfile_Vtbl)).GetMutualExclusionCount)(this, pcme), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMutualExclusion(this: *mut core::ffi::c_void, dwmeindex: u32, ppme: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile", "GetMutualExclusion", 17usize, |this| ((**(this as *const *const IWMProfile_Vtbl)).GetMutualExclusion)(this, dwmeindex, ppme), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMutualExclusion(this: *mut core::ffi::c_void, pme: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile", "RemoveMutualExclusion", 18usize, |this| ((**(this as *const *const IWMProfile_Vtbl)).RemoveMutualExclusion)(this, pme), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddMutualExclusion(this: *mut core::ffi::c_void, pme: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile", "AddMutualExclusion", 19usize, |this| ((**(this as *const *const IWMProfile_Vtbl)).AddMutualExclusion)(this, pme), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateNewMutualExclusion(this: *mut core::ffi::c_void, ppme: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile", "CreateNewMutualExclusion", 20usize, |this| ((**(this as *const *const IWMProfile_Vtbl)).CreateNewMutualExclusion)(this, ppme), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetVersion,
+            GetName,
+            SetName,
+            GetDescription,
+            SetDescription,
+            GetStreamCount,
+            GetStream,
+            GetStreamByNumber,
+            RemoveStream,
+            RemoveStreamByNumber,
+            AddStream,
+            ReconfigStream,
+            CreateNewStream,
+            GetMutualExclusionCount,
+            GetMutualExclusion,
+            RemoveMutualExclusion,
+            AddMutualExclusion,
+            CreateNewMutualExclusion,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMProfile as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMProfile2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProfileID(this: *mut core::ffi::c_void, pguidid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile2", "GetProfileID", 21usize, |this| ((**(this as *const *const IWMProfile2_Vtbl)).GetProfileID)(this, pguidid), |result| Some(*result))
+        }
+        Self { base__: <IWMProfile_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProfileID }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMProfile2 as windows_core::Interface>::IID || <IWMProfile_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMProfile3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStorageFormat(this: *mut core::ffi::c_void, pnstorageformat: *mut WMT_STORAGE_FORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile3", "GetStorageFormat", 22usize, |this| ((**(this as *const *const IWMProfile3_Vtbl)).GetStorageFormat)(this, pnstorageformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStorageFormat(this: *mut core::ffi::c_void, nstorageformat: WMT_STORAGE_FORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile3", "SetStorageFormat", 23usize, |this| ((**(this as *const *const IWMProfile3_Vtbl)).SetStorageFormat)(this, nstorageformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBandwidthSharingCount(this: *mut core::ffi::c_void, pcbs: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile3", "GetBandwidthSharingCount", 24usize, |this| ((**(this as *const *const IWMProfile3_Vtbl)).GetBandwidthSharingCount)(this, pcbs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBandwidthSharing(this: *mut core::ffi::c_void, dwbsindex: u32, ppbs: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile3", "GetBandwidthSharing", 25usize, |this| ((**(this as *const *const IWMProfile3_Vtbl)).GetBandwidthSharing)(this, dwbsindex, ppbs), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBandwidthSharing(this: *mut core::ffi::c_void, pbs: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile3", "RemoveBandwidthSharing", 26usize, |this| ((**(this as *const *const IWMProfile3_Vtbl)).RemoveBandwidthSharing)(this, pbs), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddBandwidthSharing(this: *mut core::ffi::c_void, pbs: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile3", "AddBandwidthSharing", 27usize, |this| ((**(this as *const *const IWMProfile3_Vtbl)).AddBandwidthSharing)(this, pbs), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateNewBandwidthSharing(this: *mut core::ffi::c_void, ppbs: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile3", "CreateNewBandwidthSharing", 28usize, |this| ((**(this as *const *const IWMProfile3_Vtbl)).CreateNewBandwidthSharing)(this, ppbs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamPrioritization(this: *mut core::ffi::c_void, ppsp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile3", "GetStreamPrioritization", 29usize, |this| ((**(this as *const *const IWMProfile3_Vtbl)).GetStreamPrioritization)(this, ppsp), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStreamPrioritization(this: *mut core::ffi::c_void, psp: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile3", "SetStreamPrioritization", 30usize, |this| ((**(this as *const *const IWMProfile3_Vtbl)).SetStreamPrioritization)(this, psp), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStreamPrioritization(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile3", "RemoveStreamPrioritization", 31usize, |this| ((**(this as *const *const IWMProfile3_Vtbl)).RemoveStreamPrioritization)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateNewStreamPrioritization(this: *mut core::ffi::c_void, ppsp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile3", "CreateNewStreamPrioritization", 32usize, |this| ((**(this as *const *const IWMProfile3_Vtbl)).CreateNewStreamPrioritization)(this, ppsp), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExpectedPacketCount(this: *mut core::ffi::c_void, msduration: u64, pcpackets: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfile3", "GetExpectedPacketCount", 33usize, |this| ((**(this as *const *const IWMProfile3_Vtbl)).GetExpectedPacketCount)(this, msduration, pcpackets), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMProfile2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetStorageFormat,
+            SetStorageFormat,
+            GetBandwidthSharingCount,
+            GetBandwidthSharing,
+            RemoveBandwidthSharing,
+            AddBandwidthSharing,
+            CreateNewBandwidthSharing,
+            GetStreamPrioritization,
+            SetStreamPrioritization,
+            RemoveStreamPrioritization,
+            CreateNewStreamPrioritization,
+            GetExpectedPacketCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMProfile3 as windows_core::Interface>::IID || <IWMProfile2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMProfileManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateEmptyProfile(this: *mut core::ffi::c_void, dwversion: WMT_VERSION, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfileManager", "CreateEmptyProfile", 3usize, |this| ((**(this as *const *const IWMProfileManager_Vtbl)).CreateEmptyProfile)(this, dwversion, ppprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadProfileByID(this: *mut core::ffi::c_void, guidprofile: *const windows_core::GUID, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfileManager", "LoadProfileByID", 4usize, |this| ((**(this as *const *const IWMProfileManager_Vtbl)).LoadProfileByID)(this, guidprofile, ppprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadProfileByData(this: *mut core::ffi::c_void, pwszprofile: windows_core::PCWSTR, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfileManager", "LoadProfileByData", 5usize, |this| ((**(this as *const *const IWMProfileManager_Vtbl)).LoadProfileByData)(this, pwszprofile, ppprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveProfile(this: *mut core::ffi::c_void, piwmprofile: *mut core::ffi::c_void, pwszprofile: windows_core::PCWSTR, pdwlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfileManager", "SaveProfile", 6usize, |this| ((**(this as *const *const IWMProfileManager_Vtbl)).SaveProfile)(this, piwmprofile, pwszprofile, pdwlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSystemProfileCount(this: *mut core::ffi::c_void, pcprofiles: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfileManager", "GetSystemProfileCount", 7usize, |this| ((**(this as *const *const IWMProfileManager_Vtbl)).GetSystemProfileCount)(this, pcprofiles), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadSystemProfile(this: *mut core::ffi::c_void, dwprofileindex: u32, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfileManager", "LoadSystemProfile", 8usize, |this| ((**(this as *const *const IWMProfileManager_Vtbl)).LoadSystemProfile)(this, dwprofileindex, ppprofile), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateEmptyProfile,
+            LoadProfileByID,
+            LoadProfileByData,
+            SaveProfile,
+            GetSystemProfileCount,
+            LoadSystemProfile,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMProfileManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMProfileManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSystemProfileVersion(this: *mut core::ffi::c_void, pdwversion: *mut WMT_VERSION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfileManager2", "GetSystemProfileVersion", 9usize, |this| ((**(this as *const *const IWMProfileManager2_Vtbl)).GetSystemProfileVersion)(this, pdwversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSystemProfileVersion(this: *mut core::ffi::c_void, dwversion: WMT_VERSION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfileManager2", "SetSystemProfileVersion", 10usize, |this| ((**(this as *const *const IWMProfileManager2_Vtbl)).SetSystemProfileVersion)(this, dwversion), |result| Some(*result))
+        }
+        Self { base__: <IWMProfileManager_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSystemProfileVersion, SetSystemProfileVersion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMProfileManager2 as windows_core::Interface>::IID || <IWMProfileManager_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMProfileManagerLanguage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetUserLanguageID(this: *mut core::ffi::c_void, wlangid: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfileManagerLanguage", "GetUserLanguageID", 3usize, |this| ((**(this as *const *const IWMProfileManagerLanguage_Vtbl)).GetUserLanguageID)(this, wlangid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUserLanguageID(this: *mut core::ffi::c_void, wlangid: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProfileManagerLanguage", "SetUserLanguageID", 4usize, |this| ((**(this as *const *const IWMProfileManagerLanguage_Vtbl)).SetUserLanguageID)(this, wlangid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetUserLanguageID, SetUserLanguageID }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMProfileManagerLanguage as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMPropertyVault_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPropertyCount(this: *mut core::ffi::c_void, pdwcount: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPropertyVault", "GetPropertyCount", 3usize, |this| ((**(this as *const *const IWMPropertyVault_Vtbl)).GetPropertyCount)(this, pdwcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyByName(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, ptype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pdwsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPropertyVault", "GetPropertyByName", 4usize, |this| ((**(this as *const *const IWMPropertyVault_Vtbl)).GetPropertyByName)(this, pszname, ptype, pvalue, pdwsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, ptype: WMT_ATTR_DATATYPE, pvalue: *const u8, dwsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPropertyVault", "SetProperty", 5usize, |this| ((**(this as *const *const IWMPropertyVault_Vtbl)).SetProperty)(this, pszname, ptype, pvalue, dwsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyByIndex(this: *mut core::ffi::c_void, dwindex: u32, pszname: windows_core::PWSTR, pdwnamelen: *mut u32, ptype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pdwsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPropertyVault", "GetPropertyByIndex", 6usize, |this| ((**(this as *const *const IWMPropertyVault_Vtbl)).GetPropertyByIndex)(this, dwindex, pszname, pdwnamelen, ptype, pvalue, pdwsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyPropertiesFrom(this: *mut core::ffi::c_void, piwmpropertyvault: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPropertyVault", "CopyPropertiesFrom", 7usize, |this| ((**(this as *const *const IWMPropertyVault_Vtbl)).CopyPropertiesFrom)(this, piwmpropertyvault), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMPropertyVault", "Clear", 8usize, |this| ((**(this as *const *const IWMPropertyVault_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPropertyCount,
+            GetPropertyByName,
+            SetProperty,
+            GetPropertyByIndex,
+            CopyPropertiesFrom,
+            Clear,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMPropertyVault as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMProximityDetection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartDetection(this: *mut core::ffi::c_void, pbregistrationmsg: *const u8, cbregistrationmsg: u32, pblocaladdress: *const u8, cblocaladdress: u32, dwextraportsallowed: u32, ppregistrationresponsemsg: *mut *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMProximityDetection", "StartDetection", 3usize, |this| ((**(this as *const *const IWMProximityDetection_Vtbl)).StartDetection)(this, pbregistrationmsg, cbregistrationmsg, pblocaladdress, cblocaladdress, dwextraportsallowed, ppregistrationresponsemsg, pcallback, pvcontext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartDetection }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMProximityDetection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, pcallback: *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReader", "Open", 3usize, |this| ((**(this as *const *const IWMReader_Vtbl)).Open)(this, pwszurl, pcallback, pvcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReader", "Close", 4usize, |this| ((**(this as *const *const IWMReader_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputCount(this: *mut core::ffi::c_void, pcoutputs: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReader", "GetOutputCount", 5usize, |this| ((**(this as *const *const IWMReader_Vtbl)).GetOutputCount)(this, pcoutputs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputProps(this: *mut core::ffi::c_void, dwoutputnum: u32, ppoutput: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReader", "GetOutputProps", 6usize, |this| ((**(this as *const *const IWMReader_Vtbl)).GetOutputProps)(this, dwoutputnum, ppoutput), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputProps(this: *mut core::ffi::c_void, dwoutputnum: u32, poutput: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReader", "SetOutputProps", 7usize, |this| ((**(this as *const *const IWMReader_Vtbl)).SetOutputProps)(this, dwoutputnum, poutput), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputFormatCount(this: *mut core::ffi::c_void, dwoutputnumber: u32, pcformats: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReader", "GetOutputFormatCount", 8usize, |this| ((**(this as *const *const IWMReader_Vtbl)).GetOutputFormatCount)(this, dwoutputnumber, pcformats), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputFormat(this: *mut core::ffi::c_void, dwoutputnumber: u32, dwformatnumber: u32, ppprops: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReader", "GetOutputFormat", 9usize, |this| ((**(this as *const *const IWMReader_Vtbl)).GetOutputFormat)(this, dwoutputnumber, dwformatnumber, ppprops), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void, cnsstart: u64, cnsduration: u64, frate: f32, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReader", "Start", 10usize, |this| ((**(this as *const *const IWMReader_Vtbl)).Start)(this, cnsstart, cnsduration, frate, pvcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReader", "Stop", 11usize, |this| ((**(this as *const *const IWMReader_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReader", "Pause", 12usize, |this| ((**(this as *const *const IWMReader_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReader", "Resume", 13usize, |this| ((**(this as *const *const IWMReader_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Open,
+            Close,
+            GetOutputCount,
+            GetOutputProps,
+            SetOutputProps,
+            GetOutputFormatCount,
+            GetOutputFormat,
+            Start,
+            Stop,
+            Pause,
+            Resume,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMReader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMReaderAccelerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCodecInterface(this: *mut core::ffi::c_void, dwoutputnum: u32, riid: *const windows_core::GUID, ppvcodecinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAccelerator", "GetCodecInterface", 3usize, |this| ((**(this as *const *const IWMReaderAccelerator_Vtbl)).GetCodecInterface)(this, dwoutputnum, riid, ppvcodecinterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn Notify(this: *mut core::ffi::c_void, dwoutputnum: u32, psubtype: *const WM_MEDIA_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAccelerator", "Notify", 4usize, |this| ((**(this as *const *const IWMReaderAccelerator_Vtbl)).Notify)(this, dwoutputnum, psubtype), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCodecInterface, Notify }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMReaderAccelerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMReaderAdvanced_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetUserProvidedClock(this: *mut core::ffi::c_void, fuserclock: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced", "SetUserProvidedClock", 3usize, |this| ((**(this as *const *const IWMReaderAdvanced_Vtbl)).SetUserProvidedClock)(this, fuserclock), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUserProvidedClock(this: *mut core::ffi::c_void, pfuserclock: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced", "GetUserProvidedClock", 4usize, |this| ((**(this as *const *const IWMReaderAdvanced_Vtbl)).GetUserProvidedClock)(this, pfuserclock), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeliverTime(this: *mut core::ffi::c_void, cnstime: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced", "DeliverTime", 5usize, |this| ((**(this as *const *const IWMReaderAdvanced_Vtbl)).DeliverTime)(this, cnstime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetManualStreamSelection(this: *mut core::ffi::c_void, fselection: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced", "SetManualStreamSelection", 6usize, |this| ((**(this as *const *const IWMReaderAdvanced_Vtbl)).SetManualStreamSelection)(this, fselection), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetManualStreamSelection(this: *mut core::ffi::c_void, pfselection: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced", "GetManualStreamSelection", 7usize, |this| ((**(this as *const *const IWMReaderAdvanced_Vtbl)).GetManualStreamSelection)(this, pfselection), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStreamsSelected(this: *mut core::ffi::c_void, cstreamcount: u16, pwstreamnumbers: *const u16, pselections: *const WMT_STREAM_SELECTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced", "SetStreamsSelected", 8usize, |this| ((**(this as *const *const IWMReaderAdvanced_Vtbl)).SetStreamsSelected)(this, cstreamcount, pwstreamnumbers, pselections), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamSelected(this: *mut core::ffi::c_void, wstreamnum: u16, pselection: *mut WMT_STREAM_SELECTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced", "GetStreamSelected", 9usize, |this| ((**(this as *const *const IWMReaderAdvanced_Vtbl)).GetStreamSelected)(this, wstreamnum, pselection), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReceiveSelectionCallbacks(this: *mut core::ffi::c_void, fgetcallbacks: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced", "SetReceiveSelectionCallbacks", 10usize, |this| ((**(this as *const *const IWMReaderAdvanced_Vtbl)).SetReceiveSelectionCallbacks)(this, fgetcallbacks), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReceiveSelectionCallbacks(this: *mut core::ffi::c_void, pfgetcallbacks: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced", "GetReceiveSelectionCallbacks", 11usize, |this| ((**(this as *const *const IWMReaderAdvanced_Vtbl)).GetReceiveSelectionCallbacks)(this, pfgetcallbacks), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReceiveStreamSamples(this: *mut core::ffi::c_void, wstreamnum: u16, freceivestreamsamples: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced", "SetReceiveStreamSamples", 12usize, |this| ((**(this as *const *const IWMReaderAdvanced_Vtbl)).SetReceiveStreamSamples)(this, wstreamnum, freceivestreamsamples), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReceiveStreamSamples(this: *mut core::ffi::c_void, wstreamnum: u16, pfreceivestreamsamples: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced", "GetReceiveStreamSamples", 13usize, |this| ((**(this as *const *const IWMReaderAdvanced_Vtbl)).GetReceiveStreamSamples)(this, wstreamnum, pfreceivestreamsamples), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllocateForOutput(this: *mut core::ffi::c_void, dwoutputnum: u32, fallocate: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced", "SetAllocateForOutput", 14usize, |this| ((**(this as *const *const IWMReaderAdvanced_Vtbl)).SetAllocateForOutput)(this, dwoutputnum, fallocate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllocateForOutput(this: *mut core::ffi::c_void, dwoutputnum: u32, pfallocate: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced", "GetAllocateForOutput", 15usize, |this| ((**(this as *const *const IWMReaderAdvanced_Vtbl)).GetAllocateForOutput)(this, dwoutputnum, pfallocate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllocateForStream(this: *mut core::ffi::c_void, wstreamnum: u16, fallocate: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced", "SetAllocateForStream", 16usize, |this| ((**(this as *const *const IWMReaderAdvanced_Vtbl)).SetAllocateForStream)(this, wstreamnum, fallocate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllocateForStream(this: *mut core::ffi::c_void, dwsreamnum: u16, pfallocate: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced", "GetAllocateForStream", 17usize, |this| ((**(this as *const *const IWMReaderAdvanced_Vtbl)).GetAllocateForStream)(this, dwsreamnum, pfallocate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatistics(this: *mut core::ffi::c_void, pstatistics: *mut WM_READER_STATISTICS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced", "GetStatistics", 18usize, |this| ((**(this as *const *const IWMReaderAdvanced_Vtbl)).GetStatistics)(this, pstatistics), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClientInfo(this: *mut core::ffi::c_void, pclientinfo: *const WM_READER_CLIENTINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced", "SetClientInfo", 19usize, |this| ((**(this as *const *const IWMReaderAdvanced_Vtbl)).SetClientInfo)(this, pclientinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxOutputSampleSize(this: *mut core::ffi::c_void, dwoutput: u32, pcbmax: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced", "GetMaxOutputSampleSize", 20usize, |this| ((**(this as *const *const IWMReaderAdvanced_Vtbl)).GetMaxOutputSampleSize)(this, dwoutput, pcbmax), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxStreamSampleSize(this: *mut core::ffi::c_void, wstream: u16, pcbmax: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced", "GetMaxStreamSampleSize", 21usize, |this| ((**(this as *const *const IWMReaderAdvanced_Vtbl)).GetMaxStreamSampleSize)(this, wstream, pcbmax), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyLateDelivery(this: *mut core::ffi::c_void, cnslateness: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced", "NotifyLateDelivery", 22usize, |this| ((**(this as *const *const IWMReaderAdvanced_Vtbl)).NotifyLateDelivery)(this, cnslateness), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetUserProvidedClock,
+            GetUserProvidedClock,
+            DeliverTime,
+            SetManualStreamSelection,
+            GetManualStreamSelection,
+            SetStreamsSelected,
+            GetStreamSelected,
+            SetReceiveSelectionCallbacks,
+            GetReceiveSelectionCallbacks,
+            SetReceiveStreamSamples,
+            GetReceiveStreamSamples,
+            SetAllocateForOutput,
+            GetAllocateForOutput,
+            SetAllocateForStream,
+            GetAllocateForStream,
+            GetStatistics,
+            SetClientInfo,
+            GetMaxOutputSampleSize,
+            GetMaxStreamSampleSize,
+            NotifyLateDelivery,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMReaderAdvanced as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMReaderAdvanced2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPlayMode(this: *mut core::ffi::c_void, mode: WMT_PLAY_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced2", "SetPlayMode", 23usize, |this| ((**(this as *const *const IWMReaderAdvanced2_Vtbl)).SetPlayMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPlayMode(this: *mut core::ffi::c_void, pmode: *mut WMT_PLAY_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced2", "GetPlayMode", 24usize, |this| ((**(this as *const *const IWMReaderAdvanced2_Vtbl)).GetPlayMode)(this, pmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBufferProgress(this: *mut core::ffi::c_void, pdwpercent: *mut u32, pcnsbuffering: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced2", "GetBufferProgress", 25usize, |this| ((**(this as *const *const IWMReaderAdvanced2_Vtbl)).GetBufferProgress)(this, pdwpercent, pcnsbuffering), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDownloadProgress(this: *mut core::ffi::c_void, pdwpercent: *mut u32, pqwbytesdownloaded: *mut u64, pcnsdownload: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced2", "GetDownloadProgress", 26usize, |this| ((**(this as *const *const IWMReaderAdvanced2_Vtbl)).GetDownloadProgress)(this, pdwpercent, pqwbytesdownloaded, pcnsdownload), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSaveAsProgress(this: *mut core::ffi::c_void, pdwpercent: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced2", "GetSaveAsProgress", 27usize, |this| ((**(this as *const *const IWMReaderAdvanced2_Vtbl)).GetSaveAsProgress)(this, pdwpercent), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveFileAs(this: *mut core::ffi::c_void, pwszfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced2", "SaveFileAs", 28usize, |this| ((**(this as *const *const IWMReaderAdvanced2_Vtbl)).SaveFileAs)(this, pwszfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProtocolName(this: *mut core::ffi::c_void, pwszprotocol: windows_core::PWSTR, pcchprotocol: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced2", "GetProtocolName", 29usize, |this| ((**(this as *const *const IWMReaderAdvanced2_Vtbl)).GetProtocolName)(this, pwszprotocol, pcchprotocol), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartAtMarker(this: *mut core::ffi::c_void, wmarkerindex: u16, cnsduration: u64, frate: f32, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced2", "StartAtMarker", 30usize, |this| ((**(this as *const *const IWMReaderAdvanced2_Vtbl)).StartAtMarker)(this, wmarkerindex, cnsduration, frate, pvcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputSetting(this: *mut core::ffi::c_void, dwoutputnum: u32, pszname: windows_core::PCWSTR, ptype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pcblength: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced2", "GetOutputSetting", 31usize, |this| ((**(this as *const *const IWMReaderAdvanced2_Vtbl)).GetOutputSetting)(this, dwoutputnum, pszname, ptype, pvalue, pcblength), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputSetting(this: *mut core::ffi::c_void, dwoutputnum: u32, pszname: windows_core::PCWSTR, r#type: WMT_ATTR_DATATYPE, pvalue: *const u8, cblength: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced2", "SetOutputSetting", 32usize, |this| ((**(this as *const *const IWMReaderAdvanced2_Vtbl)).SetOutputSetting)(this, dwoutputnum, pszname, r#type, pvalue, cblength), |result| Some(*result))
+        }
+        unsafe extern "system" fn Preroll(this: *mut core::ffi::c_void, cnsstart: u64, cnsduration: u64, frate: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced2", "Preroll", 33usize, |this| ((**(this as *const *const IWMReaderAdvanced2_Vtbl)).Preroll)(this, cnsstart, cnsduration, frate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLogClientID(this: *mut core::ffi::c_void, flogclientid: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced2", "SetLogClientID", 34usize, |this| ((**(this as *const *const IWMReaderAdvanced2_Vtbl)).SetLogClientID)(this, flogclientid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLogClientID(this: *mut core::ffi::c_void, pflogclientid: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced2", "GetLogClientID", 35usize, |this| ((**(this as *const *const IWMReaderAdvanced2_Vtbl)).GetLogClientID)(this, pflogclientid), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopBuffering(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced2", "StopBuffering", 36usize, |this| ((**(this as *const *const IWMReaderAdvanced2_Vtbl)).StopBuffering)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OpenStream(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced2", "OpenStream", 37usize, |this| ((**(this as *const *const IWMReaderAdvanced2_Vtbl)).OpenStream)(this, pstream, pcallback, pvcontext), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMReaderAdvanced_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetPlayMode,
+            GetPlayMode,
+            GetBufferProgress,
+            GetDownloadProgress,
+            GetSaveAsProgress,
+            SaveFileAs,
+            GetProtocolName,
+            StartAtMarker,
+            GetOutputSetting,
+            SetOutputSetting,
+            Preroll,
+            SetLogClientID,
+            GetLogClientID,
+            StopBuffering,
+            #[cfg(feature = "Win32_System_Com")]
+            OpenStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OpenStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMReaderAdvanced2 as windows_core::Interface>::IID || <IWMReaderAdvanced_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMReaderAdvanced3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StopNetStreaming(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced3", "StopNetStreaming", 38usize, |this| ((**(this as *const *const IWMReaderAdvanced3_Vtbl)).StopNetStreaming)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartAtPosition(this: *mut core::ffi::c_void, wstreamnum: u16, pvoffsetstart: *const core::ffi::c_void, pvduration: *const core::ffi::c_void, dwoffsetformat: WMT_OFFSET_FORMAT, frate: f32, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced3", "StartAtPosition", 39usize, |this| ((**(this as *const *const IWMReaderAdvanced3_Vtbl)).StartAtPosition)(this, wstreamnum, pvoffsetstart, pvduration, dwoffsetformat, frate, pvcontext), |result| Some(*result))
+        }
+        Self { base__: <IWMReaderAdvanced2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StopNetStreaming, StartAtPosition }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMReaderAdvanced3 as windows_core::Interface>::IID || <IWMReaderAdvanced2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMReaderAdvanced4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLanguageCount(this: *mut core::ffi::c_void, dwoutputnum: u32, pwlanguagecount: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced4", "GetLanguageCount", 40usize, |this| ((**(this as *const *const IWMReaderAdvanced4_Vtbl)).GetLanguageCount)(this, dwoutputnum, pwlanguagecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLanguage(this: *mut core::ffi::c_void, dwoutputnum: u32, wlanguage: u16, pwszlanguagestring: windows_core::PWSTR, pcchlanguagestringlength: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced4", "GetLanguage", 41usize, |this| ((**(this as *const *const IWMReaderAdvanced4_Vtbl)).GetLanguage)(this, dwoutputnum, wlanguage, pwszlanguagestring, pcchlanguagestringlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxSpeedFactor(this: *mut core::ffi::c_void, pdblfactor: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced4", "GetMaxSpeedFactor", 42usize, |this| ((**(this as *const *const IWMReaderAdvanced4_Vtbl)).GetMaxSpeedFactor)(this, pdblfactor), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUsingFastCache(this: *mut core::ffi::c_void, pfusingfastcache: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced4", "IsUsingFastCache", 43usize, |this| ((**(this as *const *const IWMReaderAdvanced4_Vtbl)).IsUsingFastCache)(this, pfusingfastcache), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddLogParam(this: *mut core::ffi::c_void, wsznamespace: windows_core::PCWSTR, wszname: windows_core::PCWSTR, wszvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced4", "AddLogParam", 44usize, |this| ((**(this as *const *const IWMReaderAdvanced4_Vtbl)).AddLogParam)(this, wsznamespace, wszname, wszvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendLogParams(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced4", "SendLogParams", 45usize, |this| ((**(this as *const *const IWMReaderAdvanced4_Vtbl)).SendLogParams)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanSaveFileAs(this: *mut core::ffi::c_void, pfcansave: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced4", "CanSaveFileAs", 46usize, |this| ((**(this as *const *const IWMReaderAdvanced4_Vtbl)).CanSaveFileAs)(this, pfcansave), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelSaveFileAs(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced4", "CancelSaveFileAs", 47usize, |this| ((**(this as *const *const IWMReaderAdvanced4_Vtbl)).CancelSaveFileAs)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetURL(this: *mut core::ffi::c_void, pwszurl: windows_core::PWSTR, pcchurl: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced4", "GetURL", 48usize, |this| ((**(this as *const *const IWMReaderAdvanced4_Vtbl)).GetURL)(this, pwszurl, pcchurl), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMReaderAdvanced3_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetLanguageCount,
+            GetLanguage,
+            GetMaxSpeedFactor,
+            IsUsingFastCache,
+            AddLogParam,
+            SendLogParams,
+            CanSaveFileAs,
+            CancelSaveFileAs,
+            GetURL,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMReaderAdvanced4 as windows_core::Interface>::IID || <IWMReaderAdvanced3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMReaderAdvanced5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPlayerHook(this: *mut core::ffi::c_void, dwoutputnum: u32, phook: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced5", "SetPlayerHook", 49usize, |this| ((**(this as *const *const IWMReaderAdvanced5_Vtbl)).SetPlayerHook)(this, dwoutputnum, phook), |result| Some(*result))
+        }
+        Self { base__: <IWMReaderAdvanced4_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetPlayerHook }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMReaderAdvanced5 as windows_core::Interface>::IID || <IWMReaderAdvanced4_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMReaderAdvanced6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetProtectStreamSamples(this: *mut core::ffi::c_void, pbcertificate: *const u8, cbcertificate: u32, dwcertificatetype: u32, dwflags: u32, pbinitializationvector: *mut u8, pcbinitializationvector: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAdvanced6", "SetProtectStreamSamples", 50usize, |this| ((**(this as *const *const IWMReaderAdvanced6_Vtbl)).SetProtectStreamSamples)(this, pbcertificate, cbcertificate, dwcertificatetype, dwflags, pbinitializationvector, pcbinitializationvector), |result| Some(*result))
+        }
+        Self { base__: <IWMReaderAdvanced5_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetProtectStreamSamples }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMReaderAdvanced6 as windows_core::Interface>::IID || <IWMReaderAdvanced5_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMReaderAllocatorEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AllocateForStreamEx(this: *mut core::ffi::c_void, wstreamnum: u16, cbbuffer: u32, ppbuffer: *mut *mut core::ffi::c_void, dwflags: u32, cnssampletime: u64, cnssampleduration: u64, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAllocatorEx", "AllocateForStreamEx", 3usize, |this| ((**(this as *const *const IWMReaderAllocatorEx_Vtbl)).AllocateForStreamEx)(this, wstreamnum, cbbuffer, ppbuffer, dwflags, cnssampletime, cnssampleduration, pvcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllocateForOutputEx(this: *mut core::ffi::c_void, dwoutputnum: u32, cbbuffer: u32, ppbuffer: *mut *mut core::ffi::c_void, dwflags: u32, cnssampletime: u64, cnssampleduration: u64, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderAllocatorEx", "AllocateForOutputEx", 4usize, |this| ((**(this as *const *const IWMReaderAllocatorEx_Vtbl)).AllocateForOutputEx)(this, dwoutputnum, cbbuffer, ppbuffer, dwflags, cnssampletime, cnssampleduration, pvcontext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AllocateForStreamEx, AllocateForOutputEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMReaderAllocatorEx as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMReaderCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnSample(this: *mut core::ffi::c_void, dwoutputnum: u32, cnssampletime: u64, cnssampleduration: u64, dwflags: u32, psample: *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderCallback", "OnSample", 4usize, |this| ((**(this as *const *const IWMReaderCallback_Vtbl)).OnSample)(this, dwoutputnum, cnssampletime, cnssampleduration, dwflags, psample, pvcontext), |result| Some(*result))
+        }
+        Self { base__: <IWMStatusCallback_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnSample }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMReaderCallback as windows_core::Interface>::IID || <IWMStatusCallback_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMReaderCallbackAdvanced_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnStreamSample(this: *mut core::ffi::c_void, wstreamnum: u16, cnssampletime: u64, cnssampleduration: u64, dwflags: u32, psample: *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderCallbackAdvanced", "OnStreamSample", 3usize, |this| ((**(this as *const *const IWMReaderCallbackAdvanced_Vtbl)).OnStreamSample)(this, wstreamnum, cnssampletime, cnssampleduration, dwflags, psample, pvcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnTime(this: *mut core::ffi::c_void, cnscurrenttime: u64, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderCallbackAdvanced", "OnTime", 4usize, |this| ((**(this as *const *const IWMReaderCallbackAdvanced_Vtbl)).OnTime)(this, cnscurrenttime, pvcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnStreamSelection(this: *mut core::ffi::c_void, wstreamcount: u16, pstreamnumbers: *const u16, pselections: *const WMT_STREAM_SELECTION, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderCallbackAdvanced", "OnStreamSelection", 5usize, |this| ((**(this as *const *const IWMReaderCallbackAdvanced_Vtbl)).OnStreamSelection)(this, wstreamcount, pstreamnumbers, pselections, pvcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnOutputPropsChanged(this: *mut core::ffi::c_void, dwoutputnum: u32, pmediatype: *const WM_MEDIA_TYPE, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderCallbackAdvanced", "OnOutputPropsChanged", 6usize, |this| ((**(this as *const *const IWMReaderCallbackAdvanced_Vtbl)).OnOutputPropsChanged)(this, dwoutputnum, pmediatype, pvcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllocateForStream(this: *mut core::ffi::c_void, wstreamnum: u16, cbbuffer: u32, ppbuffer: *mut *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderCallbackAdvanced", "AllocateForStream", 7usize, |this| ((**(this as *const *const IWMReaderCallbackAdvanced_Vtbl)).AllocateForStream)(this, wstreamnum, cbbuffer, ppbuffer, pvcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllocateForOutput(this: *mut core::ffi::c_void, dwoutputnum: u32, cbbuffer: u32, ppbuffer: *mut *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderCallbackAdvanced", "AllocateForOutput", 8usize, |this| ((**(this as *const *const IWMReaderCallbackAdvanced_Vtbl)).AllocateForOutput)(this, dwoutputnum, cbbuffer, ppbuffer, pvcontext), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnStreamSample,
+            OnTime,
+            OnStreamSelection,
+            OnOutputPropsChanged,
+            AllocateForStream,
+            AllocateForOutput,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMReaderCallbackAdvanced as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMReaderNetworkConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBufferingTime(this: *mut core::ffi::c_void, pcnsbufferingtime: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "GetBufferingTime", 3usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).GetBufferingTime)(this, pcnsbufferingtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBufferingTime(this: *mut core::ffi::c_void, cnsbufferingtime: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "SetBufferingTime", 4usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).SetBufferingTime)(this, cnsbufferingtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUDPPortRanges(this: *mut core::ffi::c_void, prangearray: *mut WM_PORT_NUMBER_RANGE, pcranges: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "GetUDPPortRanges", 5usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).GetUDPPortRanges)(this, prangearray, pcranges), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUDPPortRanges(this: *mut core::ffi::c_void, prangearray: *const WM_PORT_NUMBER_RANGE, cranges: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "SetUDPPortRanges", 6usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).SetUDPPortRanges)(this, prangearray, cranges), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProxySettings(this: *mut core::ffi::c_void, pwszprotocol: windows_core::PCWSTR, pproxysetting: *mut WMT_PROXY_SETTINGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "GetProxySettings", 7usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).GetProxySettings)(this, pwszprotocol, pproxysetting), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProxySettings(this: *mut core::ffi::c_void, pwszprotocol: windows_core::PCWSTR, proxysetting: WMT_PROXY_SETTINGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "SetProxySettings", 8usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).SetProxySettings)(this, pwszprotocol, proxysetting), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProxyHostName(this: *mut core::ffi::c_void, pwszprotocol: windows_core::PCWSTR, pwszhostname: windows_core::PWSTR, pcchhostname: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "GetProxyHostName", 9usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).GetProxyHostName)(this, pwszprotocol, pwszhostname, pcchhostname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProxyHostName(this: *mut core::ffi::c_void, pwszprotocol: windows_core::PCWSTR, pwszhostname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "SetProxyHostName", 10usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).SetProxyHostName)(this, pwszprotocol, pwszhostname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProxyPort(this: *mut core::ffi::c_void, pwszprotocol: windows_core::PCWSTR, pdwport: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "GetProxyPort", 11usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).GetProxyPort)(this, pwszprotocol, pdwport), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProxyPort(this: *mut core::ffi::c_void, pwszprotocol: windows_core::PCWSTR, dwport: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "SetProxyPort", 12usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).SetProxyPort)(this, pwszprotocol, dwport), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProxyExceptionList(this: *mut core::ffi::c_void, pwszprotocol: windows_core::PCWSTR, pwszexceptionlist: windows_core::PWSTR, pcchexceptionlist: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "GetProxyExceptionList", 13usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).GetProxyExceptionList)(this, pwszprotocol, pwszexceptionlist, pcchexceptionlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProxyExceptionList(this: *mut core::ffi::c_void, pwszprotocol: windows_core::PCWSTR, pwszexceptionlist: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "SetProxyExceptionList", 14usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).SetProxyExceptionList)(this, pwszprotocol, pwszexceptionlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProxyBypassForLocal(this: *mut core::ffi::c_void, pwszprotocol: windows_core::PCWSTR, pfbypassforlocal: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "GetProxyBypassForLocal", 15usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).GetProxyBypassForLocal)(this, pwszprotocol, pfbypassforlocal), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProxyBypassForLocal(this: *mut core::ffi::c_void, pwszprotocol: windows_core::PCWSTR, fbypassforlocal: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "SetProxyBypassForLocal", 16usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).SetProxyBypassForLocal)(this, pwszprotocol, fbypassforlocal), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForceRerunAutoProxyDetection(this: *mut core::ffi::c_void, pfforcererundetection: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "GetForceRerunAutoProxyDetection", 17usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).GetForceRerunAutoProxyDetection)(this, pfforcererundetection), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetForceRerunAutoProxyDetection(this: *mut core::ffi::c_void, fforcererundetection: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "SetForceRerunAutoProxyDetection", 18usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).SetForceRerunAutoProxyDetection)(this, fforcererundetection), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnableMulticast(this: *mut core::ffi::c_void, pfenablemulticast: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "GetEnableMulticast", 19usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).GetEnableMulticast)(this, pfenablemulticast), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnableMulticast(this: *mut core::ffi::c_void, fenablemulticast: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "SetEnableMulticast", 20usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).SetEnableMulticast)(this, fenablemulticast), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnableHTTP(this: *mut core::ffi::c_void, pfenablehttp: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "GetEnableHTTP", 21usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).GetEnableHTTP)(this, pfenablehttp), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnableHTTP(this: *mut core::ffi::c_void, fenablehttp: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "SetEnableHTTP", 22usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).SetEnableHTTP)(this, fenablehttp), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnableUDP(this: *mut core::ffi::c_void, pfenableudp: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "GetEnableUDP", 23usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).GetEnableUDP)(this, pfenableudp), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnableUDP(this: *mut core::ffi::c_void, fenableudp: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "SetEnableUDP", 24usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).SetEnableUDP)(this, fenableudp), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnableTCP(this: *mut core::ffi::c_void, pfenabletcp: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "GetEnableTCP", 25usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).GetEnableTCP)(this, pfenabletcp), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnableTCP(this: *mut core::ffi::c_void, fenabletcp: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "SetEnableTCP", 26usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).SetEnableTCP)(this, fenabletcp), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetProtocolRollover(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "ResetProtocolRollover", 27usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).ResetProtocolRollover)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConnectionBandwidth(this: *mut core::ffi::c_void, pdwconnectionbandwidth: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "GetConnectionBandwidth", 28usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).GetConnectionBandwidth)(this, pdwconnectionbandwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConnectionBandwidth(this: *mut core::ffi::c_void, dwconnectionbandwidth: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "SetConnectionBandwidth", 29usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).SetConnectionBandwidth)(this, dwconnectionbandwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumProtocolsSupported(this: *mut core::ffi::c_void, pcprotocols: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "GetNumProtocolsSupported", 30usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).GetNumProtocolsSupported)(this, pcprotocols), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSupportedProtocolName(this: *mut core::ffi::c_void, dwprotocolnum: u32, pwszprotocolname: windows_core::PWSTR, pcchprotocolname: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "GetSupportedProtocolName", 31usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).GetSupportedProtocolName)(this, dwprotocolnum, pwszprotocolname, pcchprotocolname), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddLoggingUrl(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "AddLoggingUrl", 32usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).AddLoggingUrl)(this, pwszurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLoggingUrl(this: *mut core::ffi::c_void, dwindex: u32, pwszurl: windows_core::PWSTR, pcchurl: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "GetLoggingUrl", 33usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).GetLoggingUrl)(this, dwindex, pwszurl, pcchurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLoggingUrlCount(this: *mut core::ffi::c_void, pdwurlcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "GetLoggingUrlCount", 34usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).GetLoggingUrlCount)(this, pdwurlcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetLoggingUrlList(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig", "ResetLoggingUrlList", 35usize, |this| ((**(this as *const *const IWMReaderNetworkConfig_Vtbl)).ResetLoggingUrlList)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetBufferingTime,
+            SetBufferingTime,
+            GetUDPPortRanges,
+            SetUDPPortRanges,
+            GetProxySettings,
+            SetProxySettings,
+            GetProxyHostName,
+            SetProxyHostName,
+            GetProxyPort,
+            SetProxyPort,
+            GetProxyExceptionList,
+            SetProxyExceptionList,
+            GetProxyBypassForLocal,
+            SetProxyBypassForLocal,
+            GetForceRerunAutoProxyDetection,
+            SetForceRerunAutoProxyDetection,
+            GetEnableMulticast,
+            SetEnableMulticast,
+            GetEnableHTTP,
+            SetEnableHTTP,
+            GetEnableUDP,
+            SetEnableUDP,
+            GetEnableTCP,
+            SetEnableTCP,
+            ResetProtocolRollover,
+            GetConnectionBandwidth,
+            SetConnectionBandwidth,
+            GetNumProtocolsSupported,
+            GetSupportedProtocolName,
+            AddLoggingUrl,
+            GetLoggingUrl,
+            GetLoggingUrlCount,
+            ResetLoggingUrlList,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMReaderNetworkConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMReaderNetworkConfig2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEnableContentCaching(this: *mut core::ffi::c_void, pfenablecontentcaching: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig2", "GetEnableContentCaching", 36usize, |this| ((**(this as *const *const IWMReaderNetworkConfig2_Vtbl)).GetEnableContentCaching)(this, pfenablecontentcaching), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnableContentCaching(this: *mut core::ffi::c_void, fenablecontentcaching: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig2", "SetEnableContentCaching", 37usize, |this| ((**(this as *const *const IWMReaderNetworkConfig2_Vtbl)).SetEnableContentCaching)(this, fenablecontentcaching), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnableFastCache(this: *mut core::ffi::c_void, pfenablefastcache: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig2", "GetEnableFastCache", 38usize, |this| ((**(this as *const *const IWMReaderNetworkConfig2_Vtbl)).GetEnableFastCache)(this, pfenablefastcache), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnableFastCache(this: *mut core::ffi::c_void, fenablefastcache: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig2", "SetEnableFastCache", 39usize, |this| ((**(this as *const *const IWMReaderNetworkConfig2_Vtbl)).SetEnableFastCache)(this, fenablefastcache), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAcceleratedStreamingDuration(this: *mut core::ffi::c_void, pcnsaccelduration: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig2", "GetAcceleratedStreamingDuration", 40usize, |this| ((**(this as *const *const IWMReaderNetworkConfig2_Vtbl)).GetAcceleratedStreamingDuration)(this, pcnsaccelduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAcceleratedStreamingDuration(this: *mut core::ffi::c_void, cnsaccelduration: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig2", "SetAcceleratedStreamingDuration", 41usize, |this| ((**(this as *const *const IWMReaderNetworkConfig2_Vtbl)).SetAcceleratedStreamingDuration)(this, cnsaccelduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAutoReconnectLimit(this: *mut core::ffi::c_void, pdwautoreconnectlimit: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig2", "GetAutoReconnectLimit", 42usize, |this| ((**(this as *const *const IWMReaderNetworkConfig2_Vtbl)).GetAutoReconnectLimit)(this, pdwautoreconnectlimit), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoReconnectLimit(this: *mut core::ffi::c_void, dwautoreconnectlimit: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig2", "SetAutoReconnectLimit", 43usize, |this| ((**(this as *const *const IWMReaderNetworkConfig2_Vtbl)).SetAutoReconnectLimit)(this, dwautoreconnectlimit), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnableResends(this: *mut core::ffi::c_void, pfenableresends: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig2", "GetEnableResends", 44usize, |this| ((**(this as *const *const IWMReaderNetworkConfig2_Vtbl)).GetEnableResends)(this, pfenableresends), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnableResends(this: *mut core::ffi::c_void, fenableresends: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig2", "SetEnableResends", 45usize, |this| ((**(this as *const *const IWMReaderNetworkConfig2_Vtbl)).SetEnableResends)(this, fenableresends), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnableThinning(this: *mut core::ffi::c_void, pfenablethinning: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig2", "GetEnableThinning", 46usize, |this| ((**(this as *const *const IWMReaderNetworkConfig2_Vtbl)).GetEnableThinning)(this, pfenablethinning), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnableThinning(this: *mut core::ffi::c_void, fenablethinning: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig2", "SetEnableThinning", 47usize, |this| ((**(this as *const *const IWMReaderNetworkConfig2_Vtbl)).SetEnableThinning)(this, fenablethinning), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxNetPacketSize(this: *mut core::ffi::c_void, pdwmaxnetpacketsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderNetworkConfig2", "GetMaxNetPacketSize", 48usize, |this| ((**(this as *const *const IWMReaderNetworkConfig2_Vtbl)).GetMaxNetPacketSize)(this, pdwmaxnetpacketsize), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMReaderNetworkConfig_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetEnableContentCaching,
+            SetEnableContentCaching,
+            GetEnableFastCache,
+            SetEnableFastCache,
+            GetAcceleratedStreamingDuration,
+            SetAcceleratedStreamingDuration,
+            GetAutoReconnectLimit,
+            SetAutoReconnectLimit,
+            GetEnableResends,
+            SetEnableResends,
+            GetEnableThinning,
+            SetEnableThinning,
+            GetMaxNetPacketSize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMReaderNetworkConfig2 as windows_core::Interface>::IID || <IWMReaderNetworkConfig_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMReaderPlaylistBurn_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitPlaylistBurn(this: *mut core::ffi::c_void, cfiles: u32, ppwszfilenames: *const windows_core::PCWSTR, pcallback: *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderPlaylistBurn", "InitPlaylistBurn", 3usize, |this| ((**(this as *const *const IWMReaderPlaylistBurn_Vtbl)).InitPlaylistBurn)(this, cfiles, ppwszfilenames, pcallback, pvcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInitResults(this: *mut core::ffi::c_void, cfiles: u32, phrstati: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderPlaylistBurn", "GetInitResults", 4usize, |this| ((**(this as *const *const IWMReaderPlaylistBurn_Vtbl)).GetInitResults)(this, cfiles, phrstati), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderPlaylistBurn", "Cancel", 5usize, |this| ((**(this as *const *const IWMReaderPlaylistBurn_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndPlaylistBurn(this: *mut core::ffi::c_void, hrburnresult: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderPlaylistBurn", "EndPlaylistBurn", 6usize, |this| ((**(this as *const *const IWMReaderPlaylistBurn_Vtbl)).EndPlaylistBurn)(this, hrburnresult), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InitPlaylistBurn, GetInitResults, Cancel, EndPlaylistBurn }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMReaderPlaylistBurn as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMReaderStreamClock_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTime(this: *mut core::ffi::c_void, pcnsnow: *const u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderStreamClock", "GetTime", 3usize, |this| ((**(this as *const *const IWMReaderStreamClock_Vtbl)).GetTime)(this, pcnsnow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTimer(this: *mut core::ffi::c_void, cnswhen: u64, pvparam: *const core::ffi::c_void, pdwtimerid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderStreamClock", "SetTimer", 4usize, |this| ((**(this as *const *const IWMReaderStreamClock_Vtbl)).SetTimer)(this, cnswhen, pvparam, pdwtimerid), |result| Some(*result))
+        }
+        unsafe extern "system" fn KillTimer(this: *mut core::ffi::c_void, dwtimerid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderStreamClock", "KillTimer", 5usize, |this| ((**(this as *const *const IWMReaderStreamClock_Vtbl)).KillTimer)(this, dwtimerid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetTime, SetTimer, KillTimer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMReaderStreamClock as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMReaderTimecode_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTimecodeRangeCount(this: *mut core::ffi::c_void, wstreamnum: u16, pwrangecount: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderTimecode", "GetTimecodeRangeCount", 3usize, |this| ((**(this as *const *const IWMReaderTimecode_Vtbl)).GetTimecodeRangeCount)(this, wstreamnum, pwrangecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTimecodeRangeBounds(this: *mut core::ffi::c_void, wstreamnum: u16, wrangenum: u16, pstarttimecode: *mut u32, pendtimecode: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderTimecode", "GetTimecodeRangeBounds", 4usize, |this| ((**(this as *const *const IWMReaderTimecode_Vtbl)).GetTimecodeRangeBounds)(this, wstreamnum, wrangenum, pstarttimecode, pendtimecode), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetTimecodeRangeCount, GetTimecodeRangeBounds }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMReaderTimecode as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMReaderTypeNegotiation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TryOutputProps(this: *mut core::ffi::c_void, dwoutputnum: u32, poutput: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMReaderTypeNegotiation", "TryOutputProps", 3usize, |this| ((**(this as *const *const IWMReaderTypeNegotiation_Vtbl)).TryOutputProps)(this, dwoutputnum, poutput), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TryOutputProps }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMReaderTypeNegotiation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMRegisterCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Advise(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMRegisterCallback", "Advise", 3usize, |this| ((**(this as *const *const IWMRegisterCallback_Vtbl)).Advise)(this, pcallback, pvcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unadvise(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMRegisterCallback", "Unadvise", 4usize, |this| ((**(this as *const *const IWMRegisterCallback_Vtbl)).Unadvise)(this, pcallback, pvcontext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Advise, Unadvise }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMRegisterCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMRegisteredDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSerialNumber(this: *mut core::ffi::c_void, pserialnumber: *mut DRM_VAL16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMRegisteredDevice", "GetDeviceSerialNumber", 3usize, |this| ((**(this as *const *const IWMRegisteredDevice_Vtbl)).GetDeviceSerialNumber)(this, pserialnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceCertificate(this: *mut core::ffi::c_void, ppcertificate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMRegisteredDevice", "GetDeviceCertificate", 4usize, |this| ((**(this as *const *const IWMRegisteredDevice_Vtbl)).GetDeviceCertificate)(this, ppcertificate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceType(this: *mut core::ffi::c_void, pdwtype: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMRegisteredDevice", "GetDeviceType", 5usize, |this| ((**(this as *const *const IWMRegisteredDevice_Vtbl)).GetDeviceType)(this, pdwtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributeCount(this: *mut core::ffi::c_void, pcattributes: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMRegisteredDevice", "GetAttributeCount", 6usize, |this| ((**(this as *const *const IWMRegisteredDevice_Vtbl)).GetAttributeCount)(this, pcattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributeByIndex(this: *mut core::ffi::c_void, dwindex: u32, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMRegisteredDevice", "GetAttributeByIndex", 7usize, |this| ((**(this as *const *const IWMRegisteredDevice_Vtbl)).GetAttributeByIndex)(this, dwindex, pbstrname, pbstrvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributeByName(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, pbstrvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMRegisteredDevice", "GetAttributeByName", 8usize, |this| ((**(this as *const *const IWMRegisteredDevice_Vtbl)).GetAttributeByName)(this, bstrname, pbstrvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAttributeByName(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, bstrvalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMRegisteredDevice", "SetAttributeByName", 9usize, |this| ((**(this as *const *const IWMRegisteredDevice_Vtbl)).SetAttributeByName)(this, bstrname, bstrvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Approve(this: *mut core::ffi::c_void, fapprove: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMRegisteredDevice", "Approve", 10usize, |this| ((**(this as *const *const IWMRegisteredDevice_Vtbl)).Approve)(this, fapprove), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsValid(this: *mut core::ffi::c_void, pfvalid: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMRegisteredDevice", "IsValid", 11usize, |this| ((**(this as *const *const IWMRegisteredDevice_Vtbl)).IsValid)(this, pfvalid), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsApproved(this: *mut core::ffi::c_void, pfapproved: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMRegisteredDevice", "IsApproved", 12usize, |this| ((**(this as *const *const IWMRegisteredDevice_Vtbl)).IsApproved)(this, pfapproved), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsWmdrmCompliant(this: *mut core::ffi::c_void, pfcompliant: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMRegisteredDevice", "IsWmdrmCompliant", 13usize, |this| ((**(this as *const *const IWMRegisteredDevice_Vtbl)).IsWmdrmCompliant)(this, pfcompliant), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOpened(this: *mut core::ffi::c_void, pfopened: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMRegisteredDevice", "IsOpened", 14usize, |this| ((**(this as *const *const IWMRegisteredDevice_Vtbl)).IsOpened)(this, pfopened), |result| Some(*result))
+        }
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMRegisteredDevice", "Open", 15usize, |this| ((**(this as *const *const IWMRegisteredDevice_Vtbl)).Open)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMRegisteredDevice", "Close", 16usize, |this| ((**(this as *const *const IWMRegisteredDevice_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDeviceSerialNumber,
+            GetDeviceCertificate,
+            GetDeviceType,
+            GetAttributeCount,
+            GetAttributeByIndex,
+            GetAttributeByName,
+            SetAttributeByName,
+            Approve,
+            IsValid,
+            IsApproved,
+            IsWmdrmCompliant,
+            IsOpened,
+            Open,
+            Close,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMRegisteredDevice as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMSBufferAllocator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AllocateBuffer(this: *mut core::ffi::c_void, dwmaxbuffersize: u32, ppbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSBufferAllocator", "AllocateBuffer", 3usize, |this| ((**(this as *const *const IWMSBufferAllocator_Vtbl)).AllocateBuffer)(this, dwmaxbuffersize, ppbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllocatePageSizeBuffer(this: *mut core::ffi::c_void, dwmaxbuffersize: u32, ppbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSBufferAllocator", "AllocatePageSizeBuffer", 4usize, |this| ((**(this as *const *const IWMSBufferAllocator_Vtbl)).AllocatePageSizeBuffer)(this, dwmaxbuffersize, ppbuffer), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AllocateBuffer, AllocatePageSizeBuffer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMSBufferAllocator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMSInternalAdminNetSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, psharednamespace: *mut core::ffi::c_void, pnamespacenode: *mut core::ffi::c_void, pnetsourcecreator: *mut core::ffi::c_void, fembeddedinserver: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSInternalAdminNetSource", "Initialize", 3usize, |this| ((**(this as *const *const IWMSInternalAdminNetSource_Vtbl)).Initialize)(this, psharednamespace, pnamespacenode, pnetsourcecreator, fembeddedinserver), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNetSourceCreator(this: *mut core::ffi::c_void, ppnetsourcecreator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSInternalAdminNetSource", "GetNetSourceCreator", 4usize, |this| ((**(this as *const *const IWMSInternalAdminNetSource_Vtbl)).GetNetSourceCreator)(this, ppnetsourcecreator), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCredentials(this: *mut core::ffi::c_void, bstrrealm: core::mem::MaybeUninit<windows_core::BSTR>, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, bstrpassword: core::mem::MaybeUninit<windows_core::BSTR>, fpersist: super::super::Foundation::BOOL, fconfirmedgood: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSInternalAdminNetSource", "SetCredentials", 5usize, |this| ((**(this as *const *const IWMSInternalAdminNetSource_Vtbl)).SetCredentials)(this, bstrrealm, bstrname, bstrpassword, fpersist, fconfirmedgood), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCredentials(this: *mut core::ffi::c_void, bstrrealm: core::mem::MaybeUninit<windows_core::BSTR>, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrpassword: *mut core::mem::MaybeUninit<windows_core::BSTR>, pfconfirmedgood: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSInternalAdminNetSource", "GetCredentials", 6usize, |this| ((**(this as *const *const IWMSInternalAdminNetSource_Vtbl)).GetCredentials)(this, bstrrealm, pbstrname, pbstrpassword, pfconfirmedgood), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteCredentials(this: *mut core::ffi::c_void, bstrrealm: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSInternalAdminNetSource", "DeleteCredentials", 7usize, |this| ((**(this as *const *const IWMSInternalAdminNetSource_Vtbl)).DeleteCredentials)(this, bstrrealm), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCredentialFlags(this: *mut core::ffi::c_void, lpdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSInternalAdminNetSource", "GetCredentialFlags", 8usize, |this| ((**(this as *const *const IWMSInternalAdminNetSource_Vtbl)).GetCredentialFlags)(this, lpdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCredentialFlags(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSInternalAdminNetSource", "SetCredentialFlags", 9usize, |this| ((**(this as *const *const IWMSInternalAdminNetSource_Vtbl)).SetCredentialFlags)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindProxyForURL(this: *mut core::ffi::c_void, bstrprotocol: core::mem::MaybeUninit<windows_core::BSTR>, bstrhost: core::mem::MaybeUninit<windows_core::BSTR>, pfproxyenabled: *mut super::super::Foundation::BOOL, pbstrproxyserver: *mut core::mem::MaybeUninit<windows_core::BSTR>, pdwproxyport: *mut u32, pdwproxycontext: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSInternalAdminNetSource", "FindProxyForURL", 10usize, |this| ((**(this as *const *const IWMSInternalAdminNetSource_Vtbl)).FindProxyForURL)(this, bstrprotocol, bstrhost, pfproxyenabled, pbstrproxyserver, pdwproxyport, pdwproxycontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterProxyFailure(this: *mut core::ffi::c_void, hrparam: windows_core::HRESULT, dwproxycontext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSInternalAdminNetSource", "RegisterProxyFailure", 11usize, |this| ((**(this as *const *const IWMSInternalAdminNetSource_Vtbl)).RegisterProxyFailure)(this, hrparam, dwproxycontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShutdownProxyContext(this: *mut core::ffi::c_void, dwproxycontext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSInternalAdminNetSource", "ShutdownProxyContext", 12usize, |this| ((**(this as *const *const IWMSInternalAdminNetSource_Vtbl)).ShutdownProxyContext)(this, dwproxycontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUsingIE(this: *mut core::ffi::c_void, dwproxycontext: u32, pfisusingie: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSInternalAdminNetSource", "IsUsingIE", 13usize, |this| ((**(this as *const *const IWMSInternalAdminNetSource_Vtbl)).IsUsingIE)(this, dwproxycontext, pfisusingie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetNetSourceCreator,
+            SetCredentials,
+            GetCredentials,
+            DeleteCredentials,
+            GetCredentialFlags,
+            SetCredentialFlags,
+            FindProxyForURL,
+            RegisterProxyFailure,
+            ShutdownProxyContext,
+            IsUsingIE,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMSInternalAdminNetSource as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMSInternalAdminNetSource2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetCredentialsEx(this: *mut core::ffi::c_void, bstrrealm: core::mem::MaybeUninit<windows_core::BSTR>, bstrurl: core::mem::MaybeUninit<windows_core::BSTR>, fproxy: super::super::Foundation::BOOL, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, bstrpassword: core::mem::MaybeUninit<windows_core::BSTR>, fpersist: super::super::Foundation::BOOL, fconfirmedgood: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSInternalAdminNetSource2", "SetCredentialsEx", 3usize, |this| ((**(this as *const *const IWMSInternalAdminNetSource2_Vtbl)).SetCredentialsEx)(this, bstrrealm, bstrurl, fproxy, bstrname, bstrpassword, fpersist, fconfirmedgood), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCredentialsEx(this: *mut core::ffi::c_void, bstrrealm: core::mem::MaybeUninit<windows_core::BSTR>, bstrurl: core::mem::MaybeUninit<windows_core::BSTR>, fproxy: super::super::Foundation::BOOL, pdwurlpolicy: *mut NETSOURCE_URLCREDPOLICY_SETTINGS, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrpassword: *mut core::mem::MaybeUninit<windows_core::BSTR>, pfconfirmedgood: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSInternalAdminNetSource2", "GetCredentialsEx", 4usize, |this| ((**(this as *const *const IWMSInternalAdminNetSource2_Vtbl)).GetCredentialsEx)(this, bstrrealm, bstrurl, fproxy, pdwurlpolicy, pbstrname, pbstrpassword, pfconfirmedgood), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteCredentialsEx(this: *mut core::ffi::c_void, bstrrealm: core::mem::MaybeUninit<windows_core::BSTR>, bstrurl: core::mem::MaybeUninit<windows_core::BSTR>, fproxy: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSInternalAdminNetSource2", "DeleteCredentialsEx", 5usize, |this| ((**(this as *const *const IWMSInternalAdminNetSource2_Vtbl)).DeleteCredentialsEx)(this, bstrrealm, bstrurl, fproxy), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindProxyForURLEx(this: *mut core::ffi::c_void, bstrprotocol: core::mem::MaybeUninit<windows_core::BSTR>, bstrhost: core::mem::MaybeUninit<windows_core::BSTR>, bstrurl: core::mem::MaybeUninit<windows_core::BSTR>, pfproxyenabled: *mut super::super::Foundation::BOOL, pbstrproxyserver: *mut core::mem::MaybeUninit<windows_core::BSTR>, pdwproxyport: *mut u32, pdwproxycontext: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSInternalAdminNetSource2", "FindProxyForURLEx", 6usize, |this| ((**(this as *const *const IWMSInternalAdminNetSource2_Vtbl)).FindProxyForURLEx)(this, bstrprotocol, bstrhost, bstrurl, pfproxyenabled, pbstrproxyserver, pdwproxyport, pdwproxycontext), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetCredentialsEx,
+            GetCredentialsEx,
+            DeleteCredentialsEx,
+            FindProxyForURLEx,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMSInternalAdminNetSource2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMSInternalAdminNetSource3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNetSourceCreator2(this: *mut core::ffi::c_void, ppnetsourcecreator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSInternalAdminNetSource3", "GetNetSourceCreator2", 7usize, |this| ((**(this as *const *const IWMSInternalAdminNetSource3_Vtbl)).GetNetSourceCreator2)(this, ppnetsourcecreator), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindProxyForURLEx2(this: *mut core::ffi::c_void, bstrprotocol: core::mem::MaybeUninit<windows_core::BSTR>, bstrhost: core::mem::MaybeUninit<windows_core::BSTR>, bstrurl: core::mem::MaybeUninit<windows_core::BSTR>, pfproxyenabled: *mut super::super::Foundation::BOOL, pbstrproxyserver: *mut core::mem::MaybeUninit<windows_core::BSTR>, pdwproxyport: *mut u32, pqwproxycontext: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSInternalAdminNetSource3", "FindProxyForURLEx2", 8usize, |this| ((**(this as *const *const IWMSInternalAdminNetSource3_Vtbl)).FindProxyForURLEx2)(this, bstrprotocol, bstrhost, bstrurl, pfproxyenabled, pbstrproxyserver, pdwproxyport, pqwproxycontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterProxyFailure2(this: *mut core::ffi::c_void, hrparam: windows_core::HRESULT, qwproxycontext: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSInternalAdminNetSource3", "RegisterProxyFailure2", 9usize, |this| ((**(this as *const *const IWMSInternalAdminNetSource3_Vtbl)).RegisterProxyFailure2)(this, hrparam, qwproxycontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShutdownProxyContext2(this: *mut core::ffi::c_void, qwproxycontext: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSInternalAdminNetSource3", "ShutdownProxyContext2", 10usize, |this| ((**(this as *const *const IWMSInternalAdminNetSource3_Vtbl)).ShutdownProxyContext2)(this, qwproxycontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUsingIE2(this: *mut core::ffi::c_void, qwproxycontext: u64, pfisusingie: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSInternalAdminNetSource3", "IsUsingIE2", 11usize, |this| ((**(this as *const *const IWMSInternalAdminNetSource3_Vtbl)).IsUsingIE2)(this, qwproxycontext, pfisusingie), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCredentialsEx2(this: *mut core::ffi::c_void, bstrrealm: core::mem::MaybeUninit<windows_core::BSTR>, bstrurl: core::mem::MaybeUninit<windows_core::BSTR>, fproxy: super::super::Foundation::BOOL, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, bstrpassword: core::mem::MaybeUninit<windows_core::BSTR>, fpersist: super::super::Foundation::BOOL, fconfirmedgood: super::super::Foundation::BOOL, fcleartextauthentication: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSInternalAdminNetSource3", "SetCredentialsEx2", 12usize, |this| ((**(this as *const *const IWMSInternalAdminNetSource3_Vtbl)).SetCredentialsEx2)(this, bstrrealm, bstrurl, fproxy, bstrname, bstrpassword, fpersist, fconfirmedgood, fcleartextauthentication), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCredentialsEx2(this: *mut core::ffi::c_void, bstrrealm: core::mem::MaybeUninit<windows_core::BSTR>, bstrurl: core::mem::MaybeUninit<windows_core::BSTR>, fproxy: super::super::Foundation::BOOL, fcleartextauthentication: super::super::Foundation::BOOL, pdwurlpolicy: *mut NETSOURCE_URLCREDPOLICY_SETTINGS, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrpassword: *mut core::mem::MaybeUninit<windows_core::BSTR>, pfconfirmedgood: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSInternalAdminNetSource3", "GetCredentialsEx2", 13usize, |this| ((**(this as *const *const IWMSInternalAdminNetSource3_Vtbl)).GetCredentialsEx2)(this, bstrrealm, bstrurl, fproxy, fcleartextauthentication, pdwurlpolicy, pbstrname, pbstrpassword, pfconfirmedgood), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMSInternalAdminNetSource2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetNetSourceCreator2,
+            FindProxyForURLEx2,
+            RegisterProxyFailure2,
+            ShutdownProxyContext2,
+            IsUsingIE2,
+            SetCredentialsEx2,
+            GetCredentialsEx2,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMSInternalAdminNetSource3 as windows_core::Interface>::IID || <IWMSInternalAdminNetSource2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMSecureChannel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WMSC_AddCertificate(this: *mut core::ffi::c_void, pcert: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSecureChannel", "WMSC_AddCertificate", 6usize, |this| ((**(this as *const *const IWMSecureChannel_Vtbl)).WMSC_AddCertificate)(this, pcert), |result| Some(*result))
+        }
+        unsafe extern "system" fn WMSC_AddSignature(this: *mut core::ffi::c_void, pbcertsig: *const u8, cbcertsig: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSecureChannel", "WMSC_AddSignature", 7usize, |this| ((**(this as *const *const IWMSecureChannel_Vtbl)).WMSC_AddSignature)(this, pbcertsig, cbcertsig), |result| Some(*result))
+        }
+        unsafe extern "system" fn WMSC_Connect(this: *mut core::ffi::c_void, potherside: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSecureChannel", "WMSC_Connect", 8usize, |this| ((**(this as *const *const IWMSecureChannel_Vtbl)).WMSC_Connect)(this, potherside), |result| Some(*result))
+        }
+        unsafe extern "system" fn WMSC_IsConnected(this: *mut core::ffi::c_void, pfisconnected: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSecureChannel", "WMSC_IsConnected", 9usize, |this| ((**(this as *const *const IWMSecureChannel_Vtbl)).WMSC_IsConnected)(this, pfisconnected), |result| Some(*result))
+        }
+        unsafe extern "system" fn WMSC_Disconnect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSecureChannel", "WMSC_Disconnect", 10usize, |this| ((**(this as *const *const IWMSecureChannel_Vtbl)).WMSC_Disconnect)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn WMSC_GetValidCertificate(this: *mut core::ffi::c_void, ppbcertificate: *mut *mut u8, pdwsignature: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSecureChannel", "WMSC_GetValidCertificate", 11usize, |this| ((**(this as *const *const IWMSecureChannel_Vtbl)).WMSC_GetValidCertificate)(this, ppbcertificate, pdwsignature), |result| Some(*result))
+        }
+        unsafe extern "system" fn WMSC_Encrypt(this: *mut core::ffi::c_void, pbdata: *const u8, cbdata: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSecureChannel", "WMSC_Encrypt", 12usize, |this| ((**(this as *const *const IWMSecureChannel_Vtbl)).WMSC_Encrypt)(this, pbdata, cbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn WMSC_Decrypt(this: *mut core::ffi::c_void, pbdata: *const u8, cbdata: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSecureChannel", "WMSC_Decrypt", 13usize, |this| ((**(this as *const *const IWMSecureChannel_Vtbl)).WMSC_Decrypt)(this, pbdata, cbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn WMSC_Lock(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSecureChannel", "WMSC_Lock", 14usize, |this| ((**(this as *const *const IWMSecureChannel_Vtbl)).WMSC_Lock)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn WMSC_Unlock(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSecureChannel", "WMSC_Unlock", 15usize, |this| ((**(this as *const *const IWMSecureChannel_Vtbl)).WMSC_Unlock)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn WMSC_SetSharedData(this: *mut core::ffi::c_void, dwcertindex: u32, pbshareddata: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSecureChannel", "WMSC_SetSharedData", 16usize, |this| ((**(this as *const *const IWMSecureChannel_Vtbl)).WMSC_SetSharedData)(this, dwcertindex, pbshareddata), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMAuthorizer_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            WMSC_AddCertificate,
+            WMSC_AddSignature,
+            WMSC_Connect,
+            WMSC_IsConnected,
+            WMSC_Disconnect,
+            WMSC_GetValidCertificate,
+            WMSC_Encrypt,
+            WMSC_Decrypt,
+            WMSC_Lock,
+            WMSC_Unlock,
+            WMSC_SetSharedData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMSecureChannel as windows_core::Interface>::IID || <IWMAuthorizer_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMStatusCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnStatus(this: *mut core::ffi::c_void, status: WMT_STATUS, hr: windows_core::HRESULT, dwtype: WMT_ATTR_DATATYPE, pvalue: *const u8, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMStatusCallback", "OnStatus", 3usize, |this| ((**(this as *const *const IWMStatusCallback_Vtbl)).OnStatus)(this, status, hr, dwtype, pvalue, pvcontext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMStatusCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMStreamConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStreamType(this: *mut core::ffi::c_void, pguidstreamtype: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMStreamConfig", "GetStreamType", 3usize, |this| ((**(this as *const *const IWMStreamConfig_Vtbl)).GetStreamType)(this, pguidstreamtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamNumber(this: *mut core::ffi::c_void, pwstreamnum: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMStreamConfig", "GetStreamNumber", 4usize, |this| ((**(this as *const *const IWMStreamConfig_Vtbl)).GetStreamNumber)(this, pwstreamnum), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStreamNumber(this: *mut core::ffi::c_void, wstreamnum: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMStreamConfig", "SetStreamNumber", 5usize, |this| ((**(this as *const *const IWMStreamConfig_Vtbl)).SetStreamNumber)(this, wstreamnum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamName(this: *mut core::ffi::c_void, pwszstreamname: windows_core::PWSTR, pcchstreamname: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMStreamConfig", "GetStreamName", 6usize, |this| ((**(this as *const *const IWMStreamConfig_Vtbl)).GetStreamName)(this, pwszstreamname, pcchstreamname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStreamName(this: *mut core::ffi::c_void, pwszstreamname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMStreamConfig", "SetStreamName", 7usize, |this| ((**(this as *const *const IWMStreamConfig_Vtbl)).SetStreamName)(this, pwszstreamname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConnectionName(this: *mut core::ffi::c_void, pwszinputname: windows_core::PWSTR, pcchinputname: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMStreamConfig", "GetConnectionName", 8usize, |this| ((**(this as *const *const IWMStreamConfig_Vtbl)).GetConnectionName)(this, pwszinputname, pcchinputname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConnectionName(this: *mut core::ffi::c_void, pwszinputname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMStreamConfig", "SetConnectionName", 9usize, |this| ((**(this as *const *const IWMStreamConfig_Vtbl)).SetConnectionName)(this, pwszinputname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBitrate(this: *mut core::ffi::c_void, pdwbitrate: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMStreamConfig", "GetBitrate", 10usize, |this| ((**(this as *const *const IWMStreamConfig_Vtbl)).GetBitrate)(this, pdwbitrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBitrate(this: *mut core::ffi::c_void, pdwbitrate: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMStreamConfig", "SetBitrate", 11usize, |this| ((**(this as *const *const IWMStreamConfig_Vtbl)).SetBitrate)(this, pdwbitrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBufferWindow(this: *mut core::ffi::c_void, pmsbufferwindow: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMStreamConfig", "GetBufferWindow", 12usize, |this| ((**(this as *const *const IWMStreamConfig_Vtbl)).GetBufferWindow)(this, pmsbufferwindow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBufferWindow(this: *mut core::ffi::c_void, msbufferwindow: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMStreamConfig", "SetBufferWindow", 13usize, |this| ((**(this as *const *const IWMStreamConfig_Vtbl)).SetBufferWindow)(this, msbufferwindow), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetStreamType,
+            GetStreamNumber,
+            SetStreamNumber,
+            GetStreamName,
+            SetStreamName,
+            GetConnectionName,
+            SetConnectionName,
+            GetBitrate,
+            SetBitrate,
+            GetBufferWindow,
+            SetBufferWindow,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMStreamConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMStreamConfig2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTransportType(this: *mut core::ffi::c_void, pntransporttype: *mut WMT_TRANSPORT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMStreamConfig2", "GetTransportType", 14usize, |this| ((**(this as *const *const IWMStreamConfig2_Vtbl)).GetTransportType)(this, pntransporttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransportType(this: *mut core::ffi::c_void, ntransporttype: WMT_TRANSPORT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMStreamConfig2", "SetTransportType", 15usize, |this| ((**(this as *const *const IWMStreamConfig2_Vtbl)).SetTransportType)(this, ntransporttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDataUnitExtension(this: *mut core::ffi::c_void, guidextensionsystemid: windows_core::GUID, cbextensiondatasize: u16, pbextensionsysteminfo: *const u8, cbextensionsysteminfo: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMStreamConfig2", "AddDataUnitExtension", 16usize, |this| ((**(this as *const *const IWMStreamConfig2_Vtbl)).AddDataUnitExtension)(this, guidextensionsystemid, cbextensiondatasize, pbextensionsysteminfo, cbextensionsysteminfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataUnitExtensionCount(this: *mut core::ffi::c_void, pcdataunitextensions: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMStreamConfig2", "GetDataUnitExtensionCount", 17usize, |this| ((**(this as *const *const IWMStreamConfig2_Vtbl)).GetDataUnitExtensionCount)(this, pcdataunitextensions), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataUnitExtension(this: *mut core::ffi::c_void, wdataunitextensionnumber: u16, pguidextensionsystemid: *mut windows_core::GUID, pcbextensiondatasize: *mut u16, pbextensionsysteminfo: *mut u8, pcbextensionsysteminfo: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMStreamConfig2", "GetDataUnitExtension", 18usize, |this| ((**(this as *const *const IWMStreamConfig2_Vtbl)).GetDataUnitExtension)(this, wdataunitextensionnumber, pguidextensionsystemid, pcbextensiondatasize, pbextensionsysteminfo, pcbextensionsysteminfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAllDataUnitExtensions(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMStreamConfig2", "RemoveAllDataUnitExtensions", 19usize, |this| ((**(this as *const *const IWMStreamConfig2_Vtbl)).RemoveAllDataUnitExtensions)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMStreamConfig_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTransportType,
+            SetTransportType,
+            AddDataUnitExtension,
+            GetDataUnitExtensionCount,
+            GetDataUnitExtension,
+            RemoveAllDataUnitExtensions,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMStreamConfig2 as windows_core::Interface>::IID || <IWMStreamConfig_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMStreamConfig3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLanguage(this: *mut core::ffi::c_void, pwszlanguagestring: windows_core::PWSTR, pcchlanguagestringlength: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMStreamConfig3", "GetLanguage", 20usize, |this| ((**(this as *const *const IWMStreamConfig3_Vtbl)).GetLanguage)(this, pwszlanguagestring, pcchlanguagestringlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLanguage(this: *mut core::ffi::c_void, pwszlanguagestring: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMStreamConfig3", "SetLanguage", 21usize, |this| ((**(this as *const *const IWMStreamConfig3_Vtbl)).SetLanguage)(this, pwszlanguagestring), |result| Some(*result))
+        }
+        Self { base__: <IWMStreamConfig2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetLanguage, SetLanguage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMStreamConfig3 as windows_core::Interface>::IID || <IWMStreamConfig2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMStreamList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStreams(this: *mut core::ffi::c_void, pwstreamnumarray: *mut u16, pcstreams: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMStreamList", "GetStreams", 3usize, |this| ((**(this as *const *const IWMStreamList_Vtbl)).GetStreams)(this, pwstreamnumarray, pcstreams), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddStream(this: *mut core::ffi::c_void, wstreamnum: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMStreamList", "AddStream", 4usize, |this| ((**(this as *const *const IWMStreamList_Vtbl)).AddStream)(this, wstreamnum), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStream(this: *mut core::ffi::c_void, wstreamnum: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMStreamList", "RemoveStream", 5usize, |this| ((**(this as *const *const IWMStreamList_Vtbl)).RemoveStream)(this, wstreamnum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStreams, AddStream, RemoveStream }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMStreamList as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMStreamPrioritization_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPriorityRecords(this: *mut core::ffi::c_void, precordarray: *mut WM_STREAM_PRIORITY_RECORD, pcrecords: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMStreamPrioritization", "GetPriorityRecords", 3usize, |this| ((**(this as *const *const IWMStreamPrioritization_Vtbl)).GetPriorityRecords)(this, precordarray, pcrecords), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPriorityRecords(this: *mut core::ffi::c_void, precordarray: *const WM_STREAM_PRIORITY_RECORD, crecords: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMStreamPrioritization", "SetPriorityRecords", 4usize, |this| ((**(this as *const *const IWMStreamPrioritization_Vtbl)).SetPriorityRecords)(this, precordarray, crecords), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPriorityRecords, SetPriorityRecords }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMStreamPrioritization as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMSyncReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, pwszfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSyncReader", "Open", 3usize, |this| ((**(this as *const *const IWMSyncReader_Vtbl)).Open)(this, pwszfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSyncReader", "Close", 4usize, |this| ((**(this as *const *const IWMSyncReader_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRange(this: *mut core::ffi::c_void, cnsstarttime: u64, cnsduration: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSyncReader", "SetRange", 5usize, |this| ((**(this as *const *const IWMSyncReader_Vtbl)).SetRange)(this, cnsstarttime, cnsduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRangeByFrame(this: *mut core::ffi::c_void, wstreamnum: u16, qwframenumber: u64, cframestoread: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSyncReader", "SetRangeByFrame", 6usize, |this| ((**(this as *const *const IWMSyncReader_Vtbl)).SetRangeByFrame)(this, wstreamnum, qwframenumber, cframestoread), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextSample(this: *mut core::ffi::c_void, wstreamnum: u16, ppsample: *mut *mut core::ffi::c_void, pcnssampletime: *mut u64, pcnsduration: *mut u64, pdwflags: *mut u32, pdwoutputnum: *mut u32, pwstreamnum: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSyncReader", "GetNextSample", 7usize, |this| ((**(this as *const *const IWMSyncReader_Vtbl)).GetNextSample)(this, wstreamnum, ppsample, pcnssampletime, pcnsduration, pdwflags, pdwoutputnum, pwstreamnum), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStreamsSelected(this: *mut core::ffi::c_void, cstreamcount: u16, pwstreamnumbers: *const u16, pselections: *const WMT_STREAM_SELECTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSyncReader", "SetStreamsSelected", 8usize, |this| ((**(this as *const *const IWMSyncReader_Vtbl)).SetStreamsSelected)(this, cstreamcount, pwstreamnumbers, pselections), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamSelected(this: *mut core::ffi::c_void, wstreamnum: u16, pselection: *mut WMT_STREAM_SELECTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSyncReader", "GetStreamSelected", 9usize, |this| ((**(this as *const *const IWMSyncReader_Vtbl)).GetStreamSelected)(this, wstreamnum, pselection), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReadStreamSamples(this: *mut core::ffi::c_void, wstreamnum: u16, fcompressed: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSyncReader", "SetReadStreamSamples", 10usize, |this| ((**(this as *const *const IWMSyncReader_Vtbl)).SetReadStreamSamples)(this, wstreamnum, fcompressed), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReadStreamSamples(this: *mut core::ffi::c_void, wstreamnum: u16, pfcompressed: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSyncReader", "GetReadStreamSamples", 11usize, |this| ((**(this as *const *const IWMSyncReader_Vtbl)).GetReadStreamSamples)(this, wstreamnum, pfcompressed), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputSetting(this: *mut core::ffi::c_void, dwoutputnum: u32, pszname: windows_core::PCWSTR, ptype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pcblength: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSyncReader", "GetOutputSetting", 12usize, |this| ((**(this as *const *const IWMSyncReader_Vtbl)).GetOutputSetting)(this, dwoutputnum, pszname, ptype, pvalue, pcblength), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputSetting(this: *mut core::ffi::c_void, dwoutputnum: u32, pszname: windows_core::PCWSTR, r#type: WMT_ATTR_DATATYPE, pvalue: *const u8, cblength: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSyncReader", "SetOutputSetting", 13usize, |this| ((**(this as *const *const IWMSyncReader_Vtbl)).SetOutputSetting)(this, dwoutputnum, pszname, r#type, pvalue, cblength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputCount(this: *mut core::ffi::c_void, pcoutputs: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSyncReader", "GetOutputCount", 14usize, |this| ((**(this as *const *const IWMSyncReader_Vtbl)).GetOutputCount)(this, pcoutputs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputProps(this: *mut core::ffi::c_void, dwoutputnum: u32, ppoutput: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSyncReader", "GetOutputProps", 15usize, |this| ((**(this as *const *const IWMSyncReader_Vtbl)).GetOutputProps)(this, dwoutputnum, ppoutput), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputProps(this: *mut core::ffi::c_void, dwoutputnum: u32, poutput: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSyncReader", "SetOutputProps", 16usize, |this| ((**(this as *const *const IWMSyncReader_Vtbl)).SetOutputProps)(this, dwoutputnum, poutput), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputFormatCount(this: *mut core::ffi::c_void, dwoutputnum: u32, pcformats: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSyncReader", "GetOutputFormatCount", 17usize, |this| ((**(this as *const *const IWMSyncReader_Vtbl)).GetOutputFormatCount)(this, dwoutputnum, pcformats), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputFormat(this: *mut core::ffi::c_void, dwoutputnum: u32, dwformatnum: u32, ppprops: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSyncReader", "GetOutputFormat", 18usize, |this| ((**(this as *const *const IWMSyncReader_Vtbl)).GetOutputFormat)(this, dwoutputnum, dwformatnum, ppprops), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputNumberForStream(this: *mut core::ffi::c_void, wstreamnum: u16, pdwoutputnum: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSyncReader", "GetOutputNumberForStream", 19usize, |this| ((**(this as *const *const IWMSyncReader_Vtbl)).GetOutputNumberForStream)(this, wstreamnum, pdwoutputnum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamNumberForOutput(this: *mut core::ffi::c_void, dwoutputnum: u32, pwstreamnum: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSyncReader", "GetStreamNumberForOutput", 20usize, |this| ((**(this as *const *const IWMSyncReader_Vtbl)).GetStreamNumberForOutput)(this, dwoutputnum, pwstreamnum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxOutputSampleSize(this: *mut core::ffi::c_void, dwoutput: u32, pcbmax: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSyncReader", "GetMaxOutputSampleSize", 21usize, |this| ((**(this as *const *const IWMSyncReader_Vtbl)).GetMaxOutputSampleSize)(this, dwoutput, pcbmax), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxStreamSampleSize(this: *mut core::ffi::c_void, wstream: u16, pcbmax: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSyncReader", "GetMaxStreamSampleSize", 22usize, |this| ((**(this as *const *const IWMSyncReader_Vtbl)).GetMaxStreamSampleSize)(this, wstream, pcbmax), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OpenStream(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSyncReader", "OpenStream", 23usize, |this| ((**(this as *const *const IWMSyncReader_Vtbl)).OpenStream)(this, pstream), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Open,
+            Close,
+            SetRange,
+            SetRangeByFrame,
+            GetNextSample,
+            SetStreamsSelected,
+            GetStreamSelected,
+            SetReadStreamSamples,
+            GetReadStreamSamples,
+            GetOutputSetting,
+            SetOutputSetting,
+            GetOutputCount,
+            GetOutputProps,
+            SetOutputProps,
+            GetOutputFormatCount,
+            GetOutputFormat,
+            GetOutputNumberForStream,
+            GetStreamNumberForOutput,
+            GetMaxOutputSampleSize,
+            GetMaxStreamSampleSize,
+            #[cfg(feature = "Win32_System_Com")]
+            OpenStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OpenStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMSyncReader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMSyncReader2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetRangeByTimecode(this: *mut core::ffi::c_void, wstreamnum: u16, pstart: *const WMT_TIMECODE_EXTENSION_DATA, pend: *const WMT_TIMECODE_EXTENSION_DATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSyncReader2", "SetRangeByTimecode", 24usize, |this| ((**(this as *const *const IWMSyncReader2_Vtbl)).SetRangeByTimecode)(this, wstreamnum, pstart, pend), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRangeByFrameEx(this: *mut core::ffi::c_void, wstreamnum: u16, qwframenumber: u64, cframestoread: i64, pcnsstarttime: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSyncReader2", "SetRangeByFrameEx", 25usize, |this| ((**(this as *const *const IWMSyncReader2_Vtbl)).SetRangeByFrameEx)(this, wstreamnum, qwframenumber, cframestoread, pcnsstarttime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllocateForOutput(this: *mut core::ffi::c_void, dwoutputnum: u32, pallocator: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSyncReader2", "SetAllocateForOutput", 26usize, |this| ((**(this as *const *const IWMSyncReader2_Vtbl)).SetAllocateForOutput)(this, dwoutputnum, pallocator), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllocateForOutput(this: *mut core::ffi::c_void, dwoutputnum: u32, ppallocator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSyncReader2", "GetAllocateForOutput", 27usize, |this| ((**(this as *const *const IWMSyncReader2_Vtbl)).GetAllocateForOutput)(this, dwoutputnum, ppallocator), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllocateForStream(this: *mut core::ffi::c_void, wstreamnum: u16, pallocator: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSyncReader2", "SetAllocateForStream", 28usize, |this| ((**(this as *const *const IWMSyncReader2_Vtbl)).SetAllocateForStream)(this, wstreamnum, pallocator), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllocateForStream(this: *mut core::ffi::c_void, dwsreamnum: u16, ppallocator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMSyncReader2", "GetAllocateForStream", 29usize, |this| ((**(this as *const *const IWMSyncReader2_Vtbl)).GetAllocateForStream)(this, dwsreamnum, ppallocator), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMSyncReader_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetRangeByTimecode,
+            SetRangeByFrameEx,
+            SetAllocateForOutput,
+            GetAllocateForOutput,
+            SetAllocateForStream,
+            GetAllocateForStream,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMSyncReader2 as windows_core::Interface>::IID || <IWMSyncReader_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMVideoMediaProps_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMaxKeyFrameSpacing(this: *mut core::ffi::c_void, plltime: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMVideoMediaProps", "GetMaxKeyFrameSpacing", 6usize, |this| ((**(this as *const *const IWMVideoMediaProps_Vtbl)).GetMaxKeyFrameSpacing)(this, plltime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxKeyFrameSpacing(this: *mut core::ffi::c_void, lltime: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMVideoMediaProps", "SetMaxKeyFrameSpacing", 7usize, |this| ((**(this as *const *const IWMVideoMediaProps_Vtbl)).SetMaxKeyFrameSpacing)(this, lltime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetQuality(this: *mut core::ffi::c_void, pdwquality: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMVideoMediaProps", "GetQuality", 8usize, |this| ((**(this as *const *const IWMVideoMediaProps_Vtbl)).GetQuality)(this, pdwquality), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQuality(this: *mut core::ffi::c_void, dwquality: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMVideoMediaProps", "SetQuality", 9usize, |this| ((**(this as *const *const IWMVideoMediaProps_Vtbl)).SetQuality)(this, dwquality), |result| Some(*result))
+        }
+        Self { base__: <IWMMediaProps_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMaxKeyFrameSpacing, SetMaxKeyFrameSpacing, GetQuality, SetQuality }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMVideoMediaProps as windows_core::Interface>::IID || <IWMMediaProps_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMWatermarkInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetWatermarkEntryCount(this: *mut core::ffi::c_void, wmettype: WMT_WATERMARK_ENTRY_TYPE, pdwcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWatermarkInfo", "GetWatermarkEntryCount", 3usize, |this| ((**(this as *const *const IWMWatermarkInfo_Vtbl)).GetWatermarkEntryCount)(this, wmettype, pdwcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWatermarkEntry(this: *mut core::ffi::c_void, wmettype: WMT_WATERMARK_ENTRY_TYPE, dwentrynum: u32, pentry: *mut WMT_WATERMARK_ENTRY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWatermarkInfo", "GetWatermarkEntry", 4usize, |this| ((**(this as *const *const IWMWatermarkInfo_Vtbl)).GetWatermarkEntry)(this, wmettype, dwentrynum, pentry), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetWatermarkEntryCount, GetWatermarkEntry }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMWatermarkInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMWriter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetProfileByID(this: *mut core::ffi::c_void, guidprofile: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriter", "SetProfileByID", 3usize, |this| ((**(this as *const *const IWMWriter_Vtbl)).SetProfileByID)(this, guidprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProfile(this: *mut core::ffi::c_void, pprofile: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriter", "SetProfile", 4usize, |this| ((**(this as *const *const IWMWriter_Vtbl)).SetProfile)(this, pprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputFilename(this: *mut core::ffi::c_void, pwszfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriter", "SetOutputFilename", 5usize, |this| ((**(this as *const *const IWMWriter_Vtbl)).SetOutputFilename)(this, pwszfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputCount(this: *mut core::ffi::c_void, pcinputs: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriter", "GetInputCount", 6usize, |this| ((**(this as *const *const IWMWriter_Vtbl)).GetInputCount)(this, pcinputs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputProps(this: *mut core::ffi::c_void, dwinputnum: u32, ppinput: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriter", "GetInputProps", 7usize, |this| ((**(this as *const *const IWMWriter_Vtbl)).GetInputProps)(this, dwinputnum, ppinput), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInputProps(this: *mut core::ffi::c_void, dwinputnum: u32, pinput: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriter", "SetInputProps", 8usize, |this| ((**(this as *const *const IWMWriter_Vtbl)).SetInputProps)(this, dwinputnum, pinput), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputFormatCount(this: *mut core::ffi::c_void, dwinputnumber: u32, pcformats: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriter", "GetInputFormatCount", 9usize, |this| ((**(this as *const *const IWMWriter_Vtbl)).GetInputFormatCount)(this, dwinputnumber, pcformats), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputFormat(this: *mut core::ffi::c_void, dwinputnumber: u32, dwformatnumber: u32, pprops: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriter", "GetInputFormat", 10usize, |this| ((**(this as *const *const IWMWriter_Vtbl)).GetInputFormat)(this, dwinputnumber, dwformatnumber, pprops), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginWriting(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriter", "BeginWriting", 11usize, |this| ((**(this as *const *const IWMWriter_Vtbl)).BeginWriting)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndWriting(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriter", "EndWriting", 12usize, |this| ((**(this as *const *const IWMWriter_Vtbl)).EndWriting)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllocateSample(this: *mut core::ffi::c_void, dwsamplesize: u32, ppsample: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriter", "AllocateSample", 13usize, |this| ((**(this as *const *const IWMWriter_Vtbl)).AllocateSample)(this, dwsamplesize, ppsample), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteSample(this: *mut core::ffi::c_void, dwinputnum: u32, cnssampletime: u64, dwflags: u32, psample: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriter", "WriteSample", 14usize, |this| ((**(this as *const *const IWMWriter_Vtbl)).WriteSample)(this, dwinputnum, cnssampletime, dwflags, psample), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flush(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriter", "Flush", 15usize, |this| ((**(this as *const *const IWMWriter_Vtbl)).Flush)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetProfileByID,
+            SetProfile,
+            SetOutputFilename,
+            GetInputCount,
+            GetInputProps,
+            SetInputProps,
+            GetInputFormatCount,
+            GetInputFormat,
+            BeginWriting,
+            EndWriting,
+            AllocateSample,
+            WriteSample,
+            Flush,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMWriter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMWriterAdvanced_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSinkCount(this: *mut core::ffi::c_void, pcsinks: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterAdvanced", "GetSinkCount", 3usize, |this| ((**(this as *const *const IWMWriterAdvanced_Vtbl)).GetSinkCount)(this, pcsinks), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSink(this: *mut core::ffi::c_void, dwsinknum: u32, ppsink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterAdvanced", "GetSink", 4usize, |this| ((**(this as *const *const IWMWriterAdvanced_Vtbl)).GetSink)(this, dwsinknum, ppsink), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddSink(this: *mut core::ffi::c_void, psink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterAdvanced", "AddSink", 5usize, |this| ((**(this as *const *const IWMWriterAdvanced_Vtbl)).AddSink)(this, psink), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSink(this: *mut core::ffi::c_void, psink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterAdvanced", "RemoveSink", 6usize, |this| ((**(this as *const *const IWMWriterAdvanced_Vtbl)).RemoveSink)(this, psink), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteStreamSample(this: *mut core::ffi::c_void, wstreamnum: u16, cnssampletime: u64, mssamplesendtime: u32, cnssampleduration: u64, dwflags: u32, psample: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterAdvanced", "WriteStreamSample", 7usize, |this| ((**(this as *const *const IWMWriterAdvanced_Vtbl)).WriteStreamSample)(this, wstreamnum, cnssampletime, mssamplesendtime, cnssampleduration, dwflags, psample), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLiveSource(this: *mut core::ffi::c_void, fislivesource: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterAdvanced", "SetLiveSource", 8usize, |this| ((**(this as *const *const IWMWriterAdvanced_Vtbl)).SetLiveSource)(this, fislivesource), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRealTime(this: *mut core::ffi::c_void, pfrealtime: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterAdvanced", "IsRealTime", 9usize, |this| ((**(this as *const *const IWMWriterAdvanced_Vtbl)).IsRealTime)(this, pfrealtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWriterTime(this: *mut core::ffi::c_void, pcnscurrenttime: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterAdvanced", "GetWriterTime", 10usize, |this| ((**(this as *const *const IWMWriterAdvanced_Vtbl)).GetWriterTime)(this, pcnscurrenttime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatistics(this: *mut core::ffi::c_void, wstreamnum: u16, pstats: *mut WM_WRITER_STATISTICS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterAdvanced", "GetStatistics", 11usize, |this| ((**(this as *const *const IWMWriterAdvanced_Vtbl)).GetStatistics)(this, wstreamnum, pstats), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSyncTolerance(this: *mut core::ffi::c_void, mswindow: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterAdvanced", "SetSyncTolerance", 12usize, |this| ((**(this as *const *const IWMWriterAdvanced_Vtbl)).SetSyncTolerance)(this, mswindow), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSyncTolerance(this: *mut core::ffi::c_void, pmswindow: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterAdvanced", "GetSyncTolerance", 13usize, |this| ((**(this as *const *const IWMWriterAdvanced_Vtbl)).GetSyncTolerance)(this, pmswindow), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSinkCount,
+            GetSink,
+            AddSink,
+            RemoveSink,
+            WriteStreamSample,
+            SetLiveSource,
+            IsRealTime,
+            GetWriterTime,
+            GetStatistics,
+            SetSyncTolerance,
+            GetSyncTolerance,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMWriterAdvanced as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMWriterAdvanced2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInputSetting(this: *mut core::ffi::c_void, dwinputnum: u32, pszname: windows_core::PCWSTR, ptype: *mut WMT_ATTR_DATATYPE, pvalue: *mut u8, pcblength: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterAdvanced2", "GetInputSetting", 14usize, |this| ((**(this as *const *const IWMWriterAdvanced2_Vtbl)).GetInputSetting)(this, dwinputnum, pszname, ptype, pvalue, pcblength), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInputSetting(this: *mut core::ffi::c_void, dwinputnum: u32, pszname: windows_core::PCWSTR, r#type: WMT_ATTR_DATATYPE, pvalue: *const u8, cblength: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterAdvanced2", "SetInputSetting", 15usize, |this| ((**(this as *const *const IWMWriterAdvanced2_Vtbl)).SetInputSetting)(this, dwinputnum, pszname, r#type, pvalue, cblength), |result| Some(*result))
+        }
+        Self { base__: <IWMWriterAdvanced_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetInputSetting, SetInputSetting }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMWriterAdvanced2 as windows_core::Interface>::IID || <IWMWriterAdvanced_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMWriterAdvanced3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStatisticsEx(this: *mut core::ffi::c_void, wstreamnum: u16, pstats: *mut WM_WRITER_STATISTICS_EX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterAdvanced3", "GetStatisticsEx", 16usize, |this| ((**(this as *const *const IWMWriterAdvanced3_Vtbl)).GetStatisticsEx)(this, wstreamnum, pstats), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNonBlocking(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterAdvanced3", "SetNonBlocking", 17usize, |this| ((**(this as *const *const IWMWriterAdvanced3_Vtbl)).SetNonBlocking)(this), |result| Some(*result))
+        }
+        Self { base__: <IWMWriterAdvanced2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStatisticsEx, SetNonBlocking }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMWriterAdvanced3 as windows_core::Interface>::IID || <IWMWriterAdvanced2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMWriterFileSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, pwszfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterFileSink", "Open", 8usize, |this| ((**(this as *const *const IWMWriterFileSink_Vtbl)).Open)(this, pwszfilename), |result| Some(*result))
+        }
+        Self { base__: <IWMWriterSink_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Open }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMWriterFileSink as windows_core::Interface>::IID || <IWMWriterSink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMWriterFileSink2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void, cnsstarttime: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterFileSink2", "Start", 9usize, |this| ((**(this as *const *const IWMWriterFileSink2_Vtbl)).Start)(this, cnsstarttime), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void, cnsstoptime: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterFileSink2", "Stop", 10usize, |this| ((**(this as *const *const IWMWriterFileSink2_Vtbl)).Stop)(this, cnsstoptime), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStopped(this: *mut core::ffi::c_void, pfstopped: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterFileSink2", "IsStopped", 11usize, |this| ((**(this as *const *const IWMWriterFileSink2_Vtbl)).IsStopped)(this, pfstopped), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileDuration(this: *mut core::ffi::c_void, pcnsduration: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterFileSink2", "GetFileDuration", 12usize, |this| ((**(this as *const *const IWMWriterFileSink2_Vtbl)).GetFileDuration)(this, pcnsduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileSize(this: *mut core::ffi::c_void, pcbfile: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterFileSink2", "GetFileSize", 13usize, |this| ((**(this as *const *const IWMWriterFileSink2_Vtbl)).GetFileSize)(this, pcbfile), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterFileSink2", "Close", 14usize, |this| ((**(this as *const *const IWMWriterFileSink2_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsClosed(this: *mut core::ffi::c_void, pfclosed: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterFileSink2", "IsClosed", 15usize, |this| ((**(this as *const *const IWMWriterFileSink2_Vtbl)).IsClosed)(this, pfclosed), |result| Some(*result))
+        }
+        Self { base__: <IWMWriterFileSink_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Start, Stop, IsStopped, GetFileDuration, GetFileSize, Close, IsClosed }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMWriterFileSink2 as windows_core::Interface>::IID || <IWMWriterFileSink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMWriterFileSink3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAutoIndexing(this: *mut core::ffi::c_void, fdoautoindexing: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterFileSink3", "SetAutoIndexing", 16usize, |this| ((**(this as *const *const IWMWriterFileSink3_Vtbl)).SetAutoIndexing)(this, fdoautoindexing), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAutoIndexing(this: *mut core::ffi::c_void, pfautoindexing: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterFileSink3", "GetAutoIndexing", 17usize, |this| ((**(this as *const *const IWMWriterFileSink3_Vtbl)).GetAutoIndexing)(this, pfautoindexing), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetControlStream(this: *mut core::ffi::c_void, wstreamnumber: u16, fshouldcontrolstartandstop: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterFileSink3", "SetControlStream", 18usize, |this| ((**(this as *const *const IWMWriterFileSink3_Vtbl)).SetControlStream)(this, wstreamnumber, fshouldcontrolstartandstop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMode(this: *mut core::ffi::c_void, pdwfilesinkmode: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterFileSink3", "GetMode", 19usize, |this| ((**(this as *const *const IWMWriterFileSink3_Vtbl)).GetMode)(this, pdwfilesinkmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDataUnitEx(this: *mut core::ffi::c_void, pfilesinkdataunit: *const WMT_FILESINK_DATA_UNIT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterFileSink3", "OnDataUnitEx", 20usize, |this| ((**(this as *const *const IWMWriterFileSink3_Vtbl)).OnDataUnitEx)(this, pfilesinkdataunit), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUnbufferedIO(this: *mut core::ffi::c_void, funbufferedio: super::super::Foundation::BOOL, frestrictmemusage: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterFileSink3", "SetUnbufferedIO", 21usize, |this| ((**(this as *const *const IWMWriterFileSink3_Vtbl)).SetUnbufferedIO)(this, funbufferedio, frestrictmemusage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUnbufferedIO(this: *mut core::ffi::c_void, pfunbufferedio: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterFileSink3", "GetUnbufferedIO", 22usize, |this| ((**(this as *const *const IWMWriterFileSink3_Vtbl)).GetUnbufferedIO)(this, pfunbufferedio), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompleteOperations(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterFileSink3", "CompleteOperations", 23usize, |this| ((**(this as *const *const IWMWriterFileSink3_Vtbl)).CompleteOperations)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMWriterFileSink2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetAutoIndexing,
+            GetAutoIndexing,
+            SetControlStream,
+            GetMode,
+            OnDataUnitEx,
+            SetUnbufferedIO,
+            GetUnbufferedIO,
+            CompleteOperations,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMWriterFileSink3 as windows_core::Interface>::IID || <IWMWriterFileSink2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMWriterNetworkSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMaximumClients(this: *mut core::ffi::c_void, dwmaxclients: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterNetworkSink", "SetMaximumClients", 8usize, |this| ((**(this as *const *const IWMWriterNetworkSink_Vtbl)).SetMaximumClients)(this, dwmaxclients), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaximumClients(this: *mut core::ffi::c_void, pdwmaxclients: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterNetworkSink", "GetMaximumClients", 9usize, |this| ((**(this as *const *const IWMWriterNetworkSink_Vtbl)).GetMaximumClients)(this, pdwmaxclients), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNetworkProtocol(this: *mut core::ffi::c_void, protocol: WMT_NET_PROTOCOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterNetworkSink", "SetNetworkProtocol", 10usize, |this| ((**(this as *const *const IWMWriterNetworkSink_Vtbl)).SetNetworkProtocol)(this, protocol), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNetworkProtocol(this: *mut core::ffi::c_void, pprotocol: *mut WMT_NET_PROTOCOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterNetworkSink", "GetNetworkProtocol", 11usize, |this| ((**(this as *const *const IWMWriterNetworkSink_Vtbl)).GetNetworkProtocol)(this, pprotocol), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHostURL(this: *mut core::ffi::c_void, pwszurl: windows_core::PWSTR, pcchurl: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterNetworkSink", "GetHostURL", 12usize, |this| ((**(this as *const *const IWMWriterNetworkSink_Vtbl)).GetHostURL)(this, pwszurl, pcchurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, pdwportnum: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterNetworkSink", "Open", 13usize, |this| ((**(this as *const *const IWMWriterNetworkSink_Vtbl)).Open)(this, pdwportnum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterNetworkSink", "Disconnect", 14usize, |this| ((**(this as *const *const IWMWriterNetworkSink_Vtbl)).Disconnect)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterNetworkSink", "Close", 15usize, |this| ((**(this as *const *const IWMWriterNetworkSink_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IWMWriterSink_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetMaximumClients,
+            GetMaximumClients,
+            SetNetworkProtocol,
+            GetNetworkProtocol,
+            GetHostURL,
+            Open,
+            Disconnect,
+            Close,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMWriterNetworkSink as windows_core::Interface>::IID || <IWMWriterSink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMWriterPostView_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPostViewCallback(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, pvcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterPostView", "SetPostViewCallback", 3usize, |this| ((**(this as *const *const IWMWriterPostView_Vtbl)).SetPostViewCallback)(this, pcallback, pvcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReceivePostViewSamples(this: *mut core::ffi::c_void, wstreamnum: u16, freceivepostviewsamples: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterPostView", "SetReceivePostViewSamples", 4usize, |this| ((**(this as *const *const IWMWriterPostView_Vtbl)).SetReceivePostViewSamples)(this, wstreamnum, freceivepostviewsamples), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReceivePostViewSamples(this: *mut core::ffi::c_void, wstreamnum: u16, pfreceivepostviewsamples: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterPostView", "GetReceivePostViewSamples", 5usize, |this| ((**(this as *const *const IWMWriterPostView_Vtbl)).GetReceivePostViewSamples)(this, wstreamnum, pfreceivepostviewsamples), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPostViewProps(this: *mut core::ffi::c_void, wstreamnumber: u16, ppoutput: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterPostView", "GetPostViewProps", 6usize, |this| ((**(this as *const *const IWMWriterPostView_Vtbl)).GetPostViewProps)(this, wstreamnumber, ppoutput), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPostViewProps(this: *mut core::ffi::c_void, wstreamnumber: u16, poutput: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterPostView", "SetPostViewProps", 7usize, |this| ((**(this as *const *const IWMWriterPostView_Vtbl)).SetPostViewProps)(this, wstreamnumber, poutput), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPostViewFormatCount(this: *mut core::ffi::c_void, wstreamnumber: u16, pcformats: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterPostView", "GetPostViewFormatCount", 8usize, |this| ((**(this as *const *const IWMWriterPostView_Vtbl)).GetPostViewFormatCount)(this, wstreamnumber, pcformats), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPostViewFormat(this: *mut core::ffi::c_void, wstreamnumber: u16, dwformatnumber: u32, ppprops: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterPostView", "GetPostViewFormat", 9usize, |this| ((**(this as *const *const IWMWriterPostView_Vtbl)).GetPostViewFormat)(this, wstreamnumber, dwformatnumber, ppprops), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllocateForPostView(this: *mut core::ffi::c_void, wstreamnumber: u16, fallocate: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterPostView", "SetAllocateForPostView", 10usize, |this| ((**(this as *const *const IWMWriterPostView_Vtbl)).SetAllocateForPostView)(this, wstreamnumber, fallocate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllocateForPostView(this: *mut core::ffi::c_void, wstreamnumber: u16, pfallocate: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterPostView", "GetAllocateForPostView", 11usize, |this| ((**(this as *const *const IWMWriterPostView_Vtbl)).GetAllocateForPostView)(this, wstreamnumber, pfallocate), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetPostViewCallback,
+            SetReceivePostViewSamples,
+            GetReceivePostViewSamples,
+            GetPostViewProps,
+            SetPostViewProps,
+            GetPostViewFormatCount,
+            GetPostViewFormat,
+            SetAllocateForPostView,
+            GetAllocateForPostView,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMWriterPostView as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMWriterPostViewCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnPostViewSample(this: *mut core::ffi::c_void, wstreamnumber: u16, cnssampletime: u64, cnssampleduration: u64, dwflags: u32, psample: *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterPostViewCallback", "OnPostViewSample", 4usize, |this| ((**(this as *const *const IWMWriterPostViewCallback_Vtbl)).OnPostViewSample)(this, wstreamnumber, cnssampletime, cnssampleduration, dwflags, psample, pvcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllocateForPostView(this: *mut core::ffi::c_void, wstreamnum: u16, cbbuffer: u32, ppbuffer: *mut *mut core::ffi::c_void, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterPostViewCallback", "AllocateForPostView", 5usize, |this| ((**(this as *const *const IWMWriterPostViewCallback_Vtbl)).AllocateForPostView)(this, wstreamnum, cbbuffer, ppbuffer, pvcontext), |result| Some(*result))
+        }
+        Self { base__: <IWMStatusCallback_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnPostViewSample, AllocateForPostView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMWriterPostViewCallback as windows_core::Interface>::IID || <IWMStatusCallback_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMWriterPreprocess_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMaxPreprocessingPasses(this: *mut core::ffi::c_void, dwinputnum: u32, dwflags: u32, pdwmaxnumpasses: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterPreprocess", "GetMaxPreprocessingPasses", 3usize, |this| ((**(this as *const *const IWMWriterPreprocess_Vtbl)).GetMaxPreprocessingPasses)(this, dwinputnum, dwflags, pdwmaxnumpasses), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNumPreprocessingPasses(this: *mut core::ffi::c_void, dwinputnum: u32, dwflags: u32, dwnumpasses: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterPreprocess", "SetNumPreprocessingPasses", 4usize, |this| ((**(this as *const *const IWMWriterPreprocess_Vtbl)).SetNumPreprocessingPasses)(this, dwinputnum, dwflags, dwnumpasses), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginPreprocessingPass(this: *mut core::ffi::c_void, dwinputnum: u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterPreprocess", "BeginPreprocessingPass", 5usize, |this| ((**(this as *const *const IWMWriterPreprocess_Vtbl)).BeginPreprocessingPass)(this, dwinputnum, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreprocessSample(this: *mut core::ffi::c_void, dwinputnum: u32, cnssampletime: u64, dwflags: u32, psample: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterPreprocess", "PreprocessSample", 6usize, |this| ((**(this as *const *const IWMWriterPreprocess_Vtbl)).PreprocessSample)(this, dwinputnum, cnssampletime, dwflags, psample), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndPreprocessingPass(this: *mut core::ffi::c_void, dwinputnum: u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterPreprocess", "EndPreprocessingPass", 7usize, |this| ((**(this as *const *const IWMWriterPreprocess_Vtbl)).EndPreprocessingPass)(this, dwinputnum, dwflags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetMaxPreprocessingPasses,
+            SetNumPreprocessingPasses,
+            BeginPreprocessingPass,
+            PreprocessSample,
+            EndPreprocessingPass,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMWriterPreprocess as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMWriterPushSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Connect(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, pwsztemplateurl: windows_core::PCWSTR, fautodestroy: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterPushSink", "Connect", 8usize, |this| ((**(this as *const *const IWMWriterPushSink_Vtbl)).Connect)(this, pwszurl, pwsztemplateurl, fautodestroy), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterPushSink", "Disconnect", 9usize, |this| ((**(this as *const *const IWMWriterPushSink_Vtbl)).Disconnect)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndSession(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterPushSink", "EndSession", 10usize, |this| ((**(this as *const *const IWMWriterPushSink_Vtbl)).EndSession)(this), |result| Some(*result))
+        }
+        Self { base__: <IWMWriterSink_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Connect, Disconnect, EndSession }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMWriterPushSink as windows_core::Interface>::IID || <IWMWriterSink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWMWriterSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnHeader(this: *mut core::ffi::c_void, pheader: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterSink", "OnHeader", 3usize, |this| ((**(this as *const *const IWMWriterSink_Vtbl)).OnHeader)(this, pheader), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRealTime(this: *mut core::ffi::c_void, pfrealtime: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterSink", "IsRealTime", 4usize, |this| ((**(this as *const *const IWMWriterSink_Vtbl)).IsRealTime)(this, pfrealtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllocateDataUnit(this: *mut core::ffi::c_void, cbdataunit: u32, ppdataunit: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterSink", "AllocateDataUnit", 5usize, |this| ((**(this as *const *const IWMWriterSink_Vtbl)).AllocateDataUnit)(this, cbdataunit, ppdataunit), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDataUnit(this: *mut core::ffi::c_void, pdataunit: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterSink", "OnDataUnit", 6usize, |this| ((**(this as *const *const IWMWriterSink_Vtbl)).OnDataUnit)(this, pdataunit), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnEndWriting(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMWriterSink", "OnEndWriting", 7usize, |this| ((**(this as *const *const IWMWriterSink_Vtbl)).OnEndWriting)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnHeader, IsRealTime, AllocateDataUnit, OnDataUnit, OnEndWriting }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMWriterSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -244,6 +244,7 @@ pub struct TIMECODE_SAMPLE_FLAGS(pub u32);
 impl windows_core::TypeKind for TIMECODE_SAMPLE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TIMECODE_SAMPLE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TIMECODE_SAMPLE_FLAGS").field(&self.0).finish()
@@ -283,7 +284,8 @@ impl core::ops::Not for TIMECODE_SAMPLE_FLAGS {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HTASK(pub *mut core::ffi::c_void);
 impl HTASK {
     pub fn is_invalid(&self) -> bool {
@@ -344,7 +346,8 @@ impl Default for MMTIME_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MMTIME_0_1 {
     pub hour: u8,
     pub min: u8,
@@ -363,7 +366,8 @@ impl Default for MMTIME_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TIMECAPS {
     pub wPeriodMin: u32,
     pub wPeriodMax: u32,
@@ -391,7 +395,8 @@ impl Default for TIMECODE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TIMECODE_0 {
     pub wFrameRate: u16,
     pub wFrameFract: u16,
@@ -426,3 +431,5 @@ pub type LPDRVCALLBACK = Option<unsafe extern "system" fn(hdrvr: Multimedia::HDR
 pub type LPTIMECALLBACK = Option<unsafe extern "system" fn(utimerid: u32, umsg: u32, dwuser: usize, dw1: usize, dw2: usize)>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
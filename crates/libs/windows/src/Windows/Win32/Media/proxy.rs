@@ -0,0 +1,43 @@
+impl windows_core::imp::ProxyVtable for IReferenceClock_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTime(this: *mut core::ffi::c_void, ptime: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReferenceClock", "GetTime", 3usize, |this| ((**(this as *const *const IReferenceClock_Vtbl)).GetTime)(this, ptime), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdviseTime(this: *mut core::ffi::c_void, basetime: i64, streamtime: i64, hevent: super::Foundation::HANDLE, pdwadvisecookie: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReferenceClock", "AdviseTime", 4usize, |this| ((**(this as *const *const IReferenceClock_Vtbl)).AdviseTime)(this, basetime, streamtime, hevent, pdwadvisecookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdvisePeriodic(this: *mut core::ffi::c_void, starttime: i64, periodtime: i64, hsemaphore: super::Foundation::HANDLE, pdwadvisecookie: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReferenceClock", "AdvisePeriodic", 5usize, |this| ((**(this as *const *const IReferenceClock_Vtbl)).AdvisePeriodic)(this, starttime, periodtime, hsemaphore, pdwadvisecookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unadvise(this: *mut core::ffi::c_void, dwadvisecookie: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReferenceClock", "Unadvise", 6usize, |this| ((**(this as *const *const IReferenceClock_Vtbl)).Unadvise)(this, dwadvisecookie), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetTime, AdviseTime, AdvisePeriodic, Unadvise }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IReferenceClock as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IReferenceClock2_Vtbl {
+    const PROXY: Self = { Self { base__: <IReferenceClock_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IReferenceClock2 as windows_core::Interface>::IID || <IReferenceClock_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IReferenceClockTimerControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDefaultTimerResolution(this: *mut core::ffi::c_void, timerresolution: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReferenceClockTimerControl", "SetDefaultTimerResolution", 3usize, |this| ((**(this as *const *const IReferenceClockTimerControl_Vtbl)).SetDefaultTimerResolution)(this, timerresolution), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultTimerResolution(this: *mut core::ffi::c_void, ptimerresolution: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReferenceClockTimerControl", "GetDefaultTimerResolution", 4usize, |this| ((**(this as *const *const IReferenceClockTimerControl_Vtbl)).GetDefaultTimerResolution)(this, ptimerresolution), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetDefaultTimerResolution, GetDefaultTimerResolution }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IReferenceClockTimerControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
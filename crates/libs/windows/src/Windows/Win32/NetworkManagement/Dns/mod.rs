@@ -810,6 +810,7 @@ pub struct DNS_CHARSET(pub i32);
 impl windows_core::TypeKind for DNS_CHARSET {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DNS_CHARSET {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DNS_CHARSET").field(&self.0).finish()
@@ -821,6 +822,7 @@ pub struct DNS_CONFIG_TYPE(pub i32);
 impl windows_core::TypeKind for DNS_CONFIG_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DNS_CONFIG_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DNS_CONFIG_TYPE").field(&self.0).finish()
@@ -832,6 +834,7 @@ pub struct DNS_CONNECTION_POLICY_TAG(pub i32);
 impl windows_core::TypeKind for DNS_CONNECTION_POLICY_TAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DNS_CONNECTION_POLICY_TAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DNS_CONNECTION_POLICY_TAG").field(&self.0).finish()
@@ -843,6 +846,7 @@ pub struct DNS_CONNECTION_PROXY_INFO_SWITCH(pub i32);
 impl windows_core::TypeKind for DNS_CONNECTION_PROXY_INFO_SWITCH {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DNS_CONNECTION_PROXY_INFO_SWITCH {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DNS_CONNECTION_PROXY_INFO_SWITCH").field(&self.0).finish()
@@ -854,6 +858,7 @@ pub struct DNS_CONNECTION_PROXY_TYPE(pub i32);
 impl windows_core::TypeKind for DNS_CONNECTION_PROXY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DNS_CONNECTION_PROXY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DNS_CONNECTION_PROXY_TYPE").field(&self.0).finish()
@@ -865,6 +870,7 @@ pub struct DNS_FREE_TYPE(pub i32);
 impl windows_core::TypeKind for DNS_FREE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DNS_FREE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DNS_FREE_TYPE").field(&self.0).finish()
@@ -876,6 +882,7 @@ pub struct DNS_NAME_FORMAT(pub i32);
 impl windows_core::TypeKind for DNS_NAME_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DNS_NAME_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DNS_NAME_FORMAT").field(&self.0).finish()
@@ -887,6 +894,7 @@ pub struct DNS_PROXY_INFORMATION_TYPE(pub i32);
 impl windows_core::TypeKind for DNS_PROXY_INFORMATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DNS_PROXY_INFORMATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DNS_PROXY_INFORMATION_TYPE").field(&self.0).finish()
@@ -898,6 +906,7 @@ pub struct DNS_QUERY_OPTIONS(pub u32);
 impl windows_core::TypeKind for DNS_QUERY_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DNS_QUERY_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DNS_QUERY_OPTIONS").field(&self.0).finish()
@@ -942,6 +951,7 @@ pub struct DNS_SECTION(pub i32);
 impl windows_core::TypeKind for DNS_SECTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DNS_SECTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DNS_SECTION").field(&self.0).finish()
@@ -953,6 +963,7 @@ pub struct DNS_SVCB_PARAM_TYPE(pub i32);
 impl windows_core::TypeKind for DNS_SVCB_PARAM_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DNS_SVCB_PARAM_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DNS_SVCB_PARAM_TYPE").field(&self.0).finish()
@@ -964,6 +975,7 @@ pub struct DNS_TYPE(pub u16);
 impl windows_core::TypeKind for DNS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DNS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DNS_TYPE").field(&self.0).finish()
@@ -1032,7 +1044,8 @@ impl Default for DNS_ADDR_ARRAY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_APPLICATION_SETTINGS {
     pub Version: u32,
     pub Flags: u64,
@@ -1046,7 +1059,8 @@ impl Default for DNS_APPLICATION_SETTINGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_ATMA_DATA {
     pub AddressType: u8,
     pub Address: [u8; 20],
@@ -1060,7 +1074,8 @@ impl Default for DNS_ATMA_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_A_DATA {
     pub IpAddress: u32,
 }
@@ -1073,7 +1088,8 @@ impl Default for DNS_A_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_CONNECTION_IFINDEX_ENTRY {
     pub pwszConnectionName: windows_core::PCWSTR,
     pub dwIfIndex: u32,
@@ -1087,7 +1103,8 @@ impl Default for DNS_CONNECTION_IFINDEX_ENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_CONNECTION_IFINDEX_LIST {
     pub pConnectionIfIndexEntries: *mut DNS_CONNECTION_IFINDEX_ENTRY,
     pub nEntries: u32,
@@ -1101,7 +1118,8 @@ impl Default for DNS_CONNECTION_IFINDEX_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_CONNECTION_NAME {
     pub wszName: [u16; 65],
 }
@@ -1114,7 +1132,8 @@ impl Default for DNS_CONNECTION_NAME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_CONNECTION_NAME_LIST {
     pub cNames: u32,
     pub pNames: *mut DNS_CONNECTION_NAME,
@@ -1128,7 +1147,8 @@ impl Default for DNS_CONNECTION_NAME_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_CONNECTION_POLICY_ENTRY {
     pub pwszHost: windows_core::PCWSTR,
     pub pwszAppId: windows_core::PCWSTR,
@@ -1147,7 +1167,8 @@ impl Default for DNS_CONNECTION_POLICY_ENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_CONNECTION_POLICY_ENTRY_LIST {
     pub pPolicyEntries: *mut DNS_CONNECTION_POLICY_ENTRY,
     pub nEntries: u32,
@@ -1206,7 +1227,8 @@ impl Default for DNS_CONNECTION_PROXY_INFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_CONNECTION_PROXY_INFO_0_0 {
     pub pwszServer: windows_core::PWSTR,
     pub pwszUsername: windows_core::PWSTR,
@@ -1224,7 +1246,8 @@ impl Default for DNS_CONNECTION_PROXY_INFO_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_CONNECTION_PROXY_INFO_0_1 {
     pub pwszScript: windows_core::PWSTR,
     pub pwszUsername: windows_core::PWSTR,
@@ -1256,7 +1279,8 @@ impl Default for DNS_CONNECTION_PROXY_INFO_EX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_CONNECTION_PROXY_LIST {
     pub cProxies: u32,
     pub pProxies: *mut DNS_CONNECTION_PROXY_ELEMENT,
@@ -1312,7 +1336,8 @@ impl Default for DNS_CUSTOM_SERVER_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_DHCID_DATA {
     pub dwByteCount: u32,
     pub DHCID: [u8; 1],
@@ -1326,7 +1351,8 @@ impl Default for DNS_DHCID_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_DS_DATA {
     pub wKeyTag: u16,
     pub chAlgorithm: u8,
@@ -1354,6 +1380,68 @@ pub struct DNS_HEADER {
     pub NameServerCount: u16,
     pub AdditionalCount: u16,
 }
+impl DNS_HEADER {
+    pub const fn RecursionDesired(&self) -> u8 {
+        self._bitfield1 & 1
+    }
+    pub fn set_RecursionDesired(&mut self, value: u8) {
+        self._bitfield1 = (self._bitfield1 & !1) | (value & 1);
+    }
+    pub const fn Truncation(&self) -> u8 {
+        (self._bitfield1 >> 1) & 1
+    }
+    pub fn set_Truncation(&mut self, value: u8) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Authoritative(&self) -> u8 {
+        (self._bitfield1 >> 2) & 1
+    }
+    pub fn set_Authoritative(&mut self, value: u8) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Opcode(&self) -> u8 {
+        (self._bitfield1 >> 3) & 15
+    }
+    pub fn set_Opcode(&mut self, value: u8) {
+        self._bitfield1 = (self._bitfield1 & !(15 << 3)) | ((value & 15) << 3);
+    }
+    pub const fn IsResponse(&self) -> u8 {
+        (self._bitfield1 >> 7) & 1
+    }
+    pub fn set_IsResponse(&mut self, value: u8) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn ResponseCode(&self) -> u8 {
+        self._bitfield2 & 15
+    }
+    pub fn set_ResponseCode(&mut self, value: u8) {
+        self._bitfield2 = (self._bitfield2 & !15) | (value & 15);
+    }
+    pub const fn CheckingDisabled(&self) -> u8 {
+        (self._bitfield2 >> 4) & 1
+    }
+    pub fn set_CheckingDisabled(&mut self, value: u8) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn AuthenticatedData(&self) -> u8 {
+        (self._bitfield2 >> 5) & 1
+    }
+    pub fn set_AuthenticatedData(&mut self, value: u8) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield2 >> 6) & 1
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn RecursionAvailable(&self) -> u8 {
+        (self._bitfield2 >> 7) & 1
+    }
+    pub fn set_RecursionAvailable(&mut self, value: u8) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for DNS_HEADER {
     type TypeKind = windows_core::CopyType;
 }
@@ -1369,6 +1457,20 @@ pub struct DNS_HEADER_EXT {
     pub chRcode: u8,
     pub chVersion: u8,
 }
+impl DNS_HEADER_EXT {
+    pub const fn Reserved(&self) -> u16 {
+        self._bitfield & 32767
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !32767) | (value & 32767);
+    }
+    pub const fn DnssecOk(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_DnssecOk(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for DNS_HEADER_EXT {
     type TypeKind = windows_core::CopyType;
 }
@@ -1378,7 +1480,8 @@ impl Default for DNS_HEADER_EXT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_KEY_DATA {
     pub wFlags: u16,
     pub chProtocol: u8,
@@ -1396,7 +1499,8 @@ impl Default for DNS_KEY_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_LOC_DATA {
     pub wVersion: u16,
     pub wSize: u16,
@@ -1429,7 +1533,8 @@ impl Default for DNS_MESSAGE_BUFFER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_MINFO_DATAA {
     pub pNameMailbox: windows_core::PSTR,
     pub pNameErrorsMailbox: windows_core::PSTR,
@@ -1443,7 +1548,8 @@ impl Default for DNS_MINFO_DATAA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_MINFO_DATAW {
     pub pNameMailbox: windows_core::PWSTR,
     pub pNameErrorsMailbox: windows_core::PWSTR,
@@ -1457,7 +1563,8 @@ impl Default for DNS_MINFO_DATAW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_MX_DATAA {
     pub pNameExchange: windows_core::PSTR,
     pub wPreference: u16,
@@ -1472,7 +1579,8 @@ impl Default for DNS_MX_DATAA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_MX_DATAW {
     pub pNameExchange: windows_core::PWSTR,
     pub wPreference: u16,
@@ -1487,7 +1595,8 @@ impl Default for DNS_MX_DATAW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_NAPTR_DATAA {
     pub wOrder: u16,
     pub wPreference: u16,
@@ -1505,7 +1614,8 @@ impl Default for DNS_NAPTR_DATAA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_NAPTR_DATAW {
     pub wOrder: u16,
     pub wPreference: u16,
@@ -1523,7 +1633,8 @@ impl Default for DNS_NAPTR_DATAW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_NSEC3PARAM_DATA {
     pub chAlgorithm: u8,
     pub bFlags: u8,
@@ -1541,7 +1652,8 @@ impl Default for DNS_NSEC3PARAM_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_NSEC3_DATA {
     pub chAlgorithm: u8,
     pub bFlags: u8,
@@ -1560,7 +1672,8 @@ impl Default for DNS_NSEC3_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_NSEC_DATAA {
     pub pNextDomainName: windows_core::PSTR,
     pub wTypeBitMapsLength: u16,
@@ -1576,7 +1689,8 @@ impl Default for DNS_NSEC_DATAA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_NSEC_DATAW {
     pub pNextDomainName: windows_core::PWSTR,
     pub wTypeBitMapsLength: u16,
@@ -1592,7 +1706,8 @@ impl Default for DNS_NSEC_DATAW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_NULL_DATA {
     pub dwByteCount: u32,
     pub Data: [u8; 1],
@@ -1606,7 +1721,8 @@ impl Default for DNS_NULL_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_NXT_DATAA {
     pub pNameNext: windows_core::PSTR,
     pub wNumTypes: u16,
@@ -1621,7 +1737,8 @@ impl Default for DNS_NXT_DATAA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_NXT_DATAW {
     pub pNameNext: windows_core::PWSTR,
     pub wNumTypes: u16,
@@ -1636,7 +1753,8 @@ impl Default for DNS_NXT_DATAW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_OPT_DATA {
     pub wDataLength: u16,
     pub wPad: u16,
@@ -1651,7 +1769,8 @@ impl Default for DNS_OPT_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_PROXY_INFORMATION {
     pub version: u32,
     pub proxyInformationType: DNS_PROXY_INFORMATION_TYPE,
@@ -1666,7 +1785,8 @@ impl Default for DNS_PROXY_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_PTR_DATAA {
     pub pNameHost: windows_core::PSTR,
 }
@@ -1679,7 +1799,8 @@ impl Default for DNS_PTR_DATAA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_PTR_DATAW {
     pub pNameHost: windows_core::PWSTR,
 }
@@ -1692,7 +1813,8 @@ impl Default for DNS_PTR_DATAW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_QUERY_CANCEL {
     pub Reserved: [i8; 32],
 }
@@ -1705,7 +1827,8 @@ impl Default for DNS_QUERY_CANCEL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_QUERY_RAW_CANCEL {
     pub reserved: [i8; 32],
 }
@@ -1793,7 +1916,8 @@ impl Default for DNS_QUERY_RAW_RESULT_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_QUERY_REQUEST {
     pub Version: u32,
     pub QueryName: windows_core::PCWSTR,
@@ -1813,7 +1937,8 @@ impl Default for DNS_QUERY_REQUEST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_QUERY_REQUEST3 {
     pub Version: u32,
     pub QueryName: windows_core::PCWSTR,
@@ -1837,7 +1962,8 @@ impl Default for DNS_QUERY_REQUEST3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_QUERY_RESULT {
     pub Version: u32,
     pub QueryStatus: i32,
@@ -2118,10 +2244,43 @@ impl Default for DNS_RECORDW_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_RECORD_FLAGS {
     pub _bitfield: u32,
 }
+impl DNS_RECORD_FLAGS {
+    pub const fn Section(&self) -> u32 {
+        self._bitfield & 3
+    }
+    pub fn set_Section(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn Delete(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Delete(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn CharSet(&self) -> u32 {
+        (self._bitfield >> 3) & 3
+    }
+    pub fn set_CharSet(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 3)) | ((value & 3) << 3);
+    }
+    pub const fn Unused(&self) -> u32 {
+        (self._bitfield >> 5) & 7
+    }
+    pub fn set_Unused(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 5)) | ((value & 7) << 5);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 8) & 16777215
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(16777215 << 8)) | ((value & 16777215) << 8);
+    }
+}
 impl windows_core::TypeKind for DNS_RECORD_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
@@ -2180,7 +2339,8 @@ impl Default for DNS_RECORD_OPTW_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_RRSET {
     pub pFirstRR: *mut DNS_RECORDA,
     pub pLastRR: *mut DNS_RECORDA,
@@ -2225,7 +2385,8 @@ impl Default for DNS_SERVICE_BROWSE_REQUEST_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_SERVICE_CANCEL {
     pub reserved: *mut core::ffi::c_void,
 }
@@ -2238,7 +2399,8 @@ impl Default for DNS_SERVICE_CANCEL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_SERVICE_INSTANCE {
     pub pszInstanceName: windows_core::PWSTR,
     pub pszHostName: windows_core::PWSTR,
@@ -2261,7 +2423,8 @@ impl Default for DNS_SERVICE_INSTANCE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_SERVICE_REGISTER_REQUEST {
     pub Version: u32,
     pub InterfaceIndex: u32,
@@ -2280,7 +2443,8 @@ impl Default for DNS_SERVICE_REGISTER_REQUEST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_SERVICE_RESOLVE_REQUEST {
     pub Version: u32,
     pub InterfaceIndex: u32,
@@ -2297,7 +2461,8 @@ impl Default for DNS_SERVICE_RESOLVE_REQUEST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_SIG_DATAA {
     pub wTypeCovered: u16,
     pub chAlgorithm: u8,
@@ -2319,7 +2484,8 @@ impl Default for DNS_SIG_DATAA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_SIG_DATAW {
     pub wTypeCovered: u16,
     pub chAlgorithm: u8,
@@ -2341,7 +2507,8 @@ impl Default for DNS_SIG_DATAW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_SOA_DATAA {
     pub pNamePrimaryServer: windows_core::PSTR,
     pub pNameAdministrator: windows_core::PSTR,
@@ -2360,7 +2527,8 @@ impl Default for DNS_SOA_DATAA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_SOA_DATAW {
     pub pNamePrimaryServer: windows_core::PWSTR,
     pub pNameAdministrator: windows_core::PWSTR,
@@ -2379,7 +2547,8 @@ impl Default for DNS_SOA_DATAW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_SRV_DATAA {
     pub pNameTarget: windows_core::PSTR,
     pub wPriority: u16,
@@ -2396,7 +2565,8 @@ impl Default for DNS_SRV_DATAA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_SRV_DATAW {
     pub pNameTarget: windows_core::PWSTR,
     pub wPriority: u16,
@@ -2413,7 +2583,8 @@ impl Default for DNS_SRV_DATAW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_SVCB_DATA {
     pub wSvcPriority: u16,
     pub pszTargetName: windows_core::PSTR,
@@ -2463,7 +2634,8 @@ impl Default for DNS_SVCB_PARAM_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_SVCB_PARAM_ALPN {
     pub cIds: u16,
     pub rgIds: [DNS_SVCB_PARAM_ALPN_ID; 1],
@@ -2477,7 +2649,8 @@ impl Default for DNS_SVCB_PARAM_ALPN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_SVCB_PARAM_ALPN_ID {
     pub cBytes: u8,
     pub pbId: *mut u8,
@@ -2491,7 +2664,8 @@ impl Default for DNS_SVCB_PARAM_ALPN_ID {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_SVCB_PARAM_IPV4 {
     pub cIps: u16,
     pub rgIps: [u32; 1],
@@ -2519,7 +2693,8 @@ impl Default for DNS_SVCB_PARAM_IPV6 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_SVCB_PARAM_MANDATORY {
     pub cMandatoryKeys: u16,
     pub rgwMandatoryKeys: [u16; 1],
@@ -2533,7 +2708,8 @@ impl Default for DNS_SVCB_PARAM_MANDATORY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_SVCB_PARAM_UNKNOWN {
     pub cBytes: u16,
     pub pbSvcParamValue: [u8; 1],
@@ -2547,7 +2723,8 @@ impl Default for DNS_SVCB_PARAM_UNKNOWN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_TKEY_DATAA {
     pub pNameAlgorithm: windows_core::PSTR,
     pub pAlgorithmPacket: *mut u8,
@@ -2571,7 +2748,8 @@ impl Default for DNS_TKEY_DATAA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_TKEY_DATAW {
     pub pNameAlgorithm: windows_core::PWSTR,
     pub pAlgorithmPacket: *mut u8,
@@ -2595,7 +2773,8 @@ impl Default for DNS_TKEY_DATAW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_TLSA_DATA {
     pub bCertUsage: u8,
     pub bSelector: u8,
@@ -2613,7 +2792,8 @@ impl Default for DNS_TLSA_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_TSIG_DATAA {
     pub pNameAlgorithm: windows_core::PSTR,
     pub pAlgorithmPacket: *mut u8,
@@ -2637,7 +2817,8 @@ impl Default for DNS_TSIG_DATAA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_TSIG_DATAW {
     pub pNameAlgorithm: windows_core::PWSTR,
     pub pAlgorithmPacket: *mut u8,
@@ -2661,7 +2842,8 @@ impl Default for DNS_TSIG_DATAW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_TXT_DATAA {
     pub dwStringCount: u32,
     pub pStringArray: [windows_core::PSTR; 1],
@@ -2675,7 +2857,8 @@ impl Default for DNS_TXT_DATAA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_TXT_DATAW {
     pub dwStringCount: u32,
     pub pStringArray: [windows_core::PWSTR; 1],
@@ -2689,7 +2872,8 @@ impl Default for DNS_TXT_DATAW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_UNKNOWN_DATA {
     pub dwByteCount: u32,
     pub bData: [u8; 1],
@@ -2703,7 +2887,8 @@ impl Default for DNS_UNKNOWN_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_WINSR_DATAA {
     pub dwMappingFlag: u32,
     pub dwLookupTimeout: u32,
@@ -2719,7 +2904,8 @@ impl Default for DNS_WINSR_DATAA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_WINSR_DATAW {
     pub dwMappingFlag: u32,
     pub dwLookupTimeout: u32,
@@ -2735,7 +2921,8 @@ impl Default for DNS_WINSR_DATAW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_WINS_DATA {
     pub dwMappingFlag: u32,
     pub dwLookupTimeout: u32,
@@ -2782,7 +2969,8 @@ impl Default for DNS_WIRE_RECORD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DNS_WKS_DATA {
     pub IpAddress: u32,
     pub chProtocol: u8,
@@ -2797,7 +2985,8 @@ impl Default for DNS_WKS_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IP4_ARRAY {
     pub AddrCount: u32,
     pub AddrArray: [u32; 1],
@@ -2848,7 +3037,8 @@ impl Default for IP6_ADDRESS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MDNS_QUERY_HANDLE {
     pub nameBuf: [u16; 256],
     pub wType: u16,
@@ -2865,7 +3055,8 @@ impl Default for MDNS_QUERY_HANDLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MDNS_QUERY_REQUEST {
     pub Version: u32,
     pub ulRefCount: u32,
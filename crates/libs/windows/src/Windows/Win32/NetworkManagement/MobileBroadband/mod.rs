@@ -1966,6 +1966,7 @@ pub struct MBN_ACTIVATION_STATE(pub i32);
 impl windows_core::TypeKind for MBN_ACTIVATION_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_ACTIVATION_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_ACTIVATION_STATE").field(&self.0).finish()
@@ -1977,6 +1978,7 @@ pub struct MBN_AUTH_PROTOCOL(pub i32);
 impl windows_core::TypeKind for MBN_AUTH_PROTOCOL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_AUTH_PROTOCOL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_AUTH_PROTOCOL").field(&self.0).finish()
@@ -1988,6 +1990,7 @@ pub struct MBN_BAND_CLASS(pub i32);
 impl windows_core::TypeKind for MBN_BAND_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_BAND_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_BAND_CLASS").field(&self.0).finish()
@@ -1999,6 +2002,7 @@ pub struct MBN_CELLULAR_CLASS(pub i32);
 impl windows_core::TypeKind for MBN_CELLULAR_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_CELLULAR_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_CELLULAR_CLASS").field(&self.0).finish()
@@ -2010,6 +2014,7 @@ pub struct MBN_COMPRESSION(pub i32);
 impl windows_core::TypeKind for MBN_COMPRESSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_COMPRESSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_COMPRESSION").field(&self.0).finish()
@@ -2021,6 +2026,7 @@ pub struct MBN_CONNECTION_MODE(pub i32);
 impl windows_core::TypeKind for MBN_CONNECTION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_CONNECTION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_CONNECTION_MODE").field(&self.0).finish()
@@ -2032,6 +2038,7 @@ pub struct MBN_CONTEXT_CONSTANTS(pub i32);
 impl windows_core::TypeKind for MBN_CONTEXT_CONSTANTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_CONTEXT_CONSTANTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_CONTEXT_CONSTANTS").field(&self.0).finish()
@@ -2043,6 +2050,7 @@ pub struct MBN_CONTEXT_TYPE(pub i32);
 impl windows_core::TypeKind for MBN_CONTEXT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_CONTEXT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_CONTEXT_TYPE").field(&self.0).finish()
@@ -2054,6 +2062,7 @@ pub struct MBN_CTRL_CAPS(pub i32);
 impl windows_core::TypeKind for MBN_CTRL_CAPS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_CTRL_CAPS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_CTRL_CAPS").field(&self.0).finish()
@@ -2065,6 +2074,7 @@ pub struct MBN_DATA_CLASS(pub i32);
 impl windows_core::TypeKind for MBN_DATA_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_DATA_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_DATA_CLASS").field(&self.0).finish()
@@ -2076,6 +2086,7 @@ pub struct MBN_DEVICE_SERVICES_INTERFACE_STATE(pub i32);
 impl windows_core::TypeKind for MBN_DEVICE_SERVICES_INTERFACE_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_DEVICE_SERVICES_INTERFACE_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_DEVICE_SERVICES_INTERFACE_STATE").field(&self.0).finish()
@@ -2087,6 +2098,7 @@ pub struct MBN_DEVICE_SERVICE_SESSIONS_STATE(pub i32);
 impl windows_core::TypeKind for MBN_DEVICE_SERVICE_SESSIONS_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_DEVICE_SERVICE_SESSIONS_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_DEVICE_SERVICE_SESSIONS_STATE").field(&self.0).finish()
@@ -2098,6 +2110,7 @@ pub struct MBN_INTERFACE_CAPS_CONSTANTS(pub i32);
 impl windows_core::TypeKind for MBN_INTERFACE_CAPS_CONSTANTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_INTERFACE_CAPS_CONSTANTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_INTERFACE_CAPS_CONSTANTS").field(&self.0).finish()
@@ -2109,6 +2122,7 @@ pub struct MBN_MSG_STATUS(pub i32);
 impl windows_core::TypeKind for MBN_MSG_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_MSG_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_MSG_STATUS").field(&self.0).finish()
@@ -2120,6 +2134,7 @@ pub struct MBN_PIN_CONSTANTS(pub i32);
 impl windows_core::TypeKind for MBN_PIN_CONSTANTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_PIN_CONSTANTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_PIN_CONSTANTS").field(&self.0).finish()
@@ -2131,6 +2146,7 @@ pub struct MBN_PIN_FORMAT(pub i32);
 impl windows_core::TypeKind for MBN_PIN_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_PIN_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_PIN_FORMAT").field(&self.0).finish()
@@ -2142,6 +2158,7 @@ pub struct MBN_PIN_MODE(pub i32);
 impl windows_core::TypeKind for MBN_PIN_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_PIN_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_PIN_MODE").field(&self.0).finish()
@@ -2153,6 +2170,7 @@ pub struct MBN_PIN_STATE(pub i32);
 impl windows_core::TypeKind for MBN_PIN_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_PIN_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_PIN_STATE").field(&self.0).finish()
@@ -2164,6 +2182,7 @@ pub struct MBN_PIN_TYPE(pub i32);
 impl windows_core::TypeKind for MBN_PIN_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_PIN_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_PIN_TYPE").field(&self.0).finish()
@@ -2175,6 +2194,7 @@ pub struct MBN_PROVIDER_CONSTANTS(pub i32);
 impl windows_core::TypeKind for MBN_PROVIDER_CONSTANTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_PROVIDER_CONSTANTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_PROVIDER_CONSTANTS").field(&self.0).finish()
@@ -2186,6 +2206,7 @@ pub struct MBN_PROVIDER_STATE(pub i32);
 impl windows_core::TypeKind for MBN_PROVIDER_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_PROVIDER_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_PROVIDER_STATE").field(&self.0).finish()
@@ -2197,6 +2218,7 @@ pub struct MBN_RADIO(pub i32);
 impl windows_core::TypeKind for MBN_RADIO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_RADIO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_RADIO").field(&self.0).finish()
@@ -2208,6 +2230,7 @@ pub struct MBN_READY_STATE(pub i32);
 impl windows_core::TypeKind for MBN_READY_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_READY_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_READY_STATE").field(&self.0).finish()
@@ -2219,6 +2242,7 @@ pub struct MBN_REGISTER_MODE(pub i32);
 impl windows_core::TypeKind for MBN_REGISTER_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_REGISTER_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_REGISTER_MODE").field(&self.0).finish()
@@ -2230,6 +2254,7 @@ pub struct MBN_REGISTER_STATE(pub i32);
 impl windows_core::TypeKind for MBN_REGISTER_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_REGISTER_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_REGISTER_STATE").field(&self.0).finish()
@@ -2241,6 +2266,7 @@ pub struct MBN_REGISTRATION_CONSTANTS(pub i32);
 impl windows_core::TypeKind for MBN_REGISTRATION_CONSTANTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_REGISTRATION_CONSTANTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_REGISTRATION_CONSTANTS").field(&self.0).finish()
@@ -2252,6 +2278,7 @@ pub struct MBN_SIGNAL_CONSTANTS(pub i32);
 impl windows_core::TypeKind for MBN_SIGNAL_CONSTANTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_SIGNAL_CONSTANTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_SIGNAL_CONSTANTS").field(&self.0).finish()
@@ -2263,6 +2290,7 @@ pub struct MBN_SMS_CAPS(pub i32);
 impl windows_core::TypeKind for MBN_SMS_CAPS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_SMS_CAPS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_SMS_CAPS").field(&self.0).finish()
@@ -2274,6 +2302,7 @@ pub struct MBN_SMS_CDMA_ENCODING(pub i32);
 impl windows_core::TypeKind for MBN_SMS_CDMA_ENCODING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_SMS_CDMA_ENCODING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_SMS_CDMA_ENCODING").field(&self.0).finish()
@@ -2285,6 +2314,7 @@ pub struct MBN_SMS_CDMA_LANG(pub i32);
 impl windows_core::TypeKind for MBN_SMS_CDMA_LANG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_SMS_CDMA_LANG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_SMS_CDMA_LANG").field(&self.0).finish()
@@ -2296,6 +2326,7 @@ pub struct MBN_SMS_FLAG(pub i32);
 impl windows_core::TypeKind for MBN_SMS_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_SMS_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_SMS_FLAG").field(&self.0).finish()
@@ -2307,6 +2338,7 @@ pub struct MBN_SMS_FORMAT(pub i32);
 impl windows_core::TypeKind for MBN_SMS_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_SMS_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_SMS_FORMAT").field(&self.0).finish()
@@ -2318,6 +2350,7 @@ pub struct MBN_SMS_STATUS_FLAG(pub i32);
 impl windows_core::TypeKind for MBN_SMS_STATUS_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_SMS_STATUS_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_SMS_STATUS_FLAG").field(&self.0).finish()
@@ -2329,6 +2362,7 @@ pub struct MBN_VOICE_CALL_STATE(pub i32);
 impl windows_core::TypeKind for MBN_VOICE_CALL_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_VOICE_CALL_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_VOICE_CALL_STATE").field(&self.0).finish()
@@ -2340,6 +2374,7 @@ pub struct MBN_VOICE_CLASS(pub i32);
 impl windows_core::TypeKind for MBN_VOICE_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MBN_VOICE_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MBN_VOICE_CLASS").field(&self.0).finish()
@@ -2351,13 +2386,15 @@ pub struct WWAEXT_SMS_CONSTANTS(pub i32);
 impl windows_core::TypeKind for WWAEXT_SMS_CONSTANTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WWAEXT_SMS_CONSTANTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WWAEXT_SMS_CONSTANTS").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MBN_CONTEXT {
     pub contextID: u32,
     pub contextType: MBN_CONTEXT_TYPE,
@@ -2381,7 +2418,8 @@ impl Default for MBN_CONTEXT {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MBN_DEVICE_SERVICE {
     pub deviceServiceID: core::mem::ManuallyDrop<windows_core::BSTR>,
     pub dataWriteSupported: super::super::Foundation::VARIANT_BOOL,
@@ -2401,7 +2439,8 @@ impl Default for MBN_DEVICE_SERVICE {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MBN_INTERFACE_CAPS {
     pub cellularClass: MBN_CELLULAR_CLASS,
     pub voiceClass: MBN_VOICE_CLASS,
@@ -2431,7 +2470,8 @@ impl Default for MBN_INTERFACE_CAPS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MBN_PIN_INFO {
     pub pinState: MBN_PIN_STATE,
     pub pinType: MBN_PIN_TYPE,
@@ -2446,7 +2486,8 @@ impl Default for MBN_PIN_INFO {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MBN_PROVIDER {
     pub providerID: core::mem::ManuallyDrop<windows_core::BSTR>,
     pub providerState: u32,
@@ -2467,7 +2508,8 @@ impl Default for MBN_PROVIDER {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MBN_PROVIDER2 {
     pub provider: MBN_PROVIDER,
     pub cellularClass: MBN_CELLULAR_CLASS,
@@ -2488,7 +2530,8 @@ impl Default for MBN_PROVIDER2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MBN_SMS_FILTER {
     pub flag: MBN_SMS_FLAG,
     pub messageIndex: u32,
@@ -2502,7 +2545,8 @@ impl Default for MBN_SMS_FILTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MBN_SMS_STATUS_INFO {
     pub flag: u32,
     pub messageIndex: u32,
@@ -2520,7 +2564,8 @@ pub const MbnConnectionProfileManager: windows_core::GUID = windows_core::GUID::
 pub const MbnDeviceServicesManager: windows_core::GUID = windows_core::GUID::from_u128(0x2269daa3_2a9f_4165_a501_ce00a6f7a75b);
 pub const MbnInterfaceManager: windows_core::GUID = windows_core::GUID::from_u128(0xbdfee05b_4418_11dd_90ed_001c257ccff1);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct __DummyPinType__ {
     pub pinType: u32,
 }
@@ -2533,7 +2578,8 @@ impl Default for __DummyPinType__ {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct __mbnapi_ReferenceRemainingTypes__ {
     pub bandClass: MBN_BAND_CLASS,
     pub contextConstants: MBN_CONTEXT_CONSTANTS,
@@ -2560,3 +2606,5 @@ impl Default for __mbnapi_ReferenceRemainingTypes__ {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
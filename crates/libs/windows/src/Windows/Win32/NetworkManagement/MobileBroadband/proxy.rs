@@ -0,0 +1,1256 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDummyMBNUCMExt_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDummyMBNUCMExt as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMbnConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConnectionID(this: *mut core::ffi::c_void, connectionid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnConnection", "ConnectionID", 3usize, |this| ((**(this as *const *const IMbnConnection_Vtbl)).ConnectionID)(this, connectionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn InterfaceID(this: *mut core::ffi::c_void, interfaceid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnConnection", "InterfaceID", 4usize, |this| ((**(this as *const *const IMbnConnection_Vtbl)).InterfaceID)(this, interfaceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Connect(this: *mut core::ffi::c_void, connectionmode: MBN_CONNECTION_MODE, strprofile: windows_core::PCWSTR, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnConnection", "Connect", 5usize, |this| ((**(this as *const *const IMbnConnection_Vtbl)).Connect)(this, connectionmode, strprofile, requestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnect(this: *mut core::ffi::c_void, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnConnection", "Disconnect", 6usize, |this| ((**(this as *const *const IMbnConnection_Vtbl)).Disconnect)(this, requestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConnectionState(this: *mut core::ffi::c_void, connectionstate: *mut MBN_ACTIVATION_STATE, profilename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnConnection", "GetConnectionState", 7usize, |this| ((**(this as *const *const IMbnConnection_Vtbl)).GetConnectionState)(this, connectionstate, profilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVoiceCallState(this: *mut core::ffi::c_void, voicecallstate: *mut MBN_VOICE_CALL_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnConnection", "GetVoiceCallState", 8usize, |this| ((**(this as *const *const IMbnConnection_Vtbl)).GetVoiceCallState)(this, voicecallstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActivationNetworkError(this: *mut core::ffi::c_void, networkerror: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnConnection", "GetActivationNetworkError", 9usize, |this| ((**(this as *const *const IMbnConnection_Vtbl)).GetActivationNetworkError)(this, networkerror), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ConnectionID,
+            InterfaceID,
+            Connect,
+            Disconnect,
+            GetConnectionState,
+            GetVoiceCallState,
+            GetActivationNetworkError,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnConnection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMbnConnectionContext_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetProvisionedContexts(this: *mut core::ffi::c_void, provisionedcontexts: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnConnectionContext", "GetProvisionedContexts", 3usize, |this| ((**(this as *const *const IMbnConnectionContext_Vtbl)).GetProvisionedContexts)(this, provisionedcontexts), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProvisionedContext(this: *mut core::ffi::c_void, provisionedcontexts: MBN_CONTEXT, providerid: windows_core::PCWSTR, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnConnectionContext", "SetProvisionedContext", 4usize, |this| ((**(this as *const *const IMbnConnectionContext_Vtbl)).SetProvisionedContext)(this, provisionedcontexts, providerid, requestid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetProvisionedContexts,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetProvisionedContexts: 0,
+            SetProvisionedContext,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnConnectionContext as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMbnConnectionContextEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnProvisionedContextListChange(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnConnectionContextEvents", "OnProvisionedContextListChange", 3usize, |this| ((**(this as *const *const IMbnConnectionContextEvents_Vtbl)).OnProvisionedContextListChange)(this, newinterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnSetProvisionedContextComplete(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnConnectionContextEvents", "OnSetProvisionedContextComplete", 4usize, |this| ((**(this as *const *const IMbnConnectionContextEvents_Vtbl)).OnSetProvisionedContextComplete)(this, newinterface, requestid, status), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnProvisionedContextListChange, OnSetProvisionedContextComplete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnConnectionContextEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMbnConnectionEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnConnectComplete(this: *mut core::ffi::c_void, newconnection: *mut core::ffi::c_void, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnConnectionEvents", "OnConnectComplete", 3usize, |this| ((**(this as *const *const IMbnConnectionEvents_Vtbl)).OnConnectComplete)(this, newconnection, requestid, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDisconnectComplete(this: *mut core::ffi::c_void, newconnection: *mut core::ffi::c_void, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnConnectionEvents", "OnDisconnectComplete", 4usize, |this| ((**(this as *const *const IMbnConnectionEvents_Vtbl)).OnDisconnectComplete)(this, newconnection, requestid, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnConnectStateChange(this: *mut core::ffi::c_void, newconnection: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnConnectionEvents", "OnConnectStateChange", 5usize, |this| ((**(this as *const *const IMbnConnectionEvents_Vtbl)).OnConnectStateChange)(this, newconnection), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnVoiceCallStateChange(this: *mut core::ffi::c_void, newconnection: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnConnectionEvents", "OnVoiceCallStateChange", 6usize, |this| ((**(this as *const *const IMbnConnectionEvents_Vtbl)).OnVoiceCallStateChange)(this, newconnection), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnConnectComplete,
+            OnDisconnectComplete,
+            OnConnectStateChange,
+            OnVoiceCallStateChange,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnConnectionEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMbnConnectionManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetConnection(this: *mut core::ffi::c_void, connectionid: windows_core::PCWSTR, mbnconnection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnConnectionManager", "GetConnection", 3usize, |this| ((**(this as *const *const IMbnConnectionManager_Vtbl)).GetConnection)(this, connectionid, mbnconnection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetConnections(this: *mut core::ffi::c_void, mbnconnections: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnConnectionManager", "GetConnections", 4usize, |this| ((**(this as *const *const IMbnConnectionManager_Vtbl)).GetConnections)(this, mbnconnections), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetConnection,
+            #[cfg(feature = "Win32_System_Com")]
+            GetConnections,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetConnections: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnConnectionManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMbnConnectionManagerEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnConnectionArrival(this: *mut core::ffi::c_void, newconnection: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnConnectionManagerEvents", "OnConnectionArrival", 3usize, |this| ((**(this as *const *const IMbnConnectionManagerEvents_Vtbl)).OnConnectionArrival)(this, newconnection), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnConnectionRemoval(this: *mut core::ffi::c_void, oldconnection: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnConnectionManagerEvents", "OnConnectionRemoval", 4usize, |this| ((**(this as *const *const IMbnConnectionManagerEvents_Vtbl)).OnConnectionRemoval)(this, oldconnection), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnConnectionArrival, OnConnectionRemoval }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnConnectionManagerEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMbnConnectionProfile_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProfileXmlData(this: *mut core::ffi::c_void, profiledata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnConnectionProfile", "GetProfileXmlData", 3usize, |this| ((**(this as *const *const IMbnConnectionProfile_Vtbl)).GetProfileXmlData)(this, profiledata), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateProfile(this: *mut core::ffi::c_void, strprofile: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnConnectionProfile", "UpdateProfile", 4usize, |this| ((**(this as *const *const IMbnConnectionProfile_Vtbl)).UpdateProfile)(this, strprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnConnectionProfile", "Delete", 5usize, |this| ((**(this as *const *const IMbnConnectionProfile_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProfileXmlData, UpdateProfile, Delete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnConnectionProfile as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMbnConnectionProfileEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnProfileUpdate(this: *mut core::ffi::c_void, newprofile: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnConnectionProfileEvents", "OnProfileUpdate", 3usize, |this| ((**(this as *const *const IMbnConnectionProfileEvents_Vtbl)).OnProfileUpdate)(this, newprofile), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnProfileUpdate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnConnectionProfileEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMbnConnectionProfileManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetConnectionProfiles(this: *mut core::ffi::c_void, mbninterface: *mut core::ffi::c_void, connectionprofiles: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnConnectionProfileManager", "GetConnectionProfiles", 3usize, |this| ((**(this as *const *const IMbnConnectionProfileManager_Vtbl)).GetConnectionProfiles)(this, mbninterface, connectionprofiles), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConnectionProfile(this: *mut core::ffi::c_void, mbninterface: *mut core::ffi::c_void, profilename: windows_core::PCWSTR, connectionprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnConnectionProfileManager", "GetConnectionProfile", 4usize, |this| ((**(this as *const *const IMbnConnectionProfileManager_Vtbl)).GetConnectionProfile)(this, mbninterface, profilename, connectionprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateConnectionProfile(this: *mut core::ffi::c_void, xmlprofile: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnConnectionProfileManager", "CreateConnectionProfile", 5usize, |this| ((**(this as *const *const IMbnConnectionProfileManager_Vtbl)).CreateConnectionProfile)(this, xmlprofile), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetConnectionProfiles,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetConnectionProfiles: 0,
+            GetConnectionProfile,
+            CreateConnectionProfile,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnConnectionProfileManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMbnConnectionProfileManagerEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnConnectionProfileArrival(this: *mut core::ffi::c_void, newconnectionprofile: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnConnectionProfileManagerEvents", "OnConnectionProfileArrival", 3usize, |this| ((**(this as *const *const IMbnConnectionProfileManagerEvents_Vtbl)).OnConnectionProfileArrival)(this, newconnectionprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnConnectionProfileRemoval(this: *mut core::ffi::c_void, oldconnectionprofile: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnConnectionProfileManagerEvents", "OnConnectionProfileRemoval", 4usize, |this| ((**(this as *const *const IMbnConnectionProfileManagerEvents_Vtbl)).OnConnectionProfileRemoval)(this, oldconnectionprofile), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnConnectionProfileArrival, OnConnectionProfileRemoval }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnConnectionProfileManagerEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMbnDeviceService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QuerySupportedCommands(this: *mut core::ffi::c_void, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceService", "QuerySupportedCommands", 3usize, |this| ((**(this as *const *const IMbnDeviceService_Vtbl)).QuerySupportedCommands)(this, requestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenCommandSession(this: *mut core::ffi::c_void, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceService", "OpenCommandSession", 4usize, |this| ((**(this as *const *const IMbnDeviceService_Vtbl)).OpenCommandSession)(this, requestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseCommandSession(this: *mut core::ffi::c_void, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceService", "CloseCommandSession", 5usize, |this| ((**(this as *const *const IMbnDeviceService_Vtbl)).CloseCommandSession)(this, requestid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetCommand(this: *mut core::ffi::c_void, commandid: u32, deviceservicedata: *const super::super::System::Com::SAFEARRAY, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceService", "SetCommand", 6usize, |this| ((**(this as *const *const IMbnDeviceService_Vtbl)).SetCommand)(this, commandid, deviceservicedata, requestid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn QueryCommand(this: *mut core::ffi::c_void, commandid: u32, deviceservicedata: *const super::super::System::Com::SAFEARRAY, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceService", "QueryCommand", 7usize, |this| ((**(this as *const *const IMbnDeviceService_Vtbl)).QueryCommand)(this, commandid, deviceservicedata, requestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenDataSession(this: *mut core::ffi::c_void, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceService", "OpenDataSession", 8usize, |this| ((**(this as *const *const IMbnDeviceService_Vtbl)).OpenDataSession)(this, requestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseDataSession(this: *mut core::ffi::c_void, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceService", "CloseDataSession", 9usize, |this| ((**(this as *const *const IMbnDeviceService_Vtbl)).CloseDataSession)(this, requestid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn WriteData(this: *mut core::ffi::c_void, deviceservicedata: *const super::super::System::Com::SAFEARRAY, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceService", "WriteData", 10usize, |this| ((**(this as *const *const IMbnDeviceService_Vtbl)).WriteData)(this, deviceservicedata, requestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn InterfaceID(this: *mut core::ffi::c_void, interfaceid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceService", "InterfaceID", 11usize, |this| ((**(this as *const *const IMbnDeviceService_Vtbl)).InterfaceID)(this, interfaceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceServiceID(this: *mut core::ffi::c_void, deviceserviceid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceService", "DeviceServiceID", 12usize, |this| ((**(this as *const *const IMbnDeviceService_Vtbl)).DeviceServiceID)(this, deviceserviceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCommandSessionOpen(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceService", "IsCommandSessionOpen", 13usize, |this| ((**(this as *const *const IMbnDeviceService_Vtbl)).IsCommandSessionOpen)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDataSessionOpen(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceService", "IsDataSessionOpen", 14usize, |this| ((**(this as *const *const IMbnDeviceService_Vtbl)).IsDataSessionOpen)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            QuerySupportedCommands,
+            OpenCommandSession,
+            CloseCommandSession,
+            #[cfg(feature = "Win32_System_Com")]
+            SetCommand,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetCommand: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            QueryCommand,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            QueryCommand: 0,
+            OpenDataSession,
+            CloseDataSession,
+            #[cfg(feature = "Win32_System_Com")]
+            WriteData,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            WriteData: 0,
+            InterfaceID,
+            DeviceServiceID,
+            IsCommandSessionOpen,
+            IsDataSessionOpen,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnDeviceService as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMbnDeviceServiceStateEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnSessionsStateChange(this: *mut core::ffi::c_void, interfaceid: core::mem::MaybeUninit<windows_core::BSTR>, statechange: MBN_DEVICE_SERVICE_SESSIONS_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceServiceStateEvents", "OnSessionsStateChange", 3usize, |this| ((**(this as *const *const IMbnDeviceServiceStateEvents_Vtbl)).OnSessionsStateChange)(this, interfaceid, statechange), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnSessionsStateChange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnDeviceServiceStateEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMbnDeviceServicesContext_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumerateDeviceServices(this: *mut core::ffi::c_void, deviceservices: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceServicesContext", "EnumerateDeviceServices", 3usize, |this| ((**(this as *const *const IMbnDeviceServicesContext_Vtbl)).EnumerateDeviceServices)(this, deviceservices), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceService(this: *mut core::ffi::c_void, deviceserviceid: core::mem::MaybeUninit<windows_core::BSTR>, mbndeviceservice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceServicesContext", "GetDeviceService", 4usize, |this| ((**(this as *const *const IMbnDeviceServicesContext_Vtbl)).GetDeviceService)(this, deviceserviceid, mbndeviceservice), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxCommandSize(this: *mut core::ffi::c_void, maxcommandsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceServicesContext", "MaxCommandSize", 5usize, |this| ((**(this as *const *const IMbnDeviceServicesContext_Vtbl)).MaxCommandSize)(this, maxcommandsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxDataSize(this: *mut core::ffi::c_void, maxdatasize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceServicesContext", "MaxDataSize", 6usize, |this| ((**(this as *const *const IMbnDeviceServicesContext_Vtbl)).MaxDataSize)(this, maxdatasize), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumerateDeviceServices,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumerateDeviceServices: 0,
+            GetDeviceService,
+            MaxCommandSize,
+            MaxDataSize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnDeviceServicesContext as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMbnDeviceServicesEvents_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnQuerySupportedCommandsComplete(this: *mut core::ffi::c_void, deviceservice: *mut core::ffi::c_void, commandidlist: *const super::super::System::Com::SAFEARRAY, status: windows_core::HRESULT, requestid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceServicesEvents", "OnQuerySupportedCommandsComplete", 3usize, |this| ((**(this as *const *const IMbnDeviceServicesEvents_Vtbl)).OnQuerySupportedCommandsComplete)(this, deviceservice, commandidlist, status, requestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnOpenCommandSessionComplete(this: *mut core::ffi::c_void, deviceservice: *mut core::ffi::c_void, status: windows_core::HRESULT, requestid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceServicesEvents", "OnOpenCommandSessionComplete", 4usize, |this| ((**(this as *const *const IMbnDeviceServicesEvents_Vtbl)).OnOpenCommandSessionComplete)(this, deviceservice, status, requestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnCloseCommandSessionComplete(this: *mut core::ffi::c_void, deviceservice: *mut core::ffi::c_void, status: windows_core::HRESULT, requestid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceServicesEvents", "OnCloseCommandSessionComplete", 5usize, |this| ((**(this as *const *const IMbnDeviceServicesEvents_Vtbl)).OnCloseCommandSessionComplete)(this, deviceservice, status, requestid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnSetCommandComplete(this: *mut core::ffi::c_void, deviceservice: *mut core::ffi::c_void, responseid: u32, deviceservicedata: *const super::super::System::Com::SAFEARRAY, status: windows_core::HRESULT, requestid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceServicesEvents", "OnSetCommandComplete", 6usize, |this| ((**(this as *const *const IMbnDeviceServicesEvents_Vtbl)).OnSetCommandComplete)(this, deviceservice, responseid, deviceservicedata, status, requestid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnQueryCommandComplete(this: *mut core::ffi::c_void, deviceservice: *mut core::ffi::c_void, responseid: u32, deviceservicedata: *const super::super::System::Com::SAFEARRAY, status: windows_core::HRESULT, requestid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceServicesEvents", "OnQueryCommandComplete", 7usize, |this| ((**(this as *const *const IMbnDeviceServicesEvents_Vtbl)).OnQueryCommandComplete)(this, deviceservice, responseid, deviceservicedata, status, requestid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnEventNotification(this: *mut core::ffi::c_void, deviceservice: *mut core::ffi::c_void, eventid: u32, deviceservicedata: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceServicesEvents", "OnEventNotification", 8usize, |this| ((**(this as *const *const IMbnDeviceServicesEvents_Vtbl)).OnEventNotification)(this, deviceservice, eventid, deviceservicedata), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnOpenDataSessionComplete(this: *mut core::ffi::c_void, deviceservice: *mut core::ffi::c_void, status: windows_core::HRESULT, requestid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceServicesEvents", "OnOpenDataSessionComplete", 9usize, |this| ((**(this as *const *const IMbnDeviceServicesEvents_Vtbl)).OnOpenDataSessionComplete)(this, deviceservice, status, requestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnCloseDataSessionComplete(this: *mut core::ffi::c_void, deviceservice: *mut core::ffi::c_void, status: windows_core::HRESULT, requestid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceServicesEvents", "OnCloseDataSessionComplete", 10usize, |this| ((**(this as *const *const IMbnDeviceServicesEvents_Vtbl)).OnCloseDataSessionComplete)(this, deviceservice, status, requestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnWriteDataComplete(this: *mut core::ffi::c_void, deviceservice: *mut core::ffi::c_void, status: windows_core::HRESULT, requestid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceServicesEvents", "OnWriteDataComplete", 11usize, |this| ((**(this as *const *const IMbnDeviceServicesEvents_Vtbl)).OnWriteDataComplete)(this, deviceservice, status, requestid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnReadData(this: *mut core::ffi::c_void, deviceservice: *mut core::ffi::c_void, deviceservicedata: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceServicesEvents", "OnReadData", 12usize, |this| ((**(this as *const *const IMbnDeviceServicesEvents_Vtbl)).OnReadData)(this, deviceservice, deviceservicedata), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnInterfaceStateChange(this: *mut core::ffi::c_void, interfaceid: core::mem::MaybeUninit<windows_core::BSTR>, statechange: MBN_DEVICE_SERVICES_INTERFACE_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceServicesEvents", "OnInterfaceStateChange", 13usize, |this| ((**(this as *const *const IMbnDeviceServicesEvents_Vtbl)).OnInterfaceStateChange)(this, interfaceid, statechange), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            OnQuerySupportedCommandsComplete,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnQuerySupportedCommandsComplete: 0,
+            OnOpenCommandSessionComplete,
+            OnCloseCommandSessionComplete,
+            #[cfg(feature = "Win32_System_Com")]
+            OnSetCommandComplete,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnSetCommandComplete: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnQueryCommandComplete,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnQueryCommandComplete: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnEventNotification,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnEventNotification: 0,
+            OnOpenDataSessionComplete,
+            OnCloseDataSessionComplete,
+            OnWriteDataComplete,
+            #[cfg(feature = "Win32_System_Com")]
+            OnReadData,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnReadData: 0,
+            OnInterfaceStateChange,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnDeviceServicesEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMbnDeviceServicesManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceServicesContext(this: *mut core::ffi::c_void, networkinterfaceid: core::mem::MaybeUninit<windows_core::BSTR>, mbndevicescontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnDeviceServicesManager", "GetDeviceServicesContext", 3usize, |this| ((**(this as *const *const IMbnDeviceServicesManager_Vtbl)).GetDeviceServicesContext)(this, networkinterfaceid, mbndevicescontext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceServicesContext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnDeviceServicesManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMbnInterface_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InterfaceID(this: *mut core::ffi::c_void, interfaceid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnInterface", "InterfaceID", 3usize, |this| ((**(this as *const *const IMbnInterface_Vtbl)).InterfaceID)(this, interfaceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInterfaceCapability(this: *mut core::ffi::c_void, interfacecaps: *mut MBN_INTERFACE_CAPS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnInterface", "GetInterfaceCapability", 4usize, |this| ((**(this as *const *const IMbnInterface_Vtbl)).GetInterfaceCapability)(this, interfacecaps), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubscriberInformation(this: *mut core::ffi::c_void, subscriberinformation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnInterface", "GetSubscriberInformation", 5usize, |this| ((**(this as *const *const IMbnInterface_Vtbl)).GetSubscriberInformation)(this, subscriberinformation), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReadyState(this: *mut core::ffi::c_void, readystate: *mut MBN_READY_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnInterface", "GetReadyState", 6usize, |this| ((**(this as *const *const IMbnInterface_Vtbl)).GetReadyState)(this, readystate), |result| Some(*result))
+        }
+        unsafe extern "system" fn InEmergencyMode(this: *mut core::ffi::c_void, emergencymode: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnInterface", "InEmergencyMode", 7usize, |this| ((**(this as *const *const IMbnInterface_Vtbl)).InEmergencyMode)(this, emergencymode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHomeProvider(this: *mut core::ffi::c_void, homeprovider: *mut MBN_PROVIDER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnInterface", "GetHomeProvider", 8usize, |this| ((**(this as *const *const IMbnInterface_Vtbl)).GetHomeProvider)(this, homeprovider), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetPreferredProviders(this: *mut core::ffi::c_void, preferredproviders: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnInterface", "GetPreferredProviders", 9usize, |this| ((**(this as *const *const IMbnInterface_Vtbl)).GetPreferredProviders)(this, preferredproviders), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetPreferredProviders(this: *mut core::ffi::c_void, preferredproviders: *const super::super::System::Com::SAFEARRAY, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnInterface", "SetPreferredProviders", 10usize, |this| ((**(this as *const *const IMbnInterface_Vtbl)).SetPreferredProviders)(this, preferredproviders, requestid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetVisibleProviders(this: *mut core::ffi::c_void, age: *mut u32, visibleproviders: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnInterface", "GetVisibleProviders", 11usize, |this| ((**(this as *const *const IMbnInterface_Vtbl)).GetVisibleProviders)(this, age, visibleproviders), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScanNetwork(this: *mut core::ffi::c_void, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnInterface", "ScanNetwork", 12usize, |this| ((**(this as *const *const IMbnInterface_Vtbl)).ScanNetwork)(this, requestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConnection(this: *mut core::ffi::c_void, mbnconnection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnInterface", "GetConnection", 13usize, |this| ((**(this as *const *const IMbnInterface_Vtbl)).GetConnection)(this, mbnconnection), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InterfaceID,
+            GetInterfaceCapability,
+            GetSubscriberInformation,
+            GetReadyState,
+            InEmergencyMode,
+            GetHomeProvider,
+            #[cfg(feature = "Win32_System_Com")]
+            GetPreferredProviders,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetPreferredProviders: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetPreferredProviders,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetPreferredProviders: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetVisibleProviders,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetVisibleProviders: 0,
+            ScanNetwork,
+            GetConnection,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnInterface as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMbnInterfaceEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnInterfaceCapabilityAvailable(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnInterfaceEvents", "OnInterfaceCapabilityAvailable", 3usize, |this| ((**(this as *const *const IMbnInterfaceEvents_Vtbl)).OnInterfaceCapabilityAvailable)(this, newinterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnSubscriberInformationChange(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnInterfaceEvents", "OnSubscriberInformationChange", 4usize, |this| ((**(this as *const *const IMbnInterfaceEvents_Vtbl)).OnSubscriberInformationChange)(this, newinterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnReadyStateChange(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnInterfaceEvents", "OnReadyStateChange", 5usize, |this| ((**(this as *const *const IMbnInterfaceEvents_Vtbl)).OnReadyStateChange)(this, newinterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnEmergencyModeChange(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnInterfaceEvents", "OnEmergencyModeChange", 6usize, |this| ((**(this as *const *const IMbnInterfaceEvents_Vtbl)).OnEmergencyModeChange)(this, newinterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnHomeProviderAvailable(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnInterfaceEvents", "OnHomeProviderAvailable", 7usize, |this| ((**(this as *const *const IMbnInterfaceEvents_Vtbl)).OnHomeProviderAvailable)(this, newinterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnPreferredProvidersChange(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnInterfaceEvents", "OnPreferredProvidersChange", 8usize, |this| ((**(this as *const *const IMbnInterfaceEvents_Vtbl)).OnPreferredProvidersChange)(this, newinterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnSetPreferredProvidersComplete(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnInterfaceEvents", "OnSetPreferredProvidersComplete", 9usize, |this| ((**(this as *const *const IMbnInterfaceEvents_Vtbl)).OnSetPreferredProvidersComplete)(this, newinterface, requestid, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnScanNetworkComplete(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnInterfaceEvents", "OnScanNetworkComplete", 10usize, |this| ((**(this as *const *const IMbnInterfaceEvents_Vtbl)).OnScanNetworkComplete)(this, newinterface, requestid, status), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnInterfaceCapabilityAvailable,
+            OnSubscriberInformationChange,
+            OnReadyStateChange,
+            OnEmergencyModeChange,
+            OnHomeProviderAvailable,
+            OnPreferredProvidersChange,
+            OnSetPreferredProvidersComplete,
+            OnScanNetworkComplete,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnInterfaceEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMbnInterfaceManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInterface(this: *mut core::ffi::c_void, interfaceid: windows_core::PCWSTR, mbninterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnInterfaceManager", "GetInterface", 3usize, |this| ((**(this as *const *const IMbnInterfaceManager_Vtbl)).GetInterface)(this, interfaceid, mbninterface), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetInterfaces(this: *mut core::ffi::c_void, mbninterfaces: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnInterfaceManager", "GetInterfaces", 4usize, |this| ((**(this as *const *const IMbnInterfaceManager_Vtbl)).GetInterfaces)(this, mbninterfaces), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetInterface,
+            #[cfg(feature = "Win32_System_Com")]
+            GetInterfaces,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetInterfaces: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnInterfaceManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMbnInterfaceManagerEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnInterfaceArrival(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnInterfaceManagerEvents", "OnInterfaceArrival", 3usize, |this| ((**(this as *const *const IMbnInterfaceManagerEvents_Vtbl)).OnInterfaceArrival)(this, newinterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnInterfaceRemoval(this: *mut core::ffi::c_void, oldinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnInterfaceManagerEvents", "OnInterfaceRemoval", 4usize, |this| ((**(this as *const *const IMbnInterfaceManagerEvents_Vtbl)).OnInterfaceRemoval)(this, oldinterface), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnInterfaceArrival, OnInterfaceRemoval }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnInterfaceManagerEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMbnMultiCarrier_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetHomeProvider(this: *mut core::ffi::c_void, homeprovider: *const MBN_PROVIDER2, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnMultiCarrier", "SetHomeProvider", 3usize, |this| ((**(this as *const *const IMbnMultiCarrier_Vtbl)).SetHomeProvider)(this, homeprovider, requestid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetPreferredProviders(this: *mut core::ffi::c_void, preferredmulticarrierproviders: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnMultiCarrier", "GetPreferredProviders", 4usize, |this| ((**(this as *const *const IMbnMultiCarrier_Vtbl)).GetPreferredProviders)(this, preferredmulticarrierproviders), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetVisibleProviders(this: *mut core::ffi::c_void, age: *mut u32, visibleproviders: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnMultiCarrier", "GetVisibleProviders", 5usize, |this| ((**(this as *const *const IMbnMultiCarrier_Vtbl)).GetVisibleProviders)(this, age, visibleproviders), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSupportedCellularClasses(this: *mut core::ffi::c_void, cellularclasses: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnMultiCarrier", "GetSupportedCellularClasses", 6usize, |this| ((**(this as *const *const IMbnMultiCarrier_Vtbl)).GetSupportedCellularClasses)(this, cellularclasses), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentCellularClass(this: *mut core::ffi::c_void, currentcellularclass: *mut MBN_CELLULAR_CLASS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnMultiCarrier", "GetCurrentCellularClass", 7usize, |this| ((**(this as *const *const IMbnMultiCarrier_Vtbl)).GetCurrentCellularClass)(this, currentcellularclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScanNetwork(this: *mut core::ffi::c_void, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnMultiCarrier", "ScanNetwork", 8usize, |this| ((**(this as *const *const IMbnMultiCarrier_Vtbl)).ScanNetwork)(this, requestid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetHomeProvider,
+            #[cfg(feature = "Win32_System_Com")]
+            GetPreferredProviders,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetPreferredProviders: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetVisibleProviders,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetVisibleProviders: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSupportedCellularClasses,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSupportedCellularClasses: 0,
+            GetCurrentCellularClass,
+            ScanNetwork,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnMultiCarrier as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMbnMultiCarrierEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnSetHomeProviderComplete(this: *mut core::ffi::c_void, mbninterface: *mut core::ffi::c_void, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnMultiCarrierEvents", "OnSetHomeProviderComplete", 3usize, |this| ((**(this as *const *const IMbnMultiCarrierEvents_Vtbl)).OnSetHomeProviderComplete)(this, mbninterface, requestid, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnCurrentCellularClassChange(this: *mut core::ffi::c_void, mbninterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnMultiCarrierEvents", "OnCurrentCellularClassChange", 4usize, |this| ((**(this as *const *const IMbnMultiCarrierEvents_Vtbl)).OnCurrentCellularClassChange)(this, mbninterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnPreferredProvidersChange(this: *mut core::ffi::c_void, mbninterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnMultiCarrierEvents", "OnPreferredProvidersChange", 5usize, |this| ((**(this as *const *const IMbnMultiCarrierEvents_Vtbl)).OnPreferredProvidersChange)(this, mbninterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnScanNetworkComplete(this: *mut core::ffi::c_void, mbninterface: *mut core::ffi::c_void, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnMultiCarrierEvents", "OnScanNetworkComplete", 6usize, |this| ((**(this as *const *const IMbnMultiCarrierEvents_Vtbl)).OnScanNetworkComplete)(this, mbninterface, requestid, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnInterfaceCapabilityChange(this: *mut core::ffi::c_void, mbninterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnMultiCarrierEvents", "OnInterfaceCapabilityChange", 7usize, |this| ((**(this as *const *const IMbnMultiCarrierEvents_Vtbl)).OnInterfaceCapabilityChange)(this, mbninterface), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnSetHomeProviderComplete,
+            OnCurrentCellularClassChange,
+            OnPreferredProvidersChange,
+            OnScanNetworkComplete,
+            OnInterfaceCapabilityChange,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnMultiCarrierEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMbnPin_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PinType(this: *mut core::ffi::c_void, pintype: *mut MBN_PIN_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnPin", "PinType", 3usize, |this| ((**(this as *const *const IMbnPin_Vtbl)).PinType)(this, pintype), |result| Some(*result))
+        }
+        unsafe extern "system" fn PinFormat(this: *mut core::ffi::c_void, pinformat: *mut MBN_PIN_FORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnPin", "PinFormat", 4usize, |this| ((**(this as *const *const IMbnPin_Vtbl)).PinFormat)(this, pinformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn PinLengthMin(this: *mut core::ffi::c_void, pinlengthmin: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnPin", "PinLengthMin", 5usize, |this| ((**(this as *const *const IMbnPin_Vtbl)).PinLengthMin)(this, pinlengthmin), |result| Some(*result))
+        }
+        unsafe extern "system" fn PinLengthMax(this: *mut core::ffi::c_void, pinlengthmax: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnPin", "PinLengthMax", 6usize, |this| ((**(this as *const *const IMbnPin_Vtbl)).PinLengthMax)(this, pinlengthmax), |result| Some(*result))
+        }
+        unsafe extern "system" fn PinMode(this: *mut core::ffi::c_void, pinmode: *mut MBN_PIN_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnPin", "PinMode", 7usize, |this| ((**(this as *const *const IMbnPin_Vtbl)).PinMode)(this, pinmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enable(this: *mut core::ffi::c_void, pin: windows_core::PCWSTR, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnPin", "Enable", 8usize, |this| ((**(this as *const *const IMbnPin_Vtbl)).Enable)(this, pin, requestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disable(this: *mut core::ffi::c_void, pin: windows_core::PCWSTR, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnPin", "Disable", 9usize, |this| ((**(this as *const *const IMbnPin_Vtbl)).Disable)(this, pin, requestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enter(this: *mut core::ffi::c_void, pin: windows_core::PCWSTR, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnPin", "Enter", 10usize, |this| ((**(this as *const *const IMbnPin_Vtbl)).Enter)(this, pin, requestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Change(this: *mut core::ffi::c_void, pin: windows_core::PCWSTR, newpin: windows_core::PCWSTR, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnPin", "Change", 11usize, |this| ((**(this as *const *const IMbnPin_Vtbl)).Change)(this, pin, newpin, requestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unblock(this: *mut core::ffi::c_void, puk: windows_core::PCWSTR, newpin: windows_core::PCWSTR, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnPin", "Unblock", 12usize, |this| ((**(this as *const *const IMbnPin_Vtbl)).Unblock)(this, puk, newpin, requestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPinManager(this: *mut core::ffi::c_void, pinmanager: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnPin", "GetPinManager", 13usize, |this| ((**(this as *const *const IMbnPin_Vtbl)).GetPinManager)(this, pinmanager), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PinType,
+            PinFormat,
+            PinLengthMin,
+            PinLengthMax,
+            PinMode,
+            Enable,
+            Disable,
+            Enter,
+            Change,
+            Unblock,
+            GetPinManager,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnPin as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMbnPinEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnEnableComplete(this: *mut core::ffi::c_void, pin: *mut core::ffi::c_void, pininfo: *const MBN_PIN_INFO, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnPinEvents", "OnEnableComplete", 3usize, |this| ((**(this as *const *const IMbnPinEvents_Vtbl)).OnEnableComplete)(this, pin, pininfo, requestid, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDisableComplete(this: *mut core::ffi::c_void, pin: *mut core::ffi::c_void, pininfo: *const MBN_PIN_INFO, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnPinEvents", "OnDisableComplete", 4usize, |this| ((**(this as *const *const IMbnPinEvents_Vtbl)).OnDisableComplete)(this, pin, pininfo, requestid, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnEnterComplete(this: *mut core::ffi::c_void, pin: *mut core::ffi::c_void, pininfo: *const MBN_PIN_INFO, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnPinEvents", "OnEnterComplete", 5usize, |this| ((**(this as *const *const IMbnPinEvents_Vtbl)).OnEnterComplete)(this, pin, pininfo, requestid, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnChangeComplete(this: *mut core::ffi::c_void, pin: *mut core::ffi::c_void, pininfo: *const MBN_PIN_INFO, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnPinEvents", "OnChangeComplete", 6usize, |this| ((**(this as *const *const IMbnPinEvents_Vtbl)).OnChangeComplete)(this, pin, pininfo, requestid, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnUnblockComplete(this: *mut core::ffi::c_void, pin: *mut core::ffi::c_void, pininfo: *const MBN_PIN_INFO, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnPinEvents", "OnUnblockComplete", 7usize, |this| ((**(this as *const *const IMbnPinEvents_Vtbl)).OnUnblockComplete)(this, pin, pininfo, requestid, status), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnEnableComplete,
+            OnDisableComplete,
+            OnEnterComplete,
+            OnChangeComplete,
+            OnUnblockComplete,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnPinEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMbnPinManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetPinList(this: *mut core::ffi::c_void, pinlist: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnPinManager", "GetPinList", 3usize, |this| ((**(this as *const *const IMbnPinManager_Vtbl)).GetPinList)(this, pinlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPin(this: *mut core::ffi::c_void, pintype: MBN_PIN_TYPE, pin: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnPinManager", "GetPin", 4usize, |this| ((**(this as *const *const IMbnPinManager_Vtbl)).GetPin)(this, pintype, pin), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPinState(this: *mut core::ffi::c_void, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnPinManager", "GetPinState", 5usize, |this| ((**(this as *const *const IMbnPinManager_Vtbl)).GetPinState)(this, requestid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetPinList,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetPinList: 0,
+            GetPin,
+            GetPinState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnPinManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMbnPinManagerEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnPinListAvailable(this: *mut core::ffi::c_void, pinmanager: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnPinManagerEvents", "OnPinListAvailable", 3usize, |this| ((**(this as *const *const IMbnPinManagerEvents_Vtbl)).OnPinListAvailable)(this, pinmanager), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnGetPinStateComplete(this: *mut core::ffi::c_void, pinmanager: *mut core::ffi::c_void, pininfo: MBN_PIN_INFO, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnPinManagerEvents", "OnGetPinStateComplete", 4usize, |this| ((**(this as *const *const IMbnPinManagerEvents_Vtbl)).OnGetPinStateComplete)(this, pinmanager, pininfo, requestid, status), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnPinListAvailable, OnGetPinStateComplete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnPinManagerEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMbnRadio_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SoftwareRadioState(this: *mut core::ffi::c_void, softwareradiostate: *mut MBN_RADIO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnRadio", "SoftwareRadioState", 3usize, |this| ((**(this as *const *const IMbnRadio_Vtbl)).SoftwareRadioState)(this, softwareradiostate), |result| Some(*result))
+        }
+        unsafe extern "system" fn HardwareRadioState(this: *mut core::ffi::c_void, hardwareradiostate: *mut MBN_RADIO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnRadio", "HardwareRadioState", 4usize, |this| ((**(this as *const *const IMbnRadio_Vtbl)).HardwareRadioState)(this, hardwareradiostate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSoftwareRadioState(this: *mut core::ffi::c_void, radiostate: MBN_RADIO, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnRadio", "SetSoftwareRadioState", 5usize, |this| ((**(this as *const *const IMbnRadio_Vtbl)).SetSoftwareRadioState)(this, radiostate, requestid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SoftwareRadioState, HardwareRadioState, SetSoftwareRadioState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnRadio as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMbnRadioEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnRadioStateChange(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnRadioEvents", "OnRadioStateChange", 3usize, |this| ((**(this as *const *const IMbnRadioEvents_Vtbl)).OnRadioStateChange)(this, newinterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnSetSoftwareRadioStateComplete(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnRadioEvents", "OnSetSoftwareRadioStateComplete", 4usize, |this| ((**(this as *const *const IMbnRadioEvents_Vtbl)).OnSetSoftwareRadioStateComplete)(this, newinterface, requestid, status), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnRadioStateChange, OnSetSoftwareRadioStateComplete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnRadioEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMbnRegistration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRegisterState(this: *mut core::ffi::c_void, registerstate: *mut MBN_REGISTER_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnRegistration", "GetRegisterState", 3usize, |this| ((**(this as *const *const IMbnRegistration_Vtbl)).GetRegisterState)(this, registerstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRegisterMode(this: *mut core::ffi::c_void, registermode: *mut MBN_REGISTER_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnRegistration", "GetRegisterMode", 4usize, |this| ((**(this as *const *const IMbnRegistration_Vtbl)).GetRegisterMode)(this, registermode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProviderID(this: *mut core::ffi::c_void, providerid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnRegistration", "GetProviderID", 5usize, |this| ((**(this as *const *const IMbnRegistration_Vtbl)).GetProviderID)(this, providerid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProviderName(this: *mut core::ffi::c_void, providername: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnRegistration", "GetProviderName", 6usize, |this| ((**(this as *const *const IMbnRegistration_Vtbl)).GetProviderName)(this, providername), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRoamingText(this: *mut core::ffi::c_void, roamingtext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnRegistration", "GetRoamingText", 7usize, |this| ((**(this as *const *const IMbnRegistration_Vtbl)).GetRoamingText)(this, roamingtext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAvailableDataClasses(this: *mut core::ffi::c_void, availabledataclasses: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnRegistration", "GetAvailableDataClasses", 8usize, |this| ((**(this as *const *const IMbnRegistration_Vtbl)).GetAvailableDataClasses)(this, availabledataclasses), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentDataClass(this: *mut core::ffi::c_void, currentdataclass: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnRegistration", "GetCurrentDataClass", 9usize, |this| ((**(this as *const *const IMbnRegistration_Vtbl)).GetCurrentDataClass)(this, currentdataclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRegistrationNetworkError(this: *mut core::ffi::c_void, registrationnetworkerror: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnRegistration", "GetRegistrationNetworkError", 10usize, |this| ((**(this as *const *const IMbnRegistration_Vtbl)).GetRegistrationNetworkError)(this, registrationnetworkerror), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPacketAttachNetworkError(this: *mut core::ffi::c_void, packetattachnetworkerror: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnRegistration", "GetPacketAttachNetworkError", 11usize, |this| ((**(this as *const *const IMbnRegistration_Vtbl)).GetPacketAttachNetworkError)(this, packetattachnetworkerror), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRegisterMode(this: *mut core::ffi::c_void, registermode: MBN_REGISTER_MODE, providerid: windows_core::PCWSTR, dataclass: u32, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnRegistration", "SetRegisterMode", 12usize, |this| ((**(this as *const *const IMbnRegistration_Vtbl)).SetRegisterMode)(this, registermode, providerid, dataclass, requestid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetRegisterState,
+            GetRegisterMode,
+            GetProviderID,
+            GetProviderName,
+            GetRoamingText,
+            GetAvailableDataClasses,
+            GetCurrentDataClass,
+            GetRegistrationNetworkError,
+            GetPacketAttachNetworkError,
+            SetRegisterMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnRegistration as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMbnRegistrationEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnRegisterModeAvailable(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnRegistrationEvents", "OnRegisterModeAvailable", 3usize, |this| ((**(this as *const *const IMbnRegistrationEvents_Vtbl)).OnRegisterModeAvailable)(this, newinterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnRegisterStateChange(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnRegistrationEvents", "OnRegisterStateChange", 4usize, |this| ((**(this as *const *const IMbnRegistrationEvents_Vtbl)).OnRegisterStateChange)(this, newinterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnPacketServiceStateChange(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnRegistrationEvents", "OnPacketServiceStateChange", 5usize, |this| ((**(this as *const *const IMbnRegistrationEvents_Vtbl)).OnPacketServiceStateChange)(this, newinterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnSetRegisterModeComplete(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnRegistrationEvents", "OnSetRegisterModeComplete", 6usize, |this| ((**(this as *const *const IMbnRegistrationEvents_Vtbl)).OnSetRegisterModeComplete)(this, newinterface, requestid, status), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnRegisterModeAvailable,
+            OnRegisterStateChange,
+            OnPacketServiceStateChange,
+            OnSetRegisterModeComplete,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnRegistrationEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMbnServiceActivation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Activate(this: *mut core::ffi::c_void, vendorspecificdata: *const super::super::System::Com::SAFEARRAY, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnServiceActivation", "Activate", 3usize, |this| ((**(this as *const *const IMbnServiceActivation_Vtbl)).Activate)(this, vendorspecificdata, requestid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Activate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Activate: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnServiceActivation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMbnServiceActivationEvents_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnActivationComplete(this: *mut core::ffi::c_void, serviceactivation: *mut core::ffi::c_void, vendorspecificdata: *const super::super::System::Com::SAFEARRAY, requestid: u32, status: windows_core::HRESULT, networkerror: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnServiceActivationEvents", "OnActivationComplete", 3usize, |this| ((**(this as *const *const IMbnServiceActivationEvents_Vtbl)).OnActivationComplete)(this, serviceactivation, vendorspecificdata, requestid, status, networkerror), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            OnActivationComplete,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnActivationComplete: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnServiceActivationEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMbnSignal_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSignalStrength(this: *mut core::ffi::c_void, signalstrength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSignal", "GetSignalStrength", 3usize, |this| ((**(this as *const *const IMbnSignal_Vtbl)).GetSignalStrength)(this, signalstrength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignalError(this: *mut core::ffi::c_void, signalerror: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSignal", "GetSignalError", 4usize, |this| ((**(this as *const *const IMbnSignal_Vtbl)).GetSignalError)(this, signalerror), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSignalStrength, GetSignalError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnSignal as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMbnSignalEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnSignalStateChange(this: *mut core::ffi::c_void, newinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSignalEvents", "OnSignalStateChange", 3usize, |this| ((**(this as *const *const IMbnSignalEvents_Vtbl)).OnSignalStateChange)(this, newinterface), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnSignalStateChange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnSignalEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMbnSms_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSmsConfiguration(this: *mut core::ffi::c_void, smsconfiguration: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSms", "GetSmsConfiguration", 3usize, |this| ((**(this as *const *const IMbnSms_Vtbl)).GetSmsConfiguration)(this, smsconfiguration), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSmsConfiguration(this: *mut core::ffi::c_void, smsconfiguration: *mut core::ffi::c_void, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSms", "SetSmsConfiguration", 4usize, |this| ((**(this as *const *const IMbnSms_Vtbl)).SetSmsConfiguration)(this, smsconfiguration, requestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SmsSendPdu(this: *mut core::ffi::c_void, pdudata: windows_core::PCWSTR, size: u8, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSms", "SmsSendPdu", 5usize, |this| ((**(this as *const *const IMbnSms_Vtbl)).SmsSendPdu)(this, pdudata, size, requestid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SmsSendCdma(this: *mut core::ffi::c_void, address: windows_core::PCWSTR, encoding: MBN_SMS_CDMA_ENCODING, language: MBN_SMS_CDMA_LANG, sizeincharacters: u32, message: *const super::super::System::Com::SAFEARRAY, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSms", "SmsSendCdma", 6usize, |this| ((**(this as *const *const IMbnSms_Vtbl)).SmsSendCdma)(this, address, encoding, language, sizeincharacters, message, requestid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SmsSendCdmaPdu(this: *mut core::ffi::c_void, message: *const super::super::System::Com::SAFEARRAY, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSms", "SmsSendCdmaPdu", 7usize, |this| ((**(this as *const *const IMbnSms_Vtbl)).SmsSendCdmaPdu)(this, message, requestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SmsRead(this: *mut core::ffi::c_void, smsfilter: *const MBN_SMS_FILTER, smsformat: MBN_SMS_FORMAT, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSms", "SmsRead", 8usize, |this| ((**(this as *const *const IMbnSms_Vtbl)).SmsRead)(this, smsfilter, smsformat, requestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SmsDelete(this: *mut core::ffi::c_void, smsfilter: *const MBN_SMS_FILTER, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSms", "SmsDelete", 9usize, |this| ((**(this as *const *const IMbnSms_Vtbl)).SmsDelete)(this, smsfilter, requestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSmsStatus(this: *mut core::ffi::c_void, smsstatusinfo: *mut MBN_SMS_STATUS_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSms", "GetSmsStatus", 10usize, |this| ((**(this as *const *const IMbnSms_Vtbl)).GetSmsStatus)(this, smsstatusinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSmsConfiguration,
+            SetSmsConfiguration,
+            SmsSendPdu,
+            #[cfg(feature = "Win32_System_Com")]
+            SmsSendCdma,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SmsSendCdma: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SmsSendCdmaPdu,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SmsSendCdmaPdu: 0,
+            SmsRead,
+            SmsDelete,
+            GetSmsStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnSms as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMbnSmsConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ServiceCenterAddress(this: *mut core::ffi::c_void, scaddress: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSmsConfiguration", "ServiceCenterAddress", 3usize, |this| ((**(this as *const *const IMbnSmsConfiguration_Vtbl)).ServiceCenterAddress)(this, scaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServiceCenterAddress(this: *mut core::ffi::c_void, scaddress: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSmsConfiguration", "SetServiceCenterAddress", 4usize, |this| ((**(this as *const *const IMbnSmsConfiguration_Vtbl)).SetServiceCenterAddress)(this, scaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxMessageIndex(this: *mut core::ffi::c_void, index: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSmsConfiguration", "MaxMessageIndex", 5usize, |this| ((**(this as *const *const IMbnSmsConfiguration_Vtbl)).MaxMessageIndex)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn CdmaShortMsgSize(this: *mut core::ffi::c_void, shortmsgsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSmsConfiguration", "CdmaShortMsgSize", 6usize, |this| ((**(this as *const *const IMbnSmsConfiguration_Vtbl)).CdmaShortMsgSize)(this, shortmsgsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SmsFormat(this: *mut core::ffi::c_void, smsformat: *mut MBN_SMS_FORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSmsConfiguration", "SmsFormat", 7usize, |this| ((**(this as *const *const IMbnSmsConfiguration_Vtbl)).SmsFormat)(this, smsformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSmsFormat(this: *mut core::ffi::c_void, smsformat: MBN_SMS_FORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSmsConfiguration", "SetSmsFormat", 8usize, |this| ((**(this as *const *const IMbnSmsConfiguration_Vtbl)).SetSmsFormat)(this, smsformat), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ServiceCenterAddress,
+            SetServiceCenterAddress,
+            MaxMessageIndex,
+            CdmaShortMsgSize,
+            SmsFormat,
+            SetSmsFormat,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnSmsConfiguration as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMbnSmsEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnSmsConfigurationChange(this: *mut core::ffi::c_void, sms: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSmsEvents", "OnSmsConfigurationChange", 3usize, |this| ((**(this as *const *const IMbnSmsEvents_Vtbl)).OnSmsConfigurationChange)(this, sms), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnSetSmsConfigurationComplete(this: *mut core::ffi::c_void, sms: *mut core::ffi::c_void, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSmsEvents", "OnSetSmsConfigurationComplete", 4usize, |this| ((**(this as *const *const IMbnSmsEvents_Vtbl)).OnSetSmsConfigurationComplete)(this, sms, requestid, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnSmsSendComplete(this: *mut core::ffi::c_void, sms: *mut core::ffi::c_void, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSmsEvents", "OnSmsSendComplete", 5usize, |this| ((**(this as *const *const IMbnSmsEvents_Vtbl)).OnSmsSendComplete)(this, sms, requestid, status), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnSmsReadComplete(this: *mut core::ffi::c_void, sms: *mut core::ffi::c_void, smsformat: MBN_SMS_FORMAT, readmsgs: *const super::super::System::Com::SAFEARRAY, moremsgs: super::super::Foundation::VARIANT_BOOL, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSmsEvents", "OnSmsReadComplete", 6usize, |this| ((**(this as *const *const IMbnSmsEvents_Vtbl)).OnSmsReadComplete)(this, sms, smsformat, readmsgs, moremsgs, requestid, status), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnSmsNewClass0Message(this: *mut core::ffi::c_void, sms: *mut core::ffi::c_void, smsformat: MBN_SMS_FORMAT, readmsgs: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSmsEvents", "OnSmsNewClass0Message", 7usize, |this| ((**(this as *const *const IMbnSmsEvents_Vtbl)).OnSmsNewClass0Message)(this, sms, smsformat, readmsgs), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnSmsDeleteComplete(this: *mut core::ffi::c_void, sms: *mut core::ffi::c_void, requestid: u32, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSmsEvents", "OnSmsDeleteComplete", 8usize, |this| ((**(this as *const *const IMbnSmsEvents_Vtbl)).OnSmsDeleteComplete)(this, sms, requestid, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnSmsStatusChange(this: *mut core::ffi::c_void, sms: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSmsEvents", "OnSmsStatusChange", 9usize, |this| ((**(this as *const *const IMbnSmsEvents_Vtbl)).OnSmsStatusChange)(this, sms), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnSmsConfigurationChange,
+            OnSetSmsConfigurationComplete,
+            OnSmsSendComplete,
+            #[cfg(feature = "Win32_System_Com")]
+            OnSmsReadComplete,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnSmsReadComplete: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnSmsNewClass0Message,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnSmsNewClass0Message: 0,
+            OnSmsDeleteComplete,
+            OnSmsStatusChange,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnSmsEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMbnSmsReadMsgPdu_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Index(this: *mut core::ffi::c_void, index: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSmsReadMsgPdu", "Index", 3usize, |this| ((**(this as *const *const IMbnSmsReadMsgPdu_Vtbl)).Index)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, status: *mut MBN_MSG_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSmsReadMsgPdu", "Status", 4usize, |this| ((**(this as *const *const IMbnSmsReadMsgPdu_Vtbl)).Status)(this, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn PduData(this: *mut core::ffi::c_void, pdudata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSmsReadMsgPdu", "PduData", 5usize, |this| ((**(this as *const *const IMbnSmsReadMsgPdu_Vtbl)).PduData)(this, pdudata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, message: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSmsReadMsgPdu", "Message", 6usize, |this| ((**(this as *const *const IMbnSmsReadMsgPdu_Vtbl)).Message)(this, message), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Index,
+            Status,
+            PduData,
+            #[cfg(feature = "Win32_System_Com")]
+            Message,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Message: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnSmsReadMsgPdu as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMbnSmsReadMsgTextCdma_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Index(this: *mut core::ffi::c_void, index: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSmsReadMsgTextCdma", "Index", 3usize, |this| ((**(this as *const *const IMbnSmsReadMsgTextCdma_Vtbl)).Index)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, status: *mut MBN_MSG_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSmsReadMsgTextCdma", "Status", 4usize, |this| ((**(this as *const *const IMbnSmsReadMsgTextCdma_Vtbl)).Status)(this, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn Address(this: *mut core::ffi::c_void, address: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSmsReadMsgTextCdma", "Address", 5usize, |this| ((**(this as *const *const IMbnSmsReadMsgTextCdma_Vtbl)).Address)(this, address), |result| Some(*result))
+        }
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, timestamp: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSmsReadMsgTextCdma", "Timestamp", 6usize, |this| ((**(this as *const *const IMbnSmsReadMsgTextCdma_Vtbl)).Timestamp)(this, timestamp), |result| Some(*result))
+        }
+        unsafe extern "system" fn EncodingID(this: *mut core::ffi::c_void, encodingid: *mut MBN_SMS_CDMA_ENCODING) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSmsReadMsgTextCdma", "EncodingID", 7usize, |this| ((**(this as *const *const IMbnSmsReadMsgTextCdma_Vtbl)).EncodingID)(this, encodingid), |result| Some(*result))
+        }
+        unsafe extern "system" fn LanguageID(this: *mut core::ffi::c_void, languageid: *mut MBN_SMS_CDMA_LANG) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSmsReadMsgTextCdma", "LanguageID", 8usize, |this| ((**(this as *const *const IMbnSmsReadMsgTextCdma_Vtbl)).LanguageID)(this, languageid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SizeInCharacters(this: *mut core::ffi::c_void, sizeincharacters: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSmsReadMsgTextCdma", "SizeInCharacters", 9usize, |this| ((**(this as *const *const IMbnSmsReadMsgTextCdma_Vtbl)).SizeInCharacters)(this, sizeincharacters), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, message: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSmsReadMsgTextCdma", "Message", 10usize, |this| ((**(this as *const *const IMbnSmsReadMsgTextCdma_Vtbl)).Message)(this, message), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Index,
+            Status,
+            Address,
+            Timestamp,
+            EncodingID,
+            LanguageID,
+            SizeInCharacters,
+            #[cfg(feature = "Win32_System_Com")]
+            Message,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Message: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnSmsReadMsgTextCdma as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMbnSubscriberInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SubscriberID(this: *mut core::ffi::c_void, subscriberid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSubscriberInformation", "SubscriberID", 3usize, |this| ((**(this as *const *const IMbnSubscriberInformation_Vtbl)).SubscriberID)(this, subscriberid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SimIccID(this: *mut core::ffi::c_void, simiccid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSubscriberInformation", "SimIccID", 4usize, |this| ((**(this as *const *const IMbnSubscriberInformation_Vtbl)).SimIccID)(this, simiccid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn TelephoneNumbers(this: *mut core::ffi::c_void, telephonenumbers: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnSubscriberInformation", "TelephoneNumbers", 5usize, |this| ((**(this as *const *const IMbnSubscriberInformation_Vtbl)).TelephoneNumbers)(this, telephonenumbers), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SubscriberID,
+            SimIccID,
+            #[cfg(feature = "Win32_System_Com")]
+            TelephoneNumbers,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            TelephoneNumbers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnSubscriberInformation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMbnVendorSpecificEvents_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnEventNotification(this: *mut core::ffi::c_void, vendoroperation: *mut core::ffi::c_void, vendorspecificdata: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnVendorSpecificEvents", "OnEventNotification", 3usize, |this| ((**(this as *const *const IMbnVendorSpecificEvents_Vtbl)).OnEventNotification)(this, vendoroperation, vendorspecificdata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnSetVendorSpecificComplete(this: *mut core::ffi::c_void, vendoroperation: *mut core::ffi::c_void, vendorspecificdata: *const super::super::System::Com::SAFEARRAY, requestid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnVendorSpecificEvents", "OnSetVendorSpecificComplete", 4usize, |this| ((**(this as *const *const IMbnVendorSpecificEvents_Vtbl)).OnSetVendorSpecificComplete)(this, vendoroperation, vendorspecificdata, requestid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            OnEventNotification,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnEventNotification: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnSetVendorSpecificComplete,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnSetVendorSpecificComplete: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnVendorSpecificEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMbnVendorSpecificOperation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetVendorSpecific(this: *mut core::ffi::c_void, vendorspecificdata: *const super::super::System::Com::SAFEARRAY, requestid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMbnVendorSpecificOperation", "SetVendorSpecific", 3usize, |this| ((**(this as *const *const IMbnVendorSpecificOperation_Vtbl)).SetVendorSpecific)(this, vendorspecificdata, requestid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            SetVendorSpecific,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetVendorSpecific: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMbnVendorSpecificOperation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
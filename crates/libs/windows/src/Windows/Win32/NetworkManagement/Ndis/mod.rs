@@ -2141,6 +2141,7 @@ pub struct IF_ADMINISTRATIVE_STATE(pub i32);
 impl windows_core::TypeKind for IF_ADMINISTRATIVE_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IF_ADMINISTRATIVE_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IF_ADMINISTRATIVE_STATE").field(&self.0).finish()
@@ -2152,6 +2153,7 @@ pub struct IF_OPER_STATUS(pub i32);
 impl windows_core::TypeKind for IF_OPER_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IF_OPER_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IF_OPER_STATUS").field(&self.0).finish()
@@ -2163,6 +2165,7 @@ pub struct NDIS_802_11_AUTHENTICATION_MODE(pub i32);
 impl windows_core::TypeKind for NDIS_802_11_AUTHENTICATION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_802_11_AUTHENTICATION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_802_11_AUTHENTICATION_MODE").field(&self.0).finish()
@@ -2174,6 +2177,7 @@ pub struct NDIS_802_11_MEDIA_STREAM_MODE(pub i32);
 impl windows_core::TypeKind for NDIS_802_11_MEDIA_STREAM_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_802_11_MEDIA_STREAM_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_802_11_MEDIA_STREAM_MODE").field(&self.0).finish()
@@ -2185,6 +2189,7 @@ pub struct NDIS_802_11_NETWORK_INFRASTRUCTURE(pub i32);
 impl windows_core::TypeKind for NDIS_802_11_NETWORK_INFRASTRUCTURE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_802_11_NETWORK_INFRASTRUCTURE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_802_11_NETWORK_INFRASTRUCTURE").field(&self.0).finish()
@@ -2196,6 +2201,7 @@ pub struct NDIS_802_11_NETWORK_TYPE(pub i32);
 impl windows_core::TypeKind for NDIS_802_11_NETWORK_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_802_11_NETWORK_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_802_11_NETWORK_TYPE").field(&self.0).finish()
@@ -2207,6 +2213,7 @@ pub struct NDIS_802_11_POWER_MODE(pub i32);
 impl windows_core::TypeKind for NDIS_802_11_POWER_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_802_11_POWER_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_802_11_POWER_MODE").field(&self.0).finish()
@@ -2218,6 +2225,7 @@ pub struct NDIS_802_11_PRIVACY_FILTER(pub i32);
 impl windows_core::TypeKind for NDIS_802_11_PRIVACY_FILTER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_802_11_PRIVACY_FILTER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_802_11_PRIVACY_FILTER").field(&self.0).finish()
@@ -2229,6 +2237,7 @@ pub struct NDIS_802_11_RADIO_STATUS(pub i32);
 impl windows_core::TypeKind for NDIS_802_11_RADIO_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_802_11_RADIO_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_802_11_RADIO_STATUS").field(&self.0).finish()
@@ -2240,6 +2249,7 @@ pub struct NDIS_802_11_RELOAD_DEFAULTS(pub i32);
 impl windows_core::TypeKind for NDIS_802_11_RELOAD_DEFAULTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_802_11_RELOAD_DEFAULTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_802_11_RELOAD_DEFAULTS").field(&self.0).finish()
@@ -2251,6 +2261,7 @@ pub struct NDIS_802_11_STATUS_TYPE(pub i32);
 impl windows_core::TypeKind for NDIS_802_11_STATUS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_802_11_STATUS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_802_11_STATUS_TYPE").field(&self.0).finish()
@@ -2262,6 +2273,7 @@ pub struct NDIS_802_11_WEP_STATUS(pub i32);
 impl windows_core::TypeKind for NDIS_802_11_WEP_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_802_11_WEP_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_802_11_WEP_STATUS").field(&self.0).finish()
@@ -2273,6 +2285,7 @@ pub struct NDIS_802_5_RING_STATE(pub i32);
 impl windows_core::TypeKind for NDIS_802_5_RING_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_802_5_RING_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_802_5_RING_STATE").field(&self.0).finish()
@@ -2284,6 +2297,7 @@ pub struct NDIS_DEVICE_POWER_STATE(pub i32);
 impl windows_core::TypeKind for NDIS_DEVICE_POWER_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_DEVICE_POWER_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_DEVICE_POWER_STATE").field(&self.0).finish()
@@ -2295,6 +2309,7 @@ pub struct NDIS_FDDI_ATTACHMENT_TYPE(pub i32);
 impl windows_core::TypeKind for NDIS_FDDI_ATTACHMENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_FDDI_ATTACHMENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_FDDI_ATTACHMENT_TYPE").field(&self.0).finish()
@@ -2306,6 +2321,7 @@ pub struct NDIS_FDDI_LCONNECTION_STATE(pub i32);
 impl windows_core::TypeKind for NDIS_FDDI_LCONNECTION_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_FDDI_LCONNECTION_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_FDDI_LCONNECTION_STATE").field(&self.0).finish()
@@ -2317,6 +2333,7 @@ pub struct NDIS_FDDI_RING_MGT_STATE(pub i32);
 impl windows_core::TypeKind for NDIS_FDDI_RING_MGT_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_FDDI_RING_MGT_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_FDDI_RING_MGT_STATE").field(&self.0).finish()
@@ -2328,6 +2345,7 @@ pub struct NDIS_HARDWARE_STATUS(pub i32);
 impl windows_core::TypeKind for NDIS_HARDWARE_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_HARDWARE_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_HARDWARE_STATUS").field(&self.0).finish()
@@ -2339,6 +2357,7 @@ pub struct NDIS_INTERRUPT_MODERATION(pub i32);
 impl windows_core::TypeKind for NDIS_INTERRUPT_MODERATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_INTERRUPT_MODERATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_INTERRUPT_MODERATION").field(&self.0).finish()
@@ -2350,6 +2369,7 @@ pub struct NDIS_MEDIA_STATE(pub i32);
 impl windows_core::TypeKind for NDIS_MEDIA_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_MEDIA_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_MEDIA_STATE").field(&self.0).finish()
@@ -2361,6 +2381,7 @@ pub struct NDIS_MEDIUM(pub i32);
 impl windows_core::TypeKind for NDIS_MEDIUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_MEDIUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_MEDIUM").field(&self.0).finish()
@@ -2372,6 +2393,7 @@ pub struct NDIS_NETWORK_CHANGE_TYPE(pub i32);
 impl windows_core::TypeKind for NDIS_NETWORK_CHANGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_NETWORK_CHANGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_NETWORK_CHANGE_TYPE").field(&self.0).finish()
@@ -2383,6 +2405,7 @@ pub struct NDIS_PHYSICAL_MEDIUM(pub i32);
 impl windows_core::TypeKind for NDIS_PHYSICAL_MEDIUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_PHYSICAL_MEDIUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_PHYSICAL_MEDIUM").field(&self.0).finish()
@@ -2394,6 +2417,7 @@ pub struct NDIS_PORT_AUTHORIZATION_STATE(pub i32);
 impl windows_core::TypeKind for NDIS_PORT_AUTHORIZATION_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_PORT_AUTHORIZATION_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_PORT_AUTHORIZATION_STATE").field(&self.0).finish()
@@ -2405,6 +2429,7 @@ pub struct NDIS_PORT_CONTROL_STATE(pub i32);
 impl windows_core::TypeKind for NDIS_PORT_CONTROL_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_PORT_CONTROL_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_PORT_CONTROL_STATE").field(&self.0).finish()
@@ -2416,6 +2441,7 @@ pub struct NDIS_PORT_TYPE(pub i32);
 impl windows_core::TypeKind for NDIS_PORT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_PORT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_PORT_TYPE").field(&self.0).finish()
@@ -2427,6 +2453,7 @@ pub struct NDIS_PROCESSOR_VENDOR(pub i32);
 impl windows_core::TypeKind for NDIS_PROCESSOR_VENDOR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_PROCESSOR_VENDOR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_PROCESSOR_VENDOR").field(&self.0).finish()
@@ -2438,6 +2465,7 @@ pub struct NDIS_REQUEST_TYPE(pub i32);
 impl windows_core::TypeKind for NDIS_REQUEST_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_REQUEST_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_REQUEST_TYPE").field(&self.0).finish()
@@ -2449,6 +2477,7 @@ pub struct NDIS_SUPPORTED_PAUSE_FUNCTIONS(pub i32);
 impl windows_core::TypeKind for NDIS_SUPPORTED_PAUSE_FUNCTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_SUPPORTED_PAUSE_FUNCTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_SUPPORTED_PAUSE_FUNCTIONS").field(&self.0).finish()
@@ -2460,6 +2489,7 @@ pub struct NDIS_WAN_HEADER_FORMAT(pub i32);
 impl windows_core::TypeKind for NDIS_WAN_HEADER_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_WAN_HEADER_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_WAN_HEADER_FORMAT").field(&self.0).finish()
@@ -2471,6 +2501,7 @@ pub struct NDIS_WAN_MEDIUM_SUBTYPE(pub i32);
 impl windows_core::TypeKind for NDIS_WAN_MEDIUM_SUBTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_WAN_MEDIUM_SUBTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_WAN_MEDIUM_SUBTYPE").field(&self.0).finish()
@@ -2482,6 +2513,7 @@ pub struct NDIS_WAN_QUALITY(pub i32);
 impl windows_core::TypeKind for NDIS_WAN_QUALITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDIS_WAN_QUALITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDIS_WAN_QUALITY").field(&self.0).finish()
@@ -2493,6 +2525,7 @@ pub struct NDK_RDMA_TECHNOLOGY(pub i32);
 impl windows_core::TypeKind for NDK_RDMA_TECHNOLOGY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NDK_RDMA_TECHNOLOGY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NDK_RDMA_TECHNOLOGY").field(&self.0).finish()
@@ -2504,6 +2537,7 @@ pub struct NET_IF_ACCESS_TYPE(pub i32);
 impl windows_core::TypeKind for NET_IF_ACCESS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NET_IF_ACCESS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NET_IF_ACCESS_TYPE").field(&self.0).finish()
@@ -2515,6 +2549,7 @@ pub struct NET_IF_ADMIN_STATUS(pub i32);
 impl windows_core::TypeKind for NET_IF_ADMIN_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NET_IF_ADMIN_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NET_IF_ADMIN_STATUS").field(&self.0).finish()
@@ -2526,6 +2561,7 @@ pub struct NET_IF_CONNECTION_TYPE(pub i32);
 impl windows_core::TypeKind for NET_IF_CONNECTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NET_IF_CONNECTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NET_IF_CONNECTION_TYPE").field(&self.0).finish()
@@ -2537,6 +2573,7 @@ pub struct NET_IF_DIRECTION_TYPE(pub i32);
 impl windows_core::TypeKind for NET_IF_DIRECTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NET_IF_DIRECTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NET_IF_DIRECTION_TYPE").field(&self.0).finish()
@@ -2548,6 +2585,7 @@ pub struct NET_IF_MEDIA_CONNECT_STATE(pub i32);
 impl windows_core::TypeKind for NET_IF_MEDIA_CONNECT_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NET_IF_MEDIA_CONNECT_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NET_IF_MEDIA_CONNECT_STATE").field(&self.0).finish()
@@ -2559,6 +2597,7 @@ pub struct NET_IF_MEDIA_DUPLEX_STATE(pub i32);
 impl windows_core::TypeKind for NET_IF_MEDIA_DUPLEX_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NET_IF_MEDIA_DUPLEX_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NET_IF_MEDIA_DUPLEX_STATE").field(&self.0).finish()
@@ -2570,6 +2609,7 @@ pub struct NET_IF_OPER_STATUS(pub i32);
 impl windows_core::TypeKind for NET_IF_OPER_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NET_IF_OPER_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NET_IF_OPER_STATUS").field(&self.0).finish()
@@ -2581,6 +2621,7 @@ pub struct NET_IF_RCV_ADDRESS_TYPE(pub i32);
 impl windows_core::TypeKind for NET_IF_RCV_ADDRESS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NET_IF_RCV_ADDRESS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NET_IF_RCV_ADDRESS_TYPE").field(&self.0).finish()
@@ -2592,6 +2633,7 @@ pub struct OFFLOAD_CONF_ALGO(pub i32);
 impl windows_core::TypeKind for OFFLOAD_CONF_ALGO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OFFLOAD_CONF_ALGO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OFFLOAD_CONF_ALGO").field(&self.0).finish()
@@ -2603,6 +2645,7 @@ pub struct OFFLOAD_INTEGRITY_ALGO(pub i32);
 impl windows_core::TypeKind for OFFLOAD_INTEGRITY_ALGO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OFFLOAD_INTEGRITY_ALGO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OFFLOAD_INTEGRITY_ALGO").field(&self.0).finish()
@@ -2614,6 +2657,7 @@ pub struct OFFLOAD_OPERATION_E(pub i32);
 impl windows_core::TypeKind for OFFLOAD_OPERATION_E {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OFFLOAD_OPERATION_E {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OFFLOAD_OPERATION_E").field(&self.0).finish()
@@ -2625,6 +2669,7 @@ pub struct TUNNEL_TYPE(pub i32);
 impl windows_core::TypeKind for TUNNEL_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TUNNEL_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TUNNEL_TYPE").field(&self.0).finish()
@@ -2636,13 +2681,15 @@ pub struct UDP_ENCAP_TYPE(pub i32);
 impl windows_core::TypeKind for UDP_ENCAP_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UDP_ENCAP_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UDP_ENCAP_TYPE").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BSSID_INFO {
     pub BSSID: [u8; 6],
     pub PMKID: [u8; 16],
@@ -2656,7 +2703,8 @@ impl Default for BSSID_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GEN_GET_NETCARD_TIME {
     pub ReadTime: u64,
 }
@@ -2669,7 +2717,8 @@ impl Default for GEN_GET_NETCARD_TIME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GEN_GET_TIME_CAPS {
     pub Flags: u32,
     pub ClockPrecision: u32,
@@ -2683,7 +2732,8 @@ impl Default for GEN_GET_TIME_CAPS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IF_COUNTED_STRING_LH {
     pub Length: u16,
     pub String: [u16; 257],
@@ -2697,7 +2747,8 @@ impl Default for IF_COUNTED_STRING_LH {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IF_PHYSICAL_ADDRESS_LH {
     pub Length: u16,
     pub Address: [u8; 32],
@@ -2711,7 +2762,8 @@ impl Default for IF_PHYSICAL_ADDRESS_LH {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_AI_REQFI {
     pub Capabilities: u16,
     pub ListenInterval: u16,
@@ -2726,7 +2778,8 @@ impl Default for NDIS_802_11_AI_REQFI {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_AI_RESFI {
     pub Capabilities: u16,
     pub StatusCode: u16,
@@ -2741,7 +2794,8 @@ impl Default for NDIS_802_11_AI_RESFI {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_ASSOCIATION_INFORMATION {
     pub Length: u32,
     pub AvailableRequestFixedIEs: u16,
@@ -2762,7 +2816,8 @@ impl Default for NDIS_802_11_ASSOCIATION_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_AUTHENTICATION_ENCRYPTION {
     pub AuthModeSupported: NDIS_802_11_AUTHENTICATION_MODE,
     pub EncryptStatusSupported: NDIS_802_11_WEP_STATUS,
@@ -2776,7 +2831,8 @@ impl Default for NDIS_802_11_AUTHENTICATION_ENCRYPTION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_AUTHENTICATION_EVENT {
     pub Status: NDIS_802_11_STATUS_INDICATION,
     pub Request: [NDIS_802_11_AUTHENTICATION_REQUEST; 1],
@@ -2790,7 +2846,8 @@ impl Default for NDIS_802_11_AUTHENTICATION_EVENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_AUTHENTICATION_REQUEST {
     pub Length: u32,
     pub Bssid: [u8; 6],
@@ -2805,7 +2862,8 @@ impl Default for NDIS_802_11_AUTHENTICATION_REQUEST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_BSSID_LIST {
     pub NumberOfItems: u32,
     pub Bssid: [NDIS_WLAN_BSSID; 1],
@@ -2819,7 +2877,8 @@ impl Default for NDIS_802_11_BSSID_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_BSSID_LIST_EX {
     pub NumberOfItems: u32,
     pub Bssid: [NDIS_WLAN_BSSID_EX; 1],
@@ -2833,7 +2892,8 @@ impl Default for NDIS_802_11_BSSID_LIST_EX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_CAPABILITY {
     pub Length: u32,
     pub Version: u32,
@@ -2850,7 +2910,8 @@ impl Default for NDIS_802_11_CAPABILITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_CONFIGURATION {
     pub Length: u32,
     pub BeaconPeriod: u32,
@@ -2867,7 +2928,8 @@ impl Default for NDIS_802_11_CONFIGURATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_CONFIGURATION_FH {
     pub Length: u32,
     pub HopPattern: u32,
@@ -2883,7 +2945,8 @@ impl Default for NDIS_802_11_CONFIGURATION_FH {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_FIXED_IEs {
     pub Timestamp: [u8; 8],
     pub BeaconInterval: u16,
@@ -2898,7 +2961,8 @@ impl Default for NDIS_802_11_FIXED_IEs {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_KEY {
     pub Length: u32,
     pub KeyIndex: u32,
@@ -2916,7 +2980,8 @@ impl Default for NDIS_802_11_KEY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_NETWORK_TYPE_LIST {
     pub NumberOfItems: u32,
     pub NetworkType: [NDIS_802_11_NETWORK_TYPE; 1],
@@ -2930,7 +2995,8 @@ impl Default for NDIS_802_11_NETWORK_TYPE_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_NON_BCAST_SSID_LIST {
     pub NumberOfItems: u32,
     pub Non_Bcast_Ssid: [NDIS_802_11_SSID; 1],
@@ -2944,7 +3010,8 @@ impl Default for NDIS_802_11_NON_BCAST_SSID_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_PMKID {
     pub Length: u32,
     pub BSSIDInfoCount: u32,
@@ -2959,7 +3026,8 @@ impl Default for NDIS_802_11_PMKID {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_PMKID_CANDIDATE_LIST {
     pub Version: u32,
     pub NumCandidates: u32,
@@ -2974,7 +3042,8 @@ impl Default for NDIS_802_11_PMKID_CANDIDATE_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_REMOVE_KEY {
     pub Length: u32,
     pub KeyIndex: u32,
@@ -2989,7 +3058,8 @@ impl Default for NDIS_802_11_REMOVE_KEY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_SSID {
     pub SsidLength: u32,
     pub Ssid: [u8; 32],
@@ -3003,7 +3073,8 @@ impl Default for NDIS_802_11_SSID {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_STATISTICS {
     pub Length: u32,
     pub TransmittedFragmentCount: i64,
@@ -3040,7 +3111,8 @@ impl Default for NDIS_802_11_STATISTICS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_STATUS_INDICATION {
     pub StatusType: NDIS_802_11_STATUS_TYPE,
 }
@@ -3082,7 +3154,8 @@ impl Default for NDIS_802_11_TEST_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_VARIABLE_IEs {
     pub ElementID: u8,
     pub Length: u8,
@@ -3097,7 +3170,8 @@ impl Default for NDIS_802_11_VARIABLE_IEs {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_802_11_WEP {
     pub Length: u32,
     pub KeyIndex: u32,
@@ -3113,7 +3187,8 @@ impl Default for NDIS_802_11_WEP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_CO_DEVICE_PROFILE {
     pub DeviceDescription: NDIS_VAR_DATA_DESC,
     pub DevSpecificInfo: NDIS_VAR_DATA_DESC,
@@ -3153,7 +3228,8 @@ impl Default for NDIS_CO_DEVICE_PROFILE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_CO_LINK_SPEED {
     pub Outbound: u32,
     pub Inbound: u32,
@@ -3197,7 +3273,8 @@ impl Default for NDIS_GUID_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_HARDWARE_CROSSTIMESTAMP {
     pub Header: NDIS_OBJECT_HEADER,
     pub Flags: u32,
@@ -3214,7 +3291,8 @@ impl Default for NDIS_HARDWARE_CROSSTIMESTAMP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_INTERFACE_INFORMATION {
     pub ifOperStatus: NET_IF_OPER_STATUS,
     pub ifOperStatusFlags: u32,
@@ -3258,7 +3336,8 @@ impl Default for NDIS_INTERFACE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_INTERRUPT_MODERATION_PARAMETERS {
     pub Header: NDIS_OBJECT_HEADER,
     pub Flags: u32,
@@ -3273,7 +3352,8 @@ impl Default for NDIS_INTERRUPT_MODERATION_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_IPSEC_OFFLOAD_V1 {
     pub Supported: NDIS_IPSEC_OFFLOAD_V1_2,
     pub IPv4AH: NDIS_IPSEC_OFFLOAD_V1_0,
@@ -3288,10 +3368,49 @@ impl Default for NDIS_IPSEC_OFFLOAD_V1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_IPSEC_OFFLOAD_V1_0 {
     pub _bitfield: u32,
 }
+impl NDIS_IPSEC_OFFLOAD_V1_0 {
+    pub const fn Md5(&self) -> u32 {
+        self._bitfield & 3
+    }
+    pub fn set_Md5(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn Sha_1(&self) -> u32 {
+        (self._bitfield >> 2) & 3
+    }
+    pub fn set_Sha_1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 2)) | ((value & 3) << 2);
+    }
+    pub const fn Transport(&self) -> u32 {
+        (self._bitfield >> 4) & 3
+    }
+    pub fn set_Transport(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 4)) | ((value & 3) << 4);
+    }
+    pub const fn Tunnel(&self) -> u32 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_Tunnel(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+    pub const fn Send(&self) -> u32 {
+        (self._bitfield >> 8) & 3
+    }
+    pub fn set_Send(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 8)) | ((value & 3) << 8);
+    }
+    pub const fn Receive(&self) -> u32 {
+        (self._bitfield >> 10) & 3
+    }
+    pub fn set_Receive(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 10)) | ((value & 3) << 10);
+    }
+}
 impl windows_core::TypeKind for NDIS_IPSEC_OFFLOAD_V1_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3301,10 +3420,61 @@ impl Default for NDIS_IPSEC_OFFLOAD_V1_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_IPSEC_OFFLOAD_V1_1 {
     pub _bitfield: u32,
 }
+impl NDIS_IPSEC_OFFLOAD_V1_1 {
+    pub const fn Des(&self) -> u32 {
+        self._bitfield & 3
+    }
+    pub fn set_Des(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 2) & 3
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 2)) | ((value & 3) << 2);
+    }
+    pub const fn TripleDes(&self) -> u32 {
+        (self._bitfield >> 4) & 3
+    }
+    pub fn set_TripleDes(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 4)) | ((value & 3) << 4);
+    }
+    pub const fn NullEsp(&self) -> u32 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_NullEsp(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+    pub const fn Transport(&self) -> u32 {
+        (self._bitfield >> 8) & 3
+    }
+    pub fn set_Transport(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 8)) | ((value & 3) << 8);
+    }
+    pub const fn Tunnel(&self) -> u32 {
+        (self._bitfield >> 10) & 3
+    }
+    pub fn set_Tunnel(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 10)) | ((value & 3) << 10);
+    }
+    pub const fn Send(&self) -> u32 {
+        (self._bitfield >> 12) & 3
+    }
+    pub fn set_Send(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 12)) | ((value & 3) << 12);
+    }
+    pub const fn Receive(&self) -> u32 {
+        (self._bitfield >> 14) & 3
+    }
+    pub fn set_Receive(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 14)) | ((value & 3) << 14);
+    }
+}
 impl windows_core::TypeKind for NDIS_IPSEC_OFFLOAD_V1_1 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3314,7 +3484,8 @@ impl Default for NDIS_IPSEC_OFFLOAD_V1_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_IPSEC_OFFLOAD_V1_2 {
     pub Encapsulation: u32,
     pub AhEspCombined: u32,
@@ -3331,7 +3502,8 @@ impl Default for NDIS_IPSEC_OFFLOAD_V1_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_IP_OPER_STATE {
     pub Header: NDIS_OBJECT_HEADER,
     pub Flags: u32,
@@ -3346,7 +3518,8 @@ impl Default for NDIS_IP_OPER_STATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_IP_OPER_STATUS {
     pub AddressFamily: u32,
     pub OperationalStatus: NET_IF_OPER_STATUS,
@@ -3361,7 +3534,8 @@ impl Default for NDIS_IP_OPER_STATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_IP_OPER_STATUS_INFO {
     pub Header: NDIS_OBJECT_HEADER,
     pub Flags: u32,
@@ -3377,7 +3551,8 @@ impl Default for NDIS_IP_OPER_STATUS_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_IRDA_PACKET_INFO {
     pub ExtraBOFs: u32,
     pub MinTurnAroundTime: u32,
@@ -3391,7 +3566,8 @@ impl Default for NDIS_IRDA_PACKET_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_LINK_PARAMETERS {
     pub Header: NDIS_OBJECT_HEADER,
     pub MediaDuplexState: NET_IF_MEDIA_DUPLEX_STATE,
@@ -3409,7 +3585,8 @@ impl Default for NDIS_LINK_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_LINK_SPEED {
     pub XmitLinkSpeed: u64,
     pub RcvLinkSpeed: u64,
@@ -3423,7 +3600,8 @@ impl Default for NDIS_LINK_SPEED {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_LINK_STATE {
     pub Header: NDIS_OBJECT_HEADER,
     pub MediaConnectState: NET_IF_MEDIA_CONNECT_STATE,
@@ -3442,7 +3620,8 @@ impl Default for NDIS_LINK_STATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_OBJECT_HEADER {
     pub Type: u8,
     pub Revision: u8,
@@ -3457,7 +3636,8 @@ impl Default for NDIS_OBJECT_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_OFFLOAD {
     pub Header: NDIS_OBJECT_HEADER,
     pub Checksum: NDIS_TCP_IP_CHECKSUM_OFFLOAD,
@@ -3475,7 +3655,8 @@ impl Default for NDIS_OFFLOAD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_OFFLOAD_PARAMETERS {
     pub Header: NDIS_OBJECT_HEADER,
     pub IPv4Checksum: u8,
@@ -3500,7 +3681,8 @@ impl Default for NDIS_OFFLOAD_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_OPER_STATE {
     pub Header: NDIS_OBJECT_HEADER,
     pub OperationalStatus: NET_IF_OPER_STATUS,
@@ -3515,7 +3697,8 @@ impl Default for NDIS_OPER_STATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_PCI_DEVICE_CUSTOM_PROPERTIES {
     pub Header: NDIS_OBJECT_HEADER,
     pub DeviceType: u32,
@@ -3540,7 +3723,8 @@ impl Default for NDIS_PCI_DEVICE_CUSTOM_PROPERTIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_PM_PACKET_PATTERN {
     pub Priority: u32,
     pub Reserved: u32,
@@ -3558,7 +3742,8 @@ impl Default for NDIS_PM_PACKET_PATTERN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_PM_WAKE_UP_CAPABILITIES {
     pub MinMagicPacketWakeUp: NDIS_DEVICE_POWER_STATE,
     pub MinPatternWakeUp: NDIS_DEVICE_POWER_STATE,
@@ -3573,7 +3758,8 @@ impl Default for NDIS_PM_WAKE_UP_CAPABILITIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_PNP_CAPABILITIES {
     pub Flags: u32,
     pub WakeUpCapabilities: NDIS_PM_WAKE_UP_CAPABILITIES,
@@ -3587,7 +3773,8 @@ impl Default for NDIS_PNP_CAPABILITIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_PORT {
     pub Next: *mut NDIS_PORT,
     pub NdisReserved: *mut core::ffi::c_void,
@@ -3604,7 +3791,8 @@ impl Default for NDIS_PORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_PORT_ARRAY {
     pub Header: NDIS_OBJECT_HEADER,
     pub NumberOfPorts: u32,
@@ -3621,7 +3809,8 @@ impl Default for NDIS_PORT_ARRAY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_PORT_AUTHENTICATION_PARAMETERS {
     pub Header: NDIS_OBJECT_HEADER,
     pub SendControlState: NDIS_PORT_CONTROL_STATE,
@@ -3638,7 +3827,8 @@ impl Default for NDIS_PORT_AUTHENTICATION_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_PORT_CHARACTERISTICS {
     pub Header: NDIS_OBJECT_HEADER,
     pub PortNumber: u32,
@@ -3662,7 +3852,8 @@ impl Default for NDIS_PORT_CHARACTERISTICS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_PORT_STATE {
     pub Header: NDIS_OBJECT_HEADER,
     pub MediaConnectState: NET_IF_MEDIA_CONNECT_STATE,
@@ -3684,7 +3875,8 @@ impl Default for NDIS_PORT_STATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_RECEIVE_HASH_PARAMETERS {
     pub Header: NDIS_OBJECT_HEADER,
     pub Flags: u32,
@@ -3701,7 +3893,8 @@ impl Default for NDIS_RECEIVE_HASH_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_RECEIVE_SCALE_CAPABILITIES {
     pub Header: NDIS_OBJECT_HEADER,
     pub CapabilitiesFlags: u32,
@@ -3717,7 +3910,8 @@ impl Default for NDIS_RECEIVE_SCALE_CAPABILITIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_RECEIVE_SCALE_PARAMETERS {
     pub Header: NDIS_OBJECT_HEADER,
     pub Flags: u16,
@@ -3737,7 +3931,8 @@ impl Default for NDIS_RECEIVE_SCALE_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_STATISTICS_INFO {
     pub Header: NDIS_OBJECT_HEADER,
     pub SupportedStatistics: u32,
@@ -3769,7 +3964,8 @@ impl Default for NDIS_STATISTICS_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_STATISTICS_VALUE {
     pub Oid: u32,
     pub DataLength: u32,
@@ -3784,7 +3980,8 @@ impl Default for NDIS_STATISTICS_VALUE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_STATISTICS_VALUE_EX {
     pub Oid: u32,
     pub DataLength: u32,
@@ -3800,7 +3997,8 @@ impl Default for NDIS_STATISTICS_VALUE_EX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TCP_CONNECTION_OFFLOAD {
     pub Header: NDIS_OBJECT_HEADER,
     pub Encapsulation: u32,
@@ -3808,6 +4006,32 @@ pub struct NDIS_TCP_CONNECTION_OFFLOAD {
     pub TcpConnectionOffloadCapacity: u32,
     pub Flags: u32,
 }
+impl NDIS_TCP_CONNECTION_OFFLOAD {
+    pub const fn SupportIPv4(&self) -> u32 {
+        self._bitfield & 3
+    }
+    pub fn set_SupportIPv4(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn SupportIPv6(&self) -> u32 {
+        (self._bitfield >> 2) & 3
+    }
+    pub fn set_SupportIPv6(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 2)) | ((value & 3) << 2);
+    }
+    pub const fn SupportIPv6ExtensionHeaders(&self) -> u32 {
+        (self._bitfield >> 4) & 3
+    }
+    pub fn set_SupportIPv6ExtensionHeaders(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 4)) | ((value & 3) << 4);
+    }
+    pub const fn SupportSack(&self) -> u32 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_SupportSack(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+}
 impl windows_core::TypeKind for NDIS_TCP_CONNECTION_OFFLOAD {
     type TypeKind = windows_core::CopyType;
 }
@@ -3817,7 +4041,8 @@ impl Default for NDIS_TCP_CONNECTION_OFFLOAD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TCP_IP_CHECKSUM_OFFLOAD {
     pub IPv4Transmit: NDIS_TCP_IP_CHECKSUM_OFFLOAD_1,
     pub IPv4Receive: NDIS_TCP_IP_CHECKSUM_OFFLOAD_0,
@@ -3833,11 +4058,44 @@ impl Default for NDIS_TCP_IP_CHECKSUM_OFFLOAD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TCP_IP_CHECKSUM_OFFLOAD_0 {
     pub Encapsulation: u32,
     pub _bitfield: u32,
 }
+impl NDIS_TCP_IP_CHECKSUM_OFFLOAD_0 {
+    pub const fn IpOptionsSupported(&self) -> u32 {
+        self._bitfield & 3
+    }
+    pub fn set_IpOptionsSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn TcpOptionsSupported(&self) -> u32 {
+        (self._bitfield >> 2) & 3
+    }
+    pub fn set_TcpOptionsSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 2)) | ((value & 3) << 2);
+    }
+    pub const fn TcpChecksum(&self) -> u32 {
+        (self._bitfield >> 4) & 3
+    }
+    pub fn set_TcpChecksum(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 4)) | ((value & 3) << 4);
+    }
+    pub const fn UdpChecksum(&self) -> u32 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_UdpChecksum(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+    pub const fn IpChecksum(&self) -> u32 {
+        (self._bitfield >> 8) & 3
+    }
+    pub fn set_IpChecksum(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 8)) | ((value & 3) << 8);
+    }
+}
 impl windows_core::TypeKind for NDIS_TCP_IP_CHECKSUM_OFFLOAD_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3847,11 +4105,44 @@ impl Default for NDIS_TCP_IP_CHECKSUM_OFFLOAD_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TCP_IP_CHECKSUM_OFFLOAD_1 {
     pub Encapsulation: u32,
     pub _bitfield: u32,
 }
+impl NDIS_TCP_IP_CHECKSUM_OFFLOAD_1 {
+    pub const fn IpOptionsSupported(&self) -> u32 {
+        self._bitfield & 3
+    }
+    pub fn set_IpOptionsSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn TcpOptionsSupported(&self) -> u32 {
+        (self._bitfield >> 2) & 3
+    }
+    pub fn set_TcpOptionsSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 2)) | ((value & 3) << 2);
+    }
+    pub const fn TcpChecksum(&self) -> u32 {
+        (self._bitfield >> 4) & 3
+    }
+    pub fn set_TcpChecksum(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 4)) | ((value & 3) << 4);
+    }
+    pub const fn UdpChecksum(&self) -> u32 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_UdpChecksum(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+    pub const fn IpChecksum(&self) -> u32 {
+        (self._bitfield >> 8) & 3
+    }
+    pub fn set_IpChecksum(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 8)) | ((value & 3) << 8);
+    }
+}
 impl windows_core::TypeKind for NDIS_TCP_IP_CHECKSUM_OFFLOAD_1 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3861,11 +4152,38 @@ impl Default for NDIS_TCP_IP_CHECKSUM_OFFLOAD_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TCP_IP_CHECKSUM_OFFLOAD_2 {
     pub Encapsulation: u32,
     pub _bitfield: u32,
 }
+impl NDIS_TCP_IP_CHECKSUM_OFFLOAD_2 {
+    pub const fn IpExtensionHeadersSupported(&self) -> u32 {
+        self._bitfield & 3
+    }
+    pub fn set_IpExtensionHeadersSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn TcpOptionsSupported(&self) -> u32 {
+        (self._bitfield >> 2) & 3
+    }
+    pub fn set_TcpOptionsSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 2)) | ((value & 3) << 2);
+    }
+    pub const fn TcpChecksum(&self) -> u32 {
+        (self._bitfield >> 4) & 3
+    }
+    pub fn set_TcpChecksum(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 4)) | ((value & 3) << 4);
+    }
+    pub const fn UdpChecksum(&self) -> u32 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_UdpChecksum(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+}
 impl windows_core::TypeKind for NDIS_TCP_IP_CHECKSUM_OFFLOAD_2 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3875,11 +4193,38 @@ impl Default for NDIS_TCP_IP_CHECKSUM_OFFLOAD_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TCP_IP_CHECKSUM_OFFLOAD_3 {
     pub Encapsulation: u32,
     pub _bitfield: u32,
 }
+impl NDIS_TCP_IP_CHECKSUM_OFFLOAD_3 {
+    pub const fn IpExtensionHeadersSupported(&self) -> u32 {
+        self._bitfield & 3
+    }
+    pub fn set_IpExtensionHeadersSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn TcpOptionsSupported(&self) -> u32 {
+        (self._bitfield >> 2) & 3
+    }
+    pub fn set_TcpOptionsSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 2)) | ((value & 3) << 2);
+    }
+    pub const fn TcpChecksum(&self) -> u32 {
+        (self._bitfield >> 4) & 3
+    }
+    pub fn set_TcpChecksum(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 4)) | ((value & 3) << 4);
+    }
+    pub const fn UdpChecksum(&self) -> u32 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_UdpChecksum(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+}
 impl windows_core::TypeKind for NDIS_TCP_IP_CHECKSUM_OFFLOAD_3 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3889,7 +4234,8 @@ impl Default for NDIS_TCP_IP_CHECKSUM_OFFLOAD_3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TCP_LARGE_SEND_OFFLOAD_V1 {
     pub IPv4: NDIS_TCP_LARGE_SEND_OFFLOAD_V1_0,
 }
@@ -3902,13 +4248,28 @@ impl Default for NDIS_TCP_LARGE_SEND_OFFLOAD_V1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TCP_LARGE_SEND_OFFLOAD_V1_0 {
     pub Encapsulation: u32,
     pub MaxOffLoadSize: u32,
     pub MinSegmentCount: u32,
     pub _bitfield: u32,
 }
+impl NDIS_TCP_LARGE_SEND_OFFLOAD_V1_0 {
+    pub const fn TcpOptions(&self) -> u32 {
+        self._bitfield & 3
+    }
+    pub fn set_TcpOptions(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn IpOptions(&self) -> u32 {
+        (self._bitfield >> 2) & 3
+    }
+    pub fn set_IpOptions(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 2)) | ((value & 3) << 2);
+    }
+}
 impl windows_core::TypeKind for NDIS_TCP_LARGE_SEND_OFFLOAD_V1_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3918,7 +4279,8 @@ impl Default for NDIS_TCP_LARGE_SEND_OFFLOAD_V1_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TCP_LARGE_SEND_OFFLOAD_V2 {
     pub IPv4: NDIS_TCP_LARGE_SEND_OFFLOAD_V2_0,
     pub IPv6: NDIS_TCP_LARGE_SEND_OFFLOAD_V2_1,
@@ -3932,7 +4294,8 @@ impl Default for NDIS_TCP_LARGE_SEND_OFFLOAD_V2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TCP_LARGE_SEND_OFFLOAD_V2_0 {
     pub Encapsulation: u32,
     pub MaxOffLoadSize: u32,
@@ -3947,13 +4310,28 @@ impl Default for NDIS_TCP_LARGE_SEND_OFFLOAD_V2_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TCP_LARGE_SEND_OFFLOAD_V2_1 {
     pub Encapsulation: u32,
     pub MaxOffLoadSize: u32,
     pub MinSegmentCount: u32,
     pub _bitfield: u32,
 }
+impl NDIS_TCP_LARGE_SEND_OFFLOAD_V2_1 {
+    pub const fn IpExtensionHeadersSupported(&self) -> u32 {
+        self._bitfield & 3
+    }
+    pub fn set_IpExtensionHeadersSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn TcpOptionsSupported(&self) -> u32 {
+        (self._bitfield >> 2) & 3
+    }
+    pub fn set_TcpOptionsSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 2)) | ((value & 3) << 2);
+    }
+}
 impl windows_core::TypeKind for NDIS_TCP_LARGE_SEND_OFFLOAD_V2_1 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3963,7 +4341,8 @@ impl Default for NDIS_TCP_LARGE_SEND_OFFLOAD_V2_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TIMEOUT_DPC_REQUEST_CAPABILITIES {
     pub Header: NDIS_OBJECT_HEADER,
     pub Flags: u32,
@@ -3979,7 +4358,8 @@ impl Default for NDIS_TIMEOUT_DPC_REQUEST_CAPABILITIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TIMESTAMP_CAPABILITIES {
     pub Header: NDIS_OBJECT_HEADER,
     pub HardwareClockFrequencyHz: u64,
@@ -3997,7 +4377,8 @@ impl Default for NDIS_TIMESTAMP_CAPABILITIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_TIMESTAMP_CAPABILITY_FLAGS {
     pub PtpV2OverUdpIPv4EventMsgReceiveHw: super::super::Foundation::BOOLEAN,
     pub PtpV2OverUdpIPv4AllMsgReceiveHw: super::super::Foundation::BOOLEAN,
@@ -4023,7 +4404,8 @@ impl Default for NDIS_TIMESTAMP_CAPABILITY_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_VAR_DATA_DESC {
     pub Length: u16,
     pub MaximumLength: u16,
@@ -4038,7 +4420,8 @@ impl Default for NDIS_VAR_DATA_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WAN_PROTOCOL_CAPS {
     pub Flags: u32,
     pub Reserved: u32,
@@ -4052,7 +4435,8 @@ impl Default for NDIS_WAN_PROTOCOL_CAPS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WLAN_BSSID {
     pub Length: u32,
     pub MacAddress: [u8; 6],
@@ -4074,7 +4458,8 @@ impl Default for NDIS_WLAN_BSSID {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WLAN_BSSID_EX {
     pub Length: u32,
     pub MacAddress: [u8; 6],
@@ -4135,7 +4520,8 @@ impl Default for NDIS_WMI_EVENT_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_IPSEC_OFFLOAD_V1 {
     pub Supported: NDIS_WMI_IPSEC_OFFLOAD_V1_2,
     pub IPv4AH: NDIS_WMI_IPSEC_OFFLOAD_V1_0,
@@ -4150,7 +4536,8 @@ impl Default for NDIS_WMI_IPSEC_OFFLOAD_V1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_IPSEC_OFFLOAD_V1_0 {
     pub Md5: u32,
     pub Sha_1: u32,
@@ -4168,7 +4555,8 @@ impl Default for NDIS_WMI_IPSEC_OFFLOAD_V1_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_IPSEC_OFFLOAD_V1_1 {
     pub Des: u32,
     pub Reserved: u32,
@@ -4188,7 +4576,8 @@ impl Default for NDIS_WMI_IPSEC_OFFLOAD_V1_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_IPSEC_OFFLOAD_V1_2 {
     pub Encapsulation: u32,
     pub AhEspCombined: u32,
@@ -4223,7 +4612,8 @@ impl Default for NDIS_WMI_METHOD_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_OFFLOAD {
     pub Header: NDIS_OBJECT_HEADER,
     pub Checksum: NDIS_WMI_TCP_IP_CHECKSUM_OFFLOAD,
@@ -4241,7 +4631,8 @@ impl Default for NDIS_WMI_OFFLOAD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_OUTPUT_INFO {
     pub Header: NDIS_OBJECT_HEADER,
     pub Flags: u32,
@@ -4275,7 +4666,8 @@ impl Default for NDIS_WMI_SET_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_TCP_CONNECTION_OFFLOAD {
     pub Header: NDIS_OBJECT_HEADER,
     pub Encapsulation: u32,
@@ -4295,7 +4687,8 @@ impl Default for NDIS_WMI_TCP_CONNECTION_OFFLOAD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_TCP_IP_CHECKSUM_OFFLOAD {
     pub IPv4Transmit: NDIS_WMI_TCP_IP_CHECKSUM_OFFLOAD_1,
     pub IPv4Receive: NDIS_WMI_TCP_IP_CHECKSUM_OFFLOAD_0,
@@ -4311,7 +4704,8 @@ impl Default for NDIS_WMI_TCP_IP_CHECKSUM_OFFLOAD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_TCP_IP_CHECKSUM_OFFLOAD_0 {
     pub Encapsulation: u32,
     pub IpOptionsSupported: u32,
@@ -4329,7 +4723,8 @@ impl Default for NDIS_WMI_TCP_IP_CHECKSUM_OFFLOAD_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_TCP_IP_CHECKSUM_OFFLOAD_1 {
     pub Encapsulation: u32,
     pub IpOptionsSupported: u32,
@@ -4347,7 +4742,8 @@ impl Default for NDIS_WMI_TCP_IP_CHECKSUM_OFFLOAD_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_TCP_IP_CHECKSUM_OFFLOAD_2 {
     pub Encapsulation: u32,
     pub IpExtensionHeadersSupported: u32,
@@ -4364,7 +4760,8 @@ impl Default for NDIS_WMI_TCP_IP_CHECKSUM_OFFLOAD_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_TCP_IP_CHECKSUM_OFFLOAD_3 {
     pub Encapsulation: u32,
     pub IpExtensionHeadersSupported: u32,
@@ -4381,7 +4778,8 @@ impl Default for NDIS_WMI_TCP_IP_CHECKSUM_OFFLOAD_3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_TCP_LARGE_SEND_OFFLOAD_V1 {
     pub IPv4: NDIS_WMI_TCP_LARGE_SEND_OFFLOAD_V1_0,
 }
@@ -4394,7 +4792,8 @@ impl Default for NDIS_WMI_TCP_LARGE_SEND_OFFLOAD_V1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_TCP_LARGE_SEND_OFFLOAD_V1_0 {
     pub Encapsulation: u32,
     pub MaxOffLoadSize: u32,
@@ -4411,7 +4810,8 @@ impl Default for NDIS_WMI_TCP_LARGE_SEND_OFFLOAD_V1_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_TCP_LARGE_SEND_OFFLOAD_V2 {
     pub IPv4: NDIS_WMI_TCP_LARGE_SEND_OFFLOAD_V2_0,
     pub IPv6: NDIS_WMI_TCP_LARGE_SEND_OFFLOAD_V2_1,
@@ -4425,7 +4825,8 @@ impl Default for NDIS_WMI_TCP_LARGE_SEND_OFFLOAD_V2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_TCP_LARGE_SEND_OFFLOAD_V2_0 {
     pub Encapsulation: u32,
     pub MaxOffLoadSize: u32,
@@ -4440,7 +4841,8 @@ impl Default for NDIS_WMI_TCP_LARGE_SEND_OFFLOAD_V2_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDIS_WMI_TCP_LARGE_SEND_OFFLOAD_V2_1 {
     pub Encapsulation: u32,
     pub MaxOffLoadSize: u32,
@@ -4457,7 +4859,8 @@ impl Default for NDIS_WMI_TCP_LARGE_SEND_OFFLOAD_V2_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDK_ADAPTER_INFO {
     pub Version: NDK_VERSION,
     pub VendorId: u32,
@@ -4491,7 +4894,8 @@ impl Default for NDK_ADAPTER_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDK_VERSION {
     pub Major: u16,
     pub Minor: u16,
@@ -4505,7 +4909,8 @@ impl Default for NDK_VERSION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NETWORK_ADDRESS {
     pub AddressLength: u16,
     pub AddressType: u16,
@@ -4520,7 +4925,8 @@ impl Default for NETWORK_ADDRESS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NETWORK_ADDRESS_IP {
     pub sin_port: u16,
     pub IN_ADDR: u32,
@@ -4535,7 +4941,8 @@ impl Default for NETWORK_ADDRESS_IP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NETWORK_ADDRESS_IP6 {
     pub sin6_port: u16,
     pub sin6_flowinfo: u32,
@@ -4551,7 +4958,8 @@ impl Default for NETWORK_ADDRESS_IP6 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NETWORK_ADDRESS_IPX {
     pub NetworkAddress: u32,
     pub NodeAddress: [u8; 6],
@@ -4566,7 +4974,8 @@ impl Default for NETWORK_ADDRESS_IPX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NETWORK_ADDRESS_LIST {
     pub AddressCount: i32,
     pub AddressType: u16,
@@ -4581,7 +4990,8 @@ impl Default for NETWORK_ADDRESS_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NET_IF_ALIAS_LH {
     pub ifAliasLength: u16,
     pub ifAliasOffset: u16,
@@ -4595,7 +5005,8 @@ impl Default for NET_IF_ALIAS_LH {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NET_IF_RCV_ADDRESS_LH {
     pub ifRcvAddressType: NET_IF_RCV_ADDRESS_TYPE,
     pub ifRcvAddressLength: u16,
@@ -4624,10 +5035,31 @@ impl Default for NET_LUID_LH {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NET_LUID_LH_0 {
     pub _bitfield: u64,
 }
+impl NET_LUID_LH_0 {
+    pub const fn Reserved(&self) -> u64 {
+        self._bitfield & 16777215
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !16777215) | (value & 16777215);
+    }
+    pub const fn NetLuidIndex(&self) -> u64 {
+        (self._bitfield >> 24) & 16777215
+    }
+    pub fn set_NetLuidIndex(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(16777215 << 24)) | ((value & 16777215) << 24);
+    }
+    pub const fn IfType(&self) -> u64 {
+        (self._bitfield >> 48) & 65535
+    }
+    pub fn set_IfType(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(65535 << 48)) | ((value & 65535) << 48);
+    }
+}
 impl windows_core::TypeKind for NET_LUID_LH_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4637,7 +5069,8 @@ impl Default for NET_LUID_LH_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NET_PHYSICAL_LOCATION_LH {
     pub BusNumber: u32,
     pub SlotNumber: u32,
@@ -4652,7 +5085,8 @@ impl Default for NET_PHYSICAL_LOCATION_LH {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OFFLOAD_ALGO_INFO {
     pub algoIdentifier: u32,
     pub algoKeylen: u32,
@@ -4667,7 +5101,8 @@ impl Default for OFFLOAD_ALGO_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OFFLOAD_IPSEC_ADD_SA {
     pub SrcAddr: u32,
     pub SrcMask: u32,
@@ -4694,7 +5129,8 @@ impl Default for OFFLOAD_IPSEC_ADD_SA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OFFLOAD_IPSEC_ADD_UDPESP_SA {
     pub SrcAddr: u32,
     pub SrcMask: u32,
@@ -4723,7 +5159,8 @@ impl Default for OFFLOAD_IPSEC_ADD_UDPESP_SA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OFFLOAD_IPSEC_DELETE_SA {
     pub OffloadHandle: super::super::Foundation::HANDLE,
 }
@@ -4736,7 +5173,8 @@ impl Default for OFFLOAD_IPSEC_DELETE_SA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OFFLOAD_IPSEC_DELETE_UDPESP_SA {
     pub OffloadHandle: super::super::Foundation::HANDLE,
     pub EncapTypeEntryOffldHandle: super::super::Foundation::HANDLE,
@@ -4750,7 +5188,8 @@ impl Default for OFFLOAD_IPSEC_DELETE_UDPESP_SA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OFFLOAD_IPSEC_UDPESP_ENCAPTYPE_ENTRY {
     pub UdpEncapType: UDP_ENCAP_TYPE,
     pub DstEncapPort: u16,
@@ -4764,7 +5203,8 @@ impl Default for OFFLOAD_IPSEC_UDPESP_ENCAPTYPE_ENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OFFLOAD_SECURITY_ASSOCIATION {
     pub Operation: OFFLOAD_OPERATION_E,
     pub SPI: u32,
@@ -4781,7 +5221,8 @@ impl Default for OFFLOAD_SECURITY_ASSOCIATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PMKID_CANDIDATE {
     pub BSSID: [u8; 6],
     pub Flags: u32,
@@ -4795,7 +5236,8 @@ impl Default for PMKID_CANDIDATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TRANSPORT_HEADER_OFFSET {
     pub ProtocolType: u16,
     pub HeaderOffset: u16,
@@ -0,0 +1,589 @@
+impl windows_core::imp::ProxyVtable for IEnumNetCfgBindingInterface_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetCfgBindingInterface", "Next", 3usize, |this| ((**(this as *const *const IEnumNetCfgBindingInterface_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetCfgBindingInterface", "Skip", 4usize, |this| ((**(this as *const *const IEnumNetCfgBindingInterface_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetCfgBindingInterface", "Reset", 5usize, |this| ((**(this as *const *const IEnumNetCfgBindingInterface_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetCfgBindingInterface", "Clone", 6usize, |this| ((**(this as *const *const IEnumNetCfgBindingInterface_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumNetCfgBindingInterface as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumNetCfgBindingPath_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetCfgBindingPath", "Next", 3usize, |this| ((**(this as *const *const IEnumNetCfgBindingPath_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetCfgBindingPath", "Skip", 4usize, |this| ((**(this as *const *const IEnumNetCfgBindingPath_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetCfgBindingPath", "Reset", 5usize, |this| ((**(this as *const *const IEnumNetCfgBindingPath_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetCfgBindingPath", "Clone", 6usize, |this| ((**(this as *const *const IEnumNetCfgBindingPath_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumNetCfgBindingPath as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumNetCfgComponent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetCfgComponent", "Next", 3usize, |this| ((**(this as *const *const IEnumNetCfgComponent_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetCfgComponent", "Skip", 4usize, |this| ((**(this as *const *const IEnumNetCfgComponent_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetCfgComponent", "Reset", 5usize, |this| ((**(this as *const *const IEnumNetCfgComponent_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetCfgComponent", "Clone", 6usize, |this| ((**(this as *const *const IEnumNetCfgComponent_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumNetCfgComponent as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetCfg_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pvreserved: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfg", "Initialize", 3usize, |this| ((**(this as *const *const INetCfg_Vtbl)).Initialize)(this, pvreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uninitialize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfg", "Uninitialize", 4usize, |this| ((**(this as *const *const INetCfg_Vtbl)).Uninitialize)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Apply(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfg", "Apply", 5usize, |this| ((**(this as *const *const INetCfg_Vtbl)).Apply)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfg", "Cancel", 6usize, |this| ((**(this as *const *const INetCfg_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumComponents(this: *mut core::ffi::c_void, pguidclass: *const windows_core::GUID, ppenumcomponent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfg", "EnumComponents", 7usize, |this| ((**(this as *const *const INetCfg_Vtbl)).EnumComponents)(this, pguidclass, ppenumcomponent), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindComponent(this: *mut core::ffi::c_void, pszwinfid: windows_core::PCWSTR, pcomponent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfg", "FindComponent", 8usize, |this| ((**(this as *const *const INetCfg_Vtbl)).FindComponent)(this, pszwinfid, pcomponent), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryNetCfgClass(this: *mut core::ffi::c_void, pguidclass: *const windows_core::GUID, riid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfg", "QueryNetCfgClass", 9usize, |this| ((**(this as *const *const INetCfg_Vtbl)).QueryNetCfgClass)(this, pguidclass, riid, ppvobject), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            Uninitialize,
+            Apply,
+            Cancel,
+            EnumComponents,
+            FindComponent,
+            QueryNetCfgClass,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetCfg as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetCfgBindingInterface_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, ppszwinterfacename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgBindingInterface", "GetName", 3usize, |this| ((**(this as *const *const INetCfgBindingInterface_Vtbl)).GetName)(this, ppszwinterfacename), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUpperComponent(this: *mut core::ffi::c_void, ppnccitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgBindingInterface", "GetUpperComponent", 4usize, |this| ((**(this as *const *const INetCfgBindingInterface_Vtbl)).GetUpperComponent)(this, ppnccitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLowerComponent(this: *mut core::ffi::c_void, ppnccitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgBindingInterface", "GetLowerComponent", 5usize, |this| ((**(this as *const *const INetCfgBindingInterface_Vtbl)).GetLowerComponent)(this, ppnccitem), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetName, GetUpperComponent, GetLowerComponent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetCfgBindingInterface as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetCfgBindingPath_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSamePathAs(this: *mut core::ffi::c_void, ppath: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgBindingPath", "IsSamePathAs", 3usize, |this| ((**(this as *const *const INetCfgBindingPath_Vtbl)).IsSamePathAs)(this, ppath), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSubPathOf(this: *mut core::ffi::c_void, ppath: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgBindingPath", "IsSubPathOf", 4usize, |this| ((**(this as *const *const INetCfgBindingPath_Vtbl)).IsSubPathOf)(this, ppath), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgBindingPath", "IsEnabled", 5usize, |this| ((**(this as *const *const INetCfgBindingPath_Vtbl)).IsEnabled)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enable(this: *mut core::ffi::c_void, fenable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgBindingPath", "Enable", 6usize, |this| ((**(this as *const *const INetCfgBindingPath_Vtbl)).Enable)(this, fenable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPathToken(this: *mut core::ffi::c_void, ppszwpathtoken: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgBindingPath", "GetPathToken", 7usize, |this| ((**(this as *const *const INetCfgBindingPath_Vtbl)).GetPathToken)(this, ppszwpathtoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOwner(this: *mut core::ffi::c_void, ppcomponent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgBindingPath", "GetOwner", 8usize, |this| ((**(this as *const *const INetCfgBindingPath_Vtbl)).GetOwner)(this, ppcomponent), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDepth(this: *mut core::ffi::c_void, pcinterfaces: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgBindingPath", "GetDepth", 9usize, |this| ((**(this as *const *const INetCfgBindingPath_Vtbl)).GetDepth)(this, pcinterfaces), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumBindingInterfaces(this: *mut core::ffi::c_void, ppenuminterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgBindingPath", "EnumBindingInterfaces", 10usize, |this| ((**(this as *const *const INetCfgBindingPath_Vtbl)).EnumBindingInterfaces)(this, ppenuminterface), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsSamePathAs,
+            IsSubPathOf,
+            IsEnabled,
+            Enable,
+            GetPathToken,
+            GetOwner,
+            GetDepth,
+            EnumBindingInterfaces,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetCfgBindingPath as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetCfgClass_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FindComponent(this: *mut core::ffi::c_void, pszwinfid: windows_core::PCWSTR, ppnccitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgClass", "FindComponent", 3usize, |this| ((**(this as *const *const INetCfgClass_Vtbl)).FindComponent)(this, pszwinfid, ppnccitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumComponents(this: *mut core::ffi::c_void, ppenumcomponent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgClass", "EnumComponents", 4usize, |this| ((**(this as *const *const INetCfgClass_Vtbl)).EnumComponents)(this, ppenumcomponent), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FindComponent, EnumComponents }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetCfgClass as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetCfgClassSetup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SelectAndInstall(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, pobotoken: *const OBO_TOKEN, ppnccitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgClassSetup", "SelectAndInstall", 3usize, |this| ((**(this as *const *const INetCfgClassSetup_Vtbl)).SelectAndInstall)(this, hwndparent, pobotoken, ppnccitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn Install(this: *mut core::ffi::c_void, pszwinfid: windows_core::PCWSTR, pobotoken: *const OBO_TOKEN, dwsetupflags: u32, dwupgradefrombuildno: u32, pszwanswerfile: windows_core::PCWSTR, pszwanswersections: windows_core::PCWSTR, ppnccitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgClassSetup", "Install", 4usize, |this| ((**(this as *const *const INetCfgClassSetup_Vtbl)).Install)(this, pszwinfid, pobotoken, dwsetupflags, dwupgradefrombuildno, pszwanswerfile, pszwanswersections, ppnccitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeInstall(this: *mut core::ffi::c_void, pcomponent: *mut core::ffi::c_void, pobotoken: *const OBO_TOKEN, pmszwrefs: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgClassSetup", "DeInstall", 5usize, |this| ((**(this as *const *const INetCfgClassSetup_Vtbl)).DeInstall)(this, pcomponent, pobotoken, pmszwrefs), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SelectAndInstall, Install, DeInstall }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetCfgClassSetup as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetCfgClassSetup2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UpdateNonEnumeratedComponent(this: *mut core::ffi::c_void, picomp: *mut core::ffi::c_void, dwsetupflags: u32, dwupgradefrombuildno: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgClassSetup2", "UpdateNonEnumeratedComponent", 6usize, |this| ((**(this as *const *const INetCfgClassSetup2_Vtbl)).UpdateNonEnumeratedComponent)(this, picomp, dwsetupflags, dwupgradefrombuildno), |result| Some(*result))
+        }
+        Self { base__: <INetCfgClassSetup_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UpdateNonEnumeratedComponent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetCfgClassSetup2 as windows_core::Interface>::IID || <INetCfgClassSetup_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Registry")]
+impl windows_core::imp::ProxyVtable for INetCfgComponent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDisplayName(this: *mut core::ffi::c_void, ppszwdisplayname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponent", "GetDisplayName", 3usize, |this| ((**(this as *const *const INetCfgComponent_Vtbl)).GetDisplayName)(this, ppszwdisplayname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, pszwdisplayname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponent", "SetDisplayName", 4usize, |this| ((**(this as *const *const INetCfgComponent_Vtbl)).SetDisplayName)(this, pszwdisplayname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHelpText(this: *mut core::ffi::c_void, pszwhelptext: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponent", "GetHelpText", 5usize, |this| ((**(this as *const *const INetCfgComponent_Vtbl)).GetHelpText)(this, pszwhelptext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetId(this: *mut core::ffi::c_void, ppszwid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponent", "GetId", 6usize, |this| ((**(this as *const *const INetCfgComponent_Vtbl)).GetId)(this, ppszwid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCharacteristics(this: *mut core::ffi::c_void, pdwcharacteristics: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponent", "GetCharacteristics", 7usize, |this| ((**(this as *const *const INetCfgComponent_Vtbl)).GetCharacteristics)(this, pdwcharacteristics), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInstanceGuid(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponent", "GetInstanceGuid", 8usize, |this| ((**(this as *const *const INetCfgComponent_Vtbl)).GetInstanceGuid)(this, pguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPnpDevNodeId(this: *mut core::ffi::c_void, ppszwdevnodeid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponent", "GetPnpDevNodeId", 9usize, |this| ((**(this as *const *const INetCfgComponent_Vtbl)).GetPnpDevNodeId)(this, ppszwdevnodeid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClassGuid(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponent", "GetClassGuid", 10usize, |this| ((**(this as *const *const INetCfgComponent_Vtbl)).GetClassGuid)(this, pguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBindName(this: *mut core::ffi::c_void, ppszwbindname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponent", "GetBindName", 11usize, |this| ((**(this as *const *const INetCfgComponent_Vtbl)).GetBindName)(this, ppszwbindname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceStatus(this: *mut core::ffi::c_void, pulstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponent", "GetDeviceStatus", 12usize, |this| ((**(this as *const *const INetCfgComponent_Vtbl)).GetDeviceStatus)(this, pulstatus), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Registry")]
+        unsafe extern "system" fn OpenParamKey(this: *mut core::ffi::c_void, phkey: *mut super::super::System::Registry::HKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponent", "OpenParamKey", 13usize, |this| ((**(this as *const *const INetCfgComponent_Vtbl)).OpenParamKey)(this, phkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn RaisePropertyUi(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, dwflags: u32, punkcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponent", "RaisePropertyUi", 14usize, |this| ((**(this as *const *const INetCfgComponent_Vtbl)).RaisePropertyUi)(this, hwndparent, dwflags, punkcontext), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDisplayName,
+            SetDisplayName,
+            GetHelpText,
+            GetId,
+            GetCharacteristics,
+            GetInstanceGuid,
+            GetPnpDevNodeId,
+            GetClassGuid,
+            GetBindName,
+            GetDeviceStatus,
+            #[cfg(feature = "Win32_System_Registry")]
+            OpenParamKey,
+            #[cfg(not(feature = "Win32_System_Registry"))]
+            OpenParamKey: 0,
+            RaisePropertyUi,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetCfgComponent as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetCfgComponentBindings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BindTo(this: *mut core::ffi::c_void, pnccitem: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentBindings", "BindTo", 3usize, |this| ((**(this as *const *const INetCfgComponentBindings_Vtbl)).BindTo)(this, pnccitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnbindFrom(this: *mut core::ffi::c_void, pnccitem: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentBindings", "UnbindFrom", 4usize, |this| ((**(this as *const *const INetCfgComponentBindings_Vtbl)).UnbindFrom)(this, pnccitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportsBindingInterface(this: *mut core::ffi::c_void, dwflags: u32, pszwinterfacename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentBindings", "SupportsBindingInterface", 5usize, |this| ((**(this as *const *const INetCfgComponentBindings_Vtbl)).SupportsBindingInterface)(this, dwflags, pszwinterfacename), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsBoundTo(this: *mut core::ffi::c_void, pnccitem: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentBindings", "IsBoundTo", 6usize, |this| ((**(this as *const *const INetCfgComponentBindings_Vtbl)).IsBoundTo)(this, pnccitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsBindableTo(this: *mut core::ffi::c_void, pnccitem: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentBindings", "IsBindableTo", 7usize, |this| ((**(this as *const *const INetCfgComponentBindings_Vtbl)).IsBindableTo)(this, pnccitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumBindingPaths(this: *mut core::ffi::c_void, dwflags: u32, ppienum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentBindings", "EnumBindingPaths", 8usize, |this| ((**(this as *const *const INetCfgComponentBindings_Vtbl)).EnumBindingPaths)(this, dwflags, ppienum), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveBefore(this: *mut core::ffi::c_void, pncbitemsrc: *mut core::ffi::c_void, pncbitemdest: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentBindings", "MoveBefore", 9usize, |this| ((**(this as *const *const INetCfgComponentBindings_Vtbl)).MoveBefore)(this, pncbitemsrc, pncbitemdest), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveAfter(this: *mut core::ffi::c_void, pncbitemsrc: *mut core::ffi::c_void, pncbitemdest: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentBindings", "MoveAfter", 10usize, |this| ((**(this as *const *const INetCfgComponentBindings_Vtbl)).MoveAfter)(this, pncbitemsrc, pncbitemdest), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BindTo,
+            UnbindFrom,
+            SupportsBindingInterface,
+            IsBoundTo,
+            IsBindableTo,
+            EnumBindingPaths,
+            MoveBefore,
+            MoveAfter,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetCfgComponentBindings as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetCfgComponentControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, picomp: *mut core::ffi::c_void, pinetcfg: *mut core::ffi::c_void, finstalling: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentControl", "Initialize", 3usize, |this| ((**(this as *const *const INetCfgComponentControl_Vtbl)).Initialize)(this, picomp, pinetcfg, finstalling), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplyRegistryChanges(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentControl", "ApplyRegistryChanges", 4usize, |this| ((**(this as *const *const INetCfgComponentControl_Vtbl)).ApplyRegistryChanges)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplyPnpChanges(this: *mut core::ffi::c_void, picallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentControl", "ApplyPnpChanges", 5usize, |this| ((**(this as *const *const INetCfgComponentControl_Vtbl)).ApplyPnpChanges)(this, picallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelChanges(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentControl", "CancelChanges", 6usize, |this| ((**(this as *const *const INetCfgComponentControl_Vtbl)).CancelChanges)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, ApplyRegistryChanges, ApplyPnpChanges, CancelChanges }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetCfgComponentControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetCfgComponentNotifyBinding_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryBindingPath(this: *mut core::ffi::c_void, dwchangeflag: u32, pipath: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentNotifyBinding", "QueryBindingPath", 3usize, |this| ((**(this as *const *const INetCfgComponentNotifyBinding_Vtbl)).QueryBindingPath)(this, dwchangeflag, pipath), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyBindingPath(this: *mut core::ffi::c_void, dwchangeflag: u32, pipath: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentNotifyBinding", "NotifyBindingPath", 4usize, |this| ((**(this as *const *const INetCfgComponentNotifyBinding_Vtbl)).NotifyBindingPath)(this, dwchangeflag, pipath), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryBindingPath, NotifyBindingPath }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetCfgComponentNotifyBinding as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetCfgComponentNotifyGlobal_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSupportedNotifications(this: *mut core::ffi::c_void, dwnotifications: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentNotifyGlobal", "GetSupportedNotifications", 3usize, |this| ((**(this as *const *const INetCfgComponentNotifyGlobal_Vtbl)).GetSupportedNotifications)(this, dwnotifications), |result| Some(*result))
+        }
+        unsafe extern "system" fn SysQueryBindingPath(this: *mut core::ffi::c_void, dwchangeflag: u32, pipath: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentNotifyGlobal", "SysQueryBindingPath", 4usize, |this| ((**(this as *const *const INetCfgComponentNotifyGlobal_Vtbl)).SysQueryBindingPath)(this, dwchangeflag, pipath), |result| Some(*result))
+        }
+        unsafe extern "system" fn SysNotifyBindingPath(this: *mut core::ffi::c_void, dwchangeflag: u32, pipath: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentNotifyGlobal", "SysNotifyBindingPath", 5usize, |this| ((**(this as *const *const INetCfgComponentNotifyGlobal_Vtbl)).SysNotifyBindingPath)(this, dwchangeflag, pipath), |result| Some(*result))
+        }
+        unsafe extern "system" fn SysNotifyComponent(this: *mut core::ffi::c_void, dwchangeflag: u32, picomp: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentNotifyGlobal", "SysNotifyComponent", 6usize, |this| ((**(this as *const *const INetCfgComponentNotifyGlobal_Vtbl)).SysNotifyComponent)(this, dwchangeflag, picomp), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSupportedNotifications,
+            SysQueryBindingPath,
+            SysNotifyBindingPath,
+            SysNotifyComponent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetCfgComponentNotifyGlobal as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetCfgComponentPropertyUi_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryPropertyUi(this: *mut core::ffi::c_void, punkreserved: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentPropertyUi", "QueryPropertyUi", 3usize, |this| ((**(this as *const *const INetCfgComponentPropertyUi_Vtbl)).QueryPropertyUi)(this, punkreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContext(this: *mut core::ffi::c_void, punkreserved: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentPropertyUi", "SetContext", 4usize, |this| ((**(this as *const *const INetCfgComponentPropertyUi_Vtbl)).SetContext)(this, punkreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn MergePropPages(this: *mut core::ffi::c_void, pdwdefpages: *mut u32, pahpspprivate: *mut *mut u8, pcpages: *mut u32, hwndparent: super::super::Foundation::HWND, pszstartpage: *const windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentPropertyUi", "MergePropPages", 5usize, |this| ((**(this as *const *const INetCfgComponentPropertyUi_Vtbl)).MergePropPages)(this, pdwdefpages, pahpspprivate, pcpages, hwndparent, pszstartpage), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValidateProperties(this: *mut core::ffi::c_void, hwndsheet: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentPropertyUi", "ValidateProperties", 6usize, |this| ((**(this as *const *const INetCfgComponentPropertyUi_Vtbl)).ValidateProperties)(this, hwndsheet), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplyProperties(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentPropertyUi", "ApplyProperties", 7usize, |this| ((**(this as *const *const INetCfgComponentPropertyUi_Vtbl)).ApplyProperties)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelProperties(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentPropertyUi", "CancelProperties", 8usize, |this| ((**(this as *const *const INetCfgComponentPropertyUi_Vtbl)).CancelProperties)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            QueryPropertyUi,
+            SetContext,
+            MergePropPages,
+            ValidateProperties,
+            ApplyProperties,
+            CancelProperties,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetCfgComponentPropertyUi as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetCfgComponentSetup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Install(this: *mut core::ffi::c_void, dwsetupflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentSetup", "Install", 3usize, |this| ((**(this as *const *const INetCfgComponentSetup_Vtbl)).Install)(this, dwsetupflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Upgrade(this: *mut core::ffi::c_void, dwsetupflags: u32, dwupgradefombuildno: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentSetup", "Upgrade", 4usize, |this| ((**(this as *const *const INetCfgComponentSetup_Vtbl)).Upgrade)(this, dwsetupflags, dwupgradefombuildno), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadAnswerFile(this: *mut core::ffi::c_void, pszwanswerfile: windows_core::PCWSTR, pszwanswersections: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentSetup", "ReadAnswerFile", 5usize, |this| ((**(this as *const *const INetCfgComponentSetup_Vtbl)).ReadAnswerFile)(this, pszwanswerfile, pszwanswersections), |result| Some(*result))
+        }
+        unsafe extern "system" fn Removing(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentSetup", "Removing", 6usize, |this| ((**(this as *const *const INetCfgComponentSetup_Vtbl)).Removing)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Install, Upgrade, ReadAnswerFile, Removing }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetCfgComponentSetup as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetCfgComponentSysPrep_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SaveAdapterParameters(this: *mut core::ffi::c_void, pncsp: *mut core::ffi::c_void, pszwanswersections: windows_core::PCWSTR, padapterinstanceguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentSysPrep", "SaveAdapterParameters", 3usize, |this| ((**(this as *const *const INetCfgComponentSysPrep_Vtbl)).SaveAdapterParameters)(this, pncsp, pszwanswersections, padapterinstanceguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestoreAdapterParameters(this: *mut core::ffi::c_void, pszwanswerfile: windows_core::PCWSTR, pszwanswersection: windows_core::PCWSTR, padapterinstanceguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentSysPrep", "RestoreAdapterParameters", 4usize, |this| ((**(this as *const *const INetCfgComponentSysPrep_Vtbl)).RestoreAdapterParameters)(this, pszwanswerfile, pszwanswersection, padapterinstanceguid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SaveAdapterParameters, RestoreAdapterParameters }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetCfgComponentSysPrep as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetCfgComponentUpperEdge_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInterfaceIdsForAdapter(this: *mut core::ffi::c_void, padapter: *mut core::ffi::c_void, pdwnuminterfaces: *mut u32, ppguidinterfaceids: *mut *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentUpperEdge", "GetInterfaceIdsForAdapter", 3usize, |this| ((**(this as *const *const INetCfgComponentUpperEdge_Vtbl)).GetInterfaceIdsForAdapter)(this, padapter, pdwnuminterfaces, ppguidinterfaceids), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddInterfacesToAdapter(this: *mut core::ffi::c_void, padapter: *mut core::ffi::c_void, dwnuminterfaces: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentUpperEdge", "AddInterfacesToAdapter", 4usize, |this| ((**(this as *const *const INetCfgComponentUpperEdge_Vtbl)).AddInterfacesToAdapter)(this, padapter, dwnuminterfaces), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveInterfacesFromAdapter(this: *mut core::ffi::c_void, padapter: *mut core::ffi::c_void, dwnuminterfaces: u32, pguidinterfaceids: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgComponentUpperEdge", "RemoveInterfacesFromAdapter", 5usize, |this| ((**(this as *const *const INetCfgComponentUpperEdge_Vtbl)).RemoveInterfacesFromAdapter)(this, padapter, dwnuminterfaces, pguidinterfaceids), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetInterfaceIdsForAdapter,
+            AddInterfacesToAdapter,
+            RemoveInterfacesFromAdapter,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetCfgComponentUpperEdge as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetCfgLock_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AcquireWriteLock(this: *mut core::ffi::c_void, cmstimeout: u32, pszwclientdescription: windows_core::PCWSTR, ppszwclientdescription: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgLock", "AcquireWriteLock", 3usize, |this| ((**(this as *const *const INetCfgLock_Vtbl)).AcquireWriteLock)(this, cmstimeout, pszwclientdescription, ppszwclientdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseWriteLock(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgLock", "ReleaseWriteLock", 4usize, |this| ((**(this as *const *const INetCfgLock_Vtbl)).ReleaseWriteLock)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsWriteLocked(this: *mut core::ffi::c_void, ppszwclientdescription: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgLock", "IsWriteLocked", 5usize, |this| ((**(this as *const *const INetCfgLock_Vtbl)).IsWriteLocked)(this, ppszwclientdescription), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AcquireWriteLock, ReleaseWriteLock, IsWriteLocked }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetCfgLock as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetCfgPnpReconfigCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SendPnpReconfig(this: *mut core::ffi::c_void, layer: NCPNP_RECONFIG_LAYER, pszwupper: windows_core::PCWSTR, pszwlower: windows_core::PCWSTR, pvdata: *const core::ffi::c_void, dwsizeofdata: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgPnpReconfigCallback", "SendPnpReconfig", 3usize, |this| ((**(this as *const *const INetCfgPnpReconfigCallback_Vtbl)).SendPnpReconfig)(this, layer, pszwupper, pszwlower, pvdata, dwsizeofdata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SendPnpReconfig }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetCfgPnpReconfigCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetCfgSysPrep_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HrSetupSetFirstDword(this: *mut core::ffi::c_void, pwszsection: windows_core::PCWSTR, pwszkey: windows_core::PCWSTR, dwvalue: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgSysPrep", "HrSetupSetFirstDword", 3usize, |this| ((**(this as *const *const INetCfgSysPrep_Vtbl)).HrSetupSetFirstDword)(this, pwszsection, pwszkey, dwvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn HrSetupSetFirstString(this: *mut core::ffi::c_void, pwszsection: windows_core::PCWSTR, pwszkey: windows_core::PCWSTR, pwszvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgSysPrep", "HrSetupSetFirstString", 4usize, |this| ((**(this as *const *const INetCfgSysPrep_Vtbl)).HrSetupSetFirstString)(this, pwszsection, pwszkey, pwszvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn HrSetupSetFirstStringAsBool(this: *mut core::ffi::c_void, pwszsection: windows_core::PCWSTR, pwszkey: windows_core::PCWSTR, fvalue: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgSysPrep", "HrSetupSetFirstStringAsBool", 5usize, |this| ((**(this as *const *const INetCfgSysPrep_Vtbl)).HrSetupSetFirstStringAsBool)(this, pwszsection, pwszkey, fvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn HrSetupSetFirstMultiSzField(this: *mut core::ffi::c_void, pwszsection: windows_core::PCWSTR, pwszkey: windows_core::PCWSTR, pmszvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetCfgSysPrep", "HrSetupSetFirstMultiSzField", 6usize, |this| ((**(this as *const *const INetCfgSysPrep_Vtbl)).HrSetupSetFirstMultiSzField)(this, pwszsection, pwszkey, pmszvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            HrSetupSetFirstDword,
+            HrSetupSetFirstString,
+            HrSetupSetFirstStringAsBool,
+            HrSetupSetFirstMultiSzField,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetCfgSysPrep as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetLanConnectionUiInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceGuid(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetLanConnectionUiInfo", "GetDeviceGuid", 3usize, |this| ((**(this as *const *const INetLanConnectionUiInfo_Vtbl)).GetDeviceGuid)(this, pguid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceGuid }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetLanConnectionUiInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetRasConnectionIpUiInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetUiInfo(this: *mut core::ffi::c_void, pinfo: *mut RASCON_IPUI) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetRasConnectionIpUiInfo", "GetUiInfo", 3usize, |this| ((**(this as *const *const INetRasConnectionIpUiInfo_Vtbl)).GetUiInfo)(this, pinfo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetUiInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetRasConnectionIpUiInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IProvisioningDomain_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pszwpathtofolder: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProvisioningDomain", "Add", 3usize, |this| ((**(this as *const *const IProvisioningDomain_Vtbl)).Add)(this, pszwpathtofolder), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn Query(this: *mut core::ffi::c_void, pszwdomain: windows_core::PCWSTR, pszwlanguage: windows_core::PCWSTR, pszwxpathquery: windows_core::PCWSTR, nodes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProvisioningDomain", "Query", 4usize, |this| ((**(this as *const *const IProvisioningDomain_Vtbl)).Query)(this, pszwdomain, pszwlanguage, pszwxpathquery, nodes), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Add,
+            #[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com"))]
+            Query,
+            #[cfg(not(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com")))]
+            Query: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProvisioningDomain as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProvisioningProfileWireless_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateProfile(this: *mut core::ffi::c_void, bstrxmlwirelessconfigprofile: core::mem::MaybeUninit<windows_core::BSTR>, bstrxmlconnectionconfigprofile: core::mem::MaybeUninit<windows_core::BSTR>, padapterinstanceguid: *const windows_core::GUID, pulstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProvisioningProfileWireless", "CreateProfile", 3usize, |this| ((**(this as *const *const IProvisioningProfileWireless_Vtbl)).CreateProfile)(this, bstrxmlwirelessconfigprofile, bstrxmlconnectionconfigprofile, padapterinstanceguid, pulstatus), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateProfile }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProvisioningProfileWireless as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
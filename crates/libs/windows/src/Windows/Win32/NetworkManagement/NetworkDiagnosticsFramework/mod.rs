@@ -382,6 +382,7 @@ pub struct ATTRIBUTE_TYPE(pub i32);
 impl windows_core::TypeKind for ATTRIBUTE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ATTRIBUTE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ATTRIBUTE_TYPE").field(&self.0).finish()
@@ -393,6 +394,7 @@ pub struct DIAGNOSIS_STATUS(pub i32);
 impl windows_core::TypeKind for DIAGNOSIS_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DIAGNOSIS_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DIAGNOSIS_STATUS").field(&self.0).finish()
@@ -404,6 +406,7 @@ pub struct PROBLEM_TYPE(pub i32);
 impl windows_core::TypeKind for PROBLEM_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROBLEM_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROBLEM_TYPE").field(&self.0).finish()
@@ -415,6 +418,7 @@ pub struct REPAIR_RISK(pub i32);
 impl windows_core::TypeKind for REPAIR_RISK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for REPAIR_RISK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("REPAIR_RISK").field(&self.0).finish()
@@ -426,6 +430,7 @@ pub struct REPAIR_SCOPE(pub i32);
 impl windows_core::TypeKind for REPAIR_SCOPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for REPAIR_SCOPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("REPAIR_SCOPE").field(&self.0).finish()
@@ -437,6 +442,7 @@ pub struct REPAIR_STATUS(pub i32);
 impl windows_core::TypeKind for REPAIR_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for REPAIR_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("REPAIR_STATUS").field(&self.0).finish()
@@ -448,13 +454,15 @@ pub struct UI_INFO_TYPE(pub i32);
 impl windows_core::TypeKind for UI_INFO_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_INFO_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_INFO_TYPE").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DIAG_SOCKADDR {
     pub family: u16,
     pub data: [i8; 126],
@@ -468,7 +476,8 @@ impl Default for DIAG_SOCKADDR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DiagnosticsInfo {
     pub cost: i32,
     pub flags: u32,
@@ -523,7 +532,8 @@ impl Default for HELPER_ATTRIBUTE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HYPOTHESIS {
     pub pwszClassName: windows_core::PWSTR,
     pub pwszDescription: windows_core::PWSTR,
@@ -539,7 +549,8 @@ impl Default for HYPOTHESIS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HelperAttributeInfo {
     pub pwszName: windows_core::PWSTR,
     pub r#type: ATTRIBUTE_TYPE,
@@ -553,7 +564,8 @@ impl Default for HelperAttributeInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HypothesisResult {
     pub hypothesis: HYPOTHESIS,
     pub pathStatus: DIAGNOSIS_STATUS,
@@ -567,7 +579,8 @@ impl Default for HypothesisResult {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LIFE_TIME {
     pub startTime: super::super::Foundation::FILETIME,
     pub endTime: super::super::Foundation::FILETIME,
@@ -581,7 +594,8 @@ impl Default for LIFE_TIME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OCTET_STRING {
     pub dwLength: u32,
     pub lpValue: *mut u8,
@@ -631,7 +645,8 @@ impl Default for RepairInfoEx {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RootCauseInfo {
     pub pwszDescription: windows_core::PWSTR,
     pub rootCauseID: windows_core::GUID,
@@ -649,7 +664,8 @@ impl Default for RootCauseInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ShellCommandInfo {
     pub pwszOperation: windows_core::PWSTR,
     pub pwszFile: windows_core::PWSTR,
@@ -697,3 +713,5 @@ impl Default for UiInfo_0 {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,137 @@
+impl windows_core::imp::ProxyVtable for INetDiagExtensibleHelper_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResolveAttributes(this: *mut core::ffi::c_void, celt: u32, rgkeyattributes: *const HELPER_ATTRIBUTE, pcelt: *mut u32, prgmatchvalues: *mut *mut HELPER_ATTRIBUTE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetDiagExtensibleHelper", "ResolveAttributes", 3usize, |this| ((**(this as *const *const INetDiagExtensibleHelper_Vtbl)).ResolveAttributes)(this, celt, rgkeyattributes, pcelt, prgmatchvalues), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ResolveAttributes }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetDiagExtensibleHelper as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetDiagHelper_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, celt: u32, rgattributes: *const HELPER_ATTRIBUTE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetDiagHelper", "Initialize", 3usize, |this| ((**(this as *const *const INetDiagHelper_Vtbl)).Initialize)(this, celt, rgattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDiagnosticsInfo(this: *mut core::ffi::c_void, ppinfo: *mut *mut DiagnosticsInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetDiagHelper", "GetDiagnosticsInfo", 4usize, |this| ((**(this as *const *const INetDiagHelper_Vtbl)).GetDiagnosticsInfo)(this, ppinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKeyAttributes(this: *mut core::ffi::c_void, pcelt: *mut u32, pprgattributes: *mut *mut HELPER_ATTRIBUTE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetDiagHelper", "GetKeyAttributes", 5usize, |this| ((**(this as *const *const INetDiagHelper_Vtbl)).GetKeyAttributes)(this, pcelt, pprgattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn LowHealth(this: *mut core::ffi::c_void, pwszinstancedescription: windows_core::PCWSTR, ppwszdescription: *mut windows_core::PWSTR, pdeferredtime: *mut i32, pstatus: *mut DIAGNOSIS_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetDiagHelper", "LowHealth", 6usize, |this| ((**(this as *const *const INetDiagHelper_Vtbl)).LowHealth)(this, pwszinstancedescription, ppwszdescription, pdeferredtime, pstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn HighUtilization(this: *mut core::ffi::c_void, pwszinstancedescription: windows_core::PCWSTR, ppwszdescription: *mut windows_core::PWSTR, pdeferredtime: *mut i32, pstatus: *mut DIAGNOSIS_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetDiagHelper", "HighUtilization", 7usize, |this| ((**(this as *const *const INetDiagHelper_Vtbl)).HighUtilization)(this, pwszinstancedescription, ppwszdescription, pdeferredtime, pstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLowerHypotheses(this: *mut core::ffi::c_void, pcelt: *mut u32, pprghypotheses: *mut *mut HYPOTHESIS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetDiagHelper", "GetLowerHypotheses", 8usize, |this| ((**(this as *const *const INetDiagHelper_Vtbl)).GetLowerHypotheses)(this, pcelt, pprghypotheses), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDownStreamHypotheses(this: *mut core::ffi::c_void, pcelt: *mut u32, pprghypotheses: *mut *mut HYPOTHESIS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetDiagHelper", "GetDownStreamHypotheses", 9usize, |this| ((**(this as *const *const INetDiagHelper_Vtbl)).GetDownStreamHypotheses)(this, pcelt, pprghypotheses), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHigherHypotheses(this: *mut core::ffi::c_void, pcelt: *mut u32, pprghypotheses: *mut *mut HYPOTHESIS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetDiagHelper", "GetHigherHypotheses", 10usize, |this| ((**(this as *const *const INetDiagHelper_Vtbl)).GetHigherHypotheses)(this, pcelt, pprghypotheses), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUpStreamHypotheses(this: *mut core::ffi::c_void, pcelt: *mut u32, pprghypotheses: *mut *mut HYPOTHESIS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetDiagHelper", "GetUpStreamHypotheses", 11usize, |this| ((**(this as *const *const INetDiagHelper_Vtbl)).GetUpStreamHypotheses)(this, pcelt, pprghypotheses), |result| Some(*result))
+        }
+        unsafe extern "system" fn Repair(this: *mut core::ffi::c_void, pinfo: *const RepairInfo, pdeferredtime: *mut i32, pstatus: *mut REPAIR_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetDiagHelper", "Repair", 12usize, |this| ((**(this as *const *const INetDiagHelper_Vtbl)).Repair)(this, pinfo, pdeferredtime, pstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn Validate(this: *mut core::ffi::c_void, problem: PROBLEM_TYPE, pdeferredtime: *mut i32, pstatus: *mut REPAIR_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetDiagHelper", "Validate", 13usize, |this| ((**(this as *const *const INetDiagHelper_Vtbl)).Validate)(this, problem, pdeferredtime, pstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRepairInfo(this: *mut core::ffi::c_void, problem: PROBLEM_TYPE, pcelt: *mut u32, ppinfo: *mut *mut RepairInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetDiagHelper", "GetRepairInfo", 14usize, |this| ((**(this as *const *const INetDiagHelper_Vtbl)).GetRepairInfo)(this, problem, pcelt, ppinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLifeTime(this: *mut core::ffi::c_void, plifetime: *mut LIFE_TIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetDiagHelper", "GetLifeTime", 15usize, |this| ((**(this as *const *const INetDiagHelper_Vtbl)).GetLifeTime)(this, plifetime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLifeTime(this: *mut core::ffi::c_void, lifetime: LIFE_TIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetDiagHelper", "SetLifeTime", 16usize, |this| ((**(this as *const *const INetDiagHelper_Vtbl)).SetLifeTime)(this, lifetime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCacheTime(this: *mut core::ffi::c_void, pcachetime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetDiagHelper", "GetCacheTime", 17usize, |this| ((**(this as *const *const INetDiagHelper_Vtbl)).GetCacheTime)(this, pcachetime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributes(this: *mut core::ffi::c_void, pcelt: *mut u32, pprgattributes: *mut *mut HELPER_ATTRIBUTE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetDiagHelper", "GetAttributes", 18usize, |this| ((**(this as *const *const INetDiagHelper_Vtbl)).GetAttributes)(this, pcelt, pprgattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetDiagHelper", "Cancel", 19usize, |this| ((**(this as *const *const INetDiagHelper_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cleanup(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetDiagHelper", "Cleanup", 20usize, |this| ((**(this as *const *const INetDiagHelper_Vtbl)).Cleanup)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetDiagnosticsInfo,
+            GetKeyAttributes,
+            LowHealth,
+            HighUtilization,
+            GetLowerHypotheses,
+            GetDownStreamHypotheses,
+            GetHigherHypotheses,
+            GetUpStreamHypotheses,
+            Repair,
+            Validate,
+            GetRepairInfo,
+            GetLifeTime,
+            SetLifeTime,
+            GetCacheTime,
+            GetAttributes,
+            Cancel,
+            Cleanup,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetDiagHelper as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetDiagHelperEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReconfirmLowHealth(this: *mut core::ffi::c_void, celt: u32, presults: *const HypothesisResult, ppwszupdateddescription: *mut windows_core::PWSTR, pupdatedstatus: *mut DIAGNOSIS_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetDiagHelperEx", "ReconfirmLowHealth", 3usize, |this| ((**(this as *const *const INetDiagHelperEx_Vtbl)).ReconfirmLowHealth)(this, celt, presults, ppwszupdateddescription, pupdatedstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUtilities(this: *mut core::ffi::c_void, putilities: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetDiagHelperEx", "SetUtilities", 4usize, |this| ((**(this as *const *const INetDiagHelperEx_Vtbl)).SetUtilities)(this, putilities), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReproduceFailure(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetDiagHelperEx", "ReproduceFailure", 5usize, |this| ((**(this as *const *const INetDiagHelperEx_Vtbl)).ReproduceFailure)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReconfirmLowHealth, SetUtilities, ReproduceFailure }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetDiagHelperEx as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetDiagHelperInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAttributeInfo(this: *mut core::ffi::c_void, pcelt: *mut u32, pprgattributeinfos: *mut *mut HelperAttributeInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetDiagHelperInfo", "GetAttributeInfo", 3usize, |this| ((**(this as *const *const INetDiagHelperInfo_Vtbl)).GetAttributeInfo)(this, pcelt, pprgattributeinfos), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAttributeInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetDiagHelperInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetDiagHelperUtilFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateUtilityInstance(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetDiagHelperUtilFactory", "CreateUtilityInstance", 3usize, |this| ((**(this as *const *const INetDiagHelperUtilFactory_Vtbl)).CreateUtilityInstance)(this, riid, ppvobject), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateUtilityInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetDiagHelperUtilFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
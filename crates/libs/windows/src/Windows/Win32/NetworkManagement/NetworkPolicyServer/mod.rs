@@ -978,6 +978,7 @@ pub struct ACCOUNTINGPROPERTIES(pub i32);
 impl windows_core::TypeKind for ACCOUNTINGPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ACCOUNTINGPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ACCOUNTINGPROPERTIES").field(&self.0).finish()
@@ -989,6 +990,7 @@ pub struct ATTRIBUTEFILTER(pub i32);
 impl windows_core::TypeKind for ATTRIBUTEFILTER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ATTRIBUTEFILTER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ATTRIBUTEFILTER").field(&self.0).finish()
@@ -1000,6 +1002,7 @@ pub struct ATTRIBUTEID(pub u32);
 impl windows_core::TypeKind for ATTRIBUTEID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ATTRIBUTEID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ATTRIBUTEID").field(&self.0).finish()
@@ -1011,6 +1014,7 @@ pub struct ATTRIBUTEINFO(pub i32);
 impl windows_core::TypeKind for ATTRIBUTEINFO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ATTRIBUTEINFO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ATTRIBUTEINFO").field(&self.0).finish()
@@ -1022,6 +1026,7 @@ pub struct ATTRIBUTEPROPERTIES(pub i32);
 impl windows_core::TypeKind for ATTRIBUTEPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ATTRIBUTEPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ATTRIBUTEPROPERTIES").field(&self.0).finish()
@@ -1033,6 +1038,7 @@ pub struct ATTRIBUTERESTRICTIONS(pub i32);
 impl windows_core::TypeKind for ATTRIBUTERESTRICTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ATTRIBUTERESTRICTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ATTRIBUTERESTRICTIONS").field(&self.0).finish()
@@ -1044,6 +1050,7 @@ pub struct ATTRIBUTESYNTAX(pub i32);
 impl windows_core::TypeKind for ATTRIBUTESYNTAX {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ATTRIBUTESYNTAX {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ATTRIBUTESYNTAX").field(&self.0).finish()
@@ -1055,6 +1062,7 @@ pub struct AUTHENTICATION_TYPE(pub i32);
 impl windows_core::TypeKind for AUTHENTICATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AUTHENTICATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AUTHENTICATION_TYPE").field(&self.0).finish()
@@ -1066,6 +1074,7 @@ pub struct CLIENTPROPERTIES(pub i32);
 impl windows_core::TypeKind for CLIENTPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLIENTPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLIENTPROPERTIES").field(&self.0).finish()
@@ -1077,6 +1086,7 @@ pub struct CONDITIONPROPERTIES(pub i32);
 impl windows_core::TypeKind for CONDITIONPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CONDITIONPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CONDITIONPROPERTIES").field(&self.0).finish()
@@ -1088,6 +1098,7 @@ pub struct DICTIONARYPROPERTIES(pub i32);
 impl windows_core::TypeKind for DICTIONARYPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DICTIONARYPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DICTIONARYPROPERTIES").field(&self.0).finish()
@@ -1099,6 +1110,7 @@ pub struct IASCOMMONPROPERTIES(pub i32);
 impl windows_core::TypeKind for IASCOMMONPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IASCOMMONPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IASCOMMONPROPERTIES").field(&self.0).finish()
@@ -1110,6 +1122,7 @@ pub struct IASCOMPONENTPROPERTIES(pub i32);
 impl windows_core::TypeKind for IASCOMPONENTPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IASCOMPONENTPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IASCOMPONENTPROPERTIES").field(&self.0).finish()
@@ -1121,6 +1134,7 @@ pub struct IASDATASTORE(pub i32);
 impl windows_core::TypeKind for IASDATASTORE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IASDATASTORE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IASDATASTORE").field(&self.0).finish()
@@ -1132,6 +1146,7 @@ pub struct IASDOMAINTYPE(pub i32);
 impl windows_core::TypeKind for IASDOMAINTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IASDOMAINTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IASDOMAINTYPE").field(&self.0).finish()
@@ -1143,6 +1158,7 @@ pub struct IASOSTYPE(pub i32);
 impl windows_core::TypeKind for IASOSTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IASOSTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IASOSTYPE").field(&self.0).finish()
@@ -1154,6 +1170,7 @@ pub struct IASPROPERTIES(pub i32);
 impl windows_core::TypeKind for IASPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IASPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IASPROPERTIES").field(&self.0).finish()
@@ -1165,6 +1182,7 @@ pub struct IDENTITY_TYPE(pub i32);
 impl windows_core::TypeKind for IDENTITY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IDENTITY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IDENTITY_TYPE").field(&self.0).finish()
@@ -1176,6 +1194,7 @@ pub struct IPFILTERPROPERTIES(pub i32);
 impl windows_core::TypeKind for IPFILTERPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IPFILTERPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IPFILTERPROPERTIES").field(&self.0).finish()
@@ -1187,6 +1206,7 @@ pub struct NAMESPROPERTIES(pub i32);
 impl windows_core::TypeKind for NAMESPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NAMESPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NAMESPROPERTIES").field(&self.0).finish()
@@ -1198,6 +1218,7 @@ pub struct NAPPROPERTIES(pub i32);
 impl windows_core::TypeKind for NAPPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NAPPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NAPPROPERTIES").field(&self.0).finish()
@@ -1209,6 +1230,7 @@ pub struct NEW_LOG_FILE_FREQUENCY(pub i32);
 impl windows_core::TypeKind for NEW_LOG_FILE_FREQUENCY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NEW_LOG_FILE_FREQUENCY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NEW_LOG_FILE_FREQUENCY").field(&self.0).finish()
@@ -1220,6 +1242,7 @@ pub struct NTEVENTLOGPROPERTIES(pub i32);
 impl windows_core::TypeKind for NTEVENTLOGPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NTEVENTLOGPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NTEVENTLOGPROPERTIES").field(&self.0).finish()
@@ -1231,6 +1254,7 @@ pub struct NTSAMPROPERTIES(pub i32);
 impl windows_core::TypeKind for NTSAMPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NTSAMPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NTSAMPROPERTIES").field(&self.0).finish()
@@ -1242,6 +1266,7 @@ pub struct POLICYPROPERTIES(pub i32);
 impl windows_core::TypeKind for POLICYPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for POLICYPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("POLICYPROPERTIES").field(&self.0).finish()
@@ -1253,6 +1278,7 @@ pub struct PROFILEPROPERTIES(pub i32);
 impl windows_core::TypeKind for PROFILEPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROFILEPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROFILEPROPERTIES").field(&self.0).finish()
@@ -1264,6 +1290,7 @@ pub struct PROTOCOLPROPERTIES(pub i32);
 impl windows_core::TypeKind for PROTOCOLPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROTOCOLPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROTOCOLPROPERTIES").field(&self.0).finish()
@@ -1275,6 +1302,7 @@ pub struct RADIUSPROPERTIES(pub i32);
 impl windows_core::TypeKind for RADIUSPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RADIUSPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RADIUSPROPERTIES").field(&self.0).finish()
@@ -1286,6 +1314,7 @@ pub struct RADIUSPROXYPROPERTIES(pub i32);
 impl windows_core::TypeKind for RADIUSPROXYPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RADIUSPROXYPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RADIUSPROXYPROPERTIES").field(&self.0).finish()
@@ -1297,6 +1326,7 @@ pub struct RADIUSSERVERGROUPPROPERTIES(pub i32);
 impl windows_core::TypeKind for RADIUSSERVERGROUPPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RADIUSSERVERGROUPPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RADIUSSERVERGROUPPROPERTIES").field(&self.0).finish()
@@ -1308,6 +1338,7 @@ pub struct RADIUSSERVERPROPERTIES(pub i32);
 impl windows_core::TypeKind for RADIUSSERVERPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RADIUSSERVERPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RADIUSSERVERPROPERTIES").field(&self.0).finish()
@@ -1319,6 +1350,7 @@ pub struct RADIUS_ACTION(pub i32);
 impl windows_core::TypeKind for RADIUS_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RADIUS_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RADIUS_ACTION").field(&self.0).finish()
@@ -1330,6 +1362,7 @@ pub struct RADIUS_ATTRIBUTE_TYPE(pub i32);
 impl windows_core::TypeKind for RADIUS_ATTRIBUTE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RADIUS_ATTRIBUTE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RADIUS_ATTRIBUTE_TYPE").field(&self.0).finish()
@@ -1341,6 +1374,7 @@ pub struct RADIUS_AUTHENTICATION_PROVIDER(pub i32);
 impl windows_core::TypeKind for RADIUS_AUTHENTICATION_PROVIDER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RADIUS_AUTHENTICATION_PROVIDER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RADIUS_AUTHENTICATION_PROVIDER").field(&self.0).finish()
@@ -1352,6 +1386,7 @@ pub struct RADIUS_CODE(pub i32);
 impl windows_core::TypeKind for RADIUS_CODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RADIUS_CODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RADIUS_CODE").field(&self.0).finish()
@@ -1363,6 +1398,7 @@ pub struct RADIUS_DATA_TYPE(pub i32);
 impl windows_core::TypeKind for RADIUS_DATA_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RADIUS_DATA_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RADIUS_DATA_TYPE").field(&self.0).finish()
@@ -1374,6 +1410,7 @@ pub struct RADIUS_EXTENSION_POINT(pub i32);
 impl windows_core::TypeKind for RADIUS_EXTENSION_POINT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RADIUS_EXTENSION_POINT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RADIUS_EXTENSION_POINT").field(&self.0).finish()
@@ -1385,6 +1422,7 @@ pub struct RADIUS_REJECT_REASON_CODE(pub i32);
 impl windows_core::TypeKind for RADIUS_REJECT_REASON_CODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RADIUS_REJECT_REASON_CODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RADIUS_REJECT_REASON_CODE").field(&self.0).finish()
@@ -1396,6 +1434,7 @@ pub struct REMEDIATIONSERVERGROUPPROPERTIES(pub i32);
 impl windows_core::TypeKind for REMEDIATIONSERVERGROUPPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for REMEDIATIONSERVERGROUPPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("REMEDIATIONSERVERGROUPPROPERTIES").field(&self.0).finish()
@@ -1407,6 +1446,7 @@ pub struct REMEDIATIONSERVERPROPERTIES(pub i32);
 impl windows_core::TypeKind for REMEDIATIONSERVERPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for REMEDIATIONSERVERPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("REMEDIATIONSERVERPROPERTIES").field(&self.0).finish()
@@ -1418,6 +1458,7 @@ pub struct REMEDIATIONSERVERSPROPERTIES(pub i32);
 impl windows_core::TypeKind for REMEDIATIONSERVERSPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for REMEDIATIONSERVERSPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("REMEDIATIONSERVERSPROPERTIES").field(&self.0).finish()
@@ -1429,6 +1470,7 @@ pub struct SERVICE_TYPE(pub i32);
 impl windows_core::TypeKind for SERVICE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SERVICE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SERVICE_TYPE").field(&self.0).finish()
@@ -1440,6 +1482,7 @@ pub struct SHAREDSECRETPROPERTIES(pub i32);
 impl windows_core::TypeKind for SHAREDSECRETPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHAREDSECRETPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHAREDSECRETPROPERTIES").field(&self.0).finish()
@@ -1451,6 +1494,7 @@ pub struct SHVTEMPLATEPROPERTIES(pub i32);
 impl windows_core::TypeKind for SHVTEMPLATEPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHVTEMPLATEPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHVTEMPLATEPROPERTIES").field(&self.0).finish()
@@ -1462,6 +1506,7 @@ pub struct SHV_COMBINATION_TYPE(pub i32);
 impl windows_core::TypeKind for SHV_COMBINATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHV_COMBINATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHV_COMBINATION_TYPE").field(&self.0).finish()
@@ -1473,6 +1518,7 @@ pub struct TEMPLATESPROPERTIES(pub i32);
 impl windows_core::TypeKind for TEMPLATESPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TEMPLATESPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TEMPLATESPROPERTIES").field(&self.0).finish()
@@ -1484,6 +1530,7 @@ pub struct USERPROPERTIES(pub i32);
 impl windows_core::TypeKind for USERPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USERPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USERPROPERTIES").field(&self.0).finish()
@@ -1495,6 +1542,7 @@ pub struct VENDORPROPERTIES(pub i32);
 impl windows_core::TypeKind for VENDORPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VENDORPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VENDORPROPERTIES").field(&self.0).finish()
@@ -1531,7 +1579,8 @@ impl Default for RADIUS_ATTRIBUTE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RADIUS_ATTRIBUTE_ARRAY {
     pub cbSize: u32,
     pub Add: isize,
@@ -1550,7 +1599,8 @@ impl Default for RADIUS_ATTRIBUTE_ARRAY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RADIUS_EXTENSION_CONTROL_BLOCK {
     pub cbSize: u32,
     pub dwVersion: u32,
@@ -1570,7 +1620,8 @@ impl Default for RADIUS_EXTENSION_CONTROL_BLOCK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RADIUS_VSA_FORMAT {
     pub VendorId: [u8; 4],
     pub VendorType: u8,
@@ -1594,3 +1645,5 @@ pub type PRADIUS_EXTENSION_PROCESS_EX = Option<unsafe extern "system" fn(pinattr
 pub type PRADIUS_EXTENSION_TERM = Option<unsafe extern "system" fn()>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
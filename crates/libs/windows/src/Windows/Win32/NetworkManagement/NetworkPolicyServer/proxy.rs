@@ -0,0 +1,276 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISdo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPropertyInfo(this: *mut core::ffi::c_void, id: i32, pppropertyinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdo", "GetPropertyInfo", 7usize, |this| ((**(this as *const *const ISdo_Vtbl)).GetPropertyInfo)(this, id, pppropertyinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, id: i32, pvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdo", "GetProperty", 8usize, |this| ((**(this as *const *const ISdo_Vtbl)).GetProperty)(this, id, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutProperty(this: *mut core::ffi::c_void, id: i32, pvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdo", "PutProperty", 9usize, |this| ((**(this as *const *const ISdo_Vtbl)).PutProperty)(this, id, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetProperty(this: *mut core::ffi::c_void, id: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdo", "ResetProperty", 10usize, |this| ((**(this as *const *const ISdo_Vtbl)).ResetProperty)(this, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn Apply(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdo", "Apply", 11usize, |this| ((**(this as *const *const ISdo_Vtbl)).Apply)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Restore(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdo", "Restore", 12usize, |this| ((**(this as *const *const ISdo_Vtbl)).Restore)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppenumvariant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdo", "_NewEnum", 13usize, |this| ((**(this as *const *const ISdo_Vtbl))._NewEnum)(this, ppenumvariant), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPropertyInfo,
+            GetProperty,
+            PutProperty,
+            ResetProperty,
+            Apply,
+            Restore,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISdo as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISdoCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoCollection", "Count", 7usize, |this| ((**(this as *const *const ISdoCollection_Vtbl)).Count)(this, pcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoCollection", "Add", 8usize, |this| ((**(this as *const *const ISdoCollection_Vtbl)).Add)(this, bstrname, ppitem), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, pitem: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoCollection", "Remove", 9usize, |this| ((**(this as *const *const ISdoCollection_Vtbl)).Remove)(this, pitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoCollection", "RemoveAll", 10usize, |this| ((**(this as *const *const ISdoCollection_Vtbl)).RemoveAll)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reload(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoCollection", "Reload", 11usize, |this| ((**(this as *const *const ISdoCollection_Vtbl)).Reload)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsNameUnique(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, pbool: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoCollection", "IsNameUnique", 12usize, |this| ((**(this as *const *const ISdoCollection_Vtbl)).IsNameUnique)(this, bstrname, pbool), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, name: *const core::mem::MaybeUninit<windows_core::VARIANT>, pitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoCollection", "Item", 13usize, |this| ((**(this as *const *const ISdoCollection_Vtbl)).Item)(this, name, pitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppenumvariant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoCollection", "_NewEnum", 14usize, |this| ((**(this as *const *const ISdoCollection_Vtbl))._NewEnum)(this, ppenumvariant), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Remove,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Remove: 0,
+            RemoveAll,
+            Reload,
+            IsNameUnique,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISdoCollection as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISdoDictionaryOld_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumAttributes(this: *mut core::ffi::c_void, id: *mut core::mem::MaybeUninit<windows_core::VARIANT>, pvalues: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoDictionaryOld", "EnumAttributes", 7usize, |this| ((**(this as *const *const ISdoDictionaryOld_Vtbl)).EnumAttributes)(this, id, pvalues), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributeInfo(this: *mut core::ffi::c_void, id: ATTRIBUTEID, pinfoids: *const core::mem::MaybeUninit<windows_core::VARIANT>, pinfovalues: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoDictionaryOld", "GetAttributeInfo", 8usize, |this| ((**(this as *const *const ISdoDictionaryOld_Vtbl)).GetAttributeInfo)(this, id, pinfoids, pinfovalues), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumAttributeValues(this: *mut core::ffi::c_void, id: ATTRIBUTEID, pvalueids: *mut core::mem::MaybeUninit<windows_core::VARIANT>, pvaluesdesc: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoDictionaryOld", "EnumAttributeValues", 9usize, |this| ((**(this as *const *const ISdoDictionaryOld_Vtbl)).EnumAttributeValues)(this, id, pvalueids, pvaluesdesc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateAttribute(this: *mut core::ffi::c_void, id: ATTRIBUTEID, ppattributeobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoDictionaryOld", "CreateAttribute", 10usize, |this| ((**(this as *const *const ISdoDictionaryOld_Vtbl)).CreateAttribute)(this, id, ppattributeobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributeID(this: *mut core::ffi::c_void, bstrattributename: core::mem::MaybeUninit<windows_core::BSTR>, pid: *mut ATTRIBUTEID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoDictionaryOld", "GetAttributeID", 11usize, |this| ((**(this as *const *const ISdoDictionaryOld_Vtbl)).GetAttributeID)(this, bstrattributename, pid), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EnumAttributes,
+            GetAttributeInfo,
+            EnumAttributeValues,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateAttribute,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateAttribute: 0,
+            GetAttributeID,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISdoDictionaryOld as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISdoMachine_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Attach(this: *mut core::ffi::c_void, bstrcomputername: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoMachine", "Attach", 7usize, |this| ((**(this as *const *const ISdoMachine_Vtbl)).Attach)(this, bstrcomputername), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDictionarySDO(this: *mut core::ffi::c_void, ppdictionarysdo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoMachine", "GetDictionarySDO", 8usize, |this| ((**(this as *const *const ISdoMachine_Vtbl)).GetDictionarySDO)(this, ppdictionarysdo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServiceSDO(this: *mut core::ffi::c_void, edatastore: IASDATASTORE, bstrservicename: core::mem::MaybeUninit<windows_core::BSTR>, ppservicesdo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoMachine", "GetServiceSDO", 9usize, |this| ((**(this as *const *const ISdoMachine_Vtbl)).GetServiceSDO)(this, edatastore, bstrservicename, ppservicesdo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUserSDO(this: *mut core::ffi::c_void, edatastore: IASDATASTORE, bstrusername: core::mem::MaybeUninit<windows_core::BSTR>, ppusersdo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoMachine", "GetUserSDO", 10usize, |this| ((**(this as *const *const ISdoMachine_Vtbl)).GetUserSDO)(this, edatastore, bstrusername, ppusersdo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOSType(this: *mut core::ffi::c_void, eostype: *mut IASOSTYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoMachine", "GetOSType", 11usize, |this| ((**(this as *const *const ISdoMachine_Vtbl)).GetOSType)(this, eostype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDomainType(this: *mut core::ffi::c_void, edomaintype: *mut IASDOMAINTYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoMachine", "GetDomainType", 12usize, |this| ((**(this as *const *const ISdoMachine_Vtbl)).GetDomainType)(this, edomaintype), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDirectoryAvailable(this: *mut core::ffi::c_void, booldirectoryavailable: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoMachine", "IsDirectoryAvailable", 13usize, |this| ((**(this as *const *const ISdoMachine_Vtbl)).IsDirectoryAvailable)(this, booldirectoryavailable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttachedComputer(this: *mut core::ffi::c_void, bstrcomputername: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoMachine", "GetAttachedComputer", 14usize, |this| ((**(this as *const *const ISdoMachine_Vtbl)).GetAttachedComputer)(this, bstrcomputername), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSDOSchema(this: *mut core::ffi::c_void, ppsdoschema: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoMachine", "GetSDOSchema", 15usize, |this| ((**(this as *const *const ISdoMachine_Vtbl)).GetSDOSchema)(this, ppsdoschema), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Attach,
+            GetDictionarySDO,
+            GetServiceSDO,
+            GetUserSDO,
+            GetOSType,
+            GetDomainType,
+            IsDirectoryAvailable,
+            GetAttachedComputer,
+            GetSDOSchema,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISdoMachine as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISdoMachine2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTemplatesSDO(this: *mut core::ffi::c_void, bstrservicename: core::mem::MaybeUninit<windows_core::BSTR>, pptemplatessdo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoMachine2", "GetTemplatesSDO", 16usize, |this| ((**(this as *const *const ISdoMachine2_Vtbl)).GetTemplatesSDO)(this, bstrservicename, pptemplatessdo), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableTemplates(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoMachine2", "EnableTemplates", 17usize, |this| ((**(this as *const *const ISdoMachine2_Vtbl)).EnableTemplates)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncConfigAgainstTemplates(this: *mut core::ffi::c_void, bstrservicename: core::mem::MaybeUninit<windows_core::BSTR>, ppconfigroot: *mut *mut core::ffi::c_void, pptemplatesroot: *mut *mut core::ffi::c_void, bforcedsync: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoMachine2", "SyncConfigAgainstTemplates", 18usize, |this| ((**(this as *const *const ISdoMachine2_Vtbl)).SyncConfigAgainstTemplates)(this, bstrservicename, ppconfigroot, pptemplatesroot, bforcedsync), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImportRemoteTemplates(this: *mut core::ffi::c_void, plocaltemplatesroot: *mut core::ffi::c_void, bstrremotemachinename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoMachine2", "ImportRemoteTemplates", 19usize, |this| ((**(this as *const *const ISdoMachine2_Vtbl)).ImportRemoteTemplates)(this, plocaltemplatesroot, bstrremotemachinename), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reload(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoMachine2", "Reload", 20usize, |this| ((**(this as *const *const ISdoMachine2_Vtbl)).Reload)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <ISdoMachine_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTemplatesSDO,
+            EnableTemplates,
+            SyncConfigAgainstTemplates,
+            ImportRemoteTemplates,
+            Reload,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISdoMachine2 as windows_core::Interface>::IID || <ISdoMachine_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISdoServiceControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartService(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoServiceControl", "StartService", 7usize, |this| ((**(this as *const *const ISdoServiceControl_Vtbl)).StartService)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopService(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoServiceControl", "StopService", 8usize, |this| ((**(this as *const *const ISdoServiceControl_Vtbl)).StopService)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServiceStatus(this: *mut core::ffi::c_void, status: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoServiceControl", "GetServiceStatus", 9usize, |this| ((**(this as *const *const ISdoServiceControl_Vtbl)).GetServiceStatus)(this, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetService(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISdoServiceControl", "ResetService", 10usize, |this| ((**(this as *const *const ISdoServiceControl_Vtbl)).ResetService)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartService,
+            StopService,
+            GetServiceStatus,
+            ResetService,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISdoServiceControl as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITemplateSdo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddToCollection(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, pcollection: *mut core::ffi::c_void, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITemplateSdo", "AddToCollection", 14usize, |this| ((**(this as *const *const ITemplateSdo_Vtbl)).AddToCollection)(this, bstrname, pcollection, ppitem), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddToSdo(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, psdotarget: *mut core::ffi::c_void, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITemplateSdo", "AddToSdo", 15usize, |this| ((**(this as *const *const ITemplateSdo_Vtbl)).AddToSdo)(this, bstrname, psdotarget, ppitem), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddToSdoAsProperty(this: *mut core::ffi::c_void, psdotarget: *mut core::ffi::c_void, id: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITemplateSdo", "AddToSdoAsProperty", 16usize, |this| ((**(this as *const *const ITemplateSdo_Vtbl)).AddToSdoAsProperty)(this, psdotarget, id), |result| Some(*result))
+        }
+        Self {
+            base__: <ISdo_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            AddToCollection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddToCollection: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AddToSdo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddToSdo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AddToSdoAsProperty,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddToSdoAsProperty: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITemplateSdo as windows_core::Interface>::IID || <ISdo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
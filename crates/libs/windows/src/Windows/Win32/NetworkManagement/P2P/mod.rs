@@ -1586,6 +1586,7 @@ pub struct DRT_ADDRESS_FLAGS(pub i32);
 impl windows_core::TypeKind for DRT_ADDRESS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DRT_ADDRESS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DRT_ADDRESS_FLAGS").field(&self.0).finish()
@@ -1597,6 +1598,7 @@ pub struct DRT_EVENT_TYPE(pub i32);
 impl windows_core::TypeKind for DRT_EVENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DRT_EVENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DRT_EVENT_TYPE").field(&self.0).finish()
@@ -1608,6 +1610,7 @@ pub struct DRT_LEAFSET_KEY_CHANGE_TYPE(pub i32);
 impl windows_core::TypeKind for DRT_LEAFSET_KEY_CHANGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DRT_LEAFSET_KEY_CHANGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DRT_LEAFSET_KEY_CHANGE_TYPE").field(&self.0).finish()
@@ -1619,6 +1622,7 @@ pub struct DRT_MATCH_TYPE(pub i32);
 impl windows_core::TypeKind for DRT_MATCH_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DRT_MATCH_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DRT_MATCH_TYPE").field(&self.0).finish()
@@ -1630,6 +1634,7 @@ pub struct DRT_REGISTRATION_STATE(pub i32);
 impl windows_core::TypeKind for DRT_REGISTRATION_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DRT_REGISTRATION_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DRT_REGISTRATION_STATE").field(&self.0).finish()
@@ -1641,6 +1646,7 @@ pub struct DRT_SCOPE(pub i32);
 impl windows_core::TypeKind for DRT_SCOPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DRT_SCOPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DRT_SCOPE").field(&self.0).finish()
@@ -1652,6 +1658,7 @@ pub struct DRT_SECURITY_MODE(pub i32);
 impl windows_core::TypeKind for DRT_SECURITY_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DRT_SECURITY_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DRT_SECURITY_MODE").field(&self.0).finish()
@@ -1663,6 +1670,7 @@ pub struct DRT_STATUS(pub i32);
 impl windows_core::TypeKind for DRT_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DRT_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DRT_STATUS").field(&self.0).finish()
@@ -1674,6 +1682,7 @@ pub struct PEERDIST_CLIENT_INFO_BY_HANDLE_CLASS(pub i32);
 impl windows_core::TypeKind for PEERDIST_CLIENT_INFO_BY_HANDLE_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEERDIST_CLIENT_INFO_BY_HANDLE_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEERDIST_CLIENT_INFO_BY_HANDLE_CLASS").field(&self.0).finish()
@@ -1685,6 +1694,7 @@ pub struct PEERDIST_RETRIEVAL_OPTIONS_CONTENTINFO_VERSION_VALUE(pub u32);
 impl windows_core::TypeKind for PEERDIST_RETRIEVAL_OPTIONS_CONTENTINFO_VERSION_VALUE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEERDIST_RETRIEVAL_OPTIONS_CONTENTINFO_VERSION_VALUE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEERDIST_RETRIEVAL_OPTIONS_CONTENTINFO_VERSION_VALUE").field(&self.0).finish()
@@ -1696,6 +1706,7 @@ pub struct PEERDIST_STATUS(pub i32);
 impl windows_core::TypeKind for PEERDIST_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEERDIST_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEERDIST_STATUS").field(&self.0).finish()
@@ -1707,6 +1718,7 @@ pub struct PEER_APPLICATION_REGISTRATION_TYPE(pub i32);
 impl windows_core::TypeKind for PEER_APPLICATION_REGISTRATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEER_APPLICATION_REGISTRATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEER_APPLICATION_REGISTRATION_TYPE").field(&self.0).finish()
@@ -1718,6 +1730,7 @@ pub struct PEER_CHANGE_TYPE(pub i32);
 impl windows_core::TypeKind for PEER_CHANGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEER_CHANGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEER_CHANGE_TYPE").field(&self.0).finish()
@@ -1729,6 +1742,7 @@ pub struct PEER_COLLAB_EVENT_TYPE(pub i32);
 impl windows_core::TypeKind for PEER_COLLAB_EVENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEER_COLLAB_EVENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEER_COLLAB_EVENT_TYPE").field(&self.0).finish()
@@ -1740,6 +1754,7 @@ pub struct PEER_CONNECTION_FLAGS(pub i32);
 impl windows_core::TypeKind for PEER_CONNECTION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEER_CONNECTION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEER_CONNECTION_FLAGS").field(&self.0).finish()
@@ -1751,6 +1766,7 @@ pub struct PEER_CONNECTION_STATUS(pub i32);
 impl windows_core::TypeKind for PEER_CONNECTION_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEER_CONNECTION_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEER_CONNECTION_STATUS").field(&self.0).finish()
@@ -1762,6 +1778,7 @@ pub struct PEER_GRAPH_EVENT_TYPE(pub i32);
 impl windows_core::TypeKind for PEER_GRAPH_EVENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEER_GRAPH_EVENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEER_GRAPH_EVENT_TYPE").field(&self.0).finish()
@@ -1773,6 +1790,7 @@ pub struct PEER_GRAPH_PROPERTY_FLAGS(pub i32);
 impl windows_core::TypeKind for PEER_GRAPH_PROPERTY_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEER_GRAPH_PROPERTY_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEER_GRAPH_PROPERTY_FLAGS").field(&self.0).finish()
@@ -1784,6 +1802,7 @@ pub struct PEER_GRAPH_SCOPE(pub i32);
 impl windows_core::TypeKind for PEER_GRAPH_SCOPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEER_GRAPH_SCOPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEER_GRAPH_SCOPE").field(&self.0).finish()
@@ -1795,6 +1814,7 @@ pub struct PEER_GRAPH_STATUS_FLAGS(pub i32);
 impl windows_core::TypeKind for PEER_GRAPH_STATUS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEER_GRAPH_STATUS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEER_GRAPH_STATUS_FLAGS").field(&self.0).finish()
@@ -1806,6 +1826,7 @@ pub struct PEER_GROUP_AUTHENTICATION_SCHEME(pub i32);
 impl windows_core::TypeKind for PEER_GROUP_AUTHENTICATION_SCHEME {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEER_GROUP_AUTHENTICATION_SCHEME {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEER_GROUP_AUTHENTICATION_SCHEME").field(&self.0).finish()
@@ -1817,6 +1838,7 @@ pub struct PEER_GROUP_EVENT_TYPE(pub i32);
 impl windows_core::TypeKind for PEER_GROUP_EVENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEER_GROUP_EVENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEER_GROUP_EVENT_TYPE").field(&self.0).finish()
@@ -1828,6 +1850,7 @@ pub struct PEER_GROUP_ISSUE_CREDENTIAL_FLAGS(pub i32);
 impl windows_core::TypeKind for PEER_GROUP_ISSUE_CREDENTIAL_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEER_GROUP_ISSUE_CREDENTIAL_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEER_GROUP_ISSUE_CREDENTIAL_FLAGS").field(&self.0).finish()
@@ -1839,6 +1862,7 @@ pub struct PEER_GROUP_PROPERTY_FLAGS(pub i32);
 impl windows_core::TypeKind for PEER_GROUP_PROPERTY_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEER_GROUP_PROPERTY_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEER_GROUP_PROPERTY_FLAGS").field(&self.0).finish()
@@ -1850,6 +1874,7 @@ pub struct PEER_GROUP_STATUS(pub i32);
 impl windows_core::TypeKind for PEER_GROUP_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEER_GROUP_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEER_GROUP_STATUS").field(&self.0).finish()
@@ -1861,6 +1886,7 @@ pub struct PEER_INVITATION_RESPONSE_TYPE(pub i32);
 impl windows_core::TypeKind for PEER_INVITATION_RESPONSE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEER_INVITATION_RESPONSE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEER_INVITATION_RESPONSE_TYPE").field(&self.0).finish()
@@ -1872,6 +1898,7 @@ pub struct PEER_MEMBER_CHANGE_TYPE(pub i32);
 impl windows_core::TypeKind for PEER_MEMBER_CHANGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEER_MEMBER_CHANGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEER_MEMBER_CHANGE_TYPE").field(&self.0).finish()
@@ -1883,6 +1910,7 @@ pub struct PEER_MEMBER_FLAGS(pub i32);
 impl windows_core::TypeKind for PEER_MEMBER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEER_MEMBER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEER_MEMBER_FLAGS").field(&self.0).finish()
@@ -1894,6 +1922,7 @@ pub struct PEER_NODE_CHANGE_TYPE(pub i32);
 impl windows_core::TypeKind for PEER_NODE_CHANGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEER_NODE_CHANGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEER_NODE_CHANGE_TYPE").field(&self.0).finish()
@@ -1905,6 +1934,7 @@ pub struct PEER_PRESENCE_STATUS(pub i32);
 impl windows_core::TypeKind for PEER_PRESENCE_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEER_PRESENCE_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEER_PRESENCE_STATUS").field(&self.0).finish()
@@ -1916,6 +1946,7 @@ pub struct PEER_PUBLICATION_SCOPE(pub i32);
 impl windows_core::TypeKind for PEER_PUBLICATION_SCOPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEER_PUBLICATION_SCOPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEER_PUBLICATION_SCOPE").field(&self.0).finish()
@@ -1927,6 +1958,7 @@ pub struct PEER_RECORD_CHANGE_TYPE(pub i32);
 impl windows_core::TypeKind for PEER_RECORD_CHANGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEER_RECORD_CHANGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEER_RECORD_CHANGE_TYPE").field(&self.0).finish()
@@ -1938,6 +1970,7 @@ pub struct PEER_RECORD_FLAGS(pub i32);
 impl windows_core::TypeKind for PEER_RECORD_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEER_RECORD_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEER_RECORD_FLAGS").field(&self.0).finish()
@@ -1949,6 +1982,7 @@ pub struct PEER_SIGNIN_FLAGS(pub i32);
 impl windows_core::TypeKind for PEER_SIGNIN_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEER_SIGNIN_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEER_SIGNIN_FLAGS").field(&self.0).finish()
@@ -1960,6 +1994,7 @@ pub struct PEER_WATCH_PERMISSION(pub i32);
 impl windows_core::TypeKind for PEER_WATCH_PERMISSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PEER_WATCH_PERMISSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PEER_WATCH_PERMISSION").field(&self.0).finish()
@@ -1971,6 +2006,7 @@ pub struct PNRP_CLOUD_FLAGS(pub i32);
 impl windows_core::TypeKind for PNRP_CLOUD_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PNRP_CLOUD_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PNRP_CLOUD_FLAGS").field(&self.0).finish()
@@ -1982,6 +2018,7 @@ pub struct PNRP_CLOUD_STATE(pub i32);
 impl windows_core::TypeKind for PNRP_CLOUD_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PNRP_CLOUD_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PNRP_CLOUD_STATE").field(&self.0).finish()
@@ -1993,6 +2030,7 @@ pub struct PNRP_EXTENDED_PAYLOAD_TYPE(pub i32);
 impl windows_core::TypeKind for PNRP_EXTENDED_PAYLOAD_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PNRP_EXTENDED_PAYLOAD_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PNRP_EXTENDED_PAYLOAD_TYPE").field(&self.0).finish()
@@ -2004,6 +2042,7 @@ pub struct PNRP_REGISTERED_ID_STATE(pub i32);
 impl windows_core::TypeKind for PNRP_REGISTERED_ID_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PNRP_REGISTERED_ID_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PNRP_REGISTERED_ID_STATE").field(&self.0).finish()
@@ -2015,6 +2054,7 @@ pub struct PNRP_RESOLVE_CRITERIA(pub i32);
 impl windows_core::TypeKind for PNRP_RESOLVE_CRITERIA {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PNRP_RESOLVE_CRITERIA {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PNRP_RESOLVE_CRITERIA").field(&self.0).finish()
@@ -2026,6 +2066,7 @@ pub struct PNRP_SCOPE(pub i32);
 impl windows_core::TypeKind for PNRP_SCOPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PNRP_SCOPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PNRP_SCOPE").field(&self.0).finish()
@@ -2033,7 +2074,8 @@ impl core::fmt::Debug for PNRP_SCOPE {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Networking_WinSock")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRT_ADDRESS {
     pub socketAddress: super::super::Networking::WinSock::SOCKADDR_STORAGE,
     pub flags: u32,
@@ -2052,7 +2094,8 @@ impl Default for DRT_ADDRESS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Networking_WinSock")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRT_ADDRESS_LIST {
     pub AddressCount: u32,
     pub AddressList: [DRT_ADDRESS; 1],
@@ -2068,7 +2111,8 @@ impl Default for DRT_ADDRESS_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRT_BOOTSTRAP_PROVIDER {
     pub pvContext: *mut core::ffi::c_void,
     pub Attach: isize,
@@ -2088,7 +2132,8 @@ impl Default for DRT_BOOTSTRAP_PROVIDER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRT_DATA {
     pub cb: u32,
     pub pb: *mut u8,
@@ -2140,7 +2185,8 @@ impl Default for DRT_EVENT_DATA_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Networking_WinSock")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRT_EVENT_DATA_0_0 {
     pub change: DRT_LEAFSET_KEY_CHANGE_TYPE,
     pub localKey: DRT_DATA,
@@ -2158,7 +2204,8 @@ impl Default for DRT_EVENT_DATA_0_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Networking_WinSock")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRT_EVENT_DATA_0_1 {
     pub state: DRT_REGISTRATION_STATE,
     pub localKey: DRT_DATA,
@@ -2175,7 +2222,8 @@ impl Default for DRT_EVENT_DATA_0_1 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Networking_WinSock")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRT_EVENT_DATA_0_2 {
     pub status: DRT_STATUS,
     pub bootstrapAddresses: DRT_EVENT_DATA_0_2_0,
@@ -2192,7 +2240,8 @@ impl Default for DRT_EVENT_DATA_0_2 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Networking_WinSock")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRT_EVENT_DATA_0_2_0 {
     pub cntAddress: u32,
     pub pAddresses: *mut super::super::Networking::WinSock::SOCKADDR_STORAGE,
@@ -2208,7 +2257,8 @@ impl Default for DRT_EVENT_DATA_0_2_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRT_REGISTRATION {
     pub key: DRT_DATA,
     pub appData: DRT_DATA,
@@ -2222,7 +2272,8 @@ impl Default for DRT_REGISTRATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRT_SEARCH_INFO {
     pub dwSize: u32,
     pub fIterative: super::super::Foundation::BOOL,
@@ -2241,7 +2292,8 @@ impl Default for DRT_SEARCH_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRT_SEARCH_RESULT {
     pub dwSize: u32,
     pub r#type: DRT_MATCH_TYPE,
@@ -2257,7 +2309,8 @@ impl Default for DRT_SEARCH_RESULT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRT_SECURITY_PROVIDER {
     pub pvContext: *mut core::ffi::c_void,
     pub Attach: isize,
@@ -2283,7 +2336,8 @@ impl Default for DRT_SECURITY_PROVIDER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRT_SETTINGS {
     pub dwSize: u32,
     pub cbKey: u32,
@@ -2305,7 +2359,8 @@ impl Default for DRT_SETTINGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEERDIST_CLIENT_BASIC_INFO {
     pub fFlashCrowd: super::super::Foundation::BOOL,
 }
@@ -2318,7 +2373,8 @@ impl Default for PEERDIST_CLIENT_BASIC_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEERDIST_CONTENT_TAG {
     pub Data: [u8; 16],
 }
@@ -2331,7 +2387,8 @@ impl Default for PEERDIST_CONTENT_TAG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEERDIST_PUBLICATION_OPTIONS {
     pub dwVersion: u32,
     pub dwFlags: u32,
@@ -2345,7 +2402,8 @@ impl Default for PEERDIST_PUBLICATION_OPTIONS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEERDIST_RETRIEVAL_OPTIONS {
     pub cbSize: u32,
     pub dwContentInfoMinVersion: u32,
@@ -2361,7 +2419,8 @@ impl Default for PEERDIST_RETRIEVAL_OPTIONS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEERDIST_STATUS_INFO {
     pub cbSize: u32,
     pub status: PEERDIST_STATUS,
@@ -2394,7 +2453,8 @@ impl Default for PEER_ADDRESS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_APPLICATION {
     pub id: windows_core::GUID,
     pub data: PEER_DATA,
@@ -2409,7 +2469,8 @@ impl Default for PEER_APPLICATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_APPLICATION_REGISTRATION_INFO {
     pub application: PEER_APPLICATION,
     pub pwzApplicationToLaunch: windows_core::PWSTR,
@@ -2426,7 +2487,8 @@ impl Default for PEER_APPLICATION_REGISTRATION_INFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Networking_WinSock")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_APP_LAUNCH_INFO {
     pub pContact: *mut PEER_CONTACT,
     pub pEndpoint: *mut PEER_ENDPOINT,
@@ -2482,7 +2544,8 @@ impl Default for PEER_COLLAB_EVENT_DATA_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_COLLAB_EVENT_REGISTRATION {
     pub eventType: PEER_COLLAB_EVENT_TYPE,
     pub pInstance: *mut windows_core::GUID,
@@ -2517,7 +2580,8 @@ impl Default for PEER_CONNECTION_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_CONTACT {
     pub pwzPeerName: windows_core::PWSTR,
     pub pwzNickName: windows_core::PWSTR,
@@ -2537,7 +2601,8 @@ impl Default for PEER_CONTACT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Security_Cryptography")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_CREDENTIAL_INFO {
     pub dwSize: u32,
     pub dwFlags: u32,
@@ -2561,7 +2626,8 @@ impl Default for PEER_CREDENTIAL_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_DATA {
     pub cbData: u32,
     pub pbData: *mut u8,
@@ -2593,7 +2659,8 @@ impl Default for PEER_ENDPOINT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Networking_WinSock")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_EVENT_APPLICATION_CHANGED_DATA {
     pub pContact: *mut PEER_CONTACT,
     pub pEndpoint: *mut PEER_ENDPOINT,
@@ -2611,7 +2678,8 @@ impl Default for PEER_EVENT_APPLICATION_CHANGED_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_EVENT_CONNECTION_CHANGE_DATA {
     pub dwSize: u32,
     pub status: PEER_CONNECTION_STATUS,
@@ -2630,7 +2698,8 @@ impl Default for PEER_EVENT_CONNECTION_CHANGE_DATA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Networking_WinSock")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_EVENT_ENDPOINT_CHANGED_DATA {
     pub pContact: *mut PEER_CONTACT,
     pub pEndpoint: *mut PEER_ENDPOINT,
@@ -2646,7 +2715,8 @@ impl Default for PEER_EVENT_ENDPOINT_CHANGED_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_EVENT_INCOMING_DATA {
     pub dwSize: u32,
     pub ullConnectionId: u64,
@@ -2662,7 +2732,8 @@ impl Default for PEER_EVENT_INCOMING_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_EVENT_MEMBER_CHANGE_DATA {
     pub dwSize: u32,
     pub changeType: PEER_MEMBER_CHANGE_TYPE,
@@ -2677,7 +2748,8 @@ impl Default for PEER_EVENT_MEMBER_CHANGE_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_EVENT_NODE_CHANGE_DATA {
     pub dwSize: u32,
     pub changeType: PEER_NODE_CHANGE_TYPE,
@@ -2694,7 +2766,8 @@ impl Default for PEER_EVENT_NODE_CHANGE_DATA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Networking_WinSock")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_EVENT_OBJECT_CHANGED_DATA {
     pub pContact: *mut PEER_CONTACT,
     pub pEndpoint: *mut PEER_ENDPOINT,
@@ -2713,7 +2786,8 @@ impl Default for PEER_EVENT_OBJECT_CHANGED_DATA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Networking_WinSock")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_EVENT_PEOPLE_NEAR_ME_CHANGED_DATA {
     pub changeType: PEER_CHANGE_TYPE,
     pub pPeopleNearMe: *mut PEER_PEOPLE_NEAR_ME,
@@ -2730,7 +2804,8 @@ impl Default for PEER_EVENT_PEOPLE_NEAR_ME_CHANGED_DATA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Networking_WinSock")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_EVENT_PRESENCE_CHANGED_DATA {
     pub pContact: *mut PEER_CONTACT,
     pub pEndpoint: *mut PEER_ENDPOINT,
@@ -2748,7 +2823,8 @@ impl Default for PEER_EVENT_PRESENCE_CHANGED_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_EVENT_RECORD_CHANGE_DATA {
     pub dwSize: u32,
     pub changeType: PEER_RECORD_CHANGE_TYPE,
@@ -2765,7 +2841,8 @@ impl Default for PEER_EVENT_RECORD_CHANGE_DATA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Networking_WinSock")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_EVENT_REQUEST_STATUS_CHANGED_DATA {
     pub pEndpoint: *mut PEER_ENDPOINT,
     pub hrChange: windows_core::HRESULT,
@@ -2781,7 +2858,8 @@ impl Default for PEER_EVENT_REQUEST_STATUS_CHANGED_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_EVENT_SYNCHRONIZED_DATA {
     pub dwSize: u32,
     pub recordType: windows_core::GUID,
@@ -2795,7 +2873,8 @@ impl Default for PEER_EVENT_SYNCHRONIZED_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_EVENT_WATCHLIST_CHANGED_DATA {
     pub pContact: *mut PEER_CONTACT,
     pub changeType: PEER_CHANGE_TYPE,
@@ -2841,7 +2920,8 @@ impl Default for PEER_GRAPH_EVENT_DATA_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_GRAPH_EVENT_REGISTRATION {
     pub eventType: PEER_GRAPH_EVENT_TYPE,
     pub pType: *mut windows_core::GUID,
@@ -2855,7 +2935,8 @@ impl Default for PEER_GRAPH_EVENT_REGISTRATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_GRAPH_PROPERTIES {
     pub dwSize: u32,
     pub dwFlags: u32,
@@ -2909,7 +2990,8 @@ impl Default for PEER_GROUP_EVENT_DATA_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_GROUP_EVENT_REGISTRATION {
     pub eventType: PEER_GROUP_EVENT_TYPE,
     pub pType: *mut windows_core::GUID,
@@ -2923,7 +3005,8 @@ impl Default for PEER_GROUP_EVENT_REGISTRATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_GROUP_PROPERTIES {
     pub dwSize: u32,
     pub dwFlags: u32,
@@ -2948,7 +3031,8 @@ impl Default for PEER_GROUP_PROPERTIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_INVITATION {
     pub applicationId: windows_core::GUID,
     pub applicationData: PEER_DATA,
@@ -2964,7 +3048,8 @@ impl Default for PEER_INVITATION {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Security_Cryptography")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_INVITATION_INFO {
     pub dwSize: u32,
     pub dwFlags: u32,
@@ -2997,7 +3082,8 @@ impl Default for PEER_INVITATION_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_INVITATION_RESPONSE {
     pub action: PEER_INVITATION_RESPONSE_TYPE,
     pub pwzMessage: windows_core::PWSTR,
@@ -3013,7 +3099,8 @@ impl Default for PEER_INVITATION_RESPONSE {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Networking_WinSock", feature = "Win32_Security_Cryptography"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_MEMBER {
     pub dwSize: u32,
     pub dwFlags: u32,
@@ -3035,7 +3122,8 @@ impl Default for PEER_MEMBER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_NAME_PAIR {
     pub dwSize: u32,
     pub pwzPeerName: windows_core::PWSTR,
@@ -3051,7 +3139,8 @@ impl Default for PEER_NAME_PAIR {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Networking_WinSock")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_NODE_INFO {
     pub dwSize: u32,
     pub ullNodeId: u64,
@@ -3071,7 +3160,8 @@ impl Default for PEER_NODE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_OBJECT {
     pub id: windows_core::GUID,
     pub data: PEER_DATA,
@@ -3104,7 +3194,8 @@ impl Default for PEER_PEOPLE_NEAR_ME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_PNRP_CLOUD_INFO {
     pub pwzCloudName: windows_core::PWSTR,
     pub dwScope: PNRP_SCOPE,
@@ -3120,7 +3211,8 @@ impl Default for PEER_PNRP_CLOUD_INFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Networking_WinSock")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_PNRP_ENDPOINT_INFO {
     pub pwzPeerName: windows_core::PWSTR,
     pub cAddresses: u32,
@@ -3140,7 +3232,8 @@ impl Default for PEER_PNRP_ENDPOINT_INFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Networking_WinSock")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_PNRP_REGISTRATION_INFO {
     pub pwzCloudName: windows_core::PWSTR,
     pub pwzPublishingIdentity: windows_core::PWSTR,
@@ -3161,7 +3254,8 @@ impl Default for PEER_PNRP_REGISTRATION_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_PRESENCE_INFO {
     pub status: PEER_PRESENCE_STATUS,
     pub pwzDescriptiveText: windows_core::PWSTR,
@@ -3175,7 +3269,8 @@ impl Default for PEER_PRESENCE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_RECORD {
     pub dwSize: u32,
     pub r#type: windows_core::GUID,
@@ -3200,7 +3295,8 @@ impl Default for PEER_RECORD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_SECURITY_INTERFACE {
     pub dwSize: u32,
     pub pwzSspFilename: windows_core::PWSTR,
@@ -3222,7 +3318,8 @@ impl Default for PEER_SECURITY_INTERFACE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEER_VERSION_DATA {
     pub wVersion: u16,
     pub wHighestVersion: u16,
@@ -3236,7 +3333,8 @@ impl Default for PEER_VERSION_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PNRPCLOUDINFO {
     pub dwSize: u32,
     pub Cloud: PNRP_CLOUD_ID,
@@ -3253,7 +3351,8 @@ impl Default for PNRPCLOUDINFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Networking_WinSock")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PNRPINFO_V1 {
     pub dwSize: u32,
     pub lpwszIdentity: windows_core::PWSTR,
@@ -3319,7 +3418,8 @@ impl Default for PNRPINFO_V2_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PNRP_CLOUD_ID {
     pub AddressFamily: i32,
     pub Scope: PNRP_SCOPE,
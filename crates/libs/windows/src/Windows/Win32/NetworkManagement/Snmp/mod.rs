@@ -609,6 +609,7 @@ pub struct SNMP_API_TRANSLATE_MODE(pub u32);
 impl windows_core::TypeKind for SNMP_API_TRANSLATE_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SNMP_API_TRANSLATE_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SNMP_API_TRANSLATE_MODE").field(&self.0).finish()
@@ -620,6 +621,7 @@ pub struct SNMP_ERROR(pub u32);
 impl windows_core::TypeKind for SNMP_ERROR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SNMP_ERROR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SNMP_ERROR").field(&self.0).finish()
@@ -631,6 +633,7 @@ pub struct SNMP_ERROR_STATUS(pub u32);
 impl windows_core::TypeKind for SNMP_ERROR_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SNMP_ERROR_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SNMP_ERROR_STATUS").field(&self.0).finish()
@@ -642,6 +645,7 @@ pub struct SNMP_EXTENSION_REQUEST_TYPE(pub u32);
 impl windows_core::TypeKind for SNMP_EXTENSION_REQUEST_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SNMP_EXTENSION_REQUEST_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SNMP_EXTENSION_REQUEST_TYPE").field(&self.0).finish()
@@ -653,6 +657,7 @@ pub struct SNMP_GENERICTRAP(pub u32);
 impl windows_core::TypeKind for SNMP_GENERICTRAP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SNMP_GENERICTRAP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SNMP_GENERICTRAP").field(&self.0).finish()
@@ -664,6 +669,7 @@ pub struct SNMP_LOG(pub i32);
 impl windows_core::TypeKind for SNMP_LOG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SNMP_LOG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SNMP_LOG").field(&self.0).finish()
@@ -675,6 +681,7 @@ pub struct SNMP_OUTPUT_LOG_TYPE(pub u32);
 impl windows_core::TypeKind for SNMP_OUTPUT_LOG_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SNMP_OUTPUT_LOG_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SNMP_OUTPUT_LOG_TYPE").field(&self.0).finish()
@@ -686,6 +693,7 @@ pub struct SNMP_PDU_TYPE(pub u32);
 impl windows_core::TypeKind for SNMP_PDU_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SNMP_PDU_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SNMP_PDU_TYPE").field(&self.0).finish()
@@ -697,6 +705,7 @@ pub struct SNMP_STATUS(pub u32);
 impl windows_core::TypeKind for SNMP_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SNMP_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SNMP_STATUS").field(&self.0).finish()
@@ -859,7 +868,8 @@ impl Default for SnmpVarBindList {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct smiCNTR64 {
     pub hipart: u32,
     pub lopart: u32,
@@ -873,7 +883,8 @@ impl Default for smiCNTR64 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct smiOCTETS {
     pub len: u32,
     pub ptr: *mut u8,
@@ -887,7 +898,8 @@ impl Default for smiOCTETS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct smiOID {
     pub len: u32,
     pub ptr: *mut u32,
@@ -933,7 +945,8 @@ impl Default for smiVALUE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct smiVENDORINFO {
     pub vendorName: [i8; 64],
     pub vendorContact: [i8; 64],
@@ -0,0 +1,263 @@
+impl windows_core::imp::ProxyVtable for IDot11AdHocInterface_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceSignature(this: *mut core::ffi::c_void, psignature: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocInterface", "GetDeviceSignature", 3usize, |this| ((**(this as *const *const IDot11AdHocInterface_Vtbl)).GetDeviceSignature)(this, psignature), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFriendlyName(this: *mut core::ffi::c_void, ppszname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocInterface", "GetFriendlyName", 4usize, |this| ((**(this as *const *const IDot11AdHocInterface_Vtbl)).GetFriendlyName)(this, ppszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDot11d(this: *mut core::ffi::c_void, pf11d: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocInterface", "IsDot11d", 5usize, |this| ((**(this as *const *const IDot11AdHocInterface_Vtbl)).IsDot11d)(this, pf11d), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAdHocCapable(this: *mut core::ffi::c_void, pfadhoccapable: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocInterface", "IsAdHocCapable", 6usize, |this| ((**(this as *const *const IDot11AdHocInterface_Vtbl)).IsAdHocCapable)(this, pfadhoccapable), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRadioOn(this: *mut core::ffi::c_void, pfisradioon: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocInterface", "IsRadioOn", 7usize, |this| ((**(this as *const *const IDot11AdHocInterface_Vtbl)).IsRadioOn)(this, pfisradioon), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActiveNetwork(this: *mut core::ffi::c_void, ppnetwork: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocInterface", "GetActiveNetwork", 8usize, |this| ((**(this as *const *const IDot11AdHocInterface_Vtbl)).GetActiveNetwork)(this, ppnetwork), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIEnumSecuritySettings(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocInterface", "GetIEnumSecuritySettings", 9usize, |this| ((**(this as *const *const IDot11AdHocInterface_Vtbl)).GetIEnumSecuritySettings)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIEnumDot11AdHocNetworks(this: *mut core::ffi::c_void, pfilterguid: *const windows_core::GUID, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocInterface", "GetIEnumDot11AdHocNetworks", 10usize, |this| ((**(this as *const *const IDot11AdHocInterface_Vtbl)).GetIEnumDot11AdHocNetworks)(this, pfilterguid, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pstate: *mut DOT11_ADHOC_NETWORK_CONNECTION_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocInterface", "GetStatus", 11usize, |this| ((**(this as *const *const IDot11AdHocInterface_Vtbl)).GetStatus)(this, pstate), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDeviceSignature,
+            GetFriendlyName,
+            IsDot11d,
+            IsAdHocCapable,
+            IsRadioOn,
+            GetActiveNetwork,
+            GetIEnumSecuritySettings,
+            GetIEnumDot11AdHocNetworks,
+            GetStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDot11AdHocInterface as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDot11AdHocInterfaceNotificationSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnConnectionStatusChange(this: *mut core::ffi::c_void, estatus: DOT11_ADHOC_NETWORK_CONNECTION_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocInterfaceNotificationSink", "OnConnectionStatusChange", 3usize, |this| ((**(this as *const *const IDot11AdHocInterfaceNotificationSink_Vtbl)).OnConnectionStatusChange)(this, estatus), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnConnectionStatusChange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDot11AdHocInterfaceNotificationSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDot11AdHocManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateNetwork(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, password: windows_core::PCWSTR, geographicalid: i32, pinterface: *mut core::ffi::c_void, psecurity: *mut core::ffi::c_void, pcontextguid: *const windows_core::GUID, piadhoc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocManager", "CreateNetwork", 3usize, |this| ((**(this as *const *const IDot11AdHocManager_Vtbl)).CreateNetwork)(this, name, password, geographicalid, pinterface, psecurity, pcontextguid, piadhoc), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommitCreatedNetwork(this: *mut core::ffi::c_void, piadhoc: *mut core::ffi::c_void, fsaveprofile: super::super::Foundation::BOOLEAN, fmakesavedprofileuserspecific: super::super::Foundation::BOOLEAN) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocManager", "CommitCreatedNetwork", 4usize, |this| ((**(this as *const *const IDot11AdHocManager_Vtbl)).CommitCreatedNetwork)(this, piadhoc, fsaveprofile, fmakesavedprofileuserspecific), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIEnumDot11AdHocNetworks(this: *mut core::ffi::c_void, pcontextguid: *const windows_core::GUID, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocManager", "GetIEnumDot11AdHocNetworks", 5usize, |this| ((**(this as *const *const IDot11AdHocManager_Vtbl)).GetIEnumDot11AdHocNetworks)(this, pcontextguid, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIEnumDot11AdHocInterfaces(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocManager", "GetIEnumDot11AdHocInterfaces", 6usize, |this| ((**(this as *const *const IDot11AdHocManager_Vtbl)).GetIEnumDot11AdHocInterfaces)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNetwork(this: *mut core::ffi::c_void, networksignature: *const windows_core::GUID, pnetwork: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocManager", "GetNetwork", 7usize, |this| ((**(this as *const *const IDot11AdHocManager_Vtbl)).GetNetwork)(this, networksignature, pnetwork), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateNetwork,
+            CommitCreatedNetwork,
+            GetIEnumDot11AdHocNetworks,
+            GetIEnumDot11AdHocInterfaces,
+            GetNetwork,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDot11AdHocManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDot11AdHocManagerNotificationSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnNetworkAdd(this: *mut core::ffi::c_void, piadhocnetwork: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocManagerNotificationSink", "OnNetworkAdd", 3usize, |this| ((**(this as *const *const IDot11AdHocManagerNotificationSink_Vtbl)).OnNetworkAdd)(this, piadhocnetwork), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnNetworkRemove(this: *mut core::ffi::c_void, signature: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocManagerNotificationSink", "OnNetworkRemove", 4usize, |this| ((**(this as *const *const IDot11AdHocManagerNotificationSink_Vtbl)).OnNetworkRemove)(this, signature), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnInterfaceAdd(this: *mut core::ffi::c_void, piadhocinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocManagerNotificationSink", "OnInterfaceAdd", 5usize, |this| ((**(this as *const *const IDot11AdHocManagerNotificationSink_Vtbl)).OnInterfaceAdd)(this, piadhocinterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnInterfaceRemove(this: *mut core::ffi::c_void, signature: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocManagerNotificationSink", "OnInterfaceRemove", 6usize, |this| ((**(this as *const *const IDot11AdHocManagerNotificationSink_Vtbl)).OnInterfaceRemove)(this, signature), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnNetworkAdd, OnNetworkRemove, OnInterfaceAdd, OnInterfaceRemove }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDot11AdHocManagerNotificationSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDot11AdHocNetwork_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, estatus: *mut DOT11_ADHOC_NETWORK_CONNECTION_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocNetwork", "GetStatus", 3usize, |this| ((**(this as *const *const IDot11AdHocNetwork_Vtbl)).GetStatus)(this, estatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSSID(this: *mut core::ffi::c_void, ppszwssid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocNetwork", "GetSSID", 4usize, |this| ((**(this as *const *const IDot11AdHocNetwork_Vtbl)).GetSSID)(this, ppszwssid), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasProfile(this: *mut core::ffi::c_void, pf11d: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocNetwork", "HasProfile", 5usize, |this| ((**(this as *const *const IDot11AdHocNetwork_Vtbl)).HasProfile)(this, pf11d), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProfileName(this: *mut core::ffi::c_void, ppszwprofilename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocNetwork", "GetProfileName", 6usize, |this| ((**(this as *const *const IDot11AdHocNetwork_Vtbl)).GetProfileName)(this, ppszwprofilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteProfile(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocNetwork", "DeleteProfile", 7usize, |this| ((**(this as *const *const IDot11AdHocNetwork_Vtbl)).DeleteProfile)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignalQuality(this: *mut core::ffi::c_void, pustrengthvalue: *mut u32, pustrengthmax: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocNetwork", "GetSignalQuality", 8usize, |this| ((**(this as *const *const IDot11AdHocNetwork_Vtbl)).GetSignalQuality)(this, pustrengthvalue, pustrengthmax), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSecuritySetting(this: *mut core::ffi::c_void, padhocsecuritysetting: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocNetwork", "GetSecuritySetting", 9usize, |this| ((**(this as *const *const IDot11AdHocNetwork_Vtbl)).GetSecuritySetting)(this, padhocsecuritysetting), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContextGuid(this: *mut core::ffi::c_void, pcontextguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocNetwork", "GetContextGuid", 10usize, |this| ((**(this as *const *const IDot11AdHocNetwork_Vtbl)).GetContextGuid)(this, pcontextguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignature(this: *mut core::ffi::c_void, psignature: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocNetwork", "GetSignature", 11usize, |this| ((**(this as *const *const IDot11AdHocNetwork_Vtbl)).GetSignature)(this, psignature), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInterface(this: *mut core::ffi::c_void, padhocinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocNetwork", "GetInterface", 12usize, |this| ((**(this as *const *const IDot11AdHocNetwork_Vtbl)).GetInterface)(this, padhocinterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn Connect(this: *mut core::ffi::c_void, passphrase: windows_core::PCWSTR, geographicalid: i32, fsaveprofile: super::super::Foundation::BOOLEAN, fmakesavedprofileuserspecific: super::super::Foundation::BOOLEAN) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocNetwork", "Connect", 13usize, |this| ((**(this as *const *const IDot11AdHocNetwork_Vtbl)).Connect)(this, passphrase, geographicalid, fsaveprofile, fmakesavedprofileuserspecific), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocNetwork", "Disconnect", 14usize, |this| ((**(this as *const *const IDot11AdHocNetwork_Vtbl)).Disconnect)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetStatus,
+            GetSSID,
+            HasProfile,
+            GetProfileName,
+            DeleteProfile,
+            GetSignalQuality,
+            GetSecuritySetting,
+            GetContextGuid,
+            GetSignature,
+            GetInterface,
+            Connect,
+            Disconnect,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDot11AdHocNetwork as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDot11AdHocNetworkNotificationSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnStatusChange(this: *mut core::ffi::c_void, estatus: DOT11_ADHOC_NETWORK_CONNECTION_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocNetworkNotificationSink", "OnStatusChange", 3usize, |this| ((**(this as *const *const IDot11AdHocNetworkNotificationSink_Vtbl)).OnStatusChange)(this, estatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnConnectFail(this: *mut core::ffi::c_void, efailreason: DOT11_ADHOC_CONNECT_FAIL_REASON) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocNetworkNotificationSink", "OnConnectFail", 4usize, |this| ((**(this as *const *const IDot11AdHocNetworkNotificationSink_Vtbl)).OnConnectFail)(this, efailreason), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnStatusChange, OnConnectFail }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDot11AdHocNetworkNotificationSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDot11AdHocSecuritySettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDot11AuthAlgorithm(this: *mut core::ffi::c_void, pauth: *mut DOT11_ADHOC_AUTH_ALGORITHM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocSecuritySettings", "GetDot11AuthAlgorithm", 3usize, |this| ((**(this as *const *const IDot11AdHocSecuritySettings_Vtbl)).GetDot11AuthAlgorithm)(this, pauth), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDot11CipherAlgorithm(this: *mut core::ffi::c_void, pcipher: *mut DOT11_ADHOC_CIPHER_ALGORITHM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDot11AdHocSecuritySettings", "GetDot11CipherAlgorithm", 4usize, |this| ((**(this as *const *const IDot11AdHocSecuritySettings_Vtbl)).GetDot11CipherAlgorithm)(this, pcipher), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDot11AuthAlgorithm, GetDot11CipherAlgorithm }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDot11AdHocSecuritySettings as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumDot11AdHocInterfaces_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDot11AdHocInterfaces", "Next", 3usize, |this| ((**(this as *const *const IEnumDot11AdHocInterfaces_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDot11AdHocInterfaces", "Skip", 4usize, |this| ((**(this as *const *const IEnumDot11AdHocInterfaces_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDot11AdHocInterfaces", "Reset", 5usize, |this| ((**(this as *const *const IEnumDot11AdHocInterfaces_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDot11AdHocInterfaces", "Clone", 6usize, |this| ((**(this as *const *const IEnumDot11AdHocInterfaces_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumDot11AdHocInterfaces as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumDot11AdHocNetworks_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDot11AdHocNetworks", "Next", 3usize, |this| ((**(this as *const *const IEnumDot11AdHocNetworks_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDot11AdHocNetworks", "Skip", 4usize, |this| ((**(this as *const *const IEnumDot11AdHocNetworks_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDot11AdHocNetworks", "Reset", 5usize, |this| ((**(this as *const *const IEnumDot11AdHocNetworks_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDot11AdHocNetworks", "Clone", 6usize, |this| ((**(this as *const *const IEnumDot11AdHocNetworks_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumDot11AdHocNetworks as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumDot11AdHocSecuritySettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDot11AdHocSecuritySettings", "Next", 3usize, |this| ((**(this as *const *const IEnumDot11AdHocSecuritySettings_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDot11AdHocSecuritySettings", "Skip", 4usize, |this| ((**(this as *const *const IEnumDot11AdHocSecuritySettings_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDot11AdHocSecuritySettings", "Reset", 5usize, |this| ((**(this as *const *const IEnumDot11AdHocSecuritySettings_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDot11AdHocSecuritySettings", "Clone", 6usize, |this| ((**(this as *const *const IEnumDot11AdHocSecuritySettings_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumDot11AdHocSecuritySettings as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
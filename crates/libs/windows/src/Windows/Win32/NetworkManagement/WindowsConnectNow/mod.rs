@@ -397,6 +397,7 @@ pub struct WCN_ATTRIBUTE_TYPE(pub i32);
 impl windows_core::TypeKind for WCN_ATTRIBUTE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WCN_ATTRIBUTE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WCN_ATTRIBUTE_TYPE").field(&self.0).finish()
@@ -408,6 +409,7 @@ pub struct WCN_PASSWORD_TYPE(pub i32);
 impl windows_core::TypeKind for WCN_PASSWORD_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WCN_PASSWORD_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WCN_PASSWORD_TYPE").field(&self.0).finish()
@@ -419,6 +421,7 @@ pub struct WCN_SESSION_STATUS(pub i32);
 impl windows_core::TypeKind for WCN_SESSION_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WCN_SESSION_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WCN_SESSION_STATUS").field(&self.0).finish()
@@ -430,6 +433,7 @@ pub struct WCN_VALUE_TYPE_ASSOCIATION_STATE(pub i32);
 impl windows_core::TypeKind for WCN_VALUE_TYPE_ASSOCIATION_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WCN_VALUE_TYPE_ASSOCIATION_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WCN_VALUE_TYPE_ASSOCIATION_STATE").field(&self.0).finish()
@@ -441,6 +445,7 @@ pub struct WCN_VALUE_TYPE_AUTHENTICATION_TYPE(pub i32);
 impl windows_core::TypeKind for WCN_VALUE_TYPE_AUTHENTICATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WCN_VALUE_TYPE_AUTHENTICATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WCN_VALUE_TYPE_AUTHENTICATION_TYPE").field(&self.0).finish()
@@ -452,6 +457,7 @@ pub struct WCN_VALUE_TYPE_BOOLEAN(pub i32);
 impl windows_core::TypeKind for WCN_VALUE_TYPE_BOOLEAN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WCN_VALUE_TYPE_BOOLEAN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WCN_VALUE_TYPE_BOOLEAN").field(&self.0).finish()
@@ -463,6 +469,7 @@ pub struct WCN_VALUE_TYPE_CONFIGURATION_ERROR(pub i32);
 impl windows_core::TypeKind for WCN_VALUE_TYPE_CONFIGURATION_ERROR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WCN_VALUE_TYPE_CONFIGURATION_ERROR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WCN_VALUE_TYPE_CONFIGURATION_ERROR").field(&self.0).finish()
@@ -474,6 +481,7 @@ pub struct WCN_VALUE_TYPE_CONFIG_METHODS(pub i32);
 impl windows_core::TypeKind for WCN_VALUE_TYPE_CONFIG_METHODS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WCN_VALUE_TYPE_CONFIG_METHODS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WCN_VALUE_TYPE_CONFIG_METHODS").field(&self.0).finish()
@@ -485,6 +493,7 @@ pub struct WCN_VALUE_TYPE_CONNECTION_TYPE(pub i32);
 impl windows_core::TypeKind for WCN_VALUE_TYPE_CONNECTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WCN_VALUE_TYPE_CONNECTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WCN_VALUE_TYPE_CONNECTION_TYPE").field(&self.0).finish()
@@ -496,6 +505,7 @@ pub struct WCN_VALUE_TYPE_DEVICE_PASSWORD_ID(pub i32);
 impl windows_core::TypeKind for WCN_VALUE_TYPE_DEVICE_PASSWORD_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WCN_VALUE_TYPE_DEVICE_PASSWORD_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WCN_VALUE_TYPE_DEVICE_PASSWORD_ID").field(&self.0).finish()
@@ -507,6 +517,7 @@ pub struct WCN_VALUE_TYPE_ENCRYPTION_TYPE(pub i32);
 impl windows_core::TypeKind for WCN_VALUE_TYPE_ENCRYPTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WCN_VALUE_TYPE_ENCRYPTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WCN_VALUE_TYPE_ENCRYPTION_TYPE").field(&self.0).finish()
@@ -518,6 +529,7 @@ pub struct WCN_VALUE_TYPE_MESSAGE_TYPE(pub i32);
 impl windows_core::TypeKind for WCN_VALUE_TYPE_MESSAGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WCN_VALUE_TYPE_MESSAGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WCN_VALUE_TYPE_MESSAGE_TYPE").field(&self.0).finish()
@@ -529,6 +541,7 @@ pub struct WCN_VALUE_TYPE_REQUEST_TYPE(pub i32);
 impl windows_core::TypeKind for WCN_VALUE_TYPE_REQUEST_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WCN_VALUE_TYPE_REQUEST_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WCN_VALUE_TYPE_REQUEST_TYPE").field(&self.0).finish()
@@ -540,6 +553,7 @@ pub struct WCN_VALUE_TYPE_RESPONSE_TYPE(pub i32);
 impl windows_core::TypeKind for WCN_VALUE_TYPE_RESPONSE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WCN_VALUE_TYPE_RESPONSE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WCN_VALUE_TYPE_RESPONSE_TYPE").field(&self.0).finish()
@@ -551,6 +565,7 @@ pub struct WCN_VALUE_TYPE_RF_BANDS(pub i32);
 impl windows_core::TypeKind for WCN_VALUE_TYPE_RF_BANDS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WCN_VALUE_TYPE_RF_BANDS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WCN_VALUE_TYPE_RF_BANDS").field(&self.0).finish()
@@ -562,6 +577,7 @@ pub struct WCN_VALUE_TYPE_VERSION(pub i32);
 impl windows_core::TypeKind for WCN_VALUE_TYPE_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WCN_VALUE_TYPE_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WCN_VALUE_TYPE_VERSION").field(&self.0).finish()
@@ -573,6 +589,7 @@ pub struct WCN_VALUE_TYPE_WI_FI_PROTECTED_SETUP_STATE(pub i32);
 impl windows_core::TypeKind for WCN_VALUE_TYPE_WI_FI_PROTECTED_SETUP_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WCN_VALUE_TYPE_WI_FI_PROTECTED_SETUP_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WCN_VALUE_TYPE_WI_FI_PROTECTED_SETUP_STATE").field(&self.0).finish()
@@ -595,7 +612,8 @@ impl Default for WCN_VALUE_TYPE_PRIMARY_DEVICE_TYPE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WCN_VENDOR_EXTENSION_SPEC {
     pub VendorId: u32,
     pub SubType: u32,
@@ -612,3 +630,5 @@ impl Default for WCN_VENDOR_EXTENSION_SPEC {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
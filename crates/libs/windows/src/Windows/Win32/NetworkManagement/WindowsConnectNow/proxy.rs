@@ -0,0 +1,70 @@
+impl windows_core::imp::ProxyVtable for IWCNConnectNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConnectSucceeded(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWCNConnectNotify", "ConnectSucceeded", 3usize, |this| ((**(this as *const *const IWCNConnectNotify_Vtbl)).ConnectSucceeded)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectFailed(this: *mut core::ffi::c_void, hrfailure: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWCNConnectNotify", "ConnectFailed", 4usize, |this| ((**(this as *const *const IWCNConnectNotify_Vtbl)).ConnectFailed)(this, hrfailure), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ConnectSucceeded, ConnectFailed }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWCNConnectNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWCNDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPassword(this: *mut core::ffi::c_void, r#type: WCN_PASSWORD_TYPE, dwpasswordlength: u32, pbpassword: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWCNDevice", "SetPassword", 3usize, |this| ((**(this as *const *const IWCNDevice_Vtbl)).SetPassword)(this, r#type, dwpasswordlength, pbpassword), |result| Some(*result))
+        }
+        unsafe extern "system" fn Connect(this: *mut core::ffi::c_void, pnotify: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWCNDevice", "Connect", 4usize, |this| ((**(this as *const *const IWCNDevice_Vtbl)).Connect)(this, pnotify), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttribute(this: *mut core::ffi::c_void, attributetype: WCN_ATTRIBUTE_TYPE, dwmaxbuffersize: u32, pbbuffer: *mut u8, pdwbufferused: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWCNDevice", "GetAttribute", 5usize, |this| ((**(this as *const *const IWCNDevice_Vtbl)).GetAttribute)(this, attributetype, dwmaxbuffersize, pbbuffer, pdwbufferused), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIntegerAttribute(this: *mut core::ffi::c_void, attributetype: WCN_ATTRIBUTE_TYPE, puinteger: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWCNDevice", "GetIntegerAttribute", 6usize, |this| ((**(this as *const *const IWCNDevice_Vtbl)).GetIntegerAttribute)(this, attributetype, puinteger), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStringAttribute(this: *mut core::ffi::c_void, attributetype: WCN_ATTRIBUTE_TYPE, cchmaxstring: u32, wszstring: windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWCNDevice", "GetStringAttribute", 7usize, |this| ((**(this as *const *const IWCNDevice_Vtbl)).GetStringAttribute)(this, attributetype, cchmaxstring, wszstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNetworkProfile(this: *mut core::ffi::c_void, cchmaxstringlength: u32, wszprofile: windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWCNDevice", "GetNetworkProfile", 8usize, |this| ((**(this as *const *const IWCNDevice_Vtbl)).GetNetworkProfile)(this, cchmaxstringlength, wszprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNetworkProfile(this: *mut core::ffi::c_void, pszprofilexml: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWCNDevice", "SetNetworkProfile", 9usize, |this| ((**(this as *const *const IWCNDevice_Vtbl)).SetNetworkProfile)(this, pszprofilexml), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVendorExtension(this: *mut core::ffi::c_void, pvendorextspec: *const WCN_VENDOR_EXTENSION_SPEC, dwmaxbuffersize: u32, pbbuffer: *mut u8, pdwbufferused: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWCNDevice", "GetVendorExtension", 10usize, |this| ((**(this as *const *const IWCNDevice_Vtbl)).GetVendorExtension)(this, pvendorextspec, dwmaxbuffersize, pbbuffer, pdwbufferused), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVendorExtension(this: *mut core::ffi::c_void, pvendorextspec: *const WCN_VENDOR_EXTENSION_SPEC, cbbuffer: u32, pbbuffer: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWCNDevice", "SetVendorExtension", 11usize, |this| ((**(this as *const *const IWCNDevice_Vtbl)).SetVendorExtension)(this, pvendorextspec, cbbuffer, pbbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unadvise(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWCNDevice", "Unadvise", 12usize, |this| ((**(this as *const *const IWCNDevice_Vtbl)).Unadvise)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNFCPasswordParams(this: *mut core::ffi::c_void, r#type: WCN_PASSWORD_TYPE, dwoobpasswordid: u32, dwpasswordlength: u32, pbpassword: *const u8, dwremotepublickeyhashlength: u32, pbremotepublickeyhash: *const u8, dwdhkeybloblength: u32, pbdhkeyblob: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWCNDevice", "SetNFCPasswordParams", 13usize, |this| ((**(this as *const *const IWCNDevice_Vtbl)).SetNFCPasswordParams)(this, r#type, dwoobpasswordid, dwpasswordlength, pbpassword, dwremotepublickeyhashlength, pbremotepublickeyhash, dwdhkeybloblength, pbdhkeyblob), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetPassword,
+            Connect,
+            GetAttribute,
+            GetIntegerAttribute,
+            GetStringAttribute,
+            GetNetworkProfile,
+            SetNetworkProfile,
+            GetVendorExtension,
+            SetVendorExtension,
+            Unadvise,
+            SetNFCPasswordParams,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWCNDevice as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -2862,6 +2862,7 @@ pub struct FW_DYNAMIC_KEYWORD_ADDRESS_ENUM_FLAGS(pub i32);
 impl windows_core::TypeKind for FW_DYNAMIC_KEYWORD_ADDRESS_ENUM_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FW_DYNAMIC_KEYWORD_ADDRESS_ENUM_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FW_DYNAMIC_KEYWORD_ADDRESS_ENUM_FLAGS").field(&self.0).finish()
@@ -2906,6 +2907,7 @@ pub struct FW_DYNAMIC_KEYWORD_ADDRESS_FLAGS(pub i32);
 impl windows_core::TypeKind for FW_DYNAMIC_KEYWORD_ADDRESS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FW_DYNAMIC_KEYWORD_ADDRESS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FW_DYNAMIC_KEYWORD_ADDRESS_FLAGS").field(&self.0).finish()
@@ -2950,6 +2952,7 @@ pub struct FW_DYNAMIC_KEYWORD_ORIGIN_TYPE(pub i32);
 impl windows_core::TypeKind for FW_DYNAMIC_KEYWORD_ORIGIN_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FW_DYNAMIC_KEYWORD_ORIGIN_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FW_DYNAMIC_KEYWORD_ORIGIN_TYPE").field(&self.0).finish()
@@ -2961,6 +2964,7 @@ pub struct ICS_TARGETTYPE(pub i32);
 impl windows_core::TypeKind for ICS_TARGETTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ICS_TARGETTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ICS_TARGETTYPE").field(&self.0).finish()
@@ -2972,6 +2976,7 @@ pub struct INET_FIREWALL_AC_CHANGE_TYPE(pub i32);
 impl windows_core::TypeKind for INET_FIREWALL_AC_CHANGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INET_FIREWALL_AC_CHANGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INET_FIREWALL_AC_CHANGE_TYPE").field(&self.0).finish()
@@ -2983,6 +2988,7 @@ pub struct INET_FIREWALL_AC_CREATION_TYPE(pub i32);
 impl windows_core::TypeKind for INET_FIREWALL_AC_CREATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INET_FIREWALL_AC_CREATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INET_FIREWALL_AC_CREATION_TYPE").field(&self.0).finish()
@@ -2994,6 +3000,7 @@ pub struct NETCONMGR_ENUM_FLAGS(pub i32);
 impl windows_core::TypeKind for NETCONMGR_ENUM_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NETCONMGR_ENUM_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NETCONMGR_ENUM_FLAGS").field(&self.0).finish()
@@ -3005,6 +3012,7 @@ pub struct NETCONUI_CONNECT_FLAGS(pub i32);
 impl windows_core::TypeKind for NETCONUI_CONNECT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NETCONUI_CONNECT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NETCONUI_CONNECT_FLAGS").field(&self.0).finish()
@@ -3016,6 +3024,7 @@ pub struct NETCON_CHARACTERISTIC_FLAGS(pub i32);
 impl windows_core::TypeKind for NETCON_CHARACTERISTIC_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NETCON_CHARACTERISTIC_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NETCON_CHARACTERISTIC_FLAGS").field(&self.0).finish()
@@ -3027,6 +3036,7 @@ pub struct NETCON_MEDIATYPE(pub i32);
 impl windows_core::TypeKind for NETCON_MEDIATYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NETCON_MEDIATYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NETCON_MEDIATYPE").field(&self.0).finish()
@@ -3038,6 +3048,7 @@ pub struct NETCON_STATUS(pub i32);
 impl windows_core::TypeKind for NETCON_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NETCON_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NETCON_STATUS").field(&self.0).finish()
@@ -3049,6 +3060,7 @@ pub struct NETCON_TYPE(pub i32);
 impl windows_core::TypeKind for NETCON_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NETCON_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NETCON_TYPE").field(&self.0).finish()
@@ -3060,6 +3072,7 @@ pub struct NETISO_ERROR_TYPE(pub i32);
 impl windows_core::TypeKind for NETISO_ERROR_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NETISO_ERROR_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NETISO_ERROR_TYPE").field(&self.0).finish()
@@ -3071,6 +3084,7 @@ pub struct NETISO_FLAG(pub i32);
 impl windows_core::TypeKind for NETISO_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NETISO_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NETISO_FLAG").field(&self.0).finish()
@@ -3082,6 +3096,7 @@ pub struct NET_FW_ACTION(pub i32);
 impl windows_core::TypeKind for NET_FW_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NET_FW_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NET_FW_ACTION").field(&self.0).finish()
@@ -3093,6 +3108,7 @@ pub struct NET_FW_AUTHENTICATE_TYPE(pub i32);
 impl windows_core::TypeKind for NET_FW_AUTHENTICATE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NET_FW_AUTHENTICATE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NET_FW_AUTHENTICATE_TYPE").field(&self.0).finish()
@@ -3104,6 +3120,7 @@ pub struct NET_FW_EDGE_TRAVERSAL_TYPE(pub i32);
 impl windows_core::TypeKind for NET_FW_EDGE_TRAVERSAL_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NET_FW_EDGE_TRAVERSAL_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NET_FW_EDGE_TRAVERSAL_TYPE").field(&self.0).finish()
@@ -3115,6 +3132,7 @@ pub struct NET_FW_IP_PROTOCOL(pub i32);
 impl windows_core::TypeKind for NET_FW_IP_PROTOCOL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NET_FW_IP_PROTOCOL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NET_FW_IP_PROTOCOL").field(&self.0).finish()
@@ -3126,6 +3144,7 @@ pub struct NET_FW_IP_VERSION(pub i32);
 impl windows_core::TypeKind for NET_FW_IP_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NET_FW_IP_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NET_FW_IP_VERSION").field(&self.0).finish()
@@ -3137,6 +3156,7 @@ pub struct NET_FW_MODIFY_STATE(pub i32);
 impl windows_core::TypeKind for NET_FW_MODIFY_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NET_FW_MODIFY_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NET_FW_MODIFY_STATE").field(&self.0).finish()
@@ -3148,6 +3168,7 @@ pub struct NET_FW_POLICY_TYPE(pub i32);
 impl windows_core::TypeKind for NET_FW_POLICY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NET_FW_POLICY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NET_FW_POLICY_TYPE").field(&self.0).finish()
@@ -3159,6 +3180,7 @@ pub struct NET_FW_PROFILE_TYPE(pub i32);
 impl windows_core::TypeKind for NET_FW_PROFILE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NET_FW_PROFILE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NET_FW_PROFILE_TYPE").field(&self.0).finish()
@@ -3170,6 +3192,7 @@ pub struct NET_FW_PROFILE_TYPE2(pub i32);
 impl windows_core::TypeKind for NET_FW_PROFILE_TYPE2 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NET_FW_PROFILE_TYPE2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NET_FW_PROFILE_TYPE2").field(&self.0).finish()
@@ -3181,6 +3204,7 @@ pub struct NET_FW_RULE_CATEGORY(pub i32);
 impl windows_core::TypeKind for NET_FW_RULE_CATEGORY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NET_FW_RULE_CATEGORY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NET_FW_RULE_CATEGORY").field(&self.0).finish()
@@ -3192,6 +3216,7 @@ pub struct NET_FW_RULE_DIRECTION(pub i32);
 impl windows_core::TypeKind for NET_FW_RULE_DIRECTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NET_FW_RULE_DIRECTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NET_FW_RULE_DIRECTION").field(&self.0).finish()
@@ -3203,6 +3228,7 @@ pub struct NET_FW_SCOPE(pub i32);
 impl windows_core::TypeKind for NET_FW_SCOPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NET_FW_SCOPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NET_FW_SCOPE").field(&self.0).finish()
@@ -3214,6 +3240,7 @@ pub struct NET_FW_SERVICE_TYPE(pub i32);
 impl windows_core::TypeKind for NET_FW_SERVICE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NET_FW_SERVICE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NET_FW_SERVICE_TYPE").field(&self.0).finish()
@@ -3225,6 +3252,7 @@ pub struct SHARINGCONNECTIONTYPE(pub i32);
 impl windows_core::TypeKind for SHARINGCONNECTIONTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHARINGCONNECTIONTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHARINGCONNECTIONTYPE").field(&self.0).finish()
@@ -3236,13 +3264,15 @@ pub struct SHARINGCONNECTION_ENUM_FLAGS(pub i32);
 impl windows_core::TypeKind for SHARINGCONNECTION_ENUM_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHARINGCONNECTION_ENUM_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHARINGCONNECTION_ENUM_FLAGS").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FW_DYNAMIC_KEYWORD_ADDRESS0 {
     pub id: windows_core::GUID,
     pub keyword: windows_core::PCWSTR,
@@ -3258,7 +3288,8 @@ impl Default for FW_DYNAMIC_KEYWORD_ADDRESS0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FW_DYNAMIC_KEYWORD_ADDRESS_DATA0 {
     pub dynamicKeywordAddress: FW_DYNAMIC_KEYWORD_ADDRESS0,
     pub next: *mut FW_DYNAMIC_KEYWORD_ADDRESS_DATA0,
@@ -3274,7 +3305,8 @@ impl Default for FW_DYNAMIC_KEYWORD_ADDRESS_DATA0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct INET_FIREWALL_AC_BINARIES {
     pub count: u32,
     pub binaries: *mut windows_core::PWSTR,
@@ -3289,7 +3321,8 @@ impl Default for INET_FIREWALL_AC_BINARIES {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Security")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct INET_FIREWALL_AC_CAPABILITIES {
     pub count: u32,
     pub capabilities: *mut super::super::Security::SID_AND_ATTRIBUTES,
@@ -3344,7 +3377,8 @@ impl Default for INET_FIREWALL_AC_CHANGE_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Security")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct INET_FIREWALL_APP_CONTAINER {
     pub appContainerSid: *mut super::super::Security::SID,
     pub userSid: *mut super::super::Security::SID,
@@ -3367,7 +3401,8 @@ impl Default for INET_FIREWALL_APP_CONTAINER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NETCON_PROPERTIES {
     pub guidId: windows_core::GUID,
     pub pszwName: windows_core::PWSTR,
@@ -3406,3 +3441,5 @@ pub type PFN_FWUPDATEDYNAMICKEYWORDADDRESS0 = Option<unsafe extern "system" fn(d
 pub type PNETISO_EDP_ID_CALLBACK_FN = Option<unsafe extern "system" fn(context: *mut core::ffi::c_void, wszenterpriseid: windows_core::PCWSTR, dwerr: u32)>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1822 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDynamicPortMapping_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExternalIPAddress(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicPortMapping", "ExternalIPAddress", 7usize, |this| ((**(this as *const *const IDynamicPortMapping_Vtbl)).ExternalIPAddress)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteHost(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicPortMapping", "RemoteHost", 8usize, |this| ((**(this as *const *const IDynamicPortMapping_Vtbl)).RemoteHost)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExternalPort(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicPortMapping", "ExternalPort", 9usize, |this| ((**(this as *const *const IDynamicPortMapping_Vtbl)).ExternalPort)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Protocol(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicPortMapping", "Protocol", 10usize, |this| ((**(this as *const *const IDynamicPortMapping_Vtbl)).Protocol)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn InternalPort(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicPortMapping", "InternalPort", 11usize, |this| ((**(this as *const *const IDynamicPortMapping_Vtbl)).InternalPort)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn InternalClient(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicPortMapping", "InternalClient", 12usize, |this| ((**(this as *const *const IDynamicPortMapping_Vtbl)).InternalClient)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicPortMapping", "Enabled", 13usize, |this| ((**(this as *const *const IDynamicPortMapping_Vtbl)).Enabled)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicPortMapping", "Description", 14usize, |this| ((**(this as *const *const IDynamicPortMapping_Vtbl)).Description)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn LeaseDuration(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicPortMapping", "LeaseDuration", 15usize, |this| ((**(this as *const *const IDynamicPortMapping_Vtbl)).LeaseDuration)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RenewLease(this: *mut core::ffi::c_void, lleasedurationdesired: i32, pleasedurationreturned: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicPortMapping", "RenewLease", 16usize, |this| ((**(this as *const *const IDynamicPortMapping_Vtbl)).RenewLease)(this, lleasedurationdesired, pleasedurationreturned), |result| Some(*result))
+        }
+        unsafe extern "system" fn EditInternalClient(this: *mut core::ffi::c_void, bstrinternalclient: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicPortMapping", "EditInternalClient", 17usize, |this| ((**(this as *const *const IDynamicPortMapping_Vtbl)).EditInternalClient)(this, bstrinternalclient), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enable(this: *mut core::ffi::c_void, vb: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicPortMapping", "Enable", 18usize, |this| ((**(this as *const *const IDynamicPortMapping_Vtbl)).Enable)(this, vb), |result| Some(*result))
+        }
+        unsafe extern "system" fn EditDescription(this: *mut core::ffi::c_void, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicPortMapping", "EditDescription", 19usize, |this| ((**(this as *const *const IDynamicPortMapping_Vtbl)).EditDescription)(this, bstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn EditInternalPort(this: *mut core::ffi::c_void, linternalport: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicPortMapping", "EditInternalPort", 20usize, |this| ((**(this as *const *const IDynamicPortMapping_Vtbl)).EditInternalPort)(this, linternalport), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ExternalIPAddress,
+            RemoteHost,
+            ExternalPort,
+            Protocol,
+            InternalPort,
+            InternalClient,
+            Enabled,
+            Description,
+            LeaseDuration,
+            RenewLease,
+            EditInternalClient,
+            Enable,
+            EditDescription,
+            EditInternalPort,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDynamicPortMapping as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDynamicPortMappingCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicPortMappingCollection", "_NewEnum", 7usize, |this| ((**(this as *const *const IDynamicPortMappingCollection_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, bstrremotehost: core::mem::MaybeUninit<windows_core::BSTR>, lexternalport: i32, bstrprotocol: core::mem::MaybeUninit<windows_core::BSTR>, ppdpm: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicPortMappingCollection", "get_Item", 8usize, |this| ((**(this as *const *const IDynamicPortMappingCollection_Vtbl)).get_Item)(this, bstrremotehost, lexternalport, bstrprotocol, ppdpm), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicPortMappingCollection", "Count", 9usize, |this| ((**(this as *const *const IDynamicPortMappingCollection_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, bstrremotehost: core::mem::MaybeUninit<windows_core::BSTR>, lexternalport: i32, bstrprotocol: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicPortMappingCollection", "Remove", 10usize, |this| ((**(this as *const *const IDynamicPortMappingCollection_Vtbl)).Remove)(this, bstrremotehost, lexternalport, bstrprotocol), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, bstrremotehost: core::mem::MaybeUninit<windows_core::BSTR>, lexternalport: i32, bstrprotocol: core::mem::MaybeUninit<windows_core::BSTR>, linternalport: i32, bstrinternalclient: core::mem::MaybeUninit<windows_core::BSTR>, benabled: super::super::Foundation::VARIANT_BOOL, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>, lleaseduration: i32, ppdpm: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicPortMappingCollection", "Add", 11usize, |this| ((**(this as *const *const IDynamicPortMappingCollection_Vtbl)).Add)(this, bstrremotehost, lexternalport, bstrprotocol, linternalport, bstrinternalclient, benabled, bstrdescription, lleaseduration, ppdpm), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            Count,
+            Remove,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDynamicPortMappingCollection as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumNetConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetConnection", "Next", 3usize, |this| ((**(this as *const *const IEnumNetConnection_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetConnection", "Skip", 4usize, |this| ((**(this as *const *const IEnumNetConnection_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetConnection", "Reset", 5usize, |this| ((**(this as *const *const IEnumNetConnection_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetConnection", "Clone", 6usize, |this| ((**(this as *const *const IEnumNetConnection_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumNetConnection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumNetSharingEveryConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgvar: *mut core::mem::MaybeUninit<windows_core::VARIANT>, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetSharingEveryConnection", "Next", 3usize, |this| ((**(this as *const *const IEnumNetSharingEveryConnection_Vtbl)).Next)(this, celt, rgvar, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetSharingEveryConnection", "Skip", 4usize, |this| ((**(this as *const *const IEnumNetSharingEveryConnection_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetSharingEveryConnection", "Reset", 5usize, |this| ((**(this as *const *const IEnumNetSharingEveryConnection_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetSharingEveryConnection", "Clone", 6usize, |this| ((**(this as *const *const IEnumNetSharingEveryConnection_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumNetSharingEveryConnection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumNetSharingPortMapping_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgvar: *mut core::mem::MaybeUninit<windows_core::VARIANT>, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetSharingPortMapping", "Next", 3usize, |this| ((**(this as *const *const IEnumNetSharingPortMapping_Vtbl)).Next)(this, celt, rgvar, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetSharingPortMapping", "Skip", 4usize, |this| ((**(this as *const *const IEnumNetSharingPortMapping_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetSharingPortMapping", "Reset", 5usize, |this| ((**(this as *const *const IEnumNetSharingPortMapping_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetSharingPortMapping", "Clone", 6usize, |this| ((**(this as *const *const IEnumNetSharingPortMapping_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumNetSharingPortMapping as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumNetSharingPrivateConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgvar: *mut core::mem::MaybeUninit<windows_core::VARIANT>, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetSharingPrivateConnection", "Next", 3usize, |this| ((**(this as *const *const IEnumNetSharingPrivateConnection_Vtbl)).Next)(this, celt, rgvar, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetSharingPrivateConnection", "Skip", 4usize, |this| ((**(this as *const *const IEnumNetSharingPrivateConnection_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetSharingPrivateConnection", "Reset", 5usize, |this| ((**(this as *const *const IEnumNetSharingPrivateConnection_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetSharingPrivateConnection", "Clone", 6usize, |this| ((**(this as *const *const IEnumNetSharingPrivateConnection_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumNetSharingPrivateConnection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumNetSharingPublicConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgvar: *mut core::mem::MaybeUninit<windows_core::VARIANT>, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetSharingPublicConnection", "Next", 3usize, |this| ((**(this as *const *const IEnumNetSharingPublicConnection_Vtbl)).Next)(this, celt, rgvar, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetSharingPublicConnection", "Skip", 4usize, |this| ((**(this as *const *const IEnumNetSharingPublicConnection_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetSharingPublicConnection", "Reset", 5usize, |this| ((**(this as *const *const IEnumNetSharingPublicConnection_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetSharingPublicConnection", "Clone", 6usize, |this| ((**(this as *const *const IEnumNetSharingPublicConnection_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumNetSharingPublicConnection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INATEventManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetExternalIPAddressCallback(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INATEventManager", "SetExternalIPAddressCallback", 7usize, |this| ((**(this as *const *const INATEventManager_Vtbl)).SetExternalIPAddressCallback)(this, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNumberOfEntriesCallback(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INATEventManager", "SetNumberOfEntriesCallback", 8usize, |this| ((**(this as *const *const INATEventManager_Vtbl)).SetNumberOfEntriesCallback)(this, punk), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetExternalIPAddressCallback,
+            SetNumberOfEntriesCallback,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INATEventManager as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INATExternalIPAddressCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NewExternalIPAddress(this: *mut core::ffi::c_void, bstrnewexternalipaddress: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INATExternalIPAddressCallback", "NewExternalIPAddress", 3usize, |this| ((**(this as *const *const INATExternalIPAddressCallback_Vtbl)).NewExternalIPAddress)(this, bstrnewexternalipaddress), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NewExternalIPAddress }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INATExternalIPAddressCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INATNumberOfEntriesCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NewNumberOfEntries(this: *mut core::ffi::c_void, lnewnumberofentries: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INATNumberOfEntriesCallback", "NewNumberOfEntries", 3usize, |this| ((**(this as *const *const INATNumberOfEntriesCallback_Vtbl)).NewNumberOfEntries)(this, lnewnumberofentries), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NewNumberOfEntries }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INATNumberOfEntriesCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Connect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetConnection", "Connect", 3usize, |this| ((**(this as *const *const INetConnection_Vtbl)).Connect)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetConnection", "Disconnect", 4usize, |this| ((**(this as *const *const INetConnection_Vtbl)).Disconnect)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetConnection", "Delete", 5usize, |this| ((**(this as *const *const INetConnection_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duplicate(this: *mut core::ffi::c_void, pszwduplicatename: windows_core::PCWSTR, ppcon: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetConnection", "Duplicate", 6usize, |this| ((**(this as *const *const INetConnection_Vtbl)).Duplicate)(this, pszwduplicatename, ppcon), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, ppprops: *mut *mut NETCON_PROPERTIES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetConnection", "GetProperties", 7usize, |this| ((**(this as *const *const INetConnection_Vtbl)).GetProperties)(this, ppprops), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUiObjectClassId(this: *mut core::ffi::c_void, pclsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetConnection", "GetUiObjectClassId", 8usize, |this| ((**(this as *const *const INetConnection_Vtbl)).GetUiObjectClassId)(this, pclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rename(this: *mut core::ffi::c_void, pszwnewname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetConnection", "Rename", 9usize, |this| ((**(this as *const *const INetConnection_Vtbl)).Rename)(this, pszwnewname), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Connect,
+            Disconnect,
+            Delete,
+            Duplicate,
+            GetProperties,
+            GetUiObjectClassId,
+            Rename,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetConnection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetConnectionConnectUi_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetConnection(this: *mut core::ffi::c_void, pcon: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetConnectionConnectUi", "SetConnection", 3usize, |this| ((**(this as *const *const INetConnectionConnectUi_Vtbl)).SetConnection)(this, pcon), |result| Some(*result))
+        }
+        unsafe extern "system" fn Connect(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetConnectionConnectUi", "Connect", 4usize, |this| ((**(this as *const *const INetConnectionConnectUi_Vtbl)).Connect)(this, hwndparent, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnect(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetConnectionConnectUi", "Disconnect", 5usize, |this| ((**(this as *const *const INetConnectionConnectUi_Vtbl)).Disconnect)(this, hwndparent, dwflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetConnection, Connect, Disconnect }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetConnectionConnectUi as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetConnectionManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumConnections(this: *mut core::ffi::c_void, flags: NETCONMGR_ENUM_FLAGS, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetConnectionManager", "EnumConnections", 3usize, |this| ((**(this as *const *const INetConnectionManager_Vtbl)).EnumConnections)(this, flags, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnumConnections }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetConnectionManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetConnectionProps_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Guid(this: *mut core::ffi::c_void, pbstrguid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetConnectionProps", "Guid", 7usize, |this| ((**(this as *const *const INetConnectionProps_Vtbl)).Guid)(this, pbstrguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetConnectionProps", "Name", 8usize, |this| ((**(this as *const *const INetConnectionProps_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceName(this: *mut core::ffi::c_void, pbstrdevicename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetConnectionProps", "DeviceName", 9usize, |this| ((**(this as *const *const INetConnectionProps_Vtbl)).DeviceName)(this, pbstrdevicename), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, pstatus: *mut NETCON_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetConnectionProps", "Status", 10usize, |this| ((**(this as *const *const INetConnectionProps_Vtbl)).Status)(this, pstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaType(this: *mut core::ffi::c_void, pmediatype: *mut NETCON_MEDIATYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetConnectionProps", "MediaType", 11usize, |this| ((**(this as *const *const INetConnectionProps_Vtbl)).MediaType)(this, pmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Characteristics(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetConnectionProps", "Characteristics", 12usize, |this| ((**(this as *const *const INetConnectionProps_Vtbl)).Characteristics)(this, pdwflags), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Guid,
+            Name,
+            DeviceName,
+            Status,
+            MediaType,
+            Characteristics,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetConnectionProps as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetFwAuthorizedApplication_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwAuthorizedApplication", "Name", 7usize, |this| ((**(this as *const *const INetFwAuthorizedApplication_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwAuthorizedApplication", "SetName", 8usize, |this| ((**(this as *const *const INetFwAuthorizedApplication_Vtbl)).SetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessImageFileName(this: *mut core::ffi::c_void, imagefilename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwAuthorizedApplication", "ProcessImageFileName", 9usize, |this| ((**(this as *const *const INetFwAuthorizedApplication_Vtbl)).ProcessImageFileName)(this, imagefilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProcessImageFileName(this: *mut core::ffi::c_void, imagefilename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwAuthorizedApplication", "SetProcessImageFileName", 10usize, |this| ((**(this as *const *const INetFwAuthorizedApplication_Vtbl)).SetProcessImageFileName)(this, imagefilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn IpVersion(this: *mut core::ffi::c_void, ipversion: *mut NET_FW_IP_VERSION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwAuthorizedApplication", "IpVersion", 11usize, |this| ((**(this as *const *const INetFwAuthorizedApplication_Vtbl)).IpVersion)(this, ipversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIpVersion(this: *mut core::ffi::c_void, ipversion: NET_FW_IP_VERSION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwAuthorizedApplication", "SetIpVersion", 12usize, |this| ((**(this as *const *const INetFwAuthorizedApplication_Vtbl)).SetIpVersion)(this, ipversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn Scope(this: *mut core::ffi::c_void, scope: *mut NET_FW_SCOPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwAuthorizedApplication", "Scope", 13usize, |this| ((**(this as *const *const INetFwAuthorizedApplication_Vtbl)).Scope)(this, scope), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScope(this: *mut core::ffi::c_void, scope: NET_FW_SCOPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwAuthorizedApplication", "SetScope", 14usize, |this| ((**(this as *const *const INetFwAuthorizedApplication_Vtbl)).SetScope)(this, scope), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteAddresses(this: *mut core::ffi::c_void, remoteaddrs: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwAuthorizedApplication", "RemoteAddresses", 15usize, |this| ((**(this as *const *const INetFwAuthorizedApplication_Vtbl)).RemoteAddresses)(this, remoteaddrs), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemoteAddresses(this: *mut core::ffi::c_void, remoteaddrs: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwAuthorizedApplication", "SetRemoteAddresses", 16usize, |this| ((**(this as *const *const INetFwAuthorizedApplication_Vtbl)).SetRemoteAddresses)(this, remoteaddrs), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, enabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwAuthorizedApplication", "Enabled", 17usize, |this| ((**(this as *const *const INetFwAuthorizedApplication_Vtbl)).Enabled)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, enabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwAuthorizedApplication", "SetEnabled", 18usize, |this| ((**(this as *const *const INetFwAuthorizedApplication_Vtbl)).SetEnabled)(this, enabled), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            SetName,
+            ProcessImageFileName,
+            SetProcessImageFileName,
+            IpVersion,
+            SetIpVersion,
+            Scope,
+            SetScope,
+            RemoteAddresses,
+            SetRemoteAddresses,
+            Enabled,
+            SetEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetFwAuthorizedApplication as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetFwAuthorizedApplications_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwAuthorizedApplications", "Count", 7usize, |this| ((**(this as *const *const INetFwAuthorizedApplications_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, app: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwAuthorizedApplications", "Add", 8usize, |this| ((**(this as *const *const INetFwAuthorizedApplications_Vtbl)).Add)(this, app), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, imagefilename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwAuthorizedApplications", "Remove", 9usize, |this| ((**(this as *const *const INetFwAuthorizedApplications_Vtbl)).Remove)(this, imagefilename), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, imagefilename: core::mem::MaybeUninit<windows_core::BSTR>, app: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwAuthorizedApplications", "Item", 10usize, |this| ((**(this as *const *const INetFwAuthorizedApplications_Vtbl)).Item)(this, imagefilename, app), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, newenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwAuthorizedApplications", "_NewEnum", 11usize, |this| ((**(this as *const *const INetFwAuthorizedApplications_Vtbl))._NewEnum)(this, newenum), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetFwAuthorizedApplications as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetFwIcmpSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AllowOutboundDestinationUnreachable(this: *mut core::ffi::c_void, allow: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwIcmpSettings", "AllowOutboundDestinationUnreachable", 7usize, |this| ((**(this as *const *const INetFwIcmpSettings_Vtbl)).AllowOutboundDestinationUnreachable)(this, allow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowOutboundDestinationUnreachable(this: *mut core::ffi::c_void, allow: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwIcmpSettings", "SetAllowOutboundDestinationUnreachable", 8usize, |this| ((**(this as *const *const INetFwIcmpSettings_Vtbl)).SetAllowOutboundDestinationUnreachable)(this, allow), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowRedirect(this: *mut core::ffi::c_void, allow: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwIcmpSettings", "AllowRedirect", 9usize, |this| ((**(this as *const *const INetFwIcmpSettings_Vtbl)).AllowRedirect)(this, allow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowRedirect(this: *mut core::ffi::c_void, allow: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwIcmpSettings", "SetAllowRedirect", 10usize, |this| ((**(this as *const *const INetFwIcmpSettings_Vtbl)).SetAllowRedirect)(this, allow), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowInboundEchoRequest(this: *mut core::ffi::c_void, allow: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwIcmpSettings", "AllowInboundEchoRequest", 11usize, |this| ((**(this as *const *const INetFwIcmpSettings_Vtbl)).AllowInboundEchoRequest)(this, allow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowInboundEchoRequest(this: *mut core::ffi::c_void, allow: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwIcmpSettings", "SetAllowInboundEchoRequest", 12usize, |this| ((**(this as *const *const INetFwIcmpSettings_Vtbl)).SetAllowInboundEchoRequest)(this, allow), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowOutboundTimeExceeded(this: *mut core::ffi::c_void, allow: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwIcmpSettings", "AllowOutboundTimeExceeded", 13usize, |this| ((**(this as *const *const INetFwIcmpSettings_Vtbl)).AllowOutboundTimeExceeded)(this, allow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowOutboundTimeExceeded(this: *mut core::ffi::c_void, allow: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwIcmpSettings", "SetAllowOutboundTimeExceeded", 14usize, |this| ((**(this as *const *const INetFwIcmpSettings_Vtbl)).SetAllowOutboundTimeExceeded)(this, allow), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowOutboundParameterProblem(this: *mut core::ffi::c_void, allow: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwIcmpSettings", "AllowOutboundParameterProblem", 15usize, |this| ((**(this as *const *const INetFwIcmpSettings_Vtbl)).AllowOutboundParameterProblem)(this, allow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowOutboundParameterProblem(this: *mut core::ffi::c_void, allow: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwIcmpSettings", "SetAllowOutboundParameterProblem", 16usize, |this| ((**(this as *const *const INetFwIcmpSettings_Vtbl)).SetAllowOutboundParameterProblem)(this, allow), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowOutboundSourceQuench(this: *mut core::ffi::c_void, allow: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwIcmpSettings", "AllowOutboundSourceQuench", 17usize, |this| ((**(this as *const *const INetFwIcmpSettings_Vtbl)).AllowOutboundSourceQuench)(this, allow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowOutboundSourceQuench(this: *mut core::ffi::c_void, allow: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwIcmpSettings", "SetAllowOutboundSourceQuench", 18usize, |this| ((**(this as *const *const INetFwIcmpSettings_Vtbl)).SetAllowOutboundSourceQuench)(this, allow), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowInboundRouterRequest(this: *mut core::ffi::c_void, allow: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwIcmpSettings", "AllowInboundRouterRequest", 19usize, |this| ((**(this as *const *const INetFwIcmpSettings_Vtbl)).AllowInboundRouterRequest)(this, allow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowInboundRouterRequest(this: *mut core::ffi::c_void, allow: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwIcmpSettings", "SetAllowInboundRouterRequest", 20usize, |this| ((**(this as *const *const INetFwIcmpSettings_Vtbl)).SetAllowInboundRouterRequest)(this, allow), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowInboundTimestampRequest(this: *mut core::ffi::c_void, allow: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwIcmpSettings", "AllowInboundTimestampRequest", 21usize, |this| ((**(this as *const *const INetFwIcmpSettings_Vtbl)).AllowInboundTimestampRequest)(this, allow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowInboundTimestampRequest(this: *mut core::ffi::c_void, allow: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwIcmpSettings", "SetAllowInboundTimestampRequest", 22usize, |this| ((**(this as *const *const INetFwIcmpSettings_Vtbl)).SetAllowInboundTimestampRequest)(this, allow), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowInboundMaskRequest(this: *mut core::ffi::c_void, allow: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwIcmpSettings", "AllowInboundMaskRequest", 23usize, |this| ((**(this as *const *const INetFwIcmpSettings_Vtbl)).AllowInboundMaskRequest)(this, allow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowInboundMaskRequest(this: *mut core::ffi::c_void, allow: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwIcmpSettings", "SetAllowInboundMaskRequest", 24usize, |this| ((**(this as *const *const INetFwIcmpSettings_Vtbl)).SetAllowInboundMaskRequest)(this, allow), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowOutboundPacketTooBig(this: *mut core::ffi::c_void, allow: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwIcmpSettings", "AllowOutboundPacketTooBig", 25usize, |this| ((**(this as *const *const INetFwIcmpSettings_Vtbl)).AllowOutboundPacketTooBig)(this, allow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowOutboundPacketTooBig(this: *mut core::ffi::c_void, allow: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwIcmpSettings", "SetAllowOutboundPacketTooBig", 26usize, |this| ((**(this as *const *const INetFwIcmpSettings_Vtbl)).SetAllowOutboundPacketTooBig)(this, allow), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AllowOutboundDestinationUnreachable,
+            SetAllowOutboundDestinationUnreachable,
+            AllowRedirect,
+            SetAllowRedirect,
+            AllowInboundEchoRequest,
+            SetAllowInboundEchoRequest,
+            AllowOutboundTimeExceeded,
+            SetAllowOutboundTimeExceeded,
+            AllowOutboundParameterProblem,
+            SetAllowOutboundParameterProblem,
+            AllowOutboundSourceQuench,
+            SetAllowOutboundSourceQuench,
+            AllowInboundRouterRequest,
+            SetAllowInboundRouterRequest,
+            AllowInboundTimestampRequest,
+            SetAllowInboundTimestampRequest,
+            AllowInboundMaskRequest,
+            SetAllowInboundMaskRequest,
+            AllowOutboundPacketTooBig,
+            SetAllowOutboundPacketTooBig,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetFwIcmpSettings as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetFwMgr_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn LocalPolicy(this: *mut core::ffi::c_void, localpolicy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwMgr", "LocalPolicy", 7usize, |this| ((**(this as *const *const INetFwMgr_Vtbl)).LocalPolicy)(this, localpolicy), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentProfileType(this: *mut core::ffi::c_void, profiletype: *mut NET_FW_PROFILE_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwMgr", "CurrentProfileType", 8usize, |this| ((**(this as *const *const INetFwMgr_Vtbl)).CurrentProfileType)(this, profiletype), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestoreDefaults(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwMgr", "RestoreDefaults", 9usize, |this| ((**(this as *const *const INetFwMgr_Vtbl)).RestoreDefaults)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPortAllowed(this: *mut core::ffi::c_void, imagefilename: core::mem::MaybeUninit<windows_core::BSTR>, ipversion: NET_FW_IP_VERSION, portnumber: i32, localaddress: core::mem::MaybeUninit<windows_core::BSTR>, ipprotocol: NET_FW_IP_PROTOCOL, allowed: *mut core::mem::MaybeUninit<windows_core::VARIANT>, restricted: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwMgr", "IsPortAllowed", 10usize, |this| ((**(this as *const *const INetFwMgr_Vtbl)).IsPortAllowed)(this, imagefilename, ipversion, portnumber, localaddress, ipprotocol, allowed, restricted), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsIcmpTypeAllowed(this: *mut core::ffi::c_void, ipversion: NET_FW_IP_VERSION, localaddress: core::mem::MaybeUninit<windows_core::BSTR>, r#type: u8, allowed: *mut core::mem::MaybeUninit<windows_core::VARIANT>, restricted: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwMgr", "IsIcmpTypeAllowed", 11usize, |this| ((**(this as *const *const INetFwMgr_Vtbl)).IsIcmpTypeAllowed)(this, ipversion, localaddress, r#type, allowed, restricted), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            LocalPolicy,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            LocalPolicy: 0,
+            CurrentProfileType,
+            RestoreDefaults,
+            IsPortAllowed,
+            IsIcmpTypeAllowed,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetFwMgr as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetFwOpenPort_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwOpenPort", "Name", 7usize, |this| ((**(this as *const *const INetFwOpenPort_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwOpenPort", "SetName", 8usize, |this| ((**(this as *const *const INetFwOpenPort_Vtbl)).SetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn IpVersion(this: *mut core::ffi::c_void, ipversion: *mut NET_FW_IP_VERSION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwOpenPort", "IpVersion", 9usize, |this| ((**(this as *const *const INetFwOpenPort_Vtbl)).IpVersion)(this, ipversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIpVersion(this: *mut core::ffi::c_void, ipversion: NET_FW_IP_VERSION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwOpenPort", "SetIpVersion", 10usize, |this| ((**(this as *const *const INetFwOpenPort_Vtbl)).SetIpVersion)(this, ipversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn Protocol(this: *mut core::ffi::c_void, ipprotocol: *mut NET_FW_IP_PROTOCOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwOpenPort", "Protocol", 11usize, |this| ((**(this as *const *const INetFwOpenPort_Vtbl)).Protocol)(this, ipprotocol), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProtocol(this: *mut core::ffi::c_void, ipprotocol: NET_FW_IP_PROTOCOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwOpenPort", "SetProtocol", 12usize, |this| ((**(this as *const *const INetFwOpenPort_Vtbl)).SetProtocol)(this, ipprotocol), |result| Some(*result))
+        }
+        unsafe extern "system" fn Port(this: *mut core::ffi::c_void, portnumber: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwOpenPort", "Port", 13usize, |this| ((**(this as *const *const INetFwOpenPort_Vtbl)).Port)(this, portnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPort(this: *mut core::ffi::c_void, portnumber: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwOpenPort", "SetPort", 14usize, |this| ((**(this as *const *const INetFwOpenPort_Vtbl)).SetPort)(this, portnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn Scope(this: *mut core::ffi::c_void, scope: *mut NET_FW_SCOPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwOpenPort", "Scope", 15usize, |this| ((**(this as *const *const INetFwOpenPort_Vtbl)).Scope)(this, scope), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScope(this: *mut core::ffi::c_void, scope: NET_FW_SCOPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwOpenPort", "SetScope", 16usize, |this| ((**(this as *const *const INetFwOpenPort_Vtbl)).SetScope)(this, scope), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteAddresses(this: *mut core::ffi::c_void, remoteaddrs: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwOpenPort", "RemoteAddresses", 17usize, |this| ((**(this as *const *const INetFwOpenPort_Vtbl)).RemoteAddresses)(this, remoteaddrs), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemoteAddresses(this: *mut core::ffi::c_void, remoteaddrs: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwOpenPort", "SetRemoteAddresses", 18usize, |this| ((**(this as *const *const INetFwOpenPort_Vtbl)).SetRemoteAddresses)(this, remoteaddrs), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, enabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwOpenPort", "Enabled", 19usize, |this| ((**(this as *const *const INetFwOpenPort_Vtbl)).Enabled)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, enabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwOpenPort", "SetEnabled", 20usize, |this| ((**(this as *const *const INetFwOpenPort_Vtbl)).SetEnabled)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn BuiltIn(this: *mut core::ffi::c_void, builtin: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwOpenPort", "BuiltIn", 21usize, |this| ((**(this as *const *const INetFwOpenPort_Vtbl)).BuiltIn)(this, builtin), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            SetName,
+            IpVersion,
+            SetIpVersion,
+            Protocol,
+            SetProtocol,
+            Port,
+            SetPort,
+            Scope,
+            SetScope,
+            RemoteAddresses,
+            SetRemoteAddresses,
+            Enabled,
+            SetEnabled,
+            BuiltIn,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetFwOpenPort as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetFwOpenPorts_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwOpenPorts", "Count", 7usize, |this| ((**(this as *const *const INetFwOpenPorts_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, port: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwOpenPorts", "Add", 8usize, |this| ((**(this as *const *const INetFwOpenPorts_Vtbl)).Add)(this, port), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, portnumber: i32, ipprotocol: NET_FW_IP_PROTOCOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwOpenPorts", "Remove", 9usize, |this| ((**(this as *const *const INetFwOpenPorts_Vtbl)).Remove)(this, portnumber, ipprotocol), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, portnumber: i32, ipprotocol: NET_FW_IP_PROTOCOL, openport: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwOpenPorts", "Item", 10usize, |this| ((**(this as *const *const INetFwOpenPorts_Vtbl)).Item)(this, portnumber, ipprotocol, openport), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, newenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwOpenPorts", "_NewEnum", 11usize, |this| ((**(this as *const *const INetFwOpenPorts_Vtbl))._NewEnum)(this, newenum), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetFwOpenPorts as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetFwPolicy_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CurrentProfile(this: *mut core::ffi::c_void, profile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwPolicy", "CurrentProfile", 7usize, |this| ((**(this as *const *const INetFwPolicy_Vtbl)).CurrentProfile)(this, profile), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetProfileByType(this: *mut core::ffi::c_void, profiletype: NET_FW_PROFILE_TYPE, profile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwPolicy", "GetProfileByType", 8usize, |this| ((**(this as *const *const INetFwPolicy_Vtbl)).GetProfileByType)(this, profiletype, profile), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CurrentProfile,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CurrentProfile: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetProfileByType,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetProfileByType: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetFwPolicy as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetFwPolicy2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentProfileTypes(this: *mut core::ffi::c_void, profiletypesbitmask: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwPolicy2", "CurrentProfileTypes", 7usize, |this| ((**(this as *const *const INetFwPolicy2_Vtbl)).CurrentProfileTypes)(this, profiletypesbitmask), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_FirewallEnabled(this: *mut core::ffi::c_void, profiletype: NET_FW_PROFILE_TYPE2, enabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwPolicy2", "get_FirewallEnabled", 8usize, |this| ((**(this as *const *const INetFwPolicy2_Vtbl)).get_FirewallEnabled)(this, profiletype, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_FirewallEnabled(this: *mut core::ffi::c_void, profiletype: NET_FW_PROFILE_TYPE2, enabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwPolicy2", "put_FirewallEnabled", 9usize, |this| ((**(this as *const *const INetFwPolicy2_Vtbl)).put_FirewallEnabled)(this, profiletype, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_ExcludedInterfaces(this: *mut core::ffi::c_void, profiletype: NET_FW_PROFILE_TYPE2, interfaces: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwPolicy2", "get_ExcludedInterfaces", 10usize, |this| ((**(this as *const *const INetFwPolicy2_Vtbl)).get_ExcludedInterfaces)(this, profiletype, interfaces), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_ExcludedInterfaces(this: *mut core::ffi::c_void, profiletype: NET_FW_PROFILE_TYPE2, interfaces: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwPolicy2", "put_ExcludedInterfaces", 11usize, |this| ((**(this as *const *const INetFwPolicy2_Vtbl)).put_ExcludedInterfaces)(this, profiletype, interfaces), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_BlockAllInboundTraffic(this: *mut core::ffi::c_void, profiletype: NET_FW_PROFILE_TYPE2, block: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwPolicy2", "get_BlockAllInboundTraffic", 12usize, |this| ((**(this as *const *const INetFwPolicy2_Vtbl)).get_BlockAllInboundTraffic)(this, profiletype, block), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_BlockAllInboundTraffic(this: *mut core::ffi::c_void, profiletype: NET_FW_PROFILE_TYPE2, block: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwPolicy2", "put_BlockAllInboundTraffic", 13usize, |this| ((**(this as *const *const INetFwPolicy2_Vtbl)).put_BlockAllInboundTraffic)(this, profiletype, block), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_NotificationsDisabled(this: *mut core::ffi::c_void, profiletype: NET_FW_PROFILE_TYPE2, disabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwPolicy2", "get_NotificationsDisabled", 14usize, |this| ((**(this as *const *const INetFwPolicy2_Vtbl)).get_NotificationsDisabled)(this, profiletype, disabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_NotificationsDisabled(this: *mut core::ffi::c_void, profiletype: NET_FW_PROFILE_TYPE2, disabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwPolicy2", "put_NotificationsDisabled", 15usize, |this| ((**(this as *const *const INetFwPolicy2_Vtbl)).put_NotificationsDisabled)(this, profiletype, disabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_UnicastResponsesToMulticastBroadcastDisabled(this: *mut core::ffi::c_void, profiletype: NET_FW_PROFILE_TYPE2, disabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwPolicy2", "get_UnicastResponsesToMulticastBroadcastDisabled", 16usize, |this| ((**(this as *const *const INetFwPolicy2_Vtbl)).get_UnicastResponsesToMulticastBroadcastDisabled)(this, profiletype, disabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_UnicastResponsesToMulticastBroadcastDisabled(this: *mut core::ffi::c_void, profiletype: NET_FW_PROFILE_TYPE2, disabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwPolicy2", "put_UnicastResponsesToMulticastBroadcastDisabled", 17usize, |this| ((**(this as *const *const INetFwPolicy2_Vtbl)).put_UnicastResponsesToMulticastBroadcastDisabled)(this, profiletype, disabled), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Rules(this: *mut core::ffi::c_void, rules: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwPolicy2", "Rules", 18usize, |this| ((**(this as *const *const INetFwPolicy2_Vtbl)).Rules)(this, rules), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ServiceRestriction(this: *mut core::ffi::c_void, servicerestriction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwPolicy2", "ServiceRestriction", 19usize, |this| ((**(this as *const *const INetFwPolicy2_Vtbl)).ServiceRestriction)(this, servicerestriction), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableRuleGroup(this: *mut core::ffi::c_void, profiletypesbitmask: i32, group: core::mem::MaybeUninit<windows_core::BSTR>, enable: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwPolicy2", "EnableRuleGroup", 20usize, |this| ((**(this as *const *const INetFwPolicy2_Vtbl)).EnableRuleGroup)(this, profiletypesbitmask, group, enable), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRuleGroupEnabled(this: *mut core::ffi::c_void, profiletypesbitmask: i32, group: core::mem::MaybeUninit<windows_core::BSTR>, enabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwPolicy2", "IsRuleGroupEnabled", 21usize, |this| ((**(this as *const *const INetFwPolicy2_Vtbl)).IsRuleGroupEnabled)(this, profiletypesbitmask, group, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestoreLocalFirewallDefaults(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwPolicy2", "RestoreLocalFirewallDefaults", 22usize, |this| ((**(this as *const *const INetFwPolicy2_Vtbl)).RestoreLocalFirewallDefaults)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_DefaultInboundAction(this: *mut core::ffi::c_void, profiletype: NET_FW_PROFILE_TYPE2, action: *mut NET_FW_ACTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwPolicy2", "get_DefaultInboundAction", 23usize, |this| ((**(this as *const *const INetFwPolicy2_Vtbl)).get_DefaultInboundAction)(this, profiletype, action), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_DefaultInboundAction(this: *mut core::ffi::c_void, profiletype: NET_FW_PROFILE_TYPE2, action: NET_FW_ACTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwPolicy2", "put_DefaultInboundAction", 24usize, |this| ((**(this as *const *const INetFwPolicy2_Vtbl)).put_DefaultInboundAction)(this, profiletype, action), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_DefaultOutboundAction(this: *mut core::ffi::c_void, profiletype: NET_FW_PROFILE_TYPE2, action: *mut NET_FW_ACTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwPolicy2", "get_DefaultOutboundAction", 25usize, |this| ((**(this as *const *const INetFwPolicy2_Vtbl)).get_DefaultOutboundAction)(this, profiletype, action), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_DefaultOutboundAction(this: *mut core::ffi::c_void, profiletype: NET_FW_PROFILE_TYPE2, action: NET_FW_ACTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwPolicy2", "put_DefaultOutboundAction", 26usize, |this| ((**(this as *const *const INetFwPolicy2_Vtbl)).put_DefaultOutboundAction)(this, profiletype, action), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_IsRuleGroupCurrentlyEnabled(this: *mut core::ffi::c_void, group: core::mem::MaybeUninit<windows_core::BSTR>, enabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwPolicy2", "get_IsRuleGroupCurrentlyEnabled", 27usize, |this| ((**(this as *const *const INetFwPolicy2_Vtbl)).get_IsRuleGroupCurrentlyEnabled)(this, group, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalPolicyModifyState(this: *mut core::ffi::c_void, modifystate: *mut NET_FW_MODIFY_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwPolicy2", "LocalPolicyModifyState", 28usize, |this| ((**(this as *const *const INetFwPolicy2_Vtbl)).LocalPolicyModifyState)(this, modifystate), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CurrentProfileTypes,
+            get_FirewallEnabled,
+            put_FirewallEnabled,
+            get_ExcludedInterfaces,
+            put_ExcludedInterfaces,
+            get_BlockAllInboundTraffic,
+            put_BlockAllInboundTraffic,
+            get_NotificationsDisabled,
+            put_NotificationsDisabled,
+            get_UnicastResponsesToMulticastBroadcastDisabled,
+            put_UnicastResponsesToMulticastBroadcastDisabled,
+            #[cfg(feature = "Win32_System_Com")]
+            Rules,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Rules: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ServiceRestriction,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ServiceRestriction: 0,
+            EnableRuleGroup,
+            IsRuleGroupEnabled,
+            RestoreLocalFirewallDefaults,
+            get_DefaultInboundAction,
+            put_DefaultInboundAction,
+            get_DefaultOutboundAction,
+            put_DefaultOutboundAction,
+            get_IsRuleGroupCurrentlyEnabled,
+            LocalPolicyModifyState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetFwPolicy2 as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetFwProduct_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RuleCategories(this: *mut core::ffi::c_void, rulecategories: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwProduct", "RuleCategories", 7usize, |this| ((**(this as *const *const INetFwProduct_Vtbl)).RuleCategories)(this, rulecategories), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRuleCategories(this: *mut core::ffi::c_void, rulecategories: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwProduct", "SetRuleCategories", 8usize, |this| ((**(this as *const *const INetFwProduct_Vtbl)).SetRuleCategories)(this, rulecategories), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, displayname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwProduct", "DisplayName", 9usize, |this| ((**(this as *const *const INetFwProduct_Vtbl)).DisplayName)(this, displayname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, displayname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwProduct", "SetDisplayName", 10usize, |this| ((**(this as *const *const INetFwProduct_Vtbl)).SetDisplayName)(this, displayname), |result| Some(*result))
+        }
+        unsafe extern "system" fn PathToSignedProductExe(this: *mut core::ffi::c_void, path: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwProduct", "PathToSignedProductExe", 11usize, |this| ((**(this as *const *const INetFwProduct_Vtbl)).PathToSignedProductExe)(this, path), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RuleCategories,
+            SetRuleCategories,
+            DisplayName,
+            SetDisplayName,
+            PathToSignedProductExe,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetFwProduct as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetFwProducts_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwProducts", "Count", 7usize, |this| ((**(this as *const *const INetFwProducts_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Register(this: *mut core::ffi::c_void, product: *mut core::ffi::c_void, registration: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwProducts", "Register", 8usize, |this| ((**(this as *const *const INetFwProducts_Vtbl)).Register)(this, product, registration), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: i32, product: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwProducts", "Item", 9usize, |this| ((**(this as *const *const INetFwProducts_Vtbl)).Item)(this, index, product), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, newenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwProducts", "_NewEnum", 10usize, |this| ((**(this as *const *const INetFwProducts_Vtbl))._NewEnum)(this, newenum), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            Register,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Register: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetFwProducts as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetFwProfile_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, r#type: *mut NET_FW_PROFILE_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwProfile", "Type", 7usize, |this| ((**(this as *const *const INetFwProfile_Vtbl)).Type)(this, r#type), |result| Some(*result))
+        }
+        unsafe extern "system" fn FirewallEnabled(this: *mut core::ffi::c_void, enabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwProfile", "FirewallEnabled", 8usize, |this| ((**(this as *const *const INetFwProfile_Vtbl)).FirewallEnabled)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFirewallEnabled(this: *mut core::ffi::c_void, enabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwProfile", "SetFirewallEnabled", 9usize, |this| ((**(this as *const *const INetFwProfile_Vtbl)).SetFirewallEnabled)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExceptionsNotAllowed(this: *mut core::ffi::c_void, notallowed: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwProfile", "ExceptionsNotAllowed", 10usize, |this| ((**(this as *const *const INetFwProfile_Vtbl)).ExceptionsNotAllowed)(this, notallowed), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExceptionsNotAllowed(this: *mut core::ffi::c_void, notallowed: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwProfile", "SetExceptionsNotAllowed", 11usize, |this| ((**(this as *const *const INetFwProfile_Vtbl)).SetExceptionsNotAllowed)(this, notallowed), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotificationsDisabled(this: *mut core::ffi::c_void, disabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwProfile", "NotificationsDisabled", 12usize, |this| ((**(this as *const *const INetFwProfile_Vtbl)).NotificationsDisabled)(this, disabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNotificationsDisabled(this: *mut core::ffi::c_void, disabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwProfile", "SetNotificationsDisabled", 13usize, |this| ((**(this as *const *const INetFwProfile_Vtbl)).SetNotificationsDisabled)(this, disabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnicastResponsesToMulticastBroadcastDisabled(this: *mut core::ffi::c_void, disabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwProfile", "UnicastResponsesToMulticastBroadcastDisabled", 14usize, |this| ((**(this as *const *const INetFwProfile_Vtbl)).UnicastResponsesToMulticastBroadcastDisabled)(this, disabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUnicastResponsesToMulticastBroadcastDisabled(this: *mut core::ffi::c_void, disabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwProfile", "SetUnicastResponsesToMulticastBroadcastDisabled", 15usize, |this| ((**(this as *const *const INetFwProfile_Vtbl)).SetUnicastResponsesToMulticastBroadcastDisabled)(this, disabled), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RemoteAdminSettings(this: *mut core::ffi::c_void, remoteadminsettings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwProfile", "RemoteAdminSettings", 16usize, |this| ((**(this as *const *const INetFwProfile_Vtbl)).RemoteAdminSettings)(this, remoteadminsettings), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn IcmpSettings(this: *mut core::ffi::c_void, icmpsettings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwProfile", "IcmpSettings", 17usize, |this| ((**(this as *const *const INetFwProfile_Vtbl)).IcmpSettings)(this, icmpsettings), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GloballyOpenPorts(this: *mut core::ffi::c_void, openports: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwProfile", "GloballyOpenPorts", 18usize, |this| ((**(this as *const *const INetFwProfile_Vtbl)).GloballyOpenPorts)(this, openports), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Services(this: *mut core::ffi::c_void, services: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwProfile", "Services", 19usize, |this| ((**(this as *const *const INetFwProfile_Vtbl)).Services)(this, services), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AuthorizedApplications(this: *mut core::ffi::c_void, apps: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwProfile", "AuthorizedApplications", 20usize, |this| ((**(this as *const *const INetFwProfile_Vtbl)).AuthorizedApplications)(this, apps), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Type,
+            FirewallEnabled,
+            SetFirewallEnabled,
+            ExceptionsNotAllowed,
+            SetExceptionsNotAllowed,
+            NotificationsDisabled,
+            SetNotificationsDisabled,
+            UnicastResponsesToMulticastBroadcastDisabled,
+            SetUnicastResponsesToMulticastBroadcastDisabled,
+            #[cfg(feature = "Win32_System_Com")]
+            RemoteAdminSettings,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RemoteAdminSettings: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            IcmpSettings,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            IcmpSettings: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GloballyOpenPorts,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GloballyOpenPorts: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Services,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Services: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AuthorizedApplications,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AuthorizedApplications: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetFwProfile as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetFwRemoteAdminSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IpVersion(this: *mut core::ffi::c_void, ipversion: *mut NET_FW_IP_VERSION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRemoteAdminSettings", "IpVersion", 7usize, |this| ((**(this as *const *const INetFwRemoteAdminSettings_Vtbl)).IpVersion)(this, ipversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIpVersion(this: *mut core::ffi::c_void, ipversion: NET_FW_IP_VERSION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRemoteAdminSettings", "SetIpVersion", 8usize, |this| ((**(this as *const *const INetFwRemoteAdminSettings_Vtbl)).SetIpVersion)(this, ipversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn Scope(this: *mut core::ffi::c_void, scope: *mut NET_FW_SCOPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRemoteAdminSettings", "Scope", 9usize, |this| ((**(this as *const *const INetFwRemoteAdminSettings_Vtbl)).Scope)(this, scope), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScope(this: *mut core::ffi::c_void, scope: NET_FW_SCOPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRemoteAdminSettings", "SetScope", 10usize, |this| ((**(this as *const *const INetFwRemoteAdminSettings_Vtbl)).SetScope)(this, scope), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteAddresses(this: *mut core::ffi::c_void, remoteaddrs: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRemoteAdminSettings", "RemoteAddresses", 11usize, |this| ((**(this as *const *const INetFwRemoteAdminSettings_Vtbl)).RemoteAddresses)(this, remoteaddrs), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemoteAddresses(this: *mut core::ffi::c_void, remoteaddrs: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRemoteAdminSettings", "SetRemoteAddresses", 12usize, |this| ((**(this as *const *const INetFwRemoteAdminSettings_Vtbl)).SetRemoteAddresses)(this, remoteaddrs), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, enabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRemoteAdminSettings", "Enabled", 13usize, |this| ((**(this as *const *const INetFwRemoteAdminSettings_Vtbl)).Enabled)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, enabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRemoteAdminSettings", "SetEnabled", 14usize, |this| ((**(this as *const *const INetFwRemoteAdminSettings_Vtbl)).SetEnabled)(this, enabled), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IpVersion,
+            SetIpVersion,
+            Scope,
+            SetScope,
+            RemoteAddresses,
+            SetRemoteAddresses,
+            Enabled,
+            SetEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetFwRemoteAdminSettings as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetFwRule_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "Name", 7usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "SetName", 8usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).SetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, desc: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "Description", 9usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).Description)(this, desc), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, desc: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "SetDescription", 10usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).SetDescription)(this, desc), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplicationName(this: *mut core::ffi::c_void, imagefilename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "ApplicationName", 11usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).ApplicationName)(this, imagefilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetApplicationName(this: *mut core::ffi::c_void, imagefilename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "SetApplicationName", 12usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).SetApplicationName)(this, imagefilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceName(this: *mut core::ffi::c_void, servicename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "ServiceName", 13usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).ServiceName)(this, servicename), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServiceName(this: *mut core::ffi::c_void, servicename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "SetServiceName", 14usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).SetServiceName)(this, servicename), |result| Some(*result))
+        }
+        unsafe extern "system" fn Protocol(this: *mut core::ffi::c_void, protocol: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "Protocol", 15usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).Protocol)(this, protocol), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProtocol(this: *mut core::ffi::c_void, protocol: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "SetProtocol", 16usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).SetProtocol)(this, protocol), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalPorts(this: *mut core::ffi::c_void, portnumbers: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "LocalPorts", 17usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).LocalPorts)(this, portnumbers), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocalPorts(this: *mut core::ffi::c_void, portnumbers: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "SetLocalPorts", 18usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).SetLocalPorts)(this, portnumbers), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemotePorts(this: *mut core::ffi::c_void, portnumbers: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "RemotePorts", 19usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).RemotePorts)(this, portnumbers), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemotePorts(this: *mut core::ffi::c_void, portnumbers: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "SetRemotePorts", 20usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).SetRemotePorts)(this, portnumbers), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalAddresses(this: *mut core::ffi::c_void, localaddrs: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "LocalAddresses", 21usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).LocalAddresses)(this, localaddrs), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocalAddresses(this: *mut core::ffi::c_void, localaddrs: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "SetLocalAddresses", 22usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).SetLocalAddresses)(this, localaddrs), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteAddresses(this: *mut core::ffi::c_void, remoteaddrs: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "RemoteAddresses", 23usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).RemoteAddresses)(this, remoteaddrs), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemoteAddresses(this: *mut core::ffi::c_void, remoteaddrs: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "SetRemoteAddresses", 24usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).SetRemoteAddresses)(this, remoteaddrs), |result| Some(*result))
+        }
+        unsafe extern "system" fn IcmpTypesAndCodes(this: *mut core::ffi::c_void, icmptypesandcodes: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "IcmpTypesAndCodes", 25usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).IcmpTypesAndCodes)(this, icmptypesandcodes), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIcmpTypesAndCodes(this: *mut core::ffi::c_void, icmptypesandcodes: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "SetIcmpTypesAndCodes", 26usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).SetIcmpTypesAndCodes)(this, icmptypesandcodes), |result| Some(*result))
+        }
+        unsafe extern "system" fn Direction(this: *mut core::ffi::c_void, dir: *mut NET_FW_RULE_DIRECTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "Direction", 27usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).Direction)(this, dir), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDirection(this: *mut core::ffi::c_void, dir: NET_FW_RULE_DIRECTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "SetDirection", 28usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).SetDirection)(this, dir), |result| Some(*result))
+        }
+        unsafe extern "system" fn Interfaces(this: *mut core::ffi::c_void, interfaces: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "Interfaces", 29usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).Interfaces)(this, interfaces), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInterfaces(this: *mut core::ffi::c_void, interfaces: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "SetInterfaces", 30usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).SetInterfaces)(this, interfaces), |result| Some(*result))
+        }
+        unsafe extern "system" fn InterfaceTypes(this: *mut core::ffi::c_void, interfacetypes: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "InterfaceTypes", 31usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).InterfaceTypes)(this, interfacetypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInterfaceTypes(this: *mut core::ffi::c_void, interfacetypes: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "SetInterfaceTypes", 32usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).SetInterfaceTypes)(this, interfacetypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, enabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "Enabled", 33usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).Enabled)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, enabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "SetEnabled", 34usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).SetEnabled)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn Grouping(this: *mut core::ffi::c_void, context: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "Grouping", 35usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).Grouping)(this, context), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGrouping(this: *mut core::ffi::c_void, context: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "SetGrouping", 36usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).SetGrouping)(this, context), |result| Some(*result))
+        }
+        unsafe extern "system" fn Profiles(this: *mut core::ffi::c_void, profiletypesbitmask: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "Profiles", 37usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).Profiles)(this, profiletypesbitmask), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProfiles(this: *mut core::ffi::c_void, profiletypesbitmask: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "SetProfiles", 38usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).SetProfiles)(this, profiletypesbitmask), |result| Some(*result))
+        }
+        unsafe extern "system" fn EdgeTraversal(this: *mut core::ffi::c_void, enabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "EdgeTraversal", 39usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).EdgeTraversal)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEdgeTraversal(this: *mut core::ffi::c_void, enabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "SetEdgeTraversal", 40usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).SetEdgeTraversal)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn Action(this: *mut core::ffi::c_void, action: *mut NET_FW_ACTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "Action", 41usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).Action)(this, action), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAction(this: *mut core::ffi::c_void, action: NET_FW_ACTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule", "SetAction", 42usize, |this| ((**(this as *const *const INetFwRule_Vtbl)).SetAction)(this, action), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            SetName,
+            Description,
+            SetDescription,
+            ApplicationName,
+            SetApplicationName,
+            ServiceName,
+            SetServiceName,
+            Protocol,
+            SetProtocol,
+            LocalPorts,
+            SetLocalPorts,
+            RemotePorts,
+            SetRemotePorts,
+            LocalAddresses,
+            SetLocalAddresses,
+            RemoteAddresses,
+            SetRemoteAddresses,
+            IcmpTypesAndCodes,
+            SetIcmpTypesAndCodes,
+            Direction,
+            SetDirection,
+            Interfaces,
+            SetInterfaces,
+            InterfaceTypes,
+            SetInterfaceTypes,
+            Enabled,
+            SetEnabled,
+            Grouping,
+            SetGrouping,
+            Profiles,
+            SetProfiles,
+            EdgeTraversal,
+            SetEdgeTraversal,
+            Action,
+            SetAction,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetFwRule as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetFwRule2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EdgeTraversalOptions(this: *mut core::ffi::c_void, loptions: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule2", "EdgeTraversalOptions", 43usize, |this| ((**(this as *const *const INetFwRule2_Vtbl)).EdgeTraversalOptions)(this, loptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEdgeTraversalOptions(this: *mut core::ffi::c_void, loptions: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule2", "SetEdgeTraversalOptions", 44usize, |this| ((**(this as *const *const INetFwRule2_Vtbl)).SetEdgeTraversalOptions)(this, loptions), |result| Some(*result))
+        }
+        Self { base__: <INetFwRule_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EdgeTraversalOptions, SetEdgeTraversalOptions }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetFwRule2 as windows_core::Interface>::IID || <INetFwRule_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetFwRule3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LocalAppPackageId(this: *mut core::ffi::c_void, wszpackageid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule3", "LocalAppPackageId", 45usize, |this| ((**(this as *const *const INetFwRule3_Vtbl)).LocalAppPackageId)(this, wszpackageid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocalAppPackageId(this: *mut core::ffi::c_void, wszpackageid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule3", "SetLocalAppPackageId", 46usize, |this| ((**(this as *const *const INetFwRule3_Vtbl)).SetLocalAppPackageId)(this, wszpackageid), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalUserOwner(this: *mut core::ffi::c_void, wszuserowner: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule3", "LocalUserOwner", 47usize, |this| ((**(this as *const *const INetFwRule3_Vtbl)).LocalUserOwner)(this, wszuserowner), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocalUserOwner(this: *mut core::ffi::c_void, wszuserowner: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule3", "SetLocalUserOwner", 48usize, |this| ((**(this as *const *const INetFwRule3_Vtbl)).SetLocalUserOwner)(this, wszuserowner), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalUserAuthorizedList(this: *mut core::ffi::c_void, wszuserauthlist: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule3", "LocalUserAuthorizedList", 49usize, |this| ((**(this as *const *const INetFwRule3_Vtbl)).LocalUserAuthorizedList)(this, wszuserauthlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocalUserAuthorizedList(this: *mut core::ffi::c_void, wszuserauthlist: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule3", "SetLocalUserAuthorizedList", 50usize, |this| ((**(this as *const *const INetFwRule3_Vtbl)).SetLocalUserAuthorizedList)(this, wszuserauthlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteUserAuthorizedList(this: *mut core::ffi::c_void, wszuserauthlist: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule3", "RemoteUserAuthorizedList", 51usize, |this| ((**(this as *const *const INetFwRule3_Vtbl)).RemoteUserAuthorizedList)(this, wszuserauthlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemoteUserAuthorizedList(this: *mut core::ffi::c_void, wszuserauthlist: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule3", "SetRemoteUserAuthorizedList", 52usize, |this| ((**(this as *const *const INetFwRule3_Vtbl)).SetRemoteUserAuthorizedList)(this, wszuserauthlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteMachineAuthorizedList(this: *mut core::ffi::c_void, wszuserauthlist: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule3", "RemoteMachineAuthorizedList", 53usize, |this| ((**(this as *const *const INetFwRule3_Vtbl)).RemoteMachineAuthorizedList)(this, wszuserauthlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemoteMachineAuthorizedList(this: *mut core::ffi::c_void, wszuserauthlist: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule3", "SetRemoteMachineAuthorizedList", 54usize, |this| ((**(this as *const *const INetFwRule3_Vtbl)).SetRemoteMachineAuthorizedList)(this, wszuserauthlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn SecureFlags(this: *mut core::ffi::c_void, loptions: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule3", "SecureFlags", 55usize, |this| ((**(this as *const *const INetFwRule3_Vtbl)).SecureFlags)(this, loptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSecureFlags(this: *mut core::ffi::c_void, loptions: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRule3", "SetSecureFlags", 56usize, |this| ((**(this as *const *const INetFwRule3_Vtbl)).SetSecureFlags)(this, loptions), |result| Some(*result))
+        }
+        Self {
+            base__: <INetFwRule2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LocalAppPackageId,
+            SetLocalAppPackageId,
+            LocalUserOwner,
+            SetLocalUserOwner,
+            LocalUserAuthorizedList,
+            SetLocalUserAuthorizedList,
+            RemoteUserAuthorizedList,
+            SetRemoteUserAuthorizedList,
+            RemoteMachineAuthorizedList,
+            SetRemoteMachineAuthorizedList,
+            SecureFlags,
+            SetSecureFlags,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetFwRule3 as windows_core::Interface>::IID || <INetFwRule2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetFwRules_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRules", "Count", 7usize, |this| ((**(this as *const *const INetFwRules_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, rule: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRules", "Add", 8usize, |this| ((**(this as *const *const INetFwRules_Vtbl)).Add)(this, rule), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRules", "Remove", 9usize, |this| ((**(this as *const *const INetFwRules_Vtbl)).Remove)(this, name), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, rule: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRules", "Item", 10usize, |this| ((**(this as *const *const INetFwRules_Vtbl)).Item)(this, name, rule), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, newenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwRules", "_NewEnum", 11usize, |this| ((**(this as *const *const INetFwRules_Vtbl))._NewEnum)(this, newenum), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetFwRules as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetFwService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwService", "Name", 7usize, |this| ((**(this as *const *const INetFwService_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, r#type: *mut NET_FW_SERVICE_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwService", "Type", 8usize, |this| ((**(this as *const *const INetFwService_Vtbl)).Type)(this, r#type), |result| Some(*result))
+        }
+        unsafe extern "system" fn Customized(this: *mut core::ffi::c_void, customized: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwService", "Customized", 9usize, |this| ((**(this as *const *const INetFwService_Vtbl)).Customized)(this, customized), |result| Some(*result))
+        }
+        unsafe extern "system" fn IpVersion(this: *mut core::ffi::c_void, ipversion: *mut NET_FW_IP_VERSION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwService", "IpVersion", 10usize, |this| ((**(this as *const *const INetFwService_Vtbl)).IpVersion)(this, ipversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIpVersion(this: *mut core::ffi::c_void, ipversion: NET_FW_IP_VERSION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwService", "SetIpVersion", 11usize, |this| ((**(this as *const *const INetFwService_Vtbl)).SetIpVersion)(this, ipversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn Scope(this: *mut core::ffi::c_void, scope: *mut NET_FW_SCOPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwService", "Scope", 12usize, |this| ((**(this as *const *const INetFwService_Vtbl)).Scope)(this, scope), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScope(this: *mut core::ffi::c_void, scope: NET_FW_SCOPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwService", "SetScope", 13usize, |this| ((**(this as *const *const INetFwService_Vtbl)).SetScope)(this, scope), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteAddresses(this: *mut core::ffi::c_void, remoteaddrs: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwService", "RemoteAddresses", 14usize, |this| ((**(this as *const *const INetFwService_Vtbl)).RemoteAddresses)(this, remoteaddrs), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemoteAddresses(this: *mut core::ffi::c_void, remoteaddrs: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwService", "SetRemoteAddresses", 15usize, |this| ((**(this as *const *const INetFwService_Vtbl)).SetRemoteAddresses)(this, remoteaddrs), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, enabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwService", "Enabled", 16usize, |this| ((**(this as *const *const INetFwService_Vtbl)).Enabled)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, enabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwService", "SetEnabled", 17usize, |this| ((**(this as *const *const INetFwService_Vtbl)).SetEnabled)(this, enabled), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GloballyOpenPorts(this: *mut core::ffi::c_void, openports: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwService", "GloballyOpenPorts", 18usize, |this| ((**(this as *const *const INetFwService_Vtbl)).GloballyOpenPorts)(this, openports), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            Type,
+            Customized,
+            IpVersion,
+            SetIpVersion,
+            Scope,
+            SetScope,
+            RemoteAddresses,
+            SetRemoteAddresses,
+            Enabled,
+            SetEnabled,
+            #[cfg(feature = "Win32_System_Com")]
+            GloballyOpenPorts,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GloballyOpenPorts: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetFwService as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetFwServiceRestriction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RestrictService(this: *mut core::ffi::c_void, servicename: core::mem::MaybeUninit<windows_core::BSTR>, appname: core::mem::MaybeUninit<windows_core::BSTR>, restrictservice: super::super::Foundation::VARIANT_BOOL, servicesidrestricted: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwServiceRestriction", "RestrictService", 7usize, |this| ((**(this as *const *const INetFwServiceRestriction_Vtbl)).RestrictService)(this, servicename, appname, restrictservice, servicesidrestricted), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceRestricted(this: *mut core::ffi::c_void, servicename: core::mem::MaybeUninit<windows_core::BSTR>, appname: core::mem::MaybeUninit<windows_core::BSTR>, servicerestricted: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwServiceRestriction", "ServiceRestricted", 8usize, |this| ((**(this as *const *const INetFwServiceRestriction_Vtbl)).ServiceRestricted)(this, servicename, appname, servicerestricted), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Rules(this: *mut core::ffi::c_void, rules: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwServiceRestriction", "Rules", 9usize, |this| ((**(this as *const *const INetFwServiceRestriction_Vtbl)).Rules)(this, rules), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RestrictService,
+            ServiceRestricted,
+            #[cfg(feature = "Win32_System_Com")]
+            Rules,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Rules: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetFwServiceRestriction as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetFwServices_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwServices", "Count", 7usize, |this| ((**(this as *const *const INetFwServices_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, svctype: NET_FW_SERVICE_TYPE, service: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwServices", "Item", 8usize, |this| ((**(this as *const *const INetFwServices_Vtbl)).Item)(this, svctype, service), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, newenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetFwServices", "_NewEnum", 9usize, |this| ((**(this as *const *const INetFwServices_Vtbl))._NewEnum)(this, newenum), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetFwServices as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetSharingConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SharingEnabled(this: *mut core::ffi::c_void, pbenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingConfiguration", "SharingEnabled", 7usize, |this| ((**(this as *const *const INetSharingConfiguration_Vtbl)).SharingEnabled)(this, pbenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SharingConnectionType(this: *mut core::ffi::c_void, ptype: *mut SHARINGCONNECTIONTYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingConfiguration", "SharingConnectionType", 8usize, |this| ((**(this as *const *const INetSharingConfiguration_Vtbl)).SharingConnectionType)(this, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableSharing(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingConfiguration", "DisableSharing", 9usize, |this| ((**(this as *const *const INetSharingConfiguration_Vtbl)).DisableSharing)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableSharing(this: *mut core::ffi::c_void, r#type: SHARINGCONNECTIONTYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingConfiguration", "EnableSharing", 10usize, |this| ((**(this as *const *const INetSharingConfiguration_Vtbl)).EnableSharing)(this, r#type), |result| Some(*result))
+        }
+        unsafe extern "system" fn InternetFirewallEnabled(this: *mut core::ffi::c_void, pbenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingConfiguration", "InternetFirewallEnabled", 11usize, |this| ((**(this as *const *const INetSharingConfiguration_Vtbl)).InternetFirewallEnabled)(this, pbenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableInternetFirewall(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingConfiguration", "DisableInternetFirewall", 12usize, |this| ((**(this as *const *const INetSharingConfiguration_Vtbl)).DisableInternetFirewall)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableInternetFirewall(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingConfiguration", "EnableInternetFirewall", 13usize, |this| ((**(this as *const *const INetSharingConfiguration_Vtbl)).EnableInternetFirewall)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_EnumPortMappings(this: *mut core::ffi::c_void, flags: SHARINGCONNECTION_ENUM_FLAGS, ppcoll: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingConfiguration", "get_EnumPortMappings", 14usize, |this| ((**(this as *const *const INetSharingConfiguration_Vtbl)).get_EnumPortMappings)(this, flags, ppcoll), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddPortMapping(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, ucipprotocol: u8, usexternalport: u16, usinternalport: u16, dwoptions: u32, bstrtargetnameoripaddress: core::mem::MaybeUninit<windows_core::BSTR>, etargettype: ICS_TARGETTYPE, ppmapping: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingConfiguration", "AddPortMapping", 15usize, |this| ((**(this as *const *const INetSharingConfiguration_Vtbl)).AddPortMapping)(this, bstrname, ucipprotocol, usexternalport, usinternalport, dwoptions, bstrtargetnameoripaddress, etargettype, ppmapping), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RemovePortMapping(this: *mut core::ffi::c_void, pmapping: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingConfiguration", "RemovePortMapping", 16usize, |this| ((**(this as *const *const INetSharingConfiguration_Vtbl)).RemovePortMapping)(this, pmapping), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SharingEnabled,
+            SharingConnectionType,
+            DisableSharing,
+            EnableSharing,
+            InternetFirewallEnabled,
+            DisableInternetFirewall,
+            EnableInternetFirewall,
+            #[cfg(feature = "Win32_System_Com")]
+            get_EnumPortMappings,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_EnumPortMappings: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AddPortMapping,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddPortMapping: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            RemovePortMapping,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RemovePortMapping: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetSharingConfiguration as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetSharingEveryConnectionCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingEveryConnectionCollection", "_NewEnum", 7usize, |this| ((**(this as *const *const INetSharingEveryConnectionCollection_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingEveryConnectionCollection", "Count", 8usize, |this| ((**(this as *const *const INetSharingEveryConnectionCollection_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, _NewEnum, Count }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetSharingEveryConnectionCollection as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetSharingManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SharingInstalled(this: *mut core::ffi::c_void, pbinstalled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingManager", "SharingInstalled", 7usize, |this| ((**(this as *const *const INetSharingManager_Vtbl)).SharingInstalled)(this, pbinstalled), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_EnumPublicConnections(this: *mut core::ffi::c_void, flags: SHARINGCONNECTION_ENUM_FLAGS, ppcoll: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingManager", "get_EnumPublicConnections", 8usize, |this| ((**(this as *const *const INetSharingManager_Vtbl)).get_EnumPublicConnections)(this, flags, ppcoll), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_EnumPrivateConnections(this: *mut core::ffi::c_void, flags: SHARINGCONNECTION_ENUM_FLAGS, ppcoll: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingManager", "get_EnumPrivateConnections", 9usize, |this| ((**(this as *const *const INetSharingManager_Vtbl)).get_EnumPrivateConnections)(this, flags, ppcoll), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_INetSharingConfigurationForINetConnection(this: *mut core::ffi::c_void, pnetconnection: *mut core::ffi::c_void, ppnetsharingconfiguration: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingManager", "get_INetSharingConfigurationForINetConnection", 10usize, |this| ((**(this as *const *const INetSharingManager_Vtbl)).get_INetSharingConfigurationForINetConnection)(this, pnetconnection, ppnetsharingconfiguration), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumEveryConnection(this: *mut core::ffi::c_void, ppcoll: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingManager", "EnumEveryConnection", 11usize, |this| ((**(this as *const *const INetSharingManager_Vtbl)).EnumEveryConnection)(this, ppcoll), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_NetConnectionProps(this: *mut core::ffi::c_void, pnetconnection: *mut core::ffi::c_void, ppprops: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingManager", "get_NetConnectionProps", 12usize, |this| ((**(this as *const *const INetSharingManager_Vtbl)).get_NetConnectionProps)(this, pnetconnection, ppprops), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SharingInstalled,
+            #[cfg(feature = "Win32_System_Com")]
+            get_EnumPublicConnections,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_EnumPublicConnections: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            get_EnumPrivateConnections,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_EnumPrivateConnections: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            get_INetSharingConfigurationForINetConnection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_INetSharingConfigurationForINetConnection: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumEveryConnection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumEveryConnection: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            get_NetConnectionProps,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_NetConnectionProps: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetSharingManager as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetSharingPortMapping_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Disable(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingPortMapping", "Disable", 7usize, |this| ((**(this as *const *const INetSharingPortMapping_Vtbl)).Disable)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enable(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingPortMapping", "Enable", 8usize, |this| ((**(this as *const *const INetSharingPortMapping_Vtbl)).Enable)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, ppnspmp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingPortMapping", "Properties", 9usize, |this| ((**(this as *const *const INetSharingPortMapping_Vtbl)).Properties)(this, ppnspmp), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingPortMapping", "Delete", 10usize, |this| ((**(this as *const *const INetSharingPortMapping_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Disable,
+            Enable,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+            Delete,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetSharingPortMapping as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetSharingPortMappingCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingPortMappingCollection", "_NewEnum", 7usize, |this| ((**(this as *const *const INetSharingPortMappingCollection_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingPortMappingCollection", "Count", 8usize, |this| ((**(this as *const *const INetSharingPortMappingCollection_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, _NewEnum, Count }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetSharingPortMappingCollection as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetSharingPortMappingProps_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingPortMappingProps", "Name", 7usize, |this| ((**(this as *const *const INetSharingPortMappingProps_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn IPProtocol(this: *mut core::ffi::c_void, pucipprot: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingPortMappingProps", "IPProtocol", 8usize, |this| ((**(this as *const *const INetSharingPortMappingProps_Vtbl)).IPProtocol)(this, pucipprot), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExternalPort(this: *mut core::ffi::c_void, pusport: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingPortMappingProps", "ExternalPort", 9usize, |this| ((**(this as *const *const INetSharingPortMappingProps_Vtbl)).ExternalPort)(this, pusport), |result| Some(*result))
+        }
+        unsafe extern "system" fn InternalPort(this: *mut core::ffi::c_void, pusport: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingPortMappingProps", "InternalPort", 10usize, |this| ((**(this as *const *const INetSharingPortMappingProps_Vtbl)).InternalPort)(this, pusport), |result| Some(*result))
+        }
+        unsafe extern "system" fn Options(this: *mut core::ffi::c_void, pdwoptions: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingPortMappingProps", "Options", 11usize, |this| ((**(this as *const *const INetSharingPortMappingProps_Vtbl)).Options)(this, pdwoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn TargetName(this: *mut core::ffi::c_void, pbstrtargetname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingPortMappingProps", "TargetName", 12usize, |this| ((**(this as *const *const INetSharingPortMappingProps_Vtbl)).TargetName)(this, pbstrtargetname), |result| Some(*result))
+        }
+        unsafe extern "system" fn TargetIPAddress(this: *mut core::ffi::c_void, pbstrtargetipaddress: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingPortMappingProps", "TargetIPAddress", 13usize, |this| ((**(this as *const *const INetSharingPortMappingProps_Vtbl)).TargetIPAddress)(this, pbstrtargetipaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, pbool: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingPortMappingProps", "Enabled", 14usize, |this| ((**(this as *const *const INetSharingPortMappingProps_Vtbl)).Enabled)(this, pbool), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            IPProtocol,
+            ExternalPort,
+            InternalPort,
+            Options,
+            TargetName,
+            TargetIPAddress,
+            Enabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetSharingPortMappingProps as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetSharingPrivateConnectionCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingPrivateConnectionCollection", "_NewEnum", 7usize, |this| ((**(this as *const *const INetSharingPrivateConnectionCollection_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingPrivateConnectionCollection", "Count", 8usize, |this| ((**(this as *const *const INetSharingPrivateConnectionCollection_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, _NewEnum, Count }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetSharingPrivateConnectionCollection as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetSharingPublicConnectionCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingPublicConnectionCollection", "_NewEnum", 7usize, |this| ((**(this as *const *const INetSharingPublicConnectionCollection_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetSharingPublicConnectionCollection", "Count", 8usize, |this| ((**(this as *const *const INetSharingPublicConnectionCollection_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, _NewEnum, Count }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetSharingPublicConnectionCollection as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IStaticPortMapping_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExternalIPAddress(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStaticPortMapping", "ExternalIPAddress", 7usize, |this| ((**(this as *const *const IStaticPortMapping_Vtbl)).ExternalIPAddress)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExternalPort(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStaticPortMapping", "ExternalPort", 8usize, |this| ((**(this as *const *const IStaticPortMapping_Vtbl)).ExternalPort)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn InternalPort(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStaticPortMapping", "InternalPort", 9usize, |this| ((**(this as *const *const IStaticPortMapping_Vtbl)).InternalPort)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Protocol(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStaticPortMapping", "Protocol", 10usize, |this| ((**(this as *const *const IStaticPortMapping_Vtbl)).Protocol)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn InternalClient(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStaticPortMapping", "InternalClient", 11usize, |this| ((**(this as *const *const IStaticPortMapping_Vtbl)).InternalClient)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStaticPortMapping", "Enabled", 12usize, |this| ((**(this as *const *const IStaticPortMapping_Vtbl)).Enabled)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStaticPortMapping", "Description", 13usize, |this| ((**(this as *const *const IStaticPortMapping_Vtbl)).Description)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn EditInternalClient(this: *mut core::ffi::c_void, bstrinternalclient: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStaticPortMapping", "EditInternalClient", 14usize, |this| ((**(this as *const *const IStaticPortMapping_Vtbl)).EditInternalClient)(this, bstrinternalclient), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enable(this: *mut core::ffi::c_void, vb: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStaticPortMapping", "Enable", 15usize, |this| ((**(this as *const *const IStaticPortMapping_Vtbl)).Enable)(this, vb), |result| Some(*result))
+        }
+        unsafe extern "system" fn EditDescription(this: *mut core::ffi::c_void, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStaticPortMapping", "EditDescription", 16usize, |this| ((**(this as *const *const IStaticPortMapping_Vtbl)).EditDescription)(this, bstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn EditInternalPort(this: *mut core::ffi::c_void, linternalport: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStaticPortMapping", "EditInternalPort", 17usize, |this| ((**(this as *const *const IStaticPortMapping_Vtbl)).EditInternalPort)(this, linternalport), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ExternalIPAddress,
+            ExternalPort,
+            InternalPort,
+            Protocol,
+            InternalClient,
+            Enabled,
+            Description,
+            EditInternalClient,
+            Enable,
+            EditDescription,
+            EditInternalPort,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStaticPortMapping as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IStaticPortMappingCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStaticPortMappingCollection", "_NewEnum", 7usize, |this| ((**(this as *const *const IStaticPortMappingCollection_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, lexternalport: i32, bstrprotocol: core::mem::MaybeUninit<windows_core::BSTR>, ppspm: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStaticPortMappingCollection", "get_Item", 8usize, |this| ((**(this as *const *const IStaticPortMappingCollection_Vtbl)).get_Item)(this, lexternalport, bstrprotocol, ppspm), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStaticPortMappingCollection", "Count", 9usize, |this| ((**(this as *const *const IStaticPortMappingCollection_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, lexternalport: i32, bstrprotocol: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStaticPortMappingCollection", "Remove", 10usize, |this| ((**(this as *const *const IStaticPortMappingCollection_Vtbl)).Remove)(this, lexternalport, bstrprotocol), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, lexternalport: i32, bstrprotocol: core::mem::MaybeUninit<windows_core::BSTR>, linternalport: i32, bstrinternalclient: core::mem::MaybeUninit<windows_core::BSTR>, benabled: super::super::Foundation::VARIANT_BOOL, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>, ppspm: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStaticPortMappingCollection", "Add", 11usize, |this| ((**(this as *const *const IStaticPortMappingCollection_Vtbl)).Add)(this, lexternalport, bstrprotocol, linternalport, bstrinternalclient, benabled, bstrdescription, ppspm), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            Count,
+            Remove,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStaticPortMappingCollection as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUPnPNAT_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn StaticPortMappingCollection(this: *mut core::ffi::c_void, ppspms: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPNAT", "StaticPortMappingCollection", 7usize, |this| ((**(this as *const *const IUPnPNAT_Vtbl)).StaticPortMappingCollection)(this, ppspms), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DynamicPortMappingCollection(this: *mut core::ffi::c_void, ppdpms: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPNAT", "DynamicPortMappingCollection", 8usize, |this| ((**(this as *const *const IUPnPNAT_Vtbl)).DynamicPortMappingCollection)(this, ppdpms), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn NATEventManager(this: *mut core::ffi::c_void, ppnem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUPnPNAT", "NATEventManager", 9usize, |this| ((**(this as *const *const IUPnPNAT_Vtbl)).NATEventManager)(this, ppnem), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            StaticPortMappingCollection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            StaticPortMappingCollection: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            DynamicPortMappingCollection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DynamicPortMappingCollection: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            NATEventManager,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            NATEventManager: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUPnPNAT as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
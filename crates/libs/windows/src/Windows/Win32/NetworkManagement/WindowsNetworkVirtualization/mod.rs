@@ -31,6 +31,7 @@ pub struct WNV_CA_NOTIFICATION_TYPE(pub i32);
 impl windows_core::TypeKind for WNV_CA_NOTIFICATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WNV_CA_NOTIFICATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WNV_CA_NOTIFICATION_TYPE").field(&self.0).finish()
@@ -42,6 +43,7 @@ pub struct WNV_NOTIFICATION_TYPE(pub i32);
 impl windows_core::TypeKind for WNV_NOTIFICATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WNV_NOTIFICATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WNV_NOTIFICATION_TYPE").field(&self.0).finish()
@@ -53,6 +55,7 @@ pub struct WNV_OBJECT_TYPE(pub i32);
 impl windows_core::TypeKind for WNV_OBJECT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WNV_OBJECT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WNV_OBJECT_TYPE").field(&self.0).finish()
@@ -115,7 +118,8 @@ impl Default for WNV_IP_ADDRESS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WNV_NOTIFICATION_PARAM {
     pub Header: WNV_OBJECT_HEADER,
     pub NotificationType: WNV_NOTIFICATION_TYPE,
@@ -165,7 +169,8 @@ impl Default for WNV_OBJECT_CHANGE_PARAM_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WNV_OBJECT_HEADER {
     pub MajorVersion: u8,
     pub MinorVersion: u8,
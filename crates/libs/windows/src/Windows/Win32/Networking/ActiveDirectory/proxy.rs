@@ -0,0 +1,3583 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADs", "Name", 7usize, |this| ((**(this as *const *const IADs_Vtbl)).Name)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Class(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADs", "Class", 8usize, |this| ((**(this as *const *const IADs_Vtbl)).Class)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GUID(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADs", "GUID", 9usize, |this| ((**(this as *const *const IADs_Vtbl)).GUID)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ADsPath(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADs", "ADsPath", 10usize, |this| ((**(this as *const *const IADs_Vtbl)).ADsPath)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Parent(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADs", "Parent", 11usize, |this| ((**(this as *const *const IADs_Vtbl)).Parent)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Schema(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADs", "Schema", 12usize, |this| ((**(this as *const *const IADs_Vtbl)).Schema)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInfo(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADs", "GetInfo", 13usize, |this| ((**(this as *const *const IADs_Vtbl)).GetInfo)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInfo(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADs", "SetInfo", 14usize, |this| ((**(this as *const *const IADs_Vtbl)).SetInfo)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Get(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, pvprop: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADs", "Get", 15usize, |this| ((**(this as *const *const IADs_Vtbl)).Get)(this, bstrname, pvprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn Put(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, vprop: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADs", "Put", 16usize, |this| ((**(this as *const *const IADs_Vtbl)).Put)(this, bstrname, vprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEx(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, pvprop: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADs", "GetEx", 17usize, |this| ((**(this as *const *const IADs_Vtbl)).GetEx)(this, bstrname, pvprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutEx(this: *mut core::ffi::c_void, lncontrolcode: i32, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, vprop: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADs", "PutEx", 18usize, |this| ((**(this as *const *const IADs_Vtbl)).PutEx)(this, lncontrolcode, bstrname, vprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInfoEx(this: *mut core::ffi::c_void, vproperties: core::mem::MaybeUninit<windows_core::VARIANT>, lnreserved: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADs", "GetInfoEx", 19usize, |this| ((**(this as *const *const IADs_Vtbl)).GetInfoEx)(this, vproperties, lnreserved), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            Class,
+            GUID,
+            ADsPath,
+            Parent,
+            Schema,
+            GetInfo,
+            SetInfo,
+            Get,
+            Put,
+            GetEx,
+            PutEx,
+            GetInfoEx,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADs as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsADSystemInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UserName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsADSystemInfo", "UserName", 7usize, |this| ((**(this as *const *const IADsADSystemInfo_Vtbl)).UserName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ComputerName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsADSystemInfo", "ComputerName", 8usize, |this| ((**(this as *const *const IADsADSystemInfo_Vtbl)).ComputerName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SiteName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsADSystemInfo", "SiteName", 9usize, |this| ((**(this as *const *const IADsADSystemInfo_Vtbl)).SiteName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DomainShortName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsADSystemInfo", "DomainShortName", 10usize, |this| ((**(this as *const *const IADsADSystemInfo_Vtbl)).DomainShortName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DomainDNSName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsADSystemInfo", "DomainDNSName", 11usize, |this| ((**(this as *const *const IADsADSystemInfo_Vtbl)).DomainDNSName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForestDNSName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsADSystemInfo", "ForestDNSName", 12usize, |this| ((**(this as *const *const IADsADSystemInfo_Vtbl)).ForestDNSName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PDCRoleOwner(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsADSystemInfo", "PDCRoleOwner", 13usize, |this| ((**(this as *const *const IADsADSystemInfo_Vtbl)).PDCRoleOwner)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SchemaRoleOwner(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsADSystemInfo", "SchemaRoleOwner", 14usize, |this| ((**(this as *const *const IADsADSystemInfo_Vtbl)).SchemaRoleOwner)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsNativeMode(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsADSystemInfo", "IsNativeMode", 15usize, |this| ((**(this as *const *const IADsADSystemInfo_Vtbl)).IsNativeMode)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAnyDCName(this: *mut core::ffi::c_void, pszdcname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsADSystemInfo", "GetAnyDCName", 16usize, |this| ((**(this as *const *const IADsADSystemInfo_Vtbl)).GetAnyDCName)(this, pszdcname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDCSiteName(this: *mut core::ffi::c_void, szserver: core::mem::MaybeUninit<windows_core::BSTR>, pszsitename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsADSystemInfo", "GetDCSiteName", 17usize, |this| ((**(this as *const *const IADsADSystemInfo_Vtbl)).GetDCSiteName)(this, szserver, pszsitename), |result| Some(*result))
+        }
+        unsafe extern "system" fn RefreshSchemaCache(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsADSystemInfo", "RefreshSchemaCache", 18usize, |this| ((**(this as *const *const IADsADSystemInfo_Vtbl)).RefreshSchemaCache)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTrees(this: *mut core::ffi::c_void, pvtrees: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsADSystemInfo", "GetTrees", 19usize, |this| ((**(this as *const *const IADsADSystemInfo_Vtbl)).GetTrees)(this, pvtrees), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            UserName,
+            ComputerName,
+            SiteName,
+            DomainShortName,
+            DomainDNSName,
+            ForestDNSName,
+            PDCRoleOwner,
+            SchemaRoleOwner,
+            IsNativeMode,
+            GetAnyDCName,
+            GetDCSiteName,
+            RefreshSchemaCache,
+            GetTrees,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsADSystemInfo as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsAccessControlEntry_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AccessMask(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAccessControlEntry", "AccessMask", 7usize, |this| ((**(this as *const *const IADsAccessControlEntry_Vtbl)).AccessMask)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAccessMask(this: *mut core::ffi::c_void, lnaccessmask: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAccessControlEntry", "SetAccessMask", 8usize, |this| ((**(this as *const *const IADsAccessControlEntry_Vtbl)).SetAccessMask)(this, lnaccessmask), |result| Some(*result))
+        }
+        unsafe extern "system" fn AceType(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAccessControlEntry", "AceType", 9usize, |this| ((**(this as *const *const IADsAccessControlEntry_Vtbl)).AceType)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAceType(this: *mut core::ffi::c_void, lnacetype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAccessControlEntry", "SetAceType", 10usize, |this| ((**(this as *const *const IADsAccessControlEntry_Vtbl)).SetAceType)(this, lnacetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn AceFlags(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAccessControlEntry", "AceFlags", 11usize, |this| ((**(this as *const *const IADsAccessControlEntry_Vtbl)).AceFlags)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAceFlags(this: *mut core::ffi::c_void, lnaceflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAccessControlEntry", "SetAceFlags", 12usize, |this| ((**(this as *const *const IADsAccessControlEntry_Vtbl)).SetAceFlags)(this, lnaceflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flags(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAccessControlEntry", "Flags", 13usize, |this| ((**(this as *const *const IADsAccessControlEntry_Vtbl)).Flags)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFlags(this: *mut core::ffi::c_void, lnflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAccessControlEntry", "SetFlags", 14usize, |this| ((**(this as *const *const IADsAccessControlEntry_Vtbl)).SetFlags)(this, lnflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn ObjectType(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAccessControlEntry", "ObjectType", 15usize, |this| ((**(this as *const *const IADsAccessControlEntry_Vtbl)).ObjectType)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetObjectType(this: *mut core::ffi::c_void, bstrobjecttype: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAccessControlEntry", "SetObjectType", 16usize, |this| ((**(this as *const *const IADsAccessControlEntry_Vtbl)).SetObjectType)(this, bstrobjecttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn InheritedObjectType(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAccessControlEntry", "InheritedObjectType", 17usize, |this| ((**(this as *const *const IADsAccessControlEntry_Vtbl)).InheritedObjectType)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInheritedObjectType(this: *mut core::ffi::c_void, bstrinheritedobjecttype: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAccessControlEntry", "SetInheritedObjectType", 18usize, |this| ((**(this as *const *const IADsAccessControlEntry_Vtbl)).SetInheritedObjectType)(this, bstrinheritedobjecttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Trustee(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAccessControlEntry", "Trustee", 19usize, |this| ((**(this as *const *const IADsAccessControlEntry_Vtbl)).Trustee)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTrustee(this: *mut core::ffi::c_void, bstrtrustee: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAccessControlEntry", "SetTrustee", 20usize, |this| ((**(this as *const *const IADsAccessControlEntry_Vtbl)).SetTrustee)(this, bstrtrustee), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AccessMask,
+            SetAccessMask,
+            AceType,
+            SetAceType,
+            AceFlags,
+            SetAceFlags,
+            Flags,
+            SetFlags,
+            ObjectType,
+            SetObjectType,
+            InheritedObjectType,
+            SetInheritedObjectType,
+            Trustee,
+            SetTrustee,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsAccessControlEntry as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsAccessControlList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AclRevision(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAccessControlList", "AclRevision", 7usize, |this| ((**(this as *const *const IADsAccessControlList_Vtbl)).AclRevision)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAclRevision(this: *mut core::ffi::c_void, lnaclrevision: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAccessControlList", "SetAclRevision", 8usize, |this| ((**(this as *const *const IADsAccessControlList_Vtbl)).SetAclRevision)(this, lnaclrevision), |result| Some(*result))
+        }
+        unsafe extern "system" fn AceCount(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAccessControlList", "AceCount", 9usize, |this| ((**(this as *const *const IADsAccessControlList_Vtbl)).AceCount)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAceCount(this: *mut core::ffi::c_void, lnacecount: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAccessControlList", "SetAceCount", 10usize, |this| ((**(this as *const *const IADsAccessControlList_Vtbl)).SetAceCount)(this, lnacecount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddAce(this: *mut core::ffi::c_void, paccesscontrolentry: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAccessControlList", "AddAce", 11usize, |this| ((**(this as *const *const IADsAccessControlList_Vtbl)).AddAce)(this, paccesscontrolentry), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RemoveAce(this: *mut core::ffi::c_void, paccesscontrolentry: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAccessControlList", "RemoveAce", 12usize, |this| ((**(this as *const *const IADsAccessControlList_Vtbl)).RemoveAce)(this, paccesscontrolentry), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CopyAccessList(this: *mut core::ffi::c_void, ppaccesscontrollist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAccessControlList", "CopyAccessList", 13usize, |this| ((**(this as *const *const IADsAccessControlList_Vtbl)).CopyAccessList)(this, ppaccesscontrollist), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAccessControlList", "_NewEnum", 14usize, |this| ((**(this as *const *const IADsAccessControlList_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AclRevision,
+            SetAclRevision,
+            AceCount,
+            SetAceCount,
+            #[cfg(feature = "Win32_System_Com")]
+            AddAce,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddAce: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            RemoveAce,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RemoveAce: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CopyAccessList,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CopyAccessList: 0,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsAccessControlList as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsAcl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProtectedAttrName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAcl", "ProtectedAttrName", 7usize, |this| ((**(this as *const *const IADsAcl_Vtbl)).ProtectedAttrName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProtectedAttrName(this: *mut core::ffi::c_void, bstrprotectedattrname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAcl", "SetProtectedAttrName", 8usize, |this| ((**(this as *const *const IADsAcl_Vtbl)).SetProtectedAttrName)(this, bstrprotectedattrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubjectName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAcl", "SubjectName", 9usize, |this| ((**(this as *const *const IADsAcl_Vtbl)).SubjectName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubjectName(this: *mut core::ffi::c_void, bstrsubjectname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAcl", "SetSubjectName", 10usize, |this| ((**(this as *const *const IADsAcl_Vtbl)).SetSubjectName)(this, bstrsubjectname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Privileges(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAcl", "Privileges", 11usize, |this| ((**(this as *const *const IADsAcl_Vtbl)).Privileges)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivileges(this: *mut core::ffi::c_void, lnprivileges: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAcl", "SetPrivileges", 12usize, |this| ((**(this as *const *const IADsAcl_Vtbl)).SetPrivileges)(this, lnprivileges), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CopyAcl(this: *mut core::ffi::c_void, ppacl: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAcl", "CopyAcl", 13usize, |this| ((**(this as *const *const IADsAcl_Vtbl)).CopyAcl)(this, ppacl), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProtectedAttrName,
+            SetProtectedAttrName,
+            SubjectName,
+            SetSubjectName,
+            Privileges,
+            SetPrivileges,
+            #[cfg(feature = "Win32_System_Com")]
+            CopyAcl,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CopyAcl: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsAcl as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IADsAggregatee_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConnectAsAggregatee(this: *mut core::ffi::c_void, pouterunknown: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAggregatee", "ConnectAsAggregatee", 3usize, |this| ((**(this as *const *const IADsAggregatee_Vtbl)).ConnectAsAggregatee)(this, pouterunknown), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisconnectAsAggregatee(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAggregatee", "DisconnectAsAggregatee", 4usize, |this| ((**(this as *const *const IADsAggregatee_Vtbl)).DisconnectAsAggregatee)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RelinquishInterface(this: *mut core::ffi::c_void, riid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAggregatee", "RelinquishInterface", 5usize, |this| ((**(this as *const *const IADsAggregatee_Vtbl)).RelinquishInterface)(this, riid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestoreInterface(this: *mut core::ffi::c_void, riid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAggregatee", "RestoreInterface", 6usize, |this| ((**(this as *const *const IADsAggregatee_Vtbl)).RestoreInterface)(this, riid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ConnectAsAggregatee,
+            DisconnectAsAggregatee,
+            RelinquishInterface,
+            RestoreInterface,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsAggregatee as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IADsAggregator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConnectAsAggregator(this: *mut core::ffi::c_void, paggregatee: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAggregator", "ConnectAsAggregator", 3usize, |this| ((**(this as *const *const IADsAggregator_Vtbl)).ConnectAsAggregator)(this, paggregatee), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisconnectAsAggregator(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsAggregator", "DisconnectAsAggregator", 4usize, |this| ((**(this as *const *const IADsAggregator_Vtbl)).DisconnectAsAggregator)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ConnectAsAggregator, DisconnectAsAggregator }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsAggregator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsBackLink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RemoteID(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsBackLink", "RemoteID", 7usize, |this| ((**(this as *const *const IADsBackLink_Vtbl)).RemoteID)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemoteID(this: *mut core::ffi::c_void, lnremoteid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsBackLink", "SetRemoteID", 8usize, |this| ((**(this as *const *const IADsBackLink_Vtbl)).SetRemoteID)(this, lnremoteid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ObjectName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsBackLink", "ObjectName", 9usize, |this| ((**(this as *const *const IADsBackLink_Vtbl)).ObjectName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetObjectName(this: *mut core::ffi::c_void, bstrobjectname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsBackLink", "SetObjectName", 10usize, |this| ((**(this as *const *const IADsBackLink_Vtbl)).SetObjectName)(this, bstrobjectname), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RemoteID, SetRemoteID, ObjectName, SetObjectName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsBackLink as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsCaseIgnoreList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CaseIgnoreList(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsCaseIgnoreList", "CaseIgnoreList", 7usize, |this| ((**(this as *const *const IADsCaseIgnoreList_Vtbl)).CaseIgnoreList)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCaseIgnoreList(this: *mut core::ffi::c_void, vcaseignorelist: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsCaseIgnoreList", "SetCaseIgnoreList", 8usize, |this| ((**(this as *const *const IADsCaseIgnoreList_Vtbl)).SetCaseIgnoreList)(this, vcaseignorelist), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CaseIgnoreList, SetCaseIgnoreList }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsCaseIgnoreList as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsClass_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrimaryInterface(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "PrimaryInterface", 20usize, |this| ((**(this as *const *const IADsClass_Vtbl)).PrimaryInterface)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CLSID(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "CLSID", 21usize, |this| ((**(this as *const *const IADsClass_Vtbl)).CLSID)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCLSID(this: *mut core::ffi::c_void, bstrclsid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "SetCLSID", 22usize, |this| ((**(this as *const *const IADsClass_Vtbl)).SetCLSID)(this, bstrclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OID(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "OID", 23usize, |this| ((**(this as *const *const IADsClass_Vtbl)).OID)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOID(this: *mut core::ffi::c_void, bstroid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "SetOID", 24usize, |this| ((**(this as *const *const IADsClass_Vtbl)).SetOID)(this, bstroid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Abstract(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "Abstract", 25usize, |this| ((**(this as *const *const IADsClass_Vtbl)).Abstract)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAbstract(this: *mut core::ffi::c_void, fabstract: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "SetAbstract", 26usize, |this| ((**(this as *const *const IADsClass_Vtbl)).SetAbstract)(this, fabstract), |result| Some(*result))
+        }
+        unsafe extern "system" fn Auxiliary(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "Auxiliary", 27usize, |this| ((**(this as *const *const IADsClass_Vtbl)).Auxiliary)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuxiliary(this: *mut core::ffi::c_void, fauxiliary: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "SetAuxiliary", 28usize, |this| ((**(this as *const *const IADsClass_Vtbl)).SetAuxiliary)(this, fauxiliary), |result| Some(*result))
+        }
+        unsafe extern "system" fn MandatoryProperties(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "MandatoryProperties", 29usize, |this| ((**(this as *const *const IADsClass_Vtbl)).MandatoryProperties)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMandatoryProperties(this: *mut core::ffi::c_void, vmandatoryproperties: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "SetMandatoryProperties", 30usize, |this| ((**(this as *const *const IADsClass_Vtbl)).SetMandatoryProperties)(this, vmandatoryproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn OptionalProperties(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "OptionalProperties", 31usize, |this| ((**(this as *const *const IADsClass_Vtbl)).OptionalProperties)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOptionalProperties(this: *mut core::ffi::c_void, voptionalproperties: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "SetOptionalProperties", 32usize, |this| ((**(this as *const *const IADsClass_Vtbl)).SetOptionalProperties)(this, voptionalproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn NamingProperties(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "NamingProperties", 33usize, |this| ((**(this as *const *const IADsClass_Vtbl)).NamingProperties)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNamingProperties(this: *mut core::ffi::c_void, vnamingproperties: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "SetNamingProperties", 34usize, |this| ((**(this as *const *const IADsClass_Vtbl)).SetNamingProperties)(this, vnamingproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn DerivedFrom(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "DerivedFrom", 35usize, |this| ((**(this as *const *const IADsClass_Vtbl)).DerivedFrom)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDerivedFrom(this: *mut core::ffi::c_void, vderivedfrom: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "SetDerivedFrom", 36usize, |this| ((**(this as *const *const IADsClass_Vtbl)).SetDerivedFrom)(this, vderivedfrom), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuxDerivedFrom(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "AuxDerivedFrom", 37usize, |this| ((**(this as *const *const IADsClass_Vtbl)).AuxDerivedFrom)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuxDerivedFrom(this: *mut core::ffi::c_void, vauxderivedfrom: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "SetAuxDerivedFrom", 38usize, |this| ((**(this as *const *const IADsClass_Vtbl)).SetAuxDerivedFrom)(this, vauxderivedfrom), |result| Some(*result))
+        }
+        unsafe extern "system" fn PossibleSuperiors(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "PossibleSuperiors", 39usize, |this| ((**(this as *const *const IADsClass_Vtbl)).PossibleSuperiors)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPossibleSuperiors(this: *mut core::ffi::c_void, vpossiblesuperiors: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "SetPossibleSuperiors", 40usize, |this| ((**(this as *const *const IADsClass_Vtbl)).SetPossibleSuperiors)(this, vpossiblesuperiors), |result| Some(*result))
+        }
+        unsafe extern "system" fn Containment(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "Containment", 41usize, |this| ((**(this as *const *const IADsClass_Vtbl)).Containment)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContainment(this: *mut core::ffi::c_void, vcontainment: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "SetContainment", 42usize, |this| ((**(this as *const *const IADsClass_Vtbl)).SetContainment)(this, vcontainment), |result| Some(*result))
+        }
+        unsafe extern "system" fn Container(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "Container", 43usize, |this| ((**(this as *const *const IADsClass_Vtbl)).Container)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContainer(this: *mut core::ffi::c_void, fcontainer: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "SetContainer", 44usize, |this| ((**(this as *const *const IADsClass_Vtbl)).SetContainer)(this, fcontainer), |result| Some(*result))
+        }
+        unsafe extern "system" fn HelpFileName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "HelpFileName", 45usize, |this| ((**(this as *const *const IADsClass_Vtbl)).HelpFileName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHelpFileName(this: *mut core::ffi::c_void, bstrhelpfilename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "SetHelpFileName", 46usize, |this| ((**(this as *const *const IADsClass_Vtbl)).SetHelpFileName)(this, bstrhelpfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn HelpFileContext(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "HelpFileContext", 47usize, |this| ((**(this as *const *const IADsClass_Vtbl)).HelpFileContext)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHelpFileContext(this: *mut core::ffi::c_void, lnhelpfilecontext: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "SetHelpFileContext", 48usize, |this| ((**(this as *const *const IADsClass_Vtbl)).SetHelpFileContext)(this, lnhelpfilecontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Qualifiers(this: *mut core::ffi::c_void, ppqualifiers: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsClass", "Qualifiers", 49usize, |this| ((**(this as *const *const IADsClass_Vtbl)).Qualifiers)(this, ppqualifiers), |result| Some(*result))
+        }
+        Self {
+            base__: <IADs_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PrimaryInterface,
+            CLSID,
+            SetCLSID,
+            OID,
+            SetOID,
+            Abstract,
+            SetAbstract,
+            Auxiliary,
+            SetAuxiliary,
+            MandatoryProperties,
+            SetMandatoryProperties,
+            OptionalProperties,
+            SetOptionalProperties,
+            NamingProperties,
+            SetNamingProperties,
+            DerivedFrom,
+            SetDerivedFrom,
+            AuxDerivedFrom,
+            SetAuxDerivedFrom,
+            PossibleSuperiors,
+            SetPossibleSuperiors,
+            Containment,
+            SetContainment,
+            Container,
+            SetContainer,
+            HelpFileName,
+            SetHelpFileName,
+            HelpFileContext,
+            SetHelpFileContext,
+            #[cfg(feature = "Win32_System_Com")]
+            Qualifiers,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Qualifiers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsClass as windows_core::Interface>::IID || <IADs_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsCollection", "_NewEnum", 7usize, |this| ((**(this as *const *const IADsCollection_Vtbl))._NewEnum)(this, ppenumerator), |result| Some(*result))
+        }
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, vitem: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsCollection", "Add", 8usize, |this| ((**(this as *const *const IADsCollection_Vtbl)).Add)(this, bstrname, vitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, bstritemtoberemoved: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsCollection", "Remove", 9usize, |this| ((**(this as *const *const IADsCollection_Vtbl)).Remove)(this, bstritemtoberemoved), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObject(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, pvitem: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsCollection", "GetObject", 10usize, |this| ((**(this as *const *const IADsCollection_Vtbl)).GetObject)(this, bstrname, pvitem), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, _NewEnum, Add, Remove, GetObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsCollection as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsComputer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ComputerID(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "ComputerID", 20usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).ComputerID)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Site(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "Site", 21usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).Site)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "Description", 22usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).Description)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "SetDescription", 23usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).SetDescription)(this, bstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn Location(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "Location", 24usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).Location)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocation(this: *mut core::ffi::c_void, bstrlocation: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "SetLocation", 25usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).SetLocation)(this, bstrlocation), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrimaryUser(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "PrimaryUser", 26usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).PrimaryUser)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrimaryUser(this: *mut core::ffi::c_void, bstrprimaryuser: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "SetPrimaryUser", 27usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).SetPrimaryUser)(this, bstrprimaryuser), |result| Some(*result))
+        }
+        unsafe extern "system" fn Owner(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "Owner", 28usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).Owner)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOwner(this: *mut core::ffi::c_void, bstrowner: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "SetOwner", 29usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).SetOwner)(this, bstrowner), |result| Some(*result))
+        }
+        unsafe extern "system" fn Division(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "Division", 30usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).Division)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDivision(this: *mut core::ffi::c_void, bstrdivision: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "SetDivision", 31usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).SetDivision)(this, bstrdivision), |result| Some(*result))
+        }
+        unsafe extern "system" fn Department(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "Department", 32usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).Department)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDepartment(this: *mut core::ffi::c_void, bstrdepartment: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "SetDepartment", 33usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).SetDepartment)(this, bstrdepartment), |result| Some(*result))
+        }
+        unsafe extern "system" fn Role(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "Role", 34usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).Role)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRole(this: *mut core::ffi::c_void, bstrrole: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "SetRole", 35usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).SetRole)(this, bstrrole), |result| Some(*result))
+        }
+        unsafe extern "system" fn OperatingSystem(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "OperatingSystem", 36usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).OperatingSystem)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOperatingSystem(this: *mut core::ffi::c_void, bstroperatingsystem: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "SetOperatingSystem", 37usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).SetOperatingSystem)(this, bstroperatingsystem), |result| Some(*result))
+        }
+        unsafe extern "system" fn OperatingSystemVersion(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "OperatingSystemVersion", 38usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).OperatingSystemVersion)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOperatingSystemVersion(this: *mut core::ffi::c_void, bstroperatingsystemversion: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "SetOperatingSystemVersion", 39usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).SetOperatingSystemVersion)(this, bstroperatingsystemversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn Model(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "Model", 40usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).Model)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetModel(this: *mut core::ffi::c_void, bstrmodel: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "SetModel", 41usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).SetModel)(this, bstrmodel), |result| Some(*result))
+        }
+        unsafe extern "system" fn Processor(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "Processor", 42usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).Processor)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProcessor(this: *mut core::ffi::c_void, bstrprocessor: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "SetProcessor", 43usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).SetProcessor)(this, bstrprocessor), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessorCount(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "ProcessorCount", 44usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).ProcessorCount)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProcessorCount(this: *mut core::ffi::c_void, bstrprocessorcount: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "SetProcessorCount", 45usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).SetProcessorCount)(this, bstrprocessorcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn MemorySize(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "MemorySize", 46usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).MemorySize)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMemorySize(this: *mut core::ffi::c_void, bstrmemorysize: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "SetMemorySize", 47usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).SetMemorySize)(this, bstrmemorysize), |result| Some(*result))
+        }
+        unsafe extern "system" fn StorageCapacity(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "StorageCapacity", 48usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).StorageCapacity)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStorageCapacity(this: *mut core::ffi::c_void, bstrstoragecapacity: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "SetStorageCapacity", 49usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).SetStorageCapacity)(this, bstrstoragecapacity), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetAddresses(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "NetAddresses", 50usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).NetAddresses)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNetAddresses(this: *mut core::ffi::c_void, vnetaddresses: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputer", "SetNetAddresses", 51usize, |this| ((**(this as *const *const IADsComputer_Vtbl)).SetNetAddresses)(this, vnetaddresses), |result| Some(*result))
+        }
+        Self {
+            base__: <IADs_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ComputerID,
+            Site,
+            Description,
+            SetDescription,
+            Location,
+            SetLocation,
+            PrimaryUser,
+            SetPrimaryUser,
+            Owner,
+            SetOwner,
+            Division,
+            SetDivision,
+            Department,
+            SetDepartment,
+            Role,
+            SetRole,
+            OperatingSystem,
+            SetOperatingSystem,
+            OperatingSystemVersion,
+            SetOperatingSystemVersion,
+            Model,
+            SetModel,
+            Processor,
+            SetProcessor,
+            ProcessorCount,
+            SetProcessorCount,
+            MemorySize,
+            SetMemorySize,
+            StorageCapacity,
+            SetStorageCapacity,
+            NetAddresses,
+            SetNetAddresses,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsComputer as windows_core::Interface>::IID || <IADs_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsComputerOperations_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputerOperations", "Status", 20usize, |this| ((**(this as *const *const IADsComputerOperations_Vtbl)).Status)(this, ppobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void, breboot: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsComputerOperations", "Shutdown", 21usize, |this| ((**(this as *const *const IADsComputerOperations_Vtbl)).Shutdown)(this, breboot), |result| Some(*result))
+        }
+        Self {
+            base__: <IADs_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Status,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Status: 0,
+            Shutdown,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsComputerOperations as windows_core::Interface>::IID || <IADs_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsContainer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsContainer", "Count", 7usize, |this| ((**(this as *const *const IADsContainer_Vtbl)).Count)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsContainer", "_NewEnum", 8usize, |this| ((**(this as *const *const IADsContainer_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Filter(this: *mut core::ffi::c_void, pvar: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsContainer", "Filter", 9usize, |this| ((**(this as *const *const IADsContainer_Vtbl)).Filter)(this, pvar), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFilter(this: *mut core::ffi::c_void, var: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsContainer", "SetFilter", 10usize, |this| ((**(this as *const *const IADsContainer_Vtbl)).SetFilter)(this, var), |result| Some(*result))
+        }
+        unsafe extern "system" fn Hints(this: *mut core::ffi::c_void, pvfilter: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsContainer", "Hints", 11usize, |this| ((**(this as *const *const IADsContainer_Vtbl)).Hints)(this, pvfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHints(this: *mut core::ffi::c_void, vhints: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsContainer", "SetHints", 12usize, |this| ((**(this as *const *const IADsContainer_Vtbl)).SetHints)(this, vhints), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetObject(this: *mut core::ffi::c_void, classname: core::mem::MaybeUninit<windows_core::BSTR>, relativename: core::mem::MaybeUninit<windows_core::BSTR>, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsContainer", "GetObject", 13usize, |this| ((**(this as *const *const IADsContainer_Vtbl)).GetObject)(this, classname, relativename, ppobject), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, classname: core::mem::MaybeUninit<windows_core::BSTR>, relativename: core::mem::MaybeUninit<windows_core::BSTR>, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsContainer", "Create", 14usize, |this| ((**(this as *const *const IADsContainer_Vtbl)).Create)(this, classname, relativename, ppobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, bstrclassname: core::mem::MaybeUninit<windows_core::BSTR>, bstrrelativename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsContainer", "Delete", 15usize, |this| ((**(this as *const *const IADsContainer_Vtbl)).Delete)(this, bstrclassname, bstrrelativename), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CopyHere(this: *mut core::ffi::c_void, sourcename: core::mem::MaybeUninit<windows_core::BSTR>, newname: core::mem::MaybeUninit<windows_core::BSTR>, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsContainer", "CopyHere", 16usize, |this| ((**(this as *const *const IADsContainer_Vtbl)).CopyHere)(this, sourcename, newname, ppobject), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn MoveHere(this: *mut core::ffi::c_void, sourcename: core::mem::MaybeUninit<windows_core::BSTR>, newname: core::mem::MaybeUninit<windows_core::BSTR>, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsContainer", "MoveHere", 17usize, |this| ((**(this as *const *const IADsContainer_Vtbl)).MoveHere)(this, sourcename, newname, ppobject), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            Filter,
+            SetFilter,
+            Hints,
+            SetHints,
+            #[cfg(feature = "Win32_System_Com")]
+            GetObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetObject: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Create,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Create: 0,
+            Delete,
+            #[cfg(feature = "Win32_System_Com")]
+            CopyHere,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CopyHere: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            MoveHere,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            MoveHere: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsContainer as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsDNWithBinary_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BinaryValue(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsDNWithBinary", "BinaryValue", 7usize, |this| ((**(this as *const *const IADsDNWithBinary_Vtbl)).BinaryValue)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBinaryValue(this: *mut core::ffi::c_void, vbinaryvalue: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsDNWithBinary", "SetBinaryValue", 8usize, |this| ((**(this as *const *const IADsDNWithBinary_Vtbl)).SetBinaryValue)(this, vbinaryvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn DNString(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsDNWithBinary", "DNString", 9usize, |this| ((**(this as *const *const IADsDNWithBinary_Vtbl)).DNString)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDNString(this: *mut core::ffi::c_void, bstrdnstring: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsDNWithBinary", "SetDNString", 10usize, |this| ((**(this as *const *const IADsDNWithBinary_Vtbl)).SetDNString)(this, bstrdnstring), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BinaryValue, SetBinaryValue, DNString, SetDNString }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsDNWithBinary as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsDNWithString_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StringValue(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsDNWithString", "StringValue", 7usize, |this| ((**(this as *const *const IADsDNWithString_Vtbl)).StringValue)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStringValue(this: *mut core::ffi::c_void, bstrstringvalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsDNWithString", "SetStringValue", 8usize, |this| ((**(this as *const *const IADsDNWithString_Vtbl)).SetStringValue)(this, bstrstringvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn DNString(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsDNWithString", "DNString", 9usize, |this| ((**(this as *const *const IADsDNWithString_Vtbl)).DNString)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDNString(this: *mut core::ffi::c_void, bstrdnstring: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsDNWithString", "SetDNString", 10usize, |this| ((**(this as *const *const IADsDNWithString_Vtbl)).SetDNString)(this, bstrdnstring), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StringValue, SetStringValue, DNString, SetDNString }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsDNWithString as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsDeleteOps_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeleteObject(this: *mut core::ffi::c_void, lnflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsDeleteOps", "DeleteObject", 7usize, |this| ((**(this as *const *const IADsDeleteOps_Vtbl)).DeleteObject)(this, lnflags), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeleteObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsDeleteOps as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsDomain_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsWorkgroup(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsDomain", "IsWorkgroup", 20usize, |this| ((**(this as *const *const IADsDomain_Vtbl)).IsWorkgroup)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinPasswordLength(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsDomain", "MinPasswordLength", 21usize, |this| ((**(this as *const *const IADsDomain_Vtbl)).MinPasswordLength)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinPasswordLength(this: *mut core::ffi::c_void, lnminpasswordlength: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsDomain", "SetMinPasswordLength", 22usize, |this| ((**(this as *const *const IADsDomain_Vtbl)).SetMinPasswordLength)(this, lnminpasswordlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinPasswordAge(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsDomain", "MinPasswordAge", 23usize, |this| ((**(this as *const *const IADsDomain_Vtbl)).MinPasswordAge)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinPasswordAge(this: *mut core::ffi::c_void, lnminpasswordage: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsDomain", "SetMinPasswordAge", 24usize, |this| ((**(this as *const *const IADsDomain_Vtbl)).SetMinPasswordAge)(this, lnminpasswordage), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxPasswordAge(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsDomain", "MaxPasswordAge", 25usize, |this| ((**(this as *const *const IADsDomain_Vtbl)).MaxPasswordAge)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxPasswordAge(this: *mut core::ffi::c_void, lnmaxpasswordage: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsDomain", "SetMaxPasswordAge", 26usize, |this| ((**(this as *const *const IADsDomain_Vtbl)).SetMaxPasswordAge)(this, lnmaxpasswordage), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxBadPasswordsAllowed(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsDomain", "MaxBadPasswordsAllowed", 27usize, |this| ((**(this as *const *const IADsDomain_Vtbl)).MaxBadPasswordsAllowed)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxBadPasswordsAllowed(this: *mut core::ffi::c_void, lnmaxbadpasswordsallowed: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsDomain", "SetMaxBadPasswordsAllowed", 28usize, |this| ((**(this as *const *const IADsDomain_Vtbl)).SetMaxBadPasswordsAllowed)(this, lnmaxbadpasswordsallowed), |result| Some(*result))
+        }
+        unsafe extern "system" fn PasswordHistoryLength(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsDomain", "PasswordHistoryLength", 29usize, |this| ((**(this as *const *const IADsDomain_Vtbl)).PasswordHistoryLength)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPasswordHistoryLength(this: *mut core::ffi::c_void, lnpasswordhistorylength: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsDomain", "SetPasswordHistoryLength", 30usize, |this| ((**(this as *const *const IADsDomain_Vtbl)).SetPasswordHistoryLength)(this, lnpasswordhistorylength), |result| Some(*result))
+        }
+        unsafe extern "system" fn PasswordAttributes(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsDomain", "PasswordAttributes", 31usize, |this| ((**(this as *const *const IADsDomain_Vtbl)).PasswordAttributes)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPasswordAttributes(this: *mut core::ffi::c_void, lnpasswordattributes: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsDomain", "SetPasswordAttributes", 32usize, |this| ((**(this as *const *const IADsDomain_Vtbl)).SetPasswordAttributes)(this, lnpasswordattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoUnlockInterval(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsDomain", "AutoUnlockInterval", 33usize, |this| ((**(this as *const *const IADsDomain_Vtbl)).AutoUnlockInterval)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoUnlockInterval(this: *mut core::ffi::c_void, lnautounlockinterval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsDomain", "SetAutoUnlockInterval", 34usize, |this| ((**(this as *const *const IADsDomain_Vtbl)).SetAutoUnlockInterval)(this, lnautounlockinterval), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockoutObservationInterval(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsDomain", "LockoutObservationInterval", 35usize, |this| ((**(this as *const *const IADsDomain_Vtbl)).LockoutObservationInterval)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLockoutObservationInterval(this: *mut core::ffi::c_void, lnlockoutobservationinterval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsDomain", "SetLockoutObservationInterval", 36usize, |this| ((**(this as *const *const IADsDomain_Vtbl)).SetLockoutObservationInterval)(this, lnlockoutobservationinterval), |result| Some(*result))
+        }
+        Self {
+            base__: <IADs_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsWorkgroup,
+            MinPasswordLength,
+            SetMinPasswordLength,
+            MinPasswordAge,
+            SetMinPasswordAge,
+            MaxPasswordAge,
+            SetMaxPasswordAge,
+            MaxBadPasswordsAllowed,
+            SetMaxBadPasswordsAllowed,
+            PasswordHistoryLength,
+            SetPasswordHistoryLength,
+            PasswordAttributes,
+            SetPasswordAttributes,
+            AutoUnlockInterval,
+            SetAutoUnlockInterval,
+            LockoutObservationInterval,
+            SetLockoutObservationInterval,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsDomain as windows_core::Interface>::IID || <IADs_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsEmail_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsEmail", "Type", 7usize, |this| ((**(this as *const *const IADsEmail_Vtbl)).Type)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetType(this: *mut core::ffi::c_void, lntype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsEmail", "SetType", 8usize, |this| ((**(this as *const *const IADsEmail_Vtbl)).SetType)(this, lntype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Address(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsEmail", "Address", 9usize, |this| ((**(this as *const *const IADsEmail_Vtbl)).Address)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAddress(this: *mut core::ffi::c_void, bstraddress: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsEmail", "SetAddress", 10usize, |this| ((**(this as *const *const IADsEmail_Vtbl)).SetAddress)(this, bstraddress), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Type, SetType, Address, SetAddress }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsEmail as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsExtension_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Operate(this: *mut core::ffi::c_void, dwcode: u32, vardata1: core::mem::MaybeUninit<windows_core::VARIANT>, vardata2: core::mem::MaybeUninit<windows_core::VARIANT>, vardata3: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsExtension", "Operate", 3usize, |this| ((**(this as *const *const IADsExtension_Vtbl)).Operate)(this, dwcode, vardata1, vardata2, vardata3), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrivateGetIDsOfNames(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, rgsznames: *const *const u16, cnames: u32, lcid: u32, rgdispid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsExtension", "PrivateGetIDsOfNames", 4usize, |this| ((**(this as *const *const IADsExtension_Vtbl)).PrivateGetIDsOfNames)(this, riid, rgsznames, cnames, lcid, rgdispid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PrivateInvoke(this: *mut core::ffi::c_void, dispidmember: i32, riid: *const windows_core::GUID, lcid: u32, wflags: u16, pdispparams: *const super::super::System::Com::DISPPARAMS, pvarresult: *mut core::mem::MaybeUninit<windows_core::VARIANT>, pexcepinfo: *mut super::super::System::Com::EXCEPINFO, puargerr: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsExtension", "PrivateInvoke", 5usize, |this| ((**(this as *const *const IADsExtension_Vtbl)).PrivateInvoke)(this, dispidmember, riid, lcid, wflags, pdispparams, pvarresult, pexcepinfo, puargerr), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Operate,
+            PrivateGetIDsOfNames,
+            #[cfg(feature = "Win32_System_Com")]
+            PrivateInvoke,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PrivateInvoke: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsExtension as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsFaxNumber_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TelephoneNumber(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsFaxNumber", "TelephoneNumber", 7usize, |this| ((**(this as *const *const IADsFaxNumber_Vtbl)).TelephoneNumber)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTelephoneNumber(this: *mut core::ffi::c_void, bstrtelephonenumber: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsFaxNumber", "SetTelephoneNumber", 8usize, |this| ((**(this as *const *const IADsFaxNumber_Vtbl)).SetTelephoneNumber)(this, bstrtelephonenumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn Parameters(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsFaxNumber", "Parameters", 9usize, |this| ((**(this as *const *const IADsFaxNumber_Vtbl)).Parameters)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParameters(this: *mut core::ffi::c_void, vparameters: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsFaxNumber", "SetParameters", 10usize, |this| ((**(this as *const *const IADsFaxNumber_Vtbl)).SetParameters)(this, vparameters), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TelephoneNumber,
+            SetTelephoneNumber,
+            Parameters,
+            SetParameters,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsFaxNumber as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsFileService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsFileService", "Description", 44usize, |this| ((**(this as *const *const IADsFileService_Vtbl)).Description)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsFileService", "SetDescription", 45usize, |this| ((**(this as *const *const IADsFileService_Vtbl)).SetDescription)(this, bstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxUserCount(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsFileService", "MaxUserCount", 46usize, |this| ((**(this as *const *const IADsFileService_Vtbl)).MaxUserCount)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxUserCount(this: *mut core::ffi::c_void, lnmaxusercount: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsFileService", "SetMaxUserCount", 47usize, |this| ((**(this as *const *const IADsFileService_Vtbl)).SetMaxUserCount)(this, lnmaxusercount), |result| Some(*result))
+        }
+        Self { base__: <IADsService_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Description, SetDescription, MaxUserCount, SetMaxUserCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsFileService as windows_core::Interface>::IID || <IADsService_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsFileServiceOperations_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Sessions(this: *mut core::ffi::c_void, ppsessions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsFileServiceOperations", "Sessions", 26usize, |this| ((**(this as *const *const IADsFileServiceOperations_Vtbl)).Sessions)(this, ppsessions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Resources(this: *mut core::ffi::c_void, ppresources: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsFileServiceOperations", "Resources", 27usize, |this| ((**(this as *const *const IADsFileServiceOperations_Vtbl)).Resources)(this, ppresources), |result| Some(*result))
+        }
+        Self {
+            base__: <IADsServiceOperations_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Sessions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Sessions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Resources,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Resources: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsFileServiceOperations as windows_core::Interface>::IID || <IADsServiceOperations_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsFileShare_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentUserCount(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsFileShare", "CurrentUserCount", 20usize, |this| ((**(this as *const *const IADsFileShare_Vtbl)).CurrentUserCount)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsFileShare", "Description", 21usize, |this| ((**(this as *const *const IADsFileShare_Vtbl)).Description)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsFileShare", "SetDescription", 22usize, |this| ((**(this as *const *const IADsFileShare_Vtbl)).SetDescription)(this, bstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn HostComputer(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsFileShare", "HostComputer", 23usize, |this| ((**(this as *const *const IADsFileShare_Vtbl)).HostComputer)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHostComputer(this: *mut core::ffi::c_void, bstrhostcomputer: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsFileShare", "SetHostComputer", 24usize, |this| ((**(this as *const *const IADsFileShare_Vtbl)).SetHostComputer)(this, bstrhostcomputer), |result| Some(*result))
+        }
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsFileShare", "Path", 25usize, |this| ((**(this as *const *const IADsFileShare_Vtbl)).Path)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPath(this: *mut core::ffi::c_void, bstrpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsFileShare", "SetPath", 26usize, |this| ((**(this as *const *const IADsFileShare_Vtbl)).SetPath)(this, bstrpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxUserCount(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsFileShare", "MaxUserCount", 27usize, |this| ((**(this as *const *const IADsFileShare_Vtbl)).MaxUserCount)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxUserCount(this: *mut core::ffi::c_void, lnmaxusercount: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsFileShare", "SetMaxUserCount", 28usize, |this| ((**(this as *const *const IADsFileShare_Vtbl)).SetMaxUserCount)(this, lnmaxusercount), |result| Some(*result))
+        }
+        Self {
+            base__: <IADs_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CurrentUserCount,
+            Description,
+            SetDescription,
+            HostComputer,
+            SetHostComputer,
+            Path,
+            SetPath,
+            MaxUserCount,
+            SetMaxUserCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsFileShare as windows_core::Interface>::IID || <IADs_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsGroup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsGroup", "Description", 20usize, |this| ((**(this as *const *const IADsGroup_Vtbl)).Description)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsGroup", "SetDescription", 21usize, |this| ((**(this as *const *const IADsGroup_Vtbl)).SetDescription)(this, bstrdescription), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Members(this: *mut core::ffi::c_void, ppmembers: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsGroup", "Members", 22usize, |this| ((**(this as *const *const IADsGroup_Vtbl)).Members)(this, ppmembers), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMember(this: *mut core::ffi::c_void, bstrmember: core::mem::MaybeUninit<windows_core::BSTR>, bmember: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsGroup", "IsMember", 23usize, |this| ((**(this as *const *const IADsGroup_Vtbl)).IsMember)(this, bstrmember, bmember), |result| Some(*result))
+        }
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, bstrnewitem: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsGroup", "Add", 24usize, |this| ((**(this as *const *const IADsGroup_Vtbl)).Add)(this, bstrnewitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, bstritemtoberemoved: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsGroup", "Remove", 25usize, |this| ((**(this as *const *const IADsGroup_Vtbl)).Remove)(this, bstritemtoberemoved), |result| Some(*result))
+        }
+        Self {
+            base__: <IADs_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Description,
+            SetDescription,
+            #[cfg(feature = "Win32_System_Com")]
+            Members,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Members: 0,
+            IsMember,
+            Add,
+            Remove,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsGroup as windows_core::Interface>::IID || <IADs_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsHold_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ObjectName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsHold", "ObjectName", 7usize, |this| ((**(this as *const *const IADsHold_Vtbl)).ObjectName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetObjectName(this: *mut core::ffi::c_void, bstrobjectname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsHold", "SetObjectName", 8usize, |this| ((**(this as *const *const IADsHold_Vtbl)).SetObjectName)(this, bstrobjectname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Amount(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsHold", "Amount", 9usize, |this| ((**(this as *const *const IADsHold_Vtbl)).Amount)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAmount(this: *mut core::ffi::c_void, lnamount: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsHold", "SetAmount", 10usize, |this| ((**(this as *const *const IADsHold_Vtbl)).SetAmount)(this, lnamount), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ObjectName, SetObjectName, Amount, SetAmount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsHold as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsLargeInteger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HighPart(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsLargeInteger", "HighPart", 7usize, |this| ((**(this as *const *const IADsLargeInteger_Vtbl)).HighPart)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHighPart(this: *mut core::ffi::c_void, lnhighpart: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsLargeInteger", "SetHighPart", 8usize, |this| ((**(this as *const *const IADsLargeInteger_Vtbl)).SetHighPart)(this, lnhighpart), |result| Some(*result))
+        }
+        unsafe extern "system" fn LowPart(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsLargeInteger", "LowPart", 9usize, |this| ((**(this as *const *const IADsLargeInteger_Vtbl)).LowPart)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLowPart(this: *mut core::ffi::c_void, lnlowpart: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsLargeInteger", "SetLowPart", 10usize, |this| ((**(this as *const *const IADsLargeInteger_Vtbl)).SetLowPart)(this, lnlowpart), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HighPart, SetHighPart, LowPart, SetLowPart }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsLargeInteger as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsLocality_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsLocality", "Description", 20usize, |this| ((**(this as *const *const IADsLocality_Vtbl)).Description)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsLocality", "SetDescription", 21usize, |this| ((**(this as *const *const IADsLocality_Vtbl)).SetDescription)(this, bstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalityName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsLocality", "LocalityName", 22usize, |this| ((**(this as *const *const IADsLocality_Vtbl)).LocalityName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocalityName(this: *mut core::ffi::c_void, bstrlocalityname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsLocality", "SetLocalityName", 23usize, |this| ((**(this as *const *const IADsLocality_Vtbl)).SetLocalityName)(this, bstrlocalityname), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostalAddress(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsLocality", "PostalAddress", 24usize, |this| ((**(this as *const *const IADsLocality_Vtbl)).PostalAddress)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPostalAddress(this: *mut core::ffi::c_void, bstrpostaladdress: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsLocality", "SetPostalAddress", 25usize, |this| ((**(this as *const *const IADsLocality_Vtbl)).SetPostalAddress)(this, bstrpostaladdress), |result| Some(*result))
+        }
+        unsafe extern "system" fn SeeAlso(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsLocality", "SeeAlso", 26usize, |this| ((**(this as *const *const IADsLocality_Vtbl)).SeeAlso)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSeeAlso(this: *mut core::ffi::c_void, vseealso: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsLocality", "SetSeeAlso", 27usize, |this| ((**(this as *const *const IADsLocality_Vtbl)).SetSeeAlso)(this, vseealso), |result| Some(*result))
+        }
+        Self {
+            base__: <IADs_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Description,
+            SetDescription,
+            LocalityName,
+            SetLocalityName,
+            PostalAddress,
+            SetPostalAddress,
+            SeeAlso,
+            SetSeeAlso,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsLocality as windows_core::Interface>::IID || <IADs_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsMembers_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsMembers", "Count", 7usize, |this| ((**(this as *const *const IADsMembers_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsMembers", "_NewEnum", 8usize, |this| ((**(this as *const *const IADsMembers_Vtbl))._NewEnum)(this, ppenumerator), |result| Some(*result))
+        }
+        unsafe extern "system" fn Filter(this: *mut core::ffi::c_void, pvfilter: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsMembers", "Filter", 9usize, |this| ((**(this as *const *const IADsMembers_Vtbl)).Filter)(this, pvfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFilter(this: *mut core::ffi::c_void, pvfilter: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsMembers", "SetFilter", 10usize, |this| ((**(this as *const *const IADsMembers_Vtbl)).SetFilter)(this, pvfilter), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Count, _NewEnum, Filter, SetFilter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsMembers as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsNameTranslate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetChaseReferral(this: *mut core::ffi::c_void, lnchasereferral: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsNameTranslate", "SetChaseReferral", 7usize, |this| ((**(this as *const *const IADsNameTranslate_Vtbl)).SetChaseReferral)(this, lnchasereferral), |result| Some(*result))
+        }
+        unsafe extern "system" fn Init(this: *mut core::ffi::c_void, lnsettype: i32, bstradspath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsNameTranslate", "Init", 8usize, |this| ((**(this as *const *const IADsNameTranslate_Vtbl)).Init)(this, lnsettype, bstradspath), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitEx(this: *mut core::ffi::c_void, lnsettype: i32, bstradspath: core::mem::MaybeUninit<windows_core::BSTR>, bstruserid: core::mem::MaybeUninit<windows_core::BSTR>, bstrdomain: core::mem::MaybeUninit<windows_core::BSTR>, bstrpassword: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsNameTranslate", "InitEx", 9usize, |this| ((**(this as *const *const IADsNameTranslate_Vtbl)).InitEx)(this, lnsettype, bstradspath, bstruserid, bstrdomain, bstrpassword), |result| Some(*result))
+        }
+        unsafe extern "system" fn Set(this: *mut core::ffi::c_void, lnsettype: i32, bstradspath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsNameTranslate", "Set", 10usize, |this| ((**(this as *const *const IADsNameTranslate_Vtbl)).Set)(this, lnsettype, bstradspath), |result| Some(*result))
+        }
+        unsafe extern "system" fn Get(this: *mut core::ffi::c_void, lnformattype: i32, pbstradspath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsNameTranslate", "Get", 11usize, |this| ((**(this as *const *const IADsNameTranslate_Vtbl)).Get)(this, lnformattype, pbstradspath), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEx(this: *mut core::ffi::c_void, lnformattype: i32, pvar: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsNameTranslate", "SetEx", 12usize, |this| ((**(this as *const *const IADsNameTranslate_Vtbl)).SetEx)(this, lnformattype, pvar), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEx(this: *mut core::ffi::c_void, lnformattype: i32, pvar: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsNameTranslate", "GetEx", 13usize, |this| ((**(this as *const *const IADsNameTranslate_Vtbl)).GetEx)(this, lnformattype, pvar), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetChaseReferral,
+            Init,
+            InitEx,
+            Set,
+            Get,
+            SetEx,
+            GetEx,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsNameTranslate as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsNamespaces_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DefaultContainer(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsNamespaces", "DefaultContainer", 20usize, |this| ((**(this as *const *const IADsNamespaces_Vtbl)).DefaultContainer)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultContainer(this: *mut core::ffi::c_void, bstrdefaultcontainer: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsNamespaces", "SetDefaultContainer", 21usize, |this| ((**(this as *const *const IADsNamespaces_Vtbl)).SetDefaultContainer)(this, bstrdefaultcontainer), |result| Some(*result))
+        }
+        Self { base__: <IADs_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DefaultContainer, SetDefaultContainer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsNamespaces as windows_core::Interface>::IID || <IADs_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsNetAddress_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddressType(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsNetAddress", "AddressType", 7usize, |this| ((**(this as *const *const IADsNetAddress_Vtbl)).AddressType)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAddressType(this: *mut core::ffi::c_void, lnaddresstype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsNetAddress", "SetAddressType", 8usize, |this| ((**(this as *const *const IADsNetAddress_Vtbl)).SetAddressType)(this, lnaddresstype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Address(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsNetAddress", "Address", 9usize, |this| ((**(this as *const *const IADsNetAddress_Vtbl)).Address)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAddress(this: *mut core::ffi::c_void, vaddress: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsNetAddress", "SetAddress", 10usize, |this| ((**(this as *const *const IADsNetAddress_Vtbl)).SetAddress)(this, vaddress), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddressType, SetAddressType, Address, SetAddress }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsNetAddress as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsO_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsO", "Description", 20usize, |this| ((**(this as *const *const IADsO_Vtbl)).Description)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsO", "SetDescription", 21usize, |this| ((**(this as *const *const IADsO_Vtbl)).SetDescription)(this, bstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalityName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsO", "LocalityName", 22usize, |this| ((**(this as *const *const IADsO_Vtbl)).LocalityName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocalityName(this: *mut core::ffi::c_void, bstrlocalityname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsO", "SetLocalityName", 23usize, |this| ((**(this as *const *const IADsO_Vtbl)).SetLocalityName)(this, bstrlocalityname), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostalAddress(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsO", "PostalAddress", 24usize, |this| ((**(this as *const *const IADsO_Vtbl)).PostalAddress)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPostalAddress(this: *mut core::ffi::c_void, bstrpostaladdress: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsO", "SetPostalAddress", 25usize, |this| ((**(this as *const *const IADsO_Vtbl)).SetPostalAddress)(this, bstrpostaladdress), |result| Some(*result))
+        }
+        unsafe extern "system" fn TelephoneNumber(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsO", "TelephoneNumber", 26usize, |this| ((**(this as *const *const IADsO_Vtbl)).TelephoneNumber)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTelephoneNumber(this: *mut core::ffi::c_void, bstrtelephonenumber: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsO", "SetTelephoneNumber", 27usize, |this| ((**(this as *const *const IADsO_Vtbl)).SetTelephoneNumber)(this, bstrtelephonenumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn FaxNumber(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsO", "FaxNumber", 28usize, |this| ((**(this as *const *const IADsO_Vtbl)).FaxNumber)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFaxNumber(this: *mut core::ffi::c_void, bstrfaxnumber: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsO", "SetFaxNumber", 29usize, |this| ((**(this as *const *const IADsO_Vtbl)).SetFaxNumber)(this, bstrfaxnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn SeeAlso(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsO", "SeeAlso", 30usize, |this| ((**(this as *const *const IADsO_Vtbl)).SeeAlso)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSeeAlso(this: *mut core::ffi::c_void, vseealso: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsO", "SetSeeAlso", 31usize, |this| ((**(this as *const *const IADsO_Vtbl)).SetSeeAlso)(this, vseealso), |result| Some(*result))
+        }
+        Self {
+            base__: <IADs_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Description,
+            SetDescription,
+            LocalityName,
+            SetLocalityName,
+            PostalAddress,
+            SetPostalAddress,
+            TelephoneNumber,
+            SetTelephoneNumber,
+            FaxNumber,
+            SetFaxNumber,
+            SeeAlso,
+            SetSeeAlso,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsO as windows_core::Interface>::IID || <IADs_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsOU_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsOU", "Description", 20usize, |this| ((**(this as *const *const IADsOU_Vtbl)).Description)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsOU", "SetDescription", 21usize, |this| ((**(this as *const *const IADsOU_Vtbl)).SetDescription)(this, bstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalityName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsOU", "LocalityName", 22usize, |this| ((**(this as *const *const IADsOU_Vtbl)).LocalityName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocalityName(this: *mut core::ffi::c_void, bstrlocalityname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsOU", "SetLocalityName", 23usize, |this| ((**(this as *const *const IADsOU_Vtbl)).SetLocalityName)(this, bstrlocalityname), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostalAddress(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsOU", "PostalAddress", 24usize, |this| ((**(this as *const *const IADsOU_Vtbl)).PostalAddress)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPostalAddress(this: *mut core::ffi::c_void, bstrpostaladdress: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsOU", "SetPostalAddress", 25usize, |this| ((**(this as *const *const IADsOU_Vtbl)).SetPostalAddress)(this, bstrpostaladdress), |result| Some(*result))
+        }
+        unsafe extern "system" fn TelephoneNumber(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsOU", "TelephoneNumber", 26usize, |this| ((**(this as *const *const IADsOU_Vtbl)).TelephoneNumber)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTelephoneNumber(this: *mut core::ffi::c_void, bstrtelephonenumber: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsOU", "SetTelephoneNumber", 27usize, |this| ((**(this as *const *const IADsOU_Vtbl)).SetTelephoneNumber)(this, bstrtelephonenumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn FaxNumber(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsOU", "FaxNumber", 28usize, |this| ((**(this as *const *const IADsOU_Vtbl)).FaxNumber)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFaxNumber(this: *mut core::ffi::c_void, bstrfaxnumber: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsOU", "SetFaxNumber", 29usize, |this| ((**(this as *const *const IADsOU_Vtbl)).SetFaxNumber)(this, bstrfaxnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn SeeAlso(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsOU", "SeeAlso", 30usize, |this| ((**(this as *const *const IADsOU_Vtbl)).SeeAlso)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSeeAlso(this: *mut core::ffi::c_void, vseealso: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsOU", "SetSeeAlso", 31usize, |this| ((**(this as *const *const IADsOU_Vtbl)).SetSeeAlso)(this, vseealso), |result| Some(*result))
+        }
+        unsafe extern "system" fn BusinessCategory(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsOU", "BusinessCategory", 32usize, |this| ((**(this as *const *const IADsOU_Vtbl)).BusinessCategory)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBusinessCategory(this: *mut core::ffi::c_void, bstrbusinesscategory: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsOU", "SetBusinessCategory", 33usize, |this| ((**(this as *const *const IADsOU_Vtbl)).SetBusinessCategory)(this, bstrbusinesscategory), |result| Some(*result))
+        }
+        Self {
+            base__: <IADs_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Description,
+            SetDescription,
+            LocalityName,
+            SetLocalityName,
+            PostalAddress,
+            SetPostalAddress,
+            TelephoneNumber,
+            SetTelephoneNumber,
+            FaxNumber,
+            SetFaxNumber,
+            SeeAlso,
+            SetSeeAlso,
+            BusinessCategory,
+            SetBusinessCategory,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsOU as windows_core::Interface>::IID || <IADs_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsObjectOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOption(this: *mut core::ffi::c_void, lnoption: i32, pvvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsObjectOptions", "GetOption", 7usize, |this| ((**(this as *const *const IADsObjectOptions_Vtbl)).GetOption)(this, lnoption, pvvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOption(this: *mut core::ffi::c_void, lnoption: i32, vvalue: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsObjectOptions", "SetOption", 8usize, |this| ((**(this as *const *const IADsObjectOptions_Vtbl)).SetOption)(this, lnoption, vvalue), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetOption, SetOption }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsObjectOptions as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsOctetList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OctetList(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsOctetList", "OctetList", 7usize, |this| ((**(this as *const *const IADsOctetList_Vtbl)).OctetList)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOctetList(this: *mut core::ffi::c_void, voctetlist: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsOctetList", "SetOctetList", 8usize, |this| ((**(this as *const *const IADsOctetList_Vtbl)).SetOctetList)(this, voctetlist), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OctetList, SetOctetList }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsOctetList as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsOpenDSObject_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OpenDSObject(this: *mut core::ffi::c_void, lpszdnname: core::mem::MaybeUninit<windows_core::BSTR>, lpszusername: core::mem::MaybeUninit<windows_core::BSTR>, lpszpassword: core::mem::MaybeUninit<windows_core::BSTR>, lnreserved: i32, ppoledsobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsOpenDSObject", "OpenDSObject", 7usize, |this| ((**(this as *const *const IADsOpenDSObject_Vtbl)).OpenDSObject)(this, lpszdnname, lpszusername, lpszpassword, lnreserved, ppoledsobj), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            OpenDSObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OpenDSObject: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsOpenDSObject as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsPath_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPath", "Type", 7usize, |this| ((**(this as *const *const IADsPath_Vtbl)).Type)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetType(this: *mut core::ffi::c_void, lntype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPath", "SetType", 8usize, |this| ((**(this as *const *const IADsPath_Vtbl)).SetType)(this, lntype), |result| Some(*result))
+        }
+        unsafe extern "system" fn VolumeName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPath", "VolumeName", 9usize, |this| ((**(this as *const *const IADsPath_Vtbl)).VolumeName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVolumeName(this: *mut core::ffi::c_void, bstrvolumename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPath", "SetVolumeName", 10usize, |this| ((**(this as *const *const IADsPath_Vtbl)).SetVolumeName)(this, bstrvolumename), |result| Some(*result))
+        }
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPath", "Path", 11usize, |this| ((**(this as *const *const IADsPath_Vtbl)).Path)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPath(this: *mut core::ffi::c_void, bstrpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPath", "SetPath", 12usize, |this| ((**(this as *const *const IADsPath_Vtbl)).SetPath)(this, bstrpath), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Type,
+            SetType,
+            VolumeName,
+            SetVolumeName,
+            Path,
+            SetPath,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsPath as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsPathname_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Set(this: *mut core::ffi::c_void, bstradspath: core::mem::MaybeUninit<windows_core::BSTR>, lnsettype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPathname", "Set", 7usize, |this| ((**(this as *const *const IADsPathname_Vtbl)).Set)(this, bstradspath, lnsettype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayType(this: *mut core::ffi::c_void, lndisplaytype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPathname", "SetDisplayType", 8usize, |this| ((**(this as *const *const IADsPathname_Vtbl)).SetDisplayType)(this, lndisplaytype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Retrieve(this: *mut core::ffi::c_void, lnformattype: i32, pbstradspath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPathname", "Retrieve", 9usize, |this| ((**(this as *const *const IADsPathname_Vtbl)).Retrieve)(this, lnformattype, pbstradspath), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumElements(this: *mut core::ffi::c_void, plnnumpathelements: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPathname", "GetNumElements", 10usize, |this| ((**(this as *const *const IADsPathname_Vtbl)).GetNumElements)(this, plnnumpathelements), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetElement(this: *mut core::ffi::c_void, lnelementindex: i32, pbstrelement: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPathname", "GetElement", 11usize, |this| ((**(this as *const *const IADsPathname_Vtbl)).GetElement)(this, lnelementindex, pbstrelement), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddLeafElement(this: *mut core::ffi::c_void, bstrleafelement: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPathname", "AddLeafElement", 12usize, |this| ((**(this as *const *const IADsPathname_Vtbl)).AddLeafElement)(this, bstrleafelement), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveLeafElement(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPathname", "RemoveLeafElement", 13usize, |this| ((**(this as *const *const IADsPathname_Vtbl)).RemoveLeafElement)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CopyPath(this: *mut core::ffi::c_void, ppadspath: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPathname", "CopyPath", 14usize, |this| ((**(this as *const *const IADsPathname_Vtbl)).CopyPath)(this, ppadspath), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEscapedElement(this: *mut core::ffi::c_void, lnreserved: i32, bstrinstr: core::mem::MaybeUninit<windows_core::BSTR>, pbstroutstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPathname", "GetEscapedElement", 15usize, |this| ((**(this as *const *const IADsPathname_Vtbl)).GetEscapedElement)(this, lnreserved, bstrinstr, pbstroutstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn EscapedMode(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPathname", "EscapedMode", 16usize, |this| ((**(this as *const *const IADsPathname_Vtbl)).EscapedMode)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEscapedMode(this: *mut core::ffi::c_void, lnescapedmode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPathname", "SetEscapedMode", 17usize, |this| ((**(this as *const *const IADsPathname_Vtbl)).SetEscapedMode)(this, lnescapedmode), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Set,
+            SetDisplayType,
+            Retrieve,
+            GetNumElements,
+            GetElement,
+            AddLeafElement,
+            RemoveLeafElement,
+            #[cfg(feature = "Win32_System_Com")]
+            CopyPath,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CopyPath: 0,
+            GetEscapedElement,
+            EscapedMode,
+            SetEscapedMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsPathname as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsPostalAddress_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PostalAddress(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPostalAddress", "PostalAddress", 7usize, |this| ((**(this as *const *const IADsPostalAddress_Vtbl)).PostalAddress)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPostalAddress(this: *mut core::ffi::c_void, vpostaladdress: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPostalAddress", "SetPostalAddress", 8usize, |this| ((**(this as *const *const IADsPostalAddress_Vtbl)).SetPostalAddress)(this, vpostaladdress), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PostalAddress, SetPostalAddress }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsPostalAddress as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsPrintJob_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HostPrintQueue(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintJob", "HostPrintQueue", 20usize, |this| ((**(this as *const *const IADsPrintJob_Vtbl)).HostPrintQueue)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintJob", "User", 21usize, |this| ((**(this as *const *const IADsPrintJob_Vtbl)).User)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserPath(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintJob", "UserPath", 22usize, |this| ((**(this as *const *const IADsPrintJob_Vtbl)).UserPath)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimeSubmitted(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintJob", "TimeSubmitted", 23usize, |this| ((**(this as *const *const IADsPrintJob_Vtbl)).TimeSubmitted)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalPages(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintJob", "TotalPages", 24usize, |this| ((**(this as *const *const IADsPrintJob_Vtbl)).TotalPages)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintJob", "Size", 25usize, |this| ((**(this as *const *const IADsPrintJob_Vtbl)).Size)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintJob", "Description", 26usize, |this| ((**(this as *const *const IADsPrintJob_Vtbl)).Description)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintJob", "SetDescription", 27usize, |this| ((**(this as *const *const IADsPrintJob_Vtbl)).SetDescription)(this, bstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn Priority(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintJob", "Priority", 28usize, |this| ((**(this as *const *const IADsPrintJob_Vtbl)).Priority)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPriority(this: *mut core::ffi::c_void, lnpriority: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintJob", "SetPriority", 29usize, |this| ((**(this as *const *const IADsPrintJob_Vtbl)).SetPriority)(this, lnpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartTime(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintJob", "StartTime", 30usize, |this| ((**(this as *const *const IADsPrintJob_Vtbl)).StartTime)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartTime(this: *mut core::ffi::c_void, dastarttime: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintJob", "SetStartTime", 31usize, |this| ((**(this as *const *const IADsPrintJob_Vtbl)).SetStartTime)(this, dastarttime), |result| Some(*result))
+        }
+        unsafe extern "system" fn UntilTime(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintJob", "UntilTime", 32usize, |this| ((**(this as *const *const IADsPrintJob_Vtbl)).UntilTime)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUntilTime(this: *mut core::ffi::c_void, dauntiltime: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintJob", "SetUntilTime", 33usize, |this| ((**(this as *const *const IADsPrintJob_Vtbl)).SetUntilTime)(this, dauntiltime), |result| Some(*result))
+        }
+        unsafe extern "system" fn Notify(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintJob", "Notify", 34usize, |this| ((**(this as *const *const IADsPrintJob_Vtbl)).Notify)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNotify(this: *mut core::ffi::c_void, bstrnotify: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintJob", "SetNotify", 35usize, |this| ((**(this as *const *const IADsPrintJob_Vtbl)).SetNotify)(this, bstrnotify), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyPath(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintJob", "NotifyPath", 36usize, |this| ((**(this as *const *const IADsPrintJob_Vtbl)).NotifyPath)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNotifyPath(this: *mut core::ffi::c_void, bstrnotifypath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintJob", "SetNotifyPath", 37usize, |this| ((**(this as *const *const IADsPrintJob_Vtbl)).SetNotifyPath)(this, bstrnotifypath), |result| Some(*result))
+        }
+        Self {
+            base__: <IADs_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            HostPrintQueue,
+            User,
+            UserPath,
+            TimeSubmitted,
+            TotalPages,
+            Size,
+            Description,
+            SetDescription,
+            Priority,
+            SetPriority,
+            StartTime,
+            SetStartTime,
+            UntilTime,
+            SetUntilTime,
+            Notify,
+            SetNotify,
+            NotifyPath,
+            SetNotifyPath,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsPrintJob as windows_core::Interface>::IID || <IADs_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsPrintJobOperations_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintJobOperations", "Status", 20usize, |this| ((**(this as *const *const IADsPrintJobOperations_Vtbl)).Status)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimeElapsed(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintJobOperations", "TimeElapsed", 21usize, |this| ((**(this as *const *const IADsPrintJobOperations_Vtbl)).TimeElapsed)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PagesPrinted(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintJobOperations", "PagesPrinted", 22usize, |this| ((**(this as *const *const IADsPrintJobOperations_Vtbl)).PagesPrinted)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Position(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintJobOperations", "Position", 23usize, |this| ((**(this as *const *const IADsPrintJobOperations_Vtbl)).Position)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPosition(this: *mut core::ffi::c_void, lnposition: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintJobOperations", "SetPosition", 24usize, |this| ((**(this as *const *const IADsPrintJobOperations_Vtbl)).SetPosition)(this, lnposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintJobOperations", "Pause", 25usize, |this| ((**(this as *const *const IADsPrintJobOperations_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintJobOperations", "Resume", 26usize, |this| ((**(this as *const *const IADsPrintJobOperations_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        Self { base__: <IADs_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, TimeElapsed, PagesPrinted, Position, SetPosition, Pause, Resume }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsPrintJobOperations as windows_core::Interface>::IID || <IADs_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsPrintQueue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrinterPath(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueue", "PrinterPath", 20usize, |this| ((**(this as *const *const IADsPrintQueue_Vtbl)).PrinterPath)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrinterPath(this: *mut core::ffi::c_void, bstrprinterpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueue", "SetPrinterPath", 21usize, |this| ((**(this as *const *const IADsPrintQueue_Vtbl)).SetPrinterPath)(this, bstrprinterpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn Model(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueue", "Model", 22usize, |this| ((**(this as *const *const IADsPrintQueue_Vtbl)).Model)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetModel(this: *mut core::ffi::c_void, bstrmodel: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueue", "SetModel", 23usize, |this| ((**(this as *const *const IADsPrintQueue_Vtbl)).SetModel)(this, bstrmodel), |result| Some(*result))
+        }
+        unsafe extern "system" fn Datatype(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueue", "Datatype", 24usize, |this| ((**(this as *const *const IADsPrintQueue_Vtbl)).Datatype)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDatatype(this: *mut core::ffi::c_void, bstrdatatype: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueue", "SetDatatype", 25usize, |this| ((**(this as *const *const IADsPrintQueue_Vtbl)).SetDatatype)(this, bstrdatatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrintProcessor(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueue", "PrintProcessor", 26usize, |this| ((**(this as *const *const IADsPrintQueue_Vtbl)).PrintProcessor)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrintProcessor(this: *mut core::ffi::c_void, bstrprintprocessor: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueue", "SetPrintProcessor", 27usize, |this| ((**(this as *const *const IADsPrintQueue_Vtbl)).SetPrintProcessor)(this, bstrprintprocessor), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueue", "Description", 28usize, |this| ((**(this as *const *const IADsPrintQueue_Vtbl)).Description)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueue", "SetDescription", 29usize, |this| ((**(this as *const *const IADsPrintQueue_Vtbl)).SetDescription)(this, bstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn Location(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueue", "Location", 30usize, |this| ((**(this as *const *const IADsPrintQueue_Vtbl)).Location)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocation(this: *mut core::ffi::c_void, bstrlocation: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueue", "SetLocation", 31usize, |this| ((**(this as *const *const IADsPrintQueue_Vtbl)).SetLocation)(this, bstrlocation), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartTime(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueue", "StartTime", 32usize, |this| ((**(this as *const *const IADsPrintQueue_Vtbl)).StartTime)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartTime(this: *mut core::ffi::c_void, dastarttime: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueue", "SetStartTime", 33usize, |this| ((**(this as *const *const IADsPrintQueue_Vtbl)).SetStartTime)(this, dastarttime), |result| Some(*result))
+        }
+        unsafe extern "system" fn UntilTime(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueue", "UntilTime", 34usize, |this| ((**(this as *const *const IADsPrintQueue_Vtbl)).UntilTime)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUntilTime(this: *mut core::ffi::c_void, dauntiltime: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueue", "SetUntilTime", 35usize, |this| ((**(this as *const *const IADsPrintQueue_Vtbl)).SetUntilTime)(this, dauntiltime), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultJobPriority(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueue", "DefaultJobPriority", 36usize, |this| ((**(this as *const *const IADsPrintQueue_Vtbl)).DefaultJobPriority)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultJobPriority(this: *mut core::ffi::c_void, lndefaultjobpriority: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueue", "SetDefaultJobPriority", 37usize, |this| ((**(this as *const *const IADsPrintQueue_Vtbl)).SetDefaultJobPriority)(this, lndefaultjobpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn Priority(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueue", "Priority", 38usize, |this| ((**(this as *const *const IADsPrintQueue_Vtbl)).Priority)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPriority(this: *mut core::ffi::c_void, lnpriority: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueue", "SetPriority", 39usize, |this| ((**(this as *const *const IADsPrintQueue_Vtbl)).SetPriority)(this, lnpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn BannerPage(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueue", "BannerPage", 40usize, |this| ((**(this as *const *const IADsPrintQueue_Vtbl)).BannerPage)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBannerPage(this: *mut core::ffi::c_void, bstrbannerpage: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueue", "SetBannerPage", 41usize, |this| ((**(this as *const *const IADsPrintQueue_Vtbl)).SetBannerPage)(this, bstrbannerpage), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrintDevices(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueue", "PrintDevices", 42usize, |this| ((**(this as *const *const IADsPrintQueue_Vtbl)).PrintDevices)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrintDevices(this: *mut core::ffi::c_void, vprintdevices: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueue", "SetPrintDevices", 43usize, |this| ((**(this as *const *const IADsPrintQueue_Vtbl)).SetPrintDevices)(this, vprintdevices), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetAddresses(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueue", "NetAddresses", 44usize, |this| ((**(this as *const *const IADsPrintQueue_Vtbl)).NetAddresses)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNetAddresses(this: *mut core::ffi::c_void, vnetaddresses: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueue", "SetNetAddresses", 45usize, |this| ((**(this as *const *const IADsPrintQueue_Vtbl)).SetNetAddresses)(this, vnetaddresses), |result| Some(*result))
+        }
+        Self {
+            base__: <IADs_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PrinterPath,
+            SetPrinterPath,
+            Model,
+            SetModel,
+            Datatype,
+            SetDatatype,
+            PrintProcessor,
+            SetPrintProcessor,
+            Description,
+            SetDescription,
+            Location,
+            SetLocation,
+            StartTime,
+            SetStartTime,
+            UntilTime,
+            SetUntilTime,
+            DefaultJobPriority,
+            SetDefaultJobPriority,
+            Priority,
+            SetPriority,
+            BannerPage,
+            SetBannerPage,
+            PrintDevices,
+            SetPrintDevices,
+            NetAddresses,
+            SetNetAddresses,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsPrintQueue as windows_core::Interface>::IID || <IADs_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsPrintQueueOperations_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueueOperations", "Status", 20usize, |this| ((**(this as *const *const IADsPrintQueueOperations_Vtbl)).Status)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PrintJobs(this: *mut core::ffi::c_void, pobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueueOperations", "PrintJobs", 21usize, |this| ((**(this as *const *const IADsPrintQueueOperations_Vtbl)).PrintJobs)(this, pobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueueOperations", "Pause", 22usize, |this| ((**(this as *const *const IADsPrintQueueOperations_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueueOperations", "Resume", 23usize, |this| ((**(this as *const *const IADsPrintQueueOperations_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Purge(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPrintQueueOperations", "Purge", 24usize, |this| ((**(this as *const *const IADsPrintQueueOperations_Vtbl)).Purge)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IADs_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            #[cfg(feature = "Win32_System_Com")]
+            PrintJobs,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PrintJobs: 0,
+            Pause,
+            Resume,
+            Purge,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsPrintQueueOperations as windows_core::Interface>::IID || <IADs_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsProperty_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OID(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsProperty", "OID", 20usize, |this| ((**(this as *const *const IADsProperty_Vtbl)).OID)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOID(this: *mut core::ffi::c_void, bstroid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsProperty", "SetOID", 21usize, |this| ((**(this as *const *const IADsProperty_Vtbl)).SetOID)(this, bstroid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Syntax(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsProperty", "Syntax", 22usize, |this| ((**(this as *const *const IADsProperty_Vtbl)).Syntax)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSyntax(this: *mut core::ffi::c_void, bstrsyntax: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsProperty", "SetSyntax", 23usize, |this| ((**(this as *const *const IADsProperty_Vtbl)).SetSyntax)(this, bstrsyntax), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxRange(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsProperty", "MaxRange", 24usize, |this| ((**(this as *const *const IADsProperty_Vtbl)).MaxRange)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxRange(this: *mut core::ffi::c_void, lnmaxrange: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsProperty", "SetMaxRange", 25usize, |this| ((**(this as *const *const IADsProperty_Vtbl)).SetMaxRange)(this, lnmaxrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinRange(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsProperty", "MinRange", 26usize, |this| ((**(this as *const *const IADsProperty_Vtbl)).MinRange)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinRange(this: *mut core::ffi::c_void, lnminrange: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsProperty", "SetMinRange", 27usize, |this| ((**(this as *const *const IADsProperty_Vtbl)).SetMinRange)(this, lnminrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn MultiValued(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsProperty", "MultiValued", 28usize, |this| ((**(this as *const *const IADsProperty_Vtbl)).MultiValued)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMultiValued(this: *mut core::ffi::c_void, fmultivalued: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsProperty", "SetMultiValued", 29usize, |this| ((**(this as *const *const IADsProperty_Vtbl)).SetMultiValued)(this, fmultivalued), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Qualifiers(this: *mut core::ffi::c_void, ppqualifiers: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsProperty", "Qualifiers", 30usize, |this| ((**(this as *const *const IADsProperty_Vtbl)).Qualifiers)(this, ppqualifiers), |result| Some(*result))
+        }
+        Self {
+            base__: <IADs_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OID,
+            SetOID,
+            Syntax,
+            SetSyntax,
+            MaxRange,
+            SetMaxRange,
+            MinRange,
+            SetMinRange,
+            MultiValued,
+            SetMultiValued,
+            #[cfg(feature = "Win32_System_Com")]
+            Qualifiers,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Qualifiers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsProperty as windows_core::Interface>::IID || <IADs_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsPropertyEntry_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyEntry", "Clear", 7usize, |this| ((**(this as *const *const IADsPropertyEntry_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyEntry", "Name", 8usize, |this| ((**(this as *const *const IADsPropertyEntry_Vtbl)).Name)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyEntry", "SetName", 9usize, |this| ((**(this as *const *const IADsPropertyEntry_Vtbl)).SetName)(this, bstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn ADsType(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyEntry", "ADsType", 10usize, |this| ((**(this as *const *const IADsPropertyEntry_Vtbl)).ADsType)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetADsType(this: *mut core::ffi::c_void, lnadstype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyEntry", "SetADsType", 11usize, |this| ((**(this as *const *const IADsPropertyEntry_Vtbl)).SetADsType)(this, lnadstype), |result| Some(*result))
+        }
+        unsafe extern "system" fn ControlCode(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyEntry", "ControlCode", 12usize, |this| ((**(this as *const *const IADsPropertyEntry_Vtbl)).ControlCode)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetControlCode(this: *mut core::ffi::c_void, lncontrolcode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyEntry", "SetControlCode", 13usize, |this| ((**(this as *const *const IADsPropertyEntry_Vtbl)).SetControlCode)(this, lncontrolcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn Values(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyEntry", "Values", 14usize, |this| ((**(this as *const *const IADsPropertyEntry_Vtbl)).Values)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValues(this: *mut core::ffi::c_void, vvalues: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyEntry", "SetValues", 15usize, |this| ((**(this as *const *const IADsPropertyEntry_Vtbl)).SetValues)(this, vvalues), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Clear,
+            Name,
+            SetName,
+            ADsType,
+            SetADsType,
+            ControlCode,
+            SetControlCode,
+            Values,
+            SetValues,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsPropertyEntry as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsPropertyList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PropertyCount(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyList", "PropertyCount", 7usize, |this| ((**(this as *const *const IADsPropertyList_Vtbl)).PropertyCount)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyList", "Next", 8usize, |this| ((**(this as *const *const IADsPropertyList_Vtbl)).Next)(this, pvariant), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celements: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyList", "Skip", 9usize, |this| ((**(this as *const *const IADsPropertyList_Vtbl)).Skip)(this, celements), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyList", "Reset", 10usize, |this| ((**(this as *const *const IADsPropertyList_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyList", "Item", 11usize, |this| ((**(this as *const *const IADsPropertyList_Vtbl)).Item)(this, varindex, pvariant), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyItem(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, lnadstype: i32, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyList", "GetPropertyItem", 12usize, |this| ((**(this as *const *const IADsPropertyList_Vtbl)).GetPropertyItem)(this, bstrname, lnadstype, pvariant), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutPropertyItem(this: *mut core::ffi::c_void, vardata: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyList", "PutPropertyItem", 13usize, |this| ((**(this as *const *const IADsPropertyList_Vtbl)).PutPropertyItem)(this, vardata), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetPropertyItem(this: *mut core::ffi::c_void, varentry: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyList", "ResetPropertyItem", 14usize, |this| ((**(this as *const *const IADsPropertyList_Vtbl)).ResetPropertyItem)(this, varentry), |result| Some(*result))
+        }
+        unsafe extern "system" fn PurgePropertyList(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyList", "PurgePropertyList", 15usize, |this| ((**(this as *const *const IADsPropertyList_Vtbl)).PurgePropertyList)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PropertyCount,
+            Next,
+            Skip,
+            Reset,
+            Item,
+            GetPropertyItem,
+            PutPropertyItem,
+            ResetPropertyItem,
+            PurgePropertyList,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsPropertyList as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsPropertyValue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyValue", "Clear", 7usize, |this| ((**(this as *const *const IADsPropertyValue_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ADsType(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyValue", "ADsType", 8usize, |this| ((**(this as *const *const IADsPropertyValue_Vtbl)).ADsType)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetADsType(this: *mut core::ffi::c_void, lnadstype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyValue", "SetADsType", 9usize, |this| ((**(this as *const *const IADsPropertyValue_Vtbl)).SetADsType)(this, lnadstype), |result| Some(*result))
+        }
+        unsafe extern "system" fn DNString(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyValue", "DNString", 10usize, |this| ((**(this as *const *const IADsPropertyValue_Vtbl)).DNString)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDNString(this: *mut core::ffi::c_void, bstrdnstring: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyValue", "SetDNString", 11usize, |this| ((**(this as *const *const IADsPropertyValue_Vtbl)).SetDNString)(this, bstrdnstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn CaseExactString(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyValue", "CaseExactString", 12usize, |this| ((**(this as *const *const IADsPropertyValue_Vtbl)).CaseExactString)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCaseExactString(this: *mut core::ffi::c_void, bstrcaseexactstring: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyValue", "SetCaseExactString", 13usize, |this| ((**(this as *const *const IADsPropertyValue_Vtbl)).SetCaseExactString)(this, bstrcaseexactstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn CaseIgnoreString(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyValue", "CaseIgnoreString", 14usize, |this| ((**(this as *const *const IADsPropertyValue_Vtbl)).CaseIgnoreString)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCaseIgnoreString(this: *mut core::ffi::c_void, bstrcaseignorestring: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyValue", "SetCaseIgnoreString", 15usize, |this| ((**(this as *const *const IADsPropertyValue_Vtbl)).SetCaseIgnoreString)(this, bstrcaseignorestring), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrintableString(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyValue", "PrintableString", 16usize, |this| ((**(this as *const *const IADsPropertyValue_Vtbl)).PrintableString)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrintableString(this: *mut core::ffi::c_void, bstrprintablestring: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyValue", "SetPrintableString", 17usize, |this| ((**(this as *const *const IADsPropertyValue_Vtbl)).SetPrintableString)(this, bstrprintablestring), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumericString(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyValue", "NumericString", 18usize, |this| ((**(this as *const *const IADsPropertyValue_Vtbl)).NumericString)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNumericString(this: *mut core::ffi::c_void, bstrnumericstring: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyValue", "SetNumericString", 19usize, |this| ((**(this as *const *const IADsPropertyValue_Vtbl)).SetNumericString)(this, bstrnumericstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn Boolean(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyValue", "Boolean", 20usize, |this| ((**(this as *const *const IADsPropertyValue_Vtbl)).Boolean)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBoolean(this: *mut core::ffi::c_void, lnboolean: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyValue", "SetBoolean", 21usize, |this| ((**(this as *const *const IADsPropertyValue_Vtbl)).SetBoolean)(this, lnboolean), |result| Some(*result))
+        }
+        unsafe extern "system" fn Integer(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyValue", "Integer", 22usize, |this| ((**(this as *const *const IADsPropertyValue_Vtbl)).Integer)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInteger(this: *mut core::ffi::c_void, lninteger: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyValue", "SetInteger", 23usize, |this| ((**(this as *const *const IADsPropertyValue_Vtbl)).SetInteger)(this, lninteger), |result| Some(*result))
+        }
+        unsafe extern "system" fn OctetString(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyValue", "OctetString", 24usize, |this| ((**(this as *const *const IADsPropertyValue_Vtbl)).OctetString)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOctetString(this: *mut core::ffi::c_void, voctetstring: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyValue", "SetOctetString", 25usize, |this| ((**(this as *const *const IADsPropertyValue_Vtbl)).SetOctetString)(this, voctetstring), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SecurityDescriptor(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyValue", "SecurityDescriptor", 26usize, |this| ((**(this as *const *const IADsPropertyValue_Vtbl)).SecurityDescriptor)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSecurityDescriptor(this: *mut core::ffi::c_void, psecuritydescriptor: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyValue", "SetSecurityDescriptor", 27usize, |this| ((**(this as *const *const IADsPropertyValue_Vtbl)).SetSecurityDescriptor)(this, psecuritydescriptor), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn LargeInteger(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyValue", "LargeInteger", 28usize, |this| ((**(this as *const *const IADsPropertyValue_Vtbl)).LargeInteger)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetLargeInteger(this: *mut core::ffi::c_void, plargeinteger: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyValue", "SetLargeInteger", 29usize, |this| ((**(this as *const *const IADsPropertyValue_Vtbl)).SetLargeInteger)(this, plargeinteger), |result| Some(*result))
+        }
+        unsafe extern "system" fn UTCTime(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyValue", "UTCTime", 30usize, |this| ((**(this as *const *const IADsPropertyValue_Vtbl)).UTCTime)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUTCTime(this: *mut core::ffi::c_void, dautctime: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyValue", "SetUTCTime", 31usize, |this| ((**(this as *const *const IADsPropertyValue_Vtbl)).SetUTCTime)(this, dautctime), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Clear,
+            ADsType,
+            SetADsType,
+            DNString,
+            SetDNString,
+            CaseExactString,
+            SetCaseExactString,
+            CaseIgnoreString,
+            SetCaseIgnoreString,
+            PrintableString,
+            SetPrintableString,
+            NumericString,
+            SetNumericString,
+            Boolean,
+            SetBoolean,
+            Integer,
+            SetInteger,
+            OctetString,
+            SetOctetString,
+            #[cfg(feature = "Win32_System_Com")]
+            SecurityDescriptor,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SecurityDescriptor: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSecurityDescriptor,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSecurityDescriptor: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            LargeInteger,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            LargeInteger: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetLargeInteger,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetLargeInteger: 0,
+            UTCTime,
+            SetUTCTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsPropertyValue as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsPropertyValue2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetObjectProperty(this: *mut core::ffi::c_void, lnadstype: *mut i32, pvprop: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyValue2", "GetObjectProperty", 7usize, |this| ((**(this as *const *const IADsPropertyValue2_Vtbl)).GetObjectProperty)(this, lnadstype, pvprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutObjectProperty(this: *mut core::ffi::c_void, lnadstype: i32, vprop: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsPropertyValue2", "PutObjectProperty", 8usize, |this| ((**(this as *const *const IADsPropertyValue2_Vtbl)).PutObjectProperty)(this, lnadstype, vprop), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetObjectProperty, PutObjectProperty }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsPropertyValue2 as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsReplicaPointer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ServerName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsReplicaPointer", "ServerName", 7usize, |this| ((**(this as *const *const IADsReplicaPointer_Vtbl)).ServerName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServerName(this: *mut core::ffi::c_void, bstrservername: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsReplicaPointer", "SetServerName", 8usize, |this| ((**(this as *const *const IADsReplicaPointer_Vtbl)).SetServerName)(this, bstrservername), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReplicaType(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsReplicaPointer", "ReplicaType", 9usize, |this| ((**(this as *const *const IADsReplicaPointer_Vtbl)).ReplicaType)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReplicaType(this: *mut core::ffi::c_void, lnreplicatype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsReplicaPointer", "SetReplicaType", 10usize, |this| ((**(this as *const *const IADsReplicaPointer_Vtbl)).SetReplicaType)(this, lnreplicatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReplicaNumber(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsReplicaPointer", "ReplicaNumber", 11usize, |this| ((**(this as *const *const IADsReplicaPointer_Vtbl)).ReplicaNumber)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReplicaNumber(this: *mut core::ffi::c_void, lnreplicanumber: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsReplicaPointer", "SetReplicaNumber", 12usize, |this| ((**(this as *const *const IADsReplicaPointer_Vtbl)).SetReplicaNumber)(this, lnreplicanumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsReplicaPointer", "Count", 13usize, |this| ((**(this as *const *const IADsReplicaPointer_Vtbl)).Count)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCount(this: *mut core::ffi::c_void, lncount: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsReplicaPointer", "SetCount", 14usize, |this| ((**(this as *const *const IADsReplicaPointer_Vtbl)).SetCount)(this, lncount), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReplicaAddressHints(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsReplicaPointer", "ReplicaAddressHints", 15usize, |this| ((**(this as *const *const IADsReplicaPointer_Vtbl)).ReplicaAddressHints)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReplicaAddressHints(this: *mut core::ffi::c_void, vreplicaaddresshints: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsReplicaPointer", "SetReplicaAddressHints", 16usize, |this| ((**(this as *const *const IADsReplicaPointer_Vtbl)).SetReplicaAddressHints)(this, vreplicaaddresshints), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ServerName,
+            SetServerName,
+            ReplicaType,
+            SetReplicaType,
+            ReplicaNumber,
+            SetReplicaNumber,
+            Count,
+            SetCount,
+            ReplicaAddressHints,
+            SetReplicaAddressHints,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsReplicaPointer as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsResource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsResource", "User", 20usize, |this| ((**(this as *const *const IADsResource_Vtbl)).User)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserPath(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsResource", "UserPath", 21usize, |this| ((**(this as *const *const IADsResource_Vtbl)).UserPath)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsResource", "Path", 22usize, |this| ((**(this as *const *const IADsResource_Vtbl)).Path)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockCount(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsResource", "LockCount", 23usize, |this| ((**(this as *const *const IADsResource_Vtbl)).LockCount)(this, retval), |result| Some(*result))
+        }
+        Self { base__: <IADs_Vtbl as windows_core::imp::ProxyVtable>::PROXY, User, UserPath, Path, LockCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsResource as windows_core::Interface>::IID || <IADs_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsSecurityDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Revision(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSecurityDescriptor", "Revision", 7usize, |this| ((**(this as *const *const IADsSecurityDescriptor_Vtbl)).Revision)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRevision(this: *mut core::ffi::c_void, lnrevision: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSecurityDescriptor", "SetRevision", 8usize, |this| ((**(this as *const *const IADsSecurityDescriptor_Vtbl)).SetRevision)(this, lnrevision), |result| Some(*result))
+        }
+        unsafe extern "system" fn Control(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSecurityDescriptor", "Control", 9usize, |this| ((**(this as *const *const IADsSecurityDescriptor_Vtbl)).Control)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetControl(this: *mut core::ffi::c_void, lncontrol: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSecurityDescriptor", "SetControl", 10usize, |this| ((**(this as *const *const IADsSecurityDescriptor_Vtbl)).SetControl)(this, lncontrol), |result| Some(*result))
+        }
+        unsafe extern "system" fn Owner(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSecurityDescriptor", "Owner", 11usize, |this| ((**(this as *const *const IADsSecurityDescriptor_Vtbl)).Owner)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOwner(this: *mut core::ffi::c_void, bstrowner: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSecurityDescriptor", "SetOwner", 12usize, |this| ((**(this as *const *const IADsSecurityDescriptor_Vtbl)).SetOwner)(this, bstrowner), |result| Some(*result))
+        }
+        unsafe extern "system" fn OwnerDefaulted(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSecurityDescriptor", "OwnerDefaulted", 13usize, |this| ((**(this as *const *const IADsSecurityDescriptor_Vtbl)).OwnerDefaulted)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOwnerDefaulted(this: *mut core::ffi::c_void, fownerdefaulted: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSecurityDescriptor", "SetOwnerDefaulted", 14usize, |this| ((**(this as *const *const IADsSecurityDescriptor_Vtbl)).SetOwnerDefaulted)(this, fownerdefaulted), |result| Some(*result))
+        }
+        unsafe extern "system" fn Group(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSecurityDescriptor", "Group", 15usize, |this| ((**(this as *const *const IADsSecurityDescriptor_Vtbl)).Group)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGroup(this: *mut core::ffi::c_void, bstrgroup: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSecurityDescriptor", "SetGroup", 16usize, |this| ((**(this as *const *const IADsSecurityDescriptor_Vtbl)).SetGroup)(this, bstrgroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn GroupDefaulted(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSecurityDescriptor", "GroupDefaulted", 17usize, |this| ((**(this as *const *const IADsSecurityDescriptor_Vtbl)).GroupDefaulted)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGroupDefaulted(this: *mut core::ffi::c_void, fgroupdefaulted: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSecurityDescriptor", "SetGroupDefaulted", 18usize, |this| ((**(this as *const *const IADsSecurityDescriptor_Vtbl)).SetGroupDefaulted)(this, fgroupdefaulted), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DiscretionaryAcl(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSecurityDescriptor", "DiscretionaryAcl", 19usize, |this| ((**(this as *const *const IADsSecurityDescriptor_Vtbl)).DiscretionaryAcl)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetDiscretionaryAcl(this: *mut core::ffi::c_void, pdiscretionaryacl: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSecurityDescriptor", "SetDiscretionaryAcl", 20usize, |this| ((**(this as *const *const IADsSecurityDescriptor_Vtbl)).SetDiscretionaryAcl)(this, pdiscretionaryacl), |result| Some(*result))
+        }
+        unsafe extern "system" fn DaclDefaulted(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSecurityDescriptor", "DaclDefaulted", 21usize, |this| ((**(this as *const *const IADsSecurityDescriptor_Vtbl)).DaclDefaulted)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDaclDefaulted(this: *mut core::ffi::c_void, fdacldefaulted: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSecurityDescriptor", "SetDaclDefaulted", 22usize, |this| ((**(this as *const *const IADsSecurityDescriptor_Vtbl)).SetDaclDefaulted)(this, fdacldefaulted), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SystemAcl(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSecurityDescriptor", "SystemAcl", 23usize, |this| ((**(this as *const *const IADsSecurityDescriptor_Vtbl)).SystemAcl)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSystemAcl(this: *mut core::ffi::c_void, psystemacl: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSecurityDescriptor", "SetSystemAcl", 24usize, |this| ((**(this as *const *const IADsSecurityDescriptor_Vtbl)).SetSystemAcl)(this, psystemacl), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaclDefaulted(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSecurityDescriptor", "SaclDefaulted", 25usize, |this| ((**(this as *const *const IADsSecurityDescriptor_Vtbl)).SaclDefaulted)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSaclDefaulted(this: *mut core::ffi::c_void, fsacldefaulted: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSecurityDescriptor", "SetSaclDefaulted", 26usize, |this| ((**(this as *const *const IADsSecurityDescriptor_Vtbl)).SetSaclDefaulted)(this, fsacldefaulted), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CopySecurityDescriptor(this: *mut core::ffi::c_void, ppsecuritydescriptor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSecurityDescriptor", "CopySecurityDescriptor", 27usize, |this| ((**(this as *const *const IADsSecurityDescriptor_Vtbl)).CopySecurityDescriptor)(this, ppsecuritydescriptor), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Revision,
+            SetRevision,
+            Control,
+            SetControl,
+            Owner,
+            SetOwner,
+            OwnerDefaulted,
+            SetOwnerDefaulted,
+            Group,
+            SetGroup,
+            GroupDefaulted,
+            SetGroupDefaulted,
+            #[cfg(feature = "Win32_System_Com")]
+            DiscretionaryAcl,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DiscretionaryAcl: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetDiscretionaryAcl,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetDiscretionaryAcl: 0,
+            DaclDefaulted,
+            SetDaclDefaulted,
+            #[cfg(feature = "Win32_System_Com")]
+            SystemAcl,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SystemAcl: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSystemAcl,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSystemAcl: 0,
+            SaclDefaulted,
+            SetSaclDefaulted,
+            #[cfg(feature = "Win32_System_Com")]
+            CopySecurityDescriptor,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CopySecurityDescriptor: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsSecurityDescriptor as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsSecurityUtility_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSecurityDescriptor(this: *mut core::ffi::c_void, varpath: core::mem::MaybeUninit<windows_core::VARIANT>, lpathformat: i32, lformat: i32, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSecurityUtility", "GetSecurityDescriptor", 7usize, |this| ((**(this as *const *const IADsSecurityUtility_Vtbl)).GetSecurityDescriptor)(this, varpath, lpathformat, lformat, pvariant), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSecurityDescriptor(this: *mut core::ffi::c_void, varpath: core::mem::MaybeUninit<windows_core::VARIANT>, lpathformat: i32, vardata: core::mem::MaybeUninit<windows_core::VARIANT>, ldataformat: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSecurityUtility", "SetSecurityDescriptor", 8usize, |this| ((**(this as *const *const IADsSecurityUtility_Vtbl)).SetSecurityDescriptor)(this, varpath, lpathformat, vardata, ldataformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertSecurityDescriptor(this: *mut core::ffi::c_void, varsd: core::mem::MaybeUninit<windows_core::VARIANT>, ldataformat: i32, loutformat: i32, presult: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSecurityUtility", "ConvertSecurityDescriptor", 9usize, |this| ((**(this as *const *const IADsSecurityUtility_Vtbl)).ConvertSecurityDescriptor)(this, varsd, ldataformat, loutformat, presult), |result| Some(*result))
+        }
+        unsafe extern "system" fn SecurityMask(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSecurityUtility", "SecurityMask", 10usize, |this| ((**(this as *const *const IADsSecurityUtility_Vtbl)).SecurityMask)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSecurityMask(this: *mut core::ffi::c_void, lnsecuritymask: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSecurityUtility", "SetSecurityMask", 11usize, |this| ((**(this as *const *const IADsSecurityUtility_Vtbl)).SetSecurityMask)(this, lnsecuritymask), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSecurityDescriptor,
+            SetSecurityDescriptor,
+            ConvertSecurityDescriptor,
+            SecurityMask,
+            SetSecurityMask,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsSecurityUtility as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HostComputer(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsService", "HostComputer", 20usize, |this| ((**(this as *const *const IADsService_Vtbl)).HostComputer)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHostComputer(this: *mut core::ffi::c_void, bstrhostcomputer: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsService", "SetHostComputer", 21usize, |this| ((**(this as *const *const IADsService_Vtbl)).SetHostComputer)(this, bstrhostcomputer), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsService", "DisplayName", 22usize, |this| ((**(this as *const *const IADsService_Vtbl)).DisplayName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, bstrdisplayname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsService", "SetDisplayName", 23usize, |this| ((**(this as *const *const IADsService_Vtbl)).SetDisplayName)(this, bstrdisplayname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Version(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsService", "Version", 24usize, |this| ((**(this as *const *const IADsService_Vtbl)).Version)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVersion(this: *mut core::ffi::c_void, bstrversion: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsService", "SetVersion", 25usize, |this| ((**(this as *const *const IADsService_Vtbl)).SetVersion)(this, bstrversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceType(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsService", "ServiceType", 26usize, |this| ((**(this as *const *const IADsService_Vtbl)).ServiceType)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServiceType(this: *mut core::ffi::c_void, lnservicetype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsService", "SetServiceType", 27usize, |this| ((**(this as *const *const IADsService_Vtbl)).SetServiceType)(this, lnservicetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartType(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsService", "StartType", 28usize, |this| ((**(this as *const *const IADsService_Vtbl)).StartType)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartType(this: *mut core::ffi::c_void, lnstarttype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsService", "SetStartType", 29usize, |this| ((**(this as *const *const IADsService_Vtbl)).SetStartType)(this, lnstarttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsService", "Path", 30usize, |this| ((**(this as *const *const IADsService_Vtbl)).Path)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPath(this: *mut core::ffi::c_void, bstrpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsService", "SetPath", 31usize, |this| ((**(this as *const *const IADsService_Vtbl)).SetPath)(this, bstrpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartupParameters(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsService", "StartupParameters", 32usize, |this| ((**(this as *const *const IADsService_Vtbl)).StartupParameters)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartupParameters(this: *mut core::ffi::c_void, bstrstartupparameters: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsService", "SetStartupParameters", 33usize, |this| ((**(this as *const *const IADsService_Vtbl)).SetStartupParameters)(this, bstrstartupparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorControl(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsService", "ErrorControl", 34usize, |this| ((**(this as *const *const IADsService_Vtbl)).ErrorControl)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetErrorControl(this: *mut core::ffi::c_void, lnerrorcontrol: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsService", "SetErrorControl", 35usize, |this| ((**(this as *const *const IADsService_Vtbl)).SetErrorControl)(this, lnerrorcontrol), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadOrderGroup(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsService", "LoadOrderGroup", 36usize, |this| ((**(this as *const *const IADsService_Vtbl)).LoadOrderGroup)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLoadOrderGroup(this: *mut core::ffi::c_void, bstrloadordergroup: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsService", "SetLoadOrderGroup", 37usize, |this| ((**(this as *const *const IADsService_Vtbl)).SetLoadOrderGroup)(this, bstrloadordergroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceAccountName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsService", "ServiceAccountName", 38usize, |this| ((**(this as *const *const IADsService_Vtbl)).ServiceAccountName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServiceAccountName(this: *mut core::ffi::c_void, bstrserviceaccountname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsService", "SetServiceAccountName", 39usize, |this| ((**(this as *const *const IADsService_Vtbl)).SetServiceAccountName)(this, bstrserviceaccountname), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceAccountPath(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsService", "ServiceAccountPath", 40usize, |this| ((**(this as *const *const IADsService_Vtbl)).ServiceAccountPath)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServiceAccountPath(this: *mut core::ffi::c_void, bstrserviceaccountpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsService", "SetServiceAccountPath", 41usize, |this| ((**(this as *const *const IADsService_Vtbl)).SetServiceAccountPath)(this, bstrserviceaccountpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn Dependencies(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsService", "Dependencies", 42usize, |this| ((**(this as *const *const IADsService_Vtbl)).Dependencies)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDependencies(this: *mut core::ffi::c_void, vdependencies: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsService", "SetDependencies", 43usize, |this| ((**(this as *const *const IADsService_Vtbl)).SetDependencies)(this, vdependencies), |result| Some(*result))
+        }
+        Self {
+            base__: <IADs_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            HostComputer,
+            SetHostComputer,
+            DisplayName,
+            SetDisplayName,
+            Version,
+            SetVersion,
+            ServiceType,
+            SetServiceType,
+            StartType,
+            SetStartType,
+            Path,
+            SetPath,
+            StartupParameters,
+            SetStartupParameters,
+            ErrorControl,
+            SetErrorControl,
+            LoadOrderGroup,
+            SetLoadOrderGroup,
+            ServiceAccountName,
+            SetServiceAccountName,
+            ServiceAccountPath,
+            SetServiceAccountPath,
+            Dependencies,
+            SetDependencies,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsService as windows_core::Interface>::IID || <IADs_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsServiceOperations_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsServiceOperations", "Status", 20usize, |this| ((**(this as *const *const IADsServiceOperations_Vtbl)).Status)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsServiceOperations", "Start", 21usize, |this| ((**(this as *const *const IADsServiceOperations_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsServiceOperations", "Stop", 22usize, |this| ((**(this as *const *const IADsServiceOperations_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsServiceOperations", "Pause", 23usize, |this| ((**(this as *const *const IADsServiceOperations_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Continue(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsServiceOperations", "Continue", 24usize, |this| ((**(this as *const *const IADsServiceOperations_Vtbl)).Continue)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPassword(this: *mut core::ffi::c_void, bstrnewpassword: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsServiceOperations", "SetPassword", 25usize, |this| ((**(this as *const *const IADsServiceOperations_Vtbl)).SetPassword)(this, bstrnewpassword), |result| Some(*result))
+        }
+        Self { base__: <IADs_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, Start, Stop, Pause, Continue, SetPassword }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsServiceOperations as windows_core::Interface>::IID || <IADs_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSession", "User", 20usize, |this| ((**(this as *const *const IADsSession_Vtbl)).User)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserPath(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSession", "UserPath", 21usize, |this| ((**(this as *const *const IADsSession_Vtbl)).UserPath)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Computer(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSession", "Computer", 22usize, |this| ((**(this as *const *const IADsSession_Vtbl)).Computer)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ComputerPath(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSession", "ComputerPath", 23usize, |this| ((**(this as *const *const IADsSession_Vtbl)).ComputerPath)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectTime(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSession", "ConnectTime", 24usize, |this| ((**(this as *const *const IADsSession_Vtbl)).ConnectTime)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IdleTime(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSession", "IdleTime", 25usize, |this| ((**(this as *const *const IADsSession_Vtbl)).IdleTime)(this, retval), |result| Some(*result))
+        }
+        Self { base__: <IADs_Vtbl as windows_core::imp::ProxyVtable>::PROXY, User, UserPath, Computer, ComputerPath, ConnectTime, IdleTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsSession as windows_core::Interface>::IID || <IADs_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsSyntax_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OleAutoDataType(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSyntax", "OleAutoDataType", 20usize, |this| ((**(this as *const *const IADsSyntax_Vtbl)).OleAutoDataType)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOleAutoDataType(this: *mut core::ffi::c_void, lnoleautodatatype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsSyntax", "SetOleAutoDataType", 21usize, |this| ((**(this as *const *const IADsSyntax_Vtbl)).SetOleAutoDataType)(this, lnoleautodatatype), |result| Some(*result))
+        }
+        Self { base__: <IADs_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OleAutoDataType, SetOleAutoDataType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsSyntax as windows_core::Interface>::IID || <IADs_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsTimestamp_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WholeSeconds(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTimestamp", "WholeSeconds", 7usize, |this| ((**(this as *const *const IADsTimestamp_Vtbl)).WholeSeconds)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWholeSeconds(this: *mut core::ffi::c_void, lnwholeseconds: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTimestamp", "SetWholeSeconds", 8usize, |this| ((**(this as *const *const IADsTimestamp_Vtbl)).SetWholeSeconds)(this, lnwholeseconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventID(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTimestamp", "EventID", 9usize, |this| ((**(this as *const *const IADsTimestamp_Vtbl)).EventID)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventID(this: *mut core::ffi::c_void, lneventid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTimestamp", "SetEventID", 10usize, |this| ((**(this as *const *const IADsTimestamp_Vtbl)).SetEventID)(this, lneventid), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WholeSeconds, SetWholeSeconds, EventID, SetEventID }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsTimestamp as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsTypedName_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ObjectName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTypedName", "ObjectName", 7usize, |this| ((**(this as *const *const IADsTypedName_Vtbl)).ObjectName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetObjectName(this: *mut core::ffi::c_void, bstrobjectname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTypedName", "SetObjectName", 8usize, |this| ((**(this as *const *const IADsTypedName_Vtbl)).SetObjectName)(this, bstrobjectname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Level(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTypedName", "Level", 9usize, |this| ((**(this as *const *const IADsTypedName_Vtbl)).Level)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLevel(this: *mut core::ffi::c_void, lnlevel: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTypedName", "SetLevel", 10usize, |this| ((**(this as *const *const IADsTypedName_Vtbl)).SetLevel)(this, lnlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn Interval(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTypedName", "Interval", 11usize, |this| ((**(this as *const *const IADsTypedName_Vtbl)).Interval)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInterval(this: *mut core::ffi::c_void, lninterval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTypedName", "SetInterval", 12usize, |this| ((**(this as *const *const IADsTypedName_Vtbl)).SetInterval)(this, lninterval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ObjectName,
+            SetObjectName,
+            Level,
+            SetLevel,
+            Interval,
+            SetInterval,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsTypedName as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsUser_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BadLoginAddress(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "BadLoginAddress", 20usize, |this| ((**(this as *const *const IADsUser_Vtbl)).BadLoginAddress)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn BadLoginCount(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "BadLoginCount", 21usize, |this| ((**(this as *const *const IADsUser_Vtbl)).BadLoginCount)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastLogin(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "LastLogin", 22usize, |this| ((**(this as *const *const IADsUser_Vtbl)).LastLogin)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastLogoff(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "LastLogoff", 23usize, |this| ((**(this as *const *const IADsUser_Vtbl)).LastLogoff)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastFailedLogin(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "LastFailedLogin", 24usize, |this| ((**(this as *const *const IADsUser_Vtbl)).LastFailedLogin)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PasswordLastChanged(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "PasswordLastChanged", 25usize, |this| ((**(this as *const *const IADsUser_Vtbl)).PasswordLastChanged)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "Description", 26usize, |this| ((**(this as *const *const IADsUser_Vtbl)).Description)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetDescription", 27usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetDescription)(this, bstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn Division(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "Division", 28usize, |this| ((**(this as *const *const IADsUser_Vtbl)).Division)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDivision(this: *mut core::ffi::c_void, bstrdivision: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetDivision", 29usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetDivision)(this, bstrdivision), |result| Some(*result))
+        }
+        unsafe extern "system" fn Department(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "Department", 30usize, |this| ((**(this as *const *const IADsUser_Vtbl)).Department)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDepartment(this: *mut core::ffi::c_void, bstrdepartment: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetDepartment", 31usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetDepartment)(this, bstrdepartment), |result| Some(*result))
+        }
+        unsafe extern "system" fn EmployeeID(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "EmployeeID", 32usize, |this| ((**(this as *const *const IADsUser_Vtbl)).EmployeeID)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEmployeeID(this: *mut core::ffi::c_void, bstremployeeid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetEmployeeID", 33usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetEmployeeID)(this, bstremployeeid), |result| Some(*result))
+        }
+        unsafe extern "system" fn FullName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "FullName", 34usize, |this| ((**(this as *const *const IADsUser_Vtbl)).FullName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFullName(this: *mut core::ffi::c_void, bstrfullname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetFullName", 35usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetFullName)(this, bstrfullname), |result| Some(*result))
+        }
+        unsafe extern "system" fn FirstName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "FirstName", 36usize, |this| ((**(this as *const *const IADsUser_Vtbl)).FirstName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFirstName(this: *mut core::ffi::c_void, bstrfirstname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetFirstName", 37usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetFirstName)(this, bstrfirstname), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "LastName", 38usize, |this| ((**(this as *const *const IADsUser_Vtbl)).LastName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLastName(this: *mut core::ffi::c_void, bstrlastname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetLastName", 39usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetLastName)(this, bstrlastname), |result| Some(*result))
+        }
+        unsafe extern "system" fn OtherName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "OtherName", 40usize, |this| ((**(this as *const *const IADsUser_Vtbl)).OtherName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOtherName(this: *mut core::ffi::c_void, bstrothername: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetOtherName", 41usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetOtherName)(this, bstrothername), |result| Some(*result))
+        }
+        unsafe extern "system" fn NamePrefix(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "NamePrefix", 42usize, |this| ((**(this as *const *const IADsUser_Vtbl)).NamePrefix)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNamePrefix(this: *mut core::ffi::c_void, bstrnameprefix: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetNamePrefix", 43usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetNamePrefix)(this, bstrnameprefix), |result| Some(*result))
+        }
+        unsafe extern "system" fn NameSuffix(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "NameSuffix", 44usize, |this| ((**(this as *const *const IADsUser_Vtbl)).NameSuffix)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNameSuffix(this: *mut core::ffi::c_void, bstrnamesuffix: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetNameSuffix", 45usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetNameSuffix)(this, bstrnamesuffix), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "Title", 46usize, |this| ((**(this as *const *const IADsUser_Vtbl)).Title)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, bstrtitle: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetTitle", 47usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetTitle)(this, bstrtitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn Manager(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "Manager", 48usize, |this| ((**(this as *const *const IADsUser_Vtbl)).Manager)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetManager(this: *mut core::ffi::c_void, bstrmanager: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetManager", 49usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetManager)(this, bstrmanager), |result| Some(*result))
+        }
+        unsafe extern "system" fn TelephoneHome(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "TelephoneHome", 50usize, |this| ((**(this as *const *const IADsUser_Vtbl)).TelephoneHome)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTelephoneHome(this: *mut core::ffi::c_void, vtelephonehome: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetTelephoneHome", 51usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetTelephoneHome)(this, vtelephonehome), |result| Some(*result))
+        }
+        unsafe extern "system" fn TelephoneMobile(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "TelephoneMobile", 52usize, |this| ((**(this as *const *const IADsUser_Vtbl)).TelephoneMobile)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTelephoneMobile(this: *mut core::ffi::c_void, vtelephonemobile: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetTelephoneMobile", 53usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetTelephoneMobile)(this, vtelephonemobile), |result| Some(*result))
+        }
+        unsafe extern "system" fn TelephoneNumber(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "TelephoneNumber", 54usize, |this| ((**(this as *const *const IADsUser_Vtbl)).TelephoneNumber)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTelephoneNumber(this: *mut core::ffi::c_void, vtelephonenumber: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetTelephoneNumber", 55usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetTelephoneNumber)(this, vtelephonenumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn TelephonePager(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "TelephonePager", 56usize, |this| ((**(this as *const *const IADsUser_Vtbl)).TelephonePager)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTelephonePager(this: *mut core::ffi::c_void, vtelephonepager: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetTelephonePager", 57usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetTelephonePager)(this, vtelephonepager), |result| Some(*result))
+        }
+        unsafe extern "system" fn FaxNumber(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "FaxNumber", 58usize, |this| ((**(this as *const *const IADsUser_Vtbl)).FaxNumber)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFaxNumber(this: *mut core::ffi::c_void, vfaxnumber: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetFaxNumber", 59usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetFaxNumber)(this, vfaxnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn OfficeLocations(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "OfficeLocations", 60usize, |this| ((**(this as *const *const IADsUser_Vtbl)).OfficeLocations)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOfficeLocations(this: *mut core::ffi::c_void, vofficelocations: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetOfficeLocations", 61usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetOfficeLocations)(this, vofficelocations), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostalAddresses(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "PostalAddresses", 62usize, |this| ((**(this as *const *const IADsUser_Vtbl)).PostalAddresses)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPostalAddresses(this: *mut core::ffi::c_void, vpostaladdresses: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetPostalAddresses", 63usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetPostalAddresses)(this, vpostaladdresses), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostalCodes(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "PostalCodes", 64usize, |this| ((**(this as *const *const IADsUser_Vtbl)).PostalCodes)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPostalCodes(this: *mut core::ffi::c_void, vpostalcodes: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetPostalCodes", 65usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetPostalCodes)(this, vpostalcodes), |result| Some(*result))
+        }
+        unsafe extern "system" fn SeeAlso(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SeeAlso", 66usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SeeAlso)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSeeAlso(this: *mut core::ffi::c_void, vseealso: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetSeeAlso", 67usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetSeeAlso)(this, vseealso), |result| Some(*result))
+        }
+        unsafe extern "system" fn AccountDisabled(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "AccountDisabled", 68usize, |this| ((**(this as *const *const IADsUser_Vtbl)).AccountDisabled)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAccountDisabled(this: *mut core::ffi::c_void, faccountdisabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetAccountDisabled", 69usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetAccountDisabled)(this, faccountdisabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn AccountExpirationDate(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "AccountExpirationDate", 70usize, |this| ((**(this as *const *const IADsUser_Vtbl)).AccountExpirationDate)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAccountExpirationDate(this: *mut core::ffi::c_void, daaccountexpirationdate: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetAccountExpirationDate", 71usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetAccountExpirationDate)(this, daaccountexpirationdate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GraceLoginsAllowed(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "GraceLoginsAllowed", 72usize, |this| ((**(this as *const *const IADsUser_Vtbl)).GraceLoginsAllowed)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGraceLoginsAllowed(this: *mut core::ffi::c_void, lngraceloginsallowed: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetGraceLoginsAllowed", 73usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetGraceLoginsAllowed)(this, lngraceloginsallowed), |result| Some(*result))
+        }
+        unsafe extern "system" fn GraceLoginsRemaining(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "GraceLoginsRemaining", 74usize, |this| ((**(this as *const *const IADsUser_Vtbl)).GraceLoginsRemaining)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGraceLoginsRemaining(this: *mut core::ffi::c_void, lngraceloginsremaining: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetGraceLoginsRemaining", 75usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetGraceLoginsRemaining)(this, lngraceloginsremaining), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAccountLocked(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "IsAccountLocked", 76usize, |this| ((**(this as *const *const IADsUser_Vtbl)).IsAccountLocked)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsAccountLocked(this: *mut core::ffi::c_void, fisaccountlocked: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetIsAccountLocked", 77usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetIsAccountLocked)(this, fisaccountlocked), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoginHours(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "LoginHours", 78usize, |this| ((**(this as *const *const IADsUser_Vtbl)).LoginHours)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLoginHours(this: *mut core::ffi::c_void, vloginhours: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetLoginHours", 79usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetLoginHours)(this, vloginhours), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoginWorkstations(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "LoginWorkstations", 80usize, |this| ((**(this as *const *const IADsUser_Vtbl)).LoginWorkstations)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLoginWorkstations(this: *mut core::ffi::c_void, vloginworkstations: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetLoginWorkstations", 81usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetLoginWorkstations)(this, vloginworkstations), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxLogins(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "MaxLogins", 82usize, |this| ((**(this as *const *const IADsUser_Vtbl)).MaxLogins)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxLogins(this: *mut core::ffi::c_void, lnmaxlogins: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetMaxLogins", 83usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetMaxLogins)(this, lnmaxlogins), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxStorage(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "MaxStorage", 84usize, |this| ((**(this as *const *const IADsUser_Vtbl)).MaxStorage)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxStorage(this: *mut core::ffi::c_void, lnmaxstorage: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetMaxStorage", 85usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetMaxStorage)(this, lnmaxstorage), |result| Some(*result))
+        }
+        unsafe extern "system" fn PasswordExpirationDate(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "PasswordExpirationDate", 86usize, |this| ((**(this as *const *const IADsUser_Vtbl)).PasswordExpirationDate)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPasswordExpirationDate(this: *mut core::ffi::c_void, dapasswordexpirationdate: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetPasswordExpirationDate", 87usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetPasswordExpirationDate)(this, dapasswordexpirationdate), |result| Some(*result))
+        }
+        unsafe extern "system" fn PasswordMinimumLength(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "PasswordMinimumLength", 88usize, |this| ((**(this as *const *const IADsUser_Vtbl)).PasswordMinimumLength)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPasswordMinimumLength(this: *mut core::ffi::c_void, lnpasswordminimumlength: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetPasswordMinimumLength", 89usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetPasswordMinimumLength)(this, lnpasswordminimumlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn PasswordRequired(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "PasswordRequired", 90usize, |this| ((**(this as *const *const IADsUser_Vtbl)).PasswordRequired)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPasswordRequired(this: *mut core::ffi::c_void, fpasswordrequired: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetPasswordRequired", 91usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetPasswordRequired)(this, fpasswordrequired), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequireUniquePassword(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "RequireUniquePassword", 92usize, |this| ((**(this as *const *const IADsUser_Vtbl)).RequireUniquePassword)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequireUniquePassword(this: *mut core::ffi::c_void, frequireuniquepassword: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetRequireUniquePassword", 93usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetRequireUniquePassword)(this, frequireuniquepassword), |result| Some(*result))
+        }
+        unsafe extern "system" fn EmailAddress(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "EmailAddress", 94usize, |this| ((**(this as *const *const IADsUser_Vtbl)).EmailAddress)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEmailAddress(this: *mut core::ffi::c_void, bstremailaddress: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetEmailAddress", 95usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetEmailAddress)(this, bstremailaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn HomeDirectory(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "HomeDirectory", 96usize, |this| ((**(this as *const *const IADsUser_Vtbl)).HomeDirectory)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHomeDirectory(this: *mut core::ffi::c_void, bstrhomedirectory: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetHomeDirectory", 97usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetHomeDirectory)(this, bstrhomedirectory), |result| Some(*result))
+        }
+        unsafe extern "system" fn Languages(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "Languages", 98usize, |this| ((**(this as *const *const IADsUser_Vtbl)).Languages)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLanguages(this: *mut core::ffi::c_void, vlanguages: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetLanguages", 99usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetLanguages)(this, vlanguages), |result| Some(*result))
+        }
+        unsafe extern "system" fn Profile(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "Profile", 100usize, |this| ((**(this as *const *const IADsUser_Vtbl)).Profile)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProfile(this: *mut core::ffi::c_void, bstrprofile: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetProfile", 101usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetProfile)(this, bstrprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoginScript(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "LoginScript", 102usize, |this| ((**(this as *const *const IADsUser_Vtbl)).LoginScript)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLoginScript(this: *mut core::ffi::c_void, bstrloginscript: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetLoginScript", 103usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetLoginScript)(this, bstrloginscript), |result| Some(*result))
+        }
+        unsafe extern "system" fn Picture(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "Picture", 104usize, |this| ((**(this as *const *const IADsUser_Vtbl)).Picture)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPicture(this: *mut core::ffi::c_void, vpicture: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetPicture", 105usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetPicture)(this, vpicture), |result| Some(*result))
+        }
+        unsafe extern "system" fn HomePage(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "HomePage", 106usize, |this| ((**(this as *const *const IADsUser_Vtbl)).HomePage)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHomePage(this: *mut core::ffi::c_void, bstrhomepage: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetHomePage", 107usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetHomePage)(this, bstrhomepage), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Groups(this: *mut core::ffi::c_void, ppgroups: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "Groups", 108usize, |this| ((**(this as *const *const IADsUser_Vtbl)).Groups)(this, ppgroups), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPassword(this: *mut core::ffi::c_void, newpassword: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "SetPassword", 109usize, |this| ((**(this as *const *const IADsUser_Vtbl)).SetPassword)(this, newpassword), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangePassword(this: *mut core::ffi::c_void, bstroldpassword: core::mem::MaybeUninit<windows_core::BSTR>, bstrnewpassword: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsUser", "ChangePassword", 110usize, |this| ((**(this as *const *const IADsUser_Vtbl)).ChangePassword)(this, bstroldpassword, bstrnewpassword), |result| Some(*result))
+        }
+        Self {
+            base__: <IADs_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BadLoginAddress,
+            BadLoginCount,
+            LastLogin,
+            LastLogoff,
+            LastFailedLogin,
+            PasswordLastChanged,
+            Description,
+            SetDescription,
+            Division,
+            SetDivision,
+            Department,
+            SetDepartment,
+            EmployeeID,
+            SetEmployeeID,
+            FullName,
+            SetFullName,
+            FirstName,
+            SetFirstName,
+            LastName,
+            SetLastName,
+            OtherName,
+            SetOtherName,
+            NamePrefix,
+            SetNamePrefix,
+            NameSuffix,
+            SetNameSuffix,
+            Title,
+            SetTitle,
+            Manager,
+            SetManager,
+            TelephoneHome,
+            SetTelephoneHome,
+            TelephoneMobile,
+            SetTelephoneMobile,
+            TelephoneNumber,
+            SetTelephoneNumber,
+            TelephonePager,
+            SetTelephonePager,
+            FaxNumber,
+            SetFaxNumber,
+            OfficeLocations,
+            SetOfficeLocations,
+            PostalAddresses,
+            SetPostalAddresses,
+            PostalCodes,
+            SetPostalCodes,
+            SeeAlso,
+            SetSeeAlso,
+            AccountDisabled,
+            SetAccountDisabled,
+            AccountExpirationDate,
+            SetAccountExpirationDate,
+            GraceLoginsAllowed,
+            SetGraceLoginsAllowed,
+            GraceLoginsRemaining,
+            SetGraceLoginsRemaining,
+            IsAccountLocked,
+            SetIsAccountLocked,
+            LoginHours,
+            SetLoginHours,
+            LoginWorkstations,
+            SetLoginWorkstations,
+            MaxLogins,
+            SetMaxLogins,
+            MaxStorage,
+            SetMaxStorage,
+            PasswordExpirationDate,
+            SetPasswordExpirationDate,
+            PasswordMinimumLength,
+            SetPasswordMinimumLength,
+            PasswordRequired,
+            SetPasswordRequired,
+            RequireUniquePassword,
+            SetRequireUniquePassword,
+            EmailAddress,
+            SetEmailAddress,
+            HomeDirectory,
+            SetHomeDirectory,
+            Languages,
+            SetLanguages,
+            Profile,
+            SetProfile,
+            LoginScript,
+            SetLoginScript,
+            Picture,
+            SetPicture,
+            HomePage,
+            SetHomePage,
+            #[cfg(feature = "Win32_System_Com")]
+            Groups,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Groups: 0,
+            SetPassword,
+            ChangePassword,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsUser as windows_core::Interface>::IID || <IADs_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsWinNTSystemInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UserName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsWinNTSystemInfo", "UserName", 7usize, |this| ((**(this as *const *const IADsWinNTSystemInfo_Vtbl)).UserName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ComputerName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsWinNTSystemInfo", "ComputerName", 8usize, |this| ((**(this as *const *const IADsWinNTSystemInfo_Vtbl)).ComputerName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DomainName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsWinNTSystemInfo", "DomainName", 9usize, |this| ((**(this as *const *const IADsWinNTSystemInfo_Vtbl)).DomainName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PDC(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsWinNTSystemInfo", "PDC", 10usize, |this| ((**(this as *const *const IADsWinNTSystemInfo_Vtbl)).PDC)(this, retval), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UserName, ComputerName, DomainName, PDC }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsWinNTSystemInfo as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for ICommonQuery_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn OpenQueryWindow(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, pquerywnd: *mut OPENQUERYWINDOW, ppdataobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommonQuery", "OpenQueryWindow", 3usize, |this| ((**(this as *const *const ICommonQuery_Vtbl)).OpenQueryWindow)(this, hwndparent, pquerywnd, ppdataobject), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            OpenQueryWindow,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            OpenQueryWindow: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICommonQuery as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDirectoryObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetObjectInformation(this: *mut core::ffi::c_void, ppobjinfo: *mut *mut ADS_OBJECT_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectoryObject", "GetObjectInformation", 3usize, |this| ((**(this as *const *const IDirectoryObject_Vtbl)).GetObjectInformation)(this, ppobjinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObjectAttributes(this: *mut core::ffi::c_void, pattributenames: *const windows_core::PCWSTR, dwnumberattributes: u32, ppattributeentries: *mut *mut ADS_ATTR_INFO, pdwnumattributesreturned: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectoryObject", "GetObjectAttributes", 4usize, |this| ((**(this as *const *const IDirectoryObject_Vtbl)).GetObjectAttributes)(this, pattributenames, dwnumberattributes, ppattributeentries, pdwnumattributesreturned), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetObjectAttributes(this: *mut core::ffi::c_void, pattributeentries: *const ADS_ATTR_INFO, dwnumattributes: u32, pdwnumattributesmodified: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectoryObject", "SetObjectAttributes", 5usize, |this| ((**(this as *const *const IDirectoryObject_Vtbl)).SetObjectAttributes)(this, pattributeentries, dwnumattributes, pdwnumattributesmodified), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateDSObject(this: *mut core::ffi::c_void, pszrdnname: windows_core::PCWSTR, pattributeentries: *const ADS_ATTR_INFO, dwnumattributes: u32, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectoryObject", "CreateDSObject", 6usize, |this| ((**(this as *const *const IDirectoryObject_Vtbl)).CreateDSObject)(this, pszrdnname, pattributeentries, dwnumattributes, ppobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteDSObject(this: *mut core::ffi::c_void, pszrdnname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectoryObject", "DeleteDSObject", 7usize, |this| ((**(this as *const *const IDirectoryObject_Vtbl)).DeleteDSObject)(this, pszrdnname), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetObjectInformation,
+            GetObjectAttributes,
+            SetObjectAttributes,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateDSObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateDSObject: 0,
+            DeleteDSObject,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectoryObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectorySchemaMgmt_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumAttributes(this: *mut core::ffi::c_void, ppszattrnames: *const windows_core::PCWSTR, dwnumattributes: u32, ppattrdefinition: *const *const ADS_ATTR_DEF, pdwnumattributes: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectorySchemaMgmt", "EnumAttributes", 3usize, |this| ((**(this as *const *const IDirectorySchemaMgmt_Vtbl)).EnumAttributes)(this, ppszattrnames, dwnumattributes, ppattrdefinition, pdwnumattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAttributeDefinition(this: *mut core::ffi::c_void, pszattributename: windows_core::PCWSTR, pattributedefinition: *const ADS_ATTR_DEF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectorySchemaMgmt", "CreateAttributeDefinition", 4usize, |this| ((**(this as *const *const IDirectorySchemaMgmt_Vtbl)).CreateAttributeDefinition)(this, pszattributename, pattributedefinition), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteAttributeDefinition(this: *mut core::ffi::c_void, pszattributename: windows_core::PCWSTR, pattributedefinition: *const ADS_ATTR_DEF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectorySchemaMgmt", "WriteAttributeDefinition", 5usize, |this| ((**(this as *const *const IDirectorySchemaMgmt_Vtbl)).WriteAttributeDefinition)(this, pszattributename, pattributedefinition), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAttributeDefinition(this: *mut core::ffi::c_void, pszattributename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectorySchemaMgmt", "DeleteAttributeDefinition", 6usize, |this| ((**(this as *const *const IDirectorySchemaMgmt_Vtbl)).DeleteAttributeDefinition)(this, pszattributename), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumClasses(this: *mut core::ffi::c_void, ppszclassnames: *const windows_core::PCWSTR, dwnumclasses: u32, ppclassdefinition: *const *const ADS_CLASS_DEF, pdwnumclasses: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectorySchemaMgmt", "EnumClasses", 7usize, |this| ((**(this as *const *const IDirectorySchemaMgmt_Vtbl)).EnumClasses)(this, ppszclassnames, dwnumclasses, ppclassdefinition, pdwnumclasses), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteClassDefinition(this: *mut core::ffi::c_void, pszclassname: windows_core::PCWSTR, pclassdefinition: *const ADS_CLASS_DEF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectorySchemaMgmt", "WriteClassDefinition", 8usize, |this| ((**(this as *const *const IDirectorySchemaMgmt_Vtbl)).WriteClassDefinition)(this, pszclassname, pclassdefinition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateClassDefinition(this: *mut core::ffi::c_void, pszclassname: windows_core::PCWSTR, pclassdefinition: *const ADS_CLASS_DEF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectorySchemaMgmt", "CreateClassDefinition", 9usize, |this| ((**(this as *const *const IDirectorySchemaMgmt_Vtbl)).CreateClassDefinition)(this, pszclassname, pclassdefinition), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteClassDefinition(this: *mut core::ffi::c_void, pszclassname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectorySchemaMgmt", "DeleteClassDefinition", 10usize, |this| ((**(this as *const *const IDirectorySchemaMgmt_Vtbl)).DeleteClassDefinition)(this, pszclassname), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EnumAttributes,
+            CreateAttributeDefinition,
+            WriteAttributeDefinition,
+            DeleteAttributeDefinition,
+            EnumClasses,
+            WriteClassDefinition,
+            CreateClassDefinition,
+            DeleteClassDefinition,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectorySchemaMgmt as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDirectorySearch_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSearchPreference(this: *mut core::ffi::c_void, psearchprefs: *const ADS_SEARCHPREF_INFO, dwnumprefs: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectorySearch", "SetSearchPreference", 3usize, |this| ((**(this as *const *const IDirectorySearch_Vtbl)).SetSearchPreference)(this, psearchprefs, dwnumprefs), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExecuteSearch(this: *mut core::ffi::c_void, pszsearchfilter: windows_core::PCWSTR, pattributenames: *const windows_core::PCWSTR, dwnumberattributes: u32, phsearchresult: *mut ADS_SEARCH_HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectorySearch", "ExecuteSearch", 4usize, |this| ((**(this as *const *const IDirectorySearch_Vtbl)).ExecuteSearch)(this, pszsearchfilter, pattributenames, dwnumberattributes, phsearchresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn AbandonSearch(this: *mut core::ffi::c_void, phsearchresult: ADS_SEARCH_HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectorySearch", "AbandonSearch", 5usize, |this| ((**(this as *const *const IDirectorySearch_Vtbl)).AbandonSearch)(this, phsearchresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFirstRow(this: *mut core::ffi::c_void, hsearchresult: ADS_SEARCH_HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectorySearch", "GetFirstRow", 6usize, |this| ((**(this as *const *const IDirectorySearch_Vtbl)).GetFirstRow)(this, hsearchresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextRow(this: *mut core::ffi::c_void, hsearchresult: ADS_SEARCH_HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectorySearch", "GetNextRow", 7usize, |this| ((**(this as *const *const IDirectorySearch_Vtbl)).GetNextRow)(this, hsearchresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreviousRow(this: *mut core::ffi::c_void, hsearchresult: ADS_SEARCH_HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectorySearch", "GetPreviousRow", 8usize, |this| ((**(this as *const *const IDirectorySearch_Vtbl)).GetPreviousRow)(this, hsearchresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextColumnName(this: *mut core::ffi::c_void, hsearchhandle: ADS_SEARCH_HANDLE, ppszcolumnname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectorySearch", "GetNextColumnName", 9usize, |this| ((**(this as *const *const IDirectorySearch_Vtbl)).GetNextColumnName)(this, hsearchhandle, ppszcolumnname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColumn(this: *mut core::ffi::c_void, hsearchresult: ADS_SEARCH_HANDLE, szcolumnname: windows_core::PCWSTR, psearchcolumn: *mut ADS_SEARCH_COLUMN) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectorySearch", "GetColumn", 10usize, |this| ((**(this as *const *const IDirectorySearch_Vtbl)).GetColumn)(this, hsearchresult, szcolumnname, psearchcolumn), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreeColumn(this: *mut core::ffi::c_void, psearchcolumn: *const ADS_SEARCH_COLUMN) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectorySearch", "FreeColumn", 11usize, |this| ((**(this as *const *const IDirectorySearch_Vtbl)).FreeColumn)(this, psearchcolumn), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseSearchHandle(this: *mut core::ffi::c_void, hsearchresult: ADS_SEARCH_HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectorySearch", "CloseSearchHandle", 12usize, |this| ((**(this as *const *const IDirectorySearch_Vtbl)).CloseSearchHandle)(this, hsearchresult), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetSearchPreference,
+            ExecuteSearch,
+            AbandonSearch,
+            GetFirstRow,
+            GetNextRow,
+            GetPreviousRow,
+            GetNextColumnName,
+            GetColumn,
+            FreeColumn,
+            CloseSearchHandle,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectorySearch as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDsAdminCreateObj_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, padscontainerobj: *mut core::ffi::c_void, padscopysource: *mut core::ffi::c_void, lpszclassname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsAdminCreateObj", "Initialize", 3usize, |this| ((**(this as *const *const IDsAdminCreateObj_Vtbl)).Initialize)(this, padscontainerobj, padscopysource, lpszclassname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateModal(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, ppadsobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsAdminCreateObj", "CreateModal", 4usize, |this| ((**(this as *const *const IDsAdminCreateObj_Vtbl)).CreateModal)(this, hwndparent, ppadsobj), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Initialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Initialize: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateModal,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateModal: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDsAdminCreateObj as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDsAdminNewObj_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetButtons(this: *mut core::ffi::c_void, ncurrindex: u32, bvalid: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsAdminNewObj", "SetButtons", 3usize, |this| ((**(this as *const *const IDsAdminNewObj_Vtbl)).SetButtons)(this, ncurrindex, bvalid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPageCounts(this: *mut core::ffi::c_void, pntotal: *mut i32, pnstartindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsAdminNewObj", "GetPageCounts", 4usize, |this| ((**(this as *const *const IDsAdminNewObj_Vtbl)).GetPageCounts)(this, pntotal, pnstartindex), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetButtons, GetPageCounts }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDsAdminNewObj as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Controls", feature = "Win32_UI_WindowsAndMessaging"))]
+impl windows_core::imp::ProxyVtable for IDsAdminNewObjExt_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_WindowsAndMessaging"))]
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, padscontainerobj: *mut core::ffi::c_void, padscopysource: *mut core::ffi::c_void, lpszclassname: windows_core::PCWSTR, pdsadminnewobj: *mut core::ffi::c_void, pdispinfo: *mut DSA_NEWOBJ_DISPINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsAdminNewObjExt", "Initialize", 3usize, |this| ((**(this as *const *const IDsAdminNewObjExt_Vtbl)).Initialize)(this, padscontainerobj, padscopysource, lpszclassname, pdsadminnewobj, pdispinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Controls")]
+        unsafe extern "system" fn AddPages(this: *mut core::ffi::c_void, lpfnaddpage: super::super::UI::Controls::LPFNSVADDPROPSHEETPAGE, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsAdminNewObjExt", "AddPages", 4usize, |this| ((**(this as *const *const IDsAdminNewObjExt_Vtbl)).AddPages)(this, lpfnaddpage, lparam), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetObject(this: *mut core::ffi::c_void, padsobj: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsAdminNewObjExt", "SetObject", 5usize, |this| ((**(this as *const *const IDsAdminNewObjExt_Vtbl)).SetObject)(this, padsobj), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteData(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, ucontext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsAdminNewObjExt", "WriteData", 6usize, |this| ((**(this as *const *const IDsAdminNewObjExt_Vtbl)).WriteData)(this, hwnd, ucontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnError(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, hr: windows_core::HRESULT, ucontext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsAdminNewObjExt", "OnError", 7usize, |this| ((**(this as *const *const IDsAdminNewObjExt_Vtbl)).OnError)(this, hwnd, hr, ucontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSummaryInfo(this: *mut core::ffi::c_void, pbstrtext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsAdminNewObjExt", "GetSummaryInfo", 8usize, |this| ((**(this as *const *const IDsAdminNewObjExt_Vtbl)).GetSummaryInfo)(this, pbstrtext), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_WindowsAndMessaging"))]
+            Initialize,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_UI_WindowsAndMessaging")))]
+            Initialize: 0,
+            #[cfg(feature = "Win32_UI_Controls")]
+            AddPages,
+            #[cfg(not(feature = "Win32_UI_Controls"))]
+            AddPages: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetObject: 0,
+            WriteData,
+            OnError,
+            GetSummaryInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDsAdminNewObjExt as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDsAdminNewObjPrimarySite_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateNew(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsAdminNewObjPrimarySite", "CreateNew", 3usize, |this| ((**(this as *const *const IDsAdminNewObjPrimarySite_Vtbl)).CreateNew)(this, pszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsAdminNewObjPrimarySite", "Commit", 4usize, |this| ((**(this as *const *const IDsAdminNewObjPrimarySite_Vtbl)).Commit)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateNew, Commit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDsAdminNewObjPrimarySite as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDsAdminNotifyHandler_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pextrainfo: *mut core::ffi::c_void, pueventflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsAdminNotifyHandler", "Initialize", 3usize, |this| ((**(this as *const *const IDsAdminNotifyHandler_Vtbl)).Initialize)(this, pextrainfo, pueventflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Begin(this: *mut core::ffi::c_void, uevent: u32, parg1: *mut core::ffi::c_void, parg2: *mut core::ffi::c_void, puflags: *mut u32, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsAdminNotifyHandler", "Begin", 4usize, |this| ((**(this as *const *const IDsAdminNotifyHandler_Vtbl)).Begin)(this, uevent, parg1, parg2, puflags, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn Notify(this: *mut core::ffi::c_void, nitem: u32, uflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsAdminNotifyHandler", "Notify", 5usize, |this| ((**(this as *const *const IDsAdminNotifyHandler_Vtbl)).Notify)(this, nitem, uflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn End(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsAdminNotifyHandler", "End", 6usize, |this| ((**(this as *const *const IDsAdminNotifyHandler_Vtbl)).End)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Initialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Initialize: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Begin,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Begin: 0,
+            Notify,
+            End,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDsAdminNotifyHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDsBrowseDomainTree_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BrowseTo(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, ppsztargetpath: *mut windows_core::PWSTR, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsBrowseDomainTree", "BrowseTo", 3usize, |this| ((**(this as *const *const IDsBrowseDomainTree_Vtbl)).BrowseTo)(this, hwndparent, ppsztargetpath, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDomains(this: *mut core::ffi::c_void, ppdomaintree: *mut *mut DOMAIN_TREE, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsBrowseDomainTree", "GetDomains", 4usize, |this| ((**(this as *const *const IDsBrowseDomainTree_Vtbl)).GetDomains)(this, ppdomaintree, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreeDomains(this: *mut core::ffi::c_void, ppdomaintree: *mut *mut DOMAIN_TREE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsBrowseDomainTree", "FreeDomains", 5usize, |this| ((**(this as *const *const IDsBrowseDomainTree_Vtbl)).FreeDomains)(this, ppdomaintree), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlushCachedDomains(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsBrowseDomainTree", "FlushCachedDomains", 6usize, |this| ((**(this as *const *const IDsBrowseDomainTree_Vtbl)).FlushCachedDomains)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetComputer(this: *mut core::ffi::c_void, pszcomputername: windows_core::PCWSTR, pszusername: windows_core::PCWSTR, pszpassword: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsBrowseDomainTree", "SetComputer", 7usize, |this| ((**(this as *const *const IDsBrowseDomainTree_Vtbl)).SetComputer)(this, pszcomputername, pszusername, pszpassword), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BrowseTo,
+            GetDomains,
+            FreeDomains,
+            FlushCachedDomains,
+            SetComputer,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDsBrowseDomainTree as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl windows_core::imp::ProxyVtable for IDsDisplaySpecifier_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetServer(this: *mut core::ffi::c_void, pszserver: windows_core::PCWSTR, pszusername: windows_core::PCWSTR, pszpassword: windows_core::PCWSTR, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsDisplaySpecifier", "SetServer", 3usize, |this| ((**(this as *const *const IDsDisplaySpecifier_Vtbl)).SetServer)(this, pszserver, pszusername, pszpassword, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLanguageID(this: *mut core::ffi::c_void, langid: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsDisplaySpecifier", "SetLanguageID", 4usize, |this| ((**(this as *const *const IDsDisplaySpecifier_Vtbl)).SetLanguageID)(this, langid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplaySpecifier(this: *mut core::ffi::c_void, pszobjectclass: windows_core::PCWSTR, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsDisplaySpecifier", "GetDisplaySpecifier", 5usize, |this| ((**(this as *const *const IDsDisplaySpecifier_Vtbl)).GetDisplaySpecifier)(this, pszobjectclass, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIconLocation(this: *mut core::ffi::c_void, pszobjectclass: windows_core::PCWSTR, dwflags: u32, pszbuffer: windows_core::PWSTR, cchbuffer: i32, presid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsDisplaySpecifier", "GetIconLocation", 6usize, |this| ((**(this as *const *const IDsDisplaySpecifier_Vtbl)).GetIconLocation)(this, pszobjectclass, dwflags, pszbuffer, cchbuffer, presid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn GetIcon(this: *mut core::ffi::c_void, pszobjectclass: windows_core::PCWSTR, dwflags: u32, cxicon: i32, cyicon: i32) -> super::super::UI::WindowsAndMessaging::HICON {
+            windows_core::imp::proxy_call(this, "IDsDisplaySpecifier", "GetIcon", 7usize, |this| ((**(this as *const *const IDsDisplaySpecifier_Vtbl)).GetIcon)(this, pszobjectclass, dwflags, cxicon, cyicon), |_| None)
+        }
+        unsafe extern "system" fn GetFriendlyClassName(this: *mut core::ffi::c_void, pszobjectclass: windows_core::PCWSTR, pszbuffer: windows_core::PWSTR, cchbuffer: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsDisplaySpecifier", "GetFriendlyClassName", 8usize, |this| ((**(this as *const *const IDsDisplaySpecifier_Vtbl)).GetFriendlyClassName)(this, pszobjectclass, pszbuffer, cchbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFriendlyAttributeName(this: *mut core::ffi::c_void, pszobjectclass: windows_core::PCWSTR, pszattributename: windows_core::PCWSTR, pszbuffer: windows_core::PWSTR, cchbuffer: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsDisplaySpecifier", "GetFriendlyAttributeName", 9usize, |this| ((**(this as *const *const IDsDisplaySpecifier_Vtbl)).GetFriendlyAttributeName)(this, pszobjectclass, pszattributename, pszbuffer, cchbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsClassContainer(this: *mut core::ffi::c_void, pszobjectclass: windows_core::PCWSTR, pszadspath: windows_core::PCWSTR, dwflags: u32) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDsDisplaySpecifier", "IsClassContainer", 10usize, |this| ((**(this as *const *const IDsDisplaySpecifier_Vtbl)).IsClassContainer)(this, pszobjectclass, pszadspath, dwflags), |_| None)
+        }
+        unsafe extern "system" fn GetClassCreationInfo(this: *mut core::ffi::c_void, pszobjectclass: windows_core::PCWSTR, ppdscci: *mut *mut DSCLASSCREATIONINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsDisplaySpecifier", "GetClassCreationInfo", 11usize, |this| ((**(this as *const *const IDsDisplaySpecifier_Vtbl)).GetClassCreationInfo)(this, pszobjectclass, ppdscci), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumClassAttributes(this: *mut core::ffi::c_void, pszobjectclass: windows_core::PCWSTR, pcbenum: LPDSENUMATTRIBUTES, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsDisplaySpecifier", "EnumClassAttributes", 12usize, |this| ((**(this as *const *const IDsDisplaySpecifier_Vtbl)).EnumClassAttributes)(this, pszobjectclass, pcbenum, lparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributeADsType(this: *mut core::ffi::c_void, pszattributename: windows_core::PCWSTR) -> ADSTYPE {
+            windows_core::imp::proxy_call(this, "IDsDisplaySpecifier", "GetAttributeADsType", 13usize, |this| ((**(this as *const *const IDsDisplaySpecifier_Vtbl)).GetAttributeADsType)(this, pszattributename), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetServer,
+            SetLanguageID,
+            GetDisplaySpecifier,
+            GetIconLocation,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            GetIcon,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            GetIcon: 0,
+            GetFriendlyClassName,
+            GetFriendlyAttributeName,
+            IsClassContainer,
+            GetClassCreationInfo,
+            EnumClassAttributes,
+            GetAttributeADsType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDsDisplaySpecifier as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDsObjectPicker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pinitinfo: *mut DSOP_INIT_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsObjectPicker", "Initialize", 3usize, |this| ((**(this as *const *const IDsObjectPicker_Vtbl)).Initialize)(this, pinitinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InvokeDialog(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, ppdoselections: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsObjectPicker", "InvokeDialog", 4usize, |this| ((**(this as *const *const IDsObjectPicker_Vtbl)).InvokeDialog)(this, hwndparent, ppdoselections), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            #[cfg(feature = "Win32_System_Com")]
+            InvokeDialog,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InvokeDialog: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDsObjectPicker as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDsObjectPickerCredentials_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetCredentials(this: *mut core::ffi::c_void, szusername: windows_core::PCWSTR, szpassword: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDsObjectPickerCredentials", "SetCredentials", 5usize, |this| ((**(this as *const *const IDsObjectPickerCredentials_Vtbl)).SetCredentials)(this, szusername, szpassword), |result| Some(*result))
+        }
+        Self { base__: <IDsObjectPicker_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetCredentials }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDsObjectPickerCredentials as windows_core::Interface>::IID || <IDsObjectPicker_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPersistQuery_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WriteString(this: *mut core::ffi::c_void, psection: windows_core::PCWSTR, pvaluename: windows_core::PCWSTR, pvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistQuery", "WriteString", 4usize, |this| ((**(this as *const *const IPersistQuery_Vtbl)).WriteString)(this, psection, pvaluename, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadString(this: *mut core::ffi::c_void, psection: windows_core::PCWSTR, pvaluename: windows_core::PCWSTR, pbuffer: windows_core::PWSTR, cchbuffer: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistQuery", "ReadString", 5usize, |this| ((**(this as *const *const IPersistQuery_Vtbl)).ReadString)(this, psection, pvaluename, pbuffer, cchbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteInt(this: *mut core::ffi::c_void, psection: windows_core::PCWSTR, pvaluename: windows_core::PCWSTR, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistQuery", "WriteInt", 6usize, |this| ((**(this as *const *const IPersistQuery_Vtbl)).WriteInt)(this, psection, pvaluename, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadInt(this: *mut core::ffi::c_void, psection: windows_core::PCWSTR, pvaluename: windows_core::PCWSTR, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistQuery", "ReadInt", 7usize, |this| ((**(this as *const *const IPersistQuery_Vtbl)).ReadInt)(this, psection, pvaluename, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteStruct(this: *mut core::ffi::c_void, psection: windows_core::PCWSTR, pvaluename: windows_core::PCWSTR, pstruct: *mut core::ffi::c_void, cbstruct: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistQuery", "WriteStruct", 8usize, |this| ((**(this as *const *const IPersistQuery_Vtbl)).WriteStruct)(this, psection, pvaluename, pstruct, cbstruct), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadStruct(this: *mut core::ffi::c_void, psection: windows_core::PCWSTR, pvaluename: windows_core::PCWSTR, pstruct: *mut core::ffi::c_void, cbstruct: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistQuery", "ReadStruct", 9usize, |this| ((**(this as *const *const IPersistQuery_Vtbl)).ReadStruct)(this, psection, pvaluename, pstruct, cbstruct), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistQuery", "Clear", 10usize, |this| ((**(this as *const *const IPersistQuery_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IPersist_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            WriteString,
+            ReadString,
+            WriteInt,
+            ReadInt,
+            WriteStruct,
+            ReadStruct,
+            Clear,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPersistQuery as windows_core::Interface>::IID || <super::super::System::Com::IPersist_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrivateDispatch_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ADSIInitializeDispatchManager(this: *mut core::ffi::c_void, dwextensionid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrivateDispatch", "ADSIInitializeDispatchManager", 3usize, |this| ((**(this as *const *const IPrivateDispatch_Vtbl)).ADSIInitializeDispatchManager)(this, dwextensionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ADSIGetTypeInfoCount(this: *mut core::ffi::c_void, pctinfo: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrivateDispatch", "ADSIGetTypeInfoCount", 4usize, |this| ((**(this as *const *const IPrivateDispatch_Vtbl)).ADSIGetTypeInfoCount)(this, pctinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ADSIGetTypeInfo(this: *mut core::ffi::c_void, itinfo: u32, lcid: u32, pptinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrivateDispatch", "ADSIGetTypeInfo", 5usize, |this| ((**(this as *const *const IPrivateDispatch_Vtbl)).ADSIGetTypeInfo)(this, itinfo, lcid, pptinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn ADSIGetIDsOfNames(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, rgsznames: *const *const u16, cnames: u32, lcid: u32, rgdispid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrivateDispatch", "ADSIGetIDsOfNames", 6usize, |this| ((**(this as *const *const IPrivateDispatch_Vtbl)).ADSIGetIDsOfNames)(this, riid, rgsznames, cnames, lcid, rgdispid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ADSIInvoke(this: *mut core::ffi::c_void, dispidmember: i32, riid: *const windows_core::GUID, lcid: u32, wflags: u16, pdispparams: *const super::super::System::Com::DISPPARAMS, pvarresult: *mut core::mem::MaybeUninit<windows_core::VARIANT>, pexcepinfo: *mut super::super::System::Com::EXCEPINFO, puargerr: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrivateDispatch", "ADSIInvoke", 7usize, |this| ((**(this as *const *const IPrivateDispatch_Vtbl)).ADSIInvoke)(this, dispidmember, riid, lcid, wflags, pdispparams, pvarresult, pexcepinfo, puargerr), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ADSIInitializeDispatchManager,
+            ADSIGetTypeInfoCount,
+            #[cfg(feature = "Win32_System_Com")]
+            ADSIGetTypeInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ADSIGetTypeInfo: 0,
+            ADSIGetIDsOfNames,
+            #[cfg(feature = "Win32_System_Com")]
+            ADSIInvoke,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ADSIInvoke: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrivateDispatch as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrivateUnknown_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ADSIInitializeObject(this: *mut core::ffi::c_void, lpszusername: core::mem::MaybeUninit<windows_core::BSTR>, lpszpassword: core::mem::MaybeUninit<windows_core::BSTR>, lnreserved: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrivateUnknown", "ADSIInitializeObject", 3usize, |this| ((**(this as *const *const IPrivateUnknown_Vtbl)).ADSIInitializeObject)(this, lpszusername, lpszpassword, lnreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn ADSIReleaseObject(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrivateUnknown", "ADSIReleaseObject", 4usize, |this| ((**(this as *const *const IPrivateUnknown_Vtbl)).ADSIReleaseObject)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ADSIInitializeObject, ADSIReleaseObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrivateUnknown as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Registry", feature = "Win32_UI_WindowsAndMessaging"))]
+impl windows_core::imp::ProxyVtable for IQueryForm_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Registry")]
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, hkform: super::super::System::Registry::HKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryForm", "Initialize", 3usize, |this| ((**(this as *const *const IQueryForm_Vtbl)).Initialize)(this, hkform), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn AddForms(this: *mut core::ffi::c_void, paddformsproc: LPCQADDFORMSPROC, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryForm", "AddForms", 4usize, |this| ((**(this as *const *const IQueryForm_Vtbl)).AddForms)(this, paddformsproc, lparam), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn AddPages(this: *mut core::ffi::c_void, paddpagesproc: LPCQADDPAGESPROC, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryForm", "AddPages", 5usize, |this| ((**(this as *const *const IQueryForm_Vtbl)).AddPages)(this, paddpagesproc, lparam), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Registry")]
+            Initialize,
+            #[cfg(not(feature = "Win32_System_Registry"))]
+            Initialize: 0,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            AddForms,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            AddForms: 0,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            AddPages,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            AddPages: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IQueryForm as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
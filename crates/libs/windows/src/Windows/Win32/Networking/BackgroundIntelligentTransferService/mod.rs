@@ -1783,6 +1783,7 @@ pub struct BG_AUTH_SCHEME(pub i32);
 impl windows_core::TypeKind for BG_AUTH_SCHEME {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BG_AUTH_SCHEME {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BG_AUTH_SCHEME").field(&self.0).finish()
@@ -1794,6 +1795,7 @@ pub struct BG_AUTH_TARGET(pub i32);
 impl windows_core::TypeKind for BG_AUTH_TARGET {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BG_AUTH_TARGET {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BG_AUTH_TARGET").field(&self.0).finish()
@@ -1805,6 +1807,7 @@ pub struct BG_CERT_STORE_LOCATION(pub i32);
 impl windows_core::TypeKind for BG_CERT_STORE_LOCATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BG_CERT_STORE_LOCATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BG_CERT_STORE_LOCATION").field(&self.0).finish()
@@ -1816,6 +1819,7 @@ pub struct BG_ERROR_CONTEXT(pub i32);
 impl windows_core::TypeKind for BG_ERROR_CONTEXT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BG_ERROR_CONTEXT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BG_ERROR_CONTEXT").field(&self.0).finish()
@@ -1827,6 +1831,7 @@ pub struct BG_JOB_PRIORITY(pub i32);
 impl windows_core::TypeKind for BG_JOB_PRIORITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BG_JOB_PRIORITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BG_JOB_PRIORITY").field(&self.0).finish()
@@ -1838,6 +1843,7 @@ pub struct BG_JOB_PROXY_USAGE(pub i32);
 impl windows_core::TypeKind for BG_JOB_PROXY_USAGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BG_JOB_PROXY_USAGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BG_JOB_PROXY_USAGE").field(&self.0).finish()
@@ -1849,6 +1855,7 @@ pub struct BG_JOB_STATE(pub i32);
 impl windows_core::TypeKind for BG_JOB_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BG_JOB_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BG_JOB_STATE").field(&self.0).finish()
@@ -1860,6 +1867,7 @@ pub struct BG_JOB_TYPE(pub i32);
 impl windows_core::TypeKind for BG_JOB_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BG_JOB_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BG_JOB_TYPE").field(&self.0).finish()
@@ -1871,6 +1879,7 @@ pub struct BG_TOKEN(pub u32);
 impl windows_core::TypeKind for BG_TOKEN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BG_TOKEN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BG_TOKEN").field(&self.0).finish()
@@ -1882,6 +1891,7 @@ pub struct BITS_FILE_PROPERTY_ID(pub i32);
 impl windows_core::TypeKind for BITS_FILE_PROPERTY_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BITS_FILE_PROPERTY_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BITS_FILE_PROPERTY_ID").field(&self.0).finish()
@@ -1893,6 +1903,7 @@ pub struct BITS_JOB_PROPERTY_ID(pub i32);
 impl windows_core::TypeKind for BITS_JOB_PROPERTY_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BITS_JOB_PROPERTY_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BITS_JOB_PROPERTY_ID").field(&self.0).finish()
@@ -1904,6 +1915,7 @@ pub struct BITS_JOB_TRANSFER_POLICY(pub i32);
 impl windows_core::TypeKind for BITS_JOB_TRANSFER_POLICY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BITS_JOB_TRANSFER_POLICY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BITS_JOB_TRANSFER_POLICY").field(&self.0).finish()
@@ -1915,6 +1927,7 @@ pub struct GROUPPROP(pub i32);
 impl windows_core::TypeKind for GROUPPROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GROUPPROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GROUPPROP").field(&self.0).finish()
@@ -1949,7 +1962,8 @@ impl Default for BG_AUTH_CREDENTIALS_UNION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BG_BASIC_CREDENTIALS {
     pub UserName: windows_core::PWSTR,
     pub Password: windows_core::PWSTR,
@@ -1963,7 +1977,8 @@ impl Default for BG_BASIC_CREDENTIALS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BG_FILE_INFO {
     pub RemoteName: windows_core::PWSTR,
     pub LocalName: windows_core::PWSTR,
@@ -1977,7 +1992,8 @@ impl Default for BG_FILE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BG_FILE_PROGRESS {
     pub BytesTotal: u64,
     pub BytesTransferred: u64,
@@ -1992,7 +2008,8 @@ impl Default for BG_FILE_PROGRESS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BG_FILE_RANGE {
     pub InitialOffset: u64,
     pub Length: u64,
@@ -2006,7 +2023,8 @@ impl Default for BG_FILE_RANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BG_JOB_PROGRESS {
     pub BytesTotal: u64,
     pub BytesTransferred: u64,
@@ -2022,7 +2040,8 @@ impl Default for BG_JOB_PROGRESS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BG_JOB_REPLY_PROGRESS {
     pub BytesTotal: u64,
     pub BytesTransferred: u64,
@@ -2036,7 +2055,8 @@ impl Default for BG_JOB_REPLY_PROGRESS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BG_JOB_TIMES {
     pub CreationTime: super::super::Foundation::FILETIME,
     pub ModificationTime: super::super::Foundation::FILETIME,
@@ -2093,7 +2113,8 @@ pub const BackgroundCopyManager4_0: windows_core::GUID = windows_core::GUID::fro
 pub const BackgroundCopyManager5_0: windows_core::GUID = windows_core::GUID::from_u128(0x1ecca34c_e88a_44e3_8d6a_8921bde9e452);
 pub const BackgroundCopyQMgr: windows_core::GUID = windows_core::GUID::from_u128(0x69ad4aee_51be_439b_a92c_86ae490e8b30);
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILESETINFO {
     pub bstrRemoteFile: core::mem::ManuallyDrop<windows_core::BSTR>,
     pub bstrLocalFile: core::mem::ManuallyDrop<windows_core::BSTR>,
@@ -2114,3 +2135,5 @@ impl Default for FILESETINFO {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
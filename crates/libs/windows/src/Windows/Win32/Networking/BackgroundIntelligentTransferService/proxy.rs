@@ -0,0 +1,1079 @@
+impl windows_core::imp::ProxyVtable for AsyncIBackgroundCopyCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Begin_JobTransferred(this: *mut core::ffi::c_void, pjob: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIBackgroundCopyCallback", "Begin_JobTransferred", 3usize, |this| ((**(this as *const *const AsyncIBackgroundCopyCallback_Vtbl)).Begin_JobTransferred)(this, pjob), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_JobTransferred(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIBackgroundCopyCallback", "Finish_JobTransferred", 4usize, |this| ((**(this as *const *const AsyncIBackgroundCopyCallback_Vtbl)).Finish_JobTransferred)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Begin_JobError(this: *mut core::ffi::c_void, pjob: *mut core::ffi::c_void, perror: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIBackgroundCopyCallback", "Begin_JobError", 5usize, |this| ((**(this as *const *const AsyncIBackgroundCopyCallback_Vtbl)).Begin_JobError)(this, pjob, perror), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_JobError(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIBackgroundCopyCallback", "Finish_JobError", 6usize, |this| ((**(this as *const *const AsyncIBackgroundCopyCallback_Vtbl)).Finish_JobError)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Begin_JobModification(this: *mut core::ffi::c_void, pjob: *mut core::ffi::c_void, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIBackgroundCopyCallback", "Begin_JobModification", 7usize, |this| ((**(this as *const *const AsyncIBackgroundCopyCallback_Vtbl)).Begin_JobModification)(this, pjob, dwreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_JobModification(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIBackgroundCopyCallback", "Finish_JobModification", 8usize, |this| ((**(this as *const *const AsyncIBackgroundCopyCallback_Vtbl)).Finish_JobModification)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Begin_JobTransferred,
+            Finish_JobTransferred,
+            Begin_JobError,
+            Finish_JobError,
+            Begin_JobModification,
+            Finish_JobModification,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<AsyncIBackgroundCopyCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IBITSExtensionSetup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnableBITSUploads(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBITSExtensionSetup", "EnableBITSUploads", 7usize, |this| ((**(this as *const *const IBITSExtensionSetup_Vtbl)).EnableBITSUploads)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableBITSUploads(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBITSExtensionSetup", "DisableBITSUploads", 8usize, |this| ((**(this as *const *const IBITSExtensionSetup_Vtbl)).DisableBITSUploads)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCleanupTaskName(this: *mut core::ffi::c_void, ptaskname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBITSExtensionSetup", "GetCleanupTaskName", 9usize, |this| ((**(this as *const *const IBITSExtensionSetup_Vtbl)).GetCleanupTaskName)(this, ptaskname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCleanupTask(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBITSExtensionSetup", "GetCleanupTask", 10usize, |this| ((**(this as *const *const IBITSExtensionSetup_Vtbl)).GetCleanupTask)(this, riid, ppunk), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EnableBITSUploads,
+            DisableBITSUploads,
+            GetCleanupTaskName,
+            GetCleanupTask,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBITSExtensionSetup as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IBITSExtensionSetupFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetObject(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, ppextensionsetup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBITSExtensionSetupFactory", "GetObject", 7usize, |this| ((**(this as *const *const IBITSExtensionSetupFactory_Vtbl)).GetObject)(this, path, ppextensionsetup), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetObject: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBITSExtensionSetupFactory as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundCopyCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn JobTransferred(this: *mut core::ffi::c_void, pjob: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyCallback", "JobTransferred", 3usize, |this| ((**(this as *const *const IBackgroundCopyCallback_Vtbl)).JobTransferred)(this, pjob), |result| Some(*result))
+        }
+        unsafe extern "system" fn JobError(this: *mut core::ffi::c_void, pjob: *mut core::ffi::c_void, perror: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyCallback", "JobError", 4usize, |this| ((**(this as *const *const IBackgroundCopyCallback_Vtbl)).JobError)(this, pjob, perror), |result| Some(*result))
+        }
+        unsafe extern "system" fn JobModification(this: *mut core::ffi::c_void, pjob: *mut core::ffi::c_void, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyCallback", "JobModification", 5usize, |this| ((**(this as *const *const IBackgroundCopyCallback_Vtbl)).JobModification)(this, pjob, dwreserved), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, JobTransferred, JobError, JobModification }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundCopyCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundCopyCallback1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnStatus(this: *mut core::ffi::c_void, pgroup: *mut core::ffi::c_void, pjob: *mut core::ffi::c_void, dwfileindex: u32, dwstatus: u32, dwnumofretries: u32, dwwin32result: u32, dwtransportresult: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyCallback1", "OnStatus", 3usize, |this| ((**(this as *const *const IBackgroundCopyCallback1_Vtbl)).OnStatus)(this, pgroup, pjob, dwfileindex, dwstatus, dwnumofretries, dwwin32result, dwtransportresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnProgress(this: *mut core::ffi::c_void, progresstype: u32, pgroup: *mut core::ffi::c_void, pjob: *mut core::ffi::c_void, dwfileindex: u32, dwprogressvalue: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyCallback1", "OnProgress", 4usize, |this| ((**(this as *const *const IBackgroundCopyCallback1_Vtbl)).OnProgress)(this, progresstype, pgroup, pjob, dwfileindex, dwprogressvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnProgressEx(this: *mut core::ffi::c_void, progresstype: u32, pgroup: *mut core::ffi::c_void, pjob: *mut core::ffi::c_void, dwfileindex: u32, dwprogressvalue: u32, dwbytearraysize: u32, pbyte: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyCallback1", "OnProgressEx", 5usize, |this| ((**(this as *const *const IBackgroundCopyCallback1_Vtbl)).OnProgressEx)(this, progresstype, pgroup, pjob, dwfileindex, dwprogressvalue, dwbytearraysize, pbyte), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnStatus, OnProgress, OnProgressEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundCopyCallback1 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundCopyCallback2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FileTransferred(this: *mut core::ffi::c_void, pjob: *mut core::ffi::c_void, pfile: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyCallback2", "FileTransferred", 6usize, |this| ((**(this as *const *const IBackgroundCopyCallback2_Vtbl)).FileTransferred)(this, pjob, pfile), |result| Some(*result))
+        }
+        Self { base__: <IBackgroundCopyCallback_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FileTransferred }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundCopyCallback2 as windows_core::Interface>::IID || <IBackgroundCopyCallback_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundCopyCallback3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FileRangesTransferred(this: *mut core::ffi::c_void, job: *mut core::ffi::c_void, file: *mut core::ffi::c_void, rangecount: u32, ranges: *const BG_FILE_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyCallback3", "FileRangesTransferred", 7usize, |this| ((**(this as *const *const IBackgroundCopyCallback3_Vtbl)).FileRangesTransferred)(this, job, file, rangecount, ranges), |result| Some(*result))
+        }
+        Self { base__: <IBackgroundCopyCallback2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FileRangesTransferred }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundCopyCallback3 as windows_core::Interface>::IID || <IBackgroundCopyCallback2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundCopyError_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetError(this: *mut core::ffi::c_void, pcontext: *mut BG_ERROR_CONTEXT, pcode: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyError", "GetError", 3usize, |this| ((**(this as *const *const IBackgroundCopyError_Vtbl)).GetError)(this, pcontext, pcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFile(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyError", "GetFile", 4usize, |this| ((**(this as *const *const IBackgroundCopyError_Vtbl)).GetFile)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetErrorDescription(this: *mut core::ffi::c_void, languageid: u32, perrordescription: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyError", "GetErrorDescription", 5usize, |this| ((**(this as *const *const IBackgroundCopyError_Vtbl)).GetErrorDescription)(this, languageid, perrordescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetErrorContextDescription(this: *mut core::ffi::c_void, languageid: u32, pcontextdescription: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyError", "GetErrorContextDescription", 6usize, |this| ((**(this as *const *const IBackgroundCopyError_Vtbl)).GetErrorContextDescription)(this, languageid, pcontextdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProtocol(this: *mut core::ffi::c_void, pprotocol: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyError", "GetProtocol", 7usize, |this| ((**(this as *const *const IBackgroundCopyError_Vtbl)).GetProtocol)(this, pprotocol), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetError,
+            GetFile,
+            GetErrorDescription,
+            GetErrorContextDescription,
+            GetProtocol,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundCopyError as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundCopyFile_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRemoteName(this: *mut core::ffi::c_void, pval: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyFile", "GetRemoteName", 3usize, |this| ((**(this as *const *const IBackgroundCopyFile_Vtbl)).GetRemoteName)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocalName(this: *mut core::ffi::c_void, pval: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyFile", "GetLocalName", 4usize, |this| ((**(this as *const *const IBackgroundCopyFile_Vtbl)).GetLocalName)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProgress(this: *mut core::ffi::c_void, pval: *mut BG_FILE_PROGRESS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyFile", "GetProgress", 5usize, |this| ((**(this as *const *const IBackgroundCopyFile_Vtbl)).GetProgress)(this, pval), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRemoteName, GetLocalName, GetProgress }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundCopyFile as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundCopyFile2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFileRanges(this: *mut core::ffi::c_void, rangecount: *mut u32, ranges: *mut *mut BG_FILE_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyFile2", "GetFileRanges", 6usize, |this| ((**(this as *const *const IBackgroundCopyFile2_Vtbl)).GetFileRanges)(this, rangecount, ranges), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemoteName(this: *mut core::ffi::c_void, val: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyFile2", "SetRemoteName", 7usize, |this| ((**(this as *const *const IBackgroundCopyFile2_Vtbl)).SetRemoteName)(this, val), |result| Some(*result))
+        }
+        Self { base__: <IBackgroundCopyFile_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFileRanges, SetRemoteName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundCopyFile2 as windows_core::Interface>::IID || <IBackgroundCopyFile_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundCopyFile3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTemporaryName(this: *mut core::ffi::c_void, pfilename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyFile3", "GetTemporaryName", 8usize, |this| ((**(this as *const *const IBackgroundCopyFile3_Vtbl)).GetTemporaryName)(this, pfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValidationState(this: *mut core::ffi::c_void, state: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyFile3", "SetValidationState", 9usize, |this| ((**(this as *const *const IBackgroundCopyFile3_Vtbl)).SetValidationState)(this, state), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValidationState(this: *mut core::ffi::c_void, pstate: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyFile3", "GetValidationState", 10usize, |this| ((**(this as *const *const IBackgroundCopyFile3_Vtbl)).GetValidationState)(this, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDownloadedFromPeer(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyFile3", "IsDownloadedFromPeer", 11usize, |this| ((**(this as *const *const IBackgroundCopyFile3_Vtbl)).IsDownloadedFromPeer)(this, pval), |result| Some(*result))
+        }
+        Self {
+            base__: <IBackgroundCopyFile2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTemporaryName,
+            SetValidationState,
+            GetValidationState,
+            IsDownloadedFromPeer,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundCopyFile3 as windows_core::Interface>::IID || <IBackgroundCopyFile2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundCopyFile4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPeerDownloadStats(this: *mut core::ffi::c_void, pfromorigin: *mut u64, pfrompeers: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyFile4", "GetPeerDownloadStats", 12usize, |this| ((**(this as *const *const IBackgroundCopyFile4_Vtbl)).GetPeerDownloadStats)(this, pfromorigin, pfrompeers), |result| Some(*result))
+        }
+        Self { base__: <IBackgroundCopyFile3_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPeerDownloadStats }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundCopyFile4 as windows_core::Interface>::IID || <IBackgroundCopyFile3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundCopyFile5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, propertyid: BITS_FILE_PROPERTY_ID, propertyvalue: BITS_FILE_PROPERTY_VALUE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyFile5", "SetProperty", 13usize, |this| ((**(this as *const *const IBackgroundCopyFile5_Vtbl)).SetProperty)(this, propertyid, propertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, propertyid: BITS_FILE_PROPERTY_ID, propertyvalue: *mut BITS_FILE_PROPERTY_VALUE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyFile5", "GetProperty", 14usize, |this| ((**(this as *const *const IBackgroundCopyFile5_Vtbl)).GetProperty)(this, propertyid, propertyvalue), |result| Some(*result))
+        }
+        Self { base__: <IBackgroundCopyFile4_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetProperty, GetProperty }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundCopyFile5 as windows_core::Interface>::IID || <IBackgroundCopyFile4_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundCopyFile6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UpdateDownloadPosition(this: *mut core::ffi::c_void, offset: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyFile6", "UpdateDownloadPosition", 15usize, |this| ((**(this as *const *const IBackgroundCopyFile6_Vtbl)).UpdateDownloadPosition)(this, offset), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestFileRanges(this: *mut core::ffi::c_void, rangecount: u32, ranges: *const BG_FILE_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyFile6", "RequestFileRanges", 16usize, |this| ((**(this as *const *const IBackgroundCopyFile6_Vtbl)).RequestFileRanges)(this, rangecount, ranges), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFilledFileRanges(this: *mut core::ffi::c_void, rangecount: *mut u32, ranges: *mut *mut BG_FILE_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyFile6", "GetFilledFileRanges", 17usize, |this| ((**(this as *const *const IBackgroundCopyFile6_Vtbl)).GetFilledFileRanges)(this, rangecount, ranges), |result| Some(*result))
+        }
+        Self { base__: <IBackgroundCopyFile5_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UpdateDownloadPosition, RequestFileRanges, GetFilledFileRanges }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundCopyFile6 as windows_core::Interface>::IID || <IBackgroundCopyFile5_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundCopyGroup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProp(this: *mut core::ffi::c_void, propid: GROUPPROP, pvarval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyGroup", "GetProp", 3usize, |this| ((**(this as *const *const IBackgroundCopyGroup_Vtbl)).GetProp)(this, propid, pvarval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProp(this: *mut core::ffi::c_void, propid: GROUPPROP, pvarval: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyGroup", "SetProp", 4usize, |this| ((**(this as *const *const IBackgroundCopyGroup_Vtbl)).SetProp)(this, propid, pvarval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProgress(this: *mut core::ffi::c_void, dwflags: u32, pdwprogress: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyGroup", "GetProgress", 5usize, |this| ((**(this as *const *const IBackgroundCopyGroup_Vtbl)).GetProgress)(this, dwflags, pdwprogress), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pdwstatus: *mut u32, pdwjobindex: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyGroup", "GetStatus", 6usize, |this| ((**(this as *const *const IBackgroundCopyGroup_Vtbl)).GetStatus)(this, pdwstatus, pdwjobindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetJob(this: *mut core::ffi::c_void, jobid: windows_core::GUID, ppjob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyGroup", "GetJob", 7usize, |this| ((**(this as *const *const IBackgroundCopyGroup_Vtbl)).GetJob)(this, jobid, ppjob), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuspendGroup(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyGroup", "SuspendGroup", 8usize, |this| ((**(this as *const *const IBackgroundCopyGroup_Vtbl)).SuspendGroup)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResumeGroup(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyGroup", "ResumeGroup", 9usize, |this| ((**(this as *const *const IBackgroundCopyGroup_Vtbl)).ResumeGroup)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelGroup(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyGroup", "CancelGroup", 10usize, |this| ((**(this as *const *const IBackgroundCopyGroup_Vtbl)).CancelGroup)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, pdwsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyGroup", "Size", 11usize, |this| ((**(this as *const *const IBackgroundCopyGroup_Vtbl)).Size)(this, pdwsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GroupID(this: *mut core::ffi::c_void, pguidgroupid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyGroup", "GroupID", 12usize, |this| ((**(this as *const *const IBackgroundCopyGroup_Vtbl)).GroupID)(this, pguidgroupid), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateJob(this: *mut core::ffi::c_void, guidjobid: windows_core::GUID, ppjob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyGroup", "CreateJob", 13usize, |this| ((**(this as *const *const IBackgroundCopyGroup_Vtbl)).CreateJob)(this, guidjobid, ppjob), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumJobs(this: *mut core::ffi::c_void, dwflags: u32, ppenumjobs: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyGroup", "EnumJobs", 14usize, |this| ((**(this as *const *const IBackgroundCopyGroup_Vtbl)).EnumJobs)(this, dwflags, ppenumjobs), |result| Some(*result))
+        }
+        unsafe extern "system" fn SwitchToForeground(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyGroup", "SwitchToForeground", 15usize, |this| ((**(this as *const *const IBackgroundCopyGroup_Vtbl)).SwitchToForeground)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryNewJobInterface(this: *mut core::ffi::c_void, iid: *const windows_core::GUID, punk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyGroup", "QueryNewJobInterface", 16usize, |this| ((**(this as *const *const IBackgroundCopyGroup_Vtbl)).QueryNewJobInterface)(this, iid, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNotificationPointer(this: *mut core::ffi::c_void, iid: *const windows_core::GUID, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyGroup", "SetNotificationPointer", 17usize, |this| ((**(this as *const *const IBackgroundCopyGroup_Vtbl)).SetNotificationPointer)(this, iid, punk), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetProp,
+            SetProp,
+            GetProgress,
+            GetStatus,
+            GetJob,
+            SuspendGroup,
+            ResumeGroup,
+            CancelGroup,
+            Size,
+            GroupID,
+            CreateJob,
+            EnumJobs,
+            SwitchToForeground,
+            QueryNewJobInterface,
+            SetNotificationPointer,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundCopyGroup as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundCopyJob_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddFileSet(this: *mut core::ffi::c_void, cfilecount: u32, pfileset: *const BG_FILE_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "AddFileSet", 3usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).AddFileSet)(this, cfilecount, pfileset), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddFile(this: *mut core::ffi::c_void, remoteurl: windows_core::PCWSTR, localname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "AddFile", 4usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).AddFile)(this, remoteurl, localname), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumFiles(this: *mut core::ffi::c_void, penum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "EnumFiles", 5usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).EnumFiles)(this, penum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Suspend(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "Suspend", 6usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).Suspend)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "Resume", 7usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "Cancel", 8usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "Complete", 9usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetId(this: *mut core::ffi::c_void, pval: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "GetId", 10usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).GetId)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, pval: *mut BG_JOB_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "GetType", 11usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).GetType)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProgress(this: *mut core::ffi::c_void, pval: *mut BG_JOB_PROGRESS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "GetProgress", 12usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).GetProgress)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTimes(this: *mut core::ffi::c_void, pval: *mut BG_JOB_TIMES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "GetTimes", 13usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).GetTimes)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetState(this: *mut core::ffi::c_void, pval: *mut BG_JOB_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "GetState", 14usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).GetState)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetError(this: *mut core::ffi::c_void, pperror: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "GetError", 15usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).GetError)(this, pperror), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOwner(this: *mut core::ffi::c_void, pval: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "GetOwner", 16usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).GetOwner)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, val: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "SetDisplayName", 17usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).SetDisplayName)(this, val), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayName(this: *mut core::ffi::c_void, pval: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "GetDisplayName", 18usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).GetDisplayName)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, val: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "SetDescription", 19usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).SetDescription)(this, val), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescription(this: *mut core::ffi::c_void, pval: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "GetDescription", 20usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).GetDescription)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPriority(this: *mut core::ffi::c_void, val: BG_JOB_PRIORITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "SetPriority", 21usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).SetPriority)(this, val), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPriority(this: *mut core::ffi::c_void, pval: *mut BG_JOB_PRIORITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "GetPriority", 22usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).GetPriority)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNotifyFlags(this: *mut core::ffi::c_void, val: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "SetNotifyFlags", 23usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).SetNotifyFlags)(this, val), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNotifyFlags(this: *mut core::ffi::c_void, pval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "GetNotifyFlags", 24usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).GetNotifyFlags)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNotifyInterface(this: *mut core::ffi::c_void, val: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "SetNotifyInterface", 25usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).SetNotifyInterface)(this, val), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNotifyInterface(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "GetNotifyInterface", 26usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).GetNotifyInterface)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinimumRetryDelay(this: *mut core::ffi::c_void, seconds: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "SetMinimumRetryDelay", 27usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).SetMinimumRetryDelay)(this, seconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMinimumRetryDelay(this: *mut core::ffi::c_void, seconds: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "GetMinimumRetryDelay", 28usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).GetMinimumRetryDelay)(this, seconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNoProgressTimeout(this: *mut core::ffi::c_void, seconds: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "SetNoProgressTimeout", 29usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).SetNoProgressTimeout)(this, seconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNoProgressTimeout(this: *mut core::ffi::c_void, seconds: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "GetNoProgressTimeout", 30usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).GetNoProgressTimeout)(this, seconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetErrorCount(this: *mut core::ffi::c_void, errors: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "GetErrorCount", 31usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).GetErrorCount)(this, errors), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProxySettings(this: *mut core::ffi::c_void, proxyusage: BG_JOB_PROXY_USAGE, proxylist: windows_core::PCWSTR, proxybypasslist: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "SetProxySettings", 32usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).SetProxySettings)(this, proxyusage, proxylist, proxybypasslist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProxySettings(this: *mut core::ffi::c_void, pproxyusage: *mut BG_JOB_PROXY_USAGE, pproxylist: *mut windows_core::PWSTR, pproxybypasslist: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "GetProxySettings", 33usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).GetProxySettings)(this, pproxyusage, pproxylist, pproxybypasslist), |result| Some(*result))
+        }
+        unsafe extern "system" fn TakeOwnership(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob", "TakeOwnership", 34usize, |this| ((**(this as *const *const IBackgroundCopyJob_Vtbl)).TakeOwnership)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddFileSet,
+            AddFile,
+            EnumFiles,
+            Suspend,
+            Resume,
+            Cancel,
+            Complete,
+            GetId,
+            GetType,
+            GetProgress,
+            GetTimes,
+            GetState,
+            GetError,
+            GetOwner,
+            SetDisplayName,
+            GetDisplayName,
+            SetDescription,
+            GetDescription,
+            SetPriority,
+            GetPriority,
+            SetNotifyFlags,
+            GetNotifyFlags,
+            SetNotifyInterface,
+            GetNotifyInterface,
+            SetMinimumRetryDelay,
+            GetMinimumRetryDelay,
+            SetNoProgressTimeout,
+            GetNoProgressTimeout,
+            GetErrorCount,
+            SetProxySettings,
+            GetProxySettings,
+            TakeOwnership,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundCopyJob as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundCopyJob1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CancelJob(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob1", "CancelJob", 3usize, |this| ((**(this as *const *const IBackgroundCopyJob1_Vtbl)).CancelJob)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProgress(this: *mut core::ffi::c_void, dwflags: u32, pdwprogress: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob1", "GetProgress", 4usize, |this| ((**(this as *const *const IBackgroundCopyJob1_Vtbl)).GetProgress)(this, dwflags, pdwprogress), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pdwstatus: *mut u32, pdwwin32result: *mut u32, pdwtransportresult: *mut u32, pdwnumofretries: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob1", "GetStatus", 5usize, |this| ((**(this as *const *const IBackgroundCopyJob1_Vtbl)).GetStatus)(this, pdwstatus, pdwwin32result, pdwtransportresult, pdwnumofretries), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddFiles(this: *mut core::ffi::c_void, cfilecount: u32, ppfileset: *const *const FILESETINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob1", "AddFiles", 6usize, |this| ((**(this as *const *const IBackgroundCopyJob1_Vtbl)).AddFiles)(this, cfilecount, ppfileset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFile(this: *mut core::ffi::c_void, cfileindex: u32, pfileinfo: *mut FILESETINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob1", "GetFile", 7usize, |this| ((**(this as *const *const IBackgroundCopyJob1_Vtbl)).GetFile)(this, cfileindex, pfileinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileCount(this: *mut core::ffi::c_void, pdwfilecount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob1", "GetFileCount", 8usize, |this| ((**(this as *const *const IBackgroundCopyJob1_Vtbl)).GetFileCount)(this, pdwfilecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SwitchToForeground(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob1", "SwitchToForeground", 9usize, |this| ((**(this as *const *const IBackgroundCopyJob1_Vtbl)).SwitchToForeground)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn JobID(this: *mut core::ffi::c_void, pguidjobid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob1", "JobID", 10usize, |this| ((**(this as *const *const IBackgroundCopyJob1_Vtbl)).JobID)(this, pguidjobid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CancelJob,
+            GetProgress,
+            GetStatus,
+            AddFiles,
+            GetFile,
+            GetFileCount,
+            SwitchToForeground,
+            JobID,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundCopyJob1 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundCopyJob2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetNotifyCmdLine(this: *mut core::ffi::c_void, program: windows_core::PCWSTR, parameters: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob2", "SetNotifyCmdLine", 35usize, |this| ((**(this as *const *const IBackgroundCopyJob2_Vtbl)).SetNotifyCmdLine)(this, program, parameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNotifyCmdLine(this: *mut core::ffi::c_void, pprogram: *mut windows_core::PWSTR, pparameters: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob2", "GetNotifyCmdLine", 36usize, |this| ((**(this as *const *const IBackgroundCopyJob2_Vtbl)).GetNotifyCmdLine)(this, pprogram, pparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReplyProgress(this: *mut core::ffi::c_void, pprogress: *mut BG_JOB_REPLY_PROGRESS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob2", "GetReplyProgress", 37usize, |this| ((**(this as *const *const IBackgroundCopyJob2_Vtbl)).GetReplyProgress)(this, pprogress), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReplyData(this: *mut core::ffi::c_void, ppbuffer: *mut *mut u8, plength: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob2", "GetReplyData", 38usize, |this| ((**(this as *const *const IBackgroundCopyJob2_Vtbl)).GetReplyData)(this, ppbuffer, plength), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReplyFileName(this: *mut core::ffi::c_void, replyfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob2", "SetReplyFileName", 39usize, |this| ((**(this as *const *const IBackgroundCopyJob2_Vtbl)).SetReplyFileName)(this, replyfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReplyFileName(this: *mut core::ffi::c_void, preplyfilename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob2", "GetReplyFileName", 40usize, |this| ((**(this as *const *const IBackgroundCopyJob2_Vtbl)).GetReplyFileName)(this, preplyfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCredentials(this: *mut core::ffi::c_void, credentials: *const BG_AUTH_CREDENTIALS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob2", "SetCredentials", 41usize, |this| ((**(this as *const *const IBackgroundCopyJob2_Vtbl)).SetCredentials)(this, credentials), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCredentials(this: *mut core::ffi::c_void, target: BG_AUTH_TARGET, scheme: BG_AUTH_SCHEME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob2", "RemoveCredentials", 42usize, |this| ((**(this as *const *const IBackgroundCopyJob2_Vtbl)).RemoveCredentials)(this, target, scheme), |result| Some(*result))
+        }
+        Self {
+            base__: <IBackgroundCopyJob_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetNotifyCmdLine,
+            GetNotifyCmdLine,
+            GetReplyProgress,
+            GetReplyData,
+            SetReplyFileName,
+            GetReplyFileName,
+            SetCredentials,
+            RemoveCredentials,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundCopyJob2 as windows_core::Interface>::IID || <IBackgroundCopyJob_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundCopyJob3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReplaceRemotePrefix(this: *mut core::ffi::c_void, oldprefix: windows_core::PCWSTR, newprefix: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob3", "ReplaceRemotePrefix", 43usize, |this| ((**(this as *const *const IBackgroundCopyJob3_Vtbl)).ReplaceRemotePrefix)(this, oldprefix, newprefix), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddFileWithRanges(this: *mut core::ffi::c_void, remoteurl: windows_core::PCWSTR, localname: windows_core::PCWSTR, rangecount: u32, ranges: *const BG_FILE_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob3", "AddFileWithRanges", 44usize, |this| ((**(this as *const *const IBackgroundCopyJob3_Vtbl)).AddFileWithRanges)(this, remoteurl, localname, rangecount, ranges), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFileACLFlags(this: *mut core::ffi::c_void, flags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob3", "SetFileACLFlags", 45usize, |this| ((**(this as *const *const IBackgroundCopyJob3_Vtbl)).SetFileACLFlags)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileACLFlags(this: *mut core::ffi::c_void, flags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob3", "GetFileACLFlags", 46usize, |this| ((**(this as *const *const IBackgroundCopyJob3_Vtbl)).GetFileACLFlags)(this, flags), |result| Some(*result))
+        }
+        Self {
+            base__: <IBackgroundCopyJob2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReplaceRemotePrefix,
+            AddFileWithRanges,
+            SetFileACLFlags,
+            GetFileACLFlags,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundCopyJob3 as windows_core::Interface>::IID || <IBackgroundCopyJob2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundCopyJob4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPeerCachingFlags(this: *mut core::ffi::c_void, flags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob4", "SetPeerCachingFlags", 47usize, |this| ((**(this as *const *const IBackgroundCopyJob4_Vtbl)).SetPeerCachingFlags)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPeerCachingFlags(this: *mut core::ffi::c_void, pflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob4", "GetPeerCachingFlags", 48usize, |this| ((**(this as *const *const IBackgroundCopyJob4_Vtbl)).GetPeerCachingFlags)(this, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOwnerIntegrityLevel(this: *mut core::ffi::c_void, plevel: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob4", "GetOwnerIntegrityLevel", 49usize, |this| ((**(this as *const *const IBackgroundCopyJob4_Vtbl)).GetOwnerIntegrityLevel)(this, plevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOwnerElevationState(this: *mut core::ffi::c_void, pelevated: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob4", "GetOwnerElevationState", 50usize, |this| ((**(this as *const *const IBackgroundCopyJob4_Vtbl)).GetOwnerElevationState)(this, pelevated), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaximumDownloadTime(this: *mut core::ffi::c_void, timeout: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob4", "SetMaximumDownloadTime", 51usize, |this| ((**(this as *const *const IBackgroundCopyJob4_Vtbl)).SetMaximumDownloadTime)(this, timeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaximumDownloadTime(this: *mut core::ffi::c_void, ptimeout: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob4", "GetMaximumDownloadTime", 52usize, |this| ((**(this as *const *const IBackgroundCopyJob4_Vtbl)).GetMaximumDownloadTime)(this, ptimeout), |result| Some(*result))
+        }
+        Self {
+            base__: <IBackgroundCopyJob3_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetPeerCachingFlags,
+            GetPeerCachingFlags,
+            GetOwnerIntegrityLevel,
+            GetOwnerElevationState,
+            SetMaximumDownloadTime,
+            GetMaximumDownloadTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundCopyJob4 as windows_core::Interface>::IID || <IBackgroundCopyJob3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundCopyJob5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, propertyid: BITS_JOB_PROPERTY_ID, propertyvalue: BITS_JOB_PROPERTY_VALUE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob5", "SetProperty", 53usize, |this| ((**(this as *const *const IBackgroundCopyJob5_Vtbl)).SetProperty)(this, propertyid, propertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, propertyid: BITS_JOB_PROPERTY_ID, propertyvalue: *mut BITS_JOB_PROPERTY_VALUE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJob5", "GetProperty", 54usize, |this| ((**(this as *const *const IBackgroundCopyJob5_Vtbl)).GetProperty)(this, propertyid, propertyvalue), |result| Some(*result))
+        }
+        Self { base__: <IBackgroundCopyJob4_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetProperty, GetProperty }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundCopyJob5 as windows_core::Interface>::IID || <IBackgroundCopyJob4_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundCopyJobHttpOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetClientCertificateByID(this: *mut core::ffi::c_void, storelocation: BG_CERT_STORE_LOCATION, storename: windows_core::PCWSTR, pcerthashblob: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJobHttpOptions", "SetClientCertificateByID", 3usize, |this| ((**(this as *const *const IBackgroundCopyJobHttpOptions_Vtbl)).SetClientCertificateByID)(this, storelocation, storename, pcerthashblob), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClientCertificateByName(this: *mut core::ffi::c_void, storelocation: BG_CERT_STORE_LOCATION, storename: windows_core::PCWSTR, subjectname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJobHttpOptions", "SetClientCertificateByName", 4usize, |this| ((**(this as *const *const IBackgroundCopyJobHttpOptions_Vtbl)).SetClientCertificateByName)(this, storelocation, storename, subjectname), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveClientCertificate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJobHttpOptions", "RemoveClientCertificate", 5usize, |this| ((**(this as *const *const IBackgroundCopyJobHttpOptions_Vtbl)).RemoveClientCertificate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClientCertificate(this: *mut core::ffi::c_void, pstorelocation: *mut BG_CERT_STORE_LOCATION, pstorename: *mut windows_core::PWSTR, ppcerthashblob: *mut *mut u8, psubjectname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJobHttpOptions", "GetClientCertificate", 6usize, |this| ((**(this as *const *const IBackgroundCopyJobHttpOptions_Vtbl)).GetClientCertificate)(this, pstorelocation, pstorename, ppcerthashblob, psubjectname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCustomHeaders(this: *mut core::ffi::c_void, requestheaders: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJobHttpOptions", "SetCustomHeaders", 7usize, |this| ((**(this as *const *const IBackgroundCopyJobHttpOptions_Vtbl)).SetCustomHeaders)(this, requestheaders), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCustomHeaders(this: *mut core::ffi::c_void, prequestheaders: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJobHttpOptions", "GetCustomHeaders", 8usize, |this| ((**(this as *const *const IBackgroundCopyJobHttpOptions_Vtbl)).GetCustomHeaders)(this, prequestheaders), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSecurityFlags(this: *mut core::ffi::c_void, flags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJobHttpOptions", "SetSecurityFlags", 9usize, |this| ((**(this as *const *const IBackgroundCopyJobHttpOptions_Vtbl)).SetSecurityFlags)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSecurityFlags(this: *mut core::ffi::c_void, pflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJobHttpOptions", "GetSecurityFlags", 10usize, |this| ((**(this as *const *const IBackgroundCopyJobHttpOptions_Vtbl)).GetSecurityFlags)(this, pflags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetClientCertificateByID,
+            SetClientCertificateByName,
+            RemoveClientCertificate,
+            GetClientCertificate,
+            SetCustomHeaders,
+            GetCustomHeaders,
+            SetSecurityFlags,
+            GetSecurityFlags,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundCopyJobHttpOptions as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundCopyJobHttpOptions2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetHttpMethod(this: *mut core::ffi::c_void, method: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJobHttpOptions2", "SetHttpMethod", 11usize, |this| ((**(this as *const *const IBackgroundCopyJobHttpOptions2_Vtbl)).SetHttpMethod)(this, method), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHttpMethod(this: *mut core::ffi::c_void, method: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJobHttpOptions2", "GetHttpMethod", 12usize, |this| ((**(this as *const *const IBackgroundCopyJobHttpOptions2_Vtbl)).GetHttpMethod)(this, method), |result| Some(*result))
+        }
+        Self { base__: <IBackgroundCopyJobHttpOptions_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetHttpMethod, GetHttpMethod }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundCopyJobHttpOptions2 as windows_core::Interface>::IID || <IBackgroundCopyJobHttpOptions_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundCopyJobHttpOptions3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetServerCertificateValidationInterface(this: *mut core::ffi::c_void, certvalidationcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJobHttpOptions3", "SetServerCertificateValidationInterface", 13usize, |this| ((**(this as *const *const IBackgroundCopyJobHttpOptions3_Vtbl)).SetServerCertificateValidationInterface)(this, certvalidationcallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn MakeCustomHeadersWriteOnly(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyJobHttpOptions3", "MakeCustomHeadersWriteOnly", 14usize, |this| ((**(this as *const *const IBackgroundCopyJobHttpOptions3_Vtbl)).MakeCustomHeadersWriteOnly)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IBackgroundCopyJobHttpOptions2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetServerCertificateValidationInterface,
+            MakeCustomHeadersWriteOnly,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundCopyJobHttpOptions3 as windows_core::Interface>::IID || <IBackgroundCopyJobHttpOptions2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundCopyManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateJob(this: *mut core::ffi::c_void, displayname: windows_core::PCWSTR, r#type: BG_JOB_TYPE, pjobid: *mut windows_core::GUID, ppjob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyManager", "CreateJob", 3usize, |this| ((**(this as *const *const IBackgroundCopyManager_Vtbl)).CreateJob)(this, displayname, r#type, pjobid, ppjob), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetJob(this: *mut core::ffi::c_void, jobid: *const windows_core::GUID, ppjob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyManager", "GetJob", 4usize, |this| ((**(this as *const *const IBackgroundCopyManager_Vtbl)).GetJob)(this, jobid, ppjob), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumJobs(this: *mut core::ffi::c_void, dwflags: u32, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyManager", "EnumJobs", 5usize, |this| ((**(this as *const *const IBackgroundCopyManager_Vtbl)).EnumJobs)(this, dwflags, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetErrorDescription(this: *mut core::ffi::c_void, hresult: windows_core::HRESULT, languageid: u32, perrordescription: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyManager", "GetErrorDescription", 6usize, |this| ((**(this as *const *const IBackgroundCopyManager_Vtbl)).GetErrorDescription)(this, hresult, languageid, perrordescription), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateJob, GetJob, EnumJobs, GetErrorDescription }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundCopyManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundCopyQMgr_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateGroup(this: *mut core::ffi::c_void, guidgroupid: windows_core::GUID, ppgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyQMgr", "CreateGroup", 3usize, |this| ((**(this as *const *const IBackgroundCopyQMgr_Vtbl)).CreateGroup)(this, guidgroupid, ppgroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGroup(this: *mut core::ffi::c_void, groupid: windows_core::GUID, ppgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyQMgr", "GetGroup", 4usize, |this| ((**(this as *const *const IBackgroundCopyQMgr_Vtbl)).GetGroup)(this, groupid, ppgroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumGroups(this: *mut core::ffi::c_void, dwflags: u32, ppenumgroups: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyQMgr", "EnumGroups", 5usize, |this| ((**(this as *const *const IBackgroundCopyQMgr_Vtbl)).EnumGroups)(this, dwflags, ppenumgroups), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateGroup, GetGroup, EnumGroups }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundCopyQMgr as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBackgroundCopyServerCertificateValidationCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ValidateServerCertificate(this: *mut core::ffi::c_void, job: *mut core::ffi::c_void, file: *mut core::ffi::c_void, certlength: u32, certdata: *const u8, certencodingtype: u32, certstorelength: u32, certstoredata: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBackgroundCopyServerCertificateValidationCallback", "ValidateServerCertificate", 3usize, |this| ((**(this as *const *const IBackgroundCopyServerCertificateValidationCallback_Vtbl)).ValidateServerCertificate)(this, job, file, certlength, certdata, certencodingtype, certstorelength, certstoredata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ValidateServerCertificate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBackgroundCopyServerCertificateValidationCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBitsPeer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPeerName(this: *mut core::ffi::c_void, pname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsPeer", "GetPeerName", 3usize, |this| ((**(this as *const *const IBitsPeer_Vtbl)).GetPeerName)(this, pname), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAuthenticated(this: *mut core::ffi::c_void, pauth: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsPeer", "IsAuthenticated", 4usize, |this| ((**(this as *const *const IBitsPeer_Vtbl)).IsAuthenticated)(this, pauth), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAvailable(this: *mut core::ffi::c_void, ponline: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsPeer", "IsAvailable", 5usize, |this| ((**(this as *const *const IBitsPeer_Vtbl)).IsAvailable)(this, ponline), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPeerName, IsAuthenticated, IsAvailable }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBitsPeer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBitsPeerCacheAdministration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMaximumCacheSize(this: *mut core::ffi::c_void, pbytes: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsPeerCacheAdministration", "GetMaximumCacheSize", 3usize, |this| ((**(this as *const *const IBitsPeerCacheAdministration_Vtbl)).GetMaximumCacheSize)(this, pbytes), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaximumCacheSize(this: *mut core::ffi::c_void, bytes: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsPeerCacheAdministration", "SetMaximumCacheSize", 4usize, |this| ((**(this as *const *const IBitsPeerCacheAdministration_Vtbl)).SetMaximumCacheSize)(this, bytes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaximumContentAge(this: *mut core::ffi::c_void, pseconds: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsPeerCacheAdministration", "GetMaximumContentAge", 5usize, |this| ((**(this as *const *const IBitsPeerCacheAdministration_Vtbl)).GetMaximumContentAge)(this, pseconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaximumContentAge(this: *mut core::ffi::c_void, seconds: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsPeerCacheAdministration", "SetMaximumContentAge", 6usize, |this| ((**(this as *const *const IBitsPeerCacheAdministration_Vtbl)).SetMaximumContentAge)(this, seconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConfigurationFlags(this: *mut core::ffi::c_void, pflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsPeerCacheAdministration", "GetConfigurationFlags", 7usize, |this| ((**(this as *const *const IBitsPeerCacheAdministration_Vtbl)).GetConfigurationFlags)(this, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConfigurationFlags(this: *mut core::ffi::c_void, flags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsPeerCacheAdministration", "SetConfigurationFlags", 8usize, |this| ((**(this as *const *const IBitsPeerCacheAdministration_Vtbl)).SetConfigurationFlags)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumRecords(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsPeerCacheAdministration", "EnumRecords", 9usize, |this| ((**(this as *const *const IBitsPeerCacheAdministration_Vtbl)).EnumRecords)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecord(this: *mut core::ffi::c_void, id: *const windows_core::GUID, pprecord: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsPeerCacheAdministration", "GetRecord", 10usize, |this| ((**(this as *const *const IBitsPeerCacheAdministration_Vtbl)).GetRecord)(this, id, pprecord), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearRecords(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsPeerCacheAdministration", "ClearRecords", 11usize, |this| ((**(this as *const *const IBitsPeerCacheAdministration_Vtbl)).ClearRecords)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteRecord(this: *mut core::ffi::c_void, id: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsPeerCacheAdministration", "DeleteRecord", 12usize, |this| ((**(this as *const *const IBitsPeerCacheAdministration_Vtbl)).DeleteRecord)(this, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteUrl(this: *mut core::ffi::c_void, url: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsPeerCacheAdministration", "DeleteUrl", 13usize, |this| ((**(this as *const *const IBitsPeerCacheAdministration_Vtbl)).DeleteUrl)(this, url), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumPeers(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsPeerCacheAdministration", "EnumPeers", 14usize, |this| ((**(this as *const *const IBitsPeerCacheAdministration_Vtbl)).EnumPeers)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearPeers(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsPeerCacheAdministration", "ClearPeers", 15usize, |this| ((**(this as *const *const IBitsPeerCacheAdministration_Vtbl)).ClearPeers)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DiscoverPeers(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsPeerCacheAdministration", "DiscoverPeers", 16usize, |this| ((**(this as *const *const IBitsPeerCacheAdministration_Vtbl)).DiscoverPeers)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetMaximumCacheSize,
+            SetMaximumCacheSize,
+            GetMaximumContentAge,
+            SetMaximumContentAge,
+            GetConfigurationFlags,
+            SetConfigurationFlags,
+            EnumRecords,
+            GetRecord,
+            ClearRecords,
+            DeleteRecord,
+            DeleteUrl,
+            EnumPeers,
+            ClearPeers,
+            DiscoverPeers,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBitsPeerCacheAdministration as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBitsPeerCacheRecord_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetId(this: *mut core::ffi::c_void, pval: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsPeerCacheRecord", "GetId", 3usize, |this| ((**(this as *const *const IBitsPeerCacheRecord_Vtbl)).GetId)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOriginUrl(this: *mut core::ffi::c_void, pval: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsPeerCacheRecord", "GetOriginUrl", 4usize, |this| ((**(this as *const *const IBitsPeerCacheRecord_Vtbl)).GetOriginUrl)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileSize(this: *mut core::ffi::c_void, pval: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsPeerCacheRecord", "GetFileSize", 5usize, |this| ((**(this as *const *const IBitsPeerCacheRecord_Vtbl)).GetFileSize)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileModificationTime(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsPeerCacheRecord", "GetFileModificationTime", 6usize, |this| ((**(this as *const *const IBitsPeerCacheRecord_Vtbl)).GetFileModificationTime)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastAccessTime(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsPeerCacheRecord", "GetLastAccessTime", 7usize, |this| ((**(this as *const *const IBitsPeerCacheRecord_Vtbl)).GetLastAccessTime)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFileValidated(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsPeerCacheRecord", "IsFileValidated", 8usize, |this| ((**(this as *const *const IBitsPeerCacheRecord_Vtbl)).IsFileValidated)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileRanges(this: *mut core::ffi::c_void, prangecount: *mut u32, ppranges: *mut *mut BG_FILE_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsPeerCacheRecord", "GetFileRanges", 9usize, |this| ((**(this as *const *const IBitsPeerCacheRecord_Vtbl)).GetFileRanges)(this, prangecount, ppranges), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetId,
+            GetOriginUrl,
+            GetFileSize,
+            GetFileModificationTime,
+            GetLastAccessTime,
+            IsFileValidated,
+            GetFileRanges,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBitsPeerCacheRecord as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBitsTokenOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetHelperTokenFlags(this: *mut core::ffi::c_void, usageflags: BG_TOKEN) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsTokenOptions", "SetHelperTokenFlags", 3usize, |this| ((**(this as *const *const IBitsTokenOptions_Vtbl)).SetHelperTokenFlags)(this, usageflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHelperTokenFlags(this: *mut core::ffi::c_void, pflags: *mut BG_TOKEN) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsTokenOptions", "GetHelperTokenFlags", 4usize, |this| ((**(this as *const *const IBitsTokenOptions_Vtbl)).GetHelperTokenFlags)(this, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHelperToken(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsTokenOptions", "SetHelperToken", 5usize, |this| ((**(this as *const *const IBitsTokenOptions_Vtbl)).SetHelperToken)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearHelperToken(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsTokenOptions", "ClearHelperToken", 6usize, |this| ((**(this as *const *const IBitsTokenOptions_Vtbl)).ClearHelperToken)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHelperTokenSid(this: *mut core::ffi::c_void, psid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBitsTokenOptions", "GetHelperTokenSid", 7usize, |this| ((**(this as *const *const IBitsTokenOptions_Vtbl)).GetHelperTokenSid)(this, psid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetHelperTokenFlags,
+            GetHelperTokenFlags,
+            SetHelperToken,
+            ClearHelperToken,
+            GetHelperTokenSid,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBitsTokenOptions as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumBackgroundCopyFiles_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBackgroundCopyFiles", "Next", 3usize, |this| ((**(this as *const *const IEnumBackgroundCopyFiles_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBackgroundCopyFiles", "Skip", 4usize, |this| ((**(this as *const *const IEnumBackgroundCopyFiles_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBackgroundCopyFiles", "Reset", 5usize, |this| ((**(this as *const *const IEnumBackgroundCopyFiles_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBackgroundCopyFiles", "Clone", 6usize, |this| ((**(this as *const *const IEnumBackgroundCopyFiles_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pucount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBackgroundCopyFiles", "GetCount", 7usize, |this| ((**(this as *const *const IEnumBackgroundCopyFiles_Vtbl)).GetCount)(this, pucount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone, GetCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumBackgroundCopyFiles as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumBackgroundCopyGroups_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut windows_core::GUID, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBackgroundCopyGroups", "Next", 3usize, |this| ((**(this as *const *const IEnumBackgroundCopyGroups_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBackgroundCopyGroups", "Skip", 4usize, |this| ((**(this as *const *const IEnumBackgroundCopyGroups_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBackgroundCopyGroups", "Reset", 5usize, |this| ((**(this as *const *const IEnumBackgroundCopyGroups_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBackgroundCopyGroups", "Clone", 6usize, |this| ((**(this as *const *const IEnumBackgroundCopyGroups_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pucount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBackgroundCopyGroups", "GetCount", 7usize, |this| ((**(this as *const *const IEnumBackgroundCopyGroups_Vtbl)).GetCount)(this, pucount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone, GetCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumBackgroundCopyGroups as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumBackgroundCopyJobs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBackgroundCopyJobs", "Next", 3usize, |this| ((**(this as *const *const IEnumBackgroundCopyJobs_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBackgroundCopyJobs", "Skip", 4usize, |this| ((**(this as *const *const IEnumBackgroundCopyJobs_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBackgroundCopyJobs", "Reset", 5usize, |this| ((**(this as *const *const IEnumBackgroundCopyJobs_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBackgroundCopyJobs", "Clone", 6usize, |this| ((**(this as *const *const IEnumBackgroundCopyJobs_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pucount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBackgroundCopyJobs", "GetCount", 7usize, |this| ((**(this as *const *const IEnumBackgroundCopyJobs_Vtbl)).GetCount)(this, pucount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone, GetCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumBackgroundCopyJobs as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumBackgroundCopyJobs1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut windows_core::GUID, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBackgroundCopyJobs1", "Next", 3usize, |this| ((**(this as *const *const IEnumBackgroundCopyJobs1_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBackgroundCopyJobs1", "Skip", 4usize, |this| ((**(this as *const *const IEnumBackgroundCopyJobs1_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBackgroundCopyJobs1", "Reset", 5usize, |this| ((**(this as *const *const IEnumBackgroundCopyJobs1_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBackgroundCopyJobs1", "Clone", 6usize, |this| ((**(this as *const *const IEnumBackgroundCopyJobs1_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pucount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBackgroundCopyJobs1", "GetCount", 7usize, |this| ((**(this as *const *const IEnumBackgroundCopyJobs1_Vtbl)).GetCount)(this, pucount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone, GetCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumBackgroundCopyJobs1 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumBitsPeerCacheRecords_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBitsPeerCacheRecords", "Next", 3usize, |this| ((**(this as *const *const IEnumBitsPeerCacheRecords_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBitsPeerCacheRecords", "Skip", 4usize, |this| ((**(this as *const *const IEnumBitsPeerCacheRecords_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBitsPeerCacheRecords", "Reset", 5usize, |this| ((**(this as *const *const IEnumBitsPeerCacheRecords_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBitsPeerCacheRecords", "Clone", 6usize, |this| ((**(this as *const *const IEnumBitsPeerCacheRecords_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pucount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBitsPeerCacheRecords", "GetCount", 7usize, |this| ((**(this as *const *const IEnumBitsPeerCacheRecords_Vtbl)).GetCount)(this, pucount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone, GetCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumBitsPeerCacheRecords as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumBitsPeers_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBitsPeers", "Next", 3usize, |this| ((**(this as *const *const IEnumBitsPeers_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBitsPeers", "Skip", 4usize, |this| ((**(this as *const *const IEnumBitsPeers_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBitsPeers", "Reset", 5usize, |this| ((**(this as *const *const IEnumBitsPeers_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBitsPeers", "Clone", 6usize, |this| ((**(this as *const *const IEnumBitsPeers_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pucount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumBitsPeers", "GetCount", 7usize, |this| ((**(this as *const *const IEnumBitsPeers_Vtbl)).GetCount)(this, pucount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone, GetCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumBitsPeers as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -8526,6 +8526,7 @@ pub struct CLCTL_CODES(pub i32);
 impl windows_core::TypeKind for CLCTL_CODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLCTL_CODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLCTL_CODES").field(&self.0).finish()
@@ -8537,6 +8538,7 @@ pub struct CLUADMEX_OBJECT_TYPE(pub i32);
 impl windows_core::TypeKind for CLUADMEX_OBJECT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUADMEX_OBJECT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUADMEX_OBJECT_TYPE").field(&self.0).finish()
@@ -8548,6 +8550,7 @@ pub struct CLUSCTL_AFFINITYRULE_CODES(pub i32);
 impl windows_core::TypeKind for CLUSCTL_AFFINITYRULE_CODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSCTL_AFFINITYRULE_CODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSCTL_AFFINITYRULE_CODES").field(&self.0).finish()
@@ -8559,6 +8562,7 @@ pub struct CLUSCTL_CLUSTER_CODES(pub i32);
 impl windows_core::TypeKind for CLUSCTL_CLUSTER_CODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSCTL_CLUSTER_CODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSCTL_CLUSTER_CODES").field(&self.0).finish()
@@ -8570,6 +8574,7 @@ pub struct CLUSCTL_GROUPSET_CODES(pub i32);
 impl windows_core::TypeKind for CLUSCTL_GROUPSET_CODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSCTL_GROUPSET_CODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSCTL_GROUPSET_CODES").field(&self.0).finish()
@@ -8581,6 +8586,7 @@ pub struct CLUSCTL_GROUP_CODES(pub i32);
 impl windows_core::TypeKind for CLUSCTL_GROUP_CODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSCTL_GROUP_CODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSCTL_GROUP_CODES").field(&self.0).finish()
@@ -8592,6 +8598,7 @@ pub struct CLUSCTL_NETINTERFACE_CODES(pub i32);
 impl windows_core::TypeKind for CLUSCTL_NETINTERFACE_CODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSCTL_NETINTERFACE_CODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSCTL_NETINTERFACE_CODES").field(&self.0).finish()
@@ -8603,6 +8610,7 @@ pub struct CLUSCTL_NETWORK_CODES(pub i32);
 impl windows_core::TypeKind for CLUSCTL_NETWORK_CODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSCTL_NETWORK_CODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSCTL_NETWORK_CODES").field(&self.0).finish()
@@ -8614,6 +8622,7 @@ pub struct CLUSCTL_NODE_CODES(pub i32);
 impl windows_core::TypeKind for CLUSCTL_NODE_CODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSCTL_NODE_CODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSCTL_NODE_CODES").field(&self.0).finish()
@@ -8625,6 +8634,7 @@ pub struct CLUSCTL_RESOURCE_CODES(pub i32);
 impl windows_core::TypeKind for CLUSCTL_RESOURCE_CODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSCTL_RESOURCE_CODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSCTL_RESOURCE_CODES").field(&self.0).finish()
@@ -8636,6 +8646,7 @@ pub struct CLUSCTL_RESOURCE_TYPE_CODES(pub i32);
 impl windows_core::TypeKind for CLUSCTL_RESOURCE_TYPE_CODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSCTL_RESOURCE_TYPE_CODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSCTL_RESOURCE_TYPE_CODES").field(&self.0).finish()
@@ -8647,6 +8658,7 @@ pub struct CLUSGROUP_TYPE(pub i32);
 impl windows_core::TypeKind for CLUSGROUP_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSGROUP_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSGROUP_TYPE").field(&self.0).finish()
@@ -8658,6 +8670,7 @@ pub struct CLUSPROP_IPADDR_ENABLENETBIOS(pub i32);
 impl windows_core::TypeKind for CLUSPROP_IPADDR_ENABLENETBIOS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSPROP_IPADDR_ENABLENETBIOS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSPROP_IPADDR_ENABLENETBIOS").field(&self.0).finish()
@@ -8669,6 +8682,7 @@ pub struct CLUSPROP_PIFLAGS(pub i32);
 impl windows_core::TypeKind for CLUSPROP_PIFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSPROP_PIFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSPROP_PIFLAGS").field(&self.0).finish()
@@ -8680,6 +8694,7 @@ pub struct CLUSTERSET_OBJECT_TYPE(pub i32);
 impl windows_core::TypeKind for CLUSTERSET_OBJECT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTERSET_OBJECT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTERSET_OBJECT_TYPE").field(&self.0).finish()
@@ -8691,6 +8706,7 @@ pub struct CLUSTER_CHANGE(pub i32);
 impl windows_core::TypeKind for CLUSTER_CHANGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_CHANGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_CHANGE").field(&self.0).finish()
@@ -8702,6 +8718,7 @@ pub struct CLUSTER_CHANGE_CLUSTER_V2(pub i32);
 impl windows_core::TypeKind for CLUSTER_CHANGE_CLUSTER_V2 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_CHANGE_CLUSTER_V2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_CHANGE_CLUSTER_V2").field(&self.0).finish()
@@ -8713,6 +8730,7 @@ pub struct CLUSTER_CHANGE_GROUPSET_V2(pub i32);
 impl windows_core::TypeKind for CLUSTER_CHANGE_GROUPSET_V2 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_CHANGE_GROUPSET_V2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_CHANGE_GROUPSET_V2").field(&self.0).finish()
@@ -8724,6 +8742,7 @@ pub struct CLUSTER_CHANGE_GROUP_V2(pub i32);
 impl windows_core::TypeKind for CLUSTER_CHANGE_GROUP_V2 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_CHANGE_GROUP_V2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_CHANGE_GROUP_V2").field(&self.0).finish()
@@ -8735,6 +8754,7 @@ pub struct CLUSTER_CHANGE_NETINTERFACE_V2(pub i32);
 impl windows_core::TypeKind for CLUSTER_CHANGE_NETINTERFACE_V2 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_CHANGE_NETINTERFACE_V2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_CHANGE_NETINTERFACE_V2").field(&self.0).finish()
@@ -8746,6 +8766,7 @@ pub struct CLUSTER_CHANGE_NETWORK_V2(pub i32);
 impl windows_core::TypeKind for CLUSTER_CHANGE_NETWORK_V2 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_CHANGE_NETWORK_V2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_CHANGE_NETWORK_V2").field(&self.0).finish()
@@ -8757,6 +8778,7 @@ pub struct CLUSTER_CHANGE_NODE_UPGRADE_PHASE_V2(pub i32);
 impl windows_core::TypeKind for CLUSTER_CHANGE_NODE_UPGRADE_PHASE_V2 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_CHANGE_NODE_UPGRADE_PHASE_V2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_CHANGE_NODE_UPGRADE_PHASE_V2").field(&self.0).finish()
@@ -8768,6 +8790,7 @@ pub struct CLUSTER_CHANGE_NODE_V2(pub i32);
 impl windows_core::TypeKind for CLUSTER_CHANGE_NODE_V2 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_CHANGE_NODE_V2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_CHANGE_NODE_V2").field(&self.0).finish()
@@ -8779,6 +8802,7 @@ pub struct CLUSTER_CHANGE_QUORUM_V2(pub i32);
 impl windows_core::TypeKind for CLUSTER_CHANGE_QUORUM_V2 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_CHANGE_QUORUM_V2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_CHANGE_QUORUM_V2").field(&self.0).finish()
@@ -8790,6 +8814,7 @@ pub struct CLUSTER_CHANGE_REGISTRY_V2(pub i32);
 impl windows_core::TypeKind for CLUSTER_CHANGE_REGISTRY_V2 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_CHANGE_REGISTRY_V2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_CHANGE_REGISTRY_V2").field(&self.0).finish()
@@ -8801,6 +8826,7 @@ pub struct CLUSTER_CHANGE_RESOURCE_TYPE_V2(pub i32);
 impl windows_core::TypeKind for CLUSTER_CHANGE_RESOURCE_TYPE_V2 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_CHANGE_RESOURCE_TYPE_V2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_CHANGE_RESOURCE_TYPE_V2").field(&self.0).finish()
@@ -8812,6 +8838,7 @@ pub struct CLUSTER_CHANGE_RESOURCE_V2(pub i32);
 impl windows_core::TypeKind for CLUSTER_CHANGE_RESOURCE_V2 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_CHANGE_RESOURCE_V2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_CHANGE_RESOURCE_V2").field(&self.0).finish()
@@ -8823,6 +8850,7 @@ pub struct CLUSTER_CHANGE_SHARED_VOLUME_V2(pub i32);
 impl windows_core::TypeKind for CLUSTER_CHANGE_SHARED_VOLUME_V2 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_CHANGE_SHARED_VOLUME_V2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_CHANGE_SHARED_VOLUME_V2").field(&self.0).finish()
@@ -8834,6 +8862,7 @@ pub struct CLUSTER_CHANGE_SPACEPORT_V2(pub i32);
 impl windows_core::TypeKind for CLUSTER_CHANGE_SPACEPORT_V2 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_CHANGE_SPACEPORT_V2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_CHANGE_SPACEPORT_V2").field(&self.0).finish()
@@ -8845,6 +8874,7 @@ pub struct CLUSTER_CLOUD_TYPE(pub i32);
 impl windows_core::TypeKind for CLUSTER_CLOUD_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_CLOUD_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_CLOUD_TYPE").field(&self.0).finish()
@@ -8856,6 +8886,7 @@ pub struct CLUSTER_CONTROL_OBJECT(pub i32);
 impl windows_core::TypeKind for CLUSTER_CONTROL_OBJECT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_CONTROL_OBJECT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_CONTROL_OBJECT").field(&self.0).finish()
@@ -8867,6 +8898,7 @@ pub struct CLUSTER_CSV_VOLUME_FAULT_STATE(pub i32);
 impl windows_core::TypeKind for CLUSTER_CSV_VOLUME_FAULT_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_CSV_VOLUME_FAULT_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_CSV_VOLUME_FAULT_STATE").field(&self.0).finish()
@@ -8878,6 +8910,7 @@ pub struct CLUSTER_ENUM(pub i32);
 impl windows_core::TypeKind for CLUSTER_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_ENUM").field(&self.0).finish()
@@ -8889,6 +8922,7 @@ pub struct CLUSTER_GROUP_AUTOFAILBACK_TYPE(pub i32);
 impl windows_core::TypeKind for CLUSTER_GROUP_AUTOFAILBACK_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_GROUP_AUTOFAILBACK_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_GROUP_AUTOFAILBACK_TYPE").field(&self.0).finish()
@@ -8900,6 +8934,7 @@ pub struct CLUSTER_GROUP_ENUM(pub i32);
 impl windows_core::TypeKind for CLUSTER_GROUP_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_GROUP_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_GROUP_ENUM").field(&self.0).finish()
@@ -8911,6 +8946,7 @@ pub struct CLUSTER_GROUP_PRIORITY(pub i32);
 impl windows_core::TypeKind for CLUSTER_GROUP_PRIORITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_GROUP_PRIORITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_GROUP_PRIORITY").field(&self.0).finish()
@@ -8922,6 +8958,7 @@ pub struct CLUSTER_GROUP_STATE(pub i32);
 impl windows_core::TypeKind for CLUSTER_GROUP_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_GROUP_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_GROUP_STATE").field(&self.0).finish()
@@ -8933,6 +8970,7 @@ pub struct CLUSTER_MGMT_POINT_RESTYPE(pub i32);
 impl windows_core::TypeKind for CLUSTER_MGMT_POINT_RESTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_MGMT_POINT_RESTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_MGMT_POINT_RESTYPE").field(&self.0).finish()
@@ -8944,6 +8982,7 @@ pub struct CLUSTER_MGMT_POINT_TYPE(pub i32);
 impl windows_core::TypeKind for CLUSTER_MGMT_POINT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_MGMT_POINT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_MGMT_POINT_TYPE").field(&self.0).finish()
@@ -8955,6 +8994,7 @@ pub struct CLUSTER_NETINTERFACE_STATE(pub i32);
 impl windows_core::TypeKind for CLUSTER_NETINTERFACE_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_NETINTERFACE_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_NETINTERFACE_STATE").field(&self.0).finish()
@@ -8966,6 +9006,7 @@ pub struct CLUSTER_NETWORK_ENUM(pub i32);
 impl windows_core::TypeKind for CLUSTER_NETWORK_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_NETWORK_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_NETWORK_ENUM").field(&self.0).finish()
@@ -8977,6 +9018,7 @@ pub struct CLUSTER_NETWORK_ROLE(pub i32);
 impl windows_core::TypeKind for CLUSTER_NETWORK_ROLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_NETWORK_ROLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_NETWORK_ROLE").field(&self.0).finish()
@@ -8988,6 +9030,7 @@ pub struct CLUSTER_NETWORK_STATE(pub i32);
 impl windows_core::TypeKind for CLUSTER_NETWORK_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_NETWORK_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_NETWORK_STATE").field(&self.0).finish()
@@ -8999,6 +9042,7 @@ pub struct CLUSTER_NODE_DRAIN_STATUS(pub i32);
 impl windows_core::TypeKind for CLUSTER_NODE_DRAIN_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_NODE_DRAIN_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_NODE_DRAIN_STATUS").field(&self.0).finish()
@@ -9010,6 +9054,7 @@ pub struct CLUSTER_NODE_ENUM(pub i32);
 impl windows_core::TypeKind for CLUSTER_NODE_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_NODE_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_NODE_ENUM").field(&self.0).finish()
@@ -9021,6 +9066,7 @@ pub struct CLUSTER_NODE_RESUME_FAILBACK_TYPE(pub i32);
 impl windows_core::TypeKind for CLUSTER_NODE_RESUME_FAILBACK_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_NODE_RESUME_FAILBACK_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_NODE_RESUME_FAILBACK_TYPE").field(&self.0).finish()
@@ -9032,6 +9078,7 @@ pub struct CLUSTER_NODE_STATE(pub i32);
 impl windows_core::TypeKind for CLUSTER_NODE_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_NODE_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_NODE_STATE").field(&self.0).finish()
@@ -9043,6 +9090,7 @@ pub struct CLUSTER_NODE_STATUS(pub i32);
 impl windows_core::TypeKind for CLUSTER_NODE_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_NODE_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_NODE_STATUS").field(&self.0).finish()
@@ -9054,6 +9102,7 @@ pub struct CLUSTER_NOTIFICATIONS_VERSION(pub i32);
 impl windows_core::TypeKind for CLUSTER_NOTIFICATIONS_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_NOTIFICATIONS_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_NOTIFICATIONS_VERSION").field(&self.0).finish()
@@ -9065,6 +9114,7 @@ pub struct CLUSTER_OBJECT_TYPE(pub i32);
 impl windows_core::TypeKind for CLUSTER_OBJECT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_OBJECT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_OBJECT_TYPE").field(&self.0).finish()
@@ -9076,6 +9126,7 @@ pub struct CLUSTER_PROPERTY_FORMAT(pub i32);
 impl windows_core::TypeKind for CLUSTER_PROPERTY_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_PROPERTY_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_PROPERTY_FORMAT").field(&self.0).finish()
@@ -9087,6 +9138,7 @@ pub struct CLUSTER_PROPERTY_SYNTAX(pub u32);
 impl windows_core::TypeKind for CLUSTER_PROPERTY_SYNTAX {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_PROPERTY_SYNTAX {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_PROPERTY_SYNTAX").field(&self.0).finish()
@@ -9098,6 +9150,7 @@ pub struct CLUSTER_PROPERTY_TYPE(pub i32);
 impl windows_core::TypeKind for CLUSTER_PROPERTY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_PROPERTY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_PROPERTY_TYPE").field(&self.0).finish()
@@ -9109,6 +9162,7 @@ pub struct CLUSTER_QUORUM_TYPE(pub i32);
 impl windows_core::TypeKind for CLUSTER_QUORUM_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_QUORUM_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_QUORUM_TYPE").field(&self.0).finish()
@@ -9120,6 +9174,7 @@ pub struct CLUSTER_QUORUM_VALUE(pub i32);
 impl windows_core::TypeKind for CLUSTER_QUORUM_VALUE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_QUORUM_VALUE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_QUORUM_VALUE").field(&self.0).finish()
@@ -9131,6 +9186,7 @@ pub struct CLUSTER_REG_COMMAND(pub i32);
 impl windows_core::TypeKind for CLUSTER_REG_COMMAND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_REG_COMMAND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_REG_COMMAND").field(&self.0).finish()
@@ -9142,6 +9198,7 @@ pub struct CLUSTER_RESOURCE_APPLICATION_STATE(pub i32);
 impl windows_core::TypeKind for CLUSTER_RESOURCE_APPLICATION_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_RESOURCE_APPLICATION_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_RESOURCE_APPLICATION_STATE").field(&self.0).finish()
@@ -9153,6 +9210,7 @@ pub struct CLUSTER_RESOURCE_CLASS(pub i32);
 impl windows_core::TypeKind for CLUSTER_RESOURCE_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_RESOURCE_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_RESOURCE_CLASS").field(&self.0).finish()
@@ -9164,6 +9222,7 @@ pub struct CLUSTER_RESOURCE_CREATE_FLAGS(pub i32);
 impl windows_core::TypeKind for CLUSTER_RESOURCE_CREATE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_RESOURCE_CREATE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_RESOURCE_CREATE_FLAGS").field(&self.0).finish()
@@ -9175,6 +9234,7 @@ pub struct CLUSTER_RESOURCE_EMBEDDED_FAILURE_ACTION(pub i32);
 impl windows_core::TypeKind for CLUSTER_RESOURCE_EMBEDDED_FAILURE_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_RESOURCE_EMBEDDED_FAILURE_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_RESOURCE_EMBEDDED_FAILURE_ACTION").field(&self.0).finish()
@@ -9186,6 +9246,7 @@ pub struct CLUSTER_RESOURCE_ENUM(pub i32);
 impl windows_core::TypeKind for CLUSTER_RESOURCE_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_RESOURCE_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_RESOURCE_ENUM").field(&self.0).finish()
@@ -9197,6 +9258,7 @@ pub struct CLUSTER_RESOURCE_RESTART_ACTION(pub i32);
 impl windows_core::TypeKind for CLUSTER_RESOURCE_RESTART_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_RESOURCE_RESTART_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_RESOURCE_RESTART_ACTION").field(&self.0).finish()
@@ -9208,6 +9270,7 @@ pub struct CLUSTER_RESOURCE_STATE(pub i32);
 impl windows_core::TypeKind for CLUSTER_RESOURCE_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_RESOURCE_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_RESOURCE_STATE").field(&self.0).finish()
@@ -9219,6 +9282,7 @@ pub struct CLUSTER_RESOURCE_STATE_CHANGE_REASON(pub i32);
 impl windows_core::TypeKind for CLUSTER_RESOURCE_STATE_CHANGE_REASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_RESOURCE_STATE_CHANGE_REASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_RESOURCE_STATE_CHANGE_REASON").field(&self.0).finish()
@@ -9230,6 +9294,7 @@ pub struct CLUSTER_RESOURCE_TYPE_ENUM(pub i32);
 impl windows_core::TypeKind for CLUSTER_RESOURCE_TYPE_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_RESOURCE_TYPE_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_RESOURCE_TYPE_ENUM").field(&self.0).finish()
@@ -9241,6 +9306,7 @@ pub struct CLUSTER_ROLE(pub i32);
 impl windows_core::TypeKind for CLUSTER_ROLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_ROLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_ROLE").field(&self.0).finish()
@@ -9252,6 +9318,7 @@ pub struct CLUSTER_ROLE_STATE(pub i32);
 impl windows_core::TypeKind for CLUSTER_ROLE_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_ROLE_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_ROLE_STATE").field(&self.0).finish()
@@ -9263,6 +9330,7 @@ pub struct CLUSTER_SETUP_PHASE(pub i32);
 impl windows_core::TypeKind for CLUSTER_SETUP_PHASE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_SETUP_PHASE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_SETUP_PHASE").field(&self.0).finish()
@@ -9274,6 +9342,7 @@ pub struct CLUSTER_SETUP_PHASE_SEVERITY(pub i32);
 impl windows_core::TypeKind for CLUSTER_SETUP_PHASE_SEVERITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_SETUP_PHASE_SEVERITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_SETUP_PHASE_SEVERITY").field(&self.0).finish()
@@ -9285,6 +9354,7 @@ pub struct CLUSTER_SETUP_PHASE_TYPE(pub i32);
 impl windows_core::TypeKind for CLUSTER_SETUP_PHASE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_SETUP_PHASE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_SETUP_PHASE_TYPE").field(&self.0).finish()
@@ -9296,6 +9366,7 @@ pub struct CLUSTER_SHARED_VOLUME_BACKUP_STATE(pub i32);
 impl windows_core::TypeKind for CLUSTER_SHARED_VOLUME_BACKUP_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_SHARED_VOLUME_BACKUP_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_SHARED_VOLUME_BACKUP_STATE").field(&self.0).finish()
@@ -9307,6 +9378,7 @@ pub struct CLUSTER_SHARED_VOLUME_RENAME_INPUT_TYPE(pub i32);
 impl windows_core::TypeKind for CLUSTER_SHARED_VOLUME_RENAME_INPUT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_SHARED_VOLUME_RENAME_INPUT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_SHARED_VOLUME_RENAME_INPUT_TYPE").field(&self.0).finish()
@@ -9318,6 +9390,7 @@ pub struct CLUSTER_SHARED_VOLUME_SNAPSHOT_STATE(pub i32);
 impl windows_core::TypeKind for CLUSTER_SHARED_VOLUME_SNAPSHOT_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_SHARED_VOLUME_SNAPSHOT_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_SHARED_VOLUME_SNAPSHOT_STATE").field(&self.0).finish()
@@ -9329,6 +9402,7 @@ pub struct CLUSTER_SHARED_VOLUME_STATE(pub i32);
 impl windows_core::TypeKind for CLUSTER_SHARED_VOLUME_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_SHARED_VOLUME_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_SHARED_VOLUME_STATE").field(&self.0).finish()
@@ -9340,6 +9414,7 @@ pub struct CLUSTER_STORAGENODE_STATE(pub i32);
 impl windows_core::TypeKind for CLUSTER_STORAGENODE_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_STORAGENODE_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_STORAGENODE_STATE").field(&self.0).finish()
@@ -9351,6 +9426,7 @@ pub struct CLUSTER_UPGRADE_PHASE(pub i32);
 impl windows_core::TypeKind for CLUSTER_UPGRADE_PHASE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSTER_UPGRADE_PHASE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSTER_UPGRADE_PHASE").field(&self.0).finish()
@@ -9362,6 +9438,7 @@ pub struct CLUS_AFFINITY_RULE_TYPE(pub i32);
 impl windows_core::TypeKind for CLUS_AFFINITY_RULE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUS_AFFINITY_RULE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUS_AFFINITY_RULE_TYPE").field(&self.0).finish()
@@ -9373,6 +9450,7 @@ pub struct CLUS_CHARACTERISTICS(pub i32);
 impl windows_core::TypeKind for CLUS_CHARACTERISTICS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUS_CHARACTERISTICS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUS_CHARACTERISTICS").field(&self.0).finish()
@@ -9384,6 +9462,7 @@ pub struct CLUS_FLAGS(pub i32);
 impl windows_core::TypeKind for CLUS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUS_FLAGS").field(&self.0).finish()
@@ -9395,6 +9474,7 @@ pub struct CLUS_GROUP_START_SETTING(pub i32);
 impl windows_core::TypeKind for CLUS_GROUP_START_SETTING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUS_GROUP_START_SETTING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUS_GROUP_START_SETTING").field(&self.0).finish()
@@ -9406,6 +9486,7 @@ pub struct CLUS_RESSUBCLASS(pub i32);
 impl windows_core::TypeKind for CLUS_RESSUBCLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUS_RESSUBCLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUS_RESSUBCLASS").field(&self.0).finish()
@@ -9417,6 +9498,7 @@ pub struct CLUS_RESSUBCLASS_NETWORK(pub i32);
 impl windows_core::TypeKind for CLUS_RESSUBCLASS_NETWORK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUS_RESSUBCLASS_NETWORK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUS_RESSUBCLASS_NETWORK").field(&self.0).finish()
@@ -9428,6 +9510,7 @@ pub struct CLUS_RESSUBCLASS_STORAGE(pub i32);
 impl windows_core::TypeKind for CLUS_RESSUBCLASS_STORAGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUS_RESSUBCLASS_STORAGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUS_RESSUBCLASS_STORAGE").field(&self.0).finish()
@@ -9439,6 +9522,7 @@ pub struct FAILURE_TYPE(pub i32);
 impl windows_core::TypeKind for FAILURE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FAILURE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FAILURE_TYPE").field(&self.0).finish()
@@ -9450,6 +9534,7 @@ pub struct FILESHARE_CHANGE_ENUM(pub i32);
 impl windows_core::TypeKind for FILESHARE_CHANGE_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FILESHARE_CHANGE_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FILESHARE_CHANGE_ENUM").field(&self.0).finish()
@@ -9461,6 +9546,7 @@ pub struct GRP_PLACEMENT_OPTIONS(pub i32);
 impl windows_core::TypeKind for GRP_PLACEMENT_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GRP_PLACEMENT_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GRP_PLACEMENT_OPTIONS").field(&self.0).finish()
@@ -9472,6 +9558,7 @@ pub struct LOG_LEVEL(pub i32);
 impl windows_core::TypeKind for LOG_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LOG_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LOG_LEVEL").field(&self.0).finish()
@@ -9483,6 +9570,7 @@ pub struct MAINTENANCE_MODE_TYPE_ENUM(pub i32);
 impl windows_core::TypeKind for MAINTENANCE_MODE_TYPE_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MAINTENANCE_MODE_TYPE_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MAINTENANCE_MODE_TYPE_ENUM").field(&self.0).finish()
@@ -9494,6 +9582,7 @@ pub struct NODE_CLUSTER_STATE(pub i32);
 impl windows_core::TypeKind for NODE_CLUSTER_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NODE_CLUSTER_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NODE_CLUSTER_STATE").field(&self.0).finish()
@@ -9505,6 +9594,7 @@ pub struct PLACEMENT_OPTIONS(pub i32);
 impl windows_core::TypeKind for PLACEMENT_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PLACEMENT_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PLACEMENT_OPTIONS").field(&self.0).finish()
@@ -9516,6 +9606,7 @@ pub struct RESDLL_CONTEXT_OPERATION_TYPE(pub i32);
 impl windows_core::TypeKind for RESDLL_CONTEXT_OPERATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RESDLL_CONTEXT_OPERATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RESDLL_CONTEXT_OPERATION_TYPE").field(&self.0).finish()
@@ -9527,6 +9618,7 @@ pub struct RESOURCE_EXIT_STATE(pub i32);
 impl windows_core::TypeKind for RESOURCE_EXIT_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RESOURCE_EXIT_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RESOURCE_EXIT_STATE").field(&self.0).finish()
@@ -9538,6 +9630,7 @@ pub struct RESOURCE_MONITOR_STATE(pub i32);
 impl windows_core::TypeKind for RESOURCE_MONITOR_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RESOURCE_MONITOR_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RESOURCE_MONITOR_STATE").field(&self.0).finish()
@@ -9549,6 +9642,7 @@ pub struct SR_DISK_REPLICATION_ELIGIBLE(pub i32);
 impl windows_core::TypeKind for SR_DISK_REPLICATION_ELIGIBLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SR_DISK_REPLICATION_ELIGIBLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SR_DISK_REPLICATION_ELIGIBLE").field(&self.0).finish()
@@ -9560,6 +9654,7 @@ pub struct SR_REPLICATED_DISK_TYPE(pub i32);
 impl windows_core::TypeKind for SR_REPLICATED_DISK_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SR_REPLICATED_DISK_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SR_REPLICATED_DISK_TYPE").field(&self.0).finish()
@@ -9571,13 +9666,15 @@ pub struct VM_RESDLL_CONTEXT(pub i32);
 impl windows_core::TypeKind for VM_RESDLL_CONTEXT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VM_RESDLL_CONTEXT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VM_RESDLL_CONTEXT").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLRES_CALLBACK_FUNCTION_TABLE {
     pub LogEvent: PLOG_EVENT_ROUTINE,
     pub SetResourceStatusEx: PSET_RESOURCE_STATUS_ROUTINE_EX,
@@ -9644,7 +9741,8 @@ impl Default for CLRES_FUNCTION_TABLE_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Registry")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLRES_V1_FUNCTIONS {
     pub Open: POPEN_ROUTINE,
     pub Close: PCLOSE_ROUTINE,
@@ -9670,7 +9768,8 @@ impl Default for CLRES_V1_FUNCTIONS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Registry")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLRES_V2_FUNCTIONS {
     pub Open: POPEN_V2_ROUTINE,
     pub Close: PCLOSE_ROUTINE,
@@ -9697,7 +9796,8 @@ impl Default for CLRES_V2_FUNCTIONS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Registry")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLRES_V3_FUNCTIONS {
     pub Open: POPEN_V2_ROUTINE,
     pub Close: PCLOSE_ROUTINE,
@@ -9724,7 +9824,8 @@ impl Default for CLRES_V3_FUNCTIONS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Registry")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLRES_V4_FUNCTIONS {
     pub Open: POPEN_V2_ROUTINE,
     pub Close: PCLOSE_ROUTINE,
@@ -9752,7 +9853,8 @@ impl Default for CLRES_V4_FUNCTIONS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUSAPI_REASON_HANDLER {
     pub lpParameter: *mut core::ffi::c_void,
     pub pfnHandler: PCLUSAPI_PFN_REASON_HANDLER,
@@ -9766,7 +9868,8 @@ impl Default for CLUSAPI_REASON_HANDLER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUSCTL_GROUP_GET_LAST_MOVE_TIME_OUTPUT {
     pub GetTickCount64: u64,
     pub GetSystemTime: super::super::Foundation::SYSTEMTIME,
@@ -9781,7 +9884,8 @@ impl Default for CLUSCTL_GROUP_GET_LAST_MOVE_TIME_OUTPUT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUSCTL_RESOURCE_STATE_CHANGE_REASON_STRUCT {
     pub dwSize: u32,
     pub dwVersion: u32,
@@ -9796,7 +9900,8 @@ impl Default for CLUSCTL_RESOURCE_STATE_CHANGE_REASON_STRUCT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUSCTL_RESOURCE_TYPE_STORAGE_GET_AVAILABLE_DISKS_EX2_INPUT {
     pub dwFlags: u32,
     pub guidPoolFilter: windows_core::GUID,
@@ -10098,7 +10203,8 @@ impl Default for CLUSPROP_SYNTAX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUSPROP_SYNTAX_0 {
     pub wFormat: u16,
     pub wType: u16,
@@ -10168,7 +10274,8 @@ impl Default for CLUSPROP_WORD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUSTERVERSIONINFO {
     pub dwVersionInfoSize: u32,
     pub MajorVersion: u16,
@@ -10190,7 +10297,8 @@ impl Default for CLUSTERVERSIONINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUSTERVERSIONINFO_NT4 {
     pub dwVersionInfoSize: u32,
     pub MajorVersion: u16,
@@ -10208,7 +10316,8 @@ impl Default for CLUSTERVERSIONINFO_NT4 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUSTER_AVAILABILITY_SET_CONFIG {
     pub dwVersion: u32,
     pub dwUpdateDomains: u32,
@@ -10224,7 +10333,8 @@ impl Default for CLUSTER_AVAILABILITY_SET_CONFIG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUSTER_BATCH_COMMAND {
     pub Command: CLUSTER_REG_COMMAND,
     pub dwOptions: u32,
@@ -10241,7 +10351,8 @@ impl Default for CLUSTER_BATCH_COMMAND {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUSTER_CREATE_GROUP_INFO {
     pub dwVersion: u32,
     pub groupType: CLUSGROUP_TYPE,
@@ -10255,7 +10366,8 @@ impl Default for CLUSTER_CREATE_GROUP_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUSTER_ENUM_ITEM {
     pub dwVersion: u32,
     pub dwType: u32,
@@ -10273,7 +10385,8 @@ impl Default for CLUSTER_ENUM_ITEM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUSTER_GROUP_ENUM_ITEM {
     pub dwVersion: u32,
     pub cbId: u32,
@@ -10298,7 +10411,8 @@ impl Default for CLUSTER_GROUP_ENUM_ITEM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUSTER_HEALTH_FAULT {
     pub Id: windows_core::PWSTR,
     pub ErrorType: u32,
@@ -10317,7 +10431,8 @@ impl Default for CLUSTER_HEALTH_FAULT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUSTER_HEALTH_FAULT_ARRAY {
     pub numFaults: u32,
     pub faults: *mut CLUSTER_HEALTH_FAULT,
@@ -10331,7 +10446,8 @@ impl Default for CLUSTER_HEALTH_FAULT_ARRAY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUSTER_IP_ENTRY {
     pub lpszIpAddress: windows_core::PCWSTR,
     pub dwPrefixLength: u32,
@@ -10345,7 +10461,8 @@ impl Default for CLUSTER_IP_ENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUSTER_MEMBERSHIP_INFO {
     pub HasQuorum: super::super::Foundation::BOOL,
     pub UpnodesSize: u32,
@@ -10360,7 +10477,8 @@ impl Default for CLUSTER_MEMBERSHIP_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUSTER_READ_BATCH_COMMAND {
     pub Command: CLUSTER_REG_COMMAND,
     pub dwOptions: u32,
@@ -10378,7 +10496,8 @@ impl Default for CLUSTER_READ_BATCH_COMMAND {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUSTER_RESOURCE_ENUM_ITEM {
     pub dwVersion: u32,
     pub cbId: u32,
@@ -10403,7 +10522,8 @@ impl Default for CLUSTER_RESOURCE_ENUM_ITEM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUSTER_SET_PASSWORD_STATUS {
     pub NodeId: u32,
     pub SetAttempted: super::super::Foundation::BOOLEAN,
@@ -10446,7 +10566,8 @@ impl Default for CLUSTER_SHARED_VOLUME_RENAME_INPUT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUSTER_SHARED_VOLUME_RENAME_INPUT_GUID_NAME {
     pub NewVolumeName: [u16; 260],
     pub NewVolumeGuid: [u16; 50],
@@ -10460,7 +10581,8 @@ impl Default for CLUSTER_SHARED_VOLUME_RENAME_INPUT_GUID_NAME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUSTER_SHARED_VOLUME_RENAME_INPUT_NAME {
     pub NewVolumeName: [u16; 260],
 }
@@ -10503,7 +10625,8 @@ impl Default for CLUSTER_SHARED_VOLUME_RENAME_INPUT_VOLUME_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUSTER_SHARED_VOLUME_STATE_INFO {
     pub szVolumeName: [u16; 260],
     pub szNodeName: [u16; 260],
@@ -10518,7 +10641,8 @@ impl Default for CLUSTER_SHARED_VOLUME_STATE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUSTER_SHARED_VOLUME_STATE_INFO_EX {
     pub szVolumeName: [u16; 260],
     pub szNodeName: [u16; 260],
@@ -10536,7 +10660,8 @@ impl Default for CLUSTER_SHARED_VOLUME_STATE_INFO_EX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUSTER_VALIDATE_CSV_FILENAME {
     pub szFileName: [u16; 1],
 }
@@ -10549,7 +10674,8 @@ impl Default for CLUSTER_VALIDATE_CSV_FILENAME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUSTER_VALIDATE_DIRECTORY {
     pub szPath: [u16; 1],
 }
@@ -10562,7 +10688,8 @@ impl Default for CLUSTER_VALIDATE_DIRECTORY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUSTER_VALIDATE_NETNAME {
     pub szNetworkName: [u16; 1],
 }
@@ -10575,7 +10702,8 @@ impl Default for CLUSTER_VALIDATE_NETNAME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUSTER_VALIDATE_PATH {
     pub szPath: [u16; 1],
 }
@@ -10588,7 +10716,8 @@ impl Default for CLUSTER_VALIDATE_PATH {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_CHKDSK_INFO {
     pub PartitionNumber: u32,
     pub ChkdskState: u32,
@@ -10604,7 +10733,8 @@ impl Default for CLUS_CHKDSK_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_CREATE_INFRASTRUCTURE_FILESERVER_INPUT {
     pub FileServerName: [u16; 16],
 }
@@ -10617,7 +10747,8 @@ impl Default for CLUS_CREATE_INFRASTRUCTURE_FILESERVER_INPUT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_CREATE_INFRASTRUCTURE_FILESERVER_OUTPUT {
     pub FileServerName: [u16; 260],
 }
@@ -10630,7 +10761,8 @@ impl Default for CLUS_CREATE_INFRASTRUCTURE_FILESERVER_OUTPUT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_CSV_MAINTENANCE_MODE_INFO {
     pub InMaintenance: super::super::Foundation::BOOL,
     pub VolumeName: [u16; 260],
@@ -10644,7 +10776,8 @@ impl Default for CLUS_CSV_MAINTENANCE_MODE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_CSV_VOLUME_INFO {
     pub VolumeOffset: u64,
     pub PartitionNumber: u32,
@@ -10662,7 +10795,8 @@ impl Default for CLUS_CSV_VOLUME_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_CSV_VOLUME_NAME {
     pub VolumeOffset: i64,
     pub szVolumeName: [u16; 260],
@@ -10677,7 +10811,8 @@ impl Default for CLUS_CSV_VOLUME_NAME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_DISK_NUMBER_INFO {
     pub DiskNumber: u32,
     pub BytesPerSector: u32,
@@ -10691,7 +10826,8 @@ impl Default for CLUS_DISK_NUMBER_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_DNN_LEADER_STATUS {
     pub IsOnline: super::super::Foundation::BOOL,
     pub IsFileServerPresent: super::super::Foundation::BOOL,
@@ -10705,7 +10841,8 @@ impl Default for CLUS_DNN_LEADER_STATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_DNN_SODAFS_CLONE_STATUS {
     pub NodeId: u32,
     pub Status: CLUSTER_RESOURCE_STATE,
@@ -10719,7 +10856,8 @@ impl Default for CLUS_DNN_SODAFS_CLONE_STATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_FORCE_QUORUM_INFO {
     pub dwSize: u32,
     pub dwNodeBitMask: u32,
@@ -10735,7 +10873,8 @@ impl Default for CLUS_FORCE_QUORUM_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_FTSET_INFO {
     pub dwRootSignature: u32,
     pub dwFtType: u32,
@@ -10749,7 +10888,8 @@ impl Default for CLUS_FTSET_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_MAINTENANCE_MODE_INFO {
     pub InMaintenance: super::super::Foundation::BOOL,
 }
@@ -10762,7 +10902,8 @@ impl Default for CLUS_MAINTENANCE_MODE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_MAINTENANCE_MODE_INFOEX {
     pub InMaintenance: super::super::Foundation::BOOL,
     pub MaintainenceModeType: MAINTENANCE_MODE_TYPE_ENUM,
@@ -10778,7 +10919,8 @@ impl Default for CLUS_MAINTENANCE_MODE_INFOEX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_NETNAME_IP_INFO_ENTRY {
     pub NodeId: u32,
     pub AddressSize: u32,
@@ -10793,7 +10935,8 @@ impl Default for CLUS_NETNAME_IP_INFO_ENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_NETNAME_IP_INFO_FOR_MULTICHANNEL {
     pub szName: [u16; 64],
     pub NumEntries: u32,
@@ -10808,7 +10951,8 @@ impl Default for CLUS_NETNAME_IP_INFO_FOR_MULTICHANNEL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_NETNAME_PWD_INFO {
     pub Flags: u32,
     pub Password: [u16; 16],
@@ -10824,7 +10968,8 @@ impl Default for CLUS_NETNAME_PWD_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_NETNAME_PWD_INFOEX {
     pub Flags: u32,
     pub Password: [u16; 128],
@@ -10840,7 +10985,8 @@ impl Default for CLUS_NETNAME_PWD_INFOEX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_NETNAME_VS_TOKEN_INFO {
     pub ProcessID: u32,
     pub DesiredAccess: u32,
@@ -10855,7 +11001,8 @@ impl Default for CLUS_NETNAME_VS_TOKEN_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_PARTITION_INFO {
     pub dwFlags: u32,
     pub szDeviceName: [u16; 260],
@@ -10874,7 +11021,8 @@ impl Default for CLUS_PARTITION_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_PARTITION_INFO_EX {
     pub dwFlags: u32,
     pub szDeviceName: [u16; 260],
@@ -10898,7 +11046,8 @@ impl Default for CLUS_PARTITION_INFO_EX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_PARTITION_INFO_EX2 {
     pub GptPartitionId: windows_core::GUID,
     pub szPartitionName: [u16; 260],
@@ -10913,7 +11062,8 @@ impl Default for CLUS_PARTITION_INFO_EX2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_PROVIDER_STATE_CHANGE_INFO {
     pub dwSize: u32,
     pub resourceState: CLUSTER_RESOURCE_STATE,
@@ -11010,7 +11160,8 @@ impl Default for CLUS_SCSI_ADDRESS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_SCSI_ADDRESS_0_0 {
     pub PortNumber: u8,
     pub PathId: u8,
@@ -11026,7 +11177,8 @@ impl Default for CLUS_SCSI_ADDRESS_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_SET_MAINTENANCE_MODE_INPUT {
     pub InMaintenance: super::super::Foundation::BOOL,
     pub ExtraParameterSize: u32,
@@ -11041,7 +11193,8 @@ impl Default for CLUS_SET_MAINTENANCE_MODE_INPUT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_SHARED_VOLUME_BACKUP_MODE {
     pub BackupState: CLUSTER_SHARED_VOLUME_BACKUP_STATE,
     pub DelayTimerInSecs: u32,
@@ -11056,7 +11209,8 @@ impl Default for CLUS_SHARED_VOLUME_BACKUP_MODE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_STARTING_PARAMS {
     pub dwSize: u32,
     pub bForm: super::super::Foundation::BOOL,
@@ -11071,7 +11225,8 @@ impl Default for CLUS_STARTING_PARAMS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_STORAGE_GET_AVAILABLE_DRIVELETTERS {
     pub AvailDrivelettersMask: u32,
 }
@@ -11084,7 +11239,8 @@ impl Default for CLUS_STORAGE_GET_AVAILABLE_DRIVELETTERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_STORAGE_REMAP_DRIVELETTER {
     pub CurrentDriveLetterMask: u32,
     pub TargetDriveLetterMask: u32,
@@ -11098,7 +11254,8 @@ impl Default for CLUS_STORAGE_REMAP_DRIVELETTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_STORAGE_SET_DRIVELETTER {
     pub PartitionNumber: u32,
     pub DriveLetterMask: u32,
@@ -11112,7 +11269,8 @@ impl Default for CLUS_STORAGE_SET_DRIVELETTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLUS_WORKER {
     pub hThread: super::super::Foundation::HANDLE,
     pub Terminate: super::super::Foundation::BOOL,
@@ -11126,7 +11284,8 @@ impl Default for CLUS_WORKER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CREATE_CLUSTER_CONFIG {
     pub dwVersion: u32,
     pub lpszClusterName: windows_core::PCWSTR,
@@ -11147,7 +11306,8 @@ impl Default for CREATE_CLUSTER_CONFIG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CREATE_CLUSTER_NAME_ACCOUNT {
     pub dwVersion: u32,
     pub lpszClusterName: windows_core::PCWSTR,
@@ -11208,7 +11368,8 @@ pub const Cluster: windows_core::GUID = windows_core::GUID::from_u128(0xf2e606e3
 pub const ClusterNames: windows_core::GUID = windows_core::GUID::from_u128(0xf2e606eb_2631_11d1_89f1_00a0c90d061e);
 pub const DomainNames: windows_core::GUID = windows_core::GUID::from_u128(0xf2e606e1_2631_11d1_89f1_00a0c90d061e);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILESHARE_CHANGE {
     pub Change: FILESHARE_CHANGE_ENUM,
     pub ShareName: [u16; 84],
@@ -11222,7 +11383,8 @@ impl Default for FILESHARE_CHANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILESHARE_CHANGE_LIST {
     pub NumEntries: u32,
     pub ChangeEntry: [FILESHARE_CHANGE; 1],
@@ -11236,7 +11398,8 @@ impl Default for FILESHARE_CHANGE_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GET_OPERATION_CONTEXT_PARAMS {
     pub Size: u32,
     pub Version: u32,
@@ -11252,7 +11415,8 @@ impl Default for GET_OPERATION_CONTEXT_PARAMS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GROUP_FAILURE_INFO {
     pub dwFailoverAttemptsRemaining: u32,
     pub dwFailoverPeriodRemaining: u32,
@@ -11266,7 +11430,8 @@ impl Default for GROUP_FAILURE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GROUP_FAILURE_INFO_BUFFER {
     pub dwVersion: u32,
     pub Info: GROUP_FAILURE_INFO,
@@ -11280,7 +11445,8 @@ impl Default for GROUP_FAILURE_INFO_BUFFER {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HCHANGE(pub isize);
 impl Default for HCHANGE {
     fn default() -> Self {
@@ -11291,7 +11457,8 @@ impl windows_core::TypeKind for HCHANGE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HCLUSCRYPTPROVIDER(pub isize);
 impl Default for HCLUSCRYPTPROVIDER {
     fn default() -> Self {
@@ -11302,7 +11469,8 @@ impl windows_core::TypeKind for HCLUSCRYPTPROVIDER {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HCLUSENUM(pub isize);
 impl Default for HCLUSENUM {
     fn default() -> Self {
@@ -11313,7 +11481,8 @@ impl windows_core::TypeKind for HCLUSENUM {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HCLUSENUMEX(pub isize);
 impl Default for HCLUSENUMEX {
     fn default() -> Self {
@@ -11324,7 +11493,8 @@ impl windows_core::TypeKind for HCLUSENUMEX {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HCLUSTER(pub isize);
 impl Default for HCLUSTER {
     fn default() -> Self {
@@ -11335,7 +11505,8 @@ impl windows_core::TypeKind for HCLUSTER {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HGROUP(pub isize);
 impl Default for HGROUP {
     fn default() -> Self {
@@ -11346,7 +11517,8 @@ impl windows_core::TypeKind for HGROUP {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HGROUPENUM(pub isize);
 impl Default for HGROUPENUM {
     fn default() -> Self {
@@ -11357,7 +11529,8 @@ impl windows_core::TypeKind for HGROUPENUM {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HGROUPENUMEX(pub isize);
 impl Default for HGROUPENUMEX {
     fn default() -> Self {
@@ -11368,7 +11541,8 @@ impl windows_core::TypeKind for HGROUPENUMEX {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HGROUPSET(pub isize);
 impl Default for HGROUPSET {
     fn default() -> Self {
@@ -11379,7 +11553,8 @@ impl windows_core::TypeKind for HGROUPSET {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HGROUPSETENUM(pub isize);
 impl Default for HGROUPSETENUM {
     fn default() -> Self {
@@ -11390,7 +11565,8 @@ impl windows_core::TypeKind for HGROUPSETENUM {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HNETINTERFACE(pub isize);
 impl Default for HNETINTERFACE {
     fn default() -> Self {
@@ -11401,7 +11577,8 @@ impl windows_core::TypeKind for HNETINTERFACE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HNETINTERFACEENUM(pub isize);
 impl Default for HNETINTERFACEENUM {
     fn default() -> Self {
@@ -11412,7 +11589,8 @@ impl windows_core::TypeKind for HNETINTERFACEENUM {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HNETWORK(pub isize);
 impl Default for HNETWORK {
     fn default() -> Self {
@@ -11423,7 +11601,8 @@ impl windows_core::TypeKind for HNETWORK {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HNETWORKENUM(pub isize);
 impl Default for HNETWORKENUM {
     fn default() -> Self {
@@ -11434,7 +11613,8 @@ impl windows_core::TypeKind for HNETWORKENUM {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HNODE(pub isize);
 impl Default for HNODE {
     fn default() -> Self {
@@ -11445,7 +11625,8 @@ impl windows_core::TypeKind for HNODE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HNODEENUM(pub isize);
 impl Default for HNODEENUM {
     fn default() -> Self {
@@ -11456,7 +11637,8 @@ impl windows_core::TypeKind for HNODEENUM {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HNODEENUMEX(pub isize);
 impl Default for HNODEENUMEX {
     fn default() -> Self {
@@ -11467,7 +11649,8 @@ impl windows_core::TypeKind for HNODEENUMEX {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HREGBATCH(pub isize);
 impl Default for HREGBATCH {
     fn default() -> Self {
@@ -11478,7 +11661,8 @@ impl windows_core::TypeKind for HREGBATCH {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HREGBATCHNOTIFICATION(pub isize);
 impl Default for HREGBATCHNOTIFICATION {
     fn default() -> Self {
@@ -11489,7 +11673,8 @@ impl windows_core::TypeKind for HREGBATCHNOTIFICATION {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HREGBATCHPORT(pub isize);
 impl Default for HREGBATCHPORT {
     fn default() -> Self {
@@ -11500,7 +11685,8 @@ impl windows_core::TypeKind for HREGBATCHPORT {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HREGREADBATCH(pub isize);
 impl Default for HREGREADBATCH {
     fn default() -> Self {
@@ -11511,7 +11697,8 @@ impl windows_core::TypeKind for HREGREADBATCH {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HREGREADBATCHREPLY(pub isize);
 impl Default for HREGREADBATCHREPLY {
     fn default() -> Self {
@@ -11522,7 +11709,8 @@ impl windows_core::TypeKind for HREGREADBATCHREPLY {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HRESENUM(pub isize);
 impl Default for HRESENUM {
     fn default() -> Self {
@@ -11533,7 +11721,8 @@ impl windows_core::TypeKind for HRESENUM {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HRESENUMEX(pub isize);
 impl Default for HRESENUMEX {
     fn default() -> Self {
@@ -11544,7 +11733,8 @@ impl windows_core::TypeKind for HRESENUMEX {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HRESOURCE(pub isize);
 impl Default for HRESOURCE {
     fn default() -> Self {
@@ -11555,7 +11745,8 @@ impl windows_core::TypeKind for HRESOURCE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HRESTYPEENUM(pub isize);
 impl Default for HRESTYPEENUM {
     fn default() -> Self {
@@ -11566,7 +11757,8 @@ impl windows_core::TypeKind for HRESTYPEENUM {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MONITOR_STATE {
     pub LastUpdate: i64,
     pub State: RESOURCE_MONITOR_STATE,
@@ -11582,7 +11774,8 @@ impl Default for MONITOR_STATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NOTIFY_FILTER_AND_TYPE {
     pub dwObjectType: u32,
     pub FilterFlags: i64,
@@ -11596,7 +11789,8 @@ impl Default for NOTIFY_FILTER_AND_TYPE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NodeUtilizationInfoElement {
     pub Id: u64,
     pub AvailableMemory: u64,
@@ -11611,7 +11805,8 @@ impl Default for NodeUtilizationInfoElement {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POST_UPGRADE_VERSION_INFO {
     pub newMajorVersion: u32,
     pub newUpgradeVersion: u32,
@@ -11628,7 +11823,8 @@ impl Default for POST_UPGRADE_VERSION_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PaxosTagCStruct {
     pub __padding__PaxosTagVtable: u64,
     pub __padding__NextEpochVtable: u64,
@@ -11653,7 +11849,8 @@ impl Default for PaxosTagCStruct {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RESOURCE_FAILURE_INFO {
     pub dwRestartAttemptsRemaining: u32,
     pub dwRestartPeriodRemaining: u32,
@@ -11667,7 +11864,8 @@ impl Default for RESOURCE_FAILURE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RESOURCE_FAILURE_INFO_BUFFER {
     pub dwVersion: u32,
     pub Info: RESOURCE_FAILURE_INFO,
@@ -11681,7 +11879,8 @@ impl Default for RESOURCE_FAILURE_INFO_BUFFER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RESOURCE_STATUS {
     pub ResourceState: CLUSTER_RESOURCE_STATE,
     pub CheckPoint: u32,
@@ -11697,7 +11896,8 @@ impl Default for RESOURCE_STATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RESOURCE_STATUS_EX {
     pub ResourceState: CLUSTER_RESOURCE_STATE,
     pub CheckPoint: u32,
@@ -11715,7 +11915,8 @@ impl Default for RESOURCE_STATUS_EX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RESOURCE_TERMINAL_FAILURE_INFO_BUFFER {
     pub isTerminalFailure: super::super::Foundation::BOOL,
     pub restartPeriodRemaining: u32,
@@ -11729,7 +11930,8 @@ impl Default for RESOURCE_TERMINAL_FAILURE_INFO_BUFFER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RESUTIL_FILETIME_DATA {
     pub Default: super::super::Foundation::FILETIME,
     pub Minimum: super::super::Foundation::FILETIME,
@@ -11744,7 +11946,8 @@ impl Default for RESUTIL_FILETIME_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RESUTIL_LARGEINT_DATA {
     pub Default: i64,
     pub Minimum: i64,
@@ -11797,7 +12000,8 @@ impl Default for RESUTIL_PROPERTY_ITEM_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RESUTIL_ULARGEINT_DATA {
     pub Default: u64,
     pub Minimum: u64,
@@ -11812,7 +12016,8 @@ impl Default for RESUTIL_ULARGEINT_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ResourceUtilizationInfoElement {
     pub PhysicalNumaId: u64,
     pub CurrentMemory: u64,
@@ -11826,7 +12031,8 @@ impl Default for ResourceUtilizationInfoElement {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SR_RESOURCE_TYPE_ADD_REPLICATION_GROUP {
     pub ReplicationGroupName: [u16; 260],
     pub Description: [u16; 260],
@@ -11851,7 +12057,8 @@ impl Default for SR_RESOURCE_TYPE_ADD_REPLICATION_GROUP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SR_RESOURCE_TYPE_ADD_REPLICATION_GROUP_RESULT {
     pub Result: u32,
     pub ErrorString: [u16; 260],
@@ -11865,7 +12072,8 @@ impl Default for SR_RESOURCE_TYPE_ADD_REPLICATION_GROUP_RESULT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SR_RESOURCE_TYPE_DISK_INFO {
     pub Reason: SR_DISK_REPLICATION_ELIGIBLE,
     pub DiskGuid: windows_core::GUID,
@@ -11879,7 +12087,8 @@ impl Default for SR_RESOURCE_TYPE_DISK_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SR_RESOURCE_TYPE_ELIGIBLE_DISKS_RESULT {
     pub Count: u16,
     pub DiskInfo: [SR_RESOURCE_TYPE_DISK_INFO; 1],
@@ -11893,7 +12102,8 @@ impl Default for SR_RESOURCE_TYPE_ELIGIBLE_DISKS_RESULT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SR_RESOURCE_TYPE_QUERY_ELIGIBLE_LOGDISKS {
     pub DataDiskGuid: windows_core::GUID,
     pub IncludeOfflineDisks: super::super::Foundation::BOOLEAN,
@@ -11907,7 +12117,8 @@ impl Default for SR_RESOURCE_TYPE_QUERY_ELIGIBLE_LOGDISKS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SR_RESOURCE_TYPE_QUERY_ELIGIBLE_SOURCE_DATADISKS {
     pub DataDiskGuid: windows_core::GUID,
     pub IncludeAvailableStoargeDisks: super::super::Foundation::BOOLEAN,
@@ -11921,7 +12132,8 @@ impl Default for SR_RESOURCE_TYPE_QUERY_ELIGIBLE_SOURCE_DATADISKS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SR_RESOURCE_TYPE_QUERY_ELIGIBLE_TARGET_DATADISKS {
     pub SourceDataDiskGuid: windows_core::GUID,
     pub TargetReplicationGroupGuid: windows_core::GUID,
@@ -11937,7 +12149,8 @@ impl Default for SR_RESOURCE_TYPE_QUERY_ELIGIBLE_TARGET_DATADISKS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SR_RESOURCE_TYPE_REPLICATED_DISK {
     pub Type: SR_REPLICATED_DISK_TYPE,
     pub ClusterDiskResourceGuid: windows_core::GUID,
@@ -11953,7 +12166,8 @@ impl Default for SR_RESOURCE_TYPE_REPLICATED_DISK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SR_RESOURCE_TYPE_REPLICATED_DISKS_RESULT {
     pub Count: u16,
     pub ReplicatedDisks: [SR_RESOURCE_TYPE_REPLICATED_DISK; 1],
@@ -11967,7 +12181,8 @@ impl Default for SR_RESOURCE_TYPE_REPLICATED_DISKS_RESULT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SR_RESOURCE_TYPE_REPLICATED_PARTITION_ARRAY {
     pub Count: u32,
     pub PartitionArray: [SR_RESOURCE_TYPE_REPLICATED_PARTITION_INFO; 1],
@@ -11981,7 +12196,8 @@ impl Default for SR_RESOURCE_TYPE_REPLICATED_PARTITION_ARRAY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SR_RESOURCE_TYPE_REPLICATED_PARTITION_INFO {
     pub PartitionOffset: u64,
     pub Capabilities: u32,
@@ -11995,7 +12211,8 @@ impl Default for SR_RESOURCE_TYPE_REPLICATED_PARTITION_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WitnessTagHelper {
     pub Version: i32,
     pub paxosToValidate: PaxosTagCStruct,
@@ -12009,7 +12226,8 @@ impl Default for WitnessTagHelper {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WitnessTagUpdateHelper {
     pub Version: i32,
     pub paxosToSet: PaxosTagCStruct,
@@ -12487,3 +12705,5 @@ pub type PWORKER_START_ROUTINE = Option<unsafe extern "system" fn(pworker: *mut
 pub type SET_APP_INSTANCE_CSV_FLAGS = Option<unsafe extern "system" fn(processhandle: super::super::Foundation::HANDLE, mask: u32, flags: u32) -> u32>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
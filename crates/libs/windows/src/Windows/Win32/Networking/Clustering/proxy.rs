@@ -0,0 +1,2329 @@
+impl windows_core::imp::ProxyVtable for IGetClusterDataInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetClusterName(this: *mut core::ffi::c_void, lpszname: core::mem::MaybeUninit<windows_core::BSTR>, pcchname: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetClusterDataInfo", "GetClusterName", 3usize, |this| ((**(this as *const *const IGetClusterDataInfo_Vtbl)).GetClusterName)(this, lpszname, pcchname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClusterHandle(this: *mut core::ffi::c_void) -> HCLUSTER {
+            windows_core::imp::proxy_call(this, "IGetClusterDataInfo", "GetClusterHandle", 4usize, |this| ((**(this as *const *const IGetClusterDataInfo_Vtbl)).GetClusterHandle)(this), |_| None)
+        }
+        unsafe extern "system" fn GetObjectCount(this: *mut core::ffi::c_void) -> i32 {
+            windows_core::imp::proxy_call(this, "IGetClusterDataInfo", "GetObjectCount", 5usize, |this| ((**(this as *const *const IGetClusterDataInfo_Vtbl)).GetObjectCount)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetClusterName, GetClusterHandle, GetObjectCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGetClusterDataInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGetClusterGroupInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetGroupHandle(this: *mut core::ffi::c_void, lobjindex: i32) -> HGROUP {
+            windows_core::imp::proxy_call(this, "IGetClusterGroupInfo", "GetGroupHandle", 3usize, |this| ((**(this as *const *const IGetClusterGroupInfo_Vtbl)).GetGroupHandle)(this, lobjindex), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetGroupHandle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGetClusterGroupInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGetClusterNetInterfaceInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNetInterfaceHandle(this: *mut core::ffi::c_void, lobjindex: i32) -> HNETINTERFACE {
+            windows_core::imp::proxy_call(this, "IGetClusterNetInterfaceInfo", "GetNetInterfaceHandle", 3usize, |this| ((**(this as *const *const IGetClusterNetInterfaceInfo_Vtbl)).GetNetInterfaceHandle)(this, lobjindex), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetNetInterfaceHandle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGetClusterNetInterfaceInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGetClusterNetworkInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNetworkHandle(this: *mut core::ffi::c_void, lobjindex: i32) -> HNETWORK {
+            windows_core::imp::proxy_call(this, "IGetClusterNetworkInfo", "GetNetworkHandle", 3usize, |this| ((**(this as *const *const IGetClusterNetworkInfo_Vtbl)).GetNetworkHandle)(this, lobjindex), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetNetworkHandle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGetClusterNetworkInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGetClusterNodeInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNodeHandle(this: *mut core::ffi::c_void, lobjindex: i32) -> HNODE {
+            windows_core::imp::proxy_call(this, "IGetClusterNodeInfo", "GetNodeHandle", 3usize, |this| ((**(this as *const *const IGetClusterNodeInfo_Vtbl)).GetNodeHandle)(this, lobjindex), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetNodeHandle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGetClusterNodeInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGetClusterObjectInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetObjectName(this: *mut core::ffi::c_void, lobjindex: i32, lpszname: core::mem::MaybeUninit<windows_core::BSTR>, pcchname: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetClusterObjectInfo", "GetObjectName", 3usize, |this| ((**(this as *const *const IGetClusterObjectInfo_Vtbl)).GetObjectName)(this, lobjindex, lpszname, pcchname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObjectType(this: *mut core::ffi::c_void, lobjindex: i32) -> CLUADMEX_OBJECT_TYPE {
+            windows_core::imp::proxy_call(this, "IGetClusterObjectInfo", "GetObjectType", 4usize, |this| ((**(this as *const *const IGetClusterObjectInfo_Vtbl)).GetObjectType)(this, lobjindex), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetObjectName, GetObjectType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGetClusterObjectInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGetClusterResourceInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetResourceHandle(this: *mut core::ffi::c_void, lobjindex: i32) -> HRESOURCE {
+            windows_core::imp::proxy_call(this, "IGetClusterResourceInfo", "GetResourceHandle", 3usize, |this| ((**(this as *const *const IGetClusterResourceInfo_Vtbl)).GetResourceHandle)(this, lobjindex), |_| None)
+        }
+        unsafe extern "system" fn GetResourceTypeName(this: *mut core::ffi::c_void, lobjindex: i32, lpszrestypename: core::mem::MaybeUninit<windows_core::BSTR>, pcchrestypename: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetClusterResourceInfo", "GetResourceTypeName", 4usize, |this| ((**(this as *const *const IGetClusterResourceInfo_Vtbl)).GetResourceTypeName)(this, lobjindex, lpszrestypename, pcchrestypename), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResourceNetworkName(this: *mut core::ffi::c_void, lobjindex: i32, lpsznetname: core::mem::MaybeUninit<windows_core::BSTR>, pcchnetname: *mut u32) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IGetClusterResourceInfo", "GetResourceNetworkName", 5usize, |this| ((**(this as *const *const IGetClusterResourceInfo_Vtbl)).GetResourceNetworkName)(this, lobjindex, lpsznetname, pcchnetname), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetResourceHandle, GetResourceTypeName, GetResourceNetworkName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGetClusterResourceInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+impl windows_core::imp::ProxyVtable for IGetClusterUIInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetClusterName(this: *mut core::ffi::c_void, lpszname: core::mem::MaybeUninit<windows_core::BSTR>, pcchname: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetClusterUIInfo", "GetClusterName", 3usize, |this| ((**(this as *const *const IGetClusterUIInfo_Vtbl)).GetClusterName)(this, lpszname, pcchname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocale(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IGetClusterUIInfo", "GetLocale", 4usize, |this| ((**(this as *const *const IGetClusterUIInfo_Vtbl)).GetLocale)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetFont(this: *mut core::ffi::c_void) -> super::super::Graphics::Gdi::HFONT {
+            windows_core::imp::proxy_call(this, "IGetClusterUIInfo", "GetFont", 5usize, |this| ((**(this as *const *const IGetClusterUIInfo_Vtbl)).GetFont)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn GetIcon(this: *mut core::ffi::c_void) -> super::super::UI::WindowsAndMessaging::HICON {
+            windows_core::imp::proxy_call(this, "IGetClusterUIInfo", "GetIcon", 6usize, |this| ((**(this as *const *const IGetClusterUIInfo_Vtbl)).GetIcon)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetClusterName,
+            GetLocale,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetFont,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetFont: 0,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            GetIcon,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            GetIcon: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGetClusterUIInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusApplication_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DomainNames(this: *mut core::ffi::c_void, ppdomains: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusApplication", "DomainNames", 7usize, |this| ((**(this as *const *const ISClusApplication_Vtbl)).DomainNames)(this, ppdomains), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ClusterNames(this: *mut core::ffi::c_void, bstrdomainname: core::mem::MaybeUninit<windows_core::BSTR>, ppclusters: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusApplication", "get_ClusterNames", 8usize, |this| ((**(this as *const *const ISClusApplication_Vtbl)).get_ClusterNames)(this, bstrdomainname, ppclusters), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OpenCluster(this: *mut core::ffi::c_void, bstrclustername: core::mem::MaybeUninit<windows_core::BSTR>, pcluster: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusApplication", "OpenCluster", 9usize, |this| ((**(this as *const *const ISClusApplication_Vtbl)).OpenCluster)(this, bstrclustername, pcluster), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            DomainNames,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DomainNames: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ClusterNames,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ClusterNames: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OpenCluster,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OpenCluster: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusApplication as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusCryptoKeys_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusCryptoKeys", "Count", 7usize, |this| ((**(this as *const *const ISClusCryptoKeys_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusCryptoKeys", "_NewEnum", 8usize, |this| ((**(this as *const *const ISClusCryptoKeys_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusCryptoKeys", "Refresh", 9usize, |this| ((**(this as *const *const ISClusCryptoKeys_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>, pbstrcyrptokey: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusCryptoKeys", "get_Item", 10usize, |this| ((**(this as *const *const ISClusCryptoKeys_Vtbl)).get_Item)(this, varindex, pbstrcyrptokey), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddItem(this: *mut core::ffi::c_void, bstrcryptokey: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusCryptoKeys", "AddItem", 11usize, |this| ((**(this as *const *const ISClusCryptoKeys_Vtbl)).AddItem)(this, bstrcryptokey), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveItem(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusCryptoKeys", "RemoveItem", 12usize, |this| ((**(this as *const *const ISClusCryptoKeys_Vtbl)).RemoveItem)(this, varindex), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            Refresh,
+            get_Item,
+            AddItem,
+            RemoveItem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusCryptoKeys as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusDisk_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Signature(this: *mut core::ffi::c_void, plsignature: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusDisk", "Signature", 7usize, |this| ((**(this as *const *const ISClusDisk_Vtbl)).Signature)(this, plsignature), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ScsiAddress(this: *mut core::ffi::c_void, ppscsiaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusDisk", "ScsiAddress", 8usize, |this| ((**(this as *const *const ISClusDisk_Vtbl)).ScsiAddress)(this, ppscsiaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn DiskNumber(this: *mut core::ffi::c_void, pldisknumber: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusDisk", "DiskNumber", 9usize, |this| ((**(this as *const *const ISClusDisk_Vtbl)).DiskNumber)(this, pldisknumber), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Partitions(this: *mut core::ffi::c_void, pppartitions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusDisk", "Partitions", 10usize, |this| ((**(this as *const *const ISClusDisk_Vtbl)).Partitions)(this, pppartitions), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Signature,
+            #[cfg(feature = "Win32_System_Com")]
+            ScsiAddress,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ScsiAddress: 0,
+            DiskNumber,
+            #[cfg(feature = "Win32_System_Com")]
+            Partitions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Partitions: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusDisk as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusDisks_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusDisks", "Count", 7usize, |this| ((**(this as *const *const ISClusDisks_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusDisks", "_NewEnum", 8usize, |this| ((**(this as *const *const ISClusDisks_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>, ppdisk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusDisks", "get_Item", 9usize, |this| ((**(this as *const *const ISClusDisks_Vtbl)).get_Item)(this, varindex, ppdisk), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusDisks as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusNetInterface_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CommonProperties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetInterface", "CommonProperties", 7usize, |this| ((**(this as *const *const ISClusNetInterface_Vtbl)).CommonProperties)(this, ppproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PrivateProperties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetInterface", "PrivateProperties", 8usize, |this| ((**(this as *const *const ISClusNetInterface_Vtbl)).PrivateProperties)(this, ppproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CommonROProperties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetInterface", "CommonROProperties", 9usize, |this| ((**(this as *const *const ISClusNetInterface_Vtbl)).CommonROProperties)(this, ppproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PrivateROProperties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetInterface", "PrivateROProperties", 10usize, |this| ((**(this as *const *const ISClusNetInterface_Vtbl)).PrivateROProperties)(this, ppproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetInterface", "Name", 11usize, |this| ((**(this as *const *const ISClusNetInterface_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Handle(this: *mut core::ffi::c_void, phandle: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetInterface", "Handle", 12usize, |this| ((**(this as *const *const ISClusNetInterface_Vtbl)).Handle)(this, phandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, dwstate: *mut CLUSTER_NETINTERFACE_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetInterface", "State", 13usize, |this| ((**(this as *const *const ISClusNetInterface_Vtbl)).State)(this, dwstate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Cluster(this: *mut core::ffi::c_void, ppcluster: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetInterface", "Cluster", 14usize, |this| ((**(this as *const *const ISClusNetInterface_Vtbl)).Cluster)(this, ppcluster), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CommonProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CommonProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PrivateProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PrivateProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CommonROProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CommonROProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PrivateROProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PrivateROProperties: 0,
+            Name,
+            Handle,
+            State,
+            #[cfg(feature = "Win32_System_Com")]
+            Cluster,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Cluster: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusNetInterface as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusNetInterfaces_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetInterfaces", "Count", 7usize, |this| ((**(this as *const *const ISClusNetInterfaces_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetInterfaces", "_NewEnum", 8usize, |this| ((**(this as *const *const ISClusNetInterfaces_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetInterfaces", "Refresh", 9usize, |this| ((**(this as *const *const ISClusNetInterfaces_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>, ppclusnetinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetInterfaces", "get_Item", 10usize, |this| ((**(this as *const *const ISClusNetInterfaces_Vtbl)).get_Item)(this, varindex, ppclusnetinterface), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            Refresh,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusNetInterfaces as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusNetwork_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CommonProperties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetwork", "CommonProperties", 7usize, |this| ((**(this as *const *const ISClusNetwork_Vtbl)).CommonProperties)(this, ppproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PrivateProperties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetwork", "PrivateProperties", 8usize, |this| ((**(this as *const *const ISClusNetwork_Vtbl)).PrivateProperties)(this, ppproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CommonROProperties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetwork", "CommonROProperties", 9usize, |this| ((**(this as *const *const ISClusNetwork_Vtbl)).CommonROProperties)(this, ppproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PrivateROProperties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetwork", "PrivateROProperties", 10usize, |this| ((**(this as *const *const ISClusNetwork_Vtbl)).PrivateROProperties)(this, ppproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn Handle(this: *mut core::ffi::c_void, phandle: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetwork", "Handle", 11usize, |this| ((**(this as *const *const ISClusNetwork_Vtbl)).Handle)(this, phandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetwork", "Name", 12usize, |this| ((**(this as *const *const ISClusNetwork_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, bstrnetworkname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetwork", "SetName", 13usize, |this| ((**(this as *const *const ISClusNetwork_Vtbl)).SetName)(this, bstrnetworkname), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkID(this: *mut core::ffi::c_void, pbstrnetworkid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetwork", "NetworkID", 14usize, |this| ((**(this as *const *const ISClusNetwork_Vtbl)).NetworkID)(this, pbstrnetworkid), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, dwstate: *mut CLUSTER_NETWORK_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetwork", "State", 15usize, |this| ((**(this as *const *const ISClusNetwork_Vtbl)).State)(this, dwstate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn NetInterfaces(this: *mut core::ffi::c_void, ppclusnetinterfaces: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetwork", "NetInterfaces", 16usize, |this| ((**(this as *const *const ISClusNetwork_Vtbl)).NetInterfaces)(this, ppclusnetinterfaces), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Cluster(this: *mut core::ffi::c_void, ppcluster: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetwork", "Cluster", 17usize, |this| ((**(this as *const *const ISClusNetwork_Vtbl)).Cluster)(this, ppcluster), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CommonProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CommonProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PrivateProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PrivateProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CommonROProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CommonROProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PrivateROProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PrivateROProperties: 0,
+            Handle,
+            Name,
+            SetName,
+            NetworkID,
+            State,
+            #[cfg(feature = "Win32_System_Com")]
+            NetInterfaces,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            NetInterfaces: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Cluster,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Cluster: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusNetwork as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusNetworkNetInterfaces_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetworkNetInterfaces", "Count", 7usize, |this| ((**(this as *const *const ISClusNetworkNetInterfaces_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetworkNetInterfaces", "_NewEnum", 8usize, |this| ((**(this as *const *const ISClusNetworkNetInterfaces_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetworkNetInterfaces", "Refresh", 9usize, |this| ((**(this as *const *const ISClusNetworkNetInterfaces_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>, ppclusnetinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetworkNetInterfaces", "get_Item", 10usize, |this| ((**(this as *const *const ISClusNetworkNetInterfaces_Vtbl)).get_Item)(this, varindex, ppclusnetinterface), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            Refresh,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusNetworkNetInterfaces as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusNetworks_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetworks", "Count", 7usize, |this| ((**(this as *const *const ISClusNetworks_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetworks", "_NewEnum", 8usize, |this| ((**(this as *const *const ISClusNetworks_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetworks", "Refresh", 9usize, |this| ((**(this as *const *const ISClusNetworks_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>, ppclusnetwork: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNetworks", "get_Item", 10usize, |this| ((**(this as *const *const ISClusNetworks_Vtbl)).get_Item)(this, varindex, ppclusnetwork), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            Refresh,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusNetworks as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusNode_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CommonProperties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNode", "CommonProperties", 7usize, |this| ((**(this as *const *const ISClusNode_Vtbl)).CommonProperties)(this, ppproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PrivateProperties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNode", "PrivateProperties", 8usize, |this| ((**(this as *const *const ISClusNode_Vtbl)).PrivateProperties)(this, ppproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CommonROProperties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNode", "CommonROProperties", 9usize, |this| ((**(this as *const *const ISClusNode_Vtbl)).CommonROProperties)(this, ppproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PrivateROProperties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNode", "PrivateROProperties", 10usize, |this| ((**(this as *const *const ISClusNode_Vtbl)).PrivateROProperties)(this, ppproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNode", "Name", 11usize, |this| ((**(this as *const *const ISClusNode_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Handle(this: *mut core::ffi::c_void, phandle: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNode", "Handle", 12usize, |this| ((**(this as *const *const ISClusNode_Vtbl)).Handle)(this, phandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn NodeID(this: *mut core::ffi::c_void, pbstrnodeid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNode", "NodeID", 13usize, |this| ((**(this as *const *const ISClusNode_Vtbl)).NodeID)(this, pbstrnodeid), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, dwstate: *mut CLUSTER_NODE_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNode", "State", 14usize, |this| ((**(this as *const *const ISClusNode_Vtbl)).State)(this, dwstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNode", "Pause", 15usize, |this| ((**(this as *const *const ISClusNode_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNode", "Resume", 16usize, |this| ((**(this as *const *const ISClusNode_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Evict(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNode", "Evict", 17usize, |this| ((**(this as *const *const ISClusNode_Vtbl)).Evict)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ResourceGroups(this: *mut core::ffi::c_void, ppresourcegroups: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNode", "ResourceGroups", 18usize, |this| ((**(this as *const *const ISClusNode_Vtbl)).ResourceGroups)(this, ppresourcegroups), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Cluster(this: *mut core::ffi::c_void, ppcluster: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNode", "Cluster", 19usize, |this| ((**(this as *const *const ISClusNode_Vtbl)).Cluster)(this, ppcluster), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn NetInterfaces(this: *mut core::ffi::c_void, ppclusnetinterfaces: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNode", "NetInterfaces", 20usize, |this| ((**(this as *const *const ISClusNode_Vtbl)).NetInterfaces)(this, ppclusnetinterfaces), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CommonProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CommonProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PrivateProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PrivateProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CommonROProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CommonROProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PrivateROProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PrivateROProperties: 0,
+            Name,
+            Handle,
+            NodeID,
+            State,
+            Pause,
+            Resume,
+            Evict,
+            #[cfg(feature = "Win32_System_Com")]
+            ResourceGroups,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ResourceGroups: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Cluster,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Cluster: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            NetInterfaces,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            NetInterfaces: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusNode as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusNodeNetInterfaces_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNodeNetInterfaces", "Count", 7usize, |this| ((**(this as *const *const ISClusNodeNetInterfaces_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNodeNetInterfaces", "_NewEnum", 8usize, |this| ((**(this as *const *const ISClusNodeNetInterfaces_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNodeNetInterfaces", "Refresh", 9usize, |this| ((**(this as *const *const ISClusNodeNetInterfaces_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>, ppclusnetinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNodeNetInterfaces", "get_Item", 10usize, |this| ((**(this as *const *const ISClusNodeNetInterfaces_Vtbl)).get_Item)(this, varindex, ppclusnetinterface), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            Refresh,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusNodeNetInterfaces as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusNodes_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNodes", "Count", 7usize, |this| ((**(this as *const *const ISClusNodes_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNodes", "_NewEnum", 8usize, |this| ((**(this as *const *const ISClusNodes_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNodes", "Refresh", 9usize, |this| ((**(this as *const *const ISClusNodes_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>, ppnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusNodes", "get_Item", 10usize, |this| ((**(this as *const *const ISClusNodes_Vtbl)).get_Item)(this, varindex, ppnode), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            Refresh,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusNodes as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusPartition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Flags(this: *mut core::ffi::c_void, plflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPartition", "Flags", 7usize, |this| ((**(this as *const *const ISClusPartition_Vtbl)).Flags)(this, plflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceName(this: *mut core::ffi::c_void, pbstrdevicename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPartition", "DeviceName", 8usize, |this| ((**(this as *const *const ISClusPartition_Vtbl)).DeviceName)(this, pbstrdevicename), |result| Some(*result))
+        }
+        unsafe extern "system" fn VolumeLabel(this: *mut core::ffi::c_void, pbstrvolumelabel: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPartition", "VolumeLabel", 9usize, |this| ((**(this as *const *const ISClusPartition_Vtbl)).VolumeLabel)(this, pbstrvolumelabel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SerialNumber(this: *mut core::ffi::c_void, plserialnumber: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPartition", "SerialNumber", 10usize, |this| ((**(this as *const *const ISClusPartition_Vtbl)).SerialNumber)(this, plserialnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaximumComponentLength(this: *mut core::ffi::c_void, plmaximumcomponentlength: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPartition", "MaximumComponentLength", 11usize, |this| ((**(this as *const *const ISClusPartition_Vtbl)).MaximumComponentLength)(this, plmaximumcomponentlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn FileSystemFlags(this: *mut core::ffi::c_void, plfilesystemflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPartition", "FileSystemFlags", 12usize, |this| ((**(this as *const *const ISClusPartition_Vtbl)).FileSystemFlags)(this, plfilesystemflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn FileSystem(this: *mut core::ffi::c_void, pbstrfilesystem: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPartition", "FileSystem", 13usize, |this| ((**(this as *const *const ISClusPartition_Vtbl)).FileSystem)(this, pbstrfilesystem), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Flags,
+            DeviceName,
+            VolumeLabel,
+            SerialNumber,
+            MaximumComponentLength,
+            FileSystemFlags,
+            FileSystem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusPartition as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusPartitionEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TotalSize(this: *mut core::ffi::c_void, pltotalsize: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPartitionEx", "TotalSize", 14usize, |this| ((**(this as *const *const ISClusPartitionEx_Vtbl)).TotalSize)(this, pltotalsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreeSpace(this: *mut core::ffi::c_void, plfreespace: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPartitionEx", "FreeSpace", 15usize, |this| ((**(this as *const *const ISClusPartitionEx_Vtbl)).FreeSpace)(this, plfreespace), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceNumber(this: *mut core::ffi::c_void, pldevicenumber: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPartitionEx", "DeviceNumber", 16usize, |this| ((**(this as *const *const ISClusPartitionEx_Vtbl)).DeviceNumber)(this, pldevicenumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn PartitionNumber(this: *mut core::ffi::c_void, plpartitionnumber: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPartitionEx", "PartitionNumber", 17usize, |this| ((**(this as *const *const ISClusPartitionEx_Vtbl)).PartitionNumber)(this, plpartitionnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn VolumeGuid(this: *mut core::ffi::c_void, pbstrvolumeguid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPartitionEx", "VolumeGuid", 18usize, |this| ((**(this as *const *const ISClusPartitionEx_Vtbl)).VolumeGuid)(this, pbstrvolumeguid), |result| Some(*result))
+        }
+        Self { base__: <ISClusPartition_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TotalSize, FreeSpace, DeviceNumber, PartitionNumber, VolumeGuid }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusPartitionEx as windows_core::Interface>::IID || <ISClusPartition_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusPartitions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPartitions", "Count", 7usize, |this| ((**(this as *const *const ISClusPartitions_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPartitions", "_NewEnum", 8usize, |this| ((**(this as *const *const ISClusPartitions_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>, pppartition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPartitions", "get_Item", 9usize, |this| ((**(this as *const *const ISClusPartitions_Vtbl)).get_Item)(this, varindex, pppartition), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusPartitions as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusProperties", "Count", 7usize, |this| ((**(this as *const *const ISClusProperties_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusProperties", "_NewEnum", 8usize, |this| ((**(this as *const *const ISClusProperties_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusProperties", "Refresh", 9usize, |this| ((**(this as *const *const ISClusProperties_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>, ppclusproperty: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusProperties", "get_Item", 10usize, |this| ((**(this as *const *const ISClusProperties_Vtbl)).get_Item)(this, varindex, ppclusproperty), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateItem(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, varvalue: core::mem::MaybeUninit<windows_core::VARIANT>, pproperty: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusProperties", "CreateItem", 11usize, |this| ((**(this as *const *const ISClusProperties_Vtbl)).CreateItem)(this, bstrname, varvalue, pproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseDefaultValue(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusProperties", "UseDefaultValue", 12usize, |this| ((**(this as *const *const ISClusProperties_Vtbl)).UseDefaultValue)(this, varindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveChanges(this: *mut core::ffi::c_void, pvarstatuscode: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusProperties", "SaveChanges", 13usize, |this| ((**(this as *const *const ISClusProperties_Vtbl)).SaveChanges)(this, pvarstatuscode), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadOnly(this: *mut core::ffi::c_void, pvarreadonly: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusProperties", "ReadOnly", 14usize, |this| ((**(this as *const *const ISClusProperties_Vtbl)).ReadOnly)(this, pvarreadonly), |result| Some(*result))
+        }
+        unsafe extern "system" fn Private(this: *mut core::ffi::c_void, pvarprivate: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusProperties", "Private", 15usize, |this| ((**(this as *const *const ISClusProperties_Vtbl)).Private)(this, pvarprivate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Common(this: *mut core::ffi::c_void, pvarcommon: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusProperties", "Common", 16usize, |this| ((**(this as *const *const ISClusProperties_Vtbl)).Common)(this, pvarcommon), |result| Some(*result))
+        }
+        unsafe extern "system" fn Modified(this: *mut core::ffi::c_void, pvarmodified: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusProperties", "Modified", 17usize, |this| ((**(this as *const *const ISClusProperties_Vtbl)).Modified)(this, pvarmodified), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            Refresh,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateItem: 0,
+            UseDefaultValue,
+            SaveChanges,
+            ReadOnly,
+            Private,
+            Common,
+            Modified,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusProperties as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusProperty_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusProperty", "Name", 7usize, |this| ((**(this as *const *const ISClusProperty_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, plength: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusProperty", "Length", 8usize, |this| ((**(this as *const *const ISClusProperty_Vtbl)).Length)(this, plength), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValueCount(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusProperty", "ValueCount", 9usize, |this| ((**(this as *const *const ISClusProperty_Vtbl)).ValueCount)(this, pcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Values(this: *mut core::ffi::c_void, ppclusterpropertyvalues: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusProperty", "Values", 10usize, |this| ((**(this as *const *const ISClusProperty_Vtbl)).Values)(this, ppclusterpropertyvalues), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, pvarvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusProperty", "Value", 11usize, |this| ((**(this as *const *const ISClusProperty_Vtbl)).Value)(this, pvarvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, varvalue: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusProperty", "SetValue", 12usize, |this| ((**(this as *const *const ISClusProperty_Vtbl)).SetValue)(this, varvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, ptype: *mut CLUSTER_PROPERTY_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusProperty", "Type", 13usize, |this| ((**(this as *const *const ISClusProperty_Vtbl)).Type)(this, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetType(this: *mut core::ffi::c_void, r#type: CLUSTER_PROPERTY_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusProperty", "SetType", 14usize, |this| ((**(this as *const *const ISClusProperty_Vtbl)).SetType)(this, r#type), |result| Some(*result))
+        }
+        unsafe extern "system" fn Format(this: *mut core::ffi::c_void, pformat: *mut CLUSTER_PROPERTY_FORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusProperty", "Format", 15usize, |this| ((**(this as *const *const ISClusProperty_Vtbl)).Format)(this, pformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFormat(this: *mut core::ffi::c_void, format: CLUSTER_PROPERTY_FORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusProperty", "SetFormat", 16usize, |this| ((**(this as *const *const ISClusProperty_Vtbl)).SetFormat)(this, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadOnly(this: *mut core::ffi::c_void, pvarreadonly: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusProperty", "ReadOnly", 17usize, |this| ((**(this as *const *const ISClusProperty_Vtbl)).ReadOnly)(this, pvarreadonly), |result| Some(*result))
+        }
+        unsafe extern "system" fn Private(this: *mut core::ffi::c_void, pvarprivate: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusProperty", "Private", 18usize, |this| ((**(this as *const *const ISClusProperty_Vtbl)).Private)(this, pvarprivate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Common(this: *mut core::ffi::c_void, pvarcommon: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusProperty", "Common", 19usize, |this| ((**(this as *const *const ISClusProperty_Vtbl)).Common)(this, pvarcommon), |result| Some(*result))
+        }
+        unsafe extern "system" fn Modified(this: *mut core::ffi::c_void, pvarmodified: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusProperty", "Modified", 20usize, |this| ((**(this as *const *const ISClusProperty_Vtbl)).Modified)(this, pvarmodified), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseDefaultValue(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusProperty", "UseDefaultValue", 21usize, |this| ((**(this as *const *const ISClusProperty_Vtbl)).UseDefaultValue)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            Length,
+            ValueCount,
+            #[cfg(feature = "Win32_System_Com")]
+            Values,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Values: 0,
+            Value,
+            SetValue,
+            Type,
+            SetType,
+            Format,
+            SetFormat,
+            ReadOnly,
+            Private,
+            Common,
+            Modified,
+            UseDefaultValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusProperty as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusPropertyValue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, pvarvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPropertyValue", "Value", 7usize, |this| ((**(this as *const *const ISClusPropertyValue_Vtbl)).Value)(this, pvarvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, varvalue: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPropertyValue", "SetValue", 8usize, |this| ((**(this as *const *const ISClusPropertyValue_Vtbl)).SetValue)(this, varvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, ptype: *mut CLUSTER_PROPERTY_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPropertyValue", "Type", 9usize, |this| ((**(this as *const *const ISClusPropertyValue_Vtbl)).Type)(this, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetType(this: *mut core::ffi::c_void, r#type: CLUSTER_PROPERTY_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPropertyValue", "SetType", 10usize, |this| ((**(this as *const *const ISClusPropertyValue_Vtbl)).SetType)(this, r#type), |result| Some(*result))
+        }
+        unsafe extern "system" fn Format(this: *mut core::ffi::c_void, pformat: *mut CLUSTER_PROPERTY_FORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPropertyValue", "Format", 11usize, |this| ((**(this as *const *const ISClusPropertyValue_Vtbl)).Format)(this, pformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFormat(this: *mut core::ffi::c_void, format: CLUSTER_PROPERTY_FORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPropertyValue", "SetFormat", 12usize, |this| ((**(this as *const *const ISClusPropertyValue_Vtbl)).SetFormat)(this, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, plength: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPropertyValue", "Length", 13usize, |this| ((**(this as *const *const ISClusPropertyValue_Vtbl)).Length)(this, plength), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataCount(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPropertyValue", "DataCount", 14usize, |this| ((**(this as *const *const ISClusPropertyValue_Vtbl)).DataCount)(this, pcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, ppclusterpropertyvaluedata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPropertyValue", "Data", 15usize, |this| ((**(this as *const *const ISClusPropertyValue_Vtbl)).Data)(this, ppclusterpropertyvaluedata), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Value,
+            SetValue,
+            Type,
+            SetType,
+            Format,
+            SetFormat,
+            Length,
+            DataCount,
+            #[cfg(feature = "Win32_System_Com")]
+            Data,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Data: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusPropertyValue as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusPropertyValueData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPropertyValueData", "Count", 7usize, |this| ((**(this as *const *const ISClusPropertyValueData_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPropertyValueData", "_NewEnum", 8usize, |this| ((**(this as *const *const ISClusPropertyValueData_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>, pvarvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPropertyValueData", "get_Item", 9usize, |this| ((**(this as *const *const ISClusPropertyValueData_Vtbl)).get_Item)(this, varindex, pvarvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateItem(this: *mut core::ffi::c_void, varvalue: core::mem::MaybeUninit<windows_core::VARIANT>, pvardata: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPropertyValueData", "CreateItem", 10usize, |this| ((**(this as *const *const ISClusPropertyValueData_Vtbl)).CreateItem)(this, varvalue, pvardata), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveItem(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPropertyValueData", "RemoveItem", 11usize, |this| ((**(this as *const *const ISClusPropertyValueData_Vtbl)).RemoveItem)(this, varindex), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Count, _NewEnum, get_Item, CreateItem, RemoveItem }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusPropertyValueData as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusPropertyValues_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPropertyValues", "Count", 7usize, |this| ((**(this as *const *const ISClusPropertyValues_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPropertyValues", "_NewEnum", 8usize, |this| ((**(this as *const *const ISClusPropertyValues_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>, pppropertyvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPropertyValues", "get_Item", 9usize, |this| ((**(this as *const *const ISClusPropertyValues_Vtbl)).get_Item)(this, varindex, pppropertyvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateItem(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, varvalue: core::mem::MaybeUninit<windows_core::VARIANT>, pppropertyvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPropertyValues", "CreateItem", 10usize, |this| ((**(this as *const *const ISClusPropertyValues_Vtbl)).CreateItem)(this, bstrname, varvalue, pppropertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveItem(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusPropertyValues", "RemoveItem", 11usize, |this| ((**(this as *const *const ISClusPropertyValues_Vtbl)).RemoveItem)(this, varindex), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateItem: 0,
+            RemoveItem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusPropertyValues as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusRefObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handle(this: *mut core::ffi::c_void, phandle: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusRefObject", "Handle", 7usize, |this| ((**(this as *const *const ISClusRefObject_Vtbl)).Handle)(this, phandle), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Handle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusRefObject as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusRegistryKeys_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusRegistryKeys", "Count", 7usize, |this| ((**(this as *const *const ISClusRegistryKeys_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusRegistryKeys", "_NewEnum", 8usize, |this| ((**(this as *const *const ISClusRegistryKeys_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusRegistryKeys", "Refresh", 9usize, |this| ((**(this as *const *const ISClusRegistryKeys_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>, pbstrregistrykey: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusRegistryKeys", "get_Item", 10usize, |this| ((**(this as *const *const ISClusRegistryKeys_Vtbl)).get_Item)(this, varindex, pbstrregistrykey), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddItem(this: *mut core::ffi::c_void, bstrregistrykey: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusRegistryKeys", "AddItem", 11usize, |this| ((**(this as *const *const ISClusRegistryKeys_Vtbl)).AddItem)(this, bstrregistrykey), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveItem(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusRegistryKeys", "RemoveItem", 12usize, |this| ((**(this as *const *const ISClusRegistryKeys_Vtbl)).RemoveItem)(this, varindex), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            Refresh,
+            get_Item,
+            AddItem,
+            RemoveItem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusRegistryKeys as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusResDependencies_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResDependencies", "Count", 7usize, |this| ((**(this as *const *const ISClusResDependencies_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResDependencies", "_NewEnum", 8usize, |this| ((**(this as *const *const ISClusResDependencies_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResDependencies", "Refresh", 9usize, |this| ((**(this as *const *const ISClusResDependencies_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>, ppclusresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResDependencies", "get_Item", 10usize, |this| ((**(this as *const *const ISClusResDependencies_Vtbl)).get_Item)(this, varindex, ppclusresource), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateItem(this: *mut core::ffi::c_void, bstrresourcename: core::mem::MaybeUninit<windows_core::BSTR>, bstrresourcetype: core::mem::MaybeUninit<windows_core::BSTR>, dwflags: CLUSTER_RESOURCE_CREATE_FLAGS, ppclusterresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResDependencies", "CreateItem", 11usize, |this| ((**(this as *const *const ISClusResDependencies_Vtbl)).CreateItem)(this, bstrresourcename, bstrresourcetype, dwflags, ppclusterresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteItem(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResDependencies", "DeleteItem", 12usize, |this| ((**(this as *const *const ISClusResDependencies_Vtbl)).DeleteItem)(this, varindex), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddItem(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResDependencies", "AddItem", 13usize, |this| ((**(this as *const *const ISClusResDependencies_Vtbl)).AddItem)(this, presource), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveItem(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResDependencies", "RemoveItem", 14usize, |this| ((**(this as *const *const ISClusResDependencies_Vtbl)).RemoveItem)(this, varindex), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            Refresh,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateItem: 0,
+            DeleteItem,
+            #[cfg(feature = "Win32_System_Com")]
+            AddItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddItem: 0,
+            RemoveItem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusResDependencies as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusResDependents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResDependents", "Count", 7usize, |this| ((**(this as *const *const ISClusResDependents_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResDependents", "_NewEnum", 8usize, |this| ((**(this as *const *const ISClusResDependents_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResDependents", "Refresh", 9usize, |this| ((**(this as *const *const ISClusResDependents_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>, ppclusresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResDependents", "get_Item", 10usize, |this| ((**(this as *const *const ISClusResDependents_Vtbl)).get_Item)(this, varindex, ppclusresource), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateItem(this: *mut core::ffi::c_void, bstrresourcename: core::mem::MaybeUninit<windows_core::BSTR>, bstrresourcetype: core::mem::MaybeUninit<windows_core::BSTR>, dwflags: CLUSTER_RESOURCE_CREATE_FLAGS, ppclusterresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResDependents", "CreateItem", 11usize, |this| ((**(this as *const *const ISClusResDependents_Vtbl)).CreateItem)(this, bstrresourcename, bstrresourcetype, dwflags, ppclusterresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteItem(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResDependents", "DeleteItem", 12usize, |this| ((**(this as *const *const ISClusResDependents_Vtbl)).DeleteItem)(this, varindex), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddItem(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResDependents", "AddItem", 13usize, |this| ((**(this as *const *const ISClusResDependents_Vtbl)).AddItem)(this, presource), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveItem(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResDependents", "RemoveItem", 14usize, |this| ((**(this as *const *const ISClusResDependents_Vtbl)).RemoveItem)(this, varindex), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            Refresh,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateItem: 0,
+            DeleteItem,
+            #[cfg(feature = "Win32_System_Com")]
+            AddItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddItem: 0,
+            RemoveItem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusResDependents as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusResGroup_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CommonProperties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroup", "CommonProperties", 7usize, |this| ((**(this as *const *const ISClusResGroup_Vtbl)).CommonProperties)(this, ppproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PrivateProperties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroup", "PrivateProperties", 8usize, |this| ((**(this as *const *const ISClusResGroup_Vtbl)).PrivateProperties)(this, ppproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CommonROProperties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroup", "CommonROProperties", 9usize, |this| ((**(this as *const *const ISClusResGroup_Vtbl)).CommonROProperties)(this, ppproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PrivateROProperties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroup", "PrivateROProperties", 10usize, |this| ((**(this as *const *const ISClusResGroup_Vtbl)).PrivateROProperties)(this, ppproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn Handle(this: *mut core::ffi::c_void, phandle: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroup", "Handle", 11usize, |this| ((**(this as *const *const ISClusResGroup_Vtbl)).Handle)(this, phandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroup", "Name", 12usize, |this| ((**(this as *const *const ISClusResGroup_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, bstrgroupname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroup", "SetName", 13usize, |this| ((**(this as *const *const ISClusResGroup_Vtbl)).SetName)(this, bstrgroupname), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, dwstate: *mut CLUSTER_GROUP_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroup", "State", 14usize, |this| ((**(this as *const *const ISClusResGroup_Vtbl)).State)(this, dwstate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OwnerNode(this: *mut core::ffi::c_void, ppownernode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroup", "OwnerNode", 15usize, |this| ((**(this as *const *const ISClusResGroup_Vtbl)).OwnerNode)(this, ppownernode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Resources(this: *mut core::ffi::c_void, ppclustergroupresources: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroup", "Resources", 16usize, |this| ((**(this as *const *const ISClusResGroup_Vtbl)).Resources)(this, ppclustergroupresources), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PreferredOwnerNodes(this: *mut core::ffi::c_void, ppownernodes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroup", "PreferredOwnerNodes", 17usize, |this| ((**(this as *const *const ISClusResGroup_Vtbl)).PreferredOwnerNodes)(this, ppownernodes), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroup", "Delete", 18usize, |this| ((**(this as *const *const ISClusResGroup_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Online(this: *mut core::ffi::c_void, vartimeout: core::mem::MaybeUninit<windows_core::VARIANT>, varnode: core::mem::MaybeUninit<windows_core::VARIANT>, pvarpending: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroup", "Online", 19usize, |this| ((**(this as *const *const ISClusResGroup_Vtbl)).Online)(this, vartimeout, varnode, pvarpending), |result| Some(*result))
+        }
+        unsafe extern "system" fn Move(this: *mut core::ffi::c_void, vartimeout: core::mem::MaybeUninit<windows_core::VARIANT>, varnode: core::mem::MaybeUninit<windows_core::VARIANT>, pvarpending: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroup", "Move", 20usize, |this| ((**(this as *const *const ISClusResGroup_Vtbl)).Move)(this, vartimeout, varnode, pvarpending), |result| Some(*result))
+        }
+        unsafe extern "system" fn Offline(this: *mut core::ffi::c_void, vartimeout: core::mem::MaybeUninit<windows_core::VARIANT>, pvarpending: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroup", "Offline", 21usize, |this| ((**(this as *const *const ISClusResGroup_Vtbl)).Offline)(this, vartimeout, pvarpending), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Cluster(this: *mut core::ffi::c_void, ppcluster: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroup", "Cluster", 22usize, |this| ((**(this as *const *const ISClusResGroup_Vtbl)).Cluster)(this, ppcluster), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CommonProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CommonProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PrivateProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PrivateProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CommonROProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CommonROProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PrivateROProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PrivateROProperties: 0,
+            Handle,
+            Name,
+            SetName,
+            State,
+            #[cfg(feature = "Win32_System_Com")]
+            OwnerNode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OwnerNode: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Resources,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Resources: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PreferredOwnerNodes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PreferredOwnerNodes: 0,
+            Delete,
+            Online,
+            Move,
+            Offline,
+            #[cfg(feature = "Win32_System_Com")]
+            Cluster,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Cluster: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusResGroup as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusResGroupPreferredOwnerNodes_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroupPreferredOwnerNodes", "Count", 7usize, |this| ((**(this as *const *const ISClusResGroupPreferredOwnerNodes_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroupPreferredOwnerNodes", "_NewEnum", 8usize, |this| ((**(this as *const *const ISClusResGroupPreferredOwnerNodes_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroupPreferredOwnerNodes", "Refresh", 9usize, |this| ((**(this as *const *const ISClusResGroupPreferredOwnerNodes_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>, ppnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroupPreferredOwnerNodes", "get_Item", 10usize, |this| ((**(this as *const *const ISClusResGroupPreferredOwnerNodes_Vtbl)).get_Item)(this, varindex, ppnode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InsertItem(this: *mut core::ffi::c_void, pnode: *mut core::ffi::c_void, nposition: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroupPreferredOwnerNodes", "InsertItem", 11usize, |this| ((**(this as *const *const ISClusResGroupPreferredOwnerNodes_Vtbl)).InsertItem)(this, pnode, nposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveItem(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroupPreferredOwnerNodes", "RemoveItem", 12usize, |this| ((**(this as *const *const ISClusResGroupPreferredOwnerNodes_Vtbl)).RemoveItem)(this, varindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn Modified(this: *mut core::ffi::c_void, pvarmodified: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroupPreferredOwnerNodes", "Modified", 13usize, |this| ((**(this as *const *const ISClusResGroupPreferredOwnerNodes_Vtbl)).Modified)(this, pvarmodified), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveChanges(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroupPreferredOwnerNodes", "SaveChanges", 14usize, |this| ((**(this as *const *const ISClusResGroupPreferredOwnerNodes_Vtbl)).SaveChanges)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddItem(this: *mut core::ffi::c_void, pnode: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroupPreferredOwnerNodes", "AddItem", 15usize, |this| ((**(this as *const *const ISClusResGroupPreferredOwnerNodes_Vtbl)).AddItem)(this, pnode), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            Refresh,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            InsertItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InsertItem: 0,
+            RemoveItem,
+            Modified,
+            SaveChanges,
+            #[cfg(feature = "Win32_System_Com")]
+            AddItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddItem: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusResGroupPreferredOwnerNodes as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusResGroupResources_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroupResources", "Count", 7usize, |this| ((**(this as *const *const ISClusResGroupResources_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroupResources", "_NewEnum", 8usize, |this| ((**(this as *const *const ISClusResGroupResources_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroupResources", "Refresh", 9usize, |this| ((**(this as *const *const ISClusResGroupResources_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>, ppclusresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroupResources", "get_Item", 10usize, |this| ((**(this as *const *const ISClusResGroupResources_Vtbl)).get_Item)(this, varindex, ppclusresource), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateItem(this: *mut core::ffi::c_void, bstrresourcename: core::mem::MaybeUninit<windows_core::BSTR>, bstrresourcetype: core::mem::MaybeUninit<windows_core::BSTR>, dwflags: CLUSTER_RESOURCE_CREATE_FLAGS, ppclusterresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroupResources", "CreateItem", 11usize, |this| ((**(this as *const *const ISClusResGroupResources_Vtbl)).CreateItem)(this, bstrresourcename, bstrresourcetype, dwflags, ppclusterresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteItem(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroupResources", "DeleteItem", 12usize, |this| ((**(this as *const *const ISClusResGroupResources_Vtbl)).DeleteItem)(this, varindex), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            Refresh,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateItem: 0,
+            DeleteItem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusResGroupResources as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusResGroups_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroups", "Count", 7usize, |this| ((**(this as *const *const ISClusResGroups_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroups", "_NewEnum", 8usize, |this| ((**(this as *const *const ISClusResGroups_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroups", "Refresh", 9usize, |this| ((**(this as *const *const ISClusResGroups_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>, ppclusresgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroups", "get_Item", 10usize, |this| ((**(this as *const *const ISClusResGroups_Vtbl)).get_Item)(this, varindex, ppclusresgroup), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateItem(this: *mut core::ffi::c_void, bstrresourcegroupname: core::mem::MaybeUninit<windows_core::BSTR>, ppresourcegroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroups", "CreateItem", 11usize, |this| ((**(this as *const *const ISClusResGroups_Vtbl)).CreateItem)(this, bstrresourcegroupname, ppresourcegroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteItem(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResGroups", "DeleteItem", 12usize, |this| ((**(this as *const *const ISClusResGroups_Vtbl)).DeleteItem)(this, varindex), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            Refresh,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateItem: 0,
+            DeleteItem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusResGroups as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusResPossibleOwnerNodes_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResPossibleOwnerNodes", "Count", 7usize, |this| ((**(this as *const *const ISClusResPossibleOwnerNodes_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResPossibleOwnerNodes", "_NewEnum", 8usize, |this| ((**(this as *const *const ISClusResPossibleOwnerNodes_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResPossibleOwnerNodes", "Refresh", 9usize, |this| ((**(this as *const *const ISClusResPossibleOwnerNodes_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>, ppnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResPossibleOwnerNodes", "get_Item", 10usize, |this| ((**(this as *const *const ISClusResPossibleOwnerNodes_Vtbl)).get_Item)(this, varindex, ppnode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddItem(this: *mut core::ffi::c_void, pnode: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResPossibleOwnerNodes", "AddItem", 11usize, |this| ((**(this as *const *const ISClusResPossibleOwnerNodes_Vtbl)).AddItem)(this, pnode), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveItem(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResPossibleOwnerNodes", "RemoveItem", 12usize, |this| ((**(this as *const *const ISClusResPossibleOwnerNodes_Vtbl)).RemoveItem)(this, varindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn Modified(this: *mut core::ffi::c_void, pvarmodified: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResPossibleOwnerNodes", "Modified", 13usize, |this| ((**(this as *const *const ISClusResPossibleOwnerNodes_Vtbl)).Modified)(this, pvarmodified), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            Refresh,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AddItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddItem: 0,
+            RemoveItem,
+            Modified,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusResPossibleOwnerNodes as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusResType_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CommonProperties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResType", "CommonProperties", 7usize, |this| ((**(this as *const *const ISClusResType_Vtbl)).CommonProperties)(this, ppproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PrivateProperties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResType", "PrivateProperties", 8usize, |this| ((**(this as *const *const ISClusResType_Vtbl)).PrivateProperties)(this, ppproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CommonROProperties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResType", "CommonROProperties", 9usize, |this| ((**(this as *const *const ISClusResType_Vtbl)).CommonROProperties)(this, ppproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PrivateROProperties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResType", "PrivateROProperties", 10usize, |this| ((**(this as *const *const ISClusResType_Vtbl)).PrivateROProperties)(this, ppproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResType", "Name", 11usize, |this| ((**(this as *const *const ISClusResType_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResType", "Delete", 12usize, |this| ((**(this as *const *const ISClusResType_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Cluster(this: *mut core::ffi::c_void, ppcluster: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResType", "Cluster", 13usize, |this| ((**(this as *const *const ISClusResType_Vtbl)).Cluster)(this, ppcluster), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Resources(this: *mut core::ffi::c_void, ppclusterrestyperesources: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResType", "Resources", 14usize, |this| ((**(this as *const *const ISClusResType_Vtbl)).Resources)(this, ppclusterrestyperesources), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PossibleOwnerNodes(this: *mut core::ffi::c_void, ppownernodes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResType", "PossibleOwnerNodes", 15usize, |this| ((**(this as *const *const ISClusResType_Vtbl)).PossibleOwnerNodes)(this, ppownernodes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AvailableDisks(this: *mut core::ffi::c_void, ppavailabledisks: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResType", "AvailableDisks", 16usize, |this| ((**(this as *const *const ISClusResType_Vtbl)).AvailableDisks)(this, ppavailabledisks), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CommonProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CommonProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PrivateProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PrivateProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CommonROProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CommonROProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PrivateROProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PrivateROProperties: 0,
+            Name,
+            Delete,
+            #[cfg(feature = "Win32_System_Com")]
+            Cluster,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Cluster: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Resources,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Resources: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PossibleOwnerNodes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PossibleOwnerNodes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AvailableDisks,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AvailableDisks: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusResType as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusResTypePossibleOwnerNodes_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResTypePossibleOwnerNodes", "Count", 7usize, |this| ((**(this as *const *const ISClusResTypePossibleOwnerNodes_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResTypePossibleOwnerNodes", "_NewEnum", 8usize, |this| ((**(this as *const *const ISClusResTypePossibleOwnerNodes_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResTypePossibleOwnerNodes", "Refresh", 9usize, |this| ((**(this as *const *const ISClusResTypePossibleOwnerNodes_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>, ppnode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResTypePossibleOwnerNodes", "get_Item", 10usize, |this| ((**(this as *const *const ISClusResTypePossibleOwnerNodes_Vtbl)).get_Item)(this, varindex, ppnode), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            Refresh,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusResTypePossibleOwnerNodes as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusResTypeResources_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResTypeResources", "Count", 7usize, |this| ((**(this as *const *const ISClusResTypeResources_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResTypeResources", "_NewEnum", 8usize, |this| ((**(this as *const *const ISClusResTypeResources_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResTypeResources", "Refresh", 9usize, |this| ((**(this as *const *const ISClusResTypeResources_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>, ppclusresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResTypeResources", "get_Item", 10usize, |this| ((**(this as *const *const ISClusResTypeResources_Vtbl)).get_Item)(this, varindex, ppclusresource), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateItem(this: *mut core::ffi::c_void, bstrresourcename: core::mem::MaybeUninit<windows_core::BSTR>, bstrgroupname: core::mem::MaybeUninit<windows_core::BSTR>, dwflags: CLUSTER_RESOURCE_CREATE_FLAGS, ppclusterresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResTypeResources", "CreateItem", 11usize, |this| ((**(this as *const *const ISClusResTypeResources_Vtbl)).CreateItem)(this, bstrresourcename, bstrgroupname, dwflags, ppclusterresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteItem(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResTypeResources", "DeleteItem", 12usize, |this| ((**(this as *const *const ISClusResTypeResources_Vtbl)).DeleteItem)(this, varindex), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            Refresh,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateItem: 0,
+            DeleteItem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusResTypeResources as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusResTypes_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResTypes", "Count", 7usize, |this| ((**(this as *const *const ISClusResTypes_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResTypes", "_NewEnum", 8usize, |this| ((**(this as *const *const ISClusResTypes_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResTypes", "Refresh", 9usize, |this| ((**(this as *const *const ISClusResTypes_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>, ppclusrestype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResTypes", "get_Item", 10usize, |this| ((**(this as *const *const ISClusResTypes_Vtbl)).get_Item)(this, varindex, ppclusrestype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateItem(this: *mut core::ffi::c_void, bstrresourcetypename: core::mem::MaybeUninit<windows_core::BSTR>, bstrdisplayname: core::mem::MaybeUninit<windows_core::BSTR>, bstrresourcetypedll: core::mem::MaybeUninit<windows_core::BSTR>, dwlooksalivepollinterval: i32, dwisalivepollinterval: i32, ppresourcetype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResTypes", "CreateItem", 11usize, |this| ((**(this as *const *const ISClusResTypes_Vtbl)).CreateItem)(this, bstrresourcetypename, bstrdisplayname, bstrresourcetypedll, dwlooksalivepollinterval, dwisalivepollinterval, ppresourcetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteItem(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResTypes", "DeleteItem", 12usize, |this| ((**(this as *const *const ISClusResTypes_Vtbl)).DeleteItem)(this, varindex), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            Refresh,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateItem: 0,
+            DeleteItem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusResTypes as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusResource_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CommonProperties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "CommonProperties", 7usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).CommonProperties)(this, ppproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PrivateProperties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "PrivateProperties", 8usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).PrivateProperties)(this, ppproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CommonROProperties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "CommonROProperties", 9usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).CommonROProperties)(this, ppproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PrivateROProperties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "PrivateROProperties", 10usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).PrivateROProperties)(this, ppproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn Handle(this: *mut core::ffi::c_void, phandle: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "Handle", 11usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).Handle)(this, phandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "Name", 12usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, bstrresourcename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "SetName", 13usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).SetName)(this, bstrresourcename), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, dwstate: *mut CLUSTER_RESOURCE_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "State", 14usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).State)(this, dwstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn CoreFlag(this: *mut core::ffi::c_void, dwcoreflag: *mut CLUS_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "CoreFlag", 15usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).CoreFlag)(this, dwcoreflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn BecomeQuorumResource(this: *mut core::ffi::c_void, bstrdevicepath: core::mem::MaybeUninit<windows_core::BSTR>, lmaxlogsize: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "BecomeQuorumResource", 16usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).BecomeQuorumResource)(this, bstrdevicepath, lmaxlogsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "Delete", 17usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Fail(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "Fail", 18usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).Fail)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Online(this: *mut core::ffi::c_void, ntimeout: i32, pvarpending: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "Online", 19usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).Online)(this, ntimeout, pvarpending), |result| Some(*result))
+        }
+        unsafe extern "system" fn Offline(this: *mut core::ffi::c_void, ntimeout: i32, pvarpending: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "Offline", 20usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).Offline)(this, ntimeout, pvarpending), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ChangeResourceGroup(this: *mut core::ffi::c_void, presourcegroup: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "ChangeResourceGroup", 21usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).ChangeResourceGroup)(this, presourcegroup), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddResourceNode(this: *mut core::ffi::c_void, pnode: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "AddResourceNode", 22usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).AddResourceNode)(this, pnode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RemoveResourceNode(this: *mut core::ffi::c_void, pnode: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "RemoveResourceNode", 23usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).RemoveResourceNode)(this, pnode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CanResourceBeDependent(this: *mut core::ffi::c_void, presource: *mut core::ffi::c_void, pvardependent: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "CanResourceBeDependent", 24usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).CanResourceBeDependent)(this, presource, pvardependent), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PossibleOwnerNodes(this: *mut core::ffi::c_void, ppownernodes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "PossibleOwnerNodes", 25usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).PossibleOwnerNodes)(this, ppownernodes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Dependencies(this: *mut core::ffi::c_void, ppresdependencies: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "Dependencies", 26usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).Dependencies)(this, ppresdependencies), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Dependents(this: *mut core::ffi::c_void, ppresdependents: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "Dependents", 27usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).Dependents)(this, ppresdependents), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Group(this: *mut core::ffi::c_void, ppresgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "Group", 28usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).Group)(this, ppresgroup), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OwnerNode(this: *mut core::ffi::c_void, ppownernode: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "OwnerNode", 29usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).OwnerNode)(this, ppownernode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Cluster(this: *mut core::ffi::c_void, ppcluster: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "Cluster", 30usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).Cluster)(this, ppcluster), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClassInfo(this: *mut core::ffi::c_void, prcclassinfo: *mut CLUSTER_RESOURCE_CLASS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "ClassInfo", 31usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).ClassInfo)(this, prcclassinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Disk(this: *mut core::ffi::c_void, ppdisk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "Disk", 32usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).Disk)(this, ppdisk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RegistryKeys(this: *mut core::ffi::c_void, ppregistrykeys: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "RegistryKeys", 33usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).RegistryKeys)(this, ppregistrykeys), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CryptoKeys(this: *mut core::ffi::c_void, ppcryptokeys: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "CryptoKeys", 34usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).CryptoKeys)(this, ppcryptokeys), |result| Some(*result))
+        }
+        unsafe extern "system" fn TypeName(this: *mut core::ffi::c_void, pbstrtypename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "TypeName", 35usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).TypeName)(this, pbstrtypename), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, ppresourcetype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "Type", 36usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).Type)(this, ppresourcetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaintenanceMode(this: *mut core::ffi::c_void, pbmaintenancemode: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "MaintenanceMode", 37usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).MaintenanceMode)(this, pbmaintenancemode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaintenanceMode(this: *mut core::ffi::c_void, bmaintenancemode: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResource", "SetMaintenanceMode", 38usize, |this| ((**(this as *const *const ISClusResource_Vtbl)).SetMaintenanceMode)(this, bmaintenancemode), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CommonProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CommonProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PrivateProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PrivateProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CommonROProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CommonROProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PrivateROProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PrivateROProperties: 0,
+            Handle,
+            Name,
+            SetName,
+            State,
+            CoreFlag,
+            BecomeQuorumResource,
+            Delete,
+            Fail,
+            Online,
+            Offline,
+            #[cfg(feature = "Win32_System_Com")]
+            ChangeResourceGroup,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ChangeResourceGroup: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AddResourceNode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddResourceNode: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            RemoveResourceNode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RemoveResourceNode: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CanResourceBeDependent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CanResourceBeDependent: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PossibleOwnerNodes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PossibleOwnerNodes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Dependencies,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Dependencies: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Dependents,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Dependents: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Group,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Group: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OwnerNode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OwnerNode: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Cluster,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Cluster: 0,
+            ClassInfo,
+            #[cfg(feature = "Win32_System_Com")]
+            Disk,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Disk: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            RegistryKeys,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RegistryKeys: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CryptoKeys,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CryptoKeys: 0,
+            TypeName,
+            #[cfg(feature = "Win32_System_Com")]
+            Type,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Type: 0,
+            MaintenanceMode,
+            SetMaintenanceMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusResource as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusResources_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResources", "Count", 7usize, |this| ((**(this as *const *const ISClusResources_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResources", "_NewEnum", 8usize, |this| ((**(this as *const *const ISClusResources_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResources", "Refresh", 9usize, |this| ((**(this as *const *const ISClusResources_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>, ppclusresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResources", "get_Item", 10usize, |this| ((**(this as *const *const ISClusResources_Vtbl)).get_Item)(this, varindex, ppclusresource), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateItem(this: *mut core::ffi::c_void, bstrresourcename: core::mem::MaybeUninit<windows_core::BSTR>, bstrresourcetype: core::mem::MaybeUninit<windows_core::BSTR>, bstrgroupname: core::mem::MaybeUninit<windows_core::BSTR>, dwflags: CLUSTER_RESOURCE_CREATE_FLAGS, ppclusterresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResources", "CreateItem", 11usize, |this| ((**(this as *const *const ISClusResources_Vtbl)).CreateItem)(this, bstrresourcename, bstrresourcetype, bstrgroupname, dwflags, ppclusterresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteItem(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusResources", "DeleteItem", 12usize, |this| ((**(this as *const *const ISClusResources_Vtbl)).DeleteItem)(this, varindex), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            Refresh,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateItem: 0,
+            DeleteItem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusResources as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusScsiAddress_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PortNumber(this: *mut core::ffi::c_void, pvarportnumber: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusScsiAddress", "PortNumber", 7usize, |this| ((**(this as *const *const ISClusScsiAddress_Vtbl)).PortNumber)(this, pvarportnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn PathId(this: *mut core::ffi::c_void, pvarpathid: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusScsiAddress", "PathId", 8usize, |this| ((**(this as *const *const ISClusScsiAddress_Vtbl)).PathId)(this, pvarpathid), |result| Some(*result))
+        }
+        unsafe extern "system" fn TargetId(this: *mut core::ffi::c_void, pvartargetid: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusScsiAddress", "TargetId", 9usize, |this| ((**(this as *const *const ISClusScsiAddress_Vtbl)).TargetId)(this, pvartargetid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Lun(this: *mut core::ffi::c_void, pvarlun: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusScsiAddress", "Lun", 10usize, |this| ((**(this as *const *const ISClusScsiAddress_Vtbl)).Lun)(this, pvarlun), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PortNumber, PathId, TargetId, Lun }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusScsiAddress as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusVersion_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrclustername: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusVersion", "Name", 7usize, |this| ((**(this as *const *const ISClusVersion_Vtbl)).Name)(this, pbstrclustername), |result| Some(*result))
+        }
+        unsafe extern "system" fn MajorVersion(this: *mut core::ffi::c_void, pnmajorversion: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusVersion", "MajorVersion", 8usize, |this| ((**(this as *const *const ISClusVersion_Vtbl)).MajorVersion)(this, pnmajorversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinorVersion(this: *mut core::ffi::c_void, pnminorversion: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusVersion", "MinorVersion", 9usize, |this| ((**(this as *const *const ISClusVersion_Vtbl)).MinorVersion)(this, pnminorversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn BuildNumber(this: *mut core::ffi::c_void, pnbuildnumber: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusVersion", "BuildNumber", 10usize, |this| ((**(this as *const *const ISClusVersion_Vtbl)).BuildNumber)(this, pnbuildnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn VendorId(this: *mut core::ffi::c_void, pbstrvendorid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusVersion", "VendorId", 11usize, |this| ((**(this as *const *const ISClusVersion_Vtbl)).VendorId)(this, pbstrvendorid), |result| Some(*result))
+        }
+        unsafe extern "system" fn CSDVersion(this: *mut core::ffi::c_void, pbstrcsdversion: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusVersion", "CSDVersion", 12usize, |this| ((**(this as *const *const ISClusVersion_Vtbl)).CSDVersion)(this, pbstrcsdversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClusterHighestVersion(this: *mut core::ffi::c_void, pnclusterhighestversion: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusVersion", "ClusterHighestVersion", 13usize, |this| ((**(this as *const *const ISClusVersion_Vtbl)).ClusterHighestVersion)(this, pnclusterhighestversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClusterLowestVersion(this: *mut core::ffi::c_void, pnclusterlowestversion: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusVersion", "ClusterLowestVersion", 14usize, |this| ((**(this as *const *const ISClusVersion_Vtbl)).ClusterLowestVersion)(this, pnclusterlowestversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flags(this: *mut core::ffi::c_void, pnflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusVersion", "Flags", 15usize, |this| ((**(this as *const *const ISClusVersion_Vtbl)).Flags)(this, pnflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn MixedVersion(this: *mut core::ffi::c_void, pvarmixedversion: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusVersion", "MixedVersion", 16usize, |this| ((**(this as *const *const ISClusVersion_Vtbl)).MixedVersion)(this, pvarmixedversion), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            MajorVersion,
+            MinorVersion,
+            BuildNumber,
+            VendorId,
+            CSDVersion,
+            ClusterHighestVersion,
+            ClusterLowestVersion,
+            Flags,
+            MixedVersion,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusVersion as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISCluster_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CommonProperties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCluster", "CommonProperties", 7usize, |this| ((**(this as *const *const ISCluster_Vtbl)).CommonProperties)(this, ppproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PrivateProperties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCluster", "PrivateProperties", 8usize, |this| ((**(this as *const *const ISCluster_Vtbl)).PrivateProperties)(this, ppproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CommonROProperties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCluster", "CommonROProperties", 9usize, |this| ((**(this as *const *const ISCluster_Vtbl)).CommonROProperties)(this, ppproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PrivateROProperties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCluster", "PrivateROProperties", 10usize, |this| ((**(this as *const *const ISCluster_Vtbl)).PrivateROProperties)(this, ppproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn Handle(this: *mut core::ffi::c_void, phandle: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCluster", "Handle", 11usize, |this| ((**(this as *const *const ISCluster_Vtbl)).Handle)(this, phandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, bstrclustername: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCluster", "Open", 12usize, |this| ((**(this as *const *const ISCluster_Vtbl)).Open)(this, bstrclustername), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCluster", "Name", 13usize, |this| ((**(this as *const *const ISCluster_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, bstrclustername: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCluster", "SetName", 14usize, |this| ((**(this as *const *const ISCluster_Vtbl)).SetName)(this, bstrclustername), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Version(this: *mut core::ffi::c_void, ppclusversion: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCluster", "Version", 15usize, |this| ((**(this as *const *const ISCluster_Vtbl)).Version)(this, ppclusversion), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetQuorumResource(this: *mut core::ffi::c_void, pclusterresource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCluster", "SetQuorumResource", 16usize, |this| ((**(this as *const *const ISCluster_Vtbl)).SetQuorumResource)(this, pclusterresource), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn QuorumResource(this: *mut core::ffi::c_void, pclusterresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCluster", "QuorumResource", 17usize, |this| ((**(this as *const *const ISCluster_Vtbl)).QuorumResource)(this, pclusterresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn QuorumLogSize(this: *mut core::ffi::c_void, pnlogsize: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCluster", "QuorumLogSize", 18usize, |this| ((**(this as *const *const ISCluster_Vtbl)).QuorumLogSize)(this, pnlogsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQuorumLogSize(this: *mut core::ffi::c_void, nlogsize: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCluster", "SetQuorumLogSize", 19usize, |this| ((**(this as *const *const ISCluster_Vtbl)).SetQuorumLogSize)(this, nlogsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn QuorumPath(this: *mut core::ffi::c_void, pppath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCluster", "QuorumPath", 20usize, |this| ((**(this as *const *const ISCluster_Vtbl)).QuorumPath)(this, pppath), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQuorumPath(this: *mut core::ffi::c_void, ppath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCluster", "SetQuorumPath", 21usize, |this| ((**(this as *const *const ISCluster_Vtbl)).SetQuorumPath)(this, ppath), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Nodes(this: *mut core::ffi::c_void, ppnodes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCluster", "Nodes", 22usize, |this| ((**(this as *const *const ISCluster_Vtbl)).Nodes)(this, ppnodes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ResourceGroups(this: *mut core::ffi::c_void, ppclusterresourcegroups: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCluster", "ResourceGroups", 23usize, |this| ((**(this as *const *const ISCluster_Vtbl)).ResourceGroups)(this, ppclusterresourcegroups), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Resources(this: *mut core::ffi::c_void, ppclusterresources: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCluster", "Resources", 24usize, |this| ((**(this as *const *const ISCluster_Vtbl)).Resources)(this, ppclusterresources), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ResourceTypes(this: *mut core::ffi::c_void, ppresourcetypes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCluster", "ResourceTypes", 25usize, |this| ((**(this as *const *const ISCluster_Vtbl)).ResourceTypes)(this, ppresourcetypes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Networks(this: *mut core::ffi::c_void, ppnetworks: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCluster", "Networks", 26usize, |this| ((**(this as *const *const ISCluster_Vtbl)).Networks)(this, ppnetworks), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn NetInterfaces(this: *mut core::ffi::c_void, ppnetinterfaces: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISCluster", "NetInterfaces", 27usize, |this| ((**(this as *const *const ISCluster_Vtbl)).NetInterfaces)(this, ppnetinterfaces), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CommonProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CommonProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PrivateProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PrivateProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CommonROProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CommonROProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PrivateROProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PrivateROProperties: 0,
+            Handle,
+            Open,
+            Name,
+            SetName,
+            #[cfg(feature = "Win32_System_Com")]
+            Version,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Version: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetQuorumResource,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetQuorumResource: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            QuorumResource,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            QuorumResource: 0,
+            QuorumLogSize,
+            SetQuorumLogSize,
+            QuorumPath,
+            SetQuorumPath,
+            #[cfg(feature = "Win32_System_Com")]
+            Nodes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Nodes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ResourceGroups,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ResourceGroups: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Resources,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Resources: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ResourceTypes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ResourceTypes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Networks,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Networks: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            NetInterfaces,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            NetInterfaces: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISCluster as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISClusterNames_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusterNames", "Count", 7usize, |this| ((**(this as *const *const ISClusterNames_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusterNames", "_NewEnum", 8usize, |this| ((**(this as *const *const ISClusterNames_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusterNames", "Refresh", 9usize, |this| ((**(this as *const *const ISClusterNames_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>, pbstrclustername: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusterNames", "get_Item", 10usize, |this| ((**(this as *const *const ISClusterNames_Vtbl)).get_Item)(this, varindex, pbstrclustername), |result| Some(*result))
+        }
+        unsafe extern "system" fn DomainName(this: *mut core::ffi::c_void, pbstrdomainname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISClusterNames", "DomainName", 11usize, |this| ((**(this as *const *const ISClusterNames_Vtbl)).DomainName)(this, pbstrdomainname), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Count, _NewEnum, Refresh, get_Item, DomainName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISClusterNames as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISDomainNames_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISDomainNames", "Count", 7usize, |this| ((**(this as *const *const ISDomainNames_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISDomainNames", "_NewEnum", 8usize, |this| ((**(this as *const *const ISDomainNames_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISDomainNames", "Refresh", 9usize, |this| ((**(this as *const *const ISDomainNames_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, varindex: core::mem::MaybeUninit<windows_core::VARIANT>, pbstrdomainname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISDomainNames", "get_Item", 10usize, |this| ((**(this as *const *const ISDomainNames_Vtbl)).get_Item)(this, varindex, pbstrdomainname), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Count, _NewEnum, Refresh, get_Item }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISDomainNames as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWCContextMenuCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddExtensionMenuItem(this: *mut core::ffi::c_void, lpszname: core::mem::MaybeUninit<windows_core::BSTR>, lpszstatusbartext: core::mem::MaybeUninit<windows_core::BSTR>, ncommandid: u32, nsubmenucommandid: u32, uflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWCContextMenuCallback", "AddExtensionMenuItem", 3usize, |this| ((**(this as *const *const IWCContextMenuCallback_Vtbl)).AddExtensionMenuItem)(this, lpszname, lpszstatusbartext, ncommandid, nsubmenucommandid, uflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddExtensionMenuItem }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWCContextMenuCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWCPropertySheetCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddPropertySheetPage(this: *mut core::ffi::c_void, hpage: *const i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWCPropertySheetCallback", "AddPropertySheetPage", 3usize, |this| ((**(this as *const *const IWCPropertySheetCallback_Vtbl)).AddPropertySheetPage)(this, hpage), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddPropertySheetPage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWCPropertySheetCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWCWizard97Callback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddWizard97Page(this: *mut core::ffi::c_void, hpage: *const i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWCWizard97Callback", "AddWizard97Page", 3usize, |this| ((**(this as *const *const IWCWizard97Callback_Vtbl)).AddWizard97Page)(this, hpage), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableNext(this: *mut core::ffi::c_void, hpage: *const i32, benable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWCWizard97Callback", "EnableNext", 4usize, |this| ((**(this as *const *const IWCWizard97Callback_Vtbl)).EnableNext)(this, hpage, benable), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddWizard97Page, EnableNext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWCWizard97Callback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWCWizardCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddWizardPage(this: *mut core::ffi::c_void, hpage: *const i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWCWizardCallback", "AddWizardPage", 3usize, |this| ((**(this as *const *const IWCWizardCallback_Vtbl)).AddWizardPage)(this, hpage), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableNext(this: *mut core::ffi::c_void, hpage: *const i32, benable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWCWizardCallback", "EnableNext", 4usize, |this| ((**(this as *const *const IWCWizardCallback_Vtbl)).EnableNext)(this, hpage, benable), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddWizardPage, EnableNext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWCWizardCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWEExtendContextMenu_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddContextMenuItems(this: *mut core::ffi::c_void, pidata: *mut core::ffi::c_void, picallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWEExtendContextMenu", "AddContextMenuItems", 3usize, |this| ((**(this as *const *const IWEExtendContextMenu_Vtbl)).AddContextMenuItems)(this, pidata, picallback), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddContextMenuItems }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWEExtendContextMenu as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWEExtendPropertySheet_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreatePropertySheetPages(this: *mut core::ffi::c_void, pidata: *mut core::ffi::c_void, picallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWEExtendPropertySheet", "CreatePropertySheetPages", 3usize, |this| ((**(this as *const *const IWEExtendPropertySheet_Vtbl)).CreatePropertySheetPages)(this, pidata, picallback), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreatePropertySheetPages }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWEExtendPropertySheet as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWEExtendWizard_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWizardPages(this: *mut core::ffi::c_void, pidata: *mut core::ffi::c_void, picallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWEExtendWizard", "CreateWizardPages", 3usize, |this| ((**(this as *const *const IWEExtendWizard_Vtbl)).CreateWizardPages)(this, pidata, picallback), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateWizardPages }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWEExtendWizard as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWEExtendWizard97_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWizard97Pages(this: *mut core::ffi::c_void, pidata: *mut core::ffi::c_void, picallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWEExtendWizard97", "CreateWizard97Pages", 3usize, |this| ((**(this as *const *const IWEExtendWizard97_Vtbl)).CreateWizard97Pages)(this, pidata, picallback), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateWizard97Pages }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWEExtendWizard97 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWEInvokeCommand_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InvokeCommand(this: *mut core::ffi::c_void, ncommandid: u32, pidata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWEInvokeCommand", "InvokeCommand", 3usize, |this| ((**(this as *const *const IWEInvokeCommand_Vtbl)).InvokeCommand)(this, ncommandid, pidata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InvokeCommand }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWEInvokeCommand as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
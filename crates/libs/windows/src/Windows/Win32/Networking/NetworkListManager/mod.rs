@@ -606,6 +606,7 @@ pub struct NLM_CONNECTION_COST(pub i32);
 impl windows_core::TypeKind for NLM_CONNECTION_COST {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NLM_CONNECTION_COST {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NLM_CONNECTION_COST").field(&self.0).finish()
@@ -617,6 +618,7 @@ pub struct NLM_CONNECTION_PROPERTY_CHANGE(pub i32);
 impl windows_core::TypeKind for NLM_CONNECTION_PROPERTY_CHANGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NLM_CONNECTION_PROPERTY_CHANGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NLM_CONNECTION_PROPERTY_CHANGE").field(&self.0).finish()
@@ -628,6 +630,7 @@ pub struct NLM_CONNECTIVITY(pub i32);
 impl windows_core::TypeKind for NLM_CONNECTIVITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NLM_CONNECTIVITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NLM_CONNECTIVITY").field(&self.0).finish()
@@ -639,6 +642,7 @@ pub struct NLM_DOMAIN_AUTHENTICATION_KIND(pub i32);
 impl windows_core::TypeKind for NLM_DOMAIN_AUTHENTICATION_KIND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NLM_DOMAIN_AUTHENTICATION_KIND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NLM_DOMAIN_AUTHENTICATION_KIND").field(&self.0).finish()
@@ -650,6 +654,7 @@ pub struct NLM_DOMAIN_TYPE(pub i32);
 impl windows_core::TypeKind for NLM_DOMAIN_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NLM_DOMAIN_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NLM_DOMAIN_TYPE").field(&self.0).finish()
@@ -661,6 +666,7 @@ pub struct NLM_ENUM_NETWORK(pub i32);
 impl windows_core::TypeKind for NLM_ENUM_NETWORK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NLM_ENUM_NETWORK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NLM_ENUM_NETWORK").field(&self.0).finish()
@@ -672,6 +678,7 @@ pub struct NLM_INTERNET_CONNECTIVITY(pub i32);
 impl windows_core::TypeKind for NLM_INTERNET_CONNECTIVITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NLM_INTERNET_CONNECTIVITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NLM_INTERNET_CONNECTIVITY").field(&self.0).finish()
@@ -683,6 +690,7 @@ pub struct NLM_NETWORK_CATEGORY(pub i32);
 impl windows_core::TypeKind for NLM_NETWORK_CATEGORY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NLM_NETWORK_CATEGORY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NLM_NETWORK_CATEGORY").field(&self.0).finish()
@@ -694,6 +702,7 @@ pub struct NLM_NETWORK_CLASS(pub i32);
 impl windows_core::TypeKind for NLM_NETWORK_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NLM_NETWORK_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NLM_NETWORK_CLASS").field(&self.0).finish()
@@ -705,13 +714,15 @@ pub struct NLM_NETWORK_PROPERTY_CHANGE(pub i32);
 impl windows_core::TypeKind for NLM_NETWORK_PROPERTY_CHANGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NLM_NETWORK_PROPERTY_CHANGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NLM_NETWORK_PROPERTY_CHANGE").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NLM_DATAPLAN_STATUS {
     pub InterfaceGuid: windows_core::GUID,
     pub UsageData: NLM_USAGE_DATA,
@@ -731,7 +742,8 @@ impl Default for NLM_DATAPLAN_STATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NLM_SIMULATED_PROFILE_INFO {
     pub ProfileName: [u16; 256],
     pub cost: NLM_CONNECTION_COST,
@@ -747,7 +759,8 @@ impl Default for NLM_SIMULATED_PROFILE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NLM_SOCKADDR {
     pub data: [u8; 128],
 }
@@ -760,7 +773,8 @@ impl Default for NLM_SOCKADDR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NLM_USAGE_DATA {
     pub UsageInMegabytes: u32,
     pub LastSyncTime: super::super::Foundation::FILETIME,
@@ -776,3 +790,5 @@ impl Default for NLM_USAGE_DATA {
 pub const NetworkListManager: windows_core::GUID = windows_core::GUID::from_u128(0xdcb00c01_570f_4a9b_8d69_199fdba5723b);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
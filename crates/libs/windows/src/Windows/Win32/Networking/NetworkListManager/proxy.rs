@@ -0,0 +1,411 @@
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IEnumNetworkConnections_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppenumvar: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetworkConnections", "_NewEnum", 7usize, |this| ((**(this as *const *const IEnumNetworkConnections_Vtbl))._NewEnum)(this, ppenumvar), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetworkConnections", "Next", 8usize, |this| ((**(this as *const *const IEnumNetworkConnections_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetworkConnections", "Skip", 9usize, |this| ((**(this as *const *const IEnumNetworkConnections_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetworkConnections", "Reset", 10usize, |this| ((**(this as *const *const IEnumNetworkConnections_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenumnetwork: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetworkConnections", "Clone", 11usize, |this| ((**(this as *const *const IEnumNetworkConnections_Vtbl)).Clone)(this, ppenumnetwork), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Ole")]
+            _NewEnum,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            _NewEnum: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Skip,
+            Reset,
+            #[cfg(feature = "Win32_System_Com")]
+            Clone,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Clone: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumNetworkConnections as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IEnumNetworks_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppenumvar: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetworks", "_NewEnum", 7usize, |this| ((**(this as *const *const IEnumNetworks_Vtbl))._NewEnum)(this, ppenumvar), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetworks", "Next", 8usize, |this| ((**(this as *const *const IEnumNetworks_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetworks", "Skip", 9usize, |this| ((**(this as *const *const IEnumNetworks_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetworks", "Reset", 10usize, |this| ((**(this as *const *const IEnumNetworks_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenumnetwork: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNetworks", "Clone", 11usize, |this| ((**(this as *const *const IEnumNetworks_Vtbl)).Clone)(this, ppenumnetwork), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Ole")]
+            _NewEnum,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            _NewEnum: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Skip,
+            Reset,
+            #[cfg(feature = "Win32_System_Com")]
+            Clone,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Clone: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumNetworks as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetwork_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, psznetworkname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetwork", "GetName", 7usize, |this| ((**(this as *const *const INetwork_Vtbl)).GetName)(this, psznetworkname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, sznetworknewname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetwork", "SetName", 8usize, |this| ((**(this as *const *const INetwork_Vtbl)).SetName)(this, sznetworknewname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescription(this: *mut core::ffi::c_void, pszdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetwork", "GetDescription", 9usize, |this| ((**(this as *const *const INetwork_Vtbl)).GetDescription)(this, pszdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, szdescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetwork", "SetDescription", 10usize, |this| ((**(this as *const *const INetwork_Vtbl)).SetDescription)(this, szdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNetworkId(this: *mut core::ffi::c_void, pgdguidnetworkid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetwork", "GetNetworkId", 11usize, |this| ((**(this as *const *const INetwork_Vtbl)).GetNetworkId)(this, pgdguidnetworkid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDomainType(this: *mut core::ffi::c_void, pnetworktype: *mut NLM_DOMAIN_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetwork", "GetDomainType", 12usize, |this| ((**(this as *const *const INetwork_Vtbl)).GetDomainType)(this, pnetworktype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetNetworkConnections(this: *mut core::ffi::c_void, ppenumnetworkconnection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetwork", "GetNetworkConnections", 13usize, |this| ((**(this as *const *const INetwork_Vtbl)).GetNetworkConnections)(this, ppenumnetworkconnection), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTimeCreatedAndConnected(this: *mut core::ffi::c_void, pdwlowdatetimecreated: *mut u32, pdwhighdatetimecreated: *mut u32, pdwlowdatetimeconnected: *mut u32, pdwhighdatetimeconnected: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetwork", "GetTimeCreatedAndConnected", 14usize, |this| ((**(this as *const *const INetwork_Vtbl)).GetTimeCreatedAndConnected)(this, pdwlowdatetimecreated, pdwhighdatetimecreated, pdwlowdatetimeconnected, pdwhighdatetimeconnected), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsConnectedToInternet(this: *mut core::ffi::c_void, pbisconnected: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetwork", "IsConnectedToInternet", 15usize, |this| ((**(this as *const *const INetwork_Vtbl)).IsConnectedToInternet)(this, pbisconnected), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsConnected(this: *mut core::ffi::c_void, pbisconnected: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetwork", "IsConnected", 16usize, |this| ((**(this as *const *const INetwork_Vtbl)).IsConnected)(this, pbisconnected), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConnectivity(this: *mut core::ffi::c_void, pconnectivity: *mut NLM_CONNECTIVITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetwork", "GetConnectivity", 17usize, |this| ((**(this as *const *const INetwork_Vtbl)).GetConnectivity)(this, pconnectivity), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCategory(this: *mut core::ffi::c_void, pcategory: *mut NLM_NETWORK_CATEGORY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetwork", "GetCategory", 18usize, |this| ((**(this as *const *const INetwork_Vtbl)).GetCategory)(this, pcategory), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCategory(this: *mut core::ffi::c_void, newcategory: NLM_NETWORK_CATEGORY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetwork", "SetCategory", 19usize, |this| ((**(this as *const *const INetwork_Vtbl)).SetCategory)(this, newcategory), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetName,
+            SetName,
+            GetDescription,
+            SetDescription,
+            GetNetworkId,
+            GetDomainType,
+            #[cfg(feature = "Win32_System_Com")]
+            GetNetworkConnections,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetNetworkConnections: 0,
+            GetTimeCreatedAndConnected,
+            IsConnectedToInternet,
+            IsConnected,
+            GetConnectivity,
+            GetCategory,
+            SetCategory,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetwork as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetwork2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsDomainAuthenticatedBy(this: *mut core::ffi::c_void, domainauthenticationkind: NLM_DOMAIN_AUTHENTICATION_KIND, pvalue: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetwork2", "IsDomainAuthenticatedBy", 20usize, |this| ((**(this as *const *const INetwork2_Vtbl)).IsDomainAuthenticatedBy)(this, domainauthenticationkind, pvalue), |result| Some(*result))
+        }
+        Self { base__: <INetwork_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsDomainAuthenticatedBy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetwork2 as windows_core::Interface>::IID || <INetwork_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetworkConnection_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetNetwork(this: *mut core::ffi::c_void, ppnetwork: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkConnection", "GetNetwork", 7usize, |this| ((**(this as *const *const INetworkConnection_Vtbl)).GetNetwork)(this, ppnetwork), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsConnectedToInternet(this: *mut core::ffi::c_void, pbisconnected: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkConnection", "IsConnectedToInternet", 8usize, |this| ((**(this as *const *const INetworkConnection_Vtbl)).IsConnectedToInternet)(this, pbisconnected), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsConnected(this: *mut core::ffi::c_void, pbisconnected: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkConnection", "IsConnected", 9usize, |this| ((**(this as *const *const INetworkConnection_Vtbl)).IsConnected)(this, pbisconnected), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConnectivity(this: *mut core::ffi::c_void, pconnectivity: *mut NLM_CONNECTIVITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkConnection", "GetConnectivity", 10usize, |this| ((**(this as *const *const INetworkConnection_Vtbl)).GetConnectivity)(this, pconnectivity), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConnectionId(this: *mut core::ffi::c_void, pgdconnectionid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkConnection", "GetConnectionId", 11usize, |this| ((**(this as *const *const INetworkConnection_Vtbl)).GetConnectionId)(this, pgdconnectionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAdapterId(this: *mut core::ffi::c_void, pgdadapterid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkConnection", "GetAdapterId", 12usize, |this| ((**(this as *const *const INetworkConnection_Vtbl)).GetAdapterId)(this, pgdadapterid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDomainType(this: *mut core::ffi::c_void, pdomaintype: *mut NLM_DOMAIN_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkConnection", "GetDomainType", 13usize, |this| ((**(this as *const *const INetworkConnection_Vtbl)).GetDomainType)(this, pdomaintype), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetNetwork,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetNetwork: 0,
+            IsConnectedToInternet,
+            IsConnected,
+            GetConnectivity,
+            GetConnectionId,
+            GetAdapterId,
+            GetDomainType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkConnection as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetworkConnection2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsDomainAuthenticatedBy(this: *mut core::ffi::c_void, domainauthenticationkind: NLM_DOMAIN_AUTHENTICATION_KIND, pvalue: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkConnection2", "IsDomainAuthenticatedBy", 14usize, |this| ((**(this as *const *const INetworkConnection2_Vtbl)).IsDomainAuthenticatedBy)(this, domainauthenticationkind, pvalue), |result| Some(*result))
+        }
+        Self { base__: <INetworkConnection_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsDomainAuthenticatedBy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkConnection2 as windows_core::Interface>::IID || <INetworkConnection_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetworkConnectionCost_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCost(this: *mut core::ffi::c_void, pcost: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkConnectionCost", "GetCost", 3usize, |this| ((**(this as *const *const INetworkConnectionCost_Vtbl)).GetCost)(this, pcost), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataPlanStatus(this: *mut core::ffi::c_void, pdataplanstatus: *mut NLM_DATAPLAN_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkConnectionCost", "GetDataPlanStatus", 4usize, |this| ((**(this as *const *const INetworkConnectionCost_Vtbl)).GetDataPlanStatus)(this, pdataplanstatus), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCost, GetDataPlanStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkConnectionCost as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetworkConnectionCostEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConnectionCostChanged(this: *mut core::ffi::c_void, connectionid: windows_core::GUID, newcost: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkConnectionCostEvents", "ConnectionCostChanged", 3usize, |this| ((**(this as *const *const INetworkConnectionCostEvents_Vtbl)).ConnectionCostChanged)(this, connectionid, newcost), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionDataPlanStatusChanged(this: *mut core::ffi::c_void, connectionid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkConnectionCostEvents", "ConnectionDataPlanStatusChanged", 4usize, |this| ((**(this as *const *const INetworkConnectionCostEvents_Vtbl)).ConnectionDataPlanStatusChanged)(this, connectionid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ConnectionCostChanged, ConnectionDataPlanStatusChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkConnectionCostEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetworkConnectionEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NetworkConnectionConnectivityChanged(this: *mut core::ffi::c_void, connectionid: windows_core::GUID, newconnectivity: NLM_CONNECTIVITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkConnectionEvents", "NetworkConnectionConnectivityChanged", 3usize, |this| ((**(this as *const *const INetworkConnectionEvents_Vtbl)).NetworkConnectionConnectivityChanged)(this, connectionid, newconnectivity), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkConnectionPropertyChanged(this: *mut core::ffi::c_void, connectionid: windows_core::GUID, flags: NLM_CONNECTION_PROPERTY_CHANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkConnectionEvents", "NetworkConnectionPropertyChanged", 4usize, |this| ((**(this as *const *const INetworkConnectionEvents_Vtbl)).NetworkConnectionPropertyChanged)(this, connectionid, flags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            NetworkConnectionConnectivityChanged,
+            NetworkConnectionPropertyChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkConnectionEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetworkCostManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCost(this: *mut core::ffi::c_void, pcost: *mut u32, pdestipaddr: *const NLM_SOCKADDR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkCostManager", "GetCost", 3usize, |this| ((**(this as *const *const INetworkCostManager_Vtbl)).GetCost)(this, pcost, pdestipaddr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataPlanStatus(this: *mut core::ffi::c_void, pdataplanstatus: *mut NLM_DATAPLAN_STATUS, pdestipaddr: *const NLM_SOCKADDR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkCostManager", "GetDataPlanStatus", 4usize, |this| ((**(this as *const *const INetworkCostManager_Vtbl)).GetDataPlanStatus)(this, pdataplanstatus, pdestipaddr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDestinationAddresses(this: *mut core::ffi::c_void, length: u32, pdestipaddrlist: *const NLM_SOCKADDR, bappend: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkCostManager", "SetDestinationAddresses", 5usize, |this| ((**(this as *const *const INetworkCostManager_Vtbl)).SetDestinationAddresses)(this, length, pdestipaddrlist, bappend), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCost, GetDataPlanStatus, SetDestinationAddresses }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkCostManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetworkCostManagerEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CostChanged(this: *mut core::ffi::c_void, newcost: u32, pdestaddr: *const NLM_SOCKADDR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkCostManagerEvents", "CostChanged", 3usize, |this| ((**(this as *const *const INetworkCostManagerEvents_Vtbl)).CostChanged)(this, newcost, pdestaddr), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataPlanStatusChanged(this: *mut core::ffi::c_void, pdestaddr: *const NLM_SOCKADDR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkCostManagerEvents", "DataPlanStatusChanged", 4usize, |this| ((**(this as *const *const INetworkCostManagerEvents_Vtbl)).DataPlanStatusChanged)(this, pdestaddr), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CostChanged, DataPlanStatusChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkCostManagerEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetworkEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NetworkAdded(this: *mut core::ffi::c_void, networkid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkEvents", "NetworkAdded", 3usize, |this| ((**(this as *const *const INetworkEvents_Vtbl)).NetworkAdded)(this, networkid), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkDeleted(this: *mut core::ffi::c_void, networkid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkEvents", "NetworkDeleted", 4usize, |this| ((**(this as *const *const INetworkEvents_Vtbl)).NetworkDeleted)(this, networkid), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkConnectivityChanged(this: *mut core::ffi::c_void, networkid: windows_core::GUID, newconnectivity: NLM_CONNECTIVITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkEvents", "NetworkConnectivityChanged", 5usize, |this| ((**(this as *const *const INetworkEvents_Vtbl)).NetworkConnectivityChanged)(this, networkid, newconnectivity), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkPropertyChanged(this: *mut core::ffi::c_void, networkid: windows_core::GUID, flags: NLM_NETWORK_PROPERTY_CHANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkEvents", "NetworkPropertyChanged", 6usize, |this| ((**(this as *const *const INetworkEvents_Vtbl)).NetworkPropertyChanged)(this, networkid, flags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            NetworkAdded,
+            NetworkDeleted,
+            NetworkConnectivityChanged,
+            NetworkPropertyChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetworkListManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetNetworks(this: *mut core::ffi::c_void, flags: NLM_ENUM_NETWORK, ppenumnetwork: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkListManager", "GetNetworks", 7usize, |this| ((**(this as *const *const INetworkListManager_Vtbl)).GetNetworks)(this, flags, ppenumnetwork), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetNetwork(this: *mut core::ffi::c_void, gdnetworkid: windows_core::GUID, ppnetwork: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkListManager", "GetNetwork", 8usize, |this| ((**(this as *const *const INetworkListManager_Vtbl)).GetNetwork)(this, gdnetworkid, ppnetwork), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetNetworkConnections(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkListManager", "GetNetworkConnections", 9usize, |this| ((**(this as *const *const INetworkListManager_Vtbl)).GetNetworkConnections)(this, ppenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetNetworkConnection(this: *mut core::ffi::c_void, gdnetworkconnectionid: windows_core::GUID, ppnetworkconnection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkListManager", "GetNetworkConnection", 10usize, |this| ((**(this as *const *const INetworkListManager_Vtbl)).GetNetworkConnection)(this, gdnetworkconnectionid, ppnetworkconnection), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsConnectedToInternet(this: *mut core::ffi::c_void, pbisconnected: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkListManager", "IsConnectedToInternet", 11usize, |this| ((**(this as *const *const INetworkListManager_Vtbl)).IsConnectedToInternet)(this, pbisconnected), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsConnected(this: *mut core::ffi::c_void, pbisconnected: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkListManager", "IsConnected", 12usize, |this| ((**(this as *const *const INetworkListManager_Vtbl)).IsConnected)(this, pbisconnected), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConnectivity(this: *mut core::ffi::c_void, pconnectivity: *mut NLM_CONNECTIVITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkListManager", "GetConnectivity", 13usize, |this| ((**(this as *const *const INetworkListManager_Vtbl)).GetConnectivity)(this, pconnectivity), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSimulatedProfileInfo(this: *mut core::ffi::c_void, psimulatedinfo: *const NLM_SIMULATED_PROFILE_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkListManager", "SetSimulatedProfileInfo", 14usize, |this| ((**(this as *const *const INetworkListManager_Vtbl)).SetSimulatedProfileInfo)(this, psimulatedinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearSimulatedProfileInfo(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkListManager", "ClearSimulatedProfileInfo", 15usize, |this| ((**(this as *const *const INetworkListManager_Vtbl)).ClearSimulatedProfileInfo)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetNetworks,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetNetworks: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetNetwork,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetNetwork: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetNetworkConnections,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetNetworkConnections: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetNetworkConnection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetNetworkConnection: 0,
+            IsConnectedToInternet,
+            IsConnected,
+            GetConnectivity,
+            SetSimulatedProfileInfo,
+            ClearSimulatedProfileInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkListManager as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INetworkListManagerEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConnectivityChanged(this: *mut core::ffi::c_void, newconnectivity: NLM_CONNECTIVITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkListManagerEvents", "ConnectivityChanged", 3usize, |this| ((**(this as *const *const INetworkListManagerEvents_Vtbl)).ConnectivityChanged)(this, newconnectivity), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ConnectivityChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkListManagerEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
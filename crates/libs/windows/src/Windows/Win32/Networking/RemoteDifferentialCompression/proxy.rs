@@ -0,0 +1,402 @@
+impl windows_core::imp::ProxyVtable for IFindSimilarResults_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSize(this: *mut core::ffi::c_void, size: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFindSimilarResults", "GetSize", 3usize, |this| ((**(this as *const *const IFindSimilarResults_Vtbl)).GetSize)(this, size), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextFileId(this: *mut core::ffi::c_void, numtraitsmatched: *mut u32, similarityfileid: *mut SimilarityFileId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFindSimilarResults", "GetNextFileId", 4usize, |this| ((**(this as *const *const IFindSimilarResults_Vtbl)).GetNextFileId)(this, numtraitsmatched, similarityfileid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSize, GetNextFileId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFindSimilarResults as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRdcComparator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Process(this: *mut core::ffi::c_void, endofinput: super::super::Foundation::BOOL, endofoutput: *mut super::super::Foundation::BOOL, inputbuffer: *mut RdcBufferPointer, outputbuffer: *mut RdcNeedPointer, rdc_errorcode: *mut RDC_ErrorCode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRdcComparator", "Process", 3usize, |this| ((**(this as *const *const IRdcComparator_Vtbl)).Process)(this, endofinput, endofoutput, inputbuffer, outputbuffer, rdc_errorcode), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Process }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRdcComparator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRdcFileReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFileSize(this: *mut core::ffi::c_void, filesize: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRdcFileReader", "GetFileSize", 3usize, |this| ((**(this as *const *const IRdcFileReader_Vtbl)).GetFileSize)(this, filesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn Read(this: *mut core::ffi::c_void, offsetfilestart: u64, bytestoread: u32, bytesactuallyread: *mut u32, buffer: *mut u8, eof: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRdcFileReader", "Read", 4usize, |this| ((**(this as *const *const IRdcFileReader_Vtbl)).Read)(this, offsetfilestart, bytestoread, bytesactuallyread, buffer, eof), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFilePosition(this: *mut core::ffi::c_void, offsetfromstart: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRdcFileReader", "GetFilePosition", 5usize, |this| ((**(this as *const *const IRdcFileReader_Vtbl)).GetFilePosition)(this, offsetfromstart), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFileSize, Read, GetFilePosition }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRdcFileReader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRdcFileWriter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Write(this: *mut core::ffi::c_void, offsetfilestart: u64, bytestowrite: u32, buffer: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRdcFileWriter", "Write", 6usize, |this| ((**(this as *const *const IRdcFileWriter_Vtbl)).Write)(this, offsetfilestart, bytestowrite, buffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn Truncate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRdcFileWriter", "Truncate", 7usize, |this| ((**(this as *const *const IRdcFileWriter_Vtbl)).Truncate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteOnClose(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRdcFileWriter", "DeleteOnClose", 8usize, |this| ((**(this as *const *const IRdcFileWriter_Vtbl)).DeleteOnClose)(this), |result| Some(*result))
+        }
+        Self { base__: <IRdcFileReader_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Write, Truncate, DeleteOnClose }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRdcFileWriter as windows_core::Interface>::IID || <IRdcFileReader_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRdcGenerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetGeneratorParameters(this: *mut core::ffi::c_void, level: u32, igeneratorparameters: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRdcGenerator", "GetGeneratorParameters", 3usize, |this| ((**(this as *const *const IRdcGenerator_Vtbl)).GetGeneratorParameters)(this, level, igeneratorparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn Process(this: *mut core::ffi::c_void, endofinput: super::super::Foundation::BOOL, endofoutput: *mut super::super::Foundation::BOOL, inputbuffer: *mut RdcBufferPointer, depth: u32, outputbuffers: *mut *mut RdcBufferPointer, rdc_errorcode: *mut RDC_ErrorCode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRdcGenerator", "Process", 4usize, |this| ((**(this as *const *const IRdcGenerator_Vtbl)).Process)(this, endofinput, endofoutput, inputbuffer, depth, outputbuffers, rdc_errorcode), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetGeneratorParameters, Process }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRdcGenerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRdcGeneratorFilterMaxParameters_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetHorizonSize(this: *mut core::ffi::c_void, horizonsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRdcGeneratorFilterMaxParameters", "GetHorizonSize", 3usize, |this| ((**(this as *const *const IRdcGeneratorFilterMaxParameters_Vtbl)).GetHorizonSize)(this, horizonsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHorizonSize(this: *mut core::ffi::c_void, horizonsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRdcGeneratorFilterMaxParameters", "SetHorizonSize", 4usize, |this| ((**(this as *const *const IRdcGeneratorFilterMaxParameters_Vtbl)).SetHorizonSize)(this, horizonsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHashWindowSize(this: *mut core::ffi::c_void, hashwindowsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRdcGeneratorFilterMaxParameters", "GetHashWindowSize", 5usize, |this| ((**(this as *const *const IRdcGeneratorFilterMaxParameters_Vtbl)).GetHashWindowSize)(this, hashwindowsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHashWindowSize(this: *mut core::ffi::c_void, hashwindowsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRdcGeneratorFilterMaxParameters", "SetHashWindowSize", 6usize, |this| ((**(this as *const *const IRdcGeneratorFilterMaxParameters_Vtbl)).SetHashWindowSize)(this, hashwindowsize), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetHorizonSize,
+            SetHorizonSize,
+            GetHashWindowSize,
+            SetHashWindowSize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRdcGeneratorFilterMaxParameters as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRdcGeneratorParameters_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetGeneratorParametersType(this: *mut core::ffi::c_void, parameterstype: *mut GeneratorParametersType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRdcGeneratorParameters", "GetGeneratorParametersType", 3usize, |this| ((**(this as *const *const IRdcGeneratorParameters_Vtbl)).GetGeneratorParametersType)(this, parameterstype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParametersVersion(this: *mut core::ffi::c_void, currentversion: *mut u32, minimumcompatibleappversion: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRdcGeneratorParameters", "GetParametersVersion", 4usize, |this| ((**(this as *const *const IRdcGeneratorParameters_Vtbl)).GetParametersVersion)(this, currentversion, minimumcompatibleappversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSerializeSize(this: *mut core::ffi::c_void, size: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRdcGeneratorParameters", "GetSerializeSize", 5usize, |this| ((**(this as *const *const IRdcGeneratorParameters_Vtbl)).GetSerializeSize)(this, size), |result| Some(*result))
+        }
+        unsafe extern "system" fn Serialize(this: *mut core::ffi::c_void, size: u32, parametersblob: *mut u8, byteswritten: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRdcGeneratorParameters", "Serialize", 6usize, |this| ((**(this as *const *const IRdcGeneratorParameters_Vtbl)).Serialize)(this, size, parametersblob, byteswritten), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetGeneratorParametersType,
+            GetParametersVersion,
+            GetSerializeSize,
+            Serialize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRdcGeneratorParameters as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRdcLibrary_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ComputeDefaultRecursionDepth(this: *mut core::ffi::c_void, filesize: u64, depth: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRdcLibrary", "ComputeDefaultRecursionDepth", 3usize, |this| ((**(this as *const *const IRdcLibrary_Vtbl)).ComputeDefaultRecursionDepth)(this, filesize, depth), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateGeneratorParameters(this: *mut core::ffi::c_void, parameterstype: GeneratorParametersType, level: u32, igeneratorparameters: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRdcLibrary", "CreateGeneratorParameters", 4usize, |this| ((**(this as *const *const IRdcLibrary_Vtbl)).CreateGeneratorParameters)(this, parameterstype, level, igeneratorparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenGeneratorParameters(this: *mut core::ffi::c_void, size: u32, parametersblob: *const u8, igeneratorparameters: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRdcLibrary", "OpenGeneratorParameters", 5usize, |this| ((**(this as *const *const IRdcLibrary_Vtbl)).OpenGeneratorParameters)(this, size, parametersblob, igeneratorparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateGenerator(this: *mut core::ffi::c_void, depth: u32, igeneratorparametersarray: *const *mut core::ffi::c_void, igenerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRdcLibrary", "CreateGenerator", 6usize, |this| ((**(this as *const *const IRdcLibrary_Vtbl)).CreateGenerator)(this, depth, igeneratorparametersarray, igenerator), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateComparator(this: *mut core::ffi::c_void, iseedsignaturesfile: *mut core::ffi::c_void, comparatorbuffersize: u32, icomparator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRdcLibrary", "CreateComparator", 7usize, |this| ((**(this as *const *const IRdcLibrary_Vtbl)).CreateComparator)(this, iseedsignaturesfile, comparatorbuffersize, icomparator), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSignatureReader(this: *mut core::ffi::c_void, ifilereader: *mut core::ffi::c_void, isignaturereader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRdcLibrary", "CreateSignatureReader", 8usize, |this| ((**(this as *const *const IRdcLibrary_Vtbl)).CreateSignatureReader)(this, ifilereader, isignaturereader), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRDCVersion(this: *mut core::ffi::c_void, currentversion: *mut u32, minimumcompatibleappversion: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRdcLibrary", "GetRDCVersion", 9usize, |this| ((**(this as *const *const IRdcLibrary_Vtbl)).GetRDCVersion)(this, currentversion, minimumcompatibleappversion), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ComputeDefaultRecursionDepth,
+            CreateGeneratorParameters,
+            OpenGeneratorParameters,
+            CreateGenerator,
+            CreateComparator,
+            CreateSignatureReader,
+            GetRDCVersion,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRdcLibrary as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRdcSignatureReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReadHeader(this: *mut core::ffi::c_void, rdc_errorcode: *mut RDC_ErrorCode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRdcSignatureReader", "ReadHeader", 3usize, |this| ((**(this as *const *const IRdcSignatureReader_Vtbl)).ReadHeader)(this, rdc_errorcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadSignatures(this: *mut core::ffi::c_void, rdcsignaturepointer: *mut RdcSignaturePointer, endofoutput: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRdcSignatureReader", "ReadSignatures", 4usize, |this| ((**(this as *const *const IRdcSignatureReader_Vtbl)).ReadSignatures)(this, rdcsignaturepointer, endofoutput), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReadHeader, ReadSignatures }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRdcSignatureReader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRdcSimilarityGenerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnableSimilarity(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRdcSimilarityGenerator", "EnableSimilarity", 3usize, |this| ((**(this as *const *const IRdcSimilarityGenerator_Vtbl)).EnableSimilarity)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Results(this: *mut core::ffi::c_void, similaritydata: *mut SimilarityData) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRdcSimilarityGenerator", "Results", 4usize, |this| ((**(this as *const *const IRdcSimilarityGenerator_Vtbl)).Results)(this, similaritydata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnableSimilarity, Results }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRdcSimilarityGenerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISimilarity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateTable(this: *mut core::ffi::c_void, path: windows_core::PCWSTR, truncate: super::super::Foundation::BOOL, securitydescriptor: *const u8, recordsize: u32, isnew: *mut RdcCreatedTables) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarity", "CreateTable", 3usize, |this| ((**(this as *const *const ISimilarity_Vtbl)).CreateTable)(this, path, truncate, securitydescriptor, recordsize, isnew), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTableIndirect(this: *mut core::ffi::c_void, mapping: *mut core::ffi::c_void, fileidfile: *mut core::ffi::c_void, truncate: super::super::Foundation::BOOL, recordsize: u32, isnew: *mut RdcCreatedTables) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarity", "CreateTableIndirect", 4usize, |this| ((**(this as *const *const ISimilarity_Vtbl)).CreateTableIndirect)(this, mapping, fileidfile, truncate, recordsize, isnew), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseTable(this: *mut core::ffi::c_void, isvalid: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarity", "CloseTable", 5usize, |this| ((**(this as *const *const ISimilarity_Vtbl)).CloseTable)(this, isvalid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Append(this: *mut core::ffi::c_void, similarityfileid: *const SimilarityFileId, similaritydata: *const SimilarityData) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarity", "Append", 6usize, |this| ((**(this as *const *const ISimilarity_Vtbl)).Append)(this, similarityfileid, similaritydata), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindSimilarFileId(this: *mut core::ffi::c_void, similaritydata: *const SimilarityData, numberofmatchesrequired: u16, resultssize: u32, findsimilarresults: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarity", "FindSimilarFileId", 7usize, |this| ((**(this as *const *const ISimilarity_Vtbl)).FindSimilarFileId)(this, similaritydata, numberofmatchesrequired, resultssize, findsimilarresults), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyAndSwap(this: *mut core::ffi::c_void, newsimilaritytables: *mut core::ffi::c_void, reportprogress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarity", "CopyAndSwap", 8usize, |this| ((**(this as *const *const ISimilarity_Vtbl)).CopyAndSwap)(this, newsimilaritytables, reportprogress), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordCount(this: *mut core::ffi::c_void, recordcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarity", "GetRecordCount", 9usize, |this| ((**(this as *const *const ISimilarity_Vtbl)).GetRecordCount)(this, recordcount), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateTable,
+            CreateTableIndirect,
+            CloseTable,
+            Append,
+            FindSimilarFileId,
+            CopyAndSwap,
+            GetRecordCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISimilarity as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISimilarityFileIdTable_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateTable(this: *mut core::ffi::c_void, path: windows_core::PCWSTR, truncate: super::super::Foundation::BOOL, securitydescriptor: *const u8, recordsize: u32, isnew: *mut RdcCreatedTables) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarityFileIdTable", "CreateTable", 3usize, |this| ((**(this as *const *const ISimilarityFileIdTable_Vtbl)).CreateTable)(this, path, truncate, securitydescriptor, recordsize, isnew), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTableIndirect(this: *mut core::ffi::c_void, fileidfile: *mut core::ffi::c_void, truncate: super::super::Foundation::BOOL, recordsize: u32, isnew: *mut RdcCreatedTables) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarityFileIdTable", "CreateTableIndirect", 4usize, |this| ((**(this as *const *const ISimilarityFileIdTable_Vtbl)).CreateTableIndirect)(this, fileidfile, truncate, recordsize, isnew), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseTable(this: *mut core::ffi::c_void, isvalid: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarityFileIdTable", "CloseTable", 5usize, |this| ((**(this as *const *const ISimilarityFileIdTable_Vtbl)).CloseTable)(this, isvalid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Append(this: *mut core::ffi::c_void, similarityfileid: *const SimilarityFileId, similarityfileindex: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarityFileIdTable", "Append", 6usize, |this| ((**(this as *const *const ISimilarityFileIdTable_Vtbl)).Append)(this, similarityfileid, similarityfileindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn Lookup(this: *mut core::ffi::c_void, similarityfileindex: u32, similarityfileid: *mut SimilarityFileId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarityFileIdTable", "Lookup", 7usize, |this| ((**(this as *const *const ISimilarityFileIdTable_Vtbl)).Lookup)(this, similarityfileindex, similarityfileid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Invalidate(this: *mut core::ffi::c_void, similarityfileindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarityFileIdTable", "Invalidate", 8usize, |this| ((**(this as *const *const ISimilarityFileIdTable_Vtbl)).Invalidate)(this, similarityfileindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordCount(this: *mut core::ffi::c_void, recordcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarityFileIdTable", "GetRecordCount", 9usize, |this| ((**(this as *const *const ISimilarityFileIdTable_Vtbl)).GetRecordCount)(this, recordcount), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateTable,
+            CreateTableIndirect,
+            CloseTable,
+            Append,
+            Lookup,
+            Invalidate,
+            GetRecordCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISimilarityFileIdTable as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISimilarityReportProgress_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportProgress(this: *mut core::ffi::c_void, percentcompleted: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarityReportProgress", "ReportProgress", 3usize, |this| ((**(this as *const *const ISimilarityReportProgress_Vtbl)).ReportProgress)(this, percentcompleted), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReportProgress }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISimilarityReportProgress as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISimilarityTableDumpState_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNextData(this: *mut core::ffi::c_void, resultssize: u32, resultsused: *mut u32, eof: *mut super::super::Foundation::BOOL, results: *mut SimilarityDumpData) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarityTableDumpState", "GetNextData", 3usize, |this| ((**(this as *const *const ISimilarityTableDumpState_Vtbl)).GetNextData)(this, resultssize, resultsused, eof, results), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetNextData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISimilarityTableDumpState as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISimilarityTraitsMappedView_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Flush(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarityTraitsMappedView", "Flush", 3usize, |this| ((**(this as *const *const ISimilarityTraitsMappedView_Vtbl)).Flush)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unmap(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarityTraitsMappedView", "Unmap", 4usize, |this| ((**(this as *const *const ISimilarityTraitsMappedView_Vtbl)).Unmap)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Get(this: *mut core::ffi::c_void, index: u64, dirty: super::super::Foundation::BOOL, numelements: u32, viewinfo: *mut SimilarityMappedViewInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarityTraitsMappedView", "Get", 5usize, |this| ((**(this as *const *const ISimilarityTraitsMappedView_Vtbl)).Get)(this, index, dirty, numelements, viewinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetView(this: *mut core::ffi::c_void, mappedpagebegin: *mut *mut u8, mappedpageend: *mut *mut u8) {
+            windows_core::imp::proxy_call(this, "ISimilarityTraitsMappedView", "GetView", 6usize, |this| ((**(this as *const *const ISimilarityTraitsMappedView_Vtbl)).GetView)(this, mappedpagebegin, mappedpageend), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Flush, Unmap, Get, GetView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISimilarityTraitsMappedView as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISimilarityTraitsMapping_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CloseMapping(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ISimilarityTraitsMapping", "CloseMapping", 3usize, |this| ((**(this as *const *const ISimilarityTraitsMapping_Vtbl)).CloseMapping)(this), |_| None)
+        }
+        unsafe extern "system" fn SetFileSize(this: *mut core::ffi::c_void, filesize: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarityTraitsMapping", "SetFileSize", 4usize, |this| ((**(this as *const *const ISimilarityTraitsMapping_Vtbl)).SetFileSize)(this, filesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileSize(this: *mut core::ffi::c_void, filesize: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarityTraitsMapping", "GetFileSize", 5usize, |this| ((**(this as *const *const ISimilarityTraitsMapping_Vtbl)).GetFileSize)(this, filesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenMapping(this: *mut core::ffi::c_void, accessmode: RdcMappingAccessMode, begin: u64, end: u64, actualend: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarityTraitsMapping", "OpenMapping", 6usize, |this| ((**(this as *const *const ISimilarityTraitsMapping_Vtbl)).OpenMapping)(this, accessmode, begin, end, actualend), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResizeMapping(this: *mut core::ffi::c_void, accessmode: RdcMappingAccessMode, begin: u64, end: u64, actualend: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarityTraitsMapping", "ResizeMapping", 7usize, |this| ((**(this as *const *const ISimilarityTraitsMapping_Vtbl)).ResizeMapping)(this, accessmode, begin, end, actualend), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPageSize(this: *mut core::ffi::c_void, pagesize: *mut u32) {
+            windows_core::imp::proxy_call(this, "ISimilarityTraitsMapping", "GetPageSize", 8usize, |this| ((**(this as *const *const ISimilarityTraitsMapping_Vtbl)).GetPageSize)(this, pagesize), |_| None)
+        }
+        unsafe extern "system" fn CreateView(this: *mut core::ffi::c_void, minimummappedpages: u32, accessmode: RdcMappingAccessMode, mappedview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarityTraitsMapping", "CreateView", 9usize, |this| ((**(this as *const *const ISimilarityTraitsMapping_Vtbl)).CreateView)(this, minimummappedpages, accessmode, mappedview), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CloseMapping,
+            SetFileSize,
+            GetFileSize,
+            OpenMapping,
+            ResizeMapping,
+            GetPageSize,
+            CreateView,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISimilarityTraitsMapping as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISimilarityTraitsTable_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateTable(this: *mut core::ffi::c_void, path: windows_core::PCWSTR, truncate: super::super::Foundation::BOOL, securitydescriptor: *const u8, isnew: *mut RdcCreatedTables) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarityTraitsTable", "CreateTable", 3usize, |this| ((**(this as *const *const ISimilarityTraitsTable_Vtbl)).CreateTable)(this, path, truncate, securitydescriptor, isnew), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTableIndirect(this: *mut core::ffi::c_void, mapping: *mut core::ffi::c_void, truncate: super::super::Foundation::BOOL, isnew: *mut RdcCreatedTables) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarityTraitsTable", "CreateTableIndirect", 4usize, |this| ((**(this as *const *const ISimilarityTraitsTable_Vtbl)).CreateTableIndirect)(this, mapping, truncate, isnew), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseTable(this: *mut core::ffi::c_void, isvalid: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarityTraitsTable", "CloseTable", 5usize, |this| ((**(this as *const *const ISimilarityTraitsTable_Vtbl)).CloseTable)(this, isvalid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Append(this: *mut core::ffi::c_void, data: *const SimilarityData, fileindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarityTraitsTable", "Append", 6usize, |this| ((**(this as *const *const ISimilarityTraitsTable_Vtbl)).Append)(this, data, fileindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindSimilarFileIndex(this: *mut core::ffi::c_void, similaritydata: *const SimilarityData, numberofmatchesrequired: u16, findsimilarfileindexresults: *mut FindSimilarFileIndexResults, resultssize: u32, resultsused: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarityTraitsTable", "FindSimilarFileIndex", 7usize, |this| ((**(this as *const *const ISimilarityTraitsTable_Vtbl)).FindSimilarFileIndex)(this, similaritydata, numberofmatchesrequired, findsimilarfileindexresults, resultssize, resultsused), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginDump(this: *mut core::ffi::c_void, similaritytabledumpstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarityTraitsTable", "BeginDump", 8usize, |this| ((**(this as *const *const ISimilarityTraitsTable_Vtbl)).BeginDump)(this, similaritytabledumpstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastIndex(this: *mut core::ffi::c_void, fileindex: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimilarityTraitsTable", "GetLastIndex", 9usize, |this| ((**(this as *const *const ISimilarityTraitsTable_Vtbl)).GetLastIndex)(this, fileindex), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateTable,
+            CreateTableIndirect,
+            CloseTable,
+            Append,
+            FindSimilarFileIndex,
+            BeginDump,
+            GetLastIndex,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISimilarityTraitsTable as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
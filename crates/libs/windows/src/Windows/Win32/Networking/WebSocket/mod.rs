@@ -148,6 +148,7 @@ pub struct WEB_SOCKET_ACTION(pub i32);
 impl windows_core::TypeKind for WEB_SOCKET_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WEB_SOCKET_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WEB_SOCKET_ACTION").field(&self.0).finish()
@@ -159,6 +160,7 @@ pub struct WEB_SOCKET_ACTION_QUEUE(pub i32);
 impl windows_core::TypeKind for WEB_SOCKET_ACTION_QUEUE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WEB_SOCKET_ACTION_QUEUE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WEB_SOCKET_ACTION_QUEUE").field(&self.0).finish()
@@ -170,6 +172,7 @@ pub struct WEB_SOCKET_BUFFER_TYPE(pub i32);
 impl windows_core::TypeKind for WEB_SOCKET_BUFFER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WEB_SOCKET_BUFFER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WEB_SOCKET_BUFFER_TYPE").field(&self.0).finish()
@@ -181,6 +184,7 @@ pub struct WEB_SOCKET_CLOSE_STATUS(pub i32);
 impl windows_core::TypeKind for WEB_SOCKET_CLOSE_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WEB_SOCKET_CLOSE_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WEB_SOCKET_CLOSE_STATUS").field(&self.0).finish()
@@ -192,6 +196,7 @@ pub struct WEB_SOCKET_PROPERTY_TYPE(pub i32);
 impl windows_core::TypeKind for WEB_SOCKET_PROPERTY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WEB_SOCKET_PROPERTY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WEB_SOCKET_PROPERTY_TYPE").field(&self.0).finish()
@@ -212,7 +217,8 @@ impl Default for WEB_SOCKET_BUFFER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WEB_SOCKET_BUFFER_0 {
     pub pbReason: *mut u8,
     pub ulReasonLength: u32,
@@ -227,7 +233,8 @@ impl Default for WEB_SOCKET_BUFFER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WEB_SOCKET_BUFFER_1 {
     pub pbBuffer: *mut u8,
     pub ulBufferLength: u32,
@@ -241,7 +248,8 @@ impl Default for WEB_SOCKET_BUFFER_1 {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WEB_SOCKET_HANDLE(pub *mut core::ffi::c_void);
 impl WEB_SOCKET_HANDLE {
     pub fn is_invalid(&self) -> bool {
@@ -265,7 +273,8 @@ impl windows_core::TypeKind for WEB_SOCKET_HANDLE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WEB_SOCKET_HTTP_HEADER {
     pub pcName: windows_core::PSTR,
     pub ulNameLength: u32,
@@ -281,7 +290,8 @@ impl Default for WEB_SOCKET_HTTP_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WEB_SOCKET_PROPERTY {
     pub Type: WEB_SOCKET_PROPERTY_TYPE,
     pub pvValue: *mut core::ffi::c_void,
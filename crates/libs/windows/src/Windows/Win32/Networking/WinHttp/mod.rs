@@ -1204,6 +1204,7 @@ pub struct WINHTTP_ACCESS_TYPE(pub u32);
 impl windows_core::TypeKind for WINHTTP_ACCESS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WINHTTP_ACCESS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WINHTTP_ACCESS_TYPE").field(&self.0).finish()
@@ -1215,6 +1216,7 @@ pub struct WINHTTP_CREDS_AUTHSCHEME(pub u32);
 impl windows_core::TypeKind for WINHTTP_CREDS_AUTHSCHEME {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WINHTTP_CREDS_AUTHSCHEME {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WINHTTP_CREDS_AUTHSCHEME").field(&self.0).finish()
@@ -1226,6 +1228,7 @@ pub struct WINHTTP_INTERNET_SCHEME(pub i32);
 impl windows_core::TypeKind for WINHTTP_INTERNET_SCHEME {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WINHTTP_INTERNET_SCHEME {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WINHTTP_INTERNET_SCHEME").field(&self.0).finish()
@@ -1237,6 +1240,7 @@ pub struct WINHTTP_OPEN_REQUEST_FLAGS(pub u32);
 impl windows_core::TypeKind for WINHTTP_OPEN_REQUEST_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WINHTTP_OPEN_REQUEST_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WINHTTP_OPEN_REQUEST_FLAGS").field(&self.0).finish()
@@ -1281,6 +1285,7 @@ pub struct WINHTTP_PROXY_SETTINGS_TYPE(pub i32);
 impl windows_core::TypeKind for WINHTTP_PROXY_SETTINGS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WINHTTP_PROXY_SETTINGS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WINHTTP_PROXY_SETTINGS_TYPE").field(&self.0).finish()
@@ -1292,6 +1297,7 @@ pub struct WINHTTP_REQUEST_STAT_ENTRY(pub i32);
 impl windows_core::TypeKind for WINHTTP_REQUEST_STAT_ENTRY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WINHTTP_REQUEST_STAT_ENTRY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WINHTTP_REQUEST_STAT_ENTRY").field(&self.0).finish()
@@ -1303,6 +1309,7 @@ pub struct WINHTTP_REQUEST_TIME_ENTRY(pub i32);
 impl windows_core::TypeKind for WINHTTP_REQUEST_TIME_ENTRY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WINHTTP_REQUEST_TIME_ENTRY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WINHTTP_REQUEST_TIME_ENTRY").field(&self.0).finish()
@@ -1314,6 +1321,7 @@ pub struct WINHTTP_SECURE_DNS_SETTING(pub i32);
 impl windows_core::TypeKind for WINHTTP_SECURE_DNS_SETTING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WINHTTP_SECURE_DNS_SETTING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WINHTTP_SECURE_DNS_SETTING").field(&self.0).finish()
@@ -1325,6 +1333,7 @@ pub struct WINHTTP_WEB_SOCKET_BUFFER_TYPE(pub i32);
 impl windows_core::TypeKind for WINHTTP_WEB_SOCKET_BUFFER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WINHTTP_WEB_SOCKET_BUFFER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WINHTTP_WEB_SOCKET_BUFFER_TYPE").field(&self.0).finish()
@@ -1336,6 +1345,7 @@ pub struct WINHTTP_WEB_SOCKET_CLOSE_STATUS(pub i32);
 impl windows_core::TypeKind for WINHTTP_WEB_SOCKET_CLOSE_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WINHTTP_WEB_SOCKET_CLOSE_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WINHTTP_WEB_SOCKET_CLOSE_STATUS").field(&self.0).finish()
@@ -1347,6 +1357,7 @@ pub struct WINHTTP_WEB_SOCKET_OPERATION(pub i32);
 impl windows_core::TypeKind for WINHTTP_WEB_SOCKET_OPERATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WINHTTP_WEB_SOCKET_OPERATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WINHTTP_WEB_SOCKET_OPERATION").field(&self.0).finish()
@@ -1358,6 +1369,7 @@ pub struct WIN_HTTP_CREATE_URL_FLAGS(pub u32);
 impl windows_core::TypeKind for WIN_HTTP_CREATE_URL_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WIN_HTTP_CREATE_URL_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WIN_HTTP_CREATE_URL_FLAGS").field(&self.0).finish()
@@ -1369,6 +1381,7 @@ pub struct WinHttpRequestAutoLogonPolicy(pub i32);
 impl windows_core::TypeKind for WinHttpRequestAutoLogonPolicy {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WinHttpRequestAutoLogonPolicy {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WinHttpRequestAutoLogonPolicy").field(&self.0).finish()
@@ -1380,6 +1393,7 @@ pub struct WinHttpRequestOption(pub i32);
 impl windows_core::TypeKind for WinHttpRequestOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WinHttpRequestOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WinHttpRequestOption").field(&self.0).finish()
@@ -1391,6 +1405,7 @@ pub struct WinHttpRequestSecureProtocols(pub i32);
 impl windows_core::TypeKind for WinHttpRequestSecureProtocols {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WinHttpRequestSecureProtocols {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WinHttpRequestSecureProtocols").field(&self.0).finish()
@@ -1402,13 +1417,15 @@ pub struct WinHttpRequestSslErrorFlags(pub i32);
 impl windows_core::TypeKind for WinHttpRequestSslErrorFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WinHttpRequestSslErrorFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WinHttpRequestSslErrorFlags").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HTTP_VERSION_INFO {
     pub dwMajorVersion: u32,
     pub dwMinorVersion: u32,
@@ -1422,7 +1439,8 @@ impl Default for HTTP_VERSION_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct URL_COMPONENTS {
     pub dwStructSize: u32,
     pub lpszScheme: windows_core::PWSTR,
@@ -1449,7 +1467,8 @@ impl Default for URL_COMPONENTS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINHTTP_ASYNC_RESULT {
     pub dwResult: usize,
     pub dwError: u32,
@@ -1463,7 +1482,8 @@ impl Default for WINHTTP_ASYNC_RESULT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINHTTP_AUTOPROXY_OPTIONS {
     pub dwFlags: u32,
     pub dwAutoDetectFlags: u32,
@@ -1481,7 +1501,8 @@ impl Default for WINHTTP_AUTOPROXY_OPTIONS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINHTTP_CERTIFICATE_INFO {
     pub ftExpiry: super::super::Foundation::FILETIME,
     pub ftStart: super::super::Foundation::FILETIME,
@@ -1501,7 +1522,8 @@ impl Default for WINHTTP_CERTIFICATE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINHTTP_CONNECTION_GROUP {
     pub cConnections: u32,
     pub guidGroup: windows_core::GUID,
@@ -1557,7 +1579,8 @@ impl Default for WINHTTP_CONNECTION_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINHTTP_CREDS {
     pub lpszUserName: windows_core::PSTR,
     pub lpszPassword: windows_core::PSTR,
@@ -1575,7 +1598,8 @@ impl Default for WINHTTP_CREDS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINHTTP_CREDS_EX {
     pub lpszUserName: windows_core::PSTR,
     pub lpszPassword: windows_core::PSTR,
@@ -1594,7 +1618,8 @@ impl Default for WINHTTP_CREDS_EX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINHTTP_CURRENT_USER_IE_PROXY_CONFIG {
     pub fAutoDetect: super::super::Foundation::BOOL,
     pub lpszAutoConfigUrl: windows_core::PWSTR,
@@ -1652,7 +1677,8 @@ impl Default for WINHTTP_EXTENDED_HEADER_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINHTTP_FAILED_CONNECTION_RETRIES {
     pub dwMaxRetries: u32,
     pub dwAllowedRetryConditions: u32,
@@ -1680,7 +1706,8 @@ impl Default for WINHTTP_HEADER_NAME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINHTTP_HOST_CONNECTION_GROUP {
     pub pwszHost: windows_core::PCWSTR,
     pub cConnectionGroups: u32,
@@ -1695,7 +1722,8 @@ impl Default for WINHTTP_HOST_CONNECTION_GROUP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINHTTP_HTTP2_RECEIVE_WINDOW {
     pub ulStreamWindow: u32,
     pub ulStreamWindowUpdateDelta: u32,
@@ -1743,7 +1771,8 @@ impl Default for WINHTTP_MATCH_CONNECTION_GUID {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINHTTP_PROXY_INFO {
     pub dwAccessType: WINHTTP_ACCESS_TYPE,
     pub lpszProxy: windows_core::PWSTR,
@@ -1758,7 +1787,8 @@ impl Default for WINHTTP_PROXY_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINHTTP_PROXY_NETWORKING_KEY {
     pub pbBuffer: [u8; 128],
 }
@@ -1771,7 +1801,8 @@ impl Default for WINHTTP_PROXY_NETWORKING_KEY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINHTTP_PROXY_RESULT {
     pub cEntries: u32,
     pub pEntries: *mut WINHTTP_PROXY_RESULT_ENTRY,
@@ -1785,7 +1816,8 @@ impl Default for WINHTTP_PROXY_RESULT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINHTTP_PROXY_RESULT_ENTRY {
     pub fProxy: super::super::Foundation::BOOL,
     pub fBypass: super::super::Foundation::BOOL,
@@ -1802,7 +1834,8 @@ impl Default for WINHTTP_PROXY_RESULT_ENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINHTTP_PROXY_RESULT_EX {
     pub cEntries: u32,
     pub pEntries: *mut WINHTTP_PROXY_RESULT_ENTRY,
@@ -1818,7 +1851,8 @@ impl Default for WINHTTP_PROXY_RESULT_EX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINHTTP_PROXY_SETTINGS {
     pub dwStructSize: u32,
     pub dwFlags: u32,
@@ -1930,7 +1964,8 @@ impl Default for WINHTTP_PROXY_SETTINGS_PARAM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINHTTP_QUERY_CONNECTION_GROUP_RESULT {
     pub cHosts: u32,
     pub pHostConnectionGroups: *mut WINHTTP_HOST_CONNECTION_GROUP,
@@ -2058,7 +2093,8 @@ impl Default for WINHTTP_RESOLVER_CACHE_CONFIG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINHTTP_WEB_SOCKET_ASYNC_RESULT {
     pub AsyncResult: WINHTTP_ASYNC_RESULT,
     pub Operation: WINHTTP_WEB_SOCKET_OPERATION,
@@ -2072,7 +2108,8 @@ impl Default for WINHTTP_WEB_SOCKET_ASYNC_RESULT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINHTTP_WEB_SOCKET_STATUS {
     pub dwBytesTransferred: u32,
     pub eBufferType: WINHTTP_WEB_SOCKET_BUFFER_TYPE,
@@ -2088,5 +2125,8 @@ impl Default for WINHTTP_WEB_SOCKET_STATUS {
 pub const WinHttpRequest: windows_core::GUID = windows_core::GUID::from_u128(0x2087c2f4_2cef_4953_a8ab_66779b670495);
 pub type WINHTTP_PROXY_CHANGE_CALLBACK = Option<unsafe extern "system" fn(ullflags: u64, pvcontext: *const core::ffi::c_void)>;
 pub type WINHTTP_STATUS_CALLBACK = Option<unsafe extern "system" fn(hinternet: *mut core::ffi::c_void, dwcontext: usize, dwinternetstatus: u32, lpvstatusinformation: *mut core::ffi::c_void, dwstatusinformationlength: u32)>;
+core::include!(core::concat!(core::env!("CARGO_MANIFEST_DIR"), "/src/includes/", "Win32/Networking/WinHttp/HttpSession.rs"));
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,120 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWinHttpRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetProxy(this: *mut core::ffi::c_void, proxysetting: i32, proxyserver: core::mem::MaybeUninit<windows_core::VARIANT>, bypasslist: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinHttpRequest", "SetProxy", 7usize, |this| ((**(this as *const *const IWinHttpRequest_Vtbl)).SetProxy)(this, proxysetting, proxyserver, bypasslist), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCredentials(this: *mut core::ffi::c_void, username: core::mem::MaybeUninit<windows_core::BSTR>, password: core::mem::MaybeUninit<windows_core::BSTR>, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinHttpRequest", "SetCredentials", 8usize, |this| ((**(this as *const *const IWinHttpRequest_Vtbl)).SetCredentials)(this, username, password, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, method: core::mem::MaybeUninit<windows_core::BSTR>, url: core::mem::MaybeUninit<windows_core::BSTR>, r#async: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinHttpRequest", "Open", 9usize, |this| ((**(this as *const *const IWinHttpRequest_Vtbl)).Open)(this, method, url, r#async), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequestHeader(this: *mut core::ffi::c_void, header: core::mem::MaybeUninit<windows_core::BSTR>, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinHttpRequest", "SetRequestHeader", 10usize, |this| ((**(this as *const *const IWinHttpRequest_Vtbl)).SetRequestHeader)(this, header, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResponseHeader(this: *mut core::ffi::c_void, header: core::mem::MaybeUninit<windows_core::BSTR>, value: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinHttpRequest", "GetResponseHeader", 11usize, |this| ((**(this as *const *const IWinHttpRequest_Vtbl)).GetResponseHeader)(this, header, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllResponseHeaders(this: *mut core::ffi::c_void, headers: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinHttpRequest", "GetAllResponseHeaders", 12usize, |this| ((**(this as *const *const IWinHttpRequest_Vtbl)).GetAllResponseHeaders)(this, headers), |result| Some(*result))
+        }
+        unsafe extern "system" fn Send(this: *mut core::ffi::c_void, body: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinHttpRequest", "Send", 13usize, |this| ((**(this as *const *const IWinHttpRequest_Vtbl)).Send)(this, body), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, status: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinHttpRequest", "Status", 14usize, |this| ((**(this as *const *const IWinHttpRequest_Vtbl)).Status)(this, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusText(this: *mut core::ffi::c_void, status: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinHttpRequest", "StatusText", 15usize, |this| ((**(this as *const *const IWinHttpRequest_Vtbl)).StatusText)(this, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResponseText(this: *mut core::ffi::c_void, body: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinHttpRequest", "ResponseText", 16usize, |this| ((**(this as *const *const IWinHttpRequest_Vtbl)).ResponseText)(this, body), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResponseBody(this: *mut core::ffi::c_void, body: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinHttpRequest", "ResponseBody", 17usize, |this| ((**(this as *const *const IWinHttpRequest_Vtbl)).ResponseBody)(this, body), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResponseStream(this: *mut core::ffi::c_void, body: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinHttpRequest", "ResponseStream", 18usize, |this| ((**(this as *const *const IWinHttpRequest_Vtbl)).ResponseStream)(this, body), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Option(this: *mut core::ffi::c_void, option: WinHttpRequestOption, value: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinHttpRequest", "get_Option", 19usize, |this| ((**(this as *const *const IWinHttpRequest_Vtbl)).get_Option)(this, option, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_Option(this: *mut core::ffi::c_void, option: WinHttpRequestOption, value: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinHttpRequest", "put_Option", 20usize, |this| ((**(this as *const *const IWinHttpRequest_Vtbl)).put_Option)(this, option, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForResponse(this: *mut core::ffi::c_void, timeout: core::mem::MaybeUninit<windows_core::VARIANT>, succeeded: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinHttpRequest", "WaitForResponse", 21usize, |this| ((**(this as *const *const IWinHttpRequest_Vtbl)).WaitForResponse)(this, timeout, succeeded), |result| Some(*result))
+        }
+        unsafe extern "system" fn Abort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinHttpRequest", "Abort", 22usize, |this| ((**(this as *const *const IWinHttpRequest_Vtbl)).Abort)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTimeouts(this: *mut core::ffi::c_void, resolvetimeout: i32, connecttimeout: i32, sendtimeout: i32, receivetimeout: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinHttpRequest", "SetTimeouts", 23usize, |this| ((**(this as *const *const IWinHttpRequest_Vtbl)).SetTimeouts)(this, resolvetimeout, connecttimeout, sendtimeout, receivetimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClientCertificate(this: *mut core::ffi::c_void, clientcertificate: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinHttpRequest", "SetClientCertificate", 24usize, |this| ((**(this as *const *const IWinHttpRequest_Vtbl)).SetClientCertificate)(this, clientcertificate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoLogonPolicy(this: *mut core::ffi::c_void, autologonpolicy: WinHttpRequestAutoLogonPolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinHttpRequest", "SetAutoLogonPolicy", 25usize, |this| ((**(this as *const *const IWinHttpRequest_Vtbl)).SetAutoLogonPolicy)(this, autologonpolicy), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetProxy,
+            SetCredentials,
+            Open,
+            SetRequestHeader,
+            GetResponseHeader,
+            GetAllResponseHeaders,
+            Send,
+            Status,
+            StatusText,
+            ResponseText,
+            ResponseBody,
+            ResponseStream,
+            get_Option,
+            put_Option,
+            WaitForResponse,
+            Abort,
+            SetTimeouts,
+            SetClientCertificate,
+            SetAutoLogonPolicy,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWinHttpRequest as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWinHttpRequestEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnResponseStart(this: *mut core::ffi::c_void, status: i32, contenttype: core::mem::MaybeUninit<windows_core::BSTR>) {
+            windows_core::imp::proxy_call(this, "IWinHttpRequestEvents", "OnResponseStart", 3usize, |this| ((**(this as *const *const IWinHttpRequestEvents_Vtbl)).OnResponseStart)(this, status, contenttype), |_| None)
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnResponseDataAvailable(this: *mut core::ffi::c_void, data: *const *const super::super::System::Com::SAFEARRAY) {
+            windows_core::imp::proxy_call(this, "IWinHttpRequestEvents", "OnResponseDataAvailable", 4usize, |this| ((**(this as *const *const IWinHttpRequestEvents_Vtbl)).OnResponseDataAvailable)(this, data), |_| None)
+        }
+        unsafe extern "system" fn OnResponseFinished(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IWinHttpRequestEvents", "OnResponseFinished", 5usize, |this| ((**(this as *const *const IWinHttpRequestEvents_Vtbl)).OnResponseFinished)(this), |_| None)
+        }
+        unsafe extern "system" fn OnError(this: *mut core::ffi::c_void, errornumber: i32, errordescription: core::mem::MaybeUninit<windows_core::BSTR>) {
+            windows_core::imp::proxy_call(this, "IWinHttpRequestEvents", "OnError", 6usize, |this| ((**(this as *const *const IWinHttpRequestEvents_Vtbl)).OnError)(this, errornumber, errordescription), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnResponseStart,
+            #[cfg(feature = "Win32_System_Com")]
+            OnResponseDataAvailable,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnResponseDataAvailable: 0,
+            OnResponseFinished,
+            OnError,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWinHttpRequestEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
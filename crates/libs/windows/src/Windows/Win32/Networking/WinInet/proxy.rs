@@ -0,0 +1,99 @@
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IDialBranding_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pwzconnectoid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialBranding", "Initialize", 3usize, |this| ((**(this as *const *const IDialBranding_Vtbl)).Initialize)(this, pwzconnectoid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetBitmap(this: *mut core::ffi::c_void, dwindex: u32, phbitmap: *mut super::super::Graphics::Gdi::HBITMAP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialBranding", "GetBitmap", 4usize, |this| ((**(this as *const *const IDialBranding_Vtbl)).GetBitmap)(this, dwindex, phbitmap), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetBitmap,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetBitmap: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDialBranding as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDialEngine_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pwzconnectoid: windows_core::PCWSTR, pides: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialEngine", "Initialize", 3usize, |this| ((**(this as *const *const IDialEngine_Vtbl)).Initialize)(this, pwzconnectoid, pides), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, pwzproperty: windows_core::PCWSTR, pwzvalue: windows_core::PCWSTR, dwbufsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialEngine", "GetProperty", 4usize, |this| ((**(this as *const *const IDialEngine_Vtbl)).GetProperty)(this, pwzproperty, pwzvalue, dwbufsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, pwzproperty: windows_core::PCWSTR, pwzvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialEngine", "SetProperty", 5usize, |this| ((**(this as *const *const IDialEngine_Vtbl)).SetProperty)(this, pwzproperty, pwzvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Dial(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialEngine", "Dial", 6usize, |this| ((**(this as *const *const IDialEngine_Vtbl)).Dial)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn HangUp(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialEngine", "HangUp", 7usize, |this| ((**(this as *const *const IDialEngine_Vtbl)).HangUp)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConnectedState(this: *mut core::ffi::c_void, pdwstate: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialEngine", "GetConnectedState", 8usize, |this| ((**(this as *const *const IDialEngine_Vtbl)).GetConnectedState)(this, pdwstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConnectHandle(this: *mut core::ffi::c_void, pdwhandle: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialEngine", "GetConnectHandle", 9usize, |this| ((**(this as *const *const IDialEngine_Vtbl)).GetConnectHandle)(this, pdwhandle), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetProperty,
+            SetProperty,
+            Dial,
+            HangUp,
+            GetConnectedState,
+            GetConnectHandle,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDialEngine as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDialEventSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnEvent(this: *mut core::ffi::c_void, dwevent: u32, dwstatus: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDialEventSink", "OnEvent", 3usize, |this| ((**(this as *const *const IDialEventSink_Vtbl)).OnEvent)(this, dwevent, dwstatus), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDialEventSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProofOfPossessionCookieInfoManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCookieInfoForUri(this: *mut core::ffi::c_void, uri: windows_core::PCWSTR, cookieinfocount: *mut u32, cookieinfo: *mut *mut ProofOfPossessionCookieInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProofOfPossessionCookieInfoManager", "GetCookieInfoForUri", 3usize, |this| ((**(this as *const *const IProofOfPossessionCookieInfoManager_Vtbl)).GetCookieInfoForUri)(this, uri, cookieinfocount, cookieinfo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCookieInfoForUri }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProofOfPossessionCookieInfoManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProofOfPossessionCookieInfoManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCookieInfoWithUriForAccount(this: *mut core::ffi::c_void, webaccount: *mut core::ffi::c_void, uri: windows_core::PCWSTR, cookieinfocount: *mut u32, cookieinfo: *mut *mut ProofOfPossessionCookieInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProofOfPossessionCookieInfoManager2", "GetCookieInfoWithUriForAccount", 3usize, |this| ((**(this as *const *const IProofOfPossessionCookieInfoManager2_Vtbl)).GetCookieInfoWithUriForAccount)(this, webaccount, uri, cookieinfocount, cookieinfo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCookieInfoWithUriForAccount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProofOfPossessionCookieInfoManager2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -3082,6 +3082,7 @@ pub struct AAL_TYPE(pub i32);
 impl windows_core::TypeKind for AAL_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AAL_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AAL_TYPE").field(&self.0).finish()
@@ -3093,6 +3094,7 @@ pub struct ADDRESS_FAMILY(pub u16);
 impl windows_core::TypeKind for ADDRESS_FAMILY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ADDRESS_FAMILY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ADDRESS_FAMILY").field(&self.0).finish()
@@ -3104,6 +3106,7 @@ pub struct ARP_HARDWARE_TYPE(pub i32);
 impl windows_core::TypeKind for ARP_HARDWARE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ARP_HARDWARE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ARP_HARDWARE_TYPE").field(&self.0).finish()
@@ -3115,6 +3118,7 @@ pub struct ARP_OPCODE(pub i32);
 impl windows_core::TypeKind for ARP_OPCODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ARP_OPCODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ARP_OPCODE").field(&self.0).finish()
@@ -3126,6 +3130,7 @@ pub struct CONTROL_CHANNEL_TRIGGER_STATUS(pub i32);
 impl windows_core::TypeKind for CONTROL_CHANNEL_TRIGGER_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CONTROL_CHANNEL_TRIGGER_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CONTROL_CHANNEL_TRIGGER_STATUS").field(&self.0).finish()
@@ -3137,6 +3142,7 @@ pub struct FALLBACK_INDEX(pub i32);
 impl windows_core::TypeKind for FALLBACK_INDEX {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FALLBACK_INDEX {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FALLBACK_INDEX").field(&self.0).finish()
@@ -3148,6 +3154,7 @@ pub struct ICMP4_TIME_EXCEED_CODE(pub i32);
 impl windows_core::TypeKind for ICMP4_TIME_EXCEED_CODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ICMP4_TIME_EXCEED_CODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ICMP4_TIME_EXCEED_CODE").field(&self.0).finish()
@@ -3159,6 +3166,7 @@ pub struct ICMP4_UNREACH_CODE(pub i32);
 impl windows_core::TypeKind for ICMP4_UNREACH_CODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ICMP4_UNREACH_CODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ICMP4_UNREACH_CODE").field(&self.0).finish()
@@ -3170,6 +3178,7 @@ pub struct IGMP_MAX_RESP_CODE_TYPE(pub i32);
 impl windows_core::TypeKind for IGMP_MAX_RESP_CODE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IGMP_MAX_RESP_CODE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IGMP_MAX_RESP_CODE_TYPE").field(&self.0).finish()
@@ -3181,6 +3190,7 @@ pub struct IPPROTO(pub i32);
 impl windows_core::TypeKind for IPPROTO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IPPROTO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IPPROTO").field(&self.0).finish()
@@ -3192,6 +3202,7 @@ pub struct IPV4_OPTION_TYPE(pub i32);
 impl windows_core::TypeKind for IPV4_OPTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IPV4_OPTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IPV4_OPTION_TYPE").field(&self.0).finish()
@@ -3203,6 +3214,7 @@ pub struct IPV6_OPTION_TYPE(pub i32);
 impl windows_core::TypeKind for IPV6_OPTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IPV6_OPTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IPV6_OPTION_TYPE").field(&self.0).finish()
@@ -3214,6 +3226,7 @@ pub struct IP_OPTION_TIMESTAMP_FLAGS(pub i32);
 impl windows_core::TypeKind for IP_OPTION_TIMESTAMP_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IP_OPTION_TIMESTAMP_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IP_OPTION_TIMESTAMP_FLAGS").field(&self.0).finish()
@@ -3225,6 +3238,7 @@ pub struct MLD_MAX_RESP_CODE_TYPE(pub i32);
 impl windows_core::TypeKind for MLD_MAX_RESP_CODE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MLD_MAX_RESP_CODE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MLD_MAX_RESP_CODE_TYPE").field(&self.0).finish()
@@ -3236,6 +3250,7 @@ pub struct MULTICAST_MODE_TYPE(pub i32);
 impl windows_core::TypeKind for MULTICAST_MODE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MULTICAST_MODE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MULTICAST_MODE_TYPE").field(&self.0).finish()
@@ -3247,6 +3262,7 @@ pub struct NAPI_PROVIDER_LEVEL(pub i32);
 impl windows_core::TypeKind for NAPI_PROVIDER_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NAPI_PROVIDER_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NAPI_PROVIDER_LEVEL").field(&self.0).finish()
@@ -3258,6 +3274,7 @@ pub struct NAPI_PROVIDER_TYPE(pub i32);
 impl windows_core::TypeKind for NAPI_PROVIDER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NAPI_PROVIDER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NAPI_PROVIDER_TYPE").field(&self.0).finish()
@@ -3269,6 +3286,7 @@ pub struct ND_OPTION_TYPE(pub i32);
 impl windows_core::TypeKind for ND_OPTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ND_OPTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ND_OPTION_TYPE").field(&self.0).finish()
@@ -3280,6 +3298,7 @@ pub struct NLA_BLOB_DATA_TYPE(pub i32);
 impl windows_core::TypeKind for NLA_BLOB_DATA_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NLA_BLOB_DATA_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NLA_BLOB_DATA_TYPE").field(&self.0).finish()
@@ -3291,6 +3310,7 @@ pub struct NLA_CONNECTIVITY_TYPE(pub i32);
 impl windows_core::TypeKind for NLA_CONNECTIVITY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NLA_CONNECTIVITY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NLA_CONNECTIVITY_TYPE").field(&self.0).finish()
@@ -3302,6 +3322,7 @@ pub struct NLA_INTERNET(pub i32);
 impl windows_core::TypeKind for NLA_INTERNET {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NLA_INTERNET {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NLA_INTERNET").field(&self.0).finish()
@@ -3313,6 +3334,7 @@ pub struct NL_ADDRESS_TYPE(pub i32);
 impl windows_core::TypeKind for NL_ADDRESS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NL_ADDRESS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NL_ADDRESS_TYPE").field(&self.0).finish()
@@ -3324,6 +3346,7 @@ pub struct NL_BANDWIDTH_FLAG(pub i32);
 impl windows_core::TypeKind for NL_BANDWIDTH_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NL_BANDWIDTH_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NL_BANDWIDTH_FLAG").field(&self.0).finish()
@@ -3335,6 +3358,7 @@ pub struct NL_DAD_STATE(pub i32);
 impl windows_core::TypeKind for NL_DAD_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NL_DAD_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NL_DAD_STATE").field(&self.0).finish()
@@ -3346,6 +3370,7 @@ pub struct NL_INTERFACE_NETWORK_CATEGORY_STATE(pub i32);
 impl windows_core::TypeKind for NL_INTERFACE_NETWORK_CATEGORY_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NL_INTERFACE_NETWORK_CATEGORY_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NL_INTERFACE_NETWORK_CATEGORY_STATE").field(&self.0).finish()
@@ -3357,6 +3382,7 @@ pub struct NL_LINK_LOCAL_ADDRESS_BEHAVIOR(pub i32);
 impl windows_core::TypeKind for NL_LINK_LOCAL_ADDRESS_BEHAVIOR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NL_LINK_LOCAL_ADDRESS_BEHAVIOR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NL_LINK_LOCAL_ADDRESS_BEHAVIOR").field(&self.0).finish()
@@ -3368,6 +3394,7 @@ pub struct NL_NEIGHBOR_STATE(pub i32);
 impl windows_core::TypeKind for NL_NEIGHBOR_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NL_NEIGHBOR_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NL_NEIGHBOR_STATE").field(&self.0).finish()
@@ -3379,6 +3406,7 @@ pub struct NL_NETWORK_CATEGORY(pub i32);
 impl windows_core::TypeKind for NL_NETWORK_CATEGORY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NL_NETWORK_CATEGORY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NL_NETWORK_CATEGORY").field(&self.0).finish()
@@ -3390,6 +3418,7 @@ pub struct NL_NETWORK_CONNECTIVITY_COST_HINT(pub i32);
 impl windows_core::TypeKind for NL_NETWORK_CONNECTIVITY_COST_HINT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NL_NETWORK_CONNECTIVITY_COST_HINT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NL_NETWORK_CONNECTIVITY_COST_HINT").field(&self.0).finish()
@@ -3401,6 +3430,7 @@ pub struct NL_NETWORK_CONNECTIVITY_LEVEL_HINT(pub i32);
 impl windows_core::TypeKind for NL_NETWORK_CONNECTIVITY_LEVEL_HINT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NL_NETWORK_CONNECTIVITY_LEVEL_HINT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NL_NETWORK_CONNECTIVITY_LEVEL_HINT").field(&self.0).finish()
@@ -3412,6 +3442,7 @@ pub struct NL_PREFIX_ORIGIN(pub i32);
 impl windows_core::TypeKind for NL_PREFIX_ORIGIN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NL_PREFIX_ORIGIN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NL_PREFIX_ORIGIN").field(&self.0).finish()
@@ -3423,6 +3454,7 @@ pub struct NL_ROUTER_DISCOVERY_BEHAVIOR(pub i32);
 impl windows_core::TypeKind for NL_ROUTER_DISCOVERY_BEHAVIOR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NL_ROUTER_DISCOVERY_BEHAVIOR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NL_ROUTER_DISCOVERY_BEHAVIOR").field(&self.0).finish()
@@ -3434,6 +3466,7 @@ pub struct NL_ROUTE_ORIGIN(pub i32);
 impl windows_core::TypeKind for NL_ROUTE_ORIGIN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NL_ROUTE_ORIGIN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NL_ROUTE_ORIGIN").field(&self.0).finish()
@@ -3445,6 +3478,7 @@ pub struct NL_ROUTE_PROTOCOL(pub i32);
 impl windows_core::TypeKind for NL_ROUTE_PROTOCOL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NL_ROUTE_PROTOCOL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NL_ROUTE_PROTOCOL").field(&self.0).finish()
@@ -3456,6 +3490,7 @@ pub struct NL_SUFFIX_ORIGIN(pub i32);
 impl windows_core::TypeKind for NL_SUFFIX_ORIGIN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NL_SUFFIX_ORIGIN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NL_SUFFIX_ORIGIN").field(&self.0).finish()
@@ -3467,6 +3502,7 @@ pub struct NPI_MODULEID_TYPE(pub i32);
 impl windows_core::TypeKind for NPI_MODULEID_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NPI_MODULEID_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NPI_MODULEID_TYPE").field(&self.0).finish()
@@ -3478,6 +3514,7 @@ pub struct PMTUD_STATE(pub i32);
 impl windows_core::TypeKind for PMTUD_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PMTUD_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PMTUD_STATE").field(&self.0).finish()
@@ -3489,6 +3526,7 @@ pub struct Q2931_IE_TYPE(pub i32);
 impl windows_core::TypeKind for Q2931_IE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for Q2931_IE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Q2931_IE_TYPE").field(&self.0).finish()
@@ -3500,6 +3538,7 @@ pub struct RCVALL_VALUE(pub i32);
 impl windows_core::TypeKind for RCVALL_VALUE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RCVALL_VALUE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RCVALL_VALUE").field(&self.0).finish()
@@ -3511,6 +3550,7 @@ pub struct RESOURCE_DISPLAY_TYPE(pub u32);
 impl windows_core::TypeKind for RESOURCE_DISPLAY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RESOURCE_DISPLAY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RESOURCE_DISPLAY_TYPE").field(&self.0).finish()
@@ -3522,6 +3562,7 @@ pub struct RIO_NOTIFICATION_COMPLETION_TYPE(pub i32);
 impl windows_core::TypeKind for RIO_NOTIFICATION_COMPLETION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RIO_NOTIFICATION_COMPLETION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RIO_NOTIFICATION_COMPLETION_TYPE").field(&self.0).finish()
@@ -3533,6 +3574,7 @@ pub struct SCOPE_LEVEL(pub i32);
 impl windows_core::TypeKind for SCOPE_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCOPE_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCOPE_LEVEL").field(&self.0).finish()
@@ -3544,6 +3586,7 @@ pub struct SEND_RECV_FLAGS(pub i32);
 impl windows_core::TypeKind for SEND_RECV_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SEND_RECV_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SEND_RECV_FLAGS").field(&self.0).finish()
@@ -3588,6 +3631,7 @@ pub struct SET_SERVICE_OPERATION(pub u32);
 impl windows_core::TypeKind for SET_SERVICE_OPERATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SET_SERVICE_OPERATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SET_SERVICE_OPERATION").field(&self.0).finish()
@@ -3599,6 +3643,7 @@ pub struct SOCKET_PRIORITY_HINT(pub i32);
 impl windows_core::TypeKind for SOCKET_PRIORITY_HINT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SOCKET_PRIORITY_HINT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SOCKET_PRIORITY_HINT").field(&self.0).finish()
@@ -3610,6 +3655,7 @@ pub struct SOCKET_SECURITY_PROTOCOL(pub i32);
 impl windows_core::TypeKind for SOCKET_SECURITY_PROTOCOL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SOCKET_SECURITY_PROTOCOL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SOCKET_SECURITY_PROTOCOL").field(&self.0).finish()
@@ -3621,6 +3667,7 @@ pub struct SOCKET_USAGE_TYPE(pub i32);
 impl windows_core::TypeKind for SOCKET_USAGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SOCKET_USAGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SOCKET_USAGE_TYPE").field(&self.0).finish()
@@ -3632,6 +3679,7 @@ pub struct TCPSTATE(pub i32);
 impl windows_core::TypeKind for TCPSTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TCPSTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TCPSTATE").field(&self.0).finish()
@@ -3643,6 +3691,7 @@ pub struct TCP_ICW_LEVEL(pub i32);
 impl windows_core::TypeKind for TCP_ICW_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TCP_ICW_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TCP_ICW_LEVEL").field(&self.0).finish()
@@ -3654,6 +3703,7 @@ pub struct TUNNEL_SUB_TYPE(pub i32);
 impl windows_core::TypeKind for TUNNEL_SUB_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TUNNEL_SUB_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TUNNEL_SUB_TYPE").field(&self.0).finish()
@@ -3665,6 +3715,7 @@ pub struct WINSOCK_SHUTDOWN_HOW(pub i32);
 impl windows_core::TypeKind for WINSOCK_SHUTDOWN_HOW {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WINSOCK_SHUTDOWN_HOW {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WINSOCK_SHUTDOWN_HOW").field(&self.0).finish()
@@ -3676,6 +3727,7 @@ pub struct WINSOCK_SOCKET_TYPE(pub i32);
 impl windows_core::TypeKind for WINSOCK_SOCKET_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WINSOCK_SOCKET_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WINSOCK_SOCKET_TYPE").field(&self.0).finish()
@@ -3687,6 +3739,7 @@ pub struct WSACOMPLETIONTYPE(pub i32);
 impl windows_core::TypeKind for WSACOMPLETIONTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WSACOMPLETIONTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WSACOMPLETIONTYPE").field(&self.0).finish()
@@ -3698,6 +3751,7 @@ pub struct WSAECOMPARATOR(pub i32);
 impl windows_core::TypeKind for WSAECOMPARATOR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WSAECOMPARATOR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WSAECOMPARATOR").field(&self.0).finish()
@@ -3709,6 +3763,7 @@ pub struct WSAESETSERVICEOP(pub i32);
 impl windows_core::TypeKind for WSAESETSERVICEOP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WSAESETSERVICEOP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WSAESETSERVICEOP").field(&self.0).finish()
@@ -3720,6 +3775,7 @@ pub struct WSAPOLL_EVENT_FLAGS(pub i16);
 impl windows_core::TypeKind for WSAPOLL_EVENT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WSAPOLL_EVENT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WSAPOLL_EVENT_FLAGS").field(&self.0).finish()
@@ -3764,6 +3820,7 @@ pub struct WSA_COMPATIBILITY_BEHAVIOR_ID(pub i32);
 impl windows_core::TypeKind for WSA_COMPATIBILITY_BEHAVIOR_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WSA_COMPATIBILITY_BEHAVIOR_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WSA_COMPATIBILITY_BEHAVIOR_ID").field(&self.0).finish()
@@ -3775,6 +3832,7 @@ pub struct WSA_ERROR(pub i32);
 impl windows_core::TypeKind for WSA_ERROR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WSA_ERROR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WSA_ERROR").field(&self.0).finish()
@@ -3786,6 +3844,7 @@ pub struct WSC_PROVIDER_INFO_TYPE(pub i32);
 impl windows_core::TypeKind for WSC_PROVIDER_INFO_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WSC_PROVIDER_INFO_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WSC_PROVIDER_INFO_TYPE").field(&self.0).finish()
@@ -3797,13 +3856,15 @@ pub struct eWINDOW_ADVANCE_METHOD(pub i32);
 impl windows_core::TypeKind for eWINDOW_ADVANCE_METHOD {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eWINDOW_ADVANCE_METHOD {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eWINDOW_ADVANCE_METHOD").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AAL5_PARAMETERS {
     pub ForwardMaxCPCSSDUSize: u32,
     pub BackwardMaxCPCSSDUSize: u32,
@@ -3819,7 +3880,8 @@ impl Default for AAL5_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AALUSER_PARAMETERS {
     pub UserDefined: u32,
 }
@@ -3860,7 +3922,8 @@ impl Default for AAL_PARAMETERS_IE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ADDRINFOA {
     pub ai_flags: i32,
     pub ai_family: i32,
@@ -3880,7 +3943,8 @@ impl Default for ADDRINFOA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ADDRINFOEX2A {
     pub ai_flags: i32,
     pub ai_family: i32,
@@ -3905,7 +3969,8 @@ impl Default for ADDRINFOEX2A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ADDRINFOEX2W {
     pub ai_flags: i32,
     pub ai_family: i32,
@@ -3930,7 +3995,8 @@ impl Default for ADDRINFOEX2W {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ADDRINFOEX3 {
     pub ai_flags: i32,
     pub ai_family: i32,
@@ -3956,7 +4022,8 @@ impl Default for ADDRINFOEX3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ADDRINFOEX4 {
     pub ai_flags: i32,
     pub ai_family: i32,
@@ -3983,7 +4050,8 @@ impl Default for ADDRINFOEX4 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ADDRINFOEX5 {
     pub ai_flags: i32,
     pub ai_family: i32,
@@ -4011,7 +4079,8 @@ impl Default for ADDRINFOEX5 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ADDRINFOEX6 {
     pub ai_flags: i32,
     pub ai_family: i32,
@@ -4042,7 +4111,8 @@ impl Default for ADDRINFOEX6 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ADDRINFOEXA {
     pub ai_flags: i32,
     pub ai_family: i32,
@@ -4065,7 +4135,8 @@ impl Default for ADDRINFOEXA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ADDRINFOEXW {
     pub ai_flags: i32,
     pub ai_family: i32,
@@ -4088,7 +4159,8 @@ impl Default for ADDRINFOEXW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ADDRINFOW {
     pub ai_flags: i32,
     pub ai_family: i32,
@@ -4138,7 +4210,8 @@ impl Default for ADDRINFO_DNS_SERVER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AFPROTOCOLS {
     pub iAddressFamily: i32,
     pub iProtocol: i32,
@@ -4152,7 +4225,8 @@ impl Default for AFPROTOCOLS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ARP_HEADER {
     pub HardwareAddressSpace: u16,
     pub ProtocolAddressSpace: u16,
@@ -4170,7 +4244,8 @@ impl Default for ARP_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ASSOCIATE_NAMERES_CONTEXT_INPUT {
     pub TransportSettingId: TRANSPORT_SETTING_ID,
     pub Handle: u64,
@@ -4184,7 +4259,8 @@ impl Default for ASSOCIATE_NAMERES_CONTEXT_INPUT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ATM_ADDRESS {
     pub AddressType: u32,
     pub NumofDigits: u32,
@@ -4199,7 +4275,8 @@ impl Default for ATM_ADDRESS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ATM_BHLI {
     pub HighLayerInfoType: u32,
     pub HighLayerInfoLength: u32,
@@ -4214,7 +4291,8 @@ impl Default for ATM_BHLI {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ATM_BLLI {
     pub Layer2Protocol: u32,
     pub Layer2UserSpecifiedProtocol: u32,
@@ -4232,7 +4310,8 @@ impl Default for ATM_BLLI {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ATM_BLLI_IE {
     pub Layer2Protocol: u32,
     pub Layer2Mode: u8,
@@ -4255,7 +4334,8 @@ impl Default for ATM_BLLI_IE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ATM_BROADBAND_BEARER_CAPABILITY_IE {
     pub BearerClass: u8,
     pub TrafficType: u8,
@@ -4272,7 +4352,8 @@ impl Default for ATM_BROADBAND_BEARER_CAPABILITY_IE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ATM_CALLING_PARTY_NUMBER_IE {
     pub ATM_Number: ATM_ADDRESS,
     pub Presentation_Indication: u8,
@@ -4287,7 +4368,8 @@ impl Default for ATM_CALLING_PARTY_NUMBER_IE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ATM_CAUSE_IE {
     pub Location: u8,
     pub Cause: u8,
@@ -4303,7 +4385,8 @@ impl Default for ATM_CAUSE_IE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ATM_CONNECTION_ID {
     pub DeviceNumber: u32,
     pub VPI: u32,
@@ -4332,7 +4415,8 @@ impl Default for ATM_PVC_PARAMS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ATM_QOS_CLASS_IE {
     pub QOSClassForward: u8,
     pub QOSClassBackward: u8,
@@ -4346,7 +4430,8 @@ impl Default for ATM_QOS_CLASS_IE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ATM_TD {
     pub PeakCellRate_CLP0: u32,
     pub PeakCellRate_CLP01: u32,
@@ -4365,7 +4450,8 @@ impl Default for ATM_TD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ATM_TRAFFIC_DESCRIPTOR_IE {
     pub Forward: ATM_TD,
     pub Backward: ATM_TD,
@@ -4380,7 +4466,8 @@ impl Default for ATM_TRAFFIC_DESCRIPTOR_IE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ATM_TRANSIT_NETWORK_SELECTION_IE {
     pub TypeOfNetworkId: u8,
     pub NetworkIdPlan: u8,
@@ -4396,7 +4483,8 @@ impl Default for ATM_TRANSIT_NETWORK_SELECTION_IE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CMSGHDR {
     pub cmsg_len: usize,
     pub cmsg_level: i32,
@@ -4411,7 +4499,8 @@ impl Default for CMSGHDR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CSADDR_INFO {
     pub LocalAddr: SOCKET_ADDRESS,
     pub RemoteAddr: SOCKET_ADDRESS,
@@ -4553,10 +4642,25 @@ impl Default for DL_OUI {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DL_OUI_0 {
     pub _bitfield: u8,
 }
+impl DL_OUI_0 {
+    pub const fn Group(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_Group(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Local(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Local(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+}
 impl windows_core::TypeKind for DL_OUI_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4703,7 +4807,8 @@ impl Default for ETHERNET_HEADER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FD_SET {
     pub fd_count: u32,
     pub fd_array: [SOCKET; 64],
@@ -4717,7 +4822,8 @@ impl Default for FD_SET {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FLOWSPEC {
     pub TokenRate: u32,
     pub TokenBucketSize: u32,
@@ -4737,7 +4843,8 @@ impl Default for FLOWSPEC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GROUP_FILTER {
     pub gf_interface: u32,
     pub gf_group: SOCKADDR_STORAGE,
@@ -4754,7 +4861,8 @@ impl Default for GROUP_FILTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GROUP_REQ {
     pub gr_interface: u32,
     pub gr_group: SOCKADDR_STORAGE,
@@ -4768,7 +4876,8 @@ impl Default for GROUP_REQ {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GROUP_SOURCE_REQ {
     pub gsr_interface: u32,
     pub gsr_group: SOCKADDR_STORAGE,
@@ -4783,7 +4892,8 @@ impl Default for GROUP_SOURCE_REQ {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HOSTENT {
     pub h_name: windows_core::PSTR,
     pub h_aliases: *mut *mut i8,
@@ -4886,7 +4996,8 @@ impl Default for ICMP_ERROR_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ICMP_HEADER {
     pub Type: u8,
     pub Code: u8,
@@ -4940,6 +5051,26 @@ pub struct IGMPV3_QUERY_HEADER {
     pub Anonymous2: IGMPV3_QUERY_HEADER_1,
     pub SourceCount: u16,
 }
+impl IGMPV3_QUERY_HEADER {
+    pub const fn QuerierRobustnessVariable(&self) -> u8 {
+        self._bitfield & 7
+    }
+    pub fn set_QuerierRobustnessVariable(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn SuppressRouterSideProcessing(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_SuppressRouterSideProcessing(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+}
 impl windows_core::TypeKind for IGMPV3_QUERY_HEADER {
     type TypeKind = windows_core::CopyType;
 }
@@ -4963,10 +5094,31 @@ impl Default for IGMPV3_QUERY_HEADER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IGMPV3_QUERY_HEADER_0_0 {
     pub _bitfield: u8,
 }
+impl IGMPV3_QUERY_HEADER_0_0 {
+    pub const fn MaxRespCodeMantissa(&self) -> u8 {
+        self._bitfield & 15
+    }
+    pub fn set_MaxRespCodeMantissa(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn MaxRespCodeExponent(&self) -> u8 {
+        (self._bitfield >> 4) & 7
+    }
+    pub fn set_MaxRespCodeExponent(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(7 << 4)) | ((value & 7) << 4);
+    }
+    pub const fn MaxRespCodeType(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_MaxRespCodeType(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for IGMPV3_QUERY_HEADER_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4990,10 +5142,31 @@ impl Default for IGMPV3_QUERY_HEADER_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IGMPV3_QUERY_HEADER_1_0 {
     pub _bitfield: u8,
 }
+impl IGMPV3_QUERY_HEADER_1_0 {
+    pub const fn QQCMantissa(&self) -> u8 {
+        self._bitfield & 15
+    }
+    pub fn set_QQCMantissa(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn QQCExponent(&self) -> u8 {
+        (self._bitfield >> 4) & 7
+    }
+    pub fn set_QQCExponent(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(7 << 4)) | ((value & 7) << 4);
+    }
+    pub const fn QQCType(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_QQCType(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for IGMPV3_QUERY_HEADER_1_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5003,7 +5176,8 @@ impl Default for IGMPV3_QUERY_HEADER_1_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IGMPV3_REPORT_HEADER {
     pub Type: u8,
     pub Reserved: u8,
@@ -5066,10 +5240,25 @@ impl Default for IGMP_HEADER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IGMP_HEADER_0_0 {
     pub _bitfield: u8,
 }
+impl IGMP_HEADER_0_0 {
+    pub const fn Type(&self) -> u8 {
+        self._bitfield & 15
+    }
+    pub fn set_Type(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn Version(&self) -> u8 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_Version(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+}
 impl windows_core::TypeKind for IGMP_HEADER_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5149,7 +5338,8 @@ impl Default for IN6_PKTINFO_EX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct INET_PORT_RANGE {
     pub StartPort: u16,
     pub NumberOfPorts: u16,
@@ -5163,7 +5353,8 @@ impl Default for INET_PORT_RANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct INET_PORT_RESERVATION_INFORMATION {
     pub OwningPid: u32,
 }
@@ -5176,7 +5367,8 @@ impl Default for INET_PORT_RESERVATION_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct INET_PORT_RESERVATION_INSTANCE {
     pub Reservation: INET_PORT_RANGE,
     pub Token: INET_PORT_RESERVATION_TOKEN,
@@ -5190,7 +5382,8 @@ impl Default for INET_PORT_RESERVATION_INSTANCE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct INET_PORT_RESERVATION_TOKEN {
     pub Token: u64,
 }
@@ -5219,7 +5412,8 @@ impl Default for INTERFACE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct INTERFACE_INFO_EX {
     pub iiFlags: u32,
     pub iiAddress: SOCKET_ADDRESS,
@@ -5263,7 +5457,8 @@ impl Default for IN_ADDR_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IN_ADDR_0_0 {
     pub s_b1: u8,
     pub s_b2: u8,
@@ -5279,7 +5474,8 @@ impl Default for IN_ADDR_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IN_ADDR_0_1 {
     pub s_w1: u16,
     pub s_w2: u16,
@@ -5321,7 +5517,8 @@ impl Default for IN_PKTINFO_EX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IN_RECVERR {
     pub protocol: IPPROTO,
     pub info: u32,
@@ -5386,10 +5583,25 @@ impl Default for IPV4_HEADER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IPV4_HEADER_0_0 {
     pub _bitfield: u8,
 }
+impl IPV4_HEADER_0_0 {
+    pub const fn HeaderLength(&self) -> u8 {
+        self._bitfield & 15
+    }
+    pub fn set_HeaderLength(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn Version(&self) -> u8 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_Version(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+}
 impl windows_core::TypeKind for IPV4_HEADER_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5413,10 +5625,25 @@ impl Default for IPV4_HEADER_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IPV4_HEADER_1_0 {
     pub _bitfield: u8,
 }
+impl IPV4_HEADER_1_0 {
+    pub const fn EcnField(&self) -> u8 {
+        self._bitfield & 3
+    }
+    pub fn set_EcnField(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn TypeOfService(&self) -> u8 {
+        (self._bitfield >> 2) & 63
+    }
+    pub fn set_TypeOfService(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(63 << 2)) | ((value & 63) << 2);
+    }
+}
 impl windows_core::TypeKind for IPV4_HEADER_1_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5440,10 +5667,43 @@ impl Default for IPV4_HEADER_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IPV4_HEADER_2_0 {
     pub _bitfield: u16,
 }
+impl IPV4_HEADER_2_0 {
+    pub const fn DontUse1(&self) -> u16 {
+        self._bitfield & 31
+    }
+    pub fn set_DontUse1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !31) | (value & 31);
+    }
+    pub const fn MoreFragments(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_MoreFragments(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn DontFragment(&self) -> u16 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_DontFragment(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn DontUse2(&self) -> u16 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_DontUse2(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+}
 impl windows_core::TypeKind for IPV4_HEADER_2_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5481,10 +5741,31 @@ impl Default for IPV4_OPTION_HEADER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IPV4_OPTION_HEADER_0_0 {
     pub _bitfield: u8,
 }
+impl IPV4_OPTION_HEADER_0_0 {
+    pub const fn OptionNumber(&self) -> u8 {
+        self._bitfield & 31
+    }
+    pub fn set_OptionNumber(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !31) | (value & 31);
+    }
+    pub const fn OptionClass(&self) -> u8 {
+        (self._bitfield >> 5) & 3
+    }
+    pub fn set_OptionClass(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(3 << 5)) | ((value & 3) << 5);
+    }
+    pub const fn CopiedFlag(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_CopiedFlag(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for IPV4_OPTION_HEADER_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5537,10 +5818,25 @@ impl Default for IPV4_TIMESTAMP_OPTION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IPV4_TIMESTAMP_OPTION_0_0 {
     pub _bitfield: u8,
 }
+impl IPV4_TIMESTAMP_OPTION_0_0 {
+    pub const fn Flags(&self) -> u8 {
+        self._bitfield & 15
+    }
+    pub fn set_Flags(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn Overflow(&self) -> u8 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_Overflow(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+}
 impl windows_core::TypeKind for IPV4_TIMESTAMP_OPTION_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5550,7 +5846,8 @@ impl Default for IPV4_TIMESTAMP_OPTION_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IPV6_EXTENSION_HEADER {
     pub NextHeader: u8,
     pub Length: u8,
@@ -5594,10 +5891,37 @@ impl Default for IPV6_FRAGMENT_HEADER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IPV6_FRAGMENT_HEADER_0_0 {
     pub _bitfield: u16,
 }
+impl IPV6_FRAGMENT_HEADER_0_0 {
+    pub const fn DontUse1(&self) -> u16 {
+        self._bitfield & 255
+    }
+    pub fn set_DontUse1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn MoreFragments(&self) -> u16 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_MoreFragments(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn ReservedBits(&self) -> u16 {
+        (self._bitfield >> 9) & 3
+    }
+    pub fn set_ReservedBits(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 9)) | ((value & 3) << 9);
+    }
+    pub const fn DontUse2(&self) -> u16 {
+        (self._bitfield >> 11) & 31
+    }
+    pub fn set_DontUse2(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(31 << 11)) | ((value & 31) << 11);
+    }
+}
 impl windows_core::TypeKind for IPV6_FRAGMENT_HEADER_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5639,10 +5963,31 @@ impl Default for IPV6_HEADER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IPV6_HEADER_0_0 {
     pub _bitfield: u32,
 }
+impl IPV6_HEADER_0_0 {
+    pub const fn Anonymous1(&self) -> u32 {
+        self._bitfield & 15
+    }
+    pub fn set_Anonymous1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn Version(&self) -> u32 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_Version(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+    pub const fn Anonymous2(&self) -> u32 {
+        (self._bitfield >> 8) & 16777215
+    }
+    pub fn set_Anonymous2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(16777215 << 8)) | ((value & 16777215) << 8);
+    }
+}
 impl windows_core::TypeKind for IPV6_HEADER_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5680,11 +6025,38 @@ impl Default for IPV6_NEIGHBOR_ADVERTISEMENT_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IPV6_NEIGHBOR_ADVERTISEMENT_FLAGS_0 {
     pub _bitfield: u8,
     pub Reserved2: [u8; 3],
 }
+impl IPV6_NEIGHBOR_ADVERTISEMENT_FLAGS_0 {
+    pub const fn Reserved1(&self) -> u8 {
+        self._bitfield & 31
+    }
+    pub fn set_Reserved1(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !31) | (value & 31);
+    }
+    pub const fn Override(&self) -> u8 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_Override(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Solicited(&self) -> u8 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_Solicited(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Router(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Router(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for IPV6_NEIGHBOR_ADVERTISEMENT_FLAGS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5694,7 +6066,8 @@ impl Default for IPV6_NEIGHBOR_ADVERTISEMENT_FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IPV6_OPTION_HEADER {
     pub Type: u8,
     pub DataLength: u8,
@@ -5708,7 +6081,8 @@ impl Default for IPV6_OPTION_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IPV6_OPTION_JUMBOGRAM {
     pub Header: IPV6_OPTION_HEADER,
     pub JumbogramLength: [u8; 4],
@@ -5722,7 +6096,8 @@ impl Default for IPV6_OPTION_JUMBOGRAM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IPV6_OPTION_ROUTER_ALERT {
     pub Header: IPV6_OPTION_HEADER,
     pub Value: [u8; 2],
@@ -5750,10 +6125,43 @@ impl Default for IPV6_ROUTER_ADVERTISEMENT_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IPV6_ROUTER_ADVERTISEMENT_FLAGS_0 {
     pub _bitfield: u8,
 }
+impl IPV6_ROUTER_ADVERTISEMENT_FLAGS_0 {
+    pub const fn Reserved(&self) -> u8 {
+        self._bitfield & 7
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn Preference(&self) -> u8 {
+        (self._bitfield >> 3) & 3
+    }
+    pub fn set_Preference(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(3 << 3)) | ((value & 3) << 3);
+    }
+    pub const fn HomeAgent(&self) -> u8 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_HomeAgent(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn OtherStatefulConfiguration(&self) -> u8 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_OtherStatefulConfiguration(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn ManagedAddressConfiguration(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_ManagedAddressConfiguration(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for IPV6_ROUTER_ADVERTISEMENT_FLAGS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5763,7 +6171,8 @@ impl Default for IPV6_ROUTER_ADVERTISEMENT_FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IPV6_ROUTING_HEADER {
     pub NextHeader: u8,
     pub Length: u8,
@@ -5780,7 +6189,8 @@ impl Default for IPV6_ROUTING_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IPX_ADDRESS_DATA {
     pub adapternum: i32,
     pub netnum: [u8; 4],
@@ -5799,7 +6209,8 @@ impl Default for IPX_ADDRESS_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IPX_NETNUM_DATA {
     pub netnum: [u8; 4],
     pub hopcount: u16,
@@ -5816,7 +6227,8 @@ impl Default for IPX_NETNUM_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IPX_SPXCONNSTATUS_DATA {
     pub ConnectionState: u8,
     pub WatchDogActive: u8,
@@ -5892,7 +6304,8 @@ impl Default for IP_MSFILTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LINGER {
     pub l_onoff: u16,
     pub l_linger: u16,
@@ -5906,7 +6319,8 @@ impl Default for LINGER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LM_IRPARMS {
     pub nTXDataBytes: u32,
     pub nRXDataBytes: u32,
@@ -5936,6 +6350,26 @@ pub struct MLDV2_QUERY_HEADER {
     pub Anonymous2: MLDV2_QUERY_HEADER_1,
     pub SourceCount: u16,
 }
+impl MLDV2_QUERY_HEADER {
+    pub const fn QuerierRobustnessVariable(&self) -> u8 {
+        self._bitfield & 7
+    }
+    pub fn set_QuerierRobustnessVariable(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn SuppressRouterSideProcessing(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_SuppressRouterSideProcessing(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn QueryReserved(&self) -> u8 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_QueryReserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+}
 impl windows_core::TypeKind for MLDV2_QUERY_HEADER {
     type TypeKind = windows_core::CopyType;
 }
@@ -5959,10 +6393,37 @@ impl Default for MLDV2_QUERY_HEADER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MLDV2_QUERY_HEADER_0_0 {
     pub _bitfield: u16,
 }
+impl MLDV2_QUERY_HEADER_0_0 {
+    pub const fn MaxRespCodeMantissaHi(&self) -> u16 {
+        self._bitfield & 15
+    }
+    pub fn set_MaxRespCodeMantissaHi(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn MaxRespCodeExponent(&self) -> u16 {
+        (self._bitfield >> 4) & 7
+    }
+    pub fn set_MaxRespCodeExponent(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(7 << 4)) | ((value & 7) << 4);
+    }
+    pub const fn MaxRespCodeType(&self) -> u16 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_MaxRespCodeType(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn MaxRespCodeMantissaLo(&self) -> u16 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_MaxRespCodeMantissaLo(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+}
 impl windows_core::TypeKind for MLDV2_QUERY_HEADER_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5986,10 +6447,31 @@ impl Default for MLDV2_QUERY_HEADER_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MLDV2_QUERY_HEADER_1_0 {
     pub _bitfield: u8,
 }
+impl MLDV2_QUERY_HEADER_1_0 {
+    pub const fn QQCMantissa(&self) -> u8 {
+        self._bitfield & 15
+    }
+    pub fn set_QQCMantissa(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn QQCExponent(&self) -> u8 {
+        (self._bitfield >> 4) & 7
+    }
+    pub fn set_QQCExponent(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(7 << 4)) | ((value & 7) << 4);
+    }
+    pub const fn QQCType(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_QQCType(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for MLDV2_QUERY_HEADER_1_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5999,7 +6481,8 @@ impl Default for MLDV2_QUERY_HEADER_1_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MLDV2_REPORT_HEADER {
     pub IcmpHeader: ICMP_HEADER,
     pub Reserved: u16,
@@ -6046,7 +6529,8 @@ impl Default for MLD_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NAPI_DOMAIN_DESCRIPTION_BLOB {
     pub AuthLevel: u32,
     pub cchDomainName: u32,
@@ -6062,7 +6546,8 @@ impl Default for NAPI_DOMAIN_DESCRIPTION_BLOB {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NAPI_PROVIDER_INSTALLATION_BLOB {
     pub dwVersion: u32,
     pub dwProviderType: u32,
@@ -6107,7 +6592,8 @@ impl Default for ND_NEIGHBOR_SOLICIT_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ND_OPTION_DNSSL {
     pub nd_opt_dnssl_type: u8,
     pub nd_opt_dnssl_len: u8,
@@ -6123,7 +6609,8 @@ impl Default for ND_OPTION_DNSSL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ND_OPTION_HDR {
     pub nd_opt_type: u8,
     pub nd_opt_len: u8,
@@ -6137,7 +6624,8 @@ impl Default for ND_OPTION_HDR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ND_OPTION_MTU {
     pub nd_opt_mtu_type: u8,
     pub nd_opt_mtu_len: u8,
@@ -6187,10 +6675,49 @@ impl Default for ND_OPTION_PREFIX_INFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ND_OPTION_PREFIX_INFO_0_0 {
     pub _bitfield: u8,
 }
+impl ND_OPTION_PREFIX_INFO_0_0 {
+    pub const fn Route(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_Route(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved1(&self) -> u8 {
+        (self._bitfield >> 1) & 7
+    }
+    pub fn set_Reserved1(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(7 << 1)) | ((value & 7) << 1);
+    }
+    pub const fn SitePrefix(&self) -> u8 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_SitePrefix(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn RouterAddress(&self) -> u8 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_RouterAddress(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Autonomous(&self) -> u8 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_Autonomous(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn OnLink(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_OnLink(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for ND_OPTION_PREFIX_INFO_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -6214,7 +6741,8 @@ impl Default for ND_OPTION_PREFIX_INFO_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ND_OPTION_PREFIX_INFO_1_0 {
     pub nd_opt_pi_reserved3: [u8; 3],
     pub nd_opt_pi_site_prefix_len: u8,
@@ -6228,7 +6756,8 @@ impl Default for ND_OPTION_PREFIX_INFO_1_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ND_OPTION_RDNSS {
     pub nd_opt_rdnss_type: u8,
     pub nd_opt_rdnss_len: u8,
@@ -6244,7 +6773,8 @@ impl Default for ND_OPTION_RDNSS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ND_OPTION_RD_HDR {
     pub nd_opt_rh_type: u8,
     pub nd_opt_rh_len: u8,
@@ -6292,10 +6822,25 @@ impl Default for ND_OPTION_ROUTE_INFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ND_OPTION_ROUTE_INFO_0_0 {
     pub _bitfield: u8,
 }
+impl ND_OPTION_ROUTE_INFO_0_0 {
+    pub const fn Reserved(&self) -> u8 {
+        self._bitfield & 7
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn Preference(&self) -> u8 {
+        (self._bitfield >> 3) & 3
+    }
+    pub fn set_Preference(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(3 << 3)) | ((value & 3) << 3);
+    }
+}
 impl windows_core::TypeKind for ND_OPTION_ROUTE_INFO_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -6348,7 +6893,8 @@ impl Default for ND_ROUTER_SOLICIT_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NETRESOURCE2A {
     pub dwScope: u32,
     pub dwType: u32,
@@ -6371,7 +6917,8 @@ impl Default for NETRESOURCE2A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NETRESOURCE2W {
     pub dwScope: u32,
     pub dwType: u32,
@@ -6425,7 +6972,8 @@ impl Default for NLA_BLOB_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NLA_BLOB_0_0 {
     pub remote: NLA_BLOB_0_0_0,
 }
@@ -6438,7 +6986,8 @@ impl Default for NLA_BLOB_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NLA_BLOB_0_0_0 {
     pub speed: u32,
     pub r#type: u32,
@@ -6455,7 +7004,8 @@ impl Default for NLA_BLOB_0_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NLA_BLOB_0_1 {
     pub r#type: NLA_CONNECTIVITY_TYPE,
     pub internet: NLA_INTERNET,
@@ -6469,7 +7019,8 @@ impl Default for NLA_BLOB_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NLA_BLOB_0_2 {
     pub dwType: u32,
     pub dwSpeed: u32,
@@ -6484,7 +7035,8 @@ impl Default for NLA_BLOB_0_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NLA_BLOB_0_3 {
     pub information: [i8; 1],
 }
@@ -6497,7 +7049,8 @@ impl Default for NLA_BLOB_0_3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NLA_BLOB_1 {
     pub r#type: NLA_BLOB_DATA_TYPE,
     pub dwSize: u32,
@@ -6512,7 +7065,8 @@ impl Default for NLA_BLOB_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NL_BANDWIDTH_INFORMATION {
     pub Bandwidth: u64,
     pub Instability: u64,
@@ -6527,10 +7081,61 @@ impl Default for NL_BANDWIDTH_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NL_INTERFACE_OFFLOAD_ROD {
     pub _bitfield: u8,
 }
+impl NL_INTERFACE_OFFLOAD_ROD {
+    pub const fn NlChecksumSupported(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_NlChecksumSupported(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn NlOptionsSupported(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_NlOptionsSupported(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn TlDatagramChecksumSupported(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_TlDatagramChecksumSupported(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn TlStreamChecksumSupported(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_TlStreamChecksumSupported(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn TlStreamOptionsSupported(&self) -> u8 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_TlStreamOptionsSupported(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn FastPathCompatible(&self) -> u8 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_FastPathCompatible(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn TlLargeSendOffloadSupported(&self) -> u8 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_TlLargeSendOffloadSupported(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn TlGiantSendOffloadSupported(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_TlGiantSendOffloadSupported(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for NL_INTERFACE_OFFLOAD_ROD {
     type TypeKind = windows_core::CopyType;
 }
@@ -6540,7 +7145,8 @@ impl Default for NL_INTERFACE_OFFLOAD_ROD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NL_NETWORK_CONNECTIVITY_HINT {
     pub ConnectivityLevel: NL_NETWORK_CONNECTIVITY_LEVEL_HINT,
     pub ConnectivityCost: NL_NETWORK_CONNECTIVITY_COST_HINT,
@@ -6557,7 +7163,8 @@ impl Default for NL_NETWORK_CONNECTIVITY_HINT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NL_PATH_BANDWIDTH_ROD {
     pub Bandwidth: u64,
     pub Instability: u64,
@@ -6602,7 +7209,8 @@ impl Default for NPI_MODULEID_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NSPV2_ROUTINE {
     pub cbSize: u32,
     pub dwMajorVersion: u32,
@@ -6627,7 +7235,8 @@ impl Default for NSPV2_ROUTINE {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_IO"))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NSP_ROUTINE {
     pub cbSize: u32,
     pub dwMajorVersion: u32,
@@ -6653,7 +7262,8 @@ impl Default for NSP_ROUTINE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NS_INFOA {
     pub dwNameSpace: u32,
     pub dwNameSpaceFlags: u32,
@@ -6668,7 +7278,8 @@ impl Default for NS_INFOA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NS_INFOW {
     pub dwNameSpace: u32,
     pub dwNameSpaceFlags: u32,
@@ -6684,7 +7295,8 @@ impl Default for NS_INFOW {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NS_SERVICE_INFOA {
     pub dwNameSpace: u32,
     pub ServiceInfo: SERVICE_INFOA,
@@ -6701,7 +7313,8 @@ impl Default for NS_SERVICE_INFOA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NS_SERVICE_INFOW {
     pub dwNameSpace: u32,
     pub ServiceInfo: SERVICE_INFOW,
@@ -6717,7 +7330,8 @@ impl Default for NS_SERVICE_INFOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PRIORITY_STATUS {
     pub Sender: SOCKET_PRIORITY_HINT,
     pub Receiver: SOCKET_PRIORITY_HINT,
@@ -6731,7 +7345,8 @@ impl Default for PRIORITY_STATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROTOCOL_INFOA {
     pub dwServiceFlags: u32,
     pub iAddressFamily: i32,
@@ -6751,7 +7366,8 @@ impl Default for PROTOCOL_INFOA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROTOCOL_INFOW {
     pub dwServiceFlags: u32,
     pub iAddressFamily: i32,
@@ -6771,7 +7387,8 @@ impl Default for PROTOCOL_INFOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROTOENT {
     pub p_name: windows_core::PSTR,
     pub p_aliases: *mut *mut i8,
@@ -6786,7 +7403,8 @@ impl Default for PROTOENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Q2931_IE {
     pub IEType: Q2931_IE_TYPE,
     pub IELength: u32,
@@ -6801,7 +7419,8 @@ impl Default for Q2931_IE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct QOS {
     pub SendingFlowspec: FLOWSPEC,
     pub ReceivingFlowspec: FLOWSPEC,
@@ -6816,7 +7435,8 @@ impl Default for QOS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RCVALL_IF {
     pub Mode: RCVALL_VALUE,
     pub Interface: u32,
@@ -6830,7 +7450,8 @@ impl Default for RCVALL_IF {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REAL_TIME_NOTIFICATION_SETTING_INPUT {
     pub TransportSettingId: TRANSPORT_SETTING_ID,
     pub BrokerEventGuid: windows_core::GUID,
@@ -6844,7 +7465,8 @@ impl Default for REAL_TIME_NOTIFICATION_SETTING_INPUT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REAL_TIME_NOTIFICATION_SETTING_INPUT_EX {
     pub TransportSettingId: TRANSPORT_SETTING_ID,
     pub BrokerEventGuid: windows_core::GUID,
@@ -6859,7 +7481,8 @@ impl Default for REAL_TIME_NOTIFICATION_SETTING_INPUT_EX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REAL_TIME_NOTIFICATION_SETTING_OUTPUT {
     pub ChannelStatus: CONTROL_CHANNEL_TRIGGER_STATUS,
 }
@@ -6872,7 +7495,8 @@ impl Default for REAL_TIME_NOTIFICATION_SETTING_OUTPUT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RIORESULT {
     pub Status: i32,
     pub BytesTransferred: u32,
@@ -6888,7 +7512,8 @@ impl Default for RIORESULT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RIO_BUF {
     pub BufferId: RIO_BUFFERID,
     pub Offset: u32,
@@ -6903,7 +7528,8 @@ impl Default for RIO_BUF {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RIO_BUFFERID(pub isize);
 impl Default for RIO_BUFFERID {
     fn default() -> Self {
@@ -6914,7 +7540,8 @@ impl windows_core::TypeKind for RIO_BUFFERID {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RIO_CMSG_BUFFER {
     pub TotalLength: u32,
 }
@@ -6927,7 +7554,8 @@ impl Default for RIO_CMSG_BUFFER {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RIO_CQ(pub isize);
 impl Default for RIO_CQ {
     fn default() -> Self {
@@ -6938,7 +7566,8 @@ impl windows_core::TypeKind for RIO_CQ {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RIO_EXTENSION_FUNCTION_TABLE {
     pub cbSize: u32,
     pub RIOReceive: LPFN_RIORECEIVE,
@@ -6992,7 +7621,8 @@ impl Default for RIO_NOTIFICATION_COMPLETION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RIO_NOTIFICATION_COMPLETION_0_0 {
     pub EventHandle: super::super::Foundation::HANDLE,
     pub NotifyReset: super::super::Foundation::BOOL,
@@ -7006,7 +7636,8 @@ impl Default for RIO_NOTIFICATION_COMPLETION_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RIO_NOTIFICATION_COMPLETION_0_1 {
     pub IocpHandle: super::super::Foundation::HANDLE,
     pub CompletionKey: *mut core::ffi::c_void,
@@ -7021,7 +7652,8 @@ impl Default for RIO_NOTIFICATION_COMPLETION_0_1 {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RIO_RQ(pub isize);
 impl Default for RIO_RQ {
     fn default() -> Self {
@@ -7032,7 +7664,8 @@ impl windows_core::TypeKind for RIO_RQ {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RM_FEC_INFO {
     pub FECBlockSize: u16,
     pub FECProActivePackets: u16,
@@ -7048,7 +7681,8 @@ impl Default for RM_FEC_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RM_RECEIVER_STATS {
     pub NumODataPacketsReceived: u64,
     pub NumRDataPacketsReceived: u64,
@@ -7078,7 +7712,8 @@ impl Default for RM_RECEIVER_STATS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RM_SENDER_STATS {
     pub DataBytesSent: u64,
     pub TotalBytesSent: u64,
@@ -7103,7 +7738,8 @@ impl Default for RM_SENDER_STATS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RM_SEND_WINDOW {
     pub RateKbitsPerSec: u32,
     pub WindowSizeInMSecs: u32,
@@ -7118,7 +7754,8 @@ impl Default for RM_SEND_WINDOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RSS_SCALABILITY_INFO {
     pub RssEnabled: super::super::Foundation::BOOLEAN,
 }
@@ -7158,10 +7795,25 @@ impl Default for SCOPE_ID_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCOPE_ID_0_0 {
     pub _bitfield: u32,
 }
+impl SCOPE_ID_0_0 {
+    pub const fn Zone(&self) -> u32 {
+        self._bitfield & 268435455
+    }
+    pub fn set_Zone(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !268435455) | (value & 268435455);
+    }
+    pub const fn Level(&self) -> u32 {
+        (self._bitfield >> 28) & 15
+    }
+    pub fn set_Level(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 28)) | ((value & 15) << 28);
+    }
+}
 impl windows_core::TypeKind for SCOPE_ID_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -7172,7 +7824,8 @@ impl Default for SCOPE_ID_0_0 {
 }
 #[repr(C)]
 #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec", target_arch = "x86_64"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SERVENT {
     pub s_name: windows_core::PSTR,
     pub s_aliases: *mut *mut i8,
@@ -7191,7 +7844,8 @@ impl Default for SERVENT {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SERVENT {
     pub s_name: windows_core::PSTR,
     pub s_aliases: *mut *mut i8,
@@ -7209,7 +7863,8 @@ impl Default for SERVENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SERVICE_ADDRESS {
     pub dwAddressType: u32,
     pub dwAddressFlags: u32,
@@ -7227,7 +7882,8 @@ impl Default for SERVICE_ADDRESS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SERVICE_ADDRESSES {
     pub dwAddressCount: u32,
     pub Addresses: [SERVICE_ADDRESS; 1],
@@ -7241,7 +7897,8 @@ impl Default for SERVICE_ADDRESSES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SERVICE_ASYNC_INFO {
     pub lpServiceCallbackProc: LPSERVICE_CALLBACK_PROC,
     pub lParam: super::super::Foundation::LPARAM,
@@ -7257,7 +7914,8 @@ impl Default for SERVICE_ASYNC_INFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SERVICE_INFOA {
     pub lpServiceType: *mut windows_core::GUID,
     pub lpServiceName: windows_core::PSTR,
@@ -7282,7 +7940,8 @@ impl Default for SERVICE_INFOA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SERVICE_INFOW {
     pub lpServiceType: *mut windows_core::GUID,
     pub lpServiceName: windows_core::PWSTR,
@@ -7306,7 +7965,8 @@ impl Default for SERVICE_INFOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SERVICE_TYPE_INFO {
     pub dwTypeNameOffset: u32,
     pub dwValueCount: u32,
@@ -7321,7 +7981,8 @@ impl Default for SERVICE_TYPE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SERVICE_TYPE_INFO_ABSA {
     pub lpTypeName: windows_core::PSTR,
     pub dwValueCount: u32,
@@ -7336,7 +7997,8 @@ impl Default for SERVICE_TYPE_INFO_ABSA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SERVICE_TYPE_INFO_ABSW {
     pub lpTypeName: windows_core::PWSTR,
     pub dwValueCount: u32,
@@ -7351,7 +8013,8 @@ impl Default for SERVICE_TYPE_INFO_ABSW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SERVICE_TYPE_VALUE {
     pub dwNameSpace: u32,
     pub dwValueType: u32,
@@ -7368,7 +8031,8 @@ impl Default for SERVICE_TYPE_VALUE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SERVICE_TYPE_VALUE_ABSA {
     pub dwNameSpace: u32,
     pub dwValueType: u32,
@@ -7385,7 +8049,8 @@ impl Default for SERVICE_TYPE_VALUE_ABSA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SERVICE_TYPE_VALUE_ABSW {
     pub dwNameSpace: u32,
     pub dwValueType: u32,
@@ -7402,7 +8067,8 @@ impl Default for SERVICE_TYPE_VALUE_ABSW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SNAP_HEADER {
     pub Dsap: u8,
     pub Ssap: u8,
@@ -7419,7 +8085,8 @@ impl Default for SNAP_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOCKADDR {
     pub sa_family: ADDRESS_FAMILY,
     pub sa_data: [i8; 14],
@@ -7433,7 +8100,8 @@ impl Default for SOCKADDR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOCKADDR_ATM {
     pub satm_family: u16,
     pub satm_number: ATM_ADDRESS,
@@ -7449,7 +8117,8 @@ impl Default for SOCKADDR_ATM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOCKADDR_DL {
     pub sdl_family: ADDRESS_FAMILY,
     pub sdl_data: [u8; 8],
@@ -7511,7 +8180,8 @@ impl Default for SOCKADDR_IN6_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOCKADDR_IN6_PAIR {
     pub SourceAddress: *mut SOCKADDR_IN6,
     pub DestinationAddress: *mut SOCKADDR_IN6,
@@ -7557,7 +8227,8 @@ impl Default for SOCKADDR_INET {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOCKADDR_IPX {
     pub sa_family: i16,
     pub sa_netnum: [i8; 4],
@@ -7573,7 +8244,8 @@ impl Default for SOCKADDR_IPX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOCKADDR_IRDA {
     pub irdaAddressFamily: u16,
     pub irdaDeviceID: [u8; 4],
@@ -7588,7 +8260,8 @@ impl Default for SOCKADDR_IRDA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOCKADDR_NB {
     pub snb_family: i16,
     pub snb_type: u16,
@@ -7603,7 +8276,8 @@ impl Default for SOCKADDR_NB {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOCKADDR_STORAGE {
     pub ss_family: ADDRESS_FAMILY,
     pub __ss_pad1: [i8; 6],
@@ -7619,7 +8293,8 @@ impl Default for SOCKADDR_STORAGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOCKADDR_STORAGE_XP {
     pub ss_family: i16,
     pub __ss_pad1: [i8; 6],
@@ -7635,7 +8310,8 @@ impl Default for SOCKADDR_STORAGE_XP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOCKADDR_TP {
     pub tp_family: u16,
     pub tp_addr_type: u16,
@@ -7652,7 +8328,8 @@ impl Default for SOCKADDR_TP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOCKADDR_UN {
     pub sun_family: ADDRESS_FAMILY,
     pub sun_path: [i8; 108],
@@ -7666,7 +8343,8 @@ impl Default for SOCKADDR_UN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOCKADDR_VNS {
     pub sin_family: u16,
     pub net_address: [u8; 4],
@@ -7684,7 +8362,8 @@ impl Default for SOCKADDR_VNS {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOCKET(pub usize);
 impl SOCKET {
     pub fn is_invalid(&self) -> bool {
@@ -7708,7 +8387,8 @@ impl windows_core::TypeKind for SOCKET {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOCKET_ADDRESS {
     pub lpSockaddr: *mut SOCKADDR,
     pub iSockaddrLength: i32,
@@ -7722,7 +8402,8 @@ impl Default for SOCKET_ADDRESS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOCKET_ADDRESS_LIST {
     pub iAddressCount: i32,
     pub Address: [SOCKET_ADDRESS; 1],
@@ -7736,7 +8417,8 @@ impl Default for SOCKET_ADDRESS_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOCKET_PEER_TARGET_NAME {
     pub SecurityProtocol: SOCKET_SECURITY_PROTOCOL,
     pub PeerAddress: SOCKADDR_STORAGE,
@@ -7753,7 +8435,8 @@ impl Default for SOCKET_PEER_TARGET_NAME {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOCKET_PROCESSOR_AFFINITY {
     pub Processor: super::super::System::Kernel::PROCESSOR_NUMBER,
     pub NumaNodeId: u16,
@@ -7770,7 +8453,8 @@ impl Default for SOCKET_PROCESSOR_AFFINITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOCKET_SECURITY_QUERY_INFO {
     pub SecurityProtocol: SOCKET_SECURITY_PROTOCOL,
     pub Flags: u32,
@@ -7786,7 +8470,8 @@ impl Default for SOCKET_SECURITY_QUERY_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOCKET_SECURITY_QUERY_INFO_IPSEC2 {
     pub SecurityProtocol: SOCKET_SECURITY_PROTOCOL,
     pub Flags: u32,
@@ -7806,7 +8491,8 @@ impl Default for SOCKET_SECURITY_QUERY_INFO_IPSEC2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOCKET_SECURITY_QUERY_TEMPLATE {
     pub SecurityProtocol: SOCKET_SECURITY_PROTOCOL,
     pub PeerAddress: SOCKADDR_STORAGE,
@@ -7821,7 +8507,8 @@ impl Default for SOCKET_SECURITY_QUERY_TEMPLATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOCKET_SECURITY_QUERY_TEMPLATE_IPSEC2 {
     pub SecurityProtocol: SOCKET_SECURITY_PROTOCOL,
     pub PeerAddress: SOCKADDR_STORAGE,
@@ -7838,7 +8525,8 @@ impl Default for SOCKET_SECURITY_QUERY_TEMPLATE_IPSEC2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOCKET_SECURITY_SETTINGS {
     pub SecurityProtocol: SOCKET_SECURITY_PROTOCOL,
     pub SecurityFlags: u32,
@@ -7852,7 +8540,8 @@ impl Default for SOCKET_SECURITY_SETTINGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOCKET_SECURITY_SETTINGS_IPSEC {
     pub SecurityProtocol: SOCKET_SECURITY_PROTOCOL,
     pub SecurityFlags: u32,
@@ -7875,7 +8564,8 @@ impl Default for SOCKET_SECURITY_SETTINGS_IPSEC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOCK_NOTIFY_REGISTRATION {
     pub socket: SOCKET,
     pub completionKey: *mut core::ffi::c_void,
@@ -7893,7 +8583,8 @@ impl Default for SOCK_NOTIFY_REGISTRATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TCP_ACK_FREQUENCY_PARAMETERS {
     pub TcpDelayedAckFrequency: u8,
 }
@@ -7918,6 +8609,20 @@ pub struct TCP_HDR {
     pub th_sum: u16,
     pub th_urp: u16,
 }
+impl TCP_HDR {
+    pub const fn th_x2(&self) -> u8 {
+        self._bitfield & 15
+    }
+    pub fn set_th_x2(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn th_len(&self) -> u8 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_th_len(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+}
 impl windows_core::TypeKind for TCP_HDR {
     type TypeKind = windows_core::CopyType;
 }
@@ -7927,7 +8632,8 @@ impl Default for TCP_HDR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TCP_ICW_PARAMETERS {
     pub Level: TCP_ICW_LEVEL,
 }
@@ -7940,7 +8646,8 @@ impl Default for TCP_ICW_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TCP_INFO_v0 {
     pub State: TCPSTATE,
     pub Mss: u32,
@@ -7971,7 +8678,8 @@ impl Default for TCP_INFO_v0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TCP_INFO_v1 {
     pub State: TCPSTATE,
     pub Mss: u32,
@@ -8011,7 +8719,8 @@ impl Default for TCP_INFO_v1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TCP_INITIAL_RTO_PARAMETERS {
     pub Rtt: u16,
     pub MaxSynRetransmissions: u8,
@@ -8143,7 +8852,8 @@ impl Default for TCP_OPT_WS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TIMESTAMPING_CONFIG {
     pub Flags: u32,
     pub TxTimestampsBuffered: u16,
@@ -8157,7 +8867,8 @@ impl Default for TIMESTAMPING_CONFIG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TIMEVAL {
     pub tv_sec: i32,
     pub tv_usec: i32,
@@ -8171,7 +8882,8 @@ impl Default for TIMEVAL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TRANSMIT_FILE_BUFFERS {
     pub Head: *mut core::ffi::c_void,
     pub HeadLength: u32,
@@ -8216,7 +8928,8 @@ impl Default for TRANSMIT_PACKETS_ELEMENT_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TRANSMIT_PACKETS_ELEMENT_0_0 {
     pub nFileOffset: i64,
     pub hFile: super::super::Foundation::HANDLE,
@@ -8230,7 +8943,8 @@ impl Default for TRANSMIT_PACKETS_ELEMENT_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TRANSPORT_SETTING_ID {
     pub Guid: windows_core::GUID,
 }
@@ -8271,10 +8985,31 @@ impl Default for VLAN_TAG_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VLAN_TAG_0_0 {
     pub _bitfield: u16,
 }
+impl VLAN_TAG_0_0 {
+    pub const fn VID(&self) -> u16 {
+        self._bitfield & 4095
+    }
+    pub fn set_VID(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !4095) | (value & 4095);
+    }
+    pub const fn CFI(&self) -> u16 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_CFI(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn User_Priority(&self) -> u16 {
+        (self._bitfield >> 13) & 7
+    }
+    pub fn set_User_Priority(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(7 << 13)) | ((value & 7) << 13);
+    }
+}
 impl windows_core::TypeKind for VLAN_TAG_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -8284,7 +9019,8 @@ impl Default for VLAN_TAG_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WCE_DEVICELIST {
     pub numDevice: u32,
     pub Device: [WCE_IRDA_DEVICE_INFO; 1],
@@ -8298,7 +9034,8 @@ impl Default for WCE_DEVICELIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WCE_IRDA_DEVICE_INFO {
     pub irdaDeviceID: [u8; 4],
     pub irdaDeviceName: [i8; 22],
@@ -8313,7 +9050,8 @@ impl Default for WCE_IRDA_DEVICE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINDOWS_DEVICELIST {
     pub numDevice: u32,
     pub Device: [WINDOWS_IRDA_DEVICE_INFO; 1],
@@ -8359,7 +9097,8 @@ impl Default for WINDOWS_IAS_QUERY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINDOWS_IAS_QUERY_0_0 {
     pub Len: u32,
     pub OctetSeq: [u8; 1024],
@@ -8373,7 +9112,8 @@ impl Default for WINDOWS_IAS_QUERY_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINDOWS_IAS_QUERY_0_1 {
     pub Len: u32,
     pub CharSet: u32,
@@ -8419,7 +9159,8 @@ impl Default for WINDOWS_IAS_SET_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINDOWS_IAS_SET_0_0 {
     pub Len: u16,
     pub OctetSeq: [u8; 1024],
@@ -8433,7 +9174,8 @@ impl Default for WINDOWS_IAS_SET_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINDOWS_IAS_SET_0_1 {
     pub Len: u8,
     pub CharSet: u8,
@@ -8448,7 +9190,8 @@ impl Default for WINDOWS_IAS_SET_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINDOWS_IRDA_DEVICE_INFO {
     pub irdaDeviceID: [u8; 4],
     pub irdaDeviceName: [i8; 22],
@@ -8465,7 +9208,8 @@ impl Default for WINDOWS_IRDA_DEVICE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSABUF {
     pub len: u32,
     pub buf: windows_core::PSTR,
@@ -8516,7 +9260,8 @@ impl Default for WSACOMPLETION_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_IO")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSACOMPLETION_0_0 {
     pub lpOverlapped: *mut super::super::System::IO::OVERLAPPED,
     pub lpfnCompletionProc: LPWSAOVERLAPPED_COMPLETION_ROUTINE,
@@ -8533,7 +9278,8 @@ impl Default for WSACOMPLETION_0_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_IO")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSACOMPLETION_0_1 {
     pub lpOverlapped: *mut super::super::System::IO::OVERLAPPED,
 }
@@ -8549,7 +9295,8 @@ impl Default for WSACOMPLETION_0_1 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_IO")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSACOMPLETION_0_2 {
     pub lpOverlapped: *mut super::super::System::IO::OVERLAPPED,
     pub hPort: super::super::Foundation::HANDLE,
@@ -8567,7 +9314,8 @@ impl Default for WSACOMPLETION_0_2 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_IO")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSACOMPLETION_0_3 {
     pub hWnd: super::super::Foundation::HWND,
     pub uMsg: u32,
@@ -8585,7 +9333,8 @@ impl Default for WSACOMPLETION_0_3 {
 }
 #[repr(C)]
 #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec", target_arch = "x86_64"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSADATA {
     pub wVersion: u16,
     pub wHighVersion: u16,
@@ -8607,7 +9356,8 @@ impl Default for WSADATA {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSADATA {
     pub wVersion: u16,
     pub wHighVersion: u16,
@@ -8628,7 +9378,8 @@ impl Default for WSADATA {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSAEVENT(pub isize);
 impl WSAEVENT {
     pub fn is_invalid(&self) -> bool {
@@ -8644,7 +9395,8 @@ impl windows_core::TypeKind for WSAEVENT {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSAMSG {
     pub name: *mut SOCKADDR,
     pub namelen: i32,
@@ -8662,7 +9414,8 @@ impl Default for WSAMSG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSANAMESPACE_INFOA {
     pub NSProviderId: windows_core::GUID,
     pub dwNameSpace: u32,
@@ -8680,7 +9433,8 @@ impl Default for WSANAMESPACE_INFOA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSANAMESPACE_INFOEXA {
     pub NSProviderId: windows_core::GUID,
     pub dwNameSpace: u32,
@@ -8701,7 +9455,8 @@ impl Default for WSANAMESPACE_INFOEXA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSANAMESPACE_INFOEXW {
     pub NSProviderId: windows_core::GUID,
     pub dwNameSpace: u32,
@@ -8721,7 +9476,8 @@ impl Default for WSANAMESPACE_INFOEXW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSANAMESPACE_INFOW {
     pub NSProviderId: windows_core::GUID,
     pub dwNameSpace: u32,
@@ -8738,7 +9494,8 @@ impl Default for WSANAMESPACE_INFOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSANETWORKEVENTS {
     pub lNetworkEvents: i32,
     pub iErrorCode: [i32; 10],
@@ -8752,7 +9509,8 @@ impl Default for WSANETWORKEVENTS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSANSCLASSINFOA {
     pub lpszName: windows_core::PSTR,
     pub dwNameSpace: u32,
@@ -8769,7 +9527,8 @@ impl Default for WSANSCLASSINFOA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSANSCLASSINFOW {
     pub lpszName: windows_core::PWSTR,
     pub dwNameSpace: u32,
@@ -8786,7 +9545,8 @@ impl Default for WSANSCLASSINFOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSAPOLLDATA {
     pub result: i32,
     pub fds: u32,
@@ -8802,7 +9562,8 @@ impl Default for WSAPOLLDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSAPOLLFD {
     pub fd: SOCKET,
     pub events: WSAPOLL_EVENT_FLAGS,
@@ -8817,7 +9578,8 @@ impl Default for WSAPOLLFD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSAPROTOCOLCHAIN {
     pub ChainLen: i32,
     pub ChainEntries: [u32; 7],
@@ -8831,7 +9593,8 @@ impl Default for WSAPROTOCOLCHAIN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSAPROTOCOL_INFOA {
     pub dwServiceFlags1: u32,
     pub dwServiceFlags2: u32,
@@ -8863,7 +9626,8 @@ impl Default for WSAPROTOCOL_INFOA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSAPROTOCOL_INFOW {
     pub dwServiceFlags1: u32,
     pub dwServiceFlags2: u32,
@@ -8896,7 +9660,8 @@ impl Default for WSAPROTOCOL_INFOW {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSAQUERYSET2A {
     pub dwSize: u32,
     pub lpszServiceInstanceName: windows_core::PSTR,
@@ -8925,7 +9690,8 @@ impl Default for WSAQUERYSET2A {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSAQUERYSET2W {
     pub dwSize: u32,
     pub lpszServiceInstanceName: windows_core::PWSTR,
@@ -8954,7 +9720,8 @@ impl Default for WSAQUERYSET2W {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSAQUERYSETA {
     pub dwSize: u32,
     pub lpszServiceInstanceName: windows_core::PSTR,
@@ -8984,7 +9751,8 @@ impl Default for WSAQUERYSETA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSAQUERYSETW {
     pub dwSize: u32,
     pub lpszServiceInstanceName: windows_core::PWSTR,
@@ -9014,7 +9782,8 @@ impl Default for WSAQUERYSETW {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_IO")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSASENDMSG {
     pub lpMsg: *mut WSAMSG,
     pub dwFlags: u32,
@@ -9033,7 +9802,8 @@ impl Default for WSASENDMSG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSASERVICECLASSINFOA {
     pub lpServiceClassId: *mut windows_core::GUID,
     pub lpszServiceClassName: windows_core::PSTR,
@@ -9049,7 +9819,8 @@ impl Default for WSASERVICECLASSINFOA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSASERVICECLASSINFOW {
     pub lpServiceClassId: *mut windows_core::GUID,
     pub lpszServiceClassName: windows_core::PWSTR,
@@ -9065,7 +9836,8 @@ impl Default for WSASERVICECLASSINFOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSATHREADID {
     pub ThreadHandle: super::super::Foundation::HANDLE,
     pub Reserved: usize,
@@ -9079,7 +9851,8 @@ impl Default for WSATHREADID {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSAVERSION {
     pub dwVersion: u32,
     pub ecHow: WSAECOMPARATOR,
@@ -9093,7 +9866,8 @@ impl Default for WSAVERSION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSA_COMPATIBILITY_MODE {
     pub BehaviorId: WSA_COMPATIBILITY_BEHAVIOR_ID,
     pub TargetOsVersion: u32,
@@ -9107,7 +9881,8 @@ impl Default for WSA_COMPATIBILITY_MODE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSC_PROVIDER_AUDIT_INFO {
     pub RecordSize: u32,
     pub Reserved: *mut core::ffi::c_void,
@@ -9121,7 +9896,8 @@ impl Default for WSC_PROVIDER_AUDIT_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSPDATA {
     pub wVersion: u16,
     pub wHighVersion: u16,
@@ -9137,7 +9913,8 @@ impl Default for WSPDATA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_IO")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSPPROC_TABLE {
     pub lpWSPAccept: LPWSPACCEPT,
     pub lpWSPAddressToString: LPWSPADDRESSTOSTRING,
@@ -9181,7 +9958,8 @@ impl Default for WSPPROC_TABLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSPUPCALLTABLE {
     pub lpWPUCloseEvent: LPWPUCLOSEEVENT,
     pub lpWPUCloseSocketHandle: LPWPUCLOSESOCKETHANDLE,
@@ -9208,7 +9986,8 @@ impl Default for WSPUPCALLTABLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct netent {
     pub n_name: windows_core::PSTR,
     pub n_aliases: *mut *mut i8,
@@ -9255,7 +10034,8 @@ impl Default for sockaddr_in6_old {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct socklen_t(pub i32);
 impl Default for socklen_t {
     fn default() -> Self {
@@ -9266,7 +10046,8 @@ impl windows_core::TypeKind for socklen_t {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct sockproto {
     pub sp_family: u16,
     pub sp_protocol: u16,
@@ -9280,7 +10061,8 @@ impl Default for sockproto {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct tcp_keepalive {
     pub onoff: u32,
     pub keepalivetime: u32,
@@ -0,0 +1,15 @@
+impl windows_core::imp::ProxyVtable for IContentPrefetcherTaskTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TriggerContentPrefetcherTask(this: *mut core::ffi::c_void, packagefullname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentPrefetcherTaskTrigger", "TriggerContentPrefetcherTask", 6usize, |this| ((**(this as *const *const IContentPrefetcherTaskTrigger_Vtbl)).TriggerContentPrefetcherTask)(this, packagefullname), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRegisteredForContentPrefetch(this: *mut core::ffi::c_void, packagefullname: windows_core::PCWSTR, isregistered: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContentPrefetcherTaskTrigger", "IsRegisteredForContentPrefetch", 7usize, |this| ((**(this as *const *const IContentPrefetcherTaskTrigger_Vtbl)).IsRegisteredForContentPrefetch)(this, packagefullname, isregistered), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TriggerContentPrefetcherTask, IsRegisteredForContentPrefetch }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContentPrefetcherTaskTrigger as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -749,6 +749,7 @@ pub struct ACCOUNT_STATE(pub i32);
 impl windows_core::TypeKind for ACCOUNT_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ACCOUNT_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ACCOUNT_STATE").field(&self.0).finish()
@@ -760,6 +761,7 @@ pub struct IDENTITY_TYPE(pub i32);
 impl windows_core::TypeKind for IDENTITY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IDENTITY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IDENTITY_TYPE").field(&self.0).finish()
@@ -771,6 +773,7 @@ pub struct IDENTITY_URL(pub i32);
 impl windows_core::TypeKind for IDENTITY_URL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IDENTITY_URL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IDENTITY_URL").field(&self.0).finish()
@@ -782,6 +785,7 @@ pub struct IdentityUpdateEvent(pub i32);
 impl windows_core::TypeKind for IdentityUpdateEvent {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IdentityUpdateEvent {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IdentityUpdateEvent").field(&self.0).finish()
@@ -824,3 +828,5 @@ pub const CIdentityProfileHandler: windows_core::GUID = windows_core::GUID::from
 pub const CoClassIdentityStore: windows_core::GUID = windows_core::GUID::from_u128(0x30d49246_d217_465f_b00b_ac9ddd652eb7);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
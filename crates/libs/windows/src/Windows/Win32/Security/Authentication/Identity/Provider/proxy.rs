@@ -0,0 +1,561 @@
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for AsyncIAssociatedIdentityProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Begin_AssociateIdentity(this: *mut core::ffi::c_void, hwndparent: super::super::super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIAssociatedIdentityProvider", "Begin_AssociateIdentity", 3usize, |this| ((**(this as *const *const AsyncIAssociatedIdentityProvider_Vtbl)).Begin_AssociateIdentity)(this, hwndparent), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn Finish_AssociateIdentity(this: *mut core::ffi::c_void, pppropertystore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIAssociatedIdentityProvider", "Finish_AssociateIdentity", 4usize, |this| ((**(this as *const *const AsyncIAssociatedIdentityProvider_Vtbl)).Finish_AssociateIdentity)(this, pppropertystore), |result| Some(*result))
+        }
+        unsafe extern "system" fn Begin_DisassociateIdentity(this: *mut core::ffi::c_void, hwndparent: super::super::super::super::Foundation::HWND, lpszuniqueid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIAssociatedIdentityProvider", "Begin_DisassociateIdentity", 5usize, |this| ((**(this as *const *const AsyncIAssociatedIdentityProvider_Vtbl)).Begin_DisassociateIdentity)(this, hwndparent, lpszuniqueid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_DisassociateIdentity(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIAssociatedIdentityProvider", "Finish_DisassociateIdentity", 6usize, |this| ((**(this as *const *const AsyncIAssociatedIdentityProvider_Vtbl)).Finish_DisassociateIdentity)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Begin_ChangeCredential(this: *mut core::ffi::c_void, hwndparent: super::super::super::super::Foundation::HWND, lpszuniqueid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIAssociatedIdentityProvider", "Begin_ChangeCredential", 7usize, |this| ((**(this as *const *const AsyncIAssociatedIdentityProvider_Vtbl)).Begin_ChangeCredential)(this, hwndparent, lpszuniqueid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_ChangeCredential(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIAssociatedIdentityProvider", "Finish_ChangeCredential", 8usize, |this| ((**(this as *const *const AsyncIAssociatedIdentityProvider_Vtbl)).Finish_ChangeCredential)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Begin_AssociateIdentity,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            Finish_AssociateIdentity,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            Finish_AssociateIdentity: 0,
+            Begin_DisassociateIdentity,
+            Finish_DisassociateIdentity,
+            Begin_ChangeCredential,
+            Finish_ChangeCredential,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<AsyncIAssociatedIdentityProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for AsyncIConnectedIdentityProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Begin_ConnectIdentity(this: *mut core::ffi::c_void, authbuffer: *const u8, authbuffersize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIConnectedIdentityProvider", "Begin_ConnectIdentity", 3usize, |this| ((**(this as *const *const AsyncIConnectedIdentityProvider_Vtbl)).Begin_ConnectIdentity)(this, authbuffer, authbuffersize), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_ConnectIdentity(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIConnectedIdentityProvider", "Finish_ConnectIdentity", 4usize, |this| ((**(this as *const *const AsyncIConnectedIdentityProvider_Vtbl)).Finish_ConnectIdentity)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Begin_DisconnectIdentity(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIConnectedIdentityProvider", "Begin_DisconnectIdentity", 5usize, |this| ((**(this as *const *const AsyncIConnectedIdentityProvider_Vtbl)).Begin_DisconnectIdentity)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_DisconnectIdentity(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIConnectedIdentityProvider", "Finish_DisconnectIdentity", 6usize, |this| ((**(this as *const *const AsyncIConnectedIdentityProvider_Vtbl)).Finish_DisconnectIdentity)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Begin_IsConnected(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIConnectedIdentityProvider", "Begin_IsConnected", 7usize, |this| ((**(this as *const *const AsyncIConnectedIdentityProvider_Vtbl)).Begin_IsConnected)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_IsConnected(this: *mut core::ffi::c_void, connected: *mut super::super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIConnectedIdentityProvider", "Finish_IsConnected", 8usize, |this| ((**(this as *const *const AsyncIConnectedIdentityProvider_Vtbl)).Finish_IsConnected)(this, connected), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Begin_GetUrl(this: *mut core::ffi::c_void, identifier: IDENTITY_URL, context: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIConnectedIdentityProvider", "Begin_GetUrl", 9usize, |this| ((**(this as *const *const AsyncIConnectedIdentityProvider_Vtbl)).Begin_GetUrl)(this, identifier, context), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_GetUrl(this: *mut core::ffi::c_void, postdata: *mut core::mem::MaybeUninit<windows_core::VARIANT>, url: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIConnectedIdentityProvider", "Finish_GetUrl", 10usize, |this| ((**(this as *const *const AsyncIConnectedIdentityProvider_Vtbl)).Finish_GetUrl)(this, postdata, url), |result| Some(*result))
+        }
+        unsafe extern "system" fn Begin_GetAccountState(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIConnectedIdentityProvider", "Begin_GetAccountState", 11usize, |this| ((**(this as *const *const AsyncIConnectedIdentityProvider_Vtbl)).Begin_GetAccountState)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_GetAccountState(this: *mut core::ffi::c_void, pstate: *mut ACCOUNT_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIConnectedIdentityProvider", "Finish_GetAccountState", 12usize, |this| ((**(this as *const *const AsyncIConnectedIdentityProvider_Vtbl)).Finish_GetAccountState)(this, pstate), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Begin_ConnectIdentity,
+            Finish_ConnectIdentity,
+            Begin_DisconnectIdentity,
+            Finish_DisconnectIdentity,
+            Begin_IsConnected,
+            Finish_IsConnected,
+            #[cfg(feature = "Win32_System_Com")]
+            Begin_GetUrl,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Begin_GetUrl: 0,
+            Finish_GetUrl,
+            Begin_GetAccountState,
+            Finish_GetAccountState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<AsyncIConnectedIdentityProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for AsyncIIdentityAdvise_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Begin_IdentityUpdated(this: *mut core::ffi::c_void, dwidentityupdateevents: u32, lpszuniqueid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityAdvise", "Begin_IdentityUpdated", 3usize, |this| ((**(this as *const *const AsyncIIdentityAdvise_Vtbl)).Begin_IdentityUpdated)(this, dwidentityupdateevents, lpszuniqueid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_IdentityUpdated(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityAdvise", "Finish_IdentityUpdated", 4usize, |this| ((**(this as *const *const AsyncIIdentityAdvise_Vtbl)).Finish_IdentityUpdated)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Begin_IdentityUpdated, Finish_IdentityUpdated }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<AsyncIIdentityAdvise as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for AsyncIIdentityAuthentication_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Begin_SetIdentityCredential(this: *mut core::ffi::c_void, credbuffer: *const u8, credbufferlength: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityAuthentication", "Begin_SetIdentityCredential", 3usize, |this| ((**(this as *const *const AsyncIIdentityAuthentication_Vtbl)).Begin_SetIdentityCredential)(this, credbuffer, credbufferlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_SetIdentityCredential(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityAuthentication", "Finish_SetIdentityCredential", 4usize, |this| ((**(this as *const *const AsyncIIdentityAuthentication_Vtbl)).Finish_SetIdentityCredential)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn Begin_ValidateIdentityCredential(this: *mut core::ffi::c_void, credbuffer: *const u8, credbufferlength: u32, ppidentityproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityAuthentication", "Begin_ValidateIdentityCredential", 5usize, |this| ((**(this as *const *const AsyncIIdentityAuthentication_Vtbl)).Begin_ValidateIdentityCredential)(this, credbuffer, credbufferlength, ppidentityproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn Finish_ValidateIdentityCredential(this: *mut core::ffi::c_void, ppidentityproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityAuthentication", "Finish_ValidateIdentityCredential", 6usize, |this| ((**(this as *const *const AsyncIIdentityAuthentication_Vtbl)).Finish_ValidateIdentityCredential)(this, ppidentityproperties), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Begin_SetIdentityCredential,
+            Finish_SetIdentityCredential,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            Begin_ValidateIdentityCredential,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            Begin_ValidateIdentityCredential: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            Finish_ValidateIdentityCredential,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            Finish_ValidateIdentityCredential: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<AsyncIIdentityAuthentication as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+impl windows_core::imp::ProxyVtable for AsyncIIdentityProvider_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn Begin_GetIdentityEnum(this: *mut core::ffi::c_void, eidentitytype: IDENTITY_TYPE, pfilterkey: *const super::super::super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pfilterpropvarvalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityProvider", "Begin_GetIdentityEnum", 3usize, |this| ((**(this as *const *const AsyncIIdentityProvider_Vtbl)).Begin_GetIdentityEnum)(this, eidentitytype, pfilterkey, pfilterpropvarvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Finish_GetIdentityEnum(this: *mut core::ffi::c_void, ppidentityenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityProvider", "Finish_GetIdentityEnum", 4usize, |this| ((**(this as *const *const AsyncIIdentityProvider_Vtbl)).Finish_GetIdentityEnum)(this, ppidentityenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Begin_Create(this: *mut core::ffi::c_void, lpszusername: windows_core::PCWSTR, pkeywordstoadd: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityProvider", "Begin_Create", 5usize, |this| ((**(this as *const *const AsyncIIdentityProvider_Vtbl)).Begin_Create)(this, lpszusername, pkeywordstoadd), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn Finish_Create(this: *mut core::ffi::c_void, pppropertystore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityProvider", "Finish_Create", 6usize, |this| ((**(this as *const *const AsyncIIdentityProvider_Vtbl)).Finish_Create)(this, pppropertystore), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn Begin_Import(this: *mut core::ffi::c_void, ppropertystore: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityProvider", "Begin_Import", 7usize, |this| ((**(this as *const *const AsyncIIdentityProvider_Vtbl)).Begin_Import)(this, ppropertystore), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_Import(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityProvider", "Finish_Import", 8usize, |this| ((**(this as *const *const AsyncIIdentityProvider_Vtbl)).Finish_Import)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Begin_Delete(this: *mut core::ffi::c_void, lpszuniqueid: windows_core::PCWSTR, pkeywordstodelete: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityProvider", "Begin_Delete", 9usize, |this| ((**(this as *const *const AsyncIIdentityProvider_Vtbl)).Begin_Delete)(this, lpszuniqueid, pkeywordstodelete), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityProvider", "Finish_Delete", 10usize, |this| ((**(this as *const *const AsyncIIdentityProvider_Vtbl)).Finish_Delete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Begin_FindByUniqueID(this: *mut core::ffi::c_void, lpszuniqueid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityProvider", "Begin_FindByUniqueID", 11usize, |this| ((**(this as *const *const AsyncIIdentityProvider_Vtbl)).Begin_FindByUniqueID)(this, lpszuniqueid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn Finish_FindByUniqueID(this: *mut core::ffi::c_void, pppropertystore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityProvider", "Finish_FindByUniqueID", 12usize, |this| ((**(this as *const *const AsyncIIdentityProvider_Vtbl)).Finish_FindByUniqueID)(this, pppropertystore), |result| Some(*result))
+        }
+        unsafe extern "system" fn Begin_GetProviderPropertyStore(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityProvider", "Begin_GetProviderPropertyStore", 13usize, |this| ((**(this as *const *const AsyncIIdentityProvider_Vtbl)).Begin_GetProviderPropertyStore)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn Finish_GetProviderPropertyStore(this: *mut core::ffi::c_void, pppropertystore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityProvider", "Finish_GetProviderPropertyStore", 14usize, |this| ((**(this as *const *const AsyncIIdentityProvider_Vtbl)).Finish_GetProviderPropertyStore)(this, pppropertystore), |result| Some(*result))
+        }
+        unsafe extern "system" fn Begin_Advise(this: *mut core::ffi::c_void, pidentityadvise: *mut core::ffi::c_void, dwidentityupdateevents: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityProvider", "Begin_Advise", 15usize, |this| ((**(this as *const *const AsyncIIdentityProvider_Vtbl)).Begin_Advise)(this, pidentityadvise, dwidentityupdateevents), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_Advise(this: *mut core::ffi::c_void, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityProvider", "Finish_Advise", 16usize, |this| ((**(this as *const *const AsyncIIdentityProvider_Vtbl)).Finish_Advise)(this, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn Begin_UnAdvise(this: *mut core::ffi::c_void, dwcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityProvider", "Begin_UnAdvise", 17usize, |this| ((**(this as *const *const AsyncIIdentityProvider_Vtbl)).Begin_UnAdvise)(this, dwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_UnAdvise(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityProvider", "Finish_UnAdvise", 18usize, |this| ((**(this as *const *const AsyncIIdentityProvider_Vtbl)).Finish_UnAdvise)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            Begin_GetIdentityEnum,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            Begin_GetIdentityEnum: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Finish_GetIdentityEnum,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Finish_GetIdentityEnum: 0,
+            Begin_Create,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            Finish_Create,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            Finish_Create: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            Begin_Import,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            Begin_Import: 0,
+            Finish_Import,
+            Begin_Delete,
+            Finish_Delete,
+            Begin_FindByUniqueID,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            Finish_FindByUniqueID,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            Finish_FindByUniqueID: 0,
+            Begin_GetProviderPropertyStore,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            Finish_GetProviderPropertyStore,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            Finish_GetProviderPropertyStore: 0,
+            Begin_Advise,
+            Finish_Advise,
+            Begin_UnAdvise,
+            Finish_UnAdvise,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<AsyncIIdentityProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+impl windows_core::imp::ProxyVtable for AsyncIIdentityStore_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Begin_GetCount(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityStore", "Begin_GetCount", 3usize, |this| ((**(this as *const *const AsyncIIdentityStore_Vtbl)).Begin_GetCount)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_GetCount(this: *mut core::ffi::c_void, pdwproviders: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityStore", "Finish_GetCount", 4usize, |this| ((**(this as *const *const AsyncIIdentityStore_Vtbl)).Finish_GetCount)(this, pdwproviders), |result| Some(*result))
+        }
+        unsafe extern "system" fn Begin_GetAt(this: *mut core::ffi::c_void, dwprovider: u32, pprovguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityStore", "Begin_GetAt", 5usize, |this| ((**(this as *const *const AsyncIIdentityStore_Vtbl)).Begin_GetAt)(this, dwprovider, pprovguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_GetAt(this: *mut core::ffi::c_void, pprovguid: *mut windows_core::GUID, ppidentityprovider: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityStore", "Finish_GetAt", 6usize, |this| ((**(this as *const *const AsyncIIdentityStore_Vtbl)).Finish_GetAt)(this, pprovguid, ppidentityprovider), |result| Some(*result))
+        }
+        unsafe extern "system" fn Begin_AddToCache(this: *mut core::ffi::c_void, lpszuniqueid: windows_core::PCWSTR, providerguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityStore", "Begin_AddToCache", 7usize, |this| ((**(this as *const *const AsyncIIdentityStore_Vtbl)).Begin_AddToCache)(this, lpszuniqueid, providerguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_AddToCache(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityStore", "Finish_AddToCache", 8usize, |this| ((**(this as *const *const AsyncIIdentityStore_Vtbl)).Finish_AddToCache)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Begin_ConvertToSid(this: *mut core::ffi::c_void, lpszuniqueid: windows_core::PCWSTR, providerguid: *const windows_core::GUID, cbsid: u16, psid: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityStore", "Begin_ConvertToSid", 9usize, |this| ((**(this as *const *const AsyncIIdentityStore_Vtbl)).Begin_ConvertToSid)(this, lpszuniqueid, providerguid, cbsid, psid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_ConvertToSid(this: *mut core::ffi::c_void, psid: *mut u8, pcbrequiredsid: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityStore", "Finish_ConvertToSid", 10usize, |this| ((**(this as *const *const AsyncIIdentityStore_Vtbl)).Finish_ConvertToSid)(this, psid, pcbrequiredsid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn Begin_EnumerateIdentities(this: *mut core::ffi::c_void, eidentitytype: IDENTITY_TYPE, pfilterkey: *const super::super::super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pfilterpropvarvalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityStore", "Begin_EnumerateIdentities", 11usize, |this| ((**(this as *const *const AsyncIIdentityStore_Vtbl)).Begin_EnumerateIdentities)(this, eidentitytype, pfilterkey, pfilterpropvarvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Finish_EnumerateIdentities(this: *mut core::ffi::c_void, ppidentityenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityStore", "Finish_EnumerateIdentities", 12usize, |this| ((**(this as *const *const AsyncIIdentityStore_Vtbl)).Finish_EnumerateIdentities)(this, ppidentityenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Begin_Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityStore", "Begin_Reset", 13usize, |this| ((**(this as *const *const AsyncIIdentityStore_Vtbl)).Begin_Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityStore", "Finish_Reset", 14usize, |this| ((**(this as *const *const AsyncIIdentityStore_Vtbl)).Finish_Reset)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Begin_GetCount,
+            Finish_GetCount,
+            Begin_GetAt,
+            Finish_GetAt,
+            Begin_AddToCache,
+            Finish_AddToCache,
+            Begin_ConvertToSid,
+            Finish_ConvertToSid,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            Begin_EnumerateIdentities,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            Begin_EnumerateIdentities: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Finish_EnumerateIdentities,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Finish_EnumerateIdentities: 0,
+            Begin_Reset,
+            Finish_Reset,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<AsyncIIdentityStore as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for AsyncIIdentityStoreEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Begin_CreateConnectedIdentity(this: *mut core::ffi::c_void, localname: windows_core::PCWSTR, connectedname: windows_core::PCWSTR, providerguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityStoreEx", "Begin_CreateConnectedIdentity", 3usize, |this| ((**(this as *const *const AsyncIIdentityStoreEx_Vtbl)).Begin_CreateConnectedIdentity)(this, localname, connectedname, providerguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_CreateConnectedIdentity(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityStoreEx", "Finish_CreateConnectedIdentity", 4usize, |this| ((**(this as *const *const AsyncIIdentityStoreEx_Vtbl)).Finish_CreateConnectedIdentity)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Begin_DeleteConnectedIdentity(this: *mut core::ffi::c_void, connectedname: windows_core::PCWSTR, providerguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityStoreEx", "Begin_DeleteConnectedIdentity", 5usize, |this| ((**(this as *const *const AsyncIIdentityStoreEx_Vtbl)).Begin_DeleteConnectedIdentity)(this, connectedname, providerguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_DeleteConnectedIdentity(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIIdentityStoreEx", "Finish_DeleteConnectedIdentity", 6usize, |this| ((**(this as *const *const AsyncIIdentityStoreEx_Vtbl)).Finish_DeleteConnectedIdentity)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Begin_CreateConnectedIdentity,
+            Finish_CreateConnectedIdentity,
+            Begin_DeleteConnectedIdentity,
+            Finish_DeleteConnectedIdentity,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<AsyncIIdentityStoreEx as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IAssociatedIdentityProvider_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn AssociateIdentity(this: *mut core::ffi::c_void, hwndparent: super::super::super::super::Foundation::HWND, pppropertystore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAssociatedIdentityProvider", "AssociateIdentity", 3usize, |this| ((**(this as *const *const IAssociatedIdentityProvider_Vtbl)).AssociateIdentity)(this, hwndparent, pppropertystore), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisassociateIdentity(this: *mut core::ffi::c_void, hwndparent: super::super::super::super::Foundation::HWND, lpszuniqueid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAssociatedIdentityProvider", "DisassociateIdentity", 4usize, |this| ((**(this as *const *const IAssociatedIdentityProvider_Vtbl)).DisassociateIdentity)(this, hwndparent, lpszuniqueid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeCredential(this: *mut core::ffi::c_void, hwndparent: super::super::super::super::Foundation::HWND, lpszuniqueid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAssociatedIdentityProvider", "ChangeCredential", 5usize, |this| ((**(this as *const *const IAssociatedIdentityProvider_Vtbl)).ChangeCredential)(this, hwndparent, lpszuniqueid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            AssociateIdentity,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            AssociateIdentity: 0,
+            DisassociateIdentity,
+            ChangeCredential,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAssociatedIdentityProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IConnectedIdentityProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConnectIdentity(this: *mut core::ffi::c_void, authbuffer: *const u8, authbuffersize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectedIdentityProvider", "ConnectIdentity", 3usize, |this| ((**(this as *const *const IConnectedIdentityProvider_Vtbl)).ConnectIdentity)(this, authbuffer, authbuffersize), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisconnectIdentity(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectedIdentityProvider", "DisconnectIdentity", 4usize, |this| ((**(this as *const *const IConnectedIdentityProvider_Vtbl)).DisconnectIdentity)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsConnected(this: *mut core::ffi::c_void, connected: *mut super::super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectedIdentityProvider", "IsConnected", 5usize, |this| ((**(this as *const *const IConnectedIdentityProvider_Vtbl)).IsConnected)(this, connected), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetUrl(this: *mut core::ffi::c_void, identifier: IDENTITY_URL, context: *mut core::ffi::c_void, postdata: *mut core::mem::MaybeUninit<windows_core::VARIANT>, url: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectedIdentityProvider", "GetUrl", 6usize, |this| ((**(this as *const *const IConnectedIdentityProvider_Vtbl)).GetUrl)(this, identifier, context, postdata, url), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAccountState(this: *mut core::ffi::c_void, pstate: *mut ACCOUNT_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectedIdentityProvider", "GetAccountState", 7usize, |this| ((**(this as *const *const IConnectedIdentityProvider_Vtbl)).GetAccountState)(this, pstate), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ConnectIdentity,
+            DisconnectIdentity,
+            IsConnected,
+            #[cfg(feature = "Win32_System_Com")]
+            GetUrl,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetUrl: 0,
+            GetAccountState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConnectedIdentityProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIdentityAdvise_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IdentityUpdated(this: *mut core::ffi::c_void, dwidentityupdateevents: u32, lpszuniqueid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIdentityAdvise", "IdentityUpdated", 3usize, |this| ((**(this as *const *const IIdentityAdvise_Vtbl)).IdentityUpdated)(this, dwidentityupdateevents, lpszuniqueid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IdentityUpdated }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIdentityAdvise as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IIdentityAuthentication_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetIdentityCredential(this: *mut core::ffi::c_void, credbuffer: *const u8, credbufferlength: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIdentityAuthentication", "SetIdentityCredential", 3usize, |this| ((**(this as *const *const IIdentityAuthentication_Vtbl)).SetIdentityCredential)(this, credbuffer, credbufferlength), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn ValidateIdentityCredential(this: *mut core::ffi::c_void, credbuffer: *const u8, credbufferlength: u32, ppidentityproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIdentityAuthentication", "ValidateIdentityCredential", 4usize, |this| ((**(this as *const *const IIdentityAuthentication_Vtbl)).ValidateIdentityCredential)(this, credbuffer, credbufferlength, ppidentityproperties), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetIdentityCredential,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            ValidateIdentityCredential,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            ValidateIdentityCredential: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIdentityAuthentication as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+impl windows_core::imp::ProxyVtable for IIdentityProvider_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+        unsafe extern "system" fn GetIdentityEnum(this: *mut core::ffi::c_void, eidentitytype: IDENTITY_TYPE, pfilterkey: *const super::super::super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pfilterpropvarvalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, ppidentityenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIdentityProvider", "GetIdentityEnum", 3usize, |this| ((**(this as *const *const IIdentityProvider_Vtbl)).GetIdentityEnum)(this, eidentitytype, pfilterkey, pfilterpropvarvalue, ppidentityenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, lpszusername: windows_core::PCWSTR, pppropertystore: *mut *mut core::ffi::c_void, pkeywordstoadd: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIdentityProvider", "Create", 4usize, |this| ((**(this as *const *const IIdentityProvider_Vtbl)).Create)(this, lpszusername, pppropertystore, pkeywordstoadd), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn Import(this: *mut core::ffi::c_void, ppropertystore: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIdentityProvider", "Import", 5usize, |this| ((**(this as *const *const IIdentityProvider_Vtbl)).Import)(this, ppropertystore), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, lpszuniqueid: windows_core::PCWSTR, pkeywordstodelete: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIdentityProvider", "Delete", 6usize, |this| ((**(this as *const *const IIdentityProvider_Vtbl)).Delete)(this, lpszuniqueid, pkeywordstodelete), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn FindByUniqueID(this: *mut core::ffi::c_void, lpszuniqueid: windows_core::PCWSTR, pppropertystore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIdentityProvider", "FindByUniqueID", 7usize, |this| ((**(this as *const *const IIdentityProvider_Vtbl)).FindByUniqueID)(this, lpszuniqueid, pppropertystore), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetProviderPropertyStore(this: *mut core::ffi::c_void, pppropertystore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIdentityProvider", "GetProviderPropertyStore", 8usize, |this| ((**(this as *const *const IIdentityProvider_Vtbl)).GetProviderPropertyStore)(this, pppropertystore), |result| Some(*result))
+        }
+        unsafe extern "system" fn Advise(this: *mut core::ffi::c_void, pidentityadvise: *mut core::ffi::c_void, dwidentityupdateevents: u32, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIdentityProvider", "Advise", 9usize, |this| ((**(this as *const *const IIdentityProvider_Vtbl)).Advise)(this, pidentityadvise, dwidentityupdateevents, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnAdvise(this: *mut core::ffi::c_void, dwcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIdentityProvider", "UnAdvise", 10usize, |this| ((**(this as *const *const IIdentityProvider_Vtbl)).UnAdvise)(this, dwcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetIdentityEnum,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem")))]
+            GetIdentityEnum: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            Create,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            Create: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            Import,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            Import: 0,
+            Delete,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            FindByUniqueID,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            FindByUniqueID: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetProviderPropertyStore,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetProviderPropertyStore: 0,
+            Advise,
+            UnAdvise,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIdentityProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+impl windows_core::imp::ProxyVtable for IIdentityStore_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pdwproviders: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIdentityStore", "GetCount", 3usize, |this| ((**(this as *const *const IIdentityStore_Vtbl)).GetCount)(this, pdwproviders), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, dwprovider: u32, pprovguid: *mut windows_core::GUID, ppidentityprovider: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIdentityStore", "GetAt", 4usize, |this| ((**(this as *const *const IIdentityStore_Vtbl)).GetAt)(this, dwprovider, pprovguid, ppidentityprovider), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddToCache(this: *mut core::ffi::c_void, lpszuniqueid: windows_core::PCWSTR, providerguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIdentityStore", "AddToCache", 5usize, |this| ((**(this as *const *const IIdentityStore_Vtbl)).AddToCache)(this, lpszuniqueid, providerguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertToSid(this: *mut core::ffi::c_void, lpszuniqueid: windows_core::PCWSTR, providerguid: *const windows_core::GUID, cbsid: u16, psid: *mut u8, pcbrequiredsid: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIdentityStore", "ConvertToSid", 6usize, |this| ((**(this as *const *const IIdentityStore_Vtbl)).ConvertToSid)(this, lpszuniqueid, providerguid, cbsid, psid, pcbrequiredsid), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+        unsafe extern "system" fn EnumerateIdentities(this: *mut core::ffi::c_void, eidentitytype: IDENTITY_TYPE, pfilterkey: *const super::super::super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pfilterpropvarvalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, ppidentityenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIdentityStore", "EnumerateIdentities", 7usize, |this| ((**(this as *const *const IIdentityStore_Vtbl)).EnumerateIdentities)(this, eidentitytype, pfilterkey, pfilterpropvarvalue, ppidentityenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIdentityStore", "Reset", 8usize, |this| ((**(this as *const *const IIdentityStore_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCount,
+            GetAt,
+            AddToCache,
+            ConvertToSid,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem"))]
+            EnumerateIdentities,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_UI_Shell_PropertiesSystem")))]
+            EnumerateIdentities: 0,
+            Reset,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIdentityStore as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIdentityStoreEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateConnectedIdentity(this: *mut core::ffi::c_void, localname: windows_core::PCWSTR, connectedname: windows_core::PCWSTR, providerguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIdentityStoreEx", "CreateConnectedIdentity", 3usize, |this| ((**(this as *const *const IIdentityStoreEx_Vtbl)).CreateConnectedIdentity)(this, localname, connectedname, providerguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteConnectedIdentity(this: *mut core::ffi::c_void, connectedname: windows_core::PCWSTR, providerguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIdentityStoreEx", "DeleteConnectedIdentity", 4usize, |this| ((**(this as *const *const IIdentityStoreEx_Vtbl)).DeleteConnectedIdentity)(this, connectedname, providerguid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateConnectedIdentity, DeleteConnectedIdentity }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIdentityStoreEx as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
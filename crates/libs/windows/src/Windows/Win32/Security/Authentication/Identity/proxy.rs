@@ -0,0 +1,12 @@
+impl windows_core::imp::ProxyVtable for ICcgDomainAuthCredentials_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPasswordCredentials(this: *mut core::ffi::c_void, plugininput: windows_core::PCWSTR, domainname: *mut windows_core::PWSTR, username: *mut windows_core::PWSTR, password: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICcgDomainAuthCredentials", "GetPasswordCredentials", 3usize, |this| ((**(this as *const *const ICcgDomainAuthCredentials_Vtbl)).GetPasswordCredentials)(this, plugininput, domainname, username, password), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPasswordCredentials }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICcgDomainAuthCredentials as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
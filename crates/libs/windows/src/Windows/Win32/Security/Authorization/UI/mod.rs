@@ -314,6 +314,7 @@ pub struct SECURITY_INFO_PAGE_FLAGS(pub u32);
 impl windows_core::TypeKind for SECURITY_INFO_PAGE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SECURITY_INFO_PAGE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SECURITY_INFO_PAGE_FLAGS").field(&self.0).finish()
@@ -358,6 +359,7 @@ pub struct SI_OBJECT_INFO_FLAGS(pub u32);
 impl windows_core::TypeKind for SI_OBJECT_INFO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SI_OBJECT_INFO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SI_OBJECT_INFO_FLAGS").field(&self.0).finish()
@@ -402,6 +404,7 @@ pub struct SI_PAGE_ACTIVATED(pub i32);
 impl windows_core::TypeKind for SI_PAGE_ACTIVATED {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SI_PAGE_ACTIVATED {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SI_PAGE_ACTIVATED").field(&self.0).finish()
@@ -413,13 +416,15 @@ pub struct SI_PAGE_TYPE(pub i32);
 impl windows_core::TypeKind for SI_PAGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SI_PAGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SI_PAGE_TYPE").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EFFPERM_RESULT_LIST {
     pub fEvaluated: super::super::super::Foundation::BOOLEAN,
     pub cObjectTypeListLength: u32,
@@ -435,7 +440,8 @@ impl Default for EFFPERM_RESULT_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SECURITY_OBJECT {
     pub pwszName: windows_core::PWSTR,
     pub pData: *mut core::ffi::c_void,
@@ -454,7 +460,8 @@ impl Default for SECURITY_OBJECT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SID_INFO {
     pub pSid: super::super::PSID,
     pub pwzCommonName: windows_core::PWSTR,
@@ -470,7 +477,8 @@ impl Default for SID_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SID_INFO_LIST {
     pub cItems: u32,
     pub aSidInfo: [SID_INFO; 1],
@@ -484,7 +492,8 @@ impl Default for SID_INFO_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SI_ACCESS {
     pub pguid: *const windows_core::GUID,
     pub mask: u32,
@@ -500,7 +509,8 @@ impl Default for SI_ACCESS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SI_INHERIT_TYPE {
     pub pguid: *const windows_core::GUID,
     pub dwFlags: super::super::ACE_FLAGS,
@@ -515,7 +525,8 @@ impl Default for SI_INHERIT_TYPE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SI_OBJECT_INFO {
     pub dwFlags: SI_OBJECT_INFO_FLAGS,
     pub hInstance: super::super::super::Foundation::HINSTANCE,
@@ -534,3 +545,5 @@ impl Default for SI_OBJECT_INFO {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
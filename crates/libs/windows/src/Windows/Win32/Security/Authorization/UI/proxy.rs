@@ -0,0 +1,154 @@
+impl windows_core::imp::ProxyVtable for IEffectivePermission_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEffectivePermission(this: *mut core::ffi::c_void, pguidobjecttype: *const windows_core::GUID, pusersid: super::super::PSID, pszservername: windows_core::PCWSTR, psd: super::super::PSECURITY_DESCRIPTOR, ppobjecttypelist: *mut *mut super::super::OBJECT_TYPE_LIST, pcobjecttypelistlength: *mut u32, ppgrantedaccesslist: *mut *mut u32, pcgrantedaccesslistlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEffectivePermission", "GetEffectivePermission", 3usize, |this| ((**(this as *const *const IEffectivePermission_Vtbl)).GetEffectivePermission)(this, pguidobjecttype, pusersid, pszservername, psd, ppobjecttypelist, pcobjecttypelistlength, ppgrantedaccesslist, pcgrantedaccesslistlength), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetEffectivePermission }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEffectivePermission as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEffectivePermission2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ComputeEffectivePermissionWithSecondarySecurity(
+            this: *mut core::ffi::c_void,
+            psid: super::super::PSID,
+            pdevicesid: super::super::PSID,
+            pszservername: windows_core::PCWSTR,
+            psecurityobjects: *mut SECURITY_OBJECT,
+            dwsecurityobjectcount: u32,
+            pusergroups: *const super::super::TOKEN_GROUPS,
+            pauthzusergroupsoperations: *const super::AUTHZ_SID_OPERATION,
+            pdevicegroups: *const super::super::TOKEN_GROUPS,
+            pauthzdevicegroupsoperations: *const super::AUTHZ_SID_OPERATION,
+            pauthzuserclaims: *const super::AUTHZ_SECURITY_ATTRIBUTES_INFORMATION,
+            pauthzuserclaimsoperations: *const super::AUTHZ_SECURITY_ATTRIBUTE_OPERATION,
+            pauthzdeviceclaims: *const super::AUTHZ_SECURITY_ATTRIBUTES_INFORMATION,
+            pauthzdeviceclaimsoperations: *const super::AUTHZ_SECURITY_ATTRIBUTE_OPERATION,
+            peffpermresultlists: *mut EFFPERM_RESULT_LIST,
+        ) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(
+                this,
+                "IEffectivePermission2",
+                "ComputeEffectivePermissionWithSecondarySecurity",
+                3usize,
+                |this| ((**(this as *const *const IEffectivePermission2_Vtbl)).ComputeEffectivePermissionWithSecondarySecurity)(this, psid, pdevicesid, pszservername, psecurityobjects, dwsecurityobjectcount, pusergroups, pauthzusergroupsoperations, pdevicegroups, pauthzdevicegroupsoperations, pauthzuserclaims, pauthzuserclaimsoperations, pauthzdeviceclaims, pauthzdeviceclaimsoperations, peffpermresultlists),
+                |result| Some(*result),
+            )
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ComputeEffectivePermissionWithSecondarySecurity }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEffectivePermission2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Controls")]
+impl windows_core::imp::ProxyVtable for ISecurityInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetObjectInformation(this: *mut core::ffi::c_void, pobjectinfo: *mut SI_OBJECT_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityInformation", "GetObjectInformation", 3usize, |this| ((**(this as *const *const ISecurityInformation_Vtbl)).GetObjectInformation)(this, pobjectinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSecurity(this: *mut core::ffi::c_void, requestedinformation: super::super::OBJECT_SECURITY_INFORMATION, ppsecuritydescriptor: *mut super::super::PSECURITY_DESCRIPTOR, fdefault: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityInformation", "GetSecurity", 4usize, |this| ((**(this as *const *const ISecurityInformation_Vtbl)).GetSecurity)(this, requestedinformation, ppsecuritydescriptor, fdefault), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSecurity(this: *mut core::ffi::c_void, securityinformation: super::super::OBJECT_SECURITY_INFORMATION, psecuritydescriptor: super::super::PSECURITY_DESCRIPTOR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityInformation", "SetSecurity", 5usize, |this| ((**(this as *const *const ISecurityInformation_Vtbl)).SetSecurity)(this, securityinformation, psecuritydescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAccessRights(this: *mut core::ffi::c_void, pguidobjecttype: *const windows_core::GUID, dwflags: SECURITY_INFO_PAGE_FLAGS, ppaccess: *mut *mut SI_ACCESS, pcaccesses: *mut u32, pidefaultaccess: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityInformation", "GetAccessRights", 6usize, |this| ((**(this as *const *const ISecurityInformation_Vtbl)).GetAccessRights)(this, pguidobjecttype, dwflags, ppaccess, pcaccesses, pidefaultaccess), |result| Some(*result))
+        }
+        unsafe extern "system" fn MapGeneric(this: *mut core::ffi::c_void, pguidobjecttype: *const windows_core::GUID, paceflags: *mut u8, pmask: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityInformation", "MapGeneric", 7usize, |this| ((**(this as *const *const ISecurityInformation_Vtbl)).MapGeneric)(this, pguidobjecttype, paceflags, pmask), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInheritTypes(this: *mut core::ffi::c_void, ppinherittypes: *mut *mut SI_INHERIT_TYPE, pcinherittypes: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityInformation", "GetInheritTypes", 8usize, |this| ((**(this as *const *const ISecurityInformation_Vtbl)).GetInheritTypes)(this, ppinherittypes, pcinherittypes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Controls")]
+        unsafe extern "system" fn PropertySheetPageCallback(this: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND, umsg: super::super::super::UI::Controls::PSPCB_MESSAGE, upage: SI_PAGE_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityInformation", "PropertySheetPageCallback", 9usize, |this| ((**(this as *const *const ISecurityInformation_Vtbl)).PropertySheetPageCallback)(this, hwnd, umsg, upage), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetObjectInformation,
+            GetSecurity,
+            SetSecurity,
+            GetAccessRights,
+            MapGeneric,
+            GetInheritTypes,
+            #[cfg(feature = "Win32_UI_Controls")]
+            PropertySheetPageCallback,
+            #[cfg(not(feature = "Win32_UI_Controls"))]
+            PropertySheetPageCallback: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISecurityInformation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISecurityInformation2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsDaclCanonical(this: *mut core::ffi::c_void, pdacl: *const super::super::ACL) -> super::super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ISecurityInformation2", "IsDaclCanonical", 3usize, |this| ((**(this as *const *const ISecurityInformation2_Vtbl)).IsDaclCanonical)(this, pdacl), |_| None)
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn LookupSids(this: *mut core::ffi::c_void, csids: u32, rgpsids: *const super::super::PSID, ppdo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityInformation2", "LookupSids", 4usize, |this| ((**(this as *const *const ISecurityInformation2_Vtbl)).LookupSids)(this, csids, rgpsids, ppdo), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsDaclCanonical,
+            #[cfg(feature = "Win32_System_Com")]
+            LookupSids,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            LookupSids: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISecurityInformation2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISecurityInformation3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFullResourceName(this: *mut core::ffi::c_void, ppszresourcename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityInformation3", "GetFullResourceName", 3usize, |this| ((**(this as *const *const ISecurityInformation3_Vtbl)).GetFullResourceName)(this, ppszresourcename), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenElevatedEditor(this: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND, upage: SI_PAGE_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityInformation3", "OpenElevatedEditor", 4usize, |this| ((**(this as *const *const ISecurityInformation3_Vtbl)).OpenElevatedEditor)(this, hwnd, upage), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFullResourceName, OpenElevatedEditor }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISecurityInformation3 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISecurityInformation4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSecondarySecurity(this: *mut core::ffi::c_void, psecurityobjects: *mut *mut SECURITY_OBJECT, psecurityobjectcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityInformation4", "GetSecondarySecurity", 3usize, |this| ((**(this as *const *const ISecurityInformation4_Vtbl)).GetSecondarySecurity)(this, psecurityobjects, psecurityobjectcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSecondarySecurity }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISecurityInformation4 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISecurityObjectTypeInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInheritSource(this: *mut core::ffi::c_void, si: u32, pacl: *mut super::super::ACL, ppinheritarray: *mut *mut super::INHERITED_FROMA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityObjectTypeInfo", "GetInheritSource", 3usize, |this| ((**(this as *const *const ISecurityObjectTypeInfo_Vtbl)).GetInheritSource)(this, si, pacl, ppinheritarray), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetInheritSource }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISecurityObjectTypeInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -0,0 +1,2088 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzApplication_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "Name", 7usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "SetName", 8usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).SetName)(this, bstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, pbstrdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "Description", 9usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).Description)(this, pbstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "SetDescription", 10usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).SetDescription)(this, bstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplicationData(this: *mut core::ffi::c_void, pbstrapplicationdata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "ApplicationData", 11usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).ApplicationData)(this, pbstrapplicationdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetApplicationData(this: *mut core::ffi::c_void, bstrapplicationdata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "SetApplicationData", 12usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).SetApplicationData)(this, bstrapplicationdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthzInterfaceClsid(this: *mut core::ffi::c_void, pbstrprop: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "AuthzInterfaceClsid", 13usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).AuthzInterfaceClsid)(this, pbstrprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthzInterfaceClsid(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "SetAuthzInterfaceClsid", 14usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).SetAuthzInterfaceClsid)(this, bstrprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn Version(this: *mut core::ffi::c_void, pbstrprop: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "Version", 15usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).Version)(this, pbstrprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVersion(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "SetVersion", 16usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).SetVersion)(this, bstrprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GenerateAudits(this: *mut core::ffi::c_void, pbprop: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "GenerateAudits", 17usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).GenerateAudits)(this, pbprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGenerateAudits(this: *mut core::ffi::c_void, bprop: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "SetGenerateAudits", 18usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).SetGenerateAudits)(this, bprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplyStoreSacl(this: *mut core::ffi::c_void, pbprop: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "ApplyStoreSacl", 19usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).ApplyStoreSacl)(this, pbprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetApplyStoreSacl(this: *mut core::ffi::c_void, bprop: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "SetApplyStoreSacl", 20usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).SetApplyStoreSacl)(this, bprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn Writable(this: *mut core::ffi::c_void, pfprop: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "Writable", 21usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).Writable)(this, pfprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, lpropid: i32, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, pvarprop: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "GetProperty", 22usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).GetProperty)(this, lpropid, varreserved, pvarprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, lpropid: i32, varprop: core::mem::MaybeUninit<windows_core::VARIANT>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "SetProperty", 23usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).SetProperty)(this, lpropid, varprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn PolicyAdministrators(this: *mut core::ffi::c_void, pvaradmins: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "PolicyAdministrators", 24usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).PolicyAdministrators)(this, pvaradmins), |result| Some(*result))
+        }
+        unsafe extern "system" fn PolicyReaders(this: *mut core::ffi::c_void, pvarreaders: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "PolicyReaders", 25usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).PolicyReaders)(this, pvarreaders), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPolicyAdministrator(this: *mut core::ffi::c_void, bstradmin: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "AddPolicyAdministrator", 26usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).AddPolicyAdministrator)(this, bstradmin, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeletePolicyAdministrator(this: *mut core::ffi::c_void, bstradmin: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "DeletePolicyAdministrator", 27usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).DeletePolicyAdministrator)(this, bstradmin, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPolicyReader(this: *mut core::ffi::c_void, bstrreader: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "AddPolicyReader", 28usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).AddPolicyReader)(this, bstrreader, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeletePolicyReader(this: *mut core::ffi::c_void, bstrreader: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "DeletePolicyReader", 29usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).DeletePolicyReader)(this, bstrreader, varreserved), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Scopes(this: *mut core::ffi::c_void, ppscopecollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "Scopes", 30usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).Scopes)(this, ppscopecollection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OpenScope(this: *mut core::ffi::c_void, bstrscopename: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, ppscope: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "OpenScope", 31usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).OpenScope)(this, bstrscopename, varreserved, ppscope), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateScope(this: *mut core::ffi::c_void, bstrscopename: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, ppscope: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "CreateScope", 32usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).CreateScope)(this, bstrscopename, varreserved, ppscope), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteScope(this: *mut core::ffi::c_void, bstrscopename: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "DeleteScope", 33usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).DeleteScope)(this, bstrscopename, varreserved), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Operations(this: *mut core::ffi::c_void, ppoperationcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "Operations", 34usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).Operations)(this, ppoperationcollection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OpenOperation(this: *mut core::ffi::c_void, bstroperationname: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, ppoperation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "OpenOperation", 35usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).OpenOperation)(this, bstroperationname, varreserved, ppoperation), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateOperation(this: *mut core::ffi::c_void, bstroperationname: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, ppoperation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "CreateOperation", 36usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).CreateOperation)(this, bstroperationname, varreserved, ppoperation), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteOperation(this: *mut core::ffi::c_void, bstroperationname: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "DeleteOperation", 37usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).DeleteOperation)(this, bstroperationname, varreserved), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Tasks(this: *mut core::ffi::c_void, pptaskcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "Tasks", 38usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).Tasks)(this, pptaskcollection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OpenTask(this: *mut core::ffi::c_void, bstrtaskname: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, pptask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "OpenTask", 39usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).OpenTask)(this, bstrtaskname, varreserved, pptask), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateTask(this: *mut core::ffi::c_void, bstrtaskname: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, pptask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "CreateTask", 40usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).CreateTask)(this, bstrtaskname, varreserved, pptask), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteTask(this: *mut core::ffi::c_void, bstrtaskname: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "DeleteTask", 41usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).DeleteTask)(this, bstrtaskname, varreserved), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ApplicationGroups(this: *mut core::ffi::c_void, ppgroupcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "ApplicationGroups", 42usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).ApplicationGroups)(this, ppgroupcollection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OpenApplicationGroup(this: *mut core::ffi::c_void, bstrgroupname: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, ppgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "OpenApplicationGroup", 43usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).OpenApplicationGroup)(this, bstrgroupname, varreserved, ppgroup), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateApplicationGroup(this: *mut core::ffi::c_void, bstrgroupname: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, ppgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "CreateApplicationGroup", 44usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).CreateApplicationGroup)(this, bstrgroupname, varreserved, ppgroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteApplicationGroup(this: *mut core::ffi::c_void, bstrgroupname: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "DeleteApplicationGroup", 45usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).DeleteApplicationGroup)(this, bstrgroupname, varreserved), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Roles(this: *mut core::ffi::c_void, pprolecollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "Roles", 46usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).Roles)(this, pprolecollection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OpenRole(this: *mut core::ffi::c_void, bstrrolename: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, pprole: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "OpenRole", 47usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).OpenRole)(this, bstrrolename, varreserved, pprole), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateRole(this: *mut core::ffi::c_void, bstrrolename: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, pprole: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "CreateRole", 48usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).CreateRole)(this, bstrrolename, varreserved, pprole), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteRole(this: *mut core::ffi::c_void, bstrrolename: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "DeleteRole", 49usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).DeleteRole)(this, bstrrolename, varreserved), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeClientContextFromToken(this: *mut core::ffi::c_void, ulltokenhandle: u64, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, ppclientcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "InitializeClientContextFromToken", 50usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).InitializeClientContextFromToken)(this, ulltokenhandle, varreserved, ppclientcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPropertyItem(this: *mut core::ffi::c_void, lpropid: i32, varprop: core::mem::MaybeUninit<windows_core::VARIANT>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "AddPropertyItem", 51usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).AddPropertyItem)(this, lpropid, varprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeletePropertyItem(this: *mut core::ffi::c_void, lpropid: i32, varprop: core::mem::MaybeUninit<windows_core::VARIANT>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "DeletePropertyItem", 52usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).DeletePropertyItem)(this, lpropid, varprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn Submit(this: *mut core::ffi::c_void, lflags: i32, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "Submit", 53usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).Submit)(this, lflags, varreserved), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeClientContextFromName(this: *mut core::ffi::c_void, clientname: core::mem::MaybeUninit<windows_core::BSTR>, domainname: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, ppclientcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "InitializeClientContextFromName", 54usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).InitializeClientContextFromName)(this, clientname, domainname, varreserved, ppclientcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn DelegatedPolicyUsers(this: *mut core::ffi::c_void, pvardelegatedpolicyusers: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "DelegatedPolicyUsers", 55usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).DelegatedPolicyUsers)(this, pvardelegatedpolicyusers), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDelegatedPolicyUser(this: *mut core::ffi::c_void, bstrdelegatedpolicyuser: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "AddDelegatedPolicyUser", 56usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).AddDelegatedPolicyUser)(this, bstrdelegatedpolicyuser, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteDelegatedPolicyUser(this: *mut core::ffi::c_void, bstrdelegatedpolicyuser: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "DeleteDelegatedPolicyUser", 57usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).DeleteDelegatedPolicyUser)(this, bstrdelegatedpolicyuser, varreserved), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeClientContextFromStringSid(this: *mut core::ffi::c_void, sidstring: core::mem::MaybeUninit<windows_core::BSTR>, loptions: i32, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, ppclientcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "InitializeClientContextFromStringSid", 58usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).InitializeClientContextFromStringSid)(this, sidstring, loptions, varreserved, ppclientcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn PolicyAdministratorsName(this: *mut core::ffi::c_void, pvaradmins: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "PolicyAdministratorsName", 59usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).PolicyAdministratorsName)(this, pvaradmins), |result| Some(*result))
+        }
+        unsafe extern "system" fn PolicyReadersName(this: *mut core::ffi::c_void, pvarreaders: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "PolicyReadersName", 60usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).PolicyReadersName)(this, pvarreaders), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPolicyAdministratorName(this: *mut core::ffi::c_void, bstradmin: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "AddPolicyAdministratorName", 61usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).AddPolicyAdministratorName)(this, bstradmin, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeletePolicyAdministratorName(this: *mut core::ffi::c_void, bstradmin: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "DeletePolicyAdministratorName", 62usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).DeletePolicyAdministratorName)(this, bstradmin, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPolicyReaderName(this: *mut core::ffi::c_void, bstrreader: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "AddPolicyReaderName", 63usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).AddPolicyReaderName)(this, bstrreader, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeletePolicyReaderName(this: *mut core::ffi::c_void, bstrreader: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "DeletePolicyReaderName", 64usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).DeletePolicyReaderName)(this, bstrreader, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DelegatedPolicyUsersName(this: *mut core::ffi::c_void, pvardelegatedpolicyusers: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "DelegatedPolicyUsersName", 65usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).DelegatedPolicyUsersName)(this, pvardelegatedpolicyusers), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDelegatedPolicyUserName(this: *mut core::ffi::c_void, bstrdelegatedpolicyuser: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "AddDelegatedPolicyUserName", 66usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).AddDelegatedPolicyUserName)(this, bstrdelegatedpolicyuser, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteDelegatedPolicyUserName(this: *mut core::ffi::c_void, bstrdelegatedpolicyuser: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication", "DeleteDelegatedPolicyUserName", 67usize, |this| ((**(this as *const *const IAzApplication_Vtbl)).DeleteDelegatedPolicyUserName)(this, bstrdelegatedpolicyuser, varreserved), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            SetName,
+            Description,
+            SetDescription,
+            ApplicationData,
+            SetApplicationData,
+            AuthzInterfaceClsid,
+            SetAuthzInterfaceClsid,
+            Version,
+            SetVersion,
+            GenerateAudits,
+            SetGenerateAudits,
+            ApplyStoreSacl,
+            SetApplyStoreSacl,
+            Writable,
+            GetProperty,
+            SetProperty,
+            PolicyAdministrators,
+            PolicyReaders,
+            AddPolicyAdministrator,
+            DeletePolicyAdministrator,
+            AddPolicyReader,
+            DeletePolicyReader,
+            #[cfg(feature = "Win32_System_Com")]
+            Scopes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Scopes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OpenScope,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OpenScope: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateScope,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateScope: 0,
+            DeleteScope,
+            #[cfg(feature = "Win32_System_Com")]
+            Operations,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Operations: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OpenOperation,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OpenOperation: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateOperation,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateOperation: 0,
+            DeleteOperation,
+            #[cfg(feature = "Win32_System_Com")]
+            Tasks,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Tasks: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OpenTask,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OpenTask: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateTask,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateTask: 0,
+            DeleteTask,
+            #[cfg(feature = "Win32_System_Com")]
+            ApplicationGroups,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ApplicationGroups: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OpenApplicationGroup,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OpenApplicationGroup: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateApplicationGroup,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateApplicationGroup: 0,
+            DeleteApplicationGroup,
+            #[cfg(feature = "Win32_System_Com")]
+            Roles,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Roles: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OpenRole,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OpenRole: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateRole,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateRole: 0,
+            DeleteRole,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeClientContextFromToken,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeClientContextFromToken: 0,
+            AddPropertyItem,
+            DeletePropertyItem,
+            Submit,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeClientContextFromName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeClientContextFromName: 0,
+            DelegatedPolicyUsers,
+            AddDelegatedPolicyUser,
+            DeleteDelegatedPolicyUser,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeClientContextFromStringSid,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeClientContextFromStringSid: 0,
+            PolicyAdministratorsName,
+            PolicyReadersName,
+            AddPolicyAdministratorName,
+            DeletePolicyAdministratorName,
+            AddPolicyReaderName,
+            DeletePolicyReaderName,
+            DelegatedPolicyUsersName,
+            AddDelegatedPolicyUserName,
+            DeleteDelegatedPolicyUserName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzApplication as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzApplication2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeClientContextFromToken2(this: *mut core::ffi::c_void, ultokenhandlelowpart: u32, ultokenhandlehighpart: u32, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, ppclientcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication2", "InitializeClientContextFromToken2", 68usize, |this| ((**(this as *const *const IAzApplication2_Vtbl)).InitializeClientContextFromToken2)(this, ultokenhandlelowpart, ultokenhandlehighpart, varreserved, ppclientcontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeClientContext2(this: *mut core::ffi::c_void, identifyingstring: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, ppclientcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication2", "InitializeClientContext2", 69usize, |this| ((**(this as *const *const IAzApplication2_Vtbl)).InitializeClientContext2)(this, identifyingstring, varreserved, ppclientcontext), |result| Some(*result))
+        }
+        Self {
+            base__: <IAzApplication_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeClientContextFromToken2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeClientContextFromToken2: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeClientContext2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeClientContext2: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzApplication2 as windows_core::Interface>::IID || <IAzApplication_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzApplication3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ScopeExists(this: *mut core::ffi::c_void, bstrscopename: core::mem::MaybeUninit<windows_core::BSTR>, pbexist: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication3", "ScopeExists", 70usize, |this| ((**(this as *const *const IAzApplication3_Vtbl)).ScopeExists)(this, bstrscopename, pbexist), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OpenScope2(this: *mut core::ffi::c_void, bstrscopename: core::mem::MaybeUninit<windows_core::BSTR>, ppscope2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication3", "OpenScope2", 71usize, |this| ((**(this as *const *const IAzApplication3_Vtbl)).OpenScope2)(this, bstrscopename, ppscope2), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateScope2(this: *mut core::ffi::c_void, bstrscopename: core::mem::MaybeUninit<windows_core::BSTR>, ppscope2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication3", "CreateScope2", 72usize, |this| ((**(this as *const *const IAzApplication3_Vtbl)).CreateScope2)(this, bstrscopename, ppscope2), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteScope2(this: *mut core::ffi::c_void, bstrscopename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication3", "DeleteScope2", 73usize, |this| ((**(this as *const *const IAzApplication3_Vtbl)).DeleteScope2)(this, bstrscopename), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RoleDefinitions(this: *mut core::ffi::c_void, pproledefinitions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication3", "RoleDefinitions", 74usize, |this| ((**(this as *const *const IAzApplication3_Vtbl)).RoleDefinitions)(this, pproledefinitions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateRoleDefinition(this: *mut core::ffi::c_void, bstrroledefinitionname: core::mem::MaybeUninit<windows_core::BSTR>, pproledefinitions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication3", "CreateRoleDefinition", 75usize, |this| ((**(this as *const *const IAzApplication3_Vtbl)).CreateRoleDefinition)(this, bstrroledefinitionname, pproledefinitions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OpenRoleDefinition(this: *mut core::ffi::c_void, bstrroledefinitionname: core::mem::MaybeUninit<windows_core::BSTR>, pproledefinitions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication3", "OpenRoleDefinition", 76usize, |this| ((**(this as *const *const IAzApplication3_Vtbl)).OpenRoleDefinition)(this, bstrroledefinitionname, pproledefinitions), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteRoleDefinition(this: *mut core::ffi::c_void, bstrroledefinitionname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication3", "DeleteRoleDefinition", 77usize, |this| ((**(this as *const *const IAzApplication3_Vtbl)).DeleteRoleDefinition)(this, bstrroledefinitionname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RoleAssignments(this: *mut core::ffi::c_void, pproleassignments: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication3", "RoleAssignments", 78usize, |this| ((**(this as *const *const IAzApplication3_Vtbl)).RoleAssignments)(this, pproleassignments), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateRoleAssignment(this: *mut core::ffi::c_void, bstrroleassignmentname: core::mem::MaybeUninit<windows_core::BSTR>, pproleassignment: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication3", "CreateRoleAssignment", 79usize, |this| ((**(this as *const *const IAzApplication3_Vtbl)).CreateRoleAssignment)(this, bstrroleassignmentname, pproleassignment), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OpenRoleAssignment(this: *mut core::ffi::c_void, bstrroleassignmentname: core::mem::MaybeUninit<windows_core::BSTR>, pproleassignment: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication3", "OpenRoleAssignment", 80usize, |this| ((**(this as *const *const IAzApplication3_Vtbl)).OpenRoleAssignment)(this, bstrroleassignmentname, pproleassignment), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteRoleAssignment(this: *mut core::ffi::c_void, bstrroleassignmentname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication3", "DeleteRoleAssignment", 81usize, |this| ((**(this as *const *const IAzApplication3_Vtbl)).DeleteRoleAssignment)(this, bstrroleassignmentname), |result| Some(*result))
+        }
+        unsafe extern "system" fn BizRulesEnabled(this: *mut core::ffi::c_void, pbenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication3", "BizRulesEnabled", 82usize, |this| ((**(this as *const *const IAzApplication3_Vtbl)).BizRulesEnabled)(this, pbenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBizRulesEnabled(this: *mut core::ffi::c_void, benabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplication3", "SetBizRulesEnabled", 83usize, |this| ((**(this as *const *const IAzApplication3_Vtbl)).SetBizRulesEnabled)(this, benabled), |result| Some(*result))
+        }
+        Self {
+            base__: <IAzApplication2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ScopeExists,
+            #[cfg(feature = "Win32_System_Com")]
+            OpenScope2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OpenScope2: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateScope2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateScope2: 0,
+            DeleteScope2,
+            #[cfg(feature = "Win32_System_Com")]
+            RoleDefinitions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RoleDefinitions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateRoleDefinition,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateRoleDefinition: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OpenRoleDefinition,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OpenRoleDefinition: 0,
+            DeleteRoleDefinition,
+            #[cfg(feature = "Win32_System_Com")]
+            RoleAssignments,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RoleAssignments: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateRoleAssignment,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateRoleAssignment: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OpenRoleAssignment,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OpenRoleAssignment: 0,
+            DeleteRoleAssignment,
+            BizRulesEnabled,
+            SetBizRulesEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzApplication3 as windows_core::Interface>::IID || <IAzApplication2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzApplicationGroup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "Name", 7usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "SetName", 8usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).SetName)(this, bstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, plprop: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "Type", 9usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).Type)(this, plprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetType(this: *mut core::ffi::c_void, lprop: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "SetType", 10usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).SetType)(this, lprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn LdapQuery(this: *mut core::ffi::c_void, pbstrprop: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "LdapQuery", 11usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).LdapQuery)(this, pbstrprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLdapQuery(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "SetLdapQuery", 12usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).SetLdapQuery)(this, bstrprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppMembers(this: *mut core::ffi::c_void, pvarprop: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "AppMembers", 13usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).AppMembers)(this, pvarprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppNonMembers(this: *mut core::ffi::c_void, pvarprop: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "AppNonMembers", 14usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).AppNonMembers)(this, pvarprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn Members(this: *mut core::ffi::c_void, pvarprop: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "Members", 15usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).Members)(this, pvarprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn NonMembers(this: *mut core::ffi::c_void, pvarprop: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "NonMembers", 16usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).NonMembers)(this, pvarprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, pbstrdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "Description", 17usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).Description)(this, pbstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "SetDescription", 18usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).SetDescription)(this, bstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddAppMember(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "AddAppMember", 19usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).AddAppMember)(this, bstrprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAppMember(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "DeleteAppMember", 20usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).DeleteAppMember)(this, bstrprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddAppNonMember(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "AddAppNonMember", 21usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).AddAppNonMember)(this, bstrprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAppNonMember(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "DeleteAppNonMember", 22usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).DeleteAppNonMember)(this, bstrprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddMember(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "AddMember", 23usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).AddMember)(this, bstrprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteMember(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "DeleteMember", 24usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).DeleteMember)(this, bstrprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddNonMember(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "AddNonMember", 25usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).AddNonMember)(this, bstrprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteNonMember(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "DeleteNonMember", 26usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).DeleteNonMember)(this, bstrprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn Writable(this: *mut core::ffi::c_void, pfprop: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "Writable", 27usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).Writable)(this, pfprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, lpropid: i32, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, pvarprop: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "GetProperty", 28usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).GetProperty)(this, lpropid, varreserved, pvarprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, lpropid: i32, varprop: core::mem::MaybeUninit<windows_core::VARIANT>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "SetProperty", 29usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).SetProperty)(this, lpropid, varprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPropertyItem(this: *mut core::ffi::c_void, lpropid: i32, varprop: core::mem::MaybeUninit<windows_core::VARIANT>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "AddPropertyItem", 30usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).AddPropertyItem)(this, lpropid, varprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeletePropertyItem(this: *mut core::ffi::c_void, lpropid: i32, varprop: core::mem::MaybeUninit<windows_core::VARIANT>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "DeletePropertyItem", 31usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).DeletePropertyItem)(this, lpropid, varprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn Submit(this: *mut core::ffi::c_void, lflags: i32, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "Submit", 32usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).Submit)(this, lflags, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddMemberName(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "AddMemberName", 33usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).AddMemberName)(this, bstrprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteMemberName(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "DeleteMemberName", 34usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).DeleteMemberName)(this, bstrprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddNonMemberName(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "AddNonMemberName", 35usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).AddNonMemberName)(this, bstrprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteNonMemberName(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "DeleteNonMemberName", 36usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).DeleteNonMemberName)(this, bstrprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn MembersName(this: *mut core::ffi::c_void, pvarprop: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "MembersName", 37usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).MembersName)(this, pvarprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn NonMembersName(this: *mut core::ffi::c_void, pvarprop: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup", "NonMembersName", 38usize, |this| ((**(this as *const *const IAzApplicationGroup_Vtbl)).NonMembersName)(this, pvarprop), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            SetName,
+            Type,
+            SetType,
+            LdapQuery,
+            SetLdapQuery,
+            AppMembers,
+            AppNonMembers,
+            Members,
+            NonMembers,
+            Description,
+            SetDescription,
+            AddAppMember,
+            DeleteAppMember,
+            AddAppNonMember,
+            DeleteAppNonMember,
+            AddMember,
+            DeleteMember,
+            AddNonMember,
+            DeleteNonMember,
+            Writable,
+            GetProperty,
+            SetProperty,
+            AddPropertyItem,
+            DeletePropertyItem,
+            Submit,
+            AddMemberName,
+            DeleteMemberName,
+            AddNonMemberName,
+            DeleteNonMemberName,
+            MembersName,
+            NonMembersName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzApplicationGroup as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzApplicationGroup2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BizRule(this: *mut core::ffi::c_void, pbstrprop: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup2", "BizRule", 39usize, |this| ((**(this as *const *const IAzApplicationGroup2_Vtbl)).BizRule)(this, pbstrprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBizRule(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup2", "SetBizRule", 40usize, |this| ((**(this as *const *const IAzApplicationGroup2_Vtbl)).SetBizRule)(this, bstrprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn BizRuleLanguage(this: *mut core::ffi::c_void, pbstrprop: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup2", "BizRuleLanguage", 41usize, |this| ((**(this as *const *const IAzApplicationGroup2_Vtbl)).BizRuleLanguage)(this, pbstrprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBizRuleLanguage(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup2", "SetBizRuleLanguage", 42usize, |this| ((**(this as *const *const IAzApplicationGroup2_Vtbl)).SetBizRuleLanguage)(this, bstrprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn BizRuleImportedPath(this: *mut core::ffi::c_void, pbstrprop: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup2", "BizRuleImportedPath", 43usize, |this| ((**(this as *const *const IAzApplicationGroup2_Vtbl)).BizRuleImportedPath)(this, pbstrprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBizRuleImportedPath(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup2", "SetBizRuleImportedPath", 44usize, |this| ((**(this as *const *const IAzApplicationGroup2_Vtbl)).SetBizRuleImportedPath)(this, bstrprop), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RoleAssignments(this: *mut core::ffi::c_void, bstrscopename: core::mem::MaybeUninit<windows_core::BSTR>, brecursive: super::super::Foundation::VARIANT_BOOL, pproleassignments: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroup2", "RoleAssignments", 45usize, |this| ((**(this as *const *const IAzApplicationGroup2_Vtbl)).RoleAssignments)(this, bstrscopename, brecursive, pproleassignments), |result| Some(*result))
+        }
+        Self {
+            base__: <IAzApplicationGroup_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BizRule,
+            SetBizRule,
+            BizRuleLanguage,
+            SetBizRuleLanguage,
+            BizRuleImportedPath,
+            SetBizRuleImportedPath,
+            #[cfg(feature = "Win32_System_Com")]
+            RoleAssignments,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RoleAssignments: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzApplicationGroup2 as windows_core::Interface>::IID || <IAzApplicationGroup_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzApplicationGroups_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, pvarobtptr: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroups", "get_Item", 7usize, |this| ((**(this as *const *const IAzApplicationGroups_Vtbl)).get_Item)(this, index, pvarobtptr), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroups", "Count", 8usize, |this| ((**(this as *const *const IAzApplicationGroups_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppenumptr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplicationGroups", "_NewEnum", 9usize, |this| ((**(this as *const *const IAzApplicationGroups_Vtbl))._NewEnum)(this, ppenumptr), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, get_Item, Count, _NewEnum }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzApplicationGroups as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzApplications_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, pvarobtptr: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplications", "get_Item", 7usize, |this| ((**(this as *const *const IAzApplications_Vtbl)).get_Item)(this, index, pvarobtptr), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplications", "Count", 8usize, |this| ((**(this as *const *const IAzApplications_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppenumptr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzApplications", "_NewEnum", 9usize, |this| ((**(this as *const *const IAzApplications_Vtbl))._NewEnum)(this, ppenumptr), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, get_Item, Count, _NewEnum }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzApplications as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzAuthorizationStore_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, pbstrdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "Description", 7usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).Description)(this, pbstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "SetDescription", 8usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).SetDescription)(this, bstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplicationData(this: *mut core::ffi::c_void, pbstrapplicationdata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "ApplicationData", 9usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).ApplicationData)(this, pbstrapplicationdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetApplicationData(this: *mut core::ffi::c_void, bstrapplicationdata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "SetApplicationData", 10usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).SetApplicationData)(this, bstrapplicationdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn DomainTimeout(this: *mut core::ffi::c_void, plprop: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "DomainTimeout", 11usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).DomainTimeout)(this, plprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDomainTimeout(this: *mut core::ffi::c_void, lprop: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "SetDomainTimeout", 12usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).SetDomainTimeout)(this, lprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScriptEngineTimeout(this: *mut core::ffi::c_void, plprop: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "ScriptEngineTimeout", 13usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).ScriptEngineTimeout)(this, plprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScriptEngineTimeout(this: *mut core::ffi::c_void, lprop: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "SetScriptEngineTimeout", 14usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).SetScriptEngineTimeout)(this, lprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxScriptEngines(this: *mut core::ffi::c_void, plprop: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "MaxScriptEngines", 15usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).MaxScriptEngines)(this, plprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxScriptEngines(this: *mut core::ffi::c_void, lprop: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "SetMaxScriptEngines", 16usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).SetMaxScriptEngines)(this, lprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GenerateAudits(this: *mut core::ffi::c_void, pbprop: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "GenerateAudits", 17usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).GenerateAudits)(this, pbprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGenerateAudits(this: *mut core::ffi::c_void, bprop: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "SetGenerateAudits", 18usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).SetGenerateAudits)(this, bprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn Writable(this: *mut core::ffi::c_void, pfprop: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "Writable", 19usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).Writable)(this, pfprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, lpropid: i32, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, pvarprop: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "GetProperty", 20usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).GetProperty)(this, lpropid, varreserved, pvarprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, lpropid: i32, varprop: core::mem::MaybeUninit<windows_core::VARIANT>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "SetProperty", 21usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).SetProperty)(this, lpropid, varprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPropertyItem(this: *mut core::ffi::c_void, lpropid: AZ_PROP_CONSTANTS, varprop: core::mem::MaybeUninit<windows_core::VARIANT>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "AddPropertyItem", 22usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).AddPropertyItem)(this, lpropid, varprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeletePropertyItem(this: *mut core::ffi::c_void, lpropid: i32, varprop: core::mem::MaybeUninit<windows_core::VARIANT>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "DeletePropertyItem", 23usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).DeletePropertyItem)(this, lpropid, varprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn PolicyAdministrators(this: *mut core::ffi::c_void, pvaradmins: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "PolicyAdministrators", 24usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).PolicyAdministrators)(this, pvaradmins), |result| Some(*result))
+        }
+        unsafe extern "system" fn PolicyReaders(this: *mut core::ffi::c_void, pvarreaders: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "PolicyReaders", 25usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).PolicyReaders)(this, pvarreaders), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPolicyAdministrator(this: *mut core::ffi::c_void, bstradmin: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "AddPolicyAdministrator", 26usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).AddPolicyAdministrator)(this, bstradmin, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeletePolicyAdministrator(this: *mut core::ffi::c_void, bstradmin: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "DeletePolicyAdministrator", 27usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).DeletePolicyAdministrator)(this, bstradmin, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPolicyReader(this: *mut core::ffi::c_void, bstrreader: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "AddPolicyReader", 28usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).AddPolicyReader)(this, bstrreader, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeletePolicyReader(this: *mut core::ffi::c_void, bstrreader: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "DeletePolicyReader", 29usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).DeletePolicyReader)(this, bstrreader, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, lflags: AZ_PROP_CONSTANTS, bstrpolicyurl: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "Initialize", 30usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).Initialize)(this, lflags, bstrpolicyurl, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateCache(this: *mut core::ffi::c_void, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "UpdateCache", 31usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).UpdateCache)(this, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "Delete", 32usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).Delete)(this, varreserved), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Applications(this: *mut core::ffi::c_void, ppappcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "Applications", 33usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).Applications)(this, ppappcollection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OpenApplication(this: *mut core::ffi::c_void, bstrapplicationname: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, ppapplication: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "OpenApplication", 34usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).OpenApplication)(this, bstrapplicationname, varreserved, ppapplication), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateApplication(this: *mut core::ffi::c_void, bstrapplicationname: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, ppapplication: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "CreateApplication", 35usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).CreateApplication)(this, bstrapplicationname, varreserved, ppapplication), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteApplication(this: *mut core::ffi::c_void, bstrapplicationname: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "DeleteApplication", 36usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).DeleteApplication)(this, bstrapplicationname, varreserved), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ApplicationGroups(this: *mut core::ffi::c_void, ppgroupcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "ApplicationGroups", 37usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).ApplicationGroups)(this, ppgroupcollection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateApplicationGroup(this: *mut core::ffi::c_void, bstrgroupname: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, ppgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "CreateApplicationGroup", 38usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).CreateApplicationGroup)(this, bstrgroupname, varreserved, ppgroup), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OpenApplicationGroup(this: *mut core::ffi::c_void, bstrgroupname: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, ppgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "OpenApplicationGroup", 39usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).OpenApplicationGroup)(this, bstrgroupname, varreserved, ppgroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteApplicationGroup(this: *mut core::ffi::c_void, bstrgroupname: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "DeleteApplicationGroup", 40usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).DeleteApplicationGroup)(this, bstrgroupname, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn Submit(this: *mut core::ffi::c_void, lflags: i32, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "Submit", 41usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).Submit)(this, lflags, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DelegatedPolicyUsers(this: *mut core::ffi::c_void, pvardelegatedpolicyusers: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "DelegatedPolicyUsers", 42usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).DelegatedPolicyUsers)(this, pvardelegatedpolicyusers), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDelegatedPolicyUser(this: *mut core::ffi::c_void, bstrdelegatedpolicyuser: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "AddDelegatedPolicyUser", 43usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).AddDelegatedPolicyUser)(this, bstrdelegatedpolicyuser, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteDelegatedPolicyUser(this: *mut core::ffi::c_void, bstrdelegatedpolicyuser: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "DeleteDelegatedPolicyUser", 44usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).DeleteDelegatedPolicyUser)(this, bstrdelegatedpolicyuser, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn TargetMachine(this: *mut core::ffi::c_void, pbstrtargetmachine: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "TargetMachine", 45usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).TargetMachine)(this, pbstrtargetmachine), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplyStoreSacl(this: *mut core::ffi::c_void, pbapplystoresacl: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "ApplyStoreSacl", 46usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).ApplyStoreSacl)(this, pbapplystoresacl), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetApplyStoreSacl(this: *mut core::ffi::c_void, bapplystoresacl: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "SetApplyStoreSacl", 47usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).SetApplyStoreSacl)(this, bapplystoresacl), |result| Some(*result))
+        }
+        unsafe extern "system" fn PolicyAdministratorsName(this: *mut core::ffi::c_void, pvaradmins: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "PolicyAdministratorsName", 48usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).PolicyAdministratorsName)(this, pvaradmins), |result| Some(*result))
+        }
+        unsafe extern "system" fn PolicyReadersName(this: *mut core::ffi::c_void, pvarreaders: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "PolicyReadersName", 49usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).PolicyReadersName)(this, pvarreaders), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPolicyAdministratorName(this: *mut core::ffi::c_void, bstradmin: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "AddPolicyAdministratorName", 50usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).AddPolicyAdministratorName)(this, bstradmin, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeletePolicyAdministratorName(this: *mut core::ffi::c_void, bstradmin: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "DeletePolicyAdministratorName", 51usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).DeletePolicyAdministratorName)(this, bstradmin, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPolicyReaderName(this: *mut core::ffi::c_void, bstrreader: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "AddPolicyReaderName", 52usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).AddPolicyReaderName)(this, bstrreader, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeletePolicyReaderName(this: *mut core::ffi::c_void, bstrreader: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "DeletePolicyReaderName", 53usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).DeletePolicyReaderName)(this, bstrreader, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DelegatedPolicyUsersName(this: *mut core::ffi::c_void, pvardelegatedpolicyusers: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "DelegatedPolicyUsersName", 54usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).DelegatedPolicyUsersName)(this, pvardelegatedpolicyusers), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDelegatedPolicyUserName(this: *mut core::ffi::c_void, bstrdelegatedpolicyuser: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "AddDelegatedPolicyUserName", 55usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).AddDelegatedPolicyUserName)(this, bstrdelegatedpolicyuser, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteDelegatedPolicyUserName(this: *mut core::ffi::c_void, bstrdelegatedpolicyuser: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "DeleteDelegatedPolicyUserName", 56usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).DeleteDelegatedPolicyUserName)(this, bstrdelegatedpolicyuser, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseApplication(this: *mut core::ffi::c_void, bstrapplicationname: core::mem::MaybeUninit<windows_core::BSTR>, lflag: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore", "CloseApplication", 57usize, |this| ((**(this as *const *const IAzAuthorizationStore_Vtbl)).CloseApplication)(this, bstrapplicationname, lflag), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Description,
+            SetDescription,
+            ApplicationData,
+            SetApplicationData,
+            DomainTimeout,
+            SetDomainTimeout,
+            ScriptEngineTimeout,
+            SetScriptEngineTimeout,
+            MaxScriptEngines,
+            SetMaxScriptEngines,
+            GenerateAudits,
+            SetGenerateAudits,
+            Writable,
+            GetProperty,
+            SetProperty,
+            AddPropertyItem,
+            DeletePropertyItem,
+            PolicyAdministrators,
+            PolicyReaders,
+            AddPolicyAdministrator,
+            DeletePolicyAdministrator,
+            AddPolicyReader,
+            DeletePolicyReader,
+            Initialize,
+            UpdateCache,
+            Delete,
+            #[cfg(feature = "Win32_System_Com")]
+            Applications,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Applications: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OpenApplication,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OpenApplication: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateApplication,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateApplication: 0,
+            DeleteApplication,
+            #[cfg(feature = "Win32_System_Com")]
+            ApplicationGroups,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ApplicationGroups: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateApplicationGroup,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateApplicationGroup: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OpenApplicationGroup,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OpenApplicationGroup: 0,
+            DeleteApplicationGroup,
+            Submit,
+            DelegatedPolicyUsers,
+            AddDelegatedPolicyUser,
+            DeleteDelegatedPolicyUser,
+            TargetMachine,
+            ApplyStoreSacl,
+            SetApplyStoreSacl,
+            PolicyAdministratorsName,
+            PolicyReadersName,
+            AddPolicyAdministratorName,
+            DeletePolicyAdministratorName,
+            AddPolicyReaderName,
+            DeletePolicyReaderName,
+            DelegatedPolicyUsersName,
+            AddDelegatedPolicyUserName,
+            DeleteDelegatedPolicyUserName,
+            CloseApplication,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzAuthorizationStore as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzAuthorizationStore2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OpenApplication2(this: *mut core::ffi::c_void, bstrapplicationname: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, ppapplication: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore2", "OpenApplication2", 58usize, |this| ((**(this as *const *const IAzAuthorizationStore2_Vtbl)).OpenApplication2)(this, bstrapplicationname, varreserved, ppapplication), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateApplication2(this: *mut core::ffi::c_void, bstrapplicationname: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, ppapplication: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore2", "CreateApplication2", 59usize, |this| ((**(this as *const *const IAzAuthorizationStore2_Vtbl)).CreateApplication2)(this, bstrapplicationname, varreserved, ppapplication), |result| Some(*result))
+        }
+        Self {
+            base__: <IAzAuthorizationStore_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            OpenApplication2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OpenApplication2: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateApplication2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateApplication2: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzAuthorizationStore2 as windows_core::Interface>::IID || <IAzAuthorizationStore_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzAuthorizationStore3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsUpdateNeeded(this: *mut core::ffi::c_void, pbisupdateneeded: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore3", "IsUpdateNeeded", 60usize, |this| ((**(this as *const *const IAzAuthorizationStore3_Vtbl)).IsUpdateNeeded)(this, pbisupdateneeded), |result| Some(*result))
+        }
+        unsafe extern "system" fn BizruleGroupSupported(this: *mut core::ffi::c_void, pbsupported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore3", "BizruleGroupSupported", 61usize, |this| ((**(this as *const *const IAzAuthorizationStore3_Vtbl)).BizruleGroupSupported)(this, pbsupported), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpgradeStoresFunctionalLevel(this: *mut core::ffi::c_void, lfunctionallevel: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore3", "UpgradeStoresFunctionalLevel", 62usize, |this| ((**(this as *const *const IAzAuthorizationStore3_Vtbl)).UpgradeStoresFunctionalLevel)(this, lfunctionallevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFunctionalLevelUpgradeSupported(this: *mut core::ffi::c_void, lfunctionallevel: i32, pbsupported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore3", "IsFunctionalLevelUpgradeSupported", 63usize, |this| ((**(this as *const *const IAzAuthorizationStore3_Vtbl)).IsFunctionalLevelUpgradeSupported)(this, lfunctionallevel, pbsupported), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSchemaVersion(this: *mut core::ffi::c_void, plmajorversion: *mut i32, plminorversion: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzAuthorizationStore3", "GetSchemaVersion", 64usize, |this| ((**(this as *const *const IAzAuthorizationStore3_Vtbl)).GetSchemaVersion)(this, plmajorversion, plminorversion), |result| Some(*result))
+        }
+        Self {
+            base__: <IAzAuthorizationStore2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsUpdateNeeded,
+            BizruleGroupSupported,
+            UpgradeStoresFunctionalLevel,
+            IsFunctionalLevelUpgradeSupported,
+            GetSchemaVersion,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzAuthorizationStore3 as windows_core::Interface>::IID || <IAzAuthorizationStore2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzBizRuleContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetBusinessRuleResult(this: *mut core::ffi::c_void, bresult: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzBizRuleContext", "SetBusinessRuleResult", 7usize, |this| ((**(this as *const *const IAzBizRuleContext_Vtbl)).SetBusinessRuleResult)(this, bresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBusinessRuleString(this: *mut core::ffi::c_void, bstrbusinessrulestring: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzBizRuleContext", "SetBusinessRuleString", 8usize, |this| ((**(this as *const *const IAzBizRuleContext_Vtbl)).SetBusinessRuleString)(this, bstrbusinessrulestring), |result| Some(*result))
+        }
+        unsafe extern "system" fn BusinessRuleString(this: *mut core::ffi::c_void, pbstrbusinessrulestring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzBizRuleContext", "BusinessRuleString", 9usize, |this| ((**(this as *const *const IAzBizRuleContext_Vtbl)).BusinessRuleString)(this, pbstrbusinessrulestring), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParameter(this: *mut core::ffi::c_void, bstrparametername: core::mem::MaybeUninit<windows_core::BSTR>, pvarparametervalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzBizRuleContext", "GetParameter", 10usize, |this| ((**(this as *const *const IAzBizRuleContext_Vtbl)).GetParameter)(this, bstrparametername, pvarparametervalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetBusinessRuleResult,
+            SetBusinessRuleString,
+            BusinessRuleString,
+            GetParameter,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzBizRuleContext as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzBizRuleInterfaces_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddInterface(this: *mut core::ffi::c_void, bstrinterfacename: core::mem::MaybeUninit<windows_core::BSTR>, linterfaceflag: i32, varinterface: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzBizRuleInterfaces", "AddInterface", 7usize, |this| ((**(this as *const *const IAzBizRuleInterfaces_Vtbl)).AddInterface)(this, bstrinterfacename, linterfaceflag, varinterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddInterfaces(this: *mut core::ffi::c_void, varinterfacenames: core::mem::MaybeUninit<windows_core::VARIANT>, varinterfaceflags: core::mem::MaybeUninit<windows_core::VARIANT>, varinterfaces: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzBizRuleInterfaces", "AddInterfaces", 8usize, |this| ((**(this as *const *const IAzBizRuleInterfaces_Vtbl)).AddInterfaces)(this, varinterfacenames, varinterfaceflags, varinterfaces), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInterfaceValue(this: *mut core::ffi::c_void, bstrinterfacename: core::mem::MaybeUninit<windows_core::BSTR>, linterfaceflag: *mut i32, varinterface: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzBizRuleInterfaces", "GetInterfaceValue", 9usize, |this| ((**(this as *const *const IAzBizRuleInterfaces_Vtbl)).GetInterfaceValue)(this, bstrinterfacename, linterfaceflag, varinterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, bstrinterfacename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzBizRuleInterfaces", "Remove", 10usize, |this| ((**(this as *const *const IAzBizRuleInterfaces_Vtbl)).Remove)(this, bstrinterfacename), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzBizRuleInterfaces", "RemoveAll", 11usize, |this| ((**(this as *const *const IAzBizRuleInterfaces_Vtbl)).RemoveAll)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzBizRuleInterfaces", "Count", 12usize, |this| ((**(this as *const *const IAzBizRuleInterfaces_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddInterface,
+            AddInterfaces,
+            GetInterfaceValue,
+            Remove,
+            RemoveAll,
+            Count,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzBizRuleInterfaces as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzBizRuleParameters_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddParameter(this: *mut core::ffi::c_void, bstrparametername: core::mem::MaybeUninit<windows_core::BSTR>, varparametervalue: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzBizRuleParameters", "AddParameter", 7usize, |this| ((**(this as *const *const IAzBizRuleParameters_Vtbl)).AddParameter)(this, bstrparametername, varparametervalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddParameters(this: *mut core::ffi::c_void, varparameternames: core::mem::MaybeUninit<windows_core::VARIANT>, varparametervalues: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzBizRuleParameters", "AddParameters", 8usize, |this| ((**(this as *const *const IAzBizRuleParameters_Vtbl)).AddParameters)(this, varparameternames, varparametervalues), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParameterValue(this: *mut core::ffi::c_void, bstrparametername: core::mem::MaybeUninit<windows_core::BSTR>, pvarparametervalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzBizRuleParameters", "GetParameterValue", 9usize, |this| ((**(this as *const *const IAzBizRuleParameters_Vtbl)).GetParameterValue)(this, bstrparametername, pvarparametervalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, varparametername: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzBizRuleParameters", "Remove", 10usize, |this| ((**(this as *const *const IAzBizRuleParameters_Vtbl)).Remove)(this, varparametername), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzBizRuleParameters", "RemoveAll", 11usize, |this| ((**(this as *const *const IAzBizRuleParameters_Vtbl)).RemoveAll)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzBizRuleParameters", "Count", 12usize, |this| ((**(this as *const *const IAzBizRuleParameters_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddParameter,
+            AddParameters,
+            GetParameterValue,
+            Remove,
+            RemoveAll,
+            Count,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzBizRuleParameters as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzClientContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AccessCheck(this: *mut core::ffi::c_void, bstrobjectname: core::mem::MaybeUninit<windows_core::BSTR>, varscopenames: core::mem::MaybeUninit<windows_core::VARIANT>, varoperations: core::mem::MaybeUninit<windows_core::VARIANT>, varparameternames: core::mem::MaybeUninit<windows_core::VARIANT>, varparametervalues: core::mem::MaybeUninit<windows_core::VARIANT>, varinterfacenames: core::mem::MaybeUninit<windows_core::VARIANT>, varinterfaceflags: core::mem::MaybeUninit<windows_core::VARIANT>, varinterfaces: core::mem::MaybeUninit<windows_core::VARIANT>, pvarresults: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzClientContext", "AccessCheck", 7usize, |this| ((**(this as *const *const IAzClientContext_Vtbl)).AccessCheck)(this, bstrobjectname, varscopenames, varoperations, varparameternames, varparametervalues, varinterfacenames, varinterfaceflags, varinterfaces, pvarresults), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBusinessRuleString(this: *mut core::ffi::c_void, pbstrbusinessrulestring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzClientContext", "GetBusinessRuleString", 8usize, |this| ((**(this as *const *const IAzClientContext_Vtbl)).GetBusinessRuleString)(this, pbstrbusinessrulestring), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserDn(this: *mut core::ffi::c_void, pbstrprop: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzClientContext", "UserDn", 9usize, |this| ((**(this as *const *const IAzClientContext_Vtbl)).UserDn)(this, pbstrprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserSamCompat(this: *mut core::ffi::c_void, pbstrprop: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzClientContext", "UserSamCompat", 10usize, |this| ((**(this as *const *const IAzClientContext_Vtbl)).UserSamCompat)(this, pbstrprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserDisplay(this: *mut core::ffi::c_void, pbstrprop: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzClientContext", "UserDisplay", 11usize, |this| ((**(this as *const *const IAzClientContext_Vtbl)).UserDisplay)(this, pbstrprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserGuid(this: *mut core::ffi::c_void, pbstrprop: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzClientContext", "UserGuid", 12usize, |this| ((**(this as *const *const IAzClientContext_Vtbl)).UserGuid)(this, pbstrprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserCanonical(this: *mut core::ffi::c_void, pbstrprop: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzClientContext", "UserCanonical", 13usize, |this| ((**(this as *const *const IAzClientContext_Vtbl)).UserCanonical)(this, pbstrprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserUpn(this: *mut core::ffi::c_void, pbstrprop: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzClientContext", "UserUpn", 14usize, |this| ((**(this as *const *const IAzClientContext_Vtbl)).UserUpn)(this, pbstrprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserDnsSamCompat(this: *mut core::ffi::c_void, pbstrprop: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzClientContext", "UserDnsSamCompat", 15usize, |this| ((**(this as *const *const IAzClientContext_Vtbl)).UserDnsSamCompat)(this, pbstrprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, lpropid: i32, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, pvarprop: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzClientContext", "GetProperty", 16usize, |this| ((**(this as *const *const IAzClientContext_Vtbl)).GetProperty)(this, lpropid, varreserved, pvarprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRoles(this: *mut core::ffi::c_void, bstrscopename: core::mem::MaybeUninit<windows_core::BSTR>, pvarrolenames: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzClientContext", "GetRoles", 17usize, |this| ((**(this as *const *const IAzClientContext_Vtbl)).GetRoles)(this, bstrscopename, pvarrolenames), |result| Some(*result))
+        }
+        unsafe extern "system" fn RoleForAccessCheck(this: *mut core::ffi::c_void, pbstrprop: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzClientContext", "RoleForAccessCheck", 18usize, |this| ((**(this as *const *const IAzClientContext_Vtbl)).RoleForAccessCheck)(this, pbstrprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRoleForAccessCheck(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzClientContext", "SetRoleForAccessCheck", 19usize, |this| ((**(this as *const *const IAzClientContext_Vtbl)).SetRoleForAccessCheck)(this, bstrprop), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AccessCheck,
+            GetBusinessRuleString,
+            UserDn,
+            UserSamCompat,
+            UserDisplay,
+            UserGuid,
+            UserCanonical,
+            UserUpn,
+            UserDnsSamCompat,
+            GetProperty,
+            GetRoles,
+            RoleForAccessCheck,
+            SetRoleForAccessCheck,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzClientContext as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzClientContext2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAssignedScopesPage(this: *mut core::ffi::c_void, loptions: i32, pagesize: i32, pvarcursor: *mut core::mem::MaybeUninit<windows_core::VARIANT>, pvarscopenames: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzClientContext2", "GetAssignedScopesPage", 20usize, |this| ((**(this as *const *const IAzClientContext2_Vtbl)).GetAssignedScopesPage)(this, loptions, pagesize, pvarcursor, pvarscopenames), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddRoles(this: *mut core::ffi::c_void, varroles: core::mem::MaybeUninit<windows_core::VARIANT>, bstrscopename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzClientContext2", "AddRoles", 21usize, |this| ((**(this as *const *const IAzClientContext2_Vtbl)).AddRoles)(this, varroles, bstrscopename), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddApplicationGroups(this: *mut core::ffi::c_void, varapplicationgroups: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzClientContext2", "AddApplicationGroups", 22usize, |this| ((**(this as *const *const IAzClientContext2_Vtbl)).AddApplicationGroups)(this, varapplicationgroups), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddStringSids(this: *mut core::ffi::c_void, varstringsids: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzClientContext2", "AddStringSids", 23usize, |this| ((**(this as *const *const IAzClientContext2_Vtbl)).AddStringSids)(this, varstringsids), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLDAPQueryDN(this: *mut core::ffi::c_void, bstrldapquerydn: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzClientContext2", "SetLDAPQueryDN", 24usize, |this| ((**(this as *const *const IAzClientContext2_Vtbl)).SetLDAPQueryDN)(this, bstrldapquerydn), |result| Some(*result))
+        }
+        unsafe extern "system" fn LDAPQueryDN(this: *mut core::ffi::c_void, pbstrldapquerydn: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzClientContext2", "LDAPQueryDN", 25usize, |this| ((**(this as *const *const IAzClientContext2_Vtbl)).LDAPQueryDN)(this, pbstrldapquerydn), |result| Some(*result))
+        }
+        Self {
+            base__: <IAzClientContext_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetAssignedScopesPage,
+            AddRoles,
+            AddApplicationGroups,
+            AddStringSids,
+            SetLDAPQueryDN,
+            LDAPQueryDN,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzClientContext2 as windows_core::Interface>::IID || <IAzClientContext_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzClientContext3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AccessCheck2(this: *mut core::ffi::c_void, bstrobjectname: core::mem::MaybeUninit<windows_core::BSTR>, bstrscopename: core::mem::MaybeUninit<windows_core::BSTR>, loperation: i32, plresult: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzClientContext3", "AccessCheck2", 26usize, |this| ((**(this as *const *const IAzClientContext3_Vtbl)).AccessCheck2)(this, bstrobjectname, bstrscopename, loperation, plresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInRoleAssignment(this: *mut core::ffi::c_void, bstrscopename: core::mem::MaybeUninit<windows_core::BSTR>, bstrrolename: core::mem::MaybeUninit<windows_core::BSTR>, pbisinrole: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzClientContext3", "IsInRoleAssignment", 27usize, |this| ((**(this as *const *const IAzClientContext3_Vtbl)).IsInRoleAssignment)(this, bstrscopename, bstrrolename, pbisinrole), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetOperations(this: *mut core::ffi::c_void, bstrscopename: core::mem::MaybeUninit<windows_core::BSTR>, ppoperationcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzClientContext3", "GetOperations", 28usize, |this| ((**(this as *const *const IAzClientContext3_Vtbl)).GetOperations)(this, bstrscopename, ppoperationcollection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetTasks(this: *mut core::ffi::c_void, bstrscopename: core::mem::MaybeUninit<windows_core::BSTR>, pptaskcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzClientContext3", "GetTasks", 29usize, |this| ((**(this as *const *const IAzClientContext3_Vtbl)).GetTasks)(this, bstrscopename, pptaskcollection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn BizRuleParameters(this: *mut core::ffi::c_void, ppbizruleparam: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzClientContext3", "BizRuleParameters", 30usize, |this| ((**(this as *const *const IAzClientContext3_Vtbl)).BizRuleParameters)(this, ppbizruleparam), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn BizRuleInterfaces(this: *mut core::ffi::c_void, ppbizruleinterfaces: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzClientContext3", "BizRuleInterfaces", 31usize, |this| ((**(this as *const *const IAzClientContext3_Vtbl)).BizRuleInterfaces)(this, ppbizruleinterfaces), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGroups(this: *mut core::ffi::c_void, bstrscopename: core::mem::MaybeUninit<windows_core::BSTR>, uloptions: u32, pgrouparray: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzClientContext3", "GetGroups", 32usize, |this| ((**(this as *const *const IAzClientContext3_Vtbl)).GetGroups)(this, bstrscopename, uloptions, pgrouparray), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sids(this: *mut core::ffi::c_void, pstringsidarray: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzClientContext3", "Sids", 33usize, |this| ((**(this as *const *const IAzClientContext3_Vtbl)).Sids)(this, pstringsidarray), |result| Some(*result))
+        }
+        Self {
+            base__: <IAzClientContext2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AccessCheck2,
+            IsInRoleAssignment,
+            #[cfg(feature = "Win32_System_Com")]
+            GetOperations,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetOperations: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetTasks,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetTasks: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            BizRuleParameters,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            BizRuleParameters: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            BizRuleInterfaces,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            BizRuleInterfaces: 0,
+            GetGroups,
+            Sids,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzClientContext3 as windows_core::Interface>::IID || <IAzClientContext2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzNameResolver_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NameFromSid(this: *mut core::ffi::c_void, bstrsid: core::mem::MaybeUninit<windows_core::BSTR>, psidtype: *mut i32, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzNameResolver", "NameFromSid", 7usize, |this| ((**(this as *const *const IAzNameResolver_Vtbl)).NameFromSid)(this, bstrsid, psidtype, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn NamesFromSids(this: *mut core::ffi::c_void, vsids: core::mem::MaybeUninit<windows_core::VARIANT>, pvsidtypes: *mut core::mem::MaybeUninit<windows_core::VARIANT>, pvnames: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzNameResolver", "NamesFromSids", 8usize, |this| ((**(this as *const *const IAzNameResolver_Vtbl)).NamesFromSids)(this, vsids, pvsidtypes, pvnames), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NameFromSid, NamesFromSids }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzNameResolver as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzObjectPicker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPrincipals(this: *mut core::ffi::c_void, hparentwnd: super::super::Foundation::HWND, bstrtitle: core::mem::MaybeUninit<windows_core::BSTR>, pvsidtypes: *mut core::mem::MaybeUninit<windows_core::VARIANT>, pvnames: *mut core::mem::MaybeUninit<windows_core::VARIANT>, pvsids: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzObjectPicker", "GetPrincipals", 7usize, |this| ((**(this as *const *const IAzObjectPicker_Vtbl)).GetPrincipals)(this, hparentwnd, bstrtitle, pvsidtypes, pvnames, pvsids), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzObjectPicker", "Name", 8usize, |this| ((**(this as *const *const IAzObjectPicker_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPrincipals, Name }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzObjectPicker as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzOperation", "Name", 7usize, |this| ((**(this as *const *const IAzOperation_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzOperation", "SetName", 8usize, |this| ((**(this as *const *const IAzOperation_Vtbl)).SetName)(this, bstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, pbstrdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzOperation", "Description", 9usize, |this| ((**(this as *const *const IAzOperation_Vtbl)).Description)(this, pbstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzOperation", "SetDescription", 10usize, |this| ((**(this as *const *const IAzOperation_Vtbl)).SetDescription)(this, bstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplicationData(this: *mut core::ffi::c_void, pbstrapplicationdata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzOperation", "ApplicationData", 11usize, |this| ((**(this as *const *const IAzOperation_Vtbl)).ApplicationData)(this, pbstrapplicationdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetApplicationData(this: *mut core::ffi::c_void, bstrapplicationdata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzOperation", "SetApplicationData", 12usize, |this| ((**(this as *const *const IAzOperation_Vtbl)).SetApplicationData)(this, bstrapplicationdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn OperationID(this: *mut core::ffi::c_void, plprop: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzOperation", "OperationID", 13usize, |this| ((**(this as *const *const IAzOperation_Vtbl)).OperationID)(this, plprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOperationID(this: *mut core::ffi::c_void, lprop: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzOperation", "SetOperationID", 14usize, |this| ((**(this as *const *const IAzOperation_Vtbl)).SetOperationID)(this, lprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn Writable(this: *mut core::ffi::c_void, pfprop: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzOperation", "Writable", 15usize, |this| ((**(this as *const *const IAzOperation_Vtbl)).Writable)(this, pfprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, lpropid: i32, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, pvarprop: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzOperation", "GetProperty", 16usize, |this| ((**(this as *const *const IAzOperation_Vtbl)).GetProperty)(this, lpropid, varreserved, pvarprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, lpropid: i32, varprop: core::mem::MaybeUninit<windows_core::VARIANT>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzOperation", "SetProperty", 17usize, |this| ((**(this as *const *const IAzOperation_Vtbl)).SetProperty)(this, lpropid, varprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn Submit(this: *mut core::ffi::c_void, lflags: i32, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzOperation", "Submit", 18usize, |this| ((**(this as *const *const IAzOperation_Vtbl)).Submit)(this, lflags, varreserved), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            SetName,
+            Description,
+            SetDescription,
+            ApplicationData,
+            SetApplicationData,
+            OperationID,
+            SetOperationID,
+            Writable,
+            GetProperty,
+            SetProperty,
+            Submit,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzOperation as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzOperation2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RoleAssignments(this: *mut core::ffi::c_void, bstrscopename: core::mem::MaybeUninit<windows_core::BSTR>, brecursive: super::super::Foundation::VARIANT_BOOL, pproleassignments: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzOperation2", "RoleAssignments", 19usize, |this| ((**(this as *const *const IAzOperation2_Vtbl)).RoleAssignments)(this, bstrscopename, brecursive, pproleassignments), |result| Some(*result))
+        }
+        Self {
+            base__: <IAzOperation_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            RoleAssignments,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RoleAssignments: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzOperation2 as windows_core::Interface>::IID || <IAzOperation_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzOperations_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, pvarobtptr: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzOperations", "get_Item", 7usize, |this| ((**(this as *const *const IAzOperations_Vtbl)).get_Item)(this, index, pvarobtptr), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzOperations", "Count", 8usize, |this| ((**(this as *const *const IAzOperations_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppenumptr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzOperations", "_NewEnum", 9usize, |this| ((**(this as *const *const IAzOperations_Vtbl))._NewEnum)(this, ppenumptr), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, get_Item, Count, _NewEnum }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzOperations as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzPrincipalLocator_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn NameResolver(this: *mut core::ffi::c_void, ppnameresolver: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzPrincipalLocator", "NameResolver", 7usize, |this| ((**(this as *const *const IAzPrincipalLocator_Vtbl)).NameResolver)(this, ppnameresolver), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ObjectPicker(this: *mut core::ffi::c_void, ppobjectpicker: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzPrincipalLocator", "ObjectPicker", 8usize, |this| ((**(this as *const *const IAzPrincipalLocator_Vtbl)).ObjectPicker)(this, ppobjectpicker), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            NameResolver,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            NameResolver: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ObjectPicker,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ObjectPicker: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzPrincipalLocator as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzRole_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRole", "Name", 7usize, |this| ((**(this as *const *const IAzRole_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRole", "SetName", 8usize, |this| ((**(this as *const *const IAzRole_Vtbl)).SetName)(this, bstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, pbstrdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRole", "Description", 9usize, |this| ((**(this as *const *const IAzRole_Vtbl)).Description)(this, pbstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRole", "SetDescription", 10usize, |this| ((**(this as *const *const IAzRole_Vtbl)).SetDescription)(this, bstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplicationData(this: *mut core::ffi::c_void, pbstrapplicationdata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRole", "ApplicationData", 11usize, |this| ((**(this as *const *const IAzRole_Vtbl)).ApplicationData)(this, pbstrapplicationdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetApplicationData(this: *mut core::ffi::c_void, bstrapplicationdata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRole", "SetApplicationData", 12usize, |this| ((**(this as *const *const IAzRole_Vtbl)).SetApplicationData)(this, bstrapplicationdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddAppMember(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRole", "AddAppMember", 13usize, |this| ((**(this as *const *const IAzRole_Vtbl)).AddAppMember)(this, bstrprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAppMember(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRole", "DeleteAppMember", 14usize, |this| ((**(this as *const *const IAzRole_Vtbl)).DeleteAppMember)(this, bstrprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTask(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRole", "AddTask", 15usize, |this| ((**(this as *const *const IAzRole_Vtbl)).AddTask)(this, bstrprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteTask(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRole", "DeleteTask", 16usize, |this| ((**(this as *const *const IAzRole_Vtbl)).DeleteTask)(this, bstrprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddOperation(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRole", "AddOperation", 17usize, |this| ((**(this as *const *const IAzRole_Vtbl)).AddOperation)(this, bstrprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteOperation(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRole", "DeleteOperation", 18usize, |this| ((**(this as *const *const IAzRole_Vtbl)).DeleteOperation)(this, bstrprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddMember(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRole", "AddMember", 19usize, |this| ((**(this as *const *const IAzRole_Vtbl)).AddMember)(this, bstrprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteMember(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRole", "DeleteMember", 20usize, |this| ((**(this as *const *const IAzRole_Vtbl)).DeleteMember)(this, bstrprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn Writable(this: *mut core::ffi::c_void, pfprop: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRole", "Writable", 21usize, |this| ((**(this as *const *const IAzRole_Vtbl)).Writable)(this, pfprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, lpropid: i32, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, pvarprop: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRole", "GetProperty", 22usize, |this| ((**(this as *const *const IAzRole_Vtbl)).GetProperty)(this, lpropid, varreserved, pvarprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, lpropid: i32, varprop: core::mem::MaybeUninit<windows_core::VARIANT>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRole", "SetProperty", 23usize, |this| ((**(this as *const *const IAzRole_Vtbl)).SetProperty)(this, lpropid, varprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppMembers(this: *mut core::ffi::c_void, pvarprop: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRole", "AppMembers", 24usize, |this| ((**(this as *const *const IAzRole_Vtbl)).AppMembers)(this, pvarprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn Members(this: *mut core::ffi::c_void, pvarprop: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRole", "Members", 25usize, |this| ((**(this as *const *const IAzRole_Vtbl)).Members)(this, pvarprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn Operations(this: *mut core::ffi::c_void, pvarprop: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRole", "Operations", 26usize, |this| ((**(this as *const *const IAzRole_Vtbl)).Operations)(this, pvarprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn Tasks(this: *mut core::ffi::c_void, pvarprop: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRole", "Tasks", 27usize, |this| ((**(this as *const *const IAzRole_Vtbl)).Tasks)(this, pvarprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPropertyItem(this: *mut core::ffi::c_void, lpropid: i32, varprop: core::mem::MaybeUninit<windows_core::VARIANT>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRole", "AddPropertyItem", 28usize, |this| ((**(this as *const *const IAzRole_Vtbl)).AddPropertyItem)(this, lpropid, varprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeletePropertyItem(this: *mut core::ffi::c_void, lpropid: i32, varprop: core::mem::MaybeUninit<windows_core::VARIANT>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRole", "DeletePropertyItem", 29usize, |this| ((**(this as *const *const IAzRole_Vtbl)).DeletePropertyItem)(this, lpropid, varprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn Submit(this: *mut core::ffi::c_void, lflags: i32, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRole", "Submit", 30usize, |this| ((**(this as *const *const IAzRole_Vtbl)).Submit)(this, lflags, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddMemberName(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRole", "AddMemberName", 31usize, |this| ((**(this as *const *const IAzRole_Vtbl)).AddMemberName)(this, bstrprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteMemberName(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRole", "DeleteMemberName", 32usize, |this| ((**(this as *const *const IAzRole_Vtbl)).DeleteMemberName)(this, bstrprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn MembersName(this: *mut core::ffi::c_void, pvarprop: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRole", "MembersName", 33usize, |this| ((**(this as *const *const IAzRole_Vtbl)).MembersName)(this, pvarprop), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            SetName,
+            Description,
+            SetDescription,
+            ApplicationData,
+            SetApplicationData,
+            AddAppMember,
+            DeleteAppMember,
+            AddTask,
+            DeleteTask,
+            AddOperation,
+            DeleteOperation,
+            AddMember,
+            DeleteMember,
+            Writable,
+            GetProperty,
+            SetProperty,
+            AppMembers,
+            Members,
+            Operations,
+            Tasks,
+            AddPropertyItem,
+            DeletePropertyItem,
+            Submit,
+            AddMemberName,
+            DeleteMemberName,
+            MembersName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzRole as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzRoleAssignment_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddRoleDefinition(this: *mut core::ffi::c_void, bstrroledefinition: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRoleAssignment", "AddRoleDefinition", 34usize, |this| ((**(this as *const *const IAzRoleAssignment_Vtbl)).AddRoleDefinition)(this, bstrroledefinition), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteRoleDefinition(this: *mut core::ffi::c_void, bstrroledefinition: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRoleAssignment", "DeleteRoleDefinition", 35usize, |this| ((**(this as *const *const IAzRoleAssignment_Vtbl)).DeleteRoleDefinition)(this, bstrroledefinition), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RoleDefinitions(this: *mut core::ffi::c_void, pproledefinitions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRoleAssignment", "RoleDefinitions", 36usize, |this| ((**(this as *const *const IAzRoleAssignment_Vtbl)).RoleDefinitions)(this, pproledefinitions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Scope(this: *mut core::ffi::c_void, ppscope: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRoleAssignment", "Scope", 37usize, |this| ((**(this as *const *const IAzRoleAssignment_Vtbl)).Scope)(this, ppscope), |result| Some(*result))
+        }
+        Self {
+            base__: <IAzRole_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddRoleDefinition,
+            DeleteRoleDefinition,
+            #[cfg(feature = "Win32_System_Com")]
+            RoleDefinitions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RoleDefinitions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Scope,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Scope: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzRoleAssignment as windows_core::Interface>::IID || <IAzRole_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzRoleAssignments_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, pvarobtptr: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRoleAssignments", "get_Item", 7usize, |this| ((**(this as *const *const IAzRoleAssignments_Vtbl)).get_Item)(this, index, pvarobtptr), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRoleAssignments", "Count", 8usize, |this| ((**(this as *const *const IAzRoleAssignments_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppenumptr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRoleAssignments", "_NewEnum", 9usize, |this| ((**(this as *const *const IAzRoleAssignments_Vtbl))._NewEnum)(this, ppenumptr), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, get_Item, Count, _NewEnum }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzRoleAssignments as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzRoleDefinition_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RoleAssignments(this: *mut core::ffi::c_void, bstrscopename: core::mem::MaybeUninit<windows_core::BSTR>, brecursive: super::super::Foundation::VARIANT_BOOL, pproleassignments: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRoleDefinition", "RoleAssignments", 33usize, |this| ((**(this as *const *const IAzRoleDefinition_Vtbl)).RoleAssignments)(this, bstrscopename, brecursive, pproleassignments), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddRoleDefinition(this: *mut core::ffi::c_void, bstrroledefinition: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRoleDefinition", "AddRoleDefinition", 34usize, |this| ((**(this as *const *const IAzRoleDefinition_Vtbl)).AddRoleDefinition)(this, bstrroledefinition), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteRoleDefinition(this: *mut core::ffi::c_void, bstrroledefinition: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRoleDefinition", "DeleteRoleDefinition", 35usize, |this| ((**(this as *const *const IAzRoleDefinition_Vtbl)).DeleteRoleDefinition)(this, bstrroledefinition), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RoleDefinitions(this: *mut core::ffi::c_void, pproledefinitions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRoleDefinition", "RoleDefinitions", 36usize, |this| ((**(this as *const *const IAzRoleDefinition_Vtbl)).RoleDefinitions)(this, pproledefinitions), |result| Some(*result))
+        }
+        Self {
+            base__: <IAzTask_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            RoleAssignments,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RoleAssignments: 0,
+            AddRoleDefinition,
+            DeleteRoleDefinition,
+            #[cfg(feature = "Win32_System_Com")]
+            RoleDefinitions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RoleDefinitions: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzRoleDefinition as windows_core::Interface>::IID || <IAzTask_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzRoleDefinitions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, pvarobtptr: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRoleDefinitions", "get_Item", 7usize, |this| ((**(this as *const *const IAzRoleDefinitions_Vtbl)).get_Item)(this, index, pvarobtptr), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRoleDefinitions", "Count", 8usize, |this| ((**(this as *const *const IAzRoleDefinitions_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppenumptr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRoleDefinitions", "_NewEnum", 9usize, |this| ((**(this as *const *const IAzRoleDefinitions_Vtbl))._NewEnum)(this, ppenumptr), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, get_Item, Count, _NewEnum }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzRoleDefinitions as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzRoles_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, pvarobtptr: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRoles", "get_Item", 7usize, |this| ((**(this as *const *const IAzRoles_Vtbl)).get_Item)(this, index, pvarobtptr), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRoles", "Count", 8usize, |this| ((**(this as *const *const IAzRoles_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppenumptr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzRoles", "_NewEnum", 9usize, |this| ((**(this as *const *const IAzRoles_Vtbl))._NewEnum)(this, ppenumptr), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, get_Item, Count, _NewEnum }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzRoles as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzScope_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "Name", 7usize, |this| ((**(this as *const *const IAzScope_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "SetName", 8usize, |this| ((**(this as *const *const IAzScope_Vtbl)).SetName)(this, bstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, pbstrdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "Description", 9usize, |this| ((**(this as *const *const IAzScope_Vtbl)).Description)(this, pbstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "SetDescription", 10usize, |this| ((**(this as *const *const IAzScope_Vtbl)).SetDescription)(this, bstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplicationData(this: *mut core::ffi::c_void, pbstrapplicationdata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "ApplicationData", 11usize, |this| ((**(this as *const *const IAzScope_Vtbl)).ApplicationData)(this, pbstrapplicationdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetApplicationData(this: *mut core::ffi::c_void, bstrapplicationdata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "SetApplicationData", 12usize, |this| ((**(this as *const *const IAzScope_Vtbl)).SetApplicationData)(this, bstrapplicationdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn Writable(this: *mut core::ffi::c_void, pfprop: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "Writable", 13usize, |this| ((**(this as *const *const IAzScope_Vtbl)).Writable)(this, pfprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, lpropid: i32, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, pvarprop: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "GetProperty", 14usize, |this| ((**(this as *const *const IAzScope_Vtbl)).GetProperty)(this, lpropid, varreserved, pvarprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, lpropid: i32, varprop: core::mem::MaybeUninit<windows_core::VARIANT>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "SetProperty", 15usize, |this| ((**(this as *const *const IAzScope_Vtbl)).SetProperty)(this, lpropid, varprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPropertyItem(this: *mut core::ffi::c_void, lpropid: i32, varprop: core::mem::MaybeUninit<windows_core::VARIANT>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "AddPropertyItem", 16usize, |this| ((**(this as *const *const IAzScope_Vtbl)).AddPropertyItem)(this, lpropid, varprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeletePropertyItem(this: *mut core::ffi::c_void, lpropid: i32, varprop: core::mem::MaybeUninit<windows_core::VARIANT>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "DeletePropertyItem", 17usize, |this| ((**(this as *const *const IAzScope_Vtbl)).DeletePropertyItem)(this, lpropid, varprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn PolicyAdministrators(this: *mut core::ffi::c_void, pvaradmins: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "PolicyAdministrators", 18usize, |this| ((**(this as *const *const IAzScope_Vtbl)).PolicyAdministrators)(this, pvaradmins), |result| Some(*result))
+        }
+        unsafe extern "system" fn PolicyReaders(this: *mut core::ffi::c_void, pvarreaders: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "PolicyReaders", 19usize, |this| ((**(this as *const *const IAzScope_Vtbl)).PolicyReaders)(this, pvarreaders), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPolicyAdministrator(this: *mut core::ffi::c_void, bstradmin: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "AddPolicyAdministrator", 20usize, |this| ((**(this as *const *const IAzScope_Vtbl)).AddPolicyAdministrator)(this, bstradmin, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeletePolicyAdministrator(this: *mut core::ffi::c_void, bstradmin: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "DeletePolicyAdministrator", 21usize, |this| ((**(this as *const *const IAzScope_Vtbl)).DeletePolicyAdministrator)(this, bstradmin, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPolicyReader(this: *mut core::ffi::c_void, bstrreader: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "AddPolicyReader", 22usize, |this| ((**(this as *const *const IAzScope_Vtbl)).AddPolicyReader)(this, bstrreader, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeletePolicyReader(this: *mut core::ffi::c_void, bstrreader: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "DeletePolicyReader", 23usize, |this| ((**(this as *const *const IAzScope_Vtbl)).DeletePolicyReader)(this, bstrreader, varreserved), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ApplicationGroups(this: *mut core::ffi::c_void, ppgroupcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "ApplicationGroups", 24usize, |this| ((**(this as *const *const IAzScope_Vtbl)).ApplicationGroups)(this, ppgroupcollection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OpenApplicationGroup(this: *mut core::ffi::c_void, bstrgroupname: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, ppgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "OpenApplicationGroup", 25usize, |this| ((**(this as *const *const IAzScope_Vtbl)).OpenApplicationGroup)(this, bstrgroupname, varreserved, ppgroup), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateApplicationGroup(this: *mut core::ffi::c_void, bstrgroupname: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, ppgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "CreateApplicationGroup", 26usize, |this| ((**(this as *const *const IAzScope_Vtbl)).CreateApplicationGroup)(this, bstrgroupname, varreserved, ppgroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteApplicationGroup(this: *mut core::ffi::c_void, bstrgroupname: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "DeleteApplicationGroup", 27usize, |this| ((**(this as *const *const IAzScope_Vtbl)).DeleteApplicationGroup)(this, bstrgroupname, varreserved), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Roles(this: *mut core::ffi::c_void, pprolecollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "Roles", 28usize, |this| ((**(this as *const *const IAzScope_Vtbl)).Roles)(this, pprolecollection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OpenRole(this: *mut core::ffi::c_void, bstrrolename: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, pprole: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "OpenRole", 29usize, |this| ((**(this as *const *const IAzScope_Vtbl)).OpenRole)(this, bstrrolename, varreserved, pprole), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateRole(this: *mut core::ffi::c_void, bstrrolename: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, pprole: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "CreateRole", 30usize, |this| ((**(this as *const *const IAzScope_Vtbl)).CreateRole)(this, bstrrolename, varreserved, pprole), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteRole(this: *mut core::ffi::c_void, bstrrolename: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "DeleteRole", 31usize, |this| ((**(this as *const *const IAzScope_Vtbl)).DeleteRole)(this, bstrrolename, varreserved), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Tasks(this: *mut core::ffi::c_void, pptaskcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "Tasks", 32usize, |this| ((**(this as *const *const IAzScope_Vtbl)).Tasks)(this, pptaskcollection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OpenTask(this: *mut core::ffi::c_void, bstrtaskname: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, pptask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "OpenTask", 33usize, |this| ((**(this as *const *const IAzScope_Vtbl)).OpenTask)(this, bstrtaskname, varreserved, pptask), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateTask(this: *mut core::ffi::c_void, bstrtaskname: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, pptask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "CreateTask", 34usize, |this| ((**(this as *const *const IAzScope_Vtbl)).CreateTask)(this, bstrtaskname, varreserved, pptask), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteTask(this: *mut core::ffi::c_void, bstrtaskname: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "DeleteTask", 35usize, |this| ((**(this as *const *const IAzScope_Vtbl)).DeleteTask)(this, bstrtaskname, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn Submit(this: *mut core::ffi::c_void, lflags: i32, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "Submit", 36usize, |this| ((**(this as *const *const IAzScope_Vtbl)).Submit)(this, lflags, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanBeDelegated(this: *mut core::ffi::c_void, pfprop: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "CanBeDelegated", 37usize, |this| ((**(this as *const *const IAzScope_Vtbl)).CanBeDelegated)(this, pfprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn BizrulesWritable(this: *mut core::ffi::c_void, pfprop: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "BizrulesWritable", 38usize, |this| ((**(this as *const *const IAzScope_Vtbl)).BizrulesWritable)(this, pfprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn PolicyAdministratorsName(this: *mut core::ffi::c_void, pvaradmins: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "PolicyAdministratorsName", 39usize, |this| ((**(this as *const *const IAzScope_Vtbl)).PolicyAdministratorsName)(this, pvaradmins), |result| Some(*result))
+        }
+        unsafe extern "system" fn PolicyReadersName(this: *mut core::ffi::c_void, pvarreaders: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "PolicyReadersName", 40usize, |this| ((**(this as *const *const IAzScope_Vtbl)).PolicyReadersName)(this, pvarreaders), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPolicyAdministratorName(this: *mut core::ffi::c_void, bstradmin: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "AddPolicyAdministratorName", 41usize, |this| ((**(this as *const *const IAzScope_Vtbl)).AddPolicyAdministratorName)(this, bstradmin, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeletePolicyAdministratorName(this: *mut core::ffi::c_void, bstradmin: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "DeletePolicyAdministratorName", 42usize, |this| ((**(this as *const *const IAzScope_Vtbl)).DeletePolicyAdministratorName)(this, bstradmin, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPolicyReaderName(this: *mut core::ffi::c_void, bstrreader: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "AddPolicyReaderName", 43usize, |this| ((**(this as *const *const IAzScope_Vtbl)).AddPolicyReaderName)(this, bstrreader, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeletePolicyReaderName(this: *mut core::ffi::c_void, bstrreader: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope", "DeletePolicyReaderName", 44usize, |this| ((**(this as *const *const IAzScope_Vtbl)).DeletePolicyReaderName)(this, bstrreader, varreserved), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            SetName,
+            Description,
+            SetDescription,
+            ApplicationData,
+            SetApplicationData,
+            Writable,
+            GetProperty,
+            SetProperty,
+            AddPropertyItem,
+            DeletePropertyItem,
+            PolicyAdministrators,
+            PolicyReaders,
+            AddPolicyAdministrator,
+            DeletePolicyAdministrator,
+            AddPolicyReader,
+            DeletePolicyReader,
+            #[cfg(feature = "Win32_System_Com")]
+            ApplicationGroups,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ApplicationGroups: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OpenApplicationGroup,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OpenApplicationGroup: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateApplicationGroup,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateApplicationGroup: 0,
+            DeleteApplicationGroup,
+            #[cfg(feature = "Win32_System_Com")]
+            Roles,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Roles: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OpenRole,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OpenRole: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateRole,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateRole: 0,
+            DeleteRole,
+            #[cfg(feature = "Win32_System_Com")]
+            Tasks,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Tasks: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OpenTask,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OpenTask: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateTask,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateTask: 0,
+            DeleteTask,
+            Submit,
+            CanBeDelegated,
+            BizrulesWritable,
+            PolicyAdministratorsName,
+            PolicyReadersName,
+            AddPolicyAdministratorName,
+            DeletePolicyAdministratorName,
+            AddPolicyReaderName,
+            DeletePolicyReaderName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzScope as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzScope2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RoleDefinitions(this: *mut core::ffi::c_void, pproledefinitions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope2", "RoleDefinitions", 45usize, |this| ((**(this as *const *const IAzScope2_Vtbl)).RoleDefinitions)(this, pproledefinitions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateRoleDefinition(this: *mut core::ffi::c_void, bstrroledefinitionname: core::mem::MaybeUninit<windows_core::BSTR>, pproledefinitions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope2", "CreateRoleDefinition", 46usize, |this| ((**(this as *const *const IAzScope2_Vtbl)).CreateRoleDefinition)(this, bstrroledefinitionname, pproledefinitions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OpenRoleDefinition(this: *mut core::ffi::c_void, bstrroledefinitionname: core::mem::MaybeUninit<windows_core::BSTR>, pproledefinitions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope2", "OpenRoleDefinition", 47usize, |this| ((**(this as *const *const IAzScope2_Vtbl)).OpenRoleDefinition)(this, bstrroledefinitionname, pproledefinitions), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteRoleDefinition(this: *mut core::ffi::c_void, bstrroledefinitionname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope2", "DeleteRoleDefinition", 48usize, |this| ((**(this as *const *const IAzScope2_Vtbl)).DeleteRoleDefinition)(this, bstrroledefinitionname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RoleAssignments(this: *mut core::ffi::c_void, pproleassignments: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope2", "RoleAssignments", 49usize, |this| ((**(this as *const *const IAzScope2_Vtbl)).RoleAssignments)(this, pproleassignments), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateRoleAssignment(this: *mut core::ffi::c_void, bstrroleassignmentname: core::mem::MaybeUninit<windows_core::BSTR>, pproleassignment: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope2", "CreateRoleAssignment", 50usize, |this| ((**(this as *const *const IAzScope2_Vtbl)).CreateRoleAssignment)(this, bstrroleassignmentname, pproleassignment), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OpenRoleAssignment(this: *mut core::ffi::c_void, bstrroleassignmentname: core::mem::MaybeUninit<windows_core::BSTR>, pproleassignment: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope2", "OpenRoleAssignment", 51usize, |this| ((**(this as *const *const IAzScope2_Vtbl)).OpenRoleAssignment)(this, bstrroleassignmentname, pproleassignment), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteRoleAssignment(this: *mut core::ffi::c_void, bstrroleassignmentname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScope2", "DeleteRoleAssignment", 52usize, |this| ((**(this as *const *const IAzScope2_Vtbl)).DeleteRoleAssignment)(this, bstrroleassignmentname), |result| Some(*result))
+        }
+        Self {
+            base__: <IAzScope_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            RoleDefinitions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RoleDefinitions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateRoleDefinition,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateRoleDefinition: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OpenRoleDefinition,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OpenRoleDefinition: 0,
+            DeleteRoleDefinition,
+            #[cfg(feature = "Win32_System_Com")]
+            RoleAssignments,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RoleAssignments: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateRoleAssignment,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateRoleAssignment: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OpenRoleAssignment,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OpenRoleAssignment: 0,
+            DeleteRoleAssignment,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzScope2 as windows_core::Interface>::IID || <IAzScope_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzScopes_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, pvarobtptr: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScopes", "get_Item", 7usize, |this| ((**(this as *const *const IAzScopes_Vtbl)).get_Item)(this, index, pvarobtptr), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScopes", "Count", 8usize, |this| ((**(this as *const *const IAzScopes_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppenumptr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzScopes", "_NewEnum", 9usize, |this| ((**(this as *const *const IAzScopes_Vtbl))._NewEnum)(this, ppenumptr), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, get_Item, Count, _NewEnum }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzScopes as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzTask_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTask", "Name", 7usize, |this| ((**(this as *const *const IAzTask_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTask", "SetName", 8usize, |this| ((**(this as *const *const IAzTask_Vtbl)).SetName)(this, bstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, pbstrdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTask", "Description", 9usize, |this| ((**(this as *const *const IAzTask_Vtbl)).Description)(this, pbstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTask", "SetDescription", 10usize, |this| ((**(this as *const *const IAzTask_Vtbl)).SetDescription)(this, bstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplicationData(this: *mut core::ffi::c_void, pbstrapplicationdata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTask", "ApplicationData", 11usize, |this| ((**(this as *const *const IAzTask_Vtbl)).ApplicationData)(this, pbstrapplicationdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetApplicationData(this: *mut core::ffi::c_void, bstrapplicationdata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTask", "SetApplicationData", 12usize, |this| ((**(this as *const *const IAzTask_Vtbl)).SetApplicationData)(this, bstrapplicationdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn BizRule(this: *mut core::ffi::c_void, pbstrprop: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTask", "BizRule", 13usize, |this| ((**(this as *const *const IAzTask_Vtbl)).BizRule)(this, pbstrprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBizRule(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTask", "SetBizRule", 14usize, |this| ((**(this as *const *const IAzTask_Vtbl)).SetBizRule)(this, bstrprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn BizRuleLanguage(this: *mut core::ffi::c_void, pbstrprop: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTask", "BizRuleLanguage", 15usize, |this| ((**(this as *const *const IAzTask_Vtbl)).BizRuleLanguage)(this, pbstrprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBizRuleLanguage(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTask", "SetBizRuleLanguage", 16usize, |this| ((**(this as *const *const IAzTask_Vtbl)).SetBizRuleLanguage)(this, bstrprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn BizRuleImportedPath(this: *mut core::ffi::c_void, pbstrprop: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTask", "BizRuleImportedPath", 17usize, |this| ((**(this as *const *const IAzTask_Vtbl)).BizRuleImportedPath)(this, pbstrprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBizRuleImportedPath(this: *mut core::ffi::c_void, bstrprop: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTask", "SetBizRuleImportedPath", 18usize, |this| ((**(this as *const *const IAzTask_Vtbl)).SetBizRuleImportedPath)(this, bstrprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRoleDefinition(this: *mut core::ffi::c_void, pfprop: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTask", "IsRoleDefinition", 19usize, |this| ((**(this as *const *const IAzTask_Vtbl)).IsRoleDefinition)(this, pfprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsRoleDefinition(this: *mut core::ffi::c_void, fprop: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTask", "SetIsRoleDefinition", 20usize, |this| ((**(this as *const *const IAzTask_Vtbl)).SetIsRoleDefinition)(this, fprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn Operations(this: *mut core::ffi::c_void, pvarprop: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTask", "Operations", 21usize, |this| ((**(this as *const *const IAzTask_Vtbl)).Operations)(this, pvarprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn Tasks(this: *mut core::ffi::c_void, pvarprop: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTask", "Tasks", 22usize, |this| ((**(this as *const *const IAzTask_Vtbl)).Tasks)(this, pvarprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddOperation(this: *mut core::ffi::c_void, bstrop: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTask", "AddOperation", 23usize, |this| ((**(this as *const *const IAzTask_Vtbl)).AddOperation)(this, bstrop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteOperation(this: *mut core::ffi::c_void, bstrop: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTask", "DeleteOperation", 24usize, |this| ((**(this as *const *const IAzTask_Vtbl)).DeleteOperation)(this, bstrop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTask(this: *mut core::ffi::c_void, bstrtask: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTask", "AddTask", 25usize, |this| ((**(this as *const *const IAzTask_Vtbl)).AddTask)(this, bstrtask, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteTask(this: *mut core::ffi::c_void, bstrtask: core::mem::MaybeUninit<windows_core::BSTR>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTask", "DeleteTask", 26usize, |this| ((**(this as *const *const IAzTask_Vtbl)).DeleteTask)(this, bstrtask, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn Writable(this: *mut core::ffi::c_void, pfprop: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTask", "Writable", 27usize, |this| ((**(this as *const *const IAzTask_Vtbl)).Writable)(this, pfprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, lpropid: i32, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>, pvarprop: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTask", "GetProperty", 28usize, |this| ((**(this as *const *const IAzTask_Vtbl)).GetProperty)(this, lpropid, varreserved, pvarprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, lpropid: i32, varprop: core::mem::MaybeUninit<windows_core::VARIANT>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTask", "SetProperty", 29usize, |this| ((**(this as *const *const IAzTask_Vtbl)).SetProperty)(this, lpropid, varprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPropertyItem(this: *mut core::ffi::c_void, lpropid: i32, varprop: core::mem::MaybeUninit<windows_core::VARIANT>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTask", "AddPropertyItem", 30usize, |this| ((**(this as *const *const IAzTask_Vtbl)).AddPropertyItem)(this, lpropid, varprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeletePropertyItem(this: *mut core::ffi::c_void, lpropid: i32, varprop: core::mem::MaybeUninit<windows_core::VARIANT>, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTask", "DeletePropertyItem", 31usize, |this| ((**(this as *const *const IAzTask_Vtbl)).DeletePropertyItem)(this, lpropid, varprop, varreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn Submit(this: *mut core::ffi::c_void, lflags: i32, varreserved: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTask", "Submit", 32usize, |this| ((**(this as *const *const IAzTask_Vtbl)).Submit)(this, lflags, varreserved), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            SetName,
+            Description,
+            SetDescription,
+            ApplicationData,
+            SetApplicationData,
+            BizRule,
+            SetBizRule,
+            BizRuleLanguage,
+            SetBizRuleLanguage,
+            BizRuleImportedPath,
+            SetBizRuleImportedPath,
+            IsRoleDefinition,
+            SetIsRoleDefinition,
+            Operations,
+            Tasks,
+            AddOperation,
+            DeleteOperation,
+            AddTask,
+            DeleteTask,
+            Writable,
+            GetProperty,
+            SetProperty,
+            AddPropertyItem,
+            DeletePropertyItem,
+            Submit,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzTask as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzTask2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RoleAssignments(this: *mut core::ffi::c_void, bstrscopename: core::mem::MaybeUninit<windows_core::BSTR>, brecursive: super::super::Foundation::VARIANT_BOOL, pproleassignments: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTask2", "RoleAssignments", 33usize, |this| ((**(this as *const *const IAzTask2_Vtbl)).RoleAssignments)(this, bstrscopename, brecursive, pproleassignments), |result| Some(*result))
+        }
+        Self {
+            base__: <IAzTask_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            RoleAssignments,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RoleAssignments: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzTask2 as windows_core::Interface>::IID || <IAzTask_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAzTasks_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, pvarobtptr: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTasks", "get_Item", 7usize, |this| ((**(this as *const *const IAzTasks_Vtbl)).get_Item)(this, index, pvarobtptr), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTasks", "Count", 8usize, |this| ((**(this as *const *const IAzTasks_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppenumptr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAzTasks", "_NewEnum", 9usize, |this| ((**(this as *const *const IAzTasks_Vtbl))._NewEnum)(this, ppenumptr), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, get_Item, Count, _NewEnum }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAzTasks as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -0,0 +1,39 @@
+impl windows_core::imp::ProxyVtable for ISceSvcAttachmentData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetData(this: *mut core::ffi::c_void, scesvchandle: *mut core::ffi::c_void, scetype: SCESVC_INFO_TYPE, ppvdata: *mut *mut core::ffi::c_void, psceenumhandle: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceSvcAttachmentData", "GetData", 3usize, |this| ((**(this as *const *const ISceSvcAttachmentData_Vtbl)).GetData)(this, scesvchandle, scetype, ppvdata, psceenumhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, lpservicename: *mut i8, lptemplatename: *mut i8, lpscesvcpersistinfo: *mut core::ffi::c_void, pscesvchandle: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceSvcAttachmentData", "Initialize", 4usize, |this| ((**(this as *const *const ISceSvcAttachmentData_Vtbl)).Initialize)(this, lpservicename, lptemplatename, lpscesvcpersistinfo, pscesvchandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreeBuffer(this: *mut core::ffi::c_void, pvdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceSvcAttachmentData", "FreeBuffer", 5usize, |this| ((**(this as *const *const ISceSvcAttachmentData_Vtbl)).FreeBuffer)(this, pvdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseHandle(this: *mut core::ffi::c_void, scesvchandle: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceSvcAttachmentData", "CloseHandle", 6usize, |this| ((**(this as *const *const ISceSvcAttachmentData_Vtbl)).CloseHandle)(this, scesvchandle), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetData, Initialize, FreeBuffer, CloseHandle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceSvcAttachmentData as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISceSvcAttachmentPersistInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void, lptemplatename: *mut i8, scesvchandle: *mut *mut core::ffi::c_void, ppvdata: *mut *mut core::ffi::c_void, pboverwriteall: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceSvcAttachmentPersistInfo", "Save", 3usize, |this| ((**(this as *const *const ISceSvcAttachmentPersistInfo_Vtbl)).Save)(this, lptemplatename, scesvchandle, ppvdata, pboverwriteall), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDirty(this: *mut core::ffi::c_void, lptemplatename: *mut i8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceSvcAttachmentPersistInfo", "IsDirty", 4usize, |this| ((**(this as *const *const ISceSvcAttachmentPersistInfo_Vtbl)).IsDirty)(this, lptemplatename), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreeBuffer(this: *mut core::ffi::c_void, pvdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISceSvcAttachmentPersistInfo", "FreeBuffer", 5usize, |this| ((**(this as *const *const ISceSvcAttachmentPersistInfo_Vtbl)).FreeBuffer)(this, pvdata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Save, IsDirty, FreeBuffer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISceSvcAttachmentPersistInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -12798,6 +12798,7 @@ pub struct ADDED_CERT_TYPE(pub i32);
 impl windows_core::TypeKind for ADDED_CERT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ADDED_CERT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ADDED_CERT_TYPE").field(&self.0).finish()
@@ -12809,6 +12810,7 @@ pub struct AlgorithmFlags(pub i32);
 impl windows_core::TypeKind for AlgorithmFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AlgorithmFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AlgorithmFlags").field(&self.0).finish()
@@ -12820,6 +12822,7 @@ pub struct AlgorithmOperationFlags(pub i32);
 impl windows_core::TypeKind for AlgorithmOperationFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AlgorithmOperationFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AlgorithmOperationFlags").field(&self.0).finish()
@@ -12831,6 +12834,7 @@ pub struct AlgorithmType(pub i32);
 impl windows_core::TypeKind for AlgorithmType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AlgorithmType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AlgorithmType").field(&self.0).finish()
@@ -12842,6 +12846,7 @@ pub struct AlternativeNameType(pub i32);
 impl windows_core::TypeKind for AlternativeNameType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AlternativeNameType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AlternativeNameType").field(&self.0).finish()
@@ -12853,6 +12858,7 @@ pub struct CERTADMIN_GET_ROLES_FLAGS(pub u32);
 impl windows_core::TypeKind for CERTADMIN_GET_ROLES_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CERTADMIN_GET_ROLES_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CERTADMIN_GET_ROLES_FLAGS").field(&self.0).finish()
@@ -12897,6 +12903,7 @@ pub struct CERTENROLL_OBJECTID(pub i32);
 impl windows_core::TypeKind for CERTENROLL_OBJECTID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CERTENROLL_OBJECTID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CERTENROLL_OBJECTID").field(&self.0).finish()
@@ -12908,6 +12915,7 @@ pub struct CERTENROLL_PROPERTYID(pub i32);
 impl windows_core::TypeKind for CERTENROLL_PROPERTYID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CERTENROLL_PROPERTYID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CERTENROLL_PROPERTYID").field(&self.0).finish()
@@ -12919,6 +12927,7 @@ pub struct CERT_ALT_NAME(pub i32);
 impl windows_core::TypeKind for CERT_ALT_NAME {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CERT_ALT_NAME {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CERT_ALT_NAME").field(&self.0).finish()
@@ -12930,6 +12939,7 @@ pub struct CERT_CREATE_REQUEST_FLAGS(pub i32);
 impl windows_core::TypeKind for CERT_CREATE_REQUEST_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CERT_CREATE_REQUEST_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CERT_CREATE_REQUEST_FLAGS").field(&self.0).finish()
@@ -12941,6 +12951,7 @@ pub struct CERT_DELETE_ROW_FLAGS(pub i32);
 impl windows_core::TypeKind for CERT_DELETE_ROW_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CERT_DELETE_ROW_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CERT_DELETE_ROW_FLAGS").field(&self.0).finish()
@@ -12952,6 +12963,7 @@ pub struct CERT_EXIT_EVENT_MASK(pub u32);
 impl windows_core::TypeKind for CERT_EXIT_EVENT_MASK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CERT_EXIT_EVENT_MASK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CERT_EXIT_EVENT_MASK").field(&self.0).finish()
@@ -12996,6 +13008,7 @@ pub struct CERT_GET_CONFIG_FLAGS(pub i32);
 impl windows_core::TypeKind for CERT_GET_CONFIG_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CERT_GET_CONFIG_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CERT_GET_CONFIG_FLAGS").field(&self.0).finish()
@@ -13007,6 +13020,7 @@ pub struct CERT_IMPORT_FLAGS(pub i32);
 impl windows_core::TypeKind for CERT_IMPORT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CERT_IMPORT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CERT_IMPORT_FLAGS").field(&self.0).finish()
@@ -13018,6 +13032,7 @@ pub struct CERT_PROPERTY_TYPE(pub i32);
 impl windows_core::TypeKind for CERT_PROPERTY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CERT_PROPERTY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CERT_PROPERTY_TYPE").field(&self.0).finish()
@@ -13029,6 +13044,7 @@ pub struct CERT_REQUEST_OUT_TYPE(pub i32);
 impl windows_core::TypeKind for CERT_REQUEST_OUT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CERT_REQUEST_OUT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CERT_REQUEST_OUT_TYPE").field(&self.0).finish()
@@ -13040,6 +13056,7 @@ pub struct CERT_VIEW_COLUMN_INDEX(pub i32);
 impl windows_core::TypeKind for CERT_VIEW_COLUMN_INDEX {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CERT_VIEW_COLUMN_INDEX {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CERT_VIEW_COLUMN_INDEX").field(&self.0).finish()
@@ -13051,6 +13068,7 @@ pub struct CERT_VIEW_SEEK_OPERATOR_FLAGS(pub i32);
 impl windows_core::TypeKind for CERT_VIEW_SEEK_OPERATOR_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CERT_VIEW_SEEK_OPERATOR_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CERT_VIEW_SEEK_OPERATOR_FLAGS").field(&self.0).finish()
@@ -13062,6 +13080,7 @@ pub struct CRLRevocationReason(pub i32);
 impl windows_core::TypeKind for CRLRevocationReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CRLRevocationReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CRLRevocationReason").field(&self.0).finish()
@@ -13073,6 +13092,7 @@ pub struct CR_DISP(pub u32);
 impl windows_core::TypeKind for CR_DISP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CR_DISP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CR_DISP").field(&self.0).finish()
@@ -13084,6 +13104,7 @@ pub struct CSBACKUP_TYPE(pub u32);
 impl windows_core::TypeKind for CSBACKUP_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CSBACKUP_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CSBACKUP_TYPE").field(&self.0).finish()
@@ -13095,6 +13116,7 @@ pub struct CVRC_COLUMN(pub i32);
 impl windows_core::TypeKind for CVRC_COLUMN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CVRC_COLUMN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CVRC_COLUMN").field(&self.0).finish()
@@ -13106,6 +13128,7 @@ pub struct CVRC_TABLE(pub i32);
 impl windows_core::TypeKind for CVRC_TABLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CVRC_TABLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CVRC_TABLE").field(&self.0).finish()
@@ -13117,6 +13140,7 @@ pub struct CommitTemplateFlags(pub i32);
 impl windows_core::TypeKind for CommitTemplateFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CommitTemplateFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CommitTemplateFlags").field(&self.0).finish()
@@ -13128,6 +13152,7 @@ pub struct DelayRetryAction(pub i32);
 impl windows_core::TypeKind for DelayRetryAction {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DelayRetryAction {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DelayRetryAction").field(&self.0).finish()
@@ -13139,6 +13164,7 @@ pub struct ENUM_CATYPES(pub i32);
 impl windows_core::TypeKind for ENUM_CATYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ENUM_CATYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ENUM_CATYPES").field(&self.0).finish()
@@ -13150,6 +13176,7 @@ pub struct ENUM_CERT_COLUMN_VALUE_FLAGS(pub i32);
 impl windows_core::TypeKind for ENUM_CERT_COLUMN_VALUE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ENUM_CERT_COLUMN_VALUE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ENUM_CERT_COLUMN_VALUE_FLAGS").field(&self.0).finish()
@@ -13161,6 +13188,7 @@ pub struct EncodingType(pub i32);
 impl windows_core::TypeKind for EncodingType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EncodingType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EncodingType").field(&self.0).finish()
@@ -13172,6 +13200,7 @@ pub struct EnrollmentCAProperty(pub i32);
 impl windows_core::TypeKind for EnrollmentCAProperty {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EnrollmentCAProperty {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EnrollmentCAProperty").field(&self.0).finish()
@@ -13183,6 +13212,7 @@ pub struct EnrollmentDisplayStatus(pub i32);
 impl windows_core::TypeKind for EnrollmentDisplayStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EnrollmentDisplayStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EnrollmentDisplayStatus").field(&self.0).finish()
@@ -13194,6 +13224,7 @@ pub struct EnrollmentEnrollStatus(pub i32);
 impl windows_core::TypeKind for EnrollmentEnrollStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EnrollmentEnrollStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EnrollmentEnrollStatus").field(&self.0).finish()
@@ -13205,6 +13236,7 @@ pub struct EnrollmentPolicyFlags(pub i32);
 impl windows_core::TypeKind for EnrollmentPolicyFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EnrollmentPolicyFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EnrollmentPolicyFlags").field(&self.0).finish()
@@ -13216,6 +13248,7 @@ pub struct EnrollmentPolicyServerPropertyFlags(pub i32);
 impl windows_core::TypeKind for EnrollmentPolicyServerPropertyFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EnrollmentPolicyServerPropertyFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EnrollmentPolicyServerPropertyFlags").field(&self.0).finish()
@@ -13227,6 +13260,7 @@ pub struct EnrollmentSelectionStatus(pub i32);
 impl windows_core::TypeKind for EnrollmentSelectionStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EnrollmentSelectionStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EnrollmentSelectionStatus").field(&self.0).finish()
@@ -13238,6 +13272,7 @@ pub struct EnrollmentTemplateProperty(pub i32);
 impl windows_core::TypeKind for EnrollmentTemplateProperty {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EnrollmentTemplateProperty {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EnrollmentTemplateProperty").field(&self.0).finish()
@@ -13249,6 +13284,7 @@ pub struct FULL_RESPONSE_PROPERTY_ID(pub i32);
 impl windows_core::TypeKind for FULL_RESPONSE_PROPERTY_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FULL_RESPONSE_PROPERTY_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FULL_RESPONSE_PROPERTY_ID").field(&self.0).finish()
@@ -13260,6 +13296,7 @@ pub struct ImportPFXFlags(pub i32);
 impl windows_core::TypeKind for ImportPFXFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ImportPFXFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ImportPFXFlags").field(&self.0).finish()
@@ -13271,6 +13308,7 @@ pub struct InnerRequestLevel(pub i32);
 impl windows_core::TypeKind for InnerRequestLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InnerRequestLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InnerRequestLevel").field(&self.0).finish()
@@ -13282,6 +13320,7 @@ pub struct InstallResponseRestrictionFlags(pub i32);
 impl windows_core::TypeKind for InstallResponseRestrictionFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InstallResponseRestrictionFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InstallResponseRestrictionFlags").field(&self.0).finish()
@@ -13293,6 +13332,7 @@ pub struct KeyAttestationClaimType(pub i32);
 impl windows_core::TypeKind for KeyAttestationClaimType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KeyAttestationClaimType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KeyAttestationClaimType").field(&self.0).finish()
@@ -13304,6 +13344,7 @@ pub struct KeyIdentifierHashAlgorithm(pub i32);
 impl windows_core::TypeKind for KeyIdentifierHashAlgorithm {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KeyIdentifierHashAlgorithm {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KeyIdentifierHashAlgorithm").field(&self.0).finish()
@@ -13315,6 +13356,7 @@ pub struct OCSPRequestFlag(pub i32);
 impl windows_core::TypeKind for OCSPRequestFlag {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OCSPRequestFlag {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OCSPRequestFlag").field(&self.0).finish()
@@ -13326,6 +13368,7 @@ pub struct OCSPSigningFlag(pub i32);
 impl windows_core::TypeKind for OCSPSigningFlag {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OCSPSigningFlag {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OCSPSigningFlag").field(&self.0).finish()
@@ -13337,6 +13380,7 @@ pub struct ObjectIdGroupId(pub i32);
 impl windows_core::TypeKind for ObjectIdGroupId {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ObjectIdGroupId {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ObjectIdGroupId").field(&self.0).finish()
@@ -13348,6 +13392,7 @@ pub struct ObjectIdPublicKeyFlags(pub i32);
 impl windows_core::TypeKind for ObjectIdPublicKeyFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ObjectIdPublicKeyFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ObjectIdPublicKeyFlags").field(&self.0).finish()
@@ -13359,6 +13404,7 @@ pub struct PENDING_REQUEST_DESIRED_PROPERTY(pub i32);
 impl windows_core::TypeKind for PENDING_REQUEST_DESIRED_PROPERTY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PENDING_REQUEST_DESIRED_PROPERTY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PENDING_REQUEST_DESIRED_PROPERTY").field(&self.0).finish()
@@ -13370,6 +13416,7 @@ pub struct PFXExportOptions(pub i32);
 impl windows_core::TypeKind for PFXExportOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PFXExportOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PFXExportOptions").field(&self.0).finish()
@@ -13381,6 +13428,7 @@ pub struct Pkcs10AllowedSignatureTypes(pub i32);
 impl windows_core::TypeKind for Pkcs10AllowedSignatureTypes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for Pkcs10AllowedSignatureTypes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Pkcs10AllowedSignatureTypes").field(&self.0).finish()
@@ -13392,6 +13440,7 @@ pub struct PolicyQualifierType(pub i32);
 impl windows_core::TypeKind for PolicyQualifierType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PolicyQualifierType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PolicyQualifierType").field(&self.0).finish()
@@ -13403,6 +13452,7 @@ pub struct PolicyServerUrlFlags(pub i32);
 impl windows_core::TypeKind for PolicyServerUrlFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PolicyServerUrlFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PolicyServerUrlFlags").field(&self.0).finish()
@@ -13414,6 +13464,7 @@ pub struct PolicyServerUrlPropertyID(pub i32);
 impl windows_core::TypeKind for PolicyServerUrlPropertyID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PolicyServerUrlPropertyID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PolicyServerUrlPropertyID").field(&self.0).finish()
@@ -13425,6 +13476,7 @@ pub struct RequestClientInfoClientId(pub i32);
 impl windows_core::TypeKind for RequestClientInfoClientId {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RequestClientInfoClientId {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RequestClientInfoClientId").field(&self.0).finish()
@@ -13436,6 +13488,7 @@ pub struct WebEnrollmentFlags(pub i32);
 impl windows_core::TypeKind for WebEnrollmentFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WebEnrollmentFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WebEnrollmentFlags").field(&self.0).finish()
@@ -13447,6 +13500,7 @@ pub struct WebSecurityLevel(pub i32);
 impl windows_core::TypeKind for WebSecurityLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WebSecurityLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WebSecurityLevel").field(&self.0).finish()
@@ -13458,6 +13512,7 @@ pub struct X500NameFlags(pub i32);
 impl windows_core::TypeKind for X500NameFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for X500NameFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("X500NameFlags").field(&self.0).finish()
@@ -13469,6 +13524,7 @@ pub struct X509CertificateEnrollmentContext(pub i32);
 impl windows_core::TypeKind for X509CertificateEnrollmentContext {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for X509CertificateEnrollmentContext {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("X509CertificateEnrollmentContext").field(&self.0).finish()
@@ -13480,6 +13536,7 @@ pub struct X509CertificateTemplateEnrollmentFlag(pub i32);
 impl windows_core::TypeKind for X509CertificateTemplateEnrollmentFlag {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for X509CertificateTemplateEnrollmentFlag {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("X509CertificateTemplateEnrollmentFlag").field(&self.0).finish()
@@ -13491,6 +13548,7 @@ pub struct X509CertificateTemplateGeneralFlag(pub i32);
 impl windows_core::TypeKind for X509CertificateTemplateGeneralFlag {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for X509CertificateTemplateGeneralFlag {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("X509CertificateTemplateGeneralFlag").field(&self.0).finish()
@@ -13502,6 +13560,7 @@ pub struct X509CertificateTemplatePrivateKeyFlag(pub i32);
 impl windows_core::TypeKind for X509CertificateTemplatePrivateKeyFlag {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for X509CertificateTemplatePrivateKeyFlag {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("X509CertificateTemplatePrivateKeyFlag").field(&self.0).finish()
@@ -13513,6 +13572,7 @@ pub struct X509CertificateTemplateSubjectNameFlag(pub i32);
 impl windows_core::TypeKind for X509CertificateTemplateSubjectNameFlag {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for X509CertificateTemplateSubjectNameFlag {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("X509CertificateTemplateSubjectNameFlag").field(&self.0).finish()
@@ -13524,6 +13584,7 @@ pub struct X509EnrollmentAuthFlags(pub i32);
 impl windows_core::TypeKind for X509EnrollmentAuthFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for X509EnrollmentAuthFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("X509EnrollmentAuthFlags").field(&self.0).finish()
@@ -13535,6 +13596,7 @@ pub struct X509EnrollmentPolicyExportFlags(pub i32);
 impl windows_core::TypeKind for X509EnrollmentPolicyExportFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for X509EnrollmentPolicyExportFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("X509EnrollmentPolicyExportFlags").field(&self.0).finish()
@@ -13546,6 +13608,7 @@ pub struct X509EnrollmentPolicyLoadOption(pub i32);
 impl windows_core::TypeKind for X509EnrollmentPolicyLoadOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for X509EnrollmentPolicyLoadOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("X509EnrollmentPolicyLoadOption").field(&self.0).finish()
@@ -13557,6 +13620,7 @@ pub struct X509HardwareKeyUsageFlags(pub i32);
 impl windows_core::TypeKind for X509HardwareKeyUsageFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for X509HardwareKeyUsageFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("X509HardwareKeyUsageFlags").field(&self.0).finish()
@@ -13568,6 +13632,7 @@ pub struct X509KeyParametersExportType(pub i32);
 impl windows_core::TypeKind for X509KeyParametersExportType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for X509KeyParametersExportType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("X509KeyParametersExportType").field(&self.0).finish()
@@ -13579,6 +13644,7 @@ pub struct X509KeySpec(pub i32);
 impl windows_core::TypeKind for X509KeySpec {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for X509KeySpec {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("X509KeySpec").field(&self.0).finish()
@@ -13590,6 +13656,7 @@ pub struct X509KeyUsageFlags(pub i32);
 impl windows_core::TypeKind for X509KeyUsageFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for X509KeyUsageFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("X509KeyUsageFlags").field(&self.0).finish()
@@ -13601,6 +13668,7 @@ pub struct X509PrivateKeyExportFlags(pub i32);
 impl windows_core::TypeKind for X509PrivateKeyExportFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for X509PrivateKeyExportFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("X509PrivateKeyExportFlags").field(&self.0).finish()
@@ -13612,6 +13680,7 @@ pub struct X509PrivateKeyProtection(pub i32);
 impl windows_core::TypeKind for X509PrivateKeyProtection {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for X509PrivateKeyProtection {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("X509PrivateKeyProtection").field(&self.0).finish()
@@ -13623,6 +13692,7 @@ pub struct X509PrivateKeyUsageFlags(pub i32);
 impl windows_core::TypeKind for X509PrivateKeyUsageFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for X509PrivateKeyUsageFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("X509PrivateKeyUsageFlags").field(&self.0).finish()
@@ -13634,6 +13704,7 @@ pub struct X509PrivateKeyVerify(pub i32);
 impl windows_core::TypeKind for X509PrivateKeyVerify {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for X509PrivateKeyVerify {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("X509PrivateKeyVerify").field(&self.0).finish()
@@ -13645,6 +13716,7 @@ pub struct X509ProviderType(pub i32);
 impl windows_core::TypeKind for X509ProviderType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for X509ProviderType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("X509ProviderType").field(&self.0).finish()
@@ -13656,6 +13728,7 @@ pub struct X509RequestInheritOptions(pub i32);
 impl windows_core::TypeKind for X509RequestInheritOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for X509RequestInheritOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("X509RequestInheritOptions").field(&self.0).finish()
@@ -13667,6 +13740,7 @@ pub struct X509RequestType(pub i32);
 impl windows_core::TypeKind for X509RequestType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for X509RequestType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("X509RequestType").field(&self.0).finish()
@@ -13678,6 +13752,7 @@ pub struct X509SCEPDisposition(pub i32);
 impl windows_core::TypeKind for X509SCEPDisposition {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for X509SCEPDisposition {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("X509SCEPDisposition").field(&self.0).finish()
@@ -13689,6 +13764,7 @@ pub struct X509SCEPFailInfo(pub i32);
 impl windows_core::TypeKind for X509SCEPFailInfo {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for X509SCEPFailInfo {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("X509SCEPFailInfo").field(&self.0).finish()
@@ -13700,6 +13776,7 @@ pub struct X509SCEPMessageType(pub i32);
 impl windows_core::TypeKind for X509SCEPMessageType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for X509SCEPMessageType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("X509SCEPMessageType").field(&self.0).finish()
@@ -13711,6 +13788,7 @@ pub struct X509SCEPProcessMessageFlags(pub i32);
 impl windows_core::TypeKind for X509SCEPProcessMessageFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for X509SCEPProcessMessageFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("X509SCEPProcessMessageFlags").field(&self.0).finish()
@@ -13722,6 +13800,7 @@ pub struct XEKL_KEYSIZE(pub i32);
 impl windows_core::TypeKind for XEKL_KEYSIZE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XEKL_KEYSIZE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XEKL_KEYSIZE").field(&self.0).finish()
@@ -13733,13 +13812,15 @@ pub struct XEKL_KEYSPEC(pub i32);
 impl windows_core::TypeKind for XEKL_KEYSPEC {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XEKL_KEYSPEC {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XEKL_KEYSPEC").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CAINFO {
     pub cbSize: u32,
     pub CAType: ENUM_CATYPES,
@@ -13799,7 +13880,8 @@ pub const CCspInformation: windows_core::GUID = windows_core::GUID::from_u128(0x
 pub const CCspInformations: windows_core::GUID = windows_core::GUID::from_u128(0x884e2008_217d_11da_b2a4_000e7bbb2b09);
 pub const CCspStatus: windows_core::GUID = windows_core::GUID::from_u128(0x884e2009_217d_11da_b2a4_000e7bbb2b09);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CERTTRANSBLOB {
     pub cb: u32,
     pub pb: *mut u8,
@@ -13813,7 +13895,8 @@ impl Default for CERTTRANSBLOB {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CERTVIEWRESTRICTION {
     pub ColumnIndex: u32,
     pub SeekOperator: i32,
@@ -13836,7 +13919,8 @@ pub const CObjectIds: windows_core::GUID = windows_core::GUID::from_u128(0x884e2
 pub const CPolicyQualifier: windows_core::GUID = windows_core::GUID::from_u128(0x884e201c_217d_11da_b2a4_000e7bbb2b09);
 pub const CPolicyQualifiers: windows_core::GUID = windows_core::GUID::from_u128(0x884e201d_217d_11da_b2a4_000e7bbb2b09);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CSEDB_RSTMAPW {
     pub pwszDatabaseName: windows_core::PWSTR,
     pub pwszNewDatabaseName: windows_core::PWSTR,
@@ -13920,3 +14004,5 @@ pub type FNIMPORTPFXTOPROVIDER = Option<unsafe extern "system" fn(hwndparent: su
 pub type FNIMPORTPFXTOPROVIDERFREEDATA = Option<unsafe extern "system" fn(ccert: u32, rgpcert: *const *const super::CERT_CONTEXT)>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
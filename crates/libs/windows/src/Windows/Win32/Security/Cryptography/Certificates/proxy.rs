@@ -0,0 +1,7288 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAlternativeName_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeFromString(this: *mut core::ffi::c_void, r#type: AlternativeNameType, strvalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlternativeName", "InitializeFromString", 7usize, |this| ((**(this as *const *const IAlternativeName_Vtbl)).InitializeFromString)(this, r#type, strvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeFromRawData(this: *mut core::ffi::c_void, r#type: AlternativeNameType, encoding: EncodingType, strrawdata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlternativeName", "InitializeFromRawData", 8usize, |this| ((**(this as *const *const IAlternativeName_Vtbl)).InitializeFromRawData)(this, r#type, encoding, strrawdata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeFromOtherName(this: *mut core::ffi::c_void, pobjectid: *mut core::ffi::c_void, encoding: EncodingType, strrawdata: core::mem::MaybeUninit<windows_core::BSTR>, tobewrapped: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlternativeName", "InitializeFromOtherName", 9usize, |this| ((**(this as *const *const IAlternativeName_Vtbl)).InitializeFromOtherName)(this, pobjectid, encoding, strrawdata, tobewrapped), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, pvalue: *mut AlternativeNameType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlternativeName", "Type", 10usize, |this| ((**(this as *const *const IAlternativeName_Vtbl)).Type)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrValue(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlternativeName", "StrValue", 11usize, |this| ((**(this as *const *const IAlternativeName_Vtbl)).StrValue)(this, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ObjectId(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlternativeName", "ObjectId", 12usize, |this| ((**(this as *const *const IAlternativeName_Vtbl)).ObjectId)(this, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_RawData(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlternativeName", "get_RawData", 13usize, |this| ((**(this as *const *const IAlternativeName_Vtbl)).get_RawData)(this, encoding, pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitializeFromString,
+            InitializeFromRawData,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeFromOtherName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeFromOtherName: 0,
+            Type,
+            StrValue,
+            #[cfg(feature = "Win32_System_Com")]
+            ObjectId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ObjectId: 0,
+            get_RawData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAlternativeName as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAlternativeNames_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ItemByIndex(this: *mut core::ffi::c_void, index: i32, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlternativeNames", "get_ItemByIndex", 7usize, |this| ((**(this as *const *const IAlternativeNames_Vtbl)).get_ItemByIndex)(this, index, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlternativeNames", "Count", 8usize, |this| ((**(this as *const *const IAlternativeNames_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlternativeNames", "_NewEnum", 9usize, |this| ((**(this as *const *const IAlternativeNames_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlternativeNames", "Add", 10usize, |this| ((**(this as *const *const IAlternativeNames_Vtbl)).Add)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlternativeNames", "Remove", 11usize, |this| ((**(this as *const *const IAlternativeNames_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlternativeNames", "Clear", 12usize, |this| ((**(this as *const *const IAlternativeNames_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ItemByIndex,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ItemByIndex: 0,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            Clear,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAlternativeNames as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IBinaryConverter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StringToString(this: *mut core::ffi::c_void, strencodedin: core::mem::MaybeUninit<windows_core::BSTR>, encodingin: EncodingType, encoding: EncodingType, pstrencoded: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBinaryConverter", "StringToString", 7usize, |this| ((**(this as *const *const IBinaryConverter_Vtbl)).StringToString)(this, strencodedin, encodingin, encoding, pstrencoded), |result| Some(*result))
+        }
+        unsafe extern "system" fn VariantByteArrayToString(this: *mut core::ffi::c_void, pvarbytearray: *const core::mem::MaybeUninit<windows_core::VARIANT>, encoding: EncodingType, pstrencoded: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBinaryConverter", "VariantByteArrayToString", 8usize, |this| ((**(this as *const *const IBinaryConverter_Vtbl)).VariantByteArrayToString)(this, pvarbytearray, encoding, pstrencoded), |result| Some(*result))
+        }
+        unsafe extern "system" fn StringToVariantByteArray(this: *mut core::ffi::c_void, strencoded: core::mem::MaybeUninit<windows_core::BSTR>, encoding: EncodingType, pvarbytearray: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBinaryConverter", "StringToVariantByteArray", 9usize, |this| ((**(this as *const *const IBinaryConverter_Vtbl)).StringToVariantByteArray)(this, strencoded, encoding, pvarbytearray), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StringToString,
+            VariantByteArrayToString,
+            StringToVariantByteArray,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBinaryConverter as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IBinaryConverter2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StringArrayToVariantArray(this: *mut core::ffi::c_void, pvarstringarray: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvarvariantarray: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBinaryConverter2", "StringArrayToVariantArray", 10usize, |this| ((**(this as *const *const IBinaryConverter2_Vtbl)).StringArrayToVariantArray)(this, pvarstringarray, pvarvariantarray), |result| Some(*result))
+        }
+        unsafe extern "system" fn VariantArrayToStringArray(this: *mut core::ffi::c_void, pvarvariantarray: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvarstringarray: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBinaryConverter2", "VariantArrayToStringArray", 11usize, |this| ((**(this as *const *const IBinaryConverter2_Vtbl)).VariantArrayToStringArray)(this, pvarvariantarray, pvarstringarray), |result| Some(*result))
+        }
+        Self { base__: <IBinaryConverter_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StringArrayToVariantArray, VariantArrayToStringArray }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBinaryConverter2 as windows_core::Interface>::IID || <IBinaryConverter_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICEnroll_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn createFilePKCS10(this: *mut core::ffi::c_void, dnname: core::mem::MaybeUninit<windows_core::BSTR>, usage: core::mem::MaybeUninit<windows_core::BSTR>, wszpkcs10filename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "createFilePKCS10", 7usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).createFilePKCS10)(this, dnname, usage, wszpkcs10filename), |result| Some(*result))
+        }
+        unsafe extern "system" fn acceptFilePKCS7(this: *mut core::ffi::c_void, wszpkcs7filename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "acceptFilePKCS7", 8usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).acceptFilePKCS7)(this, wszpkcs7filename), |result| Some(*result))
+        }
+        unsafe extern "system" fn createPKCS10(this: *mut core::ffi::c_void, dnname: core::mem::MaybeUninit<windows_core::BSTR>, usage: core::mem::MaybeUninit<windows_core::BSTR>, ppkcs10: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "createPKCS10", 9usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).createPKCS10)(this, dnname, usage, ppkcs10), |result| Some(*result))
+        }
+        unsafe extern "system" fn acceptPKCS7(this: *mut core::ffi::c_void, pkcs7: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "acceptPKCS7", 10usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).acceptPKCS7)(this, pkcs7), |result| Some(*result))
+        }
+        unsafe extern "system" fn getCertFromPKCS7(this: *mut core::ffi::c_void, wszpkcs7: core::mem::MaybeUninit<windows_core::BSTR>, pbstrcert: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "getCertFromPKCS7", 11usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).getCertFromPKCS7)(this, wszpkcs7, pbstrcert), |result| Some(*result))
+        }
+        unsafe extern "system" fn enumProviders(this: *mut core::ffi::c_void, dwindex: i32, dwflags: i32, pbstrprovname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "enumProviders", 12usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).enumProviders)(this, dwindex, dwflags, pbstrprovname), |result| Some(*result))
+        }
+        unsafe extern "system" fn enumContainers(this: *mut core::ffi::c_void, dwindex: i32, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "enumContainers", 13usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).enumContainers)(this, dwindex, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn freeRequestInfo(this: *mut core::ffi::c_void, pkcs7orpkcs10: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "freeRequestInfo", 14usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).freeRequestInfo)(this, pkcs7orpkcs10), |result| Some(*result))
+        }
+        unsafe extern "system" fn MyStoreName(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "MyStoreName", 15usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).MyStoreName)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMyStoreName(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "SetMyStoreName", 16usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).SetMyStoreName)(this, bstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn MyStoreType(this: *mut core::ffi::c_void, pbstrtype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "MyStoreType", 17usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).MyStoreType)(this, pbstrtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMyStoreType(this: *mut core::ffi::c_void, bstrtype: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "SetMyStoreType", 18usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).SetMyStoreType)(this, bstrtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn MyStoreFlags(this: *mut core::ffi::c_void, pdwflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "MyStoreFlags", 19usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).MyStoreFlags)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMyStoreFlags(this: *mut core::ffi::c_void, dwflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "SetMyStoreFlags", 20usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).SetMyStoreFlags)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn CAStoreName(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "CAStoreName", 21usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).CAStoreName)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCAStoreName(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "SetCAStoreName", 22usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).SetCAStoreName)(this, bstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn CAStoreType(this: *mut core::ffi::c_void, pbstrtype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "CAStoreType", 23usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).CAStoreType)(this, pbstrtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCAStoreType(this: *mut core::ffi::c_void, bstrtype: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "SetCAStoreType", 24usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).SetCAStoreType)(this, bstrtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn CAStoreFlags(this: *mut core::ffi::c_void, pdwflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "CAStoreFlags", 25usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).CAStoreFlags)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCAStoreFlags(this: *mut core::ffi::c_void, dwflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "SetCAStoreFlags", 26usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).SetCAStoreFlags)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn RootStoreName(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "RootStoreName", 27usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).RootStoreName)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRootStoreName(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "SetRootStoreName", 28usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).SetRootStoreName)(this, bstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn RootStoreType(this: *mut core::ffi::c_void, pbstrtype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "RootStoreType", 29usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).RootStoreType)(this, pbstrtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRootStoreType(this: *mut core::ffi::c_void, bstrtype: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "SetRootStoreType", 30usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).SetRootStoreType)(this, bstrtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn RootStoreFlags(this: *mut core::ffi::c_void, pdwflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "RootStoreFlags", 31usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).RootStoreFlags)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRootStoreFlags(this: *mut core::ffi::c_void, dwflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "SetRootStoreFlags", 32usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).SetRootStoreFlags)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestStoreName(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "RequestStoreName", 33usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).RequestStoreName)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequestStoreName(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "SetRequestStoreName", 34usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).SetRequestStoreName)(this, bstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestStoreType(this: *mut core::ffi::c_void, pbstrtype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "RequestStoreType", 35usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).RequestStoreType)(this, pbstrtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequestStoreType(this: *mut core::ffi::c_void, bstrtype: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "SetRequestStoreType", 36usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).SetRequestStoreType)(this, bstrtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestStoreFlags(this: *mut core::ffi::c_void, pdwflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "RequestStoreFlags", 37usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).RequestStoreFlags)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequestStoreFlags(this: *mut core::ffi::c_void, dwflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "SetRequestStoreFlags", 38usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).SetRequestStoreFlags)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContainerName(this: *mut core::ffi::c_void, pbstrcontainer: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "ContainerName", 39usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).ContainerName)(this, pbstrcontainer), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContainerName(this: *mut core::ffi::c_void, bstrcontainer: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "SetContainerName", 40usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).SetContainerName)(this, bstrcontainer), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderName(this: *mut core::ffi::c_void, pbstrprovider: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "ProviderName", 41usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).ProviderName)(this, pbstrprovider), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProviderName(this: *mut core::ffi::c_void, bstrprovider: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "SetProviderName", 42usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).SetProviderName)(this, bstrprovider), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderType(this: *mut core::ffi::c_void, pdwtype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "ProviderType", 43usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).ProviderType)(this, pdwtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProviderType(this: *mut core::ffi::c_void, dwtype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "SetProviderType", 44usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).SetProviderType)(this, dwtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeySpec(this: *mut core::ffi::c_void, pdw: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "KeySpec", 45usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).KeySpec)(this, pdw), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeySpec(this: *mut core::ffi::c_void, dw: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "SetKeySpec", 46usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).SetKeySpec)(this, dw), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderFlags(this: *mut core::ffi::c_void, pdwflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "ProviderFlags", 47usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).ProviderFlags)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProviderFlags(this: *mut core::ffi::c_void, dwflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "SetProviderFlags", 48usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).SetProviderFlags)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseExistingKeySet(this: *mut core::ffi::c_void, fuseexistingkeys: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "UseExistingKeySet", 49usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).UseExistingKeySet)(this, fuseexistingkeys), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUseExistingKeySet(this: *mut core::ffi::c_void, fuseexistingkeys: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "SetUseExistingKeySet", 50usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).SetUseExistingKeySet)(this, fuseexistingkeys), |result| Some(*result))
+        }
+        unsafe extern "system" fn GenKeyFlags(this: *mut core::ffi::c_void, pdwflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "GenKeyFlags", 51usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).GenKeyFlags)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGenKeyFlags(this: *mut core::ffi::c_void, dwflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "SetGenKeyFlags", 52usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).SetGenKeyFlags)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteRequestCert(this: *mut core::ffi::c_void, fdelete: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "DeleteRequestCert", 53usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).DeleteRequestCert)(this, fdelete), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeleteRequestCert(this: *mut core::ffi::c_void, fdelete: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "SetDeleteRequestCert", 54usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).SetDeleteRequestCert)(this, fdelete), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteCertToCSP(this: *mut core::ffi::c_void, fbool: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "WriteCertToCSP", 55usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).WriteCertToCSP)(this, fbool), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWriteCertToCSP(this: *mut core::ffi::c_void, fbool: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "SetWriteCertToCSP", 56usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).SetWriteCertToCSP)(this, fbool), |result| Some(*result))
+        }
+        unsafe extern "system" fn SPCFileName(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "SPCFileName", 57usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).SPCFileName)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSPCFileName(this: *mut core::ffi::c_void, bstr: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "SetSPCFileName", 58usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).SetSPCFileName)(this, bstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn PVKFileName(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "PVKFileName", 59usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).PVKFileName)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPVKFileName(this: *mut core::ffi::c_void, bstr: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "SetPVKFileName", 60usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).SetPVKFileName)(this, bstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn HashAlgorithm(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "HashAlgorithm", 61usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).HashAlgorithm)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHashAlgorithm(this: *mut core::ffi::c_void, bstr: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll", "SetHashAlgorithm", 62usize, |this| ((**(this as *const *const ICEnroll_Vtbl)).SetHashAlgorithm)(this, bstr), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            createFilePKCS10,
+            acceptFilePKCS7,
+            createPKCS10,
+            acceptPKCS7,
+            getCertFromPKCS7,
+            enumProviders,
+            enumContainers,
+            freeRequestInfo,
+            MyStoreName,
+            SetMyStoreName,
+            MyStoreType,
+            SetMyStoreType,
+            MyStoreFlags,
+            SetMyStoreFlags,
+            CAStoreName,
+            SetCAStoreName,
+            CAStoreType,
+            SetCAStoreType,
+            CAStoreFlags,
+            SetCAStoreFlags,
+            RootStoreName,
+            SetRootStoreName,
+            RootStoreType,
+            SetRootStoreType,
+            RootStoreFlags,
+            SetRootStoreFlags,
+            RequestStoreName,
+            SetRequestStoreName,
+            RequestStoreType,
+            SetRequestStoreType,
+            RequestStoreFlags,
+            SetRequestStoreFlags,
+            ContainerName,
+            SetContainerName,
+            ProviderName,
+            SetProviderName,
+            ProviderType,
+            SetProviderType,
+            KeySpec,
+            SetKeySpec,
+            ProviderFlags,
+            SetProviderFlags,
+            UseExistingKeySet,
+            SetUseExistingKeySet,
+            GenKeyFlags,
+            SetGenKeyFlags,
+            DeleteRequestCert,
+            SetDeleteRequestCert,
+            WriteCertToCSP,
+            SetWriteCertToCSP,
+            SPCFileName,
+            SetSPCFileName,
+            PVKFileName,
+            SetPVKFileName,
+            HashAlgorithm,
+            SetHashAlgorithm,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICEnroll as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICEnroll2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn addCertTypeToRequest(this: *mut core::ffi::c_void, certtype: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll2", "addCertTypeToRequest", 63usize, |this| ((**(this as *const *const ICEnroll2_Vtbl)).addCertTypeToRequest)(this, certtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn addNameValuePairToSignature(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll2", "addNameValuePairToSignature", 64usize, |this| ((**(this as *const *const ICEnroll2_Vtbl)).addNameValuePairToSignature)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteCertToUserDS(this: *mut core::ffi::c_void, fbool: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll2", "WriteCertToUserDS", 65usize, |this| ((**(this as *const *const ICEnroll2_Vtbl)).WriteCertToUserDS)(this, fbool), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWriteCertToUserDS(this: *mut core::ffi::c_void, fbool: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll2", "SetWriteCertToUserDS", 66usize, |this| ((**(this as *const *const ICEnroll2_Vtbl)).SetWriteCertToUserDS)(this, fbool), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableT61DNEncoding(this: *mut core::ffi::c_void, fbool: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll2", "EnableT61DNEncoding", 67usize, |this| ((**(this as *const *const ICEnroll2_Vtbl)).EnableT61DNEncoding)(this, fbool), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnableT61DNEncoding(this: *mut core::ffi::c_void, fbool: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll2", "SetEnableT61DNEncoding", 68usize, |this| ((**(this as *const *const ICEnroll2_Vtbl)).SetEnableT61DNEncoding)(this, fbool), |result| Some(*result))
+        }
+        Self {
+            base__: <ICEnroll_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            addCertTypeToRequest,
+            addNameValuePairToSignature,
+            WriteCertToUserDS,
+            SetWriteCertToUserDS,
+            EnableT61DNEncoding,
+            SetEnableT61DNEncoding,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICEnroll2 as windows_core::Interface>::IID || <ICEnroll_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICEnroll3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InstallPKCS7(this: *mut core::ffi::c_void, pkcs7: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll3", "InstallPKCS7", 69usize, |this| ((**(this as *const *const ICEnroll3_Vtbl)).InstallPKCS7)(this, pkcs7), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll3", "Reset", 70usize, |this| ((**(this as *const *const ICEnroll3_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSupportedKeySpec(this: *mut core::ffi::c_void, pdwkeyspec: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll3", "GetSupportedKeySpec", 71usize, |this| ((**(this as *const *const ICEnroll3_Vtbl)).GetSupportedKeySpec)(this, pdwkeyspec), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKeyLen(this: *mut core::ffi::c_void, fmin: super::super::super::Foundation::BOOL, fexchange: super::super::super::Foundation::BOOL, pdwkeysize: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll3", "GetKeyLen", 72usize, |this| ((**(this as *const *const ICEnroll3_Vtbl)).GetKeyLen)(this, fmin, fexchange, pdwkeysize), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumAlgs(this: *mut core::ffi::c_void, dwindex: i32, algclass: i32, pdwalgid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll3", "EnumAlgs", 73usize, |this| ((**(this as *const *const ICEnroll3_Vtbl)).EnumAlgs)(this, dwindex, algclass, pdwalgid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAlgName(this: *mut core::ffi::c_void, algid: i32, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll3", "GetAlgName", 74usize, |this| ((**(this as *const *const ICEnroll3_Vtbl)).GetAlgName)(this, algid, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReuseHardwareKeyIfUnableToGenNew(this: *mut core::ffi::c_void, freusehardwarekeyifunabletogennew: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll3", "SetReuseHardwareKeyIfUnableToGenNew", 75usize, |this| ((**(this as *const *const ICEnroll3_Vtbl)).SetReuseHardwareKeyIfUnableToGenNew)(this, freusehardwarekeyifunabletogennew), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReuseHardwareKeyIfUnableToGenNew(this: *mut core::ffi::c_void, freusehardwarekeyifunabletogennew: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll3", "ReuseHardwareKeyIfUnableToGenNew", 76usize, |this| ((**(this as *const *const ICEnroll3_Vtbl)).ReuseHardwareKeyIfUnableToGenNew)(this, freusehardwarekeyifunabletogennew), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHashAlgID(this: *mut core::ffi::c_void, hashalgid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll3", "SetHashAlgID", 77usize, |this| ((**(this as *const *const ICEnroll3_Vtbl)).SetHashAlgID)(this, hashalgid), |result| Some(*result))
+        }
+        unsafe extern "system" fn HashAlgID(this: *mut core::ffi::c_void, hashalgid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll3", "HashAlgID", 78usize, |this| ((**(this as *const *const ICEnroll3_Vtbl)).HashAlgID)(this, hashalgid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLimitExchangeKeyToEncipherment(this: *mut core::ffi::c_void, flimitexchangekeytoencipherment: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll3", "SetLimitExchangeKeyToEncipherment", 79usize, |this| ((**(this as *const *const ICEnroll3_Vtbl)).SetLimitExchangeKeyToEncipherment)(this, flimitexchangekeytoencipherment), |result| Some(*result))
+        }
+        unsafe extern "system" fn LimitExchangeKeyToEncipherment(this: *mut core::ffi::c_void, flimitexchangekeytoencipherment: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll3", "LimitExchangeKeyToEncipherment", 80usize, |this| ((**(this as *const *const ICEnroll3_Vtbl)).LimitExchangeKeyToEncipherment)(this, flimitexchangekeytoencipherment), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnableSMIMECapabilities(this: *mut core::ffi::c_void, fenablesmimecapabilities: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll3", "SetEnableSMIMECapabilities", 81usize, |this| ((**(this as *const *const ICEnroll3_Vtbl)).SetEnableSMIMECapabilities)(this, fenablesmimecapabilities), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableSMIMECapabilities(this: *mut core::ffi::c_void, fenablesmimecapabilities: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll3", "EnableSMIMECapabilities", 82usize, |this| ((**(this as *const *const ICEnroll3_Vtbl)).EnableSMIMECapabilities)(this, fenablesmimecapabilities), |result| Some(*result))
+        }
+        Self {
+            base__: <ICEnroll2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InstallPKCS7,
+            Reset,
+            GetSupportedKeySpec,
+            GetKeyLen,
+            EnumAlgs,
+            GetAlgName,
+            SetReuseHardwareKeyIfUnableToGenNew,
+            ReuseHardwareKeyIfUnableToGenNew,
+            SetHashAlgID,
+            HashAlgID,
+            SetLimitExchangeKeyToEncipherment,
+            LimitExchangeKeyToEncipherment,
+            SetEnableSMIMECapabilities,
+            EnableSMIMECapabilities,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICEnroll3 as windows_core::Interface>::IID || <ICEnroll2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICEnroll4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPrivateKeyArchiveCertificate(this: *mut core::ffi::c_void, bstrcert: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "SetPrivateKeyArchiveCertificate", 83usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).SetPrivateKeyArchiveCertificate)(this, bstrcert), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrivateKeyArchiveCertificate(this: *mut core::ffi::c_void, pbstrcert: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "PrivateKeyArchiveCertificate", 84usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).PrivateKeyArchiveCertificate)(this, pbstrcert), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetThumbPrint(this: *mut core::ffi::c_void, bstrthumbprint: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "SetThumbPrint", 85usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).SetThumbPrint)(this, bstrthumbprint), |result| Some(*result))
+        }
+        unsafe extern "system" fn ThumbPrint(this: *mut core::ffi::c_void, pbstrthumbprint: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "ThumbPrint", 86usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).ThumbPrint)(this, pbstrthumbprint), |result| Some(*result))
+        }
+        unsafe extern "system" fn binaryToString(this: *mut core::ffi::c_void, flags: i32, strbinary: core::mem::MaybeUninit<windows_core::BSTR>, pstrencoded: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "binaryToString", 87usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).binaryToString)(this, flags, strbinary, pstrencoded), |result| Some(*result))
+        }
+        unsafe extern "system" fn stringToBinary(this: *mut core::ffi::c_void, flags: i32, strencoded: core::mem::MaybeUninit<windows_core::BSTR>, pstrbinary: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "stringToBinary", 88usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).stringToBinary)(this, flags, strencoded, pstrbinary), |result| Some(*result))
+        }
+        unsafe extern "system" fn addExtensionToRequest(this: *mut core::ffi::c_void, flags: i32, strname: core::mem::MaybeUninit<windows_core::BSTR>, strvalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "addExtensionToRequest", 89usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).addExtensionToRequest)(this, flags, strname, strvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn addAttributeToRequest(this: *mut core::ffi::c_void, flags: i32, strname: core::mem::MaybeUninit<windows_core::BSTR>, strvalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "addAttributeToRequest", 90usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).addAttributeToRequest)(this, flags, strname, strvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn addNameValuePairToRequest(this: *mut core::ffi::c_void, flags: i32, strname: core::mem::MaybeUninit<windows_core::BSTR>, strvalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "addNameValuePairToRequest", 91usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).addNameValuePairToRequest)(this, flags, strname, strvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn resetExtensions(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "resetExtensions", 92usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).resetExtensions)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn resetAttributes(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "resetAttributes", 93usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).resetAttributes)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn createRequest(this: *mut core::ffi::c_void, flags: CERT_CREATE_REQUEST_FLAGS, strdnname: core::mem::MaybeUninit<windows_core::BSTR>, usage: core::mem::MaybeUninit<windows_core::BSTR>, pstrrequest: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "createRequest", 94usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).createRequest)(this, flags, strdnname, usage, pstrrequest), |result| Some(*result))
+        }
+        unsafe extern "system" fn createFileRequest(this: *mut core::ffi::c_void, flags: CERT_CREATE_REQUEST_FLAGS, strdnname: core::mem::MaybeUninit<windows_core::BSTR>, strusage: core::mem::MaybeUninit<windows_core::BSTR>, strrequestfilename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "createFileRequest", 95usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).createFileRequest)(this, flags, strdnname, strusage, strrequestfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn acceptResponse(this: *mut core::ffi::c_void, strresponse: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "acceptResponse", 96usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).acceptResponse)(this, strresponse), |result| Some(*result))
+        }
+        unsafe extern "system" fn acceptFileResponse(this: *mut core::ffi::c_void, strresponsefilename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "acceptFileResponse", 97usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).acceptFileResponse)(this, strresponsefilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn getCertFromResponse(this: *mut core::ffi::c_void, strresponse: core::mem::MaybeUninit<windows_core::BSTR>, pstrcert: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "getCertFromResponse", 98usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).getCertFromResponse)(this, strresponse, pstrcert), |result| Some(*result))
+        }
+        unsafe extern "system" fn getCertFromFileResponse(this: *mut core::ffi::c_void, strresponsefilename: core::mem::MaybeUninit<windows_core::BSTR>, pstrcert: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "getCertFromFileResponse", 99usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).getCertFromFileResponse)(this, strresponsefilename, pstrcert), |result| Some(*result))
+        }
+        unsafe extern "system" fn createPFX(this: *mut core::ffi::c_void, strpassword: core::mem::MaybeUninit<windows_core::BSTR>, pstrpfx: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "createPFX", 100usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).createPFX)(this, strpassword, pstrpfx), |result| Some(*result))
+        }
+        unsafe extern "system" fn createFilePFX(this: *mut core::ffi::c_void, strpassword: core::mem::MaybeUninit<windows_core::BSTR>, strpfxfilename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "createFilePFX", 101usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).createFilePFX)(this, strpassword, strpfxfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn setPendingRequestInfo(this: *mut core::ffi::c_void, lrequestid: i32, strcadns: core::mem::MaybeUninit<windows_core::BSTR>, strcaname: core::mem::MaybeUninit<windows_core::BSTR>, strfriendlyname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "setPendingRequestInfo", 102usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).setPendingRequestInfo)(this, lrequestid, strcadns, strcaname, strfriendlyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn enumPendingRequest(this: *mut core::ffi::c_void, lindex: i32, ldesiredproperty: PENDING_REQUEST_DESIRED_PROPERTY, pvarproperty: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "enumPendingRequest", 103usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).enumPendingRequest)(this, lindex, ldesiredproperty, pvarproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn removePendingRequest(this: *mut core::ffi::c_void, strthumbprint: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "removePendingRequest", 104usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).removePendingRequest)(this, strthumbprint), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKeyLenEx(this: *mut core::ffi::c_void, lsizespec: XEKL_KEYSIZE, lkeyspec: XEKL_KEYSPEC, pdwkeysize: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "GetKeyLenEx", 105usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).GetKeyLenEx)(this, lsizespec, lkeyspec, pdwkeysize), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstallPKCS7Ex(this: *mut core::ffi::c_void, pkcs7: core::mem::MaybeUninit<windows_core::BSTR>, plcertinstalled: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "InstallPKCS7Ex", 106usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).InstallPKCS7Ex)(this, pkcs7, plcertinstalled), |result| Some(*result))
+        }
+        unsafe extern "system" fn addCertTypeToRequestEx(this: *mut core::ffi::c_void, ltype: ADDED_CERT_TYPE, bstroidorname: core::mem::MaybeUninit<windows_core::BSTR>, lmajorversion: i32, fminorversion: super::super::super::Foundation::BOOL, lminorversion: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "addCertTypeToRequestEx", 107usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).addCertTypeToRequestEx)(this, ltype, bstroidorname, lmajorversion, fminorversion, lminorversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn getProviderType(this: *mut core::ffi::c_void, strprovname: core::mem::MaybeUninit<windows_core::BSTR>, plprovtype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "getProviderType", 108usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).getProviderType)(this, strprovname, plprovtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSignerCertificate(this: *mut core::ffi::c_void, bstrcert: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "SetSignerCertificate", 109usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).SetSignerCertificate)(this, bstrcert), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClientId(this: *mut core::ffi::c_void, lclientid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "SetClientId", 110usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).SetClientId)(this, lclientid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClientId(this: *mut core::ffi::c_void, plclientid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "ClientId", 111usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).ClientId)(this, plclientid), |result| Some(*result))
+        }
+        unsafe extern "system" fn addBlobPropertyToCertificate(this: *mut core::ffi::c_void, lpropertyid: i32, lreserved: i32, bstrproperty: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "addBlobPropertyToCertificate", 112usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).addBlobPropertyToCertificate)(this, lpropertyid, lreserved, bstrproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn resetBlobProperties(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "resetBlobProperties", 113usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).resetBlobProperties)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIncludeSubjectKeyID(this: *mut core::ffi::c_void, finclude: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "SetIncludeSubjectKeyID", 114usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).SetIncludeSubjectKeyID)(this, finclude), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncludeSubjectKeyID(this: *mut core::ffi::c_void, pfinclude: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICEnroll4", "IncludeSubjectKeyID", 115usize, |this| ((**(this as *const *const ICEnroll4_Vtbl)).IncludeSubjectKeyID)(this, pfinclude), |result| Some(*result))
+        }
+        Self {
+            base__: <ICEnroll3_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetPrivateKeyArchiveCertificate,
+            PrivateKeyArchiveCertificate,
+            SetThumbPrint,
+            ThumbPrint,
+            binaryToString,
+            stringToBinary,
+            addExtensionToRequest,
+            addAttributeToRequest,
+            addNameValuePairToRequest,
+            resetExtensions,
+            resetAttributes,
+            createRequest,
+            createFileRequest,
+            acceptResponse,
+            acceptFileResponse,
+            getCertFromResponse,
+            getCertFromFileResponse,
+            createPFX,
+            createFilePFX,
+            setPendingRequestInfo,
+            enumPendingRequest,
+            removePendingRequest,
+            GetKeyLenEx,
+            InstallPKCS7Ex,
+            addCertTypeToRequestEx,
+            getProviderType,
+            SetSignerCertificate,
+            SetClientId,
+            ClientId,
+            addBlobPropertyToCertificate,
+            resetBlobProperties,
+            SetIncludeSubjectKeyID,
+            IncludeSubjectKeyID,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICEnroll4 as windows_core::Interface>::IID || <ICEnroll3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertAdmin_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsValidCertificate(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, strserialnumber: core::mem::MaybeUninit<windows_core::BSTR>, pdisposition: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertAdmin", "IsValidCertificate", 7usize, |this| ((**(this as *const *const ICertAdmin_Vtbl)).IsValidCertificate)(this, strconfig, strserialnumber, pdisposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRevocationReason(this: *mut core::ffi::c_void, preason: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertAdmin", "GetRevocationReason", 8usize, |this| ((**(this as *const *const ICertAdmin_Vtbl)).GetRevocationReason)(this, preason), |result| Some(*result))
+        }
+        unsafe extern "system" fn RevokeCertificate(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, strserialnumber: core::mem::MaybeUninit<windows_core::BSTR>, reason: i32, date: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertAdmin", "RevokeCertificate", 9usize, |this| ((**(this as *const *const ICertAdmin_Vtbl)).RevokeCertificate)(this, strconfig, strserialnumber, reason, date), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequestAttributes(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, requestid: i32, strattributes: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertAdmin", "SetRequestAttributes", 10usize, |this| ((**(this as *const *const ICertAdmin_Vtbl)).SetRequestAttributes)(this, strconfig, requestid, strattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCertificateExtension(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, requestid: i32, strextensionname: core::mem::MaybeUninit<windows_core::BSTR>, r#type: CERT_PROPERTY_TYPE, flags: i32, pvarvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertAdmin", "SetCertificateExtension", 11usize, |this| ((**(this as *const *const ICertAdmin_Vtbl)).SetCertificateExtension)(this, strconfig, requestid, strextensionname, r#type, flags, pvarvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn DenyRequest(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, requestid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertAdmin", "DenyRequest", 12usize, |this| ((**(this as *const *const ICertAdmin_Vtbl)).DenyRequest)(this, strconfig, requestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResubmitRequest(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, requestid: i32, pdisposition: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertAdmin", "ResubmitRequest", 13usize, |this| ((**(this as *const *const ICertAdmin_Vtbl)).ResubmitRequest)(this, strconfig, requestid, pdisposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn PublishCRL(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, date: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertAdmin", "PublishCRL", 14usize, |this| ((**(this as *const *const ICertAdmin_Vtbl)).PublishCRL)(this, strconfig, date), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCRL(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, flags: i32, pstrcrl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertAdmin", "GetCRL", 15usize, |this| ((**(this as *const *const ICertAdmin_Vtbl)).GetCRL)(this, strconfig, flags, pstrcrl), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImportCertificate(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, strcertificate: core::mem::MaybeUninit<windows_core::BSTR>, flags: CERT_IMPORT_FLAGS, prequestid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertAdmin", "ImportCertificate", 16usize, |this| ((**(this as *const *const ICertAdmin_Vtbl)).ImportCertificate)(this, strconfig, strcertificate, flags, prequestid), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsValidCertificate,
+            GetRevocationReason,
+            RevokeCertificate,
+            SetRequestAttributes,
+            SetCertificateExtension,
+            DenyRequest,
+            ResubmitRequest,
+            PublishCRL,
+            GetCRL,
+            ImportCertificate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertAdmin as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertAdmin2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PublishCRLs(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, date: f64, crlflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertAdmin2", "PublishCRLs", 17usize, |this| ((**(this as *const *const ICertAdmin2_Vtbl)).PublishCRLs)(this, strconfig, date, crlflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCAProperty(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, propid: i32, propindex: i32, proptype: i32, flags: i32, pvarpropertyvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertAdmin2", "GetCAProperty", 18usize, |this| ((**(this as *const *const ICertAdmin2_Vtbl)).GetCAProperty)(this, strconfig, propid, propindex, proptype, flags, pvarpropertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCAProperty(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, propid: i32, propindex: i32, proptype: CERT_PROPERTY_TYPE, pvarpropertyvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertAdmin2", "SetCAProperty", 19usize, |this| ((**(this as *const *const ICertAdmin2_Vtbl)).SetCAProperty)(this, strconfig, propid, propindex, proptype, pvarpropertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCAPropertyFlags(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, propid: i32, ppropflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertAdmin2", "GetCAPropertyFlags", 20usize, |this| ((**(this as *const *const ICertAdmin2_Vtbl)).GetCAPropertyFlags)(this, strconfig, propid, ppropflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCAPropertyDisplayName(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, propid: i32, pstrdisplayname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertAdmin2", "GetCAPropertyDisplayName", 21usize, |this| ((**(this as *const *const ICertAdmin2_Vtbl)).GetCAPropertyDisplayName)(this, strconfig, propid, pstrdisplayname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetArchivedKey(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, requestid: i32, flags: i32, pstrarchivedkey: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertAdmin2", "GetArchivedKey", 22usize, |this| ((**(this as *const *const ICertAdmin2_Vtbl)).GetArchivedKey)(this, strconfig, requestid, flags, pstrarchivedkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConfigEntry(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, strnodepath: core::mem::MaybeUninit<windows_core::BSTR>, strentryname: core::mem::MaybeUninit<windows_core::BSTR>, pvarentry: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertAdmin2", "GetConfigEntry", 23usize, |this| ((**(this as *const *const ICertAdmin2_Vtbl)).GetConfigEntry)(this, strconfig, strnodepath, strentryname, pvarentry), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConfigEntry(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, strnodepath: core::mem::MaybeUninit<windows_core::BSTR>, strentryname: core::mem::MaybeUninit<windows_core::BSTR>, pvarentry: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertAdmin2", "SetConfigEntry", 24usize, |this| ((**(this as *const *const ICertAdmin2_Vtbl)).SetConfigEntry)(this, strconfig, strnodepath, strentryname, pvarentry), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImportKey(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, requestid: i32, strcerthash: core::mem::MaybeUninit<windows_core::BSTR>, flags: CERT_IMPORT_FLAGS, strkey: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertAdmin2", "ImportKey", 25usize, |this| ((**(this as *const *const ICertAdmin2_Vtbl)).ImportKey)(this, strconfig, requestid, strcerthash, flags, strkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMyRoles(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, proles: *mut CERTADMIN_GET_ROLES_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertAdmin2", "GetMyRoles", 26usize, |this| ((**(this as *const *const ICertAdmin2_Vtbl)).GetMyRoles)(this, strconfig, proles), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteRow(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, flags: CERT_DELETE_ROW_FLAGS, date: f64, table: CVRC_TABLE, rowid: i32, pcdeleted: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertAdmin2", "DeleteRow", 27usize, |this| ((**(this as *const *const ICertAdmin2_Vtbl)).DeleteRow)(this, strconfig, flags, date, table, rowid, pcdeleted), |result| Some(*result))
+        }
+        Self {
+            base__: <ICertAdmin_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PublishCRLs,
+            GetCAProperty,
+            SetCAProperty,
+            GetCAPropertyFlags,
+            GetCAPropertyDisplayName,
+            GetArchivedKey,
+            GetConfigEntry,
+            SetConfigEntry,
+            ImportKey,
+            GetMyRoles,
+            DeleteRow,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertAdmin2 as windows_core::Interface>::IID || <ICertAdmin_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void, index: i32, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertConfig", "Reset", 7usize, |this| ((**(this as *const *const ICertConfig_Vtbl)).Reset)(this, index, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, pindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertConfig", "Next", 8usize, |this| ((**(this as *const *const ICertConfig_Vtbl)).Next)(this, pindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetField(this: *mut core::ffi::c_void, strfieldname: core::mem::MaybeUninit<windows_core::BSTR>, pstrout: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertConfig", "GetField", 9usize, |this| ((**(this as *const *const ICertConfig_Vtbl)).GetField)(this, strfieldname, pstrout), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConfig(this: *mut core::ffi::c_void, flags: i32, pstrout: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertConfig", "GetConfig", 10usize, |this| ((**(this as *const *const ICertConfig_Vtbl)).GetConfig)(this, flags, pstrout), |result| Some(*result))
+        }
+        Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reset, Next, GetField, GetConfig }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertConfig as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertConfig2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSharedFolder(this: *mut core::ffi::c_void, strsharedfolder: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertConfig2", "SetSharedFolder", 11usize, |this| ((**(this as *const *const ICertConfig2_Vtbl)).SetSharedFolder)(this, strsharedfolder), |result| Some(*result))
+        }
+        Self { base__: <ICertConfig_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetSharedFolder }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertConfig2 as windows_core::Interface>::IID || <ICertConfig_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertEncodeAltName_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Decode(this: *mut core::ffi::c_void, strbinary: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeAltName", "Decode", 7usize, |this| ((**(this as *const *const ICertEncodeAltName_Vtbl)).Decode)(this, strbinary), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNameCount(this: *mut core::ffi::c_void, pnamecount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeAltName", "GetNameCount", 8usize, |this| ((**(this as *const *const ICertEncodeAltName_Vtbl)).GetNameCount)(this, pnamecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNameChoice(this: *mut core::ffi::c_void, nameindex: i32, pnamechoice: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeAltName", "GetNameChoice", 9usize, |this| ((**(this as *const *const ICertEncodeAltName_Vtbl)).GetNameChoice)(this, nameindex, pnamechoice), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, nameindex: i32, pstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeAltName", "GetName", 10usize, |this| ((**(this as *const *const ICertEncodeAltName_Vtbl)).GetName)(this, nameindex, pstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void, namecount: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeAltName", "Reset", 11usize, |this| ((**(this as *const *const ICertEncodeAltName_Vtbl)).Reset)(this, namecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNameEntry(this: *mut core::ffi::c_void, nameindex: i32, namechoice: CERT_ALT_NAME, strname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeAltName", "SetNameEntry", 12usize, |this| ((**(this as *const *const ICertEncodeAltName_Vtbl)).SetNameEntry)(this, nameindex, namechoice, strname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Encode(this: *mut core::ffi::c_void, pstrbinary: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeAltName", "Encode", 13usize, |this| ((**(this as *const *const ICertEncodeAltName_Vtbl)).Encode)(this, pstrbinary), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Decode,
+            GetNameCount,
+            GetNameChoice,
+            GetName,
+            Reset,
+            SetNameEntry,
+            Encode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertEncodeAltName as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertEncodeAltName2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DecodeBlob(this: *mut core::ffi::c_void, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>, encoding: EncodingType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeAltName2", "DecodeBlob", 14usize, |this| ((**(this as *const *const ICertEncodeAltName2_Vtbl)).DecodeBlob)(this, strencodeddata, encoding), |result| Some(*result))
+        }
+        unsafe extern "system" fn EncodeBlob(this: *mut core::ffi::c_void, encoding: EncodingType, pstrencodeddata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeAltName2", "EncodeBlob", 15usize, |this| ((**(this as *const *const ICertEncodeAltName2_Vtbl)).EncodeBlob)(this, encoding, pstrencodeddata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNameBlob(this: *mut core::ffi::c_void, nameindex: i32, encoding: EncodingType, pstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeAltName2", "GetNameBlob", 16usize, |this| ((**(this as *const *const ICertEncodeAltName2_Vtbl)).GetNameBlob)(this, nameindex, encoding, pstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNameEntryBlob(this: *mut core::ffi::c_void, nameindex: i32, namechoice: i32, strname: core::mem::MaybeUninit<windows_core::BSTR>, encoding: EncodingType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeAltName2", "SetNameEntryBlob", 17usize, |this| ((**(this as *const *const ICertEncodeAltName2_Vtbl)).SetNameEntryBlob)(this, nameindex, namechoice, strname, encoding), |result| Some(*result))
+        }
+        Self { base__: <ICertEncodeAltName_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DecodeBlob, EncodeBlob, GetNameBlob, SetNameEntryBlob }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertEncodeAltName2 as windows_core::Interface>::IID || <ICertEncodeAltName_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertEncodeBitString_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Decode(this: *mut core::ffi::c_void, strbinary: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeBitString", "Decode", 7usize, |this| ((**(this as *const *const ICertEncodeBitString_Vtbl)).Decode)(this, strbinary), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBitCount(this: *mut core::ffi::c_void, pbitcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeBitString", "GetBitCount", 8usize, |this| ((**(this as *const *const ICertEncodeBitString_Vtbl)).GetBitCount)(this, pbitcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBitString(this: *mut core::ffi::c_void, pstrbitstring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeBitString", "GetBitString", 9usize, |this| ((**(this as *const *const ICertEncodeBitString_Vtbl)).GetBitString)(this, pstrbitstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn Encode(this: *mut core::ffi::c_void, bitcount: i32, strbitstring: core::mem::MaybeUninit<windows_core::BSTR>, pstrbinary: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeBitString", "Encode", 10usize, |this| ((**(this as *const *const ICertEncodeBitString_Vtbl)).Encode)(this, bitcount, strbitstring, pstrbinary), |result| Some(*result))
+        }
+        Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Decode, GetBitCount, GetBitString, Encode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertEncodeBitString as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertEncodeBitString2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DecodeBlob(this: *mut core::ffi::c_void, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>, encoding: EncodingType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeBitString2", "DecodeBlob", 11usize, |this| ((**(this as *const *const ICertEncodeBitString2_Vtbl)).DecodeBlob)(this, strencodeddata, encoding), |result| Some(*result))
+        }
+        unsafe extern "system" fn EncodeBlob(this: *mut core::ffi::c_void, bitcount: i32, strbitstring: core::mem::MaybeUninit<windows_core::BSTR>, encodingin: EncodingType, encoding: EncodingType, pstrencodeddata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeBitString2", "EncodeBlob", 12usize, |this| ((**(this as *const *const ICertEncodeBitString2_Vtbl)).EncodeBlob)(this, bitcount, strbitstring, encodingin, encoding, pstrencodeddata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBitStringBlob(this: *mut core::ffi::c_void, encoding: EncodingType, pstrbitstring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeBitString2", "GetBitStringBlob", 13usize, |this| ((**(this as *const *const ICertEncodeBitString2_Vtbl)).GetBitStringBlob)(this, encoding, pstrbitstring), |result| Some(*result))
+        }
+        Self { base__: <ICertEncodeBitString_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DecodeBlob, EncodeBlob, GetBitStringBlob }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertEncodeBitString2 as windows_core::Interface>::IID || <ICertEncodeBitString_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertEncodeCRLDistInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Decode(this: *mut core::ffi::c_void, strbinary: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeCRLDistInfo", "Decode", 7usize, |this| ((**(this as *const *const ICertEncodeCRLDistInfo_Vtbl)).Decode)(this, strbinary), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDistPointCount(this: *mut core::ffi::c_void, pdistpointcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeCRLDistInfo", "GetDistPointCount", 8usize, |this| ((**(this as *const *const ICertEncodeCRLDistInfo_Vtbl)).GetDistPointCount)(this, pdistpointcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNameCount(this: *mut core::ffi::c_void, distpointindex: i32, pnamecount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeCRLDistInfo", "GetNameCount", 9usize, |this| ((**(this as *const *const ICertEncodeCRLDistInfo_Vtbl)).GetNameCount)(this, distpointindex, pnamecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNameChoice(this: *mut core::ffi::c_void, distpointindex: i32, nameindex: i32, pnamechoice: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeCRLDistInfo", "GetNameChoice", 10usize, |this| ((**(this as *const *const ICertEncodeCRLDistInfo_Vtbl)).GetNameChoice)(this, distpointindex, nameindex, pnamechoice), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, distpointindex: i32, nameindex: i32, pstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeCRLDistInfo", "GetName", 11usize, |this| ((**(this as *const *const ICertEncodeCRLDistInfo_Vtbl)).GetName)(this, distpointindex, nameindex, pstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void, distpointcount: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeCRLDistInfo", "Reset", 12usize, |this| ((**(this as *const *const ICertEncodeCRLDistInfo_Vtbl)).Reset)(this, distpointcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNameCount(this: *mut core::ffi::c_void, distpointindex: i32, namecount: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeCRLDistInfo", "SetNameCount", 13usize, |this| ((**(this as *const *const ICertEncodeCRLDistInfo_Vtbl)).SetNameCount)(this, distpointindex, namecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNameEntry(this: *mut core::ffi::c_void, distpointindex: i32, nameindex: i32, namechoice: CERT_ALT_NAME, strname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeCRLDistInfo", "SetNameEntry", 14usize, |this| ((**(this as *const *const ICertEncodeCRLDistInfo_Vtbl)).SetNameEntry)(this, distpointindex, nameindex, namechoice, strname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Encode(this: *mut core::ffi::c_void, pstrbinary: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeCRLDistInfo", "Encode", 15usize, |this| ((**(this as *const *const ICertEncodeCRLDistInfo_Vtbl)).Encode)(this, pstrbinary), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Decode,
+            GetDistPointCount,
+            GetNameCount,
+            GetNameChoice,
+            GetName,
+            Reset,
+            SetNameCount,
+            SetNameEntry,
+            Encode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertEncodeCRLDistInfo as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertEncodeCRLDistInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DecodeBlob(this: *mut core::ffi::c_void, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>, encoding: EncodingType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeCRLDistInfo2", "DecodeBlob", 16usize, |this| ((**(this as *const *const ICertEncodeCRLDistInfo2_Vtbl)).DecodeBlob)(this, strencodeddata, encoding), |result| Some(*result))
+        }
+        unsafe extern "system" fn EncodeBlob(this: *mut core::ffi::c_void, encoding: EncodingType, pstrencodeddata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeCRLDistInfo2", "EncodeBlob", 17usize, |this| ((**(this as *const *const ICertEncodeCRLDistInfo2_Vtbl)).EncodeBlob)(this, encoding, pstrencodeddata), |result| Some(*result))
+        }
+        Self { base__: <ICertEncodeCRLDistInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DecodeBlob, EncodeBlob }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertEncodeCRLDistInfo2 as windows_core::Interface>::IID || <ICertEncodeCRLDistInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertEncodeDateArray_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Decode(this: *mut core::ffi::c_void, strbinary: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeDateArray", "Decode", 7usize, |this| ((**(this as *const *const ICertEncodeDateArray_Vtbl)).Decode)(this, strbinary), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeDateArray", "GetCount", 8usize, |this| ((**(this as *const *const ICertEncodeDateArray_Vtbl)).GetCount)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, index: i32, pvalue: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeDateArray", "GetValue", 9usize, |this| ((**(this as *const *const ICertEncodeDateArray_Vtbl)).GetValue)(this, index, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void, count: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeDateArray", "Reset", 10usize, |this| ((**(this as *const *const ICertEncodeDateArray_Vtbl)).Reset)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, index: i32, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeDateArray", "SetValue", 11usize, |this| ((**(this as *const *const ICertEncodeDateArray_Vtbl)).SetValue)(this, index, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Encode(this: *mut core::ffi::c_void, pstrbinary: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeDateArray", "Encode", 12usize, |this| ((**(this as *const *const ICertEncodeDateArray_Vtbl)).Encode)(this, pstrbinary), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Decode,
+            GetCount,
+            GetValue,
+            Reset,
+            SetValue,
+            Encode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertEncodeDateArray as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertEncodeDateArray2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DecodeBlob(this: *mut core::ffi::c_void, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>, encoding: EncodingType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeDateArray2", "DecodeBlob", 13usize, |this| ((**(this as *const *const ICertEncodeDateArray2_Vtbl)).DecodeBlob)(this, strencodeddata, encoding), |result| Some(*result))
+        }
+        unsafe extern "system" fn EncodeBlob(this: *mut core::ffi::c_void, encoding: EncodingType, pstrencodeddata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeDateArray2", "EncodeBlob", 14usize, |this| ((**(this as *const *const ICertEncodeDateArray2_Vtbl)).EncodeBlob)(this, encoding, pstrencodeddata), |result| Some(*result))
+        }
+        Self { base__: <ICertEncodeDateArray_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DecodeBlob, EncodeBlob }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertEncodeDateArray2 as windows_core::Interface>::IID || <ICertEncodeDateArray_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertEncodeLongArray_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Decode(this: *mut core::ffi::c_void, strbinary: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeLongArray", "Decode", 7usize, |this| ((**(this as *const *const ICertEncodeLongArray_Vtbl)).Decode)(this, strbinary), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeLongArray", "GetCount", 8usize, |this| ((**(this as *const *const ICertEncodeLongArray_Vtbl)).GetCount)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, index: i32, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeLongArray", "GetValue", 9usize, |this| ((**(this as *const *const ICertEncodeLongArray_Vtbl)).GetValue)(this, index, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void, count: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeLongArray", "Reset", 10usize, |this| ((**(this as *const *const ICertEncodeLongArray_Vtbl)).Reset)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, index: i32, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeLongArray", "SetValue", 11usize, |this| ((**(this as *const *const ICertEncodeLongArray_Vtbl)).SetValue)(this, index, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Encode(this: *mut core::ffi::c_void, pstrbinary: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeLongArray", "Encode", 12usize, |this| ((**(this as *const *const ICertEncodeLongArray_Vtbl)).Encode)(this, pstrbinary), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Decode,
+            GetCount,
+            GetValue,
+            Reset,
+            SetValue,
+            Encode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertEncodeLongArray as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertEncodeLongArray2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DecodeBlob(this: *mut core::ffi::c_void, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>, encoding: EncodingType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeLongArray2", "DecodeBlob", 13usize, |this| ((**(this as *const *const ICertEncodeLongArray2_Vtbl)).DecodeBlob)(this, strencodeddata, encoding), |result| Some(*result))
+        }
+        unsafe extern "system" fn EncodeBlob(this: *mut core::ffi::c_void, encoding: EncodingType, pstrencodeddata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeLongArray2", "EncodeBlob", 14usize, |this| ((**(this as *const *const ICertEncodeLongArray2_Vtbl)).EncodeBlob)(this, encoding, pstrencodeddata), |result| Some(*result))
+        }
+        Self { base__: <ICertEncodeLongArray_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DecodeBlob, EncodeBlob }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertEncodeLongArray2 as windows_core::Interface>::IID || <ICertEncodeLongArray_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertEncodeStringArray_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Decode(this: *mut core::ffi::c_void, strbinary: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeStringArray", "Decode", 7usize, |this| ((**(this as *const *const ICertEncodeStringArray_Vtbl)).Decode)(this, strbinary), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStringType(this: *mut core::ffi::c_void, pstringtype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeStringArray", "GetStringType", 8usize, |this| ((**(this as *const *const ICertEncodeStringArray_Vtbl)).GetStringType)(this, pstringtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeStringArray", "GetCount", 9usize, |this| ((**(this as *const *const ICertEncodeStringArray_Vtbl)).GetCount)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, index: i32, pstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeStringArray", "GetValue", 10usize, |this| ((**(this as *const *const ICertEncodeStringArray_Vtbl)).GetValue)(this, index, pstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void, count: i32, stringtype: super::CERT_RDN_ATTR_VALUE_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeStringArray", "Reset", 11usize, |this| ((**(this as *const *const ICertEncodeStringArray_Vtbl)).Reset)(this, count, stringtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, index: i32, str: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeStringArray", "SetValue", 12usize, |this| ((**(this as *const *const ICertEncodeStringArray_Vtbl)).SetValue)(this, index, str), |result| Some(*result))
+        }
+        unsafe extern "system" fn Encode(this: *mut core::ffi::c_void, pstrbinary: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeStringArray", "Encode", 13usize, |this| ((**(this as *const *const ICertEncodeStringArray_Vtbl)).Encode)(this, pstrbinary), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Decode,
+            GetStringType,
+            GetCount,
+            GetValue,
+            Reset,
+            SetValue,
+            Encode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertEncodeStringArray as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertEncodeStringArray2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DecodeBlob(this: *mut core::ffi::c_void, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>, encoding: EncodingType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeStringArray2", "DecodeBlob", 14usize, |this| ((**(this as *const *const ICertEncodeStringArray2_Vtbl)).DecodeBlob)(this, strencodeddata, encoding), |result| Some(*result))
+        }
+        unsafe extern "system" fn EncodeBlob(this: *mut core::ffi::c_void, encoding: EncodingType, pstrencodeddata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertEncodeStringArray2", "EncodeBlob", 15usize, |this| ((**(this as *const *const ICertEncodeStringArray2_Vtbl)).EncodeBlob)(this, encoding, pstrencodeddata), |result| Some(*result))
+        }
+        Self { base__: <ICertEncodeStringArray_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DecodeBlob, EncodeBlob }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertEncodeStringArray2 as windows_core::Interface>::IID || <ICertEncodeStringArray_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertExit_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, peventmask: *mut CERT_EXIT_EVENT_MASK) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertExit", "Initialize", 7usize, |this| ((**(this as *const *const ICertExit_Vtbl)).Initialize)(this, strconfig, peventmask), |result| Some(*result))
+        }
+        unsafe extern "system" fn Notify(this: *mut core::ffi::c_void, exitevent: i32, context: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertExit", "Notify", 8usize, |this| ((**(this as *const *const ICertExit_Vtbl)).Notify)(this, exitevent, context), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescription(this: *mut core::ffi::c_void, pstrdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertExit", "GetDescription", 9usize, |this| ((**(this as *const *const ICertExit_Vtbl)).GetDescription)(this, pstrdescription), |result| Some(*result))
+        }
+        Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, Notify, GetDescription }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertExit as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertExit2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetManageModule(this: *mut core::ffi::c_void, ppmanagemodule: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertExit2", "GetManageModule", 10usize, |this| ((**(this as *const *const ICertExit2_Vtbl)).GetManageModule)(this, ppmanagemodule), |result| Some(*result))
+        }
+        Self {
+            base__: <ICertExit_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetManageModule,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetManageModule: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertExit2 as windows_core::Interface>::IID || <ICertExit_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertGetConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetConfig(this: *mut core::ffi::c_void, flags: CERT_GET_CONFIG_FLAGS, pstrout: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertGetConfig", "GetConfig", 7usize, |this| ((**(this as *const *const ICertGetConfig_Vtbl)).GetConfig)(this, flags, pstrout), |result| Some(*result))
+        }
+        Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetConfig }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertGetConfig as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertManageModule_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, strstoragelocation: core::mem::MaybeUninit<windows_core::BSTR>, strpropertyname: core::mem::MaybeUninit<windows_core::BSTR>, flags: i32, pvarproperty: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertManageModule", "GetProperty", 7usize, |this| ((**(this as *const *const ICertManageModule_Vtbl)).GetProperty)(this, strconfig, strstoragelocation, strpropertyname, flags, pvarproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, strstoragelocation: core::mem::MaybeUninit<windows_core::BSTR>, strpropertyname: core::mem::MaybeUninit<windows_core::BSTR>, flags: i32, pvarproperty: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertManageModule", "SetProperty", 8usize, |this| ((**(this as *const *const ICertManageModule_Vtbl)).SetProperty)(this, strconfig, strstoragelocation, strpropertyname, flags, pvarproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn Configure(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, strstoragelocation: core::mem::MaybeUninit<windows_core::BSTR>, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertManageModule", "Configure", 9usize, |this| ((**(this as *const *const ICertManageModule_Vtbl)).Configure)(this, strconfig, strstoragelocation, flags), |result| Some(*result))
+        }
+        Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProperty, SetProperty, Configure }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertManageModule as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertPolicy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPolicy", "Initialize", 7usize, |this| ((**(this as *const *const ICertPolicy_Vtbl)).Initialize)(this, strconfig), |result| Some(*result))
+        }
+        unsafe extern "system" fn VerifyRequest(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, context: i32, bnewrequest: i32, flags: i32, pdisposition: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPolicy", "VerifyRequest", 8usize, |this| ((**(this as *const *const ICertPolicy_Vtbl)).VerifyRequest)(this, strconfig, context, bnewrequest, flags, pdisposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescription(this: *mut core::ffi::c_void, pstrdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPolicy", "GetDescription", 9usize, |this| ((**(this as *const *const ICertPolicy_Vtbl)).GetDescription)(this, pstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShutDown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPolicy", "ShutDown", 10usize, |this| ((**(this as *const *const ICertPolicy_Vtbl)).ShutDown)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            VerifyRequest,
+            GetDescription,
+            ShutDown,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertPolicy as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertPolicy2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetManageModule(this: *mut core::ffi::c_void, ppmanagemodule: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPolicy2", "GetManageModule", 11usize, |this| ((**(this as *const *const ICertPolicy2_Vtbl)).GetManageModule)(this, ppmanagemodule), |result| Some(*result))
+        }
+        Self {
+            base__: <ICertPolicy_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetManageModule,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetManageModule: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertPolicy2 as windows_core::Interface>::IID || <ICertPolicy_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertProperties_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ItemByIndex(this: *mut core::ffi::c_void, index: i32, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertProperties", "get_ItemByIndex", 7usize, |this| ((**(this as *const *const ICertProperties_Vtbl)).get_ItemByIndex)(this, index, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertProperties", "Count", 8usize, |this| ((**(this as *const *const ICertProperties_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertProperties", "_NewEnum", 9usize, |this| ((**(this as *const *const ICertProperties_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertProperties", "Add", 10usize, |this| ((**(this as *const *const ICertProperties_Vtbl)).Add)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertProperties", "Remove", 11usize, |this| ((**(this as *const *const ICertProperties_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertProperties", "Clear", 12usize, |this| ((**(this as *const *const ICertProperties_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeFromCertificate(this: *mut core::ffi::c_void, machinecontext: super::super::super::Foundation::VARIANT_BOOL, encoding: EncodingType, strcertificate: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertProperties", "InitializeFromCertificate", 13usize, |this| ((**(this as *const *const ICertProperties_Vtbl)).InitializeFromCertificate)(this, machinecontext, encoding, strcertificate), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ItemByIndex,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ItemByIndex: 0,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            Clear,
+            InitializeFromCertificate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertProperties as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertProperty_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeFromCertificate(this: *mut core::ffi::c_void, machinecontext: super::super::super::Foundation::VARIANT_BOOL, encoding: EncodingType, strcertificate: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertProperty", "InitializeFromCertificate", 7usize, |this| ((**(this as *const *const ICertProperty_Vtbl)).InitializeFromCertificate)(this, machinecontext, encoding, strcertificate), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeDecode(this: *mut core::ffi::c_void, encoding: EncodingType, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertProperty", "InitializeDecode", 8usize, |this| ((**(this as *const *const ICertProperty_Vtbl)).InitializeDecode)(this, encoding, strencodeddata), |result| Some(*result))
+        }
+        unsafe extern "system" fn PropertyId(this: *mut core::ffi::c_void, pvalue: *mut CERTENROLL_PROPERTYID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertProperty", "PropertyId", 9usize, |this| ((**(this as *const *const ICertProperty_Vtbl)).PropertyId)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPropertyId(this: *mut core::ffi::c_void, value: CERTENROLL_PROPERTYID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertProperty", "SetPropertyId", 10usize, |this| ((**(this as *const *const ICertProperty_Vtbl)).SetPropertyId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_RawData(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertProperty", "get_RawData", 11usize, |this| ((**(this as *const *const ICertProperty_Vtbl)).get_RawData)(this, encoding, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFromCertificate(this: *mut core::ffi::c_void, machinecontext: super::super::super::Foundation::VARIANT_BOOL, encoding: EncodingType, strcertificate: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertProperty", "RemoveFromCertificate", 12usize, |this| ((**(this as *const *const ICertProperty_Vtbl)).RemoveFromCertificate)(this, machinecontext, encoding, strcertificate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValueOnCertificate(this: *mut core::ffi::c_void, machinecontext: super::super::super::Foundation::VARIANT_BOOL, encoding: EncodingType, strcertificate: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertProperty", "SetValueOnCertificate", 13usize, |this| ((**(this as *const *const ICertProperty_Vtbl)).SetValueOnCertificate)(this, machinecontext, encoding, strcertificate), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitializeFromCertificate,
+            InitializeDecode,
+            PropertyId,
+            SetPropertyId,
+            get_RawData,
+            RemoveFromCertificate,
+            SetValueOnCertificate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertProperty as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertPropertyArchived_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, archivedvalue: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyArchived", "Initialize", 14usize, |this| ((**(this as *const *const ICertPropertyArchived_Vtbl)).Initialize)(this, archivedvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Archived(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyArchived", "Archived", 15usize, |this| ((**(this as *const *const ICertPropertyArchived_Vtbl)).Archived)(this, pvalue), |result| Some(*result))
+        }
+        Self { base__: <ICertProperty_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, Archived }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertPropertyArchived as windows_core::Interface>::IID || <ICertProperty_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertPropertyArchivedKeyHash_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, encoding: EncodingType, strarchivedkeyhashvalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyArchivedKeyHash", "Initialize", 14usize, |this| ((**(this as *const *const ICertPropertyArchivedKeyHash_Vtbl)).Initialize)(this, encoding, strarchivedkeyhashvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_ArchivedKeyHash(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyArchivedKeyHash", "get_ArchivedKeyHash", 15usize, |this| ((**(this as *const *const ICertPropertyArchivedKeyHash_Vtbl)).get_ArchivedKeyHash)(this, encoding, pvalue), |result| Some(*result))
+        }
+        Self { base__: <ICertProperty_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, get_ArchivedKeyHash }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertPropertyArchivedKeyHash as windows_core::Interface>::IID || <ICertProperty_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertPropertyAutoEnroll_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, strtemplatename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyAutoEnroll", "Initialize", 14usize, |this| ((**(this as *const *const ICertPropertyAutoEnroll_Vtbl)).Initialize)(this, strtemplatename), |result| Some(*result))
+        }
+        unsafe extern "system" fn TemplateName(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyAutoEnroll", "TemplateName", 15usize, |this| ((**(this as *const *const ICertPropertyAutoEnroll_Vtbl)).TemplateName)(this, pvalue), |result| Some(*result))
+        }
+        Self { base__: <ICertProperty_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, TemplateName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertPropertyAutoEnroll as windows_core::Interface>::IID || <ICertProperty_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertPropertyBackedUp_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeFromCurrentTime(this: *mut core::ffi::c_void, backedupvalue: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyBackedUp", "InitializeFromCurrentTime", 14usize, |this| ((**(this as *const *const ICertPropertyBackedUp_Vtbl)).InitializeFromCurrentTime)(this, backedupvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, backedupvalue: super::super::super::Foundation::VARIANT_BOOL, date: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyBackedUp", "Initialize", 15usize, |this| ((**(this as *const *const ICertPropertyBackedUp_Vtbl)).Initialize)(this, backedupvalue, date), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackedUpValue(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyBackedUp", "BackedUpValue", 16usize, |this| ((**(this as *const *const ICertPropertyBackedUp_Vtbl)).BackedUpValue)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackedUpTime(this: *mut core::ffi::c_void, pdate: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyBackedUp", "BackedUpTime", 17usize, |this| ((**(this as *const *const ICertPropertyBackedUp_Vtbl)).BackedUpTime)(this, pdate), |result| Some(*result))
+        }
+        Self { base__: <ICertProperty_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InitializeFromCurrentTime, Initialize, BackedUpValue, BackedUpTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertPropertyBackedUp as windows_core::Interface>::IID || <ICertProperty_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertPropertyDescription_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, strdescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyDescription", "Initialize", 14usize, |this| ((**(this as *const *const ICertPropertyDescription_Vtbl)).Initialize)(this, strdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyDescription", "Description", 15usize, |this| ((**(this as *const *const ICertPropertyDescription_Vtbl)).Description)(this, pvalue), |result| Some(*result))
+        }
+        Self { base__: <ICertProperty_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, Description }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertPropertyDescription as windows_core::Interface>::IID || <ICertProperty_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertPropertyEnrollment_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, requestid: i32, strcadnsname: core::mem::MaybeUninit<windows_core::BSTR>, strcaname: core::mem::MaybeUninit<windows_core::BSTR>, strfriendlyname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyEnrollment", "Initialize", 14usize, |this| ((**(this as *const *const ICertPropertyEnrollment_Vtbl)).Initialize)(this, requestid, strcadnsname, strcaname, strfriendlyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestId(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyEnrollment", "RequestId", 15usize, |this| ((**(this as *const *const ICertPropertyEnrollment_Vtbl)).RequestId)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn CADnsName(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyEnrollment", "CADnsName", 16usize, |this| ((**(this as *const *const ICertPropertyEnrollment_Vtbl)).CADnsName)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn CAName(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyEnrollment", "CAName", 17usize, |this| ((**(this as *const *const ICertPropertyEnrollment_Vtbl)).CAName)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn FriendlyName(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyEnrollment", "FriendlyName", 18usize, |this| ((**(this as *const *const ICertPropertyEnrollment_Vtbl)).FriendlyName)(this, pvalue), |result| Some(*result))
+        }
+        Self { base__: <ICertProperty_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, RequestId, CADnsName, CAName, FriendlyName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertPropertyEnrollment as windows_core::Interface>::IID || <ICertProperty_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertPropertyEnrollmentPolicyServer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, propertyflags: EnrollmentPolicyServerPropertyFlags, authflags: X509EnrollmentAuthFlags, enrollmentserverauthflags: X509EnrollmentAuthFlags, urlflags: PolicyServerUrlFlags, strrequestid: core::mem::MaybeUninit<windows_core::BSTR>, strurl: core::mem::MaybeUninit<windows_core::BSTR>, strid: core::mem::MaybeUninit<windows_core::BSTR>, strenrollmentserverurl: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyEnrollmentPolicyServer", "Initialize", 14usize, |this| ((**(this as *const *const ICertPropertyEnrollmentPolicyServer_Vtbl)).Initialize)(this, propertyflags, authflags, enrollmentserverauthflags, urlflags, strrequestid, strurl, strid, strenrollmentserverurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPolicyServerUrl(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyEnrollmentPolicyServer", "GetPolicyServerUrl", 15usize, |this| ((**(this as *const *const ICertPropertyEnrollmentPolicyServer_Vtbl)).GetPolicyServerUrl)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPolicyServerId(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyEnrollmentPolicyServer", "GetPolicyServerId", 16usize, |this| ((**(this as *const *const ICertPropertyEnrollmentPolicyServer_Vtbl)).GetPolicyServerId)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnrollmentServerUrl(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyEnrollmentPolicyServer", "GetEnrollmentServerUrl", 17usize, |this| ((**(this as *const *const ICertPropertyEnrollmentPolicyServer_Vtbl)).GetEnrollmentServerUrl)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRequestIdString(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyEnrollmentPolicyServer", "GetRequestIdString", 18usize, |this| ((**(this as *const *const ICertPropertyEnrollmentPolicyServer_Vtbl)).GetRequestIdString)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyFlags(this: *mut core::ffi::c_void, pvalue: *mut EnrollmentPolicyServerPropertyFlags) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyEnrollmentPolicyServer", "GetPropertyFlags", 19usize, |this| ((**(this as *const *const ICertPropertyEnrollmentPolicyServer_Vtbl)).GetPropertyFlags)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUrlFlags(this: *mut core::ffi::c_void, pvalue: *mut PolicyServerUrlFlags) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyEnrollmentPolicyServer", "GetUrlFlags", 20usize, |this| ((**(this as *const *const ICertPropertyEnrollmentPolicyServer_Vtbl)).GetUrlFlags)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAuthentication(this: *mut core::ffi::c_void, pvalue: *mut X509EnrollmentAuthFlags) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyEnrollmentPolicyServer", "GetAuthentication", 21usize, |this| ((**(this as *const *const ICertPropertyEnrollmentPolicyServer_Vtbl)).GetAuthentication)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnrollmentServerAuthentication(this: *mut core::ffi::c_void, pvalue: *mut X509EnrollmentAuthFlags) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyEnrollmentPolicyServer", "GetEnrollmentServerAuthentication", 22usize, |this| ((**(this as *const *const ICertPropertyEnrollmentPolicyServer_Vtbl)).GetEnrollmentServerAuthentication)(this, pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <ICertProperty_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetPolicyServerUrl,
+            GetPolicyServerId,
+            GetEnrollmentServerUrl,
+            GetRequestIdString,
+            GetPropertyFlags,
+            GetUrlFlags,
+            GetAuthentication,
+            GetEnrollmentServerAuthentication,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertPropertyEnrollmentPolicyServer as windows_core::Interface>::IID || <ICertProperty_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertPropertyFriendlyName_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, strfriendlyname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyFriendlyName", "Initialize", 14usize, |this| ((**(this as *const *const ICertPropertyFriendlyName_Vtbl)).Initialize)(this, strfriendlyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn FriendlyName(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyFriendlyName", "FriendlyName", 15usize, |this| ((**(this as *const *const ICertPropertyFriendlyName_Vtbl)).FriendlyName)(this, pvalue), |result| Some(*result))
+        }
+        Self { base__: <ICertProperty_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, FriendlyName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertPropertyFriendlyName as windows_core::Interface>::IID || <ICertProperty_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertPropertyKeyProvInfo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyKeyProvInfo", "Initialize", 14usize, |this| ((**(this as *const *const ICertPropertyKeyProvInfo_Vtbl)).Initialize)(this, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PrivateKey(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyKeyProvInfo", "PrivateKey", 15usize, |this| ((**(this as *const *const ICertPropertyKeyProvInfo_Vtbl)).PrivateKey)(this, ppvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <ICertProperty_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Initialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Initialize: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PrivateKey,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PrivateKey: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertPropertyKeyProvInfo as windows_core::Interface>::IID || <ICertProperty_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertPropertyRenewal_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, encoding: EncodingType, strrenewalvalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyRenewal", "Initialize", 14usize, |this| ((**(this as *const *const ICertPropertyRenewal_Vtbl)).Initialize)(this, encoding, strrenewalvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeFromCertificateHash(this: *mut core::ffi::c_void, machinecontext: super::super::super::Foundation::VARIANT_BOOL, encoding: EncodingType, strcertificate: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyRenewal", "InitializeFromCertificateHash", 15usize, |this| ((**(this as *const *const ICertPropertyRenewal_Vtbl)).InitializeFromCertificateHash)(this, machinecontext, encoding, strcertificate), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Renewal(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyRenewal", "get_Renewal", 16usize, |this| ((**(this as *const *const ICertPropertyRenewal_Vtbl)).get_Renewal)(this, encoding, pvalue), |result| Some(*result))
+        }
+        Self { base__: <ICertProperty_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, InitializeFromCertificateHash, get_Renewal }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertPropertyRenewal as windows_core::Interface>::IID || <ICertProperty_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertPropertyRequestOriginator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, strrequestoriginator: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyRequestOriginator", "Initialize", 14usize, |this| ((**(this as *const *const ICertPropertyRequestOriginator_Vtbl)).Initialize)(this, strrequestoriginator), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeFromLocalRequestOriginator(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyRequestOriginator", "InitializeFromLocalRequestOriginator", 15usize, |this| ((**(this as *const *const ICertPropertyRequestOriginator_Vtbl)).InitializeFromLocalRequestOriginator)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestOriginator(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertyRequestOriginator", "RequestOriginator", 16usize, |this| ((**(this as *const *const ICertPropertyRequestOriginator_Vtbl)).RequestOriginator)(this, pvalue), |result| Some(*result))
+        }
+        Self { base__: <ICertProperty_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, InitializeFromLocalRequestOriginator, RequestOriginator }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertPropertyRequestOriginator as windows_core::Interface>::IID || <ICertProperty_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertPropertySHA1Hash_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, encoding: EncodingType, strrenewalvalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertySHA1Hash", "Initialize", 14usize, |this| ((**(this as *const *const ICertPropertySHA1Hash_Vtbl)).Initialize)(this, encoding, strrenewalvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_SHA1Hash(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertPropertySHA1Hash", "get_SHA1Hash", 15usize, |this| ((**(this as *const *const ICertPropertySHA1Hash_Vtbl)).get_SHA1Hash)(this, encoding, pvalue), |result| Some(*result))
+        }
+        Self { base__: <ICertProperty_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, get_SHA1Hash }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertPropertySHA1Hash as windows_core::Interface>::IID || <ICertProperty_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Submit(this: *mut core::ffi::c_void, flags: i32, strrequest: core::mem::MaybeUninit<windows_core::BSTR>, strattributes: core::mem::MaybeUninit<windows_core::BSTR>, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, pdisposition: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertRequest", "Submit", 7usize, |this| ((**(this as *const *const ICertRequest_Vtbl)).Submit)(this, flags, strrequest, strattributes, strconfig, pdisposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn RetrievePending(this: *mut core::ffi::c_void, requestid: i32, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, pdisposition: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertRequest", "RetrievePending", 8usize, |this| ((**(this as *const *const ICertRequest_Vtbl)).RetrievePending)(this, requestid, strconfig, pdisposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastStatus(this: *mut core::ffi::c_void, pstatus: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertRequest", "GetLastStatus", 9usize, |this| ((**(this as *const *const ICertRequest_Vtbl)).GetLastStatus)(this, pstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRequestId(this: *mut core::ffi::c_void, prequestid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertRequest", "GetRequestId", 10usize, |this| ((**(this as *const *const ICertRequest_Vtbl)).GetRequestId)(this, prequestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDispositionMessage(this: *mut core::ffi::c_void, pstrdispositionmessage: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertRequest", "GetDispositionMessage", 11usize, |this| ((**(this as *const *const ICertRequest_Vtbl)).GetDispositionMessage)(this, pstrdispositionmessage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCACertificate(this: *mut core::ffi::c_void, fexchangecertificate: i32, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, flags: i32, pstrcertificate: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertRequest", "GetCACertificate", 12usize, |this| ((**(this as *const *const ICertRequest_Vtbl)).GetCACertificate)(this, fexchangecertificate, strconfig, flags, pstrcertificate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCertificate(this: *mut core::ffi::c_void, flags: i32, pstrcertificate: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertRequest", "GetCertificate", 13usize, |this| ((**(this as *const *const ICertRequest_Vtbl)).GetCertificate)(this, flags, pstrcertificate), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Submit,
+            RetrievePending,
+            GetLastStatus,
+            GetRequestId,
+            GetDispositionMessage,
+            GetCACertificate,
+            GetCertificate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertRequest as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertRequest2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetIssuedCertificate(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, requestid: i32, strserialnumber: core::mem::MaybeUninit<windows_core::BSTR>, pdisposition: *mut CR_DISP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertRequest2", "GetIssuedCertificate", 14usize, |this| ((**(this as *const *const ICertRequest2_Vtbl)).GetIssuedCertificate)(this, strconfig, requestid, strserialnumber, pdisposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetErrorMessageText(this: *mut core::ffi::c_void, hrmessage: i32, flags: i32, pstrerrormessagetext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertRequest2", "GetErrorMessageText", 15usize, |this| ((**(this as *const *const ICertRequest2_Vtbl)).GetErrorMessageText)(this, hrmessage, flags, pstrerrormessagetext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCAProperty(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, propid: i32, propindex: i32, proptype: i32, flags: i32, pvarpropertyvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertRequest2", "GetCAProperty", 16usize, |this| ((**(this as *const *const ICertRequest2_Vtbl)).GetCAProperty)(this, strconfig, propid, propindex, proptype, flags, pvarpropertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCAPropertyFlags(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, propid: i32, ppropflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertRequest2", "GetCAPropertyFlags", 17usize, |this| ((**(this as *const *const ICertRequest2_Vtbl)).GetCAPropertyFlags)(this, strconfig, propid, ppropflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCAPropertyDisplayName(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, propid: i32, pstrdisplayname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertRequest2", "GetCAPropertyDisplayName", 18usize, |this| ((**(this as *const *const ICertRequest2_Vtbl)).GetCAPropertyDisplayName)(this, strconfig, propid, pstrdisplayname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFullResponseProperty(this: *mut core::ffi::c_void, propid: FULL_RESPONSE_PROPERTY_ID, propindex: i32, proptype: CERT_PROPERTY_TYPE, flags: CERT_REQUEST_OUT_TYPE, pvarpropertyvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertRequest2", "GetFullResponseProperty", 19usize, |this| ((**(this as *const *const ICertRequest2_Vtbl)).GetFullResponseProperty)(this, propid, propindex, proptype, flags, pvarpropertyvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <ICertRequest_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetIssuedCertificate,
+            GetErrorMessageText,
+            GetCAProperty,
+            GetCAPropertyFlags,
+            GetCAPropertyDisplayName,
+            GetFullResponseProperty,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertRequest2 as windows_core::Interface>::IID || <ICertRequest_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertRequest3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetCredential(this: *mut core::ffi::c_void, hwnd: i32, authtype: X509EnrollmentAuthFlags, strcredential: core::mem::MaybeUninit<windows_core::BSTR>, strpassword: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertRequest3", "SetCredential", 20usize, |this| ((**(this as *const *const ICertRequest3_Vtbl)).SetCredential)(this, hwnd, authtype, strcredential, strpassword), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRequestIdString(this: *mut core::ffi::c_void, pstrrequestid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertRequest3", "GetRequestIdString", 21usize, |this| ((**(this as *const *const ICertRequest3_Vtbl)).GetRequestIdString)(this, pstrrequestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIssuedCertificate2(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>, strrequestid: core::mem::MaybeUninit<windows_core::BSTR>, strserialnumber: core::mem::MaybeUninit<windows_core::BSTR>, pdisposition: *mut CR_DISP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertRequest3", "GetIssuedCertificate2", 22usize, |this| ((**(this as *const *const ICertRequest3_Vtbl)).GetIssuedCertificate2)(this, strconfig, strrequestid, strserialnumber, pdisposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRefreshPolicy(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertRequest3", "GetRefreshPolicy", 23usize, |this| ((**(this as *const *const ICertRequest3_Vtbl)).GetRefreshPolicy)(this, pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <ICertRequest2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetCredential,
+            GetRequestIdString,
+            GetIssuedCertificate2,
+            GetRefreshPolicy,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertRequest3 as windows_core::Interface>::IID || <ICertRequest2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICertRequestD_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, dwflags: u32, pwszauthority: windows_core::PCWSTR, pdwrequestid: *mut u32, pdwdisposition: *mut u32, pwszattributes: windows_core::PCWSTR, pctbrequest: *const CERTTRANSBLOB, pctbcertchain: *mut CERTTRANSBLOB, pctbencodedcert: *mut CERTTRANSBLOB, pctbdispositionmessage: *mut CERTTRANSBLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertRequestD", "Request", 3usize, |this| ((**(this as *const *const ICertRequestD_Vtbl)).Request)(this, dwflags, pwszauthority, pdwrequestid, pdwdisposition, pwszattributes, pctbrequest, pctbcertchain, pctbencodedcert, pctbdispositionmessage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCACert(this: *mut core::ffi::c_void, fchain: u32, pwszauthority: windows_core::PCWSTR, pctbout: *mut CERTTRANSBLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertRequestD", "GetCACert", 4usize, |this| ((**(this as *const *const ICertRequestD_Vtbl)).GetCACert)(this, fchain, pwszauthority, pctbout), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ping(this: *mut core::ffi::c_void, pwszauthority: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertRequestD", "Ping", 5usize, |this| ((**(this as *const *const ICertRequestD_Vtbl)).Ping)(this, pwszauthority), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request, GetCACert, Ping }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertRequestD as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICertRequestD2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request2(this: *mut core::ffi::c_void, pwszauthority: windows_core::PCWSTR, dwflags: u32, pwszserialnumber: windows_core::PCWSTR, pdwrequestid: *mut u32, pdwdisposition: *mut u32, pwszattributes: windows_core::PCWSTR, pctbrequest: *const CERTTRANSBLOB, pctbfullresponse: *mut CERTTRANSBLOB, pctbencodedcert: *mut CERTTRANSBLOB, pctbdispositionmessage: *mut CERTTRANSBLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertRequestD2", "Request2", 6usize, |this| ((**(this as *const *const ICertRequestD2_Vtbl)).Request2)(this, pwszauthority, dwflags, pwszserialnumber, pdwrequestid, pdwdisposition, pwszattributes, pctbrequest, pctbfullresponse, pctbencodedcert, pctbdispositionmessage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCAProperty(this: *mut core::ffi::c_void, pwszauthority: windows_core::PCWSTR, propid: i32, propindex: i32, proptype: i32, pctbpropertyvalue: *mut CERTTRANSBLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertRequestD2", "GetCAProperty", 7usize, |this| ((**(this as *const *const ICertRequestD2_Vtbl)).GetCAProperty)(this, pwszauthority, propid, propindex, proptype, pctbpropertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCAPropertyInfo(this: *mut core::ffi::c_void, pwszauthority: windows_core::PCWSTR, pcproperty: *mut i32, pctbpropinfo: *mut CERTTRANSBLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertRequestD2", "GetCAPropertyInfo", 8usize, |this| ((**(this as *const *const ICertRequestD2_Vtbl)).GetCAPropertyInfo)(this, pwszauthority, pcproperty, pctbpropinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ping2(this: *mut core::ffi::c_void, pwszauthority: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertRequestD2", "Ping2", 9usize, |this| ((**(this as *const *const ICertRequestD2_Vtbl)).Ping2)(this, pwszauthority), |result| Some(*result))
+        }
+        Self { base__: <ICertRequestD_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request2, GetCAProperty, GetCAPropertyInfo, Ping2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertRequestD2 as windows_core::Interface>::IID || <ICertRequestD_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertServerExit_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetContext(this: *mut core::ffi::c_void, context: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertServerExit", "SetContext", 7usize, |this| ((**(this as *const *const ICertServerExit_Vtbl)).SetContext)(this, context), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRequestProperty(this: *mut core::ffi::c_void, strpropertyname: core::mem::MaybeUninit<windows_core::BSTR>, propertytype: i32, pvarpropertyvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertServerExit", "GetRequestProperty", 8usize, |this| ((**(this as *const *const ICertServerExit_Vtbl)).GetRequestProperty)(this, strpropertyname, propertytype, pvarpropertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRequestAttribute(this: *mut core::ffi::c_void, strattributename: core::mem::MaybeUninit<windows_core::BSTR>, pstrattributevalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertServerExit", "GetRequestAttribute", 9usize, |this| ((**(this as *const *const ICertServerExit_Vtbl)).GetRequestAttribute)(this, strattributename, pstrattributevalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCertificateProperty(this: *mut core::ffi::c_void, strpropertyname: core::mem::MaybeUninit<windows_core::BSTR>, propertytype: i32, pvarpropertyvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertServerExit", "GetCertificateProperty", 10usize, |this| ((**(this as *const *const ICertServerExit_Vtbl)).GetCertificateProperty)(this, strpropertyname, propertytype, pvarpropertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCertificateExtension(this: *mut core::ffi::c_void, strextensionname: core::mem::MaybeUninit<windows_core::BSTR>, r#type: i32, pvarvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertServerExit", "GetCertificateExtension", 11usize, |this| ((**(this as *const *const ICertServerExit_Vtbl)).GetCertificateExtension)(this, strextensionname, r#type, pvarvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCertificateExtensionFlags(this: *mut core::ffi::c_void, pextflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertServerExit", "GetCertificateExtensionFlags", 12usize, |this| ((**(this as *const *const ICertServerExit_Vtbl)).GetCertificateExtensionFlags)(this, pextflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateExtensionsSetup(this: *mut core::ffi::c_void, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertServerExit", "EnumerateExtensionsSetup", 13usize, |this| ((**(this as *const *const ICertServerExit_Vtbl)).EnumerateExtensionsSetup)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateExtensions(this: *mut core::ffi::c_void, pstrextensionname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertServerExit", "EnumerateExtensions", 14usize, |this| ((**(this as *const *const ICertServerExit_Vtbl)).EnumerateExtensions)(this, pstrextensionname), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateExtensionsClose(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertServerExit", "EnumerateExtensionsClose", 15usize, |this| ((**(this as *const *const ICertServerExit_Vtbl)).EnumerateExtensionsClose)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateAttributesSetup(this: *mut core::ffi::c_void, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertServerExit", "EnumerateAttributesSetup", 16usize, |this| ((**(this as *const *const ICertServerExit_Vtbl)).EnumerateAttributesSetup)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateAttributes(this: *mut core::ffi::c_void, pstrattributename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertServerExit", "EnumerateAttributes", 17usize, |this| ((**(this as *const *const ICertServerExit_Vtbl)).EnumerateAttributes)(this, pstrattributename), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateAttributesClose(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertServerExit", "EnumerateAttributesClose", 18usize, |this| ((**(this as *const *const ICertServerExit_Vtbl)).EnumerateAttributesClose)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetContext,
+            GetRequestProperty,
+            GetRequestAttribute,
+            GetCertificateProperty,
+            GetCertificateExtension,
+            GetCertificateExtensionFlags,
+            EnumerateExtensionsSetup,
+            EnumerateExtensions,
+            EnumerateExtensionsClose,
+            EnumerateAttributesSetup,
+            EnumerateAttributes,
+            EnumerateAttributesClose,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertServerExit as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertServerPolicy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetContext(this: *mut core::ffi::c_void, context: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertServerPolicy", "SetContext", 7usize, |this| ((**(this as *const *const ICertServerPolicy_Vtbl)).SetContext)(this, context), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRequestProperty(this: *mut core::ffi::c_void, strpropertyname: core::mem::MaybeUninit<windows_core::BSTR>, propertytype: i32, pvarpropertyvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertServerPolicy", "GetRequestProperty", 8usize, |this| ((**(this as *const *const ICertServerPolicy_Vtbl)).GetRequestProperty)(this, strpropertyname, propertytype, pvarpropertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRequestAttribute(this: *mut core::ffi::c_void, strattributename: core::mem::MaybeUninit<windows_core::BSTR>, pstrattributevalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertServerPolicy", "GetRequestAttribute", 9usize, |this| ((**(this as *const *const ICertServerPolicy_Vtbl)).GetRequestAttribute)(this, strattributename, pstrattributevalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCertificateProperty(this: *mut core::ffi::c_void, strpropertyname: core::mem::MaybeUninit<windows_core::BSTR>, propertytype: CERT_PROPERTY_TYPE, pvarpropertyvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertServerPolicy", "GetCertificateProperty", 10usize, |this| ((**(this as *const *const ICertServerPolicy_Vtbl)).GetCertificateProperty)(this, strpropertyname, propertytype, pvarpropertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCertificateProperty(this: *mut core::ffi::c_void, strpropertyname: core::mem::MaybeUninit<windows_core::BSTR>, propertytype: i32, pvarpropertyvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertServerPolicy", "SetCertificateProperty", 11usize, |this| ((**(this as *const *const ICertServerPolicy_Vtbl)).SetCertificateProperty)(this, strpropertyname, propertytype, pvarpropertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCertificateExtension(this: *mut core::ffi::c_void, strextensionname: core::mem::MaybeUninit<windows_core::BSTR>, r#type: CERT_PROPERTY_TYPE, pvarvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertServerPolicy", "GetCertificateExtension", 12usize, |this| ((**(this as *const *const ICertServerPolicy_Vtbl)).GetCertificateExtension)(this, strextensionname, r#type, pvarvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCertificateExtensionFlags(this: *mut core::ffi::c_void, pextflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertServerPolicy", "GetCertificateExtensionFlags", 13usize, |this| ((**(this as *const *const ICertServerPolicy_Vtbl)).GetCertificateExtensionFlags)(this, pextflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCertificateExtension(this: *mut core::ffi::c_void, strextensionname: core::mem::MaybeUninit<windows_core::BSTR>, r#type: i32, extflags: i32, pvarvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertServerPolicy", "SetCertificateExtension", 14usize, |this| ((**(this as *const *const ICertServerPolicy_Vtbl)).SetCertificateExtension)(this, strextensionname, r#type, extflags, pvarvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateExtensionsSetup(this: *mut core::ffi::c_void, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertServerPolicy", "EnumerateExtensionsSetup", 15usize, |this| ((**(this as *const *const ICertServerPolicy_Vtbl)).EnumerateExtensionsSetup)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateExtensions(this: *mut core::ffi::c_void, pstrextensionname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertServerPolicy", "EnumerateExtensions", 16usize, |this| ((**(this as *const *const ICertServerPolicy_Vtbl)).EnumerateExtensions)(this, pstrextensionname), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateExtensionsClose(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertServerPolicy", "EnumerateExtensionsClose", 17usize, |this| ((**(this as *const *const ICertServerPolicy_Vtbl)).EnumerateExtensionsClose)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateAttributesSetup(this: *mut core::ffi::c_void, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertServerPolicy", "EnumerateAttributesSetup", 18usize, |this| ((**(this as *const *const ICertServerPolicy_Vtbl)).EnumerateAttributesSetup)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateAttributes(this: *mut core::ffi::c_void, pstrattributename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertServerPolicy", "EnumerateAttributes", 19usize, |this| ((**(this as *const *const ICertServerPolicy_Vtbl)).EnumerateAttributes)(this, pstrattributename), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateAttributesClose(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertServerPolicy", "EnumerateAttributesClose", 20usize, |this| ((**(this as *const *const ICertServerPolicy_Vtbl)).EnumerateAttributesClose)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetContext,
+            GetRequestProperty,
+            GetRequestAttribute,
+            GetCertificateProperty,
+            SetCertificateProperty,
+            GetCertificateExtension,
+            GetCertificateExtensionFlags,
+            SetCertificateExtension,
+            EnumerateExtensionsSetup,
+            EnumerateExtensions,
+            EnumerateExtensionsClose,
+            EnumerateAttributesSetup,
+            EnumerateAttributes,
+            EnumerateAttributesClose,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertServerPolicy as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertView_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenConnection(this: *mut core::ffi::c_void, strconfig: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertView", "OpenConnection", 7usize, |this| ((**(this as *const *const ICertView_Vtbl)).OpenConnection)(this, strconfig), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumCertViewColumn(this: *mut core::ffi::c_void, fresultcolumn: CVRC_COLUMN, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertView", "EnumCertViewColumn", 8usize, |this| ((**(this as *const *const ICertView_Vtbl)).EnumCertViewColumn)(this, fresultcolumn, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColumnCount(this: *mut core::ffi::c_void, fresultcolumn: CVRC_COLUMN, pccolumn: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertView", "GetColumnCount", 9usize, |this| ((**(this as *const *const ICertView_Vtbl)).GetColumnCount)(this, fresultcolumn, pccolumn), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColumnIndex(this: *mut core::ffi::c_void, fresultcolumn: CVRC_COLUMN, strcolumnname: core::mem::MaybeUninit<windows_core::BSTR>, pcolumnindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertView", "GetColumnIndex", 10usize, |this| ((**(this as *const *const ICertView_Vtbl)).GetColumnIndex)(this, fresultcolumn, strcolumnname, pcolumnindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetResultColumnCount(this: *mut core::ffi::c_void, cresultcolumn: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertView", "SetResultColumnCount", 11usize, |this| ((**(this as *const *const ICertView_Vtbl)).SetResultColumnCount)(this, cresultcolumn), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetResultColumn(this: *mut core::ffi::c_void, columnindex: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertView", "SetResultColumn", 12usize, |this| ((**(this as *const *const ICertView_Vtbl)).SetResultColumn)(this, columnindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRestriction(this: *mut core::ffi::c_void, columnindex: CERT_VIEW_COLUMN_INDEX, seekoperator: CERT_VIEW_SEEK_OPERATOR_FLAGS, sortorder: i32, pvarvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertView", "SetRestriction", 13usize, |this| ((**(this as *const *const ICertView_Vtbl)).SetRestriction)(this, columnindex, seekoperator, sortorder, pvarvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OpenView(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertView", "OpenView", 14usize, |this| ((**(this as *const *const ICertView_Vtbl)).OpenView)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OpenConnection,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumCertViewColumn,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumCertViewColumn: 0,
+            GetColumnCount,
+            GetColumnIndex,
+            SetResultColumnCount,
+            SetResultColumn,
+            SetRestriction,
+            #[cfg(feature = "Win32_System_Com")]
+            OpenView,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OpenView: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertView as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertView2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetTable(this: *mut core::ffi::c_void, table: CVRC_TABLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertView2", "SetTable", 15usize, |this| ((**(this as *const *const ICertView2_Vtbl)).SetTable)(this, table), |result| Some(*result))
+        }
+        Self { base__: <ICertView_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetTable }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertView2 as windows_core::Interface>::IID || <ICertView_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertificateAttestationChallenge_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, encoding: EncodingType, strpendingfullcmcresponsewithchallenge: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateAttestationChallenge", "Initialize", 7usize, |this| ((**(this as *const *const ICertificateAttestationChallenge_Vtbl)).Initialize)(this, encoding, strpendingfullcmcresponsewithchallenge), |result| Some(*result))
+        }
+        unsafe extern "system" fn DecryptChallenge(this: *mut core::ffi::c_void, encoding: EncodingType, pstrenvelopedpkcs7reencryptedtoca: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateAttestationChallenge", "DecryptChallenge", 8usize, |this| ((**(this as *const *const ICertificateAttestationChallenge_Vtbl)).DecryptChallenge)(this, encoding, pstrenvelopedpkcs7reencryptedtoca), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestID(this: *mut core::ffi::c_void, pstrrequestid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateAttestationChallenge", "RequestID", 9usize, |this| ((**(this as *const *const ICertificateAttestationChallenge_Vtbl)).RequestID)(this, pstrrequestid), |result| Some(*result))
+        }
+        Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, DecryptChallenge, RequestID }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertificateAttestationChallenge as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertificateAttestationChallenge2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetKeyContainerName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateAttestationChallenge2", "SetKeyContainerName", 10usize, |this| ((**(this as *const *const ICertificateAttestationChallenge2_Vtbl)).SetKeyContainerName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_KeyBlob(this: *mut core::ffi::c_void, encoding: EncodingType, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateAttestationChallenge2", "put_KeyBlob", 11usize, |this| ((**(this as *const *const ICertificateAttestationChallenge2_Vtbl)).put_KeyBlob)(this, encoding, value), |result| Some(*result))
+        }
+        Self { base__: <ICertificateAttestationChallenge_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetKeyContainerName, put_KeyBlob }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertificateAttestationChallenge2 as windows_core::Interface>::IID || <ICertificateAttestationChallenge_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertificatePolicies_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ItemByIndex(this: *mut core::ffi::c_void, index: i32, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificatePolicies", "get_ItemByIndex", 7usize, |this| ((**(this as *const *const ICertificatePolicies_Vtbl)).get_ItemByIndex)(this, index, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificatePolicies", "Count", 8usize, |this| ((**(this as *const *const ICertificatePolicies_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificatePolicies", "_NewEnum", 9usize, |this| ((**(this as *const *const ICertificatePolicies_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificatePolicies", "Add", 10usize, |this| ((**(this as *const *const ICertificatePolicies_Vtbl)).Add)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificatePolicies", "Remove", 11usize, |this| ((**(this as *const *const ICertificatePolicies_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificatePolicies", "Clear", 12usize, |this| ((**(this as *const *const ICertificatePolicies_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ItemByIndex,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ItemByIndex: 0,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            Clear,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertificatePolicies as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertificatePolicy_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificatePolicy", "Initialize", 7usize, |this| ((**(this as *const *const ICertificatePolicy_Vtbl)).Initialize)(this, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ObjectId(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificatePolicy", "ObjectId", 8usize, |this| ((**(this as *const *const ICertificatePolicy_Vtbl)).ObjectId)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PolicyQualifiers(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificatePolicy", "PolicyQualifiers", 9usize, |this| ((**(this as *const *const ICertificatePolicy_Vtbl)).PolicyQualifiers)(this, ppvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Initialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Initialize: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ObjectId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ObjectId: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PolicyQualifiers,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PolicyQualifiers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertificatePolicy as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertificationAuthorities_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ItemByIndex(this: *mut core::ffi::c_void, index: i32, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificationAuthorities", "get_ItemByIndex", 7usize, |this| ((**(this as *const *const ICertificationAuthorities_Vtbl)).get_ItemByIndex)(this, index, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificationAuthorities", "Count", 8usize, |this| ((**(this as *const *const ICertificationAuthorities_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificationAuthorities", "_NewEnum", 9usize, |this| ((**(this as *const *const ICertificationAuthorities_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificationAuthorities", "Add", 10usize, |this| ((**(this as *const *const ICertificationAuthorities_Vtbl)).Add)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificationAuthorities", "Remove", 11usize, |this| ((**(this as *const *const ICertificationAuthorities_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificationAuthorities", "Clear", 12usize, |this| ((**(this as *const *const ICertificationAuthorities_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ComputeSiteCosts(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificationAuthorities", "ComputeSiteCosts", 13usize, |this| ((**(this as *const *const ICertificationAuthorities_Vtbl)).ComputeSiteCosts)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ItemByName(this: *mut core::ffi::c_void, strname: core::mem::MaybeUninit<windows_core::BSTR>, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificationAuthorities", "get_ItemByName", 14usize, |this| ((**(this as *const *const ICertificationAuthorities_Vtbl)).get_ItemByName)(this, strname, ppvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ItemByIndex,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ItemByIndex: 0,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            Clear,
+            ComputeSiteCosts,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ItemByName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ItemByName: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertificationAuthorities as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertificationAuthority_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_Property(this: *mut core::ffi::c_void, property: EnrollmentCAProperty, pvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificationAuthority", "get_Property", 7usize, |this| ((**(this as *const *const ICertificationAuthority_Vtbl)).get_Property)(this, property, pvalue), |result| Some(*result))
+        }
+        Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, get_Property }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertificationAuthority as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICryptAttribute_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeFromObjectId(this: *mut core::ffi::c_void, pobjectid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptAttribute", "InitializeFromObjectId", 7usize, |this| ((**(this as *const *const ICryptAttribute_Vtbl)).InitializeFromObjectId)(this, pobjectid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeFromValues(this: *mut core::ffi::c_void, pattributes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptAttribute", "InitializeFromValues", 8usize, |this| ((**(this as *const *const ICryptAttribute_Vtbl)).InitializeFromValues)(this, pattributes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ObjectId(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptAttribute", "ObjectId", 9usize, |this| ((**(this as *const *const ICryptAttribute_Vtbl)).ObjectId)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Values(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptAttribute", "Values", 10usize, |this| ((**(this as *const *const ICryptAttribute_Vtbl)).Values)(this, ppvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeFromObjectId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeFromObjectId: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeFromValues,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeFromValues: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ObjectId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ObjectId: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Values,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Values: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICryptAttribute as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICryptAttributes_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ItemByIndex(this: *mut core::ffi::c_void, index: i32, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptAttributes", "get_ItemByIndex", 7usize, |this| ((**(this as *const *const ICryptAttributes_Vtbl)).get_ItemByIndex)(this, index, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptAttributes", "Count", 8usize, |this| ((**(this as *const *const ICryptAttributes_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptAttributes", "_NewEnum", 9usize, |this| ((**(this as *const *const ICryptAttributes_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptAttributes", "Add", 10usize, |this| ((**(this as *const *const ICryptAttributes_Vtbl)).Add)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptAttributes", "Remove", 11usize, |this| ((**(this as *const *const ICryptAttributes_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptAttributes", "Clear", 12usize, |this| ((**(this as *const *const ICryptAttributes_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_IndexByObjectId(this: *mut core::ffi::c_void, pobjectid: *mut core::ffi::c_void, pindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptAttributes", "get_IndexByObjectId", 13usize, |this| ((**(this as *const *const ICryptAttributes_Vtbl)).get_IndexByObjectId)(this, pobjectid, pindex), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddRange(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICryptAttributes", "AddRange", 14usize, |this| ((**(this as *const *const ICryptAttributes_Vtbl)).AddRange)(this, pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ItemByIndex,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ItemByIndex: 0,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            Clear,
+            #[cfg(feature = "Win32_System_Com")]
+            get_IndexByObjectId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_IndexByObjectId: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AddRange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddRange: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICryptAttributes as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICspAlgorithm_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetAlgorithmOid(this: *mut core::ffi::c_void, length: i32, algflags: AlgorithmFlags, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspAlgorithm", "GetAlgorithmOid", 7usize, |this| ((**(this as *const *const ICspAlgorithm_Vtbl)).GetAlgorithmOid)(this, length, algflags, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultLength(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspAlgorithm", "DefaultLength", 8usize, |this| ((**(this as *const *const ICspAlgorithm_Vtbl)).DefaultLength)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncrementLength(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspAlgorithm", "IncrementLength", 9usize, |this| ((**(this as *const *const ICspAlgorithm_Vtbl)).IncrementLength)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn LongName(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspAlgorithm", "LongName", 10usize, |this| ((**(this as *const *const ICspAlgorithm_Vtbl)).LongName)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Valid(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspAlgorithm", "Valid", 11usize, |this| ((**(this as *const *const ICspAlgorithm_Vtbl)).Valid)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxLength(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspAlgorithm", "MaxLength", 12usize, |this| ((**(this as *const *const ICspAlgorithm_Vtbl)).MaxLength)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinLength(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspAlgorithm", "MinLength", 13usize, |this| ((**(this as *const *const ICspAlgorithm_Vtbl)).MinLength)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspAlgorithm", "Name", 14usize, |this| ((**(this as *const *const ICspAlgorithm_Vtbl)).Name)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, pvalue: *mut AlgorithmType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspAlgorithm", "Type", 15usize, |this| ((**(this as *const *const ICspAlgorithm_Vtbl)).Type)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Operations(this: *mut core::ffi::c_void, pvalue: *mut AlgorithmOperationFlags) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspAlgorithm", "Operations", 16usize, |this| ((**(this as *const *const ICspAlgorithm_Vtbl)).Operations)(this, pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetAlgorithmOid,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetAlgorithmOid: 0,
+            DefaultLength,
+            IncrementLength,
+            LongName,
+            Valid,
+            MaxLength,
+            MinLength,
+            Name,
+            Type,
+            Operations,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICspAlgorithm as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICspAlgorithms_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ItemByIndex(this: *mut core::ffi::c_void, index: i32, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspAlgorithms", "get_ItemByIndex", 7usize, |this| ((**(this as *const *const ICspAlgorithms_Vtbl)).get_ItemByIndex)(this, index, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspAlgorithms", "Count", 8usize, |this| ((**(this as *const *const ICspAlgorithms_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspAlgorithms", "_NewEnum", 9usize, |this| ((**(this as *const *const ICspAlgorithms_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspAlgorithms", "Add", 10usize, |this| ((**(this as *const *const ICspAlgorithms_Vtbl)).Add)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspAlgorithms", "Remove", 11usize, |this| ((**(this as *const *const ICspAlgorithms_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspAlgorithms", "Clear", 12usize, |this| ((**(this as *const *const ICspAlgorithms_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ItemByName(this: *mut core::ffi::c_void, strname: core::mem::MaybeUninit<windows_core::BSTR>, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspAlgorithms", "get_ItemByName", 13usize, |this| ((**(this as *const *const ICspAlgorithms_Vtbl)).get_ItemByName)(this, strname, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_IndexByObjectId(this: *mut core::ffi::c_void, pobjectid: *mut core::ffi::c_void, pindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspAlgorithms", "get_IndexByObjectId", 14usize, |this| ((**(this as *const *const ICspAlgorithms_Vtbl)).get_IndexByObjectId)(this, pobjectid, pindex), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ItemByIndex,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ItemByIndex: 0,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            Clear,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ItemByName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ItemByName: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            get_IndexByObjectId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_IndexByObjectId: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICspAlgorithms as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICspInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeFromName(this: *mut core::ffi::c_void, strname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformation", "InitializeFromName", 7usize, |this| ((**(this as *const *const ICspInformation_Vtbl)).InitializeFromName)(this, strname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeFromType(this: *mut core::ffi::c_void, r#type: X509ProviderType, palgorithm: *mut core::ffi::c_void, machinecontext: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformation", "InitializeFromType", 8usize, |this| ((**(this as *const *const ICspInformation_Vtbl)).InitializeFromType)(this, r#type, palgorithm, machinecontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CspAlgorithms(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformation", "CspAlgorithms", 9usize, |this| ((**(this as *const *const ICspInformation_Vtbl)).CspAlgorithms)(this, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasHardwareRandomNumberGenerator(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformation", "HasHardwareRandomNumberGenerator", 10usize, |this| ((**(this as *const *const ICspInformation_Vtbl)).HasHardwareRandomNumberGenerator)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsHardwareDevice(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformation", "IsHardwareDevice", 11usize, |this| ((**(this as *const *const ICspInformation_Vtbl)).IsHardwareDevice)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRemovable(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformation", "IsRemovable", 12usize, |this| ((**(this as *const *const ICspInformation_Vtbl)).IsRemovable)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSoftwareDevice(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformation", "IsSoftwareDevice", 13usize, |this| ((**(this as *const *const ICspInformation_Vtbl)).IsSoftwareDevice)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Valid(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformation", "Valid", 14usize, |this| ((**(this as *const *const ICspInformation_Vtbl)).Valid)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxKeyContainerNameLength(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformation", "MaxKeyContainerNameLength", 15usize, |this| ((**(this as *const *const ICspInformation_Vtbl)).MaxKeyContainerNameLength)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformation", "Name", 16usize, |this| ((**(this as *const *const ICspInformation_Vtbl)).Name)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, pvalue: *mut X509ProviderType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformation", "Type", 17usize, |this| ((**(this as *const *const ICspInformation_Vtbl)).Type)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Version(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformation", "Version", 18usize, |this| ((**(this as *const *const ICspInformation_Vtbl)).Version)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeySpec(this: *mut core::ffi::c_void, pvalue: *mut X509KeySpec) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformation", "KeySpec", 19usize, |this| ((**(this as *const *const ICspInformation_Vtbl)).KeySpec)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSmartCard(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformation", "IsSmartCard", 20usize, |this| ((**(this as *const *const ICspInformation_Vtbl)).IsSmartCard)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultSecurityDescriptor(this: *mut core::ffi::c_void, machinecontext: super::super::super::Foundation::VARIANT_BOOL, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformation", "GetDefaultSecurityDescriptor", 21usize, |this| ((**(this as *const *const ICspInformation_Vtbl)).GetDefaultSecurityDescriptor)(this, machinecontext, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn LegacyCsp(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformation", "LegacyCsp", 22usize, |this| ((**(this as *const *const ICspInformation_Vtbl)).LegacyCsp)(this, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetCspStatusFromOperations(this: *mut core::ffi::c_void, palgorithm: *mut core::ffi::c_void, operations: AlgorithmOperationFlags, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformation", "GetCspStatusFromOperations", 23usize, |this| ((**(this as *const *const ICspInformation_Vtbl)).GetCspStatusFromOperations)(this, palgorithm, operations, ppvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitializeFromName,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeFromType,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeFromType: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CspAlgorithms,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CspAlgorithms: 0,
+            HasHardwareRandomNumberGenerator,
+            IsHardwareDevice,
+            IsRemovable,
+            IsSoftwareDevice,
+            Valid,
+            MaxKeyContainerNameLength,
+            Name,
+            Type,
+            Version,
+            KeySpec,
+            IsSmartCard,
+            GetDefaultSecurityDescriptor,
+            LegacyCsp,
+            #[cfg(feature = "Win32_System_Com")]
+            GetCspStatusFromOperations,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetCspStatusFromOperations: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICspInformation as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICspInformations_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ItemByIndex(this: *mut core::ffi::c_void, index: i32, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformations", "get_ItemByIndex", 7usize, |this| ((**(this as *const *const ICspInformations_Vtbl)).get_ItemByIndex)(this, index, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformations", "Count", 8usize, |this| ((**(this as *const *const ICspInformations_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformations", "_NewEnum", 9usize, |this| ((**(this as *const *const ICspInformations_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformations", "Add", 10usize, |this| ((**(this as *const *const ICspInformations_Vtbl)).Add)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformations", "Remove", 11usize, |this| ((**(this as *const *const ICspInformations_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformations", "Clear", 12usize, |this| ((**(this as *const *const ICspInformations_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddAvailableCsps(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformations", "AddAvailableCsps", 13usize, |this| ((**(this as *const *const ICspInformations_Vtbl)).AddAvailableCsps)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ItemByName(this: *mut core::ffi::c_void, strname: core::mem::MaybeUninit<windows_core::BSTR>, ppcspinformation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformations", "get_ItemByName", 14usize, |this| ((**(this as *const *const ICspInformations_Vtbl)).get_ItemByName)(this, strname, ppcspinformation), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetCspStatusFromProviderName(this: *mut core::ffi::c_void, strprovidername: core::mem::MaybeUninit<windows_core::BSTR>, legacykeyspec: X509KeySpec, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformations", "GetCspStatusFromProviderName", 15usize, |this| ((**(this as *const *const ICspInformations_Vtbl)).GetCspStatusFromProviderName)(this, strprovidername, legacykeyspec, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetCspStatusesFromOperations(this: *mut core::ffi::c_void, operations: AlgorithmOperationFlags, pcspinformation: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformations", "GetCspStatusesFromOperations", 16usize, |this| ((**(this as *const *const ICspInformations_Vtbl)).GetCspStatusesFromOperations)(this, operations, pcspinformation, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetEncryptionCspAlgorithms(this: *mut core::ffi::c_void, pcspinformation: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformations", "GetEncryptionCspAlgorithms", 17usize, |this| ((**(this as *const *const ICspInformations_Vtbl)).GetEncryptionCspAlgorithms)(this, pcspinformation, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetHashAlgorithms(this: *mut core::ffi::c_void, pcspinformation: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspInformations", "GetHashAlgorithms", 18usize, |this| ((**(this as *const *const ICspInformations_Vtbl)).GetHashAlgorithms)(this, pcspinformation, ppvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ItemByIndex,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ItemByIndex: 0,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            Clear,
+            AddAvailableCsps,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ItemByName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ItemByName: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetCspStatusFromProviderName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetCspStatusFromProviderName: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetCspStatusesFromOperations,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetCspStatusesFromOperations: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetEncryptionCspAlgorithms,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetEncryptionCspAlgorithms: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetHashAlgorithms,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetHashAlgorithms: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICspInformations as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICspStatus_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pcsp: *mut core::ffi::c_void, palgorithm: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspStatus", "Initialize", 7usize, |this| ((**(this as *const *const ICspStatus_Vtbl)).Initialize)(this, pcsp, palgorithm), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ordinal(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspStatus", "Ordinal", 8usize, |this| ((**(this as *const *const ICspStatus_Vtbl)).Ordinal)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOrdinal(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspStatus", "SetOrdinal", 9usize, |this| ((**(this as *const *const ICspStatus_Vtbl)).SetOrdinal)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CspAlgorithm(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspStatus", "CspAlgorithm", 10usize, |this| ((**(this as *const *const ICspStatus_Vtbl)).CspAlgorithm)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CspInformation(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspStatus", "CspInformation", 11usize, |this| ((**(this as *const *const ICspStatus_Vtbl)).CspInformation)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnrollmentStatus(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspStatus", "EnrollmentStatus", 12usize, |this| ((**(this as *const *const ICspStatus_Vtbl)).EnrollmentStatus)(this, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspStatus", "DisplayName", 13usize, |this| ((**(this as *const *const ICspStatus_Vtbl)).DisplayName)(this, pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Initialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Initialize: 0,
+            Ordinal,
+            SetOrdinal,
+            #[cfg(feature = "Win32_System_Com")]
+            CspAlgorithm,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CspAlgorithm: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CspInformation,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CspInformation: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnrollmentStatus,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnrollmentStatus: 0,
+            DisplayName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICspStatus as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICspStatuses_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ItemByIndex(this: *mut core::ffi::c_void, index: i32, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspStatuses", "get_ItemByIndex", 7usize, |this| ((**(this as *const *const ICspStatuses_Vtbl)).get_ItemByIndex)(this, index, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspStatuses", "Count", 8usize, |this| ((**(this as *const *const ICspStatuses_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspStatuses", "_NewEnum", 9usize, |this| ((**(this as *const *const ICspStatuses_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspStatuses", "Add", 10usize, |this| ((**(this as *const *const ICspStatuses_Vtbl)).Add)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspStatuses", "Remove", 11usize, |this| ((**(this as *const *const ICspStatuses_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspStatuses", "Clear", 12usize, |this| ((**(this as *const *const ICspStatuses_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ItemByName(this: *mut core::ffi::c_void, strcspname: core::mem::MaybeUninit<windows_core::BSTR>, stralgorithmname: core::mem::MaybeUninit<windows_core::BSTR>, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspStatuses", "get_ItemByName", 13usize, |this| ((**(this as *const *const ICspStatuses_Vtbl)).get_ItemByName)(this, strcspname, stralgorithmname, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ItemByOrdinal(this: *mut core::ffi::c_void, ordinal: i32, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspStatuses", "get_ItemByOrdinal", 14usize, |this| ((**(this as *const *const ICspStatuses_Vtbl)).get_ItemByOrdinal)(this, ordinal, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ItemByOperations(this: *mut core::ffi::c_void, strcspname: core::mem::MaybeUninit<windows_core::BSTR>, stralgorithmname: core::mem::MaybeUninit<windows_core::BSTR>, operations: AlgorithmOperationFlags, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspStatuses", "get_ItemByOperations", 15usize, |this| ((**(this as *const *const ICspStatuses_Vtbl)).get_ItemByOperations)(this, strcspname, stralgorithmname, operations, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ItemByProvider(this: *mut core::ffi::c_void, pcspstatus: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICspStatuses", "get_ItemByProvider", 16usize, |this| ((**(this as *const *const ICspStatuses_Vtbl)).get_ItemByProvider)(this, pcspstatus, ppvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ItemByIndex,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ItemByIndex: 0,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            Clear,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ItemByName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ItemByName: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ItemByOrdinal,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ItemByOrdinal: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ItemByOperations,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ItemByOperations: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ItemByProvider,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ItemByProvider: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICspStatuses as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnroll_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn createFilePKCS10WStr(this: *mut core::ffi::c_void, dnname: windows_core::PCWSTR, usage: windows_core::PCWSTR, wszpkcs10filename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "createFilePKCS10WStr", 3usize, |this| ((**(this as *const *const IEnroll_Vtbl)).createFilePKCS10WStr)(this, dnname, usage, wszpkcs10filename), |result| Some(*result))
+        }
+        unsafe extern "system" fn acceptFilePKCS7WStr(this: *mut core::ffi::c_void, wszpkcs7filename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "acceptFilePKCS7WStr", 4usize, |this| ((**(this as *const *const IEnroll_Vtbl)).acceptFilePKCS7WStr)(this, wszpkcs7filename), |result| Some(*result))
+        }
+        unsafe extern "system" fn createPKCS10WStr(this: *mut core::ffi::c_void, dnname: windows_core::PCWSTR, usage: windows_core::PCWSTR, ppkcs10blob: *mut super::CRYPT_INTEGER_BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "createPKCS10WStr", 5usize, |this| ((**(this as *const *const IEnroll_Vtbl)).createPKCS10WStr)(this, dnname, usage, ppkcs10blob), |result| Some(*result))
+        }
+        unsafe extern "system" fn acceptPKCS7Blob(this: *mut core::ffi::c_void, pblobpkcs7: *mut super::CRYPT_INTEGER_BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "acceptPKCS7Blob", 6usize, |this| ((**(this as *const *const IEnroll_Vtbl)).acceptPKCS7Blob)(this, pblobpkcs7), |result| Some(*result))
+        }
+        unsafe extern "system" fn getCertContextFromPKCS7(this: *mut core::ffi::c_void, pblobpkcs7: *mut super::CRYPT_INTEGER_BLOB) -> *mut super::CERT_CONTEXT {
+            windows_core::imp::proxy_call(this, "IEnroll", "getCertContextFromPKCS7", 7usize, |this| ((**(this as *const *const IEnroll_Vtbl)).getCertContextFromPKCS7)(this, pblobpkcs7), |_| None)
+        }
+        unsafe extern "system" fn getMyStore(this: *mut core::ffi::c_void) -> super::HCERTSTORE {
+            windows_core::imp::proxy_call(this, "IEnroll", "getMyStore", 8usize, |this| ((**(this as *const *const IEnroll_Vtbl)).getMyStore)(this), |_| None)
+        }
+        unsafe extern "system" fn getCAStore(this: *mut core::ffi::c_void) -> super::HCERTSTORE {
+            windows_core::imp::proxy_call(this, "IEnroll", "getCAStore", 9usize, |this| ((**(this as *const *const IEnroll_Vtbl)).getCAStore)(this), |_| None)
+        }
+        unsafe extern "system" fn getROOTHStore(this: *mut core::ffi::c_void) -> super::HCERTSTORE {
+            windows_core::imp::proxy_call(this, "IEnroll", "getROOTHStore", 10usize, |this| ((**(this as *const *const IEnroll_Vtbl)).getROOTHStore)(this), |_| None)
+        }
+        unsafe extern "system" fn enumProvidersWStr(this: *mut core::ffi::c_void, dwindex: i32, dwflags: i32, pbstrprovname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "enumProvidersWStr", 11usize, |this| ((**(this as *const *const IEnroll_Vtbl)).enumProvidersWStr)(this, dwindex, dwflags, pbstrprovname), |result| Some(*result))
+        }
+        unsafe extern "system" fn enumContainersWStr(this: *mut core::ffi::c_void, dwindex: i32, pbstr: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "enumContainersWStr", 12usize, |this| ((**(this as *const *const IEnroll_Vtbl)).enumContainersWStr)(this, dwindex, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn freeRequestInfoBlob(this: *mut core::ffi::c_void, pkcs7orpkcs10: super::CRYPT_INTEGER_BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "freeRequestInfoBlob", 13usize, |this| ((**(this as *const *const IEnroll_Vtbl)).freeRequestInfoBlob)(this, pkcs7orpkcs10), |result| Some(*result))
+        }
+        unsafe extern "system" fn MyStoreNameWStr(this: *mut core::ffi::c_void, szwname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "MyStoreNameWStr", 14usize, |this| ((**(this as *const *const IEnroll_Vtbl)).MyStoreNameWStr)(this, szwname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMyStoreNameWStr(this: *mut core::ffi::c_void, szwname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "SetMyStoreNameWStr", 15usize, |this| ((**(this as *const *const IEnroll_Vtbl)).SetMyStoreNameWStr)(this, szwname), |result| Some(*result))
+        }
+        unsafe extern "system" fn MyStoreTypeWStr(this: *mut core::ffi::c_void, szwtype: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "MyStoreTypeWStr", 16usize, |this| ((**(this as *const *const IEnroll_Vtbl)).MyStoreTypeWStr)(this, szwtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMyStoreTypeWStr(this: *mut core::ffi::c_void, szwtype: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "SetMyStoreTypeWStr", 17usize, |this| ((**(this as *const *const IEnroll_Vtbl)).SetMyStoreTypeWStr)(this, szwtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn MyStoreFlags(this: *mut core::ffi::c_void, pdwflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "MyStoreFlags", 18usize, |this| ((**(this as *const *const IEnroll_Vtbl)).MyStoreFlags)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMyStoreFlags(this: *mut core::ffi::c_void, dwflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "SetMyStoreFlags", 19usize, |this| ((**(this as *const *const IEnroll_Vtbl)).SetMyStoreFlags)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn CAStoreNameWStr(this: *mut core::ffi::c_void, szwname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "CAStoreNameWStr", 20usize, |this| ((**(this as *const *const IEnroll_Vtbl)).CAStoreNameWStr)(this, szwname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCAStoreNameWStr(this: *mut core::ffi::c_void, szwname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "SetCAStoreNameWStr", 21usize, |this| ((**(this as *const *const IEnroll_Vtbl)).SetCAStoreNameWStr)(this, szwname), |result| Some(*result))
+        }
+        unsafe extern "system" fn CAStoreTypeWStr(this: *mut core::ffi::c_void, szwtype: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "CAStoreTypeWStr", 22usize, |this| ((**(this as *const *const IEnroll_Vtbl)).CAStoreTypeWStr)(this, szwtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCAStoreTypeWStr(this: *mut core::ffi::c_void, szwtype: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "SetCAStoreTypeWStr", 23usize, |this| ((**(this as *const *const IEnroll_Vtbl)).SetCAStoreTypeWStr)(this, szwtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn CAStoreFlags(this: *mut core::ffi::c_void, pdwflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "CAStoreFlags", 24usize, |this| ((**(this as *const *const IEnroll_Vtbl)).CAStoreFlags)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCAStoreFlags(this: *mut core::ffi::c_void, dwflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "SetCAStoreFlags", 25usize, |this| ((**(this as *const *const IEnroll_Vtbl)).SetCAStoreFlags)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn RootStoreNameWStr(this: *mut core::ffi::c_void, szwname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "RootStoreNameWStr", 26usize, |this| ((**(this as *const *const IEnroll_Vtbl)).RootStoreNameWStr)(this, szwname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRootStoreNameWStr(this: *mut core::ffi::c_void, szwname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "SetRootStoreNameWStr", 27usize, |this| ((**(this as *const *const IEnroll_Vtbl)).SetRootStoreNameWStr)(this, szwname), |result| Some(*result))
+        }
+        unsafe extern "system" fn RootStoreTypeWStr(this: *mut core::ffi::c_void, szwtype: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "RootStoreTypeWStr", 28usize, |this| ((**(this as *const *const IEnroll_Vtbl)).RootStoreTypeWStr)(this, szwtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRootStoreTypeWStr(this: *mut core::ffi::c_void, szwtype: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "SetRootStoreTypeWStr", 29usize, |this| ((**(this as *const *const IEnroll_Vtbl)).SetRootStoreTypeWStr)(this, szwtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn RootStoreFlags(this: *mut core::ffi::c_void, pdwflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "RootStoreFlags", 30usize, |this| ((**(this as *const *const IEnroll_Vtbl)).RootStoreFlags)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRootStoreFlags(this: *mut core::ffi::c_void, dwflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "SetRootStoreFlags", 31usize, |this| ((**(this as *const *const IEnroll_Vtbl)).SetRootStoreFlags)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestStoreNameWStr(this: *mut core::ffi::c_void, szwname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "RequestStoreNameWStr", 32usize, |this| ((**(this as *const *const IEnroll_Vtbl)).RequestStoreNameWStr)(this, szwname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequestStoreNameWStr(this: *mut core::ffi::c_void, szwname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "SetRequestStoreNameWStr", 33usize, |this| ((**(this as *const *const IEnroll_Vtbl)).SetRequestStoreNameWStr)(this, szwname), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestStoreTypeWStr(this: *mut core::ffi::c_void, szwtype: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "RequestStoreTypeWStr", 34usize, |this| ((**(this as *const *const IEnroll_Vtbl)).RequestStoreTypeWStr)(this, szwtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequestStoreTypeWStr(this: *mut core::ffi::c_void, szwtype: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "SetRequestStoreTypeWStr", 35usize, |this| ((**(this as *const *const IEnroll_Vtbl)).SetRequestStoreTypeWStr)(this, szwtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestStoreFlags(this: *mut core::ffi::c_void, pdwflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "RequestStoreFlags", 36usize, |this| ((**(this as *const *const IEnroll_Vtbl)).RequestStoreFlags)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequestStoreFlags(this: *mut core::ffi::c_void, dwflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "SetRequestStoreFlags", 37usize, |this| ((**(this as *const *const IEnroll_Vtbl)).SetRequestStoreFlags)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContainerNameWStr(this: *mut core::ffi::c_void, szwcontainer: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "ContainerNameWStr", 38usize, |this| ((**(this as *const *const IEnroll_Vtbl)).ContainerNameWStr)(this, szwcontainer), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContainerNameWStr(this: *mut core::ffi::c_void, szwcontainer: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "SetContainerNameWStr", 39usize, |this| ((**(this as *const *const IEnroll_Vtbl)).SetContainerNameWStr)(this, szwcontainer), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderNameWStr(this: *mut core::ffi::c_void, szwprovider: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "ProviderNameWStr", 40usize, |this| ((**(this as *const *const IEnroll_Vtbl)).ProviderNameWStr)(this, szwprovider), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProviderNameWStr(this: *mut core::ffi::c_void, szwprovider: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "SetProviderNameWStr", 41usize, |this| ((**(this as *const *const IEnroll_Vtbl)).SetProviderNameWStr)(this, szwprovider), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderType(this: *mut core::ffi::c_void, pdwtype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "ProviderType", 42usize, |this| ((**(this as *const *const IEnroll_Vtbl)).ProviderType)(this, pdwtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProviderType(this: *mut core::ffi::c_void, dwtype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "SetProviderType", 43usize, |this| ((**(this as *const *const IEnroll_Vtbl)).SetProviderType)(this, dwtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeySpec(this: *mut core::ffi::c_void, pdw: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "KeySpec", 44usize, |this| ((**(this as *const *const IEnroll_Vtbl)).KeySpec)(this, pdw), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeySpec(this: *mut core::ffi::c_void, dw: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "SetKeySpec", 45usize, |this| ((**(this as *const *const IEnroll_Vtbl)).SetKeySpec)(this, dw), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderFlags(this: *mut core::ffi::c_void, pdwflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "ProviderFlags", 46usize, |this| ((**(this as *const *const IEnroll_Vtbl)).ProviderFlags)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProviderFlags(this: *mut core::ffi::c_void, dwflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "SetProviderFlags", 47usize, |this| ((**(this as *const *const IEnroll_Vtbl)).SetProviderFlags)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseExistingKeySet(this: *mut core::ffi::c_void, fuseexistingkeys: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "UseExistingKeySet", 48usize, |this| ((**(this as *const *const IEnroll_Vtbl)).UseExistingKeySet)(this, fuseexistingkeys), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUseExistingKeySet(this: *mut core::ffi::c_void, fuseexistingkeys: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "SetUseExistingKeySet", 49usize, |this| ((**(this as *const *const IEnroll_Vtbl)).SetUseExistingKeySet)(this, fuseexistingkeys), |result| Some(*result))
+        }
+        unsafe extern "system" fn GenKeyFlags(this: *mut core::ffi::c_void, pdwflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "GenKeyFlags", 50usize, |this| ((**(this as *const *const IEnroll_Vtbl)).GenKeyFlags)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGenKeyFlags(this: *mut core::ffi::c_void, dwflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "SetGenKeyFlags", 51usize, |this| ((**(this as *const *const IEnroll_Vtbl)).SetGenKeyFlags)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteRequestCert(this: *mut core::ffi::c_void, fdelete: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "DeleteRequestCert", 52usize, |this| ((**(this as *const *const IEnroll_Vtbl)).DeleteRequestCert)(this, fdelete), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeleteRequestCert(this: *mut core::ffi::c_void, fdelete: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "SetDeleteRequestCert", 53usize, |this| ((**(this as *const *const IEnroll_Vtbl)).SetDeleteRequestCert)(this, fdelete), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteCertToUserDS(this: *mut core::ffi::c_void, fbool: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "WriteCertToUserDS", 54usize, |this| ((**(this as *const *const IEnroll_Vtbl)).WriteCertToUserDS)(this, fbool), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWriteCertToUserDS(this: *mut core::ffi::c_void, fbool: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "SetWriteCertToUserDS", 55usize, |this| ((**(this as *const *const IEnroll_Vtbl)).SetWriteCertToUserDS)(this, fbool), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableT61DNEncoding(this: *mut core::ffi::c_void, fbool: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "EnableT61DNEncoding", 56usize, |this| ((**(this as *const *const IEnroll_Vtbl)).EnableT61DNEncoding)(this, fbool), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnableT61DNEncoding(this: *mut core::ffi::c_void, fbool: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "SetEnableT61DNEncoding", 57usize, |this| ((**(this as *const *const IEnroll_Vtbl)).SetEnableT61DNEncoding)(this, fbool), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteCertToCSP(this: *mut core::ffi::c_void, fbool: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "WriteCertToCSP", 58usize, |this| ((**(this as *const *const IEnroll_Vtbl)).WriteCertToCSP)(this, fbool), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWriteCertToCSP(this: *mut core::ffi::c_void, fbool: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "SetWriteCertToCSP", 59usize, |this| ((**(this as *const *const IEnroll_Vtbl)).SetWriteCertToCSP)(this, fbool), |result| Some(*result))
+        }
+        unsafe extern "system" fn SPCFileNameWStr(this: *mut core::ffi::c_void, szw: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "SPCFileNameWStr", 60usize, |this| ((**(this as *const *const IEnroll_Vtbl)).SPCFileNameWStr)(this, szw), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSPCFileNameWStr(this: *mut core::ffi::c_void, szw: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "SetSPCFileNameWStr", 61usize, |this| ((**(this as *const *const IEnroll_Vtbl)).SetSPCFileNameWStr)(this, szw), |result| Some(*result))
+        }
+        unsafe extern "system" fn PVKFileNameWStr(this: *mut core::ffi::c_void, szw: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "PVKFileNameWStr", 62usize, |this| ((**(this as *const *const IEnroll_Vtbl)).PVKFileNameWStr)(this, szw), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPVKFileNameWStr(this: *mut core::ffi::c_void, szw: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "SetPVKFileNameWStr", 63usize, |this| ((**(this as *const *const IEnroll_Vtbl)).SetPVKFileNameWStr)(this, szw), |result| Some(*result))
+        }
+        unsafe extern "system" fn HashAlgorithmWStr(this: *mut core::ffi::c_void, szw: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "HashAlgorithmWStr", 64usize, |this| ((**(this as *const *const IEnroll_Vtbl)).HashAlgorithmWStr)(this, szw), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHashAlgorithmWStr(this: *mut core::ffi::c_void, szw: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "SetHashAlgorithmWStr", 65usize, |this| ((**(this as *const *const IEnroll_Vtbl)).SetHashAlgorithmWStr)(this, szw), |result| Some(*result))
+        }
+        unsafe extern "system" fn RenewalCertificate(this: *mut core::ffi::c_void, ppcertcontext: *mut *mut super::CERT_CONTEXT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "RenewalCertificate", 66usize, |this| ((**(this as *const *const IEnroll_Vtbl)).RenewalCertificate)(this, ppcertcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRenewalCertificate(this: *mut core::ffi::c_void, pcertcontext: *const super::CERT_CONTEXT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "SetRenewalCertificate", 67usize, |this| ((**(this as *const *const IEnroll_Vtbl)).SetRenewalCertificate)(this, pcertcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddCertTypeToRequestWStr(this: *mut core::ffi::c_void, szw: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "AddCertTypeToRequestWStr", 68usize, |this| ((**(this as *const *const IEnroll_Vtbl)).AddCertTypeToRequestWStr)(this, szw), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddNameValuePairToSignatureWStr(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, value: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "AddNameValuePairToSignatureWStr", 69usize, |this| ((**(this as *const *const IEnroll_Vtbl)).AddNameValuePairToSignatureWStr)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddExtensionsToRequest(this: *mut core::ffi::c_void, pcertextensions: *mut super::CERT_EXTENSIONS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "AddExtensionsToRequest", 70usize, |this| ((**(this as *const *const IEnroll_Vtbl)).AddExtensionsToRequest)(this, pcertextensions), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddAuthenticatedAttributesToPKCS7Request(this: *mut core::ffi::c_void, pattributes: *mut super::CRYPT_ATTRIBUTES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "AddAuthenticatedAttributesToPKCS7Request", 71usize, |this| ((**(this as *const *const IEnroll_Vtbl)).AddAuthenticatedAttributesToPKCS7Request)(this, pattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePKCS7RequestFromRequest(this: *mut core::ffi::c_void, prequest: *mut super::CRYPT_INTEGER_BLOB, psigningcertcontext: *const super::CERT_CONTEXT, ppkcs7blob: *mut super::CRYPT_INTEGER_BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll", "CreatePKCS7RequestFromRequest", 72usize, |this| ((**(this as *const *const IEnroll_Vtbl)).CreatePKCS7RequestFromRequest)(this, prequest, psigningcertcontext, ppkcs7blob), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            createFilePKCS10WStr,
+            acceptFilePKCS7WStr,
+            createPKCS10WStr,
+            acceptPKCS7Blob,
+            getCertContextFromPKCS7,
+            getMyStore,
+            getCAStore,
+            getROOTHStore,
+            enumProvidersWStr,
+            enumContainersWStr,
+            freeRequestInfoBlob,
+            MyStoreNameWStr,
+            SetMyStoreNameWStr,
+            MyStoreTypeWStr,
+            SetMyStoreTypeWStr,
+            MyStoreFlags,
+            SetMyStoreFlags,
+            CAStoreNameWStr,
+            SetCAStoreNameWStr,
+            CAStoreTypeWStr,
+            SetCAStoreTypeWStr,
+            CAStoreFlags,
+            SetCAStoreFlags,
+            RootStoreNameWStr,
+            SetRootStoreNameWStr,
+            RootStoreTypeWStr,
+            SetRootStoreTypeWStr,
+            RootStoreFlags,
+            SetRootStoreFlags,
+            RequestStoreNameWStr,
+            SetRequestStoreNameWStr,
+            RequestStoreTypeWStr,
+            SetRequestStoreTypeWStr,
+            RequestStoreFlags,
+            SetRequestStoreFlags,
+            ContainerNameWStr,
+            SetContainerNameWStr,
+            ProviderNameWStr,
+            SetProviderNameWStr,
+            ProviderType,
+            SetProviderType,
+            KeySpec,
+            SetKeySpec,
+            ProviderFlags,
+            SetProviderFlags,
+            UseExistingKeySet,
+            SetUseExistingKeySet,
+            GenKeyFlags,
+            SetGenKeyFlags,
+            DeleteRequestCert,
+            SetDeleteRequestCert,
+            WriteCertToUserDS,
+            SetWriteCertToUserDS,
+            EnableT61DNEncoding,
+            SetEnableT61DNEncoding,
+            WriteCertToCSP,
+            SetWriteCertToCSP,
+            SPCFileNameWStr,
+            SetSPCFileNameWStr,
+            PVKFileNameWStr,
+            SetPVKFileNameWStr,
+            HashAlgorithmWStr,
+            SetHashAlgorithmWStr,
+            RenewalCertificate,
+            SetRenewalCertificate,
+            AddCertTypeToRequestWStr,
+            AddNameValuePairToSignatureWStr,
+            AddExtensionsToRequest,
+            AddAuthenticatedAttributesToPKCS7Request,
+            CreatePKCS7RequestFromRequest,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnroll as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnroll2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InstallPKCS7Blob(this: *mut core::ffi::c_void, pblobpkcs7: *mut super::CRYPT_INTEGER_BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll2", "InstallPKCS7Blob", 73usize, |this| ((**(this as *const *const IEnroll2_Vtbl)).InstallPKCS7Blob)(this, pblobpkcs7), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll2", "Reset", 74usize, |this| ((**(this as *const *const IEnroll2_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSupportedKeySpec(this: *mut core::ffi::c_void, pdwkeyspec: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll2", "GetSupportedKeySpec", 75usize, |this| ((**(this as *const *const IEnroll2_Vtbl)).GetSupportedKeySpec)(this, pdwkeyspec), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKeyLen(this: *mut core::ffi::c_void, fmin: super::super::super::Foundation::BOOL, fexchange: super::super::super::Foundation::BOOL, pdwkeysize: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll2", "GetKeyLen", 76usize, |this| ((**(this as *const *const IEnroll2_Vtbl)).GetKeyLen)(this, fmin, fexchange, pdwkeysize), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumAlgs(this: *mut core::ffi::c_void, dwindex: i32, algclass: i32, pdwalgid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll2", "EnumAlgs", 77usize, |this| ((**(this as *const *const IEnroll2_Vtbl)).EnumAlgs)(this, dwindex, algclass, pdwalgid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAlgNameWStr(this: *mut core::ffi::c_void, algid: i32, ppwsz: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll2", "GetAlgNameWStr", 78usize, |this| ((**(this as *const *const IEnroll2_Vtbl)).GetAlgNameWStr)(this, algid, ppwsz), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReuseHardwareKeyIfUnableToGenNew(this: *mut core::ffi::c_void, freusehardwarekeyifunabletogennew: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll2", "SetReuseHardwareKeyIfUnableToGenNew", 79usize, |this| ((**(this as *const *const IEnroll2_Vtbl)).SetReuseHardwareKeyIfUnableToGenNew)(this, freusehardwarekeyifunabletogennew), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReuseHardwareKeyIfUnableToGenNew(this: *mut core::ffi::c_void, freusehardwarekeyifunabletogennew: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll2", "ReuseHardwareKeyIfUnableToGenNew", 80usize, |this| ((**(this as *const *const IEnroll2_Vtbl)).ReuseHardwareKeyIfUnableToGenNew)(this, freusehardwarekeyifunabletogennew), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHashAlgID(this: *mut core::ffi::c_void, hashalgid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll2", "SetHashAlgID", 81usize, |this| ((**(this as *const *const IEnroll2_Vtbl)).SetHashAlgID)(this, hashalgid), |result| Some(*result))
+        }
+        unsafe extern "system" fn HashAlgID(this: *mut core::ffi::c_void, hashalgid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll2", "HashAlgID", 82usize, |this| ((**(this as *const *const IEnroll2_Vtbl)).HashAlgID)(this, hashalgid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHStoreMy(this: *mut core::ffi::c_void, hstore: super::HCERTSTORE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll2", "SetHStoreMy", 83usize, |this| ((**(this as *const *const IEnroll2_Vtbl)).SetHStoreMy)(this, hstore), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHStoreCA(this: *mut core::ffi::c_void, hstore: super::HCERTSTORE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll2", "SetHStoreCA", 84usize, |this| ((**(this as *const *const IEnroll2_Vtbl)).SetHStoreCA)(this, hstore), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHStoreROOT(this: *mut core::ffi::c_void, hstore: super::HCERTSTORE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll2", "SetHStoreROOT", 85usize, |this| ((**(this as *const *const IEnroll2_Vtbl)).SetHStoreROOT)(this, hstore), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHStoreRequest(this: *mut core::ffi::c_void, hstore: super::HCERTSTORE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll2", "SetHStoreRequest", 86usize, |this| ((**(this as *const *const IEnroll2_Vtbl)).SetHStoreRequest)(this, hstore), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLimitExchangeKeyToEncipherment(this: *mut core::ffi::c_void, flimitexchangekeytoencipherment: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll2", "SetLimitExchangeKeyToEncipherment", 87usize, |this| ((**(this as *const *const IEnroll2_Vtbl)).SetLimitExchangeKeyToEncipherment)(this, flimitexchangekeytoencipherment), |result| Some(*result))
+        }
+        unsafe extern "system" fn LimitExchangeKeyToEncipherment(this: *mut core::ffi::c_void, flimitexchangekeytoencipherment: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll2", "LimitExchangeKeyToEncipherment", 88usize, |this| ((**(this as *const *const IEnroll2_Vtbl)).LimitExchangeKeyToEncipherment)(this, flimitexchangekeytoencipherment), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnableSMIMECapabilities(this: *mut core::ffi::c_void, fenablesmimecapabilities: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll2", "SetEnableSMIMECapabilities", 89usize, |this| ((**(this as *const *const IEnroll2_Vtbl)).SetEnableSMIMECapabilities)(this, fenablesmimecapabilities), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableSMIMECapabilities(this: *mut core::ffi::c_void, fenablesmimecapabilities: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll2", "EnableSMIMECapabilities", 90usize, |this| ((**(this as *const *const IEnroll2_Vtbl)).EnableSMIMECapabilities)(this, fenablesmimecapabilities), |result| Some(*result))
+        }
+        Self {
+            base__: <IEnroll_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InstallPKCS7Blob,
+            Reset,
+            GetSupportedKeySpec,
+            GetKeyLen,
+            EnumAlgs,
+            GetAlgNameWStr,
+            SetReuseHardwareKeyIfUnableToGenNew,
+            ReuseHardwareKeyIfUnableToGenNew,
+            SetHashAlgID,
+            HashAlgID,
+            SetHStoreMy,
+            SetHStoreCA,
+            SetHStoreROOT,
+            SetHStoreRequest,
+            SetLimitExchangeKeyToEncipherment,
+            LimitExchangeKeyToEncipherment,
+            SetEnableSMIMECapabilities,
+            EnableSMIMECapabilities,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnroll2 as windows_core::Interface>::IID || <IEnroll_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnroll4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetThumbPrintWStr(this: *mut core::ffi::c_void, thumbprintblob: super::CRYPT_INTEGER_BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "SetThumbPrintWStr", 91usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).SetThumbPrintWStr)(this, thumbprintblob), |result| Some(*result))
+        }
+        unsafe extern "system" fn ThumbPrintWStr(this: *mut core::ffi::c_void, thumbprintblob: *mut super::CRYPT_INTEGER_BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "ThumbPrintWStr", 92usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).ThumbPrintWStr)(this, thumbprintblob), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivateKeyArchiveCertificate(this: *mut core::ffi::c_void, pprivatekeyarchivecert: *const super::CERT_CONTEXT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "SetPrivateKeyArchiveCertificate", 93usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).SetPrivateKeyArchiveCertificate)(this, pprivatekeyarchivecert), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrivateKeyArchiveCertificate(this: *mut core::ffi::c_void) -> *mut super::CERT_CONTEXT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "GetPrivateKeyArchiveCertificate", 94usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).GetPrivateKeyArchiveCertificate)(this), |_| None)
+        }
+        unsafe extern "system" fn binaryBlobToString(this: *mut core::ffi::c_void, flags: i32, pblobbinary: *mut super::CRYPT_INTEGER_BLOB, ppwszstring: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "binaryBlobToString", 95usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).binaryBlobToString)(this, flags, pblobbinary, ppwszstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn stringToBinaryBlob(this: *mut core::ffi::c_void, flags: i32, pwszstring: windows_core::PCWSTR, pblobbinary: *mut super::CRYPT_INTEGER_BLOB, pdwskip: *mut i32, pdwflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "stringToBinaryBlob", 96usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).stringToBinaryBlob)(this, flags, pwszstring, pblobbinary, pdwskip, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn addExtensionToRequestWStr(this: *mut core::ffi::c_void, flags: i32, pwszname: windows_core::PCWSTR, pblobvalue: *mut super::CRYPT_INTEGER_BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "addExtensionToRequestWStr", 97usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).addExtensionToRequestWStr)(this, flags, pwszname, pblobvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn addAttributeToRequestWStr(this: *mut core::ffi::c_void, flags: i32, pwszname: windows_core::PCWSTR, pblobvalue: *mut super::CRYPT_INTEGER_BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "addAttributeToRequestWStr", 98usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).addAttributeToRequestWStr)(this, flags, pwszname, pblobvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn addNameValuePairToRequestWStr(this: *mut core::ffi::c_void, flags: i32, pwszname: windows_core::PCWSTR, pwszvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "addNameValuePairToRequestWStr", 99usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).addNameValuePairToRequestWStr)(this, flags, pwszname, pwszvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn resetExtensions(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "resetExtensions", 100usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).resetExtensions)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn resetAttributes(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "resetAttributes", 101usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).resetAttributes)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn createRequestWStr(this: *mut core::ffi::c_void, flags: CERT_CREATE_REQUEST_FLAGS, pwszdnname: windows_core::PCWSTR, pwszusage: windows_core::PCWSTR, pblobrequest: *mut super::CRYPT_INTEGER_BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "createRequestWStr", 102usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).createRequestWStr)(this, flags, pwszdnname, pwszusage, pblobrequest), |result| Some(*result))
+        }
+        unsafe extern "system" fn createFileRequestWStr(this: *mut core::ffi::c_void, flags: CERT_CREATE_REQUEST_FLAGS, pwszdnname: windows_core::PCWSTR, pwszusage: windows_core::PCWSTR, pwszrequestfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "createFileRequestWStr", 103usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).createFileRequestWStr)(this, flags, pwszdnname, pwszusage, pwszrequestfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn acceptResponseBlob(this: *mut core::ffi::c_void, pblobresponse: *mut super::CRYPT_INTEGER_BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "acceptResponseBlob", 104usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).acceptResponseBlob)(this, pblobresponse), |result| Some(*result))
+        }
+        unsafe extern "system" fn acceptFileResponseWStr(this: *mut core::ffi::c_void, pwszresponsefilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "acceptFileResponseWStr", 105usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).acceptFileResponseWStr)(this, pwszresponsefilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn getCertContextFromResponseBlob(this: *mut core::ffi::c_void, pblobresponse: *mut super::CRYPT_INTEGER_BLOB, ppcertcontext: *mut *mut super::CERT_CONTEXT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "getCertContextFromResponseBlob", 106usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).getCertContextFromResponseBlob)(this, pblobresponse, ppcertcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn getCertContextFromFileResponseWStr(this: *mut core::ffi::c_void, pwszresponsefilename: windows_core::PCWSTR, ppcertcontext: *mut *mut super::CERT_CONTEXT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "getCertContextFromFileResponseWStr", 107usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).getCertContextFromFileResponseWStr)(this, pwszresponsefilename, ppcertcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn createPFXWStr(this: *mut core::ffi::c_void, pwszpassword: windows_core::PCWSTR, pblobpfx: *mut super::CRYPT_INTEGER_BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "createPFXWStr", 108usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).createPFXWStr)(this, pwszpassword, pblobpfx), |result| Some(*result))
+        }
+        unsafe extern "system" fn createFilePFXWStr(this: *mut core::ffi::c_void, pwszpassword: windows_core::PCWSTR, pwszpfxfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "createFilePFXWStr", 109usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).createFilePFXWStr)(this, pwszpassword, pwszpfxfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn setPendingRequestInfoWStr(this: *mut core::ffi::c_void, lrequestid: i32, pwszcadns: windows_core::PCWSTR, pwszcaname: windows_core::PCWSTR, pwszfriendlyname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "setPendingRequestInfoWStr", 110usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).setPendingRequestInfoWStr)(this, lrequestid, pwszcadns, pwszcaname, pwszfriendlyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn enumPendingRequestWStr(this: *mut core::ffi::c_void, lindex: i32, ldesiredproperty: PENDING_REQUEST_DESIRED_PROPERTY, ppproperty: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "enumPendingRequestWStr", 111usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).enumPendingRequestWStr)(this, lindex, ldesiredproperty, ppproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn removePendingRequestWStr(this: *mut core::ffi::c_void, thumbprintblob: super::CRYPT_INTEGER_BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "removePendingRequestWStr", 112usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).removePendingRequestWStr)(this, thumbprintblob), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKeyLenEx(this: *mut core::ffi::c_void, lsizespec: XEKL_KEYSIZE, lkeyspec: XEKL_KEYSPEC, pdwkeysize: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "GetKeyLenEx", 113usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).GetKeyLenEx)(this, lsizespec, lkeyspec, pdwkeysize), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstallPKCS7BlobEx(this: *mut core::ffi::c_void, pblobpkcs7: *mut super::CRYPT_INTEGER_BLOB, plcertinstalled: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "InstallPKCS7BlobEx", 114usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).InstallPKCS7BlobEx)(this, pblobpkcs7, plcertinstalled), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddCertTypeToRequestWStrEx(this: *mut core::ffi::c_void, ltype: ADDED_CERT_TYPE, pwszoidorname: windows_core::PCWSTR, lmajorversion: i32, fminorversion: super::super::super::Foundation::BOOL, lminorversion: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "AddCertTypeToRequestWStrEx", 115usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).AddCertTypeToRequestWStrEx)(this, ltype, pwszoidorname, lmajorversion, fminorversion, lminorversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn getProviderTypeWStr(this: *mut core::ffi::c_void, pwszprovname: windows_core::PCWSTR, plprovtype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "getProviderTypeWStr", 116usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).getProviderTypeWStr)(this, pwszprovname, plprovtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn addBlobPropertyToCertificateWStr(this: *mut core::ffi::c_void, lpropertyid: i32, lreserved: i32, pblobproperty: *mut super::CRYPT_INTEGER_BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "addBlobPropertyToCertificateWStr", 117usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).addBlobPropertyToCertificateWStr)(this, lpropertyid, lreserved, pblobproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSignerCertificate(this: *mut core::ffi::c_void, psignercert: *const super::CERT_CONTEXT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "SetSignerCertificate", 118usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).SetSignerCertificate)(this, psignercert), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClientId(this: *mut core::ffi::c_void, lclientid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "SetClientId", 119usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).SetClientId)(this, lclientid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClientId(this: *mut core::ffi::c_void, plclientid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "ClientId", 120usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).ClientId)(this, plclientid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIncludeSubjectKeyID(this: *mut core::ffi::c_void, finclude: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "SetIncludeSubjectKeyID", 121usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).SetIncludeSubjectKeyID)(this, finclude), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncludeSubjectKeyID(this: *mut core::ffi::c_void, pfinclude: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnroll4", "IncludeSubjectKeyID", 122usize, |this| ((**(this as *const *const IEnroll4_Vtbl)).IncludeSubjectKeyID)(this, pfinclude), |result| Some(*result))
+        }
+        Self {
+            base__: <IEnroll2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetThumbPrintWStr,
+            ThumbPrintWStr,
+            SetPrivateKeyArchiveCertificate,
+            GetPrivateKeyArchiveCertificate,
+            binaryBlobToString,
+            stringToBinaryBlob,
+            addExtensionToRequestWStr,
+            addAttributeToRequestWStr,
+            addNameValuePairToRequestWStr,
+            resetExtensions,
+            resetAttributes,
+            createRequestWStr,
+            createFileRequestWStr,
+            acceptResponseBlob,
+            acceptFileResponseWStr,
+            getCertContextFromResponseBlob,
+            getCertContextFromFileResponseWStr,
+            createPFXWStr,
+            createFilePFXWStr,
+            setPendingRequestInfoWStr,
+            enumPendingRequestWStr,
+            removePendingRequestWStr,
+            GetKeyLenEx,
+            InstallPKCS7BlobEx,
+            AddCertTypeToRequestWStrEx,
+            getProviderTypeWStr,
+            addBlobPropertyToCertificateWStr,
+            SetSignerCertificate,
+            SetClientId,
+            ClientId,
+            SetIncludeSubjectKeyID,
+            IncludeSubjectKeyID,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnroll4 as windows_core::Interface>::IID || <IEnroll2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumCERTVIEWATTRIBUTE_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, pindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWATTRIBUTE", "Next", 7usize, |this| ((**(this as *const *const IEnumCERTVIEWATTRIBUTE_Vtbl)).Next)(this, pindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, pstrout: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWATTRIBUTE", "GetName", 8usize, |this| ((**(this as *const *const IEnumCERTVIEWATTRIBUTE_Vtbl)).GetName)(this, pstrout), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, pstrout: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWATTRIBUTE", "GetValue", 9usize, |this| ((**(this as *const *const IEnumCERTVIEWATTRIBUTE_Vtbl)).GetValue)(this, pstrout), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWATTRIBUTE", "Skip", 10usize, |this| ((**(this as *const *const IEnumCERTVIEWATTRIBUTE_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWATTRIBUTE", "Reset", 11usize, |this| ((**(this as *const *const IEnumCERTVIEWATTRIBUTE_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWATTRIBUTE", "Clone", 12usize, |this| ((**(this as *const *const IEnumCERTVIEWATTRIBUTE_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Next,
+            GetName,
+            GetValue,
+            Skip,
+            Reset,
+            #[cfg(feature = "Win32_System_Com")]
+            Clone,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Clone: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumCERTVIEWATTRIBUTE as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumCERTVIEWCOLUMN_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, pindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWCOLUMN", "Next", 7usize, |this| ((**(this as *const *const IEnumCERTVIEWCOLUMN_Vtbl)).Next)(this, pindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, pstrout: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWCOLUMN", "GetName", 8usize, |this| ((**(this as *const *const IEnumCERTVIEWCOLUMN_Vtbl)).GetName)(this, pstrout), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayName(this: *mut core::ffi::c_void, pstrout: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWCOLUMN", "GetDisplayName", 9usize, |this| ((**(this as *const *const IEnumCERTVIEWCOLUMN_Vtbl)).GetDisplayName)(this, pstrout), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, ptype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWCOLUMN", "GetType", 10usize, |this| ((**(this as *const *const IEnumCERTVIEWCOLUMN_Vtbl)).GetType)(this, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsIndexed(this: *mut core::ffi::c_void, pindexed: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWCOLUMN", "IsIndexed", 11usize, |this| ((**(this as *const *const IEnumCERTVIEWCOLUMN_Vtbl)).IsIndexed)(this, pindexed), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxLength(this: *mut core::ffi::c_void, pmaxlength: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWCOLUMN", "GetMaxLength", 12usize, |this| ((**(this as *const *const IEnumCERTVIEWCOLUMN_Vtbl)).GetMaxLength)(this, pmaxlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, flags: ENUM_CERT_COLUMN_VALUE_FLAGS, pvarvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWCOLUMN", "GetValue", 13usize, |this| ((**(this as *const *const IEnumCERTVIEWCOLUMN_Vtbl)).GetValue)(this, flags, pvarvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWCOLUMN", "Skip", 14usize, |this| ((**(this as *const *const IEnumCERTVIEWCOLUMN_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWCOLUMN", "Reset", 15usize, |this| ((**(this as *const *const IEnumCERTVIEWCOLUMN_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWCOLUMN", "Clone", 16usize, |this| ((**(this as *const *const IEnumCERTVIEWCOLUMN_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Next,
+            GetName,
+            GetDisplayName,
+            GetType,
+            IsIndexed,
+            GetMaxLength,
+            GetValue,
+            Skip,
+            Reset,
+            #[cfg(feature = "Win32_System_Com")]
+            Clone,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Clone: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumCERTVIEWCOLUMN as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumCERTVIEWEXTENSION_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, pindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWEXTENSION", "Next", 7usize, |this| ((**(this as *const *const IEnumCERTVIEWEXTENSION_Vtbl)).Next)(this, pindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, pstrout: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWEXTENSION", "GetName", 8usize, |this| ((**(this as *const *const IEnumCERTVIEWEXTENSION_Vtbl)).GetName)(this, pstrout), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlags(this: *mut core::ffi::c_void, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWEXTENSION", "GetFlags", 9usize, |this| ((**(this as *const *const IEnumCERTVIEWEXTENSION_Vtbl)).GetFlags)(this, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, r#type: CERT_PROPERTY_TYPE, flags: ENUM_CERT_COLUMN_VALUE_FLAGS, pvarvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWEXTENSION", "GetValue", 10usize, |this| ((**(this as *const *const IEnumCERTVIEWEXTENSION_Vtbl)).GetValue)(this, r#type, flags, pvarvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWEXTENSION", "Skip", 11usize, |this| ((**(this as *const *const IEnumCERTVIEWEXTENSION_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWEXTENSION", "Reset", 12usize, |this| ((**(this as *const *const IEnumCERTVIEWEXTENSION_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWEXTENSION", "Clone", 13usize, |this| ((**(this as *const *const IEnumCERTVIEWEXTENSION_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Next,
+            GetName,
+            GetFlags,
+            GetValue,
+            Skip,
+            Reset,
+            #[cfg(feature = "Win32_System_Com")]
+            Clone,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Clone: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumCERTVIEWEXTENSION as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumCERTVIEWROW_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, pindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWROW", "Next", 7usize, |this| ((**(this as *const *const IEnumCERTVIEWROW_Vtbl)).Next)(this, pindex), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumCertViewColumn(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWROW", "EnumCertViewColumn", 8usize, |this| ((**(this as *const *const IEnumCERTVIEWROW_Vtbl)).EnumCertViewColumn)(this, ppenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumCertViewAttribute(this: *mut core::ffi::c_void, flags: i32, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWROW", "EnumCertViewAttribute", 9usize, |this| ((**(this as *const *const IEnumCERTVIEWROW_Vtbl)).EnumCertViewAttribute)(this, flags, ppenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumCertViewExtension(this: *mut core::ffi::c_void, flags: i32, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWROW", "EnumCertViewExtension", 10usize, |this| ((**(this as *const *const IEnumCERTVIEWROW_Vtbl)).EnumCertViewExtension)(this, flags, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWROW", "Skip", 11usize, |this| ((**(this as *const *const IEnumCERTVIEWROW_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWROW", "Reset", 12usize, |this| ((**(this as *const *const IEnumCERTVIEWROW_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWROW", "Clone", 13usize, |this| ((**(this as *const *const IEnumCERTVIEWROW_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxIndex(this: *mut core::ffi::c_void, pindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCERTVIEWROW", "GetMaxIndex", 14usize, |this| ((**(this as *const *const IEnumCERTVIEWROW_Vtbl)).GetMaxIndex)(this, pindex), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Next,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumCertViewColumn,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumCertViewColumn: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumCertViewAttribute,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumCertViewAttribute: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumCertViewExtension,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumCertViewExtension: 0,
+            Skip,
+            Reset,
+            #[cfg(feature = "Win32_System_Com")]
+            Clone,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Clone: 0,
+            GetMaxIndex,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumCERTVIEWROW as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INDESPolicy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDESPolicy", "Initialize", 3usize, |this| ((**(this as *const *const INDESPolicy_Vtbl)).Initialize)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uninitialize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDESPolicy", "Uninitialize", 4usize, |this| ((**(this as *const *const INDESPolicy_Vtbl)).Uninitialize)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GenerateChallenge(this: *mut core::ffi::c_void, pwsztemplate: windows_core::PCWSTR, pwszparams: windows_core::PCWSTR, ppwszresponse: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDESPolicy", "GenerateChallenge", 5usize, |this| ((**(this as *const *const INDESPolicy_Vtbl)).GenerateChallenge)(this, pwsztemplate, pwszparams, ppwszresponse), |result| Some(*result))
+        }
+        unsafe extern "system" fn VerifyRequest(this: *mut core::ffi::c_void, pctbrequest: *mut CERTTRANSBLOB, pctbsigningcertencoded: *mut CERTTRANSBLOB, pwsztemplate: windows_core::PCWSTR, pwsztransactionid: windows_core::PCWSTR, pfverified: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDESPolicy", "VerifyRequest", 6usize, |this| ((**(this as *const *const INDESPolicy_Vtbl)).VerifyRequest)(this, pctbrequest, pctbsigningcertencoded, pwsztemplate, pwsztransactionid, pfverified), |result| Some(*result))
+        }
+        unsafe extern "system" fn Notify(this: *mut core::ffi::c_void, pwszchallenge: windows_core::PCWSTR, pwsztransactionid: windows_core::PCWSTR, disposition: X509SCEPDisposition, lasthresult: i32, pctbissuedcertencoded: *mut CERTTRANSBLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INDESPolicy", "Notify", 7usize, |this| ((**(this as *const *const INDESPolicy_Vtbl)).Notify)(this, pwszchallenge, pwsztransactionid, disposition, lasthresult, pctbissuedcertencoded), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            Uninitialize,
+            GenerateChallenge,
+            VerifyRequest,
+            Notify,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INDESPolicy as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOCSPAdmin_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OCSPServiceProperties(this: *mut core::ffi::c_void, ppval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPAdmin", "OCSPServiceProperties", 7usize, |this| ((**(this as *const *const IOCSPAdmin_Vtbl)).OCSPServiceProperties)(this, ppval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OCSPCAConfigurationCollection(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPAdmin", "OCSPCAConfigurationCollection", 8usize, |this| ((**(this as *const *const IOCSPAdmin_Vtbl)).OCSPCAConfigurationCollection)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConfiguration(this: *mut core::ffi::c_void, bstrservername: core::mem::MaybeUninit<windows_core::BSTR>, bforce: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPAdmin", "GetConfiguration", 9usize, |this| ((**(this as *const *const IOCSPAdmin_Vtbl)).GetConfiguration)(this, bstrservername, bforce), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConfiguration(this: *mut core::ffi::c_void, bstrservername: core::mem::MaybeUninit<windows_core::BSTR>, bforce: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPAdmin", "SetConfiguration", 10usize, |this| ((**(this as *const *const IOCSPAdmin_Vtbl)).SetConfiguration)(this, bstrservername, bforce), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMyRoles(this: *mut core::ffi::c_void, bstrservername: core::mem::MaybeUninit<windows_core::BSTR>, proles: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPAdmin", "GetMyRoles", 11usize, |this| ((**(this as *const *const IOCSPAdmin_Vtbl)).GetMyRoles)(this, bstrservername, proles), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ping(this: *mut core::ffi::c_void, bstrservername: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPAdmin", "Ping", 12usize, |this| ((**(this as *const *const IOCSPAdmin_Vtbl)).Ping)(this, bstrservername), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSecurity(this: *mut core::ffi::c_void, bstrservername: core::mem::MaybeUninit<windows_core::BSTR>, bstrval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPAdmin", "SetSecurity", 13usize, |this| ((**(this as *const *const IOCSPAdmin_Vtbl)).SetSecurity)(this, bstrservername, bstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSecurity(this: *mut core::ffi::c_void, bstrservername: core::mem::MaybeUninit<windows_core::BSTR>, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPAdmin", "GetSecurity", 14usize, |this| ((**(this as *const *const IOCSPAdmin_Vtbl)).GetSecurity)(this, bstrservername, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSigningCertificates(this: *mut core::ffi::c_void, bstrservername: core::mem::MaybeUninit<windows_core::BSTR>, pcacertvar: *const core::mem::MaybeUninit<windows_core::VARIANT>, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPAdmin", "GetSigningCertificates", 15usize, |this| ((**(this as *const *const IOCSPAdmin_Vtbl)).GetSigningCertificates)(this, bstrservername, pcacertvar, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHashAlgorithms(this: *mut core::ffi::c_void, bstrservername: core::mem::MaybeUninit<windows_core::BSTR>, bstrcaid: core::mem::MaybeUninit<windows_core::BSTR>, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPAdmin", "GetHashAlgorithms", 16usize, |this| ((**(this as *const *const IOCSPAdmin_Vtbl)).GetHashAlgorithms)(this, bstrservername, bstrcaid, pval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            OCSPServiceProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OCSPServiceProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OCSPCAConfigurationCollection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OCSPCAConfigurationCollection: 0,
+            GetConfiguration,
+            SetConfiguration,
+            GetMyRoles,
+            Ping,
+            SetSecurity,
+            GetSecurity,
+            GetSigningCertificates,
+            GetHashAlgorithms,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOCSPAdmin as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOCSPCAConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Identifier(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfiguration", "Identifier", 7usize, |this| ((**(this as *const *const IOCSPCAConfiguration_Vtbl)).Identifier)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CACertificate(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfiguration", "CACertificate", 8usize, |this| ((**(this as *const *const IOCSPCAConfiguration_Vtbl)).CACertificate)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn HashAlgorithm(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfiguration", "HashAlgorithm", 9usize, |this| ((**(this as *const *const IOCSPCAConfiguration_Vtbl)).HashAlgorithm)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHashAlgorithm(this: *mut core::ffi::c_void, newval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfiguration", "SetHashAlgorithm", 10usize, |this| ((**(this as *const *const IOCSPCAConfiguration_Vtbl)).SetHashAlgorithm)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SigningFlags(this: *mut core::ffi::c_void, pval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfiguration", "SigningFlags", 11usize, |this| ((**(this as *const *const IOCSPCAConfiguration_Vtbl)).SigningFlags)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSigningFlags(this: *mut core::ffi::c_void, newval: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfiguration", "SetSigningFlags", 12usize, |this| ((**(this as *const *const IOCSPCAConfiguration_Vtbl)).SetSigningFlags)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SigningCertificate(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfiguration", "SigningCertificate", 13usize, |this| ((**(this as *const *const IOCSPCAConfiguration_Vtbl)).SigningCertificate)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSigningCertificate(this: *mut core::ffi::c_void, newval: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfiguration", "SetSigningCertificate", 14usize, |this| ((**(this as *const *const IOCSPCAConfiguration_Vtbl)).SetSigningCertificate)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReminderDuration(this: *mut core::ffi::c_void, pval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfiguration", "ReminderDuration", 15usize, |this| ((**(this as *const *const IOCSPCAConfiguration_Vtbl)).ReminderDuration)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReminderDuration(this: *mut core::ffi::c_void, newval: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfiguration", "SetReminderDuration", 16usize, |this| ((**(this as *const *const IOCSPCAConfiguration_Vtbl)).SetReminderDuration)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorCode(this: *mut core::ffi::c_void, pval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfiguration", "ErrorCode", 17usize, |this| ((**(this as *const *const IOCSPCAConfiguration_Vtbl)).ErrorCode)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CSPName(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfiguration", "CSPName", 18usize, |this| ((**(this as *const *const IOCSPCAConfiguration_Vtbl)).CSPName)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeySpec(this: *mut core::ffi::c_void, pval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfiguration", "KeySpec", 19usize, |this| ((**(this as *const *const IOCSPCAConfiguration_Vtbl)).KeySpec)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderCLSID(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfiguration", "ProviderCLSID", 20usize, |this| ((**(this as *const *const IOCSPCAConfiguration_Vtbl)).ProviderCLSID)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProviderCLSID(this: *mut core::ffi::c_void, newval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfiguration", "SetProviderCLSID", 21usize, |this| ((**(this as *const *const IOCSPCAConfiguration_Vtbl)).SetProviderCLSID)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderProperties(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfiguration", "ProviderProperties", 22usize, |this| ((**(this as *const *const IOCSPCAConfiguration_Vtbl)).ProviderProperties)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProviderProperties(this: *mut core::ffi::c_void, newval: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfiguration", "SetProviderProperties", 23usize, |this| ((**(this as *const *const IOCSPCAConfiguration_Vtbl)).SetProviderProperties)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Modified(this: *mut core::ffi::c_void, pval: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfiguration", "Modified", 24usize, |this| ((**(this as *const *const IOCSPCAConfiguration_Vtbl)).Modified)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalRevocationInformation(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfiguration", "LocalRevocationInformation", 25usize, |this| ((**(this as *const *const IOCSPCAConfiguration_Vtbl)).LocalRevocationInformation)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocalRevocationInformation(this: *mut core::ffi::c_void, newval: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfiguration", "SetLocalRevocationInformation", 26usize, |this| ((**(this as *const *const IOCSPCAConfiguration_Vtbl)).SetLocalRevocationInformation)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SigningCertificateTemplate(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfiguration", "SigningCertificateTemplate", 27usize, |this| ((**(this as *const *const IOCSPCAConfiguration_Vtbl)).SigningCertificateTemplate)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSigningCertificateTemplate(this: *mut core::ffi::c_void, newval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfiguration", "SetSigningCertificateTemplate", 28usize, |this| ((**(this as *const *const IOCSPCAConfiguration_Vtbl)).SetSigningCertificateTemplate)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CAConfig(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfiguration", "CAConfig", 29usize, |this| ((**(this as *const *const IOCSPCAConfiguration_Vtbl)).CAConfig)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCAConfig(this: *mut core::ffi::c_void, newval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfiguration", "SetCAConfig", 30usize, |this| ((**(this as *const *const IOCSPCAConfiguration_Vtbl)).SetCAConfig)(this, newval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Identifier,
+            CACertificate,
+            HashAlgorithm,
+            SetHashAlgorithm,
+            SigningFlags,
+            SetSigningFlags,
+            SigningCertificate,
+            SetSigningCertificate,
+            ReminderDuration,
+            SetReminderDuration,
+            ErrorCode,
+            CSPName,
+            KeySpec,
+            ProviderCLSID,
+            SetProviderCLSID,
+            ProviderProperties,
+            SetProviderProperties,
+            Modified,
+            LocalRevocationInformation,
+            SetLocalRevocationInformation,
+            SigningCertificateTemplate,
+            SetSigningCertificateTemplate,
+            CAConfig,
+            SetCAConfig,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOCSPCAConfiguration as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOCSPCAConfigurationCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfigurationCollection", "_NewEnum", 7usize, |this| ((**(this as *const *const IOCSPCAConfigurationCollection_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfigurationCollection", "get_Item", 8usize, |this| ((**(this as *const *const IOCSPCAConfigurationCollection_Vtbl)).get_Item)(this, index, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfigurationCollection", "Count", 9usize, |this| ((**(this as *const *const IOCSPCAConfigurationCollection_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_ItemByName(this: *mut core::ffi::c_void, bstridentifier: core::mem::MaybeUninit<windows_core::BSTR>, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfigurationCollection", "get_ItemByName", 10usize, |this| ((**(this as *const *const IOCSPCAConfigurationCollection_Vtbl)).get_ItemByName)(this, bstridentifier, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateCAConfiguration(this: *mut core::ffi::c_void, bstridentifier: core::mem::MaybeUninit<windows_core::BSTR>, varcacert: core::mem::MaybeUninit<windows_core::VARIANT>, ppval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfigurationCollection", "CreateCAConfiguration", 11usize, |this| ((**(this as *const *const IOCSPCAConfigurationCollection_Vtbl)).CreateCAConfiguration)(this, bstridentifier, varcacert, ppval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteCAConfiguration(this: *mut core::ffi::c_void, bstridentifier: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPCAConfigurationCollection", "DeleteCAConfiguration", 12usize, |this| ((**(this as *const *const IOCSPCAConfigurationCollection_Vtbl)).DeleteCAConfiguration)(this, bstridentifier), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            get_Item,
+            Count,
+            get_ItemByName,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateCAConfiguration,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateCAConfiguration: 0,
+            DeleteCAConfiguration,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOCSPCAConfigurationCollection as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOCSPProperty_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPProperty", "Name", 7usize, |this| ((**(this as *const *const IOCSPProperty_Vtbl)).Name)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPProperty", "Value", 8usize, |this| ((**(this as *const *const IOCSPProperty_Vtbl)).Value)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, newval: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPProperty", "SetValue", 9usize, |this| ((**(this as *const *const IOCSPProperty_Vtbl)).SetValue)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Modified(this: *mut core::ffi::c_void, pval: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPProperty", "Modified", 10usize, |this| ((**(this as *const *const IOCSPProperty_Vtbl)).Modified)(this, pval), |result| Some(*result))
+        }
+        Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, Value, SetValue, Modified }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOCSPProperty as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOCSPPropertyCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPPropertyCollection", "_NewEnum", 7usize, |this| ((**(this as *const *const IOCSPPropertyCollection_Vtbl))._NewEnum)(this, ppval), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPPropertyCollection", "get_Item", 8usize, |this| ((**(this as *const *const IOCSPPropertyCollection_Vtbl)).get_Item)(this, index, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPPropertyCollection", "Count", 9usize, |this| ((**(this as *const *const IOCSPPropertyCollection_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_ItemByName(this: *mut core::ffi::c_void, bstrpropname: core::mem::MaybeUninit<windows_core::BSTR>, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPPropertyCollection", "get_ItemByName", 10usize, |this| ((**(this as *const *const IOCSPPropertyCollection_Vtbl)).get_ItemByName)(this, bstrpropname, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateProperty(this: *mut core::ffi::c_void, bstrpropname: core::mem::MaybeUninit<windows_core::BSTR>, pvarpropvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPPropertyCollection", "CreateProperty", 11usize, |this| ((**(this as *const *const IOCSPPropertyCollection_Vtbl)).CreateProperty)(this, bstrpropname, pvarpropvalue, ppval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteProperty(this: *mut core::ffi::c_void, bstrpropname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPPropertyCollection", "DeleteProperty", 12usize, |this| ((**(this as *const *const IOCSPPropertyCollection_Vtbl)).DeleteProperty)(this, bstrpropname), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeFromProperties(this: *mut core::ffi::c_void, pvarproperties: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPPropertyCollection", "InitializeFromProperties", 13usize, |this| ((**(this as *const *const IOCSPPropertyCollection_Vtbl)).InitializeFromProperties)(this, pvarproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllProperties(this: *mut core::ffi::c_void, pvarproperties: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOCSPPropertyCollection", "GetAllProperties", 14usize, |this| ((**(this as *const *const IOCSPPropertyCollection_Vtbl)).GetAllProperties)(this, pvarproperties), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            get_Item,
+            Count,
+            get_ItemByName,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateProperty,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateProperty: 0,
+            DeleteProperty,
+            InitializeFromProperties,
+            GetAllProperties,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOCSPPropertyCollection as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IObjectId_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeFromName(this: *mut core::ffi::c_void, name: CERTENROLL_OBJECTID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectId", "InitializeFromName", 7usize, |this| ((**(this as *const *const IObjectId_Vtbl)).InitializeFromName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeFromValue(this: *mut core::ffi::c_void, strvalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectId", "InitializeFromValue", 8usize, |this| ((**(this as *const *const IObjectId_Vtbl)).InitializeFromValue)(this, strvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeFromAlgorithmName(this: *mut core::ffi::c_void, groupid: ObjectIdGroupId, keyflags: ObjectIdPublicKeyFlags, algflags: AlgorithmFlags, stralgorithmname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectId", "InitializeFromAlgorithmName", 9usize, |this| ((**(this as *const *const IObjectId_Vtbl)).InitializeFromAlgorithmName)(this, groupid, keyflags, algflags, stralgorithmname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pvalue: *mut CERTENROLL_OBJECTID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectId", "Name", 10usize, |this| ((**(this as *const *const IObjectId_Vtbl)).Name)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn FriendlyName(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectId", "FriendlyName", 11usize, |this| ((**(this as *const *const IObjectId_Vtbl)).FriendlyName)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFriendlyName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectId", "SetFriendlyName", 12usize, |this| ((**(this as *const *const IObjectId_Vtbl)).SetFriendlyName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectId", "Value", 13usize, |this| ((**(this as *const *const IObjectId_Vtbl)).Value)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAlgorithmName(this: *mut core::ffi::c_void, groupid: ObjectIdGroupId, keyflags: ObjectIdPublicKeyFlags, pstralgorithmname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectId", "GetAlgorithmName", 14usize, |this| ((**(this as *const *const IObjectId_Vtbl)).GetAlgorithmName)(this, groupid, keyflags, pstralgorithmname), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitializeFromName,
+            InitializeFromValue,
+            InitializeFromAlgorithmName,
+            Name,
+            FriendlyName,
+            SetFriendlyName,
+            Value,
+            GetAlgorithmName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IObjectId as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IObjectIds_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ItemByIndex(this: *mut core::ffi::c_void, index: i32, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectIds", "get_ItemByIndex", 7usize, |this| ((**(this as *const *const IObjectIds_Vtbl)).get_ItemByIndex)(this, index, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectIds", "Count", 8usize, |this| ((**(this as *const *const IObjectIds_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectIds", "_NewEnum", 9usize, |this| ((**(this as *const *const IObjectIds_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectIds", "Add", 10usize, |this| ((**(this as *const *const IObjectIds_Vtbl)).Add)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectIds", "Remove", 11usize, |this| ((**(this as *const *const IObjectIds_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectIds", "Clear", 12usize, |this| ((**(this as *const *const IObjectIds_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddRange(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectIds", "AddRange", 13usize, |this| ((**(this as *const *const IObjectIds_Vtbl)).AddRange)(this, pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ItemByIndex,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ItemByIndex: 0,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            Clear,
+            #[cfg(feature = "Win32_System_Com")]
+            AddRange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddRange: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IObjectIds as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPolicyQualifier_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeEncode(this: *mut core::ffi::c_void, strqualifier: core::mem::MaybeUninit<windows_core::BSTR>, r#type: PolicyQualifierType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPolicyQualifier", "InitializeEncode", 7usize, |this| ((**(this as *const *const IPolicyQualifier_Vtbl)).InitializeEncode)(this, strqualifier, r#type), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ObjectId(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPolicyQualifier", "ObjectId", 8usize, |this| ((**(this as *const *const IPolicyQualifier_Vtbl)).ObjectId)(this, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Qualifier(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPolicyQualifier", "Qualifier", 9usize, |this| ((**(this as *const *const IPolicyQualifier_Vtbl)).Qualifier)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, pvalue: *mut PolicyQualifierType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPolicyQualifier", "Type", 10usize, |this| ((**(this as *const *const IPolicyQualifier_Vtbl)).Type)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_RawData(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPolicyQualifier", "get_RawData", 11usize, |this| ((**(this as *const *const IPolicyQualifier_Vtbl)).get_RawData)(this, encoding, pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitializeEncode,
+            #[cfg(feature = "Win32_System_Com")]
+            ObjectId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ObjectId: 0,
+            Qualifier,
+            Type,
+            get_RawData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPolicyQualifier as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPolicyQualifiers_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ItemByIndex(this: *mut core::ffi::c_void, index: i32, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPolicyQualifiers", "get_ItemByIndex", 7usize, |this| ((**(this as *const *const IPolicyQualifiers_Vtbl)).get_ItemByIndex)(this, index, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPolicyQualifiers", "Count", 8usize, |this| ((**(this as *const *const IPolicyQualifiers_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPolicyQualifiers", "_NewEnum", 9usize, |this| ((**(this as *const *const IPolicyQualifiers_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPolicyQualifiers", "Add", 10usize, |this| ((**(this as *const *const IPolicyQualifiers_Vtbl)).Add)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPolicyQualifiers", "Remove", 11usize, |this| ((**(this as *const *const IPolicyQualifiers_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPolicyQualifiers", "Clear", 12usize, |this| ((**(this as *const *const IPolicyQualifiers_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ItemByIndex,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ItemByIndex: 0,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            Clear,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPolicyQualifiers as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISignerCertificate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, machinecontext: super::super::super::Foundation::VARIANT_BOOL, verifytype: X509PrivateKeyVerify, encoding: EncodingType, strcertificate: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignerCertificate", "Initialize", 7usize, |this| ((**(this as *const *const ISignerCertificate_Vtbl)).Initialize)(this, machinecontext, verifytype, encoding, strcertificate), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Certificate(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignerCertificate", "get_Certificate", 8usize, |this| ((**(this as *const *const ISignerCertificate_Vtbl)).get_Certificate)(this, encoding, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PrivateKey(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignerCertificate", "PrivateKey", 9usize, |this| ((**(this as *const *const ISignerCertificate_Vtbl)).PrivateKey)(this, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Silent(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignerCertificate", "Silent", 10usize, |this| ((**(this as *const *const ISignerCertificate_Vtbl)).Silent)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSilent(this: *mut core::ffi::c_void, value: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignerCertificate", "SetSilent", 11usize, |this| ((**(this as *const *const ISignerCertificate_Vtbl)).SetSilent)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParentWindow(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignerCertificate", "ParentWindow", 12usize, |this| ((**(this as *const *const ISignerCertificate_Vtbl)).ParentWindow)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParentWindow(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignerCertificate", "SetParentWindow", 13usize, |this| ((**(this as *const *const ISignerCertificate_Vtbl)).SetParentWindow)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UIContextMessage(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignerCertificate", "UIContextMessage", 14usize, |this| ((**(this as *const *const ISignerCertificate_Vtbl)).UIContextMessage)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUIContextMessage(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignerCertificate", "SetUIContextMessage", 15usize, |this| ((**(this as *const *const ISignerCertificate_Vtbl)).SetUIContextMessage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPin(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignerCertificate", "SetPin", 16usize, |this| ((**(this as *const *const ISignerCertificate_Vtbl)).SetPin)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SignatureInformation(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignerCertificate", "SignatureInformation", 17usize, |this| ((**(this as *const *const ISignerCertificate_Vtbl)).SignatureInformation)(this, ppvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            get_Certificate,
+            #[cfg(feature = "Win32_System_Com")]
+            PrivateKey,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PrivateKey: 0,
+            Silent,
+            SetSilent,
+            ParentWindow,
+            SetParentWindow,
+            UIContextMessage,
+            SetUIContextMessage,
+            SetPin,
+            #[cfg(feature = "Win32_System_Com")]
+            SignatureInformation,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SignatureInformation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISignerCertificate as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISignerCertificates_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ItemByIndex(this: *mut core::ffi::c_void, index: i32, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignerCertificates", "get_ItemByIndex", 7usize, |this| ((**(this as *const *const ISignerCertificates_Vtbl)).get_ItemByIndex)(this, index, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignerCertificates", "Count", 8usize, |this| ((**(this as *const *const ISignerCertificates_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignerCertificates", "_NewEnum", 9usize, |this| ((**(this as *const *const ISignerCertificates_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignerCertificates", "Add", 10usize, |this| ((**(this as *const *const ISignerCertificates_Vtbl)).Add)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignerCertificates", "Remove", 11usize, |this| ((**(this as *const *const ISignerCertificates_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignerCertificates", "Clear", 12usize, |this| ((**(this as *const *const ISignerCertificates_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Find(this: *mut core::ffi::c_void, psignercert: *mut core::ffi::c_void, pisignercert: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISignerCertificates", "Find", 13usize, |this| ((**(this as *const *const ISignerCertificates_Vtbl)).Find)(this, psignercert, pisignercert), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ItemByIndex,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ItemByIndex: 0,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            Clear,
+            #[cfg(feature = "Win32_System_Com")]
+            Find,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Find: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISignerCertificates as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISmimeCapabilities_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ItemByIndex(this: *mut core::ffi::c_void, index: i32, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmimeCapabilities", "get_ItemByIndex", 7usize, |this| ((**(this as *const *const ISmimeCapabilities_Vtbl)).get_ItemByIndex)(this, index, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmimeCapabilities", "Count", 8usize, |this| ((**(this as *const *const ISmimeCapabilities_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmimeCapabilities", "_NewEnum", 9usize, |this| ((**(this as *const *const ISmimeCapabilities_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmimeCapabilities", "Add", 10usize, |this| ((**(this as *const *const ISmimeCapabilities_Vtbl)).Add)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmimeCapabilities", "Remove", 11usize, |this| ((**(this as *const *const ISmimeCapabilities_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmimeCapabilities", "Clear", 12usize, |this| ((**(this as *const *const ISmimeCapabilities_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddFromCsp(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmimeCapabilities", "AddFromCsp", 13usize, |this| ((**(this as *const *const ISmimeCapabilities_Vtbl)).AddFromCsp)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddAvailableSmimeCapabilities(this: *mut core::ffi::c_void, machinecontext: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmimeCapabilities", "AddAvailableSmimeCapabilities", 14usize, |this| ((**(this as *const *const ISmimeCapabilities_Vtbl)).AddAvailableSmimeCapabilities)(this, machinecontext), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ItemByIndex,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ItemByIndex: 0,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            Clear,
+            #[cfg(feature = "Win32_System_Com")]
+            AddFromCsp,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddFromCsp: 0,
+            AddAvailableSmimeCapabilities,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmimeCapabilities as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISmimeCapability_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pobjectid: *mut core::ffi::c_void, bitcount: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmimeCapability", "Initialize", 7usize, |this| ((**(this as *const *const ISmimeCapability_Vtbl)).Initialize)(this, pobjectid, bitcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ObjectId(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmimeCapability", "ObjectId", 8usize, |this| ((**(this as *const *const ISmimeCapability_Vtbl)).ObjectId)(this, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn BitCount(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISmimeCapability", "BitCount", 9usize, |this| ((**(this as *const *const ISmimeCapability_Vtbl)).BitCount)(this, pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Initialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Initialize: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ObjectId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ObjectId: 0,
+            BitCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISmimeCapability as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX500DistinguishedName_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Decode(this: *mut core::ffi::c_void, strencodedname: core::mem::MaybeUninit<windows_core::BSTR>, encoding: EncodingType, nameflags: X500NameFlags) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX500DistinguishedName", "Decode", 7usize, |this| ((**(this as *const *const IX500DistinguishedName_Vtbl)).Decode)(this, strencodedname, encoding, nameflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Encode(this: *mut core::ffi::c_void, strname: core::mem::MaybeUninit<windows_core::BSTR>, nameflags: X500NameFlags) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX500DistinguishedName", "Encode", 8usize, |this| ((**(this as *const *const IX500DistinguishedName_Vtbl)).Encode)(this, strname, nameflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX500DistinguishedName", "Name", 9usize, |this| ((**(this as *const *const IX500DistinguishedName_Vtbl)).Name)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_EncodedName(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX500DistinguishedName", "get_EncodedName", 10usize, |this| ((**(this as *const *const IX500DistinguishedName_Vtbl)).get_EncodedName)(this, encoding, pvalue), |result| Some(*result))
+        }
+        Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Decode, Encode, Name, get_EncodedName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX500DistinguishedName as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509Attribute_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pobjectid: *mut core::ffi::c_void, encoding: EncodingType, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Attribute", "Initialize", 7usize, |this| ((**(this as *const *const IX509Attribute_Vtbl)).Initialize)(this, pobjectid, encoding, strencodeddata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ObjectId(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Attribute", "ObjectId", 8usize, |this| ((**(this as *const *const IX509Attribute_Vtbl)).ObjectId)(this, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_RawData(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Attribute", "get_RawData", 9usize, |this| ((**(this as *const *const IX509Attribute_Vtbl)).get_RawData)(this, encoding, pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Initialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Initialize: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ObjectId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ObjectId: 0,
+            get_RawData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509Attribute as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509AttributeArchiveKey_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeEncode(this: *mut core::ffi::c_void, pkey: *mut core::ffi::c_void, encoding: EncodingType, strcaxcert: core::mem::MaybeUninit<windows_core::BSTR>, palgorithm: *mut core::ffi::c_void, encryptionstrength: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509AttributeArchiveKey", "InitializeEncode", 10usize, |this| ((**(this as *const *const IX509AttributeArchiveKey_Vtbl)).InitializeEncode)(this, pkey, encoding, strcaxcert, palgorithm, encryptionstrength), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeDecode(this: *mut core::ffi::c_void, encoding: EncodingType, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509AttributeArchiveKey", "InitializeDecode", 11usize, |this| ((**(this as *const *const IX509AttributeArchiveKey_Vtbl)).InitializeDecode)(this, encoding, strencodeddata), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_EncryptedKeyBlob(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509AttributeArchiveKey", "get_EncryptedKeyBlob", 12usize, |this| ((**(this as *const *const IX509AttributeArchiveKey_Vtbl)).get_EncryptedKeyBlob)(this, encoding, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EncryptionAlgorithm(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509AttributeArchiveKey", "EncryptionAlgorithm", 13usize, |this| ((**(this as *const *const IX509AttributeArchiveKey_Vtbl)).EncryptionAlgorithm)(this, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn EncryptionStrength(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509AttributeArchiveKey", "EncryptionStrength", 14usize, |this| ((**(this as *const *const IX509AttributeArchiveKey_Vtbl)).EncryptionStrength)(this, pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <IX509Attribute_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeEncode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeEncode: 0,
+            InitializeDecode,
+            get_EncryptedKeyBlob,
+            #[cfg(feature = "Win32_System_Com")]
+            EncryptionAlgorithm,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EncryptionAlgorithm: 0,
+            EncryptionStrength,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509AttributeArchiveKey as windows_core::Interface>::IID || <IX509Attribute_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509AttributeArchiveKeyHash_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeEncodeFromEncryptedKeyBlob(this: *mut core::ffi::c_void, encoding: EncodingType, strencryptedkeyblob: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509AttributeArchiveKeyHash", "InitializeEncodeFromEncryptedKeyBlob", 10usize, |this| ((**(this as *const *const IX509AttributeArchiveKeyHash_Vtbl)).InitializeEncodeFromEncryptedKeyBlob)(this, encoding, strencryptedkeyblob), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeDecode(this: *mut core::ffi::c_void, encoding: EncodingType, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509AttributeArchiveKeyHash", "InitializeDecode", 11usize, |this| ((**(this as *const *const IX509AttributeArchiveKeyHash_Vtbl)).InitializeDecode)(this, encoding, strencodeddata), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_EncryptedKeyHashBlob(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509AttributeArchiveKeyHash", "get_EncryptedKeyHashBlob", 12usize, |this| ((**(this as *const *const IX509AttributeArchiveKeyHash_Vtbl)).get_EncryptedKeyHashBlob)(this, encoding, pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <IX509Attribute_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitializeEncodeFromEncryptedKeyBlob,
+            InitializeDecode,
+            get_EncryptedKeyHashBlob,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509AttributeArchiveKeyHash as windows_core::Interface>::IID || <IX509Attribute_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509AttributeClientId_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeEncode(this: *mut core::ffi::c_void, clientid: RequestClientInfoClientId, strmachinednsname: core::mem::MaybeUninit<windows_core::BSTR>, strusersamname: core::mem::MaybeUninit<windows_core::BSTR>, strprocessname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509AttributeClientId", "InitializeEncode", 10usize, |this| ((**(this as *const *const IX509AttributeClientId_Vtbl)).InitializeEncode)(this, clientid, strmachinednsname, strusersamname, strprocessname), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeDecode(this: *mut core::ffi::c_void, encoding: EncodingType, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509AttributeClientId", "InitializeDecode", 11usize, |this| ((**(this as *const *const IX509AttributeClientId_Vtbl)).InitializeDecode)(this, encoding, strencodeddata), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClientId(this: *mut core::ffi::c_void, pvalue: *mut RequestClientInfoClientId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509AttributeClientId", "ClientId", 12usize, |this| ((**(this as *const *const IX509AttributeClientId_Vtbl)).ClientId)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn MachineDnsName(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509AttributeClientId", "MachineDnsName", 13usize, |this| ((**(this as *const *const IX509AttributeClientId_Vtbl)).MachineDnsName)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserSamName(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509AttributeClientId", "UserSamName", 14usize, |this| ((**(this as *const *const IX509AttributeClientId_Vtbl)).UserSamName)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessName(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509AttributeClientId", "ProcessName", 15usize, |this| ((**(this as *const *const IX509AttributeClientId_Vtbl)).ProcessName)(this, pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <IX509Attribute_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitializeEncode,
+            InitializeDecode,
+            ClientId,
+            MachineDnsName,
+            UserSamName,
+            ProcessName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509AttributeClientId as windows_core::Interface>::IID || <IX509Attribute_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509AttributeCspProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeEncode(this: *mut core::ffi::c_void, keyspec: X509KeySpec, strprovidername: core::mem::MaybeUninit<windows_core::BSTR>, encoding: EncodingType, strsignature: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509AttributeCspProvider", "InitializeEncode", 10usize, |this| ((**(this as *const *const IX509AttributeCspProvider_Vtbl)).InitializeEncode)(this, keyspec, strprovidername, encoding, strsignature), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeDecode(this: *mut core::ffi::c_void, encoding: EncodingType, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509AttributeCspProvider", "InitializeDecode", 11usize, |this| ((**(this as *const *const IX509AttributeCspProvider_Vtbl)).InitializeDecode)(this, encoding, strencodeddata), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeySpec(this: *mut core::ffi::c_void, pvalue: *mut X509KeySpec) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509AttributeCspProvider", "KeySpec", 12usize, |this| ((**(this as *const *const IX509AttributeCspProvider_Vtbl)).KeySpec)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderName(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509AttributeCspProvider", "ProviderName", 13usize, |this| ((**(this as *const *const IX509AttributeCspProvider_Vtbl)).ProviderName)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Signature(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509AttributeCspProvider", "get_Signature", 14usize, |this| ((**(this as *const *const IX509AttributeCspProvider_Vtbl)).get_Signature)(this, encoding, pvalue), |result| Some(*result))
+        }
+        Self { base__: <IX509Attribute_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InitializeEncode, InitializeDecode, KeySpec, ProviderName, get_Signature }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509AttributeCspProvider as windows_core::Interface>::IID || <IX509Attribute_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509AttributeExtensions_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeEncode(this: *mut core::ffi::c_void, pextensions: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509AttributeExtensions", "InitializeEncode", 10usize, |this| ((**(this as *const *const IX509AttributeExtensions_Vtbl)).InitializeEncode)(this, pextensions), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeDecode(this: *mut core::ffi::c_void, encoding: EncodingType, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509AttributeExtensions", "InitializeDecode", 11usize, |this| ((**(this as *const *const IX509AttributeExtensions_Vtbl)).InitializeDecode)(this, encoding, strencodeddata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn X509Extensions(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509AttributeExtensions", "X509Extensions", 12usize, |this| ((**(this as *const *const IX509AttributeExtensions_Vtbl)).X509Extensions)(this, ppvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <IX509Attribute_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeEncode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeEncode: 0,
+            InitializeDecode,
+            #[cfg(feature = "Win32_System_Com")]
+            X509Extensions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            X509Extensions: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509AttributeExtensions as windows_core::Interface>::IID || <IX509Attribute_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509AttributeOSVersion_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeEncode(this: *mut core::ffi::c_void, strosversion: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509AttributeOSVersion", "InitializeEncode", 10usize, |this| ((**(this as *const *const IX509AttributeOSVersion_Vtbl)).InitializeEncode)(this, strosversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeDecode(this: *mut core::ffi::c_void, encoding: EncodingType, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509AttributeOSVersion", "InitializeDecode", 11usize, |this| ((**(this as *const *const IX509AttributeOSVersion_Vtbl)).InitializeDecode)(this, encoding, strencodeddata), |result| Some(*result))
+        }
+        unsafe extern "system" fn OSVersion(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509AttributeOSVersion", "OSVersion", 12usize, |this| ((**(this as *const *const IX509AttributeOSVersion_Vtbl)).OSVersion)(this, pvalue), |result| Some(*result))
+        }
+        Self { base__: <IX509Attribute_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InitializeEncode, InitializeDecode, OSVersion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509AttributeOSVersion as windows_core::Interface>::IID || <IX509Attribute_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509AttributeRenewalCertificate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeEncode(this: *mut core::ffi::c_void, encoding: EncodingType, strcert: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509AttributeRenewalCertificate", "InitializeEncode", 10usize, |this| ((**(this as *const *const IX509AttributeRenewalCertificate_Vtbl)).InitializeEncode)(this, encoding, strcert), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeDecode(this: *mut core::ffi::c_void, encoding: EncodingType, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509AttributeRenewalCertificate", "InitializeDecode", 11usize, |this| ((**(this as *const *const IX509AttributeRenewalCertificate_Vtbl)).InitializeDecode)(this, encoding, strencodeddata), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_RenewalCertificate(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509AttributeRenewalCertificate", "get_RenewalCertificate", 12usize, |this| ((**(this as *const *const IX509AttributeRenewalCertificate_Vtbl)).get_RenewalCertificate)(this, encoding, pvalue), |result| Some(*result))
+        }
+        Self { base__: <IX509Attribute_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InitializeEncode, InitializeDecode, get_RenewalCertificate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509AttributeRenewalCertificate as windows_core::Interface>::IID || <IX509Attribute_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509Attributes_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ItemByIndex(this: *mut core::ffi::c_void, index: i32, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Attributes", "get_ItemByIndex", 7usize, |this| ((**(this as *const *const IX509Attributes_Vtbl)).get_ItemByIndex)(this, index, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Attributes", "Count", 8usize, |this| ((**(this as *const *const IX509Attributes_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Attributes", "_NewEnum", 9usize, |this| ((**(this as *const *const IX509Attributes_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Attributes", "Add", 10usize, |this| ((**(this as *const *const IX509Attributes_Vtbl)).Add)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Attributes", "Remove", 11usize, |this| ((**(this as *const *const IX509Attributes_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Attributes", "Clear", 12usize, |this| ((**(this as *const *const IX509Attributes_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ItemByIndex,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ItemByIndex: 0,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            Clear,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509Attributes as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509CertificateRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, context: X509CertificateEnrollmentContext) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequest", "Initialize", 7usize, |this| ((**(this as *const *const IX509CertificateRequest_Vtbl)).Initialize)(this, context), |result| Some(*result))
+        }
+        unsafe extern "system" fn Encode(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequest", "Encode", 8usize, |this| ((**(this as *const *const IX509CertificateRequest_Vtbl)).Encode)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetForEncode(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequest", "ResetForEncode", 9usize, |this| ((**(this as *const *const IX509CertificateRequest_Vtbl)).ResetForEncode)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetInnerRequest(this: *mut core::ffi::c_void, level: InnerRequestLevel, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequest", "GetInnerRequest", 10usize, |this| ((**(this as *const *const IX509CertificateRequest_Vtbl)).GetInnerRequest)(this, level, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, pvalue: *mut X509RequestType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequest", "Type", 11usize, |this| ((**(this as *const *const IX509CertificateRequest_Vtbl)).Type)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnrollmentContext(this: *mut core::ffi::c_void, pvalue: *mut X509CertificateEnrollmentContext) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequest", "EnrollmentContext", 12usize, |this| ((**(this as *const *const IX509CertificateRequest_Vtbl)).EnrollmentContext)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Silent(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequest", "Silent", 13usize, |this| ((**(this as *const *const IX509CertificateRequest_Vtbl)).Silent)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSilent(this: *mut core::ffi::c_void, value: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequest", "SetSilent", 14usize, |this| ((**(this as *const *const IX509CertificateRequest_Vtbl)).SetSilent)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParentWindow(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequest", "ParentWindow", 15usize, |this| ((**(this as *const *const IX509CertificateRequest_Vtbl)).ParentWindow)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParentWindow(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequest", "SetParentWindow", 16usize, |this| ((**(this as *const *const IX509CertificateRequest_Vtbl)).SetParentWindow)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UIContextMessage(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequest", "UIContextMessage", 17usize, |this| ((**(this as *const *const IX509CertificateRequest_Vtbl)).UIContextMessage)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUIContextMessage(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequest", "SetUIContextMessage", 18usize, |this| ((**(this as *const *const IX509CertificateRequest_Vtbl)).SetUIContextMessage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuppressDefaults(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequest", "SuppressDefaults", 19usize, |this| ((**(this as *const *const IX509CertificateRequest_Vtbl)).SuppressDefaults)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSuppressDefaults(this: *mut core::ffi::c_void, value: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequest", "SetSuppressDefaults", 20usize, |this| ((**(this as *const *const IX509CertificateRequest_Vtbl)).SetSuppressDefaults)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_RenewalCertificate(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequest", "get_RenewalCertificate", 21usize, |this| ((**(this as *const *const IX509CertificateRequest_Vtbl)).get_RenewalCertificate)(this, encoding, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_RenewalCertificate(this: *mut core::ffi::c_void, encoding: EncodingType, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequest", "put_RenewalCertificate", 22usize, |this| ((**(this as *const *const IX509CertificateRequest_Vtbl)).put_RenewalCertificate)(this, encoding, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClientId(this: *mut core::ffi::c_void, pvalue: *mut RequestClientInfoClientId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequest", "ClientId", 23usize, |this| ((**(this as *const *const IX509CertificateRequest_Vtbl)).ClientId)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClientId(this: *mut core::ffi::c_void, value: RequestClientInfoClientId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequest", "SetClientId", 24usize, |this| ((**(this as *const *const IX509CertificateRequest_Vtbl)).SetClientId)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CspInformations(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequest", "CspInformations", 25usize, |this| ((**(this as *const *const IX509CertificateRequest_Vtbl)).CspInformations)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetCspInformations(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequest", "SetCspInformations", 26usize, |this| ((**(this as *const *const IX509CertificateRequest_Vtbl)).SetCspInformations)(this, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn HashAlgorithm(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequest", "HashAlgorithm", 27usize, |this| ((**(this as *const *const IX509CertificateRequest_Vtbl)).HashAlgorithm)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetHashAlgorithm(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequest", "SetHashAlgorithm", 28usize, |this| ((**(this as *const *const IX509CertificateRequest_Vtbl)).SetHashAlgorithm)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlternateSignatureAlgorithm(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequest", "AlternateSignatureAlgorithm", 29usize, |this| ((**(this as *const *const IX509CertificateRequest_Vtbl)).AlternateSignatureAlgorithm)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlternateSignatureAlgorithm(this: *mut core::ffi::c_void, value: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequest", "SetAlternateSignatureAlgorithm", 30usize, |this| ((**(this as *const *const IX509CertificateRequest_Vtbl)).SetAlternateSignatureAlgorithm)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_RawData(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequest", "get_RawData", 31usize, |this| ((**(this as *const *const IX509CertificateRequest_Vtbl)).get_RawData)(this, encoding, pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            Encode,
+            ResetForEncode,
+            #[cfg(feature = "Win32_System_Com")]
+            GetInnerRequest,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetInnerRequest: 0,
+            Type,
+            EnrollmentContext,
+            Silent,
+            SetSilent,
+            ParentWindow,
+            SetParentWindow,
+            UIContextMessage,
+            SetUIContextMessage,
+            SuppressDefaults,
+            SetSuppressDefaults,
+            get_RenewalCertificate,
+            put_RenewalCertificate,
+            ClientId,
+            SetClientId,
+            #[cfg(feature = "Win32_System_Com")]
+            CspInformations,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CspInformations: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetCspInformations,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetCspInformations: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            HashAlgorithm,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            HashAlgorithm: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetHashAlgorithm,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetHashAlgorithm: 0,
+            AlternateSignatureAlgorithm,
+            SetAlternateSignatureAlgorithm,
+            get_RawData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509CertificateRequest as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509CertificateRequestCertificate_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CheckPublicKeySignature(this: *mut core::ffi::c_void, ppublickey: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCertificate", "CheckPublicKeySignature", 60usize, |this| ((**(this as *const *const IX509CertificateRequestCertificate_Vtbl)).CheckPublicKeySignature)(this, ppublickey), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Issuer(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCertificate", "Issuer", 61usize, |this| ((**(this as *const *const IX509CertificateRequestCertificate_Vtbl)).Issuer)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetIssuer(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCertificate", "SetIssuer", 62usize, |this| ((**(this as *const *const IX509CertificateRequestCertificate_Vtbl)).SetIssuer)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotBefore(this: *mut core::ffi::c_void, pvalue: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCertificate", "NotBefore", 63usize, |this| ((**(this as *const *const IX509CertificateRequestCertificate_Vtbl)).NotBefore)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNotBefore(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCertificate", "SetNotBefore", 64usize, |this| ((**(this as *const *const IX509CertificateRequestCertificate_Vtbl)).SetNotBefore)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotAfter(this: *mut core::ffi::c_void, pvalue: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCertificate", "NotAfter", 65usize, |this| ((**(this as *const *const IX509CertificateRequestCertificate_Vtbl)).NotAfter)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNotAfter(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCertificate", "SetNotAfter", 66usize, |this| ((**(this as *const *const IX509CertificateRequestCertificate_Vtbl)).SetNotAfter)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_SerialNumber(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCertificate", "get_SerialNumber", 67usize, |this| ((**(this as *const *const IX509CertificateRequestCertificate_Vtbl)).get_SerialNumber)(this, encoding, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_SerialNumber(this: *mut core::ffi::c_void, encoding: EncodingType, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCertificate", "put_SerialNumber", 68usize, |this| ((**(this as *const *const IX509CertificateRequestCertificate_Vtbl)).put_SerialNumber)(this, encoding, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SignerCertificate(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCertificate", "SignerCertificate", 69usize, |this| ((**(this as *const *const IX509CertificateRequestCertificate_Vtbl)).SignerCertificate)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSignerCertificate(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCertificate", "SetSignerCertificate", 70usize, |this| ((**(this as *const *const IX509CertificateRequestCertificate_Vtbl)).SetSignerCertificate)(this, pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <IX509CertificateRequestPkcs10_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CheckPublicKeySignature,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CheckPublicKeySignature: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Issuer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Issuer: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetIssuer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetIssuer: 0,
+            NotBefore,
+            SetNotBefore,
+            NotAfter,
+            SetNotAfter,
+            get_SerialNumber,
+            put_SerialNumber,
+            #[cfg(feature = "Win32_System_Com")]
+            SignerCertificate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SignerCertificate: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSignerCertificate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSignerCertificate: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509CertificateRequestCertificate as windows_core::Interface>::IID || <IX509CertificateRequestPkcs10_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509CertificateRequestCertificate2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeFromTemplate(this: *mut core::ffi::c_void, context: X509CertificateEnrollmentContext, ppolicyserver: *mut core::ffi::c_void, ptemplate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCertificate2", "InitializeFromTemplate", 71usize, |this| ((**(this as *const *const IX509CertificateRequestCertificate2_Vtbl)).InitializeFromTemplate)(this, context, ppolicyserver, ptemplate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeFromPrivateKeyTemplate(this: *mut core::ffi::c_void, context: X509CertificateEnrollmentContext, pprivatekey: *mut core::ffi::c_void, ppolicyserver: *mut core::ffi::c_void, ptemplate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCertificate2", "InitializeFromPrivateKeyTemplate", 72usize, |this| ((**(this as *const *const IX509CertificateRequestCertificate2_Vtbl)).InitializeFromPrivateKeyTemplate)(this, context, pprivatekey, ppolicyserver, ptemplate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PolicyServer(this: *mut core::ffi::c_void, pppolicyserver: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCertificate2", "PolicyServer", 73usize, |this| ((**(this as *const *const IX509CertificateRequestCertificate2_Vtbl)).PolicyServer)(this, pppolicyserver), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Template(this: *mut core::ffi::c_void, pptemplate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCertificate2", "Template", 74usize, |this| ((**(this as *const *const IX509CertificateRequestCertificate2_Vtbl)).Template)(this, pptemplate), |result| Some(*result))
+        }
+        Self {
+            base__: <IX509CertificateRequestCertificate_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeFromTemplate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeFromTemplate: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeFromPrivateKeyTemplate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeFromPrivateKeyTemplate: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PolicyServer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PolicyServer: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Template,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Template: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509CertificateRequestCertificate2 as windows_core::Interface>::IID || <IX509CertificateRequestCertificate_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509CertificateRequestCmc_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeFromInnerRequestTemplateName(this: *mut core::ffi::c_void, pinnerrequest: *mut core::ffi::c_void, strtemplatename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc", "InitializeFromInnerRequestTemplateName", 40usize, |this| ((**(this as *const *const IX509CertificateRequestCmc_Vtbl)).InitializeFromInnerRequestTemplateName)(this, pinnerrequest, strtemplatename), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn TemplateObjectId(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc", "TemplateObjectId", 41usize, |this| ((**(this as *const *const IX509CertificateRequestCmc_Vtbl)).TemplateObjectId)(this, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn NullSigned(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc", "NullSigned", 42usize, |this| ((**(this as *const *const IX509CertificateRequestCmc_Vtbl)).NullSigned)(this, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CryptAttributes(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc", "CryptAttributes", 43usize, |this| ((**(this as *const *const IX509CertificateRequestCmc_Vtbl)).CryptAttributes)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn NameValuePairs(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc", "NameValuePairs", 44usize, |this| ((**(this as *const *const IX509CertificateRequestCmc_Vtbl)).NameValuePairs)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn X509Extensions(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc", "X509Extensions", 45usize, |this| ((**(this as *const *const IX509CertificateRequestCmc_Vtbl)).X509Extensions)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CriticalExtensions(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc", "CriticalExtensions", 46usize, |this| ((**(this as *const *const IX509CertificateRequestCmc_Vtbl)).CriticalExtensions)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SuppressOids(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc", "SuppressOids", 47usize, |this| ((**(this as *const *const IX509CertificateRequestCmc_Vtbl)).SuppressOids)(this, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransactionId(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc", "TransactionId", 48usize, |this| ((**(this as *const *const IX509CertificateRequestCmc_Vtbl)).TransactionId)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransactionId(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc", "SetTransactionId", 49usize, |this| ((**(this as *const *const IX509CertificateRequestCmc_Vtbl)).SetTransactionId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_SenderNonce(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc", "get_SenderNonce", 50usize, |this| ((**(this as *const *const IX509CertificateRequestCmc_Vtbl)).get_SenderNonce)(this, encoding, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_SenderNonce(this: *mut core::ffi::c_void, encoding: EncodingType, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc", "put_SenderNonce", 51usize, |this| ((**(this as *const *const IX509CertificateRequestCmc_Vtbl)).put_SenderNonce)(this, encoding, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SignatureInformation(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc", "SignatureInformation", 52usize, |this| ((**(this as *const *const IX509CertificateRequestCmc_Vtbl)).SignatureInformation)(this, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ArchivePrivateKey(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc", "ArchivePrivateKey", 53usize, |this| ((**(this as *const *const IX509CertificateRequestCmc_Vtbl)).ArchivePrivateKey)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetArchivePrivateKey(this: *mut core::ffi::c_void, value: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc", "SetArchivePrivateKey", 54usize, |this| ((**(this as *const *const IX509CertificateRequestCmc_Vtbl)).SetArchivePrivateKey)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_KeyArchivalCertificate(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc", "get_KeyArchivalCertificate", 55usize, |this| ((**(this as *const *const IX509CertificateRequestCmc_Vtbl)).get_KeyArchivalCertificate)(this, encoding, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_KeyArchivalCertificate(this: *mut core::ffi::c_void, encoding: EncodingType, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc", "put_KeyArchivalCertificate", 56usize, |this| ((**(this as *const *const IX509CertificateRequestCmc_Vtbl)).put_KeyArchivalCertificate)(this, encoding, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EncryptionAlgorithm(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc", "EncryptionAlgorithm", 57usize, |this| ((**(this as *const *const IX509CertificateRequestCmc_Vtbl)).EncryptionAlgorithm)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetEncryptionAlgorithm(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc", "SetEncryptionAlgorithm", 58usize, |this| ((**(this as *const *const IX509CertificateRequestCmc_Vtbl)).SetEncryptionAlgorithm)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn EncryptionStrength(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc", "EncryptionStrength", 59usize, |this| ((**(this as *const *const IX509CertificateRequestCmc_Vtbl)).EncryptionStrength)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEncryptionStrength(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc", "SetEncryptionStrength", 60usize, |this| ((**(this as *const *const IX509CertificateRequestCmc_Vtbl)).SetEncryptionStrength)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_EncryptedKeyHash(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc", "get_EncryptedKeyHash", 61usize, |this| ((**(this as *const *const IX509CertificateRequestCmc_Vtbl)).get_EncryptedKeyHash)(this, encoding, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SignerCertificates(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc", "SignerCertificates", 62usize, |this| ((**(this as *const *const IX509CertificateRequestCmc_Vtbl)).SignerCertificates)(this, ppvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <IX509CertificateRequestPkcs7_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeFromInnerRequestTemplateName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeFromInnerRequestTemplateName: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            TemplateObjectId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            TemplateObjectId: 0,
+            NullSigned,
+            #[cfg(feature = "Win32_System_Com")]
+            CryptAttributes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CryptAttributes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            NameValuePairs,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            NameValuePairs: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            X509Extensions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            X509Extensions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CriticalExtensions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CriticalExtensions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SuppressOids,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SuppressOids: 0,
+            TransactionId,
+            SetTransactionId,
+            get_SenderNonce,
+            put_SenderNonce,
+            #[cfg(feature = "Win32_System_Com")]
+            SignatureInformation,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SignatureInformation: 0,
+            ArchivePrivateKey,
+            SetArchivePrivateKey,
+            get_KeyArchivalCertificate,
+            put_KeyArchivalCertificate,
+            #[cfg(feature = "Win32_System_Com")]
+            EncryptionAlgorithm,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EncryptionAlgorithm: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetEncryptionAlgorithm,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetEncryptionAlgorithm: 0,
+            EncryptionStrength,
+            SetEncryptionStrength,
+            get_EncryptedKeyHash,
+            #[cfg(feature = "Win32_System_Com")]
+            SignerCertificates,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SignerCertificates: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509CertificateRequestCmc as windows_core::Interface>::IID || <IX509CertificateRequestPkcs7_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509CertificateRequestCmc2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeFromTemplate(this: *mut core::ffi::c_void, context: X509CertificateEnrollmentContext, ppolicyserver: *mut core::ffi::c_void, ptemplate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc2", "InitializeFromTemplate", 63usize, |this| ((**(this as *const *const IX509CertificateRequestCmc2_Vtbl)).InitializeFromTemplate)(this, context, ppolicyserver, ptemplate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeFromInnerRequestTemplate(this: *mut core::ffi::c_void, pinnerrequest: *mut core::ffi::c_void, ppolicyserver: *mut core::ffi::c_void, ptemplate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc2", "InitializeFromInnerRequestTemplate", 64usize, |this| ((**(this as *const *const IX509CertificateRequestCmc2_Vtbl)).InitializeFromInnerRequestTemplate)(this, pinnerrequest, ppolicyserver, ptemplate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PolicyServer(this: *mut core::ffi::c_void, pppolicyserver: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc2", "PolicyServer", 65usize, |this| ((**(this as *const *const IX509CertificateRequestCmc2_Vtbl)).PolicyServer)(this, pppolicyserver), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Template(this: *mut core::ffi::c_void, pptemplate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc2", "Template", 66usize, |this| ((**(this as *const *const IX509CertificateRequestCmc2_Vtbl)).Template)(this, pptemplate), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckSignature(this: *mut core::ffi::c_void, allowedsignaturetypes: Pkcs10AllowedSignatureTypes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc2", "CheckSignature", 67usize, |this| ((**(this as *const *const IX509CertificateRequestCmc2_Vtbl)).CheckSignature)(this, allowedsignaturetypes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CheckCertificateSignature(this: *mut core::ffi::c_void, psignercertificate: *mut core::ffi::c_void, validatecertificatechain: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestCmc2", "CheckCertificateSignature", 68usize, |this| ((**(this as *const *const IX509CertificateRequestCmc2_Vtbl)).CheckCertificateSignature)(this, psignercertificate, validatecertificatechain), |result| Some(*result))
+        }
+        Self {
+            base__: <IX509CertificateRequestCmc_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeFromTemplate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeFromTemplate: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeFromInnerRequestTemplate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeFromInnerRequestTemplate: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PolicyServer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PolicyServer: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Template,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Template: 0,
+            CheckSignature,
+            #[cfg(feature = "Win32_System_Com")]
+            CheckCertificateSignature,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CheckCertificateSignature: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509CertificateRequestCmc2 as windows_core::Interface>::IID || <IX509CertificateRequestCmc_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509CertificateRequestPkcs10_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeFromTemplateName(this: *mut core::ffi::c_void, context: X509CertificateEnrollmentContext, strtemplatename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10", "InitializeFromTemplateName", 32usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10_Vtbl)).InitializeFromTemplateName)(this, context, strtemplatename), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeFromPrivateKey(this: *mut core::ffi::c_void, context: X509CertificateEnrollmentContext, pprivatekey: *mut core::ffi::c_void, strtemplatename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10", "InitializeFromPrivateKey", 33usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10_Vtbl)).InitializeFromPrivateKey)(this, context, pprivatekey, strtemplatename), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeFromPublicKey(this: *mut core::ffi::c_void, context: X509CertificateEnrollmentContext, ppublickey: *mut core::ffi::c_void, strtemplatename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10", "InitializeFromPublicKey", 34usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10_Vtbl)).InitializeFromPublicKey)(this, context, ppublickey, strtemplatename), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeFromCertificate(this: *mut core::ffi::c_void, context: X509CertificateEnrollmentContext, strcertificate: core::mem::MaybeUninit<windows_core::BSTR>, encoding: EncodingType, inheritoptions: X509RequestInheritOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10", "InitializeFromCertificate", 35usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10_Vtbl)).InitializeFromCertificate)(this, context, strcertificate, encoding, inheritoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeDecode(this: *mut core::ffi::c_void, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>, encoding: EncodingType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10", "InitializeDecode", 36usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10_Vtbl)).InitializeDecode)(this, strencodeddata, encoding), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckSignature(this: *mut core::ffi::c_void, allowedsignaturetypes: Pkcs10AllowedSignatureTypes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10", "CheckSignature", 37usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10_Vtbl)).CheckSignature)(this, allowedsignaturetypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSmartCard(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10", "IsSmartCard", 38usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10_Vtbl)).IsSmartCard)(this, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn TemplateObjectId(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10", "TemplateObjectId", 39usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10_Vtbl)).TemplateObjectId)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PublicKey(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10", "PublicKey", 40usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10_Vtbl)).PublicKey)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PrivateKey(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10", "PrivateKey", 41usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10_Vtbl)).PrivateKey)(this, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn NullSigned(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10", "NullSigned", 42usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10_Vtbl)).NullSigned)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReuseKey(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10", "ReuseKey", 43usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10_Vtbl)).ReuseKey)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_OldCertificate(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10", "get_OldCertificate", 44usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10_Vtbl)).get_OldCertificate)(this, encoding, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Subject(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10", "Subject", 45usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10_Vtbl)).Subject)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSubject(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10", "SetSubject", 46usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10_Vtbl)).SetSubject)(this, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CspStatuses(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10", "CspStatuses", 47usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10_Vtbl)).CspStatuses)(this, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SmimeCapabilities(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10", "SmimeCapabilities", 48usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10_Vtbl)).SmimeCapabilities)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSmimeCapabilities(this: *mut core::ffi::c_void, value: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10", "SetSmimeCapabilities", 49usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10_Vtbl)).SetSmimeCapabilities)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SignatureInformation(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10", "SignatureInformation", 50usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10_Vtbl)).SignatureInformation)(this, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyContainerNamePrefix(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10", "KeyContainerNamePrefix", 51usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10_Vtbl)).KeyContainerNamePrefix)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeyContainerNamePrefix(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10", "SetKeyContainerNamePrefix", 52usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10_Vtbl)).SetKeyContainerNamePrefix)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CryptAttributes(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10", "CryptAttributes", 53usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10_Vtbl)).CryptAttributes)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn X509Extensions(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10", "X509Extensions", 54usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10_Vtbl)).X509Extensions)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CriticalExtensions(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10", "CriticalExtensions", 55usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10_Vtbl)).CriticalExtensions)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SuppressOids(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10", "SuppressOids", 56usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10_Vtbl)).SuppressOids)(this, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_RawDataToBeSigned(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10", "get_RawDataToBeSigned", 57usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10_Vtbl)).get_RawDataToBeSigned)(this, encoding, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Signature(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10", "get_Signature", 58usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10_Vtbl)).get_Signature)(this, encoding, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetCspStatuses(this: *mut core::ffi::c_void, keyspec: X509KeySpec, ppcspstatuses: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10", "GetCspStatuses", 59usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10_Vtbl)).GetCspStatuses)(this, keyspec, ppcspstatuses), |result| Some(*result))
+        }
+        Self {
+            base__: <IX509CertificateRequest_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitializeFromTemplateName,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeFromPrivateKey,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeFromPrivateKey: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeFromPublicKey,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeFromPublicKey: 0,
+            InitializeFromCertificate,
+            InitializeDecode,
+            CheckSignature,
+            IsSmartCard,
+            #[cfg(feature = "Win32_System_Com")]
+            TemplateObjectId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            TemplateObjectId: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PublicKey,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PublicKey: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PrivateKey,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PrivateKey: 0,
+            NullSigned,
+            ReuseKey,
+            get_OldCertificate,
+            #[cfg(feature = "Win32_System_Com")]
+            Subject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Subject: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSubject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSubject: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CspStatuses,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CspStatuses: 0,
+            SmimeCapabilities,
+            SetSmimeCapabilities,
+            #[cfg(feature = "Win32_System_Com")]
+            SignatureInformation,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SignatureInformation: 0,
+            KeyContainerNamePrefix,
+            SetKeyContainerNamePrefix,
+            #[cfg(feature = "Win32_System_Com")]
+            CryptAttributes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CryptAttributes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            X509Extensions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            X509Extensions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CriticalExtensions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CriticalExtensions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SuppressOids,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SuppressOids: 0,
+            get_RawDataToBeSigned,
+            get_Signature,
+            #[cfg(feature = "Win32_System_Com")]
+            GetCspStatuses,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetCspStatuses: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509CertificateRequestPkcs10 as windows_core::Interface>::IID || <IX509CertificateRequest_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509CertificateRequestPkcs10V2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeFromTemplate(this: *mut core::ffi::c_void, context: X509CertificateEnrollmentContext, ppolicyserver: *mut core::ffi::c_void, ptemplate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10V2", "InitializeFromTemplate", 60usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10V2_Vtbl)).InitializeFromTemplate)(this, context, ppolicyserver, ptemplate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeFromPrivateKeyTemplate(this: *mut core::ffi::c_void, context: X509CertificateEnrollmentContext, pprivatekey: *mut core::ffi::c_void, ppolicyserver: *mut core::ffi::c_void, ptemplate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10V2", "InitializeFromPrivateKeyTemplate", 61usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10V2_Vtbl)).InitializeFromPrivateKeyTemplate)(this, context, pprivatekey, ppolicyserver, ptemplate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeFromPublicKeyTemplate(this: *mut core::ffi::c_void, context: X509CertificateEnrollmentContext, ppublickey: *mut core::ffi::c_void, ppolicyserver: *mut core::ffi::c_void, ptemplate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10V2", "InitializeFromPublicKeyTemplate", 62usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10V2_Vtbl)).InitializeFromPublicKeyTemplate)(this, context, ppublickey, ppolicyserver, ptemplate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PolicyServer(this: *mut core::ffi::c_void, pppolicyserver: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10V2", "PolicyServer", 63usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10V2_Vtbl)).PolicyServer)(this, pppolicyserver), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Template(this: *mut core::ffi::c_void, pptemplate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10V2", "Template", 64usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10V2_Vtbl)).Template)(this, pptemplate), |result| Some(*result))
+        }
+        Self {
+            base__: <IX509CertificateRequestPkcs10_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeFromTemplate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeFromTemplate: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeFromPrivateKeyTemplate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeFromPrivateKeyTemplate: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeFromPublicKeyTemplate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeFromPublicKeyTemplate: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PolicyServer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PolicyServer: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Template,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Template: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509CertificateRequestPkcs10V2 as windows_core::Interface>::IID || <IX509CertificateRequestPkcs10_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509CertificateRequestPkcs10V3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AttestPrivateKey(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10V3", "AttestPrivateKey", 65usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10V3_Vtbl)).AttestPrivateKey)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAttestPrivateKey(this: *mut core::ffi::c_void, value: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10V3", "SetAttestPrivateKey", 66usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10V3_Vtbl)).SetAttestPrivateKey)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_AttestationEncryptionCertificate(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10V3", "get_AttestationEncryptionCertificate", 67usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10V3_Vtbl)).get_AttestationEncryptionCertificate)(this, encoding, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_AttestationEncryptionCertificate(this: *mut core::ffi::c_void, encoding: EncodingType, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10V3", "put_AttestationEncryptionCertificate", 68usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10V3_Vtbl)).put_AttestationEncryptionCertificate)(this, encoding, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EncryptionAlgorithm(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10V3", "EncryptionAlgorithm", 69usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10V3_Vtbl)).EncryptionAlgorithm)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetEncryptionAlgorithm(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10V3", "SetEncryptionAlgorithm", 70usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10V3_Vtbl)).SetEncryptionAlgorithm)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn EncryptionStrength(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10V3", "EncryptionStrength", 71usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10V3_Vtbl)).EncryptionStrength)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEncryptionStrength(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10V3", "SetEncryptionStrength", 72usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10V3_Vtbl)).SetEncryptionStrength)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChallengePassword(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10V3", "ChallengePassword", 73usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10V3_Vtbl)).ChallengePassword)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetChallengePassword(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10V3", "SetChallengePassword", 74usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10V3_Vtbl)).SetChallengePassword)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn NameValuePairs(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10V3", "NameValuePairs", 75usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10V3_Vtbl)).NameValuePairs)(this, ppvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <IX509CertificateRequestPkcs10V2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AttestPrivateKey,
+            SetAttestPrivateKey,
+            get_AttestationEncryptionCertificate,
+            put_AttestationEncryptionCertificate,
+            #[cfg(feature = "Win32_System_Com")]
+            EncryptionAlgorithm,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EncryptionAlgorithm: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetEncryptionAlgorithm,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetEncryptionAlgorithm: 0,
+            EncryptionStrength,
+            SetEncryptionStrength,
+            ChallengePassword,
+            SetChallengePassword,
+            #[cfg(feature = "Win32_System_Com")]
+            NameValuePairs,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            NameValuePairs: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509CertificateRequestPkcs10V3 as windows_core::Interface>::IID || <IX509CertificateRequestPkcs10V2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509CertificateRequestPkcs10V4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ClaimType(this: *mut core::ffi::c_void, pvalue: *mut KeyAttestationClaimType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10V4", "ClaimType", 76usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10V4_Vtbl)).ClaimType)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClaimType(this: *mut core::ffi::c_void, value: KeyAttestationClaimType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10V4", "SetClaimType", 77usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10V4_Vtbl)).SetClaimType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttestPrivateKeyPreferred(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10V4", "AttestPrivateKeyPreferred", 78usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10V4_Vtbl)).AttestPrivateKeyPreferred)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAttestPrivateKeyPreferred(this: *mut core::ffi::c_void, value: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs10V4", "SetAttestPrivateKeyPreferred", 79usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs10V4_Vtbl)).SetAttestPrivateKeyPreferred)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <IX509CertificateRequestPkcs10V3_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ClaimType,
+            SetClaimType,
+            AttestPrivateKeyPreferred,
+            SetAttestPrivateKeyPreferred,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509CertificateRequestPkcs10V4 as windows_core::Interface>::IID || <IX509CertificateRequestPkcs10V3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509CertificateRequestPkcs7_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeFromTemplateName(this: *mut core::ffi::c_void, context: X509CertificateEnrollmentContext, strtemplatename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs7", "InitializeFromTemplateName", 32usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs7_Vtbl)).InitializeFromTemplateName)(this, context, strtemplatename), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeFromCertificate(this: *mut core::ffi::c_void, context: X509CertificateEnrollmentContext, renewalrequest: super::super::super::Foundation::VARIANT_BOOL, strcertificate: core::mem::MaybeUninit<windows_core::BSTR>, encoding: EncodingType, inheritoptions: X509RequestInheritOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs7", "InitializeFromCertificate", 33usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs7_Vtbl)).InitializeFromCertificate)(this, context, renewalrequest, strcertificate, encoding, inheritoptions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeFromInnerRequest(this: *mut core::ffi::c_void, pinnerrequest: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs7", "InitializeFromInnerRequest", 34usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs7_Vtbl)).InitializeFromInnerRequest)(this, pinnerrequest), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeDecode(this: *mut core::ffi::c_void, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>, encoding: EncodingType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs7", "InitializeDecode", 35usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs7_Vtbl)).InitializeDecode)(this, strencodeddata, encoding), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequesterName(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs7", "RequesterName", 36usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs7_Vtbl)).RequesterName)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequesterName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs7", "SetRequesterName", 37usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs7_Vtbl)).SetRequesterName)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SignerCertificate(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs7", "SignerCertificate", 38usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs7_Vtbl)).SignerCertificate)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSignerCertificate(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs7", "SetSignerCertificate", 39usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs7_Vtbl)).SetSignerCertificate)(this, pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <IX509CertificateRequest_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitializeFromTemplateName,
+            InitializeFromCertificate,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeFromInnerRequest,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeFromInnerRequest: 0,
+            InitializeDecode,
+            RequesterName,
+            SetRequesterName,
+            #[cfg(feature = "Win32_System_Com")]
+            SignerCertificate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SignerCertificate: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSignerCertificate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSignerCertificate: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509CertificateRequestPkcs7 as windows_core::Interface>::IID || <IX509CertificateRequest_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509CertificateRequestPkcs7V2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeFromTemplate(this: *mut core::ffi::c_void, context: X509CertificateEnrollmentContext, ppolicyserver: *mut core::ffi::c_void, ptemplate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs7V2", "InitializeFromTemplate", 40usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs7V2_Vtbl)).InitializeFromTemplate)(this, context, ppolicyserver, ptemplate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PolicyServer(this: *mut core::ffi::c_void, pppolicyserver: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs7V2", "PolicyServer", 41usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs7V2_Vtbl)).PolicyServer)(this, pppolicyserver), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Template(this: *mut core::ffi::c_void, pptemplate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs7V2", "Template", 42usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs7V2_Vtbl)).Template)(this, pptemplate), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckCertificateSignature(this: *mut core::ffi::c_void, validatecertificatechain: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRequestPkcs7V2", "CheckCertificateSignature", 43usize, |this| ((**(this as *const *const IX509CertificateRequestPkcs7V2_Vtbl)).CheckCertificateSignature)(this, validatecertificatechain), |result| Some(*result))
+        }
+        Self {
+            base__: <IX509CertificateRequestPkcs7_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeFromTemplate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeFromTemplate: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PolicyServer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PolicyServer: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Template,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Template: 0,
+            CheckCertificateSignature,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509CertificateRequestPkcs7V2 as windows_core::Interface>::IID || <IX509CertificateRequestPkcs7_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509CertificateRevocationList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "Initialize", 7usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).Initialize)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeDecode(this: *mut core::ffi::c_void, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>, encoding: EncodingType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "InitializeDecode", 8usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).InitializeDecode)(this, strencodeddata, encoding), |result| Some(*result))
+        }
+        unsafe extern "system" fn Encode(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "Encode", 9usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).Encode)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetForEncode(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "ResetForEncode", 10usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).ResetForEncode)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CheckPublicKeySignature(this: *mut core::ffi::c_void, ppublickey: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "CheckPublicKeySignature", 11usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).CheckPublicKeySignature)(this, ppublickey), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckSignature(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "CheckSignature", 12usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).CheckSignature)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Issuer(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "Issuer", 13usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).Issuer)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetIssuer(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "SetIssuer", 14usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).SetIssuer)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ThisUpdate(this: *mut core::ffi::c_void, pvalue: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "ThisUpdate", 15usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).ThisUpdate)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetThisUpdate(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "SetThisUpdate", 16usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).SetThisUpdate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn NextUpdate(this: *mut core::ffi::c_void, pvalue: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "NextUpdate", 17usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).NextUpdate)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNextUpdate(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "SetNextUpdate", 18usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).SetNextUpdate)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn X509CRLEntries(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "X509CRLEntries", 19usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).X509CRLEntries)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn X509Extensions(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "X509Extensions", 20usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).X509Extensions)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CriticalExtensions(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "CriticalExtensions", 21usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).CriticalExtensions)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SignerCertificate(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "SignerCertificate", 22usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).SignerCertificate)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSignerCertificate(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "SetSignerCertificate", 23usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).SetSignerCertificate)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_CRLNumber(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "get_CRLNumber", 24usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).get_CRLNumber)(this, encoding, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_CRLNumber(this: *mut core::ffi::c_void, encoding: EncodingType, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "put_CRLNumber", 25usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).put_CRLNumber)(this, encoding, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CAVersion(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "CAVersion", 26usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).CAVersion)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCAVersion(this: *mut core::ffi::c_void, pvalue: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "SetCAVersion", 27usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).SetCAVersion)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn BaseCRL(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "BaseCRL", 28usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).BaseCRL)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn NullSigned(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "NullSigned", 29usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).NullSigned)(this, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn HashAlgorithm(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "HashAlgorithm", 30usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).HashAlgorithm)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetHashAlgorithm(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "SetHashAlgorithm", 31usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).SetHashAlgorithm)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlternateSignatureAlgorithm(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "AlternateSignatureAlgorithm", 32usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).AlternateSignatureAlgorithm)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlternateSignatureAlgorithm(this: *mut core::ffi::c_void, value: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "SetAlternateSignatureAlgorithm", 33usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).SetAlternateSignatureAlgorithm)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SignatureInformation(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "SignatureInformation", 34usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).SignatureInformation)(this, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_RawData(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "get_RawData", 35usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).get_RawData)(this, encoding, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_RawDataToBeSigned(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "get_RawDataToBeSigned", 36usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).get_RawDataToBeSigned)(this, encoding, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Signature(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationList", "get_Signature", 37usize, |this| ((**(this as *const *const IX509CertificateRevocationList_Vtbl)).get_Signature)(this, encoding, pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            InitializeDecode,
+            Encode,
+            ResetForEncode,
+            #[cfg(feature = "Win32_System_Com")]
+            CheckPublicKeySignature,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CheckPublicKeySignature: 0,
+            CheckSignature,
+            #[cfg(feature = "Win32_System_Com")]
+            Issuer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Issuer: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetIssuer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetIssuer: 0,
+            ThisUpdate,
+            SetThisUpdate,
+            NextUpdate,
+            SetNextUpdate,
+            #[cfg(feature = "Win32_System_Com")]
+            X509CRLEntries,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            X509CRLEntries: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            X509Extensions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            X509Extensions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CriticalExtensions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CriticalExtensions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SignerCertificate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SignerCertificate: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSignerCertificate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSignerCertificate: 0,
+            get_CRLNumber,
+            put_CRLNumber,
+            CAVersion,
+            SetCAVersion,
+            BaseCRL,
+            NullSigned,
+            #[cfg(feature = "Win32_System_Com")]
+            HashAlgorithm,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            HashAlgorithm: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetHashAlgorithm,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetHashAlgorithm: 0,
+            AlternateSignatureAlgorithm,
+            SetAlternateSignatureAlgorithm,
+            #[cfg(feature = "Win32_System_Com")]
+            SignatureInformation,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SignatureInformation: 0,
+            get_RawData,
+            get_RawDataToBeSigned,
+            get_Signature,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509CertificateRevocationList as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509CertificateRevocationListEntries_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ItemByIndex(this: *mut core::ffi::c_void, index: i32, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationListEntries", "get_ItemByIndex", 7usize, |this| ((**(this as *const *const IX509CertificateRevocationListEntries_Vtbl)).get_ItemByIndex)(this, index, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationListEntries", "Count", 8usize, |this| ((**(this as *const *const IX509CertificateRevocationListEntries_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationListEntries", "_NewEnum", 9usize, |this| ((**(this as *const *const IX509CertificateRevocationListEntries_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationListEntries", "Add", 10usize, |this| ((**(this as *const *const IX509CertificateRevocationListEntries_Vtbl)).Add)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationListEntries", "Remove", 11usize, |this| ((**(this as *const *const IX509CertificateRevocationListEntries_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationListEntries", "Clear", 12usize, |this| ((**(this as *const *const IX509CertificateRevocationListEntries_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_IndexBySerialNumber(this: *mut core::ffi::c_void, encoding: EncodingType, serialnumber: core::mem::MaybeUninit<windows_core::BSTR>, pindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationListEntries", "get_IndexBySerialNumber", 13usize, |this| ((**(this as *const *const IX509CertificateRevocationListEntries_Vtbl)).get_IndexBySerialNumber)(this, encoding, serialnumber, pindex), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddRange(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationListEntries", "AddRange", 14usize, |this| ((**(this as *const *const IX509CertificateRevocationListEntries_Vtbl)).AddRange)(this, pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ItemByIndex,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ItemByIndex: 0,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            Clear,
+            get_IndexBySerialNumber,
+            #[cfg(feature = "Win32_System_Com")]
+            AddRange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddRange: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509CertificateRevocationListEntries as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509CertificateRevocationListEntry_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, encoding: EncodingType, serialnumber: core::mem::MaybeUninit<windows_core::BSTR>, revocationdate: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationListEntry", "Initialize", 7usize, |this| ((**(this as *const *const IX509CertificateRevocationListEntry_Vtbl)).Initialize)(this, encoding, serialnumber, revocationdate), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_SerialNumber(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationListEntry", "get_SerialNumber", 8usize, |this| ((**(this as *const *const IX509CertificateRevocationListEntry_Vtbl)).get_SerialNumber)(this, encoding, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn RevocationDate(this: *mut core::ffi::c_void, pvalue: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationListEntry", "RevocationDate", 9usize, |this| ((**(this as *const *const IX509CertificateRevocationListEntry_Vtbl)).RevocationDate)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn RevocationReason(this: *mut core::ffi::c_void, pvalue: *mut CRLRevocationReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationListEntry", "RevocationReason", 10usize, |this| ((**(this as *const *const IX509CertificateRevocationListEntry_Vtbl)).RevocationReason)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRevocationReason(this: *mut core::ffi::c_void, value: CRLRevocationReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationListEntry", "SetRevocationReason", 11usize, |this| ((**(this as *const *const IX509CertificateRevocationListEntry_Vtbl)).SetRevocationReason)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn X509Extensions(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationListEntry", "X509Extensions", 12usize, |this| ((**(this as *const *const IX509CertificateRevocationListEntry_Vtbl)).X509Extensions)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CriticalExtensions(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateRevocationListEntry", "CriticalExtensions", 13usize, |this| ((**(this as *const *const IX509CertificateRevocationListEntry_Vtbl)).CriticalExtensions)(this, ppvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            get_SerialNumber,
+            RevocationDate,
+            RevocationReason,
+            SetRevocationReason,
+            #[cfg(feature = "Win32_System_Com")]
+            X509Extensions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            X509Extensions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CriticalExtensions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CriticalExtensions: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509CertificateRevocationListEntry as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509CertificateTemplate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_Property(this: *mut core::ffi::c_void, property: EnrollmentTemplateProperty, pvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateTemplate", "get_Property", 7usize, |this| ((**(this as *const *const IX509CertificateTemplate_Vtbl)).get_Property)(this, property, pvalue), |result| Some(*result))
+        }
+        Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, get_Property }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509CertificateTemplate as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509CertificateTemplateWritable_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateTemplateWritable", "Initialize", 7usize, |this| ((**(this as *const *const IX509CertificateTemplateWritable_Vtbl)).Initialize)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void, commitflags: CommitTemplateFlags, strservercontext: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateTemplateWritable", "Commit", 8usize, |this| ((**(this as *const *const IX509CertificateTemplateWritable_Vtbl)).Commit)(this, commitflags, strservercontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Property(this: *mut core::ffi::c_void, property: EnrollmentTemplateProperty, pvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateTemplateWritable", "get_Property", 9usize, |this| ((**(this as *const *const IX509CertificateTemplateWritable_Vtbl)).get_Property)(this, property, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_Property(this: *mut core::ffi::c_void, property: EnrollmentTemplateProperty, value: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateTemplateWritable", "put_Property", 10usize, |this| ((**(this as *const *const IX509CertificateTemplateWritable_Vtbl)).put_Property)(this, property, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Template(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateTemplateWritable", "Template", 11usize, |this| ((**(this as *const *const IX509CertificateTemplateWritable_Vtbl)).Template)(this, ppvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Initialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Initialize: 0,
+            Commit,
+            get_Property,
+            put_Property,
+            #[cfg(feature = "Win32_System_Com")]
+            Template,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Template: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509CertificateTemplateWritable as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509CertificateTemplates_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ItemByIndex(this: *mut core::ffi::c_void, index: i32, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateTemplates", "get_ItemByIndex", 7usize, |this| ((**(this as *const *const IX509CertificateTemplates_Vtbl)).get_ItemByIndex)(this, index, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateTemplates", "Count", 8usize, |this| ((**(this as *const *const IX509CertificateTemplates_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateTemplates", "_NewEnum", 9usize, |this| ((**(this as *const *const IX509CertificateTemplates_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateTemplates", "Add", 10usize, |this| ((**(this as *const *const IX509CertificateTemplates_Vtbl)).Add)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateTemplates", "Remove", 11usize, |this| ((**(this as *const *const IX509CertificateTemplates_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateTemplates", "Clear", 12usize, |this| ((**(this as *const *const IX509CertificateTemplates_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ItemByName(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateTemplates", "get_ItemByName", 13usize, |this| ((**(this as *const *const IX509CertificateTemplates_Vtbl)).get_ItemByName)(this, bstrname, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ItemByOid(this: *mut core::ffi::c_void, poid: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509CertificateTemplates", "get_ItemByOid", 14usize, |this| ((**(this as *const *const IX509CertificateTemplates_Vtbl)).get_ItemByOid)(this, poid, ppvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ItemByIndex,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ItemByIndex: 0,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            Clear,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ItemByName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ItemByName: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ItemByOid,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ItemByOid: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509CertificateTemplates as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509EndorsementKey_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProviderName(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EndorsementKey", "ProviderName", 7usize, |this| ((**(this as *const *const IX509EndorsementKey_Vtbl)).ProviderName)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProviderName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EndorsementKey", "SetProviderName", 8usize, |this| ((**(this as *const *const IX509EndorsementKey_Vtbl)).SetProviderName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EndorsementKey", "Length", 9usize, |this| ((**(this as *const *const IX509EndorsementKey_Vtbl)).Length)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Opened(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EndorsementKey", "Opened", 10usize, |this| ((**(this as *const *const IX509EndorsementKey_Vtbl)).Opened)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddCertificate(this: *mut core::ffi::c_void, encoding: EncodingType, strcertificate: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EndorsementKey", "AddCertificate", 11usize, |this| ((**(this as *const *const IX509EndorsementKey_Vtbl)).AddCertificate)(this, encoding, strcertificate), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCertificate(this: *mut core::ffi::c_void, encoding: EncodingType, strcertificate: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EndorsementKey", "RemoveCertificate", 12usize, |this| ((**(this as *const *const IX509EndorsementKey_Vtbl)).RemoveCertificate)(this, encoding, strcertificate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCertificateByIndex(this: *mut core::ffi::c_void, manufactureronly: super::super::super::Foundation::VARIANT_BOOL, dwindex: i32, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EndorsementKey", "GetCertificateByIndex", 13usize, |this| ((**(this as *const *const IX509EndorsementKey_Vtbl)).GetCertificateByIndex)(this, manufactureronly, dwindex, encoding, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCertificateCount(this: *mut core::ffi::c_void, manufactureronly: super::super::super::Foundation::VARIANT_BOOL, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EndorsementKey", "GetCertificateCount", 14usize, |this| ((**(this as *const *const IX509EndorsementKey_Vtbl)).GetCertificateCount)(this, manufactureronly, pcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ExportPublicKey(this: *mut core::ffi::c_void, pppublickey: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EndorsementKey", "ExportPublicKey", 15usize, |this| ((**(this as *const *const IX509EndorsementKey_Vtbl)).ExportPublicKey)(this, pppublickey), |result| Some(*result))
+        }
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EndorsementKey", "Open", 16usize, |this| ((**(this as *const *const IX509EndorsementKey_Vtbl)).Open)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EndorsementKey", "Close", 17usize, |this| ((**(this as *const *const IX509EndorsementKey_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProviderName,
+            SetProviderName,
+            Length,
+            Opened,
+            AddCertificate,
+            RemoveCertificate,
+            GetCertificateByIndex,
+            GetCertificateCount,
+            #[cfg(feature = "Win32_System_Com")]
+            ExportPublicKey,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ExportPublicKey: 0,
+            Open,
+            Close,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509EndorsementKey as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509Enrollment_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, context: X509CertificateEnrollmentContext) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Enrollment", "Initialize", 7usize, |this| ((**(this as *const *const IX509Enrollment_Vtbl)).Initialize)(this, context), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeFromTemplateName(this: *mut core::ffi::c_void, context: X509CertificateEnrollmentContext, strtemplatename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Enrollment", "InitializeFromTemplateName", 8usize, |this| ((**(this as *const *const IX509Enrollment_Vtbl)).InitializeFromTemplateName)(this, context, strtemplatename), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeFromRequest(this: *mut core::ffi::c_void, prequest: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Enrollment", "InitializeFromRequest", 9usize, |this| ((**(this as *const *const IX509Enrollment_Vtbl)).InitializeFromRequest)(this, prequest), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRequest(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Enrollment", "CreateRequest", 10usize, |this| ((**(this as *const *const IX509Enrollment_Vtbl)).CreateRequest)(this, encoding, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enroll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Enrollment", "Enroll", 11usize, |this| ((**(this as *const *const IX509Enrollment_Vtbl)).Enroll)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstallResponse(this: *mut core::ffi::c_void, restrictions: InstallResponseRestrictionFlags, strresponse: core::mem::MaybeUninit<windows_core::BSTR>, encoding: EncodingType, strpassword: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Enrollment", "InstallResponse", 12usize, |this| ((**(this as *const *const IX509Enrollment_Vtbl)).InstallResponse)(this, restrictions, strresponse, encoding, strpassword), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePFX(this: *mut core::ffi::c_void, strpassword: core::mem::MaybeUninit<windows_core::BSTR>, exportoptions: PFXExportOptions, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Enrollment", "CreatePFX", 13usize, |this| ((**(this as *const *const IX509Enrollment_Vtbl)).CreatePFX)(this, strpassword, exportoptions, encoding, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, pvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Enrollment", "Request", 14usize, |this| ((**(this as *const *const IX509Enrollment_Vtbl)).Request)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Silent(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Enrollment", "Silent", 15usize, |this| ((**(this as *const *const IX509Enrollment_Vtbl)).Silent)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSilent(this: *mut core::ffi::c_void, value: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Enrollment", "SetSilent", 16usize, |this| ((**(this as *const *const IX509Enrollment_Vtbl)).SetSilent)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParentWindow(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Enrollment", "ParentWindow", 17usize, |this| ((**(this as *const *const IX509Enrollment_Vtbl)).ParentWindow)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParentWindow(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Enrollment", "SetParentWindow", 18usize, |this| ((**(this as *const *const IX509Enrollment_Vtbl)).SetParentWindow)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn NameValuePairs(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Enrollment", "NameValuePairs", 19usize, |this| ((**(this as *const *const IX509Enrollment_Vtbl)).NameValuePairs)(this, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnrollmentContext(this: *mut core::ffi::c_void, pvalue: *mut X509CertificateEnrollmentContext) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Enrollment", "EnrollmentContext", 20usize, |this| ((**(this as *const *const IX509Enrollment_Vtbl)).EnrollmentContext)(this, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Enrollment", "Status", 21usize, |this| ((**(this as *const *const IX509Enrollment_Vtbl)).Status)(this, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Certificate(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Enrollment", "get_Certificate", 22usize, |this| ((**(this as *const *const IX509Enrollment_Vtbl)).get_Certificate)(this, encoding, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Response(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Enrollment", "get_Response", 23usize, |this| ((**(this as *const *const IX509Enrollment_Vtbl)).get_Response)(this, encoding, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn CertificateFriendlyName(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Enrollment", "CertificateFriendlyName", 24usize, |this| ((**(this as *const *const IX509Enrollment_Vtbl)).CertificateFriendlyName)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCertificateFriendlyName(this: *mut core::ffi::c_void, strvalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Enrollment", "SetCertificateFriendlyName", 25usize, |this| ((**(this as *const *const IX509Enrollment_Vtbl)).SetCertificateFriendlyName)(this, strvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn CertificateDescription(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Enrollment", "CertificateDescription", 26usize, |this| ((**(this as *const *const IX509Enrollment_Vtbl)).CertificateDescription)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCertificateDescription(this: *mut core::ffi::c_void, strvalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Enrollment", "SetCertificateDescription", 27usize, |this| ((**(this as *const *const IX509Enrollment_Vtbl)).SetCertificateDescription)(this, strvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestId(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Enrollment", "RequestId", 28usize, |this| ((**(this as *const *const IX509Enrollment_Vtbl)).RequestId)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn CAConfigString(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Enrollment", "CAConfigString", 29usize, |this| ((**(this as *const *const IX509Enrollment_Vtbl)).CAConfigString)(this, pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            InitializeFromTemplateName,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeFromRequest,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeFromRequest: 0,
+            CreateRequest,
+            Enroll,
+            InstallResponse,
+            CreatePFX,
+            #[cfg(feature = "Win32_System_Com")]
+            Request,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Request: 0,
+            Silent,
+            SetSilent,
+            ParentWindow,
+            SetParentWindow,
+            #[cfg(feature = "Win32_System_Com")]
+            NameValuePairs,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            NameValuePairs: 0,
+            EnrollmentContext,
+            #[cfg(feature = "Win32_System_Com")]
+            Status,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Status: 0,
+            get_Certificate,
+            get_Response,
+            CertificateFriendlyName,
+            SetCertificateFriendlyName,
+            CertificateDescription,
+            SetCertificateDescription,
+            RequestId,
+            CAConfigString,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509Enrollment as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509Enrollment2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeFromTemplate(this: *mut core::ffi::c_void, context: X509CertificateEnrollmentContext, ppolicyserver: *mut core::ffi::c_void, ptemplate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Enrollment2", "InitializeFromTemplate", 30usize, |this| ((**(this as *const *const IX509Enrollment2_Vtbl)).InitializeFromTemplate)(this, context, ppolicyserver, ptemplate), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstallResponse2(this: *mut core::ffi::c_void, restrictions: InstallResponseRestrictionFlags, strresponse: core::mem::MaybeUninit<windows_core::BSTR>, encoding: EncodingType, strpassword: core::mem::MaybeUninit<windows_core::BSTR>, strenrollmentpolicyserverurl: core::mem::MaybeUninit<windows_core::BSTR>, strenrollmentpolicyserverid: core::mem::MaybeUninit<windows_core::BSTR>, enrollmentpolicyserverflags: PolicyServerUrlFlags, authflags: X509EnrollmentAuthFlags) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Enrollment2", "InstallResponse2", 31usize, |this| ((**(this as *const *const IX509Enrollment2_Vtbl)).InstallResponse2)(this, restrictions, strresponse, encoding, strpassword, strenrollmentpolicyserverurl, strenrollmentpolicyserverid, enrollmentpolicyserverflags, authflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PolicyServer(this: *mut core::ffi::c_void, pppolicyserver: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Enrollment2", "PolicyServer", 32usize, |this| ((**(this as *const *const IX509Enrollment2_Vtbl)).PolicyServer)(this, pppolicyserver), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Template(this: *mut core::ffi::c_void, pptemplate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Enrollment2", "Template", 33usize, |this| ((**(this as *const *const IX509Enrollment2_Vtbl)).Template)(this, pptemplate), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestIdString(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Enrollment2", "RequestIdString", 34usize, |this| ((**(this as *const *const IX509Enrollment2_Vtbl)).RequestIdString)(this, pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <IX509Enrollment_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeFromTemplate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeFromTemplate: 0,
+            InstallResponse2,
+            #[cfg(feature = "Win32_System_Com")]
+            PolicyServer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PolicyServer: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Template,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Template: 0,
+            RequestIdString,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509Enrollment2 as windows_core::Interface>::IID || <IX509Enrollment_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509EnrollmentHelper_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddPolicyServer(this: *mut core::ffi::c_void, strenrollmentpolicyserveruri: core::mem::MaybeUninit<windows_core::BSTR>, strenrollmentpolicyid: core::mem::MaybeUninit<windows_core::BSTR>, enrollmentpolicyserverflags: PolicyServerUrlFlags, authflags: X509EnrollmentAuthFlags, strcredential: core::mem::MaybeUninit<windows_core::BSTR>, strpassword: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentHelper", "AddPolicyServer", 7usize, |this| ((**(this as *const *const IX509EnrollmentHelper_Vtbl)).AddPolicyServer)(this, strenrollmentpolicyserveruri, strenrollmentpolicyid, enrollmentpolicyserverflags, authflags, strcredential, strpassword), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddEnrollmentServer(this: *mut core::ffi::c_void, strenrollmentserveruri: core::mem::MaybeUninit<windows_core::BSTR>, authflags: X509EnrollmentAuthFlags, strcredential: core::mem::MaybeUninit<windows_core::BSTR>, strpassword: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentHelper", "AddEnrollmentServer", 8usize, |this| ((**(this as *const *const IX509EnrollmentHelper_Vtbl)).AddEnrollmentServer)(this, strenrollmentserveruri, authflags, strcredential, strpassword), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enroll(this: *mut core::ffi::c_void, strenrollmentpolicyserveruri: core::mem::MaybeUninit<windows_core::BSTR>, strtemplatename: core::mem::MaybeUninit<windows_core::BSTR>, encoding: EncodingType, enrollflags: WebEnrollmentFlags, pstrcertificate: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentHelper", "Enroll", 9usize, |this| ((**(this as *const *const IX509EnrollmentHelper_Vtbl)).Enroll)(this, strenrollmentpolicyserveruri, strtemplatename, encoding, enrollflags, pstrcertificate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, context: X509CertificateEnrollmentContext) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentHelper", "Initialize", 10usize, |this| ((**(this as *const *const IX509EnrollmentHelper_Vtbl)).Initialize)(this, context), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddPolicyServer,
+            AddEnrollmentServer,
+            Enroll,
+            Initialize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509EnrollmentHelper as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509EnrollmentPolicyServer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, bstrpolicyserverurl: core::mem::MaybeUninit<windows_core::BSTR>, bstrpolicyserverid: core::mem::MaybeUninit<windows_core::BSTR>, authflags: X509EnrollmentAuthFlags, fisuntrusted: super::super::super::Foundation::VARIANT_BOOL, context: X509CertificateEnrollmentContext) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentPolicyServer", "Initialize", 7usize, |this| ((**(this as *const *const IX509EnrollmentPolicyServer_Vtbl)).Initialize)(this, bstrpolicyserverurl, bstrpolicyserverid, authflags, fisuntrusted, context), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadPolicy(this: *mut core::ffi::c_void, option: X509EnrollmentPolicyLoadOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentPolicyServer", "LoadPolicy", 8usize, |this| ((**(this as *const *const IX509EnrollmentPolicyServer_Vtbl)).LoadPolicy)(this, option), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetTemplates(this: *mut core::ffi::c_void, ptemplates: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentPolicyServer", "GetTemplates", 9usize, |this| ((**(this as *const *const IX509EnrollmentPolicyServer_Vtbl)).GetTemplates)(this, ptemplates), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetCAsForTemplate(this: *mut core::ffi::c_void, ptemplate: *mut core::ffi::c_void, ppcas: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentPolicyServer", "GetCAsForTemplate", 10usize, |this| ((**(this as *const *const IX509EnrollmentPolicyServer_Vtbl)).GetCAsForTemplate)(this, ptemplate, ppcas), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetCAs(this: *mut core::ffi::c_void, ppcas: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentPolicyServer", "GetCAs", 11usize, |this| ((**(this as *const *const IX509EnrollmentPolicyServer_Vtbl)).GetCAs)(this, ppcas), |result| Some(*result))
+        }
+        unsafe extern "system" fn Validate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentPolicyServer", "Validate", 12usize, |this| ((**(this as *const *const IX509EnrollmentPolicyServer_Vtbl)).Validate)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetCustomOids(this: *mut core::ffi::c_void, ppobjectids: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentPolicyServer", "GetCustomOids", 13usize, |this| ((**(this as *const *const IX509EnrollmentPolicyServer_Vtbl)).GetCustomOids)(this, ppobjectids), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextUpdateTime(this: *mut core::ffi::c_void, pdate: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentPolicyServer", "GetNextUpdateTime", 14usize, |this| ((**(this as *const *const IX509EnrollmentPolicyServer_Vtbl)).GetNextUpdateTime)(this, pdate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastUpdateTime(this: *mut core::ffi::c_void, pdate: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentPolicyServer", "GetLastUpdateTime", 15usize, |this| ((**(this as *const *const IX509EnrollmentPolicyServer_Vtbl)).GetLastUpdateTime)(this, pdate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPolicyServerUrl(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentPolicyServer", "GetPolicyServerUrl", 16usize, |this| ((**(this as *const *const IX509EnrollmentPolicyServer_Vtbl)).GetPolicyServerUrl)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPolicyServerId(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentPolicyServer", "GetPolicyServerId", 17usize, |this| ((**(this as *const *const IX509EnrollmentPolicyServer_Vtbl)).GetPolicyServerId)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFriendlyName(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentPolicyServer", "GetFriendlyName", 18usize, |this| ((**(this as *const *const IX509EnrollmentPolicyServer_Vtbl)).GetFriendlyName)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIsDefaultCEP(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentPolicyServer", "GetIsDefaultCEP", 19usize, |this| ((**(this as *const *const IX509EnrollmentPolicyServer_Vtbl)).GetIsDefaultCEP)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUseClientId(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentPolicyServer", "GetUseClientId", 20usize, |this| ((**(this as *const *const IX509EnrollmentPolicyServer_Vtbl)).GetUseClientId)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllowUnTrustedCA(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentPolicyServer", "GetAllowUnTrustedCA", 21usize, |this| ((**(this as *const *const IX509EnrollmentPolicyServer_Vtbl)).GetAllowUnTrustedCA)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCachePath(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentPolicyServer", "GetCachePath", 22usize, |this| ((**(this as *const *const IX509EnrollmentPolicyServer_Vtbl)).GetCachePath)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCacheDir(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentPolicyServer", "GetCacheDir", 23usize, |this| ((**(this as *const *const IX509EnrollmentPolicyServer_Vtbl)).GetCacheDir)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAuthFlags(this: *mut core::ffi::c_void, pvalue: *mut X509EnrollmentAuthFlags) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentPolicyServer", "GetAuthFlags", 24usize, |this| ((**(this as *const *const IX509EnrollmentPolicyServer_Vtbl)).GetAuthFlags)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCredential(this: *mut core::ffi::c_void, hwndparent: i32, flag: X509EnrollmentAuthFlags, strcredential: core::mem::MaybeUninit<windows_core::BSTR>, strpassword: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentPolicyServer", "SetCredential", 25usize, |this| ((**(this as *const *const IX509EnrollmentPolicyServer_Vtbl)).SetCredential)(this, hwndparent, flag, strcredential, strpassword), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryChanges(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentPolicyServer", "QueryChanges", 26usize, |this| ((**(this as *const *const IX509EnrollmentPolicyServer_Vtbl)).QueryChanges)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeImport(this: *mut core::ffi::c_void, val: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentPolicyServer", "InitializeImport", 27usize, |this| ((**(this as *const *const IX509EnrollmentPolicyServer_Vtbl)).InitializeImport)(this, val), |result| Some(*result))
+        }
+        unsafe extern "system" fn Export(this: *mut core::ffi::c_void, exportflags: X509EnrollmentPolicyExportFlags, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentPolicyServer", "Export", 28usize, |this| ((**(this as *const *const IX509EnrollmentPolicyServer_Vtbl)).Export)(this, exportflags, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cost(this: *mut core::ffi::c_void, pvalue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentPolicyServer", "Cost", 29usize, |this| ((**(this as *const *const IX509EnrollmentPolicyServer_Vtbl)).Cost)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCost(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentPolicyServer", "SetCost", 30usize, |this| ((**(this as *const *const IX509EnrollmentPolicyServer_Vtbl)).SetCost)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            LoadPolicy,
+            #[cfg(feature = "Win32_System_Com")]
+            GetTemplates,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetTemplates: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetCAsForTemplate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetCAsForTemplate: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetCAs,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetCAs: 0,
+            Validate,
+            #[cfg(feature = "Win32_System_Com")]
+            GetCustomOids,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetCustomOids: 0,
+            GetNextUpdateTime,
+            GetLastUpdateTime,
+            GetPolicyServerUrl,
+            GetPolicyServerId,
+            GetFriendlyName,
+            GetIsDefaultCEP,
+            GetUseClientId,
+            GetAllowUnTrustedCA,
+            GetCachePath,
+            GetCacheDir,
+            GetAuthFlags,
+            SetCredential,
+            QueryChanges,
+            InitializeImport,
+            Export,
+            Cost,
+            SetCost,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509EnrollmentPolicyServer as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509EnrollmentStatus_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppendText(this: *mut core::ffi::c_void, strtext: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentStatus", "AppendText", 7usize, |this| ((**(this as *const *const IX509EnrollmentStatus_Vtbl)).AppendText)(this, strtext), |result| Some(*result))
+        }
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentStatus", "Text", 8usize, |this| ((**(this as *const *const IX509EnrollmentStatus_Vtbl)).Text)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetText(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentStatus", "SetText", 9usize, |this| ((**(this as *const *const IX509EnrollmentStatus_Vtbl)).SetText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Selected(this: *mut core::ffi::c_void, pvalue: *mut EnrollmentSelectionStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentStatus", "Selected", 10usize, |this| ((**(this as *const *const IX509EnrollmentStatus_Vtbl)).Selected)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelected(this: *mut core::ffi::c_void, value: EnrollmentSelectionStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentStatus", "SetSelected", 11usize, |this| ((**(this as *const *const IX509EnrollmentStatus_Vtbl)).SetSelected)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Display(this: *mut core::ffi::c_void, pvalue: *mut EnrollmentDisplayStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentStatus", "Display", 12usize, |this| ((**(this as *const *const IX509EnrollmentStatus_Vtbl)).Display)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplay(this: *mut core::ffi::c_void, value: EnrollmentDisplayStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentStatus", "SetDisplay", 13usize, |this| ((**(this as *const *const IX509EnrollmentStatus_Vtbl)).SetDisplay)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, pvalue: *mut EnrollmentEnrollStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentStatus", "Status", 14usize, |this| ((**(this as *const *const IX509EnrollmentStatus_Vtbl)).Status)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatus(this: *mut core::ffi::c_void, value: EnrollmentEnrollStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentStatus", "SetStatus", 15usize, |this| ((**(this as *const *const IX509EnrollmentStatus_Vtbl)).SetStatus)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, pvalue: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentStatus", "Error", 16usize, |this| ((**(this as *const *const IX509EnrollmentStatus_Vtbl)).Error)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetError(this: *mut core::ffi::c_void, value: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentStatus", "SetError", 17usize, |this| ((**(this as *const *const IX509EnrollmentStatus_Vtbl)).SetError)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorText(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentStatus", "ErrorText", 18usize, |this| ((**(this as *const *const IX509EnrollmentStatus_Vtbl)).ErrorText)(this, pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AppendText,
+            Text,
+            SetText,
+            Selected,
+            SetSelected,
+            Display,
+            SetDisplay,
+            Status,
+            SetStatus,
+            Error,
+            SetError,
+            ErrorText,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509EnrollmentStatus as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509EnrollmentWebClassFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateObject(this: *mut core::ffi::c_void, strprogid: core::mem::MaybeUninit<windows_core::BSTR>, ppiunknown: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509EnrollmentWebClassFactory", "CreateObject", 7usize, |this| ((**(this as *const *const IX509EnrollmentWebClassFactory_Vtbl)).CreateObject)(this, strprogid, ppiunknown), |result| Some(*result))
+        }
+        Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509EnrollmentWebClassFactory as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509Extension_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pobjectid: *mut core::ffi::c_void, encoding: EncodingType, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Extension", "Initialize", 7usize, |this| ((**(this as *const *const IX509Extension_Vtbl)).Initialize)(this, pobjectid, encoding, strencodeddata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ObjectId(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Extension", "ObjectId", 8usize, |this| ((**(this as *const *const IX509Extension_Vtbl)).ObjectId)(this, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_RawData(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Extension", "get_RawData", 9usize, |this| ((**(this as *const *const IX509Extension_Vtbl)).get_RawData)(this, encoding, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Critical(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Extension", "Critical", 10usize, |this| ((**(this as *const *const IX509Extension_Vtbl)).Critical)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCritical(this: *mut core::ffi::c_void, value: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Extension", "SetCritical", 11usize, |this| ((**(this as *const *const IX509Extension_Vtbl)).SetCritical)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Initialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Initialize: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ObjectId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ObjectId: 0,
+            get_RawData,
+            Critical,
+            SetCritical,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509Extension as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509ExtensionAlternativeNames_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeEncode(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionAlternativeNames", "InitializeEncode", 12usize, |this| ((**(this as *const *const IX509ExtensionAlternativeNames_Vtbl)).InitializeEncode)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeDecode(this: *mut core::ffi::c_void, encoding: EncodingType, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionAlternativeNames", "InitializeDecode", 13usize, |this| ((**(this as *const *const IX509ExtensionAlternativeNames_Vtbl)).InitializeDecode)(this, encoding, strencodeddata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AlternativeNames(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionAlternativeNames", "AlternativeNames", 14usize, |this| ((**(this as *const *const IX509ExtensionAlternativeNames_Vtbl)).AlternativeNames)(this, ppvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <IX509Extension_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeEncode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeEncode: 0,
+            InitializeDecode,
+            #[cfg(feature = "Win32_System_Com")]
+            AlternativeNames,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AlternativeNames: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509ExtensionAlternativeNames as windows_core::Interface>::IID || <IX509Extension_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509ExtensionAuthorityKeyIdentifier_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeEncode(this: *mut core::ffi::c_void, encoding: EncodingType, strkeyidentifier: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionAuthorityKeyIdentifier", "InitializeEncode", 12usize, |this| ((**(this as *const *const IX509ExtensionAuthorityKeyIdentifier_Vtbl)).InitializeEncode)(this, encoding, strkeyidentifier), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeDecode(this: *mut core::ffi::c_void, encoding: EncodingType, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionAuthorityKeyIdentifier", "InitializeDecode", 13usize, |this| ((**(this as *const *const IX509ExtensionAuthorityKeyIdentifier_Vtbl)).InitializeDecode)(this, encoding, strencodeddata), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_AuthorityKeyIdentifier(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionAuthorityKeyIdentifier", "get_AuthorityKeyIdentifier", 14usize, |this| ((**(this as *const *const IX509ExtensionAuthorityKeyIdentifier_Vtbl)).get_AuthorityKeyIdentifier)(this, encoding, pvalue), |result| Some(*result))
+        }
+        Self { base__: <IX509Extension_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InitializeEncode, InitializeDecode, get_AuthorityKeyIdentifier }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509ExtensionAuthorityKeyIdentifier as windows_core::Interface>::IID || <IX509Extension_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509ExtensionBasicConstraints_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeEncode(this: *mut core::ffi::c_void, isca: super::super::super::Foundation::VARIANT_BOOL, pathlenconstraint: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionBasicConstraints", "InitializeEncode", 12usize, |this| ((**(this as *const *const IX509ExtensionBasicConstraints_Vtbl)).InitializeEncode)(this, isca, pathlenconstraint), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeDecode(this: *mut core::ffi::c_void, encoding: EncodingType, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionBasicConstraints", "InitializeDecode", 13usize, |this| ((**(this as *const *const IX509ExtensionBasicConstraints_Vtbl)).InitializeDecode)(this, encoding, strencodeddata), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCA(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionBasicConstraints", "IsCA", 14usize, |this| ((**(this as *const *const IX509ExtensionBasicConstraints_Vtbl)).IsCA)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn PathLenConstraint(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionBasicConstraints", "PathLenConstraint", 15usize, |this| ((**(this as *const *const IX509ExtensionBasicConstraints_Vtbl)).PathLenConstraint)(this, pvalue), |result| Some(*result))
+        }
+        Self { base__: <IX509Extension_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InitializeEncode, InitializeDecode, IsCA, PathLenConstraint }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509ExtensionBasicConstraints as windows_core::Interface>::IID || <IX509Extension_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509ExtensionCertificatePolicies_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeEncode(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionCertificatePolicies", "InitializeEncode", 12usize, |this| ((**(this as *const *const IX509ExtensionCertificatePolicies_Vtbl)).InitializeEncode)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeDecode(this: *mut core::ffi::c_void, encoding: EncodingType, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionCertificatePolicies", "InitializeDecode", 13usize, |this| ((**(this as *const *const IX509ExtensionCertificatePolicies_Vtbl)).InitializeDecode)(this, encoding, strencodeddata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Policies(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionCertificatePolicies", "Policies", 14usize, |this| ((**(this as *const *const IX509ExtensionCertificatePolicies_Vtbl)).Policies)(this, ppvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <IX509Extension_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeEncode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeEncode: 0,
+            InitializeDecode,
+            #[cfg(feature = "Win32_System_Com")]
+            Policies,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Policies: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509ExtensionCertificatePolicies as windows_core::Interface>::IID || <IX509Extension_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509ExtensionEnhancedKeyUsage_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeEncode(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionEnhancedKeyUsage", "InitializeEncode", 12usize, |this| ((**(this as *const *const IX509ExtensionEnhancedKeyUsage_Vtbl)).InitializeEncode)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeDecode(this: *mut core::ffi::c_void, encoding: EncodingType, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionEnhancedKeyUsage", "InitializeDecode", 13usize, |this| ((**(this as *const *const IX509ExtensionEnhancedKeyUsage_Vtbl)).InitializeDecode)(this, encoding, strencodeddata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnhancedKeyUsage(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionEnhancedKeyUsage", "EnhancedKeyUsage", 14usize, |this| ((**(this as *const *const IX509ExtensionEnhancedKeyUsage_Vtbl)).EnhancedKeyUsage)(this, ppvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <IX509Extension_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeEncode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeEncode: 0,
+            InitializeDecode,
+            #[cfg(feature = "Win32_System_Com")]
+            EnhancedKeyUsage,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnhancedKeyUsage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509ExtensionEnhancedKeyUsage as windows_core::Interface>::IID || <IX509Extension_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509ExtensionKeyUsage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeEncode(this: *mut core::ffi::c_void, usageflags: X509KeyUsageFlags) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionKeyUsage", "InitializeEncode", 12usize, |this| ((**(this as *const *const IX509ExtensionKeyUsage_Vtbl)).InitializeEncode)(this, usageflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeDecode(this: *mut core::ffi::c_void, encoding: EncodingType, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionKeyUsage", "InitializeDecode", 13usize, |this| ((**(this as *const *const IX509ExtensionKeyUsage_Vtbl)).InitializeDecode)(this, encoding, strencodeddata), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyUsage(this: *mut core::ffi::c_void, pvalue: *mut X509KeyUsageFlags) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionKeyUsage", "KeyUsage", 14usize, |this| ((**(this as *const *const IX509ExtensionKeyUsage_Vtbl)).KeyUsage)(this, pvalue), |result| Some(*result))
+        }
+        Self { base__: <IX509Extension_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InitializeEncode, InitializeDecode, KeyUsage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509ExtensionKeyUsage as windows_core::Interface>::IID || <IX509Extension_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509ExtensionMSApplicationPolicies_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeEncode(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionMSApplicationPolicies", "InitializeEncode", 12usize, |this| ((**(this as *const *const IX509ExtensionMSApplicationPolicies_Vtbl)).InitializeEncode)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeDecode(this: *mut core::ffi::c_void, encoding: EncodingType, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionMSApplicationPolicies", "InitializeDecode", 13usize, |this| ((**(this as *const *const IX509ExtensionMSApplicationPolicies_Vtbl)).InitializeDecode)(this, encoding, strencodeddata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Policies(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionMSApplicationPolicies", "Policies", 14usize, |this| ((**(this as *const *const IX509ExtensionMSApplicationPolicies_Vtbl)).Policies)(this, ppvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <IX509Extension_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeEncode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeEncode: 0,
+            InitializeDecode,
+            #[cfg(feature = "Win32_System_Com")]
+            Policies,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Policies: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509ExtensionMSApplicationPolicies as windows_core::Interface>::IID || <IX509Extension_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509ExtensionSmimeCapabilities_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeEncode(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionSmimeCapabilities", "InitializeEncode", 12usize, |this| ((**(this as *const *const IX509ExtensionSmimeCapabilities_Vtbl)).InitializeEncode)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeDecode(this: *mut core::ffi::c_void, encoding: EncodingType, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionSmimeCapabilities", "InitializeDecode", 13usize, |this| ((**(this as *const *const IX509ExtensionSmimeCapabilities_Vtbl)).InitializeDecode)(this, encoding, strencodeddata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SmimeCapabilities(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionSmimeCapabilities", "SmimeCapabilities", 14usize, |this| ((**(this as *const *const IX509ExtensionSmimeCapabilities_Vtbl)).SmimeCapabilities)(this, ppvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <IX509Extension_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeEncode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeEncode: 0,
+            InitializeDecode,
+            #[cfg(feature = "Win32_System_Com")]
+            SmimeCapabilities,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SmimeCapabilities: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509ExtensionSmimeCapabilities as windows_core::Interface>::IID || <IX509Extension_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509ExtensionSubjectKeyIdentifier_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeEncode(this: *mut core::ffi::c_void, encoding: EncodingType, strkeyidentifier: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionSubjectKeyIdentifier", "InitializeEncode", 12usize, |this| ((**(this as *const *const IX509ExtensionSubjectKeyIdentifier_Vtbl)).InitializeEncode)(this, encoding, strkeyidentifier), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeDecode(this: *mut core::ffi::c_void, encoding: EncodingType, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionSubjectKeyIdentifier", "InitializeDecode", 13usize, |this| ((**(this as *const *const IX509ExtensionSubjectKeyIdentifier_Vtbl)).InitializeDecode)(this, encoding, strencodeddata), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_SubjectKeyIdentifier(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionSubjectKeyIdentifier", "get_SubjectKeyIdentifier", 14usize, |this| ((**(this as *const *const IX509ExtensionSubjectKeyIdentifier_Vtbl)).get_SubjectKeyIdentifier)(this, encoding, pvalue), |result| Some(*result))
+        }
+        Self { base__: <IX509Extension_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InitializeEncode, InitializeDecode, get_SubjectKeyIdentifier }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509ExtensionSubjectKeyIdentifier as windows_core::Interface>::IID || <IX509Extension_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509ExtensionTemplate_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitializeEncode(this: *mut core::ffi::c_void, ptemplateoid: *mut core::ffi::c_void, majorversion: i32, minorversion: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionTemplate", "InitializeEncode", 12usize, |this| ((**(this as *const *const IX509ExtensionTemplate_Vtbl)).InitializeEncode)(this, ptemplateoid, majorversion, minorversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeDecode(this: *mut core::ffi::c_void, encoding: EncodingType, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionTemplate", "InitializeDecode", 13usize, |this| ((**(this as *const *const IX509ExtensionTemplate_Vtbl)).InitializeDecode)(this, encoding, strencodeddata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn TemplateOid(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionTemplate", "TemplateOid", 14usize, |this| ((**(this as *const *const IX509ExtensionTemplate_Vtbl)).TemplateOid)(this, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn MajorVersion(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionTemplate", "MajorVersion", 15usize, |this| ((**(this as *const *const IX509ExtensionTemplate_Vtbl)).MajorVersion)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinorVersion(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionTemplate", "MinorVersion", 16usize, |this| ((**(this as *const *const IX509ExtensionTemplate_Vtbl)).MinorVersion)(this, pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <IX509Extension_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            InitializeEncode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitializeEncode: 0,
+            InitializeDecode,
+            #[cfg(feature = "Win32_System_Com")]
+            TemplateOid,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            TemplateOid: 0,
+            MajorVersion,
+            MinorVersion,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509ExtensionTemplate as windows_core::Interface>::IID || <IX509Extension_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509ExtensionTemplateName_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeEncode(this: *mut core::ffi::c_void, strtemplatename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionTemplateName", "InitializeEncode", 12usize, |this| ((**(this as *const *const IX509ExtensionTemplateName_Vtbl)).InitializeEncode)(this, strtemplatename), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeDecode(this: *mut core::ffi::c_void, encoding: EncodingType, strencodeddata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionTemplateName", "InitializeDecode", 13usize, |this| ((**(this as *const *const IX509ExtensionTemplateName_Vtbl)).InitializeDecode)(this, encoding, strencodeddata), |result| Some(*result))
+        }
+        unsafe extern "system" fn TemplateName(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509ExtensionTemplateName", "TemplateName", 14usize, |this| ((**(this as *const *const IX509ExtensionTemplateName_Vtbl)).TemplateName)(this, pvalue), |result| Some(*result))
+        }
+        Self { base__: <IX509Extension_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InitializeEncode, InitializeDecode, TemplateName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509ExtensionTemplateName as windows_core::Interface>::IID || <IX509Extension_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509Extensions_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ItemByIndex(this: *mut core::ffi::c_void, index: i32, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Extensions", "get_ItemByIndex", 7usize, |this| ((**(this as *const *const IX509Extensions_Vtbl)).get_ItemByIndex)(this, index, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Extensions", "Count", 8usize, |this| ((**(this as *const *const IX509Extensions_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Extensions", "_NewEnum", 9usize, |this| ((**(this as *const *const IX509Extensions_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Extensions", "Add", 10usize, |this| ((**(this as *const *const IX509Extensions_Vtbl)).Add)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Extensions", "Remove", 11usize, |this| ((**(this as *const *const IX509Extensions_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Extensions", "Clear", 12usize, |this| ((**(this as *const *const IX509Extensions_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_IndexByObjectId(this: *mut core::ffi::c_void, pobjectid: *mut core::ffi::c_void, pindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Extensions", "get_IndexByObjectId", 13usize, |this| ((**(this as *const *const IX509Extensions_Vtbl)).get_IndexByObjectId)(this, pobjectid, pindex), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddRange(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509Extensions", "AddRange", 14usize, |this| ((**(this as *const *const IX509Extensions_Vtbl)).AddRange)(this, pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ItemByIndex,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ItemByIndex: 0,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            Clear,
+            #[cfg(feature = "Win32_System_Com")]
+            get_IndexByObjectId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_IndexByObjectId: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AddRange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddRange: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509Extensions as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509MachineEnrollmentFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateObject(this: *mut core::ffi::c_void, strprogid: core::mem::MaybeUninit<windows_core::BSTR>, ppihelper: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509MachineEnrollmentFactory", "CreateObject", 7usize, |this| ((**(this as *const *const IX509MachineEnrollmentFactory_Vtbl)).CreateObject)(this, strprogid, ppihelper), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateObject: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509MachineEnrollmentFactory as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509NameValuePair_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, strname: core::mem::MaybeUninit<windows_core::BSTR>, strvalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509NameValuePair", "Initialize", 7usize, |this| ((**(this as *const *const IX509NameValuePair_Vtbl)).Initialize)(this, strname, strvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509NameValuePair", "Value", 8usize, |this| ((**(this as *const *const IX509NameValuePair_Vtbl)).Value)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509NameValuePair", "Name", 9usize, |this| ((**(this as *const *const IX509NameValuePair_Vtbl)).Name)(this, pvalue), |result| Some(*result))
+        }
+        Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, Value, Name }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509NameValuePair as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509NameValuePairs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ItemByIndex(this: *mut core::ffi::c_void, index: i32, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509NameValuePairs", "get_ItemByIndex", 7usize, |this| ((**(this as *const *const IX509NameValuePairs_Vtbl)).get_ItemByIndex)(this, index, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509NameValuePairs", "Count", 8usize, |this| ((**(this as *const *const IX509NameValuePairs_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509NameValuePairs", "_NewEnum", 9usize, |this| ((**(this as *const *const IX509NameValuePairs_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509NameValuePairs", "Add", 10usize, |this| ((**(this as *const *const IX509NameValuePairs_Vtbl)).Add)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509NameValuePairs", "Remove", 11usize, |this| ((**(this as *const *const IX509NameValuePairs_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509NameValuePairs", "Clear", 12usize, |this| ((**(this as *const *const IX509NameValuePairs_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ItemByIndex,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ItemByIndex: 0,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            Clear,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509NameValuePairs as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509PolicyServerListManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ItemByIndex(this: *mut core::ffi::c_void, index: i32, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PolicyServerListManager", "get_ItemByIndex", 7usize, |this| ((**(this as *const *const IX509PolicyServerListManager_Vtbl)).get_ItemByIndex)(this, index, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PolicyServerListManager", "Count", 8usize, |this| ((**(this as *const *const IX509PolicyServerListManager_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PolicyServerListManager", "_NewEnum", 9usize, |this| ((**(this as *const *const IX509PolicyServerListManager_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PolicyServerListManager", "Add", 10usize, |this| ((**(this as *const *const IX509PolicyServerListManager_Vtbl)).Add)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PolicyServerListManager", "Remove", 11usize, |this| ((**(this as *const *const IX509PolicyServerListManager_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PolicyServerListManager", "Clear", 12usize, |this| ((**(this as *const *const IX509PolicyServerListManager_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, context: X509CertificateEnrollmentContext, flags: PolicyServerUrlFlags) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PolicyServerListManager", "Initialize", 13usize, |this| ((**(this as *const *const IX509PolicyServerListManager_Vtbl)).Initialize)(this, context, flags), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ItemByIndex,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ItemByIndex: 0,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            Clear,
+            Initialize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509PolicyServerListManager as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509PolicyServerUrl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, context: X509CertificateEnrollmentContext) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PolicyServerUrl", "Initialize", 7usize, |this| ((**(this as *const *const IX509PolicyServerUrl_Vtbl)).Initialize)(this, context), |result| Some(*result))
+        }
+        unsafe extern "system" fn Url(this: *mut core::ffi::c_void, ppvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PolicyServerUrl", "Url", 8usize, |this| ((**(this as *const *const IX509PolicyServerUrl_Vtbl)).Url)(this, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUrl(this: *mut core::ffi::c_void, pvalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PolicyServerUrl", "SetUrl", 9usize, |this| ((**(this as *const *const IX509PolicyServerUrl_Vtbl)).SetUrl)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Default(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PolicyServerUrl", "Default", 10usize, |this| ((**(this as *const *const IX509PolicyServerUrl_Vtbl)).Default)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefault(this: *mut core::ffi::c_void, value: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PolicyServerUrl", "SetDefault", 11usize, |this| ((**(this as *const *const IX509PolicyServerUrl_Vtbl)).SetDefault)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flags(this: *mut core::ffi::c_void, pvalue: *mut PolicyServerUrlFlags) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PolicyServerUrl", "Flags", 12usize, |this| ((**(this as *const *const IX509PolicyServerUrl_Vtbl)).Flags)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFlags(this: *mut core::ffi::c_void, flags: PolicyServerUrlFlags) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PolicyServerUrl", "SetFlags", 13usize, |this| ((**(this as *const *const IX509PolicyServerUrl_Vtbl)).SetFlags)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthFlags(this: *mut core::ffi::c_void, pvalue: *mut X509EnrollmentAuthFlags) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PolicyServerUrl", "AuthFlags", 14usize, |this| ((**(this as *const *const IX509PolicyServerUrl_Vtbl)).AuthFlags)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthFlags(this: *mut core::ffi::c_void, flags: X509EnrollmentAuthFlags) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PolicyServerUrl", "SetAuthFlags", 15usize, |this| ((**(this as *const *const IX509PolicyServerUrl_Vtbl)).SetAuthFlags)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cost(this: *mut core::ffi::c_void, pvalue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PolicyServerUrl", "Cost", 16usize, |this| ((**(this as *const *const IX509PolicyServerUrl_Vtbl)).Cost)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCost(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PolicyServerUrl", "SetCost", 17usize, |this| ((**(this as *const *const IX509PolicyServerUrl_Vtbl)).SetCost)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStringProperty(this: *mut core::ffi::c_void, propertyid: PolicyServerUrlPropertyID, ppvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PolicyServerUrl", "GetStringProperty", 18usize, |this| ((**(this as *const *const IX509PolicyServerUrl_Vtbl)).GetStringProperty)(this, propertyid, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStringProperty(this: *mut core::ffi::c_void, propertyid: PolicyServerUrlPropertyID, pvalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PolicyServerUrl", "SetStringProperty", 19usize, |this| ((**(this as *const *const IX509PolicyServerUrl_Vtbl)).SetStringProperty)(this, propertyid, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateRegistry(this: *mut core::ffi::c_void, context: X509CertificateEnrollmentContext) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PolicyServerUrl", "UpdateRegistry", 20usize, |this| ((**(this as *const *const IX509PolicyServerUrl_Vtbl)).UpdateRegistry)(this, context), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFromRegistry(this: *mut core::ffi::c_void, context: X509CertificateEnrollmentContext) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PolicyServerUrl", "RemoveFromRegistry", 21usize, |this| ((**(this as *const *const IX509PolicyServerUrl_Vtbl)).RemoveFromRegistry)(this, context), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            Url,
+            SetUrl,
+            Default,
+            SetDefault,
+            Flags,
+            SetFlags,
+            AuthFlags,
+            SetAuthFlags,
+            Cost,
+            SetCost,
+            GetStringProperty,
+            SetStringProperty,
+            UpdateRegistry,
+            RemoveFromRegistry,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509PolicyServerUrl as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509PrivateKey_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "Open", 7usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).Open)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "Create", 8usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).Create)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "Close", 9usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "Delete", 10usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Verify(this: *mut core::ffi::c_void, verifytype: X509PrivateKeyVerify) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "Verify", 11usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).Verify)(this, verifytype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Import(this: *mut core::ffi::c_void, strexporttype: core::mem::MaybeUninit<windows_core::BSTR>, strencodedkey: core::mem::MaybeUninit<windows_core::BSTR>, encoding: EncodingType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "Import", 12usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).Import)(this, strexporttype, strencodedkey, encoding), |result| Some(*result))
+        }
+        unsafe extern "system" fn Export(this: *mut core::ffi::c_void, strexporttype: core::mem::MaybeUninit<windows_core::BSTR>, encoding: EncodingType, pstrencodedkey: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "Export", 13usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).Export)(this, strexporttype, encoding, pstrencodedkey), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ExportPublicKey(this: *mut core::ffi::c_void, pppublickey: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "ExportPublicKey", 14usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).ExportPublicKey)(this, pppublickey), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContainerName(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "ContainerName", 15usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).ContainerName)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContainerName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "SetContainerName", 16usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).SetContainerName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContainerNamePrefix(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "ContainerNamePrefix", 17usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).ContainerNamePrefix)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContainerNamePrefix(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "SetContainerNamePrefix", 18usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).SetContainerNamePrefix)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReaderName(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "ReaderName", 19usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).ReaderName)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReaderName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "SetReaderName", 20usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).SetReaderName)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CspInformations(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "CspInformations", 21usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).CspInformations)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetCspInformations(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "SetCspInformations", 22usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).SetCspInformations)(this, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CspStatus(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "CspStatus", 23usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).CspStatus)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetCspStatus(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "SetCspStatus", 24usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).SetCspStatus)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderName(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "ProviderName", 25usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).ProviderName)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProviderName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "SetProviderName", 26usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).SetProviderName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderType(this: *mut core::ffi::c_void, pvalue: *mut X509ProviderType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "ProviderType", 27usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).ProviderType)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProviderType(this: *mut core::ffi::c_void, value: X509ProviderType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "SetProviderType", 28usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).SetProviderType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LegacyCsp(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "LegacyCsp", 29usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).LegacyCsp)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLegacyCsp(this: *mut core::ffi::c_void, value: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "SetLegacyCsp", 30usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).SetLegacyCsp)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Algorithm(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "Algorithm", 31usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).Algorithm)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetAlgorithm(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "SetAlgorithm", 32usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).SetAlgorithm)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeySpec(this: *mut core::ffi::c_void, pvalue: *mut X509KeySpec) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "KeySpec", 33usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).KeySpec)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeySpec(this: *mut core::ffi::c_void, value: X509KeySpec) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "SetKeySpec", 34usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).SetKeySpec)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "Length", 35usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).Length)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLength(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "SetLength", 36usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).SetLength)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExportPolicy(this: *mut core::ffi::c_void, pvalue: *mut X509PrivateKeyExportFlags) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "ExportPolicy", 37usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).ExportPolicy)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExportPolicy(this: *mut core::ffi::c_void, value: X509PrivateKeyExportFlags) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "SetExportPolicy", 38usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).SetExportPolicy)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyUsage(this: *mut core::ffi::c_void, pvalue: *mut X509PrivateKeyUsageFlags) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "KeyUsage", 39usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).KeyUsage)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeyUsage(this: *mut core::ffi::c_void, value: X509PrivateKeyUsageFlags) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "SetKeyUsage", 40usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).SetKeyUsage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyProtection(this: *mut core::ffi::c_void, pvalue: *mut X509PrivateKeyProtection) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "KeyProtection", 41usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).KeyProtection)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeyProtection(this: *mut core::ffi::c_void, value: X509PrivateKeyProtection) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "SetKeyProtection", 42usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).SetKeyProtection)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MachineContext(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "MachineContext", 43usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).MachineContext)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMachineContext(this: *mut core::ffi::c_void, value: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "SetMachineContext", 44usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).SetMachineContext)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SecurityDescriptor(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "SecurityDescriptor", 45usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).SecurityDescriptor)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSecurityDescriptor(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "SetSecurityDescriptor", 46usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).SetSecurityDescriptor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Certificate(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "get_Certificate", 47usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).get_Certificate)(this, encoding, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_Certificate(this: *mut core::ffi::c_void, encoding: EncodingType, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "put_Certificate", 48usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).put_Certificate)(this, encoding, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UniqueContainerName(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "UniqueContainerName", 49usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).UniqueContainerName)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Opened(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "Opened", 50usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).Opened)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultContainer(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "DefaultContainer", 51usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).DefaultContainer)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Existing(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "Existing", 52usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).Existing)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExisting(this: *mut core::ffi::c_void, value: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "SetExisting", 53usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).SetExisting)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Silent(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "Silent", 54usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).Silent)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSilent(this: *mut core::ffi::c_void, value: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "SetSilent", 55usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).SetSilent)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParentWindow(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "ParentWindow", 56usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).ParentWindow)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParentWindow(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "SetParentWindow", 57usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).SetParentWindow)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UIContextMessage(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "UIContextMessage", 58usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).UIContextMessage)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUIContextMessage(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "SetUIContextMessage", 59usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).SetUIContextMessage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPin(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "SetPin", 60usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).SetPin)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FriendlyName(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "FriendlyName", 61usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).FriendlyName)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFriendlyName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "SetFriendlyName", 62usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).SetFriendlyName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "Description", 63usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).Description)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey", "SetDescription", 64usize, |this| ((**(this as *const *const IX509PrivateKey_Vtbl)).SetDescription)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Open,
+            Create,
+            Close,
+            Delete,
+            Verify,
+            Import,
+            Export,
+            #[cfg(feature = "Win32_System_Com")]
+            ExportPublicKey,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ExportPublicKey: 0,
+            ContainerName,
+            SetContainerName,
+            ContainerNamePrefix,
+            SetContainerNamePrefix,
+            ReaderName,
+            SetReaderName,
+            #[cfg(feature = "Win32_System_Com")]
+            CspInformations,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CspInformations: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetCspInformations,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetCspInformations: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CspStatus,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CspStatus: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetCspStatus,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetCspStatus: 0,
+            ProviderName,
+            SetProviderName,
+            ProviderType,
+            SetProviderType,
+            LegacyCsp,
+            SetLegacyCsp,
+            #[cfg(feature = "Win32_System_Com")]
+            Algorithm,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Algorithm: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetAlgorithm,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetAlgorithm: 0,
+            KeySpec,
+            SetKeySpec,
+            Length,
+            SetLength,
+            ExportPolicy,
+            SetExportPolicy,
+            KeyUsage,
+            SetKeyUsage,
+            KeyProtection,
+            SetKeyProtection,
+            MachineContext,
+            SetMachineContext,
+            SecurityDescriptor,
+            SetSecurityDescriptor,
+            get_Certificate,
+            put_Certificate,
+            UniqueContainerName,
+            Opened,
+            DefaultContainer,
+            Existing,
+            SetExisting,
+            Silent,
+            SetSilent,
+            ParentWindow,
+            SetParentWindow,
+            UIContextMessage,
+            SetUIContextMessage,
+            SetPin,
+            FriendlyName,
+            SetFriendlyName,
+            Description,
+            SetDescription,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509PrivateKey as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509PrivateKey2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HardwareKeyUsage(this: *mut core::ffi::c_void, pvalue: *mut X509HardwareKeyUsageFlags) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey2", "HardwareKeyUsage", 65usize, |this| ((**(this as *const *const IX509PrivateKey2_Vtbl)).HardwareKeyUsage)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHardwareKeyUsage(this: *mut core::ffi::c_void, value: X509HardwareKeyUsageFlags) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey2", "SetHardwareKeyUsage", 66usize, |this| ((**(this as *const *const IX509PrivateKey2_Vtbl)).SetHardwareKeyUsage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlternateStorageLocation(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey2", "AlternateStorageLocation", 67usize, |this| ((**(this as *const *const IX509PrivateKey2_Vtbl)).AlternateStorageLocation)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlternateStorageLocation(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey2", "SetAlternateStorageLocation", 68usize, |this| ((**(this as *const *const IX509PrivateKey2_Vtbl)).SetAlternateStorageLocation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlgorithmName(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey2", "AlgorithmName", 69usize, |this| ((**(this as *const *const IX509PrivateKey2_Vtbl)).AlgorithmName)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlgorithmName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey2", "SetAlgorithmName", 70usize, |this| ((**(this as *const *const IX509PrivateKey2_Vtbl)).SetAlgorithmName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_AlgorithmParameters(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey2", "get_AlgorithmParameters", 71usize, |this| ((**(this as *const *const IX509PrivateKey2_Vtbl)).get_AlgorithmParameters)(this, encoding, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_AlgorithmParameters(this: *mut core::ffi::c_void, encoding: EncodingType, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey2", "put_AlgorithmParameters", 72usize, |this| ((**(this as *const *const IX509PrivateKey2_Vtbl)).put_AlgorithmParameters)(this, encoding, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParametersExportType(this: *mut core::ffi::c_void, pvalue: *mut X509KeyParametersExportType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey2", "ParametersExportType", 73usize, |this| ((**(this as *const *const IX509PrivateKey2_Vtbl)).ParametersExportType)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParametersExportType(this: *mut core::ffi::c_void, value: X509KeyParametersExportType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PrivateKey2", "SetParametersExportType", 74usize, |this| ((**(this as *const *const IX509PrivateKey2_Vtbl)).SetParametersExportType)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <IX509PrivateKey_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            HardwareKeyUsage,
+            SetHardwareKeyUsage,
+            AlternateStorageLocation,
+            SetAlternateStorageLocation,
+            AlgorithmName,
+            SetAlgorithmName,
+            get_AlgorithmParameters,
+            put_AlgorithmParameters,
+            ParametersExportType,
+            SetParametersExportType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509PrivateKey2 as windows_core::Interface>::IID || <IX509PrivateKey_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509PublicKey_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pobjectid: *mut core::ffi::c_void, strencodedkey: core::mem::MaybeUninit<windows_core::BSTR>, strencodedparameters: core::mem::MaybeUninit<windows_core::BSTR>, encoding: EncodingType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PublicKey", "Initialize", 7usize, |this| ((**(this as *const *const IX509PublicKey_Vtbl)).Initialize)(this, pobjectid, strencodedkey, strencodedparameters, encoding), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeFromEncodedPublicKeyInfo(this: *mut core::ffi::c_void, strencodedpublickeyinfo: core::mem::MaybeUninit<windows_core::BSTR>, encoding: EncodingType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PublicKey", "InitializeFromEncodedPublicKeyInfo", 8usize, |this| ((**(this as *const *const IX509PublicKey_Vtbl)).InitializeFromEncodedPublicKeyInfo)(this, strencodedpublickeyinfo, encoding), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Algorithm(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PublicKey", "Algorithm", 9usize, |this| ((**(this as *const *const IX509PublicKey_Vtbl)).Algorithm)(this, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PublicKey", "Length", 10usize, |this| ((**(this as *const *const IX509PublicKey_Vtbl)).Length)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_EncodedKey(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PublicKey", "get_EncodedKey", 11usize, |this| ((**(this as *const *const IX509PublicKey_Vtbl)).get_EncodedKey)(this, encoding, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_EncodedParameters(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PublicKey", "get_EncodedParameters", 12usize, |this| ((**(this as *const *const IX509PublicKey_Vtbl)).get_EncodedParameters)(this, encoding, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ComputeKeyIdentifier(this: *mut core::ffi::c_void, algorithm: KeyIdentifierHashAlgorithm, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509PublicKey", "ComputeKeyIdentifier", 13usize, |this| ((**(this as *const *const IX509PublicKey_Vtbl)).ComputeKeyIdentifier)(this, algorithm, encoding, pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Initialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Initialize: 0,
+            InitializeFromEncodedPublicKeyInfo,
+            #[cfg(feature = "Win32_System_Com")]
+            Algorithm,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Algorithm: 0,
+            Length,
+            get_EncodedKey,
+            get_EncodedParameters,
+            ComputeKeyIdentifier,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509PublicKey as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509SCEPEnrollment_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, prequest: *mut core::ffi::c_void, strthumbprint: core::mem::MaybeUninit<windows_core::BSTR>, thumprintencoding: EncodingType, strservercertificates: core::mem::MaybeUninit<windows_core::BSTR>, encoding: EncodingType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollment", "Initialize", 7usize, |this| ((**(this as *const *const IX509SCEPEnrollment_Vtbl)).Initialize)(this, prequest, strthumbprint, thumprintencoding, strservercertificates, encoding), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeForPending(this: *mut core::ffi::c_void, context: X509CertificateEnrollmentContext) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollment", "InitializeForPending", 8usize, |this| ((**(this as *const *const IX509SCEPEnrollment_Vtbl)).InitializeForPending)(this, context), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRequestMessage(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollment", "CreateRequestMessage", 9usize, |this| ((**(this as *const *const IX509SCEPEnrollment_Vtbl)).CreateRequestMessage)(this, encoding, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRetrievePendingMessage(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollment", "CreateRetrievePendingMessage", 10usize, |this| ((**(this as *const *const IX509SCEPEnrollment_Vtbl)).CreateRetrievePendingMessage)(this, encoding, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRetrieveCertificateMessage(this: *mut core::ffi::c_void, context: X509CertificateEnrollmentContext, strissuer: core::mem::MaybeUninit<windows_core::BSTR>, issuerencoding: EncodingType, strserialnumber: core::mem::MaybeUninit<windows_core::BSTR>, serialnumberencoding: EncodingType, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollment", "CreateRetrieveCertificateMessage", 11usize, |this| ((**(this as *const *const IX509SCEPEnrollment_Vtbl)).CreateRetrieveCertificateMessage)(this, context, strissuer, issuerencoding, strserialnumber, serialnumberencoding, encoding, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessResponseMessage(this: *mut core::ffi::c_void, strresponse: core::mem::MaybeUninit<windows_core::BSTR>, encoding: EncodingType, pdisposition: *mut X509SCEPDisposition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollment", "ProcessResponseMessage", 12usize, |this| ((**(this as *const *const IX509SCEPEnrollment_Vtbl)).ProcessResponseMessage)(this, strresponse, encoding, pdisposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServerCapabilities(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollment", "SetServerCapabilities", 13usize, |this| ((**(this as *const *const IX509SCEPEnrollment_Vtbl)).SetServerCapabilities)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FailInfo(this: *mut core::ffi::c_void, pvalue: *mut X509SCEPFailInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollment", "FailInfo", 14usize, |this| ((**(this as *const *const IX509SCEPEnrollment_Vtbl)).FailInfo)(this, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SignerCertificate(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollment", "SignerCertificate", 15usize, |this| ((**(this as *const *const IX509SCEPEnrollment_Vtbl)).SignerCertificate)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSignerCertificate(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollment", "SetSignerCertificate", 16usize, |this| ((**(this as *const *const IX509SCEPEnrollment_Vtbl)).SetSignerCertificate)(this, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OldCertificate(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollment", "OldCertificate", 17usize, |this| ((**(this as *const *const IX509SCEPEnrollment_Vtbl)).OldCertificate)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetOldCertificate(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollment", "SetOldCertificate", 18usize, |this| ((**(this as *const *const IX509SCEPEnrollment_Vtbl)).SetOldCertificate)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_TransactionId(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollment", "get_TransactionId", 19usize, |this| ((**(this as *const *const IX509SCEPEnrollment_Vtbl)).get_TransactionId)(this, encoding, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_TransactionId(this: *mut core::ffi::c_void, encoding: EncodingType, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollment", "put_TransactionId", 20usize, |this| ((**(this as *const *const IX509SCEPEnrollment_Vtbl)).put_TransactionId)(this, encoding, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollment", "Request", 21usize, |this| ((**(this as *const *const IX509SCEPEnrollment_Vtbl)).Request)(this, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn CertificateFriendlyName(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollment", "CertificateFriendlyName", 22usize, |this| ((**(this as *const *const IX509SCEPEnrollment_Vtbl)).CertificateFriendlyName)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCertificateFriendlyName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollment", "SetCertificateFriendlyName", 23usize, |this| ((**(this as *const *const IX509SCEPEnrollment_Vtbl)).SetCertificateFriendlyName)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollment", "Status", 24usize, |this| ((**(this as *const *const IX509SCEPEnrollment_Vtbl)).Status)(this, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Certificate(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollment", "get_Certificate", 25usize, |this| ((**(this as *const *const IX509SCEPEnrollment_Vtbl)).get_Certificate)(this, encoding, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Silent(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollment", "Silent", 26usize, |this| ((**(this as *const *const IX509SCEPEnrollment_Vtbl)).Silent)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSilent(this: *mut core::ffi::c_void, value: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollment", "SetSilent", 27usize, |this| ((**(this as *const *const IX509SCEPEnrollment_Vtbl)).SetSilent)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteRequest(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollment", "DeleteRequest", 28usize, |this| ((**(this as *const *const IX509SCEPEnrollment_Vtbl)).DeleteRequest)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Initialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Initialize: 0,
+            InitializeForPending,
+            CreateRequestMessage,
+            CreateRetrievePendingMessage,
+            CreateRetrieveCertificateMessage,
+            ProcessResponseMessage,
+            SetServerCapabilities,
+            FailInfo,
+            #[cfg(feature = "Win32_System_Com")]
+            SignerCertificate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SignerCertificate: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSignerCertificate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSignerCertificate: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OldCertificate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OldCertificate: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetOldCertificate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetOldCertificate: 0,
+            get_TransactionId,
+            put_TransactionId,
+            #[cfg(feature = "Win32_System_Com")]
+            Request,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Request: 0,
+            CertificateFriendlyName,
+            SetCertificateFriendlyName,
+            #[cfg(feature = "Win32_System_Com")]
+            Status,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Status: 0,
+            get_Certificate,
+            Silent,
+            SetSilent,
+            DeleteRequest,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509SCEPEnrollment as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509SCEPEnrollment2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateChallengeAnswerMessage(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollment2", "CreateChallengeAnswerMessage", 29usize, |this| ((**(this as *const *const IX509SCEPEnrollment2_Vtbl)).CreateChallengeAnswerMessage)(this, encoding, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessResponseMessage2(this: *mut core::ffi::c_void, flags: X509SCEPProcessMessageFlags, strresponse: core::mem::MaybeUninit<windows_core::BSTR>, encoding: EncodingType, pdisposition: *mut X509SCEPDisposition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollment2", "ProcessResponseMessage2", 30usize, |this| ((**(this as *const *const IX509SCEPEnrollment2_Vtbl)).ProcessResponseMessage2)(this, flags, strresponse, encoding, pdisposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResultMessageText(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollment2", "ResultMessageText", 31usize, |this| ((**(this as *const *const IX509SCEPEnrollment2_Vtbl)).ResultMessageText)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn DelayRetry(this: *mut core::ffi::c_void, pvalue: *mut DelayRetryAction) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollment2", "DelayRetry", 32usize, |this| ((**(this as *const *const IX509SCEPEnrollment2_Vtbl)).DelayRetry)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivityId(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollment2", "ActivityId", 33usize, |this| ((**(this as *const *const IX509SCEPEnrollment2_Vtbl)).ActivityId)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActivityId(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollment2", "SetActivityId", 34usize, |this| ((**(this as *const *const IX509SCEPEnrollment2_Vtbl)).SetActivityId)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <IX509SCEPEnrollment_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateChallengeAnswerMessage,
+            ProcessResponseMessage2,
+            ResultMessageText,
+            DelayRetry,
+            ActivityId,
+            SetActivityId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509SCEPEnrollment2 as windows_core::Interface>::IID || <IX509SCEPEnrollment_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509SCEPEnrollmentHelper_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, strserverurl: core::mem::MaybeUninit<windows_core::BSTR>, strrequestheaders: core::mem::MaybeUninit<windows_core::BSTR>, prequest: *mut core::ffi::c_void, strcacertificatethumbprint: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollmentHelper", "Initialize", 7usize, |this| ((**(this as *const *const IX509SCEPEnrollmentHelper_Vtbl)).Initialize)(this, strserverurl, strrequestheaders, prequest, strcacertificatethumbprint), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeForPending(this: *mut core::ffi::c_void, strserverurl: core::mem::MaybeUninit<windows_core::BSTR>, strrequestheaders: core::mem::MaybeUninit<windows_core::BSTR>, context: X509CertificateEnrollmentContext, strtransactionid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollmentHelper", "InitializeForPending", 8usize, |this| ((**(this as *const *const IX509SCEPEnrollmentHelper_Vtbl)).InitializeForPending)(this, strserverurl, strrequestheaders, context, strtransactionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enroll(this: *mut core::ffi::c_void, processflags: X509SCEPProcessMessageFlags, pdisposition: *mut X509SCEPDisposition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollmentHelper", "Enroll", 9usize, |this| ((**(this as *const *const IX509SCEPEnrollmentHelper_Vtbl)).Enroll)(this, processflags, pdisposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn FetchPending(this: *mut core::ffi::c_void, processflags: X509SCEPProcessMessageFlags, pdisposition: *mut X509SCEPDisposition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollmentHelper", "FetchPending", 10usize, |this| ((**(this as *const *const IX509SCEPEnrollmentHelper_Vtbl)).FetchPending)(this, processflags, pdisposition), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn X509SCEPEnrollment(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollmentHelper", "X509SCEPEnrollment", 11usize, |this| ((**(this as *const *const IX509SCEPEnrollmentHelper_Vtbl)).X509SCEPEnrollment)(this, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResultMessageText(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SCEPEnrollmentHelper", "ResultMessageText", 12usize, |this| ((**(this as *const *const IX509SCEPEnrollmentHelper_Vtbl)).ResultMessageText)(this, pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Initialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Initialize: 0,
+            InitializeForPending,
+            Enroll,
+            FetchPending,
+            #[cfg(feature = "Win32_System_Com")]
+            X509SCEPEnrollment,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            X509SCEPEnrollment: 0,
+            ResultMessageText,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509SCEPEnrollmentHelper as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IX509SignatureInformation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn HashAlgorithm(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SignatureInformation", "HashAlgorithm", 7usize, |this| ((**(this as *const *const IX509SignatureInformation_Vtbl)).HashAlgorithm)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetHashAlgorithm(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SignatureInformation", "SetHashAlgorithm", 8usize, |this| ((**(this as *const *const IX509SignatureInformation_Vtbl)).SetHashAlgorithm)(this, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PublicKeyAlgorithm(this: *mut core::ffi::c_void, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SignatureInformation", "PublicKeyAlgorithm", 9usize, |this| ((**(this as *const *const IX509SignatureInformation_Vtbl)).PublicKeyAlgorithm)(this, ppvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetPublicKeyAlgorithm(this: *mut core::ffi::c_void, pvalue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SignatureInformation", "SetPublicKeyAlgorithm", 10usize, |this| ((**(this as *const *const IX509SignatureInformation_Vtbl)).SetPublicKeyAlgorithm)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Parameters(this: *mut core::ffi::c_void, encoding: EncodingType, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SignatureInformation", "get_Parameters", 11usize, |this| ((**(this as *const *const IX509SignatureInformation_Vtbl)).get_Parameters)(this, encoding, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_Parameters(this: *mut core::ffi::c_void, encoding: EncodingType, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SignatureInformation", "put_Parameters", 12usize, |this| ((**(this as *const *const IX509SignatureInformation_Vtbl)).put_Parameters)(this, encoding, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlternateSignatureAlgorithm(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SignatureInformation", "AlternateSignatureAlgorithm", 13usize, |this| ((**(this as *const *const IX509SignatureInformation_Vtbl)).AlternateSignatureAlgorithm)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlternateSignatureAlgorithm(this: *mut core::ffi::c_void, value: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SignatureInformation", "SetAlternateSignatureAlgorithm", 14usize, |this| ((**(this as *const *const IX509SignatureInformation_Vtbl)).SetAlternateSignatureAlgorithm)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AlternateSignatureAlgorithmSet(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SignatureInformation", "AlternateSignatureAlgorithmSet", 15usize, |this| ((**(this as *const *const IX509SignatureInformation_Vtbl)).AlternateSignatureAlgorithmSet)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn NullSigned(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SignatureInformation", "NullSigned", 16usize, |this| ((**(this as *const *const IX509SignatureInformation_Vtbl)).NullSigned)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNullSigned(this: *mut core::ffi::c_void, value: super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SignatureInformation", "SetNullSigned", 17usize, |this| ((**(this as *const *const IX509SignatureInformation_Vtbl)).SetNullSigned)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSignatureAlgorithm(this: *mut core::ffi::c_void, pkcs7signature: super::super::super::Foundation::VARIANT_BOOL, signaturekey: super::super::super::Foundation::VARIANT_BOOL, ppvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SignatureInformation", "GetSignatureAlgorithm", 18usize, |this| ((**(this as *const *const IX509SignatureInformation_Vtbl)).GetSignatureAlgorithm)(this, pkcs7signature, signaturekey, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultValues(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IX509SignatureInformation", "SetDefaultValues", 19usize, |this| ((**(this as *const *const IX509SignatureInformation_Vtbl)).SetDefaultValues)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            HashAlgorithm,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            HashAlgorithm: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetHashAlgorithm,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetHashAlgorithm: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PublicKeyAlgorithm,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PublicKeyAlgorithm: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetPublicKeyAlgorithm,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetPublicKeyAlgorithm: 0,
+            get_Parameters,
+            put_Parameters,
+            AlternateSignatureAlgorithm,
+            SetAlternateSignatureAlgorithm,
+            AlternateSignatureAlgorithmSet,
+            NullSigned,
+            SetNullSigned,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSignatureAlgorithm,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSignatureAlgorithm: 0,
+            SetDefaultValues,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IX509SignatureInformation as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -0,0 +1,328 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertSrvSetup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CAErrorId(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetup", "CAErrorId", 7usize, |this| ((**(this as *const *const ICertSrvSetup_Vtbl)).CAErrorId)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CAErrorString(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetup", "CAErrorString", 8usize, |this| ((**(this as *const *const ICertSrvSetup_Vtbl)).CAErrorString)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeDefaults(this: *mut core::ffi::c_void, bserver: super::super::Foundation::VARIANT_BOOL, bclient: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetup", "InitializeDefaults", 9usize, |this| ((**(this as *const *const ICertSrvSetup_Vtbl)).InitializeDefaults)(this, bserver, bclient), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCASetupProperty(this: *mut core::ffi::c_void, propertyid: CASetupProperty, ppropertyvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetup", "GetCASetupProperty", 10usize, |this| ((**(this as *const *const ICertSrvSetup_Vtbl)).GetCASetupProperty)(this, propertyid, ppropertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCASetupProperty(this: *mut core::ffi::c_void, propertyid: CASetupProperty, ppropertyvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetup", "SetCASetupProperty", 11usize, |this| ((**(this as *const *const ICertSrvSetup_Vtbl)).SetCASetupProperty)(this, propertyid, ppropertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPropertyEditable(this: *mut core::ffi::c_void, propertyid: CASetupProperty, pbeditable: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetup", "IsPropertyEditable", 12usize, |this| ((**(this as *const *const ICertSrvSetup_Vtbl)).IsPropertyEditable)(this, propertyid, pbeditable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSupportedCATypes(this: *mut core::ffi::c_void, pcatypes: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetup", "GetSupportedCATypes", 13usize, |this| ((**(this as *const *const ICertSrvSetup_Vtbl)).GetSupportedCATypes)(this, pcatypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProviderNameList(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetup", "GetProviderNameList", 14usize, |this| ((**(this as *const *const ICertSrvSetup_Vtbl)).GetProviderNameList)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKeyLengthList(this: *mut core::ffi::c_void, bstrprovidername: core::mem::MaybeUninit<windows_core::BSTR>, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetup", "GetKeyLengthList", 15usize, |this| ((**(this as *const *const ICertSrvSetup_Vtbl)).GetKeyLengthList)(this, bstrprovidername, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHashAlgorithmList(this: *mut core::ffi::c_void, bstrprovidername: core::mem::MaybeUninit<windows_core::BSTR>, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetup", "GetHashAlgorithmList", 16usize, |this| ((**(this as *const *const ICertSrvSetup_Vtbl)).GetHashAlgorithmList)(this, bstrprovidername, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrivateKeyContainerList(this: *mut core::ffi::c_void, bstrprovidername: core::mem::MaybeUninit<windows_core::BSTR>, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetup", "GetPrivateKeyContainerList", 17usize, |this| ((**(this as *const *const ICertSrvSetup_Vtbl)).GetPrivateKeyContainerList)(this, bstrprovidername, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetExistingCACertificates(this: *mut core::ffi::c_void, ppval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetup", "GetExistingCACertificates", 18usize, |this| ((**(this as *const *const ICertSrvSetup_Vtbl)).GetExistingCACertificates)(this, ppval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CAImportPFX(this: *mut core::ffi::c_void, bstrfilename: core::mem::MaybeUninit<windows_core::BSTR>, bstrpasswd: core::mem::MaybeUninit<windows_core::BSTR>, boverwriteexistingkey: super::super::Foundation::VARIANT_BOOL, ppval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetup", "CAImportPFX", 19usize, |this| ((**(this as *const *const ICertSrvSetup_Vtbl)).CAImportPFX)(this, bstrfilename, bstrpasswd, boverwriteexistingkey, ppval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCADistinguishedName(this: *mut core::ffi::c_void, bstrcadn: core::mem::MaybeUninit<windows_core::BSTR>, bignoreunicode: super::super::Foundation::VARIANT_BOOL, boverwriteexistingkey: super::super::Foundation::VARIANT_BOOL, boverwriteexistingcainds: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetup", "SetCADistinguishedName", 20usize, |this| ((**(this as *const *const ICertSrvSetup_Vtbl)).SetCADistinguishedName)(this, bstrcadn, bignoreunicode, boverwriteexistingkey, boverwriteexistingcainds), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDatabaseInformation(this: *mut core::ffi::c_void, bstrdbdirectory: core::mem::MaybeUninit<windows_core::BSTR>, bstrlogdirectory: core::mem::MaybeUninit<windows_core::BSTR>, bstrsharedfolder: core::mem::MaybeUninit<windows_core::BSTR>, bforceoverwrite: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetup", "SetDatabaseInformation", 21usize, |this| ((**(this as *const *const ICertSrvSetup_Vtbl)).SetDatabaseInformation)(this, bstrdbdirectory, bstrlogdirectory, bstrsharedfolder, bforceoverwrite), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParentCAInformation(this: *mut core::ffi::c_void, bstrcaconfiguration: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetup", "SetParentCAInformation", 22usize, |this| ((**(this as *const *const ICertSrvSetup_Vtbl)).SetParentCAInformation)(this, bstrcaconfiguration), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWebCAInformation(this: *mut core::ffi::c_void, bstrcaconfiguration: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetup", "SetWebCAInformation", 23usize, |this| ((**(this as *const *const ICertSrvSetup_Vtbl)).SetWebCAInformation)(this, bstrcaconfiguration), |result| Some(*result))
+        }
+        unsafe extern "system" fn Install(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetup", "Install", 24usize, |this| ((**(this as *const *const ICertSrvSetup_Vtbl)).Install)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreUnInstall(this: *mut core::ffi::c_void, bclientonly: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetup", "PreUnInstall", 25usize, |this| ((**(this as *const *const ICertSrvSetup_Vtbl)).PreUnInstall)(this, bclientonly), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostUnInstall(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetup", "PostUnInstall", 26usize, |this| ((**(this as *const *const ICertSrvSetup_Vtbl)).PostUnInstall)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CAErrorId,
+            CAErrorString,
+            InitializeDefaults,
+            GetCASetupProperty,
+            SetCASetupProperty,
+            IsPropertyEditable,
+            GetSupportedCATypes,
+            GetProviderNameList,
+            GetKeyLengthList,
+            GetHashAlgorithmList,
+            GetPrivateKeyContainerList,
+            #[cfg(feature = "Win32_System_Com")]
+            GetExistingCACertificates,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetExistingCACertificates: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CAImportPFX,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CAImportPFX: 0,
+            SetCADistinguishedName,
+            SetDatabaseInformation,
+            SetParentCAInformation,
+            SetWebCAInformation,
+            Install,
+            PreUnInstall,
+            PostUnInstall,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertSrvSetup as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertSrvSetupKeyInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProviderName(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetupKeyInformation", "ProviderName", 7usize, |this| ((**(this as *const *const ICertSrvSetupKeyInformation_Vtbl)).ProviderName)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProviderName(this: *mut core::ffi::c_void, bstrval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetupKeyInformation", "SetProviderName", 8usize, |this| ((**(this as *const *const ICertSrvSetupKeyInformation_Vtbl)).SetProviderName)(this, bstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetupKeyInformation", "Length", 9usize, |this| ((**(this as *const *const ICertSrvSetupKeyInformation_Vtbl)).Length)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLength(this: *mut core::ffi::c_void, lval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetupKeyInformation", "SetLength", 10usize, |this| ((**(this as *const *const ICertSrvSetupKeyInformation_Vtbl)).SetLength)(this, lval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Existing(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetupKeyInformation", "Existing", 11usize, |this| ((**(this as *const *const ICertSrvSetupKeyInformation_Vtbl)).Existing)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExisting(this: *mut core::ffi::c_void, bval: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetupKeyInformation", "SetExisting", 12usize, |this| ((**(this as *const *const ICertSrvSetupKeyInformation_Vtbl)).SetExisting)(this, bval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContainerName(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetupKeyInformation", "ContainerName", 13usize, |this| ((**(this as *const *const ICertSrvSetupKeyInformation_Vtbl)).ContainerName)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContainerName(this: *mut core::ffi::c_void, bstrval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetupKeyInformation", "SetContainerName", 14usize, |this| ((**(this as *const *const ICertSrvSetupKeyInformation_Vtbl)).SetContainerName)(this, bstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn HashAlgorithm(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetupKeyInformation", "HashAlgorithm", 15usize, |this| ((**(this as *const *const ICertSrvSetupKeyInformation_Vtbl)).HashAlgorithm)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHashAlgorithm(this: *mut core::ffi::c_void, bstrval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetupKeyInformation", "SetHashAlgorithm", 16usize, |this| ((**(this as *const *const ICertSrvSetupKeyInformation_Vtbl)).SetHashAlgorithm)(this, bstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExistingCACertificate(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetupKeyInformation", "ExistingCACertificate", 17usize, |this| ((**(this as *const *const ICertSrvSetupKeyInformation_Vtbl)).ExistingCACertificate)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExistingCACertificate(this: *mut core::ffi::c_void, varval: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetupKeyInformation", "SetExistingCACertificate", 18usize, |this| ((**(this as *const *const ICertSrvSetupKeyInformation_Vtbl)).SetExistingCACertificate)(this, varval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProviderName,
+            SetProviderName,
+            Length,
+            SetLength,
+            Existing,
+            SetExisting,
+            ContainerName,
+            SetContainerName,
+            HashAlgorithm,
+            SetHashAlgorithm,
+            ExistingCACertificate,
+            SetExistingCACertificate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertSrvSetupKeyInformation as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertSrvSetupKeyInformationCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetupKeyInformationCollection", "_NewEnum", 7usize, |this| ((**(this as *const *const ICertSrvSetupKeyInformationCollection_Vtbl))._NewEnum)(this, ppval), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetupKeyInformationCollection", "get_Item", 8usize, |this| ((**(this as *const *const ICertSrvSetupKeyInformationCollection_Vtbl)).get_Item)(this, index, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetupKeyInformationCollection", "Count", 9usize, |this| ((**(this as *const *const ICertSrvSetupKeyInformationCollection_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pikeyinformation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertSrvSetupKeyInformationCollection", "Add", 10usize, |this| ((**(this as *const *const ICertSrvSetupKeyInformationCollection_Vtbl)).Add)(this, pikeyinformation), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            get_Item,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertSrvSetupKeyInformationCollection as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertificateEnrollmentPolicyServerSetup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ErrorString(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateEnrollmentPolicyServerSetup", "ErrorString", 7usize, |this| ((**(this as *const *const ICertificateEnrollmentPolicyServerSetup_Vtbl)).ErrorString)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeInstallDefaults(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateEnrollmentPolicyServerSetup", "InitializeInstallDefaults", 8usize, |this| ((**(this as *const *const ICertificateEnrollmentPolicyServerSetup_Vtbl)).InitializeInstallDefaults)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, propertyid: CEPSetupProperty, ppropertyvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateEnrollmentPolicyServerSetup", "GetProperty", 9usize, |this| ((**(this as *const *const ICertificateEnrollmentPolicyServerSetup_Vtbl)).GetProperty)(this, propertyid, ppropertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, propertyid: CEPSetupProperty, ppropertyvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateEnrollmentPolicyServerSetup", "SetProperty", 10usize, |this| ((**(this as *const *const ICertificateEnrollmentPolicyServerSetup_Vtbl)).SetProperty)(this, propertyid, ppropertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Install(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateEnrollmentPolicyServerSetup", "Install", 11usize, |this| ((**(this as *const *const ICertificateEnrollmentPolicyServerSetup_Vtbl)).Install)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnInstall(this: *mut core::ffi::c_void, pauthkeybasedrenewal: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateEnrollmentPolicyServerSetup", "UnInstall", 12usize, |this| ((**(this as *const *const ICertificateEnrollmentPolicyServerSetup_Vtbl)).UnInstall)(this, pauthkeybasedrenewal), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ErrorString,
+            InitializeInstallDefaults,
+            GetProperty,
+            SetProperty,
+            Install,
+            UnInstall,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertificateEnrollmentPolicyServerSetup as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICertificateEnrollmentServerSetup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ErrorString(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateEnrollmentServerSetup", "ErrorString", 7usize, |this| ((**(this as *const *const ICertificateEnrollmentServerSetup_Vtbl)).ErrorString)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeInstallDefaults(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateEnrollmentServerSetup", "InitializeInstallDefaults", 8usize, |this| ((**(this as *const *const ICertificateEnrollmentServerSetup_Vtbl)).InitializeInstallDefaults)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, propertyid: CESSetupProperty, ppropertyvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateEnrollmentServerSetup", "GetProperty", 9usize, |this| ((**(this as *const *const ICertificateEnrollmentServerSetup_Vtbl)).GetProperty)(this, propertyid, ppropertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, propertyid: CESSetupProperty, ppropertyvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateEnrollmentServerSetup", "SetProperty", 10usize, |this| ((**(this as *const *const ICertificateEnrollmentServerSetup_Vtbl)).SetProperty)(this, propertyid, ppropertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetApplicationPoolCredentials(this: *mut core::ffi::c_void, bstrusername: core::mem::MaybeUninit<windows_core::BSTR>, bstrpassword: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateEnrollmentServerSetup", "SetApplicationPoolCredentials", 11usize, |this| ((**(this as *const *const ICertificateEnrollmentServerSetup_Vtbl)).SetApplicationPoolCredentials)(this, bstrusername, bstrpassword), |result| Some(*result))
+        }
+        unsafe extern "system" fn Install(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateEnrollmentServerSetup", "Install", 12usize, |this| ((**(this as *const *const ICertificateEnrollmentServerSetup_Vtbl)).Install)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnInstall(this: *mut core::ffi::c_void, pcaconfig: *const core::mem::MaybeUninit<windows_core::VARIANT>, pauthentication: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICertificateEnrollmentServerSetup", "UnInstall", 13usize, |this| ((**(this as *const *const ICertificateEnrollmentServerSetup_Vtbl)).UnInstall)(this, pcaconfig, pauthentication), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ErrorString,
+            InitializeInstallDefaults,
+            GetProperty,
+            SetProperty,
+            SetApplicationPoolCredentials,
+            Install,
+            UnInstall,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICertificateEnrollmentServerSetup as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSCEPSetup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MSCEPErrorId(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSCEPSetup", "MSCEPErrorId", 7usize, |this| ((**(this as *const *const IMSCEPSetup_Vtbl)).MSCEPErrorId)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn MSCEPErrorString(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSCEPSetup", "MSCEPErrorString", 8usize, |this| ((**(this as *const *const IMSCEPSetup_Vtbl)).MSCEPErrorString)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeDefaults(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSCEPSetup", "InitializeDefaults", 9usize, |this| ((**(this as *const *const IMSCEPSetup_Vtbl)).InitializeDefaults)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMSCEPSetupProperty(this: *mut core::ffi::c_void, propertyid: MSCEPSetupProperty, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSCEPSetup", "GetMSCEPSetupProperty", 10usize, |this| ((**(this as *const *const IMSCEPSetup_Vtbl)).GetMSCEPSetupProperty)(this, propertyid, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMSCEPSetupProperty(this: *mut core::ffi::c_void, propertyid: MSCEPSetupProperty, ppropertyvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSCEPSetup", "SetMSCEPSetupProperty", 11usize, |this| ((**(this as *const *const IMSCEPSetup_Vtbl)).SetMSCEPSetupProperty)(this, propertyid, ppropertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAccountInformation(this: *mut core::ffi::c_void, bstrusername: core::mem::MaybeUninit<windows_core::BSTR>, bstrpassword: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSCEPSetup", "SetAccountInformation", 12usize, |this| ((**(this as *const *const IMSCEPSetup_Vtbl)).SetAccountInformation)(this, bstrusername, bstrpassword), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMSCEPStoreEmpty(this: *mut core::ffi::c_void, pbempty: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSCEPSetup", "IsMSCEPStoreEmpty", 13usize, |this| ((**(this as *const *const IMSCEPSetup_Vtbl)).IsMSCEPStoreEmpty)(this, pbempty), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProviderNameList(this: *mut core::ffi::c_void, bexchange: super::super::Foundation::VARIANT_BOOL, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSCEPSetup", "GetProviderNameList", 14usize, |this| ((**(this as *const *const IMSCEPSetup_Vtbl)).GetProviderNameList)(this, bexchange, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKeyLengthList(this: *mut core::ffi::c_void, bexchange: super::super::Foundation::VARIANT_BOOL, bstrprovidername: core::mem::MaybeUninit<windows_core::BSTR>, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSCEPSetup", "GetKeyLengthList", 15usize, |this| ((**(this as *const *const IMSCEPSetup_Vtbl)).GetKeyLengthList)(this, bexchange, bstrprovidername, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Install(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSCEPSetup", "Install", 16usize, |this| ((**(this as *const *const IMSCEPSetup_Vtbl)).Install)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreUnInstall(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSCEPSetup", "PreUnInstall", 17usize, |this| ((**(this as *const *const IMSCEPSetup_Vtbl)).PreUnInstall)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostUnInstall(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSCEPSetup", "PostUnInstall", 18usize, |this| ((**(this as *const *const IMSCEPSetup_Vtbl)).PostUnInstall)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MSCEPErrorId,
+            MSCEPErrorString,
+            InitializeDefaults,
+            GetMSCEPSetupProperty,
+            SetMSCEPSetupProperty,
+            SetAccountInformation,
+            IsMSCEPStoreEmpty,
+            GetProviderNameList,
+            GetKeyLengthList,
+            Install,
+            PreUnInstall,
+            PostUnInstall,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSCEPSetup as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -280,6 +280,7 @@ pub struct ENTERPRISE_DATA_POLICIES(pub i32);
 impl windows_core::TypeKind for ENTERPRISE_DATA_POLICIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ENTERPRISE_DATA_POLICIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ENTERPRISE_DATA_POLICIES").field(&self.0).finish()
@@ -324,6 +325,7 @@ pub struct SRPHOSTING_TYPE(pub i32);
 impl windows_core::TypeKind for SRPHOSTING_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SRPHOSTING_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SRPHOSTING_TYPE").field(&self.0).finish()
@@ -335,13 +337,15 @@ pub struct SRPHOSTING_VERSION(pub i32);
 impl windows_core::TypeKind for SRPHOSTING_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SRPHOSTING_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SRPHOSTING_VERSION").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILE_UNPROTECT_OPTIONS {
     pub audit: u8,
 }
@@ -354,7 +358,8 @@ impl Default for FILE_UNPROTECT_OPTIONS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HTHREAD_NETWORK_CONTEXT {
     pub ThreadId: u32,
     pub ThreadContext: super::super::Foundation::HANDLE,
@@ -369,3 +374,5 @@ impl Default for HTHREAD_NETWORK_CONTEXT {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
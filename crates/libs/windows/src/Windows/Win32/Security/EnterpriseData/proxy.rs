@@ -0,0 +1,81 @@
+impl windows_core::imp::ProxyVtable for IProtectionPolicyManagerInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestAccessForWindowAsync(this: *mut core::ffi::c_void, appwindow: super::super::Foundation::HWND, sourceidentity: core::mem::MaybeUninit<windows_core::HSTRING>, targetidentity: core::mem::MaybeUninit<windows_core::HSTRING>, riid: *const windows_core::GUID, asyncoperation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerInterop", "RequestAccessForWindowAsync", 6usize, |this| ((**(this as *const *const IProtectionPolicyManagerInterop_Vtbl)).RequestAccessForWindowAsync)(this, appwindow, sourceidentity, targetidentity, riid, asyncoperation), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForWindow(this: *mut core::ffi::c_void, appwindow: super::super::Foundation::HWND, riid: *const windows_core::GUID, result: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerInterop", "GetForWindow", 7usize, |this| ((**(this as *const *const IProtectionPolicyManagerInterop_Vtbl)).GetForWindow)(this, appwindow, riid, result), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestAccessForWindowAsync, GetForWindow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProtectionPolicyManagerInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProtectionPolicyManagerInterop2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestAccessForAppWithWindowAsync(this: *mut core::ffi::c_void, appwindow: super::super::Foundation::HWND, sourceidentity: core::mem::MaybeUninit<windows_core::HSTRING>, apppackagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, riid: *const windows_core::GUID, asyncoperation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerInterop2", "RequestAccessForAppWithWindowAsync", 6usize, |this| ((**(this as *const *const IProtectionPolicyManagerInterop2_Vtbl)).RequestAccessForAppWithWindowAsync)(this, appwindow, sourceidentity, apppackagefamilyname, riid, asyncoperation), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAccessWithAuditingInfoForWindowAsync(this: *mut core::ffi::c_void, appwindow: super::super::Foundation::HWND, sourceidentity: core::mem::MaybeUninit<windows_core::HSTRING>, targetidentity: core::mem::MaybeUninit<windows_core::HSTRING>, auditinfounk: *mut core::ffi::c_void, riid: *const windows_core::GUID, asyncoperation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerInterop2", "RequestAccessWithAuditingInfoForWindowAsync", 7usize, |this| ((**(this as *const *const IProtectionPolicyManagerInterop2_Vtbl)).RequestAccessWithAuditingInfoForWindowAsync)(this, appwindow, sourceidentity, targetidentity, auditinfounk, riid, asyncoperation), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAccessWithMessageForWindowAsync(this: *mut core::ffi::c_void, appwindow: super::super::Foundation::HWND, sourceidentity: core::mem::MaybeUninit<windows_core::HSTRING>, targetidentity: core::mem::MaybeUninit<windows_core::HSTRING>, auditinfounk: *mut core::ffi::c_void, messagefromapp: core::mem::MaybeUninit<windows_core::HSTRING>, riid: *const windows_core::GUID, asyncoperation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerInterop2", "RequestAccessWithMessageForWindowAsync", 8usize, |this| ((**(this as *const *const IProtectionPolicyManagerInterop2_Vtbl)).RequestAccessWithMessageForWindowAsync)(this, appwindow, sourceidentity, targetidentity, auditinfounk, messagefromapp, riid, asyncoperation), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAccessForAppWithAuditingInfoForWindowAsync(this: *mut core::ffi::c_void, appwindow: super::super::Foundation::HWND, sourceidentity: core::mem::MaybeUninit<windows_core::HSTRING>, apppackagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, auditinfounk: *mut core::ffi::c_void, riid: *const windows_core::GUID, asyncoperation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerInterop2", "RequestAccessForAppWithAuditingInfoForWindowAsync", 9usize, |this| ((**(this as *const *const IProtectionPolicyManagerInterop2_Vtbl)).RequestAccessForAppWithAuditingInfoForWindowAsync)(this, appwindow, sourceidentity, apppackagefamilyname, auditinfounk, riid, asyncoperation), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAccessForAppWithMessageForWindowAsync(this: *mut core::ffi::c_void, appwindow: super::super::Foundation::HWND, sourceidentity: core::mem::MaybeUninit<windows_core::HSTRING>, apppackagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, auditinfounk: *mut core::ffi::c_void, messagefromapp: core::mem::MaybeUninit<windows_core::HSTRING>, riid: *const windows_core::GUID, asyncoperation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerInterop2", "RequestAccessForAppWithMessageForWindowAsync", 10usize, |this| ((**(this as *const *const IProtectionPolicyManagerInterop2_Vtbl)).RequestAccessForAppWithMessageForWindowAsync)(this, appwindow, sourceidentity, apppackagefamilyname, auditinfounk, messagefromapp, riid, asyncoperation), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestAccessForAppWithWindowAsync,
+            RequestAccessWithAuditingInfoForWindowAsync,
+            RequestAccessWithMessageForWindowAsync,
+            RequestAccessForAppWithAuditingInfoForWindowAsync,
+            RequestAccessForAppWithMessageForWindowAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProtectionPolicyManagerInterop2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProtectionPolicyManagerInterop3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestAccessWithBehaviorForWindowAsync(this: *mut core::ffi::c_void, appwindow: super::super::Foundation::HWND, sourceidentity: core::mem::MaybeUninit<windows_core::HSTRING>, targetidentity: core::mem::MaybeUninit<windows_core::HSTRING>, auditinfounk: *mut core::ffi::c_void, messagefromapp: core::mem::MaybeUninit<windows_core::HSTRING>, behavior: u32, riid: *const windows_core::GUID, asyncoperation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerInterop3", "RequestAccessWithBehaviorForWindowAsync", 6usize, |this| ((**(this as *const *const IProtectionPolicyManagerInterop3_Vtbl)).RequestAccessWithBehaviorForWindowAsync)(this, appwindow, sourceidentity, targetidentity, auditinfounk, messagefromapp, behavior, riid, asyncoperation), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAccessForAppWithBehaviorForWindowAsync(this: *mut core::ffi::c_void, appwindow: super::super::Foundation::HWND, sourceidentity: core::mem::MaybeUninit<windows_core::HSTRING>, apppackagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, auditinfounk: *mut core::ffi::c_void, messagefromapp: core::mem::MaybeUninit<windows_core::HSTRING>, behavior: u32, riid: *const windows_core::GUID, asyncoperation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerInterop3", "RequestAccessForAppWithBehaviorForWindowAsync", 7usize, |this| ((**(this as *const *const IProtectionPolicyManagerInterop3_Vtbl)).RequestAccessForAppWithBehaviorForWindowAsync)(this, appwindow, sourceidentity, apppackagefamilyname, auditinfounk, messagefromapp, behavior, riid, asyncoperation), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAccessToFilesForAppForWindowAsync(this: *mut core::ffi::c_void, appwindow: super::super::Foundation::HWND, sourceitemlistunk: *mut core::ffi::c_void, apppackagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, auditinfounk: *mut core::ffi::c_void, riid: *const windows_core::GUID, asyncoperation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerInterop3", "RequestAccessToFilesForAppForWindowAsync", 8usize, |this| ((**(this as *const *const IProtectionPolicyManagerInterop3_Vtbl)).RequestAccessToFilesForAppForWindowAsync)(this, appwindow, sourceitemlistunk, apppackagefamilyname, auditinfounk, riid, asyncoperation), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAccessToFilesForAppWithMessageAndBehaviorForWindowAsync(this: *mut core::ffi::c_void, appwindow: super::super::Foundation::HWND, sourceitemlistunk: *mut core::ffi::c_void, apppackagefamilyname: core::mem::MaybeUninit<windows_core::HSTRING>, auditinfounk: *mut core::ffi::c_void, messagefromapp: core::mem::MaybeUninit<windows_core::HSTRING>, behavior: u32, riid: *const windows_core::GUID, asyncoperation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerInterop3", "RequestAccessToFilesForAppWithMessageAndBehaviorForWindowAsync", 9usize, |this| ((**(this as *const *const IProtectionPolicyManagerInterop3_Vtbl)).RequestAccessToFilesForAppWithMessageAndBehaviorForWindowAsync)(this, appwindow, sourceitemlistunk, apppackagefamilyname, auditinfounk, messagefromapp, behavior, riid, asyncoperation), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAccessToFilesForProcessForWindowAsync(this: *mut core::ffi::c_void, appwindow: super::super::Foundation::HWND, sourceitemlistunk: *mut core::ffi::c_void, processid: u32, auditinfounk: *mut core::ffi::c_void, riid: *const windows_core::GUID, asyncoperation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerInterop3", "RequestAccessToFilesForProcessForWindowAsync", 10usize, |this| ((**(this as *const *const IProtectionPolicyManagerInterop3_Vtbl)).RequestAccessToFilesForProcessForWindowAsync)(this, appwindow, sourceitemlistunk, processid, auditinfounk, riid, asyncoperation), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAccessToFilesForProcessWithMessageAndBehaviorForWindowAsync(this: *mut core::ffi::c_void, appwindow: super::super::Foundation::HWND, sourceitemlistunk: *mut core::ffi::c_void, processid: u32, auditinfounk: *mut core::ffi::c_void, messagefromapp: core::mem::MaybeUninit<windows_core::HSTRING>, behavior: u32, riid: *const windows_core::GUID, asyncoperation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectionPolicyManagerInterop3", "RequestAccessToFilesForProcessWithMessageAndBehaviorForWindowAsync", 11usize, |this| ((**(this as *const *const IProtectionPolicyManagerInterop3_Vtbl)).RequestAccessToFilesForProcessWithMessageAndBehaviorForWindowAsync)(this, appwindow, sourceitemlistunk, processid, auditinfounk, messagefromapp, behavior, riid, asyncoperation), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestAccessWithBehaviorForWindowAsync,
+            RequestAccessForAppWithBehaviorForWindowAsync,
+            RequestAccessToFilesForAppForWindowAsync,
+            RequestAccessToFilesForAppWithMessageAndBehaviorForWindowAsync,
+            RequestAccessToFilesForProcessForWindowAsync,
+            RequestAccessToFilesForProcessWithMessageAndBehaviorForWindowAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProtectionPolicyManagerInterop3 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -0,0 +1,121 @@
+impl windows_core::imp::ProxyVtable for IAccountingProviderConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pszmachinename: windows_core::PCWSTR, puconnectionparam: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccountingProviderConfig", "Initialize", 3usize, |this| ((**(this as *const *const IAccountingProviderConfig_Vtbl)).Initialize)(this, pszmachinename, puconnectionparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uninitialize(this: *mut core::ffi::c_void, uconnectionparam: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccountingProviderConfig", "Uninitialize", 4usize, |this| ((**(this as *const *const IAccountingProviderConfig_Vtbl)).Uninitialize)(this, uconnectionparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn Configure(this: *mut core::ffi::c_void, uconnectionparam: usize, hwnd: super::super::Foundation::HWND, dwflags: u32, ureserved1: usize, ureserved2: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccountingProviderConfig", "Configure", 5usize, |this| ((**(this as *const *const IAccountingProviderConfig_Vtbl)).Configure)(this, uconnectionparam, hwnd, dwflags, ureserved1, ureserved2), |result| Some(*result))
+        }
+        unsafe extern "system" fn Activate(this: *mut core::ffi::c_void, uconnectionparam: usize, ureserved1: usize, ureserved2: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccountingProviderConfig", "Activate", 6usize, |this| ((**(this as *const *const IAccountingProviderConfig_Vtbl)).Activate)(this, uconnectionparam, ureserved1, ureserved2), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deactivate(this: *mut core::ffi::c_void, uconnectionparam: usize, ureserved1: usize, ureserved2: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccountingProviderConfig", "Deactivate", 7usize, |this| ((**(this as *const *const IAccountingProviderConfig_Vtbl)).Deactivate)(this, uconnectionparam, ureserved1, ureserved2), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, Uninitialize, Configure, Activate, Deactivate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccountingProviderConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAuthenticationProviderConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pszmachinename: windows_core::PCWSTR, puconnectionparam: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAuthenticationProviderConfig", "Initialize", 3usize, |this| ((**(this as *const *const IAuthenticationProviderConfig_Vtbl)).Initialize)(this, pszmachinename, puconnectionparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uninitialize(this: *mut core::ffi::c_void, uconnectionparam: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAuthenticationProviderConfig", "Uninitialize", 4usize, |this| ((**(this as *const *const IAuthenticationProviderConfig_Vtbl)).Uninitialize)(this, uconnectionparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn Configure(this: *mut core::ffi::c_void, uconnectionparam: usize, hwnd: super::super::Foundation::HWND, dwflags: u32, ureserved1: usize, ureserved2: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAuthenticationProviderConfig", "Configure", 5usize, |this| ((**(this as *const *const IAuthenticationProviderConfig_Vtbl)).Configure)(this, uconnectionparam, hwnd, dwflags, ureserved1, ureserved2), |result| Some(*result))
+        }
+        unsafe extern "system" fn Activate(this: *mut core::ffi::c_void, uconnectionparam: usize, ureserved1: usize, ureserved2: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAuthenticationProviderConfig", "Activate", 6usize, |this| ((**(this as *const *const IAuthenticationProviderConfig_Vtbl)).Activate)(this, uconnectionparam, ureserved1, ureserved2), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deactivate(this: *mut core::ffi::c_void, uconnectionparam: usize, ureserved1: usize, ureserved2: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAuthenticationProviderConfig", "Deactivate", 7usize, |this| ((**(this as *const *const IAuthenticationProviderConfig_Vtbl)).Deactivate)(this, uconnectionparam, ureserved1, ureserved2), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, Uninitialize, Configure, Activate, Deactivate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAuthenticationProviderConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEAPProviderConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pszmachinename: windows_core::PCWSTR, dweaptypeid: u32, puconnectionparam: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEAPProviderConfig", "Initialize", 3usize, |this| ((**(this as *const *const IEAPProviderConfig_Vtbl)).Initialize)(this, pszmachinename, dweaptypeid, puconnectionparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uninitialize(this: *mut core::ffi::c_void, dweaptypeid: u32, uconnectionparam: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEAPProviderConfig", "Uninitialize", 4usize, |this| ((**(this as *const *const IEAPProviderConfig_Vtbl)).Uninitialize)(this, dweaptypeid, uconnectionparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServerInvokeConfigUI(this: *mut core::ffi::c_void, dweaptypeid: u32, uconnectionparam: usize, hwnd: super::super::Foundation::HWND, ureserved1: usize, ureserved2: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEAPProviderConfig", "ServerInvokeConfigUI", 5usize, |this| ((**(this as *const *const IEAPProviderConfig_Vtbl)).ServerInvokeConfigUI)(this, dweaptypeid, uconnectionparam, hwnd, ureserved1, ureserved2), |result| Some(*result))
+        }
+        unsafe extern "system" fn RouterInvokeConfigUI(this: *mut core::ffi::c_void, dweaptypeid: u32, uconnectionparam: usize, hwndparent: super::super::Foundation::HWND, dwflags: u32, pconnectiondatain: *const u8, dwsizeofconnectiondatain: u32, ppconnectiondataout: *mut *mut u8, pdwsizeofconnectiondataout: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEAPProviderConfig", "RouterInvokeConfigUI", 6usize, |this| ((**(this as *const *const IEAPProviderConfig_Vtbl)).RouterInvokeConfigUI)(this, dweaptypeid, uconnectionparam, hwndparent, dwflags, pconnectiondatain, dwsizeofconnectiondatain, ppconnectiondataout, pdwsizeofconnectiondataout), |result| Some(*result))
+        }
+        unsafe extern "system" fn RouterInvokeCredentialsUI(this: *mut core::ffi::c_void, dweaptypeid: u32, uconnectionparam: usize, hwndparent: super::super::Foundation::HWND, dwflags: u32, pconnectiondatain: *const u8, dwsizeofconnectiondatain: u32, puserdatain: *const u8, dwsizeofuserdatain: u32, ppuserdataout: *mut *mut u8, pdwsizeofuserdataout: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEAPProviderConfig", "RouterInvokeCredentialsUI", 7usize, |this| ((**(this as *const *const IEAPProviderConfig_Vtbl)).RouterInvokeCredentialsUI)(this, dweaptypeid, uconnectionparam, hwndparent, dwflags, pconnectiondatain, dwsizeofconnectiondatain, puserdatain, dwsizeofuserdatain, ppuserdataout, pdwsizeofuserdataout), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            Uninitialize,
+            ServerInvokeConfigUI,
+            RouterInvokeConfigUI,
+            RouterInvokeCredentialsUI,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEAPProviderConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEAPProviderConfig2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ServerInvokeConfigUI2(this: *mut core::ffi::c_void, dweaptypeid: u32, uconnectionparam: usize, hwnd: super::super::Foundation::HWND, pconfigdatain: *const u8, dwsizeofconfigdatain: u32, ppconfigdataout: *mut *mut u8, pdwsizeofconfigdataout: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEAPProviderConfig2", "ServerInvokeConfigUI2", 8usize, |this| ((**(this as *const *const IEAPProviderConfig2_Vtbl)).ServerInvokeConfigUI2)(this, dweaptypeid, uconnectionparam, hwnd, pconfigdatain, dwsizeofconfigdatain, ppconfigdataout, pdwsizeofconfigdataout), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGlobalConfig(this: *mut core::ffi::c_void, dweaptypeid: u32, ppconfigdataout: *mut *mut u8, pdwsizeofconfigdataout: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEAPProviderConfig2", "GetGlobalConfig", 9usize, |this| ((**(this as *const *const IEAPProviderConfig2_Vtbl)).GetGlobalConfig)(this, dweaptypeid, ppconfigdataout, pdwsizeofconfigdataout), |result| Some(*result))
+        }
+        Self { base__: <IEAPProviderConfig_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ServerInvokeConfigUI2, GetGlobalConfig }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEAPProviderConfig2 as windows_core::Interface>::IID || <IEAPProviderConfig_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEAPProviderConfig3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ServerInvokeCertificateConfigUI(this: *mut core::ffi::c_void, dweaptypeid: u32, uconnectionparam: usize, hwnd: super::super::Foundation::HWND, pconfigdatain: *const u8, dwsizeofconfigdatain: u32, ppconfigdataout: *mut *mut u8, pdwsizeofconfigdataout: *mut u32, ureserved: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEAPProviderConfig3", "ServerInvokeCertificateConfigUI", 10usize, |this| ((**(this as *const *const IEAPProviderConfig3_Vtbl)).ServerInvokeCertificateConfigUI)(this, dweaptypeid, uconnectionparam, hwnd, pconfigdatain, dwsizeofconfigdatain, ppconfigdataout, pdwsizeofconfigdataout, ureserved), |result| Some(*result))
+        }
+        Self { base__: <IEAPProviderConfig2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ServerInvokeCertificateConfigUI }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEAPProviderConfig3 as windows_core::Interface>::IID || <IEAPProviderConfig2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRouterProtocolConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddProtocol(this: *mut core::ffi::c_void, pszmachinename: windows_core::PCWSTR, dwtransportid: u32, dwprotocolid: u32, hwnd: super::super::Foundation::HWND, dwflags: u32, prouter: *mut core::ffi::c_void, ureserved1: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRouterProtocolConfig", "AddProtocol", 3usize, |this| ((**(this as *const *const IRouterProtocolConfig_Vtbl)).AddProtocol)(this, pszmachinename, dwtransportid, dwprotocolid, hwnd, dwflags, prouter, ureserved1), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveProtocol(this: *mut core::ffi::c_void, pszmachinename: windows_core::PCWSTR, dwtransportid: u32, dwprotocolid: u32, hwnd: super::super::Foundation::HWND, dwflags: u32, prouter: *mut core::ffi::c_void, ureserved1: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRouterProtocolConfig", "RemoveProtocol", 4usize, |this| ((**(this as *const *const IRouterProtocolConfig_Vtbl)).RemoveProtocol)(this, pszmachinename, dwtransportid, dwprotocolid, hwnd, dwflags, prouter, ureserved1), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddProtocol, RemoveProtocol }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRouterProtocolConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -0,0 +1,55 @@
+impl windows_core::imp::ProxyVtable for IIsolatedAppLauncher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Launch(this: *mut core::ffi::c_void, appusermodelid: windows_core::PCWSTR, arguments: windows_core::PCWSTR, telemetryparameters: *const IsolatedAppLauncherTelemetryParameters) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedAppLauncher", "Launch", 3usize, |this| ((**(this as *const *const IIsolatedAppLauncher_Vtbl)).Launch)(this, appusermodelid, arguments, telemetryparameters), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Launch }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedAppLauncher as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIsolatedProcessLauncher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LaunchProcess(this: *mut core::ffi::c_void, process: windows_core::PCWSTR, arguments: windows_core::PCWSTR, workingdirectory: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedProcessLauncher", "LaunchProcess", 3usize, |this| ((**(this as *const *const IIsolatedProcessLauncher_Vtbl)).LaunchProcess)(this, process, arguments, workingdirectory), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShareDirectory(this: *mut core::ffi::c_void, hostpath: windows_core::PCWSTR, containerpath: windows_core::PCWSTR, readonly: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedProcessLauncher", "ShareDirectory", 4usize, |this| ((**(this as *const *const IIsolatedProcessLauncher_Vtbl)).ShareDirectory)(this, hostpath, containerpath, readonly), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContainerGuid(this: *mut core::ffi::c_void, guid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedProcessLauncher", "GetContainerGuid", 5usize, |this| ((**(this as *const *const IIsolatedProcessLauncher_Vtbl)).GetContainerGuid)(this, guid), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowSetForegroundAccess(this: *mut core::ffi::c_void, pid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedProcessLauncher", "AllowSetForegroundAccess", 6usize, |this| ((**(this as *const *const IIsolatedProcessLauncher_Vtbl)).AllowSetForegroundAccess)(this, pid), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsContainerRunning(this: *mut core::ffi::c_void, running: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedProcessLauncher", "IsContainerRunning", 7usize, |this| ((**(this as *const *const IIsolatedProcessLauncher_Vtbl)).IsContainerRunning)(this, running), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LaunchProcess,
+            ShareDirectory,
+            GetContainerGuid,
+            AllowSetForegroundAccess,
+            IsContainerRunning,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedProcessLauncher as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IIsolatedProcessLauncher2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LaunchProcess2(this: *mut core::ffi::c_void, process: windows_core::PCWSTR, arguments: windows_core::PCWSTR, workingdirectory: windows_core::PCWSTR, correlationguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedProcessLauncher2", "LaunchProcess2", 8usize, |this| ((**(this as *const *const IIsolatedProcessLauncher2_Vtbl)).LaunchProcess2)(this, process, arguments, workingdirectory, correlationguid), |result| Some(*result))
+        }
+        Self { base__: <IIsolatedProcessLauncher_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LaunchProcess2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedProcessLauncher2 as windows_core::Interface>::IID || <IIsolatedProcessLauncher_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -26,6 +26,7 @@ pub struct LicenseProtectionStatus(pub i32);
 impl windows_core::TypeKind for LicenseProtectionStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LicenseProtectionStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LicenseProtectionStatus").field(&self.0).finish()
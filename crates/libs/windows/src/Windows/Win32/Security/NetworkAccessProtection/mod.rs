@@ -44,6 +44,7 @@ pub struct ExtendedIsolationState(pub i32);
 impl windows_core::TypeKind for ExtendedIsolationState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ExtendedIsolationState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ExtendedIsolationState").field(&self.0).finish()
@@ -55,6 +56,7 @@ pub struct FailureCategory(pub i32);
 impl windows_core::TypeKind for FailureCategory {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FailureCategory {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FailureCategory").field(&self.0).finish()
@@ -66,6 +68,7 @@ pub struct FixupState(pub i32);
 impl windows_core::TypeKind for FixupState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FixupState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FixupState").field(&self.0).finish()
@@ -77,6 +80,7 @@ pub struct IsolationState(pub i32);
 impl windows_core::TypeKind for IsolationState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IsolationState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IsolationState").field(&self.0).finish()
@@ -88,6 +92,7 @@ pub struct NapNotifyType(pub i32);
 impl windows_core::TypeKind for NapNotifyType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NapNotifyType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NapNotifyType").field(&self.0).finish()
@@ -99,6 +104,7 @@ pub struct NapTracingLevel(pub i32);
 impl windows_core::TypeKind for NapTracingLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NapTracingLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NapTracingLevel").field(&self.0).finish()
@@ -110,13 +116,15 @@ pub struct RemoteConfigurationType(pub i32);
 impl windows_core::TypeKind for RemoteConfigurationType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RemoteConfigurationType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RemoteConfigurationType").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CorrelationId {
     pub connId: windows_core::GUID,
     pub timeStamp: super::super::Foundation::FILETIME,
@@ -130,7 +138,8 @@ impl Default for CorrelationId {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CountedString {
     pub length: u16,
     pub string: windows_core::PWSTR,
@@ -144,7 +153,8 @@ impl Default for CountedString {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FailureCategoryMapping {
     pub mappingCompliance: [super::super::Foundation::BOOL; 5],
 }
@@ -157,7 +167,8 @@ impl Default for FailureCategoryMapping {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FixupInfo {
     pub state: FixupState,
     pub percentage: u8,
@@ -173,7 +184,8 @@ impl Default for FixupInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Ipv4Address {
     pub addr: [u8; 4],
 }
@@ -186,7 +198,8 @@ impl Default for Ipv4Address {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct Ipv6Address {
     pub addr: [u8; 16],
 }
@@ -199,7 +212,8 @@ impl Default for Ipv6Address {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IsolationInfo {
     pub isolationState: IsolationState,
     pub probEndTime: super::super::Foundation::FILETIME,
@@ -214,7 +228,8 @@ impl Default for IsolationInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IsolationInfoEx {
     pub isolationState: IsolationState,
     pub extendedIsolationState: ExtendedIsolationState,
@@ -230,7 +245,8 @@ impl Default for IsolationInfoEx {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NapComponentRegistrationInfo {
     pub id: u32,
     pub friendlyName: CountedString,
@@ -251,7 +267,8 @@ impl Default for NapComponentRegistrationInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NetworkSoH {
     pub size: u16,
     pub data: *mut u8,
@@ -265,7 +282,8 @@ impl Default for NetworkSoH {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PrivateData {
     pub size: u16,
     pub data: *mut u8,
@@ -279,7 +297,8 @@ impl Default for PrivateData {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ResultCodes {
     pub count: u16,
     pub results: *mut windows_core::HRESULT,
@@ -293,7 +312,8 @@ impl Default for ResultCodes {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SoH {
     pub count: u16,
     pub attributes: *mut SoHAttribute,
@@ -307,7 +327,8 @@ impl Default for SoH {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SoHAttribute {
     pub r#type: u16,
     pub size: u16,
@@ -322,7 +343,8 @@ impl Default for SoHAttribute {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SystemHealthAgentState {
     pub id: u32,
     pub shaResultCodes: ResultCodes,
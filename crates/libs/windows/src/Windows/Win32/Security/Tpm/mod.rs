@@ -180,6 +180,7 @@ pub struct TPMVSCMGR_ERROR(pub i32);
 impl windows_core::TypeKind for TPMVSCMGR_ERROR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TPMVSCMGR_ERROR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TPMVSCMGR_ERROR").field(&self.0).finish()
@@ -191,6 +192,7 @@ pub struct TPMVSCMGR_STATUS(pub i32);
 impl windows_core::TypeKind for TPMVSCMGR_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TPMVSCMGR_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TPMVSCMGR_STATUS").field(&self.0).finish()
@@ -202,6 +204,7 @@ pub struct TPMVSC_ATTESTATION_TYPE(pub i32);
 impl windows_core::TypeKind for TPMVSC_ATTESTATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TPMVSC_ATTESTATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TPMVSC_ATTESTATION_TYPE").field(&self.0).finish()
@@ -211,3 +214,5 @@ pub const RemoteTpmVirtualSmartCardManager: windows_core::GUID = windows_core::G
 pub const TpmVirtualSmartCardManager: windows_core::GUID = windows_core::GUID::from_u128(0x16a18e86_7f6e_4c20_ad89_4ffc0db7a96a);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
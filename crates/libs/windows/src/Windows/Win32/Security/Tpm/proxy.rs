@@ -0,0 +1,54 @@
+impl windows_core::imp::ProxyVtable for ITpmVirtualSmartCardManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateVirtualSmartCard(this: *mut core::ffi::c_void, pszfriendlyname: windows_core::PCWSTR, badminalgid: u8, pbadminkey: *const u8, cbadminkey: u32, pbadminkcv: *const u8, cbadminkcv: u32, pbpuk: *const u8, cbpuk: u32, pbpin: *const u8, cbpin: u32, fgenerate: super::super::Foundation::BOOL, pstatuscallback: *mut core::ffi::c_void, ppszinstanceid: *mut windows_core::PWSTR, pfneedreboot: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITpmVirtualSmartCardManager", "CreateVirtualSmartCard", 3usize, |this| ((**(this as *const *const ITpmVirtualSmartCardManager_Vtbl)).CreateVirtualSmartCard)(this, pszfriendlyname, badminalgid, pbadminkey, cbadminkey, pbadminkcv, cbadminkcv, pbpuk, cbpuk, pbpin, cbpin, fgenerate, pstatuscallback, ppszinstanceid, pfneedreboot), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestroyVirtualSmartCard(this: *mut core::ffi::c_void, pszinstanceid: windows_core::PCWSTR, pstatuscallback: *mut core::ffi::c_void, pfneedreboot: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITpmVirtualSmartCardManager", "DestroyVirtualSmartCard", 4usize, |this| ((**(this as *const *const ITpmVirtualSmartCardManager_Vtbl)).DestroyVirtualSmartCard)(this, pszinstanceid, pstatuscallback, pfneedreboot), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateVirtualSmartCard, DestroyVirtualSmartCard }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITpmVirtualSmartCardManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITpmVirtualSmartCardManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateVirtualSmartCardWithPinPolicy(this: *mut core::ffi::c_void, pszfriendlyname: windows_core::PCWSTR, badminalgid: u8, pbadminkey: *const u8, cbadminkey: u32, pbadminkcv: *const u8, cbadminkcv: u32, pbpuk: *const u8, cbpuk: u32, pbpin: *const u8, cbpin: u32, pbpinpolicy: *const u8, cbpinpolicy: u32, fgenerate: super::super::Foundation::BOOL, pstatuscallback: *mut core::ffi::c_void, ppszinstanceid: *mut windows_core::PWSTR, pfneedreboot: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITpmVirtualSmartCardManager2", "CreateVirtualSmartCardWithPinPolicy", 5usize, |this| ((**(this as *const *const ITpmVirtualSmartCardManager2_Vtbl)).CreateVirtualSmartCardWithPinPolicy)(this, pszfriendlyname, badminalgid, pbadminkey, cbadminkey, pbadminkcv, cbadminkcv, pbpuk, cbpuk, pbpin, cbpin, pbpinpolicy, cbpinpolicy, fgenerate, pstatuscallback, ppszinstanceid, pfneedreboot), |result| Some(*result))
+        }
+        Self { base__: <ITpmVirtualSmartCardManager_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateVirtualSmartCardWithPinPolicy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITpmVirtualSmartCardManager2 as windows_core::Interface>::IID || <ITpmVirtualSmartCardManager_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITpmVirtualSmartCardManager3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateVirtualSmartCardWithAttestation(this: *mut core::ffi::c_void, pszfriendlyname: windows_core::PCWSTR, badminalgid: u8, pbadminkey: *const u8, cbadminkey: u32, pbadminkcv: *const u8, cbadminkcv: u32, pbpuk: *const u8, cbpuk: u32, pbpin: *const u8, cbpin: u32, pbpinpolicy: *const u8, cbpinpolicy: u32, attestationtype: TPMVSC_ATTESTATION_TYPE, fgenerate: super::super::Foundation::BOOL, pstatuscallback: *mut core::ffi::c_void, ppszinstanceid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITpmVirtualSmartCardManager3", "CreateVirtualSmartCardWithAttestation", 6usize, |this| ((**(this as *const *const ITpmVirtualSmartCardManager3_Vtbl)).CreateVirtualSmartCardWithAttestation)(this, pszfriendlyname, badminalgid, pbadminkey, cbadminkey, pbadminkcv, cbadminkcv, pbpuk, cbpuk, pbpin, cbpin, pbpinpolicy, cbpinpolicy, attestationtype, fgenerate, pstatuscallback, ppszinstanceid), |result| Some(*result))
+        }
+        Self { base__: <ITpmVirtualSmartCardManager2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateVirtualSmartCardWithAttestation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITpmVirtualSmartCardManager3 as windows_core::Interface>::IID || <ITpmVirtualSmartCardManager2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITpmVirtualSmartCardManagerStatusCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportProgress(this: *mut core::ffi::c_void, status: TPMVSCMGR_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITpmVirtualSmartCardManagerStatusCallback", "ReportProgress", 3usize, |this| ((**(this as *const *const ITpmVirtualSmartCardManagerStatusCallback_Vtbl)).ReportProgress)(this, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportError(this: *mut core::ffi::c_void, error: TPMVSCMGR_ERROR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITpmVirtualSmartCardManagerStatusCallback", "ReportError", 4usize, |this| ((**(this as *const *const ITpmVirtualSmartCardManagerStatusCallback_Vtbl)).ReportError)(this, error), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReportProgress, ReportError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITpmVirtualSmartCardManagerStatusCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -136,6 +136,7 @@ pub struct FCIERROR(pub i32);
 impl windows_core::TypeKind for FCIERROR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FCIERROR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FCIERROR").field(&self.0).finish()
@@ -147,6 +148,7 @@ pub struct FDICREATE_CPU_TYPE(pub i32);
 impl windows_core::TypeKind for FDICREATE_CPU_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FDICREATE_CPU_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FDICREATE_CPU_TYPE").field(&self.0).finish()
@@ -158,6 +160,7 @@ pub struct FDIDECRYPTTYPE(pub i32);
 impl windows_core::TypeKind for FDIDECRYPTTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FDIDECRYPTTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FDIDECRYPTTYPE").field(&self.0).finish()
@@ -169,6 +172,7 @@ pub struct FDIERROR(pub i32);
 impl windows_core::TypeKind for FDIERROR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FDIERROR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FDIERROR").field(&self.0).finish()
@@ -180,13 +184,15 @@ pub struct FDINOTIFICATIONTYPE(pub i32);
 impl windows_core::TypeKind for FDINOTIFICATIONTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FDINOTIFICATIONTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FDINOTIFICATIONTYPE").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CCAB {
     pub cb: u32,
     pub cbFolderThresh: u32,
@@ -210,7 +216,8 @@ impl Default for CCAB {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ERF {
     pub erfOper: i32,
     pub erfType: i32,
@@ -225,7 +232,8 @@ impl Default for ERF {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FDICABINETINFO {
     pub cbCabinet: i32,
     pub cFolders: u16,
@@ -275,7 +283,8 @@ impl Default for FDIDECRYPT_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FDIDECRYPT_0_0 {
     pub pHeaderReserve: *mut core::ffi::c_void,
     pub cbHeaderReserve: u16,
@@ -291,7 +300,8 @@ impl Default for FDIDECRYPT_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FDIDECRYPT_0_1 {
     pub pDataReserve: *mut core::ffi::c_void,
     pub cbDataReserve: u16,
@@ -309,7 +319,8 @@ impl Default for FDIDECRYPT_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FDIDECRYPT_0_2 {
     pub pFolderReserve: *mut core::ffi::c_void,
     pub cbFolderReserve: u16,
@@ -324,7 +335,8 @@ impl Default for FDIDECRYPT_0_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FDINOTIFICATION {
     pub cb: i32,
     pub psz1: windows_core::PSTR,
@@ -492,6 +492,7 @@ pub struct CF_CALLBACK_CANCEL_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_CALLBACK_CANCEL_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_CALLBACK_CANCEL_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_CALLBACK_CANCEL_FLAGS").field(&self.0).finish()
@@ -536,6 +537,7 @@ pub struct CF_CALLBACK_CLOSE_COMPLETION_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_CALLBACK_CLOSE_COMPLETION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_CALLBACK_CLOSE_COMPLETION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_CALLBACK_CLOSE_COMPLETION_FLAGS").field(&self.0).finish()
@@ -580,6 +582,7 @@ pub struct CF_CALLBACK_DEHYDRATE_COMPLETION_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_CALLBACK_DEHYDRATE_COMPLETION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_CALLBACK_DEHYDRATE_COMPLETION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_CALLBACK_DEHYDRATE_COMPLETION_FLAGS").field(&self.0).finish()
@@ -624,6 +627,7 @@ pub struct CF_CALLBACK_DEHYDRATE_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_CALLBACK_DEHYDRATE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_CALLBACK_DEHYDRATE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_CALLBACK_DEHYDRATE_FLAGS").field(&self.0).finish()
@@ -668,6 +672,7 @@ pub struct CF_CALLBACK_DEHYDRATION_REASON(pub i32);
 impl windows_core::TypeKind for CF_CALLBACK_DEHYDRATION_REASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_CALLBACK_DEHYDRATION_REASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_CALLBACK_DEHYDRATION_REASON").field(&self.0).finish()
@@ -679,6 +684,7 @@ pub struct CF_CALLBACK_DELETE_COMPLETION_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_CALLBACK_DELETE_COMPLETION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_CALLBACK_DELETE_COMPLETION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_CALLBACK_DELETE_COMPLETION_FLAGS").field(&self.0).finish()
@@ -723,6 +729,7 @@ pub struct CF_CALLBACK_DELETE_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_CALLBACK_DELETE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_CALLBACK_DELETE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_CALLBACK_DELETE_FLAGS").field(&self.0).finish()
@@ -767,6 +774,7 @@ pub struct CF_CALLBACK_FETCH_DATA_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_CALLBACK_FETCH_DATA_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_CALLBACK_FETCH_DATA_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_CALLBACK_FETCH_DATA_FLAGS").field(&self.0).finish()
@@ -811,6 +819,7 @@ pub struct CF_CALLBACK_FETCH_PLACEHOLDERS_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_CALLBACK_FETCH_PLACEHOLDERS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_CALLBACK_FETCH_PLACEHOLDERS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_CALLBACK_FETCH_PLACEHOLDERS_FLAGS").field(&self.0).finish()
@@ -855,6 +864,7 @@ pub struct CF_CALLBACK_OPEN_COMPLETION_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_CALLBACK_OPEN_COMPLETION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_CALLBACK_OPEN_COMPLETION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_CALLBACK_OPEN_COMPLETION_FLAGS").field(&self.0).finish()
@@ -899,6 +909,7 @@ pub struct CF_CALLBACK_RENAME_COMPLETION_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_CALLBACK_RENAME_COMPLETION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_CALLBACK_RENAME_COMPLETION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_CALLBACK_RENAME_COMPLETION_FLAGS").field(&self.0).finish()
@@ -943,6 +954,7 @@ pub struct CF_CALLBACK_RENAME_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_CALLBACK_RENAME_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_CALLBACK_RENAME_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_CALLBACK_RENAME_FLAGS").field(&self.0).finish()
@@ -987,6 +999,7 @@ pub struct CF_CALLBACK_TYPE(pub i32);
 impl windows_core::TypeKind for CF_CALLBACK_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_CALLBACK_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_CALLBACK_TYPE").field(&self.0).finish()
@@ -998,6 +1011,7 @@ pub struct CF_CALLBACK_VALIDATE_DATA_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_CALLBACK_VALIDATE_DATA_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_CALLBACK_VALIDATE_DATA_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_CALLBACK_VALIDATE_DATA_FLAGS").field(&self.0).finish()
@@ -1042,6 +1056,7 @@ pub struct CF_CONNECT_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_CONNECT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_CONNECT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_CONNECT_FLAGS").field(&self.0).finish()
@@ -1086,6 +1101,7 @@ pub struct CF_CONVERT_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_CONVERT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_CONVERT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_CONVERT_FLAGS").field(&self.0).finish()
@@ -1130,6 +1146,7 @@ pub struct CF_CREATE_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_CREATE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_CREATE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_CREATE_FLAGS").field(&self.0).finish()
@@ -1174,6 +1191,7 @@ pub struct CF_DEHYDRATE_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_DEHYDRATE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_DEHYDRATE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_DEHYDRATE_FLAGS").field(&self.0).finish()
@@ -1218,6 +1236,7 @@ pub struct CF_HARDLINK_POLICY(pub i32);
 impl windows_core::TypeKind for CF_HARDLINK_POLICY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_HARDLINK_POLICY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_HARDLINK_POLICY").field(&self.0).finish()
@@ -1262,6 +1281,7 @@ pub struct CF_HYDRATE_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_HYDRATE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_HYDRATE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_HYDRATE_FLAGS").field(&self.0).finish()
@@ -1306,6 +1326,7 @@ pub struct CF_HYDRATION_POLICY_MODIFIER(pub u16);
 impl windows_core::TypeKind for CF_HYDRATION_POLICY_MODIFIER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_HYDRATION_POLICY_MODIFIER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_HYDRATION_POLICY_MODIFIER").field(&self.0).finish()
@@ -1350,6 +1371,7 @@ pub struct CF_HYDRATION_POLICY_PRIMARY(pub u16);
 impl windows_core::TypeKind for CF_HYDRATION_POLICY_PRIMARY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_HYDRATION_POLICY_PRIMARY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_HYDRATION_POLICY_PRIMARY").field(&self.0).finish()
@@ -1361,6 +1383,7 @@ pub struct CF_INSYNC_POLICY(pub u32);
 impl windows_core::TypeKind for CF_INSYNC_POLICY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_INSYNC_POLICY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_INSYNC_POLICY").field(&self.0).finish()
@@ -1405,6 +1428,7 @@ pub struct CF_IN_SYNC_STATE(pub i32);
 impl windows_core::TypeKind for CF_IN_SYNC_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_IN_SYNC_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_IN_SYNC_STATE").field(&self.0).finish()
@@ -1416,6 +1440,7 @@ pub struct CF_OPEN_FILE_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_OPEN_FILE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_OPEN_FILE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_OPEN_FILE_FLAGS").field(&self.0).finish()
@@ -1460,6 +1485,7 @@ pub struct CF_OPERATION_ACK_DATA_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_OPERATION_ACK_DATA_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_OPERATION_ACK_DATA_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_OPERATION_ACK_DATA_FLAGS").field(&self.0).finish()
@@ -1504,6 +1530,7 @@ pub struct CF_OPERATION_ACK_DEHYDRATE_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_OPERATION_ACK_DEHYDRATE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_OPERATION_ACK_DEHYDRATE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_OPERATION_ACK_DEHYDRATE_FLAGS").field(&self.0).finish()
@@ -1548,6 +1575,7 @@ pub struct CF_OPERATION_ACK_DELETE_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_OPERATION_ACK_DELETE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_OPERATION_ACK_DELETE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_OPERATION_ACK_DELETE_FLAGS").field(&self.0).finish()
@@ -1592,6 +1620,7 @@ pub struct CF_OPERATION_ACK_RENAME_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_OPERATION_ACK_RENAME_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_OPERATION_ACK_RENAME_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_OPERATION_ACK_RENAME_FLAGS").field(&self.0).finish()
@@ -1636,6 +1665,7 @@ pub struct CF_OPERATION_RESTART_HYDRATION_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_OPERATION_RESTART_HYDRATION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_OPERATION_RESTART_HYDRATION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_OPERATION_RESTART_HYDRATION_FLAGS").field(&self.0).finish()
@@ -1680,6 +1710,7 @@ pub struct CF_OPERATION_RETRIEVE_DATA_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_OPERATION_RETRIEVE_DATA_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_OPERATION_RETRIEVE_DATA_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_OPERATION_RETRIEVE_DATA_FLAGS").field(&self.0).finish()
@@ -1724,6 +1755,7 @@ pub struct CF_OPERATION_TRANSFER_DATA_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_OPERATION_TRANSFER_DATA_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_OPERATION_TRANSFER_DATA_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_OPERATION_TRANSFER_DATA_FLAGS").field(&self.0).finish()
@@ -1768,6 +1800,7 @@ pub struct CF_OPERATION_TRANSFER_PLACEHOLDERS_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_OPERATION_TRANSFER_PLACEHOLDERS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_OPERATION_TRANSFER_PLACEHOLDERS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_OPERATION_TRANSFER_PLACEHOLDERS_FLAGS").field(&self.0).finish()
@@ -1812,6 +1845,7 @@ pub struct CF_OPERATION_TYPE(pub i32);
 impl windows_core::TypeKind for CF_OPERATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_OPERATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_OPERATION_TYPE").field(&self.0).finish()
@@ -1823,6 +1857,7 @@ pub struct CF_PIN_STATE(pub i32);
 impl windows_core::TypeKind for CF_PIN_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_PIN_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_PIN_STATE").field(&self.0).finish()
@@ -1834,6 +1869,7 @@ pub struct CF_PLACEHOLDER_CREATE_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_PLACEHOLDER_CREATE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_PLACEHOLDER_CREATE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_PLACEHOLDER_CREATE_FLAGS").field(&self.0).finish()
@@ -1878,6 +1914,7 @@ pub struct CF_PLACEHOLDER_INFO_CLASS(pub i32);
 impl windows_core::TypeKind for CF_PLACEHOLDER_INFO_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_PLACEHOLDER_INFO_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_PLACEHOLDER_INFO_CLASS").field(&self.0).finish()
@@ -1889,6 +1926,7 @@ pub struct CF_PLACEHOLDER_MANAGEMENT_POLICY(pub i32);
 impl windows_core::TypeKind for CF_PLACEHOLDER_MANAGEMENT_POLICY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_PLACEHOLDER_MANAGEMENT_POLICY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_PLACEHOLDER_MANAGEMENT_POLICY").field(&self.0).finish()
@@ -1900,6 +1938,7 @@ pub struct CF_PLACEHOLDER_RANGE_INFO_CLASS(pub i32);
 impl windows_core::TypeKind for CF_PLACEHOLDER_RANGE_INFO_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_PLACEHOLDER_RANGE_INFO_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_PLACEHOLDER_RANGE_INFO_CLASS").field(&self.0).finish()
@@ -1911,6 +1950,7 @@ pub struct CF_PLACEHOLDER_STATE(pub u32);
 impl windows_core::TypeKind for CF_PLACEHOLDER_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_PLACEHOLDER_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_PLACEHOLDER_STATE").field(&self.0).finish()
@@ -1955,6 +1995,7 @@ pub struct CF_POPULATION_POLICY_MODIFIER(pub u16);
 impl windows_core::TypeKind for CF_POPULATION_POLICY_MODIFIER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_POPULATION_POLICY_MODIFIER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_POPULATION_POLICY_MODIFIER").field(&self.0).finish()
@@ -1999,6 +2040,7 @@ pub struct CF_POPULATION_POLICY_PRIMARY(pub u16);
 impl windows_core::TypeKind for CF_POPULATION_POLICY_PRIMARY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_POPULATION_POLICY_PRIMARY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_POPULATION_POLICY_PRIMARY").field(&self.0).finish()
@@ -2010,6 +2052,7 @@ pub struct CF_REGISTER_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_REGISTER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_REGISTER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_REGISTER_FLAGS").field(&self.0).finish()
@@ -2054,6 +2097,7 @@ pub struct CF_REVERT_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_REVERT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_REVERT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_REVERT_FLAGS").field(&self.0).finish()
@@ -2098,6 +2142,7 @@ pub struct CF_SET_IN_SYNC_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_SET_IN_SYNC_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_SET_IN_SYNC_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_SET_IN_SYNC_FLAGS").field(&self.0).finish()
@@ -2142,6 +2187,7 @@ pub struct CF_SET_PIN_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_SET_PIN_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_SET_PIN_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_SET_PIN_FLAGS").field(&self.0).finish()
@@ -2186,6 +2232,7 @@ pub struct CF_SYNC_PROVIDER_STATUS(pub u32);
 impl windows_core::TypeKind for CF_SYNC_PROVIDER_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_SYNC_PROVIDER_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_SYNC_PROVIDER_STATUS").field(&self.0).finish()
@@ -2230,6 +2277,7 @@ pub struct CF_SYNC_ROOT_INFO_CLASS(pub i32);
 impl windows_core::TypeKind for CF_SYNC_ROOT_INFO_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_SYNC_ROOT_INFO_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_SYNC_ROOT_INFO_CLASS").field(&self.0).finish()
@@ -2241,6 +2289,7 @@ pub struct CF_UPDATE_FLAGS(pub i32);
 impl windows_core::TypeKind for CF_UPDATE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CF_UPDATE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CF_UPDATE_FLAGS").field(&self.0).finish()
@@ -2281,7 +2330,8 @@ impl core::ops::Not for CF_UPDATE_FLAGS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_CorrelationVector")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_CALLBACK_INFO {
     pub StructSize: u32,
     pub ConnectionKey: CF_CONNECTION_KEY,
@@ -2379,7 +2429,8 @@ impl Default for CF_CALLBACK_PARAMETERS_0_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_CALLBACK_PARAMETERS_0_0_0_0 {
     pub FileOffset: i64,
     pub Length: i64,
@@ -2393,7 +2444,8 @@ impl Default for CF_CALLBACK_PARAMETERS_0_0_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_CALLBACK_PARAMETERS_0_1 {
     pub Flags: CF_CALLBACK_CLOSE_COMPLETION_FLAGS,
 }
@@ -2406,7 +2458,8 @@ impl Default for CF_CALLBACK_PARAMETERS_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_CALLBACK_PARAMETERS_0_2 {
     pub Flags: CF_CALLBACK_DEHYDRATE_COMPLETION_FLAGS,
     pub Reason: CF_CALLBACK_DEHYDRATION_REASON,
@@ -2420,7 +2473,8 @@ impl Default for CF_CALLBACK_PARAMETERS_0_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_CALLBACK_PARAMETERS_0_3 {
     pub Flags: CF_CALLBACK_DEHYDRATE_FLAGS,
     pub Reason: CF_CALLBACK_DEHYDRATION_REASON,
@@ -2434,7 +2488,8 @@ impl Default for CF_CALLBACK_PARAMETERS_0_3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_CALLBACK_PARAMETERS_0_4 {
     pub Flags: CF_CALLBACK_DELETE_COMPLETION_FLAGS,
 }
@@ -2447,7 +2502,8 @@ impl Default for CF_CALLBACK_PARAMETERS_0_4 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_CALLBACK_PARAMETERS_0_5 {
     pub Flags: CF_CALLBACK_DELETE_FLAGS,
 }
@@ -2460,7 +2516,8 @@ impl Default for CF_CALLBACK_PARAMETERS_0_5 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_CALLBACK_PARAMETERS_0_6 {
     pub Flags: CF_CALLBACK_FETCH_DATA_FLAGS,
     pub RequiredFileOffset: i64,
@@ -2479,7 +2536,8 @@ impl Default for CF_CALLBACK_PARAMETERS_0_6 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_CALLBACK_PARAMETERS_0_7 {
     pub Flags: CF_CALLBACK_FETCH_PLACEHOLDERS_FLAGS,
     pub Pattern: windows_core::PCWSTR,
@@ -2493,7 +2551,8 @@ impl Default for CF_CALLBACK_PARAMETERS_0_7 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_CALLBACK_PARAMETERS_0_8 {
     pub Flags: CF_CALLBACK_OPEN_COMPLETION_FLAGS,
 }
@@ -2506,7 +2565,8 @@ impl Default for CF_CALLBACK_PARAMETERS_0_8 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_CALLBACK_PARAMETERS_0_9 {
     pub Flags: CF_CALLBACK_RENAME_COMPLETION_FLAGS,
     pub SourcePath: windows_core::PCWSTR,
@@ -2520,7 +2580,8 @@ impl Default for CF_CALLBACK_PARAMETERS_0_9 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_CALLBACK_PARAMETERS_0_10 {
     pub Flags: CF_CALLBACK_RENAME_FLAGS,
     pub TargetPath: windows_core::PCWSTR,
@@ -2534,7 +2595,8 @@ impl Default for CF_CALLBACK_PARAMETERS_0_10 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_CALLBACK_PARAMETERS_0_11 {
     pub Flags: CF_CALLBACK_VALIDATE_DATA_FLAGS,
     pub RequiredFileOffset: i64,
@@ -2550,7 +2612,8 @@ impl Default for CF_CALLBACK_PARAMETERS_0_11 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_CorrelationVector")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_CALLBACK_REGISTRATION {
     pub Type: CF_CALLBACK_TYPE,
     pub Callback: CF_CALLBACK,
@@ -2566,7 +2629,8 @@ impl Default for CF_CALLBACK_REGISTRATION {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_CONNECTION_KEY(pub i64);
 impl CF_CONNECTION_KEY {
     pub fn is_invalid(&self) -> bool {
@@ -2590,7 +2654,8 @@ impl windows_core::TypeKind for CF_CONNECTION_KEY {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_FILE_RANGE {
     pub StartingOffset: i64,
     pub Length: i64,
@@ -2605,7 +2670,8 @@ impl Default for CF_FILE_RANGE {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Storage_FileSystem")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_FS_METADATA {
     pub BasicInfo: super::FileSystem::FILE_BASIC_INFO,
     pub FileSize: i64,
@@ -2621,7 +2687,8 @@ impl Default for CF_FS_METADATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_HYDRATION_POLICY {
     pub Primary: CF_HYDRATION_POLICY_PRIMARY,
     pub Modifier: CF_HYDRATION_POLICY_MODIFIER,
@@ -2636,7 +2703,8 @@ impl Default for CF_HYDRATION_POLICY {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_CorrelationVector")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_OPERATION_INFO {
     pub StructSize: u32,
     pub Type: CF_OPERATION_TYPE,
@@ -2698,7 +2766,8 @@ impl Default for CF_OPERATION_PARAMETERS_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Storage_FileSystem")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_OPERATION_PARAMETERS_0_0 {
     pub Flags: CF_OPERATION_ACK_DATA_FLAGS,
     pub CompletionStatus: super::super::Foundation::NTSTATUS,
@@ -2717,7 +2786,8 @@ impl Default for CF_OPERATION_PARAMETERS_0_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Storage_FileSystem")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_OPERATION_PARAMETERS_0_1 {
     pub Flags: CF_OPERATION_ACK_DEHYDRATE_FLAGS,
     pub CompletionStatus: super::super::Foundation::NTSTATUS,
@@ -2736,7 +2806,8 @@ impl Default for CF_OPERATION_PARAMETERS_0_1 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Storage_FileSystem")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_OPERATION_PARAMETERS_0_2 {
     pub Flags: CF_OPERATION_ACK_DELETE_FLAGS,
     pub CompletionStatus: super::super::Foundation::NTSTATUS,
@@ -2753,7 +2824,8 @@ impl Default for CF_OPERATION_PARAMETERS_0_2 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Storage_FileSystem")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_OPERATION_PARAMETERS_0_3 {
     pub Flags: CF_OPERATION_ACK_RENAME_FLAGS,
     pub CompletionStatus: super::super::Foundation::NTSTATUS,
@@ -2770,7 +2842,8 @@ impl Default for CF_OPERATION_PARAMETERS_0_3 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Storage_FileSystem")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_OPERATION_PARAMETERS_0_4 {
     pub Flags: CF_OPERATION_RESTART_HYDRATION_FLAGS,
     pub FsMetadata: *const CF_FS_METADATA,
@@ -2789,7 +2862,8 @@ impl Default for CF_OPERATION_PARAMETERS_0_4 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Storage_FileSystem")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_OPERATION_PARAMETERS_0_5 {
     pub Flags: CF_OPERATION_RETRIEVE_DATA_FLAGS,
     pub Buffer: *mut core::ffi::c_void,
@@ -2809,7 +2883,8 @@ impl Default for CF_OPERATION_PARAMETERS_0_5 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Storage_FileSystem")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_OPERATION_PARAMETERS_0_6 {
     pub Flags: CF_OPERATION_TRANSFER_DATA_FLAGS,
     pub CompletionStatus: super::super::Foundation::NTSTATUS,
@@ -2829,7 +2904,8 @@ impl Default for CF_OPERATION_PARAMETERS_0_6 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Storage_FileSystem")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_OPERATION_PARAMETERS_0_7 {
     pub Flags: CF_OPERATION_TRANSFER_PLACEHOLDERS_FLAGS,
     pub CompletionStatus: super::super::Foundation::NTSTATUS,
@@ -2849,7 +2925,8 @@ impl Default for CF_OPERATION_PARAMETERS_0_7 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_PLACEHOLDER_BASIC_INFO {
     pub PinState: CF_PIN_STATE,
     pub InSyncState: CF_IN_SYNC_STATE,
@@ -2868,7 +2945,8 @@ impl Default for CF_PLACEHOLDER_BASIC_INFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Storage_FileSystem")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_PLACEHOLDER_CREATE_INFO {
     pub RelativeFileName: windows_core::PCWSTR,
     pub FsMetadata: CF_FS_METADATA,
@@ -2889,7 +2967,8 @@ impl Default for CF_PLACEHOLDER_CREATE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_PLACEHOLDER_STANDARD_INFO {
     pub OnDiskDataSize: i64,
     pub ValidatedDataSize: i64,
@@ -2911,7 +2990,8 @@ impl Default for CF_PLACEHOLDER_STANDARD_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_PLATFORM_INFO {
     pub BuildNumber: u32,
     pub RevisionNumber: u32,
@@ -2926,7 +3006,8 @@ impl Default for CF_PLATFORM_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_POPULATION_POLICY {
     pub Primary: CF_POPULATION_POLICY_PRIMARY,
     pub Modifier: CF_POPULATION_POLICY_MODIFIER,
@@ -2940,7 +3021,8 @@ impl Default for CF_POPULATION_POLICY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_PROCESS_INFO {
     pub StructSize: u32,
     pub ProcessId: u32,
@@ -2959,7 +3041,8 @@ impl Default for CF_PROCESS_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_SYNC_POLICIES {
     pub StructSize: u32,
     pub Hydration: CF_HYDRATION_POLICY,
@@ -2977,7 +3060,8 @@ impl Default for CF_SYNC_POLICIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_SYNC_REGISTRATION {
     pub StructSize: u32,
     pub ProviderName: windows_core::PCWSTR,
@@ -2997,7 +3081,8 @@ impl Default for CF_SYNC_REGISTRATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_SYNC_ROOT_BASIC_INFO {
     pub SyncRootFileId: i64,
 }
@@ -3010,7 +3095,8 @@ impl Default for CF_SYNC_ROOT_BASIC_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_SYNC_ROOT_PROVIDER_INFO {
     pub ProviderStatus: CF_SYNC_PROVIDER_STATUS,
     pub ProviderName: [u16; 256],
@@ -3025,7 +3111,8 @@ impl Default for CF_SYNC_ROOT_PROVIDER_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_SYNC_ROOT_STANDARD_INFO {
     pub SyncRootFileId: i64,
     pub HydrationPolicy: CF_HYDRATION_POLICY,
@@ -3047,7 +3134,8 @@ impl Default for CF_SYNC_ROOT_STANDARD_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CF_SYNC_STATUS {
     pub StructSize: u32,
     pub Code: u32,
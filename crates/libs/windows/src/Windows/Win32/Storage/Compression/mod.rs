@@ -105,6 +105,7 @@ pub struct COMPRESS_ALGORITHM(pub u32);
 impl windows_core::TypeKind for COMPRESS_ALGORITHM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMPRESS_ALGORITHM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMPRESS_ALGORITHM").field(&self.0).finish()
@@ -116,13 +117,15 @@ pub struct COMPRESS_INFORMATION_CLASS(pub i32);
 impl windows_core::TypeKind for COMPRESS_INFORMATION_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMPRESS_INFORMATION_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMPRESS_INFORMATION_CLASS").field(&self.0).finish()
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COMPRESSOR_HANDLE(pub *mut core::ffi::c_void);
 impl COMPRESSOR_HANDLE {
     pub fn is_invalid(&self) -> bool {
@@ -138,7 +141,8 @@ impl windows_core::TypeKind for COMPRESSOR_HANDLE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COMPRESS_ALLOCATION_ROUTINES {
     pub Allocate: PFN_COMPRESS_ALLOCATE,
     pub Free: PFN_COMPRESS_FREE,
@@ -153,7 +157,8 @@ impl Default for COMPRESS_ALLOCATION_ROUTINES {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DECOMPRESSOR_HANDLE(pub *mut core::ffi::c_void);
 impl DECOMPRESSOR_HANDLE {
     pub fn is_invalid(&self) -> bool {
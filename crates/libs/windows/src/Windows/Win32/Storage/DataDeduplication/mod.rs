@@ -271,6 +271,7 @@ pub struct DEDUP_BACKUP_SUPPORT_PARAM_TYPE(pub i32);
 impl windows_core::TypeKind for DEDUP_BACKUP_SUPPORT_PARAM_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEDUP_BACKUP_SUPPORT_PARAM_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEDUP_BACKUP_SUPPORT_PARAM_TYPE").field(&self.0).finish()
@@ -282,6 +283,7 @@ pub struct DEDUP_SET_PARAM_TYPE(pub i32);
 impl windows_core::TypeKind for DEDUP_SET_PARAM_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEDUP_SET_PARAM_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEDUP_SET_PARAM_TYPE").field(&self.0).finish()
@@ -293,6 +295,7 @@ pub struct DedupChunkFlags(pub i32);
 impl windows_core::TypeKind for DedupChunkFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DedupChunkFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DedupChunkFlags").field(&self.0).finish()
@@ -304,6 +307,7 @@ pub struct DedupChunkingAlgorithm(pub i32);
 impl windows_core::TypeKind for DedupChunkingAlgorithm {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DedupChunkingAlgorithm {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DedupChunkingAlgorithm").field(&self.0).finish()
@@ -315,6 +319,7 @@ pub struct DedupCompressionAlgorithm(pub i32);
 impl windows_core::TypeKind for DedupCompressionAlgorithm {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DedupCompressionAlgorithm {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DedupCompressionAlgorithm").field(&self.0).finish()
@@ -326,6 +331,7 @@ pub struct DedupDataPortManagerOption(pub i32);
 impl windows_core::TypeKind for DedupDataPortManagerOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DedupDataPortManagerOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DedupDataPortManagerOption").field(&self.0).finish()
@@ -337,6 +343,7 @@ pub struct DedupDataPortRequestStatus(pub i32);
 impl windows_core::TypeKind for DedupDataPortRequestStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DedupDataPortRequestStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DedupDataPortRequestStatus").field(&self.0).finish()
@@ -348,6 +355,7 @@ pub struct DedupDataPortVolumeStatus(pub i32);
 impl windows_core::TypeKind for DedupDataPortVolumeStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DedupDataPortVolumeStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DedupDataPortVolumeStatus").field(&self.0).finish()
@@ -359,13 +367,15 @@ pub struct DedupHashingAlgorithm(pub i32);
 impl windows_core::TypeKind for DedupHashingAlgorithm {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DedupHashingAlgorithm {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DedupHashingAlgorithm").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DDP_FILE_EXTENT {
     pub Length: i64,
     pub Offset: i64,
@@ -379,7 +389,8 @@ impl Default for DDP_FILE_EXTENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DEDUP_CHUNK_INFO_HASH32 {
     pub ChunkFlags: u32,
     pub ChunkOffsetInStream: u64,
@@ -395,7 +406,8 @@ impl Default for DEDUP_CHUNK_INFO_HASH32 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DEDUP_CONTAINER_EXTENT {
     pub ContainerIndex: u32,
     pub StartOffset: i64,
@@ -411,7 +423,8 @@ impl Default for DEDUP_CONTAINER_EXTENT {
 }
 pub const DedupBackupSupport: windows_core::GUID = windows_core::GUID::from_u128(0x73d6b2ad_2984_4715_b2e3_924c149744dd);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DedupChunk {
     pub Hash: DedupHash,
     pub Flags: DedupChunkFlags,
@@ -428,7 +441,8 @@ impl Default for DedupChunk {
 }
 pub const DedupDataPort: windows_core::GUID = windows_core::GUID::from_u128(0x8f107207_1829_48b2_a64b_e61f8e0d9acb);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DedupHash {
     pub Hash: [u8; 32],
 }
@@ -441,7 +455,8 @@ impl Default for DedupHash {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DedupStream {
     pub Path: core::mem::ManuallyDrop<windows_core::BSTR>,
     pub Offset: u64,
@@ -462,7 +477,8 @@ impl Default for DedupStream {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DedupStreamEntry {
     pub Hash: DedupHash,
     pub LogicalSize: u32,
@@ -478,3 +494,5 @@ impl Default for DedupStreamEntry {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,164 @@
+impl windows_core::imp::ProxyVtable for IDedupBackupSupport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RestoreFiles(this: *mut core::ffi::c_void, numberoffiles: u32, filefullpaths: *const core::mem::MaybeUninit<windows_core::BSTR>, store: *mut core::ffi::c_void, flags: u32, fileresults: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDedupBackupSupport", "RestoreFiles", 3usize, |this| ((**(this as *const *const IDedupBackupSupport_Vtbl)).RestoreFiles)(this, numberoffiles, filefullpaths, store, flags, fileresults), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RestoreFiles }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDedupBackupSupport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDedupChunkLibrary_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeForPushBuffers(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDedupChunkLibrary", "InitializeForPushBuffers", 3usize, |this| ((**(this as *const *const IDedupChunkLibrary_Vtbl)).InitializeForPushBuffers)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uninitialize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDedupChunkLibrary", "Uninitialize", 4usize, |this| ((**(this as *const *const IDedupChunkLibrary_Vtbl)).Uninitialize)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParameter(this: *mut core::ffi::c_void, dwparamtype: u32, vparamvalue: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDedupChunkLibrary", "SetParameter", 5usize, |this| ((**(this as *const *const IDedupChunkLibrary_Vtbl)).SetParameter)(this, dwparamtype, vparamvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartChunking(this: *mut core::ffi::c_void, iiditeratorinterfaceid: windows_core::GUID, ppchunksenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDedupChunkLibrary", "StartChunking", 6usize, |this| ((**(this as *const *const IDedupChunkLibrary_Vtbl)).StartChunking)(this, iiditeratorinterfaceid, ppchunksenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitializeForPushBuffers,
+            Uninitialize,
+            SetParameter,
+            StartChunking,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDedupChunkLibrary as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDedupDataPort_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pstatus: *mut DedupDataPortVolumeStatus, pdataheadroommb: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDedupDataPort", "GetStatus", 3usize, |this| ((**(this as *const *const IDedupDataPort_Vtbl)).GetStatus)(this, pstatus, pdataheadroommb), |result| Some(*result))
+        }
+        unsafe extern "system" fn LookupChunks(this: *mut core::ffi::c_void, count: u32, phashes: *const DedupHash, prequestid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDedupDataPort", "LookupChunks", 4usize, |this| ((**(this as *const *const IDedupDataPort_Vtbl)).LookupChunks)(this, count, phashes, prequestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertChunks(this: *mut core::ffi::c_void, chunkcount: u32, pchunkmetadata: *const DedupChunk, databytecount: u32, pchunkdata: *const u8, prequestid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDedupDataPort", "InsertChunks", 5usize, |this| ((**(this as *const *const IDedupDataPort_Vtbl)).InsertChunks)(this, chunkcount, pchunkmetadata, databytecount, pchunkdata, prequestid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InsertChunksWithStream(this: *mut core::ffi::c_void, chunkcount: u32, pchunkmetadata: *const DedupChunk, databytecount: u32, pchunkdatastream: *mut core::ffi::c_void, prequestid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDedupDataPort", "InsertChunksWithStream", 6usize, |this| ((**(this as *const *const IDedupDataPort_Vtbl)).InsertChunksWithStream)(this, chunkcount, pchunkmetadata, databytecount, pchunkdatastream, prequestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommitStreams(this: *mut core::ffi::c_void, streamcount: u32, pstreams: *const DedupStream, entrycount: u32, pentries: *const DedupStreamEntry, prequestid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDedupDataPort", "CommitStreams", 7usize, |this| ((**(this as *const *const IDedupDataPort_Vtbl)).CommitStreams)(this, streamcount, pstreams, entrycount, pentries, prequestid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CommitStreamsWithStream(this: *mut core::ffi::c_void, streamcount: u32, pstreams: *const DedupStream, entrycount: u32, pentriesstream: *mut core::ffi::c_void, prequestid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDedupDataPort", "CommitStreamsWithStream", 8usize, |this| ((**(this as *const *const IDedupDataPort_Vtbl)).CommitStreamsWithStream)(this, streamcount, pstreams, entrycount, pentriesstream, prequestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreams(this: *mut core::ffi::c_void, streamcount: u32, pstreampaths: *const core::mem::MaybeUninit<windows_core::BSTR>, prequestid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDedupDataPort", "GetStreams", 9usize, |this| ((**(this as *const *const IDedupDataPort_Vtbl)).GetStreams)(this, streamcount, pstreampaths, prequestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStreamsResults(this: *mut core::ffi::c_void, requestid: windows_core::GUID, maxwaitms: u32, streamentryindex: u32, pstreamcount: *mut u32, ppstreams: *mut *mut DedupStream, pentrycount: *mut u32, ppentries: *mut *mut DedupStreamEntry, pstatus: *mut DedupDataPortRequestStatus, ppitemresults: *mut *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDedupDataPort", "GetStreamsResults", 10usize, |this| ((**(this as *const *const IDedupDataPort_Vtbl)).GetStreamsResults)(this, requestid, maxwaitms, streamentryindex, pstreamcount, ppstreams, pentrycount, ppentries, pstatus, ppitemresults), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChunks(this: *mut core::ffi::c_void, count: u32, phashes: *const DedupHash, prequestid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDedupDataPort", "GetChunks", 11usize, |this| ((**(this as *const *const IDedupDataPort_Vtbl)).GetChunks)(this, count, phashes, prequestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChunksResults(this: *mut core::ffi::c_void, requestid: windows_core::GUID, maxwaitms: u32, chunkindex: u32, pchunkcount: *mut u32, ppchunkmetadata: *mut *mut DedupChunk, pdatabytecount: *mut u32, ppchunkdata: *mut *mut u8, pstatus: *mut DedupDataPortRequestStatus, ppitemresults: *mut *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDedupDataPort", "GetChunksResults", 12usize, |this| ((**(this as *const *const IDedupDataPort_Vtbl)).GetChunksResults)(this, requestid, maxwaitms, chunkindex, pchunkcount, ppchunkmetadata, pdatabytecount, ppchunkdata, pstatus, ppitemresults), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRequestStatus(this: *mut core::ffi::c_void, requestid: windows_core::GUID, pstatus: *mut DedupDataPortRequestStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDedupDataPort", "GetRequestStatus", 13usize, |this| ((**(this as *const *const IDedupDataPort_Vtbl)).GetRequestStatus)(this, requestid, pstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRequestResults(this: *mut core::ffi::c_void, requestid: windows_core::GUID, maxwaitms: u32, pbatchresult: *mut windows_core::HRESULT, pbatchcount: *mut u32, pstatus: *mut DedupDataPortRequestStatus, ppitemresults: *mut *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDedupDataPort", "GetRequestResults", 14usize, |this| ((**(this as *const *const IDedupDataPort_Vtbl)).GetRequestResults)(this, requestid, maxwaitms, pbatchresult, pbatchcount, pstatus, ppitemresults), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetStatus,
+            LookupChunks,
+            InsertChunks,
+            #[cfg(feature = "Win32_System_Com")]
+            InsertChunksWithStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InsertChunksWithStream: 0,
+            CommitStreams,
+            #[cfg(feature = "Win32_System_Com")]
+            CommitStreamsWithStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CommitStreamsWithStream: 0,
+            GetStreams,
+            GetStreamsResults,
+            GetChunks,
+            GetChunksResults,
+            GetRequestStatus,
+            GetRequestResults,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDedupDataPort as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDedupDataPortManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetConfiguration(this: *mut core::ffi::c_void, pminchunksize: *mut u32, pmaxchunksize: *mut u32, pchunkingalgorithm: *mut DedupChunkingAlgorithm, phashingalgorithm: *mut DedupHashingAlgorithm, pcompressionalgorithm: *mut DedupCompressionAlgorithm) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDedupDataPortManager", "GetConfiguration", 3usize, |this| ((**(this as *const *const IDedupDataPortManager_Vtbl)).GetConfiguration)(this, pminchunksize, pmaxchunksize, pchunkingalgorithm, phashingalgorithm, pcompressionalgorithm), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVolumeStatus(this: *mut core::ffi::c_void, options: u32, path: core::mem::MaybeUninit<windows_core::BSTR>, pstatus: *mut DedupDataPortVolumeStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDedupDataPortManager", "GetVolumeStatus", 4usize, |this| ((**(this as *const *const IDedupDataPortManager_Vtbl)).GetVolumeStatus)(this, options, path, pstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVolumeDataPort(this: *mut core::ffi::c_void, options: u32, path: core::mem::MaybeUninit<windows_core::BSTR>, ppdataport: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDedupDataPortManager", "GetVolumeDataPort", 5usize, |this| ((**(this as *const *const IDedupDataPortManager_Vtbl)).GetVolumeDataPort)(this, options, path, ppdataport), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetConfiguration, GetVolumeStatus, GetVolumeDataPort }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDedupDataPortManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDedupIterateChunksHash32_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PushBuffer(this: *mut core::ffi::c_void, pbuffer: *const u8, ulbufferlength: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDedupIterateChunksHash32", "PushBuffer", 3usize, |this| ((**(this as *const *const IDedupIterateChunksHash32_Vtbl)).PushBuffer)(this, pbuffer, ulbufferlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ulmaxchunks: u32, parrchunks: *mut DEDUP_CHUNK_INFO_HASH32, pulfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDedupIterateChunksHash32", "Next", 4usize, |this| ((**(this as *const *const IDedupIterateChunksHash32_Vtbl)).Next)(this, ulmaxchunks, parrchunks, pulfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Drain(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDedupIterateChunksHash32", "Drain", 5usize, |this| ((**(this as *const *const IDedupIterateChunksHash32_Vtbl)).Drain)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDedupIterateChunksHash32", "Reset", 6usize, |this| ((**(this as *const *const IDedupIterateChunksHash32_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PushBuffer, Next, Drain, Reset }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDedupIterateChunksHash32 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDedupReadFileCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReadBackupFile(this: *mut core::ffi::c_void, filefullpath: core::mem::MaybeUninit<windows_core::BSTR>, fileoffset: i64, sizetoread: u32, filebuffer: *mut u8, returnedsize: *mut u32, flags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDedupReadFileCallback", "ReadBackupFile", 3usize, |this| ((**(this as *const *const IDedupReadFileCallback_Vtbl)).ReadBackupFile)(this, filefullpath, fileoffset, sizetoread, filebuffer, returnedsize, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn OrderContainersRestore(this: *mut core::ffi::c_void, numberofcontainers: u32, containerpaths: *const core::mem::MaybeUninit<windows_core::BSTR>, readplanentries: *mut u32, readplan: *mut *mut DEDUP_CONTAINER_EXTENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDedupReadFileCallback", "OrderContainersRestore", 4usize, |this| ((**(this as *const *const IDedupReadFileCallback_Vtbl)).OrderContainersRestore)(this, numberofcontainers, containerpaths, readplanentries, readplan), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreviewContainerRead(this: *mut core::ffi::c_void, filefullpath: core::mem::MaybeUninit<windows_core::BSTR>, numberofreads: u32, readoffsets: *const DDP_FILE_EXTENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDedupReadFileCallback", "PreviewContainerRead", 5usize, |this| ((**(this as *const *const IDedupReadFileCallback_Vtbl)).PreviewContainerRead)(this, filefullpath, numberofreads, readoffsets), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReadBackupFile, OrderContainersRestore, PreviewContainerRead }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDedupReadFileCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
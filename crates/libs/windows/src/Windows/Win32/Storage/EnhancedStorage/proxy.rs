@@ -0,0 +1,137 @@
+impl windows_core::imp::ProxyVtable for IEnhancedStorageACT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Authorize(this: *mut core::ffi::c_void, hwndparent: u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnhancedStorageACT", "Authorize", 3usize, |this| ((**(this as *const *const IEnhancedStorageACT_Vtbl)).Authorize)(this, hwndparent, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unauthorize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnhancedStorageACT", "Unauthorize", 4usize, |this| ((**(this as *const *const IEnhancedStorageACT_Vtbl)).Unauthorize)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAuthorizationState(this: *mut core::ffi::c_void, pstate: *mut ACT_AUTHORIZATION_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnhancedStorageACT", "GetAuthorizationState", 5usize, |this| ((**(this as *const *const IEnhancedStorageACT_Vtbl)).GetAuthorizationState)(this, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMatchingVolume(this: *mut core::ffi::c_void, ppwszvolume: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnhancedStorageACT", "GetMatchingVolume", 6usize, |this| ((**(this as *const *const IEnhancedStorageACT_Vtbl)).GetMatchingVolume)(this, ppwszvolume), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUniqueIdentity(this: *mut core::ffi::c_void, ppwszidentity: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnhancedStorageACT", "GetUniqueIdentity", 7usize, |this| ((**(this as *const *const IEnhancedStorageACT_Vtbl)).GetUniqueIdentity)(this, ppwszidentity), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSilos(this: *mut core::ffi::c_void, pppienhancedstoragesilos: *mut *mut Option<IEnhancedStorageSilo>, pcenhancedstoragesilos: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnhancedStorageACT", "GetSilos", 8usize, |this| ((**(this as *const *const IEnhancedStorageACT_Vtbl)).GetSilos)(this, pppienhancedstoragesilos, pcenhancedstoragesilos), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Authorize,
+            Unauthorize,
+            GetAuthorizationState,
+            GetMatchingVolume,
+            GetUniqueIdentity,
+            GetSilos,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnhancedStorageACT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnhancedStorageACT2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeviceName(this: *mut core::ffi::c_void, ppwszdevicename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnhancedStorageACT2", "GetDeviceName", 9usize, |this| ((**(this as *const *const IEnhancedStorageACT2_Vtbl)).GetDeviceName)(this, ppwszdevicename), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDeviceRemovable(this: *mut core::ffi::c_void, pisdeviceremovable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnhancedStorageACT2", "IsDeviceRemovable", 10usize, |this| ((**(this as *const *const IEnhancedStorageACT2_Vtbl)).IsDeviceRemovable)(this, pisdeviceremovable), |result| Some(*result))
+        }
+        Self { base__: <IEnhancedStorageACT_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDeviceName, IsDeviceRemovable }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnhancedStorageACT2 as windows_core::Interface>::IID || <IEnhancedStorageACT_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnhancedStorageACT3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UnauthorizeEx(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnhancedStorageACT3", "UnauthorizeEx", 11usize, |this| ((**(this as *const *const IEnhancedStorageACT3_Vtbl)).UnauthorizeEx)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsQueueFrozen(this: *mut core::ffi::c_void, pisqueuefrozen: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnhancedStorageACT3", "IsQueueFrozen", 12usize, |this| ((**(this as *const *const IEnhancedStorageACT3_Vtbl)).IsQueueFrozen)(this, pisqueuefrozen), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetShellExtSupport(this: *mut core::ffi::c_void, pshellextsupport: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnhancedStorageACT3", "GetShellExtSupport", 13usize, |this| ((**(this as *const *const IEnhancedStorageACT3_Vtbl)).GetShellExtSupport)(this, pshellextsupport), |result| Some(*result))
+        }
+        Self { base__: <IEnhancedStorageACT2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UnauthorizeEx, IsQueueFrozen, GetShellExtSupport }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnhancedStorageACT3 as windows_core::Interface>::IID || <IEnhancedStorageACT2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Devices_PortableDevices")]
+impl windows_core::imp::ProxyVtable for IEnhancedStorageSilo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInfo(this: *mut core::ffi::c_void, psiloinfo: *mut SILO_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnhancedStorageSilo", "GetInfo", 3usize, |this| ((**(this as *const *const IEnhancedStorageSilo_Vtbl)).GetInfo)(this, psiloinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActions(this: *mut core::ffi::c_void, pppienhancedstoragesiloactions: *mut *mut Option<IEnhancedStorageSiloAction>, pcenhancedstoragesiloactions: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnhancedStorageSilo", "GetActions", 4usize, |this| ((**(this as *const *const IEnhancedStorageSilo_Vtbl)).GetActions)(this, pppienhancedstoragesiloactions, pcenhancedstoragesiloactions), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendCommand(this: *mut core::ffi::c_void, command: u8, pbcommandbuffer: *const u8, cbcommandbuffer: u32, pbresponsebuffer: *mut u8, pcbresponsebuffer: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnhancedStorageSilo", "SendCommand", 5usize, |this| ((**(this as *const *const IEnhancedStorageSilo_Vtbl)).SendCommand)(this, command, pbcommandbuffer, cbcommandbuffer, pbresponsebuffer, pcbresponsebuffer), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Devices_PortableDevices")]
+        unsafe extern "system" fn GetPortableDevice(this: *mut core::ffi::c_void, ppiportabledevice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnhancedStorageSilo", "GetPortableDevice", 6usize, |this| ((**(this as *const *const IEnhancedStorageSilo_Vtbl)).GetPortableDevice)(this, ppiportabledevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDevicePath(this: *mut core::ffi::c_void, ppwszsilodevicepath: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnhancedStorageSilo", "GetDevicePath", 7usize, |this| ((**(this as *const *const IEnhancedStorageSilo_Vtbl)).GetDevicePath)(this, ppwszsilodevicepath), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetInfo,
+            GetActions,
+            SendCommand,
+            #[cfg(feature = "Win32_Devices_PortableDevices")]
+            GetPortableDevice,
+            #[cfg(not(feature = "Win32_Devices_PortableDevices"))]
+            GetPortableDevice: 0,
+            GetDevicePath,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnhancedStorageSilo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnhancedStorageSiloAction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, ppwszactionname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnhancedStorageSiloAction", "GetName", 3usize, |this| ((**(this as *const *const IEnhancedStorageSiloAction_Vtbl)).GetName)(this, ppwszactionname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescription(this: *mut core::ffi::c_void, ppwszactiondescription: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnhancedStorageSiloAction", "GetDescription", 4usize, |this| ((**(this as *const *const IEnhancedStorageSiloAction_Vtbl)).GetDescription)(this, ppwszactiondescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn Invoke(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnhancedStorageSiloAction", "Invoke", 5usize, |this| ((**(this as *const *const IEnhancedStorageSiloAction_Vtbl)).Invoke)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetName, GetDescription, Invoke }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnhancedStorageSiloAction as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumEnhancedStorageACT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetACTs(this: *mut core::ffi::c_void, pppienhancedstorageacts: *mut *mut Option<IEnhancedStorageACT>, pcenhancedstorageacts: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumEnhancedStorageACT", "GetACTs", 3usize, |this| ((**(this as *const *const IEnumEnhancedStorageACT_Vtbl)).GetACTs)(this, pppienhancedstorageacts, pcenhancedstorageacts), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMatchingACT(this: *mut core::ffi::c_void, szvolume: windows_core::PCWSTR, ppienhancedstorageact: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumEnhancedStorageACT", "GetMatchingACT", 4usize, |this| ((**(this as *const *const IEnumEnhancedStorageACT_Vtbl)).GetMatchingACT)(this, szvolume, ppienhancedstorageact), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetACTs, GetMatchingACT }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumEnhancedStorageACT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
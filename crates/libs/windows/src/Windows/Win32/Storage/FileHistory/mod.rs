@@ -328,6 +328,7 @@ pub struct FH_BACKUP_STATUS(pub i32);
 impl windows_core::TypeKind for FH_BACKUP_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FH_BACKUP_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FH_BACKUP_STATUS").field(&self.0).finish()
@@ -339,6 +340,7 @@ pub struct FH_DEVICE_VALIDATION_RESULT(pub i32);
 impl windows_core::TypeKind for FH_DEVICE_VALIDATION_RESULT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FH_DEVICE_VALIDATION_RESULT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FH_DEVICE_VALIDATION_RESULT").field(&self.0).finish()
@@ -350,6 +352,7 @@ pub struct FH_LOCAL_POLICY_TYPE(pub i32);
 impl windows_core::TypeKind for FH_LOCAL_POLICY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FH_LOCAL_POLICY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FH_LOCAL_POLICY_TYPE").field(&self.0).finish()
@@ -361,6 +364,7 @@ pub struct FH_PROTECTED_ITEM_CATEGORY(pub i32);
 impl windows_core::TypeKind for FH_PROTECTED_ITEM_CATEGORY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FH_PROTECTED_ITEM_CATEGORY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FH_PROTECTED_ITEM_CATEGORY").field(&self.0).finish()
@@ -372,6 +376,7 @@ pub struct FH_RETENTION_TYPES(pub i32);
 impl windows_core::TypeKind for FH_RETENTION_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FH_RETENTION_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FH_RETENTION_TYPES").field(&self.0).finish()
@@ -383,6 +388,7 @@ pub struct FH_TARGET_DRIVE_TYPES(pub i32);
 impl windows_core::TypeKind for FH_TARGET_DRIVE_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FH_TARGET_DRIVE_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FH_TARGET_DRIVE_TYPES").field(&self.0).finish()
@@ -394,6 +400,7 @@ pub struct FH_TARGET_PROPERTY_TYPE(pub i32);
 impl windows_core::TypeKind for FH_TARGET_PROPERTY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FH_TARGET_PROPERTY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FH_TARGET_PROPERTY_TYPE").field(&self.0).finish()
@@ -405,13 +412,15 @@ pub struct FhBackupStopReason(pub i32);
 impl windows_core::TypeKind for FhBackupStopReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FhBackupStopReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FhBackupStopReason").field(&self.0).finish()
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FH_SERVICE_PIPE_HANDLE(pub *mut core::ffi::c_void);
 impl FH_SERVICE_PIPE_HANDLE {
     pub fn is_invalid(&self) -> bool {
@@ -438,3 +447,5 @@ pub const FhConfigMgr: windows_core::GUID = windows_core::GUID::from_u128(0xed43
 pub const FhReassociation: windows_core::GUID = windows_core::GUID::from_u128(0x4d728e35_16fa_4320_9e8b_bfd7100a8846);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
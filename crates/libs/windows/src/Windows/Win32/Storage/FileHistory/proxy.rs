@@ -0,0 +1,128 @@
+impl windows_core::imp::ProxyVtable for IFhConfigMgr_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LoadConfiguration(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFhConfigMgr", "LoadConfiguration", 3usize, |this| ((**(this as *const *const IFhConfigMgr_Vtbl)).LoadConfiguration)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDefaultConfiguration(this: *mut core::ffi::c_void, overwriteifexists: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFhConfigMgr", "CreateDefaultConfiguration", 4usize, |this| ((**(this as *const *const IFhConfigMgr_Vtbl)).CreateDefaultConfiguration)(this, overwriteifexists), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveConfiguration(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFhConfigMgr", "SaveConfiguration", 5usize, |this| ((**(this as *const *const IFhConfigMgr_Vtbl)).SaveConfiguration)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddRemoveExcludeRule(this: *mut core::ffi::c_void, add: super::super::Foundation::BOOL, category: FH_PROTECTED_ITEM_CATEGORY, item: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFhConfigMgr", "AddRemoveExcludeRule", 6usize, |this| ((**(this as *const *const IFhConfigMgr_Vtbl)).AddRemoveExcludeRule)(this, add, category, item), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIncludeExcludeRules(this: *mut core::ffi::c_void, include: super::super::Foundation::BOOL, category: FH_PROTECTED_ITEM_CATEGORY, iterator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFhConfigMgr", "GetIncludeExcludeRules", 7usize, |this| ((**(this as *const *const IFhConfigMgr_Vtbl)).GetIncludeExcludeRules)(this, include, category, iterator), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocalPolicy(this: *mut core::ffi::c_void, localpolicytype: FH_LOCAL_POLICY_TYPE, policyvalue: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFhConfigMgr", "GetLocalPolicy", 8usize, |this| ((**(this as *const *const IFhConfigMgr_Vtbl)).GetLocalPolicy)(this, localpolicytype, policyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocalPolicy(this: *mut core::ffi::c_void, localpolicytype: FH_LOCAL_POLICY_TYPE, policyvalue: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFhConfigMgr", "SetLocalPolicy", 9usize, |this| ((**(this as *const *const IFhConfigMgr_Vtbl)).SetLocalPolicy)(this, localpolicytype, policyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBackupStatus(this: *mut core::ffi::c_void, backupstatus: *mut FH_BACKUP_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFhConfigMgr", "GetBackupStatus", 10usize, |this| ((**(this as *const *const IFhConfigMgr_Vtbl)).GetBackupStatus)(this, backupstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackupStatus(this: *mut core::ffi::c_void, backupstatus: FH_BACKUP_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFhConfigMgr", "SetBackupStatus", 11usize, |this| ((**(this as *const *const IFhConfigMgr_Vtbl)).SetBackupStatus)(this, backupstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultTarget(this: *mut core::ffi::c_void, defaulttarget: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFhConfigMgr", "GetDefaultTarget", 12usize, |this| ((**(this as *const *const IFhConfigMgr_Vtbl)).GetDefaultTarget)(this, defaulttarget), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValidateTarget(this: *mut core::ffi::c_void, targeturl: core::mem::MaybeUninit<windows_core::BSTR>, validationresult: *mut FH_DEVICE_VALIDATION_RESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFhConfigMgr", "ValidateTarget", 13usize, |this| ((**(this as *const *const IFhConfigMgr_Vtbl)).ValidateTarget)(this, targeturl, validationresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProvisionAndSetNewTarget(this: *mut core::ffi::c_void, targeturl: core::mem::MaybeUninit<windows_core::BSTR>, targetname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFhConfigMgr", "ProvisionAndSetNewTarget", 14usize, |this| ((**(this as *const *const IFhConfigMgr_Vtbl)).ProvisionAndSetNewTarget)(this, targeturl, targetname), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeDefaultTargetRecommendation(this: *mut core::ffi::c_void, recommend: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFhConfigMgr", "ChangeDefaultTargetRecommendation", 15usize, |this| ((**(this as *const *const IFhConfigMgr_Vtbl)).ChangeDefaultTargetRecommendation)(this, recommend), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryProtectionStatus(this: *mut core::ffi::c_void, protectionstate: *mut u32, protecteduntiltime: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFhConfigMgr", "QueryProtectionStatus", 16usize, |this| ((**(this as *const *const IFhConfigMgr_Vtbl)).QueryProtectionStatus)(this, protectionstate, protecteduntiltime), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LoadConfiguration,
+            CreateDefaultConfiguration,
+            SaveConfiguration,
+            AddRemoveExcludeRule,
+            GetIncludeExcludeRules,
+            GetLocalPolicy,
+            SetLocalPolicy,
+            GetBackupStatus,
+            SetBackupStatus,
+            GetDefaultTarget,
+            ValidateTarget,
+            ProvisionAndSetNewTarget,
+            ChangeDefaultTargetRecommendation,
+            QueryProtectionStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFhConfigMgr as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFhReassociation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ValidateTarget(this: *mut core::ffi::c_void, targeturl: core::mem::MaybeUninit<windows_core::BSTR>, validationresult: *mut FH_DEVICE_VALIDATION_RESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFhReassociation", "ValidateTarget", 3usize, |this| ((**(this as *const *const IFhReassociation_Vtbl)).ValidateTarget)(this, targeturl, validationresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScanTargetForConfigurations(this: *mut core::ffi::c_void, targeturl: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFhReassociation", "ScanTargetForConfigurations", 4usize, |this| ((**(this as *const *const IFhReassociation_Vtbl)).ScanTargetForConfigurations)(this, targeturl), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConfigurationDetails(this: *mut core::ffi::c_void, index: u32, username: *mut core::mem::MaybeUninit<windows_core::BSTR>, pcname: *mut core::mem::MaybeUninit<windows_core::BSTR>, backuptime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFhReassociation", "GetConfigurationDetails", 5usize, |this| ((**(this as *const *const IFhReassociation_Vtbl)).GetConfigurationDetails)(this, index, username, pcname, backuptime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectConfiguration(this: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFhReassociation", "SelectConfiguration", 6usize, |this| ((**(this as *const *const IFhReassociation_Vtbl)).SelectConfiguration)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn PerformReassociation(this: *mut core::ffi::c_void, overwriteifexists: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFhReassociation", "PerformReassociation", 7usize, |this| ((**(this as *const *const IFhReassociation_Vtbl)).PerformReassociation)(this, overwriteifexists), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ValidateTarget,
+            ScanTargetForConfigurations,
+            GetConfigurationDetails,
+            SelectConfiguration,
+            PerformReassociation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFhReassociation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFhScopeIterator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MoveToNextItem(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFhScopeIterator", "MoveToNextItem", 3usize, |this| ((**(this as *const *const IFhScopeIterator_Vtbl)).MoveToNextItem)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItem(this: *mut core::ffi::c_void, item: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFhScopeIterator", "GetItem", 4usize, |this| ((**(this as *const *const IFhScopeIterator_Vtbl)).GetItem)(this, item), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MoveToNextItem, GetItem }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFhScopeIterator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFhTarget_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStringProperty(this: *mut core::ffi::c_void, propertytype: FH_TARGET_PROPERTY_TYPE, propertyvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFhTarget", "GetStringProperty", 3usize, |this| ((**(this as *const *const IFhTarget_Vtbl)).GetStringProperty)(this, propertytype, propertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumericalProperty(this: *mut core::ffi::c_void, propertytype: FH_TARGET_PROPERTY_TYPE, propertyvalue: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFhTarget", "GetNumericalProperty", 4usize, |this| ((**(this as *const *const IFhTarget_Vtbl)).GetNumericalProperty)(this, propertytype, propertyvalue), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStringProperty, GetNumericalProperty }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFhTarget as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
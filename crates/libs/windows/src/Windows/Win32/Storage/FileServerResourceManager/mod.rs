@@ -4452,6 +4452,7 @@ pub struct AdrClientDisplayFlags(pub i32);
 impl windows_core::TypeKind for AdrClientDisplayFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AdrClientDisplayFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AdrClientDisplayFlags").field(&self.0).finish()
@@ -4463,6 +4464,7 @@ pub struct AdrClientErrorType(pub i32);
 impl windows_core::TypeKind for AdrClientErrorType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AdrClientErrorType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AdrClientErrorType").field(&self.0).finish()
@@ -4474,6 +4476,7 @@ pub struct AdrClientFlags(pub i32);
 impl windows_core::TypeKind for AdrClientFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AdrClientFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AdrClientFlags").field(&self.0).finish()
@@ -4485,6 +4488,7 @@ pub struct AdrEmailFlags(pub i32);
 impl windows_core::TypeKind for AdrEmailFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AdrEmailFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AdrEmailFlags").field(&self.0).finish()
@@ -4496,6 +4500,7 @@ pub struct FsrmAccountType(pub i32);
 impl windows_core::TypeKind for FsrmAccountType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmAccountType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmAccountType").field(&self.0).finish()
@@ -4507,6 +4512,7 @@ pub struct FsrmActionType(pub i32);
 impl windows_core::TypeKind for FsrmActionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmActionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmActionType").field(&self.0).finish()
@@ -4518,6 +4524,7 @@ pub struct FsrmClassificationLoggingFlags(pub i32);
 impl windows_core::TypeKind for FsrmClassificationLoggingFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmClassificationLoggingFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmClassificationLoggingFlags").field(&self.0).finish()
@@ -4529,6 +4536,7 @@ pub struct FsrmCollectionState(pub i32);
 impl windows_core::TypeKind for FsrmCollectionState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmCollectionState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmCollectionState").field(&self.0).finish()
@@ -4540,6 +4548,7 @@ pub struct FsrmCommitOptions(pub i32);
 impl windows_core::TypeKind for FsrmCommitOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmCommitOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmCommitOptions").field(&self.0).finish()
@@ -4551,6 +4560,7 @@ pub struct FsrmEnumOptions(pub i32);
 impl windows_core::TypeKind for FsrmEnumOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmEnumOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmEnumOptions").field(&self.0).finish()
@@ -4562,6 +4572,7 @@ pub struct FsrmEventType(pub i32);
 impl windows_core::TypeKind for FsrmEventType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmEventType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmEventType").field(&self.0).finish()
@@ -4573,6 +4584,7 @@ pub struct FsrmExecutionOption(pub i32);
 impl windows_core::TypeKind for FsrmExecutionOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmExecutionOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmExecutionOption").field(&self.0).finish()
@@ -4584,6 +4596,7 @@ pub struct FsrmFileConditionType(pub i32);
 impl windows_core::TypeKind for FsrmFileConditionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmFileConditionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmFileConditionType").field(&self.0).finish()
@@ -4595,6 +4608,7 @@ pub struct FsrmFileManagementLoggingFlags(pub i32);
 impl windows_core::TypeKind for FsrmFileManagementLoggingFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmFileManagementLoggingFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmFileManagementLoggingFlags").field(&self.0).finish()
@@ -4606,6 +4620,7 @@ pub struct FsrmFileManagementType(pub i32);
 impl windows_core::TypeKind for FsrmFileManagementType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmFileManagementType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmFileManagementType").field(&self.0).finish()
@@ -4617,6 +4632,7 @@ pub struct FsrmFileScreenFlags(pub i32);
 impl windows_core::TypeKind for FsrmFileScreenFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmFileScreenFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmFileScreenFlags").field(&self.0).finish()
@@ -4628,6 +4644,7 @@ pub struct FsrmFileStreamingInterfaceType(pub i32);
 impl windows_core::TypeKind for FsrmFileStreamingInterfaceType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmFileStreamingInterfaceType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmFileStreamingInterfaceType").field(&self.0).finish()
@@ -4639,6 +4656,7 @@ pub struct FsrmFileStreamingMode(pub i32);
 impl windows_core::TypeKind for FsrmFileStreamingMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmFileStreamingMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmFileStreamingMode").field(&self.0).finish()
@@ -4650,6 +4668,7 @@ pub struct FsrmFileSystemPropertyId(pub i32);
 impl windows_core::TypeKind for FsrmFileSystemPropertyId {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmFileSystemPropertyId {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmFileSystemPropertyId").field(&self.0).finish()
@@ -4661,6 +4680,7 @@ pub struct FsrmGetFilePropertyOptions(pub i32);
 impl windows_core::TypeKind for FsrmGetFilePropertyOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmGetFilePropertyOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmGetFilePropertyOptions").field(&self.0).finish()
@@ -4672,6 +4692,7 @@ pub struct FsrmPipelineModuleType(pub i32);
 impl windows_core::TypeKind for FsrmPipelineModuleType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmPipelineModuleType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmPipelineModuleType").field(&self.0).finish()
@@ -4683,6 +4704,7 @@ pub struct FsrmPropertyBagField(pub i32);
 impl windows_core::TypeKind for FsrmPropertyBagField {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmPropertyBagField {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmPropertyBagField").field(&self.0).finish()
@@ -4694,6 +4716,7 @@ pub struct FsrmPropertyBagFlags(pub i32);
 impl windows_core::TypeKind for FsrmPropertyBagFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmPropertyBagFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmPropertyBagFlags").field(&self.0).finish()
@@ -4705,6 +4728,7 @@ pub struct FsrmPropertyConditionType(pub i32);
 impl windows_core::TypeKind for FsrmPropertyConditionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmPropertyConditionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmPropertyConditionType").field(&self.0).finish()
@@ -4716,6 +4740,7 @@ pub struct FsrmPropertyDefinitionAppliesTo(pub i32);
 impl windows_core::TypeKind for FsrmPropertyDefinitionAppliesTo {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmPropertyDefinitionAppliesTo {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmPropertyDefinitionAppliesTo").field(&self.0).finish()
@@ -4727,6 +4752,7 @@ pub struct FsrmPropertyDefinitionFlags(pub i32);
 impl windows_core::TypeKind for FsrmPropertyDefinitionFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmPropertyDefinitionFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmPropertyDefinitionFlags").field(&self.0).finish()
@@ -4738,6 +4764,7 @@ pub struct FsrmPropertyDefinitionType(pub i32);
 impl windows_core::TypeKind for FsrmPropertyDefinitionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmPropertyDefinitionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmPropertyDefinitionType").field(&self.0).finish()
@@ -4749,6 +4776,7 @@ pub struct FsrmPropertyFlags(pub i32);
 impl windows_core::TypeKind for FsrmPropertyFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmPropertyFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmPropertyFlags").field(&self.0).finish()
@@ -4760,6 +4788,7 @@ pub struct FsrmPropertyValueType(pub i32);
 impl windows_core::TypeKind for FsrmPropertyValueType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmPropertyValueType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmPropertyValueType").field(&self.0).finish()
@@ -4771,6 +4800,7 @@ pub struct FsrmQuotaFlags(pub i32);
 impl windows_core::TypeKind for FsrmQuotaFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmQuotaFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmQuotaFlags").field(&self.0).finish()
@@ -4782,6 +4812,7 @@ pub struct FsrmReportFilter(pub i32);
 impl windows_core::TypeKind for FsrmReportFilter {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmReportFilter {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmReportFilter").field(&self.0).finish()
@@ -4793,6 +4824,7 @@ pub struct FsrmReportFormat(pub i32);
 impl windows_core::TypeKind for FsrmReportFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmReportFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmReportFormat").field(&self.0).finish()
@@ -4804,6 +4836,7 @@ pub struct FsrmReportGenerationContext(pub i32);
 impl windows_core::TypeKind for FsrmReportGenerationContext {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmReportGenerationContext {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmReportGenerationContext").field(&self.0).finish()
@@ -4815,6 +4848,7 @@ pub struct FsrmReportLimit(pub i32);
 impl windows_core::TypeKind for FsrmReportLimit {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmReportLimit {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmReportLimit").field(&self.0).finish()
@@ -4826,6 +4860,7 @@ pub struct FsrmReportRunningStatus(pub i32);
 impl windows_core::TypeKind for FsrmReportRunningStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmReportRunningStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmReportRunningStatus").field(&self.0).finish()
@@ -4837,6 +4872,7 @@ pub struct FsrmReportType(pub i32);
 impl windows_core::TypeKind for FsrmReportType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmReportType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmReportType").field(&self.0).finish()
@@ -4848,6 +4884,7 @@ pub struct FsrmRuleFlags(pub i32);
 impl windows_core::TypeKind for FsrmRuleFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmRuleFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmRuleFlags").field(&self.0).finish()
@@ -4859,6 +4896,7 @@ pub struct FsrmRuleType(pub i32);
 impl windows_core::TypeKind for FsrmRuleType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmRuleType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmRuleType").field(&self.0).finish()
@@ -4870,6 +4908,7 @@ pub struct FsrmStorageModuleCaps(pub i32);
 impl windows_core::TypeKind for FsrmStorageModuleCaps {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmStorageModuleCaps {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmStorageModuleCaps").field(&self.0).finish()
@@ -4881,6 +4920,7 @@ pub struct FsrmStorageModuleType(pub i32);
 impl windows_core::TypeKind for FsrmStorageModuleType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmStorageModuleType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmStorageModuleType").field(&self.0).finish()
@@ -4892,6 +4932,7 @@ pub struct FsrmTemplateApplyOptions(pub i32);
 impl windows_core::TypeKind for FsrmTemplateApplyOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsrmTemplateApplyOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsrmTemplateApplyOptions").field(&self.0).finish()
@@ -4914,3 +4955,5 @@ pub const FsrmReportScheduler: windows_core::GUID = windows_core::GUID::from_u12
 pub const FsrmSetting: windows_core::GUID = windows_core::GUID::from_u128(0xf556d708_6d4d_4594_9c61_7dbb0dae2a46);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,2939 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for DIFsrmClassificationEvents_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<DIFsrmClassificationEvents as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmAccessDeniedRemediationClient_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Show(this: *mut core::ffi::c_void, parentwnd: usize, accesspath: core::mem::MaybeUninit<windows_core::BSTR>, errortype: AdrClientErrorType, flags: i32, windowtitle: core::mem::MaybeUninit<windows_core::BSTR>, windowmessage: core::mem::MaybeUninit<windows_core::BSTR>, result: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmAccessDeniedRemediationClient", "Show", 7usize, |this| ((**(this as *const *const IFsrmAccessDeniedRemediationClient_Vtbl)).Show)(this, parentwnd, accesspath, errortype, flags, windowtitle, windowmessage, result), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Show }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmAccessDeniedRemediationClient as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmAction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, id: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmAction", "Id", 7usize, |this| ((**(this as *const *const IFsrmAction_Vtbl)).Id)(this, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActionType(this: *mut core::ffi::c_void, actiontype: *mut FsrmActionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmAction", "ActionType", 8usize, |this| ((**(this as *const *const IFsrmAction_Vtbl)).ActionType)(this, actiontype), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunLimitInterval(this: *mut core::ffi::c_void, minutes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmAction", "RunLimitInterval", 9usize, |this| ((**(this as *const *const IFsrmAction_Vtbl)).RunLimitInterval)(this, minutes), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRunLimitInterval(this: *mut core::ffi::c_void, minutes: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmAction", "SetRunLimitInterval", 10usize, |this| ((**(this as *const *const IFsrmAction_Vtbl)).SetRunLimitInterval)(this, minutes), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmAction", "Delete", 11usize, |this| ((**(this as *const *const IFsrmAction_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            ActionType,
+            RunLimitInterval,
+            SetRunLimitInterval,
+            Delete,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmAction as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmActionCommand_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExecutablePath(this: *mut core::ffi::c_void, executablepath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionCommand", "ExecutablePath", 12usize, |this| ((**(this as *const *const IFsrmActionCommand_Vtbl)).ExecutablePath)(this, executablepath), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExecutablePath(this: *mut core::ffi::c_void, executablepath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionCommand", "SetExecutablePath", 13usize, |this| ((**(this as *const *const IFsrmActionCommand_Vtbl)).SetExecutablePath)(this, executablepath), |result| Some(*result))
+        }
+        unsafe extern "system" fn Arguments(this: *mut core::ffi::c_void, arguments: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionCommand", "Arguments", 14usize, |this| ((**(this as *const *const IFsrmActionCommand_Vtbl)).Arguments)(this, arguments), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetArguments(this: *mut core::ffi::c_void, arguments: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionCommand", "SetArguments", 15usize, |this| ((**(this as *const *const IFsrmActionCommand_Vtbl)).SetArguments)(this, arguments), |result| Some(*result))
+        }
+        unsafe extern "system" fn Account(this: *mut core::ffi::c_void, account: *mut FsrmAccountType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionCommand", "Account", 16usize, |this| ((**(this as *const *const IFsrmActionCommand_Vtbl)).Account)(this, account), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAccount(this: *mut core::ffi::c_void, account: FsrmAccountType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionCommand", "SetAccount", 17usize, |this| ((**(this as *const *const IFsrmActionCommand_Vtbl)).SetAccount)(this, account), |result| Some(*result))
+        }
+        unsafe extern "system" fn WorkingDirectory(this: *mut core::ffi::c_void, workingdirectory: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionCommand", "WorkingDirectory", 18usize, |this| ((**(this as *const *const IFsrmActionCommand_Vtbl)).WorkingDirectory)(this, workingdirectory), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWorkingDirectory(this: *mut core::ffi::c_void, workingdirectory: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionCommand", "SetWorkingDirectory", 19usize, |this| ((**(this as *const *const IFsrmActionCommand_Vtbl)).SetWorkingDirectory)(this, workingdirectory), |result| Some(*result))
+        }
+        unsafe extern "system" fn MonitorCommand(this: *mut core::ffi::c_void, monitorcommand: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionCommand", "MonitorCommand", 20usize, |this| ((**(this as *const *const IFsrmActionCommand_Vtbl)).MonitorCommand)(this, monitorcommand), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMonitorCommand(this: *mut core::ffi::c_void, monitorcommand: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionCommand", "SetMonitorCommand", 21usize, |this| ((**(this as *const *const IFsrmActionCommand_Vtbl)).SetMonitorCommand)(this, monitorcommand), |result| Some(*result))
+        }
+        unsafe extern "system" fn KillTimeOut(this: *mut core::ffi::c_void, minutes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionCommand", "KillTimeOut", 22usize, |this| ((**(this as *const *const IFsrmActionCommand_Vtbl)).KillTimeOut)(this, minutes), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKillTimeOut(this: *mut core::ffi::c_void, minutes: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionCommand", "SetKillTimeOut", 23usize, |this| ((**(this as *const *const IFsrmActionCommand_Vtbl)).SetKillTimeOut)(this, minutes), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogResult(this: *mut core::ffi::c_void, logresults: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionCommand", "LogResult", 24usize, |this| ((**(this as *const *const IFsrmActionCommand_Vtbl)).LogResult)(this, logresults), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLogResult(this: *mut core::ffi::c_void, logresults: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionCommand", "SetLogResult", 25usize, |this| ((**(this as *const *const IFsrmActionCommand_Vtbl)).SetLogResult)(this, logresults), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmAction_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ExecutablePath,
+            SetExecutablePath,
+            Arguments,
+            SetArguments,
+            Account,
+            SetAccount,
+            WorkingDirectory,
+            SetWorkingDirectory,
+            MonitorCommand,
+            SetMonitorCommand,
+            KillTimeOut,
+            SetKillTimeOut,
+            LogResult,
+            SetLogResult,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmActionCommand as windows_core::Interface>::IID || <IFsrmAction_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmActionEmail_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MailFrom(this: *mut core::ffi::c_void, mailfrom: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionEmail", "MailFrom", 12usize, |this| ((**(this as *const *const IFsrmActionEmail_Vtbl)).MailFrom)(this, mailfrom), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMailFrom(this: *mut core::ffi::c_void, mailfrom: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionEmail", "SetMailFrom", 13usize, |this| ((**(this as *const *const IFsrmActionEmail_Vtbl)).SetMailFrom)(this, mailfrom), |result| Some(*result))
+        }
+        unsafe extern "system" fn MailReplyTo(this: *mut core::ffi::c_void, mailreplyto: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionEmail", "MailReplyTo", 14usize, |this| ((**(this as *const *const IFsrmActionEmail_Vtbl)).MailReplyTo)(this, mailreplyto), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMailReplyTo(this: *mut core::ffi::c_void, mailreplyto: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionEmail", "SetMailReplyTo", 15usize, |this| ((**(this as *const *const IFsrmActionEmail_Vtbl)).SetMailReplyTo)(this, mailreplyto), |result| Some(*result))
+        }
+        unsafe extern "system" fn MailTo(this: *mut core::ffi::c_void, mailto: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionEmail", "MailTo", 16usize, |this| ((**(this as *const *const IFsrmActionEmail_Vtbl)).MailTo)(this, mailto), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMailTo(this: *mut core::ffi::c_void, mailto: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionEmail", "SetMailTo", 17usize, |this| ((**(this as *const *const IFsrmActionEmail_Vtbl)).SetMailTo)(this, mailto), |result| Some(*result))
+        }
+        unsafe extern "system" fn MailCc(this: *mut core::ffi::c_void, mailcc: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionEmail", "MailCc", 18usize, |this| ((**(this as *const *const IFsrmActionEmail_Vtbl)).MailCc)(this, mailcc), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMailCc(this: *mut core::ffi::c_void, mailcc: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionEmail", "SetMailCc", 19usize, |this| ((**(this as *const *const IFsrmActionEmail_Vtbl)).SetMailCc)(this, mailcc), |result| Some(*result))
+        }
+        unsafe extern "system" fn MailBcc(this: *mut core::ffi::c_void, mailbcc: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionEmail", "MailBcc", 20usize, |this| ((**(this as *const *const IFsrmActionEmail_Vtbl)).MailBcc)(this, mailbcc), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMailBcc(this: *mut core::ffi::c_void, mailbcc: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionEmail", "SetMailBcc", 21usize, |this| ((**(this as *const *const IFsrmActionEmail_Vtbl)).SetMailBcc)(this, mailbcc), |result| Some(*result))
+        }
+        unsafe extern "system" fn MailSubject(this: *mut core::ffi::c_void, mailsubject: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionEmail", "MailSubject", 22usize, |this| ((**(this as *const *const IFsrmActionEmail_Vtbl)).MailSubject)(this, mailsubject), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMailSubject(this: *mut core::ffi::c_void, mailsubject: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionEmail", "SetMailSubject", 23usize, |this| ((**(this as *const *const IFsrmActionEmail_Vtbl)).SetMailSubject)(this, mailsubject), |result| Some(*result))
+        }
+        unsafe extern "system" fn MessageText(this: *mut core::ffi::c_void, messagetext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionEmail", "MessageText", 24usize, |this| ((**(this as *const *const IFsrmActionEmail_Vtbl)).MessageText)(this, messagetext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMessageText(this: *mut core::ffi::c_void, messagetext: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionEmail", "SetMessageText", 25usize, |this| ((**(this as *const *const IFsrmActionEmail_Vtbl)).SetMessageText)(this, messagetext), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmAction_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MailFrom,
+            SetMailFrom,
+            MailReplyTo,
+            SetMailReplyTo,
+            MailTo,
+            SetMailTo,
+            MailCc,
+            SetMailCc,
+            MailBcc,
+            SetMailBcc,
+            MailSubject,
+            SetMailSubject,
+            MessageText,
+            SetMessageText,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmActionEmail as windows_core::Interface>::IID || <IFsrmAction_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmActionEmail2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AttachmentFileListSize(this: *mut core::ffi::c_void, attachmentfilelistsize: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionEmail2", "AttachmentFileListSize", 26usize, |this| ((**(this as *const *const IFsrmActionEmail2_Vtbl)).AttachmentFileListSize)(this, attachmentfilelistsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAttachmentFileListSize(this: *mut core::ffi::c_void, attachmentfilelistsize: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionEmail2", "SetAttachmentFileListSize", 27usize, |this| ((**(this as *const *const IFsrmActionEmail2_Vtbl)).SetAttachmentFileListSize)(this, attachmentfilelistsize), |result| Some(*result))
+        }
+        Self { base__: <IFsrmActionEmail_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AttachmentFileListSize, SetAttachmentFileListSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmActionEmail2 as windows_core::Interface>::IID || <IFsrmActionEmail_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmActionEventLog_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EventType(this: *mut core::ffi::c_void, eventtype: *mut FsrmEventType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionEventLog", "EventType", 12usize, |this| ((**(this as *const *const IFsrmActionEventLog_Vtbl)).EventType)(this, eventtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventType(this: *mut core::ffi::c_void, eventtype: FsrmEventType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionEventLog", "SetEventType", 13usize, |this| ((**(this as *const *const IFsrmActionEventLog_Vtbl)).SetEventType)(this, eventtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn MessageText(this: *mut core::ffi::c_void, messagetext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionEventLog", "MessageText", 14usize, |this| ((**(this as *const *const IFsrmActionEventLog_Vtbl)).MessageText)(this, messagetext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMessageText(this: *mut core::ffi::c_void, messagetext: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionEventLog", "SetMessageText", 15usize, |this| ((**(this as *const *const IFsrmActionEventLog_Vtbl)).SetMessageText)(this, messagetext), |result| Some(*result))
+        }
+        Self { base__: <IFsrmAction_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EventType, SetEventType, MessageText, SetMessageText }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmActionEventLog as windows_core::Interface>::IID || <IFsrmAction_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmActionReport_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ReportTypes(this: *mut core::ffi::c_void, reporttypes: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionReport", "ReportTypes", 12usize, |this| ((**(this as *const *const IFsrmActionReport_Vtbl)).ReportTypes)(this, reporttypes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetReportTypes(this: *mut core::ffi::c_void, reporttypes: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionReport", "SetReportTypes", 13usize, |this| ((**(this as *const *const IFsrmActionReport_Vtbl)).SetReportTypes)(this, reporttypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn MailTo(this: *mut core::ffi::c_void, mailto: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionReport", "MailTo", 14usize, |this| ((**(this as *const *const IFsrmActionReport_Vtbl)).MailTo)(this, mailto), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMailTo(this: *mut core::ffi::c_void, mailto: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmActionReport", "SetMailTo", 15usize, |this| ((**(this as *const *const IFsrmActionReport_Vtbl)).SetMailTo)(this, mailto), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmAction_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            ReportTypes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ReportTypes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetReportTypes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetReportTypes: 0,
+            MailTo,
+            SetMailTo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmActionReport as windows_core::Interface>::IID || <IFsrmAction_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmAutoApplyQuota_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ExcludeFolders(this: *mut core::ffi::c_void, folders: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmAutoApplyQuota", "ExcludeFolders", 28usize, |this| ((**(this as *const *const IFsrmAutoApplyQuota_Vtbl)).ExcludeFolders)(this, folders), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetExcludeFolders(this: *mut core::ffi::c_void, folders: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmAutoApplyQuota", "SetExcludeFolders", 29usize, |this| ((**(this as *const *const IFsrmAutoApplyQuota_Vtbl)).SetExcludeFolders)(this, folders), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CommitAndUpdateDerived(this: *mut core::ffi::c_void, commitoptions: FsrmCommitOptions, applyoptions: FsrmTemplateApplyOptions, derivedobjectsresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmAutoApplyQuota", "CommitAndUpdateDerived", 30usize, |this| ((**(this as *const *const IFsrmAutoApplyQuota_Vtbl)).CommitAndUpdateDerived)(this, commitoptions, applyoptions, derivedobjectsresult), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmQuotaObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            ExcludeFolders,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ExcludeFolders: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetExcludeFolders,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetExcludeFolders: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CommitAndUpdateDerived,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CommitAndUpdateDerived: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmAutoApplyQuota as windows_core::Interface>::IID || <IFsrmQuotaObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmClassificationManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ClassificationReportFormats(this: *mut core::ffi::c_void, formats: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationManager", "ClassificationReportFormats", 7usize, |this| ((**(this as *const *const IFsrmClassificationManager_Vtbl)).ClassificationReportFormats)(this, formats), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetClassificationReportFormats(this: *mut core::ffi::c_void, formats: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationManager", "SetClassificationReportFormats", 8usize, |this| ((**(this as *const *const IFsrmClassificationManager_Vtbl)).SetClassificationReportFormats)(this, formats), |result| Some(*result))
+        }
+        unsafe extern "system" fn Logging(this: *mut core::ffi::c_void, logging: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationManager", "Logging", 9usize, |this| ((**(this as *const *const IFsrmClassificationManager_Vtbl)).Logging)(this, logging), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLogging(this: *mut core::ffi::c_void, logging: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationManager", "SetLogging", 10usize, |this| ((**(this as *const *const IFsrmClassificationManager_Vtbl)).SetLogging)(this, logging), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClassificationReportMailTo(this: *mut core::ffi::c_void, mailto: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationManager", "ClassificationReportMailTo", 11usize, |this| ((**(this as *const *const IFsrmClassificationManager_Vtbl)).ClassificationReportMailTo)(this, mailto), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClassificationReportMailTo(this: *mut core::ffi::c_void, mailto: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationManager", "SetClassificationReportMailTo", 12usize, |this| ((**(this as *const *const IFsrmClassificationManager_Vtbl)).SetClassificationReportMailTo)(this, mailto), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClassificationReportEnabled(this: *mut core::ffi::c_void, reportenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationManager", "ClassificationReportEnabled", 13usize, |this| ((**(this as *const *const IFsrmClassificationManager_Vtbl)).ClassificationReportEnabled)(this, reportenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClassificationReportEnabled(this: *mut core::ffi::c_void, reportenabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationManager", "SetClassificationReportEnabled", 14usize, |this| ((**(this as *const *const IFsrmClassificationManager_Vtbl)).SetClassificationReportEnabled)(this, reportenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClassificationLastReportPathWithoutExtension(this: *mut core::ffi::c_void, lastreportpath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationManager", "ClassificationLastReportPathWithoutExtension", 15usize, |this| ((**(this as *const *const IFsrmClassificationManager_Vtbl)).ClassificationLastReportPathWithoutExtension)(this, lastreportpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClassificationLastError(this: *mut core::ffi::c_void, lasterror: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationManager", "ClassificationLastError", 16usize, |this| ((**(this as *const *const IFsrmClassificationManager_Vtbl)).ClassificationLastError)(this, lasterror), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClassificationRunningStatus(this: *mut core::ffi::c_void, runningstatus: *mut FsrmReportRunningStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationManager", "ClassificationRunningStatus", 17usize, |this| ((**(this as *const *const IFsrmClassificationManager_Vtbl)).ClassificationRunningStatus)(this, runningstatus), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumPropertyDefinitions(this: *mut core::ffi::c_void, options: FsrmEnumOptions, propertydefinitions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationManager", "EnumPropertyDefinitions", 18usize, |this| ((**(this as *const *const IFsrmClassificationManager_Vtbl)).EnumPropertyDefinitions)(this, options, propertydefinitions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreatePropertyDefinition(this: *mut core::ffi::c_void, propertydefinition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationManager", "CreatePropertyDefinition", 19usize, |this| ((**(this as *const *const IFsrmClassificationManager_Vtbl)).CreatePropertyDefinition)(this, propertydefinition), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetPropertyDefinition(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::BSTR>, propertydefinition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationManager", "GetPropertyDefinition", 20usize, |this| ((**(this as *const *const IFsrmClassificationManager_Vtbl)).GetPropertyDefinition)(this, propertyname, propertydefinition), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumRules(this: *mut core::ffi::c_void, ruletype: FsrmRuleType, options: FsrmEnumOptions, rules: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationManager", "EnumRules", 21usize, |this| ((**(this as *const *const IFsrmClassificationManager_Vtbl)).EnumRules)(this, ruletype, options, rules), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateRule(this: *mut core::ffi::c_void, ruletype: FsrmRuleType, rule: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationManager", "CreateRule", 22usize, |this| ((**(this as *const *const IFsrmClassificationManager_Vtbl)).CreateRule)(this, ruletype, rule), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetRule(this: *mut core::ffi::c_void, rulename: core::mem::MaybeUninit<windows_core::BSTR>, ruletype: FsrmRuleType, rule: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationManager", "GetRule", 23usize, |this| ((**(this as *const *const IFsrmClassificationManager_Vtbl)).GetRule)(this, rulename, ruletype, rule), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumModuleDefinitions(this: *mut core::ffi::c_void, moduletype: FsrmPipelineModuleType, options: FsrmEnumOptions, moduledefinitions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationManager", "EnumModuleDefinitions", 24usize, |this| ((**(this as *const *const IFsrmClassificationManager_Vtbl)).EnumModuleDefinitions)(this, moduletype, options, moduledefinitions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateModuleDefinition(this: *mut core::ffi::c_void, moduletype: FsrmPipelineModuleType, moduledefinition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationManager", "CreateModuleDefinition", 25usize, |this| ((**(this as *const *const IFsrmClassificationManager_Vtbl)).CreateModuleDefinition)(this, moduletype, moduledefinition), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetModuleDefinition(this: *mut core::ffi::c_void, modulename: core::mem::MaybeUninit<windows_core::BSTR>, moduletype: FsrmPipelineModuleType, moduledefinition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationManager", "GetModuleDefinition", 26usize, |this| ((**(this as *const *const IFsrmClassificationManager_Vtbl)).GetModuleDefinition)(this, modulename, moduletype, moduledefinition), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunClassification(this: *mut core::ffi::c_void, context: FsrmReportGenerationContext, reserved: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationManager", "RunClassification", 27usize, |this| ((**(this as *const *const IFsrmClassificationManager_Vtbl)).RunClassification)(this, context, reserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForClassificationCompletion(this: *mut core::ffi::c_void, waitseconds: i32, completed: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationManager", "WaitForClassificationCompletion", 28usize, |this| ((**(this as *const *const IFsrmClassificationManager_Vtbl)).WaitForClassificationCompletion)(this, waitseconds, completed), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelClassification(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationManager", "CancelClassification", 29usize, |this| ((**(this as *const *const IFsrmClassificationManager_Vtbl)).CancelClassification)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumFileProperties(this: *mut core::ffi::c_void, filepath: core::mem::MaybeUninit<windows_core::BSTR>, options: FsrmGetFilePropertyOptions, fileproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationManager", "EnumFileProperties", 30usize, |this| ((**(this as *const *const IFsrmClassificationManager_Vtbl)).EnumFileProperties)(this, filepath, options, fileproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFileProperty(this: *mut core::ffi::c_void, filepath: core::mem::MaybeUninit<windows_core::BSTR>, propertyname: core::mem::MaybeUninit<windows_core::BSTR>, options: FsrmGetFilePropertyOptions, property: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationManager", "GetFileProperty", 31usize, |this| ((**(this as *const *const IFsrmClassificationManager_Vtbl)).GetFileProperty)(this, filepath, propertyname, options, property), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFileProperty(this: *mut core::ffi::c_void, filepath: core::mem::MaybeUninit<windows_core::BSTR>, propertyname: core::mem::MaybeUninit<windows_core::BSTR>, propertyvalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationManager", "SetFileProperty", 32usize, |this| ((**(this as *const *const IFsrmClassificationManager_Vtbl)).SetFileProperty)(this, filepath, propertyname, propertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearFileProperty(this: *mut core::ffi::c_void, filepath: core::mem::MaybeUninit<windows_core::BSTR>, property: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationManager", "ClearFileProperty", 33usize, |this| ((**(this as *const *const IFsrmClassificationManager_Vtbl)).ClearFileProperty)(this, filepath, property), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            ClassificationReportFormats,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ClassificationReportFormats: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetClassificationReportFormats,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetClassificationReportFormats: 0,
+            Logging,
+            SetLogging,
+            ClassificationReportMailTo,
+            SetClassificationReportMailTo,
+            ClassificationReportEnabled,
+            SetClassificationReportEnabled,
+            ClassificationLastReportPathWithoutExtension,
+            ClassificationLastError,
+            ClassificationRunningStatus,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumPropertyDefinitions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumPropertyDefinitions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreatePropertyDefinition,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreatePropertyDefinition: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetPropertyDefinition,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetPropertyDefinition: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumRules,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumRules: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateRule,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateRule: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetRule,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetRule: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumModuleDefinitions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumModuleDefinitions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateModuleDefinition,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateModuleDefinition: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetModuleDefinition,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetModuleDefinition: 0,
+            RunClassification,
+            WaitForClassificationCompletion,
+            CancelClassification,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumFileProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumFileProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFileProperty,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFileProperty: 0,
+            SetFileProperty,
+            ClearFileProperty,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmClassificationManager as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmClassificationManager2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ClassifyFiles(this: *mut core::ffi::c_void, filepaths: *const super::super::System::Com::SAFEARRAY, propertynames: *const super::super::System::Com::SAFEARRAY, propertyvalues: *const super::super::System::Com::SAFEARRAY, options: FsrmGetFilePropertyOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationManager2", "ClassifyFiles", 34usize, |this| ((**(this as *const *const IFsrmClassificationManager2_Vtbl)).ClassifyFiles)(this, filepaths, propertynames, propertyvalues, options), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmClassificationManager_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            ClassifyFiles,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ClassifyFiles: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmClassificationManager2 as windows_core::Interface>::IID || <IFsrmClassificationManager_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmClassificationRule_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExecutionOption(this: *mut core::ffi::c_void, executionoption: *mut FsrmExecutionOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationRule", "ExecutionOption", 24usize, |this| ((**(this as *const *const IFsrmClassificationRule_Vtbl)).ExecutionOption)(this, executionoption), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExecutionOption(this: *mut core::ffi::c_void, executionoption: FsrmExecutionOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationRule", "SetExecutionOption", 25usize, |this| ((**(this as *const *const IFsrmClassificationRule_Vtbl)).SetExecutionOption)(this, executionoption), |result| Some(*result))
+        }
+        unsafe extern "system" fn PropertyAffected(this: *mut core::ffi::c_void, property: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationRule", "PropertyAffected", 26usize, |this| ((**(this as *const *const IFsrmClassificationRule_Vtbl)).PropertyAffected)(this, property), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPropertyAffected(this: *mut core::ffi::c_void, property: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationRule", "SetPropertyAffected", 27usize, |this| ((**(this as *const *const IFsrmClassificationRule_Vtbl)).SetPropertyAffected)(this, property), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationRule", "Value", 28usize, |this| ((**(this as *const *const IFsrmClassificationRule_Vtbl)).Value)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassificationRule", "SetValue", 29usize, |this| ((**(this as *const *const IFsrmClassificationRule_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmRule_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ExecutionOption,
+            SetExecutionOption,
+            PropertyAffected,
+            SetPropertyAffected,
+            Value,
+            SetValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmClassificationRule as windows_core::Interface>::IID || <IFsrmRule_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmClassifierModuleDefinition_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PropertiesAffected(this: *mut core::ffi::c_void, propertiesaffected: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassifierModuleDefinition", "PropertiesAffected", 31usize, |this| ((**(this as *const *const IFsrmClassifierModuleDefinition_Vtbl)).PropertiesAffected)(this, propertiesaffected), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetPropertiesAffected(this: *mut core::ffi::c_void, propertiesaffected: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassifierModuleDefinition", "SetPropertiesAffected", 32usize, |this| ((**(this as *const *const IFsrmClassifierModuleDefinition_Vtbl)).SetPropertiesAffected)(this, propertiesaffected), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PropertiesUsed(this: *mut core::ffi::c_void, propertiesused: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassifierModuleDefinition", "PropertiesUsed", 33usize, |this| ((**(this as *const *const IFsrmClassifierModuleDefinition_Vtbl)).PropertiesUsed)(this, propertiesused), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetPropertiesUsed(this: *mut core::ffi::c_void, propertiesused: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassifierModuleDefinition", "SetPropertiesUsed", 34usize, |this| ((**(this as *const *const IFsrmClassifierModuleDefinition_Vtbl)).SetPropertiesUsed)(this, propertiesused), |result| Some(*result))
+        }
+        unsafe extern "system" fn NeedsExplicitValue(this: *mut core::ffi::c_void, needsexplicitvalue: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassifierModuleDefinition", "NeedsExplicitValue", 35usize, |this| ((**(this as *const *const IFsrmClassifierModuleDefinition_Vtbl)).NeedsExplicitValue)(this, needsexplicitvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNeedsExplicitValue(this: *mut core::ffi::c_void, needsexplicitvalue: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassifierModuleDefinition", "SetNeedsExplicitValue", 36usize, |this| ((**(this as *const *const IFsrmClassifierModuleDefinition_Vtbl)).SetNeedsExplicitValue)(this, needsexplicitvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmPipelineModuleDefinition_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            PropertiesAffected,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PropertiesAffected: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetPropertiesAffected,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetPropertiesAffected: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PropertiesUsed,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PropertiesUsed: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetPropertiesUsed,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetPropertiesUsed: 0,
+            NeedsExplicitValue,
+            SetNeedsExplicitValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmClassifierModuleDefinition as windows_core::Interface>::IID || <IFsrmPipelineModuleDefinition_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmClassifierModuleImplementation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LastModified(this: *mut core::ffi::c_void, lastmodified: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassifierModuleImplementation", "LastModified", 9usize, |this| ((**(this as *const *const IFsrmClassifierModuleImplementation_Vtbl)).LastModified)(this, lastmodified), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn UseRulesAndDefinitions(this: *mut core::ffi::c_void, rules: *mut core::ffi::c_void, propertydefinitions: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassifierModuleImplementation", "UseRulesAndDefinitions", 10usize, |this| ((**(this as *const *const IFsrmClassifierModuleImplementation_Vtbl)).UseRulesAndDefinitions)(this, rules, propertydefinitions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnBeginFile(this: *mut core::ffi::c_void, propertybag: *mut core::ffi::c_void, arrayruleids: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassifierModuleImplementation", "OnBeginFile", 11usize, |this| ((**(this as *const *const IFsrmClassifierModuleImplementation_Vtbl)).OnBeginFile)(this, propertybag, arrayruleids), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoesPropertyValueApply(this: *mut core::ffi::c_void, property: core::mem::MaybeUninit<windows_core::BSTR>, value: core::mem::MaybeUninit<windows_core::BSTR>, applyvalue: *mut super::super::Foundation::VARIANT_BOOL, idrule: windows_core::GUID, idpropdef: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassifierModuleImplementation", "DoesPropertyValueApply", 12usize, |this| ((**(this as *const *const IFsrmClassifierModuleImplementation_Vtbl)).DoesPropertyValueApply)(this, property, value, applyvalue, idrule, idpropdef), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyValueToApply(this: *mut core::ffi::c_void, property: core::mem::MaybeUninit<windows_core::BSTR>, value: *mut core::mem::MaybeUninit<windows_core::BSTR>, idrule: windows_core::GUID, idpropdef: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassifierModuleImplementation", "GetPropertyValueToApply", 13usize, |this| ((**(this as *const *const IFsrmClassifierModuleImplementation_Vtbl)).GetPropertyValueToApply)(this, property, value, idrule, idpropdef), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnEndFile(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmClassifierModuleImplementation", "OnEndFile", 14usize, |this| ((**(this as *const *const IFsrmClassifierModuleImplementation_Vtbl)).OnEndFile)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmPipelineModuleImplementation_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LastModified,
+            #[cfg(feature = "Win32_System_Com")]
+            UseRulesAndDefinitions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            UseRulesAndDefinitions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnBeginFile,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnBeginFile: 0,
+            DoesPropertyValueApply,
+            GetPropertyValueToApply,
+            OnEndFile,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmClassifierModuleImplementation as windows_core::Interface>::IID || <IFsrmPipelineModuleImplementation_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, unknown: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmCollection", "_NewEnum", 7usize, |this| ((**(this as *const *const IFsrmCollection_Vtbl))._NewEnum)(this, unknown), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, item: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmCollection", "get_Item", 8usize, |this| ((**(this as *const *const IFsrmCollection_Vtbl)).get_Item)(this, index, item), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmCollection", "Count", 9usize, |this| ((**(this as *const *const IFsrmCollection_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, state: *mut FsrmCollectionState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmCollection", "State", 10usize, |this| ((**(this as *const *const IFsrmCollection_Vtbl)).State)(this, state), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmCollection", "Cancel", 11usize, |this| ((**(this as *const *const IFsrmCollection_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForCompletion(this: *mut core::ffi::c_void, waitseconds: i32, completed: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmCollection", "WaitForCompletion", 12usize, |this| ((**(this as *const *const IFsrmCollection_Vtbl)).WaitForCompletion)(this, waitseconds, completed), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetById(this: *mut core::ffi::c_void, id: windows_core::GUID, entry: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmCollection", "GetById", 13usize, |this| ((**(this as *const *const IFsrmCollection_Vtbl)).GetById)(this, id, entry), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            get_Item,
+            Count,
+            State,
+            Cancel,
+            WaitForCompletion,
+            GetById,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmCollection as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmCommittableCollection_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void, options: FsrmCommitOptions, results: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmCommittableCollection", "Commit", 18usize, |this| ((**(this as *const *const IFsrmCommittableCollection_Vtbl)).Commit)(this, options, results), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmMutableCollection_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Commit,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Commit: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmCommittableCollection as windows_core::Interface>::IID || <IFsrmMutableCollection_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmDerivedObjectsResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DerivedObjects(this: *mut core::ffi::c_void, derivedobjects: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmDerivedObjectsResult", "DerivedObjects", 7usize, |this| ((**(this as *const *const IFsrmDerivedObjectsResult_Vtbl)).DerivedObjects)(this, derivedobjects), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Results(this: *mut core::ffi::c_void, results: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmDerivedObjectsResult", "Results", 8usize, |this| ((**(this as *const *const IFsrmDerivedObjectsResult_Vtbl)).Results)(this, results), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            DerivedObjects,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DerivedObjects: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Results,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Results: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmDerivedObjectsResult as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmExportImport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExportFileGroups(this: *mut core::ffi::c_void, filepath: core::mem::MaybeUninit<windows_core::BSTR>, filegroupnamessafearray: *const core::mem::MaybeUninit<windows_core::VARIANT>, remotehost: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmExportImport", "ExportFileGroups", 7usize, |this| ((**(this as *const *const IFsrmExportImport_Vtbl)).ExportFileGroups)(this, filepath, filegroupnamessafearray, remotehost), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ImportFileGroups(this: *mut core::ffi::c_void, filepath: core::mem::MaybeUninit<windows_core::BSTR>, filegroupnamessafearray: *const core::mem::MaybeUninit<windows_core::VARIANT>, remotehost: core::mem::MaybeUninit<windows_core::BSTR>, filegroups: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmExportImport", "ImportFileGroups", 8usize, |this| ((**(this as *const *const IFsrmExportImport_Vtbl)).ImportFileGroups)(this, filepath, filegroupnamessafearray, remotehost, filegroups), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExportFileScreenTemplates(this: *mut core::ffi::c_void, filepath: core::mem::MaybeUninit<windows_core::BSTR>, templatenamessafearray: *const core::mem::MaybeUninit<windows_core::VARIANT>, remotehost: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmExportImport", "ExportFileScreenTemplates", 9usize, |this| ((**(this as *const *const IFsrmExportImport_Vtbl)).ExportFileScreenTemplates)(this, filepath, templatenamessafearray, remotehost), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ImportFileScreenTemplates(this: *mut core::ffi::c_void, filepath: core::mem::MaybeUninit<windows_core::BSTR>, templatenamessafearray: *const core::mem::MaybeUninit<windows_core::VARIANT>, remotehost: core::mem::MaybeUninit<windows_core::BSTR>, templates: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmExportImport", "ImportFileScreenTemplates", 10usize, |this| ((**(this as *const *const IFsrmExportImport_Vtbl)).ImportFileScreenTemplates)(this, filepath, templatenamessafearray, remotehost, templates), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExportQuotaTemplates(this: *mut core::ffi::c_void, filepath: core::mem::MaybeUninit<windows_core::BSTR>, templatenamessafearray: *const core::mem::MaybeUninit<windows_core::VARIANT>, remotehost: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmExportImport", "ExportQuotaTemplates", 11usize, |this| ((**(this as *const *const IFsrmExportImport_Vtbl)).ExportQuotaTemplates)(this, filepath, templatenamessafearray, remotehost), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ImportQuotaTemplates(this: *mut core::ffi::c_void, filepath: core::mem::MaybeUninit<windows_core::BSTR>, templatenamessafearray: *const core::mem::MaybeUninit<windows_core::VARIANT>, remotehost: core::mem::MaybeUninit<windows_core::BSTR>, templates: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmExportImport", "ImportQuotaTemplates", 12usize, |this| ((**(this as *const *const IFsrmExportImport_Vtbl)).ImportQuotaTemplates)(this, filepath, templatenamessafearray, remotehost, templates), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ExportFileGroups,
+            #[cfg(feature = "Win32_System_Com")]
+            ImportFileGroups,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ImportFileGroups: 0,
+            ExportFileScreenTemplates,
+            #[cfg(feature = "Win32_System_Com")]
+            ImportFileScreenTemplates,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ImportFileScreenTemplates: 0,
+            ExportQuotaTemplates,
+            #[cfg(feature = "Win32_System_Com")]
+            ImportQuotaTemplates,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ImportQuotaTemplates: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmExportImport as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmFileCondition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, pval: *mut FsrmFileConditionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileCondition", "Type", 7usize, |this| ((**(this as *const *const IFsrmFileCondition_Vtbl)).Type)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileCondition", "Delete", 8usize, |this| ((**(this as *const *const IFsrmFileCondition_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Type, Delete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmFileCondition as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmFileConditionProperty_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PropertyName(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileConditionProperty", "PropertyName", 9usize, |this| ((**(this as *const *const IFsrmFileConditionProperty_Vtbl)).PropertyName)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPropertyName(this: *mut core::ffi::c_void, newval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileConditionProperty", "SetPropertyName", 10usize, |this| ((**(this as *const *const IFsrmFileConditionProperty_Vtbl)).SetPropertyName)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PropertyId(this: *mut core::ffi::c_void, pval: *mut FsrmFileSystemPropertyId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileConditionProperty", "PropertyId", 11usize, |this| ((**(this as *const *const IFsrmFileConditionProperty_Vtbl)).PropertyId)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPropertyId(this: *mut core::ffi::c_void, newval: FsrmFileSystemPropertyId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileConditionProperty", "SetPropertyId", 12usize, |this| ((**(this as *const *const IFsrmFileConditionProperty_Vtbl)).SetPropertyId)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Operator(this: *mut core::ffi::c_void, pval: *mut FsrmPropertyConditionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileConditionProperty", "Operator", 13usize, |this| ((**(this as *const *const IFsrmFileConditionProperty_Vtbl)).Operator)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOperator(this: *mut core::ffi::c_void, newval: FsrmPropertyConditionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileConditionProperty", "SetOperator", 14usize, |this| ((**(this as *const *const IFsrmFileConditionProperty_Vtbl)).SetOperator)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValueType(this: *mut core::ffi::c_void, pval: *mut FsrmPropertyValueType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileConditionProperty", "ValueType", 15usize, |this| ((**(this as *const *const IFsrmFileConditionProperty_Vtbl)).ValueType)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValueType(this: *mut core::ffi::c_void, newval: FsrmPropertyValueType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileConditionProperty", "SetValueType", 16usize, |this| ((**(this as *const *const IFsrmFileConditionProperty_Vtbl)).SetValueType)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileConditionProperty", "Value", 17usize, |this| ((**(this as *const *const IFsrmFileConditionProperty_Vtbl)).Value)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, newval: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileConditionProperty", "SetValue", 18usize, |this| ((**(this as *const *const IFsrmFileConditionProperty_Vtbl)).SetValue)(this, newval), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmFileCondition_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PropertyName,
+            SetPropertyName,
+            PropertyId,
+            SetPropertyId,
+            Operator,
+            SetOperator,
+            ValueType,
+            SetValueType,
+            Value,
+            SetValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmFileConditionProperty as windows_core::Interface>::IID || <IFsrmFileCondition_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmFileGroup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileGroup", "Name", 12usize, |this| ((**(this as *const *const IFsrmFileGroup_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileGroup", "SetName", 13usize, |this| ((**(this as *const *const IFsrmFileGroup_Vtbl)).SetName)(this, name), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Members(this: *mut core::ffi::c_void, members: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileGroup", "Members", 14usize, |this| ((**(this as *const *const IFsrmFileGroup_Vtbl)).Members)(this, members), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetMembers(this: *mut core::ffi::c_void, members: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileGroup", "SetMembers", 15usize, |this| ((**(this as *const *const IFsrmFileGroup_Vtbl)).SetMembers)(this, members), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn NonMembers(this: *mut core::ffi::c_void, nonmembers: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileGroup", "NonMembers", 16usize, |this| ((**(this as *const *const IFsrmFileGroup_Vtbl)).NonMembers)(this, nonmembers), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetNonMembers(this: *mut core::ffi::c_void, nonmembers: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileGroup", "SetNonMembers", 17usize, |this| ((**(this as *const *const IFsrmFileGroup_Vtbl)).SetNonMembers)(this, nonmembers), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            SetName,
+            #[cfg(feature = "Win32_System_Com")]
+            Members,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Members: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetMembers,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetMembers: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            NonMembers,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            NonMembers: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetNonMembers,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetNonMembers: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmFileGroup as windows_core::Interface>::IID || <IFsrmObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmFileGroupImported_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OverwriteOnCommit(this: *mut core::ffi::c_void, overwrite: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileGroupImported", "OverwriteOnCommit", 18usize, |this| ((**(this as *const *const IFsrmFileGroupImported_Vtbl)).OverwriteOnCommit)(this, overwrite), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOverwriteOnCommit(this: *mut core::ffi::c_void, overwrite: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileGroupImported", "SetOverwriteOnCommit", 19usize, |this| ((**(this as *const *const IFsrmFileGroupImported_Vtbl)).SetOverwriteOnCommit)(this, overwrite), |result| Some(*result))
+        }
+        Self { base__: <IFsrmFileGroup_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OverwriteOnCommit, SetOverwriteOnCommit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmFileGroupImported as windows_core::Interface>::IID || <IFsrmFileGroup_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmFileGroupManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateFileGroup(this: *mut core::ffi::c_void, filegroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileGroupManager", "CreateFileGroup", 7usize, |this| ((**(this as *const *const IFsrmFileGroupManager_Vtbl)).CreateFileGroup)(this, filegroup), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFileGroup(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, filegroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileGroupManager", "GetFileGroup", 8usize, |this| ((**(this as *const *const IFsrmFileGroupManager_Vtbl)).GetFileGroup)(this, name, filegroup), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumFileGroups(this: *mut core::ffi::c_void, options: FsrmEnumOptions, filegroups: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileGroupManager", "EnumFileGroups", 9usize, |this| ((**(this as *const *const IFsrmFileGroupManager_Vtbl)).EnumFileGroups)(this, options, filegroups), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExportFileGroups(this: *mut core::ffi::c_void, filegroupnamesarray: *const core::mem::MaybeUninit<windows_core::VARIANT>, serializedfilegroups: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileGroupManager", "ExportFileGroups", 10usize, |this| ((**(this as *const *const IFsrmFileGroupManager_Vtbl)).ExportFileGroups)(this, filegroupnamesarray, serializedfilegroups), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ImportFileGroups(this: *mut core::ffi::c_void, serializedfilegroups: core::mem::MaybeUninit<windows_core::BSTR>, filegroupnamesarray: *const core::mem::MaybeUninit<windows_core::VARIANT>, filegroups: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileGroupManager", "ImportFileGroups", 11usize, |this| ((**(this as *const *const IFsrmFileGroupManager_Vtbl)).ImportFileGroups)(this, serializedfilegroups, filegroupnamesarray, filegroups), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateFileGroup,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateFileGroup: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFileGroup,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFileGroup: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumFileGroups,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumFileGroups: 0,
+            ExportFileGroups,
+            #[cfg(feature = "Win32_System_Com")]
+            ImportFileGroups,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ImportFileGroups: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmFileGroupManager as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmFileManagementJob_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "Name", 12usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "SetName", 13usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).SetName)(this, name), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn NamespaceRoots(this: *mut core::ffi::c_void, namespaceroots: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "NamespaceRoots", 14usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).NamespaceRoots)(this, namespaceroots), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetNamespaceRoots(this: *mut core::ffi::c_void, namespaceroots: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "SetNamespaceRoots", 15usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).SetNamespaceRoots)(this, namespaceroots), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, enabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "Enabled", 16usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).Enabled)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, enabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "SetEnabled", 17usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).SetEnabled)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn OperationType(this: *mut core::ffi::c_void, operationtype: *mut FsrmFileManagementType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "OperationType", 18usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).OperationType)(this, operationtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOperationType(this: *mut core::ffi::c_void, operationtype: FsrmFileManagementType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "SetOperationType", 19usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).SetOperationType)(this, operationtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExpirationDirectory(this: *mut core::ffi::c_void, expirationdirectory: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "ExpirationDirectory", 20usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).ExpirationDirectory)(this, expirationdirectory), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExpirationDirectory(this: *mut core::ffi::c_void, expirationdirectory: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "SetExpirationDirectory", 21usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).SetExpirationDirectory)(this, expirationdirectory), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CustomAction(this: *mut core::ffi::c_void, action: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "CustomAction", 22usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).CustomAction)(this, action), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Notifications(this: *mut core::ffi::c_void, notifications: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "Notifications", 23usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).Notifications)(this, notifications), |result| Some(*result))
+        }
+        unsafe extern "system" fn Logging(this: *mut core::ffi::c_void, loggingflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "Logging", 24usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).Logging)(this, loggingflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLogging(this: *mut core::ffi::c_void, loggingflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "SetLogging", 25usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).SetLogging)(this, loggingflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportEnabled(this: *mut core::ffi::c_void, reportenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "ReportEnabled", 26usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).ReportEnabled)(this, reportenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReportEnabled(this: *mut core::ffi::c_void, reportenabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "SetReportEnabled", 27usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).SetReportEnabled)(this, reportenabled), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Formats(this: *mut core::ffi::c_void, formats: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "Formats", 28usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).Formats)(this, formats), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetFormats(this: *mut core::ffi::c_void, formats: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "SetFormats", 29usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).SetFormats)(this, formats), |result| Some(*result))
+        }
+        unsafe extern "system" fn MailTo(this: *mut core::ffi::c_void, mailto: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "MailTo", 30usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).MailTo)(this, mailto), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMailTo(this: *mut core::ffi::c_void, mailto: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "SetMailTo", 31usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).SetMailTo)(this, mailto), |result| Some(*result))
+        }
+        unsafe extern "system" fn DaysSinceFileCreated(this: *mut core::ffi::c_void, dayssincecreation: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "DaysSinceFileCreated", 32usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).DaysSinceFileCreated)(this, dayssincecreation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDaysSinceFileCreated(this: *mut core::ffi::c_void, dayssincecreation: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "SetDaysSinceFileCreated", 33usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).SetDaysSinceFileCreated)(this, dayssincecreation), |result| Some(*result))
+        }
+        unsafe extern "system" fn DaysSinceFileLastAccessed(this: *mut core::ffi::c_void, dayssinceaccess: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "DaysSinceFileLastAccessed", 34usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).DaysSinceFileLastAccessed)(this, dayssinceaccess), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDaysSinceFileLastAccessed(this: *mut core::ffi::c_void, dayssinceaccess: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "SetDaysSinceFileLastAccessed", 35usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).SetDaysSinceFileLastAccessed)(this, dayssinceaccess), |result| Some(*result))
+        }
+        unsafe extern "system" fn DaysSinceFileLastModified(this: *mut core::ffi::c_void, dayssincemodify: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "DaysSinceFileLastModified", 36usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).DaysSinceFileLastModified)(this, dayssincemodify), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDaysSinceFileLastModified(this: *mut core::ffi::c_void, dayssincemodify: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "SetDaysSinceFileLastModified", 37usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).SetDaysSinceFileLastModified)(this, dayssincemodify), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PropertyConditions(this: *mut core::ffi::c_void, propertyconditions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "PropertyConditions", 38usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).PropertyConditions)(this, propertyconditions), |result| Some(*result))
+        }
+        unsafe extern "system" fn FromDate(this: *mut core::ffi::c_void, fromdate: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "FromDate", 39usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).FromDate)(this, fromdate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFromDate(this: *mut core::ffi::c_void, fromdate: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "SetFromDate", 40usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).SetFromDate)(this, fromdate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Task(this: *mut core::ffi::c_void, taskname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "Task", 41usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).Task)(this, taskname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTask(this: *mut core::ffi::c_void, taskname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "SetTask", 42usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).SetTask)(this, taskname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Parameters(this: *mut core::ffi::c_void, parameters: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "Parameters", 43usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).Parameters)(this, parameters), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetParameters(this: *mut core::ffi::c_void, parameters: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "SetParameters", 44usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).SetParameters)(this, parameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunningStatus(this: *mut core::ffi::c_void, runningstatus: *mut FsrmReportRunningStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "RunningStatus", 45usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).RunningStatus)(this, runningstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastError(this: *mut core::ffi::c_void, lasterror: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "LastError", 46usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).LastError)(this, lasterror), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastReportPathWithoutExtension(this: *mut core::ffi::c_void, path: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "LastReportPathWithoutExtension", 47usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).LastReportPathWithoutExtension)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastRun(this: *mut core::ffi::c_void, lastrun: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "LastRun", 48usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).LastRun)(this, lastrun), |result| Some(*result))
+        }
+        unsafe extern "system" fn FileNamePattern(this: *mut core::ffi::c_void, filenamepattern: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "FileNamePattern", 49usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).FileNamePattern)(this, filenamepattern), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFileNamePattern(this: *mut core::ffi::c_void, filenamepattern: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "SetFileNamePattern", 50usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).SetFileNamePattern)(this, filenamepattern), |result| Some(*result))
+        }
+        unsafe extern "system" fn Run(this: *mut core::ffi::c_void, context: FsrmReportGenerationContext) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "Run", 51usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).Run)(this, context), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForCompletion(this: *mut core::ffi::c_void, waitseconds: i32, completed: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "WaitForCompletion", 52usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).WaitForCompletion)(this, waitseconds, completed), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "Cancel", 53usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddNotification(this: *mut core::ffi::c_void, days: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "AddNotification", 54usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).AddNotification)(this, days), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteNotification(this: *mut core::ffi::c_void, days: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "DeleteNotification", 55usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).DeleteNotification)(this, days), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModifyNotification(this: *mut core::ffi::c_void, days: i32, newdays: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "ModifyNotification", 56usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).ModifyNotification)(this, days, newdays), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateNotificationAction(this: *mut core::ffi::c_void, days: i32, actiontype: FsrmActionType, action: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "CreateNotificationAction", 57usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).CreateNotificationAction)(this, days, actiontype, action), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumNotificationActions(this: *mut core::ffi::c_void, days: i32, actions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "EnumNotificationActions", 58usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).EnumNotificationActions)(this, days, actions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreatePropertyCondition(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, propertycondition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "CreatePropertyCondition", 59usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).CreatePropertyCondition)(this, name, propertycondition), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateCustomAction(this: *mut core::ffi::c_void, customaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJob", "CreateCustomAction", 60usize, |this| ((**(this as *const *const IFsrmFileManagementJob_Vtbl)).CreateCustomAction)(this, customaction), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            SetName,
+            #[cfg(feature = "Win32_System_Com")]
+            NamespaceRoots,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            NamespaceRoots: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetNamespaceRoots,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetNamespaceRoots: 0,
+            Enabled,
+            SetEnabled,
+            OperationType,
+            SetOperationType,
+            ExpirationDirectory,
+            SetExpirationDirectory,
+            #[cfg(feature = "Win32_System_Com")]
+            CustomAction,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CustomAction: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Notifications,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Notifications: 0,
+            Logging,
+            SetLogging,
+            ReportEnabled,
+            SetReportEnabled,
+            #[cfg(feature = "Win32_System_Com")]
+            Formats,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Formats: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetFormats,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetFormats: 0,
+            MailTo,
+            SetMailTo,
+            DaysSinceFileCreated,
+            SetDaysSinceFileCreated,
+            DaysSinceFileLastAccessed,
+            SetDaysSinceFileLastAccessed,
+            DaysSinceFileLastModified,
+            SetDaysSinceFileLastModified,
+            #[cfg(feature = "Win32_System_Com")]
+            PropertyConditions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PropertyConditions: 0,
+            FromDate,
+            SetFromDate,
+            Task,
+            SetTask,
+            #[cfg(feature = "Win32_System_Com")]
+            Parameters,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Parameters: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetParameters,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetParameters: 0,
+            RunningStatus,
+            LastError,
+            LastReportPathWithoutExtension,
+            LastRun,
+            FileNamePattern,
+            SetFileNamePattern,
+            Run,
+            WaitForCompletion,
+            Cancel,
+            AddNotification,
+            DeleteNotification,
+            ModifyNotification,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateNotificationAction,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateNotificationAction: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumNotificationActions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumNotificationActions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreatePropertyCondition,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreatePropertyCondition: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateCustomAction,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateCustomAction: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmFileManagementJob as windows_core::Interface>::IID || <IFsrmObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmFileManagementJobManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ActionVariables(this: *mut core::ffi::c_void, variables: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJobManager", "ActionVariables", 7usize, |this| ((**(this as *const *const IFsrmFileManagementJobManager_Vtbl)).ActionVariables)(this, variables), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ActionVariableDescriptions(this: *mut core::ffi::c_void, descriptions: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJobManager", "ActionVariableDescriptions", 8usize, |this| ((**(this as *const *const IFsrmFileManagementJobManager_Vtbl)).ActionVariableDescriptions)(this, descriptions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumFileManagementJobs(this: *mut core::ffi::c_void, options: FsrmEnumOptions, filemanagementjobs: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJobManager", "EnumFileManagementJobs", 9usize, |this| ((**(this as *const *const IFsrmFileManagementJobManager_Vtbl)).EnumFileManagementJobs)(this, options, filemanagementjobs), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateFileManagementJob(this: *mut core::ffi::c_void, filemanagementjob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJobManager", "CreateFileManagementJob", 10usize, |this| ((**(this as *const *const IFsrmFileManagementJobManager_Vtbl)).CreateFileManagementJob)(this, filemanagementjob), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFileManagementJob(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, filemanagementjob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileManagementJobManager", "GetFileManagementJob", 11usize, |this| ((**(this as *const *const IFsrmFileManagementJobManager_Vtbl)).GetFileManagementJob)(this, name, filemanagementjob), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            ActionVariables,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ActionVariables: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ActionVariableDescriptions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ActionVariableDescriptions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumFileManagementJobs,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumFileManagementJobs: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateFileManagementJob,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateFileManagementJob: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFileManagementJob,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFileManagementJob: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmFileManagementJobManager as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmFileScreen_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, path: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreen", "Path", 18usize, |this| ((**(this as *const *const IFsrmFileScreen_Vtbl)).Path)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceTemplateName(this: *mut core::ffi::c_void, filescreentemplatename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreen", "SourceTemplateName", 19usize, |this| ((**(this as *const *const IFsrmFileScreen_Vtbl)).SourceTemplateName)(this, filescreentemplatename), |result| Some(*result))
+        }
+        unsafe extern "system" fn MatchesSourceTemplate(this: *mut core::ffi::c_void, matches: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreen", "MatchesSourceTemplate", 20usize, |this| ((**(this as *const *const IFsrmFileScreen_Vtbl)).MatchesSourceTemplate)(this, matches), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserSid(this: *mut core::ffi::c_void, usersid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreen", "UserSid", 21usize, |this| ((**(this as *const *const IFsrmFileScreen_Vtbl)).UserSid)(this, usersid), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserAccount(this: *mut core::ffi::c_void, useraccount: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreen", "UserAccount", 22usize, |this| ((**(this as *const *const IFsrmFileScreen_Vtbl)).UserAccount)(this, useraccount), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplyTemplate(this: *mut core::ffi::c_void, filescreentemplatename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreen", "ApplyTemplate", 23usize, |this| ((**(this as *const *const IFsrmFileScreen_Vtbl)).ApplyTemplate)(this, filescreentemplatename), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmFileScreenBase_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Path,
+            SourceTemplateName,
+            MatchesSourceTemplate,
+            UserSid,
+            UserAccount,
+            ApplyTemplate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmFileScreen as windows_core::Interface>::IID || <IFsrmFileScreenBase_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmFileScreenBase_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn BlockedFileGroups(this: *mut core::ffi::c_void, blocklist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenBase", "BlockedFileGroups", 12usize, |this| ((**(this as *const *const IFsrmFileScreenBase_Vtbl)).BlockedFileGroups)(this, blocklist), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetBlockedFileGroups(this: *mut core::ffi::c_void, blocklist: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenBase", "SetBlockedFileGroups", 13usize, |this| ((**(this as *const *const IFsrmFileScreenBase_Vtbl)).SetBlockedFileGroups)(this, blocklist), |result| Some(*result))
+        }
+        unsafe extern "system" fn FileScreenFlags(this: *mut core::ffi::c_void, filescreenflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenBase", "FileScreenFlags", 14usize, |this| ((**(this as *const *const IFsrmFileScreenBase_Vtbl)).FileScreenFlags)(this, filescreenflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFileScreenFlags(this: *mut core::ffi::c_void, filescreenflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenBase", "SetFileScreenFlags", 15usize, |this| ((**(this as *const *const IFsrmFileScreenBase_Vtbl)).SetFileScreenFlags)(this, filescreenflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateAction(this: *mut core::ffi::c_void, actiontype: FsrmActionType, action: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenBase", "CreateAction", 16usize, |this| ((**(this as *const *const IFsrmFileScreenBase_Vtbl)).CreateAction)(this, actiontype, action), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumActions(this: *mut core::ffi::c_void, actions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenBase", "EnumActions", 17usize, |this| ((**(this as *const *const IFsrmFileScreenBase_Vtbl)).EnumActions)(this, actions), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            BlockedFileGroups,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            BlockedFileGroups: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetBlockedFileGroups,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetBlockedFileGroups: 0,
+            FileScreenFlags,
+            SetFileScreenFlags,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateAction,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateAction: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumActions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumActions: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmFileScreenBase as windows_core::Interface>::IID || <IFsrmObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmFileScreenException_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, path: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenException", "Path", 12usize, |this| ((**(this as *const *const IFsrmFileScreenException_Vtbl)).Path)(this, path), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AllowedFileGroups(this: *mut core::ffi::c_void, allowlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenException", "AllowedFileGroups", 13usize, |this| ((**(this as *const *const IFsrmFileScreenException_Vtbl)).AllowedFileGroups)(this, allowlist), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetAllowedFileGroups(this: *mut core::ffi::c_void, allowlist: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenException", "SetAllowedFileGroups", 14usize, |this| ((**(this as *const *const IFsrmFileScreenException_Vtbl)).SetAllowedFileGroups)(this, allowlist), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Path,
+            #[cfg(feature = "Win32_System_Com")]
+            AllowedFileGroups,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AllowedFileGroups: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetAllowedFileGroups,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetAllowedFileGroups: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmFileScreenException as windows_core::Interface>::IID || <IFsrmObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmFileScreenManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ActionVariables(this: *mut core::ffi::c_void, variables: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenManager", "ActionVariables", 7usize, |this| ((**(this as *const *const IFsrmFileScreenManager_Vtbl)).ActionVariables)(this, variables), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ActionVariableDescriptions(this: *mut core::ffi::c_void, descriptions: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenManager", "ActionVariableDescriptions", 8usize, |this| ((**(this as *const *const IFsrmFileScreenManager_Vtbl)).ActionVariableDescriptions)(this, descriptions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateFileScreen(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, filescreen: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenManager", "CreateFileScreen", 9usize, |this| ((**(this as *const *const IFsrmFileScreenManager_Vtbl)).CreateFileScreen)(this, path, filescreen), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFileScreen(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, filescreen: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenManager", "GetFileScreen", 10usize, |this| ((**(this as *const *const IFsrmFileScreenManager_Vtbl)).GetFileScreen)(this, path, filescreen), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumFileScreens(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, options: FsrmEnumOptions, filescreens: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenManager", "EnumFileScreens", 11usize, |this| ((**(this as *const *const IFsrmFileScreenManager_Vtbl)).EnumFileScreens)(this, path, options, filescreens), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateFileScreenException(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, filescreenexception: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenManager", "CreateFileScreenException", 12usize, |this| ((**(this as *const *const IFsrmFileScreenManager_Vtbl)).CreateFileScreenException)(this, path, filescreenexception), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFileScreenException(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, filescreenexception: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenManager", "GetFileScreenException", 13usize, |this| ((**(this as *const *const IFsrmFileScreenManager_Vtbl)).GetFileScreenException)(this, path, filescreenexception), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumFileScreenExceptions(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, options: FsrmEnumOptions, filescreenexceptions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenManager", "EnumFileScreenExceptions", 14usize, |this| ((**(this as *const *const IFsrmFileScreenManager_Vtbl)).EnumFileScreenExceptions)(this, path, options, filescreenexceptions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateFileScreenCollection(this: *mut core::ffi::c_void, collection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenManager", "CreateFileScreenCollection", 15usize, |this| ((**(this as *const *const IFsrmFileScreenManager_Vtbl)).CreateFileScreenCollection)(this, collection), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            ActionVariables,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ActionVariables: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ActionVariableDescriptions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ActionVariableDescriptions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateFileScreen,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateFileScreen: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFileScreen,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFileScreen: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumFileScreens,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumFileScreens: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateFileScreenException,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateFileScreenException: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFileScreenException,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFileScreenException: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumFileScreenExceptions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumFileScreenExceptions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateFileScreenCollection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateFileScreenCollection: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmFileScreenManager as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmFileScreenTemplate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenTemplate", "Name", 18usize, |this| ((**(this as *const *const IFsrmFileScreenTemplate_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenTemplate", "SetName", 19usize, |this| ((**(this as *const *const IFsrmFileScreenTemplate_Vtbl)).SetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyTemplate(this: *mut core::ffi::c_void, filescreentemplatename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenTemplate", "CopyTemplate", 20usize, |this| ((**(this as *const *const IFsrmFileScreenTemplate_Vtbl)).CopyTemplate)(this, filescreentemplatename), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CommitAndUpdateDerived(this: *mut core::ffi::c_void, commitoptions: FsrmCommitOptions, applyoptions: FsrmTemplateApplyOptions, derivedobjectsresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenTemplate", "CommitAndUpdateDerived", 21usize, |this| ((**(this as *const *const IFsrmFileScreenTemplate_Vtbl)).CommitAndUpdateDerived)(this, commitoptions, applyoptions, derivedobjectsresult), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmFileScreenBase_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            SetName,
+            CopyTemplate,
+            #[cfg(feature = "Win32_System_Com")]
+            CommitAndUpdateDerived,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CommitAndUpdateDerived: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmFileScreenTemplate as windows_core::Interface>::IID || <IFsrmFileScreenBase_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmFileScreenTemplateImported_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OverwriteOnCommit(this: *mut core::ffi::c_void, overwrite: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenTemplateImported", "OverwriteOnCommit", 22usize, |this| ((**(this as *const *const IFsrmFileScreenTemplateImported_Vtbl)).OverwriteOnCommit)(this, overwrite), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOverwriteOnCommit(this: *mut core::ffi::c_void, overwrite: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenTemplateImported", "SetOverwriteOnCommit", 23usize, |this| ((**(this as *const *const IFsrmFileScreenTemplateImported_Vtbl)).SetOverwriteOnCommit)(this, overwrite), |result| Some(*result))
+        }
+        Self { base__: <IFsrmFileScreenTemplate_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OverwriteOnCommit, SetOverwriteOnCommit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmFileScreenTemplateImported as windows_core::Interface>::IID || <IFsrmFileScreenTemplate_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmFileScreenTemplateManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateTemplate(this: *mut core::ffi::c_void, filescreentemplate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenTemplateManager", "CreateTemplate", 7usize, |this| ((**(this as *const *const IFsrmFileScreenTemplateManager_Vtbl)).CreateTemplate)(this, filescreentemplate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetTemplate(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, filescreentemplate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenTemplateManager", "GetTemplate", 8usize, |this| ((**(this as *const *const IFsrmFileScreenTemplateManager_Vtbl)).GetTemplate)(this, name, filescreentemplate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumTemplates(this: *mut core::ffi::c_void, options: FsrmEnumOptions, filescreentemplates: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenTemplateManager", "EnumTemplates", 9usize, |this| ((**(this as *const *const IFsrmFileScreenTemplateManager_Vtbl)).EnumTemplates)(this, options, filescreentemplates), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExportTemplates(this: *mut core::ffi::c_void, filescreentemplatenamesarray: *const core::mem::MaybeUninit<windows_core::VARIANT>, serializedfilescreentemplates: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenTemplateManager", "ExportTemplates", 10usize, |this| ((**(this as *const *const IFsrmFileScreenTemplateManager_Vtbl)).ExportTemplates)(this, filescreentemplatenamesarray, serializedfilescreentemplates), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ImportTemplates(this: *mut core::ffi::c_void, serializedfilescreentemplates: core::mem::MaybeUninit<windows_core::BSTR>, filescreentemplatenamesarray: *const core::mem::MaybeUninit<windows_core::VARIANT>, filescreentemplates: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmFileScreenTemplateManager", "ImportTemplates", 11usize, |this| ((**(this as *const *const IFsrmFileScreenTemplateManager_Vtbl)).ImportTemplates)(this, serializedfilescreentemplates, filescreentemplatenamesarray, filescreentemplates), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateTemplate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateTemplate: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetTemplate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetTemplate: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumTemplates,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumTemplates: 0,
+            ExportTemplates,
+            #[cfg(feature = "Win32_System_Com")]
+            ImportTemplates,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ImportTemplates: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmFileScreenTemplateManager as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmMutableCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, item: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmMutableCollection", "Add", 14usize, |this| ((**(this as *const *const IFsrmMutableCollection_Vtbl)).Add)(this, item), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmMutableCollection", "Remove", 15usize, |this| ((**(this as *const *const IFsrmMutableCollection_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveById(this: *mut core::ffi::c_void, id: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmMutableCollection", "RemoveById", 16usize, |this| ((**(this as *const *const IFsrmMutableCollection_Vtbl)).RemoveById)(this, id), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, collection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmMutableCollection", "Clone", 17usize, |this| ((**(this as *const *const IFsrmMutableCollection_Vtbl)).Clone)(this, collection), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmCollection_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Add,
+            Remove,
+            RemoveById,
+            #[cfg(feature = "Win32_System_Com")]
+            Clone,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Clone: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmMutableCollection as windows_core::Interface>::IID || <IFsrmCollection_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, id: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmObject", "Id", 7usize, |this| ((**(this as *const *const IFsrmObject_Vtbl)).Id)(this, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, description: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmObject", "Description", 8usize, |this| ((**(this as *const *const IFsrmObject_Vtbl)).Description)(this, description), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, description: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmObject", "SetDescription", 9usize, |this| ((**(this as *const *const IFsrmObject_Vtbl)).SetDescription)(this, description), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmObject", "Delete", 10usize, |this| ((**(this as *const *const IFsrmObject_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmObject", "Commit", 11usize, |this| ((**(this as *const *const IFsrmObject_Vtbl)).Commit)(this), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, Description, SetDescription, Delete, Commit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmObject as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmPathMapper_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSharePathsForLocalPath(this: *mut core::ffi::c_void, localpath: core::mem::MaybeUninit<windows_core::BSTR>, sharepaths: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPathMapper", "GetSharePathsForLocalPath", 7usize, |this| ((**(this as *const *const IFsrmPathMapper_Vtbl)).GetSharePathsForLocalPath)(this, localpath, sharepaths), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSharePathsForLocalPath,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSharePathsForLocalPath: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmPathMapper as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmPipelineModuleConnector_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ModuleImplementation(this: *mut core::ffi::c_void, pipelinemoduleimplementation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPipelineModuleConnector", "ModuleImplementation", 7usize, |this| ((**(this as *const *const IFsrmPipelineModuleConnector_Vtbl)).ModuleImplementation)(this, pipelinemoduleimplementation), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModuleName(this: *mut core::ffi::c_void, username: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPipelineModuleConnector", "ModuleName", 8usize, |this| ((**(this as *const *const IFsrmPipelineModuleConnector_Vtbl)).ModuleName)(this, username), |result| Some(*result))
+        }
+        unsafe extern "system" fn HostingUserAccount(this: *mut core::ffi::c_void, useraccount: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPipelineModuleConnector", "HostingUserAccount", 9usize, |this| ((**(this as *const *const IFsrmPipelineModuleConnector_Vtbl)).HostingUserAccount)(this, useraccount), |result| Some(*result))
+        }
+        unsafe extern "system" fn HostingProcessPid(this: *mut core::ffi::c_void, pid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPipelineModuleConnector", "HostingProcessPid", 10usize, |this| ((**(this as *const *const IFsrmPipelineModuleConnector_Vtbl)).HostingProcessPid)(this, pid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Bind(this: *mut core::ffi::c_void, moduledefinition: *mut core::ffi::c_void, moduleimplementation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPipelineModuleConnector", "Bind", 11usize, |this| ((**(this as *const *const IFsrmPipelineModuleConnector_Vtbl)).Bind)(this, moduledefinition, moduleimplementation), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            ModuleImplementation,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ModuleImplementation: 0,
+            ModuleName,
+            HostingUserAccount,
+            HostingProcessPid,
+            #[cfg(feature = "Win32_System_Com")]
+            Bind,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Bind: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmPipelineModuleConnector as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmPipelineModuleDefinition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ModuleClsid(this: *mut core::ffi::c_void, moduleclsid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPipelineModuleDefinition", "ModuleClsid", 12usize, |this| ((**(this as *const *const IFsrmPipelineModuleDefinition_Vtbl)).ModuleClsid)(this, moduleclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetModuleClsid(this: *mut core::ffi::c_void, moduleclsid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPipelineModuleDefinition", "SetModuleClsid", 13usize, |this| ((**(this as *const *const IFsrmPipelineModuleDefinition_Vtbl)).SetModuleClsid)(this, moduleclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPipelineModuleDefinition", "Name", 14usize, |this| ((**(this as *const *const IFsrmPipelineModuleDefinition_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPipelineModuleDefinition", "SetName", 15usize, |this| ((**(this as *const *const IFsrmPipelineModuleDefinition_Vtbl)).SetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn Company(this: *mut core::ffi::c_void, company: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPipelineModuleDefinition", "Company", 16usize, |this| ((**(this as *const *const IFsrmPipelineModuleDefinition_Vtbl)).Company)(this, company), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompany(this: *mut core::ffi::c_void, company: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPipelineModuleDefinition", "SetCompany", 17usize, |this| ((**(this as *const *const IFsrmPipelineModuleDefinition_Vtbl)).SetCompany)(this, company), |result| Some(*result))
+        }
+        unsafe extern "system" fn Version(this: *mut core::ffi::c_void, version: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPipelineModuleDefinition", "Version", 18usize, |this| ((**(this as *const *const IFsrmPipelineModuleDefinition_Vtbl)).Version)(this, version), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVersion(this: *mut core::ffi::c_void, version: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPipelineModuleDefinition", "SetVersion", 19usize, |this| ((**(this as *const *const IFsrmPipelineModuleDefinition_Vtbl)).SetVersion)(this, version), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModuleType(this: *mut core::ffi::c_void, moduletype: *mut FsrmPipelineModuleType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPipelineModuleDefinition", "ModuleType", 20usize, |this| ((**(this as *const *const IFsrmPipelineModuleDefinition_Vtbl)).ModuleType)(this, moduletype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, enabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPipelineModuleDefinition", "Enabled", 21usize, |this| ((**(this as *const *const IFsrmPipelineModuleDefinition_Vtbl)).Enabled)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, enabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPipelineModuleDefinition", "SetEnabled", 22usize, |this| ((**(this as *const *const IFsrmPipelineModuleDefinition_Vtbl)).SetEnabled)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn NeedsFileContent(this: *mut core::ffi::c_void, needsfilecontent: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPipelineModuleDefinition", "NeedsFileContent", 23usize, |this| ((**(this as *const *const IFsrmPipelineModuleDefinition_Vtbl)).NeedsFileContent)(this, needsfilecontent), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNeedsFileContent(this: *mut core::ffi::c_void, needsfilecontent: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPipelineModuleDefinition", "SetNeedsFileContent", 24usize, |this| ((**(this as *const *const IFsrmPipelineModuleDefinition_Vtbl)).SetNeedsFileContent)(this, needsfilecontent), |result| Some(*result))
+        }
+        unsafe extern "system" fn Account(this: *mut core::ffi::c_void, retrievalaccount: *mut FsrmAccountType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPipelineModuleDefinition", "Account", 25usize, |this| ((**(this as *const *const IFsrmPipelineModuleDefinition_Vtbl)).Account)(this, retrievalaccount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAccount(this: *mut core::ffi::c_void, retrievalaccount: FsrmAccountType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPipelineModuleDefinition", "SetAccount", 26usize, |this| ((**(this as *const *const IFsrmPipelineModuleDefinition_Vtbl)).SetAccount)(this, retrievalaccount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SupportedExtensions(this: *mut core::ffi::c_void, supportedextensions: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPipelineModuleDefinition", "SupportedExtensions", 27usize, |this| ((**(this as *const *const IFsrmPipelineModuleDefinition_Vtbl)).SupportedExtensions)(this, supportedextensions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSupportedExtensions(this: *mut core::ffi::c_void, supportedextensions: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPipelineModuleDefinition", "SetSupportedExtensions", 28usize, |this| ((**(this as *const *const IFsrmPipelineModuleDefinition_Vtbl)).SetSupportedExtensions)(this, supportedextensions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Parameters(this: *mut core::ffi::c_void, parameters: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPipelineModuleDefinition", "Parameters", 29usize, |this| ((**(this as *const *const IFsrmPipelineModuleDefinition_Vtbl)).Parameters)(this, parameters), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetParameters(this: *mut core::ffi::c_void, parameters: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPipelineModuleDefinition", "SetParameters", 30usize, |this| ((**(this as *const *const IFsrmPipelineModuleDefinition_Vtbl)).SetParameters)(this, parameters), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ModuleClsid,
+            SetModuleClsid,
+            Name,
+            SetName,
+            Company,
+            SetCompany,
+            Version,
+            SetVersion,
+            ModuleType,
+            Enabled,
+            SetEnabled,
+            NeedsFileContent,
+            SetNeedsFileContent,
+            Account,
+            SetAccount,
+            #[cfg(feature = "Win32_System_Com")]
+            SupportedExtensions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SupportedExtensions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSupportedExtensions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSupportedExtensions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Parameters,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Parameters: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetParameters,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetParameters: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmPipelineModuleDefinition as windows_core::Interface>::IID || <IFsrmObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmPipelineModuleImplementation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnLoad(this: *mut core::ffi::c_void, moduledefinition: *mut core::ffi::c_void, moduleconnector: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPipelineModuleImplementation", "OnLoad", 7usize, |this| ((**(this as *const *const IFsrmPipelineModuleImplementation_Vtbl)).OnLoad)(this, moduledefinition, moduleconnector), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnUnload(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPipelineModuleImplementation", "OnUnload", 8usize, |this| ((**(this as *const *const IFsrmPipelineModuleImplementation_Vtbl)).OnUnload)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            OnLoad,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnLoad: 0,
+            OnUnload,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmPipelineModuleImplementation as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmProperty_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmProperty", "Name", 7usize, |this| ((**(this as *const *const IFsrmProperty_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmProperty", "Value", 8usize, |this| ((**(this as *const *const IFsrmProperty_Vtbl)).Value)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Sources(this: *mut core::ffi::c_void, sources: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmProperty", "Sources", 9usize, |this| ((**(this as *const *const IFsrmProperty_Vtbl)).Sources)(this, sources), |result| Some(*result))
+        }
+        unsafe extern "system" fn PropertyFlags(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmProperty", "PropertyFlags", 10usize, |this| ((**(this as *const *const IFsrmProperty_Vtbl)).PropertyFlags)(this, flags), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            Value,
+            #[cfg(feature = "Win32_System_Com")]
+            Sources,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Sources: 0,
+            PropertyFlags,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmProperty as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmPropertyBag_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyBag", "Name", 7usize, |this| ((**(this as *const *const IFsrmPropertyBag_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn RelativePath(this: *mut core::ffi::c_void, path: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyBag", "RelativePath", 8usize, |this| ((**(this as *const *const IFsrmPropertyBag_Vtbl)).RelativePath)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn VolumeName(this: *mut core::ffi::c_void, volumename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyBag", "VolumeName", 9usize, |this| ((**(this as *const *const IFsrmPropertyBag_Vtbl)).VolumeName)(this, volumename), |result| Some(*result))
+        }
+        unsafe extern "system" fn RelativeNamespaceRoot(this: *mut core::ffi::c_void, relativenamespaceroot: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyBag", "RelativeNamespaceRoot", 10usize, |this| ((**(this as *const *const IFsrmPropertyBag_Vtbl)).RelativeNamespaceRoot)(this, relativenamespaceroot), |result| Some(*result))
+        }
+        unsafe extern "system" fn VolumeIndex(this: *mut core::ffi::c_void, volumeid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyBag", "VolumeIndex", 11usize, |this| ((**(this as *const *const IFsrmPropertyBag_Vtbl)).VolumeIndex)(this, volumeid), |result| Some(*result))
+        }
+        unsafe extern "system" fn FileId(this: *mut core::ffi::c_void, fileid: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyBag", "FileId", 12usize, |this| ((**(this as *const *const IFsrmPropertyBag_Vtbl)).FileId)(this, fileid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParentDirectoryId(this: *mut core::ffi::c_void, parentdirectoryid: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyBag", "ParentDirectoryId", 13usize, |this| ((**(this as *const *const IFsrmPropertyBag_Vtbl)).ParentDirectoryId)(this, parentdirectoryid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, size: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyBag", "Size", 14usize, |this| ((**(this as *const *const IFsrmPropertyBag_Vtbl)).Size)(this, size), |result| Some(*result))
+        }
+        unsafe extern "system" fn SizeAllocated(this: *mut core::ffi::c_void, sizeallocated: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyBag", "SizeAllocated", 15usize, |this| ((**(this as *const *const IFsrmPropertyBag_Vtbl)).SizeAllocated)(this, sizeallocated), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreationTime(this: *mut core::ffi::c_void, creationtime: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyBag", "CreationTime", 16usize, |this| ((**(this as *const *const IFsrmPropertyBag_Vtbl)).CreationTime)(this, creationtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastAccessTime(this: *mut core::ffi::c_void, lastaccesstime: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyBag", "LastAccessTime", 17usize, |this| ((**(this as *const *const IFsrmPropertyBag_Vtbl)).LastAccessTime)(this, lastaccesstime), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastModificationTime(this: *mut core::ffi::c_void, lastmodificationtime: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyBag", "LastModificationTime", 18usize, |this| ((**(this as *const *const IFsrmPropertyBag_Vtbl)).LastModificationTime)(this, lastmodificationtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn Attributes(this: *mut core::ffi::c_void, attributes: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyBag", "Attributes", 19usize, |this| ((**(this as *const *const IFsrmPropertyBag_Vtbl)).Attributes)(this, attributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn OwnerSid(this: *mut core::ffi::c_void, ownersid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyBag", "OwnerSid", 20usize, |this| ((**(this as *const *const IFsrmPropertyBag_Vtbl)).OwnerSid)(this, ownersid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn FilePropertyNames(this: *mut core::ffi::c_void, filepropertynames: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyBag", "FilePropertyNames", 21usize, |this| ((**(this as *const *const IFsrmPropertyBag_Vtbl)).FilePropertyNames)(this, filepropertynames), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Messages(this: *mut core::ffi::c_void, messages: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyBag", "Messages", 22usize, |this| ((**(this as *const *const IFsrmPropertyBag_Vtbl)).Messages)(this, messages), |result| Some(*result))
+        }
+        unsafe extern "system" fn PropertyBagFlags(this: *mut core::ffi::c_void, flags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyBag", "PropertyBagFlags", 23usize, |this| ((**(this as *const *const IFsrmPropertyBag_Vtbl)).PropertyBagFlags)(this, flags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFileProperty(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, fileproperty: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyBag", "GetFileProperty", 24usize, |this| ((**(this as *const *const IFsrmPropertyBag_Vtbl)).GetFileProperty)(this, name, fileproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFileProperty(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyBag", "SetFileProperty", 25usize, |this| ((**(this as *const *const IFsrmPropertyBag_Vtbl)).SetFileProperty)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddMessage(this: *mut core::ffi::c_void, message: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyBag", "AddMessage", 26usize, |this| ((**(this as *const *const IFsrmPropertyBag_Vtbl)).AddMessage)(this, message), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileStreamInterface(this: *mut core::ffi::c_void, accessmode: FsrmFileStreamingMode, interfacetype: FsrmFileStreamingInterfaceType, pstreaminterface: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyBag", "GetFileStreamInterface", 27usize, |this| ((**(this as *const *const IFsrmPropertyBag_Vtbl)).GetFileStreamInterface)(this, accessmode, interfacetype, pstreaminterface), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            RelativePath,
+            VolumeName,
+            RelativeNamespaceRoot,
+            VolumeIndex,
+            FileId,
+            ParentDirectoryId,
+            Size,
+            SizeAllocated,
+            CreationTime,
+            LastAccessTime,
+            LastModificationTime,
+            Attributes,
+            OwnerSid,
+            #[cfg(feature = "Win32_System_Com")]
+            FilePropertyNames,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            FilePropertyNames: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Messages,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Messages: 0,
+            PropertyBagFlags,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFileProperty,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFileProperty: 0,
+            SetFileProperty,
+            AddMessage,
+            GetFileStreamInterface,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmPropertyBag as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmPropertyBag2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFieldValue(this: *mut core::ffi::c_void, field: FsrmPropertyBagField, value: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyBag2", "GetFieldValue", 28usize, |this| ((**(this as *const *const IFsrmPropertyBag2_Vtbl)).GetFieldValue)(this, field, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetUntrustedInFileProperties(this: *mut core::ffi::c_void, props: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyBag2", "GetUntrustedInFileProperties", 29usize, |this| ((**(this as *const *const IFsrmPropertyBag2_Vtbl)).GetUntrustedInFileProperties)(this, props), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmPropertyBag_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetFieldValue,
+            #[cfg(feature = "Win32_System_Com")]
+            GetUntrustedInFileProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetUntrustedInFileProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmPropertyBag2 as windows_core::Interface>::IID || <IFsrmPropertyBag_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmPropertyCondition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyCondition", "Name", 7usize, |this| ((**(this as *const *const IFsrmPropertyCondition_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyCondition", "SetName", 8usize, |this| ((**(this as *const *const IFsrmPropertyCondition_Vtbl)).SetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, r#type: *mut FsrmPropertyConditionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyCondition", "Type", 9usize, |this| ((**(this as *const *const IFsrmPropertyCondition_Vtbl)).Type)(this, r#type), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetType(this: *mut core::ffi::c_void, r#type: FsrmPropertyConditionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyCondition", "SetType", 10usize, |this| ((**(this as *const *const IFsrmPropertyCondition_Vtbl)).SetType)(this, r#type), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyCondition", "Value", 11usize, |this| ((**(this as *const *const IFsrmPropertyCondition_Vtbl)).Value)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyCondition", "SetValue", 12usize, |this| ((**(this as *const *const IFsrmPropertyCondition_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyCondition", "Delete", 13usize, |this| ((**(this as *const *const IFsrmPropertyCondition_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            SetName,
+            Type,
+            SetType,
+            Value,
+            SetValue,
+            Delete,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmPropertyCondition as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmPropertyDefinition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyDefinition", "Name", 12usize, |this| ((**(this as *const *const IFsrmPropertyDefinition_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyDefinition", "SetName", 13usize, |this| ((**(this as *const *const IFsrmPropertyDefinition_Vtbl)).SetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, r#type: *mut FsrmPropertyDefinitionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyDefinition", "Type", 14usize, |this| ((**(this as *const *const IFsrmPropertyDefinition_Vtbl)).Type)(this, r#type), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetType(this: *mut core::ffi::c_void, r#type: FsrmPropertyDefinitionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyDefinition", "SetType", 15usize, |this| ((**(this as *const *const IFsrmPropertyDefinition_Vtbl)).SetType)(this, r#type), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PossibleValues(this: *mut core::ffi::c_void, possiblevalues: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyDefinition", "PossibleValues", 16usize, |this| ((**(this as *const *const IFsrmPropertyDefinition_Vtbl)).PossibleValues)(this, possiblevalues), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetPossibleValues(this: *mut core::ffi::c_void, possiblevalues: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyDefinition", "SetPossibleValues", 17usize, |this| ((**(this as *const *const IFsrmPropertyDefinition_Vtbl)).SetPossibleValues)(this, possiblevalues), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ValueDescriptions(this: *mut core::ffi::c_void, valuedescriptions: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyDefinition", "ValueDescriptions", 18usize, |this| ((**(this as *const *const IFsrmPropertyDefinition_Vtbl)).ValueDescriptions)(this, valuedescriptions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetValueDescriptions(this: *mut core::ffi::c_void, valuedescriptions: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyDefinition", "SetValueDescriptions", 19usize, |this| ((**(this as *const *const IFsrmPropertyDefinition_Vtbl)).SetValueDescriptions)(this, valuedescriptions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Parameters(this: *mut core::ffi::c_void, parameters: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyDefinition", "Parameters", 20usize, |this| ((**(this as *const *const IFsrmPropertyDefinition_Vtbl)).Parameters)(this, parameters), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetParameters(this: *mut core::ffi::c_void, parameters: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyDefinition", "SetParameters", 21usize, |this| ((**(this as *const *const IFsrmPropertyDefinition_Vtbl)).SetParameters)(this, parameters), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            SetName,
+            Type,
+            SetType,
+            #[cfg(feature = "Win32_System_Com")]
+            PossibleValues,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PossibleValues: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetPossibleValues,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetPossibleValues: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ValueDescriptions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ValueDescriptions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetValueDescriptions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetValueDescriptions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Parameters,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Parameters: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetParameters,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetParameters: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmPropertyDefinition as windows_core::Interface>::IID || <IFsrmObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmPropertyDefinition2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PropertyDefinitionFlags(this: *mut core::ffi::c_void, propertydefinitionflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyDefinition2", "PropertyDefinitionFlags", 22usize, |this| ((**(this as *const *const IFsrmPropertyDefinition2_Vtbl)).PropertyDefinitionFlags)(this, propertydefinitionflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyDefinition2", "DisplayName", 23usize, |this| ((**(this as *const *const IFsrmPropertyDefinition2_Vtbl)).DisplayName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyDefinition2", "SetDisplayName", 24usize, |this| ((**(this as *const *const IFsrmPropertyDefinition2_Vtbl)).SetDisplayName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppliesTo(this: *mut core::ffi::c_void, appliesto: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyDefinition2", "AppliesTo", 25usize, |this| ((**(this as *const *const IFsrmPropertyDefinition2_Vtbl)).AppliesTo)(this, appliesto), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ValueDefinitions(this: *mut core::ffi::c_void, valuedefinitions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyDefinition2", "ValueDefinitions", 26usize, |this| ((**(this as *const *const IFsrmPropertyDefinition2_Vtbl)).ValueDefinitions)(this, valuedefinitions), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmPropertyDefinition_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PropertyDefinitionFlags,
+            DisplayName,
+            SetDisplayName,
+            AppliesTo,
+            #[cfg(feature = "Win32_System_Com")]
+            ValueDefinitions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ValueDefinitions: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmPropertyDefinition2 as windows_core::Interface>::IID || <IFsrmPropertyDefinition_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmPropertyDefinitionValue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyDefinitionValue", "Name", 7usize, |this| ((**(this as *const *const IFsrmPropertyDefinitionValue_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, displayname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyDefinitionValue", "DisplayName", 8usize, |this| ((**(this as *const *const IFsrmPropertyDefinitionValue_Vtbl)).DisplayName)(this, displayname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, description: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyDefinitionValue", "Description", 9usize, |this| ((**(this as *const *const IFsrmPropertyDefinitionValue_Vtbl)).Description)(this, description), |result| Some(*result))
+        }
+        unsafe extern "system" fn UniqueID(this: *mut core::ffi::c_void, uniqueid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmPropertyDefinitionValue", "UniqueID", 10usize, |this| ((**(this as *const *const IFsrmPropertyDefinitionValue_Vtbl)).UniqueID)(this, uniqueid), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, DisplayName, Description, UniqueID }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmPropertyDefinitionValue as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmQuota_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QuotaUsed(this: *mut core::ffi::c_void, used: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuota", "QuotaUsed", 28usize, |this| ((**(this as *const *const IFsrmQuota_Vtbl)).QuotaUsed)(this, used), |result| Some(*result))
+        }
+        unsafe extern "system" fn QuotaPeakUsage(this: *mut core::ffi::c_void, peakusage: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuota", "QuotaPeakUsage", 29usize, |this| ((**(this as *const *const IFsrmQuota_Vtbl)).QuotaPeakUsage)(this, peakusage), |result| Some(*result))
+        }
+        unsafe extern "system" fn QuotaPeakUsageTime(this: *mut core::ffi::c_void, peakusagedatetime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuota", "QuotaPeakUsageTime", 30usize, |this| ((**(this as *const *const IFsrmQuota_Vtbl)).QuotaPeakUsageTime)(this, peakusagedatetime), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetPeakUsage(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuota", "ResetPeakUsage", 31usize, |this| ((**(this as *const *const IFsrmQuota_Vtbl)).ResetPeakUsage)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RefreshUsageProperties(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuota", "RefreshUsageProperties", 32usize, |this| ((**(this as *const *const IFsrmQuota_Vtbl)).RefreshUsageProperties)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmQuotaObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            QuotaUsed,
+            QuotaPeakUsage,
+            QuotaPeakUsageTime,
+            ResetPeakUsage,
+            RefreshUsageProperties,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmQuota as windows_core::Interface>::IID || <IFsrmQuotaObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmQuotaBase_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QuotaLimit(this: *mut core::ffi::c_void, quotalimit: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaBase", "QuotaLimit", 12usize, |this| ((**(this as *const *const IFsrmQuotaBase_Vtbl)).QuotaLimit)(this, quotalimit), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQuotaLimit(this: *mut core::ffi::c_void, quotalimit: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaBase", "SetQuotaLimit", 13usize, |this| ((**(this as *const *const IFsrmQuotaBase_Vtbl)).SetQuotaLimit)(this, quotalimit), |result| Some(*result))
+        }
+        unsafe extern "system" fn QuotaFlags(this: *mut core::ffi::c_void, quotaflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaBase", "QuotaFlags", 14usize, |this| ((**(this as *const *const IFsrmQuotaBase_Vtbl)).QuotaFlags)(this, quotaflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQuotaFlags(this: *mut core::ffi::c_void, quotaflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaBase", "SetQuotaFlags", 15usize, |this| ((**(this as *const *const IFsrmQuotaBase_Vtbl)).SetQuotaFlags)(this, quotaflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Thresholds(this: *mut core::ffi::c_void, thresholds: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaBase", "Thresholds", 16usize, |this| ((**(this as *const *const IFsrmQuotaBase_Vtbl)).Thresholds)(this, thresholds), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddThreshold(this: *mut core::ffi::c_void, threshold: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaBase", "AddThreshold", 17usize, |this| ((**(this as *const *const IFsrmQuotaBase_Vtbl)).AddThreshold)(this, threshold), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteThreshold(this: *mut core::ffi::c_void, threshold: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaBase", "DeleteThreshold", 18usize, |this| ((**(this as *const *const IFsrmQuotaBase_Vtbl)).DeleteThreshold)(this, threshold), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModifyThreshold(this: *mut core::ffi::c_void, threshold: i32, newthreshold: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaBase", "ModifyThreshold", 19usize, |this| ((**(this as *const *const IFsrmQuotaBase_Vtbl)).ModifyThreshold)(this, threshold, newthreshold), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateThresholdAction(this: *mut core::ffi::c_void, threshold: i32, actiontype: FsrmActionType, action: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaBase", "CreateThresholdAction", 20usize, |this| ((**(this as *const *const IFsrmQuotaBase_Vtbl)).CreateThresholdAction)(this, threshold, actiontype, action), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumThresholdActions(this: *mut core::ffi::c_void, threshold: i32, actions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaBase", "EnumThresholdActions", 21usize, |this| ((**(this as *const *const IFsrmQuotaBase_Vtbl)).EnumThresholdActions)(this, threshold, actions), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            QuotaLimit,
+            SetQuotaLimit,
+            QuotaFlags,
+            SetQuotaFlags,
+            #[cfg(feature = "Win32_System_Com")]
+            Thresholds,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Thresholds: 0,
+            AddThreshold,
+            DeleteThreshold,
+            ModifyThreshold,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateThresholdAction,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateThresholdAction: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumThresholdActions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumThresholdActions: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmQuotaBase as windows_core::Interface>::IID || <IFsrmObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmQuotaManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ActionVariables(this: *mut core::ffi::c_void, variables: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaManager", "ActionVariables", 7usize, |this| ((**(this as *const *const IFsrmQuotaManager_Vtbl)).ActionVariables)(this, variables), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ActionVariableDescriptions(this: *mut core::ffi::c_void, descriptions: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaManager", "ActionVariableDescriptions", 8usize, |this| ((**(this as *const *const IFsrmQuotaManager_Vtbl)).ActionVariableDescriptions)(this, descriptions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateQuota(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, quota: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaManager", "CreateQuota", 9usize, |this| ((**(this as *const *const IFsrmQuotaManager_Vtbl)).CreateQuota)(this, path, quota), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateAutoApplyQuota(this: *mut core::ffi::c_void, quotatemplatename: core::mem::MaybeUninit<windows_core::BSTR>, path: core::mem::MaybeUninit<windows_core::BSTR>, quota: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaManager", "CreateAutoApplyQuota", 10usize, |this| ((**(this as *const *const IFsrmQuotaManager_Vtbl)).CreateAutoApplyQuota)(this, quotatemplatename, path, quota), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetQuota(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, quota: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaManager", "GetQuota", 11usize, |this| ((**(this as *const *const IFsrmQuotaManager_Vtbl)).GetQuota)(this, path, quota), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetAutoApplyQuota(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, quota: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaManager", "GetAutoApplyQuota", 12usize, |this| ((**(this as *const *const IFsrmQuotaManager_Vtbl)).GetAutoApplyQuota)(this, path, quota), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetRestrictiveQuota(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, quota: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaManager", "GetRestrictiveQuota", 13usize, |this| ((**(this as *const *const IFsrmQuotaManager_Vtbl)).GetRestrictiveQuota)(this, path, quota), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumQuotas(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, options: FsrmEnumOptions, quotas: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaManager", "EnumQuotas", 14usize, |this| ((**(this as *const *const IFsrmQuotaManager_Vtbl)).EnumQuotas)(this, path, options, quotas), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumAutoApplyQuotas(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, options: FsrmEnumOptions, quotas: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaManager", "EnumAutoApplyQuotas", 15usize, |this| ((**(this as *const *const IFsrmQuotaManager_Vtbl)).EnumAutoApplyQuotas)(this, path, options, quotas), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumEffectiveQuotas(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, options: FsrmEnumOptions, quotas: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaManager", "EnumEffectiveQuotas", 16usize, |this| ((**(this as *const *const IFsrmQuotaManager_Vtbl)).EnumEffectiveQuotas)(this, path, options, quotas), |result| Some(*result))
+        }
+        unsafe extern "system" fn Scan(this: *mut core::ffi::c_void, strpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaManager", "Scan", 17usize, |this| ((**(this as *const *const IFsrmQuotaManager_Vtbl)).Scan)(this, strpath), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateQuotaCollection(this: *mut core::ffi::c_void, collection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaManager", "CreateQuotaCollection", 18usize, |this| ((**(this as *const *const IFsrmQuotaManager_Vtbl)).CreateQuotaCollection)(this, collection), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            ActionVariables,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ActionVariables: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ActionVariableDescriptions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ActionVariableDescriptions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateQuota,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateQuota: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateAutoApplyQuota,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateAutoApplyQuota: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetQuota,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetQuota: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetAutoApplyQuota,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetAutoApplyQuota: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetRestrictiveQuota,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetRestrictiveQuota: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumQuotas,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumQuotas: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumAutoApplyQuotas,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumAutoApplyQuotas: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumEffectiveQuotas,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumEffectiveQuotas: 0,
+            Scan,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateQuotaCollection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateQuotaCollection: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmQuotaManager as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmQuotaManagerEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsAffectedByQuota(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, options: FsrmEnumOptions, affected: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaManagerEx", "IsAffectedByQuota", 19usize, |this| ((**(this as *const *const IFsrmQuotaManagerEx_Vtbl)).IsAffectedByQuota)(this, path, options, affected), |result| Some(*result))
+        }
+        Self { base__: <IFsrmQuotaManager_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsAffectedByQuota }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmQuotaManagerEx as windows_core::Interface>::IID || <IFsrmQuotaManager_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmQuotaObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, path: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaObject", "Path", 22usize, |this| ((**(this as *const *const IFsrmQuotaObject_Vtbl)).Path)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserSid(this: *mut core::ffi::c_void, usersid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaObject", "UserSid", 23usize, |this| ((**(this as *const *const IFsrmQuotaObject_Vtbl)).UserSid)(this, usersid), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserAccount(this: *mut core::ffi::c_void, useraccount: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaObject", "UserAccount", 24usize, |this| ((**(this as *const *const IFsrmQuotaObject_Vtbl)).UserAccount)(this, useraccount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceTemplateName(this: *mut core::ffi::c_void, quotatemplatename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaObject", "SourceTemplateName", 25usize, |this| ((**(this as *const *const IFsrmQuotaObject_Vtbl)).SourceTemplateName)(this, quotatemplatename), |result| Some(*result))
+        }
+        unsafe extern "system" fn MatchesSourceTemplate(this: *mut core::ffi::c_void, matches: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaObject", "MatchesSourceTemplate", 26usize, |this| ((**(this as *const *const IFsrmQuotaObject_Vtbl)).MatchesSourceTemplate)(this, matches), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplyTemplate(this: *mut core::ffi::c_void, quotatemplatename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaObject", "ApplyTemplate", 27usize, |this| ((**(this as *const *const IFsrmQuotaObject_Vtbl)).ApplyTemplate)(this, quotatemplatename), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmQuotaBase_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Path,
+            UserSid,
+            UserAccount,
+            SourceTemplateName,
+            MatchesSourceTemplate,
+            ApplyTemplate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmQuotaObject as windows_core::Interface>::IID || <IFsrmQuotaBase_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmQuotaTemplate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaTemplate", "Name", 22usize, |this| ((**(this as *const *const IFsrmQuotaTemplate_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaTemplate", "SetName", 23usize, |this| ((**(this as *const *const IFsrmQuotaTemplate_Vtbl)).SetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyTemplate(this: *mut core::ffi::c_void, quotatemplatename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaTemplate", "CopyTemplate", 24usize, |this| ((**(this as *const *const IFsrmQuotaTemplate_Vtbl)).CopyTemplate)(this, quotatemplatename), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CommitAndUpdateDerived(this: *mut core::ffi::c_void, commitoptions: FsrmCommitOptions, applyoptions: FsrmTemplateApplyOptions, derivedobjectsresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaTemplate", "CommitAndUpdateDerived", 25usize, |this| ((**(this as *const *const IFsrmQuotaTemplate_Vtbl)).CommitAndUpdateDerived)(this, commitoptions, applyoptions, derivedobjectsresult), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmQuotaBase_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            SetName,
+            CopyTemplate,
+            #[cfg(feature = "Win32_System_Com")]
+            CommitAndUpdateDerived,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CommitAndUpdateDerived: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmQuotaTemplate as windows_core::Interface>::IID || <IFsrmQuotaBase_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmQuotaTemplateImported_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OverwriteOnCommit(this: *mut core::ffi::c_void, overwrite: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaTemplateImported", "OverwriteOnCommit", 26usize, |this| ((**(this as *const *const IFsrmQuotaTemplateImported_Vtbl)).OverwriteOnCommit)(this, overwrite), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOverwriteOnCommit(this: *mut core::ffi::c_void, overwrite: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaTemplateImported", "SetOverwriteOnCommit", 27usize, |this| ((**(this as *const *const IFsrmQuotaTemplateImported_Vtbl)).SetOverwriteOnCommit)(this, overwrite), |result| Some(*result))
+        }
+        Self { base__: <IFsrmQuotaTemplate_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OverwriteOnCommit, SetOverwriteOnCommit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmQuotaTemplateImported as windows_core::Interface>::IID || <IFsrmQuotaTemplate_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmQuotaTemplateManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateTemplate(this: *mut core::ffi::c_void, quotatemplate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaTemplateManager", "CreateTemplate", 7usize, |this| ((**(this as *const *const IFsrmQuotaTemplateManager_Vtbl)).CreateTemplate)(this, quotatemplate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetTemplate(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, quotatemplate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaTemplateManager", "GetTemplate", 8usize, |this| ((**(this as *const *const IFsrmQuotaTemplateManager_Vtbl)).GetTemplate)(this, name, quotatemplate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumTemplates(this: *mut core::ffi::c_void, options: FsrmEnumOptions, quotatemplates: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaTemplateManager", "EnumTemplates", 9usize, |this| ((**(this as *const *const IFsrmQuotaTemplateManager_Vtbl)).EnumTemplates)(this, options, quotatemplates), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExportTemplates(this: *mut core::ffi::c_void, quotatemplatenamesarray: *const core::mem::MaybeUninit<windows_core::VARIANT>, serializedquotatemplates: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaTemplateManager", "ExportTemplates", 10usize, |this| ((**(this as *const *const IFsrmQuotaTemplateManager_Vtbl)).ExportTemplates)(this, quotatemplatenamesarray, serializedquotatemplates), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ImportTemplates(this: *mut core::ffi::c_void, serializedquotatemplates: core::mem::MaybeUninit<windows_core::BSTR>, quotatemplatenamesarray: *const core::mem::MaybeUninit<windows_core::VARIANT>, quotatemplates: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmQuotaTemplateManager", "ImportTemplates", 11usize, |this| ((**(this as *const *const IFsrmQuotaTemplateManager_Vtbl)).ImportTemplates)(this, serializedquotatemplates, quotatemplatenamesarray, quotatemplates), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateTemplate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateTemplate: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetTemplate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetTemplate: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumTemplates,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumTemplates: 0,
+            ExportTemplates,
+            #[cfg(feature = "Win32_System_Com")]
+            ImportTemplates,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ImportTemplates: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmQuotaTemplateManager as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmReport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, reporttype: *mut FsrmReportType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReport", "Type", 7usize, |this| ((**(this as *const *const IFsrmReport_Vtbl)).Type)(this, reporttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReport", "Name", 8usize, |this| ((**(this as *const *const IFsrmReport_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReport", "SetName", 9usize, |this| ((**(this as *const *const IFsrmReport_Vtbl)).SetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, description: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReport", "Description", 10usize, |this| ((**(this as *const *const IFsrmReport_Vtbl)).Description)(this, description), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, description: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReport", "SetDescription", 11usize, |this| ((**(this as *const *const IFsrmReport_Vtbl)).SetDescription)(this, description), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastGeneratedFileNamePrefix(this: *mut core::ffi::c_void, prefix: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReport", "LastGeneratedFileNamePrefix", 12usize, |this| ((**(this as *const *const IFsrmReport_Vtbl)).LastGeneratedFileNamePrefix)(this, prefix), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFilter(this: *mut core::ffi::c_void, filter: FsrmReportFilter, filtervalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReport", "GetFilter", 13usize, |this| ((**(this as *const *const IFsrmReport_Vtbl)).GetFilter)(this, filter, filtervalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFilter(this: *mut core::ffi::c_void, filter: FsrmReportFilter, filtervalue: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReport", "SetFilter", 14usize, |this| ((**(this as *const *const IFsrmReport_Vtbl)).SetFilter)(this, filter, filtervalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReport", "Delete", 15usize, |this| ((**(this as *const *const IFsrmReport_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Type,
+            Name,
+            SetName,
+            Description,
+            SetDescription,
+            LastGeneratedFileNamePrefix,
+            GetFilter,
+            SetFilter,
+            Delete,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmReport as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmReportJob_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Task(this: *mut core::ffi::c_void, taskname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportJob", "Task", 12usize, |this| ((**(this as *const *const IFsrmReportJob_Vtbl)).Task)(this, taskname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTask(this: *mut core::ffi::c_void, taskname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportJob", "SetTask", 13usize, |this| ((**(this as *const *const IFsrmReportJob_Vtbl)).SetTask)(this, taskname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn NamespaceRoots(this: *mut core::ffi::c_void, namespaceroots: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportJob", "NamespaceRoots", 14usize, |this| ((**(this as *const *const IFsrmReportJob_Vtbl)).NamespaceRoots)(this, namespaceroots), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetNamespaceRoots(this: *mut core::ffi::c_void, namespaceroots: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportJob", "SetNamespaceRoots", 15usize, |this| ((**(this as *const *const IFsrmReportJob_Vtbl)).SetNamespaceRoots)(this, namespaceroots), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Formats(this: *mut core::ffi::c_void, formats: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportJob", "Formats", 16usize, |this| ((**(this as *const *const IFsrmReportJob_Vtbl)).Formats)(this, formats), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetFormats(this: *mut core::ffi::c_void, formats: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportJob", "SetFormats", 17usize, |this| ((**(this as *const *const IFsrmReportJob_Vtbl)).SetFormats)(this, formats), |result| Some(*result))
+        }
+        unsafe extern "system" fn MailTo(this: *mut core::ffi::c_void, mailto: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportJob", "MailTo", 18usize, |this| ((**(this as *const *const IFsrmReportJob_Vtbl)).MailTo)(this, mailto), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMailTo(this: *mut core::ffi::c_void, mailto: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportJob", "SetMailTo", 19usize, |this| ((**(this as *const *const IFsrmReportJob_Vtbl)).SetMailTo)(this, mailto), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunningStatus(this: *mut core::ffi::c_void, runningstatus: *mut FsrmReportRunningStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportJob", "RunningStatus", 20usize, |this| ((**(this as *const *const IFsrmReportJob_Vtbl)).RunningStatus)(this, runningstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastRun(this: *mut core::ffi::c_void, lastrun: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportJob", "LastRun", 21usize, |this| ((**(this as *const *const IFsrmReportJob_Vtbl)).LastRun)(this, lastrun), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastError(this: *mut core::ffi::c_void, lasterror: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportJob", "LastError", 22usize, |this| ((**(this as *const *const IFsrmReportJob_Vtbl)).LastError)(this, lasterror), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastGeneratedInDirectory(this: *mut core::ffi::c_void, path: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportJob", "LastGeneratedInDirectory", 23usize, |this| ((**(this as *const *const IFsrmReportJob_Vtbl)).LastGeneratedInDirectory)(this, path), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumReports(this: *mut core::ffi::c_void, reports: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportJob", "EnumReports", 24usize, |this| ((**(this as *const *const IFsrmReportJob_Vtbl)).EnumReports)(this, reports), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateReport(this: *mut core::ffi::c_void, reporttype: FsrmReportType, report: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportJob", "CreateReport", 25usize, |this| ((**(this as *const *const IFsrmReportJob_Vtbl)).CreateReport)(this, reporttype, report), |result| Some(*result))
+        }
+        unsafe extern "system" fn Run(this: *mut core::ffi::c_void, context: FsrmReportGenerationContext) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportJob", "Run", 26usize, |this| ((**(this as *const *const IFsrmReportJob_Vtbl)).Run)(this, context), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForCompletion(this: *mut core::ffi::c_void, waitseconds: i32, completed: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportJob", "WaitForCompletion", 27usize, |this| ((**(this as *const *const IFsrmReportJob_Vtbl)).WaitForCompletion)(this, waitseconds, completed), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportJob", "Cancel", 28usize, |this| ((**(this as *const *const IFsrmReportJob_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Task,
+            SetTask,
+            #[cfg(feature = "Win32_System_Com")]
+            NamespaceRoots,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            NamespaceRoots: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetNamespaceRoots,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetNamespaceRoots: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Formats,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Formats: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetFormats,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetFormats: 0,
+            MailTo,
+            SetMailTo,
+            RunningStatus,
+            LastRun,
+            LastError,
+            LastGeneratedInDirectory,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumReports,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumReports: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateReport,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateReport: 0,
+            Run,
+            WaitForCompletion,
+            Cancel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmReportJob as windows_core::Interface>::IID || <IFsrmObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmReportManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumReportJobs(this: *mut core::ffi::c_void, options: FsrmEnumOptions, reportjobs: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportManager", "EnumReportJobs", 7usize, |this| ((**(this as *const *const IFsrmReportManager_Vtbl)).EnumReportJobs)(this, options, reportjobs), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateReportJob(this: *mut core::ffi::c_void, reportjob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportManager", "CreateReportJob", 8usize, |this| ((**(this as *const *const IFsrmReportManager_Vtbl)).CreateReportJob)(this, reportjob), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetReportJob(this: *mut core::ffi::c_void, taskname: core::mem::MaybeUninit<windows_core::BSTR>, reportjob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportManager", "GetReportJob", 9usize, |this| ((**(this as *const *const IFsrmReportManager_Vtbl)).GetReportJob)(this, taskname, reportjob), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputDirectory(this: *mut core::ffi::c_void, context: FsrmReportGenerationContext, path: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportManager", "GetOutputDirectory", 10usize, |this| ((**(this as *const *const IFsrmReportManager_Vtbl)).GetOutputDirectory)(this, context, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputDirectory(this: *mut core::ffi::c_void, context: FsrmReportGenerationContext, path: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportManager", "SetOutputDirectory", 11usize, |this| ((**(this as *const *const IFsrmReportManager_Vtbl)).SetOutputDirectory)(this, context, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFilterValidForReportType(this: *mut core::ffi::c_void, reporttype: FsrmReportType, filter: FsrmReportFilter, valid: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportManager", "IsFilterValidForReportType", 12usize, |this| ((**(this as *const *const IFsrmReportManager_Vtbl)).IsFilterValidForReportType)(this, reporttype, filter, valid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultFilter(this: *mut core::ffi::c_void, reporttype: FsrmReportType, filter: FsrmReportFilter, filtervalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportManager", "GetDefaultFilter", 13usize, |this| ((**(this as *const *const IFsrmReportManager_Vtbl)).GetDefaultFilter)(this, reporttype, filter, filtervalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultFilter(this: *mut core::ffi::c_void, reporttype: FsrmReportType, filter: FsrmReportFilter, filtervalue: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportManager", "SetDefaultFilter", 14usize, |this| ((**(this as *const *const IFsrmReportManager_Vtbl)).SetDefaultFilter)(this, reporttype, filter, filtervalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReportSizeLimit(this: *mut core::ffi::c_void, limit: FsrmReportLimit, limitvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportManager", "GetReportSizeLimit", 15usize, |this| ((**(this as *const *const IFsrmReportManager_Vtbl)).GetReportSizeLimit)(this, limit, limitvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReportSizeLimit(this: *mut core::ffi::c_void, limit: FsrmReportLimit, limitvalue: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportManager", "SetReportSizeLimit", 16usize, |this| ((**(this as *const *const IFsrmReportManager_Vtbl)).SetReportSizeLimit)(this, limit, limitvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumReportJobs,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumReportJobs: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateReportJob,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateReportJob: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetReportJob,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetReportJob: 0,
+            GetOutputDirectory,
+            SetOutputDirectory,
+            IsFilterValidForReportType,
+            GetDefaultFilter,
+            SetDefaultFilter,
+            GetReportSizeLimit,
+            SetReportSizeLimit,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmReportManager as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmReportScheduler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VerifyNamespaces(this: *mut core::ffi::c_void, namespacessafearray: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportScheduler", "VerifyNamespaces", 7usize, |this| ((**(this as *const *const IFsrmReportScheduler_Vtbl)).VerifyNamespaces)(this, namespacessafearray), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateScheduleTask(this: *mut core::ffi::c_void, taskname: core::mem::MaybeUninit<windows_core::BSTR>, namespacessafearray: *const core::mem::MaybeUninit<windows_core::VARIANT>, serializedtask: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportScheduler", "CreateScheduleTask", 8usize, |this| ((**(this as *const *const IFsrmReportScheduler_Vtbl)).CreateScheduleTask)(this, taskname, namespacessafearray, serializedtask), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModifyScheduleTask(this: *mut core::ffi::c_void, taskname: core::mem::MaybeUninit<windows_core::BSTR>, namespacessafearray: *const core::mem::MaybeUninit<windows_core::VARIANT>, serializedtask: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportScheduler", "ModifyScheduleTask", 9usize, |this| ((**(this as *const *const IFsrmReportScheduler_Vtbl)).ModifyScheduleTask)(this, taskname, namespacessafearray, serializedtask), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteScheduleTask(this: *mut core::ffi::c_void, taskname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmReportScheduler", "DeleteScheduleTask", 10usize, |this| ((**(this as *const *const IFsrmReportScheduler_Vtbl)).DeleteScheduleTask)(this, taskname), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            VerifyNamespaces,
+            CreateScheduleTask,
+            ModifyScheduleTask,
+            DeleteScheduleTask,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmReportScheduler as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmRule_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmRule", "Name", 12usize, |this| ((**(this as *const *const IFsrmRule_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmRule", "SetName", 13usize, |this| ((**(this as *const *const IFsrmRule_Vtbl)).SetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn RuleType(this: *mut core::ffi::c_void, ruletype: *mut FsrmRuleType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmRule", "RuleType", 14usize, |this| ((**(this as *const *const IFsrmRule_Vtbl)).RuleType)(this, ruletype), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModuleDefinitionName(this: *mut core::ffi::c_void, moduledefinitionname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmRule", "ModuleDefinitionName", 15usize, |this| ((**(this as *const *const IFsrmRule_Vtbl)).ModuleDefinitionName)(this, moduledefinitionname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetModuleDefinitionName(this: *mut core::ffi::c_void, moduledefinitionname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmRule", "SetModuleDefinitionName", 16usize, |this| ((**(this as *const *const IFsrmRule_Vtbl)).SetModuleDefinitionName)(this, moduledefinitionname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn NamespaceRoots(this: *mut core::ffi::c_void, namespaceroots: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmRule", "NamespaceRoots", 17usize, |this| ((**(this as *const *const IFsrmRule_Vtbl)).NamespaceRoots)(this, namespaceroots), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetNamespaceRoots(this: *mut core::ffi::c_void, namespaceroots: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmRule", "SetNamespaceRoots", 18usize, |this| ((**(this as *const *const IFsrmRule_Vtbl)).SetNamespaceRoots)(this, namespaceroots), |result| Some(*result))
+        }
+        unsafe extern "system" fn RuleFlags(this: *mut core::ffi::c_void, ruleflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmRule", "RuleFlags", 19usize, |this| ((**(this as *const *const IFsrmRule_Vtbl)).RuleFlags)(this, ruleflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRuleFlags(this: *mut core::ffi::c_void, ruleflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmRule", "SetRuleFlags", 20usize, |this| ((**(this as *const *const IFsrmRule_Vtbl)).SetRuleFlags)(this, ruleflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Parameters(this: *mut core::ffi::c_void, parameters: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmRule", "Parameters", 21usize, |this| ((**(this as *const *const IFsrmRule_Vtbl)).Parameters)(this, parameters), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetParameters(this: *mut core::ffi::c_void, parameters: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmRule", "SetParameters", 22usize, |this| ((**(this as *const *const IFsrmRule_Vtbl)).SetParameters)(this, parameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastModified(this: *mut core::ffi::c_void, lastmodified: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmRule", "LastModified", 23usize, |this| ((**(this as *const *const IFsrmRule_Vtbl)).LastModified)(this, lastmodified), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            SetName,
+            RuleType,
+            ModuleDefinitionName,
+            SetModuleDefinitionName,
+            #[cfg(feature = "Win32_System_Com")]
+            NamespaceRoots,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            NamespaceRoots: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetNamespaceRoots,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetNamespaceRoots: 0,
+            RuleFlags,
+            SetRuleFlags,
+            #[cfg(feature = "Win32_System_Com")]
+            Parameters,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Parameters: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetParameters,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetParameters: 0,
+            LastModified,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmRule as windows_core::Interface>::IID || <IFsrmObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmSetting_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SmtpServer(this: *mut core::ffi::c_void, smtpserver: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmSetting", "SmtpServer", 7usize, |this| ((**(this as *const *const IFsrmSetting_Vtbl)).SmtpServer)(this, smtpserver), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSmtpServer(this: *mut core::ffi::c_void, smtpserver: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmSetting", "SetSmtpServer", 8usize, |this| ((**(this as *const *const IFsrmSetting_Vtbl)).SetSmtpServer)(this, smtpserver), |result| Some(*result))
+        }
+        unsafe extern "system" fn MailFrom(this: *mut core::ffi::c_void, mailfrom: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmSetting", "MailFrom", 9usize, |this| ((**(this as *const *const IFsrmSetting_Vtbl)).MailFrom)(this, mailfrom), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMailFrom(this: *mut core::ffi::c_void, mailfrom: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmSetting", "SetMailFrom", 10usize, |this| ((**(this as *const *const IFsrmSetting_Vtbl)).SetMailFrom)(this, mailfrom), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdminEmail(this: *mut core::ffi::c_void, adminemail: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmSetting", "AdminEmail", 11usize, |this| ((**(this as *const *const IFsrmSetting_Vtbl)).AdminEmail)(this, adminemail), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAdminEmail(this: *mut core::ffi::c_void, adminemail: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmSetting", "SetAdminEmail", 12usize, |this| ((**(this as *const *const IFsrmSetting_Vtbl)).SetAdminEmail)(this, adminemail), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableCommandLine(this: *mut core::ffi::c_void, disablecommandline: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmSetting", "DisableCommandLine", 13usize, |this| ((**(this as *const *const IFsrmSetting_Vtbl)).DisableCommandLine)(this, disablecommandline), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisableCommandLine(this: *mut core::ffi::c_void, disablecommandline: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmSetting", "SetDisableCommandLine", 14usize, |this| ((**(this as *const *const IFsrmSetting_Vtbl)).SetDisableCommandLine)(this, disablecommandline), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableScreeningAudit(this: *mut core::ffi::c_void, enablescreeningaudit: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmSetting", "EnableScreeningAudit", 15usize, |this| ((**(this as *const *const IFsrmSetting_Vtbl)).EnableScreeningAudit)(this, enablescreeningaudit), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnableScreeningAudit(this: *mut core::ffi::c_void, enablescreeningaudit: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmSetting", "SetEnableScreeningAudit", 16usize, |this| ((**(this as *const *const IFsrmSetting_Vtbl)).SetEnableScreeningAudit)(this, enablescreeningaudit), |result| Some(*result))
+        }
+        unsafe extern "system" fn EmailTest(this: *mut core::ffi::c_void, mailto: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmSetting", "EmailTest", 17usize, |this| ((**(this as *const *const IFsrmSetting_Vtbl)).EmailTest)(this, mailto), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActionRunLimitInterval(this: *mut core::ffi::c_void, actiontype: FsrmActionType, delaytimeminutes: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmSetting", "SetActionRunLimitInterval", 18usize, |this| ((**(this as *const *const IFsrmSetting_Vtbl)).SetActionRunLimitInterval)(this, actiontype, delaytimeminutes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActionRunLimitInterval(this: *mut core::ffi::c_void, actiontype: FsrmActionType, delaytimeminutes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmSetting", "GetActionRunLimitInterval", 19usize, |this| ((**(this as *const *const IFsrmSetting_Vtbl)).GetActionRunLimitInterval)(this, actiontype, delaytimeminutes), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SmtpServer,
+            SetSmtpServer,
+            MailFrom,
+            SetMailFrom,
+            AdminEmail,
+            SetAdminEmail,
+            DisableCommandLine,
+            SetDisableCommandLine,
+            EnableScreeningAudit,
+            SetEnableScreeningAudit,
+            EmailTest,
+            SetActionRunLimitInterval,
+            GetActionRunLimitInterval,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmSetting as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmStorageModuleDefinition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Capabilities(this: *mut core::ffi::c_void, capabilities: *mut FsrmStorageModuleCaps) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmStorageModuleDefinition", "Capabilities", 31usize, |this| ((**(this as *const *const IFsrmStorageModuleDefinition_Vtbl)).Capabilities)(this, capabilities), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCapabilities(this: *mut core::ffi::c_void, capabilities: FsrmStorageModuleCaps) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmStorageModuleDefinition", "SetCapabilities", 32usize, |this| ((**(this as *const *const IFsrmStorageModuleDefinition_Vtbl)).SetCapabilities)(this, capabilities), |result| Some(*result))
+        }
+        unsafe extern "system" fn StorageType(this: *mut core::ffi::c_void, storagetype: *mut FsrmStorageModuleType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmStorageModuleDefinition", "StorageType", 33usize, |this| ((**(this as *const *const IFsrmStorageModuleDefinition_Vtbl)).StorageType)(this, storagetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStorageType(this: *mut core::ffi::c_void, storagetype: FsrmStorageModuleType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmStorageModuleDefinition", "SetStorageType", 34usize, |this| ((**(this as *const *const IFsrmStorageModuleDefinition_Vtbl)).SetStorageType)(this, storagetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdatesFileContent(this: *mut core::ffi::c_void, updatesfilecontent: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmStorageModuleDefinition", "UpdatesFileContent", 35usize, |this| ((**(this as *const *const IFsrmStorageModuleDefinition_Vtbl)).UpdatesFileContent)(this, updatesfilecontent), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUpdatesFileContent(this: *mut core::ffi::c_void, updatesfilecontent: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmStorageModuleDefinition", "SetUpdatesFileContent", 36usize, |this| ((**(this as *const *const IFsrmStorageModuleDefinition_Vtbl)).SetUpdatesFileContent)(this, updatesfilecontent), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmPipelineModuleDefinition_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Capabilities,
+            SetCapabilities,
+            StorageType,
+            SetStorageType,
+            UpdatesFileContent,
+            SetUpdatesFileContent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmStorageModuleDefinition as windows_core::Interface>::IID || <IFsrmPipelineModuleDefinition_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsrmStorageModuleImplementation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn UseDefinitions(this: *mut core::ffi::c_void, propertydefinitions: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmStorageModuleImplementation", "UseDefinitions", 9usize, |this| ((**(this as *const *const IFsrmStorageModuleImplementation_Vtbl)).UseDefinitions)(this, propertydefinitions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn LoadProperties(this: *mut core::ffi::c_void, propertybag: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmStorageModuleImplementation", "LoadProperties", 10usize, |this| ((**(this as *const *const IFsrmStorageModuleImplementation_Vtbl)).LoadProperties)(this, propertybag), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SaveProperties(this: *mut core::ffi::c_void, propertybag: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsrmStorageModuleImplementation", "SaveProperties", 11usize, |this| ((**(this as *const *const IFsrmStorageModuleImplementation_Vtbl)).SaveProperties)(this, propertybag), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsrmPipelineModuleImplementation_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            UseDefinitions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            UseDefinitions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            LoadProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            LoadProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SaveProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SaveProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsrmStorageModuleImplementation as windows_core::Interface>::IID || <IFsrmPipelineModuleImplementation_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
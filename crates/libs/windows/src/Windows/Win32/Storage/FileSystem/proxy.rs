@@ -0,0 +1,233 @@
+#[cfg(all(feature = "Win32_Security", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IDiskQuotaControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, breadwrite: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaControl", "Initialize", 5usize, |this| ((**(this as *const *const IDiskQuotaControl_Vtbl)).Initialize)(this, pszpath, breadwrite), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQuotaState(this: *mut core::ffi::c_void, dwstate: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaControl", "SetQuotaState", 6usize, |this| ((**(this as *const *const IDiskQuotaControl_Vtbl)).SetQuotaState)(this, dwstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetQuotaState(this: *mut core::ffi::c_void, pdwstate: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaControl", "GetQuotaState", 7usize, |this| ((**(this as *const *const IDiskQuotaControl_Vtbl)).GetQuotaState)(this, pdwstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQuotaLogFlags(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaControl", "SetQuotaLogFlags", 8usize, |this| ((**(this as *const *const IDiskQuotaControl_Vtbl)).SetQuotaLogFlags)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetQuotaLogFlags(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaControl", "GetQuotaLogFlags", 9usize, |this| ((**(this as *const *const IDiskQuotaControl_Vtbl)).GetQuotaLogFlags)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultQuotaThreshold(this: *mut core::ffi::c_void, llthreshold: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaControl", "SetDefaultQuotaThreshold", 10usize, |this| ((**(this as *const *const IDiskQuotaControl_Vtbl)).SetDefaultQuotaThreshold)(this, llthreshold), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultQuotaThreshold(this: *mut core::ffi::c_void, pllthreshold: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaControl", "GetDefaultQuotaThreshold", 11usize, |this| ((**(this as *const *const IDiskQuotaControl_Vtbl)).GetDefaultQuotaThreshold)(this, pllthreshold), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultQuotaThresholdText(this: *mut core::ffi::c_void, psztext: windows_core::PCWSTR, cchtext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaControl", "GetDefaultQuotaThresholdText", 12usize, |this| ((**(this as *const *const IDiskQuotaControl_Vtbl)).GetDefaultQuotaThresholdText)(this, psztext, cchtext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultQuotaLimit(this: *mut core::ffi::c_void, lllimit: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaControl", "SetDefaultQuotaLimit", 13usize, |this| ((**(this as *const *const IDiskQuotaControl_Vtbl)).SetDefaultQuotaLimit)(this, lllimit), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultQuotaLimit(this: *mut core::ffi::c_void, plllimit: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaControl", "GetDefaultQuotaLimit", 14usize, |this| ((**(this as *const *const IDiskQuotaControl_Vtbl)).GetDefaultQuotaLimit)(this, plllimit), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultQuotaLimitText(this: *mut core::ffi::c_void, psztext: windows_core::PCWSTR, cchtext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaControl", "GetDefaultQuotaLimitText", 15usize, |this| ((**(this as *const *const IDiskQuotaControl_Vtbl)).GetDefaultQuotaLimitText)(this, psztext, cchtext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Security")]
+        unsafe extern "system" fn AddUserSid(this: *mut core::ffi::c_void, pusersid: super::super::Security::PSID, fnameresolution: DISKQUOTA_USERNAME_RESOLVE, ppuser: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaControl", "AddUserSid", 16usize, |this| ((**(this as *const *const IDiskQuotaControl_Vtbl)).AddUserSid)(this, pusersid, fnameresolution, ppuser), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddUserName(this: *mut core::ffi::c_void, pszlogonname: windows_core::PCWSTR, fnameresolution: DISKQUOTA_USERNAME_RESOLVE, ppuser: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaControl", "AddUserName", 17usize, |this| ((**(this as *const *const IDiskQuotaControl_Vtbl)).AddUserName)(this, pszlogonname, fnameresolution, ppuser), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteUser(this: *mut core::ffi::c_void, puser: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaControl", "DeleteUser", 18usize, |this| ((**(this as *const *const IDiskQuotaControl_Vtbl)).DeleteUser)(this, puser), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Security")]
+        unsafe extern "system" fn FindUserSid(this: *mut core::ffi::c_void, pusersid: super::super::Security::PSID, fnameresolution: DISKQUOTA_USERNAME_RESOLVE, ppuser: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaControl", "FindUserSid", 19usize, |this| ((**(this as *const *const IDiskQuotaControl_Vtbl)).FindUserSid)(this, pusersid, fnameresolution, ppuser), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindUserName(this: *mut core::ffi::c_void, pszlogonname: windows_core::PCWSTR, ppuser: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaControl", "FindUserName", 20usize, |this| ((**(this as *const *const IDiskQuotaControl_Vtbl)).FindUserName)(this, pszlogonname, ppuser), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Security")]
+        unsafe extern "system" fn CreateEnumUsers(this: *mut core::ffi::c_void, rgpusersids: *mut super::super::Security::PSID, cpsids: u32, fnameresolution: DISKQUOTA_USERNAME_RESOLVE, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaControl", "CreateEnumUsers", 21usize, |this| ((**(this as *const *const IDiskQuotaControl_Vtbl)).CreateEnumUsers)(this, rgpusersids, cpsids, fnameresolution, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateUserBatch(this: *mut core::ffi::c_void, ppbatch: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaControl", "CreateUserBatch", 22usize, |this| ((**(this as *const *const IDiskQuotaControl_Vtbl)).CreateUserBatch)(this, ppbatch), |result| Some(*result))
+        }
+        unsafe extern "system" fn InvalidateSidNameCache(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaControl", "InvalidateSidNameCache", 23usize, |this| ((**(this as *const *const IDiskQuotaControl_Vtbl)).InvalidateSidNameCache)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GiveUserNameResolutionPriority(this: *mut core::ffi::c_void, puser: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaControl", "GiveUserNameResolutionPriority", 24usize, |this| ((**(this as *const *const IDiskQuotaControl_Vtbl)).GiveUserNameResolutionPriority)(this, puser), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShutdownNameResolution(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaControl", "ShutdownNameResolution", 25usize, |this| ((**(this as *const *const IDiskQuotaControl_Vtbl)).ShutdownNameResolution)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IConnectionPointContainer_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            SetQuotaState,
+            GetQuotaState,
+            SetQuotaLogFlags,
+            GetQuotaLogFlags,
+            SetDefaultQuotaThreshold,
+            GetDefaultQuotaThreshold,
+            GetDefaultQuotaThresholdText,
+            SetDefaultQuotaLimit,
+            GetDefaultQuotaLimit,
+            GetDefaultQuotaLimitText,
+            #[cfg(feature = "Win32_Security")]
+            AddUserSid,
+            #[cfg(not(feature = "Win32_Security"))]
+            AddUserSid: 0,
+            AddUserName,
+            DeleteUser,
+            #[cfg(feature = "Win32_Security")]
+            FindUserSid,
+            #[cfg(not(feature = "Win32_Security"))]
+            FindUserSid: 0,
+            FindUserName,
+            #[cfg(feature = "Win32_Security")]
+            CreateEnumUsers,
+            #[cfg(not(feature = "Win32_Security"))]
+            CreateEnumUsers: 0,
+            CreateUserBatch,
+            InvalidateSidNameCache,
+            GiveUserNameResolutionPriority,
+            ShutdownNameResolution,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDiskQuotaControl as windows_core::Interface>::IID || <super::super::System::Com::IConnectionPointContainer_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDiskQuotaEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnUserNameChanged(this: *mut core::ffi::c_void, puser: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaEvents", "OnUserNameChanged", 3usize, |this| ((**(this as *const *const IDiskQuotaEvents_Vtbl)).OnUserNameChanged)(this, puser), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnUserNameChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDiskQuotaEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDiskQuotaUser_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetID(this: *mut core::ffi::c_void, pulid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaUser", "GetID", 3usize, |this| ((**(this as *const *const IDiskQuotaUser_Vtbl)).GetID)(this, pulid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, pszaccountcontainer: windows_core::PCWSTR, cchaccountcontainer: u32, pszlogonname: windows_core::PCWSTR, cchlogonname: u32, pszdisplayname: windows_core::PCWSTR, cchdisplayname: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaUser", "GetName", 4usize, |this| ((**(this as *const *const IDiskQuotaUser_Vtbl)).GetName)(this, pszaccountcontainer, cchaccountcontainer, pszlogonname, cchlogonname, pszdisplayname, cchdisplayname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSidLength(this: *mut core::ffi::c_void, pdwlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaUser", "GetSidLength", 5usize, |this| ((**(this as *const *const IDiskQuotaUser_Vtbl)).GetSidLength)(this, pdwlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSid(this: *mut core::ffi::c_void, pbsidbuffer: *mut u8, cbsidbuffer: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaUser", "GetSid", 6usize, |this| ((**(this as *const *const IDiskQuotaUser_Vtbl)).GetSid)(this, pbsidbuffer, cbsidbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetQuotaThreshold(this: *mut core::ffi::c_void, pllthreshold: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaUser", "GetQuotaThreshold", 7usize, |this| ((**(this as *const *const IDiskQuotaUser_Vtbl)).GetQuotaThreshold)(this, pllthreshold), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetQuotaThresholdText(this: *mut core::ffi::c_void, psztext: windows_core::PCWSTR, cchtext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaUser", "GetQuotaThresholdText", 8usize, |this| ((**(this as *const *const IDiskQuotaUser_Vtbl)).GetQuotaThresholdText)(this, psztext, cchtext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetQuotaLimit(this: *mut core::ffi::c_void, plllimit: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaUser", "GetQuotaLimit", 9usize, |this| ((**(this as *const *const IDiskQuotaUser_Vtbl)).GetQuotaLimit)(this, plllimit), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetQuotaLimitText(this: *mut core::ffi::c_void, psztext: windows_core::PCWSTR, cchtext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaUser", "GetQuotaLimitText", 10usize, |this| ((**(this as *const *const IDiskQuotaUser_Vtbl)).GetQuotaLimitText)(this, psztext, cchtext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetQuotaUsed(this: *mut core::ffi::c_void, pllused: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaUser", "GetQuotaUsed", 11usize, |this| ((**(this as *const *const IDiskQuotaUser_Vtbl)).GetQuotaUsed)(this, pllused), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetQuotaUsedText(this: *mut core::ffi::c_void, psztext: windows_core::PCWSTR, cchtext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaUser", "GetQuotaUsedText", 12usize, |this| ((**(this as *const *const IDiskQuotaUser_Vtbl)).GetQuotaUsedText)(this, psztext, cchtext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetQuotaInformation(this: *mut core::ffi::c_void, pbquotainfo: *mut core::ffi::c_void, cbquotainfo: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaUser", "GetQuotaInformation", 13usize, |this| ((**(this as *const *const IDiskQuotaUser_Vtbl)).GetQuotaInformation)(this, pbquotainfo, cbquotainfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQuotaThreshold(this: *mut core::ffi::c_void, llthreshold: i64, fwritethrough: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaUser", "SetQuotaThreshold", 14usize, |this| ((**(this as *const *const IDiskQuotaUser_Vtbl)).SetQuotaThreshold)(this, llthreshold, fwritethrough), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQuotaLimit(this: *mut core::ffi::c_void, lllimit: i64, fwritethrough: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaUser", "SetQuotaLimit", 15usize, |this| ((**(this as *const *const IDiskQuotaUser_Vtbl)).SetQuotaLimit)(this, lllimit, fwritethrough), |result| Some(*result))
+        }
+        unsafe extern "system" fn Invalidate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaUser", "Invalidate", 16usize, |this| ((**(this as *const *const IDiskQuotaUser_Vtbl)).Invalidate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAccountStatus(this: *mut core::ffi::c_void, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaUser", "GetAccountStatus", 17usize, |this| ((**(this as *const *const IDiskQuotaUser_Vtbl)).GetAccountStatus)(this, pdwstatus), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetID,
+            GetName,
+            GetSidLength,
+            GetSid,
+            GetQuotaThreshold,
+            GetQuotaThresholdText,
+            GetQuotaLimit,
+            GetQuotaLimitText,
+            GetQuotaUsed,
+            GetQuotaUsedText,
+            GetQuotaInformation,
+            SetQuotaThreshold,
+            SetQuotaLimit,
+            Invalidate,
+            GetAccountStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDiskQuotaUser as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDiskQuotaUserBatch_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, puser: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaUserBatch", "Add", 3usize, |this| ((**(this as *const *const IDiskQuotaUserBatch_Vtbl)).Add)(this, puser), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, puser: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaUserBatch", "Remove", 4usize, |this| ((**(this as *const *const IDiskQuotaUserBatch_Vtbl)).Remove)(this, puser), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaUserBatch", "RemoveAll", 5usize, |this| ((**(this as *const *const IDiskQuotaUserBatch_Vtbl)).RemoveAll)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlushToDisk(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiskQuotaUserBatch", "FlushToDisk", 6usize, |this| ((**(this as *const *const IDiskQuotaUserBatch_Vtbl)).FlushToDisk)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Add, Remove, RemoveAll, FlushToDisk }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDiskQuotaUserBatch as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumDiskQuotaUsers_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, cusers: u32, rgusers: *mut *mut core::ffi::c_void, pcusersfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDiskQuotaUsers", "Next", 3usize, |this| ((**(this as *const *const IEnumDiskQuotaUsers_Vtbl)).Next)(this, cusers, rgusers, pcusersfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, cusers: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDiskQuotaUsers", "Skip", 4usize, |this| ((**(this as *const *const IEnumDiskQuotaUsers_Vtbl)).Skip)(this, cusers), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDiskQuotaUsers", "Reset", 5usize, |this| ((**(this as *const *const IEnumDiskQuotaUsers_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDiskQuotaUsers", "Clone", 6usize, |this| ((**(this as *const *const IEnumDiskQuotaUsers_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumDiskQuotaUsers as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -4333,6 +4333,7 @@ pub struct DISC_RECORDER_STATE_FLAGS(pub u32);
 impl windows_core::TypeKind for DISC_RECORDER_STATE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISC_RECORDER_STATE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISC_RECORDER_STATE_FLAGS").field(&self.0).finish()
@@ -4344,6 +4345,7 @@ pub struct EmulationType(pub i32);
 impl windows_core::TypeKind for EmulationType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EmulationType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EmulationType").field(&self.0).finish()
@@ -4355,6 +4357,7 @@ pub struct FsiFileSystems(pub i32);
 impl windows_core::TypeKind for FsiFileSystems {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsiFileSystems {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsiFileSystems").field(&self.0).finish()
@@ -4366,6 +4369,7 @@ pub struct FsiItemType(pub i32);
 impl windows_core::TypeKind for FsiItemType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FsiItemType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FsiItemType").field(&self.0).finish()
@@ -4377,6 +4381,7 @@ pub struct IMAPI_BURN_VERIFICATION_LEVEL(pub i32);
 impl windows_core::TypeKind for IMAPI_BURN_VERIFICATION_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAPI_BURN_VERIFICATION_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAPI_BURN_VERIFICATION_LEVEL").field(&self.0).finish()
@@ -4388,6 +4393,7 @@ pub struct IMAPI_CD_SECTOR_TYPE(pub i32);
 impl windows_core::TypeKind for IMAPI_CD_SECTOR_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAPI_CD_SECTOR_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAPI_CD_SECTOR_TYPE").field(&self.0).finish()
@@ -4399,6 +4405,7 @@ pub struct IMAPI_CD_TRACK_DIGITAL_COPY_SETTING(pub i32);
 impl windows_core::TypeKind for IMAPI_CD_TRACK_DIGITAL_COPY_SETTING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAPI_CD_TRACK_DIGITAL_COPY_SETTING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAPI_CD_TRACK_DIGITAL_COPY_SETTING").field(&self.0).finish()
@@ -4410,6 +4417,7 @@ pub struct IMAPI_FEATURE_PAGE_TYPE(pub i32);
 impl windows_core::TypeKind for IMAPI_FEATURE_PAGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAPI_FEATURE_PAGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAPI_FEATURE_PAGE_TYPE").field(&self.0).finish()
@@ -4421,6 +4429,7 @@ pub struct IMAPI_FORMAT2_DATA_MEDIA_STATE(pub i32);
 impl windows_core::TypeKind for IMAPI_FORMAT2_DATA_MEDIA_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAPI_FORMAT2_DATA_MEDIA_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAPI_FORMAT2_DATA_MEDIA_STATE").field(&self.0).finish()
@@ -4432,6 +4441,7 @@ pub struct IMAPI_FORMAT2_DATA_WRITE_ACTION(pub i32);
 impl windows_core::TypeKind for IMAPI_FORMAT2_DATA_WRITE_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAPI_FORMAT2_DATA_WRITE_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAPI_FORMAT2_DATA_WRITE_ACTION").field(&self.0).finish()
@@ -4443,6 +4453,7 @@ pub struct IMAPI_FORMAT2_RAW_CD_DATA_SECTOR_TYPE(pub i32);
 impl windows_core::TypeKind for IMAPI_FORMAT2_RAW_CD_DATA_SECTOR_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAPI_FORMAT2_RAW_CD_DATA_SECTOR_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAPI_FORMAT2_RAW_CD_DATA_SECTOR_TYPE").field(&self.0).finish()
@@ -4454,6 +4465,7 @@ pub struct IMAPI_FORMAT2_RAW_CD_WRITE_ACTION(pub i32);
 impl windows_core::TypeKind for IMAPI_FORMAT2_RAW_CD_WRITE_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAPI_FORMAT2_RAW_CD_WRITE_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAPI_FORMAT2_RAW_CD_WRITE_ACTION").field(&self.0).finish()
@@ -4465,6 +4477,7 @@ pub struct IMAPI_FORMAT2_TAO_WRITE_ACTION(pub i32);
 impl windows_core::TypeKind for IMAPI_FORMAT2_TAO_WRITE_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAPI_FORMAT2_TAO_WRITE_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAPI_FORMAT2_TAO_WRITE_ACTION").field(&self.0).finish()
@@ -4476,6 +4489,7 @@ pub struct IMAPI_MEDIA_PHYSICAL_TYPE(pub i32);
 impl windows_core::TypeKind for IMAPI_MEDIA_PHYSICAL_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAPI_MEDIA_PHYSICAL_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAPI_MEDIA_PHYSICAL_TYPE").field(&self.0).finish()
@@ -4487,6 +4501,7 @@ pub struct IMAPI_MEDIA_WRITE_PROTECT_STATE(pub i32);
 impl windows_core::TypeKind for IMAPI_MEDIA_WRITE_PROTECT_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAPI_MEDIA_WRITE_PROTECT_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAPI_MEDIA_WRITE_PROTECT_STATE").field(&self.0).finish()
@@ -4498,6 +4513,7 @@ pub struct IMAPI_MODE_PAGE_REQUEST_TYPE(pub i32);
 impl windows_core::TypeKind for IMAPI_MODE_PAGE_REQUEST_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAPI_MODE_PAGE_REQUEST_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAPI_MODE_PAGE_REQUEST_TYPE").field(&self.0).finish()
@@ -4509,6 +4525,7 @@ pub struct IMAPI_MODE_PAGE_TYPE(pub i32);
 impl windows_core::TypeKind for IMAPI_MODE_PAGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAPI_MODE_PAGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAPI_MODE_PAGE_TYPE").field(&self.0).finish()
@@ -4520,6 +4537,7 @@ pub struct IMAPI_PROFILE_TYPE(pub i32);
 impl windows_core::TypeKind for IMAPI_PROFILE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAPI_PROFILE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAPI_PROFILE_TYPE").field(&self.0).finish()
@@ -4531,6 +4549,7 @@ pub struct IMAPI_READ_TRACK_ADDRESS_TYPE(pub i32);
 impl windows_core::TypeKind for IMAPI_READ_TRACK_ADDRESS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAPI_READ_TRACK_ADDRESS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAPI_READ_TRACK_ADDRESS_TYPE").field(&self.0).finish()
@@ -4542,6 +4561,7 @@ pub struct IMMPID_CPV_ENUM(pub i32);
 impl windows_core::TypeKind for IMMPID_CPV_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMMPID_CPV_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMMPID_CPV_ENUM").field(&self.0).finish()
@@ -4553,6 +4573,7 @@ pub struct IMMPID_MPV_ENUM(pub i32);
 impl windows_core::TypeKind for IMMPID_MPV_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMMPID_MPV_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMMPID_MPV_ENUM").field(&self.0).finish()
@@ -4564,6 +4585,7 @@ pub struct IMMPID_MP_ENUM(pub i32);
 impl windows_core::TypeKind for IMMPID_MP_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMMPID_MP_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMMPID_MP_ENUM").field(&self.0).finish()
@@ -4575,6 +4597,7 @@ pub struct IMMPID_NMP_ENUM(pub i32);
 impl windows_core::TypeKind for IMMPID_NMP_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMMPID_NMP_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMMPID_NMP_ENUM").field(&self.0).finish()
@@ -4586,6 +4609,7 @@ pub struct IMMPID_RPV_ENUM(pub i32);
 impl windows_core::TypeKind for IMMPID_RPV_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMMPID_RPV_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMMPID_RPV_ENUM").field(&self.0).finish()
@@ -4597,6 +4621,7 @@ pub struct IMMPID_RP_ENUM(pub i32);
 impl windows_core::TypeKind for IMMPID_RP_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMMPID_RP_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMMPID_RP_ENUM").field(&self.0).finish()
@@ -4608,6 +4633,7 @@ pub struct MEDIA_FLAGS(pub i32);
 impl windows_core::TypeKind for MEDIA_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MEDIA_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MEDIA_FLAGS").field(&self.0).finish()
@@ -4619,6 +4645,7 @@ pub struct MEDIA_TYPES(pub i32);
 impl windows_core::TypeKind for MEDIA_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MEDIA_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MEDIA_TYPES").field(&self.0).finish()
@@ -4630,6 +4657,7 @@ pub struct PlatformId(pub i32);
 impl windows_core::TypeKind for PlatformId {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PlatformId {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PlatformId").field(&self.0).finish()
@@ -4641,6 +4669,7 @@ pub struct RECORDER_TYPES(pub i32);
 impl windows_core::TypeKind for RECORDER_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RECORDER_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RECORDER_TYPES").field(&self.0).finish()
@@ -4657,7 +4686,8 @@ pub const FsiFileItem: windows_core::GUID = windows_core::GUID::from_u128(0x2c94
 pub const FsiNamedStreams: windows_core::GUID = windows_core::GUID::from_u128(0xc6b6f8ed_6d19_44b4_b539_b159b793a32d);
 pub const FsiStream: windows_core::GUID = windows_core::GUID::from_u128(0x2c941fcd_975b_59be_a960_9a2a262853a5);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMMP_MPV_STORE_DRIVER_HANDLE {
     pub guidSignature: windows_core::GUID,
 }
@@ -4670,7 +4700,8 @@ impl Default for IMMP_MPV_STORE_DRIVER_HANDLE {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LPMSGSESS(pub isize);
 impl Default for LPMSGSESS {
     fn default() -> Self {
@@ -4703,7 +4734,8 @@ pub const MsftWriteSpeedDescriptor: windows_core::GUID = windows_core::GUID::fro
 pub const ProgressItem: windows_core::GUID = windows_core::GUID::from_u128(0x2c941fcb_975b_59be_a960_9a2a262853a5);
 pub const ProgressItems: windows_core::GUID = windows_core::GUID::from_u128(0x2c941fc9_975b_59be_a960_9a2a262853a5);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SPropAttrArray {
     pub cValues: u32,
     pub aPropAttr: [u32; 1],
@@ -4718,7 +4750,8 @@ impl Default for SPropAttrArray {
 }
 pub const tagIMMPID_CPV_STRUCT: windows_core::GUID = windows_core::GUID::from_u128(0xa2a76b2a_e52d_11d1_aa64_00c04fa35b82);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct tagIMMPID_GUIDLIST_ITEM {
     pub pguid: *const windows_core::GUID,
     pub dwStart: u32,
@@ -4741,3 +4774,5 @@ pub const tagIMMPID_RP_STRUCT: windows_core::GUID = windows_core::GUID::from_u12
 pub type MSGCALLRELEASE = Option<unsafe extern "system" fn(ulcallerdata: u32, lpmessage: Option<super::super::System::AddressBook::IMessage>)>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
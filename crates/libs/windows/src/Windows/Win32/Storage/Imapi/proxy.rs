@@ -0,0 +1,2728 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for DDiscFormat2DataEvents_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void, object: *mut core::ffi::c_void, progress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "DDiscFormat2DataEvents", "Update", 7usize, |this| ((**(this as *const *const DDiscFormat2DataEvents_Vtbl)).Update)(this, object, progress), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Update,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Update: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<DDiscFormat2DataEvents as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for DDiscFormat2EraseEvents_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void, object: *mut core::ffi::c_void, elapsedseconds: i32, estimatedtotalseconds: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "DDiscFormat2EraseEvents", "Update", 7usize, |this| ((**(this as *const *const DDiscFormat2EraseEvents_Vtbl)).Update)(this, object, elapsedseconds, estimatedtotalseconds), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Update,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Update: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<DDiscFormat2EraseEvents as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for DDiscFormat2RawCDEvents_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void, object: *mut core::ffi::c_void, progress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "DDiscFormat2RawCDEvents", "Update", 7usize, |this| ((**(this as *const *const DDiscFormat2RawCDEvents_Vtbl)).Update)(this, object, progress), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Update,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Update: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<DDiscFormat2RawCDEvents as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for DDiscFormat2TrackAtOnceEvents_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void, object: *mut core::ffi::c_void, progress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "DDiscFormat2TrackAtOnceEvents", "Update", 7usize, |this| ((**(this as *const *const DDiscFormat2TrackAtOnceEvents_Vtbl)).Update)(this, object, progress), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Update,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Update: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<DDiscFormat2TrackAtOnceEvents as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for DDiscMaster2Events_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn NotifyDeviceAdded(this: *mut core::ffi::c_void, object: *mut core::ffi::c_void, uniqueid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "DDiscMaster2Events", "NotifyDeviceAdded", 7usize, |this| ((**(this as *const *const DDiscMaster2Events_Vtbl)).NotifyDeviceAdded)(this, object, uniqueid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn NotifyDeviceRemoved(this: *mut core::ffi::c_void, object: *mut core::ffi::c_void, uniqueid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "DDiscMaster2Events", "NotifyDeviceRemoved", 8usize, |this| ((**(this as *const *const DDiscMaster2Events_Vtbl)).NotifyDeviceRemoved)(this, object, uniqueid), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            NotifyDeviceAdded,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            NotifyDeviceAdded: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            NotifyDeviceRemoved,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            NotifyDeviceRemoved: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<DDiscMaster2Events as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for DFileSystemImageEvents_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void, object: *mut core::ffi::c_void, currentfile: core::mem::MaybeUninit<windows_core::BSTR>, copiedsectors: i32, totalsectors: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "DFileSystemImageEvents", "Update", 7usize, |this| ((**(this as *const *const DFileSystemImageEvents_Vtbl)).Update)(this, object, currentfile, copiedsectors, totalsectors), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Update,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Update: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<DFileSystemImageEvents as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for DFileSystemImageImportEvents_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn UpdateImport(this: *mut core::ffi::c_void, object: *mut core::ffi::c_void, filesystem: FsiFileSystems, currentitem: core::mem::MaybeUninit<windows_core::BSTR>, importeddirectoryitems: i32, totaldirectoryitems: i32, importedfileitems: i32, totalfileitems: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "DFileSystemImageImportEvents", "UpdateImport", 7usize, |this| ((**(this as *const *const DFileSystemImageImportEvents_Vtbl)).UpdateImport)(this, object, filesystem, currentitem, importeddirectoryitems, totaldirectoryitems, importedfileitems, totalfileitems), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            UpdateImport,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            UpdateImport: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<DFileSystemImageImportEvents as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for DWriteEngine2Events_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void, object: *mut core::ffi::c_void, progress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "DWriteEngine2Events", "Update", 7usize, |this| ((**(this as *const *const DWriteEngine2Events_Vtbl)).Update)(this, object, progress), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Update,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Update: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<DWriteEngine2Events as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IBlockRange_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartLba(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBlockRange", "StartLba", 7usize, |this| ((**(this as *const *const IBlockRange_Vtbl)).StartLba)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndLba(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBlockRange", "EndLba", 8usize, |this| ((**(this as *const *const IBlockRange_Vtbl)).EndLba)(this, value), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartLba, EndLba }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBlockRange as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IBlockRangeList_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn BlockRanges(this: *mut core::ffi::c_void, value: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBlockRangeList", "BlockRanges", 7usize, |this| ((**(this as *const *const IBlockRangeList_Vtbl)).BlockRanges)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            BlockRanges,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            BlockRanges: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBlockRangeList as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IBootOptions_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn BootImage(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBootOptions", "BootImage", 7usize, |this| ((**(this as *const *const IBootOptions_Vtbl)).BootImage)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Manufacturer(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBootOptions", "Manufacturer", 8usize, |this| ((**(this as *const *const IBootOptions_Vtbl)).Manufacturer)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetManufacturer(this: *mut core::ffi::c_void, newval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBootOptions", "SetManufacturer", 9usize, |this| ((**(this as *const *const IBootOptions_Vtbl)).SetManufacturer)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlatformId(this: *mut core::ffi::c_void, pval: *mut PlatformId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBootOptions", "PlatformId", 10usize, |this| ((**(this as *const *const IBootOptions_Vtbl)).PlatformId)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPlatformId(this: *mut core::ffi::c_void, newval: PlatformId) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBootOptions", "SetPlatformId", 11usize, |this| ((**(this as *const *const IBootOptions_Vtbl)).SetPlatformId)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Emulation(this: *mut core::ffi::c_void, pval: *mut EmulationType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBootOptions", "Emulation", 12usize, |this| ((**(this as *const *const IBootOptions_Vtbl)).Emulation)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEmulation(this: *mut core::ffi::c_void, newval: EmulationType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBootOptions", "SetEmulation", 13usize, |this| ((**(this as *const *const IBootOptions_Vtbl)).SetEmulation)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImageSize(this: *mut core::ffi::c_void, pval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBootOptions", "ImageSize", 14usize, |this| ((**(this as *const *const IBootOptions_Vtbl)).ImageSize)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AssignBootImage(this: *mut core::ffi::c_void, newval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBootOptions", "AssignBootImage", 15usize, |this| ((**(this as *const *const IBootOptions_Vtbl)).AssignBootImage)(this, newval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            BootImage,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            BootImage: 0,
+            Manufacturer,
+            SetManufacturer,
+            PlatformId,
+            SetPlatformId,
+            Emulation,
+            SetEmulation,
+            ImageSize,
+            #[cfg(feature = "Win32_System_Com")]
+            AssignBootImage,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AssignBootImage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBootOptions as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBurnVerification_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetBurnVerificationLevel(this: *mut core::ffi::c_void, value: IMAPI_BURN_VERIFICATION_LEVEL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBurnVerification", "SetBurnVerificationLevel", 3usize, |this| ((**(this as *const *const IBurnVerification_Vtbl)).SetBurnVerificationLevel)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BurnVerificationLevel(this: *mut core::ffi::c_void, value: *mut IMAPI_BURN_VERIFICATION_LEVEL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBurnVerification", "BurnVerificationLevel", 4usize, |this| ((**(this as *const *const IBurnVerification_Vtbl)).BurnVerificationLevel)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetBurnVerificationLevel, BurnVerificationLevel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBurnVerification as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDiscFormat2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn IsRecorderSupported(this: *mut core::ffi::c_void, recorder: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2", "IsRecorderSupported", 7usize, |this| ((**(this as *const *const IDiscFormat2_Vtbl)).IsRecorderSupported)(this, recorder, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn IsCurrentMediaSupported(this: *mut core::ffi::c_void, recorder: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2", "IsCurrentMediaSupported", 8usize, |this| ((**(this as *const *const IDiscFormat2_Vtbl)).IsCurrentMediaSupported)(this, recorder, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaPhysicallyBlank(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2", "MediaPhysicallyBlank", 9usize, |this| ((**(this as *const *const IDiscFormat2_Vtbl)).MediaPhysicallyBlank)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaHeuristicallyBlank(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2", "MediaHeuristicallyBlank", 10usize, |this| ((**(this as *const *const IDiscFormat2_Vtbl)).MediaHeuristicallyBlank)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SupportedMediaTypes(this: *mut core::ffi::c_void, value: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2", "SupportedMediaTypes", 11usize, |this| ((**(this as *const *const IDiscFormat2_Vtbl)).SupportedMediaTypes)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            IsRecorderSupported,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            IsRecorderSupported: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            IsCurrentMediaSupported,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            IsCurrentMediaSupported: 0,
+            MediaPhysicallyBlank,
+            MediaHeuristicallyBlank,
+            #[cfg(feature = "Win32_System_Com")]
+            SupportedMediaTypes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SupportedMediaTypes: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDiscFormat2 as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDiscFormat2Data_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetRecorder(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "SetRecorder", 12usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).SetRecorder)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Recorder(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "Recorder", 13usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).Recorder)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBufferUnderrunFreeDisabled(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "SetBufferUnderrunFreeDisabled", 14usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).SetBufferUnderrunFreeDisabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BufferUnderrunFreeDisabled(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "BufferUnderrunFreeDisabled", 15usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).BufferUnderrunFreeDisabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPostgapAlreadyInImage(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "SetPostgapAlreadyInImage", 16usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).SetPostgapAlreadyInImage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostgapAlreadyInImage(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "PostgapAlreadyInImage", 17usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).PostgapAlreadyInImage)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentMediaStatus(this: *mut core::ffi::c_void, value: *mut IMAPI_FORMAT2_DATA_MEDIA_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "CurrentMediaStatus", 18usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).CurrentMediaStatus)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteProtectStatus(this: *mut core::ffi::c_void, value: *mut IMAPI_MEDIA_WRITE_PROTECT_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "WriteProtectStatus", 19usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).WriteProtectStatus)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalSectorsOnMedia(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "TotalSectorsOnMedia", 20usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).TotalSectorsOnMedia)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreeSectorsOnMedia(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "FreeSectorsOnMedia", 21usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).FreeSectorsOnMedia)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn NextWritableAddress(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "NextWritableAddress", 22usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).NextWritableAddress)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartAddressOfPreviousSession(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "StartAddressOfPreviousSession", 23usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).StartAddressOfPreviousSession)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastWrittenAddressOfPreviousSession(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "LastWrittenAddressOfPreviousSession", 24usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).LastWrittenAddressOfPreviousSession)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetForceMediaToBeClosed(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "SetForceMediaToBeClosed", 25usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).SetForceMediaToBeClosed)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForceMediaToBeClosed(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "ForceMediaToBeClosed", 26usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).ForceMediaToBeClosed)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisableConsumerDvdCompatibilityMode(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "SetDisableConsumerDvdCompatibilityMode", 27usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).SetDisableConsumerDvdCompatibilityMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableConsumerDvdCompatibilityMode(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "DisableConsumerDvdCompatibilityMode", 28usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).DisableConsumerDvdCompatibilityMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentPhysicalMediaType(this: *mut core::ffi::c_void, value: *mut IMAPI_MEDIA_PHYSICAL_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "CurrentPhysicalMediaType", 29usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).CurrentPhysicalMediaType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClientName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "SetClientName", 30usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).SetClientName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClientName(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "ClientName", 31usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).ClientName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestedWriteSpeed(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "RequestedWriteSpeed", 32usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).RequestedWriteSpeed)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestedRotationTypeIsPureCAV(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "RequestedRotationTypeIsPureCAV", 33usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).RequestedRotationTypeIsPureCAV)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentWriteSpeed(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "CurrentWriteSpeed", 34usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).CurrentWriteSpeed)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentRotationTypeIsPureCAV(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "CurrentRotationTypeIsPureCAV", 35usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).CurrentRotationTypeIsPureCAV)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SupportedWriteSpeeds(this: *mut core::ffi::c_void, supportedspeeds: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "SupportedWriteSpeeds", 36usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).SupportedWriteSpeeds)(this, supportedspeeds), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SupportedWriteSpeedDescriptors(this: *mut core::ffi::c_void, supportedspeeddescriptors: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "SupportedWriteSpeedDescriptors", 37usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).SupportedWriteSpeedDescriptors)(this, supportedspeeddescriptors), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetForceOverwrite(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "SetForceOverwrite", 38usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).SetForceOverwrite)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForceOverwrite(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "ForceOverwrite", 39usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).ForceOverwrite)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn MultisessionInterfaces(this: *mut core::ffi::c_void, value: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "MultisessionInterfaces", 40usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).MultisessionInterfaces)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Write(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "Write", 41usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).Write)(this, data), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelWrite(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "CancelWrite", 42usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).CancelWrite)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWriteSpeed(this: *mut core::ffi::c_void, requestedsectorspersecond: i32, rotationtypeispurecav: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Data", "SetWriteSpeed", 43usize, |this| ((**(this as *const *const IDiscFormat2Data_Vtbl)).SetWriteSpeed)(this, requestedsectorspersecond, rotationtypeispurecav), |result| Some(*result))
+        }
+        Self {
+            base__: <IDiscFormat2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            SetRecorder,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetRecorder: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Recorder,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Recorder: 0,
+            SetBufferUnderrunFreeDisabled,
+            BufferUnderrunFreeDisabled,
+            SetPostgapAlreadyInImage,
+            PostgapAlreadyInImage,
+            CurrentMediaStatus,
+            WriteProtectStatus,
+            TotalSectorsOnMedia,
+            FreeSectorsOnMedia,
+            NextWritableAddress,
+            StartAddressOfPreviousSession,
+            LastWrittenAddressOfPreviousSession,
+            SetForceMediaToBeClosed,
+            ForceMediaToBeClosed,
+            SetDisableConsumerDvdCompatibilityMode,
+            DisableConsumerDvdCompatibilityMode,
+            CurrentPhysicalMediaType,
+            SetClientName,
+            ClientName,
+            RequestedWriteSpeed,
+            RequestedRotationTypeIsPureCAV,
+            CurrentWriteSpeed,
+            CurrentRotationTypeIsPureCAV,
+            #[cfg(feature = "Win32_System_Com")]
+            SupportedWriteSpeeds,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SupportedWriteSpeeds: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SupportedWriteSpeedDescriptors,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SupportedWriteSpeedDescriptors: 0,
+            SetForceOverwrite,
+            ForceOverwrite,
+            #[cfg(feature = "Win32_System_Com")]
+            MultisessionInterfaces,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            MultisessionInterfaces: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Write,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Write: 0,
+            CancelWrite,
+            SetWriteSpeed,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDiscFormat2Data as windows_core::Interface>::IID || <IDiscFormat2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDiscFormat2DataEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ElapsedTime(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2DataEventArgs", "ElapsedTime", 14usize, |this| ((**(this as *const *const IDiscFormat2DataEventArgs_Vtbl)).ElapsedTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemainingTime(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2DataEventArgs", "RemainingTime", 15usize, |this| ((**(this as *const *const IDiscFormat2DataEventArgs_Vtbl)).RemainingTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalTime(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2DataEventArgs", "TotalTime", 16usize, |this| ((**(this as *const *const IDiscFormat2DataEventArgs_Vtbl)).TotalTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentAction(this: *mut core::ffi::c_void, value: *mut IMAPI_FORMAT2_DATA_WRITE_ACTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2DataEventArgs", "CurrentAction", 17usize, |this| ((**(this as *const *const IDiscFormat2DataEventArgs_Vtbl)).CurrentAction)(this, value), |result| Some(*result))
+        }
+        Self { base__: <IWriteEngine2EventArgs_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ElapsedTime, RemainingTime, TotalTime, CurrentAction }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDiscFormat2DataEventArgs as windows_core::Interface>::IID || <IWriteEngine2EventArgs_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDiscFormat2Erase_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetRecorder(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Erase", "SetRecorder", 12usize, |this| ((**(this as *const *const IDiscFormat2Erase_Vtbl)).SetRecorder)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Recorder(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Erase", "Recorder", 13usize, |this| ((**(this as *const *const IDiscFormat2Erase_Vtbl)).Recorder)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFullErase(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Erase", "SetFullErase", 14usize, |this| ((**(this as *const *const IDiscFormat2Erase_Vtbl)).SetFullErase)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FullErase(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Erase", "FullErase", 15usize, |this| ((**(this as *const *const IDiscFormat2Erase_Vtbl)).FullErase)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentPhysicalMediaType(this: *mut core::ffi::c_void, value: *mut IMAPI_MEDIA_PHYSICAL_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Erase", "CurrentPhysicalMediaType", 16usize, |this| ((**(this as *const *const IDiscFormat2Erase_Vtbl)).CurrentPhysicalMediaType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClientName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Erase", "SetClientName", 17usize, |this| ((**(this as *const *const IDiscFormat2Erase_Vtbl)).SetClientName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClientName(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Erase", "ClientName", 18usize, |this| ((**(this as *const *const IDiscFormat2Erase_Vtbl)).ClientName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn EraseMedia(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2Erase", "EraseMedia", 19usize, |this| ((**(this as *const *const IDiscFormat2Erase_Vtbl)).EraseMedia)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IDiscFormat2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            SetRecorder,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetRecorder: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Recorder,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Recorder: 0,
+            SetFullErase,
+            FullErase,
+            CurrentPhysicalMediaType,
+            SetClientName,
+            ClientName,
+            EraseMedia,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDiscFormat2Erase as windows_core::Interface>::IID || <IDiscFormat2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDiscFormat2RawCD_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrepareMedia(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2RawCD", "PrepareMedia", 12usize, |this| ((**(this as *const *const IDiscFormat2RawCD_Vtbl)).PrepareMedia)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn WriteMedia(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2RawCD", "WriteMedia", 13usize, |this| ((**(this as *const *const IDiscFormat2RawCD_Vtbl)).WriteMedia)(this, data), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn WriteMedia2(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void, streamleadinsectors: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2RawCD", "WriteMedia2", 14usize, |this| ((**(this as *const *const IDiscFormat2RawCD_Vtbl)).WriteMedia2)(this, data, streamleadinsectors), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelWrite(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2RawCD", "CancelWrite", 15usize, |this| ((**(this as *const *const IDiscFormat2RawCD_Vtbl)).CancelWrite)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseMedia(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2RawCD", "ReleaseMedia", 16usize, |this| ((**(this as *const *const IDiscFormat2RawCD_Vtbl)).ReleaseMedia)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWriteSpeed(this: *mut core::ffi::c_void, requestedsectorspersecond: i32, rotationtypeispurecav: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2RawCD", "SetWriteSpeed", 17usize, |this| ((**(this as *const *const IDiscFormat2RawCD_Vtbl)).SetWriteSpeed)(this, requestedsectorspersecond, rotationtypeispurecav), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetRecorder(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2RawCD", "SetRecorder", 18usize, |this| ((**(this as *const *const IDiscFormat2RawCD_Vtbl)).SetRecorder)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Recorder(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2RawCD", "Recorder", 19usize, |this| ((**(this as *const *const IDiscFormat2RawCD_Vtbl)).Recorder)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBufferUnderrunFreeDisabled(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2RawCD", "SetBufferUnderrunFreeDisabled", 20usize, |this| ((**(this as *const *const IDiscFormat2RawCD_Vtbl)).SetBufferUnderrunFreeDisabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BufferUnderrunFreeDisabled(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2RawCD", "BufferUnderrunFreeDisabled", 21usize, |this| ((**(this as *const *const IDiscFormat2RawCD_Vtbl)).BufferUnderrunFreeDisabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartOfNextSession(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2RawCD", "StartOfNextSession", 22usize, |this| ((**(this as *const *const IDiscFormat2RawCD_Vtbl)).StartOfNextSession)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastPossibleStartOfLeadout(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2RawCD", "LastPossibleStartOfLeadout", 23usize, |this| ((**(this as *const *const IDiscFormat2RawCD_Vtbl)).LastPossibleStartOfLeadout)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentPhysicalMediaType(this: *mut core::ffi::c_void, value: *mut IMAPI_MEDIA_PHYSICAL_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2RawCD", "CurrentPhysicalMediaType", 24usize, |this| ((**(this as *const *const IDiscFormat2RawCD_Vtbl)).CurrentPhysicalMediaType)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SupportedSectorTypes(this: *mut core::ffi::c_void, value: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2RawCD", "SupportedSectorTypes", 25usize, |this| ((**(this as *const *const IDiscFormat2RawCD_Vtbl)).SupportedSectorTypes)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequestedSectorType(this: *mut core::ffi::c_void, value: IMAPI_FORMAT2_RAW_CD_DATA_SECTOR_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2RawCD", "SetRequestedSectorType", 26usize, |this| ((**(this as *const *const IDiscFormat2RawCD_Vtbl)).SetRequestedSectorType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestedSectorType(this: *mut core::ffi::c_void, value: *mut IMAPI_FORMAT2_RAW_CD_DATA_SECTOR_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2RawCD", "RequestedSectorType", 27usize, |this| ((**(this as *const *const IDiscFormat2RawCD_Vtbl)).RequestedSectorType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClientName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2RawCD", "SetClientName", 28usize, |this| ((**(this as *const *const IDiscFormat2RawCD_Vtbl)).SetClientName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClientName(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2RawCD", "ClientName", 29usize, |this| ((**(this as *const *const IDiscFormat2RawCD_Vtbl)).ClientName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestedWriteSpeed(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2RawCD", "RequestedWriteSpeed", 30usize, |this| ((**(this as *const *const IDiscFormat2RawCD_Vtbl)).RequestedWriteSpeed)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestedRotationTypeIsPureCAV(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2RawCD", "RequestedRotationTypeIsPureCAV", 31usize, |this| ((**(this as *const *const IDiscFormat2RawCD_Vtbl)).RequestedRotationTypeIsPureCAV)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentWriteSpeed(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2RawCD", "CurrentWriteSpeed", 32usize, |this| ((**(this as *const *const IDiscFormat2RawCD_Vtbl)).CurrentWriteSpeed)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentRotationTypeIsPureCAV(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2RawCD", "CurrentRotationTypeIsPureCAV", 33usize, |this| ((**(this as *const *const IDiscFormat2RawCD_Vtbl)).CurrentRotationTypeIsPureCAV)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SupportedWriteSpeeds(this: *mut core::ffi::c_void, supportedspeeds: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2RawCD", "SupportedWriteSpeeds", 34usize, |this| ((**(this as *const *const IDiscFormat2RawCD_Vtbl)).SupportedWriteSpeeds)(this, supportedspeeds), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SupportedWriteSpeedDescriptors(this: *mut core::ffi::c_void, supportedspeeddescriptors: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2RawCD", "SupportedWriteSpeedDescriptors", 35usize, |this| ((**(this as *const *const IDiscFormat2RawCD_Vtbl)).SupportedWriteSpeedDescriptors)(this, supportedspeeddescriptors), |result| Some(*result))
+        }
+        Self {
+            base__: <IDiscFormat2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PrepareMedia,
+            #[cfg(feature = "Win32_System_Com")]
+            WriteMedia,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            WriteMedia: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            WriteMedia2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            WriteMedia2: 0,
+            CancelWrite,
+            ReleaseMedia,
+            SetWriteSpeed,
+            #[cfg(feature = "Win32_System_Com")]
+            SetRecorder,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetRecorder: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Recorder,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Recorder: 0,
+            SetBufferUnderrunFreeDisabled,
+            BufferUnderrunFreeDisabled,
+            StartOfNextSession,
+            LastPossibleStartOfLeadout,
+            CurrentPhysicalMediaType,
+            #[cfg(feature = "Win32_System_Com")]
+            SupportedSectorTypes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SupportedSectorTypes: 0,
+            SetRequestedSectorType,
+            RequestedSectorType,
+            SetClientName,
+            ClientName,
+            RequestedWriteSpeed,
+            RequestedRotationTypeIsPureCAV,
+            CurrentWriteSpeed,
+            CurrentRotationTypeIsPureCAV,
+            #[cfg(feature = "Win32_System_Com")]
+            SupportedWriteSpeeds,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SupportedWriteSpeeds: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SupportedWriteSpeedDescriptors,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SupportedWriteSpeedDescriptors: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDiscFormat2RawCD as windows_core::Interface>::IID || <IDiscFormat2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDiscFormat2RawCDEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentAction(this: *mut core::ffi::c_void, value: *mut IMAPI_FORMAT2_RAW_CD_WRITE_ACTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2RawCDEventArgs", "CurrentAction", 14usize, |this| ((**(this as *const *const IDiscFormat2RawCDEventArgs_Vtbl)).CurrentAction)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ElapsedTime(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2RawCDEventArgs", "ElapsedTime", 15usize, |this| ((**(this as *const *const IDiscFormat2RawCDEventArgs_Vtbl)).ElapsedTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemainingTime(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2RawCDEventArgs", "RemainingTime", 16usize, |this| ((**(this as *const *const IDiscFormat2RawCDEventArgs_Vtbl)).RemainingTime)(this, value), |result| Some(*result))
+        }
+        Self { base__: <IWriteEngine2EventArgs_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CurrentAction, ElapsedTime, RemainingTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDiscFormat2RawCDEventArgs as windows_core::Interface>::IID || <IWriteEngine2EventArgs_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDiscFormat2TrackAtOnce_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrepareMedia(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnce", "PrepareMedia", 12usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnce_Vtbl)).PrepareMedia)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddAudioTrack(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnce", "AddAudioTrack", 13usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnce_Vtbl)).AddAudioTrack)(this, data), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelAddTrack(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnce", "CancelAddTrack", 14usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnce_Vtbl)).CancelAddTrack)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseMedia(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnce", "ReleaseMedia", 15usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnce_Vtbl)).ReleaseMedia)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWriteSpeed(this: *mut core::ffi::c_void, requestedsectorspersecond: i32, rotationtypeispurecav: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnce", "SetWriteSpeed", 16usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnce_Vtbl)).SetWriteSpeed)(this, requestedsectorspersecond, rotationtypeispurecav), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetRecorder(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnce", "SetRecorder", 17usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnce_Vtbl)).SetRecorder)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Recorder(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnce", "Recorder", 18usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnce_Vtbl)).Recorder)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBufferUnderrunFreeDisabled(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnce", "SetBufferUnderrunFreeDisabled", 19usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnce_Vtbl)).SetBufferUnderrunFreeDisabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BufferUnderrunFreeDisabled(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnce", "BufferUnderrunFreeDisabled", 20usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnce_Vtbl)).BufferUnderrunFreeDisabled)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfExistingTracks(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnce", "NumberOfExistingTracks", 21usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnce_Vtbl)).NumberOfExistingTracks)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalSectorsOnMedia(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnce", "TotalSectorsOnMedia", 22usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnce_Vtbl)).TotalSectorsOnMedia)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreeSectorsOnMedia(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnce", "FreeSectorsOnMedia", 23usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnce_Vtbl)).FreeSectorsOnMedia)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsedSectorsOnMedia(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnce", "UsedSectorsOnMedia", 24usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnce_Vtbl)).UsedSectorsOnMedia)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDoNotFinalizeMedia(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnce", "SetDoNotFinalizeMedia", 25usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnce_Vtbl)).SetDoNotFinalizeMedia)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoNotFinalizeMedia(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnce", "DoNotFinalizeMedia", 26usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnce_Vtbl)).DoNotFinalizeMedia)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ExpectedTableOfContents(this: *mut core::ffi::c_void, value: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnce", "ExpectedTableOfContents", 27usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnce_Vtbl)).ExpectedTableOfContents)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentPhysicalMediaType(this: *mut core::ffi::c_void, value: *mut IMAPI_MEDIA_PHYSICAL_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnce", "CurrentPhysicalMediaType", 28usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnce_Vtbl)).CurrentPhysicalMediaType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClientName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnce", "SetClientName", 29usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnce_Vtbl)).SetClientName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClientName(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnce", "ClientName", 30usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnce_Vtbl)).ClientName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestedWriteSpeed(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnce", "RequestedWriteSpeed", 31usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnce_Vtbl)).RequestedWriteSpeed)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestedRotationTypeIsPureCAV(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnce", "RequestedRotationTypeIsPureCAV", 32usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnce_Vtbl)).RequestedRotationTypeIsPureCAV)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentWriteSpeed(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnce", "CurrentWriteSpeed", 33usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnce_Vtbl)).CurrentWriteSpeed)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentRotationTypeIsPureCAV(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnce", "CurrentRotationTypeIsPureCAV", 34usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnce_Vtbl)).CurrentRotationTypeIsPureCAV)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SupportedWriteSpeeds(this: *mut core::ffi::c_void, supportedspeeds: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnce", "SupportedWriteSpeeds", 35usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnce_Vtbl)).SupportedWriteSpeeds)(this, supportedspeeds), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SupportedWriteSpeedDescriptors(this: *mut core::ffi::c_void, supportedspeeddescriptors: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnce", "SupportedWriteSpeedDescriptors", 36usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnce_Vtbl)).SupportedWriteSpeedDescriptors)(this, supportedspeeddescriptors), |result| Some(*result))
+        }
+        Self {
+            base__: <IDiscFormat2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PrepareMedia,
+            #[cfg(feature = "Win32_System_Com")]
+            AddAudioTrack,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddAudioTrack: 0,
+            CancelAddTrack,
+            ReleaseMedia,
+            SetWriteSpeed,
+            #[cfg(feature = "Win32_System_Com")]
+            SetRecorder,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetRecorder: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Recorder,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Recorder: 0,
+            SetBufferUnderrunFreeDisabled,
+            BufferUnderrunFreeDisabled,
+            NumberOfExistingTracks,
+            TotalSectorsOnMedia,
+            FreeSectorsOnMedia,
+            UsedSectorsOnMedia,
+            SetDoNotFinalizeMedia,
+            DoNotFinalizeMedia,
+            #[cfg(feature = "Win32_System_Com")]
+            ExpectedTableOfContents,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ExpectedTableOfContents: 0,
+            CurrentPhysicalMediaType,
+            SetClientName,
+            ClientName,
+            RequestedWriteSpeed,
+            RequestedRotationTypeIsPureCAV,
+            CurrentWriteSpeed,
+            CurrentRotationTypeIsPureCAV,
+            #[cfg(feature = "Win32_System_Com")]
+            SupportedWriteSpeeds,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SupportedWriteSpeeds: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SupportedWriteSpeedDescriptors,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SupportedWriteSpeedDescriptors: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDiscFormat2TrackAtOnce as windows_core::Interface>::IID || <IDiscFormat2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDiscFormat2TrackAtOnceEventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentTrackNumber(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnceEventArgs", "CurrentTrackNumber", 14usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnceEventArgs_Vtbl)).CurrentTrackNumber)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentAction(this: *mut core::ffi::c_void, value: *mut IMAPI_FORMAT2_TAO_WRITE_ACTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnceEventArgs", "CurrentAction", 15usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnceEventArgs_Vtbl)).CurrentAction)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ElapsedTime(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnceEventArgs", "ElapsedTime", 16usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnceEventArgs_Vtbl)).ElapsedTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemainingTime(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscFormat2TrackAtOnceEventArgs", "RemainingTime", 17usize, |this| ((**(this as *const *const IDiscFormat2TrackAtOnceEventArgs_Vtbl)).RemainingTime)(this, value), |result| Some(*result))
+        }
+        Self { base__: <IWriteEngine2EventArgs_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CurrentTrackNumber, CurrentAction, ElapsedTime, RemainingTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDiscFormat2TrackAtOnceEventArgs as windows_core::Interface>::IID || <IWriteEngine2EventArgs_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDiscMaster_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscMaster", "Open", 3usize, |this| ((**(this as *const *const IDiscMaster_Vtbl)).Open)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumDiscMasterFormats(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscMaster", "EnumDiscMasterFormats", 4usize, |this| ((**(this as *const *const IDiscMaster_Vtbl)).EnumDiscMasterFormats)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActiveDiscMasterFormat(this: *mut core::ffi::c_void, lpiid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscMaster", "GetActiveDiscMasterFormat", 5usize, |this| ((**(this as *const *const IDiscMaster_Vtbl)).GetActiveDiscMasterFormat)(this, lpiid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActiveDiscMasterFormat(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscMaster", "SetActiveDiscMasterFormat", 6usize, |this| ((**(this as *const *const IDiscMaster_Vtbl)).SetActiveDiscMasterFormat)(this, riid, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumDiscRecorders(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscMaster", "EnumDiscRecorders", 7usize, |this| ((**(this as *const *const IDiscMaster_Vtbl)).EnumDiscRecorders)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActiveDiscRecorder(this: *mut core::ffi::c_void, pprecorder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscMaster", "GetActiveDiscRecorder", 8usize, |this| ((**(this as *const *const IDiscMaster_Vtbl)).GetActiveDiscRecorder)(this, pprecorder), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActiveDiscRecorder(this: *mut core::ffi::c_void, precorder: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscMaster", "SetActiveDiscRecorder", 9usize, |this| ((**(this as *const *const IDiscMaster_Vtbl)).SetActiveDiscRecorder)(this, precorder), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearFormatContent(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscMaster", "ClearFormatContent", 10usize, |this| ((**(this as *const *const IDiscMaster_Vtbl)).ClearFormatContent)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProgressAdvise(this: *mut core::ffi::c_void, pevents: *mut core::ffi::c_void, pvcookie: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscMaster", "ProgressAdvise", 11usize, |this| ((**(this as *const *const IDiscMaster_Vtbl)).ProgressAdvise)(this, pevents, pvcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProgressUnadvise(this: *mut core::ffi::c_void, vcookie: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscMaster", "ProgressUnadvise", 12usize, |this| ((**(this as *const *const IDiscMaster_Vtbl)).ProgressUnadvise)(this, vcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecordDisc(this: *mut core::ffi::c_void, bsimulate: u8, bejectafterburn: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscMaster", "RecordDisc", 13usize, |this| ((**(this as *const *const IDiscMaster_Vtbl)).RecordDisc)(this, bsimulate, bejectafterburn), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscMaster", "Close", 14usize, |this| ((**(this as *const *const IDiscMaster_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Open,
+            EnumDiscMasterFormats,
+            GetActiveDiscMasterFormat,
+            SetActiveDiscMasterFormat,
+            EnumDiscRecorders,
+            GetActiveDiscRecorder,
+            SetActiveDiscRecorder,
+            ClearFormatContent,
+            ProgressAdvise,
+            ProgressUnadvise,
+            RecordDisc,
+            Close,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDiscMaster as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IDiscMaster2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscMaster2", "_NewEnum", 7usize, |this| ((**(this as *const *const IDiscMaster2_Vtbl))._NewEnum)(this, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, value: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscMaster2", "get_Item", 8usize, |this| ((**(this as *const *const IDiscMaster2_Vtbl)).get_Item)(this, index, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscMaster2", "Count", 9usize, |this| ((**(this as *const *const IDiscMaster2_Vtbl)).Count)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSupportedEnvironment(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscMaster2", "IsSupportedEnvironment", 10usize, |this| ((**(this as *const *const IDiscMaster2_Vtbl)).IsSupportedEnvironment)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Ole")]
+            _NewEnum,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            _NewEnum: 0,
+            get_Item,
+            Count,
+            IsSupportedEnvironment,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDiscMaster2 as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDiscMasterProgressEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryCancel(this: *mut core::ffi::c_void, pbcancel: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscMasterProgressEvents", "QueryCancel", 3usize, |this| ((**(this as *const *const IDiscMasterProgressEvents_Vtbl)).QueryCancel)(this, pbcancel), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyPnPActivity(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscMasterProgressEvents", "NotifyPnPActivity", 4usize, |this| ((**(this as *const *const IDiscMasterProgressEvents_Vtbl)).NotifyPnPActivity)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyAddProgress(this: *mut core::ffi::c_void, ncompletedsteps: i32, ntotalsteps: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscMasterProgressEvents", "NotifyAddProgress", 5usize, |this| ((**(this as *const *const IDiscMasterProgressEvents_Vtbl)).NotifyAddProgress)(this, ncompletedsteps, ntotalsteps), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyBlockProgress(this: *mut core::ffi::c_void, ncompleted: i32, ntotal: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscMasterProgressEvents", "NotifyBlockProgress", 6usize, |this| ((**(this as *const *const IDiscMasterProgressEvents_Vtbl)).NotifyBlockProgress)(this, ncompleted, ntotal), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyTrackProgress(this: *mut core::ffi::c_void, ncurrenttrack: i32, ntotaltracks: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscMasterProgressEvents", "NotifyTrackProgress", 7usize, |this| ((**(this as *const *const IDiscMasterProgressEvents_Vtbl)).NotifyTrackProgress)(this, ncurrenttrack, ntotaltracks), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyPreparingBurn(this: *mut core::ffi::c_void, nestimatedseconds: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscMasterProgressEvents", "NotifyPreparingBurn", 8usize, |this| ((**(this as *const *const IDiscMasterProgressEvents_Vtbl)).NotifyPreparingBurn)(this, nestimatedseconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyClosingDisc(this: *mut core::ffi::c_void, nestimatedseconds: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscMasterProgressEvents", "NotifyClosingDisc", 9usize, |this| ((**(this as *const *const IDiscMasterProgressEvents_Vtbl)).NotifyClosingDisc)(this, nestimatedseconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyBurnComplete(this: *mut core::ffi::c_void, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscMasterProgressEvents", "NotifyBurnComplete", 10usize, |this| ((**(this as *const *const IDiscMasterProgressEvents_Vtbl)).NotifyBurnComplete)(this, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyEraseComplete(this: *mut core::ffi::c_void, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscMasterProgressEvents", "NotifyEraseComplete", 11usize, |this| ((**(this as *const *const IDiscMasterProgressEvents_Vtbl)).NotifyEraseComplete)(this, status), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            QueryCancel,
+            NotifyPnPActivity,
+            NotifyAddProgress,
+            NotifyBlockProgress,
+            NotifyTrackProgress,
+            NotifyPreparingBurn,
+            NotifyClosingDisc,
+            NotifyBurnComplete,
+            NotifyEraseComplete,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDiscMasterProgressEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for IDiscRecorder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Init(this: *mut core::ffi::c_void, pbyuniqueid: *const u8, nulidsize: u32, nuldrivenumber: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder", "Init", 3usize, |this| ((**(this as *const *const IDiscRecorder_Vtbl)).Init)(this, pbyuniqueid, nulidsize, nuldrivenumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecorderGUID(this: *mut core::ffi::c_void, pbyuniqueid: *mut u8, ulbuffersize: u32, pulreturnsizerequired: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder", "GetRecorderGUID", 4usize, |this| ((**(this as *const *const IDiscRecorder_Vtbl)).GetRecorderGUID)(this, pbyuniqueid, ulbuffersize, pulreturnsizerequired), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecorderType(this: *mut core::ffi::c_void, ftypecode: *mut RECORDER_TYPES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder", "GetRecorderType", 5usize, |this| ((**(this as *const *const IDiscRecorder_Vtbl)).GetRecorderType)(this, ftypecode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayNames(this: *mut core::ffi::c_void, pbstrvendorid: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrproductid: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrrevision: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder", "GetDisplayNames", 6usize, |this| ((**(this as *const *const IDiscRecorder_Vtbl)).GetDisplayNames)(this, pbstrvendorid, pbstrproductid, pbstrrevision), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBasePnPID(this: *mut core::ffi::c_void, pbstrbasepnpid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder", "GetBasePnPID", 7usize, |this| ((**(this as *const *const IDiscRecorder_Vtbl)).GetBasePnPID)(this, pbstrbasepnpid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPath(this: *mut core::ffi::c_void, pbstrpath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder", "GetPath", 8usize, |this| ((**(this as *const *const IDiscRecorder_Vtbl)).GetPath)(this, pbstrpath), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn GetRecorderProperties(this: *mut core::ffi::c_void, pppropstg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder", "GetRecorderProperties", 9usize, |this| ((**(this as *const *const IDiscRecorder_Vtbl)).GetRecorderProperties)(this, pppropstg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn SetRecorderProperties(this: *mut core::ffi::c_void, ppropstg: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder", "SetRecorderProperties", 10usize, |this| ((**(this as *const *const IDiscRecorder_Vtbl)).SetRecorderProperties)(this, ppropstg), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecorderState(this: *mut core::ffi::c_void, puldevstateflags: *mut DISC_RECORDER_STATE_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder", "GetRecorderState", 11usize, |this| ((**(this as *const *const IDiscRecorder_Vtbl)).GetRecorderState)(this, puldevstateflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenExclusive(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder", "OpenExclusive", 12usize, |this| ((**(this as *const *const IDiscRecorder_Vtbl)).OpenExclusive)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryMediaType(this: *mut core::ffi::c_void, fmediatype: *mut MEDIA_TYPES, fmediaflags: *mut MEDIA_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder", "QueryMediaType", 13usize, |this| ((**(this as *const *const IDiscRecorder_Vtbl)).QueryMediaType)(this, fmediatype, fmediaflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryMediaInfo(this: *mut core::ffi::c_void, pbsessions: *mut u8, pblasttrack: *mut u8, ulstartaddress: *mut u32, ulnextwritable: *mut u32, ulfreeblocks: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder", "QueryMediaInfo", 14usize, |this| ((**(this as *const *const IDiscRecorder_Vtbl)).QueryMediaInfo)(this, pbsessions, pblasttrack, ulstartaddress, ulnextwritable, ulfreeblocks), |result| Some(*result))
+        }
+        unsafe extern "system" fn Eject(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder", "Eject", 15usize, |this| ((**(this as *const *const IDiscRecorder_Vtbl)).Eject)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Erase(this: *mut core::ffi::c_void, bfullerase: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder", "Erase", 16usize, |this| ((**(this as *const *const IDiscRecorder_Vtbl)).Erase)(this, bfullerase), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder", "Close", 17usize, |this| ((**(this as *const *const IDiscRecorder_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Init,
+            GetRecorderGUID,
+            GetRecorderType,
+            GetDisplayNames,
+            GetBasePnPID,
+            GetPath,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            GetRecorderProperties,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            GetRecorderProperties: 0,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            SetRecorderProperties,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            SetRecorderProperties: 0,
+            GetRecorderState,
+            OpenExclusive,
+            QueryMediaType,
+            QueryMediaInfo,
+            Eject,
+            Erase,
+            Close,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDiscRecorder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDiscRecorder2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EjectMedia(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2", "EjectMedia", 7usize, |this| ((**(this as *const *const IDiscRecorder2_Vtbl)).EjectMedia)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseTray(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2", "CloseTray", 8usize, |this| ((**(this as *const *const IDiscRecorder2_Vtbl)).CloseTray)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcquireExclusiveAccess(this: *mut core::ffi::c_void, force: super::super::Foundation::VARIANT_BOOL, __midl__idiscrecorder20000: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2", "AcquireExclusiveAccess", 9usize, |this| ((**(this as *const *const IDiscRecorder2_Vtbl)).AcquireExclusiveAccess)(this, force, __midl__idiscrecorder20000), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseExclusiveAccess(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2", "ReleaseExclusiveAccess", 10usize, |this| ((**(this as *const *const IDiscRecorder2_Vtbl)).ReleaseExclusiveAccess)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableMcn(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2", "DisableMcn", 11usize, |this| ((**(this as *const *const IDiscRecorder2_Vtbl)).DisableMcn)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableMcn(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2", "EnableMcn", 12usize, |this| ((**(this as *const *const IDiscRecorder2_Vtbl)).EnableMcn)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeDiscRecorder(this: *mut core::ffi::c_void, recorderuniqueid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2", "InitializeDiscRecorder", 13usize, |this| ((**(this as *const *const IDiscRecorder2_Vtbl)).InitializeDiscRecorder)(this, recorderuniqueid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActiveDiscRecorder(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2", "ActiveDiscRecorder", 14usize, |this| ((**(this as *const *const IDiscRecorder2_Vtbl)).ActiveDiscRecorder)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn VendorId(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2", "VendorId", 15usize, |this| ((**(this as *const *const IDiscRecorder2_Vtbl)).VendorId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProductId(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2", "ProductId", 16usize, |this| ((**(this as *const *const IDiscRecorder2_Vtbl)).ProductId)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProductRevision(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2", "ProductRevision", 17usize, |this| ((**(this as *const *const IDiscRecorder2_Vtbl)).ProductRevision)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn VolumeName(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2", "VolumeName", 18usize, |this| ((**(this as *const *const IDiscRecorder2_Vtbl)).VolumeName)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn VolumePathNames(this: *mut core::ffi::c_void, value: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2", "VolumePathNames", 19usize, |this| ((**(this as *const *const IDiscRecorder2_Vtbl)).VolumePathNames)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceCanLoadMedia(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2", "DeviceCanLoadMedia", 20usize, |this| ((**(this as *const *const IDiscRecorder2_Vtbl)).DeviceCanLoadMedia)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LegacyDeviceNumber(this: *mut core::ffi::c_void, legacydevicenumber: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2", "LegacyDeviceNumber", 21usize, |this| ((**(this as *const *const IDiscRecorder2_Vtbl)).LegacyDeviceNumber)(this, legacydevicenumber), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SupportedFeaturePages(this: *mut core::ffi::c_void, value: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2", "SupportedFeaturePages", 22usize, |this| ((**(this as *const *const IDiscRecorder2_Vtbl)).SupportedFeaturePages)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CurrentFeaturePages(this: *mut core::ffi::c_void, value: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2", "CurrentFeaturePages", 23usize, |this| ((**(this as *const *const IDiscRecorder2_Vtbl)).CurrentFeaturePages)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SupportedProfiles(this: *mut core::ffi::c_void, value: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2", "SupportedProfiles", 24usize, |this| ((**(this as *const *const IDiscRecorder2_Vtbl)).SupportedProfiles)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CurrentProfiles(this: *mut core::ffi::c_void, value: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2", "CurrentProfiles", 25usize, |this| ((**(this as *const *const IDiscRecorder2_Vtbl)).CurrentProfiles)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SupportedModePages(this: *mut core::ffi::c_void, value: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2", "SupportedModePages", 26usize, |this| ((**(this as *const *const IDiscRecorder2_Vtbl)).SupportedModePages)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExclusiveAccessOwner(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2", "ExclusiveAccessOwner", 27usize, |this| ((**(this as *const *const IDiscRecorder2_Vtbl)).ExclusiveAccessOwner)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EjectMedia,
+            CloseTray,
+            AcquireExclusiveAccess,
+            ReleaseExclusiveAccess,
+            DisableMcn,
+            EnableMcn,
+            InitializeDiscRecorder,
+            ActiveDiscRecorder,
+            VendorId,
+            ProductId,
+            ProductRevision,
+            VolumeName,
+            #[cfg(feature = "Win32_System_Com")]
+            VolumePathNames,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            VolumePathNames: 0,
+            DeviceCanLoadMedia,
+            LegacyDeviceNumber,
+            #[cfg(feature = "Win32_System_Com")]
+            SupportedFeaturePages,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SupportedFeaturePages: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CurrentFeaturePages,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CurrentFeaturePages: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SupportedProfiles,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SupportedProfiles: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CurrentProfiles,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CurrentProfiles: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SupportedModePages,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SupportedModePages: 0,
+            ExclusiveAccessOwner,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDiscRecorder2 as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDiscRecorder2Ex_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SendCommandNoData(this: *mut core::ffi::c_void, cdb: *const u8, cdbsize: u32, sensebuffer: *mut u8, timeout: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2Ex", "SendCommandNoData", 3usize, |this| ((**(this as *const *const IDiscRecorder2Ex_Vtbl)).SendCommandNoData)(this, cdb, cdbsize, sensebuffer, timeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendCommandSendDataToDevice(this: *mut core::ffi::c_void, cdb: *const u8, cdbsize: u32, sensebuffer: *mut u8, timeout: u32, buffer: *const u8, buffersize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2Ex", "SendCommandSendDataToDevice", 4usize, |this| ((**(this as *const *const IDiscRecorder2Ex_Vtbl)).SendCommandSendDataToDevice)(this, cdb, cdbsize, sensebuffer, timeout, buffer, buffersize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendCommandGetDataFromDevice(this: *mut core::ffi::c_void, cdb: *const u8, cdbsize: u32, sensebuffer: *mut u8, timeout: u32, buffer: *mut u8, buffersize: u32, bufferfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2Ex", "SendCommandGetDataFromDevice", 5usize, |this| ((**(this as *const *const IDiscRecorder2Ex_Vtbl)).SendCommandGetDataFromDevice)(this, cdb, cdbsize, sensebuffer, timeout, buffer, buffersize, bufferfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadDvdStructure(this: *mut core::ffi::c_void, format: u32, address: u32, layer: u32, agid: u32, data: *mut *mut u8, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2Ex", "ReadDvdStructure", 6usize, |this| ((**(this as *const *const IDiscRecorder2Ex_Vtbl)).ReadDvdStructure)(this, format, address, layer, agid, data, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendDvdStructure(this: *mut core::ffi::c_void, format: u32, data: *const u8, count: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2Ex", "SendDvdStructure", 7usize, |this| ((**(this as *const *const IDiscRecorder2Ex_Vtbl)).SendDvdStructure)(this, format, data, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAdapterDescriptor(this: *mut core::ffi::c_void, data: *mut *mut u8, bytesize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2Ex", "GetAdapterDescriptor", 8usize, |this| ((**(this as *const *const IDiscRecorder2Ex_Vtbl)).GetAdapterDescriptor)(this, data, bytesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceDescriptor(this: *mut core::ffi::c_void, data: *mut *mut u8, bytesize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2Ex", "GetDeviceDescriptor", 9usize, |this| ((**(this as *const *const IDiscRecorder2Ex_Vtbl)).GetDeviceDescriptor)(this, data, bytesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDiscInformation(this: *mut core::ffi::c_void, discinformation: *mut *mut u8, bytesize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2Ex", "GetDiscInformation", 10usize, |this| ((**(this as *const *const IDiscRecorder2Ex_Vtbl)).GetDiscInformation)(this, discinformation, bytesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTrackInformation(this: *mut core::ffi::c_void, address: u32, addresstype: IMAPI_READ_TRACK_ADDRESS_TYPE, trackinformation: *mut *mut u8, bytesize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2Ex", "GetTrackInformation", 11usize, |this| ((**(this as *const *const IDiscRecorder2Ex_Vtbl)).GetTrackInformation)(this, address, addresstype, trackinformation, bytesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFeaturePage(this: *mut core::ffi::c_void, requestedfeature: IMAPI_FEATURE_PAGE_TYPE, currentfeatureonly: super::super::Foundation::BOOLEAN, featuredata: *mut *mut u8, bytesize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2Ex", "GetFeaturePage", 12usize, |this| ((**(this as *const *const IDiscRecorder2Ex_Vtbl)).GetFeaturePage)(this, requestedfeature, currentfeatureonly, featuredata, bytesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetModePage(this: *mut core::ffi::c_void, requestedmodepage: IMAPI_MODE_PAGE_TYPE, requesttype: IMAPI_MODE_PAGE_REQUEST_TYPE, modepagedata: *mut *mut u8, bytesize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2Ex", "GetModePage", 13usize, |this| ((**(this as *const *const IDiscRecorder2Ex_Vtbl)).GetModePage)(this, requestedmodepage, requesttype, modepagedata, bytesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetModePage(this: *mut core::ffi::c_void, requesttype: IMAPI_MODE_PAGE_REQUEST_TYPE, data: *const u8, bytesize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2Ex", "SetModePage", 14usize, |this| ((**(this as *const *const IDiscRecorder2Ex_Vtbl)).SetModePage)(this, requesttype, data, bytesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSupportedFeaturePages(this: *mut core::ffi::c_void, currentfeatureonly: super::super::Foundation::BOOLEAN, featuredata: *mut *mut IMAPI_FEATURE_PAGE_TYPE, bytesize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2Ex", "GetSupportedFeaturePages", 15usize, |this| ((**(this as *const *const IDiscRecorder2Ex_Vtbl)).GetSupportedFeaturePages)(this, currentfeatureonly, featuredata, bytesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSupportedProfiles(this: *mut core::ffi::c_void, currentonly: super::super::Foundation::BOOLEAN, profiletypes: *mut *mut IMAPI_PROFILE_TYPE, validprofiles: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2Ex", "GetSupportedProfiles", 16usize, |this| ((**(this as *const *const IDiscRecorder2Ex_Vtbl)).GetSupportedProfiles)(this, currentonly, profiletypes, validprofiles), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSupportedModePages(this: *mut core::ffi::c_void, requesttype: IMAPI_MODE_PAGE_REQUEST_TYPE, modepagetypes: *mut *mut IMAPI_MODE_PAGE_TYPE, validpages: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2Ex", "GetSupportedModePages", 17usize, |this| ((**(this as *const *const IDiscRecorder2Ex_Vtbl)).GetSupportedModePages)(this, requesttype, modepagetypes, validpages), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetByteAlignmentMask(this: *mut core::ffi::c_void, value: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2Ex", "GetByteAlignmentMask", 18usize, |this| ((**(this as *const *const IDiscRecorder2Ex_Vtbl)).GetByteAlignmentMask)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaximumNonPageAlignedTransferSize(this: *mut core::ffi::c_void, value: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2Ex", "GetMaximumNonPageAlignedTransferSize", 19usize, |this| ((**(this as *const *const IDiscRecorder2Ex_Vtbl)).GetMaximumNonPageAlignedTransferSize)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaximumPageAlignedTransferSize(this: *mut core::ffi::c_void, value: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDiscRecorder2Ex", "GetMaximumPageAlignedTransferSize", 20usize, |this| ((**(this as *const *const IDiscRecorder2Ex_Vtbl)).GetMaximumPageAlignedTransferSize)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SendCommandNoData,
+            SendCommandSendDataToDevice,
+            SendCommandGetDataFromDevice,
+            ReadDvdStructure,
+            SendDvdStructure,
+            GetAdapterDescriptor,
+            GetDeviceDescriptor,
+            GetDiscInformation,
+            GetTrackInformation,
+            GetFeaturePage,
+            GetModePage,
+            SetModePage,
+            GetSupportedFeaturePages,
+            GetSupportedProfiles,
+            GetSupportedModePages,
+            GetByteAlignmentMask,
+            GetMaximumNonPageAlignedTransferSize,
+            GetMaximumPageAlignedTransferSize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDiscRecorder2Ex as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumDiscMasterFormats_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, cformats: u32, lpiidformatid: *mut windows_core::GUID, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDiscMasterFormats", "Next", 3usize, |this| ((**(this as *const *const IEnumDiscMasterFormats_Vtbl)).Next)(this, cformats, lpiidformatid, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, cformats: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDiscMasterFormats", "Skip", 4usize, |this| ((**(this as *const *const IEnumDiscMasterFormats_Vtbl)).Skip)(this, cformats), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDiscMasterFormats", "Reset", 5usize, |this| ((**(this as *const *const IEnumDiscMasterFormats_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDiscMasterFormats", "Clone", 6usize, |this| ((**(this as *const *const IEnumDiscMasterFormats_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumDiscMasterFormats as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumDiscRecorders_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, crecorders: u32, pprecorder: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDiscRecorders", "Next", 3usize, |this| ((**(this as *const *const IEnumDiscRecorders_Vtbl)).Next)(this, crecorders, pprecorder, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, crecorders: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDiscRecorders", "Skip", 4usize, |this| ((**(this as *const *const IEnumDiscRecorders_Vtbl)).Skip)(this, crecorders), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDiscRecorders", "Reset", 5usize, |this| ((**(this as *const *const IEnumDiscRecorders_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDiscRecorders", "Clone", 6usize, |this| ((**(this as *const *const IEnumDiscRecorders_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumDiscRecorders as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumFsiItems_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumFsiItems", "Next", 3usize, |this| ((**(this as *const *const IEnumFsiItems_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumFsiItems", "Skip", 4usize, |this| ((**(this as *const *const IEnumFsiItems_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumFsiItems", "Reset", 5usize, |this| ((**(this as *const *const IEnumFsiItems_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumFsiItems", "Clone", 6usize, |this| ((**(this as *const *const IEnumFsiItems_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Skip,
+            Reset,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumFsiItems as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumProgressItems_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumProgressItems", "Next", 3usize, |this| ((**(this as *const *const IEnumProgressItems_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumProgressItems", "Skip", 4usize, |this| ((**(this as *const *const IEnumProgressItems_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumProgressItems", "Reset", 5usize, |this| ((**(this as *const *const IEnumProgressItems_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumProgressItems", "Clone", 6usize, |this| ((**(this as *const *const IEnumProgressItems_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Skip,
+            Reset,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumProgressItems as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFileSystemImage_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Root(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "Root", 7usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).Root)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionStartBlock(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "SessionStartBlock", 8usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).SessionStartBlock)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSessionStartBlock(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "SetSessionStartBlock", 9usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).SetSessionStartBlock)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreeMediaBlocks(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "FreeMediaBlocks", 10usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).FreeMediaBlocks)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFreeMediaBlocks(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "SetFreeMediaBlocks", 11usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).SetFreeMediaBlocks)(this, newval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetMaxMediaBlocksFromDevice(this: *mut core::ffi::c_void, discrecorder: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "SetMaxMediaBlocksFromDevice", 12usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).SetMaxMediaBlocksFromDevice)(this, discrecorder), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsedBlocks(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "UsedBlocks", 13usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).UsedBlocks)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn VolumeName(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "VolumeName", 14usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).VolumeName)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVolumeName(this: *mut core::ffi::c_void, newval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "SetVolumeName", 15usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).SetVolumeName)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImportedVolumeName(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "ImportedVolumeName", 16usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).ImportedVolumeName)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn BootImageOptions(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "BootImageOptions", 17usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).BootImageOptions)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetBootImageOptions(this: *mut core::ffi::c_void, newval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "SetBootImageOptions", 18usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).SetBootImageOptions)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn FileCount(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "FileCount", 19usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).FileCount)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DirectoryCount(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "DirectoryCount", 20usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).DirectoryCount)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn WorkingDirectory(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "WorkingDirectory", 21usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).WorkingDirectory)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWorkingDirectory(this: *mut core::ffi::c_void, newval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "SetWorkingDirectory", 22usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).SetWorkingDirectory)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangePoint(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "ChangePoint", 23usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).ChangePoint)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrictFileSystemCompliance(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "StrictFileSystemCompliance", 24usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).StrictFileSystemCompliance)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrictFileSystemCompliance(this: *mut core::ffi::c_void, newval: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "SetStrictFileSystemCompliance", 25usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).SetStrictFileSystemCompliance)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseRestrictedCharacterSet(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "UseRestrictedCharacterSet", 26usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).UseRestrictedCharacterSet)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUseRestrictedCharacterSet(this: *mut core::ffi::c_void, newval: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "SetUseRestrictedCharacterSet", 27usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).SetUseRestrictedCharacterSet)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn FileSystemsToCreate(this: *mut core::ffi::c_void, pval: *mut FsiFileSystems) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "FileSystemsToCreate", 28usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).FileSystemsToCreate)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFileSystemsToCreate(this: *mut core::ffi::c_void, newval: FsiFileSystems) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "SetFileSystemsToCreate", 29usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).SetFileSystemsToCreate)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn FileSystemsSupported(this: *mut core::ffi::c_void, pval: *mut FsiFileSystems) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "FileSystemsSupported", 30usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).FileSystemsSupported)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUDFRevision(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "SetUDFRevision", 31usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).SetUDFRevision)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn UDFRevision(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "UDFRevision", 32usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).UDFRevision)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn UDFRevisionsSupported(this: *mut core::ffi::c_void, pval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "UDFRevisionsSupported", 33usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).UDFRevisionsSupported)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ChooseImageDefaults(this: *mut core::ffi::c_void, discrecorder: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "ChooseImageDefaults", 34usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).ChooseImageDefaults)(this, discrecorder), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChooseImageDefaultsForMediaType(this: *mut core::ffi::c_void, value: IMAPI_MEDIA_PHYSICAL_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "ChooseImageDefaultsForMediaType", 35usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).ChooseImageDefaultsForMediaType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetISO9660InterchangeLevel(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "SetISO9660InterchangeLevel", 36usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).SetISO9660InterchangeLevel)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ISO9660InterchangeLevel(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "ISO9660InterchangeLevel", 37usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).ISO9660InterchangeLevel)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ISO9660InterchangeLevelsSupported(this: *mut core::ffi::c_void, pval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "ISO9660InterchangeLevelsSupported", 38usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).ISO9660InterchangeLevelsSupported)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateResultImage(this: *mut core::ffi::c_void, resultstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "CreateResultImage", 39usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).CreateResultImage)(this, resultstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn Exists(this: *mut core::ffi::c_void, fullpath: core::mem::MaybeUninit<windows_core::BSTR>, itemtype: *mut FsiItemType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "Exists", 40usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).Exists)(this, fullpath, itemtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn CalculateDiscIdentifier(this: *mut core::ffi::c_void, discidentifier: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "CalculateDiscIdentifier", 41usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).CalculateDiscIdentifier)(this, discidentifier), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn IdentifyFileSystemsOnDisc(this: *mut core::ffi::c_void, discrecorder: *mut core::ffi::c_void, filesystems: *mut FsiFileSystems) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "IdentifyFileSystemsOnDisc", 42usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).IdentifyFileSystemsOnDisc)(this, discrecorder, filesystems), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultFileSystemForImport(this: *mut core::ffi::c_void, filesystems: FsiFileSystems, importdefault: *mut FsiFileSystems) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "GetDefaultFileSystemForImport", 43usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).GetDefaultFileSystemForImport)(this, filesystems, importdefault), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImportFileSystem(this: *mut core::ffi::c_void, importedfilesystem: *mut FsiFileSystems) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "ImportFileSystem", 44usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).ImportFileSystem)(this, importedfilesystem), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImportSpecificFileSystem(this: *mut core::ffi::c_void, filesystemtouse: FsiFileSystems) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "ImportSpecificFileSystem", 45usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).ImportSpecificFileSystem)(this, filesystemtouse), |result| Some(*result))
+        }
+        unsafe extern "system" fn RollbackToChangePoint(this: *mut core::ffi::c_void, changepoint: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "RollbackToChangePoint", 46usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).RollbackToChangePoint)(this, changepoint), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockInChangePoint(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "LockInChangePoint", 47usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).LockInChangePoint)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateDirectoryItem(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, newitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "CreateDirectoryItem", 48usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).CreateDirectoryItem)(this, name, newitem), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateFileItem(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, newitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "CreateFileItem", 49usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).CreateFileItem)(this, name, newitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn VolumeNameUDF(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "VolumeNameUDF", 50usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).VolumeNameUDF)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn VolumeNameJoliet(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "VolumeNameJoliet", 51usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).VolumeNameJoliet)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn VolumeNameISO9660(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "VolumeNameISO9660", 52usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).VolumeNameISO9660)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn StageFiles(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "StageFiles", 53usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).StageFiles)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStageFiles(this: *mut core::ffi::c_void, newval: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "SetStageFiles", 54usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).SetStageFiles)(this, newval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn MultisessionInterfaces(this: *mut core::ffi::c_void, pval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "MultisessionInterfaces", 55usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).MultisessionInterfaces)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetMultisessionInterfaces(this: *mut core::ffi::c_void, newval: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage", "SetMultisessionInterfaces", 56usize, |this| ((**(this as *const *const IFileSystemImage_Vtbl)).SetMultisessionInterfaces)(this, newval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Root,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Root: 0,
+            SessionStartBlock,
+            SetSessionStartBlock,
+            FreeMediaBlocks,
+            SetFreeMediaBlocks,
+            #[cfg(feature = "Win32_System_Com")]
+            SetMaxMediaBlocksFromDevice,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetMaxMediaBlocksFromDevice: 0,
+            UsedBlocks,
+            VolumeName,
+            SetVolumeName,
+            ImportedVolumeName,
+            #[cfg(feature = "Win32_System_Com")]
+            BootImageOptions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            BootImageOptions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetBootImageOptions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetBootImageOptions: 0,
+            FileCount,
+            DirectoryCount,
+            WorkingDirectory,
+            SetWorkingDirectory,
+            ChangePoint,
+            StrictFileSystemCompliance,
+            SetStrictFileSystemCompliance,
+            UseRestrictedCharacterSet,
+            SetUseRestrictedCharacterSet,
+            FileSystemsToCreate,
+            SetFileSystemsToCreate,
+            FileSystemsSupported,
+            SetUDFRevision,
+            UDFRevision,
+            #[cfg(feature = "Win32_System_Com")]
+            UDFRevisionsSupported,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            UDFRevisionsSupported: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ChooseImageDefaults,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ChooseImageDefaults: 0,
+            ChooseImageDefaultsForMediaType,
+            SetISO9660InterchangeLevel,
+            ISO9660InterchangeLevel,
+            #[cfg(feature = "Win32_System_Com")]
+            ISO9660InterchangeLevelsSupported,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ISO9660InterchangeLevelsSupported: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateResultImage,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateResultImage: 0,
+            Exists,
+            CalculateDiscIdentifier,
+            #[cfg(feature = "Win32_System_Com")]
+            IdentifyFileSystemsOnDisc,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            IdentifyFileSystemsOnDisc: 0,
+            GetDefaultFileSystemForImport,
+            ImportFileSystem,
+            ImportSpecificFileSystem,
+            RollbackToChangePoint,
+            LockInChangePoint,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateDirectoryItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateDirectoryItem: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateFileItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateFileItem: 0,
+            VolumeNameUDF,
+            VolumeNameJoliet,
+            VolumeNameISO9660,
+            StageFiles,
+            SetStageFiles,
+            #[cfg(feature = "Win32_System_Com")]
+            MultisessionInterfaces,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            MultisessionInterfaces: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetMultisessionInterfaces,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetMultisessionInterfaces: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileSystemImage as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFileSystemImage2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn BootImageOptionsArray(this: *mut core::ffi::c_void, pval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage2", "BootImageOptionsArray", 57usize, |this| ((**(this as *const *const IFileSystemImage2_Vtbl)).BootImageOptionsArray)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetBootImageOptionsArray(this: *mut core::ffi::c_void, newval: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage2", "SetBootImageOptionsArray", 58usize, |this| ((**(this as *const *const IFileSystemImage2_Vtbl)).SetBootImageOptionsArray)(this, newval), |result| Some(*result))
+        }
+        Self {
+            base__: <IFileSystemImage_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            BootImageOptionsArray,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            BootImageOptionsArray: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetBootImageOptionsArray,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetBootImageOptionsArray: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileSystemImage2 as windows_core::Interface>::IID || <IFileSystemImage_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFileSystemImage3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateRedundantUdfMetadataFiles(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage3", "CreateRedundantUdfMetadataFiles", 59usize, |this| ((**(this as *const *const IFileSystemImage3_Vtbl)).CreateRedundantUdfMetadataFiles)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCreateRedundantUdfMetadataFiles(this: *mut core::ffi::c_void, newval: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage3", "SetCreateRedundantUdfMetadataFiles", 60usize, |this| ((**(this as *const *const IFileSystemImage3_Vtbl)).SetCreateRedundantUdfMetadataFiles)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProbeSpecificFileSystem(this: *mut core::ffi::c_void, filesystemtoprobe: FsiFileSystems, isappendable: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImage3", "ProbeSpecificFileSystem", 61usize, |this| ((**(this as *const *const IFileSystemImage3_Vtbl)).ProbeSpecificFileSystem)(this, filesystemtoprobe, isappendable), |result| Some(*result))
+        }
+        Self {
+            base__: <IFileSystemImage2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateRedundantUdfMetadataFiles,
+            SetCreateRedundantUdfMetadataFiles,
+            ProbeSpecificFileSystem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileSystemImage3 as windows_core::Interface>::IID || <IFileSystemImage2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFileSystemImageResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ImageStream(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImageResult", "ImageStream", 7usize, |this| ((**(this as *const *const IFileSystemImageResult_Vtbl)).ImageStream)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ProgressItems(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImageResult", "ProgressItems", 8usize, |this| ((**(this as *const *const IFileSystemImageResult_Vtbl)).ProgressItems)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalBlocks(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImageResult", "TotalBlocks", 9usize, |this| ((**(this as *const *const IFileSystemImageResult_Vtbl)).TotalBlocks)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn BlockSize(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImageResult", "BlockSize", 10usize, |this| ((**(this as *const *const IFileSystemImageResult_Vtbl)).BlockSize)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DiscId(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImageResult", "DiscId", 11usize, |this| ((**(this as *const *const IFileSystemImageResult_Vtbl)).DiscId)(this, pval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            ImageStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ImageStream: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ProgressItems,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ProgressItems: 0,
+            TotalBlocks,
+            BlockSize,
+            DiscId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileSystemImageResult as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFileSystemImageResult2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ModifiedBlocks(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFileSystemImageResult2", "ModifiedBlocks", 12usize, |this| ((**(this as *const *const IFileSystemImageResult2_Vtbl)).ModifiedBlocks)(this, pval), |result| Some(*result))
+        }
+        Self {
+            base__: <IFileSystemImageResult_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            ModifiedBlocks,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ModifiedBlocks: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFileSystemImageResult2 as windows_core::Interface>::IID || <IFileSystemImageResult_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IFsiDirectoryItem_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, newenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiDirectoryItem", "_NewEnum", 19usize, |this| ((**(this as *const *const IFsiDirectoryItem_Vtbl))._NewEnum)(this, newenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, item: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiDirectoryItem", "get_Item", 20usize, |this| ((**(this as *const *const IFsiDirectoryItem_Vtbl)).get_Item)(this, path, item), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiDirectoryItem", "Count", 21usize, |this| ((**(this as *const *const IFsiDirectoryItem_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumFsiItems(this: *mut core::ffi::c_void, newenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiDirectoryItem", "EnumFsiItems", 22usize, |this| ((**(this as *const *const IFsiDirectoryItem_Vtbl)).EnumFsiItems)(this, newenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDirectory(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiDirectoryItem", "AddDirectory", 23usize, |this| ((**(this as *const *const IFsiDirectoryItem_Vtbl)).AddDirectory)(this, path), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddFile(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, filedata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiDirectoryItem", "AddFile", 24usize, |this| ((**(this as *const *const IFsiDirectoryItem_Vtbl)).AddFile)(this, path, filedata), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTree(this: *mut core::ffi::c_void, sourcedirectory: core::mem::MaybeUninit<windows_core::BSTR>, includebasedirectory: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiDirectoryItem", "AddTree", 25usize, |this| ((**(this as *const *const IFsiDirectoryItem_Vtbl)).AddTree)(this, sourcedirectory, includebasedirectory), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, item: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiDirectoryItem", "Add", 26usize, |this| ((**(this as *const *const IFsiDirectoryItem_Vtbl)).Add)(this, item), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiDirectoryItem", "Remove", 27usize, |this| ((**(this as *const *const IFsiDirectoryItem_Vtbl)).Remove)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTree(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiDirectoryItem", "RemoveTree", 28usize, |this| ((**(this as *const *const IFsiDirectoryItem_Vtbl)).RemoveTree)(this, path), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsiItem_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Ole")]
+            _NewEnum,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            _NewEnum: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            Count,
+            EnumFsiItems,
+            AddDirectory,
+            #[cfg(feature = "Win32_System_Com")]
+            AddFile,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddFile: 0,
+            AddTree,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            RemoveTree,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsiDirectoryItem as windows_core::Interface>::IID || <IFsiItem_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IFsiDirectoryItem2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddTreeWithNamedStreams(this: *mut core::ffi::c_void, sourcedirectory: core::mem::MaybeUninit<windows_core::BSTR>, includebasedirectory: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiDirectoryItem2", "AddTreeWithNamedStreams", 29usize, |this| ((**(this as *const *const IFsiDirectoryItem2_Vtbl)).AddTreeWithNamedStreams)(this, sourcedirectory, includebasedirectory), |result| Some(*result))
+        }
+        Self { base__: <IFsiDirectoryItem_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddTreeWithNamedStreams }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsiDirectoryItem2 as windows_core::Interface>::IID || <IFsiDirectoryItem_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsiFileItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DataSize(this: *mut core::ffi::c_void, pval: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiFileItem", "DataSize", 19usize, |this| ((**(this as *const *const IFsiFileItem_Vtbl)).DataSize)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataSize32BitLow(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiFileItem", "DataSize32BitLow", 20usize, |this| ((**(this as *const *const IFsiFileItem_Vtbl)).DataSize32BitLow)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataSize32BitHigh(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiFileItem", "DataSize32BitHigh", 21usize, |this| ((**(this as *const *const IFsiFileItem_Vtbl)).DataSize32BitHigh)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiFileItem", "Data", 22usize, |this| ((**(this as *const *const IFsiFileItem_Vtbl)).Data)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, newval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiFileItem", "SetData", 23usize, |this| ((**(this as *const *const IFsiFileItem_Vtbl)).SetData)(this, newval), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsiItem_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DataSize,
+            DataSize32BitLow,
+            DataSize32BitHigh,
+            #[cfg(feature = "Win32_System_Com")]
+            Data,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Data: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetData,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsiFileItem as windows_core::Interface>::IID || <IFsiItem_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsiFileItem2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn FsiNamedStreams(this: *mut core::ffi::c_void, streams: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiFileItem2", "FsiNamedStreams", 24usize, |this| ((**(this as *const *const IFsiFileItem2_Vtbl)).FsiNamedStreams)(this, streams), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsNamedStream(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiFileItem2", "IsNamedStream", 25usize, |this| ((**(this as *const *const IFsiFileItem2_Vtbl)).IsNamedStream)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddStream(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, streamdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiFileItem2", "AddStream", 26usize, |this| ((**(this as *const *const IFsiFileItem2_Vtbl)).AddStream)(this, name, streamdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStream(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiFileItem2", "RemoveStream", 27usize, |this| ((**(this as *const *const IFsiFileItem2_Vtbl)).RemoveStream)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRealTime(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiFileItem2", "IsRealTime", 28usize, |this| ((**(this as *const *const IFsiFileItem2_Vtbl)).IsRealTime)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsRealTime(this: *mut core::ffi::c_void, newval: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiFileItem2", "SetIsRealTime", 29usize, |this| ((**(this as *const *const IFsiFileItem2_Vtbl)).SetIsRealTime)(this, newval), |result| Some(*result))
+        }
+        Self {
+            base__: <IFsiFileItem_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            FsiNamedStreams,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            FsiNamedStreams: 0,
+            IsNamedStream,
+            #[cfg(feature = "Win32_System_Com")]
+            AddStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddStream: 0,
+            RemoveStream,
+            IsRealTime,
+            SetIsRealTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsiFileItem2 as windows_core::Interface>::IID || <IFsiFileItem_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFsiItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiItem", "Name", 7usize, |this| ((**(this as *const *const IFsiItem_Vtbl)).Name)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn FullPath(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiItem", "FullPath", 8usize, |this| ((**(this as *const *const IFsiItem_Vtbl)).FullPath)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreationTime(this: *mut core::ffi::c_void, pval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiItem", "CreationTime", 9usize, |this| ((**(this as *const *const IFsiItem_Vtbl)).CreationTime)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCreationTime(this: *mut core::ffi::c_void, newval: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiItem", "SetCreationTime", 10usize, |this| ((**(this as *const *const IFsiItem_Vtbl)).SetCreationTime)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastAccessedTime(this: *mut core::ffi::c_void, pval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiItem", "LastAccessedTime", 11usize, |this| ((**(this as *const *const IFsiItem_Vtbl)).LastAccessedTime)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLastAccessedTime(this: *mut core::ffi::c_void, newval: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiItem", "SetLastAccessedTime", 12usize, |this| ((**(this as *const *const IFsiItem_Vtbl)).SetLastAccessedTime)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastModifiedTime(this: *mut core::ffi::c_void, pval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiItem", "LastModifiedTime", 13usize, |this| ((**(this as *const *const IFsiItem_Vtbl)).LastModifiedTime)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLastModifiedTime(this: *mut core::ffi::c_void, newval: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiItem", "SetLastModifiedTime", 14usize, |this| ((**(this as *const *const IFsiItem_Vtbl)).SetLastModifiedTime)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsHidden(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiItem", "IsHidden", 15usize, |this| ((**(this as *const *const IFsiItem_Vtbl)).IsHidden)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsHidden(this: *mut core::ffi::c_void, newval: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiItem", "SetIsHidden", 16usize, |this| ((**(this as *const *const IFsiItem_Vtbl)).SetIsHidden)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn FileSystemName(this: *mut core::ffi::c_void, filesystem: FsiFileSystems, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiItem", "FileSystemName", 17usize, |this| ((**(this as *const *const IFsiItem_Vtbl)).FileSystemName)(this, filesystem, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn FileSystemPath(this: *mut core::ffi::c_void, filesystem: FsiFileSystems, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiItem", "FileSystemPath", 18usize, |this| ((**(this as *const *const IFsiItem_Vtbl)).FileSystemPath)(this, filesystem, pval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            FullPath,
+            CreationTime,
+            SetCreationTime,
+            LastAccessedTime,
+            SetLastAccessedTime,
+            LastModifiedTime,
+            SetLastModifiedTime,
+            IsHidden,
+            SetIsHidden,
+            FileSystemName,
+            FileSystemPath,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsiItem as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IFsiNamedStreams_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, newenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiNamedStreams", "_NewEnum", 7usize, |this| ((**(this as *const *const IFsiNamedStreams_Vtbl))._NewEnum)(this, newenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, item: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiNamedStreams", "get_Item", 8usize, |this| ((**(this as *const *const IFsiNamedStreams_Vtbl)).get_Item)(this, index, item), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiNamedStreams", "Count", 9usize, |this| ((**(this as *const *const IFsiNamedStreams_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumNamedStreams(this: *mut core::ffi::c_void, newenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFsiNamedStreams", "EnumNamedStreams", 10usize, |this| ((**(this as *const *const IFsiNamedStreams_Vtbl)).EnumNamedStreams)(this, newenum), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Ole")]
+            _NewEnum,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            _NewEnum: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            Count,
+            EnumNamedStreams,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFsiNamedStreams as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IIsoImageManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsoImageManager", "Path", 7usize, |this| ((**(this as *const *const IIsoImageManager_Vtbl)).Path)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Stream(this: *mut core::ffi::c_void, data: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsoImageManager", "Stream", 8usize, |this| ((**(this as *const *const IIsoImageManager_Vtbl)).Stream)(this, data), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPath(this: *mut core::ffi::c_void, val: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsoImageManager", "SetPath", 9usize, |this| ((**(this as *const *const IIsoImageManager_Vtbl)).SetPath)(this, val), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetStream(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsoImageManager", "SetStream", 10usize, |this| ((**(this as *const *const IIsoImageManager_Vtbl)).SetStream)(this, data), |result| Some(*result))
+        }
+        unsafe extern "system" fn Validate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsoImageManager", "Validate", 11usize, |this| ((**(this as *const *const IIsoImageManager_Vtbl)).Validate)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Path,
+            #[cfg(feature = "Win32_System_Com")]
+            Stream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Stream: 0,
+            SetPath,
+            #[cfg(feature = "Win32_System_Com")]
+            SetStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetStream: 0,
+            Validate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsoImageManager as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for IJolietDiscMaster_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTotalDataBlocks(this: *mut core::ffi::c_void, pnblocks: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJolietDiscMaster", "GetTotalDataBlocks", 3usize, |this| ((**(this as *const *const IJolietDiscMaster_Vtbl)).GetTotalDataBlocks)(this, pnblocks), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUsedDataBlocks(this: *mut core::ffi::c_void, pnblocks: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJolietDiscMaster", "GetUsedDataBlocks", 4usize, |this| ((**(this as *const *const IJolietDiscMaster_Vtbl)).GetUsedDataBlocks)(this, pnblocks), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataBlockSize(this: *mut core::ffi::c_void, pnblockbytes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJolietDiscMaster", "GetDataBlockSize", 5usize, |this| ((**(this as *const *const IJolietDiscMaster_Vtbl)).GetDataBlockSize)(this, pnblockbytes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn AddData(this: *mut core::ffi::c_void, pstorage: *mut core::ffi::c_void, lfileoverwrite: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJolietDiscMaster", "AddData", 6usize, |this| ((**(this as *const *const IJolietDiscMaster_Vtbl)).AddData)(this, pstorage, lfileoverwrite), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn GetJolietProperties(this: *mut core::ffi::c_void, pppropstg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJolietDiscMaster", "GetJolietProperties", 7usize, |this| ((**(this as *const *const IJolietDiscMaster_Vtbl)).GetJolietProperties)(this, pppropstg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn SetJolietProperties(this: *mut core::ffi::c_void, ppropstg: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJolietDiscMaster", "SetJolietProperties", 8usize, |this| ((**(this as *const *const IJolietDiscMaster_Vtbl)).SetJolietProperties)(this, ppropstg), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTotalDataBlocks,
+            GetUsedDataBlocks,
+            GetDataBlockSize,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            AddData,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            AddData: 0,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            GetJolietProperties,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            GetJolietProperties: 0,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            SetJolietProperties,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            SetJolietProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IJolietDiscMaster as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMultisession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSupportedOnCurrentMediaState(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultisession", "IsSupportedOnCurrentMediaState", 7usize, |this| ((**(this as *const *const IMultisession_Vtbl)).IsSupportedOnCurrentMediaState)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInUse(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultisession", "SetInUse", 8usize, |this| ((**(this as *const *const IMultisession_Vtbl)).SetInUse)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InUse(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultisession", "InUse", 9usize, |this| ((**(this as *const *const IMultisession_Vtbl)).InUse)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ImportRecorder(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultisession", "ImportRecorder", 10usize, |this| ((**(this as *const *const IMultisession_Vtbl)).ImportRecorder)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsSupportedOnCurrentMediaState,
+            SetInUse,
+            InUse,
+            #[cfg(feature = "Win32_System_Com")]
+            ImportRecorder,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ImportRecorder: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMultisession as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMultisessionRandomWrite_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WriteUnitSize(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultisessionRandomWrite", "WriteUnitSize", 11usize, |this| ((**(this as *const *const IMultisessionRandomWrite_Vtbl)).WriteUnitSize)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastWrittenAddress(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultisessionRandomWrite", "LastWrittenAddress", 12usize, |this| ((**(this as *const *const IMultisessionRandomWrite_Vtbl)).LastWrittenAddress)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalSectorsOnMedia(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultisessionRandomWrite", "TotalSectorsOnMedia", 13usize, |this| ((**(this as *const *const IMultisessionRandomWrite_Vtbl)).TotalSectorsOnMedia)(this, value), |result| Some(*result))
+        }
+        Self { base__: <IMultisession_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WriteUnitSize, LastWrittenAddress, TotalSectorsOnMedia }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMultisessionRandomWrite as windows_core::Interface>::IID || <IMultisession_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMultisessionSequential_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsFirstDataSession(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultisessionSequential", "IsFirstDataSession", 11usize, |this| ((**(this as *const *const IMultisessionSequential_Vtbl)).IsFirstDataSession)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartAddressOfPreviousSession(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultisessionSequential", "StartAddressOfPreviousSession", 12usize, |this| ((**(this as *const *const IMultisessionSequential_Vtbl)).StartAddressOfPreviousSession)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastWrittenAddressOfPreviousSession(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultisessionSequential", "LastWrittenAddressOfPreviousSession", 13usize, |this| ((**(this as *const *const IMultisessionSequential_Vtbl)).LastWrittenAddressOfPreviousSession)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn NextWritableAddress(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultisessionSequential", "NextWritableAddress", 14usize, |this| ((**(this as *const *const IMultisessionSequential_Vtbl)).NextWritableAddress)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreeSectorsOnMedia(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultisessionSequential", "FreeSectorsOnMedia", 15usize, |this| ((**(this as *const *const IMultisessionSequential_Vtbl)).FreeSectorsOnMedia)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <IMultisession_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsFirstDataSession,
+            StartAddressOfPreviousSession,
+            LastWrittenAddressOfPreviousSession,
+            NextWritableAddress,
+            FreeSectorsOnMedia,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMultisessionSequential as windows_core::Interface>::IID || <IMultisession_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMultisessionSequential2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WriteUnitSize(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultisessionSequential2", "WriteUnitSize", 16usize, |this| ((**(this as *const *const IMultisessionSequential2_Vtbl)).WriteUnitSize)(this, value), |result| Some(*result))
+        }
+        Self { base__: <IMultisessionSequential_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WriteUnitSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMultisessionSequential2 as windows_core::Interface>::IID || <IMultisessionSequential_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IProgressItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, desc: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProgressItem", "Description", 7usize, |this| ((**(this as *const *const IProgressItem_Vtbl)).Description)(this, desc), |result| Some(*result))
+        }
+        unsafe extern "system" fn FirstBlock(this: *mut core::ffi::c_void, block: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProgressItem", "FirstBlock", 8usize, |this| ((**(this as *const *const IProgressItem_Vtbl)).FirstBlock)(this, block), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastBlock(this: *mut core::ffi::c_void, block: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProgressItem", "LastBlock", 9usize, |this| ((**(this as *const *const IProgressItem_Vtbl)).LastBlock)(this, block), |result| Some(*result))
+        }
+        unsafe extern "system" fn BlockCount(this: *mut core::ffi::c_void, blocks: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProgressItem", "BlockCount", 10usize, |this| ((**(this as *const *const IProgressItem_Vtbl)).BlockCount)(this, blocks), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Description, FirstBlock, LastBlock, BlockCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProgressItem as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IProgressItems_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, newenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProgressItems", "_NewEnum", 7usize, |this| ((**(this as *const *const IProgressItems_Vtbl))._NewEnum)(this, newenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, item: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProgressItems", "get_Item", 8usize, |this| ((**(this as *const *const IProgressItems_Vtbl)).get_Item)(this, index, item), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProgressItems", "Count", 9usize, |this| ((**(this as *const *const IProgressItems_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ProgressItemFromBlock(this: *mut core::ffi::c_void, block: u32, item: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProgressItems", "ProgressItemFromBlock", 10usize, |this| ((**(this as *const *const IProgressItems_Vtbl)).ProgressItemFromBlock)(this, block, item), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ProgressItemFromDescription(this: *mut core::ffi::c_void, description: core::mem::MaybeUninit<windows_core::BSTR>, item: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProgressItems", "ProgressItemFromDescription", 11usize, |this| ((**(this as *const *const IProgressItems_Vtbl)).ProgressItemFromDescription)(this, description, item), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumProgressItems(this: *mut core::ffi::c_void, newenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProgressItems", "EnumProgressItems", 12usize, |this| ((**(this as *const *const IProgressItems_Vtbl)).EnumProgressItems)(this, newenum), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Ole")]
+            _NewEnum,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            _NewEnum: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            ProgressItemFromBlock,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ProgressItemFromBlock: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ProgressItemFromDescription,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ProgressItemFromDescription: 0,
+            EnumProgressItems,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProgressItems as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRawCDImageCreator_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateResultImage(this: *mut core::ffi::c_void, resultstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageCreator", "CreateResultImage", 7usize, |this| ((**(this as *const *const IRawCDImageCreator_Vtbl)).CreateResultImage)(this, resultstream), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddTrack(this: *mut core::ffi::c_void, datatype: IMAPI_CD_SECTOR_TYPE, data: *mut core::ffi::c_void, trackindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageCreator", "AddTrack", 8usize, |this| ((**(this as *const *const IRawCDImageCreator_Vtbl)).AddTrack)(this, datatype, data, trackindex), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddSpecialPregap(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageCreator", "AddSpecialPregap", 9usize, |this| ((**(this as *const *const IRawCDImageCreator_Vtbl)).AddSpecialPregap)(this, data), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddSubcodeRWGenerator(this: *mut core::ffi::c_void, subcode: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageCreator", "AddSubcodeRWGenerator", 10usize, |this| ((**(this as *const *const IRawCDImageCreator_Vtbl)).AddSubcodeRWGenerator)(this, subcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetResultingImageType(this: *mut core::ffi::c_void, value: IMAPI_FORMAT2_RAW_CD_DATA_SECTOR_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageCreator", "SetResultingImageType", 11usize, |this| ((**(this as *const *const IRawCDImageCreator_Vtbl)).SetResultingImageType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResultingImageType(this: *mut core::ffi::c_void, value: *mut IMAPI_FORMAT2_RAW_CD_DATA_SECTOR_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageCreator", "ResultingImageType", 12usize, |this| ((**(this as *const *const IRawCDImageCreator_Vtbl)).ResultingImageType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartOfLeadout(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageCreator", "StartOfLeadout", 13usize, |this| ((**(this as *const *const IRawCDImageCreator_Vtbl)).StartOfLeadout)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartOfLeadoutLimit(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageCreator", "SetStartOfLeadoutLimit", 14usize, |this| ((**(this as *const *const IRawCDImageCreator_Vtbl)).SetStartOfLeadoutLimit)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartOfLeadoutLimit(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageCreator", "StartOfLeadoutLimit", 15usize, |this| ((**(this as *const *const IRawCDImageCreator_Vtbl)).StartOfLeadoutLimit)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisableGaplessAudio(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageCreator", "SetDisableGaplessAudio", 16usize, |this| ((**(this as *const *const IRawCDImageCreator_Vtbl)).SetDisableGaplessAudio)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableGaplessAudio(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageCreator", "DisableGaplessAudio", 17usize, |this| ((**(this as *const *const IRawCDImageCreator_Vtbl)).DisableGaplessAudio)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMediaCatalogNumber(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageCreator", "SetMediaCatalogNumber", 18usize, |this| ((**(this as *const *const IRawCDImageCreator_Vtbl)).SetMediaCatalogNumber)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaCatalogNumber(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageCreator", "MediaCatalogNumber", 19usize, |this| ((**(this as *const *const IRawCDImageCreator_Vtbl)).MediaCatalogNumber)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartingTrackNumber(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageCreator", "SetStartingTrackNumber", 20usize, |this| ((**(this as *const *const IRawCDImageCreator_Vtbl)).SetStartingTrackNumber)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartingTrackNumber(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageCreator", "StartingTrackNumber", 21usize, |this| ((**(this as *const *const IRawCDImageCreator_Vtbl)).StartingTrackNumber)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_TrackInfo(this: *mut core::ffi::c_void, trackindex: i32, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageCreator", "get_TrackInfo", 22usize, |this| ((**(this as *const *const IRawCDImageCreator_Vtbl)).get_TrackInfo)(this, trackindex, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfExistingTracks(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageCreator", "NumberOfExistingTracks", 23usize, |this| ((**(this as *const *const IRawCDImageCreator_Vtbl)).NumberOfExistingTracks)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastUsedUserSectorInImage(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageCreator", "LastUsedUserSectorInImage", 24usize, |this| ((**(this as *const *const IRawCDImageCreator_Vtbl)).LastUsedUserSectorInImage)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ExpectedTableOfContents(this: *mut core::ffi::c_void, value: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageCreator", "ExpectedTableOfContents", 25usize, |this| ((**(this as *const *const IRawCDImageCreator_Vtbl)).ExpectedTableOfContents)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateResultImage,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateResultImage: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AddTrack,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddTrack: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AddSpecialPregap,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddSpecialPregap: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AddSubcodeRWGenerator,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddSubcodeRWGenerator: 0,
+            SetResultingImageType,
+            ResultingImageType,
+            StartOfLeadout,
+            SetStartOfLeadoutLimit,
+            StartOfLeadoutLimit,
+            SetDisableGaplessAudio,
+            DisableGaplessAudio,
+            SetMediaCatalogNumber,
+            MediaCatalogNumber,
+            SetStartingTrackNumber,
+            StartingTrackNumber,
+            #[cfg(feature = "Win32_System_Com")]
+            get_TrackInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_TrackInfo: 0,
+            NumberOfExistingTracks,
+            LastUsedUserSectorInImage,
+            #[cfg(feature = "Win32_System_Com")]
+            ExpectedTableOfContents,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ExpectedTableOfContents: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRawCDImageCreator as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRawCDImageTrackInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartingLba(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageTrackInfo", "StartingLba", 7usize, |this| ((**(this as *const *const IRawCDImageTrackInfo_Vtbl)).StartingLba)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SectorCount(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageTrackInfo", "SectorCount", 8usize, |this| ((**(this as *const *const IRawCDImageTrackInfo_Vtbl)).SectorCount)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrackNumber(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageTrackInfo", "TrackNumber", 9usize, |this| ((**(this as *const *const IRawCDImageTrackInfo_Vtbl)).TrackNumber)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SectorType(this: *mut core::ffi::c_void, value: *mut IMAPI_CD_SECTOR_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageTrackInfo", "SectorType", 10usize, |this| ((**(this as *const *const IRawCDImageTrackInfo_Vtbl)).SectorType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ISRC(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageTrackInfo", "ISRC", 11usize, |this| ((**(this as *const *const IRawCDImageTrackInfo_Vtbl)).ISRC)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetISRC(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageTrackInfo", "SetISRC", 12usize, |this| ((**(this as *const *const IRawCDImageTrackInfo_Vtbl)).SetISRC)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn DigitalAudioCopySetting(this: *mut core::ffi::c_void, value: *mut IMAPI_CD_TRACK_DIGITAL_COPY_SETTING) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageTrackInfo", "DigitalAudioCopySetting", 13usize, |this| ((**(this as *const *const IRawCDImageTrackInfo_Vtbl)).DigitalAudioCopySetting)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDigitalAudioCopySetting(this: *mut core::ffi::c_void, value: IMAPI_CD_TRACK_DIGITAL_COPY_SETTING) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageTrackInfo", "SetDigitalAudioCopySetting", 14usize, |this| ((**(this as *const *const IRawCDImageTrackInfo_Vtbl)).SetDigitalAudioCopySetting)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AudioHasPreemphasis(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageTrackInfo", "AudioHasPreemphasis", 15usize, |this| ((**(this as *const *const IRawCDImageTrackInfo_Vtbl)).AudioHasPreemphasis)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAudioHasPreemphasis(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageTrackInfo", "SetAudioHasPreemphasis", 16usize, |this| ((**(this as *const *const IRawCDImageTrackInfo_Vtbl)).SetAudioHasPreemphasis)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn TrackIndexes(this: *mut core::ffi::c_void, value: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageTrackInfo", "TrackIndexes", 17usize, |this| ((**(this as *const *const IRawCDImageTrackInfo_Vtbl)).TrackIndexes)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTrackIndex(this: *mut core::ffi::c_void, lbaoffset: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageTrackInfo", "AddTrackIndex", 18usize, |this| ((**(this as *const *const IRawCDImageTrackInfo_Vtbl)).AddTrackIndex)(this, lbaoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearTrackIndex(this: *mut core::ffi::c_void, lbaoffset: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawCDImageTrackInfo", "ClearTrackIndex", 19usize, |this| ((**(this as *const *const IRawCDImageTrackInfo_Vtbl)).ClearTrackIndex)(this, lbaoffset), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartingLba,
+            SectorCount,
+            TrackNumber,
+            SectorType,
+            ISRC,
+            SetISRC,
+            DigitalAudioCopySetting,
+            SetDigitalAudioCopySetting,
+            AudioHasPreemphasis,
+            SetAudioHasPreemphasis,
+            #[cfg(feature = "Win32_System_Com")]
+            TrackIndexes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            TrackIndexes: 0,
+            AddTrackIndex,
+            ClearTrackIndex,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRawCDImageTrackInfo as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRedbookDiscMaster_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTotalAudioTracks(this: *mut core::ffi::c_void, pntracks: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRedbookDiscMaster", "GetTotalAudioTracks", 3usize, |this| ((**(this as *const *const IRedbookDiscMaster_Vtbl)).GetTotalAudioTracks)(this, pntracks), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTotalAudioBlocks(this: *mut core::ffi::c_void, pnblocks: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRedbookDiscMaster", "GetTotalAudioBlocks", 4usize, |this| ((**(this as *const *const IRedbookDiscMaster_Vtbl)).GetTotalAudioBlocks)(this, pnblocks), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUsedAudioBlocks(this: *mut core::ffi::c_void, pnblocks: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRedbookDiscMaster", "GetUsedAudioBlocks", 5usize, |this| ((**(this as *const *const IRedbookDiscMaster_Vtbl)).GetUsedAudioBlocks)(this, pnblocks), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAvailableAudioTrackBlocks(this: *mut core::ffi::c_void, pnblocks: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRedbookDiscMaster", "GetAvailableAudioTrackBlocks", 6usize, |this| ((**(this as *const *const IRedbookDiscMaster_Vtbl)).GetAvailableAudioTrackBlocks)(this, pnblocks), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAudioBlockSize(this: *mut core::ffi::c_void, pnblockbytes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRedbookDiscMaster", "GetAudioBlockSize", 7usize, |this| ((**(this as *const *const IRedbookDiscMaster_Vtbl)).GetAudioBlockSize)(this, pnblockbytes), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAudioTrack(this: *mut core::ffi::c_void, nblocks: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRedbookDiscMaster", "CreateAudioTrack", 8usize, |this| ((**(this as *const *const IRedbookDiscMaster_Vtbl)).CreateAudioTrack)(this, nblocks), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddAudioTrackBlocks(this: *mut core::ffi::c_void, pby: *const u8, cb: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRedbookDiscMaster", "AddAudioTrackBlocks", 9usize, |this| ((**(this as *const *const IRedbookDiscMaster_Vtbl)).AddAudioTrackBlocks)(this, pby, cb), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseAudioTrack(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRedbookDiscMaster", "CloseAudioTrack", 10usize, |this| ((**(this as *const *const IRedbookDiscMaster_Vtbl)).CloseAudioTrack)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTotalAudioTracks,
+            GetTotalAudioBlocks,
+            GetUsedAudioBlocks,
+            GetAvailableAudioTrackBlocks,
+            GetAudioBlockSize,
+            CreateAudioTrack,
+            AddAudioTrackBlocks,
+            CloseAudioTrack,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRedbookDiscMaster as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IStreamConcatenate_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, stream1: *mut core::ffi::c_void, stream2: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamConcatenate", "Initialize", 14usize, |this| ((**(this as *const *const IStreamConcatenate_Vtbl)).Initialize)(this, stream1, stream2), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Initialize2(this: *mut core::ffi::c_void, streams: *const *mut core::ffi::c_void, streamcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamConcatenate", "Initialize2", 15usize, |this| ((**(this as *const *const IStreamConcatenate_Vtbl)).Initialize2)(this, streams, streamcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Append(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamConcatenate", "Append", 16usize, |this| ((**(this as *const *const IStreamConcatenate_Vtbl)).Append)(this, stream), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Append2(this: *mut core::ffi::c_void, streams: *const *mut core::ffi::c_void, streamcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamConcatenate", "Append2", 17usize, |this| ((**(this as *const *const IStreamConcatenate_Vtbl)).Append2)(this, streams, streamcount), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IStream_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Initialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Initialize: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Initialize2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Initialize2: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Append,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Append: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Append2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Append2: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamConcatenate as windows_core::Interface>::IID || <super::super::System::Com::IStream_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IStreamInterleave_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, streams: *const *mut core::ffi::c_void, interleavesizes: *const u32, streamcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamInterleave", "Initialize", 14usize, |this| ((**(this as *const *const IStreamInterleave_Vtbl)).Initialize)(this, streams, interleavesizes, streamcount), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IStream_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Initialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Initialize: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamInterleave as windows_core::Interface>::IID || <super::super::System::Com::IStream_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IStreamPseudoRandomBased_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSeed(this: *mut core::ffi::c_void, value: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamPseudoRandomBased", "SetSeed", 14usize, |this| ((**(this as *const *const IStreamPseudoRandomBased_Vtbl)).SetSeed)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Seed(this: *mut core::ffi::c_void, value: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamPseudoRandomBased", "Seed", 15usize, |this| ((**(this as *const *const IStreamPseudoRandomBased_Vtbl)).Seed)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_ExtendedSeed(this: *mut core::ffi::c_void, values: *const u32, ecount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamPseudoRandomBased", "put_ExtendedSeed", 16usize, |this| ((**(this as *const *const IStreamPseudoRandomBased_Vtbl)).put_ExtendedSeed)(this, values, ecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_ExtendedSeed(this: *mut core::ffi::c_void, values: *mut *mut u32, ecount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStreamPseudoRandomBased", "get_ExtendedSeed", 17usize, |this| ((**(this as *const *const IStreamPseudoRandomBased_Vtbl)).get_ExtendedSeed)(this, values, ecount), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IStream_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetSeed, Seed, put_ExtendedSeed, get_ExtendedSeed }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStreamPseudoRandomBased as windows_core::Interface>::IID || <super::super::System::Com::IStream_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWriteEngine2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn WriteSection(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void, startingblockaddress: i32, numberofblocks: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWriteEngine2", "WriteSection", 7usize, |this| ((**(this as *const *const IWriteEngine2_Vtbl)).WriteSection)(this, data, startingblockaddress, numberofblocks), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelWrite(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWriteEngine2", "CancelWrite", 8usize, |this| ((**(this as *const *const IWriteEngine2_Vtbl)).CancelWrite)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRecorder(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWriteEngine2", "SetRecorder", 9usize, |this| ((**(this as *const *const IWriteEngine2_Vtbl)).SetRecorder)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Recorder(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWriteEngine2", "Recorder", 10usize, |this| ((**(this as *const *const IWriteEngine2_Vtbl)).Recorder)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUseStreamingWrite12(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWriteEngine2", "SetUseStreamingWrite12", 11usize, |this| ((**(this as *const *const IWriteEngine2_Vtbl)).SetUseStreamingWrite12)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseStreamingWrite12(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWriteEngine2", "UseStreamingWrite12", 12usize, |this| ((**(this as *const *const IWriteEngine2_Vtbl)).UseStreamingWrite12)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartingSectorsPerSecond(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWriteEngine2", "SetStartingSectorsPerSecond", 13usize, |this| ((**(this as *const *const IWriteEngine2_Vtbl)).SetStartingSectorsPerSecond)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartingSectorsPerSecond(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWriteEngine2", "StartingSectorsPerSecond", 14usize, |this| ((**(this as *const *const IWriteEngine2_Vtbl)).StartingSectorsPerSecond)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEndingSectorsPerSecond(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWriteEngine2", "SetEndingSectorsPerSecond", 15usize, |this| ((**(this as *const *const IWriteEngine2_Vtbl)).SetEndingSectorsPerSecond)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndingSectorsPerSecond(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWriteEngine2", "EndingSectorsPerSecond", 16usize, |this| ((**(this as *const *const IWriteEngine2_Vtbl)).EndingSectorsPerSecond)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBytesPerSector(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWriteEngine2", "SetBytesPerSector", 17usize, |this| ((**(this as *const *const IWriteEngine2_Vtbl)).SetBytesPerSector)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BytesPerSector(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWriteEngine2", "BytesPerSector", 18usize, |this| ((**(this as *const *const IWriteEngine2_Vtbl)).BytesPerSector)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteInProgress(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWriteEngine2", "WriteInProgress", 19usize, |this| ((**(this as *const *const IWriteEngine2_Vtbl)).WriteInProgress)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            WriteSection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            WriteSection: 0,
+            CancelWrite,
+            SetRecorder,
+            Recorder,
+            SetUseStreamingWrite12,
+            UseStreamingWrite12,
+            SetStartingSectorsPerSecond,
+            StartingSectorsPerSecond,
+            SetEndingSectorsPerSecond,
+            EndingSectorsPerSecond,
+            SetBytesPerSector,
+            BytesPerSector,
+            WriteInProgress,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWriteEngine2 as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWriteEngine2EventArgs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartLba(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWriteEngine2EventArgs", "StartLba", 7usize, |this| ((**(this as *const *const IWriteEngine2EventArgs_Vtbl)).StartLba)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SectorCount(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWriteEngine2EventArgs", "SectorCount", 8usize, |this| ((**(this as *const *const IWriteEngine2EventArgs_Vtbl)).SectorCount)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastReadLba(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWriteEngine2EventArgs", "LastReadLba", 9usize, |this| ((**(this as *const *const IWriteEngine2EventArgs_Vtbl)).LastReadLba)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastWrittenLba(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWriteEngine2EventArgs", "LastWrittenLba", 10usize, |this| ((**(this as *const *const IWriteEngine2EventArgs_Vtbl)).LastWrittenLba)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalSystemBuffer(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWriteEngine2EventArgs", "TotalSystemBuffer", 11usize, |this| ((**(this as *const *const IWriteEngine2EventArgs_Vtbl)).TotalSystemBuffer)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsedSystemBuffer(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWriteEngine2EventArgs", "UsedSystemBuffer", 12usize, |this| ((**(this as *const *const IWriteEngine2EventArgs_Vtbl)).UsedSystemBuffer)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreeSystemBuffer(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWriteEngine2EventArgs", "FreeSystemBuffer", 13usize, |this| ((**(this as *const *const IWriteEngine2EventArgs_Vtbl)).FreeSystemBuffer)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartLba,
+            SectorCount,
+            LastReadLba,
+            LastWrittenLba,
+            TotalSystemBuffer,
+            UsedSystemBuffer,
+            FreeSystemBuffer,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWriteEngine2EventArgs as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWriteSpeedDescriptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MediaType(this: *mut core::ffi::c_void, value: *mut IMAPI_MEDIA_PHYSICAL_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWriteSpeedDescriptor", "MediaType", 7usize, |this| ((**(this as *const *const IWriteSpeedDescriptor_Vtbl)).MediaType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RotationTypeIsPureCAV(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWriteSpeedDescriptor", "RotationTypeIsPureCAV", 8usize, |this| ((**(this as *const *const IWriteSpeedDescriptor_Vtbl)).RotationTypeIsPureCAV)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteSpeed(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWriteSpeedDescriptor", "WriteSpeed", 9usize, |this| ((**(this as *const *const IWriteSpeedDescriptor_Vtbl)).WriteSpeed)(this, value), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MediaType, RotationTypeIsPureCAV, WriteSpeed }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWriteSpeedDescriptor as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
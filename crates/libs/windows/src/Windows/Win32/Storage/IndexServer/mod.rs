@@ -291,6 +291,7 @@ pub struct CHUNKSTATE(pub i32);
 impl windows_core::TypeKind for CHUNKSTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CHUNKSTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CHUNKSTATE").field(&self.0).finish()
@@ -302,6 +303,7 @@ pub struct CHUNK_BREAKTYPE(pub i32);
 impl windows_core::TypeKind for CHUNK_BREAKTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CHUNK_BREAKTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CHUNK_BREAKTYPE").field(&self.0).finish()
@@ -313,6 +315,7 @@ pub struct DBKINDENUM(pub i32);
 impl windows_core::TypeKind for DBKINDENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBKINDENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBKINDENUM").field(&self.0).finish()
@@ -324,6 +327,7 @@ pub struct IFILTER_FLAGS(pub i32);
 impl windows_core::TypeKind for IFILTER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IFILTER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IFILTER_FLAGS").field(&self.0).finish()
@@ -335,6 +339,7 @@ pub struct IFILTER_INIT(pub i32);
 impl windows_core::TypeKind for IFILTER_INIT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IFILTER_INIT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IFILTER_INIT").field(&self.0).finish()
@@ -346,13 +351,15 @@ pub struct WORDREP_BREAK_TYPE(pub i32);
 impl windows_core::TypeKind for WORDREP_BREAK_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WORDREP_BREAK_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WORDREP_BREAK_TYPE").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CI_STATE {
     pub cbStruct: u32,
     pub cWordList: u32,
@@ -483,7 +490,8 @@ impl Default for DBID_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILTERREGION {
     pub idChunk: u32,
     pub cwcStart: u32,
@@ -539,3 +547,5 @@ impl Default for STAT_CHUNK {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,55 @@
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for IFilter_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn Init(this: *mut core::ffi::c_void, grfflags: u32, cattributes: u32, aattributes: *const FULLPROPSPEC, pflags: *mut u32) -> i32 {
+            windows_core::imp::proxy_call(this, "IFilter", "Init", 3usize, |this| ((**(this as *const *const IFilter_Vtbl)).Init)(this, grfflags, cattributes, aattributes, pflags), |_| None)
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn GetChunk(this: *mut core::ffi::c_void, pstat: *mut STAT_CHUNK) -> i32 {
+            windows_core::imp::proxy_call(this, "IFilter", "GetChunk", 4usize, |this| ((**(this as *const *const IFilter_Vtbl)).GetChunk)(this, pstat), |_| None)
+        }
+        unsafe extern "system" fn GetText(this: *mut core::ffi::c_void, pcwcbuffer: *mut u32, awcbuffer: windows_core::PWSTR) -> i32 {
+            windows_core::imp::proxy_call(this, "IFilter", "GetText", 5usize, |this| ((**(this as *const *const IFilter_Vtbl)).GetText)(this, pcwcbuffer, awcbuffer), |_| None)
+        }
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, pppropvalue: *mut *mut windows_core::PROPVARIANT) -> i32 {
+            windows_core::imp::proxy_call(this, "IFilter", "GetValue", 6usize, |this| ((**(this as *const *const IFilter_Vtbl)).GetValue)(this, pppropvalue), |_| None)
+        }
+        unsafe extern "system" fn BindRegion(this: *mut core::ffi::c_void, origpos: FILTERREGION, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> i32 {
+            windows_core::imp::proxy_call(this, "IFilter", "BindRegion", 7usize, |this| ((**(this as *const *const IFilter_Vtbl)).BindRegion)(this, origpos, riid, ppunk), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            Init,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            Init: 0,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            GetChunk,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            GetChunk: 0,
+            GetText,
+            GetValue,
+            BindRegion,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFilter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPhraseSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PutSmallPhrase(this: *mut core::ffi::c_void, pwcnoun: windows_core::PCWSTR, cwcnoun: u32, pwcmodifier: windows_core::PCWSTR, cwcmodifier: u32, ulattachmenttype: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhraseSink", "PutSmallPhrase", 3usize, |this| ((**(this as *const *const IPhraseSink_Vtbl)).PutSmallPhrase)(this, pwcnoun, cwcnoun, pwcmodifier, cwcmodifier, ulattachmenttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutPhrase(this: *mut core::ffi::c_void, pwcphrase: windows_core::PCWSTR, cwcphrase: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPhraseSink", "PutPhrase", 4usize, |this| ((**(this as *const *const IPhraseSink_Vtbl)).PutPhrase)(this, pwcphrase, cwcphrase), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PutSmallPhrase, PutPhrase }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPhraseSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -520,6 +520,7 @@ pub struct NVME_ACCESS_FREQUENCIES(pub i32);
 impl windows_core::TypeKind for NVME_ACCESS_FREQUENCIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_ACCESS_FREQUENCIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_ACCESS_FREQUENCIES").field(&self.0).finish()
@@ -531,6 +532,7 @@ pub struct NVME_ACCESS_LATENCIES(pub i32);
 impl windows_core::TypeKind for NVME_ACCESS_LATENCIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_ACCESS_LATENCIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_ACCESS_LATENCIES").field(&self.0).finish()
@@ -542,6 +544,7 @@ pub struct NVME_ADMIN_COMMANDS(pub i32);
 impl windows_core::TypeKind for NVME_ADMIN_COMMANDS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_ADMIN_COMMANDS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_ADMIN_COMMANDS").field(&self.0).finish()
@@ -553,6 +556,7 @@ pub struct NVME_AMS_OPTION(pub i32);
 impl windows_core::TypeKind for NVME_AMS_OPTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_AMS_OPTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_AMS_OPTION").field(&self.0).finish()
@@ -564,6 +568,7 @@ pub struct NVME_ASYNC_EVENT_ERROR_STATUS_CODES(pub i32);
 impl windows_core::TypeKind for NVME_ASYNC_EVENT_ERROR_STATUS_CODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_ASYNC_EVENT_ERROR_STATUS_CODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_ASYNC_EVENT_ERROR_STATUS_CODES").field(&self.0).finish()
@@ -575,6 +580,7 @@ pub struct NVME_ASYNC_EVENT_HEALTH_STATUS_CODES(pub i32);
 impl windows_core::TypeKind for NVME_ASYNC_EVENT_HEALTH_STATUS_CODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_ASYNC_EVENT_HEALTH_STATUS_CODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_ASYNC_EVENT_HEALTH_STATUS_CODES").field(&self.0).finish()
@@ -586,6 +592,7 @@ pub struct NVME_ASYNC_EVENT_IO_COMMAND_SET_STATUS_CODES(pub i32);
 impl windows_core::TypeKind for NVME_ASYNC_EVENT_IO_COMMAND_SET_STATUS_CODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_ASYNC_EVENT_IO_COMMAND_SET_STATUS_CODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_ASYNC_EVENT_IO_COMMAND_SET_STATUS_CODES").field(&self.0).finish()
@@ -597,6 +604,7 @@ pub struct NVME_ASYNC_EVENT_NOTICE_CODES(pub i32);
 impl windows_core::TypeKind for NVME_ASYNC_EVENT_NOTICE_CODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_ASYNC_EVENT_NOTICE_CODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_ASYNC_EVENT_NOTICE_CODES").field(&self.0).finish()
@@ -608,6 +616,7 @@ pub struct NVME_ASYNC_EVENT_TYPES(pub i32);
 impl windows_core::TypeKind for NVME_ASYNC_EVENT_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_ASYNC_EVENT_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_ASYNC_EVENT_TYPES").field(&self.0).finish()
@@ -619,6 +628,7 @@ pub struct NVME_ASYNC_EVENT_TYPE_VENDOR_SPECIFIC_CODES(pub i32);
 impl windows_core::TypeKind for NVME_ASYNC_EVENT_TYPE_VENDOR_SPECIFIC_CODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_ASYNC_EVENT_TYPE_VENDOR_SPECIFIC_CODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_ASYNC_EVENT_TYPE_VENDOR_SPECIFIC_CODES").field(&self.0).finish()
@@ -630,6 +640,7 @@ pub struct NVME_CC_SHN_SHUTDOWN_NOTIFICATIONS(pub i32);
 impl windows_core::TypeKind for NVME_CC_SHN_SHUTDOWN_NOTIFICATIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_CC_SHN_SHUTDOWN_NOTIFICATIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_CC_SHN_SHUTDOWN_NOTIFICATIONS").field(&self.0).finish()
@@ -641,6 +652,7 @@ pub struct NVME_CMBSZ_SIZE_UNITS(pub i32);
 impl windows_core::TypeKind for NVME_CMBSZ_SIZE_UNITS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_CMBSZ_SIZE_UNITS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_CMBSZ_SIZE_UNITS").field(&self.0).finish()
@@ -652,6 +664,7 @@ pub struct NVME_COMMAND_EFFECT_SBUMISSION_EXECUTION_LIMITS(pub i32);
 impl windows_core::TypeKind for NVME_COMMAND_EFFECT_SBUMISSION_EXECUTION_LIMITS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_COMMAND_EFFECT_SBUMISSION_EXECUTION_LIMITS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_COMMAND_EFFECT_SBUMISSION_EXECUTION_LIMITS").field(&self.0).finish()
@@ -663,6 +676,7 @@ pub struct NVME_COMMAND_SET_IDENTIFIERS(pub i32);
 impl windows_core::TypeKind for NVME_COMMAND_SET_IDENTIFIERS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_COMMAND_SET_IDENTIFIERS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_COMMAND_SET_IDENTIFIERS").field(&self.0).finish()
@@ -674,6 +688,7 @@ pub struct NVME_CONTROLLER_METADATA_ELEMENT_TYPES(pub i32);
 impl windows_core::TypeKind for NVME_CONTROLLER_METADATA_ELEMENT_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_CONTROLLER_METADATA_ELEMENT_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_CONTROLLER_METADATA_ELEMENT_TYPES").field(&self.0).finish()
@@ -685,6 +700,7 @@ pub struct NVME_CSS_COMMAND_SETS(pub i32);
 impl windows_core::TypeKind for NVME_CSS_COMMAND_SETS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_CSS_COMMAND_SETS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_CSS_COMMAND_SETS").field(&self.0).finish()
@@ -696,6 +712,7 @@ pub struct NVME_CSTS_SHST_SHUTDOWN_STATUS(pub i32);
 impl windows_core::TypeKind for NVME_CSTS_SHST_SHUTDOWN_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_CSTS_SHST_SHUTDOWN_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_CSTS_SHST_SHUTDOWN_STATUS").field(&self.0).finish()
@@ -707,6 +724,7 @@ pub struct NVME_DIRECTIVE_RECEIVE_IDENTIFY_OPERATIONS(pub i32);
 impl windows_core::TypeKind for NVME_DIRECTIVE_RECEIVE_IDENTIFY_OPERATIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_DIRECTIVE_RECEIVE_IDENTIFY_OPERATIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_DIRECTIVE_RECEIVE_IDENTIFY_OPERATIONS").field(&self.0).finish()
@@ -718,6 +736,7 @@ pub struct NVME_DIRECTIVE_RECEIVE_STREAMS_OPERATIONS(pub i32);
 impl windows_core::TypeKind for NVME_DIRECTIVE_RECEIVE_STREAMS_OPERATIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_DIRECTIVE_RECEIVE_STREAMS_OPERATIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_DIRECTIVE_RECEIVE_STREAMS_OPERATIONS").field(&self.0).finish()
@@ -729,6 +748,7 @@ pub struct NVME_DIRECTIVE_SEND_IDENTIFY_OPERATIONS(pub i32);
 impl windows_core::TypeKind for NVME_DIRECTIVE_SEND_IDENTIFY_OPERATIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_DIRECTIVE_SEND_IDENTIFY_OPERATIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_DIRECTIVE_SEND_IDENTIFY_OPERATIONS").field(&self.0).finish()
@@ -740,6 +760,7 @@ pub struct NVME_DIRECTIVE_SEND_STREAMS_OPERATIONS(pub i32);
 impl windows_core::TypeKind for NVME_DIRECTIVE_SEND_STREAMS_OPERATIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_DIRECTIVE_SEND_STREAMS_OPERATIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_DIRECTIVE_SEND_STREAMS_OPERATIONS").field(&self.0).finish()
@@ -751,6 +772,7 @@ pub struct NVME_DIRECTIVE_TYPES(pub i32);
 impl windows_core::TypeKind for NVME_DIRECTIVE_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_DIRECTIVE_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_DIRECTIVE_TYPES").field(&self.0).finish()
@@ -762,6 +784,7 @@ pub struct NVME_ERROR_INJECTION_TYPES(pub i32);
 impl windows_core::TypeKind for NVME_ERROR_INJECTION_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_ERROR_INJECTION_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_ERROR_INJECTION_TYPES").field(&self.0).finish()
@@ -773,6 +796,7 @@ pub struct NVME_FEATURES(pub i32);
 impl windows_core::TypeKind for NVME_FEATURES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_FEATURES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_FEATURES").field(&self.0).finish()
@@ -784,6 +808,7 @@ pub struct NVME_FEATURE_VALUE_CODES(pub i32);
 impl windows_core::TypeKind for NVME_FEATURE_VALUE_CODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_FEATURE_VALUE_CODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_FEATURE_VALUE_CODES").field(&self.0).finish()
@@ -795,6 +820,7 @@ pub struct NVME_FIRMWARE_ACTIVATE_ACTIONS(pub i32);
 impl windows_core::TypeKind for NVME_FIRMWARE_ACTIVATE_ACTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_FIRMWARE_ACTIVATE_ACTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_FIRMWARE_ACTIVATE_ACTIONS").field(&self.0).finish()
@@ -806,6 +832,7 @@ pub struct NVME_FUSED_OPERATION_CODES(pub i32);
 impl windows_core::TypeKind for NVME_FUSED_OPERATION_CODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_FUSED_OPERATION_CODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_FUSED_OPERATION_CODES").field(&self.0).finish()
@@ -817,6 +844,7 @@ pub struct NVME_HOST_METADATA_ELEMENT_ACTIONS(pub i32);
 impl windows_core::TypeKind for NVME_HOST_METADATA_ELEMENT_ACTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_HOST_METADATA_ELEMENT_ACTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_HOST_METADATA_ELEMENT_ACTIONS").field(&self.0).finish()
@@ -828,6 +856,7 @@ pub struct NVME_IDENTIFIER_TYPE(pub i32);
 impl windows_core::TypeKind for NVME_IDENTIFIER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_IDENTIFIER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_IDENTIFIER_TYPE").field(&self.0).finish()
@@ -839,6 +868,7 @@ pub struct NVME_IDENTIFIER_TYPE_LENGTH(pub i32);
 impl windows_core::TypeKind for NVME_IDENTIFIER_TYPE_LENGTH {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_IDENTIFIER_TYPE_LENGTH {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_IDENTIFIER_TYPE_LENGTH").field(&self.0).finish()
@@ -850,6 +880,7 @@ pub struct NVME_IDENTIFY_CNS_CODES(pub i32);
 impl windows_core::TypeKind for NVME_IDENTIFY_CNS_CODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_IDENTIFY_CNS_CODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_IDENTIFY_CNS_CODES").field(&self.0).finish()
@@ -861,6 +892,7 @@ pub struct NVME_LBA_RANGE_TYPES(pub i32);
 impl windows_core::TypeKind for NVME_LBA_RANGE_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_LBA_RANGE_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_LBA_RANGE_TYPES").field(&self.0).finish()
@@ -872,6 +904,7 @@ pub struct NVME_LOG_PAGES(pub i32);
 impl windows_core::TypeKind for NVME_LOG_PAGES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_LOG_PAGES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_LOG_PAGES").field(&self.0).finish()
@@ -883,6 +916,7 @@ pub struct NVME_NAMESPACE_METADATA_ELEMENT_TYPES(pub i32);
 impl windows_core::TypeKind for NVME_NAMESPACE_METADATA_ELEMENT_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_NAMESPACE_METADATA_ELEMENT_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_NAMESPACE_METADATA_ELEMENT_TYPES").field(&self.0).finish()
@@ -894,6 +928,7 @@ pub struct NVME_NO_DEALLOCATE_MODIFIES_MEDIA_AFTER_SANITIZE(pub i32);
 impl windows_core::TypeKind for NVME_NO_DEALLOCATE_MODIFIES_MEDIA_AFTER_SANITIZE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_NO_DEALLOCATE_MODIFIES_MEDIA_AFTER_SANITIZE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_NO_DEALLOCATE_MODIFIES_MEDIA_AFTER_SANITIZE").field(&self.0).finish()
@@ -905,6 +940,7 @@ pub struct NVME_NVM_COMMANDS(pub i32);
 impl windows_core::TypeKind for NVME_NVM_COMMANDS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_NVM_COMMANDS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_NVM_COMMANDS").field(&self.0).finish()
@@ -916,6 +952,7 @@ pub struct NVME_NVM_QUEUE_PRIORITIES(pub i32);
 impl windows_core::TypeKind for NVME_NVM_QUEUE_PRIORITIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_NVM_QUEUE_PRIORITIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_NVM_QUEUE_PRIORITIES").field(&self.0).finish()
@@ -927,6 +964,7 @@ pub struct NVME_PERSISTENT_EVENT_LOG_EVENT_TYPES(pub i32);
 impl windows_core::TypeKind for NVME_PERSISTENT_EVENT_LOG_EVENT_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_PERSISTENT_EVENT_LOG_EVENT_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_PERSISTENT_EVENT_LOG_EVENT_TYPES").field(&self.0).finish()
@@ -938,6 +976,7 @@ pub struct NVME_PROTECTION_INFORMATION_TYPES(pub i32);
 impl windows_core::TypeKind for NVME_PROTECTION_INFORMATION_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_PROTECTION_INFORMATION_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_PROTECTION_INFORMATION_TYPES").field(&self.0).finish()
@@ -949,6 +988,7 @@ pub struct NVME_RESERVATION_ACQUIRE_ACTIONS(pub i32);
 impl windows_core::TypeKind for NVME_RESERVATION_ACQUIRE_ACTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_RESERVATION_ACQUIRE_ACTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_RESERVATION_ACQUIRE_ACTIONS").field(&self.0).finish()
@@ -960,6 +1000,7 @@ pub struct NVME_RESERVATION_NOTIFICATION_TYPES(pub i32);
 impl windows_core::TypeKind for NVME_RESERVATION_NOTIFICATION_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_RESERVATION_NOTIFICATION_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_RESERVATION_NOTIFICATION_TYPES").field(&self.0).finish()
@@ -971,6 +1012,7 @@ pub struct NVME_RESERVATION_REGISTER_ACTIONS(pub i32);
 impl windows_core::TypeKind for NVME_RESERVATION_REGISTER_ACTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_RESERVATION_REGISTER_ACTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_RESERVATION_REGISTER_ACTIONS").field(&self.0).finish()
@@ -982,6 +1024,7 @@ pub struct NVME_RESERVATION_REGISTER_PTPL_STATE_CHANGES(pub i32);
 impl windows_core::TypeKind for NVME_RESERVATION_REGISTER_PTPL_STATE_CHANGES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_RESERVATION_REGISTER_PTPL_STATE_CHANGES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_RESERVATION_REGISTER_PTPL_STATE_CHANGES").field(&self.0).finish()
@@ -993,6 +1036,7 @@ pub struct NVME_RESERVATION_RELEASE_ACTIONS(pub i32);
 impl windows_core::TypeKind for NVME_RESERVATION_RELEASE_ACTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_RESERVATION_RELEASE_ACTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_RESERVATION_RELEASE_ACTIONS").field(&self.0).finish()
@@ -1004,6 +1048,7 @@ pub struct NVME_RESERVATION_TYPES(pub i32);
 impl windows_core::TypeKind for NVME_RESERVATION_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_RESERVATION_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_RESERVATION_TYPES").field(&self.0).finish()
@@ -1015,6 +1060,7 @@ pub struct NVME_SANITIZE_ACTION(pub i32);
 impl windows_core::TypeKind for NVME_SANITIZE_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_SANITIZE_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_SANITIZE_ACTION").field(&self.0).finish()
@@ -1026,6 +1072,7 @@ pub struct NVME_SANITIZE_OPERATION_STATUS(pub i32);
 impl windows_core::TypeKind for NVME_SANITIZE_OPERATION_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_SANITIZE_OPERATION_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_SANITIZE_OPERATION_STATUS").field(&self.0).finish()
@@ -1037,6 +1084,7 @@ pub struct NVME_SECURE_ERASE_SETTINGS(pub i32);
 impl windows_core::TypeKind for NVME_SECURE_ERASE_SETTINGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_SECURE_ERASE_SETTINGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_SECURE_ERASE_SETTINGS").field(&self.0).finish()
@@ -1048,6 +1096,7 @@ pub struct NVME_STATUS_COMMAND_SPECIFIC_CODES(pub i32);
 impl windows_core::TypeKind for NVME_STATUS_COMMAND_SPECIFIC_CODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_STATUS_COMMAND_SPECIFIC_CODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_STATUS_COMMAND_SPECIFIC_CODES").field(&self.0).finish()
@@ -1059,6 +1108,7 @@ pub struct NVME_STATUS_GENERIC_COMMAND_CODES(pub i32);
 impl windows_core::TypeKind for NVME_STATUS_GENERIC_COMMAND_CODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_STATUS_GENERIC_COMMAND_CODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_STATUS_GENERIC_COMMAND_CODES").field(&self.0).finish()
@@ -1070,6 +1120,7 @@ pub struct NVME_STATUS_MEDIA_ERROR_CODES(pub i32);
 impl windows_core::TypeKind for NVME_STATUS_MEDIA_ERROR_CODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_STATUS_MEDIA_ERROR_CODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_STATUS_MEDIA_ERROR_CODES").field(&self.0).finish()
@@ -1081,6 +1132,7 @@ pub struct NVME_STATUS_TYPES(pub i32);
 impl windows_core::TypeKind for NVME_STATUS_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_STATUS_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_STATUS_TYPES").field(&self.0).finish()
@@ -1092,6 +1144,7 @@ pub struct NVME_TEMPERATURE_THRESHOLD_TYPES(pub i32);
 impl windows_core::TypeKind for NVME_TEMPERATURE_THRESHOLD_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_TEMPERATURE_THRESHOLD_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_TEMPERATURE_THRESHOLD_TYPES").field(&self.0).finish()
@@ -1103,6 +1156,7 @@ pub struct NVME_VENDOR_LOG_PAGES(pub i32);
 impl windows_core::TypeKind for NVME_VENDOR_LOG_PAGES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_VENDOR_LOG_PAGES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_VENDOR_LOG_PAGES").field(&self.0).finish()
@@ -1114,6 +1168,7 @@ pub struct NVME_WCS_DEVICE_RECOVERY_ACTION1(pub i32);
 impl windows_core::TypeKind for NVME_WCS_DEVICE_RECOVERY_ACTION1 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_WCS_DEVICE_RECOVERY_ACTION1 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_WCS_DEVICE_RECOVERY_ACTION1").field(&self.0).finish()
@@ -1125,6 +1180,7 @@ pub struct NVME_WCS_DEVICE_RECOVERY_ACTION2(pub i32);
 impl windows_core::TypeKind for NVME_WCS_DEVICE_RECOVERY_ACTION2 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_WCS_DEVICE_RECOVERY_ACTION2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_WCS_DEVICE_RECOVERY_ACTION2").field(&self.0).finish()
@@ -1136,6 +1192,7 @@ pub struct NVME_ZONE_RECEIVE_ACTION(pub i32);
 impl windows_core::TypeKind for NVME_ZONE_RECEIVE_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_ZONE_RECEIVE_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_ZONE_RECEIVE_ACTION").field(&self.0).finish()
@@ -1147,6 +1204,7 @@ pub struct NVME_ZONE_RECEIVE_ACTION_SPECIFIC(pub i32);
 impl windows_core::TypeKind for NVME_ZONE_RECEIVE_ACTION_SPECIFIC {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_ZONE_RECEIVE_ACTION_SPECIFIC {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_ZONE_RECEIVE_ACTION_SPECIFIC").field(&self.0).finish()
@@ -1158,6 +1216,7 @@ pub struct NVME_ZONE_SEND_ACTION(pub i32);
 impl windows_core::TypeKind for NVME_ZONE_SEND_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NVME_ZONE_SEND_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NVME_ZONE_SEND_ACTION").field(&self.0).finish()
@@ -1169,6 +1228,7 @@ pub struct ZONE_STATE(pub i32);
 impl windows_core::TypeKind for ZONE_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ZONE_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ZONE_STATE").field(&self.0).finish()
@@ -1206,6 +1266,86 @@ impl Default for ACTIVE_LATENCY_CONFIGURATION_0 {
 pub struct ACTIVE_LATENCY_CONFIGURATION_0_0 {
     pub _bitfield: u16,
 }
+impl ACTIVE_LATENCY_CONFIGURATION_0_0 {
+    pub const fn Read0(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_Read0(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Write0(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Write0(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Trim0(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Trim0(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Read1(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_Read1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Write1(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_Write1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Trim1(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_Trim1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Read2(&self) -> u16 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_Read2(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Write2(&self) -> u16 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Write2(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn Trim2(&self) -> u16 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_Trim2(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn Read3(&self) -> u16 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_Read3(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn Write3(&self) -> u16 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_Write3(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn Trim3(&self) -> u16 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_Trim3(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 12) & 15
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(15 << 12)) | ((value & 15) << 12);
+    }
+}
 impl windows_core::TypeKind for ACTIVE_LATENCY_CONFIGURATION_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -1235,6 +1375,86 @@ impl Default for BUCKET_COUNTER {
 pub struct DEBUG_BIT_FIELD {
     pub _bitfield: u16,
 }
+impl DEBUG_BIT_FIELD {
+    pub const fn Read0(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_Read0(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Write0(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Write0(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Trim0(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Trim0(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Read1(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_Read1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Write1(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_Write1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Trim1(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_Trim1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Read2(&self) -> u16 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_Read2(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Write2(&self) -> u16 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Write2(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn Trim2(&self) -> u16 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_Trim2(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn Read3(&self) -> u16 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_Read3(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn Write3(&self) -> u16 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_Write3(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn Trim3(&self) -> u16 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_Trim3(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 12) & 15
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(15 << 12)) | ((value & 15) << 12);
+    }
+}
 impl windows_core::TypeKind for DEBUG_BIT_FIELD {
     type TypeKind = windows_core::CopyType;
 }
@@ -1244,10 +1464,31 @@ impl Default for DEBUG_BIT_FIELD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DSSD_POWER_STATE_DESCRIPTOR {
     pub _bitfield: u8,
 }
+impl DSSD_POWER_STATE_DESCRIPTOR {
+    pub const fn NvmePowerState(&self) -> u8 {
+        self._bitfield & 31
+    }
+    pub fn set_NvmePowerState(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !31) | (value & 31);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 5) & 3
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(3 << 5)) | ((value & 3) << 5);
+    }
+    pub const fn ValidDSSDPowerState(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_ValidDSSDPowerState(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for DSSD_POWER_STATE_DESCRIPTOR {
     type TypeKind = windows_core::CopyType;
 }
@@ -1309,10 +1550,37 @@ impl Default for LATENCY_MONITOR_FEATURE_STATUS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LATENCY_MONITOR_FEATURE_STATUS_0_0 {
     pub _bitfield: u8,
 }
+impl LATENCY_MONITOR_FEATURE_STATUS_0_0 {
+    pub const fn FeatureEnabled(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_FeatureEnabled(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ActiveLatencyMode(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_ActiveLatencyMode(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ActiveMeasuredLatency(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_ActiveMeasuredLatency(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 3) & 31
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(31 << 3)) | ((value & 31) << 3);
+    }
+}
 impl windows_core::TypeKind for LATENCY_MONITOR_FEATURE_STATUS_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -1350,6 +1618,86 @@ impl Default for LATENCY_STAMP {
 pub struct LATENCY_STAMP_UNITS {
     pub _bitfield: u16,
 }
+impl LATENCY_STAMP_UNITS {
+    pub const fn Read0(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_Read0(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Write0(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Write0(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Trim0(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Trim0(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Read1(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_Read1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Write1(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_Write1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Trim1(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_Trim1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Read2(&self) -> u16 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_Read2(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Write2(&self) -> u16 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Write2(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn Trim2(&self) -> u16 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_Trim2(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn Read3(&self) -> u16 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_Read3(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn Write3(&self) -> u16 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_Write3(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn Trim3(&self) -> u16 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_Trim3(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 12) & 15
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(15 << 12)) | ((value & 15) << 12);
+    }
+}
 impl windows_core::TypeKind for LATENCY_STAMP_UNITS {
     type TypeKind = windows_core::CopyType;
 }
@@ -1383,7 +1731,8 @@ impl Default for MEASURED_LATENCY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_ACTIVE_NAMESPACE_ID_LIST {
     pub NSID: [u32; 1024],
 }
@@ -1410,10 +1759,25 @@ impl Default for NVME_ADMIN_COMPLETION_QUEUE_BASE_ADDRESS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_ADMIN_COMPLETION_QUEUE_BASE_ADDRESS_0 {
     pub _bitfield: u64,
 }
+impl NVME_ADMIN_COMPLETION_QUEUE_BASE_ADDRESS_0 {
+    pub const fn Reserved0(&self) -> u64 {
+        self._bitfield & 4095
+    }
+    pub fn set_Reserved0(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !4095) | (value & 4095);
+    }
+    pub const fn ACQB(&self) -> u64 {
+        (self._bitfield >> 12) & 4503599627370495
+    }
+    pub fn set_ACQB(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(4503599627370495 << 12)) | ((value & 4503599627370495) << 12);
+    }
+}
 impl windows_core::TypeKind for NVME_ADMIN_COMPLETION_QUEUE_BASE_ADDRESS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -1437,10 +1801,37 @@ impl Default for NVME_ADMIN_QUEUE_ATTRIBUTES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_ADMIN_QUEUE_ATTRIBUTES_0 {
     pub _bitfield: u32,
 }
+impl NVME_ADMIN_QUEUE_ATTRIBUTES_0 {
+    pub const fn ASQS(&self) -> u32 {
+        self._bitfield & 4095
+    }
+    pub fn set_ASQS(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !4095) | (value & 4095);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 12) & 15
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 12)) | ((value & 15) << 12);
+    }
+    pub const fn ACQS(&self) -> u32 {
+        (self._bitfield >> 16) & 4095
+    }
+    pub fn set_ACQS(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(4095 << 16)) | ((value & 4095) << 16);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 28) & 15
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 28)) | ((value & 15) << 28);
+    }
+}
 impl windows_core::TypeKind for NVME_ADMIN_QUEUE_ATTRIBUTES_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -1464,10 +1855,25 @@ impl Default for NVME_ADMIN_SUBMISSION_QUEUE_BASE_ADDRESS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_ADMIN_SUBMISSION_QUEUE_BASE_ADDRESS_0 {
     pub _bitfield: u64,
 }
+impl NVME_ADMIN_SUBMISSION_QUEUE_BASE_ADDRESS_0 {
+    pub const fn Reserved0(&self) -> u64 {
+        self._bitfield & 4095
+    }
+    pub fn set_Reserved0(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !4095) | (value & 4095);
+    }
+    pub const fn ASQB(&self) -> u64 {
+        (self._bitfield >> 12) & 4503599627370495
+    }
+    pub fn set_ASQB(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(4503599627370495 << 12)) | ((value & 4503599627370495) << 12);
+    }
+}
 impl windows_core::TypeKind for NVME_ADMIN_SUBMISSION_QUEUE_BASE_ADDRESS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -1477,11 +1883,32 @@ impl Default for NVME_ADMIN_SUBMISSION_QUEUE_BASE_ADDRESS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_AUTO_POWER_STATE_TRANSITION_ENTRY {
     pub _bitfield: u32,
     pub Reserved1: u32,
 }
+impl NVME_AUTO_POWER_STATE_TRANSITION_ENTRY {
+    pub const fn Reserved0(&self) -> u32 {
+        self._bitfield & 7
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn IdleTransitionPowerState(&self) -> u32 {
+        (self._bitfield >> 3) & 31
+    }
+    pub fn set_IdleTransitionPowerState(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 3)) | ((value & 31) << 3);
+    }
+    pub const fn IdleTimePriorToTransition(&self) -> u32 {
+        (self._bitfield >> 8) & 16777215
+    }
+    pub fn set_IdleTimePriorToTransition(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(16777215 << 8)) | ((value & 16777215) << 8);
+    }
+}
 impl windows_core::TypeKind for NVME_AUTO_POWER_STATE_TRANSITION_ENTRY {
     type TypeKind = windows_core::CopyType;
 }
@@ -1505,10 +1932,25 @@ impl Default for NVME_CDW0_FEATURE_ENABLE_IEEE1667_SILO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW0_FEATURE_ENABLE_IEEE1667_SILO_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW0_FEATURE_ENABLE_IEEE1667_SILO_0 {
+    pub const fn Enabled(&self) -> u32 {
+        self._bitfield & 7
+    }
+    pub fn set_Enabled(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 3) & 536870911
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(536870911 << 3)) | ((value & 536870911) << 3);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW0_FEATURE_ENABLE_IEEE1667_SILO_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -1532,10 +1974,25 @@ impl Default for NVME_CDW0_FEATURE_ERROR_INJECTION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW0_FEATURE_ERROR_INJECTION_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW0_FEATURE_ERROR_INJECTION_0 {
+    pub const fn NUM(&self) -> u32 {
+        self._bitfield & 127
+    }
+    pub fn set_NUM(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !127) | (value & 127);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 7) & 33554431
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(33554431 << 7)) | ((value & 33554431) << 7);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW0_FEATURE_ERROR_INJECTION_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -1559,10 +2016,25 @@ impl Default for NVME_CDW0_FEATURE_READONLY_WRITETHROUGH_MODE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW0_FEATURE_READONLY_WRITETHROUGH_MODE_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW0_FEATURE_READONLY_WRITETHROUGH_MODE_0 {
+    pub const fn EOLBehavior(&self) -> u32 {
+        self._bitfield & 7
+    }
+    pub fn set_EOLBehavior(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 3) & 536870911
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(536870911 << 3)) | ((value & 536870911) << 3);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW0_FEATURE_READONLY_WRITETHROUGH_MODE_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -1572,10 +2044,25 @@ impl Default for NVME_CDW0_FEATURE_READONLY_WRITETHROUGH_MODE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW0_RESERVATION_PERSISTENCE {
     pub _bitfield: u32,
 }
+impl NVME_CDW0_RESERVATION_PERSISTENCE {
+    pub const fn PTPL(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_PTPL(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW0_RESERVATION_PERSISTENCE {
     type TypeKind = windows_core::CopyType;
 }
@@ -1599,10 +2086,25 @@ impl Default for NVME_CDW10_ABORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW10_ABORT_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW10_ABORT_0 {
+    pub const fn SQID(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_SQID(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn CID(&self) -> u32 {
+        (self._bitfield >> 8) & 65535
+    }
+    pub fn set_CID(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 8)) | ((value & 65535) << 8);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW10_ABORT_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -1626,10 +2128,25 @@ impl Default for NVME_CDW10_CREATE_IO_QUEUE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW10_CREATE_IO_QUEUE_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW10_CREATE_IO_QUEUE_0 {
+    pub const fn QID(&self) -> u32 {
+        self._bitfield & 65535
+    }
+    pub fn set_QID(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn QSIZE(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_QSIZE(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW10_CREATE_IO_QUEUE_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -1653,10 +2170,25 @@ impl Default for NVME_CDW10_DATASET_MANAGEMENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW10_DATASET_MANAGEMENT_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW10_DATASET_MANAGEMENT_0 {
+    pub const fn NR(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_NR(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 8) & 16777215
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(16777215 << 8)) | ((value & 16777215) << 8);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW10_DATASET_MANAGEMENT_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -1666,7 +2198,8 @@ impl Default for NVME_CDW10_DATASET_MANAGEMENT_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW10_DIRECTIVE_RECEIVE {
     pub NUMD: u32,
 }
@@ -1679,7 +2212,8 @@ impl Default for NVME_CDW10_DIRECTIVE_RECEIVE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW10_DIRECTIVE_SEND {
     pub NUMD: u32,
 }
@@ -1706,10 +2240,31 @@ impl Default for NVME_CDW10_FIRMWARE_ACTIVATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW10_FIRMWARE_ACTIVATE_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW10_FIRMWARE_ACTIVATE_0 {
+    pub const fn FS(&self) -> u32 {
+        self._bitfield & 7
+    }
+    pub fn set_FS(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn AA(&self) -> u32 {
+        (self._bitfield >> 3) & 3
+    }
+    pub fn set_AA(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 3)) | ((value & 3) << 3);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 5) & 134217727
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(134217727 << 5)) | ((value & 134217727) << 5);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW10_FIRMWARE_ACTIVATE_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -1719,7 +2274,8 @@ impl Default for NVME_CDW10_FIRMWARE_ACTIVATE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW10_FIRMWARE_DOWNLOAD {
     pub NUMD: u32,
 }
@@ -1746,10 +2302,55 @@ impl Default for NVME_CDW10_FORMAT_NVM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW10_FORMAT_NVM_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW10_FORMAT_NVM_0 {
+    pub const fn LBAF(&self) -> u32 {
+        self._bitfield & 15
+    }
+    pub fn set_LBAF(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn MS(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_MS(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn PI(&self) -> u32 {
+        (self._bitfield >> 5) & 7
+    }
+    pub fn set_PI(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 5)) | ((value & 7) << 5);
+    }
+    pub const fn PIL(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_PIL(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn SES(&self) -> u32 {
+        (self._bitfield >> 9) & 7
+    }
+    pub fn set_SES(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 9)) | ((value & 7) << 9);
+    }
+    pub const fn ZF(&self) -> u32 {
+        (self._bitfield >> 12) & 3
+    }
+    pub fn set_ZF(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 12)) | ((value & 3) << 12);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 14) & 262143
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(262143 << 14)) | ((value & 262143) << 14);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW10_FORMAT_NVM_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -1773,10 +2374,31 @@ impl Default for NVME_CDW10_GET_FEATURES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW10_GET_FEATURES_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW10_GET_FEATURES_0 {
+    pub const fn FID(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_FID(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn SEL(&self) -> u32 {
+        (self._bitfield >> 8) & 7
+    }
+    pub fn set_SEL(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 8)) | ((value & 7) << 8);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 11) & 2097151
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2097151 << 11)) | ((value & 2097151) << 11);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW10_GET_FEATURES_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -1800,10 +2422,37 @@ impl Default for NVME_CDW10_GET_LOG_PAGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW10_GET_LOG_PAGE_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW10_GET_LOG_PAGE_0 {
+    pub const fn LID(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_LID(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn NUMD(&self) -> u32 {
+        (self._bitfield >> 16) & 4095
+    }
+    pub fn set_NUMD(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(4095 << 16)) | ((value & 4095) << 16);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 28) & 15
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 28)) | ((value & 15) << 28);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW10_GET_LOG_PAGE_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -1827,10 +2476,43 @@ impl Default for NVME_CDW10_GET_LOG_PAGE_V13 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW10_GET_LOG_PAGE_V13_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW10_GET_LOG_PAGE_V13_0 {
+    pub const fn LID(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_LID(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn LSP(&self) -> u32 {
+        (self._bitfield >> 8) & 15
+    }
+    pub fn set_LSP(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 8)) | ((value & 15) << 8);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 12) & 7
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 12)) | ((value & 7) << 12);
+    }
+    pub const fn RAE(&self) -> u32 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_RAE(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+    pub const fn NUMDL(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_NUMDL(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW10_GET_LOG_PAGE_V13_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -1854,10 +2536,31 @@ impl Default for NVME_CDW10_IDENTIFY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW10_IDENTIFY_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW10_IDENTIFY_0 {
+    pub const fn CNS(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_CNS(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn CNTID(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_CNTID(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW10_IDENTIFY_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -1881,10 +2584,43 @@ impl Default for NVME_CDW10_RESERVATION_ACQUIRE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW10_RESERVATION_ACQUIRE_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW10_RESERVATION_ACQUIRE_0 {
+    pub const fn RACQA(&self) -> u32 {
+        self._bitfield & 7
+    }
+    pub fn set_RACQA(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn IEKEY(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_IEKEY(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+    pub const fn RTYPE(&self) -> u32 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_RTYPE(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW10_RESERVATION_ACQUIRE_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -1908,10 +2644,37 @@ impl Default for NVME_CDW10_RESERVATION_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW10_RESERVATION_REGISTER_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW10_RESERVATION_REGISTER_0 {
+    pub const fn RREGA(&self) -> u32 {
+        self._bitfield & 7
+    }
+    pub fn set_RREGA(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn IEKEY(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_IEKEY(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 4) & 67108863
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(67108863 << 4)) | ((value & 67108863) << 4);
+    }
+    pub const fn CPTPL(&self) -> u32 {
+        (self._bitfield >> 30) & 3
+    }
+    pub fn set_CPTPL(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 30)) | ((value & 3) << 30);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW10_RESERVATION_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -1935,10 +2698,43 @@ impl Default for NVME_CDW10_RESERVATION_RELEASE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW10_RESERVATION_RELEASE_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW10_RESERVATION_RELEASE_0 {
+    pub const fn RRELA(&self) -> u32 {
+        self._bitfield & 7
+    }
+    pub fn set_RRELA(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn IEKEY(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_IEKEY(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+    pub const fn RTYPE(&self) -> u32 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_RTYPE(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW10_RESERVATION_RELEASE_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -1962,7 +2758,8 @@ impl Default for NVME_CDW10_RESERVATION_REPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW10_RESERVATION_REPORT_0 {
     pub NUMD: u32,
 }
@@ -1989,22 +2786,61 @@ impl Default for NVME_CDW10_SANITIZE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW10_SANITIZE_0 {
     pub _bitfield: u32,
 }
-impl windows_core::TypeKind for NVME_CDW10_SANITIZE_0 {
-    type TypeKind = windows_core::CopyType;
-}
-impl Default for NVME_CDW10_SANITIZE_0 {
-    fn default() -> Self {
-        unsafe { core::mem::zeroed() }
+impl NVME_CDW10_SANITIZE_0 {
+    pub const fn SANACT(&self) -> u32 {
+        self._bitfield & 7
     }
-}
-#[repr(C)]
-#[derive(Clone, Copy)]
-pub union NVME_CDW10_SECURITY_SEND_RECEIVE {
-    pub Anonymous: NVME_CDW10_SECURITY_SEND_RECEIVE_0,
+    pub fn set_SANACT(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn AUSE(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_AUSE(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn OWPASS(&self) -> u32 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_OWPASS(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+    pub const fn OIPBP(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_OIPBP(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn NDAS(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_NDAS(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 10) & 4194303
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(4194303 << 10)) | ((value & 4194303) << 10);
+    }
+}
+impl windows_core::TypeKind for NVME_CDW10_SANITIZE_0 {
+    type TypeKind = windows_core::CopyType;
+}
+impl Default for NVME_CDW10_SANITIZE_0 {
+    fn default() -> Self {
+        unsafe { core::mem::zeroed() }
+    }
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union NVME_CDW10_SECURITY_SEND_RECEIVE {
+    pub Anonymous: NVME_CDW10_SECURITY_SEND_RECEIVE_0,
     pub AsUlong: u32,
 }
 impl windows_core::TypeKind for NVME_CDW10_SECURITY_SEND_RECEIVE {
@@ -2016,10 +2852,31 @@ impl Default for NVME_CDW10_SECURITY_SEND_RECEIVE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW10_SECURITY_SEND_RECEIVE_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW10_SECURITY_SEND_RECEIVE_0 {
+    pub const fn Reserved0(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn SPSP(&self) -> u32 {
+        (self._bitfield >> 8) & 65535
+    }
+    pub fn set_SPSP(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 8)) | ((value & 65535) << 8);
+    }
+    pub const fn SECP(&self) -> u32 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_SECP(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW10_SECURITY_SEND_RECEIVE_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2043,10 +2900,31 @@ impl Default for NVME_CDW10_SET_FEATURES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW10_SET_FEATURES_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW10_SET_FEATURES_0 {
+    pub const fn FID(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_FID(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 8) & 8388607
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(8388607 << 8)) | ((value & 8388607) << 8);
+    }
+    pub const fn SV(&self) -> u32 {
+        (self._bitfield >> 31) & 1
+    }
+    pub fn set_SV(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 31)) | ((value & 1) << 31);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW10_SET_FEATURES_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2056,7 +2934,8 @@ impl Default for NVME_CDW10_SET_FEATURES_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW10_ZONE_APPEND {
     pub SLBA: u64,
 }
@@ -2069,7 +2948,8 @@ impl Default for NVME_CDW10_ZONE_APPEND {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW10_ZONE_MANAGEMENT_RECEIVE {
     pub SLBA: u64,
 }
@@ -2082,7 +2962,8 @@ impl Default for NVME_CDW10_ZONE_MANAGEMENT_RECEIVE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW10_ZONE_MANAGEMENT_SEND {
     pub SLBA: u64,
 }
@@ -2109,10 +2990,37 @@ impl Default for NVME_CDW11_CREATE_IO_CQ {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_CREATE_IO_CQ_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_CREATE_IO_CQ_0 {
+    pub const fn PC(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_PC(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn IEN(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_IEN(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 2) & 16383
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(16383 << 2)) | ((value & 16383) << 2);
+    }
+    pub const fn IV(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_IV(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_CREATE_IO_CQ_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2136,10 +3044,37 @@ impl Default for NVME_CDW11_CREATE_IO_SQ {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_CREATE_IO_SQ_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_CREATE_IO_SQ_0 {
+    pub const fn PC(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_PC(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn QPRIO(&self) -> u32 {
+        (self._bitfield >> 1) & 3
+    }
+    pub fn set_QPRIO(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 1)) | ((value & 3) << 1);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 3) & 8191
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(8191 << 3)) | ((value & 8191) << 3);
+    }
+    pub const fn CQID(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_CQID(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_CREATE_IO_SQ_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2163,10 +3098,37 @@ impl Default for NVME_CDW11_DATASET_MANAGEMENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_DATASET_MANAGEMENT_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_DATASET_MANAGEMENT_0 {
+    pub const fn IDR(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_IDR(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn IDW(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_IDW(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn AD(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_AD(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 3) & 536870911
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(536870911 << 3)) | ((value & 536870911) << 3);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_DATASET_MANAGEMENT_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2190,10 +3152,31 @@ impl Default for NVME_CDW11_DIRECTIVE_RECEIVE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_DIRECTIVE_RECEIVE_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_DIRECTIVE_RECEIVE_0 {
+    pub const fn DOPER(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_DOPER(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn DTYPE(&self) -> u32 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_DTYPE(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn DSPEC(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_DSPEC(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_DIRECTIVE_RECEIVE_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2217,10 +3200,31 @@ impl Default for NVME_CDW11_DIRECTIVE_SEND {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_DIRECTIVE_SEND_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_DIRECTIVE_SEND_0 {
+    pub const fn DOPER(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_DOPER(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn DTYPE(&self) -> u32 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_DTYPE(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn DSPEC(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_DSPEC(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_DIRECTIVE_SEND_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2278,10 +3282,43 @@ impl Default for NVME_CDW11_FEATURE_ARBITRATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_FEATURE_ARBITRATION_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_FEATURE_ARBITRATION_0 {
+    pub const fn AB(&self) -> u32 {
+        self._bitfield & 7
+    }
+    pub fn set_AB(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 3) & 31
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 3)) | ((value & 31) << 3);
+    }
+    pub const fn LPW(&self) -> u32 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_LPW(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn MPW(&self) -> u32 {
+        (self._bitfield >> 16) & 255
+    }
+    pub fn set_MPW(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 16)) | ((value & 255) << 16);
+    }
+    pub const fn HPW(&self) -> u32 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_HPW(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_FEATURE_ARBITRATION_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2305,10 +3342,79 @@ impl Default for NVME_CDW11_FEATURE_ASYNC_EVENT_CONFIG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_FEATURE_ASYNC_EVENT_CONFIG_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_FEATURE_ASYNC_EVENT_CONFIG_0 {
+    pub const fn CriticalWarnings(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_CriticalWarnings(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn NsAttributeNotices(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_NsAttributeNotices(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn FwActivationNotices(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_FwActivationNotices(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn TelemetryLogNotices(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_TelemetryLogNotices(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn ANAChangeNotices(&self) -> u32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_ANAChangeNotices(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn PredictableLogChangeNotices(&self) -> u32 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_PredictableLogChangeNotices(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn LBAStatusNotices(&self) -> u32 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_LBAStatusNotices(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn EnduranceEventNotices(&self) -> u32 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_EnduranceEventNotices(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 15) & 4095
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(4095 << 15)) | ((value & 4095) << 15);
+    }
+    pub const fn ZoneDescriptorNotices(&self) -> u32 {
+        (self._bitfield >> 27) & 1
+    }
+    pub fn set_ZoneDescriptorNotices(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 27)) | ((value & 1) << 27);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 28) & 15
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 28)) | ((value & 15) << 28);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_FEATURE_ASYNC_EVENT_CONFIG_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2332,10 +3438,25 @@ impl Default for NVME_CDW11_FEATURE_AUTO_POWER_STATE_TRANSITION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_FEATURE_AUTO_POWER_STATE_TRANSITION_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_FEATURE_AUTO_POWER_STATE_TRANSITION_0 {
+    pub const fn APSTE(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_APSTE(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_FEATURE_AUTO_POWER_STATE_TRANSITION_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2359,10 +3480,25 @@ impl Default for NVME_CDW11_FEATURE_CLEAR_FW_UPDATE_HISTORY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_FEATURE_CLEAR_FW_UPDATE_HISTORY_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_FEATURE_CLEAR_FW_UPDATE_HISTORY_0 {
+    pub const fn Reserved0(&self) -> u32 {
+        self._bitfield & 2147483647
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !2147483647) | (value & 2147483647);
+    }
+    pub const fn Clear(&self) -> u32 {
+        (self._bitfield >> 31) & 1
+    }
+    pub fn set_Clear(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 31)) | ((value & 1) << 31);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_FEATURE_CLEAR_FW_UPDATE_HISTORY_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2386,10 +3522,25 @@ impl Default for NVME_CDW11_FEATURE_CLEAR_PCIE_CORRECTABLE_ERROR_COUNTERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_FEATURE_CLEAR_PCIE_CORRECTABLE_ERROR_COUNTERS_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_FEATURE_CLEAR_PCIE_CORRECTABLE_ERROR_COUNTERS_0 {
+    pub const fn Reserved0(&self) -> u32 {
+        self._bitfield & 2147483647
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !2147483647) | (value & 2147483647);
+    }
+    pub const fn Clear(&self) -> u32 {
+        (self._bitfield >> 31) & 1
+    }
+    pub fn set_Clear(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 31)) | ((value & 1) << 31);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_FEATURE_CLEAR_PCIE_CORRECTABLE_ERROR_COUNTERS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2413,10 +3564,25 @@ impl Default for NVME_CDW11_FEATURE_ENABLE_IEEE1667_SILO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_FEATURE_ENABLE_IEEE1667_SILO_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_FEATURE_ENABLE_IEEE1667_SILO_0 {
+    pub const fn Reserved0(&self) -> u32 {
+        self._bitfield & 2147483647
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !2147483647) | (value & 2147483647);
+    }
+    pub const fn Enable(&self) -> u32 {
+        (self._bitfield >> 31) & 1
+    }
+    pub fn set_Enable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 31)) | ((value & 1) << 31);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_FEATURE_ENABLE_IEEE1667_SILO_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2440,10 +3606,31 @@ impl Default for NVME_CDW11_FEATURE_ERROR_RECOVERY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_FEATURE_ERROR_RECOVERY_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_FEATURE_ERROR_RECOVERY_0 {
+    pub const fn TLER(&self) -> u32 {
+        self._bitfield & 65535
+    }
+    pub fn set_TLER(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn DULBE(&self) -> u32 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_DULBE(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 17) & 32767
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(32767 << 17)) | ((value & 32767) << 17);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_FEATURE_ERROR_RECOVERY_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2467,10 +3654,25 @@ impl Default for NVME_CDW11_FEATURE_GET_HOST_METADATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_FEATURE_GET_HOST_METADATA_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_FEATURE_GET_HOST_METADATA_0 {
+    pub const fn GDHM(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_GDHM(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_FEATURE_GET_HOST_METADATA_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2480,10 +3682,25 @@ impl Default for NVME_CDW11_FEATURE_GET_HOST_METADATA_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_FEATURE_HOST_IDENTIFIER {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_FEATURE_HOST_IDENTIFIER {
+    pub const fn EXHID(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_EXHID(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_FEATURE_HOST_IDENTIFIER {
     type TypeKind = windows_core::CopyType;
 }
@@ -2507,10 +3724,31 @@ impl Default for NVME_CDW11_FEATURE_HOST_MEMORY_BUFFER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_FEATURE_HOST_MEMORY_BUFFER_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_FEATURE_HOST_MEMORY_BUFFER_0 {
+    pub const fn EHM(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_EHM(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn MR(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_MR(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 2) & 1073741823
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1073741823 << 2)) | ((value & 1073741823) << 2);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_FEATURE_HOST_MEMORY_BUFFER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2534,10 +3772,31 @@ impl Default for NVME_CDW11_FEATURE_INTERRUPT_COALESCING {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_FEATURE_INTERRUPT_COALESCING_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_FEATURE_INTERRUPT_COALESCING_0 {
+    pub const fn THR(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_THR(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn TIME(&self) -> u32 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_TIME(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_FEATURE_INTERRUPT_COALESCING_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2561,10 +3820,31 @@ impl Default for NVME_CDW11_FEATURE_INTERRUPT_VECTOR_CONFIG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_FEATURE_INTERRUPT_VECTOR_CONFIG_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_FEATURE_INTERRUPT_VECTOR_CONFIG_0 {
+    pub const fn IV(&self) -> u32 {
+        self._bitfield & 65535
+    }
+    pub fn set_IV(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn CD(&self) -> u32 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_CD(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 17) & 32767
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(32767 << 17)) | ((value & 32767) << 17);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_FEATURE_INTERRUPT_VECTOR_CONFIG_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2588,10 +3868,25 @@ impl Default for NVME_CDW11_FEATURE_IO_COMMAND_SET_PROFILE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_FEATURE_IO_COMMAND_SET_PROFILE_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_FEATURE_IO_COMMAND_SET_PROFILE_0 {
+    pub const fn IOCSCI(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_IOCSCI(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 8) & 16777215
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(16777215 << 8)) | ((value & 16777215) << 8);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_FEATURE_IO_COMMAND_SET_PROFILE_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2615,10 +3910,25 @@ impl Default for NVME_CDW11_FEATURE_LBA_RANGE_TYPE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_FEATURE_LBA_RANGE_TYPE_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_FEATURE_LBA_RANGE_TYPE_0 {
+    pub const fn NUM(&self) -> u32 {
+        self._bitfield & 63
+    }
+    pub fn set_NUM(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !63) | (value & 63);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 6) & 67108863
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(67108863 << 6)) | ((value & 67108863) << 6);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_FEATURE_LBA_RANGE_TYPE_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2642,10 +3952,25 @@ impl Default for NVME_CDW11_FEATURE_NON_OPERATIONAL_POWER_STATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_FEATURE_NON_OPERATIONAL_POWER_STATE_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_FEATURE_NON_OPERATIONAL_POWER_STATE_0 {
+    pub const fn NOPPME(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_NOPPME(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_FEATURE_NON_OPERATIONAL_POWER_STATE_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2669,10 +3994,25 @@ impl Default for NVME_CDW11_FEATURE_NUMBER_OF_QUEUES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_FEATURE_NUMBER_OF_QUEUES_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_FEATURE_NUMBER_OF_QUEUES_0 {
+    pub const fn NSQ(&self) -> u32 {
+        self._bitfield & 65535
+    }
+    pub fn set_NSQ(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn NCQ(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_NCQ(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_FEATURE_NUMBER_OF_QUEUES_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2696,10 +4036,25 @@ impl Default for NVME_CDW11_FEATURE_POWER_MANAGEMENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_FEATURE_POWER_MANAGEMENT_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_FEATURE_POWER_MANAGEMENT_0 {
+    pub const fn PS(&self) -> u32 {
+        self._bitfield & 31
+    }
+    pub fn set_PS(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !31) | (value & 31);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 5) & 134217727
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(134217727 << 5)) | ((value & 134217727) << 5);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_FEATURE_POWER_MANAGEMENT_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2723,10 +4078,25 @@ impl Default for NVME_CDW11_FEATURE_READONLY_WRITETHROUGH_MODE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_FEATURE_READONLY_WRITETHROUGH_MODE_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_FEATURE_READONLY_WRITETHROUGH_MODE_0 {
+    pub const fn Reserved0(&self) -> u32 {
+        self._bitfield & 1073741823
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1073741823) | (value & 1073741823);
+    }
+    pub const fn EOLBehavior(&self) -> u32 {
+        (self._bitfield >> 30) & 3
+    }
+    pub fn set_EOLBehavior(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 30)) | ((value & 3) << 30);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_FEATURE_READONLY_WRITETHROUGH_MODE_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2736,10 +4106,43 @@ impl Default for NVME_CDW11_FEATURE_READONLY_WRITETHROUGH_MODE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_FEATURE_RESERVATION_NOTIFICATION_MASK {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_FEATURE_RESERVATION_NOTIFICATION_MASK {
+    pub const fn Reserved(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn REGPRE(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_REGPRE(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn RESREL(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_RESREL(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn RESPRE(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_RESPRE(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 4) & 268435455
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(268435455 << 4)) | ((value & 268435455) << 4);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_FEATURE_RESERVATION_NOTIFICATION_MASK {
     type TypeKind = windows_core::CopyType;
 }
@@ -2749,10 +4152,25 @@ impl Default for NVME_CDW11_FEATURE_RESERVATION_NOTIFICATION_MASK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_FEATURE_RESERVATION_PERSISTENCE {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_FEATURE_RESERVATION_PERSISTENCE {
+    pub const fn PTPL(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_PTPL(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_FEATURE_RESERVATION_PERSISTENCE {
     type TypeKind = windows_core::CopyType;
 }
@@ -2776,10 +4194,31 @@ impl Default for NVME_CDW11_FEATURE_SET_HOST_METADATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_FEATURE_SET_HOST_METADATA_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_FEATURE_SET_HOST_METADATA_0 {
+    pub const fn Reserved0(&self) -> u32 {
+        self._bitfield & 8191
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !8191) | (value & 8191);
+    }
+    pub const fn EA(&self) -> u32 {
+        (self._bitfield >> 13) & 3
+    }
+    pub fn set_EA(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 13)) | ((value & 3) << 13);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 15) & 131071
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(131071 << 15)) | ((value & 131071) << 15);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_FEATURE_SET_HOST_METADATA_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2803,10 +4242,37 @@ impl Default for NVME_CDW11_FEATURE_SUPPORTED_CAPABILITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_FEATURE_SUPPORTED_CAPABILITY_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_FEATURE_SUPPORTED_CAPABILITY_0 {
+    pub const fn SAVE(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_SAVE(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn NSS(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_NSS(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn MOD(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_MOD(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 3) & 536870911
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(536870911 << 3)) | ((value & 536870911) << 3);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_FEATURE_SUPPORTED_CAPABILITY_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2830,10 +4296,37 @@ impl Default for NVME_CDW11_FEATURE_TEMPERATURE_THRESHOLD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_FEATURE_TEMPERATURE_THRESHOLD_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_FEATURE_TEMPERATURE_THRESHOLD_0 {
+    pub const fn TMPTH(&self) -> u32 {
+        self._bitfield & 65535
+    }
+    pub fn set_TMPTH(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn TMPSEL(&self) -> u32 {
+        (self._bitfield >> 16) & 15
+    }
+    pub fn set_TMPSEL(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 16)) | ((value & 15) << 16);
+    }
+    pub const fn THSEL(&self) -> u32 {
+        (self._bitfield >> 20) & 3
+    }
+    pub fn set_THSEL(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 20)) | ((value & 3) << 20);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 22) & 1023
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1023 << 22)) | ((value & 1023) << 22);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_FEATURE_TEMPERATURE_THRESHOLD_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2857,10 +4350,25 @@ impl Default for NVME_CDW11_FEATURE_VOLATILE_WRITE_CACHE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_FEATURE_VOLATILE_WRITE_CACHE_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_FEATURE_VOLATILE_WRITE_CACHE_0 {
+    pub const fn WCE(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_WCE(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_FEATURE_VOLATILE_WRITE_CACHE_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2884,10 +4392,25 @@ impl Default for NVME_CDW11_FEATURE_WRITE_ATOMICITY_NORMAL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_FEATURE_WRITE_ATOMICITY_NORMAL_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_FEATURE_WRITE_ATOMICITY_NORMAL_0 {
+    pub const fn DN(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_DN(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_FEATURE_WRITE_ATOMICITY_NORMAL_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2897,7 +4420,8 @@ impl Default for NVME_CDW11_FEATURE_WRITE_ATOMICITY_NORMAL_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_FIRMWARE_DOWNLOAD {
     pub OFST: u32,
 }
@@ -2924,14 +4448,29 @@ impl Default for NVME_CDW11_GET_LOG_PAGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_GET_LOG_PAGE_0 {
     pub _bitfield: u32,
 }
-impl windows_core::TypeKind for NVME_CDW11_GET_LOG_PAGE_0 {
-    type TypeKind = windows_core::CopyType;
-}
-impl Default for NVME_CDW11_GET_LOG_PAGE_0 {
+impl NVME_CDW11_GET_LOG_PAGE_0 {
+    pub const fn NUMDU(&self) -> u32 {
+        self._bitfield & 65535
+    }
+    pub fn set_NUMDU(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn LogSpecificIdentifier(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_LogSpecificIdentifier(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
+impl windows_core::TypeKind for NVME_CDW11_GET_LOG_PAGE_0 {
+    type TypeKind = windows_core::CopyType;
+}
+impl Default for NVME_CDW11_GET_LOG_PAGE_0 {
     fn default() -> Self {
         unsafe { core::mem::zeroed() }
     }
@@ -2952,7 +4491,8 @@ impl Default for NVME_CDW11_IDENTIFY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_IDENTIFY_0 {
     pub NVMSETID: u16,
     pub Reserved: u16,
@@ -2966,10 +4506,31 @@ impl Default for NVME_CDW11_IDENTIFY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_IDENTIFY_1 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_IDENTIFY_1 {
+    pub const fn CNSID(&self) -> u32 {
+        self._bitfield & 65535
+    }
+    pub fn set_CNSID(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn Reserved2(&self) -> u32 {
+        (self._bitfield >> 16) & 255
+    }
+    pub fn set_Reserved2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 16)) | ((value & 255) << 16);
+    }
+    pub const fn CSI(&self) -> u32 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_CSI(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_IDENTIFY_1 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2993,10 +4554,25 @@ impl Default for NVME_CDW11_RESERVATION_REPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_RESERVATION_REPORT_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW11_RESERVATION_REPORT_0 {
+    pub const fn EDS(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_EDS(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW11_RESERVATION_REPORT_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3020,7 +4596,8 @@ impl Default for NVME_CDW11_SANITIZE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_SANITIZE_0 {
     pub OVRPAT: u32,
 }
@@ -3033,7 +4610,8 @@ impl Default for NVME_CDW11_SANITIZE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_SECURITY_RECEIVE {
     pub AL: u32,
 }
@@ -3046,7 +4624,8 @@ impl Default for NVME_CDW11_SECURITY_RECEIVE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW11_SECURITY_SEND {
     pub TL: u32,
 }
@@ -3087,10 +4666,25 @@ impl Default for NVME_CDW12_DIRECTIVE_RECEIVE_STREAMS_ALLOCATE_RESOURCES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW12_DIRECTIVE_RECEIVE_STREAMS_ALLOCATE_RESOURCES_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW12_DIRECTIVE_RECEIVE_STREAMS_ALLOCATE_RESOURCES_0 {
+    pub const fn NSR(&self) -> u32 {
+        self._bitfield & 65535
+    }
+    pub fn set_NSR(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW12_DIRECTIVE_RECEIVE_STREAMS_ALLOCATE_RESOURCES_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3128,10 +4722,37 @@ impl Default for NVME_CDW12_DIRECTIVE_SEND_IDENTIFY_ENABLE_DIRECTIVE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW12_DIRECTIVE_SEND_IDENTIFY_ENABLE_DIRECTIVE_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW12_DIRECTIVE_SEND_IDENTIFY_ENABLE_DIRECTIVE_0 {
+    pub const fn ENDIR(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_ENDIR(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 1) & 127
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(127 << 1)) | ((value & 127) << 1);
+    }
+    pub const fn DTYPE(&self) -> u32 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_DTYPE(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW12_DIRECTIVE_SEND_IDENTIFY_ENABLE_DIRECTIVE_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3169,7 +4790,8 @@ impl Default for NVME_CDW12_FEATURE_HOST_MEMORY_BUFFER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW12_FEATURE_HOST_MEMORY_BUFFER_0 {
     pub HSIZE: u32,
 }
@@ -3182,7 +4804,8 @@ impl Default for NVME_CDW12_FEATURE_HOST_MEMORY_BUFFER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW12_GET_LOG_PAGE {
     pub LPOL: u32,
 }
@@ -3209,10 +4832,55 @@ impl Default for NVME_CDW12_READ_WRITE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW12_READ_WRITE_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW12_READ_WRITE_0 {
+    pub const fn NLB(&self) -> u32 {
+        self._bitfield & 65535
+    }
+    pub fn set_NLB(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 16) & 15
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 16)) | ((value & 15) << 16);
+    }
+    pub const fn DTYPE(&self) -> u32 {
+        (self._bitfield >> 20) & 15
+    }
+    pub fn set_DTYPE(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 20)) | ((value & 15) << 20);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 24) & 3
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 24)) | ((value & 3) << 24);
+    }
+    pub const fn PRINFO(&self) -> u32 {
+        (self._bitfield >> 26) & 15
+    }
+    pub fn set_PRINFO(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 26)) | ((value & 15) << 26);
+    }
+    pub const fn FUA(&self) -> u32 {
+        (self._bitfield >> 30) & 1
+    }
+    pub fn set_FUA(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 30)) | ((value & 1) << 30);
+    }
+    pub const fn LR(&self) -> u32 {
+        (self._bitfield >> 31) & 1
+    }
+    pub fn set_LR(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 31)) | ((value & 1) << 31);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW12_READ_WRITE_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3236,10 +4904,49 @@ impl Default for NVME_CDW12_ZONE_APPEND {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW12_ZONE_APPEND_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW12_ZONE_APPEND_0 {
+    pub const fn NLB(&self) -> u32 {
+        self._bitfield & 65535
+    }
+    pub fn set_NLB(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 16) & 511
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(511 << 16)) | ((value & 511) << 16);
+    }
+    pub const fn PIREMAP(&self) -> u32 {
+        (self._bitfield >> 25) & 1
+    }
+    pub fn set_PIREMAP(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 25)) | ((value & 1) << 25);
+    }
+    pub const fn PRINFO(&self) -> u32 {
+        (self._bitfield >> 26) & 15
+    }
+    pub fn set_PRINFO(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 26)) | ((value & 15) << 26);
+    }
+    pub const fn FUA(&self) -> u32 {
+        (self._bitfield >> 30) & 1
+    }
+    pub fn set_FUA(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 30)) | ((value & 1) << 30);
+    }
+    pub const fn LR(&self) -> u32 {
+        (self._bitfield >> 31) & 1
+    }
+    pub fn set_LR(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 31)) | ((value & 1) << 31);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW12_ZONE_APPEND_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3277,10 +4984,25 @@ impl Default for NVME_CDW13_FEATURE_HOST_MEMORY_BUFFER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW13_FEATURE_HOST_MEMORY_BUFFER_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW13_FEATURE_HOST_MEMORY_BUFFER_0 {
+    pub const fn Reserved(&self) -> u32 {
+        self._bitfield & 15
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn HMDLLA(&self) -> u32 {
+        (self._bitfield >> 4) & 268435455
+    }
+    pub fn set_HMDLLA(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(268435455 << 4)) | ((value & 268435455) << 4);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW13_FEATURE_HOST_MEMORY_BUFFER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3290,7 +5012,8 @@ impl Default for NVME_CDW13_FEATURE_HOST_MEMORY_BUFFER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW13_GET_LOG_PAGE {
     pub LPOU: u32,
 }
@@ -3317,7 +5040,8 @@ impl Default for NVME_CDW13_READ_WRITE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW13_READ_WRITE_0 {
     pub DSM: NVME_CDW13_READ_WRITE_0_0,
     pub Reserved: u8,
@@ -3332,10 +5056,37 @@ impl Default for NVME_CDW13_READ_WRITE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW13_READ_WRITE_0_0 {
     pub _bitfield: u8,
 }
+impl NVME_CDW13_READ_WRITE_0_0 {
+    pub const fn AccessFrequency(&self) -> u8 {
+        self._bitfield & 15
+    }
+    pub fn set_AccessFrequency(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn AccessLatency(&self) -> u8 {
+        (self._bitfield >> 4) & 3
+    }
+    pub fn set_AccessLatency(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(3 << 4)) | ((value & 3) << 4);
+    }
+    pub const fn SequentialRequest(&self) -> u8 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_SequentialRequest(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Incompressible(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Incompressible(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW13_READ_WRITE_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3359,10 +5110,37 @@ impl Default for NVME_CDW13_ZONE_MANAGEMENT_RECEIVE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW13_ZONE_MANAGEMENT_RECEIVE_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW13_ZONE_MANAGEMENT_RECEIVE_0 {
+    pub const fn ZRA(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_ZRA(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn ZRASpecific(&self) -> u32 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_ZRASpecific(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn Partial(&self) -> u32 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_Partial(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 17) & 32767
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(32767 << 17)) | ((value & 32767) << 17);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW13_ZONE_MANAGEMENT_RECEIVE_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3386,10 +5164,31 @@ impl Default for NVME_CDW13_ZONE_MANAGEMENT_SEND {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW13_ZONE_MANAGEMENT_SEND_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW13_ZONE_MANAGEMENT_SEND_0 {
+    pub const fn ZSA(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_ZSA(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn SelectAll(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_SelectAll(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 9) & 8388607
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(8388607 << 9)) | ((value & 8388607) << 9);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW13_ZONE_MANAGEMENT_SEND_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3427,7 +5226,8 @@ impl Default for NVME_CDW14_FEATURE_HOST_MEMORY_BUFFER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW14_FEATURE_HOST_MEMORY_BUFFER_0 {
     pub HMDLUA: u32,
 }
@@ -3454,10 +5254,31 @@ impl Default for NVME_CDW14_GET_LOG_PAGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW14_GET_LOG_PAGE_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW14_GET_LOG_PAGE_0 {
+    pub const fn UUIDIndex(&self) -> u32 {
+        self._bitfield & 127
+    }
+    pub fn set_UUIDIndex(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !127) | (value & 127);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 7) & 131071
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(131071 << 7)) | ((value & 131071) << 7);
+    }
+    pub const fn CommandSetIdentifier(&self) -> u32 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_CommandSetIdentifier(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW14_GET_LOG_PAGE_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3495,7 +5316,8 @@ impl Default for NVME_CDW15_FEATURE_HOST_MEMORY_BUFFER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW15_FEATURE_HOST_MEMORY_BUFFER_0 {
     pub HMDLEC: u32,
 }
@@ -3522,10 +5344,25 @@ impl Default for NVME_CDW15_READ_WRITE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW15_READ_WRITE_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW15_READ_WRITE_0 {
+    pub const fn ELBAT(&self) -> u32 {
+        self._bitfield & 65535
+    }
+    pub fn set_ELBAT(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn ELBATM(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_ELBATM(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW15_READ_WRITE_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3549,10 +5386,25 @@ impl Default for NVME_CDW15_ZONE_APPEND {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CDW15_ZONE_APPEND_0 {
     pub _bitfield: u32,
 }
+impl NVME_CDW15_ZONE_APPEND_0 {
+    pub const fn LBAT(&self) -> u32 {
+        self._bitfield & 65535
+    }
+    pub fn set_LBAT(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn LBATM(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_LBATM(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for NVME_CDW15_ZONE_APPEND_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3562,7 +5414,8 @@ impl Default for NVME_CDW15_ZONE_APPEND_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CHANGED_NAMESPACE_LIST_LOG {
     pub NSID: [u32; 1024],
 }
@@ -3575,7 +5428,8 @@ impl Default for NVME_CHANGED_NAMESPACE_LIST_LOG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CHANGED_ZONE_LIST_LOG {
     pub ZoneIdentifiersCount: u16,
     pub Reserved: [u8; 6],
@@ -3772,7 +5626,8 @@ impl Default for NVME_COMMAND_0_6 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_COMMAND_0_7 {
     pub CDW10: NVME_CDW10_FIRMWARE_DOWNLOAD,
     pub CDW11: NVME_CDW11_FIRMWARE_DOWNLOAD,
@@ -3808,7 +5663,8 @@ impl Default for NVME_COMMAND_0_8 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_COMMAND_0_9 {
     pub CDW10: u32,
     pub CDW11: u32,
@@ -4121,10 +5977,43 @@ impl Default for NVME_COMMAND_DWORD0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_COMMAND_DWORD0_0 {
     pub _bitfield: u32,
 }
+impl NVME_COMMAND_DWORD0_0 {
+    pub const fn OPC(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_OPC(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn FUSE(&self) -> u32 {
+        (self._bitfield >> 8) & 3
+    }
+    pub fn set_FUSE(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 8)) | ((value & 3) << 8);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 10) & 31
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 10)) | ((value & 31) << 10);
+    }
+    pub const fn PSDT(&self) -> u32 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_PSDT(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+    pub const fn CID(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_CID(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for NVME_COMMAND_DWORD0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4148,10 +6037,61 @@ impl Default for NVME_COMMAND_EFFECTS_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_COMMAND_EFFECTS_DATA_0 {
     pub _bitfield: u32,
 }
+impl NVME_COMMAND_EFFECTS_DATA_0 {
+    pub const fn CSUPP(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_CSUPP(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn LBCC(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_LBCC(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn NCC(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_NCC(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn NIC(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_NIC(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn CCC(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_CCC(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 5) & 2047
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2047 << 5)) | ((value & 2047) << 5);
+    }
+    pub const fn CSE(&self) -> u32 {
+        (self._bitfield >> 16) & 7
+    }
+    pub fn set_CSE(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 16)) | ((value & 7) << 16);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 19) & 8191
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(8191 << 19)) | ((value & 8191) << 19);
+    }
+}
 impl windows_core::TypeKind for NVME_COMMAND_EFFECTS_DATA_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4190,10 +6130,49 @@ impl Default for NVME_COMMAND_STATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_COMMAND_STATUS_0 {
     pub _bitfield: u16,
 }
+impl NVME_COMMAND_STATUS_0 {
+    pub const fn P(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_P(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn SC(&self) -> u16 {
+        (self._bitfield >> 1) & 255
+    }
+    pub fn set_SC(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(255 << 1)) | ((value & 255) << 1);
+    }
+    pub const fn SCT(&self) -> u16 {
+        (self._bitfield >> 9) & 7
+    }
+    pub fn set_SCT(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(7 << 9)) | ((value & 7) << 9);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 12) & 3
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 12)) | ((value & 3) << 12);
+    }
+    pub const fn M(&self) -> u16 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_M(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn DNR(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_DNR(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for NVME_COMMAND_STATUS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4203,10 +6182,43 @@ impl Default for NVME_COMMAND_STATUS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_COMPLETION_DW0_ASYNC_EVENT_REQUEST {
     pub _bitfield: u32,
 }
+impl NVME_COMPLETION_DW0_ASYNC_EVENT_REQUEST {
+    pub const fn AsyncEventType(&self) -> u32 {
+        self._bitfield & 7
+    }
+    pub fn set_AsyncEventType(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 3) & 31
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 3)) | ((value & 31) << 3);
+    }
+    pub const fn AsyncEventInfo(&self) -> u32 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_AsyncEventInfo(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn LogPage(&self) -> u32 {
+        (self._bitfield >> 16) & 255
+    }
+    pub fn set_LogPage(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 16)) | ((value & 255) << 16);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for NVME_COMPLETION_DW0_ASYNC_EVENT_REQUEST {
     type TypeKind = windows_core::CopyType;
 }
@@ -4216,7 +6228,8 @@ impl Default for NVME_COMPLETION_DW0_ASYNC_EVENT_REQUEST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_COMPLETION_DW0_DIRECTIVE_RECEIVE_STREAMS_ALLOCATE_RESOURCES {
     pub Anonymous: NVME_COMPLETION_DW0_DIRECTIVE_RECEIVE_STREAMS_ALLOCATE_RESOURCES_0,
     pub AsUlong: u32,
@@ -4230,10 +6243,25 @@ impl Default for NVME_COMPLETION_DW0_DIRECTIVE_RECEIVE_STREAMS_ALLOCATE_RESOURCE
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_COMPLETION_DW0_DIRECTIVE_RECEIVE_STREAMS_ALLOCATE_RESOURCES_0 {
     pub _bitfield: u32,
 }
+impl NVME_COMPLETION_DW0_DIRECTIVE_RECEIVE_STREAMS_ALLOCATE_RESOURCES_0 {
+    pub const fn NSA(&self) -> u32 {
+        self._bitfield & 65535
+    }
+    pub fn set_NSA(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for NVME_COMPLETION_DW0_DIRECTIVE_RECEIVE_STREAMS_ALLOCATE_RESOURCES_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4273,7 +6301,8 @@ impl Default for NVME_COMPLETION_ENTRY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_COMPLETION_ENTRY_0_0 {
     pub SQHD: u16,
     pub SQID: u16,
@@ -4329,10 +6358,25 @@ impl Default for NVME_COMPLETION_QUEUE_HEAD_DOORBELL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_COMPLETION_QUEUE_HEAD_DOORBELL_0 {
     pub _bitfield: u32,
 }
+impl NVME_COMPLETION_QUEUE_HEAD_DOORBELL_0 {
+    pub const fn CQH(&self) -> u32 {
+        self._bitfield & 65535
+    }
+    pub fn set_CQH(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for NVME_COMPLETION_QUEUE_HEAD_DOORBELL_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4356,10 +6400,61 @@ impl Default for NVME_CONTEXT_ATTRIBUTES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CONTEXT_ATTRIBUTES_0 {
     pub _bitfield: u32,
 }
+impl NVME_CONTEXT_ATTRIBUTES_0 {
+    pub const fn AccessFrequency(&self) -> u32 {
+        self._bitfield & 15
+    }
+    pub fn set_AccessFrequency(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn AccessLatency(&self) -> u32 {
+        (self._bitfield >> 4) & 3
+    }
+    pub fn set_AccessLatency(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 4)) | ((value & 3) << 4);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+    pub const fn SequentialReadRange(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_SequentialReadRange(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn SequentialWriteRange(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_SequentialWriteRange(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn WritePrepare(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_WritePrepare(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 11) & 8191
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(8191 << 11)) | ((value & 8191) << 11);
+    }
+    pub const fn CommandAccessSize(&self) -> u32 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_CommandAccessSize(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for NVME_CONTEXT_ATTRIBUTES_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4383,10 +6478,133 @@ impl Default for NVME_CONTROLLER_CAPABILITIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CONTROLLER_CAPABILITIES_0 {
     pub _bitfield: u64,
 }
+impl NVME_CONTROLLER_CAPABILITIES_0 {
+    pub const fn MQES(&self) -> u64 {
+        self._bitfield & 65535
+    }
+    pub fn set_MQES(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn CQR(&self) -> u64 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_CQR(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn AMS_WeightedRoundRobinWithUrgent(&self) -> u64 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_AMS_WeightedRoundRobinWithUrgent(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn AMS_VendorSpecific(&self) -> u64 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_AMS_VendorSpecific(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn Reserved0(&self) -> u64 {
+        (self._bitfield >> 19) & 31
+    }
+    pub fn set_Reserved0(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(31 << 19)) | ((value & 31) << 19);
+    }
+    pub const fn TO(&self) -> u64 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_TO(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+    pub const fn DSTRD(&self) -> u64 {
+        (self._bitfield >> 32) & 15
+    }
+    pub fn set_DSTRD(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(15 << 32)) | ((value & 15) << 32);
+    }
+    pub const fn NSSRS(&self) -> u64 {
+        (self._bitfield >> 36) & 1
+    }
+    pub fn set_NSSRS(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 36)) | ((value & 1) << 36);
+    }
+    pub const fn CSS_NVM(&self) -> u64 {
+        (self._bitfield >> 37) & 1
+    }
+    pub fn set_CSS_NVM(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 37)) | ((value & 1) << 37);
+    }
+    pub const fn CSS_Reserved0(&self) -> u64 {
+        (self._bitfield >> 38) & 1
+    }
+    pub fn set_CSS_Reserved0(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 38)) | ((value & 1) << 38);
+    }
+    pub const fn CSS_Reserved1(&self) -> u64 {
+        (self._bitfield >> 39) & 1
+    }
+    pub fn set_CSS_Reserved1(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 39)) | ((value & 1) << 39);
+    }
+    pub const fn CSS_Reserved2(&self) -> u64 {
+        (self._bitfield >> 40) & 1
+    }
+    pub fn set_CSS_Reserved2(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 40)) | ((value & 1) << 40);
+    }
+    pub const fn CSS_Reserved3(&self) -> u64 {
+        (self._bitfield >> 41) & 1
+    }
+    pub fn set_CSS_Reserved3(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 41)) | ((value & 1) << 41);
+    }
+    pub const fn CSS_Reserved4(&self) -> u64 {
+        (self._bitfield >> 42) & 1
+    }
+    pub fn set_CSS_Reserved4(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 42)) | ((value & 1) << 42);
+    }
+    pub const fn CSS_MultipleIo(&self) -> u64 {
+        (self._bitfield >> 43) & 1
+    }
+    pub fn set_CSS_MultipleIo(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 43)) | ((value & 1) << 43);
+    }
+    pub const fn CSS_AdminOnly(&self) -> u64 {
+        (self._bitfield >> 44) & 1
+    }
+    pub fn set_CSS_AdminOnly(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 44)) | ((value & 1) << 44);
+    }
+    pub const fn Reserved2(&self) -> u64 {
+        (self._bitfield >> 45) & 7
+    }
+    pub fn set_Reserved2(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(7 << 45)) | ((value & 7) << 45);
+    }
+    pub const fn MPSMIN(&self) -> u64 {
+        (self._bitfield >> 48) & 15
+    }
+    pub fn set_MPSMIN(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(15 << 48)) | ((value & 15) << 48);
+    }
+    pub const fn MPSMAX(&self) -> u64 {
+        (self._bitfield >> 52) & 15
+    }
+    pub fn set_MPSMAX(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(15 << 52)) | ((value & 15) << 52);
+    }
+    pub const fn Reserved3(&self) -> u64 {
+        (self._bitfield >> 56) & 255
+    }
+    pub fn set_Reserved3(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(255 << 56)) | ((value & 255) << 56);
+    }
+}
 impl windows_core::TypeKind for NVME_CONTROLLER_CAPABILITIES_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4410,10 +6628,67 @@ impl Default for NVME_CONTROLLER_CONFIGURATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CONTROLLER_CONFIGURATION_0 {
     pub _bitfield: u32,
 }
+impl NVME_CONTROLLER_CONFIGURATION_0 {
+    pub const fn EN(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_EN(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 1) & 7
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 1)) | ((value & 7) << 1);
+    }
+    pub const fn CSS(&self) -> u32 {
+        (self._bitfield >> 4) & 7
+    }
+    pub fn set_CSS(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 4)) | ((value & 7) << 4);
+    }
+    pub const fn MPS(&self) -> u32 {
+        (self._bitfield >> 7) & 15
+    }
+    pub fn set_MPS(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 7)) | ((value & 15) << 7);
+    }
+    pub const fn AMS(&self) -> u32 {
+        (self._bitfield >> 11) & 7
+    }
+    pub fn set_AMS(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 11)) | ((value & 7) << 11);
+    }
+    pub const fn SHN(&self) -> u32 {
+        (self._bitfield >> 14) & 3
+    }
+    pub fn set_SHN(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 14)) | ((value & 3) << 14);
+    }
+    pub const fn IOSQES(&self) -> u32 {
+        (self._bitfield >> 16) & 15
+    }
+    pub fn set_IOSQES(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 16)) | ((value & 15) << 16);
+    }
+    pub const fn IOCQES(&self) -> u32 {
+        (self._bitfield >> 20) & 15
+    }
+    pub fn set_IOCQES(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 20)) | ((value & 15) << 20);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for NVME_CONTROLLER_CONFIGURATION_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4423,7 +6698,8 @@ impl Default for NVME_CONTROLLER_CONFIGURATION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CONTROLLER_LIST {
     pub NumberOfIdentifiers: u16,
     pub ControllerID: [u16; 2047],
@@ -4451,10 +6727,31 @@ impl Default for NVME_CONTROLLER_MEMORY_BUFFER_LOCATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CONTROLLER_MEMORY_BUFFER_LOCATION_0 {
     pub _bitfield: u32,
 }
+impl NVME_CONTROLLER_MEMORY_BUFFER_LOCATION_0 {
+    pub const fn BIR(&self) -> u32 {
+        self._bitfield & 7
+    }
+    pub fn set_BIR(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 3) & 511
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(511 << 3)) | ((value & 511) << 3);
+    }
+    pub const fn OFST(&self) -> u32 {
+        (self._bitfield >> 12) & 1048575
+    }
+    pub fn set_OFST(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1048575 << 12)) | ((value & 1048575) << 12);
+    }
+}
 impl windows_core::TypeKind for NVME_CONTROLLER_MEMORY_BUFFER_LOCATION_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4478,10 +6775,61 @@ impl Default for NVME_CONTROLLER_MEMORY_BUFFER_SIZE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CONTROLLER_MEMORY_BUFFER_SIZE_0 {
     pub _bitfield: u32,
 }
+impl NVME_CONTROLLER_MEMORY_BUFFER_SIZE_0 {
+    pub const fn SQS(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_SQS(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn CQS(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_CQS(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn LISTS(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_LISTS(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn RDS(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_RDS(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn WDS(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_WDS(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 5) & 7
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 5)) | ((value & 7) << 5);
+    }
+    pub const fn SZU(&self) -> u32 {
+        (self._bitfield >> 8) & 15
+    }
+    pub fn set_SZU(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 8)) | ((value & 15) << 8);
+    }
+    pub const fn SZ(&self) -> u32 {
+        (self._bitfield >> 12) & 1048575
+    }
+    pub fn set_SZ(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1048575 << 12)) | ((value & 1048575) << 12);
+    }
+}
 impl windows_core::TypeKind for NVME_CONTROLLER_MEMORY_BUFFER_SIZE_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4533,10 +6881,49 @@ impl Default for NVME_CONTROLLER_STATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_CONTROLLER_STATUS_0 {
     pub _bitfield: u32,
 }
+impl NVME_CONTROLLER_STATUS_0 {
+    pub const fn RDY(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_RDY(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn CFS(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_CFS(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn SHST(&self) -> u32 {
+        (self._bitfield >> 2) & 3
+    }
+    pub fn set_SHST(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 2)) | ((value & 3) << 2);
+    }
+    pub const fn NSSRO(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_NSSRO(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn PP(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_PP(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 6) & 67108863
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(67108863 << 6)) | ((value & 67108863) << 6);
+    }
+}
 impl windows_core::TypeKind for NVME_CONTROLLER_STATUS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4562,10 +6949,25 @@ impl Default for NVME_DEVICE_SELF_TEST_LOG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_DEVICE_SELF_TEST_LOG_0 {
     pub _bitfield: u8,
 }
+impl NVME_DEVICE_SELF_TEST_LOG_0 {
+    pub const fn CompletePercent(&self) -> u8 {
+        self._bitfield & 127
+    }
+    pub fn set_CompletePercent(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !127) | (value & 127);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for NVME_DEVICE_SELF_TEST_LOG_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4575,10 +6977,25 @@ impl Default for NVME_DEVICE_SELF_TEST_LOG_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_DEVICE_SELF_TEST_LOG_1 {
     pub _bitfield: u8,
 }
+impl NVME_DEVICE_SELF_TEST_LOG_1 {
+    pub const fn Status(&self) -> u8 {
+        self._bitfield & 15
+    }
+    pub fn set_Status(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+}
 impl windows_core::TypeKind for NVME_DEVICE_SELF_TEST_LOG_1 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4610,10 +7027,25 @@ impl Default for NVME_DEVICE_SELF_TEST_RESULT_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_DEVICE_SELF_TEST_RESULT_DATA_0 {
     pub _bitfield: u8,
 }
+impl NVME_DEVICE_SELF_TEST_RESULT_DATA_0 {
+    pub const fn AdditionalInfo(&self) -> u8 {
+        self._bitfield & 7
+    }
+    pub fn set_AdditionalInfo(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 3) & 31
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(31 << 3)) | ((value & 31) << 3);
+    }
+}
 impl windows_core::TypeKind for NVME_DEVICE_SELF_TEST_RESULT_DATA_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4623,10 +7055,25 @@ impl Default for NVME_DEVICE_SELF_TEST_RESULT_DATA_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_DEVICE_SELF_TEST_RESULT_DATA_1 {
     pub _bitfield: u8,
 }
+impl NVME_DEVICE_SELF_TEST_RESULT_DATA_1 {
+    pub const fn Result(&self) -> u8 {
+        self._bitfield & 15
+    }
+    pub fn set_Result(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn CodeValue(&self) -> u8 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_CodeValue(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+}
 impl windows_core::TypeKind for NVME_DEVICE_SELF_TEST_RESULT_DATA_1 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4636,10 +7083,43 @@ impl Default for NVME_DEVICE_SELF_TEST_RESULT_DATA_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_DEVICE_SELF_TEST_RESULT_DATA_2 {
     pub _bitfield: u8,
 }
+impl NVME_DEVICE_SELF_TEST_RESULT_DATA_2 {
+    pub const fn NSIDValid(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_NSIDValid(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn FLBAValid(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_FLBAValid(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn SCTValid(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_SCTValid(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn SCValid(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_SCValid(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+}
 impl windows_core::TypeKind for NVME_DEVICE_SELF_TEST_RESULT_DATA_2 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4649,7 +7129,8 @@ impl Default for NVME_DEVICE_SELF_TEST_RESULT_DATA_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_DIRECTIVE_IDENTIFY_RETURN_PARAMETERS {
     pub DirectivesSupported: NVME_DIRECTIVE_IDENTIFY_RETURN_PARAMETERS_DESCRIPTOR,
     pub DirectivesEnabled: NVME_DIRECTIVE_IDENTIFY_RETURN_PARAMETERS_DESCRIPTOR,
@@ -4663,11 +7144,32 @@ impl Default for NVME_DIRECTIVE_IDENTIFY_RETURN_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_DIRECTIVE_IDENTIFY_RETURN_PARAMETERS_DESCRIPTOR {
     pub _bitfield: u8,
     pub Reserved1: [u8; 31],
 }
+impl NVME_DIRECTIVE_IDENTIFY_RETURN_PARAMETERS_DESCRIPTOR {
+    pub const fn Identify(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_Identify(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Streams(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Streams(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved0(&self) -> u8 {
+        (self._bitfield >> 2) & 63
+    }
+    pub fn set_Reserved0(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(63 << 2)) | ((value & 63) << 2);
+    }
+}
 impl windows_core::TypeKind for NVME_DIRECTIVE_IDENTIFY_RETURN_PARAMETERS_DESCRIPTOR {
     type TypeKind = windows_core::CopyType;
 }
@@ -4677,7 +7179,8 @@ impl Default for NVME_DIRECTIVE_IDENTIFY_RETURN_PARAMETERS_DESCRIPTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_DIRECTIVE_STREAMS_GET_STATUS_DATA {
     pub OpenStreamCount: u16,
     pub StreamIdentifiers: [u16; 65535],
@@ -4691,7 +7194,8 @@ impl Default for NVME_DIRECTIVE_STREAMS_GET_STATUS_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_DIRECTIVE_STREAMS_RETURN_PARAMETERS {
     pub MSL: u16,
     pub NSSA: u16,
@@ -4756,10 +7260,31 @@ impl Default for NVME_ERROR_INFO_LOG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_ERROR_INFO_LOG_0 {
     pub _bitfield: u16,
 }
+impl NVME_ERROR_INFO_LOG_0 {
+    pub const fn Byte(&self) -> u16 {
+        self._bitfield & 255
+    }
+    pub fn set_Byte(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn Bit(&self) -> u16 {
+        (self._bitfield >> 8) & 7
+    }
+    pub fn set_Bit(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(7 << 8)) | ((value & 7) << 8);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 11) & 31
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(31 << 11)) | ((value & 31) << 11);
+    }
+}
 impl windows_core::TypeKind for NVME_ERROR_INFO_LOG_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4799,10 +7324,31 @@ impl Default for NVME_ERROR_INJECTION_ENTRY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_ERROR_INJECTION_ENTRY_0_0 {
     pub _bitfield: u8,
 }
+impl NVME_ERROR_INJECTION_ENTRY_0_0 {
+    pub const fn Enable(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_Enable(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn SingleInstance(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_SingleInstance(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved0(&self) -> u8 {
+        (self._bitfield >> 2) & 63
+    }
+    pub fn set_Reserved0(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(63 << 2)) | ((value & 63) << 2);
+    }
+}
 impl windows_core::TypeKind for NVME_ERROR_INJECTION_ENTRY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4812,7 +7358,8 @@ impl Default for NVME_ERROR_INJECTION_ENTRY_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_EXTENDED_REPORT_ZONE_INFO {
     pub ZoneCount: u64,
     pub Reserved: [u64; 7],
@@ -4827,7 +7374,8 @@ impl Default for NVME_EXTENDED_REPORT_ZONE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_FEATURE_HOST_IDENTIFIER_DATA {
     pub HOSTID: [u8; 16],
 }
@@ -4840,7 +7388,8 @@ impl Default for NVME_FEATURE_HOST_IDENTIFIER_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_FEATURE_HOST_METADATA_DATA {
     pub NumberOfMetadataElementDescriptors: u8,
     pub Reserved0: u8,
@@ -4855,7 +7404,8 @@ impl Default for NVME_FEATURE_HOST_METADATA_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_FIRMWARE_SLOT_INFO_LOG {
     pub AFI: NVME_FIRMWARE_SLOT_INFO_LOG_0,
     pub Reserved0: [u8; 7],
@@ -4871,10 +7421,37 @@ impl Default for NVME_FIRMWARE_SLOT_INFO_LOG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_FIRMWARE_SLOT_INFO_LOG_0 {
     pub _bitfield: u8,
 }
+impl NVME_FIRMWARE_SLOT_INFO_LOG_0 {
+    pub const fn ActiveSlot(&self) -> u8 {
+        self._bitfield & 7
+    }
+    pub fn set_ActiveSlot(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn Reserved0(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_Reserved0(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn PendingActivateSlot(&self) -> u8 {
+        (self._bitfield >> 4) & 7
+    }
+    pub fn set_PendingActivateSlot(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(7 << 4)) | ((value & 7) << 4);
+    }
+    pub const fn Reserved1(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Reserved1(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for NVME_FIRMWARE_SLOT_INFO_LOG_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4937,10 +7514,49 @@ impl Default for NVME_HEALTH_INFO_LOG_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_HEALTH_INFO_LOG_0_0 {
     pub _bitfield: u8,
 }
+impl NVME_HEALTH_INFO_LOG_0_0 {
+    pub const fn AvailableSpaceLow(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_AvailableSpaceLow(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn TemperatureThreshold(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_TemperatureThreshold(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ReliabilityDegraded(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_ReliabilityDegraded(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn ReadOnly(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_ReadOnly(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn VolatileMemoryBackupDeviceFailed(&self) -> u8 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_VolatileMemoryBackupDeviceFailed(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 5) & 7
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(7 << 5)) | ((value & 7) << 5);
+    }
+}
 impl windows_core::TypeKind for NVME_HEALTH_INFO_LOG_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4950,7 +7566,8 @@ impl Default for NVME_HEALTH_INFO_LOG_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_HOST_MEMORY_BUFFER_DESCRIPTOR_ENTRY {
     pub BADD: u64,
     pub BSIZE: u32,
@@ -4965,11 +7582,44 @@ impl Default for NVME_HOST_MEMORY_BUFFER_DESCRIPTOR_ENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_HOST_METADATA_ELEMENT_DESCRIPTOR {
     pub _bitfield: u32,
     pub EVAL: [u8; 1],
 }
+impl NVME_HOST_METADATA_ELEMENT_DESCRIPTOR {
+    pub const fn ET(&self) -> u32 {
+        self._bitfield & 63
+    }
+    pub fn set_ET(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !63) | (value & 63);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+    pub const fn ER(&self) -> u32 {
+        (self._bitfield >> 8) & 15
+    }
+    pub fn set_ER(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 8)) | ((value & 15) << 8);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 12) & 15
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 12)) | ((value & 15) << 12);
+    }
+    pub const fn ELEN(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_ELEN(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for NVME_HOST_METADATA_ELEMENT_DESCRIPTOR {
     type TypeKind = windows_core::CopyType;
 }
@@ -4979,7 +7629,8 @@ impl Default for NVME_HOST_METADATA_ELEMENT_DESCRIPTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_CONTROLLER_DATA {
     pub VID: u16,
     pub SSVID: u16,
@@ -5072,10 +7723,61 @@ impl Default for NVME_IDENTIFY_CONTROLLER_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_CONTROLLER_DATA_0 {
     pub _bitfield: u8,
 }
+impl NVME_IDENTIFY_CONTROLLER_DATA_0 {
+    pub const fn OptimizedState(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_OptimizedState(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn NonOptimizedState(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_NonOptimizedState(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn InaccessibleState(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_InaccessibleState(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn PersistentLossState(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_PersistentLossState(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn ChangeState(&self) -> u8 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_ChangeState(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn StaticANAGRPID(&self) -> u8 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_StaticANAGRPID(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn SupportNonZeroANAGRPID(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_SupportNonZeroANAGRPID(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_CONTROLLER_DATA_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5085,10 +7787,25 @@ impl Default for NVME_IDENTIFY_CONTROLLER_DATA_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_CONTROLLER_DATA_1 {
     pub _bitfield: u8,
 }
+impl NVME_IDENTIFY_CONTROLLER_DATA_1 {
+    pub const fn Supported(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_Supported(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 1) & 127
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(127 << 1)) | ((value & 127) << 1);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_CONTROLLER_DATA_1 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5098,10 +7815,25 @@ impl Default for NVME_IDENTIFY_CONTROLLER_DATA_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_CONTROLLER_DATA_2 {
     pub _bitfield: u8,
 }
+impl NVME_IDENTIFY_CONTROLLER_DATA_2 {
+    pub const fn CommandFormatInSpec(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_CommandFormatInSpec(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 1) & 127
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(127 << 1)) | ((value & 127) << 1);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_CONTROLLER_DATA_2 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5111,10 +7843,43 @@ impl Default for NVME_IDENTIFY_CONTROLLER_DATA_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_CONTROLLER_DATA_3 {
     pub _bitfield: u8,
 }
+impl NVME_IDENTIFY_CONTROLLER_DATA_3 {
+    pub const fn MultiPCIePorts(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_MultiPCIePorts(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn MultiControllers(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_MultiControllers(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn SRIOV(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_SRIOV(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn ANAR(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_ANAR(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_CONTROLLER_DATA_3 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5124,10 +7889,25 @@ impl Default for NVME_IDENTIFY_CONTROLLER_DATA_3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_CONTROLLER_DATA_4 {
     pub _bitfield: u8,
 }
+impl NVME_IDENTIFY_CONTROLLER_DATA_4 {
+    pub const fn RequiredEntrySize(&self) -> u8 {
+        self._bitfield & 15
+    }
+    pub fn set_RequiredEntrySize(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn MaxEntrySize(&self) -> u8 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_MaxEntrySize(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_CONTROLLER_DATA_4 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5137,10 +7917,79 @@ impl Default for NVME_IDENTIFY_CONTROLLER_DATA_4 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_CONTROLLER_DATA_5 {
     pub _bitfield: u32,
 }
+impl NVME_IDENTIFY_CONTROLLER_DATA_5 {
+    pub const fn HostIdentifier128Bit(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_HostIdentifier128Bit(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn NOPSPMode(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_NOPSPMode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn NVMSets(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_NVMSets(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn ReadRecoveryLevels(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_ReadRecoveryLevels(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn EnduranceGroups(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_EnduranceGroups(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn PredictableLatencyMode(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_PredictableLatencyMode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn TBKAS(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_TBKAS(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn NamespaceGranularity(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_NamespaceGranularity(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn SQAssociations(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_SQAssociations(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn UUIDList(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_UUIDList(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 10) & 4194303
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(4194303 << 10)) | ((value & 4194303) << 10);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_CONTROLLER_DATA_5 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5150,10 +7999,43 @@ impl Default for NVME_IDENTIFY_CONTROLLER_DATA_5 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_CONTROLLER_DATA_6 {
     pub _bitfield: u8,
 }
+impl NVME_IDENTIFY_CONTROLLER_DATA_6 {
+    pub const fn FormatApplyToAll(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_FormatApplyToAll(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn SecureEraseApplyToAll(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_SecureEraseApplyToAll(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn CryptographicEraseSupported(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_CryptographicEraseSupported(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn FormatSupportNSIDAllF(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_FormatSupportNSIDAllF(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_CONTROLLER_DATA_6 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5163,10 +8045,37 @@ impl Default for NVME_IDENTIFY_CONTROLLER_DATA_6 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_CONTROLLER_DATA_7 {
     pub _bitfield: u8,
 }
+impl NVME_IDENTIFY_CONTROLLER_DATA_7 {
+    pub const fn Slot1ReadOnly(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_Slot1ReadOnly(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn SlotCount(&self) -> u8 {
+        (self._bitfield >> 1) & 7
+    }
+    pub fn set_SlotCount(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(7 << 1)) | ((value & 7) << 1);
+    }
+    pub const fn ActivationWithoutReset(&self) -> u8 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_ActivationWithoutReset(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 5) & 7
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(7 << 5)) | ((value & 7) << 5);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_CONTROLLER_DATA_7 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5176,10 +8085,25 @@ impl Default for NVME_IDENTIFY_CONTROLLER_DATA_7 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_CONTROLLER_DATA_8 {
     pub _bitfield: u16,
 }
+impl NVME_IDENTIFY_CONTROLLER_DATA_8 {
+    pub const fn CompareAndWrite(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_CompareAndWrite(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 1) & 32767
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(32767 << 1)) | ((value & 32767) << 1);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_CONTROLLER_DATA_8 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5189,10 +8113,25 @@ impl Default for NVME_IDENTIFY_CONTROLLER_DATA_8 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_CONTROLLER_DATA_9 {
     pub _bitfield: u16,
 }
+impl NVME_IDENTIFY_CONTROLLER_DATA_9 {
+    pub const fn Supported(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_Supported(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 1) & 32767
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(32767 << 1)) | ((value & 32767) << 1);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_CONTROLLER_DATA_9 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5202,10 +8141,61 @@ impl Default for NVME_IDENTIFY_CONTROLLER_DATA_9 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_CONTROLLER_DATA_10 {
     pub _bitfield: u8,
 }
+impl NVME_IDENTIFY_CONTROLLER_DATA_10 {
+    pub const fn SmartPagePerNamespace(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_SmartPagePerNamespace(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn CommandEffectsLog(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_CommandEffectsLog(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn LogPageExtendedData(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_LogPageExtendedData(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn TelemetrySupport(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_TelemetrySupport(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn PersistentEventLog(&self) -> u8 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_PersistentEventLog(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved0(&self) -> u8 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_Reserved0(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn TelemetryDataArea4(&self) -> u8 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_TelemetryDataArea4(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Reserved1(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Reserved1(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_CONTROLLER_DATA_10 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5215,10 +8205,25 @@ impl Default for NVME_IDENTIFY_CONTROLLER_DATA_10 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_CONTROLLER_DATA_11 {
     pub _bitfield: u8,
 }
+impl NVME_IDENTIFY_CONTROLLER_DATA_11 {
+    pub const fn CommandFormatInSpec(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_CommandFormatInSpec(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 1) & 127
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(127 << 1)) | ((value & 127) << 1);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_CONTROLLER_DATA_11 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5228,10 +8233,37 @@ impl Default for NVME_IDENTIFY_CONTROLLER_DATA_11 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_CONTROLLER_DATA_12 {
     pub _bitfield: u8,
 }
+impl NVME_IDENTIFY_CONTROLLER_DATA_12 {
+    pub const fn WriteProtect(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_WriteProtect(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn UntilPowerCycle(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_UntilPowerCycle(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Permanent(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Permanent(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 3) & 31
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(31 << 3)) | ((value & 31) << 3);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_CONTROLLER_DATA_12 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5241,10 +8273,79 @@ impl Default for NVME_IDENTIFY_CONTROLLER_DATA_12 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_CONTROLLER_DATA_13 {
     pub _bitfield: u16,
 }
+impl NVME_IDENTIFY_CONTROLLER_DATA_13 {
+    pub const fn SecurityCommands(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_SecurityCommands(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn FormatNVM(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_FormatNVM(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn FirmwareCommands(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_FirmwareCommands(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn NamespaceCommands(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_NamespaceCommands(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn DeviceSelfTest(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_DeviceSelfTest(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Directives(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_Directives(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn NVMeMICommands(&self) -> u16 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_NVMeMICommands(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn VirtualizationMgmt(&self) -> u16 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_VirtualizationMgmt(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn DoorBellBufferConfig(&self) -> u16 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_DoorBellBufferConfig(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn GetLBAStatus(&self) -> u16 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_GetLBAStatus(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 10) & 63
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(63 << 10)) | ((value & 63) << 10);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_CONTROLLER_DATA_13 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5254,10 +8355,79 @@ impl Default for NVME_IDENTIFY_CONTROLLER_DATA_13 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_CONTROLLER_DATA_14 {
     pub _bitfield: u32,
 }
+impl NVME_IDENTIFY_CONTROLLER_DATA_14 {
+    pub const fn Reserved0(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn NamespaceAttributeChanged(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_NamespaceAttributeChanged(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn FirmwareActivation(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_FirmwareActivation(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn AsymmetricAccessChanged(&self) -> u32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_AsymmetricAccessChanged(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn PredictableLatencyAggregateLogChanged(&self) -> u32 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_PredictableLatencyAggregateLogChanged(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn LbaStatusChanged(&self) -> u32 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_LbaStatusChanged(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn EnduranceGroupAggregateLogChanged(&self) -> u32 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_EnduranceGroupAggregateLogChanged(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn Reserved2(&self) -> u32 {
+        (self._bitfield >> 15) & 4095
+    }
+    pub fn set_Reserved2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(4095 << 15)) | ((value & 4095) << 15);
+    }
+    pub const fn ZoneInformation(&self) -> u32 {
+        (self._bitfield >> 27) & 1
+    }
+    pub fn set_ZoneInformation(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 27)) | ((value & 1) << 27);
+    }
+    pub const fn Reserved3(&self) -> u32 {
+        (self._bitfield >> 28) & 15
+    }
+    pub fn set_Reserved3(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 28)) | ((value & 15) << 28);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_CONTROLLER_DATA_14 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5267,10 +8437,67 @@ impl Default for NVME_IDENTIFY_CONTROLLER_DATA_14 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_CONTROLLER_DATA_15 {
     pub _bitfield: u16,
 }
+impl NVME_IDENTIFY_CONTROLLER_DATA_15 {
+    pub const fn Compare(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_Compare(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn WriteUncorrectable(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_WriteUncorrectable(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn DatasetManagement(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_DatasetManagement(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn WriteZeroes(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_WriteZeroes(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn FeatureField(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_FeatureField(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reservations(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_Reservations(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Timestamp(&self) -> u16 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_Timestamp(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Verify(&self) -> u16 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Verify(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_CONTROLLER_DATA_15 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5280,10 +8507,43 @@ impl Default for NVME_IDENTIFY_CONTROLLER_DATA_15 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_CONTROLLER_DATA_16 {
     pub _bitfield: u32,
 }
+impl NVME_IDENTIFY_CONTROLLER_DATA_16 {
+    pub const fn RPMBUnitCount(&self) -> u32 {
+        self._bitfield & 7
+    }
+    pub fn set_RPMBUnitCount(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn AuthenticationMethod(&self) -> u32 {
+        (self._bitfield >> 3) & 7
+    }
+    pub fn set_AuthenticationMethod(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 3)) | ((value & 7) << 3);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 6) & 1023
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1023 << 6)) | ((value & 1023) << 6);
+    }
+    pub const fn TotalSize(&self) -> u32 {
+        (self._bitfield >> 16) & 255
+    }
+    pub fn set_TotalSize(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 16)) | ((value & 255) << 16);
+    }
+    pub const fn AccessSize(&self) -> u32 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_AccessSize(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_CONTROLLER_DATA_16 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5293,10 +8553,109 @@ impl Default for NVME_IDENTIFY_CONTROLLER_DATA_16 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_CONTROLLER_DATA_17 {
     pub _bitfield: u16,
 }
+impl NVME_IDENTIFY_CONTROLLER_DATA_17 {
+    pub const fn ReadRecoveryLevel0(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_ReadRecoveryLevel0(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ReadRecoveryLevel1(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_ReadRecoveryLevel1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ReadRecoveryLevel2(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_ReadRecoveryLevel2(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn ReadRecoveryLevel3(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_ReadRecoveryLevel3(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn ReadRecoveryLevel4(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_ReadRecoveryLevel4(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn ReadRecoveryLevel5(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_ReadRecoveryLevel5(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn ReadRecoveryLevel6(&self) -> u16 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_ReadRecoveryLevel6(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn ReadRecoveryLevel7(&self) -> u16 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_ReadRecoveryLevel7(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn ReadRecoveryLevel8(&self) -> u16 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_ReadRecoveryLevel8(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn ReadRecoveryLevel9(&self) -> u16 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_ReadRecoveryLevel9(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn ReadRecoveryLevel10(&self) -> u16 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_ReadRecoveryLevel10(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn ReadRecoveryLevel11(&self) -> u16 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_ReadRecoveryLevel11(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn ReadRecoveryLevel12(&self) -> u16 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_ReadRecoveryLevel12(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn ReadRecoveryLevel13(&self) -> u16 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_ReadRecoveryLevel13(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn ReadRecoveryLevel14(&self) -> u16 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_ReadRecoveryLevel14(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn ReadRecoveryLevel15(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_ReadRecoveryLevel15(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_CONTROLLER_DATA_17 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5306,10 +8665,49 @@ impl Default for NVME_IDENTIFY_CONTROLLER_DATA_17 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_CONTROLLER_DATA_18 {
     pub _bitfield: u32,
 }
+impl NVME_IDENTIFY_CONTROLLER_DATA_18 {
+    pub const fn CryptoErase(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_CryptoErase(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn BlockErase(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_BlockErase(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Overwrite(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Overwrite(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 3) & 67108863
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(67108863 << 3)) | ((value & 67108863) << 3);
+    }
+    pub const fn NDI(&self) -> u32 {
+        (self._bitfield >> 29) & 1
+    }
+    pub fn set_NDI(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 29)) | ((value & 1) << 29);
+    }
+    pub const fn NODMMAS(&self) -> u32 {
+        (self._bitfield >> 30) & 3
+    }
+    pub fn set_NODMMAS(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 30)) | ((value & 3) << 30);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_CONTROLLER_DATA_18 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5319,10 +8717,73 @@ impl Default for NVME_IDENTIFY_CONTROLLER_DATA_18 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_CONTROLLER_DATA_19 {
     pub _bitfield: u32,
 }
+impl NVME_IDENTIFY_CONTROLLER_DATA_19 {
+    pub const fn SGLSupported(&self) -> u32 {
+        self._bitfield & 3
+    }
+    pub fn set_SGLSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn KeyedSGLData(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_KeyedSGLData(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 3) & 8191
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(8191 << 3)) | ((value & 8191) << 3);
+    }
+    pub const fn BitBucketDescrSupported(&self) -> u32 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_BitBucketDescrSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn ByteAlignedContiguousPhysicalBuffer(&self) -> u32 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_ByteAlignedContiguousPhysicalBuffer(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn SGLLengthLargerThanDataLength(&self) -> u32 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_SGLLengthLargerThanDataLength(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn MPTRSGLDescriptor(&self) -> u32 {
+        (self._bitfield >> 19) & 1
+    }
+    pub fn set_MPTRSGLDescriptor(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 19)) | ((value & 1) << 19);
+    }
+    pub const fn AddressFieldSGLDataBlock(&self) -> u32 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_AddressFieldSGLDataBlock(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn TransportSGLData(&self) -> u32 {
+        (self._bitfield >> 21) & 1
+    }
+    pub fn set_TransportSGLData(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 21)) | ((value & 1) << 21);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 22) & 1023
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1023 << 22)) | ((value & 1023) << 22);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_CONTROLLER_DATA_19 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5332,10 +8793,25 @@ impl Default for NVME_IDENTIFY_CONTROLLER_DATA_19 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_CONTROLLER_DATA_20 {
     pub _bitfield: u8,
 }
+impl NVME_IDENTIFY_CONTROLLER_DATA_20 {
+    pub const fn RequiredEntrySize(&self) -> u8 {
+        self._bitfield & 15
+    }
+    pub fn set_RequiredEntrySize(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn MaxEntrySize(&self) -> u8 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_MaxEntrySize(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_CONTROLLER_DATA_20 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5345,10 +8821,31 @@ impl Default for NVME_IDENTIFY_CONTROLLER_DATA_20 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_CONTROLLER_DATA_21 {
     pub _bitfield: u8,
 }
+impl NVME_IDENTIFY_CONTROLLER_DATA_21 {
+    pub const fn Present(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_Present(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn FlushBehavior(&self) -> u8 {
+        (self._bitfield >> 1) & 3
+    }
+    pub fn set_FlushBehavior(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(3 << 1)) | ((value & 3) << 1);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 3) & 31
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(31 << 3)) | ((value & 31) << 3);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_CONTROLLER_DATA_21 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5358,7 +8855,8 @@ impl Default for NVME_IDENTIFY_CONTROLLER_DATA_21 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_IO_COMMAND_SET {
     pub IOCommandSetVector: [u64; 512],
 }
@@ -5423,10 +8921,37 @@ impl Default for NVME_IDENTIFY_NAMESPACE_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_NAMESPACE_DATA_0 {
     pub _bitfield: u8,
 }
+impl NVME_IDENTIFY_NAMESPACE_DATA_0 {
+    pub const fn ReadBehavior(&self) -> u8 {
+        self._bitfield & 7
+    }
+    pub fn set_ReadBehavior(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn WriteZeroes(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_WriteZeroes(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn GuardFieldWithCRC(&self) -> u8 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_GuardFieldWithCRC(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 5) & 7
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(7 << 5)) | ((value & 7) << 5);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_NAMESPACE_DATA_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5436,10 +8961,49 @@ impl Default for NVME_IDENTIFY_NAMESPACE_DATA_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_NAMESPACE_DATA_1 {
     pub _bitfield: u8,
 }
+impl NVME_IDENTIFY_NAMESPACE_DATA_1 {
+    pub const fn ProtectionInfoType1(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_ProtectionInfoType1(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ProtectionInfoType2(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_ProtectionInfoType2(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ProtectionInfoType3(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_ProtectionInfoType3(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn InfoAtBeginningOfMetadata(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_InfoAtBeginningOfMetadata(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn InfoAtEndOfMetadata(&self) -> u8 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_InfoAtEndOfMetadata(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 5) & 7
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(7 << 5)) | ((value & 7) << 5);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_NAMESPACE_DATA_1 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5449,10 +9013,31 @@ impl Default for NVME_IDENTIFY_NAMESPACE_DATA_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_NAMESPACE_DATA_2 {
     pub _bitfield: u8,
 }
+impl NVME_IDENTIFY_NAMESPACE_DATA_2 {
+    pub const fn ProtectionInfoTypeEnabled(&self) -> u8 {
+        self._bitfield & 7
+    }
+    pub fn set_ProtectionInfoTypeEnabled(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn InfoAtBeginningOfMetadata(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_InfoAtBeginningOfMetadata(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_NAMESPACE_DATA_2 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5462,10 +9047,31 @@ impl Default for NVME_IDENTIFY_NAMESPACE_DATA_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_NAMESPACE_DATA_3 {
     pub _bitfield: u8,
 }
+impl NVME_IDENTIFY_NAMESPACE_DATA_3 {
+    pub const fn LbaFormatIndex(&self) -> u8 {
+        self._bitfield & 15
+    }
+    pub fn set_LbaFormatIndex(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn MetadataInExtendedDataLBA(&self) -> u8 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_MetadataInExtendedDataLBA(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 5) & 7
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(7 << 5)) | ((value & 7) << 5);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_NAMESPACE_DATA_3 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5475,10 +9081,25 @@ impl Default for NVME_IDENTIFY_NAMESPACE_DATA_3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_NAMESPACE_DATA_4 {
     pub _bitfield: u8,
 }
+impl NVME_IDENTIFY_NAMESPACE_DATA_4 {
+    pub const fn PercentageRemained(&self) -> u8 {
+        self._bitfield & 127
+    }
+    pub fn set_PercentageRemained(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !127) | (value & 127);
+    }
+    pub const fn Supported(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Supported(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_NAMESPACE_DATA_4 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5488,10 +9109,31 @@ impl Default for NVME_IDENTIFY_NAMESPACE_DATA_4 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_NAMESPACE_DATA_5 {
     pub _bitfield: u8,
 }
+impl NVME_IDENTIFY_NAMESPACE_DATA_5 {
+    pub const fn MetadataInExtendedDataLBA(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_MetadataInExtendedDataLBA(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn MetadataInSeparateBuffer(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_MetadataInSeparateBuffer(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 2) & 63
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(63 << 2)) | ((value & 63) << 2);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_NAMESPACE_DATA_5 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5501,10 +9143,25 @@ impl Default for NVME_IDENTIFY_NAMESPACE_DATA_5 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_NAMESPACE_DATA_6 {
     pub _bitfield: u8,
 }
+impl NVME_IDENTIFY_NAMESPACE_DATA_6 {
+    pub const fn SharedNameSpace(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_SharedNameSpace(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 1) & 127
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(127 << 1)) | ((value & 127) << 1);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_NAMESPACE_DATA_6 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5513,11 +9170,26 @@ impl Default for NVME_IDENTIFY_NAMESPACE_DATA_6 {
         unsafe { core::mem::zeroed() }
     }
 }
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub struct NVME_IDENTIFY_NAMESPACE_DATA_7 {
-    pub _bitfield: u8,
-}
+#[repr(C)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct NVME_IDENTIFY_NAMESPACE_DATA_7 {
+    pub _bitfield: u8,
+}
+impl NVME_IDENTIFY_NAMESPACE_DATA_7 {
+    pub const fn WriteProtected(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_WriteProtected(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 1) & 127
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(127 << 1)) | ((value & 127) << 1);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_NAMESPACE_DATA_7 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5527,10 +9199,49 @@ impl Default for NVME_IDENTIFY_NAMESPACE_DATA_7 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_NAMESPACE_DATA_8 {
     pub _bitfield: u8,
 }
+impl NVME_IDENTIFY_NAMESPACE_DATA_8 {
+    pub const fn ThinProvisioning(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_ThinProvisioning(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn NameSpaceAtomicWriteUnit(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_NameSpaceAtomicWriteUnit(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn DeallocatedOrUnwrittenError(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_DeallocatedOrUnwrittenError(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn SkipReuseUI(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_SkipReuseUI(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn NameSpaceIoOptimization(&self) -> u8 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_NameSpaceIoOptimization(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 5) & 7
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(7 << 5)) | ((value & 7) << 5);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_NAMESPACE_DATA_8 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5540,7 +9251,8 @@ impl Default for NVME_IDENTIFY_NAMESPACE_DATA_8 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_NAMESPACE_DESCRIPTOR {
     pub NIDT: u8,
     pub NIDL: u8,
@@ -5556,7 +9268,8 @@ impl Default for NVME_IDENTIFY_NAMESPACE_DESCRIPTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_NVM_SPECIFIC_CONTROLLER_IO_COMMAND_SET {
     pub VSL: u8,
     pub WZSL: u8,
@@ -5575,7 +9288,8 @@ impl Default for NVME_IDENTIFY_NVM_SPECIFIC_CONTROLLER_IO_COMMAND_SET {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_SPECIFIC_NAMESPACE_IO_COMMAND_SET {
     pub ZOC: NVME_IDENTIFY_SPECIFIC_NAMESPACE_IO_COMMAND_SET_1,
     pub OZCS: NVME_IDENTIFY_SPECIFIC_NAMESPACE_IO_COMMAND_SET_0,
@@ -5597,10 +9311,25 @@ impl Default for NVME_IDENTIFY_SPECIFIC_NAMESPACE_IO_COMMAND_SET {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_SPECIFIC_NAMESPACE_IO_COMMAND_SET_0 {
     pub _bitfield: u16,
 }
+impl NVME_IDENTIFY_SPECIFIC_NAMESPACE_IO_COMMAND_SET_0 {
+    pub const fn ReadAcrossZoneBoundaries(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_ReadAcrossZoneBoundaries(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 1) & 32767
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(32767 << 1)) | ((value & 32767) << 1);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_SPECIFIC_NAMESPACE_IO_COMMAND_SET_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5610,10 +9339,31 @@ impl Default for NVME_IDENTIFY_SPECIFIC_NAMESPACE_IO_COMMAND_SET_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_SPECIFIC_NAMESPACE_IO_COMMAND_SET_1 {
     pub _bitfield: u16,
 }
+impl NVME_IDENTIFY_SPECIFIC_NAMESPACE_IO_COMMAND_SET_1 {
+    pub const fn VariableZoneCapacity(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_VariableZoneCapacity(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ZoneExcursions(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_ZoneExcursions(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 2) & 16383
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(16383 << 2)) | ((value & 16383) << 2);
+    }
+}
 impl windows_core::TypeKind for NVME_IDENTIFY_SPECIFIC_NAMESPACE_IO_COMMAND_SET_1 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5623,7 +9373,8 @@ impl Default for NVME_IDENTIFY_SPECIFIC_NAMESPACE_IO_COMMAND_SET_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_IDENTIFY_ZNS_SPECIFIC_CONTROLLER_IO_COMMAND_SET {
     pub ZASL: u8,
     pub Reserved: [u8; 4095],
@@ -5651,12 +9402,27 @@ impl Default for NVME_LBA_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_LBA_FORMAT_0 {
     pub MS: u16,
     pub LBADS: u8,
     pub _bitfield: u8,
 }
+impl NVME_LBA_FORMAT_0 {
+    pub const fn RP(&self) -> u8 {
+        self._bitfield & 3
+    }
+    pub fn set_RP(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn Reserved0(&self) -> u8 {
+        (self._bitfield >> 2) & 63
+    }
+    pub fn set_Reserved0(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(63 << 2)) | ((value & 63) << 2);
+    }
+}
 impl windows_core::TypeKind for NVME_LBA_FORMAT_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5681,7 +9447,8 @@ impl Default for NVME_LBA_RANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_LBA_RANGET_TYPE_ENTRY {
     pub Type: u8,
     pub Attributes: NVME_LBA_RANGET_TYPE_ENTRY_0,
@@ -5700,10 +9467,31 @@ impl Default for NVME_LBA_RANGET_TYPE_ENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_LBA_RANGET_TYPE_ENTRY_0 {
     pub _bitfield: u8,
 }
+impl NVME_LBA_RANGET_TYPE_ENTRY_0 {
+    pub const fn MayOverwritten(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_MayOverwritten(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Hidden(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Hidden(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 2) & 63
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(63 << 2)) | ((value & 63) << 2);
+    }
+}
 impl windows_core::TypeKind for NVME_LBA_RANGET_TYPE_ENTRY_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5713,7 +9501,8 @@ impl Default for NVME_LBA_RANGET_TYPE_ENTRY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_LBA_ZONE_FORMAT {
     pub ZoneSize: u64,
     pub ZDES: u8,
@@ -5728,7 +9517,8 @@ impl Default for NVME_LBA_ZONE_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_NVM_SUBSYSTEM_RESET {
     pub NSSRC: u32,
 }
@@ -5784,6 +9574,32 @@ impl Default for NVME_OCP_DEVICE_CAPABILITIES_LOG_0 {
 pub struct NVME_OCP_DEVICE_CAPABILITIES_LOG_0_0 {
     pub _bitfield: u16,
 }
+impl NVME_OCP_DEVICE_CAPABILITIES_LOG_0_0 {
+    pub const fn Supported(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_Supported(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn AttribDeallocateSupported(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_AttribDeallocateSupported(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 2) & 8191
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(8191 << 2)) | ((value & 8191) << 2);
+    }
+    pub const fn CompliesWithSpec(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_CompliesWithSpec(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for NVME_OCP_DEVICE_CAPABILITIES_LOG_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5811,6 +9627,26 @@ impl Default for NVME_OCP_DEVICE_CAPABILITIES_LOG_1 {
 pub struct NVME_OCP_DEVICE_CAPABILITIES_LOG_1_0 {
     pub _bitfield: u16,
 }
+impl NVME_OCP_DEVICE_CAPABILITIES_LOG_1_0 {
+    pub const fn CWFusedSupported(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_CWFusedSupported(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 1) & 16383
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(16383 << 1)) | ((value & 16383) << 1);
+    }
+    pub const fn CompliesWithSpec(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_CompliesWithSpec(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for NVME_OCP_DEVICE_CAPABILITIES_LOG_1_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5838,6 +9674,38 @@ impl Default for NVME_OCP_DEVICE_CAPABILITIES_LOG_2 {
 pub struct NVME_OCP_DEVICE_CAPABILITIES_LOG_2_0 {
     pub _bitfield: u16,
 }
+impl NVME_OCP_DEVICE_CAPABILITIES_LOG_2_0 {
+    pub const fn MctpOverSMBusSupported(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_MctpOverSMBusSupported(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn MctpOverPcieVDMSupported(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_MctpOverPcieVDMSupported(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn BasicMgmtCommandSupported(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_BasicMgmtCommandSupported(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 3) & 4095
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(4095 << 3)) | ((value & 4095) << 3);
+    }
+    pub const fn CompliesWithSpec(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_CompliesWithSpec(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for NVME_OCP_DEVICE_CAPABILITIES_LOG_2_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5865,6 +9733,50 @@ impl Default for NVME_OCP_DEVICE_CAPABILITIES_LOG_3 {
 pub struct NVME_OCP_DEVICE_CAPABILITIES_LOG_3_0 {
     pub _bitfield: u16,
 }
+impl NVME_OCP_DEVICE_CAPABILITIES_LOG_3_0 {
+    pub const fn Supported(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_Supported(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn CryptoEraseSupported(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_CryptoEraseSupported(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn BlockEraseSupported(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_BlockEraseSupported(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn OverwriteSupported(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_OverwriteSupported(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn DeallocateLbaSupported(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_DeallocateLbaSupported(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 5) & 1023
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1023 << 5)) | ((value & 1023) << 5);
+    }
+    pub const fn CompliesWithSpec(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_CompliesWithSpec(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for NVME_OCP_DEVICE_CAPABILITIES_LOG_3_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5892,6 +9804,44 @@ impl Default for NVME_OCP_DEVICE_CAPABILITIES_LOG_4 {
 pub struct NVME_OCP_DEVICE_CAPABILITIES_LOG_4_0 {
     pub _bitfield: u16,
 }
+impl NVME_OCP_DEVICE_CAPABILITIES_LOG_4_0 {
+    pub const fn Supported(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_Supported(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn SingleLBASupported(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_SingleLBASupported(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn MaxLBASupported(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_MaxLBASupported(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn NvmeIo14Met(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_NvmeIo14Met(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 4) & 2047
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(2047 << 4)) | ((value & 2047) << 4);
+    }
+    pub const fn CompliesWithSpec(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_CompliesWithSpec(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for NVME_OCP_DEVICE_CAPABILITIES_LOG_4_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5919,6 +9869,50 @@ impl Default for NVME_OCP_DEVICE_CAPABILITIES_LOG_5 {
 pub struct NVME_OCP_DEVICE_CAPABILITIES_LOG_5_0 {
     pub _bitfield: u16,
 }
+impl NVME_OCP_DEVICE_CAPABILITIES_LOG_5_0 {
+    pub const fn Supported(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_Supported(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn DEACBitSupported(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_DEACBitSupported(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn FUABitSupported(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_FUABitSupported(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn NvmeIo5Met(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_NvmeIo5Met(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn NvmeIo6Met(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_NvmeIo6Met(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 5) & 1023
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1023 << 5)) | ((value & 1023) << 5);
+    }
+    pub const fn CompliesWithSpec(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_CompliesWithSpec(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for NVME_OCP_DEVICE_CAPABILITIES_LOG_5_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -6036,10 +10030,25 @@ impl Default for NVME_OCP_DEVICE_LATENCY_MONITOR_LOG_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_OCP_DEVICE_LATENCY_MONITOR_LOG_0_0 {
     pub _bitfield: u8,
 }
+impl NVME_OCP_DEVICE_LATENCY_MONITOR_LOG_0_0 {
+    pub const fn BasedOnTimestamp(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_BasedOnTimestamp(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 1) & 127
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(127 << 1)) | ((value & 127) << 1);
+    }
+}
 impl windows_core::TypeKind for NVME_OCP_DEVICE_LATENCY_MONITOR_LOG_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -6092,7 +10101,8 @@ impl Default for NVME_OCP_DEVICE_SMART_INFORMATION_LOG_V3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_OCP_DEVICE_SMART_INFORMATION_LOG_V3_0 {
     pub RawCount: [u8; 6],
     pub Normalized: [u8; 2],
@@ -6106,7 +10116,8 @@ impl Default for NVME_OCP_DEVICE_SMART_INFORMATION_LOG_V3_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_OCP_DEVICE_SMART_INFORMATION_LOG_V3_1 {
     pub RawCount: [u8; 6],
     pub Normalized: [u8; 2],
@@ -6134,7 +10145,8 @@ impl Default for NVME_OCP_DEVICE_SMART_INFORMATION_LOG_V3_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_OCP_DEVICE_SMART_INFORMATION_LOG_V3_3 {
     pub EventCount: u8,
     pub Status: u8,
@@ -6209,10 +10221,43 @@ impl Default for NVME_OCP_DEVICE_TCG_CONFIGURATION_LOG_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_OCP_DEVICE_TCG_CONFIGURATION_LOG_0_0 {
     pub _bitfield: u8,
 }
+impl NVME_OCP_DEVICE_TCG_CONFIGURATION_LOG_0_0 {
+    pub const fn CPINSIDValue(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_CPINSIDValue(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn CPINSIDBlocked(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_CPINSIDBlocked(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn LockingEnabled(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_LockingEnabled(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn SUMOwner(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_SUMOwner(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+}
 impl windows_core::TypeKind for NVME_OCP_DEVICE_TCG_CONFIGURATION_LOG_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -6309,7 +10354,8 @@ impl Default for NVME_PERSISTENT_EVENT_LOG_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_POWER_STATE_DESC {
     pub MP: u16,
     pub Reserved0: u8,
@@ -6327,6 +10373,104 @@ pub struct NVME_POWER_STATE_DESC {
     pub _bitfield7: u8,
     pub Reserved9: [u8; 9],
 }
+impl NVME_POWER_STATE_DESC {
+    pub const fn MPS(&self) -> u8 {
+        self._bitfield1 & 1
+    }
+    pub fn set_MPS(&mut self, value: u8) {
+        self._bitfield1 = (self._bitfield1 & !1) | (value & 1);
+    }
+    pub const fn NOPS(&self) -> u8 {
+        (self._bitfield1 >> 1) & 1
+    }
+    pub fn set_NOPS(&mut self, value: u8) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved1(&self) -> u8 {
+        (self._bitfield1 >> 2) & 63
+    }
+    pub fn set_Reserved1(&mut self, value: u8) {
+        self._bitfield1 = (self._bitfield1 & !(63 << 2)) | ((value & 63) << 2);
+    }
+    pub const fn RRT(&self) -> u8 {
+        self._bitfield2 & 31
+    }
+    pub fn set_RRT(&mut self, value: u8) {
+        self._bitfield2 = (self._bitfield2 & !31) | (value & 31);
+    }
+    pub const fn Reserved2(&self) -> u8 {
+        (self._bitfield2 >> 5) & 7
+    }
+    pub fn set_Reserved2(&mut self, value: u8) {
+        self._bitfield2 = (self._bitfield2 & !(7 << 5)) | ((value & 7) << 5);
+    }
+    pub const fn RRL(&self) -> u8 {
+        self._bitfield3 & 31
+    }
+    pub fn set_RRL(&mut self, value: u8) {
+        self._bitfield3 = (self._bitfield3 & !31) | (value & 31);
+    }
+    pub const fn Reserved3(&self) -> u8 {
+        (self._bitfield3 >> 5) & 7
+    }
+    pub fn set_Reserved3(&mut self, value: u8) {
+        self._bitfield3 = (self._bitfield3 & !(7 << 5)) | ((value & 7) << 5);
+    }
+    pub const fn RWT(&self) -> u8 {
+        self._bitfield4 & 31
+    }
+    pub fn set_RWT(&mut self, value: u8) {
+        self._bitfield4 = (self._bitfield4 & !31) | (value & 31);
+    }
+    pub const fn Reserved4(&self) -> u8 {
+        (self._bitfield4 >> 5) & 7
+    }
+    pub fn set_Reserved4(&mut self, value: u8) {
+        self._bitfield4 = (self._bitfield4 & !(7 << 5)) | ((value & 7) << 5);
+    }
+    pub const fn RWL(&self) -> u8 {
+        self._bitfield5 & 31
+    }
+    pub fn set_RWL(&mut self, value: u8) {
+        self._bitfield5 = (self._bitfield5 & !31) | (value & 31);
+    }
+    pub const fn Reserved5(&self) -> u8 {
+        (self._bitfield5 >> 5) & 7
+    }
+    pub fn set_Reserved5(&mut self, value: u8) {
+        self._bitfield5 = (self._bitfield5 & !(7 << 5)) | ((value & 7) << 5);
+    }
+    pub const fn Reserved6(&self) -> u8 {
+        self._bitfield6 & 63
+    }
+    pub fn set_Reserved6(&mut self, value: u8) {
+        self._bitfield6 = (self._bitfield6 & !63) | (value & 63);
+    }
+    pub const fn IPS(&self) -> u8 {
+        (self._bitfield6 >> 6) & 3
+    }
+    pub fn set_IPS(&mut self, value: u8) {
+        self._bitfield6 = (self._bitfield6 & !(3 << 6)) | ((value & 3) << 6);
+    }
+    pub const fn APW(&self) -> u8 {
+        self._bitfield7 & 7
+    }
+    pub fn set_APW(&mut self, value: u8) {
+        self._bitfield7 = (self._bitfield7 & !7) | (value & 7);
+    }
+    pub const fn Reserved8(&self) -> u8 {
+        (self._bitfield7 >> 3) & 7
+    }
+    pub fn set_Reserved8(&mut self, value: u8) {
+        self._bitfield7 = (self._bitfield7 & !(7 << 3)) | ((value & 7) << 3);
+    }
+    pub const fn APS(&self) -> u8 {
+        (self._bitfield7 >> 6) & 3
+    }
+    pub fn set_APS(&mut self, value: u8) {
+        self._bitfield7 = (self._bitfield7 & !(3 << 6)) | ((value & 3) << 6);
+    }
+}
 impl windows_core::TypeKind for NVME_POWER_STATE_DESC {
     type TypeKind = windows_core::CopyType;
 }
@@ -6350,10 +10494,25 @@ impl Default for NVME_PRP_ENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_PRP_ENTRY_0 {
     pub _bitfield: u64,
 }
+impl NVME_PRP_ENTRY_0 {
+    pub const fn Reserved0(&self) -> u64 {
+        self._bitfield & 3
+    }
+    pub fn set_Reserved0(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn PBAO(&self) -> u64 {
+        (self._bitfield >> 2) & 4611686018427387903
+    }
+    pub fn set_PBAO(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(4611686018427387903 << 2)) | ((value & 4611686018427387903) << 2);
+    }
+}
 impl windows_core::TypeKind for NVME_PRP_ENTRY_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -6363,7 +10522,8 @@ impl Default for NVME_PRP_ENTRY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_REGISTERED_CONTROLLER_DATA {
     pub CNTLID: u16,
     pub RCSTS: NVME_REGISTERED_CONTROLLER_DATA_0,
@@ -6380,10 +10540,25 @@ impl Default for NVME_REGISTERED_CONTROLLER_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_REGISTERED_CONTROLLER_DATA_0 {
     pub _bitfield: u8,
 }
+impl NVME_REGISTERED_CONTROLLER_DATA_0 {
+    pub const fn HoldReservation(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_HoldReservation(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 1) & 127
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(127 << 1)) | ((value & 127) << 1);
+    }
+}
 impl windows_core::TypeKind for NVME_REGISTERED_CONTROLLER_DATA_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -6393,7 +10568,8 @@ impl Default for NVME_REGISTERED_CONTROLLER_DATA_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_REGISTERED_CONTROLLER_EXTENDED_DATA {
     pub CNTLID: u16,
     pub RCSTS: NVME_REGISTERED_CONTROLLER_EXTENDED_DATA_0,
@@ -6411,10 +10587,25 @@ impl Default for NVME_REGISTERED_CONTROLLER_EXTENDED_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_REGISTERED_CONTROLLER_EXTENDED_DATA_0 {
     pub _bitfield: u8,
 }
+impl NVME_REGISTERED_CONTROLLER_EXTENDED_DATA_0 {
+    pub const fn HoldReservation(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_HoldReservation(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 1) & 127
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(127 << 1)) | ((value & 127) << 1);
+    }
+}
 impl windows_core::TypeKind for NVME_REGISTERED_CONTROLLER_EXTENDED_DATA_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -6424,7 +10615,8 @@ impl Default for NVME_REGISTERED_CONTROLLER_EXTENDED_DATA_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_REPORT_ZONE_INFO {
     pub ZoneCount: u64,
     pub Reserved: [u64; 7],
@@ -6439,7 +10631,8 @@ impl Default for NVME_REPORT_ZONE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_RESERVATION_ACQUIRE_DATA_STRUCTURE {
     pub CRKEY: u64,
     pub PRKEY: u64,
@@ -6453,7 +10646,8 @@ impl Default for NVME_RESERVATION_ACQUIRE_DATA_STRUCTURE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_RESERVATION_NOTIFICATION_LOG {
     pub LogPageCount: u64,
     pub LogPageType: u8,
@@ -6471,7 +10665,8 @@ impl Default for NVME_RESERVATION_NOTIFICATION_LOG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_RESERVATION_REGISTER_DATA_STRUCTURE {
     pub CRKEY: u64,
     pub NRKEY: u64,
@@ -6485,7 +10680,8 @@ impl Default for NVME_RESERVATION_REGISTER_DATA_STRUCTURE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_RESERVATION_RELEASE_DATA_STRUCTURE {
     pub CRKEY: u64,
 }
@@ -6545,10 +10741,37 @@ impl Default for NVME_RESERVATION_REPORT_STATUS_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_SANITIZE_STATUS {
     pub _bitfield: u16,
 }
+impl NVME_SANITIZE_STATUS {
+    pub const fn MostRecentSanitizeOperationStatus(&self) -> u16 {
+        self._bitfield & 7
+    }
+    pub fn set_MostRecentSanitizeOperationStatus(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn NumberCompletedPassesOfOverwrite(&self) -> u16 {
+        (self._bitfield >> 3) & 15
+    }
+    pub fn set_NumberCompletedPassesOfOverwrite(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(15 << 3)) | ((value & 15) << 3);
+    }
+    pub const fn GlobalDataErased(&self) -> u16 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_GlobalDataErased(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+}
 impl windows_core::TypeKind for NVME_SANITIZE_STATUS {
     type TypeKind = windows_core::CopyType;
 }
@@ -6558,7 +10781,8 @@ impl Default for NVME_SANITIZE_STATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_SANITIZE_STATUS_LOG {
     pub SPROG: u16,
     pub SSTAT: NVME_SANITIZE_STATUS,
@@ -6580,7 +10804,8 @@ impl Default for NVME_SANITIZE_STATUS_LOG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_SCSI_NAME_STRING {
     pub PCIVendorID: [i8; 4],
     pub ModelNumber: [i8; 40],
@@ -6596,7 +10821,8 @@ impl Default for NVME_SCSI_NAME_STRING {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_SET_ATTRIBUTES_ENTRY {
     pub Identifier: u16,
     pub ENDGID: u16,
@@ -6630,10 +10856,25 @@ impl Default for NVME_SUBMISSION_QUEUE_TAIL_DOORBELL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_SUBMISSION_QUEUE_TAIL_DOORBELL_0 {
     pub _bitfield: u32,
 }
+impl NVME_SUBMISSION_QUEUE_TAIL_DOORBELL_0 {
+    pub const fn SQT(&self) -> u32 {
+        self._bitfield & 65535
+    }
+    pub fn set_SQT(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for NVME_SUBMISSION_QUEUE_TAIL_DOORBELL_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -6643,7 +10884,8 @@ impl Default for NVME_SUBMISSION_QUEUE_TAIL_DOORBELL_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_TELEMETRY_CONTROLLER_INITIATED_LOG {
     pub LogIdentifier: u8,
     pub Reserved0: [u8; 4],
@@ -6667,7 +10909,8 @@ impl Default for NVME_TELEMETRY_CONTROLLER_INITIATED_LOG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_TELEMETRY_HOST_INITIATED_LOG {
     pub LogIdentifier: u8,
     pub Reserved0: [u8; 4],
@@ -6706,10 +10949,31 @@ impl Default for NVME_VERSION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_VERSION_0 {
     pub _bitfield: u32,
 }
+impl NVME_VERSION_0 {
+    pub const fn TER(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_TER(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn MNR(&self) -> u32 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_MNR(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn MJR(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_MJR(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for NVME_VERSION_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -6746,10 +11010,31 @@ impl Default for NVME_WCS_DEVICE_CAPABILITIES_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_WCS_DEVICE_CAPABILITIES_0_0 {
     pub _bitfield: u32,
 }
+impl NVME_WCS_DEVICE_CAPABILITIES_0_0 {
+    pub const fn PanicAEN(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_PanicAEN(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn PanicCFS(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_PanicCFS(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 2) & 1073741823
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1073741823 << 2)) | ((value & 1073741823) << 2);
+    }
+}
 impl windows_core::TypeKind for NVME_WCS_DEVICE_CAPABILITIES_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -6810,10 +11095,55 @@ impl Default for NVME_WCS_DEVICE_RESET_ACTION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_WCS_DEVICE_RESET_ACTION_0_0 {
     pub _bitfield: u8,
 }
+impl NVME_WCS_DEVICE_RESET_ACTION_0_0 {
+    pub const fn ControllerReset(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_ControllerReset(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn NVMeSubsystemReset(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_NVMeSubsystemReset(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn PCIeFLR(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_PCIeFLR(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn PERST(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_PERST(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn PowerCycle(&self) -> u8 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_PowerCycle(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn PCIeConventionalHotReset(&self) -> u8 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_PCIeConventionalHotReset(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+}
 impl windows_core::TypeKind for NVME_WCS_DEVICE_RESET_ACTION_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -6878,7 +11208,8 @@ impl Default for NVME_WCS_DEVICE_SMART_ATTRIBUTES_LOG_V2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_WCS_DEVICE_SMART_ATTRIBUTES_LOG_V2_0 {
     pub RawCount: [u8; 6],
     pub Normalized: [u8; 2],
@@ -6892,7 +11223,8 @@ impl Default for NVME_WCS_DEVICE_SMART_ATTRIBUTES_LOG_V2_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_WCS_DEVICE_SMART_ATTRIBUTES_LOG_V2_1 {
     pub RawCount: [u8; 6],
     pub Normalized: [u8; 2],
@@ -6920,7 +11252,8 @@ impl Default for NVME_WCS_DEVICE_SMART_ATTRIBUTES_LOG_V2_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_WCS_DEVICE_SMART_ATTRIBUTES_LOG_V2_3 {
     pub EventCount: u8,
     pub Status: u8,
@@ -6948,7 +11281,8 @@ impl Default for NVME_WCS_DEVICE_SMART_ATTRIBUTES_LOG_V2_4 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_ZONE_DESCRIPTOR {
     pub Anonymous1: NVME_ZONE_DESCRIPTOR_0,
     pub Anonymous2: NVME_ZONE_DESCRIPTOR_1,
@@ -6968,10 +11302,25 @@ impl Default for NVME_ZONE_DESCRIPTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_ZONE_DESCRIPTOR_0 {
     pub _bitfield: u8,
 }
+impl NVME_ZONE_DESCRIPTOR_0 {
+    pub const fn ZT(&self) -> u8 {
+        self._bitfield & 15
+    }
+    pub fn set_ZT(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn Reserved1(&self) -> u8 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_Reserved1(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+}
 impl windows_core::TypeKind for NVME_ZONE_DESCRIPTOR_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -6981,10 +11330,25 @@ impl Default for NVME_ZONE_DESCRIPTOR_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_ZONE_DESCRIPTOR_1 {
     pub _bitfield: u8,
 }
+impl NVME_ZONE_DESCRIPTOR_1 {
+    pub const fn Reserved2(&self) -> u8 {
+        self._bitfield & 15
+    }
+    pub fn set_Reserved2(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn ZS(&self) -> u8 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_ZS(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+}
 impl windows_core::TypeKind for NVME_ZONE_DESCRIPTOR_1 {
     type TypeKind = windows_core::CopyType;
 }
@@ -6994,10 +11358,43 @@ impl Default for NVME_ZONE_DESCRIPTOR_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_ZONE_DESCRIPTOR_2 {
     pub _bitfield: u8,
 }
+impl NVME_ZONE_DESCRIPTOR_2 {
+    pub const fn ZFC(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_ZFC(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn FZR(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_FZR(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn RZR(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_RZR(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 3) & 15
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(15 << 3)) | ((value & 15) << 3);
+    }
+    pub const fn ZDEV(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_ZDEV(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for NVME_ZONE_DESCRIPTOR_2 {
     type TypeKind = windows_core::CopyType;
 }
@@ -7007,7 +11404,8 @@ impl Default for NVME_ZONE_DESCRIPTOR_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_ZONE_DESCRIPTOR_EXTENSION {
     pub ZoneDescriptorExtensionInfo: [u8; 64],
 }
@@ -7020,7 +11418,8 @@ impl Default for NVME_ZONE_DESCRIPTOR_EXTENSION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVME_ZONE_EXTENDED_REPORT_ZONE_DESC {
     pub ZoneDescriptor: NVME_ZONE_DESCRIPTOR,
     pub ZoneDescriptorExtension: [NVME_ZONE_DESCRIPTOR_EXTENSION; 1],
@@ -7048,10 +11447,61 @@ impl Default for NVM_RESERVATION_CAPABILITIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVM_RESERVATION_CAPABILITIES_0 {
     pub _bitfield: u8,
 }
+impl NVM_RESERVATION_CAPABILITIES_0 {
+    pub const fn PersistThroughPowerLoss(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_PersistThroughPowerLoss(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn WriteExclusiveReservation(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_WriteExclusiveReservation(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ExclusiveAccessReservation(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_ExclusiveAccessReservation(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn WriteExclusiveRegistrantsOnlyReservation(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_WriteExclusiveRegistrantsOnlyReservation(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn ExclusiveAccessRegistrantsOnlyReservation(&self) -> u8 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_ExclusiveAccessRegistrantsOnlyReservation(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn WriteExclusiveAllRegistrantsReservation(&self) -> u8 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_WriteExclusiveAllRegistrantsReservation(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn ExclusiveAccessAllRegistrantsReservation(&self) -> u8 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_ExclusiveAccessAllRegistrantsReservation(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for NVM_RESERVATION_CAPABILITIES_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -7061,7 +11511,8 @@ impl Default for NVM_RESERVATION_CAPABILITIES_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NVM_SET_LIST {
     pub IdentifierCount: u8,
     pub Reserved: [u8; 127],
@@ -7076,7 +11527,8 @@ impl Default for NVM_SET_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TCG_ACTIVATE_METHOD_SPECIFIC {
     pub RangeStartLengthPolicy: u8,
 }
@@ -7116,7 +11568,8 @@ impl Default for TCG_AUTH_METHOD_SPECIFIC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TCG_BLOCKSID_METHOD_SPECIFIC {
     pub ClearEvents: u8,
 }
@@ -7153,7 +11606,8 @@ impl Default for TCG_HISTORY_ENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TCG_REACTIVATE_METHOD_SPECIFIC {
     pub RangeStartLengthPolicy: u8,
 }
@@ -7166,7 +11620,8 @@ impl Default for TCG_REACTIVATE_METHOD_SPECIFIC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UNSUPPORTED_REQUIREMENT {
     pub ReqId: [u8; 16],
 }
@@ -1541,6 +1541,7 @@ pub struct OFFLINEFILES_CACHING_MODE(pub i32);
 impl windows_core::TypeKind for OFFLINEFILES_CACHING_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OFFLINEFILES_CACHING_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OFFLINEFILES_CACHING_MODE").field(&self.0).finish()
@@ -1552,6 +1553,7 @@ pub struct OFFLINEFILES_COMPARE(pub i32);
 impl windows_core::TypeKind for OFFLINEFILES_COMPARE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OFFLINEFILES_COMPARE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OFFLINEFILES_COMPARE").field(&self.0).finish()
@@ -1563,6 +1565,7 @@ pub struct OFFLINEFILES_CONNECT_STATE(pub i32);
 impl windows_core::TypeKind for OFFLINEFILES_CONNECT_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OFFLINEFILES_CONNECT_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OFFLINEFILES_CONNECT_STATE").field(&self.0).finish()
@@ -1574,6 +1577,7 @@ pub struct OFFLINEFILES_EVENTS(pub i32);
 impl windows_core::TypeKind for OFFLINEFILES_EVENTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OFFLINEFILES_EVENTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OFFLINEFILES_EVENTS").field(&self.0).finish()
@@ -1585,6 +1589,7 @@ pub struct OFFLINEFILES_ITEM_COPY(pub i32);
 impl windows_core::TypeKind for OFFLINEFILES_ITEM_COPY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OFFLINEFILES_ITEM_COPY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OFFLINEFILES_ITEM_COPY").field(&self.0).finish()
@@ -1596,6 +1601,7 @@ pub struct OFFLINEFILES_ITEM_TIME(pub i32);
 impl windows_core::TypeKind for OFFLINEFILES_ITEM_TIME {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OFFLINEFILES_ITEM_TIME {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OFFLINEFILES_ITEM_TIME").field(&self.0).finish()
@@ -1607,6 +1613,7 @@ pub struct OFFLINEFILES_ITEM_TYPE(pub i32);
 impl windows_core::TypeKind for OFFLINEFILES_ITEM_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OFFLINEFILES_ITEM_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OFFLINEFILES_ITEM_TYPE").field(&self.0).finish()
@@ -1618,6 +1625,7 @@ pub struct OFFLINEFILES_OFFLINE_REASON(pub i32);
 impl windows_core::TypeKind for OFFLINEFILES_OFFLINE_REASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OFFLINEFILES_OFFLINE_REASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OFFLINEFILES_OFFLINE_REASON").field(&self.0).finish()
@@ -1629,6 +1637,7 @@ pub struct OFFLINEFILES_OP_RESPONSE(pub i32);
 impl windows_core::TypeKind for OFFLINEFILES_OP_RESPONSE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OFFLINEFILES_OP_RESPONSE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OFFLINEFILES_OP_RESPONSE").field(&self.0).finish()
@@ -1640,6 +1649,7 @@ pub struct OFFLINEFILES_PATHFILTER_MATCH(pub i32);
 impl windows_core::TypeKind for OFFLINEFILES_PATHFILTER_MATCH {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OFFLINEFILES_PATHFILTER_MATCH {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OFFLINEFILES_PATHFILTER_MATCH").field(&self.0).finish()
@@ -1651,6 +1661,7 @@ pub struct OFFLINEFILES_SETTING_VALUE_TYPE(pub i32);
 impl windows_core::TypeKind for OFFLINEFILES_SETTING_VALUE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OFFLINEFILES_SETTING_VALUE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OFFLINEFILES_SETTING_VALUE_TYPE").field(&self.0).finish()
@@ -1662,6 +1673,7 @@ pub struct OFFLINEFILES_SYNC_CONFLICT_RESOLVE(pub i32);
 impl windows_core::TypeKind for OFFLINEFILES_SYNC_CONFLICT_RESOLVE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OFFLINEFILES_SYNC_CONFLICT_RESOLVE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OFFLINEFILES_SYNC_CONFLICT_RESOLVE").field(&self.0).finish()
@@ -1673,6 +1685,7 @@ pub struct OFFLINEFILES_SYNC_OPERATION(pub i32);
 impl windows_core::TypeKind for OFFLINEFILES_SYNC_OPERATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OFFLINEFILES_SYNC_OPERATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OFFLINEFILES_SYNC_OPERATION").field(&self.0).finish()
@@ -1684,6 +1697,7 @@ pub struct OFFLINEFILES_SYNC_STATE(pub i32);
 impl windows_core::TypeKind for OFFLINEFILES_SYNC_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OFFLINEFILES_SYNC_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OFFLINEFILES_SYNC_STATE").field(&self.0).finish()
@@ -1693,3 +1707,5 @@ pub const OfflineFilesCache: windows_core::GUID = windows_core::GUID::from_u128(
 pub const OfflineFilesSetting: windows_core::GUID = windows_core::GUID::from_u128(0xfd3659e9_a920_4123_ad64_7fc76c7aacdf);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,835 @@
+impl windows_core::imp::ProxyVtable for IEnumOfflineFilesItems_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumOfflineFilesItems", "Next", 3usize, |this| ((**(this as *const *const IEnumOfflineFilesItems_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumOfflineFilesItems", "Skip", 4usize, |this| ((**(this as *const *const IEnumOfflineFilesItems_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumOfflineFilesItems", "Reset", 5usize, |this| ((**(this as *const *const IEnumOfflineFilesItems_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumOfflineFilesItems", "Clone", 6usize, |this| ((**(this as *const *const IEnumOfflineFilesItems_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumOfflineFilesItems as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumOfflineFilesSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumOfflineFilesSettings", "Next", 3usize, |this| ((**(this as *const *const IEnumOfflineFilesSettings_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumOfflineFilesSettings", "Skip", 4usize, |this| ((**(this as *const *const IEnumOfflineFilesSettings_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumOfflineFilesSettings", "Reset", 5usize, |this| ((**(this as *const *const IEnumOfflineFilesSettings_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumOfflineFilesSettings", "Clone", 6usize, |this| ((**(this as *const *const IEnumOfflineFilesSettings_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumOfflineFilesSettings as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesCache_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Synchronize(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, rgpszpaths: *const windows_core::PCWSTR, cpaths: u32, basync: super::super::Foundation::BOOL, dwsynccontrol: u32, pisyncconflicthandler: *mut core::ffi::c_void, piprogress: *mut core::ffi::c_void, psyncid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesCache", "Synchronize", 3usize, |this| ((**(this as *const *const IOfflineFilesCache_Vtbl)).Synchronize)(this, hwndparent, rgpszpaths, cpaths, basync, dwsynccontrol, pisyncconflicthandler, piprogress, psyncid), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteItems(this: *mut core::ffi::c_void, rgpszpaths: *const windows_core::PCWSTR, cpaths: u32, dwflags: u32, basync: super::super::Foundation::BOOL, piprogress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesCache", "DeleteItems", 4usize, |this| ((**(this as *const *const IOfflineFilesCache_Vtbl)).DeleteItems)(this, rgpszpaths, cpaths, dwflags, basync, piprogress), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteItemsForUser(this: *mut core::ffi::c_void, pszuser: windows_core::PCWSTR, rgpszpaths: *const windows_core::PCWSTR, cpaths: u32, dwflags: u32, basync: super::super::Foundation::BOOL, piprogress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesCache", "DeleteItemsForUser", 5usize, |this| ((**(this as *const *const IOfflineFilesCache_Vtbl)).DeleteItemsForUser)(this, pszuser, rgpszpaths, cpaths, dwflags, basync, piprogress), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pin(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, rgpszpaths: *const windows_core::PCWSTR, cpaths: u32, bdeep: super::super::Foundation::BOOL, basync: super::super::Foundation::BOOL, dwpincontrolflags: u32, piprogress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesCache", "Pin", 6usize, |this| ((**(this as *const *const IOfflineFilesCache_Vtbl)).Pin)(this, hwndparent, rgpszpaths, cpaths, bdeep, basync, dwpincontrolflags, piprogress), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unpin(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, rgpszpaths: *const windows_core::PCWSTR, cpaths: u32, bdeep: super::super::Foundation::BOOL, basync: super::super::Foundation::BOOL, dwpincontrolflags: u32, piprogress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesCache", "Unpin", 7usize, |this| ((**(this as *const *const IOfflineFilesCache_Vtbl)).Unpin)(this, hwndparent, rgpszpaths, cpaths, bdeep, basync, dwpincontrolflags, piprogress), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEncryptionStatus(this: *mut core::ffi::c_void, pbencrypted: *mut super::super::Foundation::BOOL, pbpartial: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesCache", "GetEncryptionStatus", 8usize, |this| ((**(this as *const *const IOfflineFilesCache_Vtbl)).GetEncryptionStatus)(this, pbencrypted, pbpartial), |result| Some(*result))
+        }
+        unsafe extern "system" fn Encrypt(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, bencrypt: super::super::Foundation::BOOL, dwencryptioncontrolflags: u32, basync: super::super::Foundation::BOOL, piprogress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesCache", "Encrypt", 9usize, |this| ((**(this as *const *const IOfflineFilesCache_Vtbl)).Encrypt)(this, hwndparent, bencrypt, dwencryptioncontrolflags, basync, piprogress), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindItem(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, dwqueryflags: u32, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesCache", "FindItem", 10usize, |this| ((**(this as *const *const IOfflineFilesCache_Vtbl)).FindItem)(this, pszpath, dwqueryflags, ppitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindItemEx(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, pincludefilefilter: *mut core::ffi::c_void, pincludedirfilter: *mut core::ffi::c_void, pexcludefilefilter: *mut core::ffi::c_void, pexcludedirfilter: *mut core::ffi::c_void, dwqueryflags: u32, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesCache", "FindItemEx", 11usize, |this| ((**(this as *const *const IOfflineFilesCache_Vtbl)).FindItemEx)(this, pszpath, pincludefilefilter, pincludedirfilter, pexcludefilefilter, pexcludedirfilter, dwqueryflags, ppitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn RenameItem(this: *mut core::ffi::c_void, pszpathoriginal: windows_core::PCWSTR, pszpathnew: windows_core::PCWSTR, breplaceifexists: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesCache", "RenameItem", 12usize, |this| ((**(this as *const *const IOfflineFilesCache_Vtbl)).RenameItem)(this, pszpathoriginal, pszpathnew, breplaceifexists), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocation(this: *mut core::ffi::c_void, ppszpath: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesCache", "GetLocation", 13usize, |this| ((**(this as *const *const IOfflineFilesCache_Vtbl)).GetLocation)(this, ppszpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDiskSpaceInformation(this: *mut core::ffi::c_void, pcbvolumetotal: *mut u64, pcblimit: *mut u64, pcbused: *mut u64, pcbunpinnedlimit: *mut u64, pcbunpinnedused: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesCache", "GetDiskSpaceInformation", 14usize, |this| ((**(this as *const *const IOfflineFilesCache_Vtbl)).GetDiskSpaceInformation)(this, pcbvolumetotal, pcblimit, pcbused, pcbunpinnedlimit, pcbunpinnedused), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDiskSpaceLimits(this: *mut core::ffi::c_void, cblimit: u64, cbunpinnedlimit: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesCache", "SetDiskSpaceLimits", 15usize, |this| ((**(this as *const *const IOfflineFilesCache_Vtbl)).SetDiskSpaceLimits)(this, cblimit, cbunpinnedlimit), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessAdminPinPolicy(this: *mut core::ffi::c_void, ppinprogress: *mut core::ffi::c_void, punpinprogress: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesCache", "ProcessAdminPinPolicy", 16usize, |this| ((**(this as *const *const IOfflineFilesCache_Vtbl)).ProcessAdminPinPolicy)(this, ppinprogress, punpinprogress), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSettingObject(this: *mut core::ffi::c_void, pszsettingname: windows_core::PCWSTR, ppsetting: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesCache", "GetSettingObject", 17usize, |this| ((**(this as *const *const IOfflineFilesCache_Vtbl)).GetSettingObject)(this, pszsettingname, ppsetting), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumSettingObjects(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesCache", "EnumSettingObjects", 18usize, |this| ((**(this as *const *const IOfflineFilesCache_Vtbl)).EnumSettingObjects)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPathCacheable(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, pbcacheable: *mut super::super::Foundation::BOOL, psharecachingmode: *mut OFFLINEFILES_CACHING_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesCache", "IsPathCacheable", 19usize, |this| ((**(this as *const *const IOfflineFilesCache_Vtbl)).IsPathCacheable)(this, pszpath, pbcacheable, psharecachingmode), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Synchronize,
+            DeleteItems,
+            DeleteItemsForUser,
+            Pin,
+            Unpin,
+            GetEncryptionStatus,
+            Encrypt,
+            FindItem,
+            FindItemEx,
+            RenameItem,
+            GetLocation,
+            GetDiskSpaceInformation,
+            SetDiskSpaceLimits,
+            ProcessAdminPinPolicy,
+            GetSettingObject,
+            EnumSettingObjects,
+            IsPathCacheable,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesCache as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesCache2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RenameItemEx(this: *mut core::ffi::c_void, pszpathoriginal: windows_core::PCWSTR, pszpathnew: windows_core::PCWSTR, breplaceifexists: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesCache2", "RenameItemEx", 20usize, |this| ((**(this as *const *const IOfflineFilesCache2_Vtbl)).RenameItemEx)(this, pszpathoriginal, pszpathnew, breplaceifexists), |result| Some(*result))
+        }
+        Self { base__: <IOfflineFilesCache_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RenameItemEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesCache2 as windows_core::Interface>::IID || <IOfflineFilesCache_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesChangeInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsDirty(this: *mut core::ffi::c_void, pbdirty: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesChangeInfo", "IsDirty", 3usize, |this| ((**(this as *const *const IOfflineFilesChangeInfo_Vtbl)).IsDirty)(this, pbdirty), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDeletedOffline(this: *mut core::ffi::c_void, pbdeletedoffline: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesChangeInfo", "IsDeletedOffline", 4usize, |this| ((**(this as *const *const IOfflineFilesChangeInfo_Vtbl)).IsDeletedOffline)(this, pbdeletedoffline), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCreatedOffline(this: *mut core::ffi::c_void, pbcreatedoffline: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesChangeInfo", "IsCreatedOffline", 5usize, |this| ((**(this as *const *const IOfflineFilesChangeInfo_Vtbl)).IsCreatedOffline)(this, pbcreatedoffline), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLocallyModifiedData(this: *mut core::ffi::c_void, pblocallymodifieddata: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesChangeInfo", "IsLocallyModifiedData", 6usize, |this| ((**(this as *const *const IOfflineFilesChangeInfo_Vtbl)).IsLocallyModifiedData)(this, pblocallymodifieddata), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLocallyModifiedAttributes(this: *mut core::ffi::c_void, pblocallymodifiedattributes: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesChangeInfo", "IsLocallyModifiedAttributes", 7usize, |this| ((**(this as *const *const IOfflineFilesChangeInfo_Vtbl)).IsLocallyModifiedAttributes)(this, pblocallymodifiedattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLocallyModifiedTime(this: *mut core::ffi::c_void, pblocallymodifiedtime: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesChangeInfo", "IsLocallyModifiedTime", 8usize, |this| ((**(this as *const *const IOfflineFilesChangeInfo_Vtbl)).IsLocallyModifiedTime)(this, pblocallymodifiedtime), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsDirty,
+            IsDeletedOffline,
+            IsCreatedOffline,
+            IsLocallyModifiedData,
+            IsLocallyModifiedAttributes,
+            IsLocallyModifiedTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesChangeInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesConnectionInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetConnectState(this: *mut core::ffi::c_void, pconnectstate: *mut OFFLINEFILES_CONNECT_STATE, pofflinereason: *mut OFFLINEFILES_OFFLINE_REASON) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesConnectionInfo", "GetConnectState", 3usize, |this| ((**(this as *const *const IOfflineFilesConnectionInfo_Vtbl)).GetConnectState)(this, pconnectstate, pofflinereason), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConnectState(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, dwflags: u32, connectstate: OFFLINEFILES_CONNECT_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesConnectionInfo", "SetConnectState", 4usize, |this| ((**(this as *const *const IOfflineFilesConnectionInfo_Vtbl)).SetConnectState)(this, hwndparent, dwflags, connectstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransitionOnline(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesConnectionInfo", "TransitionOnline", 5usize, |this| ((**(this as *const *const IOfflineFilesConnectionInfo_Vtbl)).TransitionOnline)(this, hwndparent, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransitionOffline(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, dwflags: u32, bforceopenfilesclosed: super::super::Foundation::BOOL, pbopenfilespreventedtransition: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesConnectionInfo", "TransitionOffline", 6usize, |this| ((**(this as *const *const IOfflineFilesConnectionInfo_Vtbl)).TransitionOffline)(this, hwndparent, dwflags, bforceopenfilesclosed, pbopenfilespreventedtransition), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetConnectState,
+            SetConnectState,
+            TransitionOnline,
+            TransitionOffline,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesConnectionInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesDirectoryItem_Vtbl {
+    const PROXY: Self = { Self { base__: <IOfflineFilesItem_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesDirectoryItem as windows_core::Interface>::IID || <IOfflineFilesItem_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesDirtyInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LocalDirtyByteCount(this: *mut core::ffi::c_void, pdirtybytecount: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesDirtyInfo", "LocalDirtyByteCount", 3usize, |this| ((**(this as *const *const IOfflineFilesDirtyInfo_Vtbl)).LocalDirtyByteCount)(this, pdirtybytecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteDirtyByteCount(this: *mut core::ffi::c_void, pdirtybytecount: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesDirtyInfo", "RemoteDirtyByteCount", 4usize, |this| ((**(this as *const *const IOfflineFilesDirtyInfo_Vtbl)).RemoteDirtyByteCount)(this, pdirtybytecount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LocalDirtyByteCount, RemoteDirtyByteCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesDirtyInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOfflineFilesErrorInfo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetRawData(this: *mut core::ffi::c_void, ppblob: *mut *mut super::super::System::Com::BYTE_BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesErrorInfo", "GetRawData", 3usize, |this| ((**(this as *const *const IOfflineFilesErrorInfo_Vtbl)).GetRawData)(this, ppblob), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescription(this: *mut core::ffi::c_void, ppszdescription: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesErrorInfo", "GetDescription", 4usize, |this| ((**(this as *const *const IOfflineFilesErrorInfo_Vtbl)).GetDescription)(this, ppszdescription), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetRawData,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetRawData: 0,
+            GetDescription,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesErrorInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CacheMoved(this: *mut core::ffi::c_void, pszoldpath: windows_core::PCWSTR, psznewpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents", "CacheMoved", 3usize, |this| ((**(this as *const *const IOfflineFilesEvents_Vtbl)).CacheMoved)(this, pszoldpath, psznewpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn CacheIsFull(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents", "CacheIsFull", 4usize, |this| ((**(this as *const *const IOfflineFilesEvents_Vtbl)).CacheIsFull)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CacheIsCorrupted(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents", "CacheIsCorrupted", 5usize, |this| ((**(this as *const *const IOfflineFilesEvents_Vtbl)).CacheIsCorrupted)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, benabled: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents", "Enabled", 6usize, |this| ((**(this as *const *const IOfflineFilesEvents_Vtbl)).Enabled)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn EncryptionChanged(this: *mut core::ffi::c_void, bwasencrypted: super::super::Foundation::BOOL, bwaspartial: super::super::Foundation::BOOL, bisencrypted: super::super::Foundation::BOOL, bispartial: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents", "EncryptionChanged", 7usize, |this| ((**(this as *const *const IOfflineFilesEvents_Vtbl)).EncryptionChanged)(this, bwasencrypted, bwaspartial, bisencrypted, bispartial), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncBegin(this: *mut core::ffi::c_void, rsyncid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents", "SyncBegin", 8usize, |this| ((**(this as *const *const IOfflineFilesEvents_Vtbl)).SyncBegin)(this, rsyncid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncFileResult(this: *mut core::ffi::c_void, rsyncid: *const windows_core::GUID, pszfile: windows_core::PCWSTR, hrresult: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents", "SyncFileResult", 9usize, |this| ((**(this as *const *const IOfflineFilesEvents_Vtbl)).SyncFileResult)(this, rsyncid, pszfile, hrresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncConflictRecAdded(this: *mut core::ffi::c_void, pszconflictpath: windows_core::PCWSTR, pftconflictdatetime: *const super::super::Foundation::FILETIME, conflictsyncstate: OFFLINEFILES_SYNC_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents", "SyncConflictRecAdded", 10usize, |this| ((**(this as *const *const IOfflineFilesEvents_Vtbl)).SyncConflictRecAdded)(this, pszconflictpath, pftconflictdatetime, conflictsyncstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncConflictRecUpdated(this: *mut core::ffi::c_void, pszconflictpath: windows_core::PCWSTR, pftconflictdatetime: *const super::super::Foundation::FILETIME, conflictsyncstate: OFFLINEFILES_SYNC_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents", "SyncConflictRecUpdated", 11usize, |this| ((**(this as *const *const IOfflineFilesEvents_Vtbl)).SyncConflictRecUpdated)(this, pszconflictpath, pftconflictdatetime, conflictsyncstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncConflictRecRemoved(this: *mut core::ffi::c_void, pszconflictpath: windows_core::PCWSTR, pftconflictdatetime: *const super::super::Foundation::FILETIME, conflictsyncstate: OFFLINEFILES_SYNC_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents", "SyncConflictRecRemoved", 12usize, |this| ((**(this as *const *const IOfflineFilesEvents_Vtbl)).SyncConflictRecRemoved)(this, pszconflictpath, pftconflictdatetime, conflictsyncstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncEnd(this: *mut core::ffi::c_void, rsyncid: *const windows_core::GUID, hrresult: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents", "SyncEnd", 13usize, |this| ((**(this as *const *const IOfflineFilesEvents_Vtbl)).SyncEnd)(this, rsyncid, hrresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetTransportArrived(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents", "NetTransportArrived", 14usize, |this| ((**(this as *const *const IOfflineFilesEvents_Vtbl)).NetTransportArrived)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn NoNetTransports(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents", "NoNetTransports", 15usize, |this| ((**(this as *const *const IOfflineFilesEvents_Vtbl)).NoNetTransports)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItemDisconnected(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, itemtype: OFFLINEFILES_ITEM_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents", "ItemDisconnected", 16usize, |this| ((**(this as *const *const IOfflineFilesEvents_Vtbl)).ItemDisconnected)(this, pszpath, itemtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItemReconnected(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, itemtype: OFFLINEFILES_ITEM_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents", "ItemReconnected", 17usize, |this| ((**(this as *const *const IOfflineFilesEvents_Vtbl)).ItemReconnected)(this, pszpath, itemtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItemAvailableOffline(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, itemtype: OFFLINEFILES_ITEM_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents", "ItemAvailableOffline", 18usize, |this| ((**(this as *const *const IOfflineFilesEvents_Vtbl)).ItemAvailableOffline)(this, pszpath, itemtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItemNotAvailableOffline(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, itemtype: OFFLINEFILES_ITEM_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents", "ItemNotAvailableOffline", 19usize, |this| ((**(this as *const *const IOfflineFilesEvents_Vtbl)).ItemNotAvailableOffline)(this, pszpath, itemtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItemPinned(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, itemtype: OFFLINEFILES_ITEM_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents", "ItemPinned", 20usize, |this| ((**(this as *const *const IOfflineFilesEvents_Vtbl)).ItemPinned)(this, pszpath, itemtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItemNotPinned(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, itemtype: OFFLINEFILES_ITEM_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents", "ItemNotPinned", 21usize, |this| ((**(this as *const *const IOfflineFilesEvents_Vtbl)).ItemNotPinned)(this, pszpath, itemtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItemModified(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, itemtype: OFFLINEFILES_ITEM_TYPE, bmodifieddata: super::super::Foundation::BOOL, bmodifiedattributes: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents", "ItemModified", 22usize, |this| ((**(this as *const *const IOfflineFilesEvents_Vtbl)).ItemModified)(this, pszpath, itemtype, bmodifieddata, bmodifiedattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItemAddedToCache(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, itemtype: OFFLINEFILES_ITEM_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents", "ItemAddedToCache", 23usize, |this| ((**(this as *const *const IOfflineFilesEvents_Vtbl)).ItemAddedToCache)(this, pszpath, itemtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItemDeletedFromCache(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, itemtype: OFFLINEFILES_ITEM_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents", "ItemDeletedFromCache", 24usize, |this| ((**(this as *const *const IOfflineFilesEvents_Vtbl)).ItemDeletedFromCache)(this, pszpath, itemtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItemRenamed(this: *mut core::ffi::c_void, pszoldpath: windows_core::PCWSTR, psznewpath: windows_core::PCWSTR, itemtype: OFFLINEFILES_ITEM_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents", "ItemRenamed", 25usize, |this| ((**(this as *const *const IOfflineFilesEvents_Vtbl)).ItemRenamed)(this, pszoldpath, psznewpath, itemtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataLost(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents", "DataLost", 26usize, |this| ((**(this as *const *const IOfflineFilesEvents_Vtbl)).DataLost)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ping(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents", "Ping", 27usize, |this| ((**(this as *const *const IOfflineFilesEvents_Vtbl)).Ping)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CacheMoved,
+            CacheIsFull,
+            CacheIsCorrupted,
+            Enabled,
+            EncryptionChanged,
+            SyncBegin,
+            SyncFileResult,
+            SyncConflictRecAdded,
+            SyncConflictRecUpdated,
+            SyncConflictRecRemoved,
+            SyncEnd,
+            NetTransportArrived,
+            NoNetTransports,
+            ItemDisconnected,
+            ItemReconnected,
+            ItemAvailableOffline,
+            ItemNotAvailableOffline,
+            ItemPinned,
+            ItemNotPinned,
+            ItemModified,
+            ItemAddedToCache,
+            ItemDeletedFromCache,
+            ItemRenamed,
+            DataLost,
+            Ping,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesEvents2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ItemReconnectBegin(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents2", "ItemReconnectBegin", 28usize, |this| ((**(this as *const *const IOfflineFilesEvents2_Vtbl)).ItemReconnectBegin)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItemReconnectEnd(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents2", "ItemReconnectEnd", 29usize, |this| ((**(this as *const *const IOfflineFilesEvents2_Vtbl)).ItemReconnectEnd)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CacheEvictBegin(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents2", "CacheEvictBegin", 30usize, |this| ((**(this as *const *const IOfflineFilesEvents2_Vtbl)).CacheEvictBegin)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CacheEvictEnd(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents2", "CacheEvictEnd", 31usize, |this| ((**(this as *const *const IOfflineFilesEvents2_Vtbl)).CacheEvictEnd)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackgroundSyncBegin(this: *mut core::ffi::c_void, dwsynccontrolflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents2", "BackgroundSyncBegin", 32usize, |this| ((**(this as *const *const IOfflineFilesEvents2_Vtbl)).BackgroundSyncBegin)(this, dwsynccontrolflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackgroundSyncEnd(this: *mut core::ffi::c_void, dwsynccontrolflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents2", "BackgroundSyncEnd", 33usize, |this| ((**(this as *const *const IOfflineFilesEvents2_Vtbl)).BackgroundSyncEnd)(this, dwsynccontrolflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn PolicyChangeDetected(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents2", "PolicyChangeDetected", 34usize, |this| ((**(this as *const *const IOfflineFilesEvents2_Vtbl)).PolicyChangeDetected)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreferenceChangeDetected(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents2", "PreferenceChangeDetected", 35usize, |this| ((**(this as *const *const IOfflineFilesEvents2_Vtbl)).PreferenceChangeDetected)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SettingsChangesApplied(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents2", "SettingsChangesApplied", 36usize, |this| ((**(this as *const *const IOfflineFilesEvents2_Vtbl)).SettingsChangesApplied)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IOfflineFilesEvents_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ItemReconnectBegin,
+            ItemReconnectEnd,
+            CacheEvictBegin,
+            CacheEvictEnd,
+            BackgroundSyncBegin,
+            BackgroundSyncEnd,
+            PolicyChangeDetected,
+            PreferenceChangeDetected,
+            SettingsChangesApplied,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesEvents2 as windows_core::Interface>::IID || <IOfflineFilesEvents_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesEvents3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TransparentCacheItemNotify(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, eventtype: OFFLINEFILES_EVENTS, itemtype: OFFLINEFILES_ITEM_TYPE, bmodifieddata: super::super::Foundation::BOOL, bmodifiedattributes: super::super::Foundation::BOOL, pzsoldpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents3", "TransparentCacheItemNotify", 37usize, |this| ((**(this as *const *const IOfflineFilesEvents3_Vtbl)).TransparentCacheItemNotify)(this, pszpath, eventtype, itemtype, bmodifieddata, bmodifiedattributes, pzsoldpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrefetchFileBegin(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents3", "PrefetchFileBegin", 38usize, |this| ((**(this as *const *const IOfflineFilesEvents3_Vtbl)).PrefetchFileBegin)(this, pszpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrefetchFileEnd(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, hrresult: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents3", "PrefetchFileEnd", 39usize, |this| ((**(this as *const *const IOfflineFilesEvents3_Vtbl)).PrefetchFileEnd)(this, pszpath, hrresult), |result| Some(*result))
+        }
+        Self { base__: <IOfflineFilesEvents2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TransparentCacheItemNotify, PrefetchFileBegin, PrefetchFileEnd }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesEvents3 as windows_core::Interface>::IID || <IOfflineFilesEvents2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesEvents4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrefetchCloseHandleBegin(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents4", "PrefetchCloseHandleBegin", 40usize, |this| ((**(this as *const *const IOfflineFilesEvents4_Vtbl)).PrefetchCloseHandleBegin)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrefetchCloseHandleEnd(this: *mut core::ffi::c_void, dwclosedhandlecount: u32, dwopenhandlecount: u32, hrresult: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEvents4", "PrefetchCloseHandleEnd", 41usize, |this| ((**(this as *const *const IOfflineFilesEvents4_Vtbl)).PrefetchCloseHandleEnd)(this, dwclosedhandlecount, dwopenhandlecount, hrresult), |result| Some(*result))
+        }
+        Self { base__: <IOfflineFilesEvents3_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PrefetchCloseHandleBegin, PrefetchCloseHandleEnd }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesEvents4 as windows_core::Interface>::IID || <IOfflineFilesEvents3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesEventsFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPathFilter(this: *mut core::ffi::c_void, ppszfilter: *mut windows_core::PWSTR, pmatch: *mut OFFLINEFILES_PATHFILTER_MATCH) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEventsFilter", "GetPathFilter", 3usize, |this| ((**(this as *const *const IOfflineFilesEventsFilter_Vtbl)).GetPathFilter)(this, ppszfilter, pmatch), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIncludedEvents(this: *mut core::ffi::c_void, celements: u32, prgevents: *mut OFFLINEFILES_EVENTS, pcevents: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEventsFilter", "GetIncludedEvents", 4usize, |this| ((**(this as *const *const IOfflineFilesEventsFilter_Vtbl)).GetIncludedEvents)(this, celements, prgevents, pcevents), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExcludedEvents(this: *mut core::ffi::c_void, celements: u32, prgevents: *mut OFFLINEFILES_EVENTS, pcevents: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesEventsFilter", "GetExcludedEvents", 5usize, |this| ((**(this as *const *const IOfflineFilesEventsFilter_Vtbl)).GetExcludedEvents)(this, celements, prgevents, pcevents), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPathFilter, GetIncludedEvents, GetExcludedEvents }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesEventsFilter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesFileItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSparse(this: *mut core::ffi::c_void, pbissparse: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesFileItem", "IsSparse", 8usize, |this| ((**(this as *const *const IOfflineFilesFileItem_Vtbl)).IsSparse)(this, pbissparse), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEncrypted(this: *mut core::ffi::c_void, pbisencrypted: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesFileItem", "IsEncrypted", 9usize, |this| ((**(this as *const *const IOfflineFilesFileItem_Vtbl)).IsEncrypted)(this, pbisencrypted), |result| Some(*result))
+        }
+        Self { base__: <IOfflineFilesItem_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsSparse, IsEncrypted }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesFileItem as windows_core::Interface>::IID || <IOfflineFilesItem_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesFileSysInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAttributes(this: *mut core::ffi::c_void, copy: OFFLINEFILES_ITEM_COPY, pdwattributes: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesFileSysInfo", "GetAttributes", 3usize, |this| ((**(this as *const *const IOfflineFilesFileSysInfo_Vtbl)).GetAttributes)(this, copy, pdwattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTimes(this: *mut core::ffi::c_void, copy: OFFLINEFILES_ITEM_COPY, pftcreationtime: *mut super::super::Foundation::FILETIME, pftlastwritetime: *mut super::super::Foundation::FILETIME, pftchangetime: *mut super::super::Foundation::FILETIME, pftlastaccesstime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesFileSysInfo", "GetTimes", 4usize, |this| ((**(this as *const *const IOfflineFilesFileSysInfo_Vtbl)).GetTimes)(this, copy, pftcreationtime, pftlastwritetime, pftchangetime, pftlastaccesstime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileSize(this: *mut core::ffi::c_void, copy: OFFLINEFILES_ITEM_COPY, psize: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesFileSysInfo", "GetFileSize", 5usize, |this| ((**(this as *const *const IOfflineFilesFileSysInfo_Vtbl)).GetFileSize)(this, copy, psize), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAttributes, GetTimes, GetFileSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesFileSysInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesGhostInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsGhosted(this: *mut core::ffi::c_void, pbghosted: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesGhostInfo", "IsGhosted", 3usize, |this| ((**(this as *const *const IOfflineFilesGhostInfo_Vtbl)).IsGhosted)(this, pbghosted), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsGhosted }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesGhostInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetItemType(this: *mut core::ffi::c_void, pitemtype: *mut OFFLINEFILES_ITEM_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesItem", "GetItemType", 3usize, |this| ((**(this as *const *const IOfflineFilesItem_Vtbl)).GetItemType)(this, pitemtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPath(this: *mut core::ffi::c_void, ppszpath: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesItem", "GetPath", 4usize, |this| ((**(this as *const *const IOfflineFilesItem_Vtbl)).GetPath)(this, ppszpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParentItem(this: *mut core::ffi::c_void, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesItem", "GetParentItem", 5usize, |this| ((**(this as *const *const IOfflineFilesItem_Vtbl)).GetParentItem)(this, ppitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void, dwqueryflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesItem", "Refresh", 6usize, |this| ((**(this as *const *const IOfflineFilesItem_Vtbl)).Refresh)(this, dwqueryflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMarkedForDeletion(this: *mut core::ffi::c_void, pbmarkedfordeletion: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesItem", "IsMarkedForDeletion", 7usize, |this| ((**(this as *const *const IOfflineFilesItem_Vtbl)).IsMarkedForDeletion)(this, pbmarkedfordeletion), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetItemType,
+            GetPath,
+            GetParentItem,
+            Refresh,
+            IsMarkedForDeletion,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesItem as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesItemContainer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumItems(this: *mut core::ffi::c_void, dwqueryflags: u32, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesItemContainer", "EnumItems", 3usize, |this| ((**(this as *const *const IOfflineFilesItemContainer_Vtbl)).EnumItems)(this, dwqueryflags, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumItemsEx(this: *mut core::ffi::c_void, pincludefilefilter: *mut core::ffi::c_void, pincludedirfilter: *mut core::ffi::c_void, pexcludefilefilter: *mut core::ffi::c_void, pexcludedirfilter: *mut core::ffi::c_void, dwenumflags: u32, dwqueryflags: u32, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesItemContainer", "EnumItemsEx", 4usize, |this| ((**(this as *const *const IOfflineFilesItemContainer_Vtbl)).EnumItemsEx)(this, pincludefilefilter, pincludedirfilter, pexcludefilefilter, pexcludedirfilter, dwenumflags, dwqueryflags, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnumItems, EnumItemsEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesItemContainer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesItemFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFilterFlags(this: *mut core::ffi::c_void, pullflags: *mut u64, pullmask: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesItemFilter", "GetFilterFlags", 3usize, |this| ((**(this as *const *const IOfflineFilesItemFilter_Vtbl)).GetFilterFlags)(this, pullflags, pullmask), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTimeFilter(this: *mut core::ffi::c_void, pfttime: *mut super::super::Foundation::FILETIME, pbevaltimeofday: *mut super::super::Foundation::BOOL, ptimetype: *mut OFFLINEFILES_ITEM_TIME, pcompare: *mut OFFLINEFILES_COMPARE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesItemFilter", "GetTimeFilter", 4usize, |this| ((**(this as *const *const IOfflineFilesItemFilter_Vtbl)).GetTimeFilter)(this, pfttime, pbevaltimeofday, ptimetype, pcompare), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPatternFilter(this: *mut core::ffi::c_void, pszpattern: windows_core::PWSTR, cchpattern: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesItemFilter", "GetPatternFilter", 5usize, |this| ((**(this as *const *const IOfflineFilesItemFilter_Vtbl)).GetPatternFilter)(this, pszpattern, cchpattern), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFilterFlags, GetTimeFilter, GetPatternFilter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesItemFilter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesPinInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsPinned(this: *mut core::ffi::c_void, pbpinned: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesPinInfo", "IsPinned", 3usize, |this| ((**(this as *const *const IOfflineFilesPinInfo_Vtbl)).IsPinned)(this, pbpinned), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPinnedForUser(this: *mut core::ffi::c_void, pbpinnedforuser: *mut super::super::Foundation::BOOL, pbinherit: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesPinInfo", "IsPinnedForUser", 4usize, |this| ((**(this as *const *const IOfflineFilesPinInfo_Vtbl)).IsPinnedForUser)(this, pbpinnedforuser, pbinherit), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPinnedForUserByPolicy(this: *mut core::ffi::c_void, pbpinnedforuser: *mut super::super::Foundation::BOOL, pbinherit: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesPinInfo", "IsPinnedForUserByPolicy", 5usize, |this| ((**(this as *const *const IOfflineFilesPinInfo_Vtbl)).IsPinnedForUserByPolicy)(this, pbpinnedforuser, pbinherit), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPinnedForComputer(this: *mut core::ffi::c_void, pbpinnedforcomputer: *mut super::super::Foundation::BOOL, pbinherit: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesPinInfo", "IsPinnedForComputer", 6usize, |this| ((**(this as *const *const IOfflineFilesPinInfo_Vtbl)).IsPinnedForComputer)(this, pbpinnedforcomputer, pbinherit), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPinnedForFolderRedirection(this: *mut core::ffi::c_void, pbpinnedforfolderredirection: *mut super::super::Foundation::BOOL, pbinherit: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesPinInfo", "IsPinnedForFolderRedirection", 7usize, |this| ((**(this as *const *const IOfflineFilesPinInfo_Vtbl)).IsPinnedForFolderRedirection)(this, pbpinnedforfolderredirection, pbinherit), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsPinned,
+            IsPinnedForUser,
+            IsPinnedForUserByPolicy,
+            IsPinnedForComputer,
+            IsPinnedForFolderRedirection,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesPinInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesPinInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsPartlyPinned(this: *mut core::ffi::c_void, pbpartlypinned: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesPinInfo2", "IsPartlyPinned", 8usize, |this| ((**(this as *const *const IOfflineFilesPinInfo2_Vtbl)).IsPartlyPinned)(this, pbpartlypinned), |result| Some(*result))
+        }
+        Self { base__: <IOfflineFilesPinInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsPartlyPinned }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesPinInfo2 as windows_core::Interface>::IID || <IOfflineFilesPinInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesProgress_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Begin(this: *mut core::ffi::c_void, pbabort: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesProgress", "Begin", 3usize, |this| ((**(this as *const *const IOfflineFilesProgress_Vtbl)).Begin)(this, pbabort), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryAbort(this: *mut core::ffi::c_void, pbabort: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesProgress", "QueryAbort", 4usize, |this| ((**(this as *const *const IOfflineFilesProgress_Vtbl)).QueryAbort)(this, pbabort), |result| Some(*result))
+        }
+        unsafe extern "system" fn End(this: *mut core::ffi::c_void, hrresult: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesProgress", "End", 5usize, |this| ((**(this as *const *const IOfflineFilesProgress_Vtbl)).End)(this, hrresult), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Begin, QueryAbort, End }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesProgress as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesServerItem_Vtbl {
+    const PROXY: Self = { Self { base__: <IOfflineFilesItem_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesServerItem as windows_core::Interface>::IID || <IOfflineFilesItem_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesSetting_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, ppszname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesSetting", "GetName", 3usize, |this| ((**(this as *const *const IOfflineFilesSetting_Vtbl)).GetName)(this, ppszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValueType(this: *mut core::ffi::c_void, ptype: *mut OFFLINEFILES_SETTING_VALUE_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesSetting", "GetValueType", 4usize, |this| ((**(this as *const *const IOfflineFilesSetting_Vtbl)).GetValueType)(this, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreference(this: *mut core::ffi::c_void, pvarvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>, dwscope: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesSetting", "GetPreference", 5usize, |this| ((**(this as *const *const IOfflineFilesSetting_Vtbl)).GetPreference)(this, pvarvalue, dwscope), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreferenceScope(this: *mut core::ffi::c_void, pdwscope: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesSetting", "GetPreferenceScope", 6usize, |this| ((**(this as *const *const IOfflineFilesSetting_Vtbl)).GetPreferenceScope)(this, pdwscope), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreference(this: *mut core::ffi::c_void, pvarvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>, dwscope: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesSetting", "SetPreference", 7usize, |this| ((**(this as *const *const IOfflineFilesSetting_Vtbl)).SetPreference)(this, pvarvalue, dwscope), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeletePreference(this: *mut core::ffi::c_void, dwscope: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesSetting", "DeletePreference", 8usize, |this| ((**(this as *const *const IOfflineFilesSetting_Vtbl)).DeletePreference)(this, dwscope), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPolicy(this: *mut core::ffi::c_void, pvarvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>, dwscope: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesSetting", "GetPolicy", 9usize, |this| ((**(this as *const *const IOfflineFilesSetting_Vtbl)).GetPolicy)(this, pvarvalue, dwscope), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPolicyScope(this: *mut core::ffi::c_void, pdwscope: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesSetting", "GetPolicyScope", 10usize, |this| ((**(this as *const *const IOfflineFilesSetting_Vtbl)).GetPolicyScope)(this, pdwscope), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, pvarvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>, pbsetbypolicy: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesSetting", "GetValue", 11usize, |this| ((**(this as *const *const IOfflineFilesSetting_Vtbl)).GetValue)(this, pvarvalue, pbsetbypolicy), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetName,
+            GetValueType,
+            GetPreference,
+            GetPreferenceScope,
+            SetPreference,
+            DeletePreference,
+            GetPolicy,
+            GetPolicyScope,
+            GetValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesSetting as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesShareInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetShareItem(this: *mut core::ffi::c_void, ppshareitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesShareInfo", "GetShareItem", 3usize, |this| ((**(this as *const *const IOfflineFilesShareInfo_Vtbl)).GetShareItem)(this, ppshareitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetShareCachingMode(this: *mut core::ffi::c_void, pcachingmode: *mut OFFLINEFILES_CACHING_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesShareInfo", "GetShareCachingMode", 4usize, |this| ((**(this as *const *const IOfflineFilesShareInfo_Vtbl)).GetShareCachingMode)(this, pcachingmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsShareDfsJunction(this: *mut core::ffi::c_void, pbisdfsjunction: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesShareInfo", "IsShareDfsJunction", 5usize, |this| ((**(this as *const *const IOfflineFilesShareInfo_Vtbl)).IsShareDfsJunction)(this, pbisdfsjunction), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetShareItem, GetShareCachingMode, IsShareDfsJunction }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesShareInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesShareItem_Vtbl {
+    const PROXY: Self = { Self { base__: <IOfflineFilesItem_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesShareItem as windows_core::Interface>::IID || <IOfflineFilesItem_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesSimpleProgress_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ItemBegin(this: *mut core::ffi::c_void, pszfile: windows_core::PCWSTR, presponse: *mut OFFLINEFILES_OP_RESPONSE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesSimpleProgress", "ItemBegin", 6usize, |this| ((**(this as *const *const IOfflineFilesSimpleProgress_Vtbl)).ItemBegin)(this, pszfile, presponse), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItemResult(this: *mut core::ffi::c_void, pszfile: windows_core::PCWSTR, hrresult: windows_core::HRESULT, presponse: *mut OFFLINEFILES_OP_RESPONSE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesSimpleProgress", "ItemResult", 7usize, |this| ((**(this as *const *const IOfflineFilesSimpleProgress_Vtbl)).ItemResult)(this, pszfile, hrresult, presponse), |result| Some(*result))
+        }
+        Self { base__: <IOfflineFilesProgress_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ItemBegin, ItemResult }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesSimpleProgress as windows_core::Interface>::IID || <IOfflineFilesProgress_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesSuspend_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SuspendRoot(this: *mut core::ffi::c_void, bsuspend: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesSuspend", "SuspendRoot", 3usize, |this| ((**(this as *const *const IOfflineFilesSuspend_Vtbl)).SuspendRoot)(this, bsuspend), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SuspendRoot }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesSuspend as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesSuspendInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSuspended(this: *mut core::ffi::c_void, pbsuspended: *mut super::super::Foundation::BOOL, pbsuspendedroot: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesSuspendInfo", "IsSuspended", 3usize, |this| ((**(this as *const *const IOfflineFilesSuspendInfo_Vtbl)).IsSuspended)(this, pbsuspended, pbsuspendedroot), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsSuspended }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesSuspendInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesSyncConflictHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResolveConflict(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, fstateknown: u32, state: OFFLINEFILES_SYNC_STATE, fchangedetails: u32, pconflictresolution: *mut OFFLINEFILES_SYNC_CONFLICT_RESOLVE, ppsznewname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesSyncConflictHandler", "ResolveConflict", 3usize, |this| ((**(this as *const *const IOfflineFilesSyncConflictHandler_Vtbl)).ResolveConflict)(this, pszpath, fstateknown, state, fchangedetails, pconflictresolution, ppsznewname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ResolveConflict }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesSyncConflictHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOfflineFilesSyncErrorInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSyncOperation(this: *mut core::ffi::c_void, psyncop: *mut OFFLINEFILES_SYNC_OPERATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesSyncErrorInfo", "GetSyncOperation", 5usize, |this| ((**(this as *const *const IOfflineFilesSyncErrorInfo_Vtbl)).GetSyncOperation)(this, psyncop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItemChangeFlags(this: *mut core::ffi::c_void, pdwitemchangeflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesSyncErrorInfo", "GetItemChangeFlags", 6usize, |this| ((**(this as *const *const IOfflineFilesSyncErrorInfo_Vtbl)).GetItemChangeFlags)(this, pdwitemchangeflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn InfoEnumerated(this: *mut core::ffi::c_void, pblocalenumerated: *mut super::super::Foundation::BOOL, pbremoteenumerated: *mut super::super::Foundation::BOOL, pboriginalenumerated: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesSyncErrorInfo", "InfoEnumerated", 7usize, |this| ((**(this as *const *const IOfflineFilesSyncErrorInfo_Vtbl)).InfoEnumerated)(this, pblocalenumerated, pbremoteenumerated, pboriginalenumerated), |result| Some(*result))
+        }
+        unsafe extern "system" fn InfoAvailable(this: *mut core::ffi::c_void, pblocalinfo: *mut super::super::Foundation::BOOL, pbremoteinfo: *mut super::super::Foundation::BOOL, pboriginalinfo: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesSyncErrorInfo", "InfoAvailable", 8usize, |this| ((**(this as *const *const IOfflineFilesSyncErrorInfo_Vtbl)).InfoAvailable)(this, pblocalinfo, pbremoteinfo, pboriginalinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocalInfo(this: *mut core::ffi::c_void, ppinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesSyncErrorInfo", "GetLocalInfo", 9usize, |this| ((**(this as *const *const IOfflineFilesSyncErrorInfo_Vtbl)).GetLocalInfo)(this, ppinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRemoteInfo(this: *mut core::ffi::c_void, ppinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesSyncErrorInfo", "GetRemoteInfo", 10usize, |this| ((**(this as *const *const IOfflineFilesSyncErrorInfo_Vtbl)).GetRemoteInfo)(this, ppinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOriginalInfo(this: *mut core::ffi::c_void, ppinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesSyncErrorInfo", "GetOriginalInfo", 11usize, |this| ((**(this as *const *const IOfflineFilesSyncErrorInfo_Vtbl)).GetOriginalInfo)(this, ppinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <IOfflineFilesErrorInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSyncOperation,
+            GetItemChangeFlags,
+            InfoEnumerated,
+            InfoAvailable,
+            GetLocalInfo,
+            GetRemoteInfo,
+            GetOriginalInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesSyncErrorInfo as windows_core::Interface>::IID || <IOfflineFilesErrorInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesSyncErrorItemInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFileAttributes(this: *mut core::ffi::c_void, pdwattributes: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesSyncErrorItemInfo", "GetFileAttributes", 3usize, |this| ((**(this as *const *const IOfflineFilesSyncErrorItemInfo_Vtbl)).GetFileAttributes)(this, pdwattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileTimes(this: *mut core::ffi::c_void, pftlastwrite: *mut super::super::Foundation::FILETIME, pftchange: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesSyncErrorItemInfo", "GetFileTimes", 4usize, |this| ((**(this as *const *const IOfflineFilesSyncErrorItemInfo_Vtbl)).GetFileTimes)(this, pftlastwrite, pftchange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileSize(this: *mut core::ffi::c_void, psize: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesSyncErrorItemInfo", "GetFileSize", 5usize, |this| ((**(this as *const *const IOfflineFilesSyncErrorItemInfo_Vtbl)).GetFileSize)(this, psize), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFileAttributes, GetFileTimes, GetFileSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesSyncErrorItemInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesSyncProgress_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SyncItemBegin(this: *mut core::ffi::c_void, pszfile: windows_core::PCWSTR, presponse: *mut OFFLINEFILES_OP_RESPONSE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesSyncProgress", "SyncItemBegin", 6usize, |this| ((**(this as *const *const IOfflineFilesSyncProgress_Vtbl)).SyncItemBegin)(this, pszfile, presponse), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncItemResult(this: *mut core::ffi::c_void, pszfile: windows_core::PCWSTR, hrresult: windows_core::HRESULT, perrorinfo: *mut core::ffi::c_void, presponse: *mut OFFLINEFILES_OP_RESPONSE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesSyncProgress", "SyncItemResult", 7usize, |this| ((**(this as *const *const IOfflineFilesSyncProgress_Vtbl)).SyncItemResult)(this, pszfile, hrresult, perrorinfo, presponse), |result| Some(*result))
+        }
+        Self { base__: <IOfflineFilesProgress_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SyncItemBegin, SyncItemResult }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesSyncProgress as windows_core::Interface>::IID || <IOfflineFilesProgress_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOfflineFilesTransparentCacheInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsTransparentlyCached(this: *mut core::ffi::c_void, pbtransparentlycached: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOfflineFilesTransparentCacheInfo", "IsTransparentlyCached", 3usize, |this| ((**(this as *const *const IOfflineFilesTransparentCacheInfo_Vtbl)).IsTransparentlyCached)(this, pbtransparentlycached), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsTransparentlyCached }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOfflineFilesTransparentCacheInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
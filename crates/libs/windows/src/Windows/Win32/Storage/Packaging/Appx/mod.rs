@@ -3431,6 +3431,7 @@ pub struct APPX_BUNDLE_FOOTPRINT_FILE_TYPE(pub i32);
 impl windows_core::TypeKind for APPX_BUNDLE_FOOTPRINT_FILE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for APPX_BUNDLE_FOOTPRINT_FILE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("APPX_BUNDLE_FOOTPRINT_FILE_TYPE").field(&self.0).finish()
@@ -3442,6 +3443,7 @@ pub struct APPX_BUNDLE_PAYLOAD_PACKAGE_TYPE(pub i32);
 impl windows_core::TypeKind for APPX_BUNDLE_PAYLOAD_PACKAGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for APPX_BUNDLE_PAYLOAD_PACKAGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("APPX_BUNDLE_PAYLOAD_PACKAGE_TYPE").field(&self.0).finish()
@@ -3453,6 +3455,7 @@ pub struct APPX_CAPABILITIES(pub i32);
 impl windows_core::TypeKind for APPX_CAPABILITIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for APPX_CAPABILITIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("APPX_CAPABILITIES").field(&self.0).finish()
@@ -3497,6 +3500,7 @@ pub struct APPX_CAPABILITY_CLASS_TYPE(pub i32);
 impl windows_core::TypeKind for APPX_CAPABILITY_CLASS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for APPX_CAPABILITY_CLASS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("APPX_CAPABILITY_CLASS_TYPE").field(&self.0).finish()
@@ -3508,6 +3512,7 @@ pub struct APPX_COMPRESSION_OPTION(pub i32);
 impl windows_core::TypeKind for APPX_COMPRESSION_OPTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for APPX_COMPRESSION_OPTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("APPX_COMPRESSION_OPTION").field(&self.0).finish()
@@ -3519,6 +3524,7 @@ pub struct APPX_ENCRYPTED_PACKAGE_OPTIONS(pub i32);
 impl windows_core::TypeKind for APPX_ENCRYPTED_PACKAGE_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for APPX_ENCRYPTED_PACKAGE_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("APPX_ENCRYPTED_PACKAGE_OPTIONS").field(&self.0).finish()
@@ -3563,6 +3569,7 @@ pub struct APPX_FOOTPRINT_FILE_TYPE(pub i32);
 impl windows_core::TypeKind for APPX_FOOTPRINT_FILE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for APPX_FOOTPRINT_FILE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("APPX_FOOTPRINT_FILE_TYPE").field(&self.0).finish()
@@ -3574,6 +3581,7 @@ pub struct APPX_PACKAGE_ARCHITECTURE(pub i32);
 impl windows_core::TypeKind for APPX_PACKAGE_ARCHITECTURE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for APPX_PACKAGE_ARCHITECTURE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("APPX_PACKAGE_ARCHITECTURE").field(&self.0).finish()
@@ -3585,6 +3593,7 @@ pub struct APPX_PACKAGE_ARCHITECTURE2(pub i32);
 impl windows_core::TypeKind for APPX_PACKAGE_ARCHITECTURE2 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for APPX_PACKAGE_ARCHITECTURE2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("APPX_PACKAGE_ARCHITECTURE2").field(&self.0).finish()
@@ -3596,6 +3605,7 @@ pub struct APPX_PACKAGE_EDITOR_UPDATE_PACKAGE_MANIFEST_OPTIONS(pub i32);
 impl windows_core::TypeKind for APPX_PACKAGE_EDITOR_UPDATE_PACKAGE_MANIFEST_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for APPX_PACKAGE_EDITOR_UPDATE_PACKAGE_MANIFEST_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("APPX_PACKAGE_EDITOR_UPDATE_PACKAGE_MANIFEST_OPTIONS").field(&self.0).finish()
@@ -3640,6 +3650,7 @@ pub struct APPX_PACKAGE_EDITOR_UPDATE_PACKAGE_OPTION(pub i32);
 impl windows_core::TypeKind for APPX_PACKAGE_EDITOR_UPDATE_PACKAGE_OPTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for APPX_PACKAGE_EDITOR_UPDATE_PACKAGE_OPTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("APPX_PACKAGE_EDITOR_UPDATE_PACKAGE_OPTION").field(&self.0).finish()
@@ -3651,6 +3662,7 @@ pub struct APPX_PACKAGING_CONTEXT_CHANGE_TYPE(pub i32);
 impl windows_core::TypeKind for APPX_PACKAGING_CONTEXT_CHANGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for APPX_PACKAGING_CONTEXT_CHANGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("APPX_PACKAGING_CONTEXT_CHANGE_TYPE").field(&self.0).finish()
@@ -3662,6 +3674,7 @@ pub struct AddPackageDependencyOptions(pub i32);
 impl windows_core::TypeKind for AddPackageDependencyOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AddPackageDependencyOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AddPackageDependencyOptions").field(&self.0).finish()
@@ -3706,6 +3719,7 @@ pub struct AppPolicyClrCompat(pub i32);
 impl windows_core::TypeKind for AppPolicyClrCompat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppPolicyClrCompat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppPolicyClrCompat").field(&self.0).finish()
@@ -3717,6 +3731,7 @@ pub struct AppPolicyCreateFileAccess(pub i32);
 impl windows_core::TypeKind for AppPolicyCreateFileAccess {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppPolicyCreateFileAccess {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppPolicyCreateFileAccess").field(&self.0).finish()
@@ -3728,6 +3743,7 @@ pub struct AppPolicyLifecycleManagement(pub i32);
 impl windows_core::TypeKind for AppPolicyLifecycleManagement {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppPolicyLifecycleManagement {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppPolicyLifecycleManagement").field(&self.0).finish()
@@ -3739,6 +3755,7 @@ pub struct AppPolicyMediaFoundationCodecLoading(pub i32);
 impl windows_core::TypeKind for AppPolicyMediaFoundationCodecLoading {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppPolicyMediaFoundationCodecLoading {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppPolicyMediaFoundationCodecLoading").field(&self.0).finish()
@@ -3750,6 +3767,7 @@ pub struct AppPolicyProcessTerminationMethod(pub i32);
 impl windows_core::TypeKind for AppPolicyProcessTerminationMethod {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppPolicyProcessTerminationMethod {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppPolicyProcessTerminationMethod").field(&self.0).finish()
@@ -3761,6 +3779,7 @@ pub struct AppPolicyShowDeveloperDiagnostic(pub i32);
 impl windows_core::TypeKind for AppPolicyShowDeveloperDiagnostic {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppPolicyShowDeveloperDiagnostic {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppPolicyShowDeveloperDiagnostic").field(&self.0).finish()
@@ -3772,6 +3791,7 @@ pub struct AppPolicyThreadInitializationType(pub i32);
 impl windows_core::TypeKind for AppPolicyThreadInitializationType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppPolicyThreadInitializationType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppPolicyThreadInitializationType").field(&self.0).finish()
@@ -3783,6 +3803,7 @@ pub struct AppPolicyWindowingModel(pub i32);
 impl windows_core::TypeKind for AppPolicyWindowingModel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppPolicyWindowingModel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppPolicyWindowingModel").field(&self.0).finish()
@@ -3794,6 +3815,7 @@ pub struct CreatePackageDependencyOptions(pub i32);
 impl windows_core::TypeKind for CreatePackageDependencyOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CreatePackageDependencyOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CreatePackageDependencyOptions").field(&self.0).finish()
@@ -3838,6 +3860,7 @@ pub struct DX_FEATURE_LEVEL(pub i32);
 impl windows_core::TypeKind for DX_FEATURE_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DX_FEATURE_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DX_FEATURE_LEVEL").field(&self.0).finish()
@@ -3849,6 +3872,7 @@ pub struct PackageDependencyLifetimeKind(pub i32);
 impl windows_core::TypeKind for PackageDependencyLifetimeKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PackageDependencyLifetimeKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PackageDependencyLifetimeKind").field(&self.0).finish()
@@ -3860,6 +3884,7 @@ pub struct PackageDependencyProcessorArchitectures(pub i32);
 impl windows_core::TypeKind for PackageDependencyProcessorArchitectures {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PackageDependencyProcessorArchitectures {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PackageDependencyProcessorArchitectures").field(&self.0).finish()
@@ -3904,6 +3929,7 @@ pub struct PackageInfo3Type(pub i32);
 impl windows_core::TypeKind for PackageInfo3Type {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PackageInfo3Type {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PackageInfo3Type").field(&self.0).finish()
@@ -3915,6 +3941,7 @@ pub struct PackageOrigin(pub i32);
 impl windows_core::TypeKind for PackageOrigin {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PackageOrigin {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PackageOrigin").field(&self.0).finish()
@@ -3926,13 +3953,15 @@ pub struct PackagePathType(pub i32);
 impl windows_core::TypeKind for PackagePathType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PackagePathType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PackagePathType").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct APPX_ENCRYPTED_EXEMPTIONS {
     pub count: u32,
     pub plainTextFiles: *const windows_core::PCWSTR,
@@ -3947,7 +3976,8 @@ impl Default for APPX_ENCRYPTED_EXEMPTIONS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct APPX_ENCRYPTED_PACKAGE_SETTINGS {
     pub keyLength: u32,
     pub encryptionAlgorithm: windows_core::PCWSTR,
@@ -3972,7 +4002,8 @@ impl Default for APPX_ENCRYPTED_PACKAGE_SETTINGS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct APPX_ENCRYPTED_PACKAGE_SETTINGS2 {
     pub keyLength: u32,
     pub encryptionAlgorithm: windows_core::PCWSTR,
@@ -3996,7 +4027,8 @@ impl Default for APPX_ENCRYPTED_PACKAGE_SETTINGS2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct APPX_KEY_INFO {
     pub keyLength: u32,
     pub keyIdLength: u32,
@@ -4013,7 +4045,8 @@ impl Default for APPX_KEY_INFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct APPX_PACKAGE_SETTINGS {
     pub forceZip32: super::super::super::Foundation::BOOL,
     pub hashMethod: core::mem::ManuallyDrop<Option<super::super::super::System::Com::IUri>>,
@@ -4036,7 +4069,8 @@ impl Default for APPX_PACKAGE_SETTINGS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct APPX_PACKAGE_WRITER_PAYLOAD_STREAM {
     pub inputStream: core::mem::ManuallyDrop<Option<super::super::super::System::Com::IStream>>,
     pub fileName: windows_core::PCWSTR,
@@ -4065,7 +4099,8 @@ pub const AppxFactory: windows_core::GUID = windows_core::GUID::from_u128(0x5842
 pub const AppxPackageEditor: windows_core::GUID = windows_core::GUID::from_u128(0xf004f2ca_aebc_4b0d_bf58_e516d5bcc0ab);
 pub const AppxPackagingDiagnosticEventSinkManager: windows_core::GUID = windows_core::GUID::from_u128(0x50ca0a46_1588_4161_8ed2_ef9e469ced5d);
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PACKAGEDEPENDENCY_CONTEXT(pub *mut core::ffi::c_void);
 impl PACKAGEDEPENDENCY_CONTEXT {
     pub fn is_invalid(&self) -> bool {
@@ -4194,7 +4229,8 @@ impl Default for PACKAGE_VERSION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PACKAGE_VERSION_0_0 {
     pub Revision: u16,
     pub Build: u16,
@@ -4210,7 +4246,8 @@ impl Default for PACKAGE_VERSION_0_0 {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PACKAGE_VIRTUALIZATION_CONTEXT_HANDLE(pub *mut core::ffi::c_void);
 impl PACKAGE_VIRTUALIZATION_CONTEXT_HANDLE {
     pub fn is_invalid(&self) -> bool {
@@ -4226,7 +4263,8 @@ impl windows_core::TypeKind for PACKAGE_VIRTUALIZATION_CONTEXT_HANDLE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _PACKAGE_INFO_REFERENCE {
     pub reserved: *mut core::ffi::c_void,
 }
@@ -4240,3 +4278,5 @@ impl Default for _PACKAGE_INFO_REFERENCE {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
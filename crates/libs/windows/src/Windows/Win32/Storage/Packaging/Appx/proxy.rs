@@ -0,0 +1,1913 @@
+#[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IAppxAppInstallerReader_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn GetXmlDom(this: *mut core::ffi::c_void, dom: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxAppInstallerReader", "GetXmlDom", 3usize, |this| ((**(this as *const *const IAppxAppInstallerReader_Vtbl)).GetXmlDom)(this, dom), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com"))]
+            GetXmlDom,
+            #[cfg(not(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com")))]
+            GetXmlDom: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxAppInstallerReader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxBlockMapBlock_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetHash(this: *mut core::ffi::c_void, buffersize: *mut u32, buffer: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBlockMapBlock", "GetHash", 3usize, |this| ((**(this as *const *const IAppxBlockMapBlock_Vtbl)).GetHash)(this, buffersize, buffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCompressedSize(this: *mut core::ffi::c_void, size: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBlockMapBlock", "GetCompressedSize", 4usize, |this| ((**(this as *const *const IAppxBlockMapBlock_Vtbl)).GetCompressedSize)(this, size), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetHash, GetCompressedSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxBlockMapBlock as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxBlockMapBlocksEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, block: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBlockMapBlocksEnumerator", "GetCurrent", 3usize, |this| ((**(this as *const *const IAppxBlockMapBlocksEnumerator_Vtbl)).GetCurrent)(this, block), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHasCurrent(this: *mut core::ffi::c_void, hascurrent: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBlockMapBlocksEnumerator", "GetHasCurrent", 4usize, |this| ((**(this as *const *const IAppxBlockMapBlocksEnumerator_Vtbl)).GetHasCurrent)(this, hascurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hasnext: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBlockMapBlocksEnumerator", "MoveNext", 5usize, |this| ((**(this as *const *const IAppxBlockMapBlocksEnumerator_Vtbl)).MoveNext)(this, hasnext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrent, GetHasCurrent, MoveNext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxBlockMapBlocksEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxBlockMapFile_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBlocks(this: *mut core::ffi::c_void, blocks: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBlockMapFile", "GetBlocks", 3usize, |this| ((**(this as *const *const IAppxBlockMapFile_Vtbl)).GetBlocks)(this, blocks), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocalFileHeaderSize(this: *mut core::ffi::c_void, lfhsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBlockMapFile", "GetLocalFileHeaderSize", 4usize, |this| ((**(this as *const *const IAppxBlockMapFile_Vtbl)).GetLocalFileHeaderSize)(this, lfhsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, name: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBlockMapFile", "GetName", 5usize, |this| ((**(this as *const *const IAppxBlockMapFile_Vtbl)).GetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUncompressedSize(this: *mut core::ffi::c_void, size: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBlockMapFile", "GetUncompressedSize", 6usize, |this| ((**(this as *const *const IAppxBlockMapFile_Vtbl)).GetUncompressedSize)(this, size), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ValidateFileHash(this: *mut core::ffi::c_void, filestream: *mut core::ffi::c_void, isvalid: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBlockMapFile", "ValidateFileHash", 7usize, |this| ((**(this as *const *const IAppxBlockMapFile_Vtbl)).ValidateFileHash)(this, filestream, isvalid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetBlocks,
+            GetLocalFileHeaderSize,
+            GetName,
+            GetUncompressedSize,
+            #[cfg(feature = "Win32_System_Com")]
+            ValidateFileHash,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ValidateFileHash: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxBlockMapFile as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxBlockMapFilesEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, file: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBlockMapFilesEnumerator", "GetCurrent", 3usize, |this| ((**(this as *const *const IAppxBlockMapFilesEnumerator_Vtbl)).GetCurrent)(this, file), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHasCurrent(this: *mut core::ffi::c_void, hascurrent: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBlockMapFilesEnumerator", "GetHasCurrent", 4usize, |this| ((**(this as *const *const IAppxBlockMapFilesEnumerator_Vtbl)).GetHasCurrent)(this, hascurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hascurrent: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBlockMapFilesEnumerator", "MoveNext", 5usize, |this| ((**(this as *const *const IAppxBlockMapFilesEnumerator_Vtbl)).MoveNext)(this, hascurrent), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrent, GetHasCurrent, MoveNext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxBlockMapFilesEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxBlockMapReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFile(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, file: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBlockMapReader", "GetFile", 3usize, |this| ((**(this as *const *const IAppxBlockMapReader_Vtbl)).GetFile)(this, filename, file), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFiles(this: *mut core::ffi::c_void, enumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBlockMapReader", "GetFiles", 4usize, |this| ((**(this as *const *const IAppxBlockMapReader_Vtbl)).GetFiles)(this, enumerator), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetHashMethod(this: *mut core::ffi::c_void, hashmethod: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBlockMapReader", "GetHashMethod", 5usize, |this| ((**(this as *const *const IAppxBlockMapReader_Vtbl)).GetHashMethod)(this, hashmethod), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetStream(this: *mut core::ffi::c_void, blockmapstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBlockMapReader", "GetStream", 6usize, |this| ((**(this as *const *const IAppxBlockMapReader_Vtbl)).GetStream)(this, blockmapstream), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetFile,
+            GetFiles,
+            #[cfg(feature = "Win32_System_Com")]
+            GetHashMethod,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetHashMethod: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxBlockMapReader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxBundleFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateBundleWriter(this: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, bundleversion: u64, bundlewriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleFactory", "CreateBundleWriter", 3usize, |this| ((**(this as *const *const IAppxBundleFactory_Vtbl)).CreateBundleWriter)(this, outputstream, bundleversion, bundlewriter), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateBundleReader(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, bundlereader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleFactory", "CreateBundleReader", 4usize, |this| ((**(this as *const *const IAppxBundleFactory_Vtbl)).CreateBundleReader)(this, inputstream, bundlereader), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateBundleManifestReader(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, manifestreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleFactory", "CreateBundleManifestReader", 5usize, |this| ((**(this as *const *const IAppxBundleFactory_Vtbl)).CreateBundleManifestReader)(this, inputstream, manifestreader), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateBundleWriter,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateBundleWriter: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateBundleReader,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateBundleReader: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateBundleManifestReader,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateBundleManifestReader: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxBundleFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxBundleFactory2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateBundleReader2(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, expecteddigest: windows_core::PCWSTR, bundlereader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleFactory2", "CreateBundleReader2", 3usize, |this| ((**(this as *const *const IAppxBundleFactory2_Vtbl)).CreateBundleReader2)(this, inputstream, expecteddigest, bundlereader), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateBundleReader2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateBundleReader2: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxBundleFactory2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxBundleManifestOptionalBundleInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPackageId(this: *mut core::ffi::c_void, packageid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleManifestOptionalBundleInfo", "GetPackageId", 3usize, |this| ((**(this as *const *const IAppxBundleManifestOptionalBundleInfo_Vtbl)).GetPackageId)(this, packageid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileName(this: *mut core::ffi::c_void, filename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleManifestOptionalBundleInfo", "GetFileName", 4usize, |this| ((**(this as *const *const IAppxBundleManifestOptionalBundleInfo_Vtbl)).GetFileName)(this, filename), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPackageInfoItems(this: *mut core::ffi::c_void, packageinfoitems: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleManifestOptionalBundleInfo", "GetPackageInfoItems", 5usize, |this| ((**(this as *const *const IAppxBundleManifestOptionalBundleInfo_Vtbl)).GetPackageInfoItems)(this, packageinfoitems), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPackageId, GetFileName, GetPackageInfoItems }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxBundleManifestOptionalBundleInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxBundleManifestOptionalBundleInfoEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, optionalbundle: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleManifestOptionalBundleInfoEnumerator", "GetCurrent", 3usize, |this| ((**(this as *const *const IAppxBundleManifestOptionalBundleInfoEnumerator_Vtbl)).GetCurrent)(this, optionalbundle), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHasCurrent(this: *mut core::ffi::c_void, hascurrent: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleManifestOptionalBundleInfoEnumerator", "GetHasCurrent", 4usize, |this| ((**(this as *const *const IAppxBundleManifestOptionalBundleInfoEnumerator_Vtbl)).GetHasCurrent)(this, hascurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hasnext: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleManifestOptionalBundleInfoEnumerator", "MoveNext", 5usize, |this| ((**(this as *const *const IAppxBundleManifestOptionalBundleInfoEnumerator_Vtbl)).MoveNext)(this, hasnext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrent, GetHasCurrent, MoveNext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxBundleManifestOptionalBundleInfoEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxBundleManifestPackageInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPackageType(this: *mut core::ffi::c_void, packagetype: *mut APPX_BUNDLE_PAYLOAD_PACKAGE_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleManifestPackageInfo", "GetPackageType", 3usize, |this| ((**(this as *const *const IAppxBundleManifestPackageInfo_Vtbl)).GetPackageType)(this, packagetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPackageId(this: *mut core::ffi::c_void, packageid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleManifestPackageInfo", "GetPackageId", 4usize, |this| ((**(this as *const *const IAppxBundleManifestPackageInfo_Vtbl)).GetPackageId)(this, packageid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileName(this: *mut core::ffi::c_void, filename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleManifestPackageInfo", "GetFileName", 5usize, |this| ((**(this as *const *const IAppxBundleManifestPackageInfo_Vtbl)).GetFileName)(this, filename), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOffset(this: *mut core::ffi::c_void, offset: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleManifestPackageInfo", "GetOffset", 6usize, |this| ((**(this as *const *const IAppxBundleManifestPackageInfo_Vtbl)).GetOffset)(this, offset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSize(this: *mut core::ffi::c_void, size: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleManifestPackageInfo", "GetSize", 7usize, |this| ((**(this as *const *const IAppxBundleManifestPackageInfo_Vtbl)).GetSize)(this, size), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResources(this: *mut core::ffi::c_void, resources: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleManifestPackageInfo", "GetResources", 8usize, |this| ((**(this as *const *const IAppxBundleManifestPackageInfo_Vtbl)).GetResources)(this, resources), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPackageType,
+            GetPackageId,
+            GetFileName,
+            GetOffset,
+            GetSize,
+            GetResources,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxBundleManifestPackageInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxBundleManifestPackageInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetIsPackageReference(this: *mut core::ffi::c_void, ispackagereference: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleManifestPackageInfo2", "GetIsPackageReference", 3usize, |this| ((**(this as *const *const IAppxBundleManifestPackageInfo2_Vtbl)).GetIsPackageReference)(this, ispackagereference), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIsNonQualifiedResourcePackage(this: *mut core::ffi::c_void, isnonqualifiedresourcepackage: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleManifestPackageInfo2", "GetIsNonQualifiedResourcePackage", 4usize, |this| ((**(this as *const *const IAppxBundleManifestPackageInfo2_Vtbl)).GetIsNonQualifiedResourcePackage)(this, isnonqualifiedresourcepackage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIsDefaultApplicablePackage(this: *mut core::ffi::c_void, isdefaultapplicablepackage: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleManifestPackageInfo2", "GetIsDefaultApplicablePackage", 5usize, |this| ((**(this as *const *const IAppxBundleManifestPackageInfo2_Vtbl)).GetIsDefaultApplicablePackage)(this, isdefaultapplicablepackage), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetIsPackageReference,
+            GetIsNonQualifiedResourcePackage,
+            GetIsDefaultApplicablePackage,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxBundleManifestPackageInfo2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxBundleManifestPackageInfo3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTargetDeviceFamilies(this: *mut core::ffi::c_void, targetdevicefamilies: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleManifestPackageInfo3", "GetTargetDeviceFamilies", 3usize, |this| ((**(this as *const *const IAppxBundleManifestPackageInfo3_Vtbl)).GetTargetDeviceFamilies)(this, targetdevicefamilies), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetTargetDeviceFamilies }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxBundleManifestPackageInfo3 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxBundleManifestPackageInfo4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetIsStub(this: *mut core::ffi::c_void, isstub: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleManifestPackageInfo4", "GetIsStub", 3usize, |this| ((**(this as *const *const IAppxBundleManifestPackageInfo4_Vtbl)).GetIsStub)(this, isstub), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetIsStub }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxBundleManifestPackageInfo4 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxBundleManifestPackageInfoEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, packageinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleManifestPackageInfoEnumerator", "GetCurrent", 3usize, |this| ((**(this as *const *const IAppxBundleManifestPackageInfoEnumerator_Vtbl)).GetCurrent)(this, packageinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHasCurrent(this: *mut core::ffi::c_void, hascurrent: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleManifestPackageInfoEnumerator", "GetHasCurrent", 4usize, |this| ((**(this as *const *const IAppxBundleManifestPackageInfoEnumerator_Vtbl)).GetHasCurrent)(this, hascurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hasnext: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleManifestPackageInfoEnumerator", "MoveNext", 5usize, |this| ((**(this as *const *const IAppxBundleManifestPackageInfoEnumerator_Vtbl)).MoveNext)(this, hasnext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrent, GetHasCurrent, MoveNext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxBundleManifestPackageInfoEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxBundleManifestReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPackageId(this: *mut core::ffi::c_void, packageid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleManifestReader", "GetPackageId", 3usize, |this| ((**(this as *const *const IAppxBundleManifestReader_Vtbl)).GetPackageId)(this, packageid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPackageInfoItems(this: *mut core::ffi::c_void, packageinfoitems: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleManifestReader", "GetPackageInfoItems", 4usize, |this| ((**(this as *const *const IAppxBundleManifestReader_Vtbl)).GetPackageInfoItems)(this, packageinfoitems), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetStream(this: *mut core::ffi::c_void, manifeststream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleManifestReader", "GetStream", 5usize, |this| ((**(this as *const *const IAppxBundleManifestReader_Vtbl)).GetStream)(this, manifeststream), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPackageId,
+            GetPackageInfoItems,
+            #[cfg(feature = "Win32_System_Com")]
+            GetStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxBundleManifestReader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxBundleManifestReader2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOptionalBundles(this: *mut core::ffi::c_void, optionalbundles: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleManifestReader2", "GetOptionalBundles", 3usize, |this| ((**(this as *const *const IAppxBundleManifestReader2_Vtbl)).GetOptionalBundles)(this, optionalbundles), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetOptionalBundles }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxBundleManifestReader2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxBundleReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFootprintFile(this: *mut core::ffi::c_void, filetype: APPX_BUNDLE_FOOTPRINT_FILE_TYPE, footprintfile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleReader", "GetFootprintFile", 3usize, |this| ((**(this as *const *const IAppxBundleReader_Vtbl)).GetFootprintFile)(this, filetype, footprintfile), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBlockMap(this: *mut core::ffi::c_void, blockmapreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleReader", "GetBlockMap", 4usize, |this| ((**(this as *const *const IAppxBundleReader_Vtbl)).GetBlockMap)(this, blockmapreader), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetManifest(this: *mut core::ffi::c_void, manifestreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleReader", "GetManifest", 5usize, |this| ((**(this as *const *const IAppxBundleReader_Vtbl)).GetManifest)(this, manifestreader), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPayloadPackages(this: *mut core::ffi::c_void, payloadpackages: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleReader", "GetPayloadPackages", 6usize, |this| ((**(this as *const *const IAppxBundleReader_Vtbl)).GetPayloadPackages)(this, payloadpackages), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPayloadPackage(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, payloadpackage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleReader", "GetPayloadPackage", 7usize, |this| ((**(this as *const *const IAppxBundleReader_Vtbl)).GetPayloadPackage)(this, filename, payloadpackage), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetFootprintFile,
+            GetBlockMap,
+            GetManifest,
+            GetPayloadPackages,
+            GetPayloadPackage,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxBundleReader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxBundleWriter_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddPayloadPackage(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, packagestream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleWriter", "AddPayloadPackage", 3usize, |this| ((**(this as *const *const IAppxBundleWriter_Vtbl)).AddPayloadPackage)(this, filename, packagestream), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleWriter", "Close", 4usize, |this| ((**(this as *const *const IAppxBundleWriter_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            AddPayloadPackage,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddPayloadPackage: 0,
+            Close,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxBundleWriter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxBundleWriter2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddExternalPackageReference(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, inputstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleWriter2", "AddExternalPackageReference", 3usize, |this| ((**(this as *const *const IAppxBundleWriter2_Vtbl)).AddExternalPackageReference)(this, filename, inputstream), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            AddExternalPackageReference,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddExternalPackageReference: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxBundleWriter2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxBundleWriter3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddPackageReference(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, inputstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleWriter3", "AddPackageReference", 3usize, |this| ((**(this as *const *const IAppxBundleWriter3_Vtbl)).AddPackageReference)(this, filename, inputstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void, hashmethodstring: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleWriter3", "Close", 4usize, |this| ((**(this as *const *const IAppxBundleWriter3_Vtbl)).Close)(this, hashmethodstring), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            AddPackageReference,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddPackageReference: 0,
+            Close,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxBundleWriter3 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxBundleWriter4_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddPayloadPackage(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, packagestream: *mut core::ffi::c_void, isdefaultapplicablepackage: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleWriter4", "AddPayloadPackage", 3usize, |this| ((**(this as *const *const IAppxBundleWriter4_Vtbl)).AddPayloadPackage)(this, filename, packagestream, isdefaultapplicablepackage), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddPackageReference(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, inputstream: *mut core::ffi::c_void, isdefaultapplicablepackage: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleWriter4", "AddPackageReference", 4usize, |this| ((**(this as *const *const IAppxBundleWriter4_Vtbl)).AddPackageReference)(this, filename, inputstream, isdefaultapplicablepackage), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddExternalPackageReference(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, inputstream: *mut core::ffi::c_void, isdefaultapplicablepackage: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxBundleWriter4", "AddExternalPackageReference", 5usize, |this| ((**(this as *const *const IAppxBundleWriter4_Vtbl)).AddExternalPackageReference)(this, filename, inputstream, isdefaultapplicablepackage), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            AddPayloadPackage,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddPayloadPackage: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AddPackageReference,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddPackageReference: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AddExternalPackageReference,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddExternalPackageReference: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxBundleWriter4 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxContentGroup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, groupname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxContentGroup", "GetName", 3usize, |this| ((**(this as *const *const IAppxContentGroup_Vtbl)).GetName)(this, groupname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFiles(this: *mut core::ffi::c_void, enumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxContentGroup", "GetFiles", 4usize, |this| ((**(this as *const *const IAppxContentGroup_Vtbl)).GetFiles)(this, enumerator), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetName, GetFiles }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxContentGroup as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxContentGroupFilesEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, file: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxContentGroupFilesEnumerator", "GetCurrent", 3usize, |this| ((**(this as *const *const IAppxContentGroupFilesEnumerator_Vtbl)).GetCurrent)(this, file), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHasCurrent(this: *mut core::ffi::c_void, hascurrent: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxContentGroupFilesEnumerator", "GetHasCurrent", 4usize, |this| ((**(this as *const *const IAppxContentGroupFilesEnumerator_Vtbl)).GetHasCurrent)(this, hascurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hasnext: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxContentGroupFilesEnumerator", "MoveNext", 5usize, |this| ((**(this as *const *const IAppxContentGroupFilesEnumerator_Vtbl)).MoveNext)(this, hasnext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrent, GetHasCurrent, MoveNext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxContentGroupFilesEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxContentGroupMapReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRequiredGroup(this: *mut core::ffi::c_void, requiredgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxContentGroupMapReader", "GetRequiredGroup", 3usize, |this| ((**(this as *const *const IAppxContentGroupMapReader_Vtbl)).GetRequiredGroup)(this, requiredgroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAutomaticGroups(this: *mut core::ffi::c_void, automaticgroupsenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxContentGroupMapReader", "GetAutomaticGroups", 4usize, |this| ((**(this as *const *const IAppxContentGroupMapReader_Vtbl)).GetAutomaticGroups)(this, automaticgroupsenumerator), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRequiredGroup, GetAutomaticGroups }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxContentGroupMapReader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxContentGroupMapWriter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddAutomaticGroup(this: *mut core::ffi::c_void, groupname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxContentGroupMapWriter", "AddAutomaticGroup", 3usize, |this| ((**(this as *const *const IAppxContentGroupMapWriter_Vtbl)).AddAutomaticGroup)(this, groupname), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddAutomaticFile(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxContentGroupMapWriter", "AddAutomaticFile", 4usize, |this| ((**(this as *const *const IAppxContentGroupMapWriter_Vtbl)).AddAutomaticFile)(this, filename), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxContentGroupMapWriter", "Close", 5usize, |this| ((**(this as *const *const IAppxContentGroupMapWriter_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddAutomaticGroup, AddAutomaticFile, Close }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxContentGroupMapWriter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxContentGroupsEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, stream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxContentGroupsEnumerator", "GetCurrent", 3usize, |this| ((**(this as *const *const IAppxContentGroupsEnumerator_Vtbl)).GetCurrent)(this, stream), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHasCurrent(this: *mut core::ffi::c_void, hascurrent: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxContentGroupsEnumerator", "GetHasCurrent", 4usize, |this| ((**(this as *const *const IAppxContentGroupsEnumerator_Vtbl)).GetHasCurrent)(this, hascurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hasnext: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxContentGroupsEnumerator", "MoveNext", 5usize, |this| ((**(this as *const *const IAppxContentGroupsEnumerator_Vtbl)).MoveNext)(this, hasnext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrent, GetHasCurrent, MoveNext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxContentGroupsEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxDigestProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDigest(this: *mut core::ffi::c_void, digest: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxDigestProvider", "GetDigest", 3usize, |this| ((**(this as *const *const IAppxDigestProvider_Vtbl)).GetDigest)(this, digest), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDigest }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxDigestProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxEncryptedBundleWriter_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddPayloadPackageEncrypted(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, packagestream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxEncryptedBundleWriter", "AddPayloadPackageEncrypted", 3usize, |this| ((**(this as *const *const IAppxEncryptedBundleWriter_Vtbl)).AddPayloadPackageEncrypted)(this, filename, packagestream), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxEncryptedBundleWriter", "Close", 4usize, |this| ((**(this as *const *const IAppxEncryptedBundleWriter_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            AddPayloadPackageEncrypted,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddPayloadPackageEncrypted: 0,
+            Close,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxEncryptedBundleWriter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxEncryptedBundleWriter2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddExternalPackageReference(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, inputstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxEncryptedBundleWriter2", "AddExternalPackageReference", 3usize, |this| ((**(this as *const *const IAppxEncryptedBundleWriter2_Vtbl)).AddExternalPackageReference)(this, filename, inputstream), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            AddExternalPackageReference,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddExternalPackageReference: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxEncryptedBundleWriter2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxEncryptedBundleWriter3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddPayloadPackageEncrypted(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, packagestream: *mut core::ffi::c_void, isdefaultapplicablepackage: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxEncryptedBundleWriter3", "AddPayloadPackageEncrypted", 3usize, |this| ((**(this as *const *const IAppxEncryptedBundleWriter3_Vtbl)).AddPayloadPackageEncrypted)(this, filename, packagestream, isdefaultapplicablepackage), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddExternalPackageReference(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, inputstream: *mut core::ffi::c_void, isdefaultapplicablepackage: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxEncryptedBundleWriter3", "AddExternalPackageReference", 4usize, |this| ((**(this as *const *const IAppxEncryptedBundleWriter3_Vtbl)).AddExternalPackageReference)(this, filename, inputstream, isdefaultapplicablepackage), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            AddPayloadPackageEncrypted,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddPayloadPackageEncrypted: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AddExternalPackageReference,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddExternalPackageReference: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxEncryptedBundleWriter3 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxEncryptedPackageWriter_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddPayloadFileEncrypted(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, compressionoption: APPX_COMPRESSION_OPTION, inputstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxEncryptedPackageWriter", "AddPayloadFileEncrypted", 3usize, |this| ((**(this as *const *const IAppxEncryptedPackageWriter_Vtbl)).AddPayloadFileEncrypted)(this, filename, compressionoption, inputstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxEncryptedPackageWriter", "Close", 4usize, |this| ((**(this as *const *const IAppxEncryptedPackageWriter_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            AddPayloadFileEncrypted,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddPayloadFileEncrypted: 0,
+            Close,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxEncryptedPackageWriter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxEncryptedPackageWriter2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddPayloadFilesEncrypted(this: *mut core::ffi::c_void, filecount: u32, payloadfiles: *const APPX_PACKAGE_WRITER_PAYLOAD_STREAM, memorylimit: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxEncryptedPackageWriter2", "AddPayloadFilesEncrypted", 3usize, |this| ((**(this as *const *const IAppxEncryptedPackageWriter2_Vtbl)).AddPayloadFilesEncrypted)(this, filecount, payloadfiles, memorylimit), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            AddPayloadFilesEncrypted,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddPayloadFilesEncrypted: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxEncryptedPackageWriter2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxEncryptionFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EncryptPackage(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, settings: *const APPX_ENCRYPTED_PACKAGE_SETTINGS, keyinfo: *const APPX_KEY_INFO, exemptedfiles: *const APPX_ENCRYPTED_EXEMPTIONS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxEncryptionFactory", "EncryptPackage", 3usize, |this| ((**(this as *const *const IAppxEncryptionFactory_Vtbl)).EncryptPackage)(this, inputstream, outputstream, settings, keyinfo, exemptedfiles), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DecryptPackage(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, keyinfo: *const APPX_KEY_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxEncryptionFactory", "DecryptPackage", 4usize, |this| ((**(this as *const *const IAppxEncryptionFactory_Vtbl)).DecryptPackage)(this, inputstream, outputstream, keyinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateEncryptedPackageWriter(this: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, manifeststream: *mut core::ffi::c_void, settings: *const APPX_ENCRYPTED_PACKAGE_SETTINGS, keyinfo: *const APPX_KEY_INFO, exemptedfiles: *const APPX_ENCRYPTED_EXEMPTIONS, packagewriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxEncryptionFactory", "CreateEncryptedPackageWriter", 5usize, |this| ((**(this as *const *const IAppxEncryptionFactory_Vtbl)).CreateEncryptedPackageWriter)(this, outputstream, manifeststream, settings, keyinfo, exemptedfiles, packagewriter), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateEncryptedPackageReader(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, keyinfo: *const APPX_KEY_INFO, packagereader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxEncryptionFactory", "CreateEncryptedPackageReader", 6usize, |this| ((**(this as *const *const IAppxEncryptionFactory_Vtbl)).CreateEncryptedPackageReader)(this, inputstream, keyinfo, packagereader), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EncryptBundle(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, settings: *const APPX_ENCRYPTED_PACKAGE_SETTINGS, keyinfo: *const APPX_KEY_INFO, exemptedfiles: *const APPX_ENCRYPTED_EXEMPTIONS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxEncryptionFactory", "EncryptBundle", 7usize, |this| ((**(this as *const *const IAppxEncryptionFactory_Vtbl)).EncryptBundle)(this, inputstream, outputstream, settings, keyinfo, exemptedfiles), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DecryptBundle(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, keyinfo: *const APPX_KEY_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxEncryptionFactory", "DecryptBundle", 8usize, |this| ((**(this as *const *const IAppxEncryptionFactory_Vtbl)).DecryptBundle)(this, inputstream, outputstream, keyinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateEncryptedBundleWriter(this: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, bundleversion: u64, settings: *const APPX_ENCRYPTED_PACKAGE_SETTINGS, keyinfo: *const APPX_KEY_INFO, exemptedfiles: *const APPX_ENCRYPTED_EXEMPTIONS, bundlewriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxEncryptionFactory", "CreateEncryptedBundleWriter", 9usize, |this| ((**(this as *const *const IAppxEncryptionFactory_Vtbl)).CreateEncryptedBundleWriter)(this, outputstream, bundleversion, settings, keyinfo, exemptedfiles, bundlewriter), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateEncryptedBundleReader(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, keyinfo: *const APPX_KEY_INFO, bundlereader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxEncryptionFactory", "CreateEncryptedBundleReader", 10usize, |this| ((**(this as *const *const IAppxEncryptionFactory_Vtbl)).CreateEncryptedBundleReader)(this, inputstream, keyinfo, bundlereader), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            EncryptPackage,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EncryptPackage: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            DecryptPackage,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DecryptPackage: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateEncryptedPackageWriter,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateEncryptedPackageWriter: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateEncryptedPackageReader,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateEncryptedPackageReader: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EncryptBundle,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EncryptBundle: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            DecryptBundle,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DecryptBundle: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateEncryptedBundleWriter,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateEncryptedBundleWriter: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateEncryptedBundleReader,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateEncryptedBundleReader: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxEncryptionFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxEncryptionFactory2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateEncryptedPackageWriter(this: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, manifeststream: *mut core::ffi::c_void, contentgroupmapstream: *mut core::ffi::c_void, settings: *const APPX_ENCRYPTED_PACKAGE_SETTINGS, keyinfo: *const APPX_KEY_INFO, exemptedfiles: *const APPX_ENCRYPTED_EXEMPTIONS, packagewriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxEncryptionFactory2", "CreateEncryptedPackageWriter", 3usize, |this| ((**(this as *const *const IAppxEncryptionFactory2_Vtbl)).CreateEncryptedPackageWriter)(this, outputstream, manifeststream, contentgroupmapstream, settings, keyinfo, exemptedfiles, packagewriter), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateEncryptedPackageWriter,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateEncryptedPackageWriter: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxEncryptionFactory2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxEncryptionFactory3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EncryptPackage(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, settings: *const APPX_ENCRYPTED_PACKAGE_SETTINGS2, keyinfo: *const APPX_KEY_INFO, exemptedfiles: *const APPX_ENCRYPTED_EXEMPTIONS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxEncryptionFactory3", "EncryptPackage", 3usize, |this| ((**(this as *const *const IAppxEncryptionFactory3_Vtbl)).EncryptPackage)(this, inputstream, outputstream, settings, keyinfo, exemptedfiles), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateEncryptedPackageWriter(this: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, manifeststream: *mut core::ffi::c_void, contentgroupmapstream: *mut core::ffi::c_void, settings: *const APPX_ENCRYPTED_PACKAGE_SETTINGS2, keyinfo: *const APPX_KEY_INFO, exemptedfiles: *const APPX_ENCRYPTED_EXEMPTIONS, packagewriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxEncryptionFactory3", "CreateEncryptedPackageWriter", 4usize, |this| ((**(this as *const *const IAppxEncryptionFactory3_Vtbl)).CreateEncryptedPackageWriter)(this, outputstream, manifeststream, contentgroupmapstream, settings, keyinfo, exemptedfiles, packagewriter), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EncryptBundle(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, settings: *const APPX_ENCRYPTED_PACKAGE_SETTINGS2, keyinfo: *const APPX_KEY_INFO, exemptedfiles: *const APPX_ENCRYPTED_EXEMPTIONS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxEncryptionFactory3", "EncryptBundle", 5usize, |this| ((**(this as *const *const IAppxEncryptionFactory3_Vtbl)).EncryptBundle)(this, inputstream, outputstream, settings, keyinfo, exemptedfiles), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateEncryptedBundleWriter(this: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, bundleversion: u64, settings: *const APPX_ENCRYPTED_PACKAGE_SETTINGS2, keyinfo: *const APPX_KEY_INFO, exemptedfiles: *const APPX_ENCRYPTED_EXEMPTIONS, bundlewriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxEncryptionFactory3", "CreateEncryptedBundleWriter", 6usize, |this| ((**(this as *const *const IAppxEncryptionFactory3_Vtbl)).CreateEncryptedBundleWriter)(this, outputstream, bundleversion, settings, keyinfo, exemptedfiles, bundlewriter), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            EncryptPackage,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EncryptPackage: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateEncryptedPackageWriter,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateEncryptedPackageWriter: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EncryptBundle,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EncryptBundle: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateEncryptedBundleWriter,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateEncryptedBundleWriter: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxEncryptionFactory3 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxEncryptionFactory4_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EncryptPackage(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, settings: *const APPX_ENCRYPTED_PACKAGE_SETTINGS2, keyinfo: *const APPX_KEY_INFO, exemptedfiles: *const APPX_ENCRYPTED_EXEMPTIONS, memorylimit: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxEncryptionFactory4", "EncryptPackage", 3usize, |this| ((**(this as *const *const IAppxEncryptionFactory4_Vtbl)).EncryptPackage)(this, inputstream, outputstream, settings, keyinfo, exemptedfiles, memorylimit), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            EncryptPackage,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EncryptPackage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxEncryptionFactory4 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxEncryptionFactory5_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateEncryptedPackageReader2(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, keyinfo: *const APPX_KEY_INFO, expecteddigest: windows_core::PCWSTR, packagereader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxEncryptionFactory5", "CreateEncryptedPackageReader2", 3usize, |this| ((**(this as *const *const IAppxEncryptionFactory5_Vtbl)).CreateEncryptedPackageReader2)(this, inputstream, keyinfo, expecteddigest, packagereader), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateEncryptedBundleReader2(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, keyinfo: *const APPX_KEY_INFO, expecteddigest: windows_core::PCWSTR, bundlereader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxEncryptionFactory5", "CreateEncryptedBundleReader2", 4usize, |this| ((**(this as *const *const IAppxEncryptionFactory5_Vtbl)).CreateEncryptedBundleReader2)(this, inputstream, keyinfo, expecteddigest, bundlereader), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateEncryptedPackageReader2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateEncryptedPackageReader2: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateEncryptedBundleReader2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateEncryptedBundleReader2: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxEncryptionFactory5 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreatePackageWriter(this: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, settings: *const APPX_PACKAGE_SETTINGS, packagewriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxFactory", "CreatePackageWriter", 3usize, |this| ((**(this as *const *const IAppxFactory_Vtbl)).CreatePackageWriter)(this, outputstream, settings, packagewriter), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreatePackageReader(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, packagereader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxFactory", "CreatePackageReader", 4usize, |this| ((**(this as *const *const IAppxFactory_Vtbl)).CreatePackageReader)(this, inputstream, packagereader), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateManifestReader(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, manifestreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxFactory", "CreateManifestReader", 5usize, |this| ((**(this as *const *const IAppxFactory_Vtbl)).CreateManifestReader)(this, inputstream, manifestreader), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateBlockMapReader(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, blockmapreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxFactory", "CreateBlockMapReader", 6usize, |this| ((**(this as *const *const IAppxFactory_Vtbl)).CreateBlockMapReader)(this, inputstream, blockmapreader), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateValidatedBlockMapReader(this: *mut core::ffi::c_void, blockmapstream: *mut core::ffi::c_void, signaturefilename: windows_core::PCWSTR, blockmapreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxFactory", "CreateValidatedBlockMapReader", 7usize, |this| ((**(this as *const *const IAppxFactory_Vtbl)).CreateValidatedBlockMapReader)(this, blockmapstream, signaturefilename, blockmapreader), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreatePackageWriter,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreatePackageWriter: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreatePackageReader,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreatePackageReader: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateManifestReader,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateManifestReader: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateBlockMapReader,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateBlockMapReader: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateValidatedBlockMapReader,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateValidatedBlockMapReader: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxFactory2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateContentGroupMapReader(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, contentgroupmapreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxFactory2", "CreateContentGroupMapReader", 3usize, |this| ((**(this as *const *const IAppxFactory2_Vtbl)).CreateContentGroupMapReader)(this, inputstream, contentgroupmapreader), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateSourceContentGroupMapReader(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, reader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxFactory2", "CreateSourceContentGroupMapReader", 4usize, |this| ((**(this as *const *const IAppxFactory2_Vtbl)).CreateSourceContentGroupMapReader)(this, inputstream, reader), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateContentGroupMapWriter(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, contentgroupmapwriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxFactory2", "CreateContentGroupMapWriter", 5usize, |this| ((**(this as *const *const IAppxFactory2_Vtbl)).CreateContentGroupMapWriter)(this, stream, contentgroupmapwriter), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateContentGroupMapReader,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateContentGroupMapReader: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateSourceContentGroupMapReader,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateSourceContentGroupMapReader: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateContentGroupMapWriter,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateContentGroupMapWriter: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxFactory2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxFactory3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreatePackageReader2(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, expecteddigest: windows_core::PCWSTR, packagereader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxFactory3", "CreatePackageReader2", 3usize, |this| ((**(this as *const *const IAppxFactory3_Vtbl)).CreatePackageReader2)(this, inputstream, expecteddigest, packagereader), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateManifestReader2(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, expecteddigest: windows_core::PCWSTR, manifestreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxFactory3", "CreateManifestReader2", 4usize, |this| ((**(this as *const *const IAppxFactory3_Vtbl)).CreateManifestReader2)(this, inputstream, expecteddigest, manifestreader), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateAppInstallerReader(this: *mut core::ffi::c_void, inputstream: *mut core::ffi::c_void, expecteddigest: windows_core::PCWSTR, appinstallerreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxFactory3", "CreateAppInstallerReader", 5usize, |this| ((**(this as *const *const IAppxFactory3_Vtbl)).CreateAppInstallerReader)(this, inputstream, expecteddigest, appinstallerreader), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreatePackageReader2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreatePackageReader2: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateManifestReader2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateManifestReader2: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateAppInstallerReader,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateAppInstallerReader: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxFactory3 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxFile_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCompressionOption(this: *mut core::ffi::c_void, compressionoption: *mut APPX_COMPRESSION_OPTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxFile", "GetCompressionOption", 3usize, |this| ((**(this as *const *const IAppxFile_Vtbl)).GetCompressionOption)(this, compressionoption), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContentType(this: *mut core::ffi::c_void, contenttype: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxFile", "GetContentType", 4usize, |this| ((**(this as *const *const IAppxFile_Vtbl)).GetContentType)(this, contenttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, filename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxFile", "GetName", 5usize, |this| ((**(this as *const *const IAppxFile_Vtbl)).GetName)(this, filename), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSize(this: *mut core::ffi::c_void, size: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxFile", "GetSize", 6usize, |this| ((**(this as *const *const IAppxFile_Vtbl)).GetSize)(this, size), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetStream(this: *mut core::ffi::c_void, stream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxFile", "GetStream", 7usize, |this| ((**(this as *const *const IAppxFile_Vtbl)).GetStream)(this, stream), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCompressionOption,
+            GetContentType,
+            GetName,
+            GetSize,
+            #[cfg(feature = "Win32_System_Com")]
+            GetStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxFile as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxFilesEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, file: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxFilesEnumerator", "GetCurrent", 3usize, |this| ((**(this as *const *const IAppxFilesEnumerator_Vtbl)).GetCurrent)(this, file), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHasCurrent(this: *mut core::ffi::c_void, hascurrent: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxFilesEnumerator", "GetHasCurrent", 4usize, |this| ((**(this as *const *const IAppxFilesEnumerator_Vtbl)).GetHasCurrent)(this, hascurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hasnext: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxFilesEnumerator", "MoveNext", 5usize, |this| ((**(this as *const *const IAppxFilesEnumerator_Vtbl)).MoveNext)(this, hasnext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrent, GetHasCurrent, MoveNext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxFilesEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestApplication_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStringValue(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestApplication", "GetStringValue", 3usize, |this| ((**(this as *const *const IAppxManifestApplication_Vtbl)).GetStringValue)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAppUserModelId(this: *mut core::ffi::c_void, appusermodelid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestApplication", "GetAppUserModelId", 4usize, |this| ((**(this as *const *const IAppxManifestApplication_Vtbl)).GetAppUserModelId)(this, appusermodelid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStringValue, GetAppUserModelId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestApplication as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestApplicationsEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, application: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestApplicationsEnumerator", "GetCurrent", 3usize, |this| ((**(this as *const *const IAppxManifestApplicationsEnumerator_Vtbl)).GetCurrent)(this, application), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHasCurrent(this: *mut core::ffi::c_void, hascurrent: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestApplicationsEnumerator", "GetHasCurrent", 4usize, |this| ((**(this as *const *const IAppxManifestApplicationsEnumerator_Vtbl)).GetHasCurrent)(this, hascurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hasnext: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestApplicationsEnumerator", "MoveNext", 5usize, |this| ((**(this as *const *const IAppxManifestApplicationsEnumerator_Vtbl)).MoveNext)(this, hasnext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrent, GetHasCurrent, MoveNext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestApplicationsEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestCapabilitiesEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, capability: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestCapabilitiesEnumerator", "GetCurrent", 3usize, |this| ((**(this as *const *const IAppxManifestCapabilitiesEnumerator_Vtbl)).GetCurrent)(this, capability), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHasCurrent(this: *mut core::ffi::c_void, hascurrent: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestCapabilitiesEnumerator", "GetHasCurrent", 4usize, |this| ((**(this as *const *const IAppxManifestCapabilitiesEnumerator_Vtbl)).GetHasCurrent)(this, hascurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hasnext: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestCapabilitiesEnumerator", "MoveNext", 5usize, |this| ((**(this as *const *const IAppxManifestCapabilitiesEnumerator_Vtbl)).MoveNext)(this, hasnext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrent, GetHasCurrent, MoveNext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestCapabilitiesEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestDeviceCapabilitiesEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, devicecapability: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestDeviceCapabilitiesEnumerator", "GetCurrent", 3usize, |this| ((**(this as *const *const IAppxManifestDeviceCapabilitiesEnumerator_Vtbl)).GetCurrent)(this, devicecapability), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHasCurrent(this: *mut core::ffi::c_void, hascurrent: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestDeviceCapabilitiesEnumerator", "GetHasCurrent", 4usize, |this| ((**(this as *const *const IAppxManifestDeviceCapabilitiesEnumerator_Vtbl)).GetHasCurrent)(this, hascurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hasnext: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestDeviceCapabilitiesEnumerator", "MoveNext", 5usize, |this| ((**(this as *const *const IAppxManifestDeviceCapabilitiesEnumerator_Vtbl)).MoveNext)(this, hasnext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrent, GetHasCurrent, MoveNext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestDeviceCapabilitiesEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestDriverConstraint_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, name: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestDriverConstraint", "GetName", 3usize, |this| ((**(this as *const *const IAppxManifestDriverConstraint_Vtbl)).GetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMinVersion(this: *mut core::ffi::c_void, minversion: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestDriverConstraint", "GetMinVersion", 4usize, |this| ((**(this as *const *const IAppxManifestDriverConstraint_Vtbl)).GetMinVersion)(this, minversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMinDate(this: *mut core::ffi::c_void, mindate: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestDriverConstraint", "GetMinDate", 5usize, |this| ((**(this as *const *const IAppxManifestDriverConstraint_Vtbl)).GetMinDate)(this, mindate), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetName, GetMinVersion, GetMinDate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestDriverConstraint as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestDriverConstraintsEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, driverconstraint: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestDriverConstraintsEnumerator", "GetCurrent", 3usize, |this| ((**(this as *const *const IAppxManifestDriverConstraintsEnumerator_Vtbl)).GetCurrent)(this, driverconstraint), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHasCurrent(this: *mut core::ffi::c_void, hascurrent: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestDriverConstraintsEnumerator", "GetHasCurrent", 4usize, |this| ((**(this as *const *const IAppxManifestDriverConstraintsEnumerator_Vtbl)).GetHasCurrent)(this, hascurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hasnext: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestDriverConstraintsEnumerator", "MoveNext", 5usize, |this| ((**(this as *const *const IAppxManifestDriverConstraintsEnumerator_Vtbl)).MoveNext)(this, hasnext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrent, GetHasCurrent, MoveNext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestDriverConstraintsEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestDriverDependenciesEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, driverdependency: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestDriverDependenciesEnumerator", "GetCurrent", 3usize, |this| ((**(this as *const *const IAppxManifestDriverDependenciesEnumerator_Vtbl)).GetCurrent)(this, driverdependency), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHasCurrent(this: *mut core::ffi::c_void, hascurrent: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestDriverDependenciesEnumerator", "GetHasCurrent", 4usize, |this| ((**(this as *const *const IAppxManifestDriverDependenciesEnumerator_Vtbl)).GetHasCurrent)(this, hascurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hasnext: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestDriverDependenciesEnumerator", "MoveNext", 5usize, |this| ((**(this as *const *const IAppxManifestDriverDependenciesEnumerator_Vtbl)).MoveNext)(this, hasnext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrent, GetHasCurrent, MoveNext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestDriverDependenciesEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestDriverDependency_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDriverConstraints(this: *mut core::ffi::c_void, driverconstraints: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestDriverDependency", "GetDriverConstraints", 3usize, |this| ((**(this as *const *const IAppxManifestDriverDependency_Vtbl)).GetDriverConstraints)(this, driverconstraints), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDriverConstraints }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestDriverDependency as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestHostRuntimeDependenciesEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, hostruntimedependency: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestHostRuntimeDependenciesEnumerator", "GetCurrent", 3usize, |this| ((**(this as *const *const IAppxManifestHostRuntimeDependenciesEnumerator_Vtbl)).GetCurrent)(this, hostruntimedependency), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHasCurrent(this: *mut core::ffi::c_void, hascurrent: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestHostRuntimeDependenciesEnumerator", "GetHasCurrent", 4usize, |this| ((**(this as *const *const IAppxManifestHostRuntimeDependenciesEnumerator_Vtbl)).GetHasCurrent)(this, hascurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hasnext: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestHostRuntimeDependenciesEnumerator", "MoveNext", 5usize, |this| ((**(this as *const *const IAppxManifestHostRuntimeDependenciesEnumerator_Vtbl)).MoveNext)(this, hasnext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrent, GetHasCurrent, MoveNext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestHostRuntimeDependenciesEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestHostRuntimeDependency_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, name: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestHostRuntimeDependency", "GetName", 3usize, |this| ((**(this as *const *const IAppxManifestHostRuntimeDependency_Vtbl)).GetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPublisher(this: *mut core::ffi::c_void, publisher: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestHostRuntimeDependency", "GetPublisher", 4usize, |this| ((**(this as *const *const IAppxManifestHostRuntimeDependency_Vtbl)).GetPublisher)(this, publisher), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMinVersion(this: *mut core::ffi::c_void, minversion: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestHostRuntimeDependency", "GetMinVersion", 5usize, |this| ((**(this as *const *const IAppxManifestHostRuntimeDependency_Vtbl)).GetMinVersion)(this, minversion), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetName, GetPublisher, GetMinVersion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestHostRuntimeDependency as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestHostRuntimeDependency2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPackageFamilyName(this: *mut core::ffi::c_void, packagefamilyname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestHostRuntimeDependency2", "GetPackageFamilyName", 3usize, |this| ((**(this as *const *const IAppxManifestHostRuntimeDependency2_Vtbl)).GetPackageFamilyName)(this, packagefamilyname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPackageFamilyName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestHostRuntimeDependency2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestMainPackageDependenciesEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, mainpackagedependency: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestMainPackageDependenciesEnumerator", "GetCurrent", 3usize, |this| ((**(this as *const *const IAppxManifestMainPackageDependenciesEnumerator_Vtbl)).GetCurrent)(this, mainpackagedependency), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHasCurrent(this: *mut core::ffi::c_void, hascurrent: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestMainPackageDependenciesEnumerator", "GetHasCurrent", 4usize, |this| ((**(this as *const *const IAppxManifestMainPackageDependenciesEnumerator_Vtbl)).GetHasCurrent)(this, hascurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hasnext: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestMainPackageDependenciesEnumerator", "MoveNext", 5usize, |this| ((**(this as *const *const IAppxManifestMainPackageDependenciesEnumerator_Vtbl)).MoveNext)(this, hasnext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrent, GetHasCurrent, MoveNext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestMainPackageDependenciesEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestMainPackageDependency_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, name: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestMainPackageDependency", "GetName", 3usize, |this| ((**(this as *const *const IAppxManifestMainPackageDependency_Vtbl)).GetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPublisher(this: *mut core::ffi::c_void, publisher: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestMainPackageDependency", "GetPublisher", 4usize, |this| ((**(this as *const *const IAppxManifestMainPackageDependency_Vtbl)).GetPublisher)(this, publisher), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPackageFamilyName(this: *mut core::ffi::c_void, packagefamilyname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestMainPackageDependency", "GetPackageFamilyName", 5usize, |this| ((**(this as *const *const IAppxManifestMainPackageDependency_Vtbl)).GetPackageFamilyName)(this, packagefamilyname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetName, GetPublisher, GetPackageFamilyName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestMainPackageDependency as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestOSPackageDependenciesEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, ospackagedependency: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestOSPackageDependenciesEnumerator", "GetCurrent", 3usize, |this| ((**(this as *const *const IAppxManifestOSPackageDependenciesEnumerator_Vtbl)).GetCurrent)(this, ospackagedependency), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHasCurrent(this: *mut core::ffi::c_void, hascurrent: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestOSPackageDependenciesEnumerator", "GetHasCurrent", 4usize, |this| ((**(this as *const *const IAppxManifestOSPackageDependenciesEnumerator_Vtbl)).GetHasCurrent)(this, hascurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hasnext: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestOSPackageDependenciesEnumerator", "MoveNext", 5usize, |this| ((**(this as *const *const IAppxManifestOSPackageDependenciesEnumerator_Vtbl)).MoveNext)(this, hasnext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrent, GetHasCurrent, MoveNext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestOSPackageDependenciesEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestOSPackageDependency_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, name: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestOSPackageDependency", "GetName", 3usize, |this| ((**(this as *const *const IAppxManifestOSPackageDependency_Vtbl)).GetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersion(this: *mut core::ffi::c_void, version: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestOSPackageDependency", "GetVersion", 4usize, |this| ((**(this as *const *const IAppxManifestOSPackageDependency_Vtbl)).GetVersion)(this, version), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetName, GetVersion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestOSPackageDependency as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestOptionalPackageInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetIsOptionalPackage(this: *mut core::ffi::c_void, isoptionalpackage: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestOptionalPackageInfo", "GetIsOptionalPackage", 3usize, |this| ((**(this as *const *const IAppxManifestOptionalPackageInfo_Vtbl)).GetIsOptionalPackage)(this, isoptionalpackage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMainPackageName(this: *mut core::ffi::c_void, mainpackagename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestOptionalPackageInfo", "GetMainPackageName", 4usize, |this| ((**(this as *const *const IAppxManifestOptionalPackageInfo_Vtbl)).GetMainPackageName)(this, mainpackagename), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetIsOptionalPackage, GetMainPackageName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestOptionalPackageInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestPackageDependenciesEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, dependency: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestPackageDependenciesEnumerator", "GetCurrent", 3usize, |this| ((**(this as *const *const IAppxManifestPackageDependenciesEnumerator_Vtbl)).GetCurrent)(this, dependency), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHasCurrent(this: *mut core::ffi::c_void, hascurrent: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestPackageDependenciesEnumerator", "GetHasCurrent", 4usize, |this| ((**(this as *const *const IAppxManifestPackageDependenciesEnumerator_Vtbl)).GetHasCurrent)(this, hascurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hasnext: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestPackageDependenciesEnumerator", "MoveNext", 5usize, |this| ((**(this as *const *const IAppxManifestPackageDependenciesEnumerator_Vtbl)).MoveNext)(this, hasnext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrent, GetHasCurrent, MoveNext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestPackageDependenciesEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestPackageDependency_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, name: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestPackageDependency", "GetName", 3usize, |this| ((**(this as *const *const IAppxManifestPackageDependency_Vtbl)).GetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPublisher(this: *mut core::ffi::c_void, publisher: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestPackageDependency", "GetPublisher", 4usize, |this| ((**(this as *const *const IAppxManifestPackageDependency_Vtbl)).GetPublisher)(this, publisher), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMinVersion(this: *mut core::ffi::c_void, minversion: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestPackageDependency", "GetMinVersion", 5usize, |this| ((**(this as *const *const IAppxManifestPackageDependency_Vtbl)).GetMinVersion)(this, minversion), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetName, GetPublisher, GetMinVersion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestPackageDependency as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestPackageDependency2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMaxMajorVersionTested(this: *mut core::ffi::c_void, maxmajorversiontested: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestPackageDependency2", "GetMaxMajorVersionTested", 6usize, |this| ((**(this as *const *const IAppxManifestPackageDependency2_Vtbl)).GetMaxMajorVersionTested)(this, maxmajorversiontested), |result| Some(*result))
+        }
+        Self { base__: <IAppxManifestPackageDependency_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMaxMajorVersionTested }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestPackageDependency2 as windows_core::Interface>::IID || <IAppxManifestPackageDependency_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestPackageDependency3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetIsOptional(this: *mut core::ffi::c_void, isoptional: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestPackageDependency3", "GetIsOptional", 3usize, |this| ((**(this as *const *const IAppxManifestPackageDependency3_Vtbl)).GetIsOptional)(this, isoptional), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetIsOptional }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestPackageDependency3 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestPackageId_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, name: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestPackageId", "GetName", 3usize, |this| ((**(this as *const *const IAppxManifestPackageId_Vtbl)).GetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetArchitecture(this: *mut core::ffi::c_void, architecture: *mut APPX_PACKAGE_ARCHITECTURE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestPackageId", "GetArchitecture", 4usize, |this| ((**(this as *const *const IAppxManifestPackageId_Vtbl)).GetArchitecture)(this, architecture), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPublisher(this: *mut core::ffi::c_void, publisher: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestPackageId", "GetPublisher", 5usize, |this| ((**(this as *const *const IAppxManifestPackageId_Vtbl)).GetPublisher)(this, publisher), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersion(this: *mut core::ffi::c_void, packageversion: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestPackageId", "GetVersion", 6usize, |this| ((**(this as *const *const IAppxManifestPackageId_Vtbl)).GetVersion)(this, packageversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResourceId(this: *mut core::ffi::c_void, resourceid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestPackageId", "GetResourceId", 7usize, |this| ((**(this as *const *const IAppxManifestPackageId_Vtbl)).GetResourceId)(this, resourceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ComparePublisher(this: *mut core::ffi::c_void, other: windows_core::PCWSTR, issame: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestPackageId", "ComparePublisher", 8usize, |this| ((**(this as *const *const IAppxManifestPackageId_Vtbl)).ComparePublisher)(this, other, issame), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPackageFullName(this: *mut core::ffi::c_void, packagefullname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestPackageId", "GetPackageFullName", 9usize, |this| ((**(this as *const *const IAppxManifestPackageId_Vtbl)).GetPackageFullName)(this, packagefullname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPackageFamilyName(this: *mut core::ffi::c_void, packagefamilyname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestPackageId", "GetPackageFamilyName", 10usize, |this| ((**(this as *const *const IAppxManifestPackageId_Vtbl)).GetPackageFamilyName)(this, packagefamilyname), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetName,
+            GetArchitecture,
+            GetPublisher,
+            GetVersion,
+            GetResourceId,
+            ComparePublisher,
+            GetPackageFullName,
+            GetPackageFamilyName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestPackageId as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestPackageId2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetArchitecture2(this: *mut core::ffi::c_void, architecture: *mut APPX_PACKAGE_ARCHITECTURE2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestPackageId2", "GetArchitecture2", 11usize, |this| ((**(this as *const *const IAppxManifestPackageId2_Vtbl)).GetArchitecture2)(this, architecture), |result| Some(*result))
+        }
+        Self { base__: <IAppxManifestPackageId_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetArchitecture2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestPackageId2 as windows_core::Interface>::IID || <IAppxManifestPackageId_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBoolValue(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, value: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestProperties", "GetBoolValue", 3usize, |this| ((**(this as *const *const IAppxManifestProperties_Vtbl)).GetBoolValue)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStringValue(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, value: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestProperties", "GetStringValue", 4usize, |this| ((**(this as *const *const IAppxManifestProperties_Vtbl)).GetStringValue)(this, name, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetBoolValue, GetStringValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestProperties as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestQualifiedResource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLanguage(this: *mut core::ffi::c_void, language: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestQualifiedResource", "GetLanguage", 3usize, |this| ((**(this as *const *const IAppxManifestQualifiedResource_Vtbl)).GetLanguage)(this, language), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScale(this: *mut core::ffi::c_void, scale: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestQualifiedResource", "GetScale", 4usize, |this| ((**(this as *const *const IAppxManifestQualifiedResource_Vtbl)).GetScale)(this, scale), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDXFeatureLevel(this: *mut core::ffi::c_void, dxfeaturelevel: *mut DX_FEATURE_LEVEL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestQualifiedResource", "GetDXFeatureLevel", 5usize, |this| ((**(this as *const *const IAppxManifestQualifiedResource_Vtbl)).GetDXFeatureLevel)(this, dxfeaturelevel), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetLanguage, GetScale, GetDXFeatureLevel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestQualifiedResource as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestQualifiedResourcesEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, resource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestQualifiedResourcesEnumerator", "GetCurrent", 3usize, |this| ((**(this as *const *const IAppxManifestQualifiedResourcesEnumerator_Vtbl)).GetCurrent)(this, resource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHasCurrent(this: *mut core::ffi::c_void, hascurrent: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestQualifiedResourcesEnumerator", "GetHasCurrent", 4usize, |this| ((**(this as *const *const IAppxManifestQualifiedResourcesEnumerator_Vtbl)).GetHasCurrent)(this, hascurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hasnext: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestQualifiedResourcesEnumerator", "MoveNext", 5usize, |this| ((**(this as *const *const IAppxManifestQualifiedResourcesEnumerator_Vtbl)).MoveNext)(this, hasnext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrent, GetHasCurrent, MoveNext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestQualifiedResourcesEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxManifestReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPackageId(this: *mut core::ffi::c_void, packageid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestReader", "GetPackageId", 3usize, |this| ((**(this as *const *const IAppxManifestReader_Vtbl)).GetPackageId)(this, packageid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, packageproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestReader", "GetProperties", 4usize, |this| ((**(this as *const *const IAppxManifestReader_Vtbl)).GetProperties)(this, packageproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPackageDependencies(this: *mut core::ffi::c_void, dependencies: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestReader", "GetPackageDependencies", 5usize, |this| ((**(this as *const *const IAppxManifestReader_Vtbl)).GetPackageDependencies)(this, dependencies), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCapabilities(this: *mut core::ffi::c_void, capabilities: *mut APPX_CAPABILITIES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestReader", "GetCapabilities", 6usize, |this| ((**(this as *const *const IAppxManifestReader_Vtbl)).GetCapabilities)(this, capabilities), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResources(this: *mut core::ffi::c_void, resources: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestReader", "GetResources", 7usize, |this| ((**(this as *const *const IAppxManifestReader_Vtbl)).GetResources)(this, resources), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceCapabilities(this: *mut core::ffi::c_void, devicecapabilities: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestReader", "GetDeviceCapabilities", 8usize, |this| ((**(this as *const *const IAppxManifestReader_Vtbl)).GetDeviceCapabilities)(this, devicecapabilities), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrerequisite(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, value: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestReader", "GetPrerequisite", 9usize, |this| ((**(this as *const *const IAppxManifestReader_Vtbl)).GetPrerequisite)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetApplications(this: *mut core::ffi::c_void, applications: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestReader", "GetApplications", 10usize, |this| ((**(this as *const *const IAppxManifestReader_Vtbl)).GetApplications)(this, applications), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetStream(this: *mut core::ffi::c_void, manifeststream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestReader", "GetStream", 11usize, |this| ((**(this as *const *const IAppxManifestReader_Vtbl)).GetStream)(this, manifeststream), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPackageId,
+            GetProperties,
+            GetPackageDependencies,
+            GetCapabilities,
+            GetResources,
+            GetDeviceCapabilities,
+            GetPrerequisite,
+            GetApplications,
+            #[cfg(feature = "Win32_System_Com")]
+            GetStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestReader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxManifestReader2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetQualifiedResources(this: *mut core::ffi::c_void, resources: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestReader2", "GetQualifiedResources", 12usize, |this| ((**(this as *const *const IAppxManifestReader2_Vtbl)).GetQualifiedResources)(this, resources), |result| Some(*result))
+        }
+        Self { base__: <IAppxManifestReader_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetQualifiedResources }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestReader2 as windows_core::Interface>::IID || <IAppxManifestReader_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxManifestReader3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCapabilitiesByCapabilityClass(this: *mut core::ffi::c_void, capabilityclass: APPX_CAPABILITY_CLASS_TYPE, capabilities: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestReader3", "GetCapabilitiesByCapabilityClass", 13usize, |this| ((**(this as *const *const IAppxManifestReader3_Vtbl)).GetCapabilitiesByCapabilityClass)(this, capabilityclass, capabilities), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTargetDeviceFamilies(this: *mut core::ffi::c_void, targetdevicefamilies: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestReader3", "GetTargetDeviceFamilies", 14usize, |this| ((**(this as *const *const IAppxManifestReader3_Vtbl)).GetTargetDeviceFamilies)(this, targetdevicefamilies), |result| Some(*result))
+        }
+        Self { base__: <IAppxManifestReader2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCapabilitiesByCapabilityClass, GetTargetDeviceFamilies }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestReader3 as windows_core::Interface>::IID || <IAppxManifestReader2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxManifestReader4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOptionalPackageInfo(this: *mut core::ffi::c_void, optionalpackageinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestReader4", "GetOptionalPackageInfo", 15usize, |this| ((**(this as *const *const IAppxManifestReader4_Vtbl)).GetOptionalPackageInfo)(this, optionalpackageinfo), |result| Some(*result))
+        }
+        Self { base__: <IAppxManifestReader3_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetOptionalPackageInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestReader4 as windows_core::Interface>::IID || <IAppxManifestReader3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestReader5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMainPackageDependencies(this: *mut core::ffi::c_void, mainpackagedependencies: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestReader5", "GetMainPackageDependencies", 3usize, |this| ((**(this as *const *const IAppxManifestReader5_Vtbl)).GetMainPackageDependencies)(this, mainpackagedependencies), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMainPackageDependencies }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestReader5 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestReader6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetIsNonQualifiedResourcePackage(this: *mut core::ffi::c_void, isnonqualifiedresourcepackage: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestReader6", "GetIsNonQualifiedResourcePackage", 3usize, |this| ((**(this as *const *const IAppxManifestReader6_Vtbl)).GetIsNonQualifiedResourcePackage)(this, isnonqualifiedresourcepackage), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetIsNonQualifiedResourcePackage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestReader6 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestReader7_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDriverDependencies(this: *mut core::ffi::c_void, driverdependencies: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestReader7", "GetDriverDependencies", 3usize, |this| ((**(this as *const *const IAppxManifestReader7_Vtbl)).GetDriverDependencies)(this, driverdependencies), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOSPackageDependencies(this: *mut core::ffi::c_void, ospackagedependencies: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestReader7", "GetOSPackageDependencies", 4usize, |this| ((**(this as *const *const IAppxManifestReader7_Vtbl)).GetOSPackageDependencies)(this, ospackagedependencies), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHostRuntimeDependencies(this: *mut core::ffi::c_void, hostruntimedependencies: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestReader7", "GetHostRuntimeDependencies", 5usize, |this| ((**(this as *const *const IAppxManifestReader7_Vtbl)).GetHostRuntimeDependencies)(this, hostruntimedependencies), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDriverDependencies,
+            GetOSPackageDependencies,
+            GetHostRuntimeDependencies,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestReader7 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestResourcesEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, resource: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestResourcesEnumerator", "GetCurrent", 3usize, |this| ((**(this as *const *const IAppxManifestResourcesEnumerator_Vtbl)).GetCurrent)(this, resource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHasCurrent(this: *mut core::ffi::c_void, hascurrent: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestResourcesEnumerator", "GetHasCurrent", 4usize, |this| ((**(this as *const *const IAppxManifestResourcesEnumerator_Vtbl)).GetHasCurrent)(this, hascurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hasnext: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestResourcesEnumerator", "MoveNext", 5usize, |this| ((**(this as *const *const IAppxManifestResourcesEnumerator_Vtbl)).MoveNext)(this, hasnext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrent, GetHasCurrent, MoveNext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestResourcesEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestTargetDeviceFamiliesEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, targetdevicefamily: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestTargetDeviceFamiliesEnumerator", "GetCurrent", 3usize, |this| ((**(this as *const *const IAppxManifestTargetDeviceFamiliesEnumerator_Vtbl)).GetCurrent)(this, targetdevicefamily), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHasCurrent(this: *mut core::ffi::c_void, hascurrent: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestTargetDeviceFamiliesEnumerator", "GetHasCurrent", 4usize, |this| ((**(this as *const *const IAppxManifestTargetDeviceFamiliesEnumerator_Vtbl)).GetHasCurrent)(this, hascurrent), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hasnext: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestTargetDeviceFamiliesEnumerator", "MoveNext", 5usize, |this| ((**(this as *const *const IAppxManifestTargetDeviceFamiliesEnumerator_Vtbl)).MoveNext)(this, hasnext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrent, GetHasCurrent, MoveNext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestTargetDeviceFamiliesEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxManifestTargetDeviceFamily_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, name: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestTargetDeviceFamily", "GetName", 3usize, |this| ((**(this as *const *const IAppxManifestTargetDeviceFamily_Vtbl)).GetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMinVersion(this: *mut core::ffi::c_void, minversion: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestTargetDeviceFamily", "GetMinVersion", 4usize, |this| ((**(this as *const *const IAppxManifestTargetDeviceFamily_Vtbl)).GetMinVersion)(this, minversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxVersionTested(this: *mut core::ffi::c_void, maxversiontested: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxManifestTargetDeviceFamily", "GetMaxVersionTested", 5usize, |this| ((**(this as *const *const IAppxManifestTargetDeviceFamily_Vtbl)).GetMaxVersionTested)(this, maxversiontested), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetName, GetMinVersion, GetMaxVersionTested }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxManifestTargetDeviceFamily as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxPackageEditor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetWorkingDirectory(this: *mut core::ffi::c_void, workingdirectory: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxPackageEditor", "SetWorkingDirectory", 3usize, |this| ((**(this as *const *const IAppxPackageEditor_Vtbl)).SetWorkingDirectory)(this, workingdirectory), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateDeltaPackage(this: *mut core::ffi::c_void, updatedpackagestream: *mut core::ffi::c_void, baselinepackagestream: *mut core::ffi::c_void, deltapackagestream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxPackageEditor", "CreateDeltaPackage", 4usize, |this| ((**(this as *const *const IAppxPackageEditor_Vtbl)).CreateDeltaPackage)(this, updatedpackagestream, baselinepackagestream, deltapackagestream), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateDeltaPackageUsingBaselineBlockMap(this: *mut core::ffi::c_void, updatedpackagestream: *mut core::ffi::c_void, baselineblockmapstream: *mut core::ffi::c_void, baselinepackagefullname: windows_core::PCWSTR, deltapackagestream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxPackageEditor", "CreateDeltaPackageUsingBaselineBlockMap", 5usize, |this| ((**(this as *const *const IAppxPackageEditor_Vtbl)).CreateDeltaPackageUsingBaselineBlockMap)(this, updatedpackagestream, baselineblockmapstream, baselinepackagefullname, deltapackagestream), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn UpdatePackage(this: *mut core::ffi::c_void, baselinepackagestream: *mut core::ffi::c_void, deltapackagestream: *mut core::ffi::c_void, updateoption: APPX_PACKAGE_EDITOR_UPDATE_PACKAGE_OPTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxPackageEditor", "UpdatePackage", 6usize, |this| ((**(this as *const *const IAppxPackageEditor_Vtbl)).UpdatePackage)(this, baselinepackagestream, deltapackagestream, updateoption), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn UpdateEncryptedPackage(this: *mut core::ffi::c_void, baselineencryptedpackagestream: *mut core::ffi::c_void, deltapackagestream: *mut core::ffi::c_void, updateoption: APPX_PACKAGE_EDITOR_UPDATE_PACKAGE_OPTION, settings: *const APPX_ENCRYPTED_PACKAGE_SETTINGS2, keyinfo: *const APPX_KEY_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxPackageEditor", "UpdateEncryptedPackage", 7usize, |this| ((**(this as *const *const IAppxPackageEditor_Vtbl)).UpdateEncryptedPackage)(this, baselineencryptedpackagestream, deltapackagestream, updateoption, settings, keyinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn UpdatePackageManifest(this: *mut core::ffi::c_void, packagestream: *mut core::ffi::c_void, updatedmanifeststream: *mut core::ffi::c_void, ispackageencrypted: super::super::super::Foundation::BOOL, options: APPX_PACKAGE_EDITOR_UPDATE_PACKAGE_MANIFEST_OPTIONS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxPackageEditor", "UpdatePackageManifest", 8usize, |this| ((**(this as *const *const IAppxPackageEditor_Vtbl)).UpdatePackageManifest)(this, packagestream, updatedmanifeststream, ispackageencrypted, options), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetWorkingDirectory,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateDeltaPackage,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateDeltaPackage: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateDeltaPackageUsingBaselineBlockMap,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateDeltaPackageUsingBaselineBlockMap: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            UpdatePackage,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            UpdatePackage: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            UpdateEncryptedPackage,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            UpdateEncryptedPackage: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            UpdatePackageManifest,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            UpdatePackageManifest: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxPackageEditor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxPackageReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBlockMap(this: *mut core::ffi::c_void, blockmapreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxPackageReader", "GetBlockMap", 3usize, |this| ((**(this as *const *const IAppxPackageReader_Vtbl)).GetBlockMap)(this, blockmapreader), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFootprintFile(this: *mut core::ffi::c_void, r#type: APPX_FOOTPRINT_FILE_TYPE, file: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxPackageReader", "GetFootprintFile", 4usize, |this| ((**(this as *const *const IAppxPackageReader_Vtbl)).GetFootprintFile)(this, r#type, file), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPayloadFile(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, file: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxPackageReader", "GetPayloadFile", 5usize, |this| ((**(this as *const *const IAppxPackageReader_Vtbl)).GetPayloadFile)(this, filename, file), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPayloadFiles(this: *mut core::ffi::c_void, filesenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxPackageReader", "GetPayloadFiles", 6usize, |this| ((**(this as *const *const IAppxPackageReader_Vtbl)).GetPayloadFiles)(this, filesenumerator), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetManifest(this: *mut core::ffi::c_void, manifestreader: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxPackageReader", "GetManifest", 7usize, |this| ((**(this as *const *const IAppxPackageReader_Vtbl)).GetManifest)(this, manifestreader), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetBlockMap,
+            GetFootprintFile,
+            GetPayloadFile,
+            GetPayloadFiles,
+            GetManifest,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxPackageReader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxPackageWriter_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddPayloadFile(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, contenttype: windows_core::PCWSTR, compressionoption: APPX_COMPRESSION_OPTION, inputstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxPackageWriter", "AddPayloadFile", 3usize, |this| ((**(this as *const *const IAppxPackageWriter_Vtbl)).AddPayloadFile)(this, filename, contenttype, compressionoption, inputstream), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void, manifest: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxPackageWriter", "Close", 4usize, |this| ((**(this as *const *const IAppxPackageWriter_Vtbl)).Close)(this, manifest), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            AddPayloadFile,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddPayloadFile: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Close,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Close: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxPackageWriter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxPackageWriter2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void, manifest: *mut core::ffi::c_void, contentgroupmap: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxPackageWriter2", "Close", 3usize, |this| ((**(this as *const *const IAppxPackageWriter2_Vtbl)).Close)(this, manifest, contentgroupmap), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Close,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Close: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxPackageWriter2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppxPackageWriter3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddPayloadFiles(this: *mut core::ffi::c_void, filecount: u32, payloadfiles: *const APPX_PACKAGE_WRITER_PAYLOAD_STREAM, memorylimit: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxPackageWriter3", "AddPayloadFiles", 3usize, |this| ((**(this as *const *const IAppxPackageWriter3_Vtbl)).AddPayloadFiles)(this, filecount, payloadfiles, memorylimit), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            AddPayloadFiles,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddPayloadFiles: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxPackageWriter3 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxPackagingDiagnosticEventSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportContextChange(this: *mut core::ffi::c_void, changetype: APPX_PACKAGING_CONTEXT_CHANGE_TYPE, contextid: i32, contextname: windows_core::PCSTR, contextmessage: windows_core::PCWSTR, detailsmessage: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxPackagingDiagnosticEventSink", "ReportContextChange", 3usize, |this| ((**(this as *const *const IAppxPackagingDiagnosticEventSink_Vtbl)).ReportContextChange)(this, changetype, contextid, contextname, contextmessage, detailsmessage), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportError(this: *mut core::ffi::c_void, errormessage: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxPackagingDiagnosticEventSink", "ReportError", 4usize, |this| ((**(this as *const *const IAppxPackagingDiagnosticEventSink_Vtbl)).ReportError)(this, errormessage), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReportContextChange, ReportError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxPackagingDiagnosticEventSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxPackagingDiagnosticEventSinkManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSinkForProcess(this: *mut core::ffi::c_void, sink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxPackagingDiagnosticEventSinkManager", "SetSinkForProcess", 3usize, |this| ((**(this as *const *const IAppxPackagingDiagnosticEventSinkManager_Vtbl)).SetSinkForProcess)(this, sink), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetSinkForProcess }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxPackagingDiagnosticEventSinkManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppxSourceContentGroupMapReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRequiredGroup(this: *mut core::ffi::c_void, requiredgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxSourceContentGroupMapReader", "GetRequiredGroup", 3usize, |this| ((**(this as *const *const IAppxSourceContentGroupMapReader_Vtbl)).GetRequiredGroup)(this, requiredgroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAutomaticGroups(this: *mut core::ffi::c_void, automaticgroupsenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppxSourceContentGroupMapReader", "GetAutomaticGroups", 4usize, |this| ((**(this as *const *const IAppxSourceContentGroupMapReader_Vtbl)).GetAutomaticGroups)(this, automaticgroupsenumerator), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRequiredGroup, GetAutomaticGroups }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppxSourceContentGroupMapReader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
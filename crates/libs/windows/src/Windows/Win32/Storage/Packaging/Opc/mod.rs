@@ -1584,6 +1584,7 @@ pub struct OPC_CANONICALIZATION_METHOD(pub i32);
 impl windows_core::TypeKind for OPC_CANONICALIZATION_METHOD {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPC_CANONICALIZATION_METHOD {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPC_CANONICALIZATION_METHOD").field(&self.0).finish()
@@ -1595,6 +1596,7 @@ pub struct OPC_CERTIFICATE_EMBEDDING_OPTION(pub i32);
 impl windows_core::TypeKind for OPC_CERTIFICATE_EMBEDDING_OPTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPC_CERTIFICATE_EMBEDDING_OPTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPC_CERTIFICATE_EMBEDDING_OPTION").field(&self.0).finish()
@@ -1606,6 +1608,7 @@ pub struct OPC_COMPRESSION_OPTIONS(pub i32);
 impl windows_core::TypeKind for OPC_COMPRESSION_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPC_COMPRESSION_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPC_COMPRESSION_OPTIONS").field(&self.0).finish()
@@ -1617,6 +1620,7 @@ pub struct OPC_READ_FLAGS(pub i32);
 impl windows_core::TypeKind for OPC_READ_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPC_READ_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPC_READ_FLAGS").field(&self.0).finish()
@@ -1661,6 +1665,7 @@ pub struct OPC_RELATIONSHIPS_SIGNING_OPTION(pub i32);
 impl windows_core::TypeKind for OPC_RELATIONSHIPS_SIGNING_OPTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPC_RELATIONSHIPS_SIGNING_OPTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPC_RELATIONSHIPS_SIGNING_OPTION").field(&self.0).finish()
@@ -1672,6 +1677,7 @@ pub struct OPC_RELATIONSHIP_SELECTOR(pub i32);
 impl windows_core::TypeKind for OPC_RELATIONSHIP_SELECTOR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPC_RELATIONSHIP_SELECTOR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPC_RELATIONSHIP_SELECTOR").field(&self.0).finish()
@@ -1683,6 +1689,7 @@ pub struct OPC_SIGNATURE_TIME_FORMAT(pub i32);
 impl windows_core::TypeKind for OPC_SIGNATURE_TIME_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPC_SIGNATURE_TIME_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPC_SIGNATURE_TIME_FORMAT").field(&self.0).finish()
@@ -1694,6 +1701,7 @@ pub struct OPC_SIGNATURE_VALIDATION_RESULT(pub i32);
 impl windows_core::TypeKind for OPC_SIGNATURE_VALIDATION_RESULT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPC_SIGNATURE_VALIDATION_RESULT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPC_SIGNATURE_VALIDATION_RESULT").field(&self.0).finish()
@@ -1705,6 +1713,7 @@ pub struct OPC_STREAM_IO_MODE(pub i32);
 impl windows_core::TypeKind for OPC_STREAM_IO_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPC_STREAM_IO_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPC_STREAM_IO_MODE").field(&self.0).finish()
@@ -1716,6 +1725,7 @@ pub struct OPC_URI_TARGET_MODE(pub i32);
 impl windows_core::TypeKind for OPC_URI_TARGET_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPC_URI_TARGET_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPC_URI_TARGET_MODE").field(&self.0).finish()
@@ -1727,6 +1737,7 @@ pub struct OPC_WRITE_FLAGS(pub i32);
 impl windows_core::TypeKind for OPC_WRITE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPC_WRITE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPC_WRITE_FLAGS").field(&self.0).finish()
@@ -1768,3 +1779,5 @@ impl core::ops::Not for OPC_WRITE_FLAGS {
 pub const OpcFactory: windows_core::GUID = windows_core::GUID::from_u128(0x6b2d6ba0_9f3e_4f27_920b_313cc426a39e);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1050 @@
+#[cfg(feature = "Win32_Security_Cryptography")]
+impl windows_core::imp::ProxyVtable for IOpcCertificateEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hasnext: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcCertificateEnumerator", "MoveNext", 3usize, |this| ((**(this as *const *const IOpcCertificateEnumerator_Vtbl)).MoveNext)(this, hasnext), |result| Some(*result))
+        }
+        unsafe extern "system" fn MovePrevious(this: *mut core::ffi::c_void, hasprevious: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcCertificateEnumerator", "MovePrevious", 4usize, |this| ((**(this as *const *const IOpcCertificateEnumerator_Vtbl)).MovePrevious)(this, hasprevious), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Security_Cryptography")]
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, certificate: *mut *mut super::super::super::Security::Cryptography::CERT_CONTEXT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcCertificateEnumerator", "GetCurrent", 5usize, |this| ((**(this as *const *const IOpcCertificateEnumerator_Vtbl)).GetCurrent)(this, certificate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, copy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcCertificateEnumerator", "Clone", 6usize, |this| ((**(this as *const *const IOpcCertificateEnumerator_Vtbl)).Clone)(this, copy), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MoveNext,
+            MovePrevious,
+            #[cfg(feature = "Win32_Security_Cryptography")]
+            GetCurrent,
+            #[cfg(not(feature = "Win32_Security_Cryptography"))]
+            GetCurrent: 0,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcCertificateEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Security_Cryptography")]
+impl windows_core::imp::ProxyVtable for IOpcCertificateSet_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Security_Cryptography")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, certificate: *const super::super::super::Security::Cryptography::CERT_CONTEXT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcCertificateSet", "Add", 3usize, |this| ((**(this as *const *const IOpcCertificateSet_Vtbl)).Add)(this, certificate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Security_Cryptography")]
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, certificate: *const super::super::super::Security::Cryptography::CERT_CONTEXT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcCertificateSet", "Remove", 4usize, |this| ((**(this as *const *const IOpcCertificateSet_Vtbl)).Remove)(this, certificate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnumerator(this: *mut core::ffi::c_void, certificateenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcCertificateSet", "GetEnumerator", 5usize, |this| ((**(this as *const *const IOpcCertificateSet_Vtbl)).GetEnumerator)(this, certificateenumerator), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Security_Cryptography")]
+            Add,
+            #[cfg(not(feature = "Win32_Security_Cryptography"))]
+            Add: 0,
+            #[cfg(feature = "Win32_Security_Cryptography")]
+            Remove,
+            #[cfg(not(feature = "Win32_Security_Cryptography"))]
+            Remove: 0,
+            GetEnumerator,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcCertificateSet as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOpcDigitalSignature_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNamespaces(this: *mut core::ffi::c_void, prefixes: *mut *mut windows_core::PWSTR, namespaces: *mut *mut windows_core::PWSTR, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcDigitalSignature", "GetNamespaces", 3usize, |this| ((**(this as *const *const IOpcDigitalSignature_Vtbl)).GetNamespaces)(this, prefixes, namespaces, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignatureId(this: *mut core::ffi::c_void, signatureid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcDigitalSignature", "GetSignatureId", 4usize, |this| ((**(this as *const *const IOpcDigitalSignature_Vtbl)).GetSignatureId)(this, signatureid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSignaturePartName(this: *mut core::ffi::c_void, signaturepartname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcDigitalSignature", "GetSignaturePartName", 5usize, |this| ((**(this as *const *const IOpcDigitalSignature_Vtbl)).GetSignaturePartName)(this, signaturepartname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignatureMethod(this: *mut core::ffi::c_void, signaturemethod: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcDigitalSignature", "GetSignatureMethod", 6usize, |this| ((**(this as *const *const IOpcDigitalSignature_Vtbl)).GetSignatureMethod)(this, signaturemethod), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCanonicalizationMethod(this: *mut core::ffi::c_void, canonicalizationmethod: *mut OPC_CANONICALIZATION_METHOD) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcDigitalSignature", "GetCanonicalizationMethod", 7usize, |this| ((**(this as *const *const IOpcDigitalSignature_Vtbl)).GetCanonicalizationMethod)(this, canonicalizationmethod), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignatureValue(this: *mut core::ffi::c_void, signaturevalue: *mut *mut u8, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcDigitalSignature", "GetSignatureValue", 8usize, |this| ((**(this as *const *const IOpcDigitalSignature_Vtbl)).GetSignatureValue)(this, signaturevalue, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignaturePartReferenceEnumerator(this: *mut core::ffi::c_void, partreferenceenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcDigitalSignature", "GetSignaturePartReferenceEnumerator", 9usize, |this| ((**(this as *const *const IOpcDigitalSignature_Vtbl)).GetSignaturePartReferenceEnumerator)(this, partreferenceenumerator), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignatureRelationshipReferenceEnumerator(this: *mut core::ffi::c_void, relationshipreferenceenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcDigitalSignature", "GetSignatureRelationshipReferenceEnumerator", 10usize, |this| ((**(this as *const *const IOpcDigitalSignature_Vtbl)).GetSignatureRelationshipReferenceEnumerator)(this, relationshipreferenceenumerator), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSigningTime(this: *mut core::ffi::c_void, signingtime: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcDigitalSignature", "GetSigningTime", 11usize, |this| ((**(this as *const *const IOpcDigitalSignature_Vtbl)).GetSigningTime)(this, signingtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTimeFormat(this: *mut core::ffi::c_void, timeformat: *mut OPC_SIGNATURE_TIME_FORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcDigitalSignature", "GetTimeFormat", 12usize, |this| ((**(this as *const *const IOpcDigitalSignature_Vtbl)).GetTimeFormat)(this, timeformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPackageObjectReference(this: *mut core::ffi::c_void, packageobjectreference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcDigitalSignature", "GetPackageObjectReference", 13usize, |this| ((**(this as *const *const IOpcDigitalSignature_Vtbl)).GetPackageObjectReference)(this, packageobjectreference), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCertificateEnumerator(this: *mut core::ffi::c_void, certificateenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcDigitalSignature", "GetCertificateEnumerator", 14usize, |this| ((**(this as *const *const IOpcDigitalSignature_Vtbl)).GetCertificateEnumerator)(this, certificateenumerator), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCustomReferenceEnumerator(this: *mut core::ffi::c_void, customreferenceenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcDigitalSignature", "GetCustomReferenceEnumerator", 15usize, |this| ((**(this as *const *const IOpcDigitalSignature_Vtbl)).GetCustomReferenceEnumerator)(this, customreferenceenumerator), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCustomObjectEnumerator(this: *mut core::ffi::c_void, customobjectenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcDigitalSignature", "GetCustomObjectEnumerator", 16usize, |this| ((**(this as *const *const IOpcDigitalSignature_Vtbl)).GetCustomObjectEnumerator)(this, customobjectenumerator), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignatureXml(this: *mut core::ffi::c_void, signaturexml: *mut *mut u8, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcDigitalSignature", "GetSignatureXml", 17usize, |this| ((**(this as *const *const IOpcDigitalSignature_Vtbl)).GetSignatureXml)(this, signaturexml, count), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetNamespaces,
+            GetSignatureId,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSignaturePartName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSignaturePartName: 0,
+            GetSignatureMethod,
+            GetCanonicalizationMethod,
+            GetSignatureValue,
+            GetSignaturePartReferenceEnumerator,
+            GetSignatureRelationshipReferenceEnumerator,
+            GetSigningTime,
+            GetTimeFormat,
+            GetPackageObjectReference,
+            GetCertificateEnumerator,
+            GetCustomReferenceEnumerator,
+            GetCustomObjectEnumerator,
+            GetSignatureXml,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcDigitalSignature as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOpcDigitalSignatureEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hasnext: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcDigitalSignatureEnumerator", "MoveNext", 3usize, |this| ((**(this as *const *const IOpcDigitalSignatureEnumerator_Vtbl)).MoveNext)(this, hasnext), |result| Some(*result))
+        }
+        unsafe extern "system" fn MovePrevious(this: *mut core::ffi::c_void, hasprevious: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcDigitalSignatureEnumerator", "MovePrevious", 4usize, |this| ((**(this as *const *const IOpcDigitalSignatureEnumerator_Vtbl)).MovePrevious)(this, hasprevious), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, digitalsignature: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcDigitalSignatureEnumerator", "GetCurrent", 5usize, |this| ((**(this as *const *const IOpcDigitalSignatureEnumerator_Vtbl)).GetCurrent)(this, digitalsignature), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, copy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcDigitalSignatureEnumerator", "Clone", 6usize, |this| ((**(this as *const *const IOpcDigitalSignatureEnumerator_Vtbl)).Clone)(this, copy), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MoveNext, MovePrevious, GetCurrent, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcDigitalSignatureEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Security_Cryptography", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IOpcDigitalSignatureManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSignatureOriginPartName(this: *mut core::ffi::c_void, signatureoriginpartname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcDigitalSignatureManager", "GetSignatureOriginPartName", 3usize, |this| ((**(this as *const *const IOpcDigitalSignatureManager_Vtbl)).GetSignatureOriginPartName)(this, signatureoriginpartname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSignatureOriginPartName(this: *mut core::ffi::c_void, signatureoriginpartname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcDigitalSignatureManager", "SetSignatureOriginPartName", 4usize, |this| ((**(this as *const *const IOpcDigitalSignatureManager_Vtbl)).SetSignatureOriginPartName)(this, signatureoriginpartname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignatureEnumerator(this: *mut core::ffi::c_void, signatureenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcDigitalSignatureManager", "GetSignatureEnumerator", 5usize, |this| ((**(this as *const *const IOpcDigitalSignatureManager_Vtbl)).GetSignatureEnumerator)(this, signatureenumerator), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RemoveSignature(this: *mut core::ffi::c_void, signaturepartname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcDigitalSignatureManager", "RemoveSignature", 6usize, |this| ((**(this as *const *const IOpcDigitalSignatureManager_Vtbl)).RemoveSignature)(this, signaturepartname), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSigningOptions(this: *mut core::ffi::c_void, signingoptions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcDigitalSignatureManager", "CreateSigningOptions", 7usize, |this| ((**(this as *const *const IOpcDigitalSignatureManager_Vtbl)).CreateSigningOptions)(this, signingoptions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Security_Cryptography")]
+        unsafe extern "system" fn Validate(this: *mut core::ffi::c_void, signature: *mut core::ffi::c_void, certificate: *const super::super::super::Security::Cryptography::CERT_CONTEXT, validationresult: *mut OPC_SIGNATURE_VALIDATION_RESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcDigitalSignatureManager", "Validate", 8usize, |this| ((**(this as *const *const IOpcDigitalSignatureManager_Vtbl)).Validate)(this, signature, certificate, validationresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Security_Cryptography")]
+        unsafe extern "system" fn Sign(this: *mut core::ffi::c_void, certificate: *const super::super::super::Security::Cryptography::CERT_CONTEXT, signingoptions: *mut core::ffi::c_void, digitalsignature: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcDigitalSignatureManager", "Sign", 9usize, |this| ((**(this as *const *const IOpcDigitalSignatureManager_Vtbl)).Sign)(this, certificate, signingoptions, digitalsignature), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ReplaceSignatureXml(this: *mut core::ffi::c_void, signaturepartname: *mut core::ffi::c_void, newsignaturexml: *const u8, count: u32, digitalsignature: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcDigitalSignatureManager", "ReplaceSignatureXml", 10usize, |this| ((**(this as *const *const IOpcDigitalSignatureManager_Vtbl)).ReplaceSignatureXml)(this, signaturepartname, newsignaturexml, count, digitalsignature), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSignatureOriginPartName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSignatureOriginPartName: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSignatureOriginPartName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSignatureOriginPartName: 0,
+            GetSignatureEnumerator,
+            #[cfg(feature = "Win32_System_Com")]
+            RemoveSignature,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RemoveSignature: 0,
+            CreateSigningOptions,
+            #[cfg(feature = "Win32_Security_Cryptography")]
+            Validate,
+            #[cfg(not(feature = "Win32_Security_Cryptography"))]
+            Validate: 0,
+            #[cfg(feature = "Win32_Security_Cryptography")]
+            Sign,
+            #[cfg(not(feature = "Win32_Security_Cryptography"))]
+            Sign: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ReplaceSignatureXml,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ReplaceSignatureXml: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcDigitalSignatureManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Security", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IOpcFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreatePackageRootUri(this: *mut core::ffi::c_void, rooturi: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcFactory", "CreatePackageRootUri", 3usize, |this| ((**(this as *const *const IOpcFactory_Vtbl)).CreatePackageRootUri)(this, rooturi), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreatePartUri(this: *mut core::ffi::c_void, pwzuri: windows_core::PCWSTR, parturi: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcFactory", "CreatePartUri", 4usize, |this| ((**(this as *const *const IOpcFactory_Vtbl)).CreatePartUri)(this, pwzuri, parturi), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Security", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn CreateStreamOnFile(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, iomode: OPC_STREAM_IO_MODE, securityattributes: *const super::super::super::Security::SECURITY_ATTRIBUTES, dwflagsandattributes: u32, stream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcFactory", "CreateStreamOnFile", 5usize, |this| ((**(this as *const *const IOpcFactory_Vtbl)).CreateStreamOnFile)(this, filename, iomode, securityattributes, dwflagsandattributes, stream), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePackage(this: *mut core::ffi::c_void, package: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcFactory", "CreatePackage", 6usize, |this| ((**(this as *const *const IOpcFactory_Vtbl)).CreatePackage)(this, package), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ReadPackageFromStream(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, flags: OPC_READ_FLAGS, package: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcFactory", "ReadPackageFromStream", 7usize, |this| ((**(this as *const *const IOpcFactory_Vtbl)).ReadPackageFromStream)(this, stream, flags, package), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn WritePackageToStream(this: *mut core::ffi::c_void, package: *mut core::ffi::c_void, flags: OPC_WRITE_FLAGS, stream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcFactory", "WritePackageToStream", 8usize, |this| ((**(this as *const *const IOpcFactory_Vtbl)).WritePackageToStream)(this, package, flags, stream), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDigitalSignatureManager(this: *mut core::ffi::c_void, package: *mut core::ffi::c_void, signaturemanager: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcFactory", "CreateDigitalSignatureManager", 9usize, |this| ((**(this as *const *const IOpcFactory_Vtbl)).CreateDigitalSignatureManager)(this, package, signaturemanager), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreatePackageRootUri,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreatePackageRootUri: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreatePartUri,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreatePartUri: 0,
+            #[cfg(all(feature = "Win32_Security", feature = "Win32_System_Com"))]
+            CreateStreamOnFile,
+            #[cfg(not(all(feature = "Win32_Security", feature = "Win32_System_Com")))]
+            CreateStreamOnFile: 0,
+            CreatePackage,
+            #[cfg(feature = "Win32_System_Com")]
+            ReadPackageFromStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ReadPackageFromStream: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            WritePackageToStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            WritePackageToStream: 0,
+            CreateDigitalSignatureManager,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOpcPackage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPartSet(this: *mut core::ffi::c_void, partset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcPackage", "GetPartSet", 3usize, |this| ((**(this as *const *const IOpcPackage_Vtbl)).GetPartSet)(this, partset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRelationshipSet(this: *mut core::ffi::c_void, relationshipset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcPackage", "GetRelationshipSet", 4usize, |this| ((**(this as *const *const IOpcPackage_Vtbl)).GetRelationshipSet)(this, relationshipset), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPartSet, GetRelationshipSet }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcPackage as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOpcPart_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRelationshipSet(this: *mut core::ffi::c_void, relationshipset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcPart", "GetRelationshipSet", 3usize, |this| ((**(this as *const *const IOpcPart_Vtbl)).GetRelationshipSet)(this, relationshipset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetContentStream(this: *mut core::ffi::c_void, stream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcPart", "GetContentStream", 4usize, |this| ((**(this as *const *const IOpcPart_Vtbl)).GetContentStream)(this, stream), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, name: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcPart", "GetName", 5usize, |this| ((**(this as *const *const IOpcPart_Vtbl)).GetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContentType(this: *mut core::ffi::c_void, contenttype: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcPart", "GetContentType", 6usize, |this| ((**(this as *const *const IOpcPart_Vtbl)).GetContentType)(this, contenttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCompressionOptions(this: *mut core::ffi::c_void, compressionoptions: *mut OPC_COMPRESSION_OPTIONS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcPart", "GetCompressionOptions", 7usize, |this| ((**(this as *const *const IOpcPart_Vtbl)).GetCompressionOptions)(this, compressionoptions), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetRelationshipSet,
+            #[cfg(feature = "Win32_System_Com")]
+            GetContentStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetContentStream: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetName: 0,
+            GetContentType,
+            GetCompressionOptions,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcPart as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOpcPartEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hasnext: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcPartEnumerator", "MoveNext", 3usize, |this| ((**(this as *const *const IOpcPartEnumerator_Vtbl)).MoveNext)(this, hasnext), |result| Some(*result))
+        }
+        unsafe extern "system" fn MovePrevious(this: *mut core::ffi::c_void, hasprevious: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcPartEnumerator", "MovePrevious", 4usize, |this| ((**(this as *const *const IOpcPartEnumerator_Vtbl)).MovePrevious)(this, hasprevious), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, part: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcPartEnumerator", "GetCurrent", 5usize, |this| ((**(this as *const *const IOpcPartEnumerator_Vtbl)).GetCurrent)(this, part), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, copy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcPartEnumerator", "Clone", 6usize, |this| ((**(this as *const *const IOpcPartEnumerator_Vtbl)).Clone)(this, copy), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MoveNext, MovePrevious, GetCurrent, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcPartEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOpcPartSet_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetPart(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, part: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcPartSet", "GetPart", 3usize, |this| ((**(this as *const *const IOpcPartSet_Vtbl)).GetPart)(this, name, part), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreatePart(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, contenttype: windows_core::PCWSTR, compressionoptions: OPC_COMPRESSION_OPTIONS, part: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcPartSet", "CreatePart", 4usize, |this| ((**(this as *const *const IOpcPartSet_Vtbl)).CreatePart)(this, name, contenttype, compressionoptions, part), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DeletePart(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcPartSet", "DeletePart", 5usize, |this| ((**(this as *const *const IOpcPartSet_Vtbl)).DeletePart)(this, name), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PartExists(this: *mut core::ffi::c_void, name: *mut core::ffi::c_void, partexists: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcPartSet", "PartExists", 6usize, |this| ((**(this as *const *const IOpcPartSet_Vtbl)).PartExists)(this, name, partexists), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnumerator(this: *mut core::ffi::c_void, partenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcPartSet", "GetEnumerator", 7usize, |this| ((**(this as *const *const IOpcPartSet_Vtbl)).GetEnumerator)(this, partenumerator), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetPart,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetPart: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreatePart,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreatePart: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            DeletePart,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DeletePart: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PartExists,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PartExists: 0,
+            GetEnumerator,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcPartSet as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOpcPartUri_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ComparePartUri(this: *mut core::ffi::c_void, parturi: *mut core::ffi::c_void, comparisonresult: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcPartUri", "ComparePartUri", 31usize, |this| ((**(this as *const *const IOpcPartUri_Vtbl)).ComparePartUri)(this, parturi, comparisonresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSourceUri(this: *mut core::ffi::c_void, sourceuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcPartUri", "GetSourceUri", 32usize, |this| ((**(this as *const *const IOpcPartUri_Vtbl)).GetSourceUri)(this, sourceuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRelationshipsPartUri(this: *mut core::ffi::c_void, isrelationshipuri: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcPartUri", "IsRelationshipsPartUri", 33usize, |this| ((**(this as *const *const IOpcPartUri_Vtbl)).IsRelationshipsPartUri)(this, isrelationshipuri), |result| Some(*result))
+        }
+        Self {
+            base__: <IOpcUri_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            ComparePartUri,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ComparePartUri: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSourceUri,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSourceUri: 0,
+            IsRelationshipsPartUri,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcPartUri as windows_core::Interface>::IID || <IOpcUri_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOpcRelationship_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetId(this: *mut core::ffi::c_void, relationshipidentifier: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcRelationship", "GetId", 3usize, |this| ((**(this as *const *const IOpcRelationship_Vtbl)).GetId)(this, relationshipidentifier), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRelationshipType(this: *mut core::ffi::c_void, relationshiptype: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcRelationship", "GetRelationshipType", 4usize, |this| ((**(this as *const *const IOpcRelationship_Vtbl)).GetRelationshipType)(this, relationshiptype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSourceUri(this: *mut core::ffi::c_void, sourceuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcRelationship", "GetSourceUri", 5usize, |this| ((**(this as *const *const IOpcRelationship_Vtbl)).GetSourceUri)(this, sourceuri), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetTargetUri(this: *mut core::ffi::c_void, targeturi: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcRelationship", "GetTargetUri", 6usize, |this| ((**(this as *const *const IOpcRelationship_Vtbl)).GetTargetUri)(this, targeturi), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTargetMode(this: *mut core::ffi::c_void, targetmode: *mut OPC_URI_TARGET_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcRelationship", "GetTargetMode", 7usize, |this| ((**(this as *const *const IOpcRelationship_Vtbl)).GetTargetMode)(this, targetmode), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetId,
+            GetRelationshipType,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSourceUri,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSourceUri: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetTargetUri,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetTargetUri: 0,
+            GetTargetMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcRelationship as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOpcRelationshipEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hasnext: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcRelationshipEnumerator", "MoveNext", 3usize, |this| ((**(this as *const *const IOpcRelationshipEnumerator_Vtbl)).MoveNext)(this, hasnext), |result| Some(*result))
+        }
+        unsafe extern "system" fn MovePrevious(this: *mut core::ffi::c_void, hasprevious: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcRelationshipEnumerator", "MovePrevious", 4usize, |this| ((**(this as *const *const IOpcRelationshipEnumerator_Vtbl)).MovePrevious)(this, hasprevious), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, relationship: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcRelationshipEnumerator", "GetCurrent", 5usize, |this| ((**(this as *const *const IOpcRelationshipEnumerator_Vtbl)).GetCurrent)(this, relationship), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, copy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcRelationshipEnumerator", "Clone", 6usize, |this| ((**(this as *const *const IOpcRelationshipEnumerator_Vtbl)).Clone)(this, copy), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MoveNext, MovePrevious, GetCurrent, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcRelationshipEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOpcRelationshipSelector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSelectorType(this: *mut core::ffi::c_void, selector: *mut OPC_RELATIONSHIP_SELECTOR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcRelationshipSelector", "GetSelectorType", 3usize, |this| ((**(this as *const *const IOpcRelationshipSelector_Vtbl)).GetSelectorType)(this, selector), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSelectionCriterion(this: *mut core::ffi::c_void, selectioncriterion: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcRelationshipSelector", "GetSelectionCriterion", 4usize, |this| ((**(this as *const *const IOpcRelationshipSelector_Vtbl)).GetSelectionCriterion)(this, selectioncriterion), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSelectorType, GetSelectionCriterion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcRelationshipSelector as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOpcRelationshipSelectorEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hasnext: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcRelationshipSelectorEnumerator", "MoveNext", 3usize, |this| ((**(this as *const *const IOpcRelationshipSelectorEnumerator_Vtbl)).MoveNext)(this, hasnext), |result| Some(*result))
+        }
+        unsafe extern "system" fn MovePrevious(this: *mut core::ffi::c_void, hasprevious: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcRelationshipSelectorEnumerator", "MovePrevious", 4usize, |this| ((**(this as *const *const IOpcRelationshipSelectorEnumerator_Vtbl)).MovePrevious)(this, hasprevious), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, relationshipselector: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcRelationshipSelectorEnumerator", "GetCurrent", 5usize, |this| ((**(this as *const *const IOpcRelationshipSelectorEnumerator_Vtbl)).GetCurrent)(this, relationshipselector), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, copy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcRelationshipSelectorEnumerator", "Clone", 6usize, |this| ((**(this as *const *const IOpcRelationshipSelectorEnumerator_Vtbl)).Clone)(this, copy), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MoveNext, MovePrevious, GetCurrent, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcRelationshipSelectorEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOpcRelationshipSelectorSet_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, selector: OPC_RELATIONSHIP_SELECTOR, selectioncriterion: windows_core::PCWSTR, relationshipselector: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcRelationshipSelectorSet", "Create", 3usize, |this| ((**(this as *const *const IOpcRelationshipSelectorSet_Vtbl)).Create)(this, selector, selectioncriterion, relationshipselector), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, relationshipselector: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcRelationshipSelectorSet", "Delete", 4usize, |this| ((**(this as *const *const IOpcRelationshipSelectorSet_Vtbl)).Delete)(this, relationshipselector), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnumerator(this: *mut core::ffi::c_void, relationshipselectorenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcRelationshipSelectorSet", "GetEnumerator", 5usize, |this| ((**(this as *const *const IOpcRelationshipSelectorSet_Vtbl)).GetEnumerator)(this, relationshipselectorenumerator), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create, Delete, GetEnumerator }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcRelationshipSelectorSet as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOpcRelationshipSet_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRelationship(this: *mut core::ffi::c_void, relationshipidentifier: windows_core::PCWSTR, relationship: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcRelationshipSet", "GetRelationship", 3usize, |this| ((**(this as *const *const IOpcRelationshipSet_Vtbl)).GetRelationship)(this, relationshipidentifier, relationship), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateRelationship(this: *mut core::ffi::c_void, relationshipidentifier: windows_core::PCWSTR, relationshiptype: windows_core::PCWSTR, targeturi: *mut core::ffi::c_void, targetmode: OPC_URI_TARGET_MODE, relationship: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcRelationshipSet", "CreateRelationship", 4usize, |this| ((**(this as *const *const IOpcRelationshipSet_Vtbl)).CreateRelationship)(this, relationshipidentifier, relationshiptype, targeturi, targetmode, relationship), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteRelationship(this: *mut core::ffi::c_void, relationshipidentifier: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcRelationshipSet", "DeleteRelationship", 5usize, |this| ((**(this as *const *const IOpcRelationshipSet_Vtbl)).DeleteRelationship)(this, relationshipidentifier), |result| Some(*result))
+        }
+        unsafe extern "system" fn RelationshipExists(this: *mut core::ffi::c_void, relationshipidentifier: windows_core::PCWSTR, relationshipexists: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcRelationshipSet", "RelationshipExists", 6usize, |this| ((**(this as *const *const IOpcRelationshipSet_Vtbl)).RelationshipExists)(this, relationshipidentifier, relationshipexists), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnumerator(this: *mut core::ffi::c_void, relationshipenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcRelationshipSet", "GetEnumerator", 7usize, |this| ((**(this as *const *const IOpcRelationshipSet_Vtbl)).GetEnumerator)(this, relationshipenumerator), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnumeratorForType(this: *mut core::ffi::c_void, relationshiptype: windows_core::PCWSTR, relationshipenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcRelationshipSet", "GetEnumeratorForType", 8usize, |this| ((**(this as *const *const IOpcRelationshipSet_Vtbl)).GetEnumeratorForType)(this, relationshiptype, relationshipenumerator), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetRelationshipsContentStream(this: *mut core::ffi::c_void, contents: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcRelationshipSet", "GetRelationshipsContentStream", 9usize, |this| ((**(this as *const *const IOpcRelationshipSet_Vtbl)).GetRelationshipsContentStream)(this, contents), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetRelationship,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateRelationship,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateRelationship: 0,
+            DeleteRelationship,
+            RelationshipExists,
+            GetEnumerator,
+            GetEnumeratorForType,
+            #[cfg(feature = "Win32_System_Com")]
+            GetRelationshipsContentStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetRelationshipsContentStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcRelationshipSet as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOpcSignatureCustomObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetXml(this: *mut core::ffi::c_void, xmlmarkup: *mut *mut u8, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureCustomObject", "GetXml", 3usize, |this| ((**(this as *const *const IOpcSignatureCustomObject_Vtbl)).GetXml)(this, xmlmarkup, count), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetXml }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcSignatureCustomObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOpcSignatureCustomObjectEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hasnext: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureCustomObjectEnumerator", "MoveNext", 3usize, |this| ((**(this as *const *const IOpcSignatureCustomObjectEnumerator_Vtbl)).MoveNext)(this, hasnext), |result| Some(*result))
+        }
+        unsafe extern "system" fn MovePrevious(this: *mut core::ffi::c_void, hasprevious: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureCustomObjectEnumerator", "MovePrevious", 4usize, |this| ((**(this as *const *const IOpcSignatureCustomObjectEnumerator_Vtbl)).MovePrevious)(this, hasprevious), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, customobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureCustomObjectEnumerator", "GetCurrent", 5usize, |this| ((**(this as *const *const IOpcSignatureCustomObjectEnumerator_Vtbl)).GetCurrent)(this, customobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, copy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureCustomObjectEnumerator", "Clone", 6usize, |this| ((**(this as *const *const IOpcSignatureCustomObjectEnumerator_Vtbl)).Clone)(this, copy), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MoveNext, MovePrevious, GetCurrent, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcSignatureCustomObjectEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOpcSignatureCustomObjectSet_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, xmlmarkup: *const u8, count: u32, customobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureCustomObjectSet", "Create", 3usize, |this| ((**(this as *const *const IOpcSignatureCustomObjectSet_Vtbl)).Create)(this, xmlmarkup, count, customobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, customobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureCustomObjectSet", "Delete", 4usize, |this| ((**(this as *const *const IOpcSignatureCustomObjectSet_Vtbl)).Delete)(this, customobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnumerator(this: *mut core::ffi::c_void, customobjectenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureCustomObjectSet", "GetEnumerator", 5usize, |this| ((**(this as *const *const IOpcSignatureCustomObjectSet_Vtbl)).GetEnumerator)(this, customobjectenumerator), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create, Delete, GetEnumerator }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcSignatureCustomObjectSet as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOpcSignaturePartReference_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetPartName(this: *mut core::ffi::c_void, partname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignaturePartReference", "GetPartName", 3usize, |this| ((**(this as *const *const IOpcSignaturePartReference_Vtbl)).GetPartName)(this, partname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContentType(this: *mut core::ffi::c_void, contenttype: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignaturePartReference", "GetContentType", 4usize, |this| ((**(this as *const *const IOpcSignaturePartReference_Vtbl)).GetContentType)(this, contenttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDigestMethod(this: *mut core::ffi::c_void, digestmethod: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignaturePartReference", "GetDigestMethod", 5usize, |this| ((**(this as *const *const IOpcSignaturePartReference_Vtbl)).GetDigestMethod)(this, digestmethod), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDigestValue(this: *mut core::ffi::c_void, digestvalue: *mut *mut u8, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignaturePartReference", "GetDigestValue", 6usize, |this| ((**(this as *const *const IOpcSignaturePartReference_Vtbl)).GetDigestValue)(this, digestvalue, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransformMethod(this: *mut core::ffi::c_void, transformmethod: *mut OPC_CANONICALIZATION_METHOD) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignaturePartReference", "GetTransformMethod", 7usize, |this| ((**(this as *const *const IOpcSignaturePartReference_Vtbl)).GetTransformMethod)(this, transformmethod), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetPartName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetPartName: 0,
+            GetContentType,
+            GetDigestMethod,
+            GetDigestValue,
+            GetTransformMethod,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcSignaturePartReference as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOpcSignaturePartReferenceEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hasnext: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignaturePartReferenceEnumerator", "MoveNext", 3usize, |this| ((**(this as *const *const IOpcSignaturePartReferenceEnumerator_Vtbl)).MoveNext)(this, hasnext), |result| Some(*result))
+        }
+        unsafe extern "system" fn MovePrevious(this: *mut core::ffi::c_void, hasprevious: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignaturePartReferenceEnumerator", "MovePrevious", 4usize, |this| ((**(this as *const *const IOpcSignaturePartReferenceEnumerator_Vtbl)).MovePrevious)(this, hasprevious), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, partreference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignaturePartReferenceEnumerator", "GetCurrent", 5usize, |this| ((**(this as *const *const IOpcSignaturePartReferenceEnumerator_Vtbl)).GetCurrent)(this, partreference), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, copy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignaturePartReferenceEnumerator", "Clone", 6usize, |this| ((**(this as *const *const IOpcSignaturePartReferenceEnumerator_Vtbl)).Clone)(this, copy), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MoveNext, MovePrevious, GetCurrent, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcSignaturePartReferenceEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOpcSignaturePartReferenceSet_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, parturi: *mut core::ffi::c_void, digestmethod: windows_core::PCWSTR, transformmethod: OPC_CANONICALIZATION_METHOD, partreference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignaturePartReferenceSet", "Create", 3usize, |this| ((**(this as *const *const IOpcSignaturePartReferenceSet_Vtbl)).Create)(this, parturi, digestmethod, transformmethod, partreference), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, partreference: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignaturePartReferenceSet", "Delete", 4usize, |this| ((**(this as *const *const IOpcSignaturePartReferenceSet_Vtbl)).Delete)(this, partreference), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnumerator(this: *mut core::ffi::c_void, partreferenceenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignaturePartReferenceSet", "GetEnumerator", 5usize, |this| ((**(this as *const *const IOpcSignaturePartReferenceSet_Vtbl)).GetEnumerator)(this, partreferenceenumerator), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Create,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Create: 0,
+            Delete,
+            GetEnumerator,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcSignaturePartReferenceSet as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOpcSignatureReference_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetId(this: *mut core::ffi::c_void, referenceid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureReference", "GetId", 3usize, |this| ((**(this as *const *const IOpcSignatureReference_Vtbl)).GetId)(this, referenceid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetUri(this: *mut core::ffi::c_void, referenceuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureReference", "GetUri", 4usize, |this| ((**(this as *const *const IOpcSignatureReference_Vtbl)).GetUri)(this, referenceuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, r#type: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureReference", "GetType", 5usize, |this| ((**(this as *const *const IOpcSignatureReference_Vtbl)).GetType)(this, r#type), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransformMethod(this: *mut core::ffi::c_void, transformmethod: *mut OPC_CANONICALIZATION_METHOD) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureReference", "GetTransformMethod", 6usize, |this| ((**(this as *const *const IOpcSignatureReference_Vtbl)).GetTransformMethod)(this, transformmethod), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDigestMethod(this: *mut core::ffi::c_void, digestmethod: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureReference", "GetDigestMethod", 7usize, |this| ((**(this as *const *const IOpcSignatureReference_Vtbl)).GetDigestMethod)(this, digestmethod), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDigestValue(this: *mut core::ffi::c_void, digestvalue: *mut *mut u8, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureReference", "GetDigestValue", 8usize, |this| ((**(this as *const *const IOpcSignatureReference_Vtbl)).GetDigestValue)(this, digestvalue, count), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetId,
+            #[cfg(feature = "Win32_System_Com")]
+            GetUri,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetUri: 0,
+            GetType,
+            GetTransformMethod,
+            GetDigestMethod,
+            GetDigestValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcSignatureReference as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOpcSignatureReferenceEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hasnext: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureReferenceEnumerator", "MoveNext", 3usize, |this| ((**(this as *const *const IOpcSignatureReferenceEnumerator_Vtbl)).MoveNext)(this, hasnext), |result| Some(*result))
+        }
+        unsafe extern "system" fn MovePrevious(this: *mut core::ffi::c_void, hasprevious: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureReferenceEnumerator", "MovePrevious", 4usize, |this| ((**(this as *const *const IOpcSignatureReferenceEnumerator_Vtbl)).MovePrevious)(this, hasprevious), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, reference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureReferenceEnumerator", "GetCurrent", 5usize, |this| ((**(this as *const *const IOpcSignatureReferenceEnumerator_Vtbl)).GetCurrent)(this, reference), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, copy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureReferenceEnumerator", "Clone", 6usize, |this| ((**(this as *const *const IOpcSignatureReferenceEnumerator_Vtbl)).Clone)(this, copy), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MoveNext, MovePrevious, GetCurrent, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcSignatureReferenceEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOpcSignatureReferenceSet_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, referenceuri: *mut core::ffi::c_void, referenceid: windows_core::PCWSTR, r#type: windows_core::PCWSTR, digestmethod: windows_core::PCWSTR, transformmethod: OPC_CANONICALIZATION_METHOD, reference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureReferenceSet", "Create", 3usize, |this| ((**(this as *const *const IOpcSignatureReferenceSet_Vtbl)).Create)(this, referenceuri, referenceid, r#type, digestmethod, transformmethod, reference), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, reference: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureReferenceSet", "Delete", 4usize, |this| ((**(this as *const *const IOpcSignatureReferenceSet_Vtbl)).Delete)(this, reference), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnumerator(this: *mut core::ffi::c_void, referenceenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureReferenceSet", "GetEnumerator", 5usize, |this| ((**(this as *const *const IOpcSignatureReferenceSet_Vtbl)).GetEnumerator)(this, referenceenumerator), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Create,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Create: 0,
+            Delete,
+            GetEnumerator,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcSignatureReferenceSet as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOpcSignatureRelationshipReference_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSourceUri(this: *mut core::ffi::c_void, sourceuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureRelationshipReference", "GetSourceUri", 3usize, |this| ((**(this as *const *const IOpcSignatureRelationshipReference_Vtbl)).GetSourceUri)(this, sourceuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDigestMethod(this: *mut core::ffi::c_void, digestmethod: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureRelationshipReference", "GetDigestMethod", 4usize, |this| ((**(this as *const *const IOpcSignatureRelationshipReference_Vtbl)).GetDigestMethod)(this, digestmethod), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDigestValue(this: *mut core::ffi::c_void, digestvalue: *mut *mut u8, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureRelationshipReference", "GetDigestValue", 5usize, |this| ((**(this as *const *const IOpcSignatureRelationshipReference_Vtbl)).GetDigestValue)(this, digestvalue, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransformMethod(this: *mut core::ffi::c_void, transformmethod: *mut OPC_CANONICALIZATION_METHOD) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureRelationshipReference", "GetTransformMethod", 6usize, |this| ((**(this as *const *const IOpcSignatureRelationshipReference_Vtbl)).GetTransformMethod)(this, transformmethod), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRelationshipSigningOption(this: *mut core::ffi::c_void, relationshipsigningoption: *mut OPC_RELATIONSHIPS_SIGNING_OPTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureRelationshipReference", "GetRelationshipSigningOption", 7usize, |this| ((**(this as *const *const IOpcSignatureRelationshipReference_Vtbl)).GetRelationshipSigningOption)(this, relationshipsigningoption), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRelationshipSelectorEnumerator(this: *mut core::ffi::c_void, selectorenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureRelationshipReference", "GetRelationshipSelectorEnumerator", 8usize, |this| ((**(this as *const *const IOpcSignatureRelationshipReference_Vtbl)).GetRelationshipSelectorEnumerator)(this, selectorenumerator), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSourceUri,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSourceUri: 0,
+            GetDigestMethod,
+            GetDigestValue,
+            GetTransformMethod,
+            GetRelationshipSigningOption,
+            GetRelationshipSelectorEnumerator,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcSignatureRelationshipReference as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOpcSignatureRelationshipReferenceEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, hasnext: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureRelationshipReferenceEnumerator", "MoveNext", 3usize, |this| ((**(this as *const *const IOpcSignatureRelationshipReferenceEnumerator_Vtbl)).MoveNext)(this, hasnext), |result| Some(*result))
+        }
+        unsafe extern "system" fn MovePrevious(this: *mut core::ffi::c_void, hasprevious: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureRelationshipReferenceEnumerator", "MovePrevious", 4usize, |this| ((**(this as *const *const IOpcSignatureRelationshipReferenceEnumerator_Vtbl)).MovePrevious)(this, hasprevious), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, relationshipreference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureRelationshipReferenceEnumerator", "GetCurrent", 5usize, |this| ((**(this as *const *const IOpcSignatureRelationshipReferenceEnumerator_Vtbl)).GetCurrent)(this, relationshipreference), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, copy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureRelationshipReferenceEnumerator", "Clone", 6usize, |this| ((**(this as *const *const IOpcSignatureRelationshipReferenceEnumerator_Vtbl)).Clone)(this, copy), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MoveNext, MovePrevious, GetCurrent, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcSignatureRelationshipReferenceEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOpcSignatureRelationshipReferenceSet_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, sourceuri: *mut core::ffi::c_void, digestmethod: windows_core::PCWSTR, relationshipsigningoption: OPC_RELATIONSHIPS_SIGNING_OPTION, selectorset: *mut core::ffi::c_void, transformmethod: OPC_CANONICALIZATION_METHOD, relationshipreference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureRelationshipReferenceSet", "Create", 3usize, |this| ((**(this as *const *const IOpcSignatureRelationshipReferenceSet_Vtbl)).Create)(this, sourceuri, digestmethod, relationshipsigningoption, selectorset, transformmethod, relationshipreference), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRelationshipSelectorSet(this: *mut core::ffi::c_void, selectorset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureRelationshipReferenceSet", "CreateRelationshipSelectorSet", 4usize, |this| ((**(this as *const *const IOpcSignatureRelationshipReferenceSet_Vtbl)).CreateRelationshipSelectorSet)(this, selectorset), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, relationshipreference: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureRelationshipReferenceSet", "Delete", 5usize, |this| ((**(this as *const *const IOpcSignatureRelationshipReferenceSet_Vtbl)).Delete)(this, relationshipreference), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnumerator(this: *mut core::ffi::c_void, relationshipreferenceenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSignatureRelationshipReferenceSet", "GetEnumerator", 6usize, |this| ((**(this as *const *const IOpcSignatureRelationshipReferenceSet_Vtbl)).GetEnumerator)(this, relationshipreferenceenumerator), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Create,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Create: 0,
+            CreateRelationshipSelectorSet,
+            Delete,
+            GetEnumerator,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcSignatureRelationshipReferenceSet as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOpcSigningOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSignatureId(this: *mut core::ffi::c_void, signatureid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSigningOptions", "GetSignatureId", 3usize, |this| ((**(this as *const *const IOpcSigningOptions_Vtbl)).GetSignatureId)(this, signatureid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSignatureId(this: *mut core::ffi::c_void, signatureid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSigningOptions", "SetSignatureId", 4usize, |this| ((**(this as *const *const IOpcSigningOptions_Vtbl)).SetSignatureId)(this, signatureid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignatureMethod(this: *mut core::ffi::c_void, signaturemethod: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSigningOptions", "GetSignatureMethod", 5usize, |this| ((**(this as *const *const IOpcSigningOptions_Vtbl)).GetSignatureMethod)(this, signaturemethod), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSignatureMethod(this: *mut core::ffi::c_void, signaturemethod: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSigningOptions", "SetSignatureMethod", 6usize, |this| ((**(this as *const *const IOpcSigningOptions_Vtbl)).SetSignatureMethod)(this, signaturemethod), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultDigestMethod(this: *mut core::ffi::c_void, digestmethod: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSigningOptions", "GetDefaultDigestMethod", 7usize, |this| ((**(this as *const *const IOpcSigningOptions_Vtbl)).GetDefaultDigestMethod)(this, digestmethod), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultDigestMethod(this: *mut core::ffi::c_void, digestmethod: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSigningOptions", "SetDefaultDigestMethod", 8usize, |this| ((**(this as *const *const IOpcSigningOptions_Vtbl)).SetDefaultDigestMethod)(this, digestmethod), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCertificateEmbeddingOption(this: *mut core::ffi::c_void, embeddingoption: *mut OPC_CERTIFICATE_EMBEDDING_OPTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSigningOptions", "GetCertificateEmbeddingOption", 9usize, |this| ((**(this as *const *const IOpcSigningOptions_Vtbl)).GetCertificateEmbeddingOption)(this, embeddingoption), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCertificateEmbeddingOption(this: *mut core::ffi::c_void, embeddingoption: OPC_CERTIFICATE_EMBEDDING_OPTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSigningOptions", "SetCertificateEmbeddingOption", 10usize, |this| ((**(this as *const *const IOpcSigningOptions_Vtbl)).SetCertificateEmbeddingOption)(this, embeddingoption), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTimeFormat(this: *mut core::ffi::c_void, timeformat: *mut OPC_SIGNATURE_TIME_FORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSigningOptions", "GetTimeFormat", 11usize, |this| ((**(this as *const *const IOpcSigningOptions_Vtbl)).GetTimeFormat)(this, timeformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTimeFormat(this: *mut core::ffi::c_void, timeformat: OPC_SIGNATURE_TIME_FORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSigningOptions", "SetTimeFormat", 12usize, |this| ((**(this as *const *const IOpcSigningOptions_Vtbl)).SetTimeFormat)(this, timeformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignaturePartReferenceSet(this: *mut core::ffi::c_void, partreferenceset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSigningOptions", "GetSignaturePartReferenceSet", 13usize, |this| ((**(this as *const *const IOpcSigningOptions_Vtbl)).GetSignaturePartReferenceSet)(this, partreferenceset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignatureRelationshipReferenceSet(this: *mut core::ffi::c_void, relationshipreferenceset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSigningOptions", "GetSignatureRelationshipReferenceSet", 14usize, |this| ((**(this as *const *const IOpcSigningOptions_Vtbl)).GetSignatureRelationshipReferenceSet)(this, relationshipreferenceset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCustomObjectSet(this: *mut core::ffi::c_void, customobjectset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSigningOptions", "GetCustomObjectSet", 15usize, |this| ((**(this as *const *const IOpcSigningOptions_Vtbl)).GetCustomObjectSet)(this, customobjectset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCustomReferenceSet(this: *mut core::ffi::c_void, customreferenceset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSigningOptions", "GetCustomReferenceSet", 16usize, |this| ((**(this as *const *const IOpcSigningOptions_Vtbl)).GetCustomReferenceSet)(this, customreferenceset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCertificateSet(this: *mut core::ffi::c_void, certificateset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSigningOptions", "GetCertificateSet", 17usize, |this| ((**(this as *const *const IOpcSigningOptions_Vtbl)).GetCertificateSet)(this, certificateset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSignaturePartName(this: *mut core::ffi::c_void, signaturepartname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSigningOptions", "GetSignaturePartName", 18usize, |this| ((**(this as *const *const IOpcSigningOptions_Vtbl)).GetSignaturePartName)(this, signaturepartname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSignaturePartName(this: *mut core::ffi::c_void, signaturepartname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcSigningOptions", "SetSignaturePartName", 19usize, |this| ((**(this as *const *const IOpcSigningOptions_Vtbl)).SetSignaturePartName)(this, signaturepartname), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSignatureId,
+            SetSignatureId,
+            GetSignatureMethod,
+            SetSignatureMethod,
+            GetDefaultDigestMethod,
+            SetDefaultDigestMethod,
+            GetCertificateEmbeddingOption,
+            SetCertificateEmbeddingOption,
+            GetTimeFormat,
+            SetTimeFormat,
+            GetSignaturePartReferenceSet,
+            GetSignatureRelationshipReferenceSet,
+            GetCustomObjectSet,
+            GetCustomReferenceSet,
+            GetCertificateSet,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSignaturePartName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSignaturePartName: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSignaturePartName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSignaturePartName: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcSigningOptions as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOpcUri_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetRelationshipsPartUri(this: *mut core::ffi::c_void, relationshipparturi: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcUri", "GetRelationshipsPartUri", 28usize, |this| ((**(this as *const *const IOpcUri_Vtbl)).GetRelationshipsPartUri)(this, relationshipparturi), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetRelativeUri(this: *mut core::ffi::c_void, targetparturi: *mut core::ffi::c_void, relativeuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcUri", "GetRelativeUri", 29usize, |this| ((**(this as *const *const IOpcUri_Vtbl)).GetRelativeUri)(this, targetparturi, relativeuri), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CombinePartUri(this: *mut core::ffi::c_void, relativeuri: *mut core::ffi::c_void, combineduri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpcUri", "CombinePartUri", 30usize, |this| ((**(this as *const *const IOpcUri_Vtbl)).CombinePartUri)(this, relativeuri, combineduri), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IUri_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetRelationshipsPartUri,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetRelationshipsPartUri: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetRelativeUri,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetRelativeUri: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CombinePartUri,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CombinePartUri: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpcUri as windows_core::Interface>::IID || <super::super::super::System::Com::IUri_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1,5 +1,6 @@
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct JET_API_PTR(pub usize);
 impl JET_API_PTR {
     pub fn is_invalid(&self) -> bool {
@@ -15,7 +16,8 @@ impl windows_core::TypeKind for JET_API_PTR {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct JET_HANDLE(pub usize);
 impl JET_HANDLE {
     pub fn is_invalid(&self) -> bool {
@@ -31,7 +33,8 @@ impl windows_core::TypeKind for JET_HANDLE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct JET_TABLEID(pub usize);
 impl JET_TABLEID {
     pub fn is_invalid(&self) -> bool {
@@ -414,6 +414,7 @@ pub struct APPLY_SNAPSHOT_VHDSET_FLAG(pub i32);
 impl windows_core::TypeKind for APPLY_SNAPSHOT_VHDSET_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for APPLY_SNAPSHOT_VHDSET_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("APPLY_SNAPSHOT_VHDSET_FLAG").field(&self.0).finish()
@@ -458,6 +459,7 @@ pub struct APPLY_SNAPSHOT_VHDSET_VERSION(pub i32);
 impl windows_core::TypeKind for APPLY_SNAPSHOT_VHDSET_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for APPLY_SNAPSHOT_VHDSET_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("APPLY_SNAPSHOT_VHDSET_VERSION").field(&self.0).finish()
@@ -469,6 +471,7 @@ pub struct ATTACH_VIRTUAL_DISK_FLAG(pub i32);
 impl windows_core::TypeKind for ATTACH_VIRTUAL_DISK_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ATTACH_VIRTUAL_DISK_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ATTACH_VIRTUAL_DISK_FLAG").field(&self.0).finish()
@@ -513,6 +516,7 @@ pub struct ATTACH_VIRTUAL_DISK_VERSION(pub i32);
 impl windows_core::TypeKind for ATTACH_VIRTUAL_DISK_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ATTACH_VIRTUAL_DISK_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ATTACH_VIRTUAL_DISK_VERSION").field(&self.0).finish()
@@ -524,6 +528,7 @@ pub struct COMPACT_VIRTUAL_DISK_FLAG(pub i32);
 impl windows_core::TypeKind for COMPACT_VIRTUAL_DISK_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMPACT_VIRTUAL_DISK_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMPACT_VIRTUAL_DISK_FLAG").field(&self.0).finish()
@@ -568,6 +573,7 @@ pub struct COMPACT_VIRTUAL_DISK_VERSION(pub i32);
 impl windows_core::TypeKind for COMPACT_VIRTUAL_DISK_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMPACT_VIRTUAL_DISK_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMPACT_VIRTUAL_DISK_VERSION").field(&self.0).finish()
@@ -579,6 +585,7 @@ pub struct CREATE_VIRTUAL_DISK_FLAG(pub i32);
 impl windows_core::TypeKind for CREATE_VIRTUAL_DISK_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CREATE_VIRTUAL_DISK_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CREATE_VIRTUAL_DISK_FLAG").field(&self.0).finish()
@@ -623,6 +630,7 @@ pub struct CREATE_VIRTUAL_DISK_VERSION(pub i32);
 impl windows_core::TypeKind for CREATE_VIRTUAL_DISK_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CREATE_VIRTUAL_DISK_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CREATE_VIRTUAL_DISK_VERSION").field(&self.0).finish()
@@ -634,6 +642,7 @@ pub struct DELETE_SNAPSHOT_VHDSET_FLAG(pub i32);
 impl windows_core::TypeKind for DELETE_SNAPSHOT_VHDSET_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DELETE_SNAPSHOT_VHDSET_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DELETE_SNAPSHOT_VHDSET_FLAG").field(&self.0).finish()
@@ -678,6 +687,7 @@ pub struct DELETE_SNAPSHOT_VHDSET_VERSION(pub i32);
 impl windows_core::TypeKind for DELETE_SNAPSHOT_VHDSET_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DELETE_SNAPSHOT_VHDSET_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DELETE_SNAPSHOT_VHDSET_VERSION").field(&self.0).finish()
@@ -689,6 +699,7 @@ pub struct DEPENDENT_DISK_FLAG(pub i32);
 impl windows_core::TypeKind for DEPENDENT_DISK_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEPENDENT_DISK_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEPENDENT_DISK_FLAG").field(&self.0).finish()
@@ -733,6 +744,7 @@ pub struct DETACH_VIRTUAL_DISK_FLAG(pub i32);
 impl windows_core::TypeKind for DETACH_VIRTUAL_DISK_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DETACH_VIRTUAL_DISK_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DETACH_VIRTUAL_DISK_FLAG").field(&self.0).finish()
@@ -777,6 +789,7 @@ pub struct EXPAND_VIRTUAL_DISK_FLAG(pub i32);
 impl windows_core::TypeKind for EXPAND_VIRTUAL_DISK_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EXPAND_VIRTUAL_DISK_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EXPAND_VIRTUAL_DISK_FLAG").field(&self.0).finish()
@@ -821,6 +834,7 @@ pub struct EXPAND_VIRTUAL_DISK_VERSION(pub i32);
 impl windows_core::TypeKind for EXPAND_VIRTUAL_DISK_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EXPAND_VIRTUAL_DISK_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EXPAND_VIRTUAL_DISK_VERSION").field(&self.0).finish()
@@ -832,6 +846,7 @@ pub struct FORK_VIRTUAL_DISK_FLAG(pub i32);
 impl windows_core::TypeKind for FORK_VIRTUAL_DISK_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FORK_VIRTUAL_DISK_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FORK_VIRTUAL_DISK_FLAG").field(&self.0).finish()
@@ -876,6 +891,7 @@ pub struct FORK_VIRTUAL_DISK_VERSION(pub i32);
 impl windows_core::TypeKind for FORK_VIRTUAL_DISK_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FORK_VIRTUAL_DISK_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FORK_VIRTUAL_DISK_VERSION").field(&self.0).finish()
@@ -887,6 +903,7 @@ pub struct GET_STORAGE_DEPENDENCY_FLAG(pub i32);
 impl windows_core::TypeKind for GET_STORAGE_DEPENDENCY_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GET_STORAGE_DEPENDENCY_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GET_STORAGE_DEPENDENCY_FLAG").field(&self.0).finish()
@@ -931,6 +948,7 @@ pub struct GET_VIRTUAL_DISK_INFO_VERSION(pub i32);
 impl windows_core::TypeKind for GET_VIRTUAL_DISK_INFO_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GET_VIRTUAL_DISK_INFO_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GET_VIRTUAL_DISK_INFO_VERSION").field(&self.0).finish()
@@ -942,6 +960,7 @@ pub struct MERGE_VIRTUAL_DISK_FLAG(pub i32);
 impl windows_core::TypeKind for MERGE_VIRTUAL_DISK_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MERGE_VIRTUAL_DISK_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MERGE_VIRTUAL_DISK_FLAG").field(&self.0).finish()
@@ -986,6 +1005,7 @@ pub struct MERGE_VIRTUAL_DISK_VERSION(pub i32);
 impl windows_core::TypeKind for MERGE_VIRTUAL_DISK_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MERGE_VIRTUAL_DISK_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MERGE_VIRTUAL_DISK_VERSION").field(&self.0).finish()
@@ -997,6 +1017,7 @@ pub struct MIRROR_VIRTUAL_DISK_FLAG(pub i32);
 impl windows_core::TypeKind for MIRROR_VIRTUAL_DISK_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MIRROR_VIRTUAL_DISK_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MIRROR_VIRTUAL_DISK_FLAG").field(&self.0).finish()
@@ -1041,6 +1062,7 @@ pub struct MIRROR_VIRTUAL_DISK_VERSION(pub i32);
 impl windows_core::TypeKind for MIRROR_VIRTUAL_DISK_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MIRROR_VIRTUAL_DISK_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MIRROR_VIRTUAL_DISK_VERSION").field(&self.0).finish()
@@ -1052,6 +1074,7 @@ pub struct MODIFY_VHDSET_FLAG(pub i32);
 impl windows_core::TypeKind for MODIFY_VHDSET_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MODIFY_VHDSET_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MODIFY_VHDSET_FLAG").field(&self.0).finish()
@@ -1096,6 +1119,7 @@ pub struct MODIFY_VHDSET_VERSION(pub i32);
 impl windows_core::TypeKind for MODIFY_VHDSET_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MODIFY_VHDSET_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MODIFY_VHDSET_VERSION").field(&self.0).finish()
@@ -1107,6 +1131,7 @@ pub struct OPEN_VIRTUAL_DISK_FLAG(pub i32);
 impl windows_core::TypeKind for OPEN_VIRTUAL_DISK_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPEN_VIRTUAL_DISK_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPEN_VIRTUAL_DISK_FLAG").field(&self.0).finish()
@@ -1151,6 +1176,7 @@ pub struct OPEN_VIRTUAL_DISK_VERSION(pub i32);
 impl windows_core::TypeKind for OPEN_VIRTUAL_DISK_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPEN_VIRTUAL_DISK_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPEN_VIRTUAL_DISK_VERSION").field(&self.0).finish()
@@ -1162,6 +1188,7 @@ pub struct QUERY_CHANGES_VIRTUAL_DISK_FLAG(pub i32);
 impl windows_core::TypeKind for QUERY_CHANGES_VIRTUAL_DISK_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for QUERY_CHANGES_VIRTUAL_DISK_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("QUERY_CHANGES_VIRTUAL_DISK_FLAG").field(&self.0).finish()
@@ -1206,6 +1233,7 @@ pub struct RAW_SCSI_VIRTUAL_DISK_FLAG(pub i32);
 impl windows_core::TypeKind for RAW_SCSI_VIRTUAL_DISK_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RAW_SCSI_VIRTUAL_DISK_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RAW_SCSI_VIRTUAL_DISK_FLAG").field(&self.0).finish()
@@ -1250,6 +1278,7 @@ pub struct RAW_SCSI_VIRTUAL_DISK_VERSION(pub i32);
 impl windows_core::TypeKind for RAW_SCSI_VIRTUAL_DISK_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RAW_SCSI_VIRTUAL_DISK_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RAW_SCSI_VIRTUAL_DISK_VERSION").field(&self.0).finish()
@@ -1261,6 +1290,7 @@ pub struct RESIZE_VIRTUAL_DISK_FLAG(pub i32);
 impl windows_core::TypeKind for RESIZE_VIRTUAL_DISK_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RESIZE_VIRTUAL_DISK_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RESIZE_VIRTUAL_DISK_FLAG").field(&self.0).finish()
@@ -1305,6 +1335,7 @@ pub struct RESIZE_VIRTUAL_DISK_VERSION(pub i32);
 impl windows_core::TypeKind for RESIZE_VIRTUAL_DISK_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RESIZE_VIRTUAL_DISK_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RESIZE_VIRTUAL_DISK_VERSION").field(&self.0).finish()
@@ -1316,6 +1347,7 @@ pub struct SET_VIRTUAL_DISK_INFO_VERSION(pub i32);
 impl windows_core::TypeKind for SET_VIRTUAL_DISK_INFO_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SET_VIRTUAL_DISK_INFO_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SET_VIRTUAL_DISK_INFO_VERSION").field(&self.0).finish()
@@ -1327,6 +1359,7 @@ pub struct STORAGE_DEPENDENCY_INFO_VERSION(pub i32);
 impl windows_core::TypeKind for STORAGE_DEPENDENCY_INFO_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STORAGE_DEPENDENCY_INFO_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STORAGE_DEPENDENCY_INFO_VERSION").field(&self.0).finish()
@@ -1338,6 +1371,7 @@ pub struct TAKE_SNAPSHOT_VHDSET_FLAG(pub i32);
 impl windows_core::TypeKind for TAKE_SNAPSHOT_VHDSET_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TAKE_SNAPSHOT_VHDSET_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TAKE_SNAPSHOT_VHDSET_FLAG").field(&self.0).finish()
@@ -1382,6 +1416,7 @@ pub struct TAKE_SNAPSHOT_VHDSET_VERSION(pub i32);
 impl windows_core::TypeKind for TAKE_SNAPSHOT_VHDSET_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TAKE_SNAPSHOT_VHDSET_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TAKE_SNAPSHOT_VHDSET_VERSION").field(&self.0).finish()
@@ -1393,6 +1428,7 @@ pub struct VIRTUAL_DISK_ACCESS_MASK(pub i32);
 impl windows_core::TypeKind for VIRTUAL_DISK_ACCESS_MASK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VIRTUAL_DISK_ACCESS_MASK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VIRTUAL_DISK_ACCESS_MASK").field(&self.0).finish()
@@ -1459,7 +1495,8 @@ impl Default for APPLY_SNAPSHOT_VHDSET_PARAMETERS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct APPLY_SNAPSHOT_VHDSET_PARAMETERS_0_0 {
     pub SnapshotId: windows_core::GUID,
     pub LeafSnapshotId: windows_core::GUID,
@@ -1501,7 +1538,8 @@ impl Default for ATTACH_VIRTUAL_DISK_PARAMETERS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ATTACH_VIRTUAL_DISK_PARAMETERS_0_0 {
     pub Reserved: u32,
 }
@@ -1514,7 +1552,8 @@ impl Default for ATTACH_VIRTUAL_DISK_PARAMETERS_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ATTACH_VIRTUAL_DISK_PARAMETERS_0_1 {
     pub RestrictedOffset: u64,
     pub RestrictedLength: u64,
@@ -1555,7 +1594,8 @@ impl Default for COMPACT_VIRTUAL_DISK_PARAMETERS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COMPACT_VIRTUAL_DISK_PARAMETERS_0_0 {
     pub Reserved: u32,
 }
@@ -1598,7 +1638,8 @@ impl Default for CREATE_VIRTUAL_DISK_PARAMETERS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CREATE_VIRTUAL_DISK_PARAMETERS_0_0 {
     pub UniqueId: windows_core::GUID,
     pub MaximumSize: u64,
@@ -1616,7 +1657,8 @@ impl Default for CREATE_VIRTUAL_DISK_PARAMETERS_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CREATE_VIRTUAL_DISK_PARAMETERS_0_1 {
     pub UniqueId: windows_core::GUID,
     pub MaximumSize: u64,
@@ -1639,7 +1681,8 @@ impl Default for CREATE_VIRTUAL_DISK_PARAMETERS_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CREATE_VIRTUAL_DISK_PARAMETERS_0_2 {
     pub UniqueId: windows_core::GUID,
     pub MaximumSize: u64,
@@ -1664,7 +1707,8 @@ impl Default for CREATE_VIRTUAL_DISK_PARAMETERS_0_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CREATE_VIRTUAL_DISK_PARAMETERS_0_3 {
     pub UniqueId: windows_core::GUID,
     pub MaximumSize: u64,
@@ -1718,7 +1762,8 @@ impl Default for DELETE_SNAPSHOT_VHDSET_PARAMETERS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DELETE_SNAPSHOT_VHDSET_PARAMETERS_0_0 {
     pub SnapshotId: windows_core::GUID,
 }
@@ -1758,7 +1803,8 @@ impl Default for EXPAND_VIRTUAL_DISK_PARAMETERS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EXPAND_VIRTUAL_DISK_PARAMETERS_0_0 {
     pub NewSize: u64,
 }
@@ -1798,7 +1844,8 @@ impl Default for FORK_VIRTUAL_DISK_PARAMETERS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FORK_VIRTUAL_DISK_PARAMETERS_0_0 {
     pub ForkedVirtualDiskPath: windows_core::PCWSTR,
 }
@@ -1852,7 +1899,8 @@ impl Default for GET_VIRTUAL_DISK_INFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GET_VIRTUAL_DISK_INFO_0_0 {
     pub Enabled: super::super::Foundation::BOOL,
     pub NewerChanges: super::super::Foundation::BOOL,
@@ -1867,7 +1915,8 @@ impl Default for GET_VIRTUAL_DISK_INFO_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GET_VIRTUAL_DISK_INFO_0_1 {
     pub ParentResolved: super::super::Foundation::BOOL,
     pub ParentLocationBuffer: [u16; 1],
@@ -1881,7 +1930,8 @@ impl Default for GET_VIRTUAL_DISK_INFO_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GET_VIRTUAL_DISK_INFO_0_2 {
     pub LogicalSectorSize: u32,
     pub PhysicalSectorSize: u32,
@@ -1896,7 +1946,8 @@ impl Default for GET_VIRTUAL_DISK_INFO_0_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GET_VIRTUAL_DISK_INFO_0_3 {
     pub VirtualSize: u64,
     pub PhysicalSize: u64,
@@ -1940,7 +1991,8 @@ impl Default for MERGE_VIRTUAL_DISK_PARAMETERS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MERGE_VIRTUAL_DISK_PARAMETERS_0_0 {
     pub MergeDepth: u32,
 }
@@ -1953,7 +2005,8 @@ impl Default for MERGE_VIRTUAL_DISK_PARAMETERS_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MERGE_VIRTUAL_DISK_PARAMETERS_0_1 {
     pub MergeSourceDepth: u32,
     pub MergeTargetDepth: u32,
@@ -1994,7 +2047,8 @@ impl Default for MIRROR_VIRTUAL_DISK_PARAMETERS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MIRROR_VIRTUAL_DISK_PARAMETERS_0_0 {
     pub MirrorVirtualDiskPath: windows_core::PCWSTR,
 }
@@ -2036,7 +2090,8 @@ impl Default for MODIFY_VHDSET_PARAMETERS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MODIFY_VHDSET_PARAMETERS_0_0 {
     pub SnapshotId: windows_core::GUID,
     pub SnapshotFilePath: windows_core::PCWSTR,
@@ -2079,7 +2134,8 @@ impl Default for OPEN_VIRTUAL_DISK_PARAMETERS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OPEN_VIRTUAL_DISK_PARAMETERS_0_0 {
     pub RWDepth: u32,
 }
@@ -2092,7 +2148,8 @@ impl Default for OPEN_VIRTUAL_DISK_PARAMETERS_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OPEN_VIRTUAL_DISK_PARAMETERS_0_1 {
     pub GetInfoOnly: super::super::Foundation::BOOL,
     pub ReadOnly: super::super::Foundation::BOOL,
@@ -2107,7 +2164,8 @@ impl Default for OPEN_VIRTUAL_DISK_PARAMETERS_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OPEN_VIRTUAL_DISK_PARAMETERS_0_2 {
     pub GetInfoOnly: super::super::Foundation::BOOL,
     pub ReadOnly: super::super::Foundation::BOOL,
@@ -2123,7 +2181,8 @@ impl Default for OPEN_VIRTUAL_DISK_PARAMETERS_0_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct QUERY_CHANGES_VIRTUAL_DISK_RANGE {
     pub ByteOffset: u64,
     pub ByteLength: u64,
@@ -2165,7 +2224,8 @@ impl Default for RAW_SCSI_VIRTUAL_DISK_PARAMETERS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RAW_SCSI_VIRTUAL_DISK_PARAMETERS_0_0 {
     pub RSVDHandle: super::super::Foundation::BOOL,
     pub DataIn: u8,
@@ -2213,7 +2273,8 @@ impl Default for RAW_SCSI_VIRTUAL_DISK_RESPONSE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RAW_SCSI_VIRTUAL_DISK_RESPONSE_0_0 {
     pub ScsiStatus: u8,
     pub SenseInfoLength: u8,
@@ -2255,7 +2316,8 @@ impl Default for RESIZE_VIRTUAL_DISK_PARAMETERS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RESIZE_VIRTUAL_DISK_PARAMETERS_0_0 {
     pub NewSize: u64,
 }
@@ -2301,7 +2363,8 @@ impl Default for SET_VIRTUAL_DISK_INFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SET_VIRTUAL_DISK_INFO_0_0 {
     pub LinkageId: windows_core::GUID,
     pub ParentFilePath: windows_core::PCWSTR,
@@ -2315,7 +2378,8 @@ impl Default for SET_VIRTUAL_DISK_INFO_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SET_VIRTUAL_DISK_INFO_0_1 {
     pub ChildDepth: u32,
     pub ParentFilePath: windows_core::PCWSTR,
@@ -2358,7 +2422,8 @@ impl Default for STORAGE_DEPENDENCY_INFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STORAGE_DEPENDENCY_INFO_TYPE_1 {
     pub DependencyTypeFlags: DEPENDENT_DISK_FLAG,
     pub ProviderSpecificFlags: u32,
@@ -2373,7 +2438,8 @@ impl Default for STORAGE_DEPENDENCY_INFO_TYPE_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STORAGE_DEPENDENCY_INFO_TYPE_2 {
     pub DependencyTypeFlags: DEPENDENT_DISK_FLAG,
     pub ProviderSpecificFlags: u32,
@@ -2420,7 +2486,8 @@ impl Default for TAKE_SNAPSHOT_VHDSET_PARAMETERS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TAKE_SNAPSHOT_VHDSET_PARAMETERS_0_0 {
     pub SnapshotId: windows_core::GUID,
 }
@@ -2433,7 +2500,8 @@ impl Default for TAKE_SNAPSHOT_VHDSET_PARAMETERS_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIRTUAL_DISK_PROGRESS {
     pub OperationStatus: u32,
     pub CurrentValue: u64,
@@ -2448,7 +2516,8 @@ impl Default for VIRTUAL_DISK_PROGRESS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIRTUAL_STORAGE_TYPE {
     pub DeviceId: u32,
     pub VendorId: windows_core::GUID,
@@ -3519,6 +3519,7 @@ pub struct VDS_ASYNC_OUTPUT_TYPE(pub i32);
 impl windows_core::TypeKind for VDS_ASYNC_OUTPUT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_ASYNC_OUTPUT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_ASYNC_OUTPUT_TYPE").field(&self.0).finish()
@@ -3530,6 +3531,7 @@ pub struct VDS_CONTROLLER_STATUS(pub i32);
 impl windows_core::TypeKind for VDS_CONTROLLER_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_CONTROLLER_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_CONTROLLER_STATUS").field(&self.0).finish()
@@ -3541,6 +3543,7 @@ pub struct VDS_DISK_EXTENT_TYPE(pub i32);
 impl windows_core::TypeKind for VDS_DISK_EXTENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_DISK_EXTENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_DISK_EXTENT_TYPE").field(&self.0).finish()
@@ -3552,6 +3555,7 @@ pub struct VDS_DISK_FLAG(pub i32);
 impl windows_core::TypeKind for VDS_DISK_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_DISK_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_DISK_FLAG").field(&self.0).finish()
@@ -3563,6 +3567,7 @@ pub struct VDS_DISK_OFFLINE_REASON(pub i32);
 impl windows_core::TypeKind for VDS_DISK_OFFLINE_REASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_DISK_OFFLINE_REASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_DISK_OFFLINE_REASON").field(&self.0).finish()
@@ -3574,6 +3579,7 @@ pub struct VDS_DISK_STATUS(pub i32);
 impl windows_core::TypeKind for VDS_DISK_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_DISK_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_DISK_STATUS").field(&self.0).finish()
@@ -3585,6 +3591,7 @@ pub struct VDS_DRIVE_FLAG(pub i32);
 impl windows_core::TypeKind for VDS_DRIVE_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_DRIVE_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_DRIVE_FLAG").field(&self.0).finish()
@@ -3596,6 +3603,7 @@ pub struct VDS_DRIVE_LETTER_FLAG(pub i32);
 impl windows_core::TypeKind for VDS_DRIVE_LETTER_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_DRIVE_LETTER_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_DRIVE_LETTER_FLAG").field(&self.0).finish()
@@ -3607,6 +3615,7 @@ pub struct VDS_DRIVE_STATUS(pub i32);
 impl windows_core::TypeKind for VDS_DRIVE_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_DRIVE_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_DRIVE_STATUS").field(&self.0).finish()
@@ -3618,6 +3627,7 @@ pub struct VDS_FILE_SYSTEM_FLAG(pub i32);
 impl windows_core::TypeKind for VDS_FILE_SYSTEM_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_FILE_SYSTEM_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_FILE_SYSTEM_FLAG").field(&self.0).finish()
@@ -3629,6 +3639,7 @@ pub struct VDS_FILE_SYSTEM_FORMAT_SUPPORT_FLAG(pub i32);
 impl windows_core::TypeKind for VDS_FILE_SYSTEM_FORMAT_SUPPORT_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_FILE_SYSTEM_FORMAT_SUPPORT_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_FILE_SYSTEM_FORMAT_SUPPORT_FLAG").field(&self.0).finish()
@@ -3640,6 +3651,7 @@ pub struct VDS_FILE_SYSTEM_PROP_FLAG(pub i32);
 impl windows_core::TypeKind for VDS_FILE_SYSTEM_PROP_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_FILE_SYSTEM_PROP_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_FILE_SYSTEM_PROP_FLAG").field(&self.0).finish()
@@ -3651,6 +3663,7 @@ pub struct VDS_FILE_SYSTEM_TYPE(pub i32);
 impl windows_core::TypeKind for VDS_FILE_SYSTEM_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_FILE_SYSTEM_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_FILE_SYSTEM_TYPE").field(&self.0).finish()
@@ -3662,6 +3675,7 @@ pub struct VDS_FORMAT_OPTION_FLAGS(pub i32);
 impl windows_core::TypeKind for VDS_FORMAT_OPTION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_FORMAT_OPTION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_FORMAT_OPTION_FLAGS").field(&self.0).finish()
@@ -3673,6 +3687,7 @@ pub struct VDS_HBAPORT_SPEED_FLAG(pub i32);
 impl windows_core::TypeKind for VDS_HBAPORT_SPEED_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_HBAPORT_SPEED_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_HBAPORT_SPEED_FLAG").field(&self.0).finish()
@@ -3684,6 +3699,7 @@ pub struct VDS_HBAPORT_STATUS(pub i32);
 impl windows_core::TypeKind for VDS_HBAPORT_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_HBAPORT_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_HBAPORT_STATUS").field(&self.0).finish()
@@ -3695,6 +3711,7 @@ pub struct VDS_HBAPORT_TYPE(pub i32);
 impl windows_core::TypeKind for VDS_HBAPORT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_HBAPORT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_HBAPORT_TYPE").field(&self.0).finish()
@@ -3706,6 +3723,7 @@ pub struct VDS_HEALTH(pub i32);
 impl windows_core::TypeKind for VDS_HEALTH {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_HEALTH {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_HEALTH").field(&self.0).finish()
@@ -3717,6 +3735,7 @@ pub struct VDS_HWPROVIDER_TYPE(pub i32);
 impl windows_core::TypeKind for VDS_HWPROVIDER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_HWPROVIDER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_HWPROVIDER_TYPE").field(&self.0).finish()
@@ -3728,6 +3747,7 @@ pub struct VDS_INTERCONNECT_ADDRESS_TYPE(pub i32);
 impl windows_core::TypeKind for VDS_INTERCONNECT_ADDRESS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_INTERCONNECT_ADDRESS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_INTERCONNECT_ADDRESS_TYPE").field(&self.0).finish()
@@ -3739,6 +3759,7 @@ pub struct VDS_INTERCONNECT_FLAG(pub i32);
 impl windows_core::TypeKind for VDS_INTERCONNECT_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_INTERCONNECT_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_INTERCONNECT_FLAG").field(&self.0).finish()
@@ -3750,6 +3771,7 @@ pub struct VDS_IPADDRESS_TYPE(pub i32);
 impl windows_core::TypeKind for VDS_IPADDRESS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_IPADDRESS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_IPADDRESS_TYPE").field(&self.0).finish()
@@ -3761,6 +3783,7 @@ pub struct VDS_ISCSI_AUTH_TYPE(pub i32);
 impl windows_core::TypeKind for VDS_ISCSI_AUTH_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_ISCSI_AUTH_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_ISCSI_AUTH_TYPE").field(&self.0).finish()
@@ -3772,6 +3795,7 @@ pub struct VDS_ISCSI_IPSEC_FLAG(pub i32);
 impl windows_core::TypeKind for VDS_ISCSI_IPSEC_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_ISCSI_IPSEC_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_ISCSI_IPSEC_FLAG").field(&self.0).finish()
@@ -3783,6 +3807,7 @@ pub struct VDS_ISCSI_LOGIN_FLAG(pub i32);
 impl windows_core::TypeKind for VDS_ISCSI_LOGIN_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_ISCSI_LOGIN_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_ISCSI_LOGIN_FLAG").field(&self.0).finish()
@@ -3794,6 +3819,7 @@ pub struct VDS_ISCSI_LOGIN_TYPE(pub i32);
 impl windows_core::TypeKind for VDS_ISCSI_LOGIN_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_ISCSI_LOGIN_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_ISCSI_LOGIN_TYPE").field(&self.0).finish()
@@ -3805,6 +3831,7 @@ pub struct VDS_ISCSI_PORTAL_STATUS(pub i32);
 impl windows_core::TypeKind for VDS_ISCSI_PORTAL_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_ISCSI_PORTAL_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_ISCSI_PORTAL_STATUS").field(&self.0).finish()
@@ -3816,6 +3843,7 @@ pub struct VDS_LOADBALANCE_POLICY_ENUM(pub i32);
 impl windows_core::TypeKind for VDS_LOADBALANCE_POLICY_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_LOADBALANCE_POLICY_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_LOADBALANCE_POLICY_ENUM").field(&self.0).finish()
@@ -3827,6 +3855,7 @@ pub struct VDS_LUN_FLAG(pub i32);
 impl windows_core::TypeKind for VDS_LUN_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_LUN_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_LUN_FLAG").field(&self.0).finish()
@@ -3838,6 +3867,7 @@ pub struct VDS_LUN_PLEX_FLAG(pub i32);
 impl windows_core::TypeKind for VDS_LUN_PLEX_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_LUN_PLEX_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_LUN_PLEX_FLAG").field(&self.0).finish()
@@ -3849,6 +3879,7 @@ pub struct VDS_LUN_PLEX_STATUS(pub i32);
 impl windows_core::TypeKind for VDS_LUN_PLEX_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_LUN_PLEX_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_LUN_PLEX_STATUS").field(&self.0).finish()
@@ -3860,6 +3891,7 @@ pub struct VDS_LUN_PLEX_TYPE(pub i32);
 impl windows_core::TypeKind for VDS_LUN_PLEX_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_LUN_PLEX_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_LUN_PLEX_TYPE").field(&self.0).finish()
@@ -3871,6 +3903,7 @@ pub struct VDS_LUN_RESERVE_MODE(pub i32);
 impl windows_core::TypeKind for VDS_LUN_RESERVE_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_LUN_RESERVE_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_LUN_RESERVE_MODE").field(&self.0).finish()
@@ -3882,6 +3915,7 @@ pub struct VDS_LUN_STATUS(pub i32);
 impl windows_core::TypeKind for VDS_LUN_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_LUN_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_LUN_STATUS").field(&self.0).finish()
@@ -3893,6 +3927,7 @@ pub struct VDS_LUN_TYPE(pub i32);
 impl windows_core::TypeKind for VDS_LUN_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_LUN_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_LUN_TYPE").field(&self.0).finish()
@@ -3904,6 +3939,7 @@ pub struct VDS_MAINTENANCE_OPERATION(pub i32);
 impl windows_core::TypeKind for VDS_MAINTENANCE_OPERATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_MAINTENANCE_OPERATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_MAINTENANCE_OPERATION").field(&self.0).finish()
@@ -3915,6 +3951,7 @@ pub struct VDS_NF_CONTROLLER(pub u32);
 impl windows_core::TypeKind for VDS_NF_CONTROLLER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_NF_CONTROLLER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_NF_CONTROLLER").field(&self.0).finish()
@@ -3926,6 +3963,7 @@ pub struct VDS_NF_DISK(pub u32);
 impl windows_core::TypeKind for VDS_NF_DISK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_NF_DISK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_NF_DISK").field(&self.0).finish()
@@ -3937,6 +3975,7 @@ pub struct VDS_NF_DRIVE(pub u32);
 impl windows_core::TypeKind for VDS_NF_DRIVE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_NF_DRIVE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_NF_DRIVE").field(&self.0).finish()
@@ -3948,6 +3987,7 @@ pub struct VDS_NF_FILE_SYSTEM(pub u32);
 impl windows_core::TypeKind for VDS_NF_FILE_SYSTEM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_NF_FILE_SYSTEM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_NF_FILE_SYSTEM").field(&self.0).finish()
@@ -3959,6 +3999,7 @@ pub struct VDS_NF_LUN(pub u32);
 impl windows_core::TypeKind for VDS_NF_LUN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_NF_LUN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_NF_LUN").field(&self.0).finish()
@@ -3970,6 +4011,7 @@ pub struct VDS_NF_PACK(pub u32);
 impl windows_core::TypeKind for VDS_NF_PACK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_NF_PACK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_NF_PACK").field(&self.0).finish()
@@ -3981,6 +4023,7 @@ pub struct VDS_NF_PORT(pub u32);
 impl windows_core::TypeKind for VDS_NF_PORT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_NF_PORT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_NF_PORT").field(&self.0).finish()
@@ -3992,6 +4035,7 @@ pub struct VDS_NOTIFICATION_TARGET_TYPE(pub i32);
 impl windows_core::TypeKind for VDS_NOTIFICATION_TARGET_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_NOTIFICATION_TARGET_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_NOTIFICATION_TARGET_TYPE").field(&self.0).finish()
@@ -4003,6 +4047,7 @@ pub struct VDS_OBJECT_TYPE(pub i32);
 impl windows_core::TypeKind for VDS_OBJECT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_OBJECT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_OBJECT_TYPE").field(&self.0).finish()
@@ -4014,6 +4059,7 @@ pub struct VDS_PACK_FLAG(pub i32);
 impl windows_core::TypeKind for VDS_PACK_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_PACK_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_PACK_FLAG").field(&self.0).finish()
@@ -4025,6 +4071,7 @@ pub struct VDS_PACK_STATUS(pub i32);
 impl windows_core::TypeKind for VDS_PACK_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_PACK_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_PACK_STATUS").field(&self.0).finish()
@@ -4036,6 +4083,7 @@ pub struct VDS_PARTITION_FLAG(pub i32);
 impl windows_core::TypeKind for VDS_PARTITION_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_PARTITION_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_PARTITION_FLAG").field(&self.0).finish()
@@ -4047,6 +4095,7 @@ pub struct VDS_PARTITION_STYLE(pub i32);
 impl windows_core::TypeKind for VDS_PARTITION_STYLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_PARTITION_STYLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_PARTITION_STYLE").field(&self.0).finish()
@@ -4058,6 +4107,7 @@ pub struct VDS_PATH_STATUS(pub i32);
 impl windows_core::TypeKind for VDS_PATH_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_PATH_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_PATH_STATUS").field(&self.0).finish()
@@ -4069,6 +4119,7 @@ pub struct VDS_PORT_STATUS(pub i32);
 impl windows_core::TypeKind for VDS_PORT_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_PORT_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_PORT_STATUS").field(&self.0).finish()
@@ -4080,6 +4131,7 @@ pub struct VDS_PROVIDER_FLAG(pub i32);
 impl windows_core::TypeKind for VDS_PROVIDER_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_PROVIDER_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_PROVIDER_FLAG").field(&self.0).finish()
@@ -4091,6 +4143,7 @@ pub struct VDS_PROVIDER_LBSUPPORT_FLAG(pub i32);
 impl windows_core::TypeKind for VDS_PROVIDER_LBSUPPORT_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_PROVIDER_LBSUPPORT_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_PROVIDER_LBSUPPORT_FLAG").field(&self.0).finish()
@@ -4102,6 +4155,7 @@ pub struct VDS_PROVIDER_TYPE(pub i32);
 impl windows_core::TypeKind for VDS_PROVIDER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_PROVIDER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_PROVIDER_TYPE").field(&self.0).finish()
@@ -4113,6 +4167,7 @@ pub struct VDS_QUERY_PROVIDER_FLAG(pub i32);
 impl windows_core::TypeKind for VDS_QUERY_PROVIDER_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_QUERY_PROVIDER_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_QUERY_PROVIDER_FLAG").field(&self.0).finish()
@@ -4124,6 +4179,7 @@ pub struct VDS_RAID_TYPE(pub i32);
 impl windows_core::TypeKind for VDS_RAID_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_RAID_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_RAID_TYPE").field(&self.0).finish()
@@ -4135,6 +4191,7 @@ pub struct VDS_RECOVER_ACTION(pub i32);
 impl windows_core::TypeKind for VDS_RECOVER_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_RECOVER_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_RECOVER_ACTION").field(&self.0).finish()
@@ -4146,6 +4203,7 @@ pub struct VDS_SAN_POLICY(pub i32);
 impl windows_core::TypeKind for VDS_SAN_POLICY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_SAN_POLICY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_SAN_POLICY").field(&self.0).finish()
@@ -4157,6 +4215,7 @@ pub struct VDS_SERVICE_FLAG(pub i32);
 impl windows_core::TypeKind for VDS_SERVICE_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_SERVICE_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_SERVICE_FLAG").field(&self.0).finish()
@@ -4168,6 +4227,7 @@ pub struct VDS_STORAGE_BUS_TYPE(pub i32);
 impl windows_core::TypeKind for VDS_STORAGE_BUS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_STORAGE_BUS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_STORAGE_BUS_TYPE").field(&self.0).finish()
@@ -4179,6 +4239,7 @@ pub struct VDS_STORAGE_IDENTIFIER_CODE_SET(pub i32);
 impl windows_core::TypeKind for VDS_STORAGE_IDENTIFIER_CODE_SET {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_STORAGE_IDENTIFIER_CODE_SET {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_STORAGE_IDENTIFIER_CODE_SET").field(&self.0).finish()
@@ -4190,6 +4251,7 @@ pub struct VDS_STORAGE_IDENTIFIER_TYPE(pub i32);
 impl windows_core::TypeKind for VDS_STORAGE_IDENTIFIER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_STORAGE_IDENTIFIER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_STORAGE_IDENTIFIER_TYPE").field(&self.0).finish()
@@ -4201,6 +4263,7 @@ pub struct VDS_STORAGE_POOL_STATUS(pub i32);
 impl windows_core::TypeKind for VDS_STORAGE_POOL_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_STORAGE_POOL_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_STORAGE_POOL_STATUS").field(&self.0).finish()
@@ -4212,6 +4275,7 @@ pub struct VDS_STORAGE_POOL_TYPE(pub i32);
 impl windows_core::TypeKind for VDS_STORAGE_POOL_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_STORAGE_POOL_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_STORAGE_POOL_TYPE").field(&self.0).finish()
@@ -4223,6 +4287,7 @@ pub struct VDS_SUB_SYSTEM_FLAG(pub i32);
 impl windows_core::TypeKind for VDS_SUB_SYSTEM_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_SUB_SYSTEM_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_SUB_SYSTEM_FLAG").field(&self.0).finish()
@@ -4234,6 +4299,7 @@ pub struct VDS_SUB_SYSTEM_STATUS(pub i32);
 impl windows_core::TypeKind for VDS_SUB_SYSTEM_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_SUB_SYSTEM_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_SUB_SYSTEM_STATUS").field(&self.0).finish()
@@ -4245,6 +4311,7 @@ pub struct VDS_SUB_SYSTEM_SUPPORTED_RAID_TYPE_FLAG(pub i32);
 impl windows_core::TypeKind for VDS_SUB_SYSTEM_SUPPORTED_RAID_TYPE_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_SUB_SYSTEM_SUPPORTED_RAID_TYPE_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_SUB_SYSTEM_SUPPORTED_RAID_TYPE_FLAG").field(&self.0).finish()
@@ -4256,6 +4323,7 @@ pub struct VDS_TRANSITION_STATE(pub i32);
 impl windows_core::TypeKind for VDS_TRANSITION_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_TRANSITION_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_TRANSITION_STATE").field(&self.0).finish()
@@ -4267,6 +4335,7 @@ pub struct VDS_VDISK_STATE(pub i32);
 impl windows_core::TypeKind for VDS_VDISK_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_VDISK_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_VDISK_STATE").field(&self.0).finish()
@@ -4278,6 +4347,7 @@ pub struct VDS_VERSION_SUPPORT_FLAG(pub i32);
 impl windows_core::TypeKind for VDS_VERSION_SUPPORT_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_VERSION_SUPPORT_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_VERSION_SUPPORT_FLAG").field(&self.0).finish()
@@ -4289,6 +4359,7 @@ pub struct VDS_VOLUME_FLAG(pub i32);
 impl windows_core::TypeKind for VDS_VOLUME_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_VOLUME_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_VOLUME_FLAG").field(&self.0).finish()
@@ -4300,6 +4371,7 @@ pub struct VDS_VOLUME_PLEX_STATUS(pub i32);
 impl windows_core::TypeKind for VDS_VOLUME_PLEX_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_VOLUME_PLEX_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_VOLUME_PLEX_STATUS").field(&self.0).finish()
@@ -4311,6 +4383,7 @@ pub struct VDS_VOLUME_PLEX_TYPE(pub i32);
 impl windows_core::TypeKind for VDS_VOLUME_PLEX_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_VOLUME_PLEX_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_VOLUME_PLEX_TYPE").field(&self.0).finish()
@@ -4322,6 +4395,7 @@ pub struct VDS_VOLUME_STATUS(pub i32);
 impl windows_core::TypeKind for VDS_VOLUME_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_VOLUME_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_VOLUME_STATUS").field(&self.0).finish()
@@ -4333,6 +4407,7 @@ pub struct VDS_VOLUME_TYPE(pub i32);
 impl windows_core::TypeKind for VDS_VOLUME_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VDS_VOLUME_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VDS_VOLUME_TYPE").field(&self.0).finish()
@@ -4344,6 +4419,7 @@ pub struct __VDS_PARTITION_STYLE(pub i32);
 impl windows_core::TypeKind for __VDS_PARTITION_STYLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for __VDS_PARTITION_STYLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("__VDS_PARTITION_STYLE").field(&self.0).finish()
@@ -4378,7 +4454,8 @@ impl Default for CHANGE_ATTRIBUTES_PARAMETERS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CHANGE_ATTRIBUTES_PARAMETERS_0_0 {
     pub attributes: u64,
 }
@@ -4391,7 +4468,8 @@ impl Default for CHANGE_ATTRIBUTES_PARAMETERS_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CHANGE_ATTRIBUTES_PARAMETERS_0_1 {
     pub bootIndicator: super::super::Foundation::BOOLEAN,
 }
@@ -4432,7 +4510,8 @@ impl Default for CHANGE_PARTITION_TYPE_PARAMETERS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CHANGE_PARTITION_TYPE_PARAMETERS_0_0 {
     pub partitionType: windows_core::GUID,
 }
@@ -4445,7 +4524,8 @@ impl Default for CHANGE_PARTITION_TYPE_PARAMETERS_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CHANGE_PARTITION_TYPE_PARAMETERS_0_1 {
     pub partitionType: u8,
 }
@@ -4486,7 +4566,8 @@ impl Default for CREATE_PARTITION_PARAMETERS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CREATE_PARTITION_PARAMETERS_0_0 {
     pub partitionType: windows_core::GUID,
     pub partitionId: windows_core::GUID,
@@ -4502,7 +4583,8 @@ impl Default for CREATE_PARTITION_PARAMETERS_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CREATE_PARTITION_PARAMETERS_0_1 {
     pub partitionType: u8,
     pub bootIndicator: super::super::Foundation::BOOLEAN,
@@ -4607,7 +4689,8 @@ impl Default for VDS_ASYNC_OUTPUT_0 {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_ASYNC_OUTPUT_0_0 {
     pub pVolumeUnk: core::mem::ManuallyDrop<Option<windows_core::IUnknown>>,
 }
@@ -4625,7 +4708,8 @@ impl Default for VDS_ASYNC_OUTPUT_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_ASYNC_OUTPUT_0_1 {
     pub pLunUnk: core::mem::ManuallyDrop<Option<windows_core::IUnknown>>,
 }
@@ -4643,7 +4727,8 @@ impl Default for VDS_ASYNC_OUTPUT_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_ASYNC_OUTPUT_0_2 {
     pub ullOffset: u64,
     pub volumeId: windows_core::GUID,
@@ -4657,7 +4742,8 @@ impl Default for VDS_ASYNC_OUTPUT_0_2 {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_ASYNC_OUTPUT_0_3 {
     pub pPortalGroupUnk: core::mem::ManuallyDrop<Option<windows_core::IUnknown>>,
 }
@@ -4675,7 +4761,8 @@ impl Default for VDS_ASYNC_OUTPUT_0_3 {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_ASYNC_OUTPUT_0_4 {
     pub pTargetUnk: core::mem::ManuallyDrop<Option<windows_core::IUnknown>>,
 }
@@ -4693,7 +4780,8 @@ impl Default for VDS_ASYNC_OUTPUT_0_4 {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_ASYNC_OUTPUT_0_5 {
     pub pVolumeUnk: core::mem::ManuallyDrop<Option<windows_core::IUnknown>>,
 }
@@ -4711,7 +4799,8 @@ impl Default for VDS_ASYNC_OUTPUT_0_5 {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_ASYNC_OUTPUT_0_6 {
     pub pVDiskUnk: core::mem::ManuallyDrop<Option<windows_core::IUnknown>>,
 }
@@ -4729,7 +4818,8 @@ impl Default for VDS_ASYNC_OUTPUT_0_6 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_ASYNC_OUTPUT_0_7 {
     pub ullReclaimedBytes: u64,
 }
@@ -4742,7 +4832,8 @@ impl Default for VDS_ASYNC_OUTPUT_0_7 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_CONTROLLER_NOTIFICATION {
     pub ulEvent: VDS_NF_CONTROLLER,
     pub controllerId: windows_core::GUID,
@@ -4756,7 +4847,8 @@ impl Default for VDS_CONTROLLER_NOTIFICATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_CONTROLLER_PROP {
     pub id: windows_core::GUID,
     pub pwszFriendlyName: windows_core::PWSTR,
@@ -4774,7 +4866,8 @@ impl Default for VDS_CONTROLLER_PROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_CREATE_VDISK_PARAMETERS {
     pub UniqueId: windows_core::GUID,
     pub MaximumSize: u64,
@@ -4792,7 +4885,8 @@ impl Default for VDS_CREATE_VDISK_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_DISK_EXTENT {
     pub diskId: windows_core::GUID,
     pub r#type: VDS_DISK_EXTENT_TYPE,
@@ -4811,7 +4905,8 @@ impl Default for VDS_DISK_EXTENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_DISK_FREE_EXTENT {
     pub diskId: windows_core::GUID,
     pub ullOffset: u64,
@@ -4826,7 +4921,8 @@ impl Default for VDS_DISK_FREE_EXTENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_DISK_NOTIFICATION {
     pub ulEvent: VDS_NF_DISK,
     pub diskId: windows_core::GUID,
@@ -4932,7 +5028,8 @@ impl Default for VDS_DISK_PROP2_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_DRIVE_EXTENT {
     pub id: windows_core::GUID,
     pub LunId: windows_core::GUID,
@@ -4948,7 +5045,8 @@ impl Default for VDS_DRIVE_EXTENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_DRIVE_LETTER_NOTIFICATION {
     pub ulEvent: u32,
     pub wcLetter: u16,
@@ -4963,7 +5061,8 @@ impl Default for VDS_DRIVE_LETTER_NOTIFICATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_DRIVE_LETTER_PROP {
     pub wcLetter: u16,
     pub volumeId: windows_core::GUID,
@@ -4979,7 +5078,8 @@ impl Default for VDS_DRIVE_LETTER_PROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_DRIVE_NOTIFICATION {
     pub ulEvent: VDS_NF_DRIVE,
     pub driveId: windows_core::GUID,
@@ -4993,7 +5093,8 @@ impl Default for VDS_DRIVE_NOTIFICATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_DRIVE_PROP {
     pub id: windows_core::GUID,
     pub ullSize: u64,
@@ -5014,7 +5115,8 @@ impl Default for VDS_DRIVE_PROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_DRIVE_PROP2 {
     pub id: windows_core::GUID,
     pub ullSize: u64,
@@ -5038,7 +5140,8 @@ impl Default for VDS_DRIVE_PROP2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_FILE_SYSTEM_FORMAT_SUPPORT_PROP {
     pub ulFlags: u32,
     pub usRevision: u16,
@@ -5055,7 +5158,8 @@ impl Default for VDS_FILE_SYSTEM_FORMAT_SUPPORT_PROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_FILE_SYSTEM_NOTIFICATION {
     pub ulEvent: VDS_NF_FILE_SYSTEM,
     pub volumeId: windows_core::GUID,
@@ -5070,7 +5174,8 @@ impl Default for VDS_FILE_SYSTEM_NOTIFICATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_FILE_SYSTEM_PROP {
     pub r#type: VDS_FILE_SYSTEM_TYPE,
     pub volumeId: windows_core::GUID,
@@ -5089,7 +5194,8 @@ impl Default for VDS_FILE_SYSTEM_PROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_FILE_SYSTEM_TYPE_PROP {
     pub r#type: VDS_FILE_SYSTEM_TYPE,
     pub wszName: [u16; 8],
@@ -5107,7 +5213,8 @@ impl Default for VDS_FILE_SYSTEM_TYPE_PROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_HBAPORT_PROP {
     pub id: windows_core::GUID,
     pub wwnNode: VDS_WWN,
@@ -5126,7 +5233,8 @@ impl Default for VDS_HBAPORT_PROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_HINTS {
     pub ullHintMask: u64,
     pub ullExpectedMaximumSize: u64,
@@ -5156,7 +5264,8 @@ impl Default for VDS_HINTS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_HINTS2 {
     pub ullHintMask: u64,
     pub ullExpectedMaximumSize: u64,
@@ -5199,7 +5308,8 @@ impl Default for VDS_HINTS2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_INPUT_DISK {
     pub diskId: windows_core::GUID,
     pub ullSize: u64,
@@ -5215,7 +5325,8 @@ impl Default for VDS_INPUT_DISK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_INTERCONNECT {
     pub m_addressType: VDS_INTERCONNECT_ADDRESS_TYPE,
     pub m_cbPort: u32,
@@ -5232,7 +5343,8 @@ impl Default for VDS_INTERCONNECT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_IPADDRESS {
     pub r#type: VDS_IPADDRESS_TYPE,
     pub ipv4Address: u32,
@@ -5251,7 +5363,8 @@ impl Default for VDS_IPADDRESS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_ISCSI_INITIATOR_ADAPTER_PROP {
     pub id: windows_core::GUID,
     pub pwszName: windows_core::PWSTR,
@@ -5265,7 +5378,8 @@ impl Default for VDS_ISCSI_INITIATOR_ADAPTER_PROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_ISCSI_INITIATOR_PORTAL_PROP {
     pub id: windows_core::GUID,
     pub address: VDS_IPADDRESS,
@@ -5280,7 +5394,8 @@ impl Default for VDS_ISCSI_INITIATOR_PORTAL_PROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_ISCSI_IPSEC_KEY {
     pub pKey: *mut u8,
     pub ulKeySize: u32,
@@ -5294,7 +5409,8 @@ impl Default for VDS_ISCSI_IPSEC_KEY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_ISCSI_PORTALGROUP_PROP {
     pub id: windows_core::GUID,
     pub tag: u16,
@@ -5308,7 +5424,8 @@ impl Default for VDS_ISCSI_PORTALGROUP_PROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_ISCSI_PORTAL_PROP {
     pub id: windows_core::GUID,
     pub address: VDS_IPADDRESS,
@@ -5323,7 +5440,8 @@ impl Default for VDS_ISCSI_PORTAL_PROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_ISCSI_SHARED_SECRET {
     pub pSharedSecret: *mut u8,
     pub ulSharedSecretSize: u32,
@@ -5337,7 +5455,8 @@ impl Default for VDS_ISCSI_SHARED_SECRET {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_ISCSI_TARGET_PROP {
     pub id: windows_core::GUID,
     pub pwszIscsiName: windows_core::PWSTR,
@@ -5353,7 +5472,8 @@ impl Default for VDS_ISCSI_TARGET_PROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_LUN_INFORMATION {
     pub m_version: u32,
     pub m_DeviceType: u8,
@@ -5378,7 +5498,8 @@ impl Default for VDS_LUN_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_LUN_NOTIFICATION {
     pub ulEvent: VDS_NF_LUN,
     pub LunId: windows_core::GUID,
@@ -5392,7 +5513,8 @@ impl Default for VDS_LUN_NOTIFICATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_LUN_PLEX_PROP {
     pub id: windows_core::GUID,
     pub ullSize: u64,
@@ -5413,7 +5535,8 @@ impl Default for VDS_LUN_PLEX_PROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_LUN_PROP {
     pub id: windows_core::GUID,
     pub ullSize: u64,
@@ -5436,7 +5559,8 @@ impl Default for VDS_LUN_PROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_MOUNT_POINT_NOTIFICATION {
     pub ulEvent: u32,
     pub volumeId: windows_core::GUID,
@@ -5492,7 +5616,8 @@ impl Default for VDS_NOTIFICATION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_PACK_NOTIFICATION {
     pub ulEvent: VDS_NF_PACK,
     pub packId: windows_core::GUID,
@@ -5506,7 +5631,8 @@ impl Default for VDS_PACK_NOTIFICATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_PACK_PROP {
     pub id: windows_core::GUID,
     pub pwszName: windows_core::PWSTR,
@@ -5554,7 +5680,8 @@ impl Default for VDS_PARTITION_INFORMATION_EX_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_PARTITION_INFO_GPT {
     pub partitionType: windows_core::GUID,
     pub partitionId: windows_core::GUID,
@@ -5570,7 +5697,8 @@ impl Default for VDS_PARTITION_INFO_GPT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_PARTITION_INFO_MBR {
     pub partitionType: u8,
     pub bootIndicator: super::super::Foundation::BOOLEAN,
@@ -5586,7 +5714,8 @@ impl Default for VDS_PARTITION_INFO_MBR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_PARTITION_NOTIFICATION {
     pub ulEvent: u32,
     pub diskId: windows_core::GUID,
@@ -5633,7 +5762,8 @@ impl Default for VDS_PARTITION_PROP_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_PATH_ID {
     pub ullSourceId: u64,
     pub ullPathId: u64,
@@ -5707,7 +5837,8 @@ impl Default for VDS_PATH_INFO_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_PATH_POLICY {
     pub pathId: VDS_PATH_ID,
     pub bPrimaryPath: super::super::Foundation::BOOL,
@@ -5722,7 +5853,8 @@ impl Default for VDS_PATH_POLICY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_POOL_ATTRIBUTES {
     pub ullAttributeMask: u64,
     pub raidType: VDS_RAID_TYPE,
@@ -5772,7 +5904,8 @@ impl Default for VDS_POOL_ATTRIBUTES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_POOL_CUSTOM_ATTRIBUTES {
     pub pwszName: windows_core::PWSTR,
     pub pwszValue: windows_core::PWSTR,
@@ -5786,7 +5919,8 @@ impl Default for VDS_POOL_CUSTOM_ATTRIBUTES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_PORTAL_GROUP_NOTIFICATION {
     pub ulEvent: u32,
     pub portalGroupId: windows_core::GUID,
@@ -5800,7 +5934,8 @@ impl Default for VDS_PORTAL_GROUP_NOTIFICATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_PORTAL_NOTIFICATION {
     pub ulEvent: u32,
     pub portalId: windows_core::GUID,
@@ -5814,7 +5949,8 @@ impl Default for VDS_PORTAL_NOTIFICATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_PORT_NOTIFICATION {
     pub ulEvent: VDS_NF_PORT,
     pub portId: windows_core::GUID,
@@ -5828,7 +5964,8 @@ impl Default for VDS_PORT_NOTIFICATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_PORT_PROP {
     pub id: windows_core::GUID,
     pub pwszFriendlyName: windows_core::PWSTR,
@@ -5844,7 +5981,8 @@ impl Default for VDS_PORT_PROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_PROVIDER_PROP {
     pub id: windows_core::GUID,
     pub pwszName: windows_core::PWSTR,
@@ -5864,7 +6002,8 @@ impl Default for VDS_PROVIDER_PROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_REPARSE_POINT_PROP {
     pub SourceVolumeId: windows_core::GUID,
     pub pwszPath: windows_core::PWSTR,
@@ -5878,7 +6017,8 @@ impl Default for VDS_REPARSE_POINT_PROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_SERVICE_NOTIFICATION {
     pub ulEvent: u32,
     pub action: VDS_RECOVER_ACTION,
@@ -5892,7 +6032,8 @@ impl Default for VDS_SERVICE_NOTIFICATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_SERVICE_PROP {
     pub pwszVersion: windows_core::PWSTR,
     pub ulFlags: u32,
@@ -5906,7 +6047,8 @@ impl Default for VDS_SERVICE_PROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_STORAGE_DEVICE_ID_DESCRIPTOR {
     pub m_version: u32,
     pub m_cIdentifiers: u32,
@@ -5921,7 +6063,8 @@ impl Default for VDS_STORAGE_DEVICE_ID_DESCRIPTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_STORAGE_IDENTIFIER {
     pub m_CodeSet: VDS_STORAGE_IDENTIFIER_CODE_SET,
     pub m_Type: VDS_STORAGE_IDENTIFIER_TYPE,
@@ -5937,7 +6080,8 @@ impl Default for VDS_STORAGE_IDENTIFIER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_STORAGE_POOL_DRIVE_EXTENT {
     pub id: windows_core::GUID,
     pub ullSize: u64,
@@ -5952,7 +6096,8 @@ impl Default for VDS_STORAGE_POOL_DRIVE_EXTENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_STORAGE_POOL_PROP {
     pub id: windows_core::GUID,
     pub status: VDS_STORAGE_POOL_STATUS,
@@ -5973,7 +6118,8 @@ impl Default for VDS_STORAGE_POOL_PROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_SUB_SYSTEM_NOTIFICATION {
     pub ulEvent: u32,
     pub subSystemId: windows_core::GUID,
@@ -5987,7 +6133,8 @@ impl Default for VDS_SUB_SYSTEM_NOTIFICATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_SUB_SYSTEM_PROP {
     pub id: windows_core::GUID,
     pub pwszFriendlyName: windows_core::PWSTR,
@@ -6010,7 +6157,8 @@ impl Default for VDS_SUB_SYSTEM_PROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_SUB_SYSTEM_PROP2 {
     pub id: windows_core::GUID,
     pub pwszFriendlyName: windows_core::PWSTR,
@@ -6035,7 +6183,8 @@ impl Default for VDS_SUB_SYSTEM_PROP2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_TARGET_NOTIFICATION {
     pub ulEvent: u32,
     pub targetId: windows_core::GUID,
@@ -6050,7 +6199,8 @@ impl Default for VDS_TARGET_NOTIFICATION {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Storage_Vhd")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_VDISK_PROPERTIES {
     pub Id: windows_core::GUID,
     pub State: VDS_VDISK_STATE,
@@ -6074,7 +6224,8 @@ impl Default for VDS_VDISK_PROPERTIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_VOLUME_NOTIFICATION {
     pub ulEvent: u32,
     pub volumeId: windows_core::GUID,
@@ -6090,7 +6241,8 @@ impl Default for VDS_VOLUME_NOTIFICATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_VOLUME_PLEX_PROP {
     pub id: windows_core::GUID,
     pub r#type: VDS_VOLUME_PLEX_TYPE,
@@ -6110,7 +6262,8 @@ impl Default for VDS_VOLUME_PLEX_PROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_VOLUME_PROP {
     pub id: windows_core::GUID,
     pub r#type: VDS_VOLUME_TYPE,
@@ -6131,7 +6284,8 @@ impl Default for VDS_VOLUME_PROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_VOLUME_PROP2 {
     pub id: windows_core::GUID,
     pub r#type: VDS_VOLUME_TYPE,
@@ -6154,7 +6308,8 @@ impl Default for VDS_VOLUME_PROP2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VDS_WWN {
     pub rguchWwn: [u8; 8],
 }
@@ -6168,3 +6323,5 @@ impl Default for VDS_WWN {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
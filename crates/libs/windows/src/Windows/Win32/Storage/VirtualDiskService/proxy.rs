@@ -0,0 +1,1775 @@
+impl windows_core::imp::ProxyVtable for IEnumVdsObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppobjectarray: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumVdsObject", "Next", 3usize, |this| ((**(this as *const *const IEnumVdsObject_Vtbl)).Next)(this, celt, ppobjectarray, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumVdsObject", "Skip", 4usize, |this| ((**(this as *const *const IEnumVdsObject_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumVdsObject", "Reset", 5usize, |this| ((**(this as *const *const IEnumVdsObject_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumVdsObject", "Clone", 6usize, |this| ((**(this as *const *const IEnumVdsObject_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumVdsObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsAdmin_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterProvider(this: *mut core::ffi::c_void, providerid: windows_core::GUID, providerclsid: windows_core::GUID, pwszname: windows_core::PCWSTR, r#type: VDS_PROVIDER_TYPE, pwszmachinename: windows_core::PCWSTR, pwszversion: windows_core::PCWSTR, guidversionid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsAdmin", "RegisterProvider", 3usize, |this| ((**(this as *const *const IVdsAdmin_Vtbl)).RegisterProvider)(this, providerid, providerclsid, pwszname, r#type, pwszmachinename, pwszversion, guidversionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterProvider(this: *mut core::ffi::c_void, providerid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsAdmin", "UnregisterProvider", 4usize, |this| ((**(this as *const *const IVdsAdmin_Vtbl)).UnregisterProvider)(this, providerid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterProvider, UnregisterProvider }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsAdmin as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsAdvancedDisk_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPartitionProperties(this: *mut core::ffi::c_void, ulloffset: u64, ppartitionprop: *mut VDS_PARTITION_PROP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsAdvancedDisk", "GetPartitionProperties", 3usize, |this| ((**(this as *const *const IVdsAdvancedDisk_Vtbl)).GetPartitionProperties)(this, ulloffset, ppartitionprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryPartitions(this: *mut core::ffi::c_void, pppartitionproparray: *mut *mut VDS_PARTITION_PROP, plnumberofpartitions: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsAdvancedDisk", "QueryPartitions", 4usize, |this| ((**(this as *const *const IVdsAdvancedDisk_Vtbl)).QueryPartitions)(this, pppartitionproparray, plnumberofpartitions), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePartition(this: *mut core::ffi::c_void, ulloffset: u64, ullsize: u64, para: *const CREATE_PARTITION_PARAMETERS, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsAdvancedDisk", "CreatePartition", 5usize, |this| ((**(this as *const *const IVdsAdvancedDisk_Vtbl)).CreatePartition)(this, ulloffset, ullsize, para, ppasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeletePartition(this: *mut core::ffi::c_void, ulloffset: u64, bforce: super::super::Foundation::BOOL, bforceprotected: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsAdvancedDisk", "DeletePartition", 6usize, |this| ((**(this as *const *const IVdsAdvancedDisk_Vtbl)).DeletePartition)(this, ulloffset, bforce, bforceprotected), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeAttributes(this: *mut core::ffi::c_void, ulloffset: u64, para: *const CHANGE_ATTRIBUTES_PARAMETERS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsAdvancedDisk", "ChangeAttributes", 7usize, |this| ((**(this as *const *const IVdsAdvancedDisk_Vtbl)).ChangeAttributes)(this, ulloffset, para), |result| Some(*result))
+        }
+        unsafe extern "system" fn AssignDriveLetter(this: *mut core::ffi::c_void, ulloffset: u64, wcletter: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsAdvancedDisk", "AssignDriveLetter", 8usize, |this| ((**(this as *const *const IVdsAdvancedDisk_Vtbl)).AssignDriveLetter)(this, ulloffset, wcletter), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteDriveLetter(this: *mut core::ffi::c_void, ulloffset: u64, wcletter: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsAdvancedDisk", "DeleteDriveLetter", 9usize, |this| ((**(this as *const *const IVdsAdvancedDisk_Vtbl)).DeleteDriveLetter)(this, ulloffset, wcletter), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDriveLetter(this: *mut core::ffi::c_void, ulloffset: u64, pwcletter: windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsAdvancedDisk", "GetDriveLetter", 10usize, |this| ((**(this as *const *const IVdsAdvancedDisk_Vtbl)).GetDriveLetter)(this, ulloffset, pwcletter), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormatPartition(this: *mut core::ffi::c_void, ulloffset: u64, r#type: VDS_FILE_SYSTEM_TYPE, pwszlabel: windows_core::PCWSTR, dwunitallocationsize: u32, bforce: super::super::Foundation::BOOL, bquickformat: super::super::Foundation::BOOL, benablecompression: super::super::Foundation::BOOL, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsAdvancedDisk", "FormatPartition", 11usize, |this| ((**(this as *const *const IVdsAdvancedDisk_Vtbl)).FormatPartition)(this, ulloffset, r#type, pwszlabel, dwunitallocationsize, bforce, bquickformat, benablecompression, ppasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clean(this: *mut core::ffi::c_void, bforce: super::super::Foundation::BOOL, bforceoem: super::super::Foundation::BOOL, bfullclean: super::super::Foundation::BOOL, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsAdvancedDisk", "Clean", 12usize, |this| ((**(this as *const *const IVdsAdvancedDisk_Vtbl)).Clean)(this, bforce, bforceoem, bfullclean, ppasync), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPartitionProperties,
+            QueryPartitions,
+            CreatePartition,
+            DeletePartition,
+            ChangeAttributes,
+            AssignDriveLetter,
+            DeleteDriveLetter,
+            GetDriveLetter,
+            FormatPartition,
+            Clean,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsAdvancedDisk as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsAdvancedDisk2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChangePartitionType(this: *mut core::ffi::c_void, ulloffset: u64, bforce: super::super::Foundation::BOOL, para: *const CHANGE_PARTITION_TYPE_PARAMETERS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsAdvancedDisk2", "ChangePartitionType", 3usize, |this| ((**(this as *const *const IVdsAdvancedDisk2_Vtbl)).ChangePartitionType)(this, ulloffset, bforce, para), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ChangePartitionType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsAdvancedDisk2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsAdvancedDisk3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, padvdiskprop: *mut VDS_ADVANCEDDISK_PROP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsAdvancedDisk3", "GetProperties", 3usize, |this| ((**(this as *const *const IVdsAdvancedDisk3_Vtbl)).GetProperties)(this, padvdiskprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUniqueId(this: *mut core::ffi::c_void, ppwszid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsAdvancedDisk3", "GetUniqueId", 4usize, |this| ((**(this as *const *const IVdsAdvancedDisk3_Vtbl)).GetUniqueId)(this, ppwszid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProperties, GetUniqueId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsAdvancedDisk3 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsAdviseSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnNotify(this: *mut core::ffi::c_void, lnumberofnotifications: i32, pnotificationarray: *const VDS_NOTIFICATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsAdviseSink", "OnNotify", 3usize, |this| ((**(this as *const *const IVdsAdviseSink_Vtbl)).OnNotify)(this, lnumberofnotifications, pnotificationarray), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnNotify }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsAdviseSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsAsync_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsAsync", "Cancel", 3usize, |this| ((**(this as *const *const IVdsAsync_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Wait(this: *mut core::ffi::c_void, phrresult: *mut windows_core::HRESULT, pasyncout: *mut VDS_ASYNC_OUTPUT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsAsync", "Wait", 4usize, |this| ((**(this as *const *const IVdsAsync_Vtbl)).Wait)(this, phrresult, pasyncout), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryStatus(this: *mut core::ffi::c_void, phrresult: *mut windows_core::HRESULT, pulpercentcompleted: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsAsync", "QueryStatus", 5usize, |this| ((**(this as *const *const IVdsAsync_Vtbl)).QueryStatus)(this, phrresult, pulpercentcompleted), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Cancel, Wait, QueryStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsAsync as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsController_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, pcontrollerprop: *mut VDS_CONTROLLER_PROP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsController", "GetProperties", 3usize, |this| ((**(this as *const *const IVdsController_Vtbl)).GetProperties)(this, pcontrollerprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubSystem(this: *mut core::ffi::c_void, ppsubsystem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsController", "GetSubSystem", 4usize, |this| ((**(this as *const *const IVdsController_Vtbl)).GetSubSystem)(this, ppsubsystem), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPortProperties(this: *mut core::ffi::c_void, sportnumber: i16, pportprop: *mut VDS_PORT_PROP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsController", "GetPortProperties", 5usize, |this| ((**(this as *const *const IVdsController_Vtbl)).GetPortProperties)(this, sportnumber, pportprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlushCache(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsController", "FlushCache", 6usize, |this| ((**(this as *const *const IVdsController_Vtbl)).FlushCache)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn InvalidateCache(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsController", "InvalidateCache", 7usize, |this| ((**(this as *const *const IVdsController_Vtbl)).InvalidateCache)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsController", "Reset", 8usize, |this| ((**(this as *const *const IVdsController_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryAssociatedLuns(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsController", "QueryAssociatedLuns", 9usize, |this| ((**(this as *const *const IVdsController_Vtbl)).QueryAssociatedLuns)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatus(this: *mut core::ffi::c_void, status: VDS_CONTROLLER_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsController", "SetStatus", 10usize, |this| ((**(this as *const *const IVdsController_Vtbl)).SetStatus)(this, status), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetProperties,
+            GetSubSystem,
+            GetPortProperties,
+            FlushCache,
+            InvalidateCache,
+            Reset,
+            QueryAssociatedLuns,
+            SetStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsController as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsControllerControllerPort_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryControllerPorts(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsControllerControllerPort", "QueryControllerPorts", 3usize, |this| ((**(this as *const *const IVdsControllerControllerPort_Vtbl)).QueryControllerPorts)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryControllerPorts }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsControllerControllerPort as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsControllerPort_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, pportprop: *mut VDS_PORT_PROP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsControllerPort", "GetProperties", 3usize, |this| ((**(this as *const *const IVdsControllerPort_Vtbl)).GetProperties)(this, pportprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetController(this: *mut core::ffi::c_void, ppcontroller: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsControllerPort", "GetController", 4usize, |this| ((**(this as *const *const IVdsControllerPort_Vtbl)).GetController)(this, ppcontroller), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryAssociatedLuns(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsControllerPort", "QueryAssociatedLuns", 5usize, |this| ((**(this as *const *const IVdsControllerPort_Vtbl)).QueryAssociatedLuns)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsControllerPort", "Reset", 6usize, |this| ((**(this as *const *const IVdsControllerPort_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatus(this: *mut core::ffi::c_void, status: VDS_PORT_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsControllerPort", "SetStatus", 7usize, |this| ((**(this as *const *const IVdsControllerPort_Vtbl)).SetStatus)(this, status), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetProperties,
+            GetController,
+            QueryAssociatedLuns,
+            Reset,
+            SetStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsControllerPort as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsCreatePartitionEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreatePartitionEx(this: *mut core::ffi::c_void, ulloffset: u64, ullsize: u64, ulalign: u32, para: *const CREATE_PARTITION_PARAMETERS, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsCreatePartitionEx", "CreatePartitionEx", 3usize, |this| ((**(this as *const *const IVdsCreatePartitionEx_Vtbl)).CreatePartitionEx)(this, ulloffset, ullsize, ulalign, para, ppasync), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreatePartitionEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsCreatePartitionEx as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsDisk_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, pdiskproperties: *mut VDS_DISK_PROP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsDisk", "GetProperties", 3usize, |this| ((**(this as *const *const IVdsDisk_Vtbl)).GetProperties)(this, pdiskproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPack(this: *mut core::ffi::c_void, pppack: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsDisk", "GetPack", 4usize, |this| ((**(this as *const *const IVdsDisk_Vtbl)).GetPack)(this, pppack), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIdentificationData(this: *mut core::ffi::c_void, pluninfo: *mut VDS_LUN_INFORMATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsDisk", "GetIdentificationData", 5usize, |this| ((**(this as *const *const IVdsDisk_Vtbl)).GetIdentificationData)(this, pluninfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryExtents(this: *mut core::ffi::c_void, ppextentarray: *mut *mut VDS_DISK_EXTENT, plnumberofextents: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsDisk", "QueryExtents", 6usize, |this| ((**(this as *const *const IVdsDisk_Vtbl)).QueryExtents)(this, ppextentarray, plnumberofextents), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertStyle(this: *mut core::ffi::c_void, newstyle: VDS_PARTITION_STYLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsDisk", "ConvertStyle", 7usize, |this| ((**(this as *const *const IVdsDisk_Vtbl)).ConvertStyle)(this, newstyle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFlags(this: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsDisk", "SetFlags", 8usize, |this| ((**(this as *const *const IVdsDisk_Vtbl)).SetFlags)(this, ulflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearFlags(this: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsDisk", "ClearFlags", 9usize, |this| ((**(this as *const *const IVdsDisk_Vtbl)).ClearFlags)(this, ulflags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetProperties,
+            GetPack,
+            GetIdentificationData,
+            QueryExtents,
+            ConvertStyle,
+            SetFlags,
+            ClearFlags,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsDisk as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsDisk2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSANMode(this: *mut core::ffi::c_void, benable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsDisk2", "SetSANMode", 3usize, |this| ((**(this as *const *const IVdsDisk2_Vtbl)).SetSANMode)(this, benable), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetSANMode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsDisk2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsDisk3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperties2(this: *mut core::ffi::c_void, pdiskproperties: *mut VDS_DISK_PROP2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsDisk3", "GetProperties2", 3usize, |this| ((**(this as *const *const IVdsDisk3_Vtbl)).GetProperties2)(this, pdiskproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryFreeExtents(this: *mut core::ffi::c_void, ulalign: u32, ppfreeextentarray: *mut *mut VDS_DISK_FREE_EXTENT, plnumberoffreeextents: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsDisk3", "QueryFreeExtents", 4usize, |this| ((**(this as *const *const IVdsDisk3_Vtbl)).QueryFreeExtents)(this, ulalign, ppfreeextentarray, plnumberoffreeextents), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProperties2, QueryFreeExtents }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsDisk3 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsDiskOnline_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Online(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsDiskOnline", "Online", 3usize, |this| ((**(this as *const *const IVdsDiskOnline_Vtbl)).Online)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Offline(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsDiskOnline", "Offline", 4usize, |this| ((**(this as *const *const IVdsDiskOnline_Vtbl)).Offline)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Online, Offline }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsDiskOnline as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsDiskPartitionMF_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPartitionFileSystemProperties(this: *mut core::ffi::c_void, ulloffset: u64, pfilesystemprop: *mut VDS_FILE_SYSTEM_PROP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsDiskPartitionMF", "GetPartitionFileSystemProperties", 3usize, |this| ((**(this as *const *const IVdsDiskPartitionMF_Vtbl)).GetPartitionFileSystemProperties)(this, ulloffset, pfilesystemprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPartitionFileSystemTypeName(this: *mut core::ffi::c_void, ulloffset: u64, ppwszfilesystemtypename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsDiskPartitionMF", "GetPartitionFileSystemTypeName", 4usize, |this| ((**(this as *const *const IVdsDiskPartitionMF_Vtbl)).GetPartitionFileSystemTypeName)(this, ulloffset, ppwszfilesystemtypename), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryPartitionFileSystemFormatSupport(this: *mut core::ffi::c_void, ulloffset: u64, ppfilesystemsupportprops: *mut *mut VDS_FILE_SYSTEM_FORMAT_SUPPORT_PROP, plnumberoffilesystems: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsDiskPartitionMF", "QueryPartitionFileSystemFormatSupport", 5usize, |this| ((**(this as *const *const IVdsDiskPartitionMF_Vtbl)).QueryPartitionFileSystemFormatSupport)(this, ulloffset, ppfilesystemsupportprops, plnumberoffilesystems), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormatPartitionEx(this: *mut core::ffi::c_void, ulloffset: u64, pwszfilesystemtypename: windows_core::PCWSTR, usfilesystemrevision: u16, uldesiredunitallocationsize: u32, pwszlabel: windows_core::PCWSTR, bforce: super::super::Foundation::BOOL, bquickformat: super::super::Foundation::BOOL, benablecompression: super::super::Foundation::BOOL, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsDiskPartitionMF", "FormatPartitionEx", 6usize, |this| ((**(this as *const *const IVdsDiskPartitionMF_Vtbl)).FormatPartitionEx)(this, ulloffset, pwszfilesystemtypename, usfilesystemrevision, uldesiredunitallocationsize, pwszlabel, bforce, bquickformat, benablecompression, ppasync), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPartitionFileSystemProperties,
+            GetPartitionFileSystemTypeName,
+            QueryPartitionFileSystemFormatSupport,
+            FormatPartitionEx,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsDiskPartitionMF as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsDiskPartitionMF2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FormatPartitionEx2(this: *mut core::ffi::c_void, ulloffset: u64, pwszfilesystemtypename: windows_core::PCWSTR, usfilesystemrevision: u16, uldesiredunitallocationsize: u32, pwszlabel: windows_core::PCWSTR, options: u32, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsDiskPartitionMF2", "FormatPartitionEx2", 3usize, |this| ((**(this as *const *const IVdsDiskPartitionMF2_Vtbl)).FormatPartitionEx2)(this, ulloffset, pwszfilesystemtypename, usfilesystemrevision, uldesiredunitallocationsize, pwszlabel, options, ppasync), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FormatPartitionEx2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsDiskPartitionMF2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsDrive_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, pdriveprop: *mut VDS_DRIVE_PROP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsDrive", "GetProperties", 3usize, |this| ((**(this as *const *const IVdsDrive_Vtbl)).GetProperties)(this, pdriveprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubSystem(this: *mut core::ffi::c_void, ppsubsystem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsDrive", "GetSubSystem", 4usize, |this| ((**(this as *const *const IVdsDrive_Vtbl)).GetSubSystem)(this, ppsubsystem), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryExtents(this: *mut core::ffi::c_void, ppextentarray: *mut *mut VDS_DRIVE_EXTENT, plnumberofextents: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsDrive", "QueryExtents", 5usize, |this| ((**(this as *const *const IVdsDrive_Vtbl)).QueryExtents)(this, ppextentarray, plnumberofextents), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFlags(this: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsDrive", "SetFlags", 6usize, |this| ((**(this as *const *const IVdsDrive_Vtbl)).SetFlags)(this, ulflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearFlags(this: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsDrive", "ClearFlags", 7usize, |this| ((**(this as *const *const IVdsDrive_Vtbl)).ClearFlags)(this, ulflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatus(this: *mut core::ffi::c_void, status: VDS_DRIVE_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsDrive", "SetStatus", 8usize, |this| ((**(this as *const *const IVdsDrive_Vtbl)).SetStatus)(this, status), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetProperties,
+            GetSubSystem,
+            QueryExtents,
+            SetFlags,
+            ClearFlags,
+            SetStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsDrive as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsDrive2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperties2(this: *mut core::ffi::c_void, pdriveprop2: *mut VDS_DRIVE_PROP2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsDrive2", "GetProperties2", 3usize, |this| ((**(this as *const *const IVdsDrive2_Vtbl)).GetProperties2)(this, pdriveprop2), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProperties2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsDrive2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsHbaPort_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, phbaportprop: *mut VDS_HBAPORT_PROP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsHbaPort", "GetProperties", 3usize, |this| ((**(this as *const *const IVdsHbaPort_Vtbl)).GetProperties)(this, phbaportprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllPathStatuses(this: *mut core::ffi::c_void, status: VDS_PATH_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsHbaPort", "SetAllPathStatuses", 4usize, |this| ((**(this as *const *const IVdsHbaPort_Vtbl)).SetAllPathStatuses)(this, status), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProperties, SetAllPathStatuses }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsHbaPort as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsHwProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QuerySubSystems(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsHwProvider", "QuerySubSystems", 3usize, |this| ((**(this as *const *const IVdsHwProvider_Vtbl)).QuerySubSystems)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reenumerate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsHwProvider", "Reenumerate", 4usize, |this| ((**(this as *const *const IVdsHwProvider_Vtbl)).Reenumerate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsHwProvider", "Refresh", 5usize, |this| ((**(this as *const *const IVdsHwProvider_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QuerySubSystems, Reenumerate, Refresh }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsHwProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsHwProviderPrivate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryIfCreatedLun(this: *mut core::ffi::c_void, pwszdevicepath: windows_core::PCWSTR, pvdsluninformation: *const VDS_LUN_INFORMATION, plunid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsHwProviderPrivate", "QueryIfCreatedLun", 3usize, |this| ((**(this as *const *const IVdsHwProviderPrivate_Vtbl)).QueryIfCreatedLun)(this, pwszdevicepath, pvdsluninformation, plunid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryIfCreatedLun }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsHwProviderPrivate as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsHwProviderPrivateMpio_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAllPathStatusesFromHbaPort(this: *mut core::ffi::c_void, hbaportprop: VDS_HBAPORT_PROP, status: VDS_PATH_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsHwProviderPrivateMpio", "SetAllPathStatusesFromHbaPort", 3usize, |this| ((**(this as *const *const IVdsHwProviderPrivateMpio_Vtbl)).SetAllPathStatusesFromHbaPort)(this, hbaportprop, status), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAllPathStatusesFromHbaPort }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsHwProviderPrivateMpio as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsHwProviderStoragePools_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryStoragePools(this: *mut core::ffi::c_void, ulflags: u32, ullremainingfreespace: u64, ppoolattributes: *const VDS_POOL_ATTRIBUTES, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsHwProviderStoragePools", "QueryStoragePools", 3usize, |this| ((**(this as *const *const IVdsHwProviderStoragePools_Vtbl)).QueryStoragePools)(this, ulflags, ullremainingfreespace, ppoolattributes, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateLunInStoragePool(this: *mut core::ffi::c_void, r#type: VDS_LUN_TYPE, ullsizeinbytes: u64, storagepoolid: windows_core::GUID, pwszunmaskinglist: windows_core::PCWSTR, phints2: *const VDS_HINTS2, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsHwProviderStoragePools", "CreateLunInStoragePool", 4usize, |this| ((**(this as *const *const IVdsHwProviderStoragePools_Vtbl)).CreateLunInStoragePool)(this, r#type, ullsizeinbytes, storagepoolid, pwszunmaskinglist, phints2, ppasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryMaxLunCreateSizeInStoragePool(this: *mut core::ffi::c_void, r#type: VDS_LUN_TYPE, storagepoolid: windows_core::GUID, phints2: *const VDS_HINTS2, pullmaxlunsize: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsHwProviderStoragePools", "QueryMaxLunCreateSizeInStoragePool", 5usize, |this| ((**(this as *const *const IVdsHwProviderStoragePools_Vtbl)).QueryMaxLunCreateSizeInStoragePool)(this, r#type, storagepoolid, phints2, pullmaxlunsize), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            QueryStoragePools,
+            CreateLunInStoragePool,
+            QueryMaxLunCreateSizeInStoragePool,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsHwProviderStoragePools as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsHwProviderType_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProviderType(this: *mut core::ffi::c_void, ptype: *mut VDS_HWPROVIDER_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsHwProviderType", "GetProviderType", 3usize, |this| ((**(this as *const *const IVdsHwProviderType_Vtbl)).GetProviderType)(this, ptype), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProviderType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsHwProviderType as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsHwProviderType2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProviderType2(this: *mut core::ffi::c_void, ptype: *mut VDS_HWPROVIDER_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsHwProviderType2", "GetProviderType2", 3usize, |this| ((**(this as *const *const IVdsHwProviderType2_Vtbl)).GetProviderType2)(this, ptype), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProviderType2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsHwProviderType2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsIscsiInitiatorAdapter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, pinitiatoradapterprop: *mut VDS_ISCSI_INITIATOR_ADAPTER_PROP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiInitiatorAdapter", "GetProperties", 3usize, |this| ((**(this as *const *const IVdsIscsiInitiatorAdapter_Vtbl)).GetProperties)(this, pinitiatoradapterprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryInitiatorPortals(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiInitiatorAdapter", "QueryInitiatorPortals", 4usize, |this| ((**(this as *const *const IVdsIscsiInitiatorAdapter_Vtbl)).QueryInitiatorPortals)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoginToTarget(this: *mut core::ffi::c_void, logintype: VDS_ISCSI_LOGIN_TYPE, targetid: windows_core::GUID, targetportalid: windows_core::GUID, initiatorportalid: windows_core::GUID, ulloginflags: u32, bheaderdigest: super::super::Foundation::BOOL, bdatadigest: super::super::Foundation::BOOL, authtype: VDS_ISCSI_AUTH_TYPE, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiInitiatorAdapter", "LoginToTarget", 5usize, |this| ((**(this as *const *const IVdsIscsiInitiatorAdapter_Vtbl)).LoginToTarget)(this, logintype, targetid, targetportalid, initiatorportalid, ulloginflags, bheaderdigest, bdatadigest, authtype, ppasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogoutFromTarget(this: *mut core::ffi::c_void, targetid: windows_core::GUID, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiInitiatorAdapter", "LogoutFromTarget", 6usize, |this| ((**(this as *const *const IVdsIscsiInitiatorAdapter_Vtbl)).LogoutFromTarget)(this, targetid, ppasync), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetProperties,
+            QueryInitiatorPortals,
+            LoginToTarget,
+            LogoutFromTarget,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsIscsiInitiatorAdapter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsIscsiInitiatorPortal_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, pinitiatorportalprop: *mut VDS_ISCSI_INITIATOR_PORTAL_PROP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiInitiatorPortal", "GetProperties", 3usize, |this| ((**(this as *const *const IVdsIscsiInitiatorPortal_Vtbl)).GetProperties)(this, pinitiatorportalprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInitiatorAdapter(this: *mut core::ffi::c_void, ppinitiatoradapter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiInitiatorPortal", "GetInitiatorAdapter", 4usize, |this| ((**(this as *const *const IVdsIscsiInitiatorPortal_Vtbl)).GetInitiatorAdapter)(this, ppinitiatoradapter), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIpsecTunnelAddress(this: *mut core::ffi::c_void, ptunneladdress: *const VDS_IPADDRESS, pdestinationaddress: *const VDS_IPADDRESS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiInitiatorPortal", "SetIpsecTunnelAddress", 5usize, |this| ((**(this as *const *const IVdsIscsiInitiatorPortal_Vtbl)).SetIpsecTunnelAddress)(this, ptunneladdress, pdestinationaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIpsecSecurity(this: *mut core::ffi::c_void, targetportalid: windows_core::GUID, pullsecurityflags: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiInitiatorPortal", "GetIpsecSecurity", 6usize, |this| ((**(this as *const *const IVdsIscsiInitiatorPortal_Vtbl)).GetIpsecSecurity)(this, targetportalid, pullsecurityflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIpsecSecurity(this: *mut core::ffi::c_void, targetportalid: windows_core::GUID, ullsecurityflags: u64, pipseckey: *const VDS_ISCSI_IPSEC_KEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiInitiatorPortal", "SetIpsecSecurity", 7usize, |this| ((**(this as *const *const IVdsIscsiInitiatorPortal_Vtbl)).SetIpsecSecurity)(this, targetportalid, ullsecurityflags, pipseckey), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetProperties,
+            GetInitiatorAdapter,
+            SetIpsecTunnelAddress,
+            GetIpsecSecurity,
+            SetIpsecSecurity,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsIscsiInitiatorPortal as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsIscsiPortal_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, pportalprop: *mut VDS_ISCSI_PORTAL_PROP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiPortal", "GetProperties", 3usize, |this| ((**(this as *const *const IVdsIscsiPortal_Vtbl)).GetProperties)(this, pportalprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubSystem(this: *mut core::ffi::c_void, ppsubsystem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiPortal", "GetSubSystem", 4usize, |this| ((**(this as *const *const IVdsIscsiPortal_Vtbl)).GetSubSystem)(this, ppsubsystem), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryAssociatedPortalGroups(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiPortal", "QueryAssociatedPortalGroups", 5usize, |this| ((**(this as *const *const IVdsIscsiPortal_Vtbl)).QueryAssociatedPortalGroups)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatus(this: *mut core::ffi::c_void, status: VDS_ISCSI_PORTAL_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiPortal", "SetStatus", 6usize, |this| ((**(this as *const *const IVdsIscsiPortal_Vtbl)).SetStatus)(this, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIpsecTunnelAddress(this: *mut core::ffi::c_void, ptunneladdress: *const VDS_IPADDRESS, pdestinationaddress: *const VDS_IPADDRESS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiPortal", "SetIpsecTunnelAddress", 7usize, |this| ((**(this as *const *const IVdsIscsiPortal_Vtbl)).SetIpsecTunnelAddress)(this, ptunneladdress, pdestinationaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIpsecSecurity(this: *mut core::ffi::c_void, pinitiatorportaladdress: *const VDS_IPADDRESS, pullsecurityflags: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiPortal", "GetIpsecSecurity", 8usize, |this| ((**(this as *const *const IVdsIscsiPortal_Vtbl)).GetIpsecSecurity)(this, pinitiatorportaladdress, pullsecurityflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIpsecSecurity(this: *mut core::ffi::c_void, pinitiatorportaladdress: *const VDS_IPADDRESS, ullsecurityflags: u64, pipseckey: *const VDS_ISCSI_IPSEC_KEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiPortal", "SetIpsecSecurity", 9usize, |this| ((**(this as *const *const IVdsIscsiPortal_Vtbl)).SetIpsecSecurity)(this, pinitiatorportaladdress, ullsecurityflags, pipseckey), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetProperties,
+            GetSubSystem,
+            QueryAssociatedPortalGroups,
+            SetStatus,
+            SetIpsecTunnelAddress,
+            GetIpsecSecurity,
+            SetIpsecSecurity,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsIscsiPortal as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsIscsiPortalGroup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, pportalgroupprop: *mut VDS_ISCSI_PORTALGROUP_PROP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiPortalGroup", "GetProperties", 3usize, |this| ((**(this as *const *const IVdsIscsiPortalGroup_Vtbl)).GetProperties)(this, pportalgroupprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTarget(this: *mut core::ffi::c_void, pptarget: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiPortalGroup", "GetTarget", 4usize, |this| ((**(this as *const *const IVdsIscsiPortalGroup_Vtbl)).GetTarget)(this, pptarget), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryAssociatedPortals(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiPortalGroup", "QueryAssociatedPortals", 5usize, |this| ((**(this as *const *const IVdsIscsiPortalGroup_Vtbl)).QueryAssociatedPortals)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPortal(this: *mut core::ffi::c_void, portalid: windows_core::GUID, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiPortalGroup", "AddPortal", 6usize, |this| ((**(this as *const *const IVdsIscsiPortalGroup_Vtbl)).AddPortal)(this, portalid, ppasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePortal(this: *mut core::ffi::c_void, portalid: windows_core::GUID, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiPortalGroup", "RemovePortal", 7usize, |this| ((**(this as *const *const IVdsIscsiPortalGroup_Vtbl)).RemovePortal)(this, portalid, ppasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiPortalGroup", "Delete", 8usize, |this| ((**(this as *const *const IVdsIscsiPortalGroup_Vtbl)).Delete)(this, ppasync), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetProperties,
+            GetTarget,
+            QueryAssociatedPortals,
+            AddPortal,
+            RemovePortal,
+            Delete,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsIscsiPortalGroup as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsIscsiPortalLocal_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetIpsecSecurityLocal(this: *mut core::ffi::c_void, ullsecurityflags: u64, pipseckey: *const VDS_ISCSI_IPSEC_KEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiPortalLocal", "SetIpsecSecurityLocal", 3usize, |this| ((**(this as *const *const IVdsIscsiPortalLocal_Vtbl)).SetIpsecSecurityLocal)(this, ullsecurityflags, pipseckey), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetIpsecSecurityLocal }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsIscsiPortalLocal as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsIscsiTarget_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, ptargetprop: *mut VDS_ISCSI_TARGET_PROP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiTarget", "GetProperties", 3usize, |this| ((**(this as *const *const IVdsIscsiTarget_Vtbl)).GetProperties)(this, ptargetprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubSystem(this: *mut core::ffi::c_void, ppsubsystem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiTarget", "GetSubSystem", 4usize, |this| ((**(this as *const *const IVdsIscsiTarget_Vtbl)).GetSubSystem)(this, ppsubsystem), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryPortalGroups(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiTarget", "QueryPortalGroups", 5usize, |this| ((**(this as *const *const IVdsIscsiTarget_Vtbl)).QueryPortalGroups)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryAssociatedLuns(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiTarget", "QueryAssociatedLuns", 6usize, |this| ((**(this as *const *const IVdsIscsiTarget_Vtbl)).QueryAssociatedLuns)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePortalGroup(this: *mut core::ffi::c_void, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiTarget", "CreatePortalGroup", 7usize, |this| ((**(this as *const *const IVdsIscsiTarget_Vtbl)).CreatePortalGroup)(this, ppasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiTarget", "Delete", 8usize, |this| ((**(this as *const *const IVdsIscsiTarget_Vtbl)).Delete)(this, ppasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFriendlyName(this: *mut core::ffi::c_void, pwszfriendlyname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiTarget", "SetFriendlyName", 9usize, |this| ((**(this as *const *const IVdsIscsiTarget_Vtbl)).SetFriendlyName)(this, pwszfriendlyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSharedSecret(this: *mut core::ffi::c_void, ptargetsharedsecret: *const VDS_ISCSI_SHARED_SECRET, pwszinitiatorname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiTarget", "SetSharedSecret", 10usize, |this| ((**(this as *const *const IVdsIscsiTarget_Vtbl)).SetSharedSecret)(this, ptargetsharedsecret, pwszinitiatorname), |result| Some(*result))
+        }
+        unsafe extern "system" fn RememberInitiatorSharedSecret(this: *mut core::ffi::c_void, pwszinitiatorname: windows_core::PCWSTR, pinitiatorsharedsecret: *const VDS_ISCSI_SHARED_SECRET) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiTarget", "RememberInitiatorSharedSecret", 11usize, |this| ((**(this as *const *const IVdsIscsiTarget_Vtbl)).RememberInitiatorSharedSecret)(this, pwszinitiatorname, pinitiatorsharedsecret), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConnectedInitiators(this: *mut core::ffi::c_void, pppwszinitiatorlist: *mut *mut windows_core::PWSTR, plnumberofinitiators: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsIscsiTarget", "GetConnectedInitiators", 12usize, |this| ((**(this as *const *const IVdsIscsiTarget_Vtbl)).GetConnectedInitiators)(this, pppwszinitiatorlist, plnumberofinitiators), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetProperties,
+            GetSubSystem,
+            QueryPortalGroups,
+            QueryAssociatedLuns,
+            CreatePortalGroup,
+            Delete,
+            SetFriendlyName,
+            SetSharedSecret,
+            RememberInitiatorSharedSecret,
+            GetConnectedInitiators,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsIscsiTarget as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsLun_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, plunprop: *mut VDS_LUN_PROP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLun", "GetProperties", 3usize, |this| ((**(this as *const *const IVdsLun_Vtbl)).GetProperties)(this, plunprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubSystem(this: *mut core::ffi::c_void, ppsubsystem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLun", "GetSubSystem", 4usize, |this| ((**(this as *const *const IVdsLun_Vtbl)).GetSubSystem)(this, ppsubsystem), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIdentificationData(this: *mut core::ffi::c_void, pluninfo: *mut VDS_LUN_INFORMATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLun", "GetIdentificationData", 5usize, |this| ((**(this as *const *const IVdsLun_Vtbl)).GetIdentificationData)(this, pluninfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryActiveControllers(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLun", "QueryActiveControllers", 6usize, |this| ((**(this as *const *const IVdsLun_Vtbl)).QueryActiveControllers)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Extend(this: *mut core::ffi::c_void, ullnumberofbytestoadd: u64, pdriveidarray: *const windows_core::GUID, lnumberofdrives: i32, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLun", "Extend", 7usize, |this| ((**(this as *const *const IVdsLun_Vtbl)).Extend)(this, ullnumberofbytestoadd, pdriveidarray, lnumberofdrives, ppasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shrink(this: *mut core::ffi::c_void, ullnumberofbytestoremove: u64, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLun", "Shrink", 8usize, |this| ((**(this as *const *const IVdsLun_Vtbl)).Shrink)(this, ullnumberofbytestoremove, ppasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryPlexes(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLun", "QueryPlexes", 9usize, |this| ((**(this as *const *const IVdsLun_Vtbl)).QueryPlexes)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPlex(this: *mut core::ffi::c_void, lunid: windows_core::GUID, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLun", "AddPlex", 10usize, |this| ((**(this as *const *const IVdsLun_Vtbl)).AddPlex)(this, lunid, ppasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePlex(this: *mut core::ffi::c_void, plexid: windows_core::GUID, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLun", "RemovePlex", 11usize, |this| ((**(this as *const *const IVdsLun_Vtbl)).RemovePlex)(this, plexid, ppasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn Recover(this: *mut core::ffi::c_void, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLun", "Recover", 12usize, |this| ((**(this as *const *const IVdsLun_Vtbl)).Recover)(this, ppasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMask(this: *mut core::ffi::c_void, pwszunmaskinglist: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLun", "SetMask", 13usize, |this| ((**(this as *const *const IVdsLun_Vtbl)).SetMask)(this, pwszunmaskinglist), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLun", "Delete", 14usize, |this| ((**(this as *const *const IVdsLun_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AssociateControllers(this: *mut core::ffi::c_void, pactivecontrolleridarray: *const windows_core::GUID, lnumberofactivecontrollers: i32, pinactivecontrolleridarray: *const windows_core::GUID, lnumberofinactivecontrollers: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLun", "AssociateControllers", 15usize, |this| ((**(this as *const *const IVdsLun_Vtbl)).AssociateControllers)(this, pactivecontrolleridarray, lnumberofactivecontrollers, pinactivecontrolleridarray, lnumberofinactivecontrollers), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryHints(this: *mut core::ffi::c_void, phints: *mut VDS_HINTS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLun", "QueryHints", 16usize, |this| ((**(this as *const *const IVdsLun_Vtbl)).QueryHints)(this, phints), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplyHints(this: *mut core::ffi::c_void, phints: *const VDS_HINTS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLun", "ApplyHints", 17usize, |this| ((**(this as *const *const IVdsLun_Vtbl)).ApplyHints)(this, phints), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatus(this: *mut core::ffi::c_void, status: VDS_LUN_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLun", "SetStatus", 18usize, |this| ((**(this as *const *const IVdsLun_Vtbl)).SetStatus)(this, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryMaxLunExtendSize(this: *mut core::ffi::c_void, pdriveidarray: *const windows_core::GUID, lnumberofdrives: i32, pullmaxbytestobeadded: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLun", "QueryMaxLunExtendSize", 19usize, |this| ((**(this as *const *const IVdsLun_Vtbl)).QueryMaxLunExtendSize)(this, pdriveidarray, lnumberofdrives, pullmaxbytestobeadded), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetProperties,
+            GetSubSystem,
+            GetIdentificationData,
+            QueryActiveControllers,
+            Extend,
+            Shrink,
+            QueryPlexes,
+            AddPlex,
+            RemovePlex,
+            Recover,
+            SetMask,
+            Delete,
+            AssociateControllers,
+            QueryHints,
+            ApplyHints,
+            SetStatus,
+            QueryMaxLunExtendSize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsLun as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsLun2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryHints2(this: *mut core::ffi::c_void, phints2: *mut VDS_HINTS2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLun2", "QueryHints2", 3usize, |this| ((**(this as *const *const IVdsLun2_Vtbl)).QueryHints2)(this, phints2), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplyHints2(this: *mut core::ffi::c_void, phints2: *const VDS_HINTS2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLun2", "ApplyHints2", 4usize, |this| ((**(this as *const *const IVdsLun2_Vtbl)).ApplyHints2)(this, phints2), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryHints2, ApplyHints2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsLun2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsLunControllerPorts_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AssociateControllerPorts(this: *mut core::ffi::c_void, pactivecontrollerportidarray: *const windows_core::GUID, lnumberofactivecontrollerports: i32, pinactivecontrollerportidarray: *const windows_core::GUID, lnumberofinactivecontrollerports: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLunControllerPorts", "AssociateControllerPorts", 3usize, |this| ((**(this as *const *const IVdsLunControllerPorts_Vtbl)).AssociateControllerPorts)(this, pactivecontrollerportidarray, lnumberofactivecontrollerports, pinactivecontrollerportidarray, lnumberofinactivecontrollerports), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryActiveControllerPorts(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLunControllerPorts", "QueryActiveControllerPorts", 4usize, |this| ((**(this as *const *const IVdsLunControllerPorts_Vtbl)).QueryActiveControllerPorts)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AssociateControllerPorts, QueryActiveControllerPorts }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsLunControllerPorts as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsLunIscsi_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AssociateTargets(this: *mut core::ffi::c_void, ptargetidarray: *const windows_core::GUID, lnumberoftargets: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLunIscsi", "AssociateTargets", 3usize, |this| ((**(this as *const *const IVdsLunIscsi_Vtbl)).AssociateTargets)(this, ptargetidarray, lnumberoftargets), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryAssociatedTargets(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLunIscsi", "QueryAssociatedTargets", 4usize, |this| ((**(this as *const *const IVdsLunIscsi_Vtbl)).QueryAssociatedTargets)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AssociateTargets, QueryAssociatedTargets }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsLunIscsi as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsLunMpio_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPathInfo(this: *mut core::ffi::c_void, pppaths: *mut *mut VDS_PATH_INFO, plnumberofpaths: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLunMpio", "GetPathInfo", 3usize, |this| ((**(this as *const *const IVdsLunMpio_Vtbl)).GetPathInfo)(this, pppaths, plnumberofpaths), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLoadBalancePolicy(this: *mut core::ffi::c_void, ppolicy: *mut VDS_LOADBALANCE_POLICY_ENUM, pppaths: *mut *mut VDS_PATH_POLICY, plnumberofpaths: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLunMpio", "GetLoadBalancePolicy", 4usize, |this| ((**(this as *const *const IVdsLunMpio_Vtbl)).GetLoadBalancePolicy)(this, ppolicy, pppaths, plnumberofpaths), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLoadBalancePolicy(this: *mut core::ffi::c_void, policy: VDS_LOADBALANCE_POLICY_ENUM, ppaths: *const VDS_PATH_POLICY, lnumberofpaths: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLunMpio", "SetLoadBalancePolicy", 5usize, |this| ((**(this as *const *const IVdsLunMpio_Vtbl)).SetLoadBalancePolicy)(this, policy, ppaths, lnumberofpaths), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSupportedLbPolicies(this: *mut core::ffi::c_void, pullbflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLunMpio", "GetSupportedLbPolicies", 6usize, |this| ((**(this as *const *const IVdsLunMpio_Vtbl)).GetSupportedLbPolicies)(this, pullbflags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPathInfo,
+            GetLoadBalancePolicy,
+            SetLoadBalancePolicy,
+            GetSupportedLbPolicies,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsLunMpio as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsLunNaming_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetFriendlyName(this: *mut core::ffi::c_void, pwszfriendlyname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLunNaming", "SetFriendlyName", 3usize, |this| ((**(this as *const *const IVdsLunNaming_Vtbl)).SetFriendlyName)(this, pwszfriendlyname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetFriendlyName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsLunNaming as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsLunNumber_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLunNumber(this: *mut core::ffi::c_void, pullunnumber: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLunNumber", "GetLunNumber", 3usize, |this| ((**(this as *const *const IVdsLunNumber_Vtbl)).GetLunNumber)(this, pullunnumber), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetLunNumber }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsLunNumber as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsLunPlex_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, pplexprop: *mut VDS_LUN_PLEX_PROP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLunPlex", "GetProperties", 3usize, |this| ((**(this as *const *const IVdsLunPlex_Vtbl)).GetProperties)(this, pplexprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLun(this: *mut core::ffi::c_void, pplun: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLunPlex", "GetLun", 4usize, |this| ((**(this as *const *const IVdsLunPlex_Vtbl)).GetLun)(this, pplun), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryExtents(this: *mut core::ffi::c_void, ppextentarray: *mut *mut VDS_DRIVE_EXTENT, plnumberofextents: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLunPlex", "QueryExtents", 5usize, |this| ((**(this as *const *const IVdsLunPlex_Vtbl)).QueryExtents)(this, ppextentarray, plnumberofextents), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryHints(this: *mut core::ffi::c_void, phints: *mut VDS_HINTS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLunPlex", "QueryHints", 6usize, |this| ((**(this as *const *const IVdsLunPlex_Vtbl)).QueryHints)(this, phints), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplyHints(this: *mut core::ffi::c_void, phints: *const VDS_HINTS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsLunPlex", "ApplyHints", 7usize, |this| ((**(this as *const *const IVdsLunPlex_Vtbl)).ApplyHints)(this, phints), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProperties, GetLun, QueryExtents, QueryHints, ApplyHints }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsLunPlex as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsMaintenance_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartMaintenance(this: *mut core::ffi::c_void, operation: VDS_MAINTENANCE_OPERATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsMaintenance", "StartMaintenance", 3usize, |this| ((**(this as *const *const IVdsMaintenance_Vtbl)).StartMaintenance)(this, operation), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopMaintenance(this: *mut core::ffi::c_void, operation: VDS_MAINTENANCE_OPERATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsMaintenance", "StopMaintenance", 4usize, |this| ((**(this as *const *const IVdsMaintenance_Vtbl)).StopMaintenance)(this, operation), |result| Some(*result))
+        }
+        unsafe extern "system" fn PulseMaintenance(this: *mut core::ffi::c_void, operation: VDS_MAINTENANCE_OPERATION, ulcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsMaintenance", "PulseMaintenance", 5usize, |this| ((**(this as *const *const IVdsMaintenance_Vtbl)).PulseMaintenance)(this, operation, ulcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartMaintenance, StopMaintenance, PulseMaintenance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsMaintenance as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_Vhd")]
+impl windows_core::imp::ProxyVtable for IVdsOpenVDisk_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_Vhd")]
+        unsafe extern "system" fn Attach(this: *mut core::ffi::c_void, pstringsecuritydescriptor: windows_core::PCWSTR, flags: super::Vhd::ATTACH_VIRTUAL_DISK_FLAG, providerspecificflags: u32, timeoutinms: u32, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsOpenVDisk", "Attach", 3usize, |this| ((**(this as *const *const IVdsOpenVDisk_Vtbl)).Attach)(this, pstringsecuritydescriptor, flags, providerspecificflags, timeoutinms, ppasync), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_Vhd")]
+        unsafe extern "system" fn Detach(this: *mut core::ffi::c_void, flags: super::Vhd::DETACH_VIRTUAL_DISK_FLAG, providerspecificflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsOpenVDisk", "Detach", 4usize, |this| ((**(this as *const *const IVdsOpenVDisk_Vtbl)).Detach)(this, flags, providerspecificflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_Vhd")]
+        unsafe extern "system" fn DetachAndDelete(this: *mut core::ffi::c_void, flags: super::Vhd::DETACH_VIRTUAL_DISK_FLAG, providerspecificflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsOpenVDisk", "DetachAndDelete", 5usize, |this| ((**(this as *const *const IVdsOpenVDisk_Vtbl)).DetachAndDelete)(this, flags, providerspecificflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_Vhd")]
+        unsafe extern "system" fn Compact(this: *mut core::ffi::c_void, flags: super::Vhd::COMPACT_VIRTUAL_DISK_FLAG, reserved: u32, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsOpenVDisk", "Compact", 6usize, |this| ((**(this as *const *const IVdsOpenVDisk_Vtbl)).Compact)(this, flags, reserved, ppasync), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_Vhd")]
+        unsafe extern "system" fn Merge(this: *mut core::ffi::c_void, flags: super::Vhd::MERGE_VIRTUAL_DISK_FLAG, mergedepth: u32, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsOpenVDisk", "Merge", 7usize, |this| ((**(this as *const *const IVdsOpenVDisk_Vtbl)).Merge)(this, flags, mergedepth, ppasync), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_Vhd")]
+        unsafe extern "system" fn Expand(this: *mut core::ffi::c_void, flags: super::Vhd::EXPAND_VIRTUAL_DISK_FLAG, newsize: u64, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsOpenVDisk", "Expand", 8usize, |this| ((**(this as *const *const IVdsOpenVDisk_Vtbl)).Expand)(this, flags, newsize, ppasync), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_Vhd")]
+            Attach,
+            #[cfg(not(feature = "Win32_Storage_Vhd"))]
+            Attach: 0,
+            #[cfg(feature = "Win32_Storage_Vhd")]
+            Detach,
+            #[cfg(not(feature = "Win32_Storage_Vhd"))]
+            Detach: 0,
+            #[cfg(feature = "Win32_Storage_Vhd")]
+            DetachAndDelete,
+            #[cfg(not(feature = "Win32_Storage_Vhd"))]
+            DetachAndDelete: 0,
+            #[cfg(feature = "Win32_Storage_Vhd")]
+            Compact,
+            #[cfg(not(feature = "Win32_Storage_Vhd"))]
+            Compact: 0,
+            #[cfg(feature = "Win32_Storage_Vhd")]
+            Merge,
+            #[cfg(not(feature = "Win32_Storage_Vhd"))]
+            Merge: 0,
+            #[cfg(feature = "Win32_Storage_Vhd")]
+            Expand,
+            #[cfg(not(feature = "Win32_Storage_Vhd"))]
+            Expand: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsOpenVDisk as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsPack_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, ppackprop: *mut VDS_PACK_PROP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsPack", "GetProperties", 3usize, |this| ((**(this as *const *const IVdsPack_Vtbl)).GetProperties)(this, ppackprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProvider(this: *mut core::ffi::c_void, ppprovider: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsPack", "GetProvider", 4usize, |this| ((**(this as *const *const IVdsPack_Vtbl)).GetProvider)(this, ppprovider), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryVolumes(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsPack", "QueryVolumes", 5usize, |this| ((**(this as *const *const IVdsPack_Vtbl)).QueryVolumes)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryDisks(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsPack", "QueryDisks", 6usize, |this| ((**(this as *const *const IVdsPack_Vtbl)).QueryDisks)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVolume(this: *mut core::ffi::c_void, r#type: VDS_VOLUME_TYPE, pinputdiskarray: *const VDS_INPUT_DISK, lnumberofdisks: i32, ulstripesize: u32, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsPack", "CreateVolume", 7usize, |this| ((**(this as *const *const IVdsPack_Vtbl)).CreateVolume)(this, r#type, pinputdiskarray, lnumberofdisks, ulstripesize, ppasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDisk(this: *mut core::ffi::c_void, diskid: windows_core::GUID, partitionstyle: VDS_PARTITION_STYLE, bashotspare: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsPack", "AddDisk", 8usize, |this| ((**(this as *const *const IVdsPack_Vtbl)).AddDisk)(this, diskid, partitionstyle, bashotspare), |result| Some(*result))
+        }
+        unsafe extern "system" fn MigrateDisks(this: *mut core::ffi::c_void, pdiskarray: *const windows_core::GUID, lnumberofdisks: i32, targetpack: windows_core::GUID, bforce: super::super::Foundation::BOOL, bqueryonly: super::super::Foundation::BOOL, presults: *mut windows_core::HRESULT, pbrebootneeded: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsPack", "MigrateDisks", 9usize, |this| ((**(this as *const *const IVdsPack_Vtbl)).MigrateDisks)(this, pdiskarray, lnumberofdisks, targetpack, bforce, bqueryonly, presults, pbrebootneeded), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReplaceDisk(this: *mut core::ffi::c_void, olddiskid: windows_core::GUID, newdiskid: windows_core::GUID, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsPack", "ReplaceDisk", 10usize, |this| ((**(this as *const *const IVdsPack_Vtbl)).ReplaceDisk)(this, olddiskid, newdiskid, ppasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMissingDisk(this: *mut core::ffi::c_void, diskid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsPack", "RemoveMissingDisk", 11usize, |this| ((**(this as *const *const IVdsPack_Vtbl)).RemoveMissingDisk)(this, diskid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Recover(this: *mut core::ffi::c_void, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsPack", "Recover", 12usize, |this| ((**(this as *const *const IVdsPack_Vtbl)).Recover)(this, ppasync), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetProperties,
+            GetProvider,
+            QueryVolumes,
+            QueryDisks,
+            CreateVolume,
+            AddDisk,
+            MigrateDisks,
+            ReplaceDisk,
+            RemoveMissingDisk,
+            Recover,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsPack as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsPack2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateVolume2(this: *mut core::ffi::c_void, r#type: VDS_VOLUME_TYPE, pinputdiskarray: *const VDS_INPUT_DISK, lnumberofdisks: i32, ulstripesize: u32, ulalign: u32, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsPack2", "CreateVolume2", 3usize, |this| ((**(this as *const *const IVdsPack2_Vtbl)).CreateVolume2)(this, r#type, pinputdiskarray, lnumberofdisks, ulstripesize, ulalign, ppasync), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateVolume2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsPack2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, pproviderprop: *mut VDS_PROVIDER_PROP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsProvider", "GetProperties", 3usize, |this| ((**(this as *const *const IVdsProvider_Vtbl)).GetProperties)(this, pproviderprop), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProperties }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsProviderPrivate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetObject(this: *mut core::ffi::c_void, objectid: windows_core::GUID, r#type: VDS_OBJECT_TYPE, ppobjectunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsProviderPrivate", "GetObject", 3usize, |this| ((**(this as *const *const IVdsProviderPrivate_Vtbl)).GetObject)(this, objectid, r#type, ppobjectunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnLoad(this: *mut core::ffi::c_void, pwszmachinename: windows_core::PCWSTR, pcallbackobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsProviderPrivate", "OnLoad", 4usize, |this| ((**(this as *const *const IVdsProviderPrivate_Vtbl)).OnLoad)(this, pwszmachinename, pcallbackobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnUnload(this: *mut core::ffi::c_void, bforceunload: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsProviderPrivate", "OnUnload", 5usize, |this| ((**(this as *const *const IVdsProviderPrivate_Vtbl)).OnUnload)(this, bforceunload), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetObject, OnLoad, OnUnload }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsProviderPrivate as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsProviderSupport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetVersionSupport(this: *mut core::ffi::c_void, ulversionsupport: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsProviderSupport", "GetVersionSupport", 3usize, |this| ((**(this as *const *const IVdsProviderSupport_Vtbl)).GetVersionSupport)(this, ulversionsupport), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetVersionSupport }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsProviderSupport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsRemovable_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryMedia(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsRemovable", "QueryMedia", 3usize, |this| ((**(this as *const *const IVdsRemovable_Vtbl)).QueryMedia)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Eject(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsRemovable", "Eject", 4usize, |this| ((**(this as *const *const IVdsRemovable_Vtbl)).Eject)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryMedia, Eject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsRemovable as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsServiceReady(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsService", "IsServiceReady", 3usize, |this| ((**(this as *const *const IVdsService_Vtbl)).IsServiceReady)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForServiceReady(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsService", "WaitForServiceReady", 4usize, |this| ((**(this as *const *const IVdsService_Vtbl)).WaitForServiceReady)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, pserviceprop: *mut VDS_SERVICE_PROP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsService", "GetProperties", 5usize, |this| ((**(this as *const *const IVdsService_Vtbl)).GetProperties)(this, pserviceprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryProviders(this: *mut core::ffi::c_void, masks: u32, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsService", "QueryProviders", 6usize, |this| ((**(this as *const *const IVdsService_Vtbl)).QueryProviders)(this, masks, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryMaskedDisks(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsService", "QueryMaskedDisks", 7usize, |this| ((**(this as *const *const IVdsService_Vtbl)).QueryMaskedDisks)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryUnallocatedDisks(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsService", "QueryUnallocatedDisks", 8usize, |this| ((**(this as *const *const IVdsService_Vtbl)).QueryUnallocatedDisks)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObject(this: *mut core::ffi::c_void, objectid: windows_core::GUID, r#type: VDS_OBJECT_TYPE, ppobjectunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsService", "GetObject", 9usize, |this| ((**(this as *const *const IVdsService_Vtbl)).GetObject)(this, objectid, r#type, ppobjectunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryDriveLetters(this: *mut core::ffi::c_void, wcfirstletter: u16, count: u32, pdriveletterproparray: *mut VDS_DRIVE_LETTER_PROP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsService", "QueryDriveLetters", 10usize, |this| ((**(this as *const *const IVdsService_Vtbl)).QueryDriveLetters)(this, wcfirstletter, count, pdriveletterproparray), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryFileSystemTypes(this: *mut core::ffi::c_void, ppfilesystemtypeprops: *mut *mut VDS_FILE_SYSTEM_TYPE_PROP, plnumberoffilesystems: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsService", "QueryFileSystemTypes", 11usize, |this| ((**(this as *const *const IVdsService_Vtbl)).QueryFileSystemTypes)(this, ppfilesystemtypeprops, plnumberoffilesystems), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reenumerate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsService", "Reenumerate", 12usize, |this| ((**(this as *const *const IVdsService_Vtbl)).Reenumerate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsService", "Refresh", 13usize, |this| ((**(this as *const *const IVdsService_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CleanupObsoleteMountPoints(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsService", "CleanupObsoleteMountPoints", 14usize, |this| ((**(this as *const *const IVdsService_Vtbl)).CleanupObsoleteMountPoints)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Advise(this: *mut core::ffi::c_void, psink: *mut core::ffi::c_void, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsService", "Advise", 15usize, |this| ((**(this as *const *const IVdsService_Vtbl)).Advise)(this, psink, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unadvise(this: *mut core::ffi::c_void, dwcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsService", "Unadvise", 16usize, |this| ((**(this as *const *const IVdsService_Vtbl)).Unadvise)(this, dwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reboot(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsService", "Reboot", 17usize, |this| ((**(this as *const *const IVdsService_Vtbl)).Reboot)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFlags(this: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsService", "SetFlags", 18usize, |this| ((**(this as *const *const IVdsService_Vtbl)).SetFlags)(this, ulflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearFlags(this: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsService", "ClearFlags", 19usize, |this| ((**(this as *const *const IVdsService_Vtbl)).ClearFlags)(this, ulflags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsServiceReady,
+            WaitForServiceReady,
+            GetProperties,
+            QueryProviders,
+            QueryMaskedDisks,
+            QueryUnallocatedDisks,
+            GetObject,
+            QueryDriveLetters,
+            QueryFileSystemTypes,
+            Reenumerate,
+            Refresh,
+            CleanupObsoleteMountPoints,
+            Advise,
+            Unadvise,
+            Reboot,
+            SetFlags,
+            ClearFlags,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsService as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsServiceHba_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryHbaPorts(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsServiceHba", "QueryHbaPorts", 3usize, |this| ((**(this as *const *const IVdsServiceHba_Vtbl)).QueryHbaPorts)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryHbaPorts }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsServiceHba as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsServiceInitialization_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pwszmachinename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsServiceInitialization", "Initialize", 3usize, |this| ((**(this as *const *const IVdsServiceInitialization_Vtbl)).Initialize)(this, pwszmachinename), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsServiceInitialization as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsServiceIscsi_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInitiatorName(this: *mut core::ffi::c_void, ppwsziscsiname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsServiceIscsi", "GetInitiatorName", 3usize, |this| ((**(this as *const *const IVdsServiceIscsi_Vtbl)).GetInitiatorName)(this, ppwsziscsiname), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryInitiatorAdapters(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsServiceIscsi", "QueryInitiatorAdapters", 4usize, |this| ((**(this as *const *const IVdsServiceIscsi_Vtbl)).QueryInitiatorAdapters)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIpsecGroupPresharedKey(this: *mut core::ffi::c_void, pipseckey: *const VDS_ISCSI_IPSEC_KEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsServiceIscsi", "SetIpsecGroupPresharedKey", 5usize, |this| ((**(this as *const *const IVdsServiceIscsi_Vtbl)).SetIpsecGroupPresharedKey)(this, pipseckey), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllIpsecTunnelAddresses(this: *mut core::ffi::c_void, ptunneladdress: *const VDS_IPADDRESS, pdestinationaddress: *const VDS_IPADDRESS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsServiceIscsi", "SetAllIpsecTunnelAddresses", 6usize, |this| ((**(this as *const *const IVdsServiceIscsi_Vtbl)).SetAllIpsecTunnelAddresses)(this, ptunneladdress, pdestinationaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllIpsecSecurity(this: *mut core::ffi::c_void, targetportalid: windows_core::GUID, ullsecurityflags: u64, pipseckey: *const VDS_ISCSI_IPSEC_KEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsServiceIscsi", "SetAllIpsecSecurity", 7usize, |this| ((**(this as *const *const IVdsServiceIscsi_Vtbl)).SetAllIpsecSecurity)(this, targetportalid, ullsecurityflags, pipseckey), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInitiatorSharedSecret(this: *mut core::ffi::c_void, pinitiatorsharedsecret: *const VDS_ISCSI_SHARED_SECRET, targetid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsServiceIscsi", "SetInitiatorSharedSecret", 8usize, |this| ((**(this as *const *const IVdsServiceIscsi_Vtbl)).SetInitiatorSharedSecret)(this, pinitiatorsharedsecret, targetid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RememberTargetSharedSecret(this: *mut core::ffi::c_void, targetid: windows_core::GUID, ptargetsharedsecret: *const VDS_ISCSI_SHARED_SECRET) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsServiceIscsi", "RememberTargetSharedSecret", 9usize, |this| ((**(this as *const *const IVdsServiceIscsi_Vtbl)).RememberTargetSharedSecret)(this, targetid, ptargetsharedsecret), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetInitiatorName,
+            QueryInitiatorAdapters,
+            SetIpsecGroupPresharedKey,
+            SetAllIpsecTunnelAddresses,
+            SetAllIpsecSecurity,
+            SetInitiatorSharedSecret,
+            RememberTargetSharedSecret,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsServiceIscsi as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsServiceLoader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LoadService(this: *mut core::ffi::c_void, pwszmachinename: windows_core::PCWSTR, ppservice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsServiceLoader", "LoadService", 3usize, |this| ((**(this as *const *const IVdsServiceLoader_Vtbl)).LoadService)(this, pwszmachinename, ppservice), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LoadService }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsServiceLoader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsServiceSAN_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSANPolicy(this: *mut core::ffi::c_void, psanpolicy: *mut VDS_SAN_POLICY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsServiceSAN", "GetSANPolicy", 3usize, |this| ((**(this as *const *const IVdsServiceSAN_Vtbl)).GetSANPolicy)(this, psanpolicy), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSANPolicy(this: *mut core::ffi::c_void, sanpolicy: VDS_SAN_POLICY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsServiceSAN", "SetSANPolicy", 4usize, |this| ((**(this as *const *const IVdsServiceSAN_Vtbl)).SetSANPolicy)(this, sanpolicy), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSANPolicy, SetSANPolicy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsServiceSAN as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsServiceSw_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDiskObject(this: *mut core::ffi::c_void, pwszdeviceid: windows_core::PCWSTR, ppdiskunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsServiceSw", "GetDiskObject", 3usize, |this| ((**(this as *const *const IVdsServiceSw_Vtbl)).GetDiskObject)(this, pwszdeviceid, ppdiskunk), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDiskObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsServiceSw as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsServiceUninstallDisk_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDiskIdFromLunInfo(this: *mut core::ffi::c_void, pluninfo: *const VDS_LUN_INFORMATION, pdiskid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsServiceUninstallDisk", "GetDiskIdFromLunInfo", 3usize, |this| ((**(this as *const *const IVdsServiceUninstallDisk_Vtbl)).GetDiskIdFromLunInfo)(this, pluninfo, pdiskid), |result| Some(*result))
+        }
+        unsafe extern "system" fn UninstallDisks(this: *mut core::ffi::c_void, pdiskidarray: *const windows_core::GUID, ulcount: u32, bforce: super::super::Foundation::BOOLEAN, pbreboot: *mut u8, presults: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsServiceUninstallDisk", "UninstallDisks", 4usize, |this| ((**(this as *const *const IVdsServiceUninstallDisk_Vtbl)).UninstallDisks)(this, pdiskidarray, ulcount, bforce, pbreboot, presults), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDiskIdFromLunInfo, UninstallDisks }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsServiceUninstallDisk as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsStoragePool_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProvider(this: *mut core::ffi::c_void, ppprovider: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsStoragePool", "GetProvider", 3usize, |this| ((**(this as *const *const IVdsStoragePool_Vtbl)).GetProvider)(this, ppprovider), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, pstoragepoolprop: *mut VDS_STORAGE_POOL_PROP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsStoragePool", "GetProperties", 4usize, |this| ((**(this as *const *const IVdsStoragePool_Vtbl)).GetProperties)(this, pstoragepoolprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributes(this: *mut core::ffi::c_void, pstoragepoolattributes: *mut VDS_POOL_ATTRIBUTES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsStoragePool", "GetAttributes", 5usize, |this| ((**(this as *const *const IVdsStoragePool_Vtbl)).GetAttributes)(this, pstoragepoolattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryDriveExtents(this: *mut core::ffi::c_void, ppextentarray: *mut *mut VDS_STORAGE_POOL_DRIVE_EXTENT, plnumberofextents: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsStoragePool", "QueryDriveExtents", 6usize, |this| ((**(this as *const *const IVdsStoragePool_Vtbl)).QueryDriveExtents)(this, ppextentarray, plnumberofextents), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryAllocatedLuns(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsStoragePool", "QueryAllocatedLuns", 7usize, |this| ((**(this as *const *const IVdsStoragePool_Vtbl)).QueryAllocatedLuns)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryAllocatedStoragePools(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsStoragePool", "QueryAllocatedStoragePools", 8usize, |this| ((**(this as *const *const IVdsStoragePool_Vtbl)).QueryAllocatedStoragePools)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetProvider,
+            GetProperties,
+            GetAttributes,
+            QueryDriveExtents,
+            QueryAllocatedLuns,
+            QueryAllocatedStoragePools,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsStoragePool as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsSubSystem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, psubsystemprop: *mut VDS_SUB_SYSTEM_PROP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsSubSystem", "GetProperties", 3usize, |this| ((**(this as *const *const IVdsSubSystem_Vtbl)).GetProperties)(this, psubsystemprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProvider(this: *mut core::ffi::c_void, ppprovider: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsSubSystem", "GetProvider", 4usize, |this| ((**(this as *const *const IVdsSubSystem_Vtbl)).GetProvider)(this, ppprovider), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryControllers(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsSubSystem", "QueryControllers", 5usize, |this| ((**(this as *const *const IVdsSubSystem_Vtbl)).QueryControllers)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryLuns(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsSubSystem", "QueryLuns", 6usize, |this| ((**(this as *const *const IVdsSubSystem_Vtbl)).QueryLuns)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryDrives(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsSubSystem", "QueryDrives", 7usize, |this| ((**(this as *const *const IVdsSubSystem_Vtbl)).QueryDrives)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDrive(this: *mut core::ffi::c_void, sbusnumber: i16, sslotnumber: i16, ppdrive: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsSubSystem", "GetDrive", 8usize, |this| ((**(this as *const *const IVdsSubSystem_Vtbl)).GetDrive)(this, sbusnumber, sslotnumber, ppdrive), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reenumerate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsSubSystem", "Reenumerate", 9usize, |this| ((**(this as *const *const IVdsSubSystem_Vtbl)).Reenumerate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetControllerStatus(this: *mut core::ffi::c_void, ponlinecontrolleridarray: *const windows_core::GUID, lnumberofonlinecontrollers: i32, pofflinecontrolleridarray: *const windows_core::GUID, lnumberofofflinecontrollers: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsSubSystem", "SetControllerStatus", 10usize, |this| ((**(this as *const *const IVdsSubSystem_Vtbl)).SetControllerStatus)(this, ponlinecontrolleridarray, lnumberofonlinecontrollers, pofflinecontrolleridarray, lnumberofofflinecontrollers), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateLun(this: *mut core::ffi::c_void, r#type: VDS_LUN_TYPE, ullsizeinbytes: u64, pdriveidarray: *const windows_core::GUID, lnumberofdrives: i32, pwszunmaskinglist: windows_core::PCWSTR, phints: *const VDS_HINTS, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsSubSystem", "CreateLun", 11usize, |this| ((**(this as *const *const IVdsSubSystem_Vtbl)).CreateLun)(this, r#type, ullsizeinbytes, pdriveidarray, lnumberofdrives, pwszunmaskinglist, phints, ppasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReplaceDrive(this: *mut core::ffi::c_void, drivetobereplaced: windows_core::GUID, replacementdrive: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsSubSystem", "ReplaceDrive", 12usize, |this| ((**(this as *const *const IVdsSubSystem_Vtbl)).ReplaceDrive)(this, drivetobereplaced, replacementdrive), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatus(this: *mut core::ffi::c_void, status: VDS_SUB_SYSTEM_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsSubSystem", "SetStatus", 13usize, |this| ((**(this as *const *const IVdsSubSystem_Vtbl)).SetStatus)(this, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryMaxLunCreateSize(this: *mut core::ffi::c_void, r#type: VDS_LUN_TYPE, pdriveidarray: *const windows_core::GUID, lnumberofdrives: i32, phints: *const VDS_HINTS, pullmaxlunsize: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsSubSystem", "QueryMaxLunCreateSize", 14usize, |this| ((**(this as *const *const IVdsSubSystem_Vtbl)).QueryMaxLunCreateSize)(this, r#type, pdriveidarray, lnumberofdrives, phints, pullmaxlunsize), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetProperties,
+            GetProvider,
+            QueryControllers,
+            QueryLuns,
+            QueryDrives,
+            GetDrive,
+            Reenumerate,
+            SetControllerStatus,
+            CreateLun,
+            ReplaceDrive,
+            SetStatus,
+            QueryMaxLunCreateSize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsSubSystem as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsSubSystem2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperties2(this: *mut core::ffi::c_void, psubsystemprop2: *mut VDS_SUB_SYSTEM_PROP2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsSubSystem2", "GetProperties2", 3usize, |this| ((**(this as *const *const IVdsSubSystem2_Vtbl)).GetProperties2)(this, psubsystemprop2), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDrive2(this: *mut core::ffi::c_void, sbusnumber: i16, sslotnumber: i16, ulenclosurenumber: u32, ppdrive: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsSubSystem2", "GetDrive2", 4usize, |this| ((**(this as *const *const IVdsSubSystem2_Vtbl)).GetDrive2)(this, sbusnumber, sslotnumber, ulenclosurenumber, ppdrive), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateLun2(this: *mut core::ffi::c_void, r#type: VDS_LUN_TYPE, ullsizeinbytes: u64, pdriveidarray: *const windows_core::GUID, lnumberofdrives: i32, pwszunmaskinglist: windows_core::PCWSTR, phints2: *const VDS_HINTS2, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsSubSystem2", "CreateLun2", 5usize, |this| ((**(this as *const *const IVdsSubSystem2_Vtbl)).CreateLun2)(this, r#type, ullsizeinbytes, pdriveidarray, lnumberofdrives, pwszunmaskinglist, phints2, ppasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryMaxLunCreateSize2(this: *mut core::ffi::c_void, r#type: VDS_LUN_TYPE, pdriveidarray: *const windows_core::GUID, lnumberofdrives: i32, phints2: *const VDS_HINTS2, pullmaxlunsize: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsSubSystem2", "QueryMaxLunCreateSize2", 6usize, |this| ((**(this as *const *const IVdsSubSystem2_Vtbl)).QueryMaxLunCreateSize2)(this, r#type, pdriveidarray, lnumberofdrives, phints2, pullmaxlunsize), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProperties2, GetDrive2, CreateLun2, QueryMaxLunCreateSize2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsSubSystem2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsSubSystemImportTarget_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetImportTarget(this: *mut core::ffi::c_void, ppwsziscsiname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsSubSystemImportTarget", "GetImportTarget", 3usize, |this| ((**(this as *const *const IVdsSubSystemImportTarget_Vtbl)).GetImportTarget)(this, ppwsziscsiname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetImportTarget(this: *mut core::ffi::c_void, pwsziscsiname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsSubSystemImportTarget", "SetImportTarget", 4usize, |this| ((**(this as *const *const IVdsSubSystemImportTarget_Vtbl)).SetImportTarget)(this, pwsziscsiname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetImportTarget, SetImportTarget }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsSubSystemImportTarget as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsSubSystemInterconnect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSupportedInterconnects(this: *mut core::ffi::c_void, pulsupportedinterconnectsflag: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsSubSystemInterconnect", "GetSupportedInterconnects", 3usize, |this| ((**(this as *const *const IVdsSubSystemInterconnect_Vtbl)).GetSupportedInterconnects)(this, pulsupportedinterconnectsflag), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSupportedInterconnects }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsSubSystemInterconnect as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsSubSystemIscsi_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryTargets(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsSubSystemIscsi", "QueryTargets", 3usize, |this| ((**(this as *const *const IVdsSubSystemIscsi_Vtbl)).QueryTargets)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryPortals(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsSubSystemIscsi", "QueryPortals", 4usize, |this| ((**(this as *const *const IVdsSubSystemIscsi_Vtbl)).QueryPortals)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTarget(this: *mut core::ffi::c_void, pwsziscsiname: windows_core::PCWSTR, pwszfriendlyname: windows_core::PCWSTR, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsSubSystemIscsi", "CreateTarget", 5usize, |this| ((**(this as *const *const IVdsSubSystemIscsi_Vtbl)).CreateTarget)(this, pwsziscsiname, pwszfriendlyname, ppasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIpsecGroupPresharedKey(this: *mut core::ffi::c_void, pipseckey: *const VDS_ISCSI_IPSEC_KEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsSubSystemIscsi", "SetIpsecGroupPresharedKey", 6usize, |this| ((**(this as *const *const IVdsSubSystemIscsi_Vtbl)).SetIpsecGroupPresharedKey)(this, pipseckey), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            QueryTargets,
+            QueryPortals,
+            CreateTarget,
+            SetIpsecGroupPresharedKey,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsSubSystemIscsi as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsSubSystemNaming_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetFriendlyName(this: *mut core::ffi::c_void, pwszfriendlyname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsSubSystemNaming", "SetFriendlyName", 3usize, |this| ((**(this as *const *const IVdsSubSystemNaming_Vtbl)).SetFriendlyName)(this, pwszfriendlyname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetFriendlyName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsSubSystemNaming as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsSwProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryPacks(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsSwProvider", "QueryPacks", 3usize, |this| ((**(this as *const *const IVdsSwProvider_Vtbl)).QueryPacks)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePack(this: *mut core::ffi::c_void, pppack: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsSwProvider", "CreatePack", 4usize, |this| ((**(this as *const *const IVdsSwProvider_Vtbl)).CreatePack)(this, pppack), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryPacks, CreatePack }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsSwProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_Vhd")]
+impl windows_core::imp::ProxyVtable for IVdsVDisk_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_Vhd")]
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, accessmask: super::Vhd::VIRTUAL_DISK_ACCESS_MASK, flags: super::Vhd::OPEN_VIRTUAL_DISK_FLAG, readwritedepth: u32, ppopenvdisk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVDisk", "Open", 3usize, |this| ((**(this as *const *const IVdsVDisk_Vtbl)).Open)(this, accessmask, flags, readwritedepth, ppopenvdisk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_Vhd")]
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, pdiskproperties: *mut VDS_VDISK_PROPERTIES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVDisk", "GetProperties", 4usize, |this| ((**(this as *const *const IVdsVDisk_Vtbl)).GetProperties)(this, pdiskproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHostVolume(this: *mut core::ffi::c_void, ppvolume: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVDisk", "GetHostVolume", 5usize, |this| ((**(this as *const *const IVdsVDisk_Vtbl)).GetHostVolume)(this, ppvolume), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceName(this: *mut core::ffi::c_void, ppdevicename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVDisk", "GetDeviceName", 6usize, |this| ((**(this as *const *const IVdsVDisk_Vtbl)).GetDeviceName)(this, ppdevicename), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_Vhd")]
+            Open,
+            #[cfg(not(feature = "Win32_Storage_Vhd"))]
+            Open: 0,
+            #[cfg(feature = "Win32_Storage_Vhd")]
+            GetProperties,
+            #[cfg(not(feature = "Win32_Storage_Vhd"))]
+            GetProperties: 0,
+            GetHostVolume,
+            GetDeviceName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsVDisk as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_Vhd")]
+impl windows_core::imp::ProxyVtable for IVdsVdProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryVDisks(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVdProvider", "QueryVDisks", 3usize, |this| ((**(this as *const *const IVdsVdProvider_Vtbl)).QueryVDisks)(this, ppenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_Vhd")]
+        unsafe extern "system" fn CreateVDisk(this: *mut core::ffi::c_void, virtualdevicetype: *const super::Vhd::VIRTUAL_STORAGE_TYPE, ppath: windows_core::PCWSTR, pstringsecuritydescriptor: windows_core::PCWSTR, flags: super::Vhd::CREATE_VIRTUAL_DISK_FLAG, providerspecificflags: u32, reserved: u32, pcreatediskparameters: *const VDS_CREATE_VDISK_PARAMETERS, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVdProvider", "CreateVDisk", 4usize, |this| ((**(this as *const *const IVdsVdProvider_Vtbl)).CreateVDisk)(this, virtualdevicetype, ppath, pstringsecuritydescriptor, flags, providerspecificflags, reserved, pcreatediskparameters, ppasync), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_Vhd")]
+        unsafe extern "system" fn AddVDisk(this: *mut core::ffi::c_void, virtualdevicetype: *const super::Vhd::VIRTUAL_STORAGE_TYPE, ppath: windows_core::PCWSTR, ppvdisk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVdProvider", "AddVDisk", 5usize, |this| ((**(this as *const *const IVdsVdProvider_Vtbl)).AddVDisk)(this, virtualdevicetype, ppath, ppvdisk), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDiskFromVDisk(this: *mut core::ffi::c_void, pvdisk: *mut core::ffi::c_void, ppdisk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVdProvider", "GetDiskFromVDisk", 6usize, |this| ((**(this as *const *const IVdsVdProvider_Vtbl)).GetDiskFromVDisk)(this, pvdisk, ppdisk), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVDiskFromDisk(this: *mut core::ffi::c_void, pdisk: *mut core::ffi::c_void, ppvdisk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVdProvider", "GetVDiskFromDisk", 7usize, |this| ((**(this as *const *const IVdsVdProvider_Vtbl)).GetVDiskFromDisk)(this, pdisk, ppvdisk), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            QueryVDisks,
+            #[cfg(feature = "Win32_Storage_Vhd")]
+            CreateVDisk,
+            #[cfg(not(feature = "Win32_Storage_Vhd"))]
+            CreateVDisk: 0,
+            #[cfg(feature = "Win32_Storage_Vhd")]
+            AddVDisk,
+            #[cfg(not(feature = "Win32_Storage_Vhd"))]
+            AddVDisk: 0,
+            GetDiskFromVDisk,
+            GetVDiskFromDisk,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsVdProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsVolume_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, pvolumeproperties: *mut VDS_VOLUME_PROP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolume", "GetProperties", 3usize, |this| ((**(this as *const *const IVdsVolume_Vtbl)).GetProperties)(this, pvolumeproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPack(this: *mut core::ffi::c_void, pppack: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolume", "GetPack", 4usize, |this| ((**(this as *const *const IVdsVolume_Vtbl)).GetPack)(this, pppack), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryPlexes(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolume", "QueryPlexes", 5usize, |this| ((**(this as *const *const IVdsVolume_Vtbl)).QueryPlexes)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Extend(this: *mut core::ffi::c_void, pinputdiskarray: *const VDS_INPUT_DISK, lnumberofdisks: i32, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolume", "Extend", 6usize, |this| ((**(this as *const *const IVdsVolume_Vtbl)).Extend)(this, pinputdiskarray, lnumberofdisks, ppasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shrink(this: *mut core::ffi::c_void, ullnumberofbytestoremove: u64, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolume", "Shrink", 7usize, |this| ((**(this as *const *const IVdsVolume_Vtbl)).Shrink)(this, ullnumberofbytestoremove, ppasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPlex(this: *mut core::ffi::c_void, volumeid: windows_core::GUID, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolume", "AddPlex", 8usize, |this| ((**(this as *const *const IVdsVolume_Vtbl)).AddPlex)(this, volumeid, ppasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn BreakPlex(this: *mut core::ffi::c_void, plexid: windows_core::GUID, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolume", "BreakPlex", 9usize, |this| ((**(this as *const *const IVdsVolume_Vtbl)).BreakPlex)(this, plexid, ppasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePlex(this: *mut core::ffi::c_void, plexid: windows_core::GUID, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolume", "RemovePlex", 10usize, |this| ((**(this as *const *const IVdsVolume_Vtbl)).RemovePlex)(this, plexid, ppasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, bforce: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolume", "Delete", 11usize, |this| ((**(this as *const *const IVdsVolume_Vtbl)).Delete)(this, bforce), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFlags(this: *mut core::ffi::c_void, ulflags: u32, brevertonclose: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolume", "SetFlags", 12usize, |this| ((**(this as *const *const IVdsVolume_Vtbl)).SetFlags)(this, ulflags, brevertonclose), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearFlags(this: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolume", "ClearFlags", 13usize, |this| ((**(this as *const *const IVdsVolume_Vtbl)).ClearFlags)(this, ulflags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetProperties,
+            GetPack,
+            QueryPlexes,
+            Extend,
+            Shrink,
+            AddPlex,
+            BreakPlex,
+            RemovePlex,
+            Delete,
+            SetFlags,
+            ClearFlags,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsVolume as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsVolume2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperties2(this: *mut core::ffi::c_void, pvolumeproperties: *mut VDS_VOLUME_PROP2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolume2", "GetProperties2", 3usize, |this| ((**(this as *const *const IVdsVolume2_Vtbl)).GetProperties2)(this, pvolumeproperties), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProperties2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsVolume2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsVolumeMF_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFileSystemProperties(this: *mut core::ffi::c_void, pfilesystemprop: *mut VDS_FILE_SYSTEM_PROP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolumeMF", "GetFileSystemProperties", 3usize, |this| ((**(this as *const *const IVdsVolumeMF_Vtbl)).GetFileSystemProperties)(this, pfilesystemprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn Format(this: *mut core::ffi::c_void, r#type: VDS_FILE_SYSTEM_TYPE, pwszlabel: windows_core::PCWSTR, dwunitallocationsize: u32, bforce: super::super::Foundation::BOOL, bquickformat: super::super::Foundation::BOOL, benablecompression: super::super::Foundation::BOOL, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolumeMF", "Format", 4usize, |this| ((**(this as *const *const IVdsVolumeMF_Vtbl)).Format)(this, r#type, pwszlabel, dwunitallocationsize, bforce, bquickformat, benablecompression, ppasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddAccessPath(this: *mut core::ffi::c_void, pwszpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolumeMF", "AddAccessPath", 5usize, |this| ((**(this as *const *const IVdsVolumeMF_Vtbl)).AddAccessPath)(this, pwszpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryAccessPaths(this: *mut core::ffi::c_void, pwszpatharray: *mut *mut windows_core::PWSTR, plnumberofaccesspaths: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolumeMF", "QueryAccessPaths", 6usize, |this| ((**(this as *const *const IVdsVolumeMF_Vtbl)).QueryAccessPaths)(this, pwszpatharray, plnumberofaccesspaths), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryReparsePoints(this: *mut core::ffi::c_void, ppreparsepointprops: *mut *mut VDS_REPARSE_POINT_PROP, plnumberofreparsepointprops: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolumeMF", "QueryReparsePoints", 7usize, |this| ((**(this as *const *const IVdsVolumeMF_Vtbl)).QueryReparsePoints)(this, ppreparsepointprops, plnumberofreparsepointprops), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAccessPath(this: *mut core::ffi::c_void, pwszpath: windows_core::PCWSTR, bforce: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolumeMF", "DeleteAccessPath", 8usize, |this| ((**(this as *const *const IVdsVolumeMF_Vtbl)).DeleteAccessPath)(this, pwszpath, bforce), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mount(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolumeMF", "Mount", 9usize, |this| ((**(this as *const *const IVdsVolumeMF_Vtbl)).Mount)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Dismount(this: *mut core::ffi::c_void, bforce: super::super::Foundation::BOOL, bpermanent: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolumeMF", "Dismount", 10usize, |this| ((**(this as *const *const IVdsVolumeMF_Vtbl)).Dismount)(this, bforce, bpermanent), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFileSystemFlags(this: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolumeMF", "SetFileSystemFlags", 11usize, |this| ((**(this as *const *const IVdsVolumeMF_Vtbl)).SetFileSystemFlags)(this, ulflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearFileSystemFlags(this: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolumeMF", "ClearFileSystemFlags", 12usize, |this| ((**(this as *const *const IVdsVolumeMF_Vtbl)).ClearFileSystemFlags)(this, ulflags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetFileSystemProperties,
+            Format,
+            AddAccessPath,
+            QueryAccessPaths,
+            QueryReparsePoints,
+            DeleteAccessPath,
+            Mount,
+            Dismount,
+            SetFileSystemFlags,
+            ClearFileSystemFlags,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsVolumeMF as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsVolumeMF2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFileSystemTypeName(this: *mut core::ffi::c_void, ppwszfilesystemtypename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolumeMF2", "GetFileSystemTypeName", 3usize, |this| ((**(this as *const *const IVdsVolumeMF2_Vtbl)).GetFileSystemTypeName)(this, ppwszfilesystemtypename), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryFileSystemFormatSupport(this: *mut core::ffi::c_void, ppfilesystemsupportprops: *mut *mut VDS_FILE_SYSTEM_FORMAT_SUPPORT_PROP, plnumberoffilesystems: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolumeMF2", "QueryFileSystemFormatSupport", 4usize, |this| ((**(this as *const *const IVdsVolumeMF2_Vtbl)).QueryFileSystemFormatSupport)(this, ppfilesystemsupportprops, plnumberoffilesystems), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormatEx(this: *mut core::ffi::c_void, pwszfilesystemtypename: windows_core::PCWSTR, usfilesystemrevision: u16, uldesiredunitallocationsize: u32, pwszlabel: windows_core::PCWSTR, bforce: super::super::Foundation::BOOL, bquickformat: super::super::Foundation::BOOL, benablecompression: super::super::Foundation::BOOL, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolumeMF2", "FormatEx", 5usize, |this| ((**(this as *const *const IVdsVolumeMF2_Vtbl)).FormatEx)(this, pwszfilesystemtypename, usfilesystemrevision, uldesiredunitallocationsize, pwszlabel, bforce, bquickformat, benablecompression, ppasync), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFileSystemTypeName, QueryFileSystemFormatSupport, FormatEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsVolumeMF2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsVolumeMF3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryVolumeGuidPathnames(this: *mut core::ffi::c_void, pwszpatharray: *mut *mut windows_core::PWSTR, pulnumberofpaths: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolumeMF3", "QueryVolumeGuidPathnames", 3usize, |this| ((**(this as *const *const IVdsVolumeMF3_Vtbl)).QueryVolumeGuidPathnames)(this, pwszpatharray, pulnumberofpaths), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormatEx2(this: *mut core::ffi::c_void, pwszfilesystemtypename: windows_core::PCWSTR, usfilesystemrevision: u16, uldesiredunitallocationsize: u32, pwszlabel: windows_core::PCWSTR, options: u32, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolumeMF3", "FormatEx2", 4usize, |this| ((**(this as *const *const IVdsVolumeMF3_Vtbl)).FormatEx2)(this, pwszfilesystemtypename, usfilesystemrevision, uldesiredunitallocationsize, pwszlabel, options, ppasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn OfflineVolume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolumeMF3", "OfflineVolume", 5usize, |this| ((**(this as *const *const IVdsVolumeMF3_Vtbl)).OfflineVolume)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryVolumeGuidPathnames, FormatEx2, OfflineVolume }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsVolumeMF3 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsVolumeOnline_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Online(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolumeOnline", "Online", 3usize, |this| ((**(this as *const *const IVdsVolumeOnline_Vtbl)).Online)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Online }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsVolumeOnline as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsVolumePlex_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, pplexproperties: *mut VDS_VOLUME_PLEX_PROP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolumePlex", "GetProperties", 3usize, |this| ((**(this as *const *const IVdsVolumePlex_Vtbl)).GetProperties)(this, pplexproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVolume(this: *mut core::ffi::c_void, ppvolume: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolumePlex", "GetVolume", 4usize, |this| ((**(this as *const *const IVdsVolumePlex_Vtbl)).GetVolume)(this, ppvolume), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryExtents(this: *mut core::ffi::c_void, ppextentarray: *mut *mut VDS_DISK_EXTENT, plnumberofextents: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolumePlex", "QueryExtents", 5usize, |this| ((**(this as *const *const IVdsVolumePlex_Vtbl)).QueryExtents)(this, ppextentarray, plnumberofextents), |result| Some(*result))
+        }
+        unsafe extern "system" fn Repair(this: *mut core::ffi::c_void, pinputdiskarray: *const VDS_INPUT_DISK, lnumberofdisks: i32, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolumePlex", "Repair", 6usize, |this| ((**(this as *const *const IVdsVolumePlex_Vtbl)).Repair)(this, pinputdiskarray, lnumberofdisks, ppasync), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProperties, GetVolume, QueryExtents, Repair }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsVolumePlex as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVdsVolumeShrink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryMaxReclaimableBytes(this: *mut core::ffi::c_void, pullmaxnumberofreclaimablebytes: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolumeShrink", "QueryMaxReclaimableBytes", 3usize, |this| ((**(this as *const *const IVdsVolumeShrink_Vtbl)).QueryMaxReclaimableBytes)(this, pullmaxnumberofreclaimablebytes), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shrink(this: *mut core::ffi::c_void, ulldesirednumberofreclaimablebytes: u64, ullminnumberofreclaimablebytes: u64, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVdsVolumeShrink", "Shrink", 4usize, |this| ((**(this as *const *const IVdsVolumeShrink_Vtbl)).Shrink)(this, ulldesirednumberofreclaimablebytes, ullminnumberofreclaimablebytes, ppasync), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryMaxReclaimableBytes, Shrink }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVdsVolumeShrink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1495,6 +1495,7 @@ pub struct VSS_ALTERNATE_WRITER_STATE(pub i32);
 impl windows_core::TypeKind for VSS_ALTERNATE_WRITER_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_ALTERNATE_WRITER_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_ALTERNATE_WRITER_STATE").field(&self.0).finish()
@@ -1506,6 +1507,7 @@ pub struct VSS_APPLICATION_LEVEL(pub i32);
 impl windows_core::TypeKind for VSS_APPLICATION_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_APPLICATION_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_APPLICATION_LEVEL").field(&self.0).finish()
@@ -1517,6 +1519,7 @@ pub struct VSS_BACKUP_SCHEMA(pub i32);
 impl windows_core::TypeKind for VSS_BACKUP_SCHEMA {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_BACKUP_SCHEMA {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_BACKUP_SCHEMA").field(&self.0).finish()
@@ -1528,6 +1531,7 @@ pub struct VSS_BACKUP_TYPE(pub i32);
 impl windows_core::TypeKind for VSS_BACKUP_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_BACKUP_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_BACKUP_TYPE").field(&self.0).finish()
@@ -1539,6 +1543,7 @@ pub struct VSS_COMPONENT_FLAGS(pub i32);
 impl windows_core::TypeKind for VSS_COMPONENT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_COMPONENT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_COMPONENT_FLAGS").field(&self.0).finish()
@@ -1550,6 +1555,7 @@ pub struct VSS_COMPONENT_TYPE(pub i32);
 impl windows_core::TypeKind for VSS_COMPONENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_COMPONENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_COMPONENT_TYPE").field(&self.0).finish()
@@ -1561,6 +1567,7 @@ pub struct VSS_FILE_RESTORE_STATUS(pub i32);
 impl windows_core::TypeKind for VSS_FILE_RESTORE_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_FILE_RESTORE_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_FILE_RESTORE_STATUS").field(&self.0).finish()
@@ -1572,6 +1579,7 @@ pub struct VSS_FILE_SPEC_BACKUP_TYPE(pub i32);
 impl windows_core::TypeKind for VSS_FILE_SPEC_BACKUP_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_FILE_SPEC_BACKUP_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_FILE_SPEC_BACKUP_TYPE").field(&self.0).finish()
@@ -1583,6 +1591,7 @@ pub struct VSS_HARDWARE_OPTIONS(pub i32);
 impl windows_core::TypeKind for VSS_HARDWARE_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_HARDWARE_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_HARDWARE_OPTIONS").field(&self.0).finish()
@@ -1594,6 +1603,7 @@ pub struct VSS_MGMT_OBJECT_TYPE(pub i32);
 impl windows_core::TypeKind for VSS_MGMT_OBJECT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_MGMT_OBJECT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_MGMT_OBJECT_TYPE").field(&self.0).finish()
@@ -1605,6 +1615,7 @@ pub struct VSS_OBJECT_TYPE(pub i32);
 impl windows_core::TypeKind for VSS_OBJECT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_OBJECT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_OBJECT_TYPE").field(&self.0).finish()
@@ -1616,6 +1627,7 @@ pub struct VSS_PROTECTION_FAULT(pub i32);
 impl windows_core::TypeKind for VSS_PROTECTION_FAULT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_PROTECTION_FAULT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_PROTECTION_FAULT").field(&self.0).finish()
@@ -1627,6 +1639,7 @@ pub struct VSS_PROTECTION_LEVEL(pub i32);
 impl windows_core::TypeKind for VSS_PROTECTION_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_PROTECTION_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_PROTECTION_LEVEL").field(&self.0).finish()
@@ -1638,6 +1651,7 @@ pub struct VSS_PROVIDER_CAPABILITIES(pub i32);
 impl windows_core::TypeKind for VSS_PROVIDER_CAPABILITIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_PROVIDER_CAPABILITIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_PROVIDER_CAPABILITIES").field(&self.0).finish()
@@ -1649,6 +1663,7 @@ pub struct VSS_PROVIDER_TYPE(pub i32);
 impl windows_core::TypeKind for VSS_PROVIDER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_PROVIDER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_PROVIDER_TYPE").field(&self.0).finish()
@@ -1660,6 +1675,7 @@ pub struct VSS_RECOVERY_OPTIONS(pub i32);
 impl windows_core::TypeKind for VSS_RECOVERY_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_RECOVERY_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_RECOVERY_OPTIONS").field(&self.0).finish()
@@ -1671,6 +1687,7 @@ pub struct VSS_RESTOREMETHOD_ENUM(pub i32);
 impl windows_core::TypeKind for VSS_RESTOREMETHOD_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_RESTOREMETHOD_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_RESTOREMETHOD_ENUM").field(&self.0).finish()
@@ -1682,6 +1699,7 @@ pub struct VSS_RESTORE_TARGET(pub i32);
 impl windows_core::TypeKind for VSS_RESTORE_TARGET {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_RESTORE_TARGET {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_RESTORE_TARGET").field(&self.0).finish()
@@ -1693,6 +1711,7 @@ pub struct VSS_RESTORE_TYPE(pub i32);
 impl windows_core::TypeKind for VSS_RESTORE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_RESTORE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_RESTORE_TYPE").field(&self.0).finish()
@@ -1704,6 +1723,7 @@ pub struct VSS_ROLLFORWARD_TYPE(pub i32);
 impl windows_core::TypeKind for VSS_ROLLFORWARD_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_ROLLFORWARD_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_ROLLFORWARD_TYPE").field(&self.0).finish()
@@ -1715,6 +1735,7 @@ pub struct VSS_SNAPSHOT_COMPATIBILITY(pub i32);
 impl windows_core::TypeKind for VSS_SNAPSHOT_COMPATIBILITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_SNAPSHOT_COMPATIBILITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_SNAPSHOT_COMPATIBILITY").field(&self.0).finish()
@@ -1726,6 +1747,7 @@ pub struct VSS_SNAPSHOT_CONTEXT(pub i32);
 impl windows_core::TypeKind for VSS_SNAPSHOT_CONTEXT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_SNAPSHOT_CONTEXT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_SNAPSHOT_CONTEXT").field(&self.0).finish()
@@ -1737,6 +1759,7 @@ pub struct VSS_SNAPSHOT_PROPERTY_ID(pub i32);
 impl windows_core::TypeKind for VSS_SNAPSHOT_PROPERTY_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_SNAPSHOT_PROPERTY_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_SNAPSHOT_PROPERTY_ID").field(&self.0).finish()
@@ -1748,6 +1771,7 @@ pub struct VSS_SNAPSHOT_STATE(pub i32);
 impl windows_core::TypeKind for VSS_SNAPSHOT_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_SNAPSHOT_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_SNAPSHOT_STATE").field(&self.0).finish()
@@ -1759,6 +1783,7 @@ pub struct VSS_SOURCE_TYPE(pub i32);
 impl windows_core::TypeKind for VSS_SOURCE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_SOURCE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_SOURCE_TYPE").field(&self.0).finish()
@@ -1770,6 +1795,7 @@ pub struct VSS_SUBSCRIBE_MASK(pub i32);
 impl windows_core::TypeKind for VSS_SUBSCRIBE_MASK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_SUBSCRIBE_MASK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_SUBSCRIBE_MASK").field(&self.0).finish()
@@ -1781,6 +1807,7 @@ pub struct VSS_USAGE_TYPE(pub i32);
 impl windows_core::TypeKind for VSS_USAGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_USAGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_USAGE_TYPE").field(&self.0).finish()
@@ -1792,6 +1819,7 @@ pub struct VSS_VOLUME_SNAPSHOT_ATTRIBUTES(pub i32);
 impl windows_core::TypeKind for VSS_VOLUME_SNAPSHOT_ATTRIBUTES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_VOLUME_SNAPSHOT_ATTRIBUTES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_VOLUME_SNAPSHOT_ATTRIBUTES").field(&self.0).finish()
@@ -1803,6 +1831,7 @@ pub struct VSS_WRITERRESTORE_ENUM(pub i32);
 impl windows_core::TypeKind for VSS_WRITERRESTORE_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_WRITERRESTORE_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_WRITERRESTORE_ENUM").field(&self.0).finish()
@@ -1814,6 +1843,7 @@ pub struct VSS_WRITER_STATE(pub i32);
 impl windows_core::TypeKind for VSS_WRITER_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VSS_WRITER_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VSS_WRITER_STATE").field(&self.0).finish()
@@ -1821,7 +1851,8 @@ impl core::fmt::Debug for VSS_WRITER_STATE {
 }
 pub const VSSCoordinator: windows_core::GUID = windows_core::GUID::from_u128(0xe579ab5f_1cc4_44b4_bed9_de0991ff0623);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VSS_DIFF_AREA_PROP {
     pub m_pwszVolumeName: *mut u16,
     pub m_pwszDiffAreaVolumeName: *mut u16,
@@ -1838,7 +1869,8 @@ impl Default for VSS_DIFF_AREA_PROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VSS_DIFF_VOLUME_PROP {
     pub m_pwszVolumeName: *mut u16,
     pub m_pwszVolumeDisplayName: *mut u16,
@@ -1911,7 +1943,8 @@ impl Default for VSS_OBJECT_UNION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VSS_PROVIDER_PROP {
     pub m_ProviderId: windows_core::GUID,
     pub m_pwszProviderName: *mut u16,
@@ -1929,7 +1962,8 @@ impl Default for VSS_PROVIDER_PROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VSS_SNAPSHOT_PROP {
     pub m_SnapshotId: windows_core::GUID,
     pub m_SnapshotSetId: windows_core::GUID,
@@ -1954,7 +1988,8 @@ impl Default for VSS_SNAPSHOT_PROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VSS_VOLUME_PROP {
     pub m_pwszVolumeName: *mut u16,
     pub m_pwszVolumeDisplayName: *mut u16,
@@ -1968,7 +2003,8 @@ impl Default for VSS_VOLUME_PROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VSS_VOLUME_PROTECTION_INFO {
     pub m_protectionLevel: VSS_PROTECTION_LEVEL,
     pub m_volumeIsOfflineForProtection: super::super::Foundation::BOOL,
@@ -1988,3 +2024,5 @@ impl Default for VSS_VOLUME_PROTECTION_INFO {
 pub const VssSnapshotMgmt: windows_core::GUID = windows_core::GUID::from_u128(0x0b5a2c52_3eb9_470a_96e2_6c6d4570e40f);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
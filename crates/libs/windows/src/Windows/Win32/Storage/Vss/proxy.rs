@@ -0,0 +1,807 @@
+impl windows_core::imp::ProxyVtable for IVssAdmin_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterProvider(this: *mut core::ffi::c_void, pproviderid: windows_core::GUID, classid: windows_core::GUID, pwszprovidername: *const u16, eprovidertype: VSS_PROVIDER_TYPE, pwszproviderversion: *const u16, providerversionid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssAdmin", "RegisterProvider", 3usize, |this| ((**(this as *const *const IVssAdmin_Vtbl)).RegisterProvider)(this, pproviderid, classid, pwszprovidername, eprovidertype, pwszproviderversion, providerversionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterProvider(this: *mut core::ffi::c_void, providerid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssAdmin", "UnregisterProvider", 4usize, |this| ((**(this as *const *const IVssAdmin_Vtbl)).UnregisterProvider)(this, providerid), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryProviders(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssAdmin", "QueryProviders", 5usize, |this| ((**(this as *const *const IVssAdmin_Vtbl)).QueryProviders)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn AbortAllSnapshotsInProgress(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssAdmin", "AbortAllSnapshotsInProgress", 6usize, |this| ((**(this as *const *const IVssAdmin_Vtbl)).AbortAllSnapshotsInProgress)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RegisterProvider,
+            UnregisterProvider,
+            QueryProviders,
+            AbortAllSnapshotsInProgress,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVssAdmin as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVssAdminEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProviderCapability(this: *mut core::ffi::c_void, pproviderid: windows_core::GUID, plloriginalcapabilitymask: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssAdminEx", "GetProviderCapability", 7usize, |this| ((**(this as *const *const IVssAdminEx_Vtbl)).GetProviderCapability)(this, pproviderid, plloriginalcapabilitymask), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProviderContext(this: *mut core::ffi::c_void, providerid: windows_core::GUID, plcontext: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssAdminEx", "GetProviderContext", 8usize, |this| ((**(this as *const *const IVssAdminEx_Vtbl)).GetProviderContext)(this, providerid, plcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProviderContext(this: *mut core::ffi::c_void, providerid: windows_core::GUID, lcontext: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssAdminEx", "SetProviderContext", 9usize, |this| ((**(this as *const *const IVssAdminEx_Vtbl)).SetProviderContext)(this, providerid, lcontext), |result| Some(*result))
+        }
+        Self { base__: <IVssAdmin_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProviderCapability, GetProviderContext, SetProviderContext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVssAdminEx as windows_core::Interface>::IID || <IVssAdmin_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVssAsync_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssAsync", "Cancel", 3usize, |this| ((**(this as *const *const IVssAsync_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Wait(this: *mut core::ffi::c_void, dwmilliseconds: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssAsync", "Wait", 4usize, |this| ((**(this as *const *const IVssAsync_Vtbl)).Wait)(this, dwmilliseconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryStatus(this: *mut core::ffi::c_void, phrresult: *mut windows_core::HRESULT, preserved: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssAsync", "QueryStatus", 5usize, |this| ((**(this as *const *const IVssAsync_Vtbl)).QueryStatus)(this, phrresult, preserved), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Cancel, Wait, QueryStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVssAsync as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVssComponent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLogicalPath(this: *mut core::ffi::c_void, pbstrpath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "GetLogicalPath", 3usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).GetLogicalPath)(this, pbstrpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetComponentType(this: *mut core::ffi::c_void, pct: *mut VSS_COMPONENT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "GetComponentType", 4usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).GetComponentType)(this, pct), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetComponentName(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "GetComponentName", 5usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).GetComponentName)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBackupSucceeded(this: *mut core::ffi::c_void, pbsucceeded: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "GetBackupSucceeded", 6usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).GetBackupSucceeded)(this, pbsucceeded), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAlternateLocationMappingCount(this: *mut core::ffi::c_void, pcmappings: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "GetAlternateLocationMappingCount", 7usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).GetAlternateLocationMappingCount)(this, pcmappings), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAlternateLocationMapping(this: *mut core::ffi::c_void, imapping: u32, ppfiledesc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "GetAlternateLocationMapping", 8usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).GetAlternateLocationMapping)(this, imapping, ppfiledesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackupMetadata(this: *mut core::ffi::c_void, wszdata: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "SetBackupMetadata", 9usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).SetBackupMetadata)(this, wszdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBackupMetadata(this: *mut core::ffi::c_void, pbstrdata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "GetBackupMetadata", 10usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).GetBackupMetadata)(this, pbstrdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPartialFile(this: *mut core::ffi::c_void, wszpath: windows_core::PCWSTR, wszfilename: windows_core::PCWSTR, wszranges: windows_core::PCWSTR, wszmetadata: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "AddPartialFile", 11usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).AddPartialFile)(this, wszpath, wszfilename, wszranges, wszmetadata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPartialFileCount(this: *mut core::ffi::c_void, pcpartialfiles: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "GetPartialFileCount", 12usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).GetPartialFileCount)(this, pcpartialfiles), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPartialFile(this: *mut core::ffi::c_void, ipartialfile: u32, pbstrpath: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrfilename: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrrange: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrmetadata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "GetPartialFile", 13usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).GetPartialFile)(this, ipartialfile, pbstrpath, pbstrfilename, pbstrrange, pbstrmetadata), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSelectedForRestore(this: *mut core::ffi::c_void, pbselectedforrestore: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "IsSelectedForRestore", 14usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).IsSelectedForRestore)(this, pbselectedforrestore), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAdditionalRestores(this: *mut core::ffi::c_void, pbadditionalrestores: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "GetAdditionalRestores", 15usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).GetAdditionalRestores)(this, pbadditionalrestores), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNewTargetCount(this: *mut core::ffi::c_void, pcnewtarget: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "GetNewTargetCount", 16usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).GetNewTargetCount)(this, pcnewtarget), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNewTarget(this: *mut core::ffi::c_void, inewtarget: u32, ppfiledesc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "GetNewTarget", 17usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).GetNewTarget)(this, inewtarget, ppfiledesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDirectedTarget(this: *mut core::ffi::c_void, wszsourcepath: windows_core::PCWSTR, wszsourcefilename: windows_core::PCWSTR, wszsourcerangelist: windows_core::PCWSTR, wszdestinationpath: windows_core::PCWSTR, wszdestinationfilename: windows_core::PCWSTR, wszdestinationrangelist: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "AddDirectedTarget", 18usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).AddDirectedTarget)(this, wszsourcepath, wszsourcefilename, wszsourcerangelist, wszdestinationpath, wszdestinationfilename, wszdestinationrangelist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDirectedTargetCount(this: *mut core::ffi::c_void, pcdirectedtarget: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "GetDirectedTargetCount", 19usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).GetDirectedTargetCount)(this, pcdirectedtarget), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDirectedTarget(this: *mut core::ffi::c_void, idirectedtarget: u32, pbstrsourcepath: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrsourcefilename: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrsourcerangelist: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrdestinationpath: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrdestinationfilename: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrdestinationrangelist: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "GetDirectedTarget", 20usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).GetDirectedTarget)(this, idirectedtarget, pbstrsourcepath, pbstrsourcefilename, pbstrsourcerangelist, pbstrdestinationpath, pbstrdestinationfilename, pbstrdestinationrangelist), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRestoreMetadata(this: *mut core::ffi::c_void, wszrestoremetadata: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "SetRestoreMetadata", 21usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).SetRestoreMetadata)(this, wszrestoremetadata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRestoreMetadata(this: *mut core::ffi::c_void, pbstrrestoremetadata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "GetRestoreMetadata", 22usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).GetRestoreMetadata)(this, pbstrrestoremetadata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRestoreTarget(this: *mut core::ffi::c_void, target: VSS_RESTORE_TARGET) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "SetRestoreTarget", 23usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).SetRestoreTarget)(this, target), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRestoreTarget(this: *mut core::ffi::c_void, ptarget: *mut VSS_RESTORE_TARGET) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "GetRestoreTarget", 24usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).GetRestoreTarget)(this, ptarget), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreRestoreFailureMsg(this: *mut core::ffi::c_void, wszprerestorefailuremsg: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "SetPreRestoreFailureMsg", 25usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).SetPreRestoreFailureMsg)(this, wszprerestorefailuremsg), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreRestoreFailureMsg(this: *mut core::ffi::c_void, pbstrprerestorefailuremsg: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "GetPreRestoreFailureMsg", 26usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).GetPreRestoreFailureMsg)(this, pbstrprerestorefailuremsg), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPostRestoreFailureMsg(this: *mut core::ffi::c_void, wszpostrestorefailuremsg: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "SetPostRestoreFailureMsg", 27usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).SetPostRestoreFailureMsg)(this, wszpostrestorefailuremsg), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPostRestoreFailureMsg(this: *mut core::ffi::c_void, pbstrpostrestorefailuremsg: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "GetPostRestoreFailureMsg", 28usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).GetPostRestoreFailureMsg)(this, pbstrpostrestorefailuremsg), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackupStamp(this: *mut core::ffi::c_void, wszbackupstamp: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "SetBackupStamp", 29usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).SetBackupStamp)(this, wszbackupstamp), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBackupStamp(this: *mut core::ffi::c_void, pbstrbackupstamp: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "GetBackupStamp", 30usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).GetBackupStamp)(this, pbstrbackupstamp), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreviousBackupStamp(this: *mut core::ffi::c_void, pbstrbackupstamp: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "GetPreviousBackupStamp", 31usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).GetPreviousBackupStamp)(this, pbstrbackupstamp), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBackupOptions(this: *mut core::ffi::c_void, pbstrbackupoptions: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "GetBackupOptions", 32usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).GetBackupOptions)(this, pbstrbackupoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRestoreOptions(this: *mut core::ffi::c_void, pbstrrestoreoptions: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "GetRestoreOptions", 33usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).GetRestoreOptions)(this, pbstrrestoreoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRestoreSubcomponentCount(this: *mut core::ffi::c_void, pcrestoresubcomponent: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "GetRestoreSubcomponentCount", 34usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).GetRestoreSubcomponentCount)(this, pcrestoresubcomponent), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRestoreSubcomponent(this: *mut core::ffi::c_void, icomponent: u32, pbstrlogicalpath: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrcomponentname: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbrepair: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "GetRestoreSubcomponent", 35usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).GetRestoreSubcomponent)(this, icomponent, pbstrlogicalpath, pbstrcomponentname, pbrepair), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileRestoreStatus(this: *mut core::ffi::c_void, pstatus: *mut VSS_FILE_RESTORE_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "GetFileRestoreStatus", 36usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).GetFileRestoreStatus)(this, pstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDifferencedFilesByLastModifyTime(this: *mut core::ffi::c_void, wszpath: windows_core::PCWSTR, wszfilespec: windows_core::PCWSTR, brecursive: super::super::Foundation::BOOL, ftlastmodifytime: super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "AddDifferencedFilesByLastModifyTime", 37usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).AddDifferencedFilesByLastModifyTime)(this, wszpath, wszfilespec, brecursive, ftlastmodifytime), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDifferencedFilesByLastModifyLSN(this: *mut core::ffi::c_void, wszpath: windows_core::PCWSTR, wszfilespec: windows_core::PCWSTR, brecursive: super::super::Foundation::BOOL, bstrlsnstring: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "AddDifferencedFilesByLastModifyLSN", 38usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).AddDifferencedFilesByLastModifyLSN)(this, wszpath, wszfilespec, brecursive, bstrlsnstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDifferencedFilesCount(this: *mut core::ffi::c_void, pcdifferencedfiles: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "GetDifferencedFilesCount", 39usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).GetDifferencedFilesCount)(this, pcdifferencedfiles), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDifferencedFile(this: *mut core::ffi::c_void, idifferencedfile: u32, pbstrpath: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrfilespec: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbrecursive: *mut super::super::Foundation::BOOL, pbstrlsnstring: *mut core::mem::MaybeUninit<windows_core::BSTR>, pftlastmodifytime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponent", "GetDifferencedFile", 40usize, |this| ((**(this as *const *const IVssComponent_Vtbl)).GetDifferencedFile)(this, idifferencedfile, pbstrpath, pbstrfilespec, pbrecursive, pbstrlsnstring, pftlastmodifytime), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetLogicalPath,
+            GetComponentType,
+            GetComponentName,
+            GetBackupSucceeded,
+            GetAlternateLocationMappingCount,
+            GetAlternateLocationMapping,
+            SetBackupMetadata,
+            GetBackupMetadata,
+            AddPartialFile,
+            GetPartialFileCount,
+            GetPartialFile,
+            IsSelectedForRestore,
+            GetAdditionalRestores,
+            GetNewTargetCount,
+            GetNewTarget,
+            AddDirectedTarget,
+            GetDirectedTargetCount,
+            GetDirectedTarget,
+            SetRestoreMetadata,
+            GetRestoreMetadata,
+            SetRestoreTarget,
+            GetRestoreTarget,
+            SetPreRestoreFailureMsg,
+            GetPreRestoreFailureMsg,
+            SetPostRestoreFailureMsg,
+            GetPostRestoreFailureMsg,
+            SetBackupStamp,
+            GetBackupStamp,
+            GetPreviousBackupStamp,
+            GetBackupOptions,
+            GetRestoreOptions,
+            GetRestoreSubcomponentCount,
+            GetRestoreSubcomponent,
+            GetFileRestoreStatus,
+            AddDifferencedFilesByLastModifyTime,
+            AddDifferencedFilesByLastModifyLSN,
+            GetDifferencedFilesCount,
+            GetDifferencedFile,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVssComponent as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVssComponentEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPrepareForBackupFailureMsg(this: *mut core::ffi::c_void, wszfailuremsg: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponentEx", "SetPrepareForBackupFailureMsg", 41usize, |this| ((**(this as *const *const IVssComponentEx_Vtbl)).SetPrepareForBackupFailureMsg)(this, wszfailuremsg), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPostSnapshotFailureMsg(this: *mut core::ffi::c_void, wszfailuremsg: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponentEx", "SetPostSnapshotFailureMsg", 42usize, |this| ((**(this as *const *const IVssComponentEx_Vtbl)).SetPostSnapshotFailureMsg)(this, wszfailuremsg), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrepareForBackupFailureMsg(this: *mut core::ffi::c_void, pbstrfailuremsg: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponentEx", "GetPrepareForBackupFailureMsg", 43usize, |this| ((**(this as *const *const IVssComponentEx_Vtbl)).GetPrepareForBackupFailureMsg)(this, pbstrfailuremsg), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPostSnapshotFailureMsg(this: *mut core::ffi::c_void, pbstrfailuremsg: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponentEx", "GetPostSnapshotFailureMsg", 44usize, |this| ((**(this as *const *const IVssComponentEx_Vtbl)).GetPostSnapshotFailureMsg)(this, pbstrfailuremsg), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAuthoritativeRestore(this: *mut core::ffi::c_void, pbauth: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponentEx", "GetAuthoritativeRestore", 45usize, |this| ((**(this as *const *const IVssComponentEx_Vtbl)).GetAuthoritativeRestore)(this, pbauth), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRollForward(this: *mut core::ffi::c_void, prolltype: *mut VSS_ROLLFORWARD_TYPE, pbstrpoint: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponentEx", "GetRollForward", 46usize, |this| ((**(this as *const *const IVssComponentEx_Vtbl)).GetRollForward)(this, prolltype, pbstrpoint), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRestoreName(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponentEx", "GetRestoreName", 47usize, |this| ((**(this as *const *const IVssComponentEx_Vtbl)).GetRestoreName)(this, pbstrname), |result| Some(*result))
+        }
+        Self {
+            base__: <IVssComponent_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetPrepareForBackupFailureMsg,
+            SetPostSnapshotFailureMsg,
+            GetPrepareForBackupFailureMsg,
+            GetPostSnapshotFailureMsg,
+            GetAuthoritativeRestore,
+            GetRollForward,
+            GetRestoreName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVssComponentEx as windows_core::Interface>::IID || <IVssComponent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVssComponentEx2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetFailure(this: *mut core::ffi::c_void, hr: windows_core::HRESULT, hrapplication: windows_core::HRESULT, wszapplicationmessage: windows_core::PCWSTR, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponentEx2", "SetFailure", 48usize, |this| ((**(this as *const *const IVssComponentEx2_Vtbl)).SetFailure)(this, hr, hrapplication, wszapplicationmessage, dwreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFailure(this: *mut core::ffi::c_void, phr: *mut windows_core::HRESULT, phrapplication: *mut windows_core::HRESULT, pbstrapplicationmessage: *mut core::mem::MaybeUninit<windows_core::BSTR>, pdwreserved: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssComponentEx2", "GetFailure", 49usize, |this| ((**(this as *const *const IVssComponentEx2_Vtbl)).GetFailure)(this, phr, phrapplication, pbstrapplicationmessage, pdwreserved), |result| Some(*result))
+        }
+        Self { base__: <IVssComponentEx_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetFailure, GetFailure }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVssComponentEx2 as windows_core::Interface>::IID || <IVssComponentEx_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVssCreateExpressWriterMetadata_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddExcludeFiles(this: *mut core::ffi::c_void, wszpath: windows_core::PCWSTR, wszfilespec: windows_core::PCWSTR, brecursive: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssCreateExpressWriterMetadata", "AddExcludeFiles", 3usize, |this| ((**(this as *const *const IVssCreateExpressWriterMetadata_Vtbl)).AddExcludeFiles)(this, wszpath, wszfilespec, brecursive), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddComponent(this: *mut core::ffi::c_void, ct: VSS_COMPONENT_TYPE, wszlogicalpath: windows_core::PCWSTR, wszcomponentname: windows_core::PCWSTR, wszcaption: windows_core::PCWSTR, pbicon: *const u8, cbicon: u32, brestoremetadata: u8, bnotifyonbackupcomplete: u8, bselectable: u8, bselectableforrestore: u8, dwcomponentflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssCreateExpressWriterMetadata", "AddComponent", 4usize, |this| ((**(this as *const *const IVssCreateExpressWriterMetadata_Vtbl)).AddComponent)(this, ct, wszlogicalpath, wszcomponentname, wszcaption, pbicon, cbicon, brestoremetadata, bnotifyonbackupcomplete, bselectable, bselectableforrestore, dwcomponentflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddFilesToFileGroup(this: *mut core::ffi::c_void, wszlogicalpath: windows_core::PCWSTR, wszgroupname: windows_core::PCWSTR, wszpath: windows_core::PCWSTR, wszfilespec: windows_core::PCWSTR, brecursive: u8, wszalternatelocation: windows_core::PCWSTR, dwbackuptypemask: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssCreateExpressWriterMetadata", "AddFilesToFileGroup", 5usize, |this| ((**(this as *const *const IVssCreateExpressWriterMetadata_Vtbl)).AddFilesToFileGroup)(this, wszlogicalpath, wszgroupname, wszpath, wszfilespec, brecursive, wszalternatelocation, dwbackuptypemask), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRestoreMethod(this: *mut core::ffi::c_void, method: VSS_RESTOREMETHOD_ENUM, wszservice: windows_core::PCWSTR, wszuserprocedure: windows_core::PCWSTR, writerrestore: VSS_WRITERRESTORE_ENUM, brebootrequired: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssCreateExpressWriterMetadata", "SetRestoreMethod", 6usize, |this| ((**(this as *const *const IVssCreateExpressWriterMetadata_Vtbl)).SetRestoreMethod)(this, method, wszservice, wszuserprocedure, writerrestore, brebootrequired), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddComponentDependency(this: *mut core::ffi::c_void, wszforlogicalpath: windows_core::PCWSTR, wszforcomponentname: windows_core::PCWSTR, onwriterid: windows_core::GUID, wszonlogicalpath: windows_core::PCWSTR, wszoncomponentname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssCreateExpressWriterMetadata", "AddComponentDependency", 7usize, |this| ((**(this as *const *const IVssCreateExpressWriterMetadata_Vtbl)).AddComponentDependency)(this, wszforlogicalpath, wszforcomponentname, onwriterid, wszonlogicalpath, wszoncomponentname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackupSchema(this: *mut core::ffi::c_void, dwschemamask: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssCreateExpressWriterMetadata", "SetBackupSchema", 8usize, |this| ((**(this as *const *const IVssCreateExpressWriterMetadata_Vtbl)).SetBackupSchema)(this, dwschemamask), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveAsXML(this: *mut core::ffi::c_void, pbstrxml: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssCreateExpressWriterMetadata", "SaveAsXML", 9usize, |this| ((**(this as *const *const IVssCreateExpressWriterMetadata_Vtbl)).SaveAsXML)(this, pbstrxml), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddExcludeFiles,
+            AddComponent,
+            AddFilesToFileGroup,
+            SetRestoreMethod,
+            AddComponentDependency,
+            SetBackupSchema,
+            SaveAsXML,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVssCreateExpressWriterMetadata as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVssDifferentialSoftwareSnapshotMgmt_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddDiffArea(this: *mut core::ffi::c_void, pwszvolumename: *const u16, pwszdiffareavolumename: *const u16, llmaximumdiffspace: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssDifferentialSoftwareSnapshotMgmt", "AddDiffArea", 3usize, |this| ((**(this as *const *const IVssDifferentialSoftwareSnapshotMgmt_Vtbl)).AddDiffArea)(this, pwszvolumename, pwszdiffareavolumename, llmaximumdiffspace), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeDiffAreaMaximumSize(this: *mut core::ffi::c_void, pwszvolumename: *const u16, pwszdiffareavolumename: *const u16, llmaximumdiffspace: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssDifferentialSoftwareSnapshotMgmt", "ChangeDiffAreaMaximumSize", 4usize, |this| ((**(this as *const *const IVssDifferentialSoftwareSnapshotMgmt_Vtbl)).ChangeDiffAreaMaximumSize)(this, pwszvolumename, pwszdiffareavolumename, llmaximumdiffspace), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryVolumesSupportedForDiffAreas(this: *mut core::ffi::c_void, pwszoriginalvolumename: *const u16, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssDifferentialSoftwareSnapshotMgmt", "QueryVolumesSupportedForDiffAreas", 5usize, |this| ((**(this as *const *const IVssDifferentialSoftwareSnapshotMgmt_Vtbl)).QueryVolumesSupportedForDiffAreas)(this, pwszoriginalvolumename, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryDiffAreasForVolume(this: *mut core::ffi::c_void, pwszvolumename: *const u16, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssDifferentialSoftwareSnapshotMgmt", "QueryDiffAreasForVolume", 6usize, |this| ((**(this as *const *const IVssDifferentialSoftwareSnapshotMgmt_Vtbl)).QueryDiffAreasForVolume)(this, pwszvolumename, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryDiffAreasOnVolume(this: *mut core::ffi::c_void, pwszvolumename: *const u16, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssDifferentialSoftwareSnapshotMgmt", "QueryDiffAreasOnVolume", 7usize, |this| ((**(this as *const *const IVssDifferentialSoftwareSnapshotMgmt_Vtbl)).QueryDiffAreasOnVolume)(this, pwszvolumename, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryDiffAreasForSnapshot(this: *mut core::ffi::c_void, snapshotid: windows_core::GUID, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssDifferentialSoftwareSnapshotMgmt", "QueryDiffAreasForSnapshot", 8usize, |this| ((**(this as *const *const IVssDifferentialSoftwareSnapshotMgmt_Vtbl)).QueryDiffAreasForSnapshot)(this, snapshotid, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddDiffArea,
+            ChangeDiffAreaMaximumSize,
+            QueryVolumesSupportedForDiffAreas,
+            QueryDiffAreasForVolume,
+            QueryDiffAreasOnVolume,
+            QueryDiffAreasForSnapshot,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVssDifferentialSoftwareSnapshotMgmt as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVssDifferentialSoftwareSnapshotMgmt2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChangeDiffAreaMaximumSizeEx(this: *mut core::ffi::c_void, pwszvolumename: *const u16, pwszdiffareavolumename: *const u16, llmaximumdiffspace: i64, bvolatile: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssDifferentialSoftwareSnapshotMgmt2", "ChangeDiffAreaMaximumSizeEx", 9usize, |this| ((**(this as *const *const IVssDifferentialSoftwareSnapshotMgmt2_Vtbl)).ChangeDiffAreaMaximumSizeEx)(this, pwszvolumename, pwszdiffareavolumename, llmaximumdiffspace, bvolatile), |result| Some(*result))
+        }
+        unsafe extern "system" fn MigrateDiffAreas(this: *mut core::ffi::c_void, pwszvolumename: *const u16, pwszdiffareavolumename: *const u16, pwsznewdiffareavolumename: *const u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssDifferentialSoftwareSnapshotMgmt2", "MigrateDiffAreas", 10usize, |this| ((**(this as *const *const IVssDifferentialSoftwareSnapshotMgmt2_Vtbl)).MigrateDiffAreas)(this, pwszvolumename, pwszdiffareavolumename, pwsznewdiffareavolumename), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryMigrationStatus(this: *mut core::ffi::c_void, pwszvolumename: *const u16, pwszdiffareavolumename: *const u16, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssDifferentialSoftwareSnapshotMgmt2", "QueryMigrationStatus", 11usize, |this| ((**(this as *const *const IVssDifferentialSoftwareSnapshotMgmt2_Vtbl)).QueryMigrationStatus)(this, pwszvolumename, pwszdiffareavolumename, ppasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSnapshotPriority(this: *mut core::ffi::c_void, idsnapshot: windows_core::GUID, priority: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssDifferentialSoftwareSnapshotMgmt2", "SetSnapshotPriority", 12usize, |this| ((**(this as *const *const IVssDifferentialSoftwareSnapshotMgmt2_Vtbl)).SetSnapshotPriority)(this, idsnapshot, priority), |result| Some(*result))
+        }
+        Self {
+            base__: <IVssDifferentialSoftwareSnapshotMgmt_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ChangeDiffAreaMaximumSizeEx,
+            MigrateDiffAreas,
+            QueryMigrationStatus,
+            SetSnapshotPriority,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVssDifferentialSoftwareSnapshotMgmt2 as windows_core::Interface>::IID || <IVssDifferentialSoftwareSnapshotMgmt_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVssDifferentialSoftwareSnapshotMgmt3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetVolumeProtectLevel(this: *mut core::ffi::c_void, pwszvolumename: *const u16, protectionlevel: VSS_PROTECTION_LEVEL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssDifferentialSoftwareSnapshotMgmt3", "SetVolumeProtectLevel", 13usize, |this| ((**(this as *const *const IVssDifferentialSoftwareSnapshotMgmt3_Vtbl)).SetVolumeProtectLevel)(this, pwszvolumename, protectionlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVolumeProtectLevel(this: *mut core::ffi::c_void, pwszvolumename: *const u16, protectionlevel: *mut VSS_VOLUME_PROTECTION_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssDifferentialSoftwareSnapshotMgmt3", "GetVolumeProtectLevel", 14usize, |this| ((**(this as *const *const IVssDifferentialSoftwareSnapshotMgmt3_Vtbl)).GetVolumeProtectLevel)(this, pwszvolumename, protectionlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearVolumeProtectFault(this: *mut core::ffi::c_void, pwszvolumename: *const u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssDifferentialSoftwareSnapshotMgmt3", "ClearVolumeProtectFault", 15usize, |this| ((**(this as *const *const IVssDifferentialSoftwareSnapshotMgmt3_Vtbl)).ClearVolumeProtectFault)(this, pwszvolumename), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteUnusedDiffAreas(this: *mut core::ffi::c_void, pwszdiffareavolumename: *const u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssDifferentialSoftwareSnapshotMgmt3", "DeleteUnusedDiffAreas", 16usize, |this| ((**(this as *const *const IVssDifferentialSoftwareSnapshotMgmt3_Vtbl)).DeleteUnusedDiffAreas)(this, pwszdiffareavolumename), |result| Some(*result))
+        }
+        unsafe extern "system" fn QuerySnapshotDeltaBitmap(this: *mut core::ffi::c_void, idsnapshotolder: windows_core::GUID, idsnapshotyounger: windows_core::GUID, pcblocksizeperbit: *mut u32, pcbitmaplength: *mut u32, ppbbitmap: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssDifferentialSoftwareSnapshotMgmt3", "QuerySnapshotDeltaBitmap", 17usize, |this| ((**(this as *const *const IVssDifferentialSoftwareSnapshotMgmt3_Vtbl)).QuerySnapshotDeltaBitmap)(this, idsnapshotolder, idsnapshotyounger, pcblocksizeperbit, pcbitmaplength, ppbbitmap), |result| Some(*result))
+        }
+        Self {
+            base__: <IVssDifferentialSoftwareSnapshotMgmt2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetVolumeProtectLevel,
+            GetVolumeProtectLevel,
+            ClearVolumeProtectFault,
+            DeleteUnusedDiffAreas,
+            QuerySnapshotDeltaBitmap,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVssDifferentialSoftwareSnapshotMgmt3 as windows_core::Interface>::IID || <IVssDifferentialSoftwareSnapshotMgmt2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVssEnumMgmtObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut VSS_MGMT_OBJECT_PROP, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssEnumMgmtObject", "Next", 3usize, |this| ((**(this as *const *const IVssEnumMgmtObject_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssEnumMgmtObject", "Skip", 4usize, |this| ((**(this as *const *const IVssEnumMgmtObject_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssEnumMgmtObject", "Reset", 5usize, |this| ((**(this as *const *const IVssEnumMgmtObject_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssEnumMgmtObject", "Clone", 6usize, |this| ((**(this as *const *const IVssEnumMgmtObject_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVssEnumMgmtObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVssEnumObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut VSS_OBJECT_PROP, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssEnumObject", "Next", 3usize, |this| ((**(this as *const *const IVssEnumObject_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssEnumObject", "Skip", 4usize, |this| ((**(this as *const *const IVssEnumObject_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssEnumObject", "Reset", 5usize, |this| ((**(this as *const *const IVssEnumObject_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssEnumObject", "Clone", 6usize, |this| ((**(this as *const *const IVssEnumObject_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVssEnumObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVssExpressWriter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateMetadata(this: *mut core::ffi::c_void, writerid: windows_core::GUID, writername: windows_core::PCWSTR, usagetype: VSS_USAGE_TYPE, versionmajor: u32, versionminor: u32, reserved: u32, ppmetadata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssExpressWriter", "CreateMetadata", 3usize, |this| ((**(this as *const *const IVssExpressWriter_Vtbl)).CreateMetadata)(this, writerid, writername, usagetype, versionmajor, versionminor, reserved, ppmetadata), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadMetadata(this: *mut core::ffi::c_void, metadata: windows_core::PCWSTR, reserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssExpressWriter", "LoadMetadata", 4usize, |this| ((**(this as *const *const IVssExpressWriter_Vtbl)).LoadMetadata)(this, metadata, reserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn Register(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssExpressWriter", "Register", 5usize, |this| ((**(this as *const *const IVssExpressWriter_Vtbl)).Register)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unregister(this: *mut core::ffi::c_void, writerid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssExpressWriter", "Unregister", 6usize, |this| ((**(this as *const *const IVssExpressWriter_Vtbl)).Unregister)(this, writerid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateMetadata, LoadMetadata, Register, Unregister }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVssExpressWriter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVssFileShareSnapshotProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetContext(this: *mut core::ffi::c_void, lcontext: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssFileShareSnapshotProvider", "SetContext", 3usize, |this| ((**(this as *const *const IVssFileShareSnapshotProvider_Vtbl)).SetContext)(this, lcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSnapshotProperties(this: *mut core::ffi::c_void, snapshotid: windows_core::GUID, pprop: *mut VSS_SNAPSHOT_PROP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssFileShareSnapshotProvider", "GetSnapshotProperties", 4usize, |this| ((**(this as *const *const IVssFileShareSnapshotProvider_Vtbl)).GetSnapshotProperties)(this, snapshotid, pprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn Query(this: *mut core::ffi::c_void, queriedobjectid: windows_core::GUID, equeriedobjecttype: VSS_OBJECT_TYPE, ereturnedobjectstype: VSS_OBJECT_TYPE, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssFileShareSnapshotProvider", "Query", 5usize, |this| ((**(this as *const *const IVssFileShareSnapshotProvider_Vtbl)).Query)(this, queriedobjectid, equeriedobjecttype, ereturnedobjectstype, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteSnapshots(this: *mut core::ffi::c_void, sourceobjectid: windows_core::GUID, esourceobjecttype: VSS_OBJECT_TYPE, bforcedelete: super::super::Foundation::BOOL, pldeletedsnapshots: *mut i32, pnondeletedsnapshotid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssFileShareSnapshotProvider", "DeleteSnapshots", 6usize, |this| ((**(this as *const *const IVssFileShareSnapshotProvider_Vtbl)).DeleteSnapshots)(this, sourceobjectid, esourceobjecttype, bforcedelete, pldeletedsnapshots, pnondeletedsnapshotid), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginPrepareSnapshot(this: *mut core::ffi::c_void, snapshotsetid: windows_core::GUID, snapshotid: windows_core::GUID, pwszsharepath: *const u16, lnewcontext: i32, providerid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssFileShareSnapshotProvider", "BeginPrepareSnapshot", 7usize, |this| ((**(this as *const *const IVssFileShareSnapshotProvider_Vtbl)).BeginPrepareSnapshot)(this, snapshotsetid, snapshotid, pwszsharepath, lnewcontext, providerid), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPathSupported(this: *mut core::ffi::c_void, pwszsharepath: *const u16, pbsupportedbythisprovider: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssFileShareSnapshotProvider", "IsPathSupported", 8usize, |this| ((**(this as *const *const IVssFileShareSnapshotProvider_Vtbl)).IsPathSupported)(this, pwszsharepath, pbsupportedbythisprovider), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPathSnapshotted(this: *mut core::ffi::c_void, pwszsharepath: *const u16, pbsnapshotspresent: *mut super::super::Foundation::BOOL, plsnapshotcompatibility: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssFileShareSnapshotProvider", "IsPathSnapshotted", 9usize, |this| ((**(this as *const *const IVssFileShareSnapshotProvider_Vtbl)).IsPathSnapshotted)(this, pwszsharepath, pbsnapshotspresent, plsnapshotcompatibility), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSnapshotProperty(this: *mut core::ffi::c_void, snapshotid: windows_core::GUID, esnapshotpropertyid: VSS_SNAPSHOT_PROPERTY_ID, vproperty: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssFileShareSnapshotProvider", "SetSnapshotProperty", 10usize, |this| ((**(this as *const *const IVssFileShareSnapshotProvider_Vtbl)).SetSnapshotProperty)(this, snapshotid, esnapshotpropertyid, vproperty), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetContext,
+            GetSnapshotProperties,
+            Query,
+            DeleteSnapshots,
+            BeginPrepareSnapshot,
+            IsPathSupported,
+            IsPathSnapshotted,
+            SetSnapshotProperty,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVssFileShareSnapshotProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_VirtualDiskService")]
+impl windows_core::imp::ProxyVtable for IVssHardwareSnapshotProvider_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_VirtualDiskService")]
+        unsafe extern "system" fn AreLunsSupported(this: *mut core::ffi::c_void, lluncount: i32, lcontext: i32, rgwszdevices: *const *const u16, pluninformation: *mut super::VirtualDiskService::VDS_LUN_INFORMATION, pbissupported: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssHardwareSnapshotProvider", "AreLunsSupported", 3usize, |this| ((**(this as *const *const IVssHardwareSnapshotProvider_Vtbl)).AreLunsSupported)(this, lluncount, lcontext, rgwszdevices, pluninformation, pbissupported), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_VirtualDiskService")]
+        unsafe extern "system" fn FillInLunInfo(this: *mut core::ffi::c_void, wszdevicename: *const u16, pluninfo: *mut super::VirtualDiskService::VDS_LUN_INFORMATION, pbissupported: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssHardwareSnapshotProvider", "FillInLunInfo", 4usize, |this| ((**(this as *const *const IVssHardwareSnapshotProvider_Vtbl)).FillInLunInfo)(this, wszdevicename, pluninfo, pbissupported), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_VirtualDiskService")]
+        unsafe extern "system" fn BeginPrepareSnapshot(this: *mut core::ffi::c_void, snapshotsetid: windows_core::GUID, snapshotid: windows_core::GUID, lcontext: i32, lluncount: i32, rgdevicenames: *const *const u16, rgluninformation: *mut super::VirtualDiskService::VDS_LUN_INFORMATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssHardwareSnapshotProvider", "BeginPrepareSnapshot", 5usize, |this| ((**(this as *const *const IVssHardwareSnapshotProvider_Vtbl)).BeginPrepareSnapshot)(this, snapshotsetid, snapshotid, lcontext, lluncount, rgdevicenames, rgluninformation), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_VirtualDiskService")]
+        unsafe extern "system" fn GetTargetLuns(this: *mut core::ffi::c_void, lluncount: i32, rgdevicenames: *const *const u16, rgsourceluns: *const super::VirtualDiskService::VDS_LUN_INFORMATION, rgdestinationluns: *mut super::VirtualDiskService::VDS_LUN_INFORMATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssHardwareSnapshotProvider", "GetTargetLuns", 6usize, |this| ((**(this as *const *const IVssHardwareSnapshotProvider_Vtbl)).GetTargetLuns)(this, lluncount, rgdevicenames, rgsourceluns, rgdestinationluns), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_VirtualDiskService")]
+        unsafe extern "system" fn LocateLuns(this: *mut core::ffi::c_void, lluncount: i32, rgsourceluns: *const super::VirtualDiskService::VDS_LUN_INFORMATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssHardwareSnapshotProvider", "LocateLuns", 7usize, |this| ((**(this as *const *const IVssHardwareSnapshotProvider_Vtbl)).LocateLuns)(this, lluncount, rgsourceluns), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_VirtualDiskService")]
+        unsafe extern "system" fn OnLunEmpty(this: *mut core::ffi::c_void, wszdevicename: *const u16, pinformation: *const super::VirtualDiskService::VDS_LUN_INFORMATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssHardwareSnapshotProvider", "OnLunEmpty", 8usize, |this| ((**(this as *const *const IVssHardwareSnapshotProvider_Vtbl)).OnLunEmpty)(this, wszdevicename, pinformation), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_VirtualDiskService")]
+            AreLunsSupported,
+            #[cfg(not(feature = "Win32_Storage_VirtualDiskService"))]
+            AreLunsSupported: 0,
+            #[cfg(feature = "Win32_Storage_VirtualDiskService")]
+            FillInLunInfo,
+            #[cfg(not(feature = "Win32_Storage_VirtualDiskService"))]
+            FillInLunInfo: 0,
+            #[cfg(feature = "Win32_Storage_VirtualDiskService")]
+            BeginPrepareSnapshot,
+            #[cfg(not(feature = "Win32_Storage_VirtualDiskService"))]
+            BeginPrepareSnapshot: 0,
+            #[cfg(feature = "Win32_Storage_VirtualDiskService")]
+            GetTargetLuns,
+            #[cfg(not(feature = "Win32_Storage_VirtualDiskService"))]
+            GetTargetLuns: 0,
+            #[cfg(feature = "Win32_Storage_VirtualDiskService")]
+            LocateLuns,
+            #[cfg(not(feature = "Win32_Storage_VirtualDiskService"))]
+            LocateLuns: 0,
+            #[cfg(feature = "Win32_Storage_VirtualDiskService")]
+            OnLunEmpty,
+            #[cfg(not(feature = "Win32_Storage_VirtualDiskService"))]
+            OnLunEmpty: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVssHardwareSnapshotProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_VirtualDiskService")]
+impl windows_core::imp::ProxyVtable for IVssHardwareSnapshotProviderEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProviderCapabilities(this: *mut core::ffi::c_void, plloriginalcapabilitymask: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssHardwareSnapshotProviderEx", "GetProviderCapabilities", 9usize, |this| ((**(this as *const *const IVssHardwareSnapshotProviderEx_Vtbl)).GetProviderCapabilities)(this, plloriginalcapabilitymask), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_VirtualDiskService")]
+        unsafe extern "system" fn OnLunStateChange(this: *mut core::ffi::c_void, psnapshotluns: *const super::VirtualDiskService::VDS_LUN_INFORMATION, poriginalluns: *const super::VirtualDiskService::VDS_LUN_INFORMATION, dwcount: u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssHardwareSnapshotProviderEx", "OnLunStateChange", 10usize, |this| ((**(this as *const *const IVssHardwareSnapshotProviderEx_Vtbl)).OnLunStateChange)(this, psnapshotluns, poriginalluns, dwcount, dwflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_VirtualDiskService")]
+        unsafe extern "system" fn ResyncLuns(this: *mut core::ffi::c_void, psourceluns: *const super::VirtualDiskService::VDS_LUN_INFORMATION, ptargetluns: *const super::VirtualDiskService::VDS_LUN_INFORMATION, dwcount: u32, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssHardwareSnapshotProviderEx", "ResyncLuns", 11usize, |this| ((**(this as *const *const IVssHardwareSnapshotProviderEx_Vtbl)).ResyncLuns)(this, psourceluns, ptargetluns, dwcount, ppasync), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_VirtualDiskService")]
+        unsafe extern "system" fn OnReuseLuns(this: *mut core::ffi::c_void, psnapshotluns: *const super::VirtualDiskService::VDS_LUN_INFORMATION, poriginalluns: *const super::VirtualDiskService::VDS_LUN_INFORMATION, dwcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssHardwareSnapshotProviderEx", "OnReuseLuns", 12usize, |this| ((**(this as *const *const IVssHardwareSnapshotProviderEx_Vtbl)).OnReuseLuns)(this, psnapshotluns, poriginalluns, dwcount), |result| Some(*result))
+        }
+        Self {
+            base__: <IVssHardwareSnapshotProvider_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetProviderCapabilities,
+            #[cfg(feature = "Win32_Storage_VirtualDiskService")]
+            OnLunStateChange,
+            #[cfg(not(feature = "Win32_Storage_VirtualDiskService"))]
+            OnLunStateChange: 0,
+            #[cfg(feature = "Win32_Storage_VirtualDiskService")]
+            ResyncLuns,
+            #[cfg(not(feature = "Win32_Storage_VirtualDiskService"))]
+            ResyncLuns: 0,
+            #[cfg(feature = "Win32_Storage_VirtualDiskService")]
+            OnReuseLuns,
+            #[cfg(not(feature = "Win32_Storage_VirtualDiskService"))]
+            OnReuseLuns: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVssHardwareSnapshotProviderEx as windows_core::Interface>::IID || <IVssHardwareSnapshotProvider_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVssProviderCreateSnapshotSet_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EndPrepareSnapshots(this: *mut core::ffi::c_void, snapshotsetid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssProviderCreateSnapshotSet", "EndPrepareSnapshots", 3usize, |this| ((**(this as *const *const IVssProviderCreateSnapshotSet_Vtbl)).EndPrepareSnapshots)(this, snapshotsetid), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreCommitSnapshots(this: *mut core::ffi::c_void, snapshotsetid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssProviderCreateSnapshotSet", "PreCommitSnapshots", 4usize, |this| ((**(this as *const *const IVssProviderCreateSnapshotSet_Vtbl)).PreCommitSnapshots)(this, snapshotsetid), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommitSnapshots(this: *mut core::ffi::c_void, snapshotsetid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssProviderCreateSnapshotSet", "CommitSnapshots", 5usize, |this| ((**(this as *const *const IVssProviderCreateSnapshotSet_Vtbl)).CommitSnapshots)(this, snapshotsetid), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostCommitSnapshots(this: *mut core::ffi::c_void, snapshotsetid: windows_core::GUID, lsnapshotscount: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssProviderCreateSnapshotSet", "PostCommitSnapshots", 6usize, |this| ((**(this as *const *const IVssProviderCreateSnapshotSet_Vtbl)).PostCommitSnapshots)(this, snapshotsetid, lsnapshotscount), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreFinalCommitSnapshots(this: *mut core::ffi::c_void, snapshotsetid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssProviderCreateSnapshotSet", "PreFinalCommitSnapshots", 7usize, |this| ((**(this as *const *const IVssProviderCreateSnapshotSet_Vtbl)).PreFinalCommitSnapshots)(this, snapshotsetid), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostFinalCommitSnapshots(this: *mut core::ffi::c_void, snapshotsetid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssProviderCreateSnapshotSet", "PostFinalCommitSnapshots", 8usize, |this| ((**(this as *const *const IVssProviderCreateSnapshotSet_Vtbl)).PostFinalCommitSnapshots)(this, snapshotsetid), |result| Some(*result))
+        }
+        unsafe extern "system" fn AbortSnapshots(this: *mut core::ffi::c_void, snapshotsetid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssProviderCreateSnapshotSet", "AbortSnapshots", 9usize, |this| ((**(this as *const *const IVssProviderCreateSnapshotSet_Vtbl)).AbortSnapshots)(this, snapshotsetid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EndPrepareSnapshots,
+            PreCommitSnapshots,
+            CommitSnapshots,
+            PostCommitSnapshots,
+            PreFinalCommitSnapshots,
+            PostFinalCommitSnapshots,
+            AbortSnapshots,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVssProviderCreateSnapshotSet as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVssProviderNotifications_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnLoad(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssProviderNotifications", "OnLoad", 3usize, |this| ((**(this as *const *const IVssProviderNotifications_Vtbl)).OnLoad)(this, pcallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnUnload(this: *mut core::ffi::c_void, bforceunload: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssProviderNotifications", "OnUnload", 4usize, |this| ((**(this as *const *const IVssProviderNotifications_Vtbl)).OnUnload)(this, bforceunload), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnLoad, OnUnload }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVssProviderNotifications as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVssSnapshotMgmt_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProviderMgmtInterface(this: *mut core::ffi::c_void, providerid: windows_core::GUID, interfaceid: *const windows_core::GUID, ppitf: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssSnapshotMgmt", "GetProviderMgmtInterface", 3usize, |this| ((**(this as *const *const IVssSnapshotMgmt_Vtbl)).GetProviderMgmtInterface)(this, providerid, interfaceid, ppitf), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryVolumesSupportedForSnapshots(this: *mut core::ffi::c_void, providerid: windows_core::GUID, lcontext: i32, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssSnapshotMgmt", "QueryVolumesSupportedForSnapshots", 4usize, |this| ((**(this as *const *const IVssSnapshotMgmt_Vtbl)).QueryVolumesSupportedForSnapshots)(this, providerid, lcontext, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn QuerySnapshotsByVolume(this: *mut core::ffi::c_void, pwszvolumename: *const u16, providerid: windows_core::GUID, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssSnapshotMgmt", "QuerySnapshotsByVolume", 5usize, |this| ((**(this as *const *const IVssSnapshotMgmt_Vtbl)).QuerySnapshotsByVolume)(this, pwszvolumename, providerid, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetProviderMgmtInterface,
+            QueryVolumesSupportedForSnapshots,
+            QuerySnapshotsByVolume,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVssSnapshotMgmt as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVssSnapshotMgmt2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMinDiffAreaSize(this: *mut core::ffi::c_void, pllmindiffareasize: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssSnapshotMgmt2", "GetMinDiffAreaSize", 3usize, |this| ((**(this as *const *const IVssSnapshotMgmt2_Vtbl)).GetMinDiffAreaSize)(this, pllmindiffareasize), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMinDiffAreaSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVssSnapshotMgmt2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVssSoftwareSnapshotProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetContext(this: *mut core::ffi::c_void, lcontext: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssSoftwareSnapshotProvider", "SetContext", 3usize, |this| ((**(this as *const *const IVssSoftwareSnapshotProvider_Vtbl)).SetContext)(this, lcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSnapshotProperties(this: *mut core::ffi::c_void, snapshotid: windows_core::GUID, pprop: *mut VSS_SNAPSHOT_PROP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssSoftwareSnapshotProvider", "GetSnapshotProperties", 4usize, |this| ((**(this as *const *const IVssSoftwareSnapshotProvider_Vtbl)).GetSnapshotProperties)(this, snapshotid, pprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn Query(this: *mut core::ffi::c_void, queriedobjectid: windows_core::GUID, equeriedobjecttype: VSS_OBJECT_TYPE, ereturnedobjectstype: VSS_OBJECT_TYPE, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssSoftwareSnapshotProvider", "Query", 5usize, |this| ((**(this as *const *const IVssSoftwareSnapshotProvider_Vtbl)).Query)(this, queriedobjectid, equeriedobjecttype, ereturnedobjectstype, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteSnapshots(this: *mut core::ffi::c_void, sourceobjectid: windows_core::GUID, esourceobjecttype: VSS_OBJECT_TYPE, bforcedelete: super::super::Foundation::BOOL, pldeletedsnapshots: *mut i32, pnondeletedsnapshotid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssSoftwareSnapshotProvider", "DeleteSnapshots", 6usize, |this| ((**(this as *const *const IVssSoftwareSnapshotProvider_Vtbl)).DeleteSnapshots)(this, sourceobjectid, esourceobjecttype, bforcedelete, pldeletedsnapshots, pnondeletedsnapshotid), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginPrepareSnapshot(this: *mut core::ffi::c_void, snapshotsetid: windows_core::GUID, snapshotid: windows_core::GUID, pwszvolumename: *const u16, lnewcontext: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssSoftwareSnapshotProvider", "BeginPrepareSnapshot", 7usize, |this| ((**(this as *const *const IVssSoftwareSnapshotProvider_Vtbl)).BeginPrepareSnapshot)(this, snapshotsetid, snapshotid, pwszvolumename, lnewcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsVolumeSupported(this: *mut core::ffi::c_void, pwszvolumename: *const u16, pbsupportedbythisprovider: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssSoftwareSnapshotProvider", "IsVolumeSupported", 8usize, |this| ((**(this as *const *const IVssSoftwareSnapshotProvider_Vtbl)).IsVolumeSupported)(this, pwszvolumename, pbsupportedbythisprovider), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsVolumeSnapshotted(this: *mut core::ffi::c_void, pwszvolumename: *const u16, pbsnapshotspresent: *mut super::super::Foundation::BOOL, plsnapshotcompatibility: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssSoftwareSnapshotProvider", "IsVolumeSnapshotted", 9usize, |this| ((**(this as *const *const IVssSoftwareSnapshotProvider_Vtbl)).IsVolumeSnapshotted)(this, pwszvolumename, pbsnapshotspresent, plsnapshotcompatibility), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSnapshotProperty(this: *mut core::ffi::c_void, snapshotid: windows_core::GUID, esnapshotpropertyid: VSS_SNAPSHOT_PROPERTY_ID, vproperty: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssSoftwareSnapshotProvider", "SetSnapshotProperty", 10usize, |this| ((**(this as *const *const IVssSoftwareSnapshotProvider_Vtbl)).SetSnapshotProperty)(this, snapshotid, esnapshotpropertyid, vproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn RevertToSnapshot(this: *mut core::ffi::c_void, snapshotid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssSoftwareSnapshotProvider", "RevertToSnapshot", 11usize, |this| ((**(this as *const *const IVssSoftwareSnapshotProvider_Vtbl)).RevertToSnapshot)(this, snapshotid), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryRevertStatus(this: *mut core::ffi::c_void, pwszvolume: *const u16, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssSoftwareSnapshotProvider", "QueryRevertStatus", 12usize, |this| ((**(this as *const *const IVssSoftwareSnapshotProvider_Vtbl)).QueryRevertStatus)(this, pwszvolume, ppasync), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetContext,
+            GetSnapshotProperties,
+            Query,
+            DeleteSnapshots,
+            BeginPrepareSnapshot,
+            IsVolumeSupported,
+            IsVolumeSnapshotted,
+            SetSnapshotProperty,
+            RevertToSnapshot,
+            QueryRevertStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVssSoftwareSnapshotProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVssWMDependency_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetWriterId(this: *mut core::ffi::c_void, pwriterid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssWMDependency", "GetWriterId", 3usize, |this| ((**(this as *const *const IVssWMDependency_Vtbl)).GetWriterId)(this, pwriterid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLogicalPath(this: *mut core::ffi::c_void, pbstrlogicalpath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssWMDependency", "GetLogicalPath", 4usize, |this| ((**(this as *const *const IVssWMDependency_Vtbl)).GetLogicalPath)(this, pbstrlogicalpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetComponentName(this: *mut core::ffi::c_void, pbstrcomponentname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssWMDependency", "GetComponentName", 5usize, |this| ((**(this as *const *const IVssWMDependency_Vtbl)).GetComponentName)(this, pbstrcomponentname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetWriterId, GetLogicalPath, GetComponentName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVssWMDependency as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVssWMFiledesc_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPath(this: *mut core::ffi::c_void, pbstrpath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssWMFiledesc", "GetPath", 3usize, |this| ((**(this as *const *const IVssWMFiledesc_Vtbl)).GetPath)(this, pbstrpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFilespec(this: *mut core::ffi::c_void, pbstrfilespec: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssWMFiledesc", "GetFilespec", 4usize, |this| ((**(this as *const *const IVssWMFiledesc_Vtbl)).GetFilespec)(this, pbstrfilespec), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecursive(this: *mut core::ffi::c_void, pbrecursive: *mut bool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssWMFiledesc", "GetRecursive", 5usize, |this| ((**(this as *const *const IVssWMFiledesc_Vtbl)).GetRecursive)(this, pbrecursive), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAlternateLocation(this: *mut core::ffi::c_void, pbstralternatelocation: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssWMFiledesc", "GetAlternateLocation", 6usize, |this| ((**(this as *const *const IVssWMFiledesc_Vtbl)).GetAlternateLocation)(this, pbstralternatelocation), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBackupTypeMask(this: *mut core::ffi::c_void, pdwtypemask: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVssWMFiledesc", "GetBackupTypeMask", 7usize, |this| ((**(this as *const *const IVssWMFiledesc_Vtbl)).GetBackupTypeMask)(this, pdwtypemask), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPath,
+            GetFilespec,
+            GetRecursive,
+            GetAlternateLocation,
+            GetBackupTypeMask,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVssWMFiledesc as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -0,0 +1,94 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrintDocumentPackageStatusEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PackageStatusUpdated(this: *mut core::ffi::c_void, packagestatus: *const PrintDocumentPackageStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintDocumentPackageStatusEvent", "PackageStatusUpdated", 7usize, |this| ((**(this as *const *const IPrintDocumentPackageStatusEvent_Vtbl)).PackageStatusUpdated)(this, packagestatus), |result| Some(*result))
+        }
+        Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PackageStatusUpdated }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintDocumentPackageStatusEvent as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintDocumentPackageTarget_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPackageTargetTypes(this: *mut core::ffi::c_void, targetcount: *mut u32, targettypes: *mut *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintDocumentPackageTarget", "GetPackageTargetTypes", 3usize, |this| ((**(this as *const *const IPrintDocumentPackageTarget_Vtbl)).GetPackageTargetTypes)(this, targetcount, targettypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPackageTarget(this: *mut core::ffi::c_void, guidtargettype: *const windows_core::GUID, riid: *const windows_core::GUID, ppvtarget: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintDocumentPackageTarget", "GetPackageTarget", 4usize, |this| ((**(this as *const *const IPrintDocumentPackageTarget_Vtbl)).GetPackageTarget)(this, guidtargettype, riid, ppvtarget), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintDocumentPackageTarget", "Cancel", 5usize, |this| ((**(this as *const *const IPrintDocumentPackageTarget_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPackageTargetTypes, GetPackageTarget, Cancel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintDocumentPackageTarget as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintDocumentPackageTarget2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetIsTargetIppPrinter(this: *mut core::ffi::c_void, isippprinter: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintDocumentPackageTarget2", "GetIsTargetIppPrinter", 3usize, |this| ((**(this as *const *const IPrintDocumentPackageTarget2_Vtbl)).GetIsTargetIppPrinter)(this, isippprinter), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTargetIppPrintDevice(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvtarget: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintDocumentPackageTarget2", "GetTargetIppPrintDevice", 4usize, |this| ((**(this as *const *const IPrintDocumentPackageTarget2_Vtbl)).GetTargetIppPrintDevice)(this, riid, ppvtarget), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetIsTargetIppPrinter, GetTargetIppPrintDevice }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintDocumentPackageTarget2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrintDocumentPackageTargetFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateDocumentPackageTargetForPrintJob(this: *mut core::ffi::c_void, printername: windows_core::PCWSTR, jobname: windows_core::PCWSTR, joboutputstream: *mut core::ffi::c_void, jobprintticketstream: *mut core::ffi::c_void, docpackagetarget: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintDocumentPackageTargetFactory", "CreateDocumentPackageTargetForPrintJob", 3usize, |this| ((**(this as *const *const IPrintDocumentPackageTargetFactory_Vtbl)).CreateDocumentPackageTargetForPrintJob)(this, printername, jobname, joboutputstream, jobprintticketstream, docpackagetarget), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateDocumentPackageTargetForPrintJob,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateDocumentPackageTargetForPrintJob: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintDocumentPackageTargetFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsPrintJob_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsPrintJob", "Cancel", 3usize, |this| ((**(this as *const *const IXpsPrintJob_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetJobStatus(this: *mut core::ffi::c_void, jobstatus: *mut XPS_JOB_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsPrintJob", "GetJobStatus", 4usize, |this| ((**(this as *const *const IXpsPrintJob_Vtbl)).GetJobStatus)(this, jobstatus), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Cancel, GetJobStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsPrintJob as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXpsPrintJobStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsPrintJobStream", "Close", 5usize, |this| ((**(this as *const *const IXpsPrintJobStream_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self { base__: <super::super::super::System::Com::ISequentialStream_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Close }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsPrintJobStream as windows_core::Interface>::IID || <super::super::super::System::Com::ISequentialStream_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -4863,6 +4863,7 @@ pub struct PRINTER_DEVICE_CAPABILITIES(pub u16);
 impl windows_core::TypeKind for PRINTER_DEVICE_CAPABILITIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PRINTER_DEVICE_CAPABILITIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PRINTER_DEVICE_CAPABILITIES").field(&self.0).finish()
@@ -4874,6 +4875,7 @@ pub struct PRINT_WINDOW_FLAGS(pub u32);
 impl windows_core::TypeKind for PRINT_WINDOW_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PRINT_WINDOW_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PRINT_WINDOW_FLAGS").field(&self.0).finish()
@@ -4885,6 +4887,7 @@ pub struct PSINJECT_POINT(pub u16);
 impl windows_core::TypeKind for PSINJECT_POINT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PSINJECT_POINT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PSINJECT_POINT").field(&self.0).finish()
@@ -4929,6 +4932,7 @@ pub struct XPS_COLOR_INTERPOLATION(pub i32);
 impl windows_core::TypeKind for XPS_COLOR_INTERPOLATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XPS_COLOR_INTERPOLATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XPS_COLOR_INTERPOLATION").field(&self.0).finish()
@@ -4940,6 +4944,7 @@ pub struct XPS_COLOR_TYPE(pub i32);
 impl windows_core::TypeKind for XPS_COLOR_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XPS_COLOR_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XPS_COLOR_TYPE").field(&self.0).finish()
@@ -4951,6 +4956,7 @@ pub struct XPS_DASH_CAP(pub i32);
 impl windows_core::TypeKind for XPS_DASH_CAP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XPS_DASH_CAP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XPS_DASH_CAP").field(&self.0).finish()
@@ -4962,6 +4968,7 @@ pub struct XPS_DOCUMENT_TYPE(pub i32);
 impl windows_core::TypeKind for XPS_DOCUMENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XPS_DOCUMENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XPS_DOCUMENT_TYPE").field(&self.0).finish()
@@ -4973,6 +4980,7 @@ pub struct XPS_FILL_RULE(pub i32);
 impl windows_core::TypeKind for XPS_FILL_RULE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XPS_FILL_RULE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XPS_FILL_RULE").field(&self.0).finish()
@@ -4984,6 +4992,7 @@ pub struct XPS_FONT_EMBEDDING(pub i32);
 impl windows_core::TypeKind for XPS_FONT_EMBEDDING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XPS_FONT_EMBEDDING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XPS_FONT_EMBEDDING").field(&self.0).finish()
@@ -4995,6 +5004,7 @@ pub struct XPS_IMAGE_TYPE(pub i32);
 impl windows_core::TypeKind for XPS_IMAGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XPS_IMAGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XPS_IMAGE_TYPE").field(&self.0).finish()
@@ -5006,6 +5016,7 @@ pub struct XPS_INTERLEAVING(pub i32);
 impl windows_core::TypeKind for XPS_INTERLEAVING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XPS_INTERLEAVING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XPS_INTERLEAVING").field(&self.0).finish()
@@ -5017,6 +5028,7 @@ pub struct XPS_LINE_CAP(pub i32);
 impl windows_core::TypeKind for XPS_LINE_CAP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XPS_LINE_CAP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XPS_LINE_CAP").field(&self.0).finish()
@@ -5028,6 +5040,7 @@ pub struct XPS_LINE_JOIN(pub i32);
 impl windows_core::TypeKind for XPS_LINE_JOIN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XPS_LINE_JOIN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XPS_LINE_JOIN").field(&self.0).finish()
@@ -5039,6 +5052,7 @@ pub struct XPS_OBJECT_TYPE(pub i32);
 impl windows_core::TypeKind for XPS_OBJECT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XPS_OBJECT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XPS_OBJECT_TYPE").field(&self.0).finish()
@@ -5050,6 +5064,7 @@ pub struct XPS_SEGMENT_STROKE_PATTERN(pub i32);
 impl windows_core::TypeKind for XPS_SEGMENT_STROKE_PATTERN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XPS_SEGMENT_STROKE_PATTERN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XPS_SEGMENT_STROKE_PATTERN").field(&self.0).finish()
@@ -5061,6 +5076,7 @@ pub struct XPS_SEGMENT_TYPE(pub i32);
 impl windows_core::TypeKind for XPS_SEGMENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XPS_SEGMENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XPS_SEGMENT_TYPE").field(&self.0).finish()
@@ -5072,6 +5088,7 @@ pub struct XPS_SIGNATURE_STATUS(pub i32);
 impl windows_core::TypeKind for XPS_SIGNATURE_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XPS_SIGNATURE_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XPS_SIGNATURE_STATUS").field(&self.0).finish()
@@ -5083,6 +5100,7 @@ pub struct XPS_SIGN_FLAGS(pub i32);
 impl windows_core::TypeKind for XPS_SIGN_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XPS_SIGN_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XPS_SIGN_FLAGS").field(&self.0).finish()
@@ -5127,6 +5145,7 @@ pub struct XPS_SIGN_POLICY(pub i32);
 impl windows_core::TypeKind for XPS_SIGN_POLICY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XPS_SIGN_POLICY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XPS_SIGN_POLICY").field(&self.0).finish()
@@ -5171,6 +5190,7 @@ pub struct XPS_SPREAD_METHOD(pub i32);
 impl windows_core::TypeKind for XPS_SPREAD_METHOD {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XPS_SPREAD_METHOD {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XPS_SPREAD_METHOD").field(&self.0).finish()
@@ -5182,6 +5202,7 @@ pub struct XPS_STYLE_SIMULATION(pub i32);
 impl windows_core::TypeKind for XPS_STYLE_SIMULATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XPS_STYLE_SIMULATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XPS_STYLE_SIMULATION").field(&self.0).finish()
@@ -5193,6 +5214,7 @@ pub struct XPS_THUMBNAIL_SIZE(pub i32);
 impl windows_core::TypeKind for XPS_THUMBNAIL_SIZE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XPS_THUMBNAIL_SIZE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XPS_THUMBNAIL_SIZE").field(&self.0).finish()
@@ -5204,13 +5226,15 @@ pub struct XPS_TILE_MODE(pub i32);
 impl windows_core::TypeKind for XPS_TILE_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XPS_TILE_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XPS_TILE_MODE").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DOCINFOA {
     pub cbSize: i32,
     pub lpszDocName: windows_core::PCSTR,
@@ -5227,7 +5251,8 @@ impl Default for DOCINFOA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DOCINFOW {
     pub cbSize: i32,
     pub lpszDocName: windows_core::PCWSTR,
@@ -5244,7 +5269,8 @@ impl Default for DOCINFOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRAWPATRECT {
     pub ptPosition: super::super::Foundation::POINT,
     pub ptSize: super::super::Foundation::POINT,
@@ -5260,7 +5286,8 @@ impl Default for DRAWPATRECT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PSFEATURE_CUSTPAPER {
     pub lOrientation: i32,
     pub lWidth: i32,
@@ -5277,7 +5304,8 @@ impl Default for PSFEATURE_CUSTPAPER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PSFEATURE_OUTPUT {
     pub bPageIndependent: super::super::Foundation::BOOL,
     pub bSetPageDevice: super::super::Foundation::BOOL,
@@ -5291,7 +5319,8 @@ impl Default for PSFEATURE_OUTPUT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PSINJECTDATA {
     pub DataBytes: u32,
     pub InjectionPoint: PSINJECT_POINT,
@@ -5335,7 +5364,8 @@ impl Default for XPS_COLOR_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XPS_COLOR_0_0 {
     pub channelCount: u8,
     pub channels: [f32; 9],
@@ -5349,7 +5379,8 @@ impl Default for XPS_COLOR_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XPS_COLOR_0_1 {
     pub alpha: u8,
     pub red: u8,
@@ -5365,7 +5396,8 @@ impl Default for XPS_COLOR_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XPS_COLOR_0_2 {
     pub alpha: f32,
     pub red: f32,
@@ -5381,7 +5413,8 @@ impl Default for XPS_COLOR_0_2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XPS_DASH {
     pub length: f32,
     pub gap: f32,
@@ -5395,7 +5428,8 @@ impl Default for XPS_DASH {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XPS_GLYPH_INDEX {
     pub index: i32,
     pub advanceWidth: f32,
@@ -5411,7 +5445,8 @@ impl Default for XPS_GLYPH_INDEX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XPS_GLYPH_MAPPING {
     pub unicodeStringStart: u32,
     pub unicodeStringLength: u16,
@@ -5427,7 +5462,8 @@ impl Default for XPS_GLYPH_MAPPING {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XPS_MATRIX {
     pub m11: f32,
     pub m12: f32,
@@ -5445,7 +5481,8 @@ impl Default for XPS_MATRIX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XPS_POINT {
     pub x: f32,
     pub y: f32,
@@ -5459,7 +5496,8 @@ impl Default for XPS_POINT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XPS_RECT {
     pub x: f32,
     pub y: f32,
@@ -5475,7 +5513,8 @@ impl Default for XPS_RECT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XPS_SIZE {
     pub width: f32,
     pub height: f32,
@@ -5495,3 +5534,5 @@ pub const XpsSignatureManager: windows_core::GUID = windows_core::GUID::from_u12
 pub type ABORTPROC = Option<unsafe extern "system" fn(param0: super::super::Graphics::Gdi::HDC, param1: i32) -> super::super::Foundation::BOOL>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
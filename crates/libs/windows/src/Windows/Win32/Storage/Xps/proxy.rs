@@ -0,0 +1,3247 @@
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsDocumentPackageTarget_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn GetXpsOMPackageWriter(this: *mut core::ffi::c_void, documentsequencepartname: *mut core::ffi::c_void, discardcontrolpartname: *mut core::ffi::c_void, packagewriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsDocumentPackageTarget", "GetXpsOMPackageWriter", 3usize, |this| ((**(this as *const *const IXpsDocumentPackageTarget_Vtbl)).GetXpsOMPackageWriter)(this, documentsequencepartname, discardcontrolpartname, packagewriter), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetXpsOMFactory(this: *mut core::ffi::c_void, xpsfactory: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsDocumentPackageTarget", "GetXpsOMFactory", 4usize, |this| ((**(this as *const *const IXpsDocumentPackageTarget_Vtbl)).GetXpsOMFactory)(this, xpsfactory), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetXpsType(this: *mut core::ffi::c_void, documenttype: *mut XPS_DOCUMENT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsDocumentPackageTarget", "GetXpsType", 5usize, |this| ((**(this as *const *const IXpsDocumentPackageTarget_Vtbl)).GetXpsType)(this, documenttype), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            GetXpsOMPackageWriter,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            GetXpsOMPackageWriter: 0,
+            GetXpsOMFactory,
+            GetXpsType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsDocumentPackageTarget as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsDocumentPackageTarget3D_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn GetXpsOMPackageWriter3D(this: *mut core::ffi::c_void, documentsequencepartname: *mut core::ffi::c_void, discardcontrolpartname: *mut core::ffi::c_void, modelpartname: *mut core::ffi::c_void, modeldata: *mut core::ffi::c_void, packagewriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsDocumentPackageTarget3D", "GetXpsOMPackageWriter3D", 3usize, |this| ((**(this as *const *const IXpsDocumentPackageTarget3D_Vtbl)).GetXpsOMPackageWriter3D)(this, documentsequencepartname, discardcontrolpartname, modelpartname, modeldata, packagewriter), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetXpsOMFactory(this: *mut core::ffi::c_void, xpsfactory: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsDocumentPackageTarget3D", "GetXpsOMFactory", 4usize, |this| ((**(this as *const *const IXpsDocumentPackageTarget3D_Vtbl)).GetXpsOMFactory)(this, xpsfactory), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            GetXpsOMPackageWriter3D,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            GetXpsOMPackageWriter3D: 0,
+            GetXpsOMFactory,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsDocumentPackageTarget3D as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsOMBrush_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOpacity(this: *mut core::ffi::c_void, opacity: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMBrush", "GetOpacity", 5usize, |this| ((**(this as *const *const IXpsOMBrush_Vtbl)).GetOpacity)(this, opacity), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOpacity(this: *mut core::ffi::c_void, opacity: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMBrush", "SetOpacity", 6usize, |this| ((**(this as *const *const IXpsOMBrush_Vtbl)).SetOpacity)(this, opacity), |result| Some(*result))
+        }
+        Self { base__: <IXpsOMShareable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetOpacity, SetOpacity }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMBrush as windows_core::Interface>::IID || <IXpsOMShareable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXpsOMCanvas_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetVisuals(this: *mut core::ffi::c_void, visuals: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCanvas", "GetVisuals", 30usize, |this| ((**(this as *const *const IXpsOMCanvas_Vtbl)).GetVisuals)(this, visuals), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUseAliasedEdgeMode(this: *mut core::ffi::c_void, usealiasededgemode: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCanvas", "GetUseAliasedEdgeMode", 31usize, |this| ((**(this as *const *const IXpsOMCanvas_Vtbl)).GetUseAliasedEdgeMode)(this, usealiasededgemode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUseAliasedEdgeMode(this: *mut core::ffi::c_void, usealiasededgemode: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCanvas", "SetUseAliasedEdgeMode", 32usize, |this| ((**(this as *const *const IXpsOMCanvas_Vtbl)).SetUseAliasedEdgeMode)(this, usealiasededgemode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAccessibilityShortDescription(this: *mut core::ffi::c_void, shortdescription: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCanvas", "GetAccessibilityShortDescription", 33usize, |this| ((**(this as *const *const IXpsOMCanvas_Vtbl)).GetAccessibilityShortDescription)(this, shortdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAccessibilityShortDescription(this: *mut core::ffi::c_void, shortdescription: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCanvas", "SetAccessibilityShortDescription", 34usize, |this| ((**(this as *const *const IXpsOMCanvas_Vtbl)).SetAccessibilityShortDescription)(this, shortdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAccessibilityLongDescription(this: *mut core::ffi::c_void, longdescription: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCanvas", "GetAccessibilityLongDescription", 35usize, |this| ((**(this as *const *const IXpsOMCanvas_Vtbl)).GetAccessibilityLongDescription)(this, longdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAccessibilityLongDescription(this: *mut core::ffi::c_void, longdescription: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCanvas", "SetAccessibilityLongDescription", 36usize, |this| ((**(this as *const *const IXpsOMCanvas_Vtbl)).SetAccessibilityLongDescription)(this, longdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDictionary(this: *mut core::ffi::c_void, resourcedictionary: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCanvas", "GetDictionary", 37usize, |this| ((**(this as *const *const IXpsOMCanvas_Vtbl)).GetDictionary)(this, resourcedictionary), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDictionaryLocal(this: *mut core::ffi::c_void, resourcedictionary: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCanvas", "GetDictionaryLocal", 38usize, |this| ((**(this as *const *const IXpsOMCanvas_Vtbl)).GetDictionaryLocal)(this, resourcedictionary), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDictionaryLocal(this: *mut core::ffi::c_void, resourcedictionary: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCanvas", "SetDictionaryLocal", 39usize, |this| ((**(this as *const *const IXpsOMCanvas_Vtbl)).SetDictionaryLocal)(this, resourcedictionary), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDictionaryResource(this: *mut core::ffi::c_void, remotedictionaryresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCanvas", "GetDictionaryResource", 40usize, |this| ((**(this as *const *const IXpsOMCanvas_Vtbl)).GetDictionaryResource)(this, remotedictionaryresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDictionaryResource(this: *mut core::ffi::c_void, remotedictionaryresource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCanvas", "SetDictionaryResource", 41usize, |this| ((**(this as *const *const IXpsOMCanvas_Vtbl)).SetDictionaryResource)(this, remotedictionaryresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, canvas: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCanvas", "Clone", 42usize, |this| ((**(this as *const *const IXpsOMCanvas_Vtbl)).Clone)(this, canvas), |result| Some(*result))
+        }
+        Self {
+            base__: <IXpsOMVisual_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetVisuals,
+            GetUseAliasedEdgeMode,
+            SetUseAliasedEdgeMode,
+            GetAccessibilityShortDescription,
+            SetAccessibilityShortDescription,
+            GetAccessibilityLongDescription,
+            SetAccessibilityLongDescription,
+            GetDictionary,
+            GetDictionaryLocal,
+            SetDictionaryLocal,
+            GetDictionaryResource,
+            SetDictionaryResource,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMCanvas as windows_core::Interface>::IID || <IXpsOMVisual_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMColorProfileResource_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetStream(this: *mut core::ffi::c_void, stream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMColorProfileResource", "GetStream", 5usize, |this| ((**(this as *const *const IXpsOMColorProfileResource_Vtbl)).GetStream)(this, stream), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn SetContent(this: *mut core::ffi::c_void, sourcestream: *mut core::ffi::c_void, partname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMColorProfileResource", "SetContent", 6usize, |this| ((**(this as *const *const IXpsOMColorProfileResource_Vtbl)).SetContent)(this, sourcestream, partname), |result| Some(*result))
+        }
+        Self {
+            base__: <IXpsOMResource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetStream: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            SetContent,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            SetContent: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMColorProfileResource as windows_core::Interface>::IID || <IXpsOMResource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMColorProfileResourceCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMColorProfileResourceCollection", "GetCount", 3usize, |this| ((**(this as *const *const IXpsOMColorProfileResourceCollection_Vtbl)).GetCount)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, index: u32, object: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMColorProfileResourceCollection", "GetAt", 4usize, |this| ((**(this as *const *const IXpsOMColorProfileResourceCollection_Vtbl)).GetAt)(this, index, object), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertAt(this: *mut core::ffi::c_void, index: u32, object: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMColorProfileResourceCollection", "InsertAt", 5usize, |this| ((**(this as *const *const IXpsOMColorProfileResourceCollection_Vtbl)).InsertAt)(this, index, object), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAt(this: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMColorProfileResourceCollection", "RemoveAt", 6usize, |this| ((**(this as *const *const IXpsOMColorProfileResourceCollection_Vtbl)).RemoveAt)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAt(this: *mut core::ffi::c_void, index: u32, object: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMColorProfileResourceCollection", "SetAt", 7usize, |this| ((**(this as *const *const IXpsOMColorProfileResourceCollection_Vtbl)).SetAt)(this, index, object), |result| Some(*result))
+        }
+        unsafe extern "system" fn Append(this: *mut core::ffi::c_void, object: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMColorProfileResourceCollection", "Append", 8usize, |this| ((**(this as *const *const IXpsOMColorProfileResourceCollection_Vtbl)).Append)(this, object), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn GetByPartName(this: *mut core::ffi::c_void, partname: *mut core::ffi::c_void, part: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMColorProfileResourceCollection", "GetByPartName", 9usize, |this| ((**(this as *const *const IXpsOMColorProfileResourceCollection_Vtbl)).GetByPartName)(this, partname, part), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCount,
+            GetAt,
+            InsertAt,
+            RemoveAt,
+            SetAt,
+            Append,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            GetByPartName,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            GetByPartName: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMColorProfileResourceCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMCoreProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOwner(this: *mut core::ffi::c_void, package: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "GetOwner", 5usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).GetOwner)(this, package), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCategory(this: *mut core::ffi::c_void, category: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "GetCategory", 6usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).GetCategory)(this, category), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCategory(this: *mut core::ffi::c_void, category: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "SetCategory", 7usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).SetCategory)(this, category), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContentStatus(this: *mut core::ffi::c_void, contentstatus: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "GetContentStatus", 8usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).GetContentStatus)(this, contentstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContentStatus(this: *mut core::ffi::c_void, contentstatus: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "SetContentStatus", 9usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).SetContentStatus)(this, contentstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContentType(this: *mut core::ffi::c_void, contenttype: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "GetContentType", 10usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).GetContentType)(this, contenttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContentType(this: *mut core::ffi::c_void, contenttype: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "SetContentType", 11usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).SetContentType)(this, contenttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCreated(this: *mut core::ffi::c_void, created: *mut super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "GetCreated", 12usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).GetCreated)(this, created), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCreated(this: *mut core::ffi::c_void, created: *const super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "SetCreated", 13usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).SetCreated)(this, created), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCreator(this: *mut core::ffi::c_void, creator: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "GetCreator", 14usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).GetCreator)(this, creator), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCreator(this: *mut core::ffi::c_void, creator: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "SetCreator", 15usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).SetCreator)(this, creator), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescription(this: *mut core::ffi::c_void, description: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "GetDescription", 16usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).GetDescription)(this, description), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, description: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "SetDescription", 17usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).SetDescription)(this, description), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIdentifier(this: *mut core::ffi::c_void, identifier: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "GetIdentifier", 18usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).GetIdentifier)(this, identifier), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIdentifier(this: *mut core::ffi::c_void, identifier: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "SetIdentifier", 19usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).SetIdentifier)(this, identifier), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKeywords(this: *mut core::ffi::c_void, keywords: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "GetKeywords", 20usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).GetKeywords)(this, keywords), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeywords(this: *mut core::ffi::c_void, keywords: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "SetKeywords", 21usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).SetKeywords)(this, keywords), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLanguage(this: *mut core::ffi::c_void, language: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "GetLanguage", 22usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).GetLanguage)(this, language), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLanguage(this: *mut core::ffi::c_void, language: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "SetLanguage", 23usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).SetLanguage)(this, language), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastModifiedBy(this: *mut core::ffi::c_void, lastmodifiedby: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "GetLastModifiedBy", 24usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).GetLastModifiedBy)(this, lastmodifiedby), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLastModifiedBy(this: *mut core::ffi::c_void, lastmodifiedby: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "SetLastModifiedBy", 25usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).SetLastModifiedBy)(this, lastmodifiedby), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastPrinted(this: *mut core::ffi::c_void, lastprinted: *mut super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "GetLastPrinted", 26usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).GetLastPrinted)(this, lastprinted), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLastPrinted(this: *mut core::ffi::c_void, lastprinted: *const super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "SetLastPrinted", 27usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).SetLastPrinted)(this, lastprinted), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetModified(this: *mut core::ffi::c_void, modified: *mut super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "GetModified", 28usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).GetModified)(this, modified), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetModified(this: *mut core::ffi::c_void, modified: *const super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "SetModified", 29usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).SetModified)(this, modified), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRevision(this: *mut core::ffi::c_void, revision: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "GetRevision", 30usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).GetRevision)(this, revision), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRevision(this: *mut core::ffi::c_void, revision: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "SetRevision", 31usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).SetRevision)(this, revision), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubject(this: *mut core::ffi::c_void, subject: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "GetSubject", 32usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).GetSubject)(this, subject), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubject(this: *mut core::ffi::c_void, subject: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "SetSubject", 33usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).SetSubject)(this, subject), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTitle(this: *mut core::ffi::c_void, title: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "GetTitle", 34usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).GetTitle)(this, title), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, title: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "SetTitle", 35usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).SetTitle)(this, title), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersion(this: *mut core::ffi::c_void, version: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "GetVersion", 36usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).GetVersion)(this, version), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVersion(this: *mut core::ffi::c_void, version: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "SetVersion", 37usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).SetVersion)(this, version), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, coreproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMCoreProperties", "Clone", 38usize, |this| ((**(this as *const *const IXpsOMCoreProperties_Vtbl)).Clone)(this, coreproperties), |result| Some(*result))
+        }
+        Self {
+            base__: <IXpsOMPart_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetOwner,
+            GetCategory,
+            SetCategory,
+            GetContentStatus,
+            SetContentStatus,
+            GetContentType,
+            SetContentType,
+            GetCreated,
+            SetCreated,
+            GetCreator,
+            SetCreator,
+            GetDescription,
+            SetDescription,
+            GetIdentifier,
+            SetIdentifier,
+            GetKeywords,
+            SetKeywords,
+            GetLanguage,
+            SetLanguage,
+            GetLastModifiedBy,
+            SetLastModifiedBy,
+            GetLastPrinted,
+            SetLastPrinted,
+            GetModified,
+            SetModified,
+            GetRevision,
+            SetRevision,
+            GetSubject,
+            SetSubject,
+            GetTitle,
+            SetTitle,
+            GetVersion,
+            SetVersion,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMCoreProperties as windows_core::Interface>::IID || <IXpsOMPart_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsOMDashCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDashCollection", "GetCount", 3usize, |this| ((**(this as *const *const IXpsOMDashCollection_Vtbl)).GetCount)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, index: u32, dash: *mut XPS_DASH) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDashCollection", "GetAt", 4usize, |this| ((**(this as *const *const IXpsOMDashCollection_Vtbl)).GetAt)(this, index, dash), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertAt(this: *mut core::ffi::c_void, index: u32, dash: *const XPS_DASH) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDashCollection", "InsertAt", 5usize, |this| ((**(this as *const *const IXpsOMDashCollection_Vtbl)).InsertAt)(this, index, dash), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAt(this: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDashCollection", "RemoveAt", 6usize, |this| ((**(this as *const *const IXpsOMDashCollection_Vtbl)).RemoveAt)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAt(this: *mut core::ffi::c_void, index: u32, dash: *const XPS_DASH) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDashCollection", "SetAt", 7usize, |this| ((**(this as *const *const IXpsOMDashCollection_Vtbl)).SetAt)(this, index, dash), |result| Some(*result))
+        }
+        unsafe extern "system" fn Append(this: *mut core::ffi::c_void, dash: *const XPS_DASH) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDashCollection", "Append", 8usize, |this| ((**(this as *const *const IXpsOMDashCollection_Vtbl)).Append)(this, dash), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCount, GetAt, InsertAt, RemoveAt, SetAt, Append }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMDashCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsOMDictionary_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOwner(this: *mut core::ffi::c_void, owner: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDictionary", "GetOwner", 3usize, |this| ((**(this as *const *const IXpsOMDictionary_Vtbl)).GetOwner)(this, owner), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDictionary", "GetCount", 4usize, |this| ((**(this as *const *const IXpsOMDictionary_Vtbl)).GetCount)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, index: u32, key: *mut windows_core::PWSTR, entry: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDictionary", "GetAt", 5usize, |this| ((**(this as *const *const IXpsOMDictionary_Vtbl)).GetAt)(this, index, key, entry), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetByKey(this: *mut core::ffi::c_void, key: windows_core::PCWSTR, beforeentry: *mut core::ffi::c_void, entry: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDictionary", "GetByKey", 6usize, |this| ((**(this as *const *const IXpsOMDictionary_Vtbl)).GetByKey)(this, key, beforeentry, entry), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIndex(this: *mut core::ffi::c_void, entry: *mut core::ffi::c_void, index: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDictionary", "GetIndex", 7usize, |this| ((**(this as *const *const IXpsOMDictionary_Vtbl)).GetIndex)(this, entry, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Append(this: *mut core::ffi::c_void, key: windows_core::PCWSTR, entry: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDictionary", "Append", 8usize, |this| ((**(this as *const *const IXpsOMDictionary_Vtbl)).Append)(this, key, entry), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertAt(this: *mut core::ffi::c_void, index: u32, key: windows_core::PCWSTR, entry: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDictionary", "InsertAt", 9usize, |this| ((**(this as *const *const IXpsOMDictionary_Vtbl)).InsertAt)(this, index, key, entry), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAt(this: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDictionary", "RemoveAt", 10usize, |this| ((**(this as *const *const IXpsOMDictionary_Vtbl)).RemoveAt)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAt(this: *mut core::ffi::c_void, index: u32, key: windows_core::PCWSTR, entry: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDictionary", "SetAt", 11usize, |this| ((**(this as *const *const IXpsOMDictionary_Vtbl)).SetAt)(this, index, key, entry), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, dictionary: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDictionary", "Clone", 12usize, |this| ((**(this as *const *const IXpsOMDictionary_Vtbl)).Clone)(this, dictionary), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetOwner,
+            GetCount,
+            GetAt,
+            GetByKey,
+            GetIndex,
+            Append,
+            InsertAt,
+            RemoveAt,
+            SetAt,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMDictionary as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMDocument_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOwner(this: *mut core::ffi::c_void, documentsequence: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDocument", "GetOwner", 5usize, |this| ((**(this as *const *const IXpsOMDocument_Vtbl)).GetOwner)(this, documentsequence), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPageReferences(this: *mut core::ffi::c_void, pagereferences: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDocument", "GetPageReferences", 6usize, |this| ((**(this as *const *const IXpsOMDocument_Vtbl)).GetPageReferences)(this, pagereferences), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrintTicketResource(this: *mut core::ffi::c_void, printticketresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDocument", "GetPrintTicketResource", 7usize, |this| ((**(this as *const *const IXpsOMDocument_Vtbl)).GetPrintTicketResource)(this, printticketresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrintTicketResource(this: *mut core::ffi::c_void, printticketresource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDocument", "SetPrintTicketResource", 8usize, |this| ((**(this as *const *const IXpsOMDocument_Vtbl)).SetPrintTicketResource)(this, printticketresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDocumentStructureResource(this: *mut core::ffi::c_void, documentstructureresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDocument", "GetDocumentStructureResource", 9usize, |this| ((**(this as *const *const IXpsOMDocument_Vtbl)).GetDocumentStructureResource)(this, documentstructureresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDocumentStructureResource(this: *mut core::ffi::c_void, documentstructureresource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDocument", "SetDocumentStructureResource", 10usize, |this| ((**(this as *const *const IXpsOMDocument_Vtbl)).SetDocumentStructureResource)(this, documentstructureresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignatureBlockResources(this: *mut core::ffi::c_void, signatureblockresources: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDocument", "GetSignatureBlockResources", 11usize, |this| ((**(this as *const *const IXpsOMDocument_Vtbl)).GetSignatureBlockResources)(this, signatureblockresources), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, document: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDocument", "Clone", 12usize, |this| ((**(this as *const *const IXpsOMDocument_Vtbl)).Clone)(this, document), |result| Some(*result))
+        }
+        Self {
+            base__: <IXpsOMPart_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetOwner,
+            GetPageReferences,
+            GetPrintTicketResource,
+            SetPrintTicketResource,
+            GetDocumentStructureResource,
+            SetDocumentStructureResource,
+            GetSignatureBlockResources,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMDocument as windows_core::Interface>::IID || <IXpsOMPart_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsOMDocumentCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDocumentCollection", "GetCount", 3usize, |this| ((**(this as *const *const IXpsOMDocumentCollection_Vtbl)).GetCount)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, index: u32, document: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDocumentCollection", "GetAt", 4usize, |this| ((**(this as *const *const IXpsOMDocumentCollection_Vtbl)).GetAt)(this, index, document), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertAt(this: *mut core::ffi::c_void, index: u32, document: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDocumentCollection", "InsertAt", 5usize, |this| ((**(this as *const *const IXpsOMDocumentCollection_Vtbl)).InsertAt)(this, index, document), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAt(this: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDocumentCollection", "RemoveAt", 6usize, |this| ((**(this as *const *const IXpsOMDocumentCollection_Vtbl)).RemoveAt)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAt(this: *mut core::ffi::c_void, index: u32, document: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDocumentCollection", "SetAt", 7usize, |this| ((**(this as *const *const IXpsOMDocumentCollection_Vtbl)).SetAt)(this, index, document), |result| Some(*result))
+        }
+        unsafe extern "system" fn Append(this: *mut core::ffi::c_void, document: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDocumentCollection", "Append", 8usize, |this| ((**(this as *const *const IXpsOMDocumentCollection_Vtbl)).Append)(this, document), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCount, GetAt, InsertAt, RemoveAt, SetAt, Append }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMDocumentCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMDocumentSequence_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOwner(this: *mut core::ffi::c_void, package: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDocumentSequence", "GetOwner", 5usize, |this| ((**(this as *const *const IXpsOMDocumentSequence_Vtbl)).GetOwner)(this, package), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDocuments(this: *mut core::ffi::c_void, documents: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDocumentSequence", "GetDocuments", 6usize, |this| ((**(this as *const *const IXpsOMDocumentSequence_Vtbl)).GetDocuments)(this, documents), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrintTicketResource(this: *mut core::ffi::c_void, printticketresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDocumentSequence", "GetPrintTicketResource", 7usize, |this| ((**(this as *const *const IXpsOMDocumentSequence_Vtbl)).GetPrintTicketResource)(this, printticketresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrintTicketResource(this: *mut core::ffi::c_void, printticketresource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDocumentSequence", "SetPrintTicketResource", 8usize, |this| ((**(this as *const *const IXpsOMDocumentSequence_Vtbl)).SetPrintTicketResource)(this, printticketresource), |result| Some(*result))
+        }
+        Self { base__: <IXpsOMPart_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetOwner, GetDocuments, GetPrintTicketResource, SetPrintTicketResource }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMDocumentSequence as windows_core::Interface>::IID || <IXpsOMPart_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMDocumentStructureResource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOwner(this: *mut core::ffi::c_void, owner: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDocumentStructureResource", "GetOwner", 5usize, |this| ((**(this as *const *const IXpsOMDocumentStructureResource_Vtbl)).GetOwner)(this, owner), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetStream(this: *mut core::ffi::c_void, stream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDocumentStructureResource", "GetStream", 6usize, |this| ((**(this as *const *const IXpsOMDocumentStructureResource_Vtbl)).GetStream)(this, stream), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn SetContent(this: *mut core::ffi::c_void, sourcestream: *mut core::ffi::c_void, partname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMDocumentStructureResource", "SetContent", 7usize, |this| ((**(this as *const *const IXpsOMDocumentStructureResource_Vtbl)).SetContent)(this, sourcestream, partname), |result| Some(*result))
+        }
+        Self {
+            base__: <IXpsOMResource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetOwner,
+            #[cfg(feature = "Win32_System_Com")]
+            GetStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetStream: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            SetContent,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            SetContent: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMDocumentStructureResource as windows_core::Interface>::IID || <IXpsOMResource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMFontResource_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetStream(this: *mut core::ffi::c_void, readerstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMFontResource", "GetStream", 5usize, |this| ((**(this as *const *const IXpsOMFontResource_Vtbl)).GetStream)(this, readerstream), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn SetContent(this: *mut core::ffi::c_void, sourcestream: *mut core::ffi::c_void, embeddingoption: XPS_FONT_EMBEDDING, partname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMFontResource", "SetContent", 6usize, |this| ((**(this as *const *const IXpsOMFontResource_Vtbl)).SetContent)(this, sourcestream, embeddingoption, partname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEmbeddingOption(this: *mut core::ffi::c_void, embeddingoption: *mut XPS_FONT_EMBEDDING) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMFontResource", "GetEmbeddingOption", 7usize, |this| ((**(this as *const *const IXpsOMFontResource_Vtbl)).GetEmbeddingOption)(this, embeddingoption), |result| Some(*result))
+        }
+        Self {
+            base__: <IXpsOMResource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetStream: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            SetContent,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            SetContent: 0,
+            GetEmbeddingOption,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMFontResource as windows_core::Interface>::IID || <IXpsOMResource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMFontResourceCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMFontResourceCollection", "GetCount", 3usize, |this| ((**(this as *const *const IXpsOMFontResourceCollection_Vtbl)).GetCount)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, index: u32, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMFontResourceCollection", "GetAt", 4usize, |this| ((**(this as *const *const IXpsOMFontResourceCollection_Vtbl)).GetAt)(this, index, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAt(this: *mut core::ffi::c_void, index: u32, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMFontResourceCollection", "SetAt", 5usize, |this| ((**(this as *const *const IXpsOMFontResourceCollection_Vtbl)).SetAt)(this, index, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertAt(this: *mut core::ffi::c_void, index: u32, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMFontResourceCollection", "InsertAt", 6usize, |this| ((**(this as *const *const IXpsOMFontResourceCollection_Vtbl)).InsertAt)(this, index, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Append(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMFontResourceCollection", "Append", 7usize, |this| ((**(this as *const *const IXpsOMFontResourceCollection_Vtbl)).Append)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAt(this: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMFontResourceCollection", "RemoveAt", 8usize, |this| ((**(this as *const *const IXpsOMFontResourceCollection_Vtbl)).RemoveAt)(this, index), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn GetByPartName(this: *mut core::ffi::c_void, partname: *mut core::ffi::c_void, part: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMFontResourceCollection", "GetByPartName", 9usize, |this| ((**(this as *const *const IXpsOMFontResourceCollection_Vtbl)).GetByPartName)(this, partname, part), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCount,
+            GetAt,
+            SetAt,
+            InsertAt,
+            Append,
+            RemoveAt,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            GetByPartName,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            GetByPartName: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMFontResourceCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsOMGeometry_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFigures(this: *mut core::ffi::c_void, figures: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometry", "GetFigures", 5usize, |this| ((**(this as *const *const IXpsOMGeometry_Vtbl)).GetFigures)(this, figures), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFillRule(this: *mut core::ffi::c_void, fillrule: *mut XPS_FILL_RULE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometry", "GetFillRule", 6usize, |this| ((**(this as *const *const IXpsOMGeometry_Vtbl)).GetFillRule)(this, fillrule), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFillRule(this: *mut core::ffi::c_void, fillrule: XPS_FILL_RULE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometry", "SetFillRule", 7usize, |this| ((**(this as *const *const IXpsOMGeometry_Vtbl)).SetFillRule)(this, fillrule), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransform(this: *mut core::ffi::c_void, transform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometry", "GetTransform", 8usize, |this| ((**(this as *const *const IXpsOMGeometry_Vtbl)).GetTransform)(this, transform), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransformLocal(this: *mut core::ffi::c_void, transform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometry", "GetTransformLocal", 9usize, |this| ((**(this as *const *const IXpsOMGeometry_Vtbl)).GetTransformLocal)(this, transform), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransformLocal(this: *mut core::ffi::c_void, transform: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometry", "SetTransformLocal", 10usize, |this| ((**(this as *const *const IXpsOMGeometry_Vtbl)).SetTransformLocal)(this, transform), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransformLookup(this: *mut core::ffi::c_void, lookup: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometry", "GetTransformLookup", 11usize, |this| ((**(this as *const *const IXpsOMGeometry_Vtbl)).GetTransformLookup)(this, lookup), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransformLookup(this: *mut core::ffi::c_void, lookup: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometry", "SetTransformLookup", 12usize, |this| ((**(this as *const *const IXpsOMGeometry_Vtbl)).SetTransformLookup)(this, lookup), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, geometry: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometry", "Clone", 13usize, |this| ((**(this as *const *const IXpsOMGeometry_Vtbl)).Clone)(this, geometry), |result| Some(*result))
+        }
+        Self {
+            base__: <IXpsOMShareable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetFigures,
+            GetFillRule,
+            SetFillRule,
+            GetTransform,
+            GetTransformLocal,
+            SetTransformLocal,
+            GetTransformLookup,
+            SetTransformLookup,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMGeometry as windows_core::Interface>::IID || <IXpsOMShareable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsOMGeometryFigure_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOwner(this: *mut core::ffi::c_void, owner: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometryFigure", "GetOwner", 3usize, |this| ((**(this as *const *const IXpsOMGeometryFigure_Vtbl)).GetOwner)(this, owner), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSegmentData(this: *mut core::ffi::c_void, datacount: *mut u32, segmentdata: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometryFigure", "GetSegmentData", 4usize, |this| ((**(this as *const *const IXpsOMGeometryFigure_Vtbl)).GetSegmentData)(this, datacount, segmentdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSegmentTypes(this: *mut core::ffi::c_void, segmentcount: *mut u32, segmenttypes: *mut XPS_SEGMENT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometryFigure", "GetSegmentTypes", 5usize, |this| ((**(this as *const *const IXpsOMGeometryFigure_Vtbl)).GetSegmentTypes)(this, segmentcount, segmenttypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSegmentStrokes(this: *mut core::ffi::c_void, segmentcount: *mut u32, segmentstrokes: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometryFigure", "GetSegmentStrokes", 6usize, |this| ((**(this as *const *const IXpsOMGeometryFigure_Vtbl)).GetSegmentStrokes)(this, segmentcount, segmentstrokes), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSegments(this: *mut core::ffi::c_void, segmentcount: u32, segmentdatacount: u32, segmenttypes: *const XPS_SEGMENT_TYPE, segmentdata: *const f32, segmentstrokes: *const super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometryFigure", "SetSegments", 7usize, |this| ((**(this as *const *const IXpsOMGeometryFigure_Vtbl)).SetSegments)(this, segmentcount, segmentdatacount, segmenttypes, segmentdata, segmentstrokes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStartPoint(this: *mut core::ffi::c_void, startpoint: *mut XPS_POINT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometryFigure", "GetStartPoint", 8usize, |this| ((**(this as *const *const IXpsOMGeometryFigure_Vtbl)).GetStartPoint)(this, startpoint), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartPoint(this: *mut core::ffi::c_void, startpoint: *const XPS_POINT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometryFigure", "SetStartPoint", 9usize, |this| ((**(this as *const *const IXpsOMGeometryFigure_Vtbl)).SetStartPoint)(this, startpoint), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIsClosed(this: *mut core::ffi::c_void, isclosed: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometryFigure", "GetIsClosed", 10usize, |this| ((**(this as *const *const IXpsOMGeometryFigure_Vtbl)).GetIsClosed)(this, isclosed), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsClosed(this: *mut core::ffi::c_void, isclosed: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometryFigure", "SetIsClosed", 11usize, |this| ((**(this as *const *const IXpsOMGeometryFigure_Vtbl)).SetIsClosed)(this, isclosed), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIsFilled(this: *mut core::ffi::c_void, isfilled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometryFigure", "GetIsFilled", 12usize, |this| ((**(this as *const *const IXpsOMGeometryFigure_Vtbl)).GetIsFilled)(this, isfilled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsFilled(this: *mut core::ffi::c_void, isfilled: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometryFigure", "SetIsFilled", 13usize, |this| ((**(this as *const *const IXpsOMGeometryFigure_Vtbl)).SetIsFilled)(this, isfilled), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSegmentCount(this: *mut core::ffi::c_void, segmentcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometryFigure", "GetSegmentCount", 14usize, |this| ((**(this as *const *const IXpsOMGeometryFigure_Vtbl)).GetSegmentCount)(this, segmentcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSegmentDataCount(this: *mut core::ffi::c_void, segmentdatacount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometryFigure", "GetSegmentDataCount", 15usize, |this| ((**(this as *const *const IXpsOMGeometryFigure_Vtbl)).GetSegmentDataCount)(this, segmentdatacount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSegmentStrokePattern(this: *mut core::ffi::c_void, segmentstrokepattern: *mut XPS_SEGMENT_STROKE_PATTERN) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometryFigure", "GetSegmentStrokePattern", 16usize, |this| ((**(this as *const *const IXpsOMGeometryFigure_Vtbl)).GetSegmentStrokePattern)(this, segmentstrokepattern), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, geometryfigure: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometryFigure", "Clone", 17usize, |this| ((**(this as *const *const IXpsOMGeometryFigure_Vtbl)).Clone)(this, geometryfigure), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetOwner,
+            GetSegmentData,
+            GetSegmentTypes,
+            GetSegmentStrokes,
+            SetSegments,
+            GetStartPoint,
+            SetStartPoint,
+            GetIsClosed,
+            SetIsClosed,
+            GetIsFilled,
+            SetIsFilled,
+            GetSegmentCount,
+            GetSegmentDataCount,
+            GetSegmentStrokePattern,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMGeometryFigure as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsOMGeometryFigureCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometryFigureCollection", "GetCount", 3usize, |this| ((**(this as *const *const IXpsOMGeometryFigureCollection_Vtbl)).GetCount)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, index: u32, geometryfigure: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometryFigureCollection", "GetAt", 4usize, |this| ((**(this as *const *const IXpsOMGeometryFigureCollection_Vtbl)).GetAt)(this, index, geometryfigure), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertAt(this: *mut core::ffi::c_void, index: u32, geometryfigure: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometryFigureCollection", "InsertAt", 5usize, |this| ((**(this as *const *const IXpsOMGeometryFigureCollection_Vtbl)).InsertAt)(this, index, geometryfigure), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAt(this: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometryFigureCollection", "RemoveAt", 6usize, |this| ((**(this as *const *const IXpsOMGeometryFigureCollection_Vtbl)).RemoveAt)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAt(this: *mut core::ffi::c_void, index: u32, geometryfigure: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometryFigureCollection", "SetAt", 7usize, |this| ((**(this as *const *const IXpsOMGeometryFigureCollection_Vtbl)).SetAt)(this, index, geometryfigure), |result| Some(*result))
+        }
+        unsafe extern "system" fn Append(this: *mut core::ffi::c_void, geometryfigure: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGeometryFigureCollection", "Append", 8usize, |this| ((**(this as *const *const IXpsOMGeometryFigureCollection_Vtbl)).Append)(this, geometryfigure), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCount, GetAt, InsertAt, RemoveAt, SetAt, Append }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMGeometryFigureCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXpsOMGlyphs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetUnicodeString(this: *mut core::ffi::c_void, unicodestring: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphs", "GetUnicodeString", 30usize, |this| ((**(this as *const *const IXpsOMGlyphs_Vtbl)).GetUnicodeString)(this, unicodestring), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGlyphIndexCount(this: *mut core::ffi::c_void, indexcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphs", "GetGlyphIndexCount", 31usize, |this| ((**(this as *const *const IXpsOMGlyphs_Vtbl)).GetGlyphIndexCount)(this, indexcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGlyphIndices(this: *mut core::ffi::c_void, indexcount: *mut u32, glyphindices: *mut XPS_GLYPH_INDEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphs", "GetGlyphIndices", 32usize, |this| ((**(this as *const *const IXpsOMGlyphs_Vtbl)).GetGlyphIndices)(this, indexcount, glyphindices), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGlyphMappingCount(this: *mut core::ffi::c_void, glyphmappingcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphs", "GetGlyphMappingCount", 33usize, |this| ((**(this as *const *const IXpsOMGlyphs_Vtbl)).GetGlyphMappingCount)(this, glyphmappingcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGlyphMappings(this: *mut core::ffi::c_void, glyphmappingcount: *mut u32, glyphmappings: *mut XPS_GLYPH_MAPPING) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphs", "GetGlyphMappings", 34usize, |this| ((**(this as *const *const IXpsOMGlyphs_Vtbl)).GetGlyphMappings)(this, glyphmappingcount, glyphmappings), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProhibitedCaretStopCount(this: *mut core::ffi::c_void, prohibitedcaretstopcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphs", "GetProhibitedCaretStopCount", 35usize, |this| ((**(this as *const *const IXpsOMGlyphs_Vtbl)).GetProhibitedCaretStopCount)(this, prohibitedcaretstopcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProhibitedCaretStops(this: *mut core::ffi::c_void, prohibitedcaretstopcount: *mut u32, prohibitedcaretstops: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphs", "GetProhibitedCaretStops", 36usize, |this| ((**(this as *const *const IXpsOMGlyphs_Vtbl)).GetProhibitedCaretStops)(this, prohibitedcaretstopcount, prohibitedcaretstops), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBidiLevel(this: *mut core::ffi::c_void, bidilevel: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphs", "GetBidiLevel", 37usize, |this| ((**(this as *const *const IXpsOMGlyphs_Vtbl)).GetBidiLevel)(this, bidilevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIsSideways(this: *mut core::ffi::c_void, issideways: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphs", "GetIsSideways", 38usize, |this| ((**(this as *const *const IXpsOMGlyphs_Vtbl)).GetIsSideways)(this, issideways), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceFontName(this: *mut core::ffi::c_void, devicefontname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphs", "GetDeviceFontName", 39usize, |this| ((**(this as *const *const IXpsOMGlyphs_Vtbl)).GetDeviceFontName)(this, devicefontname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStyleSimulations(this: *mut core::ffi::c_void, stylesimulations: *mut XPS_STYLE_SIMULATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphs", "GetStyleSimulations", 40usize, |this| ((**(this as *const *const IXpsOMGlyphs_Vtbl)).GetStyleSimulations)(this, stylesimulations), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStyleSimulations(this: *mut core::ffi::c_void, stylesimulations: XPS_STYLE_SIMULATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphs", "SetStyleSimulations", 41usize, |this| ((**(this as *const *const IXpsOMGlyphs_Vtbl)).SetStyleSimulations)(this, stylesimulations), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOrigin(this: *mut core::ffi::c_void, origin: *mut XPS_POINT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphs", "GetOrigin", 42usize, |this| ((**(this as *const *const IXpsOMGlyphs_Vtbl)).GetOrigin)(this, origin), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOrigin(this: *mut core::ffi::c_void, origin: *const XPS_POINT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphs", "SetOrigin", 43usize, |this| ((**(this as *const *const IXpsOMGlyphs_Vtbl)).SetOrigin)(this, origin), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontRenderingEmSize(this: *mut core::ffi::c_void, fontrenderingemsize: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphs", "GetFontRenderingEmSize", 44usize, |this| ((**(this as *const *const IXpsOMGlyphs_Vtbl)).GetFontRenderingEmSize)(this, fontrenderingemsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFontRenderingEmSize(this: *mut core::ffi::c_void, fontrenderingemsize: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphs", "SetFontRenderingEmSize", 45usize, |this| ((**(this as *const *const IXpsOMGlyphs_Vtbl)).SetFontRenderingEmSize)(this, fontrenderingemsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontResource(this: *mut core::ffi::c_void, fontresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphs", "GetFontResource", 46usize, |this| ((**(this as *const *const IXpsOMGlyphs_Vtbl)).GetFontResource)(this, fontresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFontResource(this: *mut core::ffi::c_void, fontresource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphs", "SetFontResource", 47usize, |this| ((**(this as *const *const IXpsOMGlyphs_Vtbl)).SetFontResource)(this, fontresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontFaceIndex(this: *mut core::ffi::c_void, fontfaceindex: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphs", "GetFontFaceIndex", 48usize, |this| ((**(this as *const *const IXpsOMGlyphs_Vtbl)).GetFontFaceIndex)(this, fontfaceindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFontFaceIndex(this: *mut core::ffi::c_void, fontfaceindex: i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphs", "SetFontFaceIndex", 49usize, |this| ((**(this as *const *const IXpsOMGlyphs_Vtbl)).SetFontFaceIndex)(this, fontfaceindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFillBrush(this: *mut core::ffi::c_void, fillbrush: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphs", "GetFillBrush", 50usize, |this| ((**(this as *const *const IXpsOMGlyphs_Vtbl)).GetFillBrush)(this, fillbrush), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFillBrushLocal(this: *mut core::ffi::c_void, fillbrush: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphs", "GetFillBrushLocal", 51usize, |this| ((**(this as *const *const IXpsOMGlyphs_Vtbl)).GetFillBrushLocal)(this, fillbrush), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFillBrushLocal(this: *mut core::ffi::c_void, fillbrush: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphs", "SetFillBrushLocal", 52usize, |this| ((**(this as *const *const IXpsOMGlyphs_Vtbl)).SetFillBrushLocal)(this, fillbrush), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFillBrushLookup(this: *mut core::ffi::c_void, key: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphs", "GetFillBrushLookup", 53usize, |this| ((**(this as *const *const IXpsOMGlyphs_Vtbl)).GetFillBrushLookup)(this, key), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFillBrushLookup(this: *mut core::ffi::c_void, key: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphs", "SetFillBrushLookup", 54usize, |this| ((**(this as *const *const IXpsOMGlyphs_Vtbl)).SetFillBrushLookup)(this, key), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGlyphsEditor(this: *mut core::ffi::c_void, editor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphs", "GetGlyphsEditor", 55usize, |this| ((**(this as *const *const IXpsOMGlyphs_Vtbl)).GetGlyphsEditor)(this, editor), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, glyphs: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphs", "Clone", 56usize, |this| ((**(this as *const *const IXpsOMGlyphs_Vtbl)).Clone)(this, glyphs), |result| Some(*result))
+        }
+        Self {
+            base__: <IXpsOMVisual_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetUnicodeString,
+            GetGlyphIndexCount,
+            GetGlyphIndices,
+            GetGlyphMappingCount,
+            GetGlyphMappings,
+            GetProhibitedCaretStopCount,
+            GetProhibitedCaretStops,
+            GetBidiLevel,
+            GetIsSideways,
+            GetDeviceFontName,
+            GetStyleSimulations,
+            SetStyleSimulations,
+            GetOrigin,
+            SetOrigin,
+            GetFontRenderingEmSize,
+            SetFontRenderingEmSize,
+            GetFontResource,
+            SetFontResource,
+            GetFontFaceIndex,
+            SetFontFaceIndex,
+            GetFillBrush,
+            GetFillBrushLocal,
+            SetFillBrushLocal,
+            GetFillBrushLookup,
+            SetFillBrushLookup,
+            GetGlyphsEditor,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMGlyphs as windows_core::Interface>::IID || <IXpsOMVisual_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsOMGlyphsEditor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ApplyEdits(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphsEditor", "ApplyEdits", 3usize, |this| ((**(this as *const *const IXpsOMGlyphsEditor_Vtbl)).ApplyEdits)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUnicodeString(this: *mut core::ffi::c_void, unicodestring: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphsEditor", "GetUnicodeString", 4usize, |this| ((**(this as *const *const IXpsOMGlyphsEditor_Vtbl)).GetUnicodeString)(this, unicodestring), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUnicodeString(this: *mut core::ffi::c_void, unicodestring: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphsEditor", "SetUnicodeString", 5usize, |this| ((**(this as *const *const IXpsOMGlyphsEditor_Vtbl)).SetUnicodeString)(this, unicodestring), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGlyphIndexCount(this: *mut core::ffi::c_void, indexcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphsEditor", "GetGlyphIndexCount", 6usize, |this| ((**(this as *const *const IXpsOMGlyphsEditor_Vtbl)).GetGlyphIndexCount)(this, indexcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGlyphIndices(this: *mut core::ffi::c_void, indexcount: *mut u32, glyphindices: *mut XPS_GLYPH_INDEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphsEditor", "GetGlyphIndices", 7usize, |this| ((**(this as *const *const IXpsOMGlyphsEditor_Vtbl)).GetGlyphIndices)(this, indexcount, glyphindices), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGlyphIndices(this: *mut core::ffi::c_void, indexcount: u32, glyphindices: *const XPS_GLYPH_INDEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphsEditor", "SetGlyphIndices", 8usize, |this| ((**(this as *const *const IXpsOMGlyphsEditor_Vtbl)).SetGlyphIndices)(this, indexcount, glyphindices), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGlyphMappingCount(this: *mut core::ffi::c_void, glyphmappingcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphsEditor", "GetGlyphMappingCount", 9usize, |this| ((**(this as *const *const IXpsOMGlyphsEditor_Vtbl)).GetGlyphMappingCount)(this, glyphmappingcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGlyphMappings(this: *mut core::ffi::c_void, glyphmappingcount: *mut u32, glyphmappings: *mut XPS_GLYPH_MAPPING) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphsEditor", "GetGlyphMappings", 10usize, |this| ((**(this as *const *const IXpsOMGlyphsEditor_Vtbl)).GetGlyphMappings)(this, glyphmappingcount, glyphmappings), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGlyphMappings(this: *mut core::ffi::c_void, glyphmappingcount: u32, glyphmappings: *const XPS_GLYPH_MAPPING) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphsEditor", "SetGlyphMappings", 11usize, |this| ((**(this as *const *const IXpsOMGlyphsEditor_Vtbl)).SetGlyphMappings)(this, glyphmappingcount, glyphmappings), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProhibitedCaretStopCount(this: *mut core::ffi::c_void, prohibitedcaretstopcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphsEditor", "GetProhibitedCaretStopCount", 12usize, |this| ((**(this as *const *const IXpsOMGlyphsEditor_Vtbl)).GetProhibitedCaretStopCount)(this, prohibitedcaretstopcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProhibitedCaretStops(this: *mut core::ffi::c_void, count: *mut u32, prohibitedcaretstops: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphsEditor", "GetProhibitedCaretStops", 13usize, |this| ((**(this as *const *const IXpsOMGlyphsEditor_Vtbl)).GetProhibitedCaretStops)(this, count, prohibitedcaretstops), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProhibitedCaretStops(this: *mut core::ffi::c_void, count: u32, prohibitedcaretstops: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphsEditor", "SetProhibitedCaretStops", 14usize, |this| ((**(this as *const *const IXpsOMGlyphsEditor_Vtbl)).SetProhibitedCaretStops)(this, count, prohibitedcaretstops), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBidiLevel(this: *mut core::ffi::c_void, bidilevel: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphsEditor", "GetBidiLevel", 15usize, |this| ((**(this as *const *const IXpsOMGlyphsEditor_Vtbl)).GetBidiLevel)(this, bidilevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBidiLevel(this: *mut core::ffi::c_void, bidilevel: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphsEditor", "SetBidiLevel", 16usize, |this| ((**(this as *const *const IXpsOMGlyphsEditor_Vtbl)).SetBidiLevel)(this, bidilevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIsSideways(this: *mut core::ffi::c_void, issideways: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphsEditor", "GetIsSideways", 17usize, |this| ((**(this as *const *const IXpsOMGlyphsEditor_Vtbl)).GetIsSideways)(this, issideways), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsSideways(this: *mut core::ffi::c_void, issideways: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphsEditor", "SetIsSideways", 18usize, |this| ((**(this as *const *const IXpsOMGlyphsEditor_Vtbl)).SetIsSideways)(this, issideways), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceFontName(this: *mut core::ffi::c_void, devicefontname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphsEditor", "GetDeviceFontName", 19usize, |this| ((**(this as *const *const IXpsOMGlyphsEditor_Vtbl)).GetDeviceFontName)(this, devicefontname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeviceFontName(this: *mut core::ffi::c_void, devicefontname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGlyphsEditor", "SetDeviceFontName", 20usize, |this| ((**(this as *const *const IXpsOMGlyphsEditor_Vtbl)).SetDeviceFontName)(this, devicefontname), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ApplyEdits,
+            GetUnicodeString,
+            SetUnicodeString,
+            GetGlyphIndexCount,
+            GetGlyphIndices,
+            SetGlyphIndices,
+            GetGlyphMappingCount,
+            GetGlyphMappings,
+            SetGlyphMappings,
+            GetProhibitedCaretStopCount,
+            GetProhibitedCaretStops,
+            SetProhibitedCaretStops,
+            GetBidiLevel,
+            SetBidiLevel,
+            GetIsSideways,
+            SetIsSideways,
+            GetDeviceFontName,
+            SetDeviceFontName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMGlyphsEditor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsOMGradientBrush_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetGradientStops(this: *mut core::ffi::c_void, gradientstops: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGradientBrush", "GetGradientStops", 7usize, |this| ((**(this as *const *const IXpsOMGradientBrush_Vtbl)).GetGradientStops)(this, gradientstops), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransform(this: *mut core::ffi::c_void, transform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGradientBrush", "GetTransform", 8usize, |this| ((**(this as *const *const IXpsOMGradientBrush_Vtbl)).GetTransform)(this, transform), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransformLocal(this: *mut core::ffi::c_void, transform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGradientBrush", "GetTransformLocal", 9usize, |this| ((**(this as *const *const IXpsOMGradientBrush_Vtbl)).GetTransformLocal)(this, transform), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransformLocal(this: *mut core::ffi::c_void, transform: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGradientBrush", "SetTransformLocal", 10usize, |this| ((**(this as *const *const IXpsOMGradientBrush_Vtbl)).SetTransformLocal)(this, transform), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransformLookup(this: *mut core::ffi::c_void, key: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGradientBrush", "GetTransformLookup", 11usize, |this| ((**(this as *const *const IXpsOMGradientBrush_Vtbl)).GetTransformLookup)(this, key), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransformLookup(this: *mut core::ffi::c_void, key: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGradientBrush", "SetTransformLookup", 12usize, |this| ((**(this as *const *const IXpsOMGradientBrush_Vtbl)).SetTransformLookup)(this, key), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSpreadMethod(this: *mut core::ffi::c_void, spreadmethod: *mut XPS_SPREAD_METHOD) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGradientBrush", "GetSpreadMethod", 13usize, |this| ((**(this as *const *const IXpsOMGradientBrush_Vtbl)).GetSpreadMethod)(this, spreadmethod), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSpreadMethod(this: *mut core::ffi::c_void, spreadmethod: XPS_SPREAD_METHOD) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGradientBrush", "SetSpreadMethod", 14usize, |this| ((**(this as *const *const IXpsOMGradientBrush_Vtbl)).SetSpreadMethod)(this, spreadmethod), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColorInterpolationMode(this: *mut core::ffi::c_void, colorinterpolationmode: *mut XPS_COLOR_INTERPOLATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGradientBrush", "GetColorInterpolationMode", 15usize, |this| ((**(this as *const *const IXpsOMGradientBrush_Vtbl)).GetColorInterpolationMode)(this, colorinterpolationmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColorInterpolationMode(this: *mut core::ffi::c_void, colorinterpolationmode: XPS_COLOR_INTERPOLATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGradientBrush", "SetColorInterpolationMode", 16usize, |this| ((**(this as *const *const IXpsOMGradientBrush_Vtbl)).SetColorInterpolationMode)(this, colorinterpolationmode), |result| Some(*result))
+        }
+        Self {
+            base__: <IXpsOMBrush_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetGradientStops,
+            GetTransform,
+            GetTransformLocal,
+            SetTransformLocal,
+            GetTransformLookup,
+            SetTransformLookup,
+            GetSpreadMethod,
+            SetSpreadMethod,
+            GetColorInterpolationMode,
+            SetColorInterpolationMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMGradientBrush as windows_core::Interface>::IID || <IXpsOMBrush_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsOMGradientStop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOwner(this: *mut core::ffi::c_void, owner: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGradientStop", "GetOwner", 3usize, |this| ((**(this as *const *const IXpsOMGradientStop_Vtbl)).GetOwner)(this, owner), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOffset(this: *mut core::ffi::c_void, offset: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGradientStop", "GetOffset", 4usize, |this| ((**(this as *const *const IXpsOMGradientStop_Vtbl)).GetOffset)(this, offset), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOffset(this: *mut core::ffi::c_void, offset: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGradientStop", "SetOffset", 5usize, |this| ((**(this as *const *const IXpsOMGradientStop_Vtbl)).SetOffset)(this, offset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColor(this: *mut core::ffi::c_void, color: *mut XPS_COLOR, colorprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGradientStop", "GetColor", 6usize, |this| ((**(this as *const *const IXpsOMGradientStop_Vtbl)).GetColor)(this, color, colorprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColor(this: *mut core::ffi::c_void, color: *const XPS_COLOR, colorprofile: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGradientStop", "SetColor", 7usize, |this| ((**(this as *const *const IXpsOMGradientStop_Vtbl)).SetColor)(this, color, colorprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, gradientstop: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGradientStop", "Clone", 8usize, |this| ((**(this as *const *const IXpsOMGradientStop_Vtbl)).Clone)(this, gradientstop), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetOwner, GetOffset, SetOffset, GetColor, SetColor, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMGradientStop as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsOMGradientStopCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGradientStopCollection", "GetCount", 3usize, |this| ((**(this as *const *const IXpsOMGradientStopCollection_Vtbl)).GetCount)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, index: u32, stop: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGradientStopCollection", "GetAt", 4usize, |this| ((**(this as *const *const IXpsOMGradientStopCollection_Vtbl)).GetAt)(this, index, stop), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertAt(this: *mut core::ffi::c_void, index: u32, stop: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGradientStopCollection", "InsertAt", 5usize, |this| ((**(this as *const *const IXpsOMGradientStopCollection_Vtbl)).InsertAt)(this, index, stop), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAt(this: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGradientStopCollection", "RemoveAt", 6usize, |this| ((**(this as *const *const IXpsOMGradientStopCollection_Vtbl)).RemoveAt)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAt(this: *mut core::ffi::c_void, index: u32, stop: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGradientStopCollection", "SetAt", 7usize, |this| ((**(this as *const *const IXpsOMGradientStopCollection_Vtbl)).SetAt)(this, index, stop), |result| Some(*result))
+        }
+        unsafe extern "system" fn Append(this: *mut core::ffi::c_void, stop: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMGradientStopCollection", "Append", 8usize, |this| ((**(this as *const *const IXpsOMGradientStopCollection_Vtbl)).Append)(this, stop), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCount, GetAt, InsertAt, RemoveAt, SetAt, Append }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMGradientStopCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsOMImageBrush_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetImageResource(this: *mut core::ffi::c_void, imageresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMImageBrush", "GetImageResource", 18usize, |this| ((**(this as *const *const IXpsOMImageBrush_Vtbl)).GetImageResource)(this, imageresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetImageResource(this: *mut core::ffi::c_void, imageresource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMImageBrush", "SetImageResource", 19usize, |this| ((**(this as *const *const IXpsOMImageBrush_Vtbl)).SetImageResource)(this, imageresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColorProfileResource(this: *mut core::ffi::c_void, colorprofileresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMImageBrush", "GetColorProfileResource", 20usize, |this| ((**(this as *const *const IXpsOMImageBrush_Vtbl)).GetColorProfileResource)(this, colorprofileresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColorProfileResource(this: *mut core::ffi::c_void, colorprofileresource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMImageBrush", "SetColorProfileResource", 21usize, |this| ((**(this as *const *const IXpsOMImageBrush_Vtbl)).SetColorProfileResource)(this, colorprofileresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, imagebrush: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMImageBrush", "Clone", 22usize, |this| ((**(this as *const *const IXpsOMImageBrush_Vtbl)).Clone)(this, imagebrush), |result| Some(*result))
+        }
+        Self {
+            base__: <IXpsOMTileBrush_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetImageResource,
+            SetImageResource,
+            GetColorProfileResource,
+            SetColorProfileResource,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMImageBrush as windows_core::Interface>::IID || <IXpsOMTileBrush_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMImageResource_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetStream(this: *mut core::ffi::c_void, readerstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMImageResource", "GetStream", 5usize, |this| ((**(this as *const *const IXpsOMImageResource_Vtbl)).GetStream)(this, readerstream), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn SetContent(this: *mut core::ffi::c_void, sourcestream: *mut core::ffi::c_void, imagetype: XPS_IMAGE_TYPE, partname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMImageResource", "SetContent", 6usize, |this| ((**(this as *const *const IXpsOMImageResource_Vtbl)).SetContent)(this, sourcestream, imagetype, partname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetImageType(this: *mut core::ffi::c_void, imagetype: *mut XPS_IMAGE_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMImageResource", "GetImageType", 7usize, |this| ((**(this as *const *const IXpsOMImageResource_Vtbl)).GetImageType)(this, imagetype), |result| Some(*result))
+        }
+        Self {
+            base__: <IXpsOMResource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetStream: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            SetContent,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            SetContent: 0,
+            GetImageType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMImageResource as windows_core::Interface>::IID || <IXpsOMResource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMImageResourceCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMImageResourceCollection", "GetCount", 3usize, |this| ((**(this as *const *const IXpsOMImageResourceCollection_Vtbl)).GetCount)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, index: u32, object: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMImageResourceCollection", "GetAt", 4usize, |this| ((**(this as *const *const IXpsOMImageResourceCollection_Vtbl)).GetAt)(this, index, object), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertAt(this: *mut core::ffi::c_void, index: u32, object: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMImageResourceCollection", "InsertAt", 5usize, |this| ((**(this as *const *const IXpsOMImageResourceCollection_Vtbl)).InsertAt)(this, index, object), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAt(this: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMImageResourceCollection", "RemoveAt", 6usize, |this| ((**(this as *const *const IXpsOMImageResourceCollection_Vtbl)).RemoveAt)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAt(this: *mut core::ffi::c_void, index: u32, object: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMImageResourceCollection", "SetAt", 7usize, |this| ((**(this as *const *const IXpsOMImageResourceCollection_Vtbl)).SetAt)(this, index, object), |result| Some(*result))
+        }
+        unsafe extern "system" fn Append(this: *mut core::ffi::c_void, object: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMImageResourceCollection", "Append", 8usize, |this| ((**(this as *const *const IXpsOMImageResourceCollection_Vtbl)).Append)(this, object), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn GetByPartName(this: *mut core::ffi::c_void, partname: *mut core::ffi::c_void, part: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMImageResourceCollection", "GetByPartName", 9usize, |this| ((**(this as *const *const IXpsOMImageResourceCollection_Vtbl)).GetByPartName)(this, partname, part), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCount,
+            GetAt,
+            InsertAt,
+            RemoveAt,
+            SetAt,
+            Append,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            GetByPartName,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            GetByPartName: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMImageResourceCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsOMLinearGradientBrush_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStartPoint(this: *mut core::ffi::c_void, startpoint: *mut XPS_POINT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMLinearGradientBrush", "GetStartPoint", 17usize, |this| ((**(this as *const *const IXpsOMLinearGradientBrush_Vtbl)).GetStartPoint)(this, startpoint), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartPoint(this: *mut core::ffi::c_void, startpoint: *const XPS_POINT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMLinearGradientBrush", "SetStartPoint", 18usize, |this| ((**(this as *const *const IXpsOMLinearGradientBrush_Vtbl)).SetStartPoint)(this, startpoint), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEndPoint(this: *mut core::ffi::c_void, endpoint: *mut XPS_POINT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMLinearGradientBrush", "GetEndPoint", 19usize, |this| ((**(this as *const *const IXpsOMLinearGradientBrush_Vtbl)).GetEndPoint)(this, endpoint), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEndPoint(this: *mut core::ffi::c_void, endpoint: *const XPS_POINT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMLinearGradientBrush", "SetEndPoint", 20usize, |this| ((**(this as *const *const IXpsOMLinearGradientBrush_Vtbl)).SetEndPoint)(this, endpoint), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, lineargradientbrush: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMLinearGradientBrush", "Clone", 21usize, |this| ((**(this as *const *const IXpsOMLinearGradientBrush_Vtbl)).Clone)(this, lineargradientbrush), |result| Some(*result))
+        }
+        Self { base__: <IXpsOMGradientBrush_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStartPoint, SetStartPoint, GetEndPoint, SetEndPoint, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMLinearGradientBrush as windows_core::Interface>::IID || <IXpsOMGradientBrush_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsOMMatrixTransform_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMatrix(this: *mut core::ffi::c_void, matrix: *mut XPS_MATRIX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMMatrixTransform", "GetMatrix", 5usize, |this| ((**(this as *const *const IXpsOMMatrixTransform_Vtbl)).GetMatrix)(this, matrix), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMatrix(this: *mut core::ffi::c_void, matrix: *const XPS_MATRIX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMMatrixTransform", "SetMatrix", 6usize, |this| ((**(this as *const *const IXpsOMMatrixTransform_Vtbl)).SetMatrix)(this, matrix), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, matrixtransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMMatrixTransform", "Clone", 7usize, |this| ((**(this as *const *const IXpsOMMatrixTransform_Vtbl)).Clone)(this, matrixtransform), |result| Some(*result))
+        }
+        Self { base__: <IXpsOMShareable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMatrix, SetMatrix, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMMatrixTransform as windows_core::Interface>::IID || <IXpsOMShareable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsOMNameCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMNameCollection", "GetCount", 3usize, |this| ((**(this as *const *const IXpsOMNameCollection_Vtbl)).GetCount)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, index: u32, name: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMNameCollection", "GetAt", 4usize, |this| ((**(this as *const *const IXpsOMNameCollection_Vtbl)).GetAt)(this, index, name), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCount, GetAt }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMNameCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Security", feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMObjectFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreatePackage(this: *mut core::ffi::c_void, package: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreatePackage", 3usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreatePackage)(this, package), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePackageFromFile(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, reuseobjects: super::super::Foundation::BOOL, package: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreatePackageFromFile", 4usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreatePackageFromFile)(this, filename, reuseobjects, package), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreatePackageFromStream(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, reuseobjects: super::super::Foundation::BOOL, package: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreatePackageFromStream", 5usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreatePackageFromStream)(this, stream, reuseobjects, package), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn CreateStoryFragmentsResource(this: *mut core::ffi::c_void, acquiredstream: *mut core::ffi::c_void, parturi: *mut core::ffi::c_void, storyfragmentsresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreateStoryFragmentsResource", 6usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreateStoryFragmentsResource)(this, acquiredstream, parturi, storyfragmentsresource), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn CreateDocumentStructureResource(this: *mut core::ffi::c_void, acquiredstream: *mut core::ffi::c_void, parturi: *mut core::ffi::c_void, documentstructureresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreateDocumentStructureResource", 7usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreateDocumentStructureResource)(this, acquiredstream, parturi, documentstructureresource), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn CreateSignatureBlockResource(this: *mut core::ffi::c_void, acquiredstream: *mut core::ffi::c_void, parturi: *mut core::ffi::c_void, signatureblockresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreateSignatureBlockResource", 8usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreateSignatureBlockResource)(this, acquiredstream, parturi, signatureblockresource), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn CreateRemoteDictionaryResource(this: *mut core::ffi::c_void, dictionary: *mut core::ffi::c_void, parturi: *mut core::ffi::c_void, remotedictionaryresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreateRemoteDictionaryResource", 9usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreateRemoteDictionaryResource)(this, dictionary, parturi, remotedictionaryresource), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn CreateRemoteDictionaryResourceFromStream(this: *mut core::ffi::c_void, dictionarymarkupstream: *mut core::ffi::c_void, dictionaryparturi: *mut core::ffi::c_void, resources: *mut core::ffi::c_void, dictionaryresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreateRemoteDictionaryResourceFromStream", 10usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreateRemoteDictionaryResourceFromStream)(this, dictionarymarkupstream, dictionaryparturi, resources, dictionaryresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePartResources(this: *mut core::ffi::c_void, partresources: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreatePartResources", 11usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreatePartResources)(this, partresources), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn CreateDocumentSequence(this: *mut core::ffi::c_void, parturi: *mut core::ffi::c_void, documentsequence: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreateDocumentSequence", 12usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreateDocumentSequence)(this, parturi, documentsequence), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn CreateDocument(this: *mut core::ffi::c_void, parturi: *mut core::ffi::c_void, document: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreateDocument", 13usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreateDocument)(this, parturi, document), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePageReference(this: *mut core::ffi::c_void, advisorypagedimensions: *const XPS_SIZE, pagereference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreatePageReference", 14usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreatePageReference)(this, advisorypagedimensions, pagereference), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn CreatePage(this: *mut core::ffi::c_void, pagedimensions: *const XPS_SIZE, language: windows_core::PCWSTR, parturi: *mut core::ffi::c_void, page: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreatePage", 15usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreatePage)(this, pagedimensions, language, parturi, page), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn CreatePageFromStream(this: *mut core::ffi::c_void, pagemarkupstream: *mut core::ffi::c_void, parturi: *mut core::ffi::c_void, resources: *mut core::ffi::c_void, reuseobjects: super::super::Foundation::BOOL, page: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreatePageFromStream", 16usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreatePageFromStream)(this, pagemarkupstream, parturi, resources, reuseobjects, page), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCanvas(this: *mut core::ffi::c_void, canvas: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreateCanvas", 17usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreateCanvas)(this, canvas), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateGlyphs(this: *mut core::ffi::c_void, fontresource: *mut core::ffi::c_void, glyphs: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreateGlyphs", 18usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreateGlyphs)(this, fontresource, glyphs), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePath(this: *mut core::ffi::c_void, path: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreatePath", 19usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreatePath)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateGeometry(this: *mut core::ffi::c_void, geometry: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreateGeometry", 20usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreateGeometry)(this, geometry), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateGeometryFigure(this: *mut core::ffi::c_void, startpoint: *const XPS_POINT, figure: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreateGeometryFigure", 21usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreateGeometryFigure)(this, startpoint, figure), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateMatrixTransform(this: *mut core::ffi::c_void, matrix: *const XPS_MATRIX, transform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreateMatrixTransform", 22usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreateMatrixTransform)(this, matrix, transform), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSolidColorBrush(this: *mut core::ffi::c_void, color: *const XPS_COLOR, colorprofile: *mut core::ffi::c_void, solidcolorbrush: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreateSolidColorBrush", 23usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreateSolidColorBrush)(this, color, colorprofile, solidcolorbrush), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn CreateColorProfileResource(this: *mut core::ffi::c_void, acquiredstream: *mut core::ffi::c_void, parturi: *mut core::ffi::c_void, colorprofileresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreateColorProfileResource", 24usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreateColorProfileResource)(this, acquiredstream, parturi, colorprofileresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateImageBrush(this: *mut core::ffi::c_void, image: *mut core::ffi::c_void, viewbox: *const XPS_RECT, viewport: *const XPS_RECT, imagebrush: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreateImageBrush", 25usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreateImageBrush)(this, image, viewbox, viewport, imagebrush), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVisualBrush(this: *mut core::ffi::c_void, viewbox: *const XPS_RECT, viewport: *const XPS_RECT, visualbrush: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreateVisualBrush", 26usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreateVisualBrush)(this, viewbox, viewport, visualbrush), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn CreateImageResource(this: *mut core::ffi::c_void, acquiredstream: *mut core::ffi::c_void, contenttype: XPS_IMAGE_TYPE, parturi: *mut core::ffi::c_void, imageresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreateImageResource", 27usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreateImageResource)(this, acquiredstream, contenttype, parturi, imageresource), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn CreatePrintTicketResource(this: *mut core::ffi::c_void, acquiredstream: *mut core::ffi::c_void, parturi: *mut core::ffi::c_void, printticketresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreatePrintTicketResource", 28usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreatePrintTicketResource)(this, acquiredstream, parturi, printticketresource), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn CreateFontResource(this: *mut core::ffi::c_void, acquiredstream: *mut core::ffi::c_void, fontembedding: XPS_FONT_EMBEDDING, parturi: *mut core::ffi::c_void, isobfsourcestream: super::super::Foundation::BOOL, fontresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreateFontResource", 29usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreateFontResource)(this, acquiredstream, fontembedding, parturi, isobfsourcestream, fontresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateGradientStop(this: *mut core::ffi::c_void, color: *const XPS_COLOR, colorprofile: *mut core::ffi::c_void, offset: f32, gradientstop: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreateGradientStop", 30usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreateGradientStop)(this, color, colorprofile, offset, gradientstop), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateLinearGradientBrush(this: *mut core::ffi::c_void, gradstop1: *mut core::ffi::c_void, gradstop2: *mut core::ffi::c_void, startpoint: *const XPS_POINT, endpoint: *const XPS_POINT, lineargradientbrush: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreateLinearGradientBrush", 31usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreateLinearGradientBrush)(this, gradstop1, gradstop2, startpoint, endpoint, lineargradientbrush), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRadialGradientBrush(this: *mut core::ffi::c_void, gradstop1: *mut core::ffi::c_void, gradstop2: *mut core::ffi::c_void, centerpoint: *const XPS_POINT, gradientorigin: *const XPS_POINT, radiisizes: *const XPS_SIZE, radialgradientbrush: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreateRadialGradientBrush", 32usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreateRadialGradientBrush)(this, gradstop1, gradstop2, centerpoint, gradientorigin, radiisizes, radialgradientbrush), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn CreateCoreProperties(this: *mut core::ffi::c_void, parturi: *mut core::ffi::c_void, coreproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreateCoreProperties", 33usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreateCoreProperties)(this, parturi, coreproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDictionary(this: *mut core::ffi::c_void, dictionary: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreateDictionary", 34usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreateDictionary)(this, dictionary), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePartUriCollection(this: *mut core::ffi::c_void, parturicollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreatePartUriCollection", 35usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreatePartUriCollection)(this, parturicollection), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Security", feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn CreatePackageWriterOnFile(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, securityattributes: *const super::super::Security::SECURITY_ATTRIBUTES, flagsandattributes: u32, optimizemarkupsize: super::super::Foundation::BOOL, interleaving: XPS_INTERLEAVING, documentsequencepartname: *mut core::ffi::c_void, coreproperties: *mut core::ffi::c_void, packagethumbnail: *mut core::ffi::c_void, documentsequenceprintticket: *mut core::ffi::c_void, discardcontrolpartname: *mut core::ffi::c_void, packagewriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreatePackageWriterOnFile", 36usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreatePackageWriterOnFile)(this, filename, securityattributes, flagsandattributes, optimizemarkupsize, interleaving, documentsequencepartname, coreproperties, packagethumbnail, documentsequenceprintticket, discardcontrolpartname, packagewriter), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn CreatePackageWriterOnStream(this: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, optimizemarkupsize: super::super::Foundation::BOOL, interleaving: XPS_INTERLEAVING, documentsequencepartname: *mut core::ffi::c_void, coreproperties: *mut core::ffi::c_void, packagethumbnail: *mut core::ffi::c_void, documentsequenceprintticket: *mut core::ffi::c_void, discardcontrolpartname: *mut core::ffi::c_void, packagewriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreatePackageWriterOnStream", 37usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreatePackageWriterOnStream)(this, outputstream, optimizemarkupsize, interleaving, documentsequencepartname, coreproperties, packagethumbnail, documentsequenceprintticket, discardcontrolpartname, packagewriter), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn CreatePartUri(this: *mut core::ffi::c_void, uri: windows_core::PCWSTR, parturi: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreatePartUri", 38usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreatePartUri)(this, uri, parturi), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateReadOnlyStreamOnFile(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, stream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory", "CreateReadOnlyStreamOnFile", 39usize, |this| ((**(this as *const *const IXpsOMObjectFactory_Vtbl)).CreateReadOnlyStreamOnFile)(this, filename, stream), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreatePackage,
+            CreatePackageFromFile,
+            #[cfg(feature = "Win32_System_Com")]
+            CreatePackageFromStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreatePackageFromStream: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            CreateStoryFragmentsResource,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            CreateStoryFragmentsResource: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            CreateDocumentStructureResource,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            CreateDocumentStructureResource: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            CreateSignatureBlockResource,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            CreateSignatureBlockResource: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            CreateRemoteDictionaryResource,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            CreateRemoteDictionaryResource: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            CreateRemoteDictionaryResourceFromStream,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            CreateRemoteDictionaryResourceFromStream: 0,
+            CreatePartResources,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            CreateDocumentSequence,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            CreateDocumentSequence: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            CreateDocument,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            CreateDocument: 0,
+            CreatePageReference,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            CreatePage,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            CreatePage: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            CreatePageFromStream,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            CreatePageFromStream: 0,
+            CreateCanvas,
+            CreateGlyphs,
+            CreatePath,
+            CreateGeometry,
+            CreateGeometryFigure,
+            CreateMatrixTransform,
+            CreateSolidColorBrush,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            CreateColorProfileResource,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            CreateColorProfileResource: 0,
+            CreateImageBrush,
+            CreateVisualBrush,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            CreateImageResource,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            CreateImageResource: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            CreatePrintTicketResource,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            CreatePrintTicketResource: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            CreateFontResource,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            CreateFontResource: 0,
+            CreateGradientStop,
+            CreateLinearGradientBrush,
+            CreateRadialGradientBrush,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            CreateCoreProperties,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            CreateCoreProperties: 0,
+            CreateDictionary,
+            CreatePartUriCollection,
+            #[cfg(all(feature = "Win32_Security", feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            CreatePackageWriterOnFile,
+            #[cfg(not(all(feature = "Win32_Security", feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            CreatePackageWriterOnFile: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            CreatePackageWriterOnStream,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            CreatePackageWriterOnStream: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            CreatePartUri,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            CreatePartUri: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateReadOnlyStreamOnFile,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateReadOnlyStreamOnFile: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMObjectFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Security", feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMObjectFactory1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDocumentTypeFromFile(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, documenttype: *mut XPS_DOCUMENT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory1", "GetDocumentTypeFromFile", 40usize, |this| ((**(this as *const *const IXpsOMObjectFactory1_Vtbl)).GetDocumentTypeFromFile)(this, filename, documenttype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetDocumentTypeFromStream(this: *mut core::ffi::c_void, xpsdocumentstream: *mut core::ffi::c_void, documenttype: *mut XPS_DOCUMENT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory1", "GetDocumentTypeFromStream", 41usize, |this| ((**(this as *const *const IXpsOMObjectFactory1_Vtbl)).GetDocumentTypeFromStream)(this, xpsdocumentstream, documenttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertHDPhotoToJpegXR(this: *mut core::ffi::c_void, imageresource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory1", "ConvertHDPhotoToJpegXR", 42usize, |this| ((**(this as *const *const IXpsOMObjectFactory1_Vtbl)).ConvertHDPhotoToJpegXR)(this, imageresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertJpegXRToHDPhoto(this: *mut core::ffi::c_void, imageresource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory1", "ConvertJpegXRToHDPhoto", 43usize, |this| ((**(this as *const *const IXpsOMObjectFactory1_Vtbl)).ConvertJpegXRToHDPhoto)(this, imageresource), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Security", feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn CreatePackageWriterOnFile1(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, securityattributes: *const super::super::Security::SECURITY_ATTRIBUTES, flagsandattributes: u32, optimizemarkupsize: super::super::Foundation::BOOL, interleaving: XPS_INTERLEAVING, documentsequencepartname: *mut core::ffi::c_void, coreproperties: *mut core::ffi::c_void, packagethumbnail: *mut core::ffi::c_void, documentsequenceprintticket: *mut core::ffi::c_void, discardcontrolpartname: *mut core::ffi::c_void, documenttype: XPS_DOCUMENT_TYPE, packagewriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory1", "CreatePackageWriterOnFile1", 44usize, |this| ((**(this as *const *const IXpsOMObjectFactory1_Vtbl)).CreatePackageWriterOnFile1)(this, filename, securityattributes, flagsandattributes, optimizemarkupsize, interleaving, documentsequencepartname, coreproperties, packagethumbnail, documentsequenceprintticket, discardcontrolpartname, documenttype, packagewriter), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn CreatePackageWriterOnStream1(this: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, optimizemarkupsize: super::super::Foundation::BOOL, interleaving: XPS_INTERLEAVING, documentsequencepartname: *mut core::ffi::c_void, coreproperties: *mut core::ffi::c_void, packagethumbnail: *mut core::ffi::c_void, documentsequenceprintticket: *mut core::ffi::c_void, discardcontrolpartname: *mut core::ffi::c_void, documenttype: XPS_DOCUMENT_TYPE, packagewriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory1", "CreatePackageWriterOnStream1", 45usize, |this| ((**(this as *const *const IXpsOMObjectFactory1_Vtbl)).CreatePackageWriterOnStream1)(this, outputstream, optimizemarkupsize, interleaving, documentsequencepartname, coreproperties, packagethumbnail, documentsequenceprintticket, discardcontrolpartname, documenttype, packagewriter), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePackage1(this: *mut core::ffi::c_void, package: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory1", "CreatePackage1", 46usize, |this| ((**(this as *const *const IXpsOMObjectFactory1_Vtbl)).CreatePackage1)(this, package), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreatePackageFromStream1(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, reuseobjects: super::super::Foundation::BOOL, package: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory1", "CreatePackageFromStream1", 47usize, |this| ((**(this as *const *const IXpsOMObjectFactory1_Vtbl)).CreatePackageFromStream1)(this, stream, reuseobjects, package), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePackageFromFile1(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, reuseobjects: super::super::Foundation::BOOL, package: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory1", "CreatePackageFromFile1", 48usize, |this| ((**(this as *const *const IXpsOMObjectFactory1_Vtbl)).CreatePackageFromFile1)(this, filename, reuseobjects, package), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn CreatePage1(this: *mut core::ffi::c_void, pagedimensions: *const XPS_SIZE, language: windows_core::PCWSTR, parturi: *mut core::ffi::c_void, page: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory1", "CreatePage1", 49usize, |this| ((**(this as *const *const IXpsOMObjectFactory1_Vtbl)).CreatePage1)(this, pagedimensions, language, parturi, page), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn CreatePageFromStream1(this: *mut core::ffi::c_void, pagemarkupstream: *mut core::ffi::c_void, parturi: *mut core::ffi::c_void, resources: *mut core::ffi::c_void, reuseobjects: super::super::Foundation::BOOL, page: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory1", "CreatePageFromStream1", 50usize, |this| ((**(this as *const *const IXpsOMObjectFactory1_Vtbl)).CreatePageFromStream1)(this, pagemarkupstream, parturi, resources, reuseobjects, page), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn CreateRemoteDictionaryResourceFromStream1(this: *mut core::ffi::c_void, dictionarymarkupstream: *mut core::ffi::c_void, parturi: *mut core::ffi::c_void, resources: *mut core::ffi::c_void, dictionaryresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMObjectFactory1", "CreateRemoteDictionaryResourceFromStream1", 51usize, |this| ((**(this as *const *const IXpsOMObjectFactory1_Vtbl)).CreateRemoteDictionaryResourceFromStream1)(this, dictionarymarkupstream, parturi, resources, dictionaryresource), |result| Some(*result))
+        }
+        Self {
+            base__: <IXpsOMObjectFactory_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDocumentTypeFromFile,
+            #[cfg(feature = "Win32_System_Com")]
+            GetDocumentTypeFromStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetDocumentTypeFromStream: 0,
+            ConvertHDPhotoToJpegXR,
+            ConvertJpegXRToHDPhoto,
+            #[cfg(all(feature = "Win32_Security", feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            CreatePackageWriterOnFile1,
+            #[cfg(not(all(feature = "Win32_Security", feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            CreatePackageWriterOnFile1: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            CreatePackageWriterOnStream1,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            CreatePackageWriterOnStream1: 0,
+            CreatePackage1,
+            #[cfg(feature = "Win32_System_Com")]
+            CreatePackageFromStream1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreatePackageFromStream1: 0,
+            CreatePackageFromFile1,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            CreatePage1,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            CreatePage1: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            CreatePageFromStream1,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            CreatePageFromStream1: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            CreateRemoteDictionaryResourceFromStream1,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            CreateRemoteDictionaryResourceFromStream1: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMObjectFactory1 as windows_core::Interface>::IID || <IXpsOMObjectFactory_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Security", feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMPackage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDocumentSequence(this: *mut core::ffi::c_void, documentsequence: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPackage", "GetDocumentSequence", 3usize, |this| ((**(this as *const *const IXpsOMPackage_Vtbl)).GetDocumentSequence)(this, documentsequence), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDocumentSequence(this: *mut core::ffi::c_void, documentsequence: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPackage", "SetDocumentSequence", 4usize, |this| ((**(this as *const *const IXpsOMPackage_Vtbl)).SetDocumentSequence)(this, documentsequence), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCoreProperties(this: *mut core::ffi::c_void, coreproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPackage", "GetCoreProperties", 5usize, |this| ((**(this as *const *const IXpsOMPackage_Vtbl)).GetCoreProperties)(this, coreproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCoreProperties(this: *mut core::ffi::c_void, coreproperties: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPackage", "SetCoreProperties", 6usize, |this| ((**(this as *const *const IXpsOMPackage_Vtbl)).SetCoreProperties)(this, coreproperties), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn GetDiscardControlPartName(this: *mut core::ffi::c_void, discardcontrolparturi: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPackage", "GetDiscardControlPartName", 7usize, |this| ((**(this as *const *const IXpsOMPackage_Vtbl)).GetDiscardControlPartName)(this, discardcontrolparturi), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn SetDiscardControlPartName(this: *mut core::ffi::c_void, discardcontrolparturi: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPackage", "SetDiscardControlPartName", 8usize, |this| ((**(this as *const *const IXpsOMPackage_Vtbl)).SetDiscardControlPartName)(this, discardcontrolparturi), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetThumbnailResource(this: *mut core::ffi::c_void, imageresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPackage", "GetThumbnailResource", 9usize, |this| ((**(this as *const *const IXpsOMPackage_Vtbl)).GetThumbnailResource)(this, imageresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetThumbnailResource(this: *mut core::ffi::c_void, imageresource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPackage", "SetThumbnailResource", 10usize, |this| ((**(this as *const *const IXpsOMPackage_Vtbl)).SetThumbnailResource)(this, imageresource), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Security")]
+        unsafe extern "system" fn WriteToFile(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, securityattributes: *const super::super::Security::SECURITY_ATTRIBUTES, flagsandattributes: u32, optimizemarkupsize: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPackage", "WriteToFile", 11usize, |this| ((**(this as *const *const IXpsOMPackage_Vtbl)).WriteToFile)(this, filename, securityattributes, flagsandattributes, optimizemarkupsize), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn WriteToStream(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, optimizemarkupsize: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPackage", "WriteToStream", 12usize, |this| ((**(this as *const *const IXpsOMPackage_Vtbl)).WriteToStream)(this, stream, optimizemarkupsize), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDocumentSequence,
+            SetDocumentSequence,
+            GetCoreProperties,
+            SetCoreProperties,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            GetDiscardControlPartName,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            GetDiscardControlPartName: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            SetDiscardControlPartName,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            SetDiscardControlPartName: 0,
+            GetThumbnailResource,
+            SetThumbnailResource,
+            #[cfg(feature = "Win32_Security")]
+            WriteToFile,
+            #[cfg(not(feature = "Win32_Security"))]
+            WriteToFile: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            WriteToStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            WriteToStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMPackage as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Security", feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMPackage1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDocumentType(this: *mut core::ffi::c_void, documenttype: *mut XPS_DOCUMENT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPackage1", "GetDocumentType", 13usize, |this| ((**(this as *const *const IXpsOMPackage1_Vtbl)).GetDocumentType)(this, documenttype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Security")]
+        unsafe extern "system" fn WriteToFile1(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, securityattributes: *const super::super::Security::SECURITY_ATTRIBUTES, flagsandattributes: u32, optimizemarkupsize: super::super::Foundation::BOOL, documenttype: XPS_DOCUMENT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPackage1", "WriteToFile1", 14usize, |this| ((**(this as *const *const IXpsOMPackage1_Vtbl)).WriteToFile1)(this, filename, securityattributes, flagsandattributes, optimizemarkupsize, documenttype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn WriteToStream1(this: *mut core::ffi::c_void, outputstream: *mut core::ffi::c_void, optimizemarkupsize: super::super::Foundation::BOOL, documenttype: XPS_DOCUMENT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPackage1", "WriteToStream1", 15usize, |this| ((**(this as *const *const IXpsOMPackage1_Vtbl)).WriteToStream1)(this, outputstream, optimizemarkupsize, documenttype), |result| Some(*result))
+        }
+        Self {
+            base__: <IXpsOMPackage_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDocumentType,
+            #[cfg(feature = "Win32_Security")]
+            WriteToFile1,
+            #[cfg(not(feature = "Win32_Security"))]
+            WriteToFile1: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            WriteToStream1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            WriteToStream1: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMPackage1 as windows_core::Interface>::IID || <IXpsOMPackage_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMPackageTarget_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn CreateXpsOMPackageWriter(this: *mut core::ffi::c_void, documentsequencepartname: *mut core::ffi::c_void, documentsequenceprintticket: *mut core::ffi::c_void, discardcontrolpartname: *mut core::ffi::c_void, packagewriter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPackageTarget", "CreateXpsOMPackageWriter", 3usize, |this| ((**(this as *const *const IXpsOMPackageTarget_Vtbl)).CreateXpsOMPackageWriter)(this, documentsequencepartname, documentsequenceprintticket, discardcontrolpartname, packagewriter), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            CreateXpsOMPackageWriter,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            CreateXpsOMPackageWriter: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMPackageTarget as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMPackageWriter_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn StartNewDocument(this: *mut core::ffi::c_void, documentpartname: *mut core::ffi::c_void, documentprintticket: *mut core::ffi::c_void, documentstructure: *mut core::ffi::c_void, signatureblockresources: *mut core::ffi::c_void, restrictedfonts: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPackageWriter", "StartNewDocument", 3usize, |this| ((**(this as *const *const IXpsOMPackageWriter_Vtbl)).StartNewDocument)(this, documentpartname, documentprintticket, documentstructure, signatureblockresources, restrictedfonts), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPage(this: *mut core::ffi::c_void, page: *mut core::ffi::c_void, advisorypagedimensions: *const XPS_SIZE, discardableresourceparts: *mut core::ffi::c_void, storyfragments: *mut core::ffi::c_void, pageprintticket: *mut core::ffi::c_void, pagethumbnail: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPackageWriter", "AddPage", 4usize, |this| ((**(this as *const *const IXpsOMPackageWriter_Vtbl)).AddPage)(this, page, advisorypagedimensions, discardableresourceparts, storyfragments, pageprintticket, pagethumbnail), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddResource(this: *mut core::ffi::c_void, resource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPackageWriter", "AddResource", 5usize, |this| ((**(this as *const *const IXpsOMPackageWriter_Vtbl)).AddResource)(this, resource), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPackageWriter", "Close", 6usize, |this| ((**(this as *const *const IXpsOMPackageWriter_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsClosed(this: *mut core::ffi::c_void, isclosed: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPackageWriter", "IsClosed", 7usize, |this| ((**(this as *const *const IXpsOMPackageWriter_Vtbl)).IsClosed)(this, isclosed), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            StartNewDocument,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            StartNewDocument: 0,
+            AddPage,
+            AddResource,
+            Close,
+            IsClosed,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMPackageWriter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMPackageWriter3D_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn AddModelTexture(this: *mut core::ffi::c_void, texturepartname: *mut core::ffi::c_void, texturedata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPackageWriter3D", "AddModelTexture", 8usize, |this| ((**(this as *const *const IXpsOMPackageWriter3D_Vtbl)).AddModelTexture)(this, texturepartname, texturedata), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn SetModelPrintTicket(this: *mut core::ffi::c_void, printticketpartname: *mut core::ffi::c_void, printticketdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPackageWriter3D", "SetModelPrintTicket", 9usize, |this| ((**(this as *const *const IXpsOMPackageWriter3D_Vtbl)).SetModelPrintTicket)(this, printticketpartname, printticketdata), |result| Some(*result))
+        }
+        Self {
+            base__: <IXpsOMPackageWriter_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            AddModelTexture,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            AddModelTexture: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            SetModelPrintTicket,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            SetModelPrintTicket: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMPackageWriter3D as windows_core::Interface>::IID || <IXpsOMPackageWriter_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMPage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOwner(this: *mut core::ffi::c_void, pagereference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPage", "GetOwner", 5usize, |this| ((**(this as *const *const IXpsOMPage_Vtbl)).GetOwner)(this, pagereference), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVisuals(this: *mut core::ffi::c_void, visuals: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPage", "GetVisuals", 6usize, |this| ((**(this as *const *const IXpsOMPage_Vtbl)).GetVisuals)(this, visuals), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPageDimensions(this: *mut core::ffi::c_void, pagedimensions: *mut XPS_SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPage", "GetPageDimensions", 7usize, |this| ((**(this as *const *const IXpsOMPage_Vtbl)).GetPageDimensions)(this, pagedimensions), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPageDimensions(this: *mut core::ffi::c_void, pagedimensions: *const XPS_SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPage", "SetPageDimensions", 8usize, |this| ((**(this as *const *const IXpsOMPage_Vtbl)).SetPageDimensions)(this, pagedimensions), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContentBox(this: *mut core::ffi::c_void, contentbox: *mut XPS_RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPage", "GetContentBox", 9usize, |this| ((**(this as *const *const IXpsOMPage_Vtbl)).GetContentBox)(this, contentbox), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContentBox(this: *mut core::ffi::c_void, contentbox: *const XPS_RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPage", "SetContentBox", 10usize, |this| ((**(this as *const *const IXpsOMPage_Vtbl)).SetContentBox)(this, contentbox), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBleedBox(this: *mut core::ffi::c_void, bleedbox: *mut XPS_RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPage", "GetBleedBox", 11usize, |this| ((**(this as *const *const IXpsOMPage_Vtbl)).GetBleedBox)(this, bleedbox), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBleedBox(this: *mut core::ffi::c_void, bleedbox: *const XPS_RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPage", "SetBleedBox", 12usize, |this| ((**(this as *const *const IXpsOMPage_Vtbl)).SetBleedBox)(this, bleedbox), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLanguage(this: *mut core::ffi::c_void, language: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPage", "GetLanguage", 13usize, |this| ((**(this as *const *const IXpsOMPage_Vtbl)).GetLanguage)(this, language), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLanguage(this: *mut core::ffi::c_void, language: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPage", "SetLanguage", 14usize, |this| ((**(this as *const *const IXpsOMPage_Vtbl)).SetLanguage)(this, language), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, name: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPage", "GetName", 15usize, |this| ((**(this as *const *const IXpsOMPage_Vtbl)).GetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, name: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPage", "SetName", 16usize, |this| ((**(this as *const *const IXpsOMPage_Vtbl)).SetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIsHyperlinkTarget(this: *mut core::ffi::c_void, ishyperlinktarget: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPage", "GetIsHyperlinkTarget", 17usize, |this| ((**(this as *const *const IXpsOMPage_Vtbl)).GetIsHyperlinkTarget)(this, ishyperlinktarget), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsHyperlinkTarget(this: *mut core::ffi::c_void, ishyperlinktarget: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPage", "SetIsHyperlinkTarget", 18usize, |this| ((**(this as *const *const IXpsOMPage_Vtbl)).SetIsHyperlinkTarget)(this, ishyperlinktarget), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDictionary(this: *mut core::ffi::c_void, resourcedictionary: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPage", "GetDictionary", 19usize, |this| ((**(this as *const *const IXpsOMPage_Vtbl)).GetDictionary)(this, resourcedictionary), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDictionaryLocal(this: *mut core::ffi::c_void, resourcedictionary: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPage", "GetDictionaryLocal", 20usize, |this| ((**(this as *const *const IXpsOMPage_Vtbl)).GetDictionaryLocal)(this, resourcedictionary), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDictionaryLocal(this: *mut core::ffi::c_void, resourcedictionary: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPage", "SetDictionaryLocal", 21usize, |this| ((**(this as *const *const IXpsOMPage_Vtbl)).SetDictionaryLocal)(this, resourcedictionary), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDictionaryResource(this: *mut core::ffi::c_void, remotedictionaryresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPage", "GetDictionaryResource", 22usize, |this| ((**(this as *const *const IXpsOMPage_Vtbl)).GetDictionaryResource)(this, remotedictionaryresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDictionaryResource(this: *mut core::ffi::c_void, remotedictionaryresource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPage", "SetDictionaryResource", 23usize, |this| ((**(this as *const *const IXpsOMPage_Vtbl)).SetDictionaryResource)(this, remotedictionaryresource), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Write(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, optimizemarkupsize: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPage", "Write", 24usize, |this| ((**(this as *const *const IXpsOMPage_Vtbl)).Write)(this, stream, optimizemarkupsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GenerateUnusedLookupKey(this: *mut core::ffi::c_void, r#type: XPS_OBJECT_TYPE, key: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPage", "GenerateUnusedLookupKey", 25usize, |this| ((**(this as *const *const IXpsOMPage_Vtbl)).GenerateUnusedLookupKey)(this, r#type, key), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, page: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPage", "Clone", 26usize, |this| ((**(this as *const *const IXpsOMPage_Vtbl)).Clone)(this, page), |result| Some(*result))
+        }
+        Self {
+            base__: <IXpsOMPart_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetOwner,
+            GetVisuals,
+            GetPageDimensions,
+            SetPageDimensions,
+            GetContentBox,
+            SetContentBox,
+            GetBleedBox,
+            SetBleedBox,
+            GetLanguage,
+            SetLanguage,
+            GetName,
+            SetName,
+            GetIsHyperlinkTarget,
+            SetIsHyperlinkTarget,
+            GetDictionary,
+            GetDictionaryLocal,
+            SetDictionaryLocal,
+            GetDictionaryResource,
+            SetDictionaryResource,
+            #[cfg(feature = "Win32_System_Com")]
+            Write,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Write: 0,
+            GenerateUnusedLookupKey,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMPage as windows_core::Interface>::IID || <IXpsOMPart_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMPage1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDocumentType(this: *mut core::ffi::c_void, documenttype: *mut XPS_DOCUMENT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPage1", "GetDocumentType", 27usize, |this| ((**(this as *const *const IXpsOMPage1_Vtbl)).GetDocumentType)(this, documenttype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Write1(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, optimizemarkupsize: super::super::Foundation::BOOL, documenttype: XPS_DOCUMENT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPage1", "Write1", 28usize, |this| ((**(this as *const *const IXpsOMPage1_Vtbl)).Write1)(this, stream, optimizemarkupsize, documenttype), |result| Some(*result))
+        }
+        Self {
+            base__: <IXpsOMPage_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDocumentType,
+            #[cfg(feature = "Win32_System_Com")]
+            Write1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Write1: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMPage1 as windows_core::Interface>::IID || <IXpsOMPage_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsOMPageReference_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOwner(this: *mut core::ffi::c_void, document: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPageReference", "GetOwner", 3usize, |this| ((**(this as *const *const IXpsOMPageReference_Vtbl)).GetOwner)(this, document), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPage(this: *mut core::ffi::c_void, page: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPageReference", "GetPage", 4usize, |this| ((**(this as *const *const IXpsOMPageReference_Vtbl)).GetPage)(this, page), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPage(this: *mut core::ffi::c_void, page: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPageReference", "SetPage", 5usize, |this| ((**(this as *const *const IXpsOMPageReference_Vtbl)).SetPage)(this, page), |result| Some(*result))
+        }
+        unsafe extern "system" fn DiscardPage(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPageReference", "DiscardPage", 6usize, |this| ((**(this as *const *const IXpsOMPageReference_Vtbl)).DiscardPage)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPageLoaded(this: *mut core::ffi::c_void, ispageloaded: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPageReference", "IsPageLoaded", 7usize, |this| ((**(this as *const *const IXpsOMPageReference_Vtbl)).IsPageLoaded)(this, ispageloaded), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAdvisoryPageDimensions(this: *mut core::ffi::c_void, pagedimensions: *mut XPS_SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPageReference", "GetAdvisoryPageDimensions", 8usize, |this| ((**(this as *const *const IXpsOMPageReference_Vtbl)).GetAdvisoryPageDimensions)(this, pagedimensions), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAdvisoryPageDimensions(this: *mut core::ffi::c_void, pagedimensions: *const XPS_SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPageReference", "SetAdvisoryPageDimensions", 9usize, |this| ((**(this as *const *const IXpsOMPageReference_Vtbl)).SetAdvisoryPageDimensions)(this, pagedimensions), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStoryFragmentsResource(this: *mut core::ffi::c_void, storyfragmentsresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPageReference", "GetStoryFragmentsResource", 10usize, |this| ((**(this as *const *const IXpsOMPageReference_Vtbl)).GetStoryFragmentsResource)(this, storyfragmentsresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStoryFragmentsResource(this: *mut core::ffi::c_void, storyfragmentsresource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPageReference", "SetStoryFragmentsResource", 11usize, |this| ((**(this as *const *const IXpsOMPageReference_Vtbl)).SetStoryFragmentsResource)(this, storyfragmentsresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrintTicketResource(this: *mut core::ffi::c_void, printticketresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPageReference", "GetPrintTicketResource", 12usize, |this| ((**(this as *const *const IXpsOMPageReference_Vtbl)).GetPrintTicketResource)(this, printticketresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrintTicketResource(this: *mut core::ffi::c_void, printticketresource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPageReference", "SetPrintTicketResource", 13usize, |this| ((**(this as *const *const IXpsOMPageReference_Vtbl)).SetPrintTicketResource)(this, printticketresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetThumbnailResource(this: *mut core::ffi::c_void, imageresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPageReference", "GetThumbnailResource", 14usize, |this| ((**(this as *const *const IXpsOMPageReference_Vtbl)).GetThumbnailResource)(this, imageresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetThumbnailResource(this: *mut core::ffi::c_void, imageresource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPageReference", "SetThumbnailResource", 15usize, |this| ((**(this as *const *const IXpsOMPageReference_Vtbl)).SetThumbnailResource)(this, imageresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn CollectLinkTargets(this: *mut core::ffi::c_void, linktargets: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPageReference", "CollectLinkTargets", 16usize, |this| ((**(this as *const *const IXpsOMPageReference_Vtbl)).CollectLinkTargets)(this, linktargets), |result| Some(*result))
+        }
+        unsafe extern "system" fn CollectPartResources(this: *mut core::ffi::c_void, partresources: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPageReference", "CollectPartResources", 17usize, |this| ((**(this as *const *const IXpsOMPageReference_Vtbl)).CollectPartResources)(this, partresources), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasRestrictedFonts(this: *mut core::ffi::c_void, restrictedfonts: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPageReference", "HasRestrictedFonts", 18usize, |this| ((**(this as *const *const IXpsOMPageReference_Vtbl)).HasRestrictedFonts)(this, restrictedfonts), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, pagereference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPageReference", "Clone", 19usize, |this| ((**(this as *const *const IXpsOMPageReference_Vtbl)).Clone)(this, pagereference), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetOwner,
+            GetPage,
+            SetPage,
+            DiscardPage,
+            IsPageLoaded,
+            GetAdvisoryPageDimensions,
+            SetAdvisoryPageDimensions,
+            GetStoryFragmentsResource,
+            SetStoryFragmentsResource,
+            GetPrintTicketResource,
+            SetPrintTicketResource,
+            GetThumbnailResource,
+            SetThumbnailResource,
+            CollectLinkTargets,
+            CollectPartResources,
+            HasRestrictedFonts,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMPageReference as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsOMPageReferenceCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPageReferenceCollection", "GetCount", 3usize, |this| ((**(this as *const *const IXpsOMPageReferenceCollection_Vtbl)).GetCount)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, index: u32, pagereference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPageReferenceCollection", "GetAt", 4usize, |this| ((**(this as *const *const IXpsOMPageReferenceCollection_Vtbl)).GetAt)(this, index, pagereference), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertAt(this: *mut core::ffi::c_void, index: u32, pagereference: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPageReferenceCollection", "InsertAt", 5usize, |this| ((**(this as *const *const IXpsOMPageReferenceCollection_Vtbl)).InsertAt)(this, index, pagereference), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAt(this: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPageReferenceCollection", "RemoveAt", 6usize, |this| ((**(this as *const *const IXpsOMPageReferenceCollection_Vtbl)).RemoveAt)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAt(this: *mut core::ffi::c_void, index: u32, pagereference: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPageReferenceCollection", "SetAt", 7usize, |this| ((**(this as *const *const IXpsOMPageReferenceCollection_Vtbl)).SetAt)(this, index, pagereference), |result| Some(*result))
+        }
+        unsafe extern "system" fn Append(this: *mut core::ffi::c_void, pagereference: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPageReferenceCollection", "Append", 8usize, |this| ((**(this as *const *const IXpsOMPageReferenceCollection_Vtbl)).Append)(this, pagereference), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCount, GetAt, InsertAt, RemoveAt, SetAt, Append }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMPageReferenceCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMPart_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn GetPartName(this: *mut core::ffi::c_void, parturi: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPart", "GetPartName", 3usize, |this| ((**(this as *const *const IXpsOMPart_Vtbl)).GetPartName)(this, parturi), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn SetPartName(this: *mut core::ffi::c_void, parturi: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPart", "SetPartName", 4usize, |this| ((**(this as *const *const IXpsOMPart_Vtbl)).SetPartName)(this, parturi), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            GetPartName,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            GetPartName: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            SetPartName,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            SetPartName: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMPart as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsOMPartResources_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFontResources(this: *mut core::ffi::c_void, fontresources: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPartResources", "GetFontResources", 3usize, |this| ((**(this as *const *const IXpsOMPartResources_Vtbl)).GetFontResources)(this, fontresources), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetImageResources(this: *mut core::ffi::c_void, imageresources: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPartResources", "GetImageResources", 4usize, |this| ((**(this as *const *const IXpsOMPartResources_Vtbl)).GetImageResources)(this, imageresources), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColorProfileResources(this: *mut core::ffi::c_void, colorprofileresources: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPartResources", "GetColorProfileResources", 5usize, |this| ((**(this as *const *const IXpsOMPartResources_Vtbl)).GetColorProfileResources)(this, colorprofileresources), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRemoteDictionaryResources(this: *mut core::ffi::c_void, dictionaryresources: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPartResources", "GetRemoteDictionaryResources", 6usize, |this| ((**(this as *const *const IXpsOMPartResources_Vtbl)).GetRemoteDictionaryResources)(this, dictionaryresources), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetFontResources,
+            GetImageResources,
+            GetColorProfileResources,
+            GetRemoteDictionaryResources,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMPartResources as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMPartUriCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPartUriCollection", "GetCount", 3usize, |this| ((**(this as *const *const IXpsOMPartUriCollection_Vtbl)).GetCount)(this, count), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, index: u32, parturi: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPartUriCollection", "GetAt", 4usize, |this| ((**(this as *const *const IXpsOMPartUriCollection_Vtbl)).GetAt)(this, index, parturi), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn InsertAt(this: *mut core::ffi::c_void, index: u32, parturi: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPartUriCollection", "InsertAt", 5usize, |this| ((**(this as *const *const IXpsOMPartUriCollection_Vtbl)).InsertAt)(this, index, parturi), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAt(this: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPartUriCollection", "RemoveAt", 6usize, |this| ((**(this as *const *const IXpsOMPartUriCollection_Vtbl)).RemoveAt)(this, index), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn SetAt(this: *mut core::ffi::c_void, index: u32, parturi: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPartUriCollection", "SetAt", 7usize, |this| ((**(this as *const *const IXpsOMPartUriCollection_Vtbl)).SetAt)(this, index, parturi), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn Append(this: *mut core::ffi::c_void, parturi: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPartUriCollection", "Append", 8usize, |this| ((**(this as *const *const IXpsOMPartUriCollection_Vtbl)).Append)(this, parturi), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCount,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            GetAt,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            GetAt: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            InsertAt,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            InsertAt: 0,
+            RemoveAt,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            SetAt,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            SetAt: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            Append,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            Append: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMPartUriCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXpsOMPath_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetGeometry(this: *mut core::ffi::c_void, geometry: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "GetGeometry", 30usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).GetGeometry)(this, geometry), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGeometryLocal(this: *mut core::ffi::c_void, geometry: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "GetGeometryLocal", 31usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).GetGeometryLocal)(this, geometry), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGeometryLocal(this: *mut core::ffi::c_void, geometry: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "SetGeometryLocal", 32usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).SetGeometryLocal)(this, geometry), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGeometryLookup(this: *mut core::ffi::c_void, lookup: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "GetGeometryLookup", 33usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).GetGeometryLookup)(this, lookup), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGeometryLookup(this: *mut core::ffi::c_void, lookup: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "SetGeometryLookup", 34usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).SetGeometryLookup)(this, lookup), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAccessibilityShortDescription(this: *mut core::ffi::c_void, shortdescription: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "GetAccessibilityShortDescription", 35usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).GetAccessibilityShortDescription)(this, shortdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAccessibilityShortDescription(this: *mut core::ffi::c_void, shortdescription: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "SetAccessibilityShortDescription", 36usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).SetAccessibilityShortDescription)(this, shortdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAccessibilityLongDescription(this: *mut core::ffi::c_void, longdescription: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "GetAccessibilityLongDescription", 37usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).GetAccessibilityLongDescription)(this, longdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAccessibilityLongDescription(this: *mut core::ffi::c_void, longdescription: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "SetAccessibilityLongDescription", 38usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).SetAccessibilityLongDescription)(this, longdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSnapsToPixels(this: *mut core::ffi::c_void, snapstopixels: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "GetSnapsToPixels", 39usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).GetSnapsToPixels)(this, snapstopixels), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSnapsToPixels(this: *mut core::ffi::c_void, snapstopixels: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "SetSnapsToPixels", 40usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).SetSnapsToPixels)(this, snapstopixels), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStrokeBrush(this: *mut core::ffi::c_void, brush: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "GetStrokeBrush", 41usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).GetStrokeBrush)(this, brush), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStrokeBrushLocal(this: *mut core::ffi::c_void, brush: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "GetStrokeBrushLocal", 42usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).GetStrokeBrushLocal)(this, brush), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrokeBrushLocal(this: *mut core::ffi::c_void, brush: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "SetStrokeBrushLocal", 43usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).SetStrokeBrushLocal)(this, brush), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStrokeBrushLookup(this: *mut core::ffi::c_void, lookup: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "GetStrokeBrushLookup", 44usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).GetStrokeBrushLookup)(this, lookup), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrokeBrushLookup(this: *mut core::ffi::c_void, lookup: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "SetStrokeBrushLookup", 45usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).SetStrokeBrushLookup)(this, lookup), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStrokeDashes(this: *mut core::ffi::c_void, strokedashes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "GetStrokeDashes", 46usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).GetStrokeDashes)(this, strokedashes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStrokeDashCap(this: *mut core::ffi::c_void, strokedashcap: *mut XPS_DASH_CAP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "GetStrokeDashCap", 47usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).GetStrokeDashCap)(this, strokedashcap), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrokeDashCap(this: *mut core::ffi::c_void, strokedashcap: XPS_DASH_CAP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "SetStrokeDashCap", 48usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).SetStrokeDashCap)(this, strokedashcap), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStrokeDashOffset(this: *mut core::ffi::c_void, strokedashoffset: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "GetStrokeDashOffset", 49usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).GetStrokeDashOffset)(this, strokedashoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrokeDashOffset(this: *mut core::ffi::c_void, strokedashoffset: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "SetStrokeDashOffset", 50usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).SetStrokeDashOffset)(this, strokedashoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStrokeStartLineCap(this: *mut core::ffi::c_void, strokestartlinecap: *mut XPS_LINE_CAP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "GetStrokeStartLineCap", 51usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).GetStrokeStartLineCap)(this, strokestartlinecap), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrokeStartLineCap(this: *mut core::ffi::c_void, strokestartlinecap: XPS_LINE_CAP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "SetStrokeStartLineCap", 52usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).SetStrokeStartLineCap)(this, strokestartlinecap), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStrokeEndLineCap(this: *mut core::ffi::c_void, strokeendlinecap: *mut XPS_LINE_CAP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "GetStrokeEndLineCap", 53usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).GetStrokeEndLineCap)(this, strokeendlinecap), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrokeEndLineCap(this: *mut core::ffi::c_void, strokeendlinecap: XPS_LINE_CAP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "SetStrokeEndLineCap", 54usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).SetStrokeEndLineCap)(this, strokeendlinecap), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStrokeLineJoin(this: *mut core::ffi::c_void, strokelinejoin: *mut XPS_LINE_JOIN) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "GetStrokeLineJoin", 55usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).GetStrokeLineJoin)(this, strokelinejoin), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrokeLineJoin(this: *mut core::ffi::c_void, strokelinejoin: XPS_LINE_JOIN) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "SetStrokeLineJoin", 56usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).SetStrokeLineJoin)(this, strokelinejoin), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStrokeMiterLimit(this: *mut core::ffi::c_void, strokemiterlimit: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "GetStrokeMiterLimit", 57usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).GetStrokeMiterLimit)(this, strokemiterlimit), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrokeMiterLimit(this: *mut core::ffi::c_void, strokemiterlimit: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "SetStrokeMiterLimit", 58usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).SetStrokeMiterLimit)(this, strokemiterlimit), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStrokeThickness(this: *mut core::ffi::c_void, strokethickness: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "GetStrokeThickness", 59usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).GetStrokeThickness)(this, strokethickness), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrokeThickness(this: *mut core::ffi::c_void, strokethickness: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "SetStrokeThickness", 60usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).SetStrokeThickness)(this, strokethickness), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFillBrush(this: *mut core::ffi::c_void, brush: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "GetFillBrush", 61usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).GetFillBrush)(this, brush), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFillBrushLocal(this: *mut core::ffi::c_void, brush: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "GetFillBrushLocal", 62usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).GetFillBrushLocal)(this, brush), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFillBrushLocal(this: *mut core::ffi::c_void, brush: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "SetFillBrushLocal", 63usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).SetFillBrushLocal)(this, brush), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFillBrushLookup(this: *mut core::ffi::c_void, lookup: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "GetFillBrushLookup", 64usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).GetFillBrushLookup)(this, lookup), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFillBrushLookup(this: *mut core::ffi::c_void, lookup: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "SetFillBrushLookup", 65usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).SetFillBrushLookup)(this, lookup), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, path: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPath", "Clone", 66usize, |this| ((**(this as *const *const IXpsOMPath_Vtbl)).Clone)(this, path), |result| Some(*result))
+        }
+        Self {
+            base__: <IXpsOMVisual_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetGeometry,
+            GetGeometryLocal,
+            SetGeometryLocal,
+            GetGeometryLookup,
+            SetGeometryLookup,
+            GetAccessibilityShortDescription,
+            SetAccessibilityShortDescription,
+            GetAccessibilityLongDescription,
+            SetAccessibilityLongDescription,
+            GetSnapsToPixels,
+            SetSnapsToPixels,
+            GetStrokeBrush,
+            GetStrokeBrushLocal,
+            SetStrokeBrushLocal,
+            GetStrokeBrushLookup,
+            SetStrokeBrushLookup,
+            GetStrokeDashes,
+            GetStrokeDashCap,
+            SetStrokeDashCap,
+            GetStrokeDashOffset,
+            SetStrokeDashOffset,
+            GetStrokeStartLineCap,
+            SetStrokeStartLineCap,
+            GetStrokeEndLineCap,
+            SetStrokeEndLineCap,
+            GetStrokeLineJoin,
+            SetStrokeLineJoin,
+            GetStrokeMiterLimit,
+            SetStrokeMiterLimit,
+            GetStrokeThickness,
+            SetStrokeThickness,
+            GetFillBrush,
+            GetFillBrushLocal,
+            SetFillBrushLocal,
+            GetFillBrushLookup,
+            SetFillBrushLookup,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMPath as windows_core::Interface>::IID || <IXpsOMVisual_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMPrintTicketResource_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetStream(this: *mut core::ffi::c_void, stream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPrintTicketResource", "GetStream", 5usize, |this| ((**(this as *const *const IXpsOMPrintTicketResource_Vtbl)).GetStream)(this, stream), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn SetContent(this: *mut core::ffi::c_void, sourcestream: *mut core::ffi::c_void, partname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMPrintTicketResource", "SetContent", 6usize, |this| ((**(this as *const *const IXpsOMPrintTicketResource_Vtbl)).SetContent)(this, sourcestream, partname), |result| Some(*result))
+        }
+        Self {
+            base__: <IXpsOMResource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetStream: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            SetContent,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            SetContent: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMPrintTicketResource as windows_core::Interface>::IID || <IXpsOMResource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsOMRadialGradientBrush_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCenter(this: *mut core::ffi::c_void, center: *mut XPS_POINT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMRadialGradientBrush", "GetCenter", 17usize, |this| ((**(this as *const *const IXpsOMRadialGradientBrush_Vtbl)).GetCenter)(this, center), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCenter(this: *mut core::ffi::c_void, center: *const XPS_POINT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMRadialGradientBrush", "SetCenter", 18usize, |this| ((**(this as *const *const IXpsOMRadialGradientBrush_Vtbl)).SetCenter)(this, center), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRadiiSizes(this: *mut core::ffi::c_void, radiisizes: *mut XPS_SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMRadialGradientBrush", "GetRadiiSizes", 19usize, |this| ((**(this as *const *const IXpsOMRadialGradientBrush_Vtbl)).GetRadiiSizes)(this, radiisizes), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRadiiSizes(this: *mut core::ffi::c_void, radiisizes: *const XPS_SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMRadialGradientBrush", "SetRadiiSizes", 20usize, |this| ((**(this as *const *const IXpsOMRadialGradientBrush_Vtbl)).SetRadiiSizes)(this, radiisizes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGradientOrigin(this: *mut core::ffi::c_void, origin: *mut XPS_POINT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMRadialGradientBrush", "GetGradientOrigin", 21usize, |this| ((**(this as *const *const IXpsOMRadialGradientBrush_Vtbl)).GetGradientOrigin)(this, origin), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGradientOrigin(this: *mut core::ffi::c_void, origin: *const XPS_POINT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMRadialGradientBrush", "SetGradientOrigin", 22usize, |this| ((**(this as *const *const IXpsOMRadialGradientBrush_Vtbl)).SetGradientOrigin)(this, origin), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, radialgradientbrush: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMRadialGradientBrush", "Clone", 23usize, |this| ((**(this as *const *const IXpsOMRadialGradientBrush_Vtbl)).Clone)(this, radialgradientbrush), |result| Some(*result))
+        }
+        Self {
+            base__: <IXpsOMGradientBrush_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCenter,
+            SetCenter,
+            GetRadiiSizes,
+            SetRadiiSizes,
+            GetGradientOrigin,
+            SetGradientOrigin,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMRadialGradientBrush as windows_core::Interface>::IID || <IXpsOMGradientBrush_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMRemoteDictionaryResource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDictionary(this: *mut core::ffi::c_void, dictionary: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMRemoteDictionaryResource", "GetDictionary", 5usize, |this| ((**(this as *const *const IXpsOMRemoteDictionaryResource_Vtbl)).GetDictionary)(this, dictionary), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDictionary(this: *mut core::ffi::c_void, dictionary: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMRemoteDictionaryResource", "SetDictionary", 6usize, |this| ((**(this as *const *const IXpsOMRemoteDictionaryResource_Vtbl)).SetDictionary)(this, dictionary), |result| Some(*result))
+        }
+        Self { base__: <IXpsOMResource_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDictionary, SetDictionary }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMRemoteDictionaryResource as windows_core::Interface>::IID || <IXpsOMResource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMRemoteDictionaryResource1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDocumentType(this: *mut core::ffi::c_void, documenttype: *mut XPS_DOCUMENT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMRemoteDictionaryResource1", "GetDocumentType", 7usize, |this| ((**(this as *const *const IXpsOMRemoteDictionaryResource1_Vtbl)).GetDocumentType)(this, documenttype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Write1(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, documenttype: XPS_DOCUMENT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMRemoteDictionaryResource1", "Write1", 8usize, |this| ((**(this as *const *const IXpsOMRemoteDictionaryResource1_Vtbl)).Write1)(this, stream, documenttype), |result| Some(*result))
+        }
+        Self {
+            base__: <IXpsOMRemoteDictionaryResource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDocumentType,
+            #[cfg(feature = "Win32_System_Com")]
+            Write1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Write1: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMRemoteDictionaryResource1 as windows_core::Interface>::IID || <IXpsOMRemoteDictionaryResource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMRemoteDictionaryResourceCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMRemoteDictionaryResourceCollection", "GetCount", 3usize, |this| ((**(this as *const *const IXpsOMRemoteDictionaryResourceCollection_Vtbl)).GetCount)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, index: u32, object: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMRemoteDictionaryResourceCollection", "GetAt", 4usize, |this| ((**(this as *const *const IXpsOMRemoteDictionaryResourceCollection_Vtbl)).GetAt)(this, index, object), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertAt(this: *mut core::ffi::c_void, index: u32, object: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMRemoteDictionaryResourceCollection", "InsertAt", 5usize, |this| ((**(this as *const *const IXpsOMRemoteDictionaryResourceCollection_Vtbl)).InsertAt)(this, index, object), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAt(this: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMRemoteDictionaryResourceCollection", "RemoveAt", 6usize, |this| ((**(this as *const *const IXpsOMRemoteDictionaryResourceCollection_Vtbl)).RemoveAt)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAt(this: *mut core::ffi::c_void, index: u32, object: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMRemoteDictionaryResourceCollection", "SetAt", 7usize, |this| ((**(this as *const *const IXpsOMRemoteDictionaryResourceCollection_Vtbl)).SetAt)(this, index, object), |result| Some(*result))
+        }
+        unsafe extern "system" fn Append(this: *mut core::ffi::c_void, object: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMRemoteDictionaryResourceCollection", "Append", 8usize, |this| ((**(this as *const *const IXpsOMRemoteDictionaryResourceCollection_Vtbl)).Append)(this, object), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn GetByPartName(this: *mut core::ffi::c_void, partname: *mut core::ffi::c_void, remotedictionaryresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMRemoteDictionaryResourceCollection", "GetByPartName", 9usize, |this| ((**(this as *const *const IXpsOMRemoteDictionaryResourceCollection_Vtbl)).GetByPartName)(this, partname, remotedictionaryresource), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCount,
+            GetAt,
+            InsertAt,
+            RemoveAt,
+            SetAt,
+            Append,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            GetByPartName,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            GetByPartName: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMRemoteDictionaryResourceCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMResource_Vtbl {
+    const PROXY: Self = { Self { base__: <IXpsOMPart_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMResource as windows_core::Interface>::IID || <IXpsOMPart_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsOMShareable_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOwner(this: *mut core::ffi::c_void, owner: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMShareable", "GetOwner", 3usize, |this| ((**(this as *const *const IXpsOMShareable_Vtbl)).GetOwner)(this, owner), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, r#type: *mut XPS_OBJECT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMShareable", "GetType", 4usize, |this| ((**(this as *const *const IXpsOMShareable_Vtbl)).GetType)(this, r#type), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetOwner, GetType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMShareable as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMSignatureBlockResource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOwner(this: *mut core::ffi::c_void, owner: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMSignatureBlockResource", "GetOwner", 5usize, |this| ((**(this as *const *const IXpsOMSignatureBlockResource_Vtbl)).GetOwner)(this, owner), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetStream(this: *mut core::ffi::c_void, stream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMSignatureBlockResource", "GetStream", 6usize, |this| ((**(this as *const *const IXpsOMSignatureBlockResource_Vtbl)).GetStream)(this, stream), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn SetContent(this: *mut core::ffi::c_void, sourcestream: *mut core::ffi::c_void, partname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMSignatureBlockResource", "SetContent", 7usize, |this| ((**(this as *const *const IXpsOMSignatureBlockResource_Vtbl)).SetContent)(this, sourcestream, partname), |result| Some(*result))
+        }
+        Self {
+            base__: <IXpsOMResource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetOwner,
+            #[cfg(feature = "Win32_System_Com")]
+            GetStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetStream: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            SetContent,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            SetContent: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMSignatureBlockResource as windows_core::Interface>::IID || <IXpsOMResource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMSignatureBlockResourceCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMSignatureBlockResourceCollection", "GetCount", 3usize, |this| ((**(this as *const *const IXpsOMSignatureBlockResourceCollection_Vtbl)).GetCount)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, index: u32, signatureblockresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMSignatureBlockResourceCollection", "GetAt", 4usize, |this| ((**(this as *const *const IXpsOMSignatureBlockResourceCollection_Vtbl)).GetAt)(this, index, signatureblockresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertAt(this: *mut core::ffi::c_void, index: u32, signatureblockresource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMSignatureBlockResourceCollection", "InsertAt", 5usize, |this| ((**(this as *const *const IXpsOMSignatureBlockResourceCollection_Vtbl)).InsertAt)(this, index, signatureblockresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAt(this: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMSignatureBlockResourceCollection", "RemoveAt", 6usize, |this| ((**(this as *const *const IXpsOMSignatureBlockResourceCollection_Vtbl)).RemoveAt)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAt(this: *mut core::ffi::c_void, index: u32, signatureblockresource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMSignatureBlockResourceCollection", "SetAt", 7usize, |this| ((**(this as *const *const IXpsOMSignatureBlockResourceCollection_Vtbl)).SetAt)(this, index, signatureblockresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn Append(this: *mut core::ffi::c_void, signatureblockresource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMSignatureBlockResourceCollection", "Append", 8usize, |this| ((**(this as *const *const IXpsOMSignatureBlockResourceCollection_Vtbl)).Append)(this, signatureblockresource), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn GetByPartName(this: *mut core::ffi::c_void, partname: *mut core::ffi::c_void, signatureblockresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMSignatureBlockResourceCollection", "GetByPartName", 9usize, |this| ((**(this as *const *const IXpsOMSignatureBlockResourceCollection_Vtbl)).GetByPartName)(this, partname, signatureblockresource), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCount,
+            GetAt,
+            InsertAt,
+            RemoveAt,
+            SetAt,
+            Append,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            GetByPartName,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            GetByPartName: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMSignatureBlockResourceCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsOMSolidColorBrush_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetColor(this: *mut core::ffi::c_void, color: *mut XPS_COLOR, colorprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMSolidColorBrush", "GetColor", 7usize, |this| ((**(this as *const *const IXpsOMSolidColorBrush_Vtbl)).GetColor)(this, color, colorprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColor(this: *mut core::ffi::c_void, color: *const XPS_COLOR, colorprofile: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMSolidColorBrush", "SetColor", 8usize, |this| ((**(this as *const *const IXpsOMSolidColorBrush_Vtbl)).SetColor)(this, color, colorprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, solidcolorbrush: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMSolidColorBrush", "Clone", 9usize, |this| ((**(this as *const *const IXpsOMSolidColorBrush_Vtbl)).Clone)(this, solidcolorbrush), |result| Some(*result))
+        }
+        Self { base__: <IXpsOMBrush_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetColor, SetColor, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMSolidColorBrush as windows_core::Interface>::IID || <IXpsOMBrush_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMStoryFragmentsResource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOwner(this: *mut core::ffi::c_void, owner: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMStoryFragmentsResource", "GetOwner", 5usize, |this| ((**(this as *const *const IXpsOMStoryFragmentsResource_Vtbl)).GetOwner)(this, owner), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetStream(this: *mut core::ffi::c_void, stream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMStoryFragmentsResource", "GetStream", 6usize, |this| ((**(this as *const *const IXpsOMStoryFragmentsResource_Vtbl)).GetStream)(this, stream), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn SetContent(this: *mut core::ffi::c_void, sourcestream: *mut core::ffi::c_void, partname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMStoryFragmentsResource", "SetContent", 7usize, |this| ((**(this as *const *const IXpsOMStoryFragmentsResource_Vtbl)).SetContent)(this, sourcestream, partname), |result| Some(*result))
+        }
+        Self {
+            base__: <IXpsOMResource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetOwner,
+            #[cfg(feature = "Win32_System_Com")]
+            GetStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetStream: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            SetContent,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            SetContent: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMStoryFragmentsResource as windows_core::Interface>::IID || <IXpsOMResource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsOMThumbnailGenerator_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn GenerateThumbnail(this: *mut core::ffi::c_void, page: *mut core::ffi::c_void, thumbnailtype: XPS_IMAGE_TYPE, thumbnailsize: XPS_THUMBNAIL_SIZE, imageresourcepartname: *mut core::ffi::c_void, imageresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMThumbnailGenerator", "GenerateThumbnail", 3usize, |this| ((**(this as *const *const IXpsOMThumbnailGenerator_Vtbl)).GenerateThumbnail)(this, page, thumbnailtype, thumbnailsize, imageresourcepartname, imageresource), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            GenerateThumbnail,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            GenerateThumbnail: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMThumbnailGenerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsOMTileBrush_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTransform(this: *mut core::ffi::c_void, transform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMTileBrush", "GetTransform", 7usize, |this| ((**(this as *const *const IXpsOMTileBrush_Vtbl)).GetTransform)(this, transform), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransformLocal(this: *mut core::ffi::c_void, transform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMTileBrush", "GetTransformLocal", 8usize, |this| ((**(this as *const *const IXpsOMTileBrush_Vtbl)).GetTransformLocal)(this, transform), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransformLocal(this: *mut core::ffi::c_void, transform: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMTileBrush", "SetTransformLocal", 9usize, |this| ((**(this as *const *const IXpsOMTileBrush_Vtbl)).SetTransformLocal)(this, transform), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransformLookup(this: *mut core::ffi::c_void, key: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMTileBrush", "GetTransformLookup", 10usize, |this| ((**(this as *const *const IXpsOMTileBrush_Vtbl)).GetTransformLookup)(this, key), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransformLookup(this: *mut core::ffi::c_void, key: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMTileBrush", "SetTransformLookup", 11usize, |this| ((**(this as *const *const IXpsOMTileBrush_Vtbl)).SetTransformLookup)(this, key), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetViewbox(this: *mut core::ffi::c_void, viewbox: *mut XPS_RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMTileBrush", "GetViewbox", 12usize, |this| ((**(this as *const *const IXpsOMTileBrush_Vtbl)).GetViewbox)(this, viewbox), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetViewbox(this: *mut core::ffi::c_void, viewbox: *const XPS_RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMTileBrush", "SetViewbox", 13usize, |this| ((**(this as *const *const IXpsOMTileBrush_Vtbl)).SetViewbox)(this, viewbox), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetViewport(this: *mut core::ffi::c_void, viewport: *mut XPS_RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMTileBrush", "GetViewport", 14usize, |this| ((**(this as *const *const IXpsOMTileBrush_Vtbl)).GetViewport)(this, viewport), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetViewport(this: *mut core::ffi::c_void, viewport: *const XPS_RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMTileBrush", "SetViewport", 15usize, |this| ((**(this as *const *const IXpsOMTileBrush_Vtbl)).SetViewport)(this, viewport), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTileMode(this: *mut core::ffi::c_void, tilemode: *mut XPS_TILE_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMTileBrush", "GetTileMode", 16usize, |this| ((**(this as *const *const IXpsOMTileBrush_Vtbl)).GetTileMode)(this, tilemode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTileMode(this: *mut core::ffi::c_void, tilemode: XPS_TILE_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMTileBrush", "SetTileMode", 17usize, |this| ((**(this as *const *const IXpsOMTileBrush_Vtbl)).SetTileMode)(this, tilemode), |result| Some(*result))
+        }
+        Self {
+            base__: <IXpsOMBrush_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTransform,
+            GetTransformLocal,
+            SetTransformLocal,
+            GetTransformLookup,
+            SetTransformLookup,
+            GetViewbox,
+            SetViewbox,
+            GetViewport,
+            SetViewport,
+            GetTileMode,
+            SetTileMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMTileBrush as windows_core::Interface>::IID || <IXpsOMBrush_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IXpsOMVisual_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTransform(this: *mut core::ffi::c_void, matrixtransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisual", "GetTransform", 5usize, |this| ((**(this as *const *const IXpsOMVisual_Vtbl)).GetTransform)(this, matrixtransform), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransformLocal(this: *mut core::ffi::c_void, matrixtransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisual", "GetTransformLocal", 6usize, |this| ((**(this as *const *const IXpsOMVisual_Vtbl)).GetTransformLocal)(this, matrixtransform), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransformLocal(this: *mut core::ffi::c_void, matrixtransform: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisual", "SetTransformLocal", 7usize, |this| ((**(this as *const *const IXpsOMVisual_Vtbl)).SetTransformLocal)(this, matrixtransform), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransformLookup(this: *mut core::ffi::c_void, key: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisual", "GetTransformLookup", 8usize, |this| ((**(this as *const *const IXpsOMVisual_Vtbl)).GetTransformLookup)(this, key), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransformLookup(this: *mut core::ffi::c_void, key: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisual", "SetTransformLookup", 9usize, |this| ((**(this as *const *const IXpsOMVisual_Vtbl)).SetTransformLookup)(this, key), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClipGeometry(this: *mut core::ffi::c_void, clipgeometry: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisual", "GetClipGeometry", 10usize, |this| ((**(this as *const *const IXpsOMVisual_Vtbl)).GetClipGeometry)(this, clipgeometry), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClipGeometryLocal(this: *mut core::ffi::c_void, clipgeometry: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisual", "GetClipGeometryLocal", 11usize, |this| ((**(this as *const *const IXpsOMVisual_Vtbl)).GetClipGeometryLocal)(this, clipgeometry), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClipGeometryLocal(this: *mut core::ffi::c_void, clipgeometry: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisual", "SetClipGeometryLocal", 12usize, |this| ((**(this as *const *const IXpsOMVisual_Vtbl)).SetClipGeometryLocal)(this, clipgeometry), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClipGeometryLookup(this: *mut core::ffi::c_void, key: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisual", "GetClipGeometryLookup", 13usize, |this| ((**(this as *const *const IXpsOMVisual_Vtbl)).GetClipGeometryLookup)(this, key), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClipGeometryLookup(this: *mut core::ffi::c_void, key: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisual", "SetClipGeometryLookup", 14usize, |this| ((**(this as *const *const IXpsOMVisual_Vtbl)).SetClipGeometryLookup)(this, key), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOpacity(this: *mut core::ffi::c_void, opacity: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisual", "GetOpacity", 15usize, |this| ((**(this as *const *const IXpsOMVisual_Vtbl)).GetOpacity)(this, opacity), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOpacity(this: *mut core::ffi::c_void, opacity: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisual", "SetOpacity", 16usize, |this| ((**(this as *const *const IXpsOMVisual_Vtbl)).SetOpacity)(this, opacity), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOpacityMaskBrush(this: *mut core::ffi::c_void, opacitymaskbrush: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisual", "GetOpacityMaskBrush", 17usize, |this| ((**(this as *const *const IXpsOMVisual_Vtbl)).GetOpacityMaskBrush)(this, opacitymaskbrush), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOpacityMaskBrushLocal(this: *mut core::ffi::c_void, opacitymaskbrush: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisual", "GetOpacityMaskBrushLocal", 18usize, |this| ((**(this as *const *const IXpsOMVisual_Vtbl)).GetOpacityMaskBrushLocal)(this, opacitymaskbrush), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOpacityMaskBrushLocal(this: *mut core::ffi::c_void, opacitymaskbrush: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisual", "SetOpacityMaskBrushLocal", 19usize, |this| ((**(this as *const *const IXpsOMVisual_Vtbl)).SetOpacityMaskBrushLocal)(this, opacitymaskbrush), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOpacityMaskBrushLookup(this: *mut core::ffi::c_void, key: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisual", "GetOpacityMaskBrushLookup", 20usize, |this| ((**(this as *const *const IXpsOMVisual_Vtbl)).GetOpacityMaskBrushLookup)(this, key), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOpacityMaskBrushLookup(this: *mut core::ffi::c_void, key: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisual", "SetOpacityMaskBrushLookup", 21usize, |this| ((**(this as *const *const IXpsOMVisual_Vtbl)).SetOpacityMaskBrushLookup)(this, key), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, name: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisual", "GetName", 22usize, |this| ((**(this as *const *const IXpsOMVisual_Vtbl)).GetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, name: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisual", "SetName", 23usize, |this| ((**(this as *const *const IXpsOMVisual_Vtbl)).SetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIsHyperlinkTarget(this: *mut core::ffi::c_void, ishyperlink: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisual", "GetIsHyperlinkTarget", 24usize, |this| ((**(this as *const *const IXpsOMVisual_Vtbl)).GetIsHyperlinkTarget)(this, ishyperlink), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsHyperlinkTarget(this: *mut core::ffi::c_void, ishyperlink: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisual", "SetIsHyperlinkTarget", 25usize, |this| ((**(this as *const *const IXpsOMVisual_Vtbl)).SetIsHyperlinkTarget)(this, ishyperlink), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetHyperlinkNavigateUri(this: *mut core::ffi::c_void, hyperlinkuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisual", "GetHyperlinkNavigateUri", 26usize, |this| ((**(this as *const *const IXpsOMVisual_Vtbl)).GetHyperlinkNavigateUri)(this, hyperlinkuri), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetHyperlinkNavigateUri(this: *mut core::ffi::c_void, hyperlinkuri: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisual", "SetHyperlinkNavigateUri", 27usize, |this| ((**(this as *const *const IXpsOMVisual_Vtbl)).SetHyperlinkNavigateUri)(this, hyperlinkuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLanguage(this: *mut core::ffi::c_void, language: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisual", "GetLanguage", 28usize, |this| ((**(this as *const *const IXpsOMVisual_Vtbl)).GetLanguage)(this, language), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLanguage(this: *mut core::ffi::c_void, language: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisual", "SetLanguage", 29usize, |this| ((**(this as *const *const IXpsOMVisual_Vtbl)).SetLanguage)(this, language), |result| Some(*result))
+        }
+        Self {
+            base__: <IXpsOMShareable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTransform,
+            GetTransformLocal,
+            SetTransformLocal,
+            GetTransformLookup,
+            SetTransformLookup,
+            GetClipGeometry,
+            GetClipGeometryLocal,
+            SetClipGeometryLocal,
+            GetClipGeometryLookup,
+            SetClipGeometryLookup,
+            GetOpacity,
+            SetOpacity,
+            GetOpacityMaskBrush,
+            GetOpacityMaskBrushLocal,
+            SetOpacityMaskBrushLocal,
+            GetOpacityMaskBrushLookup,
+            SetOpacityMaskBrushLookup,
+            GetName,
+            SetName,
+            GetIsHyperlinkTarget,
+            SetIsHyperlinkTarget,
+            #[cfg(feature = "Win32_System_Com")]
+            GetHyperlinkNavigateUri,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetHyperlinkNavigateUri: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetHyperlinkNavigateUri,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetHyperlinkNavigateUri: 0,
+            GetLanguage,
+            SetLanguage,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMVisual as windows_core::Interface>::IID || <IXpsOMShareable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsOMVisualBrush_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetVisual(this: *mut core::ffi::c_void, visual: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisualBrush", "GetVisual", 18usize, |this| ((**(this as *const *const IXpsOMVisualBrush_Vtbl)).GetVisual)(this, visual), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVisualLocal(this: *mut core::ffi::c_void, visual: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisualBrush", "GetVisualLocal", 19usize, |this| ((**(this as *const *const IXpsOMVisualBrush_Vtbl)).GetVisualLocal)(this, visual), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVisualLocal(this: *mut core::ffi::c_void, visual: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisualBrush", "SetVisualLocal", 20usize, |this| ((**(this as *const *const IXpsOMVisualBrush_Vtbl)).SetVisualLocal)(this, visual), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVisualLookup(this: *mut core::ffi::c_void, lookup: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisualBrush", "GetVisualLookup", 21usize, |this| ((**(this as *const *const IXpsOMVisualBrush_Vtbl)).GetVisualLookup)(this, lookup), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVisualLookup(this: *mut core::ffi::c_void, lookup: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisualBrush", "SetVisualLookup", 22usize, |this| ((**(this as *const *const IXpsOMVisualBrush_Vtbl)).SetVisualLookup)(this, lookup), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, visualbrush: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisualBrush", "Clone", 23usize, |this| ((**(this as *const *const IXpsOMVisualBrush_Vtbl)).Clone)(this, visualbrush), |result| Some(*result))
+        }
+        Self {
+            base__: <IXpsOMTileBrush_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetVisual,
+            GetVisualLocal,
+            SetVisualLocal,
+            GetVisualLookup,
+            SetVisualLookup,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMVisualBrush as windows_core::Interface>::IID || <IXpsOMTileBrush_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsOMVisualCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisualCollection", "GetCount", 3usize, |this| ((**(this as *const *const IXpsOMVisualCollection_Vtbl)).GetCount)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, index: u32, object: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisualCollection", "GetAt", 4usize, |this| ((**(this as *const *const IXpsOMVisualCollection_Vtbl)).GetAt)(this, index, object), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertAt(this: *mut core::ffi::c_void, index: u32, object: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisualCollection", "InsertAt", 5usize, |this| ((**(this as *const *const IXpsOMVisualCollection_Vtbl)).InsertAt)(this, index, object), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAt(this: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisualCollection", "RemoveAt", 6usize, |this| ((**(this as *const *const IXpsOMVisualCollection_Vtbl)).RemoveAt)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAt(this: *mut core::ffi::c_void, index: u32, object: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisualCollection", "SetAt", 7usize, |this| ((**(this as *const *const IXpsOMVisualCollection_Vtbl)).SetAt)(this, index, object), |result| Some(*result))
+        }
+        unsafe extern "system" fn Append(this: *mut core::ffi::c_void, object: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsOMVisualCollection", "Append", 8usize, |this| ((**(this as *const *const IXpsOMVisualCollection_Vtbl)).Append)(this, object), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCount, GetAt, InsertAt, RemoveAt, SetAt, Append }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsOMVisualCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Security_Cryptography", feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsSignature_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSignatureId(this: *mut core::ffi::c_void, sigid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignature", "GetSignatureId", 3usize, |this| ((**(this as *const *const IXpsSignature_Vtbl)).GetSignatureId)(this, sigid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignatureValue(this: *mut core::ffi::c_void, signaturehashvalue: *mut *mut u8, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignature", "GetSignatureValue", 4usize, |this| ((**(this as *const *const IXpsSignature_Vtbl)).GetSignatureValue)(this, signaturehashvalue, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_Packaging_Opc")]
+        unsafe extern "system" fn GetCertificateEnumerator(this: *mut core::ffi::c_void, certificateenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignature", "GetCertificateEnumerator", 5usize, |this| ((**(this as *const *const IXpsSignature_Vtbl)).GetCertificateEnumerator)(this, certificateenumerator), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSigningTime(this: *mut core::ffi::c_void, sigdatetimestring: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignature", "GetSigningTime", 6usize, |this| ((**(this as *const *const IXpsSignature_Vtbl)).GetSigningTime)(this, sigdatetimestring), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_Packaging_Opc")]
+        unsafe extern "system" fn GetSigningTimeFormat(this: *mut core::ffi::c_void, timeformat: *mut super::Packaging::Opc::OPC_SIGNATURE_TIME_FORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignature", "GetSigningTimeFormat", 7usize, |this| ((**(this as *const *const IXpsSignature_Vtbl)).GetSigningTimeFormat)(this, timeformat), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn GetSignaturePartName(this: *mut core::ffi::c_void, signaturepartname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignature", "GetSignaturePartName", 8usize, |this| ((**(this as *const *const IXpsSignature_Vtbl)).GetSignaturePartName)(this, signaturepartname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Security_Cryptography")]
+        unsafe extern "system" fn Verify(this: *mut core::ffi::c_void, x509certificate: *const super::super::Security::Cryptography::CERT_CONTEXT, sigstatus: *mut XPS_SIGNATURE_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignature", "Verify", 9usize, |this| ((**(this as *const *const IXpsSignature_Vtbl)).Verify)(this, x509certificate, sigstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPolicy(this: *mut core::ffi::c_void, policy: *mut XPS_SIGN_POLICY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignature", "GetPolicy", 10usize, |this| ((**(this as *const *const IXpsSignature_Vtbl)).GetPolicy)(this, policy), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_Packaging_Opc")]
+        unsafe extern "system" fn GetCustomObjectEnumerator(this: *mut core::ffi::c_void, customobjectenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignature", "GetCustomObjectEnumerator", 11usize, |this| ((**(this as *const *const IXpsSignature_Vtbl)).GetCustomObjectEnumerator)(this, customobjectenumerator), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_Packaging_Opc")]
+        unsafe extern "system" fn GetCustomReferenceEnumerator(this: *mut core::ffi::c_void, customreferenceenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignature", "GetCustomReferenceEnumerator", 12usize, |this| ((**(this as *const *const IXpsSignature_Vtbl)).GetCustomReferenceEnumerator)(this, customreferenceenumerator), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignatureXml(this: *mut core::ffi::c_void, signaturexml: *mut *mut u8, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignature", "GetSignatureXml", 13usize, |this| ((**(this as *const *const IXpsSignature_Vtbl)).GetSignatureXml)(this, signaturexml, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSignatureXml(this: *mut core::ffi::c_void, signaturexml: *const u8, count: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignature", "SetSignatureXml", 14usize, |this| ((**(this as *const *const IXpsSignature_Vtbl)).SetSignatureXml)(this, signaturexml, count), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSignatureId,
+            GetSignatureValue,
+            #[cfg(feature = "Win32_Storage_Packaging_Opc")]
+            GetCertificateEnumerator,
+            #[cfg(not(feature = "Win32_Storage_Packaging_Opc"))]
+            GetCertificateEnumerator: 0,
+            GetSigningTime,
+            #[cfg(feature = "Win32_Storage_Packaging_Opc")]
+            GetSigningTimeFormat,
+            #[cfg(not(feature = "Win32_Storage_Packaging_Opc"))]
+            GetSigningTimeFormat: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            GetSignaturePartName,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            GetSignaturePartName: 0,
+            #[cfg(feature = "Win32_Security_Cryptography")]
+            Verify,
+            #[cfg(not(feature = "Win32_Security_Cryptography"))]
+            Verify: 0,
+            GetPolicy,
+            #[cfg(feature = "Win32_Storage_Packaging_Opc")]
+            GetCustomObjectEnumerator,
+            #[cfg(not(feature = "Win32_Storage_Packaging_Opc"))]
+            GetCustomObjectEnumerator: 0,
+            #[cfg(feature = "Win32_Storage_Packaging_Opc")]
+            GetCustomReferenceEnumerator,
+            #[cfg(not(feature = "Win32_Storage_Packaging_Opc"))]
+            GetCustomReferenceEnumerator: 0,
+            GetSignatureXml,
+            SetSignatureXml,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsSignature as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsSignatureBlock_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRequests(this: *mut core::ffi::c_void, requests: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureBlock", "GetRequests", 3usize, |this| ((**(this as *const *const IXpsSignatureBlock_Vtbl)).GetRequests)(this, requests), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn GetPartName(this: *mut core::ffi::c_void, partname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureBlock", "GetPartName", 4usize, |this| ((**(this as *const *const IXpsSignatureBlock_Vtbl)).GetPartName)(this, partname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDocumentIndex(this: *mut core::ffi::c_void, fixeddocumentindex: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureBlock", "GetDocumentIndex", 5usize, |this| ((**(this as *const *const IXpsSignatureBlock_Vtbl)).GetDocumentIndex)(this, fixeddocumentindex), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn GetDocumentName(this: *mut core::ffi::c_void, fixeddocumentname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureBlock", "GetDocumentName", 6usize, |this| ((**(this as *const *const IXpsSignatureBlock_Vtbl)).GetDocumentName)(this, fixeddocumentname), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRequest(this: *mut core::ffi::c_void, requestid: windows_core::PCWSTR, signaturerequest: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureBlock", "CreateRequest", 7usize, |this| ((**(this as *const *const IXpsSignatureBlock_Vtbl)).CreateRequest)(this, requestid, signaturerequest), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetRequests,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            GetPartName,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            GetPartName: 0,
+            GetDocumentIndex,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            GetDocumentName,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            GetDocumentName: 0,
+            CreateRequest,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsSignatureBlock as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsSignatureBlockCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureBlockCollection", "GetCount", 3usize, |this| ((**(this as *const *const IXpsSignatureBlockCollection_Vtbl)).GetCount)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, index: u32, signatureblock: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureBlockCollection", "GetAt", 4usize, |this| ((**(this as *const *const IXpsSignatureBlockCollection_Vtbl)).GetAt)(this, index, signatureblock), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAt(this: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureBlockCollection", "RemoveAt", 5usize, |this| ((**(this as *const *const IXpsSignatureBlockCollection_Vtbl)).RemoveAt)(this, index), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCount, GetAt, RemoveAt }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsSignatureBlockCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsSignatureCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureCollection", "GetCount", 3usize, |this| ((**(this as *const *const IXpsSignatureCollection_Vtbl)).GetCount)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, index: u32, signature: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureCollection", "GetAt", 4usize, |this| ((**(this as *const *const IXpsSignatureCollection_Vtbl)).GetAt)(this, index, signature), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAt(this: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureCollection", "RemoveAt", 5usize, |this| ((**(this as *const *const IXpsSignatureCollection_Vtbl)).RemoveAt)(this, index), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCount, GetAt, RemoveAt }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsSignatureCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Security_Cryptography", feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsSignatureManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LoadPackageFile(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureManager", "LoadPackageFile", 3usize, |this| ((**(this as *const *const IXpsSignatureManager_Vtbl)).LoadPackageFile)(this, filename), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn LoadPackageStream(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureManager", "LoadPackageStream", 4usize, |this| ((**(this as *const *const IXpsSignatureManager_Vtbl)).LoadPackageStream)(this, stream), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Security_Cryptography")]
+        unsafe extern "system" fn Sign(this: *mut core::ffi::c_void, signoptions: *mut core::ffi::c_void, x509certificate: *const super::super::Security::Cryptography::CERT_CONTEXT, signature: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureManager", "Sign", 5usize, |this| ((**(this as *const *const IXpsSignatureManager_Vtbl)).Sign)(this, signoptions, x509certificate, signature), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn GetSignatureOriginPartName(this: *mut core::ffi::c_void, signatureoriginpartname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureManager", "GetSignatureOriginPartName", 6usize, |this| ((**(this as *const *const IXpsSignatureManager_Vtbl)).GetSignatureOriginPartName)(this, signatureoriginpartname), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn SetSignatureOriginPartName(this: *mut core::ffi::c_void, signatureoriginpartname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureManager", "SetSignatureOriginPartName", 7usize, |this| ((**(this as *const *const IXpsSignatureManager_Vtbl)).SetSignatureOriginPartName)(this, signatureoriginpartname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignatures(this: *mut core::ffi::c_void, signatures: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureManager", "GetSignatures", 8usize, |this| ((**(this as *const *const IXpsSignatureManager_Vtbl)).GetSignatures)(this, signatures), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn AddSignatureBlock(this: *mut core::ffi::c_void, partname: *mut core::ffi::c_void, fixeddocumentindex: u32, signatureblock: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureManager", "AddSignatureBlock", 9usize, |this| ((**(this as *const *const IXpsSignatureManager_Vtbl)).AddSignatureBlock)(this, partname, fixeddocumentindex, signatureblock), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignatureBlocks(this: *mut core::ffi::c_void, signatureblocks: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureManager", "GetSignatureBlocks", 10usize, |this| ((**(this as *const *const IXpsSignatureManager_Vtbl)).GetSignatureBlocks)(this, signatureblocks), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSigningOptions(this: *mut core::ffi::c_void, signingoptions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureManager", "CreateSigningOptions", 11usize, |this| ((**(this as *const *const IXpsSignatureManager_Vtbl)).CreateSigningOptions)(this, signingoptions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Security")]
+        unsafe extern "system" fn SavePackageToFile(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, securityattributes: *const super::super::Security::SECURITY_ATTRIBUTES, flagsandattributes: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureManager", "SavePackageToFile", 12usize, |this| ((**(this as *const *const IXpsSignatureManager_Vtbl)).SavePackageToFile)(this, filename, securityattributes, flagsandattributes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SavePackageToStream(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureManager", "SavePackageToStream", 13usize, |this| ((**(this as *const *const IXpsSignatureManager_Vtbl)).SavePackageToStream)(this, stream), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LoadPackageFile,
+            #[cfg(feature = "Win32_System_Com")]
+            LoadPackageStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            LoadPackageStream: 0,
+            #[cfg(feature = "Win32_Security_Cryptography")]
+            Sign,
+            #[cfg(not(feature = "Win32_Security_Cryptography"))]
+            Sign: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            GetSignatureOriginPartName,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            GetSignatureOriginPartName: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            SetSignatureOriginPartName,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            SetSignatureOriginPartName: 0,
+            GetSignatures,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            AddSignatureBlock,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            AddSignatureBlock: 0,
+            GetSignatureBlocks,
+            CreateSigningOptions,
+            #[cfg(feature = "Win32_Security")]
+            SavePackageToFile,
+            #[cfg(not(feature = "Win32_Security"))]
+            SavePackageToFile: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SavePackageToStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SavePackageToStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsSignatureManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsSignatureRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetIntent(this: *mut core::ffi::c_void, intent: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureRequest", "GetIntent", 3usize, |this| ((**(this as *const *const IXpsSignatureRequest_Vtbl)).GetIntent)(this, intent), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIntent(this: *mut core::ffi::c_void, intent: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureRequest", "SetIntent", 4usize, |this| ((**(this as *const *const IXpsSignatureRequest_Vtbl)).SetIntent)(this, intent), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRequestedSigner(this: *mut core::ffi::c_void, signername: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureRequest", "GetRequestedSigner", 5usize, |this| ((**(this as *const *const IXpsSignatureRequest_Vtbl)).GetRequestedSigner)(this, signername), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequestedSigner(this: *mut core::ffi::c_void, signername: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureRequest", "SetRequestedSigner", 6usize, |this| ((**(this as *const *const IXpsSignatureRequest_Vtbl)).SetRequestedSigner)(this, signername), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRequestSignByDate(this: *mut core::ffi::c_void, datestring: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureRequest", "GetRequestSignByDate", 7usize, |this| ((**(this as *const *const IXpsSignatureRequest_Vtbl)).GetRequestSignByDate)(this, datestring), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRequestSignByDate(this: *mut core::ffi::c_void, datestring: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureRequest", "SetRequestSignByDate", 8usize, |this| ((**(this as *const *const IXpsSignatureRequest_Vtbl)).SetRequestSignByDate)(this, datestring), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSigningLocale(this: *mut core::ffi::c_void, place: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureRequest", "GetSigningLocale", 9usize, |this| ((**(this as *const *const IXpsSignatureRequest_Vtbl)).GetSigningLocale)(this, place), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSigningLocale(this: *mut core::ffi::c_void, place: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureRequest", "SetSigningLocale", 10usize, |this| ((**(this as *const *const IXpsSignatureRequest_Vtbl)).SetSigningLocale)(this, place), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn GetSpotLocation(this: *mut core::ffi::c_void, pageindex: *mut i32, pagepartname: *mut *mut core::ffi::c_void, x: *mut f32, y: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureRequest", "GetSpotLocation", 11usize, |this| ((**(this as *const *const IXpsSignatureRequest_Vtbl)).GetSpotLocation)(this, pageindex, pagepartname, x, y), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSpotLocation(this: *mut core::ffi::c_void, pageindex: i32, x: f32, y: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureRequest", "SetSpotLocation", 12usize, |this| ((**(this as *const *const IXpsSignatureRequest_Vtbl)).SetSpotLocation)(this, pageindex, x, y), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRequestId(this: *mut core::ffi::c_void, requestid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureRequest", "GetRequestId", 13usize, |this| ((**(this as *const *const IXpsSignatureRequest_Vtbl)).GetRequestId)(this, requestid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignature(this: *mut core::ffi::c_void, signature: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureRequest", "GetSignature", 14usize, |this| ((**(this as *const *const IXpsSignatureRequest_Vtbl)).GetSignature)(this, signature), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetIntent,
+            SetIntent,
+            GetRequestedSigner,
+            SetRequestedSigner,
+            GetRequestSignByDate,
+            SetRequestSignByDate,
+            GetSigningLocale,
+            SetSigningLocale,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            GetSpotLocation,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            GetSpotLocation: 0,
+            SetSpotLocation,
+            GetRequestId,
+            GetSignature,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsSignatureRequest as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXpsSignatureRequestCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureRequestCollection", "GetCount", 3usize, |this| ((**(this as *const *const IXpsSignatureRequestCollection_Vtbl)).GetCount)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, index: u32, signaturerequest: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureRequestCollection", "GetAt", 4usize, |this| ((**(this as *const *const IXpsSignatureRequestCollection_Vtbl)).GetAt)(this, index, signaturerequest), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAt(this: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSignatureRequestCollection", "RemoveAt", 5usize, |this| ((**(this as *const *const IXpsSignatureRequestCollection_Vtbl)).RemoveAt)(this, index), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCount, GetAt, RemoveAt }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsSignatureRequestCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IXpsSigningOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSignatureId(this: *mut core::ffi::c_void, signatureid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSigningOptions", "GetSignatureId", 3usize, |this| ((**(this as *const *const IXpsSigningOptions_Vtbl)).GetSignatureId)(this, signatureid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSignatureId(this: *mut core::ffi::c_void, signatureid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSigningOptions", "SetSignatureId", 4usize, |this| ((**(this as *const *const IXpsSigningOptions_Vtbl)).SetSignatureId)(this, signatureid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSignatureMethod(this: *mut core::ffi::c_void, signaturemethod: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSigningOptions", "GetSignatureMethod", 5usize, |this| ((**(this as *const *const IXpsSigningOptions_Vtbl)).GetSignatureMethod)(this, signaturemethod), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSignatureMethod(this: *mut core::ffi::c_void, signaturemethod: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSigningOptions", "SetSignatureMethod", 6usize, |this| ((**(this as *const *const IXpsSigningOptions_Vtbl)).SetSignatureMethod)(this, signaturemethod), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDigestMethod(this: *mut core::ffi::c_void, digestmethod: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSigningOptions", "GetDigestMethod", 7usize, |this| ((**(this as *const *const IXpsSigningOptions_Vtbl)).GetDigestMethod)(this, digestmethod), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDigestMethod(this: *mut core::ffi::c_void, digestmethod: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSigningOptions", "SetDigestMethod", 8usize, |this| ((**(this as *const *const IXpsSigningOptions_Vtbl)).SetDigestMethod)(this, digestmethod), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn GetSignaturePartName(this: *mut core::ffi::c_void, signaturepartname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSigningOptions", "GetSignaturePartName", 9usize, |this| ((**(this as *const *const IXpsSigningOptions_Vtbl)).GetSignaturePartName)(this, signaturepartname), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn SetSignaturePartName(this: *mut core::ffi::c_void, signaturepartname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSigningOptions", "SetSignaturePartName", 10usize, |this| ((**(this as *const *const IXpsSigningOptions_Vtbl)).SetSignaturePartName)(this, signaturepartname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPolicy(this: *mut core::ffi::c_void, policy: *mut XPS_SIGN_POLICY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSigningOptions", "GetPolicy", 11usize, |this| ((**(this as *const *const IXpsSigningOptions_Vtbl)).GetPolicy)(this, policy), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPolicy(this: *mut core::ffi::c_void, policy: XPS_SIGN_POLICY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSigningOptions", "SetPolicy", 12usize, |this| ((**(this as *const *const IXpsSigningOptions_Vtbl)).SetPolicy)(this, policy), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_Packaging_Opc")]
+        unsafe extern "system" fn GetSigningTimeFormat(this: *mut core::ffi::c_void, timeformat: *mut super::Packaging::Opc::OPC_SIGNATURE_TIME_FORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSigningOptions", "GetSigningTimeFormat", 13usize, |this| ((**(this as *const *const IXpsSigningOptions_Vtbl)).GetSigningTimeFormat)(this, timeformat), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_Packaging_Opc")]
+        unsafe extern "system" fn SetSigningTimeFormat(this: *mut core::ffi::c_void, timeformat: super::Packaging::Opc::OPC_SIGNATURE_TIME_FORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSigningOptions", "SetSigningTimeFormat", 14usize, |this| ((**(this as *const *const IXpsSigningOptions_Vtbl)).SetSigningTimeFormat)(this, timeformat), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_Packaging_Opc")]
+        unsafe extern "system" fn GetCustomObjects(this: *mut core::ffi::c_void, customobjectset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSigningOptions", "GetCustomObjects", 15usize, |this| ((**(this as *const *const IXpsSigningOptions_Vtbl)).GetCustomObjects)(this, customobjectset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_Packaging_Opc")]
+        unsafe extern "system" fn GetCustomReferences(this: *mut core::ffi::c_void, customreferenceset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSigningOptions", "GetCustomReferences", 16usize, |this| ((**(this as *const *const IXpsSigningOptions_Vtbl)).GetCustomReferences)(this, customreferenceset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_Packaging_Opc")]
+        unsafe extern "system" fn GetCertificateSet(this: *mut core::ffi::c_void, certificateset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSigningOptions", "GetCertificateSet", 17usize, |this| ((**(this as *const *const IXpsSigningOptions_Vtbl)).GetCertificateSet)(this, certificateset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlags(this: *mut core::ffi::c_void, flags: *mut XPS_SIGN_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSigningOptions", "GetFlags", 18usize, |this| ((**(this as *const *const IXpsSigningOptions_Vtbl)).GetFlags)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFlags(this: *mut core::ffi::c_void, flags: XPS_SIGN_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXpsSigningOptions", "SetFlags", 19usize, |this| ((**(this as *const *const IXpsSigningOptions_Vtbl)).SetFlags)(this, flags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSignatureId,
+            SetSignatureId,
+            GetSignatureMethod,
+            SetSignatureMethod,
+            GetDigestMethod,
+            SetDigestMethod,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            GetSignaturePartName,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            GetSignaturePartName: 0,
+            #[cfg(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com"))]
+            SetSignaturePartName,
+            #[cfg(not(all(feature = "Win32_Storage_Packaging_Opc", feature = "Win32_System_Com")))]
+            SetSignaturePartName: 0,
+            GetPolicy,
+            SetPolicy,
+            #[cfg(feature = "Win32_Storage_Packaging_Opc")]
+            GetSigningTimeFormat,
+            #[cfg(not(feature = "Win32_Storage_Packaging_Opc"))]
+            GetSigningTimeFormat: 0,
+            #[cfg(feature = "Win32_Storage_Packaging_Opc")]
+            SetSigningTimeFormat,
+            #[cfg(not(feature = "Win32_Storage_Packaging_Opc"))]
+            SetSigningTimeFormat: 0,
+            #[cfg(feature = "Win32_Storage_Packaging_Opc")]
+            GetCustomObjects,
+            #[cfg(not(feature = "Win32_Storage_Packaging_Opc"))]
+            GetCustomObjects: 0,
+            #[cfg(feature = "Win32_Storage_Packaging_Opc")]
+            GetCustomReferences,
+            #[cfg(not(feature = "Win32_Storage_Packaging_Opc"))]
+            GetCustomReferences: 0,
+            #[cfg(feature = "Win32_Storage_Packaging_Opc")]
+            GetCertificateSet,
+            #[cfg(not(feature = "Win32_Storage_Packaging_Opc"))]
+            GetCertificateSet: 0,
+            GetFlags,
+            SetFlags,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXpsSigningOptions as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
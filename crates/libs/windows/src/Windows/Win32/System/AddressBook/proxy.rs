@@ -0,0 +1,893 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IABContainer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateEntry(this: *mut core::ffi::c_void, cbentryid: u32, lpentryid: *const ENTRYID, ulcreateflags: u32, lppmapipropentry: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IABContainer", "CreateEntry", 19usize, |this| ((**(this as *const *const IABContainer_Vtbl)).CreateEntry)(this, cbentryid, lpentryid, ulcreateflags, lppmapipropentry), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyEntries(this: *mut core::ffi::c_void, lpentries: *const SBinaryArray, uluiparam: usize, lpprogress: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IABContainer", "CopyEntries", 20usize, |this| ((**(this as *const *const IABContainer_Vtbl)).CopyEntries)(this, lpentries, uluiparam, lpprogress, ulflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteEntries(this: *mut core::ffi::c_void, lpentries: *const SBinaryArray, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IABContainer", "DeleteEntries", 21usize, |this| ((**(this as *const *const IABContainer_Vtbl)).DeleteEntries)(this, lpentries, ulflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ResolveNames(this: *mut core::ffi::c_void, lpproptagarray: *const SPropTagArray, ulflags: u32, lpadrlist: *const ADRLIST, lpflaglist: *mut FlagList) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IABContainer", "ResolveNames", 22usize, |this| ((**(this as *const *const IABContainer_Vtbl)).ResolveNames)(this, lpproptagarray, ulflags, lpadrlist, lpflaglist), |result| Some(*result))
+        }
+        Self {
+            base__: <IMAPIContainer_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateEntry,
+            CopyEntries,
+            DeleteEntries,
+            #[cfg(feature = "Win32_System_Com")]
+            ResolveNames,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ResolveNames: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IABContainer as windows_core::Interface>::IID || <IMAPIContainer_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAddrBook_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenEntry(this: *mut core::ffi::c_void, cbentryid: u32, lpentryid: *mut ENTRYID, lpinterface: *mut windows_core::GUID, ulflags: u32, lpulobjtype: *mut u32, lppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddrBook", "OpenEntry", 14usize, |this| ((**(this as *const *const IAddrBook_Vtbl)).OpenEntry)(this, cbentryid, lpentryid, lpinterface, ulflags, lpulobjtype, lppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompareEntryIDs(this: *mut core::ffi::c_void, cbentryid1: u32, lpentryid1: *mut ENTRYID, cbentryid2: u32, lpentryid2: *mut ENTRYID, ulflags: u32, lpulresult: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddrBook", "CompareEntryIDs", 15usize, |this| ((**(this as *const *const IAddrBook_Vtbl)).CompareEntryIDs)(this, cbentryid1, lpentryid1, cbentryid2, lpentryid2, ulflags, lpulresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn Advise(this: *mut core::ffi::c_void, cbentryid: u32, lpentryid: *mut ENTRYID, uleventmask: u32, lpadvisesink: *mut core::ffi::c_void, lpulconnection: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddrBook", "Advise", 16usize, |this| ((**(this as *const *const IAddrBook_Vtbl)).Advise)(this, cbentryid, lpentryid, uleventmask, lpadvisesink, lpulconnection), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unadvise(this: *mut core::ffi::c_void, ulconnection: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddrBook", "Unadvise", 17usize, |this| ((**(this as *const *const IAddrBook_Vtbl)).Unadvise)(this, ulconnection), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateOneOff(this: *mut core::ffi::c_void, lpszname: *mut i8, lpszadrtype: *mut i8, lpszaddress: *mut i8, ulflags: u32, lpcbentryid: *mut u32, lppentryid: *mut *mut ENTRYID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddrBook", "CreateOneOff", 18usize, |this| ((**(this as *const *const IAddrBook_Vtbl)).CreateOneOff)(this, lpszname, lpszadrtype, lpszaddress, ulflags, lpcbentryid, lppentryid), |result| Some(*result))
+        }
+        unsafe extern "system" fn NewEntry(this: *mut core::ffi::c_void, uluiparam: u32, ulflags: u32, cbeidcontainer: u32, lpeidcontainer: *mut ENTRYID, cbeidnewentrytpl: u32, lpeidnewentrytpl: *mut ENTRYID, lpcbeidnewentry: *mut u32, lppeidnewentry: *mut *mut ENTRYID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddrBook", "NewEntry", 19usize, |this| ((**(this as *const *const IAddrBook_Vtbl)).NewEntry)(this, uluiparam, ulflags, cbeidcontainer, lpeidcontainer, cbeidnewentrytpl, lpeidnewentrytpl, lpcbeidnewentry, lppeidnewentry), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ResolveName(this: *mut core::ffi::c_void, uluiparam: usize, ulflags: u32, lpsznewentrytitle: *mut i8, lpadrlist: *mut ADRLIST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddrBook", "ResolveName", 20usize, |this| ((**(this as *const *const IAddrBook_Vtbl)).ResolveName)(this, uluiparam, ulflags, lpsznewentrytitle, lpadrlist), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Address(this: *mut core::ffi::c_void, lpuluiparam: *mut u32, lpadrparms: *mut ADRPARM, lppadrlist: *mut *mut ADRLIST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddrBook", "Address", 21usize, |this| ((**(this as *const *const IAddrBook_Vtbl)).Address)(this, lpuluiparam, lpadrparms, lppadrlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn Details(this: *mut core::ffi::c_void, lpuluiparam: *mut usize, lpfndismiss: LPFNDISMISS, lpvdismisscontext: *mut core::ffi::c_void, cbentryid: u32, lpentryid: *mut ENTRYID, lpfbuttoncallback: LPFNBUTTON, lpvbuttoncontext: *mut core::ffi::c_void, lpszbuttontext: *mut i8, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddrBook", "Details", 22usize, |this| ((**(this as *const *const IAddrBook_Vtbl)).Details)(this, lpuluiparam, lpfndismiss, lpvdismisscontext, cbentryid, lpentryid, lpfbuttoncallback, lpvbuttoncontext, lpszbuttontext, ulflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RecipOptions(this: *mut core::ffi::c_void, uluiparam: u32, ulflags: u32, lprecip: *mut ADRENTRY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddrBook", "RecipOptions", 23usize, |this| ((**(this as *const *const IAddrBook_Vtbl)).RecipOptions)(this, uluiparam, ulflags, lprecip), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn QueryDefaultRecipOpt(this: *mut core::ffi::c_void, lpszadrtype: *mut i8, ulflags: u32, lpcvalues: *mut u32, lppoptions: *mut *mut SPropValue) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddrBook", "QueryDefaultRecipOpt", 24usize, |this| ((**(this as *const *const IAddrBook_Vtbl)).QueryDefaultRecipOpt)(this, lpszadrtype, ulflags, lpcvalues, lppoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPAB(this: *mut core::ffi::c_void, lpcbentryid: *mut u32, lppentryid: *mut *mut ENTRYID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddrBook", "GetPAB", 25usize, |this| ((**(this as *const *const IAddrBook_Vtbl)).GetPAB)(this, lpcbentryid, lppentryid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPAB(this: *mut core::ffi::c_void, cbentryid: u32, lpentryid: *mut ENTRYID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddrBook", "SetPAB", 26usize, |this| ((**(this as *const *const IAddrBook_Vtbl)).SetPAB)(this, cbentryid, lpentryid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultDir(this: *mut core::ffi::c_void, lpcbentryid: *mut u32, lppentryid: *mut *mut ENTRYID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddrBook", "GetDefaultDir", 27usize, |this| ((**(this as *const *const IAddrBook_Vtbl)).GetDefaultDir)(this, lpcbentryid, lppentryid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultDir(this: *mut core::ffi::c_void, cbentryid: u32, lpentryid: *mut ENTRYID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddrBook", "SetDefaultDir", 28usize, |this| ((**(this as *const *const IAddrBook_Vtbl)).SetDefaultDir)(this, cbentryid, lpentryid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSearchPath(this: *mut core::ffi::c_void, ulflags: u32, lppsearchpath: *mut *mut SRowSet) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddrBook", "GetSearchPath", 29usize, |this| ((**(this as *const *const IAddrBook_Vtbl)).GetSearchPath)(this, ulflags, lppsearchpath), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSearchPath(this: *mut core::ffi::c_void, ulflags: u32, lpsearchpath: *mut SRowSet) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddrBook", "SetSearchPath", 30usize, |this| ((**(this as *const *const IAddrBook_Vtbl)).SetSearchPath)(this, ulflags, lpsearchpath), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PrepareRecips(this: *mut core::ffi::c_void, ulflags: u32, lpproptagarray: *mut SPropTagArray, lpreciplist: *mut ADRLIST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddrBook", "PrepareRecips", 31usize, |this| ((**(this as *const *const IAddrBook_Vtbl)).PrepareRecips)(this, ulflags, lpproptagarray, lpreciplist), |result| Some(*result))
+        }
+        Self {
+            base__: <IMAPIProp_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OpenEntry,
+            CompareEntryIDs,
+            Advise,
+            Unadvise,
+            CreateOneOff,
+            NewEntry,
+            #[cfg(feature = "Win32_System_Com")]
+            ResolveName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ResolveName: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Address,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Address: 0,
+            Details,
+            #[cfg(feature = "Win32_System_Com")]
+            RecipOptions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RecipOptions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            QueryDefaultRecipOpt,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            QueryDefaultRecipOpt: 0,
+            GetPAB,
+            SetPAB,
+            GetDefaultDir,
+            SetDefaultDir,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSearchPath,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSearchPath: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSearchPath,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSearchPath: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PrepareRecips,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PrepareRecips: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAddrBook as windows_core::Interface>::IID || <IMAPIProp_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAttach_Vtbl {
+    const PROXY: Self = { Self { base__: <IMAPIProp_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAttach as windows_core::Interface>::IID || <IMAPIProp_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDistList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateEntry(this: *mut core::ffi::c_void, cbentryid: u32, lpentryid: *const ENTRYID, ulcreateflags: u32, lppmapipropentry: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDistList", "CreateEntry", 19usize, |this| ((**(this as *const *const IDistList_Vtbl)).CreateEntry)(this, cbentryid, lpentryid, ulcreateflags, lppmapipropentry), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyEntries(this: *mut core::ffi::c_void, lpentries: *const SBinaryArray, uluiparam: usize, lpprogress: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDistList", "CopyEntries", 20usize, |this| ((**(this as *const *const IDistList_Vtbl)).CopyEntries)(this, lpentries, uluiparam, lpprogress, ulflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteEntries(this: *mut core::ffi::c_void, lpentries: *const SBinaryArray, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDistList", "DeleteEntries", 21usize, |this| ((**(this as *const *const IDistList_Vtbl)).DeleteEntries)(this, lpentries, ulflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ResolveNames(this: *mut core::ffi::c_void, lpproptagarray: *const SPropTagArray, ulflags: u32, lpadrlist: *const ADRLIST, lpflaglist: *mut FlagList) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDistList", "ResolveNames", 22usize, |this| ((**(this as *const *const IDistList_Vtbl)).ResolveNames)(this, lpproptagarray, ulflags, lpadrlist, lpflaglist), |result| Some(*result))
+        }
+        Self {
+            base__: <IMAPIContainer_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateEntry,
+            CopyEntries,
+            DeleteEntries,
+            #[cfg(feature = "Win32_System_Com")]
+            ResolveNames,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ResolveNames: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDistList as windows_core::Interface>::IID || <IMAPIContainer_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMAPIAdviseSink_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnNotify(this: *mut core::ffi::c_void, cnotif: u32, lpnotifications: *mut NOTIFICATION) -> u32 {
+            windows_core::imp::proxy_call(this, "IMAPIAdviseSink", "OnNotify", 3usize, |this| ((**(this as *const *const IMAPIAdviseSink_Vtbl)).OnNotify)(this, cnotif, lpnotifications), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            OnNotify,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnNotify: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMAPIAdviseSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMAPIContainer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetContentsTable(this: *mut core::ffi::c_void, ulflags: u32, lpptable: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIContainer", "GetContentsTable", 14usize, |this| ((**(this as *const *const IMAPIContainer_Vtbl)).GetContentsTable)(this, ulflags, lpptable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHierarchyTable(this: *mut core::ffi::c_void, ulflags: u32, lpptable: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIContainer", "GetHierarchyTable", 15usize, |this| ((**(this as *const *const IMAPIContainer_Vtbl)).GetHierarchyTable)(this, ulflags, lpptable), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenEntry(this: *mut core::ffi::c_void, cbentryid: u32, lpentryid: *const ENTRYID, lpinterface: *mut windows_core::GUID, ulflags: u32, lpulobjtype: *mut u32, lppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIContainer", "OpenEntry", 16usize, |this| ((**(this as *const *const IMAPIContainer_Vtbl)).OpenEntry)(this, cbentryid, lpentryid, lpinterface, ulflags, lpulobjtype, lppunk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSearchCriteria(this: *mut core::ffi::c_void, lprestriction: *const SRestriction, lpcontainerlist: *const SBinaryArray, ulsearchflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIContainer", "SetSearchCriteria", 17usize, |this| ((**(this as *const *const IMAPIContainer_Vtbl)).SetSearchCriteria)(this, lprestriction, lpcontainerlist, ulsearchflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSearchCriteria(this: *mut core::ffi::c_void, ulflags: u32, lpprestriction: *mut *mut SRestriction, lppcontainerlist: *mut *mut SBinaryArray, lpulsearchstate: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIContainer", "GetSearchCriteria", 18usize, |this| ((**(this as *const *const IMAPIContainer_Vtbl)).GetSearchCriteria)(this, ulflags, lpprestriction, lppcontainerlist, lpulsearchstate), |result| Some(*result))
+        }
+        Self {
+            base__: <IMAPIProp_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetContentsTable,
+            GetHierarchyTable,
+            OpenEntry,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSearchCriteria,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSearchCriteria: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSearchCriteria,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSearchCriteria: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMAPIContainer as windows_core::Interface>::IID || <IMAPIProp_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMAPIControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLastError(this: *mut core::ffi::c_void, hresult: windows_core::HRESULT, ulflags: u32, lppmapierror: *mut *mut MAPIERROR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIControl", "GetLastError", 3usize, |this| ((**(this as *const *const IMAPIControl_Vtbl)).GetLastError)(this, hresult, ulflags, lppmapierror), |result| Some(*result))
+        }
+        unsafe extern "system" fn Activate(this: *mut core::ffi::c_void, ulflags: u32, uluiparam: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIControl", "Activate", 4usize, |this| ((**(this as *const *const IMAPIControl_Vtbl)).Activate)(this, ulflags, uluiparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetState(this: *mut core::ffi::c_void, ulflags: u32, lpulstate: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIControl", "GetState", 5usize, |this| ((**(this as *const *const IMAPIControl_Vtbl)).GetState)(this, ulflags, lpulstate), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetLastError, Activate, GetState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMAPIControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMAPIFolder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateMessage(this: *mut core::ffi::c_void, lpinterface: *mut windows_core::GUID, ulflags: u32, lppmessage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIFolder", "CreateMessage", 19usize, |this| ((**(this as *const *const IMAPIFolder_Vtbl)).CreateMessage)(this, lpinterface, ulflags, lppmessage), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyMessages(this: *mut core::ffi::c_void, lpmsglist: *const SBinaryArray, lpinterface: *const windows_core::GUID, lpdestfolder: *const core::ffi::c_void, uluiparam: usize, lpprogress: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIFolder", "CopyMessages", 20usize, |this| ((**(this as *const *const IMAPIFolder_Vtbl)).CopyMessages)(this, lpmsglist, lpinterface, lpdestfolder, uluiparam, lpprogress, ulflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteMessages(this: *mut core::ffi::c_void, lpmsglist: *const SBinaryArray, uluiparam: usize, lpprogress: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIFolder", "DeleteMessages", 21usize, |this| ((**(this as *const *const IMAPIFolder_Vtbl)).DeleteMessages)(this, lpmsglist, uluiparam, lpprogress, ulflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFolder(this: *mut core::ffi::c_void, ulfoldertype: u32, lpszfoldername: *const i8, lpszfoldercomment: *const i8, lpinterface: *const windows_core::GUID, ulflags: u32, lppfolder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIFolder", "CreateFolder", 22usize, |this| ((**(this as *const *const IMAPIFolder_Vtbl)).CreateFolder)(this, ulfoldertype, lpszfoldername, lpszfoldercomment, lpinterface, ulflags, lppfolder), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyFolder(this: *mut core::ffi::c_void, cbentryid: u32, lpentryid: *const ENTRYID, lpinterface: *const windows_core::GUID, lpdestfolder: *const core::ffi::c_void, lpsznewfoldername: *const i8, uluiparam: usize, lpprogress: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIFolder", "CopyFolder", 23usize, |this| ((**(this as *const *const IMAPIFolder_Vtbl)).CopyFolder)(this, cbentryid, lpentryid, lpinterface, lpdestfolder, lpsznewfoldername, uluiparam, lpprogress, ulflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteFolder(this: *mut core::ffi::c_void, cbentryid: u32, lpentryid: *const ENTRYID, uluiparam: usize, lpprogress: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIFolder", "DeleteFolder", 24usize, |this| ((**(this as *const *const IMAPIFolder_Vtbl)).DeleteFolder)(this, cbentryid, lpentryid, uluiparam, lpprogress, ulflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReadFlags(this: *mut core::ffi::c_void, lpmsglist: *const SBinaryArray, uluiparam: usize, lpprogress: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIFolder", "SetReadFlags", 25usize, |this| ((**(this as *const *const IMAPIFolder_Vtbl)).SetReadFlags)(this, lpmsglist, uluiparam, lpprogress, ulflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMessageStatus(this: *mut core::ffi::c_void, cbentryid: u32, lpentryid: *const ENTRYID, ulflags: u32, lpulmessagestatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIFolder", "GetMessageStatus", 26usize, |this| ((**(this as *const *const IMAPIFolder_Vtbl)).GetMessageStatus)(this, cbentryid, lpentryid, ulflags, lpulmessagestatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMessageStatus(this: *mut core::ffi::c_void, cbentryid: u32, lpentryid: *const ENTRYID, ulnewstatus: u32, ulnewstatusmask: u32, lpuloldstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIFolder", "SetMessageStatus", 27usize, |this| ((**(this as *const *const IMAPIFolder_Vtbl)).SetMessageStatus)(this, cbentryid, lpentryid, ulnewstatus, ulnewstatusmask, lpuloldstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveContentsSort(this: *mut core::ffi::c_void, lpsortcriteria: *const SSortOrderSet, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIFolder", "SaveContentsSort", 28usize, |this| ((**(this as *const *const IMAPIFolder_Vtbl)).SaveContentsSort)(this, lpsortcriteria, ulflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn EmptyFolder(this: *mut core::ffi::c_void, uluiparam: usize, lpprogress: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIFolder", "EmptyFolder", 29usize, |this| ((**(this as *const *const IMAPIFolder_Vtbl)).EmptyFolder)(this, uluiparam, lpprogress, ulflags), |result| Some(*result))
+        }
+        Self {
+            base__: <IMAPIContainer_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateMessage,
+            CopyMessages,
+            DeleteMessages,
+            CreateFolder,
+            CopyFolder,
+            DeleteFolder,
+            SetReadFlags,
+            GetMessageStatus,
+            SetMessageStatus,
+            SaveContentsSort,
+            EmptyFolder,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMAPIFolder as windows_core::Interface>::IID || <IMAPIContainer_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMAPIProgress_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Progress(this: *mut core::ffi::c_void, ulvalue: u32, ulcount: u32, ultotal: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIProgress", "Progress", 3usize, |this| ((**(this as *const *const IMAPIProgress_Vtbl)).Progress)(this, ulvalue, ulcount, ultotal), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlags(this: *mut core::ffi::c_void, lpulflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIProgress", "GetFlags", 4usize, |this| ((**(this as *const *const IMAPIProgress_Vtbl)).GetFlags)(this, lpulflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMax(this: *mut core::ffi::c_void, lpulmax: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIProgress", "GetMax", 5usize, |this| ((**(this as *const *const IMAPIProgress_Vtbl)).GetMax)(this, lpulmax), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMin(this: *mut core::ffi::c_void, lpulmin: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIProgress", "GetMin", 6usize, |this| ((**(this as *const *const IMAPIProgress_Vtbl)).GetMin)(this, lpulmin), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLimits(this: *mut core::ffi::c_void, lpulmin: *mut u32, lpulmax: *mut u32, lpulflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIProgress", "SetLimits", 7usize, |this| ((**(this as *const *const IMAPIProgress_Vtbl)).SetLimits)(this, lpulmin, lpulmax, lpulflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Progress, GetFlags, GetMax, GetMin, SetLimits }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMAPIProgress as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMAPIProp_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLastError(this: *mut core::ffi::c_void, hresult: windows_core::HRESULT, ulflags: u32, lppmapierror: *mut *mut MAPIERROR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIProp", "GetLastError", 3usize, |this| ((**(this as *const *const IMAPIProp_Vtbl)).GetLastError)(this, hresult, ulflags, lppmapierror), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveChanges(this: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIProp", "SaveChanges", 4usize, |this| ((**(this as *const *const IMAPIProp_Vtbl)).SaveChanges)(this, ulflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetProps(this: *mut core::ffi::c_void, lpproptagarray: *mut SPropTagArray, ulflags: u32, lpcvalues: *mut u32, lppproparray: *mut *mut SPropValue) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIProp", "GetProps", 5usize, |this| ((**(this as *const *const IMAPIProp_Vtbl)).GetProps)(this, lpproptagarray, ulflags, lpcvalues, lppproparray), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropList(this: *mut core::ffi::c_void, ulflags: u32, lppproptagarray: *mut *mut SPropTagArray) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIProp", "GetPropList", 6usize, |this| ((**(this as *const *const IMAPIProp_Vtbl)).GetPropList)(this, ulflags, lppproptagarray), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenProperty(this: *mut core::ffi::c_void, ulproptag: u32, lpiid: *mut windows_core::GUID, ulinterfaceoptions: u32, ulflags: u32, lppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIProp", "OpenProperty", 7usize, |this| ((**(this as *const *const IMAPIProp_Vtbl)).OpenProperty)(this, ulproptag, lpiid, ulinterfaceoptions, ulflags, lppunk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetProps(this: *mut core::ffi::c_void, cvalues: u32, lpproparray: *mut SPropValue, lppproblems: *mut *mut SPropProblemArray) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIProp", "SetProps", 8usize, |this| ((**(this as *const *const IMAPIProp_Vtbl)).SetProps)(this, cvalues, lpproparray, lppproblems), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteProps(this: *mut core::ffi::c_void, lpproptagarray: *mut SPropTagArray, lppproblems: *mut *mut SPropProblemArray) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIProp", "DeleteProps", 9usize, |this| ((**(this as *const *const IMAPIProp_Vtbl)).DeleteProps)(this, lpproptagarray, lppproblems), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyTo(this: *mut core::ffi::c_void, ciidexclude: u32, rgiidexclude: *mut windows_core::GUID, lpexcludeprops: *mut SPropTagArray, uluiparam: usize, lpprogress: *mut core::ffi::c_void, lpinterface: *mut windows_core::GUID, lpdestobj: *mut core::ffi::c_void, ulflags: u32, lppproblems: *mut *mut SPropProblemArray) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIProp", "CopyTo", 10usize, |this| ((**(this as *const *const IMAPIProp_Vtbl)).CopyTo)(this, ciidexclude, rgiidexclude, lpexcludeprops, uluiparam, lpprogress, lpinterface, lpdestobj, ulflags, lppproblems), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyProps(this: *mut core::ffi::c_void, lpincludeprops: *mut SPropTagArray, uluiparam: usize, lpprogress: *mut core::ffi::c_void, lpinterface: *mut windows_core::GUID, lpdestobj: *mut core::ffi::c_void, ulflags: u32, lppproblems: *mut *mut SPropProblemArray) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIProp", "CopyProps", 11usize, |this| ((**(this as *const *const IMAPIProp_Vtbl)).CopyProps)(this, lpincludeprops, uluiparam, lpprogress, lpinterface, lpdestobj, ulflags, lppproblems), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNamesFromIDs(this: *mut core::ffi::c_void, lppproptags: *mut *mut SPropTagArray, lppropsetguid: *mut windows_core::GUID, ulflags: u32, lpcpropnames: *mut u32, lppppropnames: *mut *mut *mut MAPINAMEID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIProp", "GetNamesFromIDs", 12usize, |this| ((**(this as *const *const IMAPIProp_Vtbl)).GetNamesFromIDs)(this, lppproptags, lppropsetguid, ulflags, lpcpropnames, lppppropnames), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIDsFromNames(this: *mut core::ffi::c_void, cpropnames: u32, lpppropnames: *mut *mut MAPINAMEID, ulflags: u32, lppproptags: *mut *mut SPropTagArray) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIProp", "GetIDsFromNames", 13usize, |this| ((**(this as *const *const IMAPIProp_Vtbl)).GetIDsFromNames)(this, cpropnames, lpppropnames, ulflags, lppproptags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetLastError,
+            SaveChanges,
+            #[cfg(feature = "Win32_System_Com")]
+            GetProps,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetProps: 0,
+            GetPropList,
+            OpenProperty,
+            #[cfg(feature = "Win32_System_Com")]
+            SetProps,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetProps: 0,
+            DeleteProps,
+            CopyTo,
+            CopyProps,
+            GetNamesFromIDs,
+            GetIDsFromNames,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMAPIProp as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMAPIStatus_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ValidateState(this: *mut core::ffi::c_void, uluiparam: usize, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIStatus", "ValidateState", 14usize, |this| ((**(this as *const *const IMAPIStatus_Vtbl)).ValidateState)(this, uluiparam, ulflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SettingsDialog(this: *mut core::ffi::c_void, uluiparam: usize, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIStatus", "SettingsDialog", 15usize, |this| ((**(this as *const *const IMAPIStatus_Vtbl)).SettingsDialog)(this, uluiparam, ulflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangePassword(this: *mut core::ffi::c_void, lpoldpass: *const i8, lpnewpass: *const i8, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIStatus", "ChangePassword", 16usize, |this| ((**(this as *const *const IMAPIStatus_Vtbl)).ChangePassword)(this, lpoldpass, lpnewpass, ulflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlushQueues(this: *mut core::ffi::c_void, uluiparam: usize, cbtargettransport: u32, lptargettransport: *const ENTRYID, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPIStatus", "FlushQueues", 17usize, |this| ((**(this as *const *const IMAPIStatus_Vtbl)).FlushQueues)(this, uluiparam, cbtargettransport, lptargettransport, ulflags), |result| Some(*result))
+        }
+        Self { base__: <IMAPIProp_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ValidateState, SettingsDialog, ChangePassword, FlushQueues }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMAPIStatus as windows_core::Interface>::IID || <IMAPIProp_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMAPITable_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLastError(this: *mut core::ffi::c_void, hresult: windows_core::HRESULT, ulflags: u32, lppmapierror: *mut *mut MAPIERROR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPITable", "GetLastError", 3usize, |this| ((**(this as *const *const IMAPITable_Vtbl)).GetLastError)(this, hresult, ulflags, lppmapierror), |result| Some(*result))
+        }
+        unsafe extern "system" fn Advise(this: *mut core::ffi::c_void, uleventmask: u32, lpadvisesink: *mut core::ffi::c_void, lpulconnection: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPITable", "Advise", 4usize, |this| ((**(this as *const *const IMAPITable_Vtbl)).Advise)(this, uleventmask, lpadvisesink, lpulconnection), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unadvise(this: *mut core::ffi::c_void, ulconnection: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPITable", "Unadvise", 5usize, |this| ((**(this as *const *const IMAPITable_Vtbl)).Unadvise)(this, ulconnection), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, lpultablestatus: *mut u32, lpultabletype: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPITable", "GetStatus", 6usize, |this| ((**(this as *const *const IMAPITable_Vtbl)).GetStatus)(this, lpultablestatus, lpultabletype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColumns(this: *mut core::ffi::c_void, lpproptagarray: *mut SPropTagArray, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPITable", "SetColumns", 7usize, |this| ((**(this as *const *const IMAPITable_Vtbl)).SetColumns)(this, lpproptagarray, ulflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryColumns(this: *mut core::ffi::c_void, ulflags: u32, lpproptagarray: *mut *mut SPropTagArray) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPITable", "QueryColumns", 8usize, |this| ((**(this as *const *const IMAPITable_Vtbl)).QueryColumns)(this, ulflags, lpproptagarray), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRowCount(this: *mut core::ffi::c_void, ulflags: u32, lpulcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPITable", "GetRowCount", 9usize, |this| ((**(this as *const *const IMAPITable_Vtbl)).GetRowCount)(this, ulflags, lpulcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SeekRow(this: *mut core::ffi::c_void, bkorigin: u32, lrowcount: i32, lplrowssought: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPITable", "SeekRow", 10usize, |this| ((**(this as *const *const IMAPITable_Vtbl)).SeekRow)(this, bkorigin, lrowcount, lplrowssought), |result| Some(*result))
+        }
+        unsafe extern "system" fn SeekRowApprox(this: *mut core::ffi::c_void, ulnumerator: u32, uldenominator: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPITable", "SeekRowApprox", 11usize, |this| ((**(this as *const *const IMAPITable_Vtbl)).SeekRowApprox)(this, ulnumerator, uldenominator), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryPosition(this: *mut core::ffi::c_void, lpulrow: *mut u32, lpulnumerator: *mut u32, lpuldenominator: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPITable", "QueryPosition", 12usize, |this| ((**(this as *const *const IMAPITable_Vtbl)).QueryPosition)(this, lpulrow, lpulnumerator, lpuldenominator), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn FindRow(this: *mut core::ffi::c_void, lprestriction: *mut SRestriction, bkorigin: u32, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPITable", "FindRow", 13usize, |this| ((**(this as *const *const IMAPITable_Vtbl)).FindRow)(this, lprestriction, bkorigin, ulflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Restrict(this: *mut core::ffi::c_void, lprestriction: *mut SRestriction, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPITable", "Restrict", 14usize, |this| ((**(this as *const *const IMAPITable_Vtbl)).Restrict)(this, lprestriction, ulflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBookmark(this: *mut core::ffi::c_void, lpbkposition: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPITable", "CreateBookmark", 15usize, |this| ((**(this as *const *const IMAPITable_Vtbl)).CreateBookmark)(this, lpbkposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreeBookmark(this: *mut core::ffi::c_void, bkposition: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPITable", "FreeBookmark", 16usize, |this| ((**(this as *const *const IMAPITable_Vtbl)).FreeBookmark)(this, bkposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn SortTable(this: *mut core::ffi::c_void, lpsortcriteria: *mut SSortOrderSet, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPITable", "SortTable", 17usize, |this| ((**(this as *const *const IMAPITable_Vtbl)).SortTable)(this, lpsortcriteria, ulflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn QuerySortOrder(this: *mut core::ffi::c_void, lppsortcriteria: *mut *mut SSortOrderSet) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPITable", "QuerySortOrder", 18usize, |this| ((**(this as *const *const IMAPITable_Vtbl)).QuerySortOrder)(this, lppsortcriteria), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn QueryRows(this: *mut core::ffi::c_void, lrowcount: i32, ulflags: u32, lpprows: *mut *mut SRowSet) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPITable", "QueryRows", 19usize, |this| ((**(this as *const *const IMAPITable_Vtbl)).QueryRows)(this, lrowcount, ulflags, lpprows), |result| Some(*result))
+        }
+        unsafe extern "system" fn Abort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPITable", "Abort", 20usize, |this| ((**(this as *const *const IMAPITable_Vtbl)).Abort)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ExpandRow(this: *mut core::ffi::c_void, cbinstancekey: u32, pbinstancekey: *mut u8, ulrowcount: u32, ulflags: u32, lpprows: *mut *mut SRowSet, lpulmorerows: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPITable", "ExpandRow", 21usize, |this| ((**(this as *const *const IMAPITable_Vtbl)).ExpandRow)(this, cbinstancekey, pbinstancekey, ulrowcount, ulflags, lpprows, lpulmorerows), |result| Some(*result))
+        }
+        unsafe extern "system" fn CollapseRow(this: *mut core::ffi::c_void, cbinstancekey: u32, pbinstancekey: *mut u8, ulflags: u32, lpulrowcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPITable", "CollapseRow", 22usize, |this| ((**(this as *const *const IMAPITable_Vtbl)).CollapseRow)(this, cbinstancekey, pbinstancekey, ulflags, lpulrowcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForCompletion(this: *mut core::ffi::c_void, ulflags: u32, ultimeout: u32, lpultablestatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPITable", "WaitForCompletion", 23usize, |this| ((**(this as *const *const IMAPITable_Vtbl)).WaitForCompletion)(this, ulflags, ultimeout, lpultablestatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCollapseState(this: *mut core::ffi::c_void, ulflags: u32, cbinstancekey: u32, lpbinstancekey: *mut u8, lpcbcollapsestate: *mut u32, lppbcollapsestate: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPITable", "GetCollapseState", 24usize, |this| ((**(this as *const *const IMAPITable_Vtbl)).GetCollapseState)(this, ulflags, cbinstancekey, lpbinstancekey, lpcbcollapsestate, lppbcollapsestate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCollapseState(this: *mut core::ffi::c_void, ulflags: u32, cbcollapsestate: u32, pbcollapsestate: *mut u8, lpbklocation: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMAPITable", "SetCollapseState", 25usize, |this| ((**(this as *const *const IMAPITable_Vtbl)).SetCollapseState)(this, ulflags, cbcollapsestate, pbcollapsestate, lpbklocation), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetLastError,
+            Advise,
+            Unadvise,
+            GetStatus,
+            SetColumns,
+            QueryColumns,
+            GetRowCount,
+            SeekRow,
+            SeekRowApprox,
+            QueryPosition,
+            #[cfg(feature = "Win32_System_Com")]
+            FindRow,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            FindRow: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Restrict,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Restrict: 0,
+            CreateBookmark,
+            FreeBookmark,
+            SortTable,
+            QuerySortOrder,
+            #[cfg(feature = "Win32_System_Com")]
+            QueryRows,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            QueryRows: 0,
+            Abort,
+            #[cfg(feature = "Win32_System_Com")]
+            ExpandRow,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ExpandRow: 0,
+            CollapseRow,
+            WaitForCompletion,
+            GetCollapseState,
+            SetCollapseState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMAPITable as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMailUser_Vtbl {
+    const PROXY: Self = { Self { base__: <IMAPIProp_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMailUser as windows_core::Interface>::IID || <IMAPIProp_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAttachmentTable(this: *mut core::ffi::c_void, ulflags: u32, lpptable: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessage", "GetAttachmentTable", 14usize, |this| ((**(this as *const *const IMessage_Vtbl)).GetAttachmentTable)(this, ulflags, lpptable), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenAttach(this: *mut core::ffi::c_void, ulattachmentnum: u32, lpinterface: *const windows_core::GUID, ulflags: u32, lppattach: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessage", "OpenAttach", 15usize, |this| ((**(this as *const *const IMessage_Vtbl)).OpenAttach)(this, ulattachmentnum, lpinterface, ulflags, lppattach), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAttach(this: *mut core::ffi::c_void, lpinterface: *const windows_core::GUID, ulflags: u32, lpulattachmentnum: *mut u32, lppattach: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessage", "CreateAttach", 16usize, |this| ((**(this as *const *const IMessage_Vtbl)).CreateAttach)(this, lpinterface, ulflags, lpulattachmentnum, lppattach), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAttach(this: *mut core::ffi::c_void, ulattachmentnum: u32, uluiparam: usize, lpprogress: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessage", "DeleteAttach", 17usize, |this| ((**(this as *const *const IMessage_Vtbl)).DeleteAttach)(this, ulattachmentnum, uluiparam, lpprogress, ulflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecipientTable(this: *mut core::ffi::c_void, ulflags: u32, lpptable: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessage", "GetRecipientTable", 18usize, |this| ((**(this as *const *const IMessage_Vtbl)).GetRecipientTable)(this, ulflags, lpptable), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ModifyRecipients(this: *mut core::ffi::c_void, ulflags: u32, lpmods: *const ADRLIST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessage", "ModifyRecipients", 19usize, |this| ((**(this as *const *const IMessage_Vtbl)).ModifyRecipients)(this, ulflags, lpmods), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubmitMessage(this: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessage", "SubmitMessage", 20usize, |this| ((**(this as *const *const IMessage_Vtbl)).SubmitMessage)(this, ulflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReadFlag(this: *mut core::ffi::c_void, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessage", "SetReadFlag", 21usize, |this| ((**(this as *const *const IMessage_Vtbl)).SetReadFlag)(this, ulflags), |result| Some(*result))
+        }
+        Self {
+            base__: <IMAPIProp_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetAttachmentTable,
+            OpenAttach,
+            CreateAttach,
+            DeleteAttach,
+            GetRecipientTable,
+            #[cfg(feature = "Win32_System_Com")]
+            ModifyRecipients,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ModifyRecipients: 0,
+            SubmitMessage,
+            SetReadFlag,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMessage as windows_core::Interface>::IID || <IMAPIProp_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMsgStore_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Advise(this: *mut core::ffi::c_void, cbentryid: u32, lpentryid: *const ENTRYID, uleventmask: u32, lpadvisesink: *mut core::ffi::c_void, lpulconnection: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsgStore", "Advise", 14usize, |this| ((**(this as *const *const IMsgStore_Vtbl)).Advise)(this, cbentryid, lpentryid, uleventmask, lpadvisesink, lpulconnection), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unadvise(this: *mut core::ffi::c_void, ulconnection: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsgStore", "Unadvise", 15usize, |this| ((**(this as *const *const IMsgStore_Vtbl)).Unadvise)(this, ulconnection), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompareEntryIDs(this: *mut core::ffi::c_void, cbentryid1: u32, lpentryid1: *const ENTRYID, cbentryid2: u32, lpentryid2: *const ENTRYID, ulflags: u32, lpulresult: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsgStore", "CompareEntryIDs", 16usize, |this| ((**(this as *const *const IMsgStore_Vtbl)).CompareEntryIDs)(this, cbentryid1, lpentryid1, cbentryid2, lpentryid2, ulflags, lpulresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenEntry(this: *mut core::ffi::c_void, cbentryid: u32, lpentryid: *const ENTRYID, lpinterface: *const windows_core::GUID, ulflags: u32, lpulobjtype: *mut u32, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsgStore", "OpenEntry", 17usize, |this| ((**(this as *const *const IMsgStore_Vtbl)).OpenEntry)(this, cbentryid, lpentryid, lpinterface, ulflags, lpulobjtype, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReceiveFolder(this: *mut core::ffi::c_void, lpszmessageclass: *const i8, ulflags: u32, cbentryid: u32, lpentryid: *const ENTRYID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsgStore", "SetReceiveFolder", 18usize, |this| ((**(this as *const *const IMsgStore_Vtbl)).SetReceiveFolder)(this, lpszmessageclass, ulflags, cbentryid, lpentryid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReceiveFolder(this: *mut core::ffi::c_void, lpszmessageclass: *const i8, ulflags: u32, lpcbentryid: *mut u32, lppentryid: *mut *mut ENTRYID, lppszexplicitclass: *mut *mut i8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsgStore", "GetReceiveFolder", 19usize, |this| ((**(this as *const *const IMsgStore_Vtbl)).GetReceiveFolder)(this, lpszmessageclass, ulflags, lpcbentryid, lppentryid, lppszexplicitclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReceiveFolderTable(this: *mut core::ffi::c_void, ulflags: u32, lpptable: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsgStore", "GetReceiveFolderTable", 20usize, |this| ((**(this as *const *const IMsgStore_Vtbl)).GetReceiveFolderTable)(this, ulflags, lpptable), |result| Some(*result))
+        }
+        unsafe extern "system" fn StoreLogoff(this: *mut core::ffi::c_void, lpulflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsgStore", "StoreLogoff", 21usize, |this| ((**(this as *const *const IMsgStore_Vtbl)).StoreLogoff)(this, lpulflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AbortSubmit(this: *mut core::ffi::c_void, cbentryid: u32, lpentryid: *const ENTRYID, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsgStore", "AbortSubmit", 22usize, |this| ((**(this as *const *const IMsgStore_Vtbl)).AbortSubmit)(this, cbentryid, lpentryid, ulflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutgoingQueue(this: *mut core::ffi::c_void, ulflags: u32, lpptable: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsgStore", "GetOutgoingQueue", 23usize, |this| ((**(this as *const *const IMsgStore_Vtbl)).GetOutgoingQueue)(this, ulflags, lpptable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLockState(this: *mut core::ffi::c_void, lpmessage: *mut core::ffi::c_void, ullockstate: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsgStore", "SetLockState", 24usize, |this| ((**(this as *const *const IMsgStore_Vtbl)).SetLockState)(this, lpmessage, ullockstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn FinishedMsg(this: *mut core::ffi::c_void, ulflags: u32, cbentryid: u32, lpentryid: *const ENTRYID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsgStore", "FinishedMsg", 25usize, |this| ((**(this as *const *const IMsgStore_Vtbl)).FinishedMsg)(this, ulflags, cbentryid, lpentryid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn NotifyNewMail(this: *mut core::ffi::c_void, lpnotification: *const NOTIFICATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsgStore", "NotifyNewMail", 26usize, |this| ((**(this as *const *const IMsgStore_Vtbl)).NotifyNewMail)(this, lpnotification), |result| Some(*result))
+        }
+        Self {
+            base__: <IMAPIProp_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Advise,
+            Unadvise,
+            CompareEntryIDs,
+            OpenEntry,
+            SetReceiveFolder,
+            GetReceiveFolder,
+            GetReceiveFolderTable,
+            StoreLogoff,
+            AbortSubmit,
+            GetOutgoingQueue,
+            SetLockState,
+            FinishedMsg,
+            #[cfg(feature = "Win32_System_Com")]
+            NotifyNewMail,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            NotifyNewMail: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMsgStore as windows_core::Interface>::IID || <IMAPIProp_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IProfSect_Vtbl {
+    const PROXY: Self = { Self { base__: <IMAPIProp_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProfSect as windows_core::Interface>::IID || <IMAPIProp_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPropData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HrSetObjAccess(this: *mut core::ffi::c_void, ulaccess: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropData", "HrSetObjAccess", 14usize, |this| ((**(this as *const *const IPropData_Vtbl)).HrSetObjAccess)(this, ulaccess), |result| Some(*result))
+        }
+        unsafe extern "system" fn HrSetPropAccess(this: *mut core::ffi::c_void, lpproptagarray: *mut SPropTagArray, rgulaccess: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropData", "HrSetPropAccess", 15usize, |this| ((**(this as *const *const IPropData_Vtbl)).HrSetPropAccess)(this, lpproptagarray, rgulaccess), |result| Some(*result))
+        }
+        unsafe extern "system" fn HrGetPropAccess(this: *mut core::ffi::c_void, lppproptagarray: *mut *mut SPropTagArray, lprgulaccess: *mut *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropData", "HrGetPropAccess", 16usize, |this| ((**(this as *const *const IPropData_Vtbl)).HrGetPropAccess)(this, lppproptagarray, lprgulaccess), |result| Some(*result))
+        }
+        unsafe extern "system" fn HrAddObjProps(this: *mut core::ffi::c_void, lppproptagarray: *mut SPropTagArray, lprgulaccess: *mut *mut SPropProblemArray) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropData", "HrAddObjProps", 17usize, |this| ((**(this as *const *const IPropData_Vtbl)).HrAddObjProps)(this, lppproptagarray, lprgulaccess), |result| Some(*result))
+        }
+        Self { base__: <IMAPIProp_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HrSetObjAccess, HrSetPropAccess, HrGetPropAccess, HrAddObjProps }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropData as windows_core::Interface>::IID || <IMAPIProp_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IProviderAdmin_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLastError(this: *mut core::ffi::c_void, hresult: windows_core::HRESULT, ulflags: u32, lppmapierror: *mut *mut MAPIERROR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderAdmin", "GetLastError", 3usize, |this| ((**(this as *const *const IProviderAdmin_Vtbl)).GetLastError)(this, hresult, ulflags, lppmapierror), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProviderTable(this: *mut core::ffi::c_void, ulflags: u32, lpptable: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderAdmin", "GetProviderTable", 4usize, |this| ((**(this as *const *const IProviderAdmin_Vtbl)).GetProviderTable)(this, ulflags, lpptable), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateProvider(this: *mut core::ffi::c_void, lpszprovider: *const i8, cvalues: u32, lpprops: *const SPropValue, uluiparam: usize, ulflags: u32, lpuid: *mut MAPIUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderAdmin", "CreateProvider", 5usize, |this| ((**(this as *const *const IProviderAdmin_Vtbl)).CreateProvider)(this, lpszprovider, cvalues, lpprops, uluiparam, ulflags, lpuid), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteProvider(this: *mut core::ffi::c_void, lpuid: *const MAPIUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderAdmin", "DeleteProvider", 6usize, |this| ((**(this as *const *const IProviderAdmin_Vtbl)).DeleteProvider)(this, lpuid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenProfileSection(this: *mut core::ffi::c_void, lpuid: *const MAPIUID, lpinterface: *const windows_core::GUID, ulflags: u32, lppprofsect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderAdmin", "OpenProfileSection", 7usize, |this| ((**(this as *const *const IProviderAdmin_Vtbl)).OpenProfileSection)(this, lpuid, lpinterface, ulflags, lppprofsect), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetLastError,
+            GetProviderTable,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateProvider,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateProvider: 0,
+            DeleteProvider,
+            OpenProfileSection,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProviderAdmin as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITableData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HrGetView(this: *mut core::ffi::c_void, lpssortorderset: *mut SSortOrderSet, lpfcallerrelease: *mut CALLERRELEASE, ulcallerdata: u32, lppmapitable: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITableData", "HrGetView", 3usize, |this| ((**(this as *const *const ITableData_Vtbl)).HrGetView)(this, lpssortorderset, lpfcallerrelease, ulcallerdata, lppmapitable), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn HrModifyRow(this: *mut core::ffi::c_void, param0: *mut SRow) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITableData", "HrModifyRow", 4usize, |this| ((**(this as *const *const ITableData_Vtbl)).HrModifyRow)(this, param0), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn HrDeleteRow(this: *mut core::ffi::c_void, lpspropvalue: *mut SPropValue) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITableData", "HrDeleteRow", 5usize, |this| ((**(this as *const *const ITableData_Vtbl)).HrDeleteRow)(this, lpspropvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn HrQueryRow(this: *mut core::ffi::c_void, lpspropvalue: *mut SPropValue, lppsrow: *mut *mut SRow, lpulirow: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITableData", "HrQueryRow", 6usize, |this| ((**(this as *const *const ITableData_Vtbl)).HrQueryRow)(this, lpspropvalue, lppsrow, lpulirow), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn HrEnumRow(this: *mut core::ffi::c_void, ulrownumber: u32, lppsrow: *mut *mut SRow) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITableData", "HrEnumRow", 7usize, |this| ((**(this as *const *const ITableData_Vtbl)).HrEnumRow)(this, ulrownumber, lppsrow), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn HrNotify(this: *mut core::ffi::c_void, ulflags: u32, cvalues: u32, lpspropvalue: *mut SPropValue) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITableData", "HrNotify", 8usize, |this| ((**(this as *const *const ITableData_Vtbl)).HrNotify)(this, ulflags, cvalues, lpspropvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn HrInsertRow(this: *mut core::ffi::c_void, ulirow: u32, lpsrow: *mut SRow) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITableData", "HrInsertRow", 9usize, |this| ((**(this as *const *const ITableData_Vtbl)).HrInsertRow)(this, ulirow, lpsrow), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn HrModifyRows(this: *mut core::ffi::c_void, ulflags: u32, lpsrowset: *mut SRowSet) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITableData", "HrModifyRows", 10usize, |this| ((**(this as *const *const ITableData_Vtbl)).HrModifyRows)(this, ulflags, lpsrowset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn HrDeleteRows(this: *mut core::ffi::c_void, ulflags: u32, lprowsettodelete: *mut SRowSet, crowsdeleted: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITableData", "HrDeleteRows", 11usize, |this| ((**(this as *const *const ITableData_Vtbl)).HrDeleteRows)(this, ulflags, lprowsettodelete, crowsdeleted), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            HrGetView,
+            #[cfg(feature = "Win32_System_Com")]
+            HrModifyRow,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            HrModifyRow: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            HrDeleteRow,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            HrDeleteRow: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            HrQueryRow,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            HrQueryRow: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            HrEnumRow,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            HrEnumRow: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            HrNotify,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            HrNotify: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            HrInsertRow,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            HrInsertRow: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            HrModifyRows,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            HrModifyRows: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            HrDeleteRows,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            HrDeleteRows: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITableData as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWABExtInit_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, lpwabextdisplay: *mut WABEXTDISPLAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWABExtInit", "Initialize", 3usize, |this| ((**(this as *const *const IWABExtInit_Vtbl)).Initialize)(this, lpwabextdisplay), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWABExtInit as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWABObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLastError(this: *mut core::ffi::c_void, hresult: windows_core::HRESULT, ulflags: u32, lppmapierror: *mut *mut MAPIERROR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWABObject", "GetLastError", 3usize, |this| ((**(this as *const *const IWABObject_Vtbl)).GetLastError)(this, hresult, ulflags, lppmapierror), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllocateBuffer(this: *mut core::ffi::c_void, cbsize: u32, lppbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWABObject", "AllocateBuffer", 4usize, |this| ((**(this as *const *const IWABObject_Vtbl)).AllocateBuffer)(this, cbsize, lppbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllocateMore(this: *mut core::ffi::c_void, cbsize: u32, lpobject: *const core::ffi::c_void, lppbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWABObject", "AllocateMore", 5usize, |this| ((**(this as *const *const IWABObject_Vtbl)).AllocateMore)(this, cbsize, lpobject, lppbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreeBuffer(this: *mut core::ffi::c_void, lpbuffer: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWABObject", "FreeBuffer", 6usize, |this| ((**(this as *const *const IWABObject_Vtbl)).FreeBuffer)(this, lpbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn Backup(this: *mut core::ffi::c_void, lpfilename: windows_core::PCSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWABObject", "Backup", 7usize, |this| ((**(this as *const *const IWABObject_Vtbl)).Backup)(this, lpfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn Import(this: *mut core::ffi::c_void, lpwip: windows_core::PCSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWABObject", "Import", 8usize, |this| ((**(this as *const *const IWABObject_Vtbl)).Import)(this, lpwip), |result| Some(*result))
+        }
+        unsafe extern "system" fn Find(this: *mut core::ffi::c_void, lpiab: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWABObject", "Find", 9usize, |this| ((**(this as *const *const IWABObject_Vtbl)).Find)(this, lpiab, hwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn VCardDisplay(this: *mut core::ffi::c_void, lpiab: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, lpszfilename: windows_core::PCSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWABObject", "VCardDisplay", 10usize, |this| ((**(this as *const *const IWABObject_Vtbl)).VCardDisplay)(this, lpiab, hwnd, lpszfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn LDAPUrl(this: *mut core::ffi::c_void, lpiab: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, ulflags: u32, lpszurl: windows_core::PCSTR, lppmailuser: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWABObject", "LDAPUrl", 11usize, |this| ((**(this as *const *const IWABObject_Vtbl)).LDAPUrl)(this, lpiab, hwnd, ulflags, lpszurl, lppmailuser), |result| Some(*result))
+        }
+        unsafe extern "system" fn VCardCreate(this: *mut core::ffi::c_void, lpiab: *mut core::ffi::c_void, ulflags: u32, lpszvcard: windows_core::PCSTR, lpmailuser: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWABObject", "VCardCreate", 12usize, |this| ((**(this as *const *const IWABObject_Vtbl)).VCardCreate)(this, lpiab, ulflags, lpszvcard, lpmailuser), |result| Some(*result))
+        }
+        unsafe extern "system" fn VCardRetrieve(this: *mut core::ffi::c_void, lpiab: *mut core::ffi::c_void, ulflags: u32, lpszvcard: windows_core::PCSTR, lppmailuser: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWABObject", "VCardRetrieve", 13usize, |this| ((**(this as *const *const IWABObject_Vtbl)).VCardRetrieve)(this, lpiab, ulflags, lpszvcard, lppmailuser), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMe(this: *mut core::ffi::c_void, lpiab: *mut core::ffi::c_void, ulflags: u32, lpdwaction: *mut u32, lpsbeid: *mut SBinary, hwnd: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWABObject", "GetMe", 14usize, |this| ((**(this as *const *const IWABObject_Vtbl)).GetMe)(this, lpiab, ulflags, lpdwaction, lpsbeid, hwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMe(this: *mut core::ffi::c_void, lpiab: *mut core::ffi::c_void, ulflags: u32, sbeid: SBinary, hwnd: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWABObject", "SetMe", 15usize, |this| ((**(this as *const *const IWABObject_Vtbl)).SetMe)(this, lpiab, ulflags, sbeid, hwnd), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetLastError,
+            AllocateBuffer,
+            AllocateMore,
+            FreeBuffer,
+            Backup,
+            Import,
+            Find,
+            VCardDisplay,
+            LDAPUrl,
+            VCardCreate,
+            VCardRetrieve,
+            GetMe,
+            SetMe,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWABObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
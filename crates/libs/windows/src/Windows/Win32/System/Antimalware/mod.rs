@@ -258,6 +258,7 @@ pub struct AMSI_ATTRIBUTE(pub i32);
 impl windows_core::TypeKind for AMSI_ATTRIBUTE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AMSI_ATTRIBUTE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AMSI_ATTRIBUTE").field(&self.0).finish()
@@ -269,6 +270,7 @@ pub struct AMSI_RESULT(pub i32);
 impl windows_core::TypeKind for AMSI_RESULT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AMSI_RESULT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AMSI_RESULT").field(&self.0).finish()
@@ -280,6 +282,7 @@ pub struct AMSI_UAC_MSI_ACTION(pub i32);
 impl windows_core::TypeKind for AMSI_UAC_MSI_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AMSI_UAC_MSI_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AMSI_UAC_MSI_ACTION").field(&self.0).finish()
@@ -291,6 +294,7 @@ pub struct AMSI_UAC_REQUEST_TYPE(pub i32);
 impl windows_core::TypeKind for AMSI_UAC_REQUEST_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AMSI_UAC_REQUEST_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AMSI_UAC_REQUEST_TYPE").field(&self.0).finish()
@@ -302,13 +306,15 @@ pub struct AMSI_UAC_TRUST_STATE(pub i32);
 impl windows_core::TypeKind for AMSI_UAC_TRUST_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AMSI_UAC_TRUST_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AMSI_UAC_TRUST_STATE").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AMSI_UAC_REQUEST_AX_INFO {
     pub ulLength: u32,
     pub lpwszLocalInstallPath: windows_core::PWSTR,
@@ -323,7 +329,8 @@ impl Default for AMSI_UAC_REQUEST_AX_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AMSI_UAC_REQUEST_COM_INFO {
     pub ulLength: u32,
     pub lpwszServerBinary: windows_core::PWSTR,
@@ -374,7 +381,8 @@ impl Default for AMSI_UAC_REQUEST_CONTEXT_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AMSI_UAC_REQUEST_EXE_INFO {
     pub ulLength: u32,
     pub lpwszApplicationName: windows_core::PWSTR,
@@ -390,7 +398,8 @@ impl Default for AMSI_UAC_REQUEST_EXE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AMSI_UAC_REQUEST_MSI_INFO {
     pub ulLength: u32,
     pub MsiAction: AMSI_UAC_MSI_ACTION,
@@ -413,7 +422,8 @@ impl Default for AMSI_UAC_REQUEST_MSI_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AMSI_UAC_REQUEST_PACKAGED_APP_INFO {
     pub ulLength: u32,
     pub lpwszApplicationName: windows_core::PWSTR,
@@ -431,7 +441,8 @@ impl Default for AMSI_UAC_REQUEST_PACKAGED_APP_INFO {
 }
 pub const CAntimalware: windows_core::GUID = windows_core::GUID::from_u128(0xfdb00e52_a214_4aa1_8fba_4357bb0072ec);
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HAMSICONTEXT(pub *mut core::ffi::c_void);
 impl HAMSICONTEXT {
     pub fn is_invalid(&self) -> bool {
@@ -447,7 +458,8 @@ impl windows_core::TypeKind for HAMSICONTEXT {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HAMSISESSION(pub *mut core::ffi::c_void);
 impl HAMSISESSION {
     pub fn is_invalid(&self) -> bool {
@@ -464,3 +476,5 @@ impl windows_core::TypeKind for HAMSISESSION {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,87 @@
+impl windows_core::imp::ProxyVtable for IAmsiStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAttribute(this: *mut core::ffi::c_void, attribute: AMSI_ATTRIBUTE, datasize: u32, data: *mut u8, retdata: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAmsiStream", "GetAttribute", 3usize, |this| ((**(this as *const *const IAmsiStream_Vtbl)).GetAttribute)(this, attribute, datasize, data, retdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn Read(this: *mut core::ffi::c_void, position: u64, size: u32, buffer: *mut u8, readsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAmsiStream", "Read", 4usize, |this| ((**(this as *const *const IAmsiStream_Vtbl)).Read)(this, position, size, buffer, readsize), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAttribute, Read }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAmsiStream as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAntimalware_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Scan(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, result: *mut AMSI_RESULT, provider: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAntimalware", "Scan", 3usize, |this| ((**(this as *const *const IAntimalware_Vtbl)).Scan)(this, stream, result, provider), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseSession(this: *mut core::ffi::c_void, session: u64) {
+            windows_core::imp::proxy_call(this, "IAntimalware", "CloseSession", 4usize, |this| ((**(this as *const *const IAntimalware_Vtbl)).CloseSession)(this, session), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Scan, CloseSession }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAntimalware as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAntimalware2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Notify(this: *mut core::ffi::c_void, buffer: *const core::ffi::c_void, length: u32, contentname: windows_core::PCWSTR, appname: windows_core::PCWSTR, presult: *mut AMSI_RESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAntimalware2", "Notify", 5usize, |this| ((**(this as *const *const IAntimalware2_Vtbl)).Notify)(this, buffer, length, contentname, appname, presult), |result| Some(*result))
+        }
+        Self { base__: <IAntimalware_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Notify }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAntimalware2 as windows_core::Interface>::IID || <IAntimalware_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAntimalwareProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Scan(this: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, result: *mut AMSI_RESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAntimalwareProvider", "Scan", 3usize, |this| ((**(this as *const *const IAntimalwareProvider_Vtbl)).Scan)(this, stream, result), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseSession(this: *mut core::ffi::c_void, session: u64) {
+            windows_core::imp::proxy_call(this, "IAntimalwareProvider", "CloseSession", 4usize, |this| ((**(this as *const *const IAntimalwareProvider_Vtbl)).CloseSession)(this, session), |_| None)
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, displayname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAntimalwareProvider", "DisplayName", 5usize, |this| ((**(this as *const *const IAntimalwareProvider_Vtbl)).DisplayName)(this, displayname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Scan, CloseSession, DisplayName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAntimalwareProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAntimalwareProvider2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Notify(this: *mut core::ffi::c_void, buffer: *const core::ffi::c_void, length: u32, contentname: windows_core::PCWSTR, appname: windows_core::PCWSTR, presult: *mut AMSI_RESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAntimalwareProvider2", "Notify", 6usize, |this| ((**(this as *const *const IAntimalwareProvider2_Vtbl)).Notify)(this, buffer, length, contentname, appname, presult), |result| Some(*result))
+        }
+        Self { base__: <IAntimalwareProvider_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Notify }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAntimalwareProvider2 as windows_core::Interface>::IID || <IAntimalwareProvider_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAntimalwareUacProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UacScan(this: *mut core::ffi::c_void, context: *const AMSI_UAC_REQUEST_CONTEXT, result: *mut AMSI_RESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAntimalwareUacProvider", "UacScan", 3usize, |this| ((**(this as *const *const IAntimalwareUacProvider_Vtbl)).UacScan)(this, context, result), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, displayname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAntimalwareUacProvider", "DisplayName", 4usize, |this| ((**(this as *const *const IAntimalwareUacProvider_Vtbl)).DisplayName)(this, displayname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UacScan, DisplayName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAntimalwareUacProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
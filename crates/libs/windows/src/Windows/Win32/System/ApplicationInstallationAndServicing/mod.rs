@@ -6268,6 +6268,7 @@ pub struct ACTCTX_COMPATIBILITY_ELEMENT_TYPE(pub i32);
 impl windows_core::TypeKind for ACTCTX_COMPATIBILITY_ELEMENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ACTCTX_COMPATIBILITY_ELEMENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ACTCTX_COMPATIBILITY_ELEMENT_TYPE").field(&self.0).finish()
@@ -6279,6 +6280,7 @@ pub struct ACTCTX_REQUESTED_RUN_LEVEL(pub i32);
 impl windows_core::TypeKind for ACTCTX_REQUESTED_RUN_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ACTCTX_REQUESTED_RUN_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ACTCTX_REQUESTED_RUN_LEVEL").field(&self.0).finish()
@@ -6290,6 +6292,7 @@ pub struct ADVERTISEFLAGS(pub i32);
 impl windows_core::TypeKind for ADVERTISEFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ADVERTISEFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ADVERTISEFLAGS").field(&self.0).finish()
@@ -6301,6 +6304,7 @@ pub struct ASM_BIND_FLAGS(pub i32);
 impl windows_core::TypeKind for ASM_BIND_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ASM_BIND_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ASM_BIND_FLAGS").field(&self.0).finish()
@@ -6345,6 +6349,7 @@ pub struct ASM_CMP_FLAGS(pub i32);
 impl windows_core::TypeKind for ASM_CMP_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ASM_CMP_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ASM_CMP_FLAGS").field(&self.0).finish()
@@ -6356,6 +6361,7 @@ pub struct ASM_DISPLAY_FLAGS(pub i32);
 impl windows_core::TypeKind for ASM_DISPLAY_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ASM_DISPLAY_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ASM_DISPLAY_FLAGS").field(&self.0).finish()
@@ -6367,6 +6373,7 @@ pub struct ASM_NAME(pub i32);
 impl windows_core::TypeKind for ASM_NAME {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ASM_NAME {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ASM_NAME").field(&self.0).finish()
@@ -6378,6 +6385,7 @@ pub struct CREATE_ASM_NAME_OBJ_FLAGS(pub i32);
 impl windows_core::TypeKind for CREATE_ASM_NAME_OBJ_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CREATE_ASM_NAME_OBJ_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CREATE_ASM_NAME_OBJ_FLAGS").field(&self.0).finish()
@@ -6389,6 +6397,7 @@ pub struct IASSEMBLYCACHE_UNINSTALL_DISPOSITION(pub u32);
 impl windows_core::TypeKind for IASSEMBLYCACHE_UNINSTALL_DISPOSITION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IASSEMBLYCACHE_UNINSTALL_DISPOSITION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IASSEMBLYCACHE_UNINSTALL_DISPOSITION").field(&self.0).finish()
@@ -6400,6 +6409,7 @@ pub struct INSTALLFEATUREATTRIBUTE(pub i32);
 impl windows_core::TypeKind for INSTALLFEATUREATTRIBUTE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INSTALLFEATUREATTRIBUTE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INSTALLFEATUREATTRIBUTE").field(&self.0).finish()
@@ -6411,6 +6421,7 @@ pub struct INSTALLLEVEL(pub i32);
 impl windows_core::TypeKind for INSTALLLEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INSTALLLEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INSTALLLEVEL").field(&self.0).finish()
@@ -6422,6 +6433,7 @@ pub struct INSTALLLOGATTRIBUTES(pub i32);
 impl windows_core::TypeKind for INSTALLLOGATTRIBUTES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INSTALLLOGATTRIBUTES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INSTALLLOGATTRIBUTES").field(&self.0).finish()
@@ -6433,6 +6445,7 @@ pub struct INSTALLLOGMODE(pub i32);
 impl windows_core::TypeKind for INSTALLLOGMODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INSTALLLOGMODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INSTALLLOGMODE").field(&self.0).finish()
@@ -6444,6 +6457,7 @@ pub struct INSTALLMESSAGE(pub i32);
 impl windows_core::TypeKind for INSTALLMESSAGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INSTALLMESSAGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INSTALLMESSAGE").field(&self.0).finish()
@@ -6455,6 +6469,7 @@ pub struct INSTALLMODE(pub i32);
 impl windows_core::TypeKind for INSTALLMODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INSTALLMODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INSTALLMODE").field(&self.0).finish()
@@ -6466,6 +6481,7 @@ pub struct INSTALLSTATE(pub i32);
 impl windows_core::TypeKind for INSTALLSTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INSTALLSTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INSTALLSTATE").field(&self.0).finish()
@@ -6477,6 +6493,7 @@ pub struct INSTALLTYPE(pub i32);
 impl windows_core::TypeKind for INSTALLTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INSTALLTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INSTALLTYPE").field(&self.0).finish()
@@ -6488,6 +6505,7 @@ pub struct INSTALLUILEVEL(pub i32);
 impl windows_core::TypeKind for INSTALLUILEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INSTALLUILEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INSTALLUILEVEL").field(&self.0).finish()
@@ -6499,6 +6517,7 @@ pub struct MSIADVERTISEOPTIONFLAGS(pub i32);
 impl windows_core::TypeKind for MSIADVERTISEOPTIONFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSIADVERTISEOPTIONFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSIADVERTISEOPTIONFLAGS").field(&self.0).finish()
@@ -6510,6 +6529,7 @@ pub struct MSIARCHITECTUREFLAGS(pub i32);
 impl windows_core::TypeKind for MSIARCHITECTUREFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSIARCHITECTUREFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSIARCHITECTUREFLAGS").field(&self.0).finish()
@@ -6521,6 +6541,7 @@ pub struct MSIASSEMBLYINFO(pub u32);
 impl windows_core::TypeKind for MSIASSEMBLYINFO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSIASSEMBLYINFO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSIASSEMBLYINFO").field(&self.0).finish()
@@ -6532,6 +6553,7 @@ pub struct MSICODE(pub i32);
 impl windows_core::TypeKind for MSICODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSICODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSICODE").field(&self.0).finish()
@@ -6543,6 +6565,7 @@ pub struct MSICOLINFO(pub i32);
 impl windows_core::TypeKind for MSICOLINFO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSICOLINFO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSICOLINFO").field(&self.0).finish()
@@ -6554,6 +6577,7 @@ pub struct MSICONDITION(pub i32);
 impl windows_core::TypeKind for MSICONDITION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSICONDITION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSICONDITION").field(&self.0).finish()
@@ -6565,6 +6589,7 @@ pub struct MSICOSTTREE(pub i32);
 impl windows_core::TypeKind for MSICOSTTREE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSICOSTTREE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSICOSTTREE").field(&self.0).finish()
@@ -6576,6 +6601,7 @@ pub struct MSIDBERROR(pub i32);
 impl windows_core::TypeKind for MSIDBERROR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSIDBERROR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSIDBERROR").field(&self.0).finish()
@@ -6587,6 +6613,7 @@ pub struct MSIDBSTATE(pub i32);
 impl windows_core::TypeKind for MSIDBSTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSIDBSTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSIDBSTATE").field(&self.0).finish()
@@ -6598,6 +6625,7 @@ pub struct MSIINSTALLCONTEXT(pub i32);
 impl windows_core::TypeKind for MSIINSTALLCONTEXT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSIINSTALLCONTEXT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSIINSTALLCONTEXT").field(&self.0).finish()
@@ -6609,6 +6637,7 @@ pub struct MSIMODIFY(pub i32);
 impl windows_core::TypeKind for MSIMODIFY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSIMODIFY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSIMODIFY").field(&self.0).finish()
@@ -6620,6 +6649,7 @@ pub struct MSIOPENPACKAGEFLAGS(pub i32);
 impl windows_core::TypeKind for MSIOPENPACKAGEFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSIOPENPACKAGEFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSIOPENPACKAGEFLAGS").field(&self.0).finish()
@@ -6631,6 +6661,7 @@ pub struct MSIPATCHDATATYPE(pub i32);
 impl windows_core::TypeKind for MSIPATCHDATATYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSIPATCHDATATYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSIPATCHDATATYPE").field(&self.0).finish()
@@ -6642,6 +6673,7 @@ pub struct MSIPATCHSTATE(pub i32);
 impl windows_core::TypeKind for MSIPATCHSTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSIPATCHSTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSIPATCHSTATE").field(&self.0).finish()
@@ -6653,6 +6685,7 @@ pub struct MSIRUNMODE(pub i32);
 impl windows_core::TypeKind for MSIRUNMODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSIRUNMODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSIRUNMODE").field(&self.0).finish()
@@ -6664,6 +6697,7 @@ pub struct MSISOURCETYPE(pub i32);
 impl windows_core::TypeKind for MSISOURCETYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSISOURCETYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSISOURCETYPE").field(&self.0).finish()
@@ -6675,6 +6709,7 @@ pub struct MSITRANSACTION(pub i32);
 impl windows_core::TypeKind for MSITRANSACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSITRANSACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSITRANSACTION").field(&self.0).finish()
@@ -6686,6 +6721,7 @@ pub struct MSITRANSACTIONSTATE(pub u32);
 impl windows_core::TypeKind for MSITRANSACTIONSTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSITRANSACTIONSTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSITRANSACTIONSTATE").field(&self.0).finish()
@@ -6697,6 +6733,7 @@ pub struct MSITRANSFORM_ERROR(pub i32);
 impl windows_core::TypeKind for MSITRANSFORM_ERROR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSITRANSFORM_ERROR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSITRANSFORM_ERROR").field(&self.0).finish()
@@ -6708,6 +6745,7 @@ pub struct MSITRANSFORM_VALIDATE(pub i32);
 impl windows_core::TypeKind for MSITRANSFORM_VALIDATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSITRANSFORM_VALIDATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSITRANSFORM_VALIDATE").field(&self.0).finish()
@@ -6719,6 +6757,7 @@ pub struct PACKMAN_RUNTIME(pub i32);
 impl windows_core::TypeKind for PACKMAN_RUNTIME {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PACKMAN_RUNTIME {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PACKMAN_RUNTIME").field(&self.0).finish()
@@ -6730,6 +6769,7 @@ pub struct PM_ACTIVATION_POLICY(pub i32);
 impl windows_core::TypeKind for PM_ACTIVATION_POLICY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PM_ACTIVATION_POLICY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PM_ACTIVATION_POLICY").field(&self.0).finish()
@@ -6741,6 +6781,7 @@ pub struct PM_APPLICATION_HUBTYPE(pub i32);
 impl windows_core::TypeKind for PM_APPLICATION_HUBTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PM_APPLICATION_HUBTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PM_APPLICATION_HUBTYPE").field(&self.0).finish()
@@ -6752,6 +6793,7 @@ pub struct PM_APPLICATION_INSTALL_TYPE(pub i32);
 impl windows_core::TypeKind for PM_APPLICATION_INSTALL_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PM_APPLICATION_INSTALL_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PM_APPLICATION_INSTALL_TYPE").field(&self.0).finish()
@@ -6763,6 +6805,7 @@ pub struct PM_APPLICATION_STATE(pub i32);
 impl windows_core::TypeKind for PM_APPLICATION_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PM_APPLICATION_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PM_APPLICATION_STATE").field(&self.0).finish()
@@ -6774,6 +6817,7 @@ pub struct PM_APP_GENRE(pub i32);
 impl windows_core::TypeKind for PM_APP_GENRE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PM_APP_GENRE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PM_APP_GENRE").field(&self.0).finish()
@@ -6785,6 +6829,7 @@ pub struct PM_ENUM_APP_FILTER(pub i32);
 impl windows_core::TypeKind for PM_ENUM_APP_FILTER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PM_ENUM_APP_FILTER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PM_ENUM_APP_FILTER").field(&self.0).finish()
@@ -6796,6 +6841,7 @@ pub struct PM_ENUM_BSA_FILTER(pub i32);
 impl windows_core::TypeKind for PM_ENUM_BSA_FILTER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PM_ENUM_BSA_FILTER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PM_ENUM_BSA_FILTER").field(&self.0).finish()
@@ -6807,6 +6853,7 @@ pub struct PM_ENUM_BW_FILTER(pub i32);
 impl windows_core::TypeKind for PM_ENUM_BW_FILTER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PM_ENUM_BW_FILTER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PM_ENUM_BW_FILTER").field(&self.0).finish()
@@ -6818,6 +6865,7 @@ pub struct PM_ENUM_EXTENSION_FILTER(pub i32);
 impl windows_core::TypeKind for PM_ENUM_EXTENSION_FILTER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PM_ENUM_EXTENSION_FILTER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PM_ENUM_EXTENSION_FILTER").field(&self.0).finish()
@@ -6829,6 +6877,7 @@ pub struct PM_ENUM_TASK_FILTER(pub i32);
 impl windows_core::TypeKind for PM_ENUM_TASK_FILTER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PM_ENUM_TASK_FILTER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PM_ENUM_TASK_FILTER").field(&self.0).finish()
@@ -6840,6 +6889,7 @@ pub struct PM_ENUM_TILE_FILTER(pub i32);
 impl windows_core::TypeKind for PM_ENUM_TILE_FILTER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PM_ENUM_TILE_FILTER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PM_ENUM_TILE_FILTER").field(&self.0).finish()
@@ -6851,6 +6901,7 @@ pub struct PM_LIVETILE_RECURRENCE_TYPE(pub i32);
 impl windows_core::TypeKind for PM_LIVETILE_RECURRENCE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PM_LIVETILE_RECURRENCE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PM_LIVETILE_RECURRENCE_TYPE").field(&self.0).finish()
@@ -6862,6 +6913,7 @@ pub struct PM_LOGO_SIZE(pub i32);
 impl windows_core::TypeKind for PM_LOGO_SIZE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PM_LOGO_SIZE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PM_LOGO_SIZE").field(&self.0).finish()
@@ -6873,6 +6925,7 @@ pub struct PM_STARTTILE_TYPE(pub i32);
 impl windows_core::TypeKind for PM_STARTTILE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PM_STARTTILE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PM_STARTTILE_TYPE").field(&self.0).finish()
@@ -6884,6 +6937,7 @@ pub struct PM_TASK_TRANSITION(pub i32);
 impl windows_core::TypeKind for PM_TASK_TRANSITION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PM_TASK_TRANSITION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PM_TASK_TRANSITION").field(&self.0).finish()
@@ -6895,6 +6949,7 @@ pub struct PM_TASK_TYPE(pub i32);
 impl windows_core::TypeKind for PM_TASK_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PM_TASK_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PM_TASK_TYPE").field(&self.0).finish()
@@ -6906,6 +6961,7 @@ pub struct PM_TILE_HUBTYPE(pub i32);
 impl windows_core::TypeKind for PM_TILE_HUBTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PM_TILE_HUBTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PM_TILE_HUBTYPE").field(&self.0).finish()
@@ -6917,6 +6973,7 @@ pub struct PM_TILE_SIZE(pub i32);
 impl windows_core::TypeKind for PM_TILE_SIZE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PM_TILE_SIZE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PM_TILE_SIZE").field(&self.0).finish()
@@ -6928,6 +6985,7 @@ pub struct QUERYASMINFO_FLAGS(pub u32);
 impl windows_core::TypeKind for QUERYASMINFO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for QUERYASMINFO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("QUERYASMINFO_FLAGS").field(&self.0).finish()
@@ -6972,6 +7030,7 @@ pub struct REINSTALLMODE(pub i32);
 impl windows_core::TypeKind for REINSTALLMODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for REINSTALLMODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("REINSTALLMODE").field(&self.0).finish()
@@ -6983,6 +7042,7 @@ pub struct RESULTTYPES(pub i32);
 impl windows_core::TypeKind for RESULTTYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RESULTTYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RESULTTYPES").field(&self.0).finish()
@@ -6994,6 +7054,7 @@ pub struct SCRIPTFLAGS(pub i32);
 impl windows_core::TypeKind for SCRIPTFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCRIPTFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCRIPTFLAGS").field(&self.0).finish()
@@ -7005,6 +7066,7 @@ pub struct STATUSTYPES(pub i32);
 impl windows_core::TypeKind for STATUSTYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STATUSTYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STATUSTYPES").field(&self.0).finish()
@@ -7016,6 +7078,7 @@ pub struct TILE_TEMPLATE_TYPE(pub i32);
 impl windows_core::TypeKind for TILE_TEMPLATE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TILE_TEMPLATE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TILE_TEMPLATE_TYPE").field(&self.0).finish()
@@ -7027,6 +7090,7 @@ pub struct USERINFOSTATE(pub i32);
 impl windows_core::TypeKind for USERINFOSTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USERINFOSTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USERINFOSTATE").field(&self.0).finish()
@@ -7038,6 +7102,7 @@ pub struct msidbAssemblyAttributes(pub i32);
 impl windows_core::TypeKind for msidbAssemblyAttributes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for msidbAssemblyAttributes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("msidbAssemblyAttributes").field(&self.0).finish()
@@ -7049,6 +7114,7 @@ pub struct msidbClassAttributes(pub i32);
 impl windows_core::TypeKind for msidbClassAttributes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for msidbClassAttributes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("msidbClassAttributes").field(&self.0).finish()
@@ -7060,6 +7126,7 @@ pub struct msidbComponentAttributes(pub i32);
 impl windows_core::TypeKind for msidbComponentAttributes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for msidbComponentAttributes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("msidbComponentAttributes").field(&self.0).finish()
@@ -7071,6 +7138,7 @@ pub struct msidbControlAttributes(pub i32);
 impl windows_core::TypeKind for msidbControlAttributes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for msidbControlAttributes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("msidbControlAttributes").field(&self.0).finish()
@@ -7082,6 +7150,7 @@ pub struct msidbCustomActionType(pub i32);
 impl windows_core::TypeKind for msidbCustomActionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for msidbCustomActionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("msidbCustomActionType").field(&self.0).finish()
@@ -7093,6 +7162,7 @@ pub struct msidbDialogAttributes(pub i32);
 impl windows_core::TypeKind for msidbDialogAttributes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for msidbDialogAttributes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("msidbDialogAttributes").field(&self.0).finish()
@@ -7104,6 +7174,7 @@ pub struct msidbEmbeddedUIAttributes(pub i32);
 impl windows_core::TypeKind for msidbEmbeddedUIAttributes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for msidbEmbeddedUIAttributes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("msidbEmbeddedUIAttributes").field(&self.0).finish()
@@ -7115,6 +7186,7 @@ pub struct msidbFeatureAttributes(pub i32);
 impl windows_core::TypeKind for msidbFeatureAttributes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for msidbFeatureAttributes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("msidbFeatureAttributes").field(&self.0).finish()
@@ -7126,6 +7198,7 @@ pub struct msidbFileAttributes(pub i32);
 impl windows_core::TypeKind for msidbFileAttributes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for msidbFileAttributes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("msidbFileAttributes").field(&self.0).finish()
@@ -7137,6 +7210,7 @@ pub struct msidbIniFileAction(pub i32);
 impl windows_core::TypeKind for msidbIniFileAction {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for msidbIniFileAction {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("msidbIniFileAction").field(&self.0).finish()
@@ -7148,6 +7222,7 @@ pub struct msidbLocatorType(pub i32);
 impl windows_core::TypeKind for msidbLocatorType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for msidbLocatorType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("msidbLocatorType").field(&self.0).finish()
@@ -7159,6 +7234,7 @@ pub struct msidbMoveFileOptions(pub i32);
 impl windows_core::TypeKind for msidbMoveFileOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for msidbMoveFileOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("msidbMoveFileOptions").field(&self.0).finish()
@@ -7170,6 +7246,7 @@ pub struct msidbODBCDataSourceRegistration(pub i32);
 impl windows_core::TypeKind for msidbODBCDataSourceRegistration {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for msidbODBCDataSourceRegistration {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("msidbODBCDataSourceRegistration").field(&self.0).finish()
@@ -7181,6 +7258,7 @@ pub struct msidbPatchAttributes(pub i32);
 impl windows_core::TypeKind for msidbPatchAttributes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for msidbPatchAttributes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("msidbPatchAttributes").field(&self.0).finish()
@@ -7192,6 +7270,7 @@ pub struct msidbRegistryRoot(pub i32);
 impl windows_core::TypeKind for msidbRegistryRoot {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for msidbRegistryRoot {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("msidbRegistryRoot").field(&self.0).finish()
@@ -7203,6 +7282,7 @@ pub struct msidbRemoveFileInstallMode(pub i32);
 impl windows_core::TypeKind for msidbRemoveFileInstallMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for msidbRemoveFileInstallMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("msidbRemoveFileInstallMode").field(&self.0).finish()
@@ -7214,6 +7294,7 @@ pub struct msidbServiceConfigEvent(pub i32);
 impl windows_core::TypeKind for msidbServiceConfigEvent {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for msidbServiceConfigEvent {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("msidbServiceConfigEvent").field(&self.0).finish()
@@ -7225,6 +7306,7 @@ pub struct msidbServiceControlEvent(pub i32);
 impl windows_core::TypeKind for msidbServiceControlEvent {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for msidbServiceControlEvent {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("msidbServiceControlEvent").field(&self.0).finish()
@@ -7236,6 +7318,7 @@ pub struct msidbServiceInstallErrorControl(pub i32);
 impl windows_core::TypeKind for msidbServiceInstallErrorControl {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for msidbServiceInstallErrorControl {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("msidbServiceInstallErrorControl").field(&self.0).finish()
@@ -7247,6 +7330,7 @@ pub struct msidbSumInfoSourceType(pub i32);
 impl windows_core::TypeKind for msidbSumInfoSourceType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for msidbSumInfoSourceType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("msidbSumInfoSourceType").field(&self.0).finish()
@@ -7258,6 +7342,7 @@ pub struct msidbTextStyleStyleBits(pub i32);
 impl windows_core::TypeKind for msidbTextStyleStyleBits {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for msidbTextStyleStyleBits {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("msidbTextStyleStyleBits").field(&self.0).finish()
@@ -7269,6 +7354,7 @@ pub struct msidbUpgradeAttributes(pub i32);
 impl windows_core::TypeKind for msidbUpgradeAttributes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for msidbUpgradeAttributes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("msidbUpgradeAttributes").field(&self.0).finish()
@@ -7280,6 +7366,7 @@ pub struct msifiFastInstallBits(pub i32);
 impl windows_core::TypeKind for msifiFastInstallBits {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for msifiFastInstallBits {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("msifiFastInstallBits").field(&self.0).finish()
@@ -7291,6 +7378,7 @@ pub struct msirbRebootReason(pub i32);
 impl windows_core::TypeKind for msirbRebootReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for msirbRebootReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("msirbRebootReason").field(&self.0).finish()
@@ -7302,6 +7390,7 @@ pub struct msirbRebootType(pub i32);
 impl windows_core::TypeKind for msirbRebootType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for msirbRebootType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("msirbRebootType").field(&self.0).finish()
@@ -7313,13 +7402,15 @@ pub struct msmErrorType(pub i32);
 impl windows_core::TypeKind for msmErrorType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for msmErrorType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("msmErrorType").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ACTCTXA {
     pub cbSize: u32,
     pub dwFlags: u32,
@@ -7340,7 +7431,8 @@ impl Default for ACTCTXA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ACTCTXW {
     pub cbSize: u32,
     pub dwFlags: u32,
@@ -7362,7 +7454,8 @@ impl Default for ACTCTXW {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_WindowsProgramming")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ACTCTX_SECTION_KEYED_DATA {
     pub cbSize: u32,
     pub ulDataFormatVersion: u32,
@@ -7388,7 +7481,8 @@ impl Default for ACTCTX_SECTION_KEYED_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ACTIVATION_CONTEXT_ASSEMBLY_DETAILED_INFORMATION {
     pub ulFlags: u32,
     pub ulEncodedAssemblyIdentityLength: u32,
@@ -7419,7 +7513,8 @@ impl Default for ACTIVATION_CONTEXT_ASSEMBLY_DETAILED_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ACTIVATION_CONTEXT_COMPATIBILITY_INFORMATION {
     pub ElementCount: u32,
     pub Elements: [COMPATIBILITY_CONTEXT_ELEMENT; 1],
@@ -7433,7 +7528,8 @@ impl Default for ACTIVATION_CONTEXT_COMPATIBILITY_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ACTIVATION_CONTEXT_DETAILED_INFORMATION {
     pub dwFlags: u32,
     pub ulFormatVersion: u32,
@@ -7457,7 +7553,8 @@ impl Default for ACTIVATION_CONTEXT_DETAILED_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ACTIVATION_CONTEXT_QUERY_INDEX {
     pub ulAssemblyIndex: u32,
     pub ulFileIndexInAssembly: u32,
@@ -7471,7 +7568,8 @@ impl Default for ACTIVATION_CONTEXT_QUERY_INDEX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ACTIVATION_CONTEXT_RUN_LEVEL_INFORMATION {
     pub ulFlags: u32,
     pub RunLevel: ACTCTX_REQUESTED_RUN_LEVEL,
@@ -7486,7 +7584,8 @@ impl Default for ACTIVATION_CONTEXT_RUN_LEVEL_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ASSEMBLY_FILE_DETAILED_INFORMATION {
     pub ulFlags: u32,
     pub ulFilenameLength: u32,
@@ -7503,7 +7602,8 @@ impl Default for ASSEMBLY_FILE_DETAILED_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ASSEMBLY_INFO {
     pub cbAssemblyInfo: u32,
     pub dwAssemblyFlags: u32,
@@ -7520,7 +7620,8 @@ impl Default for ASSEMBLY_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COMPATIBILITY_CONTEXT_ELEMENT {
     pub Id: windows_core::GUID,
     pub Type: ACTCTX_COMPATIBILITY_ELEMENT_TYPE,
@@ -7535,7 +7636,8 @@ impl Default for COMPATIBILITY_CONTEXT_ELEMENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DELTA_HASH {
     pub HashSize: u32,
     pub HashValue: [u8; 32],
@@ -7550,7 +7652,8 @@ impl Default for DELTA_HASH {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Security_Cryptography")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DELTA_HEADER_INFO {
     pub FileTypeSet: i64,
     pub FileType: i64,
@@ -7600,7 +7703,8 @@ impl Default for DELTA_INPUT_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DELTA_OUTPUT {
     pub lpStart: *mut core::ffi::c_void,
     pub uSize: usize,
@@ -7614,7 +7718,8 @@ impl Default for DELTA_OUTPUT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FUSION_INSTALL_REFERENCE {
     pub cbSize: u32,
     pub dwFlags: u32,
@@ -7631,7 +7736,8 @@ impl Default for FUSION_INSTALL_REFERENCE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MSIFILEHASHINFO {
     pub dwFileHashInfoSize: u32,
     pub dwData: [u32; 4],
@@ -7645,7 +7751,8 @@ impl Default for MSIFILEHASHINFO {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MSIHANDLE(pub u32);
 impl MSIHANDLE {
     pub fn is_invalid(&self) -> bool {
@@ -7669,7 +7776,8 @@ impl windows_core::TypeKind for MSIHANDLE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MSIPATCHSEQUENCEINFOA {
     pub szPatchData: windows_core::PCSTR,
     pub ePatchDataType: MSIPATCHDATATYPE,
@@ -7685,7 +7793,8 @@ impl Default for MSIPATCHSEQUENCEINFOA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MSIPATCHSEQUENCEINFOW {
     pub szPatchData: windows_core::PCWSTR,
     pub ePatchDataType: MSIPATCHDATATYPE,
@@ -7702,7 +7811,8 @@ impl Default for MSIPATCHSEQUENCEINFOW {
 }
 pub const MsmMerge: windows_core::GUID = windows_core::GUID::from_u128(0x0adda830_2c26_11d2_ad65_00a0c9af11a6);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PATCH_IGNORE_RANGE {
     pub OffsetInOldFile: u32,
     pub LengthInBytes: u32,
@@ -7716,7 +7826,8 @@ impl Default for PATCH_IGNORE_RANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PATCH_INTERLEAVE_MAP {
     pub CountRanges: u32,
     pub Range: [PATCH_INTERLEAVE_MAP_0; 1],
@@ -7730,7 +7841,8 @@ impl Default for PATCH_INTERLEAVE_MAP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PATCH_INTERLEAVE_MAP_0 {
     pub OldOffset: u32,
     pub OldLength: u32,
@@ -7778,7 +7890,8 @@ impl Default for PATCH_OLD_FILE_INFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PATCH_OLD_FILE_INFO_A {
     pub SizeOfThisStruct: u32,
     pub OldFileName: windows_core::PCSTR,
@@ -7796,7 +7909,8 @@ impl Default for PATCH_OLD_FILE_INFO_A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PATCH_OLD_FILE_INFO_H {
     pub SizeOfThisStruct: u32,
     pub OldFileHandle: super::super::Foundation::HANDLE,
@@ -7814,7 +7928,8 @@ impl Default for PATCH_OLD_FILE_INFO_H {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PATCH_OLD_FILE_INFO_W {
     pub SizeOfThisStruct: u32,
     pub OldFileName: windows_core::PCWSTR,
@@ -7832,7 +7947,8 @@ impl Default for PATCH_OLD_FILE_INFO_W {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PATCH_OPTION_DATA {
     pub SizeOfThisStruct: u32,
     pub SymbolOptionFlags: u32,
@@ -7853,7 +7969,8 @@ impl Default for PATCH_OPTION_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PATCH_RETAIN_RANGE {
     pub OffsetInOldFile: u32,
     pub LengthInBytes: u32,
@@ -7868,7 +7985,8 @@ impl Default for PATCH_RETAIN_RANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PMSIHANDLE {
     pub m_h: MSIHANDLE,
 }
@@ -7882,7 +8000,8 @@ impl Default for PMSIHANDLE {
 }
 pub const PMSvc: windows_core::GUID = windows_core::GUID::from_u128(0xb9e511fc_e364_497a_a121_b7b3612cedce);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PM_APPTASKTYPE {
     pub ProductID: windows_core::GUID,
     pub TaskType: PM_TASK_TYPE,
@@ -7896,7 +8015,8 @@ impl Default for PM_APPTASKTYPE {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PM_BSATASKID {
     pub ProductID: windows_core::GUID,
     pub TaskID: core::mem::ManuallyDrop<windows_core::BSTR>,
@@ -7915,7 +8035,8 @@ impl Default for PM_BSATASKID {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PM_BWTASKID {
     pub ProductID: windows_core::GUID,
     pub TaskID: core::mem::ManuallyDrop<windows_core::BSTR>,
@@ -7985,7 +8106,8 @@ impl Default for PM_ENUM_FILTER_0 {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PM_EXTENSIONCONSUMER {
     pub ConsumerPID: windows_core::GUID,
     pub ExtensionID: core::mem::ManuallyDrop<windows_core::BSTR>,
@@ -8004,7 +8126,8 @@ impl Default for PM_EXTENSIONCONSUMER {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PM_INSTALLINFO {
     pub ProductID: windows_core::GUID,
     pub PackagePath: core::mem::ManuallyDrop<windows_core::BSTR>,
@@ -8030,7 +8153,8 @@ impl Default for PM_INSTALLINFO {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PM_INVOCATIONINFO {
     pub URIBaseOrAUMID: core::mem::ManuallyDrop<windows_core::BSTR>,
     pub URIFragmentOrArgs: core::mem::ManuallyDrop<windows_core::BSTR>,
@@ -8049,7 +8173,8 @@ impl Default for PM_INVOCATIONINFO {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PM_STARTAPPBLOB {
     pub cbSize: u32,
     pub ProductID: windows_core::GUID,
@@ -8077,7 +8202,8 @@ impl Default for PM_STARTAPPBLOB {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PM_STARTTILEBLOB {
     pub cbSize: u32,
     pub ProductID: windows_core::GUID,
@@ -8107,7 +8233,8 @@ impl Default for PM_STARTTILEBLOB {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PM_UPDATEINFO {
     pub ProductID: windows_core::GUID,
     pub PackagePath: core::mem::ManuallyDrop<windows_core::BSTR>,
@@ -8131,7 +8258,8 @@ impl Default for PM_UPDATEINFO {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PM_UPDATEINFO_LEGACY {
     pub ProductID: windows_core::GUID,
     pub PackagePath: core::mem::ManuallyDrop<windows_core::BSTR>,
@@ -8154,7 +8282,8 @@ impl Default for PM_UPDATEINFO_LEGACY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROTECTED_FILE_DATA {
     pub FileName: [u16; 260],
     pub FileNumber: u32,
@@ -8176,3 +8305,5 @@ pub type PPATCH_PROGRESS_CALLBACK = Option<unsafe extern "system" fn(callbackcon
 pub type PPATCH_SYMLOAD_CALLBACK = Option<unsafe extern "system" fn(whichfile: u32, symbolfilename: windows_core::PCSTR, symtype: u32, symbolfilechecksum: u32, symbolfiletimedate: u32, imagefilechecksum: u32, imagefiletimedate: u32, callbackcontext: *mut core::ffi::c_void) -> super::super::Foundation::BOOL>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1624 @@
+impl windows_core::imp::ProxyVtable for IAssemblyCache_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UninstallAssembly(this: *mut core::ffi::c_void, dwflags: u32, pszassemblyname: windows_core::PCWSTR, prefdata: *mut FUSION_INSTALL_REFERENCE, puldisposition: *mut IASSEMBLYCACHE_UNINSTALL_DISPOSITION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAssemblyCache", "UninstallAssembly", 3usize, |this| ((**(this as *const *const IAssemblyCache_Vtbl)).UninstallAssembly)(this, dwflags, pszassemblyname, prefdata, puldisposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryAssemblyInfo(this: *mut core::ffi::c_void, dwflags: QUERYASMINFO_FLAGS, pszassemblyname: windows_core::PCWSTR, pasminfo: *mut ASSEMBLY_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAssemblyCache", "QueryAssemblyInfo", 4usize, |this| ((**(this as *const *const IAssemblyCache_Vtbl)).QueryAssemblyInfo)(this, dwflags, pszassemblyname, pasminfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAssemblyCacheItem(this: *mut core::ffi::c_void, dwflags: u32, pvreserved: *mut core::ffi::c_void, ppasmitem: *mut *mut core::ffi::c_void, pszassemblyname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAssemblyCache", "CreateAssemblyCacheItem", 5usize, |this| ((**(this as *const *const IAssemblyCache_Vtbl)).CreateAssemblyCacheItem)(this, dwflags, pvreserved, ppasmitem, pszassemblyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reserved(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAssemblyCache", "Reserved", 6usize, |this| ((**(this as *const *const IAssemblyCache_Vtbl)).Reserved)(this, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstallAssembly(this: *mut core::ffi::c_void, dwflags: u32, pszmanifestfilepath: windows_core::PCWSTR, prefdata: *mut FUSION_INSTALL_REFERENCE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAssemblyCache", "InstallAssembly", 7usize, |this| ((**(this as *const *const IAssemblyCache_Vtbl)).InstallAssembly)(this, dwflags, pszmanifestfilepath, prefdata), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            UninstallAssembly,
+            QueryAssemblyInfo,
+            CreateAssemblyCacheItem,
+            Reserved,
+            InstallAssembly,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAssemblyCache as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAssemblyCacheItem_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateStream(this: *mut core::ffi::c_void, dwflags: u32, pszstreamname: windows_core::PCWSTR, dwformat: u32, dwformatflags: u32, ppistream: *mut *mut core::ffi::c_void, pulimaxsize: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAssemblyCacheItem", "CreateStream", 3usize, |this| ((**(this as *const *const IAssemblyCacheItem_Vtbl)).CreateStream)(this, dwflags, pszstreamname, dwformat, dwformatflags, ppistream, pulimaxsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void, dwflags: u32, puldisposition: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAssemblyCacheItem", "Commit", 4usize, |this| ((**(this as *const *const IAssemblyCacheItem_Vtbl)).Commit)(this, dwflags, puldisposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn AbortItem(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAssemblyCacheItem", "AbortItem", 5usize, |this| ((**(this as *const *const IAssemblyCacheItem_Vtbl)).AbortItem)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateStream: 0,
+            Commit,
+            AbortItem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAssemblyCacheItem as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAssemblyName_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, propertyid: u32, pvproperty: *mut core::ffi::c_void, cbproperty: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAssemblyName", "SetProperty", 3usize, |this| ((**(this as *const *const IAssemblyName_Vtbl)).SetProperty)(this, propertyid, pvproperty, cbproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, propertyid: u32, pvproperty: *mut core::ffi::c_void, pcbproperty: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAssemblyName", "GetProperty", 4usize, |this| ((**(this as *const *const IAssemblyName_Vtbl)).GetProperty)(this, propertyid, pvproperty, pcbproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finalize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAssemblyName", "Finalize", 5usize, |this| ((**(this as *const *const IAssemblyName_Vtbl)).Finalize)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayName(this: *mut core::ffi::c_void, szdisplayname: windows_core::PWSTR, pccdisplayname: *mut u32, dwdisplayflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAssemblyName", "GetDisplayName", 6usize, |this| ((**(this as *const *const IAssemblyName_Vtbl)).GetDisplayName)(this, szdisplayname, pccdisplayname, dwdisplayflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reserved(this: *mut core::ffi::c_void, refiid: *const windows_core::GUID, punkreserved1: *mut core::ffi::c_void, punkreserved2: *mut core::ffi::c_void, szreserved: windows_core::PCWSTR, llreserved: i64, pvreserved: *mut core::ffi::c_void, cbreserved: u32, ppreserved: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAssemblyName", "Reserved", 7usize, |this| ((**(this as *const *const IAssemblyName_Vtbl)).Reserved)(this, refiid, punkreserved1, punkreserved2, szreserved, llreserved, pvreserved, cbreserved, ppreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, lpcwbuffer: *mut u32, pwzname: windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAssemblyName", "GetName", 8usize, |this| ((**(this as *const *const IAssemblyName_Vtbl)).GetName)(this, lpcwbuffer, pwzname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersion(this: *mut core::ffi::c_void, pdwversionhi: *mut u32, pdwversionlow: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAssemblyName", "GetVersion", 9usize, |this| ((**(this as *const *const IAssemblyName_Vtbl)).GetVersion)(this, pdwversionhi, pdwversionlow), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEqual(this: *mut core::ffi::c_void, pname: *mut core::ffi::c_void, dwcmpflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAssemblyName", "IsEqual", 10usize, |this| ((**(this as *const *const IAssemblyName_Vtbl)).IsEqual)(this, pname, dwcmpflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, pname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAssemblyName", "Clone", 11usize, |this| ((**(this as *const *const IAssemblyName_Vtbl)).Clone)(this, pname), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetProperty,
+            GetProperty,
+            Finalize,
+            GetDisplayName,
+            Reserved,
+            GetName,
+            GetVersion,
+            IsEqual,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAssemblyName as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumMsmDependency_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, cfetch: u32, rgmsmdependencies: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumMsmDependency", "Next", 3usize, |this| ((**(this as *const *const IEnumMsmDependency_Vtbl)).Next)(this, cfetch, rgmsmdependencies, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, cskip: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumMsmDependency", "Skip", 4usize, |this| ((**(this as *const *const IEnumMsmDependency_Vtbl)).Skip)(this, cskip), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumMsmDependency", "Reset", 5usize, |this| ((**(this as *const *const IEnumMsmDependency_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, pemsmdependencies: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumMsmDependency", "Clone", 6usize, |this| ((**(this as *const *const IEnumMsmDependency_Vtbl)).Clone)(this, pemsmdependencies), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Skip,
+            Reset,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumMsmDependency as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEnumMsmError_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, cfetch: u32, rgmsmerrors: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumMsmError", "Next", 3usize, |this| ((**(this as *const *const IEnumMsmError_Vtbl)).Next)(this, cfetch, rgmsmerrors, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, cskip: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumMsmError", "Skip", 4usize, |this| ((**(this as *const *const IEnumMsmError_Vtbl)).Skip)(this, cskip), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumMsmError", "Reset", 5usize, |this| ((**(this as *const *const IEnumMsmError_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, pemsmerrors: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumMsmError", "Clone", 6usize, |this| ((**(this as *const *const IEnumMsmError_Vtbl)).Clone)(this, pemsmerrors), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            Skip,
+            Reset,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumMsmError as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumMsmString_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, cfetch: u32, rgbstrstrings: *mut core::mem::MaybeUninit<windows_core::BSTR>, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumMsmString", "Next", 3usize, |this| ((**(this as *const *const IEnumMsmString_Vtbl)).Next)(this, cfetch, rgbstrstrings, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, cskip: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumMsmString", "Skip", 4usize, |this| ((**(this as *const *const IEnumMsmString_Vtbl)).Skip)(this, cskip), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumMsmString", "Reset", 5usize, |this| ((**(this as *const *const IEnumMsmString_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, pemsmstrings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumMsmString", "Clone", 6usize, |this| ((**(this as *const *const IEnumMsmString_Vtbl)).Clone)(this, pemsmstrings), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumMsmString as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMsmDependencies_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, item: i32, r#return: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmDependencies", "get_Item", 7usize, |this| ((**(this as *const *const IMsmDependencies_Vtbl)).get_Item)(this, item, r#return), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmDependencies", "Count", 8usize, |this| ((**(this as *const *const IMsmDependencies_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, newenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmDependencies", "_NewEnum", 9usize, |this| ((**(this as *const *const IMsmDependencies_Vtbl))._NewEnum)(this, newenum), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            Count,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMsmDependencies as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMsmDependency_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Module(this: *mut core::ffi::c_void, module: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmDependency", "Module", 7usize, |this| ((**(this as *const *const IMsmDependency_Vtbl)).Module)(this, module), |result| Some(*result))
+        }
+        unsafe extern "system" fn Language(this: *mut core::ffi::c_void, language: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmDependency", "Language", 8usize, |this| ((**(this as *const *const IMsmDependency_Vtbl)).Language)(this, language), |result| Some(*result))
+        }
+        unsafe extern "system" fn Version(this: *mut core::ffi::c_void, version: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmDependency", "Version", 9usize, |this| ((**(this as *const *const IMsmDependency_Vtbl)).Version)(this, version), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Module, Language, Version }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMsmDependency as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMsmError_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, errortype: *mut msmErrorType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmError", "Type", 7usize, |this| ((**(this as *const *const IMsmError_Vtbl)).Type)(this, errortype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, errorpath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmError", "Path", 8usize, |this| ((**(this as *const *const IMsmError_Vtbl)).Path)(this, errorpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn Language(this: *mut core::ffi::c_void, errorlanguage: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmError", "Language", 9usize, |this| ((**(this as *const *const IMsmError_Vtbl)).Language)(this, errorlanguage), |result| Some(*result))
+        }
+        unsafe extern "system" fn DatabaseTable(this: *mut core::ffi::c_void, errortable: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmError", "DatabaseTable", 10usize, |this| ((**(this as *const *const IMsmError_Vtbl)).DatabaseTable)(this, errortable), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DatabaseKeys(this: *mut core::ffi::c_void, errorkeys: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmError", "DatabaseKeys", 11usize, |this| ((**(this as *const *const IMsmError_Vtbl)).DatabaseKeys)(this, errorkeys), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModuleTable(this: *mut core::ffi::c_void, errortable: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmError", "ModuleTable", 12usize, |this| ((**(this as *const *const IMsmError_Vtbl)).ModuleTable)(this, errortable), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ModuleKeys(this: *mut core::ffi::c_void, errorkeys: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmError", "ModuleKeys", 13usize, |this| ((**(this as *const *const IMsmError_Vtbl)).ModuleKeys)(this, errorkeys), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Type,
+            Path,
+            Language,
+            DatabaseTable,
+            #[cfg(feature = "Win32_System_Com")]
+            DatabaseKeys,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DatabaseKeys: 0,
+            ModuleTable,
+            #[cfg(feature = "Win32_System_Com")]
+            ModuleKeys,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ModuleKeys: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMsmError as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMsmErrors_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, item: i32, r#return: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmErrors", "get_Item", 7usize, |this| ((**(this as *const *const IMsmErrors_Vtbl)).get_Item)(this, item, r#return), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmErrors", "Count", 8usize, |this| ((**(this as *const *const IMsmErrors_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, newenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmErrors", "_NewEnum", 9usize, |this| ((**(this as *const *const IMsmErrors_Vtbl))._NewEnum)(this, newenum), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            Count,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMsmErrors as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMsmGetFiles_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ModuleFiles(this: *mut core::ffi::c_void, files: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmGetFiles", "ModuleFiles", 7usize, |this| ((**(this as *const *const IMsmGetFiles_Vtbl)).ModuleFiles)(this, files), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            ModuleFiles,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ModuleFiles: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMsmGetFiles as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMsmMerge_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenDatabase(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmMerge", "OpenDatabase", 7usize, |this| ((**(this as *const *const IMsmMerge_Vtbl)).OpenDatabase)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenModule(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, language: i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmMerge", "OpenModule", 8usize, |this| ((**(this as *const *const IMsmMerge_Vtbl)).OpenModule)(this, path, language), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseDatabase(this: *mut core::ffi::c_void, commit: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmMerge", "CloseDatabase", 9usize, |this| ((**(this as *const *const IMsmMerge_Vtbl)).CloseDatabase)(this, commit), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseModule(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmMerge", "CloseModule", 10usize, |this| ((**(this as *const *const IMsmMerge_Vtbl)).CloseModule)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenLog(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmMerge", "OpenLog", 11usize, |this| ((**(this as *const *const IMsmMerge_Vtbl)).OpenLog)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseLog(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmMerge", "CloseLog", 12usize, |this| ((**(this as *const *const IMsmMerge_Vtbl)).CloseLog)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Log(this: *mut core::ffi::c_void, message: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmMerge", "Log", 13usize, |this| ((**(this as *const *const IMsmMerge_Vtbl)).Log)(this, message), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Errors(this: *mut core::ffi::c_void, errors: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmMerge", "Errors", 14usize, |this| ((**(this as *const *const IMsmMerge_Vtbl)).Errors)(this, errors), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Dependencies(this: *mut core::ffi::c_void, dependencies: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmMerge", "Dependencies", 15usize, |this| ((**(this as *const *const IMsmMerge_Vtbl)).Dependencies)(this, dependencies), |result| Some(*result))
+        }
+        unsafe extern "system" fn Merge(this: *mut core::ffi::c_void, feature: core::mem::MaybeUninit<windows_core::BSTR>, redirectdir: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmMerge", "Merge", 16usize, |this| ((**(this as *const *const IMsmMerge_Vtbl)).Merge)(this, feature, redirectdir), |result| Some(*result))
+        }
+        unsafe extern "system" fn Connect(this: *mut core::ffi::c_void, feature: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmMerge", "Connect", 17usize, |this| ((**(this as *const *const IMsmMerge_Vtbl)).Connect)(this, feature), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtractCAB(this: *mut core::ffi::c_void, filename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmMerge", "ExtractCAB", 18usize, |this| ((**(this as *const *const IMsmMerge_Vtbl)).ExtractCAB)(this, filename), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtractFiles(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmMerge", "ExtractFiles", 19usize, |this| ((**(this as *const *const IMsmMerge_Vtbl)).ExtractFiles)(this, path), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OpenDatabase,
+            OpenModule,
+            CloseDatabase,
+            CloseModule,
+            OpenLog,
+            CloseLog,
+            Log,
+            #[cfg(feature = "Win32_System_Com")]
+            Errors,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Errors: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Dependencies,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Dependencies: 0,
+            Merge,
+            Connect,
+            ExtractCAB,
+            ExtractFiles,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMsmMerge as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMsmStrings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, item: i32, r#return: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmStrings", "get_Item", 7usize, |this| ((**(this as *const *const IMsmStrings_Vtbl)).get_Item)(this, item, r#return), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmStrings", "Count", 8usize, |this| ((**(this as *const *const IMsmStrings_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, newenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMsmStrings", "_NewEnum", 9usize, |this| ((**(this as *const *const IMsmStrings_Vtbl))._NewEnum)(this, newenum), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, get_Item, Count, _NewEnum }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMsmStrings as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPMApplicationInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProductID(this: *mut core::ffi::c_void, pproductid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "ProductID", 3usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).ProductID)(this, pproductid), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstanceID(this: *mut core::ffi::c_void, pinstanceid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "InstanceID", 4usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).InstanceID)(this, pinstanceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OfferID(this: *mut core::ffi::c_void, pofferid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "OfferID", 5usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).OfferID)(this, pofferid), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultTask(this: *mut core::ffi::c_void, pdefaulttask: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "DefaultTask", 6usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).DefaultTask)(this, pdefaulttask), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppTitle(this: *mut core::ffi::c_void, papptitle: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "AppTitle", 7usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).AppTitle)(this, papptitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn IconPath(this: *mut core::ffi::c_void, pappiconpath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "IconPath", 8usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).IconPath)(this, pappiconpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotificationState(this: *mut core::ffi::c_void, pisnotified: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "NotificationState", 9usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).NotificationState)(this, pisnotified), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppInstallType(this: *mut core::ffi::c_void, pappinstalltype: *mut PM_APPLICATION_INSTALL_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "AppInstallType", 10usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).AppInstallType)(this, pappinstalltype), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, pstate: *mut PM_APPLICATION_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "State", 11usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).State)(this, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRevoked(this: *mut core::ffi::c_void, pisrevoked: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "IsRevoked", 12usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).IsRevoked)(this, pisrevoked), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateAvailable(this: *mut core::ffi::c_void, pisupdateavailable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "UpdateAvailable", 13usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).UpdateAvailable)(this, pisupdateavailable), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstallDate(this: *mut core::ffi::c_void, pinstalldate: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "InstallDate", 14usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).InstallDate)(this, pinstalldate), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUninstallable(this: *mut core::ffi::c_void, pisuninstallable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "IsUninstallable", 15usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).IsUninstallable)(this, pisuninstallable), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsThemable(this: *mut core::ffi::c_void, pisthemable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "IsThemable", 16usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).IsThemable)(this, pisthemable), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTrial(this: *mut core::ffi::c_void, pistrial: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "IsTrial", 17usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).IsTrial)(this, pistrial), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstallPath(this: *mut core::ffi::c_void, pinstallpath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "InstallPath", 18usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).InstallPath)(this, pinstallpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataRoot(this: *mut core::ffi::c_void, pdataroot: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "DataRoot", 19usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).DataRoot)(this, pdataroot), |result| Some(*result))
+        }
+        unsafe extern "system" fn Genre(this: *mut core::ffi::c_void, pgenre: *mut PM_APP_GENRE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "Genre", 20usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).Genre)(this, pgenre), |result| Some(*result))
+        }
+        unsafe extern "system" fn Publisher(this: *mut core::ffi::c_void, ppublisher: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "Publisher", 21usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).Publisher)(this, ppublisher), |result| Some(*result))
+        }
+        unsafe extern "system" fn Author(this: *mut core::ffi::c_void, pauthor: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "Author", 22usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).Author)(this, pauthor), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, pdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "Description", 23usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).Description)(this, pdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn Version(this: *mut core::ffi::c_void, pversion: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "Version", 24usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).Version)(this, pversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_InvocationInfo(this: *mut core::ffi::c_void, pimageurn: *mut core::mem::MaybeUninit<windows_core::BSTR>, pparameters: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "get_InvocationInfo", 25usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).get_InvocationInfo)(this, pimageurn, pparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppPlatMajorVersion(this: *mut core::ffi::c_void, pmajorver: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "AppPlatMajorVersion", 26usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).AppPlatMajorVersion)(this, pmajorver), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppPlatMinorVersion(this: *mut core::ffi::c_void, pminorver: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "AppPlatMinorVersion", 27usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).AppPlatMinorVersion)(this, pminorver), |result| Some(*result))
+        }
+        unsafe extern "system" fn PublisherID(this: *mut core::ffi::c_void, ppublisherid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "PublisherID", 28usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).PublisherID)(this, ppublisherid), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMultiCore(this: *mut core::ffi::c_void, pismulticore: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "IsMultiCore", 29usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).IsMultiCore)(this, pismulticore), |result| Some(*result))
+        }
+        unsafe extern "system" fn SID(this: *mut core::ffi::c_void, psid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "SID", 30usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).SID)(this, psid), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppPlatMajorVersionLightUp(this: *mut core::ffi::c_void, pmajorver: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "AppPlatMajorVersionLightUp", 31usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).AppPlatMajorVersionLightUp)(this, pmajorver), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppPlatMinorVersionLightUp(this: *mut core::ffi::c_void, pminorver: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "AppPlatMinorVersionLightUp", 32usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).AppPlatMinorVersionLightUp)(this, pminorver), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_UpdateAvailable(this: *mut core::ffi::c_void, isupdateavailable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "set_UpdateAvailable", 33usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).set_UpdateAvailable)(this, isupdateavailable), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_NotificationState(this: *mut core::ffi::c_void, isnotified: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "set_NotificationState", 34usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).set_NotificationState)(this, isnotified), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_IconPath(this: *mut core::ffi::c_void, appiconpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "set_IconPath", 35usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).set_IconPath)(this, appiconpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_UninstallableState(this: *mut core::ffi::c_void, isuninstallable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "set_UninstallableState", 36usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).set_UninstallableState)(this, isuninstallable), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPinableOnKidZone(this: *mut core::ffi::c_void, pispinable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "IsPinableOnKidZone", 37usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).IsPinableOnKidZone)(this, pispinable), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOriginallyPreInstalled(this: *mut core::ffi::c_void, pispreinstalled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "IsOriginallyPreInstalled", 38usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).IsOriginallyPreInstalled)(this, pispreinstalled), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInstallOnSD(this: *mut core::ffi::c_void, pisinstallonsd: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "IsInstallOnSD", 39usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).IsInstallOnSD)(this, pisinstallonsd), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOptoutOnSD(this: *mut core::ffi::c_void, pisoptoutonsd: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "IsOptoutOnSD", 40usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).IsOptoutOnSD)(this, pisoptoutonsd), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOptoutBackupRestore(this: *mut core::ffi::c_void, pisoptoutbackuprestore: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "IsOptoutBackupRestore", 41usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).IsOptoutBackupRestore)(this, pisoptoutbackuprestore), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_EnterpriseDisabled(this: *mut core::ffi::c_void, isdisabled: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "set_EnterpriseDisabled", 42usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).set_EnterpriseDisabled)(this, isdisabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_EnterpriseUninstallable(this: *mut core::ffi::c_void, isuninstallable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "set_EnterpriseUninstallable", 43usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).set_EnterpriseUninstallable)(this, isuninstallable), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnterpriseDisabled(this: *mut core::ffi::c_void, isdisabled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "EnterpriseDisabled", 44usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).EnterpriseDisabled)(this, isdisabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnterpriseUninstallable(this: *mut core::ffi::c_void, isuninstallable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "EnterpriseUninstallable", 45usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).EnterpriseUninstallable)(this, isuninstallable), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsVisibleOnAppList(this: *mut core::ffi::c_void, pisvisible: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "IsVisibleOnAppList", 46usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).IsVisibleOnAppList)(this, pisvisible), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInboxApp(this: *mut core::ffi::c_void, pisinboxapp: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "IsInboxApp", 47usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).IsInboxApp)(this, pisinboxapp), |result| Some(*result))
+        }
+        unsafe extern "system" fn StorageID(this: *mut core::ffi::c_void, pstorageid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "StorageID", 48usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).StorageID)(this, pstorageid), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartAppBlob(this: *mut core::ffi::c_void, pblob: *mut PM_STARTAPPBLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "StartAppBlob", 49usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).StartAppBlob)(this, pblob), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMovable(this: *mut core::ffi::c_void, pismovable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "IsMovable", 50usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).IsMovable)(this, pismovable), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeploymentAppEnumerationHubFilter(this: *mut core::ffi::c_void, hubtype: *mut PM_TILE_HUBTYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "DeploymentAppEnumerationHubFilter", 51usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).DeploymentAppEnumerationHubFilter)(this, hubtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModifiedDate(this: *mut core::ffi::c_void, pmodifieddate: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "ModifiedDate", 52usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).ModifiedDate)(this, pmodifieddate), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOriginallyRestored(this: *mut core::ffi::c_void, pisrestored: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "IsOriginallyRestored", 53usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).IsOriginallyRestored)(this, pisrestored), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShouldDeferMdilBind(this: *mut core::ffi::c_void, pfdefermdilbind: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "ShouldDeferMdilBind", 54usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).ShouldDeferMdilBind)(this, pfdefermdilbind), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFullyPreInstall(this: *mut core::ffi::c_void, pfisfullypreinstall: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "IsFullyPreInstall", 55usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).IsFullyPreInstall)(this, pfisfullypreinstall), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_IsMdilMaintenanceNeeded(this: *mut core::ffi::c_void, fismdilmaintenanceneeded: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "set_IsMdilMaintenanceNeeded", 56usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).set_IsMdilMaintenanceNeeded)(this, fismdilmaintenanceneeded), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_Title(this: *mut core::ffi::c_void, apptitle: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfo", "set_Title", 57usize, |this| ((**(this as *const *const IPMApplicationInfo_Vtbl)).set_Title)(this, apptitle), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProductID,
+            InstanceID,
+            OfferID,
+            DefaultTask,
+            AppTitle,
+            IconPath,
+            NotificationState,
+            AppInstallType,
+            State,
+            IsRevoked,
+            UpdateAvailable,
+            InstallDate,
+            IsUninstallable,
+            IsThemable,
+            IsTrial,
+            InstallPath,
+            DataRoot,
+            Genre,
+            Publisher,
+            Author,
+            Description,
+            Version,
+            get_InvocationInfo,
+            AppPlatMajorVersion,
+            AppPlatMinorVersion,
+            PublisherID,
+            IsMultiCore,
+            SID,
+            AppPlatMajorVersionLightUp,
+            AppPlatMinorVersionLightUp,
+            set_UpdateAvailable,
+            set_NotificationState,
+            set_IconPath,
+            set_UninstallableState,
+            IsPinableOnKidZone,
+            IsOriginallyPreInstalled,
+            IsInstallOnSD,
+            IsOptoutOnSD,
+            IsOptoutBackupRestore,
+            set_EnterpriseDisabled,
+            set_EnterpriseUninstallable,
+            EnterpriseDisabled,
+            EnterpriseUninstallable,
+            IsVisibleOnAppList,
+            IsInboxApp,
+            StorageID,
+            StartAppBlob,
+            IsMovable,
+            DeploymentAppEnumerationHubFilter,
+            ModifiedDate,
+            IsOriginallyRestored,
+            ShouldDeferMdilBind,
+            IsFullyPreInstall,
+            set_IsMdilMaintenanceNeeded,
+            set_Title,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPMApplicationInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPMApplicationInfoEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ppappinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMApplicationInfoEnumerator", "Next", 3usize, |this| ((**(this as *const *const IPMApplicationInfoEnumerator_Vtbl)).Next)(this, ppappinfo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPMApplicationInfoEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPMBackgroundServiceAgentInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProductID(this: *mut core::ffi::c_void, pproductid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMBackgroundServiceAgentInfo", "ProductID", 3usize, |this| ((**(this as *const *const IPMBackgroundServiceAgentInfo_Vtbl)).ProductID)(this, pproductid), |result| Some(*result))
+        }
+        unsafe extern "system" fn TaskID(this: *mut core::ffi::c_void, ptaskid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMBackgroundServiceAgentInfo", "TaskID", 4usize, |this| ((**(this as *const *const IPMBackgroundServiceAgentInfo_Vtbl)).TaskID)(this, ptaskid), |result| Some(*result))
+        }
+        unsafe extern "system" fn BSAID(this: *mut core::ffi::c_void, pbsaid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMBackgroundServiceAgentInfo", "BSAID", 5usize, |this| ((**(this as *const *const IPMBackgroundServiceAgentInfo_Vtbl)).BSAID)(this, pbsaid), |result| Some(*result))
+        }
+        unsafe extern "system" fn BGSpecifier(this: *mut core::ffi::c_void, pbgspecifier: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMBackgroundServiceAgentInfo", "BGSpecifier", 6usize, |this| ((**(this as *const *const IPMBackgroundServiceAgentInfo_Vtbl)).BGSpecifier)(this, pbgspecifier), |result| Some(*result))
+        }
+        unsafe extern "system" fn BGName(this: *mut core::ffi::c_void, pbgname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMBackgroundServiceAgentInfo", "BGName", 7usize, |this| ((**(this as *const *const IPMBackgroundServiceAgentInfo_Vtbl)).BGName)(this, pbgname), |result| Some(*result))
+        }
+        unsafe extern "system" fn BGSource(this: *mut core::ffi::c_void, pbgsource: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMBackgroundServiceAgentInfo", "BGSource", 8usize, |this| ((**(this as *const *const IPMBackgroundServiceAgentInfo_Vtbl)).BGSource)(this, pbgsource), |result| Some(*result))
+        }
+        unsafe extern "system" fn BGType(this: *mut core::ffi::c_void, pbgtype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMBackgroundServiceAgentInfo", "BGType", 9usize, |this| ((**(this as *const *const IPMBackgroundServiceAgentInfo_Vtbl)).BGType)(this, pbgtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPeriodic(this: *mut core::ffi::c_void, pisperiodic: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMBackgroundServiceAgentInfo", "IsPeriodic", 10usize, |this| ((**(this as *const *const IPMBackgroundServiceAgentInfo_Vtbl)).IsPeriodic)(this, pisperiodic), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsScheduled(this: *mut core::ffi::c_void, pisscheduled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMBackgroundServiceAgentInfo", "IsScheduled", 11usize, |this| ((**(this as *const *const IPMBackgroundServiceAgentInfo_Vtbl)).IsScheduled)(this, pisscheduled), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsScheduleAllowed(this: *mut core::ffi::c_void, pisscheduleallowed: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMBackgroundServiceAgentInfo", "IsScheduleAllowed", 12usize, |this| ((**(this as *const *const IPMBackgroundServiceAgentInfo_Vtbl)).IsScheduleAllowed)(this, pisscheduleallowed), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, pdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMBackgroundServiceAgentInfo", "Description", 13usize, |this| ((**(this as *const *const IPMBackgroundServiceAgentInfo_Vtbl)).Description)(this, pdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLaunchOnBoot(this: *mut core::ffi::c_void, plaunchonboot: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMBackgroundServiceAgentInfo", "IsLaunchOnBoot", 14usize, |this| ((**(this as *const *const IPMBackgroundServiceAgentInfo_Vtbl)).IsLaunchOnBoot)(this, plaunchonboot), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_IsScheduled(this: *mut core::ffi::c_void, isscheduled: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMBackgroundServiceAgentInfo", "set_IsScheduled", 15usize, |this| ((**(this as *const *const IPMBackgroundServiceAgentInfo_Vtbl)).set_IsScheduled)(this, isscheduled), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_IsScheduleAllowed(this: *mut core::ffi::c_void, isscheduleallowed: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMBackgroundServiceAgentInfo", "set_IsScheduleAllowed", 16usize, |this| ((**(this as *const *const IPMBackgroundServiceAgentInfo_Vtbl)).set_IsScheduleAllowed)(this, isscheduleallowed), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProductID,
+            TaskID,
+            BSAID,
+            BGSpecifier,
+            BGName,
+            BGSource,
+            BGType,
+            IsPeriodic,
+            IsScheduled,
+            IsScheduleAllowed,
+            Description,
+            IsLaunchOnBoot,
+            set_IsScheduled,
+            set_IsScheduleAllowed,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPMBackgroundServiceAgentInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPMBackgroundServiceAgentInfoEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ppbsainfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMBackgroundServiceAgentInfoEnumerator", "Next", 3usize, |this| ((**(this as *const *const IPMBackgroundServiceAgentInfoEnumerator_Vtbl)).Next)(this, ppbsainfo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPMBackgroundServiceAgentInfoEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPMBackgroundWorkerInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProductID(this: *mut core::ffi::c_void, pproductid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMBackgroundWorkerInfo", "ProductID", 3usize, |this| ((**(this as *const *const IPMBackgroundWorkerInfo_Vtbl)).ProductID)(this, pproductid), |result| Some(*result))
+        }
+        unsafe extern "system" fn TaskID(this: *mut core::ffi::c_void, ptaskid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMBackgroundWorkerInfo", "TaskID", 4usize, |this| ((**(this as *const *const IPMBackgroundWorkerInfo_Vtbl)).TaskID)(this, ptaskid), |result| Some(*result))
+        }
+        unsafe extern "system" fn BGName(this: *mut core::ffi::c_void, pbgname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMBackgroundWorkerInfo", "BGName", 5usize, |this| ((**(this as *const *const IPMBackgroundWorkerInfo_Vtbl)).BGName)(this, pbgname), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxStartupLatency(this: *mut core::ffi::c_void, pmaxstartuplatency: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMBackgroundWorkerInfo", "MaxStartupLatency", 6usize, |this| ((**(this as *const *const IPMBackgroundWorkerInfo_Vtbl)).MaxStartupLatency)(this, pmaxstartuplatency), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExpectedRuntime(this: *mut core::ffi::c_void, pexpectedruntime: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMBackgroundWorkerInfo", "ExpectedRuntime", 7usize, |this| ((**(this as *const *const IPMBackgroundWorkerInfo_Vtbl)).ExpectedRuntime)(this, pexpectedruntime), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsBootWorker(this: *mut core::ffi::c_void, pisbootworker: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMBackgroundWorkerInfo", "IsBootWorker", 8usize, |this| ((**(this as *const *const IPMBackgroundWorkerInfo_Vtbl)).IsBootWorker)(this, pisbootworker), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProductID,
+            TaskID,
+            BGName,
+            MaxStartupLatency,
+            ExpectedRuntime,
+            IsBootWorker,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPMBackgroundWorkerInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPMBackgroundWorkerInfoEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ppbwinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMBackgroundWorkerInfoEnumerator", "Next", 3usize, |this| ((**(this as *const *const IPMBackgroundWorkerInfoEnumerator_Vtbl)).Next)(this, ppbwinfo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPMBackgroundWorkerInfoEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPMDeploymentManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReportDownloadBegin(this: *mut core::ffi::c_void, productid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "ReportDownloadBegin", 3usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).ReportDownloadBegin)(this, productid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportDownloadProgress(this: *mut core::ffi::c_void, productid: windows_core::GUID, usprogress: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "ReportDownloadProgress", 4usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).ReportDownloadProgress)(this, productid, usprogress), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportDownloadComplete(this: *mut core::ffi::c_void, productid: windows_core::GUID, hrresult: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "ReportDownloadComplete", 5usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).ReportDownloadComplete)(this, productid, hrresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginInstall(this: *mut core::ffi::c_void, pinstallinfo: *const PM_INSTALLINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "BeginInstall", 6usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).BeginInstall)(this, pinstallinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginUpdate(this: *mut core::ffi::c_void, pupdateinfo: *const PM_UPDATEINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "BeginUpdate", 7usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).BeginUpdate)(this, pupdateinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginDeployPackage(this: *mut core::ffi::c_void, pinstallinfo: *const PM_INSTALLINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "BeginDeployPackage", 8usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).BeginDeployPackage)(this, pinstallinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginUpdateDeployedPackageLegacy(this: *mut core::ffi::c_void, pupdateinfo: *const PM_UPDATEINFO_LEGACY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "BeginUpdateDeployedPackageLegacy", 9usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).BeginUpdateDeployedPackageLegacy)(this, pupdateinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginUninstall(this: *mut core::ffi::c_void, productid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "BeginUninstall", 10usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).BeginUninstall)(this, productid), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginEnterpriseAppInstall(this: *mut core::ffi::c_void, pinstallinfo: *const PM_INSTALLINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "BeginEnterpriseAppInstall", 11usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).BeginEnterpriseAppInstall)(this, pinstallinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginEnterpriseAppUpdate(this: *mut core::ffi::c_void, pupdateinfo: *const PM_UPDATEINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "BeginEnterpriseAppUpdate", 12usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).BeginEnterpriseAppUpdate)(this, pupdateinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginUpdateLicense(this: *mut core::ffi::c_void, productid: windows_core::GUID, offerid: windows_core::GUID, pblicense: *const u8, cblicense: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "BeginUpdateLicense", 13usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).BeginUpdateLicense)(this, productid, offerid, pblicense, cblicense), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLicenseChallenge(this: *mut core::ffi::c_void, packagepath: core::mem::MaybeUninit<windows_core::BSTR>, ppbchallenge: *mut *mut u8, pcbchallenge: *mut u32, ppbkid: *mut *mut u8, pcbkid: *mut u32, ppbdeviceid: *mut *mut u8, pcbdeviceid: *mut u32, ppbsaltvalue: *mut *mut u8, pcbsaltvalue: *mut u32, ppbkgvvalue: *mut *mut u8, pcbkgvvalue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "GetLicenseChallenge", 14usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).GetLicenseChallenge)(this, packagepath, ppbchallenge, pcbchallenge, ppbkid, pcbkid, ppbdeviceid, pcbdeviceid, ppbsaltvalue, pcbsaltvalue, ppbkgvvalue, pcbkgvvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLicenseChallengeByProductID(this: *mut core::ffi::c_void, productid: windows_core::GUID, ppbchallenge: *mut *mut u8, pcblicense: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "GetLicenseChallengeByProductID", 15usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).GetLicenseChallengeByProductID)(this, productid, ppbchallenge, pcblicense), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLicenseChallengeByProductID2(this: *mut core::ffi::c_void, productid: windows_core::GUID, ppbchallenge: *mut *mut u8, pcblicense: *mut u32, ppbkid: *mut *mut u8, pcbkid: *mut u32, ppbdeviceid: *mut *mut u8, pcbdeviceid: *mut u32, ppbsaltvalue: *mut *mut u8, pcbsaltvalue: *mut u32, ppbkgvvalue: *mut *mut u8, pcbkgvvalue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "GetLicenseChallengeByProductID2", 16usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).GetLicenseChallengeByProductID2)(this, productid, ppbchallenge, pcblicense, ppbkid, pcbkid, ppbdeviceid, pcbdeviceid, ppbsaltvalue, pcbsaltvalue, ppbkgvvalue, pcbkgvvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn RevokeLicense(this: *mut core::ffi::c_void, productid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "RevokeLicense", 17usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).RevokeLicense)(this, productid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RebindMdilBinaries(this: *mut core::ffi::c_void, productid: windows_core::GUID, filenames: *const super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "RebindMdilBinaries", 18usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).RebindMdilBinaries)(this, productid, filenames), |result| Some(*result))
+        }
+        unsafe extern "system" fn RebindAllMdilBinaries(this: *mut core::ffi::c_void, productid: windows_core::GUID, instanceid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "RebindAllMdilBinaries", 19usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).RebindAllMdilBinaries)(this, productid, instanceid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RegenerateXbf(this: *mut core::ffi::c_void, productid: windows_core::GUID, assemblypaths: *const super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "RegenerateXbf", 20usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).RegenerateXbf)(this, productid, assemblypaths), |result| Some(*result))
+        }
+        unsafe extern "system" fn GenerateXbfForCurrentLocale(this: *mut core::ffi::c_void, productid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "GenerateXbfForCurrentLocale", 21usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).GenerateXbfForCurrentLocale)(this, productid), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginProvision(this: *mut core::ffi::c_void, productid: windows_core::GUID, xmlpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "BeginProvision", 22usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).BeginProvision)(this, productid, xmlpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginDeprovision(this: *mut core::ffi::c_void, productid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "BeginDeprovision", 23usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).BeginDeprovision)(this, productid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReindexSQLCEDatabases(this: *mut core::ffi::c_void, productid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "ReindexSQLCEDatabases", 24usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).ReindexSQLCEDatabases)(this, productid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetApplicationsNeedMaintenance(this: *mut core::ffi::c_void, requiredmaintenanceoperations: u32, pcapplications: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "SetApplicationsNeedMaintenance", 25usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).SetApplicationsNeedMaintenance)(this, requiredmaintenanceoperations, pcapplications), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateChamberProfile(this: *mut core::ffi::c_void, productid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "UpdateChamberProfile", 26usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).UpdateChamberProfile)(this, productid), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnterprisePolicyIsApplicationAllowed(this: *mut core::ffi::c_void, productid: windows_core::GUID, publishername: windows_core::PCWSTR, pisallowed: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "EnterprisePolicyIsApplicationAllowed", 27usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).EnterprisePolicyIsApplicationAllowed)(this, productid, publishername, pisallowed), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginUpdateDeployedPackage(this: *mut core::ffi::c_void, pupdateinfo: *const PM_UPDATEINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "BeginUpdateDeployedPackage", 28usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).BeginUpdateDeployedPackage)(this, pupdateinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportRestoreCancelled(this: *mut core::ffi::c_void, productid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "ReportRestoreCancelled", 29usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).ReportRestoreCancelled)(this, productid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResolveResourceString(this: *mut core::ffi::c_void, resourcestring: windows_core::PCWSTR, presolvedresourcestring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "ResolveResourceString", 30usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).ResolveResourceString)(this, resourcestring, presolvedresourcestring), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateCapabilitiesForModernApps(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "UpdateCapabilitiesForModernApps", 31usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).UpdateCapabilitiesForModernApps)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportDownloadStatusUpdate(this: *mut core::ffi::c_void, productid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "ReportDownloadStatusUpdate", 32usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).ReportDownloadStatusUpdate)(this, productid), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginUninstallWithOptions(this: *mut core::ffi::c_void, productid: windows_core::GUID, removaloptions: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "BeginUninstallWithOptions", 33usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).BeginUninstallWithOptions)(this, productid, removaloptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn BindDeferredMdilBinaries(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "BindDeferredMdilBinaries", 34usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).BindDeferredMdilBinaries)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GenerateXamlLightupXbfForCurrentLocale(this: *mut core::ffi::c_void, packagefamilyname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "GenerateXamlLightupXbfForCurrentLocale", 35usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).GenerateXamlLightupXbfForCurrentLocale)(this, packagefamilyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddLicenseForAppx(this: *mut core::ffi::c_void, productid: windows_core::GUID, pblicense: *const u8, cblicense: u32, pbplayreadyheader: *const u8, cbplayreadyheader: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "AddLicenseForAppx", 36usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).AddLicenseForAppx)(this, productid, pblicense, cblicense, pbplayreadyheader, cbplayreadyheader), |result| Some(*result))
+        }
+        unsafe extern "system" fn FixJunctionsForAppsOnSDCard(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMDeploymentManager", "FixJunctionsForAppsOnSDCard", 37usize, |this| ((**(this as *const *const IPMDeploymentManager_Vtbl)).FixJunctionsForAppsOnSDCard)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReportDownloadBegin,
+            ReportDownloadProgress,
+            ReportDownloadComplete,
+            BeginInstall,
+            BeginUpdate,
+            BeginDeployPackage,
+            BeginUpdateDeployedPackageLegacy,
+            BeginUninstall,
+            BeginEnterpriseAppInstall,
+            BeginEnterpriseAppUpdate,
+            BeginUpdateLicense,
+            GetLicenseChallenge,
+            GetLicenseChallengeByProductID,
+            GetLicenseChallengeByProductID2,
+            RevokeLicense,
+            #[cfg(feature = "Win32_System_Com")]
+            RebindMdilBinaries,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RebindMdilBinaries: 0,
+            RebindAllMdilBinaries,
+            #[cfg(feature = "Win32_System_Com")]
+            RegenerateXbf,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RegenerateXbf: 0,
+            GenerateXbfForCurrentLocale,
+            BeginProvision,
+            BeginDeprovision,
+            ReindexSQLCEDatabases,
+            SetApplicationsNeedMaintenance,
+            UpdateChamberProfile,
+            EnterprisePolicyIsApplicationAllowed,
+            BeginUpdateDeployedPackage,
+            ReportRestoreCancelled,
+            ResolveResourceString,
+            UpdateCapabilitiesForModernApps,
+            ReportDownloadStatusUpdate,
+            BeginUninstallWithOptions,
+            BindDeferredMdilBinaries,
+            GenerateXamlLightupXbfForCurrentLocale,
+            AddLicenseForAppx,
+            FixJunctionsForAppsOnSDCard,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPMDeploymentManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPMEnumerationManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_AllApplications(this: *mut core::ffi::c_void, ppappenum: *mut *mut core::ffi::c_void, filter: PM_ENUM_FILTER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMEnumerationManager", "get_AllApplications", 3usize, |this| ((**(this as *const *const IPMEnumerationManager_Vtbl)).get_AllApplications)(this, ppappenum, filter), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_AllTiles(this: *mut core::ffi::c_void, pptileenum: *mut *mut core::ffi::c_void, filter: PM_ENUM_FILTER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMEnumerationManager", "get_AllTiles", 4usize, |this| ((**(this as *const *const IPMEnumerationManager_Vtbl)).get_AllTiles)(this, pptileenum, filter), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_AllTasks(this: *mut core::ffi::c_void, pptaskenum: *mut *mut core::ffi::c_void, filter: PM_ENUM_FILTER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMEnumerationManager", "get_AllTasks", 5usize, |this| ((**(this as *const *const IPMEnumerationManager_Vtbl)).get_AllTasks)(this, pptaskenum, filter), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_AllExtensions(this: *mut core::ffi::c_void, ppextensionenum: *mut *mut core::ffi::c_void, filter: PM_ENUM_FILTER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMEnumerationManager", "get_AllExtensions", 6usize, |this| ((**(this as *const *const IPMEnumerationManager_Vtbl)).get_AllExtensions)(this, ppextensionenum, filter), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_AllBackgroundServiceAgents(this: *mut core::ffi::c_void, ppbsaenum: *mut *mut core::ffi::c_void, filter: PM_ENUM_FILTER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMEnumerationManager", "get_AllBackgroundServiceAgents", 7usize, |this| ((**(this as *const *const IPMEnumerationManager_Vtbl)).get_AllBackgroundServiceAgents)(this, ppbsaenum, filter), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_AllBackgroundWorkers(this: *mut core::ffi::c_void, ppbswenum: *mut *mut core::ffi::c_void, filter: PM_ENUM_FILTER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMEnumerationManager", "get_AllBackgroundWorkers", 8usize, |this| ((**(this as *const *const IPMEnumerationManager_Vtbl)).get_AllBackgroundWorkers)(this, ppbswenum, filter), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_ApplicationInfo(this: *mut core::ffi::c_void, productid: windows_core::GUID, ppappinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMEnumerationManager", "get_ApplicationInfo", 9usize, |this| ((**(this as *const *const IPMEnumerationManager_Vtbl)).get_ApplicationInfo)(this, productid, ppappinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_TileInfo(this: *mut core::ffi::c_void, productid: windows_core::GUID, tileid: core::mem::MaybeUninit<windows_core::BSTR>, pptileinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMEnumerationManager", "get_TileInfo", 10usize, |this| ((**(this as *const *const IPMEnumerationManager_Vtbl)).get_TileInfo)(this, productid, tileid, pptileinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_TaskInfo(this: *mut core::ffi::c_void, productid: windows_core::GUID, taskid: core::mem::MaybeUninit<windows_core::BSTR>, pptaskinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMEnumerationManager", "get_TaskInfo", 11usize, |this| ((**(this as *const *const IPMEnumerationManager_Vtbl)).get_TaskInfo)(this, productid, taskid, pptaskinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_TaskInfoEx(this: *mut core::ffi::c_void, productid: windows_core::GUID, taskid: windows_core::PCWSTR, pptaskinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMEnumerationManager", "get_TaskInfoEx", 12usize, |this| ((**(this as *const *const IPMEnumerationManager_Vtbl)).get_TaskInfoEx)(this, productid, taskid, pptaskinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_BackgroundServiceAgentInfo(this: *mut core::ffi::c_void, bsaid: u32, pptaskinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMEnumerationManager", "get_BackgroundServiceAgentInfo", 13usize, |this| ((**(this as *const *const IPMEnumerationManager_Vtbl)).get_BackgroundServiceAgentInfo)(this, bsaid, pptaskinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllLiveTileJobs(this: *mut core::ffi::c_void, pplivetilejobenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMEnumerationManager", "AllLiveTileJobs", 14usize, |this| ((**(this as *const *const IPMEnumerationManager_Vtbl)).AllLiveTileJobs)(this, pplivetilejobenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_LiveTileJob(this: *mut core::ffi::c_void, productid: windows_core::GUID, tileid: core::mem::MaybeUninit<windows_core::BSTR>, recurrencetype: PM_LIVETILE_RECURRENCE_TYPE, pplivetilejobinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMEnumerationManager", "get_LiveTileJob", 15usize, |this| ((**(this as *const *const IPMEnumerationManager_Vtbl)).get_LiveTileJob)(this, productid, tileid, recurrencetype, pplivetilejobinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_ApplicationInfoExternal(this: *mut core::ffi::c_void, productid: windows_core::GUID, ppappinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMEnumerationManager", "get_ApplicationInfoExternal", 16usize, |this| ((**(this as *const *const IPMEnumerationManager_Vtbl)).get_ApplicationInfoExternal)(this, productid, ppappinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_FileHandlerGenericLogo(this: *mut core::ffi::c_void, filetype: core::mem::MaybeUninit<windows_core::BSTR>, logosize: PM_LOGO_SIZE, plogo: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMEnumerationManager", "get_FileHandlerGenericLogo", 17usize, |this| ((**(this as *const *const IPMEnumerationManager_Vtbl)).get_FileHandlerGenericLogo)(this, filetype, logosize, plogo), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_ApplicationInfoFromAccessClaims(this: *mut core::ffi::c_void, sysappid0: core::mem::MaybeUninit<windows_core::BSTR>, sysappid1: core::mem::MaybeUninit<windows_core::BSTR>, ppappinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMEnumerationManager", "get_ApplicationInfoFromAccessClaims", 18usize, |this| ((**(this as *const *const IPMEnumerationManager_Vtbl)).get_ApplicationInfoFromAccessClaims)(this, sysappid0, sysappid1, ppappinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_StartTileEnumeratorBlob(this: *mut core::ffi::c_void, filter: PM_ENUM_FILTER, pctiles: *mut u32, pptileblobs: *mut *mut PM_STARTTILEBLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMEnumerationManager", "get_StartTileEnumeratorBlob", 19usize, |this| ((**(this as *const *const IPMEnumerationManager_Vtbl)).get_StartTileEnumeratorBlob)(this, filter, pctiles, pptileblobs), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_StartAppEnumeratorBlob(this: *mut core::ffi::c_void, filter: PM_ENUM_FILTER, pcapps: *mut u32, ppappblobs: *mut *mut PM_STARTAPPBLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMEnumerationManager", "get_StartAppEnumeratorBlob", 20usize, |this| ((**(this as *const *const IPMEnumerationManager_Vtbl)).get_StartAppEnumeratorBlob)(this, filter, pcapps, ppappblobs), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            get_AllApplications,
+            get_AllTiles,
+            get_AllTasks,
+            get_AllExtensions,
+            get_AllBackgroundServiceAgents,
+            get_AllBackgroundWorkers,
+            get_ApplicationInfo,
+            get_TileInfo,
+            get_TaskInfo,
+            get_TaskInfoEx,
+            get_BackgroundServiceAgentInfo,
+            AllLiveTileJobs,
+            get_LiveTileJob,
+            get_ApplicationInfoExternal,
+            get_FileHandlerGenericLogo,
+            get_ApplicationInfoFromAccessClaims,
+            get_StartTileEnumeratorBlob,
+            get_StartAppEnumeratorBlob,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPMEnumerationManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPMExtensionCachedFileUpdaterInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SupportsUpdates(this: *mut core::ffi::c_void, psupportsupdates: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMExtensionCachedFileUpdaterInfo", "SupportsUpdates", 3usize, |this| ((**(this as *const *const IPMExtensionCachedFileUpdaterInfo_Vtbl)).SupportsUpdates)(this, psupportsupdates), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SupportsUpdates }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPMExtensionCachedFileUpdaterInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPMExtensionContractInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_InvocationInfo(this: *mut core::ffi::c_void, paumid: *mut core::mem::MaybeUninit<windows_core::BSTR>, pargs: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMExtensionContractInfo", "get_InvocationInfo", 3usize, |this| ((**(this as *const *const IPMExtensionContractInfo_Vtbl)).get_InvocationInfo)(this, paumid, pargs), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, get_InvocationInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPMExtensionContractInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPMExtensionFileExtensionInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMExtensionFileExtensionInfo", "Name", 3usize, |this| ((**(this as *const *const IPMExtensionFileExtensionInfo_Vtbl)).Name)(this, pname), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, pdisplayname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMExtensionFileExtensionInfo", "DisplayName", 4usize, |this| ((**(this as *const *const IPMExtensionFileExtensionInfo_Vtbl)).DisplayName)(this, pdisplayname), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Logo(this: *mut core::ffi::c_void, logosize: PM_LOGO_SIZE, plogo: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMExtensionFileExtensionInfo", "get_Logo", 5usize, |this| ((**(this as *const *const IPMExtensionFileExtensionInfo_Vtbl)).get_Logo)(this, logosize, plogo), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_ContentType(this: *mut core::ffi::c_void, filetype: core::mem::MaybeUninit<windows_core::BSTR>, pcontenttype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMExtensionFileExtensionInfo", "get_ContentType", 6usize, |this| ((**(this as *const *const IPMExtensionFileExtensionInfo_Vtbl)).get_ContentType)(this, filetype, pcontenttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_FileType(this: *mut core::ffi::c_void, contenttype: core::mem::MaybeUninit<windows_core::BSTR>, pfiletype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMExtensionFileExtensionInfo", "get_FileType", 7usize, |this| ((**(this as *const *const IPMExtensionFileExtensionInfo_Vtbl)).get_FileType)(this, contenttype, pfiletype), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_InvocationInfo(this: *mut core::ffi::c_void, pimageurn: *mut core::mem::MaybeUninit<windows_core::BSTR>, pparameters: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMExtensionFileExtensionInfo", "get_InvocationInfo", 8usize, |this| ((**(this as *const *const IPMExtensionFileExtensionInfo_Vtbl)).get_InvocationInfo)(this, pimageurn, pparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_AllFileTypes(this: *mut core::ffi::c_void, pcbtypes: *mut u32, pptypes: *mut *mut windows_core::BSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMExtensionFileExtensionInfo", "get_AllFileTypes", 9usize, |this| ((**(this as *const *const IPMExtensionFileExtensionInfo_Vtbl)).get_AllFileTypes)(this, pcbtypes, pptypes), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            DisplayName,
+            get_Logo,
+            get_ContentType,
+            get_FileType,
+            get_InvocationInfo,
+            get_AllFileTypes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPMExtensionFileExtensionInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPMExtensionFileOpenPickerInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_AllFileTypes(this: *mut core::ffi::c_void, pctypes: *mut u32, pptypes: *mut *mut windows_core::BSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMExtensionFileOpenPickerInfo", "get_AllFileTypes", 3usize, |this| ((**(this as *const *const IPMExtensionFileOpenPickerInfo_Vtbl)).get_AllFileTypes)(this, pctypes, pptypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportsAllFileTypes(this: *mut core::ffi::c_void, psupportsalltypes: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMExtensionFileOpenPickerInfo", "SupportsAllFileTypes", 4usize, |this| ((**(this as *const *const IPMExtensionFileOpenPickerInfo_Vtbl)).SupportsAllFileTypes)(this, psupportsalltypes), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, get_AllFileTypes, SupportsAllFileTypes }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPMExtensionFileOpenPickerInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPMExtensionFileSavePickerInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_AllFileTypes(this: *mut core::ffi::c_void, pctypes: *mut u32, pptypes: *mut *mut windows_core::BSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMExtensionFileSavePickerInfo", "get_AllFileTypes", 3usize, |this| ((**(this as *const *const IPMExtensionFileSavePickerInfo_Vtbl)).get_AllFileTypes)(this, pctypes, pptypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportsAllFileTypes(this: *mut core::ffi::c_void, psupportsalltypes: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMExtensionFileSavePickerInfo", "SupportsAllFileTypes", 4usize, |this| ((**(this as *const *const IPMExtensionFileSavePickerInfo_Vtbl)).SupportsAllFileTypes)(this, psupportsalltypes), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, get_AllFileTypes, SupportsAllFileTypes }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPMExtensionFileSavePickerInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPMExtensionInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SupplierPID(this: *mut core::ffi::c_void, psupplierpid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMExtensionInfo", "SupplierPID", 3usize, |this| ((**(this as *const *const IPMExtensionInfo_Vtbl)).SupplierPID)(this, psupplierpid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupplierTaskID(this: *mut core::ffi::c_void, psuppliertid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMExtensionInfo", "SupplierTaskID", 4usize, |this| ((**(this as *const *const IPMExtensionInfo_Vtbl)).SupplierTaskID)(this, psuppliertid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, ptitle: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMExtensionInfo", "Title", 5usize, |this| ((**(this as *const *const IPMExtensionInfo_Vtbl)).Title)(this, ptitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn IconPath(this: *mut core::ffi::c_void, piconpath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMExtensionInfo", "IconPath", 6usize, |this| ((**(this as *const *const IPMExtensionInfo_Vtbl)).IconPath)(this, piconpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtraFile(this: *mut core::ffi::c_void, pfilepath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMExtensionInfo", "ExtraFile", 7usize, |this| ((**(this as *const *const IPMExtensionInfo_Vtbl)).ExtraFile)(this, pfilepath), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_InvocationInfo(this: *mut core::ffi::c_void, pimageurn: *mut core::mem::MaybeUninit<windows_core::BSTR>, pparameters: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMExtensionInfo", "get_InvocationInfo", 8usize, |this| ((**(this as *const *const IPMExtensionInfo_Vtbl)).get_InvocationInfo)(this, pimageurn, pparameters), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SupplierPID,
+            SupplierTaskID,
+            Title,
+            IconPath,
+            ExtraFile,
+            get_InvocationInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPMExtensionInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPMExtensionInfoEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ppextensioninfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMExtensionInfoEnumerator", "Next", 3usize, |this| ((**(this as *const *const IPMExtensionInfoEnumerator_Vtbl)).Next)(this, ppextensioninfo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPMExtensionInfoEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPMExtensionProtocolInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Protocol(this: *mut core::ffi::c_void, pprotocol: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMExtensionProtocolInfo", "Protocol", 3usize, |this| ((**(this as *const *const IPMExtensionProtocolInfo_Vtbl)).Protocol)(this, pprotocol), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_InvocationInfo(this: *mut core::ffi::c_void, pimageurn: *mut core::mem::MaybeUninit<windows_core::BSTR>, pparameters: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMExtensionProtocolInfo", "get_InvocationInfo", 4usize, |this| ((**(this as *const *const IPMExtensionProtocolInfo_Vtbl)).get_InvocationInfo)(this, pimageurn, pparameters), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Protocol, get_InvocationInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPMExtensionProtocolInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPMExtensionShareTargetInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_AllFileTypes(this: *mut core::ffi::c_void, pctypes: *mut u32, pptypes: *mut *mut windows_core::BSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMExtensionShareTargetInfo", "get_AllFileTypes", 3usize, |this| ((**(this as *const *const IPMExtensionShareTargetInfo_Vtbl)).get_AllFileTypes)(this, pctypes, pptypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_AllDataFormats(this: *mut core::ffi::c_void, pcdataformats: *mut u32, ppdataformats: *mut *mut windows_core::BSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMExtensionShareTargetInfo", "get_AllDataFormats", 4usize, |this| ((**(this as *const *const IPMExtensionShareTargetInfo_Vtbl)).get_AllDataFormats)(this, pcdataformats, ppdataformats), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportsAllFileTypes(this: *mut core::ffi::c_void, psupportsalltypes: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMExtensionShareTargetInfo", "SupportsAllFileTypes", 5usize, |this| ((**(this as *const *const IPMExtensionShareTargetInfo_Vtbl)).SupportsAllFileTypes)(this, psupportsalltypes), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, get_AllFileTypes, get_AllDataFormats, SupportsAllFileTypes }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPMExtensionShareTargetInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPMLiveTileJobInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProductID(this: *mut core::ffi::c_void, pproductid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMLiveTileJobInfo", "ProductID", 3usize, |this| ((**(this as *const *const IPMLiveTileJobInfo_Vtbl)).ProductID)(this, pproductid), |result| Some(*result))
+        }
+        unsafe extern "system" fn TileID(this: *mut core::ffi::c_void, ptileid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMLiveTileJobInfo", "TileID", 4usize, |this| ((**(this as *const *const IPMLiveTileJobInfo_Vtbl)).TileID)(this, ptileid), |result| Some(*result))
+        }
+        unsafe extern "system" fn NextSchedule(this: *mut core::ffi::c_void, pnextschedule: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMLiveTileJobInfo", "NextSchedule", 5usize, |this| ((**(this as *const *const IPMLiveTileJobInfo_Vtbl)).NextSchedule)(this, pnextschedule), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_NextSchedule(this: *mut core::ffi::c_void, ftnextschedule: super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMLiveTileJobInfo", "set_NextSchedule", 6usize, |this| ((**(this as *const *const IPMLiveTileJobInfo_Vtbl)).set_NextSchedule)(this, ftnextschedule), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartSchedule(this: *mut core::ffi::c_void, pstartschedule: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMLiveTileJobInfo", "StartSchedule", 7usize, |this| ((**(this as *const *const IPMLiveTileJobInfo_Vtbl)).StartSchedule)(this, pstartschedule), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_StartSchedule(this: *mut core::ffi::c_void, ftstartschedule: super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMLiveTileJobInfo", "set_StartSchedule", 8usize, |this| ((**(this as *const *const IPMLiveTileJobInfo_Vtbl)).set_StartSchedule)(this, ftstartschedule), |result| Some(*result))
+        }
+        unsafe extern "system" fn IntervalDuration(this: *mut core::ffi::c_void, pintervalduration: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMLiveTileJobInfo", "IntervalDuration", 9usize, |this| ((**(this as *const *const IPMLiveTileJobInfo_Vtbl)).IntervalDuration)(this, pintervalduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_IntervalDuration(this: *mut core::ffi::c_void, ulintervalduration: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMLiveTileJobInfo", "set_IntervalDuration", 10usize, |this| ((**(this as *const *const IPMLiveTileJobInfo_Vtbl)).set_IntervalDuration)(this, ulintervalduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunForever(this: *mut core::ffi::c_void, isrunforever: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMLiveTileJobInfo", "RunForever", 11usize, |this| ((**(this as *const *const IPMLiveTileJobInfo_Vtbl)).RunForever)(this, isrunforever), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_RunForever(this: *mut core::ffi::c_void, frunforever: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMLiveTileJobInfo", "set_RunForever", 12usize, |this| ((**(this as *const *const IPMLiveTileJobInfo_Vtbl)).set_RunForever)(this, frunforever), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxRunCount(this: *mut core::ffi::c_void, pmaxruncount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMLiveTileJobInfo", "MaxRunCount", 13usize, |this| ((**(this as *const *const IPMLiveTileJobInfo_Vtbl)).MaxRunCount)(this, pmaxruncount), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_MaxRunCount(this: *mut core::ffi::c_void, ulmaxruncount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMLiveTileJobInfo", "set_MaxRunCount", 14usize, |this| ((**(this as *const *const IPMLiveTileJobInfo_Vtbl)).set_MaxRunCount)(this, ulmaxruncount), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunCount(this: *mut core::ffi::c_void, pruncount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMLiveTileJobInfo", "RunCount", 15usize, |this| ((**(this as *const *const IPMLiveTileJobInfo_Vtbl)).RunCount)(this, pruncount), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_RunCount(this: *mut core::ffi::c_void, ulruncount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMLiveTileJobInfo", "set_RunCount", 16usize, |this| ((**(this as *const *const IPMLiveTileJobInfo_Vtbl)).set_RunCount)(this, ulruncount), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecurrenceType(this: *mut core::ffi::c_void, precurrencetype: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMLiveTileJobInfo", "RecurrenceType", 17usize, |this| ((**(this as *const *const IPMLiveTileJobInfo_Vtbl)).RecurrenceType)(this, precurrencetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_RecurrenceType(this: *mut core::ffi::c_void, ulrecurrencetype: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMLiveTileJobInfo", "set_RecurrenceType", 18usize, |this| ((**(this as *const *const IPMLiveTileJobInfo_Vtbl)).set_RecurrenceType)(this, ulrecurrencetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_TileXML(this: *mut core::ffi::c_void, ptilexml: *mut *mut u8, pcbtilexml: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMLiveTileJobInfo", "get_TileXML", 19usize, |this| ((**(this as *const *const IPMLiveTileJobInfo_Vtbl)).get_TileXML)(this, ptilexml, pcbtilexml), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_TileXML(this: *mut core::ffi::c_void, ptilexml: *const u8, cbtilexml: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMLiveTileJobInfo", "set_TileXML", 20usize, |this| ((**(this as *const *const IPMLiveTileJobInfo_Vtbl)).set_TileXML)(this, ptilexml, cbtilexml), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_UrlXML(this: *mut core::ffi::c_void, purlxml: *mut *mut u8, pcburlxml: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMLiveTileJobInfo", "get_UrlXML", 21usize, |this| ((**(this as *const *const IPMLiveTileJobInfo_Vtbl)).get_UrlXML)(this, purlxml, pcburlxml), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_UrlXML(this: *mut core::ffi::c_void, purlxml: *const u8, cburlxml: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMLiveTileJobInfo", "set_UrlXML", 22usize, |this| ((**(this as *const *const IPMLiveTileJobInfo_Vtbl)).set_UrlXML)(this, purlxml, cburlxml), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttemptCount(this: *mut core::ffi::c_void, pattemptcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMLiveTileJobInfo", "AttemptCount", 23usize, |this| ((**(this as *const *const IPMLiveTileJobInfo_Vtbl)).AttemptCount)(this, pattemptcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_AttemptCount(this: *mut core::ffi::c_void, ulattemptcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMLiveTileJobInfo", "set_AttemptCount", 24usize, |this| ((**(this as *const *const IPMLiveTileJobInfo_Vtbl)).set_AttemptCount)(this, ulattemptcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadState(this: *mut core::ffi::c_void, pdownloadstate: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMLiveTileJobInfo", "DownloadState", 25usize, |this| ((**(this as *const *const IPMLiveTileJobInfo_Vtbl)).DownloadState)(this, pdownloadstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_DownloadState(this: *mut core::ffi::c_void, uldownloadstate: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMLiveTileJobInfo", "set_DownloadState", 26usize, |this| ((**(this as *const *const IPMLiveTileJobInfo_Vtbl)).set_DownloadState)(this, uldownloadstate), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProductID,
+            TileID,
+            NextSchedule,
+            set_NextSchedule,
+            StartSchedule,
+            set_StartSchedule,
+            IntervalDuration,
+            set_IntervalDuration,
+            RunForever,
+            set_RunForever,
+            MaxRunCount,
+            set_MaxRunCount,
+            RunCount,
+            set_RunCount,
+            RecurrenceType,
+            set_RecurrenceType,
+            get_TileXML,
+            set_TileXML,
+            get_UrlXML,
+            set_UrlXML,
+            AttemptCount,
+            set_AttemptCount,
+            DownloadState,
+            set_DownloadState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPMLiveTileJobInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPMLiveTileJobInfoEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, pplivetilejobinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMLiveTileJobInfoEnumerator", "Next", 3usize, |this| ((**(this as *const *const IPMLiveTileJobInfoEnumerator_Vtbl)).Next)(this, pplivetilejobinfo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPMLiveTileJobInfoEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPMTaskInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProductID(this: *mut core::ffi::c_void, pproductid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTaskInfo", "ProductID", 3usize, |this| ((**(this as *const *const IPMTaskInfo_Vtbl)).ProductID)(this, pproductid), |result| Some(*result))
+        }
+        unsafe extern "system" fn TaskID(this: *mut core::ffi::c_void, ptaskid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTaskInfo", "TaskID", 4usize, |this| ((**(this as *const *const IPMTaskInfo_Vtbl)).TaskID)(this, ptaskid), |result| Some(*result))
+        }
+        unsafe extern "system" fn NavigationPage(this: *mut core::ffi::c_void, pnavigationpage: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTaskInfo", "NavigationPage", 5usize, |this| ((**(this as *const *const IPMTaskInfo_Vtbl)).NavigationPage)(this, pnavigationpage), |result| Some(*result))
+        }
+        unsafe extern "system" fn TaskTransition(this: *mut core::ffi::c_void, ptasktransition: *mut PM_TASK_TRANSITION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTaskInfo", "TaskTransition", 6usize, |this| ((**(this as *const *const IPMTaskInfo_Vtbl)).TaskTransition)(this, ptasktransition), |result| Some(*result))
+        }
+        unsafe extern "system" fn RuntimeType(this: *mut core::ffi::c_void, pruntimetype: *mut PACKMAN_RUNTIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTaskInfo", "RuntimeType", 7usize, |this| ((**(this as *const *const IPMTaskInfo_Vtbl)).RuntimeType)(this, pruntimetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivationPolicy(this: *mut core::ffi::c_void, pactivationpolicy: *mut PM_ACTIVATION_POLICY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTaskInfo", "ActivationPolicy", 8usize, |this| ((**(this as *const *const IPMTaskInfo_Vtbl)).ActivationPolicy)(this, pactivationpolicy), |result| Some(*result))
+        }
+        unsafe extern "system" fn TaskType(this: *mut core::ffi::c_void, ptasktype: *mut PM_TASK_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTaskInfo", "TaskType", 9usize, |this| ((**(this as *const *const IPMTaskInfo_Vtbl)).TaskType)(this, ptasktype), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_InvocationInfo(this: *mut core::ffi::c_void, pimageurn: *mut core::mem::MaybeUninit<windows_core::BSTR>, pparameters: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTaskInfo", "get_InvocationInfo", 10usize, |this| ((**(this as *const *const IPMTaskInfo_Vtbl)).get_InvocationInfo)(this, pimageurn, pparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImagePath(this: *mut core::ffi::c_void, pimagepath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTaskInfo", "ImagePath", 11usize, |this| ((**(this as *const *const IPMTaskInfo_Vtbl)).ImagePath)(this, pimagepath), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImageParams(this: *mut core::ffi::c_void, pimageparams: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTaskInfo", "ImageParams", 12usize, |this| ((**(this as *const *const IPMTaskInfo_Vtbl)).ImageParams)(this, pimageparams), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstallRootFolder(this: *mut core::ffi::c_void, pinstallrootfolder: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTaskInfo", "InstallRootFolder", 13usize, |this| ((**(this as *const *const IPMTaskInfo_Vtbl)).InstallRootFolder)(this, pinstallrootfolder), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataRootFolder(this: *mut core::ffi::c_void, pdatarootfolder: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTaskInfo", "DataRootFolder", 14usize, |this| ((**(this as *const *const IPMTaskInfo_Vtbl)).DataRootFolder)(this, pdatarootfolder), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSingleInstanceHost(this: *mut core::ffi::c_void, pissingleinstancehost: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTaskInfo", "IsSingleInstanceHost", 15usize, |this| ((**(this as *const *const IPMTaskInfo_Vtbl)).IsSingleInstanceHost)(this, pissingleinstancehost), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInteropEnabled(this: *mut core::ffi::c_void, pisinteropenabled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTaskInfo", "IsInteropEnabled", 16usize, |this| ((**(this as *const *const IPMTaskInfo_Vtbl)).IsInteropEnabled)(this, pisinteropenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplicationState(this: *mut core::ffi::c_void, papplicationstate: *mut PM_APPLICATION_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTaskInfo", "ApplicationState", 17usize, |this| ((**(this as *const *const IPMTaskInfo_Vtbl)).ApplicationState)(this, papplicationstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstallType(this: *mut core::ffi::c_void, pinstalltype: *mut PM_APPLICATION_INSTALL_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTaskInfo", "InstallType", 18usize, |this| ((**(this as *const *const IPMTaskInfo_Vtbl)).InstallType)(this, pinstalltype), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Version(this: *mut core::ffi::c_void, ptargetmajorversion: *mut u8, ptargetminorversion: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTaskInfo", "get_Version", 19usize, |this| ((**(this as *const *const IPMTaskInfo_Vtbl)).get_Version)(this, ptargetmajorversion, ptargetminorversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn BitsPerPixel(this: *mut core::ffi::c_void, pbitsperpixel: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTaskInfo", "BitsPerPixel", 20usize, |this| ((**(this as *const *const IPMTaskInfo_Vtbl)).BitsPerPixel)(this, pbitsperpixel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuppressesDehydration(this: *mut core::ffi::c_void, psuppressesdehydration: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTaskInfo", "SuppressesDehydration", 21usize, |this| ((**(this as *const *const IPMTaskInfo_Vtbl)).SuppressesDehydration)(this, psuppressesdehydration), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackgroundExecutionAbilities(this: *mut core::ffi::c_void, pbackgroundexecutionabilities: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTaskInfo", "BackgroundExecutionAbilities", 22usize, |this| ((**(this as *const *const IPMTaskInfo_Vtbl)).BackgroundExecutionAbilities)(this, pbackgroundexecutionabilities), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOptedForExtendedMem(this: *mut core::ffi::c_void, pisoptedin: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTaskInfo", "IsOptedForExtendedMem", 23usize, |this| ((**(this as *const *const IPMTaskInfo_Vtbl)).IsOptedForExtendedMem)(this, pisoptedin), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProductID,
+            TaskID,
+            NavigationPage,
+            TaskTransition,
+            RuntimeType,
+            ActivationPolicy,
+            TaskType,
+            get_InvocationInfo,
+            ImagePath,
+            ImageParams,
+            InstallRootFolder,
+            DataRootFolder,
+            IsSingleInstanceHost,
+            IsInteropEnabled,
+            ApplicationState,
+            InstallType,
+            get_Version,
+            BitsPerPixel,
+            SuppressesDehydration,
+            BackgroundExecutionAbilities,
+            IsOptedForExtendedMem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPMTaskInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPMTaskInfoEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, pptaskinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTaskInfoEnumerator", "Next", 3usize, |this| ((**(this as *const *const IPMTaskInfoEnumerator_Vtbl)).Next)(this, pptaskinfo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPMTaskInfoEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPMTileInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProductID(this: *mut core::ffi::c_void, pproductid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTileInfo", "ProductID", 3usize, |this| ((**(this as *const *const IPMTileInfo_Vtbl)).ProductID)(this, pproductid), |result| Some(*result))
+        }
+        unsafe extern "system" fn TileID(this: *mut core::ffi::c_void, ptileid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTileInfo", "TileID", 4usize, |this| ((**(this as *const *const IPMTileInfo_Vtbl)).TileID)(this, ptileid), |result| Some(*result))
+        }
+        unsafe extern "system" fn TemplateType(this: *mut core::ffi::c_void, ptemplatetype: *mut TILE_TEMPLATE_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTileInfo", "TemplateType", 5usize, |this| ((**(this as *const *const IPMTileInfo_Vtbl)).TemplateType)(this, ptemplatetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_HubPinnedState(this: *mut core::ffi::c_void, hubtype: PM_TILE_HUBTYPE, ppinned: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTileInfo", "get_HubPinnedState", 6usize, |this| ((**(this as *const *const IPMTileInfo_Vtbl)).get_HubPinnedState)(this, hubtype, ppinned), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_HubPosition(this: *mut core::ffi::c_void, hubtype: PM_TILE_HUBTYPE, pposition: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTileInfo", "get_HubPosition", 7usize, |this| ((**(this as *const *const IPMTileInfo_Vtbl)).get_HubPosition)(this, hubtype, pposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsNotified(this: *mut core::ffi::c_void, pisnotified: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTileInfo", "IsNotified", 8usize, |this| ((**(this as *const *const IPMTileInfo_Vtbl)).IsNotified)(this, pisnotified), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDefault(this: *mut core::ffi::c_void, pisdefault: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTileInfo", "IsDefault", 9usize, |this| ((**(this as *const *const IPMTileInfo_Vtbl)).IsDefault)(this, pisdefault), |result| Some(*result))
+        }
+        unsafe extern "system" fn TaskID(this: *mut core::ffi::c_void, ptaskid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTileInfo", "TaskID", 10usize, |this| ((**(this as *const *const IPMTileInfo_Vtbl)).TaskID)(this, ptaskid), |result| Some(*result))
+        }
+        unsafe extern "system" fn TileType(this: *mut core::ffi::c_void, pstarttiletype: *mut PM_STARTTILE_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTileInfo", "TileType", 11usize, |this| ((**(this as *const *const IPMTileInfo_Vtbl)).TileType)(this, pstarttiletype), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsThemable(this: *mut core::ffi::c_void, pisthemable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTileInfo", "IsThemable", 12usize, |this| ((**(this as *const *const IPMTileInfo_Vtbl)).IsThemable)(this, pisthemable), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_PropertyById(this: *mut core::ffi::c_void, propid: u32, pppropinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTileInfo", "get_PropertyById", 13usize, |this| ((**(this as *const *const IPMTileInfo_Vtbl)).get_PropertyById)(this, propid, pppropinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_InvocationInfo(this: *mut core::ffi::c_void, pimageurn: *mut core::mem::MaybeUninit<windows_core::BSTR>, pparameters: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTileInfo", "get_InvocationInfo", 14usize, |this| ((**(this as *const *const IPMTileInfo_Vtbl)).get_InvocationInfo)(this, pimageurn, pparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn PropertyEnum(this: *mut core::ffi::c_void, pptilepropenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTileInfo", "PropertyEnum", 15usize, |this| ((**(this as *const *const IPMTileInfo_Vtbl)).PropertyEnum)(this, pptilepropenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_HubTileSize(this: *mut core::ffi::c_void, hubtype: PM_TILE_HUBTYPE, psize: *mut PM_TILE_SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTileInfo", "get_HubTileSize", 16usize, |this| ((**(this as *const *const IPMTileInfo_Vtbl)).get_HubTileSize)(this, hubtype, psize), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_HubPosition(this: *mut core::ffi::c_void, hubtype: PM_TILE_HUBTYPE, position: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTileInfo", "set_HubPosition", 17usize, |this| ((**(this as *const *const IPMTileInfo_Vtbl)).set_HubPosition)(this, hubtype, position), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_NotifiedState(this: *mut core::ffi::c_void, notified: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTileInfo", "set_NotifiedState", 18usize, |this| ((**(this as *const *const IPMTileInfo_Vtbl)).set_NotifiedState)(this, notified), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_HubPinnedState(this: *mut core::ffi::c_void, hubtype: PM_TILE_HUBTYPE, pinned: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTileInfo", "set_HubPinnedState", 19usize, |this| ((**(this as *const *const IPMTileInfo_Vtbl)).set_HubPinnedState)(this, hubtype, pinned), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_HubTileSize(this: *mut core::ffi::c_void, hubtype: PM_TILE_HUBTYPE, size: PM_TILE_SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTileInfo", "set_HubTileSize", 20usize, |this| ((**(this as *const *const IPMTileInfo_Vtbl)).set_HubTileSize)(this, hubtype, size), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_InvocationInfo(this: *mut core::ffi::c_void, taskname: core::mem::MaybeUninit<windows_core::BSTR>, taskparameters: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTileInfo", "set_InvocationInfo", 21usize, |this| ((**(this as *const *const IPMTileInfo_Vtbl)).set_InvocationInfo)(this, taskname, taskparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartTileBlob(this: *mut core::ffi::c_void, pblob: *mut PM_STARTTILEBLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTileInfo", "StartTileBlob", 22usize, |this| ((**(this as *const *const IPMTileInfo_Vtbl)).StartTileBlob)(this, pblob), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRestoring(this: *mut core::ffi::c_void, pisrestoring: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTileInfo", "IsRestoring", 23usize, |this| ((**(this as *const *const IPMTileInfo_Vtbl)).IsRestoring)(this, pisrestoring), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAutoRestoreDisabled(this: *mut core::ffi::c_void, pisautorestoredisabled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTileInfo", "IsAutoRestoreDisabled", 24usize, |this| ((**(this as *const *const IPMTileInfo_Vtbl)).IsAutoRestoreDisabled)(this, pisautorestoredisabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_IsRestoring(this: *mut core::ffi::c_void, restoring: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTileInfo", "set_IsRestoring", 25usize, |this| ((**(this as *const *const IPMTileInfo_Vtbl)).set_IsRestoring)(this, restoring), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_IsAutoRestoreDisabled(this: *mut core::ffi::c_void, autorestoredisabled: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTileInfo", "set_IsAutoRestoreDisabled", 26usize, |this| ((**(this as *const *const IPMTileInfo_Vtbl)).set_IsAutoRestoreDisabled)(this, autorestoredisabled), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProductID,
+            TileID,
+            TemplateType,
+            get_HubPinnedState,
+            get_HubPosition,
+            IsNotified,
+            IsDefault,
+            TaskID,
+            TileType,
+            IsThemable,
+            get_PropertyById,
+            get_InvocationInfo,
+            PropertyEnum,
+            get_HubTileSize,
+            set_HubPosition,
+            set_NotifiedState,
+            set_HubPinnedState,
+            set_HubTileSize,
+            set_InvocationInfo,
+            StartTileBlob,
+            IsRestoring,
+            IsAutoRestoreDisabled,
+            set_IsRestoring,
+            set_IsAutoRestoreDisabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPMTileInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPMTileInfoEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, pptileinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTileInfoEnumerator", "Next", 3usize, |this| ((**(this as *const *const IPMTileInfoEnumerator_Vtbl)).Next)(this, pptileinfo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPMTileInfoEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPMTilePropertyEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, pppropinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTilePropertyEnumerator", "Next", 3usize, |this| ((**(this as *const *const IPMTilePropertyEnumerator_Vtbl)).Next)(this, pppropinfo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPMTilePropertyEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPMTilePropertyInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PropertyID(this: *mut core::ffi::c_void, ppropid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTilePropertyInfo", "PropertyID", 3usize, |this| ((**(this as *const *const IPMTilePropertyInfo_Vtbl)).PropertyID)(this, ppropid), |result| Some(*result))
+        }
+        unsafe extern "system" fn PropertyValue(this: *mut core::ffi::c_void, ppropvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTilePropertyInfo", "PropertyValue", 4usize, |this| ((**(this as *const *const IPMTilePropertyInfo_Vtbl)).PropertyValue)(this, ppropvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_Property(this: *mut core::ffi::c_void, propvalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPMTilePropertyInfo", "set_Property", 5usize, |this| ((**(this as *const *const IPMTilePropertyInfo_Vtbl)).set_Property)(this, propvalue), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PropertyID, PropertyValue, set_Property }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPMTilePropertyInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IValidate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenDatabase(this: *mut core::ffi::c_void, szdatabase: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValidate", "OpenDatabase", 3usize, |this| ((**(this as *const *const IValidate_Vtbl)).OpenDatabase)(this, szdatabase), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenCUB(this: *mut core::ffi::c_void, szcubfile: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValidate", "OpenCUB", 4usize, |this| ((**(this as *const *const IValidate_Vtbl)).OpenCUB)(this, szcubfile), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseDatabase(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValidate", "CloseDatabase", 5usize, |this| ((**(this as *const *const IValidate_Vtbl)).CloseDatabase)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseCUB(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValidate", "CloseCUB", 6usize, |this| ((**(this as *const *const IValidate_Vtbl)).CloseCUB)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplay(this: *mut core::ffi::c_void, pdisplayfunction: LPDISPLAYVAL, pcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValidate", "SetDisplay", 7usize, |this| ((**(this as *const *const IValidate_Vtbl)).SetDisplay)(this, pdisplayfunction, pcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatus(this: *mut core::ffi::c_void, pstatusfunction: LPEVALCOMCALLBACK, pcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValidate", "SetStatus", 8usize, |this| ((**(this as *const *const IValidate_Vtbl)).SetStatus)(this, pstatusfunction, pcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn Validate(this: *mut core::ffi::c_void, wzices: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValidate", "Validate", 9usize, |this| ((**(this as *const *const IValidate_Vtbl)).Validate)(this, wzices), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OpenDatabase,
+            OpenCUB,
+            CloseDatabase,
+            CloseCUB,
+            SetDisplay,
+            SetStatus,
+            Validate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IValidate as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -30,6 +30,7 @@ pub struct VERIFIER_ENUM_RESOURCE_FLAGS(pub u32);
 impl windows_core::TypeKind for VERIFIER_ENUM_RESOURCE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VERIFIER_ENUM_RESOURCE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VERIFIER_ENUM_RESOURCE_FLAGS").field(&self.0).finish()
@@ -74,6 +75,7 @@ pub struct eAvrfResourceTypes(pub i32);
 impl windows_core::TypeKind for eAvrfResourceTypes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eAvrfResourceTypes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eAvrfResourceTypes").field(&self.0).finish()
@@ -85,6 +87,7 @@ pub struct eHANDLE_TRACE_OPERATIONS(pub i32);
 impl windows_core::TypeKind for eHANDLE_TRACE_OPERATIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eHANDLE_TRACE_OPERATIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eHANDLE_TRACE_OPERATIONS").field(&self.0).finish()
@@ -96,6 +99,7 @@ pub struct eHeapAllocationState(pub i32);
 impl windows_core::TypeKind for eHeapAllocationState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eHeapAllocationState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eHeapAllocationState").field(&self.0).finish()
@@ -107,6 +111,7 @@ pub struct eHeapEnumerationLevel(pub i32);
 impl windows_core::TypeKind for eHeapEnumerationLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eHeapEnumerationLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eHeapEnumerationLevel").field(&self.0).finish()
@@ -118,13 +123,15 @@ pub struct eUserAllocationState(pub i32);
 impl windows_core::TypeKind for eUserAllocationState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for eUserAllocationState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("eUserAllocationState").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AVRF_BACKTRACE_INFORMATION {
     pub Depth: u32,
     pub Index: u32,
@@ -139,7 +146,8 @@ impl Default for AVRF_BACKTRACE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AVRF_HANDLE_OPERATION {
     pub Handle: u64,
     pub ProcessId: u32,
@@ -157,7 +165,8 @@ impl Default for AVRF_HANDLE_OPERATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AVRF_HEAP_ALLOCATION {
     pub HeapHandle: u64,
     pub UserAllocation: u64,
@@ -310,6 +310,7 @@ pub struct WINSAT_ASSESSMENT_STATE(pub i32);
 impl windows_core::TypeKind for WINSAT_ASSESSMENT_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WINSAT_ASSESSMENT_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WINSAT_ASSESSMENT_STATE").field(&self.0).finish()
@@ -321,6 +322,7 @@ pub struct WINSAT_ASSESSMENT_TYPE(pub i32);
 impl windows_core::TypeKind for WINSAT_ASSESSMENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WINSAT_ASSESSMENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WINSAT_ASSESSMENT_TYPE").field(&self.0).finish()
@@ -332,6 +334,7 @@ pub struct WINSAT_BITMAP_SIZE(pub i32);
 impl windows_core::TypeKind for WINSAT_BITMAP_SIZE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WINSAT_BITMAP_SIZE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WINSAT_BITMAP_SIZE").field(&self.0).finish()
@@ -343,6 +346,7 @@ pub struct WINSAT_OEM_CUSTOMIZATION_STATE(pub i32);
 impl windows_core::TypeKind for WINSAT_OEM_CUSTOMIZATION_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WINSAT_OEM_CUSTOMIZATION_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WINSAT_OEM_CUSTOMIZATION_STATE").field(&self.0).finish()
@@ -356,3 +360,5 @@ pub const CQueryOEMWinSATCustomization: windows_core::GUID = windows_core::GUID:
 pub const CQueryWinSAT: windows_core::GUID = windows_core::GUID::from_u128(0xf3bdfad3_f276_49e9_9b17_c474f48f0764);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,181 @@
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Accessibility"))]
+impl windows_core::imp::ProxyVtable for IAccessibleWinSAT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAccessiblityData(this: *mut core::ffi::c_void, wsname: windows_core::PCWSTR, wsvalue: windows_core::PCWSTR, wsdesc: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessibleWinSAT", "SetAccessiblityData", 28usize, |this| ((**(this as *const *const IAccessibleWinSAT_Vtbl)).SetAccessiblityData)(this, wsname, wsvalue, wsdesc), |result| Some(*result))
+        }
+        Self { base__: <super::super::UI::Accessibility::IAccessible_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAccessiblityData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccessibleWinSAT as windows_core::Interface>::IID || <super::super::UI::Accessibility::IAccessible_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInitiateWinSATAssessment_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitiateAssessment(this: *mut core::ffi::c_void, cmdline: windows_core::PCWSTR, pcallbacks: *mut core::ffi::c_void, callerhwnd: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInitiateWinSATAssessment", "InitiateAssessment", 3usize, |this| ((**(this as *const *const IInitiateWinSATAssessment_Vtbl)).InitiateAssessment)(this, cmdline, pcallbacks, callerhwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitiateFormalAssessment(this: *mut core::ffi::c_void, pcallbacks: *mut core::ffi::c_void, callerhwnd: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInitiateWinSATAssessment", "InitiateFormalAssessment", 4usize, |this| ((**(this as *const *const IInitiateWinSATAssessment_Vtbl)).InitiateFormalAssessment)(this, pcallbacks, callerhwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelAssessment(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInitiateWinSATAssessment", "CancelAssessment", 5usize, |this| ((**(this as *const *const IInitiateWinSATAssessment_Vtbl)).CancelAssessment)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InitiateAssessment, InitiateFormalAssessment, CancelAssessment }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInitiateWinSATAssessment as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IProvideWinSATAssessmentInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Score(this: *mut core::ffi::c_void, score: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProvideWinSATAssessmentInfo", "Score", 7usize, |this| ((**(this as *const *const IProvideWinSATAssessmentInfo_Vtbl)).Score)(this, score), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, title: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProvideWinSATAssessmentInfo", "Title", 8usize, |this| ((**(this as *const *const IProvideWinSATAssessmentInfo_Vtbl)).Title)(this, title), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, description: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProvideWinSATAssessmentInfo", "Description", 9usize, |this| ((**(this as *const *const IProvideWinSATAssessmentInfo_Vtbl)).Description)(this, description), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Score, Title, Description }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProvideWinSATAssessmentInfo as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IProvideWinSATResultsInfo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetAssessmentInfo(this: *mut core::ffi::c_void, assessment: WINSAT_ASSESSMENT_TYPE, ppinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProvideWinSATResultsInfo", "GetAssessmentInfo", 7usize, |this| ((**(this as *const *const IProvideWinSATResultsInfo_Vtbl)).GetAssessmentInfo)(this, assessment, ppinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn AssessmentState(this: *mut core::ffi::c_void, state: *mut WINSAT_ASSESSMENT_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProvideWinSATResultsInfo", "AssessmentState", 8usize, |this| ((**(this as *const *const IProvideWinSATResultsInfo_Vtbl)).AssessmentState)(this, state), |result| Some(*result))
+        }
+        unsafe extern "system" fn AssessmentDateTime(this: *mut core::ffi::c_void, filetime: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProvideWinSATResultsInfo", "AssessmentDateTime", 9usize, |this| ((**(this as *const *const IProvideWinSATResultsInfo_Vtbl)).AssessmentDateTime)(this, filetime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemRating(this: *mut core::ffi::c_void, level: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProvideWinSATResultsInfo", "SystemRating", 10usize, |this| ((**(this as *const *const IProvideWinSATResultsInfo_Vtbl)).SystemRating)(this, level), |result| Some(*result))
+        }
+        unsafe extern "system" fn RatingStateDesc(this: *mut core::ffi::c_void, description: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProvideWinSATResultsInfo", "RatingStateDesc", 11usize, |this| ((**(this as *const *const IProvideWinSATResultsInfo_Vtbl)).RatingStateDesc)(this, description), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetAssessmentInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetAssessmentInfo: 0,
+            AssessmentState,
+            AssessmentDateTime,
+            SystemRating,
+            RatingStateDesc,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProvideWinSATResultsInfo as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IProvideWinSATVisuals_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn get_Bitmap(this: *mut core::ffi::c_void, bitmapsize: WINSAT_BITMAP_SIZE, state: WINSAT_ASSESSMENT_STATE, rating: f32, pbitmap: *mut super::super::Graphics::Gdi::HBITMAP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProvideWinSATVisuals", "get_Bitmap", 3usize, |this| ((**(this as *const *const IProvideWinSATVisuals_Vtbl)).get_Bitmap)(this, bitmapsize, state, rating, pbitmap), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            get_Bitmap,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            get_Bitmap: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProvideWinSATVisuals as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IQueryAllWinSATAssessments_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn get_AllXML(this: *mut core::ffi::c_void, xpath: core::mem::MaybeUninit<windows_core::BSTR>, namespaces: core::mem::MaybeUninit<windows_core::BSTR>, ppdomnodelist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryAllWinSATAssessments", "get_AllXML", 7usize, |this| ((**(this as *const *const IQueryAllWinSATAssessments_Vtbl)).get_AllXML)(this, xpath, namespaces, ppdomnodelist), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com"))]
+            get_AllXML,
+            #[cfg(not(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com")))]
+            get_AllXML: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IQueryAllWinSATAssessments as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IQueryOEMWinSATCustomization_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOEMPrePopulationInfo(this: *mut core::ffi::c_void, state: *mut WINSAT_OEM_CUSTOMIZATION_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryOEMWinSATCustomization", "GetOEMPrePopulationInfo", 3usize, |this| ((**(this as *const *const IQueryOEMWinSATCustomization_Vtbl)).GetOEMPrePopulationInfo)(this, state), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetOEMPrePopulationInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IQueryOEMWinSATCustomization as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IQueryRecentWinSATAssessment_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn get_XML(this: *mut core::ffi::c_void, xpath: core::mem::MaybeUninit<windows_core::BSTR>, namespaces: core::mem::MaybeUninit<windows_core::BSTR>, ppdomnodelist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryRecentWinSATAssessment", "get_XML", 7usize, |this| ((**(this as *const *const IQueryRecentWinSATAssessment_Vtbl)).get_XML)(this, xpath, namespaces, ppdomnodelist), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Info(this: *mut core::ffi::c_void, ppwinsatassessmentinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryRecentWinSATAssessment", "Info", 8usize, |this| ((**(this as *const *const IQueryRecentWinSATAssessment_Vtbl)).Info)(this, ppwinsatassessmentinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com"))]
+            get_XML,
+            #[cfg(not(all(feature = "Win32_Data_Xml_MsXml", feature = "Win32_System_Com")))]
+            get_XML: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Info,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Info: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IQueryRecentWinSATAssessment as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWinSATInitiateEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WinSATComplete(this: *mut core::ffi::c_void, hresult: windows_core::HRESULT, strdescription: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinSATInitiateEvents", "WinSATComplete", 3usize, |this| ((**(this as *const *const IWinSATInitiateEvents_Vtbl)).WinSATComplete)(this, hresult, strdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn WinSATUpdate(this: *mut core::ffi::c_void, ucurrenttick: u32, uticktotal: u32, strcurrentstate: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinSATInitiateEvents", "WinSATUpdate", 4usize, |this| ((**(this as *const *const IWinSATInitiateEvents_Vtbl)).WinSATUpdate)(this, ucurrenttick, uticktotal, strcurrentstate), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WinSATComplete, WinSATUpdate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWinSATInitiateEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
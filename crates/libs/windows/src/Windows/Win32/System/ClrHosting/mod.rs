@@ -2986,6 +2986,7 @@ pub struct APPDOMAIN_SECURITY_FLAGS(pub i32);
 impl windows_core::TypeKind for APPDOMAIN_SECURITY_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for APPDOMAIN_SECURITY_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("APPDOMAIN_SECURITY_FLAGS").field(&self.0).finish()
@@ -2997,6 +2998,7 @@ pub struct BucketParameterIndex(pub i32);
 impl windows_core::TypeKind for BucketParameterIndex {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BucketParameterIndex {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BucketParameterIndex").field(&self.0).finish()
@@ -3008,6 +3010,7 @@ pub struct CLR_DEBUGGING_PROCESS_FLAGS(pub i32);
 impl windows_core::TypeKind for CLR_DEBUGGING_PROCESS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLR_DEBUGGING_PROCESS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLR_DEBUGGING_PROCESS_FLAGS").field(&self.0).finish()
@@ -3019,6 +3022,7 @@ pub struct CLSID_RESOLUTION_FLAGS(pub i32);
 impl windows_core::TypeKind for CLSID_RESOLUTION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLSID_RESOLUTION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLSID_RESOLUTION_FLAGS").field(&self.0).finish()
@@ -3030,6 +3034,7 @@ pub struct COR_GC_STAT_TYPES(pub i32);
 impl windows_core::TypeKind for COR_GC_STAT_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COR_GC_STAT_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COR_GC_STAT_TYPES").field(&self.0).finish()
@@ -3041,6 +3046,7 @@ pub struct COR_GC_THREAD_STATS_TYPES(pub i32);
 impl windows_core::TypeKind for COR_GC_THREAD_STATS_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COR_GC_THREAD_STATS_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COR_GC_THREAD_STATS_TYPES").field(&self.0).finish()
@@ -3052,6 +3058,7 @@ pub struct EApiCategories(pub i32);
 impl windows_core::TypeKind for EApiCategories {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EApiCategories {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EApiCategories").field(&self.0).finish()
@@ -3063,6 +3070,7 @@ pub struct EBindPolicyLevels(pub i32);
 impl windows_core::TypeKind for EBindPolicyLevels {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EBindPolicyLevels {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EBindPolicyLevels").field(&self.0).finish()
@@ -3074,6 +3082,7 @@ pub struct ECLRAssemblyIdentityFlags(pub i32);
 impl windows_core::TypeKind for ECLRAssemblyIdentityFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ECLRAssemblyIdentityFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ECLRAssemblyIdentityFlags").field(&self.0).finish()
@@ -3085,6 +3094,7 @@ pub struct EClrEvent(pub i32);
 impl windows_core::TypeKind for EClrEvent {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EClrEvent {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EClrEvent").field(&self.0).finish()
@@ -3096,6 +3106,7 @@ pub struct EClrFailure(pub i32);
 impl windows_core::TypeKind for EClrFailure {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EClrFailure {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EClrFailure").field(&self.0).finish()
@@ -3107,6 +3118,7 @@ pub struct EClrOperation(pub i32);
 impl windows_core::TypeKind for EClrOperation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EClrOperation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EClrOperation").field(&self.0).finish()
@@ -3118,6 +3130,7 @@ pub struct EClrUnhandledException(pub i32);
 impl windows_core::TypeKind for EClrUnhandledException {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EClrUnhandledException {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EClrUnhandledException").field(&self.0).finish()
@@ -3129,6 +3142,7 @@ pub struct EContextType(pub i32);
 impl windows_core::TypeKind for EContextType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EContextType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EContextType").field(&self.0).finish()
@@ -3140,6 +3154,7 @@ pub struct ECustomDumpFlavor(pub i32);
 impl windows_core::TypeKind for ECustomDumpFlavor {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ECustomDumpFlavor {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ECustomDumpFlavor").field(&self.0).finish()
@@ -3151,6 +3166,7 @@ pub struct ECustomDumpItemKind(pub i32);
 impl windows_core::TypeKind for ECustomDumpItemKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ECustomDumpItemKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ECustomDumpItemKind").field(&self.0).finish()
@@ -3162,6 +3178,7 @@ pub struct EHostApplicationPolicy(pub i32);
 impl windows_core::TypeKind for EHostApplicationPolicy {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EHostApplicationPolicy {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EHostApplicationPolicy").field(&self.0).finish()
@@ -3173,6 +3190,7 @@ pub struct EHostBindingPolicyModifyFlags(pub i32);
 impl windows_core::TypeKind for EHostBindingPolicyModifyFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EHostBindingPolicyModifyFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EHostBindingPolicyModifyFlags").field(&self.0).finish()
@@ -3184,6 +3202,7 @@ pub struct EInitializeNewDomainFlags(pub i32);
 impl windows_core::TypeKind for EInitializeNewDomainFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EInitializeNewDomainFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EInitializeNewDomainFlags").field(&self.0).finish()
@@ -3195,6 +3214,7 @@ pub struct EMemoryAvailable(pub i32);
 impl windows_core::TypeKind for EMemoryAvailable {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EMemoryAvailable {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EMemoryAvailable").field(&self.0).finish()
@@ -3206,6 +3226,7 @@ pub struct EMemoryCriticalLevel(pub i32);
 impl windows_core::TypeKind for EMemoryCriticalLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EMemoryCriticalLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EMemoryCriticalLevel").field(&self.0).finish()
@@ -3217,6 +3238,7 @@ pub struct EPolicyAction(pub i32);
 impl windows_core::TypeKind for EPolicyAction {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EPolicyAction {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EPolicyAction").field(&self.0).finish()
@@ -3228,6 +3250,7 @@ pub struct ESymbolReadingPolicy(pub i32);
 impl windows_core::TypeKind for ESymbolReadingPolicy {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ESymbolReadingPolicy {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ESymbolReadingPolicy").field(&self.0).finish()
@@ -3239,6 +3262,7 @@ pub struct ETaskType(pub i32);
 impl windows_core::TypeKind for ETaskType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ETaskType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ETaskType").field(&self.0).finish()
@@ -3250,6 +3274,7 @@ pub struct HOST_TYPE(pub i32);
 impl windows_core::TypeKind for HOST_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HOST_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HOST_TYPE").field(&self.0).finish()
@@ -3261,6 +3286,7 @@ pub struct MALLOC_TYPE(pub i32);
 impl windows_core::TypeKind for MALLOC_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MALLOC_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MALLOC_TYPE").field(&self.0).finish()
@@ -3272,6 +3298,7 @@ pub struct METAHOST_CONFIG_FLAGS(pub i32);
 impl windows_core::TypeKind for METAHOST_CONFIG_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for METAHOST_CONFIG_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("METAHOST_CONFIG_FLAGS").field(&self.0).finish()
@@ -3283,6 +3310,7 @@ pub struct METAHOST_POLICY_FLAGS(pub i32);
 impl windows_core::TypeKind for METAHOST_POLICY_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for METAHOST_POLICY_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("METAHOST_POLICY_FLAGS").field(&self.0).finish()
@@ -3294,6 +3322,7 @@ pub struct RUNTIME_INFO_FLAGS(pub i32);
 impl windows_core::TypeKind for RUNTIME_INFO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RUNTIME_INFO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RUNTIME_INFO_FLAGS").field(&self.0).finish()
@@ -3305,6 +3334,7 @@ pub struct STARTUP_FLAGS(pub i32);
 impl windows_core::TypeKind for STARTUP_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STARTUP_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STARTUP_FLAGS").field(&self.0).finish()
@@ -3316,6 +3346,7 @@ pub struct StackOverflowType(pub i32);
 impl windows_core::TypeKind for StackOverflowType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StackOverflowType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StackOverflowType").field(&self.0).finish()
@@ -3327,13 +3358,15 @@ pub struct WAIT_OPTION(pub i32);
 impl windows_core::TypeKind for WAIT_OPTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WAIT_OPTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WAIT_OPTION").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AssemblyBindInfo {
     pub dwAppDomainId: u32,
     pub lpReferencedIdentity: windows_core::PCWSTR,
@@ -3349,7 +3382,8 @@ impl Default for AssemblyBindInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BucketParameters {
     pub fInited: super::super::Foundation::BOOL,
     pub pszEventTypeName: [u16; 255],
@@ -3365,7 +3399,8 @@ impl Default for BucketParameters {
 }
 pub const CLRRuntimeHost: windows_core::GUID = windows_core::GUID::from_u128(0x90f1a06e_7712_4762_86b5_7a5eba6bdb02);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLR_DEBUGGING_VERSION {
     pub wStructVersion: u16,
     pub wMajor: u16,
@@ -3382,7 +3417,8 @@ impl Default for CLR_DEBUGGING_VERSION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COR_GC_STATS {
     pub Flags: u32,
     pub ExplicitGCCount: usize,
@@ -3405,7 +3441,8 @@ impl Default for COR_GC_STATS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COR_GC_THREAD_STATS {
     pub PerThreadAllocation: u64,
     pub Flags: u32,
@@ -3449,7 +3486,8 @@ impl Default for CustomDumpItem_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MDAInfo {
     pub lpMDACaption: windows_core::PCWSTR,
     pub lpMDAMessage: windows_core::PCWSTR,
@@ -3464,7 +3502,8 @@ impl Default for MDAInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ModuleBindInfo {
     pub dwAppDomainId: u32,
     pub lpAssemblyIdentity: windows_core::PCWSTR,
@@ -3480,7 +3519,8 @@ impl Default for ModuleBindInfo {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_System_Diagnostics_Debug", feature = "Win32_System_Kernel"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct StackOverflowInfo {
     pub soType: StackOverflowType,
     pub pExceptionInfo: *mut super::Diagnostics::Debug::EXCEPTION_POINTERS,
@@ -3506,3 +3546,5 @@ pub type PTLS_CALLBACK_FUNCTION = Option<unsafe extern "system" fn(__midl____mid
 pub type RuntimeLoadedCallbackFnPtr = Option<unsafe extern "system" fn(pruntimeinfo: Option<ICLRRuntimeInfo>, pfncallbackthreadset: CallbackThreadSetFnPtr, pfncallbackthreadunset: CallbackThreadUnsetFnPtr)>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1865 @@
+impl windows_core::imp::ProxyVtable for IActionOnCLREvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnEvent(this: *mut core::ffi::c_void, event: EClrEvent, data: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActionOnCLREvent", "OnEvent", 3usize, |this| ((**(this as *const *const IActionOnCLREvent_Vtbl)).OnEvent)(this, event, data), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActionOnCLREvent as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApartmentCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DoCallback(this: *mut core::ffi::c_void, pfunc: usize, pdata: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApartmentCallback", "DoCallback", 3usize, |this| ((**(this as *const *const IApartmentCallback_Vtbl)).DoCallback)(this, pfunc, pdata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DoCallback }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApartmentCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppDomainBinding_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnAppDomain(this: *mut core::ffi::c_void, pappdomain: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDomainBinding", "OnAppDomain", 3usize, |this| ((**(this as *const *const IAppDomainBinding_Vtbl)).OnAppDomain)(this, pappdomain), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnAppDomain }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppDomainBinding as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICLRAppDomainResourceMonitor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentAllocated(this: *mut core::ffi::c_void, dwappdomainid: u32, pbytesallocated: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRAppDomainResourceMonitor", "GetCurrentAllocated", 3usize, |this| ((**(this as *const *const ICLRAppDomainResourceMonitor_Vtbl)).GetCurrentAllocated)(this, dwappdomainid, pbytesallocated), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentSurvived(this: *mut core::ffi::c_void, dwappdomainid: u32, pappdomainbytessurvived: *mut u64, ptotalbytessurvived: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRAppDomainResourceMonitor", "GetCurrentSurvived", 4usize, |this| ((**(this as *const *const ICLRAppDomainResourceMonitor_Vtbl)).GetCurrentSurvived)(this, dwappdomainid, pappdomainbytessurvived, ptotalbytessurvived), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentCpuTime(this: *mut core::ffi::c_void, dwappdomainid: u32, pmilliseconds: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRAppDomainResourceMonitor", "GetCurrentCpuTime", 5usize, |this| ((**(this as *const *const ICLRAppDomainResourceMonitor_Vtbl)).GetCurrentCpuTime)(this, dwappdomainid, pmilliseconds), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrentAllocated, GetCurrentSurvived, GetCurrentCpuTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRAppDomainResourceMonitor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICLRAssemblyIdentityManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCLRAssemblyReferenceList(this: *mut core::ffi::c_void, ppwzassemblyreferences: *const windows_core::PCWSTR, dwnumofreferences: u32, ppreferencelist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRAssemblyIdentityManager", "GetCLRAssemblyReferenceList", 3usize, |this| ((**(this as *const *const ICLRAssemblyIdentityManager_Vtbl)).GetCLRAssemblyReferenceList)(this, ppwzassemblyreferences, dwnumofreferences, ppreferencelist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBindingIdentityFromFile(this: *mut core::ffi::c_void, pwzfilepath: windows_core::PCWSTR, dwflags: u32, pwzbuffer: windows_core::PWSTR, pcchbuffersize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRAssemblyIdentityManager", "GetBindingIdentityFromFile", 4usize, |this| ((**(this as *const *const ICLRAssemblyIdentityManager_Vtbl)).GetBindingIdentityFromFile)(this, pwzfilepath, dwflags, pwzbuffer, pcchbuffersize), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetBindingIdentityFromStream(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, dwflags: u32, pwzbuffer: windows_core::PWSTR, pcchbuffersize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRAssemblyIdentityManager", "GetBindingIdentityFromStream", 5usize, |this| ((**(this as *const *const ICLRAssemblyIdentityManager_Vtbl)).GetBindingIdentityFromStream)(this, pstream, dwflags, pwzbuffer, pcchbuffersize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReferencedAssembliesFromFile(this: *mut core::ffi::c_void, pwzfilepath: windows_core::PCWSTR, dwflags: u32, pexcludeassemblieslist: *mut core::ffi::c_void, ppreferenceenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRAssemblyIdentityManager", "GetReferencedAssembliesFromFile", 6usize, |this| ((**(this as *const *const ICLRAssemblyIdentityManager_Vtbl)).GetReferencedAssembliesFromFile)(this, pwzfilepath, dwflags, pexcludeassemblieslist, ppreferenceenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetReferencedAssembliesFromStream(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, dwflags: u32, pexcludeassemblieslist: *mut core::ffi::c_void, ppreferenceenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRAssemblyIdentityManager", "GetReferencedAssembliesFromStream", 7usize, |this| ((**(this as *const *const ICLRAssemblyIdentityManager_Vtbl)).GetReferencedAssembliesFromStream)(this, pstream, dwflags, pexcludeassemblieslist, ppreferenceenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProbingAssembliesFromReference(this: *mut core::ffi::c_void, dwmachinetype: u32, dwflags: u32, pwzreferenceidentity: windows_core::PCWSTR, ppprobingassemblyenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRAssemblyIdentityManager", "GetProbingAssembliesFromReference", 8usize, |this| ((**(this as *const *const ICLRAssemblyIdentityManager_Vtbl)).GetProbingAssembliesFromReference)(this, dwmachinetype, dwflags, pwzreferenceidentity, ppprobingassemblyenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStronglyNamed(this: *mut core::ffi::c_void, pwzassemblyidentity: windows_core::PCWSTR, pbisstronglynamed: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRAssemblyIdentityManager", "IsStronglyNamed", 9usize, |this| ((**(this as *const *const ICLRAssemblyIdentityManager_Vtbl)).IsStronglyNamed)(this, pwzassemblyidentity, pbisstronglynamed), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCLRAssemblyReferenceList,
+            GetBindingIdentityFromFile,
+            #[cfg(feature = "Win32_System_Com")]
+            GetBindingIdentityFromStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetBindingIdentityFromStream: 0,
+            GetReferencedAssembliesFromFile,
+            #[cfg(feature = "Win32_System_Com")]
+            GetReferencedAssembliesFromStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetReferencedAssembliesFromStream: 0,
+            GetProbingAssembliesFromReference,
+            IsStronglyNamed,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRAssemblyIdentityManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICLRAssemblyReferenceList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsStringAssemblyReferenceInList(this: *mut core::ffi::c_void, pwzassemblyname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRAssemblyReferenceList", "IsStringAssemblyReferenceInList", 3usize, |this| ((**(this as *const *const ICLRAssemblyReferenceList_Vtbl)).IsStringAssemblyReferenceInList)(this, pwzassemblyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAssemblyReferenceInList(this: *mut core::ffi::c_void, pname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRAssemblyReferenceList", "IsAssemblyReferenceInList", 4usize, |this| ((**(this as *const *const ICLRAssemblyReferenceList_Vtbl)).IsAssemblyReferenceInList)(this, pname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsStringAssemblyReferenceInList, IsAssemblyReferenceInList }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRAssemblyReferenceList as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICLRControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCLRManager(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRControl", "GetCLRManager", 3usize, |this| ((**(this as *const *const ICLRControl_Vtbl)).GetCLRManager)(this, riid, ppobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAppDomainManagerType(this: *mut core::ffi::c_void, pwzappdomainmanagerassembly: windows_core::PCWSTR, pwzappdomainmanagertype: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRControl", "SetAppDomainManagerType", 4usize, |this| ((**(this as *const *const ICLRControl_Vtbl)).SetAppDomainManagerType)(this, pwzappdomainmanagerassembly, pwzappdomainmanagertype), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCLRManager, SetAppDomainManagerType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Security")]
+impl windows_core::imp::ProxyVtable for ICLRDebugManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BeginConnection(this: *mut core::ffi::c_void, dwconnectionid: u32, szconnectionname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRDebugManager", "BeginConnection", 3usize, |this| ((**(this as *const *const ICLRDebugManager_Vtbl)).BeginConnection)(this, dwconnectionid, szconnectionname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConnectionTasks(this: *mut core::ffi::c_void, id: u32, dwcount: u32, ppclrtask: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRDebugManager", "SetConnectionTasks", 4usize, |this| ((**(this as *const *const ICLRDebugManager_Vtbl)).SetConnectionTasks)(this, id, dwcount, ppclrtask), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndConnection(this: *mut core::ffi::c_void, dwconnectionid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRDebugManager", "EndConnection", 5usize, |this| ((**(this as *const *const ICLRDebugManager_Vtbl)).EndConnection)(this, dwconnectionid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Security")]
+        unsafe extern "system" fn SetDacl(this: *mut core::ffi::c_void, pacl: *const super::super::Security::ACL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRDebugManager", "SetDacl", 6usize, |this| ((**(this as *const *const ICLRDebugManager_Vtbl)).SetDacl)(this, pacl), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Security")]
+        unsafe extern "system" fn GetDacl(this: *mut core::ffi::c_void, pacl: *mut *mut super::super::Security::ACL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRDebugManager", "GetDacl", 7usize, |this| ((**(this as *const *const ICLRDebugManager_Vtbl)).GetDacl)(this, pacl), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDebuggerAttached(this: *mut core::ffi::c_void, pbattached: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRDebugManager", "IsDebuggerAttached", 8usize, |this| ((**(this as *const *const ICLRDebugManager_Vtbl)).IsDebuggerAttached)(this, pbattached), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSymbolReadingPolicy(this: *mut core::ffi::c_void, policy: ESymbolReadingPolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRDebugManager", "SetSymbolReadingPolicy", 9usize, |this| ((**(this as *const *const ICLRDebugManager_Vtbl)).SetSymbolReadingPolicy)(this, policy), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BeginConnection,
+            SetConnectionTasks,
+            EndConnection,
+            #[cfg(feature = "Win32_Security")]
+            SetDacl,
+            #[cfg(not(feature = "Win32_Security"))]
+            SetDacl: 0,
+            #[cfg(feature = "Win32_Security")]
+            GetDacl,
+            #[cfg(not(feature = "Win32_Security"))]
+            GetDacl: 0,
+            IsDebuggerAttached,
+            SetSymbolReadingPolicy,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRDebugManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICLRDebugging_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenVirtualProcess(this: *mut core::ffi::c_void, modulebaseaddress: u64, pdatatarget: *mut core::ffi::c_void, plibraryprovider: *mut core::ffi::c_void, pmaxdebuggersupportedversion: *const CLR_DEBUGGING_VERSION, riidprocess: *const windows_core::GUID, ppprocess: *mut *mut core::ffi::c_void, pversion: *mut CLR_DEBUGGING_VERSION, pdwflags: *mut CLR_DEBUGGING_PROCESS_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRDebugging", "OpenVirtualProcess", 3usize, |this| ((**(this as *const *const ICLRDebugging_Vtbl)).OpenVirtualProcess)(this, modulebaseaddress, pdatatarget, plibraryprovider, pmaxdebuggersupportedversion, riidprocess, ppprocess, pversion, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanUnloadNow(this: *mut core::ffi::c_void, hmodule: super::super::Foundation::HMODULE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRDebugging", "CanUnloadNow", 4usize, |this| ((**(this as *const *const ICLRDebugging_Vtbl)).CanUnloadNow)(this, hmodule), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OpenVirtualProcess, CanUnloadNow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRDebugging as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICLRDebuggingLibraryProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProvideLibrary(this: *mut core::ffi::c_void, pwszfilename: windows_core::PCWSTR, dwtimestamp: u32, dwsizeofimage: u32, phmodule: *mut super::super::Foundation::HMODULE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRDebuggingLibraryProvider", "ProvideLibrary", 3usize, |this| ((**(this as *const *const ICLRDebuggingLibraryProvider_Vtbl)).ProvideLibrary)(this, pwszfilename, dwtimestamp, dwsizeofimage, phmodule), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ProvideLibrary }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRDebuggingLibraryProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICLRDomainManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAppDomainManagerType(this: *mut core::ffi::c_void, wszappdomainmanagerassembly: windows_core::PCWSTR, wszappdomainmanagertype: windows_core::PCWSTR, dwinitializedomainflags: EInitializeNewDomainFlags) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRDomainManager", "SetAppDomainManagerType", 3usize, |this| ((**(this as *const *const ICLRDomainManager_Vtbl)).SetAppDomainManagerType)(this, wszappdomainmanagerassembly, wszappdomainmanagertype, dwinitializedomainflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPropertiesForDefaultAppDomain(this: *mut core::ffi::c_void, nproperties: u32, pwszpropertynames: *const windows_core::PCWSTR, pwszpropertyvalues: *const windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRDomainManager", "SetPropertiesForDefaultAppDomain", 4usize, |this| ((**(this as *const *const ICLRDomainManager_Vtbl)).SetPropertiesForDefaultAppDomain)(this, nproperties, pwszpropertynames, pwszpropertyvalues), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAppDomainManagerType, SetPropertiesForDefaultAppDomain }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRDomainManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICLRErrorReportingManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBucketParametersForCurrentException(this: *mut core::ffi::c_void, pparams: *mut BucketParameters) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRErrorReportingManager", "GetBucketParametersForCurrentException", 3usize, |this| ((**(this as *const *const ICLRErrorReportingManager_Vtbl)).GetBucketParametersForCurrentException)(this, pparams), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginCustomDump(this: *mut core::ffi::c_void, dwflavor: ECustomDumpFlavor, dwnumitems: u32, items: *const CustomDumpItem, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRErrorReportingManager", "BeginCustomDump", 4usize, |this| ((**(this as *const *const ICLRErrorReportingManager_Vtbl)).BeginCustomDump)(this, dwflavor, dwnumitems, items, dwreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndCustomDump(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRErrorReportingManager", "EndCustomDump", 5usize, |this| ((**(this as *const *const ICLRErrorReportingManager_Vtbl)).EndCustomDump)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetBucketParametersForCurrentException,
+            BeginCustomDump,
+            EndCustomDump,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRErrorReportingManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICLRGCManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Collect(this: *mut core::ffi::c_void, generation: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRGCManager", "Collect", 3usize, |this| ((**(this as *const *const ICLRGCManager_Vtbl)).Collect)(this, generation), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStats(this: *mut core::ffi::c_void, pstats: *mut COR_GC_STATS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRGCManager", "GetStats", 4usize, |this| ((**(this as *const *const ICLRGCManager_Vtbl)).GetStats)(this, pstats), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGCStartupLimits(this: *mut core::ffi::c_void, segmentsize: u32, maxgen0size: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRGCManager", "SetGCStartupLimits", 5usize, |this| ((**(this as *const *const ICLRGCManager_Vtbl)).SetGCStartupLimits)(this, segmentsize, maxgen0size), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Collect, GetStats, SetGCStartupLimits }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRGCManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICLRGCManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetGCStartupLimitsEx(this: *mut core::ffi::c_void, segmentsize: usize, maxgen0size: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRGCManager2", "SetGCStartupLimitsEx", 6usize, |this| ((**(this as *const *const ICLRGCManager2_Vtbl)).SetGCStartupLimitsEx)(this, segmentsize, maxgen0size), |result| Some(*result))
+        }
+        Self { base__: <ICLRGCManager_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetGCStartupLimitsEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRGCManager2 as windows_core::Interface>::IID || <ICLRGCManager_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICLRHostBindingPolicyManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ModifyApplicationPolicy(this: *mut core::ffi::c_void, pwzsourceassemblyidentity: windows_core::PCWSTR, pwztargetassemblyidentity: windows_core::PCWSTR, pbapplicationpolicy: *const u8, cbapppolicysize: u32, dwpolicymodifyflags: u32, pbnewapplicationpolicy: *mut u8, pcbnewapppolicysize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRHostBindingPolicyManager", "ModifyApplicationPolicy", 3usize, |this| ((**(this as *const *const ICLRHostBindingPolicyManager_Vtbl)).ModifyApplicationPolicy)(this, pwzsourceassemblyidentity, pwztargetassemblyidentity, pbapplicationpolicy, cbapppolicysize, dwpolicymodifyflags, pbnewapplicationpolicy, pcbnewapppolicysize), |result| Some(*result))
+        }
+        unsafe extern "system" fn EvaluatePolicy(this: *mut core::ffi::c_void, pwzreferenceidentity: windows_core::PCWSTR, pbapplicationpolicy: *const u8, cbapppolicysize: u32, pwzpostpolicyreferenceidentity: windows_core::PWSTR, pcchpostpolicyreferenceidentity: *mut u32, pdwpoliciesapplied: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRHostBindingPolicyManager", "EvaluatePolicy", 4usize, |this| ((**(this as *const *const ICLRHostBindingPolicyManager_Vtbl)).EvaluatePolicy)(this, pwzreferenceidentity, pbapplicationpolicy, cbapppolicysize, pwzpostpolicyreferenceidentity, pcchpostpolicyreferenceidentity, pdwpoliciesapplied), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ModifyApplicationPolicy, EvaluatePolicy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRHostBindingPolicyManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICLRHostProtectionManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetProtectedCategories(this: *mut core::ffi::c_void, categories: EApiCategories) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRHostProtectionManager", "SetProtectedCategories", 3usize, |this| ((**(this as *const *const ICLRHostProtectionManager_Vtbl)).SetProtectedCategories)(this, categories), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEagerSerializeGrantSets(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRHostProtectionManager", "SetEagerSerializeGrantSets", 4usize, |this| ((**(this as *const *const ICLRHostProtectionManager_Vtbl)).SetEagerSerializeGrantSets)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetProtectedCategories, SetEagerSerializeGrantSets }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRHostProtectionManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICLRIoCompletionManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnComplete(this: *mut core::ffi::c_void, dwerrorcode: u32, numberofbytestransferred: u32, pvoverlapped: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRIoCompletionManager", "OnComplete", 3usize, |this| ((**(this as *const *const ICLRIoCompletionManager_Vtbl)).OnComplete)(this, dwerrorcode, numberofbytestransferred, pvoverlapped), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnComplete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRIoCompletionManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICLRMemoryNotificationCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnMemoryNotification(this: *mut core::ffi::c_void, ememoryavailable: EMemoryAvailable) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRMemoryNotificationCallback", "OnMemoryNotification", 3usize, |this| ((**(this as *const *const ICLRMemoryNotificationCallback_Vtbl)).OnMemoryNotification)(this, ememoryavailable), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnMemoryNotification }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRMemoryNotificationCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICLRMetaHost_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRuntime(this: *mut core::ffi::c_void, pwzversion: windows_core::PCWSTR, riid: *const windows_core::GUID, ppruntime: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRMetaHost", "GetRuntime", 3usize, |this| ((**(this as *const *const ICLRMetaHost_Vtbl)).GetRuntime)(this, pwzversion, riid, ppruntime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionFromFile(this: *mut core::ffi::c_void, pwzfilepath: windows_core::PCWSTR, pwzbuffer: windows_core::PWSTR, pcchbuffer: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRMetaHost", "GetVersionFromFile", 4usize, |this| ((**(this as *const *const ICLRMetaHost_Vtbl)).GetVersionFromFile)(this, pwzfilepath, pwzbuffer, pcchbuffer), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumerateInstalledRuntimes(this: *mut core::ffi::c_void, ppenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRMetaHost", "EnumerateInstalledRuntimes", 5usize, |this| ((**(this as *const *const ICLRMetaHost_Vtbl)).EnumerateInstalledRuntimes)(this, ppenumerator), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumerateLoadedRuntimes(this: *mut core::ffi::c_void, hndprocess: super::super::Foundation::HANDLE, ppenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRMetaHost", "EnumerateLoadedRuntimes", 6usize, |this| ((**(this as *const *const ICLRMetaHost_Vtbl)).EnumerateLoadedRuntimes)(this, hndprocess, ppenumerator), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestRuntimeLoadedNotification(this: *mut core::ffi::c_void, pcallbackfunction: RuntimeLoadedCallbackFnPtr) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRMetaHost", "RequestRuntimeLoadedNotification", 7usize, |this| ((**(this as *const *const ICLRMetaHost_Vtbl)).RequestRuntimeLoadedNotification)(this, pcallbackfunction), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryLegacyV2RuntimeBinding(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRMetaHost", "QueryLegacyV2RuntimeBinding", 8usize, |this| ((**(this as *const *const ICLRMetaHost_Vtbl)).QueryLegacyV2RuntimeBinding)(this, riid, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExitProcess(this: *mut core::ffi::c_void, iexitcode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRMetaHost", "ExitProcess", 9usize, |this| ((**(this as *const *const ICLRMetaHost_Vtbl)).ExitProcess)(this, iexitcode), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetRuntime,
+            GetVersionFromFile,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumerateInstalledRuntimes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumerateInstalledRuntimes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumerateLoadedRuntimes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumerateLoadedRuntimes: 0,
+            RequestRuntimeLoadedNotification,
+            QueryLegacyV2RuntimeBinding,
+            ExitProcess,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRMetaHost as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICLRMetaHostPolicy_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetRequestedRuntime(this: *mut core::ffi::c_void, dwpolicyflags: METAHOST_POLICY_FLAGS, pwzbinary: windows_core::PCWSTR, pcfgstream: *mut core::ffi::c_void, pwzversion: windows_core::PWSTR, pcchversion: *mut u32, pwzimageversion: windows_core::PWSTR, pcchimageversion: *mut u32, pdwconfigflags: *mut u32, riid: *const windows_core::GUID, ppruntime: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRMetaHostPolicy", "GetRequestedRuntime", 3usize, |this| ((**(this as *const *const ICLRMetaHostPolicy_Vtbl)).GetRequestedRuntime)(this, dwpolicyflags, pwzbinary, pcfgstream, pwzversion, pcchversion, pwzimageversion, pcchimageversion, pdwconfigflags, riid, ppruntime), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetRequestedRuntime,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetRequestedRuntime: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRMetaHostPolicy as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICLROnEventManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterActionOnEvent(this: *mut core::ffi::c_void, event: EClrEvent, paction: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLROnEventManager", "RegisterActionOnEvent", 3usize, |this| ((**(this as *const *const ICLROnEventManager_Vtbl)).RegisterActionOnEvent)(this, event, paction), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterActionOnEvent(this: *mut core::ffi::c_void, event: EClrEvent, paction: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLROnEventManager", "UnregisterActionOnEvent", 4usize, |this| ((**(this as *const *const ICLROnEventManager_Vtbl)).UnregisterActionOnEvent)(this, event, paction), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterActionOnEvent, UnregisterActionOnEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLROnEventManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICLRPolicyManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDefaultAction(this: *mut core::ffi::c_void, operation: EClrOperation, action: EPolicyAction) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRPolicyManager", "SetDefaultAction", 3usize, |this| ((**(this as *const *const ICLRPolicyManager_Vtbl)).SetDefaultAction)(this, operation, action), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTimeout(this: *mut core::ffi::c_void, operation: EClrOperation, dwmilliseconds: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRPolicyManager", "SetTimeout", 4usize, |this| ((**(this as *const *const ICLRPolicyManager_Vtbl)).SetTimeout)(this, operation, dwmilliseconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActionOnTimeout(this: *mut core::ffi::c_void, operation: EClrOperation, action: EPolicyAction) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRPolicyManager", "SetActionOnTimeout", 5usize, |this| ((**(this as *const *const ICLRPolicyManager_Vtbl)).SetActionOnTimeout)(this, operation, action), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTimeoutAndAction(this: *mut core::ffi::c_void, operation: EClrOperation, dwmilliseconds: u32, action: EPolicyAction) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRPolicyManager", "SetTimeoutAndAction", 6usize, |this| ((**(this as *const *const ICLRPolicyManager_Vtbl)).SetTimeoutAndAction)(this, operation, dwmilliseconds, action), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActionOnFailure(this: *mut core::ffi::c_void, failure: EClrFailure, action: EPolicyAction) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRPolicyManager", "SetActionOnFailure", 7usize, |this| ((**(this as *const *const ICLRPolicyManager_Vtbl)).SetActionOnFailure)(this, failure, action), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUnhandledExceptionPolicy(this: *mut core::ffi::c_void, policy: EClrUnhandledException) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRPolicyManager", "SetUnhandledExceptionPolicy", 8usize, |this| ((**(this as *const *const ICLRPolicyManager_Vtbl)).SetUnhandledExceptionPolicy)(this, policy), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetDefaultAction,
+            SetTimeout,
+            SetActionOnTimeout,
+            SetTimeoutAndAction,
+            SetActionOnFailure,
+            SetUnhandledExceptionPolicy,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRPolicyManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICLRProbingAssemblyEnum_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Get(this: *mut core::ffi::c_void, dwindex: u32, pwzbuffer: windows_core::PWSTR, pcchbuffersize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRProbingAssemblyEnum", "Get", 3usize, |this| ((**(this as *const *const ICLRProbingAssemblyEnum_Vtbl)).Get)(this, dwindex, pwzbuffer, pcchbuffersize), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Get }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRProbingAssemblyEnum as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICLRProfiling_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AttachProfiler(this: *mut core::ffi::c_void, dwprofileeprocessid: u32, dwmillisecondsmax: u32, pclsidprofiler: *const windows_core::GUID, wszprofilerpath: windows_core::PCWSTR, pvclientdata: *const core::ffi::c_void, cbclientdata: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRProfiling", "AttachProfiler", 3usize, |this| ((**(this as *const *const ICLRProfiling_Vtbl)).AttachProfiler)(this, dwprofileeprocessid, dwmillisecondsmax, pclsidprofiler, wszprofilerpath, pvclientdata, cbclientdata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AttachProfiler }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRProfiling as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICLRReferenceAssemblyEnum_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Get(this: *mut core::ffi::c_void, dwindex: u32, pwzbuffer: windows_core::PWSTR, pcchbuffersize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRReferenceAssemblyEnum", "Get", 3usize, |this| ((**(this as *const *const ICLRReferenceAssemblyEnum_Vtbl)).Get)(this, dwindex, pwzbuffer, pcchbuffersize), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Get }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRReferenceAssemblyEnum as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICLRRuntimeHost_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRRuntimeHost", "Start", 3usize, |this| ((**(this as *const *const ICLRRuntimeHost_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRRuntimeHost", "Stop", 4usize, |this| ((**(this as *const *const ICLRRuntimeHost_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHostControl(this: *mut core::ffi::c_void, phostcontrol: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRRuntimeHost", "SetHostControl", 5usize, |this| ((**(this as *const *const ICLRRuntimeHost_Vtbl)).SetHostControl)(this, phostcontrol), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCLRControl(this: *mut core::ffi::c_void, pclrcontrol: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRRuntimeHost", "GetCLRControl", 6usize, |this| ((**(this as *const *const ICLRRuntimeHost_Vtbl)).GetCLRControl)(this, pclrcontrol), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnloadAppDomain(this: *mut core::ffi::c_void, dwappdomainid: u32, fwaituntildone: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRRuntimeHost", "UnloadAppDomain", 7usize, |this| ((**(this as *const *const ICLRRuntimeHost_Vtbl)).UnloadAppDomain)(this, dwappdomainid, fwaituntildone), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExecuteInAppDomain(this: *mut core::ffi::c_void, dwappdomainid: u32, pcallback: FExecuteInAppDomainCallback, cookie: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRRuntimeHost", "ExecuteInAppDomain", 8usize, |this| ((**(this as *const *const ICLRRuntimeHost_Vtbl)).ExecuteInAppDomain)(this, dwappdomainid, pcallback, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentAppDomainId(this: *mut core::ffi::c_void, pdwappdomainid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRRuntimeHost", "GetCurrentAppDomainId", 9usize, |this| ((**(this as *const *const ICLRRuntimeHost_Vtbl)).GetCurrentAppDomainId)(this, pdwappdomainid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExecuteApplication(this: *mut core::ffi::c_void, pwzappfullname: windows_core::PCWSTR, dwmanifestpaths: u32, ppwzmanifestpaths: *const windows_core::PCWSTR, dwactivationdata: u32, ppwzactivationdata: *const windows_core::PCWSTR, preturnvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRRuntimeHost", "ExecuteApplication", 10usize, |this| ((**(this as *const *const ICLRRuntimeHost_Vtbl)).ExecuteApplication)(this, pwzappfullname, dwmanifestpaths, ppwzmanifestpaths, dwactivationdata, ppwzactivationdata, preturnvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExecuteInDefaultAppDomain(this: *mut core::ffi::c_void, pwzassemblypath: windows_core::PCWSTR, pwztypename: windows_core::PCWSTR, pwzmethodname: windows_core::PCWSTR, pwzargument: windows_core::PCWSTR, preturnvalue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRRuntimeHost", "ExecuteInDefaultAppDomain", 11usize, |this| ((**(this as *const *const ICLRRuntimeHost_Vtbl)).ExecuteInDefaultAppDomain)(this, pwzassemblypath, pwztypename, pwzmethodname, pwzargument, preturnvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Start,
+            Stop,
+            SetHostControl,
+            GetCLRControl,
+            UnloadAppDomain,
+            ExecuteInAppDomain,
+            GetCurrentAppDomainId,
+            ExecuteApplication,
+            ExecuteInDefaultAppDomain,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRRuntimeHost as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICLRRuntimeInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetVersionString(this: *mut core::ffi::c_void, pwzbuffer: windows_core::PWSTR, pcchbuffer: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRRuntimeInfo", "GetVersionString", 3usize, |this| ((**(this as *const *const ICLRRuntimeInfo_Vtbl)).GetVersionString)(this, pwzbuffer, pcchbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRuntimeDirectory(this: *mut core::ffi::c_void, pwzbuffer: windows_core::PWSTR, pcchbuffer: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRRuntimeInfo", "GetRuntimeDirectory", 4usize, |this| ((**(this as *const *const ICLRRuntimeInfo_Vtbl)).GetRuntimeDirectory)(this, pwzbuffer, pcchbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLoaded(this: *mut core::ffi::c_void, hndprocess: super::super::Foundation::HANDLE, pbloaded: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRRuntimeInfo", "IsLoaded", 5usize, |this| ((**(this as *const *const ICLRRuntimeInfo_Vtbl)).IsLoaded)(this, hndprocess, pbloaded), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadErrorString(this: *mut core::ffi::c_void, iresourceid: u32, pwzbuffer: windows_core::PWSTR, pcchbuffer: *mut u32, ilocaleid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRRuntimeInfo", "LoadErrorString", 6usize, |this| ((**(this as *const *const ICLRRuntimeInfo_Vtbl)).LoadErrorString)(this, iresourceid, pwzbuffer, pcchbuffer, ilocaleid), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadLibraryA(this: *mut core::ffi::c_void, pwzdllname: windows_core::PCWSTR, phndmodule: *mut super::super::Foundation::HMODULE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRRuntimeInfo", "LoadLibraryA", 7usize, |this| ((**(this as *const *const ICLRRuntimeInfo_Vtbl)).LoadLibraryA)(this, pwzdllname, phndmodule), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProcAddress(this: *mut core::ffi::c_void, pszprocname: windows_core::PCSTR, ppproc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRRuntimeInfo", "GetProcAddress", 8usize, |this| ((**(this as *const *const ICLRRuntimeInfo_Vtbl)).GetProcAddress)(this, pszprocname, ppproc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInterface(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRRuntimeInfo", "GetInterface", 9usize, |this| ((**(this as *const *const ICLRRuntimeInfo_Vtbl)).GetInterface)(this, rclsid, riid, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLoadable(this: *mut core::ffi::c_void, pbloadable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRRuntimeInfo", "IsLoadable", 10usize, |this| ((**(this as *const *const ICLRRuntimeInfo_Vtbl)).IsLoadable)(this, pbloadable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultStartupFlags(this: *mut core::ffi::c_void, dwstartupflags: u32, pwzhostconfigfile: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRRuntimeInfo", "SetDefaultStartupFlags", 11usize, |this| ((**(this as *const *const ICLRRuntimeInfo_Vtbl)).SetDefaultStartupFlags)(this, dwstartupflags, pwzhostconfigfile), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultStartupFlags(this: *mut core::ffi::c_void, pdwstartupflags: *mut u32, pwzhostconfigfile: windows_core::PWSTR, pcchhostconfigfile: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRRuntimeInfo", "GetDefaultStartupFlags", 12usize, |this| ((**(this as *const *const ICLRRuntimeInfo_Vtbl)).GetDefaultStartupFlags)(this, pdwstartupflags, pwzhostconfigfile, pcchhostconfigfile), |result| Some(*result))
+        }
+        unsafe extern "system" fn BindAsLegacyV2Runtime(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRRuntimeInfo", "BindAsLegacyV2Runtime", 13usize, |this| ((**(this as *const *const ICLRRuntimeInfo_Vtbl)).BindAsLegacyV2Runtime)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStarted(this: *mut core::ffi::c_void, pbstarted: *mut super::super::Foundation::BOOL, pdwstartupflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRRuntimeInfo", "IsStarted", 14usize, |this| ((**(this as *const *const ICLRRuntimeInfo_Vtbl)).IsStarted)(this, pbstarted, pdwstartupflags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetVersionString,
+            GetRuntimeDirectory,
+            IsLoaded,
+            LoadErrorString,
+            LoadLibraryA,
+            GetProcAddress,
+            GetInterface,
+            IsLoadable,
+            SetDefaultStartupFlags,
+            GetDefaultStartupFlags,
+            BindAsLegacyV2Runtime,
+            IsStarted,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRRuntimeInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICLRStrongName_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetHashFromAssemblyFile(this: *mut core::ffi::c_void, pszfilepath: windows_core::PCSTR, pihashalg: *mut u32, pbhash: *mut u8, cchhash: u32, pchhash: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName", "GetHashFromAssemblyFile", 3usize, |this| ((**(this as *const *const ICLRStrongName_Vtbl)).GetHashFromAssemblyFile)(this, pszfilepath, pihashalg, pbhash, cchhash, pchhash), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHashFromAssemblyFileW(this: *mut core::ffi::c_void, pwzfilepath: windows_core::PCWSTR, pihashalg: *mut u32, pbhash: *mut u8, cchhash: u32, pchhash: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName", "GetHashFromAssemblyFileW", 4usize, |this| ((**(this as *const *const ICLRStrongName_Vtbl)).GetHashFromAssemblyFileW)(this, pwzfilepath, pihashalg, pbhash, cchhash, pchhash), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHashFromBlob(this: *mut core::ffi::c_void, pbblob: *const u8, cchblob: u32, pihashalg: *mut u32, pbhash: *mut u8, cchhash: u32, pchhash: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName", "GetHashFromBlob", 5usize, |this| ((**(this as *const *const ICLRStrongName_Vtbl)).GetHashFromBlob)(this, pbblob, cchblob, pihashalg, pbhash, cchhash, pchhash), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHashFromFile(this: *mut core::ffi::c_void, pszfilepath: windows_core::PCSTR, pihashalg: *mut u32, pbhash: *mut u8, cchhash: u32, pchhash: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName", "GetHashFromFile", 6usize, |this| ((**(this as *const *const ICLRStrongName_Vtbl)).GetHashFromFile)(this, pszfilepath, pihashalg, pbhash, cchhash, pchhash), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHashFromFileW(this: *mut core::ffi::c_void, pwzfilepath: windows_core::PCWSTR, pihashalg: *mut u32, pbhash: *mut u8, cchhash: u32, pchhash: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName", "GetHashFromFileW", 7usize, |this| ((**(this as *const *const ICLRStrongName_Vtbl)).GetHashFromFileW)(this, pwzfilepath, pihashalg, pbhash, cchhash, pchhash), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHashFromHandle(this: *mut core::ffi::c_void, hfile: super::super::Foundation::HANDLE, pihashalg: *mut u32, pbhash: *mut u8, cchhash: u32, pchhash: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName", "GetHashFromHandle", 8usize, |this| ((**(this as *const *const ICLRStrongName_Vtbl)).GetHashFromHandle)(this, hfile, pihashalg, pbhash, cchhash, pchhash), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrongNameCompareAssemblies(this: *mut core::ffi::c_void, pwzassembly1: windows_core::PCWSTR, pwzassembly2: windows_core::PCWSTR, pdwresult: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName", "StrongNameCompareAssemblies", 9usize, |this| ((**(this as *const *const ICLRStrongName_Vtbl)).StrongNameCompareAssemblies)(this, pwzassembly1, pwzassembly2, pdwresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrongNameFreeBuffer(this: *mut core::ffi::c_void, pbmemory: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName", "StrongNameFreeBuffer", 10usize, |this| ((**(this as *const *const ICLRStrongName_Vtbl)).StrongNameFreeBuffer)(this, pbmemory), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrongNameGetBlob(this: *mut core::ffi::c_void, pwzfilepath: windows_core::PCWSTR, pbblob: *mut u8, pcbblob: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName", "StrongNameGetBlob", 11usize, |this| ((**(this as *const *const ICLRStrongName_Vtbl)).StrongNameGetBlob)(this, pwzfilepath, pbblob, pcbblob), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrongNameGetBlobFromImage(this: *mut core::ffi::c_void, pbbase: *const u8, dwlength: u32, pbblob: *mut u8, pcbblob: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName", "StrongNameGetBlobFromImage", 12usize, |this| ((**(this as *const *const ICLRStrongName_Vtbl)).StrongNameGetBlobFromImage)(this, pbbase, dwlength, pbblob, pcbblob), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrongNameGetPublicKey(this: *mut core::ffi::c_void, pwzkeycontainer: windows_core::PCWSTR, pbkeyblob: *const u8, cbkeyblob: u32, ppbpublickeyblob: *mut *mut u8, pcbpublickeyblob: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName", "StrongNameGetPublicKey", 13usize, |this| ((**(this as *const *const ICLRStrongName_Vtbl)).StrongNameGetPublicKey)(this, pwzkeycontainer, pbkeyblob, cbkeyblob, ppbpublickeyblob, pcbpublickeyblob), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrongNameHashSize(this: *mut core::ffi::c_void, ulhashalg: u32, pcbsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName", "StrongNameHashSize", 14usize, |this| ((**(this as *const *const ICLRStrongName_Vtbl)).StrongNameHashSize)(this, ulhashalg, pcbsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrongNameKeyDelete(this: *mut core::ffi::c_void, pwzkeycontainer: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName", "StrongNameKeyDelete", 15usize, |this| ((**(this as *const *const ICLRStrongName_Vtbl)).StrongNameKeyDelete)(this, pwzkeycontainer), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrongNameKeyGen(this: *mut core::ffi::c_void, pwzkeycontainer: windows_core::PCWSTR, dwflags: u32, ppbkeyblob: *mut *mut u8, pcbkeyblob: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName", "StrongNameKeyGen", 16usize, |this| ((**(this as *const *const ICLRStrongName_Vtbl)).StrongNameKeyGen)(this, pwzkeycontainer, dwflags, ppbkeyblob, pcbkeyblob), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrongNameKeyGenEx(this: *mut core::ffi::c_void, pwzkeycontainer: windows_core::PCWSTR, dwflags: u32, dwkeysize: u32, ppbkeyblob: *mut *mut u8, pcbkeyblob: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName", "StrongNameKeyGenEx", 17usize, |this| ((**(this as *const *const ICLRStrongName_Vtbl)).StrongNameKeyGenEx)(this, pwzkeycontainer, dwflags, dwkeysize, ppbkeyblob, pcbkeyblob), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrongNameKeyInstall(this: *mut core::ffi::c_void, pwzkeycontainer: windows_core::PCWSTR, pbkeyblob: *const u8, cbkeyblob: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName", "StrongNameKeyInstall", 18usize, |this| ((**(this as *const *const ICLRStrongName_Vtbl)).StrongNameKeyInstall)(this, pwzkeycontainer, pbkeyblob, cbkeyblob), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrongNameSignatureGeneration(this: *mut core::ffi::c_void, pwzfilepath: windows_core::PCWSTR, pwzkeycontainer: windows_core::PCWSTR, pbkeyblob: *const u8, cbkeyblob: u32, ppbsignatureblob: *mut *mut u8, pcbsignatureblob: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName", "StrongNameSignatureGeneration", 19usize, |this| ((**(this as *const *const ICLRStrongName_Vtbl)).StrongNameSignatureGeneration)(this, pwzfilepath, pwzkeycontainer, pbkeyblob, cbkeyblob, ppbsignatureblob, pcbsignatureblob), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrongNameSignatureGenerationEx(this: *mut core::ffi::c_void, wszfilepath: windows_core::PCWSTR, wszkeycontainer: windows_core::PCWSTR, pbkeyblob: *const u8, cbkeyblob: u32, ppbsignatureblob: *mut *mut u8, pcbsignatureblob: *mut u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName", "StrongNameSignatureGenerationEx", 20usize, |this| ((**(this as *const *const ICLRStrongName_Vtbl)).StrongNameSignatureGenerationEx)(this, wszfilepath, wszkeycontainer, pbkeyblob, cbkeyblob, ppbsignatureblob, pcbsignatureblob, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrongNameSignatureSize(this: *mut core::ffi::c_void, pbpublickeyblob: *const u8, cbpublickeyblob: u32, pcbsize: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName", "StrongNameSignatureSize", 21usize, |this| ((**(this as *const *const ICLRStrongName_Vtbl)).StrongNameSignatureSize)(this, pbpublickeyblob, cbpublickeyblob, pcbsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrongNameSignatureVerification(this: *mut core::ffi::c_void, pwzfilepath: windows_core::PCWSTR, dwinflags: u32, pdwoutflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName", "StrongNameSignatureVerification", 22usize, |this| ((**(this as *const *const ICLRStrongName_Vtbl)).StrongNameSignatureVerification)(this, pwzfilepath, dwinflags, pdwoutflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrongNameSignatureVerificationEx(this: *mut core::ffi::c_void, pwzfilepath: windows_core::PCWSTR, fforceverification: super::super::Foundation::BOOLEAN, pfwasverified: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName", "StrongNameSignatureVerificationEx", 23usize, |this| ((**(this as *const *const ICLRStrongName_Vtbl)).StrongNameSignatureVerificationEx)(this, pwzfilepath, fforceverification, pfwasverified), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrongNameSignatureVerificationFromImage(this: *mut core::ffi::c_void, pbbase: *const u8, dwlength: u32, dwinflags: u32, pdwoutflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName", "StrongNameSignatureVerificationFromImage", 24usize, |this| ((**(this as *const *const ICLRStrongName_Vtbl)).StrongNameSignatureVerificationFromImage)(this, pbbase, dwlength, dwinflags, pdwoutflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrongNameTokenFromAssembly(this: *mut core::ffi::c_void, pwzfilepath: windows_core::PCWSTR, ppbstrongnametoken: *mut *mut u8, pcbstrongnametoken: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName", "StrongNameTokenFromAssembly", 25usize, |this| ((**(this as *const *const ICLRStrongName_Vtbl)).StrongNameTokenFromAssembly)(this, pwzfilepath, ppbstrongnametoken, pcbstrongnametoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrongNameTokenFromAssemblyEx(this: *mut core::ffi::c_void, pwzfilepath: windows_core::PCWSTR, ppbstrongnametoken: *mut *mut u8, pcbstrongnametoken: *mut u32, ppbpublickeyblob: *mut *mut u8, pcbpublickeyblob: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName", "StrongNameTokenFromAssemblyEx", 26usize, |this| ((**(this as *const *const ICLRStrongName_Vtbl)).StrongNameTokenFromAssemblyEx)(this, pwzfilepath, ppbstrongnametoken, pcbstrongnametoken, ppbpublickeyblob, pcbpublickeyblob), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrongNameTokenFromPublicKey(this: *mut core::ffi::c_void, pbpublickeyblob: *const u8, cbpublickeyblob: u32, ppbstrongnametoken: *mut *mut u8, pcbstrongnametoken: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName", "StrongNameTokenFromPublicKey", 27usize, |this| ((**(this as *const *const ICLRStrongName_Vtbl)).StrongNameTokenFromPublicKey)(this, pbpublickeyblob, cbpublickeyblob, ppbstrongnametoken, pcbstrongnametoken), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetHashFromAssemblyFile,
+            GetHashFromAssemblyFileW,
+            GetHashFromBlob,
+            GetHashFromFile,
+            GetHashFromFileW,
+            GetHashFromHandle,
+            StrongNameCompareAssemblies,
+            StrongNameFreeBuffer,
+            StrongNameGetBlob,
+            StrongNameGetBlobFromImage,
+            StrongNameGetPublicKey,
+            StrongNameHashSize,
+            StrongNameKeyDelete,
+            StrongNameKeyGen,
+            StrongNameKeyGenEx,
+            StrongNameKeyInstall,
+            StrongNameSignatureGeneration,
+            StrongNameSignatureGenerationEx,
+            StrongNameSignatureSize,
+            StrongNameSignatureVerification,
+            StrongNameSignatureVerificationEx,
+            StrongNameSignatureVerificationFromImage,
+            StrongNameTokenFromAssembly,
+            StrongNameTokenFromAssemblyEx,
+            StrongNameTokenFromPublicKey,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRStrongName as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICLRStrongName2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StrongNameGetPublicKeyEx(this: *mut core::ffi::c_void, pwzkeycontainer: windows_core::PCWSTR, pbkeyblob: *const u8, cbkeyblob: u32, ppbpublickeyblob: *mut *mut u8, pcbpublickeyblob: *mut u32, uhashalgid: u32, ureserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName2", "StrongNameGetPublicKeyEx", 3usize, |this| ((**(this as *const *const ICLRStrongName2_Vtbl)).StrongNameGetPublicKeyEx)(this, pwzkeycontainer, pbkeyblob, cbkeyblob, ppbpublickeyblob, pcbpublickeyblob, uhashalgid, ureserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrongNameSignatureVerificationEx2(this: *mut core::ffi::c_void, wszfilepath: windows_core::PCWSTR, fforceverification: super::super::Foundation::BOOLEAN, pbecmapublickey: *const u8, cbecmapublickey: u32, pfwasverified: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName2", "StrongNameSignatureVerificationEx2", 4usize, |this| ((**(this as *const *const ICLRStrongName2_Vtbl)).StrongNameSignatureVerificationEx2)(this, wszfilepath, fforceverification, pbecmapublickey, cbecmapublickey, pfwasverified), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StrongNameGetPublicKeyEx, StrongNameSignatureVerificationEx2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRStrongName2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICLRStrongName3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StrongNameDigestGenerate(this: *mut core::ffi::c_void, wszfilepath: windows_core::PCWSTR, ppbdigestblob: *mut *mut u8, pcbdigestblob: *mut u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName3", "StrongNameDigestGenerate", 3usize, |this| ((**(this as *const *const ICLRStrongName3_Vtbl)).StrongNameDigestGenerate)(this, wszfilepath, ppbdigestblob, pcbdigestblob, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrongNameDigestSign(this: *mut core::ffi::c_void, wszkeycontainer: windows_core::PCWSTR, pbkeyblob: *const u8, cbkeyblob: u32, pbdigestblob: *const u8, cbdigestblob: u32, hashalgid: u32, ppbsignatureblob: *mut *mut u8, pcbsignatureblob: *mut u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName3", "StrongNameDigestSign", 4usize, |this| ((**(this as *const *const ICLRStrongName3_Vtbl)).StrongNameDigestSign)(this, wszkeycontainer, pbkeyblob, cbkeyblob, pbdigestblob, cbdigestblob, hashalgid, ppbsignatureblob, pcbsignatureblob, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn StrongNameDigestEmbed(this: *mut core::ffi::c_void, wszfilepath: windows_core::PCWSTR, pbsignatureblob: *const u8, cbsignatureblob: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRStrongName3", "StrongNameDigestEmbed", 5usize, |this| ((**(this as *const *const ICLRStrongName3_Vtbl)).StrongNameDigestEmbed)(this, wszfilepath, pbsignatureblob, cbsignatureblob), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StrongNameDigestGenerate,
+            StrongNameDigestSign,
+            StrongNameDigestEmbed,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRStrongName3 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICLRSyncManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMonitorOwner(this: *mut core::ffi::c_void, cookie: usize, ppownerhosttask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRSyncManager", "GetMonitorOwner", 3usize, |this| ((**(this as *const *const ICLRSyncManager_Vtbl)).GetMonitorOwner)(this, cookie, ppownerhosttask), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRWLockOwnerIterator(this: *mut core::ffi::c_void, cookie: usize, piterator: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRSyncManager", "CreateRWLockOwnerIterator", 4usize, |this| ((**(this as *const *const ICLRSyncManager_Vtbl)).CreateRWLockOwnerIterator)(this, cookie, piterator), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRWLockOwnerNext(this: *mut core::ffi::c_void, iterator: usize, ppownerhosttask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRSyncManager", "GetRWLockOwnerNext", 5usize, |this| ((**(this as *const *const ICLRSyncManager_Vtbl)).GetRWLockOwnerNext)(this, iterator, ppownerhosttask), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteRWLockOwnerIterator(this: *mut core::ffi::c_void, iterator: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRSyncManager", "DeleteRWLockOwnerIterator", 6usize, |this| ((**(this as *const *const ICLRSyncManager_Vtbl)).DeleteRWLockOwnerIterator)(this, iterator), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetMonitorOwner,
+            CreateRWLockOwnerIterator,
+            GetRWLockOwnerNext,
+            DeleteRWLockOwnerIterator,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRSyncManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICLRTask_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SwitchIn(this: *mut core::ffi::c_void, threadhandle: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRTask", "SwitchIn", 3usize, |this| ((**(this as *const *const ICLRTask_Vtbl)).SwitchIn)(this, threadhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SwitchOut(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRTask", "SwitchOut", 4usize, |this| ((**(this as *const *const ICLRTask_Vtbl)).SwitchOut)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMemStats(this: *mut core::ffi::c_void, memusage: *mut COR_GC_THREAD_STATS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRTask", "GetMemStats", 5usize, |this| ((**(this as *const *const ICLRTask_Vtbl)).GetMemStats)(this, memusage), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void, ffull: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRTask", "Reset", 6usize, |this| ((**(this as *const *const ICLRTask_Vtbl)).Reset)(this, ffull), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExitTask(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRTask", "ExitTask", 7usize, |this| ((**(this as *const *const ICLRTask_Vtbl)).ExitTask)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Abort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRTask", "Abort", 8usize, |this| ((**(this as *const *const ICLRTask_Vtbl)).Abort)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RudeAbort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRTask", "RudeAbort", 9usize, |this| ((**(this as *const *const ICLRTask_Vtbl)).RudeAbort)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn NeedsPriorityScheduling(this: *mut core::ffi::c_void, pbneedspriorityscheduling: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRTask", "NeedsPriorityScheduling", 10usize, |this| ((**(this as *const *const ICLRTask_Vtbl)).NeedsPriorityScheduling)(this, pbneedspriorityscheduling), |result| Some(*result))
+        }
+        unsafe extern "system" fn YieldTask(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRTask", "YieldTask", 11usize, |this| ((**(this as *const *const ICLRTask_Vtbl)).YieldTask)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocksHeld(this: *mut core::ffi::c_void, plockcount: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRTask", "LocksHeld", 12usize, |this| ((**(this as *const *const ICLRTask_Vtbl)).LocksHeld)(this, plockcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTaskIdentifier(this: *mut core::ffi::c_void, asked: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRTask", "SetTaskIdentifier", 13usize, |this| ((**(this as *const *const ICLRTask_Vtbl)).SetTaskIdentifier)(this, asked), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SwitchIn,
+            SwitchOut,
+            GetMemStats,
+            Reset,
+            ExitTask,
+            Abort,
+            RudeAbort,
+            NeedsPriorityScheduling,
+            YieldTask,
+            LocksHeld,
+            SetTaskIdentifier,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRTask as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICLRTask2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BeginPreventAsyncAbort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRTask2", "BeginPreventAsyncAbort", 14usize, |this| ((**(this as *const *const ICLRTask2_Vtbl)).BeginPreventAsyncAbort)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndPreventAsyncAbort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRTask2", "EndPreventAsyncAbort", 15usize, |this| ((**(this as *const *const ICLRTask2_Vtbl)).EndPreventAsyncAbort)(this), |result| Some(*result))
+        }
+        Self { base__: <ICLRTask_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BeginPreventAsyncAbort, EndPreventAsyncAbort }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRTask2 as windows_core::Interface>::IID || <ICLRTask_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICLRTaskManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateTask(this: *mut core::ffi::c_void, ptask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRTaskManager", "CreateTask", 3usize, |this| ((**(this as *const *const ICLRTaskManager_Vtbl)).CreateTask)(this, ptask), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentTask(this: *mut core::ffi::c_void, ptask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRTaskManager", "GetCurrentTask", 4usize, |this| ((**(this as *const *const ICLRTaskManager_Vtbl)).GetCurrentTask)(this, ptask), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUILocale(this: *mut core::ffi::c_void, lcid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRTaskManager", "SetUILocale", 5usize, |this| ((**(this as *const *const ICLRTaskManager_Vtbl)).SetUILocale)(this, lcid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocale(this: *mut core::ffi::c_void, lcid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRTaskManager", "SetLocale", 6usize, |this| ((**(this as *const *const ICLRTaskManager_Vtbl)).SetLocale)(this, lcid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentTaskType(this: *mut core::ffi::c_void, ptasktype: *mut ETaskType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICLRTaskManager", "GetCurrentTaskType", 7usize, |this| ((**(this as *const *const ICLRTaskManager_Vtbl)).GetCurrentTaskType)(this, ptasktype), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateTask,
+            GetCurrentTask,
+            SetUILocale,
+            SetLocale,
+            GetCurrentTaskType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICLRTaskManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICatalogServices_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Autodone(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalogServices", "Autodone", 3usize, |this| ((**(this as *const *const ICatalogServices_Vtbl)).Autodone)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotAutodone(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalogServices", "NotAutodone", 4usize, |this| ((**(this as *const *const ICatalogServices_Vtbl)).NotAutodone)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Autodone, NotAutodone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICatalogServices as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICorConfiguration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetGCThreadControl(this: *mut core::ffi::c_void, pgcthreadcontrol: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorConfiguration", "SetGCThreadControl", 3usize, |this| ((**(this as *const *const ICorConfiguration_Vtbl)).SetGCThreadControl)(this, pgcthreadcontrol), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGCHostControl(this: *mut core::ffi::c_void, pgchostcontrol: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorConfiguration", "SetGCHostControl", 4usize, |this| ((**(this as *const *const ICorConfiguration_Vtbl)).SetGCHostControl)(this, pgchostcontrol), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDebuggerThreadControl(this: *mut core::ffi::c_void, pdebuggerthreadcontrol: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorConfiguration", "SetDebuggerThreadControl", 5usize, |this| ((**(this as *const *const ICorConfiguration_Vtbl)).SetDebuggerThreadControl)(this, pdebuggerthreadcontrol), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDebuggerSpecialThread(this: *mut core::ffi::c_void, dwspecialthreadid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorConfiguration", "AddDebuggerSpecialThread", 6usize, |this| ((**(this as *const *const ICorConfiguration_Vtbl)).AddDebuggerSpecialThread)(this, dwspecialthreadid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetGCThreadControl,
+            SetGCHostControl,
+            SetDebuggerThreadControl,
+            AddDebuggerSpecialThread,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorConfiguration as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICorRuntimeHost_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateLogicalThreadState(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorRuntimeHost", "CreateLogicalThreadState", 3usize, |this| ((**(this as *const *const ICorRuntimeHost_Vtbl)).CreateLogicalThreadState)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteLogicalThreadState(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorRuntimeHost", "DeleteLogicalThreadState", 4usize, |this| ((**(this as *const *const ICorRuntimeHost_Vtbl)).DeleteLogicalThreadState)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SwitchInLogicalThreadState(this: *mut core::ffi::c_void, pfibercookie: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorRuntimeHost", "SwitchInLogicalThreadState", 5usize, |this| ((**(this as *const *const ICorRuntimeHost_Vtbl)).SwitchInLogicalThreadState)(this, pfibercookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn SwitchOutLogicalThreadState(this: *mut core::ffi::c_void, pfibercookie: *mut *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorRuntimeHost", "SwitchOutLogicalThreadState", 6usize, |this| ((**(this as *const *const ICorRuntimeHost_Vtbl)).SwitchOutLogicalThreadState)(this, pfibercookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocksHeldByLogicalThread(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorRuntimeHost", "LocksHeldByLogicalThread", 7usize, |this| ((**(this as *const *const ICorRuntimeHost_Vtbl)).LocksHeldByLogicalThread)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn MapFile(this: *mut core::ffi::c_void, hfile: super::super::Foundation::HANDLE, hmapaddress: *mut super::super::Foundation::HMODULE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorRuntimeHost", "MapFile", 8usize, |this| ((**(this as *const *const ICorRuntimeHost_Vtbl)).MapFile)(this, hfile, hmapaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConfiguration(this: *mut core::ffi::c_void, pconfiguration: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorRuntimeHost", "GetConfiguration", 9usize, |this| ((**(this as *const *const ICorRuntimeHost_Vtbl)).GetConfiguration)(this, pconfiguration), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorRuntimeHost", "Start", 10usize, |this| ((**(this as *const *const ICorRuntimeHost_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorRuntimeHost", "Stop", 11usize, |this| ((**(this as *const *const ICorRuntimeHost_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDomain(this: *mut core::ffi::c_void, pwzfriendlyname: windows_core::PCWSTR, pidentityarray: *mut core::ffi::c_void, pappdomain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorRuntimeHost", "CreateDomain", 12usize, |this| ((**(this as *const *const ICorRuntimeHost_Vtbl)).CreateDomain)(this, pwzfriendlyname, pidentityarray, pappdomain), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultDomain(this: *mut core::ffi::c_void, pappdomain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorRuntimeHost", "GetDefaultDomain", 13usize, |this| ((**(this as *const *const ICorRuntimeHost_Vtbl)).GetDefaultDomain)(this, pappdomain), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumDomains(this: *mut core::ffi::c_void, henum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorRuntimeHost", "EnumDomains", 14usize, |this| ((**(this as *const *const ICorRuntimeHost_Vtbl)).EnumDomains)(this, henum), |result| Some(*result))
+        }
+        unsafe extern "system" fn NextDomain(this: *mut core::ffi::c_void, henum: *const core::ffi::c_void, pappdomain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorRuntimeHost", "NextDomain", 15usize, |this| ((**(this as *const *const ICorRuntimeHost_Vtbl)).NextDomain)(this, henum, pappdomain), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseEnum(this: *mut core::ffi::c_void, henum: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorRuntimeHost", "CloseEnum", 16usize, |this| ((**(this as *const *const ICorRuntimeHost_Vtbl)).CloseEnum)(this, henum), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDomainEx(this: *mut core::ffi::c_void, pwzfriendlyname: windows_core::PCWSTR, psetup: *mut core::ffi::c_void, pevidence: *mut core::ffi::c_void, pappdomain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorRuntimeHost", "CreateDomainEx", 17usize, |this| ((**(this as *const *const ICorRuntimeHost_Vtbl)).CreateDomainEx)(this, pwzfriendlyname, psetup, pevidence, pappdomain), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDomainSetup(this: *mut core::ffi::c_void, pappdomainsetup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorRuntimeHost", "CreateDomainSetup", 18usize, |this| ((**(this as *const *const ICorRuntimeHost_Vtbl)).CreateDomainSetup)(this, pappdomainsetup), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateEvidence(this: *mut core::ffi::c_void, pevidence: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorRuntimeHost", "CreateEvidence", 19usize, |this| ((**(this as *const *const ICorRuntimeHost_Vtbl)).CreateEvidence)(this, pevidence), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnloadDomain(this: *mut core::ffi::c_void, pappdomain: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorRuntimeHost", "UnloadDomain", 20usize, |this| ((**(this as *const *const ICorRuntimeHost_Vtbl)).UnloadDomain)(this, pappdomain), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentDomain(this: *mut core::ffi::c_void, pappdomain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorRuntimeHost", "CurrentDomain", 21usize, |this| ((**(this as *const *const ICorRuntimeHost_Vtbl)).CurrentDomain)(this, pappdomain), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateLogicalThreadState,
+            DeleteLogicalThreadState,
+            SwitchInLogicalThreadState,
+            SwitchOutLogicalThreadState,
+            LocksHeldByLogicalThread,
+            MapFile,
+            GetConfiguration,
+            Start,
+            Stop,
+            CreateDomain,
+            GetDefaultDomain,
+            EnumDomains,
+            NextDomain,
+            CloseEnum,
+            CreateDomainEx,
+            CreateDomainSetup,
+            CreateEvidence,
+            UnloadDomain,
+            CurrentDomain,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorRuntimeHost as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_IO", feature = "Win32_System_Threading"))]
+impl windows_core::imp::ProxyVtable for ICorThreadpool_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Threading")]
+        unsafe extern "system" fn CorRegisterWaitForSingleObject(this: *mut core::ffi::c_void, phnewwaitobject: *const super::super::Foundation::HANDLE, hwaitobject: super::super::Foundation::HANDLE, callback: super::Threading::WAITORTIMERCALLBACK, context: *const core::ffi::c_void, timeout: u32, executeonlyonce: super::super::Foundation::BOOL, result: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorThreadpool", "CorRegisterWaitForSingleObject", 3usize, |this| ((**(this as *const *const ICorThreadpool_Vtbl)).CorRegisterWaitForSingleObject)(this, phnewwaitobject, hwaitobject, callback, context, timeout, executeonlyonce, result), |result| Some(*result))
+        }
+        unsafe extern "system" fn CorUnregisterWait(this: *mut core::ffi::c_void, hwaitobject: super::super::Foundation::HANDLE, completionevent: super::super::Foundation::HANDLE, result: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorThreadpool", "CorUnregisterWait", 4usize, |this| ((**(this as *const *const ICorThreadpool_Vtbl)).CorUnregisterWait)(this, hwaitobject, completionevent, result), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Threading")]
+        unsafe extern "system" fn CorQueueUserWorkItem(this: *mut core::ffi::c_void, function: super::Threading::LPTHREAD_START_ROUTINE, context: *const core::ffi::c_void, executeonlyonce: super::super::Foundation::BOOL, result: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorThreadpool", "CorQueueUserWorkItem", 5usize, |this| ((**(this as *const *const ICorThreadpool_Vtbl)).CorQueueUserWorkItem)(this, function, context, executeonlyonce, result), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Threading")]
+        unsafe extern "system" fn CorCreateTimer(this: *mut core::ffi::c_void, phnewtimer: *const super::super::Foundation::HANDLE, callback: super::Threading::WAITORTIMERCALLBACK, parameter: *const core::ffi::c_void, duetime: u32, period: u32, result: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorThreadpool", "CorCreateTimer", 6usize, |this| ((**(this as *const *const ICorThreadpool_Vtbl)).CorCreateTimer)(this, phnewtimer, callback, parameter, duetime, period, result), |result| Some(*result))
+        }
+        unsafe extern "system" fn CorChangeTimer(this: *mut core::ffi::c_void, timer: super::super::Foundation::HANDLE, duetime: u32, period: u32, result: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorThreadpool", "CorChangeTimer", 7usize, |this| ((**(this as *const *const ICorThreadpool_Vtbl)).CorChangeTimer)(this, timer, duetime, period, result), |result| Some(*result))
+        }
+        unsafe extern "system" fn CorDeleteTimer(this: *mut core::ffi::c_void, timer: super::super::Foundation::HANDLE, completionevent: super::super::Foundation::HANDLE, result: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorThreadpool", "CorDeleteTimer", 8usize, |this| ((**(this as *const *const ICorThreadpool_Vtbl)).CorDeleteTimer)(this, timer, completionevent, result), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_IO")]
+        unsafe extern "system" fn CorBindIoCompletionCallback(this: *mut core::ffi::c_void, filehandle: super::super::Foundation::HANDLE, callback: super::IO::LPOVERLAPPED_COMPLETION_ROUTINE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorThreadpool", "CorBindIoCompletionCallback", 9usize, |this| ((**(this as *const *const ICorThreadpool_Vtbl)).CorBindIoCompletionCallback)(this, filehandle, callback), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Threading")]
+        unsafe extern "system" fn CorCallOrQueueUserWorkItem(this: *mut core::ffi::c_void, function: super::Threading::LPTHREAD_START_ROUTINE, context: *const core::ffi::c_void, result: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorThreadpool", "CorCallOrQueueUserWorkItem", 10usize, |this| ((**(this as *const *const ICorThreadpool_Vtbl)).CorCallOrQueueUserWorkItem)(this, function, context, result), |result| Some(*result))
+        }
+        unsafe extern "system" fn CorSetMaxThreads(this: *mut core::ffi::c_void, maxworkerthreads: u32, maxiocompletionthreads: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorThreadpool", "CorSetMaxThreads", 11usize, |this| ((**(this as *const *const ICorThreadpool_Vtbl)).CorSetMaxThreads)(this, maxworkerthreads, maxiocompletionthreads), |result| Some(*result))
+        }
+        unsafe extern "system" fn CorGetMaxThreads(this: *mut core::ffi::c_void, maxworkerthreads: *mut u32, maxiocompletionthreads: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorThreadpool", "CorGetMaxThreads", 12usize, |this| ((**(this as *const *const ICorThreadpool_Vtbl)).CorGetMaxThreads)(this, maxworkerthreads, maxiocompletionthreads), |result| Some(*result))
+        }
+        unsafe extern "system" fn CorGetAvailableThreads(this: *mut core::ffi::c_void, availableworkerthreads: *mut u32, availableiocompletionthreads: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorThreadpool", "CorGetAvailableThreads", 13usize, |this| ((**(this as *const *const ICorThreadpool_Vtbl)).CorGetAvailableThreads)(this, availableworkerthreads, availableiocompletionthreads), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Threading")]
+            CorRegisterWaitForSingleObject,
+            #[cfg(not(feature = "Win32_System_Threading"))]
+            CorRegisterWaitForSingleObject: 0,
+            CorUnregisterWait,
+            #[cfg(feature = "Win32_System_Threading")]
+            CorQueueUserWorkItem,
+            #[cfg(not(feature = "Win32_System_Threading"))]
+            CorQueueUserWorkItem: 0,
+            #[cfg(feature = "Win32_System_Threading")]
+            CorCreateTimer,
+            #[cfg(not(feature = "Win32_System_Threading"))]
+            CorCreateTimer: 0,
+            CorChangeTimer,
+            CorDeleteTimer,
+            #[cfg(feature = "Win32_System_IO")]
+            CorBindIoCompletionCallback,
+            #[cfg(not(feature = "Win32_System_IO"))]
+            CorBindIoCompletionCallback: 0,
+            #[cfg(feature = "Win32_System_Threading")]
+            CorCallOrQueueUserWorkItem,
+            #[cfg(not(feature = "Win32_System_Threading"))]
+            CorCallOrQueueUserWorkItem: 0,
+            CorSetMaxThreads,
+            CorGetMaxThreads,
+            CorGetAvailableThreads,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorThreadpool as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebuggerInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsDebuggerAttached(this: *mut core::ffi::c_void, pbattached: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebuggerInfo", "IsDebuggerAttached", 3usize, |this| ((**(this as *const *const IDebuggerInfo_Vtbl)).IsDebuggerAttached)(this, pbattached), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsDebuggerAttached }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebuggerInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebuggerThreadControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ThreadIsBlockingForDebugger(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebuggerThreadControl", "ThreadIsBlockingForDebugger", 3usize, |this| ((**(this as *const *const IDebuggerThreadControl_Vtbl)).ThreadIsBlockingForDebugger)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseAllRuntimeThreads(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebuggerThreadControl", "ReleaseAllRuntimeThreads", 4usize, |this| ((**(this as *const *const IDebuggerThreadControl_Vtbl)).ReleaseAllRuntimeThreads)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartBlockingForDebugger(this: *mut core::ffi::c_void, dwunused: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebuggerThreadControl", "StartBlockingForDebugger", 5usize, |this| ((**(this as *const *const IDebuggerThreadControl_Vtbl)).StartBlockingForDebugger)(this, dwunused), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ThreadIsBlockingForDebugger,
+            ReleaseAllRuntimeThreads,
+            StartBlockingForDebugger,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebuggerThreadControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGCHost_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetGCStartupLimits(this: *mut core::ffi::c_void, segmentsize: u32, maxgen0size: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGCHost", "SetGCStartupLimits", 3usize, |this| ((**(this as *const *const IGCHost_Vtbl)).SetGCStartupLimits)(this, segmentsize, maxgen0size), |result| Some(*result))
+        }
+        unsafe extern "system" fn Collect(this: *mut core::ffi::c_void, generation: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGCHost", "Collect", 4usize, |this| ((**(this as *const *const IGCHost_Vtbl)).Collect)(this, generation), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStats(this: *mut core::ffi::c_void, pstats: *mut COR_GC_STATS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGCHost", "GetStats", 5usize, |this| ((**(this as *const *const IGCHost_Vtbl)).GetStats)(this, pstats), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetThreadStats(this: *mut core::ffi::c_void, pfibercookie: *const u32, pstats: *mut COR_GC_THREAD_STATS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGCHost", "GetThreadStats", 6usize, |this| ((**(this as *const *const IGCHost_Vtbl)).GetThreadStats)(this, pfibercookie, pstats), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVirtualMemLimit(this: *mut core::ffi::c_void, sztmaxvirtualmemmb: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGCHost", "SetVirtualMemLimit", 7usize, |this| ((**(this as *const *const IGCHost_Vtbl)).SetVirtualMemLimit)(this, sztmaxvirtualmemmb), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetGCStartupLimits,
+            Collect,
+            GetStats,
+            GetThreadStats,
+            SetVirtualMemLimit,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGCHost as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGCHost2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetGCStartupLimitsEx(this: *mut core::ffi::c_void, segmentsize: usize, maxgen0size: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGCHost2", "SetGCStartupLimitsEx", 8usize, |this| ((**(this as *const *const IGCHost2_Vtbl)).SetGCStartupLimitsEx)(this, segmentsize, maxgen0size), |result| Some(*result))
+        }
+        Self { base__: <IGCHost_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetGCStartupLimitsEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGCHost2 as windows_core::Interface>::IID || <IGCHost_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGCHostControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestVirtualMemLimit(this: *mut core::ffi::c_void, sztmaxvirtualmemmb: usize, psztnewmaxvirtualmemmb: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGCHostControl", "RequestVirtualMemLimit", 3usize, |this| ((**(this as *const *const IGCHostControl_Vtbl)).RequestVirtualMemLimit)(this, sztmaxvirtualmemmb, psztnewmaxvirtualmemmb), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestVirtualMemLimit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGCHostControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGCThreadControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ThreadIsBlockingForSuspension(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGCThreadControl", "ThreadIsBlockingForSuspension", 3usize, |this| ((**(this as *const *const IGCThreadControl_Vtbl)).ThreadIsBlockingForSuspension)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuspensionStarting(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGCThreadControl", "SuspensionStarting", 4usize, |this| ((**(this as *const *const IGCThreadControl_Vtbl)).SuspensionStarting)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuspensionEnding(this: *mut core::ffi::c_void, generation: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGCThreadControl", "SuspensionEnding", 5usize, |this| ((**(this as *const *const IGCThreadControl_Vtbl)).SuspensionEnding)(this, generation), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ThreadIsBlockingForSuspension,
+            SuspensionStarting,
+            SuspensionEnding,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGCThreadControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHostAssemblyManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNonHostStoreAssemblies(this: *mut core::ffi::c_void, ppreferencelist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostAssemblyManager", "GetNonHostStoreAssemblies", 3usize, |this| ((**(this as *const *const IHostAssemblyManager_Vtbl)).GetNonHostStoreAssemblies)(this, ppreferencelist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAssemblyStore(this: *mut core::ffi::c_void, ppassemblystore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostAssemblyManager", "GetAssemblyStore", 4usize, |this| ((**(this as *const *const IHostAssemblyManager_Vtbl)).GetAssemblyStore)(this, ppassemblystore), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetNonHostStoreAssemblies, GetAssemblyStore }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHostAssemblyManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IHostAssemblyStore_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ProvideAssembly(this: *mut core::ffi::c_void, pbindinfo: *const AssemblyBindInfo, passemblyid: *mut u64, pcontext: *mut u64, ppstmassemblyimage: *mut *mut core::ffi::c_void, ppstmpdb: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostAssemblyStore", "ProvideAssembly", 3usize, |this| ((**(this as *const *const IHostAssemblyStore_Vtbl)).ProvideAssembly)(this, pbindinfo, passemblyid, pcontext, ppstmassemblyimage, ppstmpdb), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ProvideModule(this: *mut core::ffi::c_void, pbindinfo: *const ModuleBindInfo, pdwmoduleid: *mut u32, ppstmmoduleimage: *mut *mut core::ffi::c_void, ppstmpdb: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostAssemblyStore", "ProvideModule", 4usize, |this| ((**(this as *const *const IHostAssemblyStore_Vtbl)).ProvideModule)(this, pbindinfo, pdwmoduleid, ppstmmoduleimage, ppstmpdb), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            ProvideAssembly,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ProvideAssembly: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ProvideModule,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ProvideModule: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHostAssemblyStore as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHostAutoEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Wait(this: *mut core::ffi::c_void, dwmilliseconds: u32, option: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostAutoEvent", "Wait", 3usize, |this| ((**(this as *const *const IHostAutoEvent_Vtbl)).Wait)(this, dwmilliseconds, option), |result| Some(*result))
+        }
+        unsafe extern "system" fn Set(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostAutoEvent", "Set", 4usize, |this| ((**(this as *const *const IHostAutoEvent_Vtbl)).Set)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Wait, Set }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHostAutoEvent as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHostControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetHostManager(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostControl", "GetHostManager", 3usize, |this| ((**(this as *const *const IHostControl_Vtbl)).GetHostManager)(this, riid, ppobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAppDomainManager(this: *mut core::ffi::c_void, dwappdomainid: u32, punkappdomainmanager: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostControl", "SetAppDomainManager", 4usize, |this| ((**(this as *const *const IHostControl_Vtbl)).SetAppDomainManager)(this, dwappdomainid, punkappdomainmanager), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetHostManager, SetAppDomainManager }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHostControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHostCrst_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Enter(this: *mut core::ffi::c_void, option: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostCrst", "Enter", 3usize, |this| ((**(this as *const *const IHostCrst_Vtbl)).Enter)(this, option), |result| Some(*result))
+        }
+        unsafe extern "system" fn Leave(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostCrst", "Leave", 4usize, |this| ((**(this as *const *const IHostCrst_Vtbl)).Leave)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn TryEnter(this: *mut core::ffi::c_void, option: u32, pbsucceeded: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostCrst", "TryEnter", 5usize, |this| ((**(this as *const *const IHostCrst_Vtbl)).TryEnter)(this, option, pbsucceeded), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSpinCount(this: *mut core::ffi::c_void, dwspincount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostCrst", "SetSpinCount", 6usize, |this| ((**(this as *const *const IHostCrst_Vtbl)).SetSpinCount)(this, dwspincount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Enter, Leave, TryEnter, SetSpinCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHostCrst as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHostGCManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ThreadIsBlockingForSuspension(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostGCManager", "ThreadIsBlockingForSuspension", 3usize, |this| ((**(this as *const *const IHostGCManager_Vtbl)).ThreadIsBlockingForSuspension)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuspensionStarting(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostGCManager", "SuspensionStarting", 4usize, |this| ((**(this as *const *const IHostGCManager_Vtbl)).SuspensionStarting)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuspensionEnding(this: *mut core::ffi::c_void, generation: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostGCManager", "SuspensionEnding", 5usize, |this| ((**(this as *const *const IHostGCManager_Vtbl)).SuspensionEnding)(this, generation), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ThreadIsBlockingForSuspension,
+            SuspensionStarting,
+            SuspensionEnding,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHostGCManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHostIoCompletionManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateIoCompletionPort(this: *mut core::ffi::c_void, phport: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostIoCompletionManager", "CreateIoCompletionPort", 3usize, |this| ((**(this as *const *const IHostIoCompletionManager_Vtbl)).CreateIoCompletionPort)(this, phport), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseIoCompletionPort(this: *mut core::ffi::c_void, hport: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostIoCompletionManager", "CloseIoCompletionPort", 4usize, |this| ((**(this as *const *const IHostIoCompletionManager_Vtbl)).CloseIoCompletionPort)(this, hport), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxThreads(this: *mut core::ffi::c_void, dwmaxiocompletionthreads: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostIoCompletionManager", "SetMaxThreads", 5usize, |this| ((**(this as *const *const IHostIoCompletionManager_Vtbl)).SetMaxThreads)(this, dwmaxiocompletionthreads), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxThreads(this: *mut core::ffi::c_void, pdwmaxiocompletionthreads: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostIoCompletionManager", "GetMaxThreads", 6usize, |this| ((**(this as *const *const IHostIoCompletionManager_Vtbl)).GetMaxThreads)(this, pdwmaxiocompletionthreads), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAvailableThreads(this: *mut core::ffi::c_void, pdwavailableiocompletionthreads: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostIoCompletionManager", "GetAvailableThreads", 7usize, |this| ((**(this as *const *const IHostIoCompletionManager_Vtbl)).GetAvailableThreads)(this, pdwavailableiocompletionthreads), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHostOverlappedSize(this: *mut core::ffi::c_void, pcbsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostIoCompletionManager", "GetHostOverlappedSize", 8usize, |this| ((**(this as *const *const IHostIoCompletionManager_Vtbl)).GetHostOverlappedSize)(this, pcbsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCLRIoCompletionManager(this: *mut core::ffi::c_void, pmanager: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostIoCompletionManager", "SetCLRIoCompletionManager", 9usize, |this| ((**(this as *const *const IHostIoCompletionManager_Vtbl)).SetCLRIoCompletionManager)(this, pmanager), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeHostOverlapped(this: *mut core::ffi::c_void, pvoverlapped: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostIoCompletionManager", "InitializeHostOverlapped", 10usize, |this| ((**(this as *const *const IHostIoCompletionManager_Vtbl)).InitializeHostOverlapped)(this, pvoverlapped), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bind(this: *mut core::ffi::c_void, hport: super::super::Foundation::HANDLE, hhandle: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostIoCompletionManager", "Bind", 11usize, |this| ((**(this as *const *const IHostIoCompletionManager_Vtbl)).Bind)(this, hport, hhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinThreads(this: *mut core::ffi::c_void, dwminiocompletionthreads: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostIoCompletionManager", "SetMinThreads", 12usize, |this| ((**(this as *const *const IHostIoCompletionManager_Vtbl)).SetMinThreads)(this, dwminiocompletionthreads), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMinThreads(this: *mut core::ffi::c_void, pdwminiocompletionthreads: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostIoCompletionManager", "GetMinThreads", 13usize, |this| ((**(this as *const *const IHostIoCompletionManager_Vtbl)).GetMinThreads)(this, pdwminiocompletionthreads), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateIoCompletionPort,
+            CloseIoCompletionPort,
+            SetMaxThreads,
+            GetMaxThreads,
+            GetAvailableThreads,
+            GetHostOverlappedSize,
+            SetCLRIoCompletionManager,
+            InitializeHostOverlapped,
+            Bind,
+            SetMinThreads,
+            GetMinThreads,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHostIoCompletionManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHostMalloc_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Alloc(this: *mut core::ffi::c_void, cbsize: usize, ecriticallevel: EMemoryCriticalLevel, ppmem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostMalloc", "Alloc", 3usize, |this| ((**(this as *const *const IHostMalloc_Vtbl)).Alloc)(this, cbsize, ecriticallevel, ppmem), |result| Some(*result))
+        }
+        unsafe extern "system" fn DebugAlloc(this: *mut core::ffi::c_void, cbsize: usize, ecriticallevel: EMemoryCriticalLevel, pszfilename: *const u8, ilineno: i32, ppmem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostMalloc", "DebugAlloc", 4usize, |this| ((**(this as *const *const IHostMalloc_Vtbl)).DebugAlloc)(this, cbsize, ecriticallevel, pszfilename, ilineno, ppmem), |result| Some(*result))
+        }
+        unsafe extern "system" fn Free(this: *mut core::ffi::c_void, pmem: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostMalloc", "Free", 5usize, |this| ((**(this as *const *const IHostMalloc_Vtbl)).Free)(this, pmem), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Alloc, DebugAlloc, Free }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHostMalloc as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHostManualEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Wait(this: *mut core::ffi::c_void, dwmilliseconds: u32, option: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostManualEvent", "Wait", 3usize, |this| ((**(this as *const *const IHostManualEvent_Vtbl)).Wait)(this, dwmilliseconds, option), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostManualEvent", "Reset", 4usize, |this| ((**(this as *const *const IHostManualEvent_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Set(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostManualEvent", "Set", 5usize, |this| ((**(this as *const *const IHostManualEvent_Vtbl)).Set)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Wait, Reset, Set }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHostManualEvent as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHostMemoryManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateMalloc(this: *mut core::ffi::c_void, dwmalloctype: u32, ppmalloc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostMemoryManager", "CreateMalloc", 3usize, |this| ((**(this as *const *const IHostMemoryManager_Vtbl)).CreateMalloc)(this, dwmalloctype, ppmalloc), |result| Some(*result))
+        }
+        unsafe extern "system" fn VirtualAlloc(this: *mut core::ffi::c_void, paddress: *const core::ffi::c_void, dwsize: usize, flallocationtype: u32, flprotect: u32, ecriticallevel: EMemoryCriticalLevel, ppmem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostMemoryManager", "VirtualAlloc", 4usize, |this| ((**(this as *const *const IHostMemoryManager_Vtbl)).VirtualAlloc)(this, paddress, dwsize, flallocationtype, flprotect, ecriticallevel, ppmem), |result| Some(*result))
+        }
+        unsafe extern "system" fn VirtualFree(this: *mut core::ffi::c_void, lpaddress: *const core::ffi::c_void, dwsize: usize, dwfreetype: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostMemoryManager", "VirtualFree", 5usize, |this| ((**(this as *const *const IHostMemoryManager_Vtbl)).VirtualFree)(this, lpaddress, dwsize, dwfreetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn VirtualQuery(this: *mut core::ffi::c_void, lpaddress: *const core::ffi::c_void, lpbuffer: *mut core::ffi::c_void, dwlength: usize, presult: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostMemoryManager", "VirtualQuery", 6usize, |this| ((**(this as *const *const IHostMemoryManager_Vtbl)).VirtualQuery)(this, lpaddress, lpbuffer, dwlength, presult), |result| Some(*result))
+        }
+        unsafe extern "system" fn VirtualProtect(this: *mut core::ffi::c_void, lpaddress: *const core::ffi::c_void, dwsize: usize, flnewprotect: u32, pfloldprotect: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostMemoryManager", "VirtualProtect", 7usize, |this| ((**(this as *const *const IHostMemoryManager_Vtbl)).VirtualProtect)(this, lpaddress, dwsize, flnewprotect, pfloldprotect), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMemoryLoad(this: *mut core::ffi::c_void, pmemoryload: *mut u32, pavailablebytes: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostMemoryManager", "GetMemoryLoad", 8usize, |this| ((**(this as *const *const IHostMemoryManager_Vtbl)).GetMemoryLoad)(this, pmemoryload, pavailablebytes), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterMemoryNotificationCallback(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostMemoryManager", "RegisterMemoryNotificationCallback", 9usize, |this| ((**(this as *const *const IHostMemoryManager_Vtbl)).RegisterMemoryNotificationCallback)(this, pcallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn NeedsVirtualAddressSpace(this: *mut core::ffi::c_void, startaddress: *const core::ffi::c_void, size: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostMemoryManager", "NeedsVirtualAddressSpace", 10usize, |this| ((**(this as *const *const IHostMemoryManager_Vtbl)).NeedsVirtualAddressSpace)(this, startaddress, size), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcquiredVirtualAddressSpace(this: *mut core::ffi::c_void, startaddress: *const core::ffi::c_void, size: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostMemoryManager", "AcquiredVirtualAddressSpace", 11usize, |this| ((**(this as *const *const IHostMemoryManager_Vtbl)).AcquiredVirtualAddressSpace)(this, startaddress, size), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleasedVirtualAddressSpace(this: *mut core::ffi::c_void, startaddress: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostMemoryManager", "ReleasedVirtualAddressSpace", 12usize, |this| ((**(this as *const *const IHostMemoryManager_Vtbl)).ReleasedVirtualAddressSpace)(this, startaddress), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateMalloc,
+            VirtualAlloc,
+            VirtualFree,
+            VirtualQuery,
+            VirtualProtect,
+            GetMemoryLoad,
+            RegisterMemoryNotificationCallback,
+            NeedsVirtualAddressSpace,
+            AcquiredVirtualAddressSpace,
+            ReleasedVirtualAddressSpace,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHostMemoryManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHostPolicyManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnDefaultAction(this: *mut core::ffi::c_void, operation: EClrOperation, action: EPolicyAction) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostPolicyManager", "OnDefaultAction", 3usize, |this| ((**(this as *const *const IHostPolicyManager_Vtbl)).OnDefaultAction)(this, operation, action), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnTimeout(this: *mut core::ffi::c_void, operation: EClrOperation, action: EPolicyAction) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostPolicyManager", "OnTimeout", 4usize, |this| ((**(this as *const *const IHostPolicyManager_Vtbl)).OnTimeout)(this, operation, action), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnFailure(this: *mut core::ffi::c_void, failure: EClrFailure, action: EPolicyAction) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostPolicyManager", "OnFailure", 5usize, |this| ((**(this as *const *const IHostPolicyManager_Vtbl)).OnFailure)(this, failure, action), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnDefaultAction, OnTimeout, OnFailure }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHostPolicyManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHostSecurityContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Capture(this: *mut core::ffi::c_void, ppclonedcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostSecurityContext", "Capture", 3usize, |this| ((**(this as *const *const IHostSecurityContext_Vtbl)).Capture)(this, ppclonedcontext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Capture }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHostSecurityContext as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHostSecurityManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ImpersonateLoggedOnUser(this: *mut core::ffi::c_void, htoken: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostSecurityManager", "ImpersonateLoggedOnUser", 3usize, |this| ((**(this as *const *const IHostSecurityManager_Vtbl)).ImpersonateLoggedOnUser)(this, htoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn RevertToSelf(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostSecurityManager", "RevertToSelf", 4usize, |this| ((**(this as *const *const IHostSecurityManager_Vtbl)).RevertToSelf)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenThreadToken(this: *mut core::ffi::c_void, dwdesiredaccess: u32, bopenasself: super::super::Foundation::BOOL, phthreadtoken: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostSecurityManager", "OpenThreadToken", 5usize, |this| ((**(this as *const *const IHostSecurityManager_Vtbl)).OpenThreadToken)(this, dwdesiredaccess, bopenasself, phthreadtoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetThreadToken(this: *mut core::ffi::c_void, htoken: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostSecurityManager", "SetThreadToken", 6usize, |this| ((**(this as *const *const IHostSecurityManager_Vtbl)).SetThreadToken)(this, htoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSecurityContext(this: *mut core::ffi::c_void, econtexttype: EContextType, ppsecuritycontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostSecurityManager", "GetSecurityContext", 7usize, |this| ((**(this as *const *const IHostSecurityManager_Vtbl)).GetSecurityContext)(this, econtexttype, ppsecuritycontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSecurityContext(this: *mut core::ffi::c_void, econtexttype: EContextType, psecuritycontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostSecurityManager", "SetSecurityContext", 8usize, |this| ((**(this as *const *const IHostSecurityManager_Vtbl)).SetSecurityContext)(this, econtexttype, psecuritycontext), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ImpersonateLoggedOnUser,
+            RevertToSelf,
+            OpenThreadToken,
+            SetThreadToken,
+            GetSecurityContext,
+            SetSecurityContext,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHostSecurityManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHostSemaphore_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Wait(this: *mut core::ffi::c_void, dwmilliseconds: u32, option: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostSemaphore", "Wait", 3usize, |this| ((**(this as *const *const IHostSemaphore_Vtbl)).Wait)(this, dwmilliseconds, option), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseSemaphore(this: *mut core::ffi::c_void, lreleasecount: i32, lppreviouscount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostSemaphore", "ReleaseSemaphore", 4usize, |this| ((**(this as *const *const IHostSemaphore_Vtbl)).ReleaseSemaphore)(this, lreleasecount, lppreviouscount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Wait, ReleaseSemaphore }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHostSemaphore as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHostSyncManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetCLRSyncManager(this: *mut core::ffi::c_void, pmanager: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostSyncManager", "SetCLRSyncManager", 3usize, |this| ((**(this as *const *const IHostSyncManager_Vtbl)).SetCLRSyncManager)(this, pmanager), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCrst(this: *mut core::ffi::c_void, ppcrst: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostSyncManager", "CreateCrst", 4usize, |this| ((**(this as *const *const IHostSyncManager_Vtbl)).CreateCrst)(this, ppcrst), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCrstWithSpinCount(this: *mut core::ffi::c_void, dwspincount: u32, ppcrst: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostSyncManager", "CreateCrstWithSpinCount", 5usize, |this| ((**(this as *const *const IHostSyncManager_Vtbl)).CreateCrstWithSpinCount)(this, dwspincount, ppcrst), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAutoEvent(this: *mut core::ffi::c_void, ppevent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostSyncManager", "CreateAutoEvent", 6usize, |this| ((**(this as *const *const IHostSyncManager_Vtbl)).CreateAutoEvent)(this, ppevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateManualEvent(this: *mut core::ffi::c_void, binitialstate: super::super::Foundation::BOOL, ppevent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostSyncManager", "CreateManualEvent", 7usize, |this| ((**(this as *const *const IHostSyncManager_Vtbl)).CreateManualEvent)(this, binitialstate, ppevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateMonitorEvent(this: *mut core::ffi::c_void, cookie: usize, ppevent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostSyncManager", "CreateMonitorEvent", 8usize, |this| ((**(this as *const *const IHostSyncManager_Vtbl)).CreateMonitorEvent)(this, cookie, ppevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRWLockWriterEvent(this: *mut core::ffi::c_void, cookie: usize, ppevent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostSyncManager", "CreateRWLockWriterEvent", 9usize, |this| ((**(this as *const *const IHostSyncManager_Vtbl)).CreateRWLockWriterEvent)(this, cookie, ppevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRWLockReaderEvent(this: *mut core::ffi::c_void, binitialstate: super::super::Foundation::BOOL, cookie: usize, ppevent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostSyncManager", "CreateRWLockReaderEvent", 10usize, |this| ((**(this as *const *const IHostSyncManager_Vtbl)).CreateRWLockReaderEvent)(this, binitialstate, cookie, ppevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSemaphoreA(this: *mut core::ffi::c_void, dwinitial: u32, dwmax: u32, ppsemaphore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostSyncManager", "CreateSemaphoreA", 11usize, |this| ((**(this as *const *const IHostSyncManager_Vtbl)).CreateSemaphoreA)(this, dwinitial, dwmax, ppsemaphore), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetCLRSyncManager,
+            CreateCrst,
+            CreateCrstWithSpinCount,
+            CreateAutoEvent,
+            CreateManualEvent,
+            CreateMonitorEvent,
+            CreateRWLockWriterEvent,
+            CreateRWLockReaderEvent,
+            CreateSemaphoreA,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHostSyncManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHostTask_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostTask", "Start", 3usize, |this| ((**(this as *const *const IHostTask_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Alert(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostTask", "Alert", 4usize, |this| ((**(this as *const *const IHostTask_Vtbl)).Alert)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Join(this: *mut core::ffi::c_void, dwmilliseconds: u32, option: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostTask", "Join", 5usize, |this| ((**(this as *const *const IHostTask_Vtbl)).Join)(this, dwmilliseconds, option), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPriority(this: *mut core::ffi::c_void, newpriority: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostTask", "SetPriority", 6usize, |this| ((**(this as *const *const IHostTask_Vtbl)).SetPriority)(this, newpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPriority(this: *mut core::ffi::c_void, ppriority: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostTask", "GetPriority", 7usize, |this| ((**(this as *const *const IHostTask_Vtbl)).GetPriority)(this, ppriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCLRTask(this: *mut core::ffi::c_void, pclrtask: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostTask", "SetCLRTask", 8usize, |this| ((**(this as *const *const IHostTask_Vtbl)).SetCLRTask)(this, pclrtask), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Start, Alert, Join, SetPriority, GetPriority, SetCLRTask }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHostTask as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Threading")]
+impl windows_core::imp::ProxyVtable for IHostTaskManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentTask(this: *mut core::ffi::c_void, ptask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostTaskManager", "GetCurrentTask", 3usize, |this| ((**(this as *const *const IHostTaskManager_Vtbl)).GetCurrentTask)(this, ptask), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Threading")]
+        unsafe extern "system" fn CreateTask(this: *mut core::ffi::c_void, dwstacksize: u32, pstartaddress: super::Threading::LPTHREAD_START_ROUTINE, pparameter: *const core::ffi::c_void, pptask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostTaskManager", "CreateTask", 4usize, |this| ((**(this as *const *const IHostTaskManager_Vtbl)).CreateTask)(this, dwstacksize, pstartaddress, pparameter, pptask), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sleep(this: *mut core::ffi::c_void, dwmilliseconds: u32, option: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostTaskManager", "Sleep", 5usize, |this| ((**(this as *const *const IHostTaskManager_Vtbl)).Sleep)(this, dwmilliseconds, option), |result| Some(*result))
+        }
+        unsafe extern "system" fn SwitchToTask(this: *mut core::ffi::c_void, option: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostTaskManager", "SwitchToTask", 6usize, |this| ((**(this as *const *const IHostTaskManager_Vtbl)).SwitchToTask)(this, option), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUILocale(this: *mut core::ffi::c_void, lcid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostTaskManager", "SetUILocale", 7usize, |this| ((**(this as *const *const IHostTaskManager_Vtbl)).SetUILocale)(this, lcid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocale(this: *mut core::ffi::c_void, lcid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostTaskManager", "SetLocale", 8usize, |this| ((**(this as *const *const IHostTaskManager_Vtbl)).SetLocale)(this, lcid), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallNeedsHostHook(this: *mut core::ffi::c_void, target: usize, pbcallneedshosthook: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostTaskManager", "CallNeedsHostHook", 9usize, |this| ((**(this as *const *const IHostTaskManager_Vtbl)).CallNeedsHostHook)(this, target, pbcallneedshosthook), |result| Some(*result))
+        }
+        unsafe extern "system" fn LeaveRuntime(this: *mut core::ffi::c_void, target: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostTaskManager", "LeaveRuntime", 10usize, |this| ((**(this as *const *const IHostTaskManager_Vtbl)).LeaveRuntime)(this, target), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnterRuntime(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostTaskManager", "EnterRuntime", 11usize, |this| ((**(this as *const *const IHostTaskManager_Vtbl)).EnterRuntime)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReverseLeaveRuntime(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostTaskManager", "ReverseLeaveRuntime", 12usize, |this| ((**(this as *const *const IHostTaskManager_Vtbl)).ReverseLeaveRuntime)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReverseEnterRuntime(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostTaskManager", "ReverseEnterRuntime", 13usize, |this| ((**(this as *const *const IHostTaskManager_Vtbl)).ReverseEnterRuntime)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginDelayAbort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostTaskManager", "BeginDelayAbort", 14usize, |this| ((**(this as *const *const IHostTaskManager_Vtbl)).BeginDelayAbort)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndDelayAbort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostTaskManager", "EndDelayAbort", 15usize, |this| ((**(this as *const *const IHostTaskManager_Vtbl)).EndDelayAbort)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginThreadAffinity(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostTaskManager", "BeginThreadAffinity", 16usize, |this| ((**(this as *const *const IHostTaskManager_Vtbl)).BeginThreadAffinity)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndThreadAffinity(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostTaskManager", "EndThreadAffinity", 17usize, |this| ((**(this as *const *const IHostTaskManager_Vtbl)).EndThreadAffinity)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStackGuarantee(this: *mut core::ffi::c_void, guarantee: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostTaskManager", "SetStackGuarantee", 18usize, |this| ((**(this as *const *const IHostTaskManager_Vtbl)).SetStackGuarantee)(this, guarantee), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStackGuarantee(this: *mut core::ffi::c_void, pguarantee: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostTaskManager", "GetStackGuarantee", 19usize, |this| ((**(this as *const *const IHostTaskManager_Vtbl)).GetStackGuarantee)(this, pguarantee), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCLRTaskManager(this: *mut core::ffi::c_void, ppmanager: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostTaskManager", "SetCLRTaskManager", 20usize, |this| ((**(this as *const *const IHostTaskManager_Vtbl)).SetCLRTaskManager)(this, ppmanager), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCurrentTask,
+            #[cfg(feature = "Win32_System_Threading")]
+            CreateTask,
+            #[cfg(not(feature = "Win32_System_Threading"))]
+            CreateTask: 0,
+            Sleep,
+            SwitchToTask,
+            SetUILocale,
+            SetLocale,
+            CallNeedsHostHook,
+            LeaveRuntime,
+            EnterRuntime,
+            ReverseLeaveRuntime,
+            ReverseEnterRuntime,
+            BeginDelayAbort,
+            EndDelayAbort,
+            BeginThreadAffinity,
+            EndThreadAffinity,
+            SetStackGuarantee,
+            GetStackGuarantee,
+            SetCLRTaskManager,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHostTaskManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Threading")]
+impl windows_core::imp::ProxyVtable for IHostThreadpoolManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Threading")]
+        unsafe extern "system" fn QueueUserWorkItem(this: *mut core::ffi::c_void, function: super::Threading::LPTHREAD_START_ROUTINE, context: *const core::ffi::c_void, flags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostThreadpoolManager", "QueueUserWorkItem", 3usize, |this| ((**(this as *const *const IHostThreadpoolManager_Vtbl)).QueueUserWorkItem)(this, function, context, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxThreads(this: *mut core::ffi::c_void, dwmaxworkerthreads: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostThreadpoolManager", "SetMaxThreads", 4usize, |this| ((**(this as *const *const IHostThreadpoolManager_Vtbl)).SetMaxThreads)(this, dwmaxworkerthreads), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxThreads(this: *mut core::ffi::c_void, pdwmaxworkerthreads: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostThreadpoolManager", "GetMaxThreads", 5usize, |this| ((**(this as *const *const IHostThreadpoolManager_Vtbl)).GetMaxThreads)(this, pdwmaxworkerthreads), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAvailableThreads(this: *mut core::ffi::c_void, pdwavailableworkerthreads: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostThreadpoolManager", "GetAvailableThreads", 6usize, |this| ((**(this as *const *const IHostThreadpoolManager_Vtbl)).GetAvailableThreads)(this, pdwavailableworkerthreads), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinThreads(this: *mut core::ffi::c_void, dwminiocompletionthreads: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostThreadpoolManager", "SetMinThreads", 7usize, |this| ((**(this as *const *const IHostThreadpoolManager_Vtbl)).SetMinThreads)(this, dwminiocompletionthreads), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMinThreads(this: *mut core::ffi::c_void, pdwminiocompletionthreads: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostThreadpoolManager", "GetMinThreads", 8usize, |this| ((**(this as *const *const IHostThreadpoolManager_Vtbl)).GetMinThreads)(this, pdwminiocompletionthreads), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Threading")]
+            QueueUserWorkItem,
+            #[cfg(not(feature = "Win32_System_Threading"))]
+            QueueUserWorkItem: 0,
+            SetMaxThreads,
+            GetMaxThreads,
+            GetAvailableThreads,
+            SetMinThreads,
+            GetMinThreads,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHostThreadpoolManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IManagedObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSerializedBuffer(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManagedObject", "GetSerializedBuffer", 3usize, |this| ((**(this as *const *const IManagedObject_Vtbl)).GetSerializedBuffer)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObjectIdentity(this: *mut core::ffi::c_void, pbstrguid: *mut core::mem::MaybeUninit<windows_core::BSTR>, appdomainid: *mut i32, pccw: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManagedObject", "GetObjectIdentity", 4usize, |this| ((**(this as *const *const IManagedObject_Vtbl)).GetObjectIdentity)(this, pbstrguid, appdomainid, pccw), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSerializedBuffer, GetObjectIdentity }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IManagedObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IObjectHandle_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Unwrap(this: *mut core::ffi::c_void, ppv: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectHandle", "Unwrap", 3usize, |this| ((**(this as *const *const IObjectHandle_Vtbl)).Unwrap)(this, ppv), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Unwrap }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IObjectHandle as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITypeName_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNameCount(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeName", "GetNameCount", 3usize, |this| ((**(this as *const *const ITypeName_Vtbl)).GetNameCount)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNames(this: *mut core::ffi::c_void, count: u32, rgbsznames: *mut core::mem::MaybeUninit<windows_core::BSTR>, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeName", "GetNames", 4usize, |this| ((**(this as *const *const ITypeName_Vtbl)).GetNames)(this, count, rgbsznames, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTypeArgumentCount(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeName", "GetTypeArgumentCount", 5usize, |this| ((**(this as *const *const ITypeName_Vtbl)).GetTypeArgumentCount)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTypeArguments(this: *mut core::ffi::c_void, count: u32, rgparguments: *mut *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeName", "GetTypeArguments", 6usize, |this| ((**(this as *const *const ITypeName_Vtbl)).GetTypeArguments)(this, count, rgparguments, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetModifierLength(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeName", "GetModifierLength", 7usize, |this| ((**(this as *const *const ITypeName_Vtbl)).GetModifierLength)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetModifiers(this: *mut core::ffi::c_void, count: u32, rgmodifiers: *mut u32, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeName", "GetModifiers", 8usize, |this| ((**(this as *const *const ITypeName_Vtbl)).GetModifiers)(this, count, rgmodifiers, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAssemblyName(this: *mut core::ffi::c_void, rgbszassemblynames: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeName", "GetAssemblyName", 9usize, |this| ((**(this as *const *const ITypeName_Vtbl)).GetAssemblyName)(this, rgbszassemblynames), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetNameCount,
+            GetNames,
+            GetTypeArgumentCount,
+            GetTypeArguments,
+            GetModifierLength,
+            GetModifiers,
+            GetAssemblyName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITypeName as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITypeNameBuilder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenGenericArguments(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeNameBuilder", "OpenGenericArguments", 3usize, |this| ((**(this as *const *const ITypeNameBuilder_Vtbl)).OpenGenericArguments)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseGenericArguments(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeNameBuilder", "CloseGenericArguments", 4usize, |this| ((**(this as *const *const ITypeNameBuilder_Vtbl)).CloseGenericArguments)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenGenericArgument(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeNameBuilder", "OpenGenericArgument", 5usize, |this| ((**(this as *const *const ITypeNameBuilder_Vtbl)).OpenGenericArgument)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseGenericArgument(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeNameBuilder", "CloseGenericArgument", 6usize, |this| ((**(this as *const *const ITypeNameBuilder_Vtbl)).CloseGenericArgument)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddName(this: *mut core::ffi::c_void, szname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeNameBuilder", "AddName", 7usize, |this| ((**(this as *const *const ITypeNameBuilder_Vtbl)).AddName)(this, szname), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPointer(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeNameBuilder", "AddPointer", 8usize, |this| ((**(this as *const *const ITypeNameBuilder_Vtbl)).AddPointer)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddByRef(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeNameBuilder", "AddByRef", 9usize, |this| ((**(this as *const *const ITypeNameBuilder_Vtbl)).AddByRef)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddSzArray(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeNameBuilder", "AddSzArray", 10usize, |this| ((**(this as *const *const ITypeNameBuilder_Vtbl)).AddSzArray)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddArray(this: *mut core::ffi::c_void, rank: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeNameBuilder", "AddArray", 11usize, |this| ((**(this as *const *const ITypeNameBuilder_Vtbl)).AddArray)(this, rank), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddAssemblySpec(this: *mut core::ffi::c_void, szassemblyspec: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeNameBuilder", "AddAssemblySpec", 12usize, |this| ((**(this as *const *const ITypeNameBuilder_Vtbl)).AddAssemblySpec)(this, szassemblyspec), |result| Some(*result))
+        }
+        unsafe extern "system" fn ToString(this: *mut core::ffi::c_void, pszstringrepresentation: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeNameBuilder", "ToString", 13usize, |this| ((**(this as *const *const ITypeNameBuilder_Vtbl)).ToString)(this, pszstringrepresentation), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeNameBuilder", "Clear", 14usize, |this| ((**(this as *const *const ITypeNameBuilder_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OpenGenericArguments,
+            CloseGenericArguments,
+            OpenGenericArgument,
+            CloseGenericArgument,
+            AddName,
+            AddPointer,
+            AddByRef,
+            AddSzArray,
+            AddArray,
+            AddAssemblySpec,
+            ToString,
+            Clear,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITypeNameBuilder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITypeNameFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ParseTypeName(this: *mut core::ffi::c_void, szname: windows_core::PCWSTR, perror: *mut u32, pptypename: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeNameFactory", "ParseTypeName", 3usize, |this| ((**(this as *const *const ITypeNameFactory_Vtbl)).ParseTypeName)(this, szname, perror, pptypename), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTypeNameBuilder(this: *mut core::ffi::c_void, pptypebuilder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeNameFactory", "GetTypeNameBuilder", 4usize, |this| ((**(this as *const *const ITypeNameFactory_Vtbl)).GetTypeNameBuilder)(this, pptypebuilder), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ParseTypeName, GetTypeNameBuilder }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITypeNameFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -295,6 +295,7 @@ pub struct CALLFRAME_COPY(pub i32);
 impl windows_core::TypeKind for CALLFRAME_COPY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CALLFRAME_COPY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CALLFRAME_COPY").field(&self.0).finish()
@@ -306,6 +307,7 @@ pub struct CALLFRAME_FREE(pub i32);
 impl windows_core::TypeKind for CALLFRAME_FREE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CALLFRAME_FREE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CALLFRAME_FREE").field(&self.0).finish()
@@ -317,6 +319,7 @@ pub struct CALLFRAME_NULL(pub i32);
 impl windows_core::TypeKind for CALLFRAME_NULL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CALLFRAME_NULL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CALLFRAME_NULL").field(&self.0).finish()
@@ -328,13 +331,15 @@ pub struct CALLFRAME_WALK(pub i32);
 impl windows_core::TypeKind for CALLFRAME_WALK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CALLFRAME_WALK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CALLFRAME_WALK").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CALLFRAMEINFO {
     pub iMethod: u32,
     pub fHasInValues: super::super::super::Foundation::BOOL,
@@ -358,7 +363,8 @@ impl Default for CALLFRAMEINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CALLFRAMEPARAMINFO {
     pub fIn: super::super::super::Foundation::BOOLEAN,
     pub fOut: super::super::super::Foundation::BOOLEAN,
@@ -374,7 +380,8 @@ impl Default for CALLFRAMEPARAMINFO {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CALLFRAME_MARSHALCONTEXT {
     pub fIn: super::super::super::Foundation::BOOLEAN,
     pub dwDestContext: u32,
@@ -397,3 +404,5 @@ impl Default for CALLFRAME_MARSHALCONTEXT {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
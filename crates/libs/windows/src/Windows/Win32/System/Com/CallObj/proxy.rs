@@ -0,0 +1,177 @@
+impl windows_core::imp::ProxyVtable for ICallFrame_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInfo(this: *mut core::ffi::c_void, pinfo: *mut CALLFRAMEINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallFrame", "GetInfo", 3usize, |this| ((**(this as *const *const ICallFrame_Vtbl)).GetInfo)(this, pinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIIDAndMethod(this: *mut core::ffi::c_void, piid: *mut windows_core::GUID, pimethod: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallFrame", "GetIIDAndMethod", 4usize, |this| ((**(this as *const *const ICallFrame_Vtbl)).GetIIDAndMethod)(this, piid, pimethod), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNames(this: *mut core::ffi::c_void, pwszinterface: *mut windows_core::PWSTR, pwszmethod: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallFrame", "GetNames", 5usize, |this| ((**(this as *const *const ICallFrame_Vtbl)).GetNames)(this, pwszinterface, pwszmethod), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStackLocation(this: *mut core::ffi::c_void) -> *mut core::ffi::c_void {
+            windows_core::imp::proxy_call(this, "ICallFrame", "GetStackLocation", 6usize, |this| ((**(this as *const *const ICallFrame_Vtbl)).GetStackLocation)(this), |_| None)
+        }
+        unsafe extern "system" fn SetStackLocation(this: *mut core::ffi::c_void, pvstack: *const core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ICallFrame", "SetStackLocation", 7usize, |this| ((**(this as *const *const ICallFrame_Vtbl)).SetStackLocation)(this, pvstack), |_| None)
+        }
+        unsafe extern "system" fn SetReturnValue(this: *mut core::ffi::c_void, hr: windows_core::HRESULT) {
+            windows_core::imp::proxy_call(this, "ICallFrame", "SetReturnValue", 8usize, |this| ((**(this as *const *const ICallFrame_Vtbl)).SetReturnValue)(this, hr), |_| None)
+        }
+        unsafe extern "system" fn GetReturnValue(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallFrame", "GetReturnValue", 9usize, |this| ((**(this as *const *const ICallFrame_Vtbl)).GetReturnValue)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParamInfo(this: *mut core::ffi::c_void, iparam: u32, pinfo: *mut CALLFRAMEPARAMINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallFrame", "GetParamInfo", 10usize, |this| ((**(this as *const *const ICallFrame_Vtbl)).GetParamInfo)(this, iparam, pinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParam(this: *mut core::ffi::c_void, iparam: u32, pvar: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallFrame", "SetParam", 11usize, |this| ((**(this as *const *const ICallFrame_Vtbl)).SetParam)(this, iparam, pvar), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParam(this: *mut core::ffi::c_void, iparam: u32, pvar: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallFrame", "GetParam", 12usize, |this| ((**(this as *const *const ICallFrame_Vtbl)).GetParam)(this, iparam, pvar), |result| Some(*result))
+        }
+        unsafe extern "system" fn Copy(this: *mut core::ffi::c_void, copycontrol: CALLFRAME_COPY, pwalker: *mut core::ffi::c_void, ppframe: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallFrame", "Copy", 13usize, |this| ((**(this as *const *const ICallFrame_Vtbl)).Copy)(this, copycontrol, pwalker, ppframe), |result| Some(*result))
+        }
+        unsafe extern "system" fn Free(this: *mut core::ffi::c_void, pframeargsdest: *mut core::ffi::c_void, pwalkerdestfree: *mut core::ffi::c_void, pwalkercopy: *mut core::ffi::c_void, freeflags: u32, pwalkerfree: *mut core::ffi::c_void, nullflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallFrame", "Free", 14usize, |this| ((**(this as *const *const ICallFrame_Vtbl)).Free)(this, pframeargsdest, pwalkerdestfree, pwalkercopy, freeflags, pwalkerfree, nullflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreeParam(this: *mut core::ffi::c_void, iparam: u32, freeflags: u32, pwalkerfree: *mut core::ffi::c_void, nullflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallFrame", "FreeParam", 15usize, |this| ((**(this as *const *const ICallFrame_Vtbl)).FreeParam)(this, iparam, freeflags, pwalkerfree, nullflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn WalkFrame(this: *mut core::ffi::c_void, walkwhat: u32, pwalker: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallFrame", "WalkFrame", 16usize, |this| ((**(this as *const *const ICallFrame_Vtbl)).WalkFrame)(this, walkwhat, pwalker), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMarshalSizeMax(this: *mut core::ffi::c_void, pmshlcontext: *const CALLFRAME_MARSHALCONTEXT, mshlflags: super::MSHLFLAGS, pcbbufferneeded: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallFrame", "GetMarshalSizeMax", 17usize, |this| ((**(this as *const *const ICallFrame_Vtbl)).GetMarshalSizeMax)(this, pmshlcontext, mshlflags, pcbbufferneeded), |result| Some(*result))
+        }
+        unsafe extern "system" fn Marshal(this: *mut core::ffi::c_void, pmshlcontext: *const CALLFRAME_MARSHALCONTEXT, mshlflags: super::MSHLFLAGS, pbuffer: *const core::ffi::c_void, cbbuffer: u32, pcbbufferused: *mut u32, pdatarep: *mut u32, prpcflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallFrame", "Marshal", 18usize, |this| ((**(this as *const *const ICallFrame_Vtbl)).Marshal)(this, pmshlcontext, mshlflags, pbuffer, cbbuffer, pcbbufferused, pdatarep, prpcflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unmarshal(this: *mut core::ffi::c_void, pbuffer: *const core::ffi::c_void, cbbuffer: u32, datarep: u32, pcontext: *const CALLFRAME_MARSHALCONTEXT, pcbunmarshalled: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallFrame", "Unmarshal", 19usize, |this| ((**(this as *const *const ICallFrame_Vtbl)).Unmarshal)(this, pbuffer, cbbuffer, datarep, pcontext, pcbunmarshalled), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseMarshalData(this: *mut core::ffi::c_void, pbuffer: *const core::ffi::c_void, cbbuffer: u32, ibfirstrelease: u32, datarep: u32, pcontext: *const CALLFRAME_MARSHALCONTEXT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallFrame", "ReleaseMarshalData", 20usize, |this| ((**(this as *const *const ICallFrame_Vtbl)).ReleaseMarshalData)(this, pbuffer, cbbuffer, ibfirstrelease, datarep, pcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn Invoke(this: *mut core::ffi::c_void, pvreceiver: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallFrame", "Invoke", 21usize, |this| ((**(this as *const *const ICallFrame_Vtbl)).Invoke)(this, pvreceiver), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetInfo,
+            GetIIDAndMethod,
+            GetNames,
+            GetStackLocation,
+            SetStackLocation,
+            SetReturnValue,
+            GetReturnValue,
+            GetParamInfo,
+            SetParam,
+            GetParam,
+            Copy,
+            Free,
+            FreeParam,
+            WalkFrame,
+            GetMarshalSizeMax,
+            Marshal,
+            Unmarshal,
+            ReleaseMarshalData,
+            Invoke,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICallFrame as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICallFrameEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnCall(this: *mut core::ffi::c_void, pframe: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallFrameEvents", "OnCall", 3usize, |this| ((**(this as *const *const ICallFrameEvents_Vtbl)).OnCall)(this, pframe), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnCall }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICallFrameEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICallFrameWalker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnWalkInterface(this: *mut core::ffi::c_void, iid: *const windows_core::GUID, ppvinterface: *const *const core::ffi::c_void, fin: super::super::super::Foundation::BOOL, fout: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallFrameWalker", "OnWalkInterface", 3usize, |this| ((**(this as *const *const ICallFrameWalker_Vtbl)).OnWalkInterface)(this, iid, ppvinterface, fin, fout), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnWalkInterface }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICallFrameWalker as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICallIndirect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CallIndirect(this: *mut core::ffi::c_void, phrreturn: *mut windows_core::HRESULT, imethod: u32, pvargs: *const core::ffi::c_void, cbargs: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallIndirect", "CallIndirect", 3usize, |this| ((**(this as *const *const ICallIndirect_Vtbl)).CallIndirect)(this, phrreturn, imethod, pvargs, cbargs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMethodInfo(this: *mut core::ffi::c_void, imethod: u32, pinfo: *mut CALLFRAMEINFO, pwszmethod: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallIndirect", "GetMethodInfo", 4usize, |this| ((**(this as *const *const ICallIndirect_Vtbl)).GetMethodInfo)(this, imethod, pinfo, pwszmethod), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStackSize(this: *mut core::ffi::c_void, imethod: u32, cbargs: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallIndirect", "GetStackSize", 5usize, |this| ((**(this as *const *const ICallIndirect_Vtbl)).GetStackSize)(this, imethod, cbargs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIID(this: *mut core::ffi::c_void, piid: *mut windows_core::GUID, pfderivesfromidispatch: *mut super::super::super::Foundation::BOOL, pcmethod: *mut u32, pwszinterface: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallIndirect", "GetIID", 6usize, |this| ((**(this as *const *const ICallIndirect_Vtbl)).GetIID)(this, piid, pfderivesfromidispatch, pcmethod, pwszinterface), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CallIndirect, GetMethodInfo, GetStackSize, GetIID }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICallIndirect as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICallInterceptor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterSink(this: *mut core::ffi::c_void, psink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallInterceptor", "RegisterSink", 7usize, |this| ((**(this as *const *const ICallInterceptor_Vtbl)).RegisterSink)(this, psink), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRegisteredSink(this: *mut core::ffi::c_void, ppsink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallInterceptor", "GetRegisteredSink", 8usize, |this| ((**(this as *const *const ICallInterceptor_Vtbl)).GetRegisteredSink)(this, ppsink), |result| Some(*result))
+        }
+        Self { base__: <ICallIndirect_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterSink, GetRegisteredSink }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICallInterceptor as windows_core::Interface>::IID || <ICallIndirect_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICallUnmarshal_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Unmarshal(this: *mut core::ffi::c_void, imethod: u32, pbuffer: *const core::ffi::c_void, cbbuffer: u32, fforcebuffercopy: super::super::super::Foundation::BOOL, datarep: u32, pcontext: *const CALLFRAME_MARSHALCONTEXT, pcbunmarshalled: *mut u32, ppframe: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallUnmarshal", "Unmarshal", 3usize, |this| ((**(this as *const *const ICallUnmarshal_Vtbl)).Unmarshal)(this, imethod, pbuffer, cbbuffer, fforcebuffercopy, datarep, pcontext, pcbunmarshalled, ppframe), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseMarshalData(this: *mut core::ffi::c_void, imethod: u32, pbuffer: *const core::ffi::c_void, cbbuffer: u32, ibfirstrelease: u32, datarep: u32, pcontext: *const CALLFRAME_MARSHALCONTEXT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallUnmarshal", "ReleaseMarshalData", 4usize, |this| ((**(this as *const *const ICallUnmarshal_Vtbl)).ReleaseMarshalData)(this, imethod, pbuffer, cbbuffer, ibfirstrelease, datarep, pcontext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Unmarshal, ReleaseMarshalData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICallUnmarshal as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInterfaceRelated_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetIID(this: *mut core::ffi::c_void, iid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInterfaceRelated", "SetIID", 3usize, |this| ((**(this as *const *const IInterfaceRelated_Vtbl)).SetIID)(this, iid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIID(this: *mut core::ffi::c_void, piid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInterfaceRelated", "GetIID", 4usize, |this| ((**(this as *const *const IInterfaceRelated_Vtbl)).GetIID)(this, piid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetIID, GetIID }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInterfaceRelated as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -0,0 +1,51 @@
+impl windows_core::imp::ProxyVtable for IChannelCredentials_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetWindowsCredential(this: *mut core::ffi::c_void, domain: core::mem::MaybeUninit<windows_core::BSTR>, username: core::mem::MaybeUninit<windows_core::BSTR>, password: core::mem::MaybeUninit<windows_core::BSTR>, impersonationlevel: i32, allowntlm: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChannelCredentials", "SetWindowsCredential", 7usize, |this| ((**(this as *const *const IChannelCredentials_Vtbl)).SetWindowsCredential)(this, domain, username, password, impersonationlevel, allowntlm), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUserNameCredential(this: *mut core::ffi::c_void, username: core::mem::MaybeUninit<windows_core::BSTR>, password: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChannelCredentials", "SetUserNameCredential", 8usize, |this| ((**(this as *const *const IChannelCredentials_Vtbl)).SetUserNameCredential)(this, username, password), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClientCertificateFromStore(this: *mut core::ffi::c_void, storelocation: core::mem::MaybeUninit<windows_core::BSTR>, storename: core::mem::MaybeUninit<windows_core::BSTR>, findyype: core::mem::MaybeUninit<windows_core::BSTR>, findvalue: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChannelCredentials", "SetClientCertificateFromStore", 9usize, |this| ((**(this as *const *const IChannelCredentials_Vtbl)).SetClientCertificateFromStore)(this, storelocation, storename, findyype, findvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClientCertificateFromStoreByName(this: *mut core::ffi::c_void, subjectname: core::mem::MaybeUninit<windows_core::BSTR>, storelocation: core::mem::MaybeUninit<windows_core::BSTR>, storename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChannelCredentials", "SetClientCertificateFromStoreByName", 10usize, |this| ((**(this as *const *const IChannelCredentials_Vtbl)).SetClientCertificateFromStoreByName)(this, subjectname, storelocation, storename), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClientCertificateFromFile(this: *mut core::ffi::c_void, filename: core::mem::MaybeUninit<windows_core::BSTR>, password: core::mem::MaybeUninit<windows_core::BSTR>, keystorageflags: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChannelCredentials", "SetClientCertificateFromFile", 11usize, |this| ((**(this as *const *const IChannelCredentials_Vtbl)).SetClientCertificateFromFile)(this, filename, password, keystorageflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultServiceCertificateFromStore(this: *mut core::ffi::c_void, storelocation: core::mem::MaybeUninit<windows_core::BSTR>, storename: core::mem::MaybeUninit<windows_core::BSTR>, findtype: core::mem::MaybeUninit<windows_core::BSTR>, findvalue: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChannelCredentials", "SetDefaultServiceCertificateFromStore", 12usize, |this| ((**(this as *const *const IChannelCredentials_Vtbl)).SetDefaultServiceCertificateFromStore)(this, storelocation, storename, findtype, findvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultServiceCertificateFromStoreByName(this: *mut core::ffi::c_void, subjectname: core::mem::MaybeUninit<windows_core::BSTR>, storelocation: core::mem::MaybeUninit<windows_core::BSTR>, storename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChannelCredentials", "SetDefaultServiceCertificateFromStoreByName", 13usize, |this| ((**(this as *const *const IChannelCredentials_Vtbl)).SetDefaultServiceCertificateFromStoreByName)(this, subjectname, storelocation, storename), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultServiceCertificateFromFile(this: *mut core::ffi::c_void, filename: core::mem::MaybeUninit<windows_core::BSTR>, password: core::mem::MaybeUninit<windows_core::BSTR>, keystorageflags: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChannelCredentials", "SetDefaultServiceCertificateFromFile", 14usize, |this| ((**(this as *const *const IChannelCredentials_Vtbl)).SetDefaultServiceCertificateFromFile)(this, filename, password, keystorageflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServiceCertificateAuthentication(this: *mut core::ffi::c_void, storelocation: core::mem::MaybeUninit<windows_core::BSTR>, revocationmode: core::mem::MaybeUninit<windows_core::BSTR>, certificatevalidationmode: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChannelCredentials", "SetServiceCertificateAuthentication", 15usize, |this| ((**(this as *const *const IChannelCredentials_Vtbl)).SetServiceCertificateAuthentication)(this, storelocation, revocationmode, certificatevalidationmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIssuedToken(this: *mut core::ffi::c_void, localissueraddres: core::mem::MaybeUninit<windows_core::BSTR>, localissuerbindingtype: core::mem::MaybeUninit<windows_core::BSTR>, localissuerbinding: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChannelCredentials", "SetIssuedToken", 16usize, |this| ((**(this as *const *const IChannelCredentials_Vtbl)).SetIssuedToken)(this, localissueraddres, localissuerbindingtype, localissuerbinding), |result| Some(*result))
+        }
+        Self {
+            base__: <super::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetWindowsCredential,
+            SetUserNameCredential,
+            SetClientCertificateFromStore,
+            SetClientCertificateFromStoreByName,
+            SetClientCertificateFromFile,
+            SetDefaultServiceCertificateFromStore,
+            SetDefaultServiceCertificateFromStoreByName,
+            SetDefaultServiceCertificateFromFile,
+            SetServiceCertificateAuthentication,
+            SetIssuedToken,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChannelCredentials as windows_core::Interface>::IID || <super::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -0,0 +1,572 @@
+impl windows_core::imp::ProxyVtable for IDontSupportEventSubscription_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDontSupportEventSubscription as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumEventObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumEventObject", "Clone", 3usize, |this| ((**(this as *const *const IEnumEventObject_Vtbl)).Clone)(this, ppinterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, creqelem: u32, ppinterface: *mut *mut core::ffi::c_void, cretelem: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumEventObject", "Next", 4usize, |this| ((**(this as *const *const IEnumEventObject_Vtbl)).Next)(this, creqelem, ppinterface, cretelem), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumEventObject", "Reset", 5usize, |this| ((**(this as *const *const IEnumEventObject_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, cskipelem: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumEventObject", "Skip", 6usize, |this| ((**(this as *const *const IEnumEventObject_Vtbl)).Skip)(this, cskipelem), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Clone, Next, Reset, Skip }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumEventObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEventClass_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EventClassID(this: *mut core::ffi::c_void, pbstreventclassid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventClass", "EventClassID", 7usize, |this| ((**(this as *const *const IEventClass_Vtbl)).EventClassID)(this, pbstreventclassid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventClassID(this: *mut core::ffi::c_void, bstreventclassid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventClass", "SetEventClassID", 8usize, |this| ((**(this as *const *const IEventClass_Vtbl)).SetEventClassID)(this, bstreventclassid), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventClassName(this: *mut core::ffi::c_void, pbstreventclassname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventClass", "EventClassName", 9usize, |this| ((**(this as *const *const IEventClass_Vtbl)).EventClassName)(this, pbstreventclassname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventClassName(this: *mut core::ffi::c_void, bstreventclassname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventClass", "SetEventClassName", 10usize, |this| ((**(this as *const *const IEventClass_Vtbl)).SetEventClassName)(this, bstreventclassname), |result| Some(*result))
+        }
+        unsafe extern "system" fn OwnerSID(this: *mut core::ffi::c_void, pbstrownersid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventClass", "OwnerSID", 11usize, |this| ((**(this as *const *const IEventClass_Vtbl)).OwnerSID)(this, pbstrownersid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOwnerSID(this: *mut core::ffi::c_void, bstrownersid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventClass", "SetOwnerSID", 12usize, |this| ((**(this as *const *const IEventClass_Vtbl)).SetOwnerSID)(this, bstrownersid), |result| Some(*result))
+        }
+        unsafe extern "system" fn FiringInterfaceID(this: *mut core::ffi::c_void, pbstrfiringinterfaceid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventClass", "FiringInterfaceID", 13usize, |this| ((**(this as *const *const IEventClass_Vtbl)).FiringInterfaceID)(this, pbstrfiringinterfaceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFiringInterfaceID(this: *mut core::ffi::c_void, bstrfiringinterfaceid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventClass", "SetFiringInterfaceID", 14usize, |this| ((**(this as *const *const IEventClass_Vtbl)).SetFiringInterfaceID)(this, bstrfiringinterfaceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, pbstrdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventClass", "Description", 15usize, |this| ((**(this as *const *const IEventClass_Vtbl)).Description)(this, pbstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventClass", "SetDescription", 16usize, |this| ((**(this as *const *const IEventClass_Vtbl)).SetDescription)(this, bstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn CustomConfigCLSID(this: *mut core::ffi::c_void, pbstrcustomconfigclsid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventClass", "CustomConfigCLSID", 17usize, |this| ((**(this as *const *const IEventClass_Vtbl)).CustomConfigCLSID)(this, pbstrcustomconfigclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCustomConfigCLSID(this: *mut core::ffi::c_void, bstrcustomconfigclsid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventClass", "SetCustomConfigCLSID", 18usize, |this| ((**(this as *const *const IEventClass_Vtbl)).SetCustomConfigCLSID)(this, bstrcustomconfigclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn TypeLib(this: *mut core::ffi::c_void, pbstrtypelib: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventClass", "TypeLib", 19usize, |this| ((**(this as *const *const IEventClass_Vtbl)).TypeLib)(this, pbstrtypelib), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTypeLib(this: *mut core::ffi::c_void, bstrtypelib: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventClass", "SetTypeLib", 20usize, |this| ((**(this as *const *const IEventClass_Vtbl)).SetTypeLib)(this, bstrtypelib), |result| Some(*result))
+        }
+        Self {
+            base__: <super::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EventClassID,
+            SetEventClassID,
+            EventClassName,
+            SetEventClassName,
+            OwnerSID,
+            SetOwnerSID,
+            FiringInterfaceID,
+            SetFiringInterfaceID,
+            Description,
+            SetDescription,
+            CustomConfigCLSID,
+            SetCustomConfigCLSID,
+            TypeLib,
+            SetTypeLib,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEventClass as windows_core::Interface>::IID || <super::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEventClass2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PublisherID(this: *mut core::ffi::c_void, pbstrpublisherid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventClass2", "PublisherID", 21usize, |this| ((**(this as *const *const IEventClass2_Vtbl)).PublisherID)(this, pbstrpublisherid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPublisherID(this: *mut core::ffi::c_void, bstrpublisherid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventClass2", "SetPublisherID", 22usize, |this| ((**(this as *const *const IEventClass2_Vtbl)).SetPublisherID)(this, bstrpublisherid), |result| Some(*result))
+        }
+        unsafe extern "system" fn MultiInterfacePublisherFilterCLSID(this: *mut core::ffi::c_void, pbstrpubfilclsid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventClass2", "MultiInterfacePublisherFilterCLSID", 23usize, |this| ((**(this as *const *const IEventClass2_Vtbl)).MultiInterfacePublisherFilterCLSID)(this, pbstrpubfilclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMultiInterfacePublisherFilterCLSID(this: *mut core::ffi::c_void, bstrpubfilclsid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventClass2", "SetMultiInterfacePublisherFilterCLSID", 24usize, |this| ((**(this as *const *const IEventClass2_Vtbl)).SetMultiInterfacePublisherFilterCLSID)(this, bstrpubfilclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowInprocActivation(this: *mut core::ffi::c_void, pfallowinprocactivation: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventClass2", "AllowInprocActivation", 25usize, |this| ((**(this as *const *const IEventClass2_Vtbl)).AllowInprocActivation)(this, pfallowinprocactivation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowInprocActivation(this: *mut core::ffi::c_void, fallowinprocactivation: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventClass2", "SetAllowInprocActivation", 26usize, |this| ((**(this as *const *const IEventClass2_Vtbl)).SetAllowInprocActivation)(this, fallowinprocactivation), |result| Some(*result))
+        }
+        unsafe extern "system" fn FireInParallel(this: *mut core::ffi::c_void, pffireinparallel: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventClass2", "FireInParallel", 27usize, |this| ((**(this as *const *const IEventClass2_Vtbl)).FireInParallel)(this, pffireinparallel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFireInParallel(this: *mut core::ffi::c_void, ffireinparallel: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventClass2", "SetFireInParallel", 28usize, |this| ((**(this as *const *const IEventClass2_Vtbl)).SetFireInParallel)(this, ffireinparallel), |result| Some(*result))
+        }
+        Self {
+            base__: <IEventClass_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PublisherID,
+            SetPublisherID,
+            MultiInterfacePublisherFilterCLSID,
+            SetMultiInterfacePublisherFilterCLSID,
+            AllowInprocActivation,
+            SetAllowInprocActivation,
+            FireInParallel,
+            SetFireInParallel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEventClass2 as windows_core::Interface>::IID || <IEventClass_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEventControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPublisherFilter(this: *mut core::ffi::c_void, methodname: core::mem::MaybeUninit<windows_core::BSTR>, ppublisherfilter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventControl", "SetPublisherFilter", 7usize, |this| ((**(this as *const *const IEventControl_Vtbl)).SetPublisherFilter)(this, methodname, ppublisherfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowInprocActivation(this: *mut core::ffi::c_void, pfallowinprocactivation: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventControl", "AllowInprocActivation", 8usize, |this| ((**(this as *const *const IEventControl_Vtbl)).AllowInprocActivation)(this, pfallowinprocactivation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowInprocActivation(this: *mut core::ffi::c_void, fallowinprocactivation: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventControl", "SetAllowInprocActivation", 9usize, |this| ((**(this as *const *const IEventControl_Vtbl)).SetAllowInprocActivation)(this, fallowinprocactivation), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubscriptions(this: *mut core::ffi::c_void, methodname: core::mem::MaybeUninit<windows_core::BSTR>, optionalcriteria: core::mem::MaybeUninit<windows_core::BSTR>, optionalerrorindex: *const i32, ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventControl", "GetSubscriptions", 10usize, |this| ((**(this as *const *const IEventControl_Vtbl)).GetSubscriptions)(this, methodname, optionalcriteria, optionalerrorindex, ppcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultQuery(this: *mut core::ffi::c_void, methodname: core::mem::MaybeUninit<windows_core::BSTR>, criteria: core::mem::MaybeUninit<windows_core::BSTR>, errorindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventControl", "SetDefaultQuery", 11usize, |this| ((**(this as *const *const IEventControl_Vtbl)).SetDefaultQuery)(this, methodname, criteria, errorindex), |result| Some(*result))
+        }
+        Self {
+            base__: <super::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetPublisherFilter,
+            AllowInprocActivation,
+            SetAllowInprocActivation,
+            GetSubscriptions,
+            SetDefaultQuery,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEventControl as windows_core::Interface>::IID || <super::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEventObjectChange_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChangedSubscription(this: *mut core::ffi::c_void, changetype: EOC_ChangeType, bstrsubscriptionid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventObjectChange", "ChangedSubscription", 3usize, |this| ((**(this as *const *const IEventObjectChange_Vtbl)).ChangedSubscription)(this, changetype, bstrsubscriptionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangedEventClass(this: *mut core::ffi::c_void, changetype: EOC_ChangeType, bstreventclassid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventObjectChange", "ChangedEventClass", 4usize, |this| ((**(this as *const *const IEventObjectChange_Vtbl)).ChangedEventClass)(this, changetype, bstreventclassid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangedPublisher(this: *mut core::ffi::c_void, changetype: EOC_ChangeType, bstrpublisherid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventObjectChange", "ChangedPublisher", 5usize, |this| ((**(this as *const *const IEventObjectChange_Vtbl)).ChangedPublisher)(this, changetype, bstrpublisherid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ChangedSubscription, ChangedEventClass, ChangedPublisher }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEventObjectChange as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEventObjectChange2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChangedSubscription(this: *mut core::ffi::c_void, pinfo: *const COMEVENTSYSCHANGEINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventObjectChange2", "ChangedSubscription", 3usize, |this| ((**(this as *const *const IEventObjectChange2_Vtbl)).ChangedSubscription)(this, pinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangedEventClass(this: *mut core::ffi::c_void, pinfo: *const COMEVENTSYSCHANGEINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventObjectChange2", "ChangedEventClass", 4usize, |this| ((**(this as *const *const IEventObjectChange2_Vtbl)).ChangedEventClass)(this, pinfo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ChangedSubscription, ChangedEventClass }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEventObjectChange2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEventObjectCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppunkenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventObjectCollection", "_NewEnum", 7usize, |this| ((**(this as *const *const IEventObjectCollection_Vtbl))._NewEnum)(this, ppunkenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, objectid: core::mem::MaybeUninit<windows_core::BSTR>, pitem: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventObjectCollection", "get_Item", 8usize, |this| ((**(this as *const *const IEventObjectCollection_Vtbl)).get_Item)(this, objectid, pitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn NewEnum(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventObjectCollection", "NewEnum", 9usize, |this| ((**(this as *const *const IEventObjectCollection_Vtbl)).NewEnum)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventObjectCollection", "Count", 10usize, |this| ((**(this as *const *const IEventObjectCollection_Vtbl)).Count)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, item: *const core::mem::MaybeUninit<windows_core::VARIANT>, objectid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventObjectCollection", "Add", 11usize, |this| ((**(this as *const *const IEventObjectCollection_Vtbl)).Add)(this, item, objectid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, objectid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventObjectCollection", "Remove", 12usize, |this| ((**(this as *const *const IEventObjectCollection_Vtbl)).Remove)(this, objectid), |result| Some(*result))
+        }
+        Self { base__: <super::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, _NewEnum, get_Item, NewEnum, Count, Add, Remove }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEventObjectCollection as windows_core::Interface>::IID || <super::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEventProperty_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, propertyname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventProperty", "Name", 7usize, |this| ((**(this as *const *const IEventProperty_Vtbl)).Name)(this, propertyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventProperty", "SetName", 8usize, |this| ((**(this as *const *const IEventProperty_Vtbl)).SetName)(this, propertyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, propertyvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventProperty", "Value", 9usize, |this| ((**(this as *const *const IEventProperty_Vtbl)).Value)(this, propertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, propertyvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventProperty", "SetValue", 10usize, |this| ((**(this as *const *const IEventProperty_Vtbl)).SetValue)(this, propertyvalue), |result| Some(*result))
+        }
+        Self { base__: <super::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, SetName, Value, SetValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEventProperty as windows_core::Interface>::IID || <super::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEventPublisher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PublisherID(this: *mut core::ffi::c_void, pbstrpublisherid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventPublisher", "PublisherID", 7usize, |this| ((**(this as *const *const IEventPublisher_Vtbl)).PublisherID)(this, pbstrpublisherid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPublisherID(this: *mut core::ffi::c_void, bstrpublisherid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventPublisher", "SetPublisherID", 8usize, |this| ((**(this as *const *const IEventPublisher_Vtbl)).SetPublisherID)(this, bstrpublisherid), |result| Some(*result))
+        }
+        unsafe extern "system" fn PublisherName(this: *mut core::ffi::c_void, pbstrpublishername: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventPublisher", "PublisherName", 9usize, |this| ((**(this as *const *const IEventPublisher_Vtbl)).PublisherName)(this, pbstrpublishername), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPublisherName(this: *mut core::ffi::c_void, bstrpublishername: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventPublisher", "SetPublisherName", 10usize, |this| ((**(this as *const *const IEventPublisher_Vtbl)).SetPublisherName)(this, bstrpublishername), |result| Some(*result))
+        }
+        unsafe extern "system" fn PublisherType(this: *mut core::ffi::c_void, pbstrpublishertype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventPublisher", "PublisherType", 11usize, |this| ((**(this as *const *const IEventPublisher_Vtbl)).PublisherType)(this, pbstrpublishertype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPublisherType(this: *mut core::ffi::c_void, bstrpublishertype: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventPublisher", "SetPublisherType", 12usize, |this| ((**(this as *const *const IEventPublisher_Vtbl)).SetPublisherType)(this, bstrpublishertype), |result| Some(*result))
+        }
+        unsafe extern "system" fn OwnerSID(this: *mut core::ffi::c_void, pbstrownersid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventPublisher", "OwnerSID", 13usize, |this| ((**(this as *const *const IEventPublisher_Vtbl)).OwnerSID)(this, pbstrownersid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOwnerSID(this: *mut core::ffi::c_void, bstrownersid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventPublisher", "SetOwnerSID", 14usize, |this| ((**(this as *const *const IEventPublisher_Vtbl)).SetOwnerSID)(this, bstrownersid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, pbstrdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventPublisher", "Description", 15usize, |this| ((**(this as *const *const IEventPublisher_Vtbl)).Description)(this, pbstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventPublisher", "SetDescription", 16usize, |this| ((**(this as *const *const IEventPublisher_Vtbl)).SetDescription)(this, bstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultProperty(this: *mut core::ffi::c_void, bstrpropertyname: core::mem::MaybeUninit<windows_core::BSTR>, propertyvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventPublisher", "GetDefaultProperty", 17usize, |this| ((**(this as *const *const IEventPublisher_Vtbl)).GetDefaultProperty)(this, bstrpropertyname, propertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutDefaultProperty(this: *mut core::ffi::c_void, bstrpropertyname: core::mem::MaybeUninit<windows_core::BSTR>, propertyvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventPublisher", "PutDefaultProperty", 18usize, |this| ((**(this as *const *const IEventPublisher_Vtbl)).PutDefaultProperty)(this, bstrpropertyname, propertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDefaultProperty(this: *mut core::ffi::c_void, bstrpropertyname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventPublisher", "RemoveDefaultProperty", 19usize, |this| ((**(this as *const *const IEventPublisher_Vtbl)).RemoveDefaultProperty)(this, bstrpropertyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultPropertyCollection(this: *mut core::ffi::c_void, collection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventPublisher", "GetDefaultPropertyCollection", 20usize, |this| ((**(this as *const *const IEventPublisher_Vtbl)).GetDefaultPropertyCollection)(this, collection), |result| Some(*result))
+        }
+        Self {
+            base__: <super::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PublisherID,
+            SetPublisherID,
+            PublisherName,
+            SetPublisherName,
+            PublisherType,
+            SetPublisherType,
+            OwnerSID,
+            SetOwnerSID,
+            Description,
+            SetDescription,
+            GetDefaultProperty,
+            PutDefaultProperty,
+            RemoveDefaultProperty,
+            GetDefaultPropertyCollection,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEventPublisher as windows_core::Interface>::IID || <super::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEventSubscription_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SubscriptionID(this: *mut core::ffi::c_void, pbstrsubscriptionid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "SubscriptionID", 7usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).SubscriptionID)(this, pbstrsubscriptionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubscriptionID(this: *mut core::ffi::c_void, bstrsubscriptionid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "SetSubscriptionID", 8usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).SetSubscriptionID)(this, bstrsubscriptionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubscriptionName(this: *mut core::ffi::c_void, pbstrsubscriptionname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "SubscriptionName", 9usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).SubscriptionName)(this, pbstrsubscriptionname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubscriptionName(this: *mut core::ffi::c_void, bstrsubscriptionname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "SetSubscriptionName", 10usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).SetSubscriptionName)(this, bstrsubscriptionname), |result| Some(*result))
+        }
+        unsafe extern "system" fn PublisherID(this: *mut core::ffi::c_void, pbstrpublisherid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "PublisherID", 11usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).PublisherID)(this, pbstrpublisherid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPublisherID(this: *mut core::ffi::c_void, bstrpublisherid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "SetPublisherID", 12usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).SetPublisherID)(this, bstrpublisherid), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventClassID(this: *mut core::ffi::c_void, pbstreventclassid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "EventClassID", 13usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).EventClassID)(this, pbstreventclassid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventClassID(this: *mut core::ffi::c_void, bstreventclassid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "SetEventClassID", 14usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).SetEventClassID)(this, bstreventclassid), |result| Some(*result))
+        }
+        unsafe extern "system" fn MethodName(this: *mut core::ffi::c_void, pbstrmethodname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "MethodName", 15usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).MethodName)(this, pbstrmethodname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMethodName(this: *mut core::ffi::c_void, bstrmethodname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "SetMethodName", 16usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).SetMethodName)(this, bstrmethodname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubscriberCLSID(this: *mut core::ffi::c_void, pbstrsubscriberclsid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "SubscriberCLSID", 17usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).SubscriberCLSID)(this, pbstrsubscriberclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubscriberCLSID(this: *mut core::ffi::c_void, bstrsubscriberclsid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "SetSubscriberCLSID", 18usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).SetSubscriberCLSID)(this, bstrsubscriberclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubscriberInterface(this: *mut core::ffi::c_void, ppsubscriberinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "SubscriberInterface", 19usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).SubscriberInterface)(this, ppsubscriberinterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubscriberInterface(this: *mut core::ffi::c_void, psubscriberinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "SetSubscriberInterface", 20usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).SetSubscriberInterface)(this, psubscriberinterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn PerUser(this: *mut core::ffi::c_void, pfperuser: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "PerUser", 21usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).PerUser)(this, pfperuser), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPerUser(this: *mut core::ffi::c_void, fperuser: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "SetPerUser", 22usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).SetPerUser)(this, fperuser), |result| Some(*result))
+        }
+        unsafe extern "system" fn OwnerSID(this: *mut core::ffi::c_void, pbstrownersid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "OwnerSID", 23usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).OwnerSID)(this, pbstrownersid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOwnerSID(this: *mut core::ffi::c_void, bstrownersid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "SetOwnerSID", 24usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).SetOwnerSID)(this, bstrownersid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, pfenabled: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "Enabled", 25usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).Enabled)(this, pfenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, fenabled: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "SetEnabled", 26usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).SetEnabled)(this, fenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, pbstrdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "Description", 27usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).Description)(this, pbstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, bstrdescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "SetDescription", 28usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).SetDescription)(this, bstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn MachineName(this: *mut core::ffi::c_void, pbstrmachinename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "MachineName", 29usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).MachineName)(this, pbstrmachinename), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMachineName(this: *mut core::ffi::c_void, bstrmachinename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "SetMachineName", 30usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).SetMachineName)(this, bstrmachinename), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPublisherProperty(this: *mut core::ffi::c_void, bstrpropertyname: core::mem::MaybeUninit<windows_core::BSTR>, propertyvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "GetPublisherProperty", 31usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).GetPublisherProperty)(this, bstrpropertyname, propertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutPublisherProperty(this: *mut core::ffi::c_void, bstrpropertyname: core::mem::MaybeUninit<windows_core::BSTR>, propertyvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "PutPublisherProperty", 32usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).PutPublisherProperty)(this, bstrpropertyname, propertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePublisherProperty(this: *mut core::ffi::c_void, bstrpropertyname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "RemovePublisherProperty", 33usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).RemovePublisherProperty)(this, bstrpropertyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPublisherPropertyCollection(this: *mut core::ffi::c_void, collection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "GetPublisherPropertyCollection", 34usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).GetPublisherPropertyCollection)(this, collection), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubscriberProperty(this: *mut core::ffi::c_void, bstrpropertyname: core::mem::MaybeUninit<windows_core::BSTR>, propertyvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "GetSubscriberProperty", 35usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).GetSubscriberProperty)(this, bstrpropertyname, propertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutSubscriberProperty(this: *mut core::ffi::c_void, bstrpropertyname: core::mem::MaybeUninit<windows_core::BSTR>, propertyvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "PutSubscriberProperty", 36usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).PutSubscriberProperty)(this, bstrpropertyname, propertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSubscriberProperty(this: *mut core::ffi::c_void, bstrpropertyname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "RemoveSubscriberProperty", 37usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).RemoveSubscriberProperty)(this, bstrpropertyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubscriberPropertyCollection(this: *mut core::ffi::c_void, collection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "GetSubscriberPropertyCollection", 38usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).GetSubscriberPropertyCollection)(this, collection), |result| Some(*result))
+        }
+        unsafe extern "system" fn InterfaceID(this: *mut core::ffi::c_void, pbstrinterfaceid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "InterfaceID", 39usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).InterfaceID)(this, pbstrinterfaceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInterfaceID(this: *mut core::ffi::c_void, bstrinterfaceid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSubscription", "SetInterfaceID", 40usize, |this| ((**(this as *const *const IEventSubscription_Vtbl)).SetInterfaceID)(this, bstrinterfaceid), |result| Some(*result))
+        }
+        Self {
+            base__: <super::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SubscriptionID,
+            SetSubscriptionID,
+            SubscriptionName,
+            SetSubscriptionName,
+            PublisherID,
+            SetPublisherID,
+            EventClassID,
+            SetEventClassID,
+            MethodName,
+            SetMethodName,
+            SubscriberCLSID,
+            SetSubscriberCLSID,
+            SubscriberInterface,
+            SetSubscriberInterface,
+            PerUser,
+            SetPerUser,
+            OwnerSID,
+            SetOwnerSID,
+            Enabled,
+            SetEnabled,
+            Description,
+            SetDescription,
+            MachineName,
+            SetMachineName,
+            GetPublisherProperty,
+            PutPublisherProperty,
+            RemovePublisherProperty,
+            GetPublisherPropertyCollection,
+            GetSubscriberProperty,
+            PutSubscriberProperty,
+            RemoveSubscriberProperty,
+            GetSubscriberPropertyCollection,
+            InterfaceID,
+            SetInterfaceID,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEventSubscription as windows_core::Interface>::IID || <super::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEventSystem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Query(this: *mut core::ffi::c_void, progid: core::mem::MaybeUninit<windows_core::BSTR>, querycriteria: core::mem::MaybeUninit<windows_core::BSTR>, errorindex: *mut i32, ppinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSystem", "Query", 7usize, |this| ((**(this as *const *const IEventSystem_Vtbl)).Query)(this, progid, querycriteria, errorindex, ppinterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn Store(this: *mut core::ffi::c_void, progid: core::mem::MaybeUninit<windows_core::BSTR>, pinterface: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSystem", "Store", 8usize, |this| ((**(this as *const *const IEventSystem_Vtbl)).Store)(this, progid, pinterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, progid: core::mem::MaybeUninit<windows_core::BSTR>, querycriteria: core::mem::MaybeUninit<windows_core::BSTR>, errorindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSystem", "Remove", 9usize, |this| ((**(this as *const *const IEventSystem_Vtbl)).Remove)(this, progid, querycriteria, errorindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventObjectChangeEventClassID(this: *mut core::ffi::c_void, pbstreventclassid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSystem", "EventObjectChangeEventClassID", 10usize, |this| ((**(this as *const *const IEventSystem_Vtbl)).EventObjectChangeEventClassID)(this, pbstreventclassid), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryS(this: *mut core::ffi::c_void, progid: core::mem::MaybeUninit<windows_core::BSTR>, querycriteria: core::mem::MaybeUninit<windows_core::BSTR>, ppinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSystem", "QueryS", 11usize, |this| ((**(this as *const *const IEventSystem_Vtbl)).QueryS)(this, progid, querycriteria, ppinterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveS(this: *mut core::ffi::c_void, progid: core::mem::MaybeUninit<windows_core::BSTR>, querycriteria: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventSystem", "RemoveS", 12usize, |this| ((**(this as *const *const IEventSystem_Vtbl)).RemoveS)(this, progid, querycriteria), |result| Some(*result))
+        }
+        Self { base__: <super::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Query, Store, Remove, EventObjectChangeEventClassID, QueryS, RemoveS }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEventSystem as windows_core::Interface>::IID || <super::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFiringControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FireSubscription(this: *mut core::ffi::c_void, subscription: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFiringControl", "FireSubscription", 7usize, |this| ((**(this as *const *const IFiringControl_Vtbl)).FireSubscription)(this, subscription), |result| Some(*result))
+        }
+        Self { base__: <super::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FireSubscription }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFiringControl as windows_core::Interface>::IID || <super::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMultiInterfaceEventControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMultiInterfacePublisherFilter(this: *mut core::ffi::c_void, classfilter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiInterfaceEventControl", "SetMultiInterfacePublisherFilter", 3usize, |this| ((**(this as *const *const IMultiInterfaceEventControl_Vtbl)).SetMultiInterfacePublisherFilter)(this, classfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubscriptions(this: *mut core::ffi::c_void, eventiid: *const windows_core::GUID, bstrmethodname: core::mem::MaybeUninit<windows_core::BSTR>, optionalcriteria: core::mem::MaybeUninit<windows_core::BSTR>, optionalerrorindex: *const i32, ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiInterfaceEventControl", "GetSubscriptions", 4usize, |this| ((**(this as *const *const IMultiInterfaceEventControl_Vtbl)).GetSubscriptions)(this, eventiid, bstrmethodname, optionalcriteria, optionalerrorindex, ppcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultQuery(this: *mut core::ffi::c_void, eventiid: *const windows_core::GUID, bstrmethodname: core::mem::MaybeUninit<windows_core::BSTR>, bstrcriteria: core::mem::MaybeUninit<windows_core::BSTR>, errorindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiInterfaceEventControl", "SetDefaultQuery", 5usize, |this| ((**(this as *const *const IMultiInterfaceEventControl_Vtbl)).SetDefaultQuery)(this, eventiid, bstrmethodname, bstrcriteria, errorindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowInprocActivation(this: *mut core::ffi::c_void, pfallowinprocactivation: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiInterfaceEventControl", "AllowInprocActivation", 6usize, |this| ((**(this as *const *const IMultiInterfaceEventControl_Vtbl)).AllowInprocActivation)(this, pfallowinprocactivation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowInprocActivation(this: *mut core::ffi::c_void, fallowinprocactivation: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiInterfaceEventControl", "SetAllowInprocActivation", 7usize, |this| ((**(this as *const *const IMultiInterfaceEventControl_Vtbl)).SetAllowInprocActivation)(this, fallowinprocactivation), |result| Some(*result))
+        }
+        unsafe extern "system" fn FireInParallel(this: *mut core::ffi::c_void, pffireinparallel: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiInterfaceEventControl", "FireInParallel", 8usize, |this| ((**(this as *const *const IMultiInterfaceEventControl_Vtbl)).FireInParallel)(this, pffireinparallel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFireInParallel(this: *mut core::ffi::c_void, ffireinparallel: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiInterfaceEventControl", "SetFireInParallel", 9usize, |this| ((**(this as *const *const IMultiInterfaceEventControl_Vtbl)).SetFireInParallel)(this, ffireinparallel), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetMultiInterfacePublisherFilter,
+            GetSubscriptions,
+            SetDefaultQuery,
+            AllowInprocActivation,
+            SetAllowInprocActivation,
+            FireInParallel,
+            SetFireInParallel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMultiInterfaceEventControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMultiInterfacePublisherFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, peic: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiInterfacePublisherFilter", "Initialize", 3usize, |this| ((**(this as *const *const IMultiInterfacePublisherFilter_Vtbl)).Initialize)(this, peic), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrepareToFire(this: *mut core::ffi::c_void, iid: *const windows_core::GUID, methodname: core::mem::MaybeUninit<windows_core::BSTR>, firingcontrol: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiInterfacePublisherFilter", "PrepareToFire", 4usize, |this| ((**(this as *const *const IMultiInterfacePublisherFilter_Vtbl)).PrepareToFire)(this, iid, methodname, firingcontrol), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, PrepareToFire }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMultiInterfacePublisherFilter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPublisherFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, methodname: core::mem::MaybeUninit<windows_core::BSTR>, dispuserdefined: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPublisherFilter", "Initialize", 3usize, |this| ((**(this as *const *const IPublisherFilter_Vtbl)).Initialize)(this, methodname, dispuserdefined), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrepareToFire(this: *mut core::ffi::c_void, methodname: core::mem::MaybeUninit<windows_core::BSTR>, firingcontrol: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPublisherFilter", "PrepareToFire", 4usize, |this| ((**(this as *const *const IPublisherFilter_Vtbl)).PrepareToFire)(this, methodname, firingcontrol), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, PrepareToFire }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPublisherFilter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
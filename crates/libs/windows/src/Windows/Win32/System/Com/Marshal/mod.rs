@@ -742,6 +742,7 @@ pub struct STDMSHLFLAGS(pub i32);
 impl windows_core::TypeKind for STDMSHLFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STDMSHLFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STDMSHLFLAGS").field(&self.0).finish()
@@ -749,3 +750,5 @@ impl core::fmt::Debug for STDMSHLFLAGS {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
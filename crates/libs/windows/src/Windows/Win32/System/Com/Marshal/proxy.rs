@@ -0,0 +1,54 @@
+impl windows_core::imp::ProxyVtable for IMarshal_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetUnmarshalClass(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, pv: *const core::ffi::c_void, dwdestcontext: u32, pvdestcontext: *const core::ffi::c_void, mshlflags: u32, pcid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMarshal", "GetUnmarshalClass", 3usize, |this| ((**(this as *const *const IMarshal_Vtbl)).GetUnmarshalClass)(this, riid, pv, dwdestcontext, pvdestcontext, mshlflags, pcid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMarshalSizeMax(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, pv: *const core::ffi::c_void, dwdestcontext: u32, pvdestcontext: *const core::ffi::c_void, mshlflags: u32, psize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMarshal", "GetMarshalSizeMax", 4usize, |this| ((**(this as *const *const IMarshal_Vtbl)).GetMarshalSizeMax)(this, riid, pv, dwdestcontext, pvdestcontext, mshlflags, psize), |result| Some(*result))
+        }
+        unsafe extern "system" fn MarshalInterface(this: *mut core::ffi::c_void, pstm: *mut core::ffi::c_void, riid: *const windows_core::GUID, pv: *const core::ffi::c_void, dwdestcontext: u32, pvdestcontext: *const core::ffi::c_void, mshlflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMarshal", "MarshalInterface", 5usize, |this| ((**(this as *const *const IMarshal_Vtbl)).MarshalInterface)(this, pstm, riid, pv, dwdestcontext, pvdestcontext, mshlflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnmarshalInterface(this: *mut core::ffi::c_void, pstm: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMarshal", "UnmarshalInterface", 6usize, |this| ((**(this as *const *const IMarshal_Vtbl)).UnmarshalInterface)(this, pstm, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseMarshalData(this: *mut core::ffi::c_void, pstm: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMarshal", "ReleaseMarshalData", 7usize, |this| ((**(this as *const *const IMarshal_Vtbl)).ReleaseMarshalData)(this, pstm), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisconnectObject(this: *mut core::ffi::c_void, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMarshal", "DisconnectObject", 8usize, |this| ((**(this as *const *const IMarshal_Vtbl)).DisconnectObject)(this, dwreserved), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetUnmarshalClass,
+            GetMarshalSizeMax,
+            MarshalInterface,
+            UnmarshalInterface,
+            ReleaseMarshalData,
+            DisconnectObject,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMarshal as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMarshal2_Vtbl {
+    const PROXY: Self = { Self { base__: <IMarshal_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMarshal2 as windows_core::Interface>::IID || <IMarshal_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMarshalingStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMarshalingContextAttribute(this: *mut core::ffi::c_void, attribute: super::CO_MARSHALING_CONTEXT_ATTRIBUTES, pattributevalue: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMarshalingStream", "GetMarshalingContextAttribute", 14usize, |this| ((**(this as *const *const IMarshalingStream_Vtbl)).GetMarshalingContextAttribute)(this, attribute, pattributevalue), |result| Some(*result))
+        }
+        Self { base__: <super::IStream_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMarshalingContextAttribute }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMarshalingStream as windows_core::Interface>::IID || <super::IStream_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
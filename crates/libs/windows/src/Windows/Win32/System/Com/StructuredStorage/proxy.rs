@@ -0,0 +1,423 @@
+impl windows_core::imp::ProxyVtable for IDirectWriterLock_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WaitForWriteAccess(this: *mut core::ffi::c_void, dwtimeout: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectWriterLock", "WaitForWriteAccess", 3usize, |this| ((**(this as *const *const IDirectWriterLock_Vtbl)).WaitForWriteAccess)(this, dwtimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseWriteAccess(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectWriterLock", "ReleaseWriteAccess", 4usize, |this| ((**(this as *const *const IDirectWriterLock_Vtbl)).ReleaseWriteAccess)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn HaveWriteAccess(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirectWriterLock", "HaveWriteAccess", 5usize, |this| ((**(this as *const *const IDirectWriterLock_Vtbl)).HaveWriteAccess)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WaitForWriteAccess, ReleaseWriteAccess, HaveWriteAccess }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirectWriterLock as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumSTATPROPSETSTG_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut STATPROPSETSTG, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSTATPROPSETSTG", "Next", 3usize, |this| ((**(this as *const *const IEnumSTATPROPSETSTG_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSTATPROPSETSTG", "Skip", 4usize, |this| ((**(this as *const *const IEnumSTATPROPSETSTG_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSTATPROPSETSTG", "Reset", 5usize, |this| ((**(this as *const *const IEnumSTATPROPSETSTG_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSTATPROPSETSTG", "Clone", 6usize, |this| ((**(this as *const *const IEnumSTATPROPSETSTG_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumSTATPROPSETSTG as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Variant")]
+impl windows_core::imp::ProxyVtable for IEnumSTATPROPSTG_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Variant")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut STATPROPSTG, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSTATPROPSTG", "Next", 3usize, |this| ((**(this as *const *const IEnumSTATPROPSTG_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSTATPROPSTG", "Skip", 4usize, |this| ((**(this as *const *const IEnumSTATPROPSTG_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSTATPROPSTG", "Reset", 5usize, |this| ((**(this as *const *const IEnumSTATPROPSTG_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSTATPROPSTG", "Clone", 6usize, |this| ((**(this as *const *const IEnumSTATPROPSTG_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Variant")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Variant"))]
+            Next: 0,
+            Skip,
+            Reset,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumSTATPROPSTG as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumSTATSTG_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut super::STATSTG, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSTATSTG", "Next", 3usize, |this| ((**(this as *const *const IEnumSTATSTG_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSTATSTG", "Skip", 4usize, |this| ((**(this as *const *const IEnumSTATSTG_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSTATSTG", "Reset", 5usize, |this| ((**(this as *const *const IEnumSTATSTG_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSTATSTG", "Clone", 6usize, |this| ((**(this as *const *const IEnumSTATSTG_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumSTATSTG as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFillLockBytes_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FillAppend(this: *mut core::ffi::c_void, pv: *const core::ffi::c_void, cb: u32, pcbwritten: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFillLockBytes", "FillAppend", 3usize, |this| ((**(this as *const *const IFillLockBytes_Vtbl)).FillAppend)(this, pv, cb, pcbwritten), |result| Some(*result))
+        }
+        unsafe extern "system" fn FillAt(this: *mut core::ffi::c_void, uloffset: u64, pv: *const core::ffi::c_void, cb: u32, pcbwritten: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFillLockBytes", "FillAt", 4usize, |this| ((**(this as *const *const IFillLockBytes_Vtbl)).FillAt)(this, uloffset, pv, cb, pcbwritten), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFillSize(this: *mut core::ffi::c_void, ulsize: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFillLockBytes", "SetFillSize", 5usize, |this| ((**(this as *const *const IFillLockBytes_Vtbl)).SetFillSize)(this, ulsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn Terminate(this: *mut core::ffi::c_void, bcanceled: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFillLockBytes", "Terminate", 6usize, |this| ((**(this as *const *const IFillLockBytes_Vtbl)).Terminate)(this, bcanceled), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FillAppend, FillAt, SetFillSize, Terminate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFillLockBytes as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILayoutStorage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LayoutScript(this: *mut core::ffi::c_void, pstoragelayout: *const super::StorageLayout, nentries: u32, glfinterleavedflag: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILayoutStorage", "LayoutScript", 3usize, |this| ((**(this as *const *const ILayoutStorage_Vtbl)).LayoutScript)(this, pstoragelayout, nentries, glfinterleavedflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginMonitor(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILayoutStorage", "BeginMonitor", 4usize, |this| ((**(this as *const *const ILayoutStorage_Vtbl)).BeginMonitor)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndMonitor(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILayoutStorage", "EndMonitor", 5usize, |this| ((**(this as *const *const ILayoutStorage_Vtbl)).EndMonitor)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReLayoutDocfile(this: *mut core::ffi::c_void, pwcsnewdfname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILayoutStorage", "ReLayoutDocfile", 6usize, |this| ((**(this as *const *const ILayoutStorage_Vtbl)).ReLayoutDocfile)(this, pwcsnewdfname), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReLayoutDocfileOnILockBytes(this: *mut core::ffi::c_void, pilockbytes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILayoutStorage", "ReLayoutDocfileOnILockBytes", 7usize, |this| ((**(this as *const *const ILayoutStorage_Vtbl)).ReLayoutDocfileOnILockBytes)(this, pilockbytes), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LayoutScript,
+            BeginMonitor,
+            EndMonitor,
+            ReLayoutDocfile,
+            ReLayoutDocfileOnILockBytes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILayoutStorage as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILockBytes_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReadAt(this: *mut core::ffi::c_void, uloffset: u64, pv: *mut core::ffi::c_void, cb: u32, pcbread: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockBytes", "ReadAt", 3usize, |this| ((**(this as *const *const ILockBytes_Vtbl)).ReadAt)(this, uloffset, pv, cb, pcbread), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteAt(this: *mut core::ffi::c_void, uloffset: u64, pv: *const core::ffi::c_void, cb: u32, pcbwritten: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockBytes", "WriteAt", 4usize, |this| ((**(this as *const *const ILockBytes_Vtbl)).WriteAt)(this, uloffset, pv, cb, pcbwritten), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flush(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockBytes", "Flush", 5usize, |this| ((**(this as *const *const ILockBytes_Vtbl)).Flush)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSize(this: *mut core::ffi::c_void, cb: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockBytes", "SetSize", 6usize, |this| ((**(this as *const *const ILockBytes_Vtbl)).SetSize)(this, cb), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockRegion(this: *mut core::ffi::c_void, liboffset: u64, cb: u64, dwlocktype: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockBytes", "LockRegion", 7usize, |this| ((**(this as *const *const ILockBytes_Vtbl)).LockRegion)(this, liboffset, cb, dwlocktype), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockRegion(this: *mut core::ffi::c_void, liboffset: u64, cb: u64, dwlocktype: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockBytes", "UnlockRegion", 8usize, |this| ((**(this as *const *const ILockBytes_Vtbl)).UnlockRegion)(this, liboffset, cb, dwlocktype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stat(this: *mut core::ffi::c_void, pstatstg: *mut super::STATSTG, grfstatflag: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILockBytes", "Stat", 9usize, |this| ((**(this as *const *const ILockBytes_Vtbl)).Stat)(this, pstatstg, grfstatflag), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReadAt, WriteAt, Flush, SetSize, LockRegion, UnlockRegion, Stat }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILockBytes as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPersistStorage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsDirty(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistStorage", "IsDirty", 4usize, |this| ((**(this as *const *const IPersistStorage_Vtbl)).IsDirty)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitNew(this: *mut core::ffi::c_void, pstg: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistStorage", "InitNew", 5usize, |this| ((**(this as *const *const IPersistStorage_Vtbl)).InitNew)(this, pstg), |result| Some(*result))
+        }
+        unsafe extern "system" fn Load(this: *mut core::ffi::c_void, pstg: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistStorage", "Load", 6usize, |this| ((**(this as *const *const IPersistStorage_Vtbl)).Load)(this, pstg), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void, pstgsave: *mut core::ffi::c_void, fsameasload: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistStorage", "Save", 7usize, |this| ((**(this as *const *const IPersistStorage_Vtbl)).Save)(this, pstgsave, fsameasload), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveCompleted(this: *mut core::ffi::c_void, pstgnew: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistStorage", "SaveCompleted", 8usize, |this| ((**(this as *const *const IPersistStorage_Vtbl)).SaveCompleted)(this, pstgnew), |result| Some(*result))
+        }
+        unsafe extern "system" fn HandsOffStorage(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistStorage", "HandsOffStorage", 9usize, |this| ((**(this as *const *const IPersistStorage_Vtbl)).HandsOffStorage)(this), |result| Some(*result))
+        }
+        Self { base__: <super::IPersist_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsDirty, InitNew, Load, Save, SaveCompleted, HandsOffStorage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPersistStorage as windows_core::Interface>::IID || <super::IPersist_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPropertyBag_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Read(this: *mut core::ffi::c_void, pszpropname: windows_core::PCWSTR, pvar: *mut core::mem::MaybeUninit<windows_core::VARIANT>, perrorlog: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyBag", "Read", 3usize, |this| ((**(this as *const *const IPropertyBag_Vtbl)).Read)(this, pszpropname, pvar, perrorlog), |result| Some(*result))
+        }
+        unsafe extern "system" fn Write(this: *mut core::ffi::c_void, pszpropname: windows_core::PCWSTR, pvar: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyBag", "Write", 4usize, |this| ((**(this as *const *const IPropertyBag_Vtbl)).Write)(this, pszpropname, pvar), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Read, Write }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertyBag as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Variant")]
+impl windows_core::imp::ProxyVtable for IPropertyBag2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Variant")]
+        unsafe extern "system" fn Read(this: *mut core::ffi::c_void, cproperties: u32, ppropbag: *const PROPBAG2, perrlog: *mut core::ffi::c_void, pvarvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>, phrerror: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyBag2", "Read", 3usize, |this| ((**(this as *const *const IPropertyBag2_Vtbl)).Read)(this, cproperties, ppropbag, perrlog, pvarvalue, phrerror), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Variant")]
+        unsafe extern "system" fn Write(this: *mut core::ffi::c_void, cproperties: u32, ppropbag: *const PROPBAG2, pvarvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyBag2", "Write", 4usize, |this| ((**(this as *const *const IPropertyBag2_Vtbl)).Write)(this, cproperties, ppropbag, pvarvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn CountProperties(this: *mut core::ffi::c_void, pcproperties: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyBag2", "CountProperties", 5usize, |this| ((**(this as *const *const IPropertyBag2_Vtbl)).CountProperties)(this, pcproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Variant")]
+        unsafe extern "system" fn GetPropertyInfo(this: *mut core::ffi::c_void, iproperty: u32, cproperties: u32, ppropbag: *mut PROPBAG2, pcproperties: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyBag2", "GetPropertyInfo", 6usize, |this| ((**(this as *const *const IPropertyBag2_Vtbl)).GetPropertyInfo)(this, iproperty, cproperties, ppropbag, pcproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadObject(this: *mut core::ffi::c_void, pstrname: windows_core::PCWSTR, dwhint: u32, punkobject: *mut core::ffi::c_void, perrlog: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyBag2", "LoadObject", 7usize, |this| ((**(this as *const *const IPropertyBag2_Vtbl)).LoadObject)(this, pstrname, dwhint, punkobject, perrlog), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Variant")]
+            Read,
+            #[cfg(not(feature = "Win32_System_Variant"))]
+            Read: 0,
+            #[cfg(feature = "Win32_System_Variant")]
+            Write,
+            #[cfg(not(feature = "Win32_System_Variant"))]
+            Write: 0,
+            CountProperties,
+            #[cfg(feature = "Win32_System_Variant")]
+            GetPropertyInfo,
+            #[cfg(not(feature = "Win32_System_Variant"))]
+            GetPropertyInfo: 0,
+            LoadObject,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertyBag2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPropertySetStorage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, rfmtid: *const windows_core::GUID, pclsid: *const windows_core::GUID, grfflags: u32, grfmode: u32, ppprstg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertySetStorage", "Create", 3usize, |this| ((**(this as *const *const IPropertySetStorage_Vtbl)).Create)(this, rfmtid, pclsid, grfflags, grfmode, ppprstg), |result| Some(*result))
+        }
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, rfmtid: *const windows_core::GUID, grfmode: u32, ppprstg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertySetStorage", "Open", 4usize, |this| ((**(this as *const *const IPropertySetStorage_Vtbl)).Open)(this, rfmtid, grfmode, ppprstg), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, rfmtid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertySetStorage", "Delete", 5usize, |this| ((**(this as *const *const IPropertySetStorage_Vtbl)).Delete)(this, rfmtid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enum(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertySetStorage", "Enum", 6usize, |this| ((**(this as *const *const IPropertySetStorage_Vtbl)).Enum)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create, Open, Delete, Enum }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertySetStorage as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPropertyStorage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReadMultiple(this: *mut core::ffi::c_void, cpspec: u32, rgpspec: *const PROPSPEC, rgpropvar: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStorage", "ReadMultiple", 3usize, |this| ((**(this as *const *const IPropertyStorage_Vtbl)).ReadMultiple)(this, cpspec, rgpspec, rgpropvar), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteMultiple(this: *mut core::ffi::c_void, cpspec: u32, rgpspec: *const PROPSPEC, rgpropvar: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, propidnamefirst: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStorage", "WriteMultiple", 4usize, |this| ((**(this as *const *const IPropertyStorage_Vtbl)).WriteMultiple)(this, cpspec, rgpspec, rgpropvar, propidnamefirst), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteMultiple(this: *mut core::ffi::c_void, cpspec: u32, rgpspec: *const PROPSPEC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStorage", "DeleteMultiple", 5usize, |this| ((**(this as *const *const IPropertyStorage_Vtbl)).DeleteMultiple)(this, cpspec, rgpspec), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadPropertyNames(this: *mut core::ffi::c_void, cpropid: u32, rgpropid: *const u32, rglpwstrname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStorage", "ReadPropertyNames", 6usize, |this| ((**(this as *const *const IPropertyStorage_Vtbl)).ReadPropertyNames)(this, cpropid, rgpropid, rglpwstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn WritePropertyNames(this: *mut core::ffi::c_void, cpropid: u32, rgpropid: *const u32, rglpwstrname: *const windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStorage", "WritePropertyNames", 7usize, |this| ((**(this as *const *const IPropertyStorage_Vtbl)).WritePropertyNames)(this, cpropid, rgpropid, rglpwstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeletePropertyNames(this: *mut core::ffi::c_void, cpropid: u32, rgpropid: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStorage", "DeletePropertyNames", 8usize, |this| ((**(this as *const *const IPropertyStorage_Vtbl)).DeletePropertyNames)(this, cpropid, rgpropid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void, grfcommitflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStorage", "Commit", 9usize, |this| ((**(this as *const *const IPropertyStorage_Vtbl)).Commit)(this, grfcommitflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Revert(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStorage", "Revert", 10usize, |this| ((**(this as *const *const IPropertyStorage_Vtbl)).Revert)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enum(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStorage", "Enum", 11usize, |this| ((**(this as *const *const IPropertyStorage_Vtbl)).Enum)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTimes(this: *mut core::ffi::c_void, pctime: *const super::super::super::Foundation::FILETIME, patime: *const super::super::super::Foundation::FILETIME, pmtime: *const super::super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStorage", "SetTimes", 12usize, |this| ((**(this as *const *const IPropertyStorage_Vtbl)).SetTimes)(this, pctime, patime, pmtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClass(this: *mut core::ffi::c_void, clsid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStorage", "SetClass", 13usize, |this| ((**(this as *const *const IPropertyStorage_Vtbl)).SetClass)(this, clsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stat(this: *mut core::ffi::c_void, pstatpsstg: *mut STATPROPSETSTG) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStorage", "Stat", 14usize, |this| ((**(this as *const *const IPropertyStorage_Vtbl)).Stat)(this, pstatpsstg), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReadMultiple,
+            WriteMultiple,
+            DeleteMultiple,
+            ReadPropertyNames,
+            WritePropertyNames,
+            DeletePropertyNames,
+            Commit,
+            Revert,
+            Enum,
+            SetTimes,
+            SetClass,
+            Stat,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertyStorage as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRootStorage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SwitchToFile(this: *mut core::ffi::c_void, pszfile: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRootStorage", "SwitchToFile", 3usize, |this| ((**(this as *const *const IRootStorage_Vtbl)).SwitchToFile)(this, pszfile), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SwitchToFile }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRootStorage as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateStream(this: *mut core::ffi::c_void, pwcsname: windows_core::PCWSTR, grfmode: super::STGM, reserved1: u32, reserved2: u32, ppstm: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorage", "CreateStream", 3usize, |this| ((**(this as *const *const IStorage_Vtbl)).CreateStream)(this, pwcsname, grfmode, reserved1, reserved2, ppstm), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenStream(this: *mut core::ffi::c_void, pwcsname: windows_core::PCWSTR, reserved1: *const core::ffi::c_void, grfmode: super::STGM, reserved2: u32, ppstm: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorage", "OpenStream", 4usize, |this| ((**(this as *const *const IStorage_Vtbl)).OpenStream)(this, pwcsname, reserved1, grfmode, reserved2, ppstm), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateStorage(this: *mut core::ffi::c_void, pwcsname: windows_core::PCWSTR, grfmode: super::STGM, reserved1: u32, reserved2: u32, ppstg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorage", "CreateStorage", 5usize, |this| ((**(this as *const *const IStorage_Vtbl)).CreateStorage)(this, pwcsname, grfmode, reserved1, reserved2, ppstg), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenStorage(this: *mut core::ffi::c_void, pwcsname: windows_core::PCWSTR, pstgpriority: *mut core::ffi::c_void, grfmode: super::STGM, snbexclude: *const *const u16, reserved: u32, ppstg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorage", "OpenStorage", 6usize, |this| ((**(this as *const *const IStorage_Vtbl)).OpenStorage)(this, pwcsname, pstgpriority, grfmode, snbexclude, reserved, ppstg), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyTo(this: *mut core::ffi::c_void, ciidexclude: u32, rgiidexclude: *const windows_core::GUID, snbexclude: *const *const u16, pstgdest: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorage", "CopyTo", 7usize, |this| ((**(this as *const *const IStorage_Vtbl)).CopyTo)(this, ciidexclude, rgiidexclude, snbexclude, pstgdest), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveElementTo(this: *mut core::ffi::c_void, pwcsname: windows_core::PCWSTR, pstgdest: *mut core::ffi::c_void, pwcsnewname: windows_core::PCWSTR, grfflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorage", "MoveElementTo", 8usize, |this| ((**(this as *const *const IStorage_Vtbl)).MoveElementTo)(this, pwcsname, pstgdest, pwcsnewname, grfflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void, grfcommitflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorage", "Commit", 9usize, |this| ((**(this as *const *const IStorage_Vtbl)).Commit)(this, grfcommitflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Revert(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorage", "Revert", 10usize, |this| ((**(this as *const *const IStorage_Vtbl)).Revert)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumElements(this: *mut core::ffi::c_void, reserved1: u32, reserved2: *const core::ffi::c_void, reserved3: u32, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorage", "EnumElements", 11usize, |this| ((**(this as *const *const IStorage_Vtbl)).EnumElements)(this, reserved1, reserved2, reserved3, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestroyElement(this: *mut core::ffi::c_void, pwcsname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorage", "DestroyElement", 12usize, |this| ((**(this as *const *const IStorage_Vtbl)).DestroyElement)(this, pwcsname), |result| Some(*result))
+        }
+        unsafe extern "system" fn RenameElement(this: *mut core::ffi::c_void, pwcsoldname: windows_core::PCWSTR, pwcsnewname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorage", "RenameElement", 13usize, |this| ((**(this as *const *const IStorage_Vtbl)).RenameElement)(this, pwcsoldname, pwcsnewname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetElementTimes(this: *mut core::ffi::c_void, pwcsname: windows_core::PCWSTR, pctime: *const super::super::super::Foundation::FILETIME, patime: *const super::super::super::Foundation::FILETIME, pmtime: *const super::super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorage", "SetElementTimes", 14usize, |this| ((**(this as *const *const IStorage_Vtbl)).SetElementTimes)(this, pwcsname, pctime, patime, pmtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClass(this: *mut core::ffi::c_void, clsid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorage", "SetClass", 15usize, |this| ((**(this as *const *const IStorage_Vtbl)).SetClass)(this, clsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStateBits(this: *mut core::ffi::c_void, grfstatebits: u32, grfmask: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorage", "SetStateBits", 16usize, |this| ((**(this as *const *const IStorage_Vtbl)).SetStateBits)(this, grfstatebits, grfmask), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stat(this: *mut core::ffi::c_void, pstatstg: *mut super::STATSTG, grfstatflag: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorage", "Stat", 17usize, |this| ((**(this as *const *const IStorage_Vtbl)).Stat)(this, pstatstg, grfstatflag), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateStream,
+            OpenStream,
+            CreateStorage,
+            OpenStorage,
+            CopyTo,
+            MoveElementTo,
+            Commit,
+            Revert,
+            EnumElements,
+            DestroyElement,
+            RenameElement,
+            SetElementTimes,
+            SetClass,
+            SetStateBits,
+            Stat,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorage as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -62,3 +62,5 @@ pub struct IThumbnailExtractor_Vtbl {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,48 @@
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+impl windows_core::imp::ProxyVtable for IDummyHICONIncluder_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+        unsafe extern "system" fn Dummy(this: *mut core::ffi::c_void, h1: super::super::super::UI::WindowsAndMessaging::HICON, h2: super::super::super::Graphics::Gdi::HDC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDummyHICONIncluder", "Dummy", 3usize, |this| ((**(this as *const *const IDummyHICONIncluder_Vtbl)).Dummy)(this, h1, h2), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+            Dummy,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging")))]
+            Dummy: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDummyHICONIncluder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+impl windows_core::imp::ProxyVtable for IThumbnailExtractor_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+        unsafe extern "system" fn ExtractThumbnail(this: *mut core::ffi::c_void, pstg: *mut core::ffi::c_void, ullength: u32, ulheight: u32, puloutputlength: *mut u32, puloutputheight: *mut u32, phoutputbitmap: *mut super::super::super::Graphics::Gdi::HBITMAP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IThumbnailExtractor", "ExtractThumbnail", 3usize, |this| ((**(this as *const *const IThumbnailExtractor_Vtbl)).ExtractThumbnail)(this, pstg, ullength, ulheight, puloutputlength, puloutputheight, phoutputbitmap), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn OnFileUpdated(this: *mut core::ffi::c_void, pstg: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IThumbnailExtractor", "OnFileUpdated", 4usize, |this| ((**(this as *const *const IThumbnailExtractor_Vtbl)).OnFileUpdated)(this, pstg), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+            ExtractThumbnail,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage")))]
+            ExtractThumbnail: 0,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            OnFileUpdated,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            OnFileUpdated: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IThumbnailExtractor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -2633,6 +2633,7 @@ pub struct AUTHENTICATEF(pub i32);
 impl windows_core::TypeKind for AUTHENTICATEF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AUTHENTICATEF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AUTHENTICATEF").field(&self.0).finish()
@@ -2644,6 +2645,7 @@ pub struct BINDF(pub i32);
 impl windows_core::TypeKind for BINDF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BINDF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BINDF").field(&self.0).finish()
@@ -2655,6 +2657,7 @@ pub struct BINDF2(pub i32);
 impl windows_core::TypeKind for BINDF2 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BINDF2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BINDF2").field(&self.0).finish()
@@ -2666,6 +2669,7 @@ pub struct BINDHANDLETYPES(pub i32);
 impl windows_core::TypeKind for BINDHANDLETYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BINDHANDLETYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BINDHANDLETYPES").field(&self.0).finish()
@@ -2677,6 +2681,7 @@ pub struct BINDINFO_OPTIONS(pub i32);
 impl windows_core::TypeKind for BINDINFO_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BINDINFO_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BINDINFO_OPTIONS").field(&self.0).finish()
@@ -2688,6 +2693,7 @@ pub struct BINDSTATUS(pub i32);
 impl windows_core::TypeKind for BINDSTATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BINDSTATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BINDSTATUS").field(&self.0).finish()
@@ -2699,6 +2705,7 @@ pub struct BINDSTRING(pub i32);
 impl windows_core::TypeKind for BINDSTRING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BINDSTRING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BINDSTRING").field(&self.0).finish()
@@ -2710,6 +2717,7 @@ pub struct BINDVERB(pub i32);
 impl windows_core::TypeKind for BINDVERB {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BINDVERB {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BINDVERB").field(&self.0).finish()
@@ -2721,6 +2729,7 @@ pub struct BSCF(pub i32);
 impl windows_core::TypeKind for BSCF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BSCF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BSCF").field(&self.0).finish()
@@ -2732,6 +2741,7 @@ pub struct CIP_STATUS(pub i32);
 impl windows_core::TypeKind for CIP_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CIP_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CIP_STATUS").field(&self.0).finish()
@@ -2743,6 +2753,7 @@ pub struct IEObjectType(pub i32);
 impl windows_core::TypeKind for IEObjectType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IEObjectType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IEObjectType").field(&self.0).finish()
@@ -2754,6 +2765,7 @@ pub struct INET_ZONE_MANAGER_CONSTANTS(pub i32);
 impl windows_core::TypeKind for INET_ZONE_MANAGER_CONSTANTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INET_ZONE_MANAGER_CONSTANTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INET_ZONE_MANAGER_CONSTANTS").field(&self.0).finish()
@@ -2765,6 +2777,7 @@ pub struct INTERNETFEATURELIST(pub i32);
 impl windows_core::TypeKind for INTERNETFEATURELIST {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INTERNETFEATURELIST {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INTERNETFEATURELIST").field(&self.0).finish()
@@ -2776,6 +2789,7 @@ pub struct MONIKERPROPERTY(pub i32);
 impl windows_core::TypeKind for MONIKERPROPERTY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MONIKERPROPERTY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MONIKERPROPERTY").field(&self.0).finish()
@@ -2787,6 +2801,7 @@ pub struct OIBDG_FLAGS(pub i32);
 impl windows_core::TypeKind for OIBDG_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OIBDG_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OIBDG_FLAGS").field(&self.0).finish()
@@ -2798,6 +2813,7 @@ pub struct PARSEACTION(pub i32);
 impl windows_core::TypeKind for PARSEACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PARSEACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PARSEACTION").field(&self.0).finish()
@@ -2809,6 +2825,7 @@ pub struct PI_FLAGS(pub i32);
 impl windows_core::TypeKind for PI_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PI_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PI_FLAGS").field(&self.0).finish()
@@ -2820,6 +2837,7 @@ pub struct PSUACTION(pub i32);
 impl windows_core::TypeKind for PSUACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PSUACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PSUACTION").field(&self.0).finish()
@@ -2831,6 +2849,7 @@ pub struct PUAF(pub i32);
 impl windows_core::TypeKind for PUAF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PUAF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PUAF").field(&self.0).finish()
@@ -2842,6 +2861,7 @@ pub struct PUAFOUT(pub i32);
 impl windows_core::TypeKind for PUAFOUT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PUAFOUT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PUAFOUT").field(&self.0).finish()
@@ -2853,6 +2873,7 @@ pub struct QUERYOPTION(pub i32);
 impl windows_core::TypeKind for QUERYOPTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for QUERYOPTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("QUERYOPTION").field(&self.0).finish()
@@ -2864,6 +2885,7 @@ pub struct SZM_FLAGS(pub i32);
 impl windows_core::TypeKind for SZM_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SZM_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SZM_FLAGS").field(&self.0).finish()
@@ -2875,6 +2897,7 @@ pub struct URLTEMPLATE(pub i32);
 impl windows_core::TypeKind for URLTEMPLATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for URLTEMPLATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("URLTEMPLATE").field(&self.0).finish()
@@ -2886,6 +2909,7 @@ pub struct URLZONE(pub i32);
 impl windows_core::TypeKind for URLZONE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for URLZONE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("URLZONE").field(&self.0).finish()
@@ -2897,6 +2921,7 @@ pub struct URLZONEREG(pub i32);
 impl windows_core::TypeKind for URLZONEREG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for URLZONEREG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("URLZONEREG").field(&self.0).finish()
@@ -2908,6 +2933,7 @@ pub struct URL_ENCODING(pub i32);
 impl windows_core::TypeKind for URL_ENCODING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for URL_ENCODING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("URL_ENCODING").field(&self.0).finish()
@@ -2919,6 +2945,7 @@ pub struct Uri_HOST_TYPE(pub i32);
 impl windows_core::TypeKind for Uri_HOST_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for Uri_HOST_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Uri_HOST_TYPE").field(&self.0).finish()
@@ -2930,13 +2957,15 @@ pub struct ZAFLAGS(pub i32);
 impl windows_core::TypeKind for ZAFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ZAFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ZAFLAGS").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CODEBASEHOLD {
     pub cbSize: u32,
     pub szDistUnit: windows_core::PWSTR,
@@ -2954,7 +2983,8 @@ impl Default for CODEBASEHOLD {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CONFIRMSAFETY {
     pub clsid: windows_core::GUID,
     pub pUnk: core::mem::ManuallyDrop<Option<windows_core::IUnknown>>,
@@ -2974,7 +3004,8 @@ impl Default for CONFIRMSAFETY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DATAINFO {
     pub ulTotalSize: u32,
     pub ulavrPacketSize: u32,
@@ -2990,7 +3021,8 @@ impl Default for DATAINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HIT_LOGGING_INFO {
     pub dwStructSize: u32,
     pub lpszLoggedUrlName: windows_core::PSTR,
@@ -3007,7 +3039,8 @@ impl Default for HIT_LOGGING_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROTOCOLDATA {
     pub grfFlags: u32,
     pub dwState: u32,
@@ -3023,7 +3056,8 @@ impl Default for PROTOCOLDATA {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROTOCOLFILTERDATA {
     pub cbSize: u32,
     pub pProtocolSink: core::mem::ManuallyDrop<Option<IInternetProtocolSink>>,
@@ -3045,7 +3079,8 @@ impl Default for PROTOCOLFILTERDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROTOCOL_ARGUMENT {
     pub szMethod: windows_core::PCWSTR,
     pub szTargetUrl: windows_core::PCWSTR,
@@ -3059,7 +3094,8 @@ impl Default for PROTOCOL_ARGUMENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REMSECURITY_ATTRIBUTES {
     pub nLength: u32,
     pub lpSecurityDescriptor: u32,
@@ -3074,7 +3110,8 @@ impl Default for REMSECURITY_ATTRIBUTES {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RemBINDINFO {
     pub cbSize: u32,
     pub szExtraInfo: windows_core::PWSTR,
@@ -3104,7 +3141,8 @@ impl Default for RemBINDINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RemFORMATETC {
     pub cfFormat: u32,
     pub ptd: u32,
@@ -3121,7 +3159,8 @@ impl Default for RemFORMATETC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOFTDISTINFO {
     pub cbSize: u32,
     pub dwFlags: u32,
@@ -3146,7 +3185,8 @@ impl Default for SOFTDISTINFO {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct StartParam {
     pub iid: windows_core::GUID,
     pub pIBindCtx: core::mem::ManuallyDrop<Option<super::IBindCtx>>,
@@ -3166,7 +3206,8 @@ impl Default for StartParam {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ZONEATTRIBUTES {
     pub cbSize: u32,
     pub szDisplayName: [u16; 260],
@@ -3187,3 +3228,5 @@ impl Default for ZONEATTRIBUTES {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,865 @@
+impl windows_core::imp::ProxyVtable for IBindCallbackRedirect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Redirect(this: *mut core::ffi::c_void, lpcurl: windows_core::PCWSTR, vbcancel: *mut super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBindCallbackRedirect", "Redirect", 3usize, |this| ((**(this as *const *const IBindCallbackRedirect_Vtbl)).Redirect)(this, lpcurl, vbcancel), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Redirect }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBindCallbackRedirect as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBindHttpSecurity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetIgnoreCertMask(this: *mut core::ffi::c_void, pdwignorecertmask: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBindHttpSecurity", "GetIgnoreCertMask", 3usize, |this| ((**(this as *const *const IBindHttpSecurity_Vtbl)).GetIgnoreCertMask)(this, pdwignorecertmask), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetIgnoreCertMask }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBindHttpSecurity as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBindProtocol_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateBinding(this: *mut core::ffi::c_void, szurl: windows_core::PCWSTR, pbc: *mut core::ffi::c_void, ppb: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBindProtocol", "CreateBinding", 3usize, |this| ((**(this as *const *const IBindProtocol_Vtbl)).CreateBinding)(this, szurl, pbc, ppb), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateBinding }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBindProtocol as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICatalogFileInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCatalogFile(this: *mut core::ffi::c_void, ppszcatalogfile: *mut windows_core::PSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalogFileInfo", "GetCatalogFile", 3usize, |this| ((**(this as *const *const ICatalogFileInfo_Vtbl)).GetCatalogFile)(this, ppszcatalogfile), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetJavaTrust(this: *mut core::ffi::c_void, ppjavatrust: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalogFileInfo", "GetJavaTrust", 4usize, |this| ((**(this as *const *const ICatalogFileInfo_Vtbl)).GetJavaTrust)(this, ppjavatrust), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCatalogFile, GetJavaTrust }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICatalogFileInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICodeInstall_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnCodeInstallProblem(this: *mut core::ffi::c_void, ulstatuscode: u32, szdestination: windows_core::PCWSTR, szsource: windows_core::PCWSTR, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICodeInstall", "OnCodeInstallProblem", 4usize, |this| ((**(this as *const *const ICodeInstall_Vtbl)).OnCodeInstallProblem)(this, ulstatuscode, szdestination, szsource, dwreserved), |result| Some(*result))
+        }
+        Self { base__: <IWindowForBindingUI_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnCodeInstallProblem }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICodeInstall as windows_core::Interface>::IID || <IWindowForBindingUI_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DoEncode(this: *mut core::ffi::c_void, dwflags: u32, linbuffersize: i32, pbinbuffer: *const u8, loutbuffersize: i32, pboutbuffer: *mut u8, linbytesavailable: i32, plinbytesread: *mut i32, ploutbyteswritten: *mut i32, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataFilter", "DoEncode", 3usize, |this| ((**(this as *const *const IDataFilter_Vtbl)).DoEncode)(this, dwflags, linbuffersize, pbinbuffer, loutbuffersize, pboutbuffer, linbytesavailable, plinbytesread, ploutbyteswritten, dwreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoDecode(this: *mut core::ffi::c_void, dwflags: u32, linbuffersize: i32, pbinbuffer: *const u8, loutbuffersize: i32, pboutbuffer: *mut u8, linbytesavailable: i32, plinbytesread: *mut i32, ploutbyteswritten: *mut i32, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataFilter", "DoDecode", 4usize, |this| ((**(this as *const *const IDataFilter_Vtbl)).DoDecode)(this, dwflags, linbuffersize, pbinbuffer, loutbuffersize, pboutbuffer, linbytesavailable, plinbytesread, ploutbyteswritten, dwreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEncodingLevel(this: *mut core::ffi::c_void, dwenclevel: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataFilter", "SetEncodingLevel", 5usize, |this| ((**(this as *const *const IDataFilter_Vtbl)).SetEncodingLevel)(this, dwenclevel), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DoEncode, DoDecode, SetEncodingLevel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataFilter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEncodingFilterFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FindBestFilter(this: *mut core::ffi::c_void, pwzcodein: windows_core::PCWSTR, pwzcodeout: windows_core::PCWSTR, info: DATAINFO, ppdf: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEncodingFilterFactory", "FindBestFilter", 3usize, |this| ((**(this as *const *const IEncodingFilterFactory_Vtbl)).FindBestFilter)(this, pwzcodein, pwzcodeout, info, ppdf), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultFilter(this: *mut core::ffi::c_void, pwzcodein: windows_core::PCWSTR, pwzcodeout: windows_core::PCWSTR, ppdf: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEncodingFilterFactory", "GetDefaultFilter", 4usize, |this| ((**(this as *const *const IEncodingFilterFactory_Vtbl)).GetDefaultFilter)(this, pwzcodein, pwzcodeout, ppdf), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FindBestFilter, GetDefaultFilter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEncodingFilterFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGetBindHandle_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBindHandle(this: *mut core::ffi::c_void, enumrequestedhandle: BINDHANDLETYPES, prethandle: *mut super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetBindHandle", "GetBindHandle", 3usize, |this| ((**(this as *const *const IGetBindHandle_Vtbl)).GetBindHandle)(this, enumrequestedhandle, prethandle), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetBindHandle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGetBindHandle as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpNegotiate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BeginningTransaction(this: *mut core::ffi::c_void, szurl: windows_core::PCWSTR, szheaders: windows_core::PCWSTR, dwreserved: u32, pszadditionalheaders: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpNegotiate", "BeginningTransaction", 3usize, |this| ((**(this as *const *const IHttpNegotiate_Vtbl)).BeginningTransaction)(this, szurl, szheaders, dwreserved, pszadditionalheaders), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnResponse(this: *mut core::ffi::c_void, dwresponsecode: u32, szresponseheaders: windows_core::PCWSTR, szrequestheaders: windows_core::PCWSTR, pszadditionalrequestheaders: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpNegotiate", "OnResponse", 4usize, |this| ((**(this as *const *const IHttpNegotiate_Vtbl)).OnResponse)(this, dwresponsecode, szresponseheaders, szrequestheaders, pszadditionalrequestheaders), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BeginningTransaction, OnResponse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpNegotiate as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpNegotiate2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRootSecurityId(this: *mut core::ffi::c_void, pbsecurityid: *mut u8, pcbsecurityid: *mut u32, dwreserved: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpNegotiate2", "GetRootSecurityId", 5usize, |this| ((**(this as *const *const IHttpNegotiate2_Vtbl)).GetRootSecurityId)(this, pbsecurityid, pcbsecurityid, dwreserved), |result| Some(*result))
+        }
+        Self { base__: <IHttpNegotiate_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRootSecurityId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpNegotiate2 as windows_core::Interface>::IID || <IHttpNegotiate_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpNegotiate3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSerializedClientCertContext(this: *mut core::ffi::c_void, ppbcert: *mut *mut u8, pcbcert: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpNegotiate3", "GetSerializedClientCertContext", 6usize, |this| ((**(this as *const *const IHttpNegotiate3_Vtbl)).GetSerializedClientCertContext)(this, ppbcert, pcbcert), |result| Some(*result))
+        }
+        Self { base__: <IHttpNegotiate2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSerializedClientCertContext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpNegotiate3 as windows_core::Interface>::IID || <IHttpNegotiate2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHttpSecurity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnSecurityProblem(this: *mut core::ffi::c_void, dwproblem: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHttpSecurity", "OnSecurityProblem", 4usize, |this| ((**(this as *const *const IHttpSecurity_Vtbl)).OnSecurityProblem)(this, dwproblem), |result| Some(*result))
+        }
+        Self { base__: <IWindowForBindingUI_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnSecurityProblem }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHttpSecurity as windows_core::Interface>::IID || <IWindowForBindingUI_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInternet_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInternet as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Security", feature = "Win32_System_Com_StructuredStorage"))]
+impl windows_core::imp::ProxyVtable for IInternetBindInfo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Security", feature = "Win32_System_Com_StructuredStorage"))]
+        unsafe extern "system" fn GetBindInfo(this: *mut core::ffi::c_void, grfbindf: *mut u32, pbindinfo: *mut super::BINDINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetBindInfo", "GetBindInfo", 3usize, |this| ((**(this as *const *const IInternetBindInfo_Vtbl)).GetBindInfo)(this, grfbindf, pbindinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBindString(this: *mut core::ffi::c_void, ulstringtype: u32, ppwzstr: *mut windows_core::PWSTR, cel: u32, pcelfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetBindInfo", "GetBindString", 4usize, |this| ((**(this as *const *const IInternetBindInfo_Vtbl)).GetBindString)(this, ulstringtype, ppwzstr, cel, pcelfetched), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Security", feature = "Win32_System_Com_StructuredStorage"))]
+            GetBindInfo,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Security", feature = "Win32_System_Com_StructuredStorage")))]
+            GetBindInfo: 0,
+            GetBindString,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInternetBindInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Security", feature = "Win32_System_Com_StructuredStorage"))]
+impl windows_core::imp::ProxyVtable for IInternetBindInfoEx_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Security", feature = "Win32_System_Com_StructuredStorage"))]
+        unsafe extern "system" fn GetBindInfoEx(this: *mut core::ffi::c_void, grfbindf: *mut u32, pbindinfo: *mut super::BINDINFO, grfbindf2: *mut u32, pdwreserved: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetBindInfoEx", "GetBindInfoEx", 5usize, |this| ((**(this as *const *const IInternetBindInfoEx_Vtbl)).GetBindInfoEx)(this, grfbindf, pbindinfo, grfbindf2, pdwreserved), |result| Some(*result))
+        }
+        Self {
+            base__: <IInternetBindInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Security", feature = "Win32_System_Com_StructuredStorage"))]
+            GetBindInfoEx,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Security", feature = "Win32_System_Com_StructuredStorage")))]
+            GetBindInfoEx: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInternetBindInfoEx as windows_core::Interface>::IID || <IInternetBindInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInternetHostSecurityManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSecurityId(this: *mut core::ffi::c_void, pbsecurityid: *mut u8, pcbsecurityid: *mut u32, dwreserved: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetHostSecurityManager", "GetSecurityId", 3usize, |this| ((**(this as *const *const IInternetHostSecurityManager_Vtbl)).GetSecurityId)(this, pbsecurityid, pcbsecurityid, dwreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessUrlAction(this: *mut core::ffi::c_void, dwaction: u32, ppolicy: *mut u8, cbpolicy: u32, pcontext: *const u8, cbcontext: u32, dwflags: u32, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetHostSecurityManager", "ProcessUrlAction", 4usize, |this| ((**(this as *const *const IInternetHostSecurityManager_Vtbl)).ProcessUrlAction)(this, dwaction, ppolicy, cbpolicy, pcontext, cbcontext, dwflags, dwreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryCustomPolicy(this: *mut core::ffi::c_void, guidkey: *const windows_core::GUID, pppolicy: *mut *mut u8, pcbpolicy: *mut u32, pcontext: *const u8, cbcontext: u32, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetHostSecurityManager", "QueryCustomPolicy", 5usize, |this| ((**(this as *const *const IInternetHostSecurityManager_Vtbl)).QueryCustomPolicy)(this, guidkey, pppolicy, pcbpolicy, pcontext, cbcontext, dwreserved), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSecurityId, ProcessUrlAction, QueryCustomPolicy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInternetHostSecurityManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInternetPriority_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPriority(this: *mut core::ffi::c_void, npriority: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetPriority", "SetPriority", 3usize, |this| ((**(this as *const *const IInternetPriority_Vtbl)).SetPriority)(this, npriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPriority(this: *mut core::ffi::c_void, pnpriority: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetPriority", "GetPriority", 4usize, |this| ((**(this as *const *const IInternetPriority_Vtbl)).GetPriority)(this, pnpriority), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetPriority, GetPriority }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInternetPriority as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInternetProtocol_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Read(this: *mut core::ffi::c_void, pv: *mut core::ffi::c_void, cb: u32, pcbread: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetProtocol", "Read", 9usize, |this| ((**(this as *const *const IInternetProtocol_Vtbl)).Read)(this, pv, cb, pcbread), |result| Some(*result))
+        }
+        unsafe extern "system" fn Seek(this: *mut core::ffi::c_void, dlibmove: i64, dworigin: u32, plibnewposition: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetProtocol", "Seek", 10usize, |this| ((**(this as *const *const IInternetProtocol_Vtbl)).Seek)(this, dlibmove, dworigin, plibnewposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockRequest(this: *mut core::ffi::c_void, dwoptions: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetProtocol", "LockRequest", 11usize, |this| ((**(this as *const *const IInternetProtocol_Vtbl)).LockRequest)(this, dwoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockRequest(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetProtocol", "UnlockRequest", 12usize, |this| ((**(this as *const *const IInternetProtocol_Vtbl)).UnlockRequest)(this), |result| Some(*result))
+        }
+        Self { base__: <IInternetProtocolRoot_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Read, Seek, LockRequest, UnlockRequest }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInternetProtocol as windows_core::Interface>::IID || <IInternetProtocolRoot_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInternetProtocolEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartEx(this: *mut core::ffi::c_void, puri: *mut core::ffi::c_void, poiprotsink: *mut core::ffi::c_void, poibindinfo: *mut core::ffi::c_void, grfpi: u32, dwreserved: super::super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetProtocolEx", "StartEx", 13usize, |this| ((**(this as *const *const IInternetProtocolEx_Vtbl)).StartEx)(this, puri, poiprotsink, poibindinfo, grfpi, dwreserved), |result| Some(*result))
+        }
+        Self { base__: <IInternetProtocol_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInternetProtocolEx as windows_core::Interface>::IID || <IInternetProtocol_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInternetProtocolInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ParseUrl(this: *mut core::ffi::c_void, pwzurl: windows_core::PCWSTR, parseaction: PARSEACTION, dwparseflags: u32, pwzresult: windows_core::PWSTR, cchresult: u32, pcchresult: *mut u32, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetProtocolInfo", "ParseUrl", 3usize, |this| ((**(this as *const *const IInternetProtocolInfo_Vtbl)).ParseUrl)(this, pwzurl, parseaction, dwparseflags, pwzresult, cchresult, pcchresult, dwreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn CombineUrl(this: *mut core::ffi::c_void, pwzbaseurl: windows_core::PCWSTR, pwzrelativeurl: windows_core::PCWSTR, dwcombineflags: u32, pwzresult: windows_core::PCWSTR, cchresult: u32, pcchresult: *mut u32, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetProtocolInfo", "CombineUrl", 4usize, |this| ((**(this as *const *const IInternetProtocolInfo_Vtbl)).CombineUrl)(this, pwzbaseurl, pwzrelativeurl, dwcombineflags, pwzresult, cchresult, pcchresult, dwreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompareUrl(this: *mut core::ffi::c_void, pwzurl1: windows_core::PCWSTR, pwzurl2: windows_core::PCWSTR, dwcompareflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetProtocolInfo", "CompareUrl", 5usize, |this| ((**(this as *const *const IInternetProtocolInfo_Vtbl)).CompareUrl)(this, pwzurl1, pwzurl2, dwcompareflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryInfo(this: *mut core::ffi::c_void, pwzurl: windows_core::PCWSTR, oueryoption: QUERYOPTION, dwqueryflags: u32, pbuffer: *mut core::ffi::c_void, cbbuffer: u32, pcbbuf: *mut u32, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetProtocolInfo", "QueryInfo", 6usize, |this| ((**(this as *const *const IInternetProtocolInfo_Vtbl)).QueryInfo)(this, pwzurl, oueryoption, dwqueryflags, pbuffer, cbbuffer, pcbbuf, dwreserved), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ParseUrl, CombineUrl, CompareUrl, QueryInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInternetProtocolInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInternetProtocolRoot_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void, szurl: windows_core::PCWSTR, poiprotsink: *mut core::ffi::c_void, poibindinfo: *mut core::ffi::c_void, grfpi: u32, dwreserved: super::super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetProtocolRoot", "Start", 3usize, |this| ((**(this as *const *const IInternetProtocolRoot_Vtbl)).Start)(this, szurl, poiprotsink, poibindinfo, grfpi, dwreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn Continue(this: *mut core::ffi::c_void, pprotocoldata: *const PROTOCOLDATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetProtocolRoot", "Continue", 4usize, |this| ((**(this as *const *const IInternetProtocolRoot_Vtbl)).Continue)(this, pprotocoldata), |result| Some(*result))
+        }
+        unsafe extern "system" fn Abort(this: *mut core::ffi::c_void, hrreason: windows_core::HRESULT, dwoptions: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetProtocolRoot", "Abort", 5usize, |this| ((**(this as *const *const IInternetProtocolRoot_Vtbl)).Abort)(this, hrreason, dwoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn Terminate(this: *mut core::ffi::c_void, dwoptions: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetProtocolRoot", "Terminate", 6usize, |this| ((**(this as *const *const IInternetProtocolRoot_Vtbl)).Terminate)(this, dwoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn Suspend(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetProtocolRoot", "Suspend", 7usize, |this| ((**(this as *const *const IInternetProtocolRoot_Vtbl)).Suspend)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetProtocolRoot", "Resume", 8usize, |this| ((**(this as *const *const IInternetProtocolRoot_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Start, Continue, Abort, Terminate, Suspend, Resume }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInternetProtocolRoot as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInternetProtocolSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Switch(this: *mut core::ffi::c_void, pprotocoldata: *const PROTOCOLDATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetProtocolSink", "Switch", 3usize, |this| ((**(this as *const *const IInternetProtocolSink_Vtbl)).Switch)(this, pprotocoldata), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportProgress(this: *mut core::ffi::c_void, ulstatuscode: u32, szstatustext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetProtocolSink", "ReportProgress", 4usize, |this| ((**(this as *const *const IInternetProtocolSink_Vtbl)).ReportProgress)(this, ulstatuscode, szstatustext), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportData(this: *mut core::ffi::c_void, grfbscf: u32, ulprogress: u32, ulprogressmax: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetProtocolSink", "ReportData", 5usize, |this| ((**(this as *const *const IInternetProtocolSink_Vtbl)).ReportData)(this, grfbscf, ulprogress, ulprogressmax), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportResult(this: *mut core::ffi::c_void, hrresult: windows_core::HRESULT, dwerror: u32, szresult: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetProtocolSink", "ReportResult", 6usize, |this| ((**(this as *const *const IInternetProtocolSink_Vtbl)).ReportResult)(this, hrresult, dwerror, szresult), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Switch, ReportProgress, ReportData, ReportResult }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInternetProtocolSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInternetProtocolSinkStackable_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SwitchSink(this: *mut core::ffi::c_void, poiprotsink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetProtocolSinkStackable", "SwitchSink", 3usize, |this| ((**(this as *const *const IInternetProtocolSinkStackable_Vtbl)).SwitchSink)(this, poiprotsink), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommitSwitch(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetProtocolSinkStackable", "CommitSwitch", 4usize, |this| ((**(this as *const *const IInternetProtocolSinkStackable_Vtbl)).CommitSwitch)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RollbackSwitch(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetProtocolSinkStackable", "RollbackSwitch", 5usize, |this| ((**(this as *const *const IInternetProtocolSinkStackable_Vtbl)).RollbackSwitch)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SwitchSink, CommitSwitch, RollbackSwitch }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInternetProtocolSinkStackable as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInternetSecurityManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSecuritySite(this: *mut core::ffi::c_void, psite: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetSecurityManager", "SetSecuritySite", 3usize, |this| ((**(this as *const *const IInternetSecurityManager_Vtbl)).SetSecuritySite)(this, psite), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSecuritySite(this: *mut core::ffi::c_void, ppsite: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetSecurityManager", "GetSecuritySite", 4usize, |this| ((**(this as *const *const IInternetSecurityManager_Vtbl)).GetSecuritySite)(this, ppsite), |result| Some(*result))
+        }
+        unsafe extern "system" fn MapUrlToZone(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, pdwzone: *mut u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetSecurityManager", "MapUrlToZone", 5usize, |this| ((**(this as *const *const IInternetSecurityManager_Vtbl)).MapUrlToZone)(this, pwszurl, pdwzone, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSecurityId(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, pbsecurityid: *mut u8, pcbsecurityid: *mut u32, dwreserved: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetSecurityManager", "GetSecurityId", 6usize, |this| ((**(this as *const *const IInternetSecurityManager_Vtbl)).GetSecurityId)(this, pwszurl, pbsecurityid, pcbsecurityid, dwreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessUrlAction(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, dwaction: u32, ppolicy: *mut u8, cbpolicy: u32, pcontext: *const u8, cbcontext: u32, dwflags: u32, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetSecurityManager", "ProcessUrlAction", 7usize, |this| ((**(this as *const *const IInternetSecurityManager_Vtbl)).ProcessUrlAction)(this, pwszurl, dwaction, ppolicy, cbpolicy, pcontext, cbcontext, dwflags, dwreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryCustomPolicy(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, guidkey: *const windows_core::GUID, pppolicy: *mut *mut u8, pcbpolicy: *mut u32, pcontext: *const u8, cbcontext: u32, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetSecurityManager", "QueryCustomPolicy", 8usize, |this| ((**(this as *const *const IInternetSecurityManager_Vtbl)).QueryCustomPolicy)(this, pwszurl, guidkey, pppolicy, pcbpolicy, pcontext, cbcontext, dwreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetZoneMapping(this: *mut core::ffi::c_void, dwzone: u32, lpszpattern: windows_core::PCWSTR, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetSecurityManager", "SetZoneMapping", 9usize, |this| ((**(this as *const *const IInternetSecurityManager_Vtbl)).SetZoneMapping)(this, dwzone, lpszpattern, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetZoneMappings(this: *mut core::ffi::c_void, dwzone: u32, ppenumstring: *mut *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetSecurityManager", "GetZoneMappings", 10usize, |this| ((**(this as *const *const IInternetSecurityManager_Vtbl)).GetZoneMappings)(this, dwzone, ppenumstring, dwflags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetSecuritySite,
+            GetSecuritySite,
+            MapUrlToZone,
+            GetSecurityId,
+            ProcessUrlAction,
+            QueryCustomPolicy,
+            SetZoneMapping,
+            GetZoneMappings,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInternetSecurityManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInternetSecurityManagerEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProcessUrlActionEx(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, dwaction: u32, ppolicy: *mut u8, cbpolicy: u32, pcontext: *const u8, cbcontext: u32, dwflags: u32, dwreserved: u32, pdwoutflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetSecurityManagerEx", "ProcessUrlActionEx", 11usize, |this| ((**(this as *const *const IInternetSecurityManagerEx_Vtbl)).ProcessUrlActionEx)(this, pwszurl, dwaction, ppolicy, cbpolicy, pcontext, cbcontext, dwflags, dwreserved, pdwoutflags), |result| Some(*result))
+        }
+        Self { base__: <IInternetSecurityManager_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ProcessUrlActionEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInternetSecurityManagerEx as windows_core::Interface>::IID || <IInternetSecurityManager_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInternetSecurityManagerEx2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MapUrlToZoneEx2(this: *mut core::ffi::c_void, puri: *mut core::ffi::c_void, pdwzone: *mut u32, dwflags: u32, ppwszmappedurl: *mut windows_core::PWSTR, pdwoutflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetSecurityManagerEx2", "MapUrlToZoneEx2", 12usize, |this| ((**(this as *const *const IInternetSecurityManagerEx2_Vtbl)).MapUrlToZoneEx2)(this, puri, pdwzone, dwflags, ppwszmappedurl, pdwoutflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessUrlActionEx2(this: *mut core::ffi::c_void, puri: *mut core::ffi::c_void, dwaction: u32, ppolicy: *mut u8, cbpolicy: u32, pcontext: *const u8, cbcontext: u32, dwflags: u32, dwreserved: usize, pdwoutflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetSecurityManagerEx2", "ProcessUrlActionEx2", 13usize, |this| ((**(this as *const *const IInternetSecurityManagerEx2_Vtbl)).ProcessUrlActionEx2)(this, puri, dwaction, ppolicy, cbpolicy, pcontext, cbcontext, dwflags, dwreserved, pdwoutflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSecurityIdEx2(this: *mut core::ffi::c_void, puri: *mut core::ffi::c_void, pbsecurityid: *mut u8, pcbsecurityid: *mut u32, dwreserved: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetSecurityManagerEx2", "GetSecurityIdEx2", 14usize, |this| ((**(this as *const *const IInternetSecurityManagerEx2_Vtbl)).GetSecurityIdEx2)(this, puri, pbsecurityid, pcbsecurityid, dwreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryCustomPolicyEx2(this: *mut core::ffi::c_void, puri: *mut core::ffi::c_void, guidkey: *const windows_core::GUID, pppolicy: *mut *mut u8, pcbpolicy: *mut u32, pcontext: *const u8, cbcontext: u32, dwreserved: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetSecurityManagerEx2", "QueryCustomPolicyEx2", 15usize, |this| ((**(this as *const *const IInternetSecurityManagerEx2_Vtbl)).QueryCustomPolicyEx2)(this, puri, guidkey, pppolicy, pcbpolicy, pcontext, cbcontext, dwreserved), |result| Some(*result))
+        }
+        Self {
+            base__: <IInternetSecurityManagerEx_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MapUrlToZoneEx2,
+            ProcessUrlActionEx2,
+            GetSecurityIdEx2,
+            QueryCustomPolicyEx2,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInternetSecurityManagerEx2 as windows_core::Interface>::IID || <IInternetSecurityManagerEx_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInternetSecurityMgrSite_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetWindow(this: *mut core::ffi::c_void, phwnd: *mut super::super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetSecurityMgrSite", "GetWindow", 3usize, |this| ((**(this as *const *const IInternetSecurityMgrSite_Vtbl)).GetWindow)(this, phwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableModeless(this: *mut core::ffi::c_void, fenable: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetSecurityMgrSite", "EnableModeless", 4usize, |this| ((**(this as *const *const IInternetSecurityMgrSite_Vtbl)).EnableModeless)(this, fenable), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetWindow, EnableModeless }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInternetSecurityMgrSite as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInternetSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterNameSpace(this: *mut core::ffi::c_void, pcf: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, pwzprotocol: windows_core::PCWSTR, cpatterns: u32, ppwzpatterns: *const windows_core::PCWSTR, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetSession", "RegisterNameSpace", 3usize, |this| ((**(this as *const *const IInternetSession_Vtbl)).RegisterNameSpace)(this, pcf, rclsid, pwzprotocol, cpatterns, ppwzpatterns, dwreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterNameSpace(this: *mut core::ffi::c_void, pcf: *mut core::ffi::c_void, pszprotocol: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetSession", "UnregisterNameSpace", 4usize, |this| ((**(this as *const *const IInternetSession_Vtbl)).UnregisterNameSpace)(this, pcf, pszprotocol), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterMimeFilter(this: *mut core::ffi::c_void, pcf: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, pwztype: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetSession", "RegisterMimeFilter", 5usize, |this| ((**(this as *const *const IInternetSession_Vtbl)).RegisterMimeFilter)(this, pcf, rclsid, pwztype), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterMimeFilter(this: *mut core::ffi::c_void, pcf: *mut core::ffi::c_void, pwztype: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetSession", "UnregisterMimeFilter", 6usize, |this| ((**(this as *const *const IInternetSession_Vtbl)).UnregisterMimeFilter)(this, pcf, pwztype), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBinding(this: *mut core::ffi::c_void, pbc: *mut core::ffi::c_void, szurl: windows_core::PCWSTR, punkouter: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void, ppoinetprot: *mut *mut core::ffi::c_void, dwoption: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetSession", "CreateBinding", 7usize, |this| ((**(this as *const *const IInternetSession_Vtbl)).CreateBinding)(this, pbc, szurl, punkouter, ppunk, ppoinetprot, dwoption), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSessionOption(this: *mut core::ffi::c_void, dwoption: u32, pbuffer: *const core::ffi::c_void, dwbufferlength: u32, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetSession", "SetSessionOption", 8usize, |this| ((**(this as *const *const IInternetSession_Vtbl)).SetSessionOption)(this, dwoption, pbuffer, dwbufferlength, dwreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSessionOption(this: *mut core::ffi::c_void, dwoption: u32, pbuffer: *mut core::ffi::c_void, pdwbufferlength: *mut u32, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetSession", "GetSessionOption", 9usize, |this| ((**(this as *const *const IInternetSession_Vtbl)).GetSessionOption)(this, dwoption, pbuffer, pdwbufferlength, dwreserved), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RegisterNameSpace,
+            UnregisterNameSpace,
+            RegisterMimeFilter,
+            UnregisterMimeFilter,
+            CreateBinding,
+            SetSessionOption,
+            GetSessionOption,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInternetSession as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInternetThreadSwitch_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Prepare(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetThreadSwitch", "Prepare", 3usize, |this| ((**(this as *const *const IInternetThreadSwitch_Vtbl)).Prepare)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Continue(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetThreadSwitch", "Continue", 4usize, |this| ((**(this as *const *const IInternetThreadSwitch_Vtbl)).Continue)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Prepare, Continue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInternetThreadSwitch as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInternetZoneManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetZoneAttributes(this: *mut core::ffi::c_void, dwzone: u32, pzoneattributes: *mut ZONEATTRIBUTES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetZoneManager", "GetZoneAttributes", 3usize, |this| ((**(this as *const *const IInternetZoneManager_Vtbl)).GetZoneAttributes)(this, dwzone, pzoneattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetZoneAttributes(this: *mut core::ffi::c_void, dwzone: u32, pzoneattributes: *const ZONEATTRIBUTES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetZoneManager", "SetZoneAttributes", 4usize, |this| ((**(this as *const *const IInternetZoneManager_Vtbl)).SetZoneAttributes)(this, dwzone, pzoneattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetZoneCustomPolicy(this: *mut core::ffi::c_void, dwzone: u32, guidkey: *const windows_core::GUID, pppolicy: *mut *mut u8, pcbpolicy: *mut u32, urlzonereg: URLZONEREG) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetZoneManager", "GetZoneCustomPolicy", 5usize, |this| ((**(this as *const *const IInternetZoneManager_Vtbl)).GetZoneCustomPolicy)(this, dwzone, guidkey, pppolicy, pcbpolicy, urlzonereg), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetZoneCustomPolicy(this: *mut core::ffi::c_void, dwzone: u32, guidkey: *const windows_core::GUID, ppolicy: *const u8, cbpolicy: u32, urlzonereg: URLZONEREG) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetZoneManager", "SetZoneCustomPolicy", 6usize, |this| ((**(this as *const *const IInternetZoneManager_Vtbl)).SetZoneCustomPolicy)(this, dwzone, guidkey, ppolicy, cbpolicy, urlzonereg), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetZoneActionPolicy(this: *mut core::ffi::c_void, dwzone: u32, dwaction: u32, ppolicy: *mut u8, cbpolicy: u32, urlzonereg: URLZONEREG) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetZoneManager", "GetZoneActionPolicy", 7usize, |this| ((**(this as *const *const IInternetZoneManager_Vtbl)).GetZoneActionPolicy)(this, dwzone, dwaction, ppolicy, cbpolicy, urlzonereg), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetZoneActionPolicy(this: *mut core::ffi::c_void, dwzone: u32, dwaction: u32, ppolicy: *const u8, cbpolicy: u32, urlzonereg: URLZONEREG) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetZoneManager", "SetZoneActionPolicy", 8usize, |this| ((**(this as *const *const IInternetZoneManager_Vtbl)).SetZoneActionPolicy)(this, dwzone, dwaction, ppolicy, cbpolicy, urlzonereg), |result| Some(*result))
+        }
+        unsafe extern "system" fn PromptAction(this: *mut core::ffi::c_void, dwaction: u32, hwndparent: super::super::super::Foundation::HWND, pwszurl: windows_core::PCWSTR, pwsztext: windows_core::PCWSTR, dwpromptflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetZoneManager", "PromptAction", 9usize, |this| ((**(this as *const *const IInternetZoneManager_Vtbl)).PromptAction)(this, dwaction, hwndparent, pwszurl, pwsztext, dwpromptflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogAction(this: *mut core::ffi::c_void, dwaction: u32, pwszurl: windows_core::PCWSTR, pwsztext: windows_core::PCWSTR, dwlogflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetZoneManager", "LogAction", 10usize, |this| ((**(this as *const *const IInternetZoneManager_Vtbl)).LogAction)(this, dwaction, pwszurl, pwsztext, dwlogflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateZoneEnumerator(this: *mut core::ffi::c_void, pdwenum: *mut u32, pdwcount: *mut u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetZoneManager", "CreateZoneEnumerator", 11usize, |this| ((**(this as *const *const IInternetZoneManager_Vtbl)).CreateZoneEnumerator)(this, pdwenum, pdwcount, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetZoneAt(this: *mut core::ffi::c_void, dwenum: u32, dwindex: u32, pdwzone: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetZoneManager", "GetZoneAt", 12usize, |this| ((**(this as *const *const IInternetZoneManager_Vtbl)).GetZoneAt)(this, dwenum, dwindex, pdwzone), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestroyZoneEnumerator(this: *mut core::ffi::c_void, dwenum: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetZoneManager", "DestroyZoneEnumerator", 13usize, |this| ((**(this as *const *const IInternetZoneManager_Vtbl)).DestroyZoneEnumerator)(this, dwenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyTemplatePoliciesToZone(this: *mut core::ffi::c_void, dwtemplate: u32, dwzone: u32, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetZoneManager", "CopyTemplatePoliciesToZone", 14usize, |this| ((**(this as *const *const IInternetZoneManager_Vtbl)).CopyTemplatePoliciesToZone)(this, dwtemplate, dwzone, dwreserved), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetZoneAttributes,
+            SetZoneAttributes,
+            GetZoneCustomPolicy,
+            SetZoneCustomPolicy,
+            GetZoneActionPolicy,
+            SetZoneActionPolicy,
+            PromptAction,
+            LogAction,
+            CreateZoneEnumerator,
+            GetZoneAt,
+            DestroyZoneEnumerator,
+            CopyTemplatePoliciesToZone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInternetZoneManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInternetZoneManagerEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetZoneActionPolicyEx(this: *mut core::ffi::c_void, dwzone: u32, dwaction: u32, ppolicy: *mut u8, cbpolicy: u32, urlzonereg: URLZONEREG, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetZoneManagerEx", "GetZoneActionPolicyEx", 15usize, |this| ((**(this as *const *const IInternetZoneManagerEx_Vtbl)).GetZoneActionPolicyEx)(this, dwzone, dwaction, ppolicy, cbpolicy, urlzonereg, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetZoneActionPolicyEx(this: *mut core::ffi::c_void, dwzone: u32, dwaction: u32, ppolicy: *const u8, cbpolicy: u32, urlzonereg: URLZONEREG, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetZoneManagerEx", "SetZoneActionPolicyEx", 16usize, |this| ((**(this as *const *const IInternetZoneManagerEx_Vtbl)).SetZoneActionPolicyEx)(this, dwzone, dwaction, ppolicy, cbpolicy, urlzonereg, dwflags), |result| Some(*result))
+        }
+        Self { base__: <IInternetZoneManager_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetZoneActionPolicyEx, SetZoneActionPolicyEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInternetZoneManagerEx as windows_core::Interface>::IID || <IInternetZoneManager_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInternetZoneManagerEx2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetZoneAttributesEx(this: *mut core::ffi::c_void, dwzone: u32, pzoneattributes: *mut ZONEATTRIBUTES, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetZoneManagerEx2", "GetZoneAttributesEx", 17usize, |this| ((**(this as *const *const IInternetZoneManagerEx2_Vtbl)).GetZoneAttributesEx)(this, dwzone, pzoneattributes, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetZoneSecurityState(this: *mut core::ffi::c_void, dwzoneindex: u32, frespectpolicy: super::super::super::Foundation::BOOL, pdwstate: *mut u32, pfpolicyencountered: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetZoneManagerEx2", "GetZoneSecurityState", 18usize, |this| ((**(this as *const *const IInternetZoneManagerEx2_Vtbl)).GetZoneSecurityState)(this, dwzoneindex, frespectpolicy, pdwstate, pfpolicyencountered), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIESecurityState(this: *mut core::ffi::c_void, frespectpolicy: super::super::super::Foundation::BOOL, pdwstate: *mut u32, pfpolicyencountered: *mut super::super::super::Foundation::BOOL, fnocache: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetZoneManagerEx2", "GetIESecurityState", 19usize, |this| ((**(this as *const *const IInternetZoneManagerEx2_Vtbl)).GetIESecurityState)(this, frespectpolicy, pdwstate, pfpolicyencountered, fnocache), |result| Some(*result))
+        }
+        unsafe extern "system" fn FixUnsecureSettings(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetZoneManagerEx2", "FixUnsecureSettings", 20usize, |this| ((**(this as *const *const IInternetZoneManagerEx2_Vtbl)).FixUnsecureSettings)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IInternetZoneManagerEx_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetZoneAttributesEx,
+            GetZoneSecurityState,
+            GetIESecurityState,
+            FixUnsecureSettings,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInternetZoneManagerEx2 as windows_core::Interface>::IID || <IInternetZoneManagerEx_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMonikerProp_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PutProperty(this: *mut core::ffi::c_void, mkp: MONIKERPROPERTY, val: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMonikerProp", "PutProperty", 3usize, |this| ((**(this as *const *const IMonikerProp_Vtbl)).PutProperty)(this, mkp, val), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PutProperty }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMonikerProp as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPersistMoniker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetClassID(this: *mut core::ffi::c_void, pclassid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistMoniker", "GetClassID", 3usize, |this| ((**(this as *const *const IPersistMoniker_Vtbl)).GetClassID)(this, pclassid), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDirty(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistMoniker", "IsDirty", 4usize, |this| ((**(this as *const *const IPersistMoniker_Vtbl)).IsDirty)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Load(this: *mut core::ffi::c_void, ffullyavailable: super::super::super::Foundation::BOOL, pimkname: *mut core::ffi::c_void, pibc: *mut core::ffi::c_void, grfmode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistMoniker", "Load", 5usize, |this| ((**(this as *const *const IPersistMoniker_Vtbl)).Load)(this, ffullyavailable, pimkname, pibc, grfmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void, pimkname: *mut core::ffi::c_void, pbc: *mut core::ffi::c_void, fremember: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistMoniker", "Save", 6usize, |this| ((**(this as *const *const IPersistMoniker_Vtbl)).Save)(this, pimkname, pbc, fremember), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveCompleted(this: *mut core::ffi::c_void, pimkname: *mut core::ffi::c_void, pibc: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistMoniker", "SaveCompleted", 7usize, |this| ((**(this as *const *const IPersistMoniker_Vtbl)).SaveCompleted)(this, pimkname, pibc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurMoniker(this: *mut core::ffi::c_void, ppimkname: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistMoniker", "GetCurMoniker", 8usize, |this| ((**(this as *const *const IPersistMoniker_Vtbl)).GetCurMoniker)(this, ppimkname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetClassID, IsDirty, Load, Save, SaveCompleted, GetCurMoniker }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPersistMoniker as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Data_Xml_MsXml")]
+impl windows_core::imp::ProxyVtable for ISoftDistExt_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Data_Xml_MsXml")]
+        unsafe extern "system" fn ProcessSoftDist(this: *mut core::ffi::c_void, szcdfurl: windows_core::PCWSTR, psoftdistelement: *mut core::ffi::c_void, lpsdi: *mut SOFTDISTINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftDistExt", "ProcessSoftDist", 3usize, |this| ((**(this as *const *const ISoftDistExt_Vtbl)).ProcessSoftDist)(this, szcdfurl, psoftdistelement, lpsdi), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFirstCodeBase(this: *mut core::ffi::c_void, szcodebase: *const windows_core::PCWSTR, dwmaxsize: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftDistExt", "GetFirstCodeBase", 4usize, |this| ((**(this as *const *const ISoftDistExt_Vtbl)).GetFirstCodeBase)(this, szcodebase, dwmaxsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextCodeBase(this: *mut core::ffi::c_void, szcodebase: *const windows_core::PCWSTR, dwmaxsize: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftDistExt", "GetNextCodeBase", 5usize, |this| ((**(this as *const *const ISoftDistExt_Vtbl)).GetNextCodeBase)(this, szcodebase, dwmaxsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn AsyncInstallDistributionUnit(this: *mut core::ffi::c_void, pbc: *mut core::ffi::c_void, pvreserved: *const core::ffi::c_void, flags: u32, lpcbh: *const CODEBASEHOLD) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftDistExt", "AsyncInstallDistributionUnit", 6usize, |this| ((**(this as *const *const ISoftDistExt_Vtbl)).AsyncInstallDistributionUnit)(this, pbc, pvreserved, flags, lpcbh), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Data_Xml_MsXml")]
+            ProcessSoftDist,
+            #[cfg(not(feature = "Win32_Data_Xml_MsXml"))]
+            ProcessSoftDist: 0,
+            GetFirstCodeBase,
+            GetNextCodeBase,
+            AsyncInstallDistributionUnit,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISoftDistExt as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUriBuilderFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateIUriBuilder(this: *mut core::ffi::c_void, dwflags: u32, dwreserved: usize, ppiuribuilder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriBuilderFactory", "CreateIUriBuilder", 3usize, |this| ((**(this as *const *const IUriBuilderFactory_Vtbl)).CreateIUriBuilder)(this, dwflags, dwreserved, ppiuribuilder), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInitializedIUriBuilder(this: *mut core::ffi::c_void, dwflags: u32, dwreserved: usize, ppiuribuilder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriBuilderFactory", "CreateInitializedIUriBuilder", 4usize, |this| ((**(this as *const *const IUriBuilderFactory_Vtbl)).CreateInitializedIUriBuilder)(this, dwflags, dwreserved, ppiuribuilder), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateIUriBuilder, CreateInitializedIUriBuilder }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUriBuilderFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUriContainer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetIUri(this: *mut core::ffi::c_void, ppiuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriContainer", "GetIUri", 3usize, |this| ((**(this as *const *const IUriContainer_Vtbl)).GetIUri)(this, ppiuri), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetIUri }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUriContainer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWinInetCacheHints_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetCacheExtension(this: *mut core::ffi::c_void, pwzext: windows_core::PCWSTR, pszcachefile: *mut core::ffi::c_void, pcbcachefile: *mut u32, pdwwinineterror: *mut u32, pdwreserved: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinInetCacheHints", "SetCacheExtension", 3usize, |this| ((**(this as *const *const IWinInetCacheHints_Vtbl)).SetCacheExtension)(this, pwzext, pszcachefile, pcbcachefile, pdwwinineterror, pdwreserved), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetCacheExtension }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWinInetCacheHints as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWinInetCacheHints2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetCacheExtension2(this: *mut core::ffi::c_void, pwzext: windows_core::PCWSTR, pwzcachefile: windows_core::PWSTR, pcchcachefile: *mut u32, pdwwinineterror: *mut u32, pdwreserved: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinInetCacheHints2", "SetCacheExtension2", 4usize, |this| ((**(this as *const *const IWinInetCacheHints2_Vtbl)).SetCacheExtension2)(this, pwzext, pwzcachefile, pcchcachefile, pdwwinineterror, pdwreserved), |result| Some(*result))
+        }
+        Self { base__: <IWinInetCacheHints_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetCacheExtension2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWinInetCacheHints2 as windows_core::Interface>::IID || <IWinInetCacheHints_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWinInetFileStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetHandleForUnlock(this: *mut core::ffi::c_void, hwininetlockhandle: usize, dwreserved: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinInetFileStream", "SetHandleForUnlock", 3usize, |this| ((**(this as *const *const IWinInetFileStream_Vtbl)).SetHandleForUnlock)(this, hwininetlockhandle, dwreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeleteFile(this: *mut core::ffi::c_void, dwreserved: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinInetFileStream", "SetDeleteFile", 4usize, |this| ((**(this as *const *const IWinInetFileStream_Vtbl)).SetDeleteFile)(this, dwreserved), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetHandleForUnlock, SetDeleteFile }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWinInetFileStream as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWinInetHttpInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryInfo(this: *mut core::ffi::c_void, dwoption: u32, pbuffer: *mut core::ffi::c_void, pcbbuf: *mut u32, pdwflags: *mut u32, pdwreserved: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinInetHttpInfo", "QueryInfo", 4usize, |this| ((**(this as *const *const IWinInetHttpInfo_Vtbl)).QueryInfo)(this, dwoption, pbuffer, pcbbuf, pdwflags, pdwreserved), |result| Some(*result))
+        }
+        Self { base__: <IWinInetInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWinInetHttpInfo as windows_core::Interface>::IID || <IWinInetInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWinInetHttpTimeouts_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRequestTimeouts(this: *mut core::ffi::c_void, pdwconnecttimeout: *mut u32, pdwsendtimeout: *mut u32, pdwreceivetimeout: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinInetHttpTimeouts", "GetRequestTimeouts", 3usize, |this| ((**(this as *const *const IWinInetHttpTimeouts_Vtbl)).GetRequestTimeouts)(this, pdwconnecttimeout, pdwsendtimeout, pdwreceivetimeout), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRequestTimeouts }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWinInetHttpTimeouts as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWinInetInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryOption(this: *mut core::ffi::c_void, dwoption: u32, pbuffer: *mut core::ffi::c_void, pcbbuf: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWinInetInfo", "QueryOption", 3usize, |this| ((**(this as *const *const IWinInetInfo_Vtbl)).QueryOption)(this, dwoption, pbuffer, pcbbuf), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryOption }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWinInetInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowForBindingUI_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetWindow(this: *mut core::ffi::c_void, rguidreason: *const windows_core::GUID, phwnd: *mut super::super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowForBindingUI", "GetWindow", 3usize, |this| ((**(this as *const *const IWindowForBindingUI_Vtbl)).GetWindow)(this, rguidreason, phwnd), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetWindow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowForBindingUI as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWrappedProtocol_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetWrapperCode(this: *mut core::ffi::c_void, pncode: *mut i32, dwreserved: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWrappedProtocol", "GetWrapperCode", 3usize, |this| ((**(this as *const *const IWrappedProtocol_Vtbl)).GetWrapperCode)(this, pncode, dwreserved), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetWrapperCode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWrappedProtocol as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IZoneIdentifier_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetId(this: *mut core::ffi::c_void, pdwzone: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IZoneIdentifier", "GetId", 3usize, |this| ((**(this as *const *const IZoneIdentifier_Vtbl)).GetId)(this, pdwzone), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetId(this: *mut core::ffi::c_void, dwzone: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IZoneIdentifier", "SetId", 4usize, |this| ((**(this as *const *const IZoneIdentifier_Vtbl)).SetId)(this, dwzone), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IZoneIdentifier", "Remove", 5usize, |this| ((**(this as *const *const IZoneIdentifier_Vtbl)).Remove)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetId, SetId, Remove }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IZoneIdentifier as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IZoneIdentifier2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLastWriterPackageFamilyName(this: *mut core::ffi::c_void, packagefamilyname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IZoneIdentifier2", "GetLastWriterPackageFamilyName", 6usize, |this| ((**(this as *const *const IZoneIdentifier2_Vtbl)).GetLastWriterPackageFamilyName)(this, packagefamilyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLastWriterPackageFamilyName(this: *mut core::ffi::c_void, packagefamilyname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IZoneIdentifier2", "SetLastWriterPackageFamilyName", 7usize, |this| ((**(this as *const *const IZoneIdentifier2_Vtbl)).SetLastWriterPackageFamilyName)(this, packagefamilyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveLastWriterPackageFamilyName(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IZoneIdentifier2", "RemoveLastWriterPackageFamilyName", 8usize, |this| ((**(this as *const *const IZoneIdentifier2_Vtbl)).RemoveLastWriterPackageFamilyName)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAppZoneId(this: *mut core::ffi::c_void, zone: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IZoneIdentifier2", "GetAppZoneId", 9usize, |this| ((**(this as *const *const IZoneIdentifier2_Vtbl)).GetAppZoneId)(this, zone), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAppZoneId(this: *mut core::ffi::c_void, zone: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IZoneIdentifier2", "SetAppZoneId", 10usize, |this| ((**(this as *const *const IZoneIdentifier2_Vtbl)).SetAppZoneId)(this, zone), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAppZoneId(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IZoneIdentifier2", "RemoveAppZoneId", 11usize, |this| ((**(this as *const *const IZoneIdentifier2_Vtbl)).RemoveAppZoneId)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IZoneIdentifier_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetLastWriterPackageFamilyName,
+            SetLastWriterPackageFamilyName,
+            RemoveLastWriterPackageFamilyName,
+            GetAppZoneId,
+            SetAppZoneId,
+            RemoveAppZoneId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IZoneIdentifier2 as windows_core::Interface>::IID || <IZoneIdentifier_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -5264,6 +5264,7 @@ pub struct ADVANCED_FEATURE_FLAGS(pub u16);
 impl windows_core::TypeKind for ADVANCED_FEATURE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ADVANCED_FEATURE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ADVANCED_FEATURE_FLAGS").field(&self.0).finish()
@@ -5308,6 +5309,7 @@ pub struct ADVF(pub i32);
 impl windows_core::TypeKind for ADVF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ADVF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ADVF").field(&self.0).finish()
@@ -5319,6 +5321,7 @@ pub struct APTTYPE(pub i32);
 impl windows_core::TypeKind for APTTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for APTTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("APTTYPE").field(&self.0).finish()
@@ -5330,6 +5333,7 @@ pub struct APTTYPEQUALIFIER(pub i32);
 impl windows_core::TypeKind for APTTYPEQUALIFIER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for APTTYPEQUALIFIER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("APTTYPEQUALIFIER").field(&self.0).finish()
@@ -5341,6 +5345,7 @@ pub struct ApplicationType(pub i32);
 impl windows_core::TypeKind for ApplicationType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ApplicationType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ApplicationType").field(&self.0).finish()
@@ -5352,6 +5357,7 @@ pub struct BINDINFOF(pub i32);
 impl windows_core::TypeKind for BINDINFOF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BINDINFOF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BINDINFOF").field(&self.0).finish()
@@ -5363,6 +5369,7 @@ pub struct BIND_FLAGS(pub i32);
 impl windows_core::TypeKind for BIND_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BIND_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BIND_FLAGS").field(&self.0).finish()
@@ -5374,6 +5381,7 @@ pub struct CALLCONV(pub i32);
 impl windows_core::TypeKind for CALLCONV {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CALLCONV {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CALLCONV").field(&self.0).finish()
@@ -5385,6 +5393,7 @@ pub struct CALLTYPE(pub i32);
 impl windows_core::TypeKind for CALLTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CALLTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CALLTYPE").field(&self.0).finish()
@@ -5396,6 +5405,7 @@ pub struct CLSCTX(pub u32);
 impl windows_core::TypeKind for CLSCTX {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLSCTX {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLSCTX").field(&self.0).finish()
@@ -5440,6 +5450,7 @@ pub struct COINIT(pub i32);
 impl windows_core::TypeKind for COINIT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COINIT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COINIT").field(&self.0).finish()
@@ -5484,6 +5495,7 @@ pub struct COINITBASE(pub i32);
 impl windows_core::TypeKind for COINITBASE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COINITBASE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COINITBASE").field(&self.0).finish()
@@ -5495,6 +5507,7 @@ pub struct COMSD(pub i32);
 impl windows_core::TypeKind for COMSD {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMSD {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMSD").field(&self.0).finish()
@@ -5506,6 +5519,7 @@ pub struct COWAIT_FLAGS(pub i32);
 impl windows_core::TypeKind for COWAIT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COWAIT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COWAIT_FLAGS").field(&self.0).finish()
@@ -5550,6 +5564,7 @@ pub struct CO_MARSHALING_CONTEXT_ATTRIBUTES(pub i32);
 impl windows_core::TypeKind for CO_MARSHALING_CONTEXT_ATTRIBUTES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CO_MARSHALING_CONTEXT_ATTRIBUTES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CO_MARSHALING_CONTEXT_ATTRIBUTES").field(&self.0).finish()
@@ -5561,6 +5576,7 @@ pub struct CWMO_FLAGS(pub i32);
 impl windows_core::TypeKind for CWMO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CWMO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CWMO_FLAGS").field(&self.0).finish()
@@ -5605,6 +5621,7 @@ pub struct DATADIR(pub i32);
 impl windows_core::TypeKind for DATADIR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DATADIR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DATADIR").field(&self.0).finish()
@@ -5616,6 +5633,7 @@ pub struct DCOM_CALL_STATE(pub i32);
 impl windows_core::TypeKind for DCOM_CALL_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DCOM_CALL_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DCOM_CALL_STATE").field(&self.0).finish()
@@ -5627,6 +5645,7 @@ pub struct DESCKIND(pub i32);
 impl windows_core::TypeKind for DESCKIND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DESCKIND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DESCKIND").field(&self.0).finish()
@@ -5638,6 +5657,7 @@ pub struct DISPATCH_FLAGS(pub u16);
 impl windows_core::TypeKind for DISPATCH_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPATCH_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPATCH_FLAGS").field(&self.0).finish()
@@ -5682,6 +5702,7 @@ pub struct DVASPECT(pub u32);
 impl windows_core::TypeKind for DVASPECT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVASPECT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVASPECT").field(&self.0).finish()
@@ -5693,6 +5714,7 @@ pub struct EOLE_AUTHENTICATION_CAPABILITIES(pub i32);
 impl windows_core::TypeKind for EOLE_AUTHENTICATION_CAPABILITIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EOLE_AUTHENTICATION_CAPABILITIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EOLE_AUTHENTICATION_CAPABILITIES").field(&self.0).finish()
@@ -5704,6 +5726,7 @@ pub struct EXTCONN(pub i32);
 impl windows_core::TypeKind for EXTCONN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EXTCONN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EXTCONN").field(&self.0).finish()
@@ -5715,6 +5738,7 @@ pub struct FUNCFLAGS(pub u16);
 impl windows_core::TypeKind for FUNCFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FUNCFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FUNCFLAGS").field(&self.0).finish()
@@ -5726,6 +5750,7 @@ pub struct FUNCKIND(pub i32);
 impl windows_core::TypeKind for FUNCKIND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FUNCKIND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FUNCKIND").field(&self.0).finish()
@@ -5737,6 +5762,7 @@ pub struct GLOBALOPT_EH_VALUES(pub i32);
 impl windows_core::TypeKind for GLOBALOPT_EH_VALUES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GLOBALOPT_EH_VALUES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GLOBALOPT_EH_VALUES").field(&self.0).finish()
@@ -5748,6 +5774,7 @@ pub struct GLOBALOPT_PROPERTIES(pub i32);
 impl windows_core::TypeKind for GLOBALOPT_PROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GLOBALOPT_PROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GLOBALOPT_PROPERTIES").field(&self.0).finish()
@@ -5759,6 +5786,7 @@ pub struct GLOBALOPT_RO_FLAGS(pub i32);
 impl windows_core::TypeKind for GLOBALOPT_RO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GLOBALOPT_RO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GLOBALOPT_RO_FLAGS").field(&self.0).finish()
@@ -5770,6 +5798,7 @@ pub struct GLOBALOPT_RPCTP_VALUES(pub i32);
 impl windows_core::TypeKind for GLOBALOPT_RPCTP_VALUES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GLOBALOPT_RPCTP_VALUES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GLOBALOPT_RPCTP_VALUES").field(&self.0).finish()
@@ -5781,6 +5810,7 @@ pub struct GLOBALOPT_UNMARSHALING_POLICY_VALUES(pub i32);
 impl windows_core::TypeKind for GLOBALOPT_UNMARSHALING_POLICY_VALUES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GLOBALOPT_UNMARSHALING_POLICY_VALUES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GLOBALOPT_UNMARSHALING_POLICY_VALUES").field(&self.0).finish()
@@ -5792,6 +5822,7 @@ pub struct IDLFLAGS(pub u16);
 impl windows_core::TypeKind for IDLFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IDLFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IDLFLAGS").field(&self.0).finish()
@@ -5836,6 +5867,7 @@ pub struct IMPLTYPEFLAGS(pub i32);
 impl windows_core::TypeKind for IMPLTYPEFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMPLTYPEFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMPLTYPEFLAGS").field(&self.0).finish()
@@ -5880,6 +5912,7 @@ pub struct INVOKEKIND(pub i32);
 impl windows_core::TypeKind for INVOKEKIND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INVOKEKIND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INVOKEKIND").field(&self.0).finish()
@@ -5891,6 +5924,7 @@ pub struct LOCKTYPE(pub i32);
 impl windows_core::TypeKind for LOCKTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LOCKTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LOCKTYPE").field(&self.0).finish()
@@ -5902,6 +5936,7 @@ pub struct MEMCTX(pub i32);
 impl windows_core::TypeKind for MEMCTX {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MEMCTX {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MEMCTX").field(&self.0).finish()
@@ -5913,6 +5948,7 @@ pub struct MKRREDUCE(pub i32);
 impl windows_core::TypeKind for MKRREDUCE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MKRREDUCE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MKRREDUCE").field(&self.0).finish()
@@ -5924,6 +5960,7 @@ pub struct MKSYS(pub i32);
 impl windows_core::TypeKind for MKSYS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MKSYS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MKSYS").field(&self.0).finish()
@@ -5935,6 +5972,7 @@ pub struct MSHCTX(pub i32);
 impl windows_core::TypeKind for MSHCTX {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSHCTX {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSHCTX").field(&self.0).finish()
@@ -5946,6 +5984,7 @@ pub struct MSHLFLAGS(pub i32);
 impl windows_core::TypeKind for MSHLFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSHLFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSHLFLAGS").field(&self.0).finish()
@@ -5957,6 +5996,7 @@ pub struct PENDINGMSG(pub i32);
 impl windows_core::TypeKind for PENDINGMSG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PENDINGMSG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PENDINGMSG").field(&self.0).finish()
@@ -5968,6 +6008,7 @@ pub struct PENDINGTYPE(pub i32);
 impl windows_core::TypeKind for PENDINGTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PENDINGTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PENDINGTYPE").field(&self.0).finish()
@@ -5979,6 +6020,7 @@ pub struct REGCLS(pub i32);
 impl windows_core::TypeKind for REGCLS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for REGCLS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("REGCLS").field(&self.0).finish()
@@ -6023,6 +6065,7 @@ pub struct ROT_FLAGS(pub u32);
 impl windows_core::TypeKind for ROT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ROT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ROT_FLAGS").field(&self.0).finish()
@@ -6067,6 +6110,7 @@ pub struct RPCOPT_PROPERTIES(pub i32);
 impl windows_core::TypeKind for RPCOPT_PROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RPCOPT_PROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RPCOPT_PROPERTIES").field(&self.0).finish()
@@ -6078,6 +6122,7 @@ pub struct RPCOPT_SERVER_LOCALITY_VALUES(pub i32);
 impl windows_core::TypeKind for RPCOPT_SERVER_LOCALITY_VALUES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RPCOPT_SERVER_LOCALITY_VALUES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RPCOPT_SERVER_LOCALITY_VALUES").field(&self.0).finish()
@@ -6089,6 +6134,7 @@ pub struct RPC_C_AUTHN_LEVEL(pub u32);
 impl windows_core::TypeKind for RPC_C_AUTHN_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RPC_C_AUTHN_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RPC_C_AUTHN_LEVEL").field(&self.0).finish()
@@ -6100,6 +6146,7 @@ pub struct RPC_C_IMP_LEVEL(pub u32);
 impl windows_core::TypeKind for RPC_C_IMP_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RPC_C_IMP_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RPC_C_IMP_LEVEL").field(&self.0).finish()
@@ -6111,6 +6158,7 @@ pub struct SERVERCALL(pub i32);
 impl windows_core::TypeKind for SERVERCALL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SERVERCALL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SERVERCALL").field(&self.0).finish()
@@ -6122,6 +6170,7 @@ pub struct STATFLAG(pub i32);
 impl windows_core::TypeKind for STATFLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STATFLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STATFLAG").field(&self.0).finish()
@@ -6133,6 +6182,7 @@ pub struct STGC(pub i32);
 impl windows_core::TypeKind for STGC {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STGC {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STGC").field(&self.0).finish()
@@ -6177,6 +6227,7 @@ pub struct STGM(pub u32);
 impl windows_core::TypeKind for STGM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STGM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STGM").field(&self.0).finish()
@@ -6221,6 +6272,7 @@ pub struct STGTY(pub i32);
 impl windows_core::TypeKind for STGTY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STGTY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STGTY").field(&self.0).finish()
@@ -6232,6 +6284,7 @@ pub struct STREAM_SEEK(pub u32);
 impl windows_core::TypeKind for STREAM_SEEK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STREAM_SEEK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STREAM_SEEK").field(&self.0).finish()
@@ -6243,6 +6296,7 @@ pub struct SYSKIND(pub i32);
 impl windows_core::TypeKind for SYSKIND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYSKIND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYSKIND").field(&self.0).finish()
@@ -6254,6 +6308,7 @@ pub struct ShutdownType(pub i32);
 impl windows_core::TypeKind for ShutdownType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ShutdownType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ShutdownType").field(&self.0).finish()
@@ -6265,6 +6320,7 @@ pub struct THDTYPE(pub i32);
 impl windows_core::TypeKind for THDTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for THDTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("THDTYPE").field(&self.0).finish()
@@ -6276,6 +6332,7 @@ pub struct TYMED(pub i32);
 impl windows_core::TypeKind for TYMED {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TYMED {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TYMED").field(&self.0).finish()
@@ -6287,6 +6344,7 @@ pub struct TYPEKIND(pub i32);
 impl windows_core::TypeKind for TYPEKIND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TYPEKIND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TYPEKIND").field(&self.0).finish()
@@ -6298,6 +6356,7 @@ pub struct TYSPEC(pub i32);
 impl windows_core::TypeKind for TYSPEC {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TYSPEC {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TYSPEC").field(&self.0).finish()
@@ -6309,6 +6368,7 @@ pub struct URI_CREATE_FLAGS(pub u32);
 impl windows_core::TypeKind for URI_CREATE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for URI_CREATE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("URI_CREATE_FLAGS").field(&self.0).finish()
@@ -6353,6 +6413,7 @@ pub struct Uri_PROPERTY(pub i32);
 impl windows_core::TypeKind for Uri_PROPERTY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for Uri_PROPERTY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("Uri_PROPERTY").field(&self.0).finish()
@@ -6364,6 +6425,7 @@ pub struct VARFLAGS(pub u16);
 impl windows_core::TypeKind for VARFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VARFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VARFLAGS").field(&self.0).finish()
@@ -6375,13 +6437,15 @@ pub struct VARKIND(pub i32);
 impl windows_core::TypeKind for VARKIND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VARKIND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VARKIND").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AUTHENTICATEINFO {
     pub dwFlags: u32,
     pub dwReserved: u32,
@@ -6452,7 +6516,8 @@ impl Default for BINDPTR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BIND_OPTS {
     pub cbStruct: u32,
     pub grfFlags: u32,
@@ -6468,7 +6533,8 @@ impl Default for BIND_OPTS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BIND_OPTS2 {
     pub Base: BIND_OPTS,
     pub dwTrackFlags: u32,
@@ -6485,7 +6551,8 @@ impl Default for BIND_OPTS2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BIND_OPTS3 {
     pub Base: BIND_OPTS2,
     pub hwnd: super::super::Foundation::HWND,
@@ -6499,7 +6566,8 @@ impl Default for BIND_OPTS3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BLOB {
     pub cbSize: u32,
     pub pBlobData: *mut u8,
@@ -6513,7 +6581,8 @@ impl Default for BLOB {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BYTE_BLOB {
     pub clSize: u32,
     pub abData: [u8; 1],
@@ -6527,7 +6596,8 @@ impl Default for BYTE_BLOB {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BYTE_SIZEDARR {
     pub clSize: u32,
     pub pData: *mut u8,
@@ -6541,7 +6611,8 @@ impl Default for BYTE_SIZEDARR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CATEGORYINFO {
     pub catid: windows_core::GUID,
     pub lcid: u32,
@@ -6556,7 +6627,8 @@ impl Default for CATEGORYINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COAUTHIDENTITY {
     pub User: *mut u16,
     pub UserLength: u32,
@@ -6575,7 +6647,8 @@ impl Default for COAUTHIDENTITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COAUTHINFO {
     pub dwAuthnSvc: u32,
     pub dwAuthzSvc: u32,
@@ -6594,7 +6667,8 @@ impl Default for COAUTHINFO {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CONNECTDATA {
     pub pUnk: core::mem::ManuallyDrop<Option<windows_core::IUnknown>>,
     pub dwCookie: u32,
@@ -6613,7 +6687,8 @@ impl Default for CONNECTDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COSERVERINFO {
     pub dwReserved1: u32,
     pub pwszName: windows_core::PWSTR,
@@ -6629,7 +6704,8 @@ impl Default for COSERVERINFO {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CO_DEVICE_CATALOG_COOKIE(pub *mut core::ffi::c_void);
 impl CO_DEVICE_CATALOG_COOKIE {
     pub fn is_invalid(&self) -> bool {
@@ -6653,7 +6729,8 @@ impl windows_core::TypeKind for CO_DEVICE_CATALOG_COOKIE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CO_MTA_USAGE_COOKIE(pub *mut core::ffi::c_void);
 impl CO_MTA_USAGE_COOKIE {
     pub fn is_invalid(&self) -> bool {
@@ -6677,7 +6754,8 @@ impl windows_core::TypeKind for CO_MTA_USAGE_COOKIE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CSPLATFORM {
     pub dwPlatformId: u32,
     pub dwVersionHi: u32,
@@ -6693,7 +6771,8 @@ impl Default for CSPLATFORM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CUSTDATA {
     pub cCustData: u32,
     pub prgCustData: *mut CUSTDATAITEM,
@@ -6707,7 +6786,8 @@ impl Default for CUSTDATA {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CUSTDATAITEM {
     pub guid: windows_core::GUID,
     pub varValue: core::mem::ManuallyDrop<windows_core::VARIANT>,
@@ -6740,7 +6820,8 @@ impl Default for CY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CY_0 {
     pub Lo: u32,
     pub Hi: i32,
@@ -6754,7 +6835,8 @@ impl Default for CY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ComCallData {
     pub dwDispid: u32,
     pub dwReserved: u32,
@@ -6769,7 +6851,8 @@ impl Default for ComCallData {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ContextProperty {
     pub policyId: windows_core::GUID,
     pub flags: u32,
@@ -6789,7 +6872,8 @@ impl Default for ContextProperty {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPPARAMS {
     pub rgvarg: *mut windows_core::VARIANT,
     pub rgdispidNamedArgs: *mut i32,
@@ -6805,7 +6889,8 @@ impl Default for DISPPARAMS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DVTARGETDEVICE {
     pub tdSize: u32,
     pub tdDriverNameOffset: u16,
@@ -6823,7 +6908,8 @@ impl Default for DVTARGETDEVICE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWORD_BLOB {
     pub clSize: u32,
     pub alData: [u32; 1],
@@ -6837,7 +6923,8 @@ impl Default for DWORD_BLOB {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DWORD_SIZEDARR {
     pub clSize: u32,
     pub pData: *mut u32,
@@ -6885,7 +6972,7 @@ impl Default for ELEMDESC_0 {
     }
 }
 #[repr(C)]
-#[derive(Debug)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EXCEPINFO {
     pub wCode: u16,
     pub wReserved: u16,
@@ -6911,7 +6998,8 @@ impl Default for EXCEPINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FLAGGED_BYTE_BLOB {
     pub fFlags: u32,
     pub clSize: u32,
@@ -6926,7 +7014,8 @@ impl Default for FLAGGED_BYTE_BLOB {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FLAGGED_WORD_BLOB {
     pub fFlags: u32,
     pub clSize: u32,
@@ -6964,7 +7053,8 @@ impl Default for FLAG_STGMEDIUM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FORMATETC {
     pub cfFormat: u16,
     pub ptd: *mut DVTARGETDEVICE,
@@ -7043,7 +7133,8 @@ impl Default for GDI_OBJECT_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HYPER_SIZEDARR {
     pub clSize: u32,
     pub pData: *mut i64,
@@ -7057,7 +7148,8 @@ impl Default for HYPER_SIZEDARR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IDLDESC {
     pub dwReserved: usize,
     pub wIDLFlags: IDLFLAGS,
@@ -7071,7 +7163,8 @@ impl Default for IDLDESC {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct INTERFACEINFO {
     pub pUnk: core::mem::ManuallyDrop<Option<windows_core::IUnknown>>,
     pub iid: windows_core::GUID,
@@ -7091,7 +7184,8 @@ impl Default for INTERFACEINFO {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MULTI_QI {
     pub pIID: *const windows_core::GUID,
     pub pItf: core::mem::ManuallyDrop<Option<windows_core::IUnknown>>,
@@ -7111,7 +7205,8 @@ impl Default for MULTI_QI {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MachineGlobalObjectTableRegistrationToken(pub *mut core::ffi::c_void);
 impl MachineGlobalObjectTableRegistrationToken {
     pub fn is_invalid(&self) -> bool {
@@ -7127,7 +7222,8 @@ impl windows_core::TypeKind for MachineGlobalObjectTableRegistrationToken {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct QUERYCONTEXT {
     pub dwContext: u32,
     pub Platform: CSPLATFORM,
@@ -7144,7 +7240,8 @@ impl Default for QUERYCONTEXT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPCOLEMESSAGE {
     pub reserved1: *mut core::ffi::c_void,
     pub dataRepresentation: u32,
@@ -7163,7 +7260,8 @@ impl Default for RPCOLEMESSAGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RemSTGMEDIUM {
     pub tymed: u32,
     pub dwHandleType: u32,
@@ -7181,7 +7279,8 @@ impl Default for RemSTGMEDIUM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SAFEARRAY {
     pub cDims: u16,
     pub fFeatures: ADVANCED_FEATURE_FLAGS,
@@ -7199,7 +7298,8 @@ impl Default for SAFEARRAY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SAFEARRAYBOUND {
     pub cElements: u32,
     pub lLbound: i32,
@@ -7213,7 +7313,8 @@ impl Default for SAFEARRAYBOUND {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SChannelHookCallInfo {
     pub iid: windows_core::GUID,
     pub cbSize: u32,
@@ -7231,7 +7332,8 @@ impl Default for SChannelHookCallInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOLE_AUTHENTICATION_INFO {
     pub dwAuthnSvc: u32,
     pub dwAuthzSvc: u32,
@@ -7246,7 +7348,8 @@ impl Default for SOLE_AUTHENTICATION_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOLE_AUTHENTICATION_LIST {
     pub cAuthInfo: u32,
     pub aAuthInfo: *mut SOLE_AUTHENTICATION_INFO,
@@ -7260,7 +7363,8 @@ impl Default for SOLE_AUTHENTICATION_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOLE_AUTHENTICATION_SERVICE {
     pub dwAuthnSvc: u32,
     pub dwAuthzSvc: u32,
@@ -7276,7 +7380,8 @@ impl Default for SOLE_AUTHENTICATION_SERVICE {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STATDATA {
     pub formatetc: FORMATETC,
     pub advf: u32,
@@ -7297,7 +7402,8 @@ impl Default for STATDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STATSTG {
     pub pwcsName: windows_core::PWSTR,
     pub r#type: u32,
@@ -7370,7 +7476,8 @@ impl Default for STGMEDIUM_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct StorageLayout {
     pub LayoutType: u32,
     pub pwcsElementName: windows_core::PWSTR,
@@ -7386,7 +7493,8 @@ impl Default for StorageLayout {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TLIBATTR {
     pub guid: windows_core::GUID,
     pub lcid: u32,
@@ -7510,7 +7618,8 @@ impl Default for VARDESC_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WORD_BLOB {
     pub clSize: u32,
     pub asData: [u16; 1],
@@ -7524,7 +7633,8 @@ impl Default for WORD_BLOB {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WORD_SIZEDARR {
     pub clSize: u32,
     pub pData: *mut u16,
@@ -7571,7 +7681,8 @@ impl Default for uCLSSPEC_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct uCLSSPEC_0_0 {
     pub pPackageName: windows_core::PWSTR,
     pub PolicyId: windows_core::GUID,
@@ -7585,7 +7696,8 @@ impl Default for uCLSSPEC_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct uCLSSPEC_0_1 {
     pub ObjectId: windows_core::GUID,
     pub PolicyId: windows_core::GUID,
@@ -7686,5 +7798,9 @@ pub type LPEXCEPFINO_DEFERRED_FILLIN = Option<unsafe extern "system" fn(pexcepin
 pub type LPFNCANUNLOADNOW = Option<unsafe extern "system" fn() -> windows_core::HRESULT>;
 pub type LPFNGETCLASSOBJECT = Option<unsafe extern "system" fn(param0: *const windows_core::GUID, param1: *const windows_core::GUID, param2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT>;
 pub type PFNCONTEXTCALL = Option<unsafe extern "system" fn(pparam: *mut ComCallData) -> windows_core::HRESULT>;
+core::include!(core::concat!(core::env!("CARGO_MANIFEST_DIR"), "/src/includes/", "Win32/System/Com/Persist.rs"));
+core::include!(core::concat!(core::env!("CARGO_MANIFEST_DIR"), "/src/includes/", "Win32/System/Com/Security.rs"));
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,2765 @@
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+impl windows_core::imp::ProxyVtable for AsyncIAdviseSink_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+        unsafe extern "system" fn Begin_OnDataChange(this: *mut core::ffi::c_void, pformatetc: *const FORMATETC, pstgmed: *const STGMEDIUM) {
+            windows_core::imp::proxy_call(this, "AsyncIAdviseSink", "Begin_OnDataChange", 3usize, |this| ((**(this as *const *const AsyncIAdviseSink_Vtbl)).Begin_OnDataChange)(this, pformatetc, pstgmed), |_| None)
+        }
+        unsafe extern "system" fn Finish_OnDataChange(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "AsyncIAdviseSink", "Finish_OnDataChange", 4usize, |this| ((**(this as *const *const AsyncIAdviseSink_Vtbl)).Finish_OnDataChange)(this), |_| None)
+        }
+        unsafe extern "system" fn Begin_OnViewChange(this: *mut core::ffi::c_void, dwaspect: u32, lindex: i32) {
+            windows_core::imp::proxy_call(this, "AsyncIAdviseSink", "Begin_OnViewChange", 5usize, |this| ((**(this as *const *const AsyncIAdviseSink_Vtbl)).Begin_OnViewChange)(this, dwaspect, lindex), |_| None)
+        }
+        unsafe extern "system" fn Finish_OnViewChange(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "AsyncIAdviseSink", "Finish_OnViewChange", 6usize, |this| ((**(this as *const *const AsyncIAdviseSink_Vtbl)).Finish_OnViewChange)(this), |_| None)
+        }
+        unsafe extern "system" fn Begin_OnRename(this: *mut core::ffi::c_void, pmk: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "AsyncIAdviseSink", "Begin_OnRename", 7usize, |this| ((**(this as *const *const AsyncIAdviseSink_Vtbl)).Begin_OnRename)(this, pmk), |_| None)
+        }
+        unsafe extern "system" fn Finish_OnRename(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "AsyncIAdviseSink", "Finish_OnRename", 8usize, |this| ((**(this as *const *const AsyncIAdviseSink_Vtbl)).Finish_OnRename)(this), |_| None)
+        }
+        unsafe extern "system" fn Begin_OnSave(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "AsyncIAdviseSink", "Begin_OnSave", 9usize, |this| ((**(this as *const *const AsyncIAdviseSink_Vtbl)).Begin_OnSave)(this), |_| None)
+        }
+        unsafe extern "system" fn Finish_OnSave(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "AsyncIAdviseSink", "Finish_OnSave", 10usize, |this| ((**(this as *const *const AsyncIAdviseSink_Vtbl)).Finish_OnSave)(this), |_| None)
+        }
+        unsafe extern "system" fn Begin_OnClose(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "AsyncIAdviseSink", "Begin_OnClose", 11usize, |this| ((**(this as *const *const AsyncIAdviseSink_Vtbl)).Begin_OnClose)(this), |_| None)
+        }
+        unsafe extern "system" fn Finish_OnClose(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "AsyncIAdviseSink", "Finish_OnClose", 12usize, |this| ((**(this as *const *const AsyncIAdviseSink_Vtbl)).Finish_OnClose)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+            Begin_OnDataChange,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage")))]
+            Begin_OnDataChange: 0,
+            Finish_OnDataChange,
+            Begin_OnViewChange,
+            Finish_OnViewChange,
+            Begin_OnRename,
+            Finish_OnRename,
+            Begin_OnSave,
+            Finish_OnSave,
+            Begin_OnClose,
+            Finish_OnClose,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<AsyncIAdviseSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+impl windows_core::imp::ProxyVtable for AsyncIAdviseSink2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Begin_OnLinkSrcChange(this: *mut core::ffi::c_void, pmk: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "AsyncIAdviseSink2", "Begin_OnLinkSrcChange", 13usize, |this| ((**(this as *const *const AsyncIAdviseSink2_Vtbl)).Begin_OnLinkSrcChange)(this, pmk), |_| None)
+        }
+        unsafe extern "system" fn Finish_OnLinkSrcChange(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "AsyncIAdviseSink2", "Finish_OnLinkSrcChange", 14usize, |this| ((**(this as *const *const AsyncIAdviseSink2_Vtbl)).Finish_OnLinkSrcChange)(this), |_| None)
+        }
+        Self { base__: <AsyncIAdviseSink_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Begin_OnLinkSrcChange, Finish_OnLinkSrcChange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<AsyncIAdviseSink2 as windows_core::Interface>::IID || <AsyncIAdviseSink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for AsyncIMultiQI_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Begin_QueryMultipleInterfaces(this: *mut core::ffi::c_void, cmqis: u32, pmqis: *mut MULTI_QI) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIMultiQI", "Begin_QueryMultipleInterfaces", 3usize, |this| ((**(this as *const *const AsyncIMultiQI_Vtbl)).Begin_QueryMultipleInterfaces)(this, cmqis, pmqis), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_QueryMultipleInterfaces(this: *mut core::ffi::c_void, pmqis: *mut MULTI_QI) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIMultiQI", "Finish_QueryMultipleInterfaces", 4usize, |this| ((**(this as *const *const AsyncIMultiQI_Vtbl)).Finish_QueryMultipleInterfaces)(this, pmqis), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Begin_QueryMultipleInterfaces, Finish_QueryMultipleInterfaces }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<AsyncIMultiQI as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for AsyncIPipeByte_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Begin_Pull(this: *mut core::ffi::c_void, crequest: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIPipeByte", "Begin_Pull", 3usize, |this| ((**(this as *const *const AsyncIPipeByte_Vtbl)).Begin_Pull)(this, crequest), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_Pull(this: *mut core::ffi::c_void, buf: *mut u8, pcreturned: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIPipeByte", "Finish_Pull", 4usize, |this| ((**(this as *const *const AsyncIPipeByte_Vtbl)).Finish_Pull)(this, buf, pcreturned), |result| Some(*result))
+        }
+        unsafe extern "system" fn Begin_Push(this: *mut core::ffi::c_void, buf: *const u8, csent: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIPipeByte", "Begin_Push", 5usize, |this| ((**(this as *const *const AsyncIPipeByte_Vtbl)).Begin_Push)(this, buf, csent), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_Push(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIPipeByte", "Finish_Push", 6usize, |this| ((**(this as *const *const AsyncIPipeByte_Vtbl)).Finish_Push)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Begin_Pull, Finish_Pull, Begin_Push, Finish_Push }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<AsyncIPipeByte as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for AsyncIPipeDouble_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Begin_Pull(this: *mut core::ffi::c_void, crequest: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIPipeDouble", "Begin_Pull", 3usize, |this| ((**(this as *const *const AsyncIPipeDouble_Vtbl)).Begin_Pull)(this, crequest), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_Pull(this: *mut core::ffi::c_void, buf: *mut f64, pcreturned: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIPipeDouble", "Finish_Pull", 4usize, |this| ((**(this as *const *const AsyncIPipeDouble_Vtbl)).Finish_Pull)(this, buf, pcreturned), |result| Some(*result))
+        }
+        unsafe extern "system" fn Begin_Push(this: *mut core::ffi::c_void, buf: *const f64, csent: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIPipeDouble", "Begin_Push", 5usize, |this| ((**(this as *const *const AsyncIPipeDouble_Vtbl)).Begin_Push)(this, buf, csent), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_Push(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIPipeDouble", "Finish_Push", 6usize, |this| ((**(this as *const *const AsyncIPipeDouble_Vtbl)).Finish_Push)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Begin_Pull, Finish_Pull, Begin_Push, Finish_Push }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<AsyncIPipeDouble as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for AsyncIPipeLong_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Begin_Pull(this: *mut core::ffi::c_void, crequest: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIPipeLong", "Begin_Pull", 3usize, |this| ((**(this as *const *const AsyncIPipeLong_Vtbl)).Begin_Pull)(this, crequest), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_Pull(this: *mut core::ffi::c_void, buf: *mut i32, pcreturned: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIPipeLong", "Finish_Pull", 4usize, |this| ((**(this as *const *const AsyncIPipeLong_Vtbl)).Finish_Pull)(this, buf, pcreturned), |result| Some(*result))
+        }
+        unsafe extern "system" fn Begin_Push(this: *mut core::ffi::c_void, buf: *const i32, csent: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIPipeLong", "Begin_Push", 5usize, |this| ((**(this as *const *const AsyncIPipeLong_Vtbl)).Begin_Push)(this, buf, csent), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_Push(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIPipeLong", "Finish_Push", 6usize, |this| ((**(this as *const *const AsyncIPipeLong_Vtbl)).Finish_Push)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Begin_Pull, Finish_Pull, Begin_Push, Finish_Push }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<AsyncIPipeLong as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for AsyncIUnknown_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Begin_QueryInterface(this: *mut core::ffi::c_void, riid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIUnknown", "Begin_QueryInterface", 3usize, |this| ((**(this as *const *const AsyncIUnknown_Vtbl)).Begin_QueryInterface)(this, riid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_QueryInterface(this: *mut core::ffi::c_void, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIUnknown", "Finish_QueryInterface", 4usize, |this| ((**(this as *const *const AsyncIUnknown_Vtbl)).Finish_QueryInterface)(this, ppvobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn Begin_AddRef(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIUnknown", "Begin_AddRef", 5usize, |this| ((**(this as *const *const AsyncIUnknown_Vtbl)).Begin_AddRef)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_AddRef(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "AsyncIUnknown", "Finish_AddRef", 6usize, |this| ((**(this as *const *const AsyncIUnknown_Vtbl)).Finish_AddRef)(this), |_| None)
+        }
+        unsafe extern "system" fn Begin_Release(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIUnknown", "Begin_Release", 7usize, |this| ((**(this as *const *const AsyncIUnknown_Vtbl)).Begin_Release)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_Release(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "AsyncIUnknown", "Finish_Release", 8usize, |this| ((**(this as *const *const AsyncIUnknown_Vtbl)).Finish_Release)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Begin_QueryInterface,
+            Finish_QueryInterface,
+            Begin_AddRef,
+            Finish_AddRef,
+            Begin_Release,
+            Finish_Release,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<AsyncIUnknown as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActivationFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HandleActivation(this: *mut core::ffi::c_void, dwactivationtype: u32, rclsid: *const windows_core::GUID, preplacementclsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationFilter", "HandleActivation", 3usize, |this| ((**(this as *const *const IActivationFilter_Vtbl)).HandleActivation)(this, dwactivationtype, rclsid, preplacementclsid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HandleActivation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActivationFilter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAddrExclusionControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentAddrExclusionList(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddrExclusionControl", "GetCurrentAddrExclusionList", 3usize, |this| ((**(this as *const *const IAddrExclusionControl_Vtbl)).GetCurrentAddrExclusionList)(this, riid, ppenumerator), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateAddrExclusionList(this: *mut core::ffi::c_void, penumerator: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddrExclusionControl", "UpdateAddrExclusionList", 4usize, |this| ((**(this as *const *const IAddrExclusionControl_Vtbl)).UpdateAddrExclusionList)(this, penumerator), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrentAddrExclusionList, UpdateAddrExclusionList }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAddrExclusionControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAddrTrackingControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnableCOMDynamicAddrTracking(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddrTrackingControl", "EnableCOMDynamicAddrTracking", 3usize, |this| ((**(this as *const *const IAddrTrackingControl_Vtbl)).EnableCOMDynamicAddrTracking)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableCOMDynamicAddrTracking(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAddrTrackingControl", "DisableCOMDynamicAddrTracking", 4usize, |this| ((**(this as *const *const IAddrTrackingControl_Vtbl)).DisableCOMDynamicAddrTracking)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnableCOMDynamicAddrTracking, DisableCOMDynamicAddrTracking }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAddrTrackingControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+impl windows_core::imp::ProxyVtable for IAdviseSink_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+        unsafe extern "system" fn OnDataChange(this: *mut core::ffi::c_void, pformatetc: *const FORMATETC, pstgmed: *const STGMEDIUM) {
+            windows_core::imp::proxy_call(this, "IAdviseSink", "OnDataChange", 3usize, |this| ((**(this as *const *const IAdviseSink_Vtbl)).OnDataChange)(this, pformatetc, pstgmed), |_| None)
+        }
+        unsafe extern "system" fn OnViewChange(this: *mut core::ffi::c_void, dwaspect: u32, lindex: i32) {
+            windows_core::imp::proxy_call(this, "IAdviseSink", "OnViewChange", 4usize, |this| ((**(this as *const *const IAdviseSink_Vtbl)).OnViewChange)(this, dwaspect, lindex), |_| None)
+        }
+        unsafe extern "system" fn OnRename(this: *mut core::ffi::c_void, pmk: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IAdviseSink", "OnRename", 5usize, |this| ((**(this as *const *const IAdviseSink_Vtbl)).OnRename)(this, pmk), |_| None)
+        }
+        unsafe extern "system" fn OnSave(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IAdviseSink", "OnSave", 6usize, |this| ((**(this as *const *const IAdviseSink_Vtbl)).OnSave)(this), |_| None)
+        }
+        unsafe extern "system" fn OnClose(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IAdviseSink", "OnClose", 7usize, |this| ((**(this as *const *const IAdviseSink_Vtbl)).OnClose)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+            OnDataChange,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage")))]
+            OnDataChange: 0,
+            OnViewChange,
+            OnRename,
+            OnSave,
+            OnClose,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdviseSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+impl windows_core::imp::ProxyVtable for IAdviseSink2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnLinkSrcChange(this: *mut core::ffi::c_void, pmk: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IAdviseSink2", "OnLinkSrcChange", 8usize, |this| ((**(this as *const *const IAdviseSink2_Vtbl)).OnLinkSrcChange)(this, pmk), |_| None)
+        }
+        Self { base__: <IAdviseSink_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnLinkSrcChange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdviseSink2 as windows_core::Interface>::IID || <IAdviseSink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAgileObject_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAgileObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAsyncManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CompleteCall(this: *mut core::ffi::c_void, result: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncManager", "CompleteCall", 3usize, |this| ((**(this as *const *const IAsyncManager_Vtbl)).CompleteCall)(this, result), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCallContext(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, pinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncManager", "GetCallContext", 4usize, |this| ((**(this as *const *const IAsyncManager_Vtbl)).GetCallContext)(this, riid, pinterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetState(this: *mut core::ffi::c_void, pulstateflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncManager", "GetState", 5usize, |this| ((**(this as *const *const IAsyncManager_Vtbl)).GetState)(this, pulstateflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CompleteCall, GetCallContext, GetState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAsyncManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAsyncRpcChannelBuffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Send(this: *mut core::ffi::c_void, pmsg: *mut RPCOLEMESSAGE, psync: *mut core::ffi::c_void, pulstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncRpcChannelBuffer", "Send", 9usize, |this| ((**(this as *const *const IAsyncRpcChannelBuffer_Vtbl)).Send)(this, pmsg, psync, pulstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn Receive(this: *mut core::ffi::c_void, pmsg: *mut RPCOLEMESSAGE, pulstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncRpcChannelBuffer", "Receive", 10usize, |this| ((**(this as *const *const IAsyncRpcChannelBuffer_Vtbl)).Receive)(this, pmsg, pulstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDestCtxEx(this: *mut core::ffi::c_void, pmsg: *const RPCOLEMESSAGE, pdwdestcontext: *mut u32, ppvdestcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncRpcChannelBuffer", "GetDestCtxEx", 11usize, |this| ((**(this as *const *const IAsyncRpcChannelBuffer_Vtbl)).GetDestCtxEx)(this, pmsg, pdwdestcontext, ppvdestcontext), |result| Some(*result))
+        }
+        Self { base__: <IRpcChannelBuffer2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Send, Receive, GetDestCtxEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAsyncRpcChannelBuffer as windows_core::Interface>::IID || <IRpcChannelBuffer2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAuthenticate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Authenticate(this: *mut core::ffi::c_void, phwnd: *mut super::super::Foundation::HWND, pszusername: *mut windows_core::PWSTR, pszpassword: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAuthenticate", "Authenticate", 3usize, |this| ((**(this as *const *const IAuthenticate_Vtbl)).Authenticate)(this, phwnd, pszusername, pszpassword), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Authenticate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAuthenticate as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAuthenticateEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AuthenticateEx(this: *mut core::ffi::c_void, phwnd: *mut super::super::Foundation::HWND, pszusername: *mut windows_core::PWSTR, pszpassword: *mut windows_core::PWSTR, pauthinfo: *const AUTHENTICATEINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAuthenticateEx", "AuthenticateEx", 4usize, |this| ((**(this as *const *const IAuthenticateEx_Vtbl)).AuthenticateEx)(this, phwnd, pszusername, pszpassword, pauthinfo), |result| Some(*result))
+        }
+        Self { base__: <IAuthenticate_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AuthenticateEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAuthenticateEx as windows_core::Interface>::IID || <IAuthenticate_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBindCtx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterObjectBound(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBindCtx", "RegisterObjectBound", 3usize, |this| ((**(this as *const *const IBindCtx_Vtbl)).RegisterObjectBound)(this, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn RevokeObjectBound(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBindCtx", "RevokeObjectBound", 4usize, |this| ((**(this as *const *const IBindCtx_Vtbl)).RevokeObjectBound)(this, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseBoundObjects(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBindCtx", "ReleaseBoundObjects", 5usize, |this| ((**(this as *const *const IBindCtx_Vtbl)).ReleaseBoundObjects)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBindOptions(this: *mut core::ffi::c_void, pbindopts: *const BIND_OPTS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBindCtx", "SetBindOptions", 6usize, |this| ((**(this as *const *const IBindCtx_Vtbl)).SetBindOptions)(this, pbindopts), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBindOptions(this: *mut core::ffi::c_void, pbindopts: *mut BIND_OPTS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBindCtx", "GetBindOptions", 7usize, |this| ((**(this as *const *const IBindCtx_Vtbl)).GetBindOptions)(this, pbindopts), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRunningObjectTable(this: *mut core::ffi::c_void, pprot: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBindCtx", "GetRunningObjectTable", 8usize, |this| ((**(this as *const *const IBindCtx_Vtbl)).GetRunningObjectTable)(this, pprot), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterObjectParam(this: *mut core::ffi::c_void, pszkey: windows_core::PCWSTR, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBindCtx", "RegisterObjectParam", 9usize, |this| ((**(this as *const *const IBindCtx_Vtbl)).RegisterObjectParam)(this, pszkey, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObjectParam(this: *mut core::ffi::c_void, pszkey: windows_core::PCWSTR, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBindCtx", "GetObjectParam", 10usize, |this| ((**(this as *const *const IBindCtx_Vtbl)).GetObjectParam)(this, pszkey, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumObjectParam(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBindCtx", "EnumObjectParam", 11usize, |this| ((**(this as *const *const IBindCtx_Vtbl)).EnumObjectParam)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn RevokeObjectParam(this: *mut core::ffi::c_void, pszkey: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBindCtx", "RevokeObjectParam", 12usize, |this| ((**(this as *const *const IBindCtx_Vtbl)).RevokeObjectParam)(this, pszkey), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RegisterObjectBound,
+            RevokeObjectBound,
+            ReleaseBoundObjects,
+            SetBindOptions,
+            GetBindOptions,
+            GetRunningObjectTable,
+            RegisterObjectParam,
+            GetObjectParam,
+            EnumObjectParam,
+            RevokeObjectParam,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBindCtx as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBindHost_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateMoniker(this: *mut core::ffi::c_void, szname: windows_core::PCWSTR, pbc: *mut core::ffi::c_void, ppmk: *mut *mut core::ffi::c_void, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBindHost", "CreateMoniker", 3usize, |this| ((**(this as *const *const IBindHost_Vtbl)).CreateMoniker)(this, szname, pbc, ppmk, dwreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn MonikerBindToStorage(this: *mut core::ffi::c_void, pmk: *mut core::ffi::c_void, pbc: *mut core::ffi::c_void, pbsc: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBindHost", "MonikerBindToStorage", 4usize, |this| ((**(this as *const *const IBindHost_Vtbl)).MonikerBindToStorage)(this, pmk, pbc, pbsc, riid, ppvobj), |result| Some(*result))
+        }
+        unsafe extern "system" fn MonikerBindToObject(this: *mut core::ffi::c_void, pmk: *mut core::ffi::c_void, pbc: *mut core::ffi::c_void, pbsc: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBindHost", "MonikerBindToObject", 5usize, |this| ((**(this as *const *const IBindHost_Vtbl)).MonikerBindToObject)(this, pmk, pbc, pbsc, riid, ppvobj), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateMoniker, MonikerBindToStorage, MonikerBindToObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBindHost as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Security", feature = "Win32_System_Com_StructuredStorage"))]
+impl windows_core::imp::ProxyVtable for IBindStatusCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnStartBinding(this: *mut core::ffi::c_void, dwreserved: u32, pib: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBindStatusCallback", "OnStartBinding", 3usize, |this| ((**(this as *const *const IBindStatusCallback_Vtbl)).OnStartBinding)(this, dwreserved, pib), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPriority(this: *mut core::ffi::c_void, pnpriority: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBindStatusCallback", "GetPriority", 4usize, |this| ((**(this as *const *const IBindStatusCallback_Vtbl)).GetPriority)(this, pnpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnLowResource(this: *mut core::ffi::c_void, reserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBindStatusCallback", "OnLowResource", 5usize, |this| ((**(this as *const *const IBindStatusCallback_Vtbl)).OnLowResource)(this, reserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnProgress(this: *mut core::ffi::c_void, ulprogress: u32, ulprogressmax: u32, ulstatuscode: u32, szstatustext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBindStatusCallback", "OnProgress", 6usize, |this| ((**(this as *const *const IBindStatusCallback_Vtbl)).OnProgress)(this, ulprogress, ulprogressmax, ulstatuscode, szstatustext), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnStopBinding(this: *mut core::ffi::c_void, hresult: windows_core::HRESULT, szerror: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBindStatusCallback", "OnStopBinding", 7usize, |this| ((**(this as *const *const IBindStatusCallback_Vtbl)).OnStopBinding)(this, hresult, szerror), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Security", feature = "Win32_System_Com_StructuredStorage"))]
+        unsafe extern "system" fn GetBindInfo(this: *mut core::ffi::c_void, grfbindf: *mut u32, pbindinfo: *mut BINDINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBindStatusCallback", "GetBindInfo", 8usize, |this| ((**(this as *const *const IBindStatusCallback_Vtbl)).GetBindInfo)(this, grfbindf, pbindinfo), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+        unsafe extern "system" fn OnDataAvailable(this: *mut core::ffi::c_void, grfbscf: u32, dwsize: u32, pformatetc: *const FORMATETC, pstgmed: *const STGMEDIUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBindStatusCallback", "OnDataAvailable", 9usize, |this| ((**(this as *const *const IBindStatusCallback_Vtbl)).OnDataAvailable)(this, grfbscf, dwsize, pformatetc, pstgmed), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnObjectAvailable(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBindStatusCallback", "OnObjectAvailable", 10usize, |this| ((**(this as *const *const IBindStatusCallback_Vtbl)).OnObjectAvailable)(this, riid, punk), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnStartBinding,
+            GetPriority,
+            OnLowResource,
+            OnProgress,
+            OnStopBinding,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Security", feature = "Win32_System_Com_StructuredStorage"))]
+            GetBindInfo,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Security", feature = "Win32_System_Com_StructuredStorage")))]
+            GetBindInfo: 0,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+            OnDataAvailable,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage")))]
+            OnDataAvailable: 0,
+            OnObjectAvailable,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBindStatusCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Security", feature = "Win32_System_Com_StructuredStorage"))]
+impl windows_core::imp::ProxyVtable for IBindStatusCallbackEx_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Security", feature = "Win32_System_Com_StructuredStorage"))]
+        unsafe extern "system" fn GetBindInfoEx(this: *mut core::ffi::c_void, grfbindf: *mut u32, pbindinfo: *mut BINDINFO, grfbindf2: *mut u32, pdwreserved: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBindStatusCallbackEx", "GetBindInfoEx", 11usize, |this| ((**(this as *const *const IBindStatusCallbackEx_Vtbl)).GetBindInfoEx)(this, grfbindf, pbindinfo, grfbindf2, pdwreserved), |result| Some(*result))
+        }
+        Self {
+            base__: <IBindStatusCallback_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Security", feature = "Win32_System_Com_StructuredStorage"))]
+            GetBindInfoEx,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Security", feature = "Win32_System_Com_StructuredStorage")))]
+            GetBindInfoEx: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBindStatusCallbackEx as windows_core::Interface>::IID || <IBindStatusCallback_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBinding_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Abort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBinding", "Abort", 3usize, |this| ((**(this as *const *const IBinding_Vtbl)).Abort)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Suspend(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBinding", "Suspend", 4usize, |this| ((**(this as *const *const IBinding_Vtbl)).Suspend)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBinding", "Resume", 5usize, |this| ((**(this as *const *const IBinding_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPriority(this: *mut core::ffi::c_void, npriority: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBinding", "SetPriority", 6usize, |this| ((**(this as *const *const IBinding_Vtbl)).SetPriority)(this, npriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPriority(this: *mut core::ffi::c_void, pnpriority: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBinding", "GetPriority", 7usize, |this| ((**(this as *const *const IBinding_Vtbl)).GetPriority)(this, pnpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBindResult(this: *mut core::ffi::c_void, pclsidprotocol: *mut windows_core::GUID, pdwresult: *mut u32, pszresult: *mut windows_core::PWSTR, pdwreserved: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBinding", "GetBindResult", 8usize, |this| ((**(this as *const *const IBinding_Vtbl)).GetBindResult)(this, pclsidprotocol, pdwresult, pszresult, pdwreserved), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Abort, Suspend, Resume, SetPriority, GetPriority, GetBindResult }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBinding as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBlockingLock_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Lock(this: *mut core::ffi::c_void, dwtimeout: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBlockingLock", "Lock", 3usize, |this| ((**(this as *const *const IBlockingLock_Vtbl)).Lock)(this, dwtimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unlock(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBlockingLock", "Unlock", 4usize, |this| ((**(this as *const *const IBlockingLock_Vtbl)).Unlock)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Lock, Unlock }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBlockingLock as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICallFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateCall(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, pctrlunk: *mut core::ffi::c_void, riid2: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICallFactory", "CreateCall", 3usize, |this| ((**(this as *const *const ICallFactory_Vtbl)).CreateCall)(this, riid, pctrlunk, riid2, ppv), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateCall }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICallFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICancelMethodCalls_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void, ulseconds: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICancelMethodCalls", "Cancel", 3usize, |this| ((**(this as *const *const ICancelMethodCalls_Vtbl)).Cancel)(this, ulseconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn TestCancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICancelMethodCalls", "TestCancel", 4usize, |this| ((**(this as *const *const ICancelMethodCalls_Vtbl)).TestCancel)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Cancel, TestCancel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICancelMethodCalls as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICatInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumCategories(this: *mut core::ffi::c_void, lcid: u32, ppenumcategoryinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatInformation", "EnumCategories", 3usize, |this| ((**(this as *const *const ICatInformation_Vtbl)).EnumCategories)(this, lcid, ppenumcategoryinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCategoryDesc(this: *mut core::ffi::c_void, rcatid: *const windows_core::GUID, lcid: u32, pszdesc: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatInformation", "GetCategoryDesc", 4usize, |this| ((**(this as *const *const ICatInformation_Vtbl)).GetCategoryDesc)(this, rcatid, lcid, pszdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumClassesOfCategories(this: *mut core::ffi::c_void, cimplemented: u32, rgcatidimpl: *const windows_core::GUID, crequired: u32, rgcatidreq: *const windows_core::GUID, ppenumclsid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatInformation", "EnumClassesOfCategories", 5usize, |this| ((**(this as *const *const ICatInformation_Vtbl)).EnumClassesOfCategories)(this, cimplemented, rgcatidimpl, crequired, rgcatidreq, ppenumclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsClassOfCategories(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, cimplemented: u32, rgcatidimpl: *const windows_core::GUID, crequired: u32, rgcatidreq: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatInformation", "IsClassOfCategories", 6usize, |this| ((**(this as *const *const ICatInformation_Vtbl)).IsClassOfCategories)(this, rclsid, cimplemented, rgcatidimpl, crequired, rgcatidreq), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumImplCategoriesOfClass(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, ppenumcatid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatInformation", "EnumImplCategoriesOfClass", 7usize, |this| ((**(this as *const *const ICatInformation_Vtbl)).EnumImplCategoriesOfClass)(this, rclsid, ppenumcatid), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumReqCategoriesOfClass(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, ppenumcatid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatInformation", "EnumReqCategoriesOfClass", 8usize, |this| ((**(this as *const *const ICatInformation_Vtbl)).EnumReqCategoriesOfClass)(this, rclsid, ppenumcatid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EnumCategories,
+            GetCategoryDesc,
+            EnumClassesOfCategories,
+            IsClassOfCategories,
+            EnumImplCategoriesOfClass,
+            EnumReqCategoriesOfClass,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICatInformation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICatRegister_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterCategories(this: *mut core::ffi::c_void, ccategories: u32, rgcategoryinfo: *const CATEGORYINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatRegister", "RegisterCategories", 3usize, |this| ((**(this as *const *const ICatRegister_Vtbl)).RegisterCategories)(this, ccategories, rgcategoryinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnRegisterCategories(this: *mut core::ffi::c_void, ccategories: u32, rgcatid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatRegister", "UnRegisterCategories", 4usize, |this| ((**(this as *const *const ICatRegister_Vtbl)).UnRegisterCategories)(this, ccategories, rgcatid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterClassImplCategories(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, ccategories: u32, rgcatid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatRegister", "RegisterClassImplCategories", 5usize, |this| ((**(this as *const *const ICatRegister_Vtbl)).RegisterClassImplCategories)(this, rclsid, ccategories, rgcatid), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnRegisterClassImplCategories(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, ccategories: u32, rgcatid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatRegister", "UnRegisterClassImplCategories", 6usize, |this| ((**(this as *const *const ICatRegister_Vtbl)).UnRegisterClassImplCategories)(this, rclsid, ccategories, rgcatid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterClassReqCategories(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, ccategories: u32, rgcatid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatRegister", "RegisterClassReqCategories", 7usize, |this| ((**(this as *const *const ICatRegister_Vtbl)).RegisterClassReqCategories)(this, rclsid, ccategories, rgcatid), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnRegisterClassReqCategories(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, ccategories: u32, rgcatid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatRegister", "UnRegisterClassReqCategories", 8usize, |this| ((**(this as *const *const ICatRegister_Vtbl)).UnRegisterClassReqCategories)(this, rclsid, ccategories, rgcatid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RegisterCategories,
+            UnRegisterCategories,
+            RegisterClassImplCategories,
+            UnRegisterClassImplCategories,
+            RegisterClassReqCategories,
+            UnRegisterClassReqCategories,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICatRegister as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChannelHook_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ClientGetSize(this: *mut core::ffi::c_void, uextent: *const windows_core::GUID, riid: *const windows_core::GUID, pdatasize: *mut u32) {
+            windows_core::imp::proxy_call(this, "IChannelHook", "ClientGetSize", 3usize, |this| ((**(this as *const *const IChannelHook_Vtbl)).ClientGetSize)(this, uextent, riid, pdatasize), |_| None)
+        }
+        unsafe extern "system" fn ClientFillBuffer(this: *mut core::ffi::c_void, uextent: *const windows_core::GUID, riid: *const windows_core::GUID, pdatasize: *mut u32, pdatabuffer: *const core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IChannelHook", "ClientFillBuffer", 4usize, |this| ((**(this as *const *const IChannelHook_Vtbl)).ClientFillBuffer)(this, uextent, riid, pdatasize, pdatabuffer), |_| None)
+        }
+        unsafe extern "system" fn ClientNotify(this: *mut core::ffi::c_void, uextent: *const windows_core::GUID, riid: *const windows_core::GUID, cbdatasize: u32, pdatabuffer: *const core::ffi::c_void, ldatarep: u32, hrfault: windows_core::HRESULT) {
+            windows_core::imp::proxy_call(this, "IChannelHook", "ClientNotify", 5usize, |this| ((**(this as *const *const IChannelHook_Vtbl)).ClientNotify)(this, uextent, riid, cbdatasize, pdatabuffer, ldatarep, hrfault), |_| None)
+        }
+        unsafe extern "system" fn ServerNotify(this: *mut core::ffi::c_void, uextent: *const windows_core::GUID, riid: *const windows_core::GUID, cbdatasize: u32, pdatabuffer: *const core::ffi::c_void, ldatarep: u32) {
+            windows_core::imp::proxy_call(this, "IChannelHook", "ServerNotify", 6usize, |this| ((**(this as *const *const IChannelHook_Vtbl)).ServerNotify)(this, uextent, riid, cbdatasize, pdatabuffer, ldatarep), |_| None)
+        }
+        unsafe extern "system" fn ServerGetSize(this: *mut core::ffi::c_void, uextent: *const windows_core::GUID, riid: *const windows_core::GUID, hrfault: windows_core::HRESULT, pdatasize: *mut u32) {
+            windows_core::imp::proxy_call(this, "IChannelHook", "ServerGetSize", 7usize, |this| ((**(this as *const *const IChannelHook_Vtbl)).ServerGetSize)(this, uextent, riid, hrfault, pdatasize), |_| None)
+        }
+        unsafe extern "system" fn ServerFillBuffer(this: *mut core::ffi::c_void, uextent: *const windows_core::GUID, riid: *const windows_core::GUID, pdatasize: *mut u32, pdatabuffer: *const core::ffi::c_void, hrfault: windows_core::HRESULT) {
+            windows_core::imp::proxy_call(this, "IChannelHook", "ServerFillBuffer", 8usize, |this| ((**(this as *const *const IChannelHook_Vtbl)).ServerFillBuffer)(this, uextent, riid, pdatasize, pdatabuffer, hrfault), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ClientGetSize,
+            ClientFillBuffer,
+            ClientNotify,
+            ServerNotify,
+            ServerGetSize,
+            ServerFillBuffer,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChannelHook as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClassActivator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetClassObject(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, dwclasscontext: u32, locale: u32, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClassActivator", "GetClassObject", 3usize, |this| ((**(this as *const *const IClassActivator_Vtbl)).GetClassObject)(this, rclsid, dwclasscontext, locale, riid, ppv), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetClassObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClassActivator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClassFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClassFactory", "CreateInstance", 3usize, |this| ((**(this as *const *const IClassFactory_Vtbl)).CreateInstance)(this, punkouter, riid, ppvobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockServer(this: *mut core::ffi::c_void, flock: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClassFactory", "LockServer", 4usize, |this| ((**(this as *const *const IClassFactory_Vtbl)).LockServer)(this, flock), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance, LockServer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClassFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClientSecurity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryBlanket(this: *mut core::ffi::c_void, pproxy: *mut core::ffi::c_void, pauthnsvc: *mut u32, pauthzsvc: *mut u32, pserverprincname: *mut *mut u16, pauthnlevel: *mut RPC_C_AUTHN_LEVEL, pimplevel: *mut RPC_C_IMP_LEVEL, pauthinfo: *mut *mut core::ffi::c_void, pcapabilites: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClientSecurity", "QueryBlanket", 3usize, |this| ((**(this as *const *const IClientSecurity_Vtbl)).QueryBlanket)(this, pproxy, pauthnsvc, pauthzsvc, pserverprincname, pauthnlevel, pimplevel, pauthinfo, pcapabilites), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBlanket(this: *mut core::ffi::c_void, pproxy: *mut core::ffi::c_void, dwauthnsvc: u32, dwauthzsvc: u32, pserverprincname: windows_core::PCWSTR, dwauthnlevel: RPC_C_AUTHN_LEVEL, dwimplevel: RPC_C_IMP_LEVEL, pauthinfo: *const core::ffi::c_void, dwcapabilities: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClientSecurity", "SetBlanket", 4usize, |this| ((**(this as *const *const IClientSecurity_Vtbl)).SetBlanket)(this, pproxy, dwauthnsvc, dwauthzsvc, pserverprincname, dwauthnlevel, dwimplevel, pauthinfo, dwcapabilities), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyProxy(this: *mut core::ffi::c_void, pproxy: *mut core::ffi::c_void, ppcopy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClientSecurity", "CopyProxy", 5usize, |this| ((**(this as *const *const IClientSecurity_Vtbl)).CopyProxy)(this, pproxy, ppcopy), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryBlanket, SetBlanket, CopyProxy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClientSecurity as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComThreadingInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentApartmentType(this: *mut core::ffi::c_void, papttype: *mut APTTYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComThreadingInfo", "GetCurrentApartmentType", 3usize, |this| ((**(this as *const *const IComThreadingInfo_Vtbl)).GetCurrentApartmentType)(this, papttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentThreadType(this: *mut core::ffi::c_void, pthreadtype: *mut THDTYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComThreadingInfo", "GetCurrentThreadType", 4usize, |this| ((**(this as *const *const IComThreadingInfo_Vtbl)).GetCurrentThreadType)(this, pthreadtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentLogicalThreadId(this: *mut core::ffi::c_void, pguidlogicalthreadid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComThreadingInfo", "GetCurrentLogicalThreadId", 5usize, |this| ((**(this as *const *const IComThreadingInfo_Vtbl)).GetCurrentLogicalThreadId)(this, pguidlogicalthreadid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentLogicalThreadId(this: *mut core::ffi::c_void, rguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComThreadingInfo", "SetCurrentLogicalThreadId", 6usize, |this| ((**(this as *const *const IComThreadingInfo_Vtbl)).SetCurrentLogicalThreadId)(this, rguid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCurrentApartmentType,
+            GetCurrentThreadType,
+            GetCurrentLogicalThreadId,
+            SetCurrentLogicalThreadId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComThreadingInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConnectionPoint_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetConnectionInterface(this: *mut core::ffi::c_void, piid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionPoint", "GetConnectionInterface", 3usize, |this| ((**(this as *const *const IConnectionPoint_Vtbl)).GetConnectionInterface)(this, piid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConnectionPointContainer(this: *mut core::ffi::c_void, ppcpc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionPoint", "GetConnectionPointContainer", 4usize, |this| ((**(this as *const *const IConnectionPoint_Vtbl)).GetConnectionPointContainer)(this, ppcpc), |result| Some(*result))
+        }
+        unsafe extern "system" fn Advise(this: *mut core::ffi::c_void, punksink: *mut core::ffi::c_void, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionPoint", "Advise", 5usize, |this| ((**(this as *const *const IConnectionPoint_Vtbl)).Advise)(this, punksink, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unadvise(this: *mut core::ffi::c_void, dwcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionPoint", "Unadvise", 6usize, |this| ((**(this as *const *const IConnectionPoint_Vtbl)).Unadvise)(this, dwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumConnections(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionPoint", "EnumConnections", 7usize, |this| ((**(this as *const *const IConnectionPoint_Vtbl)).EnumConnections)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetConnectionInterface,
+            GetConnectionPointContainer,
+            Advise,
+            Unadvise,
+            EnumConnections,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConnectionPoint as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConnectionPointContainer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumConnectionPoints(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionPointContainer", "EnumConnectionPoints", 3usize, |this| ((**(this as *const *const IConnectionPointContainer_Vtbl)).EnumConnectionPoints)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindConnectionPoint(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppcp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConnectionPointContainer", "FindConnectionPoint", 4usize, |this| ((**(this as *const *const IConnectionPointContainer_Vtbl)).FindConnectionPoint)(this, riid, ppcp), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnumConnectionPoints, FindConnectionPoint }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConnectionPointContainer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, rpolicyid: *const windows_core::GUID, flags: u32, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContext", "SetProperty", 3usize, |this| ((**(this as *const *const IContext_Vtbl)).SetProperty)(this, rpolicyid, flags, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveProperty(this: *mut core::ffi::c_void, rpolicyid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContext", "RemoveProperty", 4usize, |this| ((**(this as *const *const IContext_Vtbl)).RemoveProperty)(this, rpolicyid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, rguid: *const windows_core::GUID, pflags: *mut u32, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContext", "GetProperty", 5usize, |this| ((**(this as *const *const IContext_Vtbl)).GetProperty)(this, rguid, pflags, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumContextProps(this: *mut core::ffi::c_void, ppenumcontextprops: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContext", "EnumContextProps", 6usize, |this| ((**(this as *const *const IContext_Vtbl)).EnumContextProps)(this, ppenumcontextprops), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetProperty, RemoveProperty, GetProperty, EnumContextProps }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContext as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContextCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContextCallback(this: *mut core::ffi::c_void, pfncallback: PFNCONTEXTCALL, pparam: *const ComCallData, riid: *const windows_core::GUID, imethod: i32, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContextCallback", "ContextCallback", 3usize, |this| ((**(this as *const *const IContextCallback_Vtbl)).ContextCallback)(this, pfncallback, pparam, riid, imethod, punk), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ContextCallback }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContextCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataAdviseHolder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Advise(this: *mut core::ffi::c_void, pdataobject: *mut core::ffi::c_void, pfetc: *const FORMATETC, advf: u32, padvise: *mut core::ffi::c_void, pdwconnection: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataAdviseHolder", "Advise", 3usize, |this| ((**(this as *const *const IDataAdviseHolder_Vtbl)).Advise)(this, pdataobject, pfetc, advf, padvise, pdwconnection), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unadvise(this: *mut core::ffi::c_void, dwconnection: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataAdviseHolder", "Unadvise", 4usize, |this| ((**(this as *const *const IDataAdviseHolder_Vtbl)).Unadvise)(this, dwconnection), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumAdvise(this: *mut core::ffi::c_void, ppenumadvise: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataAdviseHolder", "EnumAdvise", 5usize, |this| ((**(this as *const *const IDataAdviseHolder_Vtbl)).EnumAdvise)(this, ppenumadvise), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendOnDataChange(this: *mut core::ffi::c_void, pdataobject: *mut core::ffi::c_void, dwreserved: u32, advf: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataAdviseHolder", "SendOnDataChange", 6usize, |this| ((**(this as *const *const IDataAdviseHolder_Vtbl)).SendOnDataChange)(this, pdataobject, dwreserved, advf), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Advise, Unadvise, EnumAdvise, SendOnDataChange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataAdviseHolder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+impl windows_core::imp::ProxyVtable for IDataObject_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+        unsafe extern "system" fn GetData(this: *mut core::ffi::c_void, pformatetcin: *const FORMATETC, pmedium: *mut STGMEDIUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataObject", "GetData", 3usize, |this| ((**(this as *const *const IDataObject_Vtbl)).GetData)(this, pformatetcin, pmedium), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+        unsafe extern "system" fn GetDataHere(this: *mut core::ffi::c_void, pformatetc: *const FORMATETC, pmedium: *mut STGMEDIUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataObject", "GetDataHere", 4usize, |this| ((**(this as *const *const IDataObject_Vtbl)).GetDataHere)(this, pformatetc, pmedium), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryGetData(this: *mut core::ffi::c_void, pformatetc: *const FORMATETC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataObject", "QueryGetData", 5usize, |this| ((**(this as *const *const IDataObject_Vtbl)).QueryGetData)(this, pformatetc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCanonicalFormatEtc(this: *mut core::ffi::c_void, pformatectin: *const FORMATETC, pformatetcout: *mut FORMATETC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataObject", "GetCanonicalFormatEtc", 6usize, |this| ((**(this as *const *const IDataObject_Vtbl)).GetCanonicalFormatEtc)(this, pformatectin, pformatetcout), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, pformatetc: *const FORMATETC, pmedium: *const STGMEDIUM, frelease: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataObject", "SetData", 7usize, |this| ((**(this as *const *const IDataObject_Vtbl)).SetData)(this, pformatetc, pmedium, frelease), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumFormatEtc(this: *mut core::ffi::c_void, dwdirection: u32, ppenumformatetc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataObject", "EnumFormatEtc", 8usize, |this| ((**(this as *const *const IDataObject_Vtbl)).EnumFormatEtc)(this, dwdirection, ppenumformatetc), |result| Some(*result))
+        }
+        unsafe extern "system" fn DAdvise(this: *mut core::ffi::c_void, pformatetc: *const FORMATETC, advf: u32, padvsink: *mut core::ffi::c_void, pdwconnection: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataObject", "DAdvise", 9usize, |this| ((**(this as *const *const IDataObject_Vtbl)).DAdvise)(this, pformatetc, advf, padvsink, pdwconnection), |result| Some(*result))
+        }
+        unsafe extern "system" fn DUnadvise(this: *mut core::ffi::c_void, dwconnection: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataObject", "DUnadvise", 10usize, |this| ((**(this as *const *const IDataObject_Vtbl)).DUnadvise)(this, dwconnection), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumDAdvise(this: *mut core::ffi::c_void, ppenumadvise: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataObject", "EnumDAdvise", 11usize, |this| ((**(this as *const *const IDataObject_Vtbl)).EnumDAdvise)(this, ppenumadvise), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+            GetData,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage")))]
+            GetData: 0,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+            GetDataHere,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage")))]
+            GetDataHere: 0,
+            QueryGetData,
+            GetCanonicalFormatEtc,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+            SetData,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage")))]
+            SetData: 0,
+            EnumFormatEtc,
+            DAdvise,
+            DUnadvise,
+            EnumDAdvise,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDispatch_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTypeInfoCount(this: *mut core::ffi::c_void, pctinfo: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatch", "GetTypeInfoCount", 3usize, |this| ((**(this as *const *const IDispatch_Vtbl)).GetTypeInfoCount)(this, pctinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTypeInfo(this: *mut core::ffi::c_void, itinfo: u32, lcid: u32, pptinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatch", "GetTypeInfo", 4usize, |this| ((**(this as *const *const IDispatch_Vtbl)).GetTypeInfo)(this, itinfo, lcid, pptinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIDsOfNames(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, rgsznames: *const windows_core::PCWSTR, cnames: u32, lcid: u32, rgdispid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatch", "GetIDsOfNames", 5usize, |this| ((**(this as *const *const IDispatch_Vtbl)).GetIDsOfNames)(this, riid, rgsznames, cnames, lcid, rgdispid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Invoke(this: *mut core::ffi::c_void, dispidmember: i32, riid: *const windows_core::GUID, lcid: u32, wflags: DISPATCH_FLAGS, pdispparams: *const DISPPARAMS, pvarresult: *mut core::mem::MaybeUninit<windows_core::VARIANT>, pexcepinfo: *mut EXCEPINFO, puargerr: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatch", "Invoke", 6usize, |this| ((**(this as *const *const IDispatch_Vtbl)).Invoke)(this, dispidmember, riid, lcid, wflags, pdispparams, pvarresult, pexcepinfo, puargerr), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetTypeInfoCount, GetTypeInfo, GetIDsOfNames, Invoke }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDispatch as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumCATEGORYINFO_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut CATEGORYINFO, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCATEGORYINFO", "Next", 3usize, |this| ((**(this as *const *const IEnumCATEGORYINFO_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCATEGORYINFO", "Skip", 4usize, |this| ((**(this as *const *const IEnumCATEGORYINFO_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCATEGORYINFO", "Reset", 5usize, |this| ((**(this as *const *const IEnumCATEGORYINFO_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumCATEGORYINFO", "Clone", 6usize, |this| ((**(this as *const *const IEnumCATEGORYINFO_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumCATEGORYINFO as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumConnectionPoints_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, cconnections: u32, ppcp: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumConnectionPoints", "Next", 3usize, |this| ((**(this as *const *const IEnumConnectionPoints_Vtbl)).Next)(this, cconnections, ppcp, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, cconnections: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumConnectionPoints", "Skip", 4usize, |this| ((**(this as *const *const IEnumConnectionPoints_Vtbl)).Skip)(this, cconnections), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumConnectionPoints", "Reset", 5usize, |this| ((**(this as *const *const IEnumConnectionPoints_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumConnectionPoints", "Clone", 6usize, |this| ((**(this as *const *const IEnumConnectionPoints_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumConnectionPoints as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumConnections_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, cconnections: u32, rgcd: *mut CONNECTDATA, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumConnections", "Next", 3usize, |this| ((**(this as *const *const IEnumConnections_Vtbl)).Next)(this, cconnections, rgcd, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, cconnections: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumConnections", "Skip", 4usize, |this| ((**(this as *const *const IEnumConnections_Vtbl)).Skip)(this, cconnections), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumConnections", "Reset", 5usize, |this| ((**(this as *const *const IEnumConnections_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumConnections", "Clone", 6usize, |this| ((**(this as *const *const IEnumConnections_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumConnections as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumContextProps_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, pcontextproperties: *mut ContextProperty, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumContextProps", "Next", 3usize, |this| ((**(this as *const *const IEnumContextProps_Vtbl)).Next)(this, celt, pcontextproperties, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumContextProps", "Skip", 4usize, |this| ((**(this as *const *const IEnumContextProps_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumContextProps", "Reset", 5usize, |this| ((**(this as *const *const IEnumContextProps_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenumcontextprops: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumContextProps", "Clone", 6usize, |this| ((**(this as *const *const IEnumContextProps_Vtbl)).Clone)(this, ppenumcontextprops), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pcelt: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumContextProps", "Count", 7usize, |this| ((**(this as *const *const IEnumContextProps_Vtbl)).Count)(this, pcelt), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone, Count }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumContextProps as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumFORMATETC_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut FORMATETC, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumFORMATETC", "Next", 3usize, |this| ((**(this as *const *const IEnumFORMATETC_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumFORMATETC", "Skip", 4usize, |this| ((**(this as *const *const IEnumFORMATETC_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumFORMATETC", "Reset", 5usize, |this| ((**(this as *const *const IEnumFORMATETC_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumFORMATETC", "Clone", 6usize, |this| ((**(this as *const *const IEnumFORMATETC_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumFORMATETC as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumGUID_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut windows_core::GUID, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumGUID", "Next", 3usize, |this| ((**(this as *const *const IEnumGUID_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumGUID", "Skip", 4usize, |this| ((**(this as *const *const IEnumGUID_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumGUID", "Reset", 5usize, |this| ((**(this as *const *const IEnumGUID_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumGUID", "Clone", 6usize, |this| ((**(this as *const *const IEnumGUID_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumGUID as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumMoniker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumMoniker", "Next", 3usize, |this| ((**(this as *const *const IEnumMoniker_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumMoniker", "Skip", 4usize, |this| ((**(this as *const *const IEnumMoniker_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumMoniker", "Reset", 5usize, |this| ((**(this as *const *const IEnumMoniker_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumMoniker", "Clone", 6usize, |this| ((**(this as *const *const IEnumMoniker_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumMoniker as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumSTATDATA_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut STATDATA, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSTATDATA", "Next", 3usize, |this| ((**(this as *const *const IEnumSTATDATA_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSTATDATA", "Skip", 4usize, |this| ((**(this as *const *const IEnumSTATDATA_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSTATDATA", "Reset", 5usize, |this| ((**(this as *const *const IEnumSTATDATA_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSTATDATA", "Clone", 6usize, |this| ((**(this as *const *const IEnumSTATDATA_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumSTATDATA as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumString_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut windows_core::PWSTR, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumString", "Next", 3usize, |this| ((**(this as *const *const IEnumString_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumString", "Skip", 4usize, |this| ((**(this as *const *const IEnumString_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumString", "Reset", 5usize, |this| ((**(this as *const *const IEnumString_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumString", "Clone", 6usize, |this| ((**(this as *const *const IEnumString_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumString as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumUnknown_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumUnknown", "Next", 3usize, |this| ((**(this as *const *const IEnumUnknown_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumUnknown", "Skip", 4usize, |this| ((**(this as *const *const IEnumUnknown_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumUnknown", "Reset", 5usize, |this| ((**(this as *const *const IEnumUnknown_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumUnknown", "Clone", 6usize, |this| ((**(this as *const *const IEnumUnknown_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumUnknown as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IErrorInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetGUID(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IErrorInfo", "GetGUID", 3usize, |this| ((**(this as *const *const IErrorInfo_Vtbl)).GetGUID)(this, pguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSource(this: *mut core::ffi::c_void, pbstrsource: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IErrorInfo", "GetSource", 4usize, |this| ((**(this as *const *const IErrorInfo_Vtbl)).GetSource)(this, pbstrsource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescription(this: *mut core::ffi::c_void, pbstrdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IErrorInfo", "GetDescription", 5usize, |this| ((**(this as *const *const IErrorInfo_Vtbl)).GetDescription)(this, pbstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHelpFile(this: *mut core::ffi::c_void, pbstrhelpfile: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IErrorInfo", "GetHelpFile", 6usize, |this| ((**(this as *const *const IErrorInfo_Vtbl)).GetHelpFile)(this, pbstrhelpfile), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHelpContext(this: *mut core::ffi::c_void, pdwhelpcontext: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IErrorInfo", "GetHelpContext", 7usize, |this| ((**(this as *const *const IErrorInfo_Vtbl)).GetHelpContext)(this, pdwhelpcontext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetGUID, GetSource, GetDescription, GetHelpFile, GetHelpContext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IErrorInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IErrorLog_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddError(this: *mut core::ffi::c_void, pszpropname: windows_core::PCWSTR, pexcepinfo: *const EXCEPINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IErrorLog", "AddError", 3usize, |this| ((**(this as *const *const IErrorLog_Vtbl)).AddError)(this, pszpropname, pexcepinfo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IErrorLog as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IExternalConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddConnection(this: *mut core::ffi::c_void, extconn: u32, reserved: u32) -> u32 {
+            windows_core::imp::proxy_call(this, "IExternalConnection", "AddConnection", 3usize, |this| ((**(this as *const *const IExternalConnection_Vtbl)).AddConnection)(this, extconn, reserved), |_| None)
+        }
+        unsafe extern "system" fn ReleaseConnection(this: *mut core::ffi::c_void, extconn: u32, reserved: u32, flastreleasecloses: super::super::Foundation::BOOL) -> u32 {
+            windows_core::imp::proxy_call(this, "IExternalConnection", "ReleaseConnection", 4usize, |this| ((**(this as *const *const IExternalConnection_Vtbl)).ReleaseConnection)(this, extconn, reserved, flastreleasecloses), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddConnection, ReleaseConnection }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IExternalConnection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFastRundown_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFastRundown as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IForegroundTransfer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AllowForegroundTransfer(this: *mut core::ffi::c_void, lpvreserved: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForegroundTransfer", "AllowForegroundTransfer", 3usize, |this| ((**(this as *const *const IForegroundTransfer_Vtbl)).AllowForegroundTransfer)(this, lpvreserved), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AllowForegroundTransfer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IForegroundTransfer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGlobalInterfaceTable_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterInterfaceInGlobal(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void, riid: *const windows_core::GUID, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalInterfaceTable", "RegisterInterfaceInGlobal", 3usize, |this| ((**(this as *const *const IGlobalInterfaceTable_Vtbl)).RegisterInterfaceInGlobal)(this, punk, riid, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn RevokeInterfaceFromGlobal(this: *mut core::ffi::c_void, dwcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalInterfaceTable", "RevokeInterfaceFromGlobal", 4usize, |this| ((**(this as *const *const IGlobalInterfaceTable_Vtbl)).RevokeInterfaceFromGlobal)(this, dwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInterfaceFromGlobal(this: *mut core::ffi::c_void, dwcookie: u32, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalInterfaceTable", "GetInterfaceFromGlobal", 5usize, |this| ((**(this as *const *const IGlobalInterfaceTable_Vtbl)).GetInterfaceFromGlobal)(this, dwcookie, riid, ppv), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RegisterInterfaceInGlobal,
+            RevokeInterfaceFromGlobal,
+            GetInterfaceFromGlobal,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGlobalInterfaceTable as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGlobalOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Set(this: *mut core::ffi::c_void, dwproperty: GLOBALOPT_PROPERTIES, dwvalue: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalOptions", "Set", 3usize, |this| ((**(this as *const *const IGlobalOptions_Vtbl)).Set)(this, dwproperty, dwvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Query(this: *mut core::ffi::c_void, dwproperty: GLOBALOPT_PROPERTIES, pdwvalue: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGlobalOptions", "Query", 4usize, |this| ((**(this as *const *const IGlobalOptions_Vtbl)).Query)(this, dwproperty, pdwvalue), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Set, Query }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGlobalOptions as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInitializeSpy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PreInitialize(this: *mut core::ffi::c_void, dwcoinit: u32, dwcurthreadaptrefs: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInitializeSpy", "PreInitialize", 3usize, |this| ((**(this as *const *const IInitializeSpy_Vtbl)).PreInitialize)(this, dwcoinit, dwcurthreadaptrefs), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostInitialize(this: *mut core::ffi::c_void, hrcoinit: windows_core::HRESULT, dwcoinit: u32, dwnewthreadaptrefs: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInitializeSpy", "PostInitialize", 4usize, |this| ((**(this as *const *const IInitializeSpy_Vtbl)).PostInitialize)(this, hrcoinit, dwcoinit, dwnewthreadaptrefs), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreUninitialize(this: *mut core::ffi::c_void, dwcurthreadaptrefs: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInitializeSpy", "PreUninitialize", 5usize, |this| ((**(this as *const *const IInitializeSpy_Vtbl)).PreUninitialize)(this, dwcurthreadaptrefs), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostUninitialize(this: *mut core::ffi::c_void, dwnewthreadaptrefs: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInitializeSpy", "PostUninitialize", 6usize, |this| ((**(this as *const *const IInitializeSpy_Vtbl)).PostUninitialize)(this, dwnewthreadaptrefs), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PreInitialize, PostInitialize, PreUninitialize, PostUninitialize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInitializeSpy as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInternalUnknown_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryInternalInterface(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternalUnknown", "QueryInternalInterface", 3usize, |this| ((**(this as *const *const IInternalUnknown_Vtbl)).QueryInternalInterface)(this, riid, ppv), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryInternalInterface }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInternalUnknown as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMachineGlobalObjectTable_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterObject(this: *mut core::ffi::c_void, clsid: *const windows_core::GUID, identifier: windows_core::PCWSTR, object: *mut core::ffi::c_void, token: *mut MachineGlobalObjectTableRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMachineGlobalObjectTable", "RegisterObject", 3usize, |this| ((**(this as *const *const IMachineGlobalObjectTable_Vtbl)).RegisterObject)(this, clsid, identifier, object, token), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObject(this: *mut core::ffi::c_void, clsid: *const windows_core::GUID, identifier: windows_core::PCWSTR, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMachineGlobalObjectTable", "GetObject", 4usize, |this| ((**(this as *const *const IMachineGlobalObjectTable_Vtbl)).GetObject)(this, clsid, identifier, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn RevokeObject(this: *mut core::ffi::c_void, token: MachineGlobalObjectTableRegistrationToken) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMachineGlobalObjectTable", "RevokeObject", 5usize, |this| ((**(this as *const *const IMachineGlobalObjectTable_Vtbl)).RevokeObject)(this, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterObject, GetObject, RevokeObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMachineGlobalObjectTable as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMalloc_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Alloc(this: *mut core::ffi::c_void, cb: usize) -> *mut core::ffi::c_void {
+            windows_core::imp::proxy_call(this, "IMalloc", "Alloc", 3usize, |this| ((**(this as *const *const IMalloc_Vtbl)).Alloc)(this, cb), |_| None)
+        }
+        unsafe extern "system" fn Realloc(this: *mut core::ffi::c_void, pv: *const core::ffi::c_void, cb: usize) -> *mut core::ffi::c_void {
+            windows_core::imp::proxy_call(this, "IMalloc", "Realloc", 4usize, |this| ((**(this as *const *const IMalloc_Vtbl)).Realloc)(this, pv, cb), |_| None)
+        }
+        unsafe extern "system" fn Free(this: *mut core::ffi::c_void, pv: *const core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IMalloc", "Free", 5usize, |this| ((**(this as *const *const IMalloc_Vtbl)).Free)(this, pv), |_| None)
+        }
+        unsafe extern "system" fn GetSize(this: *mut core::ffi::c_void, pv: *const core::ffi::c_void) -> usize {
+            windows_core::imp::proxy_call(this, "IMalloc", "GetSize", 6usize, |this| ((**(this as *const *const IMalloc_Vtbl)).GetSize)(this, pv), |_| None)
+        }
+        unsafe extern "system" fn DidAlloc(this: *mut core::ffi::c_void, pv: *const core::ffi::c_void) -> i32 {
+            windows_core::imp::proxy_call(this, "IMalloc", "DidAlloc", 7usize, |this| ((**(this as *const *const IMalloc_Vtbl)).DidAlloc)(this, pv), |_| None)
+        }
+        unsafe extern "system" fn HeapMinimize(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IMalloc", "HeapMinimize", 8usize, |this| ((**(this as *const *const IMalloc_Vtbl)).HeapMinimize)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Alloc, Realloc, Free, GetSize, DidAlloc, HeapMinimize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMalloc as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMallocSpy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PreAlloc(this: *mut core::ffi::c_void, cbrequest: usize) -> usize {
+            windows_core::imp::proxy_call(this, "IMallocSpy", "PreAlloc", 3usize, |this| ((**(this as *const *const IMallocSpy_Vtbl)).PreAlloc)(this, cbrequest), |_| None)
+        }
+        unsafe extern "system" fn PostAlloc(this: *mut core::ffi::c_void, pactual: *const core::ffi::c_void) -> *mut core::ffi::c_void {
+            windows_core::imp::proxy_call(this, "IMallocSpy", "PostAlloc", 4usize, |this| ((**(this as *const *const IMallocSpy_Vtbl)).PostAlloc)(this, pactual), |_| None)
+        }
+        unsafe extern "system" fn PreFree(this: *mut core::ffi::c_void, prequest: *const core::ffi::c_void, fspyed: super::super::Foundation::BOOL) -> *mut core::ffi::c_void {
+            windows_core::imp::proxy_call(this, "IMallocSpy", "PreFree", 5usize, |this| ((**(this as *const *const IMallocSpy_Vtbl)).PreFree)(this, prequest, fspyed), |_| None)
+        }
+        unsafe extern "system" fn PostFree(this: *mut core::ffi::c_void, fspyed: super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "IMallocSpy", "PostFree", 6usize, |this| ((**(this as *const *const IMallocSpy_Vtbl)).PostFree)(this, fspyed), |_| None)
+        }
+        unsafe extern "system" fn PreRealloc(this: *mut core::ffi::c_void, prequest: *const core::ffi::c_void, cbrequest: usize, ppnewrequest: *mut *mut core::ffi::c_void, fspyed: super::super::Foundation::BOOL) -> usize {
+            windows_core::imp::proxy_call(this, "IMallocSpy", "PreRealloc", 7usize, |this| ((**(this as *const *const IMallocSpy_Vtbl)).PreRealloc)(this, prequest, cbrequest, ppnewrequest, fspyed), |_| None)
+        }
+        unsafe extern "system" fn PostRealloc(this: *mut core::ffi::c_void, pactual: *const core::ffi::c_void, fspyed: super::super::Foundation::BOOL) -> *mut core::ffi::c_void {
+            windows_core::imp::proxy_call(this, "IMallocSpy", "PostRealloc", 8usize, |this| ((**(this as *const *const IMallocSpy_Vtbl)).PostRealloc)(this, pactual, fspyed), |_| None)
+        }
+        unsafe extern "system" fn PreGetSize(this: *mut core::ffi::c_void, prequest: *const core::ffi::c_void, fspyed: super::super::Foundation::BOOL) -> *mut core::ffi::c_void {
+            windows_core::imp::proxy_call(this, "IMallocSpy", "PreGetSize", 9usize, |this| ((**(this as *const *const IMallocSpy_Vtbl)).PreGetSize)(this, prequest, fspyed), |_| None)
+        }
+        unsafe extern "system" fn PostGetSize(this: *mut core::ffi::c_void, cbactual: usize, fspyed: super::super::Foundation::BOOL) -> usize {
+            windows_core::imp::proxy_call(this, "IMallocSpy", "PostGetSize", 10usize, |this| ((**(this as *const *const IMallocSpy_Vtbl)).PostGetSize)(this, cbactual, fspyed), |_| None)
+        }
+        unsafe extern "system" fn PreDidAlloc(this: *mut core::ffi::c_void, prequest: *const core::ffi::c_void, fspyed: super::super::Foundation::BOOL) -> *mut core::ffi::c_void {
+            windows_core::imp::proxy_call(this, "IMallocSpy", "PreDidAlloc", 11usize, |this| ((**(this as *const *const IMallocSpy_Vtbl)).PreDidAlloc)(this, prequest, fspyed), |_| None)
+        }
+        unsafe extern "system" fn PostDidAlloc(this: *mut core::ffi::c_void, prequest: *const core::ffi::c_void, fspyed: super::super::Foundation::BOOL, factual: i32) -> i32 {
+            windows_core::imp::proxy_call(this, "IMallocSpy", "PostDidAlloc", 12usize, |this| ((**(this as *const *const IMallocSpy_Vtbl)).PostDidAlloc)(this, prequest, fspyed, factual), |_| None)
+        }
+        unsafe extern "system" fn PreHeapMinimize(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IMallocSpy", "PreHeapMinimize", 13usize, |this| ((**(this as *const *const IMallocSpy_Vtbl)).PreHeapMinimize)(this), |_| None)
+        }
+        unsafe extern "system" fn PostHeapMinimize(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IMallocSpy", "PostHeapMinimize", 14usize, |this| ((**(this as *const *const IMallocSpy_Vtbl)).PostHeapMinimize)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PreAlloc,
+            PostAlloc,
+            PreFree,
+            PostFree,
+            PreRealloc,
+            PostRealloc,
+            PreGetSize,
+            PostGetSize,
+            PreDidAlloc,
+            PostDidAlloc,
+            PreHeapMinimize,
+            PostHeapMinimize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMallocSpy as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMoniker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BindToObject(this: *mut core::ffi::c_void, pbc: *mut core::ffi::c_void, pmktoleft: *mut core::ffi::c_void, riidresult: *const windows_core::GUID, ppvresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMoniker", "BindToObject", 8usize, |this| ((**(this as *const *const IMoniker_Vtbl)).BindToObject)(this, pbc, pmktoleft, riidresult, ppvresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn BindToStorage(this: *mut core::ffi::c_void, pbc: *mut core::ffi::c_void, pmktoleft: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMoniker", "BindToStorage", 9usize, |this| ((**(this as *const *const IMoniker_Vtbl)).BindToStorage)(this, pbc, pmktoleft, riid, ppvobj), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reduce(this: *mut core::ffi::c_void, pbc: *mut core::ffi::c_void, dwreducehowfar: u32, ppmktoleft: *mut *mut core::ffi::c_void, ppmkreduced: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMoniker", "Reduce", 10usize, |this| ((**(this as *const *const IMoniker_Vtbl)).Reduce)(this, pbc, dwreducehowfar, ppmktoleft, ppmkreduced), |result| Some(*result))
+        }
+        unsafe extern "system" fn ComposeWith(this: *mut core::ffi::c_void, pmkright: *mut core::ffi::c_void, fonlyifnotgeneric: super::super::Foundation::BOOL, ppmkcomposite: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMoniker", "ComposeWith", 11usize, |this| ((**(this as *const *const IMoniker_Vtbl)).ComposeWith)(this, pmkright, fonlyifnotgeneric, ppmkcomposite), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enum(this: *mut core::ffi::c_void, fforward: super::super::Foundation::BOOL, ppenummoniker: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMoniker", "Enum", 12usize, |this| ((**(this as *const *const IMoniker_Vtbl)).Enum)(this, fforward, ppenummoniker), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEqual(this: *mut core::ffi::c_void, pmkothermoniker: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMoniker", "IsEqual", 13usize, |this| ((**(this as *const *const IMoniker_Vtbl)).IsEqual)(this, pmkothermoniker), |result| Some(*result))
+        }
+        unsafe extern "system" fn Hash(this: *mut core::ffi::c_void, pdwhash: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMoniker", "Hash", 14usize, |this| ((**(this as *const *const IMoniker_Vtbl)).Hash)(this, pdwhash), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRunning(this: *mut core::ffi::c_void, pbc: *mut core::ffi::c_void, pmktoleft: *mut core::ffi::c_void, pmknewlyrunning: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMoniker", "IsRunning", 15usize, |this| ((**(this as *const *const IMoniker_Vtbl)).IsRunning)(this, pbc, pmktoleft, pmknewlyrunning), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTimeOfLastChange(this: *mut core::ffi::c_void, pbc: *mut core::ffi::c_void, pmktoleft: *mut core::ffi::c_void, pfiletime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMoniker", "GetTimeOfLastChange", 16usize, |this| ((**(this as *const *const IMoniker_Vtbl)).GetTimeOfLastChange)(this, pbc, pmktoleft, pfiletime), |result| Some(*result))
+        }
+        unsafe extern "system" fn Inverse(this: *mut core::ffi::c_void, ppmk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMoniker", "Inverse", 17usize, |this| ((**(this as *const *const IMoniker_Vtbl)).Inverse)(this, ppmk), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommonPrefixWith(this: *mut core::ffi::c_void, pmkother: *mut core::ffi::c_void, ppmkprefix: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMoniker", "CommonPrefixWith", 18usize, |this| ((**(this as *const *const IMoniker_Vtbl)).CommonPrefixWith)(this, pmkother, ppmkprefix), |result| Some(*result))
+        }
+        unsafe extern "system" fn RelativePathTo(this: *mut core::ffi::c_void, pmkother: *mut core::ffi::c_void, ppmkrelpath: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMoniker", "RelativePathTo", 19usize, |this| ((**(this as *const *const IMoniker_Vtbl)).RelativePathTo)(this, pmkother, ppmkrelpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayName(this: *mut core::ffi::c_void, pbc: *mut core::ffi::c_void, pmktoleft: *mut core::ffi::c_void, ppszdisplayname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMoniker", "GetDisplayName", 20usize, |this| ((**(this as *const *const IMoniker_Vtbl)).GetDisplayName)(this, pbc, pmktoleft, ppszdisplayname), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParseDisplayName(this: *mut core::ffi::c_void, pbc: *mut core::ffi::c_void, pmktoleft: *mut core::ffi::c_void, pszdisplayname: windows_core::PCWSTR, pcheaten: *mut u32, ppmkout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMoniker", "ParseDisplayName", 21usize, |this| ((**(this as *const *const IMoniker_Vtbl)).ParseDisplayName)(this, pbc, pmktoleft, pszdisplayname, pcheaten, ppmkout), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSystemMoniker(this: *mut core::ffi::c_void, pdwmksys: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMoniker", "IsSystemMoniker", 22usize, |this| ((**(this as *const *const IMoniker_Vtbl)).IsSystemMoniker)(this, pdwmksys), |result| Some(*result))
+        }
+        Self {
+            base__: <IPersistStream_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BindToObject,
+            BindToStorage,
+            Reduce,
+            ComposeWith,
+            Enum,
+            IsEqual,
+            Hash,
+            IsRunning,
+            GetTimeOfLastChange,
+            Inverse,
+            CommonPrefixWith,
+            RelativePathTo,
+            GetDisplayName,
+            ParseDisplayName,
+            IsSystemMoniker,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMoniker as windows_core::Interface>::IID || <IPersistStream_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMultiQI_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryMultipleInterfaces(this: *mut core::ffi::c_void, cmqis: u32, pmqis: *mut MULTI_QI) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultiQI", "QueryMultipleInterfaces", 3usize, |this| ((**(this as *const *const IMultiQI_Vtbl)).QueryMultipleInterfaces)(this, cmqis, pmqis), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryMultipleInterfaces }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMultiQI as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INoMarshal_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INoMarshal as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOplockStorage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateStorageEx(this: *mut core::ffi::c_void, pwcsname: windows_core::PCWSTR, grfmode: u32, stgfmt: u32, grfattrs: u32, riid: *const windows_core::GUID, ppstgopen: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOplockStorage", "CreateStorageEx", 3usize, |this| ((**(this as *const *const IOplockStorage_Vtbl)).CreateStorageEx)(this, pwcsname, grfmode, stgfmt, grfattrs, riid, ppstgopen), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenStorageEx(this: *mut core::ffi::c_void, pwcsname: windows_core::PCWSTR, grfmode: u32, stgfmt: u32, grfattrs: u32, riid: *const windows_core::GUID, ppstgopen: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOplockStorage", "OpenStorageEx", 4usize, |this| ((**(this as *const *const IOplockStorage_Vtbl)).OpenStorageEx)(this, pwcsname, grfmode, stgfmt, grfattrs, riid, ppstgopen), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateStorageEx, OpenStorageEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOplockStorage as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPSFactoryBuffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateProxy(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppproxy: *mut *mut core::ffi::c_void, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPSFactoryBuffer", "CreateProxy", 3usize, |this| ((**(this as *const *const IPSFactoryBuffer_Vtbl)).CreateProxy)(this, punkouter, riid, ppproxy, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateStub(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, punkserver: *mut core::ffi::c_void, ppstub: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPSFactoryBuffer", "CreateStub", 4usize, |this| ((**(this as *const *const IPSFactoryBuffer_Vtbl)).CreateStub)(this, riid, punkserver, ppstub), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateProxy, CreateStub }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPSFactoryBuffer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPersist_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetClassID(this: *mut core::ffi::c_void, pclassid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersist", "GetClassID", 3usize, |this| ((**(this as *const *const IPersist_Vtbl)).GetClassID)(this, pclassid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetClassID }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPersist as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPersistFile_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsDirty(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistFile", "IsDirty", 4usize, |this| ((**(this as *const *const IPersistFile_Vtbl)).IsDirty)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Load(this: *mut core::ffi::c_void, pszfilename: windows_core::PCWSTR, dwmode: STGM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistFile", "Load", 5usize, |this| ((**(this as *const *const IPersistFile_Vtbl)).Load)(this, pszfilename, dwmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void, pszfilename: windows_core::PCWSTR, fremember: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistFile", "Save", 6usize, |this| ((**(this as *const *const IPersistFile_Vtbl)).Save)(this, pszfilename, fremember), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveCompleted(this: *mut core::ffi::c_void, pszfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistFile", "SaveCompleted", 7usize, |this| ((**(this as *const *const IPersistFile_Vtbl)).SaveCompleted)(this, pszfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurFile(this: *mut core::ffi::c_void, ppszfilename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistFile", "GetCurFile", 8usize, |this| ((**(this as *const *const IPersistFile_Vtbl)).GetCurFile)(this, ppszfilename), |result| Some(*result))
+        }
+        Self { base__: <IPersist_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsDirty, Load, Save, SaveCompleted, GetCurFile }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPersistFile as windows_core::Interface>::IID || <IPersist_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPersistMemory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsDirty(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistMemory", "IsDirty", 4usize, |this| ((**(this as *const *const IPersistMemory_Vtbl)).IsDirty)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Load(this: *mut core::ffi::c_void, pmem: *const core::ffi::c_void, cbsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistMemory", "Load", 5usize, |this| ((**(this as *const *const IPersistMemory_Vtbl)).Load)(this, pmem, cbsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void, pmem: *mut core::ffi::c_void, fcleardirty: super::super::Foundation::BOOL, cbsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistMemory", "Save", 6usize, |this| ((**(this as *const *const IPersistMemory_Vtbl)).Save)(this, pmem, fcleardirty, cbsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSizeMax(this: *mut core::ffi::c_void, pcbsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistMemory", "GetSizeMax", 7usize, |this| ((**(this as *const *const IPersistMemory_Vtbl)).GetSizeMax)(this, pcbsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitNew(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistMemory", "InitNew", 8usize, |this| ((**(this as *const *const IPersistMemory_Vtbl)).InitNew)(this), |result| Some(*result))
+        }
+        Self { base__: <IPersist_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsDirty, Load, Save, GetSizeMax, InitNew }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPersistMemory as windows_core::Interface>::IID || <IPersist_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPersistStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsDirty(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistStream", "IsDirty", 4usize, |this| ((**(this as *const *const IPersistStream_Vtbl)).IsDirty)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Load(this: *mut core::ffi::c_void, pstm: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistStream", "Load", 5usize, |this| ((**(this as *const *const IPersistStream_Vtbl)).Load)(this, pstm), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void, pstm: *mut core::ffi::c_void, fcleardirty: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistStream", "Save", 6usize, |this| ((**(this as *const *const IPersistStream_Vtbl)).Save)(this, pstm, fcleardirty), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSizeMax(this: *mut core::ffi::c_void, pcbsize: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistStream", "GetSizeMax", 7usize, |this| ((**(this as *const *const IPersistStream_Vtbl)).GetSizeMax)(this, pcbsize), |result| Some(*result))
+        }
+        Self { base__: <IPersist_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsDirty, Load, Save, GetSizeMax }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPersistStream as windows_core::Interface>::IID || <IPersist_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPersistStreamInit_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsDirty(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistStreamInit", "IsDirty", 4usize, |this| ((**(this as *const *const IPersistStreamInit_Vtbl)).IsDirty)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Load(this: *mut core::ffi::c_void, pstm: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistStreamInit", "Load", 5usize, |this| ((**(this as *const *const IPersistStreamInit_Vtbl)).Load)(this, pstm), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void, pstm: *mut core::ffi::c_void, fcleardirty: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistStreamInit", "Save", 6usize, |this| ((**(this as *const *const IPersistStreamInit_Vtbl)).Save)(this, pstm, fcleardirty), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSizeMax(this: *mut core::ffi::c_void, pcbsize: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistStreamInit", "GetSizeMax", 7usize, |this| ((**(this as *const *const IPersistStreamInit_Vtbl)).GetSizeMax)(this, pcbsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitNew(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistStreamInit", "InitNew", 8usize, |this| ((**(this as *const *const IPersistStreamInit_Vtbl)).InitNew)(this), |result| Some(*result))
+        }
+        Self { base__: <IPersist_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsDirty, Load, Save, GetSizeMax, InitNew }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPersistStreamInit as windows_core::Interface>::IID || <IPersist_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPipeByte_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Pull(this: *mut core::ffi::c_void, buf: *mut u8, crequest: u32, pcreturned: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPipeByte", "Pull", 3usize, |this| ((**(this as *const *const IPipeByte_Vtbl)).Pull)(this, buf, crequest, pcreturned), |result| Some(*result))
+        }
+        unsafe extern "system" fn Push(this: *mut core::ffi::c_void, buf: *const u8, csent: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPipeByte", "Push", 4usize, |this| ((**(this as *const *const IPipeByte_Vtbl)).Push)(this, buf, csent), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Pull, Push }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPipeByte as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPipeDouble_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Pull(this: *mut core::ffi::c_void, buf: *mut f64, crequest: u32, pcreturned: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPipeDouble", "Pull", 3usize, |this| ((**(this as *const *const IPipeDouble_Vtbl)).Pull)(this, buf, crequest, pcreturned), |result| Some(*result))
+        }
+        unsafe extern "system" fn Push(this: *mut core::ffi::c_void, buf: *const f64, csent: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPipeDouble", "Push", 4usize, |this| ((**(this as *const *const IPipeDouble_Vtbl)).Push)(this, buf, csent), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Pull, Push }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPipeDouble as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPipeLong_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Pull(this: *mut core::ffi::c_void, buf: *mut i32, crequest: u32, pcreturned: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPipeLong", "Pull", 3usize, |this| ((**(this as *const *const IPipeLong_Vtbl)).Pull)(this, buf, crequest, pcreturned), |result| Some(*result))
+        }
+        unsafe extern "system" fn Push(this: *mut core::ffi::c_void, buf: *const i32, csent: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPipeLong", "Push", 4usize, |this| ((**(this as *const *const IPipeLong_Vtbl)).Push)(this, buf, csent), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Pull, Push }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPipeLong as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProcessInitControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResetInitializerTimeout(this: *mut core::ffi::c_void, dwsecondsremaining: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessInitControl", "ResetInitializerTimeout", 3usize, |this| ((**(this as *const *const IProcessInitControl_Vtbl)).ResetInitializerTimeout)(this, dwsecondsremaining), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ResetInitializerTimeout }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProcessInitControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProcessLock_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddRefOnProcess(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IProcessLock", "AddRefOnProcess", 3usize, |this| ((**(this as *const *const IProcessLock_Vtbl)).AddRefOnProcess)(this), |_| None)
+        }
+        unsafe extern "system" fn ReleaseRefOnProcess(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IProcessLock", "ReleaseRefOnProcess", 4usize, |this| ((**(this as *const *const IProcessLock_Vtbl)).ReleaseRefOnProcess)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddRefOnProcess, ReleaseRefOnProcess }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProcessLock as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProgressNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnProgress(this: *mut core::ffi::c_void, dwprogresscurrent: u32, dwprogressmaximum: u32, faccurate: super::super::Foundation::BOOL, fowner: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProgressNotify", "OnProgress", 3usize, |this| ((**(this as *const *const IProgressNotify_Vtbl)).OnProgress)(this, dwprogresscurrent, dwprogressmaximum, faccurate, fowner), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnProgress }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProgressNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IROTData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetComparisonData(this: *mut core::ffi::c_void, pbdata: *mut u8, cbmax: u32, pcbdata: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IROTData", "GetComparisonData", 3usize, |this| ((**(this as *const *const IROTData_Vtbl)).GetComparisonData)(this, pbdata, cbmax, pcbdata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetComparisonData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IROTData as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IReleaseMarshalBuffers_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReleaseMarshalBuffer(this: *mut core::ffi::c_void, pmsg: *mut RPCOLEMESSAGE, dwflags: u32, pchnl: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReleaseMarshalBuffers", "ReleaseMarshalBuffer", 3usize, |this| ((**(this as *const *const IReleaseMarshalBuffers_Vtbl)).ReleaseMarshalBuffer)(this, pmsg, dwflags, pchnl), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReleaseMarshalBuffer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IReleaseMarshalBuffers as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRpcChannelBuffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBuffer(this: *mut core::ffi::c_void, pmessage: *mut RPCOLEMESSAGE, riid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRpcChannelBuffer", "GetBuffer", 3usize, |this| ((**(this as *const *const IRpcChannelBuffer_Vtbl)).GetBuffer)(this, pmessage, riid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendReceive(this: *mut core::ffi::c_void, pmessage: *mut RPCOLEMESSAGE, pstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRpcChannelBuffer", "SendReceive", 4usize, |this| ((**(this as *const *const IRpcChannelBuffer_Vtbl)).SendReceive)(this, pmessage, pstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreeBuffer(this: *mut core::ffi::c_void, pmessage: *mut RPCOLEMESSAGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRpcChannelBuffer", "FreeBuffer", 5usize, |this| ((**(this as *const *const IRpcChannelBuffer_Vtbl)).FreeBuffer)(this, pmessage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDestCtx(this: *mut core::ffi::c_void, pdwdestcontext: *mut u32, ppvdestcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRpcChannelBuffer", "GetDestCtx", 6usize, |this| ((**(this as *const *const IRpcChannelBuffer_Vtbl)).GetDestCtx)(this, pdwdestcontext, ppvdestcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsConnected(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRpcChannelBuffer", "IsConnected", 7usize, |this| ((**(this as *const *const IRpcChannelBuffer_Vtbl)).IsConnected)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetBuffer, SendReceive, FreeBuffer, GetDestCtx, IsConnected }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRpcChannelBuffer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRpcChannelBuffer2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProtocolVersion(this: *mut core::ffi::c_void, pdwversion: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRpcChannelBuffer2", "GetProtocolVersion", 8usize, |this| ((**(this as *const *const IRpcChannelBuffer2_Vtbl)).GetProtocolVersion)(this, pdwversion), |result| Some(*result))
+        }
+        Self { base__: <IRpcChannelBuffer_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProtocolVersion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRpcChannelBuffer2 as windows_core::Interface>::IID || <IRpcChannelBuffer_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRpcChannelBuffer3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Send(this: *mut core::ffi::c_void, pmsg: *mut RPCOLEMESSAGE, pulstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRpcChannelBuffer3", "Send", 9usize, |this| ((**(this as *const *const IRpcChannelBuffer3_Vtbl)).Send)(this, pmsg, pulstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn Receive(this: *mut core::ffi::c_void, pmsg: *mut RPCOLEMESSAGE, ulsize: u32, pulstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRpcChannelBuffer3", "Receive", 10usize, |this| ((**(this as *const *const IRpcChannelBuffer3_Vtbl)).Receive)(this, pmsg, ulsize, pulstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void, pmsg: *mut RPCOLEMESSAGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRpcChannelBuffer3", "Cancel", 11usize, |this| ((**(this as *const *const IRpcChannelBuffer3_Vtbl)).Cancel)(this, pmsg), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCallContext(this: *mut core::ffi::c_void, pmsg: *const RPCOLEMESSAGE, riid: *const windows_core::GUID, pinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRpcChannelBuffer3", "GetCallContext", 12usize, |this| ((**(this as *const *const IRpcChannelBuffer3_Vtbl)).GetCallContext)(this, pmsg, riid, pinterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDestCtxEx(this: *mut core::ffi::c_void, pmsg: *const RPCOLEMESSAGE, pdwdestcontext: *mut u32, ppvdestcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRpcChannelBuffer3", "GetDestCtxEx", 13usize, |this| ((**(this as *const *const IRpcChannelBuffer3_Vtbl)).GetDestCtxEx)(this, pmsg, pdwdestcontext, ppvdestcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetState(this: *mut core::ffi::c_void, pmsg: *const RPCOLEMESSAGE, pstate: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRpcChannelBuffer3", "GetState", 14usize, |this| ((**(this as *const *const IRpcChannelBuffer3_Vtbl)).GetState)(this, pmsg, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterAsync(this: *mut core::ffi::c_void, pmsg: *mut RPCOLEMESSAGE, pasyncmgr: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRpcChannelBuffer3", "RegisterAsync", 15usize, |this| ((**(this as *const *const IRpcChannelBuffer3_Vtbl)).RegisterAsync)(this, pmsg, pasyncmgr), |result| Some(*result))
+        }
+        Self {
+            base__: <IRpcChannelBuffer2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Send,
+            Receive,
+            Cancel,
+            GetCallContext,
+            GetDestCtxEx,
+            GetState,
+            RegisterAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRpcChannelBuffer3 as windows_core::Interface>::IID || <IRpcChannelBuffer2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRpcHelper_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDCOMProtocolVersion(this: *mut core::ffi::c_void, pcomversion: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRpcHelper", "GetDCOMProtocolVersion", 3usize, |this| ((**(this as *const *const IRpcHelper_Vtbl)).GetDCOMProtocolVersion)(this, pcomversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIIDFromOBJREF(this: *mut core::ffi::c_void, pobjref: *const core::ffi::c_void, piid: *mut *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRpcHelper", "GetIIDFromOBJREF", 4usize, |this| ((**(this as *const *const IRpcHelper_Vtbl)).GetIIDFromOBJREF)(this, pobjref, piid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDCOMProtocolVersion, GetIIDFromOBJREF }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRpcHelper as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRpcOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Set(this: *mut core::ffi::c_void, pprx: *mut core::ffi::c_void, dwproperty: RPCOPT_PROPERTIES, dwvalue: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRpcOptions", "Set", 3usize, |this| ((**(this as *const *const IRpcOptions_Vtbl)).Set)(this, pprx, dwproperty, dwvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Query(this: *mut core::ffi::c_void, pprx: *mut core::ffi::c_void, dwproperty: RPCOPT_PROPERTIES, pdwvalue: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRpcOptions", "Query", 4usize, |this| ((**(this as *const *const IRpcOptions_Vtbl)).Query)(this, pprx, dwproperty, pdwvalue), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Set, Query }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRpcOptions as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRpcProxyBuffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Connect(this: *mut core::ffi::c_void, prpcchannelbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRpcProxyBuffer", "Connect", 3usize, |this| ((**(this as *const *const IRpcProxyBuffer_Vtbl)).Connect)(this, prpcchannelbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnect(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IRpcProxyBuffer", "Disconnect", 4usize, |this| ((**(this as *const *const IRpcProxyBuffer_Vtbl)).Disconnect)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Connect, Disconnect }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRpcProxyBuffer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRpcStubBuffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Connect(this: *mut core::ffi::c_void, punkserver: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRpcStubBuffer", "Connect", 3usize, |this| ((**(this as *const *const IRpcStubBuffer_Vtbl)).Connect)(this, punkserver), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnect(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IRpcStubBuffer", "Disconnect", 4usize, |this| ((**(this as *const *const IRpcStubBuffer_Vtbl)).Disconnect)(this), |_| None)
+        }
+        unsafe extern "system" fn Invoke(this: *mut core::ffi::c_void, _prpcmsg: *mut RPCOLEMESSAGE, _prpcchannelbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRpcStubBuffer", "Invoke", 5usize, |this| ((**(this as *const *const IRpcStubBuffer_Vtbl)).Invoke)(this, _prpcmsg, _prpcchannelbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsIIDSupported(this: *mut core::ffi::c_void, riid: *const windows_core::GUID) -> Option<IRpcStubBuffer> {
+            windows_core::imp::proxy_call(this, "IRpcStubBuffer", "IsIIDSupported", 6usize, |this| ((**(this as *const *const IRpcStubBuffer_Vtbl)).IsIIDSupported)(this, riid), |_| None)
+        }
+        unsafe extern "system" fn CountRefs(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "IRpcStubBuffer", "CountRefs", 7usize, |this| ((**(this as *const *const IRpcStubBuffer_Vtbl)).CountRefs)(this), |_| None)
+        }
+        unsafe extern "system" fn DebugServerQueryInterface(this: *mut core::ffi::c_void, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRpcStubBuffer", "DebugServerQueryInterface", 8usize, |this| ((**(this as *const *const IRpcStubBuffer_Vtbl)).DebugServerQueryInterface)(this, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn DebugServerRelease(this: *mut core::ffi::c_void, pv: *const core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IRpcStubBuffer", "DebugServerRelease", 9usize, |this| ((**(this as *const *const IRpcStubBuffer_Vtbl)).DebugServerRelease)(this, pv), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Connect,
+            Disconnect,
+            Invoke,
+            IsIIDSupported,
+            CountRefs,
+            DebugServerQueryInterface,
+            DebugServerRelease,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRpcStubBuffer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRpcSyntaxNegotiate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NegotiateSyntax(this: *mut core::ffi::c_void, pmsg: *mut RPCOLEMESSAGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRpcSyntaxNegotiate", "NegotiateSyntax", 3usize, |this| ((**(this as *const *const IRpcSyntaxNegotiate_Vtbl)).NegotiateSyntax)(this, pmsg), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NegotiateSyntax }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRpcSyntaxNegotiate as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRunnableObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRunningClass(this: *mut core::ffi::c_void, lpclsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRunnableObject", "GetRunningClass", 3usize, |this| ((**(this as *const *const IRunnableObject_Vtbl)).GetRunningClass)(this, lpclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Run(this: *mut core::ffi::c_void, pbc: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRunnableObject", "Run", 4usize, |this| ((**(this as *const *const IRunnableObject_Vtbl)).Run)(this, pbc), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRunning(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IRunnableObject", "IsRunning", 5usize, |this| ((**(this as *const *const IRunnableObject_Vtbl)).IsRunning)(this), |_| None)
+        }
+        unsafe extern "system" fn LockRunning(this: *mut core::ffi::c_void, flock: super::super::Foundation::BOOL, flastunlockcloses: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRunnableObject", "LockRunning", 6usize, |this| ((**(this as *const *const IRunnableObject_Vtbl)).LockRunning)(this, flock, flastunlockcloses), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContainedObject(this: *mut core::ffi::c_void, fcontained: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRunnableObject", "SetContainedObject", 7usize, |this| ((**(this as *const *const IRunnableObject_Vtbl)).SetContainedObject)(this, fcontained), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRunningClass, Run, IsRunning, LockRunning, SetContainedObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRunnableObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRunningObjectTable_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Register(this: *mut core::ffi::c_void, grfflags: ROT_FLAGS, punkobject: *mut core::ffi::c_void, pmkobjectname: *mut core::ffi::c_void, pdwregister: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRunningObjectTable", "Register", 3usize, |this| ((**(this as *const *const IRunningObjectTable_Vtbl)).Register)(this, grfflags, punkobject, pmkobjectname, pdwregister), |result| Some(*result))
+        }
+        unsafe extern "system" fn Revoke(this: *mut core::ffi::c_void, dwregister: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRunningObjectTable", "Revoke", 4usize, |this| ((**(this as *const *const IRunningObjectTable_Vtbl)).Revoke)(this, dwregister), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRunning(this: *mut core::ffi::c_void, pmkobjectname: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRunningObjectTable", "IsRunning", 5usize, |this| ((**(this as *const *const IRunningObjectTable_Vtbl)).IsRunning)(this, pmkobjectname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObject(this: *mut core::ffi::c_void, pmkobjectname: *mut core::ffi::c_void, ppunkobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRunningObjectTable", "GetObject", 6usize, |this| ((**(this as *const *const IRunningObjectTable_Vtbl)).GetObject)(this, pmkobjectname, ppunkobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn NoteChangeTime(this: *mut core::ffi::c_void, dwregister: u32, pfiletime: *const super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRunningObjectTable", "NoteChangeTime", 7usize, |this| ((**(this as *const *const IRunningObjectTable_Vtbl)).NoteChangeTime)(this, dwregister, pfiletime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTimeOfLastChange(this: *mut core::ffi::c_void, pmkobjectname: *mut core::ffi::c_void, pfiletime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRunningObjectTable", "GetTimeOfLastChange", 8usize, |this| ((**(this as *const *const IRunningObjectTable_Vtbl)).GetTimeOfLastChange)(this, pmkobjectname, pfiletime), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumRunning(this: *mut core::ffi::c_void, ppenummoniker: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRunningObjectTable", "EnumRunning", 9usize, |this| ((**(this as *const *const IRunningObjectTable_Vtbl)).EnumRunning)(this, ppenummoniker), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Register,
+            Revoke,
+            IsRunning,
+            GetObject,
+            NoteChangeTime,
+            GetTimeOfLastChange,
+            EnumRunning,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRunningObjectTable as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISequentialStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Read(this: *mut core::ffi::c_void, pv: *mut core::ffi::c_void, cb: u32, pcbread: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISequentialStream", "Read", 3usize, |this| ((**(this as *const *const ISequentialStream_Vtbl)).Read)(this, pv, cb, pcbread), |result| Some(*result))
+        }
+        unsafe extern "system" fn Write(this: *mut core::ffi::c_void, pv: *const core::ffi::c_void, cb: u32, pcbwritten: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISequentialStream", "Write", 4usize, |this| ((**(this as *const *const ISequentialStream_Vtbl)).Write)(this, pv, cb, pcbwritten), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Read, Write }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISequentialStream as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IServerSecurity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryBlanket(this: *mut core::ffi::c_void, pauthnsvc: *mut u32, pauthzsvc: *mut u32, pserverprincname: *mut *mut u16, pauthnlevel: *mut u32, pimplevel: *mut u32, pprivs: *mut *mut core::ffi::c_void, pcapabilities: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerSecurity", "QueryBlanket", 3usize, |this| ((**(this as *const *const IServerSecurity_Vtbl)).QueryBlanket)(this, pauthnsvc, pauthzsvc, pserverprincname, pauthnlevel, pimplevel, pprivs, pcapabilities), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImpersonateClient(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerSecurity", "ImpersonateClient", 4usize, |this| ((**(this as *const *const IServerSecurity_Vtbl)).ImpersonateClient)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RevertToSelf(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServerSecurity", "RevertToSelf", 5usize, |this| ((**(this as *const *const IServerSecurity_Vtbl)).RevertToSelf)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsImpersonating(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IServerSecurity", "IsImpersonating", 6usize, |this| ((**(this as *const *const IServerSecurity_Vtbl)).IsImpersonating)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryBlanket, ImpersonateClient, RevertToSelf, IsImpersonating }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServerSecurity as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IServiceProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryService(this: *mut core::ffi::c_void, guidservice: *const windows_core::GUID, riid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceProvider", "QueryService", 3usize, |this| ((**(this as *const *const IServiceProvider_Vtbl)).QueryService)(this, guidservice, riid, ppvobject), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryService }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServiceProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStdMarshalInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetClassForHandler(this: *mut core::ffi::c_void, dwdestcontext: u32, pvdestcontext: *const core::ffi::c_void, pclsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStdMarshalInfo", "GetClassForHandler", 3usize, |this| ((**(this as *const *const IStdMarshalInfo_Vtbl)).GetClassForHandler)(this, dwdestcontext, pvdestcontext, pclsid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetClassForHandler }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStdMarshalInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Seek(this: *mut core::ffi::c_void, dlibmove: i64, dworigin: STREAM_SEEK, plibnewposition: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStream", "Seek", 5usize, |this| ((**(this as *const *const IStream_Vtbl)).Seek)(this, dlibmove, dworigin, plibnewposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSize(this: *mut core::ffi::c_void, libnewsize: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStream", "SetSize", 6usize, |this| ((**(this as *const *const IStream_Vtbl)).SetSize)(this, libnewsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyTo(this: *mut core::ffi::c_void, pstm: *mut core::ffi::c_void, cb: u64, pcbread: *mut u64, pcbwritten: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStream", "CopyTo", 7usize, |this| ((**(this as *const *const IStream_Vtbl)).CopyTo)(this, pstm, cb, pcbread, pcbwritten), |result| Some(*result))
+        }
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void, grfcommitflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStream", "Commit", 8usize, |this| ((**(this as *const *const IStream_Vtbl)).Commit)(this, grfcommitflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Revert(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStream", "Revert", 9usize, |this| ((**(this as *const *const IStream_Vtbl)).Revert)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockRegion(this: *mut core::ffi::c_void, liboffset: u64, cb: u64, dwlocktype: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStream", "LockRegion", 10usize, |this| ((**(this as *const *const IStream_Vtbl)).LockRegion)(this, liboffset, cb, dwlocktype), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockRegion(this: *mut core::ffi::c_void, liboffset: u64, cb: u64, dwlocktype: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStream", "UnlockRegion", 11usize, |this| ((**(this as *const *const IStream_Vtbl)).UnlockRegion)(this, liboffset, cb, dwlocktype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stat(this: *mut core::ffi::c_void, pstatstg: *mut STATSTG, grfstatflag: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStream", "Stat", 12usize, |this| ((**(this as *const *const IStream_Vtbl)).Stat)(this, pstatstg, grfstatflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppstm: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStream", "Clone", 13usize, |this| ((**(this as *const *const IStream_Vtbl)).Clone)(this, ppstm), |result| Some(*result))
+        }
+        Self {
+            base__: <ISequentialStream_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Seek,
+            SetSize,
+            CopyTo,
+            Commit,
+            Revert,
+            LockRegion,
+            UnlockRegion,
+            Stat,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStream as windows_core::Interface>::IID || <ISequentialStream_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISupportAllowLowerTrustActivation_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISupportAllowLowerTrustActivation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISupportErrorInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InterfaceSupportsErrorInfo(this: *mut core::ffi::c_void, riid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISupportErrorInfo", "InterfaceSupportsErrorInfo", 3usize, |this| ((**(this as *const *const ISupportErrorInfo_Vtbl)).InterfaceSupportsErrorInfo)(this, riid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InterfaceSupportsErrorInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISupportErrorInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISurrogate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LoadDllServer(this: *mut core::ffi::c_void, clsid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISurrogate", "LoadDllServer", 3usize, |this| ((**(this as *const *const ISurrogate_Vtbl)).LoadDllServer)(this, clsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreeSurrogate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISurrogate", "FreeSurrogate", 4usize, |this| ((**(this as *const *const ISurrogate_Vtbl)).FreeSurrogate)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LoadDllServer, FreeSurrogate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISurrogate as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISurrogateService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Init(this: *mut core::ffi::c_void, rguidprocessid: *const windows_core::GUID, pprocesslock: *mut core::ffi::c_void, pfapplicationaware: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISurrogateService", "Init", 3usize, |this| ((**(this as *const *const ISurrogateService_Vtbl)).Init)(this, rguidprocessid, pprocesslock, pfapplicationaware), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplicationLaunch(this: *mut core::ffi::c_void, rguidapplid: *const windows_core::GUID, apptype: ApplicationType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISurrogateService", "ApplicationLaunch", 4usize, |this| ((**(this as *const *const ISurrogateService_Vtbl)).ApplicationLaunch)(this, rguidapplid, apptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplicationFree(this: *mut core::ffi::c_void, rguidapplid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISurrogateService", "ApplicationFree", 5usize, |this| ((**(this as *const *const ISurrogateService_Vtbl)).ApplicationFree)(this, rguidapplid), |result| Some(*result))
+        }
+        unsafe extern "system" fn CatalogRefresh(this: *mut core::ffi::c_void, ulreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISurrogateService", "CatalogRefresh", 6usize, |this| ((**(this as *const *const ISurrogateService_Vtbl)).CatalogRefresh)(this, ulreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessShutdown(this: *mut core::ffi::c_void, shutdowntype: ShutdownType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISurrogateService", "ProcessShutdown", 7usize, |this| ((**(this as *const *const ISurrogateService_Vtbl)).ProcessShutdown)(this, shutdowntype), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Init,
+            ApplicationLaunch,
+            ApplicationFree,
+            CatalogRefresh,
+            ProcessShutdown,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISurrogateService as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISynchronize_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Wait(this: *mut core::ffi::c_void, dwflags: u32, dwmilliseconds: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISynchronize", "Wait", 3usize, |this| ((**(this as *const *const ISynchronize_Vtbl)).Wait)(this, dwflags, dwmilliseconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn Signal(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISynchronize", "Signal", 4usize, |this| ((**(this as *const *const ISynchronize_Vtbl)).Signal)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISynchronize", "Reset", 5usize, |this| ((**(this as *const *const ISynchronize_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Wait, Signal, Reset }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISynchronize as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISynchronizeContainer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddSynchronize(this: *mut core::ffi::c_void, psync: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISynchronizeContainer", "AddSynchronize", 3usize, |this| ((**(this as *const *const ISynchronizeContainer_Vtbl)).AddSynchronize)(this, psync), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitMultiple(this: *mut core::ffi::c_void, dwflags: u32, dwtimeout: u32, ppsync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISynchronizeContainer", "WaitMultiple", 4usize, |this| ((**(this as *const *const ISynchronizeContainer_Vtbl)).WaitMultiple)(this, dwflags, dwtimeout, ppsync), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddSynchronize, WaitMultiple }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISynchronizeContainer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISynchronizeEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetEventHandle(this: *mut core::ffi::c_void, ph: *const super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISynchronizeEvent", "SetEventHandle", 4usize, |this| ((**(this as *const *const ISynchronizeEvent_Vtbl)).SetEventHandle)(this, ph), |result| Some(*result))
+        }
+        Self { base__: <ISynchronizeHandle_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetEventHandle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISynchronizeEvent as windows_core::Interface>::IID || <ISynchronizeHandle_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISynchronizeHandle_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetHandle(this: *mut core::ffi::c_void, ph: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISynchronizeHandle", "GetHandle", 3usize, |this| ((**(this as *const *const ISynchronizeHandle_Vtbl)).GetHandle)(this, ph), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetHandle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISynchronizeHandle as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISynchronizeMutex_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReleaseMutex(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISynchronizeMutex", "ReleaseMutex", 6usize, |this| ((**(this as *const *const ISynchronizeMutex_Vtbl)).ReleaseMutex)(this), |result| Some(*result))
+        }
+        Self { base__: <ISynchronize_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReleaseMutex }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISynchronizeMutex as windows_core::Interface>::IID || <ISynchronize_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITimeAndNoticeControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SuppressChanges(this: *mut core::ffi::c_void, res1: u32, res2: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimeAndNoticeControl", "SuppressChanges", 3usize, |this| ((**(this as *const *const ITimeAndNoticeControl_Vtbl)).SuppressChanges)(this, res1, res2), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SuppressChanges }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimeAndNoticeControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::imp::ProxyVtable for ITypeComp_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+        unsafe extern "system" fn Bind(this: *mut core::ffi::c_void, szname: windows_core::PCWSTR, lhashval: u32, wflags: u16, pptinfo: *mut *mut core::ffi::c_void, pdesckind: *mut DESCKIND, pbindptr: *mut BINDPTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeComp", "Bind", 3usize, |this| ((**(this as *const *const ITypeComp_Vtbl)).Bind)(this, szname, lhashval, wflags, pptinfo, pdesckind, pbindptr), |result| Some(*result))
+        }
+        unsafe extern "system" fn BindType(this: *mut core::ffi::c_void, szname: windows_core::PCWSTR, lhashval: u32, pptinfo: *mut *mut core::ffi::c_void, pptcomp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeComp", "BindType", 4usize, |this| ((**(this as *const *const ITypeComp_Vtbl)).BindType)(this, szname, lhashval, pptinfo, pptcomp), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+            Bind,
+            #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
+            Bind: 0,
+            BindType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITypeComp as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::imp::ProxyVtable for ITypeInfo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+        unsafe extern "system" fn GetTypeAttr(this: *mut core::ffi::c_void, pptypeattr: *mut *mut TYPEATTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo", "GetTypeAttr", 3usize, |this| ((**(this as *const *const ITypeInfo_Vtbl)).GetTypeAttr)(this, pptypeattr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTypeComp(this: *mut core::ffi::c_void, pptcomp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo", "GetTypeComp", 4usize, |this| ((**(this as *const *const ITypeInfo_Vtbl)).GetTypeComp)(this, pptcomp), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+        unsafe extern "system" fn GetFuncDesc(this: *mut core::ffi::c_void, index: u32, ppfuncdesc: *mut *mut FUNCDESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo", "GetFuncDesc", 5usize, |this| ((**(this as *const *const ITypeInfo_Vtbl)).GetFuncDesc)(this, index, ppfuncdesc), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+        unsafe extern "system" fn GetVarDesc(this: *mut core::ffi::c_void, index: u32, ppvardesc: *mut *mut VARDESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo", "GetVarDesc", 6usize, |this| ((**(this as *const *const ITypeInfo_Vtbl)).GetVarDesc)(this, index, ppvardesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNames(this: *mut core::ffi::c_void, memid: i32, rgbstrnames: *mut core::mem::MaybeUninit<windows_core::BSTR>, cmaxnames: u32, pcnames: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo", "GetNames", 7usize, |this| ((**(this as *const *const ITypeInfo_Vtbl)).GetNames)(this, memid, rgbstrnames, cmaxnames, pcnames), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRefTypeOfImplType(this: *mut core::ffi::c_void, index: u32, preftype: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo", "GetRefTypeOfImplType", 8usize, |this| ((**(this as *const *const ITypeInfo_Vtbl)).GetRefTypeOfImplType)(this, index, preftype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetImplTypeFlags(this: *mut core::ffi::c_void, index: u32, pimpltypeflags: *mut IMPLTYPEFLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo", "GetImplTypeFlags", 9usize, |this| ((**(this as *const *const ITypeInfo_Vtbl)).GetImplTypeFlags)(this, index, pimpltypeflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIDsOfNames(this: *mut core::ffi::c_void, rgsznames: *const windows_core::PCWSTR, cnames: u32, pmemid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo", "GetIDsOfNames", 10usize, |this| ((**(this as *const *const ITypeInfo_Vtbl)).GetIDsOfNames)(this, rgsznames, cnames, pmemid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Invoke(this: *mut core::ffi::c_void, pvinstance: *const core::ffi::c_void, memid: i32, wflags: DISPATCH_FLAGS, pdispparams: *mut DISPPARAMS, pvarresult: *mut core::mem::MaybeUninit<windows_core::VARIANT>, pexcepinfo: *mut EXCEPINFO, puargerr: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo", "Invoke", 11usize, |this| ((**(this as *const *const ITypeInfo_Vtbl)).Invoke)(this, pvinstance, memid, wflags, pdispparams, pvarresult, pexcepinfo, puargerr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDocumentation(this: *mut core::ffi::c_void, memid: i32, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrdocstring: *mut core::mem::MaybeUninit<windows_core::BSTR>, pdwhelpcontext: *mut u32, pbstrhelpfile: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo", "GetDocumentation", 12usize, |this| ((**(this as *const *const ITypeInfo_Vtbl)).GetDocumentation)(this, memid, pbstrname, pbstrdocstring, pdwhelpcontext, pbstrhelpfile), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDllEntry(this: *mut core::ffi::c_void, memid: i32, invkind: INVOKEKIND, pbstrdllname: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>, pwordinal: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo", "GetDllEntry", 13usize, |this| ((**(this as *const *const ITypeInfo_Vtbl)).GetDllEntry)(this, memid, invkind, pbstrdllname, pbstrname, pwordinal), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRefTypeInfo(this: *mut core::ffi::c_void, hreftype: u32, pptinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo", "GetRefTypeInfo", 14usize, |this| ((**(this as *const *const ITypeInfo_Vtbl)).GetRefTypeInfo)(this, hreftype, pptinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddressOfMember(this: *mut core::ffi::c_void, memid: i32, invkind: INVOKEKIND, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo", "AddressOfMember", 15usize, |this| ((**(this as *const *const ITypeInfo_Vtbl)).AddressOfMember)(this, memid, invkind, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo", "CreateInstance", 16usize, |this| ((**(this as *const *const ITypeInfo_Vtbl)).CreateInstance)(this, punkouter, riid, ppvobj), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMops(this: *mut core::ffi::c_void, memid: i32, pbstrmops: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo", "GetMops", 17usize, |this| ((**(this as *const *const ITypeInfo_Vtbl)).GetMops)(this, memid, pbstrmops), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContainingTypeLib(this: *mut core::ffi::c_void, pptlib: *mut *mut core::ffi::c_void, pindex: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo", "GetContainingTypeLib", 18usize, |this| ((**(this as *const *const ITypeInfo_Vtbl)).GetContainingTypeLib)(this, pptlib, pindex), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+        unsafe extern "system" fn ReleaseTypeAttr(this: *mut core::ffi::c_void, ptypeattr: *const TYPEATTR) {
+            windows_core::imp::proxy_call(this, "ITypeInfo", "ReleaseTypeAttr", 19usize, |this| ((**(this as *const *const ITypeInfo_Vtbl)).ReleaseTypeAttr)(this, ptypeattr), |_| None)
+        }
+        #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+        unsafe extern "system" fn ReleaseFuncDesc(this: *mut core::ffi::c_void, pfuncdesc: *const FUNCDESC) {
+            windows_core::imp::proxy_call(this, "ITypeInfo", "ReleaseFuncDesc", 20usize, |this| ((**(this as *const *const ITypeInfo_Vtbl)).ReleaseFuncDesc)(this, pfuncdesc), |_| None)
+        }
+        #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+        unsafe extern "system" fn ReleaseVarDesc(this: *mut core::ffi::c_void, pvardesc: *const VARDESC) {
+            windows_core::imp::proxy_call(this, "ITypeInfo", "ReleaseVarDesc", 21usize, |this| ((**(this as *const *const ITypeInfo_Vtbl)).ReleaseVarDesc)(this, pvardesc), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+            GetTypeAttr,
+            #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
+            GetTypeAttr: 0,
+            GetTypeComp,
+            #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+            GetFuncDesc,
+            #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
+            GetFuncDesc: 0,
+            #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+            GetVarDesc,
+            #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
+            GetVarDesc: 0,
+            GetNames,
+            GetRefTypeOfImplType,
+            GetImplTypeFlags,
+            GetIDsOfNames,
+            Invoke,
+            GetDocumentation,
+            GetDllEntry,
+            GetRefTypeInfo,
+            AddressOfMember,
+            CreateInstance,
+            GetMops,
+            GetContainingTypeLib,
+            #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+            ReleaseTypeAttr,
+            #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
+            ReleaseTypeAttr: 0,
+            #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+            ReleaseFuncDesc,
+            #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
+            ReleaseFuncDesc: 0,
+            #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+            ReleaseVarDesc,
+            #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
+            ReleaseVarDesc: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITypeInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::imp::ProxyVtable for ITypeInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTypeKind(this: *mut core::ffi::c_void, ptypekind: *mut TYPEKIND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo2", "GetTypeKind", 22usize, |this| ((**(this as *const *const ITypeInfo2_Vtbl)).GetTypeKind)(this, ptypekind), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTypeFlags(this: *mut core::ffi::c_void, ptypeflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo2", "GetTypeFlags", 23usize, |this| ((**(this as *const *const ITypeInfo2_Vtbl)).GetTypeFlags)(this, ptypeflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFuncIndexOfMemId(this: *mut core::ffi::c_void, memid: i32, invkind: INVOKEKIND, pfuncindex: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo2", "GetFuncIndexOfMemId", 24usize, |this| ((**(this as *const *const ITypeInfo2_Vtbl)).GetFuncIndexOfMemId)(this, memid, invkind, pfuncindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVarIndexOfMemId(this: *mut core::ffi::c_void, memid: i32, pvarindex: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo2", "GetVarIndexOfMemId", 25usize, |this| ((**(this as *const *const ITypeInfo2_Vtbl)).GetVarIndexOfMemId)(this, memid, pvarindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCustData(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pvarval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo2", "GetCustData", 26usize, |this| ((**(this as *const *const ITypeInfo2_Vtbl)).GetCustData)(this, guid, pvarval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFuncCustData(this: *mut core::ffi::c_void, index: u32, guid: *const windows_core::GUID, pvarval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo2", "GetFuncCustData", 27usize, |this| ((**(this as *const *const ITypeInfo2_Vtbl)).GetFuncCustData)(this, index, guid, pvarval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParamCustData(this: *mut core::ffi::c_void, indexfunc: u32, indexparam: u32, guid: *const windows_core::GUID, pvarval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo2", "GetParamCustData", 28usize, |this| ((**(this as *const *const ITypeInfo2_Vtbl)).GetParamCustData)(this, indexfunc, indexparam, guid, pvarval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVarCustData(this: *mut core::ffi::c_void, index: u32, guid: *const windows_core::GUID, pvarval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo2", "GetVarCustData", 29usize, |this| ((**(this as *const *const ITypeInfo2_Vtbl)).GetVarCustData)(this, index, guid, pvarval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetImplTypeCustData(this: *mut core::ffi::c_void, index: u32, guid: *const windows_core::GUID, pvarval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo2", "GetImplTypeCustData", 30usize, |this| ((**(this as *const *const ITypeInfo2_Vtbl)).GetImplTypeCustData)(this, index, guid, pvarval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDocumentation2(this: *mut core::ffi::c_void, memid: i32, lcid: u32, pbstrhelpstring: *mut core::mem::MaybeUninit<windows_core::BSTR>, pdwhelpstringcontext: *mut u32, pbstrhelpstringdll: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo2", "GetDocumentation2", 31usize, |this| ((**(this as *const *const ITypeInfo2_Vtbl)).GetDocumentation2)(this, memid, lcid, pbstrhelpstring, pdwhelpstringcontext, pbstrhelpstringdll), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllCustData(this: *mut core::ffi::c_void, pcustdata: *mut CUSTDATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo2", "GetAllCustData", 32usize, |this| ((**(this as *const *const ITypeInfo2_Vtbl)).GetAllCustData)(this, pcustdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllFuncCustData(this: *mut core::ffi::c_void, index: u32, pcustdata: *mut CUSTDATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo2", "GetAllFuncCustData", 33usize, |this| ((**(this as *const *const ITypeInfo2_Vtbl)).GetAllFuncCustData)(this, index, pcustdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllParamCustData(this: *mut core::ffi::c_void, indexfunc: u32, indexparam: u32, pcustdata: *mut CUSTDATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo2", "GetAllParamCustData", 34usize, |this| ((**(this as *const *const ITypeInfo2_Vtbl)).GetAllParamCustData)(this, indexfunc, indexparam, pcustdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllVarCustData(this: *mut core::ffi::c_void, index: u32, pcustdata: *mut CUSTDATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo2", "GetAllVarCustData", 35usize, |this| ((**(this as *const *const ITypeInfo2_Vtbl)).GetAllVarCustData)(this, index, pcustdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllImplTypeCustData(this: *mut core::ffi::c_void, index: u32, pcustdata: *mut CUSTDATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeInfo2", "GetAllImplTypeCustData", 36usize, |this| ((**(this as *const *const ITypeInfo2_Vtbl)).GetAllImplTypeCustData)(this, index, pcustdata), |result| Some(*result))
+        }
+        Self {
+            base__: <ITypeInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTypeKind,
+            GetTypeFlags,
+            GetFuncIndexOfMemId,
+            GetVarIndexOfMemId,
+            GetCustData,
+            GetFuncCustData,
+            GetParamCustData,
+            GetVarCustData,
+            GetImplTypeCustData,
+            GetDocumentation2,
+            GetAllCustData,
+            GetAllFuncCustData,
+            GetAllParamCustData,
+            GetAllVarCustData,
+            GetAllImplTypeCustData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITypeInfo2 as windows_core::Interface>::IID || <ITypeInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITypeLib_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTypeInfoCount(this: *mut core::ffi::c_void) -> u32 {
+            windows_core::imp::proxy_call(this, "ITypeLib", "GetTypeInfoCount", 3usize, |this| ((**(this as *const *const ITypeLib_Vtbl)).GetTypeInfoCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetTypeInfo(this: *mut core::ffi::c_void, index: u32, pptinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeLib", "GetTypeInfo", 4usize, |this| ((**(this as *const *const ITypeLib_Vtbl)).GetTypeInfo)(this, index, pptinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTypeInfoType(this: *mut core::ffi::c_void, index: u32, ptkind: *mut TYPEKIND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeLib", "GetTypeInfoType", 5usize, |this| ((**(this as *const *const ITypeLib_Vtbl)).GetTypeInfoType)(this, index, ptkind), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTypeInfoOfGuid(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pptinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeLib", "GetTypeInfoOfGuid", 6usize, |this| ((**(this as *const *const ITypeLib_Vtbl)).GetTypeInfoOfGuid)(this, guid, pptinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLibAttr(this: *mut core::ffi::c_void, pptlibattr: *mut *mut TLIBATTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeLib", "GetLibAttr", 7usize, |this| ((**(this as *const *const ITypeLib_Vtbl)).GetLibAttr)(this, pptlibattr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTypeComp(this: *mut core::ffi::c_void, pptcomp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeLib", "GetTypeComp", 8usize, |this| ((**(this as *const *const ITypeLib_Vtbl)).GetTypeComp)(this, pptcomp), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDocumentation(this: *mut core::ffi::c_void, index: i32, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrdocstring: *mut core::mem::MaybeUninit<windows_core::BSTR>, pdwhelpcontext: *mut u32, pbstrhelpfile: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeLib", "GetDocumentation", 9usize, |this| ((**(this as *const *const ITypeLib_Vtbl)).GetDocumentation)(this, index, pbstrname, pbstrdocstring, pdwhelpcontext, pbstrhelpfile), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsName(this: *mut core::ffi::c_void, sznamebuf: windows_core::PWSTR, lhashval: u32, pfname: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeLib", "IsName", 10usize, |this| ((**(this as *const *const ITypeLib_Vtbl)).IsName)(this, sznamebuf, lhashval, pfname), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindName(this: *mut core::ffi::c_void, sznamebuf: windows_core::PWSTR, lhashval: u32, pptinfo: *mut *mut core::ffi::c_void, rgmemid: *mut i32, pcfound: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeLib", "FindName", 11usize, |this| ((**(this as *const *const ITypeLib_Vtbl)).FindName)(this, sznamebuf, lhashval, pptinfo, rgmemid, pcfound), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseTLibAttr(this: *mut core::ffi::c_void, ptlibattr: *const TLIBATTR) {
+            windows_core::imp::proxy_call(this, "ITypeLib", "ReleaseTLibAttr", 12usize, |this| ((**(this as *const *const ITypeLib_Vtbl)).ReleaseTLibAttr)(this, ptlibattr), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTypeInfoCount,
+            GetTypeInfo,
+            GetTypeInfoType,
+            GetTypeInfoOfGuid,
+            GetLibAttr,
+            GetTypeComp,
+            GetDocumentation,
+            IsName,
+            FindName,
+            ReleaseTLibAttr,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITypeLib as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITypeLib2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCustData(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pvarval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeLib2", "GetCustData", 13usize, |this| ((**(this as *const *const ITypeLib2_Vtbl)).GetCustData)(this, guid, pvarval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLibStatistics(this: *mut core::ffi::c_void, pcuniquenames: *mut u32, pcchuniquenames: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeLib2", "GetLibStatistics", 14usize, |this| ((**(this as *const *const ITypeLib2_Vtbl)).GetLibStatistics)(this, pcuniquenames, pcchuniquenames), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDocumentation2(this: *mut core::ffi::c_void, index: i32, lcid: u32, pbstrhelpstring: *mut core::mem::MaybeUninit<windows_core::BSTR>, pdwhelpstringcontext: *mut u32, pbstrhelpstringdll: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeLib2", "GetDocumentation2", 15usize, |this| ((**(this as *const *const ITypeLib2_Vtbl)).GetDocumentation2)(this, index, lcid, pbstrhelpstring, pdwhelpstringcontext, pbstrhelpstringdll), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllCustData(this: *mut core::ffi::c_void, pcustdata: *mut CUSTDATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeLib2", "GetAllCustData", 16usize, |this| ((**(this as *const *const ITypeLib2_Vtbl)).GetAllCustData)(this, pcustdata), |result| Some(*result))
+        }
+        Self { base__: <ITypeLib_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCustData, GetLibStatistics, GetDocumentation2, GetAllCustData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITypeLib2 as windows_core::Interface>::IID || <ITypeLib_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITypeLibRegistration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetGuid(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeLibRegistration", "GetGuid", 3usize, |this| ((**(this as *const *const ITypeLibRegistration_Vtbl)).GetGuid)(this, pguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersion(this: *mut core::ffi::c_void, pversion: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeLibRegistration", "GetVersion", 4usize, |this| ((**(this as *const *const ITypeLibRegistration_Vtbl)).GetVersion)(this, pversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLcid(this: *mut core::ffi::c_void, plcid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeLibRegistration", "GetLcid", 5usize, |this| ((**(this as *const *const ITypeLibRegistration_Vtbl)).GetLcid)(this, plcid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWin32Path(this: *mut core::ffi::c_void, pwin32path: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeLibRegistration", "GetWin32Path", 6usize, |this| ((**(this as *const *const ITypeLibRegistration_Vtbl)).GetWin32Path)(this, pwin32path), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWin64Path(this: *mut core::ffi::c_void, pwin64path: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeLibRegistration", "GetWin64Path", 7usize, |this| ((**(this as *const *const ITypeLibRegistration_Vtbl)).GetWin64Path)(this, pwin64path), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayName(this: *mut core::ffi::c_void, pdisplayname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeLibRegistration", "GetDisplayName", 8usize, |this| ((**(this as *const *const ITypeLibRegistration_Vtbl)).GetDisplayName)(this, pdisplayname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlags(this: *mut core::ffi::c_void, pflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeLibRegistration", "GetFlags", 9usize, |this| ((**(this as *const *const ITypeLibRegistration_Vtbl)).GetFlags)(this, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHelpDir(this: *mut core::ffi::c_void, phelpdir: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeLibRegistration", "GetHelpDir", 10usize, |this| ((**(this as *const *const ITypeLibRegistration_Vtbl)).GetHelpDir)(this, phelpdir), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetGuid,
+            GetVersion,
+            GetLcid,
+            GetWin32Path,
+            GetWin64Path,
+            GetDisplayName,
+            GetFlags,
+            GetHelpDir,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITypeLibRegistration as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITypeLibRegistrationReader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumTypeLibRegistrations(this: *mut core::ffi::c_void, ppenumunknown: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeLibRegistrationReader", "EnumTypeLibRegistrations", 3usize, |this| ((**(this as *const *const ITypeLibRegistrationReader_Vtbl)).EnumTypeLibRegistrations)(this, ppenumunknown), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnumTypeLibRegistrations }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITypeLibRegistrationReader as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUri_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPropertyBSTR(this: *mut core::ffi::c_void, uriprop: Uri_PROPERTY, pbstrproperty: *mut core::mem::MaybeUninit<windows_core::BSTR>, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUri", "GetPropertyBSTR", 3usize, |this| ((**(this as *const *const IUri_Vtbl)).GetPropertyBSTR)(this, uriprop, pbstrproperty, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyLength(this: *mut core::ffi::c_void, uriprop: Uri_PROPERTY, pcchproperty: *mut u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUri", "GetPropertyLength", 4usize, |this| ((**(this as *const *const IUri_Vtbl)).GetPropertyLength)(this, uriprop, pcchproperty, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyDWORD(this: *mut core::ffi::c_void, uriprop: Uri_PROPERTY, pdwproperty: *mut u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUri", "GetPropertyDWORD", 5usize, |this| ((**(this as *const *const IUri_Vtbl)).GetPropertyDWORD)(this, uriprop, pdwproperty, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasProperty(this: *mut core::ffi::c_void, uriprop: Uri_PROPERTY, pfhasproperty: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUri", "HasProperty", 6usize, |this| ((**(this as *const *const IUri_Vtbl)).HasProperty)(this, uriprop, pfhasproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAbsoluteUri(this: *mut core::ffi::c_void, pbstrabsoluteuri: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUri", "GetAbsoluteUri", 7usize, |this| ((**(this as *const *const IUri_Vtbl)).GetAbsoluteUri)(this, pbstrabsoluteuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAuthority(this: *mut core::ffi::c_void, pbstrauthority: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUri", "GetAuthority", 8usize, |this| ((**(this as *const *const IUri_Vtbl)).GetAuthority)(this, pbstrauthority), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayUri(this: *mut core::ffi::c_void, pbstrdisplaystring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUri", "GetDisplayUri", 9usize, |this| ((**(this as *const *const IUri_Vtbl)).GetDisplayUri)(this, pbstrdisplaystring), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDomain(this: *mut core::ffi::c_void, pbstrdomain: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUri", "GetDomain", 10usize, |this| ((**(this as *const *const IUri_Vtbl)).GetDomain)(this, pbstrdomain), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExtension(this: *mut core::ffi::c_void, pbstrextension: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUri", "GetExtension", 11usize, |this| ((**(this as *const *const IUri_Vtbl)).GetExtension)(this, pbstrextension), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFragment(this: *mut core::ffi::c_void, pbstrfragment: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUri", "GetFragment", 12usize, |this| ((**(this as *const *const IUri_Vtbl)).GetFragment)(this, pbstrfragment), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHost(this: *mut core::ffi::c_void, pbstrhost: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUri", "GetHost", 13usize, |this| ((**(this as *const *const IUri_Vtbl)).GetHost)(this, pbstrhost), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPassword(this: *mut core::ffi::c_void, pbstrpassword: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUri", "GetPassword", 14usize, |this| ((**(this as *const *const IUri_Vtbl)).GetPassword)(this, pbstrpassword), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPath(this: *mut core::ffi::c_void, pbstrpath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUri", "GetPath", 15usize, |this| ((**(this as *const *const IUri_Vtbl)).GetPath)(this, pbstrpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPathAndQuery(this: *mut core::ffi::c_void, pbstrpathandquery: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUri", "GetPathAndQuery", 16usize, |this| ((**(this as *const *const IUri_Vtbl)).GetPathAndQuery)(this, pbstrpathandquery), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetQuery(this: *mut core::ffi::c_void, pbstrquery: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUri", "GetQuery", 17usize, |this| ((**(this as *const *const IUri_Vtbl)).GetQuery)(this, pbstrquery), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRawUri(this: *mut core::ffi::c_void, pbstrrawuri: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUri", "GetRawUri", 18usize, |this| ((**(this as *const *const IUri_Vtbl)).GetRawUri)(this, pbstrrawuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSchemeName(this: *mut core::ffi::c_void, pbstrschemename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUri", "GetSchemeName", 19usize, |this| ((**(this as *const *const IUri_Vtbl)).GetSchemeName)(this, pbstrschemename), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUserInfo(this: *mut core::ffi::c_void, pbstruserinfo: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUri", "GetUserInfo", 20usize, |this| ((**(this as *const *const IUri_Vtbl)).GetUserInfo)(this, pbstruserinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUserName(this: *mut core::ffi::c_void, pbstrusername: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUri", "GetUserName", 21usize, |this| ((**(this as *const *const IUri_Vtbl)).GetUserName)(this, pbstrusername), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHostType(this: *mut core::ffi::c_void, pdwhosttype: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUri", "GetHostType", 22usize, |this| ((**(this as *const *const IUri_Vtbl)).GetHostType)(this, pdwhosttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPort(this: *mut core::ffi::c_void, pdwport: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUri", "GetPort", 23usize, |this| ((**(this as *const *const IUri_Vtbl)).GetPort)(this, pdwport), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScheme(this: *mut core::ffi::c_void, pdwscheme: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUri", "GetScheme", 24usize, |this| ((**(this as *const *const IUri_Vtbl)).GetScheme)(this, pdwscheme), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetZone(this: *mut core::ffi::c_void, pdwzone: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUri", "GetZone", 25usize, |this| ((**(this as *const *const IUri_Vtbl)).GetZone)(this, pdwzone), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUri", "GetProperties", 26usize, |this| ((**(this as *const *const IUri_Vtbl)).GetProperties)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEqual(this: *mut core::ffi::c_void, puri: *mut core::ffi::c_void, pfequal: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUri", "IsEqual", 27usize, |this| ((**(this as *const *const IUri_Vtbl)).IsEqual)(this, puri, pfequal), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPropertyBSTR,
+            GetPropertyLength,
+            GetPropertyDWORD,
+            HasProperty,
+            GetAbsoluteUri,
+            GetAuthority,
+            GetDisplayUri,
+            GetDomain,
+            GetExtension,
+            GetFragment,
+            GetHost,
+            GetPassword,
+            GetPath,
+            GetPathAndQuery,
+            GetQuery,
+            GetRawUri,
+            GetSchemeName,
+            GetUserInfo,
+            GetUserName,
+            GetHostType,
+            GetPort,
+            GetScheme,
+            GetZone,
+            GetProperties,
+            IsEqual,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUri as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUriBuilder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateUriSimple(this: *mut core::ffi::c_void, dwallowencodingpropertymask: u32, dwreserved: usize, ppiuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriBuilder", "CreateUriSimple", 3usize, |this| ((**(this as *const *const IUriBuilder_Vtbl)).CreateUriSimple)(this, dwallowencodingpropertymask, dwreserved, ppiuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateUri(this: *mut core::ffi::c_void, dwcreateflags: u32, dwallowencodingpropertymask: u32, dwreserved: usize, ppiuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriBuilder", "CreateUri", 4usize, |this| ((**(this as *const *const IUriBuilder_Vtbl)).CreateUri)(this, dwcreateflags, dwallowencodingpropertymask, dwreserved, ppiuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateUriWithFlags(this: *mut core::ffi::c_void, dwcreateflags: u32, dwuribuilderflags: u32, dwallowencodingpropertymask: u32, dwreserved: usize, ppiuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriBuilder", "CreateUriWithFlags", 5usize, |this| ((**(this as *const *const IUriBuilder_Vtbl)).CreateUriWithFlags)(this, dwcreateflags, dwuribuilderflags, dwallowencodingpropertymask, dwreserved, ppiuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIUri(this: *mut core::ffi::c_void, ppiuri: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriBuilder", "GetIUri", 6usize, |this| ((**(this as *const *const IUriBuilder_Vtbl)).GetIUri)(this, ppiuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIUri(this: *mut core::ffi::c_void, piuri: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriBuilder", "SetIUri", 7usize, |this| ((**(this as *const *const IUriBuilder_Vtbl)).SetIUri)(this, piuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFragment(this: *mut core::ffi::c_void, pcchfragment: *mut u32, ppwzfragment: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriBuilder", "GetFragment", 8usize, |this| ((**(this as *const *const IUriBuilder_Vtbl)).GetFragment)(this, pcchfragment, ppwzfragment), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHost(this: *mut core::ffi::c_void, pcchhost: *mut u32, ppwzhost: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriBuilder", "GetHost", 9usize, |this| ((**(this as *const *const IUriBuilder_Vtbl)).GetHost)(this, pcchhost, ppwzhost), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPassword(this: *mut core::ffi::c_void, pcchpassword: *mut u32, ppwzpassword: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriBuilder", "GetPassword", 10usize, |this| ((**(this as *const *const IUriBuilder_Vtbl)).GetPassword)(this, pcchpassword, ppwzpassword), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPath(this: *mut core::ffi::c_void, pcchpath: *mut u32, ppwzpath: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriBuilder", "GetPath", 11usize, |this| ((**(this as *const *const IUriBuilder_Vtbl)).GetPath)(this, pcchpath, ppwzpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPort(this: *mut core::ffi::c_void, pfhasport: *mut super::super::Foundation::BOOL, pdwport: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriBuilder", "GetPort", 12usize, |this| ((**(this as *const *const IUriBuilder_Vtbl)).GetPort)(this, pfhasport, pdwport), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetQuery(this: *mut core::ffi::c_void, pcchquery: *mut u32, ppwzquery: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriBuilder", "GetQuery", 13usize, |this| ((**(this as *const *const IUriBuilder_Vtbl)).GetQuery)(this, pcchquery, ppwzquery), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSchemeName(this: *mut core::ffi::c_void, pcchschemename: *mut u32, ppwzschemename: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriBuilder", "GetSchemeName", 14usize, |this| ((**(this as *const *const IUriBuilder_Vtbl)).GetSchemeName)(this, pcchschemename, ppwzschemename), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUserName(this: *mut core::ffi::c_void, pcchusername: *mut u32, ppwzusername: *mut windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriBuilder", "GetUserName", 15usize, |this| ((**(this as *const *const IUriBuilder_Vtbl)).GetUserName)(this, pcchusername, ppwzusername), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFragment(this: *mut core::ffi::c_void, pwznewvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriBuilder", "SetFragment", 16usize, |this| ((**(this as *const *const IUriBuilder_Vtbl)).SetFragment)(this, pwznewvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHost(this: *mut core::ffi::c_void, pwznewvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriBuilder", "SetHost", 17usize, |this| ((**(this as *const *const IUriBuilder_Vtbl)).SetHost)(this, pwznewvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPassword(this: *mut core::ffi::c_void, pwznewvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriBuilder", "SetPassword", 18usize, |this| ((**(this as *const *const IUriBuilder_Vtbl)).SetPassword)(this, pwznewvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPath(this: *mut core::ffi::c_void, pwznewvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriBuilder", "SetPath", 19usize, |this| ((**(this as *const *const IUriBuilder_Vtbl)).SetPath)(this, pwznewvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPort(this: *mut core::ffi::c_void, fhasport: super::super::Foundation::BOOL, dwnewvalue: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriBuilder", "SetPort", 20usize, |this| ((**(this as *const *const IUriBuilder_Vtbl)).SetPort)(this, fhasport, dwnewvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQuery(this: *mut core::ffi::c_void, pwznewvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriBuilder", "SetQuery", 21usize, |this| ((**(this as *const *const IUriBuilder_Vtbl)).SetQuery)(this, pwznewvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSchemeName(this: *mut core::ffi::c_void, pwznewvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriBuilder", "SetSchemeName", 22usize, |this| ((**(this as *const *const IUriBuilder_Vtbl)).SetSchemeName)(this, pwznewvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUserName(this: *mut core::ffi::c_void, pwznewvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriBuilder", "SetUserName", 23usize, |this| ((**(this as *const *const IUriBuilder_Vtbl)).SetUserName)(this, pwznewvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveProperties(this: *mut core::ffi::c_void, dwpropertymask: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriBuilder", "RemoveProperties", 24usize, |this| ((**(this as *const *const IUriBuilder_Vtbl)).RemoveProperties)(this, dwpropertymask), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasBeenModified(this: *mut core::ffi::c_void, pfmodified: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUriBuilder", "HasBeenModified", 25usize, |this| ((**(this as *const *const IUriBuilder_Vtbl)).HasBeenModified)(this, pfmodified), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateUriSimple,
+            CreateUri,
+            CreateUriWithFlags,
+            GetIUri,
+            SetIUri,
+            GetFragment,
+            GetHost,
+            GetPassword,
+            GetPath,
+            GetPort,
+            GetQuery,
+            GetSchemeName,
+            GetUserName,
+            SetFragment,
+            SetHost,
+            SetPassword,
+            SetPath,
+            SetPort,
+            SetQuery,
+            SetSchemeName,
+            SetUserName,
+            RemoveProperties,
+            HasBeenModified,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUriBuilder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUrlMon_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AsyncGetClassBits(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, psztype: windows_core::PCWSTR, pszext: windows_core::PCWSTR, dwfileversionms: u32, dwfileversionls: u32, pszcodebase: windows_core::PCWSTR, pbc: *mut core::ffi::c_void, dwclasscontext: u32, riid: *const windows_core::GUID, flags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUrlMon", "AsyncGetClassBits", 3usize, |this| ((**(this as *const *const IUrlMon_Vtbl)).AsyncGetClassBits)(this, rclsid, psztype, pszext, dwfileversionms, dwfileversionls, pszcodebase, pbc, dwclasscontext, riid, flags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AsyncGetClassBits }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUrlMon as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWaitMultiple_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WaitMultiple(this: *mut core::ffi::c_void, timeout: u32, psync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWaitMultiple", "WaitMultiple", 3usize, |this| ((**(this as *const *const IWaitMultiple_Vtbl)).WaitMultiple)(this, timeout, psync), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddSynchronize(this: *mut core::ffi::c_void, psync: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWaitMultiple", "AddSynchronize", 4usize, |this| ((**(this as *const *const IWaitMultiple_Vtbl)).AddSynchronize)(this, psync), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WaitMultiple, AddSynchronize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWaitMultiple as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
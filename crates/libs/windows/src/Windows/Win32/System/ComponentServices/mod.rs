@@ -5211,6 +5211,7 @@ pub struct AutoSvcs_Error_Constants(pub u32);
 impl windows_core::TypeKind for AutoSvcs_Error_Constants {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AutoSvcs_Error_Constants {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AutoSvcs_Error_Constants").field(&self.0).finish()
@@ -5222,6 +5223,7 @@ pub struct COMAdminAccessChecksLevelOptions(pub i32);
 impl windows_core::TypeKind for COMAdminAccessChecksLevelOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMAdminAccessChecksLevelOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMAdminAccessChecksLevelOptions").field(&self.0).finish()
@@ -5233,6 +5235,7 @@ pub struct COMAdminActivationOptions(pub i32);
 impl windows_core::TypeKind for COMAdminActivationOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMAdminActivationOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMAdminActivationOptions").field(&self.0).finish()
@@ -5244,6 +5247,7 @@ pub struct COMAdminApplicationExportOptions(pub i32);
 impl windows_core::TypeKind for COMAdminApplicationExportOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMAdminApplicationExportOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMAdminApplicationExportOptions").field(&self.0).finish()
@@ -5255,6 +5259,7 @@ pub struct COMAdminApplicationInstallOptions(pub i32);
 impl windows_core::TypeKind for COMAdminApplicationInstallOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMAdminApplicationInstallOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMAdminApplicationInstallOptions").field(&self.0).finish()
@@ -5266,6 +5271,7 @@ pub struct COMAdminAuthenticationCapabilitiesOptions(pub i32);
 impl windows_core::TypeKind for COMAdminAuthenticationCapabilitiesOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMAdminAuthenticationCapabilitiesOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMAdminAuthenticationCapabilitiesOptions").field(&self.0).finish()
@@ -5277,6 +5283,7 @@ pub struct COMAdminAuthenticationLevelOptions(pub i32);
 impl windows_core::TypeKind for COMAdminAuthenticationLevelOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMAdminAuthenticationLevelOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMAdminAuthenticationLevelOptions").field(&self.0).finish()
@@ -5288,6 +5295,7 @@ pub struct COMAdminComponentFlags(pub i32);
 impl windows_core::TypeKind for COMAdminComponentFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMAdminComponentFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMAdminComponentFlags").field(&self.0).finish()
@@ -5299,6 +5307,7 @@ pub struct COMAdminComponentType(pub i32);
 impl windows_core::TypeKind for COMAdminComponentType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMAdminComponentType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMAdminComponentType").field(&self.0).finish()
@@ -5310,6 +5319,7 @@ pub struct COMAdminErrorCodes(pub i32);
 impl windows_core::TypeKind for COMAdminErrorCodes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMAdminErrorCodes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMAdminErrorCodes").field(&self.0).finish()
@@ -5321,6 +5331,7 @@ pub struct COMAdminFileFlags(pub i32);
 impl windows_core::TypeKind for COMAdminFileFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMAdminFileFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMAdminFileFlags").field(&self.0).finish()
@@ -5332,6 +5343,7 @@ pub struct COMAdminImpersonationLevelOptions(pub i32);
 impl windows_core::TypeKind for COMAdminImpersonationLevelOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMAdminImpersonationLevelOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMAdminImpersonationLevelOptions").field(&self.0).finish()
@@ -5343,6 +5355,7 @@ pub struct COMAdminInUse(pub i32);
 impl windows_core::TypeKind for COMAdminInUse {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMAdminInUse {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMAdminInUse").field(&self.0).finish()
@@ -5354,6 +5367,7 @@ pub struct COMAdminOS(pub i32);
 impl windows_core::TypeKind for COMAdminOS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMAdminOS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMAdminOS").field(&self.0).finish()
@@ -5365,6 +5379,7 @@ pub struct COMAdminQCMessageAuthenticateOptions(pub i32);
 impl windows_core::TypeKind for COMAdminQCMessageAuthenticateOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMAdminQCMessageAuthenticateOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMAdminQCMessageAuthenticateOptions").field(&self.0).finish()
@@ -5376,6 +5391,7 @@ pub struct COMAdminServiceOptions(pub i32);
 impl windows_core::TypeKind for COMAdminServiceOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMAdminServiceOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMAdminServiceOptions").field(&self.0).finish()
@@ -5387,6 +5403,7 @@ pub struct COMAdminServiceStatusOptions(pub i32);
 impl windows_core::TypeKind for COMAdminServiceStatusOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMAdminServiceStatusOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMAdminServiceStatusOptions").field(&self.0).finish()
@@ -5398,6 +5415,7 @@ pub struct COMAdminSynchronizationOptions(pub i32);
 impl windows_core::TypeKind for COMAdminSynchronizationOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMAdminSynchronizationOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMAdminSynchronizationOptions").field(&self.0).finish()
@@ -5409,6 +5427,7 @@ pub struct COMAdminThreadingModels(pub i32);
 impl windows_core::TypeKind for COMAdminThreadingModels {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMAdminThreadingModels {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMAdminThreadingModels").field(&self.0).finish()
@@ -5420,6 +5439,7 @@ pub struct COMAdminTransactionOptions(pub i32);
 impl windows_core::TypeKind for COMAdminTransactionOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMAdminTransactionOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMAdminTransactionOptions").field(&self.0).finish()
@@ -5431,6 +5451,7 @@ pub struct COMAdminTxIsolationLevelOptions(pub i32);
 impl windows_core::TypeKind for COMAdminTxIsolationLevelOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMAdminTxIsolationLevelOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMAdminTxIsolationLevelOptions").field(&self.0).finish()
@@ -5442,6 +5463,7 @@ pub struct COMPLUS_APPTYPE(pub i32);
 impl windows_core::TypeKind for COMPLUS_APPTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMPLUS_APPTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMPLUS_APPTYPE").field(&self.0).finish()
@@ -5453,6 +5475,7 @@ pub struct CRMFLAGS(pub i32);
 impl windows_core::TypeKind for CRMFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CRMFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CRMFLAGS").field(&self.0).finish()
@@ -5464,6 +5487,7 @@ pub struct CRMREGFLAGS(pub i32);
 impl windows_core::TypeKind for CRMREGFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CRMREGFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CRMREGFLAGS").field(&self.0).finish()
@@ -5475,6 +5499,7 @@ pub struct CSC_Binding(pub i32);
 impl windows_core::TypeKind for CSC_Binding {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CSC_Binding {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CSC_Binding").field(&self.0).finish()
@@ -5486,6 +5511,7 @@ pub struct CSC_COMTIIntrinsicsConfig(pub i32);
 impl windows_core::TypeKind for CSC_COMTIIntrinsicsConfig {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CSC_COMTIIntrinsicsConfig {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CSC_COMTIIntrinsicsConfig").field(&self.0).finish()
@@ -5497,6 +5523,7 @@ pub struct CSC_IISIntrinsicsConfig(pub i32);
 impl windows_core::TypeKind for CSC_IISIntrinsicsConfig {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CSC_IISIntrinsicsConfig {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CSC_IISIntrinsicsConfig").field(&self.0).finish()
@@ -5508,6 +5535,7 @@ pub struct CSC_InheritanceConfig(pub i32);
 impl windows_core::TypeKind for CSC_InheritanceConfig {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CSC_InheritanceConfig {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CSC_InheritanceConfig").field(&self.0).finish()
@@ -5519,6 +5547,7 @@ pub struct CSC_PartitionConfig(pub i32);
 impl windows_core::TypeKind for CSC_PartitionConfig {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CSC_PartitionConfig {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CSC_PartitionConfig").field(&self.0).finish()
@@ -5530,6 +5559,7 @@ pub struct CSC_SxsConfig(pub i32);
 impl windows_core::TypeKind for CSC_SxsConfig {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CSC_SxsConfig {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CSC_SxsConfig").field(&self.0).finish()
@@ -5541,6 +5571,7 @@ pub struct CSC_SynchronizationConfig(pub i32);
 impl windows_core::TypeKind for CSC_SynchronizationConfig {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CSC_SynchronizationConfig {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CSC_SynchronizationConfig").field(&self.0).finish()
@@ -5552,6 +5583,7 @@ pub struct CSC_ThreadPool(pub i32);
 impl windows_core::TypeKind for CSC_ThreadPool {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CSC_ThreadPool {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CSC_ThreadPool").field(&self.0).finish()
@@ -5563,6 +5595,7 @@ pub struct CSC_TrackerConfig(pub i32);
 impl windows_core::TypeKind for CSC_TrackerConfig {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CSC_TrackerConfig {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CSC_TrackerConfig").field(&self.0).finish()
@@ -5574,6 +5607,7 @@ pub struct CSC_TransactionConfig(pub i32);
 impl windows_core::TypeKind for CSC_TransactionConfig {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CSC_TransactionConfig {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CSC_TransactionConfig").field(&self.0).finish()
@@ -5585,6 +5619,7 @@ pub struct CrmTransactionState(pub i32);
 impl windows_core::TypeKind for CrmTransactionState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CrmTransactionState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CrmTransactionState").field(&self.0).finish()
@@ -5596,6 +5631,7 @@ pub struct DUMPTYPE(pub i32);
 impl windows_core::TypeKind for DUMPTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DUMPTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DUMPTYPE").field(&self.0).finish()
@@ -5607,6 +5643,7 @@ pub struct GetAppTrackerDataFlags(pub i32);
 impl windows_core::TypeKind for GetAppTrackerDataFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GetAppTrackerDataFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GetAppTrackerDataFlags").field(&self.0).finish()
@@ -5618,6 +5655,7 @@ pub struct LockModes(pub i32);
 impl windows_core::TypeKind for LockModes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LockModes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LockModes").field(&self.0).finish()
@@ -5629,6 +5667,7 @@ pub struct ReleaseModes(pub i32);
 impl windows_core::TypeKind for ReleaseModes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ReleaseModes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ReleaseModes").field(&self.0).finish()
@@ -5640,6 +5679,7 @@ pub struct TRACKING_COLL_TYPE(pub i32);
 impl windows_core::TypeKind for TRACKING_COLL_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TRACKING_COLL_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TRACKING_COLL_TYPE").field(&self.0).finish()
@@ -5651,13 +5691,15 @@ pub struct TransactionVote(pub i32);
 impl windows_core::TypeKind for TransactionVote {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TransactionVote {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TransactionVote").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct APPDATA {
     pub m_idApp: u32,
     pub m_szAppGuid: [u16; 40],
@@ -5673,7 +5715,8 @@ impl Default for APPDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct APPSTATISTICS {
     pub m_cTotalCalls: u32,
     pub m_cTotalInstances: u32,
@@ -5690,7 +5733,8 @@ impl Default for APPSTATISTICS {
 }
 pub const AppDomainHelper: windows_core::GUID = windows_core::GUID::from_u128(0xef24f689_14f8_4d92_b4af_d7b1f0e70fd4);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ApplicationProcessRecycleInfo {
     pub IsRecyclable: super::super::Foundation::BOOL,
     pub IsRecycled: super::super::Foundation::BOOL,
@@ -5716,7 +5760,8 @@ impl Default for ApplicationProcessRecycleInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ApplicationProcessStatistics {
     pub NumCallsOutstanding: u32,
     pub NumTrackedComponents: u32,
@@ -5736,7 +5781,8 @@ impl Default for ApplicationProcessStatistics {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ApplicationProcessSummary {
     pub PartitionIdPrimaryApplication: windows_core::GUID,
     pub ApplicationIdPrimaryApplication: windows_core::GUID,
@@ -5757,7 +5803,8 @@ impl Default for ApplicationProcessSummary {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ApplicationSummary {
     pub ApplicationInstanceId: windows_core::GUID,
     pub PartitionId: windows_core::GUID,
@@ -5777,7 +5824,8 @@ impl Default for ApplicationSummary {
 }
 pub const ByotServerEx: windows_core::GUID = windows_core::GUID::from_u128(0xecabb0aa_7f19_11d2_978e_0000f8757e2a);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLSIDDATA {
     pub m_clsid: windows_core::GUID,
     pub m_cReferences: u32,
@@ -5797,7 +5845,8 @@ impl Default for CLSIDDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLSIDDATA2 {
     pub m_clsid: windows_core::GUID,
     pub m_appid: windows_core::GUID,
@@ -5826,7 +5875,8 @@ pub const COMAdminCatalogCollection: windows_core::GUID = windows_core::GUID::fr
 pub const COMAdminCatalogObject: windows_core::GUID = windows_core::GUID::from_u128(0xf618c515_dfb8_11d1_a2cf_00805fc79235);
 pub const COMEvents: windows_core::GUID = windows_core::GUID::from_u128(0xecabb0ab_7f19_11d2_978e_0000f8757e2a);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COMSVCSEVENTINFO {
     pub cbSize: u32,
     pub dwPid: u32,
@@ -5852,7 +5902,8 @@ pub const CoMTSLocator: windows_core::GUID = windows_core::GUID::from_u128(0xeca
 pub const ComServiceEvents: windows_core::GUID = windows_core::GUID::from_u128(0xecabb0c3_7f19_11d2_978e_0000f8757e2a);
 pub const ComSystemAppEventData: windows_core::GUID = windows_core::GUID::from_u128(0xecabb0c6_7f19_11d2_978e_0000f8757e2a);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ComponentHangMonitorInfo {
     pub IsMonitored: super::super::Foundation::BOOL,
     pub TerminateOnHang: super::super::Foundation::BOOL,
@@ -5867,7 +5918,8 @@ impl Default for ComponentHangMonitorInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ComponentStatistics {
     pub NumInstances: u32,
     pub NumBoundReferences: u32,
@@ -5892,7 +5944,8 @@ impl Default for ComponentStatistics {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ComponentSummary {
     pub ApplicationInstanceId: windows_core::GUID,
     pub PartitionId: windows_core::GUID,
@@ -5911,7 +5964,8 @@ impl Default for ComponentSummary {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CrmLogRecordRead {
     pub dwCrmFlags: u32,
     pub dwSequenceNumber: u32,
@@ -5932,7 +5986,8 @@ pub const Dummy30040732: windows_core::GUID = windows_core::GUID::from_u128(0xec
 pub const EventServer: windows_core::GUID = windows_core::GUID::from_u128(0xecabafbc_7f19_11d2_978e_0000f8757e2a);
 pub const GetSecurityCallContextAppObject: windows_core::GUID = windows_core::GUID::from_u128(0xecabb0a8_7f19_11d2_978e_0000f8757e2a);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HANG_INFO {
     pub fAppHangMonitorEnabled: super::super::Foundation::BOOL,
     pub fTerminateOnHang: super::super::Foundation::BOOL,
@@ -5954,7 +6009,8 @@ pub const MessageMover: windows_core::GUID = windows_core::GUID::from_u128(0xeca
 pub const MtsGrp: windows_core::GUID = windows_core::GUID::from_u128(0x4b2e958d_0393_11d1_b1ab_00aa00ba3258);
 pub const PoolMgr: windows_core::GUID = windows_core::GUID::from_u128(0xecabafb5_7f19_11d2_978e_0000f8757e2a);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RECYCLE_INFO {
     pub guidCombaseProcessIdentifier: windows_core::GUID,
     pub ProcessStartTime: i64,
@@ -5983,3 +6039,5 @@ pub const TransactionContext: windows_core::GUID = windows_core::GUID::from_u128
 pub const TransactionContextEx: windows_core::GUID = windows_core::GUID::from_u128(0x5cb66670_d3d4_11cf_acab_00a024a55aef);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,3423 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ContextInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsInTransaction(this: *mut core::ffi::c_void, pbisintx: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ContextInfo", "IsInTransaction", 7usize, |this| ((**(this as *const *const ContextInfo_Vtbl)).IsInTransaction)(this, pbisintx), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransaction(this: *mut core::ffi::c_void, pptx: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ContextInfo", "GetTransaction", 8usize, |this| ((**(this as *const *const ContextInfo_Vtbl)).GetTransaction)(this, pptx), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransactionId(this: *mut core::ffi::c_void, pbstrtxid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ContextInfo", "GetTransactionId", 9usize, |this| ((**(this as *const *const ContextInfo_Vtbl)).GetTransactionId)(this, pbstrtxid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActivityId(this: *mut core::ffi::c_void, pbstractivityid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ContextInfo", "GetActivityId", 10usize, |this| ((**(this as *const *const ContextInfo_Vtbl)).GetActivityId)(this, pbstractivityid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContextId(this: *mut core::ffi::c_void, pbstrctxid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ContextInfo", "GetContextId", 11usize, |this| ((**(this as *const *const ContextInfo_Vtbl)).GetContextId)(this, pbstrctxid), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsInTransaction,
+            GetTransaction,
+            GetTransactionId,
+            GetActivityId,
+            GetContextId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ContextInfo as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ContextInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPartitionId(this: *mut core::ffi::c_void, __midl__contextinfo20000: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ContextInfo2", "GetPartitionId", 12usize, |this| ((**(this as *const *const ContextInfo2_Vtbl)).GetPartitionId)(this, __midl__contextinfo20000), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetApplicationId(this: *mut core::ffi::c_void, __midl__contextinfo20001: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ContextInfo2", "GetApplicationId", 13usize, |this| ((**(this as *const *const ContextInfo2_Vtbl)).GetApplicationId)(this, __midl__contextinfo20001), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetApplicationInstanceId(this: *mut core::ffi::c_void, __midl__contextinfo20002: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ContextInfo2", "GetApplicationInstanceId", 14usize, |this| ((**(this as *const *const ContextInfo2_Vtbl)).GetApplicationInstanceId)(this, __midl__contextinfo20002), |result| Some(*result))
+        }
+        Self { base__: <ContextInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPartitionId, GetApplicationId, GetApplicationInstanceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ContextInfo2 as windows_core::Interface>::IID || <ContextInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAppDomainHelper_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, punkad: *mut core::ffi::c_void, __midl__iappdomainhelper0000: isize, ppool: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDomainHelper", "Initialize", 7usize, |this| ((**(this as *const *const IAppDomainHelper_Vtbl)).Initialize)(this, punkad, __midl__iappdomainhelper0000, ppool), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoCallback(this: *mut core::ffi::c_void, punkad: *mut core::ffi::c_void, __midl__iappdomainhelper0001: isize, ppool: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppDomainHelper", "DoCallback", 8usize, |this| ((**(this as *const *const IAppDomainHelper_Vtbl)).DoCallback)(this, punkad, __midl__iappdomainhelper0001, ppool), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, DoCallback }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppDomainHelper as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAssemblyLocator_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetModules(this: *mut core::ffi::c_void, applicationdir: core::mem::MaybeUninit<windows_core::BSTR>, applicationname: core::mem::MaybeUninit<windows_core::BSTR>, assemblyname: core::mem::MaybeUninit<windows_core::BSTR>, pmodules: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAssemblyLocator", "GetModules", 7usize, |this| ((**(this as *const *const IAssemblyLocator_Vtbl)).GetModules)(this, applicationdir, applicationname, assemblyname, pmodules), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetModules,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetModules: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAssemblyLocator as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAsyncErrorNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnError(this: *mut core::ffi::c_void, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsyncErrorNotify", "OnError", 3usize, |this| ((**(this as *const *const IAsyncErrorNotify_Vtbl)).OnError)(this, hr), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAsyncErrorNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICOMAdminCatalog_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetCollection(this: *mut core::ffi::c_void, bstrcollname: core::mem::MaybeUninit<windows_core::BSTR>, ppcatalogcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog", "GetCollection", 7usize, |this| ((**(this as *const *const ICOMAdminCatalog_Vtbl)).GetCollection)(this, bstrcollname, ppcatalogcollection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Connect(this: *mut core::ffi::c_void, bstrcatalogservername: core::mem::MaybeUninit<windows_core::BSTR>, ppcatalogcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog", "Connect", 8usize, |this| ((**(this as *const *const ICOMAdminCatalog_Vtbl)).Connect)(this, bstrcatalogservername, ppcatalogcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn MajorVersion(this: *mut core::ffi::c_void, plmajorversion: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog", "MajorVersion", 9usize, |this| ((**(this as *const *const ICOMAdminCatalog_Vtbl)).MajorVersion)(this, plmajorversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinorVersion(this: *mut core::ffi::c_void, plminorversion: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog", "MinorVersion", 10usize, |this| ((**(this as *const *const ICOMAdminCatalog_Vtbl)).MinorVersion)(this, plminorversion), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetCollectionByQuery(this: *mut core::ffi::c_void, bstrcollname: core::mem::MaybeUninit<windows_core::BSTR>, ppsavarquery: *const *const super::Com::SAFEARRAY, ppcatalogcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog", "GetCollectionByQuery", 11usize, |this| ((**(this as *const *const ICOMAdminCatalog_Vtbl)).GetCollectionByQuery)(this, bstrcollname, ppsavarquery, ppcatalogcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImportComponent(this: *mut core::ffi::c_void, bstrapplidorname: core::mem::MaybeUninit<windows_core::BSTR>, bstrclsidorprogid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog", "ImportComponent", 12usize, |this| ((**(this as *const *const ICOMAdminCatalog_Vtbl)).ImportComponent)(this, bstrapplidorname, bstrclsidorprogid), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstallComponent(this: *mut core::ffi::c_void, bstrapplidorname: core::mem::MaybeUninit<windows_core::BSTR>, bstrdll: core::mem::MaybeUninit<windows_core::BSTR>, bstrtlb: core::mem::MaybeUninit<windows_core::BSTR>, bstrpsdll: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog", "InstallComponent", 13usize, |this| ((**(this as *const *const ICOMAdminCatalog_Vtbl)).InstallComponent)(this, bstrapplidorname, bstrdll, bstrtlb, bstrpsdll), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShutdownApplication(this: *mut core::ffi::c_void, bstrapplidorname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog", "ShutdownApplication", 14usize, |this| ((**(this as *const *const ICOMAdminCatalog_Vtbl)).ShutdownApplication)(this, bstrapplidorname), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExportApplication(this: *mut core::ffi::c_void, bstrapplidorname: core::mem::MaybeUninit<windows_core::BSTR>, bstrapplicationfile: core::mem::MaybeUninit<windows_core::BSTR>, loptions: COMAdminApplicationExportOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog", "ExportApplication", 15usize, |this| ((**(this as *const *const ICOMAdminCatalog_Vtbl)).ExportApplication)(this, bstrapplidorname, bstrapplicationfile, loptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstallApplication(this: *mut core::ffi::c_void, bstrapplicationfile: core::mem::MaybeUninit<windows_core::BSTR>, bstrdestinationdirectory: core::mem::MaybeUninit<windows_core::BSTR>, loptions: COMAdminApplicationInstallOptions, bstruserid: core::mem::MaybeUninit<windows_core::BSTR>, bstrpassword: core::mem::MaybeUninit<windows_core::BSTR>, bstrrsn: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog", "InstallApplication", 16usize, |this| ((**(this as *const *const ICOMAdminCatalog_Vtbl)).InstallApplication)(this, bstrapplicationfile, bstrdestinationdirectory, loptions, bstruserid, bstrpassword, bstrrsn), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopRouter(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog", "StopRouter", 17usize, |this| ((**(this as *const *const ICOMAdminCatalog_Vtbl)).StopRouter)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RefreshRouter(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog", "RefreshRouter", 18usize, |this| ((**(this as *const *const ICOMAdminCatalog_Vtbl)).RefreshRouter)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartRouter(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog", "StartRouter", 19usize, |this| ((**(this as *const *const ICOMAdminCatalog_Vtbl)).StartRouter)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reserved1(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog", "Reserved1", 20usize, |this| ((**(this as *const *const ICOMAdminCatalog_Vtbl)).Reserved1)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reserved2(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog", "Reserved2", 21usize, |this| ((**(this as *const *const ICOMAdminCatalog_Vtbl)).Reserved2)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InstallMultipleComponents(this: *mut core::ffi::c_void, bstrapplidorname: core::mem::MaybeUninit<windows_core::BSTR>, ppsavarfilenames: *const *const super::Com::SAFEARRAY, ppsavarclsids: *const *const super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog", "InstallMultipleComponents", 22usize, |this| ((**(this as *const *const ICOMAdminCatalog_Vtbl)).InstallMultipleComponents)(this, bstrapplidorname, ppsavarfilenames, ppsavarclsids), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetMultipleComponentsInfo(this: *mut core::ffi::c_void, bstrapplidorname: core::mem::MaybeUninit<windows_core::BSTR>, ppsavarfilenames: *const *const super::Com::SAFEARRAY, ppsavarclsids: *mut *mut super::Com::SAFEARRAY, ppsavarclassnames: *mut *mut super::Com::SAFEARRAY, ppsavarfileflags: *mut *mut super::Com::SAFEARRAY, ppsavarcomponentflags: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog", "GetMultipleComponentsInfo", 23usize, |this| ((**(this as *const *const ICOMAdminCatalog_Vtbl)).GetMultipleComponentsInfo)(this, bstrapplidorname, ppsavarfilenames, ppsavarclsids, ppsavarclassnames, ppsavarfileflags, ppsavarcomponentflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn RefreshComponents(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog", "RefreshComponents", 24usize, |this| ((**(this as *const *const ICOMAdminCatalog_Vtbl)).RefreshComponents)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackupREGDB(this: *mut core::ffi::c_void, bstrbackupfilepath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog", "BackupREGDB", 25usize, |this| ((**(this as *const *const ICOMAdminCatalog_Vtbl)).BackupREGDB)(this, bstrbackupfilepath), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestoreREGDB(this: *mut core::ffi::c_void, bstrbackupfilepath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog", "RestoreREGDB", 26usize, |this| ((**(this as *const *const ICOMAdminCatalog_Vtbl)).RestoreREGDB)(this, bstrbackupfilepath), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn QueryApplicationFile(this: *mut core::ffi::c_void, bstrapplicationfile: core::mem::MaybeUninit<windows_core::BSTR>, pbstrapplicationname: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrapplicationdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbhasusers: *mut super::super::Foundation::VARIANT_BOOL, pbisproxy: *mut super::super::Foundation::VARIANT_BOOL, ppsavarfilenames: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog", "QueryApplicationFile", 27usize, |this| ((**(this as *const *const ICOMAdminCatalog_Vtbl)).QueryApplicationFile)(this, bstrapplicationfile, pbstrapplicationname, pbstrapplicationdescription, pbhasusers, pbisproxy, ppsavarfilenames), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartApplication(this: *mut core::ffi::c_void, bstrapplidorname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog", "StartApplication", 28usize, |this| ((**(this as *const *const ICOMAdminCatalog_Vtbl)).StartApplication)(this, bstrapplidorname), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceCheck(this: *mut core::ffi::c_void, lservice: i32, plstatus: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog", "ServiceCheck", 29usize, |this| ((**(this as *const *const ICOMAdminCatalog_Vtbl)).ServiceCheck)(this, lservice, plstatus), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InstallMultipleEventClasses(this: *mut core::ffi::c_void, bstrapplidorname: core::mem::MaybeUninit<windows_core::BSTR>, ppsavarfilenames: *const *const super::Com::SAFEARRAY, ppsavarclsids: *const *const super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog", "InstallMultipleEventClasses", 30usize, |this| ((**(this as *const *const ICOMAdminCatalog_Vtbl)).InstallMultipleEventClasses)(this, bstrapplidorname, ppsavarfilenames, ppsavarclsids), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstallEventClass(this: *mut core::ffi::c_void, bstrapplidorname: core::mem::MaybeUninit<windows_core::BSTR>, bstrdll: core::mem::MaybeUninit<windows_core::BSTR>, bstrtlb: core::mem::MaybeUninit<windows_core::BSTR>, bstrpsdll: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog", "InstallEventClass", 31usize, |this| ((**(this as *const *const ICOMAdminCatalog_Vtbl)).InstallEventClass)(this, bstrapplidorname, bstrdll, bstrtlb, bstrpsdll), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetEventClassesForIID(this: *mut core::ffi::c_void, bstriid: core::mem::MaybeUninit<windows_core::BSTR>, ppsavarclsids: *mut *mut super::Com::SAFEARRAY, ppsavarprogids: *mut *mut super::Com::SAFEARRAY, ppsavardescriptions: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog", "GetEventClassesForIID", 32usize, |this| ((**(this as *const *const ICOMAdminCatalog_Vtbl)).GetEventClassesForIID)(this, bstriid, ppsavarclsids, ppsavarprogids, ppsavardescriptions), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetCollection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetCollection: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Connect,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Connect: 0,
+            MajorVersion,
+            MinorVersion,
+            #[cfg(feature = "Win32_System_Com")]
+            GetCollectionByQuery,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetCollectionByQuery: 0,
+            ImportComponent,
+            InstallComponent,
+            ShutdownApplication,
+            ExportApplication,
+            InstallApplication,
+            StopRouter,
+            RefreshRouter,
+            StartRouter,
+            Reserved1,
+            Reserved2,
+            #[cfg(feature = "Win32_System_Com")]
+            InstallMultipleComponents,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InstallMultipleComponents: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetMultipleComponentsInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetMultipleComponentsInfo: 0,
+            RefreshComponents,
+            BackupREGDB,
+            RestoreREGDB,
+            #[cfg(feature = "Win32_System_Com")]
+            QueryApplicationFile,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            QueryApplicationFile: 0,
+            StartApplication,
+            ServiceCheck,
+            #[cfg(feature = "Win32_System_Com")]
+            InstallMultipleEventClasses,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InstallMultipleEventClasses: 0,
+            InstallEventClass,
+            #[cfg(feature = "Win32_System_Com")]
+            GetEventClassesForIID,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetEventClassesForIID: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICOMAdminCatalog as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICOMAdminCatalog2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetCollectionByQuery2(this: *mut core::ffi::c_void, bstrcollectionname: core::mem::MaybeUninit<windows_core::BSTR>, pvarquerystrings: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppcatalogcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "GetCollectionByQuery2", 33usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).GetCollectionByQuery2)(this, bstrcollectionname, pvarquerystrings, ppcatalogcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetApplicationInstanceIDFromProcessID(this: *mut core::ffi::c_void, lprocessid: i32, pbstrapplicationinstanceid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "GetApplicationInstanceIDFromProcessID", 34usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).GetApplicationInstanceIDFromProcessID)(this, lprocessid, pbstrapplicationinstanceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShutdownApplicationInstances(this: *mut core::ffi::c_void, pvarapplicationinstanceid: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "ShutdownApplicationInstances", 35usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).ShutdownApplicationInstances)(this, pvarapplicationinstanceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn PauseApplicationInstances(this: *mut core::ffi::c_void, pvarapplicationinstanceid: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "PauseApplicationInstances", 36usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).PauseApplicationInstances)(this, pvarapplicationinstanceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResumeApplicationInstances(this: *mut core::ffi::c_void, pvarapplicationinstanceid: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "ResumeApplicationInstances", 37usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).ResumeApplicationInstances)(this, pvarapplicationinstanceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecycleApplicationInstances(this: *mut core::ffi::c_void, pvarapplicationinstanceid: *const core::mem::MaybeUninit<windows_core::VARIANT>, lreasoncode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "RecycleApplicationInstances", 38usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).RecycleApplicationInstances)(this, pvarapplicationinstanceid, lreasoncode), |result| Some(*result))
+        }
+        unsafe extern "system" fn AreApplicationInstancesPaused(this: *mut core::ffi::c_void, pvarapplicationinstanceid: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvarboolpaused: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "AreApplicationInstancesPaused", 39usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).AreApplicationInstancesPaused)(this, pvarapplicationinstanceid, pvarboolpaused), |result| Some(*result))
+        }
+        unsafe extern "system" fn DumpApplicationInstance(this: *mut core::ffi::c_void, bstrapplicationinstanceid: core::mem::MaybeUninit<windows_core::BSTR>, bstrdirectory: core::mem::MaybeUninit<windows_core::BSTR>, lmaximages: i32, pbstrdumpfile: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "DumpApplicationInstance", 40usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).DumpApplicationInstance)(this, bstrapplicationinstanceid, bstrdirectory, lmaximages, pbstrdumpfile), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsApplicationInstanceDumpSupported(this: *mut core::ffi::c_void, pvarbooldumpsupported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "IsApplicationInstanceDumpSupported", 41usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).IsApplicationInstanceDumpSupported)(this, pvarbooldumpsupported), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateServiceForApplication(this: *mut core::ffi::c_void, bstrapplicationidorname: core::mem::MaybeUninit<windows_core::BSTR>, bstrservicename: core::mem::MaybeUninit<windows_core::BSTR>, bstrstarttype: core::mem::MaybeUninit<windows_core::BSTR>, bstrerrorcontrol: core::mem::MaybeUninit<windows_core::BSTR>, bstrdependencies: core::mem::MaybeUninit<windows_core::BSTR>, bstrrunas: core::mem::MaybeUninit<windows_core::BSTR>, bstrpassword: core::mem::MaybeUninit<windows_core::BSTR>, bdesktopok: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "CreateServiceForApplication", 42usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).CreateServiceForApplication)(this, bstrapplicationidorname, bstrservicename, bstrstarttype, bstrerrorcontrol, bstrdependencies, bstrrunas, bstrpassword, bdesktopok), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteServiceForApplication(this: *mut core::ffi::c_void, bstrapplicationidorname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "DeleteServiceForApplication", 43usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).DeleteServiceForApplication)(this, bstrapplicationidorname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPartitionID(this: *mut core::ffi::c_void, bstrapplicationidorname: core::mem::MaybeUninit<windows_core::BSTR>, pbstrpartitionid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "GetPartitionID", 44usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).GetPartitionID)(this, bstrapplicationidorname, pbstrpartitionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPartitionName(this: *mut core::ffi::c_void, bstrapplicationidorname: core::mem::MaybeUninit<windows_core::BSTR>, pbstrpartitionname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "GetPartitionName", 45usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).GetPartitionName)(this, bstrapplicationidorname, pbstrpartitionname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentPartition(this: *mut core::ffi::c_void, bstrpartitionidorname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "SetCurrentPartition", 46usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).SetCurrentPartition)(this, bstrpartitionidorname), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentPartitionID(this: *mut core::ffi::c_void, pbstrpartitionid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "CurrentPartitionID", 47usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).CurrentPartitionID)(this, pbstrpartitionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentPartitionName(this: *mut core::ffi::c_void, pbstrpartitionname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "CurrentPartitionName", 48usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).CurrentPartitionName)(this, pbstrpartitionname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GlobalPartitionID(this: *mut core::ffi::c_void, pbstrglobalpartitionid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "GlobalPartitionID", 49usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).GlobalPartitionID)(this, pbstrglobalpartitionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlushPartitionCache(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "FlushPartitionCache", 50usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).FlushPartitionCache)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyApplications(this: *mut core::ffi::c_void, bstrsourcepartitionidorname: core::mem::MaybeUninit<windows_core::BSTR>, pvarapplicationid: *const core::mem::MaybeUninit<windows_core::VARIANT>, bstrdestinationpartitionidorname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "CopyApplications", 51usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).CopyApplications)(this, bstrsourcepartitionidorname, pvarapplicationid, bstrdestinationpartitionidorname), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyComponents(this: *mut core::ffi::c_void, bstrsourceapplicationidorname: core::mem::MaybeUninit<windows_core::BSTR>, pvarclsidorprogid: *const core::mem::MaybeUninit<windows_core::VARIANT>, bstrdestinationapplicationidorname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "CopyComponents", 52usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).CopyComponents)(this, bstrsourceapplicationidorname, pvarclsidorprogid, bstrdestinationapplicationidorname), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveComponents(this: *mut core::ffi::c_void, bstrsourceapplicationidorname: core::mem::MaybeUninit<windows_core::BSTR>, pvarclsidorprogid: *const core::mem::MaybeUninit<windows_core::VARIANT>, bstrdestinationapplicationidorname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "MoveComponents", 53usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).MoveComponents)(this, bstrsourceapplicationidorname, pvarclsidorprogid, bstrdestinationapplicationidorname), |result| Some(*result))
+        }
+        unsafe extern "system" fn AliasComponent(this: *mut core::ffi::c_void, bstrsrcapplicationidorname: core::mem::MaybeUninit<windows_core::BSTR>, bstrclsidorprogid: core::mem::MaybeUninit<windows_core::BSTR>, bstrdestapplicationidorname: core::mem::MaybeUninit<windows_core::BSTR>, bstrnewprogid: core::mem::MaybeUninit<windows_core::BSTR>, bstrnewclsid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "AliasComponent", 54usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).AliasComponent)(this, bstrsrcapplicationidorname, bstrclsidorprogid, bstrdestapplicationidorname, bstrnewprogid, bstrnewclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSafeToDelete(this: *mut core::ffi::c_void, bstrdllname: core::mem::MaybeUninit<windows_core::BSTR>, pcomadmininuse: *mut COMAdminInUse) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "IsSafeToDelete", 55usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).IsSafeToDelete)(this, bstrdllname, pcomadmininuse), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImportUnconfiguredComponents(this: *mut core::ffi::c_void, bstrapplicationidorname: core::mem::MaybeUninit<windows_core::BSTR>, pvarclsidorprogid: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvarcomponenttype: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "ImportUnconfiguredComponents", 56usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).ImportUnconfiguredComponents)(this, bstrapplicationidorname, pvarclsidorprogid, pvarcomponenttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn PromoteUnconfiguredComponents(this: *mut core::ffi::c_void, bstrapplicationidorname: core::mem::MaybeUninit<windows_core::BSTR>, pvarclsidorprogid: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvarcomponenttype: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "PromoteUnconfiguredComponents", 57usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).PromoteUnconfiguredComponents)(this, bstrapplicationidorname, pvarclsidorprogid, pvarcomponenttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImportComponents(this: *mut core::ffi::c_void, bstrapplicationidorname: core::mem::MaybeUninit<windows_core::BSTR>, pvarclsidorprogid: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvarcomponenttype: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "ImportComponents", 58usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).ImportComponents)(this, bstrapplicationidorname, pvarclsidorprogid, pvarcomponenttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Is64BitCatalogServer(this: *mut core::ffi::c_void, pbis64bit: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "Is64BitCatalogServer", 59usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).Is64BitCatalogServer)(this, pbis64bit), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExportPartition(this: *mut core::ffi::c_void, bstrpartitionidorname: core::mem::MaybeUninit<windows_core::BSTR>, bstrpartitionfilename: core::mem::MaybeUninit<windows_core::BSTR>, loptions: COMAdminApplicationExportOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "ExportPartition", 60usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).ExportPartition)(this, bstrpartitionidorname, bstrpartitionfilename, loptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstallPartition(this: *mut core::ffi::c_void, bstrfilename: core::mem::MaybeUninit<windows_core::BSTR>, bstrdestdirectory: core::mem::MaybeUninit<windows_core::BSTR>, loptions: COMAdminApplicationInstallOptions, bstruserid: core::mem::MaybeUninit<windows_core::BSTR>, bstrpassword: core::mem::MaybeUninit<windows_core::BSTR>, bstrrsn: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "InstallPartition", 61usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).InstallPartition)(this, bstrfilename, bstrdestdirectory, loptions, bstruserid, bstrpassword, bstrrsn), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn QueryApplicationFile2(this: *mut core::ffi::c_void, bstrapplicationfile: core::mem::MaybeUninit<windows_core::BSTR>, ppfilesforimport: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "QueryApplicationFile2", 62usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).QueryApplicationFile2)(this, bstrapplicationfile, ppfilesforimport), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetComponentVersionCount(this: *mut core::ffi::c_void, bstrclsidorprogid: core::mem::MaybeUninit<windows_core::BSTR>, plversioncount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMAdminCatalog2", "GetComponentVersionCount", 63usize, |this| ((**(this as *const *const ICOMAdminCatalog2_Vtbl)).GetComponentVersionCount)(this, bstrclsidorprogid, plversioncount), |result| Some(*result))
+        }
+        Self {
+            base__: <ICOMAdminCatalog_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetCollectionByQuery2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetCollectionByQuery2: 0,
+            GetApplicationInstanceIDFromProcessID,
+            ShutdownApplicationInstances,
+            PauseApplicationInstances,
+            ResumeApplicationInstances,
+            RecycleApplicationInstances,
+            AreApplicationInstancesPaused,
+            DumpApplicationInstance,
+            IsApplicationInstanceDumpSupported,
+            CreateServiceForApplication,
+            DeleteServiceForApplication,
+            GetPartitionID,
+            GetPartitionName,
+            SetCurrentPartition,
+            CurrentPartitionID,
+            CurrentPartitionName,
+            GlobalPartitionID,
+            FlushPartitionCache,
+            CopyApplications,
+            CopyComponents,
+            MoveComponents,
+            AliasComponent,
+            IsSafeToDelete,
+            ImportUnconfiguredComponents,
+            PromoteUnconfiguredComponents,
+            ImportComponents,
+            Is64BitCatalogServer,
+            ExportPartition,
+            InstallPartition,
+            #[cfg(feature = "Win32_System_Com")]
+            QueryApplicationFile2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            QueryApplicationFile2: 0,
+            GetComponentVersionCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICOMAdminCatalog2 as windows_core::Interface>::IID || <ICOMAdminCatalog_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICOMLBArguments_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCLSID(this: *mut core::ffi::c_void, pclsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMLBArguments", "GetCLSID", 3usize, |this| ((**(this as *const *const ICOMLBArguments_Vtbl)).GetCLSID)(this, pclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCLSID(this: *mut core::ffi::c_void, pclsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMLBArguments", "SetCLSID", 4usize, |this| ((**(this as *const *const ICOMLBArguments_Vtbl)).SetCLSID)(this, pclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMachineName(this: *mut core::ffi::c_void, cchsvr: u32, szservername: windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMLBArguments", "GetMachineName", 5usize, |this| ((**(this as *const *const ICOMLBArguments_Vtbl)).GetMachineName)(this, cchsvr, szservername), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMachineName(this: *mut core::ffi::c_void, cchsvr: u32, szservername: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICOMLBArguments", "SetMachineName", 6usize, |this| ((**(this as *const *const ICOMLBArguments_Vtbl)).SetMachineName)(this, cchsvr, szservername), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCLSID, SetCLSID, GetMachineName, SetMachineName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICOMLBArguments as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICatalogCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppenumvariant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalogCollection", "_NewEnum", 7usize, |this| ((**(this as *const *const ICatalogCollection_Vtbl))._NewEnum)(this, ppenumvariant), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, lindex: i32, ppcatalogobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalogCollection", "get_Item", 8usize, |this| ((**(this as *const *const ICatalogCollection_Vtbl)).get_Item)(this, lindex, ppcatalogobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plobjectcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalogCollection", "Count", 9usize, |this| ((**(this as *const *const ICatalogCollection_Vtbl)).Count)(this, plobjectcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, lindex: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalogCollection", "Remove", 10usize, |this| ((**(this as *const *const ICatalogCollection_Vtbl)).Remove)(this, lindex), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, ppcatalogobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalogCollection", "Add", 11usize, |this| ((**(this as *const *const ICatalogCollection_Vtbl)).Add)(this, ppcatalogobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn Populate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalogCollection", "Populate", 12usize, |this| ((**(this as *const *const ICatalogCollection_Vtbl)).Populate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveChanges(this: *mut core::ffi::c_void, pcchanges: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalogCollection", "SaveChanges", 13usize, |this| ((**(this as *const *const ICatalogCollection_Vtbl)).SaveChanges)(this, pcchanges), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetCollection(this: *mut core::ffi::c_void, bstrcollname: core::mem::MaybeUninit<windows_core::BSTR>, varobjectkey: core::mem::MaybeUninit<windows_core::VARIANT>, ppcatalogcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalogCollection", "GetCollection", 14usize, |this| ((**(this as *const *const ICatalogCollection_Vtbl)).GetCollection)(this, bstrcollname, varobjectkey, ppcatalogcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pvarnamel: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalogCollection", "Name", 15usize, |this| ((**(this as *const *const ICatalogCollection_Vtbl)).Name)(this, pvarnamel), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddEnabled(this: *mut core::ffi::c_void, pvarbool: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalogCollection", "AddEnabled", 16usize, |this| ((**(this as *const *const ICatalogCollection_Vtbl)).AddEnabled)(this, pvarbool), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEnabled(this: *mut core::ffi::c_void, pvarbool: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalogCollection", "RemoveEnabled", 17usize, |this| ((**(this as *const *const ICatalogCollection_Vtbl)).RemoveEnabled)(this, pvarbool), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetUtilInterface(this: *mut core::ffi::c_void, ppidispatch: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalogCollection", "GetUtilInterface", 18usize, |this| ((**(this as *const *const ICatalogCollection_Vtbl)).GetUtilInterface)(this, ppidispatch), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataStoreMajorVersion(this: *mut core::ffi::c_void, plmajorversion: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalogCollection", "DataStoreMajorVersion", 19usize, |this| ((**(this as *const *const ICatalogCollection_Vtbl)).DataStoreMajorVersion)(this, plmajorversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataStoreMinorVersion(this: *mut core::ffi::c_void, plminorversionl: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalogCollection", "DataStoreMinorVersion", 20usize, |this| ((**(this as *const *const ICatalogCollection_Vtbl)).DataStoreMinorVersion)(this, plminorversionl), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PopulateByKey(this: *mut core::ffi::c_void, psakeys: *const super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalogCollection", "PopulateByKey", 21usize, |this| ((**(this as *const *const ICatalogCollection_Vtbl)).PopulateByKey)(this, psakeys), |result| Some(*result))
+        }
+        unsafe extern "system" fn PopulateByQuery(this: *mut core::ffi::c_void, bstrquerystring: core::mem::MaybeUninit<windows_core::BSTR>, lquerytype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalogCollection", "PopulateByQuery", 22usize, |this| ((**(this as *const *const ICatalogCollection_Vtbl)).PopulateByQuery)(this, bstrquerystring, lquerytype), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            Count,
+            Remove,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Populate,
+            SaveChanges,
+            #[cfg(feature = "Win32_System_Com")]
+            GetCollection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetCollection: 0,
+            Name,
+            AddEnabled,
+            RemoveEnabled,
+            #[cfg(feature = "Win32_System_Com")]
+            GetUtilInterface,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetUtilInterface: 0,
+            DataStoreMajorVersion,
+            DataStoreMinorVersion,
+            #[cfg(feature = "Win32_System_Com")]
+            PopulateByKey,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PopulateByKey: 0,
+            PopulateByQuery,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICatalogCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICatalogObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_Value(this: *mut core::ffi::c_void, bstrpropname: core::mem::MaybeUninit<windows_core::BSTR>, pvarretval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalogObject", "get_Value", 7usize, |this| ((**(this as *const *const ICatalogObject_Vtbl)).get_Value)(this, bstrpropname, pvarretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_Value(this: *mut core::ffi::c_void, bstrpropname: core::mem::MaybeUninit<windows_core::BSTR>, val: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalogObject", "put_Value", 8usize, |this| ((**(this as *const *const ICatalogObject_Vtbl)).put_Value)(this, bstrpropname, val), |result| Some(*result))
+        }
+        unsafe extern "system" fn Key(this: *mut core::ffi::c_void, pvarretval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalogObject", "Key", 9usize, |this| ((**(this as *const *const ICatalogObject_Vtbl)).Key)(this, pvarretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pvarretval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalogObject", "Name", 10usize, |this| ((**(this as *const *const ICatalogObject_Vtbl)).Name)(this, pvarretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPropertyReadOnly(this: *mut core::ffi::c_void, bstrpropname: core::mem::MaybeUninit<windows_core::BSTR>, pbretval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalogObject", "IsPropertyReadOnly", 11usize, |this| ((**(this as *const *const ICatalogObject_Vtbl)).IsPropertyReadOnly)(this, bstrpropname, pbretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Valid(this: *mut core::ffi::c_void, pbretval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalogObject", "Valid", 12usize, |this| ((**(this as *const *const ICatalogObject_Vtbl)).Valid)(this, pbretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPropertyWriteOnly(this: *mut core::ffi::c_void, bstrpropname: core::mem::MaybeUninit<windows_core::BSTR>, pbretval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalogObject", "IsPropertyWriteOnly", 13usize, |this| ((**(this as *const *const ICatalogObject_Vtbl)).IsPropertyWriteOnly)(this, bstrpropname, pbretval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            get_Value,
+            put_Value,
+            Key,
+            Name,
+            IsPropertyReadOnly,
+            Valid,
+            IsPropertyWriteOnly,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICatalogObject as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICheckSxsConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSameSxsConfig(this: *mut core::ffi::c_void, wszsxsname: windows_core::PCWSTR, wszsxsdirectory: windows_core::PCWSTR, wszsxsappname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICheckSxsConfig", "IsSameSxsConfig", 3usize, |this| ((**(this as *const *const ICheckSxsConfig_Vtbl)).IsSameSxsConfig)(this, wszsxsname, wszsxsdirectory, wszsxsappname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsSameSxsConfig }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICheckSxsConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComActivityEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnActivityCreate(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidactivity: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComActivityEvents", "OnActivityCreate", 3usize, |this| ((**(this as *const *const IComActivityEvents_Vtbl)).OnActivityCreate)(this, pinfo, guidactivity), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnActivityDestroy(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidactivity: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComActivityEvents", "OnActivityDestroy", 4usize, |this| ((**(this as *const *const IComActivityEvents_Vtbl)).OnActivityDestroy)(this, pinfo, guidactivity), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnActivityEnter(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidcurrent: *const windows_core::GUID, guidentered: *const windows_core::GUID, dwthread: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComActivityEvents", "OnActivityEnter", 5usize, |this| ((**(this as *const *const IComActivityEvents_Vtbl)).OnActivityEnter)(this, pinfo, guidcurrent, guidentered, dwthread), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnActivityTimeout(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidcurrent: *const windows_core::GUID, guidentered: *const windows_core::GUID, dwthread: u32, dwtimeout: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComActivityEvents", "OnActivityTimeout", 6usize, |this| ((**(this as *const *const IComActivityEvents_Vtbl)).OnActivityTimeout)(this, pinfo, guidcurrent, guidentered, dwthread, dwtimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnActivityReenter(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidcurrent: *const windows_core::GUID, dwthread: u32, dwcalldepth: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComActivityEvents", "OnActivityReenter", 7usize, |this| ((**(this as *const *const IComActivityEvents_Vtbl)).OnActivityReenter)(this, pinfo, guidcurrent, dwthread, dwcalldepth), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnActivityLeave(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidcurrent: *const windows_core::GUID, guidleft: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComActivityEvents", "OnActivityLeave", 8usize, |this| ((**(this as *const *const IComActivityEvents_Vtbl)).OnActivityLeave)(this, pinfo, guidcurrent, guidleft), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnActivityLeaveSame(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidcurrent: *const windows_core::GUID, dwcalldepth: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComActivityEvents", "OnActivityLeaveSame", 9usize, |this| ((**(this as *const *const IComActivityEvents_Vtbl)).OnActivityLeaveSame)(this, pinfo, guidcurrent, dwcalldepth), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnActivityCreate,
+            OnActivityDestroy,
+            OnActivityEnter,
+            OnActivityTimeout,
+            OnActivityReenter,
+            OnActivityLeave,
+            OnActivityLeaveSame,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComActivityEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComApp2Events_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnAppActivation2(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidapp: windows_core::GUID, guidprocess: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComApp2Events", "OnAppActivation2", 3usize, |this| ((**(this as *const *const IComApp2Events_Vtbl)).OnAppActivation2)(this, pinfo, guidapp, guidprocess), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnAppShutdown2(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidapp: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComApp2Events", "OnAppShutdown2", 4usize, |this| ((**(this as *const *const IComApp2Events_Vtbl)).OnAppShutdown2)(this, pinfo, guidapp), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnAppForceShutdown2(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidapp: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComApp2Events", "OnAppForceShutdown2", 5usize, |this| ((**(this as *const *const IComApp2Events_Vtbl)).OnAppForceShutdown2)(this, pinfo, guidapp), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnAppPaused2(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidapp: windows_core::GUID, bpaused: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComApp2Events", "OnAppPaused2", 6usize, |this| ((**(this as *const *const IComApp2Events_Vtbl)).OnAppPaused2)(this, pinfo, guidapp, bpaused), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnAppRecycle2(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidapp: windows_core::GUID, guidprocess: windows_core::GUID, lreason: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComApp2Events", "OnAppRecycle2", 7usize, |this| ((**(this as *const *const IComApp2Events_Vtbl)).OnAppRecycle2)(this, pinfo, guidapp, guidprocess, lreason), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnAppActivation2,
+            OnAppShutdown2,
+            OnAppForceShutdown2,
+            OnAppPaused2,
+            OnAppRecycle2,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComApp2Events as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComAppEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnAppActivation(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidapp: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComAppEvents", "OnAppActivation", 3usize, |this| ((**(this as *const *const IComAppEvents_Vtbl)).OnAppActivation)(this, pinfo, guidapp), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnAppShutdown(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidapp: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComAppEvents", "OnAppShutdown", 4usize, |this| ((**(this as *const *const IComAppEvents_Vtbl)).OnAppShutdown)(this, pinfo, guidapp), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnAppForceShutdown(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidapp: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComAppEvents", "OnAppForceShutdown", 5usize, |this| ((**(this as *const *const IComAppEvents_Vtbl)).OnAppForceShutdown)(this, pinfo, guidapp), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnAppActivation, OnAppShutdown, OnAppForceShutdown }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComAppEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComCRMEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnCRMRecoveryStart(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidapp: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComCRMEvents", "OnCRMRecoveryStart", 3usize, |this| ((**(this as *const *const IComCRMEvents_Vtbl)).OnCRMRecoveryStart)(this, pinfo, guidapp), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnCRMRecoveryDone(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidapp: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComCRMEvents", "OnCRMRecoveryDone", 4usize, |this| ((**(this as *const *const IComCRMEvents_Vtbl)).OnCRMRecoveryDone)(this, pinfo, guidapp), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnCRMCheckpoint(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidapp: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComCRMEvents", "OnCRMCheckpoint", 5usize, |this| ((**(this as *const *const IComCRMEvents_Vtbl)).OnCRMCheckpoint)(this, pinfo, guidapp), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnCRMBegin(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidclerkclsid: windows_core::GUID, guidactivity: windows_core::GUID, guidtx: windows_core::GUID, szprogidcompensator: windows_core::PCWSTR, szdescription: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComCRMEvents", "OnCRMBegin", 6usize, |this| ((**(this as *const *const IComCRMEvents_Vtbl)).OnCRMBegin)(this, pinfo, guidclerkclsid, guidactivity, guidtx, szprogidcompensator, szdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnCRMPrepare(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidclerkclsid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComCRMEvents", "OnCRMPrepare", 7usize, |this| ((**(this as *const *const IComCRMEvents_Vtbl)).OnCRMPrepare)(this, pinfo, guidclerkclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnCRMCommit(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidclerkclsid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComCRMEvents", "OnCRMCommit", 8usize, |this| ((**(this as *const *const IComCRMEvents_Vtbl)).OnCRMCommit)(this, pinfo, guidclerkclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnCRMAbort(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidclerkclsid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComCRMEvents", "OnCRMAbort", 9usize, |this| ((**(this as *const *const IComCRMEvents_Vtbl)).OnCRMAbort)(this, pinfo, guidclerkclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnCRMIndoubt(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidclerkclsid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComCRMEvents", "OnCRMIndoubt", 10usize, |this| ((**(this as *const *const IComCRMEvents_Vtbl)).OnCRMIndoubt)(this, pinfo, guidclerkclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnCRMDone(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidclerkclsid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComCRMEvents", "OnCRMDone", 11usize, |this| ((**(this as *const *const IComCRMEvents_Vtbl)).OnCRMDone)(this, pinfo, guidclerkclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnCRMRelease(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidclerkclsid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComCRMEvents", "OnCRMRelease", 12usize, |this| ((**(this as *const *const IComCRMEvents_Vtbl)).OnCRMRelease)(this, pinfo, guidclerkclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnCRMAnalyze(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidclerkclsid: windows_core::GUID, dwcrmrecordtype: u32, dwrecordsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComCRMEvents", "OnCRMAnalyze", 13usize, |this| ((**(this as *const *const IComCRMEvents_Vtbl)).OnCRMAnalyze)(this, pinfo, guidclerkclsid, dwcrmrecordtype, dwrecordsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnCRMWrite(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidclerkclsid: windows_core::GUID, fvariants: super::super::Foundation::BOOL, dwrecordsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComCRMEvents", "OnCRMWrite", 14usize, |this| ((**(this as *const *const IComCRMEvents_Vtbl)).OnCRMWrite)(this, pinfo, guidclerkclsid, fvariants, dwrecordsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnCRMForget(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidclerkclsid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComCRMEvents", "OnCRMForget", 15usize, |this| ((**(this as *const *const IComCRMEvents_Vtbl)).OnCRMForget)(this, pinfo, guidclerkclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnCRMForce(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidclerkclsid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComCRMEvents", "OnCRMForce", 16usize, |this| ((**(this as *const *const IComCRMEvents_Vtbl)).OnCRMForce)(this, pinfo, guidclerkclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnCRMDeliver(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidclerkclsid: windows_core::GUID, fvariants: super::super::Foundation::BOOL, dwrecordsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComCRMEvents", "OnCRMDeliver", 17usize, |this| ((**(this as *const *const IComCRMEvents_Vtbl)).OnCRMDeliver)(this, pinfo, guidclerkclsid, fvariants, dwrecordsize), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnCRMRecoveryStart,
+            OnCRMRecoveryDone,
+            OnCRMCheckpoint,
+            OnCRMBegin,
+            OnCRMPrepare,
+            OnCRMCommit,
+            OnCRMAbort,
+            OnCRMIndoubt,
+            OnCRMDone,
+            OnCRMRelease,
+            OnCRMAnalyze,
+            OnCRMWrite,
+            OnCRMForget,
+            OnCRMForce,
+            OnCRMDeliver,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComCRMEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComExceptionEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnExceptionUser(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, code: u32, address: u64, pszstacktrace: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComExceptionEvents", "OnExceptionUser", 3usize, |this| ((**(this as *const *const IComExceptionEvents_Vtbl)).OnExceptionUser)(this, pinfo, code, address, pszstacktrace), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnExceptionUser }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComExceptionEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComIdentityEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnIISRequestInfo(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, objid: u64, pszclientip: windows_core::PCWSTR, pszserverip: windows_core::PCWSTR, pszurl: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComIdentityEvents", "OnIISRequestInfo", 3usize, |this| ((**(this as *const *const IComIdentityEvents_Vtbl)).OnIISRequestInfo)(this, pinfo, objid, pszclientip, pszserverip, pszurl), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnIISRequestInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComIdentityEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComInstance2Events_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnObjectCreate2(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidactivity: *const windows_core::GUID, clsid: *const windows_core::GUID, tsid: *const windows_core::GUID, ctxtid: u64, objectid: u64, guidpartition: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComInstance2Events", "OnObjectCreate2", 3usize, |this| ((**(this as *const *const IComInstance2Events_Vtbl)).OnObjectCreate2)(this, pinfo, guidactivity, clsid, tsid, ctxtid, objectid, guidpartition), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnObjectDestroy2(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, ctxtid: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComInstance2Events", "OnObjectDestroy2", 4usize, |this| ((**(this as *const *const IComInstance2Events_Vtbl)).OnObjectDestroy2)(this, pinfo, ctxtid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnObjectCreate2, OnObjectDestroy2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComInstance2Events as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComInstanceEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnObjectCreate(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidactivity: *const windows_core::GUID, clsid: *const windows_core::GUID, tsid: *const windows_core::GUID, ctxtid: u64, objectid: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComInstanceEvents", "OnObjectCreate", 3usize, |this| ((**(this as *const *const IComInstanceEvents_Vtbl)).OnObjectCreate)(this, pinfo, guidactivity, clsid, tsid, ctxtid, objectid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnObjectDestroy(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, ctxtid: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComInstanceEvents", "OnObjectDestroy", 4usize, |this| ((**(this as *const *const IComInstanceEvents_Vtbl)).OnObjectDestroy)(this, pinfo, ctxtid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnObjectCreate, OnObjectDestroy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComInstanceEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComLTxEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnLtxTransactionStart(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidltx: windows_core::GUID, tsid: windows_core::GUID, froot: super::super::Foundation::BOOL, nisolationlevel: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComLTxEvents", "OnLtxTransactionStart", 3usize, |this| ((**(this as *const *const IComLTxEvents_Vtbl)).OnLtxTransactionStart)(this, pinfo, guidltx, tsid, froot, nisolationlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnLtxTransactionPrepare(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidltx: windows_core::GUID, fvote: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComLTxEvents", "OnLtxTransactionPrepare", 4usize, |this| ((**(this as *const *const IComLTxEvents_Vtbl)).OnLtxTransactionPrepare)(this, pinfo, guidltx, fvote), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnLtxTransactionAbort(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidltx: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComLTxEvents", "OnLtxTransactionAbort", 5usize, |this| ((**(this as *const *const IComLTxEvents_Vtbl)).OnLtxTransactionAbort)(this, pinfo, guidltx), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnLtxTransactionCommit(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidltx: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComLTxEvents", "OnLtxTransactionCommit", 6usize, |this| ((**(this as *const *const IComLTxEvents_Vtbl)).OnLtxTransactionCommit)(this, pinfo, guidltx), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnLtxTransactionPromote(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidltx: windows_core::GUID, txnid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComLTxEvents", "OnLtxTransactionPromote", 7usize, |this| ((**(this as *const *const IComLTxEvents_Vtbl)).OnLtxTransactionPromote)(this, pinfo, guidltx, txnid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnLtxTransactionStart,
+            OnLtxTransactionPrepare,
+            OnLtxTransactionAbort,
+            OnLtxTransactionCommit,
+            OnLtxTransactionPromote,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComLTxEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComMethod2Events_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnMethodCall2(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, oid: u64, guidcid: *const windows_core::GUID, guidrid: *const windows_core::GUID, dwthread: u32, imeth: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComMethod2Events", "OnMethodCall2", 3usize, |this| ((**(this as *const *const IComMethod2Events_Vtbl)).OnMethodCall2)(this, pinfo, oid, guidcid, guidrid, dwthread, imeth), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnMethodReturn2(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, oid: u64, guidcid: *const windows_core::GUID, guidrid: *const windows_core::GUID, dwthread: u32, imeth: u32, hresult: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComMethod2Events", "OnMethodReturn2", 4usize, |this| ((**(this as *const *const IComMethod2Events_Vtbl)).OnMethodReturn2)(this, pinfo, oid, guidcid, guidrid, dwthread, imeth, hresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnMethodException2(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, oid: u64, guidcid: *const windows_core::GUID, guidrid: *const windows_core::GUID, dwthread: u32, imeth: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComMethod2Events", "OnMethodException2", 5usize, |this| ((**(this as *const *const IComMethod2Events_Vtbl)).OnMethodException2)(this, pinfo, oid, guidcid, guidrid, dwthread, imeth), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnMethodCall2, OnMethodReturn2, OnMethodException2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComMethod2Events as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComMethodEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnMethodCall(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, oid: u64, guidcid: *const windows_core::GUID, guidrid: *const windows_core::GUID, imeth: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComMethodEvents", "OnMethodCall", 3usize, |this| ((**(this as *const *const IComMethodEvents_Vtbl)).OnMethodCall)(this, pinfo, oid, guidcid, guidrid, imeth), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnMethodReturn(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, oid: u64, guidcid: *const windows_core::GUID, guidrid: *const windows_core::GUID, imeth: u32, hresult: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComMethodEvents", "OnMethodReturn", 4usize, |this| ((**(this as *const *const IComMethodEvents_Vtbl)).OnMethodReturn)(this, pinfo, oid, guidcid, guidrid, imeth, hresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnMethodException(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, oid: u64, guidcid: *const windows_core::GUID, guidrid: *const windows_core::GUID, imeth: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComMethodEvents", "OnMethodException", 5usize, |this| ((**(this as *const *const IComMethodEvents_Vtbl)).OnMethodException)(this, pinfo, oid, guidcid, guidrid, imeth), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnMethodCall, OnMethodReturn, OnMethodException }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComMethodEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComMtaThreadPoolKnobs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MTASetMaxThreadCount(this: *mut core::ffi::c_void, dwmaxthreads: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComMtaThreadPoolKnobs", "MTASetMaxThreadCount", 3usize, |this| ((**(this as *const *const IComMtaThreadPoolKnobs_Vtbl)).MTASetMaxThreadCount)(this, dwmaxthreads), |result| Some(*result))
+        }
+        unsafe extern "system" fn MTAGetMaxThreadCount(this: *mut core::ffi::c_void, pdwmaxthreads: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComMtaThreadPoolKnobs", "MTAGetMaxThreadCount", 4usize, |this| ((**(this as *const *const IComMtaThreadPoolKnobs_Vtbl)).MTAGetMaxThreadCount)(this, pdwmaxthreads), |result| Some(*result))
+        }
+        unsafe extern "system" fn MTASetThrottleValue(this: *mut core::ffi::c_void, dwthrottle: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComMtaThreadPoolKnobs", "MTASetThrottleValue", 5usize, |this| ((**(this as *const *const IComMtaThreadPoolKnobs_Vtbl)).MTASetThrottleValue)(this, dwthrottle), |result| Some(*result))
+        }
+        unsafe extern "system" fn MTAGetThrottleValue(this: *mut core::ffi::c_void, pdwthrottle: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComMtaThreadPoolKnobs", "MTAGetThrottleValue", 6usize, |this| ((**(this as *const *const IComMtaThreadPoolKnobs_Vtbl)).MTAGetThrottleValue)(this, pdwthrottle), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MTASetMaxThreadCount,
+            MTAGetMaxThreadCount,
+            MTASetThrottleValue,
+            MTAGetThrottleValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComMtaThreadPoolKnobs as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComObjectConstruction2Events_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnObjectConstruct2(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidobject: *const windows_core::GUID, sconstructstring: windows_core::PCWSTR, oid: u64, guidpartition: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComObjectConstruction2Events", "OnObjectConstruct2", 3usize, |this| ((**(this as *const *const IComObjectConstruction2Events_Vtbl)).OnObjectConstruct2)(this, pinfo, guidobject, sconstructstring, oid, guidpartition), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnObjectConstruct2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComObjectConstruction2Events as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComObjectConstructionEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnObjectConstruct(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidobject: *const windows_core::GUID, sconstructstring: windows_core::PCWSTR, oid: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComObjectConstructionEvents", "OnObjectConstruct", 3usize, |this| ((**(this as *const *const IComObjectConstructionEvents_Vtbl)).OnObjectConstruct)(this, pinfo, guidobject, sconstructstring, oid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnObjectConstruct }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComObjectConstructionEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComObjectEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnObjectActivate(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, ctxtid: u64, objectid: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComObjectEvents", "OnObjectActivate", 3usize, |this| ((**(this as *const *const IComObjectEvents_Vtbl)).OnObjectActivate)(this, pinfo, ctxtid, objectid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnObjectDeactivate(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, ctxtid: u64, objectid: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComObjectEvents", "OnObjectDeactivate", 4usize, |this| ((**(this as *const *const IComObjectEvents_Vtbl)).OnObjectDeactivate)(this, pinfo, ctxtid, objectid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDisableCommit(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, ctxtid: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComObjectEvents", "OnDisableCommit", 5usize, |this| ((**(this as *const *const IComObjectEvents_Vtbl)).OnDisableCommit)(this, pinfo, ctxtid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnEnableCommit(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, ctxtid: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComObjectEvents", "OnEnableCommit", 6usize, |this| ((**(this as *const *const IComObjectEvents_Vtbl)).OnEnableCommit)(this, pinfo, ctxtid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnSetComplete(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, ctxtid: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComObjectEvents", "OnSetComplete", 7usize, |this| ((**(this as *const *const IComObjectEvents_Vtbl)).OnSetComplete)(this, pinfo, ctxtid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnSetAbort(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, ctxtid: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComObjectEvents", "OnSetAbort", 8usize, |this| ((**(this as *const *const IComObjectEvents_Vtbl)).OnSetAbort)(this, pinfo, ctxtid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnObjectActivate,
+            OnObjectDeactivate,
+            OnDisableCommit,
+            OnEnableCommit,
+            OnSetComplete,
+            OnSetAbort,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComObjectEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComObjectPool2Events_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnObjPoolPutObject2(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidobject: *const windows_core::GUID, nreason: i32, dwavailable: u32, oid: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComObjectPool2Events", "OnObjPoolPutObject2", 3usize, |this| ((**(this as *const *const IComObjectPool2Events_Vtbl)).OnObjPoolPutObject2)(this, pinfo, guidobject, nreason, dwavailable, oid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnObjPoolGetObject2(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidactivity: *const windows_core::GUID, guidobject: *const windows_core::GUID, dwavailable: u32, oid: u64, guidpartition: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComObjectPool2Events", "OnObjPoolGetObject2", 4usize, |this| ((**(this as *const *const IComObjectPool2Events_Vtbl)).OnObjPoolGetObject2)(this, pinfo, guidactivity, guidobject, dwavailable, oid, guidpartition), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnObjPoolRecycleToTx2(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidactivity: *const windows_core::GUID, guidobject: *const windows_core::GUID, guidtx: *const windows_core::GUID, objid: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComObjectPool2Events", "OnObjPoolRecycleToTx2", 5usize, |this| ((**(this as *const *const IComObjectPool2Events_Vtbl)).OnObjPoolRecycleToTx2)(this, pinfo, guidactivity, guidobject, guidtx, objid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnObjPoolGetFromTx2(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidactivity: *const windows_core::GUID, guidobject: *const windows_core::GUID, guidtx: *const windows_core::GUID, objid: u64, guidpartition: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComObjectPool2Events", "OnObjPoolGetFromTx2", 6usize, |this| ((**(this as *const *const IComObjectPool2Events_Vtbl)).OnObjPoolGetFromTx2)(this, pinfo, guidactivity, guidobject, guidtx, objid, guidpartition), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnObjPoolPutObject2,
+            OnObjPoolGetObject2,
+            OnObjPoolRecycleToTx2,
+            OnObjPoolGetFromTx2,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComObjectPool2Events as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComObjectPoolEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnObjPoolPutObject(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidobject: *const windows_core::GUID, nreason: i32, dwavailable: u32, oid: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComObjectPoolEvents", "OnObjPoolPutObject", 3usize, |this| ((**(this as *const *const IComObjectPoolEvents_Vtbl)).OnObjPoolPutObject)(this, pinfo, guidobject, nreason, dwavailable, oid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnObjPoolGetObject(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidactivity: *const windows_core::GUID, guidobject: *const windows_core::GUID, dwavailable: u32, oid: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComObjectPoolEvents", "OnObjPoolGetObject", 4usize, |this| ((**(this as *const *const IComObjectPoolEvents_Vtbl)).OnObjPoolGetObject)(this, pinfo, guidactivity, guidobject, dwavailable, oid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnObjPoolRecycleToTx(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidactivity: *const windows_core::GUID, guidobject: *const windows_core::GUID, guidtx: *const windows_core::GUID, objid: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComObjectPoolEvents", "OnObjPoolRecycleToTx", 5usize, |this| ((**(this as *const *const IComObjectPoolEvents_Vtbl)).OnObjPoolRecycleToTx)(this, pinfo, guidactivity, guidobject, guidtx, objid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnObjPoolGetFromTx(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidactivity: *const windows_core::GUID, guidobject: *const windows_core::GUID, guidtx: *const windows_core::GUID, objid: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComObjectPoolEvents", "OnObjPoolGetFromTx", 6usize, |this| ((**(this as *const *const IComObjectPoolEvents_Vtbl)).OnObjPoolGetFromTx)(this, pinfo, guidactivity, guidobject, guidtx, objid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnObjPoolPutObject,
+            OnObjPoolGetObject,
+            OnObjPoolRecycleToTx,
+            OnObjPoolGetFromTx,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComObjectPoolEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComObjectPoolEvents2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnObjPoolCreateObject(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidobject: *const windows_core::GUID, dwobjscreated: u32, oid: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComObjectPoolEvents2", "OnObjPoolCreateObject", 3usize, |this| ((**(this as *const *const IComObjectPoolEvents2_Vtbl)).OnObjPoolCreateObject)(this, pinfo, guidobject, dwobjscreated, oid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnObjPoolDestroyObject(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidobject: *const windows_core::GUID, dwobjscreated: u32, oid: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComObjectPoolEvents2", "OnObjPoolDestroyObject", 4usize, |this| ((**(this as *const *const IComObjectPoolEvents2_Vtbl)).OnObjPoolDestroyObject)(this, pinfo, guidobject, dwobjscreated, oid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnObjPoolCreateDecision(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, dwthreadswaiting: u32, dwavail: u32, dwcreated: u32, dwmin: u32, dwmax: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComObjectPoolEvents2", "OnObjPoolCreateDecision", 5usize, |this| ((**(this as *const *const IComObjectPoolEvents2_Vtbl)).OnObjPoolCreateDecision)(this, pinfo, dwthreadswaiting, dwavail, dwcreated, dwmin, dwmax), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnObjPoolTimeout(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidobject: *const windows_core::GUID, guidactivity: *const windows_core::GUID, dwtimeout: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComObjectPoolEvents2", "OnObjPoolTimeout", 6usize, |this| ((**(this as *const *const IComObjectPoolEvents2_Vtbl)).OnObjPoolTimeout)(this, pinfo, guidobject, guidactivity, dwtimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnObjPoolCreatePool(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidobject: *const windows_core::GUID, dwmin: u32, dwmax: u32, dwtimeout: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComObjectPoolEvents2", "OnObjPoolCreatePool", 7usize, |this| ((**(this as *const *const IComObjectPoolEvents2_Vtbl)).OnObjPoolCreatePool)(this, pinfo, guidobject, dwmin, dwmax, dwtimeout), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnObjPoolCreateObject,
+            OnObjPoolDestroyObject,
+            OnObjPoolCreateDecision,
+            OnObjPoolTimeout,
+            OnObjPoolCreatePool,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComObjectPoolEvents2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComQCEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnQCRecord(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, objid: u64, szqueue: windows_core::PCWSTR, guidmsgid: *const windows_core::GUID, guidworkflowid: *const windows_core::GUID, msmqhr: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComQCEvents", "OnQCRecord", 3usize, |this| ((**(this as *const *const IComQCEvents_Vtbl)).OnQCRecord)(this, pinfo, objid, szqueue, guidmsgid, guidworkflowid, msmqhr), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnQCQueueOpen(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, szqueue: windows_core::PCWSTR, queueid: u64, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComQCEvents", "OnQCQueueOpen", 4usize, |this| ((**(this as *const *const IComQCEvents_Vtbl)).OnQCQueueOpen)(this, pinfo, szqueue, queueid, hr), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnQCReceive(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, queueid: u64, guidmsgid: *const windows_core::GUID, guidworkflowid: *const windows_core::GUID, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComQCEvents", "OnQCReceive", 5usize, |this| ((**(this as *const *const IComQCEvents_Vtbl)).OnQCReceive)(this, pinfo, queueid, guidmsgid, guidworkflowid, hr), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnQCReceiveFail(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, queueid: u64, msmqhr: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComQCEvents", "OnQCReceiveFail", 6usize, |this| ((**(this as *const *const IComQCEvents_Vtbl)).OnQCReceiveFail)(this, pinfo, queueid, msmqhr), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnQCMoveToReTryQueue(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidmsgid: *const windows_core::GUID, guidworkflowid: *const windows_core::GUID, retryindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComQCEvents", "OnQCMoveToReTryQueue", 7usize, |this| ((**(this as *const *const IComQCEvents_Vtbl)).OnQCMoveToReTryQueue)(this, pinfo, guidmsgid, guidworkflowid, retryindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnQCMoveToDeadQueue(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidmsgid: *const windows_core::GUID, guidworkflowid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComQCEvents", "OnQCMoveToDeadQueue", 8usize, |this| ((**(this as *const *const IComQCEvents_Vtbl)).OnQCMoveToDeadQueue)(this, pinfo, guidmsgid, guidworkflowid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnQCPlayback(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, objid: u64, guidmsgid: *const windows_core::GUID, guidworkflowid: *const windows_core::GUID, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComQCEvents", "OnQCPlayback", 9usize, |this| ((**(this as *const *const IComQCEvents_Vtbl)).OnQCPlayback)(this, pinfo, objid, guidmsgid, guidworkflowid, hr), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnQCRecord,
+            OnQCQueueOpen,
+            OnQCReceive,
+            OnQCReceiveFail,
+            OnQCMoveToReTryQueue,
+            OnQCMoveToDeadQueue,
+            OnQCPlayback,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComQCEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComResourceEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnResourceCreate(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, objectid: u64, psztype: windows_core::PCWSTR, resid: u64, enlisted: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComResourceEvents", "OnResourceCreate", 3usize, |this| ((**(this as *const *const IComResourceEvents_Vtbl)).OnResourceCreate)(this, pinfo, objectid, psztype, resid, enlisted), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnResourceAllocate(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, objectid: u64, psztype: windows_core::PCWSTR, resid: u64, enlisted: super::super::Foundation::BOOL, numrated: u32, rating: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComResourceEvents", "OnResourceAllocate", 4usize, |this| ((**(this as *const *const IComResourceEvents_Vtbl)).OnResourceAllocate)(this, pinfo, objectid, psztype, resid, enlisted, numrated, rating), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnResourceRecycle(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, objectid: u64, psztype: windows_core::PCWSTR, resid: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComResourceEvents", "OnResourceRecycle", 5usize, |this| ((**(this as *const *const IComResourceEvents_Vtbl)).OnResourceRecycle)(this, pinfo, objectid, psztype, resid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnResourceDestroy(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, objectid: u64, hr: windows_core::HRESULT, psztype: windows_core::PCWSTR, resid: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComResourceEvents", "OnResourceDestroy", 6usize, |this| ((**(this as *const *const IComResourceEvents_Vtbl)).OnResourceDestroy)(this, pinfo, objectid, hr, psztype, resid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnResourceTrack(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, objectid: u64, psztype: windows_core::PCWSTR, resid: u64, enlisted: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComResourceEvents", "OnResourceTrack", 7usize, |this| ((**(this as *const *const IComResourceEvents_Vtbl)).OnResourceTrack)(this, pinfo, objectid, psztype, resid, enlisted), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnResourceCreate,
+            OnResourceAllocate,
+            OnResourceRecycle,
+            OnResourceDestroy,
+            OnResourceTrack,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComResourceEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComSecurityEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnAuthenticate(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidactivity: *const windows_core::GUID, objectid: u64, guidiid: *const windows_core::GUID, imeth: u32, cbbyteorig: u32, psidoriginaluser: *const u8, cbbytecur: u32, psidcurrentuser: *const u8, bcurrentuserinpersonatinginproc: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComSecurityEvents", "OnAuthenticate", 3usize, |this| ((**(this as *const *const IComSecurityEvents_Vtbl)).OnAuthenticate)(this, pinfo, guidactivity, objectid, guidiid, imeth, cbbyteorig, psidoriginaluser, cbbytecur, psidcurrentuser, bcurrentuserinpersonatinginproc), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnAuthenticateFail(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidactivity: *const windows_core::GUID, objectid: u64, guidiid: *const windows_core::GUID, imeth: u32, cbbyteorig: u32, psidoriginaluser: *const u8, cbbytecur: u32, psidcurrentuser: *const u8, bcurrentuserinpersonatinginproc: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComSecurityEvents", "OnAuthenticateFail", 4usize, |this| ((**(this as *const *const IComSecurityEvents_Vtbl)).OnAuthenticateFail)(this, pinfo, guidactivity, objectid, guidiid, imeth, cbbyteorig, psidoriginaluser, cbbytecur, psidcurrentuser, bcurrentuserinpersonatinginproc), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnAuthenticate, OnAuthenticateFail }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComSecurityEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComStaThreadPoolKnobs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMinThreadCount(this: *mut core::ffi::c_void, minthreads: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComStaThreadPoolKnobs", "SetMinThreadCount", 3usize, |this| ((**(this as *const *const IComStaThreadPoolKnobs_Vtbl)).SetMinThreadCount)(this, minthreads), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMinThreadCount(this: *mut core::ffi::c_void, minthreads: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComStaThreadPoolKnobs", "GetMinThreadCount", 4usize, |this| ((**(this as *const *const IComStaThreadPoolKnobs_Vtbl)).GetMinThreadCount)(this, minthreads), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxThreadCount(this: *mut core::ffi::c_void, maxthreads: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComStaThreadPoolKnobs", "SetMaxThreadCount", 5usize, |this| ((**(this as *const *const IComStaThreadPoolKnobs_Vtbl)).SetMaxThreadCount)(this, maxthreads), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxThreadCount(this: *mut core::ffi::c_void, maxthreads: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComStaThreadPoolKnobs", "GetMaxThreadCount", 6usize, |this| ((**(this as *const *const IComStaThreadPoolKnobs_Vtbl)).GetMaxThreadCount)(this, maxthreads), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActivityPerThread(this: *mut core::ffi::c_void, activitiesperthread: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComStaThreadPoolKnobs", "SetActivityPerThread", 7usize, |this| ((**(this as *const *const IComStaThreadPoolKnobs_Vtbl)).SetActivityPerThread)(this, activitiesperthread), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActivityPerThread(this: *mut core::ffi::c_void, activitiesperthread: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComStaThreadPoolKnobs", "GetActivityPerThread", 8usize, |this| ((**(this as *const *const IComStaThreadPoolKnobs_Vtbl)).GetActivityPerThread)(this, activitiesperthread), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActivityRatio(this: *mut core::ffi::c_void, activityratio: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComStaThreadPoolKnobs", "SetActivityRatio", 9usize, |this| ((**(this as *const *const IComStaThreadPoolKnobs_Vtbl)).SetActivityRatio)(this, activityratio), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActivityRatio(this: *mut core::ffi::c_void, activityratio: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComStaThreadPoolKnobs", "GetActivityRatio", 10usize, |this| ((**(this as *const *const IComStaThreadPoolKnobs_Vtbl)).GetActivityRatio)(this, activityratio), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetThreadCount(this: *mut core::ffi::c_void, pdwthreads: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComStaThreadPoolKnobs", "GetThreadCount", 11usize, |this| ((**(this as *const *const IComStaThreadPoolKnobs_Vtbl)).GetThreadCount)(this, pdwthreads), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetQueueDepth(this: *mut core::ffi::c_void, pdwqdepth: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComStaThreadPoolKnobs", "GetQueueDepth", 12usize, |this| ((**(this as *const *const IComStaThreadPoolKnobs_Vtbl)).GetQueueDepth)(this, pdwqdepth), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQueueDepth(this: *mut core::ffi::c_void, dwqdepth: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComStaThreadPoolKnobs", "SetQueueDepth", 13usize, |this| ((**(this as *const *const IComStaThreadPoolKnobs_Vtbl)).SetQueueDepth)(this, dwqdepth), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetMinThreadCount,
+            GetMinThreadCount,
+            SetMaxThreadCount,
+            GetMaxThreadCount,
+            SetActivityPerThread,
+            GetActivityPerThread,
+            SetActivityRatio,
+            GetActivityRatio,
+            GetThreadCount,
+            GetQueueDepth,
+            SetQueueDepth,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComStaThreadPoolKnobs as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComStaThreadPoolKnobs2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMaxCPULoad(this: *mut core::ffi::c_void, pdwload: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComStaThreadPoolKnobs2", "GetMaxCPULoad", 14usize, |this| ((**(this as *const *const IComStaThreadPoolKnobs2_Vtbl)).GetMaxCPULoad)(this, pdwload), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxCPULoad(this: *mut core::ffi::c_void, pdwload: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComStaThreadPoolKnobs2", "SetMaxCPULoad", 15usize, |this| ((**(this as *const *const IComStaThreadPoolKnobs2_Vtbl)).SetMaxCPULoad)(this, pdwload), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCPUMetricEnabled(this: *mut core::ffi::c_void, pbmetricenabled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComStaThreadPoolKnobs2", "GetCPUMetricEnabled", 16usize, |this| ((**(this as *const *const IComStaThreadPoolKnobs2_Vtbl)).GetCPUMetricEnabled)(this, pbmetricenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCPUMetricEnabled(this: *mut core::ffi::c_void, bmetricenabled: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComStaThreadPoolKnobs2", "SetCPUMetricEnabled", 17usize, |this| ((**(this as *const *const IComStaThreadPoolKnobs2_Vtbl)).SetCPUMetricEnabled)(this, bmetricenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCreateThreadsAggressively(this: *mut core::ffi::c_void, pbmetricenabled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComStaThreadPoolKnobs2", "GetCreateThreadsAggressively", 18usize, |this| ((**(this as *const *const IComStaThreadPoolKnobs2_Vtbl)).GetCreateThreadsAggressively)(this, pbmetricenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCreateThreadsAggressively(this: *mut core::ffi::c_void, bmetricenabled: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComStaThreadPoolKnobs2", "SetCreateThreadsAggressively", 19usize, |this| ((**(this as *const *const IComStaThreadPoolKnobs2_Vtbl)).SetCreateThreadsAggressively)(this, bmetricenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxCSR(this: *mut core::ffi::c_void, pdwcsr: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComStaThreadPoolKnobs2", "GetMaxCSR", 20usize, |this| ((**(this as *const *const IComStaThreadPoolKnobs2_Vtbl)).GetMaxCSR)(this, pdwcsr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxCSR(this: *mut core::ffi::c_void, dwcsr: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComStaThreadPoolKnobs2", "SetMaxCSR", 21usize, |this| ((**(this as *const *const IComStaThreadPoolKnobs2_Vtbl)).SetMaxCSR)(this, dwcsr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWaitTimeForThreadCleanup(this: *mut core::ffi::c_void, pdwthreadcleanupwaittime: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComStaThreadPoolKnobs2", "GetWaitTimeForThreadCleanup", 22usize, |this| ((**(this as *const *const IComStaThreadPoolKnobs2_Vtbl)).GetWaitTimeForThreadCleanup)(this, pdwthreadcleanupwaittime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWaitTimeForThreadCleanup(this: *mut core::ffi::c_void, dwthreadcleanupwaittime: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComStaThreadPoolKnobs2", "SetWaitTimeForThreadCleanup", 23usize, |this| ((**(this as *const *const IComStaThreadPoolKnobs2_Vtbl)).SetWaitTimeForThreadCleanup)(this, dwthreadcleanupwaittime), |result| Some(*result))
+        }
+        Self {
+            base__: <IComStaThreadPoolKnobs_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetMaxCPULoad,
+            SetMaxCPULoad,
+            GetCPUMetricEnabled,
+            SetCPUMetricEnabled,
+            GetCreateThreadsAggressively,
+            SetCreateThreadsAggressively,
+            GetMaxCSR,
+            SetMaxCSR,
+            GetWaitTimeForThreadCleanup,
+            SetWaitTimeForThreadCleanup,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComStaThreadPoolKnobs2 as windows_core::Interface>::IID || <IComStaThreadPoolKnobs_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComThreadEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnThreadStart(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, threadid: u64, dwthread: u32, dwtheadcnt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComThreadEvents", "OnThreadStart", 3usize, |this| ((**(this as *const *const IComThreadEvents_Vtbl)).OnThreadStart)(this, pinfo, threadid, dwthread, dwtheadcnt), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnThreadTerminate(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, threadid: u64, dwthread: u32, dwtheadcnt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComThreadEvents", "OnThreadTerminate", 4usize, |this| ((**(this as *const *const IComThreadEvents_Vtbl)).OnThreadTerminate)(this, pinfo, threadid, dwthread, dwtheadcnt), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnThreadBindToApartment(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, threadid: u64, aptid: u64, dwactcnt: u32, dwlowcnt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComThreadEvents", "OnThreadBindToApartment", 5usize, |this| ((**(this as *const *const IComThreadEvents_Vtbl)).OnThreadBindToApartment)(this, pinfo, threadid, aptid, dwactcnt, dwlowcnt), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnThreadUnBind(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, threadid: u64, aptid: u64, dwactcnt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComThreadEvents", "OnThreadUnBind", 6usize, |this| ((**(this as *const *const IComThreadEvents_Vtbl)).OnThreadUnBind)(this, pinfo, threadid, aptid, dwactcnt), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnThreadWorkEnque(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, threadid: u64, msgworkid: u64, queuelen: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComThreadEvents", "OnThreadWorkEnque", 7usize, |this| ((**(this as *const *const IComThreadEvents_Vtbl)).OnThreadWorkEnque)(this, pinfo, threadid, msgworkid, queuelen), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnThreadWorkPrivate(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, threadid: u64, msgworkid: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComThreadEvents", "OnThreadWorkPrivate", 8usize, |this| ((**(this as *const *const IComThreadEvents_Vtbl)).OnThreadWorkPrivate)(this, pinfo, threadid, msgworkid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnThreadWorkPublic(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, threadid: u64, msgworkid: u64, queuelen: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComThreadEvents", "OnThreadWorkPublic", 9usize, |this| ((**(this as *const *const IComThreadEvents_Vtbl)).OnThreadWorkPublic)(this, pinfo, threadid, msgworkid, queuelen), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnThreadWorkRedirect(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, threadid: u64, msgworkid: u64, queuelen: u32, threadnum: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComThreadEvents", "OnThreadWorkRedirect", 10usize, |this| ((**(this as *const *const IComThreadEvents_Vtbl)).OnThreadWorkRedirect)(this, pinfo, threadid, msgworkid, queuelen, threadnum), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnThreadWorkReject(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, threadid: u64, msgworkid: u64, queuelen: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComThreadEvents", "OnThreadWorkReject", 11usize, |this| ((**(this as *const *const IComThreadEvents_Vtbl)).OnThreadWorkReject)(this, pinfo, threadid, msgworkid, queuelen), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnThreadAssignApartment(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidactivity: *const windows_core::GUID, aptid: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComThreadEvents", "OnThreadAssignApartment", 12usize, |this| ((**(this as *const *const IComThreadEvents_Vtbl)).OnThreadAssignApartment)(this, pinfo, guidactivity, aptid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnThreadUnassignApartment(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, aptid: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComThreadEvents", "OnThreadUnassignApartment", 13usize, |this| ((**(this as *const *const IComThreadEvents_Vtbl)).OnThreadUnassignApartment)(this, pinfo, aptid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnThreadStart,
+            OnThreadTerminate,
+            OnThreadBindToApartment,
+            OnThreadUnBind,
+            OnThreadWorkEnque,
+            OnThreadWorkPrivate,
+            OnThreadWorkPublic,
+            OnThreadWorkRedirect,
+            OnThreadWorkReject,
+            OnThreadAssignApartment,
+            OnThreadUnassignApartment,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComThreadEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComTrackingInfoCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, ptype: *mut TRACKING_COLL_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComTrackingInfoCollection", "Type", 3usize, |this| ((**(this as *const *const IComTrackingInfoCollection_Vtbl)).Type)(this, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComTrackingInfoCollection", "Count", 4usize, |this| ((**(this as *const *const IComTrackingInfoCollection_Vtbl)).Count)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, ulindex: u32, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComTrackingInfoCollection", "Item", 5usize, |this| ((**(this as *const *const IComTrackingInfoCollection_Vtbl)).Item)(this, ulindex, riid, ppv), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Type, Count, Item }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComTrackingInfoCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComTrackingInfoEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnNewTrackingInfo(this: *mut core::ffi::c_void, ptoplevelcollection: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComTrackingInfoEvents", "OnNewTrackingInfo", 3usize, |this| ((**(this as *const *const IComTrackingInfoEvents_Vtbl)).OnNewTrackingInfo)(this, ptoplevelcollection), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnNewTrackingInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComTrackingInfoEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComTrackingInfoObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, szpropertyname: windows_core::PCWSTR, pvarout: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComTrackingInfoObject", "GetValue", 3usize, |this| ((**(this as *const *const IComTrackingInfoObject_Vtbl)).GetValue)(this, szpropertyname, pvarout), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComTrackingInfoObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComTrackingInfoProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PropCount(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComTrackingInfoProperties", "PropCount", 3usize, |this| ((**(this as *const *const IComTrackingInfoProperties_Vtbl)).PropCount)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropName(this: *mut core::ffi::c_void, ulindex: u32, ppszpropname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComTrackingInfoProperties", "GetPropName", 4usize, |this| ((**(this as *const *const IComTrackingInfoProperties_Vtbl)).GetPropName)(this, ulindex, ppszpropname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PropCount, GetPropName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComTrackingInfoProperties as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComTransaction2Events_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnTransactionStart2(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidtx: *const windows_core::GUID, tsid: *const windows_core::GUID, froot: super::super::Foundation::BOOL, nisolationlevel: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComTransaction2Events", "OnTransactionStart2", 3usize, |this| ((**(this as *const *const IComTransaction2Events_Vtbl)).OnTransactionStart2)(this, pinfo, guidtx, tsid, froot, nisolationlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnTransactionPrepare2(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidtx: *const windows_core::GUID, fvoteyes: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComTransaction2Events", "OnTransactionPrepare2", 4usize, |this| ((**(this as *const *const IComTransaction2Events_Vtbl)).OnTransactionPrepare2)(this, pinfo, guidtx, fvoteyes), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnTransactionAbort2(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidtx: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComTransaction2Events", "OnTransactionAbort2", 5usize, |this| ((**(this as *const *const IComTransaction2Events_Vtbl)).OnTransactionAbort2)(this, pinfo, guidtx), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnTransactionCommit2(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidtx: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComTransaction2Events", "OnTransactionCommit2", 6usize, |this| ((**(this as *const *const IComTransaction2Events_Vtbl)).OnTransactionCommit2)(this, pinfo, guidtx), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnTransactionStart2,
+            OnTransactionPrepare2,
+            OnTransactionAbort2,
+            OnTransactionCommit2,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComTransaction2Events as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComTransactionEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnTransactionStart(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidtx: *const windows_core::GUID, tsid: *const windows_core::GUID, froot: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComTransactionEvents", "OnTransactionStart", 3usize, |this| ((**(this as *const *const IComTransactionEvents_Vtbl)).OnTransactionStart)(this, pinfo, guidtx, tsid, froot), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnTransactionPrepare(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidtx: *const windows_core::GUID, fvoteyes: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComTransactionEvents", "OnTransactionPrepare", 4usize, |this| ((**(this as *const *const IComTransactionEvents_Vtbl)).OnTransactionPrepare)(this, pinfo, guidtx, fvoteyes), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnTransactionAbort(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidtx: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComTransactionEvents", "OnTransactionAbort", 5usize, |this| ((**(this as *const *const IComTransactionEvents_Vtbl)).OnTransactionAbort)(this, pinfo, guidtx), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnTransactionCommit(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, guidtx: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComTransactionEvents", "OnTransactionCommit", 6usize, |this| ((**(this as *const *const IComTransactionEvents_Vtbl)).OnTransactionCommit)(this, pinfo, guidtx), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnTransactionStart,
+            OnTransactionPrepare,
+            OnTransactionAbort,
+            OnTransactionCommit,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComTransactionEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IComUserEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnUserEvent(this: *mut core::ffi::c_void, pinfo: *const COMSVCSEVENTINFO, pvarevent: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComUserEvent", "OnUserEvent", 3usize, |this| ((**(this as *const *const IComUserEvent_Vtbl)).OnUserEvent)(this, pinfo, pvarevent), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnUserEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComUserEvent as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContextProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContextProperties", "Count", 3usize, |this| ((**(this as *const *const IContextProperties_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, pproperty: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContextProperties", "GetProperty", 4usize, |this| ((**(this as *const *const IContextProperties_Vtbl)).GetProperty)(this, name, pproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumNames(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContextProperties", "EnumNames", 5usize, |this| ((**(this as *const *const IContextProperties_Vtbl)).EnumNames)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, property: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContextProperties", "SetProperty", 6usize, |this| ((**(this as *const *const IContextProperties_Vtbl)).SetProperty)(this, name, property), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveProperty(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContextProperties", "RemoveProperty", 7usize, |this| ((**(this as *const *const IContextProperties_Vtbl)).RemoveProperty)(this, name), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Count, GetProperty, EnumNames, SetProperty, RemoveProperty }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContextProperties as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContextSecurityPerimeter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPerimeterFlag(this: *mut core::ffi::c_void, pflag: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContextSecurityPerimeter", "GetPerimeterFlag", 3usize, |this| ((**(this as *const *const IContextSecurityPerimeter_Vtbl)).GetPerimeterFlag)(this, pflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPerimeterFlag(this: *mut core::ffi::c_void, fflag: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContextSecurityPerimeter", "SetPerimeterFlag", 4usize, |this| ((**(this as *const *const IContextSecurityPerimeter_Vtbl)).SetPerimeterFlag)(this, fflag), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPerimeterFlag, SetPerimeterFlag }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContextSecurityPerimeter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContextState_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDeactivateOnReturn(this: *mut core::ffi::c_void, bdeactivate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContextState", "SetDeactivateOnReturn", 3usize, |this| ((**(this as *const *const IContextState_Vtbl)).SetDeactivateOnReturn)(this, bdeactivate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeactivateOnReturn(this: *mut core::ffi::c_void, pbdeactivate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContextState", "GetDeactivateOnReturn", 4usize, |this| ((**(this as *const *const IContextState_Vtbl)).GetDeactivateOnReturn)(this, pbdeactivate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMyTransactionVote(this: *mut core::ffi::c_void, txvote: TransactionVote) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContextState", "SetMyTransactionVote", 5usize, |this| ((**(this as *const *const IContextState_Vtbl)).SetMyTransactionVote)(this, txvote), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMyTransactionVote(this: *mut core::ffi::c_void, ptxvote: *mut TransactionVote) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContextState", "GetMyTransactionVote", 6usize, |this| ((**(this as *const *const IContextState_Vtbl)).GetMyTransactionVote)(this, ptxvote), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetDeactivateOnReturn,
+            GetDeactivateOnReturn,
+            SetMyTransactionVote,
+            GetMyTransactionVote,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContextState as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICreateWithLocalTransaction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstanceWithSysTx(this: *mut core::ffi::c_void, ptransaction: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, riid: *const windows_core::GUID, pobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateWithLocalTransaction", "CreateInstanceWithSysTx", 3usize, |this| ((**(this as *const *const ICreateWithLocalTransaction_Vtbl)).CreateInstanceWithSysTx)(this, ptransaction, rclsid, riid, pobject), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstanceWithSysTx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICreateWithLocalTransaction as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICreateWithTipTransactionEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, bstrtipurl: core::mem::MaybeUninit<windows_core::BSTR>, rclsid: *const windows_core::GUID, riid: *const windows_core::GUID, pobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateWithTipTransactionEx", "CreateInstance", 3usize, |this| ((**(this as *const *const ICreateWithTipTransactionEx_Vtbl)).CreateInstance)(this, bstrtipurl, rclsid, riid, pobject), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICreateWithTipTransactionEx as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_DistributedTransactionCoordinator")]
+impl windows_core::imp::ProxyVtable for ICreateWithTransactionEx_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_DistributedTransactionCoordinator")]
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, ptransaction: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, riid: *const windows_core::GUID, pobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateWithTransactionEx", "CreateInstance", 3usize, |this| ((**(this as *const *const ICreateWithTransactionEx_Vtbl)).CreateInstance)(this, ptransaction, rclsid, riid, pobject), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_DistributedTransactionCoordinator")]
+            CreateInstance,
+            #[cfg(not(feature = "Win32_System_DistributedTransactionCoordinator"))]
+            CreateInstance: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICreateWithTransactionEx as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICrmCompensator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetLogControl(this: *mut core::ffi::c_void, plogcontrol: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmCompensator", "SetLogControl", 3usize, |this| ((**(this as *const *const ICrmCompensator_Vtbl)).SetLogControl)(this, plogcontrol), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginPrepare(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmCompensator", "BeginPrepare", 4usize, |this| ((**(this as *const *const ICrmCompensator_Vtbl)).BeginPrepare)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PrepareRecord(this: *mut core::ffi::c_void, crmlogrec: CrmLogRecordRead, pfforget: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmCompensator", "PrepareRecord", 5usize, |this| ((**(this as *const *const ICrmCompensator_Vtbl)).PrepareRecord)(this, crmlogrec, pfforget), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndPrepare(this: *mut core::ffi::c_void, pfoktoprepare: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmCompensator", "EndPrepare", 6usize, |this| ((**(this as *const *const ICrmCompensator_Vtbl)).EndPrepare)(this, pfoktoprepare), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginCommit(this: *mut core::ffi::c_void, frecovery: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmCompensator", "BeginCommit", 7usize, |this| ((**(this as *const *const ICrmCompensator_Vtbl)).BeginCommit)(this, frecovery), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CommitRecord(this: *mut core::ffi::c_void, crmlogrec: CrmLogRecordRead, pfforget: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmCompensator", "CommitRecord", 8usize, |this| ((**(this as *const *const ICrmCompensator_Vtbl)).CommitRecord)(this, crmlogrec, pfforget), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndCommit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmCompensator", "EndCommit", 9usize, |this| ((**(this as *const *const ICrmCompensator_Vtbl)).EndCommit)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginAbort(this: *mut core::ffi::c_void, frecovery: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmCompensator", "BeginAbort", 10usize, |this| ((**(this as *const *const ICrmCompensator_Vtbl)).BeginAbort)(this, frecovery), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AbortRecord(this: *mut core::ffi::c_void, crmlogrec: CrmLogRecordRead, pfforget: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmCompensator", "AbortRecord", 11usize, |this| ((**(this as *const *const ICrmCompensator_Vtbl)).AbortRecord)(this, crmlogrec, pfforget), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndAbort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmCompensator", "EndAbort", 12usize, |this| ((**(this as *const *const ICrmCompensator_Vtbl)).EndAbort)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetLogControl,
+            BeginPrepare,
+            #[cfg(feature = "Win32_System_Com")]
+            PrepareRecord,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PrepareRecord: 0,
+            EndPrepare,
+            BeginCommit,
+            #[cfg(feature = "Win32_System_Com")]
+            CommitRecord,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CommitRecord: 0,
+            EndCommit,
+            BeginAbort,
+            #[cfg(feature = "Win32_System_Com")]
+            AbortRecord,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AbortRecord: 0,
+            EndAbort,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICrmCompensator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICrmCompensatorVariants_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetLogControlVariants(this: *mut core::ffi::c_void, plogcontrol: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmCompensatorVariants", "SetLogControlVariants", 3usize, |this| ((**(this as *const *const ICrmCompensatorVariants_Vtbl)).SetLogControlVariants)(this, plogcontrol), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginPrepareVariants(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmCompensatorVariants", "BeginPrepareVariants", 4usize, |this| ((**(this as *const *const ICrmCompensatorVariants_Vtbl)).BeginPrepareVariants)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrepareRecordVariants(this: *mut core::ffi::c_void, plogrecord: *const core::mem::MaybeUninit<windows_core::VARIANT>, pbforget: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmCompensatorVariants", "PrepareRecordVariants", 5usize, |this| ((**(this as *const *const ICrmCompensatorVariants_Vtbl)).PrepareRecordVariants)(this, plogrecord, pbforget), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndPrepareVariants(this: *mut core::ffi::c_void, pboktoprepare: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmCompensatorVariants", "EndPrepareVariants", 6usize, |this| ((**(this as *const *const ICrmCompensatorVariants_Vtbl)).EndPrepareVariants)(this, pboktoprepare), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginCommitVariants(this: *mut core::ffi::c_void, brecovery: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmCompensatorVariants", "BeginCommitVariants", 7usize, |this| ((**(this as *const *const ICrmCompensatorVariants_Vtbl)).BeginCommitVariants)(this, brecovery), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommitRecordVariants(this: *mut core::ffi::c_void, plogrecord: *const core::mem::MaybeUninit<windows_core::VARIANT>, pbforget: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmCompensatorVariants", "CommitRecordVariants", 8usize, |this| ((**(this as *const *const ICrmCompensatorVariants_Vtbl)).CommitRecordVariants)(this, plogrecord, pbforget), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndCommitVariants(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmCompensatorVariants", "EndCommitVariants", 9usize, |this| ((**(this as *const *const ICrmCompensatorVariants_Vtbl)).EndCommitVariants)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginAbortVariants(this: *mut core::ffi::c_void, brecovery: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmCompensatorVariants", "BeginAbortVariants", 10usize, |this| ((**(this as *const *const ICrmCompensatorVariants_Vtbl)).BeginAbortVariants)(this, brecovery), |result| Some(*result))
+        }
+        unsafe extern "system" fn AbortRecordVariants(this: *mut core::ffi::c_void, plogrecord: *const core::mem::MaybeUninit<windows_core::VARIANT>, pbforget: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmCompensatorVariants", "AbortRecordVariants", 11usize, |this| ((**(this as *const *const ICrmCompensatorVariants_Vtbl)).AbortRecordVariants)(this, plogrecord, pbforget), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndAbortVariants(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmCompensatorVariants", "EndAbortVariants", 12usize, |this| ((**(this as *const *const ICrmCompensatorVariants_Vtbl)).EndAbortVariants)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetLogControlVariants,
+            BeginPrepareVariants,
+            PrepareRecordVariants,
+            EndPrepareVariants,
+            BeginCommitVariants,
+            CommitRecordVariants,
+            EndCommitVariants,
+            BeginAbortVariants,
+            AbortRecordVariants,
+            EndAbortVariants,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICrmCompensatorVariants as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICrmFormatLogRecords_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetColumnCount(this: *mut core::ffi::c_void, plcolumncount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmFormatLogRecords", "GetColumnCount", 3usize, |this| ((**(this as *const *const ICrmFormatLogRecords_Vtbl)).GetColumnCount)(this, plcolumncount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColumnHeaders(this: *mut core::ffi::c_void, pheaders: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmFormatLogRecords", "GetColumnHeaders", 4usize, |this| ((**(this as *const *const ICrmFormatLogRecords_Vtbl)).GetColumnHeaders)(this, pheaders), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetColumn(this: *mut core::ffi::c_void, crmlogrec: CrmLogRecordRead, pformattedlogrecord: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmFormatLogRecords", "GetColumn", 5usize, |this| ((**(this as *const *const ICrmFormatLogRecords_Vtbl)).GetColumn)(this, crmlogrec, pformattedlogrecord), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColumnVariants(this: *mut core::ffi::c_void, logrecord: core::mem::MaybeUninit<windows_core::VARIANT>, pformattedlogrecord: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmFormatLogRecords", "GetColumnVariants", 6usize, |this| ((**(this as *const *const ICrmFormatLogRecords_Vtbl)).GetColumnVariants)(this, logrecord, pformattedlogrecord), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetColumnCount,
+            GetColumnHeaders,
+            #[cfg(feature = "Win32_System_Com")]
+            GetColumn,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetColumn: 0,
+            GetColumnVariants,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICrmFormatLogRecords as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICrmLogControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TransactionUOW(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmLogControl", "TransactionUOW", 3usize, |this| ((**(this as *const *const ICrmLogControl_Vtbl)).TransactionUOW)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterCompensator(this: *mut core::ffi::c_void, lpcwstrprogidcompensator: windows_core::PCWSTR, lpcwstrdescription: windows_core::PCWSTR, lcrmregflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmLogControl", "RegisterCompensator", 4usize, |this| ((**(this as *const *const ICrmLogControl_Vtbl)).RegisterCompensator)(this, lpcwstrprogidcompensator, lpcwstrdescription, lcrmregflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteLogRecordVariants(this: *mut core::ffi::c_void, plogrecord: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmLogControl", "WriteLogRecordVariants", 5usize, |this| ((**(this as *const *const ICrmLogControl_Vtbl)).WriteLogRecordVariants)(this, plogrecord), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForceLog(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmLogControl", "ForceLog", 6usize, |this| ((**(this as *const *const ICrmLogControl_Vtbl)).ForceLog)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForgetLogRecord(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmLogControl", "ForgetLogRecord", 7usize, |this| ((**(this as *const *const ICrmLogControl_Vtbl)).ForgetLogRecord)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForceTransactionToAbort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmLogControl", "ForceTransactionToAbort", 8usize, |this| ((**(this as *const *const ICrmLogControl_Vtbl)).ForceTransactionToAbort)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn WriteLogRecord(this: *mut core::ffi::c_void, rgblob: *const super::Com::BLOB, cblob: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmLogControl", "WriteLogRecord", 9usize, |this| ((**(this as *const *const ICrmLogControl_Vtbl)).WriteLogRecord)(this, rgblob, cblob), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TransactionUOW,
+            RegisterCompensator,
+            WriteLogRecordVariants,
+            ForceLog,
+            ForgetLogRecord,
+            ForceTransactionToAbort,
+            #[cfg(feature = "Win32_System_Com")]
+            WriteLogRecord,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            WriteLogRecord: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICrmLogControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICrmMonitor_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetClerks(this: *mut core::ffi::c_void, pclerks: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmMonitor", "GetClerks", 3usize, |this| ((**(this as *const *const ICrmMonitor_Vtbl)).GetClerks)(this, pclerks), |result| Some(*result))
+        }
+        unsafe extern "system" fn HoldClerk(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>, pitem: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmMonitor", "HoldClerk", 4usize, |this| ((**(this as *const *const ICrmMonitor_Vtbl)).HoldClerk)(this, index, pitem), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetClerks,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetClerks: 0,
+            HoldClerk,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICrmMonitor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICrmMonitorClerks_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>, pitem: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmMonitorClerks", "Item", 7usize, |this| ((**(this as *const *const ICrmMonitorClerks_Vtbl)).Item)(this, index, pitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmMonitorClerks", "_NewEnum", 8usize, |this| ((**(this as *const *const ICrmMonitorClerks_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmMonitorClerks", "Count", 9usize, |this| ((**(this as *const *const ICrmMonitorClerks_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProgIdCompensator(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>, pitem: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmMonitorClerks", "ProgIdCompensator", 10usize, |this| ((**(this as *const *const ICrmMonitorClerks_Vtbl)).ProgIdCompensator)(this, index, pitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>, pitem: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmMonitorClerks", "Description", 11usize, |this| ((**(this as *const *const ICrmMonitorClerks_Vtbl)).Description)(this, index, pitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransactionUOW(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>, pitem: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmMonitorClerks", "TransactionUOW", 12usize, |this| ((**(this as *const *const ICrmMonitorClerks_Vtbl)).TransactionUOW)(this, index, pitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivityId(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>, pitem: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmMonitorClerks", "ActivityId", 13usize, |this| ((**(this as *const *const ICrmMonitorClerks_Vtbl)).ActivityId)(this, index, pitem), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Item,
+            _NewEnum,
+            Count,
+            ProgIdCompensator,
+            Description,
+            TransactionUOW,
+            ActivityId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICrmMonitorClerks as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICrmMonitorLogRecords_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmMonitorLogRecords", "Count", 3usize, |this| ((**(this as *const *const ICrmMonitorLogRecords_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransactionState(this: *mut core::ffi::c_void, pval: *mut CrmTransactionState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmMonitorLogRecords", "TransactionState", 4usize, |this| ((**(this as *const *const ICrmMonitorLogRecords_Vtbl)).TransactionState)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn StructuredRecords(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmMonitorLogRecords", "StructuredRecords", 5usize, |this| ((**(this as *const *const ICrmMonitorLogRecords_Vtbl)).StructuredRecords)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetLogRecord(this: *mut core::ffi::c_void, dwindex: u32, pcrmlogrec: *mut CrmLogRecordRead) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmMonitorLogRecords", "GetLogRecord", 6usize, |this| ((**(this as *const *const ICrmMonitorLogRecords_Vtbl)).GetLogRecord)(this, dwindex, pcrmlogrec), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLogRecordVariants(this: *mut core::ffi::c_void, indexnumber: core::mem::MaybeUninit<windows_core::VARIANT>, plogrecord: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICrmMonitorLogRecords", "GetLogRecordVariants", 7usize, |this| ((**(this as *const *const ICrmMonitorLogRecords_Vtbl)).GetLogRecordVariants)(this, indexnumber, plogrecord), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            TransactionState,
+            StructuredRecords,
+            #[cfg(feature = "Win32_System_Com")]
+            GetLogRecord,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetLogRecord: 0,
+            GetLogRecordVariants,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICrmMonitorLogRecords as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDispenserDriver_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateResource(this: *mut core::ffi::c_void, restypid: usize, presid: *mut usize, psecsfreebeforedestroy: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispenserDriver", "CreateResource", 3usize, |this| ((**(this as *const *const IDispenserDriver_Vtbl)).CreateResource)(this, restypid, presid, psecsfreebeforedestroy), |result| Some(*result))
+        }
+        unsafe extern "system" fn RateResource(this: *mut core::ffi::c_void, restypid: usize, resid: usize, frequirestransactionenlistment: super::super::Foundation::BOOL, prating: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispenserDriver", "RateResource", 4usize, |this| ((**(this as *const *const IDispenserDriver_Vtbl)).RateResource)(this, restypid, resid, frequirestransactionenlistment, prating), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnlistResource(this: *mut core::ffi::c_void, resid: usize, transid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispenserDriver", "EnlistResource", 5usize, |this| ((**(this as *const *const IDispenserDriver_Vtbl)).EnlistResource)(this, resid, transid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetResource(this: *mut core::ffi::c_void, resid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispenserDriver", "ResetResource", 6usize, |this| ((**(this as *const *const IDispenserDriver_Vtbl)).ResetResource)(this, resid), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestroyResource(this: *mut core::ffi::c_void, resid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispenserDriver", "DestroyResource", 7usize, |this| ((**(this as *const *const IDispenserDriver_Vtbl)).DestroyResource)(this, resid), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestroyResourceS(this: *mut core::ffi::c_void, resid: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispenserDriver", "DestroyResourceS", 8usize, |this| ((**(this as *const *const IDispenserDriver_Vtbl)).DestroyResourceS)(this, resid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateResource,
+            RateResource,
+            EnlistResource,
+            ResetResource,
+            DestroyResource,
+            DestroyResourceS,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDispenserDriver as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDispenserManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterDispenser(this: *mut core::ffi::c_void, __midl__idispensermanager0000: *mut core::ffi::c_void, szdispensername: windows_core::PCWSTR, __midl__idispensermanager0001: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispenserManager", "RegisterDispenser", 3usize, |this| ((**(this as *const *const IDispenserManager_Vtbl)).RegisterDispenser)(this, __midl__idispensermanager0000, szdispensername, __midl__idispensermanager0001), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContext(this: *mut core::ffi::c_void, __midl__idispensermanager0002: *mut usize, __midl__idispensermanager0003: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispenserManager", "GetContext", 4usize, |this| ((**(this as *const *const IDispenserManager_Vtbl)).GetContext)(this, __midl__idispensermanager0002, __midl__idispensermanager0003), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterDispenser, GetContext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDispenserManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumNames_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgname: *mut core::mem::MaybeUninit<windows_core::BSTR>, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNames", "Next", 3usize, |this| ((**(this as *const *const IEnumNames_Vtbl)).Next)(this, celt, rgname, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNames", "Skip", 4usize, |this| ((**(this as *const *const IEnumNames_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNames", "Reset", 5usize, |this| ((**(this as *const *const IEnumNames_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumNames", "Clone", 6usize, |this| ((**(this as *const *const IEnumNames_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumNames as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEventServerTrace_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartTraceGuid(this: *mut core::ffi::c_void, bstrguidevent: core::mem::MaybeUninit<windows_core::BSTR>, bstrguidfilter: core::mem::MaybeUninit<windows_core::BSTR>, lpidfilter: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventServerTrace", "StartTraceGuid", 7usize, |this| ((**(this as *const *const IEventServerTrace_Vtbl)).StartTraceGuid)(this, bstrguidevent, bstrguidfilter, lpidfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopTraceGuid(this: *mut core::ffi::c_void, bstrguidevent: core::mem::MaybeUninit<windows_core::BSTR>, bstrguidfilter: core::mem::MaybeUninit<windows_core::BSTR>, lpidfilter: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventServerTrace", "StopTraceGuid", 8usize, |this| ((**(this as *const *const IEventServerTrace_Vtbl)).StopTraceGuid)(this, bstrguidevent, bstrguidfilter, lpidfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumTraceGuid(this: *mut core::ffi::c_void, plcntguids: *mut i32, pbstrguidlist: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventServerTrace", "EnumTraceGuid", 9usize, |this| ((**(this as *const *const IEventServerTrace_Vtbl)).EnumTraceGuid)(this, plcntguids, pbstrguidlist), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartTraceGuid, StopTraceGuid, EnumTraceGuid }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEventServerTrace as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGetAppTrackerData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetApplicationProcesses(this: *mut core::ffi::c_void, partitionid: *const windows_core::GUID, applicationid: *const windows_core::GUID, flags: u32, numapplicationprocesses: *mut u32, applicationprocesses: *mut *mut ApplicationProcessSummary) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetAppTrackerData", "GetApplicationProcesses", 3usize, |this| ((**(this as *const *const IGetAppTrackerData_Vtbl)).GetApplicationProcesses)(this, partitionid, applicationid, flags, numapplicationprocesses, applicationprocesses), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetApplicationProcessDetails(this: *mut core::ffi::c_void, applicationinstanceid: *const windows_core::GUID, processid: u32, flags: u32, summary: *mut ApplicationProcessSummary, statistics: *mut ApplicationProcessStatistics, recycleinfo: *mut ApplicationProcessRecycleInfo, anycomponentshangmonitored: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetAppTrackerData", "GetApplicationProcessDetails", 4usize, |this| ((**(this as *const *const IGetAppTrackerData_Vtbl)).GetApplicationProcessDetails)(this, applicationinstanceid, processid, flags, summary, statistics, recycleinfo, anycomponentshangmonitored), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetApplicationsInProcess(this: *mut core::ffi::c_void, applicationinstanceid: *const windows_core::GUID, processid: u32, partitionid: *const windows_core::GUID, flags: u32, numapplicationsinprocess: *mut u32, applications: *mut *mut ApplicationSummary) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetAppTrackerData", "GetApplicationsInProcess", 5usize, |this| ((**(this as *const *const IGetAppTrackerData_Vtbl)).GetApplicationsInProcess)(this, applicationinstanceid, processid, partitionid, flags, numapplicationsinprocess, applications), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetComponentsInProcess(this: *mut core::ffi::c_void, applicationinstanceid: *const windows_core::GUID, processid: u32, partitionid: *const windows_core::GUID, applicationid: *const windows_core::GUID, flags: u32, numcomponentsinprocess: *mut u32, components: *mut *mut ComponentSummary) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetAppTrackerData", "GetComponentsInProcess", 6usize, |this| ((**(this as *const *const IGetAppTrackerData_Vtbl)).GetComponentsInProcess)(this, applicationinstanceid, processid, partitionid, applicationid, flags, numcomponentsinprocess, components), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetComponentDetails(this: *mut core::ffi::c_void, applicationinstanceid: *const windows_core::GUID, processid: u32, clsid: *const windows_core::GUID, flags: u32, summary: *mut ComponentSummary, statistics: *mut ComponentStatistics, hangmonitorinfo: *mut ComponentHangMonitorInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetAppTrackerData", "GetComponentDetails", 7usize, |this| ((**(this as *const *const IGetAppTrackerData_Vtbl)).GetComponentDetails)(this, applicationinstanceid, processid, clsid, flags, summary, statistics, hangmonitorinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTrackerDataAsCollectionObject(this: *mut core::ffi::c_void, toplevelcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetAppTrackerData", "GetTrackerDataAsCollectionObject", 8usize, |this| ((**(this as *const *const IGetAppTrackerData_Vtbl)).GetTrackerDataAsCollectionObject)(this, toplevelcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSuggestedPollingInterval(this: *mut core::ffi::c_void, pollingintervalinseconds: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetAppTrackerData", "GetSuggestedPollingInterval", 9usize, |this| ((**(this as *const *const IGetAppTrackerData_Vtbl)).GetSuggestedPollingInterval)(this, pollingintervalinseconds), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetApplicationProcesses,
+            GetApplicationProcessDetails,
+            GetApplicationsInProcess,
+            GetComponentsInProcess,
+            GetComponentDetails,
+            GetTrackerDataAsCollectionObject,
+            GetSuggestedPollingInterval,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGetAppTrackerData as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGetContextProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetContextProperties", "Count", 3usize, |this| ((**(this as *const *const IGetContextProperties_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, pproperty: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetContextProperties", "GetProperty", 4usize, |this| ((**(this as *const *const IGetContextProperties_Vtbl)).GetProperty)(this, name, pproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumNames(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetContextProperties", "EnumNames", 5usize, |this| ((**(this as *const *const IGetContextProperties_Vtbl)).EnumNames)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Count, GetProperty, EnumNames }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGetContextProperties as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGetSecurityCallContext_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSecurityCallContext(this: *mut core::ffi::c_void, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetSecurityCallContext", "GetSecurityCallContext", 7usize, |this| ((**(this as *const *const IGetSecurityCallContext_Vtbl)).GetSecurityCallContext)(this, ppobject), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSecurityCallContext,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSecurityCallContext: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGetSecurityCallContext as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHolder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AllocResource(this: *mut core::ffi::c_void, __midl__iholder0000: usize, __midl__iholder0001: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolder", "AllocResource", 3usize, |this| ((**(this as *const *const IHolder_Vtbl)).AllocResource)(this, __midl__iholder0000, __midl__iholder0001), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreeResource(this: *mut core::ffi::c_void, __midl__iholder0002: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolder", "FreeResource", 4usize, |this| ((**(this as *const *const IHolder_Vtbl)).FreeResource)(this, __midl__iholder0002), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrackResource(this: *mut core::ffi::c_void, __midl__iholder0003: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolder", "TrackResource", 5usize, |this| ((**(this as *const *const IHolder_Vtbl)).TrackResource)(this, __midl__iholder0003), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrackResourceS(this: *mut core::ffi::c_void, __midl__iholder0004: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolder", "TrackResourceS", 6usize, |this| ((**(this as *const *const IHolder_Vtbl)).TrackResourceS)(this, __midl__iholder0004), |result| Some(*result))
+        }
+        unsafe extern "system" fn UntrackResource(this: *mut core::ffi::c_void, __midl__iholder0005: usize, __midl__iholder0006: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolder", "UntrackResource", 7usize, |this| ((**(this as *const *const IHolder_Vtbl)).UntrackResource)(this, __midl__iholder0005, __midl__iholder0006), |result| Some(*result))
+        }
+        unsafe extern "system" fn UntrackResourceS(this: *mut core::ffi::c_void, __midl__iholder0007: *mut u16, __midl__iholder0008: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolder", "UntrackResourceS", 8usize, |this| ((**(this as *const *const IHolder_Vtbl)).UntrackResourceS)(this, __midl__iholder0007, __midl__iholder0008), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolder", "Close", 9usize, |this| ((**(this as *const *const IHolder_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestDestroyResource(this: *mut core::ffi::c_void, __midl__iholder0009: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolder", "RequestDestroyResource", 10usize, |this| ((**(this as *const *const IHolder_Vtbl)).RequestDestroyResource)(this, __midl__iholder0009), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AllocResource,
+            FreeResource,
+            TrackResource,
+            TrackResourceS,
+            UntrackResource,
+            UntrackResourceS,
+            Close,
+            RequestDestroyResource,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILBEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TargetUp(this: *mut core::ffi::c_void, bstrservername: core::mem::MaybeUninit<windows_core::BSTR>, bstrclsideng: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILBEvents", "TargetUp", 3usize, |this| ((**(this as *const *const ILBEvents_Vtbl)).TargetUp)(this, bstrservername, bstrclsideng), |result| Some(*result))
+        }
+        unsafe extern "system" fn TargetDown(this: *mut core::ffi::c_void, bstrservername: core::mem::MaybeUninit<windows_core::BSTR>, bstrclsideng: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILBEvents", "TargetDown", 4usize, |this| ((**(this as *const *const ILBEvents_Vtbl)).TargetDown)(this, bstrservername, bstrclsideng), |result| Some(*result))
+        }
+        unsafe extern "system" fn EngineDefined(this: *mut core::ffi::c_void, bstrpropname: core::mem::MaybeUninit<windows_core::BSTR>, varpropvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>, bstrclsideng: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILBEvents", "EngineDefined", 5usize, |this| ((**(this as *const *const ILBEvents_Vtbl)).EngineDefined)(this, bstrpropname, varpropvalue, bstrclsideng), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TargetUp, TargetDown, EngineDefined }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILBEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMTSActivity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SynchronousCall(this: *mut core::ffi::c_void, pcall: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMTSActivity", "SynchronousCall", 3usize, |this| ((**(this as *const *const IMTSActivity_Vtbl)).SynchronousCall)(this, pcall), |result| Some(*result))
+        }
+        unsafe extern "system" fn AsyncCall(this: *mut core::ffi::c_void, pcall: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMTSActivity", "AsyncCall", 4usize, |this| ((**(this as *const *const IMTSActivity_Vtbl)).AsyncCall)(this, pcall), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reserved1(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IMTSActivity", "Reserved1", 5usize, |this| ((**(this as *const *const IMTSActivity_Vtbl)).Reserved1)(this), |_| None)
+        }
+        unsafe extern "system" fn BindToCurrentThread(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMTSActivity", "BindToCurrentThread", 6usize, |this| ((**(this as *const *const IMTSActivity_Vtbl)).BindToCurrentThread)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnbindFromThread(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMTSActivity", "UnbindFromThread", 7usize, |this| ((**(this as *const *const IMTSActivity_Vtbl)).UnbindFromThread)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SynchronousCall,
+            AsyncCall,
+            Reserved1,
+            BindToCurrentThread,
+            UnbindFromThread,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMTSActivity as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMTSCall_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnCall(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMTSCall", "OnCall", 3usize, |this| ((**(this as *const *const IMTSCall_Vtbl)).OnCall)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnCall }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMTSCall as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMTSLocator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEventDispatcher(this: *mut core::ffi::c_void, punk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMTSLocator", "GetEventDispatcher", 7usize, |this| ((**(this as *const *const IMTSLocator_Vtbl)).GetEventDispatcher)(this, punk), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetEventDispatcher }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMTSLocator as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IManagedActivationEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateManagedStub(this: *mut core::ffi::c_void, pinfo: *mut core::ffi::c_void, fdist: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManagedActivationEvents", "CreateManagedStub", 3usize, |this| ((**(this as *const *const IManagedActivationEvents_Vtbl)).CreateManagedStub)(this, pinfo, fdist), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestroyManagedStub(this: *mut core::ffi::c_void, pinfo: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManagedActivationEvents", "DestroyManagedStub", 4usize, |this| ((**(this as *const *const IManagedActivationEvents_Vtbl)).DestroyManagedStub)(this, pinfo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateManagedStub, DestroyManagedStub }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IManagedActivationEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IManagedObjectInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetIUnknown(this: *mut core::ffi::c_void, punk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManagedObjectInfo", "GetIUnknown", 3usize, |this| ((**(this as *const *const IManagedObjectInfo_Vtbl)).GetIUnknown)(this, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIObjectControl(this: *mut core::ffi::c_void, pctrl: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManagedObjectInfo", "GetIObjectControl", 4usize, |this| ((**(this as *const *const IManagedObjectInfo_Vtbl)).GetIObjectControl)(this, pctrl), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInPool(this: *mut core::ffi::c_void, binpool: super::super::Foundation::BOOL, ppooledobj: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManagedObjectInfo", "SetInPool", 5usize, |this| ((**(this as *const *const IManagedObjectInfo_Vtbl)).SetInPool)(this, binpool, ppooledobj), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWrapperStrength(this: *mut core::ffi::c_void, bstrong: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManagedObjectInfo", "SetWrapperStrength", 6usize, |this| ((**(this as *const *const IManagedObjectInfo_Vtbl)).SetWrapperStrength)(this, bstrong), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetIUnknown, GetIObjectControl, SetInPool, SetWrapperStrength }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IManagedObjectInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IManagedPoolAction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LastRelease(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManagedPoolAction", "LastRelease", 3usize, |this| ((**(this as *const *const IManagedPoolAction_Vtbl)).LastRelease)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LastRelease }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IManagedPoolAction as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IManagedPooledObj_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetHeld(this: *mut core::ffi::c_void, m_bheld: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManagedPooledObj", "SetHeld", 3usize, |this| ((**(this as *const *const IManagedPooledObj_Vtbl)).SetHeld)(this, m_bheld), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetHeld }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IManagedPooledObj as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMessageMover_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SourcePath(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageMover", "SourcePath", 7usize, |this| ((**(this as *const *const IMessageMover_Vtbl)).SourcePath)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSourcePath(this: *mut core::ffi::c_void, newval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageMover", "SetSourcePath", 8usize, |this| ((**(this as *const *const IMessageMover_Vtbl)).SetSourcePath)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestPath(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageMover", "DestPath", 9usize, |this| ((**(this as *const *const IMessageMover_Vtbl)).DestPath)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDestPath(this: *mut core::ffi::c_void, newval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageMover", "SetDestPath", 10usize, |this| ((**(this as *const *const IMessageMover_Vtbl)).SetDestPath)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommitBatchSize(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageMover", "CommitBatchSize", 11usize, |this| ((**(this as *const *const IMessageMover_Vtbl)).CommitBatchSize)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCommitBatchSize(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageMover", "SetCommitBatchSize", 12usize, |this| ((**(this as *const *const IMessageMover_Vtbl)).SetCommitBatchSize)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveMessages(this: *mut core::ffi::c_void, plmessagesmoved: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageMover", "MoveMessages", 13usize, |this| ((**(this as *const *const IMessageMover_Vtbl)).MoveMessages)(this, plmessagesmoved), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SourcePath,
+            SetSourcePath,
+            DestPath,
+            SetDestPath,
+            CommitBatchSize,
+            SetCommitBatchSize,
+            MoveMessages,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMessageMover as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMtsEventInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Names(this: *mut core::ffi::c_void, punk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMtsEventInfo", "Names", 7usize, |this| ((**(this as *const *const IMtsEventInfo_Vtbl)).Names)(this, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, sdisplayname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMtsEventInfo", "DisplayName", 8usize, |this| ((**(this as *const *const IMtsEventInfo_Vtbl)).DisplayName)(this, sdisplayname), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventID(this: *mut core::ffi::c_void, sguideventid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMtsEventInfo", "EventID", 9usize, |this| ((**(this as *const *const IMtsEventInfo_Vtbl)).EventID)(this, sguideventid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, lcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMtsEventInfo", "Count", 10usize, |this| ((**(this as *const *const IMtsEventInfo_Vtbl)).Count)(this, lcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Value(this: *mut core::ffi::c_void, skey: core::mem::MaybeUninit<windows_core::BSTR>, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMtsEventInfo", "get_Value", 11usize, |this| ((**(this as *const *const IMtsEventInfo_Vtbl)).get_Value)(this, skey, pval), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Names, DisplayName, EventID, Count, get_Value }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMtsEventInfo as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMtsEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PackageName(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMtsEvents", "PackageName", 7usize, |this| ((**(this as *const *const IMtsEvents_Vtbl)).PackageName)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PackageGuid(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMtsEvents", "PackageGuid", 8usize, |this| ((**(this as *const *const IMtsEvents_Vtbl)).PackageGuid)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostEvent(this: *mut core::ffi::c_void, vevent: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMtsEvents", "PostEvent", 9usize, |this| ((**(this as *const *const IMtsEvents_Vtbl)).PostEvent)(this, vevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn FireEvents(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMtsEvents", "FireEvents", 10usize, |this| ((**(this as *const *const IMtsEvents_Vtbl)).FireEvents)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProcessID(this: *mut core::ffi::c_void, id: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMtsEvents", "GetProcessID", 11usize, |this| ((**(this as *const *const IMtsEvents_Vtbl)).GetProcessID)(this, id), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PackageName, PackageGuid, PostEvent, FireEvents, GetProcessID }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMtsEvents as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMtsGrp_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMtsGrp", "Count", 7usize, |this| ((**(this as *const *const IMtsGrp_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, lindex: i32, ppunkdispatcher: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMtsGrp", "Item", 8usize, |this| ((**(this as *const *const IMtsGrp_Vtbl)).Item)(this, lindex, ppunkdispatcher), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMtsGrp", "Refresh", 9usize, |this| ((**(this as *const *const IMtsGrp_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Count, Item, Refresh }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMtsGrp as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IObjPool_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reserved1(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IObjPool", "Reserved1", 3usize, |this| ((**(this as *const *const IObjPool_Vtbl)).Reserved1)(this), |_| None)
+        }
+        unsafe extern "system" fn Reserved2(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IObjPool", "Reserved2", 4usize, |this| ((**(this as *const *const IObjPool_Vtbl)).Reserved2)(this), |_| None)
+        }
+        unsafe extern "system" fn Reserved3(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IObjPool", "Reserved3", 5usize, |this| ((**(this as *const *const IObjPool_Vtbl)).Reserved3)(this), |_| None)
+        }
+        unsafe extern "system" fn Reserved4(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IObjPool", "Reserved4", 6usize, |this| ((**(this as *const *const IObjPool_Vtbl)).Reserved4)(this), |_| None)
+        }
+        unsafe extern "system" fn PutEndTx(this: *mut core::ffi::c_void, pobj: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IObjPool", "PutEndTx", 7usize, |this| ((**(this as *const *const IObjPool_Vtbl)).PutEndTx)(this, pobj), |_| None)
+        }
+        unsafe extern "system" fn Reserved5(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IObjPool", "Reserved5", 8usize, |this| ((**(this as *const *const IObjPool_Vtbl)).Reserved5)(this), |_| None)
+        }
+        unsafe extern "system" fn Reserved6(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IObjPool", "Reserved6", 9usize, |this| ((**(this as *const *const IObjPool_Vtbl)).Reserved6)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Reserved1,
+            Reserved2,
+            Reserved3,
+            Reserved4,
+            PutEndTx,
+            Reserved5,
+            Reserved6,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IObjPool as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IObjectConstruct_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Construct(this: *mut core::ffi::c_void, pctorobj: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectConstruct", "Construct", 3usize, |this| ((**(this as *const *const IObjectConstruct_Vtbl)).Construct)(this, pctorobj), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Construct,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Construct: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IObjectConstruct as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IObjectConstructString_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConstructString(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectConstructString", "ConstructString", 7usize, |this| ((**(this as *const *const IObjectConstructString_Vtbl)).ConstructString)(this, pval), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ConstructString }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IObjectConstructString as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IObjectContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectContext", "CreateInstance", 3usize, |this| ((**(this as *const *const IObjectContext_Vtbl)).CreateInstance)(this, rclsid, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetComplete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectContext", "SetComplete", 4usize, |this| ((**(this as *const *const IObjectContext_Vtbl)).SetComplete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAbort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectContext", "SetAbort", 5usize, |this| ((**(this as *const *const IObjectContext_Vtbl)).SetAbort)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableCommit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectContext", "EnableCommit", 6usize, |this| ((**(this as *const *const IObjectContext_Vtbl)).EnableCommit)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableCommit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectContext", "DisableCommit", 7usize, |this| ((**(this as *const *const IObjectContext_Vtbl)).DisableCommit)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInTransaction(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IObjectContext", "IsInTransaction", 8usize, |this| ((**(this as *const *const IObjectContext_Vtbl)).IsInTransaction)(this), |_| None)
+        }
+        unsafe extern "system" fn IsSecurityEnabled(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IObjectContext", "IsSecurityEnabled", 9usize, |this| ((**(this as *const *const IObjectContext_Vtbl)).IsSecurityEnabled)(this), |_| None)
+        }
+        unsafe extern "system" fn IsCallerInRole(this: *mut core::ffi::c_void, bstrrole: core::mem::MaybeUninit<windows_core::BSTR>, pfisinrole: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectContext", "IsCallerInRole", 10usize, |this| ((**(this as *const *const IObjectContext_Vtbl)).IsCallerInRole)(this, bstrrole, pfisinrole), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateInstance,
+            SetComplete,
+            SetAbort,
+            EnableCommit,
+            DisableCommit,
+            IsInTransaction,
+            IsSecurityEnabled,
+            IsCallerInRole,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IObjectContext as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IObjectContextActivity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetActivityId(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectContextActivity", "GetActivityId", 3usize, |this| ((**(this as *const *const IObjectContextActivity_Vtbl)).GetActivityId)(this, pguid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetActivityId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IObjectContextActivity as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IObjectContextInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsInTransaction(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IObjectContextInfo", "IsInTransaction", 3usize, |this| ((**(this as *const *const IObjectContextInfo_Vtbl)).IsInTransaction)(this), |_| None)
+        }
+        unsafe extern "system" fn GetTransaction(this: *mut core::ffi::c_void, pptrans: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectContextInfo", "GetTransaction", 4usize, |this| ((**(this as *const *const IObjectContextInfo_Vtbl)).GetTransaction)(this, pptrans), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransactionId(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectContextInfo", "GetTransactionId", 5usize, |this| ((**(this as *const *const IObjectContextInfo_Vtbl)).GetTransactionId)(this, pguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActivityId(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectContextInfo", "GetActivityId", 6usize, |this| ((**(this as *const *const IObjectContextInfo_Vtbl)).GetActivityId)(this, pguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContextId(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectContextInfo", "GetContextId", 7usize, |this| ((**(this as *const *const IObjectContextInfo_Vtbl)).GetContextId)(this, pguid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsInTransaction,
+            GetTransaction,
+            GetTransactionId,
+            GetActivityId,
+            GetContextId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IObjectContextInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IObjectContextInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPartitionId(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectContextInfo2", "GetPartitionId", 8usize, |this| ((**(this as *const *const IObjectContextInfo2_Vtbl)).GetPartitionId)(this, pguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetApplicationId(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectContextInfo2", "GetApplicationId", 9usize, |this| ((**(this as *const *const IObjectContextInfo2_Vtbl)).GetApplicationId)(this, pguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetApplicationInstanceId(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectContextInfo2", "GetApplicationInstanceId", 10usize, |this| ((**(this as *const *const IObjectContextInfo2_Vtbl)).GetApplicationInstanceId)(this, pguid), |result| Some(*result))
+        }
+        Self { base__: <IObjectContextInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPartitionId, GetApplicationId, GetApplicationInstanceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IObjectContextInfo2 as windows_core::Interface>::IID || <IObjectContextInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IObjectContextTip_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTipUrl(this: *mut core::ffi::c_void, ptipurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectContextTip", "GetTipUrl", 3usize, |this| ((**(this as *const *const IObjectContextTip_Vtbl)).GetTipUrl)(this, ptipurl), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetTipUrl }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IObjectContextTip as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IObjectControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Activate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectControl", "Activate", 3usize, |this| ((**(this as *const *const IObjectControl_Vtbl)).Activate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deactivate(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IObjectControl", "Deactivate", 4usize, |this| ((**(this as *const *const IObjectControl_Vtbl)).Deactivate)(this), |_| None)
+        }
+        unsafe extern "system" fn CanBePooled(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IObjectControl", "CanBePooled", 5usize, |this| ((**(this as *const *const IObjectControl_Vtbl)).CanBePooled)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Activate, Deactivate, CanBePooled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IObjectControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlaybackControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FinalClientRetry(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaybackControl", "FinalClientRetry", 3usize, |this| ((**(this as *const *const IPlaybackControl_Vtbl)).FinalClientRetry)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn FinalServerRetry(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlaybackControl", "FinalServerRetry", 4usize, |this| ((**(this as *const *const IPlaybackControl_Vtbl)).FinalServerRetry)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FinalClientRetry, FinalServerRetry }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlaybackControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPoolManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShutdownPool(this: *mut core::ffi::c_void, clsidorprogid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPoolManager", "ShutdownPool", 7usize, |this| ((**(this as *const *const IPoolManager_Vtbl)).ShutdownPool)(this, clsidorprogid), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShutdownPool }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPoolManager as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProcessInitializer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Startup(this: *mut core::ffi::c_void, punkprocesscontrol: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessInitializer", "Startup", 3usize, |this| ((**(this as *const *const IProcessInitializer_Vtbl)).Startup)(this, punkprocesscontrol), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessInitializer", "Shutdown", 4usize, |this| ((**(this as *const *const IProcessInitializer_Vtbl)).Shutdown)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Startup, Shutdown }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProcessInitializer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISecurityCallContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityCallContext", "Count", 7usize, |this| ((**(this as *const *const ISecurityCallContext_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, pitem: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityCallContext", "get_Item", 8usize, |this| ((**(this as *const *const ISecurityCallContext_Vtbl)).get_Item)(this, name, pitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityCallContext", "_NewEnum", 9usize, |this| ((**(this as *const *const ISecurityCallContext_Vtbl))._NewEnum)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCallerInRole(this: *mut core::ffi::c_void, bstrrole: core::mem::MaybeUninit<windows_core::BSTR>, pfinrole: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityCallContext", "IsCallerInRole", 10usize, |this| ((**(this as *const *const ISecurityCallContext_Vtbl)).IsCallerInRole)(this, bstrrole, pfinrole), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSecurityEnabled(this: *mut core::ffi::c_void, pfisenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityCallContext", "IsSecurityEnabled", 11usize, |this| ((**(this as *const *const ISecurityCallContext_Vtbl)).IsSecurityEnabled)(this, pfisenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUserInRole(this: *mut core::ffi::c_void, puser: *const core::mem::MaybeUninit<windows_core::VARIANT>, bstrrole: core::mem::MaybeUninit<windows_core::BSTR>, pfinrole: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityCallContext", "IsUserInRole", 12usize, |this| ((**(this as *const *const ISecurityCallContext_Vtbl)).IsUserInRole)(this, puser, bstrrole, pfinrole), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            get_Item,
+            _NewEnum,
+            IsCallerInRole,
+            IsSecurityEnabled,
+            IsUserInRole,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISecurityCallContext as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISecurityCallersColl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityCallersColl", "Count", 7usize, |this| ((**(this as *const *const ISecurityCallersColl_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, lindex: i32, pobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityCallersColl", "get_Item", 8usize, |this| ((**(this as *const *const ISecurityCallersColl_Vtbl)).get_Item)(this, lindex, pobj), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityCallersColl", "_NewEnum", 9usize, |this| ((**(this as *const *const ISecurityCallersColl_Vtbl))._NewEnum)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISecurityCallersColl as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISecurityIdentityColl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityIdentityColl", "Count", 7usize, |this| ((**(this as *const *const ISecurityIdentityColl_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, pitem: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityIdentityColl", "get_Item", 8usize, |this| ((**(this as *const *const ISecurityIdentityColl_Vtbl)).get_Item)(this, name, pitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityIdentityColl", "_NewEnum", 9usize, |this| ((**(this as *const *const ISecurityIdentityColl_Vtbl))._NewEnum)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Count, get_Item, _NewEnum }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISecurityIdentityColl as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Security")]
+impl windows_core::imp::ProxyVtable for ISecurityProperty_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Security")]
+        unsafe extern "system" fn GetDirectCreatorSID(this: *mut core::ffi::c_void, psid: *mut super::super::Security::PSID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityProperty", "GetDirectCreatorSID", 3usize, |this| ((**(this as *const *const ISecurityProperty_Vtbl)).GetDirectCreatorSID)(this, psid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Security")]
+        unsafe extern "system" fn GetOriginalCreatorSID(this: *mut core::ffi::c_void, psid: *mut super::super::Security::PSID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityProperty", "GetOriginalCreatorSID", 4usize, |this| ((**(this as *const *const ISecurityProperty_Vtbl)).GetOriginalCreatorSID)(this, psid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Security")]
+        unsafe extern "system" fn GetDirectCallerSID(this: *mut core::ffi::c_void, psid: *mut super::super::Security::PSID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityProperty", "GetDirectCallerSID", 5usize, |this| ((**(this as *const *const ISecurityProperty_Vtbl)).GetDirectCallerSID)(this, psid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Security")]
+        unsafe extern "system" fn GetOriginalCallerSID(this: *mut core::ffi::c_void, psid: *mut super::super::Security::PSID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityProperty", "GetOriginalCallerSID", 6usize, |this| ((**(this as *const *const ISecurityProperty_Vtbl)).GetOriginalCallerSID)(this, psid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Security")]
+        unsafe extern "system" fn ReleaseSID(this: *mut core::ffi::c_void, psid: super::super::Security::PSID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityProperty", "ReleaseSID", 7usize, |this| ((**(this as *const *const ISecurityProperty_Vtbl)).ReleaseSID)(this, psid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Security")]
+            GetDirectCreatorSID,
+            #[cfg(not(feature = "Win32_Security"))]
+            GetDirectCreatorSID: 0,
+            #[cfg(feature = "Win32_Security")]
+            GetOriginalCreatorSID,
+            #[cfg(not(feature = "Win32_Security"))]
+            GetOriginalCreatorSID: 0,
+            #[cfg(feature = "Win32_Security")]
+            GetDirectCallerSID,
+            #[cfg(not(feature = "Win32_Security"))]
+            GetDirectCallerSID: 0,
+            #[cfg(feature = "Win32_Security")]
+            GetOriginalCallerSID,
+            #[cfg(not(feature = "Win32_Security"))]
+            GetOriginalCallerSID: 0,
+            #[cfg(feature = "Win32_Security")]
+            ReleaseSID,
+            #[cfg(not(feature = "Win32_Security"))]
+            ReleaseSID: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISecurityProperty as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISelectCOMLBServer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Init(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISelectCOMLBServer", "Init", 3usize, |this| ((**(this as *const *const ISelectCOMLBServer_Vtbl)).Init)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLBServer(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISelectCOMLBServer", "GetLBServer", 4usize, |this| ((**(this as *const *const ISelectCOMLBServer_Vtbl)).GetLBServer)(this, punk), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Init, GetLBServer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISelectCOMLBServer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISendMethodEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SendMethodCall(this: *mut core::ffi::c_void, pidentity: *const core::ffi::c_void, riid: *const windows_core::GUID, dwmeth: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISendMethodEvents", "SendMethodCall", 3usize, |this| ((**(this as *const *const ISendMethodEvents_Vtbl)).SendMethodCall)(this, pidentity, riid, dwmeth), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendMethodReturn(this: *mut core::ffi::c_void, pidentity: *const core::ffi::c_void, riid: *const windows_core::GUID, dwmeth: u32, hrcall: windows_core::HRESULT, hrserver: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISendMethodEvents", "SendMethodReturn", 4usize, |this| ((**(this as *const *const ISendMethodEvents_Vtbl)).SendMethodReturn)(this, pidentity, riid, dwmeth, hrcall, hrserver), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SendMethodCall, SendMethodReturn }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISendMethodEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IServiceActivity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SynchronousCall(this: *mut core::ffi::c_void, piservicecall: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceActivity", "SynchronousCall", 3usize, |this| ((**(this as *const *const IServiceActivity_Vtbl)).SynchronousCall)(this, piservicecall), |result| Some(*result))
+        }
+        unsafe extern "system" fn AsynchronousCall(this: *mut core::ffi::c_void, piservicecall: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceActivity", "AsynchronousCall", 4usize, |this| ((**(this as *const *const IServiceActivity_Vtbl)).AsynchronousCall)(this, piservicecall), |result| Some(*result))
+        }
+        unsafe extern "system" fn BindToCurrentThread(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceActivity", "BindToCurrentThread", 5usize, |this| ((**(this as *const *const IServiceActivity_Vtbl)).BindToCurrentThread)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnbindFromThread(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceActivity", "UnbindFromThread", 6usize, |this| ((**(this as *const *const IServiceActivity_Vtbl)).UnbindFromThread)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SynchronousCall,
+            AsynchronousCall,
+            BindToCurrentThread,
+            UnbindFromThread,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServiceActivity as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IServiceCall_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnCall(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceCall", "OnCall", 3usize, |this| ((**(this as *const *const IServiceCall_Vtbl)).OnCall)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnCall }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServiceCall as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IServiceComTIIntrinsicsConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ComTIIntrinsicsConfig(this: *mut core::ffi::c_void, comtiintrinsicsconfig: CSC_COMTIIntrinsicsConfig) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceComTIIntrinsicsConfig", "ComTIIntrinsicsConfig", 3usize, |this| ((**(this as *const *const IServiceComTIIntrinsicsConfig_Vtbl)).ComTIIntrinsicsConfig)(this, comtiintrinsicsconfig), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ComTIIntrinsicsConfig }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServiceComTIIntrinsicsConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IServiceIISIntrinsicsConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IISIntrinsicsConfig(this: *mut core::ffi::c_void, iisintrinsicsconfig: CSC_IISIntrinsicsConfig) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceIISIntrinsicsConfig", "IISIntrinsicsConfig", 3usize, |this| ((**(this as *const *const IServiceIISIntrinsicsConfig_Vtbl)).IISIntrinsicsConfig)(this, iisintrinsicsconfig), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IISIntrinsicsConfig }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServiceIISIntrinsicsConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IServiceInheritanceConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContainingContextTreatment(this: *mut core::ffi::c_void, inheritanceconfig: CSC_InheritanceConfig) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceInheritanceConfig", "ContainingContextTreatment", 3usize, |this| ((**(this as *const *const IServiceInheritanceConfig_Vtbl)).ContainingContextTreatment)(this, inheritanceconfig), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ContainingContextTreatment }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServiceInheritanceConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IServicePartitionConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PartitionConfig(this: *mut core::ffi::c_void, partitionconfig: CSC_PartitionConfig) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServicePartitionConfig", "PartitionConfig", 3usize, |this| ((**(this as *const *const IServicePartitionConfig_Vtbl)).PartitionConfig)(this, partitionconfig), |result| Some(*result))
+        }
+        unsafe extern "system" fn PartitionID(this: *mut core::ffi::c_void, guidpartitionid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServicePartitionConfig", "PartitionID", 4usize, |this| ((**(this as *const *const IServicePartitionConfig_Vtbl)).PartitionID)(this, guidpartitionid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PartitionConfig, PartitionID }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServicePartitionConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IServicePool_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, ppoolconfig: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServicePool", "Initialize", 3usize, |this| ((**(this as *const *const IServicePool_Vtbl)).Initialize)(this, ppoolconfig), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObject(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServicePool", "GetObject", 4usize, |this| ((**(this as *const *const IServicePool_Vtbl)).GetObject)(this, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServicePool", "Shutdown", 5usize, |this| ((**(this as *const *const IServicePool_Vtbl)).Shutdown)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, GetObject, Shutdown }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServicePool as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IServicePoolConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMaxPoolSize(this: *mut core::ffi::c_void, dwmaxpool: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServicePoolConfig", "SetMaxPoolSize", 3usize, |this| ((**(this as *const *const IServicePoolConfig_Vtbl)).SetMaxPoolSize)(this, dwmaxpool), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxPoolSize(this: *mut core::ffi::c_void, pdwmaxpool: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServicePoolConfig", "MaxPoolSize", 4usize, |this| ((**(this as *const *const IServicePoolConfig_Vtbl)).MaxPoolSize)(this, pdwmaxpool), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinPoolSize(this: *mut core::ffi::c_void, dwminpool: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServicePoolConfig", "SetMinPoolSize", 5usize, |this| ((**(this as *const *const IServicePoolConfig_Vtbl)).SetMinPoolSize)(this, dwminpool), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinPoolSize(this: *mut core::ffi::c_void, pdwminpool: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServicePoolConfig", "MinPoolSize", 6usize, |this| ((**(this as *const *const IServicePoolConfig_Vtbl)).MinPoolSize)(this, pdwminpool), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCreationTimeout(this: *mut core::ffi::c_void, dwcreationtimeout: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServicePoolConfig", "SetCreationTimeout", 7usize, |this| ((**(this as *const *const IServicePoolConfig_Vtbl)).SetCreationTimeout)(this, dwcreationtimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreationTimeout(this: *mut core::ffi::c_void, pdwcreationtimeout: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServicePoolConfig", "CreationTimeout", 8usize, |this| ((**(this as *const *const IServicePoolConfig_Vtbl)).CreationTimeout)(this, pdwcreationtimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransactionAffinity(this: *mut core::ffi::c_void, ftxaffinity: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServicePoolConfig", "SetTransactionAffinity", 9usize, |this| ((**(this as *const *const IServicePoolConfig_Vtbl)).SetTransactionAffinity)(this, ftxaffinity), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransactionAffinity(this: *mut core::ffi::c_void, pftxaffinity: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServicePoolConfig", "TransactionAffinity", 10usize, |this| ((**(this as *const *const IServicePoolConfig_Vtbl)).TransactionAffinity)(this, pftxaffinity), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetClassFactory(this: *mut core::ffi::c_void, pfactory: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServicePoolConfig", "SetClassFactory", 11usize, |this| ((**(this as *const *const IServicePoolConfig_Vtbl)).SetClassFactory)(this, pfactory), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ClassFactory(this: *mut core::ffi::c_void, pfactory: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServicePoolConfig", "ClassFactory", 12usize, |this| ((**(this as *const *const IServicePoolConfig_Vtbl)).ClassFactory)(this, pfactory), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetMaxPoolSize,
+            MaxPoolSize,
+            SetMinPoolSize,
+            MinPoolSize,
+            SetCreationTimeout,
+            CreationTimeout,
+            SetTransactionAffinity,
+            TransactionAffinity,
+            #[cfg(feature = "Win32_System_Com")]
+            SetClassFactory,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetClassFactory: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ClassFactory,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ClassFactory: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServicePoolConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IServiceSxsConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SxsConfig(this: *mut core::ffi::c_void, scsconfig: CSC_SxsConfig) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceSxsConfig", "SxsConfig", 3usize, |this| ((**(this as *const *const IServiceSxsConfig_Vtbl)).SxsConfig)(this, scsconfig), |result| Some(*result))
+        }
+        unsafe extern "system" fn SxsName(this: *mut core::ffi::c_void, szsxsname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceSxsConfig", "SxsName", 4usize, |this| ((**(this as *const *const IServiceSxsConfig_Vtbl)).SxsName)(this, szsxsname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SxsDirectory(this: *mut core::ffi::c_void, szsxsdirectory: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceSxsConfig", "SxsDirectory", 5usize, |this| ((**(this as *const *const IServiceSxsConfig_Vtbl)).SxsDirectory)(this, szsxsdirectory), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SxsConfig, SxsName, SxsDirectory }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServiceSxsConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IServiceSynchronizationConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConfigureSynchronization(this: *mut core::ffi::c_void, synchconfig: CSC_SynchronizationConfig) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceSynchronizationConfig", "ConfigureSynchronization", 3usize, |this| ((**(this as *const *const IServiceSynchronizationConfig_Vtbl)).ConfigureSynchronization)(this, synchconfig), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ConfigureSynchronization }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServiceSynchronizationConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_DistributedTransactionCoordinator")]
+impl windows_core::imp::ProxyVtable for IServiceSysTxnConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConfigureBYOTSysTxn(this: *mut core::ffi::c_void, ptxproxy: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceSysTxnConfig", "ConfigureBYOTSysTxn", 9usize, |this| ((**(this as *const *const IServiceSysTxnConfig_Vtbl)).ConfigureBYOTSysTxn)(this, ptxproxy), |result| Some(*result))
+        }
+        Self { base__: <IServiceTransactionConfig_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ConfigureBYOTSysTxn }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServiceSysTxnConfig as windows_core::Interface>::IID || <IServiceTransactionConfig_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IServiceThreadPoolConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SelectThreadPool(this: *mut core::ffi::c_void, threadpool: CSC_ThreadPool) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceThreadPoolConfig", "SelectThreadPool", 3usize, |this| ((**(this as *const *const IServiceThreadPoolConfig_Vtbl)).SelectThreadPool)(this, threadpool), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBindingInfo(this: *mut core::ffi::c_void, binding: CSC_Binding) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceThreadPoolConfig", "SetBindingInfo", 4usize, |this| ((**(this as *const *const IServiceThreadPoolConfig_Vtbl)).SetBindingInfo)(this, binding), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SelectThreadPool, SetBindingInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServiceThreadPoolConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IServiceTrackerConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TrackerConfig(this: *mut core::ffi::c_void, trackerconfig: CSC_TrackerConfig, sztrackerappname: windows_core::PCWSTR, sztrackerctxname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceTrackerConfig", "TrackerConfig", 3usize, |this| ((**(this as *const *const IServiceTrackerConfig_Vtbl)).TrackerConfig)(this, trackerconfig, sztrackerappname, sztrackerctxname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TrackerConfig }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServiceTrackerConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_DistributedTransactionCoordinator")]
+impl windows_core::imp::ProxyVtable for IServiceTransactionConfig_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_DistributedTransactionCoordinator")]
+        unsafe extern "system" fn ConfigureBYOT(this: *mut core::ffi::c_void, pitxbyot: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceTransactionConfig", "ConfigureBYOT", 8usize, |this| ((**(this as *const *const IServiceTransactionConfig_Vtbl)).ConfigureBYOT)(this, pitxbyot), |result| Some(*result))
+        }
+        Self {
+            base__: <IServiceTransactionConfigBase_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_DistributedTransactionCoordinator")]
+            ConfigureBYOT,
+            #[cfg(not(feature = "Win32_System_DistributedTransactionCoordinator"))]
+            ConfigureBYOT: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServiceTransactionConfig as windows_core::Interface>::IID || <IServiceTransactionConfigBase_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IServiceTransactionConfigBase_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConfigureTransaction(this: *mut core::ffi::c_void, transactionconfig: CSC_TransactionConfig) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceTransactionConfigBase", "ConfigureTransaction", 3usize, |this| ((**(this as *const *const IServiceTransactionConfigBase_Vtbl)).ConfigureTransaction)(this, transactionconfig), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsolationLevel(this: *mut core::ffi::c_void, option: COMAdminTxIsolationLevelOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceTransactionConfigBase", "IsolationLevel", 4usize, |this| ((**(this as *const *const IServiceTransactionConfigBase_Vtbl)).IsolationLevel)(this, option), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransactionTimeout(this: *mut core::ffi::c_void, ultimeoutsec: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceTransactionConfigBase", "TransactionTimeout", 5usize, |this| ((**(this as *const *const IServiceTransactionConfigBase_Vtbl)).TransactionTimeout)(this, ultimeoutsec), |result| Some(*result))
+        }
+        unsafe extern "system" fn BringYourOwnTransaction(this: *mut core::ffi::c_void, sztipurl: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceTransactionConfigBase", "BringYourOwnTransaction", 6usize, |this| ((**(this as *const *const IServiceTransactionConfigBase_Vtbl)).BringYourOwnTransaction)(this, sztipurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn NewTransactionDescription(this: *mut core::ffi::c_void, sztxdesc: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IServiceTransactionConfigBase", "NewTransactionDescription", 7usize, |this| ((**(this as *const *const IServiceTransactionConfigBase_Vtbl)).NewTransactionDescription)(this, sztxdesc), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ConfigureTransaction,
+            IsolationLevel,
+            TransactionTimeout,
+            BringYourOwnTransaction,
+            NewTransactionDescription,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IServiceTransactionConfigBase as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISharedProperty_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedProperty", "Value", 7usize, |this| ((**(this as *const *const ISharedProperty_Vtbl)).Value)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, val: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedProperty", "SetValue", 8usize, |this| ((**(this as *const *const ISharedProperty_Vtbl)).SetValue)(this, val), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Value, SetValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISharedProperty as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISharedPropertyGroup_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreatePropertyByPosition(this: *mut core::ffi::c_void, index: i32, fexists: *mut super::super::Foundation::VARIANT_BOOL, ppprop: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedPropertyGroup", "CreatePropertyByPosition", 7usize, |this| ((**(this as *const *const ISharedPropertyGroup_Vtbl)).CreatePropertyByPosition)(this, index, fexists, ppprop), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_PropertyByPosition(this: *mut core::ffi::c_void, index: i32, ppproperty: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedPropertyGroup", "get_PropertyByPosition", 8usize, |this| ((**(this as *const *const ISharedPropertyGroup_Vtbl)).get_PropertyByPosition)(this, index, ppproperty), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateProperty(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, fexists: *mut super::super::Foundation::VARIANT_BOOL, ppprop: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedPropertyGroup", "CreateProperty", 9usize, |this| ((**(this as *const *const ISharedPropertyGroup_Vtbl)).CreateProperty)(this, name, fexists, ppprop), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Property(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, ppproperty: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedPropertyGroup", "get_Property", 10usize, |this| ((**(this as *const *const ISharedPropertyGroup_Vtbl)).get_Property)(this, name, ppproperty), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreatePropertyByPosition,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreatePropertyByPosition: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            get_PropertyByPosition,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_PropertyByPosition: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateProperty,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateProperty: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Property,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Property: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISharedPropertyGroup as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISharedPropertyGroupManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreatePropertyGroup(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, dwisomode: *mut i32, dwrelmode: *mut i32, fexists: *mut super::super::Foundation::VARIANT_BOOL, ppgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedPropertyGroupManager", "CreatePropertyGroup", 7usize, |this| ((**(this as *const *const ISharedPropertyGroupManager_Vtbl)).CreatePropertyGroup)(this, name, dwisomode, dwrelmode, fexists, ppgroup), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Group(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, ppgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedPropertyGroupManager", "get_Group", 8usize, |this| ((**(this as *const *const ISharedPropertyGroupManager_Vtbl)).get_Group)(this, name, ppgroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISharedPropertyGroupManager", "_NewEnum", 9usize, |this| ((**(this as *const *const ISharedPropertyGroupManager_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreatePropertyGroup,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreatePropertyGroup: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Group,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Group: 0,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISharedPropertyGroupManager as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemAppEventData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Startup(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemAppEventData", "Startup", 3usize, |this| ((**(this as *const *const ISystemAppEventData_Vtbl)).Startup)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDataChanged(this: *mut core::ffi::c_void, dwpid: u32, dwmask: u32, dwnumbersinks: u32, bstrdwmethodmask: core::mem::MaybeUninit<windows_core::BSTR>, dwreason: u32, u64tracehandle: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemAppEventData", "OnDataChanged", 4usize, |this| ((**(this as *const *const ISystemAppEventData_Vtbl)).OnDataChanged)(this, dwpid, dwmask, dwnumbersinks, bstrdwmethodmask, dwreason, u64tracehandle), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Startup, OnDataChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemAppEventData as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IThreadPoolKnobs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMaxThreads(this: *mut core::ffi::c_void, plcmaxthreads: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IThreadPoolKnobs", "GetMaxThreads", 3usize, |this| ((**(this as *const *const IThreadPoolKnobs_Vtbl)).GetMaxThreads)(this, plcmaxthreads), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentThreads(this: *mut core::ffi::c_void, plccurrentthreads: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IThreadPoolKnobs", "GetCurrentThreads", 4usize, |this| ((**(this as *const *const IThreadPoolKnobs_Vtbl)).GetCurrentThreads)(this, plccurrentthreads), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxThreads(this: *mut core::ffi::c_void, lcmaxthreads: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IThreadPoolKnobs", "SetMaxThreads", 5usize, |this| ((**(this as *const *const IThreadPoolKnobs_Vtbl)).SetMaxThreads)(this, lcmaxthreads), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeleteDelay(this: *mut core::ffi::c_void, pmsecdeletedelay: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IThreadPoolKnobs", "GetDeleteDelay", 6usize, |this| ((**(this as *const *const IThreadPoolKnobs_Vtbl)).GetDeleteDelay)(this, pmsecdeletedelay), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeleteDelay(this: *mut core::ffi::c_void, msecdeletedelay: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IThreadPoolKnobs", "SetDeleteDelay", 7usize, |this| ((**(this as *const *const IThreadPoolKnobs_Vtbl)).SetDeleteDelay)(this, msecdeletedelay), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxQueuedRequests(this: *mut core::ffi::c_void, plcmaxqueuedrequests: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IThreadPoolKnobs", "GetMaxQueuedRequests", 8usize, |this| ((**(this as *const *const IThreadPoolKnobs_Vtbl)).GetMaxQueuedRequests)(this, plcmaxqueuedrequests), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentQueuedRequests(this: *mut core::ffi::c_void, plccurrentqueuedrequests: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IThreadPoolKnobs", "GetCurrentQueuedRequests", 9usize, |this| ((**(this as *const *const IThreadPoolKnobs_Vtbl)).GetCurrentQueuedRequests)(this, plccurrentqueuedrequests), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxQueuedRequests(this: *mut core::ffi::c_void, lcmaxqueuedrequests: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IThreadPoolKnobs", "SetMaxQueuedRequests", 10usize, |this| ((**(this as *const *const IThreadPoolKnobs_Vtbl)).SetMaxQueuedRequests)(this, lcmaxqueuedrequests), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinThreads(this: *mut core::ffi::c_void, lcminthreads: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IThreadPoolKnobs", "SetMinThreads", 11usize, |this| ((**(this as *const *const IThreadPoolKnobs_Vtbl)).SetMinThreads)(this, lcminthreads), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQueueDepth(this: *mut core::ffi::c_void, lcqueuedepth: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IThreadPoolKnobs", "SetQueueDepth", 12usize, |this| ((**(this as *const *const IThreadPoolKnobs_Vtbl)).SetQueueDepth)(this, lcqueuedepth), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetMaxThreads,
+            GetCurrentThreads,
+            SetMaxThreads,
+            GetDeleteDelay,
+            SetDeleteDelay,
+            GetMaxQueuedRequests,
+            GetCurrentQueuedRequests,
+            SetMaxQueuedRequests,
+            SetMinThreads,
+            SetQueueDepth,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IThreadPoolKnobs as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITransactionContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, pszprogid: core::mem::MaybeUninit<windows_core::BSTR>, pobject: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionContext", "CreateInstance", 7usize, |this| ((**(this as *const *const ITransactionContext_Vtbl)).CreateInstance)(this, pszprogid, pobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionContext", "Commit", 8usize, |this| ((**(this as *const *const ITransactionContext_Vtbl)).Commit)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Abort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionContext", "Abort", 9usize, |this| ((**(this as *const *const ITransactionContext_Vtbl)).Abort)(this), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance, Commit, Abort }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionContext as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransactionContextEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, riid: *const windows_core::GUID, pobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionContextEx", "CreateInstance", 3usize, |this| ((**(this as *const *const ITransactionContextEx_Vtbl)).CreateInstance)(this, rclsid, riid, pobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionContextEx", "Commit", 4usize, |this| ((**(this as *const *const ITransactionContextEx_Vtbl)).Commit)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Abort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionContextEx", "Abort", 5usize, |this| ((**(this as *const *const ITransactionContextEx_Vtbl)).Abort)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateInstance, Commit, Abort }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionContextEx as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransactionProperty_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reserved1(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ITransactionProperty", "Reserved1", 3usize, |this| ((**(this as *const *const ITransactionProperty_Vtbl)).Reserved1)(this), |_| None)
+        }
+        unsafe extern "system" fn Reserved2(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ITransactionProperty", "Reserved2", 4usize, |this| ((**(this as *const *const ITransactionProperty_Vtbl)).Reserved2)(this), |_| None)
+        }
+        unsafe extern "system" fn Reserved3(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ITransactionProperty", "Reserved3", 5usize, |this| ((**(this as *const *const ITransactionProperty_Vtbl)).Reserved3)(this), |_| None)
+        }
+        unsafe extern "system" fn Reserved4(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ITransactionProperty", "Reserved4", 6usize, |this| ((**(this as *const *const ITransactionProperty_Vtbl)).Reserved4)(this), |_| None)
+        }
+        unsafe extern "system" fn Reserved5(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ITransactionProperty", "Reserved5", 7usize, |this| ((**(this as *const *const ITransactionProperty_Vtbl)).Reserved5)(this), |_| None)
+        }
+        unsafe extern "system" fn Reserved6(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ITransactionProperty", "Reserved6", 8usize, |this| ((**(this as *const *const ITransactionProperty_Vtbl)).Reserved6)(this), |_| None)
+        }
+        unsafe extern "system" fn Reserved7(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ITransactionProperty", "Reserved7", 9usize, |this| ((**(this as *const *const ITransactionProperty_Vtbl)).Reserved7)(this), |_| None)
+        }
+        unsafe extern "system" fn Reserved8(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ITransactionProperty", "Reserved8", 10usize, |this| ((**(this as *const *const ITransactionProperty_Vtbl)).Reserved8)(this), |_| None)
+        }
+        unsafe extern "system" fn Reserved9(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ITransactionProperty", "Reserved9", 11usize, |this| ((**(this as *const *const ITransactionProperty_Vtbl)).Reserved9)(this), |_| None)
+        }
+        unsafe extern "system" fn GetTransactionResourcePool(this: *mut core::ffi::c_void, pptxpool: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionProperty", "GetTransactionResourcePool", 12usize, |this| ((**(this as *const *const ITransactionProperty_Vtbl)).GetTransactionResourcePool)(this, pptxpool), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reserved10(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ITransactionProperty", "Reserved10", 13usize, |this| ((**(this as *const *const ITransactionProperty_Vtbl)).Reserved10)(this), |_| None)
+        }
+        unsafe extern "system" fn Reserved11(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ITransactionProperty", "Reserved11", 14usize, |this| ((**(this as *const *const ITransactionProperty_Vtbl)).Reserved11)(this), |_| None)
+        }
+        unsafe extern "system" fn Reserved12(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ITransactionProperty", "Reserved12", 15usize, |this| ((**(this as *const *const ITransactionProperty_Vtbl)).Reserved12)(this), |_| None)
+        }
+        unsafe extern "system" fn Reserved13(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ITransactionProperty", "Reserved13", 16usize, |this| ((**(this as *const *const ITransactionProperty_Vtbl)).Reserved13)(this), |_| None)
+        }
+        unsafe extern "system" fn Reserved14(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ITransactionProperty", "Reserved14", 17usize, |this| ((**(this as *const *const ITransactionProperty_Vtbl)).Reserved14)(this), |_| None)
+        }
+        unsafe extern "system" fn Reserved15(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ITransactionProperty", "Reserved15", 18usize, |this| ((**(this as *const *const ITransactionProperty_Vtbl)).Reserved15)(this), |_| None)
+        }
+        unsafe extern "system" fn Reserved16(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ITransactionProperty", "Reserved16", 19usize, |this| ((**(this as *const *const ITransactionProperty_Vtbl)).Reserved16)(this), |_| None)
+        }
+        unsafe extern "system" fn Reserved17(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ITransactionProperty", "Reserved17", 20usize, |this| ((**(this as *const *const ITransactionProperty_Vtbl)).Reserved17)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Reserved1,
+            Reserved2,
+            Reserved3,
+            Reserved4,
+            Reserved5,
+            Reserved6,
+            Reserved7,
+            Reserved8,
+            Reserved9,
+            GetTransactionResourcePool,
+            Reserved10,
+            Reserved11,
+            Reserved12,
+            Reserved13,
+            Reserved14,
+            Reserved15,
+            Reserved16,
+            Reserved17,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionProperty as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_DistributedTransactionCoordinator")]
+impl windows_core::imp::ProxyVtable for ITransactionProxy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void, guid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionProxy", "Commit", 3usize, |this| ((**(this as *const *const ITransactionProxy_Vtbl)).Commit)(this, guid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Abort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionProxy", "Abort", 4usize, |this| ((**(this as *const *const ITransactionProxy_Vtbl)).Abort)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_DistributedTransactionCoordinator")]
+        unsafe extern "system" fn Promote(this: *mut core::ffi::c_void, ptransaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionProxy", "Promote", 5usize, |this| ((**(this as *const *const ITransactionProxy_Vtbl)).Promote)(this, ptransaction), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_DistributedTransactionCoordinator")]
+        unsafe extern "system" fn CreateVoter(this: *mut core::ffi::c_void, ptxasync: *mut core::ffi::c_void, ppballot: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionProxy", "CreateVoter", 6usize, |this| ((**(this as *const *const ITransactionProxy_Vtbl)).CreateVoter)(this, ptxasync, ppballot), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIsolationLevel(this: *mut core::ffi::c_void, __midl__itransactionproxy0000: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionProxy", "GetIsolationLevel", 7usize, |this| ((**(this as *const *const ITransactionProxy_Vtbl)).GetIsolationLevel)(this, __midl__itransactionproxy0000), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIdentifier(this: *mut core::ffi::c_void, pbstridentifier: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionProxy", "GetIdentifier", 8usize, |this| ((**(this as *const *const ITransactionProxy_Vtbl)).GetIdentifier)(this, pbstridentifier), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsReusable(this: *mut core::ffi::c_void, pfisreusable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionProxy", "IsReusable", 9usize, |this| ((**(this as *const *const ITransactionProxy_Vtbl)).IsReusable)(this, pfisreusable), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Commit,
+            Abort,
+            #[cfg(feature = "Win32_System_DistributedTransactionCoordinator")]
+            Promote,
+            #[cfg(not(feature = "Win32_System_DistributedTransactionCoordinator"))]
+            Promote: 0,
+            #[cfg(feature = "Win32_System_DistributedTransactionCoordinator")]
+            CreateVoter,
+            #[cfg(not(feature = "Win32_System_DistributedTransactionCoordinator"))]
+            CreateVoter: 0,
+            GetIsolationLevel,
+            GetIdentifier,
+            IsReusable,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionProxy as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransactionResourcePool_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PutResource(this: *mut core::ffi::c_void, ppool: *mut core::ffi::c_void, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionResourcePool", "PutResource", 3usize, |this| ((**(this as *const *const ITransactionResourcePool_Vtbl)).PutResource)(this, ppool, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResource(this: *mut core::ffi::c_void, ppool: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionResourcePool", "GetResource", 4usize, |this| ((**(this as *const *const ITransactionResourcePool_Vtbl)).GetResource)(this, ppool, ppunk), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PutResource, GetResource }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionResourcePool as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransactionStatus_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetTransactionStatus(this: *mut core::ffi::c_void, hrstatus: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionStatus", "SetTransactionStatus", 3usize, |this| ((**(this as *const *const ITransactionStatus_Vtbl)).SetTransactionStatus)(this, hrstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransactionStatus(this: *mut core::ffi::c_void, phrstatus: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionStatus", "GetTransactionStatus", 4usize, |this| ((**(this as *const *const ITransactionStatus_Vtbl)).GetTransactionStatus)(this, phrstatus), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetTransactionStatus, GetTransactionStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionStatus as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITxProxyHolder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetIdentifier(this: *mut core::ffi::c_void, pguidltx: *mut windows_core::GUID) {
+            windows_core::imp::proxy_call(this, "ITxProxyHolder", "GetIdentifier", 3usize, |this| ((**(this as *const *const ITxProxyHolder_Vtbl)).GetIdentifier)(this, pguidltx), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetIdentifier }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITxProxyHolder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ObjectContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstance(this: *mut core::ffi::c_void, bstrprogid: core::mem::MaybeUninit<windows_core::BSTR>, pobject: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ObjectContext", "CreateInstance", 7usize, |this| ((**(this as *const *const ObjectContext_Vtbl)).CreateInstance)(this, bstrprogid, pobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetComplete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ObjectContext", "SetComplete", 8usize, |this| ((**(this as *const *const ObjectContext_Vtbl)).SetComplete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAbort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ObjectContext", "SetAbort", 9usize, |this| ((**(this as *const *const ObjectContext_Vtbl)).SetAbort)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableCommit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ObjectContext", "EnableCommit", 10usize, |this| ((**(this as *const *const ObjectContext_Vtbl)).EnableCommit)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableCommit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ObjectContext", "DisableCommit", 11usize, |this| ((**(this as *const *const ObjectContext_Vtbl)).DisableCommit)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInTransaction(this: *mut core::ffi::c_void, pbisintx: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ObjectContext", "IsInTransaction", 12usize, |this| ((**(this as *const *const ObjectContext_Vtbl)).IsInTransaction)(this, pbisintx), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSecurityEnabled(this: *mut core::ffi::c_void, pbisenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ObjectContext", "IsSecurityEnabled", 13usize, |this| ((**(this as *const *const ObjectContext_Vtbl)).IsSecurityEnabled)(this, pbisenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCallerInRole(this: *mut core::ffi::c_void, bstrrole: core::mem::MaybeUninit<windows_core::BSTR>, pbinrole: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ObjectContext", "IsCallerInRole", 14usize, |this| ((**(this as *const *const ObjectContext_Vtbl)).IsCallerInRole)(this, bstrrole, pbinrole), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ObjectContext", "Count", 15usize, |this| ((**(this as *const *const ObjectContext_Vtbl)).Count)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, pitem: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ObjectContext", "get_Item", 16usize, |this| ((**(this as *const *const ObjectContext_Vtbl)).get_Item)(this, name, pitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ObjectContext", "_NewEnum", 17usize, |this| ((**(this as *const *const ObjectContext_Vtbl))._NewEnum)(this, ppenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Security(this: *mut core::ffi::c_void, ppsecurityproperty: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ObjectContext", "Security", 18usize, |this| ((**(this as *const *const ObjectContext_Vtbl)).Security)(this, ppsecurityproperty), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ContextInfo(this: *mut core::ffi::c_void, ppcontextinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ObjectContext", "ContextInfo", 19usize, |this| ((**(this as *const *const ObjectContext_Vtbl)).ContextInfo)(this, ppcontextinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateInstance,
+            SetComplete,
+            SetAbort,
+            EnableCommit,
+            DisableCommit,
+            IsInTransaction,
+            IsSecurityEnabled,
+            IsCallerInRole,
+            Count,
+            get_Item,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Security,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Security: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ContextInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ContextInfo: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ObjectContext as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ObjectControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Activate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ObjectControl", "Activate", 3usize, |this| ((**(this as *const *const ObjectControl_Vtbl)).Activate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deactivate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ObjectControl", "Deactivate", 4usize, |this| ((**(this as *const *const ObjectControl_Vtbl)).Deactivate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanBePooled(this: *mut core::ffi::c_void, pbpoolable: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ObjectControl", "CanBePooled", 5usize, |this| ((**(this as *const *const ObjectControl_Vtbl)).CanBePooled)(this, pbpoolable), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Activate, Deactivate, CanBePooled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ObjectControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for SecurityProperty_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDirectCallerName(this: *mut core::ffi::c_void, bstrusername: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "SecurityProperty", "GetDirectCallerName", 7usize, |this| ((**(this as *const *const SecurityProperty_Vtbl)).GetDirectCallerName)(this, bstrusername), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDirectCreatorName(this: *mut core::ffi::c_void, bstrusername: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "SecurityProperty", "GetDirectCreatorName", 8usize, |this| ((**(this as *const *const SecurityProperty_Vtbl)).GetDirectCreatorName)(this, bstrusername), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOriginalCallerName(this: *mut core::ffi::c_void, bstrusername: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "SecurityProperty", "GetOriginalCallerName", 9usize, |this| ((**(this as *const *const SecurityProperty_Vtbl)).GetOriginalCallerName)(this, bstrusername), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOriginalCreatorName(this: *mut core::ffi::c_void, bstrusername: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "SecurityProperty", "GetOriginalCreatorName", 10usize, |this| ((**(this as *const *const SecurityProperty_Vtbl)).GetOriginalCreatorName)(this, bstrusername), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDirectCallerName,
+            GetDirectCreatorName,
+            GetOriginalCallerName,
+            GetOriginalCreatorName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<SecurityProperty as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
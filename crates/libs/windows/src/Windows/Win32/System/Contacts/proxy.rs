@@ -0,0 +1,745 @@
+impl windows_core::imp::ProxyVtable for IContact_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetContactID(this: *mut core::ffi::c_void, pszcontactid: windows_core::PWSTR, cchcontactid: u32, pdwcchcontactidrequired: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact", "GetContactID", 3usize, |this| ((**(this as *const *const IContact_Vtbl)).GetContactID)(this, pszcontactid, cchcontactid, pdwcchcontactidrequired), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPath(this: *mut core::ffi::c_void, pszpath: windows_core::PWSTR, cchpath: u32, pdwcchpathrequired: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact", "GetPath", 4usize, |this| ((**(this as *const *const IContact_Vtbl)).GetPath)(this, pszpath, cchpath, pdwcchpathrequired), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommitChanges(this: *mut core::ffi::c_void, dwcommitflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContact", "CommitChanges", 5usize, |this| ((**(this as *const *const IContact_Vtbl)).CommitChanges)(this, dwcommitflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetContactID, GetPath, CommitChanges }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContact as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactAggregationAggregate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationAggregate", "Save", 3usize, |this| ((**(this as *const *const IContactAggregationAggregate_Vtbl)).Save)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetComponentItems(this: *mut core::ffi::c_void, pcomponentitems: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationAggregate", "GetComponentItems", 4usize, |this| ((**(this as *const *const IContactAggregationAggregate_Vtbl)).GetComponentItems)(this, pcomponentitems), |result| Some(*result))
+        }
+        unsafe extern "system" fn Link(this: *mut core::ffi::c_void, paggregateid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationAggregate", "Link", 5usize, |this| ((**(this as *const *const IContactAggregationAggregate_Vtbl)).Link)(this, paggregateid), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Groups(this: *mut core::ffi::c_void, options: CONTACT_AGGREGATION_COLLECTION_OPTIONS, ppgroups: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationAggregate", "get_Groups", 6usize, |this| ((**(this as *const *const IContactAggregationAggregate_Vtbl)).get_Groups)(this, options, ppgroups), |result| Some(*result))
+        }
+        unsafe extern "system" fn AntiLink(this: *mut core::ffi::c_void, ppantilink: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationAggregate", "AntiLink", 7usize, |this| ((**(this as *const *const IContactAggregationAggregate_Vtbl)).AntiLink)(this, ppantilink), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAntiLink(this: *mut core::ffi::c_void, pantilink: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationAggregate", "SetAntiLink", 8usize, |this| ((**(this as *const *const IContactAggregationAggregate_Vtbl)).SetAntiLink)(this, pantilink), |result| Some(*result))
+        }
+        unsafe extern "system" fn FavoriteOrder(this: *mut core::ffi::c_void, pfavoriteorder: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationAggregate", "FavoriteOrder", 9usize, |this| ((**(this as *const *const IContactAggregationAggregate_Vtbl)).FavoriteOrder)(this, pfavoriteorder), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFavoriteOrder(this: *mut core::ffi::c_void, favoriteorder: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationAggregate", "SetFavoriteOrder", 10usize, |this| ((**(this as *const *const IContactAggregationAggregate_Vtbl)).SetFavoriteOrder)(this, favoriteorder), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, ppitemid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationAggregate", "Id", 11usize, |this| ((**(this as *const *const IContactAggregationAggregate_Vtbl)).Id)(this, ppitemid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Save,
+            GetComponentItems,
+            Link,
+            get_Groups,
+            AntiLink,
+            SetAntiLink,
+            FavoriteOrder,
+            SetFavoriteOrder,
+            Id,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactAggregationAggregate as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactAggregationAggregateCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FindFirst(this: *mut core::ffi::c_void, ppaggregate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationAggregateCollection", "FindFirst", 3usize, |this| ((**(this as *const *const IContactAggregationAggregateCollection_Vtbl)).FindFirst)(this, ppaggregate), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindFirstByAntiLinkId(this: *mut core::ffi::c_void, pantilinkid: windows_core::PCWSTR, ppaggregate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationAggregateCollection", "FindFirstByAntiLinkId", 4usize, |this| ((**(this as *const *const IContactAggregationAggregateCollection_Vtbl)).FindFirstByAntiLinkId)(this, pantilinkid, ppaggregate), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindNext(this: *mut core::ffi::c_void, ppaggregate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationAggregateCollection", "FindNext", 5usize, |this| ((**(this as *const *const IContactAggregationAggregateCollection_Vtbl)).FindNext)(this, ppaggregate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationAggregateCollection", "Count", 6usize, |this| ((**(this as *const *const IContactAggregationAggregateCollection_Vtbl)).Count)(this, pcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FindFirst, FindFirstByAntiLinkId, FindNext, Count }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactAggregationAggregateCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactAggregationContact_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationContact", "Delete", 3usize, |this| ((**(this as *const *const IContactAggregationContact_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationContact", "Save", 4usize, |this| ((**(this as *const *const IContactAggregationContact_Vtbl)).Save)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveToAggregate(this: *mut core::ffi::c_void, paggregateid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationContact", "MoveToAggregate", 5usize, |this| ((**(this as *const *const IContactAggregationContact_Vtbl)).MoveToAggregate)(this, paggregateid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unlink(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationContact", "Unlink", 6usize, |this| ((**(this as *const *const IContactAggregationContact_Vtbl)).Unlink)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AccountId(this: *mut core::ffi::c_void, ppaccountid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationContact", "AccountId", 7usize, |this| ((**(this as *const *const IContactAggregationContact_Vtbl)).AccountId)(this, ppaccountid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAccountId(this: *mut core::ffi::c_void, paccountid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationContact", "SetAccountId", 8usize, |this| ((**(this as *const *const IContactAggregationContact_Vtbl)).SetAccountId)(this, paccountid), |result| Some(*result))
+        }
+        unsafe extern "system" fn AggregateId(this: *mut core::ffi::c_void, ppaggregateid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationContact", "AggregateId", 9usize, |this| ((**(this as *const *const IContactAggregationContact_Vtbl)).AggregateId)(this, ppaggregateid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, ppitemid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationContact", "Id", 10usize, |this| ((**(this as *const *const IContactAggregationContact_Vtbl)).Id)(this, ppitemid), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMe(this: *mut core::ffi::c_void, pisme: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationContact", "IsMe", 11usize, |this| ((**(this as *const *const IContactAggregationContact_Vtbl)).IsMe)(this, pisme), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsExternal(this: *mut core::ffi::c_void, pisexternal: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationContact", "IsExternal", 12usize, |this| ((**(this as *const *const IContactAggregationContact_Vtbl)).IsExternal)(this, pisexternal), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkSourceId(this: *mut core::ffi::c_void, pnetworksourceid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationContact", "NetworkSourceId", 13usize, |this| ((**(this as *const *const IContactAggregationContact_Vtbl)).NetworkSourceId)(this, pnetworksourceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNetworkSourceId(this: *mut core::ffi::c_void, networksourceid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationContact", "SetNetworkSourceId", 14usize, |this| ((**(this as *const *const IContactAggregationContact_Vtbl)).SetNetworkSourceId)(this, networksourceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkSourceIdString(this: *mut core::ffi::c_void, ppnetworksourceid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationContact", "NetworkSourceIdString", 15usize, |this| ((**(this as *const *const IContactAggregationContact_Vtbl)).NetworkSourceIdString)(this, ppnetworksourceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNetworkSourceIdString(this: *mut core::ffi::c_void, pnetworksourceid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationContact", "SetNetworkSourceIdString", 16usize, |this| ((**(this as *const *const IContactAggregationContact_Vtbl)).SetNetworkSourceIdString)(this, pnetworksourceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteObjectId(this: *mut core::ffi::c_void, ppremoteobjectid: *mut *mut CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationContact", "RemoteObjectId", 17usize, |this| ((**(this as *const *const IContactAggregationContact_Vtbl)).RemoteObjectId)(this, ppremoteobjectid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemoteObjectId(this: *mut core::ffi::c_void, premoteobjectid: *const CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationContact", "SetRemoteObjectId", 18usize, |this| ((**(this as *const *const IContactAggregationContact_Vtbl)).SetRemoteObjectId)(this, premoteobjectid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncIdentityHash(this: *mut core::ffi::c_void, ppsyncidentityhash: *mut *mut CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationContact", "SyncIdentityHash", 19usize, |this| ((**(this as *const *const IContactAggregationContact_Vtbl)).SyncIdentityHash)(this, ppsyncidentityhash), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSyncIdentityHash(this: *mut core::ffi::c_void, psyncidentityhash: *const CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationContact", "SetSyncIdentityHash", 20usize, |this| ((**(this as *const *const IContactAggregationContact_Vtbl)).SetSyncIdentityHash)(this, psyncidentityhash), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Delete,
+            Save,
+            MoveToAggregate,
+            Unlink,
+            AccountId,
+            SetAccountId,
+            AggregateId,
+            Id,
+            IsMe,
+            IsExternal,
+            NetworkSourceId,
+            SetNetworkSourceId,
+            NetworkSourceIdString,
+            SetNetworkSourceIdString,
+            RemoteObjectId,
+            SetRemoteObjectId,
+            SyncIdentityHash,
+            SetSyncIdentityHash,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactAggregationContact as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactAggregationContactCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FindFirst(this: *mut core::ffi::c_void, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationContactCollection", "FindFirst", 3usize, |this| ((**(this as *const *const IContactAggregationContactCollection_Vtbl)).FindFirst)(this, ppitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindNext(this: *mut core::ffi::c_void, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationContactCollection", "FindNext", 4usize, |this| ((**(this as *const *const IContactAggregationContactCollection_Vtbl)).FindNext)(this, ppitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindFirstByIdentityHash(this: *mut core::ffi::c_void, psourcetype: windows_core::PCWSTR, paccountid: windows_core::PCWSTR, pidentityhash: *const CONTACT_AGGREGATION_BLOB, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationContactCollection", "FindFirstByIdentityHash", 5usize, |this| ((**(this as *const *const IContactAggregationContactCollection_Vtbl)).FindFirstByIdentityHash)(this, psourcetype, paccountid, pidentityhash, ppitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationContactCollection", "Count", 6usize, |this| ((**(this as *const *const IContactAggregationContactCollection_Vtbl)).Count)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindFirstByRemoteId(this: *mut core::ffi::c_void, psourcetype: windows_core::PCWSTR, paccountid: windows_core::PCWSTR, premoteobjectid: *const CONTACT_AGGREGATION_BLOB, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationContactCollection", "FindFirstByRemoteId", 7usize, |this| ((**(this as *const *const IContactAggregationContactCollection_Vtbl)).FindFirstByRemoteId)(this, psourcetype, paccountid, premoteobjectid, ppitem), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FindFirst,
+            FindNext,
+            FindFirstByIdentityHash,
+            Count,
+            FindFirstByRemoteId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactAggregationContactCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactAggregationGroup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationGroup", "Delete", 3usize, |this| ((**(this as *const *const IContactAggregationGroup_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationGroup", "Save", 4usize, |this| ((**(this as *const *const IContactAggregationGroup_Vtbl)).Save)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, paggregateid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationGroup", "Add", 5usize, |this| ((**(this as *const *const IContactAggregationGroup_Vtbl)).Add)(this, paggregateid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, paggregateid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationGroup", "Remove", 6usize, |this| ((**(this as *const *const IContactAggregationGroup_Vtbl)).Remove)(this, paggregateid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Members(this: *mut core::ffi::c_void, ppaggregatecontactcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationGroup", "Members", 7usize, |this| ((**(this as *const *const IContactAggregationGroup_Vtbl)).Members)(this, ppaggregatecontactcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn GlobalObjectId(this: *mut core::ffi::c_void, pglobalobjectid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationGroup", "GlobalObjectId", 8usize, |this| ((**(this as *const *const IContactAggregationGroup_Vtbl)).GlobalObjectId)(this, pglobalobjectid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGlobalObjectId(this: *mut core::ffi::c_void, pglobalobjectid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationGroup", "SetGlobalObjectId", 9usize, |this| ((**(this as *const *const IContactAggregationGroup_Vtbl)).SetGlobalObjectId)(this, pglobalobjectid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, ppitemid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationGroup", "Id", 10usize, |this| ((**(this as *const *const IContactAggregationGroup_Vtbl)).Id)(this, ppitemid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, ppname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationGroup", "Name", 11usize, |this| ((**(this as *const *const IContactAggregationGroup_Vtbl)).Name)(this, ppname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, pname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationGroup", "SetName", 12usize, |this| ((**(this as *const *const IContactAggregationGroup_Vtbl)).SetName)(this, pname), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Delete,
+            Save,
+            Add,
+            Remove,
+            Members,
+            GlobalObjectId,
+            SetGlobalObjectId,
+            Id,
+            Name,
+            SetName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactAggregationGroup as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactAggregationGroupCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FindFirst(this: *mut core::ffi::c_void, ppgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationGroupCollection", "FindFirst", 3usize, |this| ((**(this as *const *const IContactAggregationGroupCollection_Vtbl)).FindFirst)(this, ppgroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindFirstByGlobalObjectId(this: *mut core::ffi::c_void, pglobalobjectid: *const windows_core::GUID, ppgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationGroupCollection", "FindFirstByGlobalObjectId", 4usize, |this| ((**(this as *const *const IContactAggregationGroupCollection_Vtbl)).FindFirstByGlobalObjectId)(this, pglobalobjectid, ppgroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindNext(this: *mut core::ffi::c_void, ppgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationGroupCollection", "FindNext", 5usize, |this| ((**(this as *const *const IContactAggregationGroupCollection_Vtbl)).FindNext)(this, ppgroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationGroupCollection", "Count", 6usize, |this| ((**(this as *const *const IContactAggregationGroupCollection_Vtbl)).Count)(this, pcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FindFirst, FindFirstByGlobalObjectId, FindNext, Count }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactAggregationGroupCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactAggregationLink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationLink", "Delete", 3usize, |this| ((**(this as *const *const IContactAggregationLink_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationLink", "Save", 4usize, |this| ((**(this as *const *const IContactAggregationLink_Vtbl)).Save)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AccountId(this: *mut core::ffi::c_void, ppaccountid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationLink", "AccountId", 5usize, |this| ((**(this as *const *const IContactAggregationLink_Vtbl)).AccountId)(this, ppaccountid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAccountId(this: *mut core::ffi::c_void, paccountid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationLink", "SetAccountId", 6usize, |this| ((**(this as *const *const IContactAggregationLink_Vtbl)).SetAccountId)(this, paccountid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, ppitemid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationLink", "Id", 7usize, |this| ((**(this as *const *const IContactAggregationLink_Vtbl)).Id)(this, ppitemid), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLinkResolved(this: *mut core::ffi::c_void, pislinkresolved: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationLink", "IsLinkResolved", 8usize, |this| ((**(this as *const *const IContactAggregationLink_Vtbl)).IsLinkResolved)(this, pislinkresolved), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsLinkResolved(this: *mut core::ffi::c_void, islinkresolved: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationLink", "SetIsLinkResolved", 9usize, |this| ((**(this as *const *const IContactAggregationLink_Vtbl)).SetIsLinkResolved)(this, islinkresolved), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkSourceIdString(this: *mut core::ffi::c_void, ppnetworksourceid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationLink", "NetworkSourceIdString", 10usize, |this| ((**(this as *const *const IContactAggregationLink_Vtbl)).NetworkSourceIdString)(this, ppnetworksourceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNetworkSourceIdString(this: *mut core::ffi::c_void, pnetworksourceid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationLink", "SetNetworkSourceIdString", 11usize, |this| ((**(this as *const *const IContactAggregationLink_Vtbl)).SetNetworkSourceIdString)(this, pnetworksourceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteObjectId(this: *mut core::ffi::c_void, ppremoteobjectid: *mut *mut CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationLink", "RemoteObjectId", 12usize, |this| ((**(this as *const *const IContactAggregationLink_Vtbl)).RemoteObjectId)(this, ppremoteobjectid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemoteObjectId(this: *mut core::ffi::c_void, premoteobjectid: *const CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationLink", "SetRemoteObjectId", 13usize, |this| ((**(this as *const *const IContactAggregationLink_Vtbl)).SetRemoteObjectId)(this, premoteobjectid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServerPerson(this: *mut core::ffi::c_void, ppserverpersonid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationLink", "ServerPerson", 14usize, |this| ((**(this as *const *const IContactAggregationLink_Vtbl)).ServerPerson)(this, ppserverpersonid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServerPerson(this: *mut core::ffi::c_void, pserverpersonid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationLink", "SetServerPerson", 15usize, |this| ((**(this as *const *const IContactAggregationLink_Vtbl)).SetServerPerson)(this, pserverpersonid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServerPersonBaseline(this: *mut core::ffi::c_void, ppserverpersonid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationLink", "ServerPersonBaseline", 16usize, |this| ((**(this as *const *const IContactAggregationLink_Vtbl)).ServerPersonBaseline)(this, ppserverpersonid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServerPersonBaseline(this: *mut core::ffi::c_void, pserverpersonid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationLink", "SetServerPersonBaseline", 17usize, |this| ((**(this as *const *const IContactAggregationLink_Vtbl)).SetServerPersonBaseline)(this, pserverpersonid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SyncIdentityHash(this: *mut core::ffi::c_void, ppsyncidentityhash: *mut *mut CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationLink", "SyncIdentityHash", 18usize, |this| ((**(this as *const *const IContactAggregationLink_Vtbl)).SyncIdentityHash)(this, ppsyncidentityhash), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSyncIdentityHash(this: *mut core::ffi::c_void, psyncidentityhash: *const CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationLink", "SetSyncIdentityHash", 19usize, |this| ((**(this as *const *const IContactAggregationLink_Vtbl)).SetSyncIdentityHash)(this, psyncidentityhash), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Delete,
+            Save,
+            AccountId,
+            SetAccountId,
+            Id,
+            IsLinkResolved,
+            SetIsLinkResolved,
+            NetworkSourceIdString,
+            SetNetworkSourceIdString,
+            RemoteObjectId,
+            SetRemoteObjectId,
+            ServerPerson,
+            SetServerPerson,
+            ServerPersonBaseline,
+            SetServerPersonBaseline,
+            SyncIdentityHash,
+            SetSyncIdentityHash,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactAggregationLink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactAggregationLinkCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FindFirst(this: *mut core::ffi::c_void, ppservercontactlink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationLinkCollection", "FindFirst", 3usize, |this| ((**(this as *const *const IContactAggregationLinkCollection_Vtbl)).FindFirst)(this, ppservercontactlink), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindFirstByRemoteId(this: *mut core::ffi::c_void, psourcetype: windows_core::PCWSTR, paccountid: windows_core::PCWSTR, premoteid: *const CONTACT_AGGREGATION_BLOB, ppservercontactlink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationLinkCollection", "FindFirstByRemoteId", 4usize, |this| ((**(this as *const *const IContactAggregationLinkCollection_Vtbl)).FindFirstByRemoteId)(this, psourcetype, paccountid, premoteid, ppservercontactlink), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindNext(this: *mut core::ffi::c_void, ppservercontactlink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationLinkCollection", "FindNext", 5usize, |this| ((**(this as *const *const IContactAggregationLinkCollection_Vtbl)).FindNext)(this, ppservercontactlink), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationLinkCollection", "Count", 6usize, |this| ((**(this as *const *const IContactAggregationLinkCollection_Vtbl)).Count)(this, pcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FindFirst, FindFirstByRemoteId, FindNext, Count }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactAggregationLinkCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactAggregationManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetVersionInfo(this: *mut core::ffi::c_void, plmajorversion: *mut i32, plminorversion: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationManager", "GetVersionInfo", 3usize, |this| ((**(this as *const *const IContactAggregationManager_Vtbl)).GetVersionInfo)(this, plmajorversion, plminorversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateOrOpenGroup(this: *mut core::ffi::c_void, pgroupname: windows_core::PCWSTR, options: CONTACT_AGGREGATION_CREATE_OR_OPEN_OPTIONS, pcreatedgroup: *mut super::super::Foundation::BOOL, ppgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationManager", "CreateOrOpenGroup", 4usize, |this| ((**(this as *const *const IContactAggregationManager_Vtbl)).CreateOrOpenGroup)(this, pgroupname, options, pcreatedgroup, ppgroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateExternalContact(this: *mut core::ffi::c_void, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationManager", "CreateExternalContact", 5usize, |this| ((**(this as *const *const IContactAggregationManager_Vtbl)).CreateExternalContact)(this, ppitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateServerPerson(this: *mut core::ffi::c_void, ppserverperson: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationManager", "CreateServerPerson", 6usize, |this| ((**(this as *const *const IContactAggregationManager_Vtbl)).CreateServerPerson)(this, ppserverperson), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateServerContactLink(this: *mut core::ffi::c_void, ppservercontactlink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationManager", "CreateServerContactLink", 7usize, |this| ((**(this as *const *const IContactAggregationManager_Vtbl)).CreateServerContactLink)(this, ppservercontactlink), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flush(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationManager", "Flush", 8usize, |this| ((**(this as *const *const IContactAggregationManager_Vtbl)).Flush)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenAggregateContact(this: *mut core::ffi::c_void, pitemid: windows_core::PCWSTR, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationManager", "OpenAggregateContact", 9usize, |this| ((**(this as *const *const IContactAggregationManager_Vtbl)).OpenAggregateContact)(this, pitemid, ppitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenContact(this: *mut core::ffi::c_void, pitemid: windows_core::PCWSTR, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationManager", "OpenContact", 10usize, |this| ((**(this as *const *const IContactAggregationManager_Vtbl)).OpenContact)(this, pitemid, ppitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenServerContactLink(this: *mut core::ffi::c_void, pitemid: windows_core::PCWSTR, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationManager", "OpenServerContactLink", 11usize, |this| ((**(this as *const *const IContactAggregationManager_Vtbl)).OpenServerContactLink)(this, pitemid, ppitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenServerPerson(this: *mut core::ffi::c_void, pitemid: windows_core::PCWSTR, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationManager", "OpenServerPerson", 12usize, |this| ((**(this as *const *const IContactAggregationManager_Vtbl)).OpenServerPerson)(this, pitemid, ppitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Contacts(this: *mut core::ffi::c_void, options: CONTACT_AGGREGATION_COLLECTION_OPTIONS, ppitems: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationManager", "get_Contacts", 13usize, |this| ((**(this as *const *const IContactAggregationManager_Vtbl)).get_Contacts)(this, options, ppitems), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_AggregateContacts(this: *mut core::ffi::c_void, options: CONTACT_AGGREGATION_COLLECTION_OPTIONS, ppaggregates: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationManager", "get_AggregateContacts", 14usize, |this| ((**(this as *const *const IContactAggregationManager_Vtbl)).get_AggregateContacts)(this, options, ppaggregates), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Groups(this: *mut core::ffi::c_void, options: CONTACT_AGGREGATION_COLLECTION_OPTIONS, ppgroups: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationManager", "get_Groups", 15usize, |this| ((**(this as *const *const IContactAggregationManager_Vtbl)).get_Groups)(this, options, ppgroups), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServerPersons(this: *mut core::ffi::c_void, ppserverpersoncollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationManager", "ServerPersons", 16usize, |this| ((**(this as *const *const IContactAggregationManager_Vtbl)).ServerPersons)(this, ppserverpersoncollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_ServerContactLinks(this: *mut core::ffi::c_void, ppersonitemid: windows_core::PCWSTR, ppservercontactlinkcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationManager", "get_ServerContactLinks", 17usize, |this| ((**(this as *const *const IContactAggregationManager_Vtbl)).get_ServerContactLinks)(this, ppersonitemid, ppservercontactlinkcollection), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetVersionInfo,
+            CreateOrOpenGroup,
+            CreateExternalContact,
+            CreateServerPerson,
+            CreateServerContactLink,
+            Flush,
+            OpenAggregateContact,
+            OpenContact,
+            OpenServerContactLink,
+            OpenServerPerson,
+            get_Contacts,
+            get_AggregateContacts,
+            get_Groups,
+            ServerPersons,
+            get_ServerContactLinks,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactAggregationManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactAggregationServerPerson_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPerson", "Delete", 3usize, |this| ((**(this as *const *const IContactAggregationServerPerson_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPerson", "Save", 4usize, |this| ((**(this as *const *const IContactAggregationServerPerson_Vtbl)).Save)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AggregateId(this: *mut core::ffi::c_void, ppaggregateid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPerson", "AggregateId", 5usize, |this| ((**(this as *const *const IContactAggregationServerPerson_Vtbl)).AggregateId)(this, ppaggregateid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAggregateId(this: *mut core::ffi::c_void, paggregateid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPerson", "SetAggregateId", 6usize, |this| ((**(this as *const *const IContactAggregationServerPerson_Vtbl)).SetAggregateId)(this, paggregateid), |result| Some(*result))
+        }
+        unsafe extern "system" fn AntiLink(this: *mut core::ffi::c_void, ppantilink: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPerson", "AntiLink", 7usize, |this| ((**(this as *const *const IContactAggregationServerPerson_Vtbl)).AntiLink)(this, ppantilink), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAntiLink(this: *mut core::ffi::c_void, pantilink: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPerson", "SetAntiLink", 8usize, |this| ((**(this as *const *const IContactAggregationServerPerson_Vtbl)).SetAntiLink)(this, pantilink), |result| Some(*result))
+        }
+        unsafe extern "system" fn AntiLinkBaseline(this: *mut core::ffi::c_void, ppantilink: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPerson", "AntiLinkBaseline", 9usize, |this| ((**(this as *const *const IContactAggregationServerPerson_Vtbl)).AntiLinkBaseline)(this, ppantilink), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAntiLinkBaseline(this: *mut core::ffi::c_void, pantilink: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPerson", "SetAntiLinkBaseline", 10usize, |this| ((**(this as *const *const IContactAggregationServerPerson_Vtbl)).SetAntiLinkBaseline)(this, pantilink), |result| Some(*result))
+        }
+        unsafe extern "system" fn FavoriteOrder(this: *mut core::ffi::c_void, pfavoriteorder: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPerson", "FavoriteOrder", 11usize, |this| ((**(this as *const *const IContactAggregationServerPerson_Vtbl)).FavoriteOrder)(this, pfavoriteorder), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFavoriteOrder(this: *mut core::ffi::c_void, favoriteorder: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPerson", "SetFavoriteOrder", 12usize, |this| ((**(this as *const *const IContactAggregationServerPerson_Vtbl)).SetFavoriteOrder)(this, favoriteorder), |result| Some(*result))
+        }
+        unsafe extern "system" fn FavoriteOrderBaseline(this: *mut core::ffi::c_void, pfavoriteorder: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPerson", "FavoriteOrderBaseline", 13usize, |this| ((**(this as *const *const IContactAggregationServerPerson_Vtbl)).FavoriteOrderBaseline)(this, pfavoriteorder), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFavoriteOrderBaseline(this: *mut core::ffi::c_void, favoriteorder: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPerson", "SetFavoriteOrderBaseline", 14usize, |this| ((**(this as *const *const IContactAggregationServerPerson_Vtbl)).SetFavoriteOrderBaseline)(this, favoriteorder), |result| Some(*result))
+        }
+        unsafe extern "system" fn Groups(this: *mut core::ffi::c_void, pgroups: *mut *mut CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPerson", "Groups", 15usize, |this| ((**(this as *const *const IContactAggregationServerPerson_Vtbl)).Groups)(this, pgroups), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGroups(this: *mut core::ffi::c_void, pgroups: *const CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPerson", "SetGroups", 16usize, |this| ((**(this as *const *const IContactAggregationServerPerson_Vtbl)).SetGroups)(this, pgroups), |result| Some(*result))
+        }
+        unsafe extern "system" fn GroupsBaseline(this: *mut core::ffi::c_void, ppgroups: *mut *mut CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPerson", "GroupsBaseline", 17usize, |this| ((**(this as *const *const IContactAggregationServerPerson_Vtbl)).GroupsBaseline)(this, ppgroups), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGroupsBaseline(this: *mut core::ffi::c_void, pgroups: *const CONTACT_AGGREGATION_BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPerson", "SetGroupsBaseline", 18usize, |this| ((**(this as *const *const IContactAggregationServerPerson_Vtbl)).SetGroupsBaseline)(this, pgroups), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, ppid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPerson", "Id", 19usize, |this| ((**(this as *const *const IContactAggregationServerPerson_Vtbl)).Id)(this, ppid), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTombstone(this: *mut core::ffi::c_void, pistombstone: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPerson", "IsTombstone", 20usize, |this| ((**(this as *const *const IContactAggregationServerPerson_Vtbl)).IsTombstone)(this, pistombstone), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsTombstone(this: *mut core::ffi::c_void, istombstone: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPerson", "SetIsTombstone", 21usize, |this| ((**(this as *const *const IContactAggregationServerPerson_Vtbl)).SetIsTombstone)(this, istombstone), |result| Some(*result))
+        }
+        unsafe extern "system" fn LinkedAggregateId(this: *mut core::ffi::c_void, pplinkedaggregateid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPerson", "LinkedAggregateId", 22usize, |this| ((**(this as *const *const IContactAggregationServerPerson_Vtbl)).LinkedAggregateId)(this, pplinkedaggregateid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLinkedAggregateId(this: *mut core::ffi::c_void, plinkedaggregateid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPerson", "SetLinkedAggregateId", 23usize, |this| ((**(this as *const *const IContactAggregationServerPerson_Vtbl)).SetLinkedAggregateId)(this, plinkedaggregateid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ObjectId(this: *mut core::ffi::c_void, ppobjectid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPerson", "ObjectId", 24usize, |this| ((**(this as *const *const IContactAggregationServerPerson_Vtbl)).ObjectId)(this, ppobjectid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetObjectId(this: *mut core::ffi::c_void, pobjectid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPerson", "SetObjectId", 25usize, |this| ((**(this as *const *const IContactAggregationServerPerson_Vtbl)).SetObjectId)(this, pobjectid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Delete,
+            Save,
+            AggregateId,
+            SetAggregateId,
+            AntiLink,
+            SetAntiLink,
+            AntiLinkBaseline,
+            SetAntiLinkBaseline,
+            FavoriteOrder,
+            SetFavoriteOrder,
+            FavoriteOrderBaseline,
+            SetFavoriteOrderBaseline,
+            Groups,
+            SetGroups,
+            GroupsBaseline,
+            SetGroupsBaseline,
+            Id,
+            IsTombstone,
+            SetIsTombstone,
+            LinkedAggregateId,
+            SetLinkedAggregateId,
+            ObjectId,
+            SetObjectId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactAggregationServerPerson as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactAggregationServerPersonCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FindFirst(this: *mut core::ffi::c_void, ppserverperson: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPersonCollection", "FindFirst", 3usize, |this| ((**(this as *const *const IContactAggregationServerPersonCollection_Vtbl)).FindFirst)(this, ppserverperson), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindFirstByServerId(this: *mut core::ffi::c_void, pserverid: windows_core::PCWSTR, ppserverperson: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPersonCollection", "FindFirstByServerId", 4usize, |this| ((**(this as *const *const IContactAggregationServerPersonCollection_Vtbl)).FindFirstByServerId)(this, pserverid, ppserverperson), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindFirstByAggregateId(this: *mut core::ffi::c_void, paggregateid: windows_core::PCWSTR, ppserverperson: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPersonCollection", "FindFirstByAggregateId", 5usize, |this| ((**(this as *const *const IContactAggregationServerPersonCollection_Vtbl)).FindFirstByAggregateId)(this, paggregateid, ppserverperson), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindFirstByLinkedAggregateId(this: *mut core::ffi::c_void, paggregateid: windows_core::PCWSTR, ppserverperson: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPersonCollection", "FindFirstByLinkedAggregateId", 6usize, |this| ((**(this as *const *const IContactAggregationServerPersonCollection_Vtbl)).FindFirstByLinkedAggregateId)(this, paggregateid, ppserverperson), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindNext(this: *mut core::ffi::c_void, ppserverperson: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPersonCollection", "FindNext", 7usize, |this| ((**(this as *const *const IContactAggregationServerPersonCollection_Vtbl)).FindNext)(this, ppserverperson), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactAggregationServerPersonCollection", "Count", 8usize, |this| ((**(this as *const *const IContactAggregationServerPersonCollection_Vtbl)).Count)(this, pcount), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FindFirst,
+            FindFirstByServerId,
+            FindFirstByAggregateId,
+            FindFirstByLinkedAggregateId,
+            FindNext,
+            Count,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactAggregationServerPersonCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactCollection", "Reset", 3usize, |this| ((**(this as *const *const IContactCollection_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactCollection", "Next", 4usize, |this| ((**(this as *const *const IContactCollection_Vtbl)).Next)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, ppcontact: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactCollection", "GetCurrent", 5usize, |this| ((**(this as *const *const IContactCollection_Vtbl)).GetCurrent)(this, ppcontact), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reset, Next, GetCurrent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pszappname: windows_core::PCWSTR, pszappversion: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManager", "Initialize", 3usize, |this| ((**(this as *const *const IContactManager_Vtbl)).Initialize)(this, pszappname, pszappversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn Load(this: *mut core::ffi::c_void, pszcontactid: windows_core::PCWSTR, ppcontact: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManager", "Load", 4usize, |this| ((**(this as *const *const IContactManager_Vtbl)).Load)(this, pszcontactid, ppcontact), |result| Some(*result))
+        }
+        unsafe extern "system" fn MergeContactIDs(this: *mut core::ffi::c_void, psznewcontactid: windows_core::PCWSTR, pszoldcontactid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManager", "MergeContactIDs", 5usize, |this| ((**(this as *const *const IContactManager_Vtbl)).MergeContactIDs)(this, psznewcontactid, pszoldcontactid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMeContact(this: *mut core::ffi::c_void, ppmecontact: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManager", "GetMeContact", 6usize, |this| ((**(this as *const *const IContactManager_Vtbl)).GetMeContact)(this, ppmecontact), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMeContact(this: *mut core::ffi::c_void, pmecontact: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManager", "SetMeContact", 7usize, |this| ((**(this as *const *const IContactManager_Vtbl)).SetMeContact)(this, pmecontact), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContactCollection(this: *mut core::ffi::c_void, ppcontactcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactManager", "GetContactCollection", 8usize, |this| ((**(this as *const *const IContactManager_Vtbl)).GetContactCollection)(this, ppcontactcollection), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            Load,
+            MergeContactIDs,
+            GetMeContact,
+            SetMeContact,
+            GetContactCollection,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IContactProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetString(this: *mut core::ffi::c_void, pszpropertyname: windows_core::PCWSTR, dwflags: u32, pszvalue: windows_core::PWSTR, cchvalue: u32, pdwcchpropertyvaluerequired: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactProperties", "GetString", 3usize, |this| ((**(this as *const *const IContactProperties_Vtbl)).GetString)(this, pszpropertyname, dwflags, pszvalue, cchvalue, pdwcchpropertyvaluerequired), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDate(this: *mut core::ffi::c_void, pszpropertyname: windows_core::PCWSTR, dwflags: u32, pftdatetime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactProperties", "GetDate", 4usize, |this| ((**(this as *const *const IContactProperties_Vtbl)).GetDate)(this, pszpropertyname, dwflags, pftdatetime), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetBinary(this: *mut core::ffi::c_void, pszpropertyname: windows_core::PCWSTR, dwflags: u32, pszcontenttype: windows_core::PWSTR, cchcontenttype: u32, pdwcchcontenttyperequired: *mut u32, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactProperties", "GetBinary", 5usize, |this| ((**(this as *const *const IContactProperties_Vtbl)).GetBinary)(this, pszpropertyname, dwflags, pszcontenttype, cchcontenttype, pdwcchcontenttyperequired, ppstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLabels(this: *mut core::ffi::c_void, pszarrayelementname: windows_core::PCWSTR, dwflags: u32, pszlabels: windows_core::PWSTR, cchlabels: u32, pdwcchlabelsrequired: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactProperties", "GetLabels", 6usize, |this| ((**(this as *const *const IContactProperties_Vtbl)).GetLabels)(this, pszarrayelementname, dwflags, pszlabels, cchlabels, pdwcchlabelsrequired), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetString(this: *mut core::ffi::c_void, pszpropertyname: windows_core::PCWSTR, dwflags: u32, pszvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactProperties", "SetString", 7usize, |this| ((**(this as *const *const IContactProperties_Vtbl)).SetString)(this, pszpropertyname, dwflags, pszvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDate(this: *mut core::ffi::c_void, pszpropertyname: windows_core::PCWSTR, dwflags: u32, ftdatetime: super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactProperties", "SetDate", 8usize, |this| ((**(this as *const *const IContactProperties_Vtbl)).SetDate)(this, pszpropertyname, dwflags, ftdatetime), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetBinary(this: *mut core::ffi::c_void, pszpropertyname: windows_core::PCWSTR, dwflags: u32, pszcontenttype: windows_core::PCWSTR, pstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactProperties", "SetBinary", 9usize, |this| ((**(this as *const *const IContactProperties_Vtbl)).SetBinary)(this, pszpropertyname, dwflags, pszcontenttype, pstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLabels(this: *mut core::ffi::c_void, pszarrayelementname: windows_core::PCWSTR, dwflags: u32, dwlabelcount: u32, ppszlabels: *const windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactProperties", "SetLabels", 10usize, |this| ((**(this as *const *const IContactProperties_Vtbl)).SetLabels)(this, pszarrayelementname, dwflags, dwlabelcount, ppszlabels), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateArrayNode(this: *mut core::ffi::c_void, pszarrayname: windows_core::PCWSTR, dwflags: u32, fappend: super::super::Foundation::BOOL, psznewarrayelementname: windows_core::PWSTR, cchnewarrayelementname: u32, pdwcchnewarrayelementnamerequired: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactProperties", "CreateArrayNode", 11usize, |this| ((**(this as *const *const IContactProperties_Vtbl)).CreateArrayNode)(this, pszarrayname, dwflags, fappend, psznewarrayelementname, cchnewarrayelementname, pdwcchnewarrayelementnamerequired), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteProperty(this: *mut core::ffi::c_void, pszpropertyname: windows_core::PCWSTR, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactProperties", "DeleteProperty", 12usize, |this| ((**(this as *const *const IContactProperties_Vtbl)).DeleteProperty)(this, pszpropertyname, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteArrayNode(this: *mut core::ffi::c_void, pszarrayelementname: windows_core::PCWSTR, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactProperties", "DeleteArrayNode", 13usize, |this| ((**(this as *const *const IContactProperties_Vtbl)).DeleteArrayNode)(this, pszarrayelementname, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteLabels(this: *mut core::ffi::c_void, pszarrayelementname: windows_core::PCWSTR, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactProperties", "DeleteLabels", 14usize, |this| ((**(this as *const *const IContactProperties_Vtbl)).DeleteLabels)(this, pszarrayelementname, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyCollection(this: *mut core::ffi::c_void, pppropertycollection: *mut *mut core::ffi::c_void, dwflags: u32, pszmultivaluename: windows_core::PCWSTR, dwlabelcount: u32, ppszlabels: *const windows_core::PCWSTR, fanylabelmatches: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactProperties", "GetPropertyCollection", 15usize, |this| ((**(this as *const *const IContactProperties_Vtbl)).GetPropertyCollection)(this, pppropertycollection, dwflags, pszmultivaluename, dwlabelcount, ppszlabels, fanylabelmatches), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetString,
+            GetDate,
+            #[cfg(feature = "Win32_System_Com")]
+            GetBinary,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetBinary: 0,
+            GetLabels,
+            SetString,
+            SetDate,
+            #[cfg(feature = "Win32_System_Com")]
+            SetBinary,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetBinary: 0,
+            SetLabels,
+            CreateArrayNode,
+            DeleteProperty,
+            DeleteArrayNode,
+            DeleteLabels,
+            GetPropertyCollection,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactProperties as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContactPropertyCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPropertyCollection", "Reset", 3usize, |this| ((**(this as *const *const IContactPropertyCollection_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPropertyCollection", "Next", 4usize, |this| ((**(this as *const *const IContactPropertyCollection_Vtbl)).Next)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyName(this: *mut core::ffi::c_void, pszpropertyname: windows_core::PWSTR, cchpropertyname: u32, pdwcchpropertynamerequired: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPropertyCollection", "GetPropertyName", 5usize, |this| ((**(this as *const *const IContactPropertyCollection_Vtbl)).GetPropertyName)(this, pszpropertyname, cchpropertyname, pdwcchpropertynamerequired), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyType(this: *mut core::ffi::c_void, pdwtype: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPropertyCollection", "GetPropertyType", 6usize, |this| ((**(this as *const *const IContactPropertyCollection_Vtbl)).GetPropertyType)(this, pdwtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyVersion(this: *mut core::ffi::c_void, pdwversion: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPropertyCollection", "GetPropertyVersion", 7usize, |this| ((**(this as *const *const IContactPropertyCollection_Vtbl)).GetPropertyVersion)(this, pdwversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyModificationDate(this: *mut core::ffi::c_void, pftmodificationdate: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPropertyCollection", "GetPropertyModificationDate", 8usize, |this| ((**(this as *const *const IContactPropertyCollection_Vtbl)).GetPropertyModificationDate)(this, pftmodificationdate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyArrayElementID(this: *mut core::ffi::c_void, pszarrayelementid: windows_core::PWSTR, ccharrayelementid: u32, pdwccharrayelementidrequired: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContactPropertyCollection", "GetPropertyArrayElementID", 9usize, |this| ((**(this as *const *const IContactPropertyCollection_Vtbl)).GetPropertyArrayElementID)(this, pszarrayelementid, ccharrayelementid, pdwccharrayelementidrequired), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Reset,
+            Next,
+            GetPropertyName,
+            GetPropertyType,
+            GetPropertyVersion,
+            GetPropertyModificationDate,
+            GetPropertyArrayElementID,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContactPropertyCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
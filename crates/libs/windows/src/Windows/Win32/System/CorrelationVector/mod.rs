@@ -24,7 +24,8 @@ pub const RTL_CORRELATION_VECTOR_V1_PREFIX_LENGTH: u32 = 16u32;
 pub const RTL_CORRELATION_VECTOR_V2_LENGTH: u32 = 128u32;
 pub const RTL_CORRELATION_VECTOR_V2_PREFIX_LENGTH: u32 = 22u32;
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CORRELATION_VECTOR {
     pub Version: i8,
     pub Vector: [i8; 129],
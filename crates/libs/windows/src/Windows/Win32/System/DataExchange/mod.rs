@@ -709,6 +709,7 @@ pub struct CONVINFO_CONVERSATION_STATE(pub u32);
 impl windows_core::TypeKind for CONVINFO_CONVERSATION_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CONVINFO_CONVERSATION_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CONVINFO_CONVERSATION_STATE").field(&self.0).finish()
@@ -720,6 +721,7 @@ pub struct CONVINFO_STATUS(pub u32);
 impl windows_core::TypeKind for CONVINFO_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CONVINFO_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CONVINFO_STATUS").field(&self.0).finish()
@@ -764,6 +766,7 @@ pub struct DDE_CLIENT_TRANSACTION_TYPE(pub u32);
 impl windows_core::TypeKind for DDE_CLIENT_TRANSACTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DDE_CLIENT_TRANSACTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DDE_CLIENT_TRANSACTION_TYPE").field(&self.0).finish()
@@ -775,6 +778,7 @@ pub struct DDE_ENABLE_CALLBACK_CMD(pub u32);
 impl windows_core::TypeKind for DDE_ENABLE_CALLBACK_CMD {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DDE_ENABLE_CALLBACK_CMD {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DDE_ENABLE_CALLBACK_CMD").field(&self.0).finish()
@@ -786,6 +790,7 @@ pub struct DDE_INITIALIZE_COMMAND(pub u32);
 impl windows_core::TypeKind for DDE_INITIALIZE_COMMAND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DDE_INITIALIZE_COMMAND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DDE_INITIALIZE_COMMAND").field(&self.0).finish()
@@ -830,6 +835,7 @@ pub struct DDE_NAME_SERVICE_CMD(pub u32);
 impl windows_core::TypeKind for DDE_NAME_SERVICE_CMD {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DDE_NAME_SERVICE_CMD {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DDE_NAME_SERVICE_CMD").field(&self.0).finish()
@@ -837,7 +843,8 @@ impl core::fmt::Debug for DDE_NAME_SERVICE_CMD {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Security")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CONVCONTEXT {
     pub cb: u32,
     pub wFlags: u32,
@@ -859,7 +866,8 @@ impl Default for CONVCONTEXT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Security")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CONVINFO {
     pub cb: u32,
     pub hUser: usize,
@@ -889,7 +897,8 @@ impl Default for CONVINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COPYDATASTRUCT {
     pub dwData: usize,
     pub cbData: u32,
@@ -904,10 +913,37 @@ impl Default for COPYDATASTRUCT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DDEACK {
     pub _bitfield: u16,
 }
+impl DDEACK {
+    pub const fn bAppReturnCode(&self) -> u16 {
+        self._bitfield & 255
+    }
+    pub fn set_bAppReturnCode(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn reserved(&self) -> u16 {
+        (self._bitfield >> 8) & 63
+    }
+    pub fn set_reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(63 << 8)) | ((value & 63) << 8);
+    }
+    pub const fn fBusy(&self) -> u16 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_fBusy(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn fAck(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_fAck(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for DDEACK {
     type TypeKind = windows_core::CopyType;
 }
@@ -917,11 +953,32 @@ impl Default for DDEACK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DDEADVISE {
     pub _bitfield: u16,
     pub cfFormat: i16,
 }
+impl DDEADVISE {
+    pub const fn reserved(&self) -> u16 {
+        self._bitfield & 16383
+    }
+    pub fn set_reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !16383) | (value & 16383);
+    }
+    pub const fn fDeferUpd(&self) -> u16 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_fDeferUpd(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn fAckReq(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_fAckReq(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for DDEADVISE {
     type TypeKind = windows_core::CopyType;
 }
@@ -931,12 +988,45 @@ impl Default for DDEADVISE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DDEDATA {
     pub _bitfield: u16,
     pub cfFormat: i16,
     pub Value: [u8; 1],
 }
+impl DDEDATA {
+    pub const fn unused(&self) -> u16 {
+        self._bitfield & 4095
+    }
+    pub fn set_unused(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !4095) | (value & 4095);
+    }
+    pub const fn fResponse(&self) -> u16 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_fResponse(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn fRelease(&self) -> u16 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_fRelease(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn reserved(&self) -> u16 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn fAckReq(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_fAckReq(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for DDEDATA {
     type TypeKind = windows_core::CopyType;
 }
@@ -946,11 +1036,38 @@ impl Default for DDEDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DDELN {
     pub _bitfield: u16,
     pub cfFormat: i16,
 }
+impl DDELN {
+    pub const fn unused(&self) -> u16 {
+        self._bitfield & 8191
+    }
+    pub fn set_unused(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !8191) | (value & 8191);
+    }
+    pub const fn fRelease(&self) -> u16 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_fRelease(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn fDeferUpd(&self) -> u16 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_fDeferUpd(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn fAckReq(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_fAckReq(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for DDELN {
     type TypeKind = windows_core::CopyType;
 }
@@ -960,7 +1077,8 @@ impl Default for DDELN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DDEML_MSG_HOOK_DATA {
     pub uiLo: usize,
     pub uiHi: usize,
@@ -976,12 +1094,33 @@ impl Default for DDEML_MSG_HOOK_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DDEPOKE {
     pub _bitfield: u16,
     pub cfFormat: i16,
     pub Value: [u8; 1],
 }
+impl DDEPOKE {
+    pub const fn unused(&self) -> u16 {
+        self._bitfield & 8191
+    }
+    pub fn set_unused(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !8191) | (value & 8191);
+    }
+    pub const fn fRelease(&self) -> u16 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_fRelease(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn fReserved(&self) -> u16 {
+        (self._bitfield >> 14) & 3
+    }
+    pub fn set_fReserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 14)) | ((value & 3) << 14);
+    }
+}
 impl windows_core::TypeKind for DDEPOKE {
     type TypeKind = windows_core::CopyType;
 }
@@ -991,12 +1130,45 @@ impl Default for DDEPOKE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DDEUP {
     pub _bitfield: u16,
     pub cfFormat: i16,
     pub rgb: [u8; 1],
 }
+impl DDEUP {
+    pub const fn unused(&self) -> u16 {
+        self._bitfield & 4095
+    }
+    pub fn set_unused(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !4095) | (value & 4095);
+    }
+    pub const fn fAck(&self) -> u16 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_fAck(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn fRelease(&self) -> u16 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_fRelease(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn fReserved(&self) -> u16 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_fReserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn fAckReq(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_fAckReq(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for DDEUP {
     type TypeKind = windows_core::CopyType;
 }
@@ -1006,7 +1178,8 @@ impl Default for DDEUP {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HCONV(pub *mut core::ffi::c_void);
 impl HCONV {
     pub fn is_invalid(&self) -> bool {
@@ -1030,7 +1203,8 @@ impl windows_core::TypeKind for HCONV {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HCONVLIST(pub *mut core::ffi::c_void);
 impl HCONVLIST {
     pub fn is_invalid(&self) -> bool {
@@ -1054,7 +1228,8 @@ impl windows_core::TypeKind for HCONVLIST {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HDDEDATA(pub *mut core::ffi::c_void);
 impl HDDEDATA {
     pub fn is_invalid(&self) -> bool {
@@ -1078,7 +1253,8 @@ impl windows_core::TypeKind for HDDEDATA {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HSZ(pub *mut core::ffi::c_void);
 impl HSZ {
     pub fn is_invalid(&self) -> bool {
@@ -1094,7 +1270,8 @@ impl windows_core::TypeKind for HSZ {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HSZPAIR {
     pub hszSvc: HSZ,
     pub hszTopic: HSZ,
@@ -1109,7 +1286,8 @@ impl Default for HSZPAIR {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct METAFILEPICT {
     pub mm: i32,
     pub xExt: i32,
@@ -1128,7 +1306,8 @@ impl Default for METAFILEPICT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Security")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MONCBSTRUCT {
     pub cb: u32,
     pub dwTime: u32,
@@ -1157,7 +1336,8 @@ impl Default for MONCBSTRUCT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MONCONVSTRUCT {
     pub cb: u32,
     pub fConnect: super::super::Foundation::BOOL,
@@ -1177,7 +1357,8 @@ impl Default for MONCONVSTRUCT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MONERRSTRUCT {
     pub cb: u32,
     pub wLastError: u32,
@@ -1193,7 +1374,8 @@ impl Default for MONERRSTRUCT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MONHSZSTRUCTA {
     pub cb: u32,
     pub fsAction: super::super::Foundation::BOOL,
@@ -1211,7 +1393,8 @@ impl Default for MONHSZSTRUCTA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MONHSZSTRUCTW {
     pub cb: u32,
     pub fsAction: super::super::Foundation::BOOL,
@@ -1229,7 +1412,8 @@ impl Default for MONHSZSTRUCTW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MONLINKSTRUCT {
     pub cb: u32,
     pub dwTime: u32,
@@ -1253,7 +1437,8 @@ impl Default for MONLINKSTRUCT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MONMSGSTRUCT {
     pub cb: u32,
     pub hwndTo: super::super::Foundation::HWND,
@@ -1273,3 +1458,4 @@ impl Default for MONMSGSTRUCT {
     }
 }
 pub type PFNCALLBACK = Option<unsafe extern "system" fn(wtype: u32, wfmt: u32, hconv: HCONV, hsz1: HSZ, hsz2: HSZ, hdata: HDDEDATA, dwdata1: usize, dwdata2: usize) -> HDDEDATA>;
+core::include!(core::concat!(core::env!("CARGO_MANIFEST_DIR"), "/src/includes/", "Win32/System/DataExchange/Clipboard.rs"));
@@ -2195,6 +2195,7 @@ pub struct CPU_ARCHITECTURE(pub u32);
 impl windows_core::TypeKind for CPU_ARCHITECTURE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CPU_ARCHITECTURE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CPU_ARCHITECTURE").field(&self.0).finish()
@@ -2206,6 +2207,7 @@ pub struct PFN_WDS_CLI_CALLBACK_MESSAGE_ID(pub u32);
 impl windows_core::TypeKind for PFN_WDS_CLI_CALLBACK_MESSAGE_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PFN_WDS_CLI_CALLBACK_MESSAGE_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PFN_WDS_CLI_CALLBACK_MESSAGE_ID").field(&self.0).finish()
@@ -2217,6 +2219,7 @@ pub struct TRANSPORTCLIENT_CALLBACK_ID(pub i32);
 impl windows_core::TypeKind for TRANSPORTCLIENT_CALLBACK_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TRANSPORTCLIENT_CALLBACK_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TRANSPORTCLIENT_CALLBACK_ID").field(&self.0).finish()
@@ -2228,6 +2231,7 @@ pub struct TRANSPORTPROVIDER_CALLBACK_ID(pub i32);
 impl windows_core::TypeKind for TRANSPORTPROVIDER_CALLBACK_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TRANSPORTPROVIDER_CALLBACK_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TRANSPORTPROVIDER_CALLBACK_ID").field(&self.0).finish()
@@ -2239,6 +2243,7 @@ pub struct WDSTRANSPORT_DIAGNOSTICS_COMPONENT_FLAGS(pub i32);
 impl windows_core::TypeKind for WDSTRANSPORT_DIAGNOSTICS_COMPONENT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WDSTRANSPORT_DIAGNOSTICS_COMPONENT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WDSTRANSPORT_DIAGNOSTICS_COMPONENT_FLAGS").field(&self.0).finish()
@@ -2250,6 +2255,7 @@ pub struct WDSTRANSPORT_DISCONNECT_TYPE(pub i32);
 impl windows_core::TypeKind for WDSTRANSPORT_DISCONNECT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WDSTRANSPORT_DISCONNECT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WDSTRANSPORT_DISCONNECT_TYPE").field(&self.0).finish()
@@ -2261,6 +2267,7 @@ pub struct WDSTRANSPORT_FEATURE_FLAGS(pub i32);
 impl windows_core::TypeKind for WDSTRANSPORT_FEATURE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WDSTRANSPORT_FEATURE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WDSTRANSPORT_FEATURE_FLAGS").field(&self.0).finish()
@@ -2272,6 +2279,7 @@ pub struct WDSTRANSPORT_IP_ADDRESS_SOURCE_TYPE(pub i32);
 impl windows_core::TypeKind for WDSTRANSPORT_IP_ADDRESS_SOURCE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WDSTRANSPORT_IP_ADDRESS_SOURCE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WDSTRANSPORT_IP_ADDRESS_SOURCE_TYPE").field(&self.0).finish()
@@ -2283,6 +2291,7 @@ pub struct WDSTRANSPORT_IP_ADDRESS_TYPE(pub i32);
 impl windows_core::TypeKind for WDSTRANSPORT_IP_ADDRESS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WDSTRANSPORT_IP_ADDRESS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WDSTRANSPORT_IP_ADDRESS_TYPE").field(&self.0).finish()
@@ -2294,6 +2303,7 @@ pub struct WDSTRANSPORT_NAMESPACE_TYPE(pub i32);
 impl windows_core::TypeKind for WDSTRANSPORT_NAMESPACE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WDSTRANSPORT_NAMESPACE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WDSTRANSPORT_NAMESPACE_TYPE").field(&self.0).finish()
@@ -2305,6 +2315,7 @@ pub struct WDSTRANSPORT_NETWORK_PROFILE_TYPE(pub i32);
 impl windows_core::TypeKind for WDSTRANSPORT_NETWORK_PROFILE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WDSTRANSPORT_NETWORK_PROFILE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WDSTRANSPORT_NETWORK_PROFILE_TYPE").field(&self.0).finish()
@@ -2316,6 +2327,7 @@ pub struct WDSTRANSPORT_PROTOCOL_FLAGS(pub i32);
 impl windows_core::TypeKind for WDSTRANSPORT_PROTOCOL_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WDSTRANSPORT_PROTOCOL_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WDSTRANSPORT_PROTOCOL_FLAGS").field(&self.0).finish()
@@ -2327,6 +2339,7 @@ pub struct WDSTRANSPORT_SERVICE_NOTIFICATION(pub i32);
 impl windows_core::TypeKind for WDSTRANSPORT_SERVICE_NOTIFICATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WDSTRANSPORT_SERVICE_NOTIFICATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WDSTRANSPORT_SERVICE_NOTIFICATION").field(&self.0).finish()
@@ -2338,6 +2351,7 @@ pub struct WDSTRANSPORT_SLOW_CLIENT_HANDLING_TYPE(pub i32);
 impl windows_core::TypeKind for WDSTRANSPORT_SLOW_CLIENT_HANDLING_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WDSTRANSPORT_SLOW_CLIENT_HANDLING_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WDSTRANSPORT_SLOW_CLIENT_HANDLING_TYPE").field(&self.0).finish()
@@ -2349,6 +2363,7 @@ pub struct WDSTRANSPORT_TFTP_CAPABILITY(pub i32);
 impl windows_core::TypeKind for WDSTRANSPORT_TFTP_CAPABILITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WDSTRANSPORT_TFTP_CAPABILITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WDSTRANSPORT_TFTP_CAPABILITY").field(&self.0).finish()
@@ -2360,6 +2375,7 @@ pub struct WDSTRANSPORT_UDP_PORT_POLICY(pub i32);
 impl windows_core::TypeKind for WDSTRANSPORT_UDP_PORT_POLICY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WDSTRANSPORT_UDP_PORT_POLICY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WDSTRANSPORT_UDP_PORT_POLICY").field(&self.0).finish()
@@ -2371,6 +2387,7 @@ pub struct WDS_CLI_FIRMWARE_TYPE(pub i32);
 impl windows_core::TypeKind for WDS_CLI_FIRMWARE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WDS_CLI_FIRMWARE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WDS_CLI_FIRMWARE_TYPE").field(&self.0).finish()
@@ -2382,6 +2399,7 @@ pub struct WDS_CLI_IMAGE_PARAM_TYPE(pub i32);
 impl windows_core::TypeKind for WDS_CLI_IMAGE_PARAM_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WDS_CLI_IMAGE_PARAM_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WDS_CLI_IMAGE_PARAM_TYPE").field(&self.0).finish()
@@ -2393,6 +2411,7 @@ pub struct WDS_CLI_IMAGE_TYPE(pub i32);
 impl windows_core::TypeKind for WDS_CLI_IMAGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WDS_CLI_IMAGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WDS_CLI_IMAGE_TYPE").field(&self.0).finish()
@@ -2404,6 +2423,7 @@ pub struct WDS_TRANSPORTCLIENT_REQUEST_AUTH_LEVEL(pub u32);
 impl windows_core::TypeKind for WDS_TRANSPORTCLIENT_REQUEST_AUTH_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WDS_TRANSPORTCLIENT_REQUEST_AUTH_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WDS_TRANSPORTCLIENT_REQUEST_AUTH_LEVEL").field(&self.0).finish()
@@ -2471,7 +2491,8 @@ impl Default for PXE_DHCPV6_MESSAGE_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PXE_DHCPV6_NESTED_RELAY_MESSAGE {
     pub pRelayMessage: *mut PXE_DHCPV6_RELAY_MESSAGE,
     pub cbRelayMessage: u32,
@@ -2576,7 +2597,8 @@ impl Default for PXE_DHCP_OPTION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PXE_PROVIDER {
     pub uSizeOfStruct: u32,
     pub pwszName: windows_core::PWSTR,
@@ -2593,7 +2615,8 @@ impl Default for PXE_PROVIDER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TRANSPORTCLIENT_SESSION_INFO {
     pub ulStructureLength: u32,
     pub ullFileSize: u64,
@@ -2608,7 +2631,8 @@ impl Default for TRANSPORTCLIENT_SESSION_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WDS_CLI_CRED {
     pub pwszUserName: windows_core::PCWSTR,
     pub pwszDomain: windows_core::PCWSTR,
@@ -2623,7 +2647,8 @@ impl Default for WDS_CLI_CRED {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WDS_TRANSPORTCLIENT_CALLBACKS {
     pub SessionStart: PFN_WdsTransportClientSessionStart,
     pub SessionStartEx: PFN_WdsTransportClientSessionStartEx,
@@ -2641,7 +2666,8 @@ impl Default for WDS_TRANSPORTCLIENT_CALLBACKS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WDS_TRANSPORTCLIENT_REQUEST {
     pub ulLength: u32,
     pub ulApiVersion: u32,
@@ -2664,7 +2690,8 @@ impl Default for WDS_TRANSPORTCLIENT_REQUEST {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Registry")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WDS_TRANSPORTPROVIDER_INIT_PARAMS {
     pub ulLength: u32,
     pub ulMcServerVersion: u32,
@@ -2682,7 +2709,8 @@ impl Default for WDS_TRANSPORTPROVIDER_INIT_PARAMS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WDS_TRANSPORTPROVIDER_SETTINGS {
     pub ulLength: u32,
     pub ulProviderVersion: u32,
@@ -2726,3 +2754,5 @@ pub type PFN_WdsTransportClientSessionStart = Option<unsafe extern "system" fn(h
 pub type PFN_WdsTransportClientSessionStartEx = Option<unsafe extern "system" fn(hsessionkey: super::super::Foundation::HANDLE, pcallerdata: *const core::ffi::c_void, info: *const TRANSPORTCLIENT_SESSION_INFO)>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,863 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWdsTransportCacheable_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Dirty(this: *mut core::ffi::c_void, pbdirty: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportCacheable", "Dirty", 7usize, |this| ((**(this as *const *const IWdsTransportCacheable_Vtbl)).Dirty)(this, pbdirty), |result| Some(*result))
+        }
+        unsafe extern "system" fn Discard(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportCacheable", "Discard", 8usize, |this| ((**(this as *const *const IWdsTransportCacheable_Vtbl)).Discard)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportCacheable", "Refresh", 9usize, |this| ((**(this as *const *const IWdsTransportCacheable_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportCacheable", "Commit", 10usize, |this| ((**(this as *const *const IWdsTransportCacheable_Vtbl)).Commit)(this), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Dirty, Discard, Refresh, Commit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWdsTransportCacheable as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWdsTransportClient_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Session(this: *mut core::ffi::c_void, ppwdstransportsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportClient", "Session", 7usize, |this| ((**(this as *const *const IWdsTransportClient_Vtbl)).Session)(this, ppwdstransportsession), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, pulid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportClient", "Id", 8usize, |this| ((**(this as *const *const IWdsTransportClient_Vtbl)).Id)(this, pulid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbszname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportClient", "Name", 9usize, |this| ((**(this as *const *const IWdsTransportClient_Vtbl)).Name)(this, pbszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn MacAddress(this: *mut core::ffi::c_void, pbszmacaddress: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportClient", "MacAddress", 10usize, |this| ((**(this as *const *const IWdsTransportClient_Vtbl)).MacAddress)(this, pbszmacaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn IpAddress(this: *mut core::ffi::c_void, pbszipaddress: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportClient", "IpAddress", 11usize, |this| ((**(this as *const *const IWdsTransportClient_Vtbl)).IpAddress)(this, pbszipaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn PercentCompletion(this: *mut core::ffi::c_void, pulpercentcompletion: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportClient", "PercentCompletion", 12usize, |this| ((**(this as *const *const IWdsTransportClient_Vtbl)).PercentCompletion)(this, pulpercentcompletion), |result| Some(*result))
+        }
+        unsafe extern "system" fn JoinDuration(this: *mut core::ffi::c_void, puljoinduration: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportClient", "JoinDuration", 13usize, |this| ((**(this as *const *const IWdsTransportClient_Vtbl)).JoinDuration)(this, puljoinduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn CpuUtilization(this: *mut core::ffi::c_void, pulcpuutilization: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportClient", "CpuUtilization", 14usize, |this| ((**(this as *const *const IWdsTransportClient_Vtbl)).CpuUtilization)(this, pulcpuutilization), |result| Some(*result))
+        }
+        unsafe extern "system" fn MemoryUtilization(this: *mut core::ffi::c_void, pulmemoryutilization: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportClient", "MemoryUtilization", 15usize, |this| ((**(this as *const *const IWdsTransportClient_Vtbl)).MemoryUtilization)(this, pulmemoryutilization), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkUtilization(this: *mut core::ffi::c_void, pulnetworkutilization: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportClient", "NetworkUtilization", 16usize, |this| ((**(this as *const *const IWdsTransportClient_Vtbl)).NetworkUtilization)(this, pulnetworkutilization), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserIdentity(this: *mut core::ffi::c_void, pbszuseridentity: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportClient", "UserIdentity", 17usize, |this| ((**(this as *const *const IWdsTransportClient_Vtbl)).UserIdentity)(this, pbszuseridentity), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnect(this: *mut core::ffi::c_void, disconnectiontype: WDSTRANSPORT_DISCONNECT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportClient", "Disconnect", 18usize, |this| ((**(this as *const *const IWdsTransportClient_Vtbl)).Disconnect)(this, disconnectiontype), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Session,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Session: 0,
+            Id,
+            Name,
+            MacAddress,
+            IpAddress,
+            PercentCompletion,
+            JoinDuration,
+            CpuUtilization,
+            MemoryUtilization,
+            NetworkUtilization,
+            UserIdentity,
+            Disconnect,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWdsTransportClient as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWdsTransportCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pulcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportCollection", "Count", 7usize, |this| ((**(this as *const *const IWdsTransportCollection_Vtbl)).Count)(this, pulcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, ulindex: u32, ppval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportCollection", "get_Item", 8usize, |this| ((**(this as *const *const IWdsTransportCollection_Vtbl)).get_Item)(this, ulindex, ppval), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const IWdsTransportCollection_Vtbl))._NewEnum)(this, ppval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWdsTransportCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWdsTransportConfigurationManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ServicePolicy(this: *mut core::ffi::c_void, ppwdstransportservicepolicy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportConfigurationManager", "ServicePolicy", 7usize, |this| ((**(this as *const *const IWdsTransportConfigurationManager_Vtbl)).ServicePolicy)(this, ppwdstransportservicepolicy), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DiagnosticsPolicy(this: *mut core::ffi::c_void, ppwdstransportdiagnosticspolicy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportConfigurationManager", "DiagnosticsPolicy", 8usize, |this| ((**(this as *const *const IWdsTransportConfigurationManager_Vtbl)).DiagnosticsPolicy)(this, ppwdstransportdiagnosticspolicy), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_WdsTransportServicesRunning(this: *mut core::ffi::c_void, brealtimestatus: super::super::Foundation::VARIANT_BOOL, pbservicesrunning: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportConfigurationManager", "get_WdsTransportServicesRunning", 9usize, |this| ((**(this as *const *const IWdsTransportConfigurationManager_Vtbl)).get_WdsTransportServicesRunning)(this, brealtimestatus, pbservicesrunning), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableWdsTransportServices(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportConfigurationManager", "EnableWdsTransportServices", 10usize, |this| ((**(this as *const *const IWdsTransportConfigurationManager_Vtbl)).EnableWdsTransportServices)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableWdsTransportServices(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportConfigurationManager", "DisableWdsTransportServices", 11usize, |this| ((**(this as *const *const IWdsTransportConfigurationManager_Vtbl)).DisableWdsTransportServices)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartWdsTransportServices(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportConfigurationManager", "StartWdsTransportServices", 12usize, |this| ((**(this as *const *const IWdsTransportConfigurationManager_Vtbl)).StartWdsTransportServices)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopWdsTransportServices(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportConfigurationManager", "StopWdsTransportServices", 13usize, |this| ((**(this as *const *const IWdsTransportConfigurationManager_Vtbl)).StopWdsTransportServices)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestartWdsTransportServices(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportConfigurationManager", "RestartWdsTransportServices", 14usize, |this| ((**(this as *const *const IWdsTransportConfigurationManager_Vtbl)).RestartWdsTransportServices)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyWdsTransportServices(this: *mut core::ffi::c_void, servicenotification: WDSTRANSPORT_SERVICE_NOTIFICATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportConfigurationManager", "NotifyWdsTransportServices", 15usize, |this| ((**(this as *const *const IWdsTransportConfigurationManager_Vtbl)).NotifyWdsTransportServices)(this, servicenotification), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            ServicePolicy,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ServicePolicy: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            DiagnosticsPolicy,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DiagnosticsPolicy: 0,
+            get_WdsTransportServicesRunning,
+            EnableWdsTransportServices,
+            DisableWdsTransportServices,
+            StartWdsTransportServices,
+            StopWdsTransportServices,
+            RestartWdsTransportServices,
+            NotifyWdsTransportServices,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWdsTransportConfigurationManager as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWdsTransportConfigurationManager2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn MulticastSessionPolicy(this: *mut core::ffi::c_void, ppwdstransportmulticastsessionpolicy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportConfigurationManager2", "MulticastSessionPolicy", 16usize, |this| ((**(this as *const *const IWdsTransportConfigurationManager2_Vtbl)).MulticastSessionPolicy)(this, ppwdstransportmulticastsessionpolicy), |result| Some(*result))
+        }
+        Self {
+            base__: <IWdsTransportConfigurationManager_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            MulticastSessionPolicy,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            MulticastSessionPolicy: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWdsTransportConfigurationManager2 as windows_core::Interface>::IID || <IWdsTransportConfigurationManager_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWdsTransportContent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Namespace(this: *mut core::ffi::c_void, ppwdstransportnamespace: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportContent", "Namespace", 7usize, |this| ((**(this as *const *const IWdsTransportContent_Vtbl)).Namespace)(this, ppwdstransportnamespace), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, pulid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportContent", "Id", 8usize, |this| ((**(this as *const *const IWdsTransportContent_Vtbl)).Id)(this, pulid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbszname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportContent", "Name", 9usize, |this| ((**(this as *const *const IWdsTransportContent_Vtbl)).Name)(this, pbszname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RetrieveSessions(this: *mut core::ffi::c_void, ppwdstransportsessions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportContent", "RetrieveSessions", 10usize, |this| ((**(this as *const *const IWdsTransportContent_Vtbl)).RetrieveSessions)(this, ppwdstransportsessions), |result| Some(*result))
+        }
+        unsafe extern "system" fn Terminate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportContent", "Terminate", 11usize, |this| ((**(this as *const *const IWdsTransportContent_Vtbl)).Terminate)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Namespace,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Namespace: 0,
+            Id,
+            Name,
+            #[cfg(feature = "Win32_System_Com")]
+            RetrieveSessions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RetrieveSessions: 0,
+            Terminate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWdsTransportContent as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWdsTransportContentProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbszname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportContentProvider", "Name", 7usize, |this| ((**(this as *const *const IWdsTransportContentProvider_Vtbl)).Name)(this, pbszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, pbszdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportContentProvider", "Description", 8usize, |this| ((**(this as *const *const IWdsTransportContentProvider_Vtbl)).Description)(this, pbszdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn FilePath(this: *mut core::ffi::c_void, pbszfilepath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportContentProvider", "FilePath", 9usize, |this| ((**(this as *const *const IWdsTransportContentProvider_Vtbl)).FilePath)(this, pbszfilepath), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializationRoutine(this: *mut core::ffi::c_void, pbszinitializationroutine: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportContentProvider", "InitializationRoutine", 10usize, |this| ((**(this as *const *const IWdsTransportContentProvider_Vtbl)).InitializationRoutine)(this, pbszinitializationroutine), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, Description, FilePath, InitializationRoutine }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWdsTransportContentProvider as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWdsTransportDiagnosticsPolicy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, pbenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportDiagnosticsPolicy", "Enabled", 11usize, |this| ((**(this as *const *const IWdsTransportDiagnosticsPolicy_Vtbl)).Enabled)(this, pbenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, benabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportDiagnosticsPolicy", "SetEnabled", 12usize, |this| ((**(this as *const *const IWdsTransportDiagnosticsPolicy_Vtbl)).SetEnabled)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn Components(this: *mut core::ffi::c_void, pulcomponents: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportDiagnosticsPolicy", "Components", 13usize, |this| ((**(this as *const *const IWdsTransportDiagnosticsPolicy_Vtbl)).Components)(this, pulcomponents), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetComponents(this: *mut core::ffi::c_void, ulcomponents: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportDiagnosticsPolicy", "SetComponents", 14usize, |this| ((**(this as *const *const IWdsTransportDiagnosticsPolicy_Vtbl)).SetComponents)(this, ulcomponents), |result| Some(*result))
+        }
+        Self { base__: <IWdsTransportCacheable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Enabled, SetEnabled, Components, SetComponents }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWdsTransportDiagnosticsPolicy as windows_core::Interface>::IID || <IWdsTransportCacheable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWdsTransportManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetWdsTransportServer(this: *mut core::ffi::c_void, bszservername: core::mem::MaybeUninit<windows_core::BSTR>, ppwdstransportserver: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportManager", "GetWdsTransportServer", 7usize, |this| ((**(this as *const *const IWdsTransportManager_Vtbl)).GetWdsTransportServer)(this, bszservername, ppwdstransportserver), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetWdsTransportServer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetWdsTransportServer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWdsTransportManager as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWdsTransportMulticastSessionPolicy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SlowClientHandling(this: *mut core::ffi::c_void, pslowclienthandling: *mut WDSTRANSPORT_SLOW_CLIENT_HANDLING_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportMulticastSessionPolicy", "SlowClientHandling", 11usize, |this| ((**(this as *const *const IWdsTransportMulticastSessionPolicy_Vtbl)).SlowClientHandling)(this, pslowclienthandling), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSlowClientHandling(this: *mut core::ffi::c_void, slowclienthandling: WDSTRANSPORT_SLOW_CLIENT_HANDLING_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportMulticastSessionPolicy", "SetSlowClientHandling", 12usize, |this| ((**(this as *const *const IWdsTransportMulticastSessionPolicy_Vtbl)).SetSlowClientHandling)(this, slowclienthandling), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoDisconnectThreshold(this: *mut core::ffi::c_void, pulthreshold: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportMulticastSessionPolicy", "AutoDisconnectThreshold", 13usize, |this| ((**(this as *const *const IWdsTransportMulticastSessionPolicy_Vtbl)).AutoDisconnectThreshold)(this, pulthreshold), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoDisconnectThreshold(this: *mut core::ffi::c_void, ulthreshold: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportMulticastSessionPolicy", "SetAutoDisconnectThreshold", 14usize, |this| ((**(this as *const *const IWdsTransportMulticastSessionPolicy_Vtbl)).SetAutoDisconnectThreshold)(this, ulthreshold), |result| Some(*result))
+        }
+        unsafe extern "system" fn MultistreamStreamCount(this: *mut core::ffi::c_void, pulstreamcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportMulticastSessionPolicy", "MultistreamStreamCount", 15usize, |this| ((**(this as *const *const IWdsTransportMulticastSessionPolicy_Vtbl)).MultistreamStreamCount)(this, pulstreamcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMultistreamStreamCount(this: *mut core::ffi::c_void, ulstreamcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportMulticastSessionPolicy", "SetMultistreamStreamCount", 16usize, |this| ((**(this as *const *const IWdsTransportMulticastSessionPolicy_Vtbl)).SetMultistreamStreamCount)(this, ulstreamcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SlowClientFallback(this: *mut core::ffi::c_void, pbclientfallback: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportMulticastSessionPolicy", "SlowClientFallback", 17usize, |this| ((**(this as *const *const IWdsTransportMulticastSessionPolicy_Vtbl)).SlowClientFallback)(this, pbclientfallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSlowClientFallback(this: *mut core::ffi::c_void, bclientfallback: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportMulticastSessionPolicy", "SetSlowClientFallback", 18usize, |this| ((**(this as *const *const IWdsTransportMulticastSessionPolicy_Vtbl)).SetSlowClientFallback)(this, bclientfallback), |result| Some(*result))
+        }
+        Self {
+            base__: <IWdsTransportCacheable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SlowClientHandling,
+            SetSlowClientHandling,
+            AutoDisconnectThreshold,
+            SetAutoDisconnectThreshold,
+            MultistreamStreamCount,
+            SetMultistreamStreamCount,
+            SlowClientFallback,
+            SetSlowClientFallback,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWdsTransportMulticastSessionPolicy as windows_core::Interface>::IID || <IWdsTransportCacheable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWdsTransportNamespace_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, ptype: *mut WDSTRANSPORT_NAMESPACE_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespace", "Type", 7usize, |this| ((**(this as *const *const IWdsTransportNamespace_Vtbl)).Type)(this, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, pulid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespace", "Id", 8usize, |this| ((**(this as *const *const IWdsTransportNamespace_Vtbl)).Id)(this, pulid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbszname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespace", "Name", 9usize, |this| ((**(this as *const *const IWdsTransportNamespace_Vtbl)).Name)(this, pbszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, bszname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespace", "SetName", 10usize, |this| ((**(this as *const *const IWdsTransportNamespace_Vtbl)).SetName)(this, bszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn FriendlyName(this: *mut core::ffi::c_void, pbszfriendlyname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespace", "FriendlyName", 11usize, |this| ((**(this as *const *const IWdsTransportNamespace_Vtbl)).FriendlyName)(this, pbszfriendlyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFriendlyName(this: *mut core::ffi::c_void, bszfriendlyname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespace", "SetFriendlyName", 12usize, |this| ((**(this as *const *const IWdsTransportNamespace_Vtbl)).SetFriendlyName)(this, bszfriendlyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, pbszdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespace", "Description", 13usize, |this| ((**(this as *const *const IWdsTransportNamespace_Vtbl)).Description)(this, pbszdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, bszdescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespace", "SetDescription", 14usize, |this| ((**(this as *const *const IWdsTransportNamespace_Vtbl)).SetDescription)(this, bszdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentProvider(this: *mut core::ffi::c_void, pbszcontentprovider: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespace", "ContentProvider", 15usize, |this| ((**(this as *const *const IWdsTransportNamespace_Vtbl)).ContentProvider)(this, pbszcontentprovider), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContentProvider(this: *mut core::ffi::c_void, bszcontentprovider: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespace", "SetContentProvider", 16usize, |this| ((**(this as *const *const IWdsTransportNamespace_Vtbl)).SetContentProvider)(this, bszcontentprovider), |result| Some(*result))
+        }
+        unsafe extern "system" fn Configuration(this: *mut core::ffi::c_void, pbszconfiguration: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespace", "Configuration", 17usize, |this| ((**(this as *const *const IWdsTransportNamespace_Vtbl)).Configuration)(this, pbszconfiguration), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConfiguration(this: *mut core::ffi::c_void, bszconfiguration: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespace", "SetConfiguration", 18usize, |this| ((**(this as *const *const IWdsTransportNamespace_Vtbl)).SetConfiguration)(this, bszconfiguration), |result| Some(*result))
+        }
+        unsafe extern "system" fn Registered(this: *mut core::ffi::c_void, pbregistered: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespace", "Registered", 19usize, |this| ((**(this as *const *const IWdsTransportNamespace_Vtbl)).Registered)(this, pbregistered), |result| Some(*result))
+        }
+        unsafe extern "system" fn Tombstoned(this: *mut core::ffi::c_void, pbtombstoned: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespace", "Tombstoned", 20usize, |this| ((**(this as *const *const IWdsTransportNamespace_Vtbl)).Tombstoned)(this, pbtombstoned), |result| Some(*result))
+        }
+        unsafe extern "system" fn TombstoneTime(this: *mut core::ffi::c_void, ptombstonetime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespace", "TombstoneTime", 21usize, |this| ((**(this as *const *const IWdsTransportNamespace_Vtbl)).TombstoneTime)(this, ptombstonetime), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransmissionStarted(this: *mut core::ffi::c_void, pbtransmissionstarted: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespace", "TransmissionStarted", 22usize, |this| ((**(this as *const *const IWdsTransportNamespace_Vtbl)).TransmissionStarted)(this, pbtransmissionstarted), |result| Some(*result))
+        }
+        unsafe extern "system" fn Register(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespace", "Register", 23usize, |this| ((**(this as *const *const IWdsTransportNamespace_Vtbl)).Register)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deregister(this: *mut core::ffi::c_void, bterminatesessions: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespace", "Deregister", 24usize, |this| ((**(this as *const *const IWdsTransportNamespace_Vtbl)).Deregister)(this, bterminatesessions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppwdstransportnamespaceclone: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespace", "Clone", 25usize, |this| ((**(this as *const *const IWdsTransportNamespace_Vtbl)).Clone)(this, ppwdstransportnamespaceclone), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespace", "Refresh", 26usize, |this| ((**(this as *const *const IWdsTransportNamespace_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RetrieveContents(this: *mut core::ffi::c_void, ppwdstransportcontents: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespace", "RetrieveContents", 27usize, |this| ((**(this as *const *const IWdsTransportNamespace_Vtbl)).RetrieveContents)(this, ppwdstransportcontents), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Type,
+            Id,
+            Name,
+            SetName,
+            FriendlyName,
+            SetFriendlyName,
+            Description,
+            SetDescription,
+            ContentProvider,
+            SetContentProvider,
+            Configuration,
+            SetConfiguration,
+            Registered,
+            Tombstoned,
+            TombstoneTime,
+            TransmissionStarted,
+            Register,
+            Deregister,
+            #[cfg(feature = "Win32_System_Com")]
+            Clone,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Clone: 0,
+            Refresh,
+            #[cfg(feature = "Win32_System_Com")]
+            RetrieveContents,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RetrieveContents: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWdsTransportNamespace as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWdsTransportNamespaceAutoCast_Vtbl {
+    const PROXY: Self = { Self { base__: <IWdsTransportNamespace_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWdsTransportNamespaceAutoCast as windows_core::Interface>::IID || <IWdsTransportNamespace_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWdsTransportNamespaceManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateNamespace(this: *mut core::ffi::c_void, namespacetype: WDSTRANSPORT_NAMESPACE_TYPE, bsznamespacename: core::mem::MaybeUninit<windows_core::BSTR>, bszcontentprovider: core::mem::MaybeUninit<windows_core::BSTR>, bszconfiguration: core::mem::MaybeUninit<windows_core::BSTR>, ppwdstransportnamespace: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespaceManager", "CreateNamespace", 7usize, |this| ((**(this as *const *const IWdsTransportNamespaceManager_Vtbl)).CreateNamespace)(this, namespacetype, bsznamespacename, bszcontentprovider, bszconfiguration, ppwdstransportnamespace), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RetrieveNamespace(this: *mut core::ffi::c_void, bsznamespacename: core::mem::MaybeUninit<windows_core::BSTR>, ppwdstransportnamespace: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespaceManager", "RetrieveNamespace", 8usize, |this| ((**(this as *const *const IWdsTransportNamespaceManager_Vtbl)).RetrieveNamespace)(this, bsznamespacename, ppwdstransportnamespace), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RetrieveNamespaces(this: *mut core::ffi::c_void, bszcontentprovider: core::mem::MaybeUninit<windows_core::BSTR>, bsznamespacename: core::mem::MaybeUninit<windows_core::BSTR>, bincludetombstones: super::super::Foundation::VARIANT_BOOL, ppwdstransportnamespaces: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespaceManager", "RetrieveNamespaces", 9usize, |this| ((**(this as *const *const IWdsTransportNamespaceManager_Vtbl)).RetrieveNamespaces)(this, bszcontentprovider, bsznamespacename, bincludetombstones, ppwdstransportnamespaces), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateNamespace,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateNamespace: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            RetrieveNamespace,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RetrieveNamespace: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            RetrieveNamespaces,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RetrieveNamespaces: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWdsTransportNamespaceManager as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWdsTransportNamespaceScheduledCast_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartTransmission(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespaceScheduledCast", "StartTransmission", 28usize, |this| ((**(this as *const *const IWdsTransportNamespaceScheduledCast_Vtbl)).StartTransmission)(this), |result| Some(*result))
+        }
+        Self { base__: <IWdsTransportNamespace_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartTransmission }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWdsTransportNamespaceScheduledCast as windows_core::Interface>::IID || <IWdsTransportNamespace_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWdsTransportNamespaceScheduledCastAutoStart_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MinimumClients(this: *mut core::ffi::c_void, pulminimumclients: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespaceScheduledCastAutoStart", "MinimumClients", 29usize, |this| ((**(this as *const *const IWdsTransportNamespaceScheduledCastAutoStart_Vtbl)).MinimumClients)(this, pulminimumclients), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinimumClients(this: *mut core::ffi::c_void, ulminimumclients: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespaceScheduledCastAutoStart", "SetMinimumClients", 30usize, |this| ((**(this as *const *const IWdsTransportNamespaceScheduledCastAutoStart_Vtbl)).SetMinimumClients)(this, ulminimumclients), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartTime(this: *mut core::ffi::c_void, pstarttime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespaceScheduledCastAutoStart", "StartTime", 31usize, |this| ((**(this as *const *const IWdsTransportNamespaceScheduledCastAutoStart_Vtbl)).StartTime)(this, pstarttime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartTime(this: *mut core::ffi::c_void, starttime: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportNamespaceScheduledCastAutoStart", "SetStartTime", 32usize, |this| ((**(this as *const *const IWdsTransportNamespaceScheduledCastAutoStart_Vtbl)).SetStartTime)(this, starttime), |result| Some(*result))
+        }
+        Self {
+            base__: <IWdsTransportNamespaceScheduledCast_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MinimumClients,
+            SetMinimumClients,
+            StartTime,
+            SetStartTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWdsTransportNamespaceScheduledCastAutoStart as windows_core::Interface>::IID || <IWdsTransportNamespaceScheduledCast_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWdsTransportNamespaceScheduledCastManualStart_Vtbl {
+    const PROXY: Self = { Self { base__: <IWdsTransportNamespaceScheduledCast_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWdsTransportNamespaceScheduledCastManualStart as windows_core::Interface>::IID || <IWdsTransportNamespaceScheduledCast_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWdsTransportServer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbszname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportServer", "Name", 7usize, |this| ((**(this as *const *const IWdsTransportServer_Vtbl)).Name)(this, pbszname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetupManager(this: *mut core::ffi::c_void, ppwdstransportsetupmanager: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportServer", "SetupManager", 8usize, |this| ((**(this as *const *const IWdsTransportServer_Vtbl)).SetupManager)(this, ppwdstransportsetupmanager), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ConfigurationManager(this: *mut core::ffi::c_void, ppwdstransportconfigurationmanager: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportServer", "ConfigurationManager", 9usize, |this| ((**(this as *const *const IWdsTransportServer_Vtbl)).ConfigurationManager)(this, ppwdstransportconfigurationmanager), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn NamespaceManager(this: *mut core::ffi::c_void, ppwdstransportnamespacemanager: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportServer", "NamespaceManager", 10usize, |this| ((**(this as *const *const IWdsTransportServer_Vtbl)).NamespaceManager)(this, ppwdstransportnamespacemanager), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisconnectClient(this: *mut core::ffi::c_void, ulclientid: u32, disconnectiontype: WDSTRANSPORT_DISCONNECT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportServer", "DisconnectClient", 11usize, |this| ((**(this as *const *const IWdsTransportServer_Vtbl)).DisconnectClient)(this, ulclientid, disconnectiontype), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            #[cfg(feature = "Win32_System_Com")]
+            SetupManager,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetupManager: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ConfigurationManager,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ConfigurationManager: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            NamespaceManager,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            NamespaceManager: 0,
+            DisconnectClient,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWdsTransportServer as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWdsTransportServer2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn TftpManager(this: *mut core::ffi::c_void, ppwdstransporttftpmanager: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportServer2", "TftpManager", 12usize, |this| ((**(this as *const *const IWdsTransportServer2_Vtbl)).TftpManager)(this, ppwdstransporttftpmanager), |result| Some(*result))
+        }
+        Self {
+            base__: <IWdsTransportServer_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            TftpManager,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            TftpManager: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWdsTransportServer2 as windows_core::Interface>::IID || <IWdsTransportServer_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWdsTransportServicePolicy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_IpAddressSource(this: *mut core::ffi::c_void, addresstype: WDSTRANSPORT_IP_ADDRESS_TYPE, psourcetype: *mut WDSTRANSPORT_IP_ADDRESS_SOURCE_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportServicePolicy", "get_IpAddressSource", 11usize, |this| ((**(this as *const *const IWdsTransportServicePolicy_Vtbl)).get_IpAddressSource)(this, addresstype, psourcetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_IpAddressSource(this: *mut core::ffi::c_void, addresstype: WDSTRANSPORT_IP_ADDRESS_TYPE, sourcetype: WDSTRANSPORT_IP_ADDRESS_SOURCE_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportServicePolicy", "put_IpAddressSource", 12usize, |this| ((**(this as *const *const IWdsTransportServicePolicy_Vtbl)).put_IpAddressSource)(this, addresstype, sourcetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_StartIpAddress(this: *mut core::ffi::c_void, addresstype: WDSTRANSPORT_IP_ADDRESS_TYPE, pbszstartipaddress: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportServicePolicy", "get_StartIpAddress", 13usize, |this| ((**(this as *const *const IWdsTransportServicePolicy_Vtbl)).get_StartIpAddress)(this, addresstype, pbszstartipaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_StartIpAddress(this: *mut core::ffi::c_void, addresstype: WDSTRANSPORT_IP_ADDRESS_TYPE, bszstartipaddress: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportServicePolicy", "put_StartIpAddress", 14usize, |this| ((**(this as *const *const IWdsTransportServicePolicy_Vtbl)).put_StartIpAddress)(this, addresstype, bszstartipaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_EndIpAddress(this: *mut core::ffi::c_void, addresstype: WDSTRANSPORT_IP_ADDRESS_TYPE, pbszendipaddress: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportServicePolicy", "get_EndIpAddress", 15usize, |this| ((**(this as *const *const IWdsTransportServicePolicy_Vtbl)).get_EndIpAddress)(this, addresstype, pbszendipaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_EndIpAddress(this: *mut core::ffi::c_void, addresstype: WDSTRANSPORT_IP_ADDRESS_TYPE, bszendipaddress: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportServicePolicy", "put_EndIpAddress", 16usize, |this| ((**(this as *const *const IWdsTransportServicePolicy_Vtbl)).put_EndIpAddress)(this, addresstype, bszendipaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartPort(this: *mut core::ffi::c_void, pulstartport: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportServicePolicy", "StartPort", 17usize, |this| ((**(this as *const *const IWdsTransportServicePolicy_Vtbl)).StartPort)(this, pulstartport), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartPort(this: *mut core::ffi::c_void, ulstartport: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportServicePolicy", "SetStartPort", 18usize, |this| ((**(this as *const *const IWdsTransportServicePolicy_Vtbl)).SetStartPort)(this, ulstartport), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndPort(this: *mut core::ffi::c_void, pulendport: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportServicePolicy", "EndPort", 19usize, |this| ((**(this as *const *const IWdsTransportServicePolicy_Vtbl)).EndPort)(this, pulendport), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEndPort(this: *mut core::ffi::c_void, ulendport: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportServicePolicy", "SetEndPort", 20usize, |this| ((**(this as *const *const IWdsTransportServicePolicy_Vtbl)).SetEndPort)(this, ulendport), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkProfile(this: *mut core::ffi::c_void, pprofiletype: *mut WDSTRANSPORT_NETWORK_PROFILE_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportServicePolicy", "NetworkProfile", 21usize, |this| ((**(this as *const *const IWdsTransportServicePolicy_Vtbl)).NetworkProfile)(this, pprofiletype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNetworkProfile(this: *mut core::ffi::c_void, profiletype: WDSTRANSPORT_NETWORK_PROFILE_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportServicePolicy", "SetNetworkProfile", 22usize, |this| ((**(this as *const *const IWdsTransportServicePolicy_Vtbl)).SetNetworkProfile)(this, profiletype), |result| Some(*result))
+        }
+        Self {
+            base__: <IWdsTransportCacheable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            get_IpAddressSource,
+            put_IpAddressSource,
+            get_StartIpAddress,
+            put_StartIpAddress,
+            get_EndIpAddress,
+            put_EndIpAddress,
+            StartPort,
+            SetStartPort,
+            EndPort,
+            SetEndPort,
+            NetworkProfile,
+            SetNetworkProfile,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWdsTransportServicePolicy as windows_core::Interface>::IID || <IWdsTransportCacheable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWdsTransportServicePolicy2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UdpPortPolicy(this: *mut core::ffi::c_void, pudpportpolicy: *mut WDSTRANSPORT_UDP_PORT_POLICY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportServicePolicy2", "UdpPortPolicy", 23usize, |this| ((**(this as *const *const IWdsTransportServicePolicy2_Vtbl)).UdpPortPolicy)(this, pudpportpolicy), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUdpPortPolicy(this: *mut core::ffi::c_void, udpportpolicy: WDSTRANSPORT_UDP_PORT_POLICY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportServicePolicy2", "SetUdpPortPolicy", 24usize, |this| ((**(this as *const *const IWdsTransportServicePolicy2_Vtbl)).SetUdpPortPolicy)(this, udpportpolicy), |result| Some(*result))
+        }
+        unsafe extern "system" fn TftpMaximumBlockSize(this: *mut core::ffi::c_void, pultftpmaximumblocksize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportServicePolicy2", "TftpMaximumBlockSize", 25usize, |this| ((**(this as *const *const IWdsTransportServicePolicy2_Vtbl)).TftpMaximumBlockSize)(this, pultftpmaximumblocksize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTftpMaximumBlockSize(this: *mut core::ffi::c_void, ultftpmaximumblocksize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportServicePolicy2", "SetTftpMaximumBlockSize", 26usize, |this| ((**(this as *const *const IWdsTransportServicePolicy2_Vtbl)).SetTftpMaximumBlockSize)(this, ultftpmaximumblocksize), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableTftpVariableWindowExtension(this: *mut core::ffi::c_void, pbenabletftpvariablewindowextension: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportServicePolicy2", "EnableTftpVariableWindowExtension", 27usize, |this| ((**(this as *const *const IWdsTransportServicePolicy2_Vtbl)).EnableTftpVariableWindowExtension)(this, pbenabletftpvariablewindowextension), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnableTftpVariableWindowExtension(this: *mut core::ffi::c_void, benabletftpvariablewindowextension: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportServicePolicy2", "SetEnableTftpVariableWindowExtension", 28usize, |this| ((**(this as *const *const IWdsTransportServicePolicy2_Vtbl)).SetEnableTftpVariableWindowExtension)(this, benabletftpvariablewindowextension), |result| Some(*result))
+        }
+        Self {
+            base__: <IWdsTransportServicePolicy_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            UdpPortPolicy,
+            SetUdpPortPolicy,
+            TftpMaximumBlockSize,
+            SetTftpMaximumBlockSize,
+            EnableTftpVariableWindowExtension,
+            SetEnableTftpVariableWindowExtension,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWdsTransportServicePolicy2 as windows_core::Interface>::IID || <IWdsTransportServicePolicy_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWdsTransportSession_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Content(this: *mut core::ffi::c_void, ppwdstransportcontent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportSession", "Content", 7usize, |this| ((**(this as *const *const IWdsTransportSession_Vtbl)).Content)(this, ppwdstransportcontent), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, pulid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportSession", "Id", 8usize, |this| ((**(this as *const *const IWdsTransportSession_Vtbl)).Id)(this, pulid), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkInterfaceName(this: *mut core::ffi::c_void, pbsznetworkinterfacename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportSession", "NetworkInterfaceName", 9usize, |this| ((**(this as *const *const IWdsTransportSession_Vtbl)).NetworkInterfaceName)(this, pbsznetworkinterfacename), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkInterfaceAddress(this: *mut core::ffi::c_void, pbsznetworkinterfaceaddress: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportSession", "NetworkInterfaceAddress", 10usize, |this| ((**(this as *const *const IWdsTransportSession_Vtbl)).NetworkInterfaceAddress)(this, pbsznetworkinterfaceaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransferRate(this: *mut core::ffi::c_void, pultransferrate: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportSession", "TransferRate", 11usize, |this| ((**(this as *const *const IWdsTransportSession_Vtbl)).TransferRate)(this, pultransferrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn MasterClientId(this: *mut core::ffi::c_void, pulmasterclientid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportSession", "MasterClientId", 12usize, |this| ((**(this as *const *const IWdsTransportSession_Vtbl)).MasterClientId)(this, pulmasterclientid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RetrieveClients(this: *mut core::ffi::c_void, ppwdstransportclients: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportSession", "RetrieveClients", 13usize, |this| ((**(this as *const *const IWdsTransportSession_Vtbl)).RetrieveClients)(this, ppwdstransportclients), |result| Some(*result))
+        }
+        unsafe extern "system" fn Terminate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportSession", "Terminate", 14usize, |this| ((**(this as *const *const IWdsTransportSession_Vtbl)).Terminate)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Content,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Content: 0,
+            Id,
+            NetworkInterfaceName,
+            NetworkInterfaceAddress,
+            TransferRate,
+            MasterClientId,
+            #[cfg(feature = "Win32_System_Com")]
+            RetrieveClients,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RetrieveClients: 0,
+            Terminate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWdsTransportSession as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWdsTransportSetupManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Version(this: *mut core::ffi::c_void, pullversion: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportSetupManager", "Version", 7usize, |this| ((**(this as *const *const IWdsTransportSetupManager_Vtbl)).Version)(this, pullversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstalledFeatures(this: *mut core::ffi::c_void, pulinstalledfeatures: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportSetupManager", "InstalledFeatures", 8usize, |this| ((**(this as *const *const IWdsTransportSetupManager_Vtbl)).InstalledFeatures)(this, pulinstalledfeatures), |result| Some(*result))
+        }
+        unsafe extern "system" fn Protocols(this: *mut core::ffi::c_void, pulprotocols: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportSetupManager", "Protocols", 9usize, |this| ((**(this as *const *const IWdsTransportSetupManager_Vtbl)).Protocols)(this, pulprotocols), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterContentProvider(this: *mut core::ffi::c_void, bszname: core::mem::MaybeUninit<windows_core::BSTR>, bszdescription: core::mem::MaybeUninit<windows_core::BSTR>, bszfilepath: core::mem::MaybeUninit<windows_core::BSTR>, bszinitializationroutine: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportSetupManager", "RegisterContentProvider", 10usize, |this| ((**(this as *const *const IWdsTransportSetupManager_Vtbl)).RegisterContentProvider)(this, bszname, bszdescription, bszfilepath, bszinitializationroutine), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeregisterContentProvider(this: *mut core::ffi::c_void, bszname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportSetupManager", "DeregisterContentProvider", 11usize, |this| ((**(this as *const *const IWdsTransportSetupManager_Vtbl)).DeregisterContentProvider)(this, bszname), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Version,
+            InstalledFeatures,
+            Protocols,
+            RegisterContentProvider,
+            DeregisterContentProvider,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWdsTransportSetupManager as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWdsTransportSetupManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TftpCapabilities(this: *mut core::ffi::c_void, pultftpcapabilities: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportSetupManager2", "TftpCapabilities", 12usize, |this| ((**(this as *const *const IWdsTransportSetupManager2_Vtbl)).TftpCapabilities)(this, pultftpcapabilities), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ContentProviders(this: *mut core::ffi::c_void, ppprovidercollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportSetupManager2", "ContentProviders", 13usize, |this| ((**(this as *const *const IWdsTransportSetupManager2_Vtbl)).ContentProviders)(this, ppprovidercollection), |result| Some(*result))
+        }
+        Self {
+            base__: <IWdsTransportSetupManager_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TftpCapabilities,
+            #[cfg(feature = "Win32_System_Com")]
+            ContentProviders,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ContentProviders: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWdsTransportSetupManager2 as windows_core::Interface>::IID || <IWdsTransportSetupManager_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWdsTransportTftpClient_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FileName(this: *mut core::ffi::c_void, pbszfilename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportTftpClient", "FileName", 7usize, |this| ((**(this as *const *const IWdsTransportTftpClient_Vtbl)).FileName)(this, pbszfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn IpAddress(this: *mut core::ffi::c_void, pbszipaddress: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportTftpClient", "IpAddress", 8usize, |this| ((**(this as *const *const IWdsTransportTftpClient_Vtbl)).IpAddress)(this, pbszipaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn Timeout(this: *mut core::ffi::c_void, pultimeout: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportTftpClient", "Timeout", 9usize, |this| ((**(this as *const *const IWdsTransportTftpClient_Vtbl)).Timeout)(this, pultimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentFileOffset(this: *mut core::ffi::c_void, pul64currentoffset: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportTftpClient", "CurrentFileOffset", 10usize, |this| ((**(this as *const *const IWdsTransportTftpClient_Vtbl)).CurrentFileOffset)(this, pul64currentoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn FileSize(this: *mut core::ffi::c_void, pul64filesize: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportTftpClient", "FileSize", 11usize, |this| ((**(this as *const *const IWdsTransportTftpClient_Vtbl)).FileSize)(this, pul64filesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn BlockSize(this: *mut core::ffi::c_void, pulblocksize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportTftpClient", "BlockSize", 12usize, |this| ((**(this as *const *const IWdsTransportTftpClient_Vtbl)).BlockSize)(this, pulblocksize), |result| Some(*result))
+        }
+        unsafe extern "system" fn WindowSize(this: *mut core::ffi::c_void, pulwindowsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportTftpClient", "WindowSize", 13usize, |this| ((**(this as *const *const IWdsTransportTftpClient_Vtbl)).WindowSize)(this, pulwindowsize), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FileName,
+            IpAddress,
+            Timeout,
+            CurrentFileOffset,
+            FileSize,
+            BlockSize,
+            WindowSize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWdsTransportTftpClient as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWdsTransportTftpManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RetrieveTftpClients(this: *mut core::ffi::c_void, ppwdstransporttftpclients: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWdsTransportTftpManager", "RetrieveTftpClients", 7usize, |this| ((**(this as *const *const IWdsTransportTftpManager_Vtbl)).RetrieveTftpClients)(this, ppwdstransporttftpclients), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            RetrieveTftpClients,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RetrieveTftpClients: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWdsTransportTftpManager as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
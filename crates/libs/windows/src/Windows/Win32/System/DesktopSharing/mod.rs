@@ -1473,6 +1473,7 @@ pub struct ATTENDEE_DISCONNECT_REASON(pub i32);
 impl windows_core::TypeKind for ATTENDEE_DISCONNECT_REASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ATTENDEE_DISCONNECT_REASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ATTENDEE_DISCONNECT_REASON").field(&self.0).finish()
@@ -1484,6 +1485,7 @@ pub struct CHANNEL_ACCESS_ENUM(pub i32);
 impl windows_core::TypeKind for CHANNEL_ACCESS_ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CHANNEL_ACCESS_ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CHANNEL_ACCESS_ENUM").field(&self.0).finish()
@@ -1495,6 +1497,7 @@ pub struct CHANNEL_FLAGS(pub i32);
 impl windows_core::TypeKind for CHANNEL_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CHANNEL_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CHANNEL_FLAGS").field(&self.0).finish()
@@ -1506,6 +1509,7 @@ pub struct CHANNEL_PRIORITY(pub i32);
 impl windows_core::TypeKind for CHANNEL_PRIORITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CHANNEL_PRIORITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CHANNEL_PRIORITY").field(&self.0).finish()
@@ -1517,6 +1521,7 @@ pub struct CTRL_LEVEL(pub i32);
 impl windows_core::TypeKind for CTRL_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CTRL_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CTRL_LEVEL").field(&self.0).finish()
@@ -1528,6 +1533,7 @@ pub struct RDPENCOMAPI_ATTENDEE_FLAGS(pub i32);
 impl windows_core::TypeKind for RDPENCOMAPI_ATTENDEE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RDPENCOMAPI_ATTENDEE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RDPENCOMAPI_ATTENDEE_FLAGS").field(&self.0).finish()
@@ -1539,6 +1545,7 @@ pub struct RDPENCOMAPI_CONSTANTS(pub i32);
 impl windows_core::TypeKind for RDPENCOMAPI_CONSTANTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RDPENCOMAPI_CONSTANTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RDPENCOMAPI_CONSTANTS").field(&self.0).finish()
@@ -1550,6 +1557,7 @@ pub struct RDPSRAPI_APP_FLAGS(pub i32);
 impl windows_core::TypeKind for RDPSRAPI_APP_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RDPSRAPI_APP_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RDPSRAPI_APP_FLAGS").field(&self.0).finish()
@@ -1561,6 +1569,7 @@ pub struct RDPSRAPI_KBD_CODE_TYPE(pub i32);
 impl windows_core::TypeKind for RDPSRAPI_KBD_CODE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RDPSRAPI_KBD_CODE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RDPSRAPI_KBD_CODE_TYPE").field(&self.0).finish()
@@ -1572,6 +1581,7 @@ pub struct RDPSRAPI_KBD_SYNC_FLAG(pub i32);
 impl windows_core::TypeKind for RDPSRAPI_KBD_SYNC_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RDPSRAPI_KBD_SYNC_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RDPSRAPI_KBD_SYNC_FLAG").field(&self.0).finish()
@@ -1583,6 +1593,7 @@ pub struct RDPSRAPI_MOUSE_BUTTON_TYPE(pub i32);
 impl windows_core::TypeKind for RDPSRAPI_MOUSE_BUTTON_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RDPSRAPI_MOUSE_BUTTON_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RDPSRAPI_MOUSE_BUTTON_TYPE").field(&self.0).finish()
@@ -1594,6 +1605,7 @@ pub struct RDPSRAPI_WND_FLAGS(pub i32);
 impl windows_core::TypeKind for RDPSRAPI_WND_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RDPSRAPI_WND_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RDPSRAPI_WND_FLAGS").field(&self.0).finish()
@@ -1617,7 +1629,8 @@ pub const RDPTransportStreamBuffer: windows_core::GUID = windows_core::GUID::fro
 pub const RDPTransportStreamEvents: windows_core::GUID = windows_core::GUID::from_u128(0x31e3ab20_5350_483f_9dc6_6748665efdeb);
 pub const RDPViewer: windows_core::GUID = windows_core::GUID::from_u128(0x32be5ed2_5c86_480f_a914_0ff8885a1b3f);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct __ReferenceRemainingTypes__ {
     pub __ctrlLevel__: CTRL_LEVEL,
     pub __attendeeDisconnectReason__: ATTENDEE_DISCONNECT_REASON,
@@ -1638,3 +1651,5 @@ impl Default for __ReferenceRemainingTypes__ {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
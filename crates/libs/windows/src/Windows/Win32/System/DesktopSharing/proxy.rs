@@ -0,0 +1,912 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRDPSRAPIApplication_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Windows(this: *mut core::ffi::c_void, pwindowlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIApplication", "Windows", 7usize, |this| ((**(this as *const *const IRDPSRAPIApplication_Vtbl)).Windows)(this, pwindowlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, pretval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIApplication", "Id", 8usize, |this| ((**(this as *const *const IRDPSRAPIApplication_Vtbl)).Id)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shared(this: *mut core::ffi::c_void, pretval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIApplication", "Shared", 9usize, |this| ((**(this as *const *const IRDPSRAPIApplication_Vtbl)).Shared)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShared(this: *mut core::ffi::c_void, newval: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIApplication", "SetShared", 10usize, |this| ((**(this as *const *const IRDPSRAPIApplication_Vtbl)).SetShared)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pretval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIApplication", "Name", 11usize, |this| ((**(this as *const *const IRDPSRAPIApplication_Vtbl)).Name)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flags(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIApplication", "Flags", 12usize, |this| ((**(this as *const *const IRDPSRAPIApplication_Vtbl)).Flags)(this, pdwflags), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Windows,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Windows: 0,
+            Id,
+            Shared,
+            SetShared,
+            Name,
+            Flags,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRDPSRAPIApplication as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRDPSRAPIApplicationFilter_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Applications(this: *mut core::ffi::c_void, papplications: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIApplicationFilter", "Applications", 7usize, |this| ((**(this as *const *const IRDPSRAPIApplicationFilter_Vtbl)).Applications)(this, papplications), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Windows(this: *mut core::ffi::c_void, pwindows: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIApplicationFilter", "Windows", 8usize, |this| ((**(this as *const *const IRDPSRAPIApplicationFilter_Vtbl)).Windows)(this, pwindows), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, pretval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIApplicationFilter", "Enabled", 9usize, |this| ((**(this as *const *const IRDPSRAPIApplicationFilter_Vtbl)).Enabled)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, newval: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIApplicationFilter", "SetEnabled", 10usize, |this| ((**(this as *const *const IRDPSRAPIApplicationFilter_Vtbl)).SetEnabled)(this, newval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Applications,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Applications: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Windows,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Windows: 0,
+            Enabled,
+            SetEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRDPSRAPIApplicationFilter as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRDPSRAPIApplicationList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIApplicationList", "_NewEnum", 7usize, |this| ((**(this as *const *const IRDPSRAPIApplicationList_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, item: i32, papplication: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIApplicationList", "get_Item", 8usize, |this| ((**(this as *const *const IRDPSRAPIApplicationList_Vtbl)).get_Item)(this, item, papplication), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRDPSRAPIApplicationList as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRDPSRAPIAttendee_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, pid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIAttendee", "Id", 7usize, |this| ((**(this as *const *const IRDPSRAPIAttendee_Vtbl)).Id)(this, pid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteName(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIAttendee", "RemoteName", 8usize, |this| ((**(this as *const *const IRDPSRAPIAttendee_Vtbl)).RemoteName)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ControlLevel(this: *mut core::ffi::c_void, pval: *mut CTRL_LEVEL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIAttendee", "ControlLevel", 9usize, |this| ((**(this as *const *const IRDPSRAPIAttendee_Vtbl)).ControlLevel)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetControlLevel(this: *mut core::ffi::c_void, pnewval: CTRL_LEVEL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIAttendee", "SetControlLevel", 10usize, |this| ((**(this as *const *const IRDPSRAPIAttendee_Vtbl)).SetControlLevel)(this, pnewval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Invitation(this: *mut core::ffi::c_void, ppval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIAttendee", "Invitation", 11usize, |this| ((**(this as *const *const IRDPSRAPIAttendee_Vtbl)).Invitation)(this, ppval), |result| Some(*result))
+        }
+        unsafe extern "system" fn TerminateConnection(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIAttendee", "TerminateConnection", 12usize, |this| ((**(this as *const *const IRDPSRAPIAttendee_Vtbl)).TerminateConnection)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flags(this: *mut core::ffi::c_void, plflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIAttendee", "Flags", 13usize, |this| ((**(this as *const *const IRDPSRAPIAttendee_Vtbl)).Flags)(this, plflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectivityInfo(this: *mut core::ffi::c_void, ppval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIAttendee", "ConnectivityInfo", 14usize, |this| ((**(this as *const *const IRDPSRAPIAttendee_Vtbl)).ConnectivityInfo)(this, ppval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            RemoteName,
+            ControlLevel,
+            SetControlLevel,
+            #[cfg(feature = "Win32_System_Com")]
+            Invitation,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Invitation: 0,
+            TerminateConnection,
+            Flags,
+            ConnectivityInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRDPSRAPIAttendee as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRDPSRAPIAttendeeDisconnectInfo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Attendee(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIAttendeeDisconnectInfo", "Attendee", 7usize, |this| ((**(this as *const *const IRDPSRAPIAttendeeDisconnectInfo_Vtbl)).Attendee)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reason(this: *mut core::ffi::c_void, preason: *mut ATTENDEE_DISCONNECT_REASON) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIAttendeeDisconnectInfo", "Reason", 8usize, |this| ((**(this as *const *const IRDPSRAPIAttendeeDisconnectInfo_Vtbl)).Reason)(this, preason), |result| Some(*result))
+        }
+        unsafe extern "system" fn Code(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIAttendeeDisconnectInfo", "Code", 9usize, |this| ((**(this as *const *const IRDPSRAPIAttendeeDisconnectInfo_Vtbl)).Code)(this, pval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Attendee,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Attendee: 0,
+            Reason,
+            Code,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRDPSRAPIAttendeeDisconnectInfo as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRDPSRAPIAttendeeManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIAttendeeManager", "_NewEnum", 7usize, |this| ((**(this as *const *const IRDPSRAPIAttendeeManager_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, id: i32, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIAttendeeManager", "get_Item", 8usize, |this| ((**(this as *const *const IRDPSRAPIAttendeeManager_Vtbl)).get_Item)(this, id, ppitem), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRDPSRAPIAttendeeManager as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRDPSRAPIAudioStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pnperiodinhundrednsintervals: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIAudioStream", "Initialize", 3usize, |this| ((**(this as *const *const IRDPSRAPIAudioStream_Vtbl)).Initialize)(this, pnperiodinhundrednsintervals), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIAudioStream", "Start", 4usize, |this| ((**(this as *const *const IRDPSRAPIAudioStream_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIAudioStream", "Stop", 5usize, |this| ((**(this as *const *const IRDPSRAPIAudioStream_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBuffer(this: *mut core::ffi::c_void, ppbdata: *mut *mut u8, pcbdata: *mut u32, ptimestamp: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIAudioStream", "GetBuffer", 6usize, |this| ((**(this as *const *const IRDPSRAPIAudioStream_Vtbl)).GetBuffer)(this, ppbdata, pcbdata, ptimestamp), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreeBuffer(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIAudioStream", "FreeBuffer", 7usize, |this| ((**(this as *const *const IRDPSRAPIAudioStream_Vtbl)).FreeBuffer)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, Start, Stop, GetBuffer, FreeBuffer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRDPSRAPIAudioStream as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRDPSRAPIClipboardUseEvents_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnPasteFromClipboard(this: *mut core::ffi::c_void, clipboardformat: u32, pattendee: *mut core::ffi::c_void, pretval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIClipboardUseEvents", "OnPasteFromClipboard", 3usize, |this| ((**(this as *const *const IRDPSRAPIClipboardUseEvents_Vtbl)).OnPasteFromClipboard)(this, clipboardformat, pattendee, pretval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            OnPasteFromClipboard,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnPasteFromClipboard: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRDPSRAPIClipboardUseEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRDPSRAPIDebug_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetCLXCmdLine(this: *mut core::ffi::c_void, clxcmdline: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIDebug", "SetCLXCmdLine", 3usize, |this| ((**(this as *const *const IRDPSRAPIDebug_Vtbl)).SetCLXCmdLine)(this, clxcmdline), |result| Some(*result))
+        }
+        unsafe extern "system" fn CLXCmdLine(this: *mut core::ffi::c_void, pclxcmdline: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIDebug", "CLXCmdLine", 4usize, |this| ((**(this as *const *const IRDPSRAPIDebug_Vtbl)).CLXCmdLine)(this, pclxcmdline), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetCLXCmdLine, CLXCmdLine }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRDPSRAPIDebug as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRDPSRAPIFrameBuffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Width(this: *mut core::ffi::c_void, plwidth: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIFrameBuffer", "Width", 7usize, |this| ((**(this as *const *const IRDPSRAPIFrameBuffer_Vtbl)).Width)(this, plwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn Height(this: *mut core::ffi::c_void, plheight: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIFrameBuffer", "Height", 8usize, |this| ((**(this as *const *const IRDPSRAPIFrameBuffer_Vtbl)).Height)(this, plheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bpp(this: *mut core::ffi::c_void, plbpp: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIFrameBuffer", "Bpp", 9usize, |this| ((**(this as *const *const IRDPSRAPIFrameBuffer_Vtbl)).Bpp)(this, plbpp), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFrameBufferBits(this: *mut core::ffi::c_void, x: i32, y: i32, width: i32, heigth: i32, ppbits: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIFrameBuffer", "GetFrameBufferBits", 10usize, |this| ((**(this as *const *const IRDPSRAPIFrameBuffer_Vtbl)).GetFrameBufferBits)(this, x, y, width, heigth, ppbits), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Width,
+            Height,
+            Bpp,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFrameBufferBits,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFrameBufferBits: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRDPSRAPIFrameBuffer as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRDPSRAPIInvitation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConnectionString(this: *mut core::ffi::c_void, pbstrval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIInvitation", "ConnectionString", 7usize, |this| ((**(this as *const *const IRDPSRAPIInvitation_Vtbl)).ConnectionString)(this, pbstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GroupName(this: *mut core::ffi::c_void, pbstrval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIInvitation", "GroupName", 8usize, |this| ((**(this as *const *const IRDPSRAPIInvitation_Vtbl)).GroupName)(this, pbstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Password(this: *mut core::ffi::c_void, pbstrval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIInvitation", "Password", 9usize, |this| ((**(this as *const *const IRDPSRAPIInvitation_Vtbl)).Password)(this, pbstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttendeeLimit(this: *mut core::ffi::c_void, pretval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIInvitation", "AttendeeLimit", 10usize, |this| ((**(this as *const *const IRDPSRAPIInvitation_Vtbl)).AttendeeLimit)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAttendeeLimit(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIInvitation", "SetAttendeeLimit", 11usize, |this| ((**(this as *const *const IRDPSRAPIInvitation_Vtbl)).SetAttendeeLimit)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Revoked(this: *mut core::ffi::c_void, pretval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIInvitation", "Revoked", 12usize, |this| ((**(this as *const *const IRDPSRAPIInvitation_Vtbl)).Revoked)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRevoked(this: *mut core::ffi::c_void, newval: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIInvitation", "SetRevoked", 13usize, |this| ((**(this as *const *const IRDPSRAPIInvitation_Vtbl)).SetRevoked)(this, newval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ConnectionString,
+            GroupName,
+            Password,
+            AttendeeLimit,
+            SetAttendeeLimit,
+            Revoked,
+            SetRevoked,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRDPSRAPIInvitation as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRDPSRAPIInvitationManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIInvitationManager", "_NewEnum", 7usize, |this| ((**(this as *const *const IRDPSRAPIInvitationManager_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, item: core::mem::MaybeUninit<windows_core::VARIANT>, ppinvitation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIInvitationManager", "get_Item", 8usize, |this| ((**(this as *const *const IRDPSRAPIInvitationManager_Vtbl)).get_Item)(this, item, ppinvitation), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pretval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIInvitationManager", "Count", 9usize, |this| ((**(this as *const *const IRDPSRAPIInvitationManager_Vtbl)).Count)(this, pretval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateInvitation(this: *mut core::ffi::c_void, bstrauthstring: core::mem::MaybeUninit<windows_core::BSTR>, bstrgroupname: core::mem::MaybeUninit<windows_core::BSTR>, bstrpassword: core::mem::MaybeUninit<windows_core::BSTR>, attendeelimit: i32, ppinvitation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIInvitationManager", "CreateInvitation", 10usize, |this| ((**(this as *const *const IRDPSRAPIInvitationManager_Vtbl)).CreateInvitation)(this, bstrauthstring, bstrgroupname, bstrpassword, attendeelimit, ppinvitation), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateInvitation,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateInvitation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRDPSRAPIInvitationManager as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRDPSRAPIPerfCounterLogger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LogValue(this: *mut core::ffi::c_void, lvalue: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIPerfCounterLogger", "LogValue", 3usize, |this| ((**(this as *const *const IRDPSRAPIPerfCounterLogger_Vtbl)).LogValue)(this, lvalue), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LogValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRDPSRAPIPerfCounterLogger as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRDPSRAPIPerfCounterLoggingManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateLogger(this: *mut core::ffi::c_void, bstrcountername: core::mem::MaybeUninit<windows_core::BSTR>, pplogger: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIPerfCounterLoggingManager", "CreateLogger", 3usize, |this| ((**(this as *const *const IRDPSRAPIPerfCounterLoggingManager_Vtbl)).CreateLogger)(this, bstrcountername, pplogger), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateLogger }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRDPSRAPIPerfCounterLoggingManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRDPSRAPISessionProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_Property(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::BSTR>, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPISessionProperties", "get_Property", 7usize, |this| ((**(this as *const *const IRDPSRAPISessionProperties_Vtbl)).get_Property)(this, propertyname, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_Property(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::BSTR>, newval: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPISessionProperties", "put_Property", 8usize, |this| ((**(this as *const *const IRDPSRAPISessionProperties_Vtbl)).put_Property)(this, propertyname, newval), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, get_Property, put_Property }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRDPSRAPISessionProperties as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRDPSRAPISharingSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPISharingSession", "Open", 7usize, |this| ((**(this as *const *const IRDPSRAPISharingSession_Vtbl)).Open)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPISharingSession", "Close", 8usize, |this| ((**(this as *const *const IRDPSRAPISharingSession_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColorDepth(this: *mut core::ffi::c_void, colordepth: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPISharingSession", "SetColorDepth", 9usize, |this| ((**(this as *const *const IRDPSRAPISharingSession_Vtbl)).SetColorDepth)(this, colordepth), |result| Some(*result))
+        }
+        unsafe extern "system" fn ColorDepth(this: *mut core::ffi::c_void, pcolordepth: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPISharingSession", "ColorDepth", 10usize, |this| ((**(this as *const *const IRDPSRAPISharingSession_Vtbl)).ColorDepth)(this, pcolordepth), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, ppval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPISharingSession", "Properties", 11usize, |this| ((**(this as *const *const IRDPSRAPISharingSession_Vtbl)).Properties)(this, ppval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Attendees(this: *mut core::ffi::c_void, ppval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPISharingSession", "Attendees", 12usize, |this| ((**(this as *const *const IRDPSRAPISharingSession_Vtbl)).Attendees)(this, ppval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Invitations(this: *mut core::ffi::c_void, ppval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPISharingSession", "Invitations", 13usize, |this| ((**(this as *const *const IRDPSRAPISharingSession_Vtbl)).Invitations)(this, ppval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ApplicationFilter(this: *mut core::ffi::c_void, ppval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPISharingSession", "ApplicationFilter", 14usize, |this| ((**(this as *const *const IRDPSRAPISharingSession_Vtbl)).ApplicationFilter)(this, ppval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn VirtualChannelManager(this: *mut core::ffi::c_void, ppval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPISharingSession", "VirtualChannelManager", 15usize, |this| ((**(this as *const *const IRDPSRAPISharingSession_Vtbl)).VirtualChannelManager)(this, ppval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPISharingSession", "Pause", 16usize, |this| ((**(this as *const *const IRDPSRAPISharingSession_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPISharingSession", "Resume", 17usize, |this| ((**(this as *const *const IRDPSRAPISharingSession_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectToClient(this: *mut core::ffi::c_void, bstrconnectionstring: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPISharingSession", "ConnectToClient", 18usize, |this| ((**(this as *const *const IRDPSRAPISharingSession_Vtbl)).ConnectToClient)(this, bstrconnectionstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesktopSharedRect(this: *mut core::ffi::c_void, left: i32, top: i32, right: i32, bottom: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPISharingSession", "SetDesktopSharedRect", 19usize, |this| ((**(this as *const *const IRDPSRAPISharingSession_Vtbl)).SetDesktopSharedRect)(this, left, top, right, bottom), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDesktopSharedRect(this: *mut core::ffi::c_void, pleft: *mut i32, ptop: *mut i32, pright: *mut i32, pbottom: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPISharingSession", "GetDesktopSharedRect", 20usize, |this| ((**(this as *const *const IRDPSRAPISharingSession_Vtbl)).GetDesktopSharedRect)(this, pleft, ptop, pright, pbottom), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Open,
+            Close,
+            SetColorDepth,
+            ColorDepth,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Attendees,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Attendees: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Invitations,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Invitations: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ApplicationFilter,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ApplicationFilter: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            VirtualChannelManager,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            VirtualChannelManager: 0,
+            Pause,
+            Resume,
+            ConnectToClient,
+            SetDesktopSharedRect,
+            GetDesktopSharedRect,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRDPSRAPISharingSession as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRDPSRAPISharingSession2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConnectUsingTransportStream(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, bstrgroup: core::mem::MaybeUninit<windows_core::BSTR>, bstrauthenticatedattendeename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPISharingSession2", "ConnectUsingTransportStream", 21usize, |this| ((**(this as *const *const IRDPSRAPISharingSession2_Vtbl)).ConnectUsingTransportStream)(this, pstream, bstrgroup, bstrauthenticatedattendeename), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn FrameBuffer(this: *mut core::ffi::c_void, ppval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPISharingSession2", "FrameBuffer", 22usize, |this| ((**(this as *const *const IRDPSRAPISharingSession2_Vtbl)).FrameBuffer)(this, ppval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SendControlLevelChangeResponse(this: *mut core::ffi::c_void, pattendee: *mut core::ffi::c_void, requestedlevel: CTRL_LEVEL, reasoncode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPISharingSession2", "SendControlLevelChangeResponse", 23usize, |this| ((**(this as *const *const IRDPSRAPISharingSession2_Vtbl)).SendControlLevelChangeResponse)(this, pattendee, requestedlevel, reasoncode), |result| Some(*result))
+        }
+        Self {
+            base__: <IRDPSRAPISharingSession_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ConnectUsingTransportStream,
+            #[cfg(feature = "Win32_System_Com")]
+            FrameBuffer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            FrameBuffer: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SendControlLevelChangeResponse,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SendControlLevelChangeResponse: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRDPSRAPISharingSession2 as windows_core::Interface>::IID || <IRDPSRAPISharingSession_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRDPSRAPITcpConnectionInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Protocol(this: *mut core::ffi::c_void, plprotocol: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPITcpConnectionInfo", "Protocol", 7usize, |this| ((**(this as *const *const IRDPSRAPITcpConnectionInfo_Vtbl)).Protocol)(this, plprotocol), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalPort(this: *mut core::ffi::c_void, plport: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPITcpConnectionInfo", "LocalPort", 8usize, |this| ((**(this as *const *const IRDPSRAPITcpConnectionInfo_Vtbl)).LocalPort)(this, plport), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalIP(this: *mut core::ffi::c_void, pbsrlocalip: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPITcpConnectionInfo", "LocalIP", 9usize, |this| ((**(this as *const *const IRDPSRAPITcpConnectionInfo_Vtbl)).LocalIP)(this, pbsrlocalip), |result| Some(*result))
+        }
+        unsafe extern "system" fn PeerPort(this: *mut core::ffi::c_void, plport: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPITcpConnectionInfo", "PeerPort", 10usize, |this| ((**(this as *const *const IRDPSRAPITcpConnectionInfo_Vtbl)).PeerPort)(this, plport), |result| Some(*result))
+        }
+        unsafe extern "system" fn PeerIP(this: *mut core::ffi::c_void, pbstrip: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPITcpConnectionInfo", "PeerIP", 11usize, |this| ((**(this as *const *const IRDPSRAPITcpConnectionInfo_Vtbl)).PeerIP)(this, pbstrip), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Protocol, LocalPort, LocalIP, PeerPort, PeerIP }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRDPSRAPITcpConnectionInfo as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRDPSRAPITransportStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AllocBuffer(this: *mut core::ffi::c_void, maxpayload: i32, ppbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPITransportStream", "AllocBuffer", 3usize, |this| ((**(this as *const *const IRDPSRAPITransportStream_Vtbl)).AllocBuffer)(this, maxpayload, ppbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreeBuffer(this: *mut core::ffi::c_void, pbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPITransportStream", "FreeBuffer", 4usize, |this| ((**(this as *const *const IRDPSRAPITransportStream_Vtbl)).FreeBuffer)(this, pbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteBuffer(this: *mut core::ffi::c_void, pbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPITransportStream", "WriteBuffer", 5usize, |this| ((**(this as *const *const IRDPSRAPITransportStream_Vtbl)).WriteBuffer)(this, pbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadBuffer(this: *mut core::ffi::c_void, pbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPITransportStream", "ReadBuffer", 6usize, |this| ((**(this as *const *const IRDPSRAPITransportStream_Vtbl)).ReadBuffer)(this, pbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, pcallbacks: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPITransportStream", "Open", 7usize, |this| ((**(this as *const *const IRDPSRAPITransportStream_Vtbl)).Open)(this, pcallbacks), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPITransportStream", "Close", 8usize, |this| ((**(this as *const *const IRDPSRAPITransportStream_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AllocBuffer, FreeBuffer, WriteBuffer, ReadBuffer, Open, Close }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRDPSRAPITransportStream as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRDPSRAPITransportStreamBuffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Storage(this: *mut core::ffi::c_void, ppbstorage: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPITransportStreamBuffer", "Storage", 3usize, |this| ((**(this as *const *const IRDPSRAPITransportStreamBuffer_Vtbl)).Storage)(this, ppbstorage), |result| Some(*result))
+        }
+        unsafe extern "system" fn StorageSize(this: *mut core::ffi::c_void, plmaxstore: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPITransportStreamBuffer", "StorageSize", 4usize, |this| ((**(this as *const *const IRDPSRAPITransportStreamBuffer_Vtbl)).StorageSize)(this, plmaxstore), |result| Some(*result))
+        }
+        unsafe extern "system" fn PayloadSize(this: *mut core::ffi::c_void, plretval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPITransportStreamBuffer", "PayloadSize", 5usize, |this| ((**(this as *const *const IRDPSRAPITransportStreamBuffer_Vtbl)).PayloadSize)(this, plretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPayloadSize(this: *mut core::ffi::c_void, lval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPITransportStreamBuffer", "SetPayloadSize", 6usize, |this| ((**(this as *const *const IRDPSRAPITransportStreamBuffer_Vtbl)).SetPayloadSize)(this, lval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PayloadOffset(this: *mut core::ffi::c_void, plretval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPITransportStreamBuffer", "PayloadOffset", 7usize, |this| ((**(this as *const *const IRDPSRAPITransportStreamBuffer_Vtbl)).PayloadOffset)(this, plretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPayloadOffset(this: *mut core::ffi::c_void, lretval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPITransportStreamBuffer", "SetPayloadOffset", 8usize, |this| ((**(this as *const *const IRDPSRAPITransportStreamBuffer_Vtbl)).SetPayloadOffset)(this, lretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flags(this: *mut core::ffi::c_void, plflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPITransportStreamBuffer", "Flags", 9usize, |this| ((**(this as *const *const IRDPSRAPITransportStreamBuffer_Vtbl)).Flags)(this, plflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFlags(this: *mut core::ffi::c_void, lflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPITransportStreamBuffer", "SetFlags", 10usize, |this| ((**(this as *const *const IRDPSRAPITransportStreamBuffer_Vtbl)).SetFlags)(this, lflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Context(this: *mut core::ffi::c_void, ppcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPITransportStreamBuffer", "Context", 11usize, |this| ((**(this as *const *const IRDPSRAPITransportStreamBuffer_Vtbl)).Context)(this, ppcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContext(this: *mut core::ffi::c_void, pcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPITransportStreamBuffer", "SetContext", 12usize, |this| ((**(this as *const *const IRDPSRAPITransportStreamBuffer_Vtbl)).SetContext)(this, pcontext), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Storage,
+            StorageSize,
+            PayloadSize,
+            SetPayloadSize,
+            PayloadOffset,
+            SetPayloadOffset,
+            Flags,
+            SetFlags,
+            Context,
+            SetContext,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRDPSRAPITransportStreamBuffer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRDPSRAPITransportStreamEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnWriteCompleted(this: *mut core::ffi::c_void, pbuffer: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IRDPSRAPITransportStreamEvents", "OnWriteCompleted", 3usize, |this| ((**(this as *const *const IRDPSRAPITransportStreamEvents_Vtbl)).OnWriteCompleted)(this, pbuffer), |_| None)
+        }
+        unsafe extern "system" fn OnReadCompleted(this: *mut core::ffi::c_void, pbuffer: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IRDPSRAPITransportStreamEvents", "OnReadCompleted", 4usize, |this| ((**(this as *const *const IRDPSRAPITransportStreamEvents_Vtbl)).OnReadCompleted)(this, pbuffer), |_| None)
+        }
+        unsafe extern "system" fn OnStreamClosed(this: *mut core::ffi::c_void, hrreason: windows_core::HRESULT) {
+            windows_core::imp::proxy_call(this, "IRDPSRAPITransportStreamEvents", "OnStreamClosed", 5usize, |this| ((**(this as *const *const IRDPSRAPITransportStreamEvents_Vtbl)).OnStreamClosed)(this, hrreason), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnWriteCompleted, OnReadCompleted, OnStreamClosed }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRDPSRAPITransportStreamEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRDPSRAPIViewer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Connect(this: *mut core::ffi::c_void, bstrconnectionstring: core::mem::MaybeUninit<windows_core::BSTR>, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, bstrpassword: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIViewer", "Connect", 7usize, |this| ((**(this as *const *const IRDPSRAPIViewer_Vtbl)).Connect)(this, bstrconnectionstring, bstrname, bstrpassword), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIViewer", "Disconnect", 8usize, |this| ((**(this as *const *const IRDPSRAPIViewer_Vtbl)).Disconnect)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Attendees(this: *mut core::ffi::c_void, ppval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIViewer", "Attendees", 9usize, |this| ((**(this as *const *const IRDPSRAPIViewer_Vtbl)).Attendees)(this, ppval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Invitations(this: *mut core::ffi::c_void, ppval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIViewer", "Invitations", 10usize, |this| ((**(this as *const *const IRDPSRAPIViewer_Vtbl)).Invitations)(this, ppval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ApplicationFilter(this: *mut core::ffi::c_void, ppval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIViewer", "ApplicationFilter", 11usize, |this| ((**(this as *const *const IRDPSRAPIViewer_Vtbl)).ApplicationFilter)(this, ppval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn VirtualChannelManager(this: *mut core::ffi::c_void, ppval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIViewer", "VirtualChannelManager", 12usize, |this| ((**(this as *const *const IRDPSRAPIViewer_Vtbl)).VirtualChannelManager)(this, ppval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSmartSizing(this: *mut core::ffi::c_void, vbsmartsizing: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIViewer", "SetSmartSizing", 13usize, |this| ((**(this as *const *const IRDPSRAPIViewer_Vtbl)).SetSmartSizing)(this, vbsmartsizing), |result| Some(*result))
+        }
+        unsafe extern "system" fn SmartSizing(this: *mut core::ffi::c_void, pvbsmartsizing: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIViewer", "SmartSizing", 14usize, |this| ((**(this as *const *const IRDPSRAPIViewer_Vtbl)).SmartSizing)(this, pvbsmartsizing), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestControl(this: *mut core::ffi::c_void, ctrllevel: CTRL_LEVEL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIViewer", "RequestControl", 15usize, |this| ((**(this as *const *const IRDPSRAPIViewer_Vtbl)).RequestControl)(this, ctrllevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisconnectedText(this: *mut core::ffi::c_void, bstrdisconnectedtext: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIViewer", "SetDisconnectedText", 16usize, |this| ((**(this as *const *const IRDPSRAPIViewer_Vtbl)).SetDisconnectedText)(this, bstrdisconnectedtext), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisconnectedText(this: *mut core::ffi::c_void, pbstrdisconnectedtext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIViewer", "DisconnectedText", 17usize, |this| ((**(this as *const *const IRDPSRAPIViewer_Vtbl)).DisconnectedText)(this, pbstrdisconnectedtext), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestColorDepthChange(this: *mut core::ffi::c_void, bpp: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIViewer", "RequestColorDepthChange", 18usize, |this| ((**(this as *const *const IRDPSRAPIViewer_Vtbl)).RequestColorDepthChange)(this, bpp), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, ppval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIViewer", "Properties", 19usize, |this| ((**(this as *const *const IRDPSRAPIViewer_Vtbl)).Properties)(this, ppval), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartReverseConnectListener(this: *mut core::ffi::c_void, bstrconnectionstring: core::mem::MaybeUninit<windows_core::BSTR>, bstrusername: core::mem::MaybeUninit<windows_core::BSTR>, bstrpassword: core::mem::MaybeUninit<windows_core::BSTR>, pbstrreverseconnectstring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIViewer", "StartReverseConnectListener", 20usize, |this| ((**(this as *const *const IRDPSRAPIViewer_Vtbl)).StartReverseConnectListener)(this, bstrconnectionstring, bstrusername, bstrpassword, pbstrreverseconnectstring), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Connect,
+            Disconnect,
+            #[cfg(feature = "Win32_System_Com")]
+            Attendees,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Attendees: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Invitations,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Invitations: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ApplicationFilter,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ApplicationFilter: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            VirtualChannelManager,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            VirtualChannelManager: 0,
+            SetSmartSizing,
+            SmartSizing,
+            RequestControl,
+            SetDisconnectedText,
+            DisconnectedText,
+            RequestColorDepthChange,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+            StartReverseConnectListener,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRDPSRAPIViewer as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRDPSRAPIVirtualChannel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SendData(this: *mut core::ffi::c_void, bstrdata: core::mem::MaybeUninit<windows_core::BSTR>, lattendeeid: i32, channelsendflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIVirtualChannel", "SendData", 7usize, |this| ((**(this as *const *const IRDPSRAPIVirtualChannel_Vtbl)).SendData)(this, bstrdata, lattendeeid, channelsendflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAccess(this: *mut core::ffi::c_void, lattendeeid: i32, accesstype: CHANNEL_ACCESS_ENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIVirtualChannel", "SetAccess", 8usize, |this| ((**(this as *const *const IRDPSRAPIVirtualChannel_Vtbl)).SetAccess)(this, lattendeeid, accesstype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIVirtualChannel", "Name", 9usize, |this| ((**(this as *const *const IRDPSRAPIVirtualChannel_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flags(this: *mut core::ffi::c_void, plflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIVirtualChannel", "Flags", 10usize, |this| ((**(this as *const *const IRDPSRAPIVirtualChannel_Vtbl)).Flags)(this, plflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Priority(this: *mut core::ffi::c_void, ppriority: *mut CHANNEL_PRIORITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIVirtualChannel", "Priority", 11usize, |this| ((**(this as *const *const IRDPSRAPIVirtualChannel_Vtbl)).Priority)(this, ppriority), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SendData, SetAccess, Name, Flags, Priority }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRDPSRAPIVirtualChannel as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRDPSRAPIVirtualChannelManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIVirtualChannelManager", "_NewEnum", 7usize, |this| ((**(this as *const *const IRDPSRAPIVirtualChannelManager_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, item: core::mem::MaybeUninit<windows_core::VARIANT>, pchannel: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIVirtualChannelManager", "get_Item", 8usize, |this| ((**(this as *const *const IRDPSRAPIVirtualChannelManager_Vtbl)).get_Item)(this, item, pchannel), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateVirtualChannel(this: *mut core::ffi::c_void, bstrchannelname: core::mem::MaybeUninit<windows_core::BSTR>, priority: CHANNEL_PRIORITY, channelflags: u32, ppchannel: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIVirtualChannelManager", "CreateVirtualChannel", 9usize, |this| ((**(this as *const *const IRDPSRAPIVirtualChannelManager_Vtbl)).CreateVirtualChannel)(this, bstrchannelname, priority, channelflags, ppchannel), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateVirtualChannel,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateVirtualChannel: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRDPSRAPIVirtualChannelManager as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRDPSRAPIWindow_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, pretval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIWindow", "Id", 7usize, |this| ((**(this as *const *const IRDPSRAPIWindow_Vtbl)).Id)(this, pretval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Application(this: *mut core::ffi::c_void, papplication: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIWindow", "Application", 8usize, |this| ((**(this as *const *const IRDPSRAPIWindow_Vtbl)).Application)(this, papplication), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shared(this: *mut core::ffi::c_void, pretval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIWindow", "Shared", 9usize, |this| ((**(this as *const *const IRDPSRAPIWindow_Vtbl)).Shared)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShared(this: *mut core::ffi::c_void, newval: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIWindow", "SetShared", 10usize, |this| ((**(this as *const *const IRDPSRAPIWindow_Vtbl)).SetShared)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pretval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIWindow", "Name", 11usize, |this| ((**(this as *const *const IRDPSRAPIWindow_Vtbl)).Name)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Show(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIWindow", "Show", 12usize, |this| ((**(this as *const *const IRDPSRAPIWindow_Vtbl)).Show)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flags(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIWindow", "Flags", 13usize, |this| ((**(this as *const *const IRDPSRAPIWindow_Vtbl)).Flags)(this, pdwflags), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            #[cfg(feature = "Win32_System_Com")]
+            Application,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Application: 0,
+            Shared,
+            SetShared,
+            Name,
+            Show,
+            Flags,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRDPSRAPIWindow as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRDPSRAPIWindowList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIWindowList", "_NewEnum", 7usize, |this| ((**(this as *const *const IRDPSRAPIWindowList_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, item: i32, pwindow: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPSRAPIWindowList", "get_Item", 8usize, |this| ((**(this as *const *const IRDPSRAPIWindowList_Vtbl)).get_Item)(this, item, pwindow), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRDPSRAPIWindowList as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRDPViewerInputSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SendMouseButtonEvent(this: *mut core::ffi::c_void, buttontype: RDPSRAPI_MOUSE_BUTTON_TYPE, vbbuttondown: super::super::Foundation::VARIANT_BOOL, xpos: u32, ypos: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPViewerInputSink", "SendMouseButtonEvent", 3usize, |this| ((**(this as *const *const IRDPViewerInputSink_Vtbl)).SendMouseButtonEvent)(this, buttontype, vbbuttondown, xpos, ypos), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendMouseMoveEvent(this: *mut core::ffi::c_void, xpos: u32, ypos: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPViewerInputSink", "SendMouseMoveEvent", 4usize, |this| ((**(this as *const *const IRDPViewerInputSink_Vtbl)).SendMouseMoveEvent)(this, xpos, ypos), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendMouseWheelEvent(this: *mut core::ffi::c_void, wheelrotation: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPViewerInputSink", "SendMouseWheelEvent", 5usize, |this| ((**(this as *const *const IRDPViewerInputSink_Vtbl)).SendMouseWheelEvent)(this, wheelrotation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendKeyboardEvent(this: *mut core::ffi::c_void, codetype: RDPSRAPI_KBD_CODE_TYPE, keycode: u16, vbkeyup: super::super::Foundation::VARIANT_BOOL, vbrepeat: super::super::Foundation::VARIANT_BOOL, vbextended: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPViewerInputSink", "SendKeyboardEvent", 6usize, |this| ((**(this as *const *const IRDPViewerInputSink_Vtbl)).SendKeyboardEvent)(this, codetype, keycode, vbkeyup, vbrepeat, vbextended), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendSyncEvent(this: *mut core::ffi::c_void, syncflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPViewerInputSink", "SendSyncEvent", 7usize, |this| ((**(this as *const *const IRDPViewerInputSink_Vtbl)).SendSyncEvent)(this, syncflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginTouchFrame(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPViewerInputSink", "BeginTouchFrame", 8usize, |this| ((**(this as *const *const IRDPViewerInputSink_Vtbl)).BeginTouchFrame)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTouchInput(this: *mut core::ffi::c_void, contactid: u32, event: u32, x: i32, y: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPViewerInputSink", "AddTouchInput", 9usize, |this| ((**(this as *const *const IRDPViewerInputSink_Vtbl)).AddTouchInput)(this, contactid, event, x, y), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndTouchFrame(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRDPViewerInputSink", "EndTouchFrame", 10usize, |this| ((**(this as *const *const IRDPViewerInputSink_Vtbl)).EndTouchFrame)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SendMouseButtonEvent,
+            SendMouseMoveEvent,
+            SendMouseWheelEvent,
+            SendKeyboardEvent,
+            SendSyncEvent,
+            BeginTouchFrame,
+            AddTouchInput,
+            EndTouchFrame,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRDPViewerInputSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for _IRDPSessionEvents_Vtbl {
+    const PROXY: Self = { Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<_IRDPSessionEvents as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
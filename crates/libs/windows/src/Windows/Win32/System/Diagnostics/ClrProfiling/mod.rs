@@ -1680,6 +1680,7 @@ pub struct COR_PRF_CLAUSE_TYPE(pub i32);
 impl windows_core::TypeKind for COR_PRF_CLAUSE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COR_PRF_CLAUSE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COR_PRF_CLAUSE_TYPE").field(&self.0).finish()
@@ -1691,6 +1692,7 @@ pub struct COR_PRF_CODEGEN_FLAGS(pub i32);
 impl windows_core::TypeKind for COR_PRF_CODEGEN_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COR_PRF_CODEGEN_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COR_PRF_CODEGEN_FLAGS").field(&self.0).finish()
@@ -1702,6 +1704,7 @@ pub struct COR_PRF_EVENTPIPE_LEVEL(pub i32);
 impl windows_core::TypeKind for COR_PRF_EVENTPIPE_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COR_PRF_EVENTPIPE_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COR_PRF_EVENTPIPE_LEVEL").field(&self.0).finish()
@@ -1713,6 +1716,7 @@ pub struct COR_PRF_EVENTPIPE_PARAM_TYPE(pub i32);
 impl windows_core::TypeKind for COR_PRF_EVENTPIPE_PARAM_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COR_PRF_EVENTPIPE_PARAM_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COR_PRF_EVENTPIPE_PARAM_TYPE").field(&self.0).finish()
@@ -1724,6 +1728,7 @@ pub struct COR_PRF_FINALIZER_FLAGS(pub i32);
 impl windows_core::TypeKind for COR_PRF_FINALIZER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COR_PRF_FINALIZER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COR_PRF_FINALIZER_FLAGS").field(&self.0).finish()
@@ -1735,6 +1740,7 @@ pub struct COR_PRF_GC_GENERATION(pub i32);
 impl windows_core::TypeKind for COR_PRF_GC_GENERATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COR_PRF_GC_GENERATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COR_PRF_GC_GENERATION").field(&self.0).finish()
@@ -1746,6 +1752,7 @@ pub struct COR_PRF_GC_REASON(pub i32);
 impl windows_core::TypeKind for COR_PRF_GC_REASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COR_PRF_GC_REASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COR_PRF_GC_REASON").field(&self.0).finish()
@@ -1757,6 +1764,7 @@ pub struct COR_PRF_GC_ROOT_FLAGS(pub i32);
 impl windows_core::TypeKind for COR_PRF_GC_ROOT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COR_PRF_GC_ROOT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COR_PRF_GC_ROOT_FLAGS").field(&self.0).finish()
@@ -1768,6 +1776,7 @@ pub struct COR_PRF_GC_ROOT_KIND(pub i32);
 impl windows_core::TypeKind for COR_PRF_GC_ROOT_KIND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COR_PRF_GC_ROOT_KIND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COR_PRF_GC_ROOT_KIND").field(&self.0).finish()
@@ -1779,6 +1788,7 @@ pub struct COR_PRF_HANDLE_TYPE(pub i32);
 impl windows_core::TypeKind for COR_PRF_HANDLE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COR_PRF_HANDLE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COR_PRF_HANDLE_TYPE").field(&self.0).finish()
@@ -1790,6 +1800,7 @@ pub struct COR_PRF_HIGH_MONITOR(pub i32);
 impl windows_core::TypeKind for COR_PRF_HIGH_MONITOR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COR_PRF_HIGH_MONITOR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COR_PRF_HIGH_MONITOR").field(&self.0).finish()
@@ -1801,6 +1812,7 @@ pub struct COR_PRF_JIT_CACHE(pub i32);
 impl windows_core::TypeKind for COR_PRF_JIT_CACHE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COR_PRF_JIT_CACHE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COR_PRF_JIT_CACHE").field(&self.0).finish()
@@ -1812,6 +1824,7 @@ pub struct COR_PRF_MISC(pub i32);
 impl windows_core::TypeKind for COR_PRF_MISC {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COR_PRF_MISC {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COR_PRF_MISC").field(&self.0).finish()
@@ -1823,6 +1836,7 @@ pub struct COR_PRF_MODULE_FLAGS(pub i32);
 impl windows_core::TypeKind for COR_PRF_MODULE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COR_PRF_MODULE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COR_PRF_MODULE_FLAGS").field(&self.0).finish()
@@ -1834,6 +1848,7 @@ pub struct COR_PRF_MONITOR(pub i32);
 impl windows_core::TypeKind for COR_PRF_MONITOR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COR_PRF_MONITOR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COR_PRF_MONITOR").field(&self.0).finish()
@@ -1845,6 +1860,7 @@ pub struct COR_PRF_REJIT_FLAGS(pub i32);
 impl windows_core::TypeKind for COR_PRF_REJIT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COR_PRF_REJIT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COR_PRF_REJIT_FLAGS").field(&self.0).finish()
@@ -1856,6 +1872,7 @@ pub struct COR_PRF_RUNTIME_TYPE(pub i32);
 impl windows_core::TypeKind for COR_PRF_RUNTIME_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COR_PRF_RUNTIME_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COR_PRF_RUNTIME_TYPE").field(&self.0).finish()
@@ -1867,6 +1884,7 @@ pub struct COR_PRF_SNAPSHOT_INFO(pub i32);
 impl windows_core::TypeKind for COR_PRF_SNAPSHOT_INFO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COR_PRF_SNAPSHOT_INFO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COR_PRF_SNAPSHOT_INFO").field(&self.0).finish()
@@ -1878,6 +1896,7 @@ pub struct COR_PRF_STATIC_TYPE(pub i32);
 impl windows_core::TypeKind for COR_PRF_STATIC_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COR_PRF_STATIC_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COR_PRF_STATIC_TYPE").field(&self.0).finish()
@@ -1889,6 +1908,7 @@ pub struct COR_PRF_SUSPEND_REASON(pub i32);
 impl windows_core::TypeKind for COR_PRF_SUSPEND_REASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COR_PRF_SUSPEND_REASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COR_PRF_SUSPEND_REASON").field(&self.0).finish()
@@ -1900,6 +1920,7 @@ pub struct COR_PRF_TRANSITION_REASON(pub i32);
 impl windows_core::TypeKind for COR_PRF_TRANSITION_REASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COR_PRF_TRANSITION_REASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COR_PRF_TRANSITION_REASON").field(&self.0).finish()
@@ -1911,13 +1932,15 @@ pub struct CorDebugIlToNativeMappingTypes(pub i32);
 impl windows_core::TypeKind for CorDebugIlToNativeMappingTypes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorDebugIlToNativeMappingTypes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorDebugIlToNativeMappingTypes").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COR_DEBUG_IL_TO_NATIVE_MAP {
     pub ilOffset: u32,
     pub nativeStartOffset: u32,
@@ -1932,7 +1955,8 @@ impl Default for COR_DEBUG_IL_TO_NATIVE_MAP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COR_IL_MAP {
     pub oldOffset: u32,
     pub newOffset: u32,
@@ -1948,7 +1972,8 @@ impl Default for COR_IL_MAP {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_WinRT_Metadata")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COR_PRF_ASSEMBLY_REFERENCE_INFO {
     pub pbPublicKeyOrToken: *mut core::ffi::c_void,
     pub cbPublicKeyOrToken: u32,
@@ -1969,7 +1994,8 @@ impl Default for COR_PRF_ASSEMBLY_REFERENCE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COR_PRF_CODE_INFO {
     pub startAddress: usize,
     pub size: usize,
@@ -1983,7 +2009,8 @@ impl Default for COR_PRF_CODE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COR_PRF_EVENTPIPE_PARAM_DESC {
     pub r#type: u32,
     pub elementType: u32,
@@ -1998,7 +2025,8 @@ impl Default for COR_PRF_EVENTPIPE_PARAM_DESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COR_PRF_EVENTPIPE_PROVIDER_CONFIG {
     pub providerName: windows_core::PCWSTR,
     pub keywords: u64,
@@ -2014,7 +2042,8 @@ impl Default for COR_PRF_EVENTPIPE_PROVIDER_CONFIG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COR_PRF_EVENT_DATA {
     pub ptr: u64,
     pub size: u32,
@@ -2029,7 +2058,8 @@ impl Default for COR_PRF_EVENT_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COR_PRF_EX_CLAUSE_INFO {
     pub clauseType: COR_PRF_CLAUSE_TYPE,
     pub programCounter: usize,
@@ -2045,7 +2075,8 @@ impl Default for COR_PRF_EX_CLAUSE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COR_PRF_FILTER_DATA {
     pub Ptr: u64,
     pub Size: u32,
@@ -2060,7 +2091,8 @@ impl Default for COR_PRF_FILTER_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COR_PRF_FUNCTION {
     pub functionId: usize,
     pub reJitId: usize,
@@ -2074,7 +2106,8 @@ impl Default for COR_PRF_FUNCTION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COR_PRF_FUNCTION_ARGUMENT_INFO {
     pub numRanges: u32,
     pub totalArgumentSize: u32,
@@ -2089,7 +2122,8 @@ impl Default for COR_PRF_FUNCTION_ARGUMENT_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COR_PRF_FUNCTION_ARGUMENT_RANGE {
     pub startAddress: usize,
     pub length: u32,
@@ -2103,7 +2137,8 @@ impl Default for COR_PRF_FUNCTION_ARGUMENT_RANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COR_PRF_GC_GENERATION_RANGE {
     pub generation: COR_PRF_GC_GENERATION,
     pub rangeStart: usize,
@@ -2119,7 +2154,8 @@ impl Default for COR_PRF_GC_GENERATION_RANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COR_PRF_METHOD {
     pub moduleId: usize,
     pub methodId: u32,
@@ -2133,7 +2169,8 @@ impl Default for COR_PRF_METHOD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COR_PRF_NONGC_HEAP_RANGE {
     pub rangeStart: usize,
     pub rangeLength: usize,
@@ -2180,3 +2217,5 @@ pub type ObjectReferenceCallback = Option<unsafe extern "system" fn(root: usize,
 pub type StackSnapshotCallback = Option<unsafe extern "system" fn(funcid: usize, ip: usize, frameinfo: usize, contextsize: u32, context: *mut u8, clientdata: *mut core::ffi::c_void) -> windows_core::HRESULT>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
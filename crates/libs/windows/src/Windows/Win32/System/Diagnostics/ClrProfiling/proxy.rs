@@ -0,0 +1,1236 @@
+#[cfg(feature = "Win32_System_WinRT_Metadata")]
+impl windows_core::imp::ProxyVtable for ICorProfilerAssemblyReferenceProvider_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_WinRT_Metadata")]
+        unsafe extern "system" fn AddAssemblyReference(this: *mut core::ffi::c_void, passemblyrefinfo: *const COR_PRF_ASSEMBLY_REFERENCE_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerAssemblyReferenceProvider", "AddAssemblyReference", 3usize, |this| ((**(this as *const *const ICorProfilerAssemblyReferenceProvider_Vtbl)).AddAssemblyReference)(this, passemblyrefinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_WinRT_Metadata")]
+            AddAssemblyReference,
+            #[cfg(not(feature = "Win32_System_WinRT_Metadata"))]
+            AddAssemblyReference: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerAssemblyReferenceProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICorProfilerCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, picorprofilerinfounk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "Initialize", 3usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).Initialize)(this, picorprofilerinfounk), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "Shutdown", 4usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).Shutdown)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppDomainCreationStarted(this: *mut core::ffi::c_void, appdomainid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "AppDomainCreationStarted", 5usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).AppDomainCreationStarted)(this, appdomainid), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppDomainCreationFinished(this: *mut core::ffi::c_void, appdomainid: usize, hrstatus: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "AppDomainCreationFinished", 6usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).AppDomainCreationFinished)(this, appdomainid, hrstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppDomainShutdownStarted(this: *mut core::ffi::c_void, appdomainid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "AppDomainShutdownStarted", 7usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).AppDomainShutdownStarted)(this, appdomainid), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppDomainShutdownFinished(this: *mut core::ffi::c_void, appdomainid: usize, hrstatus: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "AppDomainShutdownFinished", 8usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).AppDomainShutdownFinished)(this, appdomainid, hrstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn AssemblyLoadStarted(this: *mut core::ffi::c_void, assemblyid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "AssemblyLoadStarted", 9usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).AssemblyLoadStarted)(this, assemblyid), |result| Some(*result))
+        }
+        unsafe extern "system" fn AssemblyLoadFinished(this: *mut core::ffi::c_void, assemblyid: usize, hrstatus: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "AssemblyLoadFinished", 10usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).AssemblyLoadFinished)(this, assemblyid, hrstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn AssemblyUnloadStarted(this: *mut core::ffi::c_void, assemblyid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "AssemblyUnloadStarted", 11usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).AssemblyUnloadStarted)(this, assemblyid), |result| Some(*result))
+        }
+        unsafe extern "system" fn AssemblyUnloadFinished(this: *mut core::ffi::c_void, assemblyid: usize, hrstatus: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "AssemblyUnloadFinished", 12usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).AssemblyUnloadFinished)(this, assemblyid, hrstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModuleLoadStarted(this: *mut core::ffi::c_void, moduleid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ModuleLoadStarted", 13usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ModuleLoadStarted)(this, moduleid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModuleLoadFinished(this: *mut core::ffi::c_void, moduleid: usize, hrstatus: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ModuleLoadFinished", 14usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ModuleLoadFinished)(this, moduleid, hrstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModuleUnloadStarted(this: *mut core::ffi::c_void, moduleid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ModuleUnloadStarted", 15usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ModuleUnloadStarted)(this, moduleid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModuleUnloadFinished(this: *mut core::ffi::c_void, moduleid: usize, hrstatus: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ModuleUnloadFinished", 16usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ModuleUnloadFinished)(this, moduleid, hrstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModuleAttachedToAssembly(this: *mut core::ffi::c_void, moduleid: usize, assemblyid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ModuleAttachedToAssembly", 17usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ModuleAttachedToAssembly)(this, moduleid, assemblyid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClassLoadStarted(this: *mut core::ffi::c_void, classid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ClassLoadStarted", 18usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ClassLoadStarted)(this, classid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClassLoadFinished(this: *mut core::ffi::c_void, classid: usize, hrstatus: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ClassLoadFinished", 19usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ClassLoadFinished)(this, classid, hrstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClassUnloadStarted(this: *mut core::ffi::c_void, classid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ClassUnloadStarted", 20usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ClassUnloadStarted)(this, classid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClassUnloadFinished(this: *mut core::ffi::c_void, classid: usize, hrstatus: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ClassUnloadFinished", 21usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ClassUnloadFinished)(this, classid, hrstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn FunctionUnloadStarted(this: *mut core::ffi::c_void, functionid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "FunctionUnloadStarted", 22usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).FunctionUnloadStarted)(this, functionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn JITCompilationStarted(this: *mut core::ffi::c_void, functionid: usize, fissafetoblock: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "JITCompilationStarted", 23usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).JITCompilationStarted)(this, functionid, fissafetoblock), |result| Some(*result))
+        }
+        unsafe extern "system" fn JITCompilationFinished(this: *mut core::ffi::c_void, functionid: usize, hrstatus: windows_core::HRESULT, fissafetoblock: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "JITCompilationFinished", 24usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).JITCompilationFinished)(this, functionid, hrstatus, fissafetoblock), |result| Some(*result))
+        }
+        unsafe extern "system" fn JITCachedFunctionSearchStarted(this: *mut core::ffi::c_void, functionid: usize, pbusecachedfunction: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "JITCachedFunctionSearchStarted", 25usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).JITCachedFunctionSearchStarted)(this, functionid, pbusecachedfunction), |result| Some(*result))
+        }
+        unsafe extern "system" fn JITCachedFunctionSearchFinished(this: *mut core::ffi::c_void, functionid: usize, result: COR_PRF_JIT_CACHE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "JITCachedFunctionSearchFinished", 26usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).JITCachedFunctionSearchFinished)(this, functionid, result), |result| Some(*result))
+        }
+        unsafe extern "system" fn JITFunctionPitched(this: *mut core::ffi::c_void, functionid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "JITFunctionPitched", 27usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).JITFunctionPitched)(this, functionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn JITInlining(this: *mut core::ffi::c_void, callerid: usize, calleeid: usize, pfshouldinline: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "JITInlining", 28usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).JITInlining)(this, callerid, calleeid, pfshouldinline), |result| Some(*result))
+        }
+        unsafe extern "system" fn ThreadCreated(this: *mut core::ffi::c_void, threadid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ThreadCreated", 29usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ThreadCreated)(this, threadid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ThreadDestroyed(this: *mut core::ffi::c_void, threadid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ThreadDestroyed", 30usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ThreadDestroyed)(this, threadid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ThreadAssignedToOSThread(this: *mut core::ffi::c_void, managedthreadid: usize, osthreadid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ThreadAssignedToOSThread", 31usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ThreadAssignedToOSThread)(this, managedthreadid, osthreadid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemotingClientInvocationStarted(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "RemotingClientInvocationStarted", 32usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).RemotingClientInvocationStarted)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemotingClientSendingMessage(this: *mut core::ffi::c_void, pcookie: *const windows_core::GUID, fisasync: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "RemotingClientSendingMessage", 33usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).RemotingClientSendingMessage)(this, pcookie, fisasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemotingClientReceivingReply(this: *mut core::ffi::c_void, pcookie: *const windows_core::GUID, fisasync: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "RemotingClientReceivingReply", 34usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).RemotingClientReceivingReply)(this, pcookie, fisasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemotingClientInvocationFinished(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "RemotingClientInvocationFinished", 35usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).RemotingClientInvocationFinished)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemotingServerReceivingMessage(this: *mut core::ffi::c_void, pcookie: *const windows_core::GUID, fisasync: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "RemotingServerReceivingMessage", 36usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).RemotingServerReceivingMessage)(this, pcookie, fisasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemotingServerInvocationStarted(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "RemotingServerInvocationStarted", 37usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).RemotingServerInvocationStarted)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemotingServerInvocationReturned(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "RemotingServerInvocationReturned", 38usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).RemotingServerInvocationReturned)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemotingServerSendingReply(this: *mut core::ffi::c_void, pcookie: *const windows_core::GUID, fisasync: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "RemotingServerSendingReply", 39usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).RemotingServerSendingReply)(this, pcookie, fisasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnmanagedToManagedTransition(this: *mut core::ffi::c_void, functionid: usize, reason: COR_PRF_TRANSITION_REASON) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "UnmanagedToManagedTransition", 40usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).UnmanagedToManagedTransition)(this, functionid, reason), |result| Some(*result))
+        }
+        unsafe extern "system" fn ManagedToUnmanagedTransition(this: *mut core::ffi::c_void, functionid: usize, reason: COR_PRF_TRANSITION_REASON) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ManagedToUnmanagedTransition", 41usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ManagedToUnmanagedTransition)(this, functionid, reason), |result| Some(*result))
+        }
+        unsafe extern "system" fn RuntimeSuspendStarted(this: *mut core::ffi::c_void, suspendreason: COR_PRF_SUSPEND_REASON) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "RuntimeSuspendStarted", 42usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).RuntimeSuspendStarted)(this, suspendreason), |result| Some(*result))
+        }
+        unsafe extern "system" fn RuntimeSuspendFinished(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "RuntimeSuspendFinished", 43usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).RuntimeSuspendFinished)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RuntimeSuspendAborted(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "RuntimeSuspendAborted", 44usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).RuntimeSuspendAborted)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RuntimeResumeStarted(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "RuntimeResumeStarted", 45usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).RuntimeResumeStarted)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RuntimeResumeFinished(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "RuntimeResumeFinished", 46usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).RuntimeResumeFinished)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RuntimeThreadSuspended(this: *mut core::ffi::c_void, threadid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "RuntimeThreadSuspended", 47usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).RuntimeThreadSuspended)(this, threadid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RuntimeThreadResumed(this: *mut core::ffi::c_void, threadid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "RuntimeThreadResumed", 48usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).RuntimeThreadResumed)(this, threadid), |result| Some(*result))
+        }
+        unsafe extern "system" fn MovedReferences(this: *mut core::ffi::c_void, cmovedobjectidranges: u32, oldobjectidrangestart: *const usize, newobjectidrangestart: *const usize, cobjectidrangelength: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "MovedReferences", 49usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).MovedReferences)(this, cmovedobjectidranges, oldobjectidrangestart, newobjectidrangestart, cobjectidrangelength), |result| Some(*result))
+        }
+        unsafe extern "system" fn ObjectAllocated(this: *mut core::ffi::c_void, objectid: usize, classid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ObjectAllocated", 50usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ObjectAllocated)(this, objectid, classid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ObjectsAllocatedByClass(this: *mut core::ffi::c_void, cclasscount: u32, classids: *const usize, cobjects: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ObjectsAllocatedByClass", 51usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ObjectsAllocatedByClass)(this, cclasscount, classids, cobjects), |result| Some(*result))
+        }
+        unsafe extern "system" fn ObjectReferences(this: *mut core::ffi::c_void, objectid: usize, classid: usize, cobjectrefs: u32, objectrefids: *const usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ObjectReferences", 52usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ObjectReferences)(this, objectid, classid, cobjectrefs, objectrefids), |result| Some(*result))
+        }
+        unsafe extern "system" fn RootReferences(this: *mut core::ffi::c_void, crootrefs: u32, rootrefids: *const usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "RootReferences", 53usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).RootReferences)(this, crootrefs, rootrefids), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExceptionThrown(this: *mut core::ffi::c_void, thrownobjectid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ExceptionThrown", 54usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ExceptionThrown)(this, thrownobjectid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExceptionSearchFunctionEnter(this: *mut core::ffi::c_void, functionid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ExceptionSearchFunctionEnter", 55usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ExceptionSearchFunctionEnter)(this, functionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExceptionSearchFunctionLeave(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ExceptionSearchFunctionLeave", 56usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ExceptionSearchFunctionLeave)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExceptionSearchFilterEnter(this: *mut core::ffi::c_void, functionid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ExceptionSearchFilterEnter", 57usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ExceptionSearchFilterEnter)(this, functionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExceptionSearchFilterLeave(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ExceptionSearchFilterLeave", 58usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ExceptionSearchFilterLeave)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExceptionSearchCatcherFound(this: *mut core::ffi::c_void, functionid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ExceptionSearchCatcherFound", 59usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ExceptionSearchCatcherFound)(this, functionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExceptionOSHandlerEnter(this: *mut core::ffi::c_void, __unused: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ExceptionOSHandlerEnter", 60usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ExceptionOSHandlerEnter)(this, __unused), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExceptionOSHandlerLeave(this: *mut core::ffi::c_void, __unused: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ExceptionOSHandlerLeave", 61usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ExceptionOSHandlerLeave)(this, __unused), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExceptionUnwindFunctionEnter(this: *mut core::ffi::c_void, functionid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ExceptionUnwindFunctionEnter", 62usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ExceptionUnwindFunctionEnter)(this, functionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExceptionUnwindFunctionLeave(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ExceptionUnwindFunctionLeave", 63usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ExceptionUnwindFunctionLeave)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExceptionUnwindFinallyEnter(this: *mut core::ffi::c_void, functionid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ExceptionUnwindFinallyEnter", 64usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ExceptionUnwindFinallyEnter)(this, functionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExceptionUnwindFinallyLeave(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ExceptionUnwindFinallyLeave", 65usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ExceptionUnwindFinallyLeave)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExceptionCatcherEnter(this: *mut core::ffi::c_void, functionid: usize, objectid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ExceptionCatcherEnter", 66usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ExceptionCatcherEnter)(this, functionid, objectid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExceptionCatcherLeave(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ExceptionCatcherLeave", 67usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ExceptionCatcherLeave)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn COMClassicVTableCreated(this: *mut core::ffi::c_void, wrappedclassid: usize, implementediid: *const windows_core::GUID, pvtable: *const core::ffi::c_void, cslots: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "COMClassicVTableCreated", 68usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).COMClassicVTableCreated)(this, wrappedclassid, implementediid, pvtable, cslots), |result| Some(*result))
+        }
+        unsafe extern "system" fn COMClassicVTableDestroyed(this: *mut core::ffi::c_void, wrappedclassid: usize, implementediid: *const windows_core::GUID, pvtable: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "COMClassicVTableDestroyed", 69usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).COMClassicVTableDestroyed)(this, wrappedclassid, implementediid, pvtable), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExceptionCLRCatcherFound(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ExceptionCLRCatcherFound", 70usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ExceptionCLRCatcherFound)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExceptionCLRCatcherExecute(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback", "ExceptionCLRCatcherExecute", 71usize, |this| ((**(this as *const *const ICorProfilerCallback_Vtbl)).ExceptionCLRCatcherExecute)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            Shutdown,
+            AppDomainCreationStarted,
+            AppDomainCreationFinished,
+            AppDomainShutdownStarted,
+            AppDomainShutdownFinished,
+            AssemblyLoadStarted,
+            AssemblyLoadFinished,
+            AssemblyUnloadStarted,
+            AssemblyUnloadFinished,
+            ModuleLoadStarted,
+            ModuleLoadFinished,
+            ModuleUnloadStarted,
+            ModuleUnloadFinished,
+            ModuleAttachedToAssembly,
+            ClassLoadStarted,
+            ClassLoadFinished,
+            ClassUnloadStarted,
+            ClassUnloadFinished,
+            FunctionUnloadStarted,
+            JITCompilationStarted,
+            JITCompilationFinished,
+            JITCachedFunctionSearchStarted,
+            JITCachedFunctionSearchFinished,
+            JITFunctionPitched,
+            JITInlining,
+            ThreadCreated,
+            ThreadDestroyed,
+            ThreadAssignedToOSThread,
+            RemotingClientInvocationStarted,
+            RemotingClientSendingMessage,
+            RemotingClientReceivingReply,
+            RemotingClientInvocationFinished,
+            RemotingServerReceivingMessage,
+            RemotingServerInvocationStarted,
+            RemotingServerInvocationReturned,
+            RemotingServerSendingReply,
+            UnmanagedToManagedTransition,
+            ManagedToUnmanagedTransition,
+            RuntimeSuspendStarted,
+            RuntimeSuspendFinished,
+            RuntimeSuspendAborted,
+            RuntimeResumeStarted,
+            RuntimeResumeFinished,
+            RuntimeThreadSuspended,
+            RuntimeThreadResumed,
+            MovedReferences,
+            ObjectAllocated,
+            ObjectsAllocatedByClass,
+            ObjectReferences,
+            RootReferences,
+            ExceptionThrown,
+            ExceptionSearchFunctionEnter,
+            ExceptionSearchFunctionLeave,
+            ExceptionSearchFilterEnter,
+            ExceptionSearchFilterLeave,
+            ExceptionSearchCatcherFound,
+            ExceptionOSHandlerEnter,
+            ExceptionOSHandlerLeave,
+            ExceptionUnwindFunctionEnter,
+            ExceptionUnwindFunctionLeave,
+            ExceptionUnwindFinallyEnter,
+            ExceptionUnwindFinallyLeave,
+            ExceptionCatcherEnter,
+            ExceptionCatcherLeave,
+            COMClassicVTableCreated,
+            COMClassicVTableDestroyed,
+            ExceptionCLRCatcherFound,
+            ExceptionCLRCatcherExecute,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICorProfilerCallback10_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EventPipeEventDelivered(this: *mut core::ffi::c_void, provider: usize, eventid: u32, eventversion: u32, cbmetadatablob: u32, metadatablob: *const u8, cbeventdata: u32, eventdata: *const u8, pactivityid: *const windows_core::GUID, prelatedactivityid: *const windows_core::GUID, eventthread: usize, numstackframes: u32, stackframes: *const usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback10", "EventPipeEventDelivered", 95usize, |this| ((**(this as *const *const ICorProfilerCallback10_Vtbl)).EventPipeEventDelivered)(this, provider, eventid, eventversion, cbmetadatablob, metadatablob, cbeventdata, eventdata, pactivityid, prelatedactivityid, eventthread, numstackframes, stackframes), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventPipeProviderCreated(this: *mut core::ffi::c_void, provider: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback10", "EventPipeProviderCreated", 96usize, |this| ((**(this as *const *const ICorProfilerCallback10_Vtbl)).EventPipeProviderCreated)(this, provider), |result| Some(*result))
+        }
+        Self { base__: <ICorProfilerCallback9_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EventPipeEventDelivered, EventPipeProviderCreated }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerCallback10 as windows_core::Interface>::IID || <ICorProfilerCallback9_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICorProfilerCallback11_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LoadAsNotificationOnly(this: *mut core::ffi::c_void, pbnotificationonly: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback11", "LoadAsNotificationOnly", 97usize, |this| ((**(this as *const *const ICorProfilerCallback11_Vtbl)).LoadAsNotificationOnly)(this, pbnotificationonly), |result| Some(*result))
+        }
+        Self { base__: <ICorProfilerCallback10_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LoadAsNotificationOnly }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerCallback11 as windows_core::Interface>::IID || <ICorProfilerCallback10_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICorProfilerCallback2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ThreadNameChanged(this: *mut core::ffi::c_void, threadid: usize, cchname: u32, name: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback2", "ThreadNameChanged", 72usize, |this| ((**(this as *const *const ICorProfilerCallback2_Vtbl)).ThreadNameChanged)(this, threadid, cchname, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn GarbageCollectionStarted(this: *mut core::ffi::c_void, cgenerations: i32, generationcollected: *const super::super::super::Foundation::BOOL, reason: COR_PRF_GC_REASON) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback2", "GarbageCollectionStarted", 73usize, |this| ((**(this as *const *const ICorProfilerCallback2_Vtbl)).GarbageCollectionStarted)(this, cgenerations, generationcollected, reason), |result| Some(*result))
+        }
+        unsafe extern "system" fn SurvivingReferences(this: *mut core::ffi::c_void, csurvivingobjectidranges: u32, objectidrangestart: *const usize, cobjectidrangelength: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback2", "SurvivingReferences", 74usize, |this| ((**(this as *const *const ICorProfilerCallback2_Vtbl)).SurvivingReferences)(this, csurvivingobjectidranges, objectidrangestart, cobjectidrangelength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GarbageCollectionFinished(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback2", "GarbageCollectionFinished", 75usize, |this| ((**(this as *const *const ICorProfilerCallback2_Vtbl)).GarbageCollectionFinished)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn FinalizeableObjectQueued(this: *mut core::ffi::c_void, finalizerflags: u32, objectid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback2", "FinalizeableObjectQueued", 76usize, |this| ((**(this as *const *const ICorProfilerCallback2_Vtbl)).FinalizeableObjectQueued)(this, finalizerflags, objectid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RootReferences2(this: *mut core::ffi::c_void, crootrefs: u32, rootrefids: *const usize, rootkinds: *const COR_PRF_GC_ROOT_KIND, rootflags: *const COR_PRF_GC_ROOT_FLAGS, rootids: *const usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback2", "RootReferences2", 77usize, |this| ((**(this as *const *const ICorProfilerCallback2_Vtbl)).RootReferences2)(this, crootrefs, rootrefids, rootkinds, rootflags, rootids), |result| Some(*result))
+        }
+        unsafe extern "system" fn HandleCreated(this: *mut core::ffi::c_void, handleid: usize, initialobjectid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback2", "HandleCreated", 78usize, |this| ((**(this as *const *const ICorProfilerCallback2_Vtbl)).HandleCreated)(this, handleid, initialobjectid), |result| Some(*result))
+        }
+        unsafe extern "system" fn HandleDestroyed(this: *mut core::ffi::c_void, handleid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback2", "HandleDestroyed", 79usize, |this| ((**(this as *const *const ICorProfilerCallback2_Vtbl)).HandleDestroyed)(this, handleid), |result| Some(*result))
+        }
+        Self {
+            base__: <ICorProfilerCallback_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ThreadNameChanged,
+            GarbageCollectionStarted,
+            SurvivingReferences,
+            GarbageCollectionFinished,
+            FinalizeableObjectQueued,
+            RootReferences2,
+            HandleCreated,
+            HandleDestroyed,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerCallback2 as windows_core::Interface>::IID || <ICorProfilerCallback_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICorProfilerCallback3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeForAttach(this: *mut core::ffi::c_void, pcorprofilerinfounk: *mut core::ffi::c_void, pvclientdata: *const core::ffi::c_void, cbclientdata: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback3", "InitializeForAttach", 80usize, |this| ((**(this as *const *const ICorProfilerCallback3_Vtbl)).InitializeForAttach)(this, pcorprofilerinfounk, pvclientdata, cbclientdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProfilerAttachComplete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback3", "ProfilerAttachComplete", 81usize, |this| ((**(this as *const *const ICorProfilerCallback3_Vtbl)).ProfilerAttachComplete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProfilerDetachSucceeded(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback3", "ProfilerDetachSucceeded", 82usize, |this| ((**(this as *const *const ICorProfilerCallback3_Vtbl)).ProfilerDetachSucceeded)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <ICorProfilerCallback2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitializeForAttach,
+            ProfilerAttachComplete,
+            ProfilerDetachSucceeded,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerCallback3 as windows_core::Interface>::IID || <ICorProfilerCallback2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICorProfilerCallback4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReJITCompilationStarted(this: *mut core::ffi::c_void, functionid: usize, rejitid: usize, fissafetoblock: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback4", "ReJITCompilationStarted", 83usize, |this| ((**(this as *const *const ICorProfilerCallback4_Vtbl)).ReJITCompilationStarted)(this, functionid, rejitid, fissafetoblock), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReJITParameters(this: *mut core::ffi::c_void, moduleid: usize, methodid: u32, pfunctioncontrol: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback4", "GetReJITParameters", 84usize, |this| ((**(this as *const *const ICorProfilerCallback4_Vtbl)).GetReJITParameters)(this, moduleid, methodid, pfunctioncontrol), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReJITCompilationFinished(this: *mut core::ffi::c_void, functionid: usize, rejitid: usize, hrstatus: windows_core::HRESULT, fissafetoblock: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback4", "ReJITCompilationFinished", 85usize, |this| ((**(this as *const *const ICorProfilerCallback4_Vtbl)).ReJITCompilationFinished)(this, functionid, rejitid, hrstatus, fissafetoblock), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReJITError(this: *mut core::ffi::c_void, moduleid: usize, methodid: u32, functionid: usize, hrstatus: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback4", "ReJITError", 86usize, |this| ((**(this as *const *const ICorProfilerCallback4_Vtbl)).ReJITError)(this, moduleid, methodid, functionid, hrstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn MovedReferences2(this: *mut core::ffi::c_void, cmovedobjectidranges: u32, oldobjectidrangestart: *const usize, newobjectidrangestart: *const usize, cobjectidrangelength: *const usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback4", "MovedReferences2", 87usize, |this| ((**(this as *const *const ICorProfilerCallback4_Vtbl)).MovedReferences2)(this, cmovedobjectidranges, oldobjectidrangestart, newobjectidrangestart, cobjectidrangelength), |result| Some(*result))
+        }
+        unsafe extern "system" fn SurvivingReferences2(this: *mut core::ffi::c_void, csurvivingobjectidranges: u32, objectidrangestart: *const usize, cobjectidrangelength: *const usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback4", "SurvivingReferences2", 88usize, |this| ((**(this as *const *const ICorProfilerCallback4_Vtbl)).SurvivingReferences2)(this, csurvivingobjectidranges, objectidrangestart, cobjectidrangelength), |result| Some(*result))
+        }
+        Self {
+            base__: <ICorProfilerCallback3_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReJITCompilationStarted,
+            GetReJITParameters,
+            ReJITCompilationFinished,
+            ReJITError,
+            MovedReferences2,
+            SurvivingReferences2,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerCallback4 as windows_core::Interface>::IID || <ICorProfilerCallback3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICorProfilerCallback5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConditionalWeakTableElementReferences(this: *mut core::ffi::c_void, crootrefs: u32, keyrefids: *const usize, valuerefids: *const usize, rootids: *const usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback5", "ConditionalWeakTableElementReferences", 89usize, |this| ((**(this as *const *const ICorProfilerCallback5_Vtbl)).ConditionalWeakTableElementReferences)(this, crootrefs, keyrefids, valuerefids, rootids), |result| Some(*result))
+        }
+        Self { base__: <ICorProfilerCallback4_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ConditionalWeakTableElementReferences }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerCallback5 as windows_core::Interface>::IID || <ICorProfilerCallback4_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICorProfilerCallback6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAssemblyReferences(this: *mut core::ffi::c_void, wszassemblypath: windows_core::PCWSTR, pasmrefprovider: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback6", "GetAssemblyReferences", 90usize, |this| ((**(this as *const *const ICorProfilerCallback6_Vtbl)).GetAssemblyReferences)(this, wszassemblypath, pasmrefprovider), |result| Some(*result))
+        }
+        Self { base__: <ICorProfilerCallback5_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAssemblyReferences }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerCallback6 as windows_core::Interface>::IID || <ICorProfilerCallback5_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICorProfilerCallback7_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ModuleInMemorySymbolsUpdated(this: *mut core::ffi::c_void, moduleid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback7", "ModuleInMemorySymbolsUpdated", 91usize, |this| ((**(this as *const *const ICorProfilerCallback7_Vtbl)).ModuleInMemorySymbolsUpdated)(this, moduleid), |result| Some(*result))
+        }
+        Self { base__: <ICorProfilerCallback6_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ModuleInMemorySymbolsUpdated }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerCallback7 as windows_core::Interface>::IID || <ICorProfilerCallback6_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICorProfilerCallback8_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DynamicMethodJITCompilationStarted(this: *mut core::ffi::c_void, functionid: usize, fissafetoblock: super::super::super::Foundation::BOOL, pilheader: *const u8, cbilheader: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback8", "DynamicMethodJITCompilationStarted", 92usize, |this| ((**(this as *const *const ICorProfilerCallback8_Vtbl)).DynamicMethodJITCompilationStarted)(this, functionid, fissafetoblock, pilheader, cbilheader), |result| Some(*result))
+        }
+        unsafe extern "system" fn DynamicMethodJITCompilationFinished(this: *mut core::ffi::c_void, functionid: usize, hrstatus: windows_core::HRESULT, fissafetoblock: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback8", "DynamicMethodJITCompilationFinished", 93usize, |this| ((**(this as *const *const ICorProfilerCallback8_Vtbl)).DynamicMethodJITCompilationFinished)(this, functionid, hrstatus, fissafetoblock), |result| Some(*result))
+        }
+        Self {
+            base__: <ICorProfilerCallback7_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DynamicMethodJITCompilationStarted,
+            DynamicMethodJITCompilationFinished,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerCallback8 as windows_core::Interface>::IID || <ICorProfilerCallback7_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICorProfilerCallback9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DynamicMethodUnloaded(this: *mut core::ffi::c_void, functionid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerCallback9", "DynamicMethodUnloaded", 94usize, |this| ((**(this as *const *const ICorProfilerCallback9_Vtbl)).DynamicMethodUnloaded)(this, functionid), |result| Some(*result))
+        }
+        Self { base__: <ICorProfilerCallback8_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DynamicMethodUnloaded }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerCallback9 as windows_core::Interface>::IID || <ICorProfilerCallback8_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICorProfilerFunctionControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetCodegenFlags(this: *mut core::ffi::c_void, flags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerFunctionControl", "SetCodegenFlags", 3usize, |this| ((**(this as *const *const ICorProfilerFunctionControl_Vtbl)).SetCodegenFlags)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetILFunctionBody(this: *mut core::ffi::c_void, cbnewilmethodheader: u32, pbnewilmethodheader: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerFunctionControl", "SetILFunctionBody", 4usize, |this| ((**(this as *const *const ICorProfilerFunctionControl_Vtbl)).SetILFunctionBody)(this, cbnewilmethodheader, pbnewilmethodheader), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetILInstrumentedCodeMap(this: *mut core::ffi::c_void, cilmapentries: u32, rgilmapentries: *const COR_IL_MAP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerFunctionControl", "SetILInstrumentedCodeMap", 5usize, |this| ((**(this as *const *const ICorProfilerFunctionControl_Vtbl)).SetILInstrumentedCodeMap)(this, cilmapentries, rgilmapentries), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetCodegenFlags, SetILFunctionBody, SetILInstrumentedCodeMap }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerFunctionControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICorProfilerFunctionEnum_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerFunctionEnum", "Skip", 3usize, |this| ((**(this as *const *const ICorProfilerFunctionEnum_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerFunctionEnum", "Reset", 4usize, |this| ((**(this as *const *const ICorProfilerFunctionEnum_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerFunctionEnum", "Clone", 5usize, |this| ((**(this as *const *const ICorProfilerFunctionEnum_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcelt: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerFunctionEnum", "GetCount", 6usize, |this| ((**(this as *const *const ICorProfilerFunctionEnum_Vtbl)).GetCount)(this, pcelt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ids: *mut COR_PRF_FUNCTION, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerFunctionEnum", "Next", 7usize, |this| ((**(this as *const *const ICorProfilerFunctionEnum_Vtbl)).Next)(this, celt, ids, pceltfetched), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Skip, Reset, Clone, GetCount, Next }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerFunctionEnum as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_WinRT_Metadata")]
+impl windows_core::imp::ProxyVtable for ICorProfilerInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetClassFromObject(this: *mut core::ffi::c_void, objectid: usize, pclassid: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "GetClassFromObject", 3usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).GetClassFromObject)(this, objectid, pclassid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClassFromToken(this: *mut core::ffi::c_void, moduleid: usize, typedef: u32, pclassid: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "GetClassFromToken", 4usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).GetClassFromToken)(this, moduleid, typedef, pclassid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCodeInfo(this: *mut core::ffi::c_void, functionid: usize, pstart: *mut *mut u8, pcsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "GetCodeInfo", 5usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).GetCodeInfo)(this, functionid, pstart, pcsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEventMask(this: *mut core::ffi::c_void, pdwevents: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "GetEventMask", 6usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).GetEventMask)(this, pdwevents), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFunctionFromIP(this: *mut core::ffi::c_void, ip: *const u8, pfunctionid: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "GetFunctionFromIP", 7usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).GetFunctionFromIP)(this, ip, pfunctionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFunctionFromToken(this: *mut core::ffi::c_void, moduleid: usize, token: u32, pfunctionid: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "GetFunctionFromToken", 8usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).GetFunctionFromToken)(this, moduleid, token, pfunctionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHandleFromThread(this: *mut core::ffi::c_void, threadid: usize, phthread: *mut super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "GetHandleFromThread", 9usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).GetHandleFromThread)(this, threadid, phthread), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObjectSize(this: *mut core::ffi::c_void, objectid: usize, pcsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "GetObjectSize", 10usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).GetObjectSize)(this, objectid, pcsize), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_WinRT_Metadata")]
+        unsafe extern "system" fn IsArrayClass(this: *mut core::ffi::c_void, classid: usize, pbaseelemtype: *mut super::super::WinRT::Metadata::CorElementType, pbaseclassid: *mut usize, pcrank: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "IsArrayClass", 11usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).IsArrayClass)(this, classid, pbaseelemtype, pbaseclassid, pcrank), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetThreadInfo(this: *mut core::ffi::c_void, threadid: usize, pdwwin32threadid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "GetThreadInfo", 12usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).GetThreadInfo)(this, threadid, pdwwin32threadid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentThreadID(this: *mut core::ffi::c_void, pthreadid: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "GetCurrentThreadID", 13usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).GetCurrentThreadID)(this, pthreadid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClassIDInfo(this: *mut core::ffi::c_void, classid: usize, pmoduleid: *mut usize, ptypedeftoken: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "GetClassIDInfo", 14usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).GetClassIDInfo)(this, classid, pmoduleid, ptypedeftoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFunctionInfo(this: *mut core::ffi::c_void, functionid: usize, pclassid: *mut usize, pmoduleid: *mut usize, ptoken: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "GetFunctionInfo", 15usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).GetFunctionInfo)(this, functionid, pclassid, pmoduleid, ptoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventMask(this: *mut core::ffi::c_void, dwevents: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "SetEventMask", 16usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).SetEventMask)(this, dwevents), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnterLeaveFunctionHooks(this: *mut core::ffi::c_void, pfuncenter: *const FunctionEnter, pfuncleave: *const FunctionLeave, pfunctailcall: *const FunctionTailcall) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "SetEnterLeaveFunctionHooks", 17usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).SetEnterLeaveFunctionHooks)(this, pfuncenter, pfuncleave, pfunctailcall), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFunctionIDMapper(this: *mut core::ffi::c_void, pfunc: *const FunctionIDMapper) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "SetFunctionIDMapper", 18usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).SetFunctionIDMapper)(this, pfunc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTokenAndMetaDataFromFunction(this: *mut core::ffi::c_void, functionid: usize, riid: *const windows_core::GUID, ppimport: *mut *mut core::ffi::c_void, ptoken: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "GetTokenAndMetaDataFromFunction", 19usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).GetTokenAndMetaDataFromFunction)(this, functionid, riid, ppimport, ptoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetModuleInfo(this: *mut core::ffi::c_void, moduleid: usize, ppbaseloadaddress: *mut *mut u8, cchname: u32, pcchname: *mut u32, szname: windows_core::PWSTR, passemblyid: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "GetModuleInfo", 20usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).GetModuleInfo)(this, moduleid, ppbaseloadaddress, cchname, pcchname, szname, passemblyid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetModuleMetaData(this: *mut core::ffi::c_void, moduleid: usize, dwopenflags: u32, riid: *const windows_core::GUID, ppout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "GetModuleMetaData", 21usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).GetModuleMetaData)(this, moduleid, dwopenflags, riid, ppout), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetILFunctionBody(this: *mut core::ffi::c_void, moduleid: usize, methodid: u32, ppmethodheader: *mut *mut u8, pcbmethodsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "GetILFunctionBody", 22usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).GetILFunctionBody)(this, moduleid, methodid, ppmethodheader, pcbmethodsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetILFunctionBodyAllocator(this: *mut core::ffi::c_void, moduleid: usize, ppmalloc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "GetILFunctionBodyAllocator", 23usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).GetILFunctionBodyAllocator)(this, moduleid, ppmalloc), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetILFunctionBody(this: *mut core::ffi::c_void, moduleid: usize, methodid: u32, pbnewilmethodheader: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "SetILFunctionBody", 24usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).SetILFunctionBody)(this, moduleid, methodid, pbnewilmethodheader), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAppDomainInfo(this: *mut core::ffi::c_void, appdomainid: usize, cchname: u32, pcchname: *mut u32, szname: windows_core::PWSTR, pprocessid: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "GetAppDomainInfo", 25usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).GetAppDomainInfo)(this, appdomainid, cchname, pcchname, szname, pprocessid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAssemblyInfo(this: *mut core::ffi::c_void, assemblyid: usize, cchname: u32, pcchname: *mut u32, szname: windows_core::PWSTR, pappdomainid: *mut usize, pmoduleid: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "GetAssemblyInfo", 26usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).GetAssemblyInfo)(this, assemblyid, cchname, pcchname, szname, pappdomainid, pmoduleid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFunctionReJIT(this: *mut core::ffi::c_void, functionid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "SetFunctionReJIT", 27usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).SetFunctionReJIT)(this, functionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForceGC(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "ForceGC", 28usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).ForceGC)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetILInstrumentedCodeMap(this: *mut core::ffi::c_void, functionid: usize, fstartjit: super::super::super::Foundation::BOOL, cilmapentries: u32, rgilmapentries: *const COR_IL_MAP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "SetILInstrumentedCodeMap", 29usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).SetILInstrumentedCodeMap)(this, functionid, fstartjit, cilmapentries, rgilmapentries), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInprocInspectionInterface(this: *mut core::ffi::c_void, ppicd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "GetInprocInspectionInterface", 30usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).GetInprocInspectionInterface)(this, ppicd), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInprocInspectionIThisThread(this: *mut core::ffi::c_void, ppicd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "GetInprocInspectionIThisThread", 31usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).GetInprocInspectionIThisThread)(this, ppicd), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetThreadContext(this: *mut core::ffi::c_void, threadid: usize, pcontextid: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "GetThreadContext", 32usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).GetThreadContext)(this, threadid, pcontextid), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginInprocDebugging(this: *mut core::ffi::c_void, fthisthreadonly: super::super::super::Foundation::BOOL, pdwprofilercontext: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "BeginInprocDebugging", 33usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).BeginInprocDebugging)(this, fthisthreadonly, pdwprofilercontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndInprocDebugging(this: *mut core::ffi::c_void, dwprofilercontext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "EndInprocDebugging", 34usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).EndInprocDebugging)(this, dwprofilercontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetILToNativeMapping(this: *mut core::ffi::c_void, functionid: usize, cmap: u32, pcmap: *mut u32, map: *mut COR_DEBUG_IL_TO_NATIVE_MAP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo", "GetILToNativeMapping", 35usize, |this| ((**(this as *const *const ICorProfilerInfo_Vtbl)).GetILToNativeMapping)(this, functionid, cmap, pcmap, map), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetClassFromObject,
+            GetClassFromToken,
+            GetCodeInfo,
+            GetEventMask,
+            GetFunctionFromIP,
+            GetFunctionFromToken,
+            GetHandleFromThread,
+            GetObjectSize,
+            #[cfg(feature = "Win32_System_WinRT_Metadata")]
+            IsArrayClass,
+            #[cfg(not(feature = "Win32_System_WinRT_Metadata"))]
+            IsArrayClass: 0,
+            GetThreadInfo,
+            GetCurrentThreadID,
+            GetClassIDInfo,
+            GetFunctionInfo,
+            SetEventMask,
+            SetEnterLeaveFunctionHooks,
+            SetFunctionIDMapper,
+            GetTokenAndMetaDataFromFunction,
+            GetModuleInfo,
+            GetModuleMetaData,
+            GetILFunctionBody,
+            GetILFunctionBodyAllocator,
+            SetILFunctionBody,
+            GetAppDomainInfo,
+            GetAssemblyInfo,
+            SetFunctionReJIT,
+            ForceGC,
+            SetILInstrumentedCodeMap,
+            GetInprocInspectionInterface,
+            GetInprocInspectionIThisThread,
+            GetThreadContext,
+            BeginInprocDebugging,
+            EndInprocDebugging,
+            GetILToNativeMapping,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_WinRT_Metadata")]
+impl windows_core::imp::ProxyVtable for ICorProfilerInfo10_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumerateObjectReferences(this: *mut core::ffi::c_void, objectid: usize, callback: ObjectReferenceCallback, clientdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo10", "EnumerateObjectReferences", 93usize, |this| ((**(this as *const *const ICorProfilerInfo10_Vtbl)).EnumerateObjectReferences)(this, objectid, callback, clientdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFrozenObject(this: *mut core::ffi::c_void, objectid: usize, pbfrozen: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo10", "IsFrozenObject", 94usize, |this| ((**(this as *const *const ICorProfilerInfo10_Vtbl)).IsFrozenObject)(this, objectid, pbfrozen), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLOHObjectSizeThreshold(this: *mut core::ffi::c_void, pthreshold: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo10", "GetLOHObjectSizeThreshold", 95usize, |this| ((**(this as *const *const ICorProfilerInfo10_Vtbl)).GetLOHObjectSizeThreshold)(this, pthreshold), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestReJITWithInliners(this: *mut core::ffi::c_void, dwrejitflags: u32, cfunctions: u32, moduleids: *const usize, methodids: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo10", "RequestReJITWithInliners", 96usize, |this| ((**(this as *const *const ICorProfilerInfo10_Vtbl)).RequestReJITWithInliners)(this, dwrejitflags, cfunctions, moduleids, methodids), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuspendRuntime(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo10", "SuspendRuntime", 97usize, |this| ((**(this as *const *const ICorProfilerInfo10_Vtbl)).SuspendRuntime)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResumeRuntime(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo10", "ResumeRuntime", 98usize, |this| ((**(this as *const *const ICorProfilerInfo10_Vtbl)).ResumeRuntime)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <ICorProfilerInfo9_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EnumerateObjectReferences,
+            IsFrozenObject,
+            GetLOHObjectSizeThreshold,
+            RequestReJITWithInliners,
+            SuspendRuntime,
+            ResumeRuntime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerInfo10 as windows_core::Interface>::IID || <ICorProfilerInfo9_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_WinRT_Metadata")]
+impl windows_core::imp::ProxyVtable for ICorProfilerInfo11_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEnvironmentVariableA(this: *mut core::ffi::c_void, szname: windows_core::PCWSTR, cchvalue: u32, pcchvalue: *mut u32, szvalue: windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo11", "GetEnvironmentVariableA", 99usize, |this| ((**(this as *const *const ICorProfilerInfo11_Vtbl)).GetEnvironmentVariableA)(this, szname, cchvalue, pcchvalue, szvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnvironmentVariable(this: *mut core::ffi::c_void, szname: windows_core::PCWSTR, szvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo11", "SetEnvironmentVariable", 100usize, |this| ((**(this as *const *const ICorProfilerInfo11_Vtbl)).SetEnvironmentVariable)(this, szname, szvalue), |result| Some(*result))
+        }
+        Self { base__: <ICorProfilerInfo10_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetEnvironmentVariableA, SetEnvironmentVariable }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerInfo11 as windows_core::Interface>::IID || <ICorProfilerInfo10_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_WinRT_Metadata")]
+impl windows_core::imp::ProxyVtable for ICorProfilerInfo12_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EventPipeStartSession(this: *mut core::ffi::c_void, cproviderconfigs: u32, pproviderconfigs: *const COR_PRF_EVENTPIPE_PROVIDER_CONFIG, requestrundown: super::super::super::Foundation::BOOL, psession: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo12", "EventPipeStartSession", 101usize, |this| ((**(this as *const *const ICorProfilerInfo12_Vtbl)).EventPipeStartSession)(this, cproviderconfigs, pproviderconfigs, requestrundown, psession), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventPipeAddProviderToSession(this: *mut core::ffi::c_void, session: u64, providerconfig: COR_PRF_EVENTPIPE_PROVIDER_CONFIG) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo12", "EventPipeAddProviderToSession", 102usize, |this| ((**(this as *const *const ICorProfilerInfo12_Vtbl)).EventPipeAddProviderToSession)(this, session, providerconfig), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventPipeStopSession(this: *mut core::ffi::c_void, session: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo12", "EventPipeStopSession", 103usize, |this| ((**(this as *const *const ICorProfilerInfo12_Vtbl)).EventPipeStopSession)(this, session), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventPipeCreateProvider(this: *mut core::ffi::c_void, providername: windows_core::PCWSTR, pprovider: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo12", "EventPipeCreateProvider", 104usize, |this| ((**(this as *const *const ICorProfilerInfo12_Vtbl)).EventPipeCreateProvider)(this, providername, pprovider), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventPipeGetProviderInfo(this: *mut core::ffi::c_void, provider: usize, cchname: u32, pcchname: *mut u32, providername: windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo12", "EventPipeGetProviderInfo", 105usize, |this| ((**(this as *const *const ICorProfilerInfo12_Vtbl)).EventPipeGetProviderInfo)(this, provider, cchname, pcchname, providername), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventPipeDefineEvent(this: *mut core::ffi::c_void, provider: usize, eventname: windows_core::PCWSTR, eventid: u32, keywords: u64, eventversion: u32, level: u32, opcode: u8, needstack: super::super::super::Foundation::BOOL, cparamdescs: u32, pparamdescs: *const COR_PRF_EVENTPIPE_PARAM_DESC, pevent: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo12", "EventPipeDefineEvent", 106usize, |this| ((**(this as *const *const ICorProfilerInfo12_Vtbl)).EventPipeDefineEvent)(this, provider, eventname, eventid, keywords, eventversion, level, opcode, needstack, cparamdescs, pparamdescs, pevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventPipeWriteEvent(this: *mut core::ffi::c_void, event: usize, cdata: u32, data: *const COR_PRF_EVENT_DATA, pactivityid: *const windows_core::GUID, prelatedactivityid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo12", "EventPipeWriteEvent", 107usize, |this| ((**(this as *const *const ICorProfilerInfo12_Vtbl)).EventPipeWriteEvent)(this, event, cdata, data, pactivityid, prelatedactivityid), |result| Some(*result))
+        }
+        Self {
+            base__: <ICorProfilerInfo11_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EventPipeStartSession,
+            EventPipeAddProviderToSession,
+            EventPipeStopSession,
+            EventPipeCreateProvider,
+            EventPipeGetProviderInfo,
+            EventPipeDefineEvent,
+            EventPipeWriteEvent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerInfo12 as windows_core::Interface>::IID || <ICorProfilerInfo11_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_WinRT_Metadata")]
+impl windows_core::imp::ProxyVtable for ICorProfilerInfo13_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateHandle(this: *mut core::ffi::c_void, object: usize, r#type: COR_PRF_HANDLE_TYPE, phandle: *mut *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo13", "CreateHandle", 108usize, |this| ((**(this as *const *const ICorProfilerInfo13_Vtbl)).CreateHandle)(this, object, r#type, phandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestroyHandle(this: *mut core::ffi::c_void, handle: *const *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo13", "DestroyHandle", 109usize, |this| ((**(this as *const *const ICorProfilerInfo13_Vtbl)).DestroyHandle)(this, handle), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObjectIDFromHandle(this: *mut core::ffi::c_void, handle: *const *const core::ffi::c_void, pobject: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo13", "GetObjectIDFromHandle", 110usize, |this| ((**(this as *const *const ICorProfilerInfo13_Vtbl)).GetObjectIDFromHandle)(this, handle, pobject), |result| Some(*result))
+        }
+        Self { base__: <ICorProfilerInfo12_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateHandle, DestroyHandle, GetObjectIDFromHandle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerInfo13 as windows_core::Interface>::IID || <ICorProfilerInfo12_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_WinRT_Metadata")]
+impl windows_core::imp::ProxyVtable for ICorProfilerInfo14_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumerateNonGCObjects(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo14", "EnumerateNonGCObjects", 111usize, |this| ((**(this as *const *const ICorProfilerInfo14_Vtbl)).EnumerateNonGCObjects)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNonGCHeapBounds(this: *mut core::ffi::c_void, cobjectranges: u32, pcobjectranges: *mut u32, ranges: *mut COR_PRF_NONGC_HEAP_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo14", "GetNonGCHeapBounds", 112usize, |this| ((**(this as *const *const ICorProfilerInfo14_Vtbl)).GetNonGCHeapBounds)(this, cobjectranges, pcobjectranges, ranges), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventPipeCreateProvider2(this: *mut core::ffi::c_void, providername: windows_core::PCWSTR, pcallback: *const EventPipeProviderCallback, pprovider: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo14", "EventPipeCreateProvider2", 113usize, |this| ((**(this as *const *const ICorProfilerInfo14_Vtbl)).EventPipeCreateProvider2)(this, providername, pcallback, pprovider), |result| Some(*result))
+        }
+        Self { base__: <ICorProfilerInfo13_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnumerateNonGCObjects, GetNonGCHeapBounds, EventPipeCreateProvider2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerInfo14 as windows_core::Interface>::IID || <ICorProfilerInfo13_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_WinRT_Metadata")]
+impl windows_core::imp::ProxyVtable for ICorProfilerInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DoStackSnapshot(this: *mut core::ffi::c_void, thread: usize, callback: *const StackSnapshotCallback, infoflags: u32, clientdata: *const core::ffi::c_void, context: *const u8, contextsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo2", "DoStackSnapshot", 36usize, |this| ((**(this as *const *const ICorProfilerInfo2_Vtbl)).DoStackSnapshot)(this, thread, callback, infoflags, clientdata, context, contextsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnterLeaveFunctionHooks2(this: *mut core::ffi::c_void, pfuncenter: *const FunctionEnter2, pfuncleave: *const FunctionLeave2, pfunctailcall: *const FunctionTailcall2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo2", "SetEnterLeaveFunctionHooks2", 37usize, |this| ((**(this as *const *const ICorProfilerInfo2_Vtbl)).SetEnterLeaveFunctionHooks2)(this, pfuncenter, pfuncleave, pfunctailcall), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFunctionInfo2(this: *mut core::ffi::c_void, funcid: usize, frameinfo: usize, pclassid: *mut usize, pmoduleid: *mut usize, ptoken: *mut u32, ctypeargs: u32, pctypeargs: *mut u32, typeargs: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo2", "GetFunctionInfo2", 38usize, |this| ((**(this as *const *const ICorProfilerInfo2_Vtbl)).GetFunctionInfo2)(this, funcid, frameinfo, pclassid, pmoduleid, ptoken, ctypeargs, pctypeargs, typeargs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStringLayout(this: *mut core::ffi::c_void, pbufferlengthoffset: *mut u32, pstringlengthoffset: *mut u32, pbufferoffset: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo2", "GetStringLayout", 39usize, |this| ((**(this as *const *const ICorProfilerInfo2_Vtbl)).GetStringLayout)(this, pbufferlengthoffset, pstringlengthoffset, pbufferoffset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_WinRT_Metadata")]
+        unsafe extern "system" fn GetClassLayout(this: *mut core::ffi::c_void, classid: usize, rfieldoffset: *mut super::super::WinRT::Metadata::COR_FIELD_OFFSET, cfieldoffset: u32, pcfieldoffset: *mut u32, pulclasssize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo2", "GetClassLayout", 40usize, |this| ((**(this as *const *const ICorProfilerInfo2_Vtbl)).GetClassLayout)(this, classid, rfieldoffset, cfieldoffset, pcfieldoffset, pulclasssize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClassIDInfo2(this: *mut core::ffi::c_void, classid: usize, pmoduleid: *mut usize, ptypedeftoken: *mut u32, pparentclassid: *mut usize, cnumtypeargs: u32, pcnumtypeargs: *mut u32, typeargs: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo2", "GetClassIDInfo2", 41usize, |this| ((**(this as *const *const ICorProfilerInfo2_Vtbl)).GetClassIDInfo2)(this, classid, pmoduleid, ptypedeftoken, pparentclassid, cnumtypeargs, pcnumtypeargs, typeargs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCodeInfo2(this: *mut core::ffi::c_void, functionid: usize, ccodeinfos: u32, pccodeinfos: *mut u32, codeinfos: *mut COR_PRF_CODE_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo2", "GetCodeInfo2", 42usize, |this| ((**(this as *const *const ICorProfilerInfo2_Vtbl)).GetCodeInfo2)(this, functionid, ccodeinfos, pccodeinfos, codeinfos), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClassFromTokenAndTypeArgs(this: *mut core::ffi::c_void, moduleid: usize, typedef: u32, ctypeargs: u32, typeargs: *const usize, pclassid: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo2", "GetClassFromTokenAndTypeArgs", 43usize, |this| ((**(this as *const *const ICorProfilerInfo2_Vtbl)).GetClassFromTokenAndTypeArgs)(this, moduleid, typedef, ctypeargs, typeargs, pclassid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFunctionFromTokenAndTypeArgs(this: *mut core::ffi::c_void, moduleid: usize, funcdef: u32, classid: usize, ctypeargs: u32, typeargs: *const usize, pfunctionid: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo2", "GetFunctionFromTokenAndTypeArgs", 44usize, |this| ((**(this as *const *const ICorProfilerInfo2_Vtbl)).GetFunctionFromTokenAndTypeArgs)(this, moduleid, funcdef, classid, ctypeargs, typeargs, pfunctionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumModuleFrozenObjects(this: *mut core::ffi::c_void, moduleid: usize, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo2", "EnumModuleFrozenObjects", 45usize, |this| ((**(this as *const *const ICorProfilerInfo2_Vtbl)).EnumModuleFrozenObjects)(this, moduleid, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetArrayObjectInfo(this: *mut core::ffi::c_void, objectid: usize, cdimensions: u32, pdimensionsizes: *mut u32, pdimensionlowerbounds: *mut i32, ppdata: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo2", "GetArrayObjectInfo", 46usize, |this| ((**(this as *const *const ICorProfilerInfo2_Vtbl)).GetArrayObjectInfo)(this, objectid, cdimensions, pdimensionsizes, pdimensionlowerbounds, ppdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBoxClassLayout(this: *mut core::ffi::c_void, classid: usize, pbufferoffset: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo2", "GetBoxClassLayout", 47usize, |this| ((**(this as *const *const ICorProfilerInfo2_Vtbl)).GetBoxClassLayout)(this, classid, pbufferoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetThreadAppDomain(this: *mut core::ffi::c_void, threadid: usize, pappdomainid: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo2", "GetThreadAppDomain", 48usize, |this| ((**(this as *const *const ICorProfilerInfo2_Vtbl)).GetThreadAppDomain)(this, threadid, pappdomainid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRVAStaticAddress(this: *mut core::ffi::c_void, classid: usize, fieldtoken: u32, ppaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo2", "GetRVAStaticAddress", 49usize, |this| ((**(this as *const *const ICorProfilerInfo2_Vtbl)).GetRVAStaticAddress)(this, classid, fieldtoken, ppaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAppDomainStaticAddress(this: *mut core::ffi::c_void, classid: usize, fieldtoken: u32, appdomainid: usize, ppaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo2", "GetAppDomainStaticAddress", 50usize, |this| ((**(this as *const *const ICorProfilerInfo2_Vtbl)).GetAppDomainStaticAddress)(this, classid, fieldtoken, appdomainid, ppaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetThreadStaticAddress(this: *mut core::ffi::c_void, classid: usize, fieldtoken: u32, threadid: usize, ppaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo2", "GetThreadStaticAddress", 51usize, |this| ((**(this as *const *const ICorProfilerInfo2_Vtbl)).GetThreadStaticAddress)(this, classid, fieldtoken, threadid, ppaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContextStaticAddress(this: *mut core::ffi::c_void, classid: usize, fieldtoken: u32, contextid: usize, ppaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo2", "GetContextStaticAddress", 52usize, |this| ((**(this as *const *const ICorProfilerInfo2_Vtbl)).GetContextStaticAddress)(this, classid, fieldtoken, contextid, ppaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStaticFieldInfo(this: *mut core::ffi::c_void, classid: usize, fieldtoken: u32, pfieldinfo: *mut COR_PRF_STATIC_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo2", "GetStaticFieldInfo", 53usize, |this| ((**(this as *const *const ICorProfilerInfo2_Vtbl)).GetStaticFieldInfo)(this, classid, fieldtoken, pfieldinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGenerationBounds(this: *mut core::ffi::c_void, cobjectranges: u32, pcobjectranges: *mut u32, ranges: *mut COR_PRF_GC_GENERATION_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo2", "GetGenerationBounds", 54usize, |this| ((**(this as *const *const ICorProfilerInfo2_Vtbl)).GetGenerationBounds)(this, cobjectranges, pcobjectranges, ranges), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObjectGeneration(this: *mut core::ffi::c_void, objectid: usize, range: *mut COR_PRF_GC_GENERATION_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo2", "GetObjectGeneration", 55usize, |this| ((**(this as *const *const ICorProfilerInfo2_Vtbl)).GetObjectGeneration)(this, objectid, range), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNotifiedExceptionClauseInfo(this: *mut core::ffi::c_void, pinfo: *mut COR_PRF_EX_CLAUSE_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo2", "GetNotifiedExceptionClauseInfo", 56usize, |this| ((**(this as *const *const ICorProfilerInfo2_Vtbl)).GetNotifiedExceptionClauseInfo)(this, pinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <ICorProfilerInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DoStackSnapshot,
+            SetEnterLeaveFunctionHooks2,
+            GetFunctionInfo2,
+            GetStringLayout,
+            #[cfg(feature = "Win32_System_WinRT_Metadata")]
+            GetClassLayout,
+            #[cfg(not(feature = "Win32_System_WinRT_Metadata"))]
+            GetClassLayout: 0,
+            GetClassIDInfo2,
+            GetCodeInfo2,
+            GetClassFromTokenAndTypeArgs,
+            GetFunctionFromTokenAndTypeArgs,
+            EnumModuleFrozenObjects,
+            GetArrayObjectInfo,
+            GetBoxClassLayout,
+            GetThreadAppDomain,
+            GetRVAStaticAddress,
+            GetAppDomainStaticAddress,
+            GetThreadStaticAddress,
+            GetContextStaticAddress,
+            GetStaticFieldInfo,
+            GetGenerationBounds,
+            GetObjectGeneration,
+            GetNotifiedExceptionClauseInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerInfo2 as windows_core::Interface>::IID || <ICorProfilerInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_WinRT_Metadata")]
+impl windows_core::imp::ProxyVtable for ICorProfilerInfo3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumJITedFunctions(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo3", "EnumJITedFunctions", 57usize, |this| ((**(this as *const *const ICorProfilerInfo3_Vtbl)).EnumJITedFunctions)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestProfilerDetach(this: *mut core::ffi::c_void, dwexpectedcompletionmilliseconds: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo3", "RequestProfilerDetach", 58usize, |this| ((**(this as *const *const ICorProfilerInfo3_Vtbl)).RequestProfilerDetach)(this, dwexpectedcompletionmilliseconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFunctionIDMapper2(this: *mut core::ffi::c_void, pfunc: *const FunctionIDMapper2, clientdata: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo3", "SetFunctionIDMapper2", 59usize, |this| ((**(this as *const *const ICorProfilerInfo3_Vtbl)).SetFunctionIDMapper2)(this, pfunc, clientdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStringLayout2(this: *mut core::ffi::c_void, pstringlengthoffset: *mut u32, pbufferoffset: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo3", "GetStringLayout2", 60usize, |this| ((**(this as *const *const ICorProfilerInfo3_Vtbl)).GetStringLayout2)(this, pstringlengthoffset, pbufferoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnterLeaveFunctionHooks3(this: *mut core::ffi::c_void, pfuncenter3: *const FunctionEnter3, pfuncleave3: *const FunctionLeave3, pfunctailcall3: *const FunctionTailcall3) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo3", "SetEnterLeaveFunctionHooks3", 61usize, |this| ((**(this as *const *const ICorProfilerInfo3_Vtbl)).SetEnterLeaveFunctionHooks3)(this, pfuncenter3, pfuncleave3, pfunctailcall3), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnterLeaveFunctionHooks3WithInfo(this: *mut core::ffi::c_void, pfuncenter3withinfo: *const FunctionEnter3WithInfo, pfuncleave3withinfo: *const FunctionLeave3WithInfo, pfunctailcall3withinfo: *const FunctionTailcall3WithInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo3", "SetEnterLeaveFunctionHooks3WithInfo", 62usize, |this| ((**(this as *const *const ICorProfilerInfo3_Vtbl)).SetEnterLeaveFunctionHooks3WithInfo)(this, pfuncenter3withinfo, pfuncleave3withinfo, pfunctailcall3withinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFunctionEnter3Info(this: *mut core::ffi::c_void, functionid: usize, eltinfo: usize, pframeinfo: *mut usize, pcbargumentinfo: *mut u32, pargumentinfo: *mut COR_PRF_FUNCTION_ARGUMENT_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo3", "GetFunctionEnter3Info", 63usize, |this| ((**(this as *const *const ICorProfilerInfo3_Vtbl)).GetFunctionEnter3Info)(this, functionid, eltinfo, pframeinfo, pcbargumentinfo, pargumentinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFunctionLeave3Info(this: *mut core::ffi::c_void, functionid: usize, eltinfo: usize, pframeinfo: *mut usize, pretvalrange: *mut COR_PRF_FUNCTION_ARGUMENT_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo3", "GetFunctionLeave3Info", 64usize, |this| ((**(this as *const *const ICorProfilerInfo3_Vtbl)).GetFunctionLeave3Info)(this, functionid, eltinfo, pframeinfo, pretvalrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFunctionTailcall3Info(this: *mut core::ffi::c_void, functionid: usize, eltinfo: usize, pframeinfo: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo3", "GetFunctionTailcall3Info", 65usize, |this| ((**(this as *const *const ICorProfilerInfo3_Vtbl)).GetFunctionTailcall3Info)(this, functionid, eltinfo, pframeinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumModules(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo3", "EnumModules", 66usize, |this| ((**(this as *const *const ICorProfilerInfo3_Vtbl)).EnumModules)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRuntimeInformation(this: *mut core::ffi::c_void, pclrinstanceid: *mut u16, pruntimetype: *mut COR_PRF_RUNTIME_TYPE, pmajorversion: *mut u16, pminorversion: *mut u16, pbuildnumber: *mut u16, pqfeversion: *mut u16, cchversionstring: u32, pcchversionstring: *mut u32, szversionstring: windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo3", "GetRuntimeInformation", 67usize, |this| ((**(this as *const *const ICorProfilerInfo3_Vtbl)).GetRuntimeInformation)(this, pclrinstanceid, pruntimetype, pmajorversion, pminorversion, pbuildnumber, pqfeversion, cchversionstring, pcchversionstring, szversionstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetThreadStaticAddress2(this: *mut core::ffi::c_void, classid: usize, fieldtoken: u32, appdomainid: usize, threadid: usize, ppaddress: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo3", "GetThreadStaticAddress2", 68usize, |this| ((**(this as *const *const ICorProfilerInfo3_Vtbl)).GetThreadStaticAddress2)(this, classid, fieldtoken, appdomainid, threadid, ppaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAppDomainsContainingModule(this: *mut core::ffi::c_void, moduleid: usize, cappdomainids: u32, pcappdomainids: *mut u32, appdomainids: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo3", "GetAppDomainsContainingModule", 69usize, |this| ((**(this as *const *const ICorProfilerInfo3_Vtbl)).GetAppDomainsContainingModule)(this, moduleid, cappdomainids, pcappdomainids, appdomainids), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetModuleInfo2(this: *mut core::ffi::c_void, moduleid: usize, ppbaseloadaddress: *mut *mut u8, cchname: u32, pcchname: *mut u32, szname: windows_core::PWSTR, passemblyid: *mut usize, pdwmoduleflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo3", "GetModuleInfo2", 70usize, |this| ((**(this as *const *const ICorProfilerInfo3_Vtbl)).GetModuleInfo2)(this, moduleid, ppbaseloadaddress, cchname, pcchname, szname, passemblyid, pdwmoduleflags), |result| Some(*result))
+        }
+        Self {
+            base__: <ICorProfilerInfo2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EnumJITedFunctions,
+            RequestProfilerDetach,
+            SetFunctionIDMapper2,
+            GetStringLayout2,
+            SetEnterLeaveFunctionHooks3,
+            SetEnterLeaveFunctionHooks3WithInfo,
+            GetFunctionEnter3Info,
+            GetFunctionLeave3Info,
+            GetFunctionTailcall3Info,
+            EnumModules,
+            GetRuntimeInformation,
+            GetThreadStaticAddress2,
+            GetAppDomainsContainingModule,
+            GetModuleInfo2,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerInfo3 as windows_core::Interface>::IID || <ICorProfilerInfo2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_WinRT_Metadata")]
+impl windows_core::imp::ProxyVtable for ICorProfilerInfo4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumThreads(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo4", "EnumThreads", 71usize, |this| ((**(this as *const *const ICorProfilerInfo4_Vtbl)).EnumThreads)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeCurrentThread(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo4", "InitializeCurrentThread", 72usize, |this| ((**(this as *const *const ICorProfilerInfo4_Vtbl)).InitializeCurrentThread)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestReJIT(this: *mut core::ffi::c_void, cfunctions: u32, moduleids: *const usize, methodids: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo4", "RequestReJIT", 73usize, |this| ((**(this as *const *const ICorProfilerInfo4_Vtbl)).RequestReJIT)(this, cfunctions, moduleids, methodids), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestRevert(this: *mut core::ffi::c_void, cfunctions: u32, moduleids: *const usize, methodids: *const u32, status: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo4", "RequestRevert", 74usize, |this| ((**(this as *const *const ICorProfilerInfo4_Vtbl)).RequestRevert)(this, cfunctions, moduleids, methodids, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCodeInfo3(this: *mut core::ffi::c_void, functionid: usize, rejitid: usize, ccodeinfos: u32, pccodeinfos: *mut u32, codeinfos: *mut COR_PRF_CODE_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo4", "GetCodeInfo3", 75usize, |this| ((**(this as *const *const ICorProfilerInfo4_Vtbl)).GetCodeInfo3)(this, functionid, rejitid, ccodeinfos, pccodeinfos, codeinfos), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFunctionFromIP2(this: *mut core::ffi::c_void, ip: *const u8, pfunctionid: *mut usize, prejitid: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo4", "GetFunctionFromIP2", 76usize, |this| ((**(this as *const *const ICorProfilerInfo4_Vtbl)).GetFunctionFromIP2)(this, ip, pfunctionid, prejitid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReJITIDs(this: *mut core::ffi::c_void, functionid: usize, crejitids: u32, pcrejitids: *mut u32, rejitids: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo4", "GetReJITIDs", 77usize, |this| ((**(this as *const *const ICorProfilerInfo4_Vtbl)).GetReJITIDs)(this, functionid, crejitids, pcrejitids, rejitids), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetILToNativeMapping2(this: *mut core::ffi::c_void, functionid: usize, rejitid: usize, cmap: u32, pcmap: *mut u32, map: *mut COR_DEBUG_IL_TO_NATIVE_MAP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo4", "GetILToNativeMapping2", 78usize, |this| ((**(this as *const *const ICorProfilerInfo4_Vtbl)).GetILToNativeMapping2)(this, functionid, rejitid, cmap, pcmap, map), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumJITedFunctions2(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo4", "EnumJITedFunctions2", 79usize, |this| ((**(this as *const *const ICorProfilerInfo4_Vtbl)).EnumJITedFunctions2)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObjectSize2(this: *mut core::ffi::c_void, objectid: usize, pcsize: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo4", "GetObjectSize2", 80usize, |this| ((**(this as *const *const ICorProfilerInfo4_Vtbl)).GetObjectSize2)(this, objectid, pcsize), |result| Some(*result))
+        }
+        Self {
+            base__: <ICorProfilerInfo3_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EnumThreads,
+            InitializeCurrentThread,
+            RequestReJIT,
+            RequestRevert,
+            GetCodeInfo3,
+            GetFunctionFromIP2,
+            GetReJITIDs,
+            GetILToNativeMapping2,
+            EnumJITedFunctions2,
+            GetObjectSize2,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerInfo4 as windows_core::Interface>::IID || <ICorProfilerInfo3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_WinRT_Metadata")]
+impl windows_core::imp::ProxyVtable for ICorProfilerInfo5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEventMask2(this: *mut core::ffi::c_void, pdweventslow: *mut u32, pdweventshigh: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo5", "GetEventMask2", 81usize, |this| ((**(this as *const *const ICorProfilerInfo5_Vtbl)).GetEventMask2)(this, pdweventslow, pdweventshigh), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventMask2(this: *mut core::ffi::c_void, dweventslow: u32, dweventshigh: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo5", "SetEventMask2", 82usize, |this| ((**(this as *const *const ICorProfilerInfo5_Vtbl)).SetEventMask2)(this, dweventslow, dweventshigh), |result| Some(*result))
+        }
+        Self { base__: <ICorProfilerInfo4_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetEventMask2, SetEventMask2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerInfo5 as windows_core::Interface>::IID || <ICorProfilerInfo4_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_WinRT_Metadata")]
+impl windows_core::imp::ProxyVtable for ICorProfilerInfo6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumNgenModuleMethodsInliningThisMethod(this: *mut core::ffi::c_void, inlinersmoduleid: usize, inlineemoduleid: usize, inlineemethodid: u32, incompletedata: *mut super::super::super::Foundation::BOOL, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo6", "EnumNgenModuleMethodsInliningThisMethod", 83usize, |this| ((**(this as *const *const ICorProfilerInfo6_Vtbl)).EnumNgenModuleMethodsInliningThisMethod)(this, inlinersmoduleid, inlineemoduleid, inlineemethodid, incompletedata, ppenum), |result| Some(*result))
+        }
+        Self { base__: <ICorProfilerInfo5_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnumNgenModuleMethodsInliningThisMethod }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerInfo6 as windows_core::Interface>::IID || <ICorProfilerInfo5_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_WinRT_Metadata")]
+impl windows_core::imp::ProxyVtable for ICorProfilerInfo7_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ApplyMetaData(this: *mut core::ffi::c_void, moduleid: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo7", "ApplyMetaData", 84usize, |this| ((**(this as *const *const ICorProfilerInfo7_Vtbl)).ApplyMetaData)(this, moduleid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInMemorySymbolsLength(this: *mut core::ffi::c_void, moduleid: usize, pcountsymbolbytes: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo7", "GetInMemorySymbolsLength", 85usize, |this| ((**(this as *const *const ICorProfilerInfo7_Vtbl)).GetInMemorySymbolsLength)(this, moduleid, pcountsymbolbytes), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadInMemorySymbols(this: *mut core::ffi::c_void, moduleid: usize, symbolsreadoffset: u32, psymbolbytes: *mut u8, countsymbolbytes: u32, pcountsymbolbytesread: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo7", "ReadInMemorySymbols", 86usize, |this| ((**(this as *const *const ICorProfilerInfo7_Vtbl)).ReadInMemorySymbols)(this, moduleid, symbolsreadoffset, psymbolbytes, countsymbolbytes, pcountsymbolbytesread), |result| Some(*result))
+        }
+        Self { base__: <ICorProfilerInfo6_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ApplyMetaData, GetInMemorySymbolsLength, ReadInMemorySymbols }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerInfo7 as windows_core::Interface>::IID || <ICorProfilerInfo6_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_WinRT_Metadata")]
+impl windows_core::imp::ProxyVtable for ICorProfilerInfo8_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsFunctionDynamic(this: *mut core::ffi::c_void, functionid: usize, isdynamic: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo8", "IsFunctionDynamic", 87usize, |this| ((**(this as *const *const ICorProfilerInfo8_Vtbl)).IsFunctionDynamic)(this, functionid, isdynamic), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFunctionFromIP3(this: *mut core::ffi::c_void, ip: *const u8, functionid: *mut usize, prejitid: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo8", "GetFunctionFromIP3", 88usize, |this| ((**(this as *const *const ICorProfilerInfo8_Vtbl)).GetFunctionFromIP3)(this, ip, functionid, prejitid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDynamicFunctionInfo(this: *mut core::ffi::c_void, functionid: usize, moduleid: *mut usize, ppvsig: *mut *mut u8, pbsig: *mut u32, cchname: u32, pcchname: *mut u32, wszname: windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo8", "GetDynamicFunctionInfo", 89usize, |this| ((**(this as *const *const ICorProfilerInfo8_Vtbl)).GetDynamicFunctionInfo)(this, functionid, moduleid, ppvsig, pbsig, cchname, pcchname, wszname), |result| Some(*result))
+        }
+        Self { base__: <ICorProfilerInfo7_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsFunctionDynamic, GetFunctionFromIP3, GetDynamicFunctionInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerInfo8 as windows_core::Interface>::IID || <ICorProfilerInfo7_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_WinRT_Metadata")]
+impl windows_core::imp::ProxyVtable for ICorProfilerInfo9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNativeCodeStartAddresses(this: *mut core::ffi::c_void, functionid: usize, rejitid: usize, ccodestartaddresses: u32, pccodestartaddresses: *mut u32, codestartaddresses: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo9", "GetNativeCodeStartAddresses", 90usize, |this| ((**(this as *const *const ICorProfilerInfo9_Vtbl)).GetNativeCodeStartAddresses)(this, functionid, rejitid, ccodestartaddresses, pccodestartaddresses, codestartaddresses), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetILToNativeMapping3(this: *mut core::ffi::c_void, pnativecodestartaddress: usize, cmap: u32, pcmap: *mut u32, map: *mut COR_DEBUG_IL_TO_NATIVE_MAP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo9", "GetILToNativeMapping3", 91usize, |this| ((**(this as *const *const ICorProfilerInfo9_Vtbl)).GetILToNativeMapping3)(this, pnativecodestartaddress, cmap, pcmap, map), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCodeInfo4(this: *mut core::ffi::c_void, pnativecodestartaddress: usize, ccodeinfos: u32, pccodeinfos: *mut u32, codeinfos: *mut COR_PRF_CODE_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerInfo9", "GetCodeInfo4", 92usize, |this| ((**(this as *const *const ICorProfilerInfo9_Vtbl)).GetCodeInfo4)(this, pnativecodestartaddress, ccodeinfos, pccodeinfos, codeinfos), |result| Some(*result))
+        }
+        Self { base__: <ICorProfilerInfo8_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetNativeCodeStartAddresses, GetILToNativeMapping3, GetCodeInfo4 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerInfo9 as windows_core::Interface>::IID || <ICorProfilerInfo8_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICorProfilerMethodEnum_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerMethodEnum", "Skip", 3usize, |this| ((**(this as *const *const ICorProfilerMethodEnum_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerMethodEnum", "Reset", 4usize, |this| ((**(this as *const *const ICorProfilerMethodEnum_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerMethodEnum", "Clone", 5usize, |this| ((**(this as *const *const ICorProfilerMethodEnum_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcelt: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerMethodEnum", "GetCount", 6usize, |this| ((**(this as *const *const ICorProfilerMethodEnum_Vtbl)).GetCount)(this, pcelt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, elements: *mut COR_PRF_METHOD, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerMethodEnum", "Next", 7usize, |this| ((**(this as *const *const ICorProfilerMethodEnum_Vtbl)).Next)(this, celt, elements, pceltfetched), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Skip, Reset, Clone, GetCount, Next }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerMethodEnum as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICorProfilerModuleEnum_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerModuleEnum", "Skip", 3usize, |this| ((**(this as *const *const ICorProfilerModuleEnum_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerModuleEnum", "Reset", 4usize, |this| ((**(this as *const *const ICorProfilerModuleEnum_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerModuleEnum", "Clone", 5usize, |this| ((**(this as *const *const ICorProfilerModuleEnum_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcelt: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerModuleEnum", "GetCount", 6usize, |this| ((**(this as *const *const ICorProfilerModuleEnum_Vtbl)).GetCount)(this, pcelt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ids: *mut usize, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerModuleEnum", "Next", 7usize, |this| ((**(this as *const *const ICorProfilerModuleEnum_Vtbl)).Next)(this, celt, ids, pceltfetched), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Skip, Reset, Clone, GetCount, Next }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerModuleEnum as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICorProfilerObjectEnum_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerObjectEnum", "Skip", 3usize, |this| ((**(this as *const *const ICorProfilerObjectEnum_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerObjectEnum", "Reset", 4usize, |this| ((**(this as *const *const ICorProfilerObjectEnum_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerObjectEnum", "Clone", 5usize, |this| ((**(this as *const *const ICorProfilerObjectEnum_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcelt: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerObjectEnum", "GetCount", 6usize, |this| ((**(this as *const *const ICorProfilerObjectEnum_Vtbl)).GetCount)(this, pcelt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, objects: *mut usize, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerObjectEnum", "Next", 7usize, |this| ((**(this as *const *const ICorProfilerObjectEnum_Vtbl)).Next)(this, celt, objects, pceltfetched), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Skip, Reset, Clone, GetCount, Next }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerObjectEnum as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICorProfilerThreadEnum_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerThreadEnum", "Skip", 3usize, |this| ((**(this as *const *const ICorProfilerThreadEnum_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerThreadEnum", "Reset", 4usize, |this| ((**(this as *const *const ICorProfilerThreadEnum_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerThreadEnum", "Clone", 5usize, |this| ((**(this as *const *const ICorProfilerThreadEnum_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcelt: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerThreadEnum", "GetCount", 6usize, |this| ((**(this as *const *const ICorProfilerThreadEnum_Vtbl)).GetCount)(this, pcelt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ids: *mut usize, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorProfilerThreadEnum", "Next", 7usize, |this| ((**(this as *const *const ICorProfilerThreadEnum_Vtbl)).Next)(this, celt, ids, pceltfetched), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Skip, Reset, Clone, GetCount, Next }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorProfilerThreadEnum as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMethodMalloc_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Alloc(this: *mut core::ffi::c_void, cb: u32) -> *mut core::ffi::c_void {
+            windows_core::imp::proxy_call(this, "IMethodMalloc", "Alloc", 3usize, |this| ((**(this as *const *const IMethodMalloc_Vtbl)).Alloc)(this, cb), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Alloc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMethodMalloc as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
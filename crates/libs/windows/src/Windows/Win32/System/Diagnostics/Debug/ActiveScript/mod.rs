@@ -4638,6 +4638,7 @@ pub struct APPLICATION_NODE_EVENT_FILTER(pub i32);
 impl windows_core::TypeKind for APPLICATION_NODE_EVENT_FILTER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for APPLICATION_NODE_EVENT_FILTER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("APPLICATION_NODE_EVENT_FILTER").field(&self.0).finish()
@@ -4649,6 +4650,7 @@ pub struct BREAKPOINT_STATE(pub i32);
 impl windows_core::TypeKind for BREAKPOINT_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BREAKPOINT_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BREAKPOINT_STATE").field(&self.0).finish()
@@ -4660,6 +4662,7 @@ pub struct BREAKREASON(pub i32);
 impl windows_core::TypeKind for BREAKREASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BREAKREASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BREAKREASON").field(&self.0).finish()
@@ -4671,6 +4674,7 @@ pub struct BREAKRESUMEACTION(pub i32);
 impl windows_core::TypeKind for BREAKRESUMEACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BREAKRESUMEACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BREAKRESUMEACTION").field(&self.0).finish()
@@ -4682,6 +4686,7 @@ pub struct DEBUG_EVENT_INFO_TYPE(pub i32);
 impl windows_core::TypeKind for DEBUG_EVENT_INFO_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEBUG_EVENT_INFO_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEBUG_EVENT_INFO_TYPE").field(&self.0).finish()
@@ -4693,6 +4698,7 @@ pub struct DEBUG_STACKFRAME_TYPE(pub i32);
 impl windows_core::TypeKind for DEBUG_STACKFRAME_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEBUG_STACKFRAME_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEBUG_STACKFRAME_TYPE").field(&self.0).finish()
@@ -4704,6 +4710,7 @@ pub struct DOCUMENTNAMETYPE(pub i32);
 impl windows_core::TypeKind for DOCUMENTNAMETYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DOCUMENTNAMETYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DOCUMENTNAMETYPE").field(&self.0).finish()
@@ -4715,6 +4722,7 @@ pub struct ERRORRESUMEACTION(pub i32);
 impl windows_core::TypeKind for ERRORRESUMEACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ERRORRESUMEACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ERRORRESUMEACTION").field(&self.0).finish()
@@ -4726,6 +4734,7 @@ pub struct JS_PROPERTY_ATTRIBUTES(pub i32);
 impl windows_core::TypeKind for JS_PROPERTY_ATTRIBUTES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for JS_PROPERTY_ATTRIBUTES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("JS_PROPERTY_ATTRIBUTES").field(&self.0).finish()
@@ -4737,6 +4746,7 @@ pub struct JS_PROPERTY_MEMBERS(pub i32);
 impl windows_core::TypeKind for JS_PROPERTY_MEMBERS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for JS_PROPERTY_MEMBERS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("JS_PROPERTY_MEMBERS").field(&self.0).finish()
@@ -4752,6 +4762,7 @@ impl JsDebugReadMemoryFlags {
 impl windows_core::TypeKind for JsDebugReadMemoryFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for JsDebugReadMemoryFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("JsDebugReadMemoryFlags").field(&self.0).finish()
@@ -4763,6 +4774,7 @@ pub struct PROFILER_EVENT_MASK(pub i32);
 impl windows_core::TypeKind for PROFILER_EVENT_MASK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROFILER_EVENT_MASK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROFILER_EVENT_MASK").field(&self.0).finish()
@@ -4807,6 +4819,7 @@ pub struct PROFILER_HEAP_ENUM_FLAGS(pub i32);
 impl windows_core::TypeKind for PROFILER_HEAP_ENUM_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROFILER_HEAP_ENUM_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROFILER_HEAP_ENUM_FLAGS").field(&self.0).finish()
@@ -4851,6 +4864,7 @@ pub struct PROFILER_HEAP_OBJECT_FLAGS(pub i32);
 impl windows_core::TypeKind for PROFILER_HEAP_OBJECT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROFILER_HEAP_OBJECT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROFILER_HEAP_OBJECT_FLAGS").field(&self.0).finish()
@@ -4895,6 +4909,7 @@ pub struct PROFILER_HEAP_OBJECT_OPTIONAL_INFO_TYPE(pub i32);
 impl windows_core::TypeKind for PROFILER_HEAP_OBJECT_OPTIONAL_INFO_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROFILER_HEAP_OBJECT_OPTIONAL_INFO_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROFILER_HEAP_OBJECT_OPTIONAL_INFO_TYPE").field(&self.0).finish()
@@ -4906,6 +4921,7 @@ pub struct PROFILER_HEAP_OBJECT_RELATIONSHIP_FLAGS(pub i32);
 impl windows_core::TypeKind for PROFILER_HEAP_OBJECT_RELATIONSHIP_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROFILER_HEAP_OBJECT_RELATIONSHIP_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROFILER_HEAP_OBJECT_RELATIONSHIP_FLAGS").field(&self.0).finish()
@@ -4950,6 +4966,7 @@ pub struct PROFILER_HEAP_SUMMARY_VERSION(pub i32);
 impl windows_core::TypeKind for PROFILER_HEAP_SUMMARY_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROFILER_HEAP_SUMMARY_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROFILER_HEAP_SUMMARY_VERSION").field(&self.0).finish()
@@ -4961,6 +4978,7 @@ pub struct PROFILER_RELATIONSHIP_INFO(pub i32);
 impl windows_core::TypeKind for PROFILER_RELATIONSHIP_INFO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROFILER_RELATIONSHIP_INFO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROFILER_RELATIONSHIP_INFO").field(&self.0).finish()
@@ -4972,6 +4990,7 @@ pub struct PROFILER_SCRIPT_TYPE(pub i32);
 impl windows_core::TypeKind for PROFILER_SCRIPT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROFILER_SCRIPT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROFILER_SCRIPT_TYPE").field(&self.0).finish()
@@ -4983,6 +5002,7 @@ pub struct SCRIPTGCTYPE(pub i32);
 impl windows_core::TypeKind for SCRIPTGCTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCRIPTGCTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCRIPTGCTYPE").field(&self.0).finish()
@@ -4994,6 +5014,7 @@ pub struct SCRIPTLANGUAGEVERSION(pub i32);
 impl windows_core::TypeKind for SCRIPTLANGUAGEVERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCRIPTLANGUAGEVERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCRIPTLANGUAGEVERSION").field(&self.0).finish()
@@ -5005,6 +5026,7 @@ pub struct SCRIPTSTATE(pub i32);
 impl windows_core::TypeKind for SCRIPTSTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCRIPTSTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCRIPTSTATE").field(&self.0).finish()
@@ -5016,6 +5038,7 @@ pub struct SCRIPTTHREADSTATE(pub i32);
 impl windows_core::TypeKind for SCRIPTTHREADSTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCRIPTTHREADSTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCRIPTTHREADSTATE").field(&self.0).finish()
@@ -5027,6 +5050,7 @@ pub struct SCRIPTTRACEINFO(pub i32);
 impl windows_core::TypeKind for SCRIPTTRACEINFO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCRIPTTRACEINFO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCRIPTTRACEINFO").field(&self.0).finish()
@@ -5038,6 +5062,7 @@ pub struct SCRIPTUICHANDLING(pub i32);
 impl windows_core::TypeKind for SCRIPTUICHANDLING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCRIPTUICHANDLING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCRIPTUICHANDLING").field(&self.0).finish()
@@ -5049,6 +5074,7 @@ pub struct SCRIPTUICITEM(pub i32);
 impl windows_core::TypeKind for SCRIPTUICITEM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCRIPTUICITEM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCRIPTUICITEM").field(&self.0).finish()
@@ -5060,6 +5086,7 @@ pub struct SCRIPT_DEBUGGER_OPTIONS(pub i32);
 impl windows_core::TypeKind for SCRIPT_DEBUGGER_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCRIPT_DEBUGGER_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCRIPT_DEBUGGER_OPTIONS").field(&self.0).finish()
@@ -5104,6 +5131,7 @@ pub struct SCRIPT_ERROR_DEBUG_EXCEPTION_THROWN_KIND(pub i32);
 impl windows_core::TypeKind for SCRIPT_ERROR_DEBUG_EXCEPTION_THROWN_KIND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCRIPT_ERROR_DEBUG_EXCEPTION_THROWN_KIND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCRIPT_ERROR_DEBUG_EXCEPTION_THROWN_KIND").field(&self.0).finish()
@@ -5115,6 +5143,7 @@ pub struct SCRIPT_INVOCATION_CONTEXT_TYPE(pub i32);
 impl windows_core::TypeKind for SCRIPT_INVOCATION_CONTEXT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCRIPT_INVOCATION_CONTEXT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCRIPT_INVOCATION_CONTEXT_TYPE").field(&self.0).finish()
@@ -5123,7 +5152,8 @@ impl core::fmt::Debug for SCRIPT_INVOCATION_CONTEXT_TYPE {
 pub const CDebugDocumentHelper: windows_core::GUID = windows_core::GUID::from_u128(0x83b8bca6_687c_11d0_a405_00aa0060275c);
 pub const DebugHelper: windows_core::GUID = windows_core::GUID::from_u128(0x0bfcc060_8c1d_11d0_accd_00aa0060275c);
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DebugStackFrameDescriptor {
     pub pdsf: core::mem::ManuallyDrop<Option<IDebugStackFrame>>,
     pub dwMin: u32,
@@ -5145,7 +5175,8 @@ impl Default for DebugStackFrameDescriptor {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DebugStackFrameDescriptor64 {
     pub pdsf: core::mem::ManuallyDrop<Option<IDebugStackFrame>>,
     pub dwMin: u64,
@@ -5168,7 +5199,8 @@ impl Default for DebugStackFrameDescriptor64 {
 }
 pub const DefaultDebugSessionProvider: windows_core::GUID = windows_core::GUID::from_u128(0x834128a2_51f4_11d0_8f20_00805f2cd064);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct JS_NATIVE_FRAME {
     pub InstructionOffset: u64,
     pub ReturnOffset: u64,
@@ -5184,7 +5216,8 @@ impl Default for JS_NATIVE_FRAME {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct JsDebugPropertyInfo {
     pub name: core::mem::ManuallyDrop<windows_core::BSTR>,
     pub r#type: core::mem::ManuallyDrop<windows_core::BSTR>,
@@ -5338,7 +5371,8 @@ impl Default for PROFILER_HEAP_OBJECT_RELATIONSHIP_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROFILER_HEAP_OBJECT_SCOPE_LIST {
     pub count: u32,
     pub scopes: [usize; 1],
@@ -5352,7 +5386,8 @@ impl Default for PROFILER_HEAP_OBJECT_SCOPE_LIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROFILER_HEAP_SUMMARY {
     pub version: PROFILER_HEAP_SUMMARY_VERSION,
     pub totalHeapSize: u32,
@@ -5366,7 +5401,8 @@ impl Default for PROFILER_HEAP_SUMMARY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROFILER_PROPERTY_TYPE_SUBSTRING_INFO {
     pub length: u32,
     pub value: windows_core::PCWSTR,
@@ -5381,7 +5417,8 @@ impl Default for PROFILER_PROPERTY_TYPE_SUBSTRING_INFO {
 }
 pub const ProcessDebugManager: windows_core::GUID = windows_core::GUID::from_u128(0x78a51822_51f4_11d0_8f20_00805f2cd064);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TEXT_DOCUMENT_ARRAY {
     pub dwCount: u32,
     pub Members: *mut Option<IDebugDocumentText>,
@@ -5396,3 +5433,5 @@ impl Default for TEXT_DOCUMENT_ARRAY {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,3031 @@
+impl windows_core::imp::ProxyVtable for AsyncIDebugApplicationNodeEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Begin_onAddChild(this: *mut core::ffi::c_void, prddpchild: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIDebugApplicationNodeEvents", "Begin_onAddChild", 3usize, |this| ((**(this as *const *const AsyncIDebugApplicationNodeEvents_Vtbl)).Begin_onAddChild)(this, prddpchild), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_onAddChild(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIDebugApplicationNodeEvents", "Finish_onAddChild", 4usize, |this| ((**(this as *const *const AsyncIDebugApplicationNodeEvents_Vtbl)).Finish_onAddChild)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Begin_onRemoveChild(this: *mut core::ffi::c_void, prddpchild: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIDebugApplicationNodeEvents", "Begin_onRemoveChild", 5usize, |this| ((**(this as *const *const AsyncIDebugApplicationNodeEvents_Vtbl)).Begin_onRemoveChild)(this, prddpchild), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_onRemoveChild(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIDebugApplicationNodeEvents", "Finish_onRemoveChild", 6usize, |this| ((**(this as *const *const AsyncIDebugApplicationNodeEvents_Vtbl)).Finish_onRemoveChild)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Begin_onDetach(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIDebugApplicationNodeEvents", "Begin_onDetach", 7usize, |this| ((**(this as *const *const AsyncIDebugApplicationNodeEvents_Vtbl)).Begin_onDetach)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_onDetach(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIDebugApplicationNodeEvents", "Finish_onDetach", 8usize, |this| ((**(this as *const *const AsyncIDebugApplicationNodeEvents_Vtbl)).Finish_onDetach)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Begin_onAttach(this: *mut core::ffi::c_void, prddpparent: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIDebugApplicationNodeEvents", "Begin_onAttach", 9usize, |this| ((**(this as *const *const AsyncIDebugApplicationNodeEvents_Vtbl)).Begin_onAttach)(this, prddpparent), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_onAttach(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIDebugApplicationNodeEvents", "Finish_onAttach", 10usize, |this| ((**(this as *const *const AsyncIDebugApplicationNodeEvents_Vtbl)).Finish_onAttach)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Begin_onAddChild,
+            Finish_onAddChild,
+            Begin_onRemoveChild,
+            Finish_onRemoveChild,
+            Begin_onDetach,
+            Finish_onDetach,
+            Begin_onAttach,
+            Finish_onAttach,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<AsyncIDebugApplicationNodeEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IActiveScript_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetScriptSite(this: *mut core::ffi::c_void, pass: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScript", "SetScriptSite", 3usize, |this| ((**(this as *const *const IActiveScript_Vtbl)).SetScriptSite)(this, pass), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScriptSite(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScript", "GetScriptSite", 4usize, |this| ((**(this as *const *const IActiveScript_Vtbl)).GetScriptSite)(this, riid, ppvobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScriptState(this: *mut core::ffi::c_void, ss: SCRIPTSTATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScript", "SetScriptState", 5usize, |this| ((**(this as *const *const IActiveScript_Vtbl)).SetScriptState)(this, ss), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScriptState(this: *mut core::ffi::c_void, pssstate: *mut SCRIPTSTATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScript", "GetScriptState", 6usize, |this| ((**(this as *const *const IActiveScript_Vtbl)).GetScriptState)(this, pssstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScript", "Close", 7usize, |this| ((**(this as *const *const IActiveScript_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddNamedItem(this: *mut core::ffi::c_void, pstrname: windows_core::PCWSTR, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScript", "AddNamedItem", 8usize, |this| ((**(this as *const *const IActiveScript_Vtbl)).AddNamedItem)(this, pstrname, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTypeLib(this: *mut core::ffi::c_void, rguidtypelib: *const windows_core::GUID, dwmajor: u32, dwminor: u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScript", "AddTypeLib", 9usize, |this| ((**(this as *const *const IActiveScript_Vtbl)).AddTypeLib)(this, rguidtypelib, dwmajor, dwminor, dwflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetScriptDispatch(this: *mut core::ffi::c_void, pstritemname: windows_core::PCWSTR, ppdisp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScript", "GetScriptDispatch", 10usize, |this| ((**(this as *const *const IActiveScript_Vtbl)).GetScriptDispatch)(this, pstritemname, ppdisp), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentScriptThreadID(this: *mut core::ffi::c_void, pstidthread: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScript", "GetCurrentScriptThreadID", 11usize, |this| ((**(this as *const *const IActiveScript_Vtbl)).GetCurrentScriptThreadID)(this, pstidthread), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScriptThreadID(this: *mut core::ffi::c_void, dwwin32threadid: u32, pstidthread: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScript", "GetScriptThreadID", 12usize, |this| ((**(this as *const *const IActiveScript_Vtbl)).GetScriptThreadID)(this, dwwin32threadid, pstidthread), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScriptThreadState(this: *mut core::ffi::c_void, stidthread: u32, pstsstate: *mut SCRIPTTHREADSTATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScript", "GetScriptThreadState", 13usize, |this| ((**(this as *const *const IActiveScript_Vtbl)).GetScriptThreadState)(this, stidthread, pstsstate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InterruptScriptThread(this: *mut core::ffi::c_void, stidthread: u32, pexcepinfo: *const super::super::super::Com::EXCEPINFO, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScript", "InterruptScriptThread", 14usize, |this| ((**(this as *const *const IActiveScript_Vtbl)).InterruptScriptThread)(this, stidthread, pexcepinfo, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppscript: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScript", "Clone", 15usize, |this| ((**(this as *const *const IActiveScript_Vtbl)).Clone)(this, ppscript), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetScriptSite,
+            GetScriptSite,
+            SetScriptState,
+            GetScriptState,
+            Close,
+            AddNamedItem,
+            AddTypeLib,
+            #[cfg(feature = "Win32_System_Com")]
+            GetScriptDispatch,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetScriptDispatch: 0,
+            GetCurrentScriptThreadID,
+            GetScriptThreadID,
+            GetScriptThreadState,
+            #[cfg(feature = "Win32_System_Com")]
+            InterruptScriptThread,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InterruptScriptThread: 0,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScript as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IActiveScriptAuthor_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddNamedItem(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, dwflags: u32, pdisp: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptAuthor", "AddNamedItem", 3usize, |this| ((**(this as *const *const IActiveScriptAuthor_Vtbl)).AddNamedItem)(this, pszname, dwflags, pdisp), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddScriptlet(this: *mut core::ffi::c_void, pszdefaultname: windows_core::PCWSTR, pszcode: windows_core::PCWSTR, pszitemname: windows_core::PCWSTR, pszsubitemname: windows_core::PCWSTR, pszeventname: windows_core::PCWSTR, pszdelimiter: windows_core::PCWSTR, dwcookie: u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptAuthor", "AddScriptlet", 4usize, |this| ((**(this as *const *const IActiveScriptAuthor_Vtbl)).AddScriptlet)(this, pszdefaultname, pszcode, pszitemname, pszsubitemname, pszeventname, pszdelimiter, dwcookie, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParseScriptText(this: *mut core::ffi::c_void, pszcode: windows_core::PCWSTR, pszitemname: windows_core::PCWSTR, pszdelimiter: windows_core::PCWSTR, dwcookie: u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptAuthor", "ParseScriptText", 5usize, |this| ((**(this as *const *const IActiveScriptAuthor_Vtbl)).ParseScriptText)(this, pszcode, pszitemname, pszdelimiter, dwcookie, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScriptTextAttributes(this: *mut core::ffi::c_void, pszcode: windows_core::PCWSTR, cch: u32, pszdelimiter: windows_core::PCWSTR, dwflags: u32, pattr: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptAuthor", "GetScriptTextAttributes", 6usize, |this| ((**(this as *const *const IActiveScriptAuthor_Vtbl)).GetScriptTextAttributes)(this, pszcode, cch, pszdelimiter, dwflags, pattr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScriptletTextAttributes(this: *mut core::ffi::c_void, pszcode: windows_core::PCWSTR, cch: u32, pszdelimiter: windows_core::PCWSTR, dwflags: u32, pattr: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptAuthor", "GetScriptletTextAttributes", 7usize, |this| ((**(this as *const *const IActiveScriptAuthor_Vtbl)).GetScriptletTextAttributes)(this, pszcode, cch, pszdelimiter, dwflags, pattr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRoot(this: *mut core::ffi::c_void, ppsp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptAuthor", "GetRoot", 8usize, |this| ((**(this as *const *const IActiveScriptAuthor_Vtbl)).GetRoot)(this, ppsp), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLanguageFlags(this: *mut core::ffi::c_void, pgrfasa: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptAuthor", "GetLanguageFlags", 9usize, |this| ((**(this as *const *const IActiveScriptAuthor_Vtbl)).GetLanguageFlags)(this, pgrfasa), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetEventHandler(this: *mut core::ffi::c_void, pdisp: *mut core::ffi::c_void, pszitem: windows_core::PCWSTR, pszsubitem: windows_core::PCWSTR, pszevent: windows_core::PCWSTR, ppse: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptAuthor", "GetEventHandler", 10usize, |this| ((**(this as *const *const IActiveScriptAuthor_Vtbl)).GetEventHandler)(this, pdisp, pszitem, pszsubitem, pszevent, ppse), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveNamedItem(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptAuthor", "RemoveNamedItem", 11usize, |this| ((**(this as *const *const IActiveScriptAuthor_Vtbl)).RemoveNamedItem)(this, pszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTypeLib(this: *mut core::ffi::c_void, rguidtypelib: *const windows_core::GUID, dwmajor: u32, dwminor: u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptAuthor", "AddTypeLib", 12usize, |this| ((**(this as *const *const IActiveScriptAuthor_Vtbl)).AddTypeLib)(this, rguidtypelib, dwmajor, dwminor, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTypeLib(this: *mut core::ffi::c_void, rguidtypelib: *const windows_core::GUID, dwmajor: u32, dwminor: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptAuthor", "RemoveTypeLib", 13usize, |this| ((**(this as *const *const IActiveScriptAuthor_Vtbl)).RemoveTypeLib)(this, rguidtypelib, dwmajor, dwminor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChars(this: *mut core::ffi::c_void, frequestedlist: u32, pbstrchars: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptAuthor", "GetChars", 14usize, |this| ((**(this as *const *const IActiveScriptAuthor_Vtbl)).GetChars)(this, frequestedlist, pbstrchars), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInfoFromContext(this: *mut core::ffi::c_void, pszcode: windows_core::PCWSTR, cchcode: u32, ichcurrentposition: u32, dwlisttypesrequested: u32, pdwlisttypesprovided: *mut u32, pichlistanchorposition: *mut u32, pichfuncanchorposition: *mut u32, pmemid: *mut i32, picurrentparameter: *mut i32, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptAuthor", "GetInfoFromContext", 15usize, |this| ((**(this as *const *const IActiveScriptAuthor_Vtbl)).GetInfoFromContext)(this, pszcode, cchcode, ichcurrentposition, dwlisttypesrequested, pdwlisttypesprovided, pichlistanchorposition, pichfuncanchorposition, pmemid, picurrentparameter, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCommitChar(this: *mut core::ffi::c_void, ch: u16, pfcommit: *mut super::super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptAuthor", "IsCommitChar", 16usize, |this| ((**(this as *const *const IActiveScriptAuthor_Vtbl)).IsCommitChar)(this, ch, pfcommit), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            AddNamedItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddNamedItem: 0,
+            AddScriptlet,
+            ParseScriptText,
+            GetScriptTextAttributes,
+            GetScriptletTextAttributes,
+            GetRoot,
+            GetLanguageFlags,
+            #[cfg(feature = "Win32_System_Com")]
+            GetEventHandler,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetEventHandler: 0,
+            RemoveNamedItem,
+            AddTypeLib,
+            RemoveTypeLib,
+            GetChars,
+            GetInfoFromContext,
+            IsCommitChar,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptAuthor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IActiveScriptAuthorProcedure_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ParseProcedureText(this: *mut core::ffi::c_void, pszcode: windows_core::PCWSTR, pszformalparams: windows_core::PCWSTR, pszprocedurename: windows_core::PCWSTR, pszitemname: windows_core::PCWSTR, pszdelimiter: windows_core::PCWSTR, dwcookie: u32, dwflags: u32, pdispfor: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptAuthorProcedure", "ParseProcedureText", 3usize, |this| ((**(this as *const *const IActiveScriptAuthorProcedure_Vtbl)).ParseProcedureText)(this, pszcode, pszformalparams, pszprocedurename, pszitemname, pszdelimiter, dwcookie, dwflags, pdispfor), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            ParseProcedureText,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ParseProcedureText: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptAuthorProcedure as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveScriptDebug32_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetScriptTextAttributes(this: *mut core::ffi::c_void, pstrcode: windows_core::PCWSTR, unumcodechars: u32, pstrdelimiter: windows_core::PCWSTR, dwflags: u32, pattr: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptDebug32", "GetScriptTextAttributes", 3usize, |this| ((**(this as *const *const IActiveScriptDebug32_Vtbl)).GetScriptTextAttributes)(this, pstrcode, unumcodechars, pstrdelimiter, dwflags, pattr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScriptletTextAttributes(this: *mut core::ffi::c_void, pstrcode: windows_core::PCWSTR, unumcodechars: u32, pstrdelimiter: windows_core::PCWSTR, dwflags: u32, pattr: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptDebug32", "GetScriptletTextAttributes", 4usize, |this| ((**(this as *const *const IActiveScriptDebug32_Vtbl)).GetScriptletTextAttributes)(this, pstrcode, unumcodechars, pstrdelimiter, dwflags, pattr), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumCodeContextsOfPosition(this: *mut core::ffi::c_void, dwsourcecontext: u32, ucharacteroffset: u32, unumchars: u32, ppescc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptDebug32", "EnumCodeContextsOfPosition", 5usize, |this| ((**(this as *const *const IActiveScriptDebug32_Vtbl)).EnumCodeContextsOfPosition)(this, dwsourcecontext, ucharacteroffset, unumchars, ppescc), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetScriptTextAttributes,
+            GetScriptletTextAttributes,
+            EnumCodeContextsOfPosition,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptDebug32 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveScriptDebug64_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetScriptTextAttributes(this: *mut core::ffi::c_void, pstrcode: windows_core::PCWSTR, unumcodechars: u32, pstrdelimiter: windows_core::PCWSTR, dwflags: u32, pattr: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptDebug64", "GetScriptTextAttributes", 3usize, |this| ((**(this as *const *const IActiveScriptDebug64_Vtbl)).GetScriptTextAttributes)(this, pstrcode, unumcodechars, pstrdelimiter, dwflags, pattr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScriptletTextAttributes(this: *mut core::ffi::c_void, pstrcode: windows_core::PCWSTR, unumcodechars: u32, pstrdelimiter: windows_core::PCWSTR, dwflags: u32, pattr: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptDebug64", "GetScriptletTextAttributes", 4usize, |this| ((**(this as *const *const IActiveScriptDebug64_Vtbl)).GetScriptletTextAttributes)(this, pstrcode, unumcodechars, pstrdelimiter, dwflags, pattr), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumCodeContextsOfPosition(this: *mut core::ffi::c_void, dwsourcecontext: u64, ucharacteroffset: u32, unumchars: u32, ppescc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptDebug64", "EnumCodeContextsOfPosition", 5usize, |this| ((**(this as *const *const IActiveScriptDebug64_Vtbl)).EnumCodeContextsOfPosition)(this, dwsourcecontext, ucharacteroffset, unumchars, ppescc), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetScriptTextAttributes,
+            GetScriptletTextAttributes,
+            EnumCodeContextsOfPosition,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptDebug64 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveScriptEncode_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EncodeSection(this: *mut core::ffi::c_void, pchin: windows_core::PCWSTR, cchin: u32, pchout: windows_core::PWSTR, cchout: u32, pcchret: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptEncode", "EncodeSection", 3usize, |this| ((**(this as *const *const IActiveScriptEncode_Vtbl)).EncodeSection)(this, pchin, cchin, pchout, cchout, pcchret), |result| Some(*result))
+        }
+        unsafe extern "system" fn DecodeScript(this: *mut core::ffi::c_void, pchin: windows_core::PCWSTR, cchin: u32, pchout: windows_core::PWSTR, cchout: u32, pcchret: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptEncode", "DecodeScript", 4usize, |this| ((**(this as *const *const IActiveScriptEncode_Vtbl)).DecodeScript)(this, pchin, cchin, pchout, cchout, pcchret), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEncodeProgId(this: *mut core::ffi::c_void, pbstrout: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptEncode", "GetEncodeProgId", 5usize, |this| ((**(this as *const *const IActiveScriptEncode_Vtbl)).GetEncodeProgId)(this, pbstrout), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EncodeSection, DecodeScript, GetEncodeProgId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptEncode as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IActiveScriptError_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetExceptionInfo(this: *mut core::ffi::c_void, pexcepinfo: *mut super::super::super::Com::EXCEPINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptError", "GetExceptionInfo", 3usize, |this| ((**(this as *const *const IActiveScriptError_Vtbl)).GetExceptionInfo)(this, pexcepinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourcePosition(this: *mut core::ffi::c_void, pdwsourcecontext: *mut u32, pullinenumber: *mut u32, plcharacterposition: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptError", "GetSourcePosition", 4usize, |this| ((**(this as *const *const IActiveScriptError_Vtbl)).GetSourcePosition)(this, pdwsourcecontext, pullinenumber, plcharacterposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourceLineText(this: *mut core::ffi::c_void, pbstrsourceline: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptError", "GetSourceLineText", 5usize, |this| ((**(this as *const *const IActiveScriptError_Vtbl)).GetSourceLineText)(this, pbstrsourceline), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetExceptionInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetExceptionInfo: 0,
+            GetSourcePosition,
+            GetSourceLineText,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptError as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IActiveScriptError64_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSourcePosition64(this: *mut core::ffi::c_void, pdwsourcecontext: *mut u64, pullinenumber: *mut u32, plcharacterposition: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptError64", "GetSourcePosition64", 6usize, |this| ((**(this as *const *const IActiveScriptError64_Vtbl)).GetSourcePosition64)(this, pdwsourcecontext, pullinenumber, plcharacterposition), |result| Some(*result))
+        }
+        Self { base__: <IActiveScriptError_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSourcePosition64 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptError64 as windows_core::Interface>::IID || <IActiveScriptError_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IActiveScriptErrorDebug_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDocumentContext(this: *mut core::ffi::c_void, ppssc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptErrorDebug", "GetDocumentContext", 6usize, |this| ((**(this as *const *const IActiveScriptErrorDebug_Vtbl)).GetDocumentContext)(this, ppssc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStackFrame(this: *mut core::ffi::c_void, ppdsf: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptErrorDebug", "GetStackFrame", 7usize, |this| ((**(this as *const *const IActiveScriptErrorDebug_Vtbl)).GetStackFrame)(this, ppdsf), |result| Some(*result))
+        }
+        Self { base__: <IActiveScriptError_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDocumentContext, GetStackFrame }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptErrorDebug as windows_core::Interface>::IID || <IActiveScriptError_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveScriptErrorDebug110_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetExceptionThrownKind(this: *mut core::ffi::c_void, pexceptionkind: *mut SCRIPT_ERROR_DEBUG_EXCEPTION_THROWN_KIND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptErrorDebug110", "GetExceptionThrownKind", 3usize, |this| ((**(this as *const *const IActiveScriptErrorDebug110_Vtbl)).GetExceptionThrownKind)(this, pexceptionkind), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetExceptionThrownKind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptErrorDebug110 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveScriptGarbageCollector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CollectGarbage(this: *mut core::ffi::c_void, scriptgctype: SCRIPTGCTYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptGarbageCollector", "CollectGarbage", 3usize, |this| ((**(this as *const *const IActiveScriptGarbageCollector_Vtbl)).CollectGarbage)(this, scriptgctype), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CollectGarbage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptGarbageCollector as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveScriptHostEncode_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EncodeScriptHostFile(this: *mut core::ffi::c_void, bstrinfile: core::mem::MaybeUninit<windows_core::BSTR>, pbstroutfile: *mut core::mem::MaybeUninit<windows_core::BSTR>, cflags: u32, bstrdefaultlang: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptHostEncode", "EncodeScriptHostFile", 3usize, |this| ((**(this as *const *const IActiveScriptHostEncode_Vtbl)).EncodeScriptHostFile)(this, bstrinfile, pbstroutfile, cflags, bstrdefaultlang), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EncodeScriptHostFile }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptHostEncode as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IActiveScriptParse32_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitNew(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptParse32", "InitNew", 3usize, |this| ((**(this as *const *const IActiveScriptParse32_Vtbl)).InitNew)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddScriptlet(this: *mut core::ffi::c_void, pstrdefaultname: windows_core::PCWSTR, pstrcode: windows_core::PCWSTR, pstritemname: windows_core::PCWSTR, pstrsubitemname: windows_core::PCWSTR, pstreventname: windows_core::PCWSTR, pstrdelimiter: windows_core::PCWSTR, dwsourcecontextcookie: u32, ulstartinglinenumber: u32, dwflags: u32, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>, pexcepinfo: *mut super::super::super::Com::EXCEPINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptParse32", "AddScriptlet", 4usize, |this| ((**(this as *const *const IActiveScriptParse32_Vtbl)).AddScriptlet)(this, pstrdefaultname, pstrcode, pstritemname, pstrsubitemname, pstreventname, pstrdelimiter, dwsourcecontextcookie, ulstartinglinenumber, dwflags, pbstrname, pexcepinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ParseScriptText(this: *mut core::ffi::c_void, pstrcode: windows_core::PCWSTR, pstritemname: windows_core::PCWSTR, punkcontext: *mut core::ffi::c_void, pstrdelimiter: windows_core::PCWSTR, dwsourcecontextcookie: u32, ulstartinglinenumber: u32, dwflags: u32, pvarresult: *mut core::mem::MaybeUninit<windows_core::VARIANT>, pexcepinfo: *mut super::super::super::Com::EXCEPINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptParse32", "ParseScriptText", 5usize, |this| ((**(this as *const *const IActiveScriptParse32_Vtbl)).ParseScriptText)(this, pstrcode, pstritemname, punkcontext, pstrdelimiter, dwsourcecontextcookie, ulstartinglinenumber, dwflags, pvarresult, pexcepinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitNew,
+            #[cfg(feature = "Win32_System_Com")]
+            AddScriptlet,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddScriptlet: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ParseScriptText,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ParseScriptText: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptParse32 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IActiveScriptParse64_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitNew(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptParse64", "InitNew", 3usize, |this| ((**(this as *const *const IActiveScriptParse64_Vtbl)).InitNew)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddScriptlet(this: *mut core::ffi::c_void, pstrdefaultname: windows_core::PCWSTR, pstrcode: windows_core::PCWSTR, pstritemname: windows_core::PCWSTR, pstrsubitemname: windows_core::PCWSTR, pstreventname: windows_core::PCWSTR, pstrdelimiter: windows_core::PCWSTR, dwsourcecontextcookie: u64, ulstartinglinenumber: u32, dwflags: u32, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>, pexcepinfo: *mut super::super::super::Com::EXCEPINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptParse64", "AddScriptlet", 4usize, |this| ((**(this as *const *const IActiveScriptParse64_Vtbl)).AddScriptlet)(this, pstrdefaultname, pstrcode, pstritemname, pstrsubitemname, pstreventname, pstrdelimiter, dwsourcecontextcookie, ulstartinglinenumber, dwflags, pbstrname, pexcepinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ParseScriptText(this: *mut core::ffi::c_void, pstrcode: windows_core::PCWSTR, pstritemname: windows_core::PCWSTR, punkcontext: *mut core::ffi::c_void, pstrdelimiter: windows_core::PCWSTR, dwsourcecontextcookie: u64, ulstartinglinenumber: u32, dwflags: u32, pvarresult: *mut core::mem::MaybeUninit<windows_core::VARIANT>, pexcepinfo: *mut super::super::super::Com::EXCEPINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptParse64", "ParseScriptText", 5usize, |this| ((**(this as *const *const IActiveScriptParse64_Vtbl)).ParseScriptText)(this, pstrcode, pstritemname, punkcontext, pstrdelimiter, dwsourcecontextcookie, ulstartinglinenumber, dwflags, pvarresult, pexcepinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitNew,
+            #[cfg(feature = "Win32_System_Com")]
+            AddScriptlet,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddScriptlet: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ParseScriptText,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ParseScriptText: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptParse64 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IActiveScriptParseProcedure2_32_Vtbl {
+    const PROXY: Self = { Self { base__: <IActiveScriptParseProcedure32_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptParseProcedure2_32 as windows_core::Interface>::IID || <IActiveScriptParseProcedure32_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IActiveScriptParseProcedure2_64_Vtbl {
+    const PROXY: Self = { Self { base__: <IActiveScriptParseProcedure64_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptParseProcedure2_64 as windows_core::Interface>::IID || <IActiveScriptParseProcedure64_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IActiveScriptParseProcedure32_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ParseProcedureText(this: *mut core::ffi::c_void, pstrcode: windows_core::PCWSTR, pstrformalparams: windows_core::PCWSTR, pstrprocedurename: windows_core::PCWSTR, pstritemname: windows_core::PCWSTR, punkcontext: *mut core::ffi::c_void, pstrdelimiter: windows_core::PCWSTR, dwsourcecontextcookie: u32, ulstartinglinenumber: u32, dwflags: u32, ppdisp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptParseProcedure32", "ParseProcedureText", 3usize, |this| ((**(this as *const *const IActiveScriptParseProcedure32_Vtbl)).ParseProcedureText)(this, pstrcode, pstrformalparams, pstrprocedurename, pstritemname, punkcontext, pstrdelimiter, dwsourcecontextcookie, ulstartinglinenumber, dwflags, ppdisp), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            ParseProcedureText,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ParseProcedureText: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptParseProcedure32 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IActiveScriptParseProcedure64_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ParseProcedureText(this: *mut core::ffi::c_void, pstrcode: windows_core::PCWSTR, pstrformalparams: windows_core::PCWSTR, pstrprocedurename: windows_core::PCWSTR, pstritemname: windows_core::PCWSTR, punkcontext: *mut core::ffi::c_void, pstrdelimiter: windows_core::PCWSTR, dwsourcecontextcookie: u64, ulstartinglinenumber: u32, dwflags: u32, ppdisp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptParseProcedure64", "ParseProcedureText", 3usize, |this| ((**(this as *const *const IActiveScriptParseProcedure64_Vtbl)).ParseProcedureText)(this, pstrcode, pstrformalparams, pstrprocedurename, pstritemname, punkcontext, pstrdelimiter, dwsourcecontextcookie, ulstartinglinenumber, dwflags, ppdisp), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            ParseProcedureText,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ParseProcedureText: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptParseProcedure64 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IActiveScriptParseProcedureOld32_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ParseProcedureText(this: *mut core::ffi::c_void, pstrcode: windows_core::PCWSTR, pstrformalparams: windows_core::PCWSTR, pstritemname: windows_core::PCWSTR, punkcontext: *mut core::ffi::c_void, pstrdelimiter: windows_core::PCWSTR, dwsourcecontextcookie: u32, ulstartinglinenumber: u32, dwflags: u32, ppdisp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptParseProcedureOld32", "ParseProcedureText", 3usize, |this| ((**(this as *const *const IActiveScriptParseProcedureOld32_Vtbl)).ParseProcedureText)(this, pstrcode, pstrformalparams, pstritemname, punkcontext, pstrdelimiter, dwsourcecontextcookie, ulstartinglinenumber, dwflags, ppdisp), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            ParseProcedureText,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ParseProcedureText: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptParseProcedureOld32 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IActiveScriptParseProcedureOld64_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ParseProcedureText(this: *mut core::ffi::c_void, pstrcode: windows_core::PCWSTR, pstrformalparams: windows_core::PCWSTR, pstritemname: windows_core::PCWSTR, punkcontext: *mut core::ffi::c_void, pstrdelimiter: windows_core::PCWSTR, dwsourcecontextcookie: u64, ulstartinglinenumber: u32, dwflags: u32, ppdisp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptParseProcedureOld64", "ParseProcedureText", 3usize, |this| ((**(this as *const *const IActiveScriptParseProcedureOld64_Vtbl)).ParseProcedureText)(this, pstrcode, pstrformalparams, pstritemname, punkcontext, pstrdelimiter, dwsourcecontextcookie, ulstartinglinenumber, dwflags, ppdisp), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            ParseProcedureText,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ParseProcedureText: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptParseProcedureOld64 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveScriptProfilerCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, dwcontext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptProfilerCallback", "Initialize", 3usize, |this| ((**(this as *const *const IActiveScriptProfilerCallback_Vtbl)).Initialize)(this, dwcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void, hrreason: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptProfilerCallback", "Shutdown", 4usize, |this| ((**(this as *const *const IActiveScriptProfilerCallback_Vtbl)).Shutdown)(this, hrreason), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScriptCompiled(this: *mut core::ffi::c_void, scriptid: i32, r#type: PROFILER_SCRIPT_TYPE, pidebugdocumentcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptProfilerCallback", "ScriptCompiled", 5usize, |this| ((**(this as *const *const IActiveScriptProfilerCallback_Vtbl)).ScriptCompiled)(this, scriptid, r#type, pidebugdocumentcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn FunctionCompiled(this: *mut core::ffi::c_void, functionid: i32, scriptid: i32, pwszfunctionname: windows_core::PCWSTR, pwszfunctionnamehint: windows_core::PCWSTR, pidebugdocumentcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptProfilerCallback", "FunctionCompiled", 6usize, |this| ((**(this as *const *const IActiveScriptProfilerCallback_Vtbl)).FunctionCompiled)(this, functionid, scriptid, pwszfunctionname, pwszfunctionnamehint, pidebugdocumentcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnFunctionEnter(this: *mut core::ffi::c_void, scriptid: i32, functionid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptProfilerCallback", "OnFunctionEnter", 7usize, |this| ((**(this as *const *const IActiveScriptProfilerCallback_Vtbl)).OnFunctionEnter)(this, scriptid, functionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnFunctionExit(this: *mut core::ffi::c_void, scriptid: i32, functionid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptProfilerCallback", "OnFunctionExit", 8usize, |this| ((**(this as *const *const IActiveScriptProfilerCallback_Vtbl)).OnFunctionExit)(this, scriptid, functionid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            Shutdown,
+            ScriptCompiled,
+            FunctionCompiled,
+            OnFunctionEnter,
+            OnFunctionExit,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptProfilerCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveScriptProfilerCallback2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnFunctionEnterByName(this: *mut core::ffi::c_void, pwszfunctionname: windows_core::PCWSTR, r#type: PROFILER_SCRIPT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptProfilerCallback2", "OnFunctionEnterByName", 9usize, |this| ((**(this as *const *const IActiveScriptProfilerCallback2_Vtbl)).OnFunctionEnterByName)(this, pwszfunctionname, r#type), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnFunctionExitByName(this: *mut core::ffi::c_void, pwszfunctionname: windows_core::PCWSTR, r#type: PROFILER_SCRIPT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptProfilerCallback2", "OnFunctionExitByName", 10usize, |this| ((**(this as *const *const IActiveScriptProfilerCallback2_Vtbl)).OnFunctionExitByName)(this, pwszfunctionname, r#type), |result| Some(*result))
+        }
+        Self { base__: <IActiveScriptProfilerCallback_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnFunctionEnterByName, OnFunctionExitByName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptProfilerCallback2 as windows_core::Interface>::IID || <IActiveScriptProfilerCallback_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveScriptProfilerCallback3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetWebWorkerId(this: *mut core::ffi::c_void, webworkerid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptProfilerCallback3", "SetWebWorkerId", 11usize, |this| ((**(this as *const *const IActiveScriptProfilerCallback3_Vtbl)).SetWebWorkerId)(this, webworkerid), |result| Some(*result))
+        }
+        Self { base__: <IActiveScriptProfilerCallback2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetWebWorkerId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptProfilerCallback3 as windows_core::Interface>::IID || <IActiveScriptProfilerCallback2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveScriptProfilerControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartProfiling(this: *mut core::ffi::c_void, clsidprofilerobject: *const windows_core::GUID, dweventmask: u32, dwcontext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptProfilerControl", "StartProfiling", 3usize, |this| ((**(this as *const *const IActiveScriptProfilerControl_Vtbl)).StartProfiling)(this, clsidprofilerobject, dweventmask, dwcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProfilerEventMask(this: *mut core::ffi::c_void, dweventmask: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptProfilerControl", "SetProfilerEventMask", 4usize, |this| ((**(this as *const *const IActiveScriptProfilerControl_Vtbl)).SetProfilerEventMask)(this, dweventmask), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopProfiling(this: *mut core::ffi::c_void, hrshutdownreason: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptProfilerControl", "StopProfiling", 5usize, |this| ((**(this as *const *const IActiveScriptProfilerControl_Vtbl)).StopProfiling)(this, hrshutdownreason), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartProfiling, SetProfilerEventMask, StopProfiling }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptProfilerControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveScriptProfilerControl2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CompleteProfilerStart(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptProfilerControl2", "CompleteProfilerStart", 6usize, |this| ((**(this as *const *const IActiveScriptProfilerControl2_Vtbl)).CompleteProfilerStart)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrepareProfilerStop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptProfilerControl2", "PrepareProfilerStop", 7usize, |this| ((**(this as *const *const IActiveScriptProfilerControl2_Vtbl)).PrepareProfilerStop)(this), |result| Some(*result))
+        }
+        Self { base__: <IActiveScriptProfilerControl_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CompleteProfilerStart, PrepareProfilerStop }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptProfilerControl2 as windows_core::Interface>::IID || <IActiveScriptProfilerControl_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveScriptProfilerControl3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumHeap(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptProfilerControl3", "EnumHeap", 8usize, |this| ((**(this as *const *const IActiveScriptProfilerControl3_Vtbl)).EnumHeap)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <IActiveScriptProfilerControl2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnumHeap }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptProfilerControl3 as windows_core::Interface>::IID || <IActiveScriptProfilerControl2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveScriptProfilerControl4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SummarizeHeap(this: *mut core::ffi::c_void, heapsummary: *mut PROFILER_HEAP_SUMMARY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptProfilerControl4", "SummarizeHeap", 9usize, |this| ((**(this as *const *const IActiveScriptProfilerControl4_Vtbl)).SummarizeHeap)(this, heapsummary), |result| Some(*result))
+        }
+        Self { base__: <IActiveScriptProfilerControl3_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SummarizeHeap }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptProfilerControl4 as windows_core::Interface>::IID || <IActiveScriptProfilerControl3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveScriptProfilerControl5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumHeap2(this: *mut core::ffi::c_void, enumflags: PROFILER_HEAP_ENUM_FLAGS, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptProfilerControl5", "EnumHeap2", 10usize, |this| ((**(this as *const *const IActiveScriptProfilerControl5_Vtbl)).EnumHeap2)(this, enumflags, ppenum), |result| Some(*result))
+        }
+        Self { base__: <IActiveScriptProfilerControl4_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnumHeap2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptProfilerControl5 as windows_core::Interface>::IID || <IActiveScriptProfilerControl4_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveScriptProfilerHeapEnum_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, heapobjects: *mut *mut PROFILER_HEAP_OBJECT, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptProfilerHeapEnum", "Next", 3usize, |this| ((**(this as *const *const IActiveScriptProfilerHeapEnum_Vtbl)).Next)(this, celt, heapobjects, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOptionalInfo(this: *mut core::ffi::c_void, heapobject: *const PROFILER_HEAP_OBJECT, celt: u32, optionalinfo: *mut PROFILER_HEAP_OBJECT_OPTIONAL_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptProfilerHeapEnum", "GetOptionalInfo", 4usize, |this| ((**(this as *const *const IActiveScriptProfilerHeapEnum_Vtbl)).GetOptionalInfo)(this, heapobject, celt, optionalinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreeObjectAndOptionalInfo(this: *mut core::ffi::c_void, celt: u32, heapobjects: *const *const PROFILER_HEAP_OBJECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptProfilerHeapEnum", "FreeObjectAndOptionalInfo", 5usize, |this| ((**(this as *const *const IActiveScriptProfilerHeapEnum_Vtbl)).FreeObjectAndOptionalInfo)(this, celt, heapobjects), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNameIdMap(this: *mut core::ffi::c_void, pnamelist: *mut *mut *mut windows_core::PCWSTR, pcelt: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptProfilerHeapEnum", "GetNameIdMap", 6usize, |this| ((**(this as *const *const IActiveScriptProfilerHeapEnum_Vtbl)).GetNameIdMap)(this, pnamelist, pcelt), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, GetOptionalInfo, FreeObjectAndOptionalInfo, GetNameIdMap }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptProfilerHeapEnum as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveScriptProperty_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, dwproperty: u32, pvarindex: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvarvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptProperty", "GetProperty", 3usize, |this| ((**(this as *const *const IActiveScriptProperty_Vtbl)).GetProperty)(this, dwproperty, pvarindex, pvarvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, dwproperty: u32, pvarindex: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvarvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptProperty", "SetProperty", 4usize, |this| ((**(this as *const *const IActiveScriptProperty_Vtbl)).SetProperty)(this, dwproperty, pvarindex, pvarvalue), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProperty, SetProperty }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptProperty as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveScriptSIPInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSIPOID(this: *mut core::ffi::c_void, poid_sip: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptSIPInfo", "GetSIPOID", 3usize, |this| ((**(this as *const *const IActiveScriptSIPInfo_Vtbl)).GetSIPOID)(this, poid_sip), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSIPOID }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptSIPInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IActiveScriptSite_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLCID(this: *mut core::ffi::c_void, plcid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptSite", "GetLCID", 3usize, |this| ((**(this as *const *const IActiveScriptSite_Vtbl)).GetLCID)(this, plcid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetItemInfo(this: *mut core::ffi::c_void, pstrname: windows_core::PCWSTR, dwreturnmask: u32, ppiunkitem: *mut *mut core::ffi::c_void, ppti: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptSite", "GetItemInfo", 4usize, |this| ((**(this as *const *const IActiveScriptSite_Vtbl)).GetItemInfo)(this, pstrname, dwreturnmask, ppiunkitem, ppti), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDocVersionString(this: *mut core::ffi::c_void, pbstrversion: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptSite", "GetDocVersionString", 5usize, |this| ((**(this as *const *const IActiveScriptSite_Vtbl)).GetDocVersionString)(this, pbstrversion), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnScriptTerminate(this: *mut core::ffi::c_void, pvarresult: *const core::mem::MaybeUninit<windows_core::VARIANT>, pexcepinfo: *const super::super::super::Com::EXCEPINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptSite", "OnScriptTerminate", 6usize, |this| ((**(this as *const *const IActiveScriptSite_Vtbl)).OnScriptTerminate)(this, pvarresult, pexcepinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnStateChange(this: *mut core::ffi::c_void, ssscriptstate: SCRIPTSTATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptSite", "OnStateChange", 7usize, |this| ((**(this as *const *const IActiveScriptSite_Vtbl)).OnStateChange)(this, ssscriptstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnScriptError(this: *mut core::ffi::c_void, pscripterror: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptSite", "OnScriptError", 8usize, |this| ((**(this as *const *const IActiveScriptSite_Vtbl)).OnScriptError)(this, pscripterror), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnEnterScript(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptSite", "OnEnterScript", 9usize, |this| ((**(this as *const *const IActiveScriptSite_Vtbl)).OnEnterScript)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnLeaveScript(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptSite", "OnLeaveScript", 10usize, |this| ((**(this as *const *const IActiveScriptSite_Vtbl)).OnLeaveScript)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetLCID,
+            #[cfg(feature = "Win32_System_Com")]
+            GetItemInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetItemInfo: 0,
+            GetDocVersionString,
+            #[cfg(feature = "Win32_System_Com")]
+            OnScriptTerminate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnScriptTerminate: 0,
+            OnStateChange,
+            OnScriptError,
+            OnEnterScript,
+            OnLeaveScript,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptSite as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveScriptSiteDebug32_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDocumentContextFromPosition(this: *mut core::ffi::c_void, dwsourcecontext: u32, ucharacteroffset: u32, unumchars: u32, ppsc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptSiteDebug32", "GetDocumentContextFromPosition", 3usize, |this| ((**(this as *const *const IActiveScriptSiteDebug32_Vtbl)).GetDocumentContextFromPosition)(this, dwsourcecontext, ucharacteroffset, unumchars, ppsc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetApplication(this: *mut core::ffi::c_void, ppda: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptSiteDebug32", "GetApplication", 4usize, |this| ((**(this as *const *const IActiveScriptSiteDebug32_Vtbl)).GetApplication)(this, ppda), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRootApplicationNode(this: *mut core::ffi::c_void, ppdanroot: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptSiteDebug32", "GetRootApplicationNode", 5usize, |this| ((**(this as *const *const IActiveScriptSiteDebug32_Vtbl)).GetRootApplicationNode)(this, ppdanroot), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnScriptErrorDebug(this: *mut core::ffi::c_void, perrordebug: *mut core::ffi::c_void, pfenterdebugger: *mut super::super::super::super::Foundation::BOOL, pfcallonscripterrorwhencontinuing: *mut super::super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptSiteDebug32", "OnScriptErrorDebug", 6usize, |this| ((**(this as *const *const IActiveScriptSiteDebug32_Vtbl)).OnScriptErrorDebug)(this, perrordebug, pfenterdebugger, pfcallonscripterrorwhencontinuing), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDocumentContextFromPosition,
+            GetApplication,
+            GetRootApplicationNode,
+            OnScriptErrorDebug,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptSiteDebug32 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveScriptSiteDebug64_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDocumentContextFromPosition(this: *mut core::ffi::c_void, dwsourcecontext: u64, ucharacteroffset: u32, unumchars: u32, ppsc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptSiteDebug64", "GetDocumentContextFromPosition", 3usize, |this| ((**(this as *const *const IActiveScriptSiteDebug64_Vtbl)).GetDocumentContextFromPosition)(this, dwsourcecontext, ucharacteroffset, unumchars, ppsc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetApplication(this: *mut core::ffi::c_void, ppda: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptSiteDebug64", "GetApplication", 4usize, |this| ((**(this as *const *const IActiveScriptSiteDebug64_Vtbl)).GetApplication)(this, ppda), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRootApplicationNode(this: *mut core::ffi::c_void, ppdanroot: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptSiteDebug64", "GetRootApplicationNode", 5usize, |this| ((**(this as *const *const IActiveScriptSiteDebug64_Vtbl)).GetRootApplicationNode)(this, ppdanroot), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnScriptErrorDebug(this: *mut core::ffi::c_void, perrordebug: *mut core::ffi::c_void, pfenterdebugger: *mut super::super::super::super::Foundation::BOOL, pfcallonscripterrorwhencontinuing: *mut super::super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptSiteDebug64", "OnScriptErrorDebug", 6usize, |this| ((**(this as *const *const IActiveScriptSiteDebug64_Vtbl)).OnScriptErrorDebug)(this, perrordebug, pfenterdebugger, pfcallonscripterrorwhencontinuing), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDocumentContextFromPosition,
+            GetApplication,
+            GetRootApplicationNode,
+            OnScriptErrorDebug,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptSiteDebug64 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveScriptSiteDebugEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnCanNotJITScriptErrorDebug(this: *mut core::ffi::c_void, perrordebug: *mut core::ffi::c_void, pfcallonscripterrorwhencontinuing: *mut super::super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptSiteDebugEx", "OnCanNotJITScriptErrorDebug", 3usize, |this| ((**(this as *const *const IActiveScriptSiteDebugEx_Vtbl)).OnCanNotJITScriptErrorDebug)(this, perrordebug, pfcallonscripterrorwhencontinuing), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnCanNotJITScriptErrorDebug }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptSiteDebugEx as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveScriptSiteInterruptPoll_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryContinue(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptSiteInterruptPoll", "QueryContinue", 3usize, |this| ((**(this as *const *const IActiveScriptSiteInterruptPoll_Vtbl)).QueryContinue)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryContinue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptSiteInterruptPoll as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveScriptSiteTraceInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SendScriptTraceInfo(this: *mut core::ffi::c_void, stieventtype: SCRIPTTRACEINFO, guidcontextid: windows_core::GUID, dwscriptcontextcookie: u32, lscriptstatementstart: i32, lscriptstatementend: i32, dwreserved: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptSiteTraceInfo", "SendScriptTraceInfo", 3usize, |this| ((**(this as *const *const IActiveScriptSiteTraceInfo_Vtbl)).SendScriptTraceInfo)(this, stieventtype, guidcontextid, dwscriptcontextcookie, lscriptstatementstart, lscriptstatementend, dwreserved), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SendScriptTraceInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptSiteTraceInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveScriptSiteUIControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetUIBehavior(this: *mut core::ffi::c_void, uicitem: SCRIPTUICITEM, puichandling: *mut SCRIPTUICHANDLING) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptSiteUIControl", "GetUIBehavior", 3usize, |this| ((**(this as *const *const IActiveScriptSiteUIControl_Vtbl)).GetUIBehavior)(this, uicitem, puichandling), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetUIBehavior }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptSiteUIControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveScriptSiteWindow_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetWindow(this: *mut core::ffi::c_void, phwnd: *mut super::super::super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptSiteWindow", "GetWindow", 3usize, |this| ((**(this as *const *const IActiveScriptSiteWindow_Vtbl)).GetWindow)(this, phwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableModeless(this: *mut core::ffi::c_void, fenable: super::super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptSiteWindow", "EnableModeless", 4usize, |this| ((**(this as *const *const IActiveScriptSiteWindow_Vtbl)).EnableModeless)(this, fenable), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetWindow, EnableModeless }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptSiteWindow as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveScriptStats_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStat(this: *mut core::ffi::c_void, stid: u32, pluhi: *mut u32, plulo: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptStats", "GetStat", 3usize, |this| ((**(this as *const *const IActiveScriptStats_Vtbl)).GetStat)(this, stid, pluhi, plulo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatEx(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pluhi: *mut u32, plulo: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptStats", "GetStatEx", 4usize, |this| ((**(this as *const *const IActiveScriptStats_Vtbl)).GetStatEx)(this, guid, pluhi, plulo), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetStats(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptStats", "ResetStats", 5usize, |this| ((**(this as *const *const IActiveScriptStats_Vtbl)).ResetStats)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStat, GetStatEx, ResetStats }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptStats as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveScriptStringCompare_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StrComp(this: *mut core::ffi::c_void, bszstr1: core::mem::MaybeUninit<windows_core::BSTR>, bszstr2: core::mem::MaybeUninit<windows_core::BSTR>, iret: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptStringCompare", "StrComp", 3usize, |this| ((**(this as *const *const IActiveScriptStringCompare_Vtbl)).StrComp)(this, bszstr1, bszstr2, iret), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StrComp }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptStringCompare as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveScriptTraceInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartScriptTracing(this: *mut core::ffi::c_void, psitetraceinfo: *mut core::ffi::c_void, guidcontextid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptTraceInfo", "StartScriptTracing", 3usize, |this| ((**(this as *const *const IActiveScriptTraceInfo_Vtbl)).StartScriptTracing)(this, psitetraceinfo, guidcontextid), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopScriptTracing(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptTraceInfo", "StopScriptTracing", 4usize, |this| ((**(this as *const *const IActiveScriptTraceInfo_Vtbl)).StopScriptTracing)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartScriptTracing, StopScriptTracing }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptTraceInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IActiveScriptWinRTErrorDebug_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRestrictedErrorString(this: *mut core::ffi::c_void, errorstring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptWinRTErrorDebug", "GetRestrictedErrorString", 6usize, |this| ((**(this as *const *const IActiveScriptWinRTErrorDebug_Vtbl)).GetRestrictedErrorString)(this, errorstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRestrictedErrorReference(this: *mut core::ffi::c_void, referencestring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptWinRTErrorDebug", "GetRestrictedErrorReference", 7usize, |this| ((**(this as *const *const IActiveScriptWinRTErrorDebug_Vtbl)).GetRestrictedErrorReference)(this, referencestring), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCapabilitySid(this: *mut core::ffi::c_void, capabilitysid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveScriptWinRTErrorDebug", "GetCapabilitySid", 8usize, |this| ((**(this as *const *const IActiveScriptWinRTErrorDebug_Vtbl)).GetCapabilitySid)(this, capabilitysid), |result| Some(*result))
+        }
+        Self {
+            base__: <IActiveScriptError_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetRestrictedErrorString,
+            GetRestrictedErrorReference,
+            GetCapabilitySid,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveScriptWinRTErrorDebug as windows_core::Interface>::IID || <IActiveScriptError_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApplicationDebugger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryAlive(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationDebugger", "QueryAlive", 3usize, |this| ((**(this as *const *const IApplicationDebugger_Vtbl)).QueryAlive)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInstanceAtDebugger(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, punkouter: *mut core::ffi::c_void, dwclscontext: u32, riid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationDebugger", "CreateInstanceAtDebugger", 4usize, |this| ((**(this as *const *const IApplicationDebugger_Vtbl)).CreateInstanceAtDebugger)(this, rclsid, punkouter, dwclscontext, riid, ppvobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn onDebugOutput(this: *mut core::ffi::c_void, pstr: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationDebugger", "onDebugOutput", 5usize, |this| ((**(this as *const *const IApplicationDebugger_Vtbl)).onDebugOutput)(this, pstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn onHandleBreakPoint(this: *mut core::ffi::c_void, prpt: *mut core::ffi::c_void, br: BREAKREASON, perror: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationDebugger", "onHandleBreakPoint", 6usize, |this| ((**(this as *const *const IApplicationDebugger_Vtbl)).onHandleBreakPoint)(this, prpt, br, perror), |result| Some(*result))
+        }
+        unsafe extern "system" fn onClose(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationDebugger", "onClose", 7usize, |this| ((**(this as *const *const IApplicationDebugger_Vtbl)).onClose)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn onDebuggerEvent(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationDebugger", "onDebuggerEvent", 8usize, |this| ((**(this as *const *const IApplicationDebugger_Vtbl)).onDebuggerEvent)(this, riid, punk), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            QueryAlive,
+            CreateInstanceAtDebugger,
+            onDebugOutput,
+            onHandleBreakPoint,
+            onClose,
+            onDebuggerEvent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationDebugger as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApplicationDebuggerUI_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BringDocumentToTop(this: *mut core::ffi::c_void, pddt: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationDebuggerUI", "BringDocumentToTop", 3usize, |this| ((**(this as *const *const IApplicationDebuggerUI_Vtbl)).BringDocumentToTop)(this, pddt), |result| Some(*result))
+        }
+        unsafe extern "system" fn BringDocumentContextToTop(this: *mut core::ffi::c_void, pddc: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApplicationDebuggerUI", "BringDocumentContextToTop", 4usize, |this| ((**(this as *const *const IApplicationDebuggerUI_Vtbl)).BringDocumentContextToTop)(this, pddc), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BringDocumentToTop, BringDocumentContextToTop }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApplicationDebuggerUI as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IBindEventHandler_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn BindHandler(this: *mut core::ffi::c_void, pstrevent: windows_core::PCWSTR, pdisp: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBindEventHandler", "BindHandler", 3usize, |this| ((**(this as *const *const IBindEventHandler_Vtbl)).BindHandler)(this, pstrevent, pdisp), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            BindHandler,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            BindHandler: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBindEventHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugApplication11032_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SynchronousCallInMainThread(this: *mut core::ffi::c_void, pptc: *mut core::ffi::c_void, dwparam1: usize, dwparam2: usize, dwparam3: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication11032", "SynchronousCallInMainThread", 6usize, |this| ((**(this as *const *const IDebugApplication11032_Vtbl)).SynchronousCallInMainThread)(this, pptc, dwparam1, dwparam2, dwparam3), |result| Some(*result))
+        }
+        unsafe extern "system" fn AsynchronousCallInMainThread(this: *mut core::ffi::c_void, pptc: *mut core::ffi::c_void, dwparam1: usize, dwparam2: usize, dwparam3: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication11032", "AsynchronousCallInMainThread", 7usize, |this| ((**(this as *const *const IDebugApplication11032_Vtbl)).AsynchronousCallInMainThread)(this, pptc, dwparam1, dwparam2, dwparam3), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallableWaitForHandles(this: *mut core::ffi::c_void, handlecount: u32, phandles: *const super::super::super::super::Foundation::HANDLE, pindex: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication11032", "CallableWaitForHandles", 8usize, |this| ((**(this as *const *const IDebugApplication11032_Vtbl)).CallableWaitForHandles)(this, handlecount, phandles, pindex), |result| Some(*result))
+        }
+        Self {
+            base__: <IRemoteDebugApplication110_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SynchronousCallInMainThread,
+            AsynchronousCallInMainThread,
+            CallableWaitForHandles,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugApplication11032 as windows_core::Interface>::IID || <IRemoteDebugApplication110_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugApplication11064_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SynchronousCallInMainThread(this: *mut core::ffi::c_void, pptc: *mut core::ffi::c_void, dwparam1: usize, dwparam2: usize, dwparam3: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication11064", "SynchronousCallInMainThread", 6usize, |this| ((**(this as *const *const IDebugApplication11064_Vtbl)).SynchronousCallInMainThread)(this, pptc, dwparam1, dwparam2, dwparam3), |result| Some(*result))
+        }
+        unsafe extern "system" fn AsynchronousCallInMainThread(this: *mut core::ffi::c_void, pptc: *mut core::ffi::c_void, dwparam1: usize, dwparam2: usize, dwparam3: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication11064", "AsynchronousCallInMainThread", 7usize, |this| ((**(this as *const *const IDebugApplication11064_Vtbl)).AsynchronousCallInMainThread)(this, pptc, dwparam1, dwparam2, dwparam3), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallableWaitForHandles(this: *mut core::ffi::c_void, handlecount: u32, phandles: *const super::super::super::super::Foundation::HANDLE, pindex: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication11064", "CallableWaitForHandles", 8usize, |this| ((**(this as *const *const IDebugApplication11064_Vtbl)).CallableWaitForHandles)(this, handlecount, phandles, pindex), |result| Some(*result))
+        }
+        Self {
+            base__: <IRemoteDebugApplication110_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SynchronousCallInMainThread,
+            AsynchronousCallInMainThread,
+            CallableWaitForHandles,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugApplication11064 as windows_core::Interface>::IID || <IRemoteDebugApplication110_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugApplication32_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, pstrname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication32", "SetName", 14usize, |this| ((**(this as *const *const IDebugApplication32_Vtbl)).SetName)(this, pstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn StepOutComplete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication32", "StepOutComplete", 15usize, |this| ((**(this as *const *const IDebugApplication32_Vtbl)).StepOutComplete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DebugOutput(this: *mut core::ffi::c_void, pstr: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication32", "DebugOutput", 16usize, |this| ((**(this as *const *const IDebugApplication32_Vtbl)).DebugOutput)(this, pstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartDebugSession(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication32", "StartDebugSession", 17usize, |this| ((**(this as *const *const IDebugApplication32_Vtbl)).StartDebugSession)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn HandleBreakPoint(this: *mut core::ffi::c_void, br: BREAKREASON, pbra: *mut BREAKRESUMEACTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication32", "HandleBreakPoint", 18usize, |this| ((**(this as *const *const IDebugApplication32_Vtbl)).HandleBreakPoint)(this, br, pbra), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication32", "Close", 19usize, |this| ((**(this as *const *const IDebugApplication32_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBreakFlags(this: *mut core::ffi::c_void, pabf: *mut u32, pprdatsteppingthread: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication32", "GetBreakFlags", 20usize, |this| ((**(this as *const *const IDebugApplication32_Vtbl)).GetBreakFlags)(this, pabf, pprdatsteppingthread), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentThread(this: *mut core::ffi::c_void, pat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication32", "GetCurrentThread", 21usize, |this| ((**(this as *const *const IDebugApplication32_Vtbl)).GetCurrentThread)(this, pat), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAsyncDebugOperation(this: *mut core::ffi::c_void, psdo: *mut core::ffi::c_void, ppado: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication32", "CreateAsyncDebugOperation", 22usize, |this| ((**(this as *const *const IDebugApplication32_Vtbl)).CreateAsyncDebugOperation)(this, psdo, ppado), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddStackFrameSniffer(this: *mut core::ffi::c_void, pdsfs: *mut core::ffi::c_void, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication32", "AddStackFrameSniffer", 23usize, |this| ((**(this as *const *const IDebugApplication32_Vtbl)).AddStackFrameSniffer)(this, pdsfs, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStackFrameSniffer(this: *mut core::ffi::c_void, dwcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication32", "RemoveStackFrameSniffer", 24usize, |this| ((**(this as *const *const IDebugApplication32_Vtbl)).RemoveStackFrameSniffer)(this, dwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryCurrentThreadIsDebuggerThread(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication32", "QueryCurrentThreadIsDebuggerThread", 25usize, |this| ((**(this as *const *const IDebugApplication32_Vtbl)).QueryCurrentThreadIsDebuggerThread)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SynchronousCallInDebuggerThread(this: *mut core::ffi::c_void, pptc: *mut core::ffi::c_void, dwparam1: u32, dwparam2: u32, dwparam3: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication32", "SynchronousCallInDebuggerThread", 26usize, |this| ((**(this as *const *const IDebugApplication32_Vtbl)).SynchronousCallInDebuggerThread)(this, pptc, dwparam1, dwparam2, dwparam3), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateApplicationNode(this: *mut core::ffi::c_void, ppdannew: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication32", "CreateApplicationNode", 27usize, |this| ((**(this as *const *const IDebugApplication32_Vtbl)).CreateApplicationNode)(this, ppdannew), |result| Some(*result))
+        }
+        unsafe extern "system" fn FireDebuggerEvent(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication32", "FireDebuggerEvent", 28usize, |this| ((**(this as *const *const IDebugApplication32_Vtbl)).FireDebuggerEvent)(this, riid, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn HandleRuntimeError(this: *mut core::ffi::c_void, perrordebug: *mut core::ffi::c_void, pscriptsite: *mut core::ffi::c_void, pbra: *mut BREAKRESUMEACTION, perra: *mut ERRORRESUMEACTION, pfcallonscripterror: *mut super::super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication32", "HandleRuntimeError", 29usize, |this| ((**(this as *const *const IDebugApplication32_Vtbl)).HandleRuntimeError)(this, perrordebug, pscriptsite, pbra, perra, pfcallonscripterror), |result| Some(*result))
+        }
+        unsafe extern "system" fn FCanJitDebug(this: *mut core::ffi::c_void) -> super::super::super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDebugApplication32", "FCanJitDebug", 30usize, |this| ((**(this as *const *const IDebugApplication32_Vtbl)).FCanJitDebug)(this), |_| None)
+        }
+        unsafe extern "system" fn FIsAutoJitDebugEnabled(this: *mut core::ffi::c_void) -> super::super::super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDebugApplication32", "FIsAutoJitDebugEnabled", 31usize, |this| ((**(this as *const *const IDebugApplication32_Vtbl)).FIsAutoJitDebugEnabled)(this), |_| None)
+        }
+        unsafe extern "system" fn AddGlobalExpressionContextProvider(this: *mut core::ffi::c_void, pdsfs: *mut core::ffi::c_void, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication32", "AddGlobalExpressionContextProvider", 32usize, |this| ((**(this as *const *const IDebugApplication32_Vtbl)).AddGlobalExpressionContextProvider)(this, pdsfs, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveGlobalExpressionContextProvider(this: *mut core::ffi::c_void, dwcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication32", "RemoveGlobalExpressionContextProvider", 33usize, |this| ((**(this as *const *const IDebugApplication32_Vtbl)).RemoveGlobalExpressionContextProvider)(this, dwcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <IRemoteDebugApplication_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetName,
+            StepOutComplete,
+            DebugOutput,
+            StartDebugSession,
+            HandleBreakPoint,
+            Close,
+            GetBreakFlags,
+            GetCurrentThread,
+            CreateAsyncDebugOperation,
+            AddStackFrameSniffer,
+            RemoveStackFrameSniffer,
+            QueryCurrentThreadIsDebuggerThread,
+            SynchronousCallInDebuggerThread,
+            CreateApplicationNode,
+            FireDebuggerEvent,
+            HandleRuntimeError,
+            FCanJitDebug,
+            FIsAutoJitDebugEnabled,
+            AddGlobalExpressionContextProvider,
+            RemoveGlobalExpressionContextProvider,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugApplication32 as windows_core::Interface>::IID || <IRemoteDebugApplication_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugApplication64_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, pstrname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication64", "SetName", 14usize, |this| ((**(this as *const *const IDebugApplication64_Vtbl)).SetName)(this, pstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn StepOutComplete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication64", "StepOutComplete", 15usize, |this| ((**(this as *const *const IDebugApplication64_Vtbl)).StepOutComplete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DebugOutput(this: *mut core::ffi::c_void, pstr: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication64", "DebugOutput", 16usize, |this| ((**(this as *const *const IDebugApplication64_Vtbl)).DebugOutput)(this, pstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartDebugSession(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication64", "StartDebugSession", 17usize, |this| ((**(this as *const *const IDebugApplication64_Vtbl)).StartDebugSession)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn HandleBreakPoint(this: *mut core::ffi::c_void, br: BREAKREASON, pbra: *mut BREAKRESUMEACTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication64", "HandleBreakPoint", 18usize, |this| ((**(this as *const *const IDebugApplication64_Vtbl)).HandleBreakPoint)(this, br, pbra), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication64", "Close", 19usize, |this| ((**(this as *const *const IDebugApplication64_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBreakFlags(this: *mut core::ffi::c_void, pabf: *mut u32, pprdatsteppingthread: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication64", "GetBreakFlags", 20usize, |this| ((**(this as *const *const IDebugApplication64_Vtbl)).GetBreakFlags)(this, pabf, pprdatsteppingthread), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentThread(this: *mut core::ffi::c_void, pat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication64", "GetCurrentThread", 21usize, |this| ((**(this as *const *const IDebugApplication64_Vtbl)).GetCurrentThread)(this, pat), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAsyncDebugOperation(this: *mut core::ffi::c_void, psdo: *mut core::ffi::c_void, ppado: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication64", "CreateAsyncDebugOperation", 22usize, |this| ((**(this as *const *const IDebugApplication64_Vtbl)).CreateAsyncDebugOperation)(this, psdo, ppado), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddStackFrameSniffer(this: *mut core::ffi::c_void, pdsfs: *mut core::ffi::c_void, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication64", "AddStackFrameSniffer", 23usize, |this| ((**(this as *const *const IDebugApplication64_Vtbl)).AddStackFrameSniffer)(this, pdsfs, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStackFrameSniffer(this: *mut core::ffi::c_void, dwcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication64", "RemoveStackFrameSniffer", 24usize, |this| ((**(this as *const *const IDebugApplication64_Vtbl)).RemoveStackFrameSniffer)(this, dwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryCurrentThreadIsDebuggerThread(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication64", "QueryCurrentThreadIsDebuggerThread", 25usize, |this| ((**(this as *const *const IDebugApplication64_Vtbl)).QueryCurrentThreadIsDebuggerThread)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SynchronousCallInDebuggerThread(this: *mut core::ffi::c_void, pptc: *mut core::ffi::c_void, dwparam1: u64, dwparam2: u64, dwparam3: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication64", "SynchronousCallInDebuggerThread", 26usize, |this| ((**(this as *const *const IDebugApplication64_Vtbl)).SynchronousCallInDebuggerThread)(this, pptc, dwparam1, dwparam2, dwparam3), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateApplicationNode(this: *mut core::ffi::c_void, ppdannew: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication64", "CreateApplicationNode", 27usize, |this| ((**(this as *const *const IDebugApplication64_Vtbl)).CreateApplicationNode)(this, ppdannew), |result| Some(*result))
+        }
+        unsafe extern "system" fn FireDebuggerEvent(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication64", "FireDebuggerEvent", 28usize, |this| ((**(this as *const *const IDebugApplication64_Vtbl)).FireDebuggerEvent)(this, riid, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn HandleRuntimeError(this: *mut core::ffi::c_void, perrordebug: *mut core::ffi::c_void, pscriptsite: *mut core::ffi::c_void, pbra: *mut BREAKRESUMEACTION, perra: *mut ERRORRESUMEACTION, pfcallonscripterror: *mut super::super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication64", "HandleRuntimeError", 29usize, |this| ((**(this as *const *const IDebugApplication64_Vtbl)).HandleRuntimeError)(this, perrordebug, pscriptsite, pbra, perra, pfcallonscripterror), |result| Some(*result))
+        }
+        unsafe extern "system" fn FCanJitDebug(this: *mut core::ffi::c_void) -> super::super::super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDebugApplication64", "FCanJitDebug", 30usize, |this| ((**(this as *const *const IDebugApplication64_Vtbl)).FCanJitDebug)(this), |_| None)
+        }
+        unsafe extern "system" fn FIsAutoJitDebugEnabled(this: *mut core::ffi::c_void) -> super::super::super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDebugApplication64", "FIsAutoJitDebugEnabled", 31usize, |this| ((**(this as *const *const IDebugApplication64_Vtbl)).FIsAutoJitDebugEnabled)(this), |_| None)
+        }
+        unsafe extern "system" fn AddGlobalExpressionContextProvider(this: *mut core::ffi::c_void, pdsfs: *mut core::ffi::c_void, pdwcookie: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication64", "AddGlobalExpressionContextProvider", 32usize, |this| ((**(this as *const *const IDebugApplication64_Vtbl)).AddGlobalExpressionContextProvider)(this, pdsfs, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveGlobalExpressionContextProvider(this: *mut core::ffi::c_void, dwcookie: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplication64", "RemoveGlobalExpressionContextProvider", 33usize, |this| ((**(this as *const *const IDebugApplication64_Vtbl)).RemoveGlobalExpressionContextProvider)(this, dwcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <IRemoteDebugApplication_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetName,
+            StepOutComplete,
+            DebugOutput,
+            StartDebugSession,
+            HandleBreakPoint,
+            Close,
+            GetBreakFlags,
+            GetCurrentThread,
+            CreateAsyncDebugOperation,
+            AddStackFrameSniffer,
+            RemoveStackFrameSniffer,
+            QueryCurrentThreadIsDebuggerThread,
+            SynchronousCallInDebuggerThread,
+            CreateApplicationNode,
+            FireDebuggerEvent,
+            HandleRuntimeError,
+            FCanJitDebug,
+            FIsAutoJitDebugEnabled,
+            AddGlobalExpressionContextProvider,
+            RemoveGlobalExpressionContextProvider,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugApplication64 as windows_core::Interface>::IID || <IRemoteDebugApplication_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugApplicationNode_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumChildren(this: *mut core::ffi::c_void, pperddp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationNode", "EnumChildren", 6usize, |this| ((**(this as *const *const IDebugApplicationNode_Vtbl)).EnumChildren)(this, pperddp), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParent(this: *mut core::ffi::c_void, pprddp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationNode", "GetParent", 7usize, |this| ((**(this as *const *const IDebugApplicationNode_Vtbl)).GetParent)(this, pprddp), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDocumentProvider(this: *mut core::ffi::c_void, pddp: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationNode", "SetDocumentProvider", 8usize, |this| ((**(this as *const *const IDebugApplicationNode_Vtbl)).SetDocumentProvider)(this, pddp), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationNode", "Close", 9usize, |this| ((**(this as *const *const IDebugApplicationNode_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Attach(this: *mut core::ffi::c_void, pdanparent: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationNode", "Attach", 10usize, |this| ((**(this as *const *const IDebugApplicationNode_Vtbl)).Attach)(this, pdanparent), |result| Some(*result))
+        }
+        unsafe extern "system" fn Detach(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationNode", "Detach", 11usize, |this| ((**(this as *const *const IDebugApplicationNode_Vtbl)).Detach)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IDebugDocumentProvider_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EnumChildren,
+            GetParent,
+            SetDocumentProvider,
+            Close,
+            Attach,
+            Detach,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugApplicationNode as windows_core::Interface>::IID || <IDebugDocumentProvider_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugApplicationNode100_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetFilterForEventSink(this: *mut core::ffi::c_void, dwcookie: u32, filter: APPLICATION_NODE_EVENT_FILTER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationNode100", "SetFilterForEventSink", 3usize, |this| ((**(this as *const *const IDebugApplicationNode100_Vtbl)).SetFilterForEventSink)(this, dwcookie, filter), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExcludedDocuments(this: *mut core::ffi::c_void, filter: APPLICATION_NODE_EVENT_FILTER, pdocuments: *mut TEXT_DOCUMENT_ARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationNode100", "GetExcludedDocuments", 4usize, |this| ((**(this as *const *const IDebugApplicationNode100_Vtbl)).GetExcludedDocuments)(this, filter, pdocuments), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryIsChildNode(this: *mut core::ffi::c_void, psearchkey: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationNode100", "QueryIsChildNode", 5usize, |this| ((**(this as *const *const IDebugApplicationNode100_Vtbl)).QueryIsChildNode)(this, psearchkey), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetFilterForEventSink, GetExcludedDocuments, QueryIsChildNode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugApplicationNode100 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugApplicationNodeEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn onAddChild(this: *mut core::ffi::c_void, prddpchild: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationNodeEvents", "onAddChild", 3usize, |this| ((**(this as *const *const IDebugApplicationNodeEvents_Vtbl)).onAddChild)(this, prddpchild), |result| Some(*result))
+        }
+        unsafe extern "system" fn onRemoveChild(this: *mut core::ffi::c_void, prddpchild: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationNodeEvents", "onRemoveChild", 4usize, |this| ((**(this as *const *const IDebugApplicationNodeEvents_Vtbl)).onRemoveChild)(this, prddpchild), |result| Some(*result))
+        }
+        unsafe extern "system" fn onDetach(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationNodeEvents", "onDetach", 5usize, |this| ((**(this as *const *const IDebugApplicationNodeEvents_Vtbl)).onDetach)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn onAttach(this: *mut core::ffi::c_void, prddpparent: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationNodeEvents", "onAttach", 6usize, |this| ((**(this as *const *const IDebugApplicationNodeEvents_Vtbl)).onAttach)(this, prddpparent), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, onAddChild, onRemoveChild, onDetach, onAttach }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugApplicationNodeEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugApplicationThread_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SynchronousCallIntoThread32(this: *mut core::ffi::c_void, pstcb: *mut core::ffi::c_void, dwparam1: u32, dwparam2: u32, dwparam3: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationThread", "SynchronousCallIntoThread32", 12usize, |this| ((**(this as *const *const IDebugApplicationThread_Vtbl)).SynchronousCallIntoThread32)(this, pstcb, dwparam1, dwparam2, dwparam3), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryIsCurrentThread(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationThread", "QueryIsCurrentThread", 13usize, |this| ((**(this as *const *const IDebugApplicationThread_Vtbl)).QueryIsCurrentThread)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryIsDebuggerThread(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationThread", "QueryIsDebuggerThread", 14usize, |this| ((**(this as *const *const IDebugApplicationThread_Vtbl)).QueryIsDebuggerThread)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, pstrdescription: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationThread", "SetDescription", 15usize, |this| ((**(this as *const *const IDebugApplicationThread_Vtbl)).SetDescription)(this, pstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStateString(this: *mut core::ffi::c_void, pstrstate: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationThread", "SetStateString", 16usize, |this| ((**(this as *const *const IDebugApplicationThread_Vtbl)).SetStateString)(this, pstrstate), |result| Some(*result))
+        }
+        Self {
+            base__: <IRemoteDebugApplicationThread_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SynchronousCallIntoThread32,
+            QueryIsCurrentThread,
+            QueryIsDebuggerThread,
+            SetDescription,
+            SetStateString,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugApplicationThread as windows_core::Interface>::IID || <IRemoteDebugApplicationThread_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugApplicationThread11032_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetActiveThreadRequestCount(this: *mut core::ffi::c_void, puithreadrequests: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationThread11032", "GetActiveThreadRequestCount", 3usize, |this| ((**(this as *const *const IDebugApplicationThread11032_Vtbl)).GetActiveThreadRequestCount)(this, puithreadrequests), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSuspendedForBreakPoint(this: *mut core::ffi::c_void, pfissuspended: *mut super::super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationThread11032", "IsSuspendedForBreakPoint", 4usize, |this| ((**(this as *const *const IDebugApplicationThread11032_Vtbl)).IsSuspendedForBreakPoint)(this, pfissuspended), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsThreadCallable(this: *mut core::ffi::c_void, pfiscallable: *mut super::super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationThread11032", "IsThreadCallable", 5usize, |this| ((**(this as *const *const IDebugApplicationThread11032_Vtbl)).IsThreadCallable)(this, pfiscallable), |result| Some(*result))
+        }
+        unsafe extern "system" fn AsynchronousCallIntoThread(this: *mut core::ffi::c_void, pptc: *mut core::ffi::c_void, dwparam1: usize, dwparam2: usize, dwparam3: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationThread11032", "AsynchronousCallIntoThread", 6usize, |this| ((**(this as *const *const IDebugApplicationThread11032_Vtbl)).AsynchronousCallIntoThread)(this, pptc, dwparam1, dwparam2, dwparam3), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetActiveThreadRequestCount,
+            IsSuspendedForBreakPoint,
+            IsThreadCallable,
+            AsynchronousCallIntoThread,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugApplicationThread11032 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugApplicationThread11064_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetActiveThreadRequestCount(this: *mut core::ffi::c_void, puithreadrequests: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationThread11064", "GetActiveThreadRequestCount", 3usize, |this| ((**(this as *const *const IDebugApplicationThread11064_Vtbl)).GetActiveThreadRequestCount)(this, puithreadrequests), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSuspendedForBreakPoint(this: *mut core::ffi::c_void, pfissuspended: *mut super::super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationThread11064", "IsSuspendedForBreakPoint", 4usize, |this| ((**(this as *const *const IDebugApplicationThread11064_Vtbl)).IsSuspendedForBreakPoint)(this, pfissuspended), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsThreadCallable(this: *mut core::ffi::c_void, pfiscallable: *mut super::super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationThread11064", "IsThreadCallable", 5usize, |this| ((**(this as *const *const IDebugApplicationThread11064_Vtbl)).IsThreadCallable)(this, pfiscallable), |result| Some(*result))
+        }
+        unsafe extern "system" fn AsynchronousCallIntoThread(this: *mut core::ffi::c_void, pptc: *mut core::ffi::c_void, dwparam1: usize, dwparam2: usize, dwparam3: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationThread11064", "AsynchronousCallIntoThread", 6usize, |this| ((**(this as *const *const IDebugApplicationThread11064_Vtbl)).AsynchronousCallIntoThread)(this, pptc, dwparam1, dwparam2, dwparam3), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetActiveThreadRequestCount,
+            IsSuspendedForBreakPoint,
+            IsThreadCallable,
+            AsynchronousCallIntoThread,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugApplicationThread11064 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugApplicationThread64_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SynchronousCallIntoThread64(this: *mut core::ffi::c_void, pstcb: *mut core::ffi::c_void, dwparam1: u64, dwparam2: u64, dwparam3: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationThread64", "SynchronousCallIntoThread64", 17usize, |this| ((**(this as *const *const IDebugApplicationThread64_Vtbl)).SynchronousCallIntoThread64)(this, pstcb, dwparam1, dwparam2, dwparam3), |result| Some(*result))
+        }
+        Self { base__: <IDebugApplicationThread_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SynchronousCallIntoThread64 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugApplicationThread64 as windows_core::Interface>::IID || <IDebugApplicationThread_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugApplicationThreadEvents110_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnSuspendForBreakPoint(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationThreadEvents110", "OnSuspendForBreakPoint", 3usize, |this| ((**(this as *const *const IDebugApplicationThreadEvents110_Vtbl)).OnSuspendForBreakPoint)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnResumeFromBreakPoint(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationThreadEvents110", "OnResumeFromBreakPoint", 4usize, |this| ((**(this as *const *const IDebugApplicationThreadEvents110_Vtbl)).OnResumeFromBreakPoint)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnThreadRequestComplete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationThreadEvents110", "OnThreadRequestComplete", 5usize, |this| ((**(this as *const *const IDebugApplicationThreadEvents110_Vtbl)).OnThreadRequestComplete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnBeginThreadRequest(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugApplicationThreadEvents110", "OnBeginThreadRequest", 6usize, |this| ((**(this as *const *const IDebugApplicationThreadEvents110_Vtbl)).OnBeginThreadRequest)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnSuspendForBreakPoint,
+            OnResumeFromBreakPoint,
+            OnThreadRequestComplete,
+            OnBeginThreadRequest,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugApplicationThreadEvents110 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugAsyncOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSyncDebugOperation(this: *mut core::ffi::c_void, ppsdo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugAsyncOperation", "GetSyncDebugOperation", 3usize, |this| ((**(this as *const *const IDebugAsyncOperation_Vtbl)).GetSyncDebugOperation)(this, ppsdo), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void, padocb: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugAsyncOperation", "Start", 4usize, |this| ((**(this as *const *const IDebugAsyncOperation_Vtbl)).Start)(this, padocb), |result| Some(*result))
+        }
+        unsafe extern "system" fn Abort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugAsyncOperation", "Abort", 5usize, |this| ((**(this as *const *const IDebugAsyncOperation_Vtbl)).Abort)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryIsComplete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugAsyncOperation", "QueryIsComplete", 6usize, |this| ((**(this as *const *const IDebugAsyncOperation_Vtbl)).QueryIsComplete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResult(this: *mut core::ffi::c_void, phrresult: *mut windows_core::HRESULT, ppunkresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugAsyncOperation", "GetResult", 7usize, |this| ((**(this as *const *const IDebugAsyncOperation_Vtbl)).GetResult)(this, phrresult, ppunkresult), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSyncDebugOperation, Start, Abort, QueryIsComplete, GetResult }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugAsyncOperation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugAsyncOperationCallBack_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn onComplete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugAsyncOperationCallBack", "onComplete", 3usize, |this| ((**(this as *const *const IDebugAsyncOperationCallBack_Vtbl)).onComplete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, onComplete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugAsyncOperationCallBack as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugCodeContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDocumentContext(this: *mut core::ffi::c_void, ppsc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugCodeContext", "GetDocumentContext", 3usize, |this| ((**(this as *const *const IDebugCodeContext_Vtbl)).GetDocumentContext)(this, ppsc), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBreakPoint(this: *mut core::ffi::c_void, bps: BREAKPOINT_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugCodeContext", "SetBreakPoint", 4usize, |this| ((**(this as *const *const IDebugCodeContext_Vtbl)).SetBreakPoint)(this, bps), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDocumentContext, SetBreakPoint }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugCodeContext as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugCookie_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDebugCookie(this: *mut core::ffi::c_void, dwdebugappcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugCookie", "SetDebugCookie", 3usize, |this| ((**(this as *const *const IDebugCookie_Vtbl)).SetDebugCookie)(this, dwdebugappcookie), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetDebugCookie }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugCookie as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugDocument_Vtbl {
+    const PROXY: Self = { Self { base__: <IDebugDocumentInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugDocument as windows_core::Interface>::IID || <IDebugDocumentInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugDocumentContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDocument(this: *mut core::ffi::c_void, ppsd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentContext", "GetDocument", 3usize, |this| ((**(this as *const *const IDebugDocumentContext_Vtbl)).GetDocument)(this, ppsd), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumCodeContexts(this: *mut core::ffi::c_void, ppescc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentContext", "EnumCodeContexts", 4usize, |this| ((**(this as *const *const IDebugDocumentContext_Vtbl)).EnumCodeContexts)(this, ppescc), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDocument, EnumCodeContexts }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugDocumentContext as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugDocumentHelper32_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Init(this: *mut core::ffi::c_void, pda: *mut core::ffi::c_void, pszshortname: windows_core::PCWSTR, pszlongname: windows_core::PCWSTR, docattr: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper32", "Init", 3usize, |this| ((**(this as *const *const IDebugDocumentHelper32_Vtbl)).Init)(this, pda, pszshortname, pszlongname, docattr), |result| Some(*result))
+        }
+        unsafe extern "system" fn Attach(this: *mut core::ffi::c_void, pddhparent: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper32", "Attach", 4usize, |this| ((**(this as *const *const IDebugDocumentHelper32_Vtbl)).Attach)(this, pddhparent), |result| Some(*result))
+        }
+        unsafe extern "system" fn Detach(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper32", "Detach", 5usize, |this| ((**(this as *const *const IDebugDocumentHelper32_Vtbl)).Detach)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddUnicodeText(this: *mut core::ffi::c_void, psztext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper32", "AddUnicodeText", 6usize, |this| ((**(this as *const *const IDebugDocumentHelper32_Vtbl)).AddUnicodeText)(this, psztext), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDBCSText(this: *mut core::ffi::c_void, psztext: windows_core::PCSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper32", "AddDBCSText", 7usize, |this| ((**(this as *const *const IDebugDocumentHelper32_Vtbl)).AddDBCSText)(this, psztext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDebugDocumentHost(this: *mut core::ffi::c_void, pddh: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper32", "SetDebugDocumentHost", 8usize, |this| ((**(this as *const *const IDebugDocumentHelper32_Vtbl)).SetDebugDocumentHost)(this, pddh), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDeferredText(this: *mut core::ffi::c_void, cchars: u32, dwtextstartcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper32", "AddDeferredText", 9usize, |this| ((**(this as *const *const IDebugDocumentHelper32_Vtbl)).AddDeferredText)(this, cchars, dwtextstartcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefineScriptBlock(this: *mut core::ffi::c_void, ulcharoffset: u32, cchars: u32, pas: *mut core::ffi::c_void, fscriptlet: super::super::super::super::Foundation::BOOL, pdwsourcecontext: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper32", "DefineScriptBlock", 10usize, |this| ((**(this as *const *const IDebugDocumentHelper32_Vtbl)).DefineScriptBlock)(this, ulcharoffset, cchars, pas, fscriptlet, pdwsourcecontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultTextAttr(this: *mut core::ffi::c_void, statextattr: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper32", "SetDefaultTextAttr", 11usize, |this| ((**(this as *const *const IDebugDocumentHelper32_Vtbl)).SetDefaultTextAttr)(this, statextattr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTextAttributes(this: *mut core::ffi::c_void, ulcharoffset: u32, cchars: u32, pstatextattr: *const u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper32", "SetTextAttributes", 12usize, |this| ((**(this as *const *const IDebugDocumentHelper32_Vtbl)).SetTextAttributes)(this, ulcharoffset, cchars, pstatextattr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLongName(this: *mut core::ffi::c_void, pszlongname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper32", "SetLongName", 13usize, |this| ((**(this as *const *const IDebugDocumentHelper32_Vtbl)).SetLongName)(this, pszlongname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShortName(this: *mut core::ffi::c_void, pszshortname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper32", "SetShortName", 14usize, |this| ((**(this as *const *const IDebugDocumentHelper32_Vtbl)).SetShortName)(this, pszshortname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDocumentAttr(this: *mut core::ffi::c_void, pszattributes: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper32", "SetDocumentAttr", 15usize, |this| ((**(this as *const *const IDebugDocumentHelper32_Vtbl)).SetDocumentAttr)(this, pszattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDebugApplicationNode(this: *mut core::ffi::c_void, ppdan: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper32", "GetDebugApplicationNode", 16usize, |this| ((**(this as *const *const IDebugDocumentHelper32_Vtbl)).GetDebugApplicationNode)(this, ppdan), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScriptBlockInfo(this: *mut core::ffi::c_void, dwsourcecontext: u32, ppasd: *mut *mut core::ffi::c_void, picharpos: *mut u32, pcchars: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper32", "GetScriptBlockInfo", 17usize, |this| ((**(this as *const *const IDebugDocumentHelper32_Vtbl)).GetScriptBlockInfo)(this, dwsourcecontext, ppasd, picharpos, pcchars), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDebugDocumentContext(this: *mut core::ffi::c_void, icharpos: u32, cchars: u32, ppddc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper32", "CreateDebugDocumentContext", 18usize, |this| ((**(this as *const *const IDebugDocumentHelper32_Vtbl)).CreateDebugDocumentContext)(this, icharpos, cchars, ppddc), |result| Some(*result))
+        }
+        unsafe extern "system" fn BringDocumentToTop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper32", "BringDocumentToTop", 19usize, |this| ((**(this as *const *const IDebugDocumentHelper32_Vtbl)).BringDocumentToTop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn BringDocumentContextToTop(this: *mut core::ffi::c_void, pddc: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper32", "BringDocumentContextToTop", 20usize, |this| ((**(this as *const *const IDebugDocumentHelper32_Vtbl)).BringDocumentContextToTop)(this, pddc), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Init,
+            Attach,
+            Detach,
+            AddUnicodeText,
+            AddDBCSText,
+            SetDebugDocumentHost,
+            AddDeferredText,
+            DefineScriptBlock,
+            SetDefaultTextAttr,
+            SetTextAttributes,
+            SetLongName,
+            SetShortName,
+            SetDocumentAttr,
+            GetDebugApplicationNode,
+            GetScriptBlockInfo,
+            CreateDebugDocumentContext,
+            BringDocumentToTop,
+            BringDocumentContextToTop,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugDocumentHelper32 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugDocumentHelper64_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Init(this: *mut core::ffi::c_void, pda: *mut core::ffi::c_void, pszshortname: windows_core::PCWSTR, pszlongname: windows_core::PCWSTR, docattr: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper64", "Init", 3usize, |this| ((**(this as *const *const IDebugDocumentHelper64_Vtbl)).Init)(this, pda, pszshortname, pszlongname, docattr), |result| Some(*result))
+        }
+        unsafe extern "system" fn Attach(this: *mut core::ffi::c_void, pddhparent: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper64", "Attach", 4usize, |this| ((**(this as *const *const IDebugDocumentHelper64_Vtbl)).Attach)(this, pddhparent), |result| Some(*result))
+        }
+        unsafe extern "system" fn Detach(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper64", "Detach", 5usize, |this| ((**(this as *const *const IDebugDocumentHelper64_Vtbl)).Detach)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddUnicodeText(this: *mut core::ffi::c_void, psztext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper64", "AddUnicodeText", 6usize, |this| ((**(this as *const *const IDebugDocumentHelper64_Vtbl)).AddUnicodeText)(this, psztext), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDBCSText(this: *mut core::ffi::c_void, psztext: windows_core::PCSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper64", "AddDBCSText", 7usize, |this| ((**(this as *const *const IDebugDocumentHelper64_Vtbl)).AddDBCSText)(this, psztext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDebugDocumentHost(this: *mut core::ffi::c_void, pddh: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper64", "SetDebugDocumentHost", 8usize, |this| ((**(this as *const *const IDebugDocumentHelper64_Vtbl)).SetDebugDocumentHost)(this, pddh), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddDeferredText(this: *mut core::ffi::c_void, cchars: u32, dwtextstartcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper64", "AddDeferredText", 9usize, |this| ((**(this as *const *const IDebugDocumentHelper64_Vtbl)).AddDeferredText)(this, cchars, dwtextstartcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefineScriptBlock(this: *mut core::ffi::c_void, ulcharoffset: u32, cchars: u32, pas: *mut core::ffi::c_void, fscriptlet: super::super::super::super::Foundation::BOOL, pdwsourcecontext: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper64", "DefineScriptBlock", 10usize, |this| ((**(this as *const *const IDebugDocumentHelper64_Vtbl)).DefineScriptBlock)(this, ulcharoffset, cchars, pas, fscriptlet, pdwsourcecontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultTextAttr(this: *mut core::ffi::c_void, statextattr: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper64", "SetDefaultTextAttr", 11usize, |this| ((**(this as *const *const IDebugDocumentHelper64_Vtbl)).SetDefaultTextAttr)(this, statextattr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTextAttributes(this: *mut core::ffi::c_void, ulcharoffset: u32, cchars: u32, pstatextattr: *const u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper64", "SetTextAttributes", 12usize, |this| ((**(this as *const *const IDebugDocumentHelper64_Vtbl)).SetTextAttributes)(this, ulcharoffset, cchars, pstatextattr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLongName(this: *mut core::ffi::c_void, pszlongname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper64", "SetLongName", 13usize, |this| ((**(this as *const *const IDebugDocumentHelper64_Vtbl)).SetLongName)(this, pszlongname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShortName(this: *mut core::ffi::c_void, pszshortname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper64", "SetShortName", 14usize, |this| ((**(this as *const *const IDebugDocumentHelper64_Vtbl)).SetShortName)(this, pszshortname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDocumentAttr(this: *mut core::ffi::c_void, pszattributes: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper64", "SetDocumentAttr", 15usize, |this| ((**(this as *const *const IDebugDocumentHelper64_Vtbl)).SetDocumentAttr)(this, pszattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDebugApplicationNode(this: *mut core::ffi::c_void, ppdan: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper64", "GetDebugApplicationNode", 16usize, |this| ((**(this as *const *const IDebugDocumentHelper64_Vtbl)).GetDebugApplicationNode)(this, ppdan), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScriptBlockInfo(this: *mut core::ffi::c_void, dwsourcecontext: u64, ppasd: *mut *mut core::ffi::c_void, picharpos: *mut u32, pcchars: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper64", "GetScriptBlockInfo", 17usize, |this| ((**(this as *const *const IDebugDocumentHelper64_Vtbl)).GetScriptBlockInfo)(this, dwsourcecontext, ppasd, picharpos, pcchars), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDebugDocumentContext(this: *mut core::ffi::c_void, icharpos: u32, cchars: u32, ppddc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper64", "CreateDebugDocumentContext", 18usize, |this| ((**(this as *const *const IDebugDocumentHelper64_Vtbl)).CreateDebugDocumentContext)(this, icharpos, cchars, ppddc), |result| Some(*result))
+        }
+        unsafe extern "system" fn BringDocumentToTop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper64", "BringDocumentToTop", 19usize, |this| ((**(this as *const *const IDebugDocumentHelper64_Vtbl)).BringDocumentToTop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn BringDocumentContextToTop(this: *mut core::ffi::c_void, pddc: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHelper64", "BringDocumentContextToTop", 20usize, |this| ((**(this as *const *const IDebugDocumentHelper64_Vtbl)).BringDocumentContextToTop)(this, pddc), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Init,
+            Attach,
+            Detach,
+            AddUnicodeText,
+            AddDBCSText,
+            SetDebugDocumentHost,
+            AddDeferredText,
+            DefineScriptBlock,
+            SetDefaultTextAttr,
+            SetTextAttributes,
+            SetLongName,
+            SetShortName,
+            SetDocumentAttr,
+            GetDebugApplicationNode,
+            GetScriptBlockInfo,
+            CreateDebugDocumentContext,
+            BringDocumentToTop,
+            BringDocumentContextToTop,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugDocumentHelper64 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugDocumentHost_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDeferredText(this: *mut core::ffi::c_void, dwtextstartcookie: u32, pchartext: windows_core::PWSTR, pstatextattr: *mut u16, pcnumchars: *mut u32, cmaxchars: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHost", "GetDeferredText", 3usize, |this| ((**(this as *const *const IDebugDocumentHost_Vtbl)).GetDeferredText)(this, dwtextstartcookie, pchartext, pstatextattr, pcnumchars, cmaxchars), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScriptTextAttributes(this: *mut core::ffi::c_void, pstrcode: windows_core::PCWSTR, unumcodechars: u32, pstrdelimiter: windows_core::PCWSTR, dwflags: u32, pattr: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHost", "GetScriptTextAttributes", 4usize, |this| ((**(this as *const *const IDebugDocumentHost_Vtbl)).GetScriptTextAttributes)(this, pstrcode, unumcodechars, pstrdelimiter, dwflags, pattr), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnCreateDocumentContext(this: *mut core::ffi::c_void, ppunkouter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHost", "OnCreateDocumentContext", 5usize, |this| ((**(this as *const *const IDebugDocumentHost_Vtbl)).OnCreateDocumentContext)(this, ppunkouter), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPathName(this: *mut core::ffi::c_void, pbstrlongname: *mut core::mem::MaybeUninit<windows_core::BSTR>, pfisoriginalfile: *mut super::super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHost", "GetPathName", 6usize, |this| ((**(this as *const *const IDebugDocumentHost_Vtbl)).GetPathName)(this, pbstrlongname, pfisoriginalfile), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileName(this: *mut core::ffi::c_void, pbstrshortname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHost", "GetFileName", 7usize, |this| ((**(this as *const *const IDebugDocumentHost_Vtbl)).GetFileName)(this, pbstrshortname), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyChanged(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentHost", "NotifyChanged", 8usize, |this| ((**(this as *const *const IDebugDocumentHost_Vtbl)).NotifyChanged)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDeferredText,
+            GetScriptTextAttributes,
+            OnCreateDocumentContext,
+            GetPathName,
+            GetFileName,
+            NotifyChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugDocumentHost as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugDocumentInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, dnt: DOCUMENTNAMETYPE, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentInfo", "GetName", 3usize, |this| ((**(this as *const *const IDebugDocumentInfo_Vtbl)).GetName)(this, dnt, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDocumentClassId(this: *mut core::ffi::c_void, pclsiddocument: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentInfo", "GetDocumentClassId", 4usize, |this| ((**(this as *const *const IDebugDocumentInfo_Vtbl)).GetDocumentClassId)(this, pclsiddocument), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetName, GetDocumentClassId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugDocumentInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugDocumentProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDocument(this: *mut core::ffi::c_void, ppssd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentProvider", "GetDocument", 5usize, |this| ((**(this as *const *const IDebugDocumentProvider_Vtbl)).GetDocument)(this, ppssd), |result| Some(*result))
+        }
+        Self { base__: <IDebugDocumentInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDocument }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugDocumentProvider as windows_core::Interface>::IID || <IDebugDocumentInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugDocumentText_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDocumentAttributes(this: *mut core::ffi::c_void, ptextdocattr: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentText", "GetDocumentAttributes", 5usize, |this| ((**(this as *const *const IDebugDocumentText_Vtbl)).GetDocumentAttributes)(this, ptextdocattr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSize(this: *mut core::ffi::c_void, pcnumlines: *mut u32, pcnumchars: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentText", "GetSize", 6usize, |this| ((**(this as *const *const IDebugDocumentText_Vtbl)).GetSize)(this, pcnumlines, pcnumchars), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPositionOfLine(this: *mut core::ffi::c_void, clinenumber: u32, pccharacterposition: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentText", "GetPositionOfLine", 7usize, |this| ((**(this as *const *const IDebugDocumentText_Vtbl)).GetPositionOfLine)(this, clinenumber, pccharacterposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLineOfPosition(this: *mut core::ffi::c_void, ccharacterposition: u32, pclinenumber: *mut u32, pccharacteroffsetinline: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentText", "GetLineOfPosition", 8usize, |this| ((**(this as *const *const IDebugDocumentText_Vtbl)).GetLineOfPosition)(this, ccharacterposition, pclinenumber, pccharacteroffsetinline), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetText(this: *mut core::ffi::c_void, ccharacterposition: u32, pchartext: windows_core::PWSTR, pstatextattr: *mut u16, pcnumchars: *mut u32, cmaxchars: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentText", "GetText", 9usize, |this| ((**(this as *const *const IDebugDocumentText_Vtbl)).GetText)(this, ccharacterposition, pchartext, pstatextattr, pcnumchars, cmaxchars), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPositionOfContext(this: *mut core::ffi::c_void, psc: *mut core::ffi::c_void, pccharacterposition: *mut u32, cnumchars: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentText", "GetPositionOfContext", 10usize, |this| ((**(this as *const *const IDebugDocumentText_Vtbl)).GetPositionOfContext)(this, psc, pccharacterposition, cnumchars), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContextOfPosition(this: *mut core::ffi::c_void, ccharacterposition: u32, cnumchars: u32, ppsc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentText", "GetContextOfPosition", 11usize, |this| ((**(this as *const *const IDebugDocumentText_Vtbl)).GetContextOfPosition)(this, ccharacterposition, cnumchars, ppsc), |result| Some(*result))
+        }
+        Self {
+            base__: <IDebugDocument_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDocumentAttributes,
+            GetSize,
+            GetPositionOfLine,
+            GetLineOfPosition,
+            GetText,
+            GetPositionOfContext,
+            GetContextOfPosition,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugDocumentText as windows_core::Interface>::IID || <IDebugDocument_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugDocumentTextAuthor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InsertText(this: *mut core::ffi::c_void, ccharacterposition: u32, cnumtoinsert: u32, pchartext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentTextAuthor", "InsertText", 12usize, |this| ((**(this as *const *const IDebugDocumentTextAuthor_Vtbl)).InsertText)(this, ccharacterposition, cnumtoinsert, pchartext), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveText(this: *mut core::ffi::c_void, ccharacterposition: u32, cnumtoremove: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentTextAuthor", "RemoveText", 13usize, |this| ((**(this as *const *const IDebugDocumentTextAuthor_Vtbl)).RemoveText)(this, ccharacterposition, cnumtoremove), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReplaceText(this: *mut core::ffi::c_void, ccharacterposition: u32, cnumtoreplace: u32, pchartext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentTextAuthor", "ReplaceText", 14usize, |this| ((**(this as *const *const IDebugDocumentTextAuthor_Vtbl)).ReplaceText)(this, ccharacterposition, cnumtoreplace, pchartext), |result| Some(*result))
+        }
+        Self { base__: <IDebugDocumentText_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InsertText, RemoveText, ReplaceText }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugDocumentTextAuthor as windows_core::Interface>::IID || <IDebugDocumentText_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugDocumentTextEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn onDestroy(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentTextEvents", "onDestroy", 3usize, |this| ((**(this as *const *const IDebugDocumentTextEvents_Vtbl)).onDestroy)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn onInsertText(this: *mut core::ffi::c_void, ccharacterposition: u32, cnumtoinsert: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentTextEvents", "onInsertText", 4usize, |this| ((**(this as *const *const IDebugDocumentTextEvents_Vtbl)).onInsertText)(this, ccharacterposition, cnumtoinsert), |result| Some(*result))
+        }
+        unsafe extern "system" fn onRemoveText(this: *mut core::ffi::c_void, ccharacterposition: u32, cnumtoremove: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentTextEvents", "onRemoveText", 5usize, |this| ((**(this as *const *const IDebugDocumentTextEvents_Vtbl)).onRemoveText)(this, ccharacterposition, cnumtoremove), |result| Some(*result))
+        }
+        unsafe extern "system" fn onReplaceText(this: *mut core::ffi::c_void, ccharacterposition: u32, cnumtoreplace: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentTextEvents", "onReplaceText", 6usize, |this| ((**(this as *const *const IDebugDocumentTextEvents_Vtbl)).onReplaceText)(this, ccharacterposition, cnumtoreplace), |result| Some(*result))
+        }
+        unsafe extern "system" fn onUpdateTextAttributes(this: *mut core::ffi::c_void, ccharacterposition: u32, cnumtoupdate: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentTextEvents", "onUpdateTextAttributes", 7usize, |this| ((**(this as *const *const IDebugDocumentTextEvents_Vtbl)).onUpdateTextAttributes)(this, ccharacterposition, cnumtoupdate), |result| Some(*result))
+        }
+        unsafe extern "system" fn onUpdateDocumentAttributes(this: *mut core::ffi::c_void, textdocattr: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentTextEvents", "onUpdateDocumentAttributes", 8usize, |this| ((**(this as *const *const IDebugDocumentTextEvents_Vtbl)).onUpdateDocumentAttributes)(this, textdocattr), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            onDestroy,
+            onInsertText,
+            onRemoveText,
+            onReplaceText,
+            onUpdateTextAttributes,
+            onUpdateDocumentAttributes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugDocumentTextEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugDocumentTextExternalAuthor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPathName(this: *mut core::ffi::c_void, pbstrlongname: *mut core::mem::MaybeUninit<windows_core::BSTR>, pfisoriginalfile: *mut super::super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentTextExternalAuthor", "GetPathName", 3usize, |this| ((**(this as *const *const IDebugDocumentTextExternalAuthor_Vtbl)).GetPathName)(this, pbstrlongname, pfisoriginalfile), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileName(this: *mut core::ffi::c_void, pbstrshortname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentTextExternalAuthor", "GetFileName", 4usize, |this| ((**(this as *const *const IDebugDocumentTextExternalAuthor_Vtbl)).GetFileName)(this, pbstrshortname), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyChanged(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugDocumentTextExternalAuthor", "NotifyChanged", 5usize, |this| ((**(this as *const *const IDebugDocumentTextExternalAuthor_Vtbl)).NotifyChanged)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPathName, GetFileName, NotifyChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugDocumentTextExternalAuthor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugExpression_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void, pdecb: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugExpression", "Start", 3usize, |this| ((**(this as *const *const IDebugExpression_Vtbl)).Start)(this, pdecb), |result| Some(*result))
+        }
+        unsafe extern "system" fn Abort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugExpression", "Abort", 4usize, |this| ((**(this as *const *const IDebugExpression_Vtbl)).Abort)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryIsComplete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugExpression", "QueryIsComplete", 5usize, |this| ((**(this as *const *const IDebugExpression_Vtbl)).QueryIsComplete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResultAsString(this: *mut core::ffi::c_void, phrresult: *mut windows_core::HRESULT, pbstrresult: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugExpression", "GetResultAsString", 6usize, |this| ((**(this as *const *const IDebugExpression_Vtbl)).GetResultAsString)(this, phrresult, pbstrresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResultAsDebugProperty(this: *mut core::ffi::c_void, phrresult: *mut windows_core::HRESULT, ppdp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugExpression", "GetResultAsDebugProperty", 7usize, |this| ((**(this as *const *const IDebugExpression_Vtbl)).GetResultAsDebugProperty)(this, phrresult, ppdp), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Start,
+            Abort,
+            QueryIsComplete,
+            GetResultAsString,
+            GetResultAsDebugProperty,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugExpression as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugExpressionCallBack_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn onComplete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugExpressionCallBack", "onComplete", 3usize, |this| ((**(this as *const *const IDebugExpressionCallBack_Vtbl)).onComplete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, onComplete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugExpressionCallBack as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugExpressionContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ParseLanguageText(this: *mut core::ffi::c_void, pstrcode: windows_core::PCWSTR, nradix: u32, pstrdelimiter: windows_core::PCWSTR, dwflags: u32, ppe: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugExpressionContext", "ParseLanguageText", 3usize, |this| ((**(this as *const *const IDebugExpressionContext_Vtbl)).ParseLanguageText)(this, pstrcode, nradix, pstrdelimiter, dwflags, ppe), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLanguageInfo(this: *mut core::ffi::c_void, pbstrlanguagename: *mut core::mem::MaybeUninit<windows_core::BSTR>, planguageid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugExpressionContext", "GetLanguageInfo", 4usize, |this| ((**(this as *const *const IDebugExpressionContext_Vtbl)).GetLanguageInfo)(this, pbstrlanguagename, planguageid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ParseLanguageText, GetLanguageInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugExpressionContext as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+impl windows_core::imp::ProxyVtable for IDebugFormatter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStringForVariant(this: *mut core::ffi::c_void, pvar: *const core::mem::MaybeUninit<windows_core::VARIANT>, nradix: u32, pbstrvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugFormatter", "GetStringForVariant", 3usize, |this| ((**(this as *const *const IDebugFormatter_Vtbl)).GetStringForVariant)(this, pvar, nradix, pbstrvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVariantForString(this: *mut core::ffi::c_void, pwstrvalue: windows_core::PCWSTR, pvar: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugFormatter", "GetVariantForString", 4usize, |this| ((**(this as *const *const IDebugFormatter_Vtbl)).GetVariantForString)(this, pwstrvalue, pvar), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+        unsafe extern "system" fn GetStringForVarType(this: *mut core::ffi::c_void, vt: super::super::super::Variant::VARENUM, ptdescarraytype: *const super::super::super::Com::TYPEDESC, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugFormatter", "GetStringForVarType", 5usize, |this| ((**(this as *const *const IDebugFormatter_Vtbl)).GetStringForVarType)(this, vt, ptdescarraytype, pbstr), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetStringForVariant,
+            GetVariantForString,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant"))]
+            GetStringForVarType,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant")))]
+            GetStringForVarType: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugFormatter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDebugHelper_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreatePropertyBrowser(this: *mut core::ffi::c_void, pvar: *const core::mem::MaybeUninit<windows_core::VARIANT>, bstrname: windows_core::PCWSTR, pdat: *mut core::ffi::c_void, ppdob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugHelper", "CreatePropertyBrowser", 3usize, |this| ((**(this as *const *const IDebugHelper_Vtbl)).CreatePropertyBrowser)(this, pvar, bstrname, pdat, ppdob), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePropertyBrowserEx(this: *mut core::ffi::c_void, pvar: *const core::mem::MaybeUninit<windows_core::VARIANT>, bstrname: windows_core::PCWSTR, pdat: *mut core::ffi::c_void, pdf: *mut core::ffi::c_void, ppdob: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugHelper", "CreatePropertyBrowserEx", 4usize, |this| ((**(this as *const *const IDebugHelper_Vtbl)).CreatePropertyBrowserEx)(this, pvar, bstrname, pdat, pdf, ppdob), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateSimpleConnectionPoint(this: *mut core::ffi::c_void, pdisp: *mut core::ffi::c_void, ppscp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugHelper", "CreateSimpleConnectionPoint", 5usize, |this| ((**(this as *const *const IDebugHelper_Vtbl)).CreateSimpleConnectionPoint)(this, pdisp, ppscp), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreatePropertyBrowser,
+            CreatePropertyBrowserEx,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateSimpleConnectionPoint,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateSimpleConnectionPoint: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugHelper as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugSessionProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartDebugSession(this: *mut core::ffi::c_void, pda: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugSessionProvider", "StartDebugSession", 3usize, |this| ((**(this as *const *const IDebugSessionProvider_Vtbl)).StartDebugSession)(this, pda), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartDebugSession }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugSessionProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugStackFrame_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCodeContext(this: *mut core::ffi::c_void, ppcc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugStackFrame", "GetCodeContext", 3usize, |this| ((**(this as *const *const IDebugStackFrame_Vtbl)).GetCodeContext)(this, ppcc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescriptionString(this: *mut core::ffi::c_void, flong: super::super::super::super::Foundation::BOOL, pbstrdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugStackFrame", "GetDescriptionString", 4usize, |this| ((**(this as *const *const IDebugStackFrame_Vtbl)).GetDescriptionString)(this, flong, pbstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLanguageString(this: *mut core::ffi::c_void, flong: super::super::super::super::Foundation::BOOL, pbstrlanguage: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugStackFrame", "GetLanguageString", 5usize, |this| ((**(this as *const *const IDebugStackFrame_Vtbl)).GetLanguageString)(this, flong, pbstrlanguage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetThread(this: *mut core::ffi::c_void, ppat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugStackFrame", "GetThread", 6usize, |this| ((**(this as *const *const IDebugStackFrame_Vtbl)).GetThread)(this, ppat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDebugProperty(this: *mut core::ffi::c_void, ppdebugprop: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugStackFrame", "GetDebugProperty", 7usize, |this| ((**(this as *const *const IDebugStackFrame_Vtbl)).GetDebugProperty)(this, ppdebugprop), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCodeContext,
+            GetDescriptionString,
+            GetLanguageString,
+            GetThread,
+            GetDebugProperty,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugStackFrame as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugStackFrame110_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStackFrameType(this: *mut core::ffi::c_void, pstackframekind: *mut DEBUG_STACKFRAME_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugStackFrame110", "GetStackFrameType", 8usize, |this| ((**(this as *const *const IDebugStackFrame110_Vtbl)).GetStackFrameType)(this, pstackframekind), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScriptInvocationContext(this: *mut core::ffi::c_void, ppinvocationcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugStackFrame110", "GetScriptInvocationContext", 9usize, |this| ((**(this as *const *const IDebugStackFrame110_Vtbl)).GetScriptInvocationContext)(this, ppinvocationcontext), |result| Some(*result))
+        }
+        Self { base__: <IDebugStackFrame_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStackFrameType, GetScriptInvocationContext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugStackFrame110 as windows_core::Interface>::IID || <IDebugStackFrame_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugStackFrameSniffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumStackFrames(this: *mut core::ffi::c_void, ppedsf: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugStackFrameSniffer", "EnumStackFrames", 3usize, |this| ((**(this as *const *const IDebugStackFrameSniffer_Vtbl)).EnumStackFrames)(this, ppedsf), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnumStackFrames }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugStackFrameSniffer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugStackFrameSnifferEx32_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumStackFramesEx32(this: *mut core::ffi::c_void, dwspmin: u32, ppedsf: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugStackFrameSnifferEx32", "EnumStackFramesEx32", 4usize, |this| ((**(this as *const *const IDebugStackFrameSnifferEx32_Vtbl)).EnumStackFramesEx32)(this, dwspmin, ppedsf), |result| Some(*result))
+        }
+        Self { base__: <IDebugStackFrameSniffer_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnumStackFramesEx32 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugStackFrameSnifferEx32 as windows_core::Interface>::IID || <IDebugStackFrameSniffer_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugStackFrameSnifferEx64_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumStackFramesEx64(this: *mut core::ffi::c_void, dwspmin: u64, ppedsf: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugStackFrameSnifferEx64", "EnumStackFramesEx64", 4usize, |this| ((**(this as *const *const IDebugStackFrameSnifferEx64_Vtbl)).EnumStackFramesEx64)(this, dwspmin, ppedsf), |result| Some(*result))
+        }
+        Self { base__: <IDebugStackFrameSniffer_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnumStackFramesEx64 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugStackFrameSnifferEx64 as windows_core::Interface>::IID || <IDebugStackFrameSniffer_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugSyncOperation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTargetThread(this: *mut core::ffi::c_void, ppattarget: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugSyncOperation", "GetTargetThread", 3usize, |this| ((**(this as *const *const IDebugSyncOperation_Vtbl)).GetTargetThread)(this, ppattarget), |result| Some(*result))
+        }
+        unsafe extern "system" fn Execute(this: *mut core::ffi::c_void, ppunkresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugSyncOperation", "Execute", 4usize, |this| ((**(this as *const *const IDebugSyncOperation_Vtbl)).Execute)(this, ppunkresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn InProgressAbort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugSyncOperation", "InProgressAbort", 5usize, |this| ((**(this as *const *const IDebugSyncOperation_Vtbl)).InProgressAbort)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetTargetThread, Execute, InProgressAbort }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugSyncOperation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugThreadCall32_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ThreadCallHandler(this: *mut core::ffi::c_void, dwparam1: u32, dwparam2: u32, dwparam3: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugThreadCall32", "ThreadCallHandler", 3usize, |this| ((**(this as *const *const IDebugThreadCall32_Vtbl)).ThreadCallHandler)(this, dwparam1, dwparam2, dwparam3), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ThreadCallHandler }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugThreadCall32 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugThreadCall64_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ThreadCallHandler(this: *mut core::ffi::c_void, dwparam1: u64, dwparam2: u64, dwparam3: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugThreadCall64", "ThreadCallHandler", 3usize, |this| ((**(this as *const *const IDebugThreadCall64_Vtbl)).ThreadCallHandler)(this, dwparam1, dwparam2, dwparam3), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ThreadCallHandler }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugThreadCall64 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumDebugApplicationNodes_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, pprddp: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDebugApplicationNodes", "Next", 3usize, |this| ((**(this as *const *const IEnumDebugApplicationNodes_Vtbl)).Next)(this, celt, pprddp, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDebugApplicationNodes", "Skip", 4usize, |this| ((**(this as *const *const IEnumDebugApplicationNodes_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDebugApplicationNodes", "Reset", 5usize, |this| ((**(this as *const *const IEnumDebugApplicationNodes_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, pperddp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDebugApplicationNodes", "Clone", 6usize, |this| ((**(this as *const *const IEnumDebugApplicationNodes_Vtbl)).Clone)(this, pperddp), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumDebugApplicationNodes as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumDebugCodeContexts_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, pscc: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDebugCodeContexts", "Next", 3usize, |this| ((**(this as *const *const IEnumDebugCodeContexts_Vtbl)).Next)(this, celt, pscc, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDebugCodeContexts", "Skip", 4usize, |this| ((**(this as *const *const IEnumDebugCodeContexts_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDebugCodeContexts", "Reset", 5usize, |this| ((**(this as *const *const IEnumDebugCodeContexts_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppescc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDebugCodeContexts", "Clone", 6usize, |this| ((**(this as *const *const IEnumDebugCodeContexts_Vtbl)).Clone)(this, ppescc), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumDebugCodeContexts as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumDebugExpressionContexts_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppdec: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDebugExpressionContexts", "Next", 3usize, |this| ((**(this as *const *const IEnumDebugExpressionContexts_Vtbl)).Next)(this, celt, ppdec, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDebugExpressionContexts", "Skip", 4usize, |this| ((**(this as *const *const IEnumDebugExpressionContexts_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDebugExpressionContexts", "Reset", 5usize, |this| ((**(this as *const *const IEnumDebugExpressionContexts_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppedec: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDebugExpressionContexts", "Clone", 6usize, |this| ((**(this as *const *const IEnumDebugExpressionContexts_Vtbl)).Clone)(this, ppedec), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumDebugExpressionContexts as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumDebugStackFrames_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, prgdsfd: *mut DebugStackFrameDescriptor, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDebugStackFrames", "Next", 3usize, |this| ((**(this as *const *const IEnumDebugStackFrames_Vtbl)).Next)(this, celt, prgdsfd, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDebugStackFrames", "Skip", 4usize, |this| ((**(this as *const *const IEnumDebugStackFrames_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDebugStackFrames", "Reset", 5usize, |this| ((**(this as *const *const IEnumDebugStackFrames_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppedsf: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDebugStackFrames", "Clone", 6usize, |this| ((**(this as *const *const IEnumDebugStackFrames_Vtbl)).Clone)(this, ppedsf), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumDebugStackFrames as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumDebugStackFrames64_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next64(this: *mut core::ffi::c_void, celt: u32, prgdsfd: *mut DebugStackFrameDescriptor64, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDebugStackFrames64", "Next64", 7usize, |this| ((**(this as *const *const IEnumDebugStackFrames64_Vtbl)).Next64)(this, celt, prgdsfd, pceltfetched), |result| Some(*result))
+        }
+        Self { base__: <IEnumDebugStackFrames_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next64 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumDebugStackFrames64 as windows_core::Interface>::IID || <IEnumDebugStackFrames_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumJsStackFrames_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, cframecount: u32, pframes: *mut JS_NATIVE_FRAME, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumJsStackFrames", "Next", 3usize, |this| ((**(this as *const *const IEnumJsStackFrames_Vtbl)).Next)(this, cframecount, pframes, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumJsStackFrames", "Reset", 4usize, |this| ((**(this as *const *const IEnumJsStackFrames_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Reset }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumJsStackFrames as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumRemoteDebugApplicationThreads_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, pprdat: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumRemoteDebugApplicationThreads", "Next", 3usize, |this| ((**(this as *const *const IEnumRemoteDebugApplicationThreads_Vtbl)).Next)(this, celt, pprdat, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumRemoteDebugApplicationThreads", "Skip", 4usize, |this| ((**(this as *const *const IEnumRemoteDebugApplicationThreads_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumRemoteDebugApplicationThreads", "Reset", 5usize, |this| ((**(this as *const *const IEnumRemoteDebugApplicationThreads_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, pperdat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumRemoteDebugApplicationThreads", "Clone", 6usize, |this| ((**(this as *const *const IEnumRemoteDebugApplicationThreads_Vtbl)).Clone)(this, pperdat), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumRemoteDebugApplicationThreads as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumRemoteDebugApplications_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppda: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumRemoteDebugApplications", "Next", 3usize, |this| ((**(this as *const *const IEnumRemoteDebugApplications_Vtbl)).Next)(this, celt, ppda, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumRemoteDebugApplications", "Skip", 4usize, |this| ((**(this as *const *const IEnumRemoteDebugApplications_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumRemoteDebugApplications", "Reset", 5usize, |this| ((**(this as *const *const IEnumRemoteDebugApplications_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppessd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumRemoteDebugApplications", "Clone", 6usize, |this| ((**(this as *const *const IEnumRemoteDebugApplications_Vtbl)).Clone)(this, ppessd), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumRemoteDebugApplications as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IJsDebug_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenVirtualProcess(this: *mut core::ffi::c_void, processid: u32, runtimejsbaseaddress: u64, pdatatarget: *mut core::ffi::c_void, ppprocess: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebug", "OpenVirtualProcess", 3usize, |this| ((**(this as *const *const IJsDebug_Vtbl)).OpenVirtualProcess)(this, processid, runtimejsbaseaddress, pdatatarget, ppprocess), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OpenVirtualProcess }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IJsDebug as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IJsDebugBreakPoint_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, pisenabled: *mut super::super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebugBreakPoint", "IsEnabled", 3usize, |this| ((**(this as *const *const IJsDebugBreakPoint_Vtbl)).IsEnabled)(this, pisenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enable(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebugBreakPoint", "Enable", 4usize, |this| ((**(this as *const *const IJsDebugBreakPoint_Vtbl)).Enable)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disable(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebugBreakPoint", "Disable", 5usize, |this| ((**(this as *const *const IJsDebugBreakPoint_Vtbl)).Disable)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebugBreakPoint", "Delete", 6usize, |this| ((**(this as *const *const IJsDebugBreakPoint_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDocumentPosition(this: *mut core::ffi::c_void, pdocumentid: *mut u64, pcharacteroffset: *mut u32, pstatementcharcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebugBreakPoint", "GetDocumentPosition", 7usize, |this| ((**(this as *const *const IJsDebugBreakPoint_Vtbl)).GetDocumentPosition)(this, pdocumentid, pcharacteroffset, pstatementcharcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsEnabled, Enable, Disable, Delete, GetDocumentPosition }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IJsDebugBreakPoint as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IJsDebugDataTarget_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReadMemory(this: *mut core::ffi::c_void, address: u64, flags: JsDebugReadMemoryFlags, pbuffer: *mut u8, size: u32, pbytesread: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebugDataTarget", "ReadMemory", 3usize, |this| ((**(this as *const *const IJsDebugDataTarget_Vtbl)).ReadMemory)(this, address, flags, pbuffer, size, pbytesread), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteMemory(this: *mut core::ffi::c_void, address: u64, pmemory: *const u8, size: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebugDataTarget", "WriteMemory", 4usize, |this| ((**(this as *const *const IJsDebugDataTarget_Vtbl)).WriteMemory)(this, address, pmemory, size), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllocateVirtualMemory(this: *mut core::ffi::c_void, address: u64, size: u32, allocationtype: u32, pageprotection: u32, pallocatedaddress: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebugDataTarget", "AllocateVirtualMemory", 5usize, |this| ((**(this as *const *const IJsDebugDataTarget_Vtbl)).AllocateVirtualMemory)(this, address, size, allocationtype, pageprotection, pallocatedaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreeVirtualMemory(this: *mut core::ffi::c_void, address: u64, size: u32, freetype: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebugDataTarget", "FreeVirtualMemory", 6usize, |this| ((**(this as *const *const IJsDebugDataTarget_Vtbl)).FreeVirtualMemory)(this, address, size, freetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTlsValue(this: *mut core::ffi::c_void, threadid: u32, tlsindex: u32, pvalue: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebugDataTarget", "GetTlsValue", 7usize, |this| ((**(this as *const *const IJsDebugDataTarget_Vtbl)).GetTlsValue)(this, threadid, tlsindex, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadBSTR(this: *mut core::ffi::c_void, address: u64, pstring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebugDataTarget", "ReadBSTR", 8usize, |this| ((**(this as *const *const IJsDebugDataTarget_Vtbl)).ReadBSTR)(this, address, pstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadNullTerminatedString(this: *mut core::ffi::c_void, address: u64, charactersize: u16, maxcharacters: u32, pstring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebugDataTarget", "ReadNullTerminatedString", 9usize, |this| ((**(this as *const *const IJsDebugDataTarget_Vtbl)).ReadNullTerminatedString)(this, address, charactersize, maxcharacters, pstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateStackFrameEnumerator(this: *mut core::ffi::c_void, threadid: u32, ppenumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebugDataTarget", "CreateStackFrameEnumerator", 10usize, |this| ((**(this as *const *const IJsDebugDataTarget_Vtbl)).CreateStackFrameEnumerator)(this, threadid, ppenumerator), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetThreadContext(this: *mut core::ffi::c_void, threadid: u32, contextflags: u32, contextsize: u32, pcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebugDataTarget", "GetThreadContext", 11usize, |this| ((**(this as *const *const IJsDebugDataTarget_Vtbl)).GetThreadContext)(this, threadid, contextflags, contextsize, pcontext), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReadMemory,
+            WriteMemory,
+            AllocateVirtualMemory,
+            FreeVirtualMemory,
+            GetTlsValue,
+            ReadBSTR,
+            ReadNullTerminatedString,
+            CreateStackFrameEnumerator,
+            GetThreadContext,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IJsDebugDataTarget as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IJsDebugFrame_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStackRange(this: *mut core::ffi::c_void, pstart: *mut u64, pend: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebugFrame", "GetStackRange", 3usize, |this| ((**(this as *const *const IJsDebugFrame_Vtbl)).GetStackRange)(this, pstart, pend), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, pname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebugFrame", "GetName", 4usize, |this| ((**(this as *const *const IJsDebugFrame_Vtbl)).GetName)(this, pname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDocumentPositionWithId(this: *mut core::ffi::c_void, pdocumentid: *mut u64, pcharacteroffset: *mut u32, pstatementcharcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebugFrame", "GetDocumentPositionWithId", 5usize, |this| ((**(this as *const *const IJsDebugFrame_Vtbl)).GetDocumentPositionWithId)(this, pdocumentid, pcharacteroffset, pstatementcharcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDocumentPositionWithName(this: *mut core::ffi::c_void, pdocumentname: *mut core::mem::MaybeUninit<windows_core::BSTR>, pline: *mut u32, pcolumn: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebugFrame", "GetDocumentPositionWithName", 6usize, |this| ((**(this as *const *const IJsDebugFrame_Vtbl)).GetDocumentPositionWithName)(this, pdocumentname, pline, pcolumn), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDebugProperty(this: *mut core::ffi::c_void, ppdebugproperty: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebugFrame", "GetDebugProperty", 7usize, |this| ((**(this as *const *const IJsDebugFrame_Vtbl)).GetDebugProperty)(this, ppdebugproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReturnAddress(this: *mut core::ffi::c_void, preturnaddress: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebugFrame", "GetReturnAddress", 8usize, |this| ((**(this as *const *const IJsDebugFrame_Vtbl)).GetReturnAddress)(this, preturnaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn Evaluate(this: *mut core::ffi::c_void, pexpressiontext: windows_core::PCWSTR, ppdebugproperty: *mut *mut core::ffi::c_void, perror: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebugFrame", "Evaluate", 9usize, |this| ((**(this as *const *const IJsDebugFrame_Vtbl)).Evaluate)(this, pexpressiontext, ppdebugproperty, perror), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetStackRange,
+            GetName,
+            GetDocumentPositionWithId,
+            GetDocumentPositionWithName,
+            GetDebugProperty,
+            GetReturnAddress,
+            Evaluate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IJsDebugFrame as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IJsDebugProcess_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateStackWalker(this: *mut core::ffi::c_void, threadid: u32, ppstackwalker: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebugProcess", "CreateStackWalker", 3usize, |this| ((**(this as *const *const IJsDebugProcess_Vtbl)).CreateStackWalker)(this, threadid, ppstackwalker), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBreakPoint(this: *mut core::ffi::c_void, documentid: u64, characteroffset: u32, charactercount: u32, isenabled: super::super::super::super::Foundation::BOOL, ppdebugbreakpoint: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebugProcess", "CreateBreakPoint", 4usize, |this| ((**(this as *const *const IJsDebugProcess_Vtbl)).CreateBreakPoint)(this, documentid, characteroffset, charactercount, isenabled, ppdebugbreakpoint), |result| Some(*result))
+        }
+        unsafe extern "system" fn PerformAsyncBreak(this: *mut core::ffi::c_void, threadid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebugProcess", "PerformAsyncBreak", 5usize, |this| ((**(this as *const *const IJsDebugProcess_Vtbl)).PerformAsyncBreak)(this, threadid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExternalStepAddress(this: *mut core::ffi::c_void, pcodeaddress: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebugProcess", "GetExternalStepAddress", 6usize, |this| ((**(this as *const *const IJsDebugProcess_Vtbl)).GetExternalStepAddress)(this, pcodeaddress), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateStackWalker,
+            CreateBreakPoint,
+            PerformAsyncBreak,
+            GetExternalStepAddress,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IJsDebugProcess as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IJsDebugProperty_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPropertyInfo(this: *mut core::ffi::c_void, nradix: u32, ppropertyinfo: *mut JsDebugPropertyInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebugProperty", "GetPropertyInfo", 3usize, |this| ((**(this as *const *const IJsDebugProperty_Vtbl)).GetPropertyInfo)(this, nradix, ppropertyinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMembers(this: *mut core::ffi::c_void, members: JS_PROPERTY_MEMBERS, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebugProperty", "GetMembers", 4usize, |this| ((**(this as *const *const IJsDebugProperty_Vtbl)).GetMembers)(this, members, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPropertyInfo, GetMembers }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IJsDebugProperty as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IJsDebugStackWalker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNext(this: *mut core::ffi::c_void, ppframe: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsDebugStackWalker", "GetNext", 3usize, |this| ((**(this as *const *const IJsDebugStackWalker_Vtbl)).GetNext)(this, ppframe), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetNext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IJsDebugStackWalker as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IJsEnumDebugProperty_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, count: u32, ppdebugproperty: *mut *mut core::ffi::c_void, pactualcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsEnumDebugProperty", "Next", 3usize, |this| ((**(this as *const *const IJsEnumDebugProperty_Vtbl)).Next)(this, count, ppdebugproperty, pactualcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IJsEnumDebugProperty", "GetCount", 4usize, |this| ((**(this as *const *const IJsEnumDebugProperty_Vtbl)).GetCount)(this, pcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, GetCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IJsEnumDebugProperty as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMachineDebugManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddApplication(this: *mut core::ffi::c_void, pda: *mut core::ffi::c_void, pdwappcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMachineDebugManager", "AddApplication", 3usize, |this| ((**(this as *const *const IMachineDebugManager_Vtbl)).AddApplication)(this, pda, pdwappcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveApplication(this: *mut core::ffi::c_void, dwappcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMachineDebugManager", "RemoveApplication", 4usize, |this| ((**(this as *const *const IMachineDebugManager_Vtbl)).RemoveApplication)(this, dwappcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumApplications(this: *mut core::ffi::c_void, ppeda: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMachineDebugManager", "EnumApplications", 5usize, |this| ((**(this as *const *const IMachineDebugManager_Vtbl)).EnumApplications)(this, ppeda), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddApplication, RemoveApplication, EnumApplications }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMachineDebugManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMachineDebugManagerCookie_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddApplication(this: *mut core::ffi::c_void, pda: *mut core::ffi::c_void, dwdebugappcookie: u32, pdwappcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMachineDebugManagerCookie", "AddApplication", 3usize, |this| ((**(this as *const *const IMachineDebugManagerCookie_Vtbl)).AddApplication)(this, pda, dwdebugappcookie, pdwappcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveApplication(this: *mut core::ffi::c_void, dwdebugappcookie: u32, dwappcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMachineDebugManagerCookie", "RemoveApplication", 4usize, |this| ((**(this as *const *const IMachineDebugManagerCookie_Vtbl)).RemoveApplication)(this, dwdebugappcookie, dwappcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumApplications(this: *mut core::ffi::c_void, ppeda: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMachineDebugManagerCookie", "EnumApplications", 5usize, |this| ((**(this as *const *const IMachineDebugManagerCookie_Vtbl)).EnumApplications)(this, ppeda), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddApplication, RemoveApplication, EnumApplications }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMachineDebugManagerCookie as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMachineDebugManagerEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn onAddApplication(this: *mut core::ffi::c_void, pda: *mut core::ffi::c_void, dwappcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMachineDebugManagerEvents", "onAddApplication", 3usize, |this| ((**(this as *const *const IMachineDebugManagerEvents_Vtbl)).onAddApplication)(this, pda, dwappcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn onRemoveApplication(this: *mut core::ffi::c_void, pda: *mut core::ffi::c_void, dwappcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMachineDebugManagerEvents", "onRemoveApplication", 4usize, |this| ((**(this as *const *const IMachineDebugManagerEvents_Vtbl)).onRemoveApplication)(this, pda, dwappcookie), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, onAddApplication, onRemoveApplication }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMachineDebugManagerEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProcessDebugManager32_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateApplication(this: *mut core::ffi::c_void, ppda: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDebugManager32", "CreateApplication", 3usize, |this| ((**(this as *const *const IProcessDebugManager32_Vtbl)).CreateApplication)(this, ppda), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultApplication(this: *mut core::ffi::c_void, ppda: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDebugManager32", "GetDefaultApplication", 4usize, |this| ((**(this as *const *const IProcessDebugManager32_Vtbl)).GetDefaultApplication)(this, ppda), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddApplication(this: *mut core::ffi::c_void, pda: *mut core::ffi::c_void, pdwappcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDebugManager32", "AddApplication", 5usize, |this| ((**(this as *const *const IProcessDebugManager32_Vtbl)).AddApplication)(this, pda, pdwappcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveApplication(this: *mut core::ffi::c_void, dwappcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDebugManager32", "RemoveApplication", 6usize, |this| ((**(this as *const *const IProcessDebugManager32_Vtbl)).RemoveApplication)(this, dwappcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDebugDocumentHelper(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, pddh: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDebugManager32", "CreateDebugDocumentHelper", 7usize, |this| ((**(this as *const *const IProcessDebugManager32_Vtbl)).CreateDebugDocumentHelper)(this, punkouter, pddh), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateApplication,
+            GetDefaultApplication,
+            AddApplication,
+            RemoveApplication,
+            CreateDebugDocumentHelper,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProcessDebugManager32 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProcessDebugManager64_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateApplication(this: *mut core::ffi::c_void, ppda: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDebugManager64", "CreateApplication", 3usize, |this| ((**(this as *const *const IProcessDebugManager64_Vtbl)).CreateApplication)(this, ppda), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultApplication(this: *mut core::ffi::c_void, ppda: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDebugManager64", "GetDefaultApplication", 4usize, |this| ((**(this as *const *const IProcessDebugManager64_Vtbl)).GetDefaultApplication)(this, ppda), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddApplication(this: *mut core::ffi::c_void, pda: *mut core::ffi::c_void, pdwappcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDebugManager64", "AddApplication", 5usize, |this| ((**(this as *const *const IProcessDebugManager64_Vtbl)).AddApplication)(this, pda, pdwappcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveApplication(this: *mut core::ffi::c_void, dwappcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDebugManager64", "RemoveApplication", 6usize, |this| ((**(this as *const *const IProcessDebugManager64_Vtbl)).RemoveApplication)(this, dwappcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDebugDocumentHelper(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, pddh: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProcessDebugManager64", "CreateDebugDocumentHelper", 7usize, |this| ((**(this as *const *const IProcessDebugManager64_Vtbl)).CreateDebugDocumentHelper)(this, punkouter, pddh), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateApplication,
+            GetDefaultApplication,
+            AddApplication,
+            RemoveApplication,
+            CreateDebugDocumentHelper,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProcessDebugManager64 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProvideExpressionContexts_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumExpressionContexts(this: *mut core::ffi::c_void, ppedec: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProvideExpressionContexts", "EnumExpressionContexts", 3usize, |this| ((**(this as *const *const IProvideExpressionContexts_Vtbl)).EnumExpressionContexts)(this, ppedec), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnumExpressionContexts }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProvideExpressionContexts as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteDebugApplication_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResumeFromBreakPoint(this: *mut core::ffi::c_void, prptfocus: *mut core::ffi::c_void, bra: BREAKRESUMEACTION, era: ERRORRESUMEACTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplication", "ResumeFromBreakPoint", 3usize, |this| ((**(this as *const *const IRemoteDebugApplication_Vtbl)).ResumeFromBreakPoint)(this, prptfocus, bra, era), |result| Some(*result))
+        }
+        unsafe extern "system" fn CauseBreak(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplication", "CauseBreak", 4usize, |this| ((**(this as *const *const IRemoteDebugApplication_Vtbl)).CauseBreak)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectDebugger(this: *mut core::ffi::c_void, pad: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplication", "ConnectDebugger", 5usize, |this| ((**(this as *const *const IRemoteDebugApplication_Vtbl)).ConnectDebugger)(this, pad), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisconnectDebugger(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplication", "DisconnectDebugger", 6usize, |this| ((**(this as *const *const IRemoteDebugApplication_Vtbl)).DisconnectDebugger)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDebugger(this: *mut core::ffi::c_void, pad: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplication", "GetDebugger", 7usize, |this| ((**(this as *const *const IRemoteDebugApplication_Vtbl)).GetDebugger)(this, pad), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInstanceAtApplication(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, punkouter: *mut core::ffi::c_void, dwclscontext: u32, riid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplication", "CreateInstanceAtApplication", 8usize, |this| ((**(this as *const *const IRemoteDebugApplication_Vtbl)).CreateInstanceAtApplication)(this, rclsid, punkouter, dwclscontext, riid, ppvobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryAlive(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplication", "QueryAlive", 9usize, |this| ((**(this as *const *const IRemoteDebugApplication_Vtbl)).QueryAlive)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumThreads(this: *mut core::ffi::c_void, pperdat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplication", "EnumThreads", 10usize, |this| ((**(this as *const *const IRemoteDebugApplication_Vtbl)).EnumThreads)(this, pperdat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplication", "GetName", 11usize, |this| ((**(this as *const *const IRemoteDebugApplication_Vtbl)).GetName)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRootNode(this: *mut core::ffi::c_void, ppdanroot: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplication", "GetRootNode", 12usize, |this| ((**(this as *const *const IRemoteDebugApplication_Vtbl)).GetRootNode)(this, ppdanroot), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumGlobalExpressionContexts(this: *mut core::ffi::c_void, ppedec: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplication", "EnumGlobalExpressionContexts", 13usize, |this| ((**(this as *const *const IRemoteDebugApplication_Vtbl)).EnumGlobalExpressionContexts)(this, ppedec), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ResumeFromBreakPoint,
+            CauseBreak,
+            ConnectDebugger,
+            DisconnectDebugger,
+            GetDebugger,
+            CreateInstanceAtApplication,
+            QueryAlive,
+            EnumThreads,
+            GetName,
+            GetRootNode,
+            EnumGlobalExpressionContexts,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteDebugApplication as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteDebugApplication110_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDebuggerOptions(this: *mut core::ffi::c_void, mask: SCRIPT_DEBUGGER_OPTIONS, value: SCRIPT_DEBUGGER_OPTIONS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplication110", "SetDebuggerOptions", 3usize, |this| ((**(this as *const *const IRemoteDebugApplication110_Vtbl)).SetDebuggerOptions)(this, mask, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentDebuggerOptions(this: *mut core::ffi::c_void, pcurrentoptions: *mut SCRIPT_DEBUGGER_OPTIONS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplication110", "GetCurrentDebuggerOptions", 4usize, |this| ((**(this as *const *const IRemoteDebugApplication110_Vtbl)).GetCurrentDebuggerOptions)(this, pcurrentoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMainThread(this: *mut core::ffi::c_void, ppthread: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplication110", "GetMainThread", 5usize, |this| ((**(this as *const *const IRemoteDebugApplication110_Vtbl)).GetMainThread)(this, ppthread), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetDebuggerOptions, GetCurrentDebuggerOptions, GetMainThread }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteDebugApplication110 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteDebugApplicationEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnConnectDebugger(this: *mut core::ffi::c_void, pad: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplicationEvents", "OnConnectDebugger", 3usize, |this| ((**(this as *const *const IRemoteDebugApplicationEvents_Vtbl)).OnConnectDebugger)(this, pad), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDisconnectDebugger(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplicationEvents", "OnDisconnectDebugger", 4usize, |this| ((**(this as *const *const IRemoteDebugApplicationEvents_Vtbl)).OnDisconnectDebugger)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnSetName(this: *mut core::ffi::c_void, pstrname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplicationEvents", "OnSetName", 5usize, |this| ((**(this as *const *const IRemoteDebugApplicationEvents_Vtbl)).OnSetName)(this, pstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDebugOutput(this: *mut core::ffi::c_void, pstr: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplicationEvents", "OnDebugOutput", 6usize, |this| ((**(this as *const *const IRemoteDebugApplicationEvents_Vtbl)).OnDebugOutput)(this, pstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnClose(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplicationEvents", "OnClose", 7usize, |this| ((**(this as *const *const IRemoteDebugApplicationEvents_Vtbl)).OnClose)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnEnterBreakPoint(this: *mut core::ffi::c_void, prdat: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplicationEvents", "OnEnterBreakPoint", 8usize, |this| ((**(this as *const *const IRemoteDebugApplicationEvents_Vtbl)).OnEnterBreakPoint)(this, prdat), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnLeaveBreakPoint(this: *mut core::ffi::c_void, prdat: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplicationEvents", "OnLeaveBreakPoint", 9usize, |this| ((**(this as *const *const IRemoteDebugApplicationEvents_Vtbl)).OnLeaveBreakPoint)(this, prdat), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnCreateThread(this: *mut core::ffi::c_void, prdat: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplicationEvents", "OnCreateThread", 10usize, |this| ((**(this as *const *const IRemoteDebugApplicationEvents_Vtbl)).OnCreateThread)(this, prdat), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDestroyThread(this: *mut core::ffi::c_void, prdat: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplicationEvents", "OnDestroyThread", 11usize, |this| ((**(this as *const *const IRemoteDebugApplicationEvents_Vtbl)).OnDestroyThread)(this, prdat), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnBreakFlagChange(this: *mut core::ffi::c_void, abf: u32, prdatsteppingthread: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplicationEvents", "OnBreakFlagChange", 12usize, |this| ((**(this as *const *const IRemoteDebugApplicationEvents_Vtbl)).OnBreakFlagChange)(this, abf, prdatsteppingthread), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnConnectDebugger,
+            OnDisconnectDebugger,
+            OnSetName,
+            OnDebugOutput,
+            OnClose,
+            OnEnterBreakPoint,
+            OnLeaveBreakPoint,
+            OnCreateThread,
+            OnDestroyThread,
+            OnBreakFlagChange,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteDebugApplicationEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteDebugApplicationThread_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSystemThreadId(this: *mut core::ffi::c_void, dwthreadid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplicationThread", "GetSystemThreadId", 3usize, |this| ((**(this as *const *const IRemoteDebugApplicationThread_Vtbl)).GetSystemThreadId)(this, dwthreadid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetApplication(this: *mut core::ffi::c_void, pprda: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplicationThread", "GetApplication", 4usize, |this| ((**(this as *const *const IRemoteDebugApplicationThread_Vtbl)).GetApplication)(this, pprda), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumStackFrames(this: *mut core::ffi::c_void, ppedsf: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplicationThread", "EnumStackFrames", 5usize, |this| ((**(this as *const *const IRemoteDebugApplicationThread_Vtbl)).EnumStackFrames)(this, ppedsf), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescription(this: *mut core::ffi::c_void, pbstrdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrstate: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplicationThread", "GetDescription", 6usize, |this| ((**(this as *const *const IRemoteDebugApplicationThread_Vtbl)).GetDescription)(this, pbstrdescription, pbstrstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNextStatement(this: *mut core::ffi::c_void, pstackframe: *mut core::ffi::c_void, pcodecontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplicationThread", "SetNextStatement", 7usize, |this| ((**(this as *const *const IRemoteDebugApplicationThread_Vtbl)).SetNextStatement)(this, pstackframe, pcodecontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetState(this: *mut core::ffi::c_void, pstate: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplicationThread", "GetState", 8usize, |this| ((**(this as *const *const IRemoteDebugApplicationThread_Vtbl)).GetState)(this, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Suspend(this: *mut core::ffi::c_void, pdwcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplicationThread", "Suspend", 9usize, |this| ((**(this as *const *const IRemoteDebugApplicationThread_Vtbl)).Suspend)(this, pdwcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void, pdwcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplicationThread", "Resume", 10usize, |this| ((**(this as *const *const IRemoteDebugApplicationThread_Vtbl)).Resume)(this, pdwcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSuspendCount(this: *mut core::ffi::c_void, pdwcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugApplicationThread", "GetSuspendCount", 11usize, |this| ((**(this as *const *const IRemoteDebugApplicationThread_Vtbl)).GetSuspendCount)(this, pdwcount), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSystemThreadId,
+            GetApplication,
+            EnumStackFrames,
+            GetDescription,
+            SetNextStatement,
+            GetState,
+            Suspend,
+            Resume,
+            GetSuspendCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteDebugApplicationThread as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteDebugCriticalErrorEvent110_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetErrorInfo(this: *mut core::ffi::c_void, pbstrsource: *mut core::mem::MaybeUninit<windows_core::BSTR>, pmessageid: *mut i32, pbstrmessage: *mut core::mem::MaybeUninit<windows_core::BSTR>, pplocation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugCriticalErrorEvent110", "GetErrorInfo", 3usize, |this| ((**(this as *const *const IRemoteDebugCriticalErrorEvent110_Vtbl)).GetErrorInfo)(this, pbstrsource, pmessageid, pbstrmessage, pplocation), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetErrorInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteDebugCriticalErrorEvent110 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteDebugInfoEvent110_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEventInfo(this: *mut core::ffi::c_void, pmessagetype: *mut DEBUG_EVENT_INFO_TYPE, pbstrmessage: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrurl: *mut core::mem::MaybeUninit<windows_core::BSTR>, pplocation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDebugInfoEvent110", "GetEventInfo", 3usize, |this| ((**(this as *const *const IRemoteDebugInfoEvent110_Vtbl)).GetEventInfo)(this, pmessagetype, pbstrmessage, pbstrurl, pplocation), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetEventInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteDebugInfoEvent110 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IScriptEntry_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetText(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptEntry", "GetText", 13usize, |this| ((**(this as *const *const IScriptEntry_Vtbl)).GetText)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetText(this: *mut core::ffi::c_void, psz: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptEntry", "SetText", 14usize, |this| ((**(this as *const *const IScriptEntry_Vtbl)).SetText)(this, psz), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBody(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptEntry", "GetBody", 15usize, |this| ((**(this as *const *const IScriptEntry_Vtbl)).GetBody)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBody(this: *mut core::ffi::c_void, psz: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptEntry", "SetBody", 16usize, |this| ((**(this as *const *const IScriptEntry_Vtbl)).SetBody)(this, psz), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptEntry", "GetName", 17usize, |this| ((**(this as *const *const IScriptEntry_Vtbl)).GetName)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, psz: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptEntry", "SetName", 18usize, |this| ((**(this as *const *const IScriptEntry_Vtbl)).SetName)(this, psz), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItemName(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptEntry", "GetItemName", 19usize, |this| ((**(this as *const *const IScriptEntry_Vtbl)).GetItemName)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetItemName(this: *mut core::ffi::c_void, psz: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptEntry", "SetItemName", 20usize, |this| ((**(this as *const *const IScriptEntry_Vtbl)).SetItemName)(this, psz), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSignature(this: *mut core::ffi::c_void, ppti: *mut *mut core::ffi::c_void, pimethod: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptEntry", "GetSignature", 21usize, |this| ((**(this as *const *const IScriptEntry_Vtbl)).GetSignature)(this, ppti, pimethod), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSignature(this: *mut core::ffi::c_void, pti: *mut core::ffi::c_void, imethod: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptEntry", "SetSignature", 22usize, |this| ((**(this as *const *const IScriptEntry_Vtbl)).SetSignature)(this, pti, imethod), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRange(this: *mut core::ffi::c_void, pichmin: *mut u32, pcch: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptEntry", "GetRange", 23usize, |this| ((**(this as *const *const IScriptEntry_Vtbl)).GetRange)(this, pichmin, pcch), |result| Some(*result))
+        }
+        Self {
+            base__: <IScriptNode_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetText,
+            SetText,
+            GetBody,
+            SetBody,
+            GetName,
+            SetName,
+            GetItemName,
+            SetItemName,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSignature,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSignature: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSignature,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSignature: 0,
+            GetRange,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScriptEntry as windows_core::Interface>::IID || <IScriptNode_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IScriptInvocationContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetContextType(this: *mut core::ffi::c_void, pinvocationcontexttype: *mut SCRIPT_INVOCATION_CONTEXT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptInvocationContext", "GetContextType", 3usize, |this| ((**(this as *const *const IScriptInvocationContext_Vtbl)).GetContextType)(this, pinvocationcontexttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContextDescription(this: *mut core::ffi::c_void, pdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptInvocationContext", "GetContextDescription", 4usize, |this| ((**(this as *const *const IScriptInvocationContext_Vtbl)).GetContextDescription)(this, pdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContextObject(this: *mut core::ffi::c_void, ppcontextobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptInvocationContext", "GetContextObject", 5usize, |this| ((**(this as *const *const IScriptInvocationContext_Vtbl)).GetContextObject)(this, ppcontextobject), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetContextType, GetContextDescription, GetContextObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScriptInvocationContext as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IScriptNode_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Alive(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptNode", "Alive", 3usize, |this| ((**(this as *const *const IScriptNode_Vtbl)).Alive)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptNode", "Delete", 4usize, |this| ((**(this as *const *const IScriptNode_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParent(this: *mut core::ffi::c_void, ppsnparent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptNode", "GetParent", 5usize, |this| ((**(this as *const *const IScriptNode_Vtbl)).GetParent)(this, ppsnparent), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIndexInParent(this: *mut core::ffi::c_void, pisn: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptNode", "GetIndexInParent", 6usize, |this| ((**(this as *const *const IScriptNode_Vtbl)).GetIndexInParent)(this, pisn), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCookie(this: *mut core::ffi::c_void, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptNode", "GetCookie", 7usize, |this| ((**(this as *const *const IScriptNode_Vtbl)).GetCookie)(this, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumberOfChildren(this: *mut core::ffi::c_void, pcsn: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptNode", "GetNumberOfChildren", 8usize, |this| ((**(this as *const *const IScriptNode_Vtbl)).GetNumberOfChildren)(this, pcsn), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChild(this: *mut core::ffi::c_void, isn: u32, ppsn: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptNode", "GetChild", 9usize, |this| ((**(this as *const *const IScriptNode_Vtbl)).GetChild)(this, isn, ppsn), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLanguage(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptNode", "GetLanguage", 10usize, |this| ((**(this as *const *const IScriptNode_Vtbl)).GetLanguage)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateChildEntry(this: *mut core::ffi::c_void, isn: u32, dwcookie: u32, pszdelimiter: windows_core::PCWSTR, ppse: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptNode", "CreateChildEntry", 11usize, |this| ((**(this as *const *const IScriptNode_Vtbl)).CreateChildEntry)(this, isn, dwcookie, pszdelimiter, ppse), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateChildHandler(this: *mut core::ffi::c_void, pszdefaultname: windows_core::PCWSTR, prgpsznames: *const windows_core::PCWSTR, cpsznames: u32, pszevent: windows_core::PCWSTR, pszdelimiter: windows_core::PCWSTR, ptisignature: *mut core::ffi::c_void, imethodsignature: u32, isn: u32, dwcookie: u32, ppse: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptNode", "CreateChildHandler", 12usize, |this| ((**(this as *const *const IScriptNode_Vtbl)).CreateChildHandler)(this, pszdefaultname, prgpsznames, cpsznames, pszevent, pszdelimiter, ptisignature, imethodsignature, isn, dwcookie, ppse), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Alive,
+            Delete,
+            GetParent,
+            GetIndexInParent,
+            GetCookie,
+            GetNumberOfChildren,
+            GetChild,
+            GetLanguage,
+            CreateChildEntry,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateChildHandler,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateChildHandler: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScriptNode as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IScriptScriptlet_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSubItemName(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptScriptlet", "GetSubItemName", 24usize, |this| ((**(this as *const *const IScriptScriptlet_Vtbl)).GetSubItemName)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubItemName(this: *mut core::ffi::c_void, psz: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptScriptlet", "SetSubItemName", 25usize, |this| ((**(this as *const *const IScriptScriptlet_Vtbl)).SetSubItemName)(this, psz), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEventName(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptScriptlet", "GetEventName", 26usize, |this| ((**(this as *const *const IScriptScriptlet_Vtbl)).GetEventName)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventName(this: *mut core::ffi::c_void, psz: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptScriptlet", "SetEventName", 27usize, |this| ((**(this as *const *const IScriptScriptlet_Vtbl)).SetEventName)(this, psz), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSimpleEventName(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptScriptlet", "GetSimpleEventName", 28usize, |this| ((**(this as *const *const IScriptScriptlet_Vtbl)).GetSimpleEventName)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSimpleEventName(this: *mut core::ffi::c_void, psz: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScriptScriptlet", "SetSimpleEventName", 29usize, |this| ((**(this as *const *const IScriptScriptlet_Vtbl)).SetSimpleEventName)(this, psz), |result| Some(*result))
+        }
+        Self {
+            base__: <IScriptEntry_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSubItemName,
+            SetSubItemName,
+            GetEventName,
+            SetEventName,
+            GetSimpleEventName,
+            SetSimpleEventName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScriptScriptlet as windows_core::Interface>::IID || <IScriptEntry_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISimpleConnectionPoint_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEventCount(this: *mut core::ffi::c_void, pulcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleConnectionPoint", "GetEventCount", 3usize, |this| ((**(this as *const *const ISimpleConnectionPoint_Vtbl)).GetEventCount)(this, pulcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn DescribeEvents(this: *mut core::ffi::c_void, ievent: u32, cevents: u32, prgid: *mut i32, prgbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>, pceventsfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleConnectionPoint", "DescribeEvents", 4usize, |this| ((**(this as *const *const ISimpleConnectionPoint_Vtbl)).DescribeEvents)(this, ievent, cevents, prgid, prgbstr, pceventsfetched), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Advise(this: *mut core::ffi::c_void, pdisp: *mut core::ffi::c_void, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleConnectionPoint", "Advise", 5usize, |this| ((**(this as *const *const ISimpleConnectionPoint_Vtbl)).Advise)(this, pdisp, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unadvise(this: *mut core::ffi::c_void, dwcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleConnectionPoint", "Unadvise", 6usize, |this| ((**(this as *const *const ISimpleConnectionPoint_Vtbl)).Unadvise)(this, dwcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetEventCount,
+            DescribeEvents,
+            #[cfg(feature = "Win32_System_Com")]
+            Advise,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Advise: 0,
+            Unadvise,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISimpleConnectionPoint as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITridentEventSink_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn FireEvent(this: *mut core::ffi::c_void, pstrevent: windows_core::PCWSTR, pdp: *const super::super::super::Com::DISPPARAMS, pvarres: *mut core::mem::MaybeUninit<windows_core::VARIANT>, pei: *mut super::super::super::Com::EXCEPINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITridentEventSink", "FireEvent", 3usize, |this| ((**(this as *const *const ITridentEventSink_Vtbl)).FireEvent)(this, pstrevent, pdp, pvarres, pei), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            FireEvent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            FireEvent: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITridentEventSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebAppDiagnosticsObjectInitialization_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, hpassedhandle: super::super::super::super::Foundation::HANDLE_PTR, pdebugapplication: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAppDiagnosticsObjectInitialization", "Initialize", 3usize, |this| ((**(this as *const *const IWebAppDiagnosticsObjectInitialization_Vtbl)).Initialize)(this, hpassedhandle, pdebugapplication), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAppDiagnosticsObjectInitialization as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebAppDiagnosticsSetup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DiagnosticsSupported(this: *mut core::ffi::c_void, pretval: *mut super::super::super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAppDiagnosticsSetup", "DiagnosticsSupported", 3usize, |this| ((**(this as *const *const IWebAppDiagnosticsSetup_Vtbl)).DiagnosticsSupported)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateObjectWithSiteAtWebApp(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, dwclscontext: u32, riid: *const windows_core::GUID, hpasstoobject: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAppDiagnosticsSetup", "CreateObjectWithSiteAtWebApp", 4usize, |this| ((**(this as *const *const IWebAppDiagnosticsSetup_Vtbl)).CreateObjectWithSiteAtWebApp)(this, rclsid, dwclscontext, riid, hpasstoobject), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DiagnosticsSupported, CreateObjectWithSiteAtWebApp }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAppDiagnosticsSetup as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -4522,6 +4522,7 @@ pub struct ADDRESS_MODE(pub i32);
 impl windows_core::TypeKind for ADDRESS_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ADDRESS_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ADDRESS_MODE").field(&self.0).finish()
@@ -4533,6 +4534,7 @@ pub struct BUGCHECK_ERROR(pub u32);
 impl windows_core::TypeKind for BUGCHECK_ERROR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BUGCHECK_ERROR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BUGCHECK_ERROR").field(&self.0).finish()
@@ -4544,6 +4546,7 @@ pub struct CONTEXT_FLAGS(pub u32);
 impl windows_core::TypeKind for CONTEXT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CONTEXT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CONTEXT_FLAGS").field(&self.0).finish()
@@ -4588,6 +4591,7 @@ pub struct DBGPROP_ATTRIB_FLAGS(pub i32);
 impl windows_core::TypeKind for DBGPROP_ATTRIB_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBGPROP_ATTRIB_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBGPROP_ATTRIB_FLAGS").field(&self.0).finish()
@@ -4632,6 +4636,7 @@ pub struct DBGPROP_INFO(pub i32);
 impl windows_core::TypeKind for DBGPROP_INFO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBGPROP_INFO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBGPROP_INFO").field(&self.0).finish()
@@ -4676,6 +4681,7 @@ pub struct DEBUG_EVENT_CODE(pub u32);
 impl windows_core::TypeKind for DEBUG_EVENT_CODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEBUG_EVENT_CODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEBUG_EVENT_CODE").field(&self.0).finish()
@@ -4687,6 +4693,7 @@ pub struct DUMP_TYPE(pub i32);
 impl windows_core::TypeKind for DUMP_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DUMP_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DUMP_TYPE").field(&self.0).finish()
@@ -4698,6 +4705,7 @@ pub struct EX_PROP_INFO_FLAGS(pub i32);
 impl windows_core::TypeKind for EX_PROP_INFO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EX_PROP_INFO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EX_PROP_INFO_FLAGS").field(&self.0).finish()
@@ -4709,6 +4717,7 @@ pub struct FACILITY_CODE(pub u32);
 impl windows_core::TypeKind for FACILITY_CODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FACILITY_CODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FACILITY_CODE").field(&self.0).finish()
@@ -4720,6 +4729,7 @@ pub struct FORMAT_MESSAGE_OPTIONS(pub u32);
 impl windows_core::TypeKind for FORMAT_MESSAGE_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FORMAT_MESSAGE_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FORMAT_MESSAGE_OPTIONS").field(&self.0).finish()
@@ -4764,6 +4774,7 @@ pub struct IMAGEHLP_CBA_EVENT_SEVERITY(pub u32);
 impl windows_core::TypeKind for IMAGEHLP_CBA_EVENT_SEVERITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAGEHLP_CBA_EVENT_SEVERITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAGEHLP_CBA_EVENT_SEVERITY").field(&self.0).finish()
@@ -4775,6 +4786,7 @@ pub struct IMAGEHLP_EXTENDED_OPTIONS(pub i32);
 impl windows_core::TypeKind for IMAGEHLP_EXTENDED_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAGEHLP_EXTENDED_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAGEHLP_EXTENDED_OPTIONS").field(&self.0).finish()
@@ -4786,6 +4798,7 @@ pub struct IMAGEHLP_GET_TYPE_INFO_FLAGS(pub u32);
 impl windows_core::TypeKind for IMAGEHLP_GET_TYPE_INFO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAGEHLP_GET_TYPE_INFO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAGEHLP_GET_TYPE_INFO_FLAGS").field(&self.0).finish()
@@ -4797,6 +4810,7 @@ pub struct IMAGEHLP_HD_TYPE(pub i32);
 impl windows_core::TypeKind for IMAGEHLP_HD_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAGEHLP_HD_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAGEHLP_HD_TYPE").field(&self.0).finish()
@@ -4808,6 +4822,7 @@ pub struct IMAGEHLP_SF_TYPE(pub i32);
 impl windows_core::TypeKind for IMAGEHLP_SF_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAGEHLP_SF_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAGEHLP_SF_TYPE").field(&self.0).finish()
@@ -4819,6 +4834,7 @@ pub struct IMAGEHLP_STATUS_REASON(pub i32);
 impl windows_core::TypeKind for IMAGEHLP_STATUS_REASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAGEHLP_STATUS_REASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAGEHLP_STATUS_REASON").field(&self.0).finish()
@@ -4830,6 +4846,7 @@ pub struct IMAGEHLP_SYMBOL_TYPE_INFO(pub i32);
 impl windows_core::TypeKind for IMAGEHLP_SYMBOL_TYPE_INFO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAGEHLP_SYMBOL_TYPE_INFO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAGEHLP_SYMBOL_TYPE_INFO").field(&self.0).finish()
@@ -4841,6 +4858,7 @@ pub struct IMAGE_DEBUG_TYPE(pub u32);
 impl windows_core::TypeKind for IMAGE_DEBUG_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAGE_DEBUG_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAGE_DEBUG_TYPE").field(&self.0).finish()
@@ -4852,6 +4870,7 @@ pub struct IMAGE_DIRECTORY_ENTRY(pub u16);
 impl windows_core::TypeKind for IMAGE_DIRECTORY_ENTRY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAGE_DIRECTORY_ENTRY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAGE_DIRECTORY_ENTRY").field(&self.0).finish()
@@ -4863,6 +4882,7 @@ pub struct IMAGE_DLL_CHARACTERISTICS(pub u16);
 impl windows_core::TypeKind for IMAGE_DLL_CHARACTERISTICS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAGE_DLL_CHARACTERISTICS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAGE_DLL_CHARACTERISTICS").field(&self.0).finish()
@@ -4907,6 +4927,7 @@ pub struct IMAGE_FILE_CHARACTERISTICS(pub u16);
 impl windows_core::TypeKind for IMAGE_FILE_CHARACTERISTICS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAGE_FILE_CHARACTERISTICS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAGE_FILE_CHARACTERISTICS").field(&self.0).finish()
@@ -4951,6 +4972,7 @@ pub struct IMAGE_FILE_CHARACTERISTICS2(pub u32);
 impl windows_core::TypeKind for IMAGE_FILE_CHARACTERISTICS2 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAGE_FILE_CHARACTERISTICS2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAGE_FILE_CHARACTERISTICS2").field(&self.0).finish()
@@ -4995,6 +5017,7 @@ pub struct IMAGE_OPTIONAL_HEADER_MAGIC(pub u16);
 impl windows_core::TypeKind for IMAGE_OPTIONAL_HEADER_MAGIC {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAGE_OPTIONAL_HEADER_MAGIC {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAGE_OPTIONAL_HEADER_MAGIC").field(&self.0).finish()
@@ -5006,6 +5029,7 @@ pub struct IMAGE_SECTION_CHARACTERISTICS(pub u32);
 impl windows_core::TypeKind for IMAGE_SECTION_CHARACTERISTICS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAGE_SECTION_CHARACTERISTICS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAGE_SECTION_CHARACTERISTICS").field(&self.0).finish()
@@ -5050,6 +5074,7 @@ pub struct IMAGE_SUBSYSTEM(pub u16);
 impl windows_core::TypeKind for IMAGE_SUBSYSTEM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAGE_SUBSYSTEM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAGE_SUBSYSTEM").field(&self.0).finish()
@@ -5061,6 +5086,7 @@ pub struct IPMI_OS_SEL_RECORD_TYPE(pub i32);
 impl windows_core::TypeKind for IPMI_OS_SEL_RECORD_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IPMI_OS_SEL_RECORD_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IPMI_OS_SEL_RECORD_TYPE").field(&self.0).finish()
@@ -5072,6 +5098,7 @@ pub struct MINIDUMP_CALLBACK_TYPE(pub i32);
 impl windows_core::TypeKind for MINIDUMP_CALLBACK_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MINIDUMP_CALLBACK_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MINIDUMP_CALLBACK_TYPE").field(&self.0).finish()
@@ -5083,6 +5110,7 @@ pub struct MINIDUMP_HANDLE_OBJECT_INFORMATION_TYPE(pub i32);
 impl windows_core::TypeKind for MINIDUMP_HANDLE_OBJECT_INFORMATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MINIDUMP_HANDLE_OBJECT_INFORMATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MINIDUMP_HANDLE_OBJECT_INFORMATION_TYPE").field(&self.0).finish()
@@ -5094,6 +5122,7 @@ pub struct MINIDUMP_MISC_INFO_FLAGS(pub u32);
 impl windows_core::TypeKind for MINIDUMP_MISC_INFO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MINIDUMP_MISC_INFO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MINIDUMP_MISC_INFO_FLAGS").field(&self.0).finish()
@@ -5138,6 +5167,7 @@ pub struct MINIDUMP_SECONDARY_FLAGS(pub i32);
 impl windows_core::TypeKind for MINIDUMP_SECONDARY_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MINIDUMP_SECONDARY_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MINIDUMP_SECONDARY_FLAGS").field(&self.0).finish()
@@ -5149,6 +5179,7 @@ pub struct MINIDUMP_STREAM_TYPE(pub i32);
 impl windows_core::TypeKind for MINIDUMP_STREAM_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MINIDUMP_STREAM_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MINIDUMP_STREAM_TYPE").field(&self.0).finish()
@@ -5160,6 +5191,7 @@ pub struct MINIDUMP_THREAD_INFO_DUMP_FLAGS(pub u32);
 impl windows_core::TypeKind for MINIDUMP_THREAD_INFO_DUMP_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MINIDUMP_THREAD_INFO_DUMP_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MINIDUMP_THREAD_INFO_DUMP_FLAGS").field(&self.0).finish()
@@ -5171,6 +5203,7 @@ pub struct MINIDUMP_TYPE(pub i32);
 impl windows_core::TypeKind for MINIDUMP_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MINIDUMP_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MINIDUMP_TYPE").field(&self.0).finish()
@@ -5215,6 +5248,7 @@ pub struct MODLOAD_DATA_TYPE(pub u32);
 impl windows_core::TypeKind for MODLOAD_DATA_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MODLOAD_DATA_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MODLOAD_DATA_TYPE").field(&self.0).finish()
@@ -5226,6 +5260,7 @@ pub struct MODULE_WRITE_FLAGS(pub i32);
 impl windows_core::TypeKind for MODULE_WRITE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MODULE_WRITE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MODULE_WRITE_FLAGS").field(&self.0).finish()
@@ -5237,6 +5272,7 @@ pub struct OBJECT_ATTRIB_FLAGS(pub i32);
 impl windows_core::TypeKind for OBJECT_ATTRIB_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OBJECT_ATTRIB_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OBJECT_ATTRIB_FLAGS").field(&self.0).finish()
@@ -5248,6 +5284,7 @@ pub struct OPEN_THREAD_WAIT_CHAIN_SESSION_FLAGS(pub u32);
 impl windows_core::TypeKind for OPEN_THREAD_WAIT_CHAIN_SESSION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPEN_THREAD_WAIT_CHAIN_SESSION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPEN_THREAD_WAIT_CHAIN_SESSION_FLAGS").field(&self.0).finish()
@@ -5259,6 +5296,7 @@ pub struct PROP_INFO_FLAGS(pub i32);
 impl windows_core::TypeKind for PROP_INFO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROP_INFO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROP_INFO_FLAGS").field(&self.0).finish()
@@ -5270,6 +5308,7 @@ pub struct RIP_INFO_TYPE(pub u32);
 impl windows_core::TypeKind for RIP_INFO_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RIP_INFO_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RIP_INFO_TYPE").field(&self.0).finish()
@@ -5281,6 +5320,7 @@ pub struct RTL_VIRTUAL_UNWIND_HANDLER_TYPE(pub u32);
 impl windows_core::TypeKind for RTL_VIRTUAL_UNWIND_HANDLER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTL_VIRTUAL_UNWIND_HANDLER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTL_VIRTUAL_UNWIND_HANDLER_TYPE").field(&self.0).finish()
@@ -5292,6 +5332,7 @@ pub struct SYMBOL_INFO_FLAGS(pub u32);
 impl windows_core::TypeKind for SYMBOL_INFO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYMBOL_INFO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYMBOL_INFO_FLAGS").field(&self.0).finish()
@@ -5336,6 +5377,7 @@ pub struct SYM_FIND_ID_OPTION(pub u32);
 impl windows_core::TypeKind for SYM_FIND_ID_OPTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYM_FIND_ID_OPTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYM_FIND_ID_OPTION").field(&self.0).finish()
@@ -5347,6 +5389,7 @@ pub struct SYM_LOAD_FLAGS(pub u32);
 impl windows_core::TypeKind for SYM_LOAD_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYM_LOAD_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYM_LOAD_FLAGS").field(&self.0).finish()
@@ -5391,6 +5434,7 @@ pub struct SYM_SRV_STORE_FILE_FLAGS(pub u32);
 impl windows_core::TypeKind for SYM_SRV_STORE_FILE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYM_SRV_STORE_FILE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYM_SRV_STORE_FILE_FLAGS").field(&self.0).finish()
@@ -5402,6 +5446,7 @@ pub struct SYM_TYPE(pub i32);
 impl windows_core::TypeKind for SYM_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYM_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYM_TYPE").field(&self.0).finish()
@@ -5413,6 +5458,7 @@ pub struct THREAD_ERROR_MODE(pub u32);
 impl windows_core::TypeKind for THREAD_ERROR_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for THREAD_ERROR_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("THREAD_ERROR_MODE").field(&self.0).finish()
@@ -5457,6 +5503,7 @@ pub struct THREAD_WRITE_FLAGS(pub i32);
 impl windows_core::TypeKind for THREAD_WRITE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for THREAD_WRITE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("THREAD_WRITE_FLAGS").field(&self.0).finish()
@@ -5468,6 +5515,7 @@ pub struct VER_PLATFORM(pub u32);
 impl windows_core::TypeKind for VER_PLATFORM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VER_PLATFORM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VER_PLATFORM").field(&self.0).finish()
@@ -5479,6 +5527,7 @@ pub struct WAIT_CHAIN_THREAD_OPTIONS(pub u32);
 impl windows_core::TypeKind for WAIT_CHAIN_THREAD_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WAIT_CHAIN_THREAD_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WAIT_CHAIN_THREAD_OPTIONS").field(&self.0).finish()
@@ -5490,6 +5539,7 @@ pub struct WCT_OBJECT_STATUS(pub i32);
 impl windows_core::TypeKind for WCT_OBJECT_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WCT_OBJECT_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WCT_OBJECT_STATUS").field(&self.0).finish()
@@ -5501,6 +5551,7 @@ pub struct WCT_OBJECT_TYPE(pub i32);
 impl windows_core::TypeKind for WCT_OBJECT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WCT_OBJECT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WCT_OBJECT_TYPE").field(&self.0).finish()
@@ -5512,6 +5563,7 @@ pub struct WHEA_ERROR_SOURCE_STATE(pub i32);
 impl windows_core::TypeKind for WHEA_ERROR_SOURCE_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHEA_ERROR_SOURCE_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHEA_ERROR_SOURCE_STATE").field(&self.0).finish()
@@ -5523,6 +5575,7 @@ pub struct WHEA_ERROR_SOURCE_TYPE(pub i32);
 impl windows_core::TypeKind for WHEA_ERROR_SOURCE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHEA_ERROR_SOURCE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHEA_ERROR_SOURCE_TYPE").field(&self.0).finish()
@@ -5534,6 +5587,7 @@ pub struct WOW64_CONTEXT_FLAGS(pub u32);
 impl windows_core::TypeKind for WOW64_CONTEXT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WOW64_CONTEXT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WOW64_CONTEXT_FLAGS").field(&self.0).finish()
@@ -5574,7 +5628,8 @@ impl core::ops::Not for WOW64_CONTEXT_FLAGS {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ADDRESS {
     pub Offset: u32,
     pub Segment: u16,
@@ -5591,7 +5646,8 @@ impl Default for ADDRESS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ADDRESS64 {
     pub Offset: u64,
     pub Segment: u16,
@@ -5624,6 +5680,56 @@ impl Default for AER_BRIDGE_DESCRIPTOR_FLAGS {
 pub struct AER_BRIDGE_DESCRIPTOR_FLAGS_0 {
     pub _bitfield: u16,
 }
+impl AER_BRIDGE_DESCRIPTOR_FLAGS_0 {
+    pub const fn UncorrectableErrorMaskRW(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_UncorrectableErrorMaskRW(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn UncorrectableErrorSeverityRW(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_UncorrectableErrorSeverityRW(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn CorrectableErrorMaskRW(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_CorrectableErrorMaskRW(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn AdvancedCapsAndControlRW(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_AdvancedCapsAndControlRW(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn SecondaryUncorrectableErrorMaskRW(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_SecondaryUncorrectableErrorMaskRW(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn SecondaryUncorrectableErrorSevRW(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_SecondaryUncorrectableErrorSevRW(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn SecondaryCapsAndControlRW(&self) -> u16 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_SecondaryCapsAndControlRW(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 7) & 511
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(511 << 7)) | ((value & 511) << 7);
+    }
+}
 impl windows_core::TypeKind for AER_BRIDGE_DESCRIPTOR_FLAGS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5651,6 +5757,38 @@ impl Default for AER_ENDPOINT_DESCRIPTOR_FLAGS {
 pub struct AER_ENDPOINT_DESCRIPTOR_FLAGS_0 {
     pub _bitfield: u16,
 }
+impl AER_ENDPOINT_DESCRIPTOR_FLAGS_0 {
+    pub const fn UncorrectableErrorMaskRW(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_UncorrectableErrorMaskRW(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn UncorrectableErrorSeverityRW(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_UncorrectableErrorSeverityRW(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn CorrectableErrorMaskRW(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_CorrectableErrorMaskRW(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn AdvancedCapsAndControlRW(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_AdvancedCapsAndControlRW(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 4) & 4095
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(4095 << 4)) | ((value & 4095) << 4);
+    }
+}
 impl windows_core::TypeKind for AER_ENDPOINT_DESCRIPTOR_FLAGS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5678,6 +5816,44 @@ impl Default for AER_ROOTPORT_DESCRIPTOR_FLAGS {
 pub struct AER_ROOTPORT_DESCRIPTOR_FLAGS_0 {
     pub _bitfield: u16,
 }
+impl AER_ROOTPORT_DESCRIPTOR_FLAGS_0 {
+    pub const fn UncorrectableErrorMaskRW(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_UncorrectableErrorMaskRW(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn UncorrectableErrorSeverityRW(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_UncorrectableErrorSeverityRW(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn CorrectableErrorMaskRW(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_CorrectableErrorMaskRW(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn AdvancedCapsAndControlRW(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_AdvancedCapsAndControlRW(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn RootErrorCommandRW(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_RootErrorCommandRW(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 5) & 2047
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(2047 << 5)) | ((value & 2047) << 5);
+    }
+}
 impl windows_core::TypeKind for AER_ROOTPORT_DESCRIPTOR_FLAGS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5688,7 +5864,8 @@ impl Default for AER_ROOTPORT_DESCRIPTOR_FLAGS_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct APC_CALLBACK_DATA {
     pub Parameter: usize,
     pub ContextRecord: *mut CONTEXT,
@@ -5706,7 +5883,8 @@ impl Default for APC_CALLBACK_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct API_VERSION {
     pub MajorVersion: u16,
     pub MinorVersion: u16,
@@ -5767,7 +5945,8 @@ impl Default for ARM64_NT_CONTEXT_0 {
 }
 #[repr(C)]
 #[cfg(any(target_arch = "arm64ec", target_arch = "x86", target_arch = "x86_64"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ARM64_NT_CONTEXT_0_0 {
     pub X0: u64,
     pub X1: u64,
@@ -5829,7 +6008,8 @@ impl Default for ARM64_NT_NEON128 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ARM64_NT_NEON128_0 {
     pub Low: u64,
     pub High: i64,
@@ -5895,7 +6075,8 @@ impl Default for CONTEXT_0 {
 #[repr(C)]
 #[cfg(target_arch = "aarch64")]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CONTEXT_0_0 {
     pub X0: u64,
     pub X1: u64,
@@ -6028,7 +6209,8 @@ impl Default for CONTEXT_0 {
 #[repr(C)]
 #[cfg(any(target_arch = "arm64ec", target_arch = "x86_64"))]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CONTEXT_0_0 {
     pub Header: [M128A; 2],
     pub Legacy: [M128A; 8],
@@ -6132,7 +6314,8 @@ impl Default for CPU_INFORMATION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CPU_INFORMATION_1 {
     pub VendorId: [u32; 3],
     pub VersionInformation: u32,
@@ -6149,7 +6332,8 @@ impl Default for CPU_INFORMATION_1 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Threading")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CREATE_PROCESS_DEBUG_INFO {
     pub hFile: super::super::super::Foundation::HANDLE,
     pub hProcess: super::super::super::Foundation::HANDLE,
@@ -6174,7 +6358,8 @@ impl Default for CREATE_PROCESS_DEBUG_INFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Threading")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CREATE_THREAD_DEBUG_INFO {
     pub hThread: super::super::super::Foundation::HANDLE,
     pub lpThreadLocalBase: *mut core::ffi::c_void,
@@ -6191,7 +6376,8 @@ impl Default for CREATE_THREAD_DEBUG_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DBGHELP_DATA_REPORT_STRUCT {
     pub pBinPathNonExist: windows_core::PCWSTR,
     pub pSymbolPathNonExist: windows_core::PCWSTR,
@@ -6250,7 +6436,8 @@ impl Default for DEBUG_EVENT_0 {
 #[repr(C)]
 #[cfg(target_arch = "aarch64")]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPATCHER_CONTEXT {
     pub ControlPc: usize,
     pub ImageBase: usize,
@@ -6280,7 +6467,8 @@ impl Default for DISPATCHER_CONTEXT {
 #[repr(C)]
 #[cfg(any(target_arch = "arm64ec", target_arch = "x86_64"))]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPATCHER_CONTEXT {
     pub ControlPc: u64,
     pub ImageBase: u64,
@@ -6321,10 +6509,79 @@ impl Default for DUMP_FILE_ATTRIBUTES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DUMP_FILE_ATTRIBUTES_0 {
     pub _bitfield: u32,
 }
+impl DUMP_FILE_ATTRIBUTES_0 {
+    pub const fn HiberCrash(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_HiberCrash(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn DumpDevicePowerOff(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_DumpDevicePowerOff(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn InsufficientDumpfileSize(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_InsufficientDumpfileSize(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn KernelGeneratedTriageDump(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_KernelGeneratedTriageDump(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn LiveDumpGeneratedDump(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_LiveDumpGeneratedDump(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn DumpIsGeneratedOffline(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_DumpIsGeneratedOffline(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn FilterDumpFile(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_FilterDumpFile(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn EarlyBootCrash(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_EarlyBootCrash(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn EncryptedDumpData(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_EncryptedDumpData(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn DecryptedDump(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_DecryptedDump(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn ReservedFlags(&self) -> u32 {
+        (self._bitfield >> 10) & 4194303
+    }
+    pub fn set_ReservedFlags(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(4194303 << 10)) | ((value & 4194303) << 10);
+    }
+}
 impl windows_core::TypeKind for DUMP_FILE_ATTRIBUTES_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -6460,7 +6717,8 @@ impl Default for DUMP_HEADER64_0 {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DebugPropertyInfo {
     pub m_dwValidFields: u32,
     pub m_bstrName: core::mem::ManuallyDrop<windows_core::BSTR>,
@@ -6484,7 +6742,8 @@ impl Default for DebugPropertyInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EXCEPTION_DEBUG_INFO {
     pub ExceptionRecord: EXCEPTION_RECORD,
     pub dwFirstChance: u32,
@@ -6499,7 +6758,8 @@ impl Default for EXCEPTION_DEBUG_INFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EXCEPTION_POINTERS {
     pub ExceptionRecord: *mut EXCEPTION_RECORD,
     pub ContextRecord: *mut CONTEXT,
@@ -6515,7 +6775,8 @@ impl Default for EXCEPTION_POINTERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EXCEPTION_RECORD {
     pub ExceptionCode: super::super::super::Foundation::NTSTATUS,
     pub ExceptionFlags: u32,
@@ -6533,7 +6794,8 @@ impl Default for EXCEPTION_RECORD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EXCEPTION_RECORD32 {
     pub ExceptionCode: super::super::super::Foundation::NTSTATUS,
     pub ExceptionFlags: u32,
@@ -6551,7 +6813,8 @@ impl Default for EXCEPTION_RECORD32 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EXCEPTION_RECORD64 {
     pub ExceptionCode: super::super::super::Foundation::NTSTATUS,
     pub ExceptionFlags: u32,
@@ -6570,7 +6833,8 @@ impl Default for EXCEPTION_RECORD64 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EXIT_PROCESS_DEBUG_INFO {
     pub dwExitCode: u32,
 }
@@ -6583,7 +6847,8 @@ impl Default for EXIT_PROCESS_DEBUG_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EXIT_THREAD_DEBUG_INFO {
     pub dwExitCode: u32,
 }
@@ -6597,7 +6862,8 @@ impl Default for EXIT_THREAD_DEBUG_INFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com_StructuredStorage")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ExtendedDebugPropertyInfo {
     pub dwValidFields: u32,
     pub pszName: windows_core::PWSTR,
@@ -6629,7 +6895,8 @@ impl Default for ExtendedDebugPropertyInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FPO_DATA {
     pub ulOffStart: u32,
     pub cbProcSize: u32,
@@ -6637,6 +6904,44 @@ pub struct FPO_DATA {
     pub cdwParams: u16,
     pub _bitfield: u16,
 }
+impl FPO_DATA {
+    pub const fn cbProlog(&self) -> u16 {
+        self._bitfield & 255
+    }
+    pub fn set_cbProlog(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn cbRegs(&self) -> u16 {
+        (self._bitfield >> 8) & 7
+    }
+    pub fn set_cbRegs(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(7 << 8)) | ((value & 7) << 8);
+    }
+    pub const fn fHasSEH(&self) -> u16 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_fHasSEH(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn fUseBP(&self) -> u16 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_fUseBP(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn reserved(&self) -> u16 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn cbFrame(&self) -> u16 {
+        (self._bitfield >> 14) & 3
+    }
+    pub fn set_cbFrame(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 14)) | ((value & 3) << 14);
+    }
+}
 impl windows_core::TypeKind for FPO_DATA {
     type TypeKind = windows_core::CopyType;
 }
@@ -6646,7 +6951,8 @@ impl Default for FPO_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_CBA_EVENT {
     pub severity: IMAGEHLP_CBA_EVENT_SEVERITY,
     pub code: u32,
@@ -6662,7 +6968,8 @@ impl Default for IMAGEHLP_CBA_EVENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_CBA_EVENTW {
     pub severity: IMAGEHLP_CBA_EVENT_SEVERITY,
     pub code: u32,
@@ -6678,7 +6985,8 @@ impl Default for IMAGEHLP_CBA_EVENTW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_CBA_READ_MEMORY {
     pub addr: u64,
     pub buf: *mut core::ffi::c_void,
@@ -6695,7 +7003,8 @@ impl Default for IMAGEHLP_CBA_READ_MEMORY {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_DEFERRED_SYMBOL_LOAD {
     pub SizeOfStruct: u32,
     pub BaseOfImage: u32,
@@ -6716,7 +7025,8 @@ impl Default for IMAGEHLP_DEFERRED_SYMBOL_LOAD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_DEFERRED_SYMBOL_LOAD64 {
     pub SizeOfStruct: u32,
     pub BaseOfImage: u64,
@@ -6736,7 +7046,8 @@ impl Default for IMAGEHLP_DEFERRED_SYMBOL_LOAD64 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_DEFERRED_SYMBOL_LOADW64 {
     pub SizeOfStruct: u32,
     pub BaseOfImage: u64,
@@ -6757,7 +7068,8 @@ impl Default for IMAGEHLP_DEFERRED_SYMBOL_LOADW64 {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_DUPLICATE_SYMBOL {
     pub SizeOfStruct: u32,
     pub NumberOfDups: u32,
@@ -6775,7 +7087,8 @@ impl Default for IMAGEHLP_DUPLICATE_SYMBOL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_DUPLICATE_SYMBOL64 {
     pub SizeOfStruct: u32,
     pub NumberOfDups: u32,
@@ -6791,7 +7104,8 @@ impl Default for IMAGEHLP_DUPLICATE_SYMBOL64 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_GET_TYPE_INFO_PARAMS {
     pub SizeOfStruct: u32,
     pub Flags: IMAGEHLP_GET_TYPE_INFO_FLAGS,
@@ -6821,7 +7135,8 @@ impl Default for IMAGEHLP_GET_TYPE_INFO_PARAMS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_JIT_SYMBOLMAP {
     pub SizeOfStruct: u32,
     pub Address: u64,
@@ -6837,7 +7152,8 @@ impl Default for IMAGEHLP_JIT_SYMBOLMAP {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_LINE {
     pub SizeOfStruct: u32,
     pub Key: *mut core::ffi::c_void,
@@ -6856,7 +7172,8 @@ impl Default for IMAGEHLP_LINE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_LINE64 {
     pub SizeOfStruct: u32,
     pub Key: *mut core::ffi::c_void,
@@ -6874,7 +7191,8 @@ impl Default for IMAGEHLP_LINE64 {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_LINEW {
     pub SizeOfStruct: u32,
     pub Key: *mut core::ffi::c_void,
@@ -6893,7 +7211,8 @@ impl Default for IMAGEHLP_LINEW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_LINEW64 {
     pub SizeOfStruct: u32,
     pub Key: *mut core::ffi::c_void,
@@ -6911,7 +7230,8 @@ impl Default for IMAGEHLP_LINEW64 {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_MODULE {
     pub SizeOfStruct: u32,
     pub BaseOfImage: u32,
@@ -6935,7 +7255,8 @@ impl Default for IMAGEHLP_MODULE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_MODULE64 {
     pub SizeOfStruct: u32,
     pub BaseOfImage: u64,
@@ -6972,7 +7293,8 @@ impl Default for IMAGEHLP_MODULE64 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_MODULE64_EX {
     pub Module: IMAGEHLP_MODULE64,
     pub RegionFlags: u32,
@@ -6987,7 +7309,8 @@ impl Default for IMAGEHLP_MODULE64_EX {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_MODULEW {
     pub SizeOfStruct: u32,
     pub BaseOfImage: u32,
@@ -7011,7 +7334,8 @@ impl Default for IMAGEHLP_MODULEW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_MODULEW64 {
     pub SizeOfStruct: u32,
     pub BaseOfImage: u64,
@@ -7048,7 +7372,8 @@ impl Default for IMAGEHLP_MODULEW64 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_MODULEW64_EX {
     pub Module: IMAGEHLP_MODULEW64,
     pub RegionFlags: u32,
@@ -7062,7 +7387,8 @@ impl Default for IMAGEHLP_MODULEW64_EX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_STACK_FRAME {
     pub InstructionOffset: u64,
     pub ReturnOffset: u64,
@@ -7085,7 +7411,8 @@ impl Default for IMAGEHLP_STACK_FRAME {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_SYMBOL {
     pub SizeOfStruct: u32,
     pub Address: u32,
@@ -7105,7 +7432,8 @@ impl Default for IMAGEHLP_SYMBOL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_SYMBOL64 {
     pub SizeOfStruct: u32,
     pub Address: u64,
@@ -7123,7 +7451,8 @@ impl Default for IMAGEHLP_SYMBOL64 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_SYMBOL64_PACKAGE {
     pub sym: IMAGEHLP_SYMBOL64,
     pub name: [i8; 2001],
@@ -7138,7 +7467,8 @@ impl Default for IMAGEHLP_SYMBOL64_PACKAGE {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_SYMBOLW {
     pub SizeOfStruct: u32,
     pub Address: u32,
@@ -7158,7 +7488,8 @@ impl Default for IMAGEHLP_SYMBOLW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_SYMBOLW64 {
     pub SizeOfStruct: u32,
     pub Address: u64,
@@ -7176,7 +7507,8 @@ impl Default for IMAGEHLP_SYMBOLW64 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_SYMBOLW64_PACKAGE {
     pub sym: IMAGEHLP_SYMBOLW64,
     pub name: [u16; 2001],
@@ -7191,7 +7523,8 @@ impl Default for IMAGEHLP_SYMBOLW64_PACKAGE {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_SYMBOLW_PACKAGE {
     pub sym: IMAGEHLP_SYMBOLW,
     pub name: [u16; 2001],
@@ -7208,7 +7541,8 @@ impl Default for IMAGEHLP_SYMBOLW_PACKAGE {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_SYMBOL_PACKAGE {
     pub sym: IMAGEHLP_SYMBOL,
     pub name: [i8; 2001],
@@ -7224,7 +7558,8 @@ impl Default for IMAGEHLP_SYMBOL_PACKAGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEHLP_SYMBOL_SRC {
     pub sizeofstruct: u32,
     pub r#type: u32,
@@ -7267,10 +7602,55 @@ impl Default for IMAGE_ARM64_RUNTIME_FUNCTION_ENTRY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_ARM64_RUNTIME_FUNCTION_ENTRY_0_0 {
     pub _bitfield: u32,
 }
+impl IMAGE_ARM64_RUNTIME_FUNCTION_ENTRY_0_0 {
+    pub const fn Flag(&self) -> u32 {
+        self._bitfield & 3
+    }
+    pub fn set_Flag(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn FunctionLength(&self) -> u32 {
+        (self._bitfield >> 2) & 2047
+    }
+    pub fn set_FunctionLength(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2047 << 2)) | ((value & 2047) << 2);
+    }
+    pub const fn RegF(&self) -> u32 {
+        (self._bitfield >> 13) & 7
+    }
+    pub fn set_RegF(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 13)) | ((value & 7) << 13);
+    }
+    pub const fn RegI(&self) -> u32 {
+        (self._bitfield >> 16) & 15
+    }
+    pub fn set_RegI(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 16)) | ((value & 15) << 16);
+    }
+    pub const fn H(&self) -> u32 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_H(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn CR(&self) -> u32 {
+        (self._bitfield >> 21) & 3
+    }
+    pub fn set_CR(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 21)) | ((value & 3) << 21);
+    }
+    pub const fn FrameSize(&self) -> u32 {
+        (self._bitfield >> 23) & 511
+    }
+    pub fn set_FrameSize(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(511 << 23)) | ((value & 511) << 23);
+    }
+}
 impl windows_core::TypeKind for IMAGE_ARM64_RUNTIME_FUNCTION_ENTRY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -7280,7 +7660,8 @@ impl Default for IMAGE_ARM64_RUNTIME_FUNCTION_ENTRY_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_COFF_SYMBOLS_HEADER {
     pub NumberOfSymbols: u32,
     pub LvaToFirstSymbol: u32,
@@ -7338,7 +7719,8 @@ impl Default for IMAGE_COR20_HEADER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_DATA_DIRECTORY {
     pub VirtualAddress: u32,
     pub Size: u32,
@@ -7352,7 +7734,8 @@ impl Default for IMAGE_DATA_DIRECTORY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_DEBUG_DIRECTORY {
     pub Characteristics: u32,
     pub TimeDateStamp: u32,
@@ -7374,7 +7757,8 @@ impl Default for IMAGE_DEBUG_DIRECTORY {
 #[repr(C)]
 #[cfg(target_arch = "x86")]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_DEBUG_INFORMATION {
     pub List: super::super::Kernel::LIST_ENTRY,
     pub ReservedSize: u32,
@@ -7422,7 +7806,8 @@ impl Default for IMAGE_DEBUG_INFORMATION {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_SystemInformation")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_FILE_HEADER {
     pub Machine: super::super::SystemInformation::IMAGE_FILE_MACHINE,
     pub NumberOfSections: u16,
@@ -7443,7 +7828,8 @@ impl Default for IMAGE_FILE_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_FUNCTION_ENTRY {
     pub StartingAddress: u32,
     pub EndingAddress: u32,
@@ -7487,7 +7873,8 @@ impl Default for IMAGE_FUNCTION_ENTRY64_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_LOAD_CONFIG_CODE_INTEGRITY {
     pub Flags: u16,
     pub Catalog: u16,
@@ -7503,7 +7890,8 @@ impl Default for IMAGE_LOAD_CONFIG_CODE_INTEGRITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_LOAD_CONFIG_DIRECTORY32 {
     pub Size: u32,
     pub TimeDateStamp: u32,
@@ -7626,7 +8014,8 @@ impl Default for IMAGE_LOAD_CONFIG_DIRECTORY64 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_SystemInformation")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_NT_HEADERS32 {
     pub Signature: u32,
     pub FileHeader: IMAGE_FILE_HEADER,
@@ -7661,7 +8050,8 @@ impl Default for IMAGE_NT_HEADERS64 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_OPTIONAL_HEADER32 {
     pub Magic: IMAGE_OPTIONAL_HEADER_MAGIC,
     pub MajorLinkerVersion: u8,
@@ -7747,7 +8137,8 @@ impl Default for IMAGE_OPTIONAL_HEADER64 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_SystemInformation")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_ROM_HEADERS {
     pub FileHeader: IMAGE_FILE_HEADER,
     pub OptionalHeader: IMAGE_ROM_OPTIONAL_HEADER,
@@ -7763,7 +8154,8 @@ impl Default for IMAGE_ROM_HEADERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_ROM_OPTIONAL_HEADER {
     pub Magic: u16,
     pub MajorLinkerVersion: u8,
@@ -7872,7 +8264,8 @@ impl Default for IPMI_OS_SEL_RECORD {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KDHELP {
     pub Thread: u32,
     pub ThCallbackStack: u32,
@@ -7898,7 +8291,8 @@ impl Default for KDHELP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KDHELP64 {
     pub Thread: u64,
     pub ThCallbackStack: u32,
@@ -7997,7 +8391,8 @@ impl Default for KNONVOLATILE_CONTEXT_POINTERS_0 {
 }
 #[repr(C)]
 #[cfg(any(target_arch = "arm64ec", target_arch = "x86_64"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KNONVOLATILE_CONTEXT_POINTERS_0_0 {
     pub Xmm0: *mut M128A,
     pub Xmm1: *mut M128A,
@@ -8045,7 +8440,8 @@ impl Default for KNONVOLATILE_CONTEXT_POINTERS_1 {
 }
 #[repr(C)]
 #[cfg(any(target_arch = "arm64ec", target_arch = "x86_64"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KNONVOLATILE_CONTEXT_POINTERS_1_0 {
     pub Rax: *mut u64,
     pub Rcx: *mut u64,
@@ -8120,10 +8516,73 @@ impl Default for LDT_ENTRY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LDT_ENTRY_0_0 {
     pub _bitfield: u32,
 }
+impl LDT_ENTRY_0_0 {
+    pub const fn BaseMid(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_BaseMid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn Type(&self) -> u32 {
+        (self._bitfield >> 8) & 31
+    }
+    pub fn set_Type(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 8)) | ((value & 31) << 8);
+    }
+    pub const fn Dpl(&self) -> u32 {
+        (self._bitfield >> 13) & 3
+    }
+    pub fn set_Dpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 13)) | ((value & 3) << 13);
+    }
+    pub const fn Pres(&self) -> u32 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_Pres(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+    pub const fn LimitHi(&self) -> u32 {
+        (self._bitfield >> 16) & 15
+    }
+    pub fn set_LimitHi(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 16)) | ((value & 15) << 16);
+    }
+    pub const fn Sys(&self) -> u32 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_Sys(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn Reserved_0(&self) -> u32 {
+        (self._bitfield >> 21) & 1
+    }
+    pub fn set_Reserved_0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 21)) | ((value & 1) << 21);
+    }
+    pub const fn Default_Big(&self) -> u32 {
+        (self._bitfield >> 22) & 1
+    }
+    pub fn set_Default_Big(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 22)) | ((value & 1) << 22);
+    }
+    pub const fn Granularity(&self) -> u32 {
+        (self._bitfield >> 23) & 1
+    }
+    pub fn set_Granularity(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 23)) | ((value & 1) << 23);
+    }
+    pub const fn BaseHi(&self) -> u32 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_BaseHi(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for LDT_ENTRY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -8133,7 +8592,8 @@ impl Default for LDT_ENTRY_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LDT_ENTRY_0_1 {
     pub BaseMid: u8,
     pub Flags1: u8,
@@ -8151,7 +8611,8 @@ impl Default for LDT_ENTRY_0_1 {
 #[repr(C)]
 #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec", target_arch = "x86_64"))]
 #[cfg(all(feature = "Win32_System_Kernel", feature = "Win32_System_SystemInformation"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LOADED_IMAGE {
     pub ModuleName: windows_core::PSTR,
     pub hFile: super::super::super::Foundation::HANDLE,
@@ -8183,7 +8644,8 @@ impl Default for LOADED_IMAGE {
 #[repr(C)]
 #[cfg(target_arch = "x86")]
 #[cfg(all(feature = "Win32_System_Kernel", feature = "Win32_System_SystemInformation"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LOADED_IMAGE {
     pub ModuleName: windows_core::PSTR,
     pub hFile: super::super::super::Foundation::HANDLE,
@@ -8213,7 +8675,8 @@ impl Default for LOADED_IMAGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LOAD_DLL_DEBUG_INFO {
     pub hFile: super::super::super::Foundation::HANDLE,
     pub lpBaseOfDll: *mut core::ffi::c_void,
@@ -8231,7 +8694,8 @@ impl Default for LOAD_DLL_DEBUG_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct M128A {
     pub Low: u64,
     pub High: i64,
@@ -8390,7 +8854,8 @@ impl Default for MINIDUMP_CALLBACK_OUTPUT_0_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Memory")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MINIDUMP_CALLBACK_OUTPUT_0_1 {
     pub CheckCancel: super::super::super::Foundation::BOOL,
     pub Cancel: super::super::super::Foundation::BOOL,
@@ -8441,7 +8906,8 @@ impl Default for MINIDUMP_CALLBACK_OUTPUT_0_3 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Memory")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MINIDUMP_CALLBACK_OUTPUT_0_4 {
     pub VmReadStatus: windows_core::HRESULT,
     pub VmReadBytesCompleted: u32,
@@ -9280,7 +9746,8 @@ impl Default for MINIDUMP_SYSTEM_INFO_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_SystemInformation")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MINIDUMP_SYSTEM_INFO_0_0 {
     pub NumberOfProcessors: u8,
     pub ProductType: u8,
@@ -9314,7 +9781,8 @@ impl Default for MINIDUMP_SYSTEM_INFO_1 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_SystemInformation")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MINIDUMP_SYSTEM_INFO_1_0 {
     pub SuiteMask: u16,
     pub Reserved2: u16,
@@ -9864,7 +10332,8 @@ impl Default for MINIDUMP_VM_QUERY_CALLBACK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MODLOAD_CVMISC {
     pub oCV: u32,
     pub cCV: usize,
@@ -9882,7 +10351,8 @@ impl Default for MODLOAD_CVMISC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MODLOAD_DATA {
     pub ssize: u32,
     pub ssig: MODLOAD_DATA_TYPE,
@@ -9899,7 +10369,8 @@ impl Default for MODLOAD_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MODLOAD_PDBGUID_PDBAGE {
     pub PdbGuid: windows_core::GUID,
     pub PdbAge: u32,
@@ -9913,7 +10384,8 @@ impl Default for MODLOAD_PDBGUID_PDBAGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MODULE_TYPE_INFO {
     pub dataLength: u16,
     pub leaf: u16,
@@ -9928,7 +10400,8 @@ impl Default for MODULE_TYPE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OMAP {
     pub rva: u32,
     pub rvaTo: u32,
@@ -9942,7 +10415,8 @@ impl Default for OMAP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OUTPUT_DEBUG_STRING_INFO {
     pub lpDebugStringData: windows_core::PSTR,
     pub fUnicode: u16,
@@ -9957,7 +10431,8 @@ impl Default for OUTPUT_DEBUG_STRING_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PHYSICAL_MEMORY_DESCRIPTOR32 {
     pub NumberOfRuns: u32,
     pub NumberOfPages: u32,
@@ -9972,7 +10447,8 @@ impl Default for PHYSICAL_MEMORY_DESCRIPTOR32 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PHYSICAL_MEMORY_DESCRIPTOR64 {
     pub NumberOfRuns: u32,
     pub NumberOfPages: u64,
@@ -9987,7 +10463,8 @@ impl Default for PHYSICAL_MEMORY_DESCRIPTOR64 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PHYSICAL_MEMORY_RUN32 {
     pub BasePage: u32,
     pub PageCount: u32,
@@ -10001,7 +10478,8 @@ impl Default for PHYSICAL_MEMORY_RUN32 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PHYSICAL_MEMORY_RUN64 {
     pub BasePage: u64,
     pub PageCount: u64,
@@ -10015,7 +10493,8 @@ impl Default for PHYSICAL_MEMORY_RUN64 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RIP_INFO {
     pub dwError: u32,
     pub dwType: RIP_INFO_TYPE,
@@ -10029,7 +10508,8 @@ impl Default for RIP_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOURCEFILE {
     pub ModBase: u64,
     pub FileName: windows_core::PSTR,
@@ -10043,7 +10523,8 @@ impl Default for SOURCEFILE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOURCEFILEW {
     pub ModBase: u64,
     pub FileName: windows_core::PWSTR,
@@ -10057,7 +10538,8 @@ impl Default for SOURCEFILEW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SRCCODEINFO {
     pub SizeOfStruct: u32,
     pub Key: *mut core::ffi::c_void,
@@ -10076,7 +10558,8 @@ impl Default for SRCCODEINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SRCCODEINFOW {
     pub SizeOfStruct: u32,
     pub Key: *mut core::ffi::c_void,
@@ -10096,7 +10579,8 @@ impl Default for SRCCODEINFOW {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STACKFRAME {
     pub AddrPC: ADDRESS,
     pub AddrReturn: ADDRESS,
@@ -10121,7 +10605,8 @@ impl Default for STACKFRAME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STACKFRAME64 {
     pub AddrPC: ADDRESS64,
     pub AddrReturn: ADDRESS64,
@@ -10144,7 +10629,8 @@ impl Default for STACKFRAME64 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STACKFRAME_EX {
     pub AddrPC: ADDRESS64,
     pub AddrReturn: ADDRESS64,
@@ -10169,7 +10655,8 @@ impl Default for STACKFRAME_EX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYMBOL_INFO {
     pub SizeOfStruct: u32,
     pub TypeIndex: u32,
@@ -10196,7 +10683,8 @@ impl Default for SYMBOL_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYMBOL_INFOW {
     pub SizeOfStruct: u32,
     pub TypeIndex: u32,
@@ -10223,7 +10711,8 @@ impl Default for SYMBOL_INFOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYMBOL_INFO_PACKAGE {
     pub si: SYMBOL_INFO,
     pub name: [i8; 2001],
@@ -10237,7 +10726,8 @@ impl Default for SYMBOL_INFO_PACKAGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYMBOL_INFO_PACKAGEW {
     pub si: SYMBOL_INFOW,
     pub name: [u16; 2001],
@@ -10251,7 +10741,8 @@ impl Default for SYMBOL_INFO_PACKAGEW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYMSRV_EXTENDED_OUTPUT_DATA {
     pub sizeOfStruct: u32,
     pub version: u32,
@@ -10266,7 +10757,8 @@ impl Default for SYMSRV_EXTENDED_OUTPUT_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYMSRV_INDEX_INFO {
     pub sizeofstruct: u32,
     pub file: [i8; 261],
@@ -10288,7 +10780,8 @@ impl Default for SYMSRV_INDEX_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYMSRV_INDEX_INFOW {
     pub sizeofstruct: u32,
     pub file: [u16; 261],
@@ -10310,7 +10803,8 @@ impl Default for SYMSRV_INDEX_INFOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TI_FINDCHILDREN_PARAMS {
     pub Count: u32,
     pub Start: u32,
@@ -10325,7 +10819,8 @@ impl Default for TI_FINDCHILDREN_PARAMS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UNLOAD_DLL_DEBUG_INFO {
     pub lpBaseOfDll: *mut core::ffi::c_void,
 }
@@ -10339,7 +10834,8 @@ impl Default for UNLOAD_DLL_DEBUG_INFO {
 }
 #[repr(C)]
 #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec", target_arch = "x86_64"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UNWIND_HISTORY_TABLE {
     pub Count: u32,
     pub LocalHint: u8,
@@ -10362,7 +10858,8 @@ impl Default for UNWIND_HISTORY_TABLE {
 }
 #[repr(C)]
 #[cfg(target_arch = "aarch64")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UNWIND_HISTORY_TABLE_ENTRY {
     pub ImageBase: usize,
     pub FunctionEntry: *mut IMAGE_ARM64_RUNTIME_FUNCTION_ENTRY,
@@ -10379,7 +10876,8 @@ impl Default for UNWIND_HISTORY_TABLE_ENTRY {
 }
 #[repr(C)]
 #[cfg(any(target_arch = "arm64ec", target_arch = "x86_64"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UNWIND_HISTORY_TABLE_ENTRY {
     pub ImageBase: usize,
     pub FunctionEntry: *mut IMAGE_RUNTIME_FUNCTION_ENTRY,
@@ -10424,7 +10922,8 @@ impl Default for WAITCHAIN_NODE_INFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WAITCHAIN_NODE_INFO_0_0 {
     pub ObjectName: [u16; 128],
     pub Timeout: i64,
@@ -10439,7 +10938,8 @@ impl Default for WAITCHAIN_NODE_INFO_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WAITCHAIN_NODE_INFO_0_1 {
     pub ProcessId: u32,
     pub ThreadId: u32,
@@ -10967,6 +11467,44 @@ impl Default for WHEA_NOTIFICATION_FLAGS {
 pub struct WHEA_NOTIFICATION_FLAGS_0 {
     pub _bitfield: u16,
 }
+impl WHEA_NOTIFICATION_FLAGS_0 {
+    pub const fn PollIntervalRW(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_PollIntervalRW(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn SwitchToPollingThresholdRW(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_SwitchToPollingThresholdRW(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn SwitchToPollingWindowRW(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_SwitchToPollingWindowRW(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn ErrorThresholdRW(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_ErrorThresholdRW(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn ErrorThresholdWindowRW(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_ErrorThresholdWindowRW(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 5) & 2047
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(2047 << 5)) | ((value & 2047) << 5);
+    }
+}
 impl windows_core::TypeKind for WHEA_NOTIFICATION_FLAGS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -11007,6 +11545,26 @@ impl Default for WHEA_PCI_SLOT_NUMBER_0 {
 pub struct WHEA_PCI_SLOT_NUMBER_0_0 {
     pub _bitfield: u32,
 }
+impl WHEA_PCI_SLOT_NUMBER_0_0 {
+    pub const fn DeviceNumber(&self) -> u32 {
+        self._bitfield & 31
+    }
+    pub fn set_DeviceNumber(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !31) | (value & 31);
+    }
+    pub const fn FunctionNumber(&self) -> u32 {
+        (self._bitfield >> 5) & 7
+    }
+    pub fn set_FunctionNumber(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 5)) | ((value & 7) << 5);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 8) & 16777215
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(16777215 << 8)) | ((value & 16777215) << 8);
+    }
+}
 impl windows_core::TypeKind for WHEA_PCI_SLOT_NUMBER_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -11088,7 +11646,8 @@ impl Default for WHEA_XPF_NMI_DESCRIPTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WOW64_CONTEXT {
     pub ContextFlags: WOW64_CONTEXT_FLAGS,
     pub Dr0: u32,
@@ -11139,7 +11698,8 @@ impl Default for WOW64_DESCRIPTOR_TABLE_ENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WOW64_FLOATING_SAVE_AREA {
     pub ControlWord: u32,
     pub StatusWord: u32,
@@ -11189,10 +11749,73 @@ impl Default for WOW64_LDT_ENTRY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WOW64_LDT_ENTRY_0_0 {
     pub _bitfield: u32,
 }
+impl WOW64_LDT_ENTRY_0_0 {
+    pub const fn BaseMid(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_BaseMid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn Type(&self) -> u32 {
+        (self._bitfield >> 8) & 31
+    }
+    pub fn set_Type(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 8)) | ((value & 31) << 8);
+    }
+    pub const fn Dpl(&self) -> u32 {
+        (self._bitfield >> 13) & 3
+    }
+    pub fn set_Dpl(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 13)) | ((value & 3) << 13);
+    }
+    pub const fn Pres(&self) -> u32 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_Pres(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+    pub const fn LimitHi(&self) -> u32 {
+        (self._bitfield >> 16) & 15
+    }
+    pub fn set_LimitHi(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 16)) | ((value & 15) << 16);
+    }
+    pub const fn Sys(&self) -> u32 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_Sys(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn Reserved_0(&self) -> u32 {
+        (self._bitfield >> 21) & 1
+    }
+    pub fn set_Reserved_0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 21)) | ((value & 1) << 21);
+    }
+    pub const fn Default_Big(&self) -> u32 {
+        (self._bitfield >> 22) & 1
+    }
+    pub fn set_Default_Big(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 22)) | ((value & 1) << 22);
+    }
+    pub const fn Granularity(&self) -> u32 {
+        (self._bitfield >> 23) & 1
+    }
+    pub fn set_Granularity(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 23)) | ((value & 1) << 23);
+    }
+    pub const fn BaseHi(&self) -> u32 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_BaseHi(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for WOW64_LDT_ENTRY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -11202,7 +11825,8 @@ impl Default for WOW64_LDT_ENTRY_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WOW64_LDT_ENTRY_0_1 {
     pub BaseMid: u8,
     pub Flags1: u8,
@@ -11236,6 +11860,26 @@ impl Default for XPF_MCE_FLAGS {
 pub struct XPF_MCE_FLAGS_0 {
     pub _bitfield: u32,
 }
+impl XPF_MCE_FLAGS_0 {
+    pub const fn MCG_CapabilityRW(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_MCG_CapabilityRW(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn MCG_GlobalControlRW(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_MCG_GlobalControlRW(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 2) & 1073741823
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1073741823 << 2)) | ((value & 1073741823) << 2);
+    }
+}
 impl windows_core::TypeKind for XPF_MCE_FLAGS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -11259,10 +11903,31 @@ impl Default for XPF_MC_BANK_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XPF_MC_BANK_FLAGS_0 {
     pub _bitfield: u8,
 }
+impl XPF_MC_BANK_FLAGS_0 {
+    pub const fn ClearOnInitializationRW(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_ClearOnInitializationRW(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ControlDataRW(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_ControlDataRW(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 2) & 63
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(63 << 2)) | ((value & 63) << 2);
+    }
+}
 impl windows_core::TypeKind for XPF_MC_BANK_FLAGS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -11272,7 +11937,8 @@ impl Default for XPF_MC_BANK_FLAGS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XSAVE_AREA {
     pub LegacyState: XSAVE_FORMAT,
     pub Header: XSAVE_AREA_HEADER,
@@ -11286,7 +11952,8 @@ impl Default for XSAVE_AREA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XSAVE_AREA_HEADER {
     pub Mask: u64,
     pub CompactionMask: u64,
@@ -11302,7 +11969,8 @@ impl Default for XSAVE_AREA_HEADER {
 }
 #[repr(C)]
 #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec", target_arch = "x86_64"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XSAVE_FORMAT {
     pub ControlWord: u16,
     pub StatusWord: u16,
@@ -11333,7 +12001,8 @@ impl Default for XSAVE_FORMAT {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XSAVE_FORMAT {
     pub ControlWord: u16,
     pub StatusWord: u16,
@@ -11402,10 +12071,31 @@ impl Default for XSTATE_CONFIGURATION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XSTATE_CONFIGURATION_0_0 {
     pub _bitfield: u32,
 }
+impl XSTATE_CONFIGURATION_0_0 {
+    pub const fn OptimizedSave(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_OptimizedSave(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn CompactionEnabled(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_CompactionEnabled(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ExtendedFeatureDisable(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_ExtendedFeatureDisable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+}
 impl windows_core::TypeKind for XSTATE_CONFIGURATION_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -11432,7 +12122,8 @@ impl Default for XSTATE_CONFIG_FEATURE_MSC_INFO {
 }
 #[repr(C)]
 #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec", target_arch = "x86_64"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XSTATE_CONTEXT {
     pub Mask: u64,
     pub Length: u32,
@@ -11452,7 +12143,8 @@ impl Default for XSTATE_CONTEXT {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XSTATE_CONTEXT {
     pub Mask: u64,
     pub Length: u32,
@@ -11473,7 +12165,8 @@ impl Default for XSTATE_CONTEXT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XSTATE_FEATURE {
     pub Offset: u32,
     pub Size: u32,
@@ -11592,5 +12285,8 @@ pub type SYMADDSOURCESTREAMA = Option<unsafe extern "system" fn(param0: super::s
 pub type WHEA_ERROR_SOURCE_CORRECT_DEVICE_DRIVER = Option<unsafe extern "system" fn(errorsourcedesc: *mut core::ffi::c_void, maximumsectionlength: *mut u32) -> super::super::super::Foundation::NTSTATUS>;
 pub type WHEA_ERROR_SOURCE_INITIALIZE_DEVICE_DRIVER = Option<unsafe extern "system" fn(context: *mut core::ffi::c_void, errorsourceid: u32) -> super::super::super::Foundation::NTSTATUS>;
 pub type WHEA_ERROR_SOURCE_UNINITIALIZE_DEVICE_DRIVER = Option<unsafe extern "system" fn(context: *mut core::ffi::c_void)>;
+core::include!(core::concat!(core::env!("CARGO_MANIFEST_DIR"), "/src/includes/", "Win32/System/Diagnostics/Debug/Seh.rs"));
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,202 @@
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for IDebugExtendedProperty_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn GetExtendedPropertyInfo(this: *mut core::ffi::c_void, dwfieldspec: u32, nradix: u32, pextendedpropertyinfo: *mut ExtendedDebugPropertyInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugExtendedProperty", "GetExtendedPropertyInfo", 8usize, |this| ((**(this as *const *const IDebugExtendedProperty_Vtbl)).GetExtendedPropertyInfo)(this, dwfieldspec, nradix, pextendedpropertyinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumExtendedMembers(this: *mut core::ffi::c_void, dwfieldspec: u32, nradix: u32, ppeepi: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugExtendedProperty", "EnumExtendedMembers", 9usize, |this| ((**(this as *const *const IDebugExtendedProperty_Vtbl)).EnumExtendedMembers)(this, dwfieldspec, nradix, ppeepi), |result| Some(*result))
+        }
+        Self {
+            base__: <IDebugProperty_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            GetExtendedPropertyInfo,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            GetExtendedPropertyInfo: 0,
+            EnumExtendedMembers,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugExtendedProperty as windows_core::Interface>::IID || <IDebugProperty_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugProperty_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPropertyInfo(this: *mut core::ffi::c_void, dwfieldspec: u32, nradix: u32, ppropertyinfo: *mut DebugPropertyInfo) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugProperty", "GetPropertyInfo", 3usize, |this| ((**(this as *const *const IDebugProperty_Vtbl)).GetPropertyInfo)(this, dwfieldspec, nradix, ppropertyinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExtendedInfo(this: *mut core::ffi::c_void, cinfos: u32, rgguidextendedinfo: *const windows_core::GUID, rgvar: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugProperty", "GetExtendedInfo", 4usize, |this| ((**(this as *const *const IDebugProperty_Vtbl)).GetExtendedInfo)(this, cinfos, rgguidextendedinfo, rgvar), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValueAsString(this: *mut core::ffi::c_void, pszvalue: windows_core::PCWSTR, nradix: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugProperty", "SetValueAsString", 5usize, |this| ((**(this as *const *const IDebugProperty_Vtbl)).SetValueAsString)(this, pszvalue, nradix), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumMembers(this: *mut core::ffi::c_void, dwfieldspec: u32, nradix: u32, refiid: *const windows_core::GUID, ppepi: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugProperty", "EnumMembers", 6usize, |this| ((**(this as *const *const IDebugProperty_Vtbl)).EnumMembers)(this, dwfieldspec, nradix, refiid, ppepi), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParent(this: *mut core::ffi::c_void, ppdebugprop: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugProperty", "GetParent", 7usize, |this| ((**(this as *const *const IDebugProperty_Vtbl)).GetParent)(this, ppdebugprop), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPropertyInfo,
+            GetExtendedInfo,
+            SetValueAsString,
+            EnumMembers,
+            GetParent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugProperty as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugPropertyEnumType_All_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, __midl__idebugpropertyenumtype_all0000: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDebugPropertyEnumType_All", "GetName", 3usize, |this| ((**(this as *const *const IDebugPropertyEnumType_All_Vtbl)).GetName)(this, __midl__idebugpropertyenumtype_all0000), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugPropertyEnumType_All as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugPropertyEnumType_Arguments_Vtbl {
+    const PROXY: Self = { Self { base__: <IDebugPropertyEnumType_All_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugPropertyEnumType_Arguments as windows_core::Interface>::IID || <IDebugPropertyEnumType_All_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugPropertyEnumType_Locals_Vtbl {
+    const PROXY: Self = { Self { base__: <IDebugPropertyEnumType_All_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugPropertyEnumType_Locals as windows_core::Interface>::IID || <IDebugPropertyEnumType_All_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugPropertyEnumType_LocalsPlusArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <IDebugPropertyEnumType_All_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugPropertyEnumType_LocalsPlusArgs as windows_core::Interface>::IID || <IDebugPropertyEnumType_All_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDebugPropertyEnumType_Registers_Vtbl {
+    const PROXY: Self = { Self { base__: <IDebugPropertyEnumType_All_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDebugPropertyEnumType_Registers as windows_core::Interface>::IID || <IDebugPropertyEnumType_All_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for IEnumDebugExtendedPropertyInfo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgextendedpropertyinfo: *mut ExtendedDebugPropertyInfo, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDebugExtendedPropertyInfo", "Next", 3usize, |this| ((**(this as *const *const IEnumDebugExtendedPropertyInfo_Vtbl)).Next)(this, celt, rgextendedpropertyinfo, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDebugExtendedPropertyInfo", "Skip", 4usize, |this| ((**(this as *const *const IEnumDebugExtendedPropertyInfo_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDebugExtendedPropertyInfo", "Reset", 5usize, |this| ((**(this as *const *const IEnumDebugExtendedPropertyInfo_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, pedpe: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDebugExtendedPropertyInfo", "Clone", 6usize, |this| ((**(this as *const *const IEnumDebugExtendedPropertyInfo_Vtbl)).Clone)(this, pedpe), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcelt: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDebugExtendedPropertyInfo", "GetCount", 7usize, |this| ((**(this as *const *const IEnumDebugExtendedPropertyInfo_Vtbl)).GetCount)(this, pcelt), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            Next: 0,
+            Skip,
+            Reset,
+            Clone,
+            GetCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumDebugExtendedPropertyInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumDebugPropertyInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, pi: *mut DebugPropertyInfo, pceltsfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDebugPropertyInfo", "Next", 3usize, |this| ((**(this as *const *const IEnumDebugPropertyInfo_Vtbl)).Next)(this, celt, pi, pceltsfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDebugPropertyInfo", "Skip", 4usize, |this| ((**(this as *const *const IEnumDebugPropertyInfo_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDebugPropertyInfo", "Reset", 5usize, |this| ((**(this as *const *const IEnumDebugPropertyInfo_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppepi: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDebugPropertyInfo", "Clone", 6usize, |this| ((**(this as *const *const IEnumDebugPropertyInfo_Vtbl)).Clone)(this, ppepi), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcelt: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumDebugPropertyInfo", "GetCount", 7usize, |this| ((**(this as *const *const IEnumDebugPropertyInfo_Vtbl)).GetCount)(this, pcelt), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone, GetCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumDebugPropertyInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IObjectSafety_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInterfaceSafetyOptions(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, pdwsupportedoptions: *mut u32, pdwenabledoptions: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectSafety", "GetInterfaceSafetyOptions", 3usize, |this| ((**(this as *const *const IObjectSafety_Vtbl)).GetInterfaceSafetyOptions)(this, riid, pdwsupportedoptions, pdwenabledoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInterfaceSafetyOptions(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, dwoptionsetmask: u32, dwenabledoptions: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectSafety", "SetInterfaceSafetyOptions", 4usize, |this| ((**(this as *const *const IObjectSafety_Vtbl)).SetInterfaceSafetyOptions)(this, riid, dwoptionsetmask, dwenabledoptions), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetInterfaceSafetyOptions, SetInterfaceSafetyOptions }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IObjectSafety as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Ole")]
+impl windows_core::imp::ProxyVtable for IPerPropertyBrowsing2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDisplayString(this: *mut core::ffi::c_void, dispid: i32, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerPropertyBrowsing2", "GetDisplayString", 3usize, |this| ((**(this as *const *const IPerPropertyBrowsing2_Vtbl)).GetDisplayString)(this, dispid, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn MapPropertyToPage(this: *mut core::ffi::c_void, dispid: i32, pclsidproppage: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerPropertyBrowsing2", "MapPropertyToPage", 4usize, |this| ((**(this as *const *const IPerPropertyBrowsing2_Vtbl)).MapPropertyToPage)(this, dispid, pclsidproppage), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn GetPredefinedStrings(this: *mut core::ffi::c_void, dispid: i32, pcastrings: *mut super::super::Ole::CALPOLESTR, pcacookies: *mut super::super::Ole::CADWORD) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerPropertyBrowsing2", "GetPredefinedStrings", 5usize, |this| ((**(this as *const *const IPerPropertyBrowsing2_Vtbl)).GetPredefinedStrings)(this, dispid, pcastrings, pcacookies), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPredefinedValue(this: *mut core::ffi::c_void, dispid: i32, dwcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerPropertyBrowsing2", "SetPredefinedValue", 6usize, |this| ((**(this as *const *const IPerPropertyBrowsing2_Vtbl)).SetPredefinedValue)(this, dispid, dwcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDisplayString,
+            MapPropertyToPage,
+            #[cfg(feature = "Win32_System_Ole")]
+            GetPredefinedStrings,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            GetPredefinedStrings: 0,
+            SetPredefinedValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPerPropertyBrowsing2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
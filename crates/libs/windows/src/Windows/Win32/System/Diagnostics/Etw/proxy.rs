@@ -0,0 +1,124 @@
+impl windows_core::imp::ProxyVtable for ITraceEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, newevent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceEvent", "Clone", 3usize, |this| ((**(this as *const *const ITraceEvent_Vtbl)).Clone)(this, newevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUserContext(this: *mut core::ffi::c_void, usercontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceEvent", "GetUserContext", 4usize, |this| ((**(this as *const *const ITraceEvent_Vtbl)).GetUserContext)(this, usercontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEventRecord(this: *mut core::ffi::c_void, eventrecord: *mut *mut EVENT_RECORD) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceEvent", "GetEventRecord", 5usize, |this| ((**(this as *const *const ITraceEvent_Vtbl)).GetEventRecord)(this, eventrecord), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPayload(this: *mut core::ffi::c_void, payload: *const u8, payloadsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceEvent", "SetPayload", 6usize, |this| ((**(this as *const *const ITraceEvent_Vtbl)).SetPayload)(this, payload, payloadsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventDescriptor(this: *mut core::ffi::c_void, eventdescriptor: *const EVENT_DESCRIPTOR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceEvent", "SetEventDescriptor", 7usize, |this| ((**(this as *const *const ITraceEvent_Vtbl)).SetEventDescriptor)(this, eventdescriptor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProcessId(this: *mut core::ffi::c_void, processid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceEvent", "SetProcessId", 8usize, |this| ((**(this as *const *const ITraceEvent_Vtbl)).SetProcessId)(this, processid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProcessorIndex(this: *mut core::ffi::c_void, processorindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceEvent", "SetProcessorIndex", 9usize, |this| ((**(this as *const *const ITraceEvent_Vtbl)).SetProcessorIndex)(this, processorindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetThreadId(this: *mut core::ffi::c_void, threadid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceEvent", "SetThreadId", 10usize, |this| ((**(this as *const *const ITraceEvent_Vtbl)).SetThreadId)(this, threadid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetThreadTimes(this: *mut core::ffi::c_void, kerneltime: u32, usertime: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceEvent", "SetThreadTimes", 11usize, |this| ((**(this as *const *const ITraceEvent_Vtbl)).SetThreadTimes)(this, kerneltime, usertime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActivityId(this: *mut core::ffi::c_void, activityid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceEvent", "SetActivityId", 12usize, |this| ((**(this as *const *const ITraceEvent_Vtbl)).SetActivityId)(this, activityid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTimeStamp(this: *mut core::ffi::c_void, timestamp: *const i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceEvent", "SetTimeStamp", 13usize, |this| ((**(this as *const *const ITraceEvent_Vtbl)).SetTimeStamp)(this, timestamp), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProviderId(this: *mut core::ffi::c_void, providerid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceEvent", "SetProviderId", 14usize, |this| ((**(this as *const *const ITraceEvent_Vtbl)).SetProviderId)(this, providerid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Clone,
+            GetUserContext,
+            GetEventRecord,
+            SetPayload,
+            SetEventDescriptor,
+            SetProcessId,
+            SetProcessorIndex,
+            SetThreadId,
+            SetThreadTimes,
+            SetActivityId,
+            SetTimeStamp,
+            SetProviderId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITraceEvent as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITraceEventCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnBeginProcessTrace(this: *mut core::ffi::c_void, headerevent: *mut core::ffi::c_void, relogger: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceEventCallback", "OnBeginProcessTrace", 3usize, |this| ((**(this as *const *const ITraceEventCallback_Vtbl)).OnBeginProcessTrace)(this, headerevent, relogger), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnFinalizeProcessTrace(this: *mut core::ffi::c_void, relogger: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceEventCallback", "OnFinalizeProcessTrace", 4usize, |this| ((**(this as *const *const ITraceEventCallback_Vtbl)).OnFinalizeProcessTrace)(this, relogger), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnEvent(this: *mut core::ffi::c_void, event: *mut core::ffi::c_void, relogger: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceEventCallback", "OnEvent", 5usize, |this| ((**(this as *const *const ITraceEventCallback_Vtbl)).OnEvent)(this, event, relogger), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnBeginProcessTrace, OnFinalizeProcessTrace, OnEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITraceEventCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITraceRelogger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddLogfileTraceStream(this: *mut core::ffi::c_void, logfilename: core::mem::MaybeUninit<windows_core::BSTR>, usercontext: *const core::ffi::c_void, tracehandle: *mut RELOGSTREAM_HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceRelogger", "AddLogfileTraceStream", 3usize, |this| ((**(this as *const *const ITraceRelogger_Vtbl)).AddLogfileTraceStream)(this, logfilename, usercontext, tracehandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddRealtimeTraceStream(this: *mut core::ffi::c_void, loggername: core::mem::MaybeUninit<windows_core::BSTR>, usercontext: *const core::ffi::c_void, tracehandle: *mut RELOGSTREAM_HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceRelogger", "AddRealtimeTraceStream", 4usize, |this| ((**(this as *const *const ITraceRelogger_Vtbl)).AddRealtimeTraceStream)(this, loggername, usercontext, tracehandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterCallback(this: *mut core::ffi::c_void, callback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceRelogger", "RegisterCallback", 5usize, |this| ((**(this as *const *const ITraceRelogger_Vtbl)).RegisterCallback)(this, callback), |result| Some(*result))
+        }
+        unsafe extern "system" fn Inject(this: *mut core::ffi::c_void, event: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceRelogger", "Inject", 6usize, |this| ((**(this as *const *const ITraceRelogger_Vtbl)).Inject)(this, event), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateEventInstance(this: *mut core::ffi::c_void, tracehandle: RELOGSTREAM_HANDLE, flags: u32, event: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceRelogger", "CreateEventInstance", 7usize, |this| ((**(this as *const *const ITraceRelogger_Vtbl)).CreateEventInstance)(this, tracehandle, flags, event), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessTrace(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceRelogger", "ProcessTrace", 8usize, |this| ((**(this as *const *const ITraceRelogger_Vtbl)).ProcessTrace)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputFilename(this: *mut core::ffi::c_void, logfilename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceRelogger", "SetOutputFilename", 9usize, |this| ((**(this as *const *const ITraceRelogger_Vtbl)).SetOutputFilename)(this, logfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompressionMode(this: *mut core::ffi::c_void, compressionmode: super::super::super::Foundation::BOOLEAN) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceRelogger", "SetCompressionMode", 10usize, |this| ((**(this as *const *const ITraceRelogger_Vtbl)).SetCompressionMode)(this, compressionmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceRelogger", "Cancel", 11usize, |this| ((**(this as *const *const ITraceRelogger_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddLogfileTraceStream,
+            AddRealtimeTraceStream,
+            RegisterCallback,
+            Inject,
+            CreateEventInstance,
+            ProcessTrace,
+            SetOutputFilename,
+            SetCompressionMode,
+            Cancel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITraceRelogger as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
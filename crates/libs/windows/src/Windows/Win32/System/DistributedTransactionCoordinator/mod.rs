@@ -2073,6 +2073,7 @@ pub struct APPLICATIONTYPE(pub i32);
 impl windows_core::TypeKind for APPLICATIONTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for APPLICATIONTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("APPLICATIONTYPE").field(&self.0).finish()
@@ -2084,6 +2085,7 @@ pub struct AUTHENTICATION_LEVEL(pub i32);
 impl windows_core::TypeKind for AUTHENTICATION_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AUTHENTICATION_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AUTHENTICATION_LEVEL").field(&self.0).finish()
@@ -2095,6 +2097,7 @@ pub struct DTCINITIATEDRECOVERYWORK(pub i32);
 impl windows_core::TypeKind for DTCINITIATEDRECOVERYWORK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DTCINITIATEDRECOVERYWORK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DTCINITIATEDRECOVERYWORK").field(&self.0).finish()
@@ -2106,6 +2109,7 @@ pub struct DTCLUCOMPARESTATE(pub i32);
 impl windows_core::TypeKind for DTCLUCOMPARESTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DTCLUCOMPARESTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DTCLUCOMPARESTATE").field(&self.0).finish()
@@ -2117,6 +2121,7 @@ pub struct DTCLUCOMPARESTATESCONFIRMATION(pub i32);
 impl windows_core::TypeKind for DTCLUCOMPARESTATESCONFIRMATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DTCLUCOMPARESTATESCONFIRMATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DTCLUCOMPARESTATESCONFIRMATION").field(&self.0).finish()
@@ -2128,6 +2133,7 @@ pub struct DTCLUCOMPARESTATESERROR(pub i32);
 impl windows_core::TypeKind for DTCLUCOMPARESTATESERROR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DTCLUCOMPARESTATESERROR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DTCLUCOMPARESTATESERROR").field(&self.0).finish()
@@ -2139,6 +2145,7 @@ pub struct DTCLUCOMPARESTATESRESPONSE(pub i32);
 impl windows_core::TypeKind for DTCLUCOMPARESTATESRESPONSE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DTCLUCOMPARESTATESRESPONSE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DTCLUCOMPARESTATESRESPONSE").field(&self.0).finish()
@@ -2150,6 +2157,7 @@ pub struct DTCLUXLN(pub i32);
 impl windows_core::TypeKind for DTCLUXLN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DTCLUXLN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DTCLUXLN").field(&self.0).finish()
@@ -2161,6 +2169,7 @@ pub struct DTCLUXLNCONFIRMATION(pub i32);
 impl windows_core::TypeKind for DTCLUXLNCONFIRMATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DTCLUXLNCONFIRMATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DTCLUXLNCONFIRMATION").field(&self.0).finish()
@@ -2172,6 +2181,7 @@ pub struct DTCLUXLNERROR(pub i32);
 impl windows_core::TypeKind for DTCLUXLNERROR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DTCLUXLNERROR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DTCLUXLNERROR").field(&self.0).finish()
@@ -2183,6 +2193,7 @@ pub struct DTCLUXLNRESPONSE(pub i32);
 impl windows_core::TypeKind for DTCLUXLNRESPONSE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DTCLUXLNRESPONSE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DTCLUXLNRESPONSE").field(&self.0).finish()
@@ -2194,6 +2205,7 @@ pub struct DTC_STATUS_(pub i32);
 impl windows_core::TypeKind for DTC_STATUS_ {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DTC_STATUS_ {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DTC_STATUS_").field(&self.0).finish()
@@ -2205,6 +2217,7 @@ pub struct ISOFLAG(pub i32);
 impl windows_core::TypeKind for ISOFLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ISOFLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ISOFLAG").field(&self.0).finish()
@@ -2216,6 +2229,7 @@ pub struct ISOLATIONLEVEL(pub i32);
 impl windows_core::TypeKind for ISOLATIONLEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ISOLATIONLEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ISOLATIONLEVEL").field(&self.0).finish()
@@ -2227,6 +2241,7 @@ pub struct TX_MISC_CONSTANTS(pub i32);
 impl windows_core::TypeKind for TX_MISC_CONSTANTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TX_MISC_CONSTANTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TX_MISC_CONSTANTS").field(&self.0).finish()
@@ -2238,6 +2253,7 @@ pub struct XACTCONST(pub i32);
 impl windows_core::TypeKind for XACTCONST {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XACTCONST {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XACTCONST").field(&self.0).finish()
@@ -2249,6 +2265,7 @@ pub struct XACTHEURISTIC(pub i32);
 impl windows_core::TypeKind for XACTHEURISTIC {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XACTHEURISTIC {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XACTHEURISTIC").field(&self.0).finish()
@@ -2260,6 +2277,7 @@ pub struct XACTRM(pub i32);
 impl windows_core::TypeKind for XACTRM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XACTRM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XACTRM").field(&self.0).finish()
@@ -2271,6 +2289,7 @@ pub struct XACTSTAT(pub i32);
 impl windows_core::TypeKind for XACTSTAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XACTSTAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XACTSTAT").field(&self.0).finish()
@@ -2282,6 +2301,7 @@ pub struct XACTTC(pub i32);
 impl windows_core::TypeKind for XACTTC {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XACTTC {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XACTTC").field(&self.0).finish()
@@ -2293,13 +2313,15 @@ pub struct XACT_DTC_CONSTANTS(pub i32);
 impl windows_core::TypeKind for XACT_DTC_CONSTANTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XACT_DTC_CONSTANTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XACT_DTC_CONSTANTS").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BOID {
     pub rgb: [u8; 16],
 }
@@ -2312,7 +2334,8 @@ impl Default for BOID {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLE_TM_CONFIG_PARAMS_V1 {
     pub dwVersion: u32,
     pub dwcConcurrencyHint: u32,
@@ -2326,7 +2349,8 @@ impl Default for OLE_TM_CONFIG_PARAMS_V1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLE_TM_CONFIG_PARAMS_V2 {
     pub dwVersion: u32,
     pub dwcConcurrencyHint: u32,
@@ -2342,7 +2366,8 @@ impl Default for OLE_TM_CONFIG_PARAMS_V2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROXY_CONFIG_PARAMS {
     pub wcThreadsMax: u16,
 }
@@ -2355,7 +2380,8 @@ impl Default for PROXY_CONFIG_PARAMS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XACTOPT {
     pub ulTimeout: u32,
     pub szDescription: [u8; 40],
@@ -2369,7 +2395,8 @@ impl Default for XACTOPT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XACTSTATS {
     pub cOpen: u32,
     pub cCommitting: u32,
@@ -2389,7 +2416,8 @@ impl Default for XACTSTATS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XACTTRANSINFO {
     pub uow: BOID,
     pub isoLevel: i32,
@@ -2408,7 +2436,8 @@ impl Default for XACTTRANSINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XID {
     pub formatID: i32,
     pub gtrid_length: i32,
@@ -2424,7 +2453,8 @@ impl Default for XID {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct xa_switch_t {
     pub name: [i8; 32],
     pub flags: i32,
@@ -2464,3 +2494,5 @@ pub type XA_ROLLBACK_EPT = Option<unsafe extern "system" fn(param0: *mut XID, pa
 pub type XA_START_EPT = Option<unsafe extern "system" fn(param0: *mut XID, param1: i32, param2: i32) -> i32>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1245 @@
+impl windows_core::imp::ProxyVtable for IDtcLuConfigure_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, puclupair: *const u8, cblupair: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuConfigure", "Add", 3usize, |this| ((**(this as *const *const IDtcLuConfigure_Vtbl)).Add)(this, puclupair, cblupair), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, puclupair: *const u8, cblupair: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuConfigure", "Delete", 4usize, |this| ((**(this as *const *const IDtcLuConfigure_Vtbl)).Delete)(this, puclupair, cblupair), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Add, Delete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDtcLuConfigure as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDtcLuRecovery_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDtcLuRecovery as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDtcLuRecoveryFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, puclupair: *const u8, cblupair: u32, pprecovery: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRecoveryFactory", "Create", 3usize, |this| ((**(this as *const *const IDtcLuRecoveryFactory_Vtbl)).Create)(this, puclupair, cblupair, pprecovery), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDtcLuRecoveryFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDtcLuRecoveryInitiatedByDtc_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetWork(this: *mut core::ffi::c_void, pwork: *mut DTCINITIATEDRECOVERYWORK, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRecoveryInitiatedByDtc", "GetWork", 3usize, |this| ((**(this as *const *const IDtcLuRecoveryInitiatedByDtc_Vtbl)).GetWork)(this, pwork, ppv), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetWork }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDtcLuRecoveryInitiatedByDtc as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDtcLuRecoveryInitiatedByDtcStatusWork_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HandleCheckLuStatus(this: *mut core::ffi::c_void, lrecoveryseqnum: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRecoveryInitiatedByDtcStatusWork", "HandleCheckLuStatus", 3usize, |this| ((**(this as *const *const IDtcLuRecoveryInitiatedByDtcStatusWork_Vtbl)).HandleCheckLuStatus)(this, lrecoveryseqnum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HandleCheckLuStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDtcLuRecoveryInitiatedByDtcStatusWork as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDtcLuRecoveryInitiatedByDtcTransWork_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLogNameSizes(this: *mut core::ffi::c_void, pcbourlogname: *mut u32, pcbremotelogname: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRecoveryInitiatedByDtcTransWork", "GetLogNameSizes", 3usize, |this| ((**(this as *const *const IDtcLuRecoveryInitiatedByDtcTransWork_Vtbl)).GetLogNameSizes)(this, pcbourlogname, pcbremotelogname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOurXln(this: *mut core::ffi::c_void, pxln: *mut DTCLUXLN, pourlogname: *mut u8, premotelogname: *mut u8, pdwprotocol: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRecoveryInitiatedByDtcTransWork", "GetOurXln", 4usize, |this| ((**(this as *const *const IDtcLuRecoveryInitiatedByDtcTransWork_Vtbl)).GetOurXln)(this, pxln, pourlogname, premotelogname, pdwprotocol), |result| Some(*result))
+        }
+        unsafe extern "system" fn HandleConfirmationFromOurXln(this: *mut core::ffi::c_void, confirmation: DTCLUXLNCONFIRMATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRecoveryInitiatedByDtcTransWork", "HandleConfirmationFromOurXln", 5usize, |this| ((**(this as *const *const IDtcLuRecoveryInitiatedByDtcTransWork_Vtbl)).HandleConfirmationFromOurXln)(this, confirmation), |result| Some(*result))
+        }
+        unsafe extern "system" fn HandleTheirXlnResponse(this: *mut core::ffi::c_void, xln: DTCLUXLN, premotelogname: *mut u8, cbremotelogname: u32, dwprotocol: u32, pconfirmation: *mut DTCLUXLNCONFIRMATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRecoveryInitiatedByDtcTransWork", "HandleTheirXlnResponse", 6usize, |this| ((**(this as *const *const IDtcLuRecoveryInitiatedByDtcTransWork_Vtbl)).HandleTheirXlnResponse)(this, xln, premotelogname, cbremotelogname, dwprotocol, pconfirmation), |result| Some(*result))
+        }
+        unsafe extern "system" fn HandleErrorFromOurXln(this: *mut core::ffi::c_void, error: DTCLUXLNERROR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRecoveryInitiatedByDtcTransWork", "HandleErrorFromOurXln", 7usize, |this| ((**(this as *const *const IDtcLuRecoveryInitiatedByDtcTransWork_Vtbl)).HandleErrorFromOurXln)(this, error), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckForCompareStates(this: *mut core::ffi::c_void, fcomparestates: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRecoveryInitiatedByDtcTransWork", "CheckForCompareStates", 8usize, |this| ((**(this as *const *const IDtcLuRecoveryInitiatedByDtcTransWork_Vtbl)).CheckForCompareStates)(this, fcomparestates), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOurTransIdSize(this: *mut core::ffi::c_void, pcbourtransid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRecoveryInitiatedByDtcTransWork", "GetOurTransIdSize", 9usize, |this| ((**(this as *const *const IDtcLuRecoveryInitiatedByDtcTransWork_Vtbl)).GetOurTransIdSize)(this, pcbourtransid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOurCompareStates(this: *mut core::ffi::c_void, pourtransid: *mut u8, pcomparestate: *mut DTCLUCOMPARESTATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRecoveryInitiatedByDtcTransWork", "GetOurCompareStates", 10usize, |this| ((**(this as *const *const IDtcLuRecoveryInitiatedByDtcTransWork_Vtbl)).GetOurCompareStates)(this, pourtransid, pcomparestate), |result| Some(*result))
+        }
+        unsafe extern "system" fn HandleTheirCompareStatesResponse(this: *mut core::ffi::c_void, comparestate: DTCLUCOMPARESTATE, pconfirmation: *mut DTCLUCOMPARESTATESCONFIRMATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRecoveryInitiatedByDtcTransWork", "HandleTheirCompareStatesResponse", 11usize, |this| ((**(this as *const *const IDtcLuRecoveryInitiatedByDtcTransWork_Vtbl)).HandleTheirCompareStatesResponse)(this, comparestate, pconfirmation), |result| Some(*result))
+        }
+        unsafe extern "system" fn HandleErrorFromOurCompareStates(this: *mut core::ffi::c_void, error: DTCLUCOMPARESTATESERROR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRecoveryInitiatedByDtcTransWork", "HandleErrorFromOurCompareStates", 12usize, |this| ((**(this as *const *const IDtcLuRecoveryInitiatedByDtcTransWork_Vtbl)).HandleErrorFromOurCompareStates)(this, error), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConversationLost(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRecoveryInitiatedByDtcTransWork", "ConversationLost", 13usize, |this| ((**(this as *const *const IDtcLuRecoveryInitiatedByDtcTransWork_Vtbl)).ConversationLost)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecoverySeqNum(this: *mut core::ffi::c_void, plrecoveryseqnum: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRecoveryInitiatedByDtcTransWork", "GetRecoverySeqNum", 14usize, |this| ((**(this as *const *const IDtcLuRecoveryInitiatedByDtcTransWork_Vtbl)).GetRecoverySeqNum)(this, plrecoveryseqnum), |result| Some(*result))
+        }
+        unsafe extern "system" fn ObsoleteRecoverySeqNum(this: *mut core::ffi::c_void, lnewrecoveryseqnum: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRecoveryInitiatedByDtcTransWork", "ObsoleteRecoverySeqNum", 15usize, |this| ((**(this as *const *const IDtcLuRecoveryInitiatedByDtcTransWork_Vtbl)).ObsoleteRecoverySeqNum)(this, lnewrecoveryseqnum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetLogNameSizes,
+            GetOurXln,
+            HandleConfirmationFromOurXln,
+            HandleTheirXlnResponse,
+            HandleErrorFromOurXln,
+            CheckForCompareStates,
+            GetOurTransIdSize,
+            GetOurCompareStates,
+            HandleTheirCompareStatesResponse,
+            HandleErrorFromOurCompareStates,
+            ConversationLost,
+            GetRecoverySeqNum,
+            ObsoleteRecoverySeqNum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDtcLuRecoveryInitiatedByDtcTransWork as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDtcLuRecoveryInitiatedByLu_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetObjectToHandleWorkFromLu(this: *mut core::ffi::c_void, ppwork: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRecoveryInitiatedByLu", "GetObjectToHandleWorkFromLu", 3usize, |this| ((**(this as *const *const IDtcLuRecoveryInitiatedByLu_Vtbl)).GetObjectToHandleWorkFromLu)(this, ppwork), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetObjectToHandleWorkFromLu }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDtcLuRecoveryInitiatedByLu as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDtcLuRecoveryInitiatedByLuWork_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HandleTheirXln(this: *mut core::ffi::c_void, lrecoveryseqnum: i32, xln: DTCLUXLN, premotelogname: *mut u8, cbremotelogname: u32, pourlogname: *mut u8, cbourlogname: u32, dwprotocol: u32, presponse: *mut DTCLUXLNRESPONSE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRecoveryInitiatedByLuWork", "HandleTheirXln", 3usize, |this| ((**(this as *const *const IDtcLuRecoveryInitiatedByLuWork_Vtbl)).HandleTheirXln)(this, lrecoveryseqnum, xln, premotelogname, cbremotelogname, pourlogname, cbourlogname, dwprotocol, presponse), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOurLogNameSize(this: *mut core::ffi::c_void, pcbourlogname: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRecoveryInitiatedByLuWork", "GetOurLogNameSize", 4usize, |this| ((**(this as *const *const IDtcLuRecoveryInitiatedByLuWork_Vtbl)).GetOurLogNameSize)(this, pcbourlogname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOurXln(this: *mut core::ffi::c_void, pxln: *mut DTCLUXLN, pourlogname: *mut u8, pdwprotocol: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRecoveryInitiatedByLuWork", "GetOurXln", 5usize, |this| ((**(this as *const *const IDtcLuRecoveryInitiatedByLuWork_Vtbl)).GetOurXln)(this, pxln, pourlogname, pdwprotocol), |result| Some(*result))
+        }
+        unsafe extern "system" fn HandleConfirmationOfOurXln(this: *mut core::ffi::c_void, confirmation: DTCLUXLNCONFIRMATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRecoveryInitiatedByLuWork", "HandleConfirmationOfOurXln", 6usize, |this| ((**(this as *const *const IDtcLuRecoveryInitiatedByLuWork_Vtbl)).HandleConfirmationOfOurXln)(this, confirmation), |result| Some(*result))
+        }
+        unsafe extern "system" fn HandleTheirCompareStates(this: *mut core::ffi::c_void, premotetransid: *mut u8, cbremotetransid: u32, comparestate: DTCLUCOMPARESTATE, presponse: *mut DTCLUCOMPARESTATESRESPONSE, pcomparestate: *mut DTCLUCOMPARESTATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRecoveryInitiatedByLuWork", "HandleTheirCompareStates", 7usize, |this| ((**(this as *const *const IDtcLuRecoveryInitiatedByLuWork_Vtbl)).HandleTheirCompareStates)(this, premotetransid, cbremotetransid, comparestate, presponse, pcomparestate), |result| Some(*result))
+        }
+        unsafe extern "system" fn HandleConfirmationOfOurCompareStates(this: *mut core::ffi::c_void, confirmation: DTCLUCOMPARESTATESCONFIRMATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRecoveryInitiatedByLuWork", "HandleConfirmationOfOurCompareStates", 8usize, |this| ((**(this as *const *const IDtcLuRecoveryInitiatedByLuWork_Vtbl)).HandleConfirmationOfOurCompareStates)(this, confirmation), |result| Some(*result))
+        }
+        unsafe extern "system" fn HandleErrorFromOurCompareStates(this: *mut core::ffi::c_void, error: DTCLUCOMPARESTATESERROR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRecoveryInitiatedByLuWork", "HandleErrorFromOurCompareStates", 9usize, |this| ((**(this as *const *const IDtcLuRecoveryInitiatedByLuWork_Vtbl)).HandleErrorFromOurCompareStates)(this, error), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConversationLost(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRecoveryInitiatedByLuWork", "ConversationLost", 10usize, |this| ((**(this as *const *const IDtcLuRecoveryInitiatedByLuWork_Vtbl)).ConversationLost)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            HandleTheirXln,
+            GetOurLogNameSize,
+            GetOurXln,
+            HandleConfirmationOfOurXln,
+            HandleTheirCompareStates,
+            HandleConfirmationOfOurCompareStates,
+            HandleErrorFromOurCompareStates,
+            ConversationLost,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDtcLuRecoveryInitiatedByLuWork as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDtcLuRmEnlistment_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Unplug(this: *mut core::ffi::c_void, fconversationlost: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRmEnlistment", "Unplug", 3usize, |this| ((**(this as *const *const IDtcLuRmEnlistment_Vtbl)).Unplug)(this, fconversationlost), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackedOut(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRmEnlistment", "BackedOut", 4usize, |this| ((**(this as *const *const IDtcLuRmEnlistment_Vtbl)).BackedOut)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackOut(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRmEnlistment", "BackOut", 5usize, |this| ((**(this as *const *const IDtcLuRmEnlistment_Vtbl)).BackOut)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Committed(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRmEnlistment", "Committed", 6usize, |this| ((**(this as *const *const IDtcLuRmEnlistment_Vtbl)).Committed)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Forget(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRmEnlistment", "Forget", 7usize, |this| ((**(this as *const *const IDtcLuRmEnlistment_Vtbl)).Forget)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestCommit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRmEnlistment", "RequestCommit", 8usize, |this| ((**(this as *const *const IDtcLuRmEnlistment_Vtbl)).RequestCommit)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Unplug, BackedOut, BackOut, Committed, Forget, RequestCommit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDtcLuRmEnlistment as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDtcLuRmEnlistmentFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, puclupair: *mut u8, cblupair: u32, pitransaction: *mut core::ffi::c_void, ptransid: *mut u8, cbtransid: u32, prmenlistmentsink: *mut core::ffi::c_void, pprmenlistment: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRmEnlistmentFactory", "Create", 3usize, |this| ((**(this as *const *const IDtcLuRmEnlistmentFactory_Vtbl)).Create)(this, puclupair, cblupair, pitransaction, ptransid, cbtransid, prmenlistmentsink, pprmenlistment), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDtcLuRmEnlistmentFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDtcLuRmEnlistmentSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AckUnplug(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRmEnlistmentSink", "AckUnplug", 3usize, |this| ((**(this as *const *const IDtcLuRmEnlistmentSink_Vtbl)).AckUnplug)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn TmDown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRmEnlistmentSink", "TmDown", 4usize, |this| ((**(this as *const *const IDtcLuRmEnlistmentSink_Vtbl)).TmDown)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionLost(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRmEnlistmentSink", "SessionLost", 5usize, |this| ((**(this as *const *const IDtcLuRmEnlistmentSink_Vtbl)).SessionLost)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackedOut(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRmEnlistmentSink", "BackedOut", 6usize, |this| ((**(this as *const *const IDtcLuRmEnlistmentSink_Vtbl)).BackedOut)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackOut(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRmEnlistmentSink", "BackOut", 7usize, |this| ((**(this as *const *const IDtcLuRmEnlistmentSink_Vtbl)).BackOut)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Committed(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRmEnlistmentSink", "Committed", 8usize, |this| ((**(this as *const *const IDtcLuRmEnlistmentSink_Vtbl)).Committed)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Forget(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRmEnlistmentSink", "Forget", 9usize, |this| ((**(this as *const *const IDtcLuRmEnlistmentSink_Vtbl)).Forget)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Prepare(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRmEnlistmentSink", "Prepare", 10usize, |this| ((**(this as *const *const IDtcLuRmEnlistmentSink_Vtbl)).Prepare)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestCommit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuRmEnlistmentSink", "RequestCommit", 11usize, |this| ((**(this as *const *const IDtcLuRmEnlistmentSink_Vtbl)).RequestCommit)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AckUnplug,
+            TmDown,
+            SessionLost,
+            BackedOut,
+            BackOut,
+            Committed,
+            Forget,
+            Prepare,
+            RequestCommit,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDtcLuRmEnlistmentSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDtcLuSubordinateDtc_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Unplug(this: *mut core::ffi::c_void, fconversationlost: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuSubordinateDtc", "Unplug", 3usize, |this| ((**(this as *const *const IDtcLuSubordinateDtc_Vtbl)).Unplug)(this, fconversationlost), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackedOut(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuSubordinateDtc", "BackedOut", 4usize, |this| ((**(this as *const *const IDtcLuSubordinateDtc_Vtbl)).BackedOut)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackOut(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuSubordinateDtc", "BackOut", 5usize, |this| ((**(this as *const *const IDtcLuSubordinateDtc_Vtbl)).BackOut)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Committed(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuSubordinateDtc", "Committed", 6usize, |this| ((**(this as *const *const IDtcLuSubordinateDtc_Vtbl)).Committed)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Forget(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuSubordinateDtc", "Forget", 7usize, |this| ((**(this as *const *const IDtcLuSubordinateDtc_Vtbl)).Forget)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Prepare(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuSubordinateDtc", "Prepare", 8usize, |this| ((**(this as *const *const IDtcLuSubordinateDtc_Vtbl)).Prepare)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestCommit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuSubordinateDtc", "RequestCommit", 9usize, |this| ((**(this as *const *const IDtcLuSubordinateDtc_Vtbl)).RequestCommit)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Unplug,
+            BackedOut,
+            BackOut,
+            Committed,
+            Forget,
+            Prepare,
+            RequestCommit,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDtcLuSubordinateDtc as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDtcLuSubordinateDtcFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, puclupair: *mut u8, cblupair: u32, punktransactionouter: *mut core::ffi::c_void, isolevel: i32, isoflags: u32, poptions: *mut core::ffi::c_void, pptransaction: *mut *mut core::ffi::c_void, ptransid: *mut u8, cbtransid: u32, psubordinatedtcsink: *mut core::ffi::c_void, ppsubordinatedtc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuSubordinateDtcFactory", "Create", 3usize, |this| ((**(this as *const *const IDtcLuSubordinateDtcFactory_Vtbl)).Create)(this, puclupair, cblupair, punktransactionouter, isolevel, isoflags, poptions, pptransaction, ptransid, cbtransid, psubordinatedtcsink, ppsubordinatedtc), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDtcLuSubordinateDtcFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDtcLuSubordinateDtcSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AckUnplug(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuSubordinateDtcSink", "AckUnplug", 3usize, |this| ((**(this as *const *const IDtcLuSubordinateDtcSink_Vtbl)).AckUnplug)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn TmDown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuSubordinateDtcSink", "TmDown", 4usize, |this| ((**(this as *const *const IDtcLuSubordinateDtcSink_Vtbl)).TmDown)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionLost(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuSubordinateDtcSink", "SessionLost", 5usize, |this| ((**(this as *const *const IDtcLuSubordinateDtcSink_Vtbl)).SessionLost)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackedOut(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuSubordinateDtcSink", "BackedOut", 6usize, |this| ((**(this as *const *const IDtcLuSubordinateDtcSink_Vtbl)).BackedOut)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackOut(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuSubordinateDtcSink", "BackOut", 7usize, |this| ((**(this as *const *const IDtcLuSubordinateDtcSink_Vtbl)).BackOut)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Committed(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuSubordinateDtcSink", "Committed", 8usize, |this| ((**(this as *const *const IDtcLuSubordinateDtcSink_Vtbl)).Committed)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Forget(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuSubordinateDtcSink", "Forget", 9usize, |this| ((**(this as *const *const IDtcLuSubordinateDtcSink_Vtbl)).Forget)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestCommit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcLuSubordinateDtcSink", "RequestCommit", 10usize, |this| ((**(this as *const *const IDtcLuSubordinateDtcSink_Vtbl)).RequestCommit)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AckUnplug,
+            TmDown,
+            SessionLost,
+            BackedOut,
+            BackOut,
+            Committed,
+            Forget,
+            RequestCommit,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDtcLuSubordinateDtcSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDtcNetworkAccessConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAnyNetworkAccess(this: *mut core::ffi::c_void, pbanynetworkaccess: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcNetworkAccessConfig", "GetAnyNetworkAccess", 3usize, |this| ((**(this as *const *const IDtcNetworkAccessConfig_Vtbl)).GetAnyNetworkAccess)(this, pbanynetworkaccess), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAnyNetworkAccess(this: *mut core::ffi::c_void, banynetworkaccess: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcNetworkAccessConfig", "SetAnyNetworkAccess", 4usize, |this| ((**(this as *const *const IDtcNetworkAccessConfig_Vtbl)).SetAnyNetworkAccess)(this, banynetworkaccess), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNetworkAdministrationAccess(this: *mut core::ffi::c_void, pbnetworkadministrationaccess: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcNetworkAccessConfig", "GetNetworkAdministrationAccess", 5usize, |this| ((**(this as *const *const IDtcNetworkAccessConfig_Vtbl)).GetNetworkAdministrationAccess)(this, pbnetworkadministrationaccess), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNetworkAdministrationAccess(this: *mut core::ffi::c_void, bnetworkadministrationaccess: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcNetworkAccessConfig", "SetNetworkAdministrationAccess", 6usize, |this| ((**(this as *const *const IDtcNetworkAccessConfig_Vtbl)).SetNetworkAdministrationAccess)(this, bnetworkadministrationaccess), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNetworkTransactionAccess(this: *mut core::ffi::c_void, pbnetworktransactionaccess: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcNetworkAccessConfig", "GetNetworkTransactionAccess", 7usize, |this| ((**(this as *const *const IDtcNetworkAccessConfig_Vtbl)).GetNetworkTransactionAccess)(this, pbnetworktransactionaccess), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNetworkTransactionAccess(this: *mut core::ffi::c_void, bnetworktransactionaccess: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcNetworkAccessConfig", "SetNetworkTransactionAccess", 8usize, |this| ((**(this as *const *const IDtcNetworkAccessConfig_Vtbl)).SetNetworkTransactionAccess)(this, bnetworktransactionaccess), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNetworkClientAccess(this: *mut core::ffi::c_void, pbnetworkclientaccess: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcNetworkAccessConfig", "GetNetworkClientAccess", 9usize, |this| ((**(this as *const *const IDtcNetworkAccessConfig_Vtbl)).GetNetworkClientAccess)(this, pbnetworkclientaccess), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNetworkClientAccess(this: *mut core::ffi::c_void, bnetworkclientaccess: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcNetworkAccessConfig", "SetNetworkClientAccess", 10usize, |this| ((**(this as *const *const IDtcNetworkAccessConfig_Vtbl)).SetNetworkClientAccess)(this, bnetworkclientaccess), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNetworkTIPAccess(this: *mut core::ffi::c_void, pbnetworktipaccess: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcNetworkAccessConfig", "GetNetworkTIPAccess", 11usize, |this| ((**(this as *const *const IDtcNetworkAccessConfig_Vtbl)).GetNetworkTIPAccess)(this, pbnetworktipaccess), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNetworkTIPAccess(this: *mut core::ffi::c_void, bnetworktipaccess: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcNetworkAccessConfig", "SetNetworkTIPAccess", 12usize, |this| ((**(this as *const *const IDtcNetworkAccessConfig_Vtbl)).SetNetworkTIPAccess)(this, bnetworktipaccess), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetXAAccess(this: *mut core::ffi::c_void, pbxaaccess: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcNetworkAccessConfig", "GetXAAccess", 13usize, |this| ((**(this as *const *const IDtcNetworkAccessConfig_Vtbl)).GetXAAccess)(this, pbxaaccess), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetXAAccess(this: *mut core::ffi::c_void, bxaaccess: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcNetworkAccessConfig", "SetXAAccess", 14usize, |this| ((**(this as *const *const IDtcNetworkAccessConfig_Vtbl)).SetXAAccess)(this, bxaaccess), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestartDtcService(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcNetworkAccessConfig", "RestartDtcService", 15usize, |this| ((**(this as *const *const IDtcNetworkAccessConfig_Vtbl)).RestartDtcService)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetAnyNetworkAccess,
+            SetAnyNetworkAccess,
+            GetNetworkAdministrationAccess,
+            SetNetworkAdministrationAccess,
+            GetNetworkTransactionAccess,
+            SetNetworkTransactionAccess,
+            GetNetworkClientAccess,
+            SetNetworkClientAccess,
+            GetNetworkTIPAccess,
+            SetNetworkTIPAccess,
+            GetXAAccess,
+            SetXAAccess,
+            RestartDtcService,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDtcNetworkAccessConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDtcNetworkAccessConfig2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNetworkInboundAccess(this: *mut core::ffi::c_void, pbinbound: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcNetworkAccessConfig2", "GetNetworkInboundAccess", 16usize, |this| ((**(this as *const *const IDtcNetworkAccessConfig2_Vtbl)).GetNetworkInboundAccess)(this, pbinbound), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNetworkOutboundAccess(this: *mut core::ffi::c_void, pboutbound: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcNetworkAccessConfig2", "GetNetworkOutboundAccess", 17usize, |this| ((**(this as *const *const IDtcNetworkAccessConfig2_Vtbl)).GetNetworkOutboundAccess)(this, pboutbound), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNetworkInboundAccess(this: *mut core::ffi::c_void, binbound: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcNetworkAccessConfig2", "SetNetworkInboundAccess", 18usize, |this| ((**(this as *const *const IDtcNetworkAccessConfig2_Vtbl)).SetNetworkInboundAccess)(this, binbound), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNetworkOutboundAccess(this: *mut core::ffi::c_void, boutbound: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcNetworkAccessConfig2", "SetNetworkOutboundAccess", 19usize, |this| ((**(this as *const *const IDtcNetworkAccessConfig2_Vtbl)).SetNetworkOutboundAccess)(this, boutbound), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAuthenticationLevel(this: *mut core::ffi::c_void, pauthlevel: *mut AUTHENTICATION_LEVEL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcNetworkAccessConfig2", "GetAuthenticationLevel", 20usize, |this| ((**(this as *const *const IDtcNetworkAccessConfig2_Vtbl)).GetAuthenticationLevel)(this, pauthlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthenticationLevel(this: *mut core::ffi::c_void, authlevel: AUTHENTICATION_LEVEL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcNetworkAccessConfig2", "SetAuthenticationLevel", 21usize, |this| ((**(this as *const *const IDtcNetworkAccessConfig2_Vtbl)).SetAuthenticationLevel)(this, authlevel), |result| Some(*result))
+        }
+        Self {
+            base__: <IDtcNetworkAccessConfig_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetNetworkInboundAccess,
+            GetNetworkOutboundAccess,
+            SetNetworkInboundAccess,
+            SetNetworkOutboundAccess,
+            GetAuthenticationLevel,
+            SetAuthenticationLevel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDtcNetworkAccessConfig2 as windows_core::Interface>::IID || <IDtcNetworkAccessConfig_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDtcNetworkAccessConfig3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLUAccess(this: *mut core::ffi::c_void, pbluaccess: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcNetworkAccessConfig3", "GetLUAccess", 22usize, |this| ((**(this as *const *const IDtcNetworkAccessConfig3_Vtbl)).GetLUAccess)(this, pbluaccess), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLUAccess(this: *mut core::ffi::c_void, bluaccess: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcNetworkAccessConfig3", "SetLUAccess", 23usize, |this| ((**(this as *const *const IDtcNetworkAccessConfig3_Vtbl)).SetLUAccess)(this, bluaccess), |result| Some(*result))
+        }
+        Self { base__: <IDtcNetworkAccessConfig2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetLUAccess, SetLUAccess }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDtcNetworkAccessConfig3 as windows_core::Interface>::IID || <IDtcNetworkAccessConfig2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDtcToXaHelper_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void, i_fdorecovery: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcToXaHelper", "Close", 3usize, |this| ((**(this as *const *const IDtcToXaHelper_Vtbl)).Close)(this, i_fdorecovery), |result| Some(*result))
+        }
+        unsafe extern "system" fn TranslateTridToXid(this: *mut core::ffi::c_void, pitransaction: *mut core::ffi::c_void, pguidbqual: *const windows_core::GUID, pxid: *mut XID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcToXaHelper", "TranslateTridToXid", 4usize, |this| ((**(this as *const *const IDtcToXaHelper_Vtbl)).TranslateTridToXid)(this, pitransaction, pguidbqual, pxid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Close, TranslateTridToXid }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDtcToXaHelper as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDtcToXaHelperFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, pszdsn: windows_core::PCSTR, pszclientdllname: windows_core::PCSTR, pguidrm: *mut windows_core::GUID, ppxahelper: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcToXaHelperFactory", "Create", 3usize, |this| ((**(this as *const *const IDtcToXaHelperFactory_Vtbl)).Create)(this, pszdsn, pszclientdllname, pguidrm, ppxahelper), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDtcToXaHelperFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDtcToXaHelperSinglePipe_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn XARMCreate(this: *mut core::ffi::c_void, pszdsn: windows_core::PCSTR, pszclientdll: windows_core::PCSTR, pdwrmcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcToXaHelperSinglePipe", "XARMCreate", 3usize, |this| ((**(this as *const *const IDtcToXaHelperSinglePipe_Vtbl)).XARMCreate)(this, pszdsn, pszclientdll, pdwrmcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertTridToXID(this: *mut core::ffi::c_void, pdwitrans: *mut u32, dwrmcookie: u32, pxid: *mut XID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcToXaHelperSinglePipe", "ConvertTridToXID", 4usize, |this| ((**(this as *const *const IDtcToXaHelperSinglePipe_Vtbl)).ConvertTridToXID)(this, pdwitrans, dwrmcookie, pxid), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnlistWithRM(this: *mut core::ffi::c_void, dwrmcookie: u32, i_pitransaction: *mut core::ffi::c_void, i_pitransres: *mut core::ffi::c_void, o_ppitransenslitment: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcToXaHelperSinglePipe", "EnlistWithRM", 5usize, |this| ((**(this as *const *const IDtcToXaHelperSinglePipe_Vtbl)).EnlistWithRM)(this, dwrmcookie, i_pitransaction, i_pitransres, o_ppitransenslitment), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseRMCookie(this: *mut core::ffi::c_void, i_dwrmcookie: u32, i_fnormal: super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "IDtcToXaHelperSinglePipe", "ReleaseRMCookie", 6usize, |this| ((**(this as *const *const IDtcToXaHelperSinglePipe_Vtbl)).ReleaseRMCookie)(this, i_dwrmcookie, i_fnormal), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, XARMCreate, ConvertTridToXID, EnlistWithRM, ReleaseRMCookie }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDtcToXaHelperSinglePipe as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDtcToXaMapper_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestNewResourceManager(this: *mut core::ffi::c_void, pszdsn: windows_core::PCSTR, pszclientdllname: windows_core::PCSTR, pdwrmcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcToXaMapper", "RequestNewResourceManager", 3usize, |this| ((**(this as *const *const IDtcToXaMapper_Vtbl)).RequestNewResourceManager)(this, pszdsn, pszclientdllname, pdwrmcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn TranslateTridToXid(this: *mut core::ffi::c_void, pdwitransaction: *const u32, dwrmcookie: u32, pxid: *mut XID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcToXaMapper", "TranslateTridToXid", 4usize, |this| ((**(this as *const *const IDtcToXaMapper_Vtbl)).TranslateTridToXid)(this, pdwitransaction, dwrmcookie, pxid), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnlistResourceManager(this: *mut core::ffi::c_void, dwrmcookie: u32, pdwitransaction: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcToXaMapper", "EnlistResourceManager", 5usize, |this| ((**(this as *const *const IDtcToXaMapper_Vtbl)).EnlistResourceManager)(this, dwrmcookie, pdwitransaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseResourceManager(this: *mut core::ffi::c_void, dwrmcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDtcToXaMapper", "ReleaseResourceManager", 6usize, |this| ((**(this as *const *const IDtcToXaMapper_Vtbl)).ReleaseResourceManager)(this, dwrmcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestNewResourceManager,
+            TranslateTridToXid,
+            EnlistResourceManager,
+            ReleaseResourceManager,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDtcToXaMapper as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGetDispenser_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDispenser(this: *mut core::ffi::c_void, iid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetDispenser", "GetDispenser", 3usize, |this| ((**(this as *const *const IGetDispenser_Vtbl)).GetDispenser)(this, iid, ppvobject), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDispenser }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGetDispenser as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKernelTransaction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetHandle(this: *mut core::ffi::c_void, phandle: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKernelTransaction", "GetHandle", 3usize, |this| ((**(this as *const *const IKernelTransaction_Vtbl)).GetHandle)(this, phandle), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetHandle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKernelTransaction as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILastResourceManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TransactionCommitted(this: *mut core::ffi::c_void, pprepinfo: *const u8, cbprepinfo: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILastResourceManager", "TransactionCommitted", 3usize, |this| ((**(this as *const *const ILastResourceManager_Vtbl)).TransactionCommitted)(this, pprepinfo, cbprepinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecoveryDone(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILastResourceManager", "RecoveryDone", 4usize, |this| ((**(this as *const *const ILastResourceManager_Vtbl)).RecoveryDone)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TransactionCommitted, RecoveryDone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILastResourceManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrepareInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPrepareInfoSize(this: *mut core::ffi::c_void, pcbprepinfo: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrepareInfo", "GetPrepareInfoSize", 3usize, |this| ((**(this as *const *const IPrepareInfo_Vtbl)).GetPrepareInfoSize)(this, pcbprepinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrepareInfo(this: *mut core::ffi::c_void, pprepinfo: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrepareInfo", "GetPrepareInfo", 4usize, |this| ((**(this as *const *const IPrepareInfo_Vtbl)).GetPrepareInfo)(this, pprepinfo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPrepareInfoSize, GetPrepareInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrepareInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrepareInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPrepareInfoSize(this: *mut core::ffi::c_void, pcbprepinfo: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrepareInfo2", "GetPrepareInfoSize", 3usize, |this| ((**(this as *const *const IPrepareInfo2_Vtbl)).GetPrepareInfoSize)(this, pcbprepinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrepareInfo(this: *mut core::ffi::c_void, cbprepareinfo: u32, pprepinfo: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrepareInfo2", "GetPrepareInfo", 4usize, |this| ((**(this as *const *const IPrepareInfo2_Vtbl)).GetPrepareInfo)(this, cbprepareinfo, pprepinfo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPrepareInfoSize, GetPrepareInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrepareInfo2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRMHelper_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RMCount(this: *mut core::ffi::c_void, dwctotalnumberofrms: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRMHelper", "RMCount", 3usize, |this| ((**(this as *const *const IRMHelper_Vtbl)).RMCount)(this, dwctotalnumberofrms), |result| Some(*result))
+        }
+        unsafe extern "system" fn RMInfo(this: *mut core::ffi::c_void, pxa_switch: *mut xa_switch_t, fcdeclcallingconv: super::super::Foundation::BOOL, pszopenstring: windows_core::PCSTR, pszclosestring: windows_core::PCSTR, guidrmrecovery: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRMHelper", "RMInfo", 4usize, |this| ((**(this as *const *const IRMHelper_Vtbl)).RMInfo)(this, pxa_switch, fcdeclcallingconv, pszopenstring, pszclosestring, guidrmrecovery), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RMCount, RMInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRMHelper as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IResourceManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Enlist(this: *mut core::ffi::c_void, ptransaction: *mut core::ffi::c_void, pres: *mut core::ffi::c_void, puow: *mut BOID, pisolevel: *mut i32, ppenlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceManager", "Enlist", 3usize, |this| ((**(this as *const *const IResourceManager_Vtbl)).Enlist)(this, ptransaction, pres, puow, pisolevel, ppenlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reenlist(this: *mut core::ffi::c_void, pprepinfo: *const u8, cbprepinfo: u32, ltimeout: u32, pxactstat: *mut XACTSTAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceManager", "Reenlist", 4usize, |this| ((**(this as *const *const IResourceManager_Vtbl)).Reenlist)(this, pprepinfo, cbprepinfo, ltimeout, pxactstat), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReenlistmentComplete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceManager", "ReenlistmentComplete", 5usize, |this| ((**(this as *const *const IResourceManager_Vtbl)).ReenlistmentComplete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDistributedTransactionManager(this: *mut core::ffi::c_void, iid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceManager", "GetDistributedTransactionManager", 6usize, |this| ((**(this as *const *const IResourceManager_Vtbl)).GetDistributedTransactionManager)(this, iid, ppvobject), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Enlist,
+            Reenlist,
+            ReenlistmentComplete,
+            GetDistributedTransactionManager,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IResourceManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Enlist2(this: *mut core::ffi::c_void, ptransaction: *mut core::ffi::c_void, presasync: *mut core::ffi::c_void, puow: *mut BOID, pisolevel: *mut i32, pxid: *mut XID, ppenlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceManager2", "Enlist2", 7usize, |this| ((**(this as *const *const IResourceManager2_Vtbl)).Enlist2)(this, ptransaction, presasync, puow, pisolevel, pxid, ppenlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reenlist2(this: *mut core::ffi::c_void, pxid: *const XID, dwtimeout: u32, pxactstat: *mut XACTSTAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceManager2", "Reenlist2", 8usize, |this| ((**(this as *const *const IResourceManager2_Vtbl)).Reenlist2)(this, pxid, dwtimeout, pxactstat), |result| Some(*result))
+        }
+        Self { base__: <IResourceManager_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Enlist2, Reenlist2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceManager2 as windows_core::Interface>::IID || <IResourceManager_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IResourceManagerFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, pguidrm: *const windows_core::GUID, pszrmname: windows_core::PCSTR, piresmgrsink: *mut core::ffi::c_void, ppresmgr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceManagerFactory", "Create", 3usize, |this| ((**(this as *const *const IResourceManagerFactory_Vtbl)).Create)(this, pguidrm, pszrmname, piresmgrsink, ppresmgr), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceManagerFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IResourceManagerFactory2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateEx(this: *mut core::ffi::c_void, pguidrm: *const windows_core::GUID, pszrmname: windows_core::PCSTR, piresmgrsink: *mut core::ffi::c_void, riidrequested: *const windows_core::GUID, ppvresmgr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceManagerFactory2", "CreateEx", 4usize, |this| ((**(this as *const *const IResourceManagerFactory2_Vtbl)).CreateEx)(this, pguidrm, pszrmname, piresmgrsink, riidrequested, ppvresmgr), |result| Some(*result))
+        }
+        Self { base__: <IResourceManagerFactory_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceManagerFactory2 as windows_core::Interface>::IID || <IResourceManagerFactory_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IResourceManagerRejoinable_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Rejoin(this: *mut core::ffi::c_void, pprepinfo: *const u8, cbprepinfo: u32, ltimeout: u32, pxactstat: *mut XACTSTAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceManagerRejoinable", "Rejoin", 9usize, |this| ((**(this as *const *const IResourceManagerRejoinable_Vtbl)).Rejoin)(this, pprepinfo, cbprepinfo, ltimeout, pxactstat), |result| Some(*result))
+        }
+        Self { base__: <IResourceManager2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Rejoin }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceManagerRejoinable as windows_core::Interface>::IID || <IResourceManager2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IResourceManagerSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TMDown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResourceManagerSink", "TMDown", 3usize, |this| ((**(this as *const *const IResourceManagerSink_Vtbl)).TMDown)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TMDown }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResourceManagerSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITipHelper_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Pull(this: *mut core::ffi::c_void, i_psztxurl: *const u8, o_ppitransaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITipHelper", "Pull", 3usize, |this| ((**(this as *const *const ITipHelper_Vtbl)).Pull)(this, i_psztxurl, o_ppitransaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn PullAsync(this: *mut core::ffi::c_void, i_psztxurl: *const u8, i_ptippullsink: *mut core::ffi::c_void, o_ppitransaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITipHelper", "PullAsync", 4usize, |this| ((**(this as *const *const ITipHelper_Vtbl)).PullAsync)(this, i_psztxurl, i_ptippullsink, o_ppitransaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocalTmUrl(this: *mut core::ffi::c_void, o_ppszlocaltmurl: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITipHelper", "GetLocalTmUrl", 5usize, |this| ((**(this as *const *const ITipHelper_Vtbl)).GetLocalTmUrl)(this, o_ppszlocaltmurl), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Pull, PullAsync, GetLocalTmUrl }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITipHelper as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITipPullSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PullComplete(this: *mut core::ffi::c_void, i_hrpull: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITipPullSink", "PullComplete", 3usize, |this| ((**(this as *const *const ITipPullSink_Vtbl)).PullComplete)(this, i_hrpull), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PullComplete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITipPullSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITipTransaction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Push(this: *mut core::ffi::c_void, i_pszremotetmurl: *const u8, o_ppszremotetxurl: *mut windows_core::PSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITipTransaction", "Push", 3usize, |this| ((**(this as *const *const ITipTransaction_Vtbl)).Push)(this, i_pszremotetmurl, o_ppszremotetxurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransactionUrl(this: *mut core::ffi::c_void, o_ppszlocaltxurl: *mut windows_core::PSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITipTransaction", "GetTransactionUrl", 4usize, |this| ((**(this as *const *const ITipTransaction_Vtbl)).GetTransactionUrl)(this, o_ppszlocaltxurl), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Push, GetTransactionUrl }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITipTransaction as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITmNodeName_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNodeNameSize(this: *mut core::ffi::c_void, pcbnodenamesize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITmNodeName", "GetNodeNameSize", 3usize, |this| ((**(this as *const *const ITmNodeName_Vtbl)).GetNodeNameSize)(this, pcbnodenamesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNodeName(this: *mut core::ffi::c_void, cbnodenamebuffersize: u32, pnodenamebuffer: windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITmNodeName", "GetNodeName", 4usize, |this| ((**(this as *const *const ITmNodeName_Vtbl)).GetNodeName)(this, cbnodenamebuffersize, pnodenamebuffer), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetNodeNameSize, GetNodeName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITmNodeName as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransaction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void, fretaining: super::super::Foundation::BOOL, grftc: u32, grfrm: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransaction", "Commit", 3usize, |this| ((**(this as *const *const ITransaction_Vtbl)).Commit)(this, fretaining, grftc, grfrm), |result| Some(*result))
+        }
+        unsafe extern "system" fn Abort(this: *mut core::ffi::c_void, pboidreason: *const BOID, fretaining: super::super::Foundation::BOOL, fasync: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransaction", "Abort", 4usize, |this| ((**(this as *const *const ITransaction_Vtbl)).Abort)(this, pboidreason, fretaining, fasync), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransactionInfo(this: *mut core::ffi::c_void, pinfo: *mut XACTTRANSINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransaction", "GetTransactionInfo", 5usize, |this| ((**(this as *const *const ITransaction_Vtbl)).GetTransactionInfo)(this, pinfo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Commit, Abort, GetTransactionInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransaction as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransaction2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTransactionInfo2(this: *mut core::ffi::c_void, pinfo: *mut XACTTRANSINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransaction2", "GetTransactionInfo2", 7usize, |this| ((**(this as *const *const ITransaction2_Vtbl)).GetTransactionInfo2)(this, pinfo), |result| Some(*result))
+        }
+        Self { base__: <ITransactionCloner_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetTransactionInfo2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransaction2 as windows_core::Interface>::IID || <ITransactionCloner_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransactionCloner_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CloneWithCommitDisabled(this: *mut core::ffi::c_void, ppitransaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionCloner", "CloneWithCommitDisabled", 6usize, |this| ((**(this as *const *const ITransactionCloner_Vtbl)).CloneWithCommitDisabled)(this, ppitransaction), |result| Some(*result))
+        }
+        Self { base__: <ITransaction_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CloneWithCommitDisabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionCloner as windows_core::Interface>::IID || <ITransaction_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransactionDispenser_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOptionsObject(this: *mut core::ffi::c_void, ppoptions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionDispenser", "GetOptionsObject", 3usize, |this| ((**(this as *const *const ITransactionDispenser_Vtbl)).GetOptionsObject)(this, ppoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginTransaction(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, isolevel: i32, isoflags: u32, poptions: *mut core::ffi::c_void, pptransaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionDispenser", "BeginTransaction", 4usize, |this| ((**(this as *const *const ITransactionDispenser_Vtbl)).BeginTransaction)(this, punkouter, isolevel, isoflags, poptions, pptransaction), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetOptionsObject, BeginTransaction }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionDispenser as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITransactionEnlistmentAsync_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PrepareRequestDone(this: *mut core::ffi::c_void, hr: windows_core::HRESULT, pmk: *mut core::ffi::c_void, pboidreason: *const BOID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionEnlistmentAsync", "PrepareRequestDone", 3usize, |this| ((**(this as *const *const ITransactionEnlistmentAsync_Vtbl)).PrepareRequestDone)(this, hr, pmk, pboidreason), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommitRequestDone(this: *mut core::ffi::c_void, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionEnlistmentAsync", "CommitRequestDone", 4usize, |this| ((**(this as *const *const ITransactionEnlistmentAsync_Vtbl)).CommitRequestDone)(this, hr), |result| Some(*result))
+        }
+        unsafe extern "system" fn AbortRequestDone(this: *mut core::ffi::c_void, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionEnlistmentAsync", "AbortRequestDone", 5usize, |this| ((**(this as *const *const ITransactionEnlistmentAsync_Vtbl)).AbortRequestDone)(this, hr), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            PrepareRequestDone,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PrepareRequestDone: 0,
+            CommitRequestDone,
+            AbortRequestDone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionEnlistmentAsync as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransactionExport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Export(this: *mut core::ffi::c_void, punktransaction: *mut core::ffi::c_void, pcbtransactioncookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionExport", "Export", 3usize, |this| ((**(this as *const *const ITransactionExport_Vtbl)).Export)(this, punktransaction, pcbtransactioncookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransactionCookie(this: *mut core::ffi::c_void, punktransaction: *mut core::ffi::c_void, cbtransactioncookie: u32, rgbtransactioncookie: *mut u8, pcbused: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionExport", "GetTransactionCookie", 4usize, |this| ((**(this as *const *const ITransactionExport_Vtbl)).GetTransactionCookie)(this, punktransaction, cbtransactioncookie, rgbtransactioncookie, pcbused), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Export, GetTransactionCookie }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionExport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransactionExportFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRemoteClassId(this: *mut core::ffi::c_void, pclsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionExportFactory", "GetRemoteClassId", 3usize, |this| ((**(this as *const *const ITransactionExportFactory_Vtbl)).GetRemoteClassId)(this, pclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, cbwhereabouts: u32, rgbwhereabouts: *const u8, ppexport: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionExportFactory", "Create", 4usize, |this| ((**(this as *const *const ITransactionExportFactory_Vtbl)).Create)(this, cbwhereabouts, rgbwhereabouts, ppexport), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRemoteClassId, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionExportFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransactionImport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Import(this: *mut core::ffi::c_void, cbtransactioncookie: u32, rgbtransactioncookie: *const u8, piid: *const windows_core::GUID, ppvtransaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionImport", "Import", 3usize, |this| ((**(this as *const *const ITransactionImport_Vtbl)).Import)(this, cbtransactioncookie, rgbtransactioncookie, piid, ppvtransaction), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Import }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionImport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransactionImportWhereabouts_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetWhereaboutsSize(this: *mut core::ffi::c_void, pcbwhereabouts: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionImportWhereabouts", "GetWhereaboutsSize", 3usize, |this| ((**(this as *const *const ITransactionImportWhereabouts_Vtbl)).GetWhereaboutsSize)(this, pcbwhereabouts), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWhereabouts(this: *mut core::ffi::c_void, cbwhereabouts: u32, rgbwhereabouts: *mut u8, pcbused: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionImportWhereabouts", "GetWhereabouts", 4usize, |this| ((**(this as *const *const ITransactionImportWhereabouts_Vtbl)).GetWhereabouts)(this, cbwhereabouts, rgbwhereabouts, pcbused), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetWhereaboutsSize, GetWhereabouts }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionImportWhereabouts as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransactionLastEnlistmentAsync_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TransactionOutcome(this: *mut core::ffi::c_void, xactstat: XACTSTAT, pboidreason: *const BOID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionLastEnlistmentAsync", "TransactionOutcome", 3usize, |this| ((**(this as *const *const ITransactionLastEnlistmentAsync_Vtbl)).TransactionOutcome)(this, xactstat, pboidreason), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TransactionOutcome }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionLastEnlistmentAsync as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransactionLastResourceAsync_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DelegateCommit(this: *mut core::ffi::c_void, grfrm: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionLastResourceAsync", "DelegateCommit", 3usize, |this| ((**(this as *const *const ITransactionLastResourceAsync_Vtbl)).DelegateCommit)(this, grfrm), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForgetRequest(this: *mut core::ffi::c_void, pnewuow: *const BOID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionLastResourceAsync", "ForgetRequest", 4usize, |this| ((**(this as *const *const ITransactionLastResourceAsync_Vtbl)).ForgetRequest)(this, pnewuow), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DelegateCommit, ForgetRequest }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionLastResourceAsync as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransactionOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetOptions(this: *mut core::ffi::c_void, poptions: *const XACTOPT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionOptions", "SetOptions", 3usize, |this| ((**(this as *const *const ITransactionOptions_Vtbl)).SetOptions)(this, poptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOptions(this: *mut core::ffi::c_void, poptions: *mut XACTOPT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionOptions", "GetOptions", 4usize, |this| ((**(this as *const *const ITransactionOptions_Vtbl)).GetOptions)(this, poptions), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetOptions, GetOptions }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionOptions as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransactionOutcomeEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Committed(this: *mut core::ffi::c_void, fretaining: super::super::Foundation::BOOL, pnewuow: *const BOID, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionOutcomeEvents", "Committed", 3usize, |this| ((**(this as *const *const ITransactionOutcomeEvents_Vtbl)).Committed)(this, fretaining, pnewuow, hr), |result| Some(*result))
+        }
+        unsafe extern "system" fn Aborted(this: *mut core::ffi::c_void, pboidreason: *const BOID, fretaining: super::super::Foundation::BOOL, pnewuow: *const BOID, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionOutcomeEvents", "Aborted", 4usize, |this| ((**(this as *const *const ITransactionOutcomeEvents_Vtbl)).Aborted)(this, pboidreason, fretaining, pnewuow, hr), |result| Some(*result))
+        }
+        unsafe extern "system" fn HeuristicDecision(this: *mut core::ffi::c_void, dwdecision: u32, pboidreason: *const BOID, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionOutcomeEvents", "HeuristicDecision", 5usize, |this| ((**(this as *const *const ITransactionOutcomeEvents_Vtbl)).HeuristicDecision)(this, dwdecision, pboidreason, hr), |result| Some(*result))
+        }
+        unsafe extern "system" fn Indoubt(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionOutcomeEvents", "Indoubt", 6usize, |this| ((**(this as *const *const ITransactionOutcomeEvents_Vtbl)).Indoubt)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Committed, Aborted, HeuristicDecision, Indoubt }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionOutcomeEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransactionPhase0EnlistmentAsync_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Enable(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionPhase0EnlistmentAsync", "Enable", 3usize, |this| ((**(this as *const *const ITransactionPhase0EnlistmentAsync_Vtbl)).Enable)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForEnlistment(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionPhase0EnlistmentAsync", "WaitForEnlistment", 4usize, |this| ((**(this as *const *const ITransactionPhase0EnlistmentAsync_Vtbl)).WaitForEnlistment)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Phase0Done(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionPhase0EnlistmentAsync", "Phase0Done", 5usize, |this| ((**(this as *const *const ITransactionPhase0EnlistmentAsync_Vtbl)).Phase0Done)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unenlist(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionPhase0EnlistmentAsync", "Unenlist", 6usize, |this| ((**(this as *const *const ITransactionPhase0EnlistmentAsync_Vtbl)).Unenlist)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransaction(this: *mut core::ffi::c_void, ppitransaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionPhase0EnlistmentAsync", "GetTransaction", 7usize, |this| ((**(this as *const *const ITransactionPhase0EnlistmentAsync_Vtbl)).GetTransaction)(this, ppitransaction), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Enable, WaitForEnlistment, Phase0Done, Unenlist, GetTransaction }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionPhase0EnlistmentAsync as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransactionPhase0Factory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, pphase0notify: *mut core::ffi::c_void, ppphase0enlistment: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionPhase0Factory", "Create", 3usize, |this| ((**(this as *const *const ITransactionPhase0Factory_Vtbl)).Create)(this, pphase0notify, ppphase0enlistment), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionPhase0Factory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransactionPhase0NotifyAsync_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Phase0Request(this: *mut core::ffi::c_void, fabortinghint: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionPhase0NotifyAsync", "Phase0Request", 3usize, |this| ((**(this as *const *const ITransactionPhase0NotifyAsync_Vtbl)).Phase0Request)(this, fabortinghint), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnlistCompleted(this: *mut core::ffi::c_void, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionPhase0NotifyAsync", "EnlistCompleted", 4usize, |this| ((**(this as *const *const ITransactionPhase0NotifyAsync_Vtbl)).EnlistCompleted)(this, status), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Phase0Request, EnlistCompleted }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionPhase0NotifyAsync as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransactionReceiver_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UnmarshalPropagationToken(this: *mut core::ffi::c_void, cbtoken: u32, rgbtoken: *const u8, pptransaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionReceiver", "UnmarshalPropagationToken", 3usize, |this| ((**(this as *const *const ITransactionReceiver_Vtbl)).UnmarshalPropagationToken)(this, cbtoken, rgbtoken, pptransaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReturnTokenSize(this: *mut core::ffi::c_void, pcbreturntoken: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionReceiver", "GetReturnTokenSize", 4usize, |this| ((**(this as *const *const ITransactionReceiver_Vtbl)).GetReturnTokenSize)(this, pcbreturntoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn MarshalReturnToken(this: *mut core::ffi::c_void, cbreturntoken: u32, rgbreturntoken: *mut u8, pcbused: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionReceiver", "MarshalReturnToken", 5usize, |this| ((**(this as *const *const ITransactionReceiver_Vtbl)).MarshalReturnToken)(this, cbreturntoken, rgbreturntoken, pcbused), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionReceiver", "Reset", 6usize, |this| ((**(this as *const *const ITransactionReceiver_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            UnmarshalPropagationToken,
+            GetReturnTokenSize,
+            MarshalReturnToken,
+            Reset,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionReceiver as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransactionReceiverFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, ppreceiver: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionReceiverFactory", "Create", 3usize, |this| ((**(this as *const *const ITransactionReceiverFactory_Vtbl)).Create)(this, ppreceiver), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionReceiverFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransactionResource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrepareRequest(this: *mut core::ffi::c_void, fretaining: super::super::Foundation::BOOL, grfrm: u32, fwantmoniker: super::super::Foundation::BOOL, fsinglephase: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionResource", "PrepareRequest", 3usize, |this| ((**(this as *const *const ITransactionResource_Vtbl)).PrepareRequest)(this, fretaining, grfrm, fwantmoniker, fsinglephase), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommitRequest(this: *mut core::ffi::c_void, grfrm: u32, pnewuow: *const BOID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionResource", "CommitRequest", 4usize, |this| ((**(this as *const *const ITransactionResource_Vtbl)).CommitRequest)(this, grfrm, pnewuow), |result| Some(*result))
+        }
+        unsafe extern "system" fn AbortRequest(this: *mut core::ffi::c_void, pboidreason: *const BOID, fretaining: super::super::Foundation::BOOL, pnewuow: *const BOID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionResource", "AbortRequest", 5usize, |this| ((**(this as *const *const ITransactionResource_Vtbl)).AbortRequest)(this, pboidreason, fretaining, pnewuow), |result| Some(*result))
+        }
+        unsafe extern "system" fn TMDown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionResource", "TMDown", 6usize, |this| ((**(this as *const *const ITransactionResource_Vtbl)).TMDown)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PrepareRequest, CommitRequest, AbortRequest, TMDown }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionResource as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransactionResourceAsync_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrepareRequest(this: *mut core::ffi::c_void, fretaining: super::super::Foundation::BOOL, grfrm: u32, fwantmoniker: super::super::Foundation::BOOL, fsinglephase: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionResourceAsync", "PrepareRequest", 3usize, |this| ((**(this as *const *const ITransactionResourceAsync_Vtbl)).PrepareRequest)(this, fretaining, grfrm, fwantmoniker, fsinglephase), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommitRequest(this: *mut core::ffi::c_void, grfrm: u32, pnewuow: *const BOID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionResourceAsync", "CommitRequest", 4usize, |this| ((**(this as *const *const ITransactionResourceAsync_Vtbl)).CommitRequest)(this, grfrm, pnewuow), |result| Some(*result))
+        }
+        unsafe extern "system" fn AbortRequest(this: *mut core::ffi::c_void, pboidreason: *const BOID, fretaining: super::super::Foundation::BOOL, pnewuow: *const BOID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionResourceAsync", "AbortRequest", 5usize, |this| ((**(this as *const *const ITransactionResourceAsync_Vtbl)).AbortRequest)(this, pboidreason, fretaining, pnewuow), |result| Some(*result))
+        }
+        unsafe extern "system" fn TMDown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionResourceAsync", "TMDown", 6usize, |this| ((**(this as *const *const ITransactionResourceAsync_Vtbl)).TMDown)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PrepareRequest, CommitRequest, AbortRequest, TMDown }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionResourceAsync as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransactionTransmitter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Set(this: *mut core::ffi::c_void, ptransaction: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionTransmitter", "Set", 3usize, |this| ((**(this as *const *const ITransactionTransmitter_Vtbl)).Set)(this, ptransaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropagationTokenSize(this: *mut core::ffi::c_void, pcbtoken: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionTransmitter", "GetPropagationTokenSize", 4usize, |this| ((**(this as *const *const ITransactionTransmitter_Vtbl)).GetPropagationTokenSize)(this, pcbtoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn MarshalPropagationToken(this: *mut core::ffi::c_void, cbtoken: u32, rgbtoken: *mut u8, pcbused: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionTransmitter", "MarshalPropagationToken", 5usize, |this| ((**(this as *const *const ITransactionTransmitter_Vtbl)).MarshalPropagationToken)(this, cbtoken, rgbtoken, pcbused), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnmarshalReturnToken(this: *mut core::ffi::c_void, cbreturntoken: u32, rgbreturntoken: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionTransmitter", "UnmarshalReturnToken", 6usize, |this| ((**(this as *const *const ITransactionTransmitter_Vtbl)).UnmarshalReturnToken)(this, cbreturntoken, rgbreturntoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionTransmitter", "Reset", 7usize, |this| ((**(this as *const *const ITransactionTransmitter_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Set,
+            GetPropagationTokenSize,
+            MarshalPropagationToken,
+            UnmarshalReturnToken,
+            Reset,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionTransmitter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransactionTransmitterFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, pptransmitter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionTransmitterFactory", "Create", 3usize, |this| ((**(this as *const *const ITransactionTransmitterFactory_Vtbl)).Create)(this, pptransmitter), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionTransmitterFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransactionVoterBallotAsync2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VoteRequestDone(this: *mut core::ffi::c_void, hr: windows_core::HRESULT, pboidreason: *const BOID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionVoterBallotAsync2", "VoteRequestDone", 3usize, |this| ((**(this as *const *const ITransactionVoterBallotAsync2_Vtbl)).VoteRequestDone)(this, hr, pboidreason), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, VoteRequestDone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionVoterBallotAsync2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransactionVoterFactory2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, ptransaction: *mut core::ffi::c_void, pvoternotify: *mut core::ffi::c_void, ppvoterballot: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionVoterFactory2", "Create", 3usize, |this| ((**(this as *const *const ITransactionVoterFactory2_Vtbl)).Create)(this, ptransaction, pvoternotify, ppvoterballot), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionVoterFactory2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransactionVoterNotifyAsync2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn VoteRequest(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionVoterNotifyAsync2", "VoteRequest", 7usize, |this| ((**(this as *const *const ITransactionVoterNotifyAsync2_Vtbl)).VoteRequest)(this), |result| Some(*result))
+        }
+        Self { base__: <ITransactionOutcomeEvents_Vtbl as windows_core::imp::ProxyVtable>::PROXY, VoteRequest }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionVoterNotifyAsync2 as windows_core::Interface>::IID || <ITransactionOutcomeEvents_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXAConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, clsidhelperdll: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXAConfig", "Initialize", 3usize, |this| ((**(this as *const *const IXAConfig_Vtbl)).Initialize)(this, clsidhelperdll), |result| Some(*result))
+        }
+        unsafe extern "system" fn Terminate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXAConfig", "Terminate", 4usize, |this| ((**(this as *const *const IXAConfig_Vtbl)).Terminate)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, Terminate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXAConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXAObtainRMInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ObtainRMInfo(this: *mut core::ffi::c_void, pirmhelper: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXAObtainRMInfo", "ObtainRMInfo", 3usize, |this| ((**(this as *const *const IXAObtainRMInfo_Vtbl)).ObtainRMInfo)(this, pirmhelper), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ObtainRMInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXAObtainRMInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXATransLookup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Lookup(this: *mut core::ffi::c_void, pptransaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXATransLookup", "Lookup", 3usize, |this| ((**(this as *const *const IXATransLookup_Vtbl)).Lookup)(this, pptransaction), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Lookup }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXATransLookup as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IXATransLookup2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Lookup(this: *mut core::ffi::c_void, pxid: *const XID, pptransaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IXATransLookup2", "Lookup", 3usize, |this| ((**(this as *const *const IXATransLookup2_Vtbl)).Lookup)(this, pxid, pptransaction), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Lookup }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IXATransLookup2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -166,6 +166,7 @@ pub struct EC_SUBSCRIPTION_CONFIGURATION_MODE(pub i32);
 impl windows_core::TypeKind for EC_SUBSCRIPTION_CONFIGURATION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EC_SUBSCRIPTION_CONFIGURATION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EC_SUBSCRIPTION_CONFIGURATION_MODE").field(&self.0).finish()
@@ -177,6 +178,7 @@ pub struct EC_SUBSCRIPTION_CONTENT_FORMAT(pub i32);
 impl windows_core::TypeKind for EC_SUBSCRIPTION_CONTENT_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EC_SUBSCRIPTION_CONTENT_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EC_SUBSCRIPTION_CONTENT_FORMAT").field(&self.0).finish()
@@ -188,6 +190,7 @@ pub struct EC_SUBSCRIPTION_CREDENTIALS_TYPE(pub i32);
 impl windows_core::TypeKind for EC_SUBSCRIPTION_CREDENTIALS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EC_SUBSCRIPTION_CREDENTIALS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EC_SUBSCRIPTION_CREDENTIALS_TYPE").field(&self.0).finish()
@@ -199,6 +202,7 @@ pub struct EC_SUBSCRIPTION_DELIVERY_MODE(pub i32);
 impl windows_core::TypeKind for EC_SUBSCRIPTION_DELIVERY_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EC_SUBSCRIPTION_DELIVERY_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EC_SUBSCRIPTION_DELIVERY_MODE").field(&self.0).finish()
@@ -210,6 +214,7 @@ pub struct EC_SUBSCRIPTION_PROPERTY_ID(pub i32);
 impl windows_core::TypeKind for EC_SUBSCRIPTION_PROPERTY_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EC_SUBSCRIPTION_PROPERTY_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EC_SUBSCRIPTION_PROPERTY_ID").field(&self.0).finish()
@@ -221,6 +226,7 @@ pub struct EC_SUBSCRIPTION_RUNTIME_STATUS_ACTIVE_STATUS(pub i32);
 impl windows_core::TypeKind for EC_SUBSCRIPTION_RUNTIME_STATUS_ACTIVE_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EC_SUBSCRIPTION_RUNTIME_STATUS_ACTIVE_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EC_SUBSCRIPTION_RUNTIME_STATUS_ACTIVE_STATUS").field(&self.0).finish()
@@ -232,6 +238,7 @@ pub struct EC_SUBSCRIPTION_RUNTIME_STATUS_INFO_ID(pub i32);
 impl windows_core::TypeKind for EC_SUBSCRIPTION_RUNTIME_STATUS_INFO_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EC_SUBSCRIPTION_RUNTIME_STATUS_INFO_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EC_SUBSCRIPTION_RUNTIME_STATUS_INFO_ID").field(&self.0).finish()
@@ -243,6 +250,7 @@ pub struct EC_SUBSCRIPTION_TYPE(pub i32);
 impl windows_core::TypeKind for EC_SUBSCRIPTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EC_SUBSCRIPTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EC_SUBSCRIPTION_TYPE").field(&self.0).finish()
@@ -254,6 +262,7 @@ pub struct EC_VARIANT_TYPE(pub i32);
 impl windows_core::TypeKind for EC_VARIANT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EC_VARIANT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EC_VARIANT_TYPE").field(&self.0).finish()
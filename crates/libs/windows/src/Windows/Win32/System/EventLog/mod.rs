@@ -681,6 +681,7 @@ pub struct EVT_CHANNEL_CLOCK_TYPE(pub i32);
 impl windows_core::TypeKind for EVT_CHANNEL_CLOCK_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EVT_CHANNEL_CLOCK_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EVT_CHANNEL_CLOCK_TYPE").field(&self.0).finish()
@@ -692,6 +693,7 @@ pub struct EVT_CHANNEL_CONFIG_PROPERTY_ID(pub i32);
 impl windows_core::TypeKind for EVT_CHANNEL_CONFIG_PROPERTY_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EVT_CHANNEL_CONFIG_PROPERTY_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EVT_CHANNEL_CONFIG_PROPERTY_ID").field(&self.0).finish()
@@ -703,6 +705,7 @@ pub struct EVT_CHANNEL_ISOLATION_TYPE(pub i32);
 impl windows_core::TypeKind for EVT_CHANNEL_ISOLATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EVT_CHANNEL_ISOLATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EVT_CHANNEL_ISOLATION_TYPE").field(&self.0).finish()
@@ -714,6 +717,7 @@ pub struct EVT_CHANNEL_REFERENCE_FLAGS(pub u32);
 impl windows_core::TypeKind for EVT_CHANNEL_REFERENCE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EVT_CHANNEL_REFERENCE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EVT_CHANNEL_REFERENCE_FLAGS").field(&self.0).finish()
@@ -725,6 +729,7 @@ pub struct EVT_CHANNEL_SID_TYPE(pub i32);
 impl windows_core::TypeKind for EVT_CHANNEL_SID_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EVT_CHANNEL_SID_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EVT_CHANNEL_SID_TYPE").field(&self.0).finish()
@@ -736,6 +741,7 @@ pub struct EVT_CHANNEL_TYPE(pub i32);
 impl windows_core::TypeKind for EVT_CHANNEL_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EVT_CHANNEL_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EVT_CHANNEL_TYPE").field(&self.0).finish()
@@ -747,6 +753,7 @@ pub struct EVT_EVENT_METADATA_PROPERTY_ID(pub i32);
 impl windows_core::TypeKind for EVT_EVENT_METADATA_PROPERTY_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EVT_EVENT_METADATA_PROPERTY_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EVT_EVENT_METADATA_PROPERTY_ID").field(&self.0).finish()
@@ -758,6 +765,7 @@ pub struct EVT_EVENT_PROPERTY_ID(pub i32);
 impl windows_core::TypeKind for EVT_EVENT_PROPERTY_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EVT_EVENT_PROPERTY_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EVT_EVENT_PROPERTY_ID").field(&self.0).finish()
@@ -769,6 +777,7 @@ pub struct EVT_EXPORTLOG_FLAGS(pub u32);
 impl windows_core::TypeKind for EVT_EXPORTLOG_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EVT_EXPORTLOG_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EVT_EXPORTLOG_FLAGS").field(&self.0).finish()
@@ -780,6 +789,7 @@ pub struct EVT_FORMAT_MESSAGE_FLAGS(pub u32);
 impl windows_core::TypeKind for EVT_FORMAT_MESSAGE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EVT_FORMAT_MESSAGE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EVT_FORMAT_MESSAGE_FLAGS").field(&self.0).finish()
@@ -791,6 +801,7 @@ pub struct EVT_LOGIN_CLASS(pub i32);
 impl windows_core::TypeKind for EVT_LOGIN_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EVT_LOGIN_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EVT_LOGIN_CLASS").field(&self.0).finish()
@@ -802,6 +813,7 @@ pub struct EVT_LOG_PROPERTY_ID(pub i32);
 impl windows_core::TypeKind for EVT_LOG_PROPERTY_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EVT_LOG_PROPERTY_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EVT_LOG_PROPERTY_ID").field(&self.0).finish()
@@ -813,6 +825,7 @@ pub struct EVT_OPEN_LOG_FLAGS(pub u32);
 impl windows_core::TypeKind for EVT_OPEN_LOG_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EVT_OPEN_LOG_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EVT_OPEN_LOG_FLAGS").field(&self.0).finish()
@@ -824,6 +837,7 @@ pub struct EVT_PUBLISHER_METADATA_PROPERTY_ID(pub i32);
 impl windows_core::TypeKind for EVT_PUBLISHER_METADATA_PROPERTY_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EVT_PUBLISHER_METADATA_PROPERTY_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EVT_PUBLISHER_METADATA_PROPERTY_ID").field(&self.0).finish()
@@ -835,6 +849,7 @@ pub struct EVT_QUERY_FLAGS(pub u32);
 impl windows_core::TypeKind for EVT_QUERY_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EVT_QUERY_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EVT_QUERY_FLAGS").field(&self.0).finish()
@@ -846,6 +861,7 @@ pub struct EVT_QUERY_PROPERTY_ID(pub i32);
 impl windows_core::TypeKind for EVT_QUERY_PROPERTY_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EVT_QUERY_PROPERTY_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EVT_QUERY_PROPERTY_ID").field(&self.0).finish()
@@ -857,6 +873,7 @@ pub struct EVT_RENDER_CONTEXT_FLAGS(pub u32);
 impl windows_core::TypeKind for EVT_RENDER_CONTEXT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EVT_RENDER_CONTEXT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EVT_RENDER_CONTEXT_FLAGS").field(&self.0).finish()
@@ -868,6 +885,7 @@ pub struct EVT_RENDER_FLAGS(pub u32);
 impl windows_core::TypeKind for EVT_RENDER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EVT_RENDER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EVT_RENDER_FLAGS").field(&self.0).finish()
@@ -879,6 +897,7 @@ pub struct EVT_RPC_LOGIN_FLAGS(pub u32);
 impl windows_core::TypeKind for EVT_RPC_LOGIN_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EVT_RPC_LOGIN_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EVT_RPC_LOGIN_FLAGS").field(&self.0).finish()
@@ -890,6 +909,7 @@ pub struct EVT_SEEK_FLAGS(pub u32);
 impl windows_core::TypeKind for EVT_SEEK_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EVT_SEEK_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EVT_SEEK_FLAGS").field(&self.0).finish()
@@ -901,6 +921,7 @@ pub struct EVT_SUBSCRIBE_FLAGS(pub u32);
 impl windows_core::TypeKind for EVT_SUBSCRIBE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EVT_SUBSCRIBE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EVT_SUBSCRIBE_FLAGS").field(&self.0).finish()
@@ -912,6 +933,7 @@ pub struct EVT_SUBSCRIBE_NOTIFY_ACTION(pub i32);
 impl windows_core::TypeKind for EVT_SUBSCRIBE_NOTIFY_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EVT_SUBSCRIBE_NOTIFY_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EVT_SUBSCRIBE_NOTIFY_ACTION").field(&self.0).finish()
@@ -923,6 +945,7 @@ pub struct EVT_SYSTEM_PROPERTY_ID(pub i32);
 impl windows_core::TypeKind for EVT_SYSTEM_PROPERTY_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EVT_SYSTEM_PROPERTY_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EVT_SYSTEM_PROPERTY_ID").field(&self.0).finish()
@@ -934,6 +957,7 @@ pub struct EVT_VARIANT_TYPE(pub i32);
 impl windows_core::TypeKind for EVT_VARIANT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EVT_VARIANT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EVT_VARIANT_TYPE").field(&self.0).finish()
@@ -945,6 +969,7 @@ pub struct READ_EVENT_LOG_READ_FLAGS(pub u32);
 impl windows_core::TypeKind for READ_EVENT_LOG_READ_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for READ_EVENT_LOG_READ_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("READ_EVENT_LOG_READ_FLAGS").field(&self.0).finish()
@@ -956,13 +981,15 @@ pub struct REPORT_EVENT_TYPE(pub u16);
 impl windows_core::TypeKind for REPORT_EVENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for REPORT_EVENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("REPORT_EVENT_TYPE").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EVENTLOGRECORD {
     pub Length: u32,
     pub Reserved: u32,
@@ -990,7 +1017,8 @@ impl Default for EVENTLOGRECORD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EVENTLOG_FULL_INFORMATION {
     pub dwFull: u32,
 }
@@ -1003,7 +1031,8 @@ impl Default for EVENTLOG_FULL_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EVENTSFORLOGFILE {
     pub ulSize: u32,
     pub szLogicalLogFile: [u16; 256],
@@ -1019,7 +1048,8 @@ impl Default for EVENTSFORLOGFILE {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EVT_HANDLE(pub isize);
 impl EVT_HANDLE {
     pub fn is_invalid(&self) -> bool {
@@ -1043,7 +1073,8 @@ impl windows_core::TypeKind for EVT_HANDLE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EVT_RPC_LOGIN {
     pub Server: windows_core::PWSTR,
     pub User: windows_core::PWSTR,
@@ -1133,3 +1164,4 @@ impl Default for EVT_VARIANT_0 {
     }
 }
 pub type EVT_SUBSCRIBE_CALLBACK = Option<unsafe extern "system" fn(action: EVT_SUBSCRIBE_NOTIFY_ACTION, usercontext: *const core::ffi::c_void, event: EVT_HANDLE) -> u32>;
+core::include!(core::concat!(core::env!("CARGO_MANIFEST_DIR"), "/src/includes/", "Win32/System/EventLog/EventQuery.rs"));
@@ -248,13 +248,15 @@ pub struct SENS_CONNECTION_TYPE(pub u32);
 impl windows_core::TypeKind for SENS_CONNECTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SENS_CONNECTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SENS_CONNECTION_TYPE").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct QOCINFO {
     pub dwSize: u32,
     pub dwFlags: u32,
@@ -271,7 +273,8 @@ impl Default for QOCINFO {
 }
 pub const SENS: windows_core::GUID = windows_core::GUID::from_u128(0xd597cafe_5b9f_11d1_8dd2_00aa004abd5e);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SENS_QOCINFO {
     pub dwSize: u32,
     pub dwFlags: u32,
@@ -288,3 +291,5 @@ impl Default for SENS_QOCINFO {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
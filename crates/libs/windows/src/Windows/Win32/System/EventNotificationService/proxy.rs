@@ -0,0 +1,116 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISensLogon_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Logon(this: *mut core::ffi::c_void, bstrusername: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensLogon", "Logon", 7usize, |this| ((**(this as *const *const ISensLogon_Vtbl)).Logon)(this, bstrusername), |result| Some(*result))
+        }
+        unsafe extern "system" fn Logoff(this: *mut core::ffi::c_void, bstrusername: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensLogon", "Logoff", 8usize, |this| ((**(this as *const *const ISensLogon_Vtbl)).Logoff)(this, bstrusername), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartShell(this: *mut core::ffi::c_void, bstrusername: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensLogon", "StartShell", 9usize, |this| ((**(this as *const *const ISensLogon_Vtbl)).StartShell)(this, bstrusername), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayLock(this: *mut core::ffi::c_void, bstrusername: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensLogon", "DisplayLock", 10usize, |this| ((**(this as *const *const ISensLogon_Vtbl)).DisplayLock)(this, bstrusername), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayUnlock(this: *mut core::ffi::c_void, bstrusername: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensLogon", "DisplayUnlock", 11usize, |this| ((**(this as *const *const ISensLogon_Vtbl)).DisplayUnlock)(this, bstrusername), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartScreenSaver(this: *mut core::ffi::c_void, bstrusername: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensLogon", "StartScreenSaver", 12usize, |this| ((**(this as *const *const ISensLogon_Vtbl)).StartScreenSaver)(this, bstrusername), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopScreenSaver(this: *mut core::ffi::c_void, bstrusername: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensLogon", "StopScreenSaver", 13usize, |this| ((**(this as *const *const ISensLogon_Vtbl)).StopScreenSaver)(this, bstrusername), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Logon,
+            Logoff,
+            StartShell,
+            DisplayLock,
+            DisplayUnlock,
+            StartScreenSaver,
+            StopScreenSaver,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISensLogon as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISensLogon2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Logon(this: *mut core::ffi::c_void, bstrusername: core::mem::MaybeUninit<windows_core::BSTR>, dwsessionid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensLogon2", "Logon", 7usize, |this| ((**(this as *const *const ISensLogon2_Vtbl)).Logon)(this, bstrusername, dwsessionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Logoff(this: *mut core::ffi::c_void, bstrusername: core::mem::MaybeUninit<windows_core::BSTR>, dwsessionid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensLogon2", "Logoff", 8usize, |this| ((**(this as *const *const ISensLogon2_Vtbl)).Logoff)(this, bstrusername, dwsessionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionDisconnect(this: *mut core::ffi::c_void, bstrusername: core::mem::MaybeUninit<windows_core::BSTR>, dwsessionid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensLogon2", "SessionDisconnect", 9usize, |this| ((**(this as *const *const ISensLogon2_Vtbl)).SessionDisconnect)(this, bstrusername, dwsessionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionReconnect(this: *mut core::ffi::c_void, bstrusername: core::mem::MaybeUninit<windows_core::BSTR>, dwsessionid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensLogon2", "SessionReconnect", 10usize, |this| ((**(this as *const *const ISensLogon2_Vtbl)).SessionReconnect)(this, bstrusername, dwsessionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostShell(this: *mut core::ffi::c_void, bstrusername: core::mem::MaybeUninit<windows_core::BSTR>, dwsessionid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensLogon2", "PostShell", 11usize, |this| ((**(this as *const *const ISensLogon2_Vtbl)).PostShell)(this, bstrusername, dwsessionid), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Logon, Logoff, SessionDisconnect, SessionReconnect, PostShell }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISensLogon2 as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISensNetwork_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConnectionMade(this: *mut core::ffi::c_void, bstrconnection: core::mem::MaybeUninit<windows_core::BSTR>, ultype: u32, lpqocinfo: *const SENS_QOCINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensNetwork", "ConnectionMade", 7usize, |this| ((**(this as *const *const ISensNetwork_Vtbl)).ConnectionMade)(this, bstrconnection, ultype, lpqocinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionMadeNoQOCInfo(this: *mut core::ffi::c_void, bstrconnection: core::mem::MaybeUninit<windows_core::BSTR>, ultype: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensNetwork", "ConnectionMadeNoQOCInfo", 8usize, |this| ((**(this as *const *const ISensNetwork_Vtbl)).ConnectionMadeNoQOCInfo)(this, bstrconnection, ultype), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionLost(this: *mut core::ffi::c_void, bstrconnection: core::mem::MaybeUninit<windows_core::BSTR>, ultype: SENS_CONNECTION_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensNetwork", "ConnectionLost", 9usize, |this| ((**(this as *const *const ISensNetwork_Vtbl)).ConnectionLost)(this, bstrconnection, ultype), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestinationReachable(this: *mut core::ffi::c_void, bstrdestination: core::mem::MaybeUninit<windows_core::BSTR>, bstrconnection: core::mem::MaybeUninit<windows_core::BSTR>, ultype: u32, lpqocinfo: *const SENS_QOCINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensNetwork", "DestinationReachable", 10usize, |this| ((**(this as *const *const ISensNetwork_Vtbl)).DestinationReachable)(this, bstrdestination, bstrconnection, ultype, lpqocinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestinationReachableNoQOCInfo(this: *mut core::ffi::c_void, bstrdestination: core::mem::MaybeUninit<windows_core::BSTR>, bstrconnection: core::mem::MaybeUninit<windows_core::BSTR>, ultype: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensNetwork", "DestinationReachableNoQOCInfo", 11usize, |this| ((**(this as *const *const ISensNetwork_Vtbl)).DestinationReachableNoQOCInfo)(this, bstrdestination, bstrconnection, ultype), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ConnectionMade,
+            ConnectionMadeNoQOCInfo,
+            ConnectionLost,
+            DestinationReachable,
+            DestinationReachableNoQOCInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISensNetwork as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISensOnNow_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnACPower(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensOnNow", "OnACPower", 7usize, |this| ((**(this as *const *const ISensOnNow_Vtbl)).OnACPower)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnBatteryPower(this: *mut core::ffi::c_void, dwbatterylifepercent: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensOnNow", "OnBatteryPower", 8usize, |this| ((**(this as *const *const ISensOnNow_Vtbl)).OnBatteryPower)(this, dwbatterylifepercent), |result| Some(*result))
+        }
+        unsafe extern "system" fn BatteryLow(this: *mut core::ffi::c_void, dwbatterylifepercent: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISensOnNow", "BatteryLow", 9usize, |this| ((**(this as *const *const ISensOnNow_Vtbl)).BatteryLow)(this, dwbatterylifepercent), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnACPower, OnBatteryPower, BatteryLow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISensOnNow as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -3652,6 +3652,7 @@ pub struct APPSTATE(pub i32);
 impl windows_core::TypeKind for APPSTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for APPSTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("APPSTATE").field(&self.0).finish()
@@ -3663,6 +3664,7 @@ pub struct GPMBackupType(pub i32);
 impl windows_core::TypeKind for GPMBackupType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GPMBackupType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GPMBackupType").field(&self.0).finish()
@@ -3674,6 +3676,7 @@ pub struct GPMDestinationOption(pub i32);
 impl windows_core::TypeKind for GPMDestinationOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GPMDestinationOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GPMDestinationOption").field(&self.0).finish()
@@ -3685,6 +3688,7 @@ pub struct GPMEntryType(pub i32);
 impl windows_core::TypeKind for GPMEntryType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GPMEntryType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GPMEntryType").field(&self.0).finish()
@@ -3696,6 +3700,7 @@ pub struct GPMPermissionType(pub i32);
 impl windows_core::TypeKind for GPMPermissionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GPMPermissionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GPMPermissionType").field(&self.0).finish()
@@ -3707,6 +3712,7 @@ pub struct GPMRSOPMode(pub i32);
 impl windows_core::TypeKind for GPMRSOPMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GPMRSOPMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GPMRSOPMode").field(&self.0).finish()
@@ -3718,6 +3724,7 @@ pub struct GPMReportType(pub i32);
 impl windows_core::TypeKind for GPMReportType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GPMReportType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GPMReportType").field(&self.0).finish()
@@ -3729,6 +3736,7 @@ pub struct GPMReportingOptions(pub i32);
 impl windows_core::TypeKind for GPMReportingOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GPMReportingOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GPMReportingOptions").field(&self.0).finish()
@@ -3740,6 +3748,7 @@ pub struct GPMSOMType(pub i32);
 impl windows_core::TypeKind for GPMSOMType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GPMSOMType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GPMSOMType").field(&self.0).finish()
@@ -3751,6 +3760,7 @@ pub struct GPMSearchOperation(pub i32);
 impl windows_core::TypeKind for GPMSearchOperation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GPMSearchOperation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GPMSearchOperation").field(&self.0).finish()
@@ -3762,6 +3772,7 @@ pub struct GPMSearchProperty(pub i32);
 impl windows_core::TypeKind for GPMSearchProperty {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GPMSearchProperty {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GPMSearchProperty").field(&self.0).finish()
@@ -3773,6 +3784,7 @@ pub struct GPMStarterGPOType(pub i32);
 impl windows_core::TypeKind for GPMStarterGPOType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GPMStarterGPOType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GPMStarterGPOType").field(&self.0).finish()
@@ -3784,6 +3796,7 @@ pub struct GPO_LINK(pub i32);
 impl windows_core::TypeKind for GPO_LINK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GPO_LINK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GPO_LINK").field(&self.0).finish()
@@ -3795,6 +3808,7 @@ pub struct GPO_OPEN_FLAGS(pub u32);
 impl windows_core::TypeKind for GPO_OPEN_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GPO_OPEN_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GPO_OPEN_FLAGS").field(&self.0).finish()
@@ -3806,6 +3820,7 @@ pub struct GPO_OPTIONS(pub u32);
 impl windows_core::TypeKind for GPO_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GPO_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GPO_OPTIONS").field(&self.0).finish()
@@ -3850,6 +3865,7 @@ pub struct GPO_SECTION(pub u32);
 impl windows_core::TypeKind for GPO_SECTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GPO_SECTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GPO_SECTION").field(&self.0).finish()
@@ -3861,6 +3877,7 @@ pub struct GROUP_POLICY_HINT_TYPE(pub i32);
 impl windows_core::TypeKind for GROUP_POLICY_HINT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GROUP_POLICY_HINT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GROUP_POLICY_HINT_TYPE").field(&self.0).finish()
@@ -3872,6 +3889,7 @@ pub struct GROUP_POLICY_OBJECT_TYPE(pub i32);
 impl windows_core::TypeKind for GROUP_POLICY_OBJECT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GROUP_POLICY_OBJECT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GROUP_POLICY_OBJECT_TYPE").field(&self.0).finish()
@@ -3883,6 +3901,7 @@ pub struct INSTALLSPECTYPE(pub i32);
 impl windows_core::TypeKind for INSTALLSPECTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INSTALLSPECTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INSTALLSPECTYPE").field(&self.0).finish()
@@ -3894,6 +3913,7 @@ pub struct SETTINGSTATUS(pub i32);
 impl windows_core::TypeKind for SETTINGSTATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SETTINGSTATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SETTINGSTATUS").field(&self.0).finish()
@@ -3934,7 +3954,8 @@ pub const GPMTrustee: windows_core::GUID = windows_core::GUID::from_u128(0xc54a7
 pub const GPMWMIFilter: windows_core::GUID = windows_core::GUID::from_u128(0x626745d8_0dea_4062_bf60_cfc5b1ca1286);
 pub const GPMWMIFilterCollection: windows_core::GUID = windows_core::GUID::from_u128(0x74dc6d28_e820_47d6_a0b8_f08d93d7fa33);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GPOBROWSEINFO {
     pub dwSize: u32,
     pub dwFlags: u32,
@@ -3957,7 +3978,8 @@ impl Default for GPOBROWSEINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GROUP_POLICY_OBJECTA {
     pub dwOptions: u32,
     pub dwVersion: u32,
@@ -3982,7 +4004,8 @@ impl Default for GROUP_POLICY_OBJECTA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GROUP_POLICY_OBJECTW {
     pub dwOptions: u32,
     pub dwVersion: u32,
@@ -4037,7 +4060,8 @@ impl Default for INSTALLSPEC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct INSTALLSPEC_0 {
     pub Name: windows_core::PWSTR,
     pub GPOId: windows_core::GUID,
@@ -4051,7 +4075,8 @@ impl Default for INSTALLSPEC_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct INSTALLSPEC_1 {
     pub Clsid: windows_core::GUID,
     pub ClsCtx: u32,
@@ -4065,7 +4090,8 @@ impl Default for INSTALLSPEC_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LOCALMANAGEDAPPLICATION {
     pub pszDeploymentName: windows_core::PWSTR,
     pub pszPolicyName: windows_core::PWSTR,
@@ -4081,7 +4107,8 @@ impl Default for LOCALMANAGEDAPPLICATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MANAGEDAPPLICATION {
     pub pszPackageName: windows_core::PWSTR,
     pub pszPublisher: windows_core::PWSTR,
@@ -4109,7 +4136,8 @@ impl Default for MANAGEDAPPLICATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POLICYSETTINGSTATUSINFO {
     pub szKey: windows_core::PWSTR,
     pub szEventSource: windows_core::PWSTR,
@@ -4129,7 +4157,8 @@ impl Default for POLICYSETTINGSTATUSINFO {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Wmi"))]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RSOP_TARGET {
     pub pwszAccountName: windows_core::PWSTR,
     pub pwszNewSOM: windows_core::PWSTR,
@@ -4163,3 +4192,5 @@ pub type PFNPROCESSGROUPPOLICYEX = Option<unsafe extern "system" fn(dwflags: u32
 pub type PFNSTATUSMESSAGECALLBACK = Option<unsafe extern "system" fn(bverbose: super::super::Foundation::BOOL, lpmessage: windows_core::PCWSTR) -> u32>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
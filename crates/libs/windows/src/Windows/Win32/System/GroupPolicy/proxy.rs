@@ -0,0 +1,2299 @@
+#[cfg(feature = "Win32_System_Registry")]
+impl windows_core::imp::ProxyVtable for IGPEInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, pszname: windows_core::PWSTR, cchmaxlength: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPEInformation", "GetName", 3usize, |this| ((**(this as *const *const IGPEInformation_Vtbl)).GetName)(this, pszname, cchmaxlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayName(this: *mut core::ffi::c_void, pszname: windows_core::PWSTR, cchmaxlength: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPEInformation", "GetDisplayName", 4usize, |this| ((**(this as *const *const IGPEInformation_Vtbl)).GetDisplayName)(this, pszname, cchmaxlength), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Registry")]
+        unsafe extern "system" fn GetRegistryKey(this: *mut core::ffi::c_void, dwsection: u32, hkey: *mut super::Registry::HKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPEInformation", "GetRegistryKey", 5usize, |this| ((**(this as *const *const IGPEInformation_Vtbl)).GetRegistryKey)(this, dwsection, hkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDSPath(this: *mut core::ffi::c_void, dwsection: u32, pszpath: windows_core::PWSTR, cchmaxpath: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPEInformation", "GetDSPath", 6usize, |this| ((**(this as *const *const IGPEInformation_Vtbl)).GetDSPath)(this, dwsection, pszpath, cchmaxpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileSysPath(this: *mut core::ffi::c_void, dwsection: u32, pszpath: windows_core::PWSTR, cchmaxpath: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPEInformation", "GetFileSysPath", 7usize, |this| ((**(this as *const *const IGPEInformation_Vtbl)).GetFileSysPath)(this, dwsection, pszpath, cchmaxpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOptions(this: *mut core::ffi::c_void, dwoptions: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPEInformation", "GetOptions", 8usize, |this| ((**(this as *const *const IGPEInformation_Vtbl)).GetOptions)(this, dwoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, gpotype: *mut GROUP_POLICY_OBJECT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPEInformation", "GetType", 9usize, |this| ((**(this as *const *const IGPEInformation_Vtbl)).GetType)(this, gpotype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHint(this: *mut core::ffi::c_void, gphint: *mut GROUP_POLICY_HINT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPEInformation", "GetHint", 10usize, |this| ((**(this as *const *const IGPEInformation_Vtbl)).GetHint)(this, gphint), |result| Some(*result))
+        }
+        unsafe extern "system" fn PolicyChanged(this: *mut core::ffi::c_void, bmachine: super::super::Foundation::BOOL, badd: super::super::Foundation::BOOL, pguidextension: *mut windows_core::GUID, pguidsnapin: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPEInformation", "PolicyChanged", 11usize, |this| ((**(this as *const *const IGPEInformation_Vtbl)).PolicyChanged)(this, bmachine, badd, pguidextension, pguidsnapin), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetName,
+            GetDisplayName,
+            #[cfg(feature = "Win32_System_Registry")]
+            GetRegistryKey,
+            #[cfg(not(feature = "Win32_System_Registry"))]
+            GetRegistryKey: 0,
+            GetDSPath,
+            GetFileSysPath,
+            GetOptions,
+            GetType,
+            GetHint,
+            PolicyChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPEInformation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPM_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetDomain(this: *mut core::ffi::c_void, bstrdomain: core::mem::MaybeUninit<windows_core::BSTR>, bstrdomaincontroller: core::mem::MaybeUninit<windows_core::BSTR>, ldcflags: i32, pigpmdomain: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPM", "GetDomain", 7usize, |this| ((**(this as *const *const IGPM_Vtbl)).GetDomain)(this, bstrdomain, bstrdomaincontroller, ldcflags, pigpmdomain), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetBackupDir(this: *mut core::ffi::c_void, bstrbackupdir: core::mem::MaybeUninit<windows_core::BSTR>, pigpmbackupdir: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPM", "GetBackupDir", 8usize, |this| ((**(this as *const *const IGPM_Vtbl)).GetBackupDir)(this, bstrbackupdir, pigpmbackupdir), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSitesContainer(this: *mut core::ffi::c_void, bstrforest: core::mem::MaybeUninit<windows_core::BSTR>, bstrdomain: core::mem::MaybeUninit<windows_core::BSTR>, bstrdomaincontroller: core::mem::MaybeUninit<windows_core::BSTR>, ldcflags: i32, ppigpmsitescontainer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPM", "GetSitesContainer", 9usize, |this| ((**(this as *const *const IGPM_Vtbl)).GetSitesContainer)(this, bstrforest, bstrdomain, bstrdomaincontroller, ldcflags, ppigpmsitescontainer), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetRSOP(this: *mut core::ffi::c_void, gpmrsopmode: GPMRSOPMode, bstrnamespace: core::mem::MaybeUninit<windows_core::BSTR>, lflags: i32, ppigpmrsop: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPM", "GetRSOP", 10usize, |this| ((**(this as *const *const IGPM_Vtbl)).GetRSOP)(this, gpmrsopmode, bstrnamespace, lflags, ppigpmrsop), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreatePermission(this: *mut core::ffi::c_void, bstrtrustee: core::mem::MaybeUninit<windows_core::BSTR>, perm: GPMPermissionType, binheritable: super::super::Foundation::VARIANT_BOOL, ppperm: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPM", "CreatePermission", 11usize, |this| ((**(this as *const *const IGPM_Vtbl)).CreatePermission)(this, bstrtrustee, perm, binheritable, ppperm), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateSearchCriteria(this: *mut core::ffi::c_void, ppigpmsearchcriteria: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPM", "CreateSearchCriteria", 12usize, |this| ((**(this as *const *const IGPM_Vtbl)).CreateSearchCriteria)(this, ppigpmsearchcriteria), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateTrustee(this: *mut core::ffi::c_void, bstrtrustee: core::mem::MaybeUninit<windows_core::BSTR>, ppigpmtrustee: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPM", "CreateTrustee", 13usize, |this| ((**(this as *const *const IGPM_Vtbl)).CreateTrustee)(this, bstrtrustee, ppigpmtrustee), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetClientSideExtensions(this: *mut core::ffi::c_void, ppigpmcsecollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPM", "GetClientSideExtensions", 14usize, |this| ((**(this as *const *const IGPM_Vtbl)).GetClientSideExtensions)(this, ppigpmcsecollection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetConstants(this: *mut core::ffi::c_void, ppigpmconstants: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPM", "GetConstants", 15usize, |this| ((**(this as *const *const IGPM_Vtbl)).GetConstants)(this, ppigpmconstants), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetMigrationTable(this: *mut core::ffi::c_void, bstrmigrationtablepath: core::mem::MaybeUninit<windows_core::BSTR>, ppmigrationtable: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPM", "GetMigrationTable", 16usize, |this| ((**(this as *const *const IGPM_Vtbl)).GetMigrationTable)(this, bstrmigrationtablepath, ppmigrationtable), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateMigrationTable(this: *mut core::ffi::c_void, ppmigrationtable: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPM", "CreateMigrationTable", 17usize, |this| ((**(this as *const *const IGPM_Vtbl)).CreateMigrationTable)(this, ppmigrationtable), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeReporting(this: *mut core::ffi::c_void, bstradmpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPM", "InitializeReporting", 18usize, |this| ((**(this as *const *const IGPM_Vtbl)).InitializeReporting)(this, bstradmpath), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetDomain,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetDomain: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetBackupDir,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetBackupDir: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSitesContainer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSitesContainer: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetRSOP,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetRSOP: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreatePermission,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreatePermission: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateSearchCriteria,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateSearchCriteria: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateTrustee,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateTrustee: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetClientSideExtensions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetClientSideExtensions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetConstants,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetConstants: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetMigrationTable,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetMigrationTable: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateMigrationTable,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateMigrationTable: 0,
+            InitializeReporting,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPM as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPM2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetBackupDirEx(this: *mut core::ffi::c_void, bstrbackupdir: core::mem::MaybeUninit<windows_core::BSTR>, backupdirtype: GPMBackupType, ppigpmbackupdirex: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPM2", "GetBackupDirEx", 19usize, |this| ((**(this as *const *const IGPM2_Vtbl)).GetBackupDirEx)(this, bstrbackupdir, backupdirtype, ppigpmbackupdirex), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeReportingEx(this: *mut core::ffi::c_void, bstradmpath: core::mem::MaybeUninit<windows_core::BSTR>, reportingoptions: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPM2", "InitializeReportingEx", 20usize, |this| ((**(this as *const *const IGPM2_Vtbl)).InitializeReportingEx)(this, bstradmpath, reportingoptions), |result| Some(*result))
+        }
+        Self {
+            base__: <IGPM_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetBackupDirEx,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetBackupDirEx: 0,
+            InitializeReportingEx,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPM2 as windows_core::Interface>::IID || <IGPM_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPMAsyncCancel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMAsyncCancel", "Cancel", 7usize, |this| ((**(this as *const *const IGPMAsyncCancel_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Cancel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMAsyncCancel as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPMAsyncProgress_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, lprogressnumerator: i32, lprogressdenominator: i32, hrstatus: windows_core::HRESULT, presult: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppigpmstatusmsgcollection: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMAsyncProgress", "Status", 7usize, |this| ((**(this as *const *const IGPMAsyncProgress_Vtbl)).Status)(this, lprogressnumerator, lprogressdenominator, hrstatus, presult, ppigpmstatusmsgcollection), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Status,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Status: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMAsyncProgress as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPMBackup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ID(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMBackup", "ID", 7usize, |this| ((**(this as *const *const IGPMBackup_Vtbl)).ID)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GPOID(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMBackup", "GPOID", 8usize, |this| ((**(this as *const *const IGPMBackup_Vtbl)).GPOID)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GPODomain(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMBackup", "GPODomain", 9usize, |this| ((**(this as *const *const IGPMBackup_Vtbl)).GPODomain)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GPODisplayName(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMBackup", "GPODisplayName", 10usize, |this| ((**(this as *const *const IGPMBackup_Vtbl)).GPODisplayName)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, pval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMBackup", "Timestamp", 11usize, |this| ((**(this as *const *const IGPMBackup_Vtbl)).Timestamp)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Comment(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMBackup", "Comment", 12usize, |this| ((**(this as *const *const IGPMBackup_Vtbl)).Comment)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackupDir(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMBackup", "BackupDir", 13usize, |this| ((**(this as *const *const IGPMBackup_Vtbl)).BackupDir)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMBackup", "Delete", 14usize, |this| ((**(this as *const *const IGPMBackup_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GenerateReport(this: *mut core::ffi::c_void, gpmreporttype: GPMReportType, pvargpmprogress: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvargpmcancel: *mut core::mem::MaybeUninit<windows_core::VARIANT>, ppigpmresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMBackup", "GenerateReport", 15usize, |this| ((**(this as *const *const IGPMBackup_Vtbl)).GenerateReport)(this, gpmreporttype, pvargpmprogress, pvargpmcancel, ppigpmresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GenerateReportToFile(this: *mut core::ffi::c_void, gpmreporttype: GPMReportType, bstrtargetfilepath: core::mem::MaybeUninit<windows_core::BSTR>, ppigpmresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMBackup", "GenerateReportToFile", 16usize, |this| ((**(this as *const *const IGPMBackup_Vtbl)).GenerateReportToFile)(this, gpmreporttype, bstrtargetfilepath, ppigpmresult), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ID,
+            GPOID,
+            GPODomain,
+            GPODisplayName,
+            Timestamp,
+            Comment,
+            BackupDir,
+            Delete,
+            #[cfg(feature = "Win32_System_Com")]
+            GenerateReport,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GenerateReport: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GenerateReportToFile,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GenerateReportToFile: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMBackup as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IGPMBackupCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMBackupCollection", "Count", 7usize, |this| ((**(this as *const *const IGPMBackupCollection_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, lindex: i32, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMBackupCollection", "get_Item", 8usize, |this| ((**(this as *const *const IGPMBackupCollection_Vtbl)).get_Item)(this, lindex, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppigpmbackup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMBackupCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const IGPMBackupCollection_Vtbl))._NewEnum)(this, ppigpmbackup), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            get_Item,
+            #[cfg(feature = "Win32_System_Ole")]
+            _NewEnum,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            _NewEnum: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMBackupCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPMBackupDir_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BackupDirectory(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMBackupDir", "BackupDirectory", 7usize, |this| ((**(this as *const *const IGPMBackupDir_Vtbl)).BackupDirectory)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetBackup(this: *mut core::ffi::c_void, bstrid: core::mem::MaybeUninit<windows_core::BSTR>, ppbackup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMBackupDir", "GetBackup", 8usize, |this| ((**(this as *const *const IGPMBackupDir_Vtbl)).GetBackup)(this, bstrid, ppbackup), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SearchBackups(this: *mut core::ffi::c_void, pigpmsearchcriteria: *mut core::ffi::c_void, ppigpmbackupcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMBackupDir", "SearchBackups", 9usize, |this| ((**(this as *const *const IGPMBackupDir_Vtbl)).SearchBackups)(this, pigpmsearchcriteria, ppigpmbackupcollection), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BackupDirectory,
+            #[cfg(feature = "Win32_System_Com")]
+            GetBackup,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetBackup: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SearchBackups,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SearchBackups: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMBackupDir as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPMBackupDirEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BackupDir(this: *mut core::ffi::c_void, pbstrbackupdir: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMBackupDirEx", "BackupDir", 7usize, |this| ((**(this as *const *const IGPMBackupDirEx_Vtbl)).BackupDir)(this, pbstrbackupdir), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackupType(this: *mut core::ffi::c_void, pgpmbackuptype: *mut GPMBackupType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMBackupDirEx", "BackupType", 8usize, |this| ((**(this as *const *const IGPMBackupDirEx_Vtbl)).BackupType)(this, pgpmbackuptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBackup(this: *mut core::ffi::c_void, bstrid: core::mem::MaybeUninit<windows_core::BSTR>, pvarbackup: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMBackupDirEx", "GetBackup", 9usize, |this| ((**(this as *const *const IGPMBackupDirEx_Vtbl)).GetBackup)(this, bstrid, pvarbackup), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SearchBackups(this: *mut core::ffi::c_void, pigpmsearchcriteria: *mut core::ffi::c_void, pvarbackupcollection: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMBackupDirEx", "SearchBackups", 10usize, |this| ((**(this as *const *const IGPMBackupDirEx_Vtbl)).SearchBackups)(this, pigpmsearchcriteria, pvarbackupcollection), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BackupDir,
+            BackupType,
+            GetBackup,
+            #[cfg(feature = "Win32_System_Com")]
+            SearchBackups,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SearchBackups: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMBackupDirEx as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IGPMCSECollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMCSECollection", "Count", 7usize, |this| ((**(this as *const *const IGPMCSECollection_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, lindex: i32, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMCSECollection", "get_Item", 8usize, |this| ((**(this as *const *const IGPMCSECollection_Vtbl)).get_Item)(this, lindex, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppigpmcses: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMCSECollection", "_NewEnum", 9usize, |this| ((**(this as *const *const IGPMCSECollection_Vtbl))._NewEnum)(this, ppigpmcses), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            get_Item,
+            #[cfg(feature = "Win32_System_Ole")]
+            _NewEnum,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            _NewEnum: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMCSECollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPMClientSideExtension_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ID(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMClientSideExtension", "ID", 7usize, |this| ((**(this as *const *const IGPMClientSideExtension_Vtbl)).ID)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMClientSideExtension", "DisplayName", 8usize, |this| ((**(this as *const *const IGPMClientSideExtension_Vtbl)).DisplayName)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUserEnabled(this: *mut core::ffi::c_void, pvbenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMClientSideExtension", "IsUserEnabled", 9usize, |this| ((**(this as *const *const IGPMClientSideExtension_Vtbl)).IsUserEnabled)(this, pvbenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsComputerEnabled(this: *mut core::ffi::c_void, pvbenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMClientSideExtension", "IsComputerEnabled", 10usize, |this| ((**(this as *const *const IGPMClientSideExtension_Vtbl)).IsComputerEnabled)(this, pvbenabled), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ID, DisplayName, IsUserEnabled, IsComputerEnabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMClientSideExtension as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPMConstants_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PermGPOApply(this: *mut core::ffi::c_void, pval: *mut GPMPermissionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "PermGPOApply", 7usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).PermGPOApply)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PermGPORead(this: *mut core::ffi::c_void, pval: *mut GPMPermissionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "PermGPORead", 8usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).PermGPORead)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PermGPOEdit(this: *mut core::ffi::c_void, pval: *mut GPMPermissionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "PermGPOEdit", 9usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).PermGPOEdit)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PermGPOEditSecurityAndDelete(this: *mut core::ffi::c_void, pval: *mut GPMPermissionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "PermGPOEditSecurityAndDelete", 10usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).PermGPOEditSecurityAndDelete)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PermGPOCustom(this: *mut core::ffi::c_void, pval: *mut GPMPermissionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "PermGPOCustom", 11usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).PermGPOCustom)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PermWMIFilterEdit(this: *mut core::ffi::c_void, pval: *mut GPMPermissionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "PermWMIFilterEdit", 12usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).PermWMIFilterEdit)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PermWMIFilterFullControl(this: *mut core::ffi::c_void, pval: *mut GPMPermissionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "PermWMIFilterFullControl", 13usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).PermWMIFilterFullControl)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PermWMIFilterCustom(this: *mut core::ffi::c_void, pval: *mut GPMPermissionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "PermWMIFilterCustom", 14usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).PermWMIFilterCustom)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PermSOMLink(this: *mut core::ffi::c_void, pval: *mut GPMPermissionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "PermSOMLink", 15usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).PermSOMLink)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PermSOMLogging(this: *mut core::ffi::c_void, pval: *mut GPMPermissionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "PermSOMLogging", 16usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).PermSOMLogging)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PermSOMPlanning(this: *mut core::ffi::c_void, pval: *mut GPMPermissionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "PermSOMPlanning", 17usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).PermSOMPlanning)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PermSOMGPOCreate(this: *mut core::ffi::c_void, pval: *mut GPMPermissionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "PermSOMGPOCreate", 18usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).PermSOMGPOCreate)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PermSOMWMICreate(this: *mut core::ffi::c_void, pval: *mut GPMPermissionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "PermSOMWMICreate", 19usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).PermSOMWMICreate)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PermSOMWMIFullControl(this: *mut core::ffi::c_void, pval: *mut GPMPermissionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "PermSOMWMIFullControl", 20usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).PermSOMWMIFullControl)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchPropertyGPOPermissions(this: *mut core::ffi::c_void, pval: *mut GPMSearchProperty) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "SearchPropertyGPOPermissions", 21usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).SearchPropertyGPOPermissions)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchPropertyGPOEffectivePermissions(this: *mut core::ffi::c_void, pval: *mut GPMSearchProperty) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "SearchPropertyGPOEffectivePermissions", 22usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).SearchPropertyGPOEffectivePermissions)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchPropertyGPODisplayName(this: *mut core::ffi::c_void, pval: *mut GPMSearchProperty) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "SearchPropertyGPODisplayName", 23usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).SearchPropertyGPODisplayName)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchPropertyGPOWMIFilter(this: *mut core::ffi::c_void, pval: *mut GPMSearchProperty) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "SearchPropertyGPOWMIFilter", 24usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).SearchPropertyGPOWMIFilter)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchPropertyGPOID(this: *mut core::ffi::c_void, pval: *mut GPMSearchProperty) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "SearchPropertyGPOID", 25usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).SearchPropertyGPOID)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchPropertyGPOComputerExtensions(this: *mut core::ffi::c_void, pval: *mut GPMSearchProperty) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "SearchPropertyGPOComputerExtensions", 26usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).SearchPropertyGPOComputerExtensions)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchPropertyGPOUserExtensions(this: *mut core::ffi::c_void, pval: *mut GPMSearchProperty) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "SearchPropertyGPOUserExtensions", 27usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).SearchPropertyGPOUserExtensions)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchPropertySOMLinks(this: *mut core::ffi::c_void, pval: *mut GPMSearchProperty) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "SearchPropertySOMLinks", 28usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).SearchPropertySOMLinks)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchPropertyGPODomain(this: *mut core::ffi::c_void, pval: *mut GPMSearchProperty) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "SearchPropertyGPODomain", 29usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).SearchPropertyGPODomain)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchPropertyBackupMostRecent(this: *mut core::ffi::c_void, pval: *mut GPMSearchProperty) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "SearchPropertyBackupMostRecent", 30usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).SearchPropertyBackupMostRecent)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchOpEquals(this: *mut core::ffi::c_void, pval: *mut GPMSearchOperation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "SearchOpEquals", 31usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).SearchOpEquals)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchOpContains(this: *mut core::ffi::c_void, pval: *mut GPMSearchOperation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "SearchOpContains", 32usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).SearchOpContains)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchOpNotContains(this: *mut core::ffi::c_void, pval: *mut GPMSearchOperation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "SearchOpNotContains", 33usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).SearchOpNotContains)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchOpNotEquals(this: *mut core::ffi::c_void, pval: *mut GPMSearchOperation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "SearchOpNotEquals", 34usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).SearchOpNotEquals)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn UsePDC(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "UsePDC", 35usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).UsePDC)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseAnyDC(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "UseAnyDC", 36usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).UseAnyDC)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoNotUseW2KDC(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "DoNotUseW2KDC", 37usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).DoNotUseW2KDC)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SOMSite(this: *mut core::ffi::c_void, pval: *mut GPMSOMType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "SOMSite", 38usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).SOMSite)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SOMDomain(this: *mut core::ffi::c_void, pval: *mut GPMSOMType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "SOMDomain", 39usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).SOMDomain)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SOMOU(this: *mut core::ffi::c_void, pval: *mut GPMSOMType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "SOMOU", 40usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).SOMOU)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_SecurityFlags(this: *mut core::ffi::c_void, vbowner: super::super::Foundation::VARIANT_BOOL, vbgroup: super::super::Foundation::VARIANT_BOOL, vbdacl: super::super::Foundation::VARIANT_BOOL, vbsacl: super::super::Foundation::VARIANT_BOOL, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "get_SecurityFlags", 41usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).get_SecurityFlags)(this, vbowner, vbgroup, vbdacl, vbsacl, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoNotValidateDC(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "DoNotValidateDC", 42usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).DoNotValidateDC)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportHTML(this: *mut core::ffi::c_void, pval: *mut GPMReportType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "ReportHTML", 43usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).ReportHTML)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportXML(this: *mut core::ffi::c_void, pval: *mut GPMReportType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "ReportXML", 44usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).ReportXML)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RSOPModeUnknown(this: *mut core::ffi::c_void, pval: *mut GPMRSOPMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "RSOPModeUnknown", 45usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).RSOPModeUnknown)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RSOPModePlanning(this: *mut core::ffi::c_void, pval: *mut GPMRSOPMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "RSOPModePlanning", 46usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).RSOPModePlanning)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RSOPModeLogging(this: *mut core::ffi::c_void, pval: *mut GPMRSOPMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "RSOPModeLogging", 47usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).RSOPModeLogging)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn EntryTypeUser(this: *mut core::ffi::c_void, pval: *mut GPMEntryType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "EntryTypeUser", 48usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).EntryTypeUser)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn EntryTypeComputer(this: *mut core::ffi::c_void, pval: *mut GPMEntryType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "EntryTypeComputer", 49usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).EntryTypeComputer)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn EntryTypeLocalGroup(this: *mut core::ffi::c_void, pval: *mut GPMEntryType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "EntryTypeLocalGroup", 50usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).EntryTypeLocalGroup)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn EntryTypeGlobalGroup(this: *mut core::ffi::c_void, pval: *mut GPMEntryType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "EntryTypeGlobalGroup", 51usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).EntryTypeGlobalGroup)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn EntryTypeUniversalGroup(this: *mut core::ffi::c_void, pval: *mut GPMEntryType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "EntryTypeUniversalGroup", 52usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).EntryTypeUniversalGroup)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn EntryTypeUNCPath(this: *mut core::ffi::c_void, pval: *mut GPMEntryType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "EntryTypeUNCPath", 53usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).EntryTypeUNCPath)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn EntryTypeUnknown(this: *mut core::ffi::c_void, pval: *mut GPMEntryType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "EntryTypeUnknown", 54usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).EntryTypeUnknown)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestinationOptionSameAsSource(this: *mut core::ffi::c_void, pval: *mut GPMDestinationOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "DestinationOptionSameAsSource", 55usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).DestinationOptionSameAsSource)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestinationOptionNone(this: *mut core::ffi::c_void, pval: *mut GPMDestinationOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "DestinationOptionNone", 56usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).DestinationOptionNone)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestinationOptionByRelativeName(this: *mut core::ffi::c_void, pval: *mut GPMDestinationOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "DestinationOptionByRelativeName", 57usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).DestinationOptionByRelativeName)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestinationOptionSet(this: *mut core::ffi::c_void, pval: *mut GPMDestinationOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "DestinationOptionSet", 58usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).DestinationOptionSet)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn MigrationTableOnly(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "MigrationTableOnly", 59usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).MigrationTableOnly)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessSecurity(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "ProcessSecurity", 60usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).ProcessSecurity)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RsopLoggingNoComputer(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "RsopLoggingNoComputer", 61usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).RsopLoggingNoComputer)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RsopLoggingNoUser(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "RsopLoggingNoUser", 62usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).RsopLoggingNoUser)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RsopPlanningAssumeSlowLink(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "RsopPlanningAssumeSlowLink", 63usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).RsopPlanningAssumeSlowLink)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_RsopPlanningLoopbackOption(this: *mut core::ffi::c_void, vbmerge: super::super::Foundation::VARIANT_BOOL, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "get_RsopPlanningLoopbackOption", 64usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).get_RsopPlanningLoopbackOption)(this, vbmerge, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RsopPlanningAssumeUserWQLFilterTrue(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "RsopPlanningAssumeUserWQLFilterTrue", 65usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).RsopPlanningAssumeUserWQLFilterTrue)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RsopPlanningAssumeCompWQLFilterTrue(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants", "RsopPlanningAssumeCompWQLFilterTrue", 66usize, |this| ((**(this as *const *const IGPMConstants_Vtbl)).RsopPlanningAssumeCompWQLFilterTrue)(this, pval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PermGPOApply,
+            PermGPORead,
+            PermGPOEdit,
+            PermGPOEditSecurityAndDelete,
+            PermGPOCustom,
+            PermWMIFilterEdit,
+            PermWMIFilterFullControl,
+            PermWMIFilterCustom,
+            PermSOMLink,
+            PermSOMLogging,
+            PermSOMPlanning,
+            PermSOMGPOCreate,
+            PermSOMWMICreate,
+            PermSOMWMIFullControl,
+            SearchPropertyGPOPermissions,
+            SearchPropertyGPOEffectivePermissions,
+            SearchPropertyGPODisplayName,
+            SearchPropertyGPOWMIFilter,
+            SearchPropertyGPOID,
+            SearchPropertyGPOComputerExtensions,
+            SearchPropertyGPOUserExtensions,
+            SearchPropertySOMLinks,
+            SearchPropertyGPODomain,
+            SearchPropertyBackupMostRecent,
+            SearchOpEquals,
+            SearchOpContains,
+            SearchOpNotContains,
+            SearchOpNotEquals,
+            UsePDC,
+            UseAnyDC,
+            DoNotUseW2KDC,
+            SOMSite,
+            SOMDomain,
+            SOMOU,
+            get_SecurityFlags,
+            DoNotValidateDC,
+            ReportHTML,
+            ReportXML,
+            RSOPModeUnknown,
+            RSOPModePlanning,
+            RSOPModeLogging,
+            EntryTypeUser,
+            EntryTypeComputer,
+            EntryTypeLocalGroup,
+            EntryTypeGlobalGroup,
+            EntryTypeUniversalGroup,
+            EntryTypeUNCPath,
+            EntryTypeUnknown,
+            DestinationOptionSameAsSource,
+            DestinationOptionNone,
+            DestinationOptionByRelativeName,
+            DestinationOptionSet,
+            MigrationTableOnly,
+            ProcessSecurity,
+            RsopLoggingNoComputer,
+            RsopLoggingNoUser,
+            RsopPlanningAssumeSlowLink,
+            get_RsopPlanningLoopbackOption,
+            RsopPlanningAssumeUserWQLFilterTrue,
+            RsopPlanningAssumeCompWQLFilterTrue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMConstants as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPMConstants2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BackupTypeGPO(this: *mut core::ffi::c_void, pval: *mut GPMBackupType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants2", "BackupTypeGPO", 67usize, |this| ((**(this as *const *const IGPMConstants2_Vtbl)).BackupTypeGPO)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackupTypeStarterGPO(this: *mut core::ffi::c_void, pval: *mut GPMBackupType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants2", "BackupTypeStarterGPO", 68usize, |this| ((**(this as *const *const IGPMConstants2_Vtbl)).BackupTypeStarterGPO)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn StarterGPOTypeSystem(this: *mut core::ffi::c_void, pval: *mut GPMStarterGPOType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants2", "StarterGPOTypeSystem", 69usize, |this| ((**(this as *const *const IGPMConstants2_Vtbl)).StarterGPOTypeSystem)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn StarterGPOTypeCustom(this: *mut core::ffi::c_void, pval: *mut GPMStarterGPOType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants2", "StarterGPOTypeCustom", 70usize, |this| ((**(this as *const *const IGPMConstants2_Vtbl)).StarterGPOTypeCustom)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchPropertyStarterGPOPermissions(this: *mut core::ffi::c_void, pval: *mut GPMSearchProperty) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants2", "SearchPropertyStarterGPOPermissions", 71usize, |this| ((**(this as *const *const IGPMConstants2_Vtbl)).SearchPropertyStarterGPOPermissions)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchPropertyStarterGPOEffectivePermissions(this: *mut core::ffi::c_void, pval: *mut GPMSearchProperty) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants2", "SearchPropertyStarterGPOEffectivePermissions", 72usize, |this| ((**(this as *const *const IGPMConstants2_Vtbl)).SearchPropertyStarterGPOEffectivePermissions)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchPropertyStarterGPODisplayName(this: *mut core::ffi::c_void, pval: *mut GPMSearchProperty) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants2", "SearchPropertyStarterGPODisplayName", 73usize, |this| ((**(this as *const *const IGPMConstants2_Vtbl)).SearchPropertyStarterGPODisplayName)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchPropertyStarterGPOID(this: *mut core::ffi::c_void, pval: *mut GPMSearchProperty) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants2", "SearchPropertyStarterGPOID", 74usize, |this| ((**(this as *const *const IGPMConstants2_Vtbl)).SearchPropertyStarterGPOID)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchPropertyStarterGPODomain(this: *mut core::ffi::c_void, pval: *mut GPMSearchProperty) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants2", "SearchPropertyStarterGPODomain", 75usize, |this| ((**(this as *const *const IGPMConstants2_Vtbl)).SearchPropertyStarterGPODomain)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PermStarterGPORead(this: *mut core::ffi::c_void, pval: *mut GPMPermissionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants2", "PermStarterGPORead", 76usize, |this| ((**(this as *const *const IGPMConstants2_Vtbl)).PermStarterGPORead)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PermStarterGPOEdit(this: *mut core::ffi::c_void, pval: *mut GPMPermissionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants2", "PermStarterGPOEdit", 77usize, |this| ((**(this as *const *const IGPMConstants2_Vtbl)).PermStarterGPOEdit)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PermStarterGPOFullControl(this: *mut core::ffi::c_void, pval: *mut GPMPermissionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants2", "PermStarterGPOFullControl", 78usize, |this| ((**(this as *const *const IGPMConstants2_Vtbl)).PermStarterGPOFullControl)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PermStarterGPOCustom(this: *mut core::ffi::c_void, pval: *mut GPMPermissionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants2", "PermStarterGPOCustom", 79usize, |this| ((**(this as *const *const IGPMConstants2_Vtbl)).PermStarterGPOCustom)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportLegacy(this: *mut core::ffi::c_void, pval: *mut GPMReportingOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants2", "ReportLegacy", 80usize, |this| ((**(this as *const *const IGPMConstants2_Vtbl)).ReportLegacy)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportComments(this: *mut core::ffi::c_void, pval: *mut GPMReportingOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMConstants2", "ReportComments", 81usize, |this| ((**(this as *const *const IGPMConstants2_Vtbl)).ReportComments)(this, pval), |result| Some(*result))
+        }
+        Self {
+            base__: <IGPMConstants_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BackupTypeGPO,
+            BackupTypeStarterGPO,
+            StarterGPOTypeSystem,
+            StarterGPOTypeCustom,
+            SearchPropertyStarterGPOPermissions,
+            SearchPropertyStarterGPOEffectivePermissions,
+            SearchPropertyStarterGPODisplayName,
+            SearchPropertyStarterGPOID,
+            SearchPropertyStarterGPODomain,
+            PermStarterGPORead,
+            PermStarterGPOEdit,
+            PermStarterGPOFullControl,
+            PermStarterGPOCustom,
+            ReportLegacy,
+            ReportComments,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMConstants2 as windows_core::Interface>::IID || <IGPMConstants_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPMDomain_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DomainController(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMDomain", "DomainController", 7usize, |this| ((**(this as *const *const IGPMDomain_Vtbl)).DomainController)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Domain(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMDomain", "Domain", 8usize, |this| ((**(this as *const *const IGPMDomain_Vtbl)).Domain)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateGPO(this: *mut core::ffi::c_void, ppnewgpo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMDomain", "CreateGPO", 9usize, |this| ((**(this as *const *const IGPMDomain_Vtbl)).CreateGPO)(this, ppnewgpo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetGPO(this: *mut core::ffi::c_void, bstrguid: core::mem::MaybeUninit<windows_core::BSTR>, ppgpo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMDomain", "GetGPO", 10usize, |this| ((**(this as *const *const IGPMDomain_Vtbl)).GetGPO)(this, bstrguid, ppgpo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SearchGPOs(this: *mut core::ffi::c_void, pigpmsearchcriteria: *mut core::ffi::c_void, ppigpmgpocollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMDomain", "SearchGPOs", 11usize, |this| ((**(this as *const *const IGPMDomain_Vtbl)).SearchGPOs)(this, pigpmsearchcriteria, ppigpmgpocollection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RestoreGPO(this: *mut core::ffi::c_void, pigpmbackup: *mut core::ffi::c_void, ldcflags: i32, pvargpmprogress: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvargpmcancel: *mut core::mem::MaybeUninit<windows_core::VARIANT>, ppigpmresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMDomain", "RestoreGPO", 12usize, |this| ((**(this as *const *const IGPMDomain_Vtbl)).RestoreGPO)(this, pigpmbackup, ldcflags, pvargpmprogress, pvargpmcancel, ppigpmresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSOM(this: *mut core::ffi::c_void, bstrpath: core::mem::MaybeUninit<windows_core::BSTR>, ppsom: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMDomain", "GetSOM", 13usize, |this| ((**(this as *const *const IGPMDomain_Vtbl)).GetSOM)(this, bstrpath, ppsom), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SearchSOMs(this: *mut core::ffi::c_void, pigpmsearchcriteria: *mut core::ffi::c_void, ppigpmsomcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMDomain", "SearchSOMs", 14usize, |this| ((**(this as *const *const IGPMDomain_Vtbl)).SearchSOMs)(this, pigpmsearchcriteria, ppigpmsomcollection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetWMIFilter(this: *mut core::ffi::c_void, bstrpath: core::mem::MaybeUninit<windows_core::BSTR>, ppwmifilter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMDomain", "GetWMIFilter", 15usize, |this| ((**(this as *const *const IGPMDomain_Vtbl)).GetWMIFilter)(this, bstrpath, ppwmifilter), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SearchWMIFilters(this: *mut core::ffi::c_void, pigpmsearchcriteria: *mut core::ffi::c_void, ppigpmwmifiltercollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMDomain", "SearchWMIFilters", 16usize, |this| ((**(this as *const *const IGPMDomain_Vtbl)).SearchWMIFilters)(this, pigpmsearchcriteria, ppigpmwmifiltercollection), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DomainController,
+            Domain,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateGPO,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateGPO: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetGPO,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetGPO: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SearchGPOs,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SearchGPOs: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            RestoreGPO,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RestoreGPO: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSOM,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSOM: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SearchSOMs,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SearchSOMs: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetWMIFilter,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetWMIFilter: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SearchWMIFilters,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SearchWMIFilters: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMDomain as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPMDomain2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateStarterGPO(this: *mut core::ffi::c_void, ppnewtemplate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMDomain2", "CreateStarterGPO", 17usize, |this| ((**(this as *const *const IGPMDomain2_Vtbl)).CreateStarterGPO)(this, ppnewtemplate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateGPOFromStarterGPO(this: *mut core::ffi::c_void, pgpotemplate: *mut core::ffi::c_void, ppnewgpo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMDomain2", "CreateGPOFromStarterGPO", 18usize, |this| ((**(this as *const *const IGPMDomain2_Vtbl)).CreateGPOFromStarterGPO)(this, pgpotemplate, ppnewgpo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetStarterGPO(this: *mut core::ffi::c_void, bstrguid: core::mem::MaybeUninit<windows_core::BSTR>, pptemplate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMDomain2", "GetStarterGPO", 19usize, |this| ((**(this as *const *const IGPMDomain2_Vtbl)).GetStarterGPO)(this, bstrguid, pptemplate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SearchStarterGPOs(this: *mut core::ffi::c_void, pigpmsearchcriteria: *mut core::ffi::c_void, ppigpmtemplatecollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMDomain2", "SearchStarterGPOs", 20usize, |this| ((**(this as *const *const IGPMDomain2_Vtbl)).SearchStarterGPOs)(this, pigpmsearchcriteria, ppigpmtemplatecollection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn LoadStarterGPO(this: *mut core::ffi::c_void, bstrloadfile: core::mem::MaybeUninit<windows_core::BSTR>, boverwrite: super::super::Foundation::VARIANT_BOOL, pvargpmprogress: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvargpmcancel: *mut core::mem::MaybeUninit<windows_core::VARIANT>, ppigpmresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMDomain2", "LoadStarterGPO", 21usize, |this| ((**(this as *const *const IGPMDomain2_Vtbl)).LoadStarterGPO)(this, bstrloadfile, boverwrite, pvargpmprogress, pvargpmcancel, ppigpmresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RestoreStarterGPO(this: *mut core::ffi::c_void, pigpmtmplbackup: *mut core::ffi::c_void, pvargpmprogress: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvargpmcancel: *mut core::mem::MaybeUninit<windows_core::VARIANT>, ppigpmresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMDomain2", "RestoreStarterGPO", 22usize, |this| ((**(this as *const *const IGPMDomain2_Vtbl)).RestoreStarterGPO)(this, pigpmtmplbackup, pvargpmprogress, pvargpmcancel, ppigpmresult), |result| Some(*result))
+        }
+        Self {
+            base__: <IGPMDomain_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateStarterGPO,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateStarterGPO: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateGPOFromStarterGPO,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateGPOFromStarterGPO: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetStarterGPO,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetStarterGPO: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SearchStarterGPOs,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SearchStarterGPOs: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            LoadStarterGPO,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            LoadStarterGPO: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            RestoreStarterGPO,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RestoreStarterGPO: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMDomain2 as windows_core::Interface>::IID || <IGPMDomain_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPMDomain3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GenerateReport(this: *mut core::ffi::c_void, gpmreporttype: GPMReportType, pvargpmprogress: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvargpmcancel: *mut core::mem::MaybeUninit<windows_core::VARIANT>, ppigpmresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMDomain3", "GenerateReport", 23usize, |this| ((**(this as *const *const IGPMDomain3_Vtbl)).GenerateReport)(this, gpmreporttype, pvargpmprogress, pvargpmcancel, ppigpmresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn InfrastructureDC(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMDomain3", "InfrastructureDC", 24usize, |this| ((**(this as *const *const IGPMDomain3_Vtbl)).InfrastructureDC)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInfrastructureDC(this: *mut core::ffi::c_void, newval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMDomain3", "SetInfrastructureDC", 25usize, |this| ((**(this as *const *const IGPMDomain3_Vtbl)).SetInfrastructureDC)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInfrastructureFlags(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMDomain3", "SetInfrastructureFlags", 26usize, |this| ((**(this as *const *const IGPMDomain3_Vtbl)).SetInfrastructureFlags)(this, dwflags), |result| Some(*result))
+        }
+        Self {
+            base__: <IGPMDomain2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GenerateReport,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GenerateReport: 0,
+            InfrastructureDC,
+            SetInfrastructureDC,
+            SetInfrastructureFlags,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMDomain3 as windows_core::Interface>::IID || <IGPMDomain2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPMGPO_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "DisplayName", 7usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).DisplayName)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, newval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "SetDisplayName", 8usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).SetDisplayName)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "Path", 9usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).Path)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ID(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "ID", 10usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).ID)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DomainName(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "DomainName", 11usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).DomainName)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreationTime(this: *mut core::ffi::c_void, pdate: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "CreationTime", 12usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).CreationTime)(this, pdate), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModificationTime(this: *mut core::ffi::c_void, pdate: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "ModificationTime", 13usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).ModificationTime)(this, pdate), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserDSVersionNumber(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "UserDSVersionNumber", 14usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).UserDSVersionNumber)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ComputerDSVersionNumber(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "ComputerDSVersionNumber", 15usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).ComputerDSVersionNumber)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserSysvolVersionNumber(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "UserSysvolVersionNumber", 16usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).UserSysvolVersionNumber)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ComputerSysvolVersionNumber(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "ComputerSysvolVersionNumber", 17usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).ComputerSysvolVersionNumber)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetWMIFilter(this: *mut core::ffi::c_void, ppigpmwmifilter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "GetWMIFilter", 18usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).GetWMIFilter)(this, ppigpmwmifilter), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetWMIFilter(this: *mut core::ffi::c_void, pigpmwmifilter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "SetWMIFilter", 19usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).SetWMIFilter)(this, pigpmwmifilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUserEnabled(this: *mut core::ffi::c_void, vbenabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "SetUserEnabled", 20usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).SetUserEnabled)(this, vbenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetComputerEnabled(this: *mut core::ffi::c_void, vbenabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "SetComputerEnabled", 21usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).SetComputerEnabled)(this, vbenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUserEnabled(this: *mut core::ffi::c_void, pvbenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "IsUserEnabled", 22usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).IsUserEnabled)(this, pvbenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsComputerEnabled(this: *mut core::ffi::c_void, pvbenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "IsComputerEnabled", 23usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).IsComputerEnabled)(this, pvbenabled), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSecurityInfo(this: *mut core::ffi::c_void, ppsecurityinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "GetSecurityInfo", 24usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).GetSecurityInfo)(this, ppsecurityinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSecurityInfo(this: *mut core::ffi::c_void, psecurityinfo: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "SetSecurityInfo", 25usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).SetSecurityInfo)(this, psecurityinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "Delete", 26usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Backup(this: *mut core::ffi::c_void, bstrbackupdir: core::mem::MaybeUninit<windows_core::BSTR>, bstrcomment: core::mem::MaybeUninit<windows_core::BSTR>, pvargpmprogress: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvargpmcancel: *mut core::mem::MaybeUninit<windows_core::VARIANT>, ppigpmresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "Backup", 27usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).Backup)(this, bstrbackupdir, bstrcomment, pvargpmprogress, pvargpmcancel, ppigpmresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Import(this: *mut core::ffi::c_void, lflags: i32, pigpmbackup: *mut core::ffi::c_void, pvarmigrationtable: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvargpmprogress: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvargpmcancel: *mut core::mem::MaybeUninit<windows_core::VARIANT>, ppigpmresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "Import", 28usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).Import)(this, lflags, pigpmbackup, pvarmigrationtable, pvargpmprogress, pvargpmcancel, ppigpmresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GenerateReport(this: *mut core::ffi::c_void, gpmreporttype: GPMReportType, pvargpmprogress: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvargpmcancel: *mut core::mem::MaybeUninit<windows_core::VARIANT>, ppigpmresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "GenerateReport", 29usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).GenerateReport)(this, gpmreporttype, pvargpmprogress, pvargpmcancel, ppigpmresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GenerateReportToFile(this: *mut core::ffi::c_void, gpmreporttype: GPMReportType, bstrtargetfilepath: core::mem::MaybeUninit<windows_core::BSTR>, ppigpmresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "GenerateReportToFile", 30usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).GenerateReportToFile)(this, gpmreporttype, bstrtargetfilepath, ppigpmresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CopyTo(this: *mut core::ffi::c_void, lflags: i32, pigpmdomain: *mut core::ffi::c_void, pvarnewdisplayname: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvarmigrationtable: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvargpmprogress: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvargpmcancel: *mut core::mem::MaybeUninit<windows_core::VARIANT>, ppigpmresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "CopyTo", 31usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).CopyTo)(this, lflags, pigpmdomain, pvarnewdisplayname, pvarmigrationtable, pvargpmprogress, pvargpmcancel, ppigpmresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSecurityDescriptor(this: *mut core::ffi::c_void, lflags: i32, psd: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "SetSecurityDescriptor", 32usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).SetSecurityDescriptor)(this, lflags, psd), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSecurityDescriptor(this: *mut core::ffi::c_void, lflags: i32, ppsd: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "GetSecurityDescriptor", 33usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).GetSecurityDescriptor)(this, lflags, ppsd), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsACLConsistent(this: *mut core::ffi::c_void, pvbconsistent: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "IsACLConsistent", 34usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).IsACLConsistent)(this, pvbconsistent), |result| Some(*result))
+        }
+        unsafe extern "system" fn MakeACLConsistent(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO", "MakeACLConsistent", 35usize, |this| ((**(this as *const *const IGPMGPO_Vtbl)).MakeACLConsistent)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DisplayName,
+            SetDisplayName,
+            Path,
+            ID,
+            DomainName,
+            CreationTime,
+            ModificationTime,
+            UserDSVersionNumber,
+            ComputerDSVersionNumber,
+            UserSysvolVersionNumber,
+            ComputerSysvolVersionNumber,
+            #[cfg(feature = "Win32_System_Com")]
+            GetWMIFilter,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetWMIFilter: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetWMIFilter,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetWMIFilter: 0,
+            SetUserEnabled,
+            SetComputerEnabled,
+            IsUserEnabled,
+            IsComputerEnabled,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSecurityInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSecurityInfo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSecurityInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSecurityInfo: 0,
+            Delete,
+            #[cfg(feature = "Win32_System_Com")]
+            Backup,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Backup: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Import,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Import: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GenerateReport,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GenerateReport: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GenerateReportToFile,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GenerateReportToFile: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CopyTo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CopyTo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSecurityDescriptor,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSecurityDescriptor: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSecurityDescriptor,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSecurityDescriptor: 0,
+            IsACLConsistent,
+            MakeACLConsistent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMGPO as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPMGPO2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO2", "Description", 36usize, |this| ((**(this as *const *const IGPMGPO2_Vtbl)).Description)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, newval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO2", "SetDescription", 37usize, |this| ((**(this as *const *const IGPMGPO2_Vtbl)).SetDescription)(this, newval), |result| Some(*result))
+        }
+        Self { base__: <IGPMGPO_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Description, SetDescription }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMGPO2 as windows_core::Interface>::IID || <IGPMGPO_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPMGPO3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InfrastructureDC(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO3", "InfrastructureDC", 38usize, |this| ((**(this as *const *const IGPMGPO3_Vtbl)).InfrastructureDC)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInfrastructureDC(this: *mut core::ffi::c_void, newval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO3", "SetInfrastructureDC", 39usize, |this| ((**(this as *const *const IGPMGPO3_Vtbl)).SetInfrastructureDC)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInfrastructureFlags(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPO3", "SetInfrastructureFlags", 40usize, |this| ((**(this as *const *const IGPMGPO3_Vtbl)).SetInfrastructureFlags)(this, dwflags), |result| Some(*result))
+        }
+        Self { base__: <IGPMGPO2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InfrastructureDC, SetInfrastructureDC, SetInfrastructureFlags }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMGPO3 as windows_core::Interface>::IID || <IGPMGPO2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IGPMGPOCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPOCollection", "Count", 7usize, |this| ((**(this as *const *const IGPMGPOCollection_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, lindex: i32, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPOCollection", "get_Item", 8usize, |this| ((**(this as *const *const IGPMGPOCollection_Vtbl)).get_Item)(this, lindex, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppigpmgpos: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPOCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const IGPMGPOCollection_Vtbl))._NewEnum)(this, ppigpmgpos), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            get_Item,
+            #[cfg(feature = "Win32_System_Ole")]
+            _NewEnum,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            _NewEnum: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMGPOCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPMGPOLink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GPOID(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPOLink", "GPOID", 7usize, |this| ((**(this as *const *const IGPMGPOLink_Vtbl)).GPOID)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GPODomain(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPOLink", "GPODomain", 8usize, |this| ((**(this as *const *const IGPMGPOLink_Vtbl)).GPODomain)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPOLink", "Enabled", 9usize, |this| ((**(this as *const *const IGPMGPOLink_Vtbl)).Enabled)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, newval: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPOLink", "SetEnabled", 10usize, |this| ((**(this as *const *const IGPMGPOLink_Vtbl)).SetEnabled)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enforced(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPOLink", "Enforced", 11usize, |this| ((**(this as *const *const IGPMGPOLink_Vtbl)).Enforced)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnforced(this: *mut core::ffi::c_void, newval: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPOLink", "SetEnforced", 12usize, |this| ((**(this as *const *const IGPMGPOLink_Vtbl)).SetEnforced)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SOMLinkOrder(this: *mut core::ffi::c_void, lval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPOLink", "SOMLinkOrder", 13usize, |this| ((**(this as *const *const IGPMGPOLink_Vtbl)).SOMLinkOrder)(this, lval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SOM(this: *mut core::ffi::c_void, ppigpmsom: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPOLink", "SOM", 14usize, |this| ((**(this as *const *const IGPMGPOLink_Vtbl)).SOM)(this, ppigpmsom), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPOLink", "Delete", 15usize, |this| ((**(this as *const *const IGPMGPOLink_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GPOID,
+            GPODomain,
+            Enabled,
+            SetEnabled,
+            Enforced,
+            SetEnforced,
+            SOMLinkOrder,
+            #[cfg(feature = "Win32_System_Com")]
+            SOM,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SOM: 0,
+            Delete,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMGPOLink as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IGPMGPOLinksCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPOLinksCollection", "Count", 7usize, |this| ((**(this as *const *const IGPMGPOLinksCollection_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, lindex: i32, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPOLinksCollection", "get_Item", 8usize, |this| ((**(this as *const *const IGPMGPOLinksCollection_Vtbl)).get_Item)(this, lindex, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppigpmlinks: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMGPOLinksCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const IGPMGPOLinksCollection_Vtbl))._NewEnum)(this, ppigpmlinks), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            get_Item,
+            #[cfg(feature = "Win32_System_Ole")]
+            _NewEnum,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            _NewEnum: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMGPOLinksCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPMMapEntry_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Source(this: *mut core::ffi::c_void, pbstrsource: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMMapEntry", "Source", 7usize, |this| ((**(this as *const *const IGPMMapEntry_Vtbl)).Source)(this, pbstrsource), |result| Some(*result))
+        }
+        unsafe extern "system" fn Destination(this: *mut core::ffi::c_void, pbstrdestination: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMMapEntry", "Destination", 8usize, |this| ((**(this as *const *const IGPMMapEntry_Vtbl)).Destination)(this, pbstrdestination), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestinationOption(this: *mut core::ffi::c_void, pgpmdestoption: *mut GPMDestinationOption) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMMapEntry", "DestinationOption", 9usize, |this| ((**(this as *const *const IGPMMapEntry_Vtbl)).DestinationOption)(this, pgpmdestoption), |result| Some(*result))
+        }
+        unsafe extern "system" fn EntryType(this: *mut core::ffi::c_void, pgpmentrytype: *mut GPMEntryType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMMapEntry", "EntryType", 10usize, |this| ((**(this as *const *const IGPMMapEntry_Vtbl)).EntryType)(this, pgpmentrytype), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Source, Destination, DestinationOption, EntryType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMMapEntry as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IGPMMapEntryCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMMapEntryCollection", "Count", 7usize, |this| ((**(this as *const *const IGPMMapEntryCollection_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, lindex: i32, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMMapEntryCollection", "get_Item", 8usize, |this| ((**(this as *const *const IGPMMapEntryCollection_Vtbl)).get_Item)(this, lindex, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMMapEntryCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const IGPMMapEntryCollection_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            get_Item,
+            #[cfg(feature = "Win32_System_Ole")]
+            _NewEnum,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            _NewEnum: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMMapEntryCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPMMigrationTable_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void, bstrmigrationtablepath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMMigrationTable", "Save", 7usize, |this| ((**(this as *const *const IGPMMigrationTable_Vtbl)).Save)(this, bstrmigrationtablepath), |result| Some(*result))
+        }
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, lflags: i32, var: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMMigrationTable", "Add", 8usize, |this| ((**(this as *const *const IGPMMigrationTable_Vtbl)).Add)(this, lflags, var), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddEntry(this: *mut core::ffi::c_void, bstrsource: core::mem::MaybeUninit<windows_core::BSTR>, gpmentrytype: GPMEntryType, pvardestination: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppentry: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMMigrationTable", "AddEntry", 9usize, |this| ((**(this as *const *const IGPMMigrationTable_Vtbl)).AddEntry)(this, bstrsource, gpmentrytype, pvardestination, ppentry), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetEntry(this: *mut core::ffi::c_void, bstrsource: core::mem::MaybeUninit<windows_core::BSTR>, ppentry: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMMigrationTable", "GetEntry", 10usize, |this| ((**(this as *const *const IGPMMigrationTable_Vtbl)).GetEntry)(this, bstrsource, ppentry), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteEntry(this: *mut core::ffi::c_void, bstrsource: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMMigrationTable", "DeleteEntry", 11usize, |this| ((**(this as *const *const IGPMMigrationTable_Vtbl)).DeleteEntry)(this, bstrsource), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn UpdateDestination(this: *mut core::ffi::c_void, bstrsource: core::mem::MaybeUninit<windows_core::BSTR>, pvardestination: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppentry: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMMigrationTable", "UpdateDestination", 12usize, |this| ((**(this as *const *const IGPMMigrationTable_Vtbl)).UpdateDestination)(this, bstrsource, pvardestination, ppentry), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Validate(this: *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMMigrationTable", "Validate", 13usize, |this| ((**(this as *const *const IGPMMigrationTable_Vtbl)).Validate)(this, ppresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetEntries(this: *mut core::ffi::c_void, ppentries: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMMigrationTable", "GetEntries", 14usize, |this| ((**(this as *const *const IGPMMigrationTable_Vtbl)).GetEntries)(this, ppentries), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Save,
+            Add,
+            #[cfg(feature = "Win32_System_Com")]
+            AddEntry,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddEntry: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetEntry,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetEntry: 0,
+            DeleteEntry,
+            #[cfg(feature = "Win32_System_Com")]
+            UpdateDestination,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            UpdateDestination: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Validate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Validate: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetEntries,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetEntries: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMMigrationTable as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPMPermission_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Inherited(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMPermission", "Inherited", 7usize, |this| ((**(this as *const *const IGPMPermission_Vtbl)).Inherited)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Inheritable(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMPermission", "Inheritable", 8usize, |this| ((**(this as *const *const IGPMPermission_Vtbl)).Inheritable)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Denied(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMPermission", "Denied", 9usize, |this| ((**(this as *const *const IGPMPermission_Vtbl)).Denied)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Permission(this: *mut core::ffi::c_void, pval: *mut GPMPermissionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMPermission", "Permission", 10usize, |this| ((**(this as *const *const IGPMPermission_Vtbl)).Permission)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Trustee(this: *mut core::ffi::c_void, ppigpmtrustee: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMPermission", "Trustee", 11usize, |this| ((**(this as *const *const IGPMPermission_Vtbl)).Trustee)(this, ppigpmtrustee), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Inherited,
+            Inheritable,
+            Denied,
+            Permission,
+            #[cfg(feature = "Win32_System_Com")]
+            Trustee,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Trustee: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMPermission as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPMRSOP_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, pval: *mut GPMRSOPMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "Mode", 7usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).Mode)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Namespace(this: *mut core::ffi::c_void, bstrval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "Namespace", 8usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).Namespace)(this, bstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLoggingComputer(this: *mut core::ffi::c_void, bstrval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "SetLoggingComputer", 9usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).SetLoggingComputer)(this, bstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoggingComputer(this: *mut core::ffi::c_void, bstrval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "LoggingComputer", 10usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).LoggingComputer)(this, bstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLoggingUser(this: *mut core::ffi::c_void, bstrval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "SetLoggingUser", 11usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).SetLoggingUser)(this, bstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoggingUser(this: *mut core::ffi::c_void, bstrval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "LoggingUser", 12usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).LoggingUser)(this, bstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLoggingFlags(this: *mut core::ffi::c_void, lval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "SetLoggingFlags", 13usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).SetLoggingFlags)(this, lval), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoggingFlags(this: *mut core::ffi::c_void, lval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "LoggingFlags", 14usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).LoggingFlags)(this, lval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPlanningFlags(this: *mut core::ffi::c_void, lval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "SetPlanningFlags", 15usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).SetPlanningFlags)(this, lval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlanningFlags(this: *mut core::ffi::c_void, lval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "PlanningFlags", 16usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).PlanningFlags)(this, lval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPlanningDomainController(this: *mut core::ffi::c_void, bstrval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "SetPlanningDomainController", 17usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).SetPlanningDomainController)(this, bstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlanningDomainController(this: *mut core::ffi::c_void, bstrval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "PlanningDomainController", 18usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).PlanningDomainController)(this, bstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPlanningSiteName(this: *mut core::ffi::c_void, bstrval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "SetPlanningSiteName", 19usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).SetPlanningSiteName)(this, bstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlanningSiteName(this: *mut core::ffi::c_void, bstrval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "PlanningSiteName", 20usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).PlanningSiteName)(this, bstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPlanningUser(this: *mut core::ffi::c_void, bstrval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "SetPlanningUser", 21usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).SetPlanningUser)(this, bstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlanningUser(this: *mut core::ffi::c_void, bstrval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "PlanningUser", 22usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).PlanningUser)(this, bstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPlanningUserSOM(this: *mut core::ffi::c_void, bstrval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "SetPlanningUserSOM", 23usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).SetPlanningUserSOM)(this, bstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlanningUserSOM(this: *mut core::ffi::c_void, bstrval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "PlanningUserSOM", 24usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).PlanningUserSOM)(this, bstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPlanningUserWMIFilters(this: *mut core::ffi::c_void, varval: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "SetPlanningUserWMIFilters", 25usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).SetPlanningUserWMIFilters)(this, varval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlanningUserWMIFilters(this: *mut core::ffi::c_void, varval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "PlanningUserWMIFilters", 26usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).PlanningUserWMIFilters)(this, varval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPlanningUserSecurityGroups(this: *mut core::ffi::c_void, varval: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "SetPlanningUserSecurityGroups", 27usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).SetPlanningUserSecurityGroups)(this, varval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlanningUserSecurityGroups(this: *mut core::ffi::c_void, varval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "PlanningUserSecurityGroups", 28usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).PlanningUserSecurityGroups)(this, varval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPlanningComputer(this: *mut core::ffi::c_void, bstrval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "SetPlanningComputer", 29usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).SetPlanningComputer)(this, bstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlanningComputer(this: *mut core::ffi::c_void, bstrval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "PlanningComputer", 30usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).PlanningComputer)(this, bstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPlanningComputerSOM(this: *mut core::ffi::c_void, bstrval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "SetPlanningComputerSOM", 31usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).SetPlanningComputerSOM)(this, bstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlanningComputerSOM(this: *mut core::ffi::c_void, bstrval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "PlanningComputerSOM", 32usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).PlanningComputerSOM)(this, bstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPlanningComputerWMIFilters(this: *mut core::ffi::c_void, varval: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "SetPlanningComputerWMIFilters", 33usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).SetPlanningComputerWMIFilters)(this, varval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlanningComputerWMIFilters(this: *mut core::ffi::c_void, varval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "PlanningComputerWMIFilters", 34usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).PlanningComputerWMIFilters)(this, varval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPlanningComputerSecurityGroups(this: *mut core::ffi::c_void, varval: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "SetPlanningComputerSecurityGroups", 35usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).SetPlanningComputerSecurityGroups)(this, varval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlanningComputerSecurityGroups(this: *mut core::ffi::c_void, varval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "PlanningComputerSecurityGroups", 36usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).PlanningComputerSecurityGroups)(this, varval), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoggingEnumerateUsers(this: *mut core::ffi::c_void, varval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "LoggingEnumerateUsers", 37usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).LoggingEnumerateUsers)(this, varval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateQueryResults(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "CreateQueryResults", 38usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).CreateQueryResults)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseQueryResults(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "ReleaseQueryResults", 39usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).ReleaseQueryResults)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GenerateReport(this: *mut core::ffi::c_void, gpmreporttype: GPMReportType, pvargpmprogress: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvargpmcancel: *mut core::mem::MaybeUninit<windows_core::VARIANT>, ppigpmresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "GenerateReport", 40usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).GenerateReport)(this, gpmreporttype, pvargpmprogress, pvargpmcancel, ppigpmresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GenerateReportToFile(this: *mut core::ffi::c_void, gpmreporttype: GPMReportType, bstrtargetfilepath: core::mem::MaybeUninit<windows_core::BSTR>, ppigpmresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMRSOP", "GenerateReportToFile", 41usize, |this| ((**(this as *const *const IGPMRSOP_Vtbl)).GenerateReportToFile)(this, gpmreporttype, bstrtargetfilepath, ppigpmresult), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Mode,
+            Namespace,
+            SetLoggingComputer,
+            LoggingComputer,
+            SetLoggingUser,
+            LoggingUser,
+            SetLoggingFlags,
+            LoggingFlags,
+            SetPlanningFlags,
+            PlanningFlags,
+            SetPlanningDomainController,
+            PlanningDomainController,
+            SetPlanningSiteName,
+            PlanningSiteName,
+            SetPlanningUser,
+            PlanningUser,
+            SetPlanningUserSOM,
+            PlanningUserSOM,
+            SetPlanningUserWMIFilters,
+            PlanningUserWMIFilters,
+            SetPlanningUserSecurityGroups,
+            PlanningUserSecurityGroups,
+            SetPlanningComputer,
+            PlanningComputer,
+            SetPlanningComputerSOM,
+            PlanningComputerSOM,
+            SetPlanningComputerWMIFilters,
+            PlanningComputerWMIFilters,
+            SetPlanningComputerSecurityGroups,
+            PlanningComputerSecurityGroups,
+            LoggingEnumerateUsers,
+            CreateQueryResults,
+            ReleaseQueryResults,
+            #[cfg(feature = "Win32_System_Com")]
+            GenerateReport,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GenerateReport: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GenerateReportToFile,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GenerateReportToFile: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMRSOP as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPMResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, ppigpmstatusmsgcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMResult", "Status", 7usize, |this| ((**(this as *const *const IGPMResult_Vtbl)).Status)(this, ppigpmstatusmsgcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn Result(this: *mut core::ffi::c_void, pvarresult: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMResult", "Result", 8usize, |this| ((**(this as *const *const IGPMResult_Vtbl)).Result)(this, pvarresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn OverallStatus(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMResult", "OverallStatus", 9usize, |this| ((**(this as *const *const IGPMResult_Vtbl)).OverallStatus)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Status,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Status: 0,
+            Result,
+            OverallStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMResult as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPMSOM_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GPOInheritanceBlocked(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMSOM", "GPOInheritanceBlocked", 7usize, |this| ((**(this as *const *const IGPMSOM_Vtbl)).GPOInheritanceBlocked)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGPOInheritanceBlocked(this: *mut core::ffi::c_void, newval: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMSOM", "SetGPOInheritanceBlocked", 8usize, |this| ((**(this as *const *const IGPMSOM_Vtbl)).SetGPOInheritanceBlocked)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMSOM", "Name", 9usize, |this| ((**(this as *const *const IGPMSOM_Vtbl)).Name)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMSOM", "Path", 10usize, |this| ((**(this as *const *const IGPMSOM_Vtbl)).Path)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateGPOLink(this: *mut core::ffi::c_void, llinkpos: i32, pgpo: *mut core::ffi::c_void, ppnewgpolink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMSOM", "CreateGPOLink", 11usize, |this| ((**(this as *const *const IGPMSOM_Vtbl)).CreateGPOLink)(this, llinkpos, pgpo, ppnewgpolink), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, pval: *mut GPMSOMType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMSOM", "Type", 12usize, |this| ((**(this as *const *const IGPMSOM_Vtbl)).Type)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetGPOLinks(this: *mut core::ffi::c_void, ppgpolinks: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMSOM", "GetGPOLinks", 13usize, |this| ((**(this as *const *const IGPMSOM_Vtbl)).GetGPOLinks)(this, ppgpolinks), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetInheritedGPOLinks(this: *mut core::ffi::c_void, ppgpolinks: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMSOM", "GetInheritedGPOLinks", 14usize, |this| ((**(this as *const *const IGPMSOM_Vtbl)).GetInheritedGPOLinks)(this, ppgpolinks), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSecurityInfo(this: *mut core::ffi::c_void, ppsecurityinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMSOM", "GetSecurityInfo", 15usize, |this| ((**(this as *const *const IGPMSOM_Vtbl)).GetSecurityInfo)(this, ppsecurityinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSecurityInfo(this: *mut core::ffi::c_void, psecurityinfo: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMSOM", "SetSecurityInfo", 16usize, |this| ((**(this as *const *const IGPMSOM_Vtbl)).SetSecurityInfo)(this, psecurityinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GPOInheritanceBlocked,
+            SetGPOInheritanceBlocked,
+            Name,
+            Path,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateGPOLink,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateGPOLink: 0,
+            Type,
+            #[cfg(feature = "Win32_System_Com")]
+            GetGPOLinks,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetGPOLinks: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetInheritedGPOLinks,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetInheritedGPOLinks: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSecurityInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSecurityInfo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSecurityInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSecurityInfo: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMSOM as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IGPMSOMCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMSOMCollection", "Count", 7usize, |this| ((**(this as *const *const IGPMSOMCollection_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, lindex: i32, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMSOMCollection", "get_Item", 8usize, |this| ((**(this as *const *const IGPMSOMCollection_Vtbl)).get_Item)(this, lindex, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppigpmsom: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMSOMCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const IGPMSOMCollection_Vtbl))._NewEnum)(this, ppigpmsom), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            get_Item,
+            #[cfg(feature = "Win32_System_Ole")]
+            _NewEnum,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            _NewEnum: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMSOMCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPMSearchCriteria_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, searchproperty: GPMSearchProperty, searchoperation: GPMSearchOperation, varvalue: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMSearchCriteria", "Add", 7usize, |this| ((**(this as *const *const IGPMSearchCriteria_Vtbl)).Add)(this, searchproperty, searchoperation, varvalue), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Add }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMSearchCriteria as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IGPMSecurityInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMSecurityInfo", "Count", 7usize, |this| ((**(this as *const *const IGPMSecurityInfo_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, lindex: i32, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMSecurityInfo", "get_Item", 8usize, |this| ((**(this as *const *const IGPMSecurityInfo_Vtbl)).get_Item)(this, lindex, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMSecurityInfo", "_NewEnum", 9usize, |this| ((**(this as *const *const IGPMSecurityInfo_Vtbl))._NewEnum)(this, ppenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pperm: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMSecurityInfo", "Add", 10usize, |this| ((**(this as *const *const IGPMSecurityInfo_Vtbl)).Add)(this, pperm), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, pperm: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMSecurityInfo", "Remove", 11usize, |this| ((**(this as *const *const IGPMSecurityInfo_Vtbl)).Remove)(this, pperm), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTrustee(this: *mut core::ffi::c_void, bstrtrustee: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMSecurityInfo", "RemoveTrustee", 12usize, |this| ((**(this as *const *const IGPMSecurityInfo_Vtbl)).RemoveTrustee)(this, bstrtrustee), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            get_Item,
+            #[cfg(feature = "Win32_System_Ole")]
+            _NewEnum,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            _NewEnum: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Remove,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Remove: 0,
+            RemoveTrustee,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMSecurityInfo as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPMSitesContainer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DomainController(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMSitesContainer", "DomainController", 7usize, |this| ((**(this as *const *const IGPMSitesContainer_Vtbl)).DomainController)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Domain(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMSitesContainer", "Domain", 8usize, |this| ((**(this as *const *const IGPMSitesContainer_Vtbl)).Domain)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Forest(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMSitesContainer", "Forest", 9usize, |this| ((**(this as *const *const IGPMSitesContainer_Vtbl)).Forest)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSite(this: *mut core::ffi::c_void, bstrsitename: core::mem::MaybeUninit<windows_core::BSTR>, ppsom: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMSitesContainer", "GetSite", 10usize, |this| ((**(this as *const *const IGPMSitesContainer_Vtbl)).GetSite)(this, bstrsitename, ppsom), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SearchSites(this: *mut core::ffi::c_void, pigpmsearchcriteria: *mut core::ffi::c_void, ppigpmsomcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMSitesContainer", "SearchSites", 11usize, |this| ((**(this as *const *const IGPMSitesContainer_Vtbl)).SearchSites)(this, pigpmsearchcriteria, ppigpmsomcollection), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DomainController,
+            Domain,
+            Forest,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSite,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSite: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SearchSites,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SearchSites: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMSitesContainer as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPMStarterGPO_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPO", "DisplayName", 7usize, |this| ((**(this as *const *const IGPMStarterGPO_Vtbl)).DisplayName)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, newval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPO", "SetDisplayName", 8usize, |this| ((**(this as *const *const IGPMStarterGPO_Vtbl)).SetDisplayName)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPO", "Description", 9usize, |this| ((**(this as *const *const IGPMStarterGPO_Vtbl)).Description)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, newval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPO", "SetDescription", 10usize, |this| ((**(this as *const *const IGPMStarterGPO_Vtbl)).SetDescription)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Author(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPO", "Author", 11usize, |this| ((**(this as *const *const IGPMStarterGPO_Vtbl)).Author)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Product(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPO", "Product", 12usize, |this| ((**(this as *const *const IGPMStarterGPO_Vtbl)).Product)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreationTime(this: *mut core::ffi::c_void, pval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPO", "CreationTime", 13usize, |this| ((**(this as *const *const IGPMStarterGPO_Vtbl)).CreationTime)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ID(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPO", "ID", 14usize, |this| ((**(this as *const *const IGPMStarterGPO_Vtbl)).ID)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModifiedTime(this: *mut core::ffi::c_void, pval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPO", "ModifiedTime", 15usize, |this| ((**(this as *const *const IGPMStarterGPO_Vtbl)).ModifiedTime)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, pval: *mut GPMStarterGPOType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPO", "Type", 16usize, |this| ((**(this as *const *const IGPMStarterGPO_Vtbl)).Type)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ComputerVersion(this: *mut core::ffi::c_void, pval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPO", "ComputerVersion", 17usize, |this| ((**(this as *const *const IGPMStarterGPO_Vtbl)).ComputerVersion)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserVersion(this: *mut core::ffi::c_void, pval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPO", "UserVersion", 18usize, |this| ((**(this as *const *const IGPMStarterGPO_Vtbl)).UserVersion)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn StarterGPOVersion(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPO", "StarterGPOVersion", 19usize, |this| ((**(this as *const *const IGPMStarterGPO_Vtbl)).StarterGPOVersion)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPO", "Delete", 20usize, |this| ((**(this as *const *const IGPMStarterGPO_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void, bstrsavefile: core::mem::MaybeUninit<windows_core::BSTR>, boverwrite: super::super::Foundation::VARIANT_BOOL, bsaveassystem: super::super::Foundation::VARIANT_BOOL, bstrlanguage: *const core::mem::MaybeUninit<windows_core::VARIANT>, bstrauthor: *const core::mem::MaybeUninit<windows_core::VARIANT>, bstrproduct: *const core::mem::MaybeUninit<windows_core::VARIANT>, bstruniqueid: *const core::mem::MaybeUninit<windows_core::VARIANT>, bstrversion: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvargpmprogress: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvargpmcancel: *mut core::mem::MaybeUninit<windows_core::VARIANT>, ppigpmresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPO", "Save", 21usize, |this| ((**(this as *const *const IGPMStarterGPO_Vtbl)).Save)(this, bstrsavefile, boverwrite, bsaveassystem, bstrlanguage, bstrauthor, bstrproduct, bstruniqueid, bstrversion, pvargpmprogress, pvargpmcancel, ppigpmresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Backup(this: *mut core::ffi::c_void, bstrbackupdir: core::mem::MaybeUninit<windows_core::BSTR>, bstrcomment: core::mem::MaybeUninit<windows_core::BSTR>, pvargpmprogress: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvargpmcancel: *mut core::mem::MaybeUninit<windows_core::VARIANT>, ppigpmresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPO", "Backup", 22usize, |this| ((**(this as *const *const IGPMStarterGPO_Vtbl)).Backup)(this, bstrbackupdir, bstrcomment, pvargpmprogress, pvargpmcancel, ppigpmresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CopyTo(this: *mut core::ffi::c_void, pvarnewdisplayname: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvargpmprogress: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvargpmcancel: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppigpmresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPO", "CopyTo", 23usize, |this| ((**(this as *const *const IGPMStarterGPO_Vtbl)).CopyTo)(this, pvarnewdisplayname, pvargpmprogress, pvargpmcancel, ppigpmresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GenerateReport(this: *mut core::ffi::c_void, gpmreporttype: GPMReportType, pvargpmprogress: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvargpmcancel: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppigpmresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPO", "GenerateReport", 24usize, |this| ((**(this as *const *const IGPMStarterGPO_Vtbl)).GenerateReport)(this, gpmreporttype, pvargpmprogress, pvargpmcancel, ppigpmresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GenerateReportToFile(this: *mut core::ffi::c_void, gpmreporttype: GPMReportType, bstrtargetfilepath: core::mem::MaybeUninit<windows_core::BSTR>, ppigpmresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPO", "GenerateReportToFile", 25usize, |this| ((**(this as *const *const IGPMStarterGPO_Vtbl)).GenerateReportToFile)(this, gpmreporttype, bstrtargetfilepath, ppigpmresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSecurityInfo(this: *mut core::ffi::c_void, ppsecurityinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPO", "GetSecurityInfo", 26usize, |this| ((**(this as *const *const IGPMStarterGPO_Vtbl)).GetSecurityInfo)(this, ppsecurityinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSecurityInfo(this: *mut core::ffi::c_void, psecurityinfo: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPO", "SetSecurityInfo", 27usize, |this| ((**(this as *const *const IGPMStarterGPO_Vtbl)).SetSecurityInfo)(this, psecurityinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DisplayName,
+            SetDisplayName,
+            Description,
+            SetDescription,
+            Author,
+            Product,
+            CreationTime,
+            ID,
+            ModifiedTime,
+            Type,
+            ComputerVersion,
+            UserVersion,
+            StarterGPOVersion,
+            Delete,
+            #[cfg(feature = "Win32_System_Com")]
+            Save,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Save: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Backup,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Backup: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CopyTo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CopyTo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GenerateReport,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GenerateReport: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GenerateReportToFile,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GenerateReportToFile: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSecurityInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSecurityInfo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSecurityInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSecurityInfo: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMStarterGPO as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPMStarterGPOBackup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BackupDir(this: *mut core::ffi::c_void, pbstrbackupdir: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPOBackup", "BackupDir", 7usize, |this| ((**(this as *const *const IGPMStarterGPOBackup_Vtbl)).BackupDir)(this, pbstrbackupdir), |result| Some(*result))
+        }
+        unsafe extern "system" fn Comment(this: *mut core::ffi::c_void, pbstrcomment: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPOBackup", "Comment", 8usize, |this| ((**(this as *const *const IGPMStarterGPOBackup_Vtbl)).Comment)(this, pbstrcomment), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, pbstrdisplayname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPOBackup", "DisplayName", 9usize, |this| ((**(this as *const *const IGPMStarterGPOBackup_Vtbl)).DisplayName)(this, pbstrdisplayname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Domain(this: *mut core::ffi::c_void, pbstrtemplatedomain: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPOBackup", "Domain", 10usize, |this| ((**(this as *const *const IGPMStarterGPOBackup_Vtbl)).Domain)(this, pbstrtemplatedomain), |result| Some(*result))
+        }
+        unsafe extern "system" fn StarterGPOID(this: *mut core::ffi::c_void, pbstrtemplateid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPOBackup", "StarterGPOID", 11usize, |this| ((**(this as *const *const IGPMStarterGPOBackup_Vtbl)).StarterGPOID)(this, pbstrtemplateid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ID(this: *mut core::ffi::c_void, pbstrid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPOBackup", "ID", 12usize, |this| ((**(this as *const *const IGPMStarterGPOBackup_Vtbl)).ID)(this, pbstrid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Timestamp(this: *mut core::ffi::c_void, ptimestamp: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPOBackup", "Timestamp", 13usize, |this| ((**(this as *const *const IGPMStarterGPOBackup_Vtbl)).Timestamp)(this, ptimestamp), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, ptype: *mut GPMStarterGPOType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPOBackup", "Type", 14usize, |this| ((**(this as *const *const IGPMStarterGPOBackup_Vtbl)).Type)(this, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPOBackup", "Delete", 15usize, |this| ((**(this as *const *const IGPMStarterGPOBackup_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GenerateReport(this: *mut core::ffi::c_void, gpmreporttype: GPMReportType, pvargpmprogress: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvargpmcancel: *mut core::mem::MaybeUninit<windows_core::VARIANT>, ppigpmresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPOBackup", "GenerateReport", 16usize, |this| ((**(this as *const *const IGPMStarterGPOBackup_Vtbl)).GenerateReport)(this, gpmreporttype, pvargpmprogress, pvargpmcancel, ppigpmresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GenerateReportToFile(this: *mut core::ffi::c_void, gpmreporttype: GPMReportType, bstrtargetfilepath: core::mem::MaybeUninit<windows_core::BSTR>, ppigpmresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPOBackup", "GenerateReportToFile", 17usize, |this| ((**(this as *const *const IGPMStarterGPOBackup_Vtbl)).GenerateReportToFile)(this, gpmreporttype, bstrtargetfilepath, ppigpmresult), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BackupDir,
+            Comment,
+            DisplayName,
+            Domain,
+            StarterGPOID,
+            ID,
+            Timestamp,
+            Type,
+            Delete,
+            #[cfg(feature = "Win32_System_Com")]
+            GenerateReport,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GenerateReport: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GenerateReportToFile,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GenerateReportToFile: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMStarterGPOBackup as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IGPMStarterGPOBackupCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPOBackupCollection", "Count", 7usize, |this| ((**(this as *const *const IGPMStarterGPOBackupCollection_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, lindex: i32, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPOBackupCollection", "get_Item", 8usize, |this| ((**(this as *const *const IGPMStarterGPOBackupCollection_Vtbl)).get_Item)(this, lindex, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppigpmtmplbackup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPOBackupCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const IGPMStarterGPOBackupCollection_Vtbl))._NewEnum)(this, ppigpmtmplbackup), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            get_Item,
+            #[cfg(feature = "Win32_System_Ole")]
+            _NewEnum,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            _NewEnum: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMStarterGPOBackupCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IGPMStarterGPOCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPOCollection", "Count", 7usize, |this| ((**(this as *const *const IGPMStarterGPOCollection_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, lindex: i32, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPOCollection", "get_Item", 8usize, |this| ((**(this as *const *const IGPMStarterGPOCollection_Vtbl)).get_Item)(this, lindex, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppigpmtemplates: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStarterGPOCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const IGPMStarterGPOCollection_Vtbl))._NewEnum)(this, ppigpmtemplates), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            get_Item,
+            #[cfg(feature = "Win32_System_Ole")]
+            _NewEnum,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            _NewEnum: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMStarterGPOCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPMStatusMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ObjectPath(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStatusMessage", "ObjectPath", 7usize, |this| ((**(this as *const *const IGPMStatusMessage_Vtbl)).ObjectPath)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ErrorCode(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStatusMessage", "ErrorCode", 8usize, |this| ((**(this as *const *const IGPMStatusMessage_Vtbl)).ErrorCode)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtensionName(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStatusMessage", "ExtensionName", 9usize, |this| ((**(this as *const *const IGPMStatusMessage_Vtbl)).ExtensionName)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SettingsName(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStatusMessage", "SettingsName", 10usize, |this| ((**(this as *const *const IGPMStatusMessage_Vtbl)).SettingsName)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn OperationCode(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStatusMessage", "OperationCode", 11usize, |this| ((**(this as *const *const IGPMStatusMessage_Vtbl)).OperationCode)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStatusMessage", "Message", 12usize, |this| ((**(this as *const *const IGPMStatusMessage_Vtbl)).Message)(this, pval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ObjectPath,
+            ErrorCode,
+            ExtensionName,
+            SettingsName,
+            OperationCode,
+            Message,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMStatusMessage as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IGPMStatusMsgCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStatusMsgCollection", "Count", 7usize, |this| ((**(this as *const *const IGPMStatusMsgCollection_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, lindex: i32, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStatusMsgCollection", "get_Item", 8usize, |this| ((**(this as *const *const IGPMStatusMsgCollection_Vtbl)).get_Item)(this, lindex, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMStatusMsgCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const IGPMStatusMsgCollection_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            get_Item,
+            #[cfg(feature = "Win32_System_Ole")]
+            _NewEnum,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            _NewEnum: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMStatusMsgCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPMTrustee_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TrusteeSid(this: *mut core::ffi::c_void, bstrval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMTrustee", "TrusteeSid", 7usize, |this| ((**(this as *const *const IGPMTrustee_Vtbl)).TrusteeSid)(this, bstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrusteeName(this: *mut core::ffi::c_void, bstrval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMTrustee", "TrusteeName", 8usize, |this| ((**(this as *const *const IGPMTrustee_Vtbl)).TrusteeName)(this, bstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrusteeDomain(this: *mut core::ffi::c_void, bstrval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMTrustee", "TrusteeDomain", 9usize, |this| ((**(this as *const *const IGPMTrustee_Vtbl)).TrusteeDomain)(this, bstrval), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrusteeDSPath(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMTrustee", "TrusteeDSPath", 10usize, |this| ((**(this as *const *const IGPMTrustee_Vtbl)).TrusteeDSPath)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrusteeType(this: *mut core::ffi::c_void, lval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMTrustee", "TrusteeType", 11usize, |this| ((**(this as *const *const IGPMTrustee_Vtbl)).TrusteeType)(this, lval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TrusteeSid,
+            TrusteeName,
+            TrusteeDomain,
+            TrusteeDSPath,
+            TrusteeType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMTrustee as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IGPMWMIFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMWMIFilter", "Path", 7usize, |this| ((**(this as *const *const IGPMWMIFilter_Vtbl)).Path)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, newval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMWMIFilter", "SetName", 8usize, |this| ((**(this as *const *const IGPMWMIFilter_Vtbl)).SetName)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMWMIFilter", "Name", 9usize, |this| ((**(this as *const *const IGPMWMIFilter_Vtbl)).Name)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, newval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMWMIFilter", "SetDescription", 10usize, |this| ((**(this as *const *const IGPMWMIFilter_Vtbl)).SetDescription)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMWMIFilter", "Description", 11usize, |this| ((**(this as *const *const IGPMWMIFilter_Vtbl)).Description)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetQueryList(this: *mut core::ffi::c_void, pqrylist: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMWMIFilter", "GetQueryList", 12usize, |this| ((**(this as *const *const IGPMWMIFilter_Vtbl)).GetQueryList)(this, pqrylist), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSecurityInfo(this: *mut core::ffi::c_void, ppsecurityinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMWMIFilter", "GetSecurityInfo", 13usize, |this| ((**(this as *const *const IGPMWMIFilter_Vtbl)).GetSecurityInfo)(this, ppsecurityinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSecurityInfo(this: *mut core::ffi::c_void, psecurityinfo: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMWMIFilter", "SetSecurityInfo", 14usize, |this| ((**(this as *const *const IGPMWMIFilter_Vtbl)).SetSecurityInfo)(this, psecurityinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Path,
+            SetName,
+            Name,
+            SetDescription,
+            Description,
+            GetQueryList,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSecurityInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSecurityInfo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSecurityInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSecurityInfo: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMWMIFilter as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IGPMWMIFilterCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMWMIFilterCollection", "Count", 7usize, |this| ((**(this as *const *const IGPMWMIFilterCollection_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, lindex: i32, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMWMIFilterCollection", "get_Item", 8usize, |this| ((**(this as *const *const IGPMWMIFilterCollection_Vtbl)).get_Item)(this, lindex, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGPMWMIFilterCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const IGPMWMIFilterCollection_Vtbl))._NewEnum)(this, pval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            get_Item,
+            #[cfg(feature = "Win32_System_Ole")]
+            _NewEnum,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            _NewEnum: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGPMWMIFilterCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Registry", feature = "Win32_UI_Controls"))]
+impl windows_core::imp::ProxyVtable for IGroupPolicyObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn New(this: *mut core::ffi::c_void, pszdomainname: windows_core::PCWSTR, pszdisplayname: windows_core::PCWSTR, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGroupPolicyObject", "New", 3usize, |this| ((**(this as *const *const IGroupPolicyObject_Vtbl)).New)(this, pszdomainname, pszdisplayname, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenDSGPO(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR, dwflags: GPO_OPEN_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGroupPolicyObject", "OpenDSGPO", 4usize, |this| ((**(this as *const *const IGroupPolicyObject_Vtbl)).OpenDSGPO)(this, pszpath, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenLocalMachineGPO(this: *mut core::ffi::c_void, dwflags: GPO_OPEN_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGroupPolicyObject", "OpenLocalMachineGPO", 5usize, |this| ((**(this as *const *const IGroupPolicyObject_Vtbl)).OpenLocalMachineGPO)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenRemoteMachineGPO(this: *mut core::ffi::c_void, pszcomputername: windows_core::PCWSTR, dwflags: GPO_OPEN_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGroupPolicyObject", "OpenRemoteMachineGPO", 6usize, |this| ((**(this as *const *const IGroupPolicyObject_Vtbl)).OpenRemoteMachineGPO)(this, pszcomputername, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void, bmachine: super::super::Foundation::BOOL, badd: super::super::Foundation::BOOL, pguidextension: *mut windows_core::GUID, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGroupPolicyObject", "Save", 7usize, |this| ((**(this as *const *const IGroupPolicyObject_Vtbl)).Save)(this, bmachine, badd, pguidextension, pguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGroupPolicyObject", "Delete", 8usize, |this| ((**(this as *const *const IGroupPolicyObject_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, pszname: windows_core::PWSTR, cchmaxlength: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGroupPolicyObject", "GetName", 9usize, |this| ((**(this as *const *const IGroupPolicyObject_Vtbl)).GetName)(this, pszname, cchmaxlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayName(this: *mut core::ffi::c_void, pszname: windows_core::PWSTR, cchmaxlength: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGroupPolicyObject", "GetDisplayName", 10usize, |this| ((**(this as *const *const IGroupPolicyObject_Vtbl)).GetDisplayName)(this, pszname, cchmaxlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGroupPolicyObject", "SetDisplayName", 11usize, |this| ((**(this as *const *const IGroupPolicyObject_Vtbl)).SetDisplayName)(this, pszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPath(this: *mut core::ffi::c_void, pszpath: windows_core::PWSTR, cchmaxlength: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGroupPolicyObject", "GetPath", 12usize, |this| ((**(this as *const *const IGroupPolicyObject_Vtbl)).GetPath)(this, pszpath, cchmaxlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDSPath(this: *mut core::ffi::c_void, dwsection: u32, pszpath: windows_core::PWSTR, cchmaxpath: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGroupPolicyObject", "GetDSPath", 13usize, |this| ((**(this as *const *const IGroupPolicyObject_Vtbl)).GetDSPath)(this, dwsection, pszpath, cchmaxpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileSysPath(this: *mut core::ffi::c_void, dwsection: u32, pszpath: windows_core::PWSTR, cchmaxpath: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGroupPolicyObject", "GetFileSysPath", 14usize, |this| ((**(this as *const *const IGroupPolicyObject_Vtbl)).GetFileSysPath)(this, dwsection, pszpath, cchmaxpath), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Registry")]
+        unsafe extern "system" fn GetRegistryKey(this: *mut core::ffi::c_void, dwsection: GPO_SECTION, hkey: *mut super::Registry::HKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGroupPolicyObject", "GetRegistryKey", 15usize, |this| ((**(this as *const *const IGroupPolicyObject_Vtbl)).GetRegistryKey)(this, dwsection, hkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOptions(this: *mut core::ffi::c_void, dwoptions: *mut GPO_OPTIONS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGroupPolicyObject", "GetOptions", 16usize, |this| ((**(this as *const *const IGroupPolicyObject_Vtbl)).GetOptions)(this, dwoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOptions(this: *mut core::ffi::c_void, dwoptions: GPO_OPTIONS, dwmask: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGroupPolicyObject", "SetOptions", 17usize, |this| ((**(this as *const *const IGroupPolicyObject_Vtbl)).SetOptions)(this, dwoptions, dwmask), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, gpotype: *mut GROUP_POLICY_OBJECT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGroupPolicyObject", "GetType", 18usize, |this| ((**(this as *const *const IGroupPolicyObject_Vtbl)).GetType)(this, gpotype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMachineName(this: *mut core::ffi::c_void, pszname: windows_core::PWSTR, cchmaxlength: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGroupPolicyObject", "GetMachineName", 19usize, |this| ((**(this as *const *const IGroupPolicyObject_Vtbl)).GetMachineName)(this, pszname, cchmaxlength), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Controls")]
+        unsafe extern "system" fn GetPropertySheetPages(this: *mut core::ffi::c_void, hpages: *mut *mut super::super::UI::Controls::HPROPSHEETPAGE, upagecount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGroupPolicyObject", "GetPropertySheetPages", 20usize, |this| ((**(this as *const *const IGroupPolicyObject_Vtbl)).GetPropertySheetPages)(this, hpages, upagecount), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            New,
+            OpenDSGPO,
+            OpenLocalMachineGPO,
+            OpenRemoteMachineGPO,
+            Save,
+            Delete,
+            GetName,
+            GetDisplayName,
+            SetDisplayName,
+            GetPath,
+            GetDSPath,
+            GetFileSysPath,
+            #[cfg(feature = "Win32_System_Registry")]
+            GetRegistryKey,
+            #[cfg(not(feature = "Win32_System_Registry"))]
+            GetRegistryKey: 0,
+            GetOptions,
+            SetOptions,
+            GetType,
+            GetMachineName,
+            #[cfg(feature = "Win32_UI_Controls")]
+            GetPropertySheetPages,
+            #[cfg(not(feature = "Win32_UI_Controls"))]
+            GetPropertySheetPages: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGroupPolicyObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRSOPInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNamespace(this: *mut core::ffi::c_void, dwsection: u32, pszname: windows_core::PWSTR, cchmaxlength: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRSOPInformation", "GetNamespace", 3usize, |this| ((**(this as *const *const IRSOPInformation_Vtbl)).GetNamespace)(this, dwsection, pszname, cchmaxlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlags(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRSOPInformation", "GetFlags", 4usize, |this| ((**(this as *const *const IRSOPInformation_Vtbl)).GetFlags)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEventLogEntryText(this: *mut core::ffi::c_void, pszeventsource: windows_core::PCWSTR, pszeventlogname: windows_core::PCWSTR, pszeventtime: windows_core::PCWSTR, dweventid: u32, ppsztext: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRSOPInformation", "GetEventLogEntryText", 5usize, |this| ((**(this as *const *const IRSOPInformation_Vtbl)).GetEventLogEntryText)(this, pszeventsource, pszeventlogname, pszeventtime, dweventid, ppsztext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetNamespace, GetFlags, GetEventLogEntryText }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRSOPInformation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
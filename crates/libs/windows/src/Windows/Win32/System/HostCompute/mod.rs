@@ -1,5 +1,6 @@
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HCS_CALLBACK(pub *mut core::ffi::c_void);
 impl HCS_CALLBACK {
     pub fn is_invalid(&self) -> bool {
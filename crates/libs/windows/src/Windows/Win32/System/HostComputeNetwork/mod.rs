@@ -303,6 +303,7 @@ pub struct HCN_NOTIFICATIONS(pub i32);
 impl windows_core::TypeKind for HCN_NOTIFICATIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HCN_NOTIFICATIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HCN_NOTIFICATIONS").field(&self.0).finish()
@@ -314,6 +315,7 @@ pub struct HCN_PORT_ACCESS(pub i32);
 impl windows_core::TypeKind for HCN_PORT_ACCESS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HCN_PORT_ACCESS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HCN_PORT_ACCESS").field(&self.0).finish()
@@ -325,13 +327,15 @@ pub struct HCN_PORT_PROTOCOL(pub i32);
 impl windows_core::TypeKind for HCN_PORT_PROTOCOL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HCN_PORT_PROTOCOL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HCN_PORT_PROTOCOL").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HCN_PORT_RANGE_ENTRY {
     pub OwningPartitionId: windows_core::GUID,
     pub TargetPartitionId: windows_core::GUID,
@@ -352,7 +356,8 @@ impl Default for HCN_PORT_RANGE_ENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HCN_PORT_RANGE_RESERVATION {
     pub startingPort: u16,
     pub endingPort: u16,
@@ -659,6 +659,7 @@ pub struct HCS_CREATE_OPTIONS(pub i32);
 impl windows_core::TypeKind for HCS_CREATE_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HCS_CREATE_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HCS_CREATE_OPTIONS").field(&self.0).finish()
@@ -670,6 +671,7 @@ pub struct HCS_EVENT_OPTIONS(pub i32);
 impl windows_core::TypeKind for HCS_EVENT_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HCS_EVENT_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HCS_EVENT_OPTIONS").field(&self.0).finish()
@@ -714,6 +716,7 @@ pub struct HCS_EVENT_TYPE(pub i32);
 impl windows_core::TypeKind for HCS_EVENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HCS_EVENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HCS_EVENT_TYPE").field(&self.0).finish()
@@ -725,6 +728,7 @@ pub struct HCS_NOTIFICATIONS(pub i32);
 impl windows_core::TypeKind for HCS_NOTIFICATIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HCS_NOTIFICATIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HCS_NOTIFICATIONS").field(&self.0).finish()
@@ -736,6 +740,7 @@ pub struct HCS_NOTIFICATION_FLAGS(pub i32);
 impl windows_core::TypeKind for HCS_NOTIFICATION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HCS_NOTIFICATION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HCS_NOTIFICATION_FLAGS").field(&self.0).finish()
@@ -747,6 +752,7 @@ pub struct HCS_OPERATION_OPTIONS(pub i32);
 impl windows_core::TypeKind for HCS_OPERATION_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HCS_OPERATION_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HCS_OPERATION_OPTIONS").field(&self.0).finish()
@@ -791,6 +797,7 @@ pub struct HCS_OPERATION_TYPE(pub i32);
 impl windows_core::TypeKind for HCS_OPERATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HCS_OPERATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HCS_OPERATION_TYPE").field(&self.0).finish()
@@ -802,6 +809,7 @@ pub struct HCS_RESOURCE_TYPE(pub i32);
 impl windows_core::TypeKind for HCS_RESOURCE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HCS_RESOURCE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HCS_RESOURCE_TYPE").field(&self.0).finish()
@@ -809,7 +817,8 @@ impl core::fmt::Debug for HCS_RESOURCE_TYPE {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Security")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HCS_CREATE_OPTIONS_1 {
     pub Version: HCS_CREATE_OPTIONS,
     pub UserToken: super::super::Foundation::HANDLE,
@@ -829,7 +838,8 @@ impl Default for HCS_CREATE_OPTIONS_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HCS_EVENT {
     pub Type: HCS_EVENT_TYPE,
     pub EventData: windows_core::PCWSTR,
@@ -844,7 +854,8 @@ impl Default for HCS_EVENT {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HCS_OPERATION(pub *mut core::ffi::c_void);
 impl HCS_OPERATION {
     pub fn is_invalid(&self) -> bool {
@@ -868,7 +879,8 @@ impl windows_core::TypeKind for HCS_OPERATION {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HCS_PROCESS(pub *mut core::ffi::c_void);
 impl HCS_PROCESS {
     pub fn is_invalid(&self) -> bool {
@@ -892,7 +904,8 @@ impl windows_core::TypeKind for HCS_PROCESS {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HCS_PROCESS_INFORMATION {
     pub ProcessId: u32,
     pub Reserved: u32,
@@ -909,7 +922,8 @@ impl Default for HCS_PROCESS_INFORMATION {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HCS_SYSTEM(pub *mut core::ffi::c_void);
 impl HCS_SYSTEM {
     pub fn is_invalid(&self) -> bool {
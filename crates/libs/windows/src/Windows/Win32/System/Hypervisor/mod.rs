@@ -1549,6 +1549,7 @@ pub struct GUEST_OS_MICROSOFT_IDS(pub i32);
 impl windows_core::TypeKind for GUEST_OS_MICROSOFT_IDS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GUEST_OS_MICROSOFT_IDS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GUEST_OS_MICROSOFT_IDS").field(&self.0).finish()
@@ -1560,6 +1561,7 @@ pub struct GUEST_OS_OPENSOURCE_IDS(pub i32);
 impl windows_core::TypeKind for GUEST_OS_OPENSOURCE_IDS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GUEST_OS_OPENSOURCE_IDS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GUEST_OS_OPENSOURCE_IDS").field(&self.0).finish()
@@ -1571,6 +1573,7 @@ pub struct GUEST_OS_VENDOR(pub i32);
 impl windows_core::TypeKind for GUEST_OS_VENDOR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GUEST_OS_VENDOR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GUEST_OS_VENDOR").field(&self.0).finish()
@@ -1582,6 +1585,7 @@ pub struct HDV_DEVICE_HOST_FLAGS(pub i32);
 impl windows_core::TypeKind for HDV_DEVICE_HOST_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HDV_DEVICE_HOST_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HDV_DEVICE_HOST_FLAGS").field(&self.0).finish()
@@ -1626,6 +1630,7 @@ pub struct HDV_DEVICE_TYPE(pub i32);
 impl windows_core::TypeKind for HDV_DEVICE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HDV_DEVICE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HDV_DEVICE_TYPE").field(&self.0).finish()
@@ -1637,6 +1642,7 @@ pub struct HDV_DOORBELL_FLAGS(pub i32);
 impl windows_core::TypeKind for HDV_DOORBELL_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HDV_DOORBELL_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HDV_DOORBELL_FLAGS").field(&self.0).finish()
@@ -1648,6 +1654,7 @@ pub struct HDV_MMIO_MAPPING_FLAGS(pub i32);
 impl windows_core::TypeKind for HDV_MMIO_MAPPING_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HDV_MMIO_MAPPING_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HDV_MMIO_MAPPING_FLAGS").field(&self.0).finish()
@@ -1692,6 +1699,7 @@ pub struct HDV_PCI_BAR_SELECTOR(pub i32);
 impl windows_core::TypeKind for HDV_PCI_BAR_SELECTOR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HDV_PCI_BAR_SELECTOR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HDV_PCI_BAR_SELECTOR").field(&self.0).finish()
@@ -1703,6 +1711,7 @@ pub struct HDV_PCI_INTERFACE_VERSION(pub i32);
 impl windows_core::TypeKind for HDV_PCI_INTERFACE_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HDV_PCI_INTERFACE_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HDV_PCI_INTERFACE_VERSION").field(&self.0).finish()
@@ -1714,6 +1723,7 @@ pub struct PAGING_MODE(pub i32);
 impl windows_core::TypeKind for PAGING_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PAGING_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PAGING_MODE").field(&self.0).finish()
@@ -1725,6 +1735,7 @@ pub struct REGISTER_ID(pub i32);
 impl windows_core::TypeKind for REGISTER_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for REGISTER_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("REGISTER_ID").field(&self.0).finish()
@@ -1736,6 +1747,7 @@ pub struct VIRTUAL_PROCESSOR_ARCH(pub i32);
 impl windows_core::TypeKind for VIRTUAL_PROCESSOR_ARCH {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VIRTUAL_PROCESSOR_ARCH {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VIRTUAL_PROCESSOR_ARCH").field(&self.0).finish()
@@ -1747,6 +1759,7 @@ pub struct VIRTUAL_PROCESSOR_VENDOR(pub i32);
 impl windows_core::TypeKind for VIRTUAL_PROCESSOR_VENDOR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VIRTUAL_PROCESSOR_VENDOR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VIRTUAL_PROCESSOR_VENDOR").field(&self.0).finish()
@@ -1758,6 +1771,7 @@ pub struct WHV_ADVISE_GPA_RANGE_CODE(pub i32);
 impl windows_core::TypeKind for WHV_ADVISE_GPA_RANGE_CODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_ADVISE_GPA_RANGE_CODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_ADVISE_GPA_RANGE_CODE").field(&self.0).finish()
@@ -1769,6 +1783,7 @@ pub struct WHV_ALLOCATE_VPCI_RESOURCE_FLAGS(pub i32);
 impl windows_core::TypeKind for WHV_ALLOCATE_VPCI_RESOURCE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_ALLOCATE_VPCI_RESOURCE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_ALLOCATE_VPCI_RESOURCE_FLAGS").field(&self.0).finish()
@@ -1813,6 +1828,7 @@ pub struct WHV_CACHE_TYPE(pub i32);
 impl windows_core::TypeKind for WHV_CACHE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_CACHE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_CACHE_TYPE").field(&self.0).finish()
@@ -1824,6 +1840,7 @@ pub struct WHV_CAPABILITY_CODE(pub i32);
 impl windows_core::TypeKind for WHV_CAPABILITY_CODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_CAPABILITY_CODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_CAPABILITY_CODE").field(&self.0).finish()
@@ -1835,6 +1852,7 @@ pub struct WHV_CREATE_VPCI_DEVICE_FLAGS(pub i32);
 impl windows_core::TypeKind for WHV_CREATE_VPCI_DEVICE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_CREATE_VPCI_DEVICE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_CREATE_VPCI_DEVICE_FLAGS").field(&self.0).finish()
@@ -1879,6 +1897,7 @@ pub struct WHV_EXCEPTION_TYPE(pub i32);
 impl windows_core::TypeKind for WHV_EXCEPTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_EXCEPTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_EXCEPTION_TYPE").field(&self.0).finish()
@@ -1890,6 +1909,7 @@ pub struct WHV_INTERRUPT_DESTINATION_MODE(pub i32);
 impl windows_core::TypeKind for WHV_INTERRUPT_DESTINATION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_INTERRUPT_DESTINATION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_INTERRUPT_DESTINATION_MODE").field(&self.0).finish()
@@ -1901,6 +1921,7 @@ pub struct WHV_INTERRUPT_TRIGGER_MODE(pub i32);
 impl windows_core::TypeKind for WHV_INTERRUPT_TRIGGER_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_INTERRUPT_TRIGGER_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_INTERRUPT_TRIGGER_MODE").field(&self.0).finish()
@@ -1912,6 +1933,7 @@ pub struct WHV_INTERRUPT_TYPE(pub i32);
 impl windows_core::TypeKind for WHV_INTERRUPT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_INTERRUPT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_INTERRUPT_TYPE").field(&self.0).finish()
@@ -1923,6 +1945,7 @@ pub struct WHV_MAP_GPA_RANGE_FLAGS(pub i32);
 impl windows_core::TypeKind for WHV_MAP_GPA_RANGE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_MAP_GPA_RANGE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_MAP_GPA_RANGE_FLAGS").field(&self.0).finish()
@@ -1967,6 +1990,7 @@ pub struct WHV_MEMORY_ACCESS_TYPE(pub i32);
 impl windows_core::TypeKind for WHV_MEMORY_ACCESS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_MEMORY_ACCESS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_MEMORY_ACCESS_TYPE").field(&self.0).finish()
@@ -1978,6 +2002,7 @@ pub struct WHV_MSR_ACTION(pub i32);
 impl windows_core::TypeKind for WHV_MSR_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_MSR_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_MSR_ACTION").field(&self.0).finish()
@@ -1989,6 +2014,7 @@ pub struct WHV_NOTIFICATION_PORT_PROPERTY_CODE(pub i32);
 impl windows_core::TypeKind for WHV_NOTIFICATION_PORT_PROPERTY_CODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_NOTIFICATION_PORT_PROPERTY_CODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_NOTIFICATION_PORT_PROPERTY_CODE").field(&self.0).finish()
@@ -2000,6 +2026,7 @@ pub struct WHV_NOTIFICATION_PORT_TYPE(pub i32);
 impl windows_core::TypeKind for WHV_NOTIFICATION_PORT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_NOTIFICATION_PORT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_NOTIFICATION_PORT_TYPE").field(&self.0).finish()
@@ -2011,6 +2038,7 @@ pub struct WHV_PARTITION_COUNTER_SET(pub i32);
 impl windows_core::TypeKind for WHV_PARTITION_COUNTER_SET {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_PARTITION_COUNTER_SET {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_PARTITION_COUNTER_SET").field(&self.0).finish()
@@ -2022,6 +2050,7 @@ pub struct WHV_PARTITION_PROPERTY_CODE(pub i32);
 impl windows_core::TypeKind for WHV_PARTITION_PROPERTY_CODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_PARTITION_PROPERTY_CODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_PARTITION_PROPERTY_CODE").field(&self.0).finish()
@@ -2033,6 +2062,7 @@ pub struct WHV_PROCESSOR_COUNTER_SET(pub i32);
 impl windows_core::TypeKind for WHV_PROCESSOR_COUNTER_SET {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_PROCESSOR_COUNTER_SET {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_PROCESSOR_COUNTER_SET").field(&self.0).finish()
@@ -2044,6 +2074,7 @@ pub struct WHV_PROCESSOR_VENDOR(pub i32);
 impl windows_core::TypeKind for WHV_PROCESSOR_VENDOR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_PROCESSOR_VENDOR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_PROCESSOR_VENDOR").field(&self.0).finish()
@@ -2055,6 +2086,7 @@ pub struct WHV_REGISTER_NAME(pub i32);
 impl windows_core::TypeKind for WHV_REGISTER_NAME {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_REGISTER_NAME {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_REGISTER_NAME").field(&self.0).finish()
@@ -2066,6 +2098,7 @@ pub struct WHV_RUN_VP_CANCEL_REASON(pub i32);
 impl windows_core::TypeKind for WHV_RUN_VP_CANCEL_REASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_RUN_VP_CANCEL_REASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_RUN_VP_CANCEL_REASON").field(&self.0).finish()
@@ -2077,6 +2110,7 @@ pub struct WHV_RUN_VP_EXIT_REASON(pub i32);
 impl windows_core::TypeKind for WHV_RUN_VP_EXIT_REASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_RUN_VP_EXIT_REASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_RUN_VP_EXIT_REASON").field(&self.0).finish()
@@ -2088,6 +2122,7 @@ pub struct WHV_TRANSLATE_GVA_FLAGS(pub i32);
 impl windows_core::TypeKind for WHV_TRANSLATE_GVA_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_TRANSLATE_GVA_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_TRANSLATE_GVA_FLAGS").field(&self.0).finish()
@@ -2132,6 +2167,7 @@ pub struct WHV_TRANSLATE_GVA_RESULT_CODE(pub i32);
 impl windows_core::TypeKind for WHV_TRANSLATE_GVA_RESULT_CODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_TRANSLATE_GVA_RESULT_CODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_TRANSLATE_GVA_RESULT_CODE").field(&self.0).finish()
@@ -2143,6 +2179,7 @@ pub struct WHV_TRIGGER_TYPE(pub i32);
 impl windows_core::TypeKind for WHV_TRIGGER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_TRIGGER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_TRIGGER_TYPE").field(&self.0).finish()
@@ -2154,6 +2191,7 @@ pub struct WHV_VIRTUAL_PROCESSOR_PROPERTY_CODE(pub i32);
 impl windows_core::TypeKind for WHV_VIRTUAL_PROCESSOR_PROPERTY_CODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_VIRTUAL_PROCESSOR_PROPERTY_CODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_VIRTUAL_PROCESSOR_PROPERTY_CODE").field(&self.0).finish()
@@ -2165,6 +2203,7 @@ pub struct WHV_VIRTUAL_PROCESSOR_STATE_TYPE(pub i32);
 impl windows_core::TypeKind for WHV_VIRTUAL_PROCESSOR_STATE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_VIRTUAL_PROCESSOR_STATE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_VIRTUAL_PROCESSOR_STATE_TYPE").field(&self.0).finish()
@@ -2176,6 +2215,7 @@ pub struct WHV_VPCI_DEVICE_NOTIFICATION_TYPE(pub i32);
 impl windows_core::TypeKind for WHV_VPCI_DEVICE_NOTIFICATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_VPCI_DEVICE_NOTIFICATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_VPCI_DEVICE_NOTIFICATION_TYPE").field(&self.0).finish()
@@ -2187,6 +2227,7 @@ pub struct WHV_VPCI_DEVICE_PROPERTY_CODE(pub i32);
 impl windows_core::TypeKind for WHV_VPCI_DEVICE_PROPERTY_CODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_VPCI_DEVICE_PROPERTY_CODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_VPCI_DEVICE_PROPERTY_CODE").field(&self.0).finish()
@@ -2198,6 +2239,7 @@ pub struct WHV_VPCI_DEVICE_REGISTER_SPACE(pub i32);
 impl windows_core::TypeKind for WHV_VPCI_DEVICE_REGISTER_SPACE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_VPCI_DEVICE_REGISTER_SPACE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_VPCI_DEVICE_REGISTER_SPACE").field(&self.0).finish()
@@ -2209,6 +2251,7 @@ pub struct WHV_VPCI_INTERRUPT_TARGET_FLAGS(pub i32);
 impl windows_core::TypeKind for WHV_VPCI_INTERRUPT_TARGET_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_VPCI_INTERRUPT_TARGET_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_VPCI_INTERRUPT_TARGET_FLAGS").field(&self.0).finish()
@@ -2253,6 +2296,7 @@ pub struct WHV_VPCI_MMIO_RANGE_FLAGS(pub i32);
 impl windows_core::TypeKind for WHV_VPCI_MMIO_RANGE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_VPCI_MMIO_RANGE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_VPCI_MMIO_RANGE_FLAGS").field(&self.0).finish()
@@ -2297,6 +2341,7 @@ pub struct WHV_X64_APIC_WRITE_TYPE(pub i32);
 impl windows_core::TypeKind for WHV_X64_APIC_WRITE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_X64_APIC_WRITE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_X64_APIC_WRITE_TYPE").field(&self.0).finish()
@@ -2308,6 +2353,7 @@ pub struct WHV_X64_CPUID_RESULT2_FLAGS(pub i32);
 impl windows_core::TypeKind for WHV_X64_CPUID_RESULT2_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_X64_CPUID_RESULT2_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_X64_CPUID_RESULT2_FLAGS").field(&self.0).finish()
@@ -2352,6 +2398,7 @@ pub struct WHV_X64_LOCAL_APIC_EMULATION_MODE(pub i32);
 impl windows_core::TypeKind for WHV_X64_LOCAL_APIC_EMULATION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_X64_LOCAL_APIC_EMULATION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_X64_LOCAL_APIC_EMULATION_MODE").field(&self.0).finish()
@@ -2363,6 +2410,7 @@ pub struct WHV_X64_PENDING_EVENT_TYPE(pub i32);
 impl windows_core::TypeKind for WHV_X64_PENDING_EVENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_X64_PENDING_EVENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_X64_PENDING_EVENT_TYPE").field(&self.0).finish()
@@ -2374,6 +2422,7 @@ pub struct WHV_X64_PENDING_INTERRUPTION_TYPE(pub i32);
 impl windows_core::TypeKind for WHV_X64_PENDING_INTERRUPTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_X64_PENDING_INTERRUPTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_X64_PENDING_INTERRUPTION_TYPE").field(&self.0).finish()
@@ -2385,13 +2434,15 @@ pub struct WHV_X64_UNSUPPORTED_FEATURE_CODE(pub i32);
 impl windows_core::TypeKind for WHV_X64_UNSUPPORTED_FEATURE_CODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WHV_X64_UNSUPPORTED_FEATURE_CODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WHV_X64_UNSUPPORTED_FEATURE_CODE").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DOS_IMAGE_INFO {
     pub PdbName: windows_core::PCSTR,
     pub ImageBaseAddress: u64,
@@ -2407,7 +2458,8 @@ impl Default for DOS_IMAGE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GPA_MEMORY_CHUNK {
     pub GuestPhysicalStartPageIndex: u64,
     pub PageCount: u64,
@@ -2436,10 +2488,49 @@ impl Default for GUEST_OS_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GUEST_OS_INFO_0 {
     pub _bitfield: u64,
 }
+impl GUEST_OS_INFO_0 {
+    pub const fn BuildNumber(&self) -> u64 {
+        self._bitfield & 65535
+    }
+    pub fn set_BuildNumber(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn ServiceVersion(&self) -> u64 {
+        (self._bitfield >> 16) & 255
+    }
+    pub fn set_ServiceVersion(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(255 << 16)) | ((value & 255) << 16);
+    }
+    pub const fn MinorVersion(&self) -> u64 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_MinorVersion(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+    pub const fn MajorVersion(&self) -> u64 {
+        (self._bitfield >> 32) & 255
+    }
+    pub fn set_MajorVersion(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(255 << 32)) | ((value & 255) << 32);
+    }
+    pub const fn OsId(&self) -> u64 {
+        (self._bitfield >> 40) & 255
+    }
+    pub fn set_OsId(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(255 << 40)) | ((value & 255) << 40);
+    }
+    pub const fn VendorId(&self) -> u64 {
+        (self._bitfield >> 48) & 65535
+    }
+    pub fn set_VendorId(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(65535 << 48)) | ((value & 65535) << 48);
+    }
+}
 impl windows_core::TypeKind for GUEST_OS_INFO_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2449,10 +2540,43 @@ impl Default for GUEST_OS_INFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GUEST_OS_INFO_1 {
     pub _bitfield: u64,
 }
+impl GUEST_OS_INFO_1 {
+    pub const fn VendorSpecific1(&self) -> u64 {
+        self._bitfield & 65535
+    }
+    pub fn set_VendorSpecific1(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn Version(&self) -> u64 {
+        (self._bitfield >> 16) & 4294967295
+    }
+    pub fn set_Version(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(4294967295 << 16)) | ((value & 4294967295) << 16);
+    }
+    pub const fn VendorSpecific2(&self) -> u64 {
+        (self._bitfield >> 48) & 255
+    }
+    pub fn set_VendorSpecific2(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(255 << 48)) | ((value & 255) << 48);
+    }
+    pub const fn OsId(&self) -> u64 {
+        (self._bitfield >> 56) & 127
+    }
+    pub fn set_OsId(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(127 << 56)) | ((value & 127) << 56);
+    }
+    pub const fn IsOpenSource(&self) -> u64 {
+        (self._bitfield >> 63) & 1
+    }
+    pub fn set_IsOpenSource(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 63)) | ((value & 1) << 63);
+    }
+}
 impl windows_core::TypeKind for GUEST_OS_INFO_1 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2462,7 +2586,8 @@ impl Default for GUEST_OS_INFO_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HDV_PCI_DEVICE_INTERFACE {
     pub Version: HDV_PCI_INTERFACE_VERSION,
     pub Initialize: HDV_PCI_DEVICE_INITIALIZE,
@@ -2485,7 +2610,8 @@ impl Default for HDV_PCI_DEVICE_INTERFACE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HDV_PCI_PNP_ID {
     pub VendorID: u16,
     pub DeviceID: u16,
@@ -2505,7 +2631,8 @@ impl Default for HDV_PCI_PNP_ID {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HVSOCKET_ADDRESS_INFO {
     pub SystemId: windows_core::GUID,
     pub VirtualMachineId: windows_core::GUID,
@@ -2521,7 +2648,8 @@ impl Default for HVSOCKET_ADDRESS_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MODULE_INFO {
     pub ProcessImageName: windows_core::PCSTR,
     pub Image: DOS_IMAGE_INFO,
@@ -2536,7 +2664,8 @@ impl Default for MODULE_INFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Networking_WinSock")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOCKADDR_HV {
     pub Family: super::super::Networking::WinSock::ADDRESS_FAMILY,
     pub Reserved: u16,
@@ -2572,7 +2701,8 @@ impl Default for VIRTUAL_PROCESSOR_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIRTUAL_PROCESSOR_REGISTER_0 {
     pub Low64: u64,
     pub High64: u64,
@@ -2634,7 +2764,8 @@ impl Default for VIRTUAL_PROCESSOR_REGISTER_1_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIRTUAL_PROCESSOR_REGISTER_1_0_0_0 {
     pub LastFpEip: u32,
     pub LastFpCs: u16,
@@ -2678,10 +2809,67 @@ impl Default for VIRTUAL_PROCESSOR_REGISTER_1_1_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIRTUAL_PROCESSOR_REGISTER_1_1_0_0 {
     pub _bitfield: u16,
 }
+impl VIRTUAL_PROCESSOR_REGISTER_1_1_0_0 {
+    pub const fn SegmentType(&self) -> u16 {
+        self._bitfield & 15
+    }
+    pub fn set_SegmentType(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn NonSystemSegment(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_NonSystemSegment(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn DescriptorPrivilegeLevel(&self) -> u16 {
+        (self._bitfield >> 5) & 3
+    }
+    pub fn set_DescriptorPrivilegeLevel(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 5)) | ((value & 3) << 5);
+    }
+    pub const fn Present(&self) -> u16 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Present(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 8) & 15
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(15 << 8)) | ((value & 15) << 8);
+    }
+    pub const fn Available(&self) -> u16 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_Available(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn Long(&self) -> u16 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_Long(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn Default(&self) -> u16 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_Default(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn Granularity(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_Granularity(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for VIRTUAL_PROCESSOR_REGISTER_1_1_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2691,7 +2879,8 @@ impl Default for VIRTUAL_PROCESSOR_REGISTER_1_1_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIRTUAL_PROCESSOR_REGISTER_1_2 {
     pub Limit: u16,
     pub Base: u64,
@@ -2734,7 +2923,8 @@ impl Default for VIRTUAL_PROCESSOR_REGISTER_1_3_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VIRTUAL_PROCESSOR_REGISTER_1_3_0_0 {
     pub LastFpDp: u32,
     pub LastFpDs: u16,
@@ -2748,7 +2938,8 @@ impl Default for VIRTUAL_PROCESSOR_REGISTER_1_3_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VM_GENCOUNTER {
     pub GenerationCount: u64,
     pub GenerationCountHigh: u64,
@@ -2776,7 +2967,8 @@ impl Default for WHV_ACCESS_GPA_CONTROLS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_ACCESS_GPA_CONTROLS_0 {
     pub CacheType: WHV_CACHE_TYPE,
     pub Reserved: u32,
@@ -2831,10 +3023,31 @@ impl Default for WHV_ADVISE_GPA_RANGE_POPULATE_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_ADVISE_GPA_RANGE_POPULATE_FLAGS_0 {
     pub _bitfield: u32,
 }
+impl WHV_ADVISE_GPA_RANGE_POPULATE_FLAGS_0 {
+    pub const fn Prefetch(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Prefetch(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn AvoidHardFaults(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_AvoidHardFaults(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 2) & 1073741823
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1073741823 << 2)) | ((value & 1073741823) << 2);
+    }
+}
 impl windows_core::TypeKind for WHV_ADVISE_GPA_RANGE_POPULATE_FLAGS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2887,10 +3100,79 @@ impl Default for WHV_CAPABILITY_FEATURES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_CAPABILITY_FEATURES_0 {
     pub _bitfield: u64,
 }
+impl WHV_CAPABILITY_FEATURES_0 {
+    pub const fn PartialUnmap(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_PartialUnmap(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn LocalApicEmulation(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_LocalApicEmulation(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Xsave(&self) -> u64 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Xsave(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn DirtyPageTracking(&self) -> u64 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_DirtyPageTracking(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn SpeculationControl(&self) -> u64 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_SpeculationControl(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn ApicRemoteRead(&self) -> u64 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_ApicRemoteRead(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn IdleSuspend(&self) -> u64 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_IdleSuspend(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn VirtualPciDeviceSupport(&self) -> u64 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_VirtualPciDeviceSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn IommuSupport(&self) -> u64 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_IommuSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn VpHotAddRemove(&self) -> u64 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_VpHotAddRemove(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 10) & 18014398509481983
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(18014398509481983 << 10)) | ((value & 18014398509481983) << 10);
+    }
+}
 impl windows_core::TypeKind for WHV_CAPABILITY_FEATURES_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -2900,7 +3182,8 @@ impl Default for WHV_CAPABILITY_FEATURES_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_CAPABILITY_PROCESSOR_FREQUENCY_CAP {
     pub _bitfield: u32,
     pub HighestFrequencyMhz: u32,
@@ -2908,6 +3191,20 @@ pub struct WHV_CAPABILITY_PROCESSOR_FREQUENCY_CAP {
     pub LowestFrequencyMhz: u32,
     pub FrequencyStepMhz: u32,
 }
+impl WHV_CAPABILITY_PROCESSOR_FREQUENCY_CAP {
+    pub const fn IsSupported(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_IsSupported(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for WHV_CAPABILITY_PROCESSOR_FREQUENCY_CAP {
     type TypeKind = windows_core::CopyType;
 }
@@ -2917,7 +3214,8 @@ impl Default for WHV_CAPABILITY_PROCESSOR_FREQUENCY_CAP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_CPUID_OUTPUT {
     pub Eax: u32,
     pub Ebx: u32,
@@ -2933,13 +3231,34 @@ impl Default for WHV_CPUID_OUTPUT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_DOORBELL_MATCH_DATA {
     pub GuestAddress: u64,
     pub Value: u64,
     pub Length: u32,
     pub _bitfield: u32,
 }
+impl WHV_DOORBELL_MATCH_DATA {
+    pub const fn MatchOnValue(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_MatchOnValue(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn MatchOnLength(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_MatchOnLength(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 2) & 1073741823
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1073741823 << 2)) | ((value & 1073741823) << 2);
+    }
+}
 impl windows_core::TypeKind for WHV_DOORBELL_MATCH_DATA {
     type TypeKind = windows_core::CopyType;
 }
@@ -2949,7 +3268,8 @@ impl Default for WHV_DOORBELL_MATCH_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_EMULATOR_CALLBACKS {
     pub Size: u32,
     pub Reserved: u32,
@@ -2968,7 +3288,8 @@ impl Default for WHV_EMULATOR_CALLBACKS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_EMULATOR_IO_ACCESS_INFO {
     pub Direction: u8,
     pub Port: u16,
@@ -2984,7 +3305,8 @@ impl Default for WHV_EMULATOR_IO_ACCESS_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_EMULATOR_MEMORY_ACCESS_INFO {
     pub GpaAddress: u64,
     pub Direction: u8,
@@ -3014,10 +3336,79 @@ impl Default for WHV_EMULATOR_STATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_EMULATOR_STATUS_0 {
     pub _bitfield: u32,
 }
+impl WHV_EMULATOR_STATUS_0 {
+    pub const fn EmulationSuccessful(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_EmulationSuccessful(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn InternalEmulationFailure(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_InternalEmulationFailure(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn IoPortCallbackFailed(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_IoPortCallbackFailed(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn MemoryCallbackFailed(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_MemoryCallbackFailed(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn TranslateGvaPageCallbackFailed(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_TranslateGvaPageCallbackFailed(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn TranslateGvaPageCallbackGpaIsNotAligned(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_TranslateGvaPageCallbackGpaIsNotAligned(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn GetVirtualProcessorRegistersCallbackFailed(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_GetVirtualProcessorRegistersCallbackFailed(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn SetVirtualProcessorRegistersCallbackFailed(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_SetVirtualProcessorRegistersCallbackFailed(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn InterruptCausedIntercept(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_InterruptCausedIntercept(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn GuestCannotBeFaulted(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_GuestCannotBeFaulted(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 10) & 4194303
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(4194303 << 10)) | ((value & 4194303) << 10);
+    }
+}
 impl windows_core::TypeKind for WHV_EMULATOR_STATUS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3041,10 +3432,109 @@ impl Default for WHV_EXTENDED_VM_EXITS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_EXTENDED_VM_EXITS_0 {
     pub _bitfield: u64,
 }
+impl WHV_EXTENDED_VM_EXITS_0 {
+    pub const fn X64CpuidExit(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_X64CpuidExit(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn X64MsrExit(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_X64MsrExit(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ExceptionExit(&self) -> u64 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_ExceptionExit(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn X64RdtscExit(&self) -> u64 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_X64RdtscExit(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn X64ApicSmiExitTrap(&self) -> u64 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_X64ApicSmiExitTrap(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn HypercallExit(&self) -> u64 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_HypercallExit(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn X64ApicInitSipiExitTrap(&self) -> u64 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_X64ApicInitSipiExitTrap(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn X64ApicWriteLint0ExitTrap(&self) -> u64 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_X64ApicWriteLint0ExitTrap(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn X64ApicWriteLint1ExitTrap(&self) -> u64 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_X64ApicWriteLint1ExitTrap(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn X64ApicWriteSvrExitTrap(&self) -> u64 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_X64ApicWriteSvrExitTrap(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn UnknownSynicConnection(&self) -> u64 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_UnknownSynicConnection(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn RetargetUnknownVpciDevice(&self) -> u64 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_RetargetUnknownVpciDevice(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn X64ApicWriteLdrExitTrap(&self) -> u64 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_X64ApicWriteLdrExitTrap(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn X64ApicWriteDfrExitTrap(&self) -> u64 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_X64ApicWriteDfrExitTrap(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn GpaAccessFaultExit(&self) -> u64 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_GpaAccessFaultExit(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 15) & 562949953421311
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(562949953421311 << 15)) | ((value & 562949953421311) << 15);
+    }
+}
 impl windows_core::TypeKind for WHV_EXTENDED_VM_EXITS_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3090,10 +3580,37 @@ impl Default for WHV_INTERNAL_ACTIVITY_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_INTERNAL_ACTIVITY_REGISTER_0 {
     pub _bitfield: u64,
 }
+impl WHV_INTERNAL_ACTIVITY_REGISTER_0 {
+    pub const fn StartupSuspend(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_StartupSuspend(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn HaltSuspend(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_HaltSuspend(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn IdleSuspend(&self) -> u64 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_IdleSuspend(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 3) & 2305843009213693951
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(2305843009213693951 << 3)) | ((value & 2305843009213693951) << 3);
+    }
+}
 impl windows_core::TypeKind for WHV_INTERNAL_ACTIVITY_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3103,12 +3620,39 @@ impl Default for WHV_INTERNAL_ACTIVITY_REGISTER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_INTERRUPT_CONTROL {
     pub _bitfield: u64,
     pub Destination: u32,
     pub Vector: u32,
 }
+impl WHV_INTERRUPT_CONTROL {
+    pub const fn Type(&self) -> u64 {
+        self._bitfield & 255
+    }
+    pub fn set_Type(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn DestinationMode(&self) -> u64 {
+        (self._bitfield >> 8) & 15
+    }
+    pub fn set_DestinationMode(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(15 << 8)) | ((value & 15) << 8);
+    }
+    pub const fn TriggerMode(&self) -> u64 {
+        (self._bitfield >> 12) & 15
+    }
+    pub fn set_TriggerMode(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(15 << 12)) | ((value & 15) << 12);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 16) & 281474976710655
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(281474976710655 << 16)) | ((value & 281474976710655) << 16);
+    }
+}
 impl windows_core::TypeKind for WHV_INTERRUPT_CONTROL {
     type TypeKind = windows_core::CopyType;
 }
@@ -3150,10 +3694,37 @@ impl Default for WHV_MEMORY_ACCESS_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_MEMORY_ACCESS_INFO_0 {
     pub _bitfield: u32,
 }
+impl WHV_MEMORY_ACCESS_INFO_0 {
+    pub const fn AccessType(&self) -> u32 {
+        self._bitfield & 3
+    }
+    pub fn set_AccessType(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn GpaUnmapped(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_GpaUnmapped(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn GvaValid(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_GvaValid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 4) & 268435455
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(268435455 << 4)) | ((value & 268435455) << 4);
+    }
+}
 impl windows_core::TypeKind for WHV_MEMORY_ACCESS_INFO_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3163,7 +3734,8 @@ impl Default for WHV_MEMORY_ACCESS_INFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_MEMORY_RANGE_ENTRY {
     pub GuestAddress: u64,
     pub SizeInBytes: u64,
@@ -3177,7 +3749,8 @@ impl Default for WHV_MEMORY_RANGE_ENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_MSR_ACTION_ENTRY {
     pub Index: u32,
     pub ReadAction: u8,
@@ -3222,7 +3795,8 @@ impl Default for WHV_NOTIFICATION_PORT_PARAMETERS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_NOTIFICATION_PORT_PARAMETERS_0_0 {
     pub ConnectionId: u32,
 }
@@ -3235,7 +3809,8 @@ impl Default for WHV_NOTIFICATION_PORT_PARAMETERS_0_0 {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_PARTITION_HANDLE(pub isize);
 impl WHV_PARTITION_HANDLE {
     pub fn is_invalid(&self) -> bool {
@@ -3259,7 +3834,8 @@ impl windows_core::TypeKind for WHV_PARTITION_HANDLE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_PARTITION_MEMORY_COUNTERS {
     pub Mapped4KPageCount: u64,
     pub Mapped2MPageCount: u64,
@@ -3316,7 +3892,8 @@ impl Default for WHV_PARTITION_PROPERTY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_PROCESSOR_APIC_COUNTERS {
     pub MmioAccessCount: u64,
     pub EoiAccessCount: u64,
@@ -3333,7 +3910,8 @@ impl Default for WHV_PROCESSOR_APIC_COUNTERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_PROCESSOR_EVENT_COUNTERS {
     pub PageFaultCount: u64,
     pub ExceptionCount: u64,
@@ -3362,10 +3940,391 @@ impl Default for WHV_PROCESSOR_FEATURES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_PROCESSOR_FEATURES_0 {
     pub _bitfield: u64,
 }
+impl WHV_PROCESSOR_FEATURES_0 {
+    pub const fn Sse3Support(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_Sse3Support(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn LahfSahfSupport(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_LahfSahfSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Ssse3Support(&self) -> u64 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Ssse3Support(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Sse4_1Support(&self) -> u64 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_Sse4_1Support(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Sse4_2Support(&self) -> u64 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_Sse4_2Support(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Sse4aSupport(&self) -> u64 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_Sse4aSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn XopSupport(&self) -> u64 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_XopSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn PopCntSupport(&self) -> u64 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_PopCntSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn Cmpxchg16bSupport(&self) -> u64 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_Cmpxchg16bSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn Altmovcr8Support(&self) -> u64 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_Altmovcr8Support(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn LzcntSupport(&self) -> u64 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_LzcntSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn MisAlignSseSupport(&self) -> u64 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_MisAlignSseSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn MmxExtSupport(&self) -> u64 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_MmxExtSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn Amd3DNowSupport(&self) -> u64 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_Amd3DNowSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn ExtendedAmd3DNowSupport(&self) -> u64 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_ExtendedAmd3DNowSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn Page1GbSupport(&self) -> u64 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_Page1GbSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+    pub const fn AesSupport(&self) -> u64 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_AesSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn PclmulqdqSupport(&self) -> u64 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_PclmulqdqSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn PcidSupport(&self) -> u64 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_PcidSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn Fma4Support(&self) -> u64 {
+        (self._bitfield >> 19) & 1
+    }
+    pub fn set_Fma4Support(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 19)) | ((value & 1) << 19);
+    }
+    pub const fn F16CSupport(&self) -> u64 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_F16CSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn RdRandSupport(&self) -> u64 {
+        (self._bitfield >> 21) & 1
+    }
+    pub fn set_RdRandSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 21)) | ((value & 1) << 21);
+    }
+    pub const fn RdWrFsGsSupport(&self) -> u64 {
+        (self._bitfield >> 22) & 1
+    }
+    pub fn set_RdWrFsGsSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 22)) | ((value & 1) << 22);
+    }
+    pub const fn SmepSupport(&self) -> u64 {
+        (self._bitfield >> 23) & 1
+    }
+    pub fn set_SmepSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 23)) | ((value & 1) << 23);
+    }
+    pub const fn EnhancedFastStringSupport(&self) -> u64 {
+        (self._bitfield >> 24) & 1
+    }
+    pub fn set_EnhancedFastStringSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 24)) | ((value & 1) << 24);
+    }
+    pub const fn Bmi1Support(&self) -> u64 {
+        (self._bitfield >> 25) & 1
+    }
+    pub fn set_Bmi1Support(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 25)) | ((value & 1) << 25);
+    }
+    pub const fn Bmi2Support(&self) -> u64 {
+        (self._bitfield >> 26) & 1
+    }
+    pub fn set_Bmi2Support(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 26)) | ((value & 1) << 26);
+    }
+    pub const fn Reserved1(&self) -> u64 {
+        (self._bitfield >> 27) & 3
+    }
+    pub fn set_Reserved1(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(3 << 27)) | ((value & 3) << 27);
+    }
+    pub const fn MovbeSupport(&self) -> u64 {
+        (self._bitfield >> 29) & 1
+    }
+    pub fn set_MovbeSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 29)) | ((value & 1) << 29);
+    }
+    pub const fn Npiep1Support(&self) -> u64 {
+        (self._bitfield >> 30) & 1
+    }
+    pub fn set_Npiep1Support(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 30)) | ((value & 1) << 30);
+    }
+    pub const fn DepX87FPUSaveSupport(&self) -> u64 {
+        (self._bitfield >> 31) & 1
+    }
+    pub fn set_DepX87FPUSaveSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 31)) | ((value & 1) << 31);
+    }
+    pub const fn RdSeedSupport(&self) -> u64 {
+        (self._bitfield >> 32) & 1
+    }
+    pub fn set_RdSeedSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 32)) | ((value & 1) << 32);
+    }
+    pub const fn AdxSupport(&self) -> u64 {
+        (self._bitfield >> 33) & 1
+    }
+    pub fn set_AdxSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 33)) | ((value & 1) << 33);
+    }
+    pub const fn IntelPrefetchSupport(&self) -> u64 {
+        (self._bitfield >> 34) & 1
+    }
+    pub fn set_IntelPrefetchSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 34)) | ((value & 1) << 34);
+    }
+    pub const fn SmapSupport(&self) -> u64 {
+        (self._bitfield >> 35) & 1
+    }
+    pub fn set_SmapSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 35)) | ((value & 1) << 35);
+    }
+    pub const fn HleSupport(&self) -> u64 {
+        (self._bitfield >> 36) & 1
+    }
+    pub fn set_HleSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 36)) | ((value & 1) << 36);
+    }
+    pub const fn RtmSupport(&self) -> u64 {
+        (self._bitfield >> 37) & 1
+    }
+    pub fn set_RtmSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 37)) | ((value & 1) << 37);
+    }
+    pub const fn RdtscpSupport(&self) -> u64 {
+        (self._bitfield >> 38) & 1
+    }
+    pub fn set_RdtscpSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 38)) | ((value & 1) << 38);
+    }
+    pub const fn ClflushoptSupport(&self) -> u64 {
+        (self._bitfield >> 39) & 1
+    }
+    pub fn set_ClflushoptSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 39)) | ((value & 1) << 39);
+    }
+    pub const fn ClwbSupport(&self) -> u64 {
+        (self._bitfield >> 40) & 1
+    }
+    pub fn set_ClwbSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 40)) | ((value & 1) << 40);
+    }
+    pub const fn ShaSupport(&self) -> u64 {
+        (self._bitfield >> 41) & 1
+    }
+    pub fn set_ShaSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 41)) | ((value & 1) << 41);
+    }
+    pub const fn X87PointersSavedSupport(&self) -> u64 {
+        (self._bitfield >> 42) & 1
+    }
+    pub fn set_X87PointersSavedSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 42)) | ((value & 1) << 42);
+    }
+    pub const fn InvpcidSupport(&self) -> u64 {
+        (self._bitfield >> 43) & 1
+    }
+    pub fn set_InvpcidSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 43)) | ((value & 1) << 43);
+    }
+    pub const fn IbrsSupport(&self) -> u64 {
+        (self._bitfield >> 44) & 1
+    }
+    pub fn set_IbrsSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 44)) | ((value & 1) << 44);
+    }
+    pub const fn StibpSupport(&self) -> u64 {
+        (self._bitfield >> 45) & 1
+    }
+    pub fn set_StibpSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 45)) | ((value & 1) << 45);
+    }
+    pub const fn IbpbSupport(&self) -> u64 {
+        (self._bitfield >> 46) & 1
+    }
+    pub fn set_IbpbSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 46)) | ((value & 1) << 46);
+    }
+    pub const fn Reserved2(&self) -> u64 {
+        (self._bitfield >> 47) & 1
+    }
+    pub fn set_Reserved2(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 47)) | ((value & 1) << 47);
+    }
+    pub const fn SsbdSupport(&self) -> u64 {
+        (self._bitfield >> 48) & 1
+    }
+    pub fn set_SsbdSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 48)) | ((value & 1) << 48);
+    }
+    pub const fn FastShortRepMovSupport(&self) -> u64 {
+        (self._bitfield >> 49) & 1
+    }
+    pub fn set_FastShortRepMovSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 49)) | ((value & 1) << 49);
+    }
+    pub const fn Reserved3(&self) -> u64 {
+        (self._bitfield >> 50) & 1
+    }
+    pub fn set_Reserved3(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 50)) | ((value & 1) << 50);
+    }
+    pub const fn RdclNo(&self) -> u64 {
+        (self._bitfield >> 51) & 1
+    }
+    pub fn set_RdclNo(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 51)) | ((value & 1) << 51);
+    }
+    pub const fn IbrsAllSupport(&self) -> u64 {
+        (self._bitfield >> 52) & 1
+    }
+    pub fn set_IbrsAllSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 52)) | ((value & 1) << 52);
+    }
+    pub const fn Reserved4(&self) -> u64 {
+        (self._bitfield >> 53) & 1
+    }
+    pub fn set_Reserved4(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 53)) | ((value & 1) << 53);
+    }
+    pub const fn SsbNo(&self) -> u64 {
+        (self._bitfield >> 54) & 1
+    }
+    pub fn set_SsbNo(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 54)) | ((value & 1) << 54);
+    }
+    pub const fn RsbANo(&self) -> u64 {
+        (self._bitfield >> 55) & 1
+    }
+    pub fn set_RsbANo(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 55)) | ((value & 1) << 55);
+    }
+    pub const fn Reserved5(&self) -> u64 {
+        (self._bitfield >> 56) & 1
+    }
+    pub fn set_Reserved5(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 56)) | ((value & 1) << 56);
+    }
+    pub const fn RdPidSupport(&self) -> u64 {
+        (self._bitfield >> 57) & 1
+    }
+    pub fn set_RdPidSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 57)) | ((value & 1) << 57);
+    }
+    pub const fn UmipSupport(&self) -> u64 {
+        (self._bitfield >> 58) & 1
+    }
+    pub fn set_UmipSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 58)) | ((value & 1) << 58);
+    }
+    pub const fn MdsNoSupport(&self) -> u64 {
+        (self._bitfield >> 59) & 1
+    }
+    pub fn set_MdsNoSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 59)) | ((value & 1) << 59);
+    }
+    pub const fn MdClearSupport(&self) -> u64 {
+        (self._bitfield >> 60) & 1
+    }
+    pub fn set_MdClearSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 60)) | ((value & 1) << 60);
+    }
+    pub const fn TaaNoSupport(&self) -> u64 {
+        (self._bitfield >> 61) & 1
+    }
+    pub fn set_TaaNoSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 61)) | ((value & 1) << 61);
+    }
+    pub const fn TsxCtrlSupport(&self) -> u64 {
+        (self._bitfield >> 62) & 1
+    }
+    pub fn set_TsxCtrlSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 62)) | ((value & 1) << 62);
+    }
+    pub const fn Reserved6(&self) -> u64 {
+        (self._bitfield >> 63) & 1
+    }
+    pub fn set_Reserved6(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 63)) | ((value & 1) << 63);
+    }
+}
 impl windows_core::TypeKind for WHV_PROCESSOR_FEATURES_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3389,10 +4348,151 @@ impl Default for WHV_PROCESSOR_FEATURES1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_PROCESSOR_FEATURES1_0 {
     pub _bitfield: u64,
 }
+impl WHV_PROCESSOR_FEATURES1_0 {
+    pub const fn ACountMCountSupport(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_ACountMCountSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn TscInvariantSupport(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_TscInvariantSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ClZeroSupport(&self) -> u64 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_ClZeroSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn RdpruSupport(&self) -> u64 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_RdpruSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved2(&self) -> u64 {
+        (self._bitfield >> 4) & 3
+    }
+    pub fn set_Reserved2(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(3 << 4)) | ((value & 3) << 4);
+    }
+    pub const fn NestedVirtSupport(&self) -> u64 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_NestedVirtSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn PsfdSupport(&self) -> u64 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_PsfdSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn CetSsSupport(&self) -> u64 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_CetSsSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn CetIbtSupport(&self) -> u64 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_CetIbtSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn VmxExceptionInjectSupport(&self) -> u64 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_VmxExceptionInjectSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn Reserved4(&self) -> u64 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_Reserved4(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn UmwaitTpauseSupport(&self) -> u64 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_UmwaitTpauseSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn MovdiriSupport(&self) -> u64 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_MovdiriSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn Movdir64bSupport(&self) -> u64 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_Movdir64bSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn CldemoteSupport(&self) -> u64 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_CldemoteSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+    pub const fn SerializeSupport(&self) -> u64 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_SerializeSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn TscDeadlineTmrSupport(&self) -> u64 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_TscDeadlineTmrSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn TscAdjustSupport(&self) -> u64 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_TscAdjustSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn FZLRepMovsb(&self) -> u64 {
+        (self._bitfield >> 19) & 1
+    }
+    pub fn set_FZLRepMovsb(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 19)) | ((value & 1) << 19);
+    }
+    pub const fn FSRepStosb(&self) -> u64 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_FSRepStosb(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn FSRepCmpsb(&self) -> u64 {
+        (self._bitfield >> 21) & 1
+    }
+    pub fn set_FSRepCmpsb(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 21)) | ((value & 1) << 21);
+    }
+    pub const fn TsxLdTrkSupport(&self) -> u64 {
+        (self._bitfield >> 22) & 1
+    }
+    pub fn set_TsxLdTrkSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 22)) | ((value & 1) << 22);
+    }
+    pub const fn Reserved5(&self) -> u64 {
+        (self._bitfield >> 23) & 2199023255551
+    }
+    pub fn set_Reserved5(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(2199023255551 << 23)) | ((value & 2199023255551) << 23);
+    }
+}
 impl windows_core::TypeKind for WHV_PROCESSOR_FEATURES1_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3445,7 +4545,8 @@ impl Default for WHV_PROCESSOR_FEATURES_BANKS_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_PROCESSOR_INTERCEPT_COUNTER {
     pub Count: u64,
     pub Time100ns: u64,
@@ -3459,7 +4560,8 @@ impl Default for WHV_PROCESSOR_INTERCEPT_COUNTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_PROCESSOR_INTERCEPT_COUNTERS {
     pub PageInvalidations: WHV_PROCESSOR_INTERCEPT_COUNTER,
     pub ControlRegisterAccesses: WHV_PROCESSOR_INTERCEPT_COUNTER,
@@ -3499,10 +4601,31 @@ impl Default for WHV_PROCESSOR_PERFMON_FEATURES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_PROCESSOR_PERFMON_FEATURES_0 {
     pub _bitfield: u64,
 }
+impl WHV_PROCESSOR_PERFMON_FEATURES_0 {
+    pub const fn PmuSupport(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_PmuSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn LbrSupport(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_LbrSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 2) & 4611686018427387903
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(4611686018427387903 << 2)) | ((value & 4611686018427387903) << 2);
+    }
+}
 impl windows_core::TypeKind for WHV_PROCESSOR_PERFMON_FEATURES_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3512,7 +4635,8 @@ impl Default for WHV_PROCESSOR_PERFMON_FEATURES_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_PROCESSOR_RUNTIME_COUNTERS {
     pub TotalRuntime100ns: u64,
     pub HypervisorRuntime100ns: u64,
@@ -3526,7 +4650,8 @@ impl Default for WHV_PROCESSOR_RUNTIME_COUNTERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_PROCESSOR_SYNTHETIC_FEATURES_COUNTERS {
     pub SyntheticInterruptsCount: u64,
     pub LongSpinWaitHypercallsCount: u64,
@@ -3556,11 +4681,206 @@ impl Default for WHV_PROCESSOR_XSAVE_FEATURES {
     fn default() -> Self {
         unsafe { core::mem::zeroed() }
     }
-}
-#[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub struct WHV_PROCESSOR_XSAVE_FEATURES_0 {
-    pub _bitfield: u64,
+}
+#[repr(C)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct WHV_PROCESSOR_XSAVE_FEATURES_0 {
+    pub _bitfield: u64,
+}
+impl WHV_PROCESSOR_XSAVE_FEATURES_0 {
+    pub const fn XsaveSupport(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_XsaveSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn XsaveoptSupport(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_XsaveoptSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn AvxSupport(&self) -> u64 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_AvxSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Avx2Support(&self) -> u64 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_Avx2Support(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn FmaSupport(&self) -> u64 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_FmaSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn MpxSupport(&self) -> u64 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_MpxSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Avx512Support(&self) -> u64 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_Avx512Support(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Avx512DQSupport(&self) -> u64 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Avx512DQSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn Avx512CDSupport(&self) -> u64 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_Avx512CDSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn Avx512BWSupport(&self) -> u64 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_Avx512BWSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn Avx512VLSupport(&self) -> u64 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_Avx512VLSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn XsaveCompSupport(&self) -> u64 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_XsaveCompSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn XsaveSupervisorSupport(&self) -> u64 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_XsaveSupervisorSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn Xcr1Support(&self) -> u64 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_Xcr1Support(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn Avx512BitalgSupport(&self) -> u64 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_Avx512BitalgSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn Avx512IfmaSupport(&self) -> u64 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_Avx512IfmaSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+    pub const fn Avx512VBmiSupport(&self) -> u64 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_Avx512VBmiSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn Avx512VBmi2Support(&self) -> u64 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_Avx512VBmi2Support(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn Avx512VnniSupport(&self) -> u64 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_Avx512VnniSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn GfniSupport(&self) -> u64 {
+        (self._bitfield >> 19) & 1
+    }
+    pub fn set_GfniSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 19)) | ((value & 1) << 19);
+    }
+    pub const fn VaesSupport(&self) -> u64 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_VaesSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn Avx512VPopcntdqSupport(&self) -> u64 {
+        (self._bitfield >> 21) & 1
+    }
+    pub fn set_Avx512VPopcntdqSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 21)) | ((value & 1) << 21);
+    }
+    pub const fn VpclmulqdqSupport(&self) -> u64 {
+        (self._bitfield >> 22) & 1
+    }
+    pub fn set_VpclmulqdqSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 22)) | ((value & 1) << 22);
+    }
+    pub const fn Avx512Bf16Support(&self) -> u64 {
+        (self._bitfield >> 23) & 1
+    }
+    pub fn set_Avx512Bf16Support(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 23)) | ((value & 1) << 23);
+    }
+    pub const fn Avx512Vp2IntersectSupport(&self) -> u64 {
+        (self._bitfield >> 24) & 1
+    }
+    pub fn set_Avx512Vp2IntersectSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 24)) | ((value & 1) << 24);
+    }
+    pub const fn Avx512Fp16Support(&self) -> u64 {
+        (self._bitfield >> 25) & 1
+    }
+    pub fn set_Avx512Fp16Support(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 25)) | ((value & 1) << 25);
+    }
+    pub const fn XfdSupport(&self) -> u64 {
+        (self._bitfield >> 26) & 1
+    }
+    pub fn set_XfdSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 26)) | ((value & 1) << 26);
+    }
+    pub const fn AmxTileSupport(&self) -> u64 {
+        (self._bitfield >> 27) & 1
+    }
+    pub fn set_AmxTileSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 27)) | ((value & 1) << 27);
+    }
+    pub const fn AmxBf16Support(&self) -> u64 {
+        (self._bitfield >> 28) & 1
+    }
+    pub fn set_AmxBf16Support(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 28)) | ((value & 1) << 28);
+    }
+    pub const fn AmxInt8Support(&self) -> u64 {
+        (self._bitfield >> 29) & 1
+    }
+    pub fn set_AmxInt8Support(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 29)) | ((value & 1) << 29);
+    }
+    pub const fn AvxVnniSupport(&self) -> u64 {
+        (self._bitfield >> 30) & 1
+    }
+    pub fn set_AvxVnniSupport(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 30)) | ((value & 1) << 30);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 31) & 8589934591
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(8589934591 << 31)) | ((value & 8589934591) << 31);
+    }
 }
 impl windows_core::TypeKind for WHV_PROCESSOR_XSAVE_FEATURES_0 {
     type TypeKind = windows_core::CopyType;
@@ -3600,7 +4920,8 @@ impl Default for WHV_REGISTER_VALUE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_RUN_VP_CANCELED_CONTEXT {
     pub CancelReason: WHV_RUN_VP_CANCEL_REASON,
 }
@@ -3670,10 +4991,49 @@ impl Default for WHV_SCHEDULER_FEATURES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_SCHEDULER_FEATURES_0 {
     pub _bitfield: u64,
 }
+impl WHV_SCHEDULER_FEATURES_0 {
+    pub const fn CpuReserve(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_CpuReserve(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn CpuCap(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_CpuCap(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn CpuWeight(&self) -> u64 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_CpuWeight(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn CpuGroupId(&self) -> u64 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_CpuGroupId(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn DisableSmt(&self) -> u64 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_DisableSmt(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 5) & 576460752303423487
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(576460752303423487 << 5)) | ((value & 576460752303423487) << 5);
+    }
+}
 impl windows_core::TypeKind for WHV_SCHEDULER_FEATURES_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3683,7 +5043,8 @@ impl Default for WHV_SCHEDULER_FEATURES_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_SRIOV_RESOURCE_DESCRIPTOR {
     pub PnpInstanceId: [u16; 200],
     pub VirtualFunctionId: super::super::Foundation::LUID,
@@ -3699,7 +5060,8 @@ impl Default for WHV_SRIOV_RESOURCE_DESCRIPTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_SYNIC_EVENT_PARAMETERS {
     pub VpIndex: u32,
     pub TargetSint: u8,
@@ -3715,7 +5077,8 @@ impl Default for WHV_SYNIC_EVENT_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_SYNIC_SINT_DELIVERABLE_CONTEXT {
     pub DeliverableSints: u16,
     pub Reserved1: u16,
@@ -3744,10 +5107,205 @@ impl Default for WHV_SYNTHETIC_PROCESSOR_FEATURES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_SYNTHETIC_PROCESSOR_FEATURES_0 {
     pub _bitfield: u64,
 }
+impl WHV_SYNTHETIC_PROCESSOR_FEATURES_0 {
+    pub const fn HypervisorPresent(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_HypervisorPresent(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Hv1(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Hv1(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn AccessVpRunTimeReg(&self) -> u64 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_AccessVpRunTimeReg(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn AccessPartitionReferenceCounter(&self) -> u64 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_AccessPartitionReferenceCounter(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn AccessSynicRegs(&self) -> u64 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_AccessSynicRegs(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn AccessSyntheticTimerRegs(&self) -> u64 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_AccessSyntheticTimerRegs(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn ReservedZ6(&self) -> u64 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_ReservedZ6(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn AccessHypercallRegs(&self) -> u64 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_AccessHypercallRegs(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn AccessVpIndex(&self) -> u64 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_AccessVpIndex(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn AccessPartitionReferenceTsc(&self) -> u64 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_AccessPartitionReferenceTsc(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn ReservedZ10(&self) -> u64 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_ReservedZ10(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn ReservedZ11(&self) -> u64 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_ReservedZ11(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn ReservedZ12(&self) -> u64 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_ReservedZ12(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn ReservedZ13(&self) -> u64 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_ReservedZ13(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn ReservedZ14(&self) -> u64 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_ReservedZ14(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn ReservedZ15(&self) -> u64 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_ReservedZ15(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+    pub const fn ReservedZ16(&self) -> u64 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_ReservedZ16(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn ReservedZ17(&self) -> u64 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_ReservedZ17(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn FastHypercallOutput(&self) -> u64 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_FastHypercallOutput(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn ReservedZ19(&self) -> u64 {
+        (self._bitfield >> 19) & 1
+    }
+    pub fn set_ReservedZ19(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 19)) | ((value & 1) << 19);
+    }
+    pub const fn ReservedZ20(&self) -> u64 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_ReservedZ20(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn ReservedZ21(&self) -> u64 {
+        (self._bitfield >> 21) & 1
+    }
+    pub fn set_ReservedZ21(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 21)) | ((value & 1) << 21);
+    }
+    pub const fn DirectSyntheticTimers(&self) -> u64 {
+        (self._bitfield >> 22) & 1
+    }
+    pub fn set_DirectSyntheticTimers(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 22)) | ((value & 1) << 22);
+    }
+    pub const fn ReservedZ23(&self) -> u64 {
+        (self._bitfield >> 23) & 1
+    }
+    pub fn set_ReservedZ23(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 23)) | ((value & 1) << 23);
+    }
+    pub const fn ExtendedProcessorMasks(&self) -> u64 {
+        (self._bitfield >> 24) & 1
+    }
+    pub fn set_ExtendedProcessorMasks(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 24)) | ((value & 1) << 24);
+    }
+    pub const fn ReservedZ25(&self) -> u64 {
+        (self._bitfield >> 25) & 1
+    }
+    pub fn set_ReservedZ25(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 25)) | ((value & 1) << 25);
+    }
+    pub const fn SyntheticClusterIpi(&self) -> u64 {
+        (self._bitfield >> 26) & 1
+    }
+    pub fn set_SyntheticClusterIpi(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 26)) | ((value & 1) << 26);
+    }
+    pub const fn NotifyLongSpinWait(&self) -> u64 {
+        (self._bitfield >> 27) & 1
+    }
+    pub fn set_NotifyLongSpinWait(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 27)) | ((value & 1) << 27);
+    }
+    pub const fn QueryNumaDistance(&self) -> u64 {
+        (self._bitfield >> 28) & 1
+    }
+    pub fn set_QueryNumaDistance(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 28)) | ((value & 1) << 28);
+    }
+    pub const fn SignalEvents(&self) -> u64 {
+        (self._bitfield >> 29) & 1
+    }
+    pub fn set_SignalEvents(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 29)) | ((value & 1) << 29);
+    }
+    pub const fn RetargetDeviceInterrupt(&self) -> u64 {
+        (self._bitfield >> 30) & 1
+    }
+    pub fn set_RetargetDeviceInterrupt(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 30)) | ((value & 1) << 30);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 31) & 8589934591
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(8589934591 << 31)) | ((value & 8589934591) << 31);
+    }
+}
 impl windows_core::TypeKind for WHV_SYNTHETIC_PROCESSOR_FEATURES_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3799,7 +5357,8 @@ impl Default for WHV_SYNTHETIC_PROCESSOR_FEATURES_BANKS_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_TRANSLATE_GVA_RESULT {
     pub ResultCode: WHV_TRANSLATE_GVA_RESULT_CODE,
     pub Reserved: u32,
@@ -3843,7 +5402,8 @@ impl Default for WHV_TRIGGER_PARAMETERS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_TRIGGER_PARAMETERS_0_0 {
     pub LogicalDeviceId: u64,
     pub MsiAddress: u64,
@@ -3873,7 +5433,8 @@ impl Default for WHV_UINT128 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_UINT128_0 {
     pub Low64: u64,
     pub High64: u64,
@@ -3944,7 +5505,8 @@ impl Default for WHV_VPCI_DEVICE_NOTIFICATION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_VPCI_DEVICE_REGISTER {
     pub Location: WHV_VPCI_DEVICE_REGISTER_SPACE,
     pub SizeInBytes: u32,
@@ -3959,7 +5521,8 @@ impl Default for WHV_VPCI_DEVICE_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_VPCI_HARDWARE_IDS {
     pub VendorID: u16,
     pub DeviceID: u16,
@@ -3979,7 +5542,8 @@ impl Default for WHV_VPCI_HARDWARE_IDS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_VPCI_INTERRUPT_TARGET {
     pub Vector: u32,
     pub Flags: WHV_VPCI_INTERRUPT_TARGET_FLAGS,
@@ -3995,7 +5559,8 @@ impl Default for WHV_VPCI_INTERRUPT_TARGET {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_VPCI_MMIO_MAPPING {
     pub Location: WHV_VPCI_DEVICE_REGISTER_SPACE,
     pub Flags: WHV_VPCI_MMIO_RANGE_FLAGS,
@@ -4012,7 +5577,8 @@ impl Default for WHV_VPCI_MMIO_MAPPING {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_VPCI_PROBED_BARS {
     pub Value: [u32; 6],
 }
@@ -4059,10 +5625,31 @@ impl Default for WHV_VP_EXCEPTION_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_VP_EXCEPTION_INFO_0 {
     pub _bitfield: u32,
 }
+impl WHV_VP_EXCEPTION_INFO_0 {
+    pub const fn ErrorCodeValid(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_ErrorCodeValid(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn SoftwareException(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_SoftwareException(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 2) & 1073741823
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1073741823 << 2)) | ((value & 1073741823) << 2);
+    }
+}
 impl windows_core::TypeKind for WHV_VP_EXCEPTION_INFO_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4082,6 +5669,20 @@ pub struct WHV_VP_EXIT_CONTEXT {
     pub Rip: u64,
     pub Rflags: u64,
 }
+impl WHV_VP_EXIT_CONTEXT {
+    pub const fn InstructionLength(&self) -> u8 {
+        self._bitfield & 15
+    }
+    pub fn set_InstructionLength(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn Cr8(&self) -> u8 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_Cr8(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+}
 impl windows_core::TypeKind for WHV_VP_EXIT_CONTEXT {
     type TypeKind = windows_core::CopyType;
 }
@@ -4091,7 +5692,8 @@ impl Default for WHV_VP_EXIT_CONTEXT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_X64_APIC_EOI_CONTEXT {
     pub InterruptVector: u32,
 }
@@ -4104,7 +5706,8 @@ impl Default for WHV_X64_APIC_EOI_CONTEXT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_X64_APIC_INIT_SIPI_CONTEXT {
     pub ApicIcr: u64,
 }
@@ -4117,7 +5720,8 @@ impl Default for WHV_X64_APIC_INIT_SIPI_CONTEXT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_X64_APIC_SMI_CONTEXT {
     pub ApicIcr: u64,
 }
@@ -4130,7 +5734,8 @@ impl Default for WHV_X64_APIC_SMI_CONTEXT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_X64_APIC_WRITE_CONTEXT {
     pub Type: WHV_X64_APIC_WRITE_TYPE,
     pub Reserved: u32,
@@ -4145,7 +5750,8 @@ impl Default for WHV_X64_APIC_WRITE_CONTEXT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_X64_CPUID_ACCESS_CONTEXT {
     pub Rax: u64,
     pub Rcx: u64,
@@ -4165,7 +5771,8 @@ impl Default for WHV_X64_CPUID_ACCESS_CONTEXT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_X64_CPUID_RESULT {
     pub Function: u32,
     pub Reserved: [u32; 3],
@@ -4183,7 +5790,8 @@ impl Default for WHV_X64_CPUID_RESULT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_X64_CPUID_RESULT2 {
     pub Function: u32,
     pub Index: u32,
@@ -4215,10 +5823,43 @@ impl Default for WHV_X64_DELIVERABILITY_NOTIFICATIONS_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_X64_DELIVERABILITY_NOTIFICATIONS_REGISTER_0 {
     pub _bitfield: u64,
 }
+impl WHV_X64_DELIVERABILITY_NOTIFICATIONS_REGISTER_0 {
+    pub const fn NmiNotification(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_NmiNotification(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn InterruptNotification(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_InterruptNotification(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn InterruptPriority(&self) -> u64 {
+        (self._bitfield >> 2) & 15
+    }
+    pub fn set_InterruptPriority(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(15 << 2)) | ((value & 15) << 2);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 6) & 4398046511103
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(4398046511103 << 6)) | ((value & 4398046511103) << 6);
+    }
+    pub const fn Sint(&self) -> u64 {
+        (self._bitfield >> 48) & 65535
+    }
+    pub fn set_Sint(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(65535 << 48)) | ((value & 65535) << 48);
+    }
+}
 impl windows_core::TypeKind for WHV_X64_DELIVERABILITY_NOTIFICATIONS_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4274,7 +5915,8 @@ impl Default for WHV_X64_FP_CONTROL_STATUS_REGISTER_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_X64_FP_CONTROL_STATUS_REGISTER_0_0_0 {
     pub LastFpEip: u32,
     pub LastFpCs: u16,
@@ -4303,11 +5945,32 @@ impl Default for WHV_X64_FP_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_X64_FP_REGISTER_0 {
     pub Mantissa: u64,
     pub _bitfield: u64,
 }
+impl WHV_X64_FP_REGISTER_0 {
+    pub const fn BiasedExponent(&self) -> u64 {
+        self._bitfield & 32767
+    }
+    pub fn set_BiasedExponent(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !32767) | (value & 32767);
+    }
+    pub const fn Sign(&self) -> u64 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_Sign(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 16) & 281474976710655
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(281474976710655 << 16)) | ((value & 281474976710655) << 16);
+    }
+}
 impl windows_core::TypeKind for WHV_X64_FP_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4317,7 +5980,8 @@ impl Default for WHV_X64_FP_REGISTER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_X64_INTERRUPTION_DELIVERABLE_CONTEXT {
     pub DeliverableType: WHV_X64_PENDING_INTERRUPTION_TYPE,
 }
@@ -4344,10 +6008,31 @@ impl Default for WHV_X64_INTERRUPT_STATE_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_X64_INTERRUPT_STATE_REGISTER_0 {
     pub _bitfield: u64,
 }
+impl WHV_X64_INTERRUPT_STATE_REGISTER_0 {
+    pub const fn InterruptShadow(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_InterruptShadow(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn NmiMasked(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_NmiMasked(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 2) & 4611686018427387903
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(4611686018427387903 << 2)) | ((value & 4611686018427387903) << 2);
+    }
+}
 impl windows_core::TypeKind for WHV_X64_INTERRUPT_STATE_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4395,10 +6080,43 @@ impl Default for WHV_X64_IO_PORT_ACCESS_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_X64_IO_PORT_ACCESS_INFO_0 {
     pub _bitfield: u32,
 }
+impl WHV_X64_IO_PORT_ACCESS_INFO_0 {
+    pub const fn IsWrite(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_IsWrite(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn AccessSize(&self) -> u32 {
+        (self._bitfield >> 1) & 7
+    }
+    pub fn set_AccessSize(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 1)) | ((value & 7) << 1);
+    }
+    pub const fn StringOp(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_StringOp(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn RepPrefix(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_RepPrefix(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 6) & 67108863
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(67108863 << 6)) | ((value & 67108863) << 6);
+    }
+}
 impl windows_core::TypeKind for WHV_X64_IO_PORT_ACCESS_INFO_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4438,10 +6156,25 @@ impl Default for WHV_X64_MSR_ACCESS_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_X64_MSR_ACCESS_INFO_0 {
     pub _bitfield: u32,
 }
+impl WHV_X64_MSR_ACCESS_INFO_0 {
+    pub const fn IsWrite(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_IsWrite(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for WHV_X64_MSR_ACCESS_INFO_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4465,10 +6198,55 @@ impl Default for WHV_X64_MSR_EXIT_BITMAP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_X64_MSR_EXIT_BITMAP_0 {
     pub _bitfield: u64,
 }
+impl WHV_X64_MSR_EXIT_BITMAP_0 {
+    pub const fn UnhandledMsrs(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_UnhandledMsrs(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn TscMsrWrite(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_TscMsrWrite(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn TscMsrRead(&self) -> u64 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_TscMsrRead(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn ApicBaseMsrWrite(&self) -> u64 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_ApicBaseMsrWrite(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn MiscEnableMsrRead(&self) -> u64 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_MiscEnableMsrRead(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn McUpdatePatchLevelMsrRead(&self) -> u64 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_McUpdatePatchLevelMsrRead(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 6) & 288230376151711743
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(288230376151711743 << 6)) | ((value & 288230376151711743) << 6);
+    }
+}
 impl windows_core::TypeKind for WHV_X64_MSR_EXIT_BITMAP_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4492,10 +6270,49 @@ impl Default for WHV_X64_PENDING_DEBUG_EXCEPTION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_X64_PENDING_DEBUG_EXCEPTION_0 {
     pub _bitfield: u64,
 }
+impl WHV_X64_PENDING_DEBUG_EXCEPTION_0 {
+    pub const fn Breakpoint0(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_Breakpoint0(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Breakpoint1(&self) -> u64 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Breakpoint1(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Breakpoint2(&self) -> u64 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Breakpoint2(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Breakpoint3(&self) -> u64 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_Breakpoint3(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn SingleStep(&self) -> u64 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_SingleStep(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved0(&self) -> u64 {
+        (self._bitfield >> 5) & 576460752303423487
+    }
+    pub fn set_Reserved0(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(576460752303423487 << 5)) | ((value & 576460752303423487) << 5);
+    }
+}
 impl windows_core::TypeKind for WHV_X64_PENDING_DEBUG_EXCEPTION_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4519,12 +6336,51 @@ impl Default for WHV_X64_PENDING_EXCEPTION_EVENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_X64_PENDING_EXCEPTION_EVENT_0 {
     pub _bitfield: u32,
     pub ErrorCode: u32,
     pub ExceptionParameter: u64,
 }
+impl WHV_X64_PENDING_EXCEPTION_EVENT_0 {
+    pub const fn EventPending(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_EventPending(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn EventType(&self) -> u32 {
+        (self._bitfield >> 1) & 7
+    }
+    pub fn set_EventType(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 1)) | ((value & 7) << 1);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+    pub const fn DeliverErrorCode(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_DeliverErrorCode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 9) & 127
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(127 << 9)) | ((value & 127) << 9);
+    }
+    pub const fn Vector(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_Vector(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for WHV_X64_PENDING_EXCEPTION_EVENT_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4548,11 +6404,44 @@ impl Default for WHV_X64_PENDING_EXT_INT_EVENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_X64_PENDING_EXT_INT_EVENT_0 {
     pub _bitfield: u64,
     pub Reserved2: u64,
 }
+impl WHV_X64_PENDING_EXT_INT_EVENT_0 {
+    pub const fn EventPending(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_EventPending(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn EventType(&self) -> u64 {
+        (self._bitfield >> 1) & 7
+    }
+    pub fn set_EventType(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(7 << 1)) | ((value & 7) << 1);
+    }
+    pub const fn Reserved0(&self) -> u64 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_Reserved0(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+    pub const fn Vector(&self) -> u64 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_Vector(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn Reserved1(&self) -> u64 {
+        (self._bitfield >> 16) & 281474976710655
+    }
+    pub fn set_Reserved1(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(281474976710655 << 16)) | ((value & 281474976710655) << 16);
+    }
+}
 impl windows_core::TypeKind for WHV_X64_PENDING_EXT_INT_EVENT_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4576,11 +6465,56 @@ impl Default for WHV_X64_PENDING_INTERRUPTION_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_X64_PENDING_INTERRUPTION_REGISTER_0 {
     pub _bitfield: u32,
     pub ErrorCode: u32,
 }
+impl WHV_X64_PENDING_INTERRUPTION_REGISTER_0 {
+    pub const fn InterruptionPending(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_InterruptionPending(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn InterruptionType(&self) -> u32 {
+        (self._bitfield >> 1) & 7
+    }
+    pub fn set_InterruptionType(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 1)) | ((value & 7) << 1);
+    }
+    pub const fn DeliverErrorCode(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_DeliverErrorCode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn InstructionLength(&self) -> u32 {
+        (self._bitfield >> 5) & 15
+    }
+    pub fn set_InstructionLength(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 5)) | ((value & 15) << 5);
+    }
+    pub const fn NestedEvent(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_NestedEvent(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 10) & 63
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(63 << 10)) | ((value & 63) << 10);
+    }
+    pub const fn InterruptionVector(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_InterruptionVector(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for WHV_X64_PENDING_INTERRUPTION_REGISTER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4621,10 +6555,25 @@ impl Default for WHV_X64_RDTSC_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_X64_RDTSC_INFO_0 {
     pub _bitfield: u64,
 }
+impl WHV_X64_RDTSC_INFO_0 {
+    pub const fn IsRdtscp(&self) -> u64 {
+        self._bitfield & 1
+    }
+    pub fn set_IsRdtscp(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 1) & 9223372036854775807
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(9223372036854775807 << 1)) | ((value & 9223372036854775807) << 1);
+    }
+}
 impl windows_core::TypeKind for WHV_X64_RDTSC_INFO_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4664,10 +6613,67 @@ impl Default for WHV_X64_SEGMENT_REGISTER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_X64_SEGMENT_REGISTER_0_0 {
     pub _bitfield: u16,
 }
+impl WHV_X64_SEGMENT_REGISTER_0_0 {
+    pub const fn SegmentType(&self) -> u16 {
+        self._bitfield & 15
+    }
+    pub fn set_SegmentType(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn NonSystemSegment(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_NonSystemSegment(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn DescriptorPrivilegeLevel(&self) -> u16 {
+        (self._bitfield >> 5) & 3
+    }
+    pub fn set_DescriptorPrivilegeLevel(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 5)) | ((value & 3) << 5);
+    }
+    pub const fn Present(&self) -> u16 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Present(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 8) & 15
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(15 << 8)) | ((value & 15) << 8);
+    }
+    pub const fn Available(&self) -> u16 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_Available(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn Long(&self) -> u16 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_Long(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn Default(&self) -> u16 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_Default(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn Granularity(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_Granularity(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for WHV_X64_SEGMENT_REGISTER_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4677,7 +6683,8 @@ impl Default for WHV_X64_SEGMENT_REGISTER_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_X64_TABLE_REGISTER {
     pub Pad: [u16; 3],
     pub Limit: u16,
@@ -4692,7 +6699,8 @@ impl Default for WHV_X64_TABLE_REGISTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_X64_UNSUPPORTED_FEATURE_CONTEXT {
     pub FeatureCode: WHV_X64_UNSUPPORTED_FEATURE_CODE,
     pub Reserved: u32,
@@ -4721,10 +6729,67 @@ impl Default for WHV_X64_VP_EXECUTION_STATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_X64_VP_EXECUTION_STATE_0 {
     pub _bitfield: u16,
 }
+impl WHV_X64_VP_EXECUTION_STATE_0 {
+    pub const fn Cpl(&self) -> u16 {
+        self._bitfield & 3
+    }
+    pub fn set_Cpl(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn Cr0Pe(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Cr0Pe(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Cr0Am(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_Cr0Am(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn EferLma(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_EferLma(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn DebugActive(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_DebugActive(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn InterruptionPending(&self) -> u16 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_InterruptionPending(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Reserved0(&self) -> u16 {
+        (self._bitfield >> 7) & 31
+    }
+    pub fn set_Reserved0(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(31 << 7)) | ((value & 31) << 7);
+    }
+    pub const fn InterruptShadow(&self) -> u16 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_InterruptShadow(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn Reserved1(&self) -> u16 {
+        (self._bitfield >> 13) & 7
+    }
+    pub fn set_Reserved1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(7 << 13)) | ((value & 7) << 13);
+    }
+}
 impl windows_core::TypeKind for WHV_X64_VP_EXECUTION_STATE_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4777,7 +6842,8 @@ impl Default for WHV_X64_XMM_CONTROL_STATUS_REGISTER_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WHV_X64_XMM_CONTROL_STATUS_REGISTER_0_0_0 {
     pub LastFpDp: u32,
     pub LastFpDs: u16,
@@ -0,0 +1,463 @@
+impl windows_core::imp::ProxyVtable for AsyncIFtpAuthenticationProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Begin_AuthenticateUser(this: *mut core::ffi::c_void, pszsessionid: windows_core::PCWSTR, pszsitename: windows_core::PCWSTR, pszusername: windows_core::PCWSTR, pszpassword: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIFtpAuthenticationProvider", "Begin_AuthenticateUser", 3usize, |this| ((**(this as *const *const AsyncIFtpAuthenticationProvider_Vtbl)).Begin_AuthenticateUser)(this, pszsessionid, pszsitename, pszusername, pszpassword), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_AuthenticateUser(this: *mut core::ffi::c_void, ppszcanonicalusername: *mut windows_core::PWSTR, pfauthenticated: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIFtpAuthenticationProvider", "Finish_AuthenticateUser", 4usize, |this| ((**(this as *const *const AsyncIFtpAuthenticationProvider_Vtbl)).Finish_AuthenticateUser)(this, ppszcanonicalusername, pfauthenticated), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Begin_AuthenticateUser, Finish_AuthenticateUser }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<AsyncIFtpAuthenticationProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for AsyncIFtpAuthorizationProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Begin_GetUserAccessPermission(this: *mut core::ffi::c_void, pszsessionid: windows_core::PCWSTR, pszsitename: windows_core::PCWSTR, pszvirtualpath: windows_core::PCWSTR, pszusername: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIFtpAuthorizationProvider", "Begin_GetUserAccessPermission", 3usize, |this| ((**(this as *const *const AsyncIFtpAuthorizationProvider_Vtbl)).Begin_GetUserAccessPermission)(this, pszsessionid, pszsitename, pszvirtualpath, pszusername), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_GetUserAccessPermission(this: *mut core::ffi::c_void, pftpaccess: *mut FTP_ACCESS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIFtpAuthorizationProvider", "Finish_GetUserAccessPermission", 4usize, |this| ((**(this as *const *const AsyncIFtpAuthorizationProvider_Vtbl)).Finish_GetUserAccessPermission)(this, pftpaccess), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Begin_GetUserAccessPermission, Finish_GetUserAccessPermission }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<AsyncIFtpAuthorizationProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for AsyncIFtpHomeDirectoryProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Begin_GetUserHomeDirectoryData(this: *mut core::ffi::c_void, pszsessionid: windows_core::PCWSTR, pszsitename: windows_core::PCWSTR, pszusername: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIFtpHomeDirectoryProvider", "Begin_GetUserHomeDirectoryData", 3usize, |this| ((**(this as *const *const AsyncIFtpHomeDirectoryProvider_Vtbl)).Begin_GetUserHomeDirectoryData)(this, pszsessionid, pszsitename, pszusername), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_GetUserHomeDirectoryData(this: *mut core::ffi::c_void, ppszhomedirectorydata: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIFtpHomeDirectoryProvider", "Finish_GetUserHomeDirectoryData", 4usize, |this| ((**(this as *const *const AsyncIFtpHomeDirectoryProvider_Vtbl)).Finish_GetUserHomeDirectoryData)(this, ppszhomedirectorydata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Begin_GetUserHomeDirectoryData, Finish_GetUserHomeDirectoryData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<AsyncIFtpHomeDirectoryProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for AsyncIFtpLogProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Begin_Log(this: *mut core::ffi::c_void, ploggingparameters: *const LOGGING_PARAMETERS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIFtpLogProvider", "Begin_Log", 3usize, |this| ((**(this as *const *const AsyncIFtpLogProvider_Vtbl)).Begin_Log)(this, ploggingparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_Log(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIFtpLogProvider", "Finish_Log", 4usize, |this| ((**(this as *const *const AsyncIFtpLogProvider_Vtbl)).Finish_Log)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Begin_Log, Finish_Log }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<AsyncIFtpLogProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for AsyncIFtpPostprocessProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Begin_HandlePostprocess(this: *mut core::ffi::c_void, ppostprocessparameters: *const POST_PROCESS_PARAMETERS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIFtpPostprocessProvider", "Begin_HandlePostprocess", 3usize, |this| ((**(this as *const *const AsyncIFtpPostprocessProvider_Vtbl)).Begin_HandlePostprocess)(this, ppostprocessparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_HandlePostprocess(this: *mut core::ffi::c_void, pftpprocessstatus: *mut FTP_PROCESS_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIFtpPostprocessProvider", "Finish_HandlePostprocess", 4usize, |this| ((**(this as *const *const AsyncIFtpPostprocessProvider_Vtbl)).Finish_HandlePostprocess)(this, pftpprocessstatus), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Begin_HandlePostprocess, Finish_HandlePostprocess }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<AsyncIFtpPostprocessProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for AsyncIFtpPreprocessProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Begin_HandlePreprocess(this: *mut core::ffi::c_void, ppreprocessparameters: *const PRE_PROCESS_PARAMETERS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIFtpPreprocessProvider", "Begin_HandlePreprocess", 3usize, |this| ((**(this as *const *const AsyncIFtpPreprocessProvider_Vtbl)).Begin_HandlePreprocess)(this, ppreprocessparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_HandlePreprocess(this: *mut core::ffi::c_void, pftpprocessstatus: *mut FTP_PROCESS_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIFtpPreprocessProvider", "Finish_HandlePreprocess", 4usize, |this| ((**(this as *const *const AsyncIFtpPreprocessProvider_Vtbl)).Finish_HandlePreprocess)(this, pftpprocessstatus), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Begin_HandlePreprocess, Finish_HandlePreprocess }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<AsyncIFtpPreprocessProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for AsyncIFtpRoleProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Begin_IsUserInRole(this: *mut core::ffi::c_void, pszsessionid: windows_core::PCWSTR, pszsitename: windows_core::PCWSTR, pszusername: windows_core::PCWSTR, pszrole: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIFtpRoleProvider", "Begin_IsUserInRole", 3usize, |this| ((**(this as *const *const AsyncIFtpRoleProvider_Vtbl)).Begin_IsUserInRole)(this, pszsessionid, pszsitename, pszusername, pszrole), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_IsUserInRole(this: *mut core::ffi::c_void, pfisinrole: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIFtpRoleProvider", "Finish_IsUserInRole", 4usize, |this| ((**(this as *const *const AsyncIFtpRoleProvider_Vtbl)).Finish_IsUserInRole)(this, pfisinrole), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Begin_IsUserInRole, Finish_IsUserInRole }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<AsyncIFtpRoleProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for AsyncIMSAdminBaseSinkW_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Begin_SinkNotify(this: *mut core::ffi::c_void, dwmdnumelements: u32, pcochangelist: *const MD_CHANGE_OBJECT_W) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIMSAdminBaseSinkW", "Begin_SinkNotify", 3usize, |this| ((**(this as *const *const AsyncIMSAdminBaseSinkW_Vtbl)).Begin_SinkNotify)(this, dwmdnumelements, pcochangelist), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_SinkNotify(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIMSAdminBaseSinkW", "Finish_SinkNotify", 4usize, |this| ((**(this as *const *const AsyncIMSAdminBaseSinkW_Vtbl)).Finish_SinkNotify)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Begin_ShutdownNotify(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIMSAdminBaseSinkW", "Begin_ShutdownNotify", 5usize, |this| ((**(this as *const *const AsyncIMSAdminBaseSinkW_Vtbl)).Begin_ShutdownNotify)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish_ShutdownNotify(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "AsyncIMSAdminBaseSinkW", "Finish_ShutdownNotify", 6usize, |this| ((**(this as *const *const AsyncIMSAdminBaseSinkW_Vtbl)).Finish_ShutdownNotify)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Begin_SinkNotify,
+            Finish_SinkNotify,
+            Begin_ShutdownNotify,
+            Finish_ShutdownNotify,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<AsyncIMSAdminBaseSinkW as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IADMEXT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADMEXT", "Initialize", 3usize, |this| ((**(this as *const *const IADMEXT_Vtbl)).Initialize)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumDcomCLSIDs(this: *mut core::ffi::c_void, pclsiddcom: *mut windows_core::GUID, dwenumindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADMEXT", "EnumDcomCLSIDs", 4usize, |this| ((**(this as *const *const IADMEXT_Vtbl)).EnumDcomCLSIDs)(this, pclsiddcom, dwenumindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn Terminate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADMEXT", "Terminate", 5usize, |this| ((**(this as *const *const IADMEXT_Vtbl)).Terminate)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, EnumDcomCLSIDs, Terminate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADMEXT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFtpAuthenticationProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AuthenticateUser(this: *mut core::ffi::c_void, pszsessionid: windows_core::PCWSTR, pszsitename: windows_core::PCWSTR, pszusername: windows_core::PCWSTR, pszpassword: windows_core::PCWSTR, ppszcanonicalusername: *mut windows_core::PWSTR, pfauthenticated: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFtpAuthenticationProvider", "AuthenticateUser", 3usize, |this| ((**(this as *const *const IFtpAuthenticationProvider_Vtbl)).AuthenticateUser)(this, pszsessionid, pszsitename, pszusername, pszpassword, ppszcanonicalusername, pfauthenticated), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AuthenticateUser }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFtpAuthenticationProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFtpAuthorizationProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetUserAccessPermission(this: *mut core::ffi::c_void, pszsessionid: windows_core::PCWSTR, pszsitename: windows_core::PCWSTR, pszvirtualpath: windows_core::PCWSTR, pszusername: windows_core::PCWSTR, pftpaccess: *mut FTP_ACCESS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFtpAuthorizationProvider", "GetUserAccessPermission", 3usize, |this| ((**(this as *const *const IFtpAuthorizationProvider_Vtbl)).GetUserAccessPermission)(this, pszsessionid, pszsitename, pszvirtualpath, pszusername, pftpaccess), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetUserAccessPermission }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFtpAuthorizationProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFtpHomeDirectoryProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetUserHomeDirectoryData(this: *mut core::ffi::c_void, pszsessionid: windows_core::PCWSTR, pszsitename: windows_core::PCWSTR, pszusername: windows_core::PCWSTR, ppszhomedirectorydata: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFtpHomeDirectoryProvider", "GetUserHomeDirectoryData", 3usize, |this| ((**(this as *const *const IFtpHomeDirectoryProvider_Vtbl)).GetUserHomeDirectoryData)(this, pszsessionid, pszsitename, pszusername, ppszhomedirectorydata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetUserHomeDirectoryData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFtpHomeDirectoryProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFtpLogProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Log(this: *mut core::ffi::c_void, ploggingparameters: *const LOGGING_PARAMETERS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFtpLogProvider", "Log", 3usize, |this| ((**(this as *const *const IFtpLogProvider_Vtbl)).Log)(this, ploggingparameters), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Log }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFtpLogProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFtpPostprocessProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HandlePostprocess(this: *mut core::ffi::c_void, ppostprocessparameters: *const POST_PROCESS_PARAMETERS, pftpprocessstatus: *mut FTP_PROCESS_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFtpPostprocessProvider", "HandlePostprocess", 3usize, |this| ((**(this as *const *const IFtpPostprocessProvider_Vtbl)).HandlePostprocess)(this, ppostprocessparameters, pftpprocessstatus), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HandlePostprocess }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFtpPostprocessProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFtpPreprocessProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HandlePreprocess(this: *mut core::ffi::c_void, ppreprocessparameters: *const PRE_PROCESS_PARAMETERS, pftpprocessstatus: *mut FTP_PROCESS_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFtpPreprocessProvider", "HandlePreprocess", 3usize, |this| ((**(this as *const *const IFtpPreprocessProvider_Vtbl)).HandlePreprocess)(this, ppreprocessparameters, pftpprocessstatus), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HandlePreprocess }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFtpPreprocessProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFtpProviderConstruct_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Construct(this: *mut core::ffi::c_void, configurationentries: *const super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFtpProviderConstruct", "Construct", 3usize, |this| ((**(this as *const *const IFtpProviderConstruct_Vtbl)).Construct)(this, configurationentries), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Construct,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Construct: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFtpProviderConstruct as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFtpRoleProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsUserInRole(this: *mut core::ffi::c_void, pszsessionid: windows_core::PCWSTR, pszsitename: windows_core::PCWSTR, pszusername: windows_core::PCWSTR, pszrole: windows_core::PCWSTR, pfisinrole: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFtpRoleProvider", "IsUserInRole", 3usize, |this| ((**(this as *const *const IFtpRoleProvider_Vtbl)).IsUserInRole)(this, pszsessionid, pszsitename, pszusername, pszrole, pfisinrole), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsUserInRole }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFtpRoleProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMSAdminBase2W_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BackupWithPasswd(this: *mut core::ffi::c_void, pszmdbackuplocation: windows_core::PCWSTR, dwmdversion: u32, dwmdflags: u32, pszpasswd: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBase2W", "BackupWithPasswd", 34usize, |this| ((**(this as *const *const IMSAdminBase2W_Vtbl)).BackupWithPasswd)(this, pszmdbackuplocation, dwmdversion, dwmdflags, pszpasswd), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestoreWithPasswd(this: *mut core::ffi::c_void, pszmdbackuplocation: windows_core::PCWSTR, dwmdversion: u32, dwmdflags: u32, pszpasswd: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBase2W", "RestoreWithPasswd", 35usize, |this| ((**(this as *const *const IMSAdminBase2W_Vtbl)).RestoreWithPasswd)(this, pszmdbackuplocation, dwmdversion, dwmdflags, pszpasswd), |result| Some(*result))
+        }
+        unsafe extern "system" fn Export(this: *mut core::ffi::c_void, pszpasswd: windows_core::PCWSTR, pszfilename: windows_core::PCWSTR, pszsourcepath: windows_core::PCWSTR, dwmdflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBase2W", "Export", 36usize, |this| ((**(this as *const *const IMSAdminBase2W_Vtbl)).Export)(this, pszpasswd, pszfilename, pszsourcepath, dwmdflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Import(this: *mut core::ffi::c_void, pszpasswd: windows_core::PCWSTR, pszfilename: windows_core::PCWSTR, pszsourcepath: windows_core::PCWSTR, pszdestpath: windows_core::PCWSTR, dwmdflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBase2W", "Import", 37usize, |this| ((**(this as *const *const IMSAdminBase2W_Vtbl)).Import)(this, pszpasswd, pszfilename, pszsourcepath, pszdestpath, dwmdflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestoreHistory(this: *mut core::ffi::c_void, pszmdhistorylocation: windows_core::PCWSTR, dwmdmajorversion: u32, dwmdminorversion: u32, dwmdflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBase2W", "RestoreHistory", 38usize, |this| ((**(this as *const *const IMSAdminBase2W_Vtbl)).RestoreHistory)(this, pszmdhistorylocation, dwmdmajorversion, dwmdminorversion, dwmdflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumHistory(this: *mut core::ffi::c_void, pszmdhistorylocation: windows_core::PWSTR, pdwmdmajorversion: *mut u32, pdwmdminorversion: *mut u32, pftmdhistorytime: *mut super::super::Foundation::FILETIME, dwmdenumindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBase2W", "EnumHistory", 39usize, |this| ((**(this as *const *const IMSAdminBase2W_Vtbl)).EnumHistory)(this, pszmdhistorylocation, pdwmdmajorversion, pdwmdminorversion, pftmdhistorytime, dwmdenumindex), |result| Some(*result))
+        }
+        Self {
+            base__: <IMSAdminBaseW_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BackupWithPasswd,
+            RestoreWithPasswd,
+            Export,
+            Import,
+            RestoreHistory,
+            EnumHistory,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSAdminBase2W as windows_core::Interface>::IID || <IMSAdminBaseW_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMSAdminBase3W_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetChildPaths(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR, cchmdbuffersize: u32, pszbuffer: windows_core::PWSTR, pcchmdrequiredbuffersize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBase3W", "GetChildPaths", 40usize, |this| ((**(this as *const *const IMSAdminBase3W_Vtbl)).GetChildPaths)(this, hmdhandle, pszmdpath, cchmdbuffersize, pszbuffer, pcchmdrequiredbuffersize), |result| Some(*result))
+        }
+        Self { base__: <IMSAdminBase2W_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetChildPaths }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSAdminBase3W as windows_core::Interface>::IID || <IMSAdminBase2W_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMSAdminBaseSinkW_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SinkNotify(this: *mut core::ffi::c_void, dwmdnumelements: u32, pcochangelist: *const MD_CHANGE_OBJECT_W) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseSinkW", "SinkNotify", 3usize, |this| ((**(this as *const *const IMSAdminBaseSinkW_Vtbl)).SinkNotify)(this, dwmdnumelements, pcochangelist), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShutdownNotify(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseSinkW", "ShutdownNotify", 4usize, |this| ((**(this as *const *const IMSAdminBaseSinkW_Vtbl)).ShutdownNotify)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SinkNotify, ShutdownNotify }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSAdminBaseSinkW as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMSAdminBaseW_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddKey(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "AddKey", 3usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).AddKey)(this, hmdhandle, pszmdpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteKey(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "DeleteKey", 4usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).DeleteKey)(this, hmdhandle, pszmdpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteChildKeys(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "DeleteChildKeys", 5usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).DeleteChildKeys)(this, hmdhandle, pszmdpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumKeys(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR, pszmdname: windows_core::PWSTR, dwmdenumobjectindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "EnumKeys", 6usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).EnumKeys)(this, hmdhandle, pszmdpath, pszmdname, dwmdenumobjectindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyKey(this: *mut core::ffi::c_void, hmdsourcehandle: u32, pszmdsourcepath: windows_core::PCWSTR, hmddesthandle: u32, pszmddestpath: windows_core::PCWSTR, bmdoverwriteflag: super::super::Foundation::BOOL, bmdcopyflag: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "CopyKey", 7usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).CopyKey)(this, hmdsourcehandle, pszmdsourcepath, hmddesthandle, pszmddestpath, bmdoverwriteflag, bmdcopyflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn RenameKey(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR, pszmdnewname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "RenameKey", 8usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).RenameKey)(this, hmdhandle, pszmdpath, pszmdnewname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR, pmdrmddata: *mut METADATA_RECORD) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "SetData", 9usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).SetData)(this, hmdhandle, pszmdpath, pmdrmddata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetData(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR, pmdrmddata: *mut METADATA_RECORD, pdwmdrequireddatalen: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "GetData", 10usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).GetData)(this, hmdhandle, pszmdpath, pmdrmddata, pdwmdrequireddatalen), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteData(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR, dwmdidentifier: u32, dwmddatatype: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "DeleteData", 11usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).DeleteData)(this, hmdhandle, pszmdpath, dwmdidentifier, dwmddatatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumData(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR, pmdrmddata: *mut METADATA_RECORD, dwmdenumdataindex: u32, pdwmdrequireddatalen: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "EnumData", 12usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).EnumData)(this, hmdhandle, pszmdpath, pmdrmddata, dwmdenumdataindex, pdwmdrequireddatalen), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllData(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR, dwmdattributes: u32, dwmdusertype: u32, dwmddatatype: u32, pdwmdnumdataentries: *mut u32, pdwmddatasetnumber: *mut u32, dwmdbuffersize: u32, pbmdbuffer: *mut u8, pdwmdrequiredbuffersize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "GetAllData", 13usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).GetAllData)(this, hmdhandle, pszmdpath, dwmdattributes, dwmdusertype, dwmddatatype, pdwmdnumdataentries, pdwmddatasetnumber, dwmdbuffersize, pbmdbuffer, pdwmdrequiredbuffersize), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAllData(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR, dwmdusertype: u32, dwmddatatype: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "DeleteAllData", 14usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).DeleteAllData)(this, hmdhandle, pszmdpath, dwmdusertype, dwmddatatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyData(this: *mut core::ffi::c_void, hmdsourcehandle: u32, pszmdsourcepath: windows_core::PCWSTR, hmddesthandle: u32, pszmddestpath: windows_core::PCWSTR, dwmdattributes: u32, dwmdusertype: u32, dwmddatatype: u32, bmdcopyflag: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "CopyData", 15usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).CopyData)(this, hmdsourcehandle, pszmdsourcepath, hmddesthandle, pszmddestpath, dwmdattributes, dwmdusertype, dwmddatatype, bmdcopyflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataPaths(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR, dwmdidentifier: u32, dwmddatatype: u32, dwmdbuffersize: u32, pszbuffer: windows_core::PWSTR, pdwmdrequiredbuffersize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "GetDataPaths", 16usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).GetDataPaths)(this, hmdhandle, pszmdpath, dwmdidentifier, dwmddatatype, dwmdbuffersize, pszbuffer, pdwmdrequiredbuffersize), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenKey(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR, dwmdaccessrequested: u32, dwmdtimeout: u32, phmdnewhandle: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "OpenKey", 17usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).OpenKey)(this, hmdhandle, pszmdpath, dwmdaccessrequested, dwmdtimeout, phmdnewhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseKey(this: *mut core::ffi::c_void, hmdhandle: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "CloseKey", 18usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).CloseKey)(this, hmdhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangePermissions(this: *mut core::ffi::c_void, hmdhandle: u32, dwmdtimeout: u32, dwmdaccessrequested: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "ChangePermissions", 19usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).ChangePermissions)(this, hmdhandle, dwmdtimeout, dwmdaccessrequested), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveData(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "SaveData", 20usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).SaveData)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHandleInfo(this: *mut core::ffi::c_void, hmdhandle: u32, pmdhiinfo: *mut METADATA_HANDLE_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "GetHandleInfo", 21usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).GetHandleInfo)(this, hmdhandle, pmdhiinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSystemChangeNumber(this: *mut core::ffi::c_void, pdwsystemchangenumber: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "GetSystemChangeNumber", 22usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).GetSystemChangeNumber)(this, pdwsystemchangenumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataSetNumber(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR, pdwmddatasetnumber: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "GetDataSetNumber", 23usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).GetDataSetNumber)(this, hmdhandle, pszmdpath, pdwmddatasetnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLastChangeTime(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR, pftmdlastchangetime: *const super::super::Foundation::FILETIME, blocaltime: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "SetLastChangeTime", 24usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).SetLastChangeTime)(this, hmdhandle, pszmdpath, pftmdlastchangetime, blocaltime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastChangeTime(this: *mut core::ffi::c_void, hmdhandle: u32, pszmdpath: windows_core::PCWSTR, pftmdlastchangetime: *mut super::super::Foundation::FILETIME, blocaltime: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "GetLastChangeTime", 25usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).GetLastChangeTime)(this, hmdhandle, pszmdpath, pftmdlastchangetime, blocaltime), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyExchangePhase1(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "KeyExchangePhase1", 26usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).KeyExchangePhase1)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyExchangePhase2(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "KeyExchangePhase2", 27usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).KeyExchangePhase2)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Backup(this: *mut core::ffi::c_void, pszmdbackuplocation: windows_core::PCWSTR, dwmdversion: u32, dwmdflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "Backup", 28usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).Backup)(this, pszmdbackuplocation, dwmdversion, dwmdflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Restore(this: *mut core::ffi::c_void, pszmdbackuplocation: windows_core::PCWSTR, dwmdversion: u32, dwmdflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "Restore", 29usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).Restore)(this, pszmdbackuplocation, dwmdversion, dwmdflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumBackups(this: *mut core::ffi::c_void, pszmdbackuplocation: windows_core::PWSTR, pdwmdversion: *mut u32, pftmdbackuptime: *mut super::super::Foundation::FILETIME, dwmdenumindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "EnumBackups", 30usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).EnumBackups)(this, pszmdbackuplocation, pdwmdversion, pftmdbackuptime, dwmdenumindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteBackup(this: *mut core::ffi::c_void, pszmdbackuplocation: windows_core::PCWSTR, dwmdversion: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "DeleteBackup", 31usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).DeleteBackup)(this, pszmdbackuplocation, dwmdversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnmarshalInterface(this: *mut core::ffi::c_void, piadmbwinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "UnmarshalInterface", 32usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).UnmarshalInterface)(this, piadmbwinterface), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServerGuid(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSAdminBaseW", "GetServerGuid", 33usize, |this| ((**(this as *const *const IMSAdminBaseW_Vtbl)).GetServerGuid)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddKey,
+            DeleteKey,
+            DeleteChildKeys,
+            EnumKeys,
+            CopyKey,
+            RenameKey,
+            SetData,
+            GetData,
+            DeleteData,
+            EnumData,
+            GetAllData,
+            DeleteAllData,
+            CopyData,
+            GetDataPaths,
+            OpenKey,
+            CloseKey,
+            ChangePermissions,
+            SaveData,
+            GetHandleInfo,
+            GetSystemChangeNumber,
+            GetDataSetNumber,
+            SetLastChangeTime,
+            GetLastChangeTime,
+            KeyExchangePhase1,
+            KeyExchangePhase2,
+            Backup,
+            Restore,
+            EnumBackups,
+            DeleteBackup,
+            UnmarshalInterface,
+            GetServerGuid,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSAdminBaseW as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMSImpExpHelpW_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumeratePathsInFile(this: *mut core::ffi::c_void, pszfilename: windows_core::PCWSTR, pszkeytype: windows_core::PCWSTR, dwmdbuffersize: u32, pszbuffer: windows_core::PWSTR, pdwmdrequiredbuffersize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSImpExpHelpW", "EnumeratePathsInFile", 3usize, |this| ((**(this as *const *const IMSImpExpHelpW_Vtbl)).EnumeratePathsInFile)(this, pszfilename, pszkeytype, dwmdbuffersize, pszbuffer, pdwmdrequiredbuffersize), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnumeratePathsInFile }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSImpExpHelpW as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -537,6 +537,7 @@ pub struct JsErrorCode(pub u32);
 impl windows_core::TypeKind for JsErrorCode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for JsErrorCode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("JsErrorCode").field(&self.0).finish()
@@ -548,6 +549,7 @@ pub struct JsMemoryEventType(pub i32);
 impl windows_core::TypeKind for JsMemoryEventType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for JsMemoryEventType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("JsMemoryEventType").field(&self.0).finish()
@@ -559,6 +561,7 @@ pub struct JsRuntimeAttributes(pub i32);
 impl windows_core::TypeKind for JsRuntimeAttributes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for JsRuntimeAttributes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("JsRuntimeAttributes").field(&self.0).finish()
@@ -570,6 +573,7 @@ pub struct JsRuntimeVersion(pub i32);
 impl windows_core::TypeKind for JsRuntimeVersion {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for JsRuntimeVersion {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("JsRuntimeVersion").field(&self.0).finish()
@@ -581,6 +585,7 @@ pub struct JsValueType(pub i32);
 impl windows_core::TypeKind for JsValueType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for JsValueType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("JsValueType").field(&self.0).finish()
@@ -95,6 +95,7 @@ pub struct COMPARTMENT_ID(pub i32);
 impl windows_core::TypeKind for COMPARTMENT_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMPARTMENT_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMPARTMENT_ID").field(&self.0).finish()
@@ -106,6 +107,7 @@ pub struct EVENT_TYPE(pub i32);
 impl windows_core::TypeKind for EVENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EVENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EVENT_TYPE").field(&self.0).finish()
@@ -117,6 +119,7 @@ pub struct EXCEPTION_DISPOSITION(pub i32);
 impl windows_core::TypeKind for EXCEPTION_DISPOSITION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EXCEPTION_DISPOSITION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EXCEPTION_DISPOSITION").field(&self.0).finish()
@@ -128,6 +131,7 @@ pub struct NT_PRODUCT_TYPE(pub i32);
 impl windows_core::TypeKind for NT_PRODUCT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NT_PRODUCT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NT_PRODUCT_TYPE").field(&self.0).finish()
@@ -139,6 +143,7 @@ pub struct SUITE_TYPE(pub i32);
 impl windows_core::TypeKind for SUITE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SUITE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SUITE_TYPE").field(&self.0).finish()
@@ -150,6 +155,7 @@ pub struct TIMER_TYPE(pub i32);
 impl windows_core::TypeKind for TIMER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TIMER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TIMER_TYPE").field(&self.0).finish()
@@ -161,13 +167,15 @@ pub struct WAIT_TYPE(pub i32);
 impl windows_core::TypeKind for WAIT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WAIT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WAIT_TYPE").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CSTRING {
     pub Length: u16,
     pub MaximumLength: u16,
@@ -183,7 +191,8 @@ impl Default for CSTRING {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Diagnostics_Debug")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EXCEPTION_REGISTRATION_RECORD {
     pub Next: *mut EXCEPTION_REGISTRATION_RECORD,
     pub Handler: EXCEPTION_ROUTINE,
@@ -200,7 +209,8 @@ impl Default for EXCEPTION_REGISTRATION_RECORD {
 }
 #[repr(C)]
 #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec", target_arch = "x86_64"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FLOATING_SAVE_AREA {
     pub ControlWord: u32,
     pub StatusWord: u32,
@@ -224,7 +234,8 @@ impl Default for FLOATING_SAVE_AREA {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FLOATING_SAVE_AREA {
     pub ControlWord: u32,
     pub StatusWord: u32,
@@ -247,7 +258,8 @@ impl Default for FLOATING_SAVE_AREA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LIST_ENTRY {
     pub Flink: *mut LIST_ENTRY,
     pub Blink: *mut LIST_ENTRY,
@@ -261,7 +273,8 @@ impl Default for LIST_ENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LIST_ENTRY32 {
     pub Flink: u32,
     pub Blink: u32,
@@ -275,7 +288,8 @@ impl Default for LIST_ENTRY32 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LIST_ENTRY64 {
     pub Flink: u64,
     pub Blink: u64,
@@ -328,7 +342,8 @@ impl Default for NT_TIB_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OBJECTID {
     pub Lineage: windows_core::GUID,
     pub Uniquifier: u32,
@@ -342,7 +357,8 @@ impl Default for OBJECTID {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESSOR_NUMBER {
     pub Group: u16,
     pub Number: u8,
@@ -412,7 +428,8 @@ impl Default for RTL_BALANCED_NODE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RTL_BALANCED_NODE_0_0 {
     pub Left: *mut RTL_BALANCED_NODE,
     pub Right: *mut RTL_BALANCED_NODE,
@@ -440,7 +457,8 @@ impl Default for RTL_BALANCED_NODE_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SINGLE_LIST_ENTRY {
     pub Next: *mut SINGLE_LIST_ENTRY,
 }
@@ -453,7 +471,8 @@ impl Default for SINGLE_LIST_ENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SINGLE_LIST_ENTRY32 {
     pub Next: u32,
 }
@@ -466,7 +485,8 @@ impl Default for SINGLE_LIST_ENTRY32 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SLIST_ENTRY {
     pub Next: *mut SLIST_ENTRY,
 }
@@ -497,7 +517,8 @@ impl Default for SLIST_HEADER {
 }
 #[repr(C)]
 #[cfg(target_arch = "aarch64")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SLIST_HEADER_0 {
     pub Alignment: u64,
     pub Region: u64,
@@ -514,12 +535,40 @@ impl Default for SLIST_HEADER_0 {
 }
 #[repr(C)]
 #[cfg(target_arch = "aarch64")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SLIST_HEADER_1 {
     pub _bitfield1: u64,
     pub _bitfield2: u64,
 }
 #[cfg(target_arch = "aarch64")]
+impl SLIST_HEADER_1 {
+    pub const fn Depth(&self) -> u64 {
+        self._bitfield1 & 65535
+    }
+    pub fn set_Depth(&mut self, value: u64) {
+        self._bitfield1 = (self._bitfield1 & !65535) | (value & 65535);
+    }
+    pub const fn Sequence(&self) -> u64 {
+        (self._bitfield1 >> 16) & 281474976710655
+    }
+    pub fn set_Sequence(&mut self, value: u64) {
+        self._bitfield1 = (self._bitfield1 & !(281474976710655 << 16)) | ((value & 281474976710655) << 16);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        self._bitfield2 & 15
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield2 = (self._bitfield2 & !15) | (value & 15);
+    }
+    pub const fn NextEntry(&self) -> u64 {
+        (self._bitfield2 >> 4) & 1152921504606846975
+    }
+    pub fn set_NextEntry(&mut self, value: u64) {
+        self._bitfield2 = (self._bitfield2 & !(1152921504606846975 << 4)) | ((value & 1152921504606846975) << 4);
+    }
+}
+#[cfg(target_arch = "aarch64")]
 impl windows_core::TypeKind for SLIST_HEADER_1 {
     type TypeKind = windows_core::CopyType;
 }
@@ -548,7 +597,8 @@ impl Default for SLIST_HEADER {
 }
 #[repr(C)]
 #[cfg(any(target_arch = "arm64ec", target_arch = "x86_64"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SLIST_HEADER_0 {
     pub Alignment: u64,
     pub Region: u64,
@@ -565,12 +615,40 @@ impl Default for SLIST_HEADER_0 {
 }
 #[repr(C)]
 #[cfg(any(target_arch = "arm64ec", target_arch = "x86_64"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SLIST_HEADER_1 {
     pub _bitfield1: u64,
     pub _bitfield2: u64,
 }
 #[cfg(any(target_arch = "arm64ec", target_arch = "x86_64"))]
+impl SLIST_HEADER_1 {
+    pub const fn Depth(&self) -> u64 {
+        self._bitfield1 & 65535
+    }
+    pub fn set_Depth(&mut self, value: u64) {
+        self._bitfield1 = (self._bitfield1 & !65535) | (value & 65535);
+    }
+    pub const fn Sequence(&self) -> u64 {
+        (self._bitfield1 >> 16) & 281474976710655
+    }
+    pub fn set_Sequence(&mut self, value: u64) {
+        self._bitfield1 = (self._bitfield1 & !(281474976710655 << 16)) | ((value & 281474976710655) << 16);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        self._bitfield2 & 15
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield2 = (self._bitfield2 & !15) | (value & 15);
+    }
+    pub const fn NextEntry(&self) -> u64 {
+        (self._bitfield2 >> 4) & 1152921504606846975
+    }
+    pub fn set_NextEntry(&mut self, value: u64) {
+        self._bitfield2 = (self._bitfield2 & !(1152921504606846975 << 4)) | ((value & 1152921504606846975) << 4);
+    }
+}
+#[cfg(any(target_arch = "arm64ec", target_arch = "x86_64"))]
 impl windows_core::TypeKind for SLIST_HEADER_1 {
     type TypeKind = windows_core::CopyType;
 }
@@ -599,7 +677,8 @@ impl Default for SLIST_HEADER {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SLIST_HEADER_0 {
     pub Next: SINGLE_LIST_ENTRY,
     pub Depth: u16,
@@ -616,7 +695,8 @@ impl Default for SLIST_HEADER_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STRING {
     pub Length: u16,
     pub MaximumLength: u16,
@@ -631,7 +711,8 @@ impl Default for STRING {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STRING32 {
     pub Length: u16,
     pub MaximumLength: u16,
@@ -646,7 +727,8 @@ impl Default for STRING32 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STRING64 {
     pub Length: u16,
     pub MaximumLength: u16,
@@ -661,7 +743,8 @@ impl Default for STRING64 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WNF_STATE_NAME {
     pub Data: [u32; 2],
 }
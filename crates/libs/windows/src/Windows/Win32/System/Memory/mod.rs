@@ -886,6 +886,7 @@ pub struct FILE_MAP(pub u32);
 impl windows_core::TypeKind for FILE_MAP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FILE_MAP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FILE_MAP").field(&self.0).finish()
@@ -930,6 +931,7 @@ pub struct GLOBAL_ALLOC_FLAGS(pub u32);
 impl windows_core::TypeKind for GLOBAL_ALLOC_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GLOBAL_ALLOC_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GLOBAL_ALLOC_FLAGS").field(&self.0).finish()
@@ -974,6 +976,7 @@ pub struct HEAP_FLAGS(pub u32);
 impl windows_core::TypeKind for HEAP_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HEAP_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HEAP_FLAGS").field(&self.0).finish()
@@ -1018,6 +1021,7 @@ pub struct HEAP_INFORMATION_CLASS(pub i32);
 impl windows_core::TypeKind for HEAP_INFORMATION_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HEAP_INFORMATION_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HEAP_INFORMATION_CLASS").field(&self.0).finish()
@@ -1029,6 +1033,7 @@ pub struct LOCAL_ALLOC_FLAGS(pub u32);
 impl windows_core::TypeKind for LOCAL_ALLOC_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LOCAL_ALLOC_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LOCAL_ALLOC_FLAGS").field(&self.0).finish()
@@ -1073,6 +1078,7 @@ pub struct MEMORY_RESOURCE_NOTIFICATION_TYPE(pub i32);
 impl windows_core::TypeKind for MEMORY_RESOURCE_NOTIFICATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MEMORY_RESOURCE_NOTIFICATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MEMORY_RESOURCE_NOTIFICATION_TYPE").field(&self.0).finish()
@@ -1084,6 +1090,7 @@ pub struct MEM_DEDICATED_ATTRIBUTE_TYPE(pub i32);
 impl windows_core::TypeKind for MEM_DEDICATED_ATTRIBUTE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MEM_DEDICATED_ATTRIBUTE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MEM_DEDICATED_ATTRIBUTE_TYPE").field(&self.0).finish()
@@ -1095,6 +1102,7 @@ pub struct MEM_EXTENDED_PARAMETER_TYPE(pub i32);
 impl windows_core::TypeKind for MEM_EXTENDED_PARAMETER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MEM_EXTENDED_PARAMETER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MEM_EXTENDED_PARAMETER_TYPE").field(&self.0).finish()
@@ -1106,6 +1114,7 @@ pub struct MEM_SECTION_EXTENDED_PARAMETER_TYPE(pub i32);
 impl windows_core::TypeKind for MEM_SECTION_EXTENDED_PARAMETER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MEM_SECTION_EXTENDED_PARAMETER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MEM_SECTION_EXTENDED_PARAMETER_TYPE").field(&self.0).finish()
@@ -1117,6 +1126,7 @@ pub struct OFFER_PRIORITY(pub i32);
 impl windows_core::TypeKind for OFFER_PRIORITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OFFER_PRIORITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OFFER_PRIORITY").field(&self.0).finish()
@@ -1128,6 +1138,7 @@ pub struct PAGE_PROTECTION_FLAGS(pub u32);
 impl windows_core::TypeKind for PAGE_PROTECTION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PAGE_PROTECTION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PAGE_PROTECTION_FLAGS").field(&self.0).finish()
@@ -1172,6 +1183,7 @@ pub struct PAGE_TYPE(pub u32);
 impl windows_core::TypeKind for PAGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PAGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PAGE_TYPE").field(&self.0).finish()
@@ -1216,6 +1228,7 @@ pub struct SECTION_FLAGS(pub u32);
 impl windows_core::TypeKind for SECTION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SECTION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SECTION_FLAGS").field(&self.0).finish()
@@ -1260,6 +1273,7 @@ pub struct SETPROCESSWORKINGSETSIZEEX_FLAGS(pub u32);
 impl windows_core::TypeKind for SETPROCESSWORKINGSETSIZEEX_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SETPROCESSWORKINGSETSIZEEX_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SETPROCESSWORKINGSETSIZEEX_FLAGS").field(&self.0).finish()
@@ -1304,6 +1318,7 @@ pub struct UNMAP_VIEW_OF_FILE_FLAGS(pub u32);
 impl windows_core::TypeKind for UNMAP_VIEW_OF_FILE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UNMAP_VIEW_OF_FILE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UNMAP_VIEW_OF_FILE_FLAGS").field(&self.0).finish()
@@ -1315,6 +1330,7 @@ pub struct VIRTUAL_ALLOCATION_TYPE(pub u32);
 impl windows_core::TypeKind for VIRTUAL_ALLOCATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VIRTUAL_ALLOCATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VIRTUAL_ALLOCATION_TYPE").field(&self.0).finish()
@@ -1359,6 +1375,7 @@ pub struct VIRTUAL_FREE_TYPE(pub u32);
 impl windows_core::TypeKind for VIRTUAL_FREE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VIRTUAL_FREE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VIRTUAL_FREE_TYPE").field(&self.0).finish()
@@ -1370,6 +1387,7 @@ pub struct WIN32_MEMORY_INFORMATION_CLASS(pub i32);
 impl windows_core::TypeKind for WIN32_MEMORY_INFORMATION_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WIN32_MEMORY_INFORMATION_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WIN32_MEMORY_INFORMATION_CLASS").field(&self.0).finish()
@@ -1381,13 +1399,15 @@ pub struct WIN32_MEMORY_PARTITION_INFORMATION_CLASS(pub i32);
 impl windows_core::TypeKind for WIN32_MEMORY_PARTITION_INFORMATION_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WIN32_MEMORY_PARTITION_INFORMATION_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WIN32_MEMORY_PARTITION_INFORMATION_CLASS").field(&self.0).finish()
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AtlThunkData_t(pub isize);
 impl Default for AtlThunkData_t {
     fn default() -> Self {
@@ -1398,7 +1418,8 @@ impl windows_core::TypeKind for AtlThunkData_t {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CFG_CALL_TARGET_INFO {
     pub Offset: usize,
     pub Flags: usize,
@@ -1412,7 +1433,8 @@ impl Default for CFG_CALL_TARGET_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HEAP_SUMMARY {
     pub cb: u32,
     pub cbAllocated: usize,
@@ -1430,7 +1452,8 @@ impl Default for HEAP_SUMMARY {
 }
 #[repr(C)]
 #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec", target_arch = "x86_64"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MEMORY_BASIC_INFORMATION {
     pub BaseAddress: *mut core::ffi::c_void,
     pub AllocationBase: *mut core::ffi::c_void,
@@ -1453,7 +1476,8 @@ impl Default for MEMORY_BASIC_INFORMATION {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MEMORY_BASIC_INFORMATION {
     pub BaseAddress: *mut core::ffi::c_void,
     pub AllocationBase: *mut core::ffi::c_void,
@@ -1474,7 +1498,8 @@ impl Default for MEMORY_BASIC_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MEMORY_BASIC_INFORMATION32 {
     pub BaseAddress: u32,
     pub AllocationBase: u32,
@@ -1493,7 +1518,8 @@ impl Default for MEMORY_BASIC_INFORMATION32 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MEMORY_BASIC_INFORMATION64 {
     pub BaseAddress: u64,
     pub AllocationBase: u64,
@@ -1514,7 +1540,8 @@ impl Default for MEMORY_BASIC_INFORMATION64 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MEMORY_MAPPED_VIEW_ADDRESS {
     pub Value: *mut core::ffi::c_void,
 }
@@ -1527,7 +1554,8 @@ impl Default for MEMORY_MAPPED_VIEW_ADDRESS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MEMORY_PARTITION_DEDICATED_MEMORY_ATTRIBUTE {
     pub Type: MEM_DEDICATED_ATTRIBUTE_TYPE,
     pub Reserved: u32,
@@ -1542,7 +1570,8 @@ impl Default for MEMORY_PARTITION_DEDICATED_MEMORY_ATTRIBUTE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MEMORY_PARTITION_DEDICATED_MEMORY_INFORMATION {
     pub NextEntryOffset: u32,
     pub SizeOfInformation: u32,
@@ -1561,7 +1590,8 @@ impl Default for MEMORY_PARTITION_DEDICATED_MEMORY_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MEM_ADDRESS_REQUIREMENTS {
     pub LowestStartingAddress: *mut core::ffi::c_void,
     pub HighestEndingAddress: *mut core::ffi::c_void,
@@ -1590,10 +1620,25 @@ impl Default for MEM_EXTENDED_PARAMETER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MEM_EXTENDED_PARAMETER_0 {
     pub _bitfield: u64,
 }
+impl MEM_EXTENDED_PARAMETER_0 {
+    pub const fn Type(&self) -> u64 {
+        self._bitfield & 255
+    }
+    pub fn set_Type(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn Reserved(&self) -> u64 {
+        (self._bitfield >> 8) & 72057594037927935
+    }
+    pub fn set_Reserved(&mut self, value: u64) {
+        self._bitfield = (self._bitfield & !(72057594037927935 << 8)) | ((value & 72057594037927935) << 8);
+    }
+}
 impl windows_core::TypeKind for MEM_EXTENDED_PARAMETER_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -1652,7 +1697,8 @@ impl Default for PROCESS_HEAP_ENTRY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_HEAP_ENTRY_0_0 {
     pub hMem: super::super::Foundation::HANDLE,
     pub dwReserved: [u32; 3],
@@ -1666,7 +1712,8 @@ impl Default for PROCESS_HEAP_ENTRY_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_HEAP_ENTRY_0_1 {
     pub dwCommittedSize: u32,
     pub dwUnCommittedSize: u32,
@@ -1682,7 +1729,8 @@ impl Default for PROCESS_HEAP_ENTRY_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WIN32_MEMORY_PARTITION_INFORMATION {
     pub Flags: u32,
     pub NumaNode: u32,
@@ -1711,7 +1759,8 @@ impl Default for WIN32_MEMORY_PARTITION_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WIN32_MEMORY_RANGE_ENTRY {
     pub VirtualAddress: *mut core::ffi::c_void,
     pub NumberOfBytes: usize,
@@ -1756,10 +1805,55 @@ impl Default for WIN32_MEMORY_REGION_INFORMATION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WIN32_MEMORY_REGION_INFORMATION_0_0 {
     pub _bitfield: u32,
 }
+impl WIN32_MEMORY_REGION_INFORMATION_0_0 {
+    pub const fn Private(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Private(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn MappedDataFile(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_MappedDataFile(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn MappedImage(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_MappedImage(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn MappedPageFile(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_MappedPageFile(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn MappedPhysical(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_MappedPhysical(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn DirectMapped(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_DirectMapped(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 6) & 67108863
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(67108863 << 6)) | ((value & 67108863) << 6);
+    }
+}
 impl windows_core::TypeKind for WIN32_MEMORY_REGION_INFORMATION_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -1770,3 +1864,5 @@ impl Default for WIN32_MEMORY_REGION_INFORMATION_0_0 {
 }
 pub type PBAD_MEMORY_CALLBACK_ROUTINE = Option<unsafe extern "system" fn()>;
 pub type PSECURE_MEMORY_CACHE_CALLBACK = Option<unsafe extern "system" fn(addr: *const core::ffi::c_void, range: usize) -> super::super::Foundation::BOOLEAN>;
+core::include!(core::concat!(core::env!("CARGO_MANIFEST_DIR"), "/src/includes/", "Win32/System/Memory/FileMapping.rs"));
+core::include!(core::concat!(core::env!("CARGO_MANIFEST_DIR"), "/src/includes/", "Win32/System/Memory/VirtualMemory.rs"));
@@ -5583,6 +5583,7 @@ pub struct FOREIGN_STATUS(pub i32);
 impl windows_core::TypeKind for FOREIGN_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FOREIGN_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FOREIGN_STATUS").field(&self.0).finish()
@@ -5594,6 +5595,7 @@ pub struct MQACCESS(pub i32);
 impl windows_core::TypeKind for MQACCESS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MQACCESS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MQACCESS").field(&self.0).finish()
@@ -5605,6 +5607,7 @@ pub struct MQAUTHENTICATE(pub i32);
 impl windows_core::TypeKind for MQAUTHENTICATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MQAUTHENTICATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MQAUTHENTICATE").field(&self.0).finish()
@@ -5616,6 +5619,7 @@ pub struct MQCALG(pub i32);
 impl windows_core::TypeKind for MQCALG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MQCALG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MQCALG").field(&self.0).finish()
@@ -5627,6 +5631,7 @@ pub struct MQCERT_REGISTER(pub i32);
 impl windows_core::TypeKind for MQCERT_REGISTER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MQCERT_REGISTER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MQCERT_REGISTER").field(&self.0).finish()
@@ -5638,6 +5643,7 @@ pub struct MQConnectionState(pub i32);
 impl windows_core::TypeKind for MQConnectionState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MQConnectionState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MQConnectionState").field(&self.0).finish()
@@ -5649,6 +5655,7 @@ pub struct MQDEFAULT(pub i32);
 impl windows_core::TypeKind for MQDEFAULT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MQDEFAULT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MQDEFAULT").field(&self.0).finish()
@@ -5660,6 +5667,7 @@ pub struct MQERROR(pub i32);
 impl windows_core::TypeKind for MQERROR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MQERROR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MQERROR").field(&self.0).finish()
@@ -5671,6 +5679,7 @@ pub struct MQJOURNAL(pub i32);
 impl windows_core::TypeKind for MQJOURNAL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MQJOURNAL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MQJOURNAL").field(&self.0).finish()
@@ -5682,6 +5691,7 @@ pub struct MQMAX(pub i32);
 impl windows_core::TypeKind for MQMAX {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MQMAX {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MQMAX").field(&self.0).finish()
@@ -5693,6 +5703,7 @@ pub struct MQMSGACKNOWLEDGEMENT(pub i32);
 impl windows_core::TypeKind for MQMSGACKNOWLEDGEMENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MQMSGACKNOWLEDGEMENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MQMSGACKNOWLEDGEMENT").field(&self.0).finish()
@@ -5704,6 +5715,7 @@ pub struct MQMSGAUTHENTICATION(pub i32);
 impl windows_core::TypeKind for MQMSGAUTHENTICATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MQMSGAUTHENTICATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MQMSGAUTHENTICATION").field(&self.0).finish()
@@ -5715,6 +5727,7 @@ pub struct MQMSGAUTHLEVEL(pub i32);
 impl windows_core::TypeKind for MQMSGAUTHLEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MQMSGAUTHLEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MQMSGAUTHLEVEL").field(&self.0).finish()
@@ -5726,6 +5739,7 @@ pub struct MQMSGCLASS(pub i32);
 impl windows_core::TypeKind for MQMSGCLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MQMSGCLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MQMSGCLASS").field(&self.0).finish()
@@ -5737,6 +5751,7 @@ pub struct MQMSGCURSOR(pub i32);
 impl windows_core::TypeKind for MQMSGCURSOR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MQMSGCURSOR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MQMSGCURSOR").field(&self.0).finish()
@@ -5748,6 +5763,7 @@ pub struct MQMSGDELIVERY(pub i32);
 impl windows_core::TypeKind for MQMSGDELIVERY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MQMSGDELIVERY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MQMSGDELIVERY").field(&self.0).finish()
@@ -5759,6 +5775,7 @@ pub struct MQMSGIDSIZE(pub i32);
 impl windows_core::TypeKind for MQMSGIDSIZE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MQMSGIDSIZE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MQMSGIDSIZE").field(&self.0).finish()
@@ -5770,6 +5787,7 @@ pub struct MQMSGJOURNAL(pub i32);
 impl windows_core::TypeKind for MQMSGJOURNAL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MQMSGJOURNAL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MQMSGJOURNAL").field(&self.0).finish()
@@ -5781,6 +5799,7 @@ pub struct MQMSGMAX(pub i32);
 impl windows_core::TypeKind for MQMSGMAX {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MQMSGMAX {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MQMSGMAX").field(&self.0).finish()
@@ -5792,6 +5811,7 @@ pub struct MQMSGPRIVLEVEL(pub i32);
 impl windows_core::TypeKind for MQMSGPRIVLEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MQMSGPRIVLEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MQMSGPRIVLEVEL").field(&self.0).finish()
@@ -5803,6 +5823,7 @@ pub struct MQMSGSENDERIDTYPE(pub i32);
 impl windows_core::TypeKind for MQMSGSENDERIDTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MQMSGSENDERIDTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MQMSGSENDERIDTYPE").field(&self.0).finish()
@@ -5814,6 +5835,7 @@ pub struct MQMSGTRACE(pub i32);
 impl windows_core::TypeKind for MQMSGTRACE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MQMSGTRACE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MQMSGTRACE").field(&self.0).finish()
@@ -5825,6 +5847,7 @@ pub struct MQPRIORITY(pub i32);
 impl windows_core::TypeKind for MQPRIORITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MQPRIORITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MQPRIORITY").field(&self.0).finish()
@@ -5836,6 +5859,7 @@ pub struct MQPRIVLEVEL(pub i32);
 impl windows_core::TypeKind for MQPRIVLEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MQPRIVLEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MQPRIVLEVEL").field(&self.0).finish()
@@ -5847,6 +5871,7 @@ pub struct MQQUEUEACCESSMASK(pub u32);
 impl windows_core::TypeKind for MQQUEUEACCESSMASK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MQQUEUEACCESSMASK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MQQUEUEACCESSMASK").field(&self.0).finish()
@@ -5891,6 +5916,7 @@ pub struct MQSHARE(pub i32);
 impl windows_core::TypeKind for MQSHARE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MQSHARE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MQSHARE").field(&self.0).finish()
@@ -5902,6 +5928,7 @@ pub struct MQTRANSACTION(pub i32);
 impl windows_core::TypeKind for MQTRANSACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MQTRANSACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MQTRANSACTION").field(&self.0).finish()
@@ -5913,6 +5940,7 @@ pub struct MQTRANSACTIONAL(pub i32);
 impl windows_core::TypeKind for MQTRANSACTIONAL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MQTRANSACTIONAL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MQTRANSACTIONAL").field(&self.0).finish()
@@ -5924,6 +5952,7 @@ pub struct MQWARNING(pub i32);
 impl windows_core::TypeKind for MQWARNING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MQWARNING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MQWARNING").field(&self.0).finish()
@@ -5935,6 +5964,7 @@ pub struct QUEUE_STATE(pub i32);
 impl windows_core::TypeKind for QUEUE_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for QUEUE_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("QUEUE_STATE").field(&self.0).finish()
@@ -5946,6 +5976,7 @@ pub struct QUEUE_TYPE(pub i32);
 impl windows_core::TypeKind for QUEUE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for QUEUE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("QUEUE_TYPE").field(&self.0).finish()
@@ -5957,6 +5988,7 @@ pub struct RELOPS(pub i32);
 impl windows_core::TypeKind for RELOPS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RELOPS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RELOPS").field(&self.0).finish()
@@ -5968,13 +6000,15 @@ pub struct XACT_STATUS(pub i32);
 impl windows_core::TypeKind for XACT_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XACT_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XACT_STATUS").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MQCOLUMNSET {
     pub cCol: u32,
     pub aCol: *mut u32,
@@ -5988,7 +6022,8 @@ impl Default for MQCOLUMNSET {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MQMGMTPROPS {
     pub cProp: u32,
     pub aPropID: *mut u32,
@@ -6004,7 +6039,8 @@ impl Default for MQMGMTPROPS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MQMSGPROPS {
     pub cProp: u32,
     pub aPropID: *mut u32,
@@ -6020,7 +6056,8 @@ impl Default for MQMSGPROPS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MQPRIVATEPROPS {
     pub cProp: u32,
     pub aPropID: *mut u32,
@@ -6036,7 +6073,8 @@ impl Default for MQPRIVATEPROPS {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MQPROPERTYRESTRICTION {
     pub rel: u32,
     pub prop: u32,
@@ -6056,7 +6094,8 @@ impl Default for MQPROPERTYRESTRICTION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MQQMPROPS {
     pub cProp: u32,
     pub aPropID: *mut u32,
@@ -6072,7 +6111,8 @@ impl Default for MQQMPROPS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MQQUEUEPROPS {
     pub cProp: u32,
     pub aPropID: *mut u32,
@@ -6088,7 +6128,8 @@ impl Default for MQQUEUEPROPS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MQRESTRICTION {
     pub cRes: u32,
     pub paPropRes: *mut MQPROPERTYRESTRICTION,
@@ -6102,7 +6143,8 @@ impl Default for MQRESTRICTION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MQSORTKEY {
     pub propColumn: u32,
     pub dwOrder: u32,
@@ -6116,7 +6158,8 @@ impl Default for MQSORTKEY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MQSORTSET {
     pub cCol: u32,
     pub aCol: *mut MQSORTKEY,
@@ -6145,7 +6188,8 @@ pub const MSMQQueueManagement: windows_core::GUID = windows_core::GUID::from_u12
 pub const MSMQTransaction: windows_core::GUID = windows_core::GUID::from_u128(0xd7d6e080_dccd_11d0_aa4b_0060970debae);
 pub const MSMQTransactionDispenser: windows_core::GUID = windows_core::GUID::from_u128(0xd7d6e084_dccd_11d0_aa4b_0060970debae);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SEQUENCE_INFO {
     pub SeqID: i64,
     pub SeqNo: u32,
@@ -6163,3 +6207,5 @@ impl Default for SEQUENCE_INFO {
 pub type PMQRECEIVECALLBACK = Option<unsafe extern "system" fn(hrstatus: windows_core::HRESULT, hsource: isize, dwtimeout: u32, dwaction: u32, pmessageprops: *mut MQMSGPROPS, lpoverlapped: *mut super::IO::OVERLAPPED, hcursor: super::super::Foundation::HANDLE)>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
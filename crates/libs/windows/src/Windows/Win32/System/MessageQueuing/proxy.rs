@@ -0,0 +1,3735 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQApplication_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MachineIdOfMachineName(this: *mut core::ffi::c_void, machinename: core::mem::MaybeUninit<windows_core::BSTR>, pbstrguid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQApplication", "MachineIdOfMachineName", 7usize, |this| ((**(this as *const *const IMSMQApplication_Vtbl)).MachineIdOfMachineName)(this, machinename, pbstrguid), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MachineIdOfMachineName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQApplication as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQApplication2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterCertificate(this: *mut core::ffi::c_void, flags: *const core::mem::MaybeUninit<windows_core::VARIANT>, externalcertificate: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQApplication2", "RegisterCertificate", 8usize, |this| ((**(this as *const *const IMSMQApplication2_Vtbl)).RegisterCertificate)(this, flags, externalcertificate), |result| Some(*result))
+        }
+        unsafe extern "system" fn MachineNameOfMachineId(this: *mut core::ffi::c_void, bstrguid: core::mem::MaybeUninit<windows_core::BSTR>, pbstrmachinename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQApplication2", "MachineNameOfMachineId", 9usize, |this| ((**(this as *const *const IMSMQApplication2_Vtbl)).MachineNameOfMachineId)(this, bstrguid, pbstrmachinename), |result| Some(*result))
+        }
+        unsafe extern "system" fn MSMQVersionMajor(this: *mut core::ffi::c_void, psmsmqversionmajor: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQApplication2", "MSMQVersionMajor", 10usize, |this| ((**(this as *const *const IMSMQApplication2_Vtbl)).MSMQVersionMajor)(this, psmsmqversionmajor), |result| Some(*result))
+        }
+        unsafe extern "system" fn MSMQVersionMinor(this: *mut core::ffi::c_void, psmsmqversionminor: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQApplication2", "MSMQVersionMinor", 11usize, |this| ((**(this as *const *const IMSMQApplication2_Vtbl)).MSMQVersionMinor)(this, psmsmqversionminor), |result| Some(*result))
+        }
+        unsafe extern "system" fn MSMQVersionBuild(this: *mut core::ffi::c_void, psmsmqversionbuild: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQApplication2", "MSMQVersionBuild", 12usize, |this| ((**(this as *const *const IMSMQApplication2_Vtbl)).MSMQVersionBuild)(this, psmsmqversionbuild), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDsEnabled(this: *mut core::ffi::c_void, pfisdsenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQApplication2", "IsDsEnabled", 13usize, |this| ((**(this as *const *const IMSMQApplication2_Vtbl)).IsDsEnabled)(this, pfisdsenabled), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, ppcolproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQApplication2", "Properties", 14usize, |this| ((**(this as *const *const IMSMQApplication2_Vtbl)).Properties)(this, ppcolproperties), |result| Some(*result))
+        }
+        Self {
+            base__: <IMSMQApplication_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RegisterCertificate,
+            MachineNameOfMachineId,
+            MSMQVersionMajor,
+            MSMQVersionMinor,
+            MSMQVersionBuild,
+            IsDsEnabled,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQApplication2 as windows_core::Interface>::IID || <IMSMQApplication_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQApplication3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ActiveQueues(this: *mut core::ffi::c_void, pvactivequeues: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQApplication3", "ActiveQueues", 15usize, |this| ((**(this as *const *const IMSMQApplication3_Vtbl)).ActiveQueues)(this, pvactivequeues), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrivateQueues(this: *mut core::ffi::c_void, pvprivatequeues: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQApplication3", "PrivateQueues", 16usize, |this| ((**(this as *const *const IMSMQApplication3_Vtbl)).PrivateQueues)(this, pvprivatequeues), |result| Some(*result))
+        }
+        unsafe extern "system" fn DirectoryServiceServer(this: *mut core::ffi::c_void, pbstrdirectoryserviceserver: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQApplication3", "DirectoryServiceServer", 17usize, |this| ((**(this as *const *const IMSMQApplication3_Vtbl)).DirectoryServiceServer)(this, pbstrdirectoryserviceserver), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsConnected(this: *mut core::ffi::c_void, pfisconnected: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQApplication3", "IsConnected", 18usize, |this| ((**(this as *const *const IMSMQApplication3_Vtbl)).IsConnected)(this, pfisconnected), |result| Some(*result))
+        }
+        unsafe extern "system" fn BytesInAllQueues(this: *mut core::ffi::c_void, pvbytesinallqueues: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQApplication3", "BytesInAllQueues", 19usize, |this| ((**(this as *const *const IMSMQApplication3_Vtbl)).BytesInAllQueues)(this, pvbytesinallqueues), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMachine(this: *mut core::ffi::c_void, bstrmachine: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQApplication3", "SetMachine", 20usize, |this| ((**(this as *const *const IMSMQApplication3_Vtbl)).SetMachine)(this, bstrmachine), |result| Some(*result))
+        }
+        unsafe extern "system" fn Machine(this: *mut core::ffi::c_void, pbstrmachine: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQApplication3", "Machine", 21usize, |this| ((**(this as *const *const IMSMQApplication3_Vtbl)).Machine)(this, pbstrmachine), |result| Some(*result))
+        }
+        unsafe extern "system" fn Connect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQApplication3", "Connect", 22usize, |this| ((**(this as *const *const IMSMQApplication3_Vtbl)).Connect)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQApplication3", "Disconnect", 23usize, |this| ((**(this as *const *const IMSMQApplication3_Vtbl)).Disconnect)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Tidy(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQApplication3", "Tidy", 24usize, |this| ((**(this as *const *const IMSMQApplication3_Vtbl)).Tidy)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IMSMQApplication2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ActiveQueues,
+            PrivateQueues,
+            DirectoryServiceServer,
+            IsConnected,
+            BytesInAllQueues,
+            SetMachine,
+            Machine,
+            Connect,
+            Disconnect,
+            Tidy,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQApplication3 as windows_core::Interface>::IID || <IMSMQApplication2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvarret: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQCollection", "Item", 7usize, |this| ((**(this as *const *const IMSMQCollection_Vtbl)).Item)(this, index, pvarret), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQCollection", "Count", 8usize, |this| ((**(this as *const *const IMSMQCollection_Vtbl)).Count)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const IMSMQCollection_Vtbl))._NewEnum)(this, ppunk), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Item, Count, _NewEnum }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQCoordinatedTransactionDispenser_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn BeginTransaction(this: *mut core::ffi::c_void, ptransaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQCoordinatedTransactionDispenser", "BeginTransaction", 7usize, |this| ((**(this as *const *const IMSMQCoordinatedTransactionDispenser_Vtbl)).BeginTransaction)(this, ptransaction), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            BeginTransaction,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            BeginTransaction: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQCoordinatedTransactionDispenser as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQCoordinatedTransactionDispenser2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn BeginTransaction(this: *mut core::ffi::c_void, ptransaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQCoordinatedTransactionDispenser2", "BeginTransaction", 7usize, |this| ((**(this as *const *const IMSMQCoordinatedTransactionDispenser2_Vtbl)).BeginTransaction)(this, ptransaction), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, ppcolproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQCoordinatedTransactionDispenser2", "Properties", 8usize, |this| ((**(this as *const *const IMSMQCoordinatedTransactionDispenser2_Vtbl)).Properties)(this, ppcolproperties), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            BeginTransaction,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            BeginTransaction: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQCoordinatedTransactionDispenser2 as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQCoordinatedTransactionDispenser3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn BeginTransaction(this: *mut core::ffi::c_void, ptransaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQCoordinatedTransactionDispenser3", "BeginTransaction", 7usize, |this| ((**(this as *const *const IMSMQCoordinatedTransactionDispenser3_Vtbl)).BeginTransaction)(this, ptransaction), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, ppcolproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQCoordinatedTransactionDispenser3", "Properties", 8usize, |this| ((**(this as *const *const IMSMQCoordinatedTransactionDispenser3_Vtbl)).Properties)(this, ppcolproperties), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            BeginTransaction,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            BeginTransaction: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQCoordinatedTransactionDispenser3 as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQDestination_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQDestination", "Open", 7usize, |this| ((**(this as *const *const IMSMQDestination_Vtbl)).Open)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQDestination", "Close", 8usize, |this| ((**(this as *const *const IMSMQDestination_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOpen(this: *mut core::ffi::c_void, pfisopen: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQDestination", "IsOpen", 9usize, |this| ((**(this as *const *const IMSMQDestination_Vtbl)).IsOpen)(this, pfisopen), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn IADs(this: *mut core::ffi::c_void, ppiads: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQDestination", "IADs", 10usize, |this| ((**(this as *const *const IMSMQDestination_Vtbl)).IADs)(this, ppiads), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_IADs(this: *mut core::ffi::c_void, piads: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQDestination", "putref_IADs", 11usize, |this| ((**(this as *const *const IMSMQDestination_Vtbl)).putref_IADs)(this, piads), |result| Some(*result))
+        }
+        unsafe extern "system" fn ADsPath(this: *mut core::ffi::c_void, pbstradspath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQDestination", "ADsPath", 12usize, |this| ((**(this as *const *const IMSMQDestination_Vtbl)).ADsPath)(this, pbstradspath), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetADsPath(this: *mut core::ffi::c_void, bstradspath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQDestination", "SetADsPath", 13usize, |this| ((**(this as *const *const IMSMQDestination_Vtbl)).SetADsPath)(this, bstradspath), |result| Some(*result))
+        }
+        unsafe extern "system" fn PathName(this: *mut core::ffi::c_void, pbstrpathname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQDestination", "PathName", 14usize, |this| ((**(this as *const *const IMSMQDestination_Vtbl)).PathName)(this, pbstrpathname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPathName(this: *mut core::ffi::c_void, bstrpathname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQDestination", "SetPathName", 15usize, |this| ((**(this as *const *const IMSMQDestination_Vtbl)).SetPathName)(this, bstrpathname), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormatName(this: *mut core::ffi::c_void, pbstrformatname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQDestination", "FormatName", 16usize, |this| ((**(this as *const *const IMSMQDestination_Vtbl)).FormatName)(this, pbstrformatname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFormatName(this: *mut core::ffi::c_void, bstrformatname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQDestination", "SetFormatName", 17usize, |this| ((**(this as *const *const IMSMQDestination_Vtbl)).SetFormatName)(this, bstrformatname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Destinations(this: *mut core::ffi::c_void, ppdestinations: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQDestination", "Destinations", 18usize, |this| ((**(this as *const *const IMSMQDestination_Vtbl)).Destinations)(this, ppdestinations), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_Destinations(this: *mut core::ffi::c_void, pdestinations: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQDestination", "putref_Destinations", 19usize, |this| ((**(this as *const *const IMSMQDestination_Vtbl)).putref_Destinations)(this, pdestinations), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, ppcolproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQDestination", "Properties", 20usize, |this| ((**(this as *const *const IMSMQDestination_Vtbl)).Properties)(this, ppcolproperties), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Open,
+            Close,
+            IsOpen,
+            #[cfg(feature = "Win32_System_Com")]
+            IADs,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            IADs: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_IADs,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_IADs: 0,
+            ADsPath,
+            SetADsPath,
+            PathName,
+            SetPathName,
+            FormatName,
+            SetFormatName,
+            #[cfg(feature = "Win32_System_Com")]
+            Destinations,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Destinations: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_Destinations,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_Destinations: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQDestination as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQEvent_Vtbl {
+    const PROXY: Self = { Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQEvent as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQEvent2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, ppcolproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQEvent2", "Properties", 7usize, |this| ((**(this as *const *const IMSMQEvent2_Vtbl)).Properties)(this, ppcolproperties), |result| Some(*result))
+        }
+        Self {
+            base__: <IMSMQEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQEvent2 as windows_core::Interface>::IID || <IMSMQEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQEvent3_Vtbl {
+    const PROXY: Self = { Self { base__: <IMSMQEvent2_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQEvent3 as windows_core::Interface>::IID || <IMSMQEvent2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQManagement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Init(this: *mut core::ffi::c_void, machine: *const core::mem::MaybeUninit<windows_core::VARIANT>, pathname: *const core::mem::MaybeUninit<windows_core::VARIANT>, formatname: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQManagement", "Init", 7usize, |this| ((**(this as *const *const IMSMQManagement_Vtbl)).Init)(this, machine, pathname, formatname), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormatName(this: *mut core::ffi::c_void, pbstrformatname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQManagement", "FormatName", 8usize, |this| ((**(this as *const *const IMSMQManagement_Vtbl)).FormatName)(this, pbstrformatname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Machine(this: *mut core::ffi::c_void, pbstrmachine: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQManagement", "Machine", 9usize, |this| ((**(this as *const *const IMSMQManagement_Vtbl)).Machine)(this, pbstrmachine), |result| Some(*result))
+        }
+        unsafe extern "system" fn MessageCount(this: *mut core::ffi::c_void, plmessagecount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQManagement", "MessageCount", 10usize, |this| ((**(this as *const *const IMSMQManagement_Vtbl)).MessageCount)(this, plmessagecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForeignStatus(this: *mut core::ffi::c_void, plforeignstatus: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQManagement", "ForeignStatus", 11usize, |this| ((**(this as *const *const IMSMQManagement_Vtbl)).ForeignStatus)(this, plforeignstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueueType(this: *mut core::ffi::c_void, plqueuetype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQManagement", "QueueType", 12usize, |this| ((**(this as *const *const IMSMQManagement_Vtbl)).QueueType)(this, plqueuetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLocal(this: *mut core::ffi::c_void, pfislocal: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQManagement", "IsLocal", 13usize, |this| ((**(this as *const *const IMSMQManagement_Vtbl)).IsLocal)(this, pfislocal), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransactionalStatus(this: *mut core::ffi::c_void, pltransactionalstatus: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQManagement", "TransactionalStatus", 14usize, |this| ((**(this as *const *const IMSMQManagement_Vtbl)).TransactionalStatus)(this, pltransactionalstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn BytesInQueue(this: *mut core::ffi::c_void, pvbytesinqueue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQManagement", "BytesInQueue", 15usize, |this| ((**(this as *const *const IMSMQManagement_Vtbl)).BytesInQueue)(this, pvbytesinqueue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Init,
+            FormatName,
+            Machine,
+            MessageCount,
+            ForeignStatus,
+            QueueType,
+            IsLocal,
+            TransactionalStatus,
+            BytesInQueue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQManagement as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Class(this: *mut core::ffi::c_void, plclass: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "Class", 7usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).Class)(this, plclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrivLevel(this: *mut core::ffi::c_void, plprivlevel: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "PrivLevel", 8usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).PrivLevel)(this, plprivlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivLevel(this: *mut core::ffi::c_void, lprivlevel: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "SetPrivLevel", 9usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).SetPrivLevel)(this, lprivlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthLevel(this: *mut core::ffi::c_void, plauthlevel: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "AuthLevel", 10usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).AuthLevel)(this, plauthlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthLevel(this: *mut core::ffi::c_void, lauthlevel: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "SetAuthLevel", 11usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).SetAuthLevel)(this, lauthlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAuthenticated(this: *mut core::ffi::c_void, pisauthenticated: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "IsAuthenticated", 12usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).IsAuthenticated)(this, pisauthenticated), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delivery(this: *mut core::ffi::c_void, pldelivery: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "Delivery", 13usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).Delivery)(this, pldelivery), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDelivery(this: *mut core::ffi::c_void, ldelivery: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "SetDelivery", 14usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).SetDelivery)(this, ldelivery), |result| Some(*result))
+        }
+        unsafe extern "system" fn Trace(this: *mut core::ffi::c_void, pltrace: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "Trace", 15usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).Trace)(this, pltrace), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTrace(this: *mut core::ffi::c_void, ltrace: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "SetTrace", 16usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).SetTrace)(this, ltrace), |result| Some(*result))
+        }
+        unsafe extern "system" fn Priority(this: *mut core::ffi::c_void, plpriority: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "Priority", 17usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).Priority)(this, plpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPriority(this: *mut core::ffi::c_void, lpriority: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "SetPriority", 18usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).SetPriority)(this, lpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn Journal(this: *mut core::ffi::c_void, pljournal: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "Journal", 19usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).Journal)(this, pljournal), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetJournal(this: *mut core::ffi::c_void, ljournal: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "SetJournal", 20usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).SetJournal)(this, ljournal), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ResponseQueueInfo(this: *mut core::ffi::c_void, ppqinforesponse: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "ResponseQueueInfo", 21usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).ResponseQueueInfo)(this, ppqinforesponse), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_ResponseQueueInfo(this: *mut core::ffi::c_void, pqinforesponse: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "putref_ResponseQueueInfo", 22usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).putref_ResponseQueueInfo)(this, pqinforesponse), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppSpecific(this: *mut core::ffi::c_void, plappspecific: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "AppSpecific", 23usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).AppSpecific)(this, plappspecific), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAppSpecific(this: *mut core::ffi::c_void, lappspecific: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "SetAppSpecific", 24usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).SetAppSpecific)(this, lappspecific), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceMachineGuid(this: *mut core::ffi::c_void, pbstrguidsrcmachine: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "SourceMachineGuid", 25usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).SourceMachineGuid)(this, pbstrguidsrcmachine), |result| Some(*result))
+        }
+        unsafe extern "system" fn BodyLength(this: *mut core::ffi::c_void, pcbbody: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "BodyLength", 26usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).BodyLength)(this, pcbbody), |result| Some(*result))
+        }
+        unsafe extern "system" fn Body(this: *mut core::ffi::c_void, pvarbody: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "Body", 27usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).Body)(this, pvarbody), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBody(this: *mut core::ffi::c_void, varbody: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "SetBody", 28usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).SetBody)(this, varbody), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AdminQueueInfo(this: *mut core::ffi::c_void, ppqinfoadmin: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "AdminQueueInfo", 29usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).AdminQueueInfo)(this, ppqinfoadmin), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_AdminQueueInfo(this: *mut core::ffi::c_void, pqinfoadmin: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "putref_AdminQueueInfo", 30usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).putref_AdminQueueInfo)(this, pqinfoadmin), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, pvarmsgid: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "Id", 31usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).Id)(this, pvarmsgid), |result| Some(*result))
+        }
+        unsafe extern "system" fn CorrelationId(this: *mut core::ffi::c_void, pvarmsgid: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "CorrelationId", 32usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).CorrelationId)(this, pvarmsgid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCorrelationId(this: *mut core::ffi::c_void, varmsgid: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "SetCorrelationId", 33usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).SetCorrelationId)(this, varmsgid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ack(this: *mut core::ffi::c_void, plack: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "Ack", 34usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).Ack)(this, plack), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAck(this: *mut core::ffi::c_void, lack: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "SetAck", 35usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).SetAck)(this, lack), |result| Some(*result))
+        }
+        unsafe extern "system" fn Label(this: *mut core::ffi::c_void, pbstrlabel: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "Label", 36usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).Label)(this, pbstrlabel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLabel(this: *mut core::ffi::c_void, bstrlabel: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "SetLabel", 37usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).SetLabel)(this, bstrlabel), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxTimeToReachQueue(this: *mut core::ffi::c_void, plmaxtimetoreachqueue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "MaxTimeToReachQueue", 38usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).MaxTimeToReachQueue)(this, plmaxtimetoreachqueue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxTimeToReachQueue(this: *mut core::ffi::c_void, lmaxtimetoreachqueue: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "SetMaxTimeToReachQueue", 39usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).SetMaxTimeToReachQueue)(this, lmaxtimetoreachqueue), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxTimeToReceive(this: *mut core::ffi::c_void, plmaxtimetoreceive: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "MaxTimeToReceive", 40usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).MaxTimeToReceive)(this, plmaxtimetoreceive), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxTimeToReceive(this: *mut core::ffi::c_void, lmaxtimetoreceive: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "SetMaxTimeToReceive", 41usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).SetMaxTimeToReceive)(this, lmaxtimetoreceive), |result| Some(*result))
+        }
+        unsafe extern "system" fn HashAlgorithm(this: *mut core::ffi::c_void, plhashalg: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "HashAlgorithm", 42usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).HashAlgorithm)(this, plhashalg), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHashAlgorithm(this: *mut core::ffi::c_void, lhashalg: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "SetHashAlgorithm", 43usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).SetHashAlgorithm)(this, lhashalg), |result| Some(*result))
+        }
+        unsafe extern "system" fn EncryptAlgorithm(this: *mut core::ffi::c_void, plencryptalg: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "EncryptAlgorithm", 44usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).EncryptAlgorithm)(this, plencryptalg), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEncryptAlgorithm(this: *mut core::ffi::c_void, lencryptalg: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "SetEncryptAlgorithm", 45usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).SetEncryptAlgorithm)(this, lencryptalg), |result| Some(*result))
+        }
+        unsafe extern "system" fn SentTime(this: *mut core::ffi::c_void, pvarsenttime: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "SentTime", 46usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).SentTime)(this, pvarsenttime), |result| Some(*result))
+        }
+        unsafe extern "system" fn ArrivedTime(this: *mut core::ffi::c_void, plarrivedtime: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "ArrivedTime", 47usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).ArrivedTime)(this, plarrivedtime), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DestinationQueueInfo(this: *mut core::ffi::c_void, ppqinfodest: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "DestinationQueueInfo", 48usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).DestinationQueueInfo)(this, ppqinfodest), |result| Some(*result))
+        }
+        unsafe extern "system" fn SenderCertificate(this: *mut core::ffi::c_void, pvarsendercert: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "SenderCertificate", 49usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).SenderCertificate)(this, pvarsendercert), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSenderCertificate(this: *mut core::ffi::c_void, varsendercert: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "SetSenderCertificate", 50usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).SetSenderCertificate)(this, varsendercert), |result| Some(*result))
+        }
+        unsafe extern "system" fn SenderId(this: *mut core::ffi::c_void, pvarsenderid: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "SenderId", 51usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).SenderId)(this, pvarsenderid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SenderIdType(this: *mut core::ffi::c_void, plsenderidtype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "SenderIdType", 52usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).SenderIdType)(this, plsenderidtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSenderIdType(this: *mut core::ffi::c_void, lsenderidtype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "SetSenderIdType", 53usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).SetSenderIdType)(this, lsenderidtype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Send(this: *mut core::ffi::c_void, destinationqueue: *mut core::ffi::c_void, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "Send", 54usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).Send)(this, destinationqueue, transaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttachCurrentSecurityContext(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage", "AttachCurrentSecurityContext", 55usize, |this| ((**(this as *const *const IMSMQMessage_Vtbl)).AttachCurrentSecurityContext)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Class,
+            PrivLevel,
+            SetPrivLevel,
+            AuthLevel,
+            SetAuthLevel,
+            IsAuthenticated,
+            Delivery,
+            SetDelivery,
+            Trace,
+            SetTrace,
+            Priority,
+            SetPriority,
+            Journal,
+            SetJournal,
+            #[cfg(feature = "Win32_System_Com")]
+            ResponseQueueInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ResponseQueueInfo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_ResponseQueueInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_ResponseQueueInfo: 0,
+            AppSpecific,
+            SetAppSpecific,
+            SourceMachineGuid,
+            BodyLength,
+            Body,
+            SetBody,
+            #[cfg(feature = "Win32_System_Com")]
+            AdminQueueInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AdminQueueInfo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_AdminQueueInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_AdminQueueInfo: 0,
+            Id,
+            CorrelationId,
+            SetCorrelationId,
+            Ack,
+            SetAck,
+            Label,
+            SetLabel,
+            MaxTimeToReachQueue,
+            SetMaxTimeToReachQueue,
+            MaxTimeToReceive,
+            SetMaxTimeToReceive,
+            HashAlgorithm,
+            SetHashAlgorithm,
+            EncryptAlgorithm,
+            SetEncryptAlgorithm,
+            SentTime,
+            ArrivedTime,
+            #[cfg(feature = "Win32_System_Com")]
+            DestinationQueueInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DestinationQueueInfo: 0,
+            SenderCertificate,
+            SetSenderCertificate,
+            SenderId,
+            SenderIdType,
+            SetSenderIdType,
+            #[cfg(feature = "Win32_System_Com")]
+            Send,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Send: 0,
+            AttachCurrentSecurityContext,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQMessage as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQMessage2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Class(this: *mut core::ffi::c_void, plclass: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "Class", 7usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).Class)(this, plclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrivLevel(this: *mut core::ffi::c_void, plprivlevel: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "PrivLevel", 8usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).PrivLevel)(this, plprivlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivLevel(this: *mut core::ffi::c_void, lprivlevel: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SetPrivLevel", 9usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SetPrivLevel)(this, lprivlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthLevel(this: *mut core::ffi::c_void, plauthlevel: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "AuthLevel", 10usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).AuthLevel)(this, plauthlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthLevel(this: *mut core::ffi::c_void, lauthlevel: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SetAuthLevel", 11usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SetAuthLevel)(this, lauthlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAuthenticated(this: *mut core::ffi::c_void, pisauthenticated: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "IsAuthenticated", 12usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).IsAuthenticated)(this, pisauthenticated), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delivery(this: *mut core::ffi::c_void, pldelivery: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "Delivery", 13usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).Delivery)(this, pldelivery), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDelivery(this: *mut core::ffi::c_void, ldelivery: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SetDelivery", 14usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SetDelivery)(this, ldelivery), |result| Some(*result))
+        }
+        unsafe extern "system" fn Trace(this: *mut core::ffi::c_void, pltrace: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "Trace", 15usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).Trace)(this, pltrace), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTrace(this: *mut core::ffi::c_void, ltrace: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SetTrace", 16usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SetTrace)(this, ltrace), |result| Some(*result))
+        }
+        unsafe extern "system" fn Priority(this: *mut core::ffi::c_void, plpriority: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "Priority", 17usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).Priority)(this, plpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPriority(this: *mut core::ffi::c_void, lpriority: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SetPriority", 18usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SetPriority)(this, lpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn Journal(this: *mut core::ffi::c_void, pljournal: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "Journal", 19usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).Journal)(this, pljournal), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetJournal(this: *mut core::ffi::c_void, ljournal: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SetJournal", 20usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SetJournal)(this, ljournal), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ResponseQueueInfo_v1(this: *mut core::ffi::c_void, ppqinforesponse: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "ResponseQueueInfo_v1", 21usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).ResponseQueueInfo_v1)(this, ppqinforesponse), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_ResponseQueueInfo_v1(this: *mut core::ffi::c_void, pqinforesponse: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "putref_ResponseQueueInfo_v1", 22usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).putref_ResponseQueueInfo_v1)(this, pqinforesponse), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppSpecific(this: *mut core::ffi::c_void, plappspecific: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "AppSpecific", 23usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).AppSpecific)(this, plappspecific), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAppSpecific(this: *mut core::ffi::c_void, lappspecific: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SetAppSpecific", 24usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SetAppSpecific)(this, lappspecific), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceMachineGuid(this: *mut core::ffi::c_void, pbstrguidsrcmachine: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SourceMachineGuid", 25usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SourceMachineGuid)(this, pbstrguidsrcmachine), |result| Some(*result))
+        }
+        unsafe extern "system" fn BodyLength(this: *mut core::ffi::c_void, pcbbody: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "BodyLength", 26usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).BodyLength)(this, pcbbody), |result| Some(*result))
+        }
+        unsafe extern "system" fn Body(this: *mut core::ffi::c_void, pvarbody: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "Body", 27usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).Body)(this, pvarbody), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBody(this: *mut core::ffi::c_void, varbody: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SetBody", 28usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SetBody)(this, varbody), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AdminQueueInfo_v1(this: *mut core::ffi::c_void, ppqinfoadmin: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "AdminQueueInfo_v1", 29usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).AdminQueueInfo_v1)(this, ppqinfoadmin), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_AdminQueueInfo_v1(this: *mut core::ffi::c_void, pqinfoadmin: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "putref_AdminQueueInfo_v1", 30usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).putref_AdminQueueInfo_v1)(this, pqinfoadmin), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, pvarmsgid: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "Id", 31usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).Id)(this, pvarmsgid), |result| Some(*result))
+        }
+        unsafe extern "system" fn CorrelationId(this: *mut core::ffi::c_void, pvarmsgid: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "CorrelationId", 32usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).CorrelationId)(this, pvarmsgid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCorrelationId(this: *mut core::ffi::c_void, varmsgid: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SetCorrelationId", 33usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SetCorrelationId)(this, varmsgid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ack(this: *mut core::ffi::c_void, plack: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "Ack", 34usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).Ack)(this, plack), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAck(this: *mut core::ffi::c_void, lack: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SetAck", 35usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SetAck)(this, lack), |result| Some(*result))
+        }
+        unsafe extern "system" fn Label(this: *mut core::ffi::c_void, pbstrlabel: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "Label", 36usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).Label)(this, pbstrlabel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLabel(this: *mut core::ffi::c_void, bstrlabel: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SetLabel", 37usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SetLabel)(this, bstrlabel), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxTimeToReachQueue(this: *mut core::ffi::c_void, plmaxtimetoreachqueue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "MaxTimeToReachQueue", 38usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).MaxTimeToReachQueue)(this, plmaxtimetoreachqueue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxTimeToReachQueue(this: *mut core::ffi::c_void, lmaxtimetoreachqueue: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SetMaxTimeToReachQueue", 39usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SetMaxTimeToReachQueue)(this, lmaxtimetoreachqueue), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxTimeToReceive(this: *mut core::ffi::c_void, plmaxtimetoreceive: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "MaxTimeToReceive", 40usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).MaxTimeToReceive)(this, plmaxtimetoreceive), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxTimeToReceive(this: *mut core::ffi::c_void, lmaxtimetoreceive: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SetMaxTimeToReceive", 41usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SetMaxTimeToReceive)(this, lmaxtimetoreceive), |result| Some(*result))
+        }
+        unsafe extern "system" fn HashAlgorithm(this: *mut core::ffi::c_void, plhashalg: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "HashAlgorithm", 42usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).HashAlgorithm)(this, plhashalg), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHashAlgorithm(this: *mut core::ffi::c_void, lhashalg: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SetHashAlgorithm", 43usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SetHashAlgorithm)(this, lhashalg), |result| Some(*result))
+        }
+        unsafe extern "system" fn EncryptAlgorithm(this: *mut core::ffi::c_void, plencryptalg: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "EncryptAlgorithm", 44usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).EncryptAlgorithm)(this, plencryptalg), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEncryptAlgorithm(this: *mut core::ffi::c_void, lencryptalg: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SetEncryptAlgorithm", 45usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SetEncryptAlgorithm)(this, lencryptalg), |result| Some(*result))
+        }
+        unsafe extern "system" fn SentTime(this: *mut core::ffi::c_void, pvarsenttime: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SentTime", 46usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SentTime)(this, pvarsenttime), |result| Some(*result))
+        }
+        unsafe extern "system" fn ArrivedTime(this: *mut core::ffi::c_void, plarrivedtime: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "ArrivedTime", 47usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).ArrivedTime)(this, plarrivedtime), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DestinationQueueInfo(this: *mut core::ffi::c_void, ppqinfodest: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "DestinationQueueInfo", 48usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).DestinationQueueInfo)(this, ppqinfodest), |result| Some(*result))
+        }
+        unsafe extern "system" fn SenderCertificate(this: *mut core::ffi::c_void, pvarsendercert: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SenderCertificate", 49usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SenderCertificate)(this, pvarsendercert), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSenderCertificate(this: *mut core::ffi::c_void, varsendercert: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SetSenderCertificate", 50usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SetSenderCertificate)(this, varsendercert), |result| Some(*result))
+        }
+        unsafe extern "system" fn SenderId(this: *mut core::ffi::c_void, pvarsenderid: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SenderId", 51usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SenderId)(this, pvarsenderid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SenderIdType(this: *mut core::ffi::c_void, plsenderidtype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SenderIdType", 52usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SenderIdType)(this, plsenderidtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSenderIdType(this: *mut core::ffi::c_void, lsenderidtype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SetSenderIdType", 53usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SetSenderIdType)(this, lsenderidtype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Send(this: *mut core::ffi::c_void, destinationqueue: *mut core::ffi::c_void, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "Send", 54usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).Send)(this, destinationqueue, transaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttachCurrentSecurityContext(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "AttachCurrentSecurityContext", 55usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).AttachCurrentSecurityContext)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SenderVersion(this: *mut core::ffi::c_void, plsenderversion: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SenderVersion", 56usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SenderVersion)(this, plsenderversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn Extension(this: *mut core::ffi::c_void, pvarextension: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "Extension", 57usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).Extension)(this, pvarextension), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExtension(this: *mut core::ffi::c_void, varextension: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SetExtension", 58usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SetExtension)(this, varextension), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectorTypeGuid(this: *mut core::ffi::c_void, pbstrguidconnectortype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "ConnectorTypeGuid", 59usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).ConnectorTypeGuid)(this, pbstrguidconnectortype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConnectorTypeGuid(this: *mut core::ffi::c_void, bstrguidconnectortype: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SetConnectorTypeGuid", 60usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SetConnectorTypeGuid)(this, bstrguidconnectortype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn TransactionStatusQueueInfo(this: *mut core::ffi::c_void, ppqinfoxactstatus: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "TransactionStatusQueueInfo", 61usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).TransactionStatusQueueInfo)(this, ppqinfoxactstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestinationSymmetricKey(this: *mut core::ffi::c_void, pvardestsymmkey: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "DestinationSymmetricKey", 62usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).DestinationSymmetricKey)(this, pvardestsymmkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDestinationSymmetricKey(this: *mut core::ffi::c_void, vardestsymmkey: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SetDestinationSymmetricKey", 63usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SetDestinationSymmetricKey)(this, vardestsymmkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn Signature(this: *mut core::ffi::c_void, pvarsignature: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "Signature", 64usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).Signature)(this, pvarsignature), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSignature(this: *mut core::ffi::c_void, varsignature: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SetSignature", 65usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SetSignature)(this, varsignature), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthenticationProviderType(this: *mut core::ffi::c_void, plauthprovtype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "AuthenticationProviderType", 66usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).AuthenticationProviderType)(this, plauthprovtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthenticationProviderType(this: *mut core::ffi::c_void, lauthprovtype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SetAuthenticationProviderType", 67usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SetAuthenticationProviderType)(this, lauthprovtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthenticationProviderName(this: *mut core::ffi::c_void, pbstrauthprovname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "AuthenticationProviderName", 68usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).AuthenticationProviderName)(this, pbstrauthprovname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthenticationProviderName(this: *mut core::ffi::c_void, bstrauthprovname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SetAuthenticationProviderName", 69usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SetAuthenticationProviderName)(this, bstrauthprovname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSenderId(this: *mut core::ffi::c_void, varsenderid: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SetSenderId", 70usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SetSenderId)(this, varsenderid), |result| Some(*result))
+        }
+        unsafe extern "system" fn MsgClass(this: *mut core::ffi::c_void, plmsgclass: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "MsgClass", 71usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).MsgClass)(this, plmsgclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMsgClass(this: *mut core::ffi::c_void, lmsgclass: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "SetMsgClass", 72usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).SetMsgClass)(this, lmsgclass), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, ppcolproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "Properties", 73usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).Properties)(this, ppcolproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransactionId(this: *mut core::ffi::c_void, pvarxactid: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "TransactionId", 74usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).TransactionId)(this, pvarxactid), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFirstInTransaction(this: *mut core::ffi::c_void, pisfirstinxact: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "IsFirstInTransaction", 75usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).IsFirstInTransaction)(this, pisfirstinxact), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLastInTransaction(this: *mut core::ffi::c_void, pislastinxact: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "IsLastInTransaction", 76usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).IsLastInTransaction)(this, pislastinxact), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ResponseQueueInfo(this: *mut core::ffi::c_void, ppqinforesponse: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "ResponseQueueInfo", 77usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).ResponseQueueInfo)(this, ppqinforesponse), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_ResponseQueueInfo(this: *mut core::ffi::c_void, pqinforesponse: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "putref_ResponseQueueInfo", 78usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).putref_ResponseQueueInfo)(this, pqinforesponse), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AdminQueueInfo(this: *mut core::ffi::c_void, ppqinfoadmin: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "AdminQueueInfo", 79usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).AdminQueueInfo)(this, ppqinfoadmin), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_AdminQueueInfo(this: *mut core::ffi::c_void, pqinfoadmin: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "putref_AdminQueueInfo", 80usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).putref_AdminQueueInfo)(this, pqinfoadmin), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReceivedAuthenticationLevel(this: *mut core::ffi::c_void, psreceivedauthenticationlevel: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage2", "ReceivedAuthenticationLevel", 81usize, |this| ((**(this as *const *const IMSMQMessage2_Vtbl)).ReceivedAuthenticationLevel)(this, psreceivedauthenticationlevel), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Class,
+            PrivLevel,
+            SetPrivLevel,
+            AuthLevel,
+            SetAuthLevel,
+            IsAuthenticated,
+            Delivery,
+            SetDelivery,
+            Trace,
+            SetTrace,
+            Priority,
+            SetPriority,
+            Journal,
+            SetJournal,
+            #[cfg(feature = "Win32_System_Com")]
+            ResponseQueueInfo_v1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ResponseQueueInfo_v1: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_ResponseQueueInfo_v1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_ResponseQueueInfo_v1: 0,
+            AppSpecific,
+            SetAppSpecific,
+            SourceMachineGuid,
+            BodyLength,
+            Body,
+            SetBody,
+            #[cfg(feature = "Win32_System_Com")]
+            AdminQueueInfo_v1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AdminQueueInfo_v1: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_AdminQueueInfo_v1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_AdminQueueInfo_v1: 0,
+            Id,
+            CorrelationId,
+            SetCorrelationId,
+            Ack,
+            SetAck,
+            Label,
+            SetLabel,
+            MaxTimeToReachQueue,
+            SetMaxTimeToReachQueue,
+            MaxTimeToReceive,
+            SetMaxTimeToReceive,
+            HashAlgorithm,
+            SetHashAlgorithm,
+            EncryptAlgorithm,
+            SetEncryptAlgorithm,
+            SentTime,
+            ArrivedTime,
+            #[cfg(feature = "Win32_System_Com")]
+            DestinationQueueInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DestinationQueueInfo: 0,
+            SenderCertificate,
+            SetSenderCertificate,
+            SenderId,
+            SenderIdType,
+            SetSenderIdType,
+            #[cfg(feature = "Win32_System_Com")]
+            Send,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Send: 0,
+            AttachCurrentSecurityContext,
+            SenderVersion,
+            Extension,
+            SetExtension,
+            ConnectorTypeGuid,
+            SetConnectorTypeGuid,
+            #[cfg(feature = "Win32_System_Com")]
+            TransactionStatusQueueInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            TransactionStatusQueueInfo: 0,
+            DestinationSymmetricKey,
+            SetDestinationSymmetricKey,
+            Signature,
+            SetSignature,
+            AuthenticationProviderType,
+            SetAuthenticationProviderType,
+            AuthenticationProviderName,
+            SetAuthenticationProviderName,
+            SetSenderId,
+            MsgClass,
+            SetMsgClass,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+            TransactionId,
+            IsFirstInTransaction,
+            IsLastInTransaction,
+            #[cfg(feature = "Win32_System_Com")]
+            ResponseQueueInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ResponseQueueInfo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_ResponseQueueInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_ResponseQueueInfo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AdminQueueInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AdminQueueInfo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_AdminQueueInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_AdminQueueInfo: 0,
+            ReceivedAuthenticationLevel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQMessage2 as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQMessage3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Class(this: *mut core::ffi::c_void, plclass: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "Class", 7usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).Class)(this, plclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrivLevel(this: *mut core::ffi::c_void, plprivlevel: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "PrivLevel", 8usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).PrivLevel)(this, plprivlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivLevel(this: *mut core::ffi::c_void, lprivlevel: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SetPrivLevel", 9usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SetPrivLevel)(this, lprivlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthLevel(this: *mut core::ffi::c_void, plauthlevel: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "AuthLevel", 10usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).AuthLevel)(this, plauthlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthLevel(this: *mut core::ffi::c_void, lauthlevel: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SetAuthLevel", 11usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SetAuthLevel)(this, lauthlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAuthenticated(this: *mut core::ffi::c_void, pisauthenticated: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "IsAuthenticated", 12usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).IsAuthenticated)(this, pisauthenticated), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delivery(this: *mut core::ffi::c_void, pldelivery: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "Delivery", 13usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).Delivery)(this, pldelivery), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDelivery(this: *mut core::ffi::c_void, ldelivery: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SetDelivery", 14usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SetDelivery)(this, ldelivery), |result| Some(*result))
+        }
+        unsafe extern "system" fn Trace(this: *mut core::ffi::c_void, pltrace: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "Trace", 15usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).Trace)(this, pltrace), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTrace(this: *mut core::ffi::c_void, ltrace: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SetTrace", 16usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SetTrace)(this, ltrace), |result| Some(*result))
+        }
+        unsafe extern "system" fn Priority(this: *mut core::ffi::c_void, plpriority: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "Priority", 17usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).Priority)(this, plpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPriority(this: *mut core::ffi::c_void, lpriority: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SetPriority", 18usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SetPriority)(this, lpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn Journal(this: *mut core::ffi::c_void, pljournal: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "Journal", 19usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).Journal)(this, pljournal), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetJournal(this: *mut core::ffi::c_void, ljournal: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SetJournal", 20usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SetJournal)(this, ljournal), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ResponseQueueInfo_v1(this: *mut core::ffi::c_void, ppqinforesponse: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "ResponseQueueInfo_v1", 21usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).ResponseQueueInfo_v1)(this, ppqinforesponse), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_ResponseQueueInfo_v1(this: *mut core::ffi::c_void, pqinforesponse: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "putref_ResponseQueueInfo_v1", 22usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).putref_ResponseQueueInfo_v1)(this, pqinforesponse), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppSpecific(this: *mut core::ffi::c_void, plappspecific: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "AppSpecific", 23usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).AppSpecific)(this, plappspecific), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAppSpecific(this: *mut core::ffi::c_void, lappspecific: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SetAppSpecific", 24usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SetAppSpecific)(this, lappspecific), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceMachineGuid(this: *mut core::ffi::c_void, pbstrguidsrcmachine: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SourceMachineGuid", 25usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SourceMachineGuid)(this, pbstrguidsrcmachine), |result| Some(*result))
+        }
+        unsafe extern "system" fn BodyLength(this: *mut core::ffi::c_void, pcbbody: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "BodyLength", 26usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).BodyLength)(this, pcbbody), |result| Some(*result))
+        }
+        unsafe extern "system" fn Body(this: *mut core::ffi::c_void, pvarbody: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "Body", 27usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).Body)(this, pvarbody), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBody(this: *mut core::ffi::c_void, varbody: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SetBody", 28usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SetBody)(this, varbody), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AdminQueueInfo_v1(this: *mut core::ffi::c_void, ppqinfoadmin: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "AdminQueueInfo_v1", 29usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).AdminQueueInfo_v1)(this, ppqinfoadmin), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_AdminQueueInfo_v1(this: *mut core::ffi::c_void, pqinfoadmin: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "putref_AdminQueueInfo_v1", 30usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).putref_AdminQueueInfo_v1)(this, pqinfoadmin), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, pvarmsgid: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "Id", 31usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).Id)(this, pvarmsgid), |result| Some(*result))
+        }
+        unsafe extern "system" fn CorrelationId(this: *mut core::ffi::c_void, pvarmsgid: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "CorrelationId", 32usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).CorrelationId)(this, pvarmsgid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCorrelationId(this: *mut core::ffi::c_void, varmsgid: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SetCorrelationId", 33usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SetCorrelationId)(this, varmsgid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ack(this: *mut core::ffi::c_void, plack: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "Ack", 34usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).Ack)(this, plack), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAck(this: *mut core::ffi::c_void, lack: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SetAck", 35usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SetAck)(this, lack), |result| Some(*result))
+        }
+        unsafe extern "system" fn Label(this: *mut core::ffi::c_void, pbstrlabel: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "Label", 36usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).Label)(this, pbstrlabel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLabel(this: *mut core::ffi::c_void, bstrlabel: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SetLabel", 37usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SetLabel)(this, bstrlabel), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxTimeToReachQueue(this: *mut core::ffi::c_void, plmaxtimetoreachqueue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "MaxTimeToReachQueue", 38usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).MaxTimeToReachQueue)(this, plmaxtimetoreachqueue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxTimeToReachQueue(this: *mut core::ffi::c_void, lmaxtimetoreachqueue: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SetMaxTimeToReachQueue", 39usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SetMaxTimeToReachQueue)(this, lmaxtimetoreachqueue), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxTimeToReceive(this: *mut core::ffi::c_void, plmaxtimetoreceive: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "MaxTimeToReceive", 40usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).MaxTimeToReceive)(this, plmaxtimetoreceive), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxTimeToReceive(this: *mut core::ffi::c_void, lmaxtimetoreceive: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SetMaxTimeToReceive", 41usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SetMaxTimeToReceive)(this, lmaxtimetoreceive), |result| Some(*result))
+        }
+        unsafe extern "system" fn HashAlgorithm(this: *mut core::ffi::c_void, plhashalg: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "HashAlgorithm", 42usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).HashAlgorithm)(this, plhashalg), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHashAlgorithm(this: *mut core::ffi::c_void, lhashalg: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SetHashAlgorithm", 43usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SetHashAlgorithm)(this, lhashalg), |result| Some(*result))
+        }
+        unsafe extern "system" fn EncryptAlgorithm(this: *mut core::ffi::c_void, plencryptalg: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "EncryptAlgorithm", 44usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).EncryptAlgorithm)(this, plencryptalg), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEncryptAlgorithm(this: *mut core::ffi::c_void, lencryptalg: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SetEncryptAlgorithm", 45usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SetEncryptAlgorithm)(this, lencryptalg), |result| Some(*result))
+        }
+        unsafe extern "system" fn SentTime(this: *mut core::ffi::c_void, pvarsenttime: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SentTime", 46usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SentTime)(this, pvarsenttime), |result| Some(*result))
+        }
+        unsafe extern "system" fn ArrivedTime(this: *mut core::ffi::c_void, plarrivedtime: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "ArrivedTime", 47usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).ArrivedTime)(this, plarrivedtime), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DestinationQueueInfo(this: *mut core::ffi::c_void, ppqinfodest: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "DestinationQueueInfo", 48usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).DestinationQueueInfo)(this, ppqinfodest), |result| Some(*result))
+        }
+        unsafe extern "system" fn SenderCertificate(this: *mut core::ffi::c_void, pvarsendercert: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SenderCertificate", 49usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SenderCertificate)(this, pvarsendercert), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSenderCertificate(this: *mut core::ffi::c_void, varsendercert: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SetSenderCertificate", 50usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SetSenderCertificate)(this, varsendercert), |result| Some(*result))
+        }
+        unsafe extern "system" fn SenderId(this: *mut core::ffi::c_void, pvarsenderid: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SenderId", 51usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SenderId)(this, pvarsenderid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SenderIdType(this: *mut core::ffi::c_void, plsenderidtype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SenderIdType", 52usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SenderIdType)(this, plsenderidtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSenderIdType(this: *mut core::ffi::c_void, lsenderidtype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SetSenderIdType", 53usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SetSenderIdType)(this, lsenderidtype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Send(this: *mut core::ffi::c_void, destinationqueue: *mut core::ffi::c_void, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "Send", 54usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).Send)(this, destinationqueue, transaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttachCurrentSecurityContext(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "AttachCurrentSecurityContext", 55usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).AttachCurrentSecurityContext)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SenderVersion(this: *mut core::ffi::c_void, plsenderversion: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SenderVersion", 56usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SenderVersion)(this, plsenderversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn Extension(this: *mut core::ffi::c_void, pvarextension: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "Extension", 57usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).Extension)(this, pvarextension), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExtension(this: *mut core::ffi::c_void, varextension: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SetExtension", 58usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SetExtension)(this, varextension), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectorTypeGuid(this: *mut core::ffi::c_void, pbstrguidconnectortype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "ConnectorTypeGuid", 59usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).ConnectorTypeGuid)(this, pbstrguidconnectortype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConnectorTypeGuid(this: *mut core::ffi::c_void, bstrguidconnectortype: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SetConnectorTypeGuid", 60usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SetConnectorTypeGuid)(this, bstrguidconnectortype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn TransactionStatusQueueInfo(this: *mut core::ffi::c_void, ppqinfoxactstatus: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "TransactionStatusQueueInfo", 61usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).TransactionStatusQueueInfo)(this, ppqinfoxactstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestinationSymmetricKey(this: *mut core::ffi::c_void, pvardestsymmkey: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "DestinationSymmetricKey", 62usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).DestinationSymmetricKey)(this, pvardestsymmkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDestinationSymmetricKey(this: *mut core::ffi::c_void, vardestsymmkey: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SetDestinationSymmetricKey", 63usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SetDestinationSymmetricKey)(this, vardestsymmkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn Signature(this: *mut core::ffi::c_void, pvarsignature: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "Signature", 64usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).Signature)(this, pvarsignature), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSignature(this: *mut core::ffi::c_void, varsignature: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SetSignature", 65usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SetSignature)(this, varsignature), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthenticationProviderType(this: *mut core::ffi::c_void, plauthprovtype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "AuthenticationProviderType", 66usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).AuthenticationProviderType)(this, plauthprovtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthenticationProviderType(this: *mut core::ffi::c_void, lauthprovtype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SetAuthenticationProviderType", 67usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SetAuthenticationProviderType)(this, lauthprovtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthenticationProviderName(this: *mut core::ffi::c_void, pbstrauthprovname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "AuthenticationProviderName", 68usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).AuthenticationProviderName)(this, pbstrauthprovname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthenticationProviderName(this: *mut core::ffi::c_void, bstrauthprovname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SetAuthenticationProviderName", 69usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SetAuthenticationProviderName)(this, bstrauthprovname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSenderId(this: *mut core::ffi::c_void, varsenderid: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SetSenderId", 70usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SetSenderId)(this, varsenderid), |result| Some(*result))
+        }
+        unsafe extern "system" fn MsgClass(this: *mut core::ffi::c_void, plmsgclass: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "MsgClass", 71usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).MsgClass)(this, plmsgclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMsgClass(this: *mut core::ffi::c_void, lmsgclass: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SetMsgClass", 72usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SetMsgClass)(this, lmsgclass), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, ppcolproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "Properties", 73usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).Properties)(this, ppcolproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransactionId(this: *mut core::ffi::c_void, pvarxactid: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "TransactionId", 74usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).TransactionId)(this, pvarxactid), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFirstInTransaction(this: *mut core::ffi::c_void, pisfirstinxact: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "IsFirstInTransaction", 75usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).IsFirstInTransaction)(this, pisfirstinxact), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLastInTransaction(this: *mut core::ffi::c_void, pislastinxact: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "IsLastInTransaction", 76usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).IsLastInTransaction)(this, pislastinxact), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ResponseQueueInfo_v2(this: *mut core::ffi::c_void, ppqinforesponse: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "ResponseQueueInfo_v2", 77usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).ResponseQueueInfo_v2)(this, ppqinforesponse), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_ResponseQueueInfo_v2(this: *mut core::ffi::c_void, pqinforesponse: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "putref_ResponseQueueInfo_v2", 78usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).putref_ResponseQueueInfo_v2)(this, pqinforesponse), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AdminQueueInfo_v2(this: *mut core::ffi::c_void, ppqinfoadmin: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "AdminQueueInfo_v2", 79usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).AdminQueueInfo_v2)(this, ppqinfoadmin), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_AdminQueueInfo_v2(this: *mut core::ffi::c_void, pqinfoadmin: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "putref_AdminQueueInfo_v2", 80usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).putref_AdminQueueInfo_v2)(this, pqinfoadmin), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReceivedAuthenticationLevel(this: *mut core::ffi::c_void, psreceivedauthenticationlevel: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "ReceivedAuthenticationLevel", 81usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).ReceivedAuthenticationLevel)(this, psreceivedauthenticationlevel), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ResponseQueueInfo(this: *mut core::ffi::c_void, ppqinforesponse: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "ResponseQueueInfo", 82usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).ResponseQueueInfo)(this, ppqinforesponse), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_ResponseQueueInfo(this: *mut core::ffi::c_void, pqinforesponse: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "putref_ResponseQueueInfo", 83usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).putref_ResponseQueueInfo)(this, pqinforesponse), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AdminQueueInfo(this: *mut core::ffi::c_void, ppqinfoadmin: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "AdminQueueInfo", 84usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).AdminQueueInfo)(this, ppqinfoadmin), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_AdminQueueInfo(this: *mut core::ffi::c_void, pqinfoadmin: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "putref_AdminQueueInfo", 85usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).putref_AdminQueueInfo)(this, pqinfoadmin), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ResponseDestination(this: *mut core::ffi::c_void, ppdestresponse: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "ResponseDestination", 86usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).ResponseDestination)(this, ppdestresponse), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_ResponseDestination(this: *mut core::ffi::c_void, pdestresponse: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "putref_ResponseDestination", 87usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).putref_ResponseDestination)(this, pdestresponse), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Destination(this: *mut core::ffi::c_void, ppdestdestination: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "Destination", 88usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).Destination)(this, ppdestdestination), |result| Some(*result))
+        }
+        unsafe extern "system" fn LookupId(this: *mut core::ffi::c_void, pvarlookupid: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "LookupId", 89usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).LookupId)(this, pvarlookupid), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAuthenticated2(this: *mut core::ffi::c_void, pisauthenticated: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "IsAuthenticated2", 90usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).IsAuthenticated2)(this, pisauthenticated), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFirstInTransaction2(this: *mut core::ffi::c_void, pisfirstinxact: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "IsFirstInTransaction2", 91usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).IsFirstInTransaction2)(this, pisfirstinxact), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLastInTransaction2(this: *mut core::ffi::c_void, pislastinxact: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "IsLastInTransaction2", 92usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).IsLastInTransaction2)(this, pislastinxact), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttachCurrentSecurityContext2(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "AttachCurrentSecurityContext2", 93usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).AttachCurrentSecurityContext2)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SoapEnvelope(this: *mut core::ffi::c_void, pbstrsoapenvelope: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SoapEnvelope", 94usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SoapEnvelope)(this, pbstrsoapenvelope), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompoundMessage(this: *mut core::ffi::c_void, pvarcompoundmessage: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "CompoundMessage", 95usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).CompoundMessage)(this, pvarcompoundmessage), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSoapHeader(this: *mut core::ffi::c_void, bstrsoapheader: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SetSoapHeader", 96usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SetSoapHeader)(this, bstrsoapheader), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSoapBody(this: *mut core::ffi::c_void, bstrsoapbody: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage3", "SetSoapBody", 97usize, |this| ((**(this as *const *const IMSMQMessage3_Vtbl)).SetSoapBody)(this, bstrsoapbody), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Class,
+            PrivLevel,
+            SetPrivLevel,
+            AuthLevel,
+            SetAuthLevel,
+            IsAuthenticated,
+            Delivery,
+            SetDelivery,
+            Trace,
+            SetTrace,
+            Priority,
+            SetPriority,
+            Journal,
+            SetJournal,
+            #[cfg(feature = "Win32_System_Com")]
+            ResponseQueueInfo_v1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ResponseQueueInfo_v1: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_ResponseQueueInfo_v1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_ResponseQueueInfo_v1: 0,
+            AppSpecific,
+            SetAppSpecific,
+            SourceMachineGuid,
+            BodyLength,
+            Body,
+            SetBody,
+            #[cfg(feature = "Win32_System_Com")]
+            AdminQueueInfo_v1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AdminQueueInfo_v1: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_AdminQueueInfo_v1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_AdminQueueInfo_v1: 0,
+            Id,
+            CorrelationId,
+            SetCorrelationId,
+            Ack,
+            SetAck,
+            Label,
+            SetLabel,
+            MaxTimeToReachQueue,
+            SetMaxTimeToReachQueue,
+            MaxTimeToReceive,
+            SetMaxTimeToReceive,
+            HashAlgorithm,
+            SetHashAlgorithm,
+            EncryptAlgorithm,
+            SetEncryptAlgorithm,
+            SentTime,
+            ArrivedTime,
+            #[cfg(feature = "Win32_System_Com")]
+            DestinationQueueInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DestinationQueueInfo: 0,
+            SenderCertificate,
+            SetSenderCertificate,
+            SenderId,
+            SenderIdType,
+            SetSenderIdType,
+            #[cfg(feature = "Win32_System_Com")]
+            Send,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Send: 0,
+            AttachCurrentSecurityContext,
+            SenderVersion,
+            Extension,
+            SetExtension,
+            ConnectorTypeGuid,
+            SetConnectorTypeGuid,
+            #[cfg(feature = "Win32_System_Com")]
+            TransactionStatusQueueInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            TransactionStatusQueueInfo: 0,
+            DestinationSymmetricKey,
+            SetDestinationSymmetricKey,
+            Signature,
+            SetSignature,
+            AuthenticationProviderType,
+            SetAuthenticationProviderType,
+            AuthenticationProviderName,
+            SetAuthenticationProviderName,
+            SetSenderId,
+            MsgClass,
+            SetMsgClass,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+            TransactionId,
+            IsFirstInTransaction,
+            IsLastInTransaction,
+            #[cfg(feature = "Win32_System_Com")]
+            ResponseQueueInfo_v2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ResponseQueueInfo_v2: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_ResponseQueueInfo_v2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_ResponseQueueInfo_v2: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AdminQueueInfo_v2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AdminQueueInfo_v2: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_AdminQueueInfo_v2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_AdminQueueInfo_v2: 0,
+            ReceivedAuthenticationLevel,
+            #[cfg(feature = "Win32_System_Com")]
+            ResponseQueueInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ResponseQueueInfo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_ResponseQueueInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_ResponseQueueInfo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AdminQueueInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AdminQueueInfo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_AdminQueueInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_AdminQueueInfo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ResponseDestination,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ResponseDestination: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_ResponseDestination,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_ResponseDestination: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Destination,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Destination: 0,
+            LookupId,
+            IsAuthenticated2,
+            IsFirstInTransaction2,
+            IsLastInTransaction2,
+            AttachCurrentSecurityContext2,
+            SoapEnvelope,
+            CompoundMessage,
+            SetSoapHeader,
+            SetSoapBody,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQMessage3 as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQMessage4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Class(this: *mut core::ffi::c_void, plclass: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "Class", 7usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).Class)(this, plclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrivLevel(this: *mut core::ffi::c_void, plprivlevel: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "PrivLevel", 8usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).PrivLevel)(this, plprivlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivLevel(this: *mut core::ffi::c_void, lprivlevel: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SetPrivLevel", 9usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SetPrivLevel)(this, lprivlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthLevel(this: *mut core::ffi::c_void, plauthlevel: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "AuthLevel", 10usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).AuthLevel)(this, plauthlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthLevel(this: *mut core::ffi::c_void, lauthlevel: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SetAuthLevel", 11usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SetAuthLevel)(this, lauthlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAuthenticated(this: *mut core::ffi::c_void, pisauthenticated: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "IsAuthenticated", 12usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).IsAuthenticated)(this, pisauthenticated), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delivery(this: *mut core::ffi::c_void, pldelivery: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "Delivery", 13usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).Delivery)(this, pldelivery), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDelivery(this: *mut core::ffi::c_void, ldelivery: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SetDelivery", 14usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SetDelivery)(this, ldelivery), |result| Some(*result))
+        }
+        unsafe extern "system" fn Trace(this: *mut core::ffi::c_void, pltrace: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "Trace", 15usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).Trace)(this, pltrace), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTrace(this: *mut core::ffi::c_void, ltrace: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SetTrace", 16usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SetTrace)(this, ltrace), |result| Some(*result))
+        }
+        unsafe extern "system" fn Priority(this: *mut core::ffi::c_void, plpriority: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "Priority", 17usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).Priority)(this, plpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPriority(this: *mut core::ffi::c_void, lpriority: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SetPriority", 18usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SetPriority)(this, lpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn Journal(this: *mut core::ffi::c_void, pljournal: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "Journal", 19usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).Journal)(this, pljournal), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetJournal(this: *mut core::ffi::c_void, ljournal: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SetJournal", 20usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SetJournal)(this, ljournal), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ResponseQueueInfo_v1(this: *mut core::ffi::c_void, ppqinforesponse: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "ResponseQueueInfo_v1", 21usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).ResponseQueueInfo_v1)(this, ppqinforesponse), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_ResponseQueueInfo_v1(this: *mut core::ffi::c_void, pqinforesponse: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "putref_ResponseQueueInfo_v1", 22usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).putref_ResponseQueueInfo_v1)(this, pqinforesponse), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppSpecific(this: *mut core::ffi::c_void, plappspecific: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "AppSpecific", 23usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).AppSpecific)(this, plappspecific), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAppSpecific(this: *mut core::ffi::c_void, lappspecific: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SetAppSpecific", 24usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SetAppSpecific)(this, lappspecific), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceMachineGuid(this: *mut core::ffi::c_void, pbstrguidsrcmachine: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SourceMachineGuid", 25usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SourceMachineGuid)(this, pbstrguidsrcmachine), |result| Some(*result))
+        }
+        unsafe extern "system" fn BodyLength(this: *mut core::ffi::c_void, pcbbody: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "BodyLength", 26usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).BodyLength)(this, pcbbody), |result| Some(*result))
+        }
+        unsafe extern "system" fn Body(this: *mut core::ffi::c_void, pvarbody: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "Body", 27usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).Body)(this, pvarbody), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBody(this: *mut core::ffi::c_void, varbody: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SetBody", 28usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SetBody)(this, varbody), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AdminQueueInfo_v1(this: *mut core::ffi::c_void, ppqinfoadmin: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "AdminQueueInfo_v1", 29usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).AdminQueueInfo_v1)(this, ppqinfoadmin), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_AdminQueueInfo_v1(this: *mut core::ffi::c_void, pqinfoadmin: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "putref_AdminQueueInfo_v1", 30usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).putref_AdminQueueInfo_v1)(this, pqinfoadmin), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, pvarmsgid: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "Id", 31usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).Id)(this, pvarmsgid), |result| Some(*result))
+        }
+        unsafe extern "system" fn CorrelationId(this: *mut core::ffi::c_void, pvarmsgid: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "CorrelationId", 32usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).CorrelationId)(this, pvarmsgid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCorrelationId(this: *mut core::ffi::c_void, varmsgid: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SetCorrelationId", 33usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SetCorrelationId)(this, varmsgid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ack(this: *mut core::ffi::c_void, plack: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "Ack", 34usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).Ack)(this, plack), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAck(this: *mut core::ffi::c_void, lack: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SetAck", 35usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SetAck)(this, lack), |result| Some(*result))
+        }
+        unsafe extern "system" fn Label(this: *mut core::ffi::c_void, pbstrlabel: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "Label", 36usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).Label)(this, pbstrlabel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLabel(this: *mut core::ffi::c_void, bstrlabel: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SetLabel", 37usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SetLabel)(this, bstrlabel), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxTimeToReachQueue(this: *mut core::ffi::c_void, plmaxtimetoreachqueue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "MaxTimeToReachQueue", 38usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).MaxTimeToReachQueue)(this, plmaxtimetoreachqueue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxTimeToReachQueue(this: *mut core::ffi::c_void, lmaxtimetoreachqueue: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SetMaxTimeToReachQueue", 39usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SetMaxTimeToReachQueue)(this, lmaxtimetoreachqueue), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxTimeToReceive(this: *mut core::ffi::c_void, plmaxtimetoreceive: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "MaxTimeToReceive", 40usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).MaxTimeToReceive)(this, plmaxtimetoreceive), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxTimeToReceive(this: *mut core::ffi::c_void, lmaxtimetoreceive: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SetMaxTimeToReceive", 41usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SetMaxTimeToReceive)(this, lmaxtimetoreceive), |result| Some(*result))
+        }
+        unsafe extern "system" fn HashAlgorithm(this: *mut core::ffi::c_void, plhashalg: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "HashAlgorithm", 42usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).HashAlgorithm)(this, plhashalg), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHashAlgorithm(this: *mut core::ffi::c_void, lhashalg: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SetHashAlgorithm", 43usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SetHashAlgorithm)(this, lhashalg), |result| Some(*result))
+        }
+        unsafe extern "system" fn EncryptAlgorithm(this: *mut core::ffi::c_void, plencryptalg: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "EncryptAlgorithm", 44usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).EncryptAlgorithm)(this, plencryptalg), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEncryptAlgorithm(this: *mut core::ffi::c_void, lencryptalg: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SetEncryptAlgorithm", 45usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SetEncryptAlgorithm)(this, lencryptalg), |result| Some(*result))
+        }
+        unsafe extern "system" fn SentTime(this: *mut core::ffi::c_void, pvarsenttime: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SentTime", 46usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SentTime)(this, pvarsenttime), |result| Some(*result))
+        }
+        unsafe extern "system" fn ArrivedTime(this: *mut core::ffi::c_void, plarrivedtime: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "ArrivedTime", 47usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).ArrivedTime)(this, plarrivedtime), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DestinationQueueInfo(this: *mut core::ffi::c_void, ppqinfodest: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "DestinationQueueInfo", 48usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).DestinationQueueInfo)(this, ppqinfodest), |result| Some(*result))
+        }
+        unsafe extern "system" fn SenderCertificate(this: *mut core::ffi::c_void, pvarsendercert: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SenderCertificate", 49usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SenderCertificate)(this, pvarsendercert), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSenderCertificate(this: *mut core::ffi::c_void, varsendercert: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SetSenderCertificate", 50usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SetSenderCertificate)(this, varsendercert), |result| Some(*result))
+        }
+        unsafe extern "system" fn SenderId(this: *mut core::ffi::c_void, pvarsenderid: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SenderId", 51usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SenderId)(this, pvarsenderid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SenderIdType(this: *mut core::ffi::c_void, plsenderidtype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SenderIdType", 52usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SenderIdType)(this, plsenderidtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSenderIdType(this: *mut core::ffi::c_void, lsenderidtype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SetSenderIdType", 53usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SetSenderIdType)(this, lsenderidtype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Send(this: *mut core::ffi::c_void, destinationqueue: *mut core::ffi::c_void, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "Send", 54usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).Send)(this, destinationqueue, transaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttachCurrentSecurityContext(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "AttachCurrentSecurityContext", 55usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).AttachCurrentSecurityContext)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SenderVersion(this: *mut core::ffi::c_void, plsenderversion: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SenderVersion", 56usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SenderVersion)(this, plsenderversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn Extension(this: *mut core::ffi::c_void, pvarextension: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "Extension", 57usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).Extension)(this, pvarextension), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExtension(this: *mut core::ffi::c_void, varextension: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SetExtension", 58usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SetExtension)(this, varextension), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectorTypeGuid(this: *mut core::ffi::c_void, pbstrguidconnectortype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "ConnectorTypeGuid", 59usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).ConnectorTypeGuid)(this, pbstrguidconnectortype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConnectorTypeGuid(this: *mut core::ffi::c_void, bstrguidconnectortype: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SetConnectorTypeGuid", 60usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SetConnectorTypeGuid)(this, bstrguidconnectortype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn TransactionStatusQueueInfo(this: *mut core::ffi::c_void, ppqinfoxactstatus: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "TransactionStatusQueueInfo", 61usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).TransactionStatusQueueInfo)(this, ppqinfoxactstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestinationSymmetricKey(this: *mut core::ffi::c_void, pvardestsymmkey: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "DestinationSymmetricKey", 62usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).DestinationSymmetricKey)(this, pvardestsymmkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDestinationSymmetricKey(this: *mut core::ffi::c_void, vardestsymmkey: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SetDestinationSymmetricKey", 63usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SetDestinationSymmetricKey)(this, vardestsymmkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn Signature(this: *mut core::ffi::c_void, pvarsignature: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "Signature", 64usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).Signature)(this, pvarsignature), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSignature(this: *mut core::ffi::c_void, varsignature: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SetSignature", 65usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SetSignature)(this, varsignature), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthenticationProviderType(this: *mut core::ffi::c_void, plauthprovtype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "AuthenticationProviderType", 66usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).AuthenticationProviderType)(this, plauthprovtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthenticationProviderType(this: *mut core::ffi::c_void, lauthprovtype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SetAuthenticationProviderType", 67usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SetAuthenticationProviderType)(this, lauthprovtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthenticationProviderName(this: *mut core::ffi::c_void, pbstrauthprovname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "AuthenticationProviderName", 68usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).AuthenticationProviderName)(this, pbstrauthprovname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthenticationProviderName(this: *mut core::ffi::c_void, bstrauthprovname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SetAuthenticationProviderName", 69usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SetAuthenticationProviderName)(this, bstrauthprovname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSenderId(this: *mut core::ffi::c_void, varsenderid: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SetSenderId", 70usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SetSenderId)(this, varsenderid), |result| Some(*result))
+        }
+        unsafe extern "system" fn MsgClass(this: *mut core::ffi::c_void, plmsgclass: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "MsgClass", 71usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).MsgClass)(this, plmsgclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMsgClass(this: *mut core::ffi::c_void, lmsgclass: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SetMsgClass", 72usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SetMsgClass)(this, lmsgclass), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, ppcolproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "Properties", 73usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).Properties)(this, ppcolproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransactionId(this: *mut core::ffi::c_void, pvarxactid: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "TransactionId", 74usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).TransactionId)(this, pvarxactid), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFirstInTransaction(this: *mut core::ffi::c_void, pisfirstinxact: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "IsFirstInTransaction", 75usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).IsFirstInTransaction)(this, pisfirstinxact), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLastInTransaction(this: *mut core::ffi::c_void, pislastinxact: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "IsLastInTransaction", 76usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).IsLastInTransaction)(this, pislastinxact), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ResponseQueueInfo_v2(this: *mut core::ffi::c_void, ppqinforesponse: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "ResponseQueueInfo_v2", 77usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).ResponseQueueInfo_v2)(this, ppqinforesponse), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_ResponseQueueInfo_v2(this: *mut core::ffi::c_void, pqinforesponse: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "putref_ResponseQueueInfo_v2", 78usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).putref_ResponseQueueInfo_v2)(this, pqinforesponse), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AdminQueueInfo_v2(this: *mut core::ffi::c_void, ppqinfoadmin: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "AdminQueueInfo_v2", 79usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).AdminQueueInfo_v2)(this, ppqinfoadmin), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_AdminQueueInfo_v2(this: *mut core::ffi::c_void, pqinfoadmin: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "putref_AdminQueueInfo_v2", 80usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).putref_AdminQueueInfo_v2)(this, pqinfoadmin), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReceivedAuthenticationLevel(this: *mut core::ffi::c_void, psreceivedauthenticationlevel: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "ReceivedAuthenticationLevel", 81usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).ReceivedAuthenticationLevel)(this, psreceivedauthenticationlevel), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ResponseQueueInfo(this: *mut core::ffi::c_void, ppqinforesponse: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "ResponseQueueInfo", 82usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).ResponseQueueInfo)(this, ppqinforesponse), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_ResponseQueueInfo(this: *mut core::ffi::c_void, pqinforesponse: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "putref_ResponseQueueInfo", 83usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).putref_ResponseQueueInfo)(this, pqinforesponse), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AdminQueueInfo(this: *mut core::ffi::c_void, ppqinfoadmin: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "AdminQueueInfo", 84usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).AdminQueueInfo)(this, ppqinfoadmin), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_AdminQueueInfo(this: *mut core::ffi::c_void, pqinfoadmin: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "putref_AdminQueueInfo", 85usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).putref_AdminQueueInfo)(this, pqinfoadmin), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ResponseDestination(this: *mut core::ffi::c_void, ppdestresponse: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "ResponseDestination", 86usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).ResponseDestination)(this, ppdestresponse), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_ResponseDestination(this: *mut core::ffi::c_void, pdestresponse: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "putref_ResponseDestination", 87usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).putref_ResponseDestination)(this, pdestresponse), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Destination(this: *mut core::ffi::c_void, ppdestdestination: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "Destination", 88usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).Destination)(this, ppdestdestination), |result| Some(*result))
+        }
+        unsafe extern "system" fn LookupId(this: *mut core::ffi::c_void, pvarlookupid: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "LookupId", 89usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).LookupId)(this, pvarlookupid), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAuthenticated2(this: *mut core::ffi::c_void, pisauthenticated: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "IsAuthenticated2", 90usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).IsAuthenticated2)(this, pisauthenticated), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFirstInTransaction2(this: *mut core::ffi::c_void, pisfirstinxact: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "IsFirstInTransaction2", 91usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).IsFirstInTransaction2)(this, pisfirstinxact), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLastInTransaction2(this: *mut core::ffi::c_void, pislastinxact: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "IsLastInTransaction2", 92usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).IsLastInTransaction2)(this, pislastinxact), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttachCurrentSecurityContext2(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "AttachCurrentSecurityContext2", 93usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).AttachCurrentSecurityContext2)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SoapEnvelope(this: *mut core::ffi::c_void, pbstrsoapenvelope: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SoapEnvelope", 94usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SoapEnvelope)(this, pbstrsoapenvelope), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompoundMessage(this: *mut core::ffi::c_void, pvarcompoundmessage: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "CompoundMessage", 95usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).CompoundMessage)(this, pvarcompoundmessage), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSoapHeader(this: *mut core::ffi::c_void, bstrsoapheader: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SetSoapHeader", 96usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SetSoapHeader)(this, bstrsoapheader), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSoapBody(this: *mut core::ffi::c_void, bstrsoapbody: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQMessage4", "SetSoapBody", 97usize, |this| ((**(this as *const *const IMSMQMessage4_Vtbl)).SetSoapBody)(this, bstrsoapbody), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Class,
+            PrivLevel,
+            SetPrivLevel,
+            AuthLevel,
+            SetAuthLevel,
+            IsAuthenticated,
+            Delivery,
+            SetDelivery,
+            Trace,
+            SetTrace,
+            Priority,
+            SetPriority,
+            Journal,
+            SetJournal,
+            #[cfg(feature = "Win32_System_Com")]
+            ResponseQueueInfo_v1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ResponseQueueInfo_v1: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_ResponseQueueInfo_v1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_ResponseQueueInfo_v1: 0,
+            AppSpecific,
+            SetAppSpecific,
+            SourceMachineGuid,
+            BodyLength,
+            Body,
+            SetBody,
+            #[cfg(feature = "Win32_System_Com")]
+            AdminQueueInfo_v1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AdminQueueInfo_v1: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_AdminQueueInfo_v1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_AdminQueueInfo_v1: 0,
+            Id,
+            CorrelationId,
+            SetCorrelationId,
+            Ack,
+            SetAck,
+            Label,
+            SetLabel,
+            MaxTimeToReachQueue,
+            SetMaxTimeToReachQueue,
+            MaxTimeToReceive,
+            SetMaxTimeToReceive,
+            HashAlgorithm,
+            SetHashAlgorithm,
+            EncryptAlgorithm,
+            SetEncryptAlgorithm,
+            SentTime,
+            ArrivedTime,
+            #[cfg(feature = "Win32_System_Com")]
+            DestinationQueueInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DestinationQueueInfo: 0,
+            SenderCertificate,
+            SetSenderCertificate,
+            SenderId,
+            SenderIdType,
+            SetSenderIdType,
+            #[cfg(feature = "Win32_System_Com")]
+            Send,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Send: 0,
+            AttachCurrentSecurityContext,
+            SenderVersion,
+            Extension,
+            SetExtension,
+            ConnectorTypeGuid,
+            SetConnectorTypeGuid,
+            #[cfg(feature = "Win32_System_Com")]
+            TransactionStatusQueueInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            TransactionStatusQueueInfo: 0,
+            DestinationSymmetricKey,
+            SetDestinationSymmetricKey,
+            Signature,
+            SetSignature,
+            AuthenticationProviderType,
+            SetAuthenticationProviderType,
+            AuthenticationProviderName,
+            SetAuthenticationProviderName,
+            SetSenderId,
+            MsgClass,
+            SetMsgClass,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+            TransactionId,
+            IsFirstInTransaction,
+            IsLastInTransaction,
+            #[cfg(feature = "Win32_System_Com")]
+            ResponseQueueInfo_v2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ResponseQueueInfo_v2: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_ResponseQueueInfo_v2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_ResponseQueueInfo_v2: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AdminQueueInfo_v2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AdminQueueInfo_v2: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_AdminQueueInfo_v2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_AdminQueueInfo_v2: 0,
+            ReceivedAuthenticationLevel,
+            #[cfg(feature = "Win32_System_Com")]
+            ResponseQueueInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ResponseQueueInfo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_ResponseQueueInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_ResponseQueueInfo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AdminQueueInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AdminQueueInfo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_AdminQueueInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_AdminQueueInfo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ResponseDestination,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ResponseDestination: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_ResponseDestination,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_ResponseDestination: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Destination,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Destination: 0,
+            LookupId,
+            IsAuthenticated2,
+            IsFirstInTransaction2,
+            IsLastInTransaction2,
+            AttachCurrentSecurityContext2,
+            SoapEnvelope,
+            CompoundMessage,
+            SetSoapHeader,
+            SetSoapBody,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQMessage4 as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQOutgoingQueueManagement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, plstate: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQOutgoingQueueManagement", "State", 16usize, |this| ((**(this as *const *const IMSMQOutgoingQueueManagement_Vtbl)).State)(this, plstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn NextHops(this: *mut core::ffi::c_void, pvnexthops: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQOutgoingQueueManagement", "NextHops", 17usize, |this| ((**(this as *const *const IMSMQOutgoingQueueManagement_Vtbl)).NextHops)(this, pvnexthops), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EodGetSendInfo(this: *mut core::ffi::c_void, ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQOutgoingQueueManagement", "EodGetSendInfo", 18usize, |this| ((**(this as *const *const IMSMQOutgoingQueueManagement_Vtbl)).EodGetSendInfo)(this, ppcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQOutgoingQueueManagement", "Resume", 19usize, |this| ((**(this as *const *const IMSMQOutgoingQueueManagement_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQOutgoingQueueManagement", "Pause", 20usize, |this| ((**(this as *const *const IMSMQOutgoingQueueManagement_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EodResend(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQOutgoingQueueManagement", "EodResend", 21usize, |this| ((**(this as *const *const IMSMQOutgoingQueueManagement_Vtbl)).EodResend)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IMSMQManagement_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            State,
+            NextHops,
+            #[cfg(feature = "Win32_System_Com")]
+            EodGetSendInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EodGetSendInfo: 0,
+            Resume,
+            Pause,
+            EodResend,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQOutgoingQueueManagement as windows_core::Interface>::IID || <IMSMQManagement_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQPrivateDestination_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handle(this: *mut core::ffi::c_void, pvarhandle: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQPrivateDestination", "Handle", 7usize, |this| ((**(this as *const *const IMSMQPrivateDestination_Vtbl)).Handle)(this, pvarhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandle(this: *mut core::ffi::c_void, varhandle: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQPrivateDestination", "SetHandle", 8usize, |this| ((**(this as *const *const IMSMQPrivateDestination_Vtbl)).SetHandle)(this, varhandle), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Handle, SetHandle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQPrivateDestination as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQPrivateEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Hwnd(this: *mut core::ffi::c_void, phwnd: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQPrivateEvent", "Hwnd", 7usize, |this| ((**(this as *const *const IMSMQPrivateEvent_Vtbl)).Hwnd)(this, phwnd), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn FireArrivedEvent(this: *mut core::ffi::c_void, pq: *mut core::ffi::c_void, msgcursor: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQPrivateEvent", "FireArrivedEvent", 8usize, |this| ((**(this as *const *const IMSMQPrivateEvent_Vtbl)).FireArrivedEvent)(this, pq, msgcursor), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn FireArrivedErrorEvent(this: *mut core::ffi::c_void, pq: *mut core::ffi::c_void, hrstatus: windows_core::HRESULT, msgcursor: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQPrivateEvent", "FireArrivedErrorEvent", 9usize, |this| ((**(this as *const *const IMSMQPrivateEvent_Vtbl)).FireArrivedErrorEvent)(this, pq, hrstatus, msgcursor), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Hwnd,
+            #[cfg(feature = "Win32_System_Com")]
+            FireArrivedEvent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            FireArrivedEvent: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            FireArrivedErrorEvent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            FireArrivedErrorEvent: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQPrivateEvent as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQQuery_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn LookupQueue(this: *mut core::ffi::c_void, queueguid: *const core::mem::MaybeUninit<windows_core::VARIANT>, servicetypeguid: *const core::mem::MaybeUninit<windows_core::VARIANT>, label: *const core::mem::MaybeUninit<windows_core::VARIANT>, createtime: *const core::mem::MaybeUninit<windows_core::VARIANT>, modifytime: *const core::mem::MaybeUninit<windows_core::VARIANT>, relservicetype: *const core::mem::MaybeUninit<windows_core::VARIANT>, rellabel: *const core::mem::MaybeUninit<windows_core::VARIANT>, relcreatetime: *const core::mem::MaybeUninit<windows_core::VARIANT>, relmodifytime: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppqinfos: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQuery", "LookupQueue", 7usize, |this| ((**(this as *const *const IMSMQQuery_Vtbl)).LookupQueue)(this, queueguid, servicetypeguid, label, createtime, modifytime, relservicetype, rellabel, relcreatetime, relmodifytime, ppqinfos), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            LookupQueue,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            LookupQueue: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQQuery as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQQuery2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn LookupQueue(this: *mut core::ffi::c_void, queueguid: *const core::mem::MaybeUninit<windows_core::VARIANT>, servicetypeguid: *const core::mem::MaybeUninit<windows_core::VARIANT>, label: *const core::mem::MaybeUninit<windows_core::VARIANT>, createtime: *const core::mem::MaybeUninit<windows_core::VARIANT>, modifytime: *const core::mem::MaybeUninit<windows_core::VARIANT>, relservicetype: *const core::mem::MaybeUninit<windows_core::VARIANT>, rellabel: *const core::mem::MaybeUninit<windows_core::VARIANT>, relcreatetime: *const core::mem::MaybeUninit<windows_core::VARIANT>, relmodifytime: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppqinfos: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQuery2", "LookupQueue", 7usize, |this| ((**(this as *const *const IMSMQQuery2_Vtbl)).LookupQueue)(this, queueguid, servicetypeguid, label, createtime, modifytime, relservicetype, rellabel, relcreatetime, relmodifytime, ppqinfos), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, ppcolproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQuery2", "Properties", 8usize, |this| ((**(this as *const *const IMSMQQuery2_Vtbl)).Properties)(this, ppcolproperties), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            LookupQueue,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            LookupQueue: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQQuery2 as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQQuery3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn LookupQueue_v2(this: *mut core::ffi::c_void, queueguid: *const core::mem::MaybeUninit<windows_core::VARIANT>, servicetypeguid: *const core::mem::MaybeUninit<windows_core::VARIANT>, label: *const core::mem::MaybeUninit<windows_core::VARIANT>, createtime: *const core::mem::MaybeUninit<windows_core::VARIANT>, modifytime: *const core::mem::MaybeUninit<windows_core::VARIANT>, relservicetype: *const core::mem::MaybeUninit<windows_core::VARIANT>, rellabel: *const core::mem::MaybeUninit<windows_core::VARIANT>, relcreatetime: *const core::mem::MaybeUninit<windows_core::VARIANT>, relmodifytime: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppqinfos: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQuery3", "LookupQueue_v2", 7usize, |this| ((**(this as *const *const IMSMQQuery3_Vtbl)).LookupQueue_v2)(this, queueguid, servicetypeguid, label, createtime, modifytime, relservicetype, rellabel, relcreatetime, relmodifytime, ppqinfos), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, ppcolproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQuery3", "Properties", 8usize, |this| ((**(this as *const *const IMSMQQuery3_Vtbl)).Properties)(this, ppcolproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn LookupQueue(
+            this: *mut core::ffi::c_void,
+            queueguid: *const core::mem::MaybeUninit<windows_core::VARIANT>,
+            servicetypeguid: *const core::mem::MaybeUninit<windows_core::VARIANT>,
+            label: *const core::mem::MaybeUninit<windows_core::VARIANT>,
+            createtime: *const core::mem::MaybeUninit<windows_core::VARIANT>,
+            modifytime: *const core::mem::MaybeUninit<windows_core::VARIANT>,
+            relservicetype: *const core::mem::MaybeUninit<windows_core::VARIANT>,
+            rellabel: *const core::mem::MaybeUninit<windows_core::VARIANT>,
+            relcreatetime: *const core::mem::MaybeUninit<windows_core::VARIANT>,
+            relmodifytime: *const core::mem::MaybeUninit<windows_core::VARIANT>,
+            multicastaddress: *const core::mem::MaybeUninit<windows_core::VARIANT>,
+            relmulticastaddress: *const core::mem::MaybeUninit<windows_core::VARIANT>,
+            ppqinfos: *mut *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQuery3", "LookupQueue", 9usize, |this| ((**(this as *const *const IMSMQQuery3_Vtbl)).LookupQueue)(this, queueguid, servicetypeguid, label, createtime, modifytime, relservicetype, rellabel, relcreatetime, relmodifytime, multicastaddress, relmulticastaddress, ppqinfos), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            LookupQueue_v2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            LookupQueue_v2: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            LookupQueue,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            LookupQueue: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQQuery3 as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQQuery4_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn LookupQueue_v2(this: *mut core::ffi::c_void, queueguid: *const core::mem::MaybeUninit<windows_core::VARIANT>, servicetypeguid: *const core::mem::MaybeUninit<windows_core::VARIANT>, label: *const core::mem::MaybeUninit<windows_core::VARIANT>, createtime: *const core::mem::MaybeUninit<windows_core::VARIANT>, modifytime: *const core::mem::MaybeUninit<windows_core::VARIANT>, relservicetype: *const core::mem::MaybeUninit<windows_core::VARIANT>, rellabel: *const core::mem::MaybeUninit<windows_core::VARIANT>, relcreatetime: *const core::mem::MaybeUninit<windows_core::VARIANT>, relmodifytime: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppqinfos: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQuery4", "LookupQueue_v2", 7usize, |this| ((**(this as *const *const IMSMQQuery4_Vtbl)).LookupQueue_v2)(this, queueguid, servicetypeguid, label, createtime, modifytime, relservicetype, rellabel, relcreatetime, relmodifytime, ppqinfos), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, ppcolproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQuery4", "Properties", 8usize, |this| ((**(this as *const *const IMSMQQuery4_Vtbl)).Properties)(this, ppcolproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn LookupQueue(
+            this: *mut core::ffi::c_void,
+            queueguid: *const core::mem::MaybeUninit<windows_core::VARIANT>,
+            servicetypeguid: *const core::mem::MaybeUninit<windows_core::VARIANT>,
+            label: *const core::mem::MaybeUninit<windows_core::VARIANT>,
+            createtime: *const core::mem::MaybeUninit<windows_core::VARIANT>,
+            modifytime: *const core::mem::MaybeUninit<windows_core::VARIANT>,
+            relservicetype: *const core::mem::MaybeUninit<windows_core::VARIANT>,
+            rellabel: *const core::mem::MaybeUninit<windows_core::VARIANT>,
+            relcreatetime: *const core::mem::MaybeUninit<windows_core::VARIANT>,
+            relmodifytime: *const core::mem::MaybeUninit<windows_core::VARIANT>,
+            multicastaddress: *const core::mem::MaybeUninit<windows_core::VARIANT>,
+            relmulticastaddress: *const core::mem::MaybeUninit<windows_core::VARIANT>,
+            ppqinfos: *mut *mut core::ffi::c_void,
+        ) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQuery4", "LookupQueue", 9usize, |this| ((**(this as *const *const IMSMQQuery4_Vtbl)).LookupQueue)(this, queueguid, servicetypeguid, label, createtime, modifytime, relservicetype, rellabel, relcreatetime, relmodifytime, multicastaddress, relmulticastaddress, ppqinfos), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            LookupQueue_v2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            LookupQueue_v2: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            LookupQueue,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            LookupQueue: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQQuery4 as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQQueue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Access(this: *mut core::ffi::c_void, placcess: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue", "Access", 7usize, |this| ((**(this as *const *const IMSMQQueue_Vtbl)).Access)(this, placcess), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShareMode(this: *mut core::ffi::c_void, plsharemode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue", "ShareMode", 8usize, |this| ((**(this as *const *const IMSMQQueue_Vtbl)).ShareMode)(this, plsharemode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn QueueInfo(this: *mut core::ffi::c_void, ppqinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue", "QueueInfo", 9usize, |this| ((**(this as *const *const IMSMQQueue_Vtbl)).QueueInfo)(this, ppqinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn Handle(this: *mut core::ffi::c_void, plhandle: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue", "Handle", 10usize, |this| ((**(this as *const *const IMSMQQueue_Vtbl)).Handle)(this, plhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOpen(this: *mut core::ffi::c_void, pisopen: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue", "IsOpen", 11usize, |this| ((**(this as *const *const IMSMQQueue_Vtbl)).IsOpen)(this, pisopen), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue", "Close", 12usize, |this| ((**(this as *const *const IMSMQQueue_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Receive(this: *mut core::ffi::c_void, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue", "Receive", 13usize, |this| ((**(this as *const *const IMSMQQueue_Vtbl)).Receive)(this, transaction, wantdestinationqueue, wantbody, receivetimeout, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Peek(this: *mut core::ffi::c_void, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue", "Peek", 14usize, |this| ((**(this as *const *const IMSMQQueue_Vtbl)).Peek)(this, wantdestinationqueue, wantbody, receivetimeout, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnableNotification(this: *mut core::ffi::c_void, event: *mut core::ffi::c_void, cursor: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue", "EnableNotification", 15usize, |this| ((**(this as *const *const IMSMQQueue_Vtbl)).EnableNotification)(this, event, cursor, receivetimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue", "Reset", 16usize, |this| ((**(this as *const *const IMSMQQueue_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ReceiveCurrent(this: *mut core::ffi::c_void, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue", "ReceiveCurrent", 17usize, |this| ((**(this as *const *const IMSMQQueue_Vtbl)).ReceiveCurrent)(this, transaction, wantdestinationqueue, wantbody, receivetimeout, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PeekNext(this: *mut core::ffi::c_void, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue", "PeekNext", 18usize, |this| ((**(this as *const *const IMSMQQueue_Vtbl)).PeekNext)(this, wantdestinationqueue, wantbody, receivetimeout, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PeekCurrent(this: *mut core::ffi::c_void, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue", "PeekCurrent", 19usize, |this| ((**(this as *const *const IMSMQQueue_Vtbl)).PeekCurrent)(this, wantdestinationqueue, wantbody, receivetimeout, ppmsg), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Access,
+            ShareMode,
+            #[cfg(feature = "Win32_System_Com")]
+            QueueInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            QueueInfo: 0,
+            Handle,
+            IsOpen,
+            Close,
+            #[cfg(feature = "Win32_System_Com")]
+            Receive,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Receive: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Peek,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Peek: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnableNotification,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnableNotification: 0,
+            Reset,
+            #[cfg(feature = "Win32_System_Com")]
+            ReceiveCurrent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ReceiveCurrent: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PeekNext,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PeekNext: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PeekCurrent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PeekCurrent: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQQueue as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQQueue2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Access(this: *mut core::ffi::c_void, placcess: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue2", "Access", 7usize, |this| ((**(this as *const *const IMSMQQueue2_Vtbl)).Access)(this, placcess), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShareMode(this: *mut core::ffi::c_void, plsharemode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue2", "ShareMode", 8usize, |this| ((**(this as *const *const IMSMQQueue2_Vtbl)).ShareMode)(this, plsharemode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn QueueInfo(this: *mut core::ffi::c_void, ppqinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue2", "QueueInfo", 9usize, |this| ((**(this as *const *const IMSMQQueue2_Vtbl)).QueueInfo)(this, ppqinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn Handle(this: *mut core::ffi::c_void, plhandle: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue2", "Handle", 10usize, |this| ((**(this as *const *const IMSMQQueue2_Vtbl)).Handle)(this, plhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOpen(this: *mut core::ffi::c_void, pisopen: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue2", "IsOpen", 11usize, |this| ((**(this as *const *const IMSMQQueue2_Vtbl)).IsOpen)(this, pisopen), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue2", "Close", 12usize, |this| ((**(this as *const *const IMSMQQueue2_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Receive_v1(this: *mut core::ffi::c_void, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue2", "Receive_v1", 13usize, |this| ((**(this as *const *const IMSMQQueue2_Vtbl)).Receive_v1)(this, transaction, wantdestinationqueue, wantbody, receivetimeout, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Peek_v1(this: *mut core::ffi::c_void, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue2", "Peek_v1", 14usize, |this| ((**(this as *const *const IMSMQQueue2_Vtbl)).Peek_v1)(this, wantdestinationqueue, wantbody, receivetimeout, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnableNotification(this: *mut core::ffi::c_void, event: *mut core::ffi::c_void, cursor: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue2", "EnableNotification", 15usize, |this| ((**(this as *const *const IMSMQQueue2_Vtbl)).EnableNotification)(this, event, cursor, receivetimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue2", "Reset", 16usize, |this| ((**(this as *const *const IMSMQQueue2_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ReceiveCurrent_v1(this: *mut core::ffi::c_void, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue2", "ReceiveCurrent_v1", 17usize, |this| ((**(this as *const *const IMSMQQueue2_Vtbl)).ReceiveCurrent_v1)(this, transaction, wantdestinationqueue, wantbody, receivetimeout, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PeekNext_v1(this: *mut core::ffi::c_void, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue2", "PeekNext_v1", 18usize, |this| ((**(this as *const *const IMSMQQueue2_Vtbl)).PeekNext_v1)(this, wantdestinationqueue, wantbody, receivetimeout, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PeekCurrent_v1(this: *mut core::ffi::c_void, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue2", "PeekCurrent_v1", 19usize, |this| ((**(this as *const *const IMSMQQueue2_Vtbl)).PeekCurrent_v1)(this, wantdestinationqueue, wantbody, receivetimeout, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Receive(this: *mut core::ffi::c_void, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue2", "Receive", 20usize, |this| ((**(this as *const *const IMSMQQueue2_Vtbl)).Receive)(this, transaction, wantdestinationqueue, wantbody, receivetimeout, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Peek(this: *mut core::ffi::c_void, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue2", "Peek", 21usize, |this| ((**(this as *const *const IMSMQQueue2_Vtbl)).Peek)(this, wantdestinationqueue, wantbody, receivetimeout, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ReceiveCurrent(this: *mut core::ffi::c_void, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue2", "ReceiveCurrent", 22usize, |this| ((**(this as *const *const IMSMQQueue2_Vtbl)).ReceiveCurrent)(this, transaction, wantdestinationqueue, wantbody, receivetimeout, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PeekNext(this: *mut core::ffi::c_void, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue2", "PeekNext", 23usize, |this| ((**(this as *const *const IMSMQQueue2_Vtbl)).PeekNext)(this, wantdestinationqueue, wantbody, receivetimeout, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PeekCurrent(this: *mut core::ffi::c_void, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue2", "PeekCurrent", 24usize, |this| ((**(this as *const *const IMSMQQueue2_Vtbl)).PeekCurrent)(this, wantdestinationqueue, wantbody, receivetimeout, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, ppcolproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue2", "Properties", 25usize, |this| ((**(this as *const *const IMSMQQueue2_Vtbl)).Properties)(this, ppcolproperties), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Access,
+            ShareMode,
+            #[cfg(feature = "Win32_System_Com")]
+            QueueInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            QueueInfo: 0,
+            Handle,
+            IsOpen,
+            Close,
+            #[cfg(feature = "Win32_System_Com")]
+            Receive_v1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Receive_v1: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Peek_v1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Peek_v1: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnableNotification,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnableNotification: 0,
+            Reset,
+            #[cfg(feature = "Win32_System_Com")]
+            ReceiveCurrent_v1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ReceiveCurrent_v1: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PeekNext_v1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PeekNext_v1: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PeekCurrent_v1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PeekCurrent_v1: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Receive,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Receive: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Peek,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Peek: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ReceiveCurrent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ReceiveCurrent: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PeekNext,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PeekNext: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PeekCurrent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PeekCurrent: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQQueue2 as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQQueue3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Access(this: *mut core::ffi::c_void, placcess: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "Access", 7usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).Access)(this, placcess), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShareMode(this: *mut core::ffi::c_void, plsharemode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "ShareMode", 8usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).ShareMode)(this, plsharemode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn QueueInfo(this: *mut core::ffi::c_void, ppqinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "QueueInfo", 9usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).QueueInfo)(this, ppqinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn Handle(this: *mut core::ffi::c_void, plhandle: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "Handle", 10usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).Handle)(this, plhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOpen(this: *mut core::ffi::c_void, pisopen: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "IsOpen", 11usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).IsOpen)(this, pisopen), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "Close", 12usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Receive_v1(this: *mut core::ffi::c_void, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "Receive_v1", 13usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).Receive_v1)(this, transaction, wantdestinationqueue, wantbody, receivetimeout, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Peek_v1(this: *mut core::ffi::c_void, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "Peek_v1", 14usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).Peek_v1)(this, wantdestinationqueue, wantbody, receivetimeout, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnableNotification(this: *mut core::ffi::c_void, event: *mut core::ffi::c_void, cursor: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "EnableNotification", 15usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).EnableNotification)(this, event, cursor, receivetimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "Reset", 16usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ReceiveCurrent_v1(this: *mut core::ffi::c_void, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "ReceiveCurrent_v1", 17usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).ReceiveCurrent_v1)(this, transaction, wantdestinationqueue, wantbody, receivetimeout, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PeekNext_v1(this: *mut core::ffi::c_void, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "PeekNext_v1", 18usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).PeekNext_v1)(this, wantdestinationqueue, wantbody, receivetimeout, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PeekCurrent_v1(this: *mut core::ffi::c_void, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "PeekCurrent_v1", 19usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).PeekCurrent_v1)(this, wantdestinationqueue, wantbody, receivetimeout, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Receive(this: *mut core::ffi::c_void, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "Receive", 20usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).Receive)(this, transaction, wantdestinationqueue, wantbody, receivetimeout, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Peek(this: *mut core::ffi::c_void, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "Peek", 21usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).Peek)(this, wantdestinationqueue, wantbody, receivetimeout, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ReceiveCurrent(this: *mut core::ffi::c_void, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "ReceiveCurrent", 22usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).ReceiveCurrent)(this, transaction, wantdestinationqueue, wantbody, receivetimeout, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PeekNext(this: *mut core::ffi::c_void, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "PeekNext", 23usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).PeekNext)(this, wantdestinationqueue, wantbody, receivetimeout, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PeekCurrent(this: *mut core::ffi::c_void, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "PeekCurrent", 24usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).PeekCurrent)(this, wantdestinationqueue, wantbody, receivetimeout, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, ppcolproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "Properties", 25usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).Properties)(this, ppcolproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn Handle2(this: *mut core::ffi::c_void, pvarhandle: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "Handle2", 26usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).Handle2)(this, pvarhandle), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ReceiveByLookupId(this: *mut core::ffi::c_void, lookupid: core::mem::MaybeUninit<windows_core::VARIANT>, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "ReceiveByLookupId", 27usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).ReceiveByLookupId)(this, lookupid, transaction, wantdestinationqueue, wantbody, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ReceiveNextByLookupId(this: *mut core::ffi::c_void, lookupid: core::mem::MaybeUninit<windows_core::VARIANT>, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "ReceiveNextByLookupId", 28usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).ReceiveNextByLookupId)(this, lookupid, transaction, wantdestinationqueue, wantbody, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ReceivePreviousByLookupId(this: *mut core::ffi::c_void, lookupid: core::mem::MaybeUninit<windows_core::VARIANT>, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "ReceivePreviousByLookupId", 29usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).ReceivePreviousByLookupId)(this, lookupid, transaction, wantdestinationqueue, wantbody, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ReceiveFirstByLookupId(this: *mut core::ffi::c_void, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "ReceiveFirstByLookupId", 30usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).ReceiveFirstByLookupId)(this, transaction, wantdestinationqueue, wantbody, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ReceiveLastByLookupId(this: *mut core::ffi::c_void, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "ReceiveLastByLookupId", 31usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).ReceiveLastByLookupId)(this, transaction, wantdestinationqueue, wantbody, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PeekByLookupId(this: *mut core::ffi::c_void, lookupid: core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "PeekByLookupId", 32usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).PeekByLookupId)(this, lookupid, wantdestinationqueue, wantbody, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PeekNextByLookupId(this: *mut core::ffi::c_void, lookupid: core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "PeekNextByLookupId", 33usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).PeekNextByLookupId)(this, lookupid, wantdestinationqueue, wantbody, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PeekPreviousByLookupId(this: *mut core::ffi::c_void, lookupid: core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "PeekPreviousByLookupId", 34usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).PeekPreviousByLookupId)(this, lookupid, wantdestinationqueue, wantbody, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PeekFirstByLookupId(this: *mut core::ffi::c_void, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "PeekFirstByLookupId", 35usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).PeekFirstByLookupId)(this, wantdestinationqueue, wantbody, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PeekLastByLookupId(this: *mut core::ffi::c_void, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "PeekLastByLookupId", 36usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).PeekLastByLookupId)(this, wantdestinationqueue, wantbody, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        unsafe extern "system" fn Purge(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "Purge", 37usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).Purge)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOpen2(this: *mut core::ffi::c_void, pisopen: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue3", "IsOpen2", 38usize, |this| ((**(this as *const *const IMSMQQueue3_Vtbl)).IsOpen2)(this, pisopen), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Access,
+            ShareMode,
+            #[cfg(feature = "Win32_System_Com")]
+            QueueInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            QueueInfo: 0,
+            Handle,
+            IsOpen,
+            Close,
+            #[cfg(feature = "Win32_System_Com")]
+            Receive_v1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Receive_v1: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Peek_v1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Peek_v1: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnableNotification,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnableNotification: 0,
+            Reset,
+            #[cfg(feature = "Win32_System_Com")]
+            ReceiveCurrent_v1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ReceiveCurrent_v1: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PeekNext_v1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PeekNext_v1: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PeekCurrent_v1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PeekCurrent_v1: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Receive,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Receive: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Peek,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Peek: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ReceiveCurrent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ReceiveCurrent: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PeekNext,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PeekNext: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PeekCurrent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PeekCurrent: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+            Handle2,
+            #[cfg(feature = "Win32_System_Com")]
+            ReceiveByLookupId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ReceiveByLookupId: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ReceiveNextByLookupId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ReceiveNextByLookupId: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ReceivePreviousByLookupId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ReceivePreviousByLookupId: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ReceiveFirstByLookupId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ReceiveFirstByLookupId: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ReceiveLastByLookupId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ReceiveLastByLookupId: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PeekByLookupId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PeekByLookupId: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PeekNextByLookupId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PeekNextByLookupId: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PeekPreviousByLookupId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PeekPreviousByLookupId: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PeekFirstByLookupId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PeekFirstByLookupId: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PeekLastByLookupId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PeekLastByLookupId: 0,
+            Purge,
+            IsOpen2,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQQueue3 as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQQueue4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Access(this: *mut core::ffi::c_void, placcess: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "Access", 7usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).Access)(this, placcess), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShareMode(this: *mut core::ffi::c_void, plsharemode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "ShareMode", 8usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).ShareMode)(this, plsharemode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn QueueInfo(this: *mut core::ffi::c_void, ppqinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "QueueInfo", 9usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).QueueInfo)(this, ppqinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn Handle(this: *mut core::ffi::c_void, plhandle: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "Handle", 10usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).Handle)(this, plhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOpen(this: *mut core::ffi::c_void, pisopen: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "IsOpen", 11usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).IsOpen)(this, pisopen), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "Close", 12usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Receive_v1(this: *mut core::ffi::c_void, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "Receive_v1", 13usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).Receive_v1)(this, transaction, wantdestinationqueue, wantbody, receivetimeout, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Peek_v1(this: *mut core::ffi::c_void, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "Peek_v1", 14usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).Peek_v1)(this, wantdestinationqueue, wantbody, receivetimeout, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnableNotification(this: *mut core::ffi::c_void, event: *mut core::ffi::c_void, cursor: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "EnableNotification", 15usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).EnableNotification)(this, event, cursor, receivetimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "Reset", 16usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ReceiveCurrent_v1(this: *mut core::ffi::c_void, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "ReceiveCurrent_v1", 17usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).ReceiveCurrent_v1)(this, transaction, wantdestinationqueue, wantbody, receivetimeout, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PeekNext_v1(this: *mut core::ffi::c_void, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "PeekNext_v1", 18usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).PeekNext_v1)(this, wantdestinationqueue, wantbody, receivetimeout, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PeekCurrent_v1(this: *mut core::ffi::c_void, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "PeekCurrent_v1", 19usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).PeekCurrent_v1)(this, wantdestinationqueue, wantbody, receivetimeout, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Receive(this: *mut core::ffi::c_void, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "Receive", 20usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).Receive)(this, transaction, wantdestinationqueue, wantbody, receivetimeout, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Peek(this: *mut core::ffi::c_void, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "Peek", 21usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).Peek)(this, wantdestinationqueue, wantbody, receivetimeout, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ReceiveCurrent(this: *mut core::ffi::c_void, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "ReceiveCurrent", 22usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).ReceiveCurrent)(this, transaction, wantdestinationqueue, wantbody, receivetimeout, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PeekNext(this: *mut core::ffi::c_void, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "PeekNext", 23usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).PeekNext)(this, wantdestinationqueue, wantbody, receivetimeout, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PeekCurrent(this: *mut core::ffi::c_void, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, receivetimeout: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "PeekCurrent", 24usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).PeekCurrent)(this, wantdestinationqueue, wantbody, receivetimeout, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, ppcolproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "Properties", 25usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).Properties)(this, ppcolproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn Handle2(this: *mut core::ffi::c_void, pvarhandle: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "Handle2", 26usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).Handle2)(this, pvarhandle), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ReceiveByLookupId(this: *mut core::ffi::c_void, lookupid: core::mem::MaybeUninit<windows_core::VARIANT>, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "ReceiveByLookupId", 27usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).ReceiveByLookupId)(this, lookupid, transaction, wantdestinationqueue, wantbody, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ReceiveNextByLookupId(this: *mut core::ffi::c_void, lookupid: core::mem::MaybeUninit<windows_core::VARIANT>, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "ReceiveNextByLookupId", 28usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).ReceiveNextByLookupId)(this, lookupid, transaction, wantdestinationqueue, wantbody, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ReceivePreviousByLookupId(this: *mut core::ffi::c_void, lookupid: core::mem::MaybeUninit<windows_core::VARIANT>, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "ReceivePreviousByLookupId", 29usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).ReceivePreviousByLookupId)(this, lookupid, transaction, wantdestinationqueue, wantbody, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ReceiveFirstByLookupId(this: *mut core::ffi::c_void, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "ReceiveFirstByLookupId", 30usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).ReceiveFirstByLookupId)(this, transaction, wantdestinationqueue, wantbody, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ReceiveLastByLookupId(this: *mut core::ffi::c_void, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "ReceiveLastByLookupId", 31usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).ReceiveLastByLookupId)(this, transaction, wantdestinationqueue, wantbody, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PeekByLookupId(this: *mut core::ffi::c_void, lookupid: core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "PeekByLookupId", 32usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).PeekByLookupId)(this, lookupid, wantdestinationqueue, wantbody, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PeekNextByLookupId(this: *mut core::ffi::c_void, lookupid: core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "PeekNextByLookupId", 33usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).PeekNextByLookupId)(this, lookupid, wantdestinationqueue, wantbody, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PeekPreviousByLookupId(this: *mut core::ffi::c_void, lookupid: core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "PeekPreviousByLookupId", 34usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).PeekPreviousByLookupId)(this, lookupid, wantdestinationqueue, wantbody, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PeekFirstByLookupId(this: *mut core::ffi::c_void, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "PeekFirstByLookupId", 35usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).PeekFirstByLookupId)(this, wantdestinationqueue, wantbody, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PeekLastByLookupId(this: *mut core::ffi::c_void, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "PeekLastByLookupId", 36usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).PeekLastByLookupId)(this, wantdestinationqueue, wantbody, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        unsafe extern "system" fn Purge(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "Purge", 37usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).Purge)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOpen2(this: *mut core::ffi::c_void, pisopen: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "IsOpen2", 38usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).IsOpen2)(this, pisopen), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ReceiveByLookupIdAllowPeek(this: *mut core::ffi::c_void, lookupid: core::mem::MaybeUninit<windows_core::VARIANT>, transaction: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantdestinationqueue: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantbody: *const core::mem::MaybeUninit<windows_core::VARIANT>, wantconnectortype: *const core::mem::MaybeUninit<windows_core::VARIANT>, ppmsg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueue4", "ReceiveByLookupIdAllowPeek", 39usize, |this| ((**(this as *const *const IMSMQQueue4_Vtbl)).ReceiveByLookupIdAllowPeek)(this, lookupid, transaction, wantdestinationqueue, wantbody, wantconnectortype, ppmsg), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Access,
+            ShareMode,
+            #[cfg(feature = "Win32_System_Com")]
+            QueueInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            QueueInfo: 0,
+            Handle,
+            IsOpen,
+            Close,
+            #[cfg(feature = "Win32_System_Com")]
+            Receive_v1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Receive_v1: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Peek_v1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Peek_v1: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnableNotification,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnableNotification: 0,
+            Reset,
+            #[cfg(feature = "Win32_System_Com")]
+            ReceiveCurrent_v1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ReceiveCurrent_v1: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PeekNext_v1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PeekNext_v1: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PeekCurrent_v1,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PeekCurrent_v1: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Receive,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Receive: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Peek,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Peek: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ReceiveCurrent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ReceiveCurrent: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PeekNext,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PeekNext: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PeekCurrent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PeekCurrent: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+            Handle2,
+            #[cfg(feature = "Win32_System_Com")]
+            ReceiveByLookupId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ReceiveByLookupId: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ReceiveNextByLookupId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ReceiveNextByLookupId: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ReceivePreviousByLookupId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ReceivePreviousByLookupId: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ReceiveFirstByLookupId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ReceiveFirstByLookupId: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ReceiveLastByLookupId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ReceiveLastByLookupId: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PeekByLookupId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PeekByLookupId: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PeekNextByLookupId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PeekNextByLookupId: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PeekPreviousByLookupId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PeekPreviousByLookupId: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PeekFirstByLookupId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PeekFirstByLookupId: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PeekLastByLookupId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PeekLastByLookupId: 0,
+            Purge,
+            IsOpen2,
+            #[cfg(feature = "Win32_System_Com")]
+            ReceiveByLookupIdAllowPeek,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ReceiveByLookupIdAllowPeek: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQQueue4 as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQQueueInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueueGuid(this: *mut core::ffi::c_void, pbstrguidqueue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "QueueGuid", 7usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).QueueGuid)(this, pbstrguidqueue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceTypeGuid(this: *mut core::ffi::c_void, pbstrguidservicetype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "ServiceTypeGuid", 8usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).ServiceTypeGuid)(this, pbstrguidservicetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServiceTypeGuid(this: *mut core::ffi::c_void, bstrguidservicetype: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "SetServiceTypeGuid", 9usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).SetServiceTypeGuid)(this, bstrguidservicetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Label(this: *mut core::ffi::c_void, pbstrlabel: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "Label", 10usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).Label)(this, pbstrlabel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLabel(this: *mut core::ffi::c_void, bstrlabel: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "SetLabel", 11usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).SetLabel)(this, bstrlabel), |result| Some(*result))
+        }
+        unsafe extern "system" fn PathName(this: *mut core::ffi::c_void, pbstrpathname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "PathName", 12usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).PathName)(this, pbstrpathname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPathName(this: *mut core::ffi::c_void, bstrpathname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "SetPathName", 13usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).SetPathName)(this, bstrpathname), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormatName(this: *mut core::ffi::c_void, pbstrformatname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "FormatName", 14usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).FormatName)(this, pbstrformatname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFormatName(this: *mut core::ffi::c_void, bstrformatname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "SetFormatName", 15usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).SetFormatName)(this, bstrformatname), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTransactional(this: *mut core::ffi::c_void, pistransactional: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "IsTransactional", 16usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).IsTransactional)(this, pistransactional), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrivLevel(this: *mut core::ffi::c_void, plprivlevel: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "PrivLevel", 17usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).PrivLevel)(this, plprivlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivLevel(this: *mut core::ffi::c_void, lprivlevel: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "SetPrivLevel", 18usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).SetPrivLevel)(this, lprivlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn Journal(this: *mut core::ffi::c_void, pljournal: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "Journal", 19usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).Journal)(this, pljournal), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetJournal(this: *mut core::ffi::c_void, ljournal: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "SetJournal", 20usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).SetJournal)(this, ljournal), |result| Some(*result))
+        }
+        unsafe extern "system" fn Quota(this: *mut core::ffi::c_void, plquota: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "Quota", 21usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).Quota)(this, plquota), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQuota(this: *mut core::ffi::c_void, lquota: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "SetQuota", 22usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).SetQuota)(this, lquota), |result| Some(*result))
+        }
+        unsafe extern "system" fn BasePriority(this: *mut core::ffi::c_void, plbasepriority: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "BasePriority", 23usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).BasePriority)(this, plbasepriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBasePriority(this: *mut core::ffi::c_void, lbasepriority: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "SetBasePriority", 24usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).SetBasePriority)(this, lbasepriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTime(this: *mut core::ffi::c_void, pvarcreatetime: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "CreateTime", 25usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).CreateTime)(this, pvarcreatetime), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModifyTime(this: *mut core::ffi::c_void, pvarmodifytime: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "ModifyTime", 26usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).ModifyTime)(this, pvarmodifytime), |result| Some(*result))
+        }
+        unsafe extern "system" fn Authenticate(this: *mut core::ffi::c_void, plauthenticate: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "Authenticate", 27usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).Authenticate)(this, plauthenticate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthenticate(this: *mut core::ffi::c_void, lauthenticate: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "SetAuthenticate", 28usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).SetAuthenticate)(this, lauthenticate), |result| Some(*result))
+        }
+        unsafe extern "system" fn JournalQuota(this: *mut core::ffi::c_void, pljournalquota: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "JournalQuota", 29usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).JournalQuota)(this, pljournalquota), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetJournalQuota(this: *mut core::ffi::c_void, ljournalquota: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "SetJournalQuota", 30usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).SetJournalQuota)(this, ljournalquota), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsWorldReadable(this: *mut core::ffi::c_void, pisworldreadable: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "IsWorldReadable", 31usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).IsWorldReadable)(this, pisworldreadable), |result| Some(*result))
+        }
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, istransactional: *const core::mem::MaybeUninit<windows_core::VARIANT>, isworldreadable: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "Create", 32usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).Create)(this, istransactional, isworldreadable), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "Delete", 33usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, access: i32, sharemode: i32, ppq: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "Open", 34usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).Open)(this, access, sharemode, ppq), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "Refresh", 35usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo", "Update", 36usize, |this| ((**(this as *const *const IMSMQQueueInfo_Vtbl)).Update)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            QueueGuid,
+            ServiceTypeGuid,
+            SetServiceTypeGuid,
+            Label,
+            SetLabel,
+            PathName,
+            SetPathName,
+            FormatName,
+            SetFormatName,
+            IsTransactional,
+            PrivLevel,
+            SetPrivLevel,
+            Journal,
+            SetJournal,
+            Quota,
+            SetQuota,
+            BasePriority,
+            SetBasePriority,
+            CreateTime,
+            ModifyTime,
+            Authenticate,
+            SetAuthenticate,
+            JournalQuota,
+            SetJournalQuota,
+            IsWorldReadable,
+            Create,
+            Delete,
+            #[cfg(feature = "Win32_System_Com")]
+            Open,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Open: 0,
+            Refresh,
+            Update,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQQueueInfo as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQQueueInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueueGuid(this: *mut core::ffi::c_void, pbstrguidqueue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "QueueGuid", 7usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).QueueGuid)(this, pbstrguidqueue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceTypeGuid(this: *mut core::ffi::c_void, pbstrguidservicetype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "ServiceTypeGuid", 8usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).ServiceTypeGuid)(this, pbstrguidservicetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServiceTypeGuid(this: *mut core::ffi::c_void, bstrguidservicetype: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "SetServiceTypeGuid", 9usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).SetServiceTypeGuid)(this, bstrguidservicetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Label(this: *mut core::ffi::c_void, pbstrlabel: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "Label", 10usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).Label)(this, pbstrlabel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLabel(this: *mut core::ffi::c_void, bstrlabel: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "SetLabel", 11usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).SetLabel)(this, bstrlabel), |result| Some(*result))
+        }
+        unsafe extern "system" fn PathName(this: *mut core::ffi::c_void, pbstrpathname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "PathName", 12usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).PathName)(this, pbstrpathname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPathName(this: *mut core::ffi::c_void, bstrpathname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "SetPathName", 13usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).SetPathName)(this, bstrpathname), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormatName(this: *mut core::ffi::c_void, pbstrformatname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "FormatName", 14usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).FormatName)(this, pbstrformatname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFormatName(this: *mut core::ffi::c_void, bstrformatname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "SetFormatName", 15usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).SetFormatName)(this, bstrformatname), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTransactional(this: *mut core::ffi::c_void, pistransactional: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "IsTransactional", 16usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).IsTransactional)(this, pistransactional), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrivLevel(this: *mut core::ffi::c_void, plprivlevel: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "PrivLevel", 17usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).PrivLevel)(this, plprivlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivLevel(this: *mut core::ffi::c_void, lprivlevel: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "SetPrivLevel", 18usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).SetPrivLevel)(this, lprivlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn Journal(this: *mut core::ffi::c_void, pljournal: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "Journal", 19usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).Journal)(this, pljournal), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetJournal(this: *mut core::ffi::c_void, ljournal: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "SetJournal", 20usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).SetJournal)(this, ljournal), |result| Some(*result))
+        }
+        unsafe extern "system" fn Quota(this: *mut core::ffi::c_void, plquota: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "Quota", 21usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).Quota)(this, plquota), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQuota(this: *mut core::ffi::c_void, lquota: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "SetQuota", 22usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).SetQuota)(this, lquota), |result| Some(*result))
+        }
+        unsafe extern "system" fn BasePriority(this: *mut core::ffi::c_void, plbasepriority: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "BasePriority", 23usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).BasePriority)(this, plbasepriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBasePriority(this: *mut core::ffi::c_void, lbasepriority: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "SetBasePriority", 24usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).SetBasePriority)(this, lbasepriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTime(this: *mut core::ffi::c_void, pvarcreatetime: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "CreateTime", 25usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).CreateTime)(this, pvarcreatetime), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModifyTime(this: *mut core::ffi::c_void, pvarmodifytime: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "ModifyTime", 26usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).ModifyTime)(this, pvarmodifytime), |result| Some(*result))
+        }
+        unsafe extern "system" fn Authenticate(this: *mut core::ffi::c_void, plauthenticate: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "Authenticate", 27usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).Authenticate)(this, plauthenticate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthenticate(this: *mut core::ffi::c_void, lauthenticate: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "SetAuthenticate", 28usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).SetAuthenticate)(this, lauthenticate), |result| Some(*result))
+        }
+        unsafe extern "system" fn JournalQuota(this: *mut core::ffi::c_void, pljournalquota: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "JournalQuota", 29usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).JournalQuota)(this, pljournalquota), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetJournalQuota(this: *mut core::ffi::c_void, ljournalquota: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "SetJournalQuota", 30usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).SetJournalQuota)(this, ljournalquota), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsWorldReadable(this: *mut core::ffi::c_void, pisworldreadable: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "IsWorldReadable", 31usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).IsWorldReadable)(this, pisworldreadable), |result| Some(*result))
+        }
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, istransactional: *const core::mem::MaybeUninit<windows_core::VARIANT>, isworldreadable: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "Create", 32usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).Create)(this, istransactional, isworldreadable), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "Delete", 33usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, access: i32, sharemode: i32, ppq: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "Open", 34usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).Open)(this, access, sharemode, ppq), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "Refresh", 35usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "Update", 36usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).Update)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PathNameDNS(this: *mut core::ffi::c_void, pbstrpathnamedns: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "PathNameDNS", 37usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).PathNameDNS)(this, pbstrpathnamedns), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, ppcolproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "Properties", 38usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).Properties)(this, ppcolproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn Security(this: *mut core::ffi::c_void, pvarsecurity: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "Security", 39usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).Security)(this, pvarsecurity), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSecurity(this: *mut core::ffi::c_void, varsecurity: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo2", "SetSecurity", 40usize, |this| ((**(this as *const *const IMSMQQueueInfo2_Vtbl)).SetSecurity)(this, varsecurity), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            QueueGuid,
+            ServiceTypeGuid,
+            SetServiceTypeGuid,
+            Label,
+            SetLabel,
+            PathName,
+            SetPathName,
+            FormatName,
+            SetFormatName,
+            IsTransactional,
+            PrivLevel,
+            SetPrivLevel,
+            Journal,
+            SetJournal,
+            Quota,
+            SetQuota,
+            BasePriority,
+            SetBasePriority,
+            CreateTime,
+            ModifyTime,
+            Authenticate,
+            SetAuthenticate,
+            JournalQuota,
+            SetJournalQuota,
+            IsWorldReadable,
+            Create,
+            Delete,
+            #[cfg(feature = "Win32_System_Com")]
+            Open,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Open: 0,
+            Refresh,
+            Update,
+            PathNameDNS,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+            Security,
+            SetSecurity,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQQueueInfo2 as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQQueueInfo3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueueGuid(this: *mut core::ffi::c_void, pbstrguidqueue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "QueueGuid", 7usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).QueueGuid)(this, pbstrguidqueue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceTypeGuid(this: *mut core::ffi::c_void, pbstrguidservicetype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "ServiceTypeGuid", 8usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).ServiceTypeGuid)(this, pbstrguidservicetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServiceTypeGuid(this: *mut core::ffi::c_void, bstrguidservicetype: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "SetServiceTypeGuid", 9usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).SetServiceTypeGuid)(this, bstrguidservicetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Label(this: *mut core::ffi::c_void, pbstrlabel: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "Label", 10usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).Label)(this, pbstrlabel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLabel(this: *mut core::ffi::c_void, bstrlabel: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "SetLabel", 11usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).SetLabel)(this, bstrlabel), |result| Some(*result))
+        }
+        unsafe extern "system" fn PathName(this: *mut core::ffi::c_void, pbstrpathname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "PathName", 12usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).PathName)(this, pbstrpathname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPathName(this: *mut core::ffi::c_void, bstrpathname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "SetPathName", 13usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).SetPathName)(this, bstrpathname), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormatName(this: *mut core::ffi::c_void, pbstrformatname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "FormatName", 14usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).FormatName)(this, pbstrformatname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFormatName(this: *mut core::ffi::c_void, bstrformatname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "SetFormatName", 15usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).SetFormatName)(this, bstrformatname), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTransactional(this: *mut core::ffi::c_void, pistransactional: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "IsTransactional", 16usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).IsTransactional)(this, pistransactional), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrivLevel(this: *mut core::ffi::c_void, plprivlevel: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "PrivLevel", 17usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).PrivLevel)(this, plprivlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivLevel(this: *mut core::ffi::c_void, lprivlevel: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "SetPrivLevel", 18usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).SetPrivLevel)(this, lprivlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn Journal(this: *mut core::ffi::c_void, pljournal: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "Journal", 19usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).Journal)(this, pljournal), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetJournal(this: *mut core::ffi::c_void, ljournal: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "SetJournal", 20usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).SetJournal)(this, ljournal), |result| Some(*result))
+        }
+        unsafe extern "system" fn Quota(this: *mut core::ffi::c_void, plquota: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "Quota", 21usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).Quota)(this, plquota), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQuota(this: *mut core::ffi::c_void, lquota: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "SetQuota", 22usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).SetQuota)(this, lquota), |result| Some(*result))
+        }
+        unsafe extern "system" fn BasePriority(this: *mut core::ffi::c_void, plbasepriority: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "BasePriority", 23usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).BasePriority)(this, plbasepriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBasePriority(this: *mut core::ffi::c_void, lbasepriority: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "SetBasePriority", 24usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).SetBasePriority)(this, lbasepriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTime(this: *mut core::ffi::c_void, pvarcreatetime: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "CreateTime", 25usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).CreateTime)(this, pvarcreatetime), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModifyTime(this: *mut core::ffi::c_void, pvarmodifytime: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "ModifyTime", 26usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).ModifyTime)(this, pvarmodifytime), |result| Some(*result))
+        }
+        unsafe extern "system" fn Authenticate(this: *mut core::ffi::c_void, plauthenticate: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "Authenticate", 27usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).Authenticate)(this, plauthenticate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthenticate(this: *mut core::ffi::c_void, lauthenticate: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "SetAuthenticate", 28usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).SetAuthenticate)(this, lauthenticate), |result| Some(*result))
+        }
+        unsafe extern "system" fn JournalQuota(this: *mut core::ffi::c_void, pljournalquota: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "JournalQuota", 29usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).JournalQuota)(this, pljournalquota), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetJournalQuota(this: *mut core::ffi::c_void, ljournalquota: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "SetJournalQuota", 30usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).SetJournalQuota)(this, ljournalquota), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsWorldReadable(this: *mut core::ffi::c_void, pisworldreadable: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "IsWorldReadable", 31usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).IsWorldReadable)(this, pisworldreadable), |result| Some(*result))
+        }
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, istransactional: *const core::mem::MaybeUninit<windows_core::VARIANT>, isworldreadable: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "Create", 32usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).Create)(this, istransactional, isworldreadable), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "Delete", 33usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, access: i32, sharemode: i32, ppq: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "Open", 34usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).Open)(this, access, sharemode, ppq), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "Refresh", 35usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "Update", 36usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).Update)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PathNameDNS(this: *mut core::ffi::c_void, pbstrpathnamedns: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "PathNameDNS", 37usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).PathNameDNS)(this, pbstrpathnamedns), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, ppcolproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "Properties", 38usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).Properties)(this, ppcolproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn Security(this: *mut core::ffi::c_void, pvarsecurity: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "Security", 39usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).Security)(this, pvarsecurity), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSecurity(this: *mut core::ffi::c_void, varsecurity: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "SetSecurity", 40usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).SetSecurity)(this, varsecurity), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTransactional2(this: *mut core::ffi::c_void, pistransactional: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "IsTransactional2", 41usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).IsTransactional2)(this, pistransactional), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsWorldReadable2(this: *mut core::ffi::c_void, pisworldreadable: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "IsWorldReadable2", 42usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).IsWorldReadable2)(this, pisworldreadable), |result| Some(*result))
+        }
+        unsafe extern "system" fn MulticastAddress(this: *mut core::ffi::c_void, pbstrmulticastaddress: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "MulticastAddress", 43usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).MulticastAddress)(this, pbstrmulticastaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMulticastAddress(this: *mut core::ffi::c_void, bstrmulticastaddress: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "SetMulticastAddress", 44usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).SetMulticastAddress)(this, bstrmulticastaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn ADsPath(this: *mut core::ffi::c_void, pbstradspath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo3", "ADsPath", 45usize, |this| ((**(this as *const *const IMSMQQueueInfo3_Vtbl)).ADsPath)(this, pbstradspath), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            QueueGuid,
+            ServiceTypeGuid,
+            SetServiceTypeGuid,
+            Label,
+            SetLabel,
+            PathName,
+            SetPathName,
+            FormatName,
+            SetFormatName,
+            IsTransactional,
+            PrivLevel,
+            SetPrivLevel,
+            Journal,
+            SetJournal,
+            Quota,
+            SetQuota,
+            BasePriority,
+            SetBasePriority,
+            CreateTime,
+            ModifyTime,
+            Authenticate,
+            SetAuthenticate,
+            JournalQuota,
+            SetJournalQuota,
+            IsWorldReadable,
+            Create,
+            Delete,
+            #[cfg(feature = "Win32_System_Com")]
+            Open,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Open: 0,
+            Refresh,
+            Update,
+            PathNameDNS,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+            Security,
+            SetSecurity,
+            IsTransactional2,
+            IsWorldReadable2,
+            MulticastAddress,
+            SetMulticastAddress,
+            ADsPath,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQQueueInfo3 as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQQueueInfo4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueueGuid(this: *mut core::ffi::c_void, pbstrguidqueue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "QueueGuid", 7usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).QueueGuid)(this, pbstrguidqueue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceTypeGuid(this: *mut core::ffi::c_void, pbstrguidservicetype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "ServiceTypeGuid", 8usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).ServiceTypeGuid)(this, pbstrguidservicetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServiceTypeGuid(this: *mut core::ffi::c_void, bstrguidservicetype: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "SetServiceTypeGuid", 9usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).SetServiceTypeGuid)(this, bstrguidservicetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Label(this: *mut core::ffi::c_void, pbstrlabel: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "Label", 10usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).Label)(this, pbstrlabel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLabel(this: *mut core::ffi::c_void, bstrlabel: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "SetLabel", 11usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).SetLabel)(this, bstrlabel), |result| Some(*result))
+        }
+        unsafe extern "system" fn PathName(this: *mut core::ffi::c_void, pbstrpathname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "PathName", 12usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).PathName)(this, pbstrpathname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPathName(this: *mut core::ffi::c_void, bstrpathname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "SetPathName", 13usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).SetPathName)(this, bstrpathname), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormatName(this: *mut core::ffi::c_void, pbstrformatname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "FormatName", 14usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).FormatName)(this, pbstrformatname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFormatName(this: *mut core::ffi::c_void, bstrformatname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "SetFormatName", 15usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).SetFormatName)(this, bstrformatname), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTransactional(this: *mut core::ffi::c_void, pistransactional: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "IsTransactional", 16usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).IsTransactional)(this, pistransactional), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrivLevel(this: *mut core::ffi::c_void, plprivlevel: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "PrivLevel", 17usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).PrivLevel)(this, plprivlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivLevel(this: *mut core::ffi::c_void, lprivlevel: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "SetPrivLevel", 18usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).SetPrivLevel)(this, lprivlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn Journal(this: *mut core::ffi::c_void, pljournal: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "Journal", 19usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).Journal)(this, pljournal), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetJournal(this: *mut core::ffi::c_void, ljournal: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "SetJournal", 20usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).SetJournal)(this, ljournal), |result| Some(*result))
+        }
+        unsafe extern "system" fn Quota(this: *mut core::ffi::c_void, plquota: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "Quota", 21usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).Quota)(this, plquota), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQuota(this: *mut core::ffi::c_void, lquota: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "SetQuota", 22usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).SetQuota)(this, lquota), |result| Some(*result))
+        }
+        unsafe extern "system" fn BasePriority(this: *mut core::ffi::c_void, plbasepriority: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "BasePriority", 23usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).BasePriority)(this, plbasepriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBasePriority(this: *mut core::ffi::c_void, lbasepriority: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "SetBasePriority", 24usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).SetBasePriority)(this, lbasepriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTime(this: *mut core::ffi::c_void, pvarcreatetime: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "CreateTime", 25usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).CreateTime)(this, pvarcreatetime), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModifyTime(this: *mut core::ffi::c_void, pvarmodifytime: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "ModifyTime", 26usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).ModifyTime)(this, pvarmodifytime), |result| Some(*result))
+        }
+        unsafe extern "system" fn Authenticate(this: *mut core::ffi::c_void, plauthenticate: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "Authenticate", 27usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).Authenticate)(this, plauthenticate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthenticate(this: *mut core::ffi::c_void, lauthenticate: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "SetAuthenticate", 28usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).SetAuthenticate)(this, lauthenticate), |result| Some(*result))
+        }
+        unsafe extern "system" fn JournalQuota(this: *mut core::ffi::c_void, pljournalquota: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "JournalQuota", 29usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).JournalQuota)(this, pljournalquota), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetJournalQuota(this: *mut core::ffi::c_void, ljournalquota: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "SetJournalQuota", 30usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).SetJournalQuota)(this, ljournalquota), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsWorldReadable(this: *mut core::ffi::c_void, pisworldreadable: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "IsWorldReadable", 31usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).IsWorldReadable)(this, pisworldreadable), |result| Some(*result))
+        }
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, istransactional: *const core::mem::MaybeUninit<windows_core::VARIANT>, isworldreadable: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "Create", 32usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).Create)(this, istransactional, isworldreadable), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "Delete", 33usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, access: i32, sharemode: i32, ppq: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "Open", 34usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).Open)(this, access, sharemode, ppq), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "Refresh", 35usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "Update", 36usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).Update)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PathNameDNS(this: *mut core::ffi::c_void, pbstrpathnamedns: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "PathNameDNS", 37usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).PathNameDNS)(this, pbstrpathnamedns), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, ppcolproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "Properties", 38usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).Properties)(this, ppcolproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn Security(this: *mut core::ffi::c_void, pvarsecurity: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "Security", 39usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).Security)(this, pvarsecurity), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSecurity(this: *mut core::ffi::c_void, varsecurity: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "SetSecurity", 40usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).SetSecurity)(this, varsecurity), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTransactional2(this: *mut core::ffi::c_void, pistransactional: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "IsTransactional2", 41usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).IsTransactional2)(this, pistransactional), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsWorldReadable2(this: *mut core::ffi::c_void, pisworldreadable: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "IsWorldReadable2", 42usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).IsWorldReadable2)(this, pisworldreadable), |result| Some(*result))
+        }
+        unsafe extern "system" fn MulticastAddress(this: *mut core::ffi::c_void, pbstrmulticastaddress: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "MulticastAddress", 43usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).MulticastAddress)(this, pbstrmulticastaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMulticastAddress(this: *mut core::ffi::c_void, bstrmulticastaddress: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "SetMulticastAddress", 44usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).SetMulticastAddress)(this, bstrmulticastaddress), |result| Some(*result))
+        }
+        unsafe extern "system" fn ADsPath(this: *mut core::ffi::c_void, pbstradspath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfo4", "ADsPath", 45usize, |this| ((**(this as *const *const IMSMQQueueInfo4_Vtbl)).ADsPath)(this, pbstradspath), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            QueueGuid,
+            ServiceTypeGuid,
+            SetServiceTypeGuid,
+            Label,
+            SetLabel,
+            PathName,
+            SetPathName,
+            FormatName,
+            SetFormatName,
+            IsTransactional,
+            PrivLevel,
+            SetPrivLevel,
+            Journal,
+            SetJournal,
+            Quota,
+            SetQuota,
+            BasePriority,
+            SetBasePriority,
+            CreateTime,
+            ModifyTime,
+            Authenticate,
+            SetAuthenticate,
+            JournalQuota,
+            SetJournalQuota,
+            IsWorldReadable,
+            Create,
+            Delete,
+            #[cfg(feature = "Win32_System_Com")]
+            Open,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Open: 0,
+            Refresh,
+            Update,
+            PathNameDNS,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+            Security,
+            SetSecurity,
+            IsTransactional2,
+            IsWorldReadable2,
+            MulticastAddress,
+            SetMulticastAddress,
+            ADsPath,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQQueueInfo4 as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQQueueInfos_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfos", "Reset", 7usize, |this| ((**(this as *const *const IMSMQQueueInfos_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ppqinfonext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfos", "Next", 8usize, |this| ((**(this as *const *const IMSMQQueueInfos_Vtbl)).Next)(this, ppqinfonext), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Reset,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQQueueInfos as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQQueueInfos2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfos2", "Reset", 7usize, |this| ((**(this as *const *const IMSMQQueueInfos2_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ppqinfonext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfos2", "Next", 8usize, |this| ((**(this as *const *const IMSMQQueueInfos2_Vtbl)).Next)(this, ppqinfonext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, ppcolproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfos2", "Properties", 9usize, |this| ((**(this as *const *const IMSMQQueueInfos2_Vtbl)).Properties)(this, ppcolproperties), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Reset,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQQueueInfos2 as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQQueueInfos3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfos3", "Reset", 7usize, |this| ((**(this as *const *const IMSMQQueueInfos3_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ppqinfonext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfos3", "Next", 8usize, |this| ((**(this as *const *const IMSMQQueueInfos3_Vtbl)).Next)(this, ppqinfonext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, ppcolproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfos3", "Properties", 9usize, |this| ((**(this as *const *const IMSMQQueueInfos3_Vtbl)).Properties)(this, ppcolproperties), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Reset,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQQueueInfos3 as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQQueueInfos4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfos4", "Reset", 7usize, |this| ((**(this as *const *const IMSMQQueueInfos4_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ppqinfonext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfos4", "Next", 8usize, |this| ((**(this as *const *const IMSMQQueueInfos4_Vtbl)).Next)(this, ppqinfonext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, ppcolproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueInfos4", "Properties", 9usize, |this| ((**(this as *const *const IMSMQQueueInfos4_Vtbl)).Properties)(this, ppcolproperties), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Reset,
+            #[cfg(feature = "Win32_System_Com")]
+            Next,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Next: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQQueueInfos4 as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQQueueManagement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn JournalMessageCount(this: *mut core::ffi::c_void, pljournalmessagecount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueManagement", "JournalMessageCount", 16usize, |this| ((**(this as *const *const IMSMQQueueManagement_Vtbl)).JournalMessageCount)(this, pljournalmessagecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn BytesInJournal(this: *mut core::ffi::c_void, pvbytesinjournal: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueManagement", "BytesInJournal", 17usize, |this| ((**(this as *const *const IMSMQQueueManagement_Vtbl)).BytesInJournal)(this, pvbytesinjournal), |result| Some(*result))
+        }
+        unsafe extern "system" fn EodGetReceiveInfo(this: *mut core::ffi::c_void, pvcollection: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQQueueManagement", "EodGetReceiveInfo", 18usize, |this| ((**(this as *const *const IMSMQQueueManagement_Vtbl)).EodGetReceiveInfo)(this, pvcollection), |result| Some(*result))
+        }
+        Self { base__: <IMSMQManagement_Vtbl as windows_core::imp::ProxyVtable>::PROXY, JournalMessageCount, BytesInJournal, EodGetReceiveInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQQueueManagement as windows_core::Interface>::IID || <IMSMQManagement_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQTransaction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Transaction(this: *mut core::ffi::c_void, pltransaction: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQTransaction", "Transaction", 7usize, |this| ((**(this as *const *const IMSMQTransaction_Vtbl)).Transaction)(this, pltransaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void, fretaining: *const core::mem::MaybeUninit<windows_core::VARIANT>, grftc: *const core::mem::MaybeUninit<windows_core::VARIANT>, grfrm: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQTransaction", "Commit", 8usize, |this| ((**(this as *const *const IMSMQTransaction_Vtbl)).Commit)(this, fretaining, grftc, grfrm), |result| Some(*result))
+        }
+        unsafe extern "system" fn Abort(this: *mut core::ffi::c_void, fretaining: *const core::mem::MaybeUninit<windows_core::VARIANT>, fasync: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQTransaction", "Abort", 9usize, |this| ((**(this as *const *const IMSMQTransaction_Vtbl)).Abort)(this, fretaining, fasync), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Transaction, Commit, Abort }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQTransaction as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQTransaction2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitNew(this: *mut core::ffi::c_void, vartransaction: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQTransaction2", "InitNew", 10usize, |this| ((**(this as *const *const IMSMQTransaction2_Vtbl)).InitNew)(this, vartransaction), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, ppcolproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQTransaction2", "Properties", 11usize, |this| ((**(this as *const *const IMSMQTransaction2_Vtbl)).Properties)(this, ppcolproperties), |result| Some(*result))
+        }
+        Self {
+            base__: <IMSMQTransaction_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitNew,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQTransaction2 as windows_core::Interface>::IID || <IMSMQTransaction_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQTransaction3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ITransaction(this: *mut core::ffi::c_void, pvaritransaction: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQTransaction3", "ITransaction", 12usize, |this| ((**(this as *const *const IMSMQTransaction3_Vtbl)).ITransaction)(this, pvaritransaction), |result| Some(*result))
+        }
+        Self { base__: <IMSMQTransaction2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ITransaction }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQTransaction3 as windows_core::Interface>::IID || <IMSMQTransaction2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQTransactionDispenser_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn BeginTransaction(this: *mut core::ffi::c_void, ptransaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQTransactionDispenser", "BeginTransaction", 7usize, |this| ((**(this as *const *const IMSMQTransactionDispenser_Vtbl)).BeginTransaction)(this, ptransaction), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            BeginTransaction,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            BeginTransaction: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQTransactionDispenser as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQTransactionDispenser2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn BeginTransaction(this: *mut core::ffi::c_void, ptransaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQTransactionDispenser2", "BeginTransaction", 7usize, |this| ((**(this as *const *const IMSMQTransactionDispenser2_Vtbl)).BeginTransaction)(this, ptransaction), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, ppcolproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQTransactionDispenser2", "Properties", 8usize, |this| ((**(this as *const *const IMSMQTransactionDispenser2_Vtbl)).Properties)(this, ppcolproperties), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            BeginTransaction,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            BeginTransaction: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQTransactionDispenser2 as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMSMQTransactionDispenser3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn BeginTransaction(this: *mut core::ffi::c_void, ptransaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQTransactionDispenser3", "BeginTransaction", 7usize, |this| ((**(this as *const *const IMSMQTransactionDispenser3_Vtbl)).BeginTransaction)(this, ptransaction), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, ppcolproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMSMQTransactionDispenser3", "Properties", 8usize, |this| ((**(this as *const *const IMSMQTransactionDispenser3_Vtbl)).Properties)(this, ppcolproperties), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            BeginTransaction,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            BeginTransaction: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMSMQTransactionDispenser3 as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for _DMSMQEventEvents_Vtbl {
+    const PROXY: Self = { Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<_DMSMQEventEvents as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
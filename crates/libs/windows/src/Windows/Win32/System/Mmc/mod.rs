@@ -3435,6 +3435,7 @@ pub struct CCM_COMMANDID_MASK_CONSTANTS(pub u32);
 impl windows_core::TypeKind for CCM_COMMANDID_MASK_CONSTANTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CCM_COMMANDID_MASK_CONSTANTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CCM_COMMANDID_MASK_CONSTANTS").field(&self.0).finish()
@@ -3446,6 +3447,7 @@ pub struct CCM_INSERTIONALLOWED(pub i32);
 impl windows_core::TypeKind for CCM_INSERTIONALLOWED {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CCM_INSERTIONALLOWED {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CCM_INSERTIONALLOWED").field(&self.0).finish()
@@ -3457,6 +3459,7 @@ pub struct CCM_INSERTIONPOINTID(pub i32);
 impl windows_core::TypeKind for CCM_INSERTIONPOINTID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CCM_INSERTIONPOINTID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CCM_INSERTIONPOINTID").field(&self.0).finish()
@@ -3468,6 +3471,7 @@ pub struct CCM_SPECIAL(pub i32);
 impl windows_core::TypeKind for CCM_SPECIAL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CCM_SPECIAL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CCM_SPECIAL").field(&self.0).finish()
@@ -3479,6 +3483,7 @@ pub struct DATA_OBJECT_TYPES(pub i32);
 impl windows_core::TypeKind for DATA_OBJECT_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DATA_OBJECT_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DATA_OBJECT_TYPES").field(&self.0).finish()
@@ -3490,6 +3495,7 @@ pub struct IconIdentifier(pub i32);
 impl windows_core::TypeKind for IconIdentifier {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IconIdentifier {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IconIdentifier").field(&self.0).finish()
@@ -3501,6 +3507,7 @@ pub struct MMC_ACTION_TYPE(pub i32);
 impl windows_core::TypeKind for MMC_ACTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MMC_ACTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MMC_ACTION_TYPE").field(&self.0).finish()
@@ -3512,6 +3519,7 @@ pub struct MMC_BUTTON_STATE(pub i32);
 impl windows_core::TypeKind for MMC_BUTTON_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MMC_BUTTON_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MMC_BUTTON_STATE").field(&self.0).finish()
@@ -3523,6 +3531,7 @@ pub struct MMC_CONSOLE_VERB(pub i32);
 impl windows_core::TypeKind for MMC_CONSOLE_VERB {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MMC_CONSOLE_VERB {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MMC_CONSOLE_VERB").field(&self.0).finish()
@@ -3534,6 +3543,7 @@ pub struct MMC_CONTROL_TYPE(pub i32);
 impl windows_core::TypeKind for MMC_CONTROL_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MMC_CONTROL_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MMC_CONTROL_TYPE").field(&self.0).finish()
@@ -3545,6 +3555,7 @@ pub struct MMC_FILTER_CHANGE_CODE(pub i32);
 impl windows_core::TypeKind for MMC_FILTER_CHANGE_CODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MMC_FILTER_CHANGE_CODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MMC_FILTER_CHANGE_CODE").field(&self.0).finish()
@@ -3556,6 +3567,7 @@ pub struct MMC_FILTER_TYPE(pub i32);
 impl windows_core::TypeKind for MMC_FILTER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MMC_FILTER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MMC_FILTER_TYPE").field(&self.0).finish()
@@ -3567,6 +3579,7 @@ pub struct MMC_MENU_COMMAND_IDS(pub i32);
 impl windows_core::TypeKind for MMC_MENU_COMMAND_IDS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MMC_MENU_COMMAND_IDS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MMC_MENU_COMMAND_IDS").field(&self.0).finish()
@@ -3578,6 +3591,7 @@ pub struct MMC_NOTIFY_TYPE(pub i32);
 impl windows_core::TypeKind for MMC_NOTIFY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MMC_NOTIFY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MMC_NOTIFY_TYPE").field(&self.0).finish()
@@ -3589,6 +3603,7 @@ pub struct MMC_PROPERTY_ACTION(pub i32);
 impl windows_core::TypeKind for MMC_PROPERTY_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MMC_PROPERTY_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MMC_PROPERTY_ACTION").field(&self.0).finish()
@@ -3600,6 +3615,7 @@ pub struct MMC_RESULT_VIEW_STYLE(pub i32);
 impl windows_core::TypeKind for MMC_RESULT_VIEW_STYLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MMC_RESULT_VIEW_STYLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MMC_RESULT_VIEW_STYLE").field(&self.0).finish()
@@ -3611,6 +3627,7 @@ pub struct MMC_SCOPE_ITEM_STATE(pub i32);
 impl windows_core::TypeKind for MMC_SCOPE_ITEM_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MMC_SCOPE_ITEM_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MMC_SCOPE_ITEM_STATE").field(&self.0).finish()
@@ -3622,6 +3639,7 @@ pub struct MMC_TASK_DISPLAY_TYPE(pub i32);
 impl windows_core::TypeKind for MMC_TASK_DISPLAY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MMC_TASK_DISPLAY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MMC_TASK_DISPLAY_TYPE").field(&self.0).finish()
@@ -3633,6 +3651,7 @@ pub struct MMC_VIEW_TYPE(pub i32);
 impl windows_core::TypeKind for MMC_VIEW_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MMC_VIEW_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MMC_VIEW_TYPE").field(&self.0).finish()
@@ -3644,6 +3663,7 @@ pub struct _ColumnSortOrder(pub i32);
 impl windows_core::TypeKind for _ColumnSortOrder {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _ColumnSortOrder {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_ColumnSortOrder").field(&self.0).finish()
@@ -3655,6 +3675,7 @@ pub struct _DocumentMode(pub i32);
 impl windows_core::TypeKind for _DocumentMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _DocumentMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_DocumentMode").field(&self.0).finish()
@@ -3666,6 +3687,7 @@ pub struct _ExportListOptions(pub i32);
 impl windows_core::TypeKind for _ExportListOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _ExportListOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_ExportListOptions").field(&self.0).finish()
@@ -3677,6 +3699,7 @@ pub struct _ListViewMode(pub i32);
 impl windows_core::TypeKind for _ListViewMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _ListViewMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_ListViewMode").field(&self.0).finish()
@@ -3688,6 +3711,7 @@ pub struct _ViewOptions(pub i32);
 impl windows_core::TypeKind for _ViewOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _ViewOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_ViewOptions").field(&self.0).finish()
@@ -3696,7 +3720,8 @@ impl core::fmt::Debug for _ViewOptions {
 pub const AppEventsDHTMLConnector: windows_core::GUID = windows_core::GUID::from_u128(0xade6444b_c91f_4e37_92a4_5bb430a33340);
 pub const Application: windows_core::GUID = windows_core::GUID::from_u128(0x49b2791a_b1ae_4c90_9b8e_e860ba07f889);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CONTEXTMENUITEM {
     pub strName: windows_core::PWSTR,
     pub strStatusBarText: windows_core::PWSTR,
@@ -3714,7 +3739,8 @@ impl Default for CONTEXTMENUITEM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CONTEXTMENUITEM2 {
     pub strName: windows_core::PWSTR,
     pub strStatusBarText: windows_core::PWSTR,
@@ -3734,7 +3760,8 @@ impl Default for CONTEXTMENUITEM2 {
 }
 pub const ConsolePower: windows_core::GUID = windows_core::GUID::from_u128(0xf0285374_dff1_11d3_b433_00c04f8ecd78);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MENUBUTTONDATA {
     pub idCommand: i32,
     pub x: i32,
@@ -3749,7 +3776,8 @@ impl Default for MENUBUTTONDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MMCBUTTON {
     pub nBitmap: i32,
     pub idCommand: i32,
@@ -3768,7 +3796,8 @@ impl Default for MMCBUTTON {
 }
 pub const MMCVersionInfo: windows_core::GUID = windows_core::GUID::from_u128(0xd6fedb1d_cf21_4bd9_af3b_c5468e9c6684);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MMC_COLUMN_DATA {
     pub nColIndex: i32,
     pub dwFlags: u32,
@@ -3784,7 +3813,8 @@ impl Default for MMC_COLUMN_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MMC_COLUMN_SET_DATA {
     pub cbSize: i32,
     pub nNumCols: i32,
@@ -3799,7 +3829,8 @@ impl Default for MMC_COLUMN_SET_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MMC_EXPANDSYNC_STRUCT {
     pub bHandled: super::super::Foundation::BOOL,
     pub bExpanding: super::super::Foundation::BOOL,
@@ -3814,7 +3845,8 @@ impl Default for MMC_EXPANDSYNC_STRUCT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MMC_EXT_VIEW_DATA {
     pub viewID: windows_core::GUID,
     pub pszURL: windows_core::PCWSTR,
@@ -3831,7 +3863,8 @@ impl Default for MMC_EXT_VIEW_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MMC_FILTERDATA {
     pub pszText: windows_core::PWSTR,
     pub cchTextMax: i32,
@@ -3846,7 +3879,8 @@ impl Default for MMC_FILTERDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MMC_LISTPAD_INFO {
     pub szTitle: windows_core::PWSTR,
     pub szButtonText: windows_core::PWSTR,
@@ -3861,7 +3895,8 @@ impl Default for MMC_LISTPAD_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MMC_RESTORE_VIEW {
     pub dwSize: u32,
     pub cookie: isize,
@@ -3877,7 +3912,8 @@ impl Default for MMC_RESTORE_VIEW {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MMC_SNAPIN_PROPERTY {
     pub pszPropName: windows_core::PCWSTR,
     pub varValue: core::mem::ManuallyDrop<windows_core::VARIANT>,
@@ -3897,7 +3933,8 @@ impl Default for MMC_SNAPIN_PROPERTY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MMC_SORT_DATA {
     pub nColIndex: i32,
     pub dwSortOptions: u32,
@@ -3912,7 +3949,8 @@ impl Default for MMC_SORT_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MMC_SORT_SET_DATA {
     pub cbSize: i32,
     pub nNumItems: i32,
@@ -3959,7 +3997,8 @@ impl Default for MMC_TASK_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MMC_TASK_DISPLAY_BITMAP {
     pub szMouseOverBitmap: windows_core::PWSTR,
     pub szMouseOffBitmap: windows_core::PWSTR,
@@ -4001,7 +4040,8 @@ impl Default for MMC_TASK_DISPLAY_OBJECT_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MMC_TASK_DISPLAY_SYMBOL {
     pub szFontFamilyName: windows_core::PWSTR,
     pub szURLtoEOT: windows_core::PWSTR,
@@ -4016,7 +4056,8 @@ impl Default for MMC_TASK_DISPLAY_SYMBOL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MMC_VISIBLE_COLUMNS {
     pub nVisibleColumns: i32,
     pub rgVisibleCols: [i32; 1],
@@ -4030,7 +4071,8 @@ impl Default for MMC_VISIBLE_COLUMNS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RDCOMPARE {
     pub cbSize: u32,
     pub dwFlags: u32,
@@ -4048,7 +4090,8 @@ impl Default for RDCOMPARE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RDITEMHDR {
     pub dwFlags: u32,
     pub cookie: isize,
@@ -4063,7 +4106,8 @@ impl Default for RDITEMHDR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RESULTDATAITEM {
     pub mask: u32,
     pub bScopeItem: super::super::Foundation::BOOL,
@@ -4085,7 +4129,8 @@ impl Default for RESULTDATAITEM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RESULTFINDINFO {
     pub psz: windows_core::PWSTR,
     pub nStart: i32,
@@ -4139,7 +4184,8 @@ impl Default for RESULT_VIEW_TYPE_INFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RESULT_VIEW_TYPE_INFO_0_0 {
     pub dwHTMLOptions: u32,
     pub pstrURL: windows_core::PWSTR,
@@ -4153,7 +4199,8 @@ impl Default for RESULT_VIEW_TYPE_INFO_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RESULT_VIEW_TYPE_INFO_0_1 {
     pub dwOCXOptions: u32,
     pub pUnkControl: core::mem::ManuallyDrop<Option<windows_core::IUnknown>>,
@@ -4172,7 +4219,8 @@ impl Default for RESULT_VIEW_TYPE_INFO_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCOPEDATAITEM {
     pub mask: u32,
     pub displayname: windows_core::PWSTR,
@@ -4193,7 +4241,8 @@ impl Default for SCOPEDATAITEM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SColumnSetID {
     pub dwFlags: u32,
     pub cBytes: u32,
@@ -4209,7 +4258,8 @@ impl Default for SColumnSetID {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SMMCDataObjects {
     pub count: u32,
     pub lpDataObject: [core::mem::ManuallyDrop<Option<super::Com::IDataObject>>; 1usize],
@@ -4231,7 +4281,8 @@ impl Default for SMMCDataObjects {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SMMCObjectTypes {
     pub count: u32,
     pub guid: [windows_core::GUID; 1],
@@ -4245,7 +4296,8 @@ impl Default for SMMCObjectTypes {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SNodeID {
     pub cBytes: u32,
     pub id: [u8; 1],
@@ -4259,7 +4311,8 @@ impl Default for SNodeID {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SNodeID2 {
     pub dwFlags: u32,
     pub cBytes: u32,
@@ -4275,3 +4328,5 @@ impl Default for SNodeID2 {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,2297 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for AppEvents_Vtbl {
+    const PROXY: Self = { Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<AppEvents as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for Column_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Column", "Name", 7usize, |this| ((**(this as *const *const Column_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn Width(this: *mut core::ffi::c_void, width: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Column", "Width", 8usize, |this| ((**(this as *const *const Column_Vtbl)).Width)(this, width), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWidth(this: *mut core::ffi::c_void, width: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Column", "SetWidth", 9usize, |this| ((**(this as *const *const Column_Vtbl)).SetWidth)(this, width), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayPosition(this: *mut core::ffi::c_void, displayposition: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Column", "DisplayPosition", 10usize, |this| ((**(this as *const *const Column_Vtbl)).DisplayPosition)(this, displayposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayPosition(this: *mut core::ffi::c_void, index: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Column", "SetDisplayPosition", 11usize, |this| ((**(this as *const *const Column_Vtbl)).SetDisplayPosition)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Hidden(this: *mut core::ffi::c_void, hidden: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Column", "Hidden", 12usize, |this| ((**(this as *const *const Column_Vtbl)).Hidden)(this, hidden), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHidden(this: *mut core::ffi::c_void, hidden: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Column", "SetHidden", 13usize, |this| ((**(this as *const *const Column_Vtbl)).SetHidden)(this, hidden), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAsSortColumn(this: *mut core::ffi::c_void, sortorder: _ColumnSortOrder) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Column", "SetAsSortColumn", 14usize, |this| ((**(this as *const *const Column_Vtbl)).SetAsSortColumn)(this, sortorder), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSortColumn(this: *mut core::ffi::c_void, issortcolumn: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Column", "IsSortColumn", 15usize, |this| ((**(this as *const *const Column_Vtbl)).IsSortColumn)(this, issortcolumn), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            Width,
+            SetWidth,
+            DisplayPosition,
+            SetDisplayPosition,
+            Hidden,
+            SetHidden,
+            SetAsSortColumn,
+            IsSortColumn,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<Column as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for Columns_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: i32, column: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Columns", "Item", 7usize, |this| ((**(this as *const *const Columns_Vtbl)).Item)(this, index, column), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Columns", "Count", 8usize, |this| ((**(this as *const *const Columns_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Columns", "_NewEnum", 9usize, |this| ((**(this as *const *const Columns_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            Count,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<Columns as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ContextMenu_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ContextMenu", "_NewEnum", 7usize, |this| ((**(this as *const *const ContextMenu_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, indexorpath: core::mem::MaybeUninit<windows_core::VARIANT>, menuitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ContextMenu", "get_Item", 8usize, |this| ((**(this as *const *const ContextMenu_Vtbl)).get_Item)(this, indexorpath, menuitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ContextMenu", "Count", 9usize, |this| ((**(this as *const *const ContextMenu_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            Count,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ContextMenu as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for Document_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Document", "Save", 7usize, |this| ((**(this as *const *const Document_Vtbl)).Save)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveAs(this: *mut core::ffi::c_void, filename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Document", "SaveAs", 8usize, |this| ((**(this as *const *const Document_Vtbl)).SaveAs)(this, filename), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void, savechanges: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Document", "Close", 9usize, |this| ((**(this as *const *const Document_Vtbl)).Close)(this, savechanges), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Views(this: *mut core::ffi::c_void, views: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Document", "Views", 10usize, |this| ((**(this as *const *const Document_Vtbl)).Views)(this, views), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SnapIns(this: *mut core::ffi::c_void, snapins: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Document", "SnapIns", 11usize, |this| ((**(this as *const *const Document_Vtbl)).SnapIns)(this, snapins), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ActiveView(this: *mut core::ffi::c_void, view: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Document", "ActiveView", 12usize, |this| ((**(this as *const *const Document_Vtbl)).ActiveView)(this, view), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Document", "Name", 13usize, |this| ((**(this as *const *const Document_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Document", "SetName", 14usize, |this| ((**(this as *const *const Document_Vtbl)).SetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn Location(this: *mut core::ffi::c_void, location: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Document", "Location", 15usize, |this| ((**(this as *const *const Document_Vtbl)).Location)(this, location), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSaved(this: *mut core::ffi::c_void, issaved: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Document", "IsSaved", 16usize, |this| ((**(this as *const *const Document_Vtbl)).IsSaved)(this, issaved), |result| Some(*result))
+        }
+        unsafe extern "system" fn Mode(this: *mut core::ffi::c_void, mode: *mut _DocumentMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Document", "Mode", 17usize, |this| ((**(this as *const *const Document_Vtbl)).Mode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMode(this: *mut core::ffi::c_void, mode: _DocumentMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Document", "SetMode", 18usize, |this| ((**(this as *const *const Document_Vtbl)).SetMode)(this, mode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RootNode(this: *mut core::ffi::c_void, node: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Document", "RootNode", 19usize, |this| ((**(this as *const *const Document_Vtbl)).RootNode)(this, node), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ScopeNamespace(this: *mut core::ffi::c_void, scopenamespace: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Document", "ScopeNamespace", 20usize, |this| ((**(this as *const *const Document_Vtbl)).ScopeNamespace)(this, scopenamespace), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateProperties(this: *mut core::ffi::c_void, properties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Document", "CreateProperties", 21usize, |this| ((**(this as *const *const Document_Vtbl)).CreateProperties)(this, properties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Application(this: *mut core::ffi::c_void, application: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Document", "Application", 22usize, |this| ((**(this as *const *const Document_Vtbl)).Application)(this, application), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Save,
+            SaveAs,
+            Close,
+            #[cfg(feature = "Win32_System_Com")]
+            Views,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Views: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SnapIns,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SnapIns: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ActiveView,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ActiveView: 0,
+            Name,
+            SetName,
+            Location,
+            IsSaved,
+            Mode,
+            SetMode,
+            #[cfg(feature = "Win32_System_Com")]
+            RootNode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RootNode: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ScopeNamespace,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ScopeNamespace: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Application,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Application: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<Document as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for Extension_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Extension", "Name", 7usize, |this| ((**(this as *const *const Extension_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn Vendor(this: *mut core::ffi::c_void, vendor: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Extension", "Vendor", 8usize, |this| ((**(this as *const *const Extension_Vtbl)).Vendor)(this, vendor), |result| Some(*result))
+        }
+        unsafe extern "system" fn Version(this: *mut core::ffi::c_void, version: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Extension", "Version", 9usize, |this| ((**(this as *const *const Extension_Vtbl)).Version)(this, version), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Extensions(this: *mut core::ffi::c_void, extensions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Extension", "Extensions", 10usize, |this| ((**(this as *const *const Extension_Vtbl)).Extensions)(this, extensions), |result| Some(*result))
+        }
+        unsafe extern "system" fn SnapinCLSID(this: *mut core::ffi::c_void, snapinclsid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Extension", "SnapinCLSID", 11usize, |this| ((**(this as *const *const Extension_Vtbl)).SnapinCLSID)(this, snapinclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableAllExtensions(this: *mut core::ffi::c_void, enable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Extension", "EnableAllExtensions", 12usize, |this| ((**(this as *const *const Extension_Vtbl)).EnableAllExtensions)(this, enable), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enable(this: *mut core::ffi::c_void, enable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Extension", "Enable", 13usize, |this| ((**(this as *const *const Extension_Vtbl)).Enable)(this, enable), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            Vendor,
+            Version,
+            #[cfg(feature = "Win32_System_Com")]
+            Extensions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Extensions: 0,
+            SnapinCLSID,
+            EnableAllExtensions,
+            Enable,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<Extension as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for Extensions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Extensions", "_NewEnum", 7usize, |this| ((**(this as *const *const Extensions_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: i32, extension: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Extensions", "Item", 8usize, |this| ((**(this as *const *const Extensions_Vtbl)).Item)(this, index, extension), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Extensions", "Count", 9usize, |this| ((**(this as *const *const Extensions_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            Count,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<Extensions as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for Frame_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Maximize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Frame", "Maximize", 7usize, |this| ((**(this as *const *const Frame_Vtbl)).Maximize)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Minimize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Frame", "Minimize", 8usize, |this| ((**(this as *const *const Frame_Vtbl)).Minimize)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Restore(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Frame", "Restore", 9usize, |this| ((**(this as *const *const Frame_Vtbl)).Restore)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Top(this: *mut core::ffi::c_void, top: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Frame", "Top", 10usize, |this| ((**(this as *const *const Frame_Vtbl)).Top)(this, top), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTop(this: *mut core::ffi::c_void, top: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Frame", "SetTop", 11usize, |this| ((**(this as *const *const Frame_Vtbl)).SetTop)(this, top), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bottom(this: *mut core::ffi::c_void, bottom: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Frame", "Bottom", 12usize, |this| ((**(this as *const *const Frame_Vtbl)).Bottom)(this, bottom), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBottom(this: *mut core::ffi::c_void, bottom: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Frame", "SetBottom", 13usize, |this| ((**(this as *const *const Frame_Vtbl)).SetBottom)(this, bottom), |result| Some(*result))
+        }
+        unsafe extern "system" fn Left(this: *mut core::ffi::c_void, left: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Frame", "Left", 14usize, |this| ((**(this as *const *const Frame_Vtbl)).Left)(this, left), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLeft(this: *mut core::ffi::c_void, left: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Frame", "SetLeft", 15usize, |this| ((**(this as *const *const Frame_Vtbl)).SetLeft)(this, left), |result| Some(*result))
+        }
+        unsafe extern "system" fn Right(this: *mut core::ffi::c_void, right: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Frame", "Right", 16usize, |this| ((**(this as *const *const Frame_Vtbl)).Right)(this, right), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRight(this: *mut core::ffi::c_void, right: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Frame", "SetRight", 17usize, |this| ((**(this as *const *const Frame_Vtbl)).SetRight)(this, right), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Maximize,
+            Minimize,
+            Restore,
+            Top,
+            SetTop,
+            Bottom,
+            SetBottom,
+            Left,
+            SetLeft,
+            Right,
+            SetRight,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<Frame as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IColumnData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetColumnConfigData(this: *mut core::ffi::c_void, pcolid: *const SColumnSetID, pcolsetdata: *const MMC_COLUMN_SET_DATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColumnData", "SetColumnConfigData", 3usize, |this| ((**(this as *const *const IColumnData_Vtbl)).SetColumnConfigData)(this, pcolid, pcolsetdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColumnConfigData(this: *mut core::ffi::c_void, pcolid: *const SColumnSetID, ppcolsetdata: *mut *mut MMC_COLUMN_SET_DATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColumnData", "GetColumnConfigData", 4usize, |this| ((**(this as *const *const IColumnData_Vtbl)).GetColumnConfigData)(this, pcolid, ppcolsetdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColumnSortData(this: *mut core::ffi::c_void, pcolid: *const SColumnSetID, pcolsortdata: *const MMC_SORT_SET_DATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColumnData", "SetColumnSortData", 5usize, |this| ((**(this as *const *const IColumnData_Vtbl)).SetColumnSortData)(this, pcolid, pcolsortdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColumnSortData(this: *mut core::ffi::c_void, pcolid: *const SColumnSetID, ppcolsortdata: *mut *mut MMC_SORT_SET_DATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColumnData", "GetColumnSortData", 6usize, |this| ((**(this as *const *const IColumnData_Vtbl)).GetColumnSortData)(this, pcolid, ppcolsortdata), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetColumnConfigData,
+            GetColumnConfigData,
+            SetColumnSortData,
+            GetColumnSortData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IColumnData as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IComponent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, lpconsole: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponent", "Initialize", 3usize, |this| ((**(this as *const *const IComponent_Vtbl)).Initialize)(this, lpconsole), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Notify(this: *mut core::ffi::c_void, lpdataobject: *mut core::ffi::c_void, event: MMC_NOTIFY_TYPE, arg: super::super::Foundation::LPARAM, param3: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponent", "Notify", 4usize, |this| ((**(this as *const *const IComponent_Vtbl)).Notify)(this, lpdataobject, event, arg, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn Destroy(this: *mut core::ffi::c_void, cookie: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponent", "Destroy", 5usize, |this| ((**(this as *const *const IComponent_Vtbl)).Destroy)(this, cookie), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn QueryDataObject(this: *mut core::ffi::c_void, cookie: isize, r#type: DATA_OBJECT_TYPES, ppdataobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponent", "QueryDataObject", 6usize, |this| ((**(this as *const *const IComponent_Vtbl)).QueryDataObject)(this, cookie, r#type, ppdataobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResultViewType(this: *mut core::ffi::c_void, cookie: isize, ppviewtype: *mut windows_core::PWSTR, pviewoptions: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponent", "GetResultViewType", 7usize, |this| ((**(this as *const *const IComponent_Vtbl)).GetResultViewType)(this, cookie, ppviewtype, pviewoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayInfo(this: *mut core::ffi::c_void, presultdataitem: *mut RESULTDATAITEM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponent", "GetDisplayInfo", 8usize, |this| ((**(this as *const *const IComponent_Vtbl)).GetDisplayInfo)(this, presultdataitem), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CompareObjects(this: *mut core::ffi::c_void, lpdataobjecta: *mut core::ffi::c_void, lpdataobjectb: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponent", "CompareObjects", 9usize, |this| ((**(this as *const *const IComponent_Vtbl)).CompareObjects)(this, lpdataobjecta, lpdataobjectb), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            #[cfg(feature = "Win32_System_Com")]
+            Notify,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Notify: 0,
+            Destroy,
+            #[cfg(feature = "Win32_System_Com")]
+            QueryDataObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            QueryDataObject: 0,
+            GetResultViewType,
+            GetDisplayInfo,
+            #[cfg(feature = "Win32_System_Com")]
+            CompareObjects,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CompareObjects: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComponent as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IComponent2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn QueryDispatch(this: *mut core::ffi::c_void, cookie: isize, r#type: DATA_OBJECT_TYPES, ppdispatch: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponent2", "QueryDispatch", 10usize, |this| ((**(this as *const *const IComponent2_Vtbl)).QueryDispatch)(this, cookie, r#type, ppdispatch), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResultViewType2(this: *mut core::ffi::c_void, cookie: isize, presultviewtype: *mut RESULT_VIEW_TYPE_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponent2", "GetResultViewType2", 11usize, |this| ((**(this as *const *const IComponent2_Vtbl)).GetResultViewType2)(this, cookie, presultviewtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestoreResultView(this: *mut core::ffi::c_void, cookie: isize, presultviewtype: *const RESULT_VIEW_TYPE_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponent2", "RestoreResultView", 12usize, |this| ((**(this as *const *const IComponent2_Vtbl)).RestoreResultView)(this, cookie, presultviewtype), |result| Some(*result))
+        }
+        Self {
+            base__: <IComponent_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            QueryDispatch,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            QueryDispatch: 0,
+            GetResultViewType2,
+            RestoreResultView,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComponent2 as windows_core::Interface>::IID || <IComponent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IComponentData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, punknown: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentData", "Initialize", 3usize, |this| ((**(this as *const *const IComponentData_Vtbl)).Initialize)(this, punknown), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateComponent(this: *mut core::ffi::c_void, ppcomponent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentData", "CreateComponent", 4usize, |this| ((**(this as *const *const IComponentData_Vtbl)).CreateComponent)(this, ppcomponent), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Notify(this: *mut core::ffi::c_void, lpdataobject: *mut core::ffi::c_void, event: MMC_NOTIFY_TYPE, arg: super::super::Foundation::LPARAM, param3: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentData", "Notify", 5usize, |this| ((**(this as *const *const IComponentData_Vtbl)).Notify)(this, lpdataobject, event, arg, param3), |result| Some(*result))
+        }
+        unsafe extern "system" fn Destroy(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentData", "Destroy", 6usize, |this| ((**(this as *const *const IComponentData_Vtbl)).Destroy)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn QueryDataObject(this: *mut core::ffi::c_void, cookie: isize, r#type: DATA_OBJECT_TYPES, ppdataobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentData", "QueryDataObject", 7usize, |this| ((**(this as *const *const IComponentData_Vtbl)).QueryDataObject)(this, cookie, r#type, ppdataobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayInfo(this: *mut core::ffi::c_void, pscopedataitem: *mut SCOPEDATAITEM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentData", "GetDisplayInfo", 8usize, |this| ((**(this as *const *const IComponentData_Vtbl)).GetDisplayInfo)(this, pscopedataitem), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CompareObjects(this: *mut core::ffi::c_void, lpdataobjecta: *mut core::ffi::c_void, lpdataobjectb: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentData", "CompareObjects", 9usize, |this| ((**(this as *const *const IComponentData_Vtbl)).CompareObjects)(this, lpdataobjecta, lpdataobjectb), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            CreateComponent,
+            #[cfg(feature = "Win32_System_Com")]
+            Notify,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Notify: 0,
+            Destroy,
+            #[cfg(feature = "Win32_System_Com")]
+            QueryDataObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            QueryDataObject: 0,
+            GetDisplayInfo,
+            #[cfg(feature = "Win32_System_Com")]
+            CompareObjects,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CompareObjects: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComponentData as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IComponentData2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn QueryDispatch(this: *mut core::ffi::c_void, cookie: isize, r#type: DATA_OBJECT_TYPES, ppdispatch: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentData2", "QueryDispatch", 10usize, |this| ((**(this as *const *const IComponentData2_Vtbl)).QueryDispatch)(this, cookie, r#type, ppdispatch), |result| Some(*result))
+        }
+        Self {
+            base__: <IComponentData_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            QueryDispatch,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            QueryDispatch: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComponentData2 as windows_core::Interface>::IID || <IComponentData_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IConsole_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetHeader(this: *mut core::ffi::c_void, pheader: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsole", "SetHeader", 3usize, |this| ((**(this as *const *const IConsole_Vtbl)).SetHeader)(this, pheader), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetToolbar(this: *mut core::ffi::c_void, ptoolbar: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsole", "SetToolbar", 4usize, |this| ((**(this as *const *const IConsole_Vtbl)).SetToolbar)(this, ptoolbar), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryResultView(this: *mut core::ffi::c_void, punknown: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsole", "QueryResultView", 5usize, |this| ((**(this as *const *const IConsole_Vtbl)).QueryResultView)(this, punknown), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryScopeImageList(this: *mut core::ffi::c_void, ppimagelist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsole", "QueryScopeImageList", 6usize, |this| ((**(this as *const *const IConsole_Vtbl)).QueryScopeImageList)(this, ppimagelist), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryResultImageList(this: *mut core::ffi::c_void, ppimagelist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsole", "QueryResultImageList", 7usize, |this| ((**(this as *const *const IConsole_Vtbl)).QueryResultImageList)(this, ppimagelist), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn UpdateAllViews(this: *mut core::ffi::c_void, lpdataobject: *mut core::ffi::c_void, data: super::super::Foundation::LPARAM, hint: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsole", "UpdateAllViews", 8usize, |this| ((**(this as *const *const IConsole_Vtbl)).UpdateAllViews)(this, lpdataobject, data, hint), |result| Some(*result))
+        }
+        unsafe extern "system" fn MessageBox(this: *mut core::ffi::c_void, lpsztext: windows_core::PCWSTR, lpsztitle: windows_core::PCWSTR, fustyle: u32, piretval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsole", "MessageBox", 9usize, |this| ((**(this as *const *const IConsole_Vtbl)).MessageBox)(this, lpsztext, lpsztitle, fustyle, piretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryConsoleVerb(this: *mut core::ffi::c_void, ppconsoleverb: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsole", "QueryConsoleVerb", 10usize, |this| ((**(this as *const *const IConsole_Vtbl)).QueryConsoleVerb)(this, ppconsoleverb), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectScopeItem(this: *mut core::ffi::c_void, hscopeitem: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsole", "SelectScopeItem", 11usize, |this| ((**(this as *const *const IConsole_Vtbl)).SelectScopeItem)(this, hscopeitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMainWindow(this: *mut core::ffi::c_void, phwnd: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsole", "GetMainWindow", 12usize, |this| ((**(this as *const *const IConsole_Vtbl)).GetMainWindow)(this, phwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn NewWindow(this: *mut core::ffi::c_void, hscopeitem: isize, loptions: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsole", "NewWindow", 13usize, |this| ((**(this as *const *const IConsole_Vtbl)).NewWindow)(this, hscopeitem, loptions), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetHeader,
+            SetToolbar,
+            QueryResultView,
+            QueryScopeImageList,
+            QueryResultImageList,
+            #[cfg(feature = "Win32_System_Com")]
+            UpdateAllViews,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            UpdateAllViews: 0,
+            MessageBox,
+            QueryConsoleVerb,
+            SelectScopeItem,
+            GetMainWindow,
+            NewWindow,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConsole as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IConsole2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Expand(this: *mut core::ffi::c_void, hitem: isize, bexpand: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsole2", "Expand", 14usize, |this| ((**(this as *const *const IConsole2_Vtbl)).Expand)(this, hitem, bexpand), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTaskpadViewPreferred(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsole2", "IsTaskpadViewPreferred", 15usize, |this| ((**(this as *const *const IConsole2_Vtbl)).IsTaskpadViewPreferred)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatusText(this: *mut core::ffi::c_void, pszstatustext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsole2", "SetStatusText", 16usize, |this| ((**(this as *const *const IConsole2_Vtbl)).SetStatusText)(this, pszstatustext), |result| Some(*result))
+        }
+        Self { base__: <IConsole_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Expand, IsTaskpadViewPreferred, SetStatusText }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConsole2 as windows_core::Interface>::IID || <IConsole_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IConsole3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RenameScopeItem(this: *mut core::ffi::c_void, hscopeitem: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsole3", "RenameScopeItem", 17usize, |this| ((**(this as *const *const IConsole3_Vtbl)).RenameScopeItem)(this, hscopeitem), |result| Some(*result))
+        }
+        Self { base__: <IConsole2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RenameScopeItem }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConsole3 as windows_core::Interface>::IID || <IConsole2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConsoleNameSpace_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InsertItem(this: *mut core::ffi::c_void, item: *mut SCOPEDATAITEM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsoleNameSpace", "InsertItem", 3usize, |this| ((**(this as *const *const IConsoleNameSpace_Vtbl)).InsertItem)(this, item), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteItem(this: *mut core::ffi::c_void, hitem: isize, fdeletethis: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsoleNameSpace", "DeleteItem", 4usize, |this| ((**(this as *const *const IConsoleNameSpace_Vtbl)).DeleteItem)(this, hitem, fdeletethis), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetItem(this: *mut core::ffi::c_void, item: *const SCOPEDATAITEM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsoleNameSpace", "SetItem", 5usize, |this| ((**(this as *const *const IConsoleNameSpace_Vtbl)).SetItem)(this, item), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItem(this: *mut core::ffi::c_void, item: *mut SCOPEDATAITEM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsoleNameSpace", "GetItem", 6usize, |this| ((**(this as *const *const IConsoleNameSpace_Vtbl)).GetItem)(this, item), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChildItem(this: *mut core::ffi::c_void, item: isize, pitemchild: *mut isize, pcookie: *mut isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsoleNameSpace", "GetChildItem", 7usize, |this| ((**(this as *const *const IConsoleNameSpace_Vtbl)).GetChildItem)(this, item, pitemchild, pcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextItem(this: *mut core::ffi::c_void, item: isize, pitemnext: *mut isize, pcookie: *mut isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsoleNameSpace", "GetNextItem", 8usize, |this| ((**(this as *const *const IConsoleNameSpace_Vtbl)).GetNextItem)(this, item, pitemnext, pcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParentItem(this: *mut core::ffi::c_void, item: isize, pitemparent: *mut isize, pcookie: *mut isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsoleNameSpace", "GetParentItem", 9usize, |this| ((**(this as *const *const IConsoleNameSpace_Vtbl)).GetParentItem)(this, item, pitemparent, pcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InsertItem,
+            DeleteItem,
+            SetItem,
+            GetItem,
+            GetChildItem,
+            GetNextItem,
+            GetParentItem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConsoleNameSpace as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConsoleNameSpace2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Expand(this: *mut core::ffi::c_void, hitem: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsoleNameSpace2", "Expand", 10usize, |this| ((**(this as *const *const IConsoleNameSpace2_Vtbl)).Expand)(this, hitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddExtension(this: *mut core::ffi::c_void, hitem: isize, lpclsid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsoleNameSpace2", "AddExtension", 11usize, |this| ((**(this as *const *const IConsoleNameSpace2_Vtbl)).AddExtension)(this, hitem, lpclsid), |result| Some(*result))
+        }
+        Self { base__: <IConsoleNameSpace_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Expand, AddExtension }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConsoleNameSpace2 as windows_core::Interface>::IID || <IConsoleNameSpace_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConsolePower_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetExecutionState(this: *mut core::ffi::c_void, dwadd: u32, dwremove: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsolePower", "SetExecutionState", 3usize, |this| ((**(this as *const *const IConsolePower_Vtbl)).SetExecutionState)(this, dwadd, dwremove), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetIdleTimer(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsolePower", "ResetIdleTimer", 4usize, |this| ((**(this as *const *const IConsolePower_Vtbl)).ResetIdleTimer)(this, dwflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetExecutionState, ResetIdleTimer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConsolePower as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConsolePowerSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnPowerBroadcast(this: *mut core::ffi::c_void, nevent: u32, lparam: super::super::Foundation::LPARAM, plreturn: *mut super::super::Foundation::LRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsolePowerSink", "OnPowerBroadcast", 3usize, |this| ((**(this as *const *const IConsolePowerSink_Vtbl)).OnPowerBroadcast)(this, nevent, lparam, plreturn), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnPowerBroadcast }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConsolePowerSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConsoleVerb_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetVerbState(this: *mut core::ffi::c_void, ecmdid: MMC_CONSOLE_VERB, nstate: MMC_BUTTON_STATE, pstate: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsoleVerb", "GetVerbState", 3usize, |this| ((**(this as *const *const IConsoleVerb_Vtbl)).GetVerbState)(this, ecmdid, nstate, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVerbState(this: *mut core::ffi::c_void, ecmdid: MMC_CONSOLE_VERB, nstate: MMC_BUTTON_STATE, bstate: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsoleVerb", "SetVerbState", 4usize, |this| ((**(this as *const *const IConsoleVerb_Vtbl)).SetVerbState)(this, ecmdid, nstate, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultVerb(this: *mut core::ffi::c_void, ecmdid: MMC_CONSOLE_VERB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsoleVerb", "SetDefaultVerb", 5usize, |this| ((**(this as *const *const IConsoleVerb_Vtbl)).SetDefaultVerb)(this, ecmdid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultVerb(this: *mut core::ffi::c_void, pecmdid: *mut MMC_CONSOLE_VERB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConsoleVerb", "GetDefaultVerb", 6usize, |this| ((**(this as *const *const IConsoleVerb_Vtbl)).GetDefaultVerb)(this, pecmdid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetVerbState, SetVerbState, SetDefaultVerb, GetDefaultVerb }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConsoleVerb as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContextMenuCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddItem(this: *mut core::ffi::c_void, pitem: *const CONTEXTMENUITEM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContextMenuCallback", "AddItem", 3usize, |this| ((**(this as *const *const IContextMenuCallback_Vtbl)).AddItem)(this, pitem), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddItem }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContextMenuCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContextMenuCallback2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddItem(this: *mut core::ffi::c_void, pitem: *const CONTEXTMENUITEM2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContextMenuCallback2", "AddItem", 3usize, |this| ((**(this as *const *const IContextMenuCallback2_Vtbl)).AddItem)(this, pitem), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddItem }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContextMenuCallback2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IContextMenuProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EmptyMenuList(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContextMenuProvider", "EmptyMenuList", 4usize, |this| ((**(this as *const *const IContextMenuProvider_Vtbl)).EmptyMenuList)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddPrimaryExtensionItems(this: *mut core::ffi::c_void, piextension: *mut core::ffi::c_void, pidataobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContextMenuProvider", "AddPrimaryExtensionItems", 5usize, |this| ((**(this as *const *const IContextMenuProvider_Vtbl)).AddPrimaryExtensionItems)(this, piextension, pidataobject), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddThirdPartyExtensionItems(this: *mut core::ffi::c_void, pidataobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContextMenuProvider", "AddThirdPartyExtensionItems", 6usize, |this| ((**(this as *const *const IContextMenuProvider_Vtbl)).AddThirdPartyExtensionItems)(this, pidataobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowContextMenu(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, xpos: i32, ypos: i32, plselected: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContextMenuProvider", "ShowContextMenu", 7usize, |this| ((**(this as *const *const IContextMenuProvider_Vtbl)).ShowContextMenu)(this, hwndparent, xpos, ypos, plselected), |result| Some(*result))
+        }
+        Self {
+            base__: <IContextMenuCallback_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EmptyMenuList,
+            #[cfg(feature = "Win32_System_Com")]
+            AddPrimaryExtensionItems,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddPrimaryExtensionItems: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AddThirdPartyExtensionItems,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddThirdPartyExtensionItems: 0,
+            ShowContextMenu,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContextMenuProvider as windows_core::Interface>::IID || <IContextMenuCallback_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IControlbar_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, ntype: MMC_CONTROL_TYPE, pextendcontrolbar: *mut core::ffi::c_void, ppunknown: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IControlbar", "Create", 3usize, |this| ((**(this as *const *const IControlbar_Vtbl)).Create)(this, ntype, pextendcontrolbar, ppunknown), |result| Some(*result))
+        }
+        unsafe extern "system" fn Attach(this: *mut core::ffi::c_void, ntype: MMC_CONTROL_TYPE, lpunknown: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IControlbar", "Attach", 4usize, |this| ((**(this as *const *const IControlbar_Vtbl)).Attach)(this, ntype, lpunknown), |result| Some(*result))
+        }
+        unsafe extern "system" fn Detach(this: *mut core::ffi::c_void, lpunknown: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IControlbar", "Detach", 5usize, |this| ((**(this as *const *const IControlbar_Vtbl)).Detach)(this, lpunknown), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create, Attach, Detach }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IControlbar as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayHelp_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowTopic(this: *mut core::ffi::c_void, pszhelptopic: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayHelp", "ShowTopic", 3usize, |this| ((**(this as *const *const IDisplayHelp_Vtbl)).ShowTopic)(this, pszhelptopic), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShowTopic }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayHelp as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumTASK_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut MMC_TASK, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTASK", "Next", 3usize, |this| ((**(this as *const *const IEnumTASK_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTASK", "Skip", 4usize, |this| ((**(this as *const *const IEnumTASK_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTASK", "Reset", 5usize, |this| ((**(this as *const *const IEnumTASK_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTASK", "Clone", 6usize, |this| ((**(this as *const *const IEnumTASK_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumTASK as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IExtendContextMenu_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddMenuItems(this: *mut core::ffi::c_void, pidataobject: *mut core::ffi::c_void, picallback: *mut core::ffi::c_void, pinsertionallowed: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendContextMenu", "AddMenuItems", 3usize, |this| ((**(this as *const *const IExtendContextMenu_Vtbl)).AddMenuItems)(this, pidataobject, picallback, pinsertionallowed), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Command(this: *mut core::ffi::c_void, lcommandid: i32, pidataobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendContextMenu", "Command", 4usize, |this| ((**(this as *const *const IExtendContextMenu_Vtbl)).Command)(this, lcommandid, pidataobject), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            AddMenuItems,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddMenuItems: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Command,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Command: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IExtendContextMenu as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IExtendControlbar_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetControlbar(this: *mut core::ffi::c_void, pcontrolbar: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendControlbar", "SetControlbar", 3usize, |this| ((**(this as *const *const IExtendControlbar_Vtbl)).SetControlbar)(this, pcontrolbar), |result| Some(*result))
+        }
+        unsafe extern "system" fn ControlbarNotify(this: *mut core::ffi::c_void, event: MMC_NOTIFY_TYPE, arg: super::super::Foundation::LPARAM, param2: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendControlbar", "ControlbarNotify", 4usize, |this| ((**(this as *const *const IExtendControlbar_Vtbl)).ControlbarNotify)(this, event, arg, param2), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetControlbar, ControlbarNotify }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IExtendControlbar as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IExtendPropertySheet_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreatePropertyPages(this: *mut core::ffi::c_void, lpprovider: *mut core::ffi::c_void, handle: isize, lpidataobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendPropertySheet", "CreatePropertyPages", 3usize, |this| ((**(this as *const *const IExtendPropertySheet_Vtbl)).CreatePropertyPages)(this, lpprovider, handle, lpidataobject), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn QueryPagesFor(this: *mut core::ffi::c_void, lpdataobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendPropertySheet", "QueryPagesFor", 4usize, |this| ((**(this as *const *const IExtendPropertySheet_Vtbl)).QueryPagesFor)(this, lpdataobject), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreatePropertyPages,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreatePropertyPages: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            QueryPagesFor,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            QueryPagesFor: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IExtendPropertySheet as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IExtendPropertySheet2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn GetWatermarks(this: *mut core::ffi::c_void, lpidataobject: *mut core::ffi::c_void, lphwatermark: *mut super::super::Graphics::Gdi::HBITMAP, lphheader: *mut super::super::Graphics::Gdi::HBITMAP, lphpalette: *mut super::super::Graphics::Gdi::HPALETTE, bstretch: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendPropertySheet2", "GetWatermarks", 5usize, |this| ((**(this as *const *const IExtendPropertySheet2_Vtbl)).GetWatermarks)(this, lpidataobject, lphwatermark, lphheader, lphpalette, bstretch), |result| Some(*result))
+        }
+        Self {
+            base__: <IExtendPropertySheet_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+            GetWatermarks,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com")))]
+            GetWatermarks: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IExtendPropertySheet2 as windows_core::Interface>::IID || <IExtendPropertySheet_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IExtendTaskPad_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn TaskNotify(this: *mut core::ffi::c_void, pdo: *mut core::ffi::c_void, arg: *const core::mem::MaybeUninit<windows_core::VARIANT>, param2: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendTaskPad", "TaskNotify", 3usize, |this| ((**(this as *const *const IExtendTaskPad_Vtbl)).TaskNotify)(this, pdo, arg, param2), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumTasks(this: *mut core::ffi::c_void, pdo: *mut core::ffi::c_void, sztaskgroup: windows_core::PCWSTR, ppenumtask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendTaskPad", "EnumTasks", 4usize, |this| ((**(this as *const *const IExtendTaskPad_Vtbl)).EnumTasks)(this, pdo, sztaskgroup, ppenumtask), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTitle(this: *mut core::ffi::c_void, pszgroup: windows_core::PCWSTR, psztitle: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendTaskPad", "GetTitle", 5usize, |this| ((**(this as *const *const IExtendTaskPad_Vtbl)).GetTitle)(this, pszgroup, psztitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescriptiveText(this: *mut core::ffi::c_void, pszgroup: windows_core::PCWSTR, pszdescriptivetext: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendTaskPad", "GetDescriptiveText", 6usize, |this| ((**(this as *const *const IExtendTaskPad_Vtbl)).GetDescriptiveText)(this, pszgroup, pszdescriptivetext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBackground(this: *mut core::ffi::c_void, pszgroup: windows_core::PCWSTR, ptdo: *mut MMC_TASK_DISPLAY_OBJECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendTaskPad", "GetBackground", 7usize, |this| ((**(this as *const *const IExtendTaskPad_Vtbl)).GetBackground)(this, pszgroup, ptdo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetListPadInfo(this: *mut core::ffi::c_void, pszgroup: windows_core::PCWSTR, lplistpadinfo: *mut MMC_LISTPAD_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendTaskPad", "GetListPadInfo", 8usize, |this| ((**(this as *const *const IExtendTaskPad_Vtbl)).GetListPadInfo)(this, pszgroup, lplistpadinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            TaskNotify,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            TaskNotify: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumTasks,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumTasks: 0,
+            GetTitle,
+            GetDescriptiveText,
+            GetBackground,
+            GetListPadInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IExtendTaskPad as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IExtendView_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetViews(this: *mut core::ffi::c_void, pdataobject: *mut core::ffi::c_void, pviewextensioncallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExtendView", "GetViews", 3usize, |this| ((**(this as *const *const IExtendView_Vtbl)).GetViews)(this, pdataobject, pviewextensioncallback), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetViews,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetViews: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IExtendView as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHeaderCtrl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InsertColumn(this: *mut core::ffi::c_void, ncol: i32, title: windows_core::PCWSTR, nformat: i32, nwidth: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderCtrl", "InsertColumn", 3usize, |this| ((**(this as *const *const IHeaderCtrl_Vtbl)).InsertColumn)(this, ncol, title, nformat, nwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteColumn(this: *mut core::ffi::c_void, ncol: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderCtrl", "DeleteColumn", 4usize, |this| ((**(this as *const *const IHeaderCtrl_Vtbl)).DeleteColumn)(this, ncol), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColumnText(this: *mut core::ffi::c_void, ncol: i32, title: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderCtrl", "SetColumnText", 5usize, |this| ((**(this as *const *const IHeaderCtrl_Vtbl)).SetColumnText)(this, ncol, title), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColumnText(this: *mut core::ffi::c_void, ncol: i32, ptext: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderCtrl", "GetColumnText", 6usize, |this| ((**(this as *const *const IHeaderCtrl_Vtbl)).GetColumnText)(this, ncol, ptext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColumnWidth(this: *mut core::ffi::c_void, ncol: i32, nwidth: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderCtrl", "SetColumnWidth", 7usize, |this| ((**(this as *const *const IHeaderCtrl_Vtbl)).SetColumnWidth)(this, ncol, nwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColumnWidth(this: *mut core::ffi::c_void, ncol: i32, pwidth: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderCtrl", "GetColumnWidth", 8usize, |this| ((**(this as *const *const IHeaderCtrl_Vtbl)).GetColumnWidth)(this, ncol, pwidth), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InsertColumn,
+            DeleteColumn,
+            SetColumnText,
+            GetColumnText,
+            SetColumnWidth,
+            GetColumnWidth,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHeaderCtrl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHeaderCtrl2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetChangeTimeOut(this: *mut core::ffi::c_void, utimeout: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderCtrl2", "SetChangeTimeOut", 9usize, |this| ((**(this as *const *const IHeaderCtrl2_Vtbl)).SetChangeTimeOut)(this, utimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColumnFilter(this: *mut core::ffi::c_void, ncolumn: u32, dwtype: u32, pfilterdata: *const MMC_FILTERDATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderCtrl2", "SetColumnFilter", 10usize, |this| ((**(this as *const *const IHeaderCtrl2_Vtbl)).SetColumnFilter)(this, ncolumn, dwtype, pfilterdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColumnFilter(this: *mut core::ffi::c_void, ncolumn: u32, pdwtype: *mut u32, pfilterdata: *mut MMC_FILTERDATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderCtrl2", "GetColumnFilter", 11usize, |this| ((**(this as *const *const IHeaderCtrl2_Vtbl)).GetColumnFilter)(this, ncolumn, pdwtype, pfilterdata), |result| Some(*result))
+        }
+        Self { base__: <IHeaderCtrl_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetChangeTimeOut, SetColumnFilter, GetColumnFilter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHeaderCtrl2 as windows_core::Interface>::IID || <IHeaderCtrl_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IImageList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ImageListSetIcon(this: *mut core::ffi::c_void, picon: *const isize, nloc: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "ImageListSetIcon", 3usize, |this| ((**(this as *const *const IImageList_Vtbl)).ImageListSetIcon)(this, picon, nloc), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImageListSetStrip(this: *mut core::ffi::c_void, pbmapsm: *const isize, pbmaplg: *const isize, nstartloc: i32, cmask: super::super::Foundation::COLORREF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "ImageListSetStrip", 4usize, |this| ((**(this as *const *const IImageList_Vtbl)).ImageListSetStrip)(this, pbmapsm, pbmaplg, nstartloc, cmask), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ImageListSetIcon, ImageListSetStrip }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImageList as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMMCVersionInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMMCVersion(this: *mut core::ffi::c_void, pversionmajor: *mut i32, pversionminor: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMMCVersionInfo", "GetMMCVersion", 3usize, |this| ((**(this as *const *const IMMCVersionInfo_Vtbl)).GetMMCVersion)(this, pversionmajor, pversionminor), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMMCVersion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMMCVersionInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMenuButton_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddButton(this: *mut core::ffi::c_void, idcommand: i32, lpbuttontext: windows_core::PCWSTR, lptooltiptext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMenuButton", "AddButton", 3usize, |this| ((**(this as *const *const IMenuButton_Vtbl)).AddButton)(this, idcommand, lpbuttontext, lptooltiptext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetButton(this: *mut core::ffi::c_void, idcommand: i32, lpbuttontext: windows_core::PCWSTR, lptooltiptext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMenuButton", "SetButton", 4usize, |this| ((**(this as *const *const IMenuButton_Vtbl)).SetButton)(this, idcommand, lpbuttontext, lptooltiptext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetButtonState(this: *mut core::ffi::c_void, idcommand: i32, nstate: MMC_BUTTON_STATE, bstate: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMenuButton", "SetButtonState", 5usize, |this| ((**(this as *const *const IMenuButton_Vtbl)).SetButtonState)(this, idcommand, nstate, bstate), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddButton, SetButton, SetButtonState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMenuButton as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMessageView_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetTitleText(this: *mut core::ffi::c_void, psztitletext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageView", "SetTitleText", 3usize, |this| ((**(this as *const *const IMessageView_Vtbl)).SetTitleText)(this, psztitletext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBodyText(this: *mut core::ffi::c_void, pszbodytext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageView", "SetBodyText", 4usize, |this| ((**(this as *const *const IMessageView_Vtbl)).SetBodyText)(this, pszbodytext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIcon(this: *mut core::ffi::c_void, id: IconIdentifier) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageView", "SetIcon", 5usize, |this| ((**(this as *const *const IMessageView_Vtbl)).SetIcon)(this, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageView", "Clear", 6usize, |this| ((**(this as *const *const IMessageView_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetTitleText, SetBodyText, SetIcon, Clear }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMessageView as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INodeProperties_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, pdataobject: *mut core::ffi::c_void, szpropertyname: core::mem::MaybeUninit<windows_core::BSTR>, pbstrproperty: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INodeProperties", "GetProperty", 3usize, |this| ((**(this as *const *const INodeProperties_Vtbl)).GetProperty)(this, pdataobject, szpropertyname, pbstrproperty), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetProperty,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetProperty: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INodeProperties as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Controls")]
+impl windows_core::imp::ProxyVtable for IPropertySheetCallback_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_Controls")]
+        unsafe extern "system" fn AddPage(this: *mut core::ffi::c_void, hpage: super::super::UI::Controls::HPROPSHEETPAGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertySheetCallback", "AddPage", 3usize, |this| ((**(this as *const *const IPropertySheetCallback_Vtbl)).AddPage)(this, hpage), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Controls")]
+        unsafe extern "system" fn RemovePage(this: *mut core::ffi::c_void, hpage: super::super::UI::Controls::HPROPSHEETPAGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertySheetCallback", "RemovePage", 4usize, |this| ((**(this as *const *const IPropertySheetCallback_Vtbl)).RemovePage)(this, hpage), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_Controls")]
+            AddPage,
+            #[cfg(not(feature = "Win32_UI_Controls"))]
+            AddPage: 0,
+            #[cfg(feature = "Win32_UI_Controls")]
+            RemovePage,
+            #[cfg(not(feature = "Win32_UI_Controls"))]
+            RemovePage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertySheetCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPropertySheetProvider_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreatePropertySheet(this: *mut core::ffi::c_void, title: windows_core::PCWSTR, r#type: u8, cookie: isize, pidataobjectm: *mut core::ffi::c_void, dwoptions: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertySheetProvider", "CreatePropertySheet", 3usize, |this| ((**(this as *const *const IPropertySheetProvider_Vtbl)).CreatePropertySheet)(this, title, r#type, cookie, pidataobjectm, dwoptions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn FindPropertySheet(this: *mut core::ffi::c_void, hitem: isize, lpcomponent: *mut core::ffi::c_void, lpdataobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertySheetProvider", "FindPropertySheet", 4usize, |this| ((**(this as *const *const IPropertySheetProvider_Vtbl)).FindPropertySheet)(this, hitem, lpcomponent, lpdataobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPrimaryPages(this: *mut core::ffi::c_void, lpunknown: *mut core::ffi::c_void, bcreatehandle: super::super::Foundation::BOOL, hnotifywindow: super::super::Foundation::HWND, bscopepane: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertySheetProvider", "AddPrimaryPages", 5usize, |this| ((**(this as *const *const IPropertySheetProvider_Vtbl)).AddPrimaryPages)(this, lpunknown, bcreatehandle, hnotifywindow, bscopepane), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddExtensionPages(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertySheetProvider", "AddExtensionPages", 6usize, |this| ((**(this as *const *const IPropertySheetProvider_Vtbl)).AddExtensionPages)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Show(this: *mut core::ffi::c_void, window: isize, page: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertySheetProvider", "Show", 7usize, |this| ((**(this as *const *const IPropertySheetProvider_Vtbl)).Show)(this, window, page), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreatePropertySheet,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreatePropertySheet: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            FindPropertySheet,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            FindPropertySheet: 0,
+            AddPrimaryPages,
+            AddExtensionPages,
+            Show,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertySheetProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRequiredExtensions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnableAllExtensions(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRequiredExtensions", "EnableAllExtensions", 3usize, |this| ((**(this as *const *const IRequiredExtensions_Vtbl)).EnableAllExtensions)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFirstExtension(this: *mut core::ffi::c_void, pextclsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRequiredExtensions", "GetFirstExtension", 4usize, |this| ((**(this as *const *const IRequiredExtensions_Vtbl)).GetFirstExtension)(this, pextclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextExtension(this: *mut core::ffi::c_void, pextclsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRequiredExtensions", "GetNextExtension", 5usize, |this| ((**(this as *const *const IRequiredExtensions_Vtbl)).GetNextExtension)(this, pextclsid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnableAllExtensions, GetFirstExtension, GetNextExtension }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRequiredExtensions as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IResultData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InsertItem(this: *mut core::ffi::c_void, item: *mut RESULTDATAITEM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResultData", "InsertItem", 3usize, |this| ((**(this as *const *const IResultData_Vtbl)).InsertItem)(this, item), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteItem(this: *mut core::ffi::c_void, itemid: isize, ncol: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResultData", "DeleteItem", 4usize, |this| ((**(this as *const *const IResultData_Vtbl)).DeleteItem)(this, itemid, ncol), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindItemByLParam(this: *mut core::ffi::c_void, lparam: super::super::Foundation::LPARAM, pitemid: *mut isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResultData", "FindItemByLParam", 5usize, |this| ((**(this as *const *const IResultData_Vtbl)).FindItemByLParam)(this, lparam, pitemid), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAllRsltItems(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResultData", "DeleteAllRsltItems", 6usize, |this| ((**(this as *const *const IResultData_Vtbl)).DeleteAllRsltItems)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetItem(this: *mut core::ffi::c_void, item: *const RESULTDATAITEM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResultData", "SetItem", 7usize, |this| ((**(this as *const *const IResultData_Vtbl)).SetItem)(this, item), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItem(this: *mut core::ffi::c_void, item: *mut RESULTDATAITEM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResultData", "GetItem", 8usize, |this| ((**(this as *const *const IResultData_Vtbl)).GetItem)(this, item), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextItem(this: *mut core::ffi::c_void, item: *mut RESULTDATAITEM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResultData", "GetNextItem", 9usize, |this| ((**(this as *const *const IResultData_Vtbl)).GetNextItem)(this, item), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModifyItemState(this: *mut core::ffi::c_void, nindex: i32, itemid: isize, uadd: u32, uremove: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResultData", "ModifyItemState", 10usize, |this| ((**(this as *const *const IResultData_Vtbl)).ModifyItemState)(this, nindex, itemid, uadd, uremove), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModifyViewStyle(this: *mut core::ffi::c_void, add: MMC_RESULT_VIEW_STYLE, remove: MMC_RESULT_VIEW_STYLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResultData", "ModifyViewStyle", 11usize, |this| ((**(this as *const *const IResultData_Vtbl)).ModifyViewStyle)(this, add, remove), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetViewMode(this: *mut core::ffi::c_void, lviewmode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResultData", "SetViewMode", 12usize, |this| ((**(this as *const *const IResultData_Vtbl)).SetViewMode)(this, lviewmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetViewMode(this: *mut core::ffi::c_void, lviewmode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResultData", "GetViewMode", 13usize, |this| ((**(this as *const *const IResultData_Vtbl)).GetViewMode)(this, lviewmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateItem(this: *mut core::ffi::c_void, itemid: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResultData", "UpdateItem", 14usize, |this| ((**(this as *const *const IResultData_Vtbl)).UpdateItem)(this, itemid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Sort(this: *mut core::ffi::c_void, ncolumn: i32, dwsortoptions: u32, luserparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResultData", "Sort", 15usize, |this| ((**(this as *const *const IResultData_Vtbl)).Sort)(this, ncolumn, dwsortoptions, luserparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescBarText(this: *mut core::ffi::c_void, desctext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResultData", "SetDescBarText", 16usize, |this| ((**(this as *const *const IResultData_Vtbl)).SetDescBarText)(this, desctext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetItemCount(this: *mut core::ffi::c_void, nitemcount: i32, dwoptions: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResultData", "SetItemCount", 17usize, |this| ((**(this as *const *const IResultData_Vtbl)).SetItemCount)(this, nitemcount, dwoptions), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InsertItem,
+            DeleteItem,
+            FindItemByLParam,
+            DeleteAllRsltItems,
+            SetItem,
+            GetItem,
+            GetNextItem,
+            ModifyItemState,
+            ModifyViewStyle,
+            SetViewMode,
+            GetViewMode,
+            UpdateItem,
+            Sort,
+            SetDescBarText,
+            SetItemCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResultData as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IResultData2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RenameResultItem(this: *mut core::ffi::c_void, itemid: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResultData2", "RenameResultItem", 18usize, |this| ((**(this as *const *const IResultData2_Vtbl)).RenameResultItem)(this, itemid), |result| Some(*result))
+        }
+        Self { base__: <IResultData_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RenameResultItem }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResultData2 as windows_core::Interface>::IID || <IResultData_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IResultDataCompare_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Compare(this: *mut core::ffi::c_void, luserparam: super::super::Foundation::LPARAM, cookiea: isize, cookieb: isize, pnresult: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResultDataCompare", "Compare", 3usize, |this| ((**(this as *const *const IResultDataCompare_Vtbl)).Compare)(this, luserparam, cookiea, cookieb, pnresult), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Compare }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResultDataCompare as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IResultDataCompareEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Compare(this: *mut core::ffi::c_void, prdc: *const RDCOMPARE, pnresult: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResultDataCompareEx", "Compare", 3usize, |this| ((**(this as *const *const IResultDataCompareEx_Vtbl)).Compare)(this, prdc, pnresult), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Compare }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResultDataCompareEx as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IResultOwnerData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FindItem(this: *mut core::ffi::c_void, pfindinfo: *const RESULTFINDINFO, pnfoundindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResultOwnerData", "FindItem", 3usize, |this| ((**(this as *const *const IResultOwnerData_Vtbl)).FindItem)(this, pfindinfo, pnfoundindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn CacheHint(this: *mut core::ffi::c_void, nstartindex: i32, nendindex: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResultOwnerData", "CacheHint", 4usize, |this| ((**(this as *const *const IResultOwnerData_Vtbl)).CacheHint)(this, nstartindex, nendindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn SortItems(this: *mut core::ffi::c_void, ncolumn: i32, dwsortoptions: u32, luserparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IResultOwnerData", "SortItems", 5usize, |this| ((**(this as *const *const IResultOwnerData_Vtbl)).SortItems)(this, ncolumn, dwsortoptions, luserparam), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FindItem, CacheHint, SortItems }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IResultOwnerData as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+impl windows_core::imp::ProxyVtable for ISnapinAbout_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSnapinDescription(this: *mut core::ffi::c_void, lpdescription: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISnapinAbout", "GetSnapinDescription", 3usize, |this| ((**(this as *const *const ISnapinAbout_Vtbl)).GetSnapinDescription)(this, lpdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProvider(this: *mut core::ffi::c_void, lpname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISnapinAbout", "GetProvider", 4usize, |this| ((**(this as *const *const ISnapinAbout_Vtbl)).GetProvider)(this, lpname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSnapinVersion(this: *mut core::ffi::c_void, lpversion: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISnapinAbout", "GetSnapinVersion", 5usize, |this| ((**(this as *const *const ISnapinAbout_Vtbl)).GetSnapinVersion)(this, lpversion), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn GetSnapinImage(this: *mut core::ffi::c_void, happicon: *mut super::super::UI::WindowsAndMessaging::HICON) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISnapinAbout", "GetSnapinImage", 6usize, |this| ((**(this as *const *const ISnapinAbout_Vtbl)).GetSnapinImage)(this, happicon), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetStaticFolderImage(this: *mut core::ffi::c_void, hsmallimage: *mut super::super::Graphics::Gdi::HBITMAP, hsmallimageopen: *mut super::super::Graphics::Gdi::HBITMAP, hlargeimage: *mut super::super::Graphics::Gdi::HBITMAP, cmask: *mut super::super::Foundation::COLORREF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISnapinAbout", "GetStaticFolderImage", 7usize, |this| ((**(this as *const *const ISnapinAbout_Vtbl)).GetStaticFolderImage)(this, hsmallimage, hsmallimageopen, hlargeimage, cmask), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSnapinDescription,
+            GetProvider,
+            GetSnapinVersion,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            GetSnapinImage,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            GetSnapinImage: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetStaticFolderImage,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetStaticFolderImage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISnapinAbout as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISnapinHelp_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetHelpTopic(this: *mut core::ffi::c_void, lpcompiledhelpfile: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISnapinHelp", "GetHelpTopic", 3usize, |this| ((**(this as *const *const ISnapinHelp_Vtbl)).GetHelpTopic)(this, lpcompiledhelpfile), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetHelpTopic }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISnapinHelp as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISnapinHelp2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLinkedTopics(this: *mut core::ffi::c_void, lpcompiledhelpfiles: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISnapinHelp2", "GetLinkedTopics", 4usize, |this| ((**(this as *const *const ISnapinHelp2_Vtbl)).GetLinkedTopics)(this, lpcompiledhelpfiles), |result| Some(*result))
+        }
+        Self { base__: <ISnapinHelp_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetLinkedTopics }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISnapinHelp2 as windows_core::Interface>::IID || <ISnapinHelp_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISnapinProperties_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pproperties: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISnapinProperties", "Initialize", 3usize, |this| ((**(this as *const *const ISnapinProperties_Vtbl)).Initialize)(this, pproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryPropertyNames(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISnapinProperties", "QueryPropertyNames", 4usize, |this| ((**(this as *const *const ISnapinProperties_Vtbl)).QueryPropertyNames)(this, pcallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn PropertiesChanged(this: *mut core::ffi::c_void, cproperties: i32, pproperties: *const MMC_SNAPIN_PROPERTY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISnapinProperties", "PropertiesChanged", 5usize, |this| ((**(this as *const *const ISnapinProperties_Vtbl)).PropertiesChanged)(this, cproperties, pproperties), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Initialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Initialize: 0,
+            QueryPropertyNames,
+            PropertiesChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISnapinProperties as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISnapinPropertiesCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddPropertyName(this: *mut core::ffi::c_void, pszpropname: windows_core::PCWSTR, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISnapinPropertiesCallback", "AddPropertyName", 3usize, |this| ((**(this as *const *const ISnapinPropertiesCallback_Vtbl)).AddPropertyName)(this, pszpropname, dwflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddPropertyName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISnapinPropertiesCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IStringTable_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddString(this: *mut core::ffi::c_void, pszadd: windows_core::PCWSTR, pstringid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStringTable", "AddString", 3usize, |this| ((**(this as *const *const IStringTable_Vtbl)).AddString)(this, pszadd, pstringid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetString(this: *mut core::ffi::c_void, stringid: u32, cchbuffer: u32, lpbuffer: windows_core::PWSTR, pcchout: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStringTable", "GetString", 4usize, |this| ((**(this as *const *const IStringTable_Vtbl)).GetString)(this, stringid, cchbuffer, lpbuffer, pcchout), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStringLength(this: *mut core::ffi::c_void, stringid: u32, pcchstring: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStringTable", "GetStringLength", 5usize, |this| ((**(this as *const *const IStringTable_Vtbl)).GetStringLength)(this, stringid, pcchstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteString(this: *mut core::ffi::c_void, stringid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStringTable", "DeleteString", 6usize, |this| ((**(this as *const *const IStringTable_Vtbl)).DeleteString)(this, stringid), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAllStrings(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStringTable", "DeleteAllStrings", 7usize, |this| ((**(this as *const *const IStringTable_Vtbl)).DeleteAllStrings)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindString(this: *mut core::ffi::c_void, pszfind: windows_core::PCWSTR, pstringid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStringTable", "FindString", 8usize, |this| ((**(this as *const *const IStringTable_Vtbl)).FindString)(this, pszfind, pstringid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Enumerate(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStringTable", "Enumerate", 9usize, |this| ((**(this as *const *const IStringTable_Vtbl)).Enumerate)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddString,
+            GetString,
+            GetStringLength,
+            DeleteString,
+            DeleteAllStrings,
+            FindString,
+            #[cfg(feature = "Win32_System_Com")]
+            Enumerate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Enumerate: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStringTable as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IToolbar_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn AddBitmap(this: *mut core::ffi::c_void, nimages: i32, hbmp: super::super::Graphics::Gdi::HBITMAP, cxsize: i32, cysize: i32, crmask: super::super::Foundation::COLORREF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToolbar", "AddBitmap", 3usize, |this| ((**(this as *const *const IToolbar_Vtbl)).AddBitmap)(this, nimages, hbmp, cxsize, cysize, crmask), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddButtons(this: *mut core::ffi::c_void, nbuttons: i32, lpbuttons: *const MMCBUTTON) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToolbar", "AddButtons", 4usize, |this| ((**(this as *const *const IToolbar_Vtbl)).AddButtons)(this, nbuttons, lpbuttons), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertButton(this: *mut core::ffi::c_void, nindex: i32, lpbutton: *const MMCBUTTON) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToolbar", "InsertButton", 5usize, |this| ((**(this as *const *const IToolbar_Vtbl)).InsertButton)(this, nindex, lpbutton), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteButton(this: *mut core::ffi::c_void, nindex: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToolbar", "DeleteButton", 6usize, |this| ((**(this as *const *const IToolbar_Vtbl)).DeleteButton)(this, nindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetButtonState(this: *mut core::ffi::c_void, idcommand: i32, nstate: MMC_BUTTON_STATE, pstate: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToolbar", "GetButtonState", 7usize, |this| ((**(this as *const *const IToolbar_Vtbl)).GetButtonState)(this, idcommand, nstate, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetButtonState(this: *mut core::ffi::c_void, idcommand: i32, nstate: MMC_BUTTON_STATE, bstate: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToolbar", "SetButtonState", 8usize, |this| ((**(this as *const *const IToolbar_Vtbl)).SetButtonState)(this, idcommand, nstate, bstate), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            AddBitmap,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            AddBitmap: 0,
+            AddButtons,
+            InsertButton,
+            DeleteButton,
+            GetButtonState,
+            SetButtonState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToolbar as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IViewExtensionCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddView(this: *mut core::ffi::c_void, pextviewdata: *const MMC_EXT_VIEW_DATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewExtensionCallback", "AddView", 3usize, |this| ((**(this as *const *const IViewExtensionCallback_Vtbl)).AddView)(this, pextviewdata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IViewExtensionCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for MenuItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, displayname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "MenuItem", "DisplayName", 7usize, |this| ((**(this as *const *const MenuItem_Vtbl)).DisplayName)(this, displayname), |result| Some(*result))
+        }
+        unsafe extern "system" fn LanguageIndependentName(this: *mut core::ffi::c_void, languageindependentname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "MenuItem", "LanguageIndependentName", 8usize, |this| ((**(this as *const *const MenuItem_Vtbl)).LanguageIndependentName)(this, languageindependentname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, path: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "MenuItem", "Path", 9usize, |this| ((**(this as *const *const MenuItem_Vtbl)).Path)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn LanguageIndependentPath(this: *mut core::ffi::c_void, languageindependentpath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "MenuItem", "LanguageIndependentPath", 10usize, |this| ((**(this as *const *const MenuItem_Vtbl)).LanguageIndependentPath)(this, languageindependentpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn Execute(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "MenuItem", "Execute", 11usize, |this| ((**(this as *const *const MenuItem_Vtbl)).Execute)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, enabled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "MenuItem", "Enabled", 12usize, |this| ((**(this as *const *const MenuItem_Vtbl)).Enabled)(this, enabled), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DisplayName,
+            LanguageIndependentName,
+            Path,
+            LanguageIndependentPath,
+            Execute,
+            Enabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<MenuItem as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for Node_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Node", "Name", 7usize, |this| ((**(this as *const *const Node_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Property(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::BSTR>, propertyvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Node", "get_Property", 8usize, |this| ((**(this as *const *const Node_Vtbl)).get_Property)(this, propertyname, propertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bookmark(this: *mut core::ffi::c_void, bookmark: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Node", "Bookmark", 9usize, |this| ((**(this as *const *const Node_Vtbl)).Bookmark)(this, bookmark), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsScopeNode(this: *mut core::ffi::c_void, isscopenode: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Node", "IsScopeNode", 10usize, |this| ((**(this as *const *const Node_Vtbl)).IsScopeNode)(this, isscopenode), |result| Some(*result))
+        }
+        unsafe extern "system" fn Nodetype(this: *mut core::ffi::c_void, nodetype: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Node", "Nodetype", 11usize, |this| ((**(this as *const *const Node_Vtbl)).Nodetype)(this, nodetype), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, get_Property, Bookmark, IsScopeNode, Nodetype }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<Node as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for Nodes_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Nodes", "_NewEnum", 7usize, |this| ((**(this as *const *const Nodes_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: i32, node: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Nodes", "Item", 8usize, |this| ((**(this as *const *const Nodes_Vtbl)).Item)(this, index, node), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Nodes", "Count", 9usize, |this| ((**(this as *const *const Nodes_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            Count,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<Nodes as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for Properties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Properties", "_NewEnum", 7usize, |this| ((**(this as *const *const Properties_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, property: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Properties", "Item", 8usize, |this| ((**(this as *const *const Properties_Vtbl)).Item)(this, name, property), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Properties", "Count", 9usize, |this| ((**(this as *const *const Properties_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Properties", "Remove", 10usize, |this| ((**(this as *const *const Properties_Vtbl)).Remove)(this, name), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            Count,
+            Remove,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<Properties as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for Property_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Property", "Value", 7usize, |this| ((**(this as *const *const Property_Vtbl)).Value)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Property", "SetValue", 8usize, |this| ((**(this as *const *const Property_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Property", "Name", 9usize, |this| ((**(this as *const *const Property_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Value, SetValue, Name }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<Property as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ScopeNamespace_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetParent(this: *mut core::ffi::c_void, node: *mut core::ffi::c_void, parent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ScopeNamespace", "GetParent", 7usize, |this| ((**(this as *const *const ScopeNamespace_Vtbl)).GetParent)(this, node, parent), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetChild(this: *mut core::ffi::c_void, node: *mut core::ffi::c_void, child: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ScopeNamespace", "GetChild", 8usize, |this| ((**(this as *const *const ScopeNamespace_Vtbl)).GetChild)(this, node, child), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetNext(this: *mut core::ffi::c_void, node: *mut core::ffi::c_void, next: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ScopeNamespace", "GetNext", 9usize, |this| ((**(this as *const *const ScopeNamespace_Vtbl)).GetNext)(this, node, next), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetRoot(this: *mut core::ffi::c_void, root: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ScopeNamespace", "GetRoot", 10usize, |this| ((**(this as *const *const ScopeNamespace_Vtbl)).GetRoot)(this, root), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Expand(this: *mut core::ffi::c_void, node: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ScopeNamespace", "Expand", 11usize, |this| ((**(this as *const *const ScopeNamespace_Vtbl)).Expand)(this, node), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetParent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetParent: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetChild,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetChild: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetNext,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetNext: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetRoot,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetRoot: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Expand,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Expand: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ScopeNamespace as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for SnapIn_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "SnapIn", "Name", 7usize, |this| ((**(this as *const *const SnapIn_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn Vendor(this: *mut core::ffi::c_void, vendor: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "SnapIn", "Vendor", 8usize, |this| ((**(this as *const *const SnapIn_Vtbl)).Vendor)(this, vendor), |result| Some(*result))
+        }
+        unsafe extern "system" fn Version(this: *mut core::ffi::c_void, version: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "SnapIn", "Version", 9usize, |this| ((**(this as *const *const SnapIn_Vtbl)).Version)(this, version), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Extensions(this: *mut core::ffi::c_void, extensions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "SnapIn", "Extensions", 10usize, |this| ((**(this as *const *const SnapIn_Vtbl)).Extensions)(this, extensions), |result| Some(*result))
+        }
+        unsafe extern "system" fn SnapinCLSID(this: *mut core::ffi::c_void, snapinclsid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "SnapIn", "SnapinCLSID", 11usize, |this| ((**(this as *const *const SnapIn_Vtbl)).SnapinCLSID)(this, snapinclsid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, properties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "SnapIn", "Properties", 12usize, |this| ((**(this as *const *const SnapIn_Vtbl)).Properties)(this, properties), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableAllExtensions(this: *mut core::ffi::c_void, enable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "SnapIn", "EnableAllExtensions", 13usize, |this| ((**(this as *const *const SnapIn_Vtbl)).EnableAllExtensions)(this, enable), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            Vendor,
+            Version,
+            #[cfg(feature = "Win32_System_Com")]
+            Extensions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Extensions: 0,
+            SnapinCLSID,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+            EnableAllExtensions,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<SnapIn as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for SnapIns_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "SnapIns", "_NewEnum", 7usize, |this| ((**(this as *const *const SnapIns_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: i32, snapin: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "SnapIns", "Item", 8usize, |this| ((**(this as *const *const SnapIns_Vtbl)).Item)(this, index, snapin), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "SnapIns", "Count", 9usize, |this| ((**(this as *const *const SnapIns_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, snapinnameorclsid: core::mem::MaybeUninit<windows_core::BSTR>, parentsnapin: core::mem::MaybeUninit<windows_core::VARIANT>, properties: core::mem::MaybeUninit<windows_core::VARIANT>, snapin: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "SnapIns", "Add", 10usize, |this| ((**(this as *const *const SnapIns_Vtbl)).Add)(this, snapinnameorclsid, parentsnapin, properties, snapin), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, snapin: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "SnapIns", "Remove", 11usize, |this| ((**(this as *const *const SnapIns_Vtbl)).Remove)(this, snapin), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Remove,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Remove: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<SnapIns as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for View_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ActiveScopeNode(this: *mut core::ffi::c_void, node: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "ActiveScopeNode", 7usize, |this| ((**(this as *const *const View_Vtbl)).ActiveScopeNode)(this, node), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetActiveScopeNode(this: *mut core::ffi::c_void, node: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "SetActiveScopeNode", 8usize, |this| ((**(this as *const *const View_Vtbl)).SetActiveScopeNode)(this, node), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Selection(this: *mut core::ffi::c_void, nodes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "Selection", 9usize, |this| ((**(this as *const *const View_Vtbl)).Selection)(this, nodes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ListItems(this: *mut core::ffi::c_void, nodes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "ListItems", 10usize, |this| ((**(this as *const *const View_Vtbl)).ListItems)(this, nodes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SnapinScopeObject(this: *mut core::ffi::c_void, scopenode: core::mem::MaybeUninit<windows_core::VARIANT>, scopenodeobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "SnapinScopeObject", 11usize, |this| ((**(this as *const *const View_Vtbl)).SnapinScopeObject)(this, scopenode, scopenodeobject), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SnapinSelectionObject(this: *mut core::ffi::c_void, selectionobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "SnapinSelectionObject", 12usize, |this| ((**(this as *const *const View_Vtbl)).SnapinSelectionObject)(this, selectionobject), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Is(this: *mut core::ffi::c_void, view: *mut core::ffi::c_void, thesame: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "Is", 13usize, |this| ((**(this as *const *const View_Vtbl)).Is)(this, view, thesame), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Document(this: *mut core::ffi::c_void, document: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "Document", 14usize, |this| ((**(this as *const *const View_Vtbl)).Document)(this, document), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "SelectAll", 15usize, |this| ((**(this as *const *const View_Vtbl)).SelectAll)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Select(this: *mut core::ffi::c_void, node: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "Select", 16usize, |this| ((**(this as *const *const View_Vtbl)).Select)(this, node), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Deselect(this: *mut core::ffi::c_void, node: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "Deselect", 17usize, |this| ((**(this as *const *const View_Vtbl)).Deselect)(this, node), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn IsSelected(this: *mut core::ffi::c_void, node: *mut core::ffi::c_void, isselected: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "IsSelected", 18usize, |this| ((**(this as *const *const View_Vtbl)).IsSelected)(this, node, isselected), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayScopeNodePropertySheet(this: *mut core::ffi::c_void, scopenode: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "DisplayScopeNodePropertySheet", 19usize, |this| ((**(this as *const *const View_Vtbl)).DisplayScopeNodePropertySheet)(this, scopenode), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplaySelectionPropertySheet(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "DisplaySelectionPropertySheet", 20usize, |this| ((**(this as *const *const View_Vtbl)).DisplaySelectionPropertySheet)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyScopeNode(this: *mut core::ffi::c_void, scopenode: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "CopyScopeNode", 21usize, |this| ((**(this as *const *const View_Vtbl)).CopyScopeNode)(this, scopenode), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopySelection(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "CopySelection", 22usize, |this| ((**(this as *const *const View_Vtbl)).CopySelection)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteScopeNode(this: *mut core::ffi::c_void, scopenode: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "DeleteScopeNode", 23usize, |this| ((**(this as *const *const View_Vtbl)).DeleteScopeNode)(this, scopenode), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteSelection(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "DeleteSelection", 24usize, |this| ((**(this as *const *const View_Vtbl)).DeleteSelection)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RenameScopeNode(this: *mut core::ffi::c_void, newname: core::mem::MaybeUninit<windows_core::BSTR>, scopenode: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "RenameScopeNode", 25usize, |this| ((**(this as *const *const View_Vtbl)).RenameScopeNode)(this, newname, scopenode), |result| Some(*result))
+        }
+        unsafe extern "system" fn RenameSelectedItem(this: *mut core::ffi::c_void, newname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "RenameSelectedItem", 26usize, |this| ((**(this as *const *const View_Vtbl)).RenameSelectedItem)(this, newname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_ScopeNodeContextMenu(this: *mut core::ffi::c_void, scopenode: core::mem::MaybeUninit<windows_core::VARIANT>, contextmenu: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "get_ScopeNodeContextMenu", 27usize, |this| ((**(this as *const *const View_Vtbl)).get_ScopeNodeContextMenu)(this, scopenode, contextmenu), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SelectionContextMenu(this: *mut core::ffi::c_void, contextmenu: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "SelectionContextMenu", 28usize, |this| ((**(this as *const *const View_Vtbl)).SelectionContextMenu)(this, contextmenu), |result| Some(*result))
+        }
+        unsafe extern "system" fn RefreshScopeNode(this: *mut core::ffi::c_void, scopenode: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "RefreshScopeNode", 29usize, |this| ((**(this as *const *const View_Vtbl)).RefreshScopeNode)(this, scopenode), |result| Some(*result))
+        }
+        unsafe extern "system" fn RefreshSelection(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "RefreshSelection", 30usize, |this| ((**(this as *const *const View_Vtbl)).RefreshSelection)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExecuteSelectionMenuItem(this: *mut core::ffi::c_void, menuitempath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "ExecuteSelectionMenuItem", 31usize, |this| ((**(this as *const *const View_Vtbl)).ExecuteSelectionMenuItem)(this, menuitempath), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExecuteScopeNodeMenuItem(this: *mut core::ffi::c_void, menuitempath: core::mem::MaybeUninit<windows_core::BSTR>, scopenode: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "ExecuteScopeNodeMenuItem", 32usize, |this| ((**(this as *const *const View_Vtbl)).ExecuteScopeNodeMenuItem)(this, menuitempath, scopenode), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExecuteShellCommand(this: *mut core::ffi::c_void, command: core::mem::MaybeUninit<windows_core::BSTR>, directory: core::mem::MaybeUninit<windows_core::BSTR>, parameters: core::mem::MaybeUninit<windows_core::BSTR>, windowstate: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "ExecuteShellCommand", 33usize, |this| ((**(this as *const *const View_Vtbl)).ExecuteShellCommand)(this, command, directory, parameters, windowstate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Frame(this: *mut core::ffi::c_void, frame: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "Frame", 34usize, |this| ((**(this as *const *const View_Vtbl)).Frame)(this, frame), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "Close", 35usize, |this| ((**(this as *const *const View_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScopeTreeVisible(this: *mut core::ffi::c_void, visible: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "ScopeTreeVisible", 36usize, |this| ((**(this as *const *const View_Vtbl)).ScopeTreeVisible)(this, visible), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScopeTreeVisible(this: *mut core::ffi::c_void, visible: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "SetScopeTreeVisible", 37usize, |this| ((**(this as *const *const View_Vtbl)).SetScopeTreeVisible)(this, visible), |result| Some(*result))
+        }
+        unsafe extern "system" fn Back(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "Back", 38usize, |this| ((**(this as *const *const View_Vtbl)).Back)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Forward(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "Forward", 39usize, |this| ((**(this as *const *const View_Vtbl)).Forward)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatusBarText(this: *mut core::ffi::c_void, statusbartext: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "SetStatusBarText", 40usize, |this| ((**(this as *const *const View_Vtbl)).SetStatusBarText)(this, statusbartext), |result| Some(*result))
+        }
+        unsafe extern "system" fn Memento(this: *mut core::ffi::c_void, memento: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "Memento", 41usize, |this| ((**(this as *const *const View_Vtbl)).Memento)(this, memento), |result| Some(*result))
+        }
+        unsafe extern "system" fn ViewMemento(this: *mut core::ffi::c_void, memento: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "ViewMemento", 42usize, |this| ((**(this as *const *const View_Vtbl)).ViewMemento)(this, memento), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Columns(this: *mut core::ffi::c_void, columns: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "Columns", 43usize, |this| ((**(this as *const *const View_Vtbl)).Columns)(this, columns), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_CellContents(this: *mut core::ffi::c_void, node: *mut core::ffi::c_void, column: i32, cellcontents: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "get_CellContents", 44usize, |this| ((**(this as *const *const View_Vtbl)).get_CellContents)(this, node, column, cellcontents), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExportList(this: *mut core::ffi::c_void, file: core::mem::MaybeUninit<windows_core::BSTR>, exportoptions: _ExportListOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "ExportList", 45usize, |this| ((**(this as *const *const View_Vtbl)).ExportList)(this, file, exportoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn ListViewMode(this: *mut core::ffi::c_void, mode: *mut _ListViewMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "ListViewMode", 46usize, |this| ((**(this as *const *const View_Vtbl)).ListViewMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetListViewMode(this: *mut core::ffi::c_void, mode: _ListViewMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "SetListViewMode", 47usize, |this| ((**(this as *const *const View_Vtbl)).SetListViewMode)(this, mode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ControlObject(this: *mut core::ffi::c_void, control: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "View", "ControlObject", 48usize, |this| ((**(this as *const *const View_Vtbl)).ControlObject)(this, control), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            ActiveScopeNode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ActiveScopeNode: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetActiveScopeNode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetActiveScopeNode: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Selection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Selection: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ListItems,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ListItems: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SnapinScopeObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SnapinScopeObject: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SnapinSelectionObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SnapinSelectionObject: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Is,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Is: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Document,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Document: 0,
+            SelectAll,
+            #[cfg(feature = "Win32_System_Com")]
+            Select,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Select: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Deselect,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Deselect: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            IsSelected,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            IsSelected: 0,
+            DisplayScopeNodePropertySheet,
+            DisplaySelectionPropertySheet,
+            CopyScopeNode,
+            CopySelection,
+            DeleteScopeNode,
+            DeleteSelection,
+            RenameScopeNode,
+            RenameSelectedItem,
+            #[cfg(feature = "Win32_System_Com")]
+            get_ScopeNodeContextMenu,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_ScopeNodeContextMenu: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SelectionContextMenu,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SelectionContextMenu: 0,
+            RefreshScopeNode,
+            RefreshSelection,
+            ExecuteSelectionMenuItem,
+            ExecuteScopeNodeMenuItem,
+            ExecuteShellCommand,
+            #[cfg(feature = "Win32_System_Com")]
+            Frame,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Frame: 0,
+            Close,
+            ScopeTreeVisible,
+            SetScopeTreeVisible,
+            Back,
+            Forward,
+            SetStatusBarText,
+            Memento,
+            ViewMemento,
+            #[cfg(feature = "Win32_System_Com")]
+            Columns,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Columns: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            get_CellContents,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_CellContents: 0,
+            ExportList,
+            ListViewMode,
+            SetListViewMode,
+            #[cfg(feature = "Win32_System_Com")]
+            ControlObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ControlObject: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<View as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for Views_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: i32, view: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Views", "Item", 7usize, |this| ((**(this as *const *const Views_Vtbl)).Item)(this, index, view), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Views", "Count", 8usize, |this| ((**(this as *const *const Views_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, node: *mut core::ffi::c_void, viewoptions: _ViewOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Views", "Add", 9usize, |this| ((**(this as *const *const Views_Vtbl)).Add)(this, node, viewoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Views", "_NewEnum", 10usize, |this| ((**(this as *const *const Views_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<Views as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for _AppEvents_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnQuit(this: *mut core::ffi::c_void, application: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_AppEvents", "OnQuit", 7usize, |this| ((**(this as *const *const _AppEvents_Vtbl)).OnQuit)(this, application), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnDocumentOpen(this: *mut core::ffi::c_void, document: *mut core::ffi::c_void, new: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_AppEvents", "OnDocumentOpen", 8usize, |this| ((**(this as *const *const _AppEvents_Vtbl)).OnDocumentOpen)(this, document, new), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnDocumentClose(this: *mut core::ffi::c_void, document: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_AppEvents", "OnDocumentClose", 9usize, |this| ((**(this as *const *const _AppEvents_Vtbl)).OnDocumentClose)(this, document), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnSnapInAdded(this: *mut core::ffi::c_void, document: *mut core::ffi::c_void, snapin: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_AppEvents", "OnSnapInAdded", 10usize, |this| ((**(this as *const *const _AppEvents_Vtbl)).OnSnapInAdded)(this, document, snapin), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnSnapInRemoved(this: *mut core::ffi::c_void, document: *mut core::ffi::c_void, snapin: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_AppEvents", "OnSnapInRemoved", 11usize, |this| ((**(this as *const *const _AppEvents_Vtbl)).OnSnapInRemoved)(this, document, snapin), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnNewView(this: *mut core::ffi::c_void, view: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_AppEvents", "OnNewView", 12usize, |this| ((**(this as *const *const _AppEvents_Vtbl)).OnNewView)(this, view), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnViewClose(this: *mut core::ffi::c_void, view: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_AppEvents", "OnViewClose", 13usize, |this| ((**(this as *const *const _AppEvents_Vtbl)).OnViewClose)(this, view), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnViewChange(this: *mut core::ffi::c_void, view: *mut core::ffi::c_void, newownernode: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_AppEvents", "OnViewChange", 14usize, |this| ((**(this as *const *const _AppEvents_Vtbl)).OnViewChange)(this, view, newownernode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnSelectionChange(this: *mut core::ffi::c_void, view: *mut core::ffi::c_void, newnodes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_AppEvents", "OnSelectionChange", 15usize, |this| ((**(this as *const *const _AppEvents_Vtbl)).OnSelectionChange)(this, view, newnodes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnContextMenuExecuted(this: *mut core::ffi::c_void, menuitem: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_AppEvents", "OnContextMenuExecuted", 16usize, |this| ((**(this as *const *const _AppEvents_Vtbl)).OnContextMenuExecuted)(this, menuitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnToolbarButtonClicked(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_AppEvents", "OnToolbarButtonClicked", 17usize, |this| ((**(this as *const *const _AppEvents_Vtbl)).OnToolbarButtonClicked)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnListUpdated(this: *mut core::ffi::c_void, view: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_AppEvents", "OnListUpdated", 18usize, |this| ((**(this as *const *const _AppEvents_Vtbl)).OnListUpdated)(this, view), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            OnQuit,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnQuit: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnDocumentOpen,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnDocumentOpen: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnDocumentClose,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnDocumentClose: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnSnapInAdded,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnSnapInAdded: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnSnapInRemoved,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnSnapInRemoved: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnNewView,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnNewView: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnViewClose,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnViewClose: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnViewChange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnViewChange: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnSelectionChange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnSelectionChange: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OnContextMenuExecuted,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnContextMenuExecuted: 0,
+            OnToolbarButtonClicked,
+            #[cfg(feature = "Win32_System_Com")]
+            OnListUpdated,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnListUpdated: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<_AppEvents as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for _Application_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Help(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "_Application", "Help", 7usize, |this| ((**(this as *const *const _Application_Vtbl)).Help)(this), |_| None)
+        }
+        unsafe extern "system" fn Quit(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "_Application", "Quit", 8usize, |this| ((**(this as *const *const _Application_Vtbl)).Quit)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Document(this: *mut core::ffi::c_void, document: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_Application", "Document", 9usize, |this| ((**(this as *const *const _Application_Vtbl)).Document)(this, document), |result| Some(*result))
+        }
+        unsafe extern "system" fn Load(this: *mut core::ffi::c_void, filename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_Application", "Load", 10usize, |this| ((**(this as *const *const _Application_Vtbl)).Load)(this, filename), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Frame(this: *mut core::ffi::c_void, frame: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_Application", "Frame", 11usize, |this| ((**(this as *const *const _Application_Vtbl)).Frame)(this, frame), |result| Some(*result))
+        }
+        unsafe extern "system" fn Visible(this: *mut core::ffi::c_void, visible: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_Application", "Visible", 12usize, |this| ((**(this as *const *const _Application_Vtbl)).Visible)(this, visible), |result| Some(*result))
+        }
+        unsafe extern "system" fn Show(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_Application", "Show", 13usize, |this| ((**(this as *const *const _Application_Vtbl)).Show)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Hide(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_Application", "Hide", 14usize, |this| ((**(this as *const *const _Application_Vtbl)).Hide)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserControl(this: *mut core::ffi::c_void, usercontrol: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_Application", "UserControl", 15usize, |this| ((**(this as *const *const _Application_Vtbl)).UserControl)(this, usercontrol), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUserControl(this: *mut core::ffi::c_void, usercontrol: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_Application", "SetUserControl", 16usize, |this| ((**(this as *const *const _Application_Vtbl)).SetUserControl)(this, usercontrol), |result| Some(*result))
+        }
+        unsafe extern "system" fn VersionMajor(this: *mut core::ffi::c_void, versionmajor: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_Application", "VersionMajor", 17usize, |this| ((**(this as *const *const _Application_Vtbl)).VersionMajor)(this, versionmajor), |result| Some(*result))
+        }
+        unsafe extern "system" fn VersionMinor(this: *mut core::ffi::c_void, versionminor: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_Application", "VersionMinor", 18usize, |this| ((**(this as *const *const _Application_Vtbl)).VersionMinor)(this, versionminor), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Help,
+            Quit,
+            #[cfg(feature = "Win32_System_Com")]
+            Document,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Document: 0,
+            Load,
+            #[cfg(feature = "Win32_System_Com")]
+            Frame,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Frame: 0,
+            Visible,
+            Show,
+            Hide,
+            UserControl,
+            SetUserControl,
+            VersionMajor,
+            VersionMinor,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<_Application as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for _EventConnector_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ConnectTo(this: *mut core::ffi::c_void, application: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_EventConnector", "ConnectTo", 7usize, |this| ((**(this as *const *const _EventConnector_Vtbl)).ConnectTo)(this, application), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_EventConnector", "Disconnect", 8usize, |this| ((**(this as *const *const _EventConnector_Vtbl)).Disconnect)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            ConnectTo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ConnectTo: 0,
+            Disconnect,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<_EventConnector as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
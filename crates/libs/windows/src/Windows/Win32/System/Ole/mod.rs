@@ -8288,6 +8288,7 @@ pub struct ACTIVATEFLAGS(pub i32);
 impl windows_core::TypeKind for ACTIVATEFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ACTIVATEFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ACTIVATEFLAGS").field(&self.0).finish()
@@ -8299,6 +8300,7 @@ pub struct ACTIVEOBJECT_FLAGS(pub u32);
 impl windows_core::TypeKind for ACTIVEOBJECT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ACTIVEOBJECT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ACTIVEOBJECT_FLAGS").field(&self.0).finish()
@@ -8343,6 +8345,7 @@ pub struct BINDSPEED(pub i32);
 impl windows_core::TypeKind for BINDSPEED {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BINDSPEED {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BINDSPEED").field(&self.0).finish()
@@ -8354,6 +8357,7 @@ pub struct BUSY_DIALOG_FLAGS(pub u32);
 impl windows_core::TypeKind for BUSY_DIALOG_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BUSY_DIALOG_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BUSY_DIALOG_FLAGS").field(&self.0).finish()
@@ -8398,6 +8402,7 @@ pub struct CHANGEKIND(pub i32);
 impl windows_core::TypeKind for CHANGEKIND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CHANGEKIND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CHANGEKIND").field(&self.0).finish()
@@ -8409,6 +8414,7 @@ pub struct CHANGE_ICON_FLAGS(pub u32);
 impl windows_core::TypeKind for CHANGE_ICON_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CHANGE_ICON_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CHANGE_ICON_FLAGS").field(&self.0).finish()
@@ -8453,6 +8459,7 @@ pub struct CHANGE_SOURCE_FLAGS(pub u32);
 impl windows_core::TypeKind for CHANGE_SOURCE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CHANGE_SOURCE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CHANGE_SOURCE_FLAGS").field(&self.0).finish()
@@ -8497,6 +8504,7 @@ pub struct CLIPBOARD_FORMAT(pub u16);
 impl windows_core::TypeKind for CLIPBOARD_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLIPBOARD_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLIPBOARD_FORMAT").field(&self.0).finish()
@@ -8508,6 +8516,7 @@ pub struct CTRLINFO(pub i32);
 impl windows_core::TypeKind for CTRLINFO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CTRLINFO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CTRLINFO").field(&self.0).finish()
@@ -8519,6 +8528,7 @@ pub struct DISCARDCACHE(pub i32);
 impl windows_core::TypeKind for DISCARDCACHE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISCARDCACHE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISCARDCACHE").field(&self.0).finish()
@@ -8530,6 +8540,7 @@ pub struct DOCMISC(pub i32);
 impl windows_core::TypeKind for DOCMISC {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DOCMISC {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DOCMISC").field(&self.0).finish()
@@ -8541,6 +8552,7 @@ pub struct DROPEFFECT(pub u32);
 impl windows_core::TypeKind for DROPEFFECT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DROPEFFECT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DROPEFFECT").field(&self.0).finish()
@@ -8585,6 +8597,7 @@ pub struct DVASPECTINFOFLAG(pub i32);
 impl windows_core::TypeKind for DVASPECTINFOFLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVASPECTINFOFLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVASPECTINFOFLAG").field(&self.0).finish()
@@ -8596,6 +8609,7 @@ pub struct DVEXTENTMODE(pub i32);
 impl windows_core::TypeKind for DVEXTENTMODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DVEXTENTMODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DVEXTENTMODE").field(&self.0).finish()
@@ -8607,6 +8621,7 @@ pub struct EDIT_LINKS_FLAGS(pub u32);
 impl windows_core::TypeKind for EDIT_LINKS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EDIT_LINKS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EDIT_LINKS_FLAGS").field(&self.0).finish()
@@ -8651,6 +8666,7 @@ pub struct EMBDHLP_FLAGS(pub u32);
 impl windows_core::TypeKind for EMBDHLP_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EMBDHLP_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EMBDHLP_FLAGS").field(&self.0).finish()
@@ -8695,6 +8711,7 @@ pub struct ENUM_CONTROLS_WHICH_FLAGS(pub u32);
 impl windows_core::TypeKind for ENUM_CONTROLS_WHICH_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ENUM_CONTROLS_WHICH_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ENUM_CONTROLS_WHICH_FLAGS").field(&self.0).finish()
@@ -8706,6 +8723,7 @@ pub struct FDEX_PROP_FLAGS(pub u32);
 impl windows_core::TypeKind for FDEX_PROP_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FDEX_PROP_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FDEX_PROP_FLAGS").field(&self.0).finish()
@@ -8750,6 +8768,7 @@ pub struct GUIDKIND(pub i32);
 impl windows_core::TypeKind for GUIDKIND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GUIDKIND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GUIDKIND").field(&self.0).finish()
@@ -8761,6 +8780,7 @@ pub struct HITRESULT(pub i32);
 impl windows_core::TypeKind for HITRESULT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HITRESULT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HITRESULT").field(&self.0).finish()
@@ -8772,6 +8792,7 @@ pub struct IGNOREMIME(pub i32);
 impl windows_core::TypeKind for IGNOREMIME {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IGNOREMIME {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IGNOREMIME").field(&self.0).finish()
@@ -8783,6 +8804,7 @@ pub struct INSERT_OBJECT_FLAGS(pub u32);
 impl windows_core::TypeKind for INSERT_OBJECT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INSERT_OBJECT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INSERT_OBJECT_FLAGS").field(&self.0).finish()
@@ -8827,6 +8849,7 @@ pub struct KEYMODIFIERS(pub u32);
 impl windows_core::TypeKind for KEYMODIFIERS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KEYMODIFIERS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KEYMODIFIERS").field(&self.0).finish()
@@ -8871,6 +8894,7 @@ pub struct LIBFLAGS(pub i32);
 impl windows_core::TypeKind for LIBFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LIBFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LIBFLAGS").field(&self.0).finish()
@@ -8882,6 +8906,7 @@ pub struct LOAD_PICTURE_FLAGS(pub u32);
 impl windows_core::TypeKind for LOAD_PICTURE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LOAD_PICTURE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LOAD_PICTURE_FLAGS").field(&self.0).finish()
@@ -8926,6 +8951,7 @@ pub struct MEDIAPLAYBACK_STATE(pub i32);
 impl windows_core::TypeKind for MEDIAPLAYBACK_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MEDIAPLAYBACK_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MEDIAPLAYBACK_STATE").field(&self.0).finish()
@@ -8937,6 +8963,7 @@ pub struct MULTICLASSINFO_FLAGS(pub u32);
 impl windows_core::TypeKind for MULTICLASSINFO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MULTICLASSINFO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MULTICLASSINFO_FLAGS").field(&self.0).finish()
@@ -8948,6 +8975,7 @@ pub struct NUMPARSE_FLAGS(pub u32);
 impl windows_core::TypeKind for NUMPARSE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NUMPARSE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NUMPARSE_FLAGS").field(&self.0).finish()
@@ -8992,6 +9020,7 @@ pub struct OBJECT_PROPERTIES_FLAGS(pub u32);
 impl windows_core::TypeKind for OBJECT_PROPERTIES_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OBJECT_PROPERTIES_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OBJECT_PROPERTIES_FLAGS").field(&self.0).finish()
@@ -9036,6 +9065,7 @@ pub struct OLECLOSE(pub i32);
 impl windows_core::TypeKind for OLECLOSE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OLECLOSE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OLECLOSE").field(&self.0).finish()
@@ -9047,6 +9077,7 @@ pub struct OLECMDEXECOPT(pub i32);
 impl windows_core::TypeKind for OLECMDEXECOPT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OLECMDEXECOPT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OLECMDEXECOPT").field(&self.0).finish()
@@ -9058,6 +9089,7 @@ pub struct OLECMDF(pub i32);
 impl windows_core::TypeKind for OLECMDF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OLECMDF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OLECMDF").field(&self.0).finish()
@@ -9069,6 +9101,7 @@ pub struct OLECMDID(pub i32);
 impl windows_core::TypeKind for OLECMDID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OLECMDID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OLECMDID").field(&self.0).finish()
@@ -9080,6 +9113,7 @@ pub struct OLECMDID_BROWSERSTATEFLAG(pub i32);
 impl windows_core::TypeKind for OLECMDID_BROWSERSTATEFLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OLECMDID_BROWSERSTATEFLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OLECMDID_BROWSERSTATEFLAG").field(&self.0).finish()
@@ -9091,6 +9125,7 @@ pub struct OLECMDID_OPTICAL_ZOOMFLAG(pub i32);
 impl windows_core::TypeKind for OLECMDID_OPTICAL_ZOOMFLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OLECMDID_OPTICAL_ZOOMFLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OLECMDID_OPTICAL_ZOOMFLAG").field(&self.0).finish()
@@ -9102,6 +9137,7 @@ pub struct OLECMDID_PAGEACTIONFLAG(pub i32);
 impl windows_core::TypeKind for OLECMDID_PAGEACTIONFLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OLECMDID_PAGEACTIONFLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OLECMDID_PAGEACTIONFLAG").field(&self.0).finish()
@@ -9113,6 +9149,7 @@ pub struct OLECMDID_REFRESHFLAG(pub i32);
 impl windows_core::TypeKind for OLECMDID_REFRESHFLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OLECMDID_REFRESHFLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OLECMDID_REFRESHFLAG").field(&self.0).finish()
@@ -9124,6 +9161,7 @@ pub struct OLECMDID_VIEWPORT_MODE_FLAG(pub i32);
 impl windows_core::TypeKind for OLECMDID_VIEWPORT_MODE_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OLECMDID_VIEWPORT_MODE_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OLECMDID_VIEWPORT_MODE_FLAG").field(&self.0).finish()
@@ -9135,6 +9173,7 @@ pub struct OLECMDID_WINDOWSTATE_FLAG(pub i32);
 impl windows_core::TypeKind for OLECMDID_WINDOWSTATE_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OLECMDID_WINDOWSTATE_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OLECMDID_WINDOWSTATE_FLAG").field(&self.0).finish()
@@ -9146,6 +9185,7 @@ pub struct OLECMDTEXTF(pub i32);
 impl windows_core::TypeKind for OLECMDTEXTF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OLECMDTEXTF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OLECMDTEXTF").field(&self.0).finish()
@@ -9157,6 +9197,7 @@ pub struct OLECONTF(pub i32);
 impl windows_core::TypeKind for OLECONTF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OLECONTF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OLECONTF").field(&self.0).finish()
@@ -9168,6 +9209,7 @@ pub struct OLECREATE(pub u32);
 impl windows_core::TypeKind for OLECREATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OLECREATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OLECREATE").field(&self.0).finish()
@@ -9179,6 +9221,7 @@ pub struct OLEDCFLAGS(pub i32);
 impl windows_core::TypeKind for OLEDCFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OLEDCFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OLEDCFLAGS").field(&self.0).finish()
@@ -9190,6 +9233,7 @@ pub struct OLEGETMONIKER(pub i32);
 impl windows_core::TypeKind for OLEGETMONIKER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OLEGETMONIKER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OLEGETMONIKER").field(&self.0).finish()
@@ -9201,6 +9245,7 @@ pub struct OLEIVERB(pub i32);
 impl windows_core::TypeKind for OLEIVERB {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OLEIVERB {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OLEIVERB").field(&self.0).finish()
@@ -9212,6 +9257,7 @@ pub struct OLELINKBIND(pub i32);
 impl windows_core::TypeKind for OLELINKBIND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OLELINKBIND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OLELINKBIND").field(&self.0).finish()
@@ -9223,6 +9269,7 @@ pub struct OLEMISC(pub i32);
 impl windows_core::TypeKind for OLEMISC {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OLEMISC {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OLEMISC").field(&self.0).finish()
@@ -9234,6 +9281,7 @@ pub struct OLERENDER(pub i32);
 impl windows_core::TypeKind for OLERENDER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OLERENDER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OLERENDER").field(&self.0).finish()
@@ -9245,6 +9293,7 @@ pub struct OLEUIPASTEFLAG(pub i32);
 impl windows_core::TypeKind for OLEUIPASTEFLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OLEUIPASTEFLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OLEUIPASTEFLAG").field(&self.0).finish()
@@ -9256,6 +9305,7 @@ pub struct OLEUPDATE(pub i32);
 impl windows_core::TypeKind for OLEUPDATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OLEUPDATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OLEUPDATE").field(&self.0).finish()
@@ -9267,6 +9317,7 @@ pub struct OLEVERBATTRIB(pub i32);
 impl windows_core::TypeKind for OLEVERBATTRIB {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OLEVERBATTRIB {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OLEVERBATTRIB").field(&self.0).finish()
@@ -9278,6 +9329,7 @@ pub struct OLEWHICHMK(pub i32);
 impl windows_core::TypeKind for OLEWHICHMK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OLEWHICHMK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OLEWHICHMK").field(&self.0).finish()
@@ -9289,6 +9341,7 @@ pub struct OLE_TRISTATE(pub i32);
 impl windows_core::TypeKind for OLE_TRISTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OLE_TRISTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OLE_TRISTATE").field(&self.0).finish()
@@ -9300,6 +9353,7 @@ pub struct PAGEACTION_UI(pub i32);
 impl windows_core::TypeKind for PAGEACTION_UI {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PAGEACTION_UI {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PAGEACTION_UI").field(&self.0).finish()
@@ -9311,6 +9365,7 @@ pub struct PARAMFLAGS(pub u16);
 impl windows_core::TypeKind for PARAMFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PARAMFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PARAMFLAGS").field(&self.0).finish()
@@ -9355,6 +9410,7 @@ pub struct PASTE_SPECIAL_FLAGS(pub u32);
 impl windows_core::TypeKind for PASTE_SPECIAL_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PASTE_SPECIAL_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PASTE_SPECIAL_FLAGS").field(&self.0).finish()
@@ -9399,6 +9455,7 @@ pub struct PICTUREATTRIBUTES(pub i32);
 impl windows_core::TypeKind for PICTUREATTRIBUTES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PICTUREATTRIBUTES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PICTUREATTRIBUTES").field(&self.0).finish()
@@ -9410,6 +9467,7 @@ pub struct PICTYPE(pub i16);
 impl windows_core::TypeKind for PICTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PICTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PICTYPE").field(&self.0).finish()
@@ -9421,6 +9479,7 @@ pub struct POINTERINACTIVE(pub i32);
 impl windows_core::TypeKind for POINTERINACTIVE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for POINTERINACTIVE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("POINTERINACTIVE").field(&self.0).finish()
@@ -9432,6 +9491,7 @@ pub struct PRINTFLAG(pub i32);
 impl windows_core::TypeKind for PRINTFLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PRINTFLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PRINTFLAG").field(&self.0).finish()
@@ -9476,6 +9536,7 @@ pub struct PROPBAG2_TYPE(pub i32);
 impl windows_core::TypeKind for PROPBAG2_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROPBAG2_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROPBAG2_TYPE").field(&self.0).finish()
@@ -9487,6 +9548,7 @@ pub struct PROPPAGESTATUS(pub i32);
 impl windows_core::TypeKind for PROPPAGESTATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROPPAGESTATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROPPAGESTATUS").field(&self.0).finish()
@@ -9498,6 +9560,7 @@ pub struct QACONTAINERFLAGS(pub i32);
 impl windows_core::TypeKind for QACONTAINERFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for QACONTAINERFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("QACONTAINERFLAGS").field(&self.0).finish()
@@ -9509,6 +9572,7 @@ pub struct READYSTATE(pub i32);
 impl windows_core::TypeKind for READYSTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for READYSTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("READYSTATE").field(&self.0).finish()
@@ -9520,6 +9584,7 @@ pub struct REGKIND(pub i32);
 impl windows_core::TypeKind for REGKIND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for REGKIND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("REGKIND").field(&self.0).finish()
@@ -9531,6 +9596,7 @@ pub struct SF_TYPE(pub i32);
 impl windows_core::TypeKind for SF_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SF_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SF_TYPE").field(&self.0).finish()
@@ -9542,6 +9608,7 @@ pub struct TYPEFLAGS(pub i32);
 impl windows_core::TypeKind for TYPEFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TYPEFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TYPEFLAGS").field(&self.0).finish()
@@ -9553,6 +9620,7 @@ pub struct UASFLAGS(pub i32);
 impl windows_core::TypeKind for UASFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UASFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UASFLAGS").field(&self.0).finish()
@@ -9564,6 +9632,7 @@ pub struct UI_CONVERT_FLAGS(pub u32);
 impl windows_core::TypeKind for UI_CONVERT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_CONVERT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_CONVERT_FLAGS").field(&self.0).finish()
@@ -9608,6 +9677,7 @@ pub struct UPDFCACHE_FLAGS(pub u32);
 impl windows_core::TypeKind for UPDFCACHE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UPDFCACHE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UPDFCACHE_FLAGS").field(&self.0).finish()
@@ -9652,6 +9722,7 @@ pub struct USERCLASSTYPE(pub i32);
 impl windows_core::TypeKind for USERCLASSTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USERCLASSTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USERCLASSTYPE").field(&self.0).finish()
@@ -9663,6 +9734,7 @@ pub struct VARCMP(pub u32);
 impl windows_core::TypeKind for VARCMP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VARCMP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VARCMP").field(&self.0).finish()
@@ -9674,6 +9746,7 @@ pub struct VARFORMAT_FIRST_DAY(pub i32);
 impl windows_core::TypeKind for VARFORMAT_FIRST_DAY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VARFORMAT_FIRST_DAY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VARFORMAT_FIRST_DAY").field(&self.0).finish()
@@ -9685,6 +9758,7 @@ pub struct VARFORMAT_FIRST_WEEK(pub i32);
 impl windows_core::TypeKind for VARFORMAT_FIRST_WEEK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VARFORMAT_FIRST_WEEK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VARFORMAT_FIRST_WEEK").field(&self.0).finish()
@@ -9696,6 +9770,7 @@ pub struct VARFORMAT_GROUP(pub i32);
 impl windows_core::TypeKind for VARFORMAT_GROUP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VARFORMAT_GROUP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VARFORMAT_GROUP").field(&self.0).finish()
@@ -9707,6 +9782,7 @@ pub struct VARFORMAT_LEADING_DIGIT(pub i32);
 impl windows_core::TypeKind for VARFORMAT_LEADING_DIGIT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VARFORMAT_LEADING_DIGIT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VARFORMAT_LEADING_DIGIT").field(&self.0).finish()
@@ -9718,6 +9794,7 @@ pub struct VARFORMAT_NAMED_FORMAT(pub i32);
 impl windows_core::TypeKind for VARFORMAT_NAMED_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VARFORMAT_NAMED_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VARFORMAT_NAMED_FORMAT").field(&self.0).finish()
@@ -9729,6 +9806,7 @@ pub struct VARFORMAT_PARENTHESES(pub i32);
 impl windows_core::TypeKind for VARFORMAT_PARENTHESES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VARFORMAT_PARENTHESES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VARFORMAT_PARENTHESES").field(&self.0).finish()
@@ -9740,6 +9818,7 @@ pub struct VIEWSTATUS(pub i32);
 impl windows_core::TypeKind for VIEWSTATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VIEWSTATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VIEWSTATUS").field(&self.0).finish()
@@ -9751,6 +9830,7 @@ pub struct VIEW_OBJECT_PROPERTIES_FLAGS(pub u32);
 impl windows_core::TypeKind for VIEW_OBJECT_PROPERTIES_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VIEW_OBJECT_PROPERTIES_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VIEW_OBJECT_PROPERTIES_FLAGS").field(&self.0).finish()
@@ -9795,6 +9875,7 @@ pub struct WPCSETTING(pub i32);
 impl windows_core::TypeKind for WPCSETTING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPCSETTING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPCSETTING").field(&self.0).finish()
@@ -9806,6 +9887,7 @@ pub struct XFORMCOORDS(pub i32);
 impl windows_core::TypeKind for XFORMCOORDS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XFORMCOORDS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XFORMCOORDS").field(&self.0).finish()
@@ -9830,7 +9912,8 @@ impl Default for ARRAYDESC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CADWORD {
     pub cElems: u32,
     pub pElems: *mut u32,
@@ -9844,7 +9927,8 @@ impl Default for CADWORD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CALPOLESTR {
     pub cElems: u32,
     pub pElems: *mut windows_core::PWSTR,
@@ -9858,7 +9942,8 @@ impl Default for CALPOLESTR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CAUUID {
     pub cElems: u32,
     pub pElems: *mut windows_core::GUID,
@@ -9872,7 +9957,8 @@ impl Default for CAUUID {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CLEANLOCALSTORAGE {
     pub pInterface: core::mem::ManuallyDrop<Option<windows_core::IUnknown>>,
     pub pStorage: *mut core::ffi::c_void,
@@ -9893,7 +9979,8 @@ impl Default for CLEANLOCALSTORAGE {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CONTROLINFO {
     pub cb: u32,
     pub hAccel: super::super::UI::WindowsAndMessaging::HACCEL,
@@ -9911,7 +9998,8 @@ impl Default for CONTROLINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DVASPECTINFO {
     pub cb: u32,
     pub dwFlags: u32,
@@ -9925,7 +10013,8 @@ impl Default for DVASPECTINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DVEXTENTINFO {
     pub cb: u32,
     pub dwExtentMode: u32,
@@ -9964,7 +10053,8 @@ impl Default for FONTDESC {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct INTERFACEDATA {
     pub pmethdata: *mut METHODDATA,
     pub cMembers: u32,
@@ -9980,7 +10070,8 @@ impl Default for INTERFACEDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LICINFO {
     pub cbLicInfo: i32,
     pub fRuntimeKeyAvail: super::super::Foundation::BOOL,
@@ -9996,7 +10087,8 @@ impl Default for LICINFO {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct METHODDATA {
     pub szName: windows_core::PWSTR,
     pub ppdata: *mut PARAMDATA,
@@ -10018,7 +10110,8 @@ impl Default for METHODDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NUMPARSE {
     pub cDig: i32,
     pub dwInFlags: NUMPARSE_FLAGS,
@@ -10036,7 +10129,8 @@ impl Default for NUMPARSE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OBJECTDESCRIPTOR {
     pub cbSize: u32,
     pub clsid: windows_core::GUID,
@@ -10056,7 +10150,8 @@ impl Default for OBJECTDESCRIPTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OCPFIPARAMS {
     pub cbStructSize: u32,
     pub hWndOwner: super::super::Foundation::HWND,
@@ -10079,7 +10174,8 @@ impl Default for OCPFIPARAMS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLECMD {
     pub cmdID: u32,
     pub cmdf: u32,
@@ -10093,7 +10189,8 @@ impl Default for OLECMD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLECMDTEXT {
     pub cmdtextf: u32,
     pub cwActual: u32,
@@ -10110,7 +10207,8 @@ impl Default for OLECMDTEXT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLEINPLACEFRAMEINFO {
     pub cb: u32,
     pub fMDIApp: super::super::Foundation::BOOL,
@@ -10129,7 +10227,8 @@ impl Default for OLEINPLACEFRAMEINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLEMENUGROUPWIDTHS {
     pub width: [i32; 6],
 }
@@ -10143,7 +10242,8 @@ impl Default for OLEMENUGROUPWIDTHS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Media")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLEUIBUSYA {
     pub cbStruct: u32,
     pub dwFlags: BUSY_DIALOG_FLAGS,
@@ -10169,7 +10269,8 @@ impl Default for OLEUIBUSYA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Media")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLEUIBUSYW {
     pub cbStruct: u32,
     pub dwFlags: BUSY_DIALOG_FLAGS,
@@ -10194,7 +10295,8 @@ impl Default for OLEUIBUSYW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLEUICHANGEICONA {
     pub cbStruct: u32,
     pub dwFlags: CHANGE_ICON_FLAGS,
@@ -10219,7 +10321,8 @@ impl Default for OLEUICHANGEICONA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLEUICHANGEICONW {
     pub cbStruct: u32,
     pub dwFlags: CHANGE_ICON_FLAGS,
@@ -10245,7 +10348,7 @@ impl Default for OLEUICHANGEICONW {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Controls_Dialogs")]
-#[derive(Debug)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLEUICHANGESOURCEA {
     pub cbStruct: u32,
     pub dwFlags: CHANGE_SOURCE_FLAGS,
@@ -10283,7 +10386,7 @@ impl Default for OLEUICHANGESOURCEA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Controls_Dialogs")]
-#[derive(Debug)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLEUICHANGESOURCEW {
     pub cbStruct: u32,
     pub dwFlags: CHANGE_SOURCE_FLAGS,
@@ -10320,7 +10423,8 @@ impl Default for OLEUICHANGESOURCEW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLEUICONVERTA {
     pub cbStruct: u32,
     pub dwFlags: UI_CONVERT_FLAGS,
@@ -10354,7 +10458,8 @@ impl Default for OLEUICONVERTA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLEUICONVERTW {
     pub cbStruct: u32,
     pub dwFlags: UI_CONVERT_FLAGS,
@@ -10388,7 +10493,7 @@ impl Default for OLEUICONVERTW {
     }
 }
 #[repr(C)]
-#[derive(Debug)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLEUIEDITLINKSA {
     pub cbStruct: u32,
     pub dwFlags: EDIT_LINKS_FLAGS,
@@ -10415,7 +10520,7 @@ impl Default for OLEUIEDITLINKSA {
     }
 }
 #[repr(C)]
-#[derive(Debug)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLEUIEDITLINKSW {
     pub cbStruct: u32,
     pub dwFlags: EDIT_LINKS_FLAGS,
@@ -10443,7 +10548,8 @@ impl Default for OLEUIEDITLINKSW {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_Controls", feature = "Win32_UI_WindowsAndMessaging"))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLEUIGNRLPROPSA {
     pub cbStruct: u32,
     pub dwFlags: u32,
@@ -10465,7 +10571,8 @@ impl Default for OLEUIGNRLPROPSA {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_Controls", feature = "Win32_UI_WindowsAndMessaging"))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLEUIGNRLPROPSW {
     pub cbStruct: u32,
     pub dwFlags: u32,
@@ -10487,7 +10594,7 @@ impl Default for OLEUIGNRLPROPSW {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com_StructuredStorage")]
-#[derive(Debug)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLEUIINSERTOBJECTA {
     pub cbStruct: u32,
     pub dwFlags: INSERT_OBJECT_FLAGS,
@@ -10530,7 +10637,7 @@ impl Default for OLEUIINSERTOBJECTA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com_StructuredStorage")]
-#[derive(Debug)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLEUIINSERTOBJECTW {
     pub cbStruct: u32,
     pub dwFlags: INSERT_OBJECT_FLAGS,
@@ -10573,7 +10680,8 @@ impl Default for OLEUIINSERTOBJECTW {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_Controls", feature = "Win32_UI_WindowsAndMessaging"))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLEUILINKPROPSA {
     pub cbStruct: u32,
     pub dwFlags: u32,
@@ -10595,7 +10703,8 @@ impl Default for OLEUILINKPROPSA {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_Controls", feature = "Win32_UI_WindowsAndMessaging"))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLEUILINKPROPSW {
     pub cbStruct: u32,
     pub dwFlags: u32,
@@ -10617,7 +10726,8 @@ impl Default for OLEUILINKPROPSW {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_Controls", feature = "Win32_UI_WindowsAndMessaging"))]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLEUIOBJECTPROPSA {
     pub cbStruct: u32,
     pub dwFlags: OBJECT_PROPERTIES_FLAGS,
@@ -10648,7 +10758,8 @@ impl Default for OLEUIOBJECTPROPSA {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_Controls", feature = "Win32_UI_WindowsAndMessaging"))]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLEUIOBJECTPROPSW {
     pub cbStruct: u32,
     pub dwFlags: OBJECT_PROPERTIES_FLAGS,
@@ -10679,7 +10790,8 @@ impl Default for OLEUIOBJECTPROPSW {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLEUIPASTEENTRYA {
     pub fmtetc: super::Com::FORMATETC,
     pub lpstrFormatName: windows_core::PCSTR,
@@ -10699,7 +10811,8 @@ impl Default for OLEUIPASTEENTRYA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLEUIPASTEENTRYW {
     pub fmtetc: super::Com::FORMATETC,
     pub lpstrFormatName: windows_core::PCWSTR,
@@ -10719,7 +10832,7 @@ impl Default for OLEUIPASTEENTRYW {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Debug)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLEUIPASTESPECIALA {
     pub cbStruct: u32,
     pub dwFlags: PASTE_SPECIAL_FLAGS,
@@ -10760,7 +10873,7 @@ impl Default for OLEUIPASTESPECIALA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Debug)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLEUIPASTESPECIALW {
     pub cbStruct: u32,
     pub dwFlags: PASTE_SPECIAL_FLAGS,
@@ -10801,7 +10914,8 @@ impl Default for OLEUIPASTESPECIALW {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_Controls", feature = "Win32_UI_WindowsAndMessaging"))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLEUIVIEWPROPSA {
     pub cbStruct: u32,
     pub dwFlags: VIEW_OBJECT_PROPERTIES_FLAGS,
@@ -10825,7 +10939,8 @@ impl Default for OLEUIVIEWPROPSA {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_Controls", feature = "Win32_UI_WindowsAndMessaging"))]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLEUIVIEWPROPSW {
     pub cbStruct: u32,
     pub dwFlags: VIEW_OBJECT_PROPERTIES_FLAGS,
@@ -10849,7 +10964,8 @@ impl Default for OLEUIVIEWPROPSW {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLEVERB {
     pub lVerb: OLEIVERB,
     pub lpszVerbName: windows_core::PWSTR,
@@ -10867,7 +10983,8 @@ impl Default for OLEVERB {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OLE_HANDLE(pub u32);
 impl OLE_HANDLE {
     pub fn is_invalid(&self) -> bool {
@@ -10883,7 +11000,8 @@ impl windows_core::TypeKind for OLE_HANDLE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PAGERANGE {
     pub nFromPage: i32,
     pub nToPage: i32,
@@ -10897,7 +11015,8 @@ impl Default for PAGERANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PAGESET {
     pub cbStruct: u32,
     pub fOddPages: super::super::Foundation::BOOL,
@@ -10915,7 +11034,8 @@ impl Default for PAGESET {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Variant")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PARAMDATA {
     pub szName: windows_core::PWSTR,
     pub vt: super::Variant::VARENUM,
@@ -10931,7 +11051,8 @@ impl Default for PARAMDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PARAMDESC {
     pub pparamdescex: *mut PARAMDESCEX,
     pub wParamFlags: PARAMFLAGS,
@@ -10945,7 +11066,8 @@ impl Default for PARAMDESC {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PARAMDESCEX {
     pub cBytes: u32,
     pub varDefaultValue: core::mem::ManuallyDrop<windows_core::VARIANT>,
@@ -11002,7 +11124,8 @@ impl Default for PICTDESC_0 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PICTDESC_0_0 {
     pub hbitmap: super::super::Graphics::Gdi::HBITMAP,
     pub hpal: super::super::Graphics::Gdi::HPALETTE,
@@ -11019,7 +11142,8 @@ impl Default for PICTDESC_0_0 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PICTDESC_0_1 {
     pub hemf: super::super::Graphics::Gdi::HENHMETAFILE,
 }
@@ -11035,7 +11159,8 @@ impl Default for PICTDESC_0_1 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PICTDESC_0_2 {
     pub hicon: super::super::UI::WindowsAndMessaging::HICON,
 }
@@ -11051,7 +11176,8 @@ impl Default for PICTDESC_0_2 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PICTDESC_0_3 {
     pub hmeta: super::super::Graphics::Gdi::HMETAFILE,
     pub xExt: i32,
@@ -11068,7 +11194,8 @@ impl Default for PICTDESC_0_3 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POINTF {
     pub x: f32,
     pub y: f32,
@@ -11082,7 +11209,8 @@ impl Default for POINTF {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROPPAGEINFO {
     pub cb: u32,
     pub pszTitle: windows_core::PWSTR,
@@ -11101,7 +11229,8 @@ impl Default for PROPPAGEINFO {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct QACONTAINER {
     pub cbSize: u32,
     pub pClientSite: core::mem::ManuallyDrop<Option<IOleClientSite>>,
@@ -11137,7 +11266,8 @@ impl Default for QACONTAINER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct QACONTROL {
     pub cbSize: u32,
     pub dwMiscStatus: u32,
@@ -11197,7 +11327,8 @@ impl Default for SAFEARRAYUNION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SAFEARR_BRECORD {
     pub Size: u32,
     pub aRecord: *mut *mut _wireBRECORD,
@@ -11212,7 +11343,8 @@ impl Default for SAFEARR_BRECORD {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SAFEARR_BSTR {
     pub Size: u32,
     pub aBstr: *mut *mut super::Com::FLAGGED_WORD_BLOB,
@@ -11229,7 +11361,8 @@ impl Default for SAFEARR_BSTR {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SAFEARR_DISPATCH {
     pub Size: u32,
     pub apDispatch: *mut Option<super::Com::IDispatch>,
@@ -11245,7 +11378,8 @@ impl Default for SAFEARR_DISPATCH {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SAFEARR_HAVEIID {
     pub Size: u32,
     pub apUnknown: *mut Option<windows_core::IUnknown>,
@@ -11260,7 +11394,8 @@ impl Default for SAFEARR_HAVEIID {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SAFEARR_UNKNOWN {
     pub Size: u32,
     pub apUnknown: *mut Option<windows_core::IUnknown>,
@@ -11275,7 +11410,8 @@ impl Default for SAFEARR_UNKNOWN {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SAFEARR_VARIANT {
     pub Size: u32,
     pub aVariant: *mut *mut _wireVARIANT,
@@ -11291,7 +11427,8 @@ impl Default for SAFEARR_VARIANT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UDATE {
     pub st: super::super::Foundation::SYSTEMTIME,
     pub wDayOfYear: u16,
@@ -11305,7 +11442,8 @@ impl Default for UDATE {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _wireBRECORD {
     pub fFlags: u32,
     pub clSize: u32,
@@ -11439,5 +11577,8 @@ impl Default for _wireVARIANT_0 {
 }
 pub type LPFNOLEUIHOOK = Option<unsafe extern "system" fn(param0: super::super::Foundation::HWND, param1: u32, param2: super::super::Foundation::WPARAM, param3: super::super::Foundation::LPARAM) -> u32>;
 pub type OLESTREAMQUERYCONVERTOLELINKCALLBACK = Option<unsafe extern "system" fn(pclsid: *const windows_core::GUID, szclass: windows_core::PCWSTR, sztopicname: windows_core::PCWSTR, szitemname: windows_core::PCWSTR, szuncname: windows_core::PCWSTR, linkupdatingoption: u32, pvcontext: *const core::ffi::c_void) -> windows_core::HRESULT>;
+core::include!(core::concat!(core::env!("CARGO_MANIFEST_DIR"), "/src/includes/", "Win32/System/Ole/SafeArray.rs"));
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,3060 @@
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+impl windows_core::imp::ProxyVtable for IAdviseSinkEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnViewStatusChange(this: *mut core::ffi::c_void, dwviewstatus: u32) {
+            windows_core::imp::proxy_call(this, "IAdviseSinkEx", "OnViewStatusChange", 8usize, |this| ((**(this as *const *const IAdviseSinkEx_Vtbl)).OnViewStatusChange)(this, dwviewstatus), |_| None)
+        }
+        Self { base__: <super::Com::IAdviseSink_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnViewStatusChange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAdviseSinkEx as windows_core::Interface>::IID || <super::Com::IAdviseSink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICanHandleException_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CanHandleException(this: *mut core::ffi::c_void, pexcepinfo: *const super::Com::EXCEPINFO, pvar: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICanHandleException", "CanHandleException", 3usize, |this| ((**(this as *const *const ICanHandleException_Vtbl)).CanHandleException)(this, pexcepinfo, pvar), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CanHandleException,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CanHandleException: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICanHandleException as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IClassFactory2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLicInfo(this: *mut core::ffi::c_void, plicinfo: *mut LICINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClassFactory2", "GetLicInfo", 5usize, |this| ((**(this as *const *const IClassFactory2_Vtbl)).GetLicInfo)(this, plicinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestLicKey(this: *mut core::ffi::c_void, dwreserved: u32, pbstrkey: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClassFactory2", "RequestLicKey", 6usize, |this| ((**(this as *const *const IClassFactory2_Vtbl)).RequestLicKey)(this, dwreserved, pbstrkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInstanceLic(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, punkreserved: *mut core::ffi::c_void, riid: *const windows_core::GUID, bstrkey: core::mem::MaybeUninit<windows_core::BSTR>, ppvobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClassFactory2", "CreateInstanceLic", 7usize, |this| ((**(this as *const *const IClassFactory2_Vtbl)).CreateInstanceLic)(this, punkouter, punkreserved, riid, bstrkey, ppvobj), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IClassFactory_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetLicInfo, RequestLicKey, CreateInstanceLic }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClassFactory2 as windows_core::Interface>::IID || <super::Com::IClassFactory_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContinue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FContinue(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContinue", "FContinue", 3usize, |this| ((**(this as *const *const IContinue_Vtbl)).FContinue)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FContinue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContinue as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContinueCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FContinue(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContinueCallback", "FContinue", 3usize, |this| ((**(this as *const *const IContinueCallback_Vtbl)).FContinue)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn FContinuePrinting(this: *mut core::ffi::c_void, ncntprinted: i32, ncurpage: i32, pwszprintstatus: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContinueCallback", "FContinuePrinting", 4usize, |this| ((**(this as *const *const IContinueCallback_Vtbl)).FContinuePrinting)(this, ncntprinted, ncurpage, pwszprintstatus), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FContinue, FContinuePrinting }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContinueCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICreateErrorInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetGUID(this: *mut core::ffi::c_void, rguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateErrorInfo", "SetGUID", 3usize, |this| ((**(this as *const *const ICreateErrorInfo_Vtbl)).SetGUID)(this, rguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSource(this: *mut core::ffi::c_void, szsource: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateErrorInfo", "SetSource", 4usize, |this| ((**(this as *const *const ICreateErrorInfo_Vtbl)).SetSource)(this, szsource), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, szdescription: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateErrorInfo", "SetDescription", 5usize, |this| ((**(this as *const *const ICreateErrorInfo_Vtbl)).SetDescription)(this, szdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHelpFile(this: *mut core::ffi::c_void, szhelpfile: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateErrorInfo", "SetHelpFile", 6usize, |this| ((**(this as *const *const ICreateErrorInfo_Vtbl)).SetHelpFile)(this, szhelpfile), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHelpContext(this: *mut core::ffi::c_void, dwhelpcontext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateErrorInfo", "SetHelpContext", 7usize, |this| ((**(this as *const *const ICreateErrorInfo_Vtbl)).SetHelpContext)(this, dwhelpcontext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetGUID, SetSource, SetDescription, SetHelpFile, SetHelpContext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICreateErrorInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
+impl windows_core::imp::ProxyVtable for ICreateTypeInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetGuid(this: *mut core::ffi::c_void, guid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo", "SetGuid", 3usize, |this| ((**(this as *const *const ICreateTypeInfo_Vtbl)).SetGuid)(this, guid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTypeFlags(this: *mut core::ffi::c_void, utypeflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo", "SetTypeFlags", 4usize, |this| ((**(this as *const *const ICreateTypeInfo_Vtbl)).SetTypeFlags)(this, utypeflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDocString(this: *mut core::ffi::c_void, pstrdoc: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo", "SetDocString", 5usize, |this| ((**(this as *const *const ICreateTypeInfo_Vtbl)).SetDocString)(this, pstrdoc), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHelpContext(this: *mut core::ffi::c_void, dwhelpcontext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo", "SetHelpContext", 6usize, |this| ((**(this as *const *const ICreateTypeInfo_Vtbl)).SetHelpContext)(this, dwhelpcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVersion(this: *mut core::ffi::c_void, wmajorvernum: u16, wminorvernum: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo", "SetVersion", 7usize, |this| ((**(this as *const *const ICreateTypeInfo_Vtbl)).SetVersion)(this, wmajorvernum, wminorvernum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddRefTypeInfo(this: *mut core::ffi::c_void, ptinfo: *mut core::ffi::c_void, phreftype: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo", "AddRefTypeInfo", 8usize, |this| ((**(this as *const *const ICreateTypeInfo_Vtbl)).AddRefTypeInfo)(this, ptinfo, phreftype), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
+        unsafe extern "system" fn AddFuncDesc(this: *mut core::ffi::c_void, index: u32, pfuncdesc: *const super::Com::FUNCDESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo", "AddFuncDesc", 9usize, |this| ((**(this as *const *const ICreateTypeInfo_Vtbl)).AddFuncDesc)(this, index, pfuncdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddImplType(this: *mut core::ffi::c_void, index: u32, hreftype: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo", "AddImplType", 10usize, |this| ((**(this as *const *const ICreateTypeInfo_Vtbl)).AddImplType)(this, index, hreftype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetImplTypeFlags(this: *mut core::ffi::c_void, index: u32, impltypeflags: super::Com::IMPLTYPEFLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo", "SetImplTypeFlags", 11usize, |this| ((**(this as *const *const ICreateTypeInfo_Vtbl)).SetImplTypeFlags)(this, index, impltypeflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlignment(this: *mut core::ffi::c_void, cbalignment: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo", "SetAlignment", 12usize, |this| ((**(this as *const *const ICreateTypeInfo_Vtbl)).SetAlignment)(this, cbalignment), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSchema(this: *mut core::ffi::c_void, pstrschema: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo", "SetSchema", 13usize, |this| ((**(this as *const *const ICreateTypeInfo_Vtbl)).SetSchema)(this, pstrschema), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
+        unsafe extern "system" fn AddVarDesc(this: *mut core::ffi::c_void, index: u32, pvardesc: *const super::Com::VARDESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo", "AddVarDesc", 14usize, |this| ((**(this as *const *const ICreateTypeInfo_Vtbl)).AddVarDesc)(this, index, pvardesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFuncAndParamNames(this: *mut core::ffi::c_void, index: u32, rgsznames: *const windows_core::PCWSTR, cnames: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo", "SetFuncAndParamNames", 15usize, |this| ((**(this as *const *const ICreateTypeInfo_Vtbl)).SetFuncAndParamNames)(this, index, rgsznames, cnames), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVarName(this: *mut core::ffi::c_void, index: u32, szname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo", "SetVarName", 16usize, |this| ((**(this as *const *const ICreateTypeInfo_Vtbl)).SetVarName)(this, index, szname), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
+        unsafe extern "system" fn SetTypeDescAlias(this: *mut core::ffi::c_void, ptdescalias: *const super::Com::TYPEDESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo", "SetTypeDescAlias", 17usize, |this| ((**(this as *const *const ICreateTypeInfo_Vtbl)).SetTypeDescAlias)(this, ptdescalias), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefineFuncAsDllEntry(this: *mut core::ffi::c_void, index: u32, szdllname: windows_core::PCWSTR, szprocname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo", "DefineFuncAsDllEntry", 18usize, |this| ((**(this as *const *const ICreateTypeInfo_Vtbl)).DefineFuncAsDllEntry)(this, index, szdllname, szprocname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFuncDocString(this: *mut core::ffi::c_void, index: u32, szdocstring: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo", "SetFuncDocString", 19usize, |this| ((**(this as *const *const ICreateTypeInfo_Vtbl)).SetFuncDocString)(this, index, szdocstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVarDocString(this: *mut core::ffi::c_void, index: u32, szdocstring: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo", "SetVarDocString", 20usize, |this| ((**(this as *const *const ICreateTypeInfo_Vtbl)).SetVarDocString)(this, index, szdocstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFuncHelpContext(this: *mut core::ffi::c_void, index: u32, dwhelpcontext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo", "SetFuncHelpContext", 21usize, |this| ((**(this as *const *const ICreateTypeInfo_Vtbl)).SetFuncHelpContext)(this, index, dwhelpcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVarHelpContext(this: *mut core::ffi::c_void, index: u32, dwhelpcontext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo", "SetVarHelpContext", 22usize, |this| ((**(this as *const *const ICreateTypeInfo_Vtbl)).SetVarHelpContext)(this, index, dwhelpcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMops(this: *mut core::ffi::c_void, index: u32, bstrmops: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo", "SetMops", 23usize, |this| ((**(this as *const *const ICreateTypeInfo_Vtbl)).SetMops)(this, index, bstrmops), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetTypeIdldesc(this: *mut core::ffi::c_void, pidldesc: *const super::Com::IDLDESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo", "SetTypeIdldesc", 24usize, |this| ((**(this as *const *const ICreateTypeInfo_Vtbl)).SetTypeIdldesc)(this, pidldesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn LayOut(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo", "LayOut", 25usize, |this| ((**(this as *const *const ICreateTypeInfo_Vtbl)).LayOut)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetGuid,
+            SetTypeFlags,
+            SetDocString,
+            SetHelpContext,
+            SetVersion,
+            #[cfg(feature = "Win32_System_Com")]
+            AddRefTypeInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddRefTypeInfo: 0,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
+            AddFuncDesc,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Variant")))]
+            AddFuncDesc: 0,
+            AddImplType,
+            #[cfg(feature = "Win32_System_Com")]
+            SetImplTypeFlags,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetImplTypeFlags: 0,
+            SetAlignment,
+            SetSchema,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
+            AddVarDesc,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Variant")))]
+            AddVarDesc: 0,
+            SetFuncAndParamNames,
+            SetVarName,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
+            SetTypeDescAlias,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Variant")))]
+            SetTypeDescAlias: 0,
+            DefineFuncAsDllEntry,
+            SetFuncDocString,
+            SetVarDocString,
+            SetFuncHelpContext,
+            SetVarHelpContext,
+            SetMops,
+            #[cfg(feature = "Win32_System_Com")]
+            SetTypeIdldesc,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetTypeIdldesc: 0,
+            LayOut,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICreateTypeInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Variant"))]
+impl windows_core::imp::ProxyVtable for ICreateTypeInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeleteFuncDesc(this: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo2", "DeleteFuncDesc", 26usize, |this| ((**(this as *const *const ICreateTypeInfo2_Vtbl)).DeleteFuncDesc)(this, index), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DeleteFuncDescByMemId(this: *mut core::ffi::c_void, memid: i32, invkind: super::Com::INVOKEKIND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo2", "DeleteFuncDescByMemId", 27usize, |this| ((**(this as *const *const ICreateTypeInfo2_Vtbl)).DeleteFuncDescByMemId)(this, memid, invkind), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteVarDesc(this: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo2", "DeleteVarDesc", 28usize, |this| ((**(this as *const *const ICreateTypeInfo2_Vtbl)).DeleteVarDesc)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteVarDescByMemId(this: *mut core::ffi::c_void, memid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo2", "DeleteVarDescByMemId", 29usize, |this| ((**(this as *const *const ICreateTypeInfo2_Vtbl)).DeleteVarDescByMemId)(this, memid), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteImplType(this: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo2", "DeleteImplType", 30usize, |this| ((**(this as *const *const ICreateTypeInfo2_Vtbl)).DeleteImplType)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCustData(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pvarval: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo2", "SetCustData", 31usize, |this| ((**(this as *const *const ICreateTypeInfo2_Vtbl)).SetCustData)(this, guid, pvarval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFuncCustData(this: *mut core::ffi::c_void, index: u32, guid: *const windows_core::GUID, pvarval: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo2", "SetFuncCustData", 32usize, |this| ((**(this as *const *const ICreateTypeInfo2_Vtbl)).SetFuncCustData)(this, index, guid, pvarval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParamCustData(this: *mut core::ffi::c_void, indexfunc: u32, indexparam: u32, guid: *const windows_core::GUID, pvarval: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo2", "SetParamCustData", 33usize, |this| ((**(this as *const *const ICreateTypeInfo2_Vtbl)).SetParamCustData)(this, indexfunc, indexparam, guid, pvarval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVarCustData(this: *mut core::ffi::c_void, index: u32, guid: *const windows_core::GUID, pvarval: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo2", "SetVarCustData", 34usize, |this| ((**(this as *const *const ICreateTypeInfo2_Vtbl)).SetVarCustData)(this, index, guid, pvarval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetImplTypeCustData(this: *mut core::ffi::c_void, index: u32, guid: *const windows_core::GUID, pvarval: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo2", "SetImplTypeCustData", 35usize, |this| ((**(this as *const *const ICreateTypeInfo2_Vtbl)).SetImplTypeCustData)(this, index, guid, pvarval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHelpStringContext(this: *mut core::ffi::c_void, dwhelpstringcontext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo2", "SetHelpStringContext", 36usize, |this| ((**(this as *const *const ICreateTypeInfo2_Vtbl)).SetHelpStringContext)(this, dwhelpstringcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFuncHelpStringContext(this: *mut core::ffi::c_void, index: u32, dwhelpstringcontext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo2", "SetFuncHelpStringContext", 37usize, |this| ((**(this as *const *const ICreateTypeInfo2_Vtbl)).SetFuncHelpStringContext)(this, index, dwhelpstringcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVarHelpStringContext(this: *mut core::ffi::c_void, index: u32, dwhelpstringcontext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo2", "SetVarHelpStringContext", 38usize, |this| ((**(this as *const *const ICreateTypeInfo2_Vtbl)).SetVarHelpStringContext)(this, index, dwhelpstringcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn Invalidate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo2", "Invalidate", 39usize, |this| ((**(this as *const *const ICreateTypeInfo2_Vtbl)).Invalidate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, szname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeInfo2", "SetName", 40usize, |this| ((**(this as *const *const ICreateTypeInfo2_Vtbl)).SetName)(this, szname), |result| Some(*result))
+        }
+        Self {
+            base__: <ICreateTypeInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeleteFuncDesc,
+            #[cfg(feature = "Win32_System_Com")]
+            DeleteFuncDescByMemId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DeleteFuncDescByMemId: 0,
+            DeleteVarDesc,
+            DeleteVarDescByMemId,
+            DeleteImplType,
+            SetCustData,
+            SetFuncCustData,
+            SetParamCustData,
+            SetVarCustData,
+            SetImplTypeCustData,
+            SetHelpStringContext,
+            SetFuncHelpStringContext,
+            SetVarHelpStringContext,
+            Invalidate,
+            SetName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICreateTypeInfo2 as windows_core::Interface>::IID || <ICreateTypeInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICreateTypeLib_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateTypeInfo(this: *mut core::ffi::c_void, szname: windows_core::PCWSTR, tkind: super::Com::TYPEKIND, ppctinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeLib", "CreateTypeInfo", 3usize, |this| ((**(this as *const *const ICreateTypeLib_Vtbl)).CreateTypeInfo)(this, szname, tkind, ppctinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, szname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeLib", "SetName", 4usize, |this| ((**(this as *const *const ICreateTypeLib_Vtbl)).SetName)(this, szname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVersion(this: *mut core::ffi::c_void, wmajorvernum: u16, wminorvernum: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeLib", "SetVersion", 5usize, |this| ((**(this as *const *const ICreateTypeLib_Vtbl)).SetVersion)(this, wmajorvernum, wminorvernum), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGuid(this: *mut core::ffi::c_void, guid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeLib", "SetGuid", 6usize, |this| ((**(this as *const *const ICreateTypeLib_Vtbl)).SetGuid)(this, guid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDocString(this: *mut core::ffi::c_void, szdoc: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeLib", "SetDocString", 7usize, |this| ((**(this as *const *const ICreateTypeLib_Vtbl)).SetDocString)(this, szdoc), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHelpFileName(this: *mut core::ffi::c_void, szhelpfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeLib", "SetHelpFileName", 8usize, |this| ((**(this as *const *const ICreateTypeLib_Vtbl)).SetHelpFileName)(this, szhelpfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHelpContext(this: *mut core::ffi::c_void, dwhelpcontext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeLib", "SetHelpContext", 9usize, |this| ((**(this as *const *const ICreateTypeLib_Vtbl)).SetHelpContext)(this, dwhelpcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLcid(this: *mut core::ffi::c_void, lcid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeLib", "SetLcid", 10usize, |this| ((**(this as *const *const ICreateTypeLib_Vtbl)).SetLcid)(this, lcid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLibFlags(this: *mut core::ffi::c_void, ulibflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeLib", "SetLibFlags", 11usize, |this| ((**(this as *const *const ICreateTypeLib_Vtbl)).SetLibFlags)(this, ulibflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveAllChanges(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeLib", "SaveAllChanges", 12usize, |this| ((**(this as *const *const ICreateTypeLib_Vtbl)).SaveAllChanges)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateTypeInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateTypeInfo: 0,
+            SetName,
+            SetVersion,
+            SetGuid,
+            SetDocString,
+            SetHelpFileName,
+            SetHelpContext,
+            SetLcid,
+            SetLibFlags,
+            SaveAllChanges,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICreateTypeLib as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICreateTypeLib2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeleteTypeInfo(this: *mut core::ffi::c_void, szname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeLib2", "DeleteTypeInfo", 13usize, |this| ((**(this as *const *const ICreateTypeLib2_Vtbl)).DeleteTypeInfo)(this, szname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCustData(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, pvarval: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeLib2", "SetCustData", 14usize, |this| ((**(this as *const *const ICreateTypeLib2_Vtbl)).SetCustData)(this, guid, pvarval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHelpStringContext(this: *mut core::ffi::c_void, dwhelpstringcontext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeLib2", "SetHelpStringContext", 15usize, |this| ((**(this as *const *const ICreateTypeLib2_Vtbl)).SetHelpStringContext)(this, dwhelpstringcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHelpStringDll(this: *mut core::ffi::c_void, szfilename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateTypeLib2", "SetHelpStringDll", 16usize, |this| ((**(this as *const *const ICreateTypeLib2_Vtbl)).SetHelpStringDll)(this, szfilename), |result| Some(*result))
+        }
+        Self { base__: <ICreateTypeLib_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeleteTypeInfo, SetCustData, SetHelpStringContext, SetHelpStringDll }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICreateTypeLib2 as windows_core::Interface>::IID || <ICreateTypeLib_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDispError_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryErrorInfo(this: *mut core::ffi::c_void, guiderrortype: windows_core::GUID, ppde: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispError", "QueryErrorInfo", 3usize, |this| ((**(this as *const *const IDispError_Vtbl)).QueryErrorInfo)(this, guiderrortype, ppde), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNext(this: *mut core::ffi::c_void, ppde: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispError", "GetNext", 4usize, |this| ((**(this as *const *const IDispError_Vtbl)).GetNext)(this, ppde), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHresult(this: *mut core::ffi::c_void, phr: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispError", "GetHresult", 5usize, |this| ((**(this as *const *const IDispError_Vtbl)).GetHresult)(this, phr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSource(this: *mut core::ffi::c_void, pbstrsource: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispError", "GetSource", 6usize, |this| ((**(this as *const *const IDispError_Vtbl)).GetSource)(this, pbstrsource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHelpInfo(this: *mut core::ffi::c_void, pbstrfilename: *mut core::mem::MaybeUninit<windows_core::BSTR>, pdwcontext: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispError", "GetHelpInfo", 7usize, |this| ((**(this as *const *const IDispError_Vtbl)).GetHelpInfo)(this, pbstrfilename, pdwcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescription(this: *mut core::ffi::c_void, pbstrdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispError", "GetDescription", 8usize, |this| ((**(this as *const *const IDispError_Vtbl)).GetDescription)(this, pbstrdescription), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            QueryErrorInfo,
+            GetNext,
+            GetHresult,
+            GetSource,
+            GetHelpInfo,
+            GetDescription,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDispError as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDispatchEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDispID(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, grfdex: u32, pid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatchEx", "GetDispID", 7usize, |this| ((**(this as *const *const IDispatchEx_Vtbl)).GetDispID)(this, bstrname, grfdex, pid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InvokeEx(this: *mut core::ffi::c_void, id: i32, lcid: u32, wflags: u16, pdp: *const super::Com::DISPPARAMS, pvarres: *mut core::mem::MaybeUninit<windows_core::VARIANT>, pei: *mut super::Com::EXCEPINFO, pspcaller: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatchEx", "InvokeEx", 8usize, |this| ((**(this as *const *const IDispatchEx_Vtbl)).InvokeEx)(this, id, lcid, wflags, pdp, pvarres, pei, pspcaller), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteMemberByName(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, grfdex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatchEx", "DeleteMemberByName", 9usize, |this| ((**(this as *const *const IDispatchEx_Vtbl)).DeleteMemberByName)(this, bstrname, grfdex), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteMemberByDispID(this: *mut core::ffi::c_void, id: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatchEx", "DeleteMemberByDispID", 10usize, |this| ((**(this as *const *const IDispatchEx_Vtbl)).DeleteMemberByDispID)(this, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMemberProperties(this: *mut core::ffi::c_void, id: i32, grfdexfetch: u32, pgrfdex: *mut FDEX_PROP_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatchEx", "GetMemberProperties", 11usize, |this| ((**(this as *const *const IDispatchEx_Vtbl)).GetMemberProperties)(this, id, grfdexfetch, pgrfdex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMemberName(this: *mut core::ffi::c_void, id: i32, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatchEx", "GetMemberName", 12usize, |this| ((**(this as *const *const IDispatchEx_Vtbl)).GetMemberName)(this, id, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextDispID(this: *mut core::ffi::c_void, grfdex: u32, id: i32, pid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatchEx", "GetNextDispID", 13usize, |this| ((**(this as *const *const IDispatchEx_Vtbl)).GetNextDispID)(this, grfdex, id, pid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNameSpaceParent(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDispatchEx", "GetNameSpaceParent", 14usize, |this| ((**(this as *const *const IDispatchEx_Vtbl)).GetNameSpaceParent)(this, ppunk), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDispID,
+            #[cfg(feature = "Win32_System_Com")]
+            InvokeEx,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InvokeEx: 0,
+            DeleteMemberByName,
+            DeleteMemberByDispID,
+            GetMemberProperties,
+            GetMemberName,
+            GetNextDispID,
+            GetNameSpaceParent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDispatchEx as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_SystemServices")]
+impl windows_core::imp::ProxyVtable for IDropSource_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_SystemServices")]
+        unsafe extern "system" fn QueryContinueDrag(this: *mut core::ffi::c_void, fescapepressed: super::super::Foundation::BOOL, grfkeystate: super::SystemServices::MODIFIERKEYS_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDropSource", "QueryContinueDrag", 3usize, |this| ((**(this as *const *const IDropSource_Vtbl)).QueryContinueDrag)(this, fescapepressed, grfkeystate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GiveFeedback(this: *mut core::ffi::c_void, dweffect: DROPEFFECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDropSource", "GiveFeedback", 4usize, |this| ((**(this as *const *const IDropSource_Vtbl)).GiveFeedback)(this, dweffect), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_SystemServices")]
+            QueryContinueDrag,
+            #[cfg(not(feature = "Win32_System_SystemServices"))]
+            QueryContinueDrag: 0,
+            GiveFeedback,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDropSource as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDropSourceNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DragEnterTarget(this: *mut core::ffi::c_void, hwndtarget: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDropSourceNotify", "DragEnterTarget", 3usize, |this| ((**(this as *const *const IDropSourceNotify_Vtbl)).DragEnterTarget)(this, hwndtarget), |result| Some(*result))
+        }
+        unsafe extern "system" fn DragLeaveTarget(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDropSourceNotify", "DragLeaveTarget", 4usize, |this| ((**(this as *const *const IDropSourceNotify_Vtbl)).DragLeaveTarget)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DragEnterTarget, DragLeaveTarget }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDropSourceNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_SystemServices"))]
+impl windows_core::imp::ProxyVtable for IDropTarget_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_SystemServices"))]
+        unsafe extern "system" fn DragEnter(this: *mut core::ffi::c_void, pdataobj: *mut core::ffi::c_void, grfkeystate: super::SystemServices::MODIFIERKEYS_FLAGS, pt: super::super::Foundation::POINTL, pdweffect: *mut DROPEFFECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDropTarget", "DragEnter", 3usize, |this| ((**(this as *const *const IDropTarget_Vtbl)).DragEnter)(this, pdataobj, grfkeystate, pt, pdweffect), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_SystemServices")]
+        unsafe extern "system" fn DragOver(this: *mut core::ffi::c_void, grfkeystate: super::SystemServices::MODIFIERKEYS_FLAGS, pt: super::super::Foundation::POINTL, pdweffect: *mut DROPEFFECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDropTarget", "DragOver", 4usize, |this| ((**(this as *const *const IDropTarget_Vtbl)).DragOver)(this, grfkeystate, pt, pdweffect), |result| Some(*result))
+        }
+        unsafe extern "system" fn DragLeave(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDropTarget", "DragLeave", 5usize, |this| ((**(this as *const *const IDropTarget_Vtbl)).DragLeave)(this), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_SystemServices"))]
+        unsafe extern "system" fn Drop(this: *mut core::ffi::c_void, pdataobj: *mut core::ffi::c_void, grfkeystate: super::SystemServices::MODIFIERKEYS_FLAGS, pt: super::super::Foundation::POINTL, pdweffect: *mut DROPEFFECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDropTarget", "Drop", 6usize, |this| ((**(this as *const *const IDropTarget_Vtbl)).Drop)(this, pdataobj, grfkeystate, pt, pdweffect), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_SystemServices"))]
+            DragEnter,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_SystemServices")))]
+            DragEnter: 0,
+            #[cfg(feature = "Win32_System_SystemServices")]
+            DragOver,
+            #[cfg(not(feature = "Win32_System_SystemServices"))]
+            DragOver: 0,
+            DragLeave,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_SystemServices"))]
+            Drop,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_SystemServices")))]
+            Drop: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDropTarget as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnterpriseDropTarget_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDropSourceEnterpriseId(this: *mut core::ffi::c_void, identity: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnterpriseDropTarget", "SetDropSourceEnterpriseId", 3usize, |this| ((**(this as *const *const IEnterpriseDropTarget_Vtbl)).SetDropSourceEnterpriseId)(this, identity), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEvaluatingEdpPolicy(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnterpriseDropTarget", "IsEvaluatingEdpPolicy", 4usize, |this| ((**(this as *const *const IEnterpriseDropTarget_Vtbl)).IsEvaluatingEdpPolicy)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetDropSourceEnterpriseId, IsEvaluatingEdpPolicy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnterpriseDropTarget as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl windows_core::imp::ProxyVtable for IEnumOLEVERB_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut OLEVERB, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumOLEVERB", "Next", 3usize, |this| ((**(this as *const *const IEnumOLEVERB_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumOLEVERB", "Skip", 4usize, |this| ((**(this as *const *const IEnumOLEVERB_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumOLEVERB", "Reset", 5usize, |this| ((**(this as *const *const IEnumOLEVERB_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumOLEVERB", "Clone", 6usize, |this| ((**(this as *const *const IEnumOLEVERB_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            Next,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            Next: 0,
+            Skip,
+            Reset,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumOLEVERB as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumOleDocumentViews_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, cviews: u32, rgpview: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumOleDocumentViews", "Next", 3usize, |this| ((**(this as *const *const IEnumOleDocumentViews_Vtbl)).Next)(this, cviews, rgpview, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, cviews: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumOleDocumentViews", "Skip", 4usize, |this| ((**(this as *const *const IEnumOleDocumentViews_Vtbl)).Skip)(this, cviews), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumOleDocumentViews", "Reset", 5usize, |this| ((**(this as *const *const IEnumOleDocumentViews_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumOleDocumentViews", "Clone", 6usize, |this| ((**(this as *const *const IEnumOleDocumentViews_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumOleDocumentViews as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumOleUndoUnits_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumOleUndoUnits", "Next", 3usize, |this| ((**(this as *const *const IEnumOleUndoUnits_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumOleUndoUnits", "Skip", 4usize, |this| ((**(this as *const *const IEnumOleUndoUnits_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumOleUndoUnits", "Reset", 5usize, |this| ((**(this as *const *const IEnumOleUndoUnits_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumOleUndoUnits", "Clone", 6usize, |this| ((**(this as *const *const IEnumOleUndoUnits_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumOleUndoUnits as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumVARIANT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgvar: *mut core::mem::MaybeUninit<windows_core::VARIANT>, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumVARIANT", "Next", 3usize, |this| ((**(this as *const *const IEnumVARIANT_Vtbl)).Next)(this, celt, rgvar, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumVARIANT", "Skip", 4usize, |this| ((**(this as *const *const IEnumVARIANT_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumVARIANT", "Reset", 5usize, |this| ((**(this as *const *const IEnumVARIANT_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumVARIANT", "Clone", 6usize, |this| ((**(this as *const *const IEnumVARIANT_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumVARIANT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IFont_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFont", "Name", 3usize, |this| ((**(this as *const *const IFont_Vtbl)).Name)(this, pname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFont", "SetName", 4usize, |this| ((**(this as *const *const IFont_Vtbl)).SetName)(this, name), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, psize: *mut super::Com::CY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFont", "Size", 5usize, |this| ((**(this as *const *const IFont_Vtbl)).Size)(this, psize), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSize(this: *mut core::ffi::c_void, size: super::Com::CY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFont", "SetSize", 6usize, |this| ((**(this as *const *const IFont_Vtbl)).SetSize)(this, size), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bold(this: *mut core::ffi::c_void, pbold: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFont", "Bold", 7usize, |this| ((**(this as *const *const IFont_Vtbl)).Bold)(this, pbold), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBold(this: *mut core::ffi::c_void, bold: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFont", "SetBold", 8usize, |this| ((**(this as *const *const IFont_Vtbl)).SetBold)(this, bold), |result| Some(*result))
+        }
+        unsafe extern "system" fn Italic(this: *mut core::ffi::c_void, pitalic: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFont", "Italic", 9usize, |this| ((**(this as *const *const IFont_Vtbl)).Italic)(this, pitalic), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetItalic(this: *mut core::ffi::c_void, italic: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFont", "SetItalic", 10usize, |this| ((**(this as *const *const IFont_Vtbl)).SetItalic)(this, italic), |result| Some(*result))
+        }
+        unsafe extern "system" fn Underline(this: *mut core::ffi::c_void, punderline: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFont", "Underline", 11usize, |this| ((**(this as *const *const IFont_Vtbl)).Underline)(this, punderline), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUnderline(this: *mut core::ffi::c_void, underline: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFont", "SetUnderline", 12usize, |this| ((**(this as *const *const IFont_Vtbl)).SetUnderline)(this, underline), |result| Some(*result))
+        }
+        unsafe extern "system" fn Strikethrough(this: *mut core::ffi::c_void, pstrikethrough: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFont", "Strikethrough", 13usize, |this| ((**(this as *const *const IFont_Vtbl)).Strikethrough)(this, pstrikethrough), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrikethrough(this: *mut core::ffi::c_void, strikethrough: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFont", "SetStrikethrough", 14usize, |this| ((**(this as *const *const IFont_Vtbl)).SetStrikethrough)(this, strikethrough), |result| Some(*result))
+        }
+        unsafe extern "system" fn Weight(this: *mut core::ffi::c_void, pweight: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFont", "Weight", 15usize, |this| ((**(this as *const *const IFont_Vtbl)).Weight)(this, pweight), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWeight(this: *mut core::ffi::c_void, weight: i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFont", "SetWeight", 16usize, |this| ((**(this as *const *const IFont_Vtbl)).SetWeight)(this, weight), |result| Some(*result))
+        }
+        unsafe extern "system" fn Charset(this: *mut core::ffi::c_void, pcharset: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFont", "Charset", 17usize, |this| ((**(this as *const *const IFont_Vtbl)).Charset)(this, pcharset), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCharset(this: *mut core::ffi::c_void, charset: i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFont", "SetCharset", 18usize, |this| ((**(this as *const *const IFont_Vtbl)).SetCharset)(this, charset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn hFont(this: *mut core::ffi::c_void, phfont: *mut super::super::Graphics::Gdi::HFONT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFont", "hFont", 19usize, |this| ((**(this as *const *const IFont_Vtbl)).hFont)(this, phfont), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppfont: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFont", "Clone", 20usize, |this| ((**(this as *const *const IFont_Vtbl)).Clone)(this, ppfont), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEqual(this: *mut core::ffi::c_void, pfontother: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFont", "IsEqual", 21usize, |this| ((**(this as *const *const IFont_Vtbl)).IsEqual)(this, pfontother), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRatio(this: *mut core::ffi::c_void, cylogical: i32, cyhimetric: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFont", "SetRatio", 22usize, |this| ((**(this as *const *const IFont_Vtbl)).SetRatio)(this, cylogical, cyhimetric), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn QueryTextMetrics(this: *mut core::ffi::c_void, ptm: *mut super::super::Graphics::Gdi::TEXTMETRICW) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFont", "QueryTextMetrics", 23usize, |this| ((**(this as *const *const IFont_Vtbl)).QueryTextMetrics)(this, ptm), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn AddRefHfont(this: *mut core::ffi::c_void, hfont: super::super::Graphics::Gdi::HFONT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFont", "AddRefHfont", 24usize, |this| ((**(this as *const *const IFont_Vtbl)).AddRefHfont)(this, hfont), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn ReleaseHfont(this: *mut core::ffi::c_void, hfont: super::super::Graphics::Gdi::HFONT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFont", "ReleaseHfont", 25usize, |this| ((**(this as *const *const IFont_Vtbl)).ReleaseHfont)(this, hfont), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn SetHdc(this: *mut core::ffi::c_void, hdc: super::super::Graphics::Gdi::HDC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFont", "SetHdc", 26usize, |this| ((**(this as *const *const IFont_Vtbl)).SetHdc)(this, hdc), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            SetName,
+            #[cfg(feature = "Win32_System_Com")]
+            Size,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Size: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSize: 0,
+            Bold,
+            SetBold,
+            Italic,
+            SetItalic,
+            Underline,
+            SetUnderline,
+            Strikethrough,
+            SetStrikethrough,
+            Weight,
+            SetWeight,
+            Charset,
+            SetCharset,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            hFont,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            hFont: 0,
+            Clone,
+            IsEqual,
+            SetRatio,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            QueryTextMetrics,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            QueryTextMetrics: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            AddRefHfont,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            AddRefHfont: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            ReleaseHfont,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            ReleaseHfont: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            SetHdc,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            SetHdc: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFont as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFontDisp_Vtbl {
+    const PROXY: Self = { Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFontDisp as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFontEventsDisp_Vtbl {
+    const PROXY: Self = { Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFontEventsDisp as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGetOleObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOleObject(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetOleObject", "GetOleObject", 3usize, |this| ((**(this as *const *const IGetOleObject_Vtbl)).GetOleObject)(this, riid, ppvobj), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetOleObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGetOleObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGetVBAObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetObject(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvobj: *mut *mut core::ffi::c_void, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetVBAObject", "GetObject", 3usize, |this| ((**(this as *const *const IGetVBAObject_Vtbl)).GetObject)(this, riid, ppvobj, dwreserved), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGetVBAObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IObjectIdentity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsEqualObject(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectIdentity", "IsEqualObject", 3usize, |this| ((**(this as *const *const IObjectIdentity_Vtbl)).IsEqualObject)(this, punk), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsEqualObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IObjectIdentity as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IObjectWithSite_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSite(this: *mut core::ffi::c_void, punksite: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectWithSite", "SetSite", 3usize, |this| ((**(this as *const *const IObjectWithSite_Vtbl)).SetSite)(this, punksite), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSite(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvsite: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectWithSite", "GetSite", 4usize, |this| ((**(this as *const *const IObjectWithSite_Vtbl)).GetSite)(this, riid, ppvsite), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetSite, GetSite }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IObjectWithSite as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOleAdviseHolder_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Advise(this: *mut core::ffi::c_void, padvise: *mut core::ffi::c_void, pdwconnection: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleAdviseHolder", "Advise", 3usize, |this| ((**(this as *const *const IOleAdviseHolder_Vtbl)).Advise)(this, padvise, pdwconnection), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unadvise(this: *mut core::ffi::c_void, dwconnection: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleAdviseHolder", "Unadvise", 4usize, |this| ((**(this as *const *const IOleAdviseHolder_Vtbl)).Unadvise)(this, dwconnection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumAdvise(this: *mut core::ffi::c_void, ppenumadvise: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleAdviseHolder", "EnumAdvise", 5usize, |this| ((**(this as *const *const IOleAdviseHolder_Vtbl)).EnumAdvise)(this, ppenumadvise), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SendOnRename(this: *mut core::ffi::c_void, pmk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleAdviseHolder", "SendOnRename", 6usize, |this| ((**(this as *const *const IOleAdviseHolder_Vtbl)).SendOnRename)(this, pmk), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendOnSave(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleAdviseHolder", "SendOnSave", 7usize, |this| ((**(this as *const *const IOleAdviseHolder_Vtbl)).SendOnSave)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendOnClose(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleAdviseHolder", "SendOnClose", 8usize, |this| ((**(this as *const *const IOleAdviseHolder_Vtbl)).SendOnClose)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Advise,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Advise: 0,
+            Unadvise,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumAdvise,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumAdvise: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SendOnRename,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SendOnRename: 0,
+            SendOnSave,
+            SendOnClose,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleAdviseHolder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+impl windows_core::imp::ProxyVtable for IOleCache_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Cache(this: *mut core::ffi::c_void, pformatetc: *const super::Com::FORMATETC, advf: u32, pdwconnection: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleCache", "Cache", 3usize, |this| ((**(this as *const *const IOleCache_Vtbl)).Cache)(this, pformatetc, advf, pdwconnection), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uncache(this: *mut core::ffi::c_void, dwconnection: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleCache", "Uncache", 4usize, |this| ((**(this as *const *const IOleCache_Vtbl)).Uncache)(this, dwconnection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumCache(this: *mut core::ffi::c_void, ppenumstatdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleCache", "EnumCache", 5usize, |this| ((**(this as *const *const IOleCache_Vtbl)).EnumCache)(this, ppenumstatdata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitCache(this: *mut core::ffi::c_void, pdataobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleCache", "InitCache", 6usize, |this| ((**(this as *const *const IOleCache_Vtbl)).InitCache)(this, pdataobject), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, pformatetc: *const super::Com::FORMATETC, pmedium: *const super::Com::STGMEDIUM, frelease: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleCache", "SetData", 7usize, |this| ((**(this as *const *const IOleCache_Vtbl)).SetData)(this, pformatetc, pmedium, frelease), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Cache,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Cache: 0,
+            Uncache,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumCache,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumCache: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            InitCache,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitCache: 0,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+            SetData,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage")))]
+            SetData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleCache as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+impl windows_core::imp::ProxyVtable for IOleCache2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn UpdateCache(this: *mut core::ffi::c_void, pdataobject: *mut core::ffi::c_void, grfupdf: UPDFCACHE_FLAGS, preserved: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleCache2", "UpdateCache", 8usize, |this| ((**(this as *const *const IOleCache2_Vtbl)).UpdateCache)(this, pdataobject, grfupdf, preserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn DiscardCache(this: *mut core::ffi::c_void, dwdiscardoptions: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleCache2", "DiscardCache", 9usize, |this| ((**(this as *const *const IOleCache2_Vtbl)).DiscardCache)(this, dwdiscardoptions), |result| Some(*result))
+        }
+        Self {
+            base__: <IOleCache_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            UpdateCache,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            UpdateCache: 0,
+            DiscardCache,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleCache2 as windows_core::Interface>::IID || <IOleCache_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOleCacheControl_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnRun(this: *mut core::ffi::c_void, pdataobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleCacheControl", "OnRun", 3usize, |this| ((**(this as *const *const IOleCacheControl_Vtbl)).OnRun)(this, pdataobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnStop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleCacheControl", "OnStop", 4usize, |this| ((**(this as *const *const IOleCacheControl_Vtbl)).OnStop)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            OnRun,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnRun: 0,
+            OnStop,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleCacheControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOleClientSite_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SaveObject(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleClientSite", "SaveObject", 3usize, |this| ((**(this as *const *const IOleClientSite_Vtbl)).SaveObject)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetMoniker(this: *mut core::ffi::c_void, dwassign: u32, dwwhichmoniker: u32, ppmk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleClientSite", "GetMoniker", 4usize, |this| ((**(this as *const *const IOleClientSite_Vtbl)).GetMoniker)(this, dwassign, dwwhichmoniker, ppmk), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContainer(this: *mut core::ffi::c_void, ppcontainer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleClientSite", "GetContainer", 5usize, |this| ((**(this as *const *const IOleClientSite_Vtbl)).GetContainer)(this, ppcontainer), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowObject(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleClientSite", "ShowObject", 6usize, |this| ((**(this as *const *const IOleClientSite_Vtbl)).ShowObject)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnShowWindow(this: *mut core::ffi::c_void, fshow: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleClientSite", "OnShowWindow", 7usize, |this| ((**(this as *const *const IOleClientSite_Vtbl)).OnShowWindow)(this, fshow), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestNewObjectLayout(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleClientSite", "RequestNewObjectLayout", 8usize, |this| ((**(this as *const *const IOleClientSite_Vtbl)).RequestNewObjectLayout)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SaveObject,
+            #[cfg(feature = "Win32_System_Com")]
+            GetMoniker,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetMoniker: 0,
+            GetContainer,
+            ShowObject,
+            OnShowWindow,
+            RequestNewObjectLayout,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleClientSite as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOleCommandTarget_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryStatus(this: *mut core::ffi::c_void, pguidcmdgroup: *const windows_core::GUID, ccmds: u32, prgcmds: *mut OLECMD, pcmdtext: *mut OLECMDTEXT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleCommandTarget", "QueryStatus", 3usize, |this| ((**(this as *const *const IOleCommandTarget_Vtbl)).QueryStatus)(this, pguidcmdgroup, ccmds, prgcmds, pcmdtext), |result| Some(*result))
+        }
+        unsafe extern "system" fn Exec(this: *mut core::ffi::c_void, pguidcmdgroup: *const windows_core::GUID, ncmdid: u32, ncmdexecopt: u32, pvain: *const core::mem::MaybeUninit<windows_core::VARIANT>, pvaout: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleCommandTarget", "Exec", 4usize, |this| ((**(this as *const *const IOleCommandTarget_Vtbl)).Exec)(this, pguidcmdgroup, ncmdid, ncmdexecopt, pvain, pvaout), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryStatus, Exec }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleCommandTarget as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOleContainer_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumObjects(this: *mut core::ffi::c_void, grfflags: u32, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleContainer", "EnumObjects", 4usize, |this| ((**(this as *const *const IOleContainer_Vtbl)).EnumObjects)(this, grfflags, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockContainer(this: *mut core::ffi::c_void, flock: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleContainer", "LockContainer", 5usize, |this| ((**(this as *const *const IOleContainer_Vtbl)).LockContainer)(this, flock), |result| Some(*result))
+        }
+        Self {
+            base__: <IParseDisplayName_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumObjects,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumObjects: 0,
+            LockContainer,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleContainer as windows_core::Interface>::IID || <IParseDisplayName_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl windows_core::imp::ProxyVtable for IOleControl_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn GetControlInfo(this: *mut core::ffi::c_void, pci: *mut CONTROLINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleControl", "GetControlInfo", 3usize, |this| ((**(this as *const *const IOleControl_Vtbl)).GetControlInfo)(this, pci), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn OnMnemonic(this: *mut core::ffi::c_void, pmsg: *const super::super::UI::WindowsAndMessaging::MSG) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleControl", "OnMnemonic", 4usize, |this| ((**(this as *const *const IOleControl_Vtbl)).OnMnemonic)(this, pmsg), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnAmbientPropertyChange(this: *mut core::ffi::c_void, dispid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleControl", "OnAmbientPropertyChange", 5usize, |this| ((**(this as *const *const IOleControl_Vtbl)).OnAmbientPropertyChange)(this, dispid), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreezeEvents(this: *mut core::ffi::c_void, bfreeze: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleControl", "FreezeEvents", 6usize, |this| ((**(this as *const *const IOleControl_Vtbl)).FreezeEvents)(this, bfreeze), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            GetControlInfo,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            GetControlInfo: 0,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            OnMnemonic,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            OnMnemonic: 0,
+            OnAmbientPropertyChange,
+            FreezeEvents,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_WindowsAndMessaging"))]
+impl windows_core::imp::ProxyVtable for IOleControlSite_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnControlInfoChanged(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleControlSite", "OnControlInfoChanged", 3usize, |this| ((**(this as *const *const IOleControlSite_Vtbl)).OnControlInfoChanged)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockInPlaceActive(this: *mut core::ffi::c_void, flock: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleControlSite", "LockInPlaceActive", 4usize, |this| ((**(this as *const *const IOleControlSite_Vtbl)).LockInPlaceActive)(this, flock), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetExtendedControl(this: *mut core::ffi::c_void, ppdisp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleControlSite", "GetExtendedControl", 5usize, |this| ((**(this as *const *const IOleControlSite_Vtbl)).GetExtendedControl)(this, ppdisp), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransformCoords(this: *mut core::ffi::c_void, pptlhimetric: *mut super::super::Foundation::POINTL, pptfcontainer: *mut POINTF, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleControlSite", "TransformCoords", 6usize, |this| ((**(this as *const *const IOleControlSite_Vtbl)).TransformCoords)(this, pptlhimetric, pptfcontainer, dwflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn TranslateAccelerator(this: *mut core::ffi::c_void, pmsg: *const super::super::UI::WindowsAndMessaging::MSG, grfmodifiers: KEYMODIFIERS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleControlSite", "TranslateAccelerator", 7usize, |this| ((**(this as *const *const IOleControlSite_Vtbl)).TranslateAccelerator)(this, pmsg, grfmodifiers), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnFocus(this: *mut core::ffi::c_void, fgotfocus: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleControlSite", "OnFocus", 8usize, |this| ((**(this as *const *const IOleControlSite_Vtbl)).OnFocus)(this, fgotfocus), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowPropertyFrame(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleControlSite", "ShowPropertyFrame", 9usize, |this| ((**(this as *const *const IOleControlSite_Vtbl)).ShowPropertyFrame)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnControlInfoChanged,
+            LockInPlaceActive,
+            #[cfg(feature = "Win32_System_Com")]
+            GetExtendedControl,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetExtendedControl: 0,
+            TransformCoords,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            TranslateAccelerator,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            TranslateAccelerator: 0,
+            OnFocus,
+            ShowPropertyFrame,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleControlSite as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOleDocument_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateView(this: *mut core::ffi::c_void, pipsite: *mut core::ffi::c_void, pstm: *mut core::ffi::c_void, dwreserved: u32, ppview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleDocument", "CreateView", 3usize, |this| ((**(this as *const *const IOleDocument_Vtbl)).CreateView)(this, pipsite, pstm, dwreserved, ppview), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDocMiscStatus(this: *mut core::ffi::c_void, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleDocument", "GetDocMiscStatus", 4usize, |this| ((**(this as *const *const IOleDocument_Vtbl)).GetDocMiscStatus)(this, pdwstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumViews(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void, ppview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleDocument", "EnumViews", 5usize, |this| ((**(this as *const *const IOleDocument_Vtbl)).EnumViews)(this, ppenum, ppview), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateView,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateView: 0,
+            GetDocMiscStatus,
+            EnumViews,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleDocument as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOleDocumentSite_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ActivateMe(this: *mut core::ffi::c_void, pviewtoactivate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleDocumentSite", "ActivateMe", 3usize, |this| ((**(this as *const *const IOleDocumentSite_Vtbl)).ActivateMe)(this, pviewtoactivate), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ActivateMe }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleDocumentSite as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOleDocumentView_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetInPlaceSite(this: *mut core::ffi::c_void, pipsite: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleDocumentView", "SetInPlaceSite", 3usize, |this| ((**(this as *const *const IOleDocumentView_Vtbl)).SetInPlaceSite)(this, pipsite), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInPlaceSite(this: *mut core::ffi::c_void, ppipsite: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleDocumentView", "GetInPlaceSite", 4usize, |this| ((**(this as *const *const IOleDocumentView_Vtbl)).GetInPlaceSite)(this, ppipsite), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDocument(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleDocumentView", "GetDocument", 5usize, |this| ((**(this as *const *const IOleDocumentView_Vtbl)).GetDocument)(this, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRect(this: *mut core::ffi::c_void, prcview: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleDocumentView", "SetRect", 6usize, |this| ((**(this as *const *const IOleDocumentView_Vtbl)).SetRect)(this, prcview), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRect(this: *mut core::ffi::c_void, prcview: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleDocumentView", "GetRect", 7usize, |this| ((**(this as *const *const IOleDocumentView_Vtbl)).GetRect)(this, prcview), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRectComplex(this: *mut core::ffi::c_void, prcview: *const super::super::Foundation::RECT, prchscroll: *const super::super::Foundation::RECT, prcvscroll: *const super::super::Foundation::RECT, prcsizebox: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleDocumentView", "SetRectComplex", 8usize, |this| ((**(this as *const *const IOleDocumentView_Vtbl)).SetRectComplex)(this, prcview, prchscroll, prcvscroll, prcsizebox), |result| Some(*result))
+        }
+        unsafe extern "system" fn Show(this: *mut core::ffi::c_void, fshow: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleDocumentView", "Show", 9usize, |this| ((**(this as *const *const IOleDocumentView_Vtbl)).Show)(this, fshow), |result| Some(*result))
+        }
+        unsafe extern "system" fn UIActivate(this: *mut core::ffi::c_void, fuiactivate: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleDocumentView", "UIActivate", 10usize, |this| ((**(this as *const *const IOleDocumentView_Vtbl)).UIActivate)(this, fuiactivate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleDocumentView", "Open", 11usize, |this| ((**(this as *const *const IOleDocumentView_Vtbl)).Open)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseView(this: *mut core::ffi::c_void, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleDocumentView", "CloseView", 12usize, |this| ((**(this as *const *const IOleDocumentView_Vtbl)).CloseView)(this, dwreserved), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SaveViewState(this: *mut core::ffi::c_void, pstm: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleDocumentView", "SaveViewState", 13usize, |this| ((**(this as *const *const IOleDocumentView_Vtbl)).SaveViewState)(this, pstm), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ApplyViewState(this: *mut core::ffi::c_void, pstm: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleDocumentView", "ApplyViewState", 14usize, |this| ((**(this as *const *const IOleDocumentView_Vtbl)).ApplyViewState)(this, pstm), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, pipsitenew: *mut core::ffi::c_void, ppviewnew: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleDocumentView", "Clone", 15usize, |this| ((**(this as *const *const IOleDocumentView_Vtbl)).Clone)(this, pipsitenew, ppviewnew), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetInPlaceSite,
+            GetInPlaceSite,
+            GetDocument,
+            SetRect,
+            GetRect,
+            SetRectComplex,
+            Show,
+            UIActivate,
+            Open,
+            CloseView,
+            #[cfg(feature = "Win32_System_Com")]
+            SaveViewState,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SaveViewState: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ApplyViewState,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ApplyViewState: 0,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleDocumentView as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl windows_core::imp::ProxyVtable for IOleInPlaceActiveObject_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn TranslateAccelerator(this: *mut core::ffi::c_void, lpmsg: *const super::super::UI::WindowsAndMessaging::MSG) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceActiveObject", "TranslateAccelerator", 5usize, |this| ((**(this as *const *const IOleInPlaceActiveObject_Vtbl)).TranslateAccelerator)(this, lpmsg), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnFrameWindowActivate(this: *mut core::ffi::c_void, factivate: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceActiveObject", "OnFrameWindowActivate", 6usize, |this| ((**(this as *const *const IOleInPlaceActiveObject_Vtbl)).OnFrameWindowActivate)(this, factivate), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDocWindowActivate(this: *mut core::ffi::c_void, factivate: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceActiveObject", "OnDocWindowActivate", 7usize, |this| ((**(this as *const *const IOleInPlaceActiveObject_Vtbl)).OnDocWindowActivate)(this, factivate), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResizeBorder(this: *mut core::ffi::c_void, prcborder: *const super::super::Foundation::RECT, puiwindow: *mut core::ffi::c_void, fframewindow: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceActiveObject", "ResizeBorder", 8usize, |this| ((**(this as *const *const IOleInPlaceActiveObject_Vtbl)).ResizeBorder)(this, prcborder, puiwindow, fframewindow), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableModeless(this: *mut core::ffi::c_void, fenable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceActiveObject", "EnableModeless", 9usize, |this| ((**(this as *const *const IOleInPlaceActiveObject_Vtbl)).EnableModeless)(this, fenable), |result| Some(*result))
+        }
+        Self {
+            base__: <IOleWindow_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            TranslateAccelerator,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            TranslateAccelerator: 0,
+            OnFrameWindowActivate,
+            OnDocWindowActivate,
+            ResizeBorder,
+            EnableModeless,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleInPlaceActiveObject as windows_core::Interface>::IID || <IOleWindow_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl windows_core::imp::ProxyVtable for IOleInPlaceFrame_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn InsertMenus(this: *mut core::ffi::c_void, hmenushared: super::super::UI::WindowsAndMessaging::HMENU, lpmenuwidths: *mut OLEMENUGROUPWIDTHS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceFrame", "InsertMenus", 9usize, |this| ((**(this as *const *const IOleInPlaceFrame_Vtbl)).InsertMenus)(this, hmenushared, lpmenuwidths), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn SetMenu(this: *mut core::ffi::c_void, hmenushared: super::super::UI::WindowsAndMessaging::HMENU, holemenu: isize, hwndactiveobject: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceFrame", "SetMenu", 10usize, |this| ((**(this as *const *const IOleInPlaceFrame_Vtbl)).SetMenu)(this, hmenushared, holemenu, hwndactiveobject), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn RemoveMenus(this: *mut core::ffi::c_void, hmenushared: super::super::UI::WindowsAndMessaging::HMENU) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceFrame", "RemoveMenus", 11usize, |this| ((**(this as *const *const IOleInPlaceFrame_Vtbl)).RemoveMenus)(this, hmenushared), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatusText(this: *mut core::ffi::c_void, pszstatustext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceFrame", "SetStatusText", 12usize, |this| ((**(this as *const *const IOleInPlaceFrame_Vtbl)).SetStatusText)(this, pszstatustext), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableModeless(this: *mut core::ffi::c_void, fenable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceFrame", "EnableModeless", 13usize, |this| ((**(this as *const *const IOleInPlaceFrame_Vtbl)).EnableModeless)(this, fenable), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn TranslateAccelerator(this: *mut core::ffi::c_void, lpmsg: *const super::super::UI::WindowsAndMessaging::MSG, wid: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceFrame", "TranslateAccelerator", 14usize, |this| ((**(this as *const *const IOleInPlaceFrame_Vtbl)).TranslateAccelerator)(this, lpmsg, wid), |result| Some(*result))
+        }
+        Self {
+            base__: <IOleInPlaceUIWindow_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            InsertMenus,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            InsertMenus: 0,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            SetMenu,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            SetMenu: 0,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            RemoveMenus,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            RemoveMenus: 0,
+            SetStatusText,
+            EnableModeless,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            TranslateAccelerator,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            TranslateAccelerator: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleInPlaceFrame as windows_core::Interface>::IID || <IOleInPlaceUIWindow_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOleInPlaceObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InPlaceDeactivate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceObject", "InPlaceDeactivate", 5usize, |this| ((**(this as *const *const IOleInPlaceObject_Vtbl)).InPlaceDeactivate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn UIDeactivate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceObject", "UIDeactivate", 6usize, |this| ((**(this as *const *const IOleInPlaceObject_Vtbl)).UIDeactivate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetObjectRects(this: *mut core::ffi::c_void, lprcposrect: *const super::super::Foundation::RECT, lprccliprect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceObject", "SetObjectRects", 7usize, |this| ((**(this as *const *const IOleInPlaceObject_Vtbl)).SetObjectRects)(this, lprcposrect, lprccliprect), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReactivateAndUndo(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceObject", "ReactivateAndUndo", 8usize, |this| ((**(this as *const *const IOleInPlaceObject_Vtbl)).ReactivateAndUndo)(this), |result| Some(*result))
+        }
+        Self { base__: <IOleWindow_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InPlaceDeactivate, UIDeactivate, SetObjectRects, ReactivateAndUndo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleInPlaceObject as windows_core::Interface>::IID || <IOleWindow_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOleInPlaceObjectWindowless_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnWindowMessage(this: *mut core::ffi::c_void, msg: u32, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM, plresult: *mut super::super::Foundation::LRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceObjectWindowless", "OnWindowMessage", 9usize, |this| ((**(this as *const *const IOleInPlaceObjectWindowless_Vtbl)).OnWindowMessage)(this, msg, wparam, lparam, plresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDropTarget(this: *mut core::ffi::c_void, ppdroptarget: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceObjectWindowless", "GetDropTarget", 10usize, |this| ((**(this as *const *const IOleInPlaceObjectWindowless_Vtbl)).GetDropTarget)(this, ppdroptarget), |result| Some(*result))
+        }
+        Self { base__: <IOleInPlaceObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnWindowMessage, GetDropTarget }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleInPlaceObjectWindowless as windows_core::Interface>::IID || <IOleInPlaceObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl windows_core::imp::ProxyVtable for IOleInPlaceSite_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CanInPlaceActivate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceSite", "CanInPlaceActivate", 5usize, |this| ((**(this as *const *const IOleInPlaceSite_Vtbl)).CanInPlaceActivate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnInPlaceActivate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceSite", "OnInPlaceActivate", 6usize, |this| ((**(this as *const *const IOleInPlaceSite_Vtbl)).OnInPlaceActivate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnUIActivate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceSite", "OnUIActivate", 7usize, |this| ((**(this as *const *const IOleInPlaceSite_Vtbl)).OnUIActivate)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn GetWindowContext(this: *mut core::ffi::c_void, ppframe: *mut *mut core::ffi::c_void, ppdoc: *mut *mut core::ffi::c_void, lprcposrect: *mut super::super::Foundation::RECT, lprccliprect: *mut super::super::Foundation::RECT, lpframeinfo: *mut OLEINPLACEFRAMEINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceSite", "GetWindowContext", 8usize, |this| ((**(this as *const *const IOleInPlaceSite_Vtbl)).GetWindowContext)(this, ppframe, ppdoc, lprcposrect, lprccliprect, lpframeinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn Scroll(this: *mut core::ffi::c_void, scrollextant: super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceSite", "Scroll", 9usize, |this| ((**(this as *const *const IOleInPlaceSite_Vtbl)).Scroll)(this, scrollextant), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnUIDeactivate(this: *mut core::ffi::c_void, fundoable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceSite", "OnUIDeactivate", 10usize, |this| ((**(this as *const *const IOleInPlaceSite_Vtbl)).OnUIDeactivate)(this, fundoable), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnInPlaceDeactivate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceSite", "OnInPlaceDeactivate", 11usize, |this| ((**(this as *const *const IOleInPlaceSite_Vtbl)).OnInPlaceDeactivate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DiscardUndoState(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceSite", "DiscardUndoState", 12usize, |this| ((**(this as *const *const IOleInPlaceSite_Vtbl)).DiscardUndoState)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeactivateAndUndo(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceSite", "DeactivateAndUndo", 13usize, |this| ((**(this as *const *const IOleInPlaceSite_Vtbl)).DeactivateAndUndo)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnPosRectChange(this: *mut core::ffi::c_void, lprcposrect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceSite", "OnPosRectChange", 14usize, |this| ((**(this as *const *const IOleInPlaceSite_Vtbl)).OnPosRectChange)(this, lprcposrect), |result| Some(*result))
+        }
+        Self {
+            base__: <IOleWindow_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CanInPlaceActivate,
+            OnInPlaceActivate,
+            OnUIActivate,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            GetWindowContext,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            GetWindowContext: 0,
+            Scroll,
+            OnUIDeactivate,
+            OnInPlaceDeactivate,
+            DiscardUndoState,
+            DeactivateAndUndo,
+            OnPosRectChange,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleInPlaceSite as windows_core::Interface>::IID || <IOleWindow_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl windows_core::imp::ProxyVtable for IOleInPlaceSiteEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnInPlaceActivateEx(this: *mut core::ffi::c_void, pfnoredraw: *mut super::super::Foundation::BOOL, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceSiteEx", "OnInPlaceActivateEx", 15usize, |this| ((**(this as *const *const IOleInPlaceSiteEx_Vtbl)).OnInPlaceActivateEx)(this, pfnoredraw, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnInPlaceDeactivateEx(this: *mut core::ffi::c_void, fnoredraw: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceSiteEx", "OnInPlaceDeactivateEx", 16usize, |this| ((**(this as *const *const IOleInPlaceSiteEx_Vtbl)).OnInPlaceDeactivateEx)(this, fnoredraw), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestUIActivate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceSiteEx", "RequestUIActivate", 17usize, |this| ((**(this as *const *const IOleInPlaceSiteEx_Vtbl)).RequestUIActivate)(this), |result| Some(*result))
+        }
+        Self { base__: <IOleInPlaceSite_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnInPlaceActivateEx, OnInPlaceDeactivateEx, RequestUIActivate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleInPlaceSiteEx as windows_core::Interface>::IID || <IOleInPlaceSite_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+impl windows_core::imp::ProxyVtable for IOleInPlaceSiteWindowless_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CanWindowlessActivate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceSiteWindowless", "CanWindowlessActivate", 18usize, |this| ((**(this as *const *const IOleInPlaceSiteWindowless_Vtbl)).CanWindowlessActivate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCapture(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceSiteWindowless", "GetCapture", 19usize, |this| ((**(this as *const *const IOleInPlaceSiteWindowless_Vtbl)).GetCapture)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCapture(this: *mut core::ffi::c_void, fcapture: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceSiteWindowless", "SetCapture", 20usize, |this| ((**(this as *const *const IOleInPlaceSiteWindowless_Vtbl)).SetCapture)(this, fcapture), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFocus(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceSiteWindowless", "GetFocus", 21usize, |this| ((**(this as *const *const IOleInPlaceSiteWindowless_Vtbl)).GetFocus)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFocus(this: *mut core::ffi::c_void, ffocus: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceSiteWindowless", "SetFocus", 22usize, |this| ((**(this as *const *const IOleInPlaceSiteWindowless_Vtbl)).SetFocus)(this, ffocus), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetDC(this: *mut core::ffi::c_void, prect: *const super::super::Foundation::RECT, grfflags: u32, phdc: *mut super::super::Graphics::Gdi::HDC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceSiteWindowless", "GetDC", 23usize, |this| ((**(this as *const *const IOleInPlaceSiteWindowless_Vtbl)).GetDC)(this, prect, grfflags, phdc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn ReleaseDC(this: *mut core::ffi::c_void, hdc: super::super::Graphics::Gdi::HDC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceSiteWindowless", "ReleaseDC", 24usize, |this| ((**(this as *const *const IOleInPlaceSiteWindowless_Vtbl)).ReleaseDC)(this, hdc), |result| Some(*result))
+        }
+        unsafe extern "system" fn InvalidateRect(this: *mut core::ffi::c_void, prect: *const super::super::Foundation::RECT, ferase: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceSiteWindowless", "InvalidateRect", 25usize, |this| ((**(this as *const *const IOleInPlaceSiteWindowless_Vtbl)).InvalidateRect)(this, prect, ferase), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn InvalidateRgn(this: *mut core::ffi::c_void, hrgn: super::super::Graphics::Gdi::HRGN, ferase: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceSiteWindowless", "InvalidateRgn", 26usize, |this| ((**(this as *const *const IOleInPlaceSiteWindowless_Vtbl)).InvalidateRgn)(this, hrgn, ferase), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScrollRect(this: *mut core::ffi::c_void, dx: i32, dy: i32, prectscroll: *const super::super::Foundation::RECT, prectclip: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceSiteWindowless", "ScrollRect", 27usize, |this| ((**(this as *const *const IOleInPlaceSiteWindowless_Vtbl)).ScrollRect)(this, dx, dy, prectscroll, prectclip), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdjustRect(this: *mut core::ffi::c_void, prc: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceSiteWindowless", "AdjustRect", 28usize, |this| ((**(this as *const *const IOleInPlaceSiteWindowless_Vtbl)).AdjustRect)(this, prc), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDefWindowMessage(this: *mut core::ffi::c_void, msg: u32, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM, plresult: *mut super::super::Foundation::LRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceSiteWindowless", "OnDefWindowMessage", 29usize, |this| ((**(this as *const *const IOleInPlaceSiteWindowless_Vtbl)).OnDefWindowMessage)(this, msg, wparam, lparam, plresult), |result| Some(*result))
+        }
+        Self {
+            base__: <IOleInPlaceSiteEx_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CanWindowlessActivate,
+            GetCapture,
+            SetCapture,
+            GetFocus,
+            SetFocus,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetDC,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetDC: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            ReleaseDC,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            ReleaseDC: 0,
+            InvalidateRect,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            InvalidateRgn,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            InvalidateRgn: 0,
+            ScrollRect,
+            AdjustRect,
+            OnDefWindowMessage,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleInPlaceSiteWindowless as windows_core::Interface>::IID || <IOleInPlaceSiteEx_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOleInPlaceUIWindow_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBorder(this: *mut core::ffi::c_void, lprectborder: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceUIWindow", "GetBorder", 5usize, |this| ((**(this as *const *const IOleInPlaceUIWindow_Vtbl)).GetBorder)(this, lprectborder), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestBorderSpace(this: *mut core::ffi::c_void, pborderwidths: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceUIWindow", "RequestBorderSpace", 6usize, |this| ((**(this as *const *const IOleInPlaceUIWindow_Vtbl)).RequestBorderSpace)(this, pborderwidths), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBorderSpace(this: *mut core::ffi::c_void, pborderwidths: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceUIWindow", "SetBorderSpace", 7usize, |this| ((**(this as *const *const IOleInPlaceUIWindow_Vtbl)).SetBorderSpace)(this, pborderwidths), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActiveObject(this: *mut core::ffi::c_void, pactiveobject: *mut core::ffi::c_void, pszobjname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleInPlaceUIWindow", "SetActiveObject", 8usize, |this| ((**(this as *const *const IOleInPlaceUIWindow_Vtbl)).SetActiveObject)(this, pactiveobject, pszobjname), |result| Some(*result))
+        }
+        Self { base__: <IOleWindow_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetBorder, RequestBorderSpace, SetBorderSpace, SetActiveObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleInPlaceUIWindow as windows_core::Interface>::IID || <IOleWindow_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOleItemContainer_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetObject(this: *mut core::ffi::c_void, pszitem: windows_core::PCWSTR, dwspeedneeded: u32, pbc: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleItemContainer", "GetObject", 6usize, |this| ((**(this as *const *const IOleItemContainer_Vtbl)).GetObject)(this, pszitem, dwspeedneeded, pbc, riid, ppvobject), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetObjectStorage(this: *mut core::ffi::c_void, pszitem: windows_core::PCWSTR, pbc: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvstorage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleItemContainer", "GetObjectStorage", 7usize, |this| ((**(this as *const *const IOleItemContainer_Vtbl)).GetObjectStorage)(this, pszitem, pbc, riid, ppvstorage), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRunning(this: *mut core::ffi::c_void, pszitem: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleItemContainer", "IsRunning", 8usize, |this| ((**(this as *const *const IOleItemContainer_Vtbl)).IsRunning)(this, pszitem), |result| Some(*result))
+        }
+        Self {
+            base__: <IOleContainer_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetObject: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetObjectStorage,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetObjectStorage: 0,
+            IsRunning,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleItemContainer as windows_core::Interface>::IID || <IOleContainer_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOleLink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetUpdateOptions(this: *mut core::ffi::c_void, dwupdateopt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleLink", "SetUpdateOptions", 3usize, |this| ((**(this as *const *const IOleLink_Vtbl)).SetUpdateOptions)(this, dwupdateopt), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUpdateOptions(this: *mut core::ffi::c_void, pdwupdateopt: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleLink", "GetUpdateOptions", 4usize, |this| ((**(this as *const *const IOleLink_Vtbl)).GetUpdateOptions)(this, pdwupdateopt), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSourceMoniker(this: *mut core::ffi::c_void, pmk: *mut core::ffi::c_void, rclsid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleLink", "SetSourceMoniker", 5usize, |this| ((**(this as *const *const IOleLink_Vtbl)).SetSourceMoniker)(this, pmk, rclsid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSourceMoniker(this: *mut core::ffi::c_void, ppmk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleLink", "GetSourceMoniker", 6usize, |this| ((**(this as *const *const IOleLink_Vtbl)).GetSourceMoniker)(this, ppmk), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSourceDisplayName(this: *mut core::ffi::c_void, pszstatustext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleLink", "SetSourceDisplayName", 7usize, |this| ((**(this as *const *const IOleLink_Vtbl)).SetSourceDisplayName)(this, pszstatustext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourceDisplayName(this: *mut core::ffi::c_void, ppszdisplayname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleLink", "GetSourceDisplayName", 8usize, |this| ((**(this as *const *const IOleLink_Vtbl)).GetSourceDisplayName)(this, ppszdisplayname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn BindToSource(this: *mut core::ffi::c_void, bindflags: u32, pbc: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleLink", "BindToSource", 9usize, |this| ((**(this as *const *const IOleLink_Vtbl)).BindToSource)(this, bindflags, pbc), |result| Some(*result))
+        }
+        unsafe extern "system" fn BindIfRunning(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleLink", "BindIfRunning", 10usize, |this| ((**(this as *const *const IOleLink_Vtbl)).BindIfRunning)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBoundSource(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleLink", "GetBoundSource", 11usize, |this| ((**(this as *const *const IOleLink_Vtbl)).GetBoundSource)(this, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnbindSource(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleLink", "UnbindSource", 12usize, |this| ((**(this as *const *const IOleLink_Vtbl)).UnbindSource)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void, pbc: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleLink", "Update", 13usize, |this| ((**(this as *const *const IOleLink_Vtbl)).Update)(this, pbc), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetUpdateOptions,
+            GetUpdateOptions,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSourceMoniker,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSourceMoniker: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSourceMoniker,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSourceMoniker: 0,
+            SetSourceDisplayName,
+            GetSourceDisplayName,
+            #[cfg(feature = "Win32_System_Com")]
+            BindToSource,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            BindToSource: 0,
+            BindIfRunning,
+            GetBoundSource,
+            UnbindSource,
+            #[cfg(feature = "Win32_System_Com")]
+            Update,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Update: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleLink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com", feature = "Win32_UI_WindowsAndMessaging"))]
+impl windows_core::imp::ProxyVtable for IOleObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetClientSite(this: *mut core::ffi::c_void, pclientsite: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleObject", "SetClientSite", 3usize, |this| ((**(this as *const *const IOleObject_Vtbl)).SetClientSite)(this, pclientsite), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClientSite(this: *mut core::ffi::c_void, ppclientsite: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleObject", "GetClientSite", 4usize, |this| ((**(this as *const *const IOleObject_Vtbl)).GetClientSite)(this, ppclientsite), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHostNames(this: *mut core::ffi::c_void, szcontainerapp: windows_core::PCWSTR, szcontainerobj: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleObject", "SetHostNames", 5usize, |this| ((**(this as *const *const IOleObject_Vtbl)).SetHostNames)(this, szcontainerapp, szcontainerobj), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void, dwsaveoption: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleObject", "Close", 6usize, |this| ((**(this as *const *const IOleObject_Vtbl)).Close)(this, dwsaveoption), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetMoniker(this: *mut core::ffi::c_void, dwwhichmoniker: u32, pmk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleObject", "SetMoniker", 7usize, |this| ((**(this as *const *const IOleObject_Vtbl)).SetMoniker)(this, dwwhichmoniker, pmk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetMoniker(this: *mut core::ffi::c_void, dwassign: u32, dwwhichmoniker: u32, ppmk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleObject", "GetMoniker", 8usize, |this| ((**(this as *const *const IOleObject_Vtbl)).GetMoniker)(this, dwassign, dwwhichmoniker, ppmk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InitFromData(this: *mut core::ffi::c_void, pdataobject: *mut core::ffi::c_void, fcreation: super::super::Foundation::BOOL, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleObject", "InitFromData", 9usize, |this| ((**(this as *const *const IOleObject_Vtbl)).InitFromData)(this, pdataobject, fcreation, dwreserved), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetClipboardData(this: *mut core::ffi::c_void, dwreserved: u32, ppdataobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleObject", "GetClipboardData", 10usize, |this| ((**(this as *const *const IOleObject_Vtbl)).GetClipboardData)(this, dwreserved, ppdataobject), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn DoVerb(this: *mut core::ffi::c_void, iverb: i32, lpmsg: *const super::super::UI::WindowsAndMessaging::MSG, pactivesite: *mut core::ffi::c_void, lindex: i32, hwndparent: super::super::Foundation::HWND, lprcposrect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleObject", "DoVerb", 11usize, |this| ((**(this as *const *const IOleObject_Vtbl)).DoVerb)(this, iverb, lpmsg, pactivesite, lindex, hwndparent, lprcposrect), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumVerbs(this: *mut core::ffi::c_void, ppenumoleverb: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleObject", "EnumVerbs", 12usize, |this| ((**(this as *const *const IOleObject_Vtbl)).EnumVerbs)(this, ppenumoleverb), |result| Some(*result))
+        }
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleObject", "Update", 13usize, |this| ((**(this as *const *const IOleObject_Vtbl)).Update)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUpToDate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleObject", "IsUpToDate", 14usize, |this| ((**(this as *const *const IOleObject_Vtbl)).IsUpToDate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUserClassID(this: *mut core::ffi::c_void, pclsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleObject", "GetUserClassID", 15usize, |this| ((**(this as *const *const IOleObject_Vtbl)).GetUserClassID)(this, pclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUserType(this: *mut core::ffi::c_void, dwformoftype: u32, pszusertype: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleObject", "GetUserType", 16usize, |this| ((**(this as *const *const IOleObject_Vtbl)).GetUserType)(this, dwformoftype, pszusertype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetExtent(this: *mut core::ffi::c_void, dwdrawaspect: super::Com::DVASPECT, psizel: *const super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleObject", "SetExtent", 17usize, |this| ((**(this as *const *const IOleObject_Vtbl)).SetExtent)(this, dwdrawaspect, psizel), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetExtent(this: *mut core::ffi::c_void, dwdrawaspect: super::Com::DVASPECT, psizel: *mut super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleObject", "GetExtent", 18usize, |this| ((**(this as *const *const IOleObject_Vtbl)).GetExtent)(this, dwdrawaspect, psizel), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Advise(this: *mut core::ffi::c_void, padvsink: *mut core::ffi::c_void, pdwconnection: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleObject", "Advise", 19usize, |this| ((**(this as *const *const IOleObject_Vtbl)).Advise)(this, padvsink, pdwconnection), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unadvise(this: *mut core::ffi::c_void, dwconnection: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleObject", "Unadvise", 20usize, |this| ((**(this as *const *const IOleObject_Vtbl)).Unadvise)(this, dwconnection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumAdvise(this: *mut core::ffi::c_void, ppenumadvise: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleObject", "EnumAdvise", 21usize, |this| ((**(this as *const *const IOleObject_Vtbl)).EnumAdvise)(this, ppenumadvise), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetMiscStatus(this: *mut core::ffi::c_void, dwaspect: super::Com::DVASPECT, pdwstatus: *mut OLEMISC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleObject", "GetMiscStatus", 22usize, |this| ((**(this as *const *const IOleObject_Vtbl)).GetMiscStatus)(this, dwaspect, pdwstatus), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn SetColorScheme(this: *mut core::ffi::c_void, plogpal: *const super::super::Graphics::Gdi::LOGPALETTE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleObject", "SetColorScheme", 23usize, |this| ((**(this as *const *const IOleObject_Vtbl)).SetColorScheme)(this, plogpal), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetClientSite,
+            GetClientSite,
+            SetHostNames,
+            Close,
+            #[cfg(feature = "Win32_System_Com")]
+            SetMoniker,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetMoniker: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetMoniker,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetMoniker: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            InitFromData,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InitFromData: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetClipboardData,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetClipboardData: 0,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            DoVerb,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            DoVerb: 0,
+            EnumVerbs,
+            Update,
+            IsUpToDate,
+            GetUserClassID,
+            GetUserType,
+            #[cfg(feature = "Win32_System_Com")]
+            SetExtent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetExtent: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetExtent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetExtent: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Advise,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Advise: 0,
+            Unadvise,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumAdvise,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumAdvise: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetMiscStatus,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetMiscStatus: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            SetColorScheme,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            SetColorScheme: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOleParentUndoUnit_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, ppuu: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleParentUndoUnit", "Open", 7usize, |this| ((**(this as *const *const IOleParentUndoUnit_Vtbl)).Open)(this, ppuu), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void, ppuu: *mut core::ffi::c_void, fcommit: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleParentUndoUnit", "Close", 8usize, |this| ((**(this as *const *const IOleParentUndoUnit_Vtbl)).Close)(this, ppuu, fcommit), |result| Some(*result))
+        }
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, puu: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleParentUndoUnit", "Add", 9usize, |this| ((**(this as *const *const IOleParentUndoUnit_Vtbl)).Add)(this, puu), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindUnit(this: *mut core::ffi::c_void, puu: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleParentUndoUnit", "FindUnit", 10usize, |this| ((**(this as *const *const IOleParentUndoUnit_Vtbl)).FindUnit)(this, puu), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParentState(this: *mut core::ffi::c_void, pdwstate: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleParentUndoUnit", "GetParentState", 11usize, |this| ((**(this as *const *const IOleParentUndoUnit_Vtbl)).GetParentState)(this, pdwstate), |result| Some(*result))
+        }
+        Self { base__: <IOleUndoUnit_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Open, Close, Add, FindUnit, GetParentState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleParentUndoUnit as windows_core::Interface>::IID || <IOleUndoUnit_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOleUILinkContainerA_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNextLink(this: *mut core::ffi::c_void, dwlink: u32) -> u32 {
+            windows_core::imp::proxy_call(this, "IOleUILinkContainerA", "GetNextLink", 3usize, |this| ((**(this as *const *const IOleUILinkContainerA_Vtbl)).GetNextLink)(this, dwlink), |_| None)
+        }
+        unsafe extern "system" fn SetLinkUpdateOptions(this: *mut core::ffi::c_void, dwlink: u32, dwupdateopt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUILinkContainerA", "SetLinkUpdateOptions", 4usize, |this| ((**(this as *const *const IOleUILinkContainerA_Vtbl)).SetLinkUpdateOptions)(this, dwlink, dwupdateopt), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLinkUpdateOptions(this: *mut core::ffi::c_void, dwlink: u32, lpdwupdateopt: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUILinkContainerA", "GetLinkUpdateOptions", 5usize, |this| ((**(this as *const *const IOleUILinkContainerA_Vtbl)).GetLinkUpdateOptions)(this, dwlink, lpdwupdateopt), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLinkSource(this: *mut core::ffi::c_void, dwlink: u32, lpszdisplayname: windows_core::PCSTR, lenfilename: u32, pcheaten: *mut u32, fvalidatesource: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUILinkContainerA", "SetLinkSource", 6usize, |this| ((**(this as *const *const IOleUILinkContainerA_Vtbl)).SetLinkSource)(this, dwlink, lpszdisplayname, lenfilename, pcheaten, fvalidatesource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLinkSource(this: *mut core::ffi::c_void, dwlink: u32, lplpszdisplayname: *mut windows_core::PSTR, lplenfilename: *mut u32, lplpszfulllinktype: *mut windows_core::PSTR, lplpszshortlinktype: *mut windows_core::PSTR, lpfsourceavailable: *mut super::super::Foundation::BOOL, lpfisselected: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUILinkContainerA", "GetLinkSource", 7usize, |this| ((**(this as *const *const IOleUILinkContainerA_Vtbl)).GetLinkSource)(this, dwlink, lplpszdisplayname, lplenfilename, lplpszfulllinktype, lplpszshortlinktype, lpfsourceavailable, lpfisselected), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenLinkSource(this: *mut core::ffi::c_void, dwlink: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUILinkContainerA", "OpenLinkSource", 8usize, |this| ((**(this as *const *const IOleUILinkContainerA_Vtbl)).OpenLinkSource)(this, dwlink), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateLink(this: *mut core::ffi::c_void, dwlink: u32, ferrormessage: super::super::Foundation::BOOL, freserved: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUILinkContainerA", "UpdateLink", 9usize, |this| ((**(this as *const *const IOleUILinkContainerA_Vtbl)).UpdateLink)(this, dwlink, ferrormessage, freserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelLink(this: *mut core::ffi::c_void, dwlink: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUILinkContainerA", "CancelLink", 10usize, |this| ((**(this as *const *const IOleUILinkContainerA_Vtbl)).CancelLink)(this, dwlink), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetNextLink,
+            SetLinkUpdateOptions,
+            GetLinkUpdateOptions,
+            SetLinkSource,
+            GetLinkSource,
+            OpenLinkSource,
+            UpdateLink,
+            CancelLink,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleUILinkContainerA as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOleUILinkContainerW_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNextLink(this: *mut core::ffi::c_void, dwlink: u32) -> u32 {
+            windows_core::imp::proxy_call(this, "IOleUILinkContainerW", "GetNextLink", 3usize, |this| ((**(this as *const *const IOleUILinkContainerW_Vtbl)).GetNextLink)(this, dwlink), |_| None)
+        }
+        unsafe extern "system" fn SetLinkUpdateOptions(this: *mut core::ffi::c_void, dwlink: u32, dwupdateopt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUILinkContainerW", "SetLinkUpdateOptions", 4usize, |this| ((**(this as *const *const IOleUILinkContainerW_Vtbl)).SetLinkUpdateOptions)(this, dwlink, dwupdateopt), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLinkUpdateOptions(this: *mut core::ffi::c_void, dwlink: u32, lpdwupdateopt: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUILinkContainerW", "GetLinkUpdateOptions", 5usize, |this| ((**(this as *const *const IOleUILinkContainerW_Vtbl)).GetLinkUpdateOptions)(this, dwlink, lpdwupdateopt), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLinkSource(this: *mut core::ffi::c_void, dwlink: u32, lpszdisplayname: windows_core::PCWSTR, lenfilename: u32, pcheaten: *mut u32, fvalidatesource: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUILinkContainerW", "SetLinkSource", 6usize, |this| ((**(this as *const *const IOleUILinkContainerW_Vtbl)).SetLinkSource)(this, dwlink, lpszdisplayname, lenfilename, pcheaten, fvalidatesource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLinkSource(this: *mut core::ffi::c_void, dwlink: u32, lplpszdisplayname: *mut windows_core::PWSTR, lplenfilename: *mut u32, lplpszfulllinktype: *mut windows_core::PWSTR, lplpszshortlinktype: *mut windows_core::PWSTR, lpfsourceavailable: *mut super::super::Foundation::BOOL, lpfisselected: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUILinkContainerW", "GetLinkSource", 7usize, |this| ((**(this as *const *const IOleUILinkContainerW_Vtbl)).GetLinkSource)(this, dwlink, lplpszdisplayname, lplenfilename, lplpszfulllinktype, lplpszshortlinktype, lpfsourceavailable, lpfisselected), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenLinkSource(this: *mut core::ffi::c_void, dwlink: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUILinkContainerW", "OpenLinkSource", 8usize, |this| ((**(this as *const *const IOleUILinkContainerW_Vtbl)).OpenLinkSource)(this, dwlink), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateLink(this: *mut core::ffi::c_void, dwlink: u32, ferrormessage: super::super::Foundation::BOOL, freserved: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUILinkContainerW", "UpdateLink", 9usize, |this| ((**(this as *const *const IOleUILinkContainerW_Vtbl)).UpdateLink)(this, dwlink, ferrormessage, freserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelLink(this: *mut core::ffi::c_void, dwlink: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUILinkContainerW", "CancelLink", 10usize, |this| ((**(this as *const *const IOleUILinkContainerW_Vtbl)).CancelLink)(this, dwlink), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetNextLink,
+            SetLinkUpdateOptions,
+            GetLinkUpdateOptions,
+            SetLinkSource,
+            GetLinkSource,
+            OpenLinkSource,
+            UpdateLink,
+            CancelLink,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleUILinkContainerW as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOleUILinkInfoA_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLastUpdate(this: *mut core::ffi::c_void, dwlink: u32, lplastupdate: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUILinkInfoA", "GetLastUpdate", 11usize, |this| ((**(this as *const *const IOleUILinkInfoA_Vtbl)).GetLastUpdate)(this, dwlink, lplastupdate), |result| Some(*result))
+        }
+        Self { base__: <IOleUILinkContainerA_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetLastUpdate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleUILinkInfoA as windows_core::Interface>::IID || <IOleUILinkContainerA_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOleUILinkInfoW_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLastUpdate(this: *mut core::ffi::c_void, dwlink: u32, lplastupdate: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUILinkInfoW", "GetLastUpdate", 11usize, |this| ((**(this as *const *const IOleUILinkInfoW_Vtbl)).GetLastUpdate)(this, dwlink, lplastupdate), |result| Some(*result))
+        }
+        Self { base__: <IOleUILinkContainerW_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetLastUpdate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleUILinkInfoW as windows_core::Interface>::IID || <IOleUILinkContainerW_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOleUIObjInfoA_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetObjectInfo(this: *mut core::ffi::c_void, dwobject: u32, lpdwobjsize: *mut u32, lplpszlabel: *mut windows_core::PSTR, lplpsztype: *mut windows_core::PSTR, lplpszshorttype: *mut windows_core::PSTR, lplpszlocation: *mut windows_core::PSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUIObjInfoA", "GetObjectInfo", 3usize, |this| ((**(this as *const *const IOleUIObjInfoA_Vtbl)).GetObjectInfo)(this, dwobject, lpdwobjsize, lplpszlabel, lplpsztype, lplpszshorttype, lplpszlocation), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConvertInfo(this: *mut core::ffi::c_void, dwobject: u32, lpclassid: *mut windows_core::GUID, lpwformat: *mut u16, lpconvertdefaultclassid: *mut windows_core::GUID, lplpclsidexclude: *mut *mut windows_core::GUID, lpcclsidexclude: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUIObjInfoA", "GetConvertInfo", 4usize, |this| ((**(this as *const *const IOleUIObjInfoA_Vtbl)).GetConvertInfo)(this, dwobject, lpclassid, lpwformat, lpconvertdefaultclassid, lplpclsidexclude, lpcclsidexclude), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertObject(this: *mut core::ffi::c_void, dwobject: u32, clsidnew: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUIObjInfoA", "ConvertObject", 5usize, |this| ((**(this as *const *const IOleUIObjInfoA_Vtbl)).ConvertObject)(this, dwobject, clsidnew), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetViewInfo(this: *mut core::ffi::c_void, dwobject: u32, phmetapict: *const super::super::Foundation::HGLOBAL, pdvaspect: *const u32, pncurrentscale: *const i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUIObjInfoA", "GetViewInfo", 6usize, |this| ((**(this as *const *const IOleUIObjInfoA_Vtbl)).GetViewInfo)(this, dwobject, phmetapict, pdvaspect, pncurrentscale), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetViewInfo(this: *mut core::ffi::c_void, dwobject: u32, hmetapict: super::super::Foundation::HGLOBAL, dvaspect: u32, ncurrentscale: i32, brelativetoorig: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUIObjInfoA", "SetViewInfo", 7usize, |this| ((**(this as *const *const IOleUIObjInfoA_Vtbl)).SetViewInfo)(this, dwobject, hmetapict, dvaspect, ncurrentscale, brelativetoorig), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetObjectInfo,
+            GetConvertInfo,
+            ConvertObject,
+            GetViewInfo,
+            SetViewInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleUIObjInfoA as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOleUIObjInfoW_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetObjectInfo(this: *mut core::ffi::c_void, dwobject: u32, lpdwobjsize: *mut u32, lplpszlabel: *mut windows_core::PWSTR, lplpsztype: *mut windows_core::PWSTR, lplpszshorttype: *mut windows_core::PWSTR, lplpszlocation: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUIObjInfoW", "GetObjectInfo", 3usize, |this| ((**(this as *const *const IOleUIObjInfoW_Vtbl)).GetObjectInfo)(this, dwobject, lpdwobjsize, lplpszlabel, lplpsztype, lplpszshorttype, lplpszlocation), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConvertInfo(this: *mut core::ffi::c_void, dwobject: u32, lpclassid: *mut windows_core::GUID, lpwformat: *mut u16, lpconvertdefaultclassid: *mut windows_core::GUID, lplpclsidexclude: *mut *mut windows_core::GUID, lpcclsidexclude: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUIObjInfoW", "GetConvertInfo", 4usize, |this| ((**(this as *const *const IOleUIObjInfoW_Vtbl)).GetConvertInfo)(this, dwobject, lpclassid, lpwformat, lpconvertdefaultclassid, lplpclsidexclude, lpcclsidexclude), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertObject(this: *mut core::ffi::c_void, dwobject: u32, clsidnew: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUIObjInfoW", "ConvertObject", 5usize, |this| ((**(this as *const *const IOleUIObjInfoW_Vtbl)).ConvertObject)(this, dwobject, clsidnew), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetViewInfo(this: *mut core::ffi::c_void, dwobject: u32, phmetapict: *const super::super::Foundation::HGLOBAL, pdvaspect: *const u32, pncurrentscale: *const i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUIObjInfoW", "GetViewInfo", 6usize, |this| ((**(this as *const *const IOleUIObjInfoW_Vtbl)).GetViewInfo)(this, dwobject, phmetapict, pdvaspect, pncurrentscale), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetViewInfo(this: *mut core::ffi::c_void, dwobject: u32, hmetapict: super::super::Foundation::HGLOBAL, dvaspect: u32, ncurrentscale: i32, brelativetoorig: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUIObjInfoW", "SetViewInfo", 7usize, |this| ((**(this as *const *const IOleUIObjInfoW_Vtbl)).SetViewInfo)(this, dwobject, hmetapict, dvaspect, ncurrentscale, brelativetoorig), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetObjectInfo,
+            GetConvertInfo,
+            ConvertObject,
+            GetViewInfo,
+            SetViewInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleUIObjInfoW as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOleUndoManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, ppuu: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUndoManager", "Open", 3usize, |this| ((**(this as *const *const IOleUndoManager_Vtbl)).Open)(this, ppuu), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void, ppuu: *mut core::ffi::c_void, fcommit: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUndoManager", "Close", 4usize, |this| ((**(this as *const *const IOleUndoManager_Vtbl)).Close)(this, ppuu, fcommit), |result| Some(*result))
+        }
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, puu: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUndoManager", "Add", 5usize, |this| ((**(this as *const *const IOleUndoManager_Vtbl)).Add)(this, puu), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOpenParentState(this: *mut core::ffi::c_void, pdwstate: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUndoManager", "GetOpenParentState", 6usize, |this| ((**(this as *const *const IOleUndoManager_Vtbl)).GetOpenParentState)(this, pdwstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn DiscardFrom(this: *mut core::ffi::c_void, puu: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUndoManager", "DiscardFrom", 7usize, |this| ((**(this as *const *const IOleUndoManager_Vtbl)).DiscardFrom)(this, puu), |result| Some(*result))
+        }
+        unsafe extern "system" fn UndoTo(this: *mut core::ffi::c_void, puu: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUndoManager", "UndoTo", 8usize, |this| ((**(this as *const *const IOleUndoManager_Vtbl)).UndoTo)(this, puu), |result| Some(*result))
+        }
+        unsafe extern "system" fn RedoTo(this: *mut core::ffi::c_void, puu: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUndoManager", "RedoTo", 9usize, |this| ((**(this as *const *const IOleUndoManager_Vtbl)).RedoTo)(this, puu), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumUndoable(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUndoManager", "EnumUndoable", 10usize, |this| ((**(this as *const *const IOleUndoManager_Vtbl)).EnumUndoable)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumRedoable(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUndoManager", "EnumRedoable", 11usize, |this| ((**(this as *const *const IOleUndoManager_Vtbl)).EnumRedoable)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastUndoDescription(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUndoManager", "GetLastUndoDescription", 12usize, |this| ((**(this as *const *const IOleUndoManager_Vtbl)).GetLastUndoDescription)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastRedoDescription(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUndoManager", "GetLastRedoDescription", 13usize, |this| ((**(this as *const *const IOleUndoManager_Vtbl)).GetLastRedoDescription)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enable(this: *mut core::ffi::c_void, fenable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUndoManager", "Enable", 14usize, |this| ((**(this as *const *const IOleUndoManager_Vtbl)).Enable)(this, fenable), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Open,
+            Close,
+            Add,
+            GetOpenParentState,
+            DiscardFrom,
+            UndoTo,
+            RedoTo,
+            EnumUndoable,
+            EnumRedoable,
+            GetLastUndoDescription,
+            GetLastRedoDescription,
+            Enable,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleUndoManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOleUndoUnit_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Do(this: *mut core::ffi::c_void, pundomanager: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUndoUnit", "Do", 3usize, |this| ((**(this as *const *const IOleUndoUnit_Vtbl)).Do)(this, pundomanager), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescription(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUndoUnit", "GetDescription", 4usize, |this| ((**(this as *const *const IOleUndoUnit_Vtbl)).GetDescription)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUnitType(this: *mut core::ffi::c_void, pclsid: *mut windows_core::GUID, plid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUndoUnit", "GetUnitType", 5usize, |this| ((**(this as *const *const IOleUndoUnit_Vtbl)).GetUnitType)(this, pclsid, plid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnNextAdd(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleUndoUnit", "OnNextAdd", 6usize, |this| ((**(this as *const *const IOleUndoUnit_Vtbl)).OnNextAdd)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Do, GetDescription, GetUnitType, OnNextAdd }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleUndoUnit as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOleWindow_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetWindow(this: *mut core::ffi::c_void, phwnd: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleWindow", "GetWindow", 3usize, |this| ((**(this as *const *const IOleWindow_Vtbl)).GetWindow)(this, phwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContextSensitiveHelp(this: *mut core::ffi::c_void, fentermode: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOleWindow", "ContextSensitiveHelp", 4usize, |this| ((**(this as *const *const IOleWindow_Vtbl)).ContextSensitiveHelp)(this, fentermode), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetWindow, ContextSensitiveHelp }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOleWindow as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IParseDisplayName_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ParseDisplayName(this: *mut core::ffi::c_void, pbc: *mut core::ffi::c_void, pszdisplayname: windows_core::PCWSTR, pcheaten: *mut u32, ppmkout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IParseDisplayName", "ParseDisplayName", 3usize, |this| ((**(this as *const *const IParseDisplayName_Vtbl)).ParseDisplayName)(this, pbc, pszdisplayname, pcheaten, ppmkout), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            ParseDisplayName,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ParseDisplayName: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IParseDisplayName as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPerPropertyBrowsing_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDisplayString(this: *mut core::ffi::c_void, dispid: i32, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerPropertyBrowsing", "GetDisplayString", 3usize, |this| ((**(this as *const *const IPerPropertyBrowsing_Vtbl)).GetDisplayString)(this, dispid, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn MapPropertyToPage(this: *mut core::ffi::c_void, dispid: i32, pclsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerPropertyBrowsing", "MapPropertyToPage", 4usize, |this| ((**(this as *const *const IPerPropertyBrowsing_Vtbl)).MapPropertyToPage)(this, dispid, pclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPredefinedStrings(this: *mut core::ffi::c_void, dispid: i32, pcastringsout: *mut CALPOLESTR, pcacookiesout: *mut CADWORD) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerPropertyBrowsing", "GetPredefinedStrings", 5usize, |this| ((**(this as *const *const IPerPropertyBrowsing_Vtbl)).GetPredefinedStrings)(this, dispid, pcastringsout, pcacookiesout), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPredefinedValue(this: *mut core::ffi::c_void, dispid: i32, dwcookie: u32, pvarout: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerPropertyBrowsing", "GetPredefinedValue", 6usize, |this| ((**(this as *const *const IPerPropertyBrowsing_Vtbl)).GetPredefinedValue)(this, dispid, dwcookie, pvarout), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDisplayString,
+            MapPropertyToPage,
+            GetPredefinedStrings,
+            GetPredefinedValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPerPropertyBrowsing as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for IPersistPropertyBag_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitNew(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistPropertyBag", "InitNew", 4usize, |this| ((**(this as *const *const IPersistPropertyBag_Vtbl)).InitNew)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn Load(this: *mut core::ffi::c_void, ppropbag: *mut core::ffi::c_void, perrorlog: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistPropertyBag", "Load", 5usize, |this| ((**(this as *const *const IPersistPropertyBag_Vtbl)).Load)(this, ppropbag, perrorlog), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void, ppropbag: *mut core::ffi::c_void, fcleardirty: super::super::Foundation::BOOL, fsaveallproperties: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistPropertyBag", "Save", 6usize, |this| ((**(this as *const *const IPersistPropertyBag_Vtbl)).Save)(this, ppropbag, fcleardirty, fsaveallproperties), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IPersist_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitNew,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            Load,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            Load: 0,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            Save,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            Save: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPersistPropertyBag as windows_core::Interface>::IID || <super::Com::IPersist_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for IPersistPropertyBag2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitNew(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistPropertyBag2", "InitNew", 4usize, |this| ((**(this as *const *const IPersistPropertyBag2_Vtbl)).InitNew)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn Load(this: *mut core::ffi::c_void, ppropbag: *mut core::ffi::c_void, perrlog: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistPropertyBag2", "Load", 5usize, |this| ((**(this as *const *const IPersistPropertyBag2_Vtbl)).Load)(this, ppropbag, perrlog), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void, ppropbag: *mut core::ffi::c_void, fcleardirty: super::super::Foundation::BOOL, fsaveallproperties: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistPropertyBag2", "Save", 6usize, |this| ((**(this as *const *const IPersistPropertyBag2_Vtbl)).Save)(this, ppropbag, fcleardirty, fsaveallproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDirty(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistPropertyBag2", "IsDirty", 7usize, |this| ((**(this as *const *const IPersistPropertyBag2_Vtbl)).IsDirty)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IPersist_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitNew,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            Load,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            Load: 0,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            Save,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            Save: 0,
+            IsDirty,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPersistPropertyBag2 as windows_core::Interface>::IID || <super::Com::IPersist_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IPicture_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handle(this: *mut core::ffi::c_void, phandle: *mut OLE_HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPicture", "Handle", 3usize, |this| ((**(this as *const *const IPicture_Vtbl)).Handle)(this, phandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn hPal(this: *mut core::ffi::c_void, phpal: *mut OLE_HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPicture", "hPal", 4usize, |this| ((**(this as *const *const IPicture_Vtbl)).hPal)(this, phpal), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, ptype: *mut PICTYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPicture", "Type", 5usize, |this| ((**(this as *const *const IPicture_Vtbl)).Type)(this, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Width(this: *mut core::ffi::c_void, pwidth: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPicture", "Width", 6usize, |this| ((**(this as *const *const IPicture_Vtbl)).Width)(this, pwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn Height(this: *mut core::ffi::c_void, pheight: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPicture", "Height", 7usize, |this| ((**(this as *const *const IPicture_Vtbl)).Height)(this, pheight), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn Render(this: *mut core::ffi::c_void, hdc: super::super::Graphics::Gdi::HDC, x: i32, y: i32, cx: i32, cy: i32, xsrc: i32, ysrc: i32, cxsrc: i32, cysrc: i32, prcwbounds: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPicture", "Render", 8usize, |this| ((**(this as *const *const IPicture_Vtbl)).Render)(this, hdc, x, y, cx, cy, xsrc, ysrc, cxsrc, cysrc, prcwbounds), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_hPal(this: *mut core::ffi::c_void, hpal: OLE_HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPicture", "set_hPal", 9usize, |this| ((**(this as *const *const IPicture_Vtbl)).set_hPal)(this, hpal), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn CurDC(this: *mut core::ffi::c_void, phdc: *mut super::super::Graphics::Gdi::HDC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPicture", "CurDC", 10usize, |this| ((**(this as *const *const IPicture_Vtbl)).CurDC)(this, phdc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn SelectPicture(this: *mut core::ffi::c_void, hdcin: super::super::Graphics::Gdi::HDC, phdcout: *mut super::super::Graphics::Gdi::HDC, phbmpout: *mut OLE_HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPicture", "SelectPicture", 11usize, |this| ((**(this as *const *const IPicture_Vtbl)).SelectPicture)(this, hdcin, phdcout, phbmpout), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeepOriginalFormat(this: *mut core::ffi::c_void, pkeep: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPicture", "KeepOriginalFormat", 12usize, |this| ((**(this as *const *const IPicture_Vtbl)).KeepOriginalFormat)(this, pkeep), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeepOriginalFormat(this: *mut core::ffi::c_void, keep: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPicture", "SetKeepOriginalFormat", 13usize, |this| ((**(this as *const *const IPicture_Vtbl)).SetKeepOriginalFormat)(this, keep), |result| Some(*result))
+        }
+        unsafe extern "system" fn PictureChanged(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPicture", "PictureChanged", 14usize, |this| ((**(this as *const *const IPicture_Vtbl)).PictureChanged)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SaveAsFile(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, fsavememcopy: super::super::Foundation::BOOL, pcbsize: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPicture", "SaveAsFile", 15usize, |this| ((**(this as *const *const IPicture_Vtbl)).SaveAsFile)(this, pstream, fsavememcopy, pcbsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn Attributes(this: *mut core::ffi::c_void, pdwattr: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPicture", "Attributes", 16usize, |this| ((**(this as *const *const IPicture_Vtbl)).Attributes)(this, pdwattr), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Handle,
+            hPal,
+            Type,
+            Width,
+            Height,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            Render,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            Render: 0,
+            set_hPal,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            CurDC,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            CurDC: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            SelectPicture,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            SelectPicture: 0,
+            KeepOriginalFormat,
+            SetKeepOriginalFormat,
+            PictureChanged,
+            #[cfg(feature = "Win32_System_Com")]
+            SaveAsFile,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SaveAsFile: 0,
+            Attributes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPicture as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IPicture2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Handle(this: *mut core::ffi::c_void, phandle: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPicture2", "Handle", 3usize, |this| ((**(this as *const *const IPicture2_Vtbl)).Handle)(this, phandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn hPal(this: *mut core::ffi::c_void, phpal: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPicture2", "hPal", 4usize, |this| ((**(this as *const *const IPicture2_Vtbl)).hPal)(this, phpal), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, ptype: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPicture2", "Type", 5usize, |this| ((**(this as *const *const IPicture2_Vtbl)).Type)(this, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Width(this: *mut core::ffi::c_void, pwidth: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPicture2", "Width", 6usize, |this| ((**(this as *const *const IPicture2_Vtbl)).Width)(this, pwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn Height(this: *mut core::ffi::c_void, pheight: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPicture2", "Height", 7usize, |this| ((**(this as *const *const IPicture2_Vtbl)).Height)(this, pheight), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn Render(this: *mut core::ffi::c_void, hdc: super::super::Graphics::Gdi::HDC, x: i32, y: i32, cx: i32, cy: i32, xsrc: i32, ysrc: i32, cxsrc: i32, cysrc: i32, prcwbounds: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPicture2", "Render", 8usize, |this| ((**(this as *const *const IPicture2_Vtbl)).Render)(this, hdc, x, y, cx, cy, xsrc, ysrc, cxsrc, cysrc, prcwbounds), |result| Some(*result))
+        }
+        unsafe extern "system" fn set_hPal(this: *mut core::ffi::c_void, hpal: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPicture2", "set_hPal", 9usize, |this| ((**(this as *const *const IPicture2_Vtbl)).set_hPal)(this, hpal), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn CurDC(this: *mut core::ffi::c_void, phdc: *mut super::super::Graphics::Gdi::HDC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPicture2", "CurDC", 10usize, |this| ((**(this as *const *const IPicture2_Vtbl)).CurDC)(this, phdc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn SelectPicture(this: *mut core::ffi::c_void, hdcin: super::super::Graphics::Gdi::HDC, phdcout: *mut super::super::Graphics::Gdi::HDC, phbmpout: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPicture2", "SelectPicture", 11usize, |this| ((**(this as *const *const IPicture2_Vtbl)).SelectPicture)(this, hdcin, phdcout, phbmpout), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeepOriginalFormat(this: *mut core::ffi::c_void, pkeep: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPicture2", "KeepOriginalFormat", 12usize, |this| ((**(this as *const *const IPicture2_Vtbl)).KeepOriginalFormat)(this, pkeep), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeepOriginalFormat(this: *mut core::ffi::c_void, keep: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPicture2", "SetKeepOriginalFormat", 13usize, |this| ((**(this as *const *const IPicture2_Vtbl)).SetKeepOriginalFormat)(this, keep), |result| Some(*result))
+        }
+        unsafe extern "system" fn PictureChanged(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPicture2", "PictureChanged", 14usize, |this| ((**(this as *const *const IPicture2_Vtbl)).PictureChanged)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SaveAsFile(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, fsavememcopy: super::super::Foundation::BOOL, pcbsize: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPicture2", "SaveAsFile", 15usize, |this| ((**(this as *const *const IPicture2_Vtbl)).SaveAsFile)(this, pstream, fsavememcopy, pcbsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn Attributes(this: *mut core::ffi::c_void, pdwattr: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPicture2", "Attributes", 16usize, |this| ((**(this as *const *const IPicture2_Vtbl)).Attributes)(this, pdwattr), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Handle,
+            hPal,
+            Type,
+            Width,
+            Height,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            Render,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            Render: 0,
+            set_hPal,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            CurDC,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            CurDC: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            SelectPicture,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            SelectPicture: 0,
+            KeepOriginalFormat,
+            SetKeepOriginalFormat,
+            PictureChanged,
+            #[cfg(feature = "Win32_System_Com")]
+            SaveAsFile,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SaveAsFile: 0,
+            Attributes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPicture2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPictureDisp_Vtbl {
+    const PROXY: Self = { Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPictureDisp as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPointerInactive_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetActivationPolicy(this: *mut core::ffi::c_void, pdwpolicy: *mut POINTERINACTIVE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerInactive", "GetActivationPolicy", 3usize, |this| ((**(this as *const *const IPointerInactive_Vtbl)).GetActivationPolicy)(this, pdwpolicy), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnInactiveMouseMove(this: *mut core::ffi::c_void, prectbounds: *const super::super::Foundation::RECT, x: i32, y: i32, grfkeystate: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerInactive", "OnInactiveMouseMove", 4usize, |this| ((**(this as *const *const IPointerInactive_Vtbl)).OnInactiveMouseMove)(this, prectbounds, x, y, grfkeystate), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnInactiveSetCursor(this: *mut core::ffi::c_void, prectbounds: *const super::super::Foundation::RECT, x: i32, y: i32, dwmousemsg: u32, fsetalways: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPointerInactive", "OnInactiveSetCursor", 5usize, |this| ((**(this as *const *const IPointerInactive_Vtbl)).OnInactiveSetCursor)(this, prectbounds, x, y, dwmousemsg, fsetalways), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetActivationPolicy, OnInactiveMouseMove, OnInactiveSetCursor }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPointerInactive as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+impl windows_core::imp::ProxyVtable for IPrint_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetInitialPageNum(this: *mut core::ffi::c_void, nfirstpage: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint", "SetInitialPageNum", 3usize, |this| ((**(this as *const *const IPrint_Vtbl)).SetInitialPageNum)(this, nfirstpage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPageInfo(this: *mut core::ffi::c_void, pnfirstpage: *mut i32, pcpages: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint", "GetPageInfo", 4usize, |this| ((**(this as *const *const IPrint_Vtbl)).GetPageInfo)(this, pnfirstpage, pcpages), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+        unsafe extern "system" fn Print(this: *mut core::ffi::c_void, grfflags: u32, pptd: *mut *mut super::Com::DVTARGETDEVICE, pppageset: *mut *mut PAGESET, pstgmoptions: *mut super::Com::STGMEDIUM, pcallback: *mut core::ffi::c_void, nfirstpage: i32, pcpagesprinted: *mut i32, pnlastpage: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrint", "Print", 5usize, |this| ((**(this as *const *const IPrint_Vtbl)).Print)(this, grfflags, pptd, pppageset, pstgmoptions, pcallback, nfirstpage, pcpagesprinted, pnlastpage), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetInitialPageNum,
+            GetPageInfo,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage"))]
+            Print,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com_StructuredStorage")))]
+            Print: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrint as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPropertyNotifySink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnChanged(this: *mut core::ffi::c_void, dispid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyNotifySink", "OnChanged", 3usize, |this| ((**(this as *const *const IPropertyNotifySink_Vtbl)).OnChanged)(this, dispid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnRequestEdit(this: *mut core::ffi::c_void, dispid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyNotifySink", "OnRequestEdit", 4usize, |this| ((**(this as *const *const IPropertyNotifySink_Vtbl)).OnRequestEdit)(this, dispid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnChanged, OnRequestEdit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertyNotifySink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl windows_core::imp::ProxyVtable for IPropertyPage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPageSite(this: *mut core::ffi::c_void, ppagesite: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyPage", "SetPageSite", 3usize, |this| ((**(this as *const *const IPropertyPage_Vtbl)).SetPageSite)(this, ppagesite), |result| Some(*result))
+        }
+        unsafe extern "system" fn Activate(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, prect: *const super::super::Foundation::RECT, bmodal: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyPage", "Activate", 4usize, |this| ((**(this as *const *const IPropertyPage_Vtbl)).Activate)(this, hwndparent, prect, bmodal), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deactivate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyPage", "Deactivate", 5usize, |this| ((**(this as *const *const IPropertyPage_Vtbl)).Deactivate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPageInfo(this: *mut core::ffi::c_void, ppageinfo: *mut PROPPAGEINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyPage", "GetPageInfo", 6usize, |this| ((**(this as *const *const IPropertyPage_Vtbl)).GetPageInfo)(this, ppageinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetObjects(this: *mut core::ffi::c_void, cobjects: u32, ppunk: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyPage", "SetObjects", 7usize, |this| ((**(this as *const *const IPropertyPage_Vtbl)).SetObjects)(this, cobjects, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn Show(this: *mut core::ffi::c_void, ncmdshow: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyPage", "Show", 8usize, |this| ((**(this as *const *const IPropertyPage_Vtbl)).Show)(this, ncmdshow), |result| Some(*result))
+        }
+        unsafe extern "system" fn Move(this: *mut core::ffi::c_void, prect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyPage", "Move", 9usize, |this| ((**(this as *const *const IPropertyPage_Vtbl)).Move)(this, prect), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPageDirty(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyPage", "IsPageDirty", 10usize, |this| ((**(this as *const *const IPropertyPage_Vtbl)).IsPageDirty)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Apply(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyPage", "Apply", 11usize, |this| ((**(this as *const *const IPropertyPage_Vtbl)).Apply)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Help(this: *mut core::ffi::c_void, pszhelpdir: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyPage", "Help", 12usize, |this| ((**(this as *const *const IPropertyPage_Vtbl)).Help)(this, pszhelpdir), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn TranslateAccelerator(this: *mut core::ffi::c_void, pmsg: *const super::super::UI::WindowsAndMessaging::MSG) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyPage", "TranslateAccelerator", 13usize, |this| ((**(this as *const *const IPropertyPage_Vtbl)).TranslateAccelerator)(this, pmsg), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetPageSite,
+            Activate,
+            Deactivate,
+            GetPageInfo,
+            SetObjects,
+            Show,
+            Move,
+            IsPageDirty,
+            Apply,
+            Help,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            TranslateAccelerator,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            TranslateAccelerator: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertyPage as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl windows_core::imp::ProxyVtable for IPropertyPage2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EditProperty(this: *mut core::ffi::c_void, dispid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyPage2", "EditProperty", 14usize, |this| ((**(this as *const *const IPropertyPage2_Vtbl)).EditProperty)(this, dispid), |result| Some(*result))
+        }
+        Self { base__: <IPropertyPage_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EditProperty }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertyPage2 as windows_core::Interface>::IID || <IPropertyPage_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl windows_core::imp::ProxyVtable for IPropertyPageSite_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnStatusChange(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyPageSite", "OnStatusChange", 3usize, |this| ((**(this as *const *const IPropertyPageSite_Vtbl)).OnStatusChange)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocaleID(this: *mut core::ffi::c_void, plocaleid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyPageSite", "GetLocaleID", 4usize, |this| ((**(this as *const *const IPropertyPageSite_Vtbl)).GetLocaleID)(this, plocaleid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPageContainer(this: *mut core::ffi::c_void, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyPageSite", "GetPageContainer", 5usize, |this| ((**(this as *const *const IPropertyPageSite_Vtbl)).GetPageContainer)(this, ppunk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn TranslateAccelerator(this: *mut core::ffi::c_void, pmsg: *const super::super::UI::WindowsAndMessaging::MSG) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyPageSite", "TranslateAccelerator", 6usize, |this| ((**(this as *const *const IPropertyPageSite_Vtbl)).TranslateAccelerator)(this, pmsg), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnStatusChange,
+            GetLocaleID,
+            GetPageContainer,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            TranslateAccelerator,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            TranslateAccelerator: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertyPageSite as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProtectFocus_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AllowFocusChange(this: *mut core::ffi::c_void, pfallow: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectFocus", "AllowFocusChange", 3usize, |this| ((**(this as *const *const IProtectFocus_Vtbl)).AllowFocusChange)(this, pfallow), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AllowFocusChange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProtectFocus as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl windows_core::imp::ProxyVtable for IProtectedModeMenuServices_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn CreateMenu(this: *mut core::ffi::c_void, phmenu: *mut super::super::UI::WindowsAndMessaging::HMENU) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectedModeMenuServices", "CreateMenu", 3usize, |this| ((**(this as *const *const IProtectedModeMenuServices_Vtbl)).CreateMenu)(this, phmenu), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn LoadMenu(this: *mut core::ffi::c_void, pszmodulename: windows_core::PCWSTR, pszmenuname: windows_core::PCWSTR, phmenu: *mut super::super::UI::WindowsAndMessaging::HMENU) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectedModeMenuServices", "LoadMenu", 4usize, |this| ((**(this as *const *const IProtectedModeMenuServices_Vtbl)).LoadMenu)(this, pszmodulename, pszmenuname, phmenu), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn LoadMenuID(this: *mut core::ffi::c_void, pszmodulename: windows_core::PCWSTR, wresourceid: u16, phmenu: *mut super::super::UI::WindowsAndMessaging::HMENU) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtectedModeMenuServices", "LoadMenuID", 5usize, |this| ((**(this as *const *const IProtectedModeMenuServices_Vtbl)).LoadMenuID)(this, pszmodulename, wresourceid, phmenu), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            CreateMenu,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            CreateMenu: 0,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            LoadMenu,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            LoadMenu: 0,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            LoadMenuID,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            LoadMenuID: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProtectedModeMenuServices as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IProvideClassInfo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetClassInfo(this: *mut core::ffi::c_void, ppti: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProvideClassInfo", "GetClassInfo", 3usize, |this| ((**(this as *const *const IProvideClassInfo_Vtbl)).GetClassInfo)(this, ppti), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetClassInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetClassInfo: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProvideClassInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IProvideClassInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetGUID(this: *mut core::ffi::c_void, dwguidkind: u32, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProvideClassInfo2", "GetGUID", 4usize, |this| ((**(this as *const *const IProvideClassInfo2_Vtbl)).GetGUID)(this, dwguidkind, pguid), |result| Some(*result))
+        }
+        Self { base__: <IProvideClassInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetGUID }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProvideClassInfo2 as windows_core::Interface>::IID || <IProvideClassInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IProvideMultipleClassInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMultiTypeInfoCount(this: *mut core::ffi::c_void, pcti: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProvideMultipleClassInfo", "GetMultiTypeInfoCount", 5usize, |this| ((**(this as *const *const IProvideMultipleClassInfo_Vtbl)).GetMultiTypeInfoCount)(this, pcti), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetInfoOfIndex(this: *mut core::ffi::c_void, iti: u32, dwflags: MULTICLASSINFO_FLAGS, ppticoclass: *mut *mut core::ffi::c_void, pdwtiflags: *mut u32, pcdispidreserved: *mut u32, piidprimary: *mut windows_core::GUID, piidsource: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProvideMultipleClassInfo", "GetInfoOfIndex", 6usize, |this| ((**(this as *const *const IProvideMultipleClassInfo_Vtbl)).GetInfoOfIndex)(this, iti, dwflags, ppticoclass, pdwtiflags, pcdispidreserved, piidprimary, piidsource), |result| Some(*result))
+        }
+        Self {
+            base__: <IProvideClassInfo2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetMultiTypeInfoCount,
+            #[cfg(feature = "Win32_System_Com")]
+            GetInfoOfIndex,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetInfoOfIndex: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProvideMultipleClassInfo as windows_core::Interface>::IID || <IProvideClassInfo2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProvideRuntimeContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentSourceContext(this: *mut core::ffi::c_void, pdwcontext: *mut usize, pfexecutingglobalcode: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProvideRuntimeContext", "GetCurrentSourceContext", 3usize, |this| ((**(this as *const *const IProvideRuntimeContext_Vtbl)).GetCurrentSourceContext)(this, pdwcontext, pfexecutingglobalcode), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrentSourceContext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProvideRuntimeContext as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IQuickActivate_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn QuickActivate(this: *mut core::ffi::c_void, pqacontainer: *const QACONTAINER, pqacontrol: *mut QACONTROL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQuickActivate", "QuickActivate", 3usize, |this| ((**(this as *const *const IQuickActivate_Vtbl)).QuickActivate)(this, pqacontainer, pqacontrol), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContentExtent(this: *mut core::ffi::c_void, psizel: *const super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQuickActivate", "SetContentExtent", 4usize, |this| ((**(this as *const *const IQuickActivate_Vtbl)).SetContentExtent)(this, psizel), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContentExtent(this: *mut core::ffi::c_void, psizel: *mut super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQuickActivate", "GetContentExtent", 5usize, |this| ((**(this as *const *const IQuickActivate_Vtbl)).GetContentExtent)(this, psizel), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+            QuickActivate,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com")))]
+            QuickActivate: 0,
+            SetContentExtent,
+            GetContentExtent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IQuickActivate as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRecordInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RecordInit(this: *mut core::ffi::c_void, pvnew: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRecordInfo", "RecordInit", 3usize, |this| ((**(this as *const *const IRecordInfo_Vtbl)).RecordInit)(this, pvnew), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecordClear(this: *mut core::ffi::c_void, pvexisting: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRecordInfo", "RecordClear", 4usize, |this| ((**(this as *const *const IRecordInfo_Vtbl)).RecordClear)(this, pvexisting), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecordCopy(this: *mut core::ffi::c_void, pvexisting: *const core::ffi::c_void, pvnew: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRecordInfo", "RecordCopy", 5usize, |this| ((**(this as *const *const IRecordInfo_Vtbl)).RecordCopy)(this, pvexisting, pvnew), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGuid(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRecordInfo", "GetGuid", 6usize, |this| ((**(this as *const *const IRecordInfo_Vtbl)).GetGuid)(this, pguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRecordInfo", "GetName", 7usize, |this| ((**(this as *const *const IRecordInfo_Vtbl)).GetName)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSize(this: *mut core::ffi::c_void, pcbsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRecordInfo", "GetSize", 8usize, |this| ((**(this as *const *const IRecordInfo_Vtbl)).GetSize)(this, pcbsize), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetTypeInfo(this: *mut core::ffi::c_void, pptypeinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRecordInfo", "GetTypeInfo", 9usize, |this| ((**(this as *const *const IRecordInfo_Vtbl)).GetTypeInfo)(this, pptypeinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetField(this: *mut core::ffi::c_void, pvdata: *const core::ffi::c_void, szfieldname: windows_core::PCWSTR, pvarfield: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRecordInfo", "GetField", 10usize, |this| ((**(this as *const *const IRecordInfo_Vtbl)).GetField)(this, pvdata, szfieldname, pvarfield), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFieldNoCopy(this: *mut core::ffi::c_void, pvdata: *const core::ffi::c_void, szfieldname: windows_core::PCWSTR, pvarfield: *mut core::mem::MaybeUninit<windows_core::VARIANT>, ppvdatacarray: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRecordInfo", "GetFieldNoCopy", 11usize, |this| ((**(this as *const *const IRecordInfo_Vtbl)).GetFieldNoCopy)(this, pvdata, szfieldname, pvarfield, ppvdatacarray), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutField(this: *mut core::ffi::c_void, wflags: u32, pvdata: *mut core::ffi::c_void, szfieldname: windows_core::PCWSTR, pvarfield: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRecordInfo", "PutField", 12usize, |this| ((**(this as *const *const IRecordInfo_Vtbl)).PutField)(this, wflags, pvdata, szfieldname, pvarfield), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutFieldNoCopy(this: *mut core::ffi::c_void, wflags: u32, pvdata: *mut core::ffi::c_void, szfieldname: windows_core::PCWSTR, pvarfield: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRecordInfo", "PutFieldNoCopy", 13usize, |this| ((**(this as *const *const IRecordInfo_Vtbl)).PutFieldNoCopy)(this, wflags, pvdata, szfieldname, pvarfield), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFieldNames(this: *mut core::ffi::c_void, pcnames: *mut u32, rgbstrnames: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRecordInfo", "GetFieldNames", 14usize, |this| ((**(this as *const *const IRecordInfo_Vtbl)).GetFieldNames)(this, pcnames, rgbstrnames), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMatchingType(this: *mut core::ffi::c_void, precordinfo: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IRecordInfo", "IsMatchingType", 15usize, |this| ((**(this as *const *const IRecordInfo_Vtbl)).IsMatchingType)(this, precordinfo), |_| None)
+        }
+        unsafe extern "system" fn RecordCreate(this: *mut core::ffi::c_void) -> *mut core::ffi::c_void {
+            windows_core::imp::proxy_call(this, "IRecordInfo", "RecordCreate", 16usize, |this| ((**(this as *const *const IRecordInfo_Vtbl)).RecordCreate)(this), |_| None)
+        }
+        unsafe extern "system" fn RecordCreateCopy(this: *mut core::ffi::c_void, pvsource: *const core::ffi::c_void, ppvdest: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRecordInfo", "RecordCreateCopy", 17usize, |this| ((**(this as *const *const IRecordInfo_Vtbl)).RecordCreateCopy)(this, pvsource, ppvdest), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecordDestroy(this: *mut core::ffi::c_void, pvrecord: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRecordInfo", "RecordDestroy", 18usize, |this| ((**(this as *const *const IRecordInfo_Vtbl)).RecordDestroy)(this, pvrecord), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RecordInit,
+            RecordClear,
+            RecordCopy,
+            GetGuid,
+            GetName,
+            GetSize,
+            #[cfg(feature = "Win32_System_Com")]
+            GetTypeInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetTypeInfo: 0,
+            GetField,
+            GetFieldNoCopy,
+            PutField,
+            PutFieldNoCopy,
+            GetFieldNames,
+            IsMatchingType,
+            RecordCreate,
+            RecordCreateCopy,
+            RecordDestroy,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRecordInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISimpleFrameSite_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PreMessageFilter(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, msg: u32, wp: super::super::Foundation::WPARAM, lp: super::super::Foundation::LPARAM, plresult: *mut super::super::Foundation::LRESULT, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleFrameSite", "PreMessageFilter", 3usize, |this| ((**(this as *const *const ISimpleFrameSite_Vtbl)).PreMessageFilter)(this, hwnd, msg, wp, lp, plresult, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostMessageFilter(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, msg: u32, wp: super::super::Foundation::WPARAM, lp: super::super::Foundation::LPARAM, plresult: *mut super::super::Foundation::LRESULT, dwcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleFrameSite", "PostMessageFilter", 4usize, |this| ((**(this as *const *const ISimpleFrameSite_Vtbl)).PostMessageFilter)(this, hwnd, msg, wp, lp, plresult, dwcookie), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PreMessageFilter, PostMessageFilter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISimpleFrameSite as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpecifyPropertyPages_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPages(this: *mut core::ffi::c_void, ppages: *mut CAUUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpecifyPropertyPages", "GetPages", 3usize, |this| ((**(this as *const *const ISpecifyPropertyPages_Vtbl)).GetPages)(this, ppages), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPages }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpecifyPropertyPages as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITypeChangeEvents_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RequestTypeChange(this: *mut core::ffi::c_void, changekind: CHANGEKIND, ptinfobefore: *mut core::ffi::c_void, pstrname: windows_core::PCWSTR, pfcancel: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeChangeEvents", "RequestTypeChange", 3usize, |this| ((**(this as *const *const ITypeChangeEvents_Vtbl)).RequestTypeChange)(this, changekind, ptinfobefore, pstrname, pfcancel), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AfterTypeChange(this: *mut core::ffi::c_void, changekind: CHANGEKIND, ptinfoafter: *mut core::ffi::c_void, pstrname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeChangeEvents", "AfterTypeChange", 4usize, |this| ((**(this as *const *const ITypeChangeEvents_Vtbl)).AfterTypeChange)(this, changekind, ptinfoafter, pstrname), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            RequestTypeChange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RequestTypeChange: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AfterTypeChange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AfterTypeChange: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITypeChangeEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITypeFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateFromTypeInfo(this: *mut core::ffi::c_void, ptypeinfo: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeFactory", "CreateFromTypeInfo", 3usize, |this| ((**(this as *const *const ITypeFactory_Vtbl)).CreateFromTypeInfo)(this, ptypeinfo, riid, ppv), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateFromTypeInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateFromTypeInfo: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITypeFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITypeMarshal_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, pvtype: *const core::ffi::c_void, dwdestcontext: u32, pvdestcontext: *const core::ffi::c_void, psize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeMarshal", "Size", 3usize, |this| ((**(this as *const *const ITypeMarshal_Vtbl)).Size)(this, pvtype, dwdestcontext, pvdestcontext, psize), |result| Some(*result))
+        }
+        unsafe extern "system" fn Marshal(this: *mut core::ffi::c_void, pvtype: *const core::ffi::c_void, dwdestcontext: u32, pvdestcontext: *const core::ffi::c_void, cbbufferlength: u32, pbuffer: *mut u8, pcbwritten: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeMarshal", "Marshal", 4usize, |this| ((**(this as *const *const ITypeMarshal_Vtbl)).Marshal)(this, pvtype, dwdestcontext, pvdestcontext, cbbufferlength, pbuffer, pcbwritten), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unmarshal(this: *mut core::ffi::c_void, pvtype: *mut core::ffi::c_void, dwflags: u32, cbbufferlength: u32, pbuffer: *const u8, pcbread: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeMarshal", "Unmarshal", 5usize, |this| ((**(this as *const *const ITypeMarshal_Vtbl)).Unmarshal)(this, pvtype, dwflags, cbbufferlength, pbuffer, pcbread), |result| Some(*result))
+        }
+        unsafe extern "system" fn Free(this: *mut core::ffi::c_void, pvtype: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITypeMarshal", "Free", 6usize, |this| ((**(this as *const *const ITypeMarshal_Vtbl)).Free)(this, pvtype), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Size, Marshal, Unmarshal, Free }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITypeMarshal as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVBFormat_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Format(this: *mut core::ffi::c_void, vdata: *mut core::mem::MaybeUninit<windows_core::VARIANT>, bstrformat: core::mem::MaybeUninit<windows_core::BSTR>, lpbuffer: *mut core::ffi::c_void, cb: u16, lcid: i32, sfirstdayofweek: i16, sfirstweekofyear: u16, rcb: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBFormat", "Format", 3usize, |this| ((**(this as *const *const IVBFormat_Vtbl)).Format)(this, vdata, bstrformat, lpbuffer, cb, lcid, sfirstdayofweek, sfirstweekofyear, rcb), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Format }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVBFormat as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IVBGetControl_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumControls(this: *mut core::ffi::c_void, dwolecontf: u32, dwwhich: ENUM_CONTROLS_WHICH_FLAGS, ppenumunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVBGetControl", "EnumControls", 3usize, |this| ((**(this as *const *const IVBGetControl_Vtbl)).EnumControls)(this, dwolecontf, dwwhich, ppenumunk), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumControls,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumControls: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVBGetControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Variant")]
+impl windows_core::imp::ProxyVtable for IVariantChangeType_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Variant")]
+        unsafe extern "system" fn ChangeType(this: *mut core::ffi::c_void, pvardst: *mut core::mem::MaybeUninit<windows_core::VARIANT>, pvarsrc: *const core::mem::MaybeUninit<windows_core::VARIANT>, lcid: u32, vtnew: super::Variant::VARENUM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVariantChangeType", "ChangeType", 3usize, |this| ((**(this as *const *const IVariantChangeType_Vtbl)).ChangeType)(this, pvardst, pvarsrc, lcid, vtnew), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Variant")]
+            ChangeType,
+            #[cfg(not(feature = "Win32_System_Variant"))]
+            ChangeType: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVariantChangeType as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IViewObject_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn Draw(this: *mut core::ffi::c_void, dwdrawaspect: super::Com::DVASPECT, lindex: i32, pvaspect: *mut core::ffi::c_void, ptd: *const super::Com::DVTARGETDEVICE, hdctargetdev: super::super::Graphics::Gdi::HDC, hdcdraw: super::super::Graphics::Gdi::HDC, lprcbounds: *const super::super::Foundation::RECTL, lprcwbounds: *const super::super::Foundation::RECTL, pfncontinue: isize, dwcontinue: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewObject", "Draw", 3usize, |this| ((**(this as *const *const IViewObject_Vtbl)).Draw)(this, dwdrawaspect, lindex, pvaspect, ptd, hdctargetdev, hdcdraw, lprcbounds, lprcwbounds, pfncontinue, dwcontinue), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn GetColorSet(this: *mut core::ffi::c_void, dwdrawaspect: super::Com::DVASPECT, lindex: i32, pvaspect: *mut core::ffi::c_void, ptd: *const super::Com::DVTARGETDEVICE, hictargetdev: super::super::Graphics::Gdi::HDC, ppcolorset: *mut *mut super::super::Graphics::Gdi::LOGPALETTE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewObject", "GetColorSet", 4usize, |this| ((**(this as *const *const IViewObject_Vtbl)).GetColorSet)(this, dwdrawaspect, lindex, pvaspect, ptd, hictargetdev, ppcolorset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Freeze(this: *mut core::ffi::c_void, dwdrawaspect: super::Com::DVASPECT, lindex: i32, pvaspect: *mut core::ffi::c_void, pdwfreeze: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewObject", "Freeze", 5usize, |this| ((**(this as *const *const IViewObject_Vtbl)).Freeze)(this, dwdrawaspect, lindex, pvaspect, pdwfreeze), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unfreeze(this: *mut core::ffi::c_void, dwfreeze: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewObject", "Unfreeze", 6usize, |this| ((**(this as *const *const IViewObject_Vtbl)).Unfreeze)(this, dwfreeze), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetAdvise(this: *mut core::ffi::c_void, aspects: super::Com::DVASPECT, advf: u32, padvsink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewObject", "SetAdvise", 7usize, |this| ((**(this as *const *const IViewObject_Vtbl)).SetAdvise)(this, aspects, advf, padvsink), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetAdvise(this: *mut core::ffi::c_void, paspects: *mut u32, padvf: *mut u32, ppadvsink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewObject", "GetAdvise", 8usize, |this| ((**(this as *const *const IViewObject_Vtbl)).GetAdvise)(this, paspects, padvf, ppadvsink), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+            Draw,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com")))]
+            Draw: 0,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+            GetColorSet,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com")))]
+            GetColorSet: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Freeze,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Freeze: 0,
+            Unfreeze,
+            #[cfg(feature = "Win32_System_Com")]
+            SetAdvise,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetAdvise: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetAdvise,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetAdvise: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IViewObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IViewObject2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetExtent(this: *mut core::ffi::c_void, dwdrawaspect: super::Com::DVASPECT, lindex: i32, ptd: *const super::Com::DVTARGETDEVICE, lpsizel: *mut super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewObject2", "GetExtent", 9usize, |this| ((**(this as *const *const IViewObject2_Vtbl)).GetExtent)(this, dwdrawaspect, lindex, ptd, lpsizel), |result| Some(*result))
+        }
+        Self {
+            base__: <IViewObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetExtent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetExtent: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IViewObject2 as windows_core::Interface>::IID || <IViewObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IViewObjectEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRect(this: *mut core::ffi::c_void, dwaspect: u32, prect: *mut super::super::Foundation::RECTL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewObjectEx", "GetRect", 10usize, |this| ((**(this as *const *const IViewObjectEx_Vtbl)).GetRect)(this, dwaspect, prect), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetViewStatus(this: *mut core::ffi::c_void, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewObjectEx", "GetViewStatus", 11usize, |this| ((**(this as *const *const IViewObjectEx_Vtbl)).GetViewStatus)(this, pdwstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryHitPoint(this: *mut core::ffi::c_void, dwaspect: u32, prectbounds: *const super::super::Foundation::RECT, ptlloc: super::super::Foundation::POINT, lclosehint: i32, phitresult: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewObjectEx", "QueryHitPoint", 12usize, |this| ((**(this as *const *const IViewObjectEx_Vtbl)).QueryHitPoint)(this, dwaspect, prectbounds, ptlloc, lclosehint, phitresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryHitRect(this: *mut core::ffi::c_void, dwaspect: u32, prectbounds: *const super::super::Foundation::RECT, prectloc: *const super::super::Foundation::RECT, lclosehint: i32, phitresult: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewObjectEx", "QueryHitRect", 13usize, |this| ((**(this as *const *const IViewObjectEx_Vtbl)).QueryHitRect)(this, dwaspect, prectbounds, prectloc, lclosehint, phitresult), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn GetNaturalExtent(this: *mut core::ffi::c_void, dwaspect: super::Com::DVASPECT, lindex: i32, ptd: *const super::Com::DVTARGETDEVICE, hictargetdev: super::super::Graphics::Gdi::HDC, pextentinfo: *const DVEXTENTINFO, psizel: *mut super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewObjectEx", "GetNaturalExtent", 14usize, |this| ((**(this as *const *const IViewObjectEx_Vtbl)).GetNaturalExtent)(this, dwaspect, lindex, ptd, hictargetdev, pextentinfo, psizel), |result| Some(*result))
+        }
+        Self {
+            base__: <IViewObject2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetRect,
+            GetViewStatus,
+            QueryHitPoint,
+            QueryHitRect,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+            GetNaturalExtent,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com")))]
+            GetNaturalExtent: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IViewObjectEx as windows_core::Interface>::IID || <IViewObject2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IZoomEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnZoomPercentChanged(this: *mut core::ffi::c_void, ulzoompercent: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IZoomEvents", "OnZoomPercentChanged", 3usize, |this| ((**(this as *const *const IZoomEvents_Vtbl)).OnZoomPercentChanged)(this, ulzoompercent), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnZoomPercentChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IZoomEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -617,6 +617,7 @@ pub struct WPCFLAG_IM_FEATURE(pub i32);
 impl windows_core::TypeKind for WPCFLAG_IM_FEATURE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPCFLAG_IM_FEATURE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPCFLAG_IM_FEATURE").field(&self.0).finish()
@@ -628,6 +629,7 @@ pub struct WPCFLAG_IM_LEAVE(pub i32);
 impl windows_core::TypeKind for WPCFLAG_IM_LEAVE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPCFLAG_IM_LEAVE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPCFLAG_IM_LEAVE").field(&self.0).finish()
@@ -639,6 +641,7 @@ pub struct WPCFLAG_ISBLOCKED(pub i32);
 impl windows_core::TypeKind for WPCFLAG_ISBLOCKED {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPCFLAG_ISBLOCKED {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPCFLAG_ISBLOCKED").field(&self.0).finish()
@@ -650,6 +653,7 @@ pub struct WPCFLAG_LOGOFF_TYPE(pub i32);
 impl windows_core::TypeKind for WPCFLAG_LOGOFF_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPCFLAG_LOGOFF_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPCFLAG_LOGOFF_TYPE").field(&self.0).finish()
@@ -661,6 +665,7 @@ pub struct WPCFLAG_OVERRIDE(pub i32);
 impl windows_core::TypeKind for WPCFLAG_OVERRIDE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPCFLAG_OVERRIDE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPCFLAG_OVERRIDE").field(&self.0).finish()
@@ -672,6 +677,7 @@ pub struct WPCFLAG_RESTRICTION(pub i32);
 impl windows_core::TypeKind for WPCFLAG_RESTRICTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPCFLAG_RESTRICTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPCFLAG_RESTRICTION").field(&self.0).finish()
@@ -683,6 +689,7 @@ pub struct WPCFLAG_VISIBILITY(pub i32);
 impl windows_core::TypeKind for WPCFLAG_VISIBILITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPCFLAG_VISIBILITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPCFLAG_VISIBILITY").field(&self.0).finish()
@@ -694,6 +701,7 @@ pub struct WPCFLAG_WEB_SETTING(pub i32);
 impl windows_core::TypeKind for WPCFLAG_WEB_SETTING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPCFLAG_WEB_SETTING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPCFLAG_WEB_SETTING").field(&self.0).finish()
@@ -705,6 +713,7 @@ pub struct WPC_ARGS_APPLICATIONEVENT(pub i32);
 impl windows_core::TypeKind for WPC_ARGS_APPLICATIONEVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPC_ARGS_APPLICATIONEVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPC_ARGS_APPLICATIONEVENT").field(&self.0).finish()
@@ -716,6 +725,7 @@ pub struct WPC_ARGS_APPOVERRIDEEVENT(pub i32);
 impl windows_core::TypeKind for WPC_ARGS_APPOVERRIDEEVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPC_ARGS_APPOVERRIDEEVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPC_ARGS_APPOVERRIDEEVENT").field(&self.0).finish()
@@ -727,6 +737,7 @@ pub struct WPC_ARGS_COMPUTERUSAGEEVENT(pub i32);
 impl windows_core::TypeKind for WPC_ARGS_COMPUTERUSAGEEVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPC_ARGS_COMPUTERUSAGEEVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPC_ARGS_COMPUTERUSAGEEVENT").field(&self.0).finish()
@@ -738,6 +749,7 @@ pub struct WPC_ARGS_CONTENTUSAGEEVENT(pub i32);
 impl windows_core::TypeKind for WPC_ARGS_CONTENTUSAGEEVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPC_ARGS_CONTENTUSAGEEVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPC_ARGS_CONTENTUSAGEEVENT").field(&self.0).finish()
@@ -749,6 +761,7 @@ pub struct WPC_ARGS_CONVERSATIONINITEVENT(pub i32);
 impl windows_core::TypeKind for WPC_ARGS_CONVERSATIONINITEVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPC_ARGS_CONVERSATIONINITEVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPC_ARGS_CONVERSATIONINITEVENT").field(&self.0).finish()
@@ -760,6 +773,7 @@ pub struct WPC_ARGS_CONVERSATIONJOINEVENT(pub i32);
 impl windows_core::TypeKind for WPC_ARGS_CONVERSATIONJOINEVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPC_ARGS_CONVERSATIONJOINEVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPC_ARGS_CONVERSATIONJOINEVENT").field(&self.0).finish()
@@ -771,6 +785,7 @@ pub struct WPC_ARGS_CONVERSATIONLEAVEEVENT(pub i32);
 impl windows_core::TypeKind for WPC_ARGS_CONVERSATIONLEAVEEVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPC_ARGS_CONVERSATIONLEAVEEVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPC_ARGS_CONVERSATIONLEAVEEVENT").field(&self.0).finish()
@@ -782,6 +797,7 @@ pub struct WPC_ARGS_CUSTOMEVENT(pub i32);
 impl windows_core::TypeKind for WPC_ARGS_CUSTOMEVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPC_ARGS_CUSTOMEVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPC_ARGS_CUSTOMEVENT").field(&self.0).finish()
@@ -793,6 +809,7 @@ pub struct WPC_ARGS_EMAILCONTACTEVENT(pub i32);
 impl windows_core::TypeKind for WPC_ARGS_EMAILCONTACTEVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPC_ARGS_EMAILCONTACTEVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPC_ARGS_EMAILCONTACTEVENT").field(&self.0).finish()
@@ -804,6 +821,7 @@ pub struct WPC_ARGS_EMAILRECEIEVEDEVENT(pub i32);
 impl windows_core::TypeKind for WPC_ARGS_EMAILRECEIEVEDEVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPC_ARGS_EMAILRECEIEVEDEVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPC_ARGS_EMAILRECEIEVEDEVENT").field(&self.0).finish()
@@ -815,6 +833,7 @@ pub struct WPC_ARGS_EMAILSENTEVENT(pub i32);
 impl windows_core::TypeKind for WPC_ARGS_EMAILSENTEVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPC_ARGS_EMAILSENTEVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPC_ARGS_EMAILSENTEVENT").field(&self.0).finish()
@@ -826,6 +845,7 @@ pub struct WPC_ARGS_FILEDOWNLOADEVENT(pub i32);
 impl windows_core::TypeKind for WPC_ARGS_FILEDOWNLOADEVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPC_ARGS_FILEDOWNLOADEVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPC_ARGS_FILEDOWNLOADEVENT").field(&self.0).finish()
@@ -837,6 +857,7 @@ pub struct WPC_ARGS_GAMESTARTEVENT(pub i32);
 impl windows_core::TypeKind for WPC_ARGS_GAMESTARTEVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPC_ARGS_GAMESTARTEVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPC_ARGS_GAMESTARTEVENT").field(&self.0).finish()
@@ -848,6 +869,7 @@ pub struct WPC_ARGS_IMCONTACTEVENT(pub i32);
 impl windows_core::TypeKind for WPC_ARGS_IMCONTACTEVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPC_ARGS_IMCONTACTEVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPC_ARGS_IMCONTACTEVENT").field(&self.0).finish()
@@ -859,6 +881,7 @@ pub struct WPC_ARGS_IMFEATUREEVENT(pub i32);
 impl windows_core::TypeKind for WPC_ARGS_IMFEATUREEVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPC_ARGS_IMFEATUREEVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPC_ARGS_IMFEATUREEVENT").field(&self.0).finish()
@@ -870,6 +893,7 @@ pub struct WPC_ARGS_MEDIADOWNLOADEVENT(pub i32);
 impl windows_core::TypeKind for WPC_ARGS_MEDIADOWNLOADEVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPC_ARGS_MEDIADOWNLOADEVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPC_ARGS_MEDIADOWNLOADEVENT").field(&self.0).finish()
@@ -881,6 +905,7 @@ pub struct WPC_ARGS_MEDIAPLAYBACKEVENT(pub i32);
 impl windows_core::TypeKind for WPC_ARGS_MEDIAPLAYBACKEVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPC_ARGS_MEDIAPLAYBACKEVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPC_ARGS_MEDIAPLAYBACKEVENT").field(&self.0).finish()
@@ -892,6 +917,7 @@ pub struct WPC_ARGS_SAFERAPPBLOCKED(pub i32);
 impl windows_core::TypeKind for WPC_ARGS_SAFERAPPBLOCKED {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPC_ARGS_SAFERAPPBLOCKED {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPC_ARGS_SAFERAPPBLOCKED").field(&self.0).finish()
@@ -903,6 +929,7 @@ pub struct WPC_ARGS_SETTINGSCHANGEEVENT(pub i32);
 impl windows_core::TypeKind for WPC_ARGS_SETTINGSCHANGEEVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPC_ARGS_SETTINGSCHANGEEVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPC_ARGS_SETTINGSCHANGEEVENT").field(&self.0).finish()
@@ -914,6 +941,7 @@ pub struct WPC_ARGS_URLVISITEVENT(pub i32);
 impl windows_core::TypeKind for WPC_ARGS_URLVISITEVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPC_ARGS_URLVISITEVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPC_ARGS_URLVISITEVENT").field(&self.0).finish()
@@ -925,6 +953,7 @@ pub struct WPC_ARGS_WEBOVERRIDEEVENT(pub i32);
 impl windows_core::TypeKind for WPC_ARGS_WEBOVERRIDEEVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPC_ARGS_WEBOVERRIDEEVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPC_ARGS_WEBOVERRIDEEVENT").field(&self.0).finish()
@@ -936,6 +965,7 @@ pub struct WPC_ARGS_WEBSITEVISITEVENT(pub i32);
 impl windows_core::TypeKind for WPC_ARGS_WEBSITEVISITEVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPC_ARGS_WEBSITEVISITEVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPC_ARGS_WEBSITEVISITEVENT").field(&self.0).finish()
@@ -947,6 +977,7 @@ pub struct WPC_MEDIA_EXPLICIT(pub i32);
 impl windows_core::TypeKind for WPC_MEDIA_EXPLICIT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPC_MEDIA_EXPLICIT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPC_MEDIA_EXPLICIT").field(&self.0).finish()
@@ -958,6 +989,7 @@ pub struct WPC_MEDIA_TYPE(pub i32);
 impl windows_core::TypeKind for WPC_MEDIA_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPC_MEDIA_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPC_MEDIA_TYPE").field(&self.0).finish()
@@ -969,6 +1001,7 @@ pub struct WPC_SETTINGS(pub i32);
 impl windows_core::TypeKind for WPC_SETTINGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WPC_SETTINGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WPC_SETTINGS").field(&self.0).finish()
@@ -979,3 +1012,5 @@ pub const WpcProviderSupport: windows_core::GUID = windows_core::GUID::from_u128
 pub const WpcSettingsProvider: windows_core::GUID = windows_core::GUID::from_u128(0x355dffaa_3b9f_435c_b428_5d44290bc5f2);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,123 @@
+impl windows_core::imp::ProxyVtable for IWPCGamesSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsBlocked(this: *mut core::ffi::c_void, guidappid: windows_core::GUID, pdwreasons: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWPCGamesSettings", "IsBlocked", 6usize, |this| ((**(this as *const *const IWPCGamesSettings_Vtbl)).IsBlocked)(this, guidappid, pdwreasons), |result| Some(*result))
+        }
+        Self { base__: <IWPCSettings_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsBlocked }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWPCGamesSettings as windows_core::Interface>::IID || <IWPCSettings_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWPCProviderConfig_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetUserSummary(this: *mut core::ffi::c_void, bstrsid: core::mem::MaybeUninit<windows_core::BSTR>, pbstrusersummary: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWPCProviderConfig", "GetUserSummary", 3usize, |this| ((**(this as *const *const IWPCProviderConfig_Vtbl)).GetUserSummary)(this, bstrsid, pbstrusersummary), |result| Some(*result))
+        }
+        unsafe extern "system" fn Configure(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, bstrsid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWPCProviderConfig", "Configure", 4usize, |this| ((**(this as *const *const IWPCProviderConfig_Vtbl)).Configure)(this, hwnd, bstrsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestOverride(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, bstrpath: core::mem::MaybeUninit<windows_core::BSTR>, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWPCProviderConfig", "RequestOverride", 5usize, |this| ((**(this as *const *const IWPCProviderConfig_Vtbl)).RequestOverride)(this, hwnd, bstrpath, dwflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetUserSummary, Configure, RequestOverride }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWPCProviderConfig as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWPCProviderState_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Enable(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWPCProviderState", "Enable", 3usize, |this| ((**(this as *const *const IWPCProviderState_Vtbl)).Enable)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disable(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWPCProviderState", "Disable", 4usize, |this| ((**(this as *const *const IWPCProviderState_Vtbl)).Disable)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Enable, Disable }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWPCProviderState as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWPCProviderSupport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrent(this: *mut core::ffi::c_void, pguidprovider: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWPCProviderSupport", "GetCurrent", 3usize, |this| ((**(this as *const *const IWPCProviderSupport_Vtbl)).GetCurrent)(this, pguidprovider), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCurrent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWPCProviderSupport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWPCSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsLoggingRequired(this: *mut core::ffi::c_void, pfrequired: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWPCSettings", "IsLoggingRequired", 3usize, |this| ((**(this as *const *const IWPCSettings_Vtbl)).IsLoggingRequired)(this, pfrequired), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastSettingsChangeTime(this: *mut core::ffi::c_void, ptime: *mut super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWPCSettings", "GetLastSettingsChangeTime", 4usize, |this| ((**(this as *const *const IWPCSettings_Vtbl)).GetLastSettingsChangeTime)(this, ptime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRestrictions(this: *mut core::ffi::c_void, pdwrestrictions: *mut WPCFLAG_RESTRICTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWPCSettings", "GetRestrictions", 5usize, |this| ((**(this as *const *const IWPCSettings_Vtbl)).GetRestrictions)(this, pdwrestrictions), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsLoggingRequired, GetLastSettingsChangeTime, GetRestrictions }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWPCSettings as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWPCWebSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSettings(this: *mut core::ffi::c_void, pdwsettings: *mut WPCFLAG_WEB_SETTING) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWPCWebSettings", "GetSettings", 6usize, |this| ((**(this as *const *const IWPCWebSettings_Vtbl)).GetSettings)(this, pdwsettings), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestURLOverride(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, pcszurl: windows_core::PCWSTR, curls: u32, ppcszsuburls: *const windows_core::PCWSTR, pfchanged: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWPCWebSettings", "RequestURLOverride", 7usize, |this| ((**(this as *const *const IWPCWebSettings_Vtbl)).RequestURLOverride)(this, hwnd, pcszurl, curls, ppcszsuburls, pfchanged), |result| Some(*result))
+        }
+        Self { base__: <IWPCSettings_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSettings, RequestURLOverride }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWPCWebSettings as windows_core::Interface>::IID || <IWPCSettings_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowsParentalControls_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetGamesSettings(this: *mut core::ffi::c_void, pcszsid: windows_core::PCWSTR, ppsettings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsParentalControls", "GetGamesSettings", 7usize, |this| ((**(this as *const *const IWindowsParentalControls_Vtbl)).GetGamesSettings)(this, pcszsid, ppsettings), |result| Some(*result))
+        }
+        Self { base__: <IWindowsParentalControlsCore_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetGamesSettings }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsParentalControls as windows_core::Interface>::IID || <IWindowsParentalControlsCore_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowsParentalControlsCore_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetVisibility(this: *mut core::ffi::c_void, pevisibility: *mut WPCFLAG_VISIBILITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsParentalControlsCore", "GetVisibility", 3usize, |this| ((**(this as *const *const IWindowsParentalControlsCore_Vtbl)).GetVisibility)(this, pevisibility), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUserSettings(this: *mut core::ffi::c_void, pcszsid: windows_core::PCWSTR, ppsettings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsParentalControlsCore", "GetUserSettings", 4usize, |this| ((**(this as *const *const IWindowsParentalControlsCore_Vtbl)).GetUserSettings)(this, pcszsid, ppsettings), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWebSettings(this: *mut core::ffi::c_void, pcszsid: windows_core::PCWSTR, ppsettings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsParentalControlsCore", "GetWebSettings", 5usize, |this| ((**(this as *const *const IWindowsParentalControlsCore_Vtbl)).GetWebSettings)(this, pcszsid, ppsettings), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWebFilterInfo(this: *mut core::ffi::c_void, pguidid: *mut windows_core::GUID, ppszname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsParentalControlsCore", "GetWebFilterInfo", 6usize, |this| ((**(this as *const *const IWindowsParentalControlsCore_Vtbl)).GetWebFilterInfo)(this, pguidid, ppszname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetVisibility, GetUserSettings, GetWebSettings, GetWebFilterInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsParentalControlsCore as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
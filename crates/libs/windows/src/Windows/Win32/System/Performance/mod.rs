@@ -5193,6 +5193,7 @@ pub struct AutoPathFormat(pub i32);
 impl windows_core::TypeKind for AutoPathFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AutoPathFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AutoPathFormat").field(&self.0).finish()
@@ -5204,6 +5205,7 @@ pub struct ClockType(pub i32);
 impl windows_core::TypeKind for ClockType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ClockType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ClockType").field(&self.0).finish()
@@ -5215,6 +5217,7 @@ pub struct CommitMode(pub i32);
 impl windows_core::TypeKind for CommitMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CommitMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CommitMode").field(&self.0).finish()
@@ -5226,6 +5229,7 @@ pub struct DataCollectorSetStatus(pub i32);
 impl windows_core::TypeKind for DataCollectorSetStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DataCollectorSetStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DataCollectorSetStatus").field(&self.0).finish()
@@ -5237,6 +5241,7 @@ pub struct DataCollectorType(pub i32);
 impl windows_core::TypeKind for DataCollectorType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DataCollectorType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DataCollectorType").field(&self.0).finish()
@@ -5248,6 +5253,7 @@ pub struct DataManagerSteps(pub i32);
 impl windows_core::TypeKind for DataManagerSteps {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DataManagerSteps {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DataManagerSteps").field(&self.0).finish()
@@ -5259,6 +5265,7 @@ pub struct DataSourceTypeConstants(pub i32);
 impl windows_core::TypeKind for DataSourceTypeConstants {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DataSourceTypeConstants {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DataSourceTypeConstants").field(&self.0).finish()
@@ -5270,6 +5277,7 @@ pub struct DisplayTypeConstants(pub i32);
 impl windows_core::TypeKind for DisplayTypeConstants {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DisplayTypeConstants {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DisplayTypeConstants").field(&self.0).finish()
@@ -5281,6 +5289,7 @@ pub struct FileFormat(pub i32);
 impl windows_core::TypeKind for FileFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FileFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FileFormat").field(&self.0).finish()
@@ -5292,6 +5301,7 @@ pub struct FolderActionSteps(pub i32);
 impl windows_core::TypeKind for FolderActionSteps {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FolderActionSteps {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FolderActionSteps").field(&self.0).finish()
@@ -5303,6 +5313,7 @@ pub struct PDH_DLL_VERSION(pub u32);
 impl windows_core::TypeKind for PDH_DLL_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PDH_DLL_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PDH_DLL_VERSION").field(&self.0).finish()
@@ -5314,6 +5325,7 @@ pub struct PDH_FMT(pub u32);
 impl windows_core::TypeKind for PDH_FMT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PDH_FMT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PDH_FMT").field(&self.0).finish()
@@ -5325,6 +5337,7 @@ pub struct PDH_LOG(pub u32);
 impl windows_core::TypeKind for PDH_LOG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PDH_LOG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PDH_LOG").field(&self.0).finish()
@@ -5336,6 +5349,7 @@ pub struct PDH_LOG_TYPE(pub u32);
 impl windows_core::TypeKind for PDH_LOG_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PDH_LOG_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PDH_LOG_TYPE").field(&self.0).finish()
@@ -5347,6 +5361,7 @@ pub struct PDH_PATH_FLAGS(pub u32);
 impl windows_core::TypeKind for PDH_PATH_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PDH_PATH_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PDH_PATH_FLAGS").field(&self.0).finish()
@@ -5358,6 +5373,7 @@ pub struct PDH_SELECT_DATA_SOURCE_FLAGS(pub u32);
 impl windows_core::TypeKind for PDH_SELECT_DATA_SOURCE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PDH_SELECT_DATA_SOURCE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PDH_SELECT_DATA_SOURCE_FLAGS").field(&self.0).finish()
@@ -5369,6 +5385,7 @@ pub struct PERF_COUNTER_AGGREGATE_FUNC(pub u32);
 impl windows_core::TypeKind for PERF_COUNTER_AGGREGATE_FUNC {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PERF_COUNTER_AGGREGATE_FUNC {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PERF_COUNTER_AGGREGATE_FUNC").field(&self.0).finish()
@@ -5380,6 +5397,7 @@ pub struct PERF_DETAIL(pub u32);
 impl windows_core::TypeKind for PERF_DETAIL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PERF_DETAIL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PERF_DETAIL").field(&self.0).finish()
@@ -5391,6 +5409,7 @@ pub struct PerfCounterDataType(pub i32);
 impl windows_core::TypeKind for PerfCounterDataType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PerfCounterDataType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PerfCounterDataType").field(&self.0).finish()
@@ -5402,6 +5421,7 @@ pub struct PerfRegInfoType(pub i32);
 impl windows_core::TypeKind for PerfRegInfoType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PerfRegInfoType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PerfRegInfoType").field(&self.0).finish()
@@ -5413,6 +5433,7 @@ pub struct REAL_TIME_DATA_SOURCE_ID_FLAGS(pub u32);
 impl windows_core::TypeKind for REAL_TIME_DATA_SOURCE_ID_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for REAL_TIME_DATA_SOURCE_ID_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("REAL_TIME_DATA_SOURCE_ID_FLAGS").field(&self.0).finish()
@@ -5424,6 +5445,7 @@ pub struct ReportValueTypeConstants(pub i32);
 impl windows_core::TypeKind for ReportValueTypeConstants {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ReportValueTypeConstants {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ReportValueTypeConstants").field(&self.0).finish()
@@ -5435,6 +5457,7 @@ pub struct ResourcePolicy(pub i32);
 impl windows_core::TypeKind for ResourcePolicy {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ResourcePolicy {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ResourcePolicy").field(&self.0).finish()
@@ -5446,6 +5469,7 @@ pub struct StreamMode(pub i32);
 impl windows_core::TypeKind for StreamMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StreamMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StreamMode").field(&self.0).finish()
@@ -5457,6 +5481,7 @@ pub struct SysmonBatchReason(pub i32);
 impl windows_core::TypeKind for SysmonBatchReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SysmonBatchReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SysmonBatchReason").field(&self.0).finish()
@@ -5468,6 +5493,7 @@ pub struct SysmonDataType(pub i32);
 impl windows_core::TypeKind for SysmonDataType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SysmonDataType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SysmonDataType").field(&self.0).finish()
@@ -5479,6 +5505,7 @@ pub struct SysmonFileType(pub i32);
 impl windows_core::TypeKind for SysmonFileType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SysmonFileType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SysmonFileType").field(&self.0).finish()
@@ -5490,6 +5517,7 @@ pub struct ValueMapType(pub i32);
 impl windows_core::TypeKind for ValueMapType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ValueMapType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ValueMapType").field(&self.0).finish()
@@ -5501,6 +5529,7 @@ pub struct WeekDays(pub i32);
 impl windows_core::TypeKind for WeekDays {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WeekDays {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WeekDays").field(&self.0).finish()
@@ -5524,7 +5553,8 @@ pub const LegacyTraceSessionCollection: windows_core::GUID = windows_core::GUID:
 pub const LogFileItem: windows_core::GUID = windows_core::GUID::from_u128(0x16ec5be8_df93_4237_94e4_9ee918111d71);
 pub const LogFiles: windows_core::GUID = windows_core::GUID::from_u128(0x2735d9fd_f6b9_4f19_a5d9_e2d068584bc5);
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PDH_BROWSE_DLG_CONFIG_A {
     pub _bitfield: u32,
     pub hWndOwner: super::super::Foundation::HWND,
@@ -5537,6 +5567,74 @@ pub struct PDH_BROWSE_DLG_CONFIG_A {
     pub dwDefaultDetailLevel: PERF_DETAIL,
     pub szDialogBoxCaption: windows_core::PSTR,
 }
+impl PDH_BROWSE_DLG_CONFIG_A {
+    pub const fn bIncludeInstanceIndex(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_bIncludeInstanceIndex(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn bSingleCounterPerAdd(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_bSingleCounterPerAdd(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn bSingleCounterPerDialog(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_bSingleCounterPerDialog(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn bLocalCountersOnly(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_bLocalCountersOnly(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn bWildCardInstances(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_bWildCardInstances(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn bHideDetailBox(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_bHideDetailBox(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn bInitializePath(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_bInitializePath(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn bDisableMachineSelection(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_bDisableMachineSelection(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn bIncludeCostlyObjects(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_bIncludeCostlyObjects(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn bShowObjectBrowser(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_bShowObjectBrowser(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn bReserved(&self) -> u32 {
+        (self._bitfield >> 10) & 4194303
+    }
+    pub fn set_bReserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(4194303 << 10)) | ((value & 4194303) << 10);
+    }
+}
 impl windows_core::TypeKind for PDH_BROWSE_DLG_CONFIG_A {
     type TypeKind = windows_core::CopyType;
 }
@@ -5546,7 +5644,8 @@ impl Default for PDH_BROWSE_DLG_CONFIG_A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PDH_BROWSE_DLG_CONFIG_HA {
     pub _bitfield: u32,
     pub hWndOwner: super::super::Foundation::HWND,
@@ -5559,6 +5658,74 @@ pub struct PDH_BROWSE_DLG_CONFIG_HA {
     pub dwDefaultDetailLevel: PERF_DETAIL,
     pub szDialogBoxCaption: windows_core::PSTR,
 }
+impl PDH_BROWSE_DLG_CONFIG_HA {
+    pub const fn bIncludeInstanceIndex(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_bIncludeInstanceIndex(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn bSingleCounterPerAdd(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_bSingleCounterPerAdd(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn bSingleCounterPerDialog(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_bSingleCounterPerDialog(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn bLocalCountersOnly(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_bLocalCountersOnly(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn bWildCardInstances(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_bWildCardInstances(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn bHideDetailBox(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_bHideDetailBox(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn bInitializePath(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_bInitializePath(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn bDisableMachineSelection(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_bDisableMachineSelection(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn bIncludeCostlyObjects(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_bIncludeCostlyObjects(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn bShowObjectBrowser(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_bShowObjectBrowser(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn bReserved(&self) -> u32 {
+        (self._bitfield >> 10) & 4194303
+    }
+    pub fn set_bReserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(4194303 << 10)) | ((value & 4194303) << 10);
+    }
+}
 impl windows_core::TypeKind for PDH_BROWSE_DLG_CONFIG_HA {
     type TypeKind = windows_core::CopyType;
 }
@@ -5568,7 +5735,8 @@ impl Default for PDH_BROWSE_DLG_CONFIG_HA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PDH_BROWSE_DLG_CONFIG_HW {
     pub _bitfield: u32,
     pub hWndOwner: super::super::Foundation::HWND,
@@ -5581,6 +5749,74 @@ pub struct PDH_BROWSE_DLG_CONFIG_HW {
     pub dwDefaultDetailLevel: PERF_DETAIL,
     pub szDialogBoxCaption: windows_core::PWSTR,
 }
+impl PDH_BROWSE_DLG_CONFIG_HW {
+    pub const fn bIncludeInstanceIndex(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_bIncludeInstanceIndex(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn bSingleCounterPerAdd(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_bSingleCounterPerAdd(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn bSingleCounterPerDialog(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_bSingleCounterPerDialog(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn bLocalCountersOnly(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_bLocalCountersOnly(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn bWildCardInstances(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_bWildCardInstances(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn bHideDetailBox(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_bHideDetailBox(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn bInitializePath(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_bInitializePath(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn bDisableMachineSelection(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_bDisableMachineSelection(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn bIncludeCostlyObjects(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_bIncludeCostlyObjects(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn bShowObjectBrowser(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_bShowObjectBrowser(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn bReserved(&self) -> u32 {
+        (self._bitfield >> 10) & 4194303
+    }
+    pub fn set_bReserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(4194303 << 10)) | ((value & 4194303) << 10);
+    }
+}
 impl windows_core::TypeKind for PDH_BROWSE_DLG_CONFIG_HW {
     type TypeKind = windows_core::CopyType;
 }
@@ -5590,7 +5826,8 @@ impl Default for PDH_BROWSE_DLG_CONFIG_HW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PDH_BROWSE_DLG_CONFIG_W {
     pub _bitfield: u32,
     pub hWndOwner: super::super::Foundation::HWND,
@@ -5603,6 +5840,74 @@ pub struct PDH_BROWSE_DLG_CONFIG_W {
     pub dwDefaultDetailLevel: PERF_DETAIL,
     pub szDialogBoxCaption: windows_core::PWSTR,
 }
+impl PDH_BROWSE_DLG_CONFIG_W {
+    pub const fn bIncludeInstanceIndex(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_bIncludeInstanceIndex(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn bSingleCounterPerAdd(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_bSingleCounterPerAdd(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn bSingleCounterPerDialog(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_bSingleCounterPerDialog(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn bLocalCountersOnly(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_bLocalCountersOnly(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn bWildCardInstances(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_bWildCardInstances(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn bHideDetailBox(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_bHideDetailBox(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn bInitializePath(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_bInitializePath(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn bDisableMachineSelection(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_bDisableMachineSelection(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn bIncludeCostlyObjects(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_bIncludeCostlyObjects(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn bShowObjectBrowser(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_bShowObjectBrowser(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn bReserved(&self) -> u32 {
+        (self._bitfield >> 10) & 4194303
+    }
+    pub fn set_bReserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(4194303 << 10)) | ((value & 4194303) << 10);
+    }
+}
 impl windows_core::TypeKind for PDH_BROWSE_DLG_CONFIG_W {
     type TypeKind = windows_core::CopyType;
 }
@@ -5651,7 +5956,8 @@ impl Default for PDH_COUNTER_INFO_A_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PDH_COUNTER_INFO_A_0_0 {
     pub szMachineName: windows_core::PSTR,
     pub szObjectName: windows_core::PSTR,
@@ -5708,7 +6014,8 @@ impl Default for PDH_COUNTER_INFO_W_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PDH_COUNTER_INFO_W_0_0 {
     pub szMachineName: windows_core::PWSTR,
     pub szObjectName: windows_core::PWSTR,
@@ -5726,7 +6033,8 @@ impl Default for PDH_COUNTER_INFO_W_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PDH_COUNTER_PATH_ELEMENTS_A {
     pub szMachineName: windows_core::PSTR,
     pub szObjectName: windows_core::PSTR,
@@ -5744,7 +6052,8 @@ impl Default for PDH_COUNTER_PATH_ELEMENTS_A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PDH_COUNTER_PATH_ELEMENTS_W {
     pub szMachineName: windows_core::PWSTR,
     pub szObjectName: windows_core::PWSTR,
@@ -5762,7 +6071,8 @@ impl Default for PDH_COUNTER_PATH_ELEMENTS_W {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PDH_DATA_ITEM_PATH_ELEMENTS_A {
     pub szMachineName: windows_core::PSTR,
     pub ObjectGUID: windows_core::GUID,
@@ -5778,7 +6088,8 @@ impl Default for PDH_DATA_ITEM_PATH_ELEMENTS_A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PDH_DATA_ITEM_PATH_ELEMENTS_W {
     pub szMachineName: windows_core::PWSTR,
     pub ObjectGUID: windows_core::GUID,
@@ -5888,7 +6199,8 @@ impl Default for PDH_LOG_SERVICE_QUERY_INFO_A_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PDH_LOG_SERVICE_QUERY_INFO_A_0_0 {
     pub PdlAutoNameInterval: u32,
     pub PdlAutoNameUnits: u32,
@@ -5908,7 +6220,8 @@ impl Default for PDH_LOG_SERVICE_QUERY_INFO_A_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PDH_LOG_SERVICE_QUERY_INFO_A_0_1 {
     pub TlNumberOfBuffers: u32,
     pub TlMinimumBuffers: u32,
@@ -5965,7 +6278,8 @@ impl Default for PDH_LOG_SERVICE_QUERY_INFO_W_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PDH_LOG_SERVICE_QUERY_INFO_W_0_0 {
     pub PdlAutoNameInterval: u32,
     pub PdlAutoNameUnits: u32,
@@ -5985,7 +6299,8 @@ impl Default for PDH_LOG_SERVICE_QUERY_INFO_W_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PDH_LOG_SERVICE_QUERY_INFO_W_0_1 {
     pub TlNumberOfBuffers: u32,
     pub TlMinimumBuffers: u32,
@@ -6007,7 +6322,8 @@ impl Default for PDH_LOG_SERVICE_QUERY_INFO_W_0_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PDH_RAW_COUNTER {
     pub CStatus: u32,
     pub TimeStamp: super::super::Foundation::FILETIME,
@@ -6024,7 +6340,8 @@ impl Default for PDH_RAW_COUNTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PDH_RAW_COUNTER_ITEM_A {
     pub szName: windows_core::PSTR,
     pub RawValue: PDH_RAW_COUNTER,
@@ -6038,7 +6355,8 @@ impl Default for PDH_RAW_COUNTER_ITEM_A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PDH_RAW_COUNTER_ITEM_W {
     pub szName: windows_core::PWSTR,
     pub RawValue: PDH_RAW_COUNTER,
@@ -6052,7 +6370,8 @@ impl Default for PDH_RAW_COUNTER_ITEM_W {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PDH_RAW_LOG_RECORD {
     pub dwStructureSize: u32,
     pub dwRecordType: PDH_LOG_TYPE,
@@ -6085,7 +6404,8 @@ impl Default for PDH_STATISTICS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PDH_TIME_INFO {
     pub StartTime: i64,
     pub EndTime: i64,
@@ -6100,7 +6420,8 @@ impl Default for PDH_TIME_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PERF_COUNTERSET_INFO {
     pub CounterSetGuid: windows_core::GUID,
     pub ProviderGuid: windows_core::GUID,
@@ -6116,7 +6437,8 @@ impl Default for PERF_COUNTERSET_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PERF_COUNTERSET_INSTANCE {
     pub CounterSetGuid: windows_core::GUID,
     pub dwSize: u32,
@@ -6133,7 +6455,8 @@ impl Default for PERF_COUNTERSET_INSTANCE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PERF_COUNTERSET_REG_INFO {
     pub CounterSetGuid: windows_core::GUID,
     pub CounterSetType: u32,
@@ -6150,7 +6473,8 @@ impl Default for PERF_COUNTERSET_REG_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PERF_COUNTER_BLOCK {
     pub ByteLength: u32,
 }
@@ -6163,7 +6487,8 @@ impl Default for PERF_COUNTER_BLOCK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PERF_COUNTER_DATA {
     pub dwDataSize: u32,
     pub dwSize: u32,
@@ -6178,7 +6503,8 @@ impl Default for PERF_COUNTER_DATA {
 }
 #[repr(C)]
 #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec", target_arch = "x86_64"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PERF_COUNTER_DEFINITION {
     pub ByteLength: u32,
     pub CounterNameTitleIndex: u32,
@@ -6203,7 +6529,8 @@ impl Default for PERF_COUNTER_DEFINITION {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PERF_COUNTER_DEFINITION {
     pub ByteLength: u32,
     pub CounterNameTitleIndex: u32,
@@ -6227,7 +6554,8 @@ impl Default for PERF_COUNTER_DEFINITION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PERF_COUNTER_HEADER {
     pub dwStatus: u32,
     pub dwType: PerfCounterDataType,
@@ -6243,7 +6571,8 @@ impl Default for PERF_COUNTER_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PERF_COUNTER_IDENTIFIER {
     pub CounterSetGuid: windows_core::GUID,
     pub Status: u32,
@@ -6262,7 +6591,8 @@ impl Default for PERF_COUNTER_IDENTIFIER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PERF_COUNTER_IDENTITY {
     pub CounterSetGuid: windows_core::GUID,
     pub BufferSize: u32,
@@ -6281,7 +6611,8 @@ impl Default for PERF_COUNTER_IDENTITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PERF_COUNTER_INFO {
     pub CounterId: u32,
     pub Type: u32,
@@ -6300,7 +6631,8 @@ impl Default for PERF_COUNTER_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PERF_COUNTER_REG_INFO {
     pub CounterId: u32,
     pub Type: u32,
@@ -6323,7 +6655,8 @@ impl Default for PERF_COUNTER_REG_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PERF_DATA_BLOCK {
     pub Signature: [u16; 4],
     pub LittleEndian: u32,
@@ -6349,7 +6682,8 @@ impl Default for PERF_DATA_BLOCK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PERF_DATA_HEADER {
     pub dwTotalSize: u32,
     pub dwNumCounters: u32,
@@ -6367,7 +6701,8 @@ impl Default for PERF_DATA_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PERF_INSTANCE_DEFINITION {
     pub ByteLength: u32,
     pub ParentObjectTitleIndex: u32,
@@ -6385,7 +6720,8 @@ impl Default for PERF_INSTANCE_DEFINITION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PERF_INSTANCE_HEADER {
     pub Size: u32,
     pub InstanceId: u32,
@@ -6399,7 +6735,8 @@ impl Default for PERF_INSTANCE_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PERF_MULTI_COUNTERS {
     pub dwSize: u32,
     pub dwCounters: u32,
@@ -6413,7 +6750,8 @@ impl Default for PERF_MULTI_COUNTERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PERF_MULTI_INSTANCES {
     pub dwTotalSize: u32,
     pub dwInstances: u32,
@@ -6428,7 +6766,8 @@ impl Default for PERF_MULTI_INSTANCES {
 }
 #[repr(C)]
 #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec", target_arch = "x86_64"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PERF_OBJECT_TYPE {
     pub TotalByteLength: u32,
     pub DefinitionLength: u32,
@@ -6457,7 +6796,8 @@ impl Default for PERF_OBJECT_TYPE {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PERF_OBJECT_TYPE {
     pub TotalByteLength: u32,
     pub DefinitionLength: u32,
@@ -6485,7 +6825,8 @@ impl Default for PERF_OBJECT_TYPE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PERF_PROVIDER_CONTEXT {
     pub ContextSize: u32,
     pub Reserved: u32,
@@ -6503,7 +6844,8 @@ impl Default for PERF_PROVIDER_CONTEXT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PERF_STRING_BUFFER_HEADER {
     pub dwSize: u32,
     pub dwCounters: u32,
@@ -6517,7 +6859,8 @@ impl Default for PERF_STRING_BUFFER_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PERF_STRING_COUNTER_HEADER {
     pub dwCounterId: u32,
     pub dwOffset: u32,
@@ -6549,5 +6892,8 @@ pub type PLA_CABEXTRACT_CALLBACK = Option<unsafe extern "system" fn(filename: wi
 pub type PM_CLOSE_PROC = Option<unsafe extern "system" fn() -> u32>;
 pub type PM_COLLECT_PROC = Option<unsafe extern "system" fn(pvaluename: windows_core::PCWSTR, ppdata: *mut *mut core::ffi::c_void, pcbtotalbytes: *mut u32, pnumobjecttypes: *mut u32) -> u32>;
 pub type PM_OPEN_PROC = Option<unsafe extern "system" fn(pcontext: windows_core::PCWSTR) -> u32>;
+core::include!(core::concat!(core::env!("CARGO_MANIFEST_DIR"), "/src/includes/", "Win32/System/Performance/CounterQuery.rs"));
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
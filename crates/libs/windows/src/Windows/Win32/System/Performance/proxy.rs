@@ -0,0 +1,2857 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for DICounterItem_Vtbl {
+    const PROXY: Self = { Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<DICounterItem as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for DILogFileItem_Vtbl {
+    const PROXY: Self = { Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<DILogFileItem as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for DISystemMonitor_Vtbl {
+    const PROXY: Self = { Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<DISystemMonitor as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for DISystemMonitorEvents_Vtbl {
+    const PROXY: Self = { Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<DISystemMonitorEvents as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for DISystemMonitorInternal_Vtbl {
+    const PROXY: Self = { Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<DISystemMonitorInternal as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAlertDataCollector_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AlertThresholds(this: *mut core::ffi::c_void, alerts: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlertDataCollector", "AlertThresholds", 32usize, |this| ((**(this as *const *const IAlertDataCollector_Vtbl)).AlertThresholds)(this, alerts), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetAlertThresholds(this: *mut core::ffi::c_void, alerts: *const super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlertDataCollector", "SetAlertThresholds", 33usize, |this| ((**(this as *const *const IAlertDataCollector_Vtbl)).SetAlertThresholds)(this, alerts), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventLog(this: *mut core::ffi::c_void, log: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlertDataCollector", "EventLog", 34usize, |this| ((**(this as *const *const IAlertDataCollector_Vtbl)).EventLog)(this, log), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventLog(this: *mut core::ffi::c_void, log: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlertDataCollector", "SetEventLog", 35usize, |this| ((**(this as *const *const IAlertDataCollector_Vtbl)).SetEventLog)(this, log), |result| Some(*result))
+        }
+        unsafe extern "system" fn SampleInterval(this: *mut core::ffi::c_void, interval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlertDataCollector", "SampleInterval", 36usize, |this| ((**(this as *const *const IAlertDataCollector_Vtbl)).SampleInterval)(this, interval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSampleInterval(this: *mut core::ffi::c_void, interval: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlertDataCollector", "SetSampleInterval", 37usize, |this| ((**(this as *const *const IAlertDataCollector_Vtbl)).SetSampleInterval)(this, interval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Task(this: *mut core::ffi::c_void, task: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlertDataCollector", "Task", 38usize, |this| ((**(this as *const *const IAlertDataCollector_Vtbl)).Task)(this, task), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTask(this: *mut core::ffi::c_void, task: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlertDataCollector", "SetTask", 39usize, |this| ((**(this as *const *const IAlertDataCollector_Vtbl)).SetTask)(this, task), |result| Some(*result))
+        }
+        unsafe extern "system" fn TaskRunAsSelf(this: *mut core::ffi::c_void, runasself: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlertDataCollector", "TaskRunAsSelf", 40usize, |this| ((**(this as *const *const IAlertDataCollector_Vtbl)).TaskRunAsSelf)(this, runasself), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTaskRunAsSelf(this: *mut core::ffi::c_void, runasself: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlertDataCollector", "SetTaskRunAsSelf", 41usize, |this| ((**(this as *const *const IAlertDataCollector_Vtbl)).SetTaskRunAsSelf)(this, runasself), |result| Some(*result))
+        }
+        unsafe extern "system" fn TaskArguments(this: *mut core::ffi::c_void, task: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlertDataCollector", "TaskArguments", 42usize, |this| ((**(this as *const *const IAlertDataCollector_Vtbl)).TaskArguments)(this, task), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTaskArguments(this: *mut core::ffi::c_void, task: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlertDataCollector", "SetTaskArguments", 43usize, |this| ((**(this as *const *const IAlertDataCollector_Vtbl)).SetTaskArguments)(this, task), |result| Some(*result))
+        }
+        unsafe extern "system" fn TaskUserTextArguments(this: *mut core::ffi::c_void, task: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlertDataCollector", "TaskUserTextArguments", 44usize, |this| ((**(this as *const *const IAlertDataCollector_Vtbl)).TaskUserTextArguments)(this, task), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTaskUserTextArguments(this: *mut core::ffi::c_void, task: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlertDataCollector", "SetTaskUserTextArguments", 45usize, |this| ((**(this as *const *const IAlertDataCollector_Vtbl)).SetTaskUserTextArguments)(this, task), |result| Some(*result))
+        }
+        unsafe extern "system" fn TriggerDataCollectorSet(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlertDataCollector", "TriggerDataCollectorSet", 46usize, |this| ((**(this as *const *const IAlertDataCollector_Vtbl)).TriggerDataCollectorSet)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTriggerDataCollectorSet(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlertDataCollector", "SetTriggerDataCollectorSet", 47usize, |this| ((**(this as *const *const IAlertDataCollector_Vtbl)).SetTriggerDataCollectorSet)(this, name), |result| Some(*result))
+        }
+        Self {
+            base__: <IDataCollector_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            AlertThresholds,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AlertThresholds: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetAlertThresholds,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetAlertThresholds: 0,
+            EventLog,
+            SetEventLog,
+            SampleInterval,
+            SetSampleInterval,
+            Task,
+            SetTask,
+            TaskRunAsSelf,
+            SetTaskRunAsSelf,
+            TaskArguments,
+            SetTaskArguments,
+            TaskUserTextArguments,
+            SetTaskUserTextArguments,
+            TriggerDataCollectorSet,
+            SetTriggerDataCollectorSet,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAlertDataCollector as windows_core::Interface>::IID || <IDataCollector_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IApiTracingDataCollector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LogApiNamesOnly(this: *mut core::ffi::c_void, logapinames: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApiTracingDataCollector", "LogApiNamesOnly", 32usize, |this| ((**(this as *const *const IApiTracingDataCollector_Vtbl)).LogApiNamesOnly)(this, logapinames), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLogApiNamesOnly(this: *mut core::ffi::c_void, logapinames: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApiTracingDataCollector", "SetLogApiNamesOnly", 33usize, |this| ((**(this as *const *const IApiTracingDataCollector_Vtbl)).SetLogApiNamesOnly)(this, logapinames), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogApisRecursively(this: *mut core::ffi::c_void, logrecursively: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApiTracingDataCollector", "LogApisRecursively", 34usize, |this| ((**(this as *const *const IApiTracingDataCollector_Vtbl)).LogApisRecursively)(this, logrecursively), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLogApisRecursively(this: *mut core::ffi::c_void, logrecursively: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApiTracingDataCollector", "SetLogApisRecursively", 35usize, |this| ((**(this as *const *const IApiTracingDataCollector_Vtbl)).SetLogApisRecursively)(this, logrecursively), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExePath(this: *mut core::ffi::c_void, exepath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApiTracingDataCollector", "ExePath", 36usize, |this| ((**(this as *const *const IApiTracingDataCollector_Vtbl)).ExePath)(this, exepath), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExePath(this: *mut core::ffi::c_void, exepath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApiTracingDataCollector", "SetExePath", 37usize, |this| ((**(this as *const *const IApiTracingDataCollector_Vtbl)).SetExePath)(this, exepath), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogFilePath(this: *mut core::ffi::c_void, logfilepath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApiTracingDataCollector", "LogFilePath", 38usize, |this| ((**(this as *const *const IApiTracingDataCollector_Vtbl)).LogFilePath)(this, logfilepath), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLogFilePath(this: *mut core::ffi::c_void, logfilepath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApiTracingDataCollector", "SetLogFilePath", 39usize, |this| ((**(this as *const *const IApiTracingDataCollector_Vtbl)).SetLogFilePath)(this, logfilepath), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn IncludeModules(this: *mut core::ffi::c_void, includemodules: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApiTracingDataCollector", "IncludeModules", 40usize, |this| ((**(this as *const *const IApiTracingDataCollector_Vtbl)).IncludeModules)(this, includemodules), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetIncludeModules(this: *mut core::ffi::c_void, includemodules: *const super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApiTracingDataCollector", "SetIncludeModules", 41usize, |this| ((**(this as *const *const IApiTracingDataCollector_Vtbl)).SetIncludeModules)(this, includemodules), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn IncludeApis(this: *mut core::ffi::c_void, includeapis: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApiTracingDataCollector", "IncludeApis", 42usize, |this| ((**(this as *const *const IApiTracingDataCollector_Vtbl)).IncludeApis)(this, includeapis), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetIncludeApis(this: *mut core::ffi::c_void, includeapis: *const super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApiTracingDataCollector", "SetIncludeApis", 43usize, |this| ((**(this as *const *const IApiTracingDataCollector_Vtbl)).SetIncludeApis)(this, includeapis), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ExcludeApis(this: *mut core::ffi::c_void, excludeapis: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApiTracingDataCollector", "ExcludeApis", 44usize, |this| ((**(this as *const *const IApiTracingDataCollector_Vtbl)).ExcludeApis)(this, excludeapis), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetExcludeApis(this: *mut core::ffi::c_void, excludeapis: *const super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IApiTracingDataCollector", "SetExcludeApis", 45usize, |this| ((**(this as *const *const IApiTracingDataCollector_Vtbl)).SetExcludeApis)(this, excludeapis), |result| Some(*result))
+        }
+        Self {
+            base__: <IDataCollector_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LogApiNamesOnly,
+            SetLogApiNamesOnly,
+            LogApisRecursively,
+            SetLogApisRecursively,
+            ExePath,
+            SetExePath,
+            LogFilePath,
+            SetLogFilePath,
+            #[cfg(feature = "Win32_System_Com")]
+            IncludeModules,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            IncludeModules: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetIncludeModules,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetIncludeModules: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            IncludeApis,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            IncludeApis: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetIncludeApis,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetIncludeApis: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ExcludeApis,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ExcludeApis: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetExcludeApis,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetExcludeApis: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApiTracingDataCollector as windows_core::Interface>::IID || <IDataCollector_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IConfigurationDataCollector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FileMaxCount(this: *mut core::ffi::c_void, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigurationDataCollector", "FileMaxCount", 32usize, |this| ((**(this as *const *const IConfigurationDataCollector_Vtbl)).FileMaxCount)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFileMaxCount(this: *mut core::ffi::c_void, count: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigurationDataCollector", "SetFileMaxCount", 33usize, |this| ((**(this as *const *const IConfigurationDataCollector_Vtbl)).SetFileMaxCount)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn FileMaxRecursiveDepth(this: *mut core::ffi::c_void, depth: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigurationDataCollector", "FileMaxRecursiveDepth", 34usize, |this| ((**(this as *const *const IConfigurationDataCollector_Vtbl)).FileMaxRecursiveDepth)(this, depth), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFileMaxRecursiveDepth(this: *mut core::ffi::c_void, depth: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigurationDataCollector", "SetFileMaxRecursiveDepth", 35usize, |this| ((**(this as *const *const IConfigurationDataCollector_Vtbl)).SetFileMaxRecursiveDepth)(this, depth), |result| Some(*result))
+        }
+        unsafe extern "system" fn FileMaxTotalSize(this: *mut core::ffi::c_void, size: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigurationDataCollector", "FileMaxTotalSize", 36usize, |this| ((**(this as *const *const IConfigurationDataCollector_Vtbl)).FileMaxTotalSize)(this, size), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFileMaxTotalSize(this: *mut core::ffi::c_void, size: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigurationDataCollector", "SetFileMaxTotalSize", 37usize, |this| ((**(this as *const *const IConfigurationDataCollector_Vtbl)).SetFileMaxTotalSize)(this, size), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Files(this: *mut core::ffi::c_void, files: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigurationDataCollector", "Files", 38usize, |this| ((**(this as *const *const IConfigurationDataCollector_Vtbl)).Files)(this, files), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetFiles(this: *mut core::ffi::c_void, files: *const super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigurationDataCollector", "SetFiles", 39usize, |this| ((**(this as *const *const IConfigurationDataCollector_Vtbl)).SetFiles)(this, files), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ManagementQueries(this: *mut core::ffi::c_void, queries: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigurationDataCollector", "ManagementQueries", 40usize, |this| ((**(this as *const *const IConfigurationDataCollector_Vtbl)).ManagementQueries)(this, queries), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetManagementQueries(this: *mut core::ffi::c_void, queries: *const super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigurationDataCollector", "SetManagementQueries", 41usize, |this| ((**(this as *const *const IConfigurationDataCollector_Vtbl)).SetManagementQueries)(this, queries), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryNetworkAdapters(this: *mut core::ffi::c_void, network: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigurationDataCollector", "QueryNetworkAdapters", 42usize, |this| ((**(this as *const *const IConfigurationDataCollector_Vtbl)).QueryNetworkAdapters)(this, network), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQueryNetworkAdapters(this: *mut core::ffi::c_void, network: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigurationDataCollector", "SetQueryNetworkAdapters", 43usize, |this| ((**(this as *const *const IConfigurationDataCollector_Vtbl)).SetQueryNetworkAdapters)(this, network), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RegistryKeys(this: *mut core::ffi::c_void, query: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigurationDataCollector", "RegistryKeys", 44usize, |this| ((**(this as *const *const IConfigurationDataCollector_Vtbl)).RegistryKeys)(this, query), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetRegistryKeys(this: *mut core::ffi::c_void, query: *const super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigurationDataCollector", "SetRegistryKeys", 45usize, |this| ((**(this as *const *const IConfigurationDataCollector_Vtbl)).SetRegistryKeys)(this, query), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegistryMaxRecursiveDepth(this: *mut core::ffi::c_void, depth: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigurationDataCollector", "RegistryMaxRecursiveDepth", 46usize, |this| ((**(this as *const *const IConfigurationDataCollector_Vtbl)).RegistryMaxRecursiveDepth)(this, depth), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRegistryMaxRecursiveDepth(this: *mut core::ffi::c_void, depth: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigurationDataCollector", "SetRegistryMaxRecursiveDepth", 47usize, |this| ((**(this as *const *const IConfigurationDataCollector_Vtbl)).SetRegistryMaxRecursiveDepth)(this, depth), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemStateFile(this: *mut core::ffi::c_void, filename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigurationDataCollector", "SystemStateFile", 48usize, |this| ((**(this as *const *const IConfigurationDataCollector_Vtbl)).SystemStateFile)(this, filename), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSystemStateFile(this: *mut core::ffi::c_void, filename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConfigurationDataCollector", "SetSystemStateFile", 49usize, |this| ((**(this as *const *const IConfigurationDataCollector_Vtbl)).SetSystemStateFile)(this, filename), |result| Some(*result))
+        }
+        Self {
+            base__: <IDataCollector_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FileMaxCount,
+            SetFileMaxCount,
+            FileMaxRecursiveDepth,
+            SetFileMaxRecursiveDepth,
+            FileMaxTotalSize,
+            SetFileMaxTotalSize,
+            #[cfg(feature = "Win32_System_Com")]
+            Files,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Files: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetFiles,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetFiles: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ManagementQueries,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ManagementQueries: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetManagementQueries,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetManagementQueries: 0,
+            QueryNetworkAdapters,
+            SetQueryNetworkAdapters,
+            #[cfg(feature = "Win32_System_Com")]
+            RegistryKeys,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RegistryKeys: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetRegistryKeys,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetRegistryKeys: 0,
+            RegistryMaxRecursiveDepth,
+            SetRegistryMaxRecursiveDepth,
+            SystemStateFile,
+            SetSystemStateFile,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConfigurationDataCollector as windows_core::Interface>::IID || <IDataCollector_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICounterItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, pdblvalue: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICounterItem", "Value", 3usize, |this| ((**(this as *const *const ICounterItem_Vtbl)).Value)(this, pdblvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColor(this: *mut core::ffi::c_void, color: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICounterItem", "SetColor", 4usize, |this| ((**(this as *const *const ICounterItem_Vtbl)).SetColor)(this, color), |result| Some(*result))
+        }
+        unsafe extern "system" fn Color(this: *mut core::ffi::c_void, pcolor: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICounterItem", "Color", 5usize, |this| ((**(this as *const *const ICounterItem_Vtbl)).Color)(this, pcolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWidth(this: *mut core::ffi::c_void, iwidth: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICounterItem", "SetWidth", 6usize, |this| ((**(this as *const *const ICounterItem_Vtbl)).SetWidth)(this, iwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn Width(this: *mut core::ffi::c_void, pivalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICounterItem", "Width", 7usize, |this| ((**(this as *const *const ICounterItem_Vtbl)).Width)(this, pivalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLineStyle(this: *mut core::ffi::c_void, ilinestyle: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICounterItem", "SetLineStyle", 8usize, |this| ((**(this as *const *const ICounterItem_Vtbl)).SetLineStyle)(this, ilinestyle), |result| Some(*result))
+        }
+        unsafe extern "system" fn LineStyle(this: *mut core::ffi::c_void, pivalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICounterItem", "LineStyle", 9usize, |this| ((**(this as *const *const ICounterItem_Vtbl)).LineStyle)(this, pivalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScaleFactor(this: *mut core::ffi::c_void, iscale: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICounterItem", "SetScaleFactor", 10usize, |this| ((**(this as *const *const ICounterItem_Vtbl)).SetScaleFactor)(this, iscale), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScaleFactor(this: *mut core::ffi::c_void, pivalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICounterItem", "ScaleFactor", 11usize, |this| ((**(this as *const *const ICounterItem_Vtbl)).ScaleFactor)(this, pivalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, pstrvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICounterItem", "Path", 12usize, |this| ((**(this as *const *const ICounterItem_Vtbl)).Path)(this, pstrvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, value: *mut f64, status: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICounterItem", "GetValue", 13usize, |this| ((**(this as *const *const ICounterItem_Vtbl)).GetValue)(this, value, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatistics(this: *mut core::ffi::c_void, max: *mut f64, min: *mut f64, avg: *mut f64, status: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICounterItem", "GetStatistics", 14usize, |this| ((**(this as *const *const ICounterItem_Vtbl)).GetStatistics)(this, max, min, avg, status), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Value,
+            SetColor,
+            Color,
+            SetWidth,
+            Width,
+            SetLineStyle,
+            LineStyle,
+            SetScaleFactor,
+            ScaleFactor,
+            Path,
+            GetValue,
+            GetStatistics,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICounterItem as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICounterItem2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSelected(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICounterItem2", "SetSelected", 15usize, |this| ((**(this as *const *const ICounterItem2_Vtbl)).SetSelected)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Selected(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICounterItem2", "Selected", 16usize, |this| ((**(this as *const *const ICounterItem2_Vtbl)).Selected)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVisible(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICounterItem2", "SetVisible", 17usize, |this| ((**(this as *const *const ICounterItem2_Vtbl)).SetVisible)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Visible(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICounterItem2", "Visible", 18usize, |this| ((**(this as *const *const ICounterItem2_Vtbl)).Visible)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataAt(this: *mut core::ffi::c_void, iindex: i32, iwhich: SysmonDataType, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICounterItem2", "GetDataAt", 19usize, |this| ((**(this as *const *const ICounterItem2_Vtbl)).GetDataAt)(this, iindex, iwhich, pvariant), |result| Some(*result))
+        }
+        Self { base__: <ICounterItem_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetSelected, Selected, SetVisible, Visible, GetDataAt }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICounterItem2 as windows_core::Interface>::IID || <ICounterItem_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICounters_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plong: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICounters", "Count", 7usize, |this| ((**(this as *const *const ICounters_Vtbl)).Count)(this, plong), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppiunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICounters", "_NewEnum", 8usize, |this| ((**(this as *const *const ICounters_Vtbl))._NewEnum)(this, ppiunk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>, ppi: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICounters", "get_Item", 9usize, |this| ((**(this as *const *const ICounters_Vtbl)).get_Item)(this, index, ppi), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pathname: core::mem::MaybeUninit<windows_core::BSTR>, ppi: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICounters", "Add", 10usize, |this| ((**(this as *const *const ICounters_Vtbl)).Add)(this, pathname, ppi), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICounters", "Remove", 11usize, |this| ((**(this as *const *const ICounters_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICounters as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDataCollector_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DataCollectorSet(this: *mut core::ffi::c_void, group: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollector", "DataCollectorSet", 7usize, |this| ((**(this as *const *const IDataCollector_Vtbl)).DataCollectorSet)(this, group), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetDataCollectorSet(this: *mut core::ffi::c_void, group: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollector", "SetDataCollectorSet", 8usize, |this| ((**(this as *const *const IDataCollector_Vtbl)).SetDataCollectorSet)(this, group), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataCollectorType(this: *mut core::ffi::c_void, r#type: *mut DataCollectorType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollector", "DataCollectorType", 9usize, |this| ((**(this as *const *const IDataCollector_Vtbl)).DataCollectorType)(this, r#type), |result| Some(*result))
+        }
+        unsafe extern "system" fn FileName(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollector", "FileName", 10usize, |this| ((**(this as *const *const IDataCollector_Vtbl)).FileName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFileName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollector", "SetFileName", 11usize, |this| ((**(this as *const *const IDataCollector_Vtbl)).SetFileName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn FileNameFormat(this: *mut core::ffi::c_void, format: *mut AutoPathFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollector", "FileNameFormat", 12usize, |this| ((**(this as *const *const IDataCollector_Vtbl)).FileNameFormat)(this, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFileNameFormat(this: *mut core::ffi::c_void, format: AutoPathFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollector", "SetFileNameFormat", 13usize, |this| ((**(this as *const *const IDataCollector_Vtbl)).SetFileNameFormat)(this, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn FileNameFormatPattern(this: *mut core::ffi::c_void, pattern: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollector", "FileNameFormatPattern", 14usize, |this| ((**(this as *const *const IDataCollector_Vtbl)).FileNameFormatPattern)(this, pattern), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFileNameFormatPattern(this: *mut core::ffi::c_void, pattern: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollector", "SetFileNameFormatPattern", 15usize, |this| ((**(this as *const *const IDataCollector_Vtbl)).SetFileNameFormatPattern)(this, pattern), |result| Some(*result))
+        }
+        unsafe extern "system" fn LatestOutputLocation(this: *mut core::ffi::c_void, path: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollector", "LatestOutputLocation", 16usize, |this| ((**(this as *const *const IDataCollector_Vtbl)).LatestOutputLocation)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLatestOutputLocation(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollector", "SetLatestOutputLocation", 17usize, |this| ((**(this as *const *const IDataCollector_Vtbl)).SetLatestOutputLocation)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogAppend(this: *mut core::ffi::c_void, append: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollector", "LogAppend", 18usize, |this| ((**(this as *const *const IDataCollector_Vtbl)).LogAppend)(this, append), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLogAppend(this: *mut core::ffi::c_void, append: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollector", "SetLogAppend", 19usize, |this| ((**(this as *const *const IDataCollector_Vtbl)).SetLogAppend)(this, append), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogCircular(this: *mut core::ffi::c_void, circular: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollector", "LogCircular", 20usize, |this| ((**(this as *const *const IDataCollector_Vtbl)).LogCircular)(this, circular), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLogCircular(this: *mut core::ffi::c_void, circular: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollector", "SetLogCircular", 21usize, |this| ((**(this as *const *const IDataCollector_Vtbl)).SetLogCircular)(this, circular), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogOverwrite(this: *mut core::ffi::c_void, overwrite: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollector", "LogOverwrite", 22usize, |this| ((**(this as *const *const IDataCollector_Vtbl)).LogOverwrite)(this, overwrite), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLogOverwrite(this: *mut core::ffi::c_void, overwrite: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollector", "SetLogOverwrite", 23usize, |this| ((**(this as *const *const IDataCollector_Vtbl)).SetLogOverwrite)(this, overwrite), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollector", "Name", 24usize, |this| ((**(this as *const *const IDataCollector_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollector", "SetName", 25usize, |this| ((**(this as *const *const IDataCollector_Vtbl)).SetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutputLocation(this: *mut core::ffi::c_void, path: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollector", "OutputLocation", 26usize, |this| ((**(this as *const *const IDataCollector_Vtbl)).OutputLocation)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn Index(this: *mut core::ffi::c_void, index: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollector", "Index", 27usize, |this| ((**(this as *const *const IDataCollector_Vtbl)).Index)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIndex(this: *mut core::ffi::c_void, index: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollector", "SetIndex", 28usize, |this| ((**(this as *const *const IDataCollector_Vtbl)).SetIndex)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Xml(this: *mut core::ffi::c_void, xml: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollector", "Xml", 29usize, |this| ((**(this as *const *const IDataCollector_Vtbl)).Xml)(this, xml), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetXml(this: *mut core::ffi::c_void, xml: core::mem::MaybeUninit<windows_core::BSTR>, validation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollector", "SetXml", 30usize, |this| ((**(this as *const *const IDataCollector_Vtbl)).SetXml)(this, xml, validation), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateOutputLocation(this: *mut core::ffi::c_void, latest: super::super::Foundation::VARIANT_BOOL, location: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollector", "CreateOutputLocation", 31usize, |this| ((**(this as *const *const IDataCollector_Vtbl)).CreateOutputLocation)(this, latest, location), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            DataCollectorSet,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DataCollectorSet: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetDataCollectorSet,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetDataCollectorSet: 0,
+            DataCollectorType,
+            FileName,
+            SetFileName,
+            FileNameFormat,
+            SetFileNameFormat,
+            FileNameFormatPattern,
+            SetFileNameFormatPattern,
+            LatestOutputLocation,
+            SetLatestOutputLocation,
+            LogAppend,
+            SetLogAppend,
+            LogCircular,
+            SetLogCircular,
+            LogOverwrite,
+            SetLogOverwrite,
+            Name,
+            SetName,
+            OutputLocation,
+            Index,
+            SetIndex,
+            Xml,
+            #[cfg(feature = "Win32_System_Com")]
+            SetXml,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetXml: 0,
+            CreateOutputLocation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataCollector as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDataCollectorCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorCollection", "Count", 7usize, |this| ((**(this as *const *const IDataCollectorCollection_Vtbl)).Count)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>, collector: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorCollection", "get_Item", 8usize, |this| ((**(this as *const *const IDataCollectorCollection_Vtbl)).get_Item)(this, index, collector), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const IDataCollectorCollection_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, collector: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorCollection", "Add", 10usize, |this| ((**(this as *const *const IDataCollectorCollection_Vtbl)).Add)(this, collector), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, collector: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorCollection", "Remove", 11usize, |this| ((**(this as *const *const IDataCollectorCollection_Vtbl)).Remove)(this, collector), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorCollection", "Clear", 12usize, |this| ((**(this as *const *const IDataCollectorCollection_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddRange(this: *mut core::ffi::c_void, collectors: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorCollection", "AddRange", 13usize, |this| ((**(this as *const *const IDataCollectorCollection_Vtbl)).AddRange)(this, collectors), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateDataCollectorFromXml(this: *mut core::ffi::c_void, bstrxml: core::mem::MaybeUninit<windows_core::BSTR>, pvalidation: *mut *mut core::ffi::c_void, pcollector: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorCollection", "CreateDataCollectorFromXml", 14usize, |this| ((**(this as *const *const IDataCollectorCollection_Vtbl)).CreateDataCollectorFromXml)(this, bstrxml, pvalidation, pcollector), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateDataCollector(this: *mut core::ffi::c_void, r#type: DataCollectorType, collector: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorCollection", "CreateDataCollector", 15usize, |this| ((**(this as *const *const IDataCollectorCollection_Vtbl)).CreateDataCollector)(this, r#type, collector), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            Clear,
+            #[cfg(feature = "Win32_System_Com")]
+            AddRange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddRange: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateDataCollectorFromXml,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateDataCollectorFromXml: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateDataCollector,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateDataCollector: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataCollectorCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDataCollectorSet_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DataCollectors(this: *mut core::ffi::c_void, collectors: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "DataCollectors", 7usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).DataCollectors)(this, collectors), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, seconds: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "Duration", 8usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).Duration)(this, seconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDuration(this: *mut core::ffi::c_void, seconds: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SetDuration", 9usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SetDuration)(this, seconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, description: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "Description", 10usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).Description)(this, description), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, description: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SetDescription", 11usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SetDescription)(this, description), |result| Some(*result))
+        }
+        unsafe extern "system" fn DescriptionUnresolved(this: *mut core::ffi::c_void, descr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "DescriptionUnresolved", 12usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).DescriptionUnresolved)(this, descr), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, displayname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "DisplayName", 13usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).DisplayName)(this, displayname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, displayname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SetDisplayName", 14usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SetDisplayName)(this, displayname), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayNameUnresolved(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "DisplayNameUnresolved", 15usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).DisplayNameUnresolved)(this, name), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Keywords(this: *mut core::ffi::c_void, keywords: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "Keywords", 16usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).Keywords)(this, keywords), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetKeywords(this: *mut core::ffi::c_void, keywords: *const super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SetKeywords", 17usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SetKeywords)(this, keywords), |result| Some(*result))
+        }
+        unsafe extern "system" fn LatestOutputLocation(this: *mut core::ffi::c_void, path: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "LatestOutputLocation", 18usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).LatestOutputLocation)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLatestOutputLocation(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SetLatestOutputLocation", 19usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SetLatestOutputLocation)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "Name", 20usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn OutputLocation(this: *mut core::ffi::c_void, path: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "OutputLocation", 21usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).OutputLocation)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn RootPath(this: *mut core::ffi::c_void, folder: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "RootPath", 22usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).RootPath)(this, folder), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRootPath(this: *mut core::ffi::c_void, folder: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SetRootPath", 23usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SetRootPath)(this, folder), |result| Some(*result))
+        }
+        unsafe extern "system" fn Segment(this: *mut core::ffi::c_void, segment: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "Segment", 24usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).Segment)(this, segment), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSegment(this: *mut core::ffi::c_void, segment: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SetSegment", 25usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SetSegment)(this, segment), |result| Some(*result))
+        }
+        unsafe extern "system" fn SegmentMaxDuration(this: *mut core::ffi::c_void, seconds: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SegmentMaxDuration", 26usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SegmentMaxDuration)(this, seconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSegmentMaxDuration(this: *mut core::ffi::c_void, seconds: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SetSegmentMaxDuration", 27usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SetSegmentMaxDuration)(this, seconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn SegmentMaxSize(this: *mut core::ffi::c_void, size: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SegmentMaxSize", 28usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SegmentMaxSize)(this, size), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSegmentMaxSize(this: *mut core::ffi::c_void, size: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SetSegmentMaxSize", 29usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SetSegmentMaxSize)(this, size), |result| Some(*result))
+        }
+        unsafe extern "system" fn SerialNumber(this: *mut core::ffi::c_void, index: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SerialNumber", 30usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SerialNumber)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSerialNumber(this: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SetSerialNumber", 31usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SetSerialNumber)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Server(this: *mut core::ffi::c_void, server: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "Server", 32usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).Server)(this, server), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, status: *mut DataCollectorSetStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "Status", 33usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).Status)(this, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subdirectory(this: *mut core::ffi::c_void, folder: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "Subdirectory", 34usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).Subdirectory)(this, folder), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubdirectory(this: *mut core::ffi::c_void, folder: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SetSubdirectory", 35usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SetSubdirectory)(this, folder), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubdirectoryFormat(this: *mut core::ffi::c_void, format: *mut AutoPathFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SubdirectoryFormat", 36usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SubdirectoryFormat)(this, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubdirectoryFormat(this: *mut core::ffi::c_void, format: AutoPathFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SetSubdirectoryFormat", 37usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SetSubdirectoryFormat)(this, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubdirectoryFormatPattern(this: *mut core::ffi::c_void, pattern: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SubdirectoryFormatPattern", 38usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SubdirectoryFormatPattern)(this, pattern), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubdirectoryFormatPattern(this: *mut core::ffi::c_void, pattern: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SetSubdirectoryFormatPattern", 39usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SetSubdirectoryFormatPattern)(this, pattern), |result| Some(*result))
+        }
+        unsafe extern "system" fn Task(this: *mut core::ffi::c_void, task: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "Task", 40usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).Task)(this, task), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTask(this: *mut core::ffi::c_void, task: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SetTask", 41usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SetTask)(this, task), |result| Some(*result))
+        }
+        unsafe extern "system" fn TaskRunAsSelf(this: *mut core::ffi::c_void, runasself: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "TaskRunAsSelf", 42usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).TaskRunAsSelf)(this, runasself), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTaskRunAsSelf(this: *mut core::ffi::c_void, runasself: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SetTaskRunAsSelf", 43usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SetTaskRunAsSelf)(this, runasself), |result| Some(*result))
+        }
+        unsafe extern "system" fn TaskArguments(this: *mut core::ffi::c_void, task: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "TaskArguments", 44usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).TaskArguments)(this, task), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTaskArguments(this: *mut core::ffi::c_void, task: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SetTaskArguments", 45usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SetTaskArguments)(this, task), |result| Some(*result))
+        }
+        unsafe extern "system" fn TaskUserTextArguments(this: *mut core::ffi::c_void, usertext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "TaskUserTextArguments", 46usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).TaskUserTextArguments)(this, usertext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTaskUserTextArguments(this: *mut core::ffi::c_void, usertext: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SetTaskUserTextArguments", 47usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SetTaskUserTextArguments)(this, usertext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Schedules(this: *mut core::ffi::c_void, ppschedules: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "Schedules", 48usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).Schedules)(this, ppschedules), |result| Some(*result))
+        }
+        unsafe extern "system" fn SchedulesEnabled(this: *mut core::ffi::c_void, enabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SchedulesEnabled", 49usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SchedulesEnabled)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSchedulesEnabled(this: *mut core::ffi::c_void, enabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SetSchedulesEnabled", 50usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SetSchedulesEnabled)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserAccount(this: *mut core::ffi::c_void, user: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "UserAccount", 51usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).UserAccount)(this, user), |result| Some(*result))
+        }
+        unsafe extern "system" fn Xml(this: *mut core::ffi::c_void, xml: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "Xml", 52usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).Xml)(this, xml), |result| Some(*result))
+        }
+        unsafe extern "system" fn Security(this: *mut core::ffi::c_void, pbstrsecurity: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "Security", 53usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).Security)(this, pbstrsecurity), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSecurity(this: *mut core::ffi::c_void, bstrsecurity: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SetSecurity", 54usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SetSecurity)(this, bstrsecurity), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopOnCompletion(this: *mut core::ffi::c_void, stop: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "StopOnCompletion", 55usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).StopOnCompletion)(this, stop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStopOnCompletion(this: *mut core::ffi::c_void, stop: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SetStopOnCompletion", 56usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SetStopOnCompletion)(this, stop), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DataManager(this: *mut core::ffi::c_void, datamanager: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "DataManager", 57usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).DataManager)(this, datamanager), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCredentials(this: *mut core::ffi::c_void, user: core::mem::MaybeUninit<windows_core::BSTR>, password: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SetCredentials", 58usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SetCredentials)(this, user, password), |result| Some(*result))
+        }
+        unsafe extern "system" fn Query(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, server: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "Query", 59usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).Query)(this, name, server), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, server: core::mem::MaybeUninit<windows_core::BSTR>, mode: CommitMode, validation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "Commit", 60usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).Commit)(this, name, server, mode, validation), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "Delete", 61usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).Delete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void, synchronous: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "Start", 62usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).Start)(this, synchronous), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void, synchronous: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "Stop", 63usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).Stop)(this, synchronous), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetXml(this: *mut core::ffi::c_void, xml: core::mem::MaybeUninit<windows_core::BSTR>, validation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SetXml", 64usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SetXml)(this, xml, validation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, key: core::mem::MaybeUninit<windows_core::BSTR>, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "SetValue", 65usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).SetValue)(this, key, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, key: core::mem::MaybeUninit<windows_core::BSTR>, value: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSet", "GetValue", 66usize, |this| ((**(this as *const *const IDataCollectorSet_Vtbl)).GetValue)(this, key, value), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            DataCollectors,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DataCollectors: 0,
+            Duration,
+            SetDuration,
+            Description,
+            SetDescription,
+            DescriptionUnresolved,
+            DisplayName,
+            SetDisplayName,
+            DisplayNameUnresolved,
+            #[cfg(feature = "Win32_System_Com")]
+            Keywords,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Keywords: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetKeywords,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetKeywords: 0,
+            LatestOutputLocation,
+            SetLatestOutputLocation,
+            Name,
+            OutputLocation,
+            RootPath,
+            SetRootPath,
+            Segment,
+            SetSegment,
+            SegmentMaxDuration,
+            SetSegmentMaxDuration,
+            SegmentMaxSize,
+            SetSegmentMaxSize,
+            SerialNumber,
+            SetSerialNumber,
+            Server,
+            Status,
+            Subdirectory,
+            SetSubdirectory,
+            SubdirectoryFormat,
+            SetSubdirectoryFormat,
+            SubdirectoryFormatPattern,
+            SetSubdirectoryFormatPattern,
+            Task,
+            SetTask,
+            TaskRunAsSelf,
+            SetTaskRunAsSelf,
+            TaskArguments,
+            SetTaskArguments,
+            TaskUserTextArguments,
+            SetTaskUserTextArguments,
+            #[cfg(feature = "Win32_System_Com")]
+            Schedules,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Schedules: 0,
+            SchedulesEnabled,
+            SetSchedulesEnabled,
+            UserAccount,
+            Xml,
+            Security,
+            SetSecurity,
+            StopOnCompletion,
+            SetStopOnCompletion,
+            #[cfg(feature = "Win32_System_Com")]
+            DataManager,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DataManager: 0,
+            SetCredentials,
+            Query,
+            #[cfg(feature = "Win32_System_Com")]
+            Commit,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Commit: 0,
+            Delete,
+            Start,
+            Stop,
+            #[cfg(feature = "Win32_System_Com")]
+            SetXml,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetXml: 0,
+            SetValue,
+            GetValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataCollectorSet as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDataCollectorSetCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSetCollection", "Count", 7usize, |this| ((**(this as *const *const IDataCollectorSetCollection_Vtbl)).Count)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>, set: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSetCollection", "get_Item", 8usize, |this| ((**(this as *const *const IDataCollectorSetCollection_Vtbl)).get_Item)(this, index, set), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSetCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const IDataCollectorSetCollection_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, set: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSetCollection", "Add", 10usize, |this| ((**(this as *const *const IDataCollectorSetCollection_Vtbl)).Add)(this, set), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, set: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSetCollection", "Remove", 11usize, |this| ((**(this as *const *const IDataCollectorSetCollection_Vtbl)).Remove)(this, set), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSetCollection", "Clear", 12usize, |this| ((**(this as *const *const IDataCollectorSetCollection_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddRange(this: *mut core::ffi::c_void, sets: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSetCollection", "AddRange", 13usize, |this| ((**(this as *const *const IDataCollectorSetCollection_Vtbl)).AddRange)(this, sets), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataCollectorSets(this: *mut core::ffi::c_void, server: core::mem::MaybeUninit<windows_core::BSTR>, filter: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataCollectorSetCollection", "GetDataCollectorSets", 14usize, |this| ((**(this as *const *const IDataCollectorSetCollection_Vtbl)).GetDataCollectorSets)(this, server, filter), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            Clear,
+            #[cfg(feature = "Win32_System_Com")]
+            AddRange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddRange: 0,
+            GetDataCollectorSets,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataCollectorSetCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDataManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, pfenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataManager", "Enabled", 7usize, |this| ((**(this as *const *const IDataManager_Vtbl)).Enabled)(this, pfenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, fenabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataManager", "SetEnabled", 8usize, |this| ((**(this as *const *const IDataManager_Vtbl)).SetEnabled)(this, fenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckBeforeRunning(this: *mut core::ffi::c_void, pfcheck: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataManager", "CheckBeforeRunning", 9usize, |this| ((**(this as *const *const IDataManager_Vtbl)).CheckBeforeRunning)(this, pfcheck), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCheckBeforeRunning(this: *mut core::ffi::c_void, fcheck: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataManager", "SetCheckBeforeRunning", 10usize, |this| ((**(this as *const *const IDataManager_Vtbl)).SetCheckBeforeRunning)(this, fcheck), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinFreeDisk(this: *mut core::ffi::c_void, minfreedisk: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataManager", "MinFreeDisk", 11usize, |this| ((**(this as *const *const IDataManager_Vtbl)).MinFreeDisk)(this, minfreedisk), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinFreeDisk(this: *mut core::ffi::c_void, minfreedisk: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataManager", "SetMinFreeDisk", 12usize, |this| ((**(this as *const *const IDataManager_Vtbl)).SetMinFreeDisk)(this, minfreedisk), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxSize(this: *mut core::ffi::c_void, pulmaxsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataManager", "MaxSize", 13usize, |this| ((**(this as *const *const IDataManager_Vtbl)).MaxSize)(this, pulmaxsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxSize(this: *mut core::ffi::c_void, ulmaxsize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataManager", "SetMaxSize", 14usize, |this| ((**(this as *const *const IDataManager_Vtbl)).SetMaxSize)(this, ulmaxsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxFolderCount(this: *mut core::ffi::c_void, pulmaxfoldercount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataManager", "MaxFolderCount", 15usize, |this| ((**(this as *const *const IDataManager_Vtbl)).MaxFolderCount)(this, pulmaxfoldercount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxFolderCount(this: *mut core::ffi::c_void, ulmaxfoldercount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataManager", "SetMaxFolderCount", 16usize, |this| ((**(this as *const *const IDataManager_Vtbl)).SetMaxFolderCount)(this, ulmaxfoldercount), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResourcePolicy(this: *mut core::ffi::c_void, ppolicy: *mut ResourcePolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataManager", "ResourcePolicy", 17usize, |this| ((**(this as *const *const IDataManager_Vtbl)).ResourcePolicy)(this, ppolicy), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetResourcePolicy(this: *mut core::ffi::c_void, policy: ResourcePolicy) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataManager", "SetResourcePolicy", 18usize, |this| ((**(this as *const *const IDataManager_Vtbl)).SetResourcePolicy)(this, policy), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn FolderActions(this: *mut core::ffi::c_void, actions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataManager", "FolderActions", 19usize, |this| ((**(this as *const *const IDataManager_Vtbl)).FolderActions)(this, actions), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportSchema(this: *mut core::ffi::c_void, reportschema: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataManager", "ReportSchema", 20usize, |this| ((**(this as *const *const IDataManager_Vtbl)).ReportSchema)(this, reportschema), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReportSchema(this: *mut core::ffi::c_void, reportschema: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataManager", "SetReportSchema", 21usize, |this| ((**(this as *const *const IDataManager_Vtbl)).SetReportSchema)(this, reportschema), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportFileName(this: *mut core::ffi::c_void, pbstrfilename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataManager", "ReportFileName", 22usize, |this| ((**(this as *const *const IDataManager_Vtbl)).ReportFileName)(this, pbstrfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReportFileName(this: *mut core::ffi::c_void, pbstrfilename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataManager", "SetReportFileName", 23usize, |this| ((**(this as *const *const IDataManager_Vtbl)).SetReportFileName)(this, pbstrfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn RuleTargetFileName(this: *mut core::ffi::c_void, filename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataManager", "RuleTargetFileName", 24usize, |this| ((**(this as *const *const IDataManager_Vtbl)).RuleTargetFileName)(this, filename), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRuleTargetFileName(this: *mut core::ffi::c_void, filename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataManager", "SetRuleTargetFileName", 25usize, |this| ((**(this as *const *const IDataManager_Vtbl)).SetRuleTargetFileName)(this, filename), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventsFileName(this: *mut core::ffi::c_void, pbstrfilename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataManager", "EventsFileName", 26usize, |this| ((**(this as *const *const IDataManager_Vtbl)).EventsFileName)(this, pbstrfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventsFileName(this: *mut core::ffi::c_void, pbstrfilename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataManager", "SetEventsFileName", 27usize, |this| ((**(this as *const *const IDataManager_Vtbl)).SetEventsFileName)(this, pbstrfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rules(this: *mut core::ffi::c_void, pbstrxml: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataManager", "Rules", 28usize, |this| ((**(this as *const *const IDataManager_Vtbl)).Rules)(this, pbstrxml), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRules(this: *mut core::ffi::c_void, bstrxml: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataManager", "SetRules", 29usize, |this| ((**(this as *const *const IDataManager_Vtbl)).SetRules)(this, bstrxml), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Run(this: *mut core::ffi::c_void, steps: DataManagerSteps, bstrfolder: core::mem::MaybeUninit<windows_core::BSTR>, errors: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataManager", "Run", 30usize, |this| ((**(this as *const *const IDataManager_Vtbl)).Run)(this, steps, bstrfolder, errors), |result| Some(*result))
+        }
+        unsafe extern "system" fn Extract(this: *mut core::ffi::c_void, cabfilename: core::mem::MaybeUninit<windows_core::BSTR>, destinationpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataManager", "Extract", 31usize, |this| ((**(this as *const *const IDataManager_Vtbl)).Extract)(this, cabfilename, destinationpath), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Enabled,
+            SetEnabled,
+            CheckBeforeRunning,
+            SetCheckBeforeRunning,
+            MinFreeDisk,
+            SetMinFreeDisk,
+            MaxSize,
+            SetMaxSize,
+            MaxFolderCount,
+            SetMaxFolderCount,
+            ResourcePolicy,
+            SetResourcePolicy,
+            #[cfg(feature = "Win32_System_Com")]
+            FolderActions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            FolderActions: 0,
+            ReportSchema,
+            SetReportSchema,
+            ReportFileName,
+            SetReportFileName,
+            RuleTargetFileName,
+            SetRuleTargetFileName,
+            EventsFileName,
+            SetEventsFileName,
+            Rules,
+            SetRules,
+            #[cfg(feature = "Win32_System_Com")]
+            Run,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Run: 0,
+            Extract,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataManager as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFolderAction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Age(this: *mut core::ffi::c_void, pulage: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderAction", "Age", 7usize, |this| ((**(this as *const *const IFolderAction_Vtbl)).Age)(this, pulage), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAge(this: *mut core::ffi::c_void, ulage: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderAction", "SetAge", 8usize, |this| ((**(this as *const *const IFolderAction_Vtbl)).SetAge)(this, ulage), |result| Some(*result))
+        }
+        unsafe extern "system" fn Size(this: *mut core::ffi::c_void, pulage: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderAction", "Size", 9usize, |this| ((**(this as *const *const IFolderAction_Vtbl)).Size)(this, pulage), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSize(this: *mut core::ffi::c_void, ulage: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderAction", "SetSize", 10usize, |this| ((**(this as *const *const IFolderAction_Vtbl)).SetSize)(this, ulage), |result| Some(*result))
+        }
+        unsafe extern "system" fn Actions(this: *mut core::ffi::c_void, steps: *mut FolderActionSteps) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderAction", "Actions", 11usize, |this| ((**(this as *const *const IFolderAction_Vtbl)).Actions)(this, steps), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActions(this: *mut core::ffi::c_void, steps: FolderActionSteps) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderAction", "SetActions", 12usize, |this| ((**(this as *const *const IFolderAction_Vtbl)).SetActions)(this, steps), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendCabTo(this: *mut core::ffi::c_void, pbstrdestination: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderAction", "SendCabTo", 13usize, |this| ((**(this as *const *const IFolderAction_Vtbl)).SendCabTo)(this, pbstrdestination), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSendCabTo(this: *mut core::ffi::c_void, bstrdestination: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderAction", "SetSendCabTo", 14usize, |this| ((**(this as *const *const IFolderAction_Vtbl)).SetSendCabTo)(this, bstrdestination), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Age,
+            SetAge,
+            Size,
+            SetSize,
+            Actions,
+            SetActions,
+            SendCabTo,
+            SetSendCabTo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFolderAction as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFolderActionCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderActionCollection", "Count", 7usize, |this| ((**(this as *const *const IFolderActionCollection_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>, action: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderActionCollection", "get_Item", 8usize, |this| ((**(this as *const *const IFolderActionCollection_Vtbl)).get_Item)(this, index, action), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, r#enum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderActionCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const IFolderActionCollection_Vtbl))._NewEnum)(this, r#enum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, action: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderActionCollection", "Add", 10usize, |this| ((**(this as *const *const IFolderActionCollection_Vtbl)).Add)(this, action), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderActionCollection", "Remove", 11usize, |this| ((**(this as *const *const IFolderActionCollection_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderActionCollection", "Clear", 12usize, |this| ((**(this as *const *const IFolderActionCollection_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddRange(this: *mut core::ffi::c_void, actions: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderActionCollection", "AddRange", 13usize, |this| ((**(this as *const *const IFolderActionCollection_Vtbl)).AddRange)(this, actions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateFolderAction(this: *mut core::ffi::c_void, folderaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFolderActionCollection", "CreateFolderAction", 14usize, |this| ((**(this as *const *const IFolderActionCollection_Vtbl)).CreateFolderAction)(this, folderaction), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            Clear,
+            #[cfg(feature = "Win32_System_Com")]
+            AddRange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddRange: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateFolderAction,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateFolderAction: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFolderActionCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILogFileItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, pstrvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILogFileItem", "Path", 3usize, |this| ((**(this as *const *const ILogFileItem_Vtbl)).Path)(this, pstrvalue), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Path }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILogFileItem as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ILogFiles_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, plong: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILogFiles", "Count", 7usize, |this| ((**(this as *const *const ILogFiles_Vtbl)).Count)(this, plong), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppiunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILogFiles", "_NewEnum", 8usize, |this| ((**(this as *const *const ILogFiles_Vtbl))._NewEnum)(this, ppiunk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>, ppi: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILogFiles", "get_Item", 9usize, |this| ((**(this as *const *const ILogFiles_Vtbl)).get_Item)(this, index, ppi), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pathname: core::mem::MaybeUninit<windows_core::BSTR>, ppi: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILogFiles", "Add", 10usize, |this| ((**(this as *const *const ILogFiles_Vtbl)).Add)(this, pathname, ppi), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILogFiles", "Remove", 11usize, |this| ((**(this as *const *const ILogFiles_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILogFiles as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPerformanceCounterDataCollector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DataSourceName(this: *mut core::ffi::c_void, dsn: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerformanceCounterDataCollector", "DataSourceName", 32usize, |this| ((**(this as *const *const IPerformanceCounterDataCollector_Vtbl)).DataSourceName)(this, dsn), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDataSourceName(this: *mut core::ffi::c_void, dsn: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerformanceCounterDataCollector", "SetDataSourceName", 33usize, |this| ((**(this as *const *const IPerformanceCounterDataCollector_Vtbl)).SetDataSourceName)(this, dsn), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PerformanceCounters(this: *mut core::ffi::c_void, counters: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerformanceCounterDataCollector", "PerformanceCounters", 34usize, |this| ((**(this as *const *const IPerformanceCounterDataCollector_Vtbl)).PerformanceCounters)(this, counters), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetPerformanceCounters(this: *mut core::ffi::c_void, counters: *const super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerformanceCounterDataCollector", "SetPerformanceCounters", 35usize, |this| ((**(this as *const *const IPerformanceCounterDataCollector_Vtbl)).SetPerformanceCounters)(this, counters), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogFileFormat(this: *mut core::ffi::c_void, format: *mut FileFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerformanceCounterDataCollector", "LogFileFormat", 36usize, |this| ((**(this as *const *const IPerformanceCounterDataCollector_Vtbl)).LogFileFormat)(this, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLogFileFormat(this: *mut core::ffi::c_void, format: FileFormat) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerformanceCounterDataCollector", "SetLogFileFormat", 37usize, |this| ((**(this as *const *const IPerformanceCounterDataCollector_Vtbl)).SetLogFileFormat)(this, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn SampleInterval(this: *mut core::ffi::c_void, interval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerformanceCounterDataCollector", "SampleInterval", 38usize, |this| ((**(this as *const *const IPerformanceCounterDataCollector_Vtbl)).SampleInterval)(this, interval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSampleInterval(this: *mut core::ffi::c_void, interval: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerformanceCounterDataCollector", "SetSampleInterval", 39usize, |this| ((**(this as *const *const IPerformanceCounterDataCollector_Vtbl)).SetSampleInterval)(this, interval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SegmentMaxRecords(this: *mut core::ffi::c_void, records: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerformanceCounterDataCollector", "SegmentMaxRecords", 40usize, |this| ((**(this as *const *const IPerformanceCounterDataCollector_Vtbl)).SegmentMaxRecords)(this, records), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSegmentMaxRecords(this: *mut core::ffi::c_void, records: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPerformanceCounterDataCollector", "SetSegmentMaxRecords", 41usize, |this| ((**(this as *const *const IPerformanceCounterDataCollector_Vtbl)).SetSegmentMaxRecords)(this, records), |result| Some(*result))
+        }
+        Self {
+            base__: <IDataCollector_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DataSourceName,
+            SetDataSourceName,
+            #[cfg(feature = "Win32_System_Com")]
+            PerformanceCounters,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PerformanceCounters: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetPerformanceCounters,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetPerformanceCounters: 0,
+            LogFileFormat,
+            SetLogFileFormat,
+            SampleInterval,
+            SetSampleInterval,
+            SegmentMaxRecords,
+            SetSegmentMaxRecords,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPerformanceCounterDataCollector as windows_core::Interface>::IID || <IDataCollector_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISchedule_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartDate(this: *mut core::ffi::c_void, start: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchedule", "StartDate", 7usize, |this| ((**(this as *const *const ISchedule_Vtbl)).StartDate)(this, start), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartDate(this: *mut core::ffi::c_void, start: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchedule", "SetStartDate", 8usize, |this| ((**(this as *const *const ISchedule_Vtbl)).SetStartDate)(this, start), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndDate(this: *mut core::ffi::c_void, end: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchedule", "EndDate", 9usize, |this| ((**(this as *const *const ISchedule_Vtbl)).EndDate)(this, end), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEndDate(this: *mut core::ffi::c_void, end: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchedule", "SetEndDate", 10usize, |this| ((**(this as *const *const ISchedule_Vtbl)).SetEndDate)(this, end), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartTime(this: *mut core::ffi::c_void, start: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchedule", "StartTime", 11usize, |this| ((**(this as *const *const ISchedule_Vtbl)).StartTime)(this, start), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartTime(this: *mut core::ffi::c_void, start: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchedule", "SetStartTime", 12usize, |this| ((**(this as *const *const ISchedule_Vtbl)).SetStartTime)(this, start), |result| Some(*result))
+        }
+        unsafe extern "system" fn Days(this: *mut core::ffi::c_void, days: *mut WeekDays) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchedule", "Days", 13usize, |this| ((**(this as *const *const ISchedule_Vtbl)).Days)(this, days), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDays(this: *mut core::ffi::c_void, days: WeekDays) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchedule", "SetDays", 14usize, |this| ((**(this as *const *const ISchedule_Vtbl)).SetDays)(this, days), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartDate,
+            SetStartDate,
+            EndDate,
+            SetEndDate,
+            StartTime,
+            SetStartTime,
+            Days,
+            SetDays,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISchedule as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IScheduleCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduleCollection", "Count", 7usize, |this| ((**(this as *const *const IScheduleCollection_Vtbl)).Count)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>, ppschedule: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduleCollection", "get_Item", 8usize, |this| ((**(this as *const *const IScheduleCollection_Vtbl)).get_Item)(this, index, ppschedule), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ienum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduleCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const IScheduleCollection_Vtbl))._NewEnum)(this, ienum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pschedule: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduleCollection", "Add", 10usize, |this| ((**(this as *const *const IScheduleCollection_Vtbl)).Add)(this, pschedule), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, vschedule: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduleCollection", "Remove", 11usize, |this| ((**(this as *const *const IScheduleCollection_Vtbl)).Remove)(this, vschedule), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduleCollection", "Clear", 12usize, |this| ((**(this as *const *const IScheduleCollection_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddRange(this: *mut core::ffi::c_void, pschedules: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduleCollection", "AddRange", 13usize, |this| ((**(this as *const *const IScheduleCollection_Vtbl)).AddRange)(this, pschedules), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateSchedule(this: *mut core::ffi::c_void, schedule: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduleCollection", "CreateSchedule", 14usize, |this| ((**(this as *const *const IScheduleCollection_Vtbl)).CreateSchedule)(this, schedule), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            Clear,
+            #[cfg(feature = "Win32_System_Com")]
+            AddRange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddRange: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateSchedule,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateSchedule: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScheduleCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for ISystemMonitor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Appearance(this: *mut core::ffi::c_void, iappearance: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "Appearance", 3usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).Appearance)(this, iappearance), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAppearance(this: *mut core::ffi::c_void, iappearance: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetAppearance", 4usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetAppearance)(this, iappearance), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackColor(this: *mut core::ffi::c_void, pcolor: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "BackColor", 5usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).BackColor)(this, pcolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackColor(this: *mut core::ffi::c_void, color: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetBackColor", 6usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetBackColor)(this, color), |result| Some(*result))
+        }
+        unsafe extern "system" fn BorderStyle(this: *mut core::ffi::c_void, iborderstyle: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "BorderStyle", 7usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).BorderStyle)(this, iborderstyle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBorderStyle(this: *mut core::ffi::c_void, iborderstyle: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetBorderStyle", 8usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetBorderStyle)(this, iborderstyle), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForeColor(this: *mut core::ffi::c_void, pcolor: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "ForeColor", 9usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).ForeColor)(this, pcolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetForeColor(this: *mut core::ffi::c_void, color: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetForeColor", 10usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetForeColor)(this, color), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+        unsafe extern "system" fn Font(this: *mut core::ffi::c_void, ppfont: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "Font", 11usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).Font)(this, ppfont), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+        unsafe extern "system" fn putref_Font(this: *mut core::ffi::c_void, pfont: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "putref_Font", 12usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).putref_Font)(this, pfont), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Counters(this: *mut core::ffi::c_void, ppicounters: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "Counters", 13usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).Counters)(this, ppicounters), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShowVerticalGrid(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetShowVerticalGrid", 14usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetShowVerticalGrid)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowVerticalGrid(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "ShowVerticalGrid", 15usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).ShowVerticalGrid)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShowHorizontalGrid(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetShowHorizontalGrid", 16usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetShowHorizontalGrid)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowHorizontalGrid(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "ShowHorizontalGrid", 17usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).ShowHorizontalGrid)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShowLegend(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetShowLegend", 18usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetShowLegend)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowLegend(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "ShowLegend", 19usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).ShowLegend)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShowScaleLabels(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetShowScaleLabels", 20usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetShowScaleLabels)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowScaleLabels(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "ShowScaleLabels", 21usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).ShowScaleLabels)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShowValueBar(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetShowValueBar", 22usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetShowValueBar)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowValueBar(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "ShowValueBar", 23usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).ShowValueBar)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaximumScale(this: *mut core::ffi::c_void, ivalue: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetMaximumScale", 24usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetMaximumScale)(this, ivalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaximumScale(this: *mut core::ffi::c_void, pivalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "MaximumScale", 25usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).MaximumScale)(this, pivalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinimumScale(this: *mut core::ffi::c_void, ivalue: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetMinimumScale", 26usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetMinimumScale)(this, ivalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinimumScale(this: *mut core::ffi::c_void, pivalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "MinimumScale", 27usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).MinimumScale)(this, pivalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUpdateInterval(this: *mut core::ffi::c_void, fvalue: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetUpdateInterval", 28usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetUpdateInterval)(this, fvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateInterval(this: *mut core::ffi::c_void, pfvalue: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "UpdateInterval", 29usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).UpdateInterval)(this, pfvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayType(this: *mut core::ffi::c_void, edisplaytype: DisplayTypeConstants) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetDisplayType", 30usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetDisplayType)(this, edisplaytype), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayType(this: *mut core::ffi::c_void, pedisplaytype: *mut DisplayTypeConstants) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "DisplayType", 31usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).DisplayType)(this, pedisplaytype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetManualUpdate(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetManualUpdate", 32usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetManualUpdate)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn ManualUpdate(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "ManualUpdate", 33usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).ManualUpdate)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGraphTitle(this: *mut core::ffi::c_void, bstitle: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetGraphTitle", 34usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetGraphTitle)(this, bstitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn GraphTitle(this: *mut core::ffi::c_void, pbstitle: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "GraphTitle", 35usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).GraphTitle)(this, pbstitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetYAxisLabel(this: *mut core::ffi::c_void, bstitle: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetYAxisLabel", 36usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetYAxisLabel)(this, bstitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn YAxisLabel(this: *mut core::ffi::c_void, pbstitle: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "YAxisLabel", 37usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).YAxisLabel)(this, pbstitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn CollectSample(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "CollectSample", 38usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).CollectSample)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateGraph(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "UpdateGraph", 39usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).UpdateGraph)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn BrowseCounters(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "BrowseCounters", 40usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).BrowseCounters)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayProperties(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "DisplayProperties", 41usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).DisplayProperties)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Counter(this: *mut core::ffi::c_void, iindex: i32, ppicounter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "Counter", 42usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).Counter)(this, iindex, ppicounter), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddCounter(this: *mut core::ffi::c_void, bspath: core::mem::MaybeUninit<windows_core::BSTR>, ppicounter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "AddCounter", 43usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).AddCounter)(this, bspath, ppicounter), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteCounter(this: *mut core::ffi::c_void, pctr: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "DeleteCounter", 44usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).DeleteCounter)(this, pctr), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackColorCtl(this: *mut core::ffi::c_void, pcolor: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "BackColorCtl", 45usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).BackColorCtl)(this, pcolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackColorCtl(this: *mut core::ffi::c_void, color: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetBackColorCtl", 46usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetBackColorCtl)(this, color), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLogFileName(this: *mut core::ffi::c_void, bsfilename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetLogFileName", 47usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetLogFileName)(this, bsfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogFileName(this: *mut core::ffi::c_void, bsfilename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "LogFileName", 48usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).LogFileName)(this, bsfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLogViewStart(this: *mut core::ffi::c_void, starttime: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetLogViewStart", 49usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetLogViewStart)(this, starttime), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogViewStart(this: *mut core::ffi::c_void, starttime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "LogViewStart", 50usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).LogViewStart)(this, starttime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLogViewStop(this: *mut core::ffi::c_void, stoptime: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetLogViewStop", 51usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetLogViewStop)(this, stoptime), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogViewStop(this: *mut core::ffi::c_void, stoptime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "LogViewStop", 52usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).LogViewStop)(this, stoptime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GridColor(this: *mut core::ffi::c_void, pcolor: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "GridColor", 53usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).GridColor)(this, pcolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGridColor(this: *mut core::ffi::c_void, color: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetGridColor", 54usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetGridColor)(this, color), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimeBarColor(this: *mut core::ffi::c_void, pcolor: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "TimeBarColor", 55usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).TimeBarColor)(this, pcolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTimeBarColor(this: *mut core::ffi::c_void, color: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetTimeBarColor", 56usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetTimeBarColor)(this, color), |result| Some(*result))
+        }
+        unsafe extern "system" fn Highlight(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "Highlight", 57usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).Highlight)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHighlight(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetHighlight", 58usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetHighlight)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowToolbar(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "ShowToolbar", 59usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).ShowToolbar)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShowToolbar(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetShowToolbar", 60usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetShowToolbar)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Paste(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "Paste", 61usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).Paste)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Copy(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "Copy", 62usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).Copy)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "Reset", 63usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReadOnly(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetReadOnly", 64usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetReadOnly)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadOnly(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "ReadOnly", 65usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).ReadOnly)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReportValueType(this: *mut core::ffi::c_void, ereportvaluetype: ReportValueTypeConstants) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetReportValueType", 66usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetReportValueType)(this, ereportvaluetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportValueType(this: *mut core::ffi::c_void, pereportvaluetype: *mut ReportValueTypeConstants) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "ReportValueType", 67usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).ReportValueType)(this, pereportvaluetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMonitorDuplicateInstances(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetMonitorDuplicateInstances", 68usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetMonitorDuplicateInstances)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn MonitorDuplicateInstances(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "MonitorDuplicateInstances", 69usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).MonitorDuplicateInstances)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayFilter(this: *mut core::ffi::c_void, ivalue: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetDisplayFilter", 70usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetDisplayFilter)(this, ivalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayFilter(this: *mut core::ffi::c_void, pivalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "DisplayFilter", 71usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).DisplayFilter)(this, pivalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn LogFiles(this: *mut core::ffi::c_void, ppilogfiles: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "LogFiles", 72usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).LogFiles)(this, ppilogfiles), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDataSourceType(this: *mut core::ffi::c_void, edatasourcetype: DataSourceTypeConstants) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetDataSourceType", 73usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetDataSourceType)(this, edatasourcetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataSourceType(this: *mut core::ffi::c_void, pedatasourcetype: *mut DataSourceTypeConstants) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "DataSourceType", 74usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).DataSourceType)(this, pedatasourcetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSqlDsnName(this: *mut core::ffi::c_void, bssqldsnname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetSqlDsnName", 75usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetSqlDsnName)(this, bssqldsnname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SqlDsnName(this: *mut core::ffi::c_void, bssqldsnname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SqlDsnName", 76usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SqlDsnName)(this, bssqldsnname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSqlLogSetName(this: *mut core::ffi::c_void, bssqllogsetname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SetSqlLogSetName", 77usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SetSqlLogSetName)(this, bssqllogsetname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SqlLogSetName(this: *mut core::ffi::c_void, bssqllogsetname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor", "SqlLogSetName", 78usize, |this| ((**(this as *const *const ISystemMonitor_Vtbl)).SqlLogSetName)(this, bssqllogsetname), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Appearance,
+            SetAppearance,
+            BackColor,
+            SetBackColor,
+            BorderStyle,
+            SetBorderStyle,
+            ForeColor,
+            SetForeColor,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+            Font,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Ole")))]
+            Font: 0,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+            putref_Font,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Ole")))]
+            putref_Font: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Counters,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Counters: 0,
+            SetShowVerticalGrid,
+            ShowVerticalGrid,
+            SetShowHorizontalGrid,
+            ShowHorizontalGrid,
+            SetShowLegend,
+            ShowLegend,
+            SetShowScaleLabels,
+            ShowScaleLabels,
+            SetShowValueBar,
+            ShowValueBar,
+            SetMaximumScale,
+            MaximumScale,
+            SetMinimumScale,
+            MinimumScale,
+            SetUpdateInterval,
+            UpdateInterval,
+            SetDisplayType,
+            DisplayType,
+            SetManualUpdate,
+            ManualUpdate,
+            SetGraphTitle,
+            GraphTitle,
+            SetYAxisLabel,
+            YAxisLabel,
+            CollectSample,
+            UpdateGraph,
+            BrowseCounters,
+            DisplayProperties,
+            Counter,
+            AddCounter,
+            DeleteCounter,
+            BackColorCtl,
+            SetBackColorCtl,
+            SetLogFileName,
+            LogFileName,
+            SetLogViewStart,
+            LogViewStart,
+            SetLogViewStop,
+            LogViewStop,
+            GridColor,
+            SetGridColor,
+            TimeBarColor,
+            SetTimeBarColor,
+            Highlight,
+            SetHighlight,
+            ShowToolbar,
+            SetShowToolbar,
+            Paste,
+            Copy,
+            Reset,
+            SetReadOnly,
+            ReadOnly,
+            SetReportValueType,
+            ReportValueType,
+            SetMonitorDuplicateInstances,
+            MonitorDuplicateInstances,
+            SetDisplayFilter,
+            DisplayFilter,
+            #[cfg(feature = "Win32_System_Com")]
+            LogFiles,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            LogFiles: 0,
+            SetDataSourceType,
+            DataSourceType,
+            SetSqlDsnName,
+            SqlDsnName,
+            SetSqlLogSetName,
+            SqlLogSetName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemMonitor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for ISystemMonitor2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetEnableDigitGrouping(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor2", "SetEnableDigitGrouping", 79usize, |this| ((**(this as *const *const ISystemMonitor2_Vtbl)).SetEnableDigitGrouping)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableDigitGrouping(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor2", "EnableDigitGrouping", 80usize, |this| ((**(this as *const *const ISystemMonitor2_Vtbl)).EnableDigitGrouping)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnableToolTips(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor2", "SetEnableToolTips", 81usize, |this| ((**(this as *const *const ISystemMonitor2_Vtbl)).SetEnableToolTips)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableToolTips(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor2", "EnableToolTips", 82usize, |this| ((**(this as *const *const ISystemMonitor2_Vtbl)).EnableToolTips)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShowTimeAxisLabels(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor2", "SetShowTimeAxisLabels", 83usize, |this| ((**(this as *const *const ISystemMonitor2_Vtbl)).SetShowTimeAxisLabels)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowTimeAxisLabels(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor2", "ShowTimeAxisLabels", 84usize, |this| ((**(this as *const *const ISystemMonitor2_Vtbl)).ShowTimeAxisLabels)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetChartScroll(this: *mut core::ffi::c_void, bscroll: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor2", "SetChartScroll", 85usize, |this| ((**(this as *const *const ISystemMonitor2_Vtbl)).SetChartScroll)(this, bscroll), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChartScroll(this: *mut core::ffi::c_void, pbscroll: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor2", "ChartScroll", 86usize, |this| ((**(this as *const *const ISystemMonitor2_Vtbl)).ChartScroll)(this, pbscroll), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDataPointCount(this: *mut core::ffi::c_void, inewcount: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor2", "SetDataPointCount", 87usize, |this| ((**(this as *const *const ISystemMonitor2_Vtbl)).SetDataPointCount)(this, inewcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataPointCount(this: *mut core::ffi::c_void, pidatapointcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor2", "DataPointCount", 88usize, |this| ((**(this as *const *const ISystemMonitor2_Vtbl)).DataPointCount)(this, pidatapointcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScaleToFit(this: *mut core::ffi::c_void, bselectedcountersonly: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor2", "ScaleToFit", 89usize, |this| ((**(this as *const *const ISystemMonitor2_Vtbl)).ScaleToFit)(this, bselectedcountersonly), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveAs(this: *mut core::ffi::c_void, bstrfilename: core::mem::MaybeUninit<windows_core::BSTR>, esysmonfiletype: SysmonFileType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor2", "SaveAs", 90usize, |this| ((**(this as *const *const ISystemMonitor2_Vtbl)).SaveAs)(this, bstrfilename, esysmonfiletype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Relog(this: *mut core::ffi::c_void, bstrfilename: core::mem::MaybeUninit<windows_core::BSTR>, esysmonfiletype: SysmonFileType, ifilter: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor2", "Relog", 91usize, |this| ((**(this as *const *const ISystemMonitor2_Vtbl)).Relog)(this, bstrfilename, esysmonfiletype, ifilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearData(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor2", "ClearData", 92usize, |this| ((**(this as *const *const ISystemMonitor2_Vtbl)).ClearData)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogSourceStartTime(this: *mut core::ffi::c_void, pdate: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor2", "LogSourceStartTime", 93usize, |this| ((**(this as *const *const ISystemMonitor2_Vtbl)).LogSourceStartTime)(this, pdate), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogSourceStopTime(this: *mut core::ffi::c_void, pdate: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor2", "LogSourceStopTime", 94usize, |this| ((**(this as *const *const ISystemMonitor2_Vtbl)).LogSourceStopTime)(this, pdate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLogViewRange(this: *mut core::ffi::c_void, starttime: f64, stoptime: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor2", "SetLogViewRange", 95usize, |this| ((**(this as *const *const ISystemMonitor2_Vtbl)).SetLogViewRange)(this, starttime, stoptime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLogViewRange(this: *mut core::ffi::c_void, starttime: *mut f64, stoptime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor2", "GetLogViewRange", 96usize, |this| ((**(this as *const *const ISystemMonitor2_Vtbl)).GetLogViewRange)(this, starttime, stoptime), |result| Some(*result))
+        }
+        unsafe extern "system" fn BatchingLock(this: *mut core::ffi::c_void, flock: super::super::Foundation::VARIANT_BOOL, ebatchreason: SysmonBatchReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor2", "BatchingLock", 97usize, |this| ((**(this as *const *const ISystemMonitor2_Vtbl)).BatchingLock)(this, flock, ebatchreason), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadSettings(this: *mut core::ffi::c_void, bstrsettingfilename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMonitor2", "LoadSettings", 98usize, |this| ((**(this as *const *const ISystemMonitor2_Vtbl)).LoadSettings)(this, bstrsettingfilename), |result| Some(*result))
+        }
+        Self {
+            base__: <ISystemMonitor_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetEnableDigitGrouping,
+            EnableDigitGrouping,
+            SetEnableToolTips,
+            EnableToolTips,
+            SetShowTimeAxisLabels,
+            ShowTimeAxisLabels,
+            SetChartScroll,
+            ChartScroll,
+            SetDataPointCount,
+            DataPointCount,
+            ScaleToFit,
+            SaveAs,
+            Relog,
+            ClearData,
+            LogSourceStartTime,
+            LogSourceStopTime,
+            SetLogViewRange,
+            GetLogViewRange,
+            BatchingLock,
+            LoadSettings,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemMonitor2 as windows_core::Interface>::IID || <ISystemMonitor_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemMonitorEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnCounterSelected(this: *mut core::ffi::c_void, index: i32) {
+            windows_core::imp::proxy_call(this, "ISystemMonitorEvents", "OnCounterSelected", 3usize, |this| ((**(this as *const *const ISystemMonitorEvents_Vtbl)).OnCounterSelected)(this, index), |_| None)
+        }
+        unsafe extern "system" fn OnCounterAdded(this: *mut core::ffi::c_void, index: i32) {
+            windows_core::imp::proxy_call(this, "ISystemMonitorEvents", "OnCounterAdded", 4usize, |this| ((**(this as *const *const ISystemMonitorEvents_Vtbl)).OnCounterAdded)(this, index), |_| None)
+        }
+        unsafe extern "system" fn OnCounterDeleted(this: *mut core::ffi::c_void, index: i32) {
+            windows_core::imp::proxy_call(this, "ISystemMonitorEvents", "OnCounterDeleted", 5usize, |this| ((**(this as *const *const ISystemMonitorEvents_Vtbl)).OnCounterDeleted)(this, index), |_| None)
+        }
+        unsafe extern "system" fn OnSampleCollected(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ISystemMonitorEvents", "OnSampleCollected", 6usize, |this| ((**(this as *const *const ISystemMonitorEvents_Vtbl)).OnSampleCollected)(this), |_| None)
+        }
+        unsafe extern "system" fn OnDblClick(this: *mut core::ffi::c_void, index: i32) {
+            windows_core::imp::proxy_call(this, "ISystemMonitorEvents", "OnDblClick", 7usize, |this| ((**(this as *const *const ISystemMonitorEvents_Vtbl)).OnDblClick)(this, index), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnCounterSelected,
+            OnCounterAdded,
+            OnCounterDeleted,
+            OnSampleCollected,
+            OnDblClick,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemMonitorEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITraceDataCollector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BufferSize(this: *mut core::ffi::c_void, size: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "BufferSize", 32usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).BufferSize)(this, size), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBufferSize(this: *mut core::ffi::c_void, size: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "SetBufferSize", 33usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).SetBufferSize)(this, size), |result| Some(*result))
+        }
+        unsafe extern "system" fn BuffersLost(this: *mut core::ffi::c_void, buffers: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "BuffersLost", 34usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).BuffersLost)(this, buffers), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBuffersLost(this: *mut core::ffi::c_void, buffers: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "SetBuffersLost", 35usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).SetBuffersLost)(this, buffers), |result| Some(*result))
+        }
+        unsafe extern "system" fn BuffersWritten(this: *mut core::ffi::c_void, buffers: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "BuffersWritten", 36usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).BuffersWritten)(this, buffers), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBuffersWritten(this: *mut core::ffi::c_void, buffers: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "SetBuffersWritten", 37usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).SetBuffersWritten)(this, buffers), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClockType(this: *mut core::ffi::c_void, clock: *mut ClockType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "ClockType", 38usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).ClockType)(this, clock), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClockType(this: *mut core::ffi::c_void, clock: ClockType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "SetClockType", 39usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).SetClockType)(this, clock), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventsLost(this: *mut core::ffi::c_void, events: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "EventsLost", 40usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).EventsLost)(this, events), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventsLost(this: *mut core::ffi::c_void, events: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "SetEventsLost", 41usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).SetEventsLost)(this, events), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedModes(this: *mut core::ffi::c_void, mode: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "ExtendedModes", 42usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).ExtendedModes)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExtendedModes(this: *mut core::ffi::c_void, mode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "SetExtendedModes", 43usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).SetExtendedModes)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlushTimer(this: *mut core::ffi::c_void, seconds: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "FlushTimer", 44usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).FlushTimer)(this, seconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFlushTimer(this: *mut core::ffi::c_void, seconds: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "SetFlushTimer", 45usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).SetFlushTimer)(this, seconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreeBuffers(this: *mut core::ffi::c_void, buffers: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "FreeBuffers", 46usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).FreeBuffers)(this, buffers), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFreeBuffers(this: *mut core::ffi::c_void, buffers: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "SetFreeBuffers", 47usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).SetFreeBuffers)(this, buffers), |result| Some(*result))
+        }
+        unsafe extern "system" fn Guid(this: *mut core::ffi::c_void, guid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "Guid", 48usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).Guid)(this, guid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGuid(this: *mut core::ffi::c_void, guid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "SetGuid", 49usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).SetGuid)(this, guid), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsKernelTrace(this: *mut core::ffi::c_void, kernel: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "IsKernelTrace", 50usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).IsKernelTrace)(this, kernel), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaximumBuffers(this: *mut core::ffi::c_void, buffers: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "MaximumBuffers", 51usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).MaximumBuffers)(this, buffers), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaximumBuffers(this: *mut core::ffi::c_void, buffers: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "SetMaximumBuffers", 52usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).SetMaximumBuffers)(this, buffers), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinimumBuffers(this: *mut core::ffi::c_void, buffers: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "MinimumBuffers", 53usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).MinimumBuffers)(this, buffers), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinimumBuffers(this: *mut core::ffi::c_void, buffers: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "SetMinimumBuffers", 54usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).SetMinimumBuffers)(this, buffers), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfBuffers(this: *mut core::ffi::c_void, buffers: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "NumberOfBuffers", 55usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).NumberOfBuffers)(this, buffers), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNumberOfBuffers(this: *mut core::ffi::c_void, buffers: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "SetNumberOfBuffers", 56usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).SetNumberOfBuffers)(this, buffers), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreallocateFile(this: *mut core::ffi::c_void, allocate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "PreallocateFile", 57usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).PreallocateFile)(this, allocate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreallocateFile(this: *mut core::ffi::c_void, allocate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "SetPreallocateFile", 58usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).SetPreallocateFile)(this, allocate), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessMode(this: *mut core::ffi::c_void, process: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "ProcessMode", 59usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).ProcessMode)(this, process), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProcessMode(this: *mut core::ffi::c_void, process: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "SetProcessMode", 60usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).SetProcessMode)(this, process), |result| Some(*result))
+        }
+        unsafe extern "system" fn RealTimeBuffersLost(this: *mut core::ffi::c_void, buffers: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "RealTimeBuffersLost", 61usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).RealTimeBuffersLost)(this, buffers), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRealTimeBuffersLost(this: *mut core::ffi::c_void, buffers: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "SetRealTimeBuffersLost", 62usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).SetRealTimeBuffersLost)(this, buffers), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionId(this: *mut core::ffi::c_void, id: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "SessionId", 63usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).SessionId)(this, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSessionId(this: *mut core::ffi::c_void, id: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "SetSessionId", 64usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).SetSessionId)(this, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionName(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "SessionName", 65usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).SessionName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSessionName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "SetSessionName", 66usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).SetSessionName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionThreadId(this: *mut core::ffi::c_void, tid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "SessionThreadId", 67usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).SessionThreadId)(this, tid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSessionThreadId(this: *mut core::ffi::c_void, tid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "SetSessionThreadId", 68usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).SetSessionThreadId)(this, tid), |result| Some(*result))
+        }
+        unsafe extern "system" fn StreamMode(this: *mut core::ffi::c_void, mode: *mut StreamMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "StreamMode", 69usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).StreamMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStreamMode(this: *mut core::ffi::c_void, mode: StreamMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "SetStreamMode", 70usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).SetStreamMode)(this, mode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn TraceDataProviders(this: *mut core::ffi::c_void, providers: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataCollector", "TraceDataProviders", 71usize, |this| ((**(this as *const *const ITraceDataCollector_Vtbl)).TraceDataProviders)(this, providers), |result| Some(*result))
+        }
+        Self {
+            base__: <IDataCollector_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BufferSize,
+            SetBufferSize,
+            BuffersLost,
+            SetBuffersLost,
+            BuffersWritten,
+            SetBuffersWritten,
+            ClockType,
+            SetClockType,
+            EventsLost,
+            SetEventsLost,
+            ExtendedModes,
+            SetExtendedModes,
+            FlushTimer,
+            SetFlushTimer,
+            FreeBuffers,
+            SetFreeBuffers,
+            Guid,
+            SetGuid,
+            IsKernelTrace,
+            MaximumBuffers,
+            SetMaximumBuffers,
+            MinimumBuffers,
+            SetMinimumBuffers,
+            NumberOfBuffers,
+            SetNumberOfBuffers,
+            PreallocateFile,
+            SetPreallocateFile,
+            ProcessMode,
+            SetProcessMode,
+            RealTimeBuffersLost,
+            SetRealTimeBuffersLost,
+            SessionId,
+            SetSessionId,
+            SessionName,
+            SetSessionName,
+            SessionThreadId,
+            SetSessionThreadId,
+            StreamMode,
+            SetStreamMode,
+            #[cfg(feature = "Win32_System_Com")]
+            TraceDataProviders,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            TraceDataProviders: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITraceDataCollector as windows_core::Interface>::IID || <IDataCollector_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITraceDataProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProvider", "DisplayName", 7usize, |this| ((**(this as *const *const ITraceDataProvider_Vtbl)).DisplayName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProvider", "SetDisplayName", 8usize, |this| ((**(this as *const *const ITraceDataProvider_Vtbl)).SetDisplayName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn Guid(this: *mut core::ffi::c_void, guid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProvider", "Guid", 9usize, |this| ((**(this as *const *const ITraceDataProvider_Vtbl)).Guid)(this, guid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGuid(this: *mut core::ffi::c_void, guid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProvider", "SetGuid", 10usize, |this| ((**(this as *const *const ITraceDataProvider_Vtbl)).SetGuid)(this, guid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Level(this: *mut core::ffi::c_void, pplevel: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProvider", "Level", 11usize, |this| ((**(this as *const *const ITraceDataProvider_Vtbl)).Level)(this, pplevel), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn KeywordsAny(this: *mut core::ffi::c_void, ppkeywords: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProvider", "KeywordsAny", 12usize, |this| ((**(this as *const *const ITraceDataProvider_Vtbl)).KeywordsAny)(this, ppkeywords), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn KeywordsAll(this: *mut core::ffi::c_void, ppkeywords: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProvider", "KeywordsAll", 13usize, |this| ((**(this as *const *const ITraceDataProvider_Vtbl)).KeywordsAll)(this, ppkeywords), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties(this: *mut core::ffi::c_void, ppproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProvider", "Properties", 14usize, |this| ((**(this as *const *const ITraceDataProvider_Vtbl)).Properties)(this, ppproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn FilterEnabled(this: *mut core::ffi::c_void, filterenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProvider", "FilterEnabled", 15usize, |this| ((**(this as *const *const ITraceDataProvider_Vtbl)).FilterEnabled)(this, filterenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFilterEnabled(this: *mut core::ffi::c_void, filterenabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProvider", "SetFilterEnabled", 16usize, |this| ((**(this as *const *const ITraceDataProvider_Vtbl)).SetFilterEnabled)(this, filterenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn FilterType(this: *mut core::ffi::c_void, pultype: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProvider", "FilterType", 17usize, |this| ((**(this as *const *const ITraceDataProvider_Vtbl)).FilterType)(this, pultype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFilterType(this: *mut core::ffi::c_void, ultype: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProvider", "SetFilterType", 18usize, |this| ((**(this as *const *const ITraceDataProvider_Vtbl)).SetFilterType)(this, ultype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn FilterData(this: *mut core::ffi::c_void, ppdata: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProvider", "FilterData", 19usize, |this| ((**(this as *const *const ITraceDataProvider_Vtbl)).FilterData)(this, ppdata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetFilterData(this: *mut core::ffi::c_void, pdata: *const super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProvider", "SetFilterData", 20usize, |this| ((**(this as *const *const ITraceDataProvider_Vtbl)).SetFilterData)(this, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn Query(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, bstrserver: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProvider", "Query", 21usize, |this| ((**(this as *const *const ITraceDataProvider_Vtbl)).Query)(this, bstrname, bstrserver), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Resolve(this: *mut core::ffi::c_void, pfrom: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProvider", "Resolve", 22usize, |this| ((**(this as *const *const ITraceDataProvider_Vtbl)).Resolve)(this, pfrom), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSecurity(this: *mut core::ffi::c_void, sddl: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProvider", "SetSecurity", 23usize, |this| ((**(this as *const *const ITraceDataProvider_Vtbl)).SetSecurity)(this, sddl), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSecurity(this: *mut core::ffi::c_void, securityinfo: u32, sddl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProvider", "GetSecurity", 24usize, |this| ((**(this as *const *const ITraceDataProvider_Vtbl)).GetSecurity)(this, securityinfo, sddl), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetRegisteredProcesses(this: *mut core::ffi::c_void, processes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProvider", "GetRegisteredProcesses", 25usize, |this| ((**(this as *const *const ITraceDataProvider_Vtbl)).GetRegisteredProcesses)(this, processes), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DisplayName,
+            SetDisplayName,
+            Guid,
+            SetGuid,
+            #[cfg(feature = "Win32_System_Com")]
+            Level,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Level: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            KeywordsAny,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            KeywordsAny: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            KeywordsAll,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            KeywordsAll: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties: 0,
+            FilterEnabled,
+            SetFilterEnabled,
+            FilterType,
+            SetFilterType,
+            #[cfg(feature = "Win32_System_Com")]
+            FilterData,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            FilterData: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetFilterData,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetFilterData: 0,
+            Query,
+            #[cfg(feature = "Win32_System_Com")]
+            Resolve,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Resolve: 0,
+            SetSecurity,
+            GetSecurity,
+            #[cfg(feature = "Win32_System_Com")]
+            GetRegisteredProcesses,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetRegisteredProcesses: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITraceDataProvider as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITraceDataProviderCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProviderCollection", "Count", 7usize, |this| ((**(this as *const *const ITraceDataProviderCollection_Vtbl)).Count)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>, ppprovider: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProviderCollection", "get_Item", 8usize, |this| ((**(this as *const *const ITraceDataProviderCollection_Vtbl)).get_Item)(this, index, ppprovider), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProviderCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const ITraceDataProviderCollection_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pprovider: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProviderCollection", "Add", 10usize, |this| ((**(this as *const *const ITraceDataProviderCollection_Vtbl)).Add)(this, pprovider), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, vprovider: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProviderCollection", "Remove", 11usize, |this| ((**(this as *const *const ITraceDataProviderCollection_Vtbl)).Remove)(this, vprovider), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProviderCollection", "Clear", 12usize, |this| ((**(this as *const *const ITraceDataProviderCollection_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddRange(this: *mut core::ffi::c_void, providers: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProviderCollection", "AddRange", 13usize, |this| ((**(this as *const *const ITraceDataProviderCollection_Vtbl)).AddRange)(this, providers), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateTraceDataProvider(this: *mut core::ffi::c_void, provider: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProviderCollection", "CreateTraceDataProvider", 14usize, |this| ((**(this as *const *const ITraceDataProviderCollection_Vtbl)).CreateTraceDataProvider)(this, provider), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTraceDataProviders(this: *mut core::ffi::c_void, server: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProviderCollection", "GetTraceDataProviders", 15usize, |this| ((**(this as *const *const ITraceDataProviderCollection_Vtbl)).GetTraceDataProviders)(this, server), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTraceDataProvidersByProcess(this: *mut core::ffi::c_void, server: core::mem::MaybeUninit<windows_core::BSTR>, pid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITraceDataProviderCollection", "GetTraceDataProvidersByProcess", 16usize, |this| ((**(this as *const *const ITraceDataProviderCollection_Vtbl)).GetTraceDataProvidersByProcess)(this, server, pid), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            Clear,
+            #[cfg(feature = "Win32_System_Com")]
+            AddRange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddRange: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateTraceDataProvider,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateTraceDataProvider: 0,
+            GetTraceDataProviders,
+            GetTraceDataProvidersByProcess,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITraceDataProviderCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IValueMap_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueMap", "Count", 7usize, |this| ((**(this as *const *const IValueMap_Vtbl)).Count)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueMap", "get_Item", 8usize, |this| ((**(this as *const *const IValueMap_Vtbl)).get_Item)(this, index, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueMap", "_NewEnum", 9usize, |this| ((**(this as *const *const IValueMap_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, description: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueMap", "Description", 10usize, |this| ((**(this as *const *const IValueMap_Vtbl)).Description)(this, description), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, description: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueMap", "SetDescription", 11usize, |this| ((**(this as *const *const IValueMap_Vtbl)).SetDescription)(this, description), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueMap", "Value", 12usize, |this| ((**(this as *const *const IValueMap_Vtbl)).Value)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueMap", "SetValue", 13usize, |this| ((**(this as *const *const IValueMap_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValueMapType(this: *mut core::ffi::c_void, r#type: *mut ValueMapType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueMap", "ValueMapType", 14usize, |this| ((**(this as *const *const IValueMap_Vtbl)).ValueMapType)(this, r#type), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValueMapType(this: *mut core::ffi::c_void, r#type: ValueMapType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueMap", "SetValueMapType", 15usize, |this| ((**(this as *const *const IValueMap_Vtbl)).SetValueMapType)(this, r#type), |result| Some(*result))
+        }
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueMap", "Add", 16usize, |this| ((**(this as *const *const IValueMap_Vtbl)).Add)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueMap", "Remove", 17usize, |this| ((**(this as *const *const IValueMap_Vtbl)).Remove)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueMap", "Clear", 18usize, |this| ((**(this as *const *const IValueMap_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddRange(this: *mut core::ffi::c_void, map: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueMap", "AddRange", 19usize, |this| ((**(this as *const *const IValueMap_Vtbl)).AddRange)(this, map), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateValueMapItem(this: *mut core::ffi::c_void, item: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueMap", "CreateValueMapItem", 20usize, |this| ((**(this as *const *const IValueMap_Vtbl)).CreateValueMapItem)(this, item), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            _NewEnum,
+            Description,
+            SetDescription,
+            Value,
+            SetValue,
+            ValueMapType,
+            SetValueMapType,
+            Add,
+            Remove,
+            Clear,
+            #[cfg(feature = "Win32_System_Com")]
+            AddRange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddRange: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateValueMapItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateValueMapItem: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IValueMap as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IValueMapItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, description: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueMapItem", "Description", 7usize, |this| ((**(this as *const *const IValueMapItem_Vtbl)).Description)(this, description), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, description: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueMapItem", "SetDescription", 8usize, |this| ((**(this as *const *const IValueMapItem_Vtbl)).SetDescription)(this, description), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, enabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueMapItem", "Enabled", 9usize, |this| ((**(this as *const *const IValueMapItem_Vtbl)).Enabled)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, enabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueMapItem", "SetEnabled", 10usize, |this| ((**(this as *const *const IValueMapItem_Vtbl)).SetEnabled)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn Key(this: *mut core::ffi::c_void, key: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueMapItem", "Key", 11usize, |this| ((**(this as *const *const IValueMapItem_Vtbl)).Key)(this, key), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKey(this: *mut core::ffi::c_void, key: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueMapItem", "SetKey", 12usize, |this| ((**(this as *const *const IValueMapItem_Vtbl)).SetKey)(this, key), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueMapItem", "Value", 13usize, |this| ((**(this as *const *const IValueMapItem_Vtbl)).Value)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueMapItem", "SetValue", 14usize, |this| ((**(this as *const *const IValueMapItem_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValueMapType(this: *mut core::ffi::c_void, r#type: *mut ValueMapType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueMapItem", "ValueMapType", 15usize, |this| ((**(this as *const *const IValueMapItem_Vtbl)).ValueMapType)(this, r#type), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValueMapType(this: *mut core::ffi::c_void, r#type: ValueMapType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueMapItem", "SetValueMapType", 16usize, |this| ((**(this as *const *const IValueMapItem_Vtbl)).SetValueMapType)(this, r#type), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Description,
+            SetDescription,
+            Enabled,
+            SetEnabled,
+            Key,
+            SetKey,
+            Value,
+            SetValue,
+            ValueMapType,
+            SetValueMapType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IValueMapItem as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for _ICounterItemUnion_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, pdblvalue: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ICounterItemUnion", "Value", 3usize, |this| ((**(this as *const *const _ICounterItemUnion_Vtbl)).Value)(this, pdblvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColor(this: *mut core::ffi::c_void, color: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ICounterItemUnion", "SetColor", 4usize, |this| ((**(this as *const *const _ICounterItemUnion_Vtbl)).SetColor)(this, color), |result| Some(*result))
+        }
+        unsafe extern "system" fn Color(this: *mut core::ffi::c_void, pcolor: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ICounterItemUnion", "Color", 5usize, |this| ((**(this as *const *const _ICounterItemUnion_Vtbl)).Color)(this, pcolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWidth(this: *mut core::ffi::c_void, iwidth: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ICounterItemUnion", "SetWidth", 6usize, |this| ((**(this as *const *const _ICounterItemUnion_Vtbl)).SetWidth)(this, iwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn Width(this: *mut core::ffi::c_void, pivalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ICounterItemUnion", "Width", 7usize, |this| ((**(this as *const *const _ICounterItemUnion_Vtbl)).Width)(this, pivalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLineStyle(this: *mut core::ffi::c_void, ilinestyle: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ICounterItemUnion", "SetLineStyle", 8usize, |this| ((**(this as *const *const _ICounterItemUnion_Vtbl)).SetLineStyle)(this, ilinestyle), |result| Some(*result))
+        }
+        unsafe extern "system" fn LineStyle(this: *mut core::ffi::c_void, pivalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ICounterItemUnion", "LineStyle", 9usize, |this| ((**(this as *const *const _ICounterItemUnion_Vtbl)).LineStyle)(this, pivalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScaleFactor(this: *mut core::ffi::c_void, iscale: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ICounterItemUnion", "SetScaleFactor", 10usize, |this| ((**(this as *const *const _ICounterItemUnion_Vtbl)).SetScaleFactor)(this, iscale), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScaleFactor(this: *mut core::ffi::c_void, pivalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ICounterItemUnion", "ScaleFactor", 11usize, |this| ((**(this as *const *const _ICounterItemUnion_Vtbl)).ScaleFactor)(this, pivalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, pstrvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ICounterItemUnion", "Path", 12usize, |this| ((**(this as *const *const _ICounterItemUnion_Vtbl)).Path)(this, pstrvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, value: *mut f64, status: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ICounterItemUnion", "GetValue", 13usize, |this| ((**(this as *const *const _ICounterItemUnion_Vtbl)).GetValue)(this, value, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatistics(this: *mut core::ffi::c_void, max: *mut f64, min: *mut f64, avg: *mut f64, status: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ICounterItemUnion", "GetStatistics", 14usize, |this| ((**(this as *const *const _ICounterItemUnion_Vtbl)).GetStatistics)(this, max, min, avg, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelected(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ICounterItemUnion", "SetSelected", 15usize, |this| ((**(this as *const *const _ICounterItemUnion_Vtbl)).SetSelected)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Selected(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ICounterItemUnion", "Selected", 16usize, |this| ((**(this as *const *const _ICounterItemUnion_Vtbl)).Selected)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVisible(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ICounterItemUnion", "SetVisible", 17usize, |this| ((**(this as *const *const _ICounterItemUnion_Vtbl)).SetVisible)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Visible(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ICounterItemUnion", "Visible", 18usize, |this| ((**(this as *const *const _ICounterItemUnion_Vtbl)).Visible)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataAt(this: *mut core::ffi::c_void, iindex: i32, iwhich: SysmonDataType, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ICounterItemUnion", "GetDataAt", 19usize, |this| ((**(this as *const *const _ICounterItemUnion_Vtbl)).GetDataAt)(this, iindex, iwhich, pvariant), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Value,
+            SetColor,
+            Color,
+            SetWidth,
+            Width,
+            SetLineStyle,
+            LineStyle,
+            SetScaleFactor,
+            ScaleFactor,
+            Path,
+            GetValue,
+            GetStatistics,
+            SetSelected,
+            Selected,
+            SetVisible,
+            Visible,
+            GetDataAt,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<_ICounterItemUnion as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for _ISystemMonitorUnion_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Appearance(this: *mut core::ffi::c_void, iappearance: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "Appearance", 3usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).Appearance)(this, iappearance), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAppearance(this: *mut core::ffi::c_void, iappearance: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetAppearance", 4usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetAppearance)(this, iappearance), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackColor(this: *mut core::ffi::c_void, pcolor: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "BackColor", 5usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).BackColor)(this, pcolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackColor(this: *mut core::ffi::c_void, color: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetBackColor", 6usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetBackColor)(this, color), |result| Some(*result))
+        }
+        unsafe extern "system" fn BorderStyle(this: *mut core::ffi::c_void, iborderstyle: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "BorderStyle", 7usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).BorderStyle)(this, iborderstyle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBorderStyle(this: *mut core::ffi::c_void, iborderstyle: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetBorderStyle", 8usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetBorderStyle)(this, iborderstyle), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForeColor(this: *mut core::ffi::c_void, pcolor: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "ForeColor", 9usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).ForeColor)(this, pcolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetForeColor(this: *mut core::ffi::c_void, color: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetForeColor", 10usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetForeColor)(this, color), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+        unsafe extern "system" fn Font(this: *mut core::ffi::c_void, ppfont: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "Font", 11usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).Font)(this, ppfont), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+        unsafe extern "system" fn putref_Font(this: *mut core::ffi::c_void, pfont: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "putref_Font", 12usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).putref_Font)(this, pfont), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Counters(this: *mut core::ffi::c_void, ppicounters: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "Counters", 13usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).Counters)(this, ppicounters), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShowVerticalGrid(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetShowVerticalGrid", 14usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetShowVerticalGrid)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowVerticalGrid(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "ShowVerticalGrid", 15usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).ShowVerticalGrid)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShowHorizontalGrid(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetShowHorizontalGrid", 16usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetShowHorizontalGrid)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowHorizontalGrid(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "ShowHorizontalGrid", 17usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).ShowHorizontalGrid)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShowLegend(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetShowLegend", 18usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetShowLegend)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowLegend(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "ShowLegend", 19usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).ShowLegend)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShowScaleLabels(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetShowScaleLabels", 20usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetShowScaleLabels)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowScaleLabels(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "ShowScaleLabels", 21usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).ShowScaleLabels)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShowValueBar(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetShowValueBar", 22usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetShowValueBar)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowValueBar(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "ShowValueBar", 23usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).ShowValueBar)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaximumScale(this: *mut core::ffi::c_void, ivalue: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetMaximumScale", 24usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetMaximumScale)(this, ivalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaximumScale(this: *mut core::ffi::c_void, pivalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "MaximumScale", 25usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).MaximumScale)(this, pivalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinimumScale(this: *mut core::ffi::c_void, ivalue: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetMinimumScale", 26usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetMinimumScale)(this, ivalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinimumScale(this: *mut core::ffi::c_void, pivalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "MinimumScale", 27usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).MinimumScale)(this, pivalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUpdateInterval(this: *mut core::ffi::c_void, fvalue: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetUpdateInterval", 28usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetUpdateInterval)(this, fvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateInterval(this: *mut core::ffi::c_void, pfvalue: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "UpdateInterval", 29usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).UpdateInterval)(this, pfvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayType(this: *mut core::ffi::c_void, edisplaytype: DisplayTypeConstants) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetDisplayType", 30usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetDisplayType)(this, edisplaytype), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayType(this: *mut core::ffi::c_void, pedisplaytype: *mut DisplayTypeConstants) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "DisplayType", 31usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).DisplayType)(this, pedisplaytype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetManualUpdate(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetManualUpdate", 32usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetManualUpdate)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn ManualUpdate(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "ManualUpdate", 33usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).ManualUpdate)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGraphTitle(this: *mut core::ffi::c_void, bstitle: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetGraphTitle", 34usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetGraphTitle)(this, bstitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn GraphTitle(this: *mut core::ffi::c_void, pbstitle: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "GraphTitle", 35usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).GraphTitle)(this, pbstitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetYAxisLabel(this: *mut core::ffi::c_void, bstitle: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetYAxisLabel", 36usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetYAxisLabel)(this, bstitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn YAxisLabel(this: *mut core::ffi::c_void, pbstitle: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "YAxisLabel", 37usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).YAxisLabel)(this, pbstitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn CollectSample(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "CollectSample", 38usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).CollectSample)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateGraph(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "UpdateGraph", 39usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).UpdateGraph)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn BrowseCounters(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "BrowseCounters", 40usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).BrowseCounters)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayProperties(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "DisplayProperties", 41usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).DisplayProperties)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Counter(this: *mut core::ffi::c_void, iindex: i32, ppicounter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "Counter", 42usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).Counter)(this, iindex, ppicounter), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddCounter(this: *mut core::ffi::c_void, bspath: core::mem::MaybeUninit<windows_core::BSTR>, ppicounter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "AddCounter", 43usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).AddCounter)(this, bspath, ppicounter), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteCounter(this: *mut core::ffi::c_void, pctr: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "DeleteCounter", 44usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).DeleteCounter)(this, pctr), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackColorCtl(this: *mut core::ffi::c_void, pcolor: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "BackColorCtl", 45usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).BackColorCtl)(this, pcolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackColorCtl(this: *mut core::ffi::c_void, color: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetBackColorCtl", 46usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetBackColorCtl)(this, color), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLogFileName(this: *mut core::ffi::c_void, bsfilename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetLogFileName", 47usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetLogFileName)(this, bsfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogFileName(this: *mut core::ffi::c_void, bsfilename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "LogFileName", 48usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).LogFileName)(this, bsfilename), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLogViewStart(this: *mut core::ffi::c_void, starttime: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetLogViewStart", 49usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetLogViewStart)(this, starttime), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogViewStart(this: *mut core::ffi::c_void, starttime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "LogViewStart", 50usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).LogViewStart)(this, starttime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLogViewStop(this: *mut core::ffi::c_void, stoptime: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetLogViewStop", 51usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetLogViewStop)(this, stoptime), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogViewStop(this: *mut core::ffi::c_void, stoptime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "LogViewStop", 52usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).LogViewStop)(this, stoptime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GridColor(this: *mut core::ffi::c_void, pcolor: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "GridColor", 53usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).GridColor)(this, pcolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGridColor(this: *mut core::ffi::c_void, color: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetGridColor", 54usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetGridColor)(this, color), |result| Some(*result))
+        }
+        unsafe extern "system" fn TimeBarColor(this: *mut core::ffi::c_void, pcolor: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "TimeBarColor", 55usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).TimeBarColor)(this, pcolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTimeBarColor(this: *mut core::ffi::c_void, color: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetTimeBarColor", 56usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetTimeBarColor)(this, color), |result| Some(*result))
+        }
+        unsafe extern "system" fn Highlight(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "Highlight", 57usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).Highlight)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHighlight(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetHighlight", 58usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetHighlight)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowToolbar(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "ShowToolbar", 59usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).ShowToolbar)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShowToolbar(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetShowToolbar", 60usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetShowToolbar)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Paste(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "Paste", 61usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).Paste)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Copy(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "Copy", 62usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).Copy)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "Reset", 63usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReadOnly(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetReadOnly", 64usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetReadOnly)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadOnly(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "ReadOnly", 65usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).ReadOnly)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReportValueType(this: *mut core::ffi::c_void, ereportvaluetype: ReportValueTypeConstants) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetReportValueType", 66usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetReportValueType)(this, ereportvaluetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReportValueType(this: *mut core::ffi::c_void, pereportvaluetype: *mut ReportValueTypeConstants) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "ReportValueType", 67usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).ReportValueType)(this, pereportvaluetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMonitorDuplicateInstances(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetMonitorDuplicateInstances", 68usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetMonitorDuplicateInstances)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn MonitorDuplicateInstances(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "MonitorDuplicateInstances", 69usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).MonitorDuplicateInstances)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayFilter(this: *mut core::ffi::c_void, ivalue: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetDisplayFilter", 70usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetDisplayFilter)(this, ivalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayFilter(this: *mut core::ffi::c_void, pivalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "DisplayFilter", 71usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).DisplayFilter)(this, pivalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn LogFiles(this: *mut core::ffi::c_void, ppilogfiles: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "LogFiles", 72usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).LogFiles)(this, ppilogfiles), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDataSourceType(this: *mut core::ffi::c_void, edatasourcetype: DataSourceTypeConstants) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetDataSourceType", 73usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetDataSourceType)(this, edatasourcetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataSourceType(this: *mut core::ffi::c_void, pedatasourcetype: *mut DataSourceTypeConstants) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "DataSourceType", 74usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).DataSourceType)(this, pedatasourcetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSqlDsnName(this: *mut core::ffi::c_void, bssqldsnname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetSqlDsnName", 75usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetSqlDsnName)(this, bssqldsnname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SqlDsnName(this: *mut core::ffi::c_void, bssqldsnname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SqlDsnName", 76usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SqlDsnName)(this, bssqldsnname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSqlLogSetName(this: *mut core::ffi::c_void, bssqllogsetname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetSqlLogSetName", 77usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetSqlLogSetName)(this, bssqllogsetname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SqlLogSetName(this: *mut core::ffi::c_void, bssqllogsetname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SqlLogSetName", 78usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SqlLogSetName)(this, bssqllogsetname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnableDigitGrouping(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetEnableDigitGrouping", 79usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetEnableDigitGrouping)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableDigitGrouping(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "EnableDigitGrouping", 80usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).EnableDigitGrouping)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnableToolTips(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetEnableToolTips", 81usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetEnableToolTips)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableToolTips(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "EnableToolTips", 82usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).EnableToolTips)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShowTimeAxisLabels(this: *mut core::ffi::c_void, bstate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetShowTimeAxisLabels", 83usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetShowTimeAxisLabels)(this, bstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowTimeAxisLabels(this: *mut core::ffi::c_void, pbstate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "ShowTimeAxisLabels", 84usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).ShowTimeAxisLabels)(this, pbstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetChartScroll(this: *mut core::ffi::c_void, bscroll: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetChartScroll", 85usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetChartScroll)(this, bscroll), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChartScroll(this: *mut core::ffi::c_void, pbscroll: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "ChartScroll", 86usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).ChartScroll)(this, pbscroll), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDataPointCount(this: *mut core::ffi::c_void, inewcount: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetDataPointCount", 87usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetDataPointCount)(this, inewcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataPointCount(this: *mut core::ffi::c_void, pidatapointcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "DataPointCount", 88usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).DataPointCount)(this, pidatapointcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScaleToFit(this: *mut core::ffi::c_void, bselectedcountersonly: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "ScaleToFit", 89usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).ScaleToFit)(this, bselectedcountersonly), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveAs(this: *mut core::ffi::c_void, bstrfilename: core::mem::MaybeUninit<windows_core::BSTR>, esysmonfiletype: SysmonFileType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SaveAs", 90usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SaveAs)(this, bstrfilename, esysmonfiletype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Relog(this: *mut core::ffi::c_void, bstrfilename: core::mem::MaybeUninit<windows_core::BSTR>, esysmonfiletype: SysmonFileType, ifilter: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "Relog", 91usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).Relog)(this, bstrfilename, esysmonfiletype, ifilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearData(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "ClearData", 92usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).ClearData)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogSourceStartTime(this: *mut core::ffi::c_void, pdate: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "LogSourceStartTime", 93usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).LogSourceStartTime)(this, pdate), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogSourceStopTime(this: *mut core::ffi::c_void, pdate: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "LogSourceStopTime", 94usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).LogSourceStopTime)(this, pdate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLogViewRange(this: *mut core::ffi::c_void, starttime: f64, stoptime: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "SetLogViewRange", 95usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).SetLogViewRange)(this, starttime, stoptime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLogViewRange(this: *mut core::ffi::c_void, starttime: *mut f64, stoptime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "GetLogViewRange", 96usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).GetLogViewRange)(this, starttime, stoptime), |result| Some(*result))
+        }
+        unsafe extern "system" fn BatchingLock(this: *mut core::ffi::c_void, flock: super::super::Foundation::VARIANT_BOOL, ebatchreason: SysmonBatchReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "BatchingLock", 97usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).BatchingLock)(this, flock, ebatchreason), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadSettings(this: *mut core::ffi::c_void, bstrsettingfilename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_ISystemMonitorUnion", "LoadSettings", 98usize, |this| ((**(this as *const *const _ISystemMonitorUnion_Vtbl)).LoadSettings)(this, bstrsettingfilename), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Appearance,
+            SetAppearance,
+            BackColor,
+            SetBackColor,
+            BorderStyle,
+            SetBorderStyle,
+            ForeColor,
+            SetForeColor,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+            Font,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Ole")))]
+            Font: 0,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+            putref_Font,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Ole")))]
+            putref_Font: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Counters,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Counters: 0,
+            SetShowVerticalGrid,
+            ShowVerticalGrid,
+            SetShowHorizontalGrid,
+            ShowHorizontalGrid,
+            SetShowLegend,
+            ShowLegend,
+            SetShowScaleLabels,
+            ShowScaleLabels,
+            SetShowValueBar,
+            ShowValueBar,
+            SetMaximumScale,
+            MaximumScale,
+            SetMinimumScale,
+            MinimumScale,
+            SetUpdateInterval,
+            UpdateInterval,
+            SetDisplayType,
+            DisplayType,
+            SetManualUpdate,
+            ManualUpdate,
+            SetGraphTitle,
+            GraphTitle,
+            SetYAxisLabel,
+            YAxisLabel,
+            CollectSample,
+            UpdateGraph,
+            BrowseCounters,
+            DisplayProperties,
+            Counter,
+            AddCounter,
+            DeleteCounter,
+            BackColorCtl,
+            SetBackColorCtl,
+            SetLogFileName,
+            LogFileName,
+            SetLogViewStart,
+            LogViewStart,
+            SetLogViewStop,
+            LogViewStop,
+            GridColor,
+            SetGridColor,
+            TimeBarColor,
+            SetTimeBarColor,
+            Highlight,
+            SetHighlight,
+            ShowToolbar,
+            SetShowToolbar,
+            Paste,
+            Copy,
+            Reset,
+            SetReadOnly,
+            ReadOnly,
+            SetReportValueType,
+            ReportValueType,
+            SetMonitorDuplicateInstances,
+            MonitorDuplicateInstances,
+            SetDisplayFilter,
+            DisplayFilter,
+            #[cfg(feature = "Win32_System_Com")]
+            LogFiles,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            LogFiles: 0,
+            SetDataSourceType,
+            DataSourceType,
+            SetSqlDsnName,
+            SqlDsnName,
+            SetSqlLogSetName,
+            SqlLogSetName,
+            SetEnableDigitGrouping,
+            EnableDigitGrouping,
+            SetEnableToolTips,
+            EnableToolTips,
+            SetShowTimeAxisLabels,
+            ShowTimeAxisLabels,
+            SetChartScroll,
+            ChartScroll,
+            SetDataPointCount,
+            DataPointCount,
+            ScaleToFit,
+            SaveAs,
+            Relog,
+            ClearData,
+            LogSourceStartTime,
+            LogSourceStopTime,
+            SetLogViewRange,
+            GetLogViewRange,
+            BatchingLock,
+            LoadSettings,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<_ISystemMonitorUnion as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
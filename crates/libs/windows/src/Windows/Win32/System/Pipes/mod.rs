@@ -197,6 +197,7 @@ pub struct NAMED_PIPE_MODE(pub u32);
 impl windows_core::TypeKind for NAMED_PIPE_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NAMED_PIPE_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NAMED_PIPE_MODE").field(&self.0).finish()
@@ -235,3 +236,4 @@ impl core::ops::Not for NAMED_PIPE_MODE {
         Self(self.0.not())
     }
 }
+core::include!(core::concat!(core::env!("CARGO_MANIFEST_DIR"), "/src/includes/", "Win32/System/Pipes/NamedPipe.rs"));
@@ -3463,6 +3463,7 @@ pub struct RTC_ACE_SCOPE(pub i32);
 impl windows_core::TypeKind for RTC_ACE_SCOPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_ACE_SCOPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_ACE_SCOPE").field(&self.0).finish()
@@ -3474,6 +3475,7 @@ pub struct RTC_ANSWER_MODE(pub i32);
 impl windows_core::TypeKind for RTC_ANSWER_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_ANSWER_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_ANSWER_MODE").field(&self.0).finish()
@@ -3485,6 +3487,7 @@ pub struct RTC_AUDIO_DEVICE(pub i32);
 impl windows_core::TypeKind for RTC_AUDIO_DEVICE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_AUDIO_DEVICE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_AUDIO_DEVICE").field(&self.0).finish()
@@ -3496,6 +3499,7 @@ pub struct RTC_BUDDY_EVENT_TYPE(pub i32);
 impl windows_core::TypeKind for RTC_BUDDY_EVENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_BUDDY_EVENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_BUDDY_EVENT_TYPE").field(&self.0).finish()
@@ -3507,6 +3511,7 @@ pub struct RTC_BUDDY_SUBSCRIPTION_TYPE(pub i32);
 impl windows_core::TypeKind for RTC_BUDDY_SUBSCRIPTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_BUDDY_SUBSCRIPTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_BUDDY_SUBSCRIPTION_TYPE").field(&self.0).finish()
@@ -3518,6 +3523,7 @@ pub struct RTC_CLIENT_EVENT_TYPE(pub i32);
 impl windows_core::TypeKind for RTC_CLIENT_EVENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_CLIENT_EVENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_CLIENT_EVENT_TYPE").field(&self.0).finish()
@@ -3529,6 +3535,7 @@ pub struct RTC_DTMF(pub i32);
 impl windows_core::TypeKind for RTC_DTMF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_DTMF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_DTMF").field(&self.0).finish()
@@ -3540,6 +3547,7 @@ pub struct RTC_EVENT(pub i32);
 impl windows_core::TypeKind for RTC_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_EVENT").field(&self.0).finish()
@@ -3551,6 +3559,7 @@ pub struct RTC_GROUP_EVENT_TYPE(pub i32);
 impl windows_core::TypeKind for RTC_GROUP_EVENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_GROUP_EVENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_GROUP_EVENT_TYPE").field(&self.0).finish()
@@ -3562,6 +3571,7 @@ pub struct RTC_LISTEN_MODE(pub i32);
 impl windows_core::TypeKind for RTC_LISTEN_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_LISTEN_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_LISTEN_MODE").field(&self.0).finish()
@@ -3573,6 +3583,7 @@ pub struct RTC_MEDIA_EVENT_REASON(pub i32);
 impl windows_core::TypeKind for RTC_MEDIA_EVENT_REASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_MEDIA_EVENT_REASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_MEDIA_EVENT_REASON").field(&self.0).finish()
@@ -3584,6 +3595,7 @@ pub struct RTC_MEDIA_EVENT_TYPE(pub i32);
 impl windows_core::TypeKind for RTC_MEDIA_EVENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_MEDIA_EVENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_MEDIA_EVENT_TYPE").field(&self.0).finish()
@@ -3595,6 +3607,7 @@ pub struct RTC_MESSAGING_EVENT_TYPE(pub i32);
 impl windows_core::TypeKind for RTC_MESSAGING_EVENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_MESSAGING_EVENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_MESSAGING_EVENT_TYPE").field(&self.0).finish()
@@ -3606,6 +3619,7 @@ pub struct RTC_MESSAGING_USER_STATUS(pub i32);
 impl windows_core::TypeKind for RTC_MESSAGING_USER_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_MESSAGING_USER_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_MESSAGING_USER_STATUS").field(&self.0).finish()
@@ -3617,6 +3631,7 @@ pub struct RTC_OFFER_WATCHER_MODE(pub i32);
 impl windows_core::TypeKind for RTC_OFFER_WATCHER_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_OFFER_WATCHER_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_OFFER_WATCHER_MODE").field(&self.0).finish()
@@ -3628,6 +3643,7 @@ pub struct RTC_PARTICIPANT_STATE(pub i32);
 impl windows_core::TypeKind for RTC_PARTICIPANT_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_PARTICIPANT_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_PARTICIPANT_STATE").field(&self.0).finish()
@@ -3639,6 +3655,7 @@ pub struct RTC_PORT_TYPE(pub i32);
 impl windows_core::TypeKind for RTC_PORT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_PORT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_PORT_TYPE").field(&self.0).finish()
@@ -3650,6 +3667,7 @@ pub struct RTC_PRESENCE_PROPERTY(pub i32);
 impl windows_core::TypeKind for RTC_PRESENCE_PROPERTY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_PRESENCE_PROPERTY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_PRESENCE_PROPERTY").field(&self.0).finish()
@@ -3661,6 +3679,7 @@ pub struct RTC_PRESENCE_STATUS(pub i32);
 impl windows_core::TypeKind for RTC_PRESENCE_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_PRESENCE_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_PRESENCE_STATUS").field(&self.0).finish()
@@ -3672,6 +3691,7 @@ pub struct RTC_PRIVACY_MODE(pub i32);
 impl windows_core::TypeKind for RTC_PRIVACY_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_PRIVACY_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_PRIVACY_MODE").field(&self.0).finish()
@@ -3683,6 +3703,7 @@ pub struct RTC_PROFILE_EVENT_TYPE(pub i32);
 impl windows_core::TypeKind for RTC_PROFILE_EVENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_PROFILE_EVENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_PROFILE_EVENT_TYPE").field(&self.0).finish()
@@ -3694,6 +3715,7 @@ pub struct RTC_PROVIDER_URI(pub i32);
 impl windows_core::TypeKind for RTC_PROVIDER_URI {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_PROVIDER_URI {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_PROVIDER_URI").field(&self.0).finish()
@@ -3705,6 +3727,7 @@ pub struct RTC_REGISTRATION_STATE(pub i32);
 impl windows_core::TypeKind for RTC_REGISTRATION_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_REGISTRATION_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_REGISTRATION_STATE").field(&self.0).finish()
@@ -3716,6 +3739,7 @@ pub struct RTC_REINVITE_STATE(pub i32);
 impl windows_core::TypeKind for RTC_REINVITE_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_REINVITE_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_REINVITE_STATE").field(&self.0).finish()
@@ -3727,6 +3751,7 @@ pub struct RTC_RING_TYPE(pub i32);
 impl windows_core::TypeKind for RTC_RING_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_RING_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_RING_TYPE").field(&self.0).finish()
@@ -3738,6 +3763,7 @@ pub struct RTC_ROAMING_EVENT_TYPE(pub i32);
 impl windows_core::TypeKind for RTC_ROAMING_EVENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_ROAMING_EVENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_ROAMING_EVENT_TYPE").field(&self.0).finish()
@@ -3749,6 +3775,7 @@ pub struct RTC_SECURITY_LEVEL(pub i32);
 impl windows_core::TypeKind for RTC_SECURITY_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_SECURITY_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_SECURITY_LEVEL").field(&self.0).finish()
@@ -3760,6 +3787,7 @@ pub struct RTC_SECURITY_TYPE(pub i32);
 impl windows_core::TypeKind for RTC_SECURITY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_SECURITY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_SECURITY_TYPE").field(&self.0).finish()
@@ -3771,6 +3799,7 @@ pub struct RTC_SESSION_REFER_STATUS(pub i32);
 impl windows_core::TypeKind for RTC_SESSION_REFER_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_SESSION_REFER_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_SESSION_REFER_STATUS").field(&self.0).finish()
@@ -3782,6 +3811,7 @@ pub struct RTC_SESSION_STATE(pub i32);
 impl windows_core::TypeKind for RTC_SESSION_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_SESSION_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_SESSION_STATE").field(&self.0).finish()
@@ -3793,6 +3823,7 @@ pub struct RTC_SESSION_TYPE(pub i32);
 impl windows_core::TypeKind for RTC_SESSION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_SESSION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_SESSION_TYPE").field(&self.0).finish()
@@ -3804,6 +3835,7 @@ pub struct RTC_T120_APPLET(pub i32);
 impl windows_core::TypeKind for RTC_T120_APPLET {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_T120_APPLET {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_T120_APPLET").field(&self.0).finish()
@@ -3815,6 +3847,7 @@ pub struct RTC_TERMINATE_REASON(pub i32);
 impl windows_core::TypeKind for RTC_TERMINATE_REASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_TERMINATE_REASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_TERMINATE_REASON").field(&self.0).finish()
@@ -3826,6 +3859,7 @@ pub struct RTC_USER_SEARCH_COLUMN(pub i32);
 impl windows_core::TypeKind for RTC_USER_SEARCH_COLUMN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_USER_SEARCH_COLUMN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_USER_SEARCH_COLUMN").field(&self.0).finish()
@@ -3837,6 +3871,7 @@ pub struct RTC_USER_SEARCH_PREFERENCE(pub i32);
 impl windows_core::TypeKind for RTC_USER_SEARCH_PREFERENCE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_USER_SEARCH_PREFERENCE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_USER_SEARCH_PREFERENCE").field(&self.0).finish()
@@ -3848,6 +3883,7 @@ pub struct RTC_VIDEO_DEVICE(pub i32);
 impl windows_core::TypeKind for RTC_VIDEO_DEVICE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_VIDEO_DEVICE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_VIDEO_DEVICE").field(&self.0).finish()
@@ -3859,6 +3895,7 @@ pub struct RTC_WATCHER_EVENT_TYPE(pub i32);
 impl windows_core::TypeKind for RTC_WATCHER_EVENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_WATCHER_EVENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_WATCHER_EVENT_TYPE").field(&self.0).finish()
@@ -3870,6 +3907,7 @@ pub struct RTC_WATCHER_MATCH_MODE(pub i32);
 impl windows_core::TypeKind for RTC_WATCHER_MATCH_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_WATCHER_MATCH_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_WATCHER_MATCH_MODE").field(&self.0).finish()
@@ -3881,6 +3919,7 @@ pub struct RTC_WATCHER_STATE(pub i32);
 impl windows_core::TypeKind for RTC_WATCHER_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTC_WATCHER_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTC_WATCHER_STATE").field(&self.0).finish()
@@ -3889,7 +3928,8 @@ impl core::fmt::Debug for RTC_WATCHER_STATE {
 pub const RTCClient: windows_core::GUID = windows_core::GUID::from_u128(0x7a42ea29_a2b7_40c4_b091_f6f024aa89be);
 #[repr(C)]
 #[cfg(feature = "Win32_Networking_WinSock")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TRANSPORT_SETTING {
     pub SettingId: super::super::Networking::WinSock::TRANSPORT_SETTING_ID,
     pub Length: *mut u32,
@@ -3907,3 +3947,5 @@ impl Default for TRANSPORT_SETTING {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,2001 @@
+#[cfg(feature = "Win32_Networking_WinSock")]
+impl windows_core::imp::ProxyVtable for INetworkTransportSettings_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Networking_WinSock")]
+        unsafe extern "system" fn ApplySetting(this: *mut core::ffi::c_void, settingid: *const super::super::Networking::WinSock::TRANSPORT_SETTING_ID, lengthin: u32, valuein: *const u8, lengthout: *mut u32, valueout: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkTransportSettings", "ApplySetting", 3usize, |this| ((**(this as *const *const INetworkTransportSettings_Vtbl)).ApplySetting)(this, settingid, lengthin, valuein, lengthout, valueout), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Networking_WinSock")]
+        unsafe extern "system" fn QuerySetting(this: *mut core::ffi::c_void, settingid: *const super::super::Networking::WinSock::TRANSPORT_SETTING_ID, lengthin: u32, valuein: *const u8, lengthout: *mut u32, valueout: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkTransportSettings", "QuerySetting", 4usize, |this| ((**(this as *const *const INetworkTransportSettings_Vtbl)).QuerySetting)(this, settingid, lengthin, valuein, lengthout, valueout), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Networking_WinSock")]
+            ApplySetting,
+            #[cfg(not(feature = "Win32_Networking_WinSock"))]
+            ApplySetting: 0,
+            #[cfg(feature = "Win32_Networking_WinSock")]
+            QuerySetting,
+            #[cfg(not(feature = "Win32_Networking_WinSock"))]
+            QuerySetting: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkTransportSettings as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INotificationTransportSync_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CompleteDelivery(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotificationTransportSync", "CompleteDelivery", 3usize, |this| ((**(this as *const *const INotificationTransportSync_Vtbl)).CompleteDelivery)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flush(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotificationTransportSync", "Flush", 4usize, |this| ((**(this as *const *const INotificationTransportSync_Vtbl)).Flush)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CompleteDelivery, Flush }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INotificationTransportSync as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRTCBuddy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, penstatus: *mut RTC_PRESENCE_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCBuddy", "Status", 11usize, |this| ((**(this as *const *const IRTCBuddy_Vtbl)).Status)(this, penstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn Notes(this: *mut core::ffi::c_void, pbstrnotes: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCBuddy", "Notes", 12usize, |this| ((**(this as *const *const IRTCBuddy_Vtbl)).Notes)(this, pbstrnotes), |result| Some(*result))
+        }
+        Self { base__: <IRTCPresenceContact_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, Notes }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCBuddy as windows_core::Interface>::IID || <IRTCPresenceContact_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCBuddy2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Profile(this: *mut core::ffi::c_void, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCBuddy2", "Profile", 13usize, |this| ((**(this as *const *const IRTCBuddy2_Vtbl)).Profile)(this, ppprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCBuddy2", "Refresh", 14usize, |this| ((**(this as *const *const IRTCBuddy2_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateGroups(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCBuddy2", "EnumerateGroups", 15usize, |this| ((**(this as *const *const IRTCBuddy2_Vtbl)).EnumerateGroups)(this, ppenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Groups(this: *mut core::ffi::c_void, ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCBuddy2", "Groups", 16usize, |this| ((**(this as *const *const IRTCBuddy2_Vtbl)).Groups)(this, ppcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_PresenceProperty(this: *mut core::ffi::c_void, enproperty: RTC_PRESENCE_PROPERTY, pbstrproperty: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCBuddy2", "get_PresenceProperty", 17usize, |this| ((**(this as *const *const IRTCBuddy2_Vtbl)).get_PresenceProperty)(this, enproperty, pbstrproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumeratePresenceDevices(this: *mut core::ffi::c_void, ppenumdevices: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCBuddy2", "EnumeratePresenceDevices", 18usize, |this| ((**(this as *const *const IRTCBuddy2_Vtbl)).EnumeratePresenceDevices)(this, ppenumdevices), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PresenceDevices(this: *mut core::ffi::c_void, ppdevicescollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCBuddy2", "PresenceDevices", 19usize, |this| ((**(this as *const *const IRTCBuddy2_Vtbl)).PresenceDevices)(this, ppdevicescollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn SubscriptionType(this: *mut core::ffi::c_void, pensubscriptiontype: *mut RTC_BUDDY_SUBSCRIPTION_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCBuddy2", "SubscriptionType", 20usize, |this| ((**(this as *const *const IRTCBuddy2_Vtbl)).SubscriptionType)(this, pensubscriptiontype), |result| Some(*result))
+        }
+        Self {
+            base__: <IRTCBuddy_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Profile,
+            Refresh,
+            EnumerateGroups,
+            #[cfg(feature = "Win32_System_Com")]
+            Groups,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Groups: 0,
+            get_PresenceProperty,
+            EnumeratePresenceDevices,
+            #[cfg(feature = "Win32_System_Com")]
+            PresenceDevices,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PresenceDevices: 0,
+            SubscriptionType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCBuddy2 as windows_core::Interface>::IID || <IRTCBuddy_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCBuddyEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Buddy(this: *mut core::ffi::c_void, ppbuddy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCBuddyEvent", "Buddy", 7usize, |this| ((**(this as *const *const IRTCBuddyEvent_Vtbl)).Buddy)(this, ppbuddy), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Buddy }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCBuddyEvent as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCBuddyEvent2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EventType(this: *mut core::ffi::c_void, peventtype: *mut RTC_BUDDY_EVENT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCBuddyEvent2", "EventType", 8usize, |this| ((**(this as *const *const IRTCBuddyEvent2_Vtbl)).EventType)(this, peventtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusCode(this: *mut core::ffi::c_void, plstatuscode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCBuddyEvent2", "StatusCode", 9usize, |this| ((**(this as *const *const IRTCBuddyEvent2_Vtbl)).StatusCode)(this, plstatuscode), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusText(this: *mut core::ffi::c_void, pbstrstatustext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCBuddyEvent2", "StatusText", 10usize, |this| ((**(this as *const *const IRTCBuddyEvent2_Vtbl)).StatusText)(this, pbstrstatustext), |result| Some(*result))
+        }
+        Self { base__: <IRTCBuddyEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EventType, StatusCode, StatusText }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCBuddyEvent2 as windows_core::Interface>::IID || <IRTCBuddyEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCBuddyGroup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrgroupname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCBuddyGroup", "Name", 3usize, |this| ((**(this as *const *const IRTCBuddyGroup_Vtbl)).Name)(this, pbstrgroupname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, bstrgroupname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCBuddyGroup", "SetName", 4usize, |this| ((**(this as *const *const IRTCBuddyGroup_Vtbl)).SetName)(this, bstrgroupname), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddBuddy(this: *mut core::ffi::c_void, pbuddy: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCBuddyGroup", "AddBuddy", 5usize, |this| ((**(this as *const *const IRTCBuddyGroup_Vtbl)).AddBuddy)(this, pbuddy), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBuddy(this: *mut core::ffi::c_void, pbuddy: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCBuddyGroup", "RemoveBuddy", 6usize, |this| ((**(this as *const *const IRTCBuddyGroup_Vtbl)).RemoveBuddy)(this, pbuddy), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateBuddies(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCBuddyGroup", "EnumerateBuddies", 7usize, |this| ((**(this as *const *const IRTCBuddyGroup_Vtbl)).EnumerateBuddies)(this, ppenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Buddies(this: *mut core::ffi::c_void, ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCBuddyGroup", "Buddies", 8usize, |this| ((**(this as *const *const IRTCBuddyGroup_Vtbl)).Buddies)(this, ppcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, pbstrdata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCBuddyGroup", "Data", 9usize, |this| ((**(this as *const *const IRTCBuddyGroup_Vtbl)).Data)(this, pbstrdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, bstrdata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCBuddyGroup", "SetData", 10usize, |this| ((**(this as *const *const IRTCBuddyGroup_Vtbl)).SetData)(this, bstrdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn Profile(this: *mut core::ffi::c_void, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCBuddyGroup", "Profile", 11usize, |this| ((**(this as *const *const IRTCBuddyGroup_Vtbl)).Profile)(this, ppprofile), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            SetName,
+            AddBuddy,
+            RemoveBuddy,
+            EnumerateBuddies,
+            #[cfg(feature = "Win32_System_Com")]
+            Buddies,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Buddies: 0,
+            Data,
+            SetData,
+            Profile,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCBuddyGroup as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCBuddyGroupEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EventType(this: *mut core::ffi::c_void, peventtype: *mut RTC_GROUP_EVENT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCBuddyGroupEvent", "EventType", 7usize, |this| ((**(this as *const *const IRTCBuddyGroupEvent_Vtbl)).EventType)(this, peventtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Group(this: *mut core::ffi::c_void, ppgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCBuddyGroupEvent", "Group", 8usize, |this| ((**(this as *const *const IRTCBuddyGroupEvent_Vtbl)).Group)(this, ppgroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn Buddy(this: *mut core::ffi::c_void, ppbuddy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCBuddyGroupEvent", "Buddy", 9usize, |this| ((**(this as *const *const IRTCBuddyGroupEvent_Vtbl)).Buddy)(this, ppbuddy), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusCode(this: *mut core::ffi::c_void, plstatuscode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCBuddyGroupEvent", "StatusCode", 10usize, |this| ((**(this as *const *const IRTCBuddyGroupEvent_Vtbl)).StatusCode)(this, plstatuscode), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EventType, Group, Buddy, StatusCode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCBuddyGroupEvent as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Media_DirectShow", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IRTCClient_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "Initialize", 3usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).Initialize)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "Shutdown", 4usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).Shutdown)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrepareForShutdown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "PrepareForShutdown", 5usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).PrepareForShutdown)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventFilter(this: *mut core::ffi::c_void, lfilter: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "SetEventFilter", 6usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).SetEventFilter)(this, lfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventFilter(this: *mut core::ffi::c_void, plfilter: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "EventFilter", 7usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).EventFilter)(this, plfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreferredMediaTypes(this: *mut core::ffi::c_void, lmediatypes: i32, fpersistent: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "SetPreferredMediaTypes", 8usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).SetPreferredMediaTypes)(this, lmediatypes, fpersistent), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreferredMediaTypes(this: *mut core::ffi::c_void, plmediatypes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "PreferredMediaTypes", 9usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).PreferredMediaTypes)(this, plmediatypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn MediaCapabilities(this: *mut core::ffi::c_void, plmediatypes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "MediaCapabilities", 10usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).MediaCapabilities)(this, plmediatypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSession(this: *mut core::ffi::c_void, entype: RTC_SESSION_TYPE, bstrlocalphoneuri: core::mem::MaybeUninit<windows_core::BSTR>, pprofile: *mut core::ffi::c_void, lflags: i32, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "CreateSession", 11usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).CreateSession)(this, entype, bstrlocalphoneuri, pprofile, lflags, ppsession), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetListenForIncomingSessions(this: *mut core::ffi::c_void, enlisten: RTC_LISTEN_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "SetListenForIncomingSessions", 12usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).SetListenForIncomingSessions)(this, enlisten), |result| Some(*result))
+        }
+        unsafe extern "system" fn ListenForIncomingSessions(this: *mut core::ffi::c_void, penlisten: *mut RTC_LISTEN_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "ListenForIncomingSessions", 13usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).ListenForIncomingSessions)(this, penlisten), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_NetworkAddresses(this: *mut core::ffi::c_void, ftcp: super::super::Foundation::VARIANT_BOOL, fexternal: super::super::Foundation::VARIANT_BOOL, pvaddresses: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "get_NetworkAddresses", 14usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).get_NetworkAddresses)(this, ftcp, fexternal, pvaddresses), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_Volume(this: *mut core::ffi::c_void, endevice: RTC_AUDIO_DEVICE, lvolume: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "put_Volume", 15usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).put_Volume)(this, endevice, lvolume), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Volume(this: *mut core::ffi::c_void, endevice: RTC_AUDIO_DEVICE, plvolume: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "get_Volume", 16usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).get_Volume)(this, endevice, plvolume), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_AudioMuted(this: *mut core::ffi::c_void, endevice: RTC_AUDIO_DEVICE, fmuted: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "put_AudioMuted", 17usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).put_AudioMuted)(this, endevice, fmuted), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_AudioMuted(this: *mut core::ffi::c_void, endevice: RTC_AUDIO_DEVICE, pfmuted: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "get_AudioMuted", 18usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).get_AudioMuted)(this, endevice, pfmuted), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Media_DirectShow", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn get_IVideoWindow(this: *mut core::ffi::c_void, endevice: RTC_VIDEO_DEVICE, ppivideowindow: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "get_IVideoWindow", 19usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).get_IVideoWindow)(this, endevice, ppivideowindow), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_PreferredAudioDevice(this: *mut core::ffi::c_void, endevice: RTC_AUDIO_DEVICE, bstrdevicename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "put_PreferredAudioDevice", 20usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).put_PreferredAudioDevice)(this, endevice, bstrdevicename), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_PreferredAudioDevice(this: *mut core::ffi::c_void, endevice: RTC_AUDIO_DEVICE, pbstrdevicename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "get_PreferredAudioDevice", 21usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).get_PreferredAudioDevice)(this, endevice, pbstrdevicename), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_PreferredVolume(this: *mut core::ffi::c_void, endevice: RTC_AUDIO_DEVICE, lvolume: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "put_PreferredVolume", 22usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).put_PreferredVolume)(this, endevice, lvolume), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_PreferredVolume(this: *mut core::ffi::c_void, endevice: RTC_AUDIO_DEVICE, plvolume: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "get_PreferredVolume", 23usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).get_PreferredVolume)(this, endevice, plvolume), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreferredAEC(this: *mut core::ffi::c_void, benable: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "SetPreferredAEC", 24usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).SetPreferredAEC)(this, benable), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreferredAEC(this: *mut core::ffi::c_void, pbenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "PreferredAEC", 25usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).PreferredAEC)(this, pbenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreferredVideoDevice(this: *mut core::ffi::c_void, bstrdevicename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "SetPreferredVideoDevice", 26usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).SetPreferredVideoDevice)(this, bstrdevicename), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreferredVideoDevice(this: *mut core::ffi::c_void, pbstrdevicename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "PreferredVideoDevice", 27usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).PreferredVideoDevice)(this, pbstrdevicename), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActiveMedia(this: *mut core::ffi::c_void, plmediatype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "ActiveMedia", 28usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).ActiveMedia)(this, plmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxBitrate(this: *mut core::ffi::c_void, lmaxbitrate: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "SetMaxBitrate", 29usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).SetMaxBitrate)(this, lmaxbitrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxBitrate(this: *mut core::ffi::c_void, plmaxbitrate: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "MaxBitrate", 30usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).MaxBitrate)(this, plmaxbitrate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTemporalSpatialTradeOff(this: *mut core::ffi::c_void, lvalue: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "SetTemporalSpatialTradeOff", 31usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).SetTemporalSpatialTradeOff)(this, lvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn TemporalSpatialTradeOff(this: *mut core::ffi::c_void, plvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "TemporalSpatialTradeOff", 32usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).TemporalSpatialTradeOff)(this, plvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn NetworkQuality(this: *mut core::ffi::c_void, plnetworkquality: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "NetworkQuality", 33usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).NetworkQuality)(this, plnetworkquality), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartT120Applet(this: *mut core::ffi::c_void, enapplet: RTC_T120_APPLET) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "StartT120Applet", 34usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).StartT120Applet)(this, enapplet), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopT120Applets(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "StopT120Applets", 35usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).StopT120Applets)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_IsT120AppletRunning(this: *mut core::ffi::c_void, enapplet: RTC_T120_APPLET, pfrunning: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "get_IsT120AppletRunning", 36usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).get_IsT120AppletRunning)(this, enapplet, pfrunning), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalUserURI(this: *mut core::ffi::c_void, pbstruseruri: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "LocalUserURI", 37usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).LocalUserURI)(this, pbstruseruri), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocalUserURI(this: *mut core::ffi::c_void, bstruseruri: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "SetLocalUserURI", 38usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).SetLocalUserURI)(this, bstruseruri), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalUserName(this: *mut core::ffi::c_void, pbstrusername: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "LocalUserName", 39usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).LocalUserName)(this, pbstrusername), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocalUserName(this: *mut core::ffi::c_void, bstrusername: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "SetLocalUserName", 40usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).SetLocalUserName)(this, bstrusername), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlayRing(this: *mut core::ffi::c_void, entype: RTC_RING_TYPE, bplay: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "PlayRing", 41usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).PlayRing)(this, entype, bplay), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendDTMF(this: *mut core::ffi::c_void, endtmf: RTC_DTMF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "SendDTMF", 42usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).SendDTMF)(this, endtmf), |result| Some(*result))
+        }
+        unsafe extern "system" fn InvokeTuningWizard(this: *mut core::ffi::c_void, hwndparent: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "InvokeTuningWizard", 43usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).InvokeTuningWizard)(this, hwndparent), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTuned(this: *mut core::ffi::c_void, pftuned: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient", "IsTuned", 44usize, |this| ((**(this as *const *const IRTCClient_Vtbl)).IsTuned)(this, pftuned), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            Shutdown,
+            PrepareForShutdown,
+            SetEventFilter,
+            EventFilter,
+            SetPreferredMediaTypes,
+            PreferredMediaTypes,
+            MediaCapabilities,
+            CreateSession,
+            SetListenForIncomingSessions,
+            ListenForIncomingSessions,
+            get_NetworkAddresses,
+            put_Volume,
+            get_Volume,
+            put_AudioMuted,
+            get_AudioMuted,
+            #[cfg(all(feature = "Win32_Media_DirectShow", feature = "Win32_System_Com"))]
+            get_IVideoWindow,
+            #[cfg(not(all(feature = "Win32_Media_DirectShow", feature = "Win32_System_Com")))]
+            get_IVideoWindow: 0,
+            put_PreferredAudioDevice,
+            get_PreferredAudioDevice,
+            put_PreferredVolume,
+            get_PreferredVolume,
+            SetPreferredAEC,
+            PreferredAEC,
+            SetPreferredVideoDevice,
+            PreferredVideoDevice,
+            ActiveMedia,
+            SetMaxBitrate,
+            MaxBitrate,
+            SetTemporalSpatialTradeOff,
+            TemporalSpatialTradeOff,
+            NetworkQuality,
+            StartT120Applet,
+            StopT120Applets,
+            get_IsT120AppletRunning,
+            LocalUserURI,
+            SetLocalUserURI,
+            LocalUserName,
+            SetLocalUserName,
+            PlayRing,
+            SendDTMF,
+            InvokeTuningWizard,
+            IsTuned,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCClient as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Media_DirectShow", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IRTCClient2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn put_AnswerMode(this: *mut core::ffi::c_void, entype: RTC_SESSION_TYPE, enmode: RTC_ANSWER_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient2", "put_AnswerMode", 45usize, |this| ((**(this as *const *const IRTCClient2_Vtbl)).put_AnswerMode)(this, entype, enmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_AnswerMode(this: *mut core::ffi::c_void, entype: RTC_SESSION_TYPE, penmode: *mut RTC_ANSWER_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient2", "get_AnswerMode", 46usize, |this| ((**(this as *const *const IRTCClient2_Vtbl)).get_AnswerMode)(this, entype, penmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn InvokeTuningWizardEx(this: *mut core::ffi::c_void, hwndparent: isize, fallowaudio: super::super::Foundation::VARIANT_BOOL, fallowvideo: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient2", "InvokeTuningWizardEx", 47usize, |this| ((**(this as *const *const IRTCClient2_Vtbl)).InvokeTuningWizardEx)(this, hwndparent, fallowaudio, fallowvideo), |result| Some(*result))
+        }
+        unsafe extern "system" fn Version(this: *mut core::ffi::c_void, plversion: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient2", "Version", 48usize, |this| ((**(this as *const *const IRTCClient2_Vtbl)).Version)(this, plversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClientName(this: *mut core::ffi::c_void, bstrclientname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient2", "SetClientName", 49usize, |this| ((**(this as *const *const IRTCClient2_Vtbl)).SetClientName)(this, bstrclientname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClientCurVer(this: *mut core::ffi::c_void, bstrclientcurver: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient2", "SetClientCurVer", 50usize, |this| ((**(this as *const *const IRTCClient2_Vtbl)).SetClientCurVer)(this, bstrclientcurver), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeEx(this: *mut core::ffi::c_void, lflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient2", "InitializeEx", 51usize, |this| ((**(this as *const *const IRTCClient2_Vtbl)).InitializeEx)(this, lflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSessionWithDescription(this: *mut core::ffi::c_void, bstrcontenttype: core::mem::MaybeUninit<windows_core::BSTR>, bstrsessiondescription: core::mem::MaybeUninit<windows_core::BSTR>, pprofile: *mut core::ffi::c_void, lflags: i32, ppsession2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient2", "CreateSessionWithDescription", 52usize, |this| ((**(this as *const *const IRTCClient2_Vtbl)).CreateSessionWithDescription)(this, bstrcontenttype, bstrsessiondescription, pprofile, lflags, ppsession2), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSessionDescriptionManager(this: *mut core::ffi::c_void, psessiondescriptionmanager: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient2", "SetSessionDescriptionManager", 53usize, |this| ((**(this as *const *const IRTCClient2_Vtbl)).SetSessionDescriptionManager)(this, psessiondescriptionmanager), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_PreferredSecurityLevel(this: *mut core::ffi::c_void, ensecuritytype: RTC_SECURITY_TYPE, ensecuritylevel: RTC_SECURITY_LEVEL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient2", "put_PreferredSecurityLevel", 54usize, |this| ((**(this as *const *const IRTCClient2_Vtbl)).put_PreferredSecurityLevel)(this, ensecuritytype, ensecuritylevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_PreferredSecurityLevel(this: *mut core::ffi::c_void, ensecuritytype: RTC_SECURITY_TYPE, pensecuritylevel: *mut RTC_SECURITY_LEVEL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient2", "get_PreferredSecurityLevel", 55usize, |this| ((**(this as *const *const IRTCClient2_Vtbl)).get_PreferredSecurityLevel)(this, ensecuritytype, pensecuritylevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_AllowedPorts(this: *mut core::ffi::c_void, ltransport: i32, enlistenmode: RTC_LISTEN_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient2", "put_AllowedPorts", 56usize, |this| ((**(this as *const *const IRTCClient2_Vtbl)).put_AllowedPorts)(this, ltransport, enlistenmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_AllowedPorts(this: *mut core::ffi::c_void, ltransport: i32, penlistenmode: *mut RTC_LISTEN_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClient2", "get_AllowedPorts", 57usize, |this| ((**(this as *const *const IRTCClient2_Vtbl)).get_AllowedPorts)(this, ltransport, penlistenmode), |result| Some(*result))
+        }
+        Self {
+            base__: <IRTCClient_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            put_AnswerMode,
+            get_AnswerMode,
+            InvokeTuningWizardEx,
+            Version,
+            SetClientName,
+            SetClientCurVer,
+            InitializeEx,
+            CreateSessionWithDescription,
+            SetSessionDescriptionManager,
+            put_PreferredSecurityLevel,
+            get_PreferredSecurityLevel,
+            put_AllowedPorts,
+            get_AllowedPorts,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCClient2 as windows_core::Interface>::IID || <IRTCClient_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCClientEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EventType(this: *mut core::ffi::c_void, peneventtype: *mut RTC_CLIENT_EVENT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientEvent", "EventType", 7usize, |this| ((**(this as *const *const IRTCClientEvent_Vtbl)).EventType)(this, peneventtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Client(this: *mut core::ffi::c_void, ppclient: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientEvent", "Client", 8usize, |this| ((**(this as *const *const IRTCClientEvent_Vtbl)).Client)(this, ppclient), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EventType, Client }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCClientEvent as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRTCClientPortManagement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartListenAddressAndPort(this: *mut core::ffi::c_void, bstrinternallocaladdress: core::mem::MaybeUninit<windows_core::BSTR>, linternallocalport: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPortManagement", "StartListenAddressAndPort", 3usize, |this| ((**(this as *const *const IRTCClientPortManagement_Vtbl)).StartListenAddressAndPort)(this, bstrinternallocaladdress, linternallocalport), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopListenAddressAndPort(this: *mut core::ffi::c_void, bstrinternallocaladdress: core::mem::MaybeUninit<windows_core::BSTR>, linternallocalport: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPortManagement", "StopListenAddressAndPort", 4usize, |this| ((**(this as *const *const IRTCClientPortManagement_Vtbl)).StopListenAddressAndPort)(this, bstrinternallocaladdress, linternallocalport), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPortRange(this: *mut core::ffi::c_void, enporttype: RTC_PORT_TYPE, plminvalue: *mut i32, plmaxvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPortManagement", "GetPortRange", 5usize, |this| ((**(this as *const *const IRTCClientPortManagement_Vtbl)).GetPortRange)(this, enporttype, plminvalue, plmaxvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartListenAddressAndPort,
+            StopListenAddressAndPort,
+            GetPortRange,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCClientPortManagement as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCClientPresence_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnablePresence(this: *mut core::ffi::c_void, fusestorage: super::super::Foundation::VARIANT_BOOL, varstorage: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence", "EnablePresence", 3usize, |this| ((**(this as *const *const IRTCClientPresence_Vtbl)).EnablePresence)(this, fusestorage, varstorage), |result| Some(*result))
+        }
+        unsafe extern "system" fn Export(this: *mut core::ffi::c_void, varstorage: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence", "Export", 4usize, |this| ((**(this as *const *const IRTCClientPresence_Vtbl)).Export)(this, varstorage), |result| Some(*result))
+        }
+        unsafe extern "system" fn Import(this: *mut core::ffi::c_void, varstorage: core::mem::MaybeUninit<windows_core::VARIANT>, freplaceall: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence", "Import", 5usize, |this| ((**(this as *const *const IRTCClientPresence_Vtbl)).Import)(this, varstorage, freplaceall), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateBuddies(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence", "EnumerateBuddies", 6usize, |this| ((**(this as *const *const IRTCClientPresence_Vtbl)).EnumerateBuddies)(this, ppenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Buddies(this: *mut core::ffi::c_void, ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence", "Buddies", 7usize, |this| ((**(this as *const *const IRTCClientPresence_Vtbl)).Buddies)(this, ppcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Buddy(this: *mut core::ffi::c_void, bstrpresentityuri: core::mem::MaybeUninit<windows_core::BSTR>, ppbuddy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence", "get_Buddy", 8usize, |this| ((**(this as *const *const IRTCClientPresence_Vtbl)).get_Buddy)(this, bstrpresentityuri, ppbuddy), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddBuddy(this: *mut core::ffi::c_void, bstrpresentityuri: core::mem::MaybeUninit<windows_core::BSTR>, bstrusername: core::mem::MaybeUninit<windows_core::BSTR>, bstrdata: core::mem::MaybeUninit<windows_core::BSTR>, fpersistent: super::super::Foundation::VARIANT_BOOL, pprofile: *mut core::ffi::c_void, lflags: i32, ppbuddy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence", "AddBuddy", 9usize, |this| ((**(this as *const *const IRTCClientPresence_Vtbl)).AddBuddy)(this, bstrpresentityuri, bstrusername, bstrdata, fpersistent, pprofile, lflags, ppbuddy), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBuddy(this: *mut core::ffi::c_void, pbuddy: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence", "RemoveBuddy", 10usize, |this| ((**(this as *const *const IRTCClientPresence_Vtbl)).RemoveBuddy)(this, pbuddy), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateWatchers(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence", "EnumerateWatchers", 11usize, |this| ((**(this as *const *const IRTCClientPresence_Vtbl)).EnumerateWatchers)(this, ppenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Watchers(this: *mut core::ffi::c_void, ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence", "Watchers", 12usize, |this| ((**(this as *const *const IRTCClientPresence_Vtbl)).Watchers)(this, ppcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Watcher(this: *mut core::ffi::c_void, bstrpresentityuri: core::mem::MaybeUninit<windows_core::BSTR>, ppwatcher: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence", "get_Watcher", 13usize, |this| ((**(this as *const *const IRTCClientPresence_Vtbl)).get_Watcher)(this, bstrpresentityuri, ppwatcher), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddWatcher(this: *mut core::ffi::c_void, bstrpresentityuri: core::mem::MaybeUninit<windows_core::BSTR>, bstrusername: core::mem::MaybeUninit<windows_core::BSTR>, bstrdata: core::mem::MaybeUninit<windows_core::BSTR>, fblocked: super::super::Foundation::VARIANT_BOOL, fpersistent: super::super::Foundation::VARIANT_BOOL, ppwatcher: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence", "AddWatcher", 14usize, |this| ((**(this as *const *const IRTCClientPresence_Vtbl)).AddWatcher)(this, bstrpresentityuri, bstrusername, bstrdata, fblocked, fpersistent, ppwatcher), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveWatcher(this: *mut core::ffi::c_void, pwatcher: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence", "RemoveWatcher", 15usize, |this| ((**(this as *const *const IRTCClientPresence_Vtbl)).RemoveWatcher)(this, pwatcher), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocalPresenceInfo(this: *mut core::ffi::c_void, enstatus: RTC_PRESENCE_STATUS, bstrnotes: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence", "SetLocalPresenceInfo", 16usize, |this| ((**(this as *const *const IRTCClientPresence_Vtbl)).SetLocalPresenceInfo)(this, enstatus, bstrnotes), |result| Some(*result))
+        }
+        unsafe extern "system" fn OfferWatcherMode(this: *mut core::ffi::c_void, penmode: *mut RTC_OFFER_WATCHER_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence", "OfferWatcherMode", 17usize, |this| ((**(this as *const *const IRTCClientPresence_Vtbl)).OfferWatcherMode)(this, penmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOfferWatcherMode(this: *mut core::ffi::c_void, enmode: RTC_OFFER_WATCHER_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence", "SetOfferWatcherMode", 18usize, |this| ((**(this as *const *const IRTCClientPresence_Vtbl)).SetOfferWatcherMode)(this, enmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrivacyMode(this: *mut core::ffi::c_void, penmode: *mut RTC_PRIVACY_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence", "PrivacyMode", 19usize, |this| ((**(this as *const *const IRTCClientPresence_Vtbl)).PrivacyMode)(this, penmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrivacyMode(this: *mut core::ffi::c_void, enmode: RTC_PRIVACY_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence", "SetPrivacyMode", 20usize, |this| ((**(this as *const *const IRTCClientPresence_Vtbl)).SetPrivacyMode)(this, enmode), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EnablePresence,
+            Export,
+            Import,
+            EnumerateBuddies,
+            #[cfg(feature = "Win32_System_Com")]
+            Buddies,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Buddies: 0,
+            get_Buddy,
+            AddBuddy,
+            RemoveBuddy,
+            EnumerateWatchers,
+            #[cfg(feature = "Win32_System_Com")]
+            Watchers,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Watchers: 0,
+            get_Watcher,
+            AddWatcher,
+            RemoveWatcher,
+            SetLocalPresenceInfo,
+            OfferWatcherMode,
+            SetOfferWatcherMode,
+            PrivacyMode,
+            SetPrivacyMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCClientPresence as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCClientPresence2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnablePresenceEx(this: *mut core::ffi::c_void, pprofile: *mut core::ffi::c_void, varstorage: core::mem::MaybeUninit<windows_core::VARIANT>, lflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence2", "EnablePresenceEx", 21usize, |this| ((**(this as *const *const IRTCClientPresence2_Vtbl)).EnablePresenceEx)(this, pprofile, varstorage, lflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisablePresence(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence2", "DisablePresence", 22usize, |this| ((**(this as *const *const IRTCClientPresence2_Vtbl)).DisablePresence)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddGroup(this: *mut core::ffi::c_void, bstrgroupname: core::mem::MaybeUninit<windows_core::BSTR>, bstrdata: core::mem::MaybeUninit<windows_core::BSTR>, pprofile: *mut core::ffi::c_void, lflags: i32, ppgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence2", "AddGroup", 23usize, |this| ((**(this as *const *const IRTCClientPresence2_Vtbl)).AddGroup)(this, bstrgroupname, bstrdata, pprofile, lflags, ppgroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveGroup(this: *mut core::ffi::c_void, pgroup: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence2", "RemoveGroup", 24usize, |this| ((**(this as *const *const IRTCClientPresence2_Vtbl)).RemoveGroup)(this, pgroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateGroups(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence2", "EnumerateGroups", 25usize, |this| ((**(this as *const *const IRTCClientPresence2_Vtbl)).EnumerateGroups)(this, ppenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Groups(this: *mut core::ffi::c_void, ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence2", "Groups", 26usize, |this| ((**(this as *const *const IRTCClientPresence2_Vtbl)).Groups)(this, ppcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Group(this: *mut core::ffi::c_void, bstrgroupname: core::mem::MaybeUninit<windows_core::BSTR>, ppgroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence2", "get_Group", 27usize, |this| ((**(this as *const *const IRTCClientPresence2_Vtbl)).get_Group)(this, bstrgroupname, ppgroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddWatcherEx(this: *mut core::ffi::c_void, bstrpresentityuri: core::mem::MaybeUninit<windows_core::BSTR>, bstrusername: core::mem::MaybeUninit<windows_core::BSTR>, bstrdata: core::mem::MaybeUninit<windows_core::BSTR>, enstate: RTC_WATCHER_STATE, fpersistent: super::super::Foundation::VARIANT_BOOL, enscope: RTC_ACE_SCOPE, pprofile: *mut core::ffi::c_void, lflags: i32, ppwatcher: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence2", "AddWatcherEx", 28usize, |this| ((**(this as *const *const IRTCClientPresence2_Vtbl)).AddWatcherEx)(this, bstrpresentityuri, bstrusername, bstrdata, enstate, fpersistent, enscope, pprofile, lflags, ppwatcher), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_WatcherEx(this: *mut core::ffi::c_void, enmode: RTC_WATCHER_MATCH_MODE, bstrpresentityuri: core::mem::MaybeUninit<windows_core::BSTR>, ppwatcher: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence2", "get_WatcherEx", 29usize, |this| ((**(this as *const *const IRTCClientPresence2_Vtbl)).get_WatcherEx)(this, enmode, bstrpresentityuri, ppwatcher), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_PresenceProperty(this: *mut core::ffi::c_void, enproperty: RTC_PRESENCE_PROPERTY, bstrproperty: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence2", "put_PresenceProperty", 30usize, |this| ((**(this as *const *const IRTCClientPresence2_Vtbl)).put_PresenceProperty)(this, enproperty, bstrproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_PresenceProperty(this: *mut core::ffi::c_void, enproperty: RTC_PRESENCE_PROPERTY, pbstrproperty: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence2", "get_PresenceProperty", 31usize, |this| ((**(this as *const *const IRTCClientPresence2_Vtbl)).get_PresenceProperty)(this, enproperty, pbstrproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPresenceData(this: *mut core::ffi::c_void, bstrnamespace: core::mem::MaybeUninit<windows_core::BSTR>, bstrdata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence2", "SetPresenceData", 32usize, |this| ((**(this as *const *const IRTCClientPresence2_Vtbl)).SetPresenceData)(this, bstrnamespace, bstrdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPresenceData(this: *mut core::ffi::c_void, pbstrnamespace: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrdata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence2", "GetPresenceData", 33usize, |this| ((**(this as *const *const IRTCClientPresence2_Vtbl)).GetPresenceData)(this, pbstrnamespace, pbstrdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocalPresenceInfo(this: *mut core::ffi::c_void, penstatus: *mut RTC_PRESENCE_STATUS, pbstrnotes: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence2", "GetLocalPresenceInfo", 34usize, |this| ((**(this as *const *const IRTCClientPresence2_Vtbl)).GetLocalPresenceInfo)(this, penstatus, pbstrnotes), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddBuddyEx(this: *mut core::ffi::c_void, bstrpresentityuri: core::mem::MaybeUninit<windows_core::BSTR>, bstrusername: core::mem::MaybeUninit<windows_core::BSTR>, bstrdata: core::mem::MaybeUninit<windows_core::BSTR>, fpersistent: super::super::Foundation::VARIANT_BOOL, ensubscriptiontype: RTC_BUDDY_SUBSCRIPTION_TYPE, pprofile: *mut core::ffi::c_void, lflags: i32, ppbuddy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientPresence2", "AddBuddyEx", 35usize, |this| ((**(this as *const *const IRTCClientPresence2_Vtbl)).AddBuddyEx)(this, bstrpresentityuri, bstrusername, bstrdata, fpersistent, ensubscriptiontype, pprofile, lflags, ppbuddy), |result| Some(*result))
+        }
+        Self {
+            base__: <IRTCClientPresence_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EnablePresenceEx,
+            DisablePresence,
+            AddGroup,
+            RemoveGroup,
+            EnumerateGroups,
+            #[cfg(feature = "Win32_System_Com")]
+            Groups,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Groups: 0,
+            get_Group,
+            AddWatcherEx,
+            get_WatcherEx,
+            put_PresenceProperty,
+            get_PresenceProperty,
+            SetPresenceData,
+            GetPresenceData,
+            GetLocalPresenceInfo,
+            AddBuddyEx,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCClientPresence2 as windows_core::Interface>::IID || <IRTCClientPresence_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCClientProvisioning_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateProfile(this: *mut core::ffi::c_void, bstrprofilexml: core::mem::MaybeUninit<windows_core::BSTR>, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientProvisioning", "CreateProfile", 3usize, |this| ((**(this as *const *const IRTCClientProvisioning_Vtbl)).CreateProfile)(this, bstrprofilexml, ppprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableProfile(this: *mut core::ffi::c_void, pprofile: *mut core::ffi::c_void, lregisterflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientProvisioning", "EnableProfile", 4usize, |this| ((**(this as *const *const IRTCClientProvisioning_Vtbl)).EnableProfile)(this, pprofile, lregisterflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableProfile(this: *mut core::ffi::c_void, pprofile: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientProvisioning", "DisableProfile", 5usize, |this| ((**(this as *const *const IRTCClientProvisioning_Vtbl)).DisableProfile)(this, pprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateProfiles(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientProvisioning", "EnumerateProfiles", 6usize, |this| ((**(this as *const *const IRTCClientProvisioning_Vtbl)).EnumerateProfiles)(this, ppenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Profiles(this: *mut core::ffi::c_void, ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientProvisioning", "Profiles", 7usize, |this| ((**(this as *const *const IRTCClientProvisioning_Vtbl)).Profiles)(this, ppcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProfile(this: *mut core::ffi::c_void, bstruseraccount: core::mem::MaybeUninit<windows_core::BSTR>, bstruserpassword: core::mem::MaybeUninit<windows_core::BSTR>, bstruseruri: core::mem::MaybeUninit<windows_core::BSTR>, bstrserver: core::mem::MaybeUninit<windows_core::BSTR>, ltransport: i32, lcookie: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientProvisioning", "GetProfile", 8usize, |this| ((**(this as *const *const IRTCClientProvisioning_Vtbl)).GetProfile)(this, bstruseraccount, bstruserpassword, bstruseruri, bstrserver, ltransport, lcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionCapabilities(this: *mut core::ffi::c_void, plsupportedsessions: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientProvisioning", "SessionCapabilities", 9usize, |this| ((**(this as *const *const IRTCClientProvisioning_Vtbl)).SessionCapabilities)(this, plsupportedsessions), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateProfile,
+            EnableProfile,
+            DisableProfile,
+            EnumerateProfiles,
+            #[cfg(feature = "Win32_System_Com")]
+            Profiles,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Profiles: 0,
+            GetProfile,
+            SessionCapabilities,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCClientProvisioning as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCClientProvisioning2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnableProfileEx(this: *mut core::ffi::c_void, pprofile: *mut core::ffi::c_void, lregisterflags: i32, lroamingflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCClientProvisioning2", "EnableProfileEx", 10usize, |this| ((**(this as *const *const IRTCClientProvisioning2_Vtbl)).EnableProfileEx)(this, pprofile, lregisterflags, lroamingflags), |result| Some(*result))
+        }
+        Self { base__: <IRTCClientProvisioning_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnableProfileEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCClientProvisioning2 as windows_core::Interface>::IID || <IRTCClientProvisioning_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, lcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCCollection", "Count", 7usize, |this| ((**(this as *const *const IRTCCollection_Vtbl)).Count)(this, lcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, pvariant: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCCollection", "get_Item", 8usize, |this| ((**(this as *const *const IRTCCollection_Vtbl)).get_Item)(this, index, pvariant), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppnewenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const IRTCCollection_Vtbl))._NewEnum)(this, ppnewenum), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Count, get_Item, _NewEnum }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCDispatchEventNotification_Vtbl {
+    const PROXY: Self = { Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCDispatchEventNotification as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRTCEnumBuddies_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEnumBuddies", "Next", 3usize, |this| ((**(this as *const *const IRTCEnumBuddies_Vtbl)).Next)(this, celt, ppelements, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEnumBuddies", "Reset", 4usize, |this| ((**(this as *const *const IRTCEnumBuddies_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEnumBuddies", "Skip", 5usize, |this| ((**(this as *const *const IRTCEnumBuddies_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEnumBuddies", "Clone", 6usize, |this| ((**(this as *const *const IRTCEnumBuddies_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Reset, Skip, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCEnumBuddies as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRTCEnumGroups_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEnumGroups", "Next", 3usize, |this| ((**(this as *const *const IRTCEnumGroups_Vtbl)).Next)(this, celt, ppelements, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEnumGroups", "Reset", 4usize, |this| ((**(this as *const *const IRTCEnumGroups_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEnumGroups", "Skip", 5usize, |this| ((**(this as *const *const IRTCEnumGroups_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEnumGroups", "Clone", 6usize, |this| ((**(this as *const *const IRTCEnumGroups_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Reset, Skip, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCEnumGroups as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRTCEnumParticipants_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEnumParticipants", "Next", 3usize, |this| ((**(this as *const *const IRTCEnumParticipants_Vtbl)).Next)(this, celt, ppelements, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEnumParticipants", "Reset", 4usize, |this| ((**(this as *const *const IRTCEnumParticipants_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEnumParticipants", "Skip", 5usize, |this| ((**(this as *const *const IRTCEnumParticipants_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEnumParticipants", "Clone", 6usize, |this| ((**(this as *const *const IRTCEnumParticipants_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Reset, Skip, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCEnumParticipants as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRTCEnumPresenceDevices_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEnumPresenceDevices", "Next", 3usize, |this| ((**(this as *const *const IRTCEnumPresenceDevices_Vtbl)).Next)(this, celt, ppelements, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEnumPresenceDevices", "Reset", 4usize, |this| ((**(this as *const *const IRTCEnumPresenceDevices_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEnumPresenceDevices", "Skip", 5usize, |this| ((**(this as *const *const IRTCEnumPresenceDevices_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEnumPresenceDevices", "Clone", 6usize, |this| ((**(this as *const *const IRTCEnumPresenceDevices_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Reset, Skip, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCEnumPresenceDevices as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRTCEnumProfiles_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEnumProfiles", "Next", 3usize, |this| ((**(this as *const *const IRTCEnumProfiles_Vtbl)).Next)(this, celt, ppelements, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEnumProfiles", "Reset", 4usize, |this| ((**(this as *const *const IRTCEnumProfiles_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEnumProfiles", "Skip", 5usize, |this| ((**(this as *const *const IRTCEnumProfiles_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEnumProfiles", "Clone", 6usize, |this| ((**(this as *const *const IRTCEnumProfiles_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Reset, Skip, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCEnumProfiles as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRTCEnumUserSearchResults_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEnumUserSearchResults", "Next", 3usize, |this| ((**(this as *const *const IRTCEnumUserSearchResults_Vtbl)).Next)(this, celt, ppelements, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEnumUserSearchResults", "Reset", 4usize, |this| ((**(this as *const *const IRTCEnumUserSearchResults_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEnumUserSearchResults", "Skip", 5usize, |this| ((**(this as *const *const IRTCEnumUserSearchResults_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEnumUserSearchResults", "Clone", 6usize, |this| ((**(this as *const *const IRTCEnumUserSearchResults_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Reset, Skip, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCEnumUserSearchResults as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRTCEnumWatchers_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppelements: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEnumWatchers", "Next", 3usize, |this| ((**(this as *const *const IRTCEnumWatchers_Vtbl)).Next)(this, celt, ppelements, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEnumWatchers", "Reset", 4usize, |this| ((**(this as *const *const IRTCEnumWatchers_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEnumWatchers", "Skip", 5usize, |this| ((**(this as *const *const IRTCEnumWatchers_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEnumWatchers", "Clone", 6usize, |this| ((**(this as *const *const IRTCEnumWatchers_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Reset, Skip, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCEnumWatchers as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCEventNotification_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Event(this: *mut core::ffi::c_void, rtcevent: RTC_EVENT, pevent: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCEventNotification", "Event", 3usize, |this| ((**(this as *const *const IRTCEventNotification_Vtbl)).Event)(this, rtcevent, pevent), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Event,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Event: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCEventNotification as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCInfoEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Session(this: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCInfoEvent", "Session", 7usize, |this| ((**(this as *const *const IRTCInfoEvent_Vtbl)).Session)(this, ppsession), |result| Some(*result))
+        }
+        unsafe extern "system" fn Participant(this: *mut core::ffi::c_void, ppparticipant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCInfoEvent", "Participant", 8usize, |this| ((**(this as *const *const IRTCInfoEvent_Vtbl)).Participant)(this, ppparticipant), |result| Some(*result))
+        }
+        unsafe extern "system" fn Info(this: *mut core::ffi::c_void, pbstrinfo: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCInfoEvent", "Info", 9usize, |this| ((**(this as *const *const IRTCInfoEvent_Vtbl)).Info)(this, pbstrinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn InfoHeader(this: *mut core::ffi::c_void, pbstrinfoheader: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCInfoEvent", "InfoHeader", 10usize, |this| ((**(this as *const *const IRTCInfoEvent_Vtbl)).InfoHeader)(this, pbstrinfoheader), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Session, Participant, Info, InfoHeader }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCInfoEvent as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCIntensityEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Level(this: *mut core::ffi::c_void, pllevel: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCIntensityEvent", "Level", 7usize, |this| ((**(this as *const *const IRTCIntensityEvent_Vtbl)).Level)(this, pllevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn Min(this: *mut core::ffi::c_void, plmin: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCIntensityEvent", "Min", 8usize, |this| ((**(this as *const *const IRTCIntensityEvent_Vtbl)).Min)(this, plmin), |result| Some(*result))
+        }
+        unsafe extern "system" fn Max(this: *mut core::ffi::c_void, plmax: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCIntensityEvent", "Max", 9usize, |this| ((**(this as *const *const IRTCIntensityEvent_Vtbl)).Max)(this, plmax), |result| Some(*result))
+        }
+        unsafe extern "system" fn Direction(this: *mut core::ffi::c_void, pendirection: *mut RTC_AUDIO_DEVICE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCIntensityEvent", "Direction", 10usize, |this| ((**(this as *const *const IRTCIntensityEvent_Vtbl)).Direction)(this, pendirection), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Level, Min, Max, Direction }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCIntensityEvent as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCMediaEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MediaType(this: *mut core::ffi::c_void, pmediatype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCMediaEvent", "MediaType", 7usize, |this| ((**(this as *const *const IRTCMediaEvent_Vtbl)).MediaType)(this, pmediatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventType(this: *mut core::ffi::c_void, peneventtype: *mut RTC_MEDIA_EVENT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCMediaEvent", "EventType", 8usize, |this| ((**(this as *const *const IRTCMediaEvent_Vtbl)).EventType)(this, peneventtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventReason(this: *mut core::ffi::c_void, peneventreason: *mut RTC_MEDIA_EVENT_REASON) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCMediaEvent", "EventReason", 9usize, |this| ((**(this as *const *const IRTCMediaEvent_Vtbl)).EventReason)(this, peneventreason), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MediaType, EventType, EventReason }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCMediaEvent as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCMediaRequestEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Session(this: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCMediaRequestEvent", "Session", 7usize, |this| ((**(this as *const *const IRTCMediaRequestEvent_Vtbl)).Session)(this, ppsession), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProposedMedia(this: *mut core::ffi::c_void, plmediatypes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCMediaRequestEvent", "ProposedMedia", 8usize, |this| ((**(this as *const *const IRTCMediaRequestEvent_Vtbl)).ProposedMedia)(this, plmediatypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentMedia(this: *mut core::ffi::c_void, plmediatypes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCMediaRequestEvent", "CurrentMedia", 9usize, |this| ((**(this as *const *const IRTCMediaRequestEvent_Vtbl)).CurrentMedia)(this, plmediatypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn Accept(this: *mut core::ffi::c_void, lmediatypes: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCMediaRequestEvent", "Accept", 10usize, |this| ((**(this as *const *const IRTCMediaRequestEvent_Vtbl)).Accept)(this, lmediatypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_RemotePreferredSecurityLevel(this: *mut core::ffi::c_void, ensecuritytype: RTC_SECURITY_TYPE, pensecuritylevel: *mut RTC_SECURITY_LEVEL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCMediaRequestEvent", "get_RemotePreferredSecurityLevel", 11usize, |this| ((**(this as *const *const IRTCMediaRequestEvent_Vtbl)).get_RemotePreferredSecurityLevel)(this, ensecuritytype, pensecuritylevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reject(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCMediaRequestEvent", "Reject", 12usize, |this| ((**(this as *const *const IRTCMediaRequestEvent_Vtbl)).Reject)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, pstate: *mut RTC_REINVITE_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCMediaRequestEvent", "State", 13usize, |this| ((**(this as *const *const IRTCMediaRequestEvent_Vtbl)).State)(this, pstate), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Session,
+            ProposedMedia,
+            CurrentMedia,
+            Accept,
+            get_RemotePreferredSecurityLevel,
+            Reject,
+            State,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCMediaRequestEvent as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCMessagingEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Session(this: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCMessagingEvent", "Session", 7usize, |this| ((**(this as *const *const IRTCMessagingEvent_Vtbl)).Session)(this, ppsession), |result| Some(*result))
+        }
+        unsafe extern "system" fn Participant(this: *mut core::ffi::c_void, ppparticipant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCMessagingEvent", "Participant", 8usize, |this| ((**(this as *const *const IRTCMessagingEvent_Vtbl)).Participant)(this, ppparticipant), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventType(this: *mut core::ffi::c_void, peneventtype: *mut RTC_MESSAGING_EVENT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCMessagingEvent", "EventType", 9usize, |this| ((**(this as *const *const IRTCMessagingEvent_Vtbl)).EventType)(this, peneventtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, pbstrmessage: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCMessagingEvent", "Message", 10usize, |this| ((**(this as *const *const IRTCMessagingEvent_Vtbl)).Message)(this, pbstrmessage), |result| Some(*result))
+        }
+        unsafe extern "system" fn MessageHeader(this: *mut core::ffi::c_void, pbstrmessageheader: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCMessagingEvent", "MessageHeader", 11usize, |this| ((**(this as *const *const IRTCMessagingEvent_Vtbl)).MessageHeader)(this, pbstrmessageheader), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserStatus(this: *mut core::ffi::c_void, penuserstatus: *mut RTC_MESSAGING_USER_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCMessagingEvent", "UserStatus", 12usize, |this| ((**(this as *const *const IRTCMessagingEvent_Vtbl)).UserStatus)(this, penuserstatus), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Session,
+            Participant,
+            EventType,
+            Message,
+            MessageHeader,
+            UserStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCMessagingEvent as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRTCParticipant_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UserURI(this: *mut core::ffi::c_void, pbstruseruri: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCParticipant", "UserURI", 3usize, |this| ((**(this as *const *const IRTCParticipant_Vtbl)).UserURI)(this, pbstruseruri), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCParticipant", "Name", 4usize, |this| ((**(this as *const *const IRTCParticipant_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Removable(this: *mut core::ffi::c_void, pfremovable: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCParticipant", "Removable", 5usize, |this| ((**(this as *const *const IRTCParticipant_Vtbl)).Removable)(this, pfremovable), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, penstate: *mut RTC_PARTICIPANT_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCParticipant", "State", 6usize, |this| ((**(this as *const *const IRTCParticipant_Vtbl)).State)(this, penstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Session(this: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCParticipant", "Session", 7usize, |this| ((**(this as *const *const IRTCParticipant_Vtbl)).Session)(this, ppsession), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UserURI, Name, Removable, State, Session }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCParticipant as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCParticipantStateChangeEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Participant(this: *mut core::ffi::c_void, ppparticipant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCParticipantStateChangeEvent", "Participant", 7usize, |this| ((**(this as *const *const IRTCParticipantStateChangeEvent_Vtbl)).Participant)(this, ppparticipant), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, penstate: *mut RTC_PARTICIPANT_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCParticipantStateChangeEvent", "State", 8usize, |this| ((**(this as *const *const IRTCParticipantStateChangeEvent_Vtbl)).State)(this, penstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusCode(this: *mut core::ffi::c_void, plstatuscode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCParticipantStateChangeEvent", "StatusCode", 9usize, |this| ((**(this as *const *const IRTCParticipantStateChangeEvent_Vtbl)).StatusCode)(this, plstatuscode), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Participant, State, StatusCode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCParticipantStateChangeEvent as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRTCPortManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMapping(this: *mut core::ffi::c_void, bstrremoteaddress: core::mem::MaybeUninit<windows_core::BSTR>, enporttype: RTC_PORT_TYPE, pbstrinternallocaladdress: *mut core::mem::MaybeUninit<windows_core::BSTR>, plinternallocalport: *mut i32, pbstrexternallocaladdress: *mut core::mem::MaybeUninit<windows_core::BSTR>, plexternallocalport: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCPortManager", "GetMapping", 3usize, |this| ((**(this as *const *const IRTCPortManager_Vtbl)).GetMapping)(this, bstrremoteaddress, enporttype, pbstrinternallocaladdress, plinternallocalport, pbstrexternallocaladdress, plexternallocalport), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateRemoteAddress(this: *mut core::ffi::c_void, bstrremoteaddress: core::mem::MaybeUninit<windows_core::BSTR>, bstrinternallocaladdress: core::mem::MaybeUninit<windows_core::BSTR>, linternallocalport: i32, bstrexternallocaladdress: core::mem::MaybeUninit<windows_core::BSTR>, lexternallocalport: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCPortManager", "UpdateRemoteAddress", 4usize, |this| ((**(this as *const *const IRTCPortManager_Vtbl)).UpdateRemoteAddress)(this, bstrremoteaddress, bstrinternallocaladdress, linternallocalport, bstrexternallocaladdress, lexternallocalport), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseMapping(this: *mut core::ffi::c_void, bstrinternallocaladdress: core::mem::MaybeUninit<windows_core::BSTR>, linternallocalport: i32, bstrexternallocaladdress: core::mem::MaybeUninit<windows_core::BSTR>, lexternallocaladdress: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCPortManager", "ReleaseMapping", 5usize, |this| ((**(this as *const *const IRTCPortManager_Vtbl)).ReleaseMapping)(this, bstrinternallocaladdress, linternallocalport, bstrexternallocaladdress, lexternallocaladdress), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMapping, UpdateRemoteAddress, ReleaseMapping }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCPortManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRTCPresenceContact_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PresentityURI(this: *mut core::ffi::c_void, pbstrpresentityuri: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCPresenceContact", "PresentityURI", 3usize, |this| ((**(this as *const *const IRTCPresenceContact_Vtbl)).PresentityURI)(this, pbstrpresentityuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPresentityURI(this: *mut core::ffi::c_void, bstrpresentityuri: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCPresenceContact", "SetPresentityURI", 4usize, |this| ((**(this as *const *const IRTCPresenceContact_Vtbl)).SetPresentityURI)(this, bstrpresentityuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCPresenceContact", "Name", 5usize, |this| ((**(this as *const *const IRTCPresenceContact_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCPresenceContact", "SetName", 6usize, |this| ((**(this as *const *const IRTCPresenceContact_Vtbl)).SetName)(this, bstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, pbstrdata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCPresenceContact", "Data", 7usize, |this| ((**(this as *const *const IRTCPresenceContact_Vtbl)).Data)(this, pbstrdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, bstrdata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCPresenceContact", "SetData", 8usize, |this| ((**(this as *const *const IRTCPresenceContact_Vtbl)).SetData)(this, bstrdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn Persistent(this: *mut core::ffi::c_void, pfpersistent: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCPresenceContact", "Persistent", 9usize, |this| ((**(this as *const *const IRTCPresenceContact_Vtbl)).Persistent)(this, pfpersistent), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPersistent(this: *mut core::ffi::c_void, fpersistent: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCPresenceContact", "SetPersistent", 10usize, |this| ((**(this as *const *const IRTCPresenceContact_Vtbl)).SetPersistent)(this, fpersistent), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PresentityURI,
+            SetPresentityURI,
+            Name,
+            SetName,
+            Data,
+            SetData,
+            Persistent,
+            SetPersistent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCPresenceContact as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCPresenceDataEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StatusCode(this: *mut core::ffi::c_void, plstatuscode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCPresenceDataEvent", "StatusCode", 7usize, |this| ((**(this as *const *const IRTCPresenceDataEvent_Vtbl)).StatusCode)(this, plstatuscode), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusText(this: *mut core::ffi::c_void, pbstrstatustext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCPresenceDataEvent", "StatusText", 8usize, |this| ((**(this as *const *const IRTCPresenceDataEvent_Vtbl)).StatusText)(this, pbstrstatustext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPresenceData(this: *mut core::ffi::c_void, pbstrnamespace: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrdata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCPresenceDataEvent", "GetPresenceData", 9usize, |this| ((**(this as *const *const IRTCPresenceDataEvent_Vtbl)).GetPresenceData)(this, pbstrnamespace, pbstrdata), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StatusCode, StatusText, GetPresenceData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCPresenceDataEvent as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRTCPresenceDevice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, penstatus: *mut RTC_PRESENCE_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCPresenceDevice", "Status", 3usize, |this| ((**(this as *const *const IRTCPresenceDevice_Vtbl)).Status)(this, penstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn Notes(this: *mut core::ffi::c_void, pbstrnotes: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCPresenceDevice", "Notes", 4usize, |this| ((**(this as *const *const IRTCPresenceDevice_Vtbl)).Notes)(this, pbstrnotes), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_PresenceProperty(this: *mut core::ffi::c_void, enproperty: RTC_PRESENCE_PROPERTY, pbstrproperty: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCPresenceDevice", "get_PresenceProperty", 5usize, |this| ((**(this as *const *const IRTCPresenceDevice_Vtbl)).get_PresenceProperty)(this, enproperty, pbstrproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPresenceData(this: *mut core::ffi::c_void, pbstrnamespace: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrdata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCPresenceDevice", "GetPresenceData", 6usize, |this| ((**(this as *const *const IRTCPresenceDevice_Vtbl)).GetPresenceData)(this, pbstrnamespace, pbstrdata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Status, Notes, get_PresenceProperty, GetPresenceData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCPresenceDevice as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCPresencePropertyEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StatusCode(this: *mut core::ffi::c_void, plstatuscode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCPresencePropertyEvent", "StatusCode", 7usize, |this| ((**(this as *const *const IRTCPresencePropertyEvent_Vtbl)).StatusCode)(this, plstatuscode), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusText(this: *mut core::ffi::c_void, pbstrstatustext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCPresencePropertyEvent", "StatusText", 8usize, |this| ((**(this as *const *const IRTCPresencePropertyEvent_Vtbl)).StatusText)(this, pbstrstatustext), |result| Some(*result))
+        }
+        unsafe extern "system" fn PresenceProperty(this: *mut core::ffi::c_void, penpresprop: *mut RTC_PRESENCE_PROPERTY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCPresencePropertyEvent", "PresenceProperty", 9usize, |this| ((**(this as *const *const IRTCPresencePropertyEvent_Vtbl)).PresenceProperty)(this, penpresprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, pbstrvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCPresencePropertyEvent", "Value", 10usize, |this| ((**(this as *const *const IRTCPresencePropertyEvent_Vtbl)).Value)(this, pbstrvalue), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StatusCode, StatusText, PresenceProperty, Value }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCPresencePropertyEvent as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCPresenceStatusEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StatusCode(this: *mut core::ffi::c_void, plstatuscode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCPresenceStatusEvent", "StatusCode", 7usize, |this| ((**(this as *const *const IRTCPresenceStatusEvent_Vtbl)).StatusCode)(this, plstatuscode), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusText(this: *mut core::ffi::c_void, pbstrstatustext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCPresenceStatusEvent", "StatusText", 8usize, |this| ((**(this as *const *const IRTCPresenceStatusEvent_Vtbl)).StatusText)(this, pbstrstatustext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLocalPresenceInfo(this: *mut core::ffi::c_void, penstatus: *mut RTC_PRESENCE_STATUS, pbstrnotes: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCPresenceStatusEvent", "GetLocalPresenceInfo", 9usize, |this| ((**(this as *const *const IRTCPresenceStatusEvent_Vtbl)).GetLocalPresenceInfo)(this, penstatus, pbstrnotes), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StatusCode, StatusText, GetLocalPresenceInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCPresenceStatusEvent as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRTCProfile_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Key(this: *mut core::ffi::c_void, pbstrkey: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCProfile", "Key", 3usize, |this| ((**(this as *const *const IRTCProfile_Vtbl)).Key)(this, pbstrkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCProfile", "Name", 4usize, |this| ((**(this as *const *const IRTCProfile_Vtbl)).Name)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn XML(this: *mut core::ffi::c_void, pbstrxml: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCProfile", "XML", 5usize, |this| ((**(this as *const *const IRTCProfile_Vtbl)).XML)(this, pbstrxml), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderName(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCProfile", "ProviderName", 6usize, |this| ((**(this as *const *const IRTCProfile_Vtbl)).ProviderName)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_ProviderURI(this: *mut core::ffi::c_void, enuri: RTC_PROVIDER_URI, pbstruri: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCProfile", "get_ProviderURI", 7usize, |this| ((**(this as *const *const IRTCProfile_Vtbl)).get_ProviderURI)(this, enuri, pbstruri), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProviderData(this: *mut core::ffi::c_void, pbstrdata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCProfile", "ProviderData", 8usize, |this| ((**(this as *const *const IRTCProfile_Vtbl)).ProviderData)(this, pbstrdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClientName(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCProfile", "ClientName", 9usize, |this| ((**(this as *const *const IRTCProfile_Vtbl)).ClientName)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClientBanner(this: *mut core::ffi::c_void, pfbanner: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCProfile", "ClientBanner", 10usize, |this| ((**(this as *const *const IRTCProfile_Vtbl)).ClientBanner)(this, pfbanner), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClientMinVer(this: *mut core::ffi::c_void, pbstrminver: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCProfile", "ClientMinVer", 11usize, |this| ((**(this as *const *const IRTCProfile_Vtbl)).ClientMinVer)(this, pbstrminver), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClientCurVer(this: *mut core::ffi::c_void, pbstrcurver: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCProfile", "ClientCurVer", 12usize, |this| ((**(this as *const *const IRTCProfile_Vtbl)).ClientCurVer)(this, pbstrcurver), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClientUpdateURI(this: *mut core::ffi::c_void, pbstrupdateuri: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCProfile", "ClientUpdateURI", 13usize, |this| ((**(this as *const *const IRTCProfile_Vtbl)).ClientUpdateURI)(this, pbstrupdateuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClientData(this: *mut core::ffi::c_void, pbstrdata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCProfile", "ClientData", 14usize, |this| ((**(this as *const *const IRTCProfile_Vtbl)).ClientData)(this, pbstrdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserURI(this: *mut core::ffi::c_void, pbstruseruri: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCProfile", "UserURI", 15usize, |this| ((**(this as *const *const IRTCProfile_Vtbl)).UserURI)(this, pbstruseruri), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserName(this: *mut core::ffi::c_void, pbstrusername: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCProfile", "UserName", 16usize, |this| ((**(this as *const *const IRTCProfile_Vtbl)).UserName)(this, pbstrusername), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserAccount(this: *mut core::ffi::c_void, pbstruseraccount: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCProfile", "UserAccount", 17usize, |this| ((**(this as *const *const IRTCProfile_Vtbl)).UserAccount)(this, pbstruseraccount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCredentials(this: *mut core::ffi::c_void, bstruseruri: core::mem::MaybeUninit<windows_core::BSTR>, bstruseraccount: core::mem::MaybeUninit<windows_core::BSTR>, bstrpassword: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCProfile", "SetCredentials", 18usize, |this| ((**(this as *const *const IRTCProfile_Vtbl)).SetCredentials)(this, bstruseruri, bstruseraccount, bstrpassword), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionCapabilities(this: *mut core::ffi::c_void, plsupportedsessions: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCProfile", "SessionCapabilities", 19usize, |this| ((**(this as *const *const IRTCProfile_Vtbl)).SessionCapabilities)(this, plsupportedsessions), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, penstate: *mut RTC_REGISTRATION_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCProfile", "State", 20usize, |this| ((**(this as *const *const IRTCProfile_Vtbl)).State)(this, penstate), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Key,
+            Name,
+            XML,
+            ProviderName,
+            get_ProviderURI,
+            ProviderData,
+            ClientName,
+            ClientBanner,
+            ClientMinVer,
+            ClientCurVer,
+            ClientUpdateURI,
+            ClientData,
+            UserURI,
+            UserName,
+            UserAccount,
+            SetCredentials,
+            SessionCapabilities,
+            State,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCProfile as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRTCProfile2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Realm(this: *mut core::ffi::c_void, pbstrrealm: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCProfile2", "Realm", 21usize, |this| ((**(this as *const *const IRTCProfile2_Vtbl)).Realm)(this, pbstrrealm), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRealm(this: *mut core::ffi::c_void, bstrrealm: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCProfile2", "SetRealm", 22usize, |this| ((**(this as *const *const IRTCProfile2_Vtbl)).SetRealm)(this, bstrrealm), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowedAuth(this: *mut core::ffi::c_void, plallowedauth: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCProfile2", "AllowedAuth", 23usize, |this| ((**(this as *const *const IRTCProfile2_Vtbl)).AllowedAuth)(this, plallowedauth), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowedAuth(this: *mut core::ffi::c_void, lallowedauth: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCProfile2", "SetAllowedAuth", 24usize, |this| ((**(this as *const *const IRTCProfile2_Vtbl)).SetAllowedAuth)(this, lallowedauth), |result| Some(*result))
+        }
+        Self { base__: <IRTCProfile_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Realm, SetRealm, AllowedAuth, SetAllowedAuth }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCProfile2 as windows_core::Interface>::IID || <IRTCProfile_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCProfileEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Profile(this: *mut core::ffi::c_void, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCProfileEvent", "Profile", 7usize, |this| ((**(this as *const *const IRTCProfileEvent_Vtbl)).Profile)(this, ppprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cookie(this: *mut core::ffi::c_void, plcookie: *mut isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCProfileEvent", "Cookie", 8usize, |this| ((**(this as *const *const IRTCProfileEvent_Vtbl)).Cookie)(this, plcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusCode(this: *mut core::ffi::c_void, plstatuscode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCProfileEvent", "StatusCode", 9usize, |this| ((**(this as *const *const IRTCProfileEvent_Vtbl)).StatusCode)(this, plstatuscode), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Profile, Cookie, StatusCode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCProfileEvent as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCProfileEvent2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EventType(this: *mut core::ffi::c_void, peventtype: *mut RTC_PROFILE_EVENT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCProfileEvent2", "EventType", 10usize, |this| ((**(this as *const *const IRTCProfileEvent2_Vtbl)).EventType)(this, peventtype), |result| Some(*result))
+        }
+        Self { base__: <IRTCProfileEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EventType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCProfileEvent2 as windows_core::Interface>::IID || <IRTCProfileEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCReInviteEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Session(this: *mut core::ffi::c_void, ppsession2: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCReInviteEvent", "Session", 7usize, |this| ((**(this as *const *const IRTCReInviteEvent_Vtbl)).Session)(this, ppsession2), |result| Some(*result))
+        }
+        unsafe extern "system" fn Accept(this: *mut core::ffi::c_void, bstrcontenttype: core::mem::MaybeUninit<windows_core::BSTR>, bstrsessiondescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCReInviteEvent", "Accept", 8usize, |this| ((**(this as *const *const IRTCReInviteEvent_Vtbl)).Accept)(this, bstrcontenttype, bstrsessiondescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reject(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCReInviteEvent", "Reject", 9usize, |this| ((**(this as *const *const IRTCReInviteEvent_Vtbl)).Reject)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, pstate: *mut RTC_REINVITE_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCReInviteEvent", "State", 10usize, |this| ((**(this as *const *const IRTCReInviteEvent_Vtbl)).State)(this, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRemoteSessionDescription(this: *mut core::ffi::c_void, pbstrcontenttype: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrsessiondescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCReInviteEvent", "GetRemoteSessionDescription", 11usize, |this| ((**(this as *const *const IRTCReInviteEvent_Vtbl)).GetRemoteSessionDescription)(this, pbstrcontenttype, pbstrsessiondescription), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Session, Accept, Reject, State, GetRemoteSessionDescription }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCReInviteEvent as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCRegistrationStateChangeEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Profile(this: *mut core::ffi::c_void, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCRegistrationStateChangeEvent", "Profile", 7usize, |this| ((**(this as *const *const IRTCRegistrationStateChangeEvent_Vtbl)).Profile)(this, ppprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, penstate: *mut RTC_REGISTRATION_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCRegistrationStateChangeEvent", "State", 8usize, |this| ((**(this as *const *const IRTCRegistrationStateChangeEvent_Vtbl)).State)(this, penstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusCode(this: *mut core::ffi::c_void, plstatuscode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCRegistrationStateChangeEvent", "StatusCode", 9usize, |this| ((**(this as *const *const IRTCRegistrationStateChangeEvent_Vtbl)).StatusCode)(this, plstatuscode), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusText(this: *mut core::ffi::c_void, pbstrstatustext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCRegistrationStateChangeEvent", "StatusText", 10usize, |this| ((**(this as *const *const IRTCRegistrationStateChangeEvent_Vtbl)).StatusText)(this, pbstrstatustext), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Profile, State, StatusCode, StatusText }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCRegistrationStateChangeEvent as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCRoamingEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EventType(this: *mut core::ffi::c_void, peventtype: *mut RTC_ROAMING_EVENT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCRoamingEvent", "EventType", 7usize, |this| ((**(this as *const *const IRTCRoamingEvent_Vtbl)).EventType)(this, peventtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Profile(this: *mut core::ffi::c_void, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCRoamingEvent", "Profile", 8usize, |this| ((**(this as *const *const IRTCRoamingEvent_Vtbl)).Profile)(this, ppprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusCode(this: *mut core::ffi::c_void, plstatuscode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCRoamingEvent", "StatusCode", 9usize, |this| ((**(this as *const *const IRTCRoamingEvent_Vtbl)).StatusCode)(this, plstatuscode), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusText(this: *mut core::ffi::c_void, pbstrstatustext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCRoamingEvent", "StatusText", 10usize, |this| ((**(this as *const *const IRTCRoamingEvent_Vtbl)).StatusText)(this, pbstrstatustext), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EventType, Profile, StatusCode, StatusText }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCRoamingEvent as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Client(this: *mut core::ffi::c_void, ppclient: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSession", "Client", 3usize, |this| ((**(this as *const *const IRTCSession_Vtbl)).Client)(this, ppclient), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, penstate: *mut RTC_SESSION_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSession", "State", 4usize, |this| ((**(this as *const *const IRTCSession_Vtbl)).State)(this, penstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, pentype: *mut RTC_SESSION_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSession", "Type", 5usize, |this| ((**(this as *const *const IRTCSession_Vtbl)).Type)(this, pentype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Profile(this: *mut core::ffi::c_void, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSession", "Profile", 6usize, |this| ((**(this as *const *const IRTCSession_Vtbl)).Profile)(this, ppprofile), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Participants(this: *mut core::ffi::c_void, ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSession", "Participants", 7usize, |this| ((**(this as *const *const IRTCSession_Vtbl)).Participants)(this, ppcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn Answer(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSession", "Answer", 8usize, |this| ((**(this as *const *const IRTCSession_Vtbl)).Answer)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Terminate(this: *mut core::ffi::c_void, enreason: RTC_TERMINATE_REASON) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSession", "Terminate", 9usize, |this| ((**(this as *const *const IRTCSession_Vtbl)).Terminate)(this, enreason), |result| Some(*result))
+        }
+        unsafe extern "system" fn Redirect(this: *mut core::ffi::c_void, entype: RTC_SESSION_TYPE, bstrlocalphoneuri: core::mem::MaybeUninit<windows_core::BSTR>, pprofile: *mut core::ffi::c_void, lflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSession", "Redirect", 10usize, |this| ((**(this as *const *const IRTCSession_Vtbl)).Redirect)(this, entype, bstrlocalphoneuri, pprofile, lflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddParticipant(this: *mut core::ffi::c_void, bstraddress: core::mem::MaybeUninit<windows_core::BSTR>, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, ppparticipant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSession", "AddParticipant", 11usize, |this| ((**(this as *const *const IRTCSession_Vtbl)).AddParticipant)(this, bstraddress, bstrname, ppparticipant), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveParticipant(this: *mut core::ffi::c_void, pparticipant: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSession", "RemoveParticipant", 12usize, |this| ((**(this as *const *const IRTCSession_Vtbl)).RemoveParticipant)(this, pparticipant), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateParticipants(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSession", "EnumerateParticipants", 13usize, |this| ((**(this as *const *const IRTCSession_Vtbl)).EnumerateParticipants)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanAddParticipants(this: *mut core::ffi::c_void, pfcanadd: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSession", "CanAddParticipants", 14usize, |this| ((**(this as *const *const IRTCSession_Vtbl)).CanAddParticipants)(this, pfcanadd), |result| Some(*result))
+        }
+        unsafe extern "system" fn RedirectedUserURI(this: *mut core::ffi::c_void, pbstruseruri: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSession", "RedirectedUserURI", 15usize, |this| ((**(this as *const *const IRTCSession_Vtbl)).RedirectedUserURI)(this, pbstruseruri), |result| Some(*result))
+        }
+        unsafe extern "system" fn RedirectedUserName(this: *mut core::ffi::c_void, pbstrusername: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSession", "RedirectedUserName", 16usize, |this| ((**(this as *const *const IRTCSession_Vtbl)).RedirectedUserName)(this, pbstrusername), |result| Some(*result))
+        }
+        unsafe extern "system" fn NextRedirectedUser(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSession", "NextRedirectedUser", 17usize, |this| ((**(this as *const *const IRTCSession_Vtbl)).NextRedirectedUser)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendMessage(this: *mut core::ffi::c_void, bstrmessageheader: core::mem::MaybeUninit<windows_core::BSTR>, bstrmessage: core::mem::MaybeUninit<windows_core::BSTR>, lcookie: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSession", "SendMessage", 18usize, |this| ((**(this as *const *const IRTCSession_Vtbl)).SendMessage)(this, bstrmessageheader, bstrmessage, lcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendMessageStatus(this: *mut core::ffi::c_void, enuserstatus: RTC_MESSAGING_USER_STATUS, lcookie: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSession", "SendMessageStatus", 19usize, |this| ((**(this as *const *const IRTCSession_Vtbl)).SendMessageStatus)(this, enuserstatus, lcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddStream(this: *mut core::ffi::c_void, lmediatype: i32, lcookie: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSession", "AddStream", 20usize, |this| ((**(this as *const *const IRTCSession_Vtbl)).AddStream)(this, lmediatype, lcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStream(this: *mut core::ffi::c_void, lmediatype: i32, lcookie: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSession", "RemoveStream", 21usize, |this| ((**(this as *const *const IRTCSession_Vtbl)).RemoveStream)(this, lmediatype, lcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_EncryptionKey(this: *mut core::ffi::c_void, lmediatype: i32, encryptionkey: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSession", "put_EncryptionKey", 22usize, |this| ((**(this as *const *const IRTCSession_Vtbl)).put_EncryptionKey)(this, lmediatype, encryptionkey), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Client,
+            State,
+            Type,
+            Profile,
+            #[cfg(feature = "Win32_System_Com")]
+            Participants,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Participants: 0,
+            Answer,
+            Terminate,
+            Redirect,
+            AddParticipant,
+            RemoveParticipant,
+            EnumerateParticipants,
+            CanAddParticipants,
+            RedirectedUserURI,
+            RedirectedUserName,
+            NextRedirectedUser,
+            SendMessage,
+            SendMessageStatus,
+            AddStream,
+            RemoveStream,
+            put_EncryptionKey,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCSession as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCSession2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SendInfo(this: *mut core::ffi::c_void, bstrinfoheader: core::mem::MaybeUninit<windows_core::BSTR>, bstrinfo: core::mem::MaybeUninit<windows_core::BSTR>, lcookie: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSession2", "SendInfo", 23usize, |this| ((**(this as *const *const IRTCSession2_Vtbl)).SendInfo)(this, bstrinfoheader, bstrinfo, lcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_PreferredSecurityLevel(this: *mut core::ffi::c_void, ensecuritytype: RTC_SECURITY_TYPE, ensecuritylevel: RTC_SECURITY_LEVEL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSession2", "put_PreferredSecurityLevel", 24usize, |this| ((**(this as *const *const IRTCSession2_Vtbl)).put_PreferredSecurityLevel)(this, ensecuritytype, ensecuritylevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_PreferredSecurityLevel(this: *mut core::ffi::c_void, ensecuritytype: RTC_SECURITY_TYPE, pensecuritylevel: *mut RTC_SECURITY_LEVEL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSession2", "get_PreferredSecurityLevel", 25usize, |this| ((**(this as *const *const IRTCSession2_Vtbl)).get_PreferredSecurityLevel)(this, ensecuritytype, pensecuritylevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSecurityEnabled(this: *mut core::ffi::c_void, ensecuritytype: RTC_SECURITY_TYPE, pfsecurityenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSession2", "IsSecurityEnabled", 26usize, |this| ((**(this as *const *const IRTCSession2_Vtbl)).IsSecurityEnabled)(this, ensecuritytype, pfsecurityenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn AnswerWithSessionDescription(this: *mut core::ffi::c_void, bstrcontenttype: core::mem::MaybeUninit<windows_core::BSTR>, bstrsessiondescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSession2", "AnswerWithSessionDescription", 27usize, |this| ((**(this as *const *const IRTCSession2_Vtbl)).AnswerWithSessionDescription)(this, bstrcontenttype, bstrsessiondescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReInviteWithSessionDescription(this: *mut core::ffi::c_void, bstrcontenttype: core::mem::MaybeUninit<windows_core::BSTR>, bstrsessiondescription: core::mem::MaybeUninit<windows_core::BSTR>, lcookie: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSession2", "ReInviteWithSessionDescription", 28usize, |this| ((**(this as *const *const IRTCSession2_Vtbl)).ReInviteWithSessionDescription)(this, bstrcontenttype, bstrsessiondescription, lcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <IRTCSession_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SendInfo,
+            put_PreferredSecurityLevel,
+            get_PreferredSecurityLevel,
+            IsSecurityEnabled,
+            AnswerWithSessionDescription,
+            ReInviteWithSessionDescription,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCSession2 as windows_core::Interface>::IID || <IRTCSession_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRTCSessionCallControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Hold(this: *mut core::ffi::c_void, lcookie: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionCallControl", "Hold", 3usize, |this| ((**(this as *const *const IRTCSessionCallControl_Vtbl)).Hold)(this, lcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnHold(this: *mut core::ffi::c_void, lcookie: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionCallControl", "UnHold", 4usize, |this| ((**(this as *const *const IRTCSessionCallControl_Vtbl)).UnHold)(this, lcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn Forward(this: *mut core::ffi::c_void, bstrforwardtouri: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionCallControl", "Forward", 5usize, |this| ((**(this as *const *const IRTCSessionCallControl_Vtbl)).Forward)(this, bstrforwardtouri), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refer(this: *mut core::ffi::c_void, bstrrefertouri: core::mem::MaybeUninit<windows_core::BSTR>, bstrrefercookie: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionCallControl", "Refer", 6usize, |this| ((**(this as *const *const IRTCSessionCallControl_Vtbl)).Refer)(this, bstrrefertouri, bstrrefercookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReferredByURI(this: *mut core::ffi::c_void, bstrreferredbyuri: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionCallControl", "SetReferredByURI", 7usize, |this| ((**(this as *const *const IRTCSessionCallControl_Vtbl)).SetReferredByURI)(this, bstrreferredbyuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReferredByURI(this: *mut core::ffi::c_void, pbstrreferredbyuri: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionCallControl", "ReferredByURI", 8usize, |this| ((**(this as *const *const IRTCSessionCallControl_Vtbl)).ReferredByURI)(this, pbstrreferredbyuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReferCookie(this: *mut core::ffi::c_void, bstrrefercookie: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionCallControl", "SetReferCookie", 9usize, |this| ((**(this as *const *const IRTCSessionCallControl_Vtbl)).SetReferCookie)(this, bstrrefercookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReferCookie(this: *mut core::ffi::c_void, pbstrrefercookie: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionCallControl", "ReferCookie", 10usize, |this| ((**(this as *const *const IRTCSessionCallControl_Vtbl)).ReferCookie)(this, pbstrrefercookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsReferred(this: *mut core::ffi::c_void, pfisreferred: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionCallControl", "IsReferred", 11usize, |this| ((**(this as *const *const IRTCSessionCallControl_Vtbl)).IsReferred)(this, pfisreferred), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Hold,
+            UnHold,
+            Forward,
+            Refer,
+            SetReferredByURI,
+            ReferredByURI,
+            SetReferCookie,
+            ReferCookie,
+            IsReferred,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCSessionCallControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRTCSessionDescriptionManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EvaluateSessionDescription(this: *mut core::ffi::c_void, bstrcontenttype: core::mem::MaybeUninit<windows_core::BSTR>, bstrsessiondescription: core::mem::MaybeUninit<windows_core::BSTR>, pfapplicationsession: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionDescriptionManager", "EvaluateSessionDescription", 3usize, |this| ((**(this as *const *const IRTCSessionDescriptionManager_Vtbl)).EvaluateSessionDescription)(this, bstrcontenttype, bstrsessiondescription, pfapplicationsession), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EvaluateSessionDescription }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCSessionDescriptionManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCSessionOperationCompleteEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Session(this: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionOperationCompleteEvent", "Session", 7usize, |this| ((**(this as *const *const IRTCSessionOperationCompleteEvent_Vtbl)).Session)(this, ppsession), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cookie(this: *mut core::ffi::c_void, plcookie: *mut isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionOperationCompleteEvent", "Cookie", 8usize, |this| ((**(this as *const *const IRTCSessionOperationCompleteEvent_Vtbl)).Cookie)(this, plcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusCode(this: *mut core::ffi::c_void, plstatuscode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionOperationCompleteEvent", "StatusCode", 9usize, |this| ((**(this as *const *const IRTCSessionOperationCompleteEvent_Vtbl)).StatusCode)(this, plstatuscode), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusText(this: *mut core::ffi::c_void, pbstrstatustext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionOperationCompleteEvent", "StatusText", 10usize, |this| ((**(this as *const *const IRTCSessionOperationCompleteEvent_Vtbl)).StatusText)(this, pbstrstatustext), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Session, Cookie, StatusCode, StatusText }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCSessionOperationCompleteEvent as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCSessionOperationCompleteEvent2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Participant(this: *mut core::ffi::c_void, ppparticipant: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionOperationCompleteEvent2", "Participant", 11usize, |this| ((**(this as *const *const IRTCSessionOperationCompleteEvent2_Vtbl)).Participant)(this, ppparticipant), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRemoteSessionDescription(this: *mut core::ffi::c_void, pbstrcontenttype: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrsessiondescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionOperationCompleteEvent2", "GetRemoteSessionDescription", 12usize, |this| ((**(this as *const *const IRTCSessionOperationCompleteEvent2_Vtbl)).GetRemoteSessionDescription)(this, pbstrcontenttype, pbstrsessiondescription), |result| Some(*result))
+        }
+        Self { base__: <IRTCSessionOperationCompleteEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Participant, GetRemoteSessionDescription }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCSessionOperationCompleteEvent2 as windows_core::Interface>::IID || <IRTCSessionOperationCompleteEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRTCSessionPortManagement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPortManager(this: *mut core::ffi::c_void, pportmanager: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionPortManagement", "SetPortManager", 3usize, |this| ((**(this as *const *const IRTCSessionPortManagement_Vtbl)).SetPortManager)(this, pportmanager), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetPortManager }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCSessionPortManagement as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCSessionReferStatusEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Session(this: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionReferStatusEvent", "Session", 7usize, |this| ((**(this as *const *const IRTCSessionReferStatusEvent_Vtbl)).Session)(this, ppsession), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReferStatus(this: *mut core::ffi::c_void, penreferstatus: *mut RTC_SESSION_REFER_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionReferStatusEvent", "ReferStatus", 8usize, |this| ((**(this as *const *const IRTCSessionReferStatusEvent_Vtbl)).ReferStatus)(this, penreferstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusCode(this: *mut core::ffi::c_void, plstatuscode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionReferStatusEvent", "StatusCode", 9usize, |this| ((**(this as *const *const IRTCSessionReferStatusEvent_Vtbl)).StatusCode)(this, plstatuscode), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusText(this: *mut core::ffi::c_void, pbstrstatustext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionReferStatusEvent", "StatusText", 10usize, |this| ((**(this as *const *const IRTCSessionReferStatusEvent_Vtbl)).StatusText)(this, pbstrstatustext), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Session, ReferStatus, StatusCode, StatusText }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCSessionReferStatusEvent as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCSessionReferredEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Session(this: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionReferredEvent", "Session", 7usize, |this| ((**(this as *const *const IRTCSessionReferredEvent_Vtbl)).Session)(this, ppsession), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReferredByURI(this: *mut core::ffi::c_void, pbstrreferredbyuri: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionReferredEvent", "ReferredByURI", 8usize, |this| ((**(this as *const *const IRTCSessionReferredEvent_Vtbl)).ReferredByURI)(this, pbstrreferredbyuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReferToURI(this: *mut core::ffi::c_void, pbstrreferouri: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionReferredEvent", "ReferToURI", 9usize, |this| ((**(this as *const *const IRTCSessionReferredEvent_Vtbl)).ReferToURI)(this, pbstrreferouri), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReferCookie(this: *mut core::ffi::c_void, pbstrrefercookie: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionReferredEvent", "ReferCookie", 10usize, |this| ((**(this as *const *const IRTCSessionReferredEvent_Vtbl)).ReferCookie)(this, pbstrrefercookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn Accept(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionReferredEvent", "Accept", 11usize, |this| ((**(this as *const *const IRTCSessionReferredEvent_Vtbl)).Accept)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reject(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionReferredEvent", "Reject", 12usize, |this| ((**(this as *const *const IRTCSessionReferredEvent_Vtbl)).Reject)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReferredSessionState(this: *mut core::ffi::c_void, enstate: RTC_SESSION_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionReferredEvent", "SetReferredSessionState", 13usize, |this| ((**(this as *const *const IRTCSessionReferredEvent_Vtbl)).SetReferredSessionState)(this, enstate), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Session,
+            ReferredByURI,
+            ReferToURI,
+            ReferCookie,
+            Accept,
+            Reject,
+            SetReferredSessionState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCSessionReferredEvent as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCSessionStateChangeEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Session(this: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionStateChangeEvent", "Session", 7usize, |this| ((**(this as *const *const IRTCSessionStateChangeEvent_Vtbl)).Session)(this, ppsession), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, penstate: *mut RTC_SESSION_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionStateChangeEvent", "State", 8usize, |this| ((**(this as *const *const IRTCSessionStateChangeEvent_Vtbl)).State)(this, penstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusCode(this: *mut core::ffi::c_void, plstatuscode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionStateChangeEvent", "StatusCode", 9usize, |this| ((**(this as *const *const IRTCSessionStateChangeEvent_Vtbl)).StatusCode)(this, plstatuscode), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusText(this: *mut core::ffi::c_void, pbstrstatustext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionStateChangeEvent", "StatusText", 10usize, |this| ((**(this as *const *const IRTCSessionStateChangeEvent_Vtbl)).StatusText)(this, pbstrstatustext), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Session, State, StatusCode, StatusText }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCSessionStateChangeEvent as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCSessionStateChangeEvent2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MediaTypes(this: *mut core::ffi::c_void, pmediatypes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionStateChangeEvent2", "MediaTypes", 11usize, |this| ((**(this as *const *const IRTCSessionStateChangeEvent2_Vtbl)).MediaTypes)(this, pmediatypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_RemotePreferredSecurityLevel(this: *mut core::ffi::c_void, ensecuritytype: RTC_SECURITY_TYPE, pensecuritylevel: *mut RTC_SECURITY_LEVEL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionStateChangeEvent2", "get_RemotePreferredSecurityLevel", 12usize, |this| ((**(this as *const *const IRTCSessionStateChangeEvent2_Vtbl)).get_RemotePreferredSecurityLevel)(this, ensecuritytype, pensecuritylevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsForked(this: *mut core::ffi::c_void, pfisforked: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionStateChangeEvent2", "IsForked", 13usize, |this| ((**(this as *const *const IRTCSessionStateChangeEvent2_Vtbl)).IsForked)(this, pfisforked), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRemoteSessionDescription(this: *mut core::ffi::c_void, pbstrcontenttype: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrsessiondescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCSessionStateChangeEvent2", "GetRemoteSessionDescription", 14usize, |this| ((**(this as *const *const IRTCSessionStateChangeEvent2_Vtbl)).GetRemoteSessionDescription)(this, pbstrcontenttype, pbstrsessiondescription), |result| Some(*result))
+        }
+        Self {
+            base__: <IRTCSessionStateChangeEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            MediaTypes,
+            get_RemotePreferredSecurityLevel,
+            IsForked,
+            GetRemoteSessionDescription,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCSessionStateChangeEvent2 as windows_core::Interface>::IID || <IRTCSessionStateChangeEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRTCUserSearch_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateQuery(this: *mut core::ffi::c_void, ppquery: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCUserSearch", "CreateQuery", 3usize, |this| ((**(this as *const *const IRTCUserSearch_Vtbl)).CreateQuery)(this, ppquery), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExecuteSearch(this: *mut core::ffi::c_void, pquery: *mut core::ffi::c_void, pprofile: *mut core::ffi::c_void, lcookie: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCUserSearch", "ExecuteSearch", 4usize, |this| ((**(this as *const *const IRTCUserSearch_Vtbl)).ExecuteSearch)(this, pquery, pprofile, lcookie), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateQuery, ExecuteSearch }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCUserSearch as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRTCUserSearchQuery_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn put_SearchTerm(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, bstrvalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCUserSearchQuery", "put_SearchTerm", 3usize, |this| ((**(this as *const *const IRTCUserSearchQuery_Vtbl)).put_SearchTerm)(this, bstrname, bstrvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_SearchTerm(this: *mut core::ffi::c_void, bstrname: core::mem::MaybeUninit<windows_core::BSTR>, pbstrvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCUserSearchQuery", "get_SearchTerm", 4usize, |this| ((**(this as *const *const IRTCUserSearchQuery_Vtbl)).get_SearchTerm)(this, bstrname, pbstrvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchTerms(this: *mut core::ffi::c_void, pbstrnames: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCUserSearchQuery", "SearchTerms", 5usize, |this| ((**(this as *const *const IRTCUserSearchQuery_Vtbl)).SearchTerms)(this, pbstrnames), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_SearchPreference(this: *mut core::ffi::c_void, enpreference: RTC_USER_SEARCH_PREFERENCE, lvalue: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCUserSearchQuery", "put_SearchPreference", 6usize, |this| ((**(this as *const *const IRTCUserSearchQuery_Vtbl)).put_SearchPreference)(this, enpreference, lvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_SearchPreference(this: *mut core::ffi::c_void, enpreference: RTC_USER_SEARCH_PREFERENCE, plvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCUserSearchQuery", "get_SearchPreference", 7usize, |this| ((**(this as *const *const IRTCUserSearchQuery_Vtbl)).get_SearchPreference)(this, enpreference, plvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSearchDomain(this: *mut core::ffi::c_void, bstrdomain: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCUserSearchQuery", "SetSearchDomain", 8usize, |this| ((**(this as *const *const IRTCUserSearchQuery_Vtbl)).SetSearchDomain)(this, bstrdomain), |result| Some(*result))
+        }
+        unsafe extern "system" fn SearchDomain(this: *mut core::ffi::c_void, pbstrdomain: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCUserSearchQuery", "SearchDomain", 9usize, |this| ((**(this as *const *const IRTCUserSearchQuery_Vtbl)).SearchDomain)(this, pbstrdomain), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            put_SearchTerm,
+            get_SearchTerm,
+            SearchTerms,
+            put_SearchPreference,
+            get_SearchPreference,
+            SetSearchDomain,
+            SearchDomain,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCUserSearchQuery as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRTCUserSearchResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_Value(this: *mut core::ffi::c_void, encolumn: RTC_USER_SEARCH_COLUMN, pbstrvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCUserSearchResult", "get_Value", 3usize, |this| ((**(this as *const *const IRTCUserSearchResult_Vtbl)).get_Value)(this, encolumn, pbstrvalue), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, get_Value }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCUserSearchResult as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCUserSearchResultsEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumerateResults(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCUserSearchResultsEvent", "EnumerateResults", 7usize, |this| ((**(this as *const *const IRTCUserSearchResultsEvent_Vtbl)).EnumerateResults)(this, ppenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Results(this: *mut core::ffi::c_void, ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCUserSearchResultsEvent", "Results", 8usize, |this| ((**(this as *const *const IRTCUserSearchResultsEvent_Vtbl)).Results)(this, ppcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn Profile(this: *mut core::ffi::c_void, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCUserSearchResultsEvent", "Profile", 9usize, |this| ((**(this as *const *const IRTCUserSearchResultsEvent_Vtbl)).Profile)(this, ppprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn Query(this: *mut core::ffi::c_void, ppquery: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCUserSearchResultsEvent", "Query", 10usize, |this| ((**(this as *const *const IRTCUserSearchResultsEvent_Vtbl)).Query)(this, ppquery), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cookie(this: *mut core::ffi::c_void, plcookie: *mut isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCUserSearchResultsEvent", "Cookie", 11usize, |this| ((**(this as *const *const IRTCUserSearchResultsEvent_Vtbl)).Cookie)(this, plcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusCode(this: *mut core::ffi::c_void, plstatuscode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCUserSearchResultsEvent", "StatusCode", 12usize, |this| ((**(this as *const *const IRTCUserSearchResultsEvent_Vtbl)).StatusCode)(this, plstatuscode), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoreAvailable(this: *mut core::ffi::c_void, pfmoreavailable: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCUserSearchResultsEvent", "MoreAvailable", 13usize, |this| ((**(this as *const *const IRTCUserSearchResultsEvent_Vtbl)).MoreAvailable)(this, pfmoreavailable), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EnumerateResults,
+            #[cfg(feature = "Win32_System_Com")]
+            Results,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Results: 0,
+            Profile,
+            Query,
+            Cookie,
+            StatusCode,
+            MoreAvailable,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCUserSearchResultsEvent as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRTCWatcher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, penstate: *mut RTC_WATCHER_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCWatcher", "State", 11usize, |this| ((**(this as *const *const IRTCWatcher_Vtbl)).State)(this, penstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetState(this: *mut core::ffi::c_void, enstate: RTC_WATCHER_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCWatcher", "SetState", 12usize, |this| ((**(this as *const *const IRTCWatcher_Vtbl)).SetState)(this, enstate), |result| Some(*result))
+        }
+        Self { base__: <IRTCPresenceContact_Vtbl as windows_core::imp::ProxyVtable>::PROXY, State, SetState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCWatcher as windows_core::Interface>::IID || <IRTCPresenceContact_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRTCWatcher2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Profile(this: *mut core::ffi::c_void, ppprofile: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCWatcher2", "Profile", 13usize, |this| ((**(this as *const *const IRTCWatcher2_Vtbl)).Profile)(this, ppprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn Scope(this: *mut core::ffi::c_void, penscope: *mut RTC_ACE_SCOPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCWatcher2", "Scope", 14usize, |this| ((**(this as *const *const IRTCWatcher2_Vtbl)).Scope)(this, penscope), |result| Some(*result))
+        }
+        Self { base__: <IRTCWatcher_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Profile, Scope }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCWatcher2 as windows_core::Interface>::IID || <IRTCWatcher_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCWatcherEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Watcher(this: *mut core::ffi::c_void, ppwatcher: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCWatcherEvent", "Watcher", 7usize, |this| ((**(this as *const *const IRTCWatcherEvent_Vtbl)).Watcher)(this, ppwatcher), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Watcher }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCWatcherEvent as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRTCWatcherEvent2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EventType(this: *mut core::ffi::c_void, peventtype: *mut RTC_WATCHER_EVENT_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCWatcherEvent2", "EventType", 8usize, |this| ((**(this as *const *const IRTCWatcherEvent2_Vtbl)).EventType)(this, peventtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn StatusCode(this: *mut core::ffi::c_void, plstatuscode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRTCWatcherEvent2", "StatusCode", 9usize, |this| ((**(this as *const *const IRTCWatcherEvent2_Vtbl)).StatusCode)(this, plstatuscode), |result| Some(*result))
+        }
+        Self { base__: <IRTCWatcherEvent_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EventType, StatusCode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRTCWatcherEvent2 as windows_core::Interface>::IID || <IRTCWatcherEvent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Networking_WinSock")]
+impl windows_core::imp::ProxyVtable for ITransportSettingsInternal_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Networking_WinSock")]
+        unsafe extern "system" fn ApplySetting(this: *mut core::ffi::c_void, setting: *mut TRANSPORT_SETTING) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransportSettingsInternal", "ApplySetting", 3usize, |this| ((**(this as *const *const ITransportSettingsInternal_Vtbl)).ApplySetting)(this, setting), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Networking_WinSock")]
+        unsafe extern "system" fn QuerySetting(this: *mut core::ffi::c_void, setting: *mut TRANSPORT_SETTING) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransportSettingsInternal", "QuerySetting", 4usize, |this| ((**(this as *const *const ITransportSettingsInternal_Vtbl)).QuerySetting)(this, setting), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Networking_WinSock")]
+            ApplySetting,
+            #[cfg(not(feature = "Win32_Networking_WinSock"))]
+            ApplySetting: 0,
+            #[cfg(feature = "Win32_Networking_WinSock")]
+            QuerySetting,
+            #[cfg(not(feature = "Win32_Networking_WinSock"))]
+            QuerySetting: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransportSettingsInternal as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -63,6 +63,7 @@ pub struct REGISTER_APPLICATION_RESTART_FLAGS(pub u32);
 impl windows_core::TypeKind for REGISTER_APPLICATION_RESTART_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for REGISTER_APPLICATION_RESTART_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("REGISTER_APPLICATION_RESTART_FLAGS").field(&self.0).finish()
@@ -1738,6 +1738,7 @@ pub struct REG_CREATE_KEY_DISPOSITION(pub u32);
 impl windows_core::TypeKind for REG_CREATE_KEY_DISPOSITION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for REG_CREATE_KEY_DISPOSITION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("REG_CREATE_KEY_DISPOSITION").field(&self.0).finish()
@@ -1749,6 +1750,7 @@ pub struct REG_NOTIFY_FILTER(pub u32);
 impl windows_core::TypeKind for REG_NOTIFY_FILTER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for REG_NOTIFY_FILTER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("REG_NOTIFY_FILTER").field(&self.0).finish()
@@ -1793,6 +1795,7 @@ pub struct REG_OPEN_CREATE_OPTIONS(pub u32);
 impl windows_core::TypeKind for REG_OPEN_CREATE_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for REG_OPEN_CREATE_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("REG_OPEN_CREATE_OPTIONS").field(&self.0).finish()
@@ -1837,6 +1840,7 @@ pub struct REG_RESTORE_KEY_FLAGS(pub i32);
 impl windows_core::TypeKind for REG_RESTORE_KEY_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for REG_RESTORE_KEY_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("REG_RESTORE_KEY_FLAGS").field(&self.0).finish()
@@ -1848,6 +1852,7 @@ pub struct REG_ROUTINE_FLAGS(pub u32);
 impl windows_core::TypeKind for REG_ROUTINE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for REG_ROUTINE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("REG_ROUTINE_FLAGS").field(&self.0).finish()
@@ -1892,6 +1897,7 @@ pub struct REG_SAM_FLAGS(pub u32);
 impl windows_core::TypeKind for REG_SAM_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for REG_SAM_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("REG_SAM_FLAGS").field(&self.0).finish()
@@ -1936,6 +1942,7 @@ pub struct REG_SAVE_FORMAT(pub u32);
 impl windows_core::TypeKind for REG_SAVE_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for REG_SAVE_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("REG_SAVE_FORMAT").field(&self.0).finish()
@@ -1947,13 +1954,15 @@ pub struct REG_VALUE_TYPE(pub u32);
 impl windows_core::TypeKind for REG_VALUE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for REG_VALUE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("REG_VALUE_TYPE").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DSKTLSYSTEMTIME {
     pub wYear: u16,
     pub wMonth: u16,
@@ -1974,7 +1983,8 @@ impl Default for DSKTLSYSTEMTIME {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HKEY(pub *mut core::ffi::c_void);
 impl HKEY {
     pub fn is_invalid(&self) -> bool {
@@ -1998,7 +2008,8 @@ impl windows_core::TypeKind for HKEY {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PVALUEA {
     pub pv_valuename: windows_core::PSTR,
     pub pv_valuelen: i32,
@@ -2014,7 +2025,8 @@ impl Default for PVALUEA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PVALUEW {
     pub pv_valuename: windows_core::PWSTR,
     pub pv_valuelen: i32,
@@ -2030,7 +2042,8 @@ impl Default for PVALUEW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REG_PROVIDER {
     pub pi_R0_1val: PQUERYHANDLER,
     pub pi_R0_allvals: PQUERYHANDLER,
@@ -2048,7 +2061,8 @@ impl Default for REG_PROVIDER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VALENTA {
     pub ve_valuename: windows_core::PSTR,
     pub ve_valuelen: u32,
@@ -2064,7 +2078,8 @@ impl Default for VALENTA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VALENTW {
     pub ve_valuename: windows_core::PWSTR,
     pub ve_valuelen: u32,
@@ -2080,7 +2095,8 @@ impl Default for VALENTW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct val_context {
     pub valuelen: i32,
     pub value_context: *mut core::ffi::c_void,
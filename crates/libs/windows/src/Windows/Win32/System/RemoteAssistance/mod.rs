@@ -104,6 +104,7 @@ pub struct RENDEZVOUS_SESSION_FLAGS(pub i32);
 impl windows_core::TypeKind for RENDEZVOUS_SESSION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RENDEZVOUS_SESSION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RENDEZVOUS_SESSION_FLAGS").field(&self.0).finish()
@@ -115,6 +116,7 @@ pub struct RENDEZVOUS_SESSION_STATE(pub i32);
 impl windows_core::TypeKind for RENDEZVOUS_SESSION_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RENDEZVOUS_SESSION_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RENDEZVOUS_SESSION_STATE").field(&self.0).finish()
@@ -123,3 +125,5 @@ impl core::fmt::Debug for RENDEZVOUS_SESSION_STATE {
 pub const RendezvousApplication: windows_core::GUID = windows_core::GUID::from_u128(0x0b7e019a_b5de_47fa_8966_9082f82fb192);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,44 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for DRendezvousSessionEvents_Vtbl {
+    const PROXY: Self = { Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<DRendezvousSessionEvents as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRendezvousApplication_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetRendezvousSession(this: *mut core::ffi::c_void, prendezvoussession: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRendezvousApplication", "SetRendezvousSession", 3usize, |this| ((**(this as *const *const IRendezvousApplication_Vtbl)).SetRendezvousSession)(this, prendezvoussession), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetRendezvousSession }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRendezvousApplication as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRendezvousSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, psessionstate: *mut RENDEZVOUS_SESSION_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRendezvousSession", "State", 3usize, |this| ((**(this as *const *const IRendezvousSession_Vtbl)).State)(this, psessionstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteUser(this: *mut core::ffi::c_void, bstrusername: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRendezvousSession", "RemoteUser", 4usize, |this| ((**(this as *const *const IRendezvousSession_Vtbl)).RemoteUser)(this, bstrusername), |result| Some(*result))
+        }
+        unsafe extern "system" fn Flags(this: *mut core::ffi::c_void, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRendezvousSession", "Flags", 5usize, |this| ((**(this as *const *const IRendezvousSession_Vtbl)).Flags)(this, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendContextData(this: *mut core::ffi::c_void, bstrdata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRendezvousSession", "SendContextData", 6usize, |this| ((**(this as *const *const IRendezvousSession_Vtbl)).SendContextData)(this, bstrdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn Terminate(this: *mut core::ffi::c_void, hr: windows_core::HRESULT, bstrappdata: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRendezvousSession", "Terminate", 7usize, |this| ((**(this as *const *const IRendezvousSession_Vtbl)).Terminate)(this, hr, bstrappdata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, State, RemoteUser, Flags, SendContextData, Terminate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRendezvousSession as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -0,0 +1,2779 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IADsTSUserEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TerminalServicesProfilePath(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "TerminalServicesProfilePath", 7usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).TerminalServicesProfilePath)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTerminalServicesProfilePath(this: *mut core::ffi::c_void, pnewval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "SetTerminalServicesProfilePath", 8usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).SetTerminalServicesProfilePath)(this, pnewval), |result| Some(*result))
+        }
+        unsafe extern "system" fn TerminalServicesHomeDirectory(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "TerminalServicesHomeDirectory", 9usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).TerminalServicesHomeDirectory)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTerminalServicesHomeDirectory(this: *mut core::ffi::c_void, pnewval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "SetTerminalServicesHomeDirectory", 10usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).SetTerminalServicesHomeDirectory)(this, pnewval), |result| Some(*result))
+        }
+        unsafe extern "system" fn TerminalServicesHomeDrive(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "TerminalServicesHomeDrive", 11usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).TerminalServicesHomeDrive)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTerminalServicesHomeDrive(this: *mut core::ffi::c_void, pnewval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "SetTerminalServicesHomeDrive", 12usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).SetTerminalServicesHomeDrive)(this, pnewval), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowLogon(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "AllowLogon", 13usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).AllowLogon)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowLogon(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "SetAllowLogon", 14usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).SetAllowLogon)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableRemoteControl(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "EnableRemoteControl", 15usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).EnableRemoteControl)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnableRemoteControl(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "SetEnableRemoteControl", 16usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).SetEnableRemoteControl)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxDisconnectionTime(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "MaxDisconnectionTime", 17usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).MaxDisconnectionTime)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxDisconnectionTime(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "SetMaxDisconnectionTime", 18usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).SetMaxDisconnectionTime)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxConnectionTime(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "MaxConnectionTime", 19usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).MaxConnectionTime)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxConnectionTime(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "SetMaxConnectionTime", 20usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).SetMaxConnectionTime)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxIdleTime(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "MaxIdleTime", 21usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).MaxIdleTime)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxIdleTime(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "SetMaxIdleTime", 22usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).SetMaxIdleTime)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReconnectionAction(this: *mut core::ffi::c_void, pnewval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "ReconnectionAction", 23usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).ReconnectionAction)(this, pnewval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReconnectionAction(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "SetReconnectionAction", 24usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).SetReconnectionAction)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn BrokenConnectionAction(this: *mut core::ffi::c_void, pnewval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "BrokenConnectionAction", 25usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).BrokenConnectionAction)(this, pnewval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBrokenConnectionAction(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "SetBrokenConnectionAction", 26usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).SetBrokenConnectionAction)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectClientDrivesAtLogon(this: *mut core::ffi::c_void, pnewval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "ConnectClientDrivesAtLogon", 27usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).ConnectClientDrivesAtLogon)(this, pnewval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConnectClientDrivesAtLogon(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "SetConnectClientDrivesAtLogon", 28usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).SetConnectClientDrivesAtLogon)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectClientPrintersAtLogon(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "ConnectClientPrintersAtLogon", 29usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).ConnectClientPrintersAtLogon)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConnectClientPrintersAtLogon(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "SetConnectClientPrintersAtLogon", 30usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).SetConnectClientPrintersAtLogon)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultToMainPrinter(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "DefaultToMainPrinter", 31usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).DefaultToMainPrinter)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultToMainPrinter(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "SetDefaultToMainPrinter", 32usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).SetDefaultToMainPrinter)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn TerminalServicesWorkDirectory(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "TerminalServicesWorkDirectory", 33usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).TerminalServicesWorkDirectory)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTerminalServicesWorkDirectory(this: *mut core::ffi::c_void, pnewval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "SetTerminalServicesWorkDirectory", 34usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).SetTerminalServicesWorkDirectory)(this, pnewval), |result| Some(*result))
+        }
+        unsafe extern "system" fn TerminalServicesInitialProgram(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "TerminalServicesInitialProgram", 35usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).TerminalServicesInitialProgram)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTerminalServicesInitialProgram(this: *mut core::ffi::c_void, pnewval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADsTSUserEx", "SetTerminalServicesInitialProgram", 36usize, |this| ((**(this as *const *const IADsTSUserEx_Vtbl)).SetTerminalServicesInitialProgram)(this, pnewval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TerminalServicesProfilePath,
+            SetTerminalServicesProfilePath,
+            TerminalServicesHomeDirectory,
+            SetTerminalServicesHomeDirectory,
+            TerminalServicesHomeDrive,
+            SetTerminalServicesHomeDrive,
+            AllowLogon,
+            SetAllowLogon,
+            EnableRemoteControl,
+            SetEnableRemoteControl,
+            MaxDisconnectionTime,
+            SetMaxDisconnectionTime,
+            MaxConnectionTime,
+            SetMaxConnectionTime,
+            MaxIdleTime,
+            SetMaxIdleTime,
+            ReconnectionAction,
+            SetReconnectionAction,
+            BrokenConnectionAction,
+            SetBrokenConnectionAction,
+            ConnectClientDrivesAtLogon,
+            SetConnectClientDrivesAtLogon,
+            ConnectClientPrintersAtLogon,
+            SetConnectClientPrintersAtLogon,
+            DefaultToMainPrinter,
+            SetDefaultToMainPrinter,
+            TerminalServicesWorkDirectory,
+            SetTerminalServicesWorkDirectory,
+            TerminalServicesInitialProgram,
+            SetTerminalServicesInitialProgram,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADsTSUserEx as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioDeviceEndpoint_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetBuffer(this: *mut core::ffi::c_void, maxperiod: i64, u32latencycoefficient: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioDeviceEndpoint", "SetBuffer", 3usize, |this| ((**(this as *const *const IAudioDeviceEndpoint_Vtbl)).SetBuffer)(this, maxperiod, u32latencycoefficient), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRTCaps(this: *mut core::ffi::c_void, pbisrtcapable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioDeviceEndpoint", "GetRTCaps", 4usize, |this| ((**(this as *const *const IAudioDeviceEndpoint_Vtbl)).GetRTCaps)(this, pbisrtcapable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEventDrivenCapable(this: *mut core::ffi::c_void, pbiseventcapable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioDeviceEndpoint", "GetEventDrivenCapable", 5usize, |this| ((**(this as *const *const IAudioDeviceEndpoint_Vtbl)).GetEventDrivenCapable)(this, pbiseventcapable), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteExclusiveModeParametersToSharedMemory(this: *mut core::ffi::c_void, htargetprocess: usize, hnsperiod: i64, hnsbufferduration: i64, u32latencycoefficient: u32, pu32sharedmemorysize: *mut u32, phsharedmemory: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioDeviceEndpoint", "WriteExclusiveModeParametersToSharedMemory", 6usize, |this| ((**(this as *const *const IAudioDeviceEndpoint_Vtbl)).WriteExclusiveModeParametersToSharedMemory)(this, htargetprocess, hnsperiod, hnsbufferduration, u32latencycoefficient, pu32sharedmemorysize, phsharedmemory), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetBuffer,
+            GetRTCaps,
+            GetEventDrivenCapable,
+            WriteExclusiveModeParametersToSharedMemory,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioDeviceEndpoint as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_Audio")]
+impl windows_core::imp::ProxyVtable for IAudioEndpoint_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_Audio")]
+        unsafe extern "system" fn GetFrameFormat(this: *mut core::ffi::c_void, ppformat: *mut *mut super::super::Media::Audio::WAVEFORMATEX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpoint", "GetFrameFormat", 3usize, |this| ((**(this as *const *const IAudioEndpoint_Vtbl)).GetFrameFormat)(this, ppformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFramesPerPacket(this: *mut core::ffi::c_void, pframesperpacket: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpoint", "GetFramesPerPacket", 4usize, |this| ((**(this as *const *const IAudioEndpoint_Vtbl)).GetFramesPerPacket)(this, pframesperpacket), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLatency(this: *mut core::ffi::c_void, platency: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpoint", "GetLatency", 5usize, |this| ((**(this as *const *const IAudioEndpoint_Vtbl)).GetLatency)(this, platency), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStreamFlags(this: *mut core::ffi::c_void, streamflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpoint", "SetStreamFlags", 6usize, |this| ((**(this as *const *const IAudioEndpoint_Vtbl)).SetStreamFlags)(this, streamflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventHandle(this: *mut core::ffi::c_void, eventhandle: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpoint", "SetEventHandle", 7usize, |this| ((**(this as *const *const IAudioEndpoint_Vtbl)).SetEventHandle)(this, eventhandle), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_Audio")]
+            GetFrameFormat,
+            #[cfg(not(feature = "Win32_Media_Audio"))]
+            GetFrameFormat: 0,
+            GetFramesPerPacket,
+            GetLatency,
+            SetStreamFlags,
+            SetEventHandle,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioEndpoint as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioEndpointControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointControl", "Start", 3usize, |this| ((**(this as *const *const IAudioEndpointControl_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointControl", "Reset", 4usize, |this| ((**(this as *const *const IAudioEndpointControl_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointControl", "Stop", 5usize, |this| ((**(this as *const *const IAudioEndpointControl_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Start, Reset, Stop }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioEndpointControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioEndpointRT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentPadding(this: *mut core::ffi::c_void, ppadding: *mut i64, paecurrentposition: *mut AE_CURRENT_POSITION) {
+            windows_core::imp::proxy_call(this, "IAudioEndpointRT", "GetCurrentPadding", 3usize, |this| ((**(this as *const *const IAudioEndpointRT_Vtbl)).GetCurrentPadding)(this, ppadding, paecurrentposition), |_| None)
+        }
+        unsafe extern "system" fn ProcessingComplete(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IAudioEndpointRT", "ProcessingComplete", 4usize, |this| ((**(this as *const *const IAudioEndpointRT_Vtbl)).ProcessingComplete)(this), |_| None)
+        }
+        unsafe extern "system" fn SetPinInactive(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointRT", "SetPinInactive", 5usize, |this| ((**(this as *const *const IAudioEndpointRT_Vtbl)).SetPinInactive)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPinActive(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioEndpointRT", "SetPinActive", 6usize, |this| ((**(this as *const *const IAudioEndpointRT_Vtbl)).SetPinActive)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCurrentPadding,
+            ProcessingComplete,
+            SetPinInactive,
+            SetPinActive,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioEndpointRT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_Audio_Apo")]
+impl windows_core::imp::ProxyVtable for IAudioInputEndpointRT_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Media_Audio_Apo")]
+        unsafe extern "system" fn GetInputDataPointer(this: *mut core::ffi::c_void, pconnectionproperty: *mut super::super::Media::Audio::Apo::APO_CONNECTION_PROPERTY, paetimestamp: *mut AE_CURRENT_POSITION) {
+            windows_core::imp::proxy_call(this, "IAudioInputEndpointRT", "GetInputDataPointer", 3usize, |this| ((**(this as *const *const IAudioInputEndpointRT_Vtbl)).GetInputDataPointer)(this, pconnectionproperty, paetimestamp), |_| None)
+        }
+        unsafe extern "system" fn ReleaseInputDataPointer(this: *mut core::ffi::c_void, u32framecount: u32, pdatapointer: usize) {
+            windows_core::imp::proxy_call(this, "IAudioInputEndpointRT", "ReleaseInputDataPointer", 4usize, |this| ((**(this as *const *const IAudioInputEndpointRT_Vtbl)).ReleaseInputDataPointer)(this, u32framecount, pdatapointer), |_| None)
+        }
+        unsafe extern "system" fn PulseEndpoint(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IAudioInputEndpointRT", "PulseEndpoint", 5usize, |this| ((**(this as *const *const IAudioInputEndpointRT_Vtbl)).PulseEndpoint)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Media_Audio_Apo")]
+            GetInputDataPointer,
+            #[cfg(not(feature = "Win32_Media_Audio_Apo"))]
+            GetInputDataPointer: 0,
+            ReleaseInputDataPointer,
+            PulseEndpoint,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioInputEndpointRT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Media_Audio_Apo")]
+impl windows_core::imp::ProxyVtable for IAudioOutputEndpointRT_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOutputDataPointer(this: *mut core::ffi::c_void, u32framecount: u32, paetimestamp: *const AE_CURRENT_POSITION) -> usize {
+            windows_core::imp::proxy_call(this, "IAudioOutputEndpointRT", "GetOutputDataPointer", 3usize, |this| ((**(this as *const *const IAudioOutputEndpointRT_Vtbl)).GetOutputDataPointer)(this, u32framecount, paetimestamp), |_| None)
+        }
+        #[cfg(feature = "Win32_Media_Audio_Apo")]
+        unsafe extern "system" fn ReleaseOutputDataPointer(this: *mut core::ffi::c_void, pconnectionproperty: *const super::super::Media::Audio::Apo::APO_CONNECTION_PROPERTY) {
+            windows_core::imp::proxy_call(this, "IAudioOutputEndpointRT", "ReleaseOutputDataPointer", 4usize, |this| ((**(this as *const *const IAudioOutputEndpointRT_Vtbl)).ReleaseOutputDataPointer)(this, pconnectionproperty), |_| None)
+        }
+        unsafe extern "system" fn PulseEndpoint(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IAudioOutputEndpointRT", "PulseEndpoint", 5usize, |this| ((**(this as *const *const IAudioOutputEndpointRT_Vtbl)).PulseEndpoint)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetOutputDataPointer,
+            #[cfg(feature = "Win32_Media_Audio_Apo")]
+            ReleaseOutputDataPointer,
+            #[cfg(not(feature = "Win32_Media_Audio_Apo"))]
+            ReleaseOutputDataPointer: 0,
+            PulseEndpoint,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioOutputEndpointRT as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRemoteDesktopClient_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Connect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopClient", "Connect", 7usize, |this| ((**(this as *const *const IRemoteDesktopClient_Vtbl)).Connect)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopClient", "Disconnect", 8usize, |this| ((**(this as *const *const IRemoteDesktopClient_Vtbl)).Disconnect)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reconnect(this: *mut core::ffi::c_void, width: u32, height: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopClient", "Reconnect", 9usize, |this| ((**(this as *const *const IRemoteDesktopClient_Vtbl)).Reconnect)(this, width, height), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Settings(this: *mut core::ffi::c_void, settings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopClient", "Settings", 10usize, |this| ((**(this as *const *const IRemoteDesktopClient_Vtbl)).Settings)(this, settings), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Actions(this: *mut core::ffi::c_void, actions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopClient", "Actions", 11usize, |this| ((**(this as *const *const IRemoteDesktopClient_Vtbl)).Actions)(this, actions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn TouchPointer(this: *mut core::ffi::c_void, touchpointer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopClient", "TouchPointer", 12usize, |this| ((**(this as *const *const IRemoteDesktopClient_Vtbl)).TouchPointer)(this, touchpointer), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteSavedCredentials(this: *mut core::ffi::c_void, servername: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopClient", "DeleteSavedCredentials", 13usize, |this| ((**(this as *const *const IRemoteDesktopClient_Vtbl)).DeleteSavedCredentials)(this, servername), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateSessionDisplaySettings(this: *mut core::ffi::c_void, width: u32, height: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopClient", "UpdateSessionDisplaySettings", 14usize, |this| ((**(this as *const *const IRemoteDesktopClient_Vtbl)).UpdateSessionDisplaySettings)(this, width, height), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn attachEvent(this: *mut core::ffi::c_void, eventname: core::mem::MaybeUninit<windows_core::BSTR>, callback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopClient", "attachEvent", 15usize, |this| ((**(this as *const *const IRemoteDesktopClient_Vtbl)).attachEvent)(this, eventname, callback), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn detachEvent(this: *mut core::ffi::c_void, eventname: core::mem::MaybeUninit<windows_core::BSTR>, callback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopClient", "detachEvent", 16usize, |this| ((**(this as *const *const IRemoteDesktopClient_Vtbl)).detachEvent)(this, eventname, callback), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Connect,
+            Disconnect,
+            Reconnect,
+            #[cfg(feature = "Win32_System_Com")]
+            Settings,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Settings: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Actions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Actions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            TouchPointer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            TouchPointer: 0,
+            DeleteSavedCredentials,
+            UpdateSessionDisplaySettings,
+            #[cfg(feature = "Win32_System_Com")]
+            attachEvent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            attachEvent: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            detachEvent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            detachEvent: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteDesktopClient as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRemoteDesktopClientActions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SuspendScreenUpdates(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopClientActions", "SuspendScreenUpdates", 7usize, |this| ((**(this as *const *const IRemoteDesktopClientActions_Vtbl)).SuspendScreenUpdates)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResumeScreenUpdates(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopClientActions", "ResumeScreenUpdates", 8usize, |this| ((**(this as *const *const IRemoteDesktopClientActions_Vtbl)).ResumeScreenUpdates)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExecuteRemoteAction(this: *mut core::ffi::c_void, remoteaction: RemoteActionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopClientActions", "ExecuteRemoteAction", 9usize, |this| ((**(this as *const *const IRemoteDesktopClientActions_Vtbl)).ExecuteRemoteAction)(this, remoteaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSnapshot(this: *mut core::ffi::c_void, snapshotencoding: SnapshotEncodingType, snapshotformat: SnapshotFormatType, snapshotwidth: u32, snapshotheight: u32, snapshotdata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopClientActions", "GetSnapshot", 10usize, |this| ((**(this as *const *const IRemoteDesktopClientActions_Vtbl)).GetSnapshot)(this, snapshotencoding, snapshotformat, snapshotwidth, snapshotheight, snapshotdata), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SuspendScreenUpdates,
+            ResumeScreenUpdates,
+            ExecuteRemoteAction,
+            GetSnapshot,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteDesktopClientActions as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRemoteDesktopClientSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ApplySettings(this: *mut core::ffi::c_void, rdpfilecontents: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopClientSettings", "ApplySettings", 7usize, |this| ((**(this as *const *const IRemoteDesktopClientSettings_Vtbl)).ApplySettings)(this, rdpfilecontents), |result| Some(*result))
+        }
+        unsafe extern "system" fn RetrieveSettings(this: *mut core::ffi::c_void, rdpfilecontents: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopClientSettings", "RetrieveSettings", 8usize, |this| ((**(this as *const *const IRemoteDesktopClientSettings_Vtbl)).RetrieveSettings)(this, rdpfilecontents), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRdpProperty(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::BSTR>, value: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopClientSettings", "GetRdpProperty", 9usize, |this| ((**(this as *const *const IRemoteDesktopClientSettings_Vtbl)).GetRdpProperty)(this, propertyname, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRdpProperty(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::BSTR>, value: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopClientSettings", "SetRdpProperty", 10usize, |this| ((**(this as *const *const IRemoteDesktopClientSettings_Vtbl)).SetRdpProperty)(this, propertyname, value), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ApplySettings, RetrieveSettings, GetRdpProperty, SetRdpProperty }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteDesktopClientSettings as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRemoteDesktopClientTouchPointer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, enabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopClientTouchPointer", "SetEnabled", 7usize, |this| ((**(this as *const *const IRemoteDesktopClientTouchPointer_Vtbl)).SetEnabled)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, enabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopClientTouchPointer", "Enabled", 8usize, |this| ((**(this as *const *const IRemoteDesktopClientTouchPointer_Vtbl)).Enabled)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventsEnabled(this: *mut core::ffi::c_void, eventsenabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopClientTouchPointer", "SetEventsEnabled", 9usize, |this| ((**(this as *const *const IRemoteDesktopClientTouchPointer_Vtbl)).SetEventsEnabled)(this, eventsenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn EventsEnabled(this: *mut core::ffi::c_void, eventsenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopClientTouchPointer", "EventsEnabled", 10usize, |this| ((**(this as *const *const IRemoteDesktopClientTouchPointer_Vtbl)).EventsEnabled)(this, eventsenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPointerSpeed(this: *mut core::ffi::c_void, pointerspeed: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopClientTouchPointer", "SetPointerSpeed", 11usize, |this| ((**(this as *const *const IRemoteDesktopClientTouchPointer_Vtbl)).SetPointerSpeed)(this, pointerspeed), |result| Some(*result))
+        }
+        unsafe extern "system" fn PointerSpeed(this: *mut core::ffi::c_void, pointerspeed: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteDesktopClientTouchPointer", "PointerSpeed", 12usize, |this| ((**(this as *const *const IRemoteDesktopClientTouchPointer_Vtbl)).PointerSpeed)(this, pointerspeed), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetEnabled,
+            Enabled,
+            SetEventsEnabled,
+            EventsEnabled,
+            SetPointerSpeed,
+            PointerSpeed,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteDesktopClientTouchPointer as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRemoteSystemAdditionalInfoProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAdditionalInfo(this: *mut core::ffi::c_void, deduplicationid: *mut core::mem::MaybeUninit<windows_core::HSTRING>, riid: *const windows_core::GUID, mapview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteSystemAdditionalInfoProvider", "GetAdditionalInfo", 3usize, |this| ((**(this as *const *const IRemoteSystemAdditionalInfoProvider_Vtbl)).GetAdditionalInfo)(this, deduplicationid, riid, mapview), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAdditionalInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteSystemAdditionalInfoProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITSGAccountingEngine_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DoAccounting(this: *mut core::ffi::c_void, accountingdatatype: AAAccountingDataType, accountingdata: AAAccountingData) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSGAccountingEngine", "DoAccounting", 3usize, |this| ((**(this as *const *const ITSGAccountingEngine_Vtbl)).DoAccounting)(this, accountingdatatype, accountingdata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DoAccounting }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITSGAccountingEngine as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITSGAuthenticateUserSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnUserAuthenticated(this: *mut core::ffi::c_void, username: core::mem::MaybeUninit<windows_core::BSTR>, userdomain: core::mem::MaybeUninit<windows_core::BSTR>, context: usize, usertoken: super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSGAuthenticateUserSink", "OnUserAuthenticated", 3usize, |this| ((**(this as *const *const ITSGAuthenticateUserSink_Vtbl)).OnUserAuthenticated)(this, username, userdomain, context, usertoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnUserAuthenticationFailed(this: *mut core::ffi::c_void, context: usize, genericerrorcode: windows_core::HRESULT, specificerrorcode: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSGAuthenticateUserSink", "OnUserAuthenticationFailed", 4usize, |this| ((**(this as *const *const ITSGAuthenticateUserSink_Vtbl)).OnUserAuthenticationFailed)(this, context, genericerrorcode, specificerrorcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReauthenticateUser(this: *mut core::ffi::c_void, context: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSGAuthenticateUserSink", "ReauthenticateUser", 5usize, |this| ((**(this as *const *const ITSGAuthenticateUserSink_Vtbl)).ReauthenticateUser)(this, context), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisconnectUser(this: *mut core::ffi::c_void, context: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSGAuthenticateUserSink", "DisconnectUser", 6usize, |this| ((**(this as *const *const ITSGAuthenticateUserSink_Vtbl)).DisconnectUser)(this, context), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnUserAuthenticated,
+            OnUserAuthenticationFailed,
+            ReauthenticateUser,
+            DisconnectUser,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITSGAuthenticateUserSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITSGAuthenticationEngine_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AuthenticateUser(this: *mut core::ffi::c_void, mainsessionid: windows_core::GUID, cookiedata: *const u8, numcookiebytes: u32, context: usize, psink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSGAuthenticationEngine", "AuthenticateUser", 3usize, |this| ((**(this as *const *const ITSGAuthenticationEngine_Vtbl)).AuthenticateUser)(this, mainsessionid, cookiedata, numcookiebytes, context, psink), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelAuthentication(this: *mut core::ffi::c_void, mainsessionid: windows_core::GUID, context: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSGAuthenticationEngine", "CancelAuthentication", 4usize, |this| ((**(this as *const *const ITSGAuthenticationEngine_Vtbl)).CancelAuthentication)(this, mainsessionid, context), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AuthenticateUser, CancelAuthentication }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITSGAuthenticationEngine as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITSGAuthorizeConnectionSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnConnectionAuthorized(this: *mut core::ffi::c_void, hrin: windows_core::HRESULT, mainsessionid: windows_core::GUID, cbsohresponse: u32, pbsohresponse: *const u8, idletimeout: u32, sessiontimeout: u32, sessiontimeoutaction: SESSION_TIMEOUT_ACTION_TYPE, trustclass: AATrustClassID, policyattributes: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSGAuthorizeConnectionSink", "OnConnectionAuthorized", 3usize, |this| ((**(this as *const *const ITSGAuthorizeConnectionSink_Vtbl)).OnConnectionAuthorized)(this, hrin, mainsessionid, cbsohresponse, pbsohresponse, idletimeout, sessiontimeout, sessiontimeoutaction, trustclass, policyattributes), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnConnectionAuthorized }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITSGAuthorizeConnectionSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITSGAuthorizeResourceSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnChannelAuthorized(this: *mut core::ffi::c_void, hrin: windows_core::HRESULT, mainsessionid: windows_core::GUID, subsessionid: i32, allowedresourcenames: *const core::mem::MaybeUninit<windows_core::BSTR>, numallowedresourcenames: u32, failedresourcenames: *const core::mem::MaybeUninit<windows_core::BSTR>, numfailedresourcenames: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSGAuthorizeResourceSink", "OnChannelAuthorized", 3usize, |this| ((**(this as *const *const ITSGAuthorizeResourceSink_Vtbl)).OnChannelAuthorized)(this, hrin, mainsessionid, subsessionid, allowedresourcenames, numallowedresourcenames, failedresourcenames, numfailedresourcenames), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnChannelAuthorized }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITSGAuthorizeResourceSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITSGPolicyEngine_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AuthorizeConnection(this: *mut core::ffi::c_void, mainsessionid: windows_core::GUID, username: core::mem::MaybeUninit<windows_core::BSTR>, authtype: AAAuthSchemes, clientmachineip: core::mem::MaybeUninit<windows_core::BSTR>, clientmachinename: core::mem::MaybeUninit<windows_core::BSTR>, sohdata: *const u8, numsohbytes: u32, cookiedata: *const u8, numcookiebytes: u32, usertoken: super::super::Foundation::HANDLE_PTR, psink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSGPolicyEngine", "AuthorizeConnection", 3usize, |this| ((**(this as *const *const ITSGPolicyEngine_Vtbl)).AuthorizeConnection)(this, mainsessionid, username, authtype, clientmachineip, clientmachinename, sohdata, numsohbytes, cookiedata, numcookiebytes, usertoken, psink), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthorizeResource(this: *mut core::ffi::c_void, mainsessionid: windows_core::GUID, subsessionid: i32, username: core::mem::MaybeUninit<windows_core::BSTR>, resourcenames: *const core::mem::MaybeUninit<windows_core::BSTR>, numresources: u32, alternateresourcenames: *const core::mem::MaybeUninit<windows_core::BSTR>, numalternateresourcename: u32, portnumber: u32, operation: core::mem::MaybeUninit<windows_core::BSTR>, cookie: *const u8, numbytesincookie: u32, psink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSGPolicyEngine", "AuthorizeResource", 4usize, |this| ((**(this as *const *const ITSGPolicyEngine_Vtbl)).AuthorizeResource)(this, mainsessionid, subsessionid, username, resourcenames, numresources, alternateresourcenames, numalternateresourcename, portnumber, operation, cookie, numbytesincookie, psink), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSGPolicyEngine", "Refresh", 5usize, |this| ((**(this as *const *const ITSGPolicyEngine_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsQuarantineEnabled(this: *mut core::ffi::c_void, quarantineenabled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITSGPolicyEngine", "IsQuarantineEnabled", 6usize, |this| ((**(this as *const *const ITSGPolicyEngine_Vtbl)).IsQuarantineEnabled)(this, quarantineenabled), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AuthorizeConnection,
+            AuthorizeResource,
+            Refresh,
+            IsQuarantineEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITSGPolicyEngine as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITsSbBaseNotifySink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnError(this: *mut core::ffi::c_void, hrerror: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbBaseNotifySink", "OnError", 3usize, |this| ((**(this as *const *const ITsSbBaseNotifySink_Vtbl)).OnError)(this, hrerror), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnReportStatus(this: *mut core::ffi::c_void, messagetype: CLIENT_MESSAGE_TYPE, messageid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbBaseNotifySink", "OnReportStatus", 4usize, |this| ((**(this as *const *const ITsSbBaseNotifySink_Vtbl)).OnReportStatus)(this, messagetype, messageid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnError, OnReportStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbBaseNotifySink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for ITsSbClientConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UserName(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbClientConnection", "UserName", 3usize, |this| ((**(this as *const *const ITsSbClientConnection_Vtbl)).UserName)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Domain(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbClientConnection", "Domain", 4usize, |this| ((**(this as *const *const ITsSbClientConnection_Vtbl)).Domain)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitialProgram(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbClientConnection", "InitialProgram", 5usize, |this| ((**(this as *const *const ITsSbClientConnection_Vtbl)).InitialProgram)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadBalanceResult(this: *mut core::ffi::c_void, ppval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbClientConnection", "LoadBalanceResult", 6usize, |this| ((**(this as *const *const ITsSbClientConnection_Vtbl)).LoadBalanceResult)(this, ppval), |result| Some(*result))
+        }
+        unsafe extern "system" fn FarmName(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbClientConnection", "FarmName", 7usize, |this| ((**(this as *const *const ITsSbClientConnection_Vtbl)).FarmName)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutContext(this: *mut core::ffi::c_void, contextid: core::mem::MaybeUninit<windows_core::BSTR>, context: core::mem::MaybeUninit<windows_core::VARIANT>, existingcontext: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbClientConnection", "PutContext", 8usize, |this| ((**(this as *const *const ITsSbClientConnection_Vtbl)).PutContext)(this, contextid, context, existingcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContext(this: *mut core::ffi::c_void, contextid: core::mem::MaybeUninit<windows_core::BSTR>, context: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbClientConnection", "GetContext", 9usize, |this| ((**(this as *const *const ITsSbClientConnection_Vtbl)).GetContext)(this, contextid, context), |result| Some(*result))
+        }
+        unsafe extern "system" fn Environment(this: *mut core::ffi::c_void, ppenvironment: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbClientConnection", "Environment", 10usize, |this| ((**(this as *const *const ITsSbClientConnection_Vtbl)).Environment)(this, ppenvironment), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_ConnectionError(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbClientConnection", "get_ConnectionError", 11usize, |this| ((**(this as *const *const ITsSbClientConnection_Vtbl)).get_ConnectionError)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SamUserAccount(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbClientConnection", "SamUserAccount", 12usize, |this| ((**(this as *const *const ITsSbClientConnection_Vtbl)).SamUserAccount)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn ClientConnectionPropertySet(this: *mut core::ffi::c_void, pppropertyset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbClientConnection", "ClientConnectionPropertySet", 13usize, |this| ((**(this as *const *const ITsSbClientConnection_Vtbl)).ClientConnectionPropertySet)(this, pppropertyset), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsFirstAssignment(this: *mut core::ffi::c_void, ppval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbClientConnection", "IsFirstAssignment", 14usize, |this| ((**(this as *const *const ITsSbClientConnection_Vtbl)).IsFirstAssignment)(this, ppval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RdFarmType(this: *mut core::ffi::c_void, prdfarmtype: *mut RD_FARM_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbClientConnection", "RdFarmType", 15usize, |this| ((**(this as *const *const ITsSbClientConnection_Vtbl)).RdFarmType)(this, prdfarmtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserSidString(this: *mut core::ffi::c_void, pszusersidstring: *mut *mut i8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbClientConnection", "UserSidString", 16usize, |this| ((**(this as *const *const ITsSbClientConnection_Vtbl)).UserSidString)(this, pszusersidstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisconnectedSession(this: *mut core::ffi::c_void, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbClientConnection", "GetDisconnectedSession", 17usize, |this| ((**(this as *const *const ITsSbClientConnection_Vtbl)).GetDisconnectedSession)(this, ppsession), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            UserName,
+            Domain,
+            InitialProgram,
+            LoadBalanceResult,
+            FarmName,
+            PutContext,
+            GetContext,
+            Environment,
+            get_ConnectionError,
+            SamUserAccount,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            ClientConnectionPropertySet,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            ClientConnectionPropertySet: 0,
+            IsFirstAssignment,
+            RdFarmType,
+            UserSidString,
+            GetDisconnectedSession,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbClientConnection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for ITsSbClientConnectionPropertySet_Vtbl {
+    const PROXY: Self = { Self { base__: <ITsSbPropertySet_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbClientConnectionPropertySet as windows_core::Interface>::IID || <ITsSbPropertySet_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for ITsSbEnvironment_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbEnvironment", "Name", 3usize, |this| ((**(this as *const *const ITsSbEnvironment_Vtbl)).Name)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServerWeight(this: *mut core::ffi::c_void, pval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbEnvironment", "ServerWeight", 4usize, |this| ((**(this as *const *const ITsSbEnvironment_Vtbl)).ServerWeight)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn EnvironmentPropertySet(this: *mut core::ffi::c_void, pppropertyset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbEnvironment", "EnvironmentPropertySet", 5usize, |this| ((**(this as *const *const ITsSbEnvironment_Vtbl)).EnvironmentPropertySet)(this, pppropertyset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn SetEnvironmentPropertySet(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbEnvironment", "SetEnvironmentPropertySet", 6usize, |this| ((**(this as *const *const ITsSbEnvironment_Vtbl)).SetEnvironmentPropertySet)(this, pval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            ServerWeight,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            EnvironmentPropertySet,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            EnvironmentPropertySet: 0,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            SetEnvironmentPropertySet,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            SetEnvironmentPropertySet: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbEnvironment as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for ITsSbEnvironmentPropertySet_Vtbl {
+    const PROXY: Self = { Self { base__: <ITsSbPropertySet_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbEnvironmentPropertySet as windows_core::Interface>::IID || <ITsSbPropertySet_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for ITsSbFilterPluginStore_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn SaveProperties(this: *mut core::ffi::c_void, ppropertyset: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbFilterPluginStore", "SaveProperties", 3usize, |this| ((**(this as *const *const ITsSbFilterPluginStore_Vtbl)).SaveProperties)(this, ppropertyset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn EnumerateProperties(this: *mut core::ffi::c_void, pppropertyset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbFilterPluginStore", "EnumerateProperties", 4usize, |this| ((**(this as *const *const ITsSbFilterPluginStore_Vtbl)).EnumerateProperties)(this, pppropertyset), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteProperties(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbFilterPluginStore", "DeleteProperties", 5usize, |this| ((**(this as *const *const ITsSbFilterPluginStore_Vtbl)).DeleteProperties)(this, propertyname), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            SaveProperties,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            SaveProperties: 0,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            EnumerateProperties,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            EnumerateProperties: 0,
+            DeleteProperties,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbFilterPluginStore as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITsSbGenericNotifySink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnCompleted(this: *mut core::ffi::c_void, status: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbGenericNotifySink", "OnCompleted", 3usize, |this| ((**(this as *const *const ITsSbGenericNotifySink_Vtbl)).OnCompleted)(this, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWaitTimeout(this: *mut core::ffi::c_void, pfttimeout: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbGenericNotifySink", "GetWaitTimeout", 4usize, |this| ((**(this as *const *const ITsSbGenericNotifySink_Vtbl)).GetWaitTimeout)(this, pfttimeout), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnCompleted, GetWaitTimeout }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbGenericNotifySink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITsSbGlobalStore_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryTarget(this: *mut core::ffi::c_void, providername: core::mem::MaybeUninit<windows_core::BSTR>, targetname: core::mem::MaybeUninit<windows_core::BSTR>, farmname: core::mem::MaybeUninit<windows_core::BSTR>, pptarget: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbGlobalStore", "QueryTarget", 3usize, |this| ((**(this as *const *const ITsSbGlobalStore_Vtbl)).QueryTarget)(this, providername, targetname, farmname, pptarget), |result| Some(*result))
+        }
+        unsafe extern "system" fn QuerySessionBySessionId(this: *mut core::ffi::c_void, providername: core::mem::MaybeUninit<windows_core::BSTR>, dwsessionid: u32, targetname: core::mem::MaybeUninit<windows_core::BSTR>, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbGlobalStore", "QuerySessionBySessionId", 4usize, |this| ((**(this as *const *const ITsSbGlobalStore_Vtbl)).QuerySessionBySessionId)(this, providername, dwsessionid, targetname, ppsession), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumerateFarms(this: *mut core::ffi::c_void, providername: core::mem::MaybeUninit<windows_core::BSTR>, pdwcount: *mut u32, pval: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbGlobalStore", "EnumerateFarms", 5usize, |this| ((**(this as *const *const ITsSbGlobalStore_Vtbl)).EnumerateFarms)(this, providername, pdwcount, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateTargets(this: *mut core::ffi::c_void, providername: core::mem::MaybeUninit<windows_core::BSTR>, farmname: core::mem::MaybeUninit<windows_core::BSTR>, envname: core::mem::MaybeUninit<windows_core::BSTR>, pdwcount: *mut u32, pval: *mut *mut Option<ITsSbTarget>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbGlobalStore", "EnumerateTargets", 6usize, |this| ((**(this as *const *const ITsSbGlobalStore_Vtbl)).EnumerateTargets)(this, providername, farmname, envname, pdwcount, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateEnvironmentsByProvider(this: *mut core::ffi::c_void, providername: core::mem::MaybeUninit<windows_core::BSTR>, pdwcount: *mut u32, ppval: *mut *mut Option<ITsSbEnvironment>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbGlobalStore", "EnumerateEnvironmentsByProvider", 7usize, |this| ((**(this as *const *const ITsSbGlobalStore_Vtbl)).EnumerateEnvironmentsByProvider)(this, providername, pdwcount, ppval), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateSessions(this: *mut core::ffi::c_void, providername: core::mem::MaybeUninit<windows_core::BSTR>, targetname: core::mem::MaybeUninit<windows_core::BSTR>, username: core::mem::MaybeUninit<windows_core::BSTR>, userdomain: core::mem::MaybeUninit<windows_core::BSTR>, poolname: core::mem::MaybeUninit<windows_core::BSTR>, initialprogram: core::mem::MaybeUninit<windows_core::BSTR>, psessionstate: *const TSSESSION_STATE, pdwcount: *mut u32, ppval: *mut *mut Option<ITsSbSession>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbGlobalStore", "EnumerateSessions", 8usize, |this| ((**(this as *const *const ITsSbGlobalStore_Vtbl)).EnumerateSessions)(this, providername, targetname, username, userdomain, poolname, initialprogram, psessionstate, pdwcount, ppval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFarmProperty(this: *mut core::ffi::c_void, farmname: core::mem::MaybeUninit<windows_core::BSTR>, propertyname: core::mem::MaybeUninit<windows_core::BSTR>, pvarvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbGlobalStore", "GetFarmProperty", 9usize, |this| ((**(this as *const *const ITsSbGlobalStore_Vtbl)).GetFarmProperty)(this, farmname, propertyname, pvarvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            QueryTarget,
+            QuerySessionBySessionId,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumerateFarms,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumerateFarms: 0,
+            EnumerateTargets,
+            EnumerateEnvironmentsByProvider,
+            EnumerateSessions,
+            GetFarmProperty,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbGlobalStore as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITsSbLoadBalanceResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TargetName(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbLoadBalanceResult", "TargetName", 3usize, |this| ((**(this as *const *const ITsSbLoadBalanceResult_Vtbl)).TargetName)(this, pval), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TargetName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbLoadBalanceResult as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for ITsSbLoadBalancing_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMostSuitableTarget(this: *mut core::ffi::c_void, pconnection: *mut core::ffi::c_void, plbsink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbLoadBalancing", "GetMostSuitableTarget", 5usize, |this| ((**(this as *const *const ITsSbLoadBalancing_Vtbl)).GetMostSuitableTarget)(this, pconnection, plbsink), |result| Some(*result))
+        }
+        Self { base__: <ITsSbPlugin_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMostSuitableTarget }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbLoadBalancing as windows_core::Interface>::IID || <ITsSbPlugin_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITsSbLoadBalancingNotifySink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnGetMostSuitableTarget(this: *mut core::ffi::c_void, plbresult: *mut core::ffi::c_void, fisnewconnection: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbLoadBalancingNotifySink", "OnGetMostSuitableTarget", 5usize, |this| ((**(this as *const *const ITsSbLoadBalancingNotifySink_Vtbl)).OnGetMostSuitableTarget)(this, plbresult, fisnewconnection), |result| Some(*result))
+        }
+        Self { base__: <ITsSbBaseNotifySink_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnGetMostSuitableTarget }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbLoadBalancingNotifySink as windows_core::Interface>::IID || <ITsSbBaseNotifySink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for ITsSbOrchestration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrepareTargetForConnect(this: *mut core::ffi::c_void, pconnection: *mut core::ffi::c_void, porchestrationnotifysink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbOrchestration", "PrepareTargetForConnect", 5usize, |this| ((**(this as *const *const ITsSbOrchestration_Vtbl)).PrepareTargetForConnect)(this, pconnection, porchestrationnotifysink), |result| Some(*result))
+        }
+        Self { base__: <ITsSbPlugin_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PrepareTargetForConnect }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbOrchestration as windows_core::Interface>::IID || <ITsSbPlugin_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITsSbOrchestrationNotifySink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnReadyToConnect(this: *mut core::ffi::c_void, ptarget: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbOrchestrationNotifySink", "OnReadyToConnect", 5usize, |this| ((**(this as *const *const ITsSbOrchestrationNotifySink_Vtbl)).OnReadyToConnect)(this, ptarget), |result| Some(*result))
+        }
+        Self { base__: <ITsSbBaseNotifySink_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnReadyToConnect }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbOrchestrationNotifySink as windows_core::Interface>::IID || <ITsSbBaseNotifySink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for ITsSbPlacement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryEnvironmentForTarget(this: *mut core::ffi::c_void, pconnection: *mut core::ffi::c_void, pplacementsink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbPlacement", "QueryEnvironmentForTarget", 5usize, |this| ((**(this as *const *const ITsSbPlacement_Vtbl)).QueryEnvironmentForTarget)(this, pconnection, pplacementsink), |result| Some(*result))
+        }
+        Self { base__: <ITsSbPlugin_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryEnvironmentForTarget }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbPlacement as windows_core::Interface>::IID || <ITsSbPlugin_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITsSbPlacementNotifySink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnQueryEnvironmentCompleted(this: *mut core::ffi::c_void, penvironment: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbPlacementNotifySink", "OnQueryEnvironmentCompleted", 5usize, |this| ((**(this as *const *const ITsSbPlacementNotifySink_Vtbl)).OnQueryEnvironmentCompleted)(this, penvironment), |result| Some(*result))
+        }
+        Self { base__: <ITsSbBaseNotifySink_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnQueryEnvironmentCompleted }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbPlacementNotifySink as windows_core::Interface>::IID || <ITsSbBaseNotifySink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for ITsSbPlugin_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pprovider: *mut core::ffi::c_void, pnotifysink: *mut core::ffi::c_void, ppropertyset: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbPlugin", "Initialize", 3usize, |this| ((**(this as *const *const ITsSbPlugin_Vtbl)).Initialize)(this, pprovider, pnotifysink, ppropertyset), |result| Some(*result))
+        }
+        unsafe extern "system" fn Terminate(this: *mut core::ffi::c_void, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbPlugin", "Terminate", 4usize, |this| ((**(this as *const *const ITsSbPlugin_Vtbl)).Terminate)(this, hr), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            Initialize,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            Initialize: 0,
+            Terminate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbPlugin as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITsSbPluginNotifySink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnInitialized(this: *mut core::ffi::c_void, hr: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbPluginNotifySink", "OnInitialized", 5usize, |this| ((**(this as *const *const ITsSbPluginNotifySink_Vtbl)).OnInitialized)(this, hr), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnTerminated(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbPluginNotifySink", "OnTerminated", 6usize, |this| ((**(this as *const *const ITsSbPluginNotifySink_Vtbl)).OnTerminated)(this), |result| Some(*result))
+        }
+        Self { base__: <ITsSbBaseNotifySink_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnInitialized, OnTerminated }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbPluginNotifySink as windows_core::Interface>::IID || <ITsSbBaseNotifySink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for ITsSbPluginPropertySet_Vtbl {
+    const PROXY: Self = { Self { base__: <ITsSbPropertySet_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbPluginPropertySet as windows_core::Interface>::IID || <ITsSbPropertySet_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for ITsSbPropertySet_Vtbl {
+    const PROXY: Self = { Self { base__: <super::Com::StructuredStorage::IPropertyBag_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbPropertySet as windows_core::Interface>::IID || <super::Com::StructuredStorage::IPropertyBag_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for ITsSbProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateTargetObject(this: *mut core::ffi::c_void, targetname: core::mem::MaybeUninit<windows_core::BSTR>, environmentname: core::mem::MaybeUninit<windows_core::BSTR>, pptarget: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbProvider", "CreateTargetObject", 3usize, |this| ((**(this as *const *const ITsSbProvider_Vtbl)).CreateTargetObject)(this, targetname, environmentname, pptarget), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateLoadBalanceResultObject(this: *mut core::ffi::c_void, targetname: core::mem::MaybeUninit<windows_core::BSTR>, pplbresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbProvider", "CreateLoadBalanceResultObject", 4usize, |this| ((**(this as *const *const ITsSbProvider_Vtbl)).CreateLoadBalanceResultObject)(this, targetname, pplbresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSessionObject(this: *mut core::ffi::c_void, targetname: core::mem::MaybeUninit<windows_core::BSTR>, username: core::mem::MaybeUninit<windows_core::BSTR>, domain: core::mem::MaybeUninit<windows_core::BSTR>, sessionid: u32, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbProvider", "CreateSessionObject", 5usize, |this| ((**(this as *const *const ITsSbProvider_Vtbl)).CreateSessionObject)(this, targetname, username, domain, sessionid, ppsession), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn CreatePluginPropertySet(this: *mut core::ffi::c_void, pppropertyset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbProvider", "CreatePluginPropertySet", 6usize, |this| ((**(this as *const *const ITsSbProvider_Vtbl)).CreatePluginPropertySet)(this, pppropertyset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn CreateTargetPropertySetObject(this: *mut core::ffi::c_void, pppropertyset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbProvider", "CreateTargetPropertySetObject", 7usize, |this| ((**(this as *const *const ITsSbProvider_Vtbl)).CreateTargetPropertySetObject)(this, pppropertyset), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateEnvironmentObject(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, serverweight: u32, ppenvironment: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbProvider", "CreateEnvironmentObject", 8usize, |this| ((**(this as *const *const ITsSbProvider_Vtbl)).CreateEnvironmentObject)(this, name, serverweight, ppenvironment), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResourcePluginStore(this: *mut core::ffi::c_void, ppstore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbProvider", "GetResourcePluginStore", 9usize, |this| ((**(this as *const *const ITsSbProvider_Vtbl)).GetResourcePluginStore)(this, ppstore), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFilterPluginStore(this: *mut core::ffi::c_void, ppstore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbProvider", "GetFilterPluginStore", 10usize, |this| ((**(this as *const *const ITsSbProvider_Vtbl)).GetFilterPluginStore)(this, ppstore), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterForNotification(this: *mut core::ffi::c_void, notificationtype: u32, resourcetomonitor: core::mem::MaybeUninit<windows_core::BSTR>, ppluginnotification: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbProvider", "RegisterForNotification", 11usize, |this| ((**(this as *const *const ITsSbProvider_Vtbl)).RegisterForNotification)(this, notificationtype, resourcetomonitor, ppluginnotification), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnRegisterForNotification(this: *mut core::ffi::c_void, notificationtype: u32, resourcetomonitor: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbProvider", "UnRegisterForNotification", 12usize, |this| ((**(this as *const *const ITsSbProvider_Vtbl)).UnRegisterForNotification)(this, notificationtype, resourcetomonitor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInstanceOfGlobalStore(this: *mut core::ffi::c_void, ppglobalstore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbProvider", "GetInstanceOfGlobalStore", 13usize, |this| ((**(this as *const *const ITsSbProvider_Vtbl)).GetInstanceOfGlobalStore)(this, ppglobalstore), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn CreateEnvironmentPropertySetObject(this: *mut core::ffi::c_void, pppropertyset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbProvider", "CreateEnvironmentPropertySetObject", 14usize, |this| ((**(this as *const *const ITsSbProvider_Vtbl)).CreateEnvironmentPropertySetObject)(this, pppropertyset), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateTargetObject,
+            CreateLoadBalanceResultObject,
+            CreateSessionObject,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            CreatePluginPropertySet,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            CreatePluginPropertySet: 0,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            CreateTargetPropertySetObject,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            CreateTargetPropertySetObject: 0,
+            CreateEnvironmentObject,
+            GetResourcePluginStore,
+            GetFilterPluginStore,
+            RegisterForNotification,
+            UnRegisterForNotification,
+            GetInstanceOfGlobalStore,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            CreateEnvironmentPropertySetObject,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            CreateEnvironmentPropertySetObject: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for ITsSbProvisioning_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateVirtualMachines(this: *mut core::ffi::c_void, jobxmlstring: core::mem::MaybeUninit<windows_core::BSTR>, jobguid: core::mem::MaybeUninit<windows_core::BSTR>, psink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbProvisioning", "CreateVirtualMachines", 5usize, |this| ((**(this as *const *const ITsSbProvisioning_Vtbl)).CreateVirtualMachines)(this, jobxmlstring, jobguid, psink), |result| Some(*result))
+        }
+        unsafe extern "system" fn PatchVirtualMachines(this: *mut core::ffi::c_void, jobxmlstring: core::mem::MaybeUninit<windows_core::BSTR>, jobguid: core::mem::MaybeUninit<windows_core::BSTR>, psink: *mut core::ffi::c_void, pvmpatchinfo: *const VM_PATCH_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbProvisioning", "PatchVirtualMachines", 6usize, |this| ((**(this as *const *const ITsSbProvisioning_Vtbl)).PatchVirtualMachines)(this, jobxmlstring, jobguid, psink, pvmpatchinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteVirtualMachines(this: *mut core::ffi::c_void, jobxmlstring: core::mem::MaybeUninit<windows_core::BSTR>, jobguid: core::mem::MaybeUninit<windows_core::BSTR>, psink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbProvisioning", "DeleteVirtualMachines", 7usize, |this| ((**(this as *const *const ITsSbProvisioning_Vtbl)).DeleteVirtualMachines)(this, jobxmlstring, jobguid, psink), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelJob(this: *mut core::ffi::c_void, jobguid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbProvisioning", "CancelJob", 8usize, |this| ((**(this as *const *const ITsSbProvisioning_Vtbl)).CancelJob)(this, jobguid), |result| Some(*result))
+        }
+        Self {
+            base__: <ITsSbPlugin_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateVirtualMachines,
+            PatchVirtualMachines,
+            DeleteVirtualMachines,
+            CancelJob,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbProvisioning as windows_core::Interface>::IID || <ITsSbPlugin_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITsSbProvisioningPluginNotifySink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnJobCreated(this: *mut core::ffi::c_void, pvmnotifyinfo: *const VM_NOTIFY_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbProvisioningPluginNotifySink", "OnJobCreated", 3usize, |this| ((**(this as *const *const ITsSbProvisioningPluginNotifySink_Vtbl)).OnJobCreated)(this, pvmnotifyinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnVirtualMachineStatusChanged(this: *mut core::ffi::c_void, pvmnotifyentry: *const VM_NOTIFY_ENTRY, vmnotifystatus: VM_NOTIFY_STATUS, errorcode: windows_core::HRESULT, errordescr: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbProvisioningPluginNotifySink", "OnVirtualMachineStatusChanged", 4usize, |this| ((**(this as *const *const ITsSbProvisioningPluginNotifySink_Vtbl)).OnVirtualMachineStatusChanged)(this, pvmnotifyentry, vmnotifystatus, errorcode, errordescr), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnJobCompleted(this: *mut core::ffi::c_void, resultcode: windows_core::HRESULT, resultdescription: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbProvisioningPluginNotifySink", "OnJobCompleted", 5usize, |this| ((**(this as *const *const ITsSbProvisioningPluginNotifySink_Vtbl)).OnJobCompleted)(this, resultcode, resultdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnJobCancelled(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbProvisioningPluginNotifySink", "OnJobCancelled", 6usize, |this| ((**(this as *const *const ITsSbProvisioningPluginNotifySink_Vtbl)).OnJobCancelled)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockVirtualMachine(this: *mut core::ffi::c_void, pvmnotifyentry: *const VM_NOTIFY_ENTRY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbProvisioningPluginNotifySink", "LockVirtualMachine", 7usize, |this| ((**(this as *const *const ITsSbProvisioningPluginNotifySink_Vtbl)).LockVirtualMachine)(this, pvmnotifyentry), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnVirtualMachineHostStatusChanged(this: *mut core::ffi::c_void, vmhost: core::mem::MaybeUninit<windows_core::BSTR>, vmhostnotifystatus: VM_HOST_NOTIFY_STATUS, errorcode: windows_core::HRESULT, errordescr: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbProvisioningPluginNotifySink", "OnVirtualMachineHostStatusChanged", 8usize, |this| ((**(this as *const *const ITsSbProvisioningPluginNotifySink_Vtbl)).OnVirtualMachineHostStatusChanged)(this, vmhost, vmhostnotifystatus, errorcode, errordescr), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnJobCreated,
+            OnVirtualMachineStatusChanged,
+            OnJobCompleted,
+            OnJobCancelled,
+            LockVirtualMachine,
+            OnVirtualMachineHostStatusChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbProvisioningPluginNotifySink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITsSbResourceNotification_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NotifySessionChange(this: *mut core::ffi::c_void, changetype: TSSESSION_STATE, psession: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourceNotification", "NotifySessionChange", 3usize, |this| ((**(this as *const *const ITsSbResourceNotification_Vtbl)).NotifySessionChange)(this, changetype, psession), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyTargetChange(this: *mut core::ffi::c_void, targetchangetype: u32, ptarget: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourceNotification", "NotifyTargetChange", 4usize, |this| ((**(this as *const *const ITsSbResourceNotification_Vtbl)).NotifyTargetChange)(this, targetchangetype, ptarget), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyClientConnectionStateChange(this: *mut core::ffi::c_void, changetype: CONNECTION_CHANGE_NOTIFICATION, pconnection: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourceNotification", "NotifyClientConnectionStateChange", 5usize, |this| ((**(this as *const *const ITsSbResourceNotification_Vtbl)).NotifyClientConnectionStateChange)(this, changetype, pconnection), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            NotifySessionChange,
+            NotifyTargetChange,
+            NotifyClientConnectionStateChange,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbResourceNotification as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITsSbResourceNotificationEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NotifySessionChangeEx(this: *mut core::ffi::c_void, targetname: core::mem::MaybeUninit<windows_core::BSTR>, username: core::mem::MaybeUninit<windows_core::BSTR>, domain: core::mem::MaybeUninit<windows_core::BSTR>, sessionid: u32, sessionstate: TSSESSION_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourceNotificationEx", "NotifySessionChangeEx", 3usize, |this| ((**(this as *const *const ITsSbResourceNotificationEx_Vtbl)).NotifySessionChangeEx)(this, targetname, username, domain, sessionid, sessionstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyTargetChangeEx(this: *mut core::ffi::c_void, targetname: core::mem::MaybeUninit<windows_core::BSTR>, targetchangetype: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourceNotificationEx", "NotifyTargetChangeEx", 4usize, |this| ((**(this as *const *const ITsSbResourceNotificationEx_Vtbl)).NotifyTargetChangeEx)(this, targetname, targetchangetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyClientConnectionStateChangeEx(this: *mut core::ffi::c_void, username: core::mem::MaybeUninit<windows_core::BSTR>, domain: core::mem::MaybeUninit<windows_core::BSTR>, initialprogram: core::mem::MaybeUninit<windows_core::BSTR>, poolname: core::mem::MaybeUninit<windows_core::BSTR>, targetname: core::mem::MaybeUninit<windows_core::BSTR>, connectionchangetype: CONNECTION_CHANGE_NOTIFICATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourceNotificationEx", "NotifyClientConnectionStateChangeEx", 5usize, |this| ((**(this as *const *const ITsSbResourceNotificationEx_Vtbl)).NotifyClientConnectionStateChangeEx)(this, username, domain, initialprogram, poolname, targetname, connectionchangetype), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            NotifySessionChangeEx,
+            NotifyTargetChangeEx,
+            NotifyClientConnectionStateChangeEx,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbResourceNotificationEx as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for ITsSbResourcePlugin_Vtbl {
+    const PROXY: Self = { Self { base__: <ITsSbPlugin_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbResourcePlugin as windows_core::Interface>::IID || <ITsSbPlugin_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITsSbResourcePluginStore_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryTarget(this: *mut core::ffi::c_void, targetname: core::mem::MaybeUninit<windows_core::BSTR>, farmname: core::mem::MaybeUninit<windows_core::BSTR>, pptarget: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourcePluginStore", "QueryTarget", 3usize, |this| ((**(this as *const *const ITsSbResourcePluginStore_Vtbl)).QueryTarget)(this, targetname, farmname, pptarget), |result| Some(*result))
+        }
+        unsafe extern "system" fn QuerySessionBySessionId(this: *mut core::ffi::c_void, dwsessionid: u32, targetname: core::mem::MaybeUninit<windows_core::BSTR>, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourcePluginStore", "QuerySessionBySessionId", 4usize, |this| ((**(this as *const *const ITsSbResourcePluginStore_Vtbl)).QuerySessionBySessionId)(this, dwsessionid, targetname, ppsession), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTargetToStore(this: *mut core::ffi::c_void, ptarget: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourcePluginStore", "AddTargetToStore", 5usize, |this| ((**(this as *const *const ITsSbResourcePluginStore_Vtbl)).AddTargetToStore)(this, ptarget), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddSessionToStore(this: *mut core::ffi::c_void, psession: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourcePluginStore", "AddSessionToStore", 6usize, |this| ((**(this as *const *const ITsSbResourcePluginStore_Vtbl)).AddSessionToStore)(this, psession), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddEnvironmentToStore(this: *mut core::ffi::c_void, penvironment: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourcePluginStore", "AddEnvironmentToStore", 7usize, |this| ((**(this as *const *const ITsSbResourcePluginStore_Vtbl)).AddEnvironmentToStore)(this, penvironment), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEnvironmentFromStore(this: *mut core::ffi::c_void, environmentname: core::mem::MaybeUninit<windows_core::BSTR>, bignoreowner: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourcePluginStore", "RemoveEnvironmentFromStore", 8usize, |this| ((**(this as *const *const ITsSbResourcePluginStore_Vtbl)).RemoveEnvironmentFromStore)(this, environmentname, bignoreowner), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumerateFarms(this: *mut core::ffi::c_void, pdwcount: *mut u32, pval: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourcePluginStore", "EnumerateFarms", 9usize, |this| ((**(this as *const *const ITsSbResourcePluginStore_Vtbl)).EnumerateFarms)(this, pdwcount, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryEnvironment(this: *mut core::ffi::c_void, environmentname: core::mem::MaybeUninit<windows_core::BSTR>, ppenvironment: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourcePluginStore", "QueryEnvironment", 10usize, |this| ((**(this as *const *const ITsSbResourcePluginStore_Vtbl)).QueryEnvironment)(this, environmentname, ppenvironment), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateEnvironments(this: *mut core::ffi::c_void, pdwcount: *mut u32, pval: *mut *mut Option<ITsSbEnvironment>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourcePluginStore", "EnumerateEnvironments", 11usize, |this| ((**(this as *const *const ITsSbResourcePluginStore_Vtbl)).EnumerateEnvironments)(this, pdwcount, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveTarget(this: *mut core::ffi::c_void, ptarget: *mut core::ffi::c_void, bforcewrite: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourcePluginStore", "SaveTarget", 12usize, |this| ((**(this as *const *const ITsSbResourcePluginStore_Vtbl)).SaveTarget)(this, ptarget, bforcewrite), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveEnvironment(this: *mut core::ffi::c_void, penvironment: *mut core::ffi::c_void, bforcewrite: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourcePluginStore", "SaveEnvironment", 13usize, |this| ((**(this as *const *const ITsSbResourcePluginStore_Vtbl)).SaveEnvironment)(this, penvironment, bforcewrite), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveSession(this: *mut core::ffi::c_void, psession: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourcePluginStore", "SaveSession", 14usize, |this| ((**(this as *const *const ITsSbResourcePluginStore_Vtbl)).SaveSession)(this, psession), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTargetProperty(this: *mut core::ffi::c_void, targetname: core::mem::MaybeUninit<windows_core::BSTR>, propertyname: core::mem::MaybeUninit<windows_core::BSTR>, pproperty: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourcePluginStore", "SetTargetProperty", 15usize, |this| ((**(this as *const *const ITsSbResourcePluginStore_Vtbl)).SetTargetProperty)(this, targetname, propertyname, pproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnvironmentProperty(this: *mut core::ffi::c_void, environmentname: core::mem::MaybeUninit<windows_core::BSTR>, propertyname: core::mem::MaybeUninit<windows_core::BSTR>, pproperty: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourcePluginStore", "SetEnvironmentProperty", 16usize, |this| ((**(this as *const *const ITsSbResourcePluginStore_Vtbl)).SetEnvironmentProperty)(this, environmentname, propertyname, pproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTargetState(this: *mut core::ffi::c_void, targetname: core::mem::MaybeUninit<windows_core::BSTR>, newstate: TARGET_STATE, poldstate: *mut TARGET_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourcePluginStore", "SetTargetState", 17usize, |this| ((**(this as *const *const ITsSbResourcePluginStore_Vtbl)).SetTargetState)(this, targetname, newstate, poldstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSessionState(this: *mut core::ffi::c_void, sbsession: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourcePluginStore", "SetSessionState", 18usize, |this| ((**(this as *const *const ITsSbResourcePluginStore_Vtbl)).SetSessionState)(this, sbsession), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateTargets(this: *mut core::ffi::c_void, farmname: core::mem::MaybeUninit<windows_core::BSTR>, envname: core::mem::MaybeUninit<windows_core::BSTR>, sortbyfieldid: TS_SB_SORT_BY, sortybypropname: core::mem::MaybeUninit<windows_core::BSTR>, pdwcount: *mut u32, pval: *mut *mut Option<ITsSbTarget>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourcePluginStore", "EnumerateTargets", 19usize, |this| ((**(this as *const *const ITsSbResourcePluginStore_Vtbl)).EnumerateTargets)(this, farmname, envname, sortbyfieldid, sortybypropname, pdwcount, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateSessions(this: *mut core::ffi::c_void, targetname: core::mem::MaybeUninit<windows_core::BSTR>, username: core::mem::MaybeUninit<windows_core::BSTR>, userdomain: core::mem::MaybeUninit<windows_core::BSTR>, poolname: core::mem::MaybeUninit<windows_core::BSTR>, initialprogram: core::mem::MaybeUninit<windows_core::BSTR>, psessionstate: *const TSSESSION_STATE, pdwcount: *mut u32, ppval: *mut *mut Option<ITsSbSession>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourcePluginStore", "EnumerateSessions", 20usize, |this| ((**(this as *const *const ITsSbResourcePluginStore_Vtbl)).EnumerateSessions)(this, targetname, username, userdomain, poolname, initialprogram, psessionstate, pdwcount, ppval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFarmProperty(this: *mut core::ffi::c_void, farmname: core::mem::MaybeUninit<windows_core::BSTR>, propertyname: core::mem::MaybeUninit<windows_core::BSTR>, pvarvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourcePluginStore", "GetFarmProperty", 21usize, |this| ((**(this as *const *const ITsSbResourcePluginStore_Vtbl)).GetFarmProperty)(this, farmname, propertyname, pvarvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteTarget(this: *mut core::ffi::c_void, targetname: core::mem::MaybeUninit<windows_core::BSTR>, hostname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourcePluginStore", "DeleteTarget", 22usize, |this| ((**(this as *const *const ITsSbResourcePluginStore_Vtbl)).DeleteTarget)(this, targetname, hostname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTargetPropertyWithVersionCheck(this: *mut core::ffi::c_void, ptarget: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::BSTR>, pproperty: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourcePluginStore", "SetTargetPropertyWithVersionCheck", 23usize, |this| ((**(this as *const *const ITsSbResourcePluginStore_Vtbl)).SetTargetPropertyWithVersionCheck)(this, ptarget, propertyname, pproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnvironmentPropertyWithVersionCheck(this: *mut core::ffi::c_void, penvironment: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::BSTR>, pproperty: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourcePluginStore", "SetEnvironmentPropertyWithVersionCheck", 24usize, |this| ((**(this as *const *const ITsSbResourcePluginStore_Vtbl)).SetEnvironmentPropertyWithVersionCheck)(this, penvironment, propertyname, pproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcquireTargetLock(this: *mut core::ffi::c_void, targetname: core::mem::MaybeUninit<windows_core::BSTR>, dwtimeout: u32, ppcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourcePluginStore", "AcquireTargetLock", 25usize, |this| ((**(this as *const *const ITsSbResourcePluginStore_Vtbl)).AcquireTargetLock)(this, targetname, dwtimeout, ppcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseTargetLock(this: *mut core::ffi::c_void, pcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourcePluginStore", "ReleaseTargetLock", 26usize, |this| ((**(this as *const *const ITsSbResourcePluginStore_Vtbl)).ReleaseTargetLock)(this, pcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn TestAndSetServerState(this: *mut core::ffi::c_void, poolname: core::mem::MaybeUninit<windows_core::BSTR>, serverfqdn: core::mem::MaybeUninit<windows_core::BSTR>, newstate: TARGET_STATE, teststate: TARGET_STATE, pinitstate: *mut TARGET_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourcePluginStore", "TestAndSetServerState", 27usize, |this| ((**(this as *const *const ITsSbResourcePluginStore_Vtbl)).TestAndSetServerState)(this, poolname, serverfqdn, newstate, teststate, pinitstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServerWaitingToStart(this: *mut core::ffi::c_void, poolname: core::mem::MaybeUninit<windows_core::BSTR>, servername: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourcePluginStore", "SetServerWaitingToStart", 28usize, |this| ((**(this as *const *const ITsSbResourcePluginStore_Vtbl)).SetServerWaitingToStart)(this, poolname, servername), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServerState(this: *mut core::ffi::c_void, poolname: core::mem::MaybeUninit<windows_core::BSTR>, serverfqdn: core::mem::MaybeUninit<windows_core::BSTR>, pstate: *mut TARGET_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourcePluginStore", "GetServerState", 29usize, |this| ((**(this as *const *const ITsSbResourcePluginStore_Vtbl)).GetServerState)(this, poolname, serverfqdn, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServerDrainMode(this: *mut core::ffi::c_void, serverfqdn: core::mem::MaybeUninit<windows_core::BSTR>, drainmode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbResourcePluginStore", "SetServerDrainMode", 30usize, |this| ((**(this as *const *const ITsSbResourcePluginStore_Vtbl)).SetServerDrainMode)(this, serverfqdn, drainmode), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            QueryTarget,
+            QuerySessionBySessionId,
+            AddTargetToStore,
+            AddSessionToStore,
+            AddEnvironmentToStore,
+            RemoveEnvironmentFromStore,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumerateFarms,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumerateFarms: 0,
+            QueryEnvironment,
+            EnumerateEnvironments,
+            SaveTarget,
+            SaveEnvironment,
+            SaveSession,
+            SetTargetProperty,
+            SetEnvironmentProperty,
+            SetTargetState,
+            SetSessionState,
+            EnumerateTargets,
+            EnumerateSessions,
+            GetFarmProperty,
+            DeleteTarget,
+            SetTargetPropertyWithVersionCheck,
+            SetEnvironmentPropertyWithVersionCheck,
+            AcquireTargetLock,
+            ReleaseTargetLock,
+            TestAndSetServerState,
+            SetServerWaitingToStart,
+            GetServerState,
+            SetServerDrainMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbResourcePluginStore as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITsSbServiceNotification_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NotifyServiceFailure(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbServiceNotification", "NotifyServiceFailure", 3usize, |this| ((**(this as *const *const ITsSbServiceNotification_Vtbl)).NotifyServiceFailure)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyServiceSuccess(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbServiceNotification", "NotifyServiceSuccess", 4usize, |this| ((**(this as *const *const ITsSbServiceNotification_Vtbl)).NotifyServiceSuccess)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NotifyServiceFailure, NotifyServiceSuccess }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbServiceNotification as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITsSbSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SessionId(this: *mut core::ffi::c_void, pval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbSession", "SessionId", 3usize, |this| ((**(this as *const *const ITsSbSession_Vtbl)).SessionId)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn TargetName(this: *mut core::ffi::c_void, targetname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbSession", "TargetName", 4usize, |this| ((**(this as *const *const ITsSbSession_Vtbl)).TargetName)(this, targetname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTargetName(this: *mut core::ffi::c_void, targetname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbSession", "SetTargetName", 5usize, |this| ((**(this as *const *const ITsSbSession_Vtbl)).SetTargetName)(this, targetname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Username(this: *mut core::ffi::c_void, username: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbSession", "Username", 6usize, |this| ((**(this as *const *const ITsSbSession_Vtbl)).Username)(this, username), |result| Some(*result))
+        }
+        unsafe extern "system" fn Domain(this: *mut core::ffi::c_void, domain: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbSession", "Domain", 7usize, |this| ((**(this as *const *const ITsSbSession_Vtbl)).Domain)(this, domain), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, pstate: *mut TSSESSION_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbSession", "State", 8usize, |this| ((**(this as *const *const ITsSbSession_Vtbl)).State)(this, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetState(this: *mut core::ffi::c_void, state: TSSESSION_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbSession", "SetState", 9usize, |this| ((**(this as *const *const ITsSbSession_Vtbl)).SetState)(this, state), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTime(this: *mut core::ffi::c_void, ptime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbSession", "CreateTime", 10usize, |this| ((**(this as *const *const ITsSbSession_Vtbl)).CreateTime)(this, ptime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCreateTime(this: *mut core::ffi::c_void, time: super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbSession", "SetCreateTime", 11usize, |this| ((**(this as *const *const ITsSbSession_Vtbl)).SetCreateTime)(this, time), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisconnectTime(this: *mut core::ffi::c_void, ptime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbSession", "DisconnectTime", 12usize, |this| ((**(this as *const *const ITsSbSession_Vtbl)).DisconnectTime)(this, ptime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisconnectTime(this: *mut core::ffi::c_void, time: super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbSession", "SetDisconnectTime", 13usize, |this| ((**(this as *const *const ITsSbSession_Vtbl)).SetDisconnectTime)(this, time), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitialProgram(this: *mut core::ffi::c_void, app: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbSession", "InitialProgram", 14usize, |this| ((**(this as *const *const ITsSbSession_Vtbl)).InitialProgram)(this, app), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInitialProgram(this: *mut core::ffi::c_void, application: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbSession", "SetInitialProgram", 15usize, |this| ((**(this as *const *const ITsSbSession_Vtbl)).SetInitialProgram)(this, application), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClientDisplay(this: *mut core::ffi::c_void, pclientdisplay: *mut CLIENT_DISPLAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbSession", "ClientDisplay", 16usize, |this| ((**(this as *const *const ITsSbSession_Vtbl)).ClientDisplay)(this, pclientdisplay), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClientDisplay(this: *mut core::ffi::c_void, pclientdisplay: CLIENT_DISPLAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbSession", "SetClientDisplay", 17usize, |this| ((**(this as *const *const ITsSbSession_Vtbl)).SetClientDisplay)(this, pclientdisplay), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtocolType(this: *mut core::ffi::c_void, pval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbSession", "ProtocolType", 18usize, |this| ((**(this as *const *const ITsSbSession_Vtbl)).ProtocolType)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProtocolType(this: *mut core::ffi::c_void, val: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbSession", "SetProtocolType", 19usize, |this| ((**(this as *const *const ITsSbSession_Vtbl)).SetProtocolType)(this, val), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SessionId,
+            TargetName,
+            SetTargetName,
+            Username,
+            Domain,
+            State,
+            SetState,
+            CreateTime,
+            SetCreateTime,
+            DisconnectTime,
+            SetDisconnectTime,
+            InitialProgram,
+            SetInitialProgram,
+            ClientDisplay,
+            SetClientDisplay,
+            ProtocolType,
+            SetProtocolType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbSession as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for ITsSbTarget_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TargetName(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTarget", "TargetName", 3usize, |this| ((**(this as *const *const ITsSbTarget_Vtbl)).TargetName)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTargetName(this: *mut core::ffi::c_void, val: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTarget", "SetTargetName", 4usize, |this| ((**(this as *const *const ITsSbTarget_Vtbl)).SetTargetName)(this, val), |result| Some(*result))
+        }
+        unsafe extern "system" fn FarmName(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTarget", "FarmName", 5usize, |this| ((**(this as *const *const ITsSbTarget_Vtbl)).FarmName)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFarmName(this: *mut core::ffi::c_void, val: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTarget", "SetFarmName", 6usize, |this| ((**(this as *const *const ITsSbTarget_Vtbl)).SetFarmName)(this, val), |result| Some(*result))
+        }
+        unsafe extern "system" fn TargetFQDN(this: *mut core::ffi::c_void, targetfqdnname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTarget", "TargetFQDN", 7usize, |this| ((**(this as *const *const ITsSbTarget_Vtbl)).TargetFQDN)(this, targetfqdnname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTargetFQDN(this: *mut core::ffi::c_void, val: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTarget", "SetTargetFQDN", 8usize, |this| ((**(this as *const *const ITsSbTarget_Vtbl)).SetTargetFQDN)(this, val), |result| Some(*result))
+        }
+        unsafe extern "system" fn TargetNetbios(this: *mut core::ffi::c_void, targetnetbiosname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTarget", "TargetNetbios", 9usize, |this| ((**(this as *const *const ITsSbTarget_Vtbl)).TargetNetbios)(this, targetnetbiosname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTargetNetbios(this: *mut core::ffi::c_void, val: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTarget", "SetTargetNetbios", 10usize, |this| ((**(this as *const *const ITsSbTarget_Vtbl)).SetTargetNetbios)(this, val), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_IpAddresses(this: *mut core::ffi::c_void, sockaddr: *mut TSSD_ConnectionPoint, numaddresses: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTarget", "get_IpAddresses", 11usize, |this| ((**(this as *const *const ITsSbTarget_Vtbl)).get_IpAddresses)(this, sockaddr, numaddresses), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_IpAddresses(this: *mut core::ffi::c_void, sockaddr: *const TSSD_ConnectionPoint, numaddresses: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTarget", "put_IpAddresses", 12usize, |this| ((**(this as *const *const ITsSbTarget_Vtbl)).put_IpAddresses)(this, sockaddr, numaddresses), |result| Some(*result))
+        }
+        unsafe extern "system" fn TargetState(this: *mut core::ffi::c_void, pstate: *mut TARGET_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTarget", "TargetState", 13usize, |this| ((**(this as *const *const ITsSbTarget_Vtbl)).TargetState)(this, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTargetState(this: *mut core::ffi::c_void, state: TARGET_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTarget", "SetTargetState", 14usize, |this| ((**(this as *const *const ITsSbTarget_Vtbl)).SetTargetState)(this, state), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn TargetPropertySet(this: *mut core::ffi::c_void, pppropertyset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTarget", "TargetPropertySet", 15usize, |this| ((**(this as *const *const ITsSbTarget_Vtbl)).TargetPropertySet)(this, pppropertyset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn SetTargetPropertySet(this: *mut core::ffi::c_void, pval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTarget", "SetTargetPropertySet", 16usize, |this| ((**(this as *const *const ITsSbTarget_Vtbl)).SetTargetPropertySet)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnvironmentName(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTarget", "EnvironmentName", 17usize, |this| ((**(this as *const *const ITsSbTarget_Vtbl)).EnvironmentName)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnvironmentName(this: *mut core::ffi::c_void, val: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTarget", "SetEnvironmentName", 18usize, |this| ((**(this as *const *const ITsSbTarget_Vtbl)).SetEnvironmentName)(this, val), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumSessions(this: *mut core::ffi::c_void, pnumsessions: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTarget", "NumSessions", 19usize, |this| ((**(this as *const *const ITsSbTarget_Vtbl)).NumSessions)(this, pnumsessions), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumPendingConnections(this: *mut core::ffi::c_void, pnumpendingconnections: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTarget", "NumPendingConnections", 20usize, |this| ((**(this as *const *const ITsSbTarget_Vtbl)).NumPendingConnections)(this, pnumpendingconnections), |result| Some(*result))
+        }
+        unsafe extern "system" fn TargetLoad(this: *mut core::ffi::c_void, ptargetload: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTarget", "TargetLoad", 21usize, |this| ((**(this as *const *const ITsSbTarget_Vtbl)).TargetLoad)(this, ptargetload), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TargetName,
+            SetTargetName,
+            FarmName,
+            SetFarmName,
+            TargetFQDN,
+            SetTargetFQDN,
+            TargetNetbios,
+            SetTargetNetbios,
+            get_IpAddresses,
+            put_IpAddresses,
+            TargetState,
+            SetTargetState,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            TargetPropertySet,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            TargetPropertySet: 0,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            SetTargetPropertySet,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            SetTargetPropertySet: 0,
+            EnvironmentName,
+            SetEnvironmentName,
+            NumSessions,
+            NumPendingConnections,
+            TargetLoad,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbTarget as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for ITsSbTargetPropertySet_Vtbl {
+    const PROXY: Self = { Self { base__: <ITsSbPropertySet_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbTargetPropertySet as windows_core::Interface>::IID || <ITsSbPropertySet_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITsSbTaskInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TargetId(this: *mut core::ffi::c_void, pname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTaskInfo", "TargetId", 3usize, |this| ((**(this as *const *const ITsSbTaskInfo_Vtbl)).TargetId)(this, pname), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartTime(this: *mut core::ffi::c_void, pstarttime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTaskInfo", "StartTime", 4usize, |this| ((**(this as *const *const ITsSbTaskInfo_Vtbl)).StartTime)(this, pstarttime), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndTime(this: *mut core::ffi::c_void, pendtime: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTaskInfo", "EndTime", 5usize, |this| ((**(this as *const *const ITsSbTaskInfo_Vtbl)).EndTime)(this, pendtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deadline(this: *mut core::ffi::c_void, pdeadline: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTaskInfo", "Deadline", 6usize, |this| ((**(this as *const *const ITsSbTaskInfo_Vtbl)).Deadline)(this, pdeadline), |result| Some(*result))
+        }
+        unsafe extern "system" fn Identifier(this: *mut core::ffi::c_void, pidentifier: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTaskInfo", "Identifier", 7usize, |this| ((**(this as *const *const ITsSbTaskInfo_Vtbl)).Identifier)(this, pidentifier), |result| Some(*result))
+        }
+        unsafe extern "system" fn Label(this: *mut core::ffi::c_void, plabel: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTaskInfo", "Label", 8usize, |this| ((**(this as *const *const ITsSbTaskInfo_Vtbl)).Label)(this, plabel), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Context(this: *mut core::ffi::c_void, pcontext: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTaskInfo", "Context", 9usize, |this| ((**(this as *const *const ITsSbTaskInfo_Vtbl)).Context)(this, pcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn Plugin(this: *mut core::ffi::c_void, pplugin: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTaskInfo", "Plugin", 10usize, |this| ((**(this as *const *const ITsSbTaskInfo_Vtbl)).Plugin)(this, pplugin), |result| Some(*result))
+        }
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, pstatus: *mut RDV_TASK_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTaskInfo", "Status", 11usize, |this| ((**(this as *const *const ITsSbTaskInfo_Vtbl)).Status)(this, pstatus), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TargetId,
+            StartTime,
+            EndTime,
+            Deadline,
+            Identifier,
+            Label,
+            #[cfg(feature = "Win32_System_Com")]
+            Context,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Context: 0,
+            Plugin,
+            Status,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbTaskInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for ITsSbTaskPlugin_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializeTaskPlugin(this: *mut core::ffi::c_void, pitssbtaskpluginnotifysink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTaskPlugin", "InitializeTaskPlugin", 5usize, |this| ((**(this as *const *const ITsSbTaskPlugin_Vtbl)).InitializeTaskPlugin)(this, pitssbtaskpluginnotifysink), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTaskQueue(this: *mut core::ffi::c_void, pszhostname: core::mem::MaybeUninit<windows_core::BSTR>, sbtaskinfosize: u32, pitssbtaskinfo: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTaskPlugin", "SetTaskQueue", 6usize, |this| ((**(this as *const *const ITsSbTaskPlugin_Vtbl)).SetTaskQueue)(this, pszhostname, sbtaskinfosize, pitssbtaskinfo), |result| Some(*result))
+        }
+        Self { base__: <ITsSbPlugin_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InitializeTaskPlugin, SetTaskQueue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbTaskPlugin as windows_core::Interface>::IID || <ITsSbPlugin_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITsSbTaskPluginNotifySink_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnSetTaskTime(this: *mut core::ffi::c_void, sztargetname: core::mem::MaybeUninit<windows_core::BSTR>, taskstarttime: super::super::Foundation::FILETIME, taskendtime: super::super::Foundation::FILETIME, taskdeadline: super::super::Foundation::FILETIME, sztasklabel: core::mem::MaybeUninit<windows_core::BSTR>, sztaskidentifier: core::mem::MaybeUninit<windows_core::BSTR>, sztaskplugin: core::mem::MaybeUninit<windows_core::BSTR>, dwtaskstatus: u32, sacontext: *const super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTaskPluginNotifySink", "OnSetTaskTime", 5usize, |this| ((**(this as *const *const ITsSbTaskPluginNotifySink_Vtbl)).OnSetTaskTime)(this, sztargetname, taskstarttime, taskendtime, taskdeadline, sztasklabel, sztaskidentifier, sztaskplugin, dwtaskstatus, sacontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDeleteTaskTime(this: *mut core::ffi::c_void, sztargetname: core::mem::MaybeUninit<windows_core::BSTR>, sztaskidentifier: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTaskPluginNotifySink", "OnDeleteTaskTime", 6usize, |this| ((**(this as *const *const ITsSbTaskPluginNotifySink_Vtbl)).OnDeleteTaskTime)(this, sztargetname, sztaskidentifier), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnUpdateTaskStatus(this: *mut core::ffi::c_void, sztargetname: core::mem::MaybeUninit<windows_core::BSTR>, taskidentifier: core::mem::MaybeUninit<windows_core::BSTR>, taskstatus: RDV_TASK_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTaskPluginNotifySink", "OnUpdateTaskStatus", 7usize, |this| ((**(this as *const *const ITsSbTaskPluginNotifySink_Vtbl)).OnUpdateTaskStatus)(this, sztargetname, taskidentifier, taskstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnReportTasks(this: *mut core::ffi::c_void, szhostname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITsSbTaskPluginNotifySink", "OnReportTasks", 8usize, |this| ((**(this as *const *const ITsSbTaskPluginNotifySink_Vtbl)).OnReportTasks)(this, szhostname), |result| Some(*result))
+        }
+        Self {
+            base__: <ITsSbBaseNotifySink_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            OnSetTaskTime,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnSetTaskTime: 0,
+            OnDeleteTaskTime,
+            OnUpdateTaskStatus,
+            OnReportTasks,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITsSbTaskPluginNotifySink as windows_core::Interface>::IID || <ITsSbBaseNotifySink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWRdsEnhancedFastReconnectArbitrator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSessionForEnhancedFastReconnect(this: *mut core::ffi::c_void, psessionidarray: *const i32, dwsessioncount: u32, presultsessionid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsEnhancedFastReconnectArbitrator", "GetSessionForEnhancedFastReconnect", 3usize, |this| ((**(this as *const *const IWRdsEnhancedFastReconnectArbitrator_Vtbl)).GetSessionForEnhancedFastReconnect)(this, psessionidarray, dwsessioncount, presultsessionid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSessionForEnhancedFastReconnect }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWRdsEnhancedFastReconnectArbitrator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWRdsGraphicsChannel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Write(this: *mut core::ffi::c_void, cbsize: u32, pbuffer: *const u8, pcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsGraphicsChannel", "Write", 3usize, |this| ((**(this as *const *const IWRdsGraphicsChannel_Vtbl)).Write)(this, cbsize, pbuffer, pcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsGraphicsChannel", "Close", 4usize, |this| ((**(this as *const *const IWRdsGraphicsChannel_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, pchannelevents: *mut core::ffi::c_void, popencontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsGraphicsChannel", "Open", 5usize, |this| ((**(this as *const *const IWRdsGraphicsChannel_Vtbl)).Open)(this, pchannelevents, popencontext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Write, Close, Open }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWRdsGraphicsChannel as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWRdsGraphicsChannelEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnDataReceived(this: *mut core::ffi::c_void, cbsize: u32, pbuffer: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsGraphicsChannelEvents", "OnDataReceived", 3usize, |this| ((**(this as *const *const IWRdsGraphicsChannelEvents_Vtbl)).OnDataReceived)(this, cbsize, pbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnClose(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsGraphicsChannelEvents", "OnClose", 4usize, |this| ((**(this as *const *const IWRdsGraphicsChannelEvents_Vtbl)).OnClose)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnChannelOpened(this: *mut core::ffi::c_void, openresult: windows_core::HRESULT, popencontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsGraphicsChannelEvents", "OnChannelOpened", 5usize, |this| ((**(this as *const *const IWRdsGraphicsChannelEvents_Vtbl)).OnChannelOpened)(this, openresult, popencontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDataSent(this: *mut core::ffi::c_void, pwritecontext: *mut core::ffi::c_void, bcancelled: super::super::Foundation::BOOL, pbuffer: *const u8, cbbuffer: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsGraphicsChannelEvents", "OnDataSent", 6usize, |this| ((**(this as *const *const IWRdsGraphicsChannelEvents_Vtbl)).OnDataSent)(this, pwritecontext, bcancelled, pbuffer, cbbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnMetricsUpdate(this: *mut core::ffi::c_void, bandwidth: u32, rtt: u32, lastsentbyteindex: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsGraphicsChannelEvents", "OnMetricsUpdate", 7usize, |this| ((**(this as *const *const IWRdsGraphicsChannelEvents_Vtbl)).OnMetricsUpdate)(this, bandwidth, rtt, lastsentbyteindex), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnDataReceived,
+            OnClose,
+            OnChannelOpened,
+            OnDataSent,
+            OnMetricsUpdate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWRdsGraphicsChannelEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWRdsGraphicsChannelManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateChannel(this: *mut core::ffi::c_void, pszchannelname: *const u8, channeltype: WRdsGraphicsChannelType, ppvirtualchannel: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsGraphicsChannelManager", "CreateChannel", 3usize, |this| ((**(this as *const *const IWRdsGraphicsChannelManager_Vtbl)).CreateChannel)(this, pszchannelname, channeltype, ppvirtualchannel), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateChannel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWRdsGraphicsChannelManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWRdsProtocolConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLogonErrorRedirector(this: *mut core::ffi::c_void, pplogonerrorredir: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnection", "GetLogonErrorRedirector", 3usize, |this| ((**(this as *const *const IWRdsProtocolConnection_Vtbl)).GetLogonErrorRedirector)(this, pplogonerrorredir), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcceptConnection(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnection", "AcceptConnection", 4usize, |this| ((**(this as *const *const IWRdsProtocolConnection_Vtbl)).AcceptConnection)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClientData(this: *mut core::ffi::c_void, pclientdata: *mut WTS_CLIENT_DATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnection", "GetClientData", 5usize, |this| ((**(this as *const *const IWRdsProtocolConnection_Vtbl)).GetClientData)(this, pclientdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClientMonitorData(this: *mut core::ffi::c_void, pnummonitors: *mut u32, pprimarymonitor: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnection", "GetClientMonitorData", 6usize, |this| ((**(this as *const *const IWRdsProtocolConnection_Vtbl)).GetClientMonitorData)(this, pnummonitors, pprimarymonitor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUserCredentials(this: *mut core::ffi::c_void, pusercreds: *mut WTS_USER_CREDENTIAL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnection", "GetUserCredentials", 7usize, |this| ((**(this as *const *const IWRdsProtocolConnection_Vtbl)).GetUserCredentials)(this, pusercreds), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLicenseConnection(this: *mut core::ffi::c_void, pplicenseconnection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnection", "GetLicenseConnection", 8usize, |this| ((**(this as *const *const IWRdsProtocolConnection_Vtbl)).GetLicenseConnection)(this, pplicenseconnection), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthenticateClientToSession(this: *mut core::ffi::c_void, sessionid: *mut WTS_SESSION_ID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnection", "AuthenticateClientToSession", 9usize, |this| ((**(this as *const *const IWRdsProtocolConnection_Vtbl)).AuthenticateClientToSession)(this, sessionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifySessionId(this: *mut core::ffi::c_void, sessionid: *const WTS_SESSION_ID, sessionhandle: super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnection", "NotifySessionId", 10usize, |this| ((**(this as *const *const IWRdsProtocolConnection_Vtbl)).NotifySessionId)(this, sessionid, sessionhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputHandles(this: *mut core::ffi::c_void, pkeyboardhandle: *mut super::super::Foundation::HANDLE_PTR, pmousehandle: *mut super::super::Foundation::HANDLE_PTR, pbeephandle: *mut super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnection", "GetInputHandles", 11usize, |this| ((**(this as *const *const IWRdsProtocolConnection_Vtbl)).GetInputHandles)(this, pkeyboardhandle, pmousehandle, pbeephandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVideoHandle(this: *mut core::ffi::c_void, pvideohandle: *mut super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnection", "GetVideoHandle", 12usize, |this| ((**(this as *const *const IWRdsProtocolConnection_Vtbl)).GetVideoHandle)(this, pvideohandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectNotify(this: *mut core::ffi::c_void, sessionid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnection", "ConnectNotify", 13usize, |this| ((**(this as *const *const IWRdsProtocolConnection_Vtbl)).ConnectNotify)(this, sessionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUserAllowedToLogon(this: *mut core::ffi::c_void, sessionid: u32, usertoken: super::super::Foundation::HANDLE_PTR, pdomainname: windows_core::PCWSTR, pusername: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnection", "IsUserAllowedToLogon", 14usize, |this| ((**(this as *const *const IWRdsProtocolConnection_Vtbl)).IsUserAllowedToLogon)(this, sessionid, usertoken, pdomainname, pusername), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionArbitrationEnumeration(this: *mut core::ffi::c_void, husertoken: super::super::Foundation::HANDLE_PTR, bsinglesessionperuserenabled: super::super::Foundation::BOOL, psessionidarray: *mut u32, pdwsessionidentifiercount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnection", "SessionArbitrationEnumeration", 15usize, |this| ((**(this as *const *const IWRdsProtocolConnection_Vtbl)).SessionArbitrationEnumeration)(this, husertoken, bsinglesessionperuserenabled, psessionidarray, pdwsessionidentifiercount), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogonNotify(this: *mut core::ffi::c_void, hclienttoken: super::super::Foundation::HANDLE_PTR, wszusername: windows_core::PCWSTR, wszdomainname: windows_core::PCWSTR, sessionid: *const WTS_SESSION_ID, pwrdsconnectionsettings: *mut WRDS_CONNECTION_SETTINGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnection", "LogonNotify", 16usize, |this| ((**(this as *const *const IWRdsProtocolConnection_Vtbl)).LogonNotify)(this, hclienttoken, wszusername, wszdomainname, sessionid, pwrdsconnectionsettings), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreDisconnect(this: *mut core::ffi::c_void, disconnectreason: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnection", "PreDisconnect", 17usize, |this| ((**(this as *const *const IWRdsProtocolConnection_Vtbl)).PreDisconnect)(this, disconnectreason), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisconnectNotify(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnection", "DisconnectNotify", 18usize, |this| ((**(this as *const *const IWRdsProtocolConnection_Vtbl)).DisconnectNotify)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnection", "Close", 19usize, |this| ((**(this as *const *const IWRdsProtocolConnection_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProtocolStatus(this: *mut core::ffi::c_void, pprotocolstatus: *mut WTS_PROTOCOL_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnection", "GetProtocolStatus", 20usize, |this| ((**(this as *const *const IWRdsProtocolConnection_Vtbl)).GetProtocolStatus)(this, pprotocolstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastInputTime(this: *mut core::ffi::c_void, plastinputtime: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnection", "GetLastInputTime", 21usize, |this| ((**(this as *const *const IWRdsProtocolConnection_Vtbl)).GetLastInputTime)(this, plastinputtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetErrorInfo(this: *mut core::ffi::c_void, ulerror: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnection", "SetErrorInfo", 22usize, |this| ((**(this as *const *const IWRdsProtocolConnection_Vtbl)).SetErrorInfo)(this, ulerror), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVirtualChannel(this: *mut core::ffi::c_void, szendpointname: windows_core::PCSTR, bstatic: super::super::Foundation::BOOL, requestedpriority: u32, phchannel: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnection", "CreateVirtualChannel", 23usize, |this| ((**(this as *const *const IWRdsProtocolConnection_Vtbl)).CreateVirtualChannel)(this, szendpointname, bstatic, requestedpriority, phchannel), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryProperty(this: *mut core::ffi::c_void, querytype: windows_core::GUID, ulnumentriesin: u32, ulnumentriesout: u32, ppropertyentriesin: *const WTS_PROPERTY_VALUE, ppropertyentriesout: *mut WTS_PROPERTY_VALUE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnection", "QueryProperty", 24usize, |this| ((**(this as *const *const IWRdsProtocolConnection_Vtbl)).QueryProperty)(this, querytype, ulnumentriesin, ulnumentriesout, ppropertyentriesin, ppropertyentriesout), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetShadowConnection(this: *mut core::ffi::c_void, ppshadowconnection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnection", "GetShadowConnection", 25usize, |this| ((**(this as *const *const IWRdsProtocolConnection_Vtbl)).GetShadowConnection)(this, ppshadowconnection), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyCommandProcessCreated(this: *mut core::ffi::c_void, sessionid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnection", "NotifyCommandProcessCreated", 26usize, |this| ((**(this as *const *const IWRdsProtocolConnection_Vtbl)).NotifyCommandProcessCreated)(this, sessionid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetLogonErrorRedirector,
+            AcceptConnection,
+            GetClientData,
+            GetClientMonitorData,
+            GetUserCredentials,
+            GetLicenseConnection,
+            AuthenticateClientToSession,
+            NotifySessionId,
+            GetInputHandles,
+            GetVideoHandle,
+            ConnectNotify,
+            IsUserAllowedToLogon,
+            SessionArbitrationEnumeration,
+            LogonNotify,
+            PreDisconnect,
+            DisconnectNotify,
+            Close,
+            GetProtocolStatus,
+            GetLastInputTime,
+            SetErrorInfo,
+            CreateVirtualChannel,
+            QueryProperty,
+            GetShadowConnection,
+            NotifyCommandProcessCreated,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWRdsProtocolConnection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWRdsProtocolConnectionCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnReady(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnectionCallback", "OnReady", 3usize, |this| ((**(this as *const *const IWRdsProtocolConnectionCallback_Vtbl)).OnReady)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn BrokenConnection(this: *mut core::ffi::c_void, reason: u32, source: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnectionCallback", "BrokenConnection", 4usize, |this| ((**(this as *const *const IWRdsProtocolConnectionCallback_Vtbl)).BrokenConnection)(this, reason, source), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopScreenUpdates(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnectionCallback", "StopScreenUpdates", 5usize, |this| ((**(this as *const *const IWRdsProtocolConnectionCallback_Vtbl)).StopScreenUpdates)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RedrawWindow(this: *mut core::ffi::c_void, rect: *const WTS_SMALL_RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnectionCallback", "RedrawWindow", 6usize, |this| ((**(this as *const *const IWRdsProtocolConnectionCallback_Vtbl)).RedrawWindow)(this, rect), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConnectionId(this: *mut core::ffi::c_void, pconnectionid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnectionCallback", "GetConnectionId", 7usize, |this| ((**(this as *const *const IWRdsProtocolConnectionCallback_Vtbl)).GetConnectionId)(this, pconnectionid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnReady,
+            BrokenConnection,
+            StopScreenUpdates,
+            RedrawWindow,
+            GetConnectionId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWRdsProtocolConnectionCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWRdsProtocolConnectionSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetConnectionSetting(this: *mut core::ffi::c_void, propertyid: windows_core::GUID, ppropertyentriesin: *const WTS_PROPERTY_VALUE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnectionSettings", "SetConnectionSetting", 3usize, |this| ((**(this as *const *const IWRdsProtocolConnectionSettings_Vtbl)).SetConnectionSetting)(this, propertyid, ppropertyentriesin), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConnectionSetting(this: *mut core::ffi::c_void, propertyid: windows_core::GUID, ppropertyentriesout: *mut WTS_PROPERTY_VALUE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolConnectionSettings", "GetConnectionSetting", 4usize, |this| ((**(this as *const *const IWRdsProtocolConnectionSettings_Vtbl)).GetConnectionSetting)(this, propertyid, ppropertyentriesout), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetConnectionSetting, GetConnectionSetting }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWRdsProtocolConnectionSettings as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWRdsProtocolLicenseConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestLicensingCapabilities(this: *mut core::ffi::c_void, pplicensecapabilities: *mut WTS_LICENSE_CAPABILITIES, pcblicensecapabilities: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolLicenseConnection", "RequestLicensingCapabilities", 3usize, |this| ((**(this as *const *const IWRdsProtocolLicenseConnection_Vtbl)).RequestLicensingCapabilities)(this, pplicensecapabilities, pcblicensecapabilities), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendClientLicense(this: *mut core::ffi::c_void, pclientlicense: *const u8, cbclientlicense: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolLicenseConnection", "SendClientLicense", 4usize, |this| ((**(this as *const *const IWRdsProtocolLicenseConnection_Vtbl)).SendClientLicense)(this, pclientlicense, cbclientlicense), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestClientLicense(this: *mut core::ffi::c_void, reserve1: *const u8, reserve2: u32, ppclientlicense: *mut u8, pcbclientlicense: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolLicenseConnection", "RequestClientLicense", 5usize, |this| ((**(this as *const *const IWRdsProtocolLicenseConnection_Vtbl)).RequestClientLicense)(this, reserve1, reserve2, ppclientlicense, pcbclientlicense), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtocolComplete(this: *mut core::ffi::c_void, ulcomplete: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolLicenseConnection", "ProtocolComplete", 6usize, |this| ((**(this as *const *const IWRdsProtocolLicenseConnection_Vtbl)).ProtocolComplete)(this, ulcomplete), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestLicensingCapabilities,
+            SendClientLicense,
+            RequestClientLicense,
+            ProtocolComplete,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWRdsProtocolLicenseConnection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWRdsProtocolListener_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSettings(this: *mut core::ffi::c_void, wrdslistenersettinglevel: WRDS_LISTENER_SETTING_LEVEL, pwrdslistenersettings: *mut WRDS_LISTENER_SETTINGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolListener", "GetSettings", 3usize, |this| ((**(this as *const *const IWRdsProtocolListener_Vtbl)).GetSettings)(this, wrdslistenersettinglevel, pwrdslistenersettings), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartListen(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolListener", "StartListen", 4usize, |this| ((**(this as *const *const IWRdsProtocolListener_Vtbl)).StartListen)(this, pcallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopListen(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolListener", "StopListen", 5usize, |this| ((**(this as *const *const IWRdsProtocolListener_Vtbl)).StopListen)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSettings, StartListen, StopListen }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWRdsProtocolListener as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWRdsProtocolListenerCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnConnected(this: *mut core::ffi::c_void, pconnection: *mut core::ffi::c_void, pwrdsconnectionsettings: *const WRDS_CONNECTION_SETTINGS, pcallback: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolListenerCallback", "OnConnected", 3usize, |this| ((**(this as *const *const IWRdsProtocolListenerCallback_Vtbl)).OnConnected)(this, pconnection, pwrdsconnectionsettings, pcallback), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnConnected }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWRdsProtocolListenerCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWRdsProtocolLogonErrorRedirector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnBeginPainting(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolLogonErrorRedirector", "OnBeginPainting", 3usize, |this| ((**(this as *const *const IWRdsProtocolLogonErrorRedirector_Vtbl)).OnBeginPainting)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RedirectStatus(this: *mut core::ffi::c_void, pszmessage: windows_core::PCWSTR, presponse: *mut WTS_LOGON_ERROR_REDIRECTOR_RESPONSE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolLogonErrorRedirector", "RedirectStatus", 4usize, |this| ((**(this as *const *const IWRdsProtocolLogonErrorRedirector_Vtbl)).RedirectStatus)(this, pszmessage, presponse), |result| Some(*result))
+        }
+        unsafe extern "system" fn RedirectMessage(this: *mut core::ffi::c_void, pszcaption: windows_core::PCWSTR, pszmessage: windows_core::PCWSTR, utype: u32, presponse: *mut WTS_LOGON_ERROR_REDIRECTOR_RESPONSE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolLogonErrorRedirector", "RedirectMessage", 5usize, |this| ((**(this as *const *const IWRdsProtocolLogonErrorRedirector_Vtbl)).RedirectMessage)(this, pszcaption, pszmessage, utype, presponse), |result| Some(*result))
+        }
+        unsafe extern "system" fn RedirectLogonError(this: *mut core::ffi::c_void, ntsstatus: i32, ntssubstatus: i32, pszcaption: windows_core::PCWSTR, pszmessage: windows_core::PCWSTR, utype: u32, presponse: *mut WTS_LOGON_ERROR_REDIRECTOR_RESPONSE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolLogonErrorRedirector", "RedirectLogonError", 6usize, |this| ((**(this as *const *const IWRdsProtocolLogonErrorRedirector_Vtbl)).RedirectLogonError)(this, ntsstatus, ntssubstatus, pszcaption, pszmessage, utype, presponse), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnBeginPainting,
+            RedirectStatus,
+            RedirectMessage,
+            RedirectLogonError,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWRdsProtocolLogonErrorRedirector as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWRdsProtocolManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, piwrdssettings: *mut core::ffi::c_void, pwrdssettings: *const WRDS_SETTINGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolManager", "Initialize", 3usize, |this| ((**(this as *const *const IWRdsProtocolManager_Vtbl)).Initialize)(this, piwrdssettings, pwrdssettings), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateListener(this: *mut core::ffi::c_void, wszlistenername: windows_core::PCWSTR, pprotocollistener: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolManager", "CreateListener", 4usize, |this| ((**(this as *const *const IWRdsProtocolManager_Vtbl)).CreateListener)(this, wszlistenername, pprotocollistener), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyServiceStateChange(this: *mut core::ffi::c_void, ptsservicestatechange: *const WTS_SERVICE_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolManager", "NotifyServiceStateChange", 5usize, |this| ((**(this as *const *const IWRdsProtocolManager_Vtbl)).NotifyServiceStateChange)(this, ptsservicestatechange), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifySessionOfServiceStart(this: *mut core::ffi::c_void, sessionid: *const WTS_SESSION_ID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolManager", "NotifySessionOfServiceStart", 6usize, |this| ((**(this as *const *const IWRdsProtocolManager_Vtbl)).NotifySessionOfServiceStart)(this, sessionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifySessionOfServiceStop(this: *mut core::ffi::c_void, sessionid: *const WTS_SESSION_ID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolManager", "NotifySessionOfServiceStop", 7usize, |this| ((**(this as *const *const IWRdsProtocolManager_Vtbl)).NotifySessionOfServiceStop)(this, sessionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifySessionStateChange(this: *mut core::ffi::c_void, sessionid: *const WTS_SESSION_ID, eventid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolManager", "NotifySessionStateChange", 8usize, |this| ((**(this as *const *const IWRdsProtocolManager_Vtbl)).NotifySessionStateChange)(this, sessionid, eventid), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifySettingsChange(this: *mut core::ffi::c_void, pwrdssettings: *const WRDS_SETTINGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolManager", "NotifySettingsChange", 9usize, |this| ((**(this as *const *const IWRdsProtocolManager_Vtbl)).NotifySettingsChange)(this, pwrdssettings), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uninitialize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolManager", "Uninitialize", 10usize, |this| ((**(this as *const *const IWRdsProtocolManager_Vtbl)).Uninitialize)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            CreateListener,
+            NotifyServiceStateChange,
+            NotifySessionOfServiceStart,
+            NotifySessionOfServiceStop,
+            NotifySessionStateChange,
+            NotifySettingsChange,
+            Uninitialize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWRdsProtocolManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWRdsProtocolSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSettings(this: *mut core::ffi::c_void, wrdssettingtype: WRDS_SETTING_TYPE, wrdssettinglevel: WRDS_SETTING_LEVEL, pwrdssettings: *mut WRDS_SETTINGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolSettings", "GetSettings", 3usize, |this| ((**(this as *const *const IWRdsProtocolSettings_Vtbl)).GetSettings)(this, wrdssettingtype, wrdssettinglevel, pwrdssettings), |result| Some(*result))
+        }
+        unsafe extern "system" fn MergeSettings(this: *mut core::ffi::c_void, pwrdssettings: *const WRDS_SETTINGS, wrdsconnectionsettinglevel: WRDS_CONNECTION_SETTING_LEVEL, pwrdsconnectionsettings: *mut WRDS_CONNECTION_SETTINGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolSettings", "MergeSettings", 4usize, |this| ((**(this as *const *const IWRdsProtocolSettings_Vtbl)).MergeSettings)(this, pwrdssettings, wrdsconnectionsettinglevel, pwrdsconnectionsettings), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSettings, MergeSettings }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWRdsProtocolSettings as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWRdsProtocolShadowCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StopShadow(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolShadowCallback", "StopShadow", 3usize, |this| ((**(this as *const *const IWRdsProtocolShadowCallback_Vtbl)).StopShadow)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn InvokeTargetShadow(this: *mut core::ffi::c_void, ptargetservername: windows_core::PCWSTR, targetsessionid: u32, pparam1: *const u8, param1size: u32, pparam2: *const u8, param2size: u32, pparam3: *const u8, param3size: u32, pparam4: *const u8, param4size: u32, pclientname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolShadowCallback", "InvokeTargetShadow", 4usize, |this| ((**(this as *const *const IWRdsProtocolShadowCallback_Vtbl)).InvokeTargetShadow)(this, ptargetservername, targetsessionid, pparam1, param1size, pparam2, param2size, pparam3, param3size, pparam4, param4size, pclientname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StopShadow, InvokeTargetShadow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWRdsProtocolShadowCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWRdsProtocolShadowConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void, ptargetservername: windows_core::PCWSTR, targetsessionid: u32, hotkeyvk: u8, hotkeymodifiers: u16, pshadowcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolShadowConnection", "Start", 3usize, |this| ((**(this as *const *const IWRdsProtocolShadowConnection_Vtbl)).Start)(this, ptargetservername, targetsessionid, hotkeyvk, hotkeymodifiers, pshadowcallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolShadowConnection", "Stop", 4usize, |this| ((**(this as *const *const IWRdsProtocolShadowConnection_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoTarget(this: *mut core::ffi::c_void, pparam1: *const u8, param1size: u32, pparam2: *const u8, param2size: u32, pparam3: *const u8, param3size: u32, pparam4: *const u8, param4size: u32, pclientname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsProtocolShadowConnection", "DoTarget", 5usize, |this| ((**(this as *const *const IWRdsProtocolShadowConnection_Vtbl)).DoTarget)(this, pparam1, param1size, pparam2, param2size, pparam3, param3size, pparam4, param4size, pclientname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Start, Stop, DoTarget }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWRdsProtocolShadowConnection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWRdsWddmIddProps_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetHardwareId(this: *mut core::ffi::c_void, pdisplaydriverhardwareid: windows_core::PCWSTR, count: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsWddmIddProps", "GetHardwareId", 3usize, |this| ((**(this as *const *const IWRdsWddmIddProps_Vtbl)).GetHardwareId)(this, pdisplaydriverhardwareid, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDriverLoad(this: *mut core::ffi::c_void, sessionid: u32, driverhandle: super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsWddmIddProps", "OnDriverLoad", 4usize, |this| ((**(this as *const *const IWRdsWddmIddProps_Vtbl)).OnDriverLoad)(this, sessionid, driverhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDriverUnload(this: *mut core::ffi::c_void, sessionid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsWddmIddProps", "OnDriverUnload", 5usize, |this| ((**(this as *const *const IWRdsWddmIddProps_Vtbl)).OnDriverUnload)(this, sessionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableWddmIdd(this: *mut core::ffi::c_void, enabled: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsWddmIddProps", "EnableWddmIdd", 6usize, |this| ((**(this as *const *const IWRdsWddmIddProps_Vtbl)).EnableWddmIdd)(this, enabled), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetHardwareId, OnDriverLoad, OnDriverUnload, EnableWddmIdd }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWRdsWddmIddProps as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWRdsWddmIddProps1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetHardwareId(this: *mut core::ffi::c_void, pdisplaydriverhardwareid: windows_core::PCWSTR, count: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsWddmIddProps1", "GetHardwareId", 3usize, |this| ((**(this as *const *const IWRdsWddmIddProps1_Vtbl)).GetHardwareId)(this, pdisplaydriverhardwareid, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDriverLoad(this: *mut core::ffi::c_void, sessionid: u32, deviceinstance: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsWddmIddProps1", "OnDriverLoad", 4usize, |this| ((**(this as *const *const IWRdsWddmIddProps1_Vtbl)).OnDriverLoad)(this, sessionid, deviceinstance), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDriverUnload(this: *mut core::ffi::c_void, sessionid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWRdsWddmIddProps1", "OnDriverUnload", 5usize, |this| ((**(this as *const *const IWRdsWddmIddProps1_Vtbl)).OnDriverUnload)(this, sessionid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetHardwareId, OnDriverLoad, OnDriverUnload }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWRdsWddmIddProps1 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWTSBitmapRenderService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMappedRenderer(this: *mut core::ffi::c_void, mappingid: u64, pmappedrenderercallback: *mut core::ffi::c_void, ppmappedrenderer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSBitmapRenderService", "GetMappedRenderer", 3usize, |this| ((**(this as *const *const IWTSBitmapRenderService_Vtbl)).GetMappedRenderer)(this, mappingid, pmappedrenderercallback, ppmappedrenderer), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMappedRenderer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWTSBitmapRenderService as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWTSBitmapRenderer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Render(this: *mut core::ffi::c_void, imageformat: windows_core::GUID, dwwidth: u32, dwheight: u32, cbstride: i32, cbimagebuffer: u32, pimagebuffer: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSBitmapRenderer", "Render", 3usize, |this| ((**(this as *const *const IWTSBitmapRenderer_Vtbl)).Render)(this, imageformat, dwwidth, dwheight, cbstride, cbimagebuffer, pimagebuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRendererStatistics(this: *mut core::ffi::c_void, pstatistics: *mut BITMAP_RENDERER_STATISTICS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSBitmapRenderer", "GetRendererStatistics", 4usize, |this| ((**(this as *const *const IWTSBitmapRenderer_Vtbl)).GetRendererStatistics)(this, pstatistics), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveMapping(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSBitmapRenderer", "RemoveMapping", 5usize, |this| ((**(this as *const *const IWTSBitmapRenderer_Vtbl)).RemoveMapping)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Render, GetRendererStatistics, RemoveMapping }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWTSBitmapRenderer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWTSBitmapRendererCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnTargetSizeChanged(this: *mut core::ffi::c_void, rcnewsize: super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSBitmapRendererCallback", "OnTargetSizeChanged", 3usize, |this| ((**(this as *const *const IWTSBitmapRendererCallback_Vtbl)).OnTargetSizeChanged)(this, rcnewsize), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnTargetSizeChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWTSBitmapRendererCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for IWTSListener_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn GetConfiguration(this: *mut core::ffi::c_void, pppropertybag: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSListener", "GetConfiguration", 3usize, |this| ((**(this as *const *const IWTSListener_Vtbl)).GetConfiguration)(this, pppropertybag), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            GetConfiguration,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            GetConfiguration: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWTSListener as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWTSListenerCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnNewChannelConnection(this: *mut core::ffi::c_void, pchannel: *mut core::ffi::c_void, data: core::mem::MaybeUninit<windows_core::BSTR>, pbaccept: *mut super::super::Foundation::BOOL, ppcallback: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSListenerCallback", "OnNewChannelConnection", 3usize, |this| ((**(this as *const *const IWTSListenerCallback_Vtbl)).OnNewChannelConnection)(this, pchannel, data, pbaccept, ppcallback), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnNewChannelConnection }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWTSListenerCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWTSPlugin_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pchannelmgr: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSPlugin", "Initialize", 3usize, |this| ((**(this as *const *const IWTSPlugin_Vtbl)).Initialize)(this, pchannelmgr), |result| Some(*result))
+        }
+        unsafe extern "system" fn Connected(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSPlugin", "Connected", 4usize, |this| ((**(this as *const *const IWTSPlugin_Vtbl)).Connected)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnected(this: *mut core::ffi::c_void, dwdisconnectcode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSPlugin", "Disconnected", 5usize, |this| ((**(this as *const *const IWTSPlugin_Vtbl)).Disconnected)(this, dwdisconnectcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn Terminated(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSPlugin", "Terminated", 6usize, |this| ((**(this as *const *const IWTSPlugin_Vtbl)).Terminated)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, Connected, Disconnected, Terminated }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWTSPlugin as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWTSPluginServiceProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetService(this: *mut core::ffi::c_void, serviceid: windows_core::GUID, ppunkobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSPluginServiceProvider", "GetService", 3usize, |this| ((**(this as *const *const IWTSPluginServiceProvider_Vtbl)).GetService)(this, serviceid, ppunkobject), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetService }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWTSPluginServiceProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWTSProtocolConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLogonErrorRedirector(this: *mut core::ffi::c_void, pplogonerrorredir: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolConnection", "GetLogonErrorRedirector", 3usize, |this| ((**(this as *const *const IWTSProtocolConnection_Vtbl)).GetLogonErrorRedirector)(this, pplogonerrorredir), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendPolicyData(this: *mut core::ffi::c_void, ppolicydata: *const WTS_POLICY_DATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolConnection", "SendPolicyData", 4usize, |this| ((**(this as *const *const IWTSProtocolConnection_Vtbl)).SendPolicyData)(this, ppolicydata), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcceptConnection(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolConnection", "AcceptConnection", 5usize, |this| ((**(this as *const *const IWTSProtocolConnection_Vtbl)).AcceptConnection)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClientData(this: *mut core::ffi::c_void, pclientdata: *mut WTS_CLIENT_DATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolConnection", "GetClientData", 6usize, |this| ((**(this as *const *const IWTSProtocolConnection_Vtbl)).GetClientData)(this, pclientdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUserCredentials(this: *mut core::ffi::c_void, pusercreds: *mut WTS_USER_CREDENTIAL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolConnection", "GetUserCredentials", 7usize, |this| ((**(this as *const *const IWTSProtocolConnection_Vtbl)).GetUserCredentials)(this, pusercreds), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLicenseConnection(this: *mut core::ffi::c_void, pplicenseconnection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolConnection", "GetLicenseConnection", 8usize, |this| ((**(this as *const *const IWTSProtocolConnection_Vtbl)).GetLicenseConnection)(this, pplicenseconnection), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthenticateClientToSession(this: *mut core::ffi::c_void, sessionid: *mut WTS_SESSION_ID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolConnection", "AuthenticateClientToSession", 9usize, |this| ((**(this as *const *const IWTSProtocolConnection_Vtbl)).AuthenticateClientToSession)(this, sessionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifySessionId(this: *mut core::ffi::c_void, sessionid: *const WTS_SESSION_ID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolConnection", "NotifySessionId", 10usize, |this| ((**(this as *const *const IWTSProtocolConnection_Vtbl)).NotifySessionId)(this, sessionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProtocolHandles(this: *mut core::ffi::c_void, pkeyboardhandle: *mut super::super::Foundation::HANDLE_PTR, pmousehandle: *mut super::super::Foundation::HANDLE_PTR, pbeephandle: *mut super::super::Foundation::HANDLE_PTR, pvideohandle: *mut super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolConnection", "GetProtocolHandles", 11usize, |this| ((**(this as *const *const IWTSProtocolConnection_Vtbl)).GetProtocolHandles)(this, pkeyboardhandle, pmousehandle, pbeephandle, pvideohandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectNotify(this: *mut core::ffi::c_void, sessionid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolConnection", "ConnectNotify", 12usize, |this| ((**(this as *const *const IWTSProtocolConnection_Vtbl)).ConnectNotify)(this, sessionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUserAllowedToLogon(this: *mut core::ffi::c_void, sessionid: u32, usertoken: super::super::Foundation::HANDLE_PTR, pdomainname: windows_core::PCWSTR, pusername: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolConnection", "IsUserAllowedToLogon", 13usize, |this| ((**(this as *const *const IWTSProtocolConnection_Vtbl)).IsUserAllowedToLogon)(this, sessionid, usertoken, pdomainname, pusername), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionArbitrationEnumeration(this: *mut core::ffi::c_void, husertoken: super::super::Foundation::HANDLE_PTR, bsinglesessionperuserenabled: super::super::Foundation::BOOL, psessionidarray: *mut u32, pdwsessionidentifiercount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolConnection", "SessionArbitrationEnumeration", 14usize, |this| ((**(this as *const *const IWTSProtocolConnection_Vtbl)).SessionArbitrationEnumeration)(this, husertoken, bsinglesessionperuserenabled, psessionidarray, pdwsessionidentifiercount), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogonNotify(this: *mut core::ffi::c_void, hclienttoken: super::super::Foundation::HANDLE_PTR, wszusername: windows_core::PCWSTR, wszdomainname: windows_core::PCWSTR, sessionid: *const WTS_SESSION_ID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolConnection", "LogonNotify", 15usize, |this| ((**(this as *const *const IWTSProtocolConnection_Vtbl)).LogonNotify)(this, hclienttoken, wszusername, wszdomainname, sessionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUserData(this: *mut core::ffi::c_void, ppolicydata: *const WTS_POLICY_DATA, pclientdata: *mut WTS_USER_DATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolConnection", "GetUserData", 16usize, |this| ((**(this as *const *const IWTSProtocolConnection_Vtbl)).GetUserData)(this, ppolicydata, pclientdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisconnectNotify(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolConnection", "DisconnectNotify", 17usize, |this| ((**(this as *const *const IWTSProtocolConnection_Vtbl)).DisconnectNotify)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolConnection", "Close", 18usize, |this| ((**(this as *const *const IWTSProtocolConnection_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProtocolStatus(this: *mut core::ffi::c_void, pprotocolstatus: *mut WTS_PROTOCOL_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolConnection", "GetProtocolStatus", 19usize, |this| ((**(this as *const *const IWTSProtocolConnection_Vtbl)).GetProtocolStatus)(this, pprotocolstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastInputTime(this: *mut core::ffi::c_void, plastinputtime: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolConnection", "GetLastInputTime", 20usize, |this| ((**(this as *const *const IWTSProtocolConnection_Vtbl)).GetLastInputTime)(this, plastinputtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetErrorInfo(this: *mut core::ffi::c_void, ulerror: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolConnection", "SetErrorInfo", 21usize, |this| ((**(this as *const *const IWTSProtocolConnection_Vtbl)).SetErrorInfo)(this, ulerror), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendBeep(this: *mut core::ffi::c_void, frequency: u32, duration: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolConnection", "SendBeep", 22usize, |this| ((**(this as *const *const IWTSProtocolConnection_Vtbl)).SendBeep)(this, frequency, duration), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateVirtualChannel(this: *mut core::ffi::c_void, szendpointname: windows_core::PCSTR, bstatic: super::super::Foundation::BOOL, requestedpriority: u32, phchannel: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolConnection", "CreateVirtualChannel", 23usize, |this| ((**(this as *const *const IWTSProtocolConnection_Vtbl)).CreateVirtualChannel)(this, szendpointname, bstatic, requestedpriority, phchannel), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryProperty(this: *mut core::ffi::c_void, querytype: windows_core::GUID, ulnumentriesin: u32, ulnumentriesout: u32, ppropertyentriesin: *const WTS_PROPERTY_VALUE, ppropertyentriesout: *mut WTS_PROPERTY_VALUE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolConnection", "QueryProperty", 24usize, |this| ((**(this as *const *const IWTSProtocolConnection_Vtbl)).QueryProperty)(this, querytype, ulnumentriesin, ulnumentriesout, ppropertyentriesin, ppropertyentriesout), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetShadowConnection(this: *mut core::ffi::c_void, ppshadowconnection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolConnection", "GetShadowConnection", 25usize, |this| ((**(this as *const *const IWTSProtocolConnection_Vtbl)).GetShadowConnection)(this, ppshadowconnection), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetLogonErrorRedirector,
+            SendPolicyData,
+            AcceptConnection,
+            GetClientData,
+            GetUserCredentials,
+            GetLicenseConnection,
+            AuthenticateClientToSession,
+            NotifySessionId,
+            GetProtocolHandles,
+            ConnectNotify,
+            IsUserAllowedToLogon,
+            SessionArbitrationEnumeration,
+            LogonNotify,
+            GetUserData,
+            DisconnectNotify,
+            Close,
+            GetProtocolStatus,
+            GetLastInputTime,
+            SetErrorInfo,
+            SendBeep,
+            CreateVirtualChannel,
+            QueryProperty,
+            GetShadowConnection,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWTSProtocolConnection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWTSProtocolConnectionCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnReady(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolConnectionCallback", "OnReady", 3usize, |this| ((**(this as *const *const IWTSProtocolConnectionCallback_Vtbl)).OnReady)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn BrokenConnection(this: *mut core::ffi::c_void, reason: u32, source: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolConnectionCallback", "BrokenConnection", 4usize, |this| ((**(this as *const *const IWTSProtocolConnectionCallback_Vtbl)).BrokenConnection)(this, reason, source), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopScreenUpdates(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolConnectionCallback", "StopScreenUpdates", 5usize, |this| ((**(this as *const *const IWTSProtocolConnectionCallback_Vtbl)).StopScreenUpdates)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RedrawWindow(this: *mut core::ffi::c_void, rect: *const WTS_SMALL_RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolConnectionCallback", "RedrawWindow", 6usize, |this| ((**(this as *const *const IWTSProtocolConnectionCallback_Vtbl)).RedrawWindow)(this, rect), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayIOCtl(this: *mut core::ffi::c_void, displayioctl: *const WTS_DISPLAY_IOCTL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolConnectionCallback", "DisplayIOCtl", 7usize, |this| ((**(this as *const *const IWTSProtocolConnectionCallback_Vtbl)).DisplayIOCtl)(this, displayioctl), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnReady,
+            BrokenConnection,
+            StopScreenUpdates,
+            RedrawWindow,
+            DisplayIOCtl,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWTSProtocolConnectionCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWTSProtocolLicenseConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestLicensingCapabilities(this: *mut core::ffi::c_void, pplicensecapabilities: *mut WTS_LICENSE_CAPABILITIES, pcblicensecapabilities: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolLicenseConnection", "RequestLicensingCapabilities", 3usize, |this| ((**(this as *const *const IWTSProtocolLicenseConnection_Vtbl)).RequestLicensingCapabilities)(this, pplicensecapabilities, pcblicensecapabilities), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendClientLicense(this: *mut core::ffi::c_void, pclientlicense: *const u8, cbclientlicense: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolLicenseConnection", "SendClientLicense", 4usize, |this| ((**(this as *const *const IWTSProtocolLicenseConnection_Vtbl)).SendClientLicense)(this, pclientlicense, cbclientlicense), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestClientLicense(this: *mut core::ffi::c_void, reserve1: *const u8, reserve2: u32, ppclientlicense: *mut u8, pcbclientlicense: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolLicenseConnection", "RequestClientLicense", 5usize, |this| ((**(this as *const *const IWTSProtocolLicenseConnection_Vtbl)).RequestClientLicense)(this, reserve1, reserve2, ppclientlicense, pcbclientlicense), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProtocolComplete(this: *mut core::ffi::c_void, ulcomplete: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolLicenseConnection", "ProtocolComplete", 6usize, |this| ((**(this as *const *const IWTSProtocolLicenseConnection_Vtbl)).ProtocolComplete)(this, ulcomplete), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestLicensingCapabilities,
+            SendClientLicense,
+            RequestClientLicense,
+            ProtocolComplete,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWTSProtocolLicenseConnection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWTSProtocolListener_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartListen(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolListener", "StartListen", 3usize, |this| ((**(this as *const *const IWTSProtocolListener_Vtbl)).StartListen)(this, pcallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopListen(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolListener", "StopListen", 4usize, |this| ((**(this as *const *const IWTSProtocolListener_Vtbl)).StopListen)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartListen, StopListen }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWTSProtocolListener as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWTSProtocolListenerCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnConnected(this: *mut core::ffi::c_void, pconnection: *mut core::ffi::c_void, pcallback: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolListenerCallback", "OnConnected", 3usize, |this| ((**(this as *const *const IWTSProtocolListenerCallback_Vtbl)).OnConnected)(this, pconnection, pcallback), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnConnected }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWTSProtocolListenerCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWTSProtocolLogonErrorRedirector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnBeginPainting(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolLogonErrorRedirector", "OnBeginPainting", 3usize, |this| ((**(this as *const *const IWTSProtocolLogonErrorRedirector_Vtbl)).OnBeginPainting)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RedirectStatus(this: *mut core::ffi::c_void, pszmessage: windows_core::PCWSTR, presponse: *mut WTS_LOGON_ERROR_REDIRECTOR_RESPONSE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolLogonErrorRedirector", "RedirectStatus", 4usize, |this| ((**(this as *const *const IWTSProtocolLogonErrorRedirector_Vtbl)).RedirectStatus)(this, pszmessage, presponse), |result| Some(*result))
+        }
+        unsafe extern "system" fn RedirectMessage(this: *mut core::ffi::c_void, pszcaption: windows_core::PCWSTR, pszmessage: windows_core::PCWSTR, utype: u32, presponse: *mut WTS_LOGON_ERROR_REDIRECTOR_RESPONSE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolLogonErrorRedirector", "RedirectMessage", 5usize, |this| ((**(this as *const *const IWTSProtocolLogonErrorRedirector_Vtbl)).RedirectMessage)(this, pszcaption, pszmessage, utype, presponse), |result| Some(*result))
+        }
+        unsafe extern "system" fn RedirectLogonError(this: *mut core::ffi::c_void, ntsstatus: i32, ntssubstatus: i32, pszcaption: windows_core::PCWSTR, pszmessage: windows_core::PCWSTR, utype: u32, presponse: *mut WTS_LOGON_ERROR_REDIRECTOR_RESPONSE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolLogonErrorRedirector", "RedirectLogonError", 6usize, |this| ((**(this as *const *const IWTSProtocolLogonErrorRedirector_Vtbl)).RedirectLogonError)(this, ntsstatus, ntssubstatus, pszcaption, pszmessage, utype, presponse), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnBeginPainting,
+            RedirectStatus,
+            RedirectMessage,
+            RedirectLogonError,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWTSProtocolLogonErrorRedirector as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWTSProtocolManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateListener(this: *mut core::ffi::c_void, wszlistenername: windows_core::PCWSTR, pprotocollistener: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolManager", "CreateListener", 3usize, |this| ((**(this as *const *const IWTSProtocolManager_Vtbl)).CreateListener)(this, wszlistenername, pprotocollistener), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyServiceStateChange(this: *mut core::ffi::c_void, ptsservicestatechange: *const WTS_SERVICE_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolManager", "NotifyServiceStateChange", 4usize, |this| ((**(this as *const *const IWTSProtocolManager_Vtbl)).NotifyServiceStateChange)(this, ptsservicestatechange), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifySessionOfServiceStart(this: *mut core::ffi::c_void, sessionid: *const WTS_SESSION_ID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolManager", "NotifySessionOfServiceStart", 5usize, |this| ((**(this as *const *const IWTSProtocolManager_Vtbl)).NotifySessionOfServiceStart)(this, sessionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifySessionOfServiceStop(this: *mut core::ffi::c_void, sessionid: *const WTS_SESSION_ID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolManager", "NotifySessionOfServiceStop", 6usize, |this| ((**(this as *const *const IWTSProtocolManager_Vtbl)).NotifySessionOfServiceStop)(this, sessionid), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifySessionStateChange(this: *mut core::ffi::c_void, sessionid: *const WTS_SESSION_ID, eventid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolManager", "NotifySessionStateChange", 7usize, |this| ((**(this as *const *const IWTSProtocolManager_Vtbl)).NotifySessionStateChange)(this, sessionid, eventid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateListener,
+            NotifyServiceStateChange,
+            NotifySessionOfServiceStart,
+            NotifySessionOfServiceStop,
+            NotifySessionStateChange,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWTSProtocolManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWTSProtocolShadowCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StopShadow(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolShadowCallback", "StopShadow", 3usize, |this| ((**(this as *const *const IWTSProtocolShadowCallback_Vtbl)).StopShadow)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn InvokeTargetShadow(this: *mut core::ffi::c_void, ptargetservername: windows_core::PCWSTR, targetsessionid: u32, pparam1: *const u8, param1size: u32, pparam2: *const u8, param2size: u32, pparam3: *const u8, param3size: u32, pparam4: *const u8, param4size: u32, pclientname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolShadowCallback", "InvokeTargetShadow", 4usize, |this| ((**(this as *const *const IWTSProtocolShadowCallback_Vtbl)).InvokeTargetShadow)(this, ptargetservername, targetsessionid, pparam1, param1size, pparam2, param2size, pparam3, param3size, pparam4, param4size, pclientname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StopShadow, InvokeTargetShadow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWTSProtocolShadowCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWTSProtocolShadowConnection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void, ptargetservername: windows_core::PCWSTR, targetsessionid: u32, hotkeyvk: u8, hotkeymodifiers: u16, pshadowcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolShadowConnection", "Start", 3usize, |this| ((**(this as *const *const IWTSProtocolShadowConnection_Vtbl)).Start)(this, ptargetservername, targetsessionid, hotkeyvk, hotkeymodifiers, pshadowcallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolShadowConnection", "Stop", 4usize, |this| ((**(this as *const *const IWTSProtocolShadowConnection_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoTarget(this: *mut core::ffi::c_void, pparam1: *const u8, param1size: u32, pparam2: *const u8, param2size: u32, pparam3: *const u8, param3size: u32, pparam4: *const u8, param4size: u32, pclientname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSProtocolShadowConnection", "DoTarget", 5usize, |this| ((**(this as *const *const IWTSProtocolShadowConnection_Vtbl)).DoTarget)(this, pparam1, param1size, pparam2, param2size, pparam3, param3size, pparam4, param4size, pclientname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Start, Stop, DoTarget }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWTSProtocolShadowConnection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWTSSBPlugin_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, plugincapabilities: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSSBPlugin", "Initialize", 3usize, |this| ((**(this as *const *const IWTSSBPlugin_Vtbl)).Initialize)(this, plugincapabilities), |result| Some(*result))
+        }
+        unsafe extern "system" fn WTSSBX_MachineChangeNotification(this: *mut core::ffi::c_void, notificationtype: WTSSBX_NOTIFICATION_TYPE, machineid: i32, pmachineinfo: *const WTSSBX_MACHINE_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSSBPlugin", "WTSSBX_MachineChangeNotification", 4usize, |this| ((**(this as *const *const IWTSSBPlugin_Vtbl)).WTSSBX_MachineChangeNotification)(this, notificationtype, machineid, pmachineinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn WTSSBX_SessionChangeNotification(this: *mut core::ffi::c_void, notificationtype: WTSSBX_NOTIFICATION_TYPE, machineid: i32, numofsessions: u32, sessioninfo: *const WTSSBX_SESSION_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSSBPlugin", "WTSSBX_SessionChangeNotification", 5usize, |this| ((**(this as *const *const IWTSSBPlugin_Vtbl)).WTSSBX_SessionChangeNotification)(this, notificationtype, machineid, numofsessions, sessioninfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn WTSSBX_GetMostSuitableServer(this: *mut core::ffi::c_void, username: windows_core::PCWSTR, domainname: windows_core::PCWSTR, applicationtype: windows_core::PCWSTR, farmname: windows_core::PCWSTR, pmachineid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSSBPlugin", "WTSSBX_GetMostSuitableServer", 6usize, |this| ((**(this as *const *const IWTSSBPlugin_Vtbl)).WTSSBX_GetMostSuitableServer)(this, username, domainname, applicationtype, farmname, pmachineid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Terminated(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSSBPlugin", "Terminated", 7usize, |this| ((**(this as *const *const IWTSSBPlugin_Vtbl)).Terminated)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn WTSSBX_GetUserExternalSession(this: *mut core::ffi::c_void, username: windows_core::PCWSTR, domainname: windows_core::PCWSTR, applicationtype: windows_core::PCWSTR, redirectorinternalip: *const WTSSBX_IP_ADDRESS, psessionid: *mut u32, pmachineconnectinfo: *mut WTSSBX_MACHINE_CONNECT_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSSBPlugin", "WTSSBX_GetUserExternalSession", 8usize, |this| ((**(this as *const *const IWTSSBPlugin_Vtbl)).WTSSBX_GetUserExternalSession)(this, username, domainname, applicationtype, redirectorinternalip, psessionid, pmachineconnectinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            WTSSBX_MachineChangeNotification,
+            WTSSBX_SessionChangeNotification,
+            WTSSBX_GetMostSuitableServer,
+            Terminated,
+            WTSSBX_GetUserExternalSession,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWTSSBPlugin as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWTSVirtualChannel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Write(this: *mut core::ffi::c_void, cbsize: u32, pbuffer: *const u8, preserved: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSVirtualChannel", "Write", 3usize, |this| ((**(this as *const *const IWTSVirtualChannel_Vtbl)).Write)(this, cbsize, pbuffer, preserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSVirtualChannel", "Close", 4usize, |this| ((**(this as *const *const IWTSVirtualChannel_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Write, Close }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWTSVirtualChannel as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWTSVirtualChannelCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnDataReceived(this: *mut core::ffi::c_void, cbsize: u32, pbuffer: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSVirtualChannelCallback", "OnDataReceived", 3usize, |this| ((**(this as *const *const IWTSVirtualChannelCallback_Vtbl)).OnDataReceived)(this, cbsize, pbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnClose(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSVirtualChannelCallback", "OnClose", 4usize, |this| ((**(this as *const *const IWTSVirtualChannelCallback_Vtbl)).OnClose)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnDataReceived, OnClose }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWTSVirtualChannelCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWTSVirtualChannelManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateListener(this: *mut core::ffi::c_void, pszchannelname: windows_core::PCSTR, uflags: u32, plistenercallback: *mut core::ffi::c_void, pplistener: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWTSVirtualChannelManager", "CreateListener", 3usize, |this| ((**(this as *const *const IWTSVirtualChannelManager_Vtbl)).CreateListener)(this, pszchannelname, uflags, plistenercallback, pplistener), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateListener }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWTSVirtualChannelManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWorkspace_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetWorkspaceNames(this: *mut core::ffi::c_void, psawkspnames: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspace", "GetWorkspaceNames", 3usize, |this| ((**(this as *const *const IWorkspace_Vtbl)).GetWorkspaceNames)(this, psawkspnames), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn StartRemoteApplication(this: *mut core::ffi::c_void, bstrworkspaceid: core::mem::MaybeUninit<windows_core::BSTR>, psaparams: *const super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspace", "StartRemoteApplication", 4usize, |this| ((**(this as *const *const IWorkspace_Vtbl)).StartRemoteApplication)(this, bstrworkspaceid, psaparams), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProcessId(this: *mut core::ffi::c_void, pulprocessid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspace", "GetProcessId", 5usize, |this| ((**(this as *const *const IWorkspace_Vtbl)).GetProcessId)(this, pulprocessid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetWorkspaceNames,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetWorkspaceNames: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            StartRemoteApplication,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            StartRemoteApplication: 0,
+            GetProcessId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWorkspace as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWorkspace2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn StartRemoteApplicationEx(this: *mut core::ffi::c_void, bstrworkspaceid: core::mem::MaybeUninit<windows_core::BSTR>, bstrrequestingappid: core::mem::MaybeUninit<windows_core::BSTR>, bstrrequestingappfamilyname: core::mem::MaybeUninit<windows_core::BSTR>, blaunchintoimmersiveclient: super::super::Foundation::VARIANT_BOOL, bstrimmersiveclientactivationcontext: core::mem::MaybeUninit<windows_core::BSTR>, psaparams: *const super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspace2", "StartRemoteApplicationEx", 6usize, |this| ((**(this as *const *const IWorkspace2_Vtbl)).StartRemoteApplicationEx)(this, bstrworkspaceid, bstrrequestingappid, bstrrequestingappfamilyname, blaunchintoimmersiveclient, bstrimmersiveclientactivationcontext, psaparams), |result| Some(*result))
+        }
+        Self {
+            base__: <IWorkspace_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            StartRemoteApplicationEx,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            StartRemoteApplicationEx: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWorkspace2 as windows_core::Interface>::IID || <IWorkspace_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWorkspace3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetClaimsToken2(this: *mut core::ffi::c_void, bstrclaimshint: core::mem::MaybeUninit<windows_core::BSTR>, bstruserhint: core::mem::MaybeUninit<windows_core::BSTR>, claimcookie: u32, hwndcreduiparent: u32, rectcreduiparent: super::super::Foundation::RECT, pbstraccesstoken: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspace3", "GetClaimsToken2", 7usize, |this| ((**(this as *const *const IWorkspace3_Vtbl)).GetClaimsToken2)(this, bstrclaimshint, bstruserhint, claimcookie, hwndcreduiparent, rectcreduiparent, pbstraccesstoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClaimsToken(this: *mut core::ffi::c_void, bstraccesstoken: core::mem::MaybeUninit<windows_core::BSTR>, ullaccesstokenexpiration: u64, bstrrefreshtoken: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspace3", "SetClaimsToken", 8usize, |this| ((**(this as *const *const IWorkspace3_Vtbl)).SetClaimsToken)(this, bstraccesstoken, ullaccesstokenexpiration, bstrrefreshtoken), |result| Some(*result))
+        }
+        Self { base__: <IWorkspace2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetClaimsToken2, SetClaimsToken }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWorkspace3 as windows_core::Interface>::IID || <IWorkspace2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWorkspaceClientExt_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetResourceId(this: *mut core::ffi::c_void, bstrworkspaceid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspaceClientExt", "GetResourceId", 3usize, |this| ((**(this as *const *const IWorkspaceClientExt_Vtbl)).GetResourceId)(this, bstrworkspaceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResourceDisplayName(this: *mut core::ffi::c_void, bstrworkspacedisplayname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspaceClientExt", "GetResourceDisplayName", 4usize, |this| ((**(this as *const *const IWorkspaceClientExt_Vtbl)).GetResourceDisplayName)(this, bstrworkspacedisplayname), |result| Some(*result))
+        }
+        unsafe extern "system" fn IssueDisconnect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspaceClientExt", "IssueDisconnect", 5usize, |this| ((**(this as *const *const IWorkspaceClientExt_Vtbl)).IssueDisconnect)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetResourceId, GetResourceDisplayName, IssueDisconnect }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWorkspaceClientExt as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWorkspaceRegistration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddResource(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspaceRegistration", "AddResource", 3usize, |this| ((**(this as *const *const IWorkspaceRegistration_Vtbl)).AddResource)(this, punk, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveResource(this: *mut core::ffi::c_void, dwcookieconnection: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspaceRegistration", "RemoveResource", 4usize, |this| ((**(this as *const *const IWorkspaceRegistration_Vtbl)).RemoveResource)(this, dwcookieconnection), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddResource, RemoveResource }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWorkspaceRegistration as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWorkspaceRegistration2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddResourceEx(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void, bstreventloguploadaddress: core::mem::MaybeUninit<windows_core::BSTR>, pdwcookie: *mut u32, correlationid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspaceRegistration2", "AddResourceEx", 5usize, |this| ((**(this as *const *const IWorkspaceRegistration2_Vtbl)).AddResourceEx)(this, punk, bstreventloguploadaddress, pdwcookie, correlationid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveResourceEx(this: *mut core::ffi::c_void, dwcookieconnection: u32, correlationid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspaceRegistration2", "RemoveResourceEx", 6usize, |this| ((**(this as *const *const IWorkspaceRegistration2_Vtbl)).RemoveResourceEx)(this, dwcookieconnection, correlationid), |result| Some(*result))
+        }
+        Self { base__: <IWorkspaceRegistration_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddResourceEx, RemoveResourceEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWorkspaceRegistration2 as windows_core::Interface>::IID || <IWorkspaceRegistration_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWorkspaceReportMessage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterErrorLogMessage(this: *mut core::ffi::c_void, bstrmessage: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspaceReportMessage", "RegisterErrorLogMessage", 3usize, |this| ((**(this as *const *const IWorkspaceReportMessage_Vtbl)).RegisterErrorLogMessage)(this, bstrmessage), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsErrorMessageRegistered(this: *mut core::ffi::c_void, bstrwkspid: core::mem::MaybeUninit<windows_core::BSTR>, dwerrortype: u32, bstrerrormessagetype: core::mem::MaybeUninit<windows_core::BSTR>, dwerrorcode: u32, pferrorexist: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspaceReportMessage", "IsErrorMessageRegistered", 4usize, |this| ((**(this as *const *const IWorkspaceReportMessage_Vtbl)).IsErrorMessageRegistered)(this, bstrwkspid, dwerrortype, bstrerrormessagetype, dwerrorcode, pferrorexist), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterErrorEvent(this: *mut core::ffi::c_void, bstrwkspid: core::mem::MaybeUninit<windows_core::BSTR>, dwerrortype: u32, bstrerrormessagetype: core::mem::MaybeUninit<windows_core::BSTR>, dwerrorcode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspaceReportMessage", "RegisterErrorEvent", 5usize, |this| ((**(this as *const *const IWorkspaceReportMessage_Vtbl)).RegisterErrorEvent)(this, bstrwkspid, dwerrortype, bstrerrormessagetype, dwerrorcode), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RegisterErrorLogMessage,
+            IsErrorMessageRegistered,
+            RegisterErrorEvent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWorkspaceReportMessage as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWorkspaceResTypeRegistry_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddResourceType(this: *mut core::ffi::c_void, fmachinewide: super::super::Foundation::VARIANT_BOOL, bstrfileextension: core::mem::MaybeUninit<windows_core::BSTR>, bstrlauncher: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspaceResTypeRegistry", "AddResourceType", 7usize, |this| ((**(this as *const *const IWorkspaceResTypeRegistry_Vtbl)).AddResourceType)(this, fmachinewide, bstrfileextension, bstrlauncher), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteResourceType(this: *mut core::ffi::c_void, fmachinewide: super::super::Foundation::VARIANT_BOOL, bstrfileextension: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspaceResTypeRegistry", "DeleteResourceType", 8usize, |this| ((**(this as *const *const IWorkspaceResTypeRegistry_Vtbl)).DeleteResourceType)(this, fmachinewide, bstrfileextension), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetRegisteredFileExtensions(this: *mut core::ffi::c_void, fmachinewide: super::super::Foundation::VARIANT_BOOL, psafileextensions: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspaceResTypeRegistry", "GetRegisteredFileExtensions", 9usize, |this| ((**(this as *const *const IWorkspaceResTypeRegistry_Vtbl)).GetRegisteredFileExtensions)(this, fmachinewide, psafileextensions), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResourceTypeInfo(this: *mut core::ffi::c_void, fmachinewide: super::super::Foundation::VARIANT_BOOL, bstrfileextension: core::mem::MaybeUninit<windows_core::BSTR>, pbstrlauncher: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspaceResTypeRegistry", "GetResourceTypeInfo", 10usize, |this| ((**(this as *const *const IWorkspaceResTypeRegistry_Vtbl)).GetResourceTypeInfo)(this, fmachinewide, bstrfileextension, pbstrlauncher), |result| Some(*result))
+        }
+        unsafe extern "system" fn ModifyResourceType(this: *mut core::ffi::c_void, fmachinewide: super::super::Foundation::VARIANT_BOOL, bstrfileextension: core::mem::MaybeUninit<windows_core::BSTR>, bstrlauncher: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspaceResTypeRegistry", "ModifyResourceType", 11usize, |this| ((**(this as *const *const IWorkspaceResTypeRegistry_Vtbl)).ModifyResourceType)(this, fmachinewide, bstrfileextension, bstrlauncher), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddResourceType,
+            DeleteResourceType,
+            #[cfg(feature = "Win32_System_Com")]
+            GetRegisteredFileExtensions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetRegisteredFileExtensions: 0,
+            GetResourceTypeInfo,
+            ModifyResourceType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWorkspaceResTypeRegistry as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWorkspaceScriptable_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisconnectWorkspace(this: *mut core::ffi::c_void, bstrworkspaceid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspaceScriptable", "DisconnectWorkspace", 7usize, |this| ((**(this as *const *const IWorkspaceScriptable_Vtbl)).DisconnectWorkspace)(this, bstrworkspaceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartWorkspace(this: *mut core::ffi::c_void, bstrworkspaceid: core::mem::MaybeUninit<windows_core::BSTR>, bstrusername: core::mem::MaybeUninit<windows_core::BSTR>, bstrpassword: core::mem::MaybeUninit<windows_core::BSTR>, bstrworkspaceparams: core::mem::MaybeUninit<windows_core::BSTR>, ltimeout: i32, lflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspaceScriptable", "StartWorkspace", 8usize, |this| ((**(this as *const *const IWorkspaceScriptable_Vtbl)).StartWorkspace)(this, bstrworkspaceid, bstrusername, bstrpassword, bstrworkspaceparams, ltimeout, lflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsWorkspaceCredentialSpecified(this: *mut core::ffi::c_void, bstrworkspaceid: core::mem::MaybeUninit<windows_core::BSTR>, bcountunauthenticatedcredentials: super::super::Foundation::VARIANT_BOOL, pbcredexist: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspaceScriptable", "IsWorkspaceCredentialSpecified", 9usize, |this| ((**(this as *const *const IWorkspaceScriptable_Vtbl)).IsWorkspaceCredentialSpecified)(this, bstrworkspaceid, bcountunauthenticatedcredentials, pbcredexist), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsWorkspaceSSOEnabled(this: *mut core::ffi::c_void, pbssoenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspaceScriptable", "IsWorkspaceSSOEnabled", 10usize, |this| ((**(this as *const *const IWorkspaceScriptable_Vtbl)).IsWorkspaceSSOEnabled)(this, pbssoenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearWorkspaceCredential(this: *mut core::ffi::c_void, bstrworkspaceid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspaceScriptable", "ClearWorkspaceCredential", 11usize, |this| ((**(this as *const *const IWorkspaceScriptable_Vtbl)).ClearWorkspaceCredential)(this, bstrworkspaceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnAuthenticated(this: *mut core::ffi::c_void, bstrworkspaceid: core::mem::MaybeUninit<windows_core::BSTR>, bstrusername: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspaceScriptable", "OnAuthenticated", 12usize, |this| ((**(this as *const *const IWorkspaceScriptable_Vtbl)).OnAuthenticated)(this, bstrworkspaceid, bstrusername), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisconnectWorkspaceByFriendlyName(this: *mut core::ffi::c_void, bstrworkspacefriendlyname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspaceScriptable", "DisconnectWorkspaceByFriendlyName", 13usize, |this| ((**(this as *const *const IWorkspaceScriptable_Vtbl)).DisconnectWorkspaceByFriendlyName)(this, bstrworkspacefriendlyname), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DisconnectWorkspace,
+            StartWorkspace,
+            IsWorkspaceCredentialSpecified,
+            IsWorkspaceSSOEnabled,
+            ClearWorkspaceCredential,
+            OnAuthenticated,
+            DisconnectWorkspaceByFriendlyName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWorkspaceScriptable as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWorkspaceScriptable2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartWorkspaceEx(this: *mut core::ffi::c_void, bstrworkspaceid: core::mem::MaybeUninit<windows_core::BSTR>, bstrworkspacefriendlyname: core::mem::MaybeUninit<windows_core::BSTR>, bstrredirectorname: core::mem::MaybeUninit<windows_core::BSTR>, bstrusername: core::mem::MaybeUninit<windows_core::BSTR>, bstrpassword: core::mem::MaybeUninit<windows_core::BSTR>, bstrappcontainer: core::mem::MaybeUninit<windows_core::BSTR>, bstrworkspaceparams: core::mem::MaybeUninit<windows_core::BSTR>, ltimeout: i32, lflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspaceScriptable2", "StartWorkspaceEx", 14usize, |this| ((**(this as *const *const IWorkspaceScriptable2_Vtbl)).StartWorkspaceEx)(this, bstrworkspaceid, bstrworkspacefriendlyname, bstrredirectorname, bstrusername, bstrpassword, bstrappcontainer, bstrworkspaceparams, ltimeout, lflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResourceDismissed(this: *mut core::ffi::c_void, bstrworkspaceid: core::mem::MaybeUninit<windows_core::BSTR>, bstrworkspacefriendlyname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspaceScriptable2", "ResourceDismissed", 15usize, |this| ((**(this as *const *const IWorkspaceScriptable2_Vtbl)).ResourceDismissed)(this, bstrworkspaceid, bstrworkspacefriendlyname), |result| Some(*result))
+        }
+        Self { base__: <IWorkspaceScriptable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartWorkspaceEx, ResourceDismissed }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWorkspaceScriptable2 as windows_core::Interface>::IID || <IWorkspaceScriptable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWorkspaceScriptable3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartWorkspaceEx2(this: *mut core::ffi::c_void, bstrworkspaceid: core::mem::MaybeUninit<windows_core::BSTR>, bstrworkspacefriendlyname: core::mem::MaybeUninit<windows_core::BSTR>, bstrredirectorname: core::mem::MaybeUninit<windows_core::BSTR>, bstrusername: core::mem::MaybeUninit<windows_core::BSTR>, bstrpassword: core::mem::MaybeUninit<windows_core::BSTR>, bstrappcontainer: core::mem::MaybeUninit<windows_core::BSTR>, bstrworkspaceparams: core::mem::MaybeUninit<windows_core::BSTR>, ltimeout: i32, lflags: i32, bstreventloguploadaddress: core::mem::MaybeUninit<windows_core::BSTR>, correlationid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWorkspaceScriptable3", "StartWorkspaceEx2", 16usize, |this| ((**(this as *const *const IWorkspaceScriptable3_Vtbl)).StartWorkspaceEx2)(this, bstrworkspaceid, bstrworkspacefriendlyname, bstrredirectorname, bstrusername, bstrpassword, bstrappcontainer, bstrworkspaceparams, ltimeout, lflags, bstreventloguploadaddress, correlationid), |result| Some(*result))
+        }
+        Self { base__: <IWorkspaceScriptable2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartWorkspaceEx2 }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWorkspaceScriptable3 as windows_core::Interface>::IID || <IWorkspaceScriptable2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ItsPubPlugin_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetResourceList(this: *mut core::ffi::c_void, userid: windows_core::PCWSTR, pceapplistsize: *mut i32, resourcelist: *mut *mut pluginResource) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ItsPubPlugin", "GetResourceList", 3usize, |this| ((**(this as *const *const ItsPubPlugin_Vtbl)).GetResourceList)(this, userid, pceapplistsize, resourcelist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResource(this: *mut core::ffi::c_void, alias: windows_core::PCWSTR, flags: i32, resource: *mut pluginResource) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ItsPubPlugin", "GetResource", 4usize, |this| ((**(this as *const *const ItsPubPlugin_Vtbl)).GetResource)(this, alias, flags, resource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCacheLastUpdateTime(this: *mut core::ffi::c_void, lastupdatetime: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ItsPubPlugin", "GetCacheLastUpdateTime", 5usize, |this| ((**(this as *const *const ItsPubPlugin_Vtbl)).GetCacheLastUpdateTime)(this, lastupdatetime), |result| Some(*result))
+        }
+        unsafe extern "system" fn pluginName(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ItsPubPlugin", "pluginName", 6usize, |this| ((**(this as *const *const ItsPubPlugin_Vtbl)).pluginName)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn pluginVersion(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ItsPubPlugin", "pluginVersion", 7usize, |this| ((**(this as *const *const ItsPubPlugin_Vtbl)).pluginVersion)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResolveResource(this: *mut core::ffi::c_void, resourcetype: *mut u32, resourcelocation: windows_core::PWSTR, endpointname: windows_core::PWSTR, userid: windows_core::PCWSTR, alias: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ItsPubPlugin", "ResolveResource", 8usize, |this| ((**(this as *const *const ItsPubPlugin_Vtbl)).ResolveResource)(this, resourcetype, resourcelocation, endpointname, userid, alias), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetResourceList,
+            GetResource,
+            GetCacheLastUpdateTime,
+            pluginName,
+            pluginVersion,
+            ResolveResource,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ItsPubPlugin as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ItsPubPlugin2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetResource2List(this: *mut core::ffi::c_void, userid: windows_core::PCWSTR, pceapplistsize: *mut i32, resourcelist: *mut *mut pluginResource2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ItsPubPlugin2", "GetResource2List", 9usize, |this| ((**(this as *const *const ItsPubPlugin2_Vtbl)).GetResource2List)(this, userid, pceapplistsize, resourcelist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResource2(this: *mut core::ffi::c_void, alias: windows_core::PCWSTR, flags: i32, resource: *mut pluginResource2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ItsPubPlugin2", "GetResource2", 10usize, |this| ((**(this as *const *const ItsPubPlugin2_Vtbl)).GetResource2)(this, alias, flags, resource), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResolvePersonalDesktop(this: *mut core::ffi::c_void, userid: windows_core::PCWSTR, poolid: windows_core::PCWSTR, epdresolutiontype: TSPUB_PLUGIN_PD_RESOLUTION_TYPE, ppdassignmenttype: *mut TSPUB_PLUGIN_PD_ASSIGNMENT_TYPE, endpointname: windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ItsPubPlugin2", "ResolvePersonalDesktop", 11usize, |this| ((**(this as *const *const ItsPubPlugin2_Vtbl)).ResolvePersonalDesktop)(this, userid, poolid, epdresolutiontype, ppdassignmenttype, endpointname), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeletePersonalDesktopAssignment(this: *mut core::ffi::c_void, userid: windows_core::PCWSTR, poolid: windows_core::PCWSTR, endpointname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ItsPubPlugin2", "DeletePersonalDesktopAssignment", 12usize, |this| ((**(this as *const *const ItsPubPlugin2_Vtbl)).DeletePersonalDesktopAssignment)(this, userid, poolid, endpointname), |result| Some(*result))
+        }
+        Self {
+            base__: <ItsPubPlugin_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetResource2List,
+            GetResource2,
+            ResolvePersonalDesktop,
+            DeletePersonalDesktopAssignment,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ItsPubPlugin2 as windows_core::Interface>::IID || <ItsPubPlugin_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for _ITSWkspEvents_Vtbl {
+    const PROXY: Self = { Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<_ITSWkspEvents as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
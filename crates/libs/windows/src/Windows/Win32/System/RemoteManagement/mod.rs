@@ -1588,6 +1588,7 @@ pub struct WSManAuthenticationFlags(pub i32);
 impl windows_core::TypeKind for WSManAuthenticationFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WSManAuthenticationFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WSManAuthenticationFlags").field(&self.0).finish()
@@ -1599,6 +1600,7 @@ pub struct WSManCallbackFlags(pub i32);
 impl windows_core::TypeKind for WSManCallbackFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WSManCallbackFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WSManCallbackFlags").field(&self.0).finish()
@@ -1610,6 +1612,7 @@ pub struct WSManDataType(pub i32);
 impl windows_core::TypeKind for WSManDataType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WSManDataType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WSManDataType").field(&self.0).finish()
@@ -1621,6 +1624,7 @@ pub struct WSManEnumFlags(pub i32);
 impl windows_core::TypeKind for WSManEnumFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WSManEnumFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WSManEnumFlags").field(&self.0).finish()
@@ -1632,6 +1636,7 @@ pub struct WSManProxyAccessType(pub i32);
 impl windows_core::TypeKind for WSManProxyAccessType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WSManProxyAccessType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WSManProxyAccessType").field(&self.0).finish()
@@ -1643,6 +1648,7 @@ pub struct WSManProxyAccessTypeFlags(pub i32);
 impl windows_core::TypeKind for WSManProxyAccessTypeFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WSManProxyAccessTypeFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WSManProxyAccessTypeFlags").field(&self.0).finish()
@@ -1654,6 +1660,7 @@ pub struct WSManProxyAuthenticationFlags(pub i32);
 impl windows_core::TypeKind for WSManProxyAuthenticationFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WSManProxyAuthenticationFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WSManProxyAuthenticationFlags").field(&self.0).finish()
@@ -1665,6 +1672,7 @@ pub struct WSManSessionFlags(pub i32);
 impl windows_core::TypeKind for WSManSessionFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WSManSessionFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WSManSessionFlags").field(&self.0).finish()
@@ -1676,6 +1684,7 @@ pub struct WSManSessionOption(pub i32);
 impl windows_core::TypeKind for WSManSessionOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WSManSessionOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WSManSessionOption").field(&self.0).finish()
@@ -1687,13 +1696,15 @@ pub struct WSManShellFlag(pub i32);
 impl windows_core::TypeKind for WSManShellFlag {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WSManShellFlag {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WSManShellFlag").field(&self.0).finish()
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_API_HANDLE(pub isize);
 impl Default for WSMAN_API_HANDLE {
     fn default() -> Self {
@@ -1732,7 +1743,8 @@ impl Default for WSMAN_AUTHENTICATION_CREDENTIALS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_AUTHZ_QUOTA {
     pub maxAllowedConcurrentShells: u32,
     pub maxAllowedConcurrentOperations: u32,
@@ -1748,7 +1760,8 @@ impl Default for WSMAN_AUTHZ_QUOTA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_CERTIFICATE_DETAILS {
     pub subject: windows_core::PCWSTR,
     pub issuerName: windows_core::PCWSTR,
@@ -1764,7 +1777,8 @@ impl Default for WSMAN_CERTIFICATE_DETAILS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_COMMAND_ARG_SET {
     pub argsCount: u32,
     pub args: *const windows_core::PCWSTR,
@@ -1778,7 +1792,8 @@ impl Default for WSMAN_COMMAND_ARG_SET {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_COMMAND_HANDLE(pub isize);
 impl Default for WSMAN_COMMAND_HANDLE {
     fn default() -> Self {
@@ -1844,7 +1859,8 @@ impl Default for WSMAN_DATA_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_DATA_BINARY {
     pub dataLength: u32,
     pub data: *mut u8,
@@ -1858,7 +1874,8 @@ impl Default for WSMAN_DATA_BINARY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_DATA_TEXT {
     pub bufferLength: u32,
     pub buffer: windows_core::PCWSTR,
@@ -1872,7 +1889,8 @@ impl Default for WSMAN_DATA_TEXT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_ENVIRONMENT_VARIABLE {
     pub name: windows_core::PCWSTR,
     pub value: windows_core::PCWSTR,
@@ -1886,7 +1904,8 @@ impl Default for WSMAN_ENVIRONMENT_VARIABLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_ENVIRONMENT_VARIABLE_SET {
     pub varsCount: u32,
     pub vars: *mut WSMAN_ENVIRONMENT_VARIABLE,
@@ -1900,7 +1919,8 @@ impl Default for WSMAN_ENVIRONMENT_VARIABLE_SET {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_ERROR {
     pub code: u32,
     pub errorDetail: windows_core::PCWSTR,
@@ -1917,7 +1937,8 @@ impl Default for WSMAN_ERROR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_FILTER {
     pub filter: windows_core::PCWSTR,
     pub dialect: windows_core::PCWSTR,
@@ -1931,7 +1952,8 @@ impl Default for WSMAN_FILTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_FRAGMENT {
     pub path: windows_core::PCWSTR,
     pub dialect: windows_core::PCWSTR,
@@ -1945,7 +1967,8 @@ impl Default for WSMAN_FRAGMENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_KEY {
     pub key: windows_core::PCWSTR,
     pub value: windows_core::PCWSTR,
@@ -1959,7 +1982,8 @@ impl Default for WSMAN_KEY {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_OPERATION_HANDLE(pub isize);
 impl Default for WSMAN_OPERATION_HANDLE {
     fn default() -> Self {
@@ -1970,7 +1994,8 @@ impl windows_core::TypeKind for WSMAN_OPERATION_HANDLE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_OPERATION_INFO {
     pub fragment: WSMAN_FRAGMENT,
     pub filter: WSMAN_FILTER,
@@ -1988,7 +2013,8 @@ impl Default for WSMAN_OPERATION_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_OPERATION_INFOEX {
     pub fragment: WSMAN_FRAGMENT,
     pub filter: WSMAN_FILTER,
@@ -2007,7 +2033,8 @@ impl Default for WSMAN_OPERATION_INFOEX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_OPTION {
     pub name: windows_core::PCWSTR,
     pub value: windows_core::PCWSTR,
@@ -2022,7 +2049,8 @@ impl Default for WSMAN_OPTION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_OPTION_SET {
     pub optionsCount: u32,
     pub options: *mut WSMAN_OPTION,
@@ -2037,7 +2065,8 @@ impl Default for WSMAN_OPTION_SET {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_OPTION_SETEX {
     pub optionsCount: u32,
     pub options: *mut WSMAN_OPTION,
@@ -2053,7 +2082,8 @@ impl Default for WSMAN_OPTION_SETEX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_PLUGIN_REQUEST {
     pub senderDetails: *mut WSMAN_SENDER_DETAILS,
     pub locale: windows_core::PCWSTR,
@@ -2117,7 +2147,8 @@ impl Default for WSMAN_RESPONSE_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_SELECTOR_SET {
     pub numberKeys: u32,
     pub keys: *mut WSMAN_KEY,
@@ -2131,7 +2162,8 @@ impl Default for WSMAN_SELECTOR_SET {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_SENDER_DETAILS {
     pub senderName: windows_core::PCWSTR,
     pub authenticationMechanism: windows_core::PCWSTR,
@@ -2148,7 +2180,8 @@ impl Default for WSMAN_SENDER_DETAILS {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_SESSION_HANDLE(pub isize);
 impl Default for WSMAN_SESSION_HANDLE {
     fn default() -> Self {
@@ -2159,7 +2192,8 @@ impl windows_core::TypeKind for WSMAN_SESSION_HANDLE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_SHELL_ASYNC {
     pub operationContext: *mut core::ffi::c_void,
     pub completionFunction: WSMAN_SHELL_COMPLETION_FUNCTION,
@@ -2173,7 +2207,8 @@ impl Default for WSMAN_SHELL_ASYNC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_SHELL_DISCONNECT_INFO {
     pub idleTimeoutMs: u32,
 }
@@ -2186,7 +2221,8 @@ impl Default for WSMAN_SHELL_DISCONNECT_INFO {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_SHELL_HANDLE(pub isize);
 impl Default for WSMAN_SHELL_HANDLE {
     fn default() -> Self {
@@ -2197,7 +2233,8 @@ impl windows_core::TypeKind for WSMAN_SHELL_HANDLE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_SHELL_STARTUP_INFO_V10 {
     pub inputStreamSet: *mut WSMAN_STREAM_ID_SET,
     pub outputStreamSet: *mut WSMAN_STREAM_ID_SET,
@@ -2214,7 +2251,8 @@ impl Default for WSMAN_SHELL_STARTUP_INFO_V10 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_SHELL_STARTUP_INFO_V11 {
     pub Base: WSMAN_SHELL_STARTUP_INFO_V10,
     pub name: windows_core::PCWSTR,
@@ -2228,7 +2266,8 @@ impl Default for WSMAN_SHELL_STARTUP_INFO_V11 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_STREAM_ID_SET {
     pub streamIDsCount: u32,
     pub streamIDs: *const windows_core::PCWSTR,
@@ -2242,7 +2281,8 @@ impl Default for WSMAN_STREAM_ID_SET {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WSMAN_USERNAME_PASSWORD_CREDS {
     pub username: windows_core::PCWSTR,
     pub password: windows_core::PCWSTR,
@@ -2274,3 +2314,5 @@ pub type WSMAN_PLUGIN_STARTUP = Option<unsafe extern "system" fn(flags: u32, app
 pub type WSMAN_SHELL_COMPLETION_FUNCTION = Option<unsafe extern "system" fn(operationcontext: *const core::ffi::c_void, flags: u32, error: *const WSMAN_ERROR, shell: WSMAN_SHELL_HANDLE, command: WSMAN_COMMAND_HANDLE, operationhandle: WSMAN_OPERATION_HANDLE, data: *const WSMAN_RESPONSE_DATA)>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,438 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWSMan_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateSession(this: *mut core::ffi::c_void, connection: core::mem::MaybeUninit<windows_core::BSTR>, flags: i32, connectionoptions: *mut core::ffi::c_void, session: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSMan", "CreateSession", 7usize, |this| ((**(this as *const *const IWSMan_Vtbl)).CreateSession)(this, connection, flags, connectionoptions, session), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateConnectionOptions(this: *mut core::ffi::c_void, connectionoptions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSMan", "CreateConnectionOptions", 8usize, |this| ((**(this as *const *const IWSMan_Vtbl)).CreateConnectionOptions)(this, connectionoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommandLine(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSMan", "CommandLine", 9usize, |this| ((**(this as *const *const IWSMan_Vtbl)).CommandLine)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSMan", "Error", 10usize, |this| ((**(this as *const *const IWSMan_Vtbl)).Error)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateSession,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateSession: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateConnectionOptions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateConnectionOptions: 0,
+            CommandLine,
+            Error,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSMan as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWSManConnectionOptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UserName(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManConnectionOptions", "UserName", 7usize, |this| ((**(this as *const *const IWSManConnectionOptions_Vtbl)).UserName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUserName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManConnectionOptions", "SetUserName", 8usize, |this| ((**(this as *const *const IWSManConnectionOptions_Vtbl)).SetUserName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPassword(this: *mut core::ffi::c_void, password: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManConnectionOptions", "SetPassword", 9usize, |this| ((**(this as *const *const IWSManConnectionOptions_Vtbl)).SetPassword)(this, password), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UserName, SetUserName, SetPassword }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSManConnectionOptions as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWSManConnectionOptionsEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CertificateThumbprint(this: *mut core::ffi::c_void, thumbprint: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManConnectionOptionsEx", "CertificateThumbprint", 10usize, |this| ((**(this as *const *const IWSManConnectionOptionsEx_Vtbl)).CertificateThumbprint)(this, thumbprint), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCertificateThumbprint(this: *mut core::ffi::c_void, thumbprint: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManConnectionOptionsEx", "SetCertificateThumbprint", 11usize, |this| ((**(this as *const *const IWSManConnectionOptionsEx_Vtbl)).SetCertificateThumbprint)(this, thumbprint), |result| Some(*result))
+        }
+        Self { base__: <IWSManConnectionOptions_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CertificateThumbprint, SetCertificateThumbprint }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSManConnectionOptionsEx as windows_core::Interface>::IID || <IWSManConnectionOptions_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWSManConnectionOptionsEx2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetProxy(this: *mut core::ffi::c_void, accesstype: i32, authenticationmechanism: i32, username: core::mem::MaybeUninit<windows_core::BSTR>, password: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManConnectionOptionsEx2", "SetProxy", 12usize, |this| ((**(this as *const *const IWSManConnectionOptionsEx2_Vtbl)).SetProxy)(this, accesstype, authenticationmechanism, username, password), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProxyIEConfig(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManConnectionOptionsEx2", "ProxyIEConfig", 13usize, |this| ((**(this as *const *const IWSManConnectionOptionsEx2_Vtbl)).ProxyIEConfig)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProxyWinHttpConfig(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManConnectionOptionsEx2", "ProxyWinHttpConfig", 14usize, |this| ((**(this as *const *const IWSManConnectionOptionsEx2_Vtbl)).ProxyWinHttpConfig)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProxyAutoDetect(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManConnectionOptionsEx2", "ProxyAutoDetect", 15usize, |this| ((**(this as *const *const IWSManConnectionOptionsEx2_Vtbl)).ProxyAutoDetect)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProxyNoProxyServer(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManConnectionOptionsEx2", "ProxyNoProxyServer", 16usize, |this| ((**(this as *const *const IWSManConnectionOptionsEx2_Vtbl)).ProxyNoProxyServer)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProxyAuthenticationUseNegotiate(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManConnectionOptionsEx2", "ProxyAuthenticationUseNegotiate", 17usize, |this| ((**(this as *const *const IWSManConnectionOptionsEx2_Vtbl)).ProxyAuthenticationUseNegotiate)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProxyAuthenticationUseBasic(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManConnectionOptionsEx2", "ProxyAuthenticationUseBasic", 18usize, |this| ((**(this as *const *const IWSManConnectionOptionsEx2_Vtbl)).ProxyAuthenticationUseBasic)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProxyAuthenticationUseDigest(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManConnectionOptionsEx2", "ProxyAuthenticationUseDigest", 19usize, |this| ((**(this as *const *const IWSManConnectionOptionsEx2_Vtbl)).ProxyAuthenticationUseDigest)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <IWSManConnectionOptionsEx_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetProxy,
+            ProxyIEConfig,
+            ProxyWinHttpConfig,
+            ProxyAutoDetect,
+            ProxyNoProxyServer,
+            ProxyAuthenticationUseNegotiate,
+            ProxyAuthenticationUseBasic,
+            ProxyAuthenticationUseDigest,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSManConnectionOptionsEx2 as windows_core::Interface>::IID || <IWSManConnectionOptionsEx_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWSManEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReadItem(this: *mut core::ffi::c_void, resource: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEnumerator", "ReadItem", 7usize, |this| ((**(this as *const *const IWSManEnumerator_Vtbl)).ReadItem)(this, resource), |result| Some(*result))
+        }
+        unsafe extern "system" fn AtEndOfStream(this: *mut core::ffi::c_void, eos: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEnumerator", "AtEndOfStream", 8usize, |this| ((**(this as *const *const IWSManEnumerator_Vtbl)).AtEndOfStream)(this, eos), |result| Some(*result))
+        }
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEnumerator", "Error", 9usize, |this| ((**(this as *const *const IWSManEnumerator_Vtbl)).Error)(this, value), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReadItem, AtEndOfStream, Error }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSManEnumerator as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWSManEx_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateResourceLocator(this: *mut core::ffi::c_void, strresourcelocator: core::mem::MaybeUninit<windows_core::BSTR>, newresourcelocator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEx", "CreateResourceLocator", 11usize, |this| ((**(this as *const *const IWSManEx_Vtbl)).CreateResourceLocator)(this, strresourcelocator, newresourcelocator), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionFlagUTF8(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEx", "SessionFlagUTF8", 12usize, |this| ((**(this as *const *const IWSManEx_Vtbl)).SessionFlagUTF8)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionFlagCredUsernamePassword(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEx", "SessionFlagCredUsernamePassword", 13usize, |this| ((**(this as *const *const IWSManEx_Vtbl)).SessionFlagCredUsernamePassword)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionFlagSkipCACheck(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEx", "SessionFlagSkipCACheck", 14usize, |this| ((**(this as *const *const IWSManEx_Vtbl)).SessionFlagSkipCACheck)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionFlagSkipCNCheck(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEx", "SessionFlagSkipCNCheck", 15usize, |this| ((**(this as *const *const IWSManEx_Vtbl)).SessionFlagSkipCNCheck)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionFlagUseDigest(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEx", "SessionFlagUseDigest", 16usize, |this| ((**(this as *const *const IWSManEx_Vtbl)).SessionFlagUseDigest)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionFlagUseNegotiate(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEx", "SessionFlagUseNegotiate", 17usize, |this| ((**(this as *const *const IWSManEx_Vtbl)).SessionFlagUseNegotiate)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionFlagUseBasic(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEx", "SessionFlagUseBasic", 18usize, |this| ((**(this as *const *const IWSManEx_Vtbl)).SessionFlagUseBasic)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionFlagUseKerberos(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEx", "SessionFlagUseKerberos", 19usize, |this| ((**(this as *const *const IWSManEx_Vtbl)).SessionFlagUseKerberos)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionFlagNoEncryption(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEx", "SessionFlagNoEncryption", 20usize, |this| ((**(this as *const *const IWSManEx_Vtbl)).SessionFlagNoEncryption)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionFlagEnableSPNServerPort(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEx", "SessionFlagEnableSPNServerPort", 21usize, |this| ((**(this as *const *const IWSManEx_Vtbl)).SessionFlagEnableSPNServerPort)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionFlagUseNoAuthentication(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEx", "SessionFlagUseNoAuthentication", 22usize, |this| ((**(this as *const *const IWSManEx_Vtbl)).SessionFlagUseNoAuthentication)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerationFlagNonXmlText(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEx", "EnumerationFlagNonXmlText", 23usize, |this| ((**(this as *const *const IWSManEx_Vtbl)).EnumerationFlagNonXmlText)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerationFlagReturnEPR(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEx", "EnumerationFlagReturnEPR", 24usize, |this| ((**(this as *const *const IWSManEx_Vtbl)).EnumerationFlagReturnEPR)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerationFlagReturnObjectAndEPR(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEx", "EnumerationFlagReturnObjectAndEPR", 25usize, |this| ((**(this as *const *const IWSManEx_Vtbl)).EnumerationFlagReturnObjectAndEPR)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetErrorMessage(this: *mut core::ffi::c_void, errornumber: u32, errormessage: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEx", "GetErrorMessage", 26usize, |this| ((**(this as *const *const IWSManEx_Vtbl)).GetErrorMessage)(this, errornumber, errormessage), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerationFlagHierarchyDeep(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEx", "EnumerationFlagHierarchyDeep", 27usize, |this| ((**(this as *const *const IWSManEx_Vtbl)).EnumerationFlagHierarchyDeep)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerationFlagHierarchyShallow(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEx", "EnumerationFlagHierarchyShallow", 28usize, |this| ((**(this as *const *const IWSManEx_Vtbl)).EnumerationFlagHierarchyShallow)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerationFlagHierarchyDeepBasePropsOnly(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEx", "EnumerationFlagHierarchyDeepBasePropsOnly", 29usize, |this| ((**(this as *const *const IWSManEx_Vtbl)).EnumerationFlagHierarchyDeepBasePropsOnly)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerationFlagReturnObject(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEx", "EnumerationFlagReturnObject", 30usize, |this| ((**(this as *const *const IWSManEx_Vtbl)).EnumerationFlagReturnObject)(this, flags), |result| Some(*result))
+        }
+        Self {
+            base__: <IWSMan_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateResourceLocator,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateResourceLocator: 0,
+            SessionFlagUTF8,
+            SessionFlagCredUsernamePassword,
+            SessionFlagSkipCACheck,
+            SessionFlagSkipCNCheck,
+            SessionFlagUseDigest,
+            SessionFlagUseNegotiate,
+            SessionFlagUseBasic,
+            SessionFlagUseKerberos,
+            SessionFlagNoEncryption,
+            SessionFlagEnableSPNServerPort,
+            SessionFlagUseNoAuthentication,
+            EnumerationFlagNonXmlText,
+            EnumerationFlagReturnEPR,
+            EnumerationFlagReturnObjectAndEPR,
+            GetErrorMessage,
+            EnumerationFlagHierarchyDeep,
+            EnumerationFlagHierarchyShallow,
+            EnumerationFlagHierarchyDeepBasePropsOnly,
+            EnumerationFlagReturnObject,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSManEx as windows_core::Interface>::IID || <IWSMan_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWSManEx2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SessionFlagUseClientCertificate(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEx2", "SessionFlagUseClientCertificate", 31usize, |this| ((**(this as *const *const IWSManEx2_Vtbl)).SessionFlagUseClientCertificate)(this, flags), |result| Some(*result))
+        }
+        Self { base__: <IWSManEx_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SessionFlagUseClientCertificate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSManEx2 as windows_core::Interface>::IID || <IWSManEx_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWSManEx3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SessionFlagUTF16(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEx3", "SessionFlagUTF16", 32usize, |this| ((**(this as *const *const IWSManEx3_Vtbl)).SessionFlagUTF16)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionFlagUseCredSsp(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEx3", "SessionFlagUseCredSsp", 33usize, |this| ((**(this as *const *const IWSManEx3_Vtbl)).SessionFlagUseCredSsp)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerationFlagAssociationInstance(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEx3", "EnumerationFlagAssociationInstance", 34usize, |this| ((**(this as *const *const IWSManEx3_Vtbl)).EnumerationFlagAssociationInstance)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerationFlagAssociatedInstance(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEx3", "EnumerationFlagAssociatedInstance", 35usize, |this| ((**(this as *const *const IWSManEx3_Vtbl)).EnumerationFlagAssociatedInstance)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionFlagSkipRevocationCheck(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEx3", "SessionFlagSkipRevocationCheck", 36usize, |this| ((**(this as *const *const IWSManEx3_Vtbl)).SessionFlagSkipRevocationCheck)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionFlagAllowNegotiateImplicitCredentials(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEx3", "SessionFlagAllowNegotiateImplicitCredentials", 37usize, |this| ((**(this as *const *const IWSManEx3_Vtbl)).SessionFlagAllowNegotiateImplicitCredentials)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SessionFlagUseSsl(this: *mut core::ffi::c_void, flags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManEx3", "SessionFlagUseSsl", 38usize, |this| ((**(this as *const *const IWSManEx3_Vtbl)).SessionFlagUseSsl)(this, flags), |result| Some(*result))
+        }
+        Self {
+            base__: <IWSManEx2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SessionFlagUTF16,
+            SessionFlagUseCredSsp,
+            EnumerationFlagAssociationInstance,
+            EnumerationFlagAssociatedInstance,
+            SessionFlagSkipRevocationCheck,
+            SessionFlagAllowNegotiateImplicitCredentials,
+            SessionFlagUseSsl,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSManEx3 as windows_core::Interface>::IID || <IWSManEx2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWSManInternal_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ConfigSDDL(this: *mut core::ffi::c_void, session: *mut core::ffi::c_void, resourceuri: core::mem::MaybeUninit<windows_core::VARIANT>, flags: i32, resource: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManInternal", "ConfigSDDL", 7usize, |this| ((**(this as *const *const IWSManInternal_Vtbl)).ConfigSDDL)(this, session, resourceuri, flags, resource), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            ConfigSDDL,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ConfigSDDL: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSManInternal as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWSManResourceLocator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetResourceURI(this: *mut core::ffi::c_void, uri: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManResourceLocator", "SetResourceURI", 7usize, |this| ((**(this as *const *const IWSManResourceLocator_Vtbl)).SetResourceURI)(this, uri), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResourceURI(this: *mut core::ffi::c_void, uri: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManResourceLocator", "ResourceURI", 8usize, |this| ((**(this as *const *const IWSManResourceLocator_Vtbl)).ResourceURI)(this, uri), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddSelector(this: *mut core::ffi::c_void, resourceselname: core::mem::MaybeUninit<windows_core::BSTR>, selvalue: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManResourceLocator", "AddSelector", 9usize, |this| ((**(this as *const *const IWSManResourceLocator_Vtbl)).AddSelector)(this, resourceselname, selvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearSelectors(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManResourceLocator", "ClearSelectors", 10usize, |this| ((**(this as *const *const IWSManResourceLocator_Vtbl)).ClearSelectors)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn FragmentPath(this: *mut core::ffi::c_void, text: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManResourceLocator", "FragmentPath", 11usize, |this| ((**(this as *const *const IWSManResourceLocator_Vtbl)).FragmentPath)(this, text), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFragmentPath(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManResourceLocator", "SetFragmentPath", 12usize, |this| ((**(this as *const *const IWSManResourceLocator_Vtbl)).SetFragmentPath)(this, text), |result| Some(*result))
+        }
+        unsafe extern "system" fn FragmentDialect(this: *mut core::ffi::c_void, text: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManResourceLocator", "FragmentDialect", 13usize, |this| ((**(this as *const *const IWSManResourceLocator_Vtbl)).FragmentDialect)(this, text), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFragmentDialect(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManResourceLocator", "SetFragmentDialect", 14usize, |this| ((**(this as *const *const IWSManResourceLocator_Vtbl)).SetFragmentDialect)(this, text), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddOption(this: *mut core::ffi::c_void, optionname: core::mem::MaybeUninit<windows_core::BSTR>, optionvalue: core::mem::MaybeUninit<windows_core::VARIANT>, mustcomply: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManResourceLocator", "AddOption", 15usize, |this| ((**(this as *const *const IWSManResourceLocator_Vtbl)).AddOption)(this, optionname, optionvalue, mustcomply), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMustUnderstandOptions(this: *mut core::ffi::c_void, mustunderstand: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManResourceLocator", "SetMustUnderstandOptions", 16usize, |this| ((**(this as *const *const IWSManResourceLocator_Vtbl)).SetMustUnderstandOptions)(this, mustunderstand), |result| Some(*result))
+        }
+        unsafe extern "system" fn MustUnderstandOptions(this: *mut core::ffi::c_void, mustunderstand: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManResourceLocator", "MustUnderstandOptions", 17usize, |this| ((**(this as *const *const IWSManResourceLocator_Vtbl)).MustUnderstandOptions)(this, mustunderstand), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearOptions(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManResourceLocator", "ClearOptions", 18usize, |this| ((**(this as *const *const IWSManResourceLocator_Vtbl)).ClearOptions)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManResourceLocator", "Error", 19usize, |this| ((**(this as *const *const IWSManResourceLocator_Vtbl)).Error)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetResourceURI,
+            ResourceURI,
+            AddSelector,
+            ClearSelectors,
+            FragmentPath,
+            SetFragmentPath,
+            FragmentDialect,
+            SetFragmentDialect,
+            AddOption,
+            SetMustUnderstandOptions,
+            MustUnderstandOptions,
+            ClearOptions,
+            Error,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSManResourceLocator as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWSManResourceLocatorInternal_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSManResourceLocatorInternal as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWSManSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Get(this: *mut core::ffi::c_void, resourceuri: core::mem::MaybeUninit<windows_core::VARIANT>, flags: i32, resource: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManSession", "Get", 7usize, |this| ((**(this as *const *const IWSManSession_Vtbl)).Get)(this, resourceuri, flags, resource), |result| Some(*result))
+        }
+        unsafe extern "system" fn Put(this: *mut core::ffi::c_void, resourceuri: core::mem::MaybeUninit<windows_core::VARIANT>, resource: core::mem::MaybeUninit<windows_core::BSTR>, flags: i32, resultresource: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManSession", "Put", 8usize, |this| ((**(this as *const *const IWSManSession_Vtbl)).Put)(this, resourceuri, resource, flags, resultresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, resourceuri: core::mem::MaybeUninit<windows_core::VARIANT>, resource: core::mem::MaybeUninit<windows_core::BSTR>, flags: i32, newuri: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManSession", "Create", 9usize, |this| ((**(this as *const *const IWSManSession_Vtbl)).Create)(this, resourceuri, resource, flags, newuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, resourceuri: core::mem::MaybeUninit<windows_core::VARIANT>, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManSession", "Delete", 10usize, |this| ((**(this as *const *const IWSManSession_Vtbl)).Delete)(this, resourceuri, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Invoke(this: *mut core::ffi::c_void, actionuri: core::mem::MaybeUninit<windows_core::BSTR>, resourceuri: core::mem::MaybeUninit<windows_core::VARIANT>, parameters: core::mem::MaybeUninit<windows_core::BSTR>, flags: i32, result: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManSession", "Invoke", 11usize, |this| ((**(this as *const *const IWSManSession_Vtbl)).Invoke)(this, actionuri, resourceuri, parameters, flags, result), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Enumerate(this: *mut core::ffi::c_void, resourceuri: core::mem::MaybeUninit<windows_core::VARIANT>, filter: core::mem::MaybeUninit<windows_core::BSTR>, dialect: core::mem::MaybeUninit<windows_core::BSTR>, flags: i32, resultset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManSession", "Enumerate", 12usize, |this| ((**(this as *const *const IWSManSession_Vtbl)).Enumerate)(this, resourceuri, filter, dialect, flags, resultset), |result| Some(*result))
+        }
+        unsafe extern "system" fn Identify(this: *mut core::ffi::c_void, flags: i32, result: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManSession", "Identify", 13usize, |this| ((**(this as *const *const IWSManSession_Vtbl)).Identify)(this, flags, result), |result| Some(*result))
+        }
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManSession", "Error", 14usize, |this| ((**(this as *const *const IWSManSession_Vtbl)).Error)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BatchItems(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManSession", "BatchItems", 15usize, |this| ((**(this as *const *const IWSManSession_Vtbl)).BatchItems)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBatchItems(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManSession", "SetBatchItems", 16usize, |this| ((**(this as *const *const IWSManSession_Vtbl)).SetBatchItems)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Timeout(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManSession", "Timeout", 17usize, |this| ((**(this as *const *const IWSManSession_Vtbl)).Timeout)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTimeout(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSManSession", "SetTimeout", 18usize, |this| ((**(this as *const *const IWSManSession_Vtbl)).SetTimeout)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Get,
+            Put,
+            Create,
+            Delete,
+            Invoke,
+            #[cfg(feature = "Win32_System_Com")]
+            Enumerate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Enumerate: 0,
+            Identify,
+            Error,
+            BatchItems,
+            SetBatchItems,
+            Timeout,
+            SetTimeout,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSManSession as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
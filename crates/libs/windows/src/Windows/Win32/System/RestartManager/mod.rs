@@ -114,6 +114,7 @@ pub struct RM_APP_STATUS(pub i32);
 impl windows_core::TypeKind for RM_APP_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RM_APP_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RM_APP_STATUS").field(&self.0).finish()
@@ -125,6 +126,7 @@ pub struct RM_APP_TYPE(pub i32);
 impl windows_core::TypeKind for RM_APP_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RM_APP_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RM_APP_TYPE").field(&self.0).finish()
@@ -136,6 +138,7 @@ pub struct RM_FILTER_ACTION(pub i32);
 impl windows_core::TypeKind for RM_FILTER_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RM_FILTER_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RM_FILTER_ACTION").field(&self.0).finish()
@@ -147,6 +150,7 @@ pub struct RM_FILTER_TRIGGER(pub i32);
 impl windows_core::TypeKind for RM_FILTER_TRIGGER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RM_FILTER_TRIGGER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RM_FILTER_TRIGGER").field(&self.0).finish()
@@ -158,6 +162,7 @@ pub struct RM_REBOOT_REASON(pub i32);
 impl windows_core::TypeKind for RM_REBOOT_REASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RM_REBOOT_REASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RM_REBOOT_REASON").field(&self.0).finish()
@@ -169,6 +174,7 @@ pub struct RM_SHUTDOWN_TYPE(pub i32);
 impl windows_core::TypeKind for RM_SHUTDOWN_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RM_SHUTDOWN_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RM_SHUTDOWN_TYPE").field(&self.0).finish()
@@ -206,7 +212,8 @@ impl Default for RM_FILTER_INFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RM_PROCESS_INFO {
     pub Process: RM_UNIQUE_PROCESS,
     pub strAppName: [u16; 256],
@@ -225,7 +232,8 @@ impl Default for RM_PROCESS_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RM_UNIQUE_PROCESS {
     pub dwProcessId: u32,
     pub ProcessStartTime: super::super::Foundation::FILETIME,
@@ -239,3 +247,4 @@ impl Default for RM_UNIQUE_PROCESS {
     }
 }
 pub type RM_WRITE_STATUS_CALLBACK = Option<unsafe extern "system" fn(npercentcomplete: u32)>;
+core::include!(core::concat!(core::env!("CARGO_MANIFEST_DIR"), "/src/includes/", "Win32/System/RestartManager/RestartSession.rs"));
@@ -49,6 +49,7 @@ pub struct RESTOREPOINTINFO_EVENT_TYPE(pub u32);
 impl windows_core::TypeKind for RESTOREPOINTINFO_EVENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RESTOREPOINTINFO_EVENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RESTOREPOINTINFO_EVENT_TYPE").field(&self.0).finish()
@@ -60,6 +61,7 @@ pub struct RESTOREPOINTINFO_TYPE(pub u32);
 impl windows_core::TypeKind for RESTOREPOINTINFO_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RESTOREPOINTINFO_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RESTOREPOINTINFO_TYPE").field(&self.0).finish()
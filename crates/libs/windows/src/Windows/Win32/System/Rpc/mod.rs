@@ -3488,6 +3488,7 @@ pub struct EXPR_TOKEN(pub i32);
 impl windows_core::TypeKind for EXPR_TOKEN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EXPR_TOKEN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EXPR_TOKEN").field(&self.0).finish()
@@ -3499,6 +3500,7 @@ pub struct ExtendedErrorParamTypes(pub i32);
 impl windows_core::TypeKind for ExtendedErrorParamTypes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ExtendedErrorParamTypes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ExtendedErrorParamTypes").field(&self.0).finish()
@@ -3510,6 +3512,7 @@ pub struct GROUP_NAME_SYNTAX(pub u32);
 impl windows_core::TypeKind for GROUP_NAME_SYNTAX {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GROUP_NAME_SYNTAX {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GROUP_NAME_SYNTAX").field(&self.0).finish()
@@ -3521,6 +3524,7 @@ pub struct IDL_CS_CONVERT(pub i32);
 impl windows_core::TypeKind for IDL_CS_CONVERT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IDL_CS_CONVERT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IDL_CS_CONVERT").field(&self.0).finish()
@@ -3532,6 +3536,7 @@ pub struct LRPC_SYSTEM_HANDLE_MARSHAL_DIRECTION(pub i32);
 impl windows_core::TypeKind for LRPC_SYSTEM_HANDLE_MARSHAL_DIRECTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LRPC_SYSTEM_HANDLE_MARSHAL_DIRECTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LRPC_SYSTEM_HANDLE_MARSHAL_DIRECTION").field(&self.0).finish()
@@ -3543,6 +3548,7 @@ pub struct MIDL_ES_CODE(pub i32);
 impl windows_core::TypeKind for MIDL_ES_CODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MIDL_ES_CODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MIDL_ES_CODE").field(&self.0).finish()
@@ -3554,6 +3560,7 @@ pub struct MIDL_ES_HANDLE_STYLE(pub i32);
 impl windows_core::TypeKind for MIDL_ES_HANDLE_STYLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MIDL_ES_HANDLE_STYLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MIDL_ES_HANDLE_STYLE").field(&self.0).finish()
@@ -3565,6 +3572,7 @@ pub struct PROXY_PHASE(pub i32);
 impl windows_core::TypeKind for PROXY_PHASE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROXY_PHASE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROXY_PHASE").field(&self.0).finish()
@@ -3576,6 +3584,7 @@ pub struct RPC_ADDRESS_CHANGE_TYPE(pub i32);
 impl windows_core::TypeKind for RPC_ADDRESS_CHANGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RPC_ADDRESS_CHANGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RPC_ADDRESS_CHANGE_TYPE").field(&self.0).finish()
@@ -3587,6 +3596,7 @@ pub struct RPC_ASYNC_EVENT(pub i32);
 impl windows_core::TypeKind for RPC_ASYNC_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RPC_ASYNC_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RPC_ASYNC_EVENT").field(&self.0).finish()
@@ -3598,6 +3608,7 @@ pub struct RPC_BINDING_HANDLE_OPTIONS_FLAGS(pub u32);
 impl windows_core::TypeKind for RPC_BINDING_HANDLE_OPTIONS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RPC_BINDING_HANDLE_OPTIONS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RPC_BINDING_HANDLE_OPTIONS_FLAGS").field(&self.0).finish()
@@ -3642,6 +3653,7 @@ pub struct RPC_C_AUTHN_INFO_TYPE(pub u32);
 impl windows_core::TypeKind for RPC_C_AUTHN_INFO_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RPC_C_AUTHN_INFO_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RPC_C_AUTHN_INFO_TYPE").field(&self.0).finish()
@@ -3653,6 +3665,7 @@ pub struct RPC_C_HTTP_AUTHN_TARGET(pub u32);
 impl windows_core::TypeKind for RPC_C_HTTP_AUTHN_TARGET {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RPC_C_HTTP_AUTHN_TARGET {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RPC_C_HTTP_AUTHN_TARGET").field(&self.0).finish()
@@ -3697,6 +3710,7 @@ pub struct RPC_C_HTTP_FLAGS(pub u32);
 impl windows_core::TypeKind for RPC_C_HTTP_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RPC_C_HTTP_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RPC_C_HTTP_FLAGS").field(&self.0).finish()
@@ -3741,6 +3755,7 @@ pub struct RPC_C_QOS_CAPABILITIES(pub u32);
 impl windows_core::TypeKind for RPC_C_QOS_CAPABILITIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RPC_C_QOS_CAPABILITIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RPC_C_QOS_CAPABILITIES").field(&self.0).finish()
@@ -3785,6 +3800,7 @@ pub struct RPC_C_QOS_IDENTITY(pub u32);
 impl windows_core::TypeKind for RPC_C_QOS_IDENTITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RPC_C_QOS_IDENTITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RPC_C_QOS_IDENTITY").field(&self.0).finish()
@@ -3796,6 +3812,7 @@ pub struct RPC_HTTP_REDIRECTOR_STAGE(pub i32);
 impl windows_core::TypeKind for RPC_HTTP_REDIRECTOR_STAGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RPC_HTTP_REDIRECTOR_STAGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RPC_HTTP_REDIRECTOR_STAGE").field(&self.0).finish()
@@ -3807,6 +3824,7 @@ pub struct RPC_NOTIFICATIONS(pub i32);
 impl windows_core::TypeKind for RPC_NOTIFICATIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RPC_NOTIFICATIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RPC_NOTIFICATIONS").field(&self.0).finish()
@@ -3818,6 +3836,7 @@ pub struct RPC_NOTIFICATION_TYPES(pub i32);
 impl windows_core::TypeKind for RPC_NOTIFICATION_TYPES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RPC_NOTIFICATION_TYPES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RPC_NOTIFICATION_TYPES").field(&self.0).finish()
@@ -3830,6 +3849,7 @@ pub struct RPC_STATUS(pub i32);
 impl windows_core::TypeKind for RPC_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RPC_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RPC_STATUS").field(&self.0).finish()
@@ -3841,6 +3861,7 @@ pub struct RpcCallClientLocality(pub i32);
 impl windows_core::TypeKind for RpcCallClientLocality {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RpcCallClientLocality {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RpcCallClientLocality").field(&self.0).finish()
@@ -3852,6 +3873,7 @@ pub struct RpcCallType(pub i32);
 impl windows_core::TypeKind for RpcCallType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RpcCallType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RpcCallType").field(&self.0).finish()
@@ -3863,6 +3885,7 @@ pub struct RpcLocalAddressFormat(pub i32);
 impl windows_core::TypeKind for RpcLocalAddressFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RpcLocalAddressFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RpcLocalAddressFormat").field(&self.0).finish()
@@ -3874,6 +3897,7 @@ pub struct RpcPerfCounters(pub i32);
 impl windows_core::TypeKind for RpcPerfCounters {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RpcPerfCounters {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RpcPerfCounters").field(&self.0).finish()
@@ -3885,6 +3909,7 @@ pub struct SEC_WINNT_AUTH_IDENTITY(pub u32);
 impl windows_core::TypeKind for SEC_WINNT_AUTH_IDENTITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SEC_WINNT_AUTH_IDENTITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SEC_WINNT_AUTH_IDENTITY").field(&self.0).finish()
@@ -3896,6 +3921,7 @@ pub struct STUB_PHASE(pub i32);
 impl windows_core::TypeKind for STUB_PHASE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STUB_PHASE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STUB_PHASE").field(&self.0).finish()
@@ -3907,6 +3933,7 @@ pub struct USER_MARSHAL_CB_TYPE(pub i32);
 impl windows_core::TypeKind for USER_MARSHAL_CB_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USER_MARSHAL_CB_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USER_MARSHAL_CB_TYPE").field(&self.0).finish()
@@ -3918,6 +3945,7 @@ pub struct XLAT_SIDE(pub i32);
 impl windows_core::TypeKind for XLAT_SIDE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XLAT_SIDE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XLAT_SIDE").field(&self.0).finish()
@@ -3929,13 +3957,15 @@ pub struct system_handle_t(pub i32);
 impl windows_core::TypeKind for system_handle_t {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for system_handle_t {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("system_handle_t").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ARRAY_INFO {
     pub Dimension: i32,
     pub BufferConformanceMark: *mut u32,
@@ -3953,7 +3983,8 @@ impl Default for ARRAY_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BinaryParam {
     pub Buffer: *mut core::ffi::c_void,
     pub Size: i16,
@@ -3981,7 +4012,8 @@ impl Default for CLIENT_CALL_RETURN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COMM_FAULT_OFFSETS {
     pub CommOffset: i16,
     pub FaultOffset: i16,
@@ -3995,7 +4027,8 @@ impl Default for COMM_FAULT_OFFSETS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FULL_PTR_XLAT_TABLES {
     pub RefIdToPointer: *mut core::ffi::c_void,
     pub PointerToRefId: *mut core::ffi::c_void,
@@ -4011,7 +4044,8 @@ impl Default for FULL_PTR_XLAT_TABLES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GENERIC_BINDING_INFO {
     pub pObj: *mut core::ffi::c_void,
     pub Size: u32,
@@ -4027,7 +4061,8 @@ impl Default for GENERIC_BINDING_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GENERIC_BINDING_ROUTINE_PAIR {
     pub pfnBind: GENERIC_BINDING_ROUTINE,
     pub pfnUnbind: GENERIC_UNBIND_ROUTINE,
@@ -4041,7 +4076,8 @@ impl Default for GENERIC_BINDING_ROUTINE_PAIR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct I_RpcProxyCallbackInterface {
     pub IsValidMachineFn: I_RpcProxyIsValidMachineFn,
     pub GetClientAddressFn: I_RpcProxyGetClientAddressFn,
@@ -4062,7 +4098,8 @@ impl Default for I_RpcProxyCallbackInterface {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MALLOC_FREE_STRUCT {
     pub pfnAllocate: isize,
     pub pfnFree: isize,
@@ -4076,7 +4113,8 @@ impl Default for MALLOC_FREE_STRUCT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MIDL_FORMAT_STRING {
     pub Pad: i16,
     pub Format: [u8; 1],
@@ -4090,7 +4128,8 @@ impl Default for MIDL_FORMAT_STRING {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MIDL_INTERCEPTION_INFO {
     pub Version: u32,
     pub ProcString: *mut u8,
@@ -4107,7 +4146,8 @@ impl Default for MIDL_INTERCEPTION_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MIDL_INTERFACE_METHOD_PROPERTIES {
     pub MethodCount: u16,
     pub MethodProperties: *const *const MIDL_METHOD_PROPERTY_MAP,
@@ -4121,7 +4161,8 @@ impl Default for MIDL_INTERFACE_METHOD_PROPERTIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MIDL_METHOD_PROPERTY {
     pub Id: u32,
     pub Value: usize,
@@ -4135,7 +4176,8 @@ impl Default for MIDL_METHOD_PROPERTY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MIDL_METHOD_PROPERTY_MAP {
     pub Count: u32,
     pub Properties: *const MIDL_METHOD_PROPERTY,
@@ -4150,7 +4192,8 @@ impl Default for MIDL_METHOD_PROPERTY_MAP {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MIDL_SERVER_INFO {
     pub pStubDesc: *mut MIDL_STUB_DESC,
     pub DispatchTable: *const SERVER_ROUTINE,
@@ -4173,7 +4216,8 @@ impl Default for MIDL_SERVER_INFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MIDL_STUBLESS_PROXY_INFO {
     pub pStubDesc: *mut MIDL_STUB_DESC,
     pub ProcFormatString: *mut u8,
@@ -4247,7 +4291,7 @@ impl Default for MIDL_STUB_DESC_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Debug)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MIDL_STUB_MESSAGE {
     pub RpcMsg: *mut RPC_MESSAGE,
     pub Buffer: *mut u8,
@@ -4310,6 +4354,99 @@ pub struct MIDL_STUB_MESSAGE {
     pub Reserved51_5: isize,
 }
 #[cfg(feature = "Win32_System_Com")]
+impl MIDL_STUB_MESSAGE {
+    pub const fn fInDontFree(&self) -> i32 {
+        self._bitfield & 1
+    }
+    pub fn set_fInDontFree(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn fDontCallFreeInst(&self) -> i32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_fDontCallFreeInst(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn fUnused1(&self) -> i32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_fUnused1(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn fHasReturn(&self) -> i32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_fHasReturn(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn fHasExtensions(&self) -> i32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_fHasExtensions(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn fHasNewCorrDesc(&self) -> i32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_fHasNewCorrDesc(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn fIsIn(&self) -> i32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_fIsIn(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn fIsOut(&self) -> i32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_fIsOut(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn fIsOicf(&self) -> i32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_fIsOicf(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn fBufferValid(&self) -> i32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_fBufferValid(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn fHasMemoryValidateCallback(&self) -> i32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_fHasMemoryValidateCallback(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn fInFree(&self) -> i32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_fInFree(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn fNeedMCCP(&self) -> i32 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_fNeedMCCP(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn fUnused2(&self) -> i32 {
+        (self._bitfield >> 13) & 7
+    }
+    pub fn set_fUnused2(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(7 << 13)) | ((value & 7) << 13);
+    }
+    pub const fn fUnused3(&self) -> i32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_fUnused3(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
 impl Clone for MIDL_STUB_MESSAGE {
     fn clone(&self) -> Self {
         unsafe { core::mem::transmute_copy(self) }
@@ -4326,7 +4463,8 @@ impl Default for MIDL_STUB_MESSAGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MIDL_SYNTAX_INFO {
     pub TransferSyntax: RPC_SYNTAX_IDENTIFIER,
     pub DispatchTable: *mut RPC_DISPATCH_TABLE,
@@ -4346,7 +4484,8 @@ impl Default for MIDL_SYNTAX_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MIDL_TYPE_PICKLING_INFO {
     pub Version: u32,
     pub Flags: u32,
@@ -4362,7 +4501,8 @@ impl Default for MIDL_TYPE_PICKLING_INFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MIDL_WINRT_TYPE_SERIALIZATION_INFO {
     pub Version: u32,
     pub TypeFormatString: *mut u8,
@@ -4381,7 +4521,8 @@ impl Default for MIDL_WINRT_TYPE_SERIALIZATION_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_ARRAY_ELEMENT_INFO {
     pub ElementMemSize: u32,
     pub Element: *mut core::ffi::c_void,
@@ -4395,10 +4536,61 @@ impl Default for NDR64_ARRAY_ELEMENT_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_ARRAY_FLAGS {
     pub _bitfield: u8,
 }
+impl NDR64_ARRAY_FLAGS {
+    pub const fn HasPointerInfo(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_HasPointerInfo(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn HasElementInfo(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_HasElementInfo(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn IsMultiDimensional(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_IsMultiDimensional(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn IsArrayofStrings(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_IsArrayofStrings(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved1(&self) -> u8 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_Reserved1(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved2(&self) -> u8 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_Reserved2(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Reserved3(&self) -> u8 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_Reserved3(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Reserved4(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Reserved4(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for NDR64_ARRAY_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
@@ -4423,7 +4615,8 @@ impl Default for NDR64_BINDINGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_BIND_AND_NOTIFY_EXTENSION {
     pub Binding: NDR64_BIND_CONTEXT,
     pub NotifyIndex: u16,
@@ -4437,7 +4630,8 @@ impl Default for NDR64_BIND_AND_NOTIFY_EXTENSION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_BIND_CONTEXT {
     pub HandleType: u8,
     pub Flags: u8,
@@ -4454,7 +4648,8 @@ impl Default for NDR64_BIND_CONTEXT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_BIND_GENERIC {
     pub HandleType: u8,
     pub Flags: u8,
@@ -4471,7 +4666,8 @@ impl Default for NDR64_BIND_GENERIC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_BIND_PRIMITIVE {
     pub HandleType: u8,
     pub Flags: u8,
@@ -4487,7 +4683,8 @@ impl Default for NDR64_BIND_PRIMITIVE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_BOGUS_ARRAY_HEADER_FORMAT {
     pub FormatCode: u8,
     pub Alignment: u8,
@@ -4505,7 +4702,8 @@ impl Default for NDR64_BOGUS_ARRAY_HEADER_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_BOGUS_STRUCTURE_HEADER_FORMAT {
     pub FormatCode: u8,
     pub Alignment: u8,
@@ -4525,7 +4723,8 @@ impl Default for NDR64_BOGUS_STRUCTURE_HEADER_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_BUFFER_ALIGN_FORMAT {
     pub FormatCode: u8,
     pub Alignment: u8,
@@ -4541,7 +4740,8 @@ impl Default for NDR64_BUFFER_ALIGN_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_CONFORMANT_STRING_FORMAT {
     pub Header: NDR64_STRING_HEADER_FORMAT,
 }
@@ -4554,7 +4754,8 @@ impl Default for NDR64_CONFORMANT_STRING_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_CONF_ARRAY_HEADER_FORMAT {
     pub FormatCode: u8,
     pub Alignment: u8,
@@ -4572,7 +4773,8 @@ impl Default for NDR64_CONF_ARRAY_HEADER_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_CONF_BOGUS_STRUCTURE_HEADER_FORMAT {
     pub FormatCode: u8,
     pub Alignment: u8,
@@ -4593,7 +4795,8 @@ impl Default for NDR64_CONF_BOGUS_STRUCTURE_HEADER_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_CONF_STRUCTURE_HEADER_FORMAT {
     pub FormatCode: u8,
     pub Alignment: u8,
@@ -4611,7 +4814,8 @@ impl Default for NDR64_CONF_STRUCTURE_HEADER_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_CONF_VAR_ARRAY_HEADER_FORMAT {
     pub FormatCode: u8,
     pub Alignment: u8,
@@ -4630,7 +4834,8 @@ impl Default for NDR64_CONF_VAR_ARRAY_HEADER_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_CONF_VAR_BOGUS_ARRAY_HEADER_FORMAT {
     pub FixedArrayFormat: NDR64_BOGUS_ARRAY_HEADER_FORMAT,
     pub ConfDescription: *mut core::ffi::c_void,
@@ -4646,7 +4851,8 @@ impl Default for NDR64_CONF_VAR_BOGUS_ARRAY_HEADER_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_CONSTANT_IID_FORMAT {
     pub FormatCode: u8,
     pub Flags: u8,
@@ -4662,10 +4868,61 @@ impl Default for NDR64_CONSTANT_IID_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_CONTEXT_HANDLE_FLAGS {
     pub _bitfield: u8,
 }
+impl NDR64_CONTEXT_HANDLE_FLAGS {
+    pub const fn CannotBeNull(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_CannotBeNull(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Serialize(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Serialize(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn NoSerialize(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_NoSerialize(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Strict(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_Strict(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn IsReturn(&self) -> u8 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_IsReturn(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn IsOut(&self) -> u8 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_IsOut(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn IsIn(&self) -> u8 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_IsIn(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn IsViaPointer(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_IsViaPointer(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for NDR64_CONTEXT_HANDLE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
@@ -4675,7 +4932,8 @@ impl Default for NDR64_CONTEXT_HANDLE_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_CONTEXT_HANDLE_FORMAT {
     pub FormatCode: u8,
     pub ContextFlags: u8,
@@ -4691,7 +4949,8 @@ impl Default for NDR64_CONTEXT_HANDLE_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_EMBEDDED_COMPLEX_FORMAT {
     pub FormatCode: u8,
     pub Reserve1: u8,
@@ -4707,7 +4966,8 @@ impl Default for NDR64_EMBEDDED_COMPLEX_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_ENCAPSULATED_UNION {
     pub FormatCode: u8,
     pub Alignment: u8,
@@ -4726,7 +4986,8 @@ impl Default for NDR64_ENCAPSULATED_UNION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_EXPR_CONST32 {
     pub ExprType: u8,
     pub Reserved: u8,
@@ -4742,7 +5003,8 @@ impl Default for NDR64_EXPR_CONST32 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_EXPR_CONST64 {
     pub ExprType: u8,
     pub Reserved: u8,
@@ -4758,7 +5020,8 @@ impl Default for NDR64_EXPR_CONST64 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_EXPR_NOOP {
     pub ExprType: u8,
     pub Size: u8,
@@ -4773,7 +5036,8 @@ impl Default for NDR64_EXPR_NOOP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_EXPR_OPERATOR {
     pub ExprType: u8,
     pub Operator: u8,
@@ -4789,7 +5053,8 @@ impl Default for NDR64_EXPR_OPERATOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_EXPR_VAR {
     pub ExprType: u8,
     pub VarType: u8,
@@ -4805,7 +5070,8 @@ impl Default for NDR64_EXPR_VAR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_FIXED_REPEAT_FORMAT {
     pub RepeatFormat: NDR64_REPEAT_FORMAT,
     pub Iterations: u32,
@@ -4820,7 +5086,8 @@ impl Default for NDR64_FIXED_REPEAT_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_FIX_ARRAY_HEADER_FORMAT {
     pub FormatCode: u8,
     pub Alignment: u8,
@@ -4837,10 +5104,25 @@ impl Default for NDR64_FIX_ARRAY_HEADER_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_IID_FLAGS {
     pub _bitfield: u8,
 }
+impl NDR64_IID_FLAGS {
+    pub const fn ConstantIID(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_ConstantIID(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 1) & 127
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(127 << 1)) | ((value & 127) << 1);
+    }
+}
 impl windows_core::TypeKind for NDR64_IID_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
@@ -4850,7 +5132,8 @@ impl Default for NDR64_IID_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_IID_FORMAT {
     pub FormatCode: u8,
     pub Flags: u8,
@@ -4866,7 +5149,8 @@ impl Default for NDR64_IID_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_MEMPAD_FORMAT {
     pub FormatCode: u8,
     pub Reserve1: u8,
@@ -4882,7 +5166,8 @@ impl Default for NDR64_MEMPAD_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_NON_CONFORMANT_STRING_FORMAT {
     pub Header: NDR64_STRING_HEADER_FORMAT,
     pub TotalSize: u32,
@@ -4896,7 +5181,8 @@ impl Default for NDR64_NON_CONFORMANT_STRING_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_NON_ENCAPSULATED_UNION {
     pub FormatCode: u8,
     pub Alignment: u8,
@@ -4915,7 +5201,8 @@ impl Default for NDR64_NON_ENCAPSULATED_UNION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_NO_REPEAT_FORMAT {
     pub FormatCode: u8,
     pub Flags: u8,
@@ -4931,10 +5218,103 @@ impl Default for NDR64_NO_REPEAT_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_PARAM_FLAGS {
     pub _bitfield: u16,
 }
+impl NDR64_PARAM_FLAGS {
+    pub const fn MustSize(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_MustSize(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn MustFree(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_MustFree(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn IsPipe(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_IsPipe(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn IsIn(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_IsIn(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn IsOut(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_IsOut(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn IsReturn(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_IsReturn(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn IsBasetype(&self) -> u16 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_IsBasetype(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn IsByValue(&self) -> u16 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_IsByValue(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn IsSimpleRef(&self) -> u16 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_IsSimpleRef(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn IsDontCallFreeInst(&self) -> u16 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_IsDontCallFreeInst(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn SaveForAsyncFinish(&self) -> u16 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_SaveForAsyncFinish(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn IsPartialIgnore(&self) -> u16 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_IsPartialIgnore(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn IsForceAllocate(&self) -> u16 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_IsForceAllocate(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 13) & 3
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(3 << 13)) | ((value & 3) << 13);
+    }
+    pub const fn UseCache(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_UseCache(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for NDR64_PARAM_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
@@ -4944,7 +5324,8 @@ impl Default for NDR64_PARAM_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_PARAM_FORMAT {
     pub Type: *mut core::ffi::c_void,
     pub Attributes: NDR64_PARAM_FLAGS,
@@ -4960,10 +5341,37 @@ impl Default for NDR64_PARAM_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_PIPE_FLAGS {
     pub _bitfield: u8,
 }
+impl NDR64_PIPE_FLAGS {
+    pub const fn Reserved1(&self) -> u8 {
+        self._bitfield & 31
+    }
+    pub fn set_Reserved1(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !31) | (value & 31);
+    }
+    pub const fn HasRange(&self) -> u8 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_HasRange(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn BlockCopy(&self) -> u8 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_BlockCopy(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Reserved2(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Reserved2(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for NDR64_PIPE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
@@ -4973,7 +5381,8 @@ impl Default for NDR64_PIPE_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_PIPE_FORMAT {
     pub FormatCode: u8,
     pub Flags: u8,
@@ -4992,7 +5401,8 @@ impl Default for NDR64_PIPE_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_POINTER_FORMAT {
     pub FormatCode: u8,
     pub Flags: u8,
@@ -5008,7 +5418,8 @@ impl Default for NDR64_POINTER_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_POINTER_INSTANCE_HEADER_FORMAT {
     pub Offset: u32,
     pub Reserved: u32,
@@ -5022,10 +5433,25 @@ impl Default for NDR64_POINTER_INSTANCE_HEADER_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_POINTER_REPEAT_FLAGS {
     pub _bitfield: u8,
 }
+impl NDR64_POINTER_REPEAT_FLAGS {
+    pub const fn SetCorrMark(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_SetCorrMark(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 1) & 127
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(127 << 1)) | ((value & 127) << 1);
+    }
+}
 impl windows_core::TypeKind for NDR64_POINTER_REPEAT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
@@ -5035,10 +5461,145 @@ impl Default for NDR64_POINTER_REPEAT_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_PROC_FLAGS {
     pub _bitfield: u32,
 }
+impl NDR64_PROC_FLAGS {
+    pub const fn HandleType(&self) -> u32 {
+        self._bitfield & 7
+    }
+    pub fn set_HandleType(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn ProcType(&self) -> u32 {
+        (self._bitfield >> 3) & 7
+    }
+    pub fn set_ProcType(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 3)) | ((value & 7) << 3);
+    }
+    pub const fn IsInterpreted(&self) -> u32 {
+        (self._bitfield >> 6) & 3
+    }
+    pub fn set_IsInterpreted(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 6)) | ((value & 3) << 6);
+    }
+    pub const fn IsObject(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_IsObject(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn IsAsync(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_IsAsync(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn IsEncode(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_IsEncode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn IsDecode(&self) -> u32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_IsDecode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn UsesFullPtrPackage(&self) -> u32 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_UsesFullPtrPackage(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn UsesRpcSmPackage(&self) -> u32 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_UsesRpcSmPackage(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn UsesPipes(&self) -> u32 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_UsesPipes(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn HandlesExceptions(&self) -> u32 {
+        (self._bitfield >> 15) & 3
+    }
+    pub fn set_HandlesExceptions(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 15)) | ((value & 3) << 15);
+    }
+    pub const fn ServerMustSize(&self) -> u32 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_ServerMustSize(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn ClientMustSize(&self) -> u32 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_ClientMustSize(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn HasReturn(&self) -> u32 {
+        (self._bitfield >> 19) & 1
+    }
+    pub fn set_HasReturn(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 19)) | ((value & 1) << 19);
+    }
+    pub const fn HasComplexReturn(&self) -> u32 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_HasComplexReturn(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn ServerHasCorrelation(&self) -> u32 {
+        (self._bitfield >> 21) & 1
+    }
+    pub fn set_ServerHasCorrelation(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 21)) | ((value & 1) << 21);
+    }
+    pub const fn ClientHasCorrelation(&self) -> u32 {
+        (self._bitfield >> 22) & 1
+    }
+    pub fn set_ClientHasCorrelation(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 22)) | ((value & 1) << 22);
+    }
+    pub const fn HasNotify(&self) -> u32 {
+        (self._bitfield >> 23) & 1
+    }
+    pub fn set_HasNotify(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 23)) | ((value & 1) << 23);
+    }
+    pub const fn HasOtherExtensions(&self) -> u32 {
+        (self._bitfield >> 24) & 1
+    }
+    pub fn set_HasOtherExtensions(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 24)) | ((value & 1) << 24);
+    }
+    pub const fn HasBigByValueParam(&self) -> u32 {
+        (self._bitfield >> 25) & 1
+    }
+    pub fn set_HasBigByValueParam(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 25)) | ((value & 1) << 25);
+    }
+    pub const fn HasArmParamLayout(&self) -> u32 {
+        (self._bitfield >> 26) & 1
+    }
+    pub fn set_HasArmParamLayout(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 26)) | ((value & 1) << 26);
+    }
+    pub const fn Reserved(&self) -> u32 {
+        (self._bitfield >> 27) & 31
+    }
+    pub fn set_Reserved(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 27)) | ((value & 31) << 27);
+    }
+}
 impl windows_core::TypeKind for NDR64_PROC_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
@@ -5048,7 +5609,8 @@ impl Default for NDR64_PROC_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_PROC_FORMAT {
     pub Flags: u32,
     pub StackSize: u32,
@@ -5068,7 +5630,8 @@ impl Default for NDR64_PROC_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_RANGED_STRING_FORMAT {
     pub Header: NDR64_STRING_HEADER_FORMAT,
     pub Reserved: u32,
@@ -5084,7 +5647,8 @@ impl Default for NDR64_RANGED_STRING_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_RANGE_FORMAT {
     pub FormatCode: u8,
     pub RangeType: u8,
@@ -5101,7 +5665,8 @@ impl Default for NDR64_RANGE_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_RANGE_PIPE_FORMAT {
     pub FormatCode: u8,
     pub Flags: u8,
@@ -5122,7 +5687,8 @@ impl Default for NDR64_RANGE_PIPE_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_REPEAT_FORMAT {
     pub FormatCode: u8,
     pub Flags: NDR64_POINTER_REPEAT_FLAGS,
@@ -5140,10 +5706,79 @@ impl Default for NDR64_REPEAT_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_RPC_FLAGS {
     pub _bitfield: u16,
 }
+impl NDR64_RPC_FLAGS {
+    pub const fn Idempotent(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_Idempotent(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Broadcast(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Broadcast(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Maybe(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Maybe(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved0(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_Reserved0(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn HasGuarantee(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_HasGuarantee(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved1(&self) -> u16 {
+        (self._bitfield >> 5) & 7
+    }
+    pub fn set_Reserved1(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(7 << 5)) | ((value & 7) << 5);
+    }
+    pub const fn Message(&self) -> u16 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_Message(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn Reserved2(&self) -> u16 {
+        (self._bitfield >> 9) & 15
+    }
+    pub fn set_Reserved2(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(15 << 9)) | ((value & 15) << 9);
+    }
+    pub const fn InputSynchronous(&self) -> u16 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_InputSynchronous(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn Asynchronous(&self) -> u16 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_Asynchronous(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn WinrtRemoteAsync(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_WinrtRemoteAsync(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for NDR64_RPC_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
@@ -5153,7 +5788,8 @@ impl Default for NDR64_RPC_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_SIMPLE_MEMBER_FORMAT {
     pub FormatCode: u8,
     pub Reserved1: u8,
@@ -5169,7 +5805,8 @@ impl Default for NDR64_SIMPLE_MEMBER_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_SIMPLE_REGION_FORMAT {
     pub FormatCode: u8,
     pub Alignment: u8,
@@ -5185,7 +5822,8 @@ impl Default for NDR64_SIMPLE_REGION_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_SIZED_CONFORMANT_STRING_FORMAT {
     pub Header: NDR64_STRING_HEADER_FORMAT,
     pub SizeDescription: *mut core::ffi::c_void,
@@ -5199,10 +5837,61 @@ impl Default for NDR64_SIZED_CONFORMANT_STRING_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_STRING_FLAGS {
     pub _bitfield: u8,
 }
+impl NDR64_STRING_FLAGS {
+    pub const fn IsSized(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_IsSized(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn IsRanged(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_IsRanged(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved3(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_Reserved3(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved4(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_Reserved4(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn Reserved5(&self) -> u8 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_Reserved5(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved6(&self) -> u8 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_Reserved6(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Reserved7(&self) -> u8 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_Reserved7(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Reserved8(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Reserved8(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for NDR64_STRING_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
@@ -5212,7 +5901,8 @@ impl Default for NDR64_STRING_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_STRING_HEADER_FORMAT {
     pub FormatCode: u8,
     pub Flags: NDR64_STRING_FLAGS,
@@ -5227,10 +5917,61 @@ impl Default for NDR64_STRING_HEADER_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_STRUCTURE_FLAGS {
     pub _bitfield: u8,
 }
+impl NDR64_STRUCTURE_FLAGS {
+    pub const fn HasPointerInfo(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_HasPointerInfo(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn HasMemberInfo(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_HasMemberInfo(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn HasConfArray(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_HasConfArray(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn HasOrigPointerInfo(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_HasOrigPointerInfo(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn HasOrigMemberInfo(&self) -> u8 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_HasOrigMemberInfo(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn Reserved1(&self) -> u8 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_Reserved1(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn Reserved2(&self) -> u8 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_Reserved2(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn Reserved3(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_Reserved3(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for NDR64_STRUCTURE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
@@ -5240,7 +5981,8 @@ impl Default for NDR64_STRUCTURE_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_STRUCTURE_HEADER_FORMAT {
     pub FormatCode: u8,
     pub Alignment: u8,
@@ -5257,7 +5999,8 @@ impl Default for NDR64_STRUCTURE_HEADER_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_SYSTEM_HANDLE_FORMAT {
     pub FormatCode: u8,
     pub HandleType: u8,
@@ -5272,10 +6015,37 @@ impl Default for NDR64_SYSTEM_HANDLE_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_TRANSMIT_AS_FLAGS {
     pub _bitfield: u8,
 }
+impl NDR64_TRANSMIT_AS_FLAGS {
+    pub const fn PresentedTypeIsArray(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_PresentedTypeIsArray(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn PresentedTypeAlign4(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_PresentedTypeAlign4(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn PresentedTypeAlign8(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_PresentedTypeAlign8(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 3) & 31
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(31 << 3)) | ((value & 31) << 3);
+    }
+}
 impl windows_core::TypeKind for NDR64_TRANSMIT_AS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
@@ -5285,7 +6055,8 @@ impl Default for NDR64_TRANSMIT_AS_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_TRANSMIT_AS_FORMAT {
     pub FormatCode: u8,
     pub Flags: u8,
@@ -5305,7 +6076,8 @@ impl Default for NDR64_TRANSMIT_AS_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_TYPE_STRICT_CONTEXT_HANDLE {
     pub FormatCode: u8,
     pub RealFormatCode: u8,
@@ -5323,7 +6095,8 @@ impl Default for NDR64_TYPE_STRICT_CONTEXT_HANDLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_UNION_ARM {
     pub CaseValue: i64,
     pub Type: *mut core::ffi::c_void,
@@ -5338,7 +6111,8 @@ impl Default for NDR64_UNION_ARM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_UNION_ARM_SELECTOR {
     pub Reserved1: u8,
     pub Alignment: u8,
@@ -5354,10 +6128,37 @@ impl Default for NDR64_UNION_ARM_SELECTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_USER_MARSHAL_FLAGS {
     pub _bitfield: u8,
 }
+impl NDR64_USER_MARSHAL_FLAGS {
+    pub const fn Reserved(&self) -> u8 {
+        self._bitfield & 31
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !31) | (value & 31);
+    }
+    pub const fn IID(&self) -> u8 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_IID(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn RefPointer(&self) -> u8 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_RefPointer(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn UniquePointer(&self) -> u8 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_UniquePointer(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for NDR64_USER_MARSHAL_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
@@ -5367,7 +6168,8 @@ impl Default for NDR64_USER_MARSHAL_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_USER_MARSHAL_FORMAT {
     pub FormatCode: u8,
     pub Flags: u8,
@@ -5387,7 +6189,8 @@ impl Default for NDR64_USER_MARSHAL_FORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR64_VAR_ARRAY_HEADER_FORMAT {
     pub FormatCode: u8,
     pub Alignment: u8,
@@ -5406,7 +6209,8 @@ impl Default for NDR64_VAR_ARRAY_HEADER_FORMAT {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR_ALLOC_ALL_NODES_CONTEXT(pub isize);
 impl Default for NDR_ALLOC_ALL_NODES_CONTEXT {
     fn default() -> Self {
@@ -5417,7 +6221,8 @@ impl windows_core::TypeKind for NDR_ALLOC_ALL_NODES_CONTEXT {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR_CS_ROUTINES {
     pub pSizeConvertRoutines: *mut NDR_CS_SIZE_CONVERT_ROUTINES,
     pub pTagGettingRoutines: *mut CS_TAG_GETTING_ROUTINE,
@@ -5431,7 +6236,8 @@ impl Default for NDR_CS_ROUTINES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR_CS_SIZE_CONVERT_ROUTINES {
     pub pfnNetSize: CS_TYPE_NET_SIZE_ROUTINE,
     pub pfnToNetCs: CS_TYPE_TO_NETCS_ROUTINE,
@@ -5447,7 +6253,8 @@ impl Default for NDR_CS_SIZE_CONVERT_ROUTINES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR_EXPR_DESC {
     pub pOffset: *const u16,
     pub pFormatExpr: *mut u8,
@@ -5461,7 +6268,8 @@ impl Default for NDR_EXPR_DESC {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR_POINTER_QUEUE_STATE(pub isize);
 impl Default for NDR_POINTER_QUEUE_STATE {
     fn default() -> Self {
@@ -5472,7 +6280,8 @@ impl windows_core::TypeKind for NDR_POINTER_QUEUE_STATE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR_SCONTEXT {
     pub pad: [*mut core::ffi::c_void; 2],
     pub userContext: *mut core::ffi::c_void,
@@ -5530,7 +6339,8 @@ impl Default for NDR_USER_MARSHAL_INFO_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NDR_USER_MARSHAL_INFO_LEVEL1 {
     pub Buffer: *mut core::ffi::c_void,
     pub BufferSize: u32,
@@ -5556,7 +6366,8 @@ impl Default for NDR_USER_MARSHAL_INFO_LEVEL1 {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PNDR_ASYNC_MESSAGE(pub isize);
 impl Default for PNDR_ASYNC_MESSAGE {
     fn default() -> Self {
@@ -5567,7 +6378,8 @@ impl windows_core::TypeKind for PNDR_ASYNC_MESSAGE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PNDR_CORRELATION_INFO(pub isize);
 impl Default for PNDR_CORRELATION_INFO {
     fn default() -> Self {
@@ -5578,7 +6390,8 @@ impl windows_core::TypeKind for PNDR_CORRELATION_INFO {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RDR_CALLOUT_STATE {
     pub LastError: RPC_STATUS,
     pub LastEEInfo: *mut core::ffi::c_void,
@@ -5625,7 +6438,8 @@ impl Default for RPC_ASYNC_NOTIFICATION_INFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_IO")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_ASYNC_NOTIFICATION_INFO_0 {
     pub NotificationRoutine: PFN_RPCNOTIFICATION_ROUTINE,
     pub hThread: super::super::Foundation::HANDLE,
@@ -5642,7 +6456,8 @@ impl Default for RPC_ASYNC_NOTIFICATION_INFO_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_IO")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_ASYNC_NOTIFICATION_INFO_1 {
     pub hIOPort: super::super::Foundation::HANDLE,
     pub dwNumberOfBytesTransferred: u32,
@@ -5661,7 +6476,8 @@ impl Default for RPC_ASYNC_NOTIFICATION_INFO_1 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_IO")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_ASYNC_NOTIFICATION_INFO_2 {
     pub hWnd: super::super::Foundation::HWND,
     pub Msg: u32,
@@ -5703,7 +6519,8 @@ impl Default for RPC_ASYNC_STATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_BINDING_HANDLE_OPTIONS_V1 {
     pub Version: u32,
     pub Flags: RPC_BINDING_HANDLE_OPTIONS_FLAGS,
@@ -5720,7 +6537,8 @@ impl Default for RPC_BINDING_HANDLE_OPTIONS_V1 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_BINDING_HANDLE_SECURITY_V1_A {
     pub Version: u32,
     pub ServerPrincName: *mut u8,
@@ -5741,7 +6559,8 @@ impl Default for RPC_BINDING_HANDLE_SECURITY_V1_A {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_BINDING_HANDLE_SECURITY_V1_W {
     pub Version: u32,
     pub ServerPrincName: *mut u16,
@@ -5825,7 +6644,8 @@ impl Default for RPC_BINDING_HANDLE_TEMPLATE_V1_W_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_BINDING_VECTOR {
     pub Count: u32,
     pub BindingH: [*mut core::ffi::c_void; 1],
@@ -5839,7 +6659,8 @@ impl Default for RPC_BINDING_VECTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_CALL_ATTRIBUTES_V1_A {
     pub Version: u32,
     pub Flags: u32,
@@ -5860,7 +6681,8 @@ impl Default for RPC_CALL_ATTRIBUTES_V1_A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_CALL_ATTRIBUTES_V1_W {
     pub Version: u32,
     pub Flags: u32,
@@ -5881,7 +6703,8 @@ impl Default for RPC_CALL_ATTRIBUTES_V1_W {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_CALL_ATTRIBUTES_V2_A {
     pub Version: u32,
     pub Flags: u32,
@@ -5911,7 +6734,8 @@ impl Default for RPC_CALL_ATTRIBUTES_V2_A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_CALL_ATTRIBUTES_V2_W {
     pub Version: u32,
     pub Flags: u32,
@@ -5941,7 +6765,8 @@ impl Default for RPC_CALL_ATTRIBUTES_V2_W {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_CALL_ATTRIBUTES_V3_A {
     pub Version: u32,
     pub Flags: u32,
@@ -5973,7 +6798,8 @@ impl Default for RPC_CALL_ATTRIBUTES_V3_A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_CALL_ATTRIBUTES_V3_W {
     pub Version: u32,
     pub Flags: u32,
@@ -6005,7 +6831,8 @@ impl Default for RPC_CALL_ATTRIBUTES_V3_W {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_CALL_LOCAL_ADDRESS_V1 {
     pub Version: u32,
     pub Buffer: *mut core::ffi::c_void,
@@ -6021,7 +6848,8 @@ impl Default for RPC_CALL_LOCAL_ADDRESS_V1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_CLIENT_INFORMATION1 {
     pub UserName: *mut u8,
     pub ComputerName: *mut u8,
@@ -6037,7 +6865,8 @@ impl Default for RPC_CLIENT_INFORMATION1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_CLIENT_INTERFACE {
     pub Length: u32,
     pub InterfaceId: RPC_SYNTAX_IDENTIFIER,
@@ -6058,7 +6887,8 @@ impl Default for RPC_CLIENT_INTERFACE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_C_OPT_COOKIE_AUTH_DESCRIPTOR {
     pub BufferSize: u32,
     pub Buffer: windows_core::PSTR,
@@ -6072,7 +6902,8 @@ impl Default for RPC_C_OPT_COOKIE_AUTH_DESCRIPTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_DISPATCH_TABLE {
     pub DispatchTableCount: u32,
     pub DispatchTable: RPC_DISPATCH_FUNCTION,
@@ -6119,7 +6950,8 @@ impl Default for RPC_EE_INFO_PARAM_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_ENDPOINT_TEMPLATEA {
     pub Version: u32,
     pub ProtSeq: windows_core::PSTR,
@@ -6136,7 +6968,8 @@ impl Default for RPC_ENDPOINT_TEMPLATEA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_ENDPOINT_TEMPLATEW {
     pub Version: u32,
     pub ProtSeq: windows_core::PWSTR,
@@ -6153,7 +6986,8 @@ impl Default for RPC_ENDPOINT_TEMPLATEW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_ERROR_ENUM_HANDLE {
     pub Signature: u32,
     pub CurrentPos: *mut core::ffi::c_void,
@@ -6204,7 +7038,8 @@ impl Default for RPC_EXTENDED_ERROR_INFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_HTTP_TRANSPORT_CREDENTIALS_A {
     pub TransportCredentials: *mut SEC_WINNT_AUTH_IDENTITY_A,
     pub Flags: RPC_C_HTTP_FLAGS,
@@ -6222,7 +7057,8 @@ impl Default for RPC_HTTP_TRANSPORT_CREDENTIALS_A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_HTTP_TRANSPORT_CREDENTIALS_V2_A {
     pub TransportCredentials: *mut SEC_WINNT_AUTH_IDENTITY_A,
     pub Flags: RPC_C_HTTP_FLAGS,
@@ -6243,7 +7079,8 @@ impl Default for RPC_HTTP_TRANSPORT_CREDENTIALS_V2_A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_HTTP_TRANSPORT_CREDENTIALS_V2_W {
     pub TransportCredentials: *mut SEC_WINNT_AUTH_IDENTITY_W,
     pub Flags: RPC_C_HTTP_FLAGS,
@@ -6264,7 +7101,8 @@ impl Default for RPC_HTTP_TRANSPORT_CREDENTIALS_V2_W {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_HTTP_TRANSPORT_CREDENTIALS_V3_A {
     pub TransportCredentials: *mut core::ffi::c_void,
     pub Flags: RPC_C_HTTP_FLAGS,
@@ -6285,7 +7123,8 @@ impl Default for RPC_HTTP_TRANSPORT_CREDENTIALS_V3_A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_HTTP_TRANSPORT_CREDENTIALS_V3_W {
     pub TransportCredentials: *mut core::ffi::c_void,
     pub Flags: RPC_C_HTTP_FLAGS,
@@ -6306,7 +7145,8 @@ impl Default for RPC_HTTP_TRANSPORT_CREDENTIALS_V3_W {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_HTTP_TRANSPORT_CREDENTIALS_W {
     pub TransportCredentials: *mut SEC_WINNT_AUTH_IDENTITY_W,
     pub Flags: RPC_C_HTTP_FLAGS,
@@ -6324,7 +7164,8 @@ impl Default for RPC_HTTP_TRANSPORT_CREDENTIALS_W {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_IF_ID {
     pub Uuid: windows_core::GUID,
     pub VersMajor: u16,
@@ -6339,7 +7180,8 @@ impl Default for RPC_IF_ID {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_IF_ID_VECTOR {
     pub Count: u32,
     pub IfId: [*mut RPC_IF_ID; 1],
@@ -6353,7 +7195,8 @@ impl Default for RPC_IF_ID_VECTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_IMPORT_CONTEXT_P {
     pub LookupContext: *mut core::ffi::c_void,
     pub ProposedHandle: *mut core::ffi::c_void,
@@ -6368,7 +7211,8 @@ impl Default for RPC_IMPORT_CONTEXT_P {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_INTERFACE_TEMPLATEA {
     pub Version: u32,
     pub IfSpec: *mut core::ffi::c_void,
@@ -6391,7 +7235,8 @@ impl Default for RPC_INTERFACE_TEMPLATEA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_INTERFACE_TEMPLATEW {
     pub Version: u32,
     pub IfSpec: *mut core::ffi::c_void,
@@ -6414,7 +7259,8 @@ impl Default for RPC_INTERFACE_TEMPLATEW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_MESSAGE {
     pub Handle: *mut core::ffi::c_void,
     pub DataRepresentation: u32,
@@ -6437,7 +7283,8 @@ impl Default for RPC_MESSAGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_POLICY {
     pub Length: u32,
     pub EndpointFlags: u32,
@@ -6452,7 +7299,8 @@ impl Default for RPC_POLICY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_PROTSEQ_ENDPOINT {
     pub RpcProtocolSequence: *mut u8,
     pub Endpoint: *mut u8,
@@ -6466,7 +7314,8 @@ impl Default for RPC_PROTSEQ_ENDPOINT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_PROTSEQ_VECTORA {
     pub Count: u32,
     pub Protseq: [*mut u8; 1],
@@ -6480,7 +7329,8 @@ impl Default for RPC_PROTSEQ_VECTORA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_PROTSEQ_VECTORW {
     pub Count: u32,
     pub Protseq: [*mut u16; 1],
@@ -6495,7 +7345,8 @@ impl Default for RPC_PROTSEQ_VECTORW {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_SECURITY_QOS {
     pub Version: u32,
     pub Capabilities: RPC_C_QOS_CAPABILITIES,
@@ -6821,7 +7672,8 @@ impl Default for RPC_SECURITY_QOS_V5_W_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_SEC_CONTEXT_KEY_INFO {
     pub EncryptAlgorithm: u32,
     pub KeySize: u32,
@@ -6836,7 +7688,8 @@ impl Default for RPC_SEC_CONTEXT_KEY_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_SERVER_INTERFACE {
     pub Length: u32,
     pub InterfaceId: RPC_SYNTAX_IDENTIFIER,
@@ -6857,7 +7710,8 @@ impl Default for RPC_SERVER_INTERFACE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_STATS_VECTOR {
     pub Count: u32,
     pub Stats: [u32; 1],
@@ -6871,7 +7725,8 @@ impl Default for RPC_STATS_VECTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_SYNTAX_IDENTIFIER {
     pub SyntaxGUID: windows_core::GUID,
     pub SyntaxVersion: RPC_VERSION,
@@ -6885,7 +7740,8 @@ impl Default for RPC_SYNTAX_IDENTIFIER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_TRANSFER_SYNTAX {
     pub Uuid: windows_core::GUID,
     pub VersMajor: u16,
@@ -6900,7 +7756,8 @@ impl Default for RPC_TRANSFER_SYNTAX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RPC_VERSION {
     pub MajorVersion: u16,
     pub MinorVersion: u16,
@@ -6914,7 +7771,8 @@ impl Default for RPC_VERSION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCONTEXT_QUEUE {
     pub NumberOfObjects: u32,
     pub ArrayOfObjects: *mut *mut NDR_SCONTEXT,
@@ -6928,7 +7786,8 @@ impl Default for SCONTEXT_QUEUE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SEC_WINNT_AUTH_IDENTITY_A {
     pub User: *mut u8,
     pub UserLength: u32,
@@ -6947,7 +7806,8 @@ impl Default for SEC_WINNT_AUTH_IDENTITY_A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SEC_WINNT_AUTH_IDENTITY_W {
     pub User: *mut u16,
     pub UserLength: u32,
@@ -6967,7 +7827,8 @@ impl Default for SEC_WINNT_AUTH_IDENTITY_W {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USER_MARSHAL_CB {
     pub Flags: u32,
     pub pStubMsg: *mut MIDL_STUB_MESSAGE,
@@ -6988,7 +7849,8 @@ impl Default for USER_MARSHAL_CB {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USER_MARSHAL_ROUTINE_QUADRUPLE {
     pub pfnBufferSize: USER_MARSHAL_SIZING_ROUTINE,
     pub pfnMarshall: USER_MARSHAL_MARSHALLING_ROUTINE,
@@ -7004,7 +7866,8 @@ impl Default for USER_MARSHAL_ROUTINE_QUADRUPLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UUID_VECTOR {
     pub Count: u32,
     pub Uuid: [*mut windows_core::GUID; 1],
@@ -7019,7 +7882,8 @@ impl Default for UUID_VECTOR {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XMIT_ROUTINE_QUINTUPLE {
     pub pfnTranslateToXmit: XMIT_HELPER_ROUTINE,
     pub pfnTranslateFromXmit: XMIT_HELPER_ROUTINE,
@@ -7037,7 +7901,8 @@ impl Default for XMIT_ROUTINE_QUINTUPLE {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct _NDR_PROC_CONTEXT(pub isize);
 impl Default for _NDR_PROC_CONTEXT {
     fn default() -> Self {
@@ -23,6 +23,7 @@ pub struct CONDITION_OPERATION(pub i32);
 impl windows_core::TypeKind for CONDITION_OPERATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CONDITION_OPERATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CONDITION_OPERATION").field(&self.0).finish()
@@ -34,6 +35,7 @@ pub struct CONDITION_TYPE(pub i32);
 impl windows_core::TypeKind for CONDITION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CONDITION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CONDITION_TYPE").field(&self.0).finish()
@@ -12297,6 +12297,7 @@ pub struct ACCESS_MASKENUM(pub i32);
 impl windows_core::TypeKind for ACCESS_MASKENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ACCESS_MASKENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ACCESS_MASKENUM").field(&self.0).finish()
@@ -12308,6 +12309,7 @@ pub struct AUTH_TYPE(pub i32);
 impl windows_core::TypeKind for AUTH_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AUTH_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AUTH_TYPE").field(&self.0).finish()
@@ -12319,6 +12321,7 @@ pub struct CASE_REQUIREMENT(pub i32);
 impl windows_core::TypeKind for CASE_REQUIREMENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CASE_REQUIREMENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CASE_REQUIREMENT").field(&self.0).finish()
@@ -12330,6 +12333,7 @@ pub struct CHANNEL_AGENT_FLAGS(pub i32);
 impl windows_core::TypeKind for CHANNEL_AGENT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CHANNEL_AGENT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CHANNEL_AGENT_FLAGS").field(&self.0).finish()
@@ -12341,6 +12345,7 @@ pub struct CLUSION_REASON(pub i32);
 impl windows_core::TypeKind for CLUSION_REASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLUSION_REASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLUSION_REASON").field(&self.0).finish()
@@ -12352,6 +12357,7 @@ pub struct CONDITION_CREATION_OPTIONS(pub i32);
 impl windows_core::TypeKind for CONDITION_CREATION_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CONDITION_CREATION_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CONDITION_CREATION_OPTIONS").field(&self.0).finish()
@@ -12396,6 +12402,7 @@ pub struct CREATESUBSCRIPTIONFLAGS(pub i32);
 impl windows_core::TypeKind for CREATESUBSCRIPTIONFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CREATESUBSCRIPTIONFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CREATESUBSCRIPTIONFLAGS").field(&self.0).finish()
@@ -12407,6 +12414,7 @@ pub struct CatalogPausedReason(pub i32);
 impl windows_core::TypeKind for CatalogPausedReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CatalogPausedReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CatalogPausedReason").field(&self.0).finish()
@@ -12418,6 +12426,7 @@ pub struct CatalogStatus(pub i32);
 impl windows_core::TypeKind for CatalogStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CatalogStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CatalogStatus").field(&self.0).finish()
@@ -12429,6 +12438,7 @@ pub struct DBACCESSORFLAGSENUM(pub i32);
 impl windows_core::TypeKind for DBACCESSORFLAGSENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBACCESSORFLAGSENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBACCESSORFLAGSENUM").field(&self.0).finish()
@@ -12440,6 +12450,7 @@ pub struct DBASYNCHOPENUM(pub i32);
 impl windows_core::TypeKind for DBASYNCHOPENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBASYNCHOPENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBASYNCHOPENUM").field(&self.0).finish()
@@ -12451,6 +12462,7 @@ pub struct DBASYNCHPHASEENUM(pub i32);
 impl windows_core::TypeKind for DBASYNCHPHASEENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBASYNCHPHASEENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBASYNCHPHASEENUM").field(&self.0).finish()
@@ -12462,6 +12474,7 @@ pub struct DBBINDFLAGENUM(pub i32);
 impl windows_core::TypeKind for DBBINDFLAGENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBBINDFLAGENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBBINDFLAGENUM").field(&self.0).finish()
@@ -12473,6 +12486,7 @@ pub struct DBBINDSTATUSENUM(pub i32);
 impl windows_core::TypeKind for DBBINDSTATUSENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBBINDSTATUSENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBBINDSTATUSENUM").field(&self.0).finish()
@@ -12484,6 +12498,7 @@ pub struct DBBINDURLFLAGENUM(pub i32);
 impl windows_core::TypeKind for DBBINDURLFLAGENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBBINDURLFLAGENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBBINDURLFLAGENUM").field(&self.0).finish()
@@ -12495,6 +12510,7 @@ pub struct DBBINDURLSTATUSENUM(pub i32);
 impl windows_core::TypeKind for DBBINDURLSTATUSENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBBINDURLSTATUSENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBBINDURLSTATUSENUM").field(&self.0).finish()
@@ -12506,6 +12522,7 @@ pub struct DBBOOKMARK(pub i32);
 impl windows_core::TypeKind for DBBOOKMARK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBBOOKMARK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBBOOKMARK").field(&self.0).finish()
@@ -12517,6 +12534,7 @@ pub struct DBCOLUMNDESCFLAGSENUM(pub i32);
 impl windows_core::TypeKind for DBCOLUMNDESCFLAGSENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBCOLUMNDESCFLAGSENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBCOLUMNDESCFLAGSENUM").field(&self.0).finish()
@@ -12528,6 +12546,7 @@ pub struct DBCOLUMNFLAGS15ENUM(pub i32);
 impl windows_core::TypeKind for DBCOLUMNFLAGS15ENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBCOLUMNFLAGS15ENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBCOLUMNFLAGS15ENUM").field(&self.0).finish()
@@ -12539,6 +12558,7 @@ pub struct DBCOLUMNFLAGSDEPRECATED(pub i32);
 impl windows_core::TypeKind for DBCOLUMNFLAGSDEPRECATED {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBCOLUMNFLAGSDEPRECATED {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBCOLUMNFLAGSDEPRECATED").field(&self.0).finish()
@@ -12550,6 +12570,7 @@ pub struct DBCOLUMNFLAGSENUM(pub i32);
 impl windows_core::TypeKind for DBCOLUMNFLAGSENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBCOLUMNFLAGSENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBCOLUMNFLAGSENUM").field(&self.0).finish()
@@ -12561,6 +12582,7 @@ pub struct DBCOLUMNFLAGSENUM20(pub i32);
 impl windows_core::TypeKind for DBCOLUMNFLAGSENUM20 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBCOLUMNFLAGSENUM20 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBCOLUMNFLAGSENUM20").field(&self.0).finish()
@@ -12572,6 +12594,7 @@ pub struct DBCOLUMNFLAGSENUM21(pub i32);
 impl windows_core::TypeKind for DBCOLUMNFLAGSENUM21 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBCOLUMNFLAGSENUM21 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBCOLUMNFLAGSENUM21").field(&self.0).finish()
@@ -12583,6 +12606,7 @@ pub struct DBCOLUMNFLAGSENUM26(pub i32);
 impl windows_core::TypeKind for DBCOLUMNFLAGSENUM26 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBCOLUMNFLAGSENUM26 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBCOLUMNFLAGSENUM26").field(&self.0).finish()
@@ -12594,6 +12618,7 @@ pub struct DBCOMMANDPERSISTFLAGENUM(pub i32);
 impl windows_core::TypeKind for DBCOMMANDPERSISTFLAGENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBCOMMANDPERSISTFLAGENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBCOMMANDPERSISTFLAGENUM").field(&self.0).finish()
@@ -12605,6 +12630,7 @@ pub struct DBCOMMANDPERSISTFLAGENUM21(pub i32);
 impl windows_core::TypeKind for DBCOMMANDPERSISTFLAGENUM21 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBCOMMANDPERSISTFLAGENUM21 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBCOMMANDPERSISTFLAGENUM21").field(&self.0).finish()
@@ -12616,6 +12642,7 @@ pub struct DBCOMPAREENUM(pub i32);
 impl windows_core::TypeKind for DBCOMPAREENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBCOMPAREENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBCOMPAREENUM").field(&self.0).finish()
@@ -12627,6 +12654,7 @@ pub struct DBCOMPAREOPSENUM(pub i32);
 impl windows_core::TypeKind for DBCOMPAREOPSENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBCOMPAREOPSENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBCOMPAREOPSENUM").field(&self.0).finish()
@@ -12638,6 +12666,7 @@ pub struct DBCOMPAREOPSENUM20(pub i32);
 impl windows_core::TypeKind for DBCOMPAREOPSENUM20 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBCOMPAREOPSENUM20 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBCOMPAREOPSENUM20").field(&self.0).finish()
@@ -12649,6 +12678,7 @@ pub struct DBCONSTRAINTTYPEENUM(pub i32);
 impl windows_core::TypeKind for DBCONSTRAINTTYPEENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBCONSTRAINTTYPEENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBCONSTRAINTTYPEENUM").field(&self.0).finish()
@@ -12660,6 +12690,7 @@ pub struct DBCONVERTFLAGSENUM(pub i32);
 impl windows_core::TypeKind for DBCONVERTFLAGSENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBCONVERTFLAGSENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBCONVERTFLAGSENUM").field(&self.0).finish()
@@ -12671,6 +12702,7 @@ pub struct DBCONVERTFLAGSENUM20(pub i32);
 impl windows_core::TypeKind for DBCONVERTFLAGSENUM20 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBCONVERTFLAGSENUM20 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBCONVERTFLAGSENUM20").field(&self.0).finish()
@@ -12682,6 +12714,7 @@ pub struct DBCOPYFLAGSENUM(pub i32);
 impl windows_core::TypeKind for DBCOPYFLAGSENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBCOPYFLAGSENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBCOPYFLAGSENUM").field(&self.0).finish()
@@ -12693,6 +12726,7 @@ pub struct DBCOSTUNITENUM(pub i32);
 impl windows_core::TypeKind for DBCOSTUNITENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBCOSTUNITENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBCOSTUNITENUM").field(&self.0).finish()
@@ -12704,6 +12738,7 @@ pub struct DBDATACONVERTENUM(pub i32);
 impl windows_core::TypeKind for DBDATACONVERTENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBDATACONVERTENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBDATACONVERTENUM").field(&self.0).finish()
@@ -12715,6 +12750,7 @@ pub struct DBDEFERRABILITYENUM(pub i32);
 impl windows_core::TypeKind for DBDEFERRABILITYENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBDEFERRABILITYENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBDEFERRABILITYENUM").field(&self.0).finish()
@@ -12726,6 +12762,7 @@ pub struct DBDELETEFLAGSENUM(pub i32);
 impl windows_core::TypeKind for DBDELETEFLAGSENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBDELETEFLAGSENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBDELETEFLAGSENUM").field(&self.0).finish()
@@ -12737,6 +12774,7 @@ pub struct DBEVENTPHASEENUM(pub i32);
 impl windows_core::TypeKind for DBEVENTPHASEENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBEVENTPHASEENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBEVENTPHASEENUM").field(&self.0).finish()
@@ -12748,6 +12786,7 @@ pub struct DBEXECLIMITSENUM(pub i32);
 impl windows_core::TypeKind for DBEXECLIMITSENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBEXECLIMITSENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBEXECLIMITSENUM").field(&self.0).finish()
@@ -12759,6 +12798,7 @@ pub struct DBINDEX_COL_ORDERENUM(pub i32);
 impl windows_core::TypeKind for DBINDEX_COL_ORDERENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBINDEX_COL_ORDERENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBINDEX_COL_ORDERENUM").field(&self.0).finish()
@@ -12770,6 +12810,7 @@ pub struct DBLITERALENUM(pub i32);
 impl windows_core::TypeKind for DBLITERALENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBLITERALENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBLITERALENUM").field(&self.0).finish()
@@ -12781,6 +12822,7 @@ pub struct DBLITERALENUM20(pub i32);
 impl windows_core::TypeKind for DBLITERALENUM20 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBLITERALENUM20 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBLITERALENUM20").field(&self.0).finish()
@@ -12792,6 +12834,7 @@ pub struct DBLITERALENUM21(pub i32);
 impl windows_core::TypeKind for DBLITERALENUM21 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBLITERALENUM21 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBLITERALENUM21").field(&self.0).finish()
@@ -12803,6 +12846,7 @@ pub struct DBMATCHTYPEENUM(pub i32);
 impl windows_core::TypeKind for DBMATCHTYPEENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBMATCHTYPEENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBMATCHTYPEENUM").field(&self.0).finish()
@@ -12814,6 +12858,7 @@ pub struct DBMEMOWNERENUM(pub i32);
 impl windows_core::TypeKind for DBMEMOWNERENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBMEMOWNERENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBMEMOWNERENUM").field(&self.0).finish()
@@ -12825,6 +12870,7 @@ pub struct DBMOVEFLAGSENUM(pub i32);
 impl windows_core::TypeKind for DBMOVEFLAGSENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBMOVEFLAGSENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBMOVEFLAGSENUM").field(&self.0).finish()
@@ -12836,6 +12882,7 @@ pub struct DBPARAMFLAGSENUM(pub i32);
 impl windows_core::TypeKind for DBPARAMFLAGSENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBPARAMFLAGSENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBPARAMFLAGSENUM").field(&self.0).finish()
@@ -12847,6 +12894,7 @@ pub struct DBPARAMFLAGSENUM20(pub i32);
 impl windows_core::TypeKind for DBPARAMFLAGSENUM20 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBPARAMFLAGSENUM20 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBPARAMFLAGSENUM20").field(&self.0).finish()
@@ -12858,6 +12906,7 @@ pub struct DBPARAMIOENUM(pub i32);
 impl windows_core::TypeKind for DBPARAMIOENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBPARAMIOENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBPARAMIOENUM").field(&self.0).finish()
@@ -12869,6 +12918,7 @@ pub struct DBPARTENUM(pub i32);
 impl windows_core::TypeKind for DBPARTENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBPARTENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBPARTENUM").field(&self.0).finish()
@@ -12880,6 +12930,7 @@ pub struct DBPENDINGSTATUSENUM(pub i32);
 impl windows_core::TypeKind for DBPENDINGSTATUSENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBPENDINGSTATUSENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBPENDINGSTATUSENUM").field(&self.0).finish()
@@ -12891,6 +12942,7 @@ pub struct DBPOSITIONFLAGSENUM(pub i32);
 impl windows_core::TypeKind for DBPOSITIONFLAGSENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBPOSITIONFLAGSENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBPOSITIONFLAGSENUM").field(&self.0).finish()
@@ -12902,6 +12954,7 @@ pub struct DBPROMPTOPTIONSENUM(pub i32);
 impl windows_core::TypeKind for DBPROMPTOPTIONSENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBPROMPTOPTIONSENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBPROMPTOPTIONSENUM").field(&self.0).finish()
@@ -12913,6 +12966,7 @@ pub struct DBPROPENUM(pub i32);
 impl windows_core::TypeKind for DBPROPENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBPROPENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBPROPENUM").field(&self.0).finish()
@@ -12924,6 +12978,7 @@ pub struct DBPROPENUM15(pub i32);
 impl windows_core::TypeKind for DBPROPENUM15 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBPROPENUM15 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBPROPENUM15").field(&self.0).finish()
@@ -12935,6 +12990,7 @@ pub struct DBPROPENUM20(pub i32);
 impl windows_core::TypeKind for DBPROPENUM20 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBPROPENUM20 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBPROPENUM20").field(&self.0).finish()
@@ -12946,6 +13002,7 @@ pub struct DBPROPENUM21(pub i32);
 impl windows_core::TypeKind for DBPROPENUM21 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBPROPENUM21 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBPROPENUM21").field(&self.0).finish()
@@ -12957,6 +13014,7 @@ pub struct DBPROPENUM25(pub i32);
 impl windows_core::TypeKind for DBPROPENUM25 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBPROPENUM25 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBPROPENUM25").field(&self.0).finish()
@@ -12968,6 +13026,7 @@ pub struct DBPROPENUM25_DEPRECATED(pub i32);
 impl windows_core::TypeKind for DBPROPENUM25_DEPRECATED {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBPROPENUM25_DEPRECATED {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBPROPENUM25_DEPRECATED").field(&self.0).finish()
@@ -12979,6 +13038,7 @@ pub struct DBPROPENUM26(pub i32);
 impl windows_core::TypeKind for DBPROPENUM26 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBPROPENUM26 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBPROPENUM26").field(&self.0).finish()
@@ -12990,6 +13050,7 @@ pub struct DBPROPENUMDEPRECATED(pub i32);
 impl windows_core::TypeKind for DBPROPENUMDEPRECATED {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBPROPENUMDEPRECATED {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBPROPENUMDEPRECATED").field(&self.0).finish()
@@ -13001,6 +13062,7 @@ pub struct DBPROPFLAGSENUM(pub i32);
 impl windows_core::TypeKind for DBPROPFLAGSENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBPROPFLAGSENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBPROPFLAGSENUM").field(&self.0).finish()
@@ -13012,6 +13074,7 @@ pub struct DBPROPFLAGSENUM21(pub i32);
 impl windows_core::TypeKind for DBPROPFLAGSENUM21 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBPROPFLAGSENUM21 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBPROPFLAGSENUM21").field(&self.0).finish()
@@ -13023,6 +13086,7 @@ pub struct DBPROPFLAGSENUM25(pub i32);
 impl windows_core::TypeKind for DBPROPFLAGSENUM25 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBPROPFLAGSENUM25 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBPROPFLAGSENUM25").field(&self.0).finish()
@@ -13034,6 +13098,7 @@ pub struct DBPROPFLAGSENUM26(pub i32);
 impl windows_core::TypeKind for DBPROPFLAGSENUM26 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBPROPFLAGSENUM26 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBPROPFLAGSENUM26").field(&self.0).finish()
@@ -13045,6 +13110,7 @@ pub struct DBPROPOPTIONSENUM(pub i32);
 impl windows_core::TypeKind for DBPROPOPTIONSENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBPROPOPTIONSENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBPROPOPTIONSENUM").field(&self.0).finish()
@@ -13056,6 +13122,7 @@ pub struct DBPROPSTATUSENUM(pub i32);
 impl windows_core::TypeKind for DBPROPSTATUSENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBPROPSTATUSENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBPROPSTATUSENUM").field(&self.0).finish()
@@ -13067,6 +13134,7 @@ pub struct DBPROPSTATUSENUM21(pub i32);
 impl windows_core::TypeKind for DBPROPSTATUSENUM21 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBPROPSTATUSENUM21 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBPROPSTATUSENUM21").field(&self.0).finish()
@@ -13078,6 +13146,7 @@ pub struct DBRANGEENUM(pub i32);
 impl windows_core::TypeKind for DBRANGEENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBRANGEENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBRANGEENUM").field(&self.0).finish()
@@ -13089,6 +13158,7 @@ pub struct DBRANGEENUM20(pub i32);
 impl windows_core::TypeKind for DBRANGEENUM20 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBRANGEENUM20 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBRANGEENUM20").field(&self.0).finish()
@@ -13100,6 +13170,7 @@ pub struct DBREASONENUM(pub i32);
 impl windows_core::TypeKind for DBREASONENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBREASONENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBREASONENUM").field(&self.0).finish()
@@ -13111,6 +13182,7 @@ pub struct DBREASONENUM15(pub i32);
 impl windows_core::TypeKind for DBREASONENUM15 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBREASONENUM15 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBREASONENUM15").field(&self.0).finish()
@@ -13122,6 +13194,7 @@ pub struct DBREASONENUM25(pub i32);
 impl windows_core::TypeKind for DBREASONENUM25 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBREASONENUM25 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBREASONENUM25").field(&self.0).finish()
@@ -13133,6 +13206,7 @@ pub struct DBRESOURCEKINDENUM(pub i32);
 impl windows_core::TypeKind for DBRESOURCEKINDENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBRESOURCEKINDENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBRESOURCEKINDENUM").field(&self.0).finish()
@@ -13144,6 +13218,7 @@ pub struct DBRESULTFLAGENUM(pub i32);
 impl windows_core::TypeKind for DBRESULTFLAGENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBRESULTFLAGENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBRESULTFLAGENUM").field(&self.0).finish()
@@ -13155,6 +13230,7 @@ pub struct DBROWCHANGEKINDENUM(pub i32);
 impl windows_core::TypeKind for DBROWCHANGEKINDENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBROWCHANGEKINDENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBROWCHANGEKINDENUM").field(&self.0).finish()
@@ -13166,6 +13242,7 @@ pub struct DBROWSTATUSENUM(pub i32);
 impl windows_core::TypeKind for DBROWSTATUSENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBROWSTATUSENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBROWSTATUSENUM").field(&self.0).finish()
@@ -13177,6 +13254,7 @@ pub struct DBROWSTATUSENUM20(pub i32);
 impl windows_core::TypeKind for DBROWSTATUSENUM20 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBROWSTATUSENUM20 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBROWSTATUSENUM20").field(&self.0).finish()
@@ -13188,6 +13266,7 @@ pub struct DBSEEKENUM(pub i32);
 impl windows_core::TypeKind for DBSEEKENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBSEEKENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBSEEKENUM").field(&self.0).finish()
@@ -13199,6 +13278,7 @@ pub struct DBSORTENUM(pub i32);
 impl windows_core::TypeKind for DBSORTENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBSORTENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBSORTENUM").field(&self.0).finish()
@@ -13210,6 +13290,7 @@ pub struct DBSOURCETYPEENUM(pub i32);
 impl windows_core::TypeKind for DBSOURCETYPEENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBSOURCETYPEENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBSOURCETYPEENUM").field(&self.0).finish()
@@ -13221,6 +13302,7 @@ pub struct DBSOURCETYPEENUM20(pub i32);
 impl windows_core::TypeKind for DBSOURCETYPEENUM20 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBSOURCETYPEENUM20 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBSOURCETYPEENUM20").field(&self.0).finish()
@@ -13232,6 +13314,7 @@ pub struct DBSOURCETYPEENUM25(pub i32);
 impl windows_core::TypeKind for DBSOURCETYPEENUM25 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBSOURCETYPEENUM25 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBSOURCETYPEENUM25").field(&self.0).finish()
@@ -13243,6 +13326,7 @@ pub struct DBSTATUSENUM(pub i32);
 impl windows_core::TypeKind for DBSTATUSENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBSTATUSENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBSTATUSENUM").field(&self.0).finish()
@@ -13254,6 +13338,7 @@ pub struct DBSTATUSENUM20(pub i32);
 impl windows_core::TypeKind for DBSTATUSENUM20 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBSTATUSENUM20 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBSTATUSENUM20").field(&self.0).finish()
@@ -13265,6 +13350,7 @@ pub struct DBSTATUSENUM21(pub i32);
 impl windows_core::TypeKind for DBSTATUSENUM21 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBSTATUSENUM21 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBSTATUSENUM21").field(&self.0).finish()
@@ -13276,6 +13362,7 @@ pub struct DBSTATUSENUM25(pub i32);
 impl windows_core::TypeKind for DBSTATUSENUM25 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBSTATUSENUM25 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBSTATUSENUM25").field(&self.0).finish()
@@ -13287,6 +13374,7 @@ pub struct DBSTATUSENUM26(pub i32);
 impl windows_core::TypeKind for DBSTATUSENUM26 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBSTATUSENUM26 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBSTATUSENUM26").field(&self.0).finish()
@@ -13298,6 +13386,7 @@ pub struct DBTABLESTATISTICSTYPE26(pub i32);
 impl windows_core::TypeKind for DBTABLESTATISTICSTYPE26 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBTABLESTATISTICSTYPE26 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBTABLESTATISTICSTYPE26").field(&self.0).finish()
@@ -13309,6 +13398,7 @@ pub struct DBTYPEENUM(pub i32);
 impl windows_core::TypeKind for DBTYPEENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBTYPEENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBTYPEENUM").field(&self.0).finish()
@@ -13320,6 +13410,7 @@ pub struct DBTYPEENUM15(pub i32);
 impl windows_core::TypeKind for DBTYPEENUM15 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBTYPEENUM15 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBTYPEENUM15").field(&self.0).finish()
@@ -13331,6 +13422,7 @@ pub struct DBTYPEENUM20(pub i32);
 impl windows_core::TypeKind for DBTYPEENUM20 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBTYPEENUM20 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBTYPEENUM20").field(&self.0).finish()
@@ -13342,6 +13434,7 @@ pub struct DBUPDELRULEENUM(pub i32);
 impl windows_core::TypeKind for DBUPDELRULEENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBUPDELRULEENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBUPDELRULEENUM").field(&self.0).finish()
@@ -13353,6 +13446,7 @@ pub struct DBWATCHMODEENUM(pub i32);
 impl windows_core::TypeKind for DBWATCHMODEENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBWATCHMODEENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBWATCHMODEENUM").field(&self.0).finish()
@@ -13364,6 +13458,7 @@ pub struct DBWATCHNOTIFYENUM(pub i32);
 impl windows_core::TypeKind for DBWATCHNOTIFYENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DBWATCHNOTIFYENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DBWATCHNOTIFYENUM").field(&self.0).finish()
@@ -13375,6 +13470,7 @@ pub struct DCINFOTYPEENUM(pub i32);
 impl windows_core::TypeKind for DCINFOTYPEENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DCINFOTYPEENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DCINFOTYPEENUM").field(&self.0).finish()
@@ -13386,6 +13482,7 @@ pub struct DELIVERY_AGENT_FLAGS(pub i32);
 impl windows_core::TypeKind for DELIVERY_AGENT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DELIVERY_AGENT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DELIVERY_AGENT_FLAGS").field(&self.0).finish()
@@ -13397,6 +13494,7 @@ pub struct EBindInfoOptions(pub i32);
 impl windows_core::TypeKind for EBindInfoOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EBindInfoOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EBindInfoOptions").field(&self.0).finish()
@@ -13408,6 +13506,7 @@ pub struct FOLLOW_FLAGS(pub i32);
 impl windows_core::TypeKind for FOLLOW_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FOLLOW_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FOLLOW_FLAGS").field(&self.0).finish()
@@ -13419,6 +13518,7 @@ pub struct INTERVAL_LIMIT_KIND(pub i32);
 impl windows_core::TypeKind for INTERVAL_LIMIT_KIND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INTERVAL_LIMIT_KIND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INTERVAL_LIMIT_KIND").field(&self.0).finish()
@@ -13430,6 +13530,7 @@ pub struct KAGREQDIAGFLAGSENUM(pub i32);
 impl windows_core::TypeKind for KAGREQDIAGFLAGSENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KAGREQDIAGFLAGSENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KAGREQDIAGFLAGSENUM").field(&self.0).finish()
@@ -13441,6 +13542,7 @@ pub struct LOCKMODEENUM(pub i32);
 impl windows_core::TypeKind for LOCKMODEENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LOCKMODEENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LOCKMODEENUM").field(&self.0).finish()
@@ -13452,6 +13554,7 @@ pub struct MSDSDBINITPROPENUM(pub i32);
 impl windows_core::TypeKind for MSDSDBINITPROPENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSDSDBINITPROPENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSDSDBINITPROPENUM").field(&self.0).finish()
@@ -13463,6 +13566,7 @@ pub struct MSDSSESSIONPROPENUM(pub i32);
 impl windows_core::TypeKind for MSDSSESSIONPROPENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MSDSSESSIONPROPENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MSDSSESSIONPROPENUM").field(&self.0).finish()
@@ -13474,6 +13578,7 @@ pub struct NAMED_ENTITY_CERTAINTY(pub i32);
 impl windows_core::TypeKind for NAMED_ENTITY_CERTAINTY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NAMED_ENTITY_CERTAINTY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NAMED_ENTITY_CERTAINTY").field(&self.0).finish()
@@ -13485,6 +13590,7 @@ pub struct OSPCOMP(pub i32);
 impl windows_core::TypeKind for OSPCOMP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OSPCOMP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OSPCOMP").field(&self.0).finish()
@@ -13496,6 +13602,7 @@ pub struct OSPFIND(pub i32);
 impl windows_core::TypeKind for OSPFIND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OSPFIND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OSPFIND").field(&self.0).finish()
@@ -13507,6 +13614,7 @@ pub struct OSPFORMAT(pub i32);
 impl windows_core::TypeKind for OSPFORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OSPFORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OSPFORMAT").field(&self.0).finish()
@@ -13518,6 +13626,7 @@ pub struct OSPRW(pub i32);
 impl windows_core::TypeKind for OSPRW {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OSPRW {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OSPRW").field(&self.0).finish()
@@ -13529,6 +13638,7 @@ pub struct OSPXFER(pub i32);
 impl windows_core::TypeKind for OSPXFER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OSPXFER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OSPXFER").field(&self.0).finish()
@@ -13540,6 +13650,7 @@ pub struct PRIORITIZE_FLAGS(pub i32);
 impl windows_core::TypeKind for PRIORITIZE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PRIORITIZE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PRIORITIZE_FLAGS").field(&self.0).finish()
@@ -13551,6 +13662,7 @@ pub struct PRIORITY_LEVEL(pub i32);
 impl windows_core::TypeKind for PRIORITY_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PRIORITY_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PRIORITY_LEVEL").field(&self.0).finish()
@@ -13562,6 +13674,7 @@ pub struct PROXY_ACCESS(pub i32);
 impl windows_core::TypeKind for PROXY_ACCESS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROXY_ACCESS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROXY_ACCESS").field(&self.0).finish()
@@ -13573,6 +13686,7 @@ pub struct QUERY_PARSER_MANAGER_OPTION(pub i32);
 impl windows_core::TypeKind for QUERY_PARSER_MANAGER_OPTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for QUERY_PARSER_MANAGER_OPTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("QUERY_PARSER_MANAGER_OPTION").field(&self.0).finish()
@@ -13584,6 +13698,7 @@ pub struct ROWSETEVENT_ITEMSTATE(pub i32);
 impl windows_core::TypeKind for ROWSETEVENT_ITEMSTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ROWSETEVENT_ITEMSTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ROWSETEVENT_ITEMSTATE").field(&self.0).finish()
@@ -13595,6 +13710,7 @@ pub struct ROWSETEVENT_TYPE(pub i32);
 impl windows_core::TypeKind for ROWSETEVENT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ROWSETEVENT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ROWSETEVENT_TYPE").field(&self.0).finish()
@@ -13606,6 +13722,7 @@ pub struct SEARCH_INDEXING_PHASE(pub i32);
 impl windows_core::TypeKind for SEARCH_INDEXING_PHASE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SEARCH_INDEXING_PHASE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SEARCH_INDEXING_PHASE").field(&self.0).finish()
@@ -13617,6 +13734,7 @@ pub struct SEARCH_KIND_OF_CHANGE(pub i32);
 impl windows_core::TypeKind for SEARCH_KIND_OF_CHANGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SEARCH_KIND_OF_CHANGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SEARCH_KIND_OF_CHANGE").field(&self.0).finish()
@@ -13628,6 +13746,7 @@ pub struct SEARCH_NOTIFICATION_PRIORITY(pub i32);
 impl windows_core::TypeKind for SEARCH_NOTIFICATION_PRIORITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SEARCH_NOTIFICATION_PRIORITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SEARCH_NOTIFICATION_PRIORITY").field(&self.0).finish()
@@ -13639,6 +13758,7 @@ pub struct SEARCH_QUERY_SYNTAX(pub i32);
 impl windows_core::TypeKind for SEARCH_QUERY_SYNTAX {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SEARCH_QUERY_SYNTAX {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SEARCH_QUERY_SYNTAX").field(&self.0).finish()
@@ -13650,6 +13770,7 @@ pub struct SEARCH_TERM_EXPANSION(pub i32);
 impl windows_core::TypeKind for SEARCH_TERM_EXPANSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SEARCH_TERM_EXPANSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SEARCH_TERM_EXPANSION").field(&self.0).finish()
@@ -13661,6 +13782,7 @@ pub struct SQLINTERVAL(pub i32);
 impl windows_core::TypeKind for SQLINTERVAL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SQLINTERVAL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SQLINTERVAL").field(&self.0).finish()
@@ -13672,6 +13794,7 @@ pub struct SQLVARENUM(pub i32);
 impl windows_core::TypeKind for SQLVARENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SQLVARENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SQLVARENUM").field(&self.0).finish()
@@ -13683,6 +13806,7 @@ pub struct STRUCTURED_QUERY_MULTIOPTION(pub i32);
 impl windows_core::TypeKind for STRUCTURED_QUERY_MULTIOPTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STRUCTURED_QUERY_MULTIOPTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STRUCTURED_QUERY_MULTIOPTION").field(&self.0).finish()
@@ -13694,6 +13818,7 @@ pub struct STRUCTURED_QUERY_PARSE_ERROR(pub i32);
 impl windows_core::TypeKind for STRUCTURED_QUERY_PARSE_ERROR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STRUCTURED_QUERY_PARSE_ERROR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STRUCTURED_QUERY_PARSE_ERROR").field(&self.0).finish()
@@ -13705,6 +13830,7 @@ pub struct STRUCTURED_QUERY_RESOLVE_OPTION(pub i32);
 impl windows_core::TypeKind for STRUCTURED_QUERY_RESOLVE_OPTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STRUCTURED_QUERY_RESOLVE_OPTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STRUCTURED_QUERY_RESOLVE_OPTION").field(&self.0).finish()
@@ -13749,6 +13875,7 @@ pub struct STRUCTURED_QUERY_SINGLE_OPTION(pub i32);
 impl windows_core::TypeKind for STRUCTURED_QUERY_SINGLE_OPTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STRUCTURED_QUERY_SINGLE_OPTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STRUCTURED_QUERY_SINGLE_OPTION").field(&self.0).finish()
@@ -13760,6 +13887,7 @@ pub struct STRUCTURED_QUERY_SYNTAX(pub i32);
 impl windows_core::TypeKind for STRUCTURED_QUERY_SYNTAX {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STRUCTURED_QUERY_SYNTAX {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STRUCTURED_QUERY_SYNTAX").field(&self.0).finish()
@@ -13771,6 +13899,7 @@ pub struct SUBSCRIPTIONINFOFLAGS(pub i32);
 impl windows_core::TypeKind for SUBSCRIPTIONINFOFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SUBSCRIPTIONINFOFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SUBSCRIPTIONINFOFLAGS").field(&self.0).finish()
@@ -13782,6 +13911,7 @@ pub struct SUBSCRIPTIONSCHEDULE(pub i32);
 impl windows_core::TypeKind for SUBSCRIPTIONSCHEDULE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SUBSCRIPTIONSCHEDULE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SUBSCRIPTIONSCHEDULE").field(&self.0).finish()
@@ -13793,6 +13923,7 @@ pub struct SUBSCRIPTIONTYPE(pub i32);
 impl windows_core::TypeKind for SUBSCRIPTIONTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SUBSCRIPTIONTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SUBSCRIPTIONTYPE").field(&self.0).finish()
@@ -13804,13 +13935,15 @@ pub struct WEBCRAWL_RECURSEFLAGS(pub i32);
 impl windows_core::TypeKind for WEBCRAWL_RECURSEFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WEBCRAWL_RECURSEFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WEBCRAWL_RECURSEFLAGS").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AUTHENTICATION_INFO {
     pub dwSize: u32,
     pub atAuthenticationType: AUTH_TYPE,
@@ -13826,7 +13959,8 @@ impl Default for AUTHENTICATION_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BUCKETCATEGORIZE {
     pub cBuckets: u32,
     pub Distribution: u32,
@@ -13877,7 +14011,8 @@ impl Default for CATEGORIZATION_0 {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com_StructuredStorage"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CATEGORIZATIONSET {
     pub cCat: u32,
     pub aCat: *mut CATEGORIZATION,
@@ -13894,7 +14029,8 @@ impl Default for CATEGORIZATIONSET {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com_StructuredStorage"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COLUMNSET {
     pub cCol: u32,
     pub aCol: *mut super::super::Storage::IndexServer::FULLPROPSPEC,
@@ -13935,7 +14071,8 @@ pub const CSearchScopeRule: windows_core::GUID = windows_core::GUID::from_u128(0
 pub const CompoundCondition: windows_core::GUID = windows_core::GUID::from_u128(0x116f8d13_101e_4fa5_84d4_ff8279381935);
 pub const ConditionFactory: windows_core::GUID = windows_core::GUID::from_u128(0xe03e85b0_7be3_4000_ba98_6c13de9fa486);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DATE_STRUCT {
     pub year: i16,
     pub month: u16,
@@ -14329,7 +14466,8 @@ impl Default for DBCOST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DBDATE {
     pub year: i16,
     pub month: u16,
@@ -14344,7 +14482,8 @@ impl Default for DBDATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DBDATETIM4 {
     pub numdays: u16,
     pub nummins: u16,
@@ -14358,7 +14497,8 @@ impl Default for DBDATETIM4 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DBDATETIME {
     pub dtdays: i32,
     pub dttime: u32,
@@ -14530,7 +14670,8 @@ impl Default for DBLITERALINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DBMONEY {
     pub mnyhigh: i32,
     pub mnylow: u32,
@@ -14973,7 +15114,8 @@ impl Default for DBROWWATCHCHANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DBTIME {
     pub hour: u16,
     pub minute: u16,
@@ -15032,7 +15174,8 @@ impl Default for DBTIMESTAMP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DBVARYBIN {
     pub len: i16,
     pub array: [u8; 8001],
@@ -15046,7 +15189,8 @@ impl Default for DBVARYBIN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DBVARYCHAR {
     pub len: i16,
     pub str: [i8; 8001],
@@ -15094,7 +15238,8 @@ impl Default for DBVECTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DB_NUMERIC {
     pub precision: u8,
     pub scale: u8,
@@ -15110,7 +15255,8 @@ impl Default for DB_NUMERIC {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DB_VARNUMERIC {
     pub precision: u8,
     pub scale: i8,
@@ -15126,7 +15272,8 @@ impl Default for DB_VARNUMERIC {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DCINFO {
     pub eInfoType: u32,
     pub vData: core::mem::ManuallyDrop<windows_core::VARIANT>,
@@ -15186,7 +15333,8 @@ impl Default for ERRORINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILTERED_DATA_SOURCES {
     pub pwcsExtension: windows_core::PCWSTR,
     pub pwcsMime: windows_core::PCWSTR,
@@ -15203,7 +15351,8 @@ impl Default for FILTERED_DATA_SOURCES {
 }
 pub const FilterRegistration: windows_core::GUID = windows_core::GUID::from_u128(0x9e175b8d_f52a_11d8_b9a5_505054503030);
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HACCESSOR(pub usize);
 impl HACCESSOR {
     pub fn is_invalid(&self) -> bool {
@@ -15219,7 +15368,8 @@ impl windows_core::TypeKind for HACCESSOR {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HITRANGE {
     pub iPosition: u32,
     pub cLength: u32,
@@ -15233,7 +15383,8 @@ impl Default for HITRANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct INCREMENTAL_ACCESS_INFO {
     pub dwSize: u32,
     pub ftLastModifiedTime: super::super::Foundation::FILETIME,
@@ -15247,7 +15398,8 @@ impl Default for INCREMENTAL_ACCESS_INFO {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ITEMPROP {
     pub variantValue: core::mem::ManuallyDrop<windows_core::VARIANT>,
     pub pwszName: windows_core::PWSTR,
@@ -15266,7 +15418,8 @@ impl Default for ITEMPROP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ITEM_INFO {
     pub dwSize: u32,
     pub pcwszFromEMail: windows_core::PCWSTR,
@@ -15284,7 +15437,8 @@ impl Default for ITEM_INFO {
 }
 pub const Interval: windows_core::GUID = windows_core::GUID::from_u128(0xd957171f_4bf9_4de2_bcd5_c70a7ca55836);
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KAGGETDIAG {
     pub ulSize: u32,
     pub vDiagInfo: core::mem::ManuallyDrop<windows_core::VARIANT>,
@@ -15305,7 +15459,8 @@ impl Default for KAGGETDIAG {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Variant")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KAGREQDIAG {
     pub ulDiagFlags: u32,
     pub vt: super::Variant::VARENUM,
@@ -15389,7 +15544,8 @@ impl Default for NATLANGUAGERESTRICTION {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com_StructuredStorage"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NODERESTRICTION {
     pub cRes: u32,
     pub paRes: *mut *mut RESTRICTION,
@@ -15407,7 +15563,8 @@ impl Default for NODERESTRICTION {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com_StructuredStorage"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NOTRESTRICTION {
     pub pRes: *mut RESTRICTION,
 }
@@ -15492,7 +15649,8 @@ impl Default for PROPERTYRESTRICTION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROXY_INFO {
     pub dwSize: u32,
     pub pcwszUserAgent: windows_core::PCWSTR,
@@ -15513,7 +15671,8 @@ impl Default for PROXY_INFO {
 pub const QueryParser: windows_core::GUID = windows_core::GUID::from_u128(0xb72f8fd8_0fab_4dd9_bdbf_245a6ce1485b);
 pub const QueryParserManager: windows_core::GUID = windows_core::GUID::from_u128(0x5088b39a_29b4_4d9d_8245_4ee289222f66);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RANGECATEGORIZE {
     pub cRange: u32,
     pub aRangeBegin: *mut windows_core::PROPVARIANT,
@@ -15648,7 +15807,8 @@ impl Default for RMTPACK {
 }
 pub const RootBinder: windows_core::GUID = windows_core::GUID::from_u128(0xff151822_b0bf_11d1_a80d_000000000000);
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SEARCH_COLUMN_PROPERTIES {
     pub Value: core::mem::ManuallyDrop<windows_core::PROPVARIANT>,
     pub lcid: u32,
@@ -15668,7 +15828,8 @@ impl Default for SEARCH_COLUMN_PROPERTIES {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SEARCH_ITEM_CHANGE {
     pub Change: SEARCH_KIND_OF_CHANGE,
     pub Priority: SEARCH_NOTIFICATION_PRIORITY,
@@ -15687,7 +15848,8 @@ impl Default for SEARCH_ITEM_CHANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SEARCH_ITEM_INDEXING_STATUS {
     pub dwDocID: u32,
     pub hrIndexingStatus: windows_core::HRESULT,
@@ -15701,7 +15863,8 @@ impl Default for SEARCH_ITEM_INDEXING_STATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SEARCH_ITEM_PERSISTENT_CHANGE {
     pub Change: SEARCH_KIND_OF_CHANGE,
     pub URL: windows_core::PWSTR,
@@ -15816,7 +15979,8 @@ impl Default for SORTKEY {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com_StructuredStorage"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SORTSET {
     pub cCol: u32,
     pub aCol: *mut SORTKEY,
@@ -15832,7 +15996,8 @@ impl Default for SORTSET {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SQLPERF {
     pub TimerResolution: u32,
     pub SQLidu: u32,
@@ -15877,7 +16042,8 @@ impl Default for SQLPERF {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SQL_DAY_SECOND_STRUCT {
     pub day: u32,
     pub hour: u32,
@@ -15923,7 +16089,8 @@ impl Default for SQL_INTERVAL_STRUCT_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SQL_NUMERIC_STRUCT {
     pub precision: u8,
     pub scale: i8,
@@ -15939,7 +16106,8 @@ impl Default for SQL_NUMERIC_STRUCT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SQL_YEAR_MONTH_STRUCT {
     pub year: u32,
     pub month: u32,
@@ -15953,7 +16121,8 @@ impl Default for SQL_YEAR_MONTH_STRUCT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SSERRORINFO {
     pub pwszMessage: windows_core::PWSTR,
     pub pwszServer: windows_core::PWSTR,
@@ -16055,7 +16224,8 @@ impl Default for SSVARIANT_0_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SSVARIANT_0_1 {
     pub sActualLength: i16,
     pub sMaxLength: i16,
@@ -16074,7 +16244,8 @@ impl Default for SSVARIANT_0_1 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SSVARIANT_0_2 {
     pub sActualLength: i16,
     pub sMaxLength: i16,
@@ -16095,7 +16266,8 @@ impl Default for SSVARIANT_0_2 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SSVARIANT_0_3 {
     pub sActualLength: i16,
     pub sMaxLength: i16,
@@ -16116,7 +16288,8 @@ impl Default for SSVARIANT_0_3 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SSVARIANT_0_4 {
     pub dwActualLength: u32,
     pub rgMetadata: [u8; 16],
@@ -16133,7 +16306,8 @@ impl Default for SSVARIANT_0_4 {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SUBSCRIPTIONINFO {
     pub cbSize: u32,
     pub fUpdateFlags: u32,
@@ -16169,7 +16343,8 @@ impl Default for SUBSCRIPTIONINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SUBSCRIPTIONITEMINFO {
     pub cbSize: u32,
     pub dwFlags: u32,
@@ -16187,7 +16362,8 @@ impl Default for SUBSCRIPTIONITEMINFO {
 }
 pub const SubscriptionMgr: windows_core::GUID = windows_core::GUID::from_u128(0xabbe31d0_6dae_11d0_beca_00c04fd940be);
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TEXT_SOURCE {
     pub pfnFillTextBuffer: PFNFILLTEXTBUFFER,
     pub awcBuffer: windows_core::PCWSTR,
@@ -16203,7 +16379,8 @@ impl Default for TEXT_SOURCE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TIMEOUT_INFO {
     pub dwSize: u32,
     pub dwConnectTimeout: u32,
@@ -16218,7 +16395,8 @@ impl Default for TIMEOUT_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TIMESTAMP_STRUCT {
     pub year: i16,
     pub month: u16,
@@ -16237,7 +16415,8 @@ impl Default for TIMESTAMP_STRUCT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TIME_STRUCT {
     pub hour: u16,
     pub minute: u16,
@@ -16253,7 +16432,8 @@ impl Default for TIME_STRUCT {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com_StructuredStorage"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct VECTORRESTRICTION {
     pub Node: NODERESTRICTION,
     pub RankMethod: u32,
@@ -16272,3 +16452,5 @@ pub type PFNFILLTEXTBUFFER = Option<unsafe extern "system" fn(ptextsource: *mut
 pub type SQL_ASYNC_NOTIFICATION_CALLBACK = Option<unsafe extern "system" fn(pcontext: *const core::ffi::c_void, flast: super::super::Foundation::BOOL) -> i16>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,4469 @@
+impl windows_core::imp::ProxyVtable for DataSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn getDataMember(this: *mut core::ffi::c_void, bstrdm: *const u16, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "DataSource", "getDataMember", 3usize, |this| ((**(this as *const *const DataSource_Vtbl)).getDataMember)(this, bstrdm, riid, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn getDataMemberName(this: *mut core::ffi::c_void, lindex: i32, pbstrdm: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "DataSource", "getDataMemberName", 4usize, |this| ((**(this as *const *const DataSource_Vtbl)).getDataMemberName)(this, lindex, pbstrdm), |result| Some(*result))
+        }
+        unsafe extern "system" fn getDataMemberCount(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "DataSource", "getDataMemberCount", 5usize, |this| ((**(this as *const *const DataSource_Vtbl)).getDataMemberCount)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn addDataSourceListener(this: *mut core::ffi::c_void, pdsl: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "DataSource", "addDataSourceListener", 6usize, |this| ((**(this as *const *const DataSource_Vtbl)).addDataSourceListener)(this, pdsl), |result| Some(*result))
+        }
+        unsafe extern "system" fn removeDataSourceListener(this: *mut core::ffi::c_void, pdsl: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "DataSource", "removeDataSourceListener", 7usize, |this| ((**(this as *const *const DataSource_Vtbl)).removeDataSourceListener)(this, pdsl), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            getDataMember,
+            getDataMemberName,
+            getDataMemberCount,
+            addDataSourceListener,
+            removeDataSourceListener,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<DataSource as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for DataSourceListener_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn dataMemberChanged(this: *mut core::ffi::c_void, bstrdm: *const u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "DataSourceListener", "dataMemberChanged", 3usize, |this| ((**(this as *const *const DataSourceListener_Vtbl)).dataMemberChanged)(this, bstrdm), |result| Some(*result))
+        }
+        unsafe extern "system" fn dataMemberAdded(this: *mut core::ffi::c_void, bstrdm: *const u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "DataSourceListener", "dataMemberAdded", 4usize, |this| ((**(this as *const *const DataSourceListener_Vtbl)).dataMemberAdded)(this, bstrdm), |result| Some(*result))
+        }
+        unsafe extern "system" fn dataMemberRemoved(this: *mut core::ffi::c_void, bstrdm: *const u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "DataSourceListener", "dataMemberRemoved", 5usize, |this| ((**(this as *const *const DataSourceListener_Vtbl)).dataMemberRemoved)(this, bstrdm), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, dataMemberChanged, dataMemberAdded, dataMemberRemoved }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<DataSourceListener as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for DataSourceObject_Vtbl {
+    const PROXY: Self = { Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<DataSourceObject as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAccessor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddRefAccessor(this: *mut core::ffi::c_void, haccessor: HACCESSOR, pcrefcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessor", "AddRefAccessor", 3usize, |this| ((**(this as *const *const IAccessor_Vtbl)).AddRefAccessor)(this, haccessor, pcrefcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateAccessor(this: *mut core::ffi::c_void, dwaccessorflags: u32, cbindings: usize, rgbindings: *const DBBINDING, cbrowsize: usize, phaccessor: *mut HACCESSOR, rgstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessor", "CreateAccessor", 4usize, |this| ((**(this as *const *const IAccessor_Vtbl)).CreateAccessor)(this, dwaccessorflags, cbindings, rgbindings, cbrowsize, phaccessor, rgstatus), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetBindings(this: *mut core::ffi::c_void, haccessor: HACCESSOR, pdwaccessorflags: *mut u32, pcbindings: *mut usize, prgbindings: *mut *mut DBBINDING) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessor", "GetBindings", 5usize, |this| ((**(this as *const *const IAccessor_Vtbl)).GetBindings)(this, haccessor, pdwaccessorflags, pcbindings, prgbindings), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseAccessor(this: *mut core::ffi::c_void, haccessor: HACCESSOR, pcrefcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessor", "ReleaseAccessor", 6usize, |this| ((**(this as *const *const IAccessor_Vtbl)).ReleaseAccessor)(this, haccessor, pcrefcount), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddRefAccessor,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateAccessor,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateAccessor: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetBindings,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetBindings: 0,
+            ReleaseAccessor,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccessor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_IndexServer")]
+impl windows_core::imp::ProxyVtable for IAlterIndex_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn AlterIndex(this: *mut core::ffi::c_void, ptableid: *const super::super::Storage::IndexServer::DBID, pindexid: *const super::super::Storage::IndexServer::DBID, pnewindexid: *const super::super::Storage::IndexServer::DBID, cpropertysets: u32, rgpropertysets: *mut DBPROPSET) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlterIndex", "AlterIndex", 3usize, |this| ((**(this as *const *const IAlterIndex_Vtbl)).AlterIndex)(this, ptableid, pindexid, pnewindexid, cpropertysets, rgpropertysets), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            AlterIndex,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            AlterIndex: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAlterIndex as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IAlterTable_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn AlterColumn(this: *mut core::ffi::c_void, ptableid: *const super::super::Storage::IndexServer::DBID, pcolumnid: *const super::super::Storage::IndexServer::DBID, dwcolumndescflags: u32, pcolumndesc: *const DBCOLUMNDESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlterTable", "AlterColumn", 3usize, |this| ((**(this as *const *const IAlterTable_Vtbl)).AlterColumn)(this, ptableid, pcolumnid, dwcolumndescflags, pcolumndesc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn AlterTable(this: *mut core::ffi::c_void, ptableid: *const super::super::Storage::IndexServer::DBID, pnewtableid: *const super::super::Storage::IndexServer::DBID, cpropertysets: u32, rgpropertysets: *mut DBPROPSET) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAlterTable", "AlterTable", 4usize, |this| ((**(this as *const *const IAlterTable_Vtbl)).AlterTable)(this, ptableid, pnewtableid, cpropertysets, rgpropertysets), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com"))]
+            AlterColumn,
+            #[cfg(not(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com")))]
+            AlterColumn: 0,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            AlterTable,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            AlterTable: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAlterTable as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IBindResource_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Bind(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, dwbindurlflags: u32, rguid: *const windows_core::GUID, riid: *const windows_core::GUID, pauthenticate: *mut core::ffi::c_void, pimplsession: *mut DBIMPLICITSESSION, pdwbindstatus: *mut u32, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBindResource", "Bind", 3usize, |this| ((**(this as *const *const IBindResource_Vtbl)).Bind)(this, punkouter, pwszurl, dwbindurlflags, rguid, riid, pauthenticate, pimplsession, pdwbindstatus, ppunk), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Bind,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Bind: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBindResource as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChapteredRowset_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddRefChapter(this: *mut core::ffi::c_void, hchapter: usize, pcrefcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChapteredRowset", "AddRefChapter", 3usize, |this| ((**(this as *const *const IChapteredRowset_Vtbl)).AddRefChapter)(this, hchapter, pcrefcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseChapter(this: *mut core::ffi::c_void, hchapter: usize, pcrefcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChapteredRowset", "ReleaseChapter", 4usize, |this| ((**(this as *const *const IChapteredRowset_Vtbl)).ReleaseChapter)(this, hchapter, pcrefcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddRefChapter, ReleaseChapter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChapteredRowset as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_IndexServer")]
+impl windows_core::imp::ProxyVtable for IColumnMapper_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn GetPropInfoFromName(this: *mut core::ffi::c_void, wcspropname: windows_core::PCWSTR, pppropid: *mut *mut super::super::Storage::IndexServer::DBID, pproptype: *mut u16, puiwidth: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColumnMapper", "GetPropInfoFromName", 3usize, |this| ((**(this as *const *const IColumnMapper_Vtbl)).GetPropInfoFromName)(this, wcspropname, pppropid, pproptype, puiwidth), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn GetPropInfoFromId(this: *mut core::ffi::c_void, ppropid: *const super::super::Storage::IndexServer::DBID, pwcsname: *mut *mut u16, pproptype: *mut u16, puiwidth: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColumnMapper", "GetPropInfoFromId", 4usize, |this| ((**(this as *const *const IColumnMapper_Vtbl)).GetPropInfoFromId)(this, ppropid, pwcsname, pproptype, puiwidth), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn EnumPropInfo(this: *mut core::ffi::c_void, ientry: u32, pwcsname: *const *const u16, pppropid: *mut *mut super::super::Storage::IndexServer::DBID, pproptype: *mut u16, puiwidth: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColumnMapper", "EnumPropInfo", 5usize, |this| ((**(this as *const *const IColumnMapper_Vtbl)).EnumPropInfo)(this, ientry, pwcsname, pppropid, pproptype, puiwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMapUpToDate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColumnMapper", "IsMapUpToDate", 6usize, |this| ((**(this as *const *const IColumnMapper_Vtbl)).IsMapUpToDate)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            GetPropInfoFromName,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            GetPropInfoFromName: 0,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            GetPropInfoFromId,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            GetPropInfoFromId: 0,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            EnumPropInfo,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            EnumPropInfo: 0,
+            IsMapUpToDate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IColumnMapper as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IColumnMapperCreator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetColumnMapper(this: *mut core::ffi::c_void, wcsmachinename: windows_core::PCWSTR, wcscatalogname: windows_core::PCWSTR, ppcolumnmapper: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColumnMapperCreator", "GetColumnMapper", 3usize, |this| ((**(this as *const *const IColumnMapperCreator_Vtbl)).GetColumnMapper)(this, wcsmachinename, wcscatalogname, ppcolumnmapper), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetColumnMapper }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IColumnMapperCreator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IColumnsInfo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn GetColumnInfo(this: *mut core::ffi::c_void, pccolumns: *mut usize, prginfo: *mut *mut DBCOLUMNINFO, ppstringsbuffer: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColumnsInfo", "GetColumnInfo", 3usize, |this| ((**(this as *const *const IColumnsInfo_Vtbl)).GetColumnInfo)(this, pccolumns, prginfo, ppstringsbuffer), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn MapColumnIDs(this: *mut core::ffi::c_void, ccolumnids: usize, rgcolumnids: *const super::super::Storage::IndexServer::DBID, rgcolumns: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColumnsInfo", "MapColumnIDs", 4usize, |this| ((**(this as *const *const IColumnsInfo_Vtbl)).MapColumnIDs)(this, ccolumnids, rgcolumnids, rgcolumns), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com"))]
+            GetColumnInfo,
+            #[cfg(not(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com")))]
+            GetColumnInfo: 0,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            MapColumnIDs,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            MapColumnIDs: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IColumnsInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IColumnsInfo2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn GetRestrictedColumnInfo(this: *mut core::ffi::c_void, ccolumnidmasks: usize, rgcolumnidmasks: *const super::super::Storage::IndexServer::DBID, dwflags: u32, pccolumns: *mut usize, prgcolumnids: *mut *mut super::super::Storage::IndexServer::DBID, prgcolumninfo: *mut *mut DBCOLUMNINFO, ppstringsbuffer: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColumnsInfo2", "GetRestrictedColumnInfo", 5usize, |this| ((**(this as *const *const IColumnsInfo2_Vtbl)).GetRestrictedColumnInfo)(this, ccolumnidmasks, rgcolumnidmasks, dwflags, pccolumns, prgcolumnids, prgcolumninfo, ppstringsbuffer), |result| Some(*result))
+        }
+        Self {
+            base__: <IColumnsInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com"))]
+            GetRestrictedColumnInfo,
+            #[cfg(not(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com")))]
+            GetRestrictedColumnInfo: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IColumnsInfo2 as windows_core::Interface>::IID || <IColumnsInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_IndexServer")]
+impl windows_core::imp::ProxyVtable for IColumnsRowset_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn GetAvailableColumns(this: *mut core::ffi::c_void, pcoptcolumns: *mut usize, prgoptcolumns: *mut *mut super::super::Storage::IndexServer::DBID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColumnsRowset", "GetAvailableColumns", 3usize, |this| ((**(this as *const *const IColumnsRowset_Vtbl)).GetAvailableColumns)(this, pcoptcolumns, prgoptcolumns), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn GetColumnsRowset(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, coptcolumns: usize, rgoptcolumns: *const super::super::Storage::IndexServer::DBID, riid: *const windows_core::GUID, cpropertysets: u32, rgpropertysets: *mut DBPROPSET, ppcolrowset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IColumnsRowset", "GetColumnsRowset", 4usize, |this| ((**(this as *const *const IColumnsRowset_Vtbl)).GetColumnsRowset)(this, punkouter, coptcolumns, rgoptcolumns, riid, cpropertysets, rgpropertysets, ppcolrowset), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            GetAvailableColumns,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            GetAvailableColumns: 0,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            GetColumnsRowset,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            GetColumnsRowset: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IColumnsRowset as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICommand_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommand", "Cancel", 3usize, |this| ((**(this as *const *const ICommand_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Execute(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, riid: *const windows_core::GUID, pparams: *mut DBPARAMS, pcrowsaffected: *mut isize, pprowset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommand", "Execute", 4usize, |this| ((**(this as *const *const ICommand_Vtbl)).Execute)(this, punkouter, riid, pparams, pcrowsaffected, pprowset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDBSession(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommand", "GetDBSession", 5usize, |this| ((**(this as *const *const ICommand_Vtbl)).GetDBSession)(this, riid, ppsession), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Cancel, Execute, GetDBSession }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICommand as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICommandCost_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAccumulatedCost(this: *mut core::ffi::c_void, pwszrowsetname: windows_core::PCWSTR, pccostlimits: *mut u32, prgcostlimits: *mut *mut DBCOST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandCost", "GetAccumulatedCost", 3usize, |this| ((**(this as *const *const ICommandCost_Vtbl)).GetAccumulatedCost)(this, pwszrowsetname, pccostlimits, prgcostlimits), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCostEstimate(this: *mut core::ffi::c_void, pwszrowsetname: windows_core::PCWSTR, pccostestimates: *mut u32, prgcostestimates: *mut DBCOST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandCost", "GetCostEstimate", 4usize, |this| ((**(this as *const *const ICommandCost_Vtbl)).GetCostEstimate)(this, pwszrowsetname, pccostestimates, prgcostestimates), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCostGoals(this: *mut core::ffi::c_void, pwszrowsetname: windows_core::PCWSTR, pccostgoals: *mut u32, prgcostgoals: *mut DBCOST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandCost", "GetCostGoals", 5usize, |this| ((**(this as *const *const ICommandCost_Vtbl)).GetCostGoals)(this, pwszrowsetname, pccostgoals, prgcostgoals), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCostLimits(this: *mut core::ffi::c_void, pwszrowsetname: windows_core::PCWSTR, pccostlimits: *mut u32, prgcostlimits: *mut DBCOST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandCost", "GetCostLimits", 6usize, |this| ((**(this as *const *const ICommandCost_Vtbl)).GetCostLimits)(this, pwszrowsetname, pccostlimits, prgcostlimits), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCostGoals(this: *mut core::ffi::c_void, pwszrowsetname: windows_core::PCWSTR, ccostgoals: u32, rgcostgoals: *const DBCOST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandCost", "SetCostGoals", 7usize, |this| ((**(this as *const *const ICommandCost_Vtbl)).SetCostGoals)(this, pwszrowsetname, ccostgoals, rgcostgoals), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCostLimits(this: *mut core::ffi::c_void, pwszrowsetname: windows_core::PCWSTR, ccostlimits: u32, prgcostlimits: *const DBCOST, dwexecutionflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandCost", "SetCostLimits", 8usize, |this| ((**(this as *const *const ICommandCost_Vtbl)).SetCostLimits)(this, pwszrowsetname, ccostlimits, prgcostlimits, dwexecutionflags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetAccumulatedCost,
+            GetCostEstimate,
+            GetCostGoals,
+            GetCostLimits,
+            SetCostGoals,
+            SetCostLimits,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICommandCost as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_IndexServer")]
+impl windows_core::imp::ProxyVtable for ICommandPersist_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn DeleteCommand(this: *mut core::ffi::c_void, pcommandid: *const super::super::Storage::IndexServer::DBID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandPersist", "DeleteCommand", 3usize, |this| ((**(this as *const *const ICommandPersist_Vtbl)).DeleteCommand)(this, pcommandid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn GetCurrentCommand(this: *mut core::ffi::c_void, ppcommandid: *mut *mut super::super::Storage::IndexServer::DBID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandPersist", "GetCurrentCommand", 4usize, |this| ((**(this as *const *const ICommandPersist_Vtbl)).GetCurrentCommand)(this, ppcommandid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn LoadCommand(this: *mut core::ffi::c_void, pcommandid: *const super::super::Storage::IndexServer::DBID, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandPersist", "LoadCommand", 5usize, |this| ((**(this as *const *const ICommandPersist_Vtbl)).LoadCommand)(this, pcommandid, dwflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn SaveCommand(this: *mut core::ffi::c_void, pcommandid: *const super::super::Storage::IndexServer::DBID, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandPersist", "SaveCommand", 6usize, |this| ((**(this as *const *const ICommandPersist_Vtbl)).SaveCommand)(this, pcommandid, dwflags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            DeleteCommand,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            DeleteCommand: 0,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            GetCurrentCommand,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            GetCurrentCommand: 0,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            LoadCommand,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            LoadCommand: 0,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            SaveCommand,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            SaveCommand: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICommandPersist as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICommandPrepare_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Prepare(this: *mut core::ffi::c_void, cexpectedruns: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandPrepare", "Prepare", 3usize, |this| ((**(this as *const *const ICommandPrepare_Vtbl)).Prepare)(this, cexpectedruns), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unprepare(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandPrepare", "Unprepare", 4usize, |this| ((**(this as *const *const ICommandPrepare_Vtbl)).Unprepare)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Prepare, Unprepare }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICommandPrepare as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_IndexServer")]
+impl windows_core::imp::ProxyVtable for ICommandProperties_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, cpropertyidsets: u32, rgpropertyidsets: *const DBPROPIDSET, pcpropertysets: *mut u32, prgpropertysets: *mut *mut DBPROPSET) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandProperties", "GetProperties", 3usize, |this| ((**(this as *const *const ICommandProperties_Vtbl)).GetProperties)(this, cpropertyidsets, rgpropertyidsets, pcpropertysets, prgpropertysets), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn SetProperties(this: *mut core::ffi::c_void, cpropertysets: u32, rgpropertysets: *const DBPROPSET) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandProperties", "SetProperties", 4usize, |this| ((**(this as *const *const ICommandProperties_Vtbl)).SetProperties)(this, cpropertysets, rgpropertysets), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            GetProperties,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            GetProperties: 0,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            SetProperties,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            SetProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICommandProperties as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICommandStream_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCommandStream(this: *mut core::ffi::c_void, piid: *mut windows_core::GUID, pguiddialect: *mut windows_core::GUID, ppcommandstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandStream", "GetCommandStream", 3usize, |this| ((**(this as *const *const ICommandStream_Vtbl)).GetCommandStream)(this, piid, pguiddialect, ppcommandstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCommandStream(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, rguiddialect: *const windows_core::GUID, pcommandstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandStream", "SetCommandStream", 4usize, |this| ((**(this as *const *const ICommandStream_Vtbl)).SetCommandStream)(this, riid, rguiddialect, pcommandstream), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCommandStream, SetCommandStream }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICommandStream as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICommandText_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCommandText(this: *mut core::ffi::c_void, pguiddialect: *mut windows_core::GUID, ppwszcommand: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandText", "GetCommandText", 6usize, |this| ((**(this as *const *const ICommandText_Vtbl)).GetCommandText)(this, pguiddialect, ppwszcommand), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCommandText(this: *mut core::ffi::c_void, rguiddialect: *const windows_core::GUID, pwszcommand: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandText", "SetCommandText", 7usize, |this| ((**(this as *const *const ICommandText_Vtbl)).SetCommandText)(this, rguiddialect, pwszcommand), |result| Some(*result))
+        }
+        Self { base__: <ICommand_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCommandText, SetCommandText }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICommandText as windows_core::Interface>::IID || <ICommand_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICommandValidate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ValidateCompletely(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandValidate", "ValidateCompletely", 3usize, |this| ((**(this as *const *const ICommandValidate_Vtbl)).ValidateCompletely)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValidateSyntax(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandValidate", "ValidateSyntax", 4usize, |this| ((**(this as *const *const ICommandValidate_Vtbl)).ValidateSyntax)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ValidateCompletely, ValidateSyntax }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICommandValidate as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICommandWithParameters_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetParameterInfo(this: *mut core::ffi::c_void, pcparams: *mut usize, prgparaminfo: *mut *mut DBPARAMINFO, ppnamesbuffer: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandWithParameters", "GetParameterInfo", 3usize, |this| ((**(this as *const *const ICommandWithParameters_Vtbl)).GetParameterInfo)(this, pcparams, prgparaminfo, ppnamesbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn MapParameterNames(this: *mut core::ffi::c_void, cparamnames: usize, rgparamnames: *const windows_core::PCWSTR, rgparamordinals: *mut isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandWithParameters", "MapParameterNames", 4usize, |this| ((**(this as *const *const ICommandWithParameters_Vtbl)).MapParameterNames)(this, cparamnames, rgparamnames, rgparamordinals), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParameterInfo(this: *mut core::ffi::c_void, cparams: usize, rgparamordinals: *const usize, rgparambindinfo: *const DBPARAMBINDINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICommandWithParameters", "SetParameterInfo", 5usize, |this| ((**(this as *const *const ICommandWithParameters_Vtbl)).SetParameterInfo)(this, cparams, rgparamordinals, rgparambindinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetParameterInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetParameterInfo: 0,
+            MapParameterNames,
+            SetParameterInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICommandWithParameters as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Search_Common"))]
+impl windows_core::imp::ProxyVtable for ICondition_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Search_Common")]
+        unsafe extern "system" fn GetConditionType(this: *mut core::ffi::c_void, pnodetype: *mut Common::CONDITION_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICondition", "GetConditionType", 8usize, |this| ((**(this as *const *const ICondition_Vtbl)).GetConditionType)(this, pnodetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubConditions(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICondition", "GetSubConditions", 9usize, |this| ((**(this as *const *const ICondition_Vtbl)).GetSubConditions)(this, riid, ppv), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Search_Common")]
+        unsafe extern "system" fn GetComparisonInfo(this: *mut core::ffi::c_void, ppszpropertyname: *mut windows_core::PWSTR, pcop: *mut Common::CONDITION_OPERATION, ppropvar: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICondition", "GetComparisonInfo", 10usize, |this| ((**(this as *const *const ICondition_Vtbl)).GetComparisonInfo)(this, ppszpropertyname, pcop, ppropvar), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValueType(this: *mut core::ffi::c_void, ppszvaluetypename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICondition", "GetValueType", 11usize, |this| ((**(this as *const *const ICondition_Vtbl)).GetValueType)(this, ppszvaluetypename), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValueNormalization(this: *mut core::ffi::c_void, ppsznormalization: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICondition", "GetValueNormalization", 12usize, |this| ((**(this as *const *const ICondition_Vtbl)).GetValueNormalization)(this, ppsznormalization), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputTerms(this: *mut core::ffi::c_void, pppropertyterm: *mut *mut core::ffi::c_void, ppoperationterm: *mut *mut core::ffi::c_void, ppvalueterm: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICondition", "GetInputTerms", 13usize, |this| ((**(this as *const *const ICondition_Vtbl)).GetInputTerms)(this, pppropertyterm, ppoperationterm, ppvalueterm), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICondition", "Clone", 14usize, |this| ((**(this as *const *const ICondition_Vtbl)).Clone)(this, ppc), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IPersistStream_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Search_Common")]
+            GetConditionType,
+            #[cfg(not(feature = "Win32_System_Search_Common"))]
+            GetConditionType: 0,
+            GetSubConditions,
+            #[cfg(feature = "Win32_System_Search_Common")]
+            GetComparisonInfo,
+            #[cfg(not(feature = "Win32_System_Search_Common"))]
+            GetComparisonInfo: 0,
+            GetValueType,
+            GetValueNormalization,
+            GetInputTerms,
+            #[cfg(feature = "Win32_System_Com")]
+            Clone,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Clone: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICondition as windows_core::Interface>::IID || <super::Com::IPersistStream_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Search_Common", feature = "Win32_UI_Shell_PropertiesSystem"))]
+impl windows_core::imp::ProxyVtable for ICondition2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLocale(this: *mut core::ffi::c_void, ppszlocalename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICondition2", "GetLocale", 15usize, |this| ((**(this as *const *const ICondition2_Vtbl)).GetLocale)(this, ppszlocalename), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Search_Common", feature = "Win32_UI_Shell_PropertiesSystem"))]
+        unsafe extern "system" fn GetLeafConditionInfo(this: *mut core::ffi::c_void, ppropkey: *mut super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pcop: *mut Common::CONDITION_OPERATION, ppropvar: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICondition2", "GetLeafConditionInfo", 16usize, |this| ((**(this as *const *const ICondition2_Vtbl)).GetLeafConditionInfo)(this, ppropkey, pcop, ppropvar), |result| Some(*result))
+        }
+        Self {
+            base__: <ICondition_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetLocale,
+            #[cfg(all(feature = "Win32_System_Search_Common", feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetLeafConditionInfo,
+            #[cfg(not(all(feature = "Win32_System_Search_Common", feature = "Win32_UI_Shell_PropertiesSystem")))]
+            GetLeafConditionInfo: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICondition2 as windows_core::Interface>::IID || <ICondition_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Search_Common"))]
+impl windows_core::imp::ProxyVtable for IConditionFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn MakeNot(this: *mut core::ffi::c_void, pcsub: *mut core::ffi::c_void, fsimplify: super::super::Foundation::BOOL, ppcresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConditionFactory", "MakeNot", 3usize, |this| ((**(this as *const *const IConditionFactory_Vtbl)).MakeNot)(this, pcsub, fsimplify, ppcresult), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Search_Common"))]
+        unsafe extern "system" fn MakeAndOr(this: *mut core::ffi::c_void, ct: Common::CONDITION_TYPE, peusubs: *mut core::ffi::c_void, fsimplify: super::super::Foundation::BOOL, ppcresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConditionFactory", "MakeAndOr", 4usize, |this| ((**(this as *const *const IConditionFactory_Vtbl)).MakeAndOr)(this, ct, peusubs, fsimplify, ppcresult), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Search_Common"))]
+        unsafe extern "system" fn MakeLeaf(this: *mut core::ffi::c_void, pszpropertyname: windows_core::PCWSTR, cop: Common::CONDITION_OPERATION, pszvaluetype: windows_core::PCWSTR, ppropvar: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, ppropertynameterm: *mut core::ffi::c_void, poperationterm: *mut core::ffi::c_void, pvalueterm: *mut core::ffi::c_void, fexpand: super::super::Foundation::BOOL, ppcresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConditionFactory", "MakeLeaf", 5usize, |this| ((**(this as *const *const IConditionFactory_Vtbl)).MakeLeaf)(this, pszpropertyname, cop, pszvaluetype, ppropvar, ppropertynameterm, poperationterm, pvalueterm, fexpand, ppcresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Resolve(this: *mut core::ffi::c_void, pc: *mut core::ffi::c_void, sqro: STRUCTURED_QUERY_RESOLVE_OPTION, pstreferencetime: *const super::super::Foundation::SYSTEMTIME, ppcresolved: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConditionFactory", "Resolve", 6usize, |this| ((**(this as *const *const IConditionFactory_Vtbl)).Resolve)(this, pc, sqro, pstreferencetime, ppcresolved), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            MakeNot,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            MakeNot: 0,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Search_Common"))]
+            MakeAndOr,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Search_Common")))]
+            MakeAndOr: 0,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Search_Common"))]
+            MakeLeaf,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Search_Common")))]
+            MakeLeaf: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Resolve,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Resolve: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConditionFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Search_Common", feature = "Win32_UI_Shell_Common", feature = "Win32_UI_Shell_PropertiesSystem"))]
+impl windows_core::imp::ProxyVtable for IConditionFactory2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateTrueFalse(this: *mut core::ffi::c_void, fval: super::super::Foundation::BOOL, cco: CONDITION_CREATION_OPTIONS, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConditionFactory2", "CreateTrueFalse", 7usize, |this| ((**(this as *const *const IConditionFactory2_Vtbl)).CreateTrueFalse)(this, fval, cco, riid, ppv), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateNegation(this: *mut core::ffi::c_void, pcsub: *mut core::ffi::c_void, cco: CONDITION_CREATION_OPTIONS, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConditionFactory2", "CreateNegation", 8usize, |this| ((**(this as *const *const IConditionFactory2_Vtbl)).CreateNegation)(this, pcsub, cco, riid, ppv), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Search_Common", feature = "Win32_UI_Shell_Common"))]
+        unsafe extern "system" fn CreateCompoundFromObjectArray(this: *mut core::ffi::c_void, ct: Common::CONDITION_TYPE, poasubs: *mut core::ffi::c_void, cco: CONDITION_CREATION_OPTIONS, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConditionFactory2", "CreateCompoundFromObjectArray", 9usize, |this| ((**(this as *const *const IConditionFactory2_Vtbl)).CreateCompoundFromObjectArray)(this, ct, poasubs, cco, riid, ppv), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Search_Common"))]
+        unsafe extern "system" fn CreateCompoundFromArray(this: *mut core::ffi::c_void, ct: Common::CONDITION_TYPE, ppcondsubs: *const *mut core::ffi::c_void, csubs: u32, cco: CONDITION_CREATION_OPTIONS, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConditionFactory2", "CreateCompoundFromArray", 10usize, |this| ((**(this as *const *const IConditionFactory2_Vtbl)).CreateCompoundFromArray)(this, ct, ppcondsubs, csubs, cco, riid, ppv), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Search_Common", feature = "Win32_UI_Shell_PropertiesSystem"))]
+        unsafe extern "system" fn CreateStringLeaf(this: *mut core::ffi::c_void, propkey: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, cop: Common::CONDITION_OPERATION, pszvalue: windows_core::PCWSTR, pszlocalename: windows_core::PCWSTR, cco: CONDITION_CREATION_OPTIONS, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConditionFactory2", "CreateStringLeaf", 11usize, |this| ((**(this as *const *const IConditionFactory2_Vtbl)).CreateStringLeaf)(this, propkey, cop, pszvalue, pszlocalename, cco, riid, ppv), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Search_Common", feature = "Win32_UI_Shell_PropertiesSystem"))]
+        unsafe extern "system" fn CreateIntegerLeaf(this: *mut core::ffi::c_void, propkey: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, cop: Common::CONDITION_OPERATION, lvalue: i32, cco: CONDITION_CREATION_OPTIONS, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConditionFactory2", "CreateIntegerLeaf", 12usize, |this| ((**(this as *const *const IConditionFactory2_Vtbl)).CreateIntegerLeaf)(this, propkey, cop, lvalue, cco, riid, ppv), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Search_Common", feature = "Win32_UI_Shell_PropertiesSystem"))]
+        unsafe extern "system" fn CreateBooleanLeaf(this: *mut core::ffi::c_void, propkey: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, cop: Common::CONDITION_OPERATION, fvalue: super::super::Foundation::BOOL, cco: CONDITION_CREATION_OPTIONS, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConditionFactory2", "CreateBooleanLeaf", 13usize, |this| ((**(this as *const *const IConditionFactory2_Vtbl)).CreateBooleanLeaf)(this, propkey, cop, fvalue, cco, riid, ppv), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Search_Common", feature = "Win32_UI_Shell_PropertiesSystem"))]
+        unsafe extern "system" fn CreateLeaf(this: *mut core::ffi::c_void, propkey: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, cop: Common::CONDITION_OPERATION, propvar: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, pszsemantictype: windows_core::PCWSTR, pszlocalename: windows_core::PCWSTR, ppropertynameterm: *mut core::ffi::c_void, poperationterm: *mut core::ffi::c_void, pvalueterm: *mut core::ffi::c_void, cco: CONDITION_CREATION_OPTIONS, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConditionFactory2", "CreateLeaf", 14usize, |this| ((**(this as *const *const IConditionFactory2_Vtbl)).CreateLeaf)(this, propkey, cop, propvar, pszsemantictype, pszlocalename, ppropertynameterm, poperationterm, pvalueterm, cco, riid, ppv), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ResolveCondition(this: *mut core::ffi::c_void, pc: *mut core::ffi::c_void, sqro: STRUCTURED_QUERY_RESOLVE_OPTION, pstreferencetime: *const super::super::Foundation::SYSTEMTIME, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConditionFactory2", "ResolveCondition", 15usize, |this| ((**(this as *const *const IConditionFactory2_Vtbl)).ResolveCondition)(this, pc, sqro, pstreferencetime, riid, ppv), |result| Some(*result))
+        }
+        Self {
+            base__: <IConditionFactory_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateTrueFalse,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateNegation,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateNegation: 0,
+            #[cfg(all(feature = "Win32_System_Search_Common", feature = "Win32_UI_Shell_Common"))]
+            CreateCompoundFromObjectArray,
+            #[cfg(not(all(feature = "Win32_System_Search_Common", feature = "Win32_UI_Shell_Common")))]
+            CreateCompoundFromObjectArray: 0,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Search_Common"))]
+            CreateCompoundFromArray,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Search_Common")))]
+            CreateCompoundFromArray: 0,
+            #[cfg(all(feature = "Win32_System_Search_Common", feature = "Win32_UI_Shell_PropertiesSystem"))]
+            CreateStringLeaf,
+            #[cfg(not(all(feature = "Win32_System_Search_Common", feature = "Win32_UI_Shell_PropertiesSystem")))]
+            CreateStringLeaf: 0,
+            #[cfg(all(feature = "Win32_System_Search_Common", feature = "Win32_UI_Shell_PropertiesSystem"))]
+            CreateIntegerLeaf,
+            #[cfg(not(all(feature = "Win32_System_Search_Common", feature = "Win32_UI_Shell_PropertiesSystem")))]
+            CreateIntegerLeaf: 0,
+            #[cfg(all(feature = "Win32_System_Search_Common", feature = "Win32_UI_Shell_PropertiesSystem"))]
+            CreateBooleanLeaf,
+            #[cfg(not(all(feature = "Win32_System_Search_Common", feature = "Win32_UI_Shell_PropertiesSystem")))]
+            CreateBooleanLeaf: 0,
+            #[cfg(all(feature = "Win32_System_Search_Common", feature = "Win32_UI_Shell_PropertiesSystem"))]
+            CreateLeaf,
+            #[cfg(not(all(feature = "Win32_System_Search_Common", feature = "Win32_UI_Shell_PropertiesSystem")))]
+            CreateLeaf: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ResolveCondition,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ResolveCondition: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConditionFactory2 as windows_core::Interface>::IID || <IConditionFactory_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Search_Common"))]
+impl windows_core::imp::ProxyVtable for IConditionGenerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pschemaprovider: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConditionGenerator", "Initialize", 3usize, |this| ((**(this as *const *const IConditionGenerator_Vtbl)).Initialize)(this, pschemaprovider), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecognizeNamedEntities(this: *mut core::ffi::c_void, pszinputstring: windows_core::PCWSTR, lciduserlocale: u32, ptokencollection: *mut core::ffi::c_void, pnamedentities: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConditionGenerator", "RecognizeNamedEntities", 4usize, |this| ((**(this as *const *const IConditionGenerator_Vtbl)).RecognizeNamedEntities)(this, pszinputstring, lciduserlocale, ptokencollection, pnamedentities), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Search_Common"))]
+        unsafe extern "system" fn GenerateForLeaf(this: *mut core::ffi::c_void, pconditionfactory: *mut core::ffi::c_void, pszpropertyname: windows_core::PCWSTR, cop: Common::CONDITION_OPERATION, pszvaluetype: windows_core::PCWSTR, pszvalue: windows_core::PCWSTR, pszvalue2: windows_core::PCWSTR, ppropertynameterm: *mut core::ffi::c_void, poperationterm: *mut core::ffi::c_void, pvalueterm: *mut core::ffi::c_void, automaticwildcard: super::super::Foundation::BOOL, pnostringquery: *mut super::super::Foundation::BOOL, ppqueryexpression: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConditionGenerator", "GenerateForLeaf", 5usize, |this| ((**(this as *const *const IConditionGenerator_Vtbl)).GenerateForLeaf)(this, pconditionfactory, pszpropertyname, cop, pszvaluetype, pszvalue, pszvalue2, ppropertynameterm, poperationterm, pvalueterm, automaticwildcard, pnostringquery, ppqueryexpression), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultPhrase(this: *mut core::ffi::c_void, pszvaluetype: windows_core::PCWSTR, ppropvar: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, fuseenglish: super::super::Foundation::BOOL, ppszphrase: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConditionGenerator", "DefaultPhrase", 6usize, |this| ((**(this as *const *const IConditionGenerator_Vtbl)).DefaultPhrase)(this, pszvaluetype, ppropvar, fuseenglish, ppszphrase), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            RecognizeNamedEntities,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Search_Common"))]
+            GenerateForLeaf,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Search_Common")))]
+            GenerateForLeaf: 0,
+            DefaultPhrase,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConditionGenerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConvertType_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CanConvert(this: *mut core::ffi::c_void, wfromtype: u16, wtotype: u16, dwconvertflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConvertType", "CanConvert", 3usize, |this| ((**(this as *const *const IConvertType_Vtbl)).CanConvert)(this, wfromtype, wtotype, dwconvertflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CanConvert }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConvertType as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICreateRow_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateRow(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, dwbindurlflags: u32, rguid: *const windows_core::GUID, riid: *const windows_core::GUID, pauthenticate: *mut core::ffi::c_void, pimplsession: *mut DBIMPLICITSESSION, pdwbindstatus: *mut u32, ppwsznewurl: *mut windows_core::PWSTR, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateRow", "CreateRow", 3usize, |this| ((**(this as *const *const ICreateRow_Vtbl)).CreateRow)(this, punkouter, pwszurl, dwbindurlflags, rguid, riid, pauthenticate, pimplsession, pdwbindstatus, ppwsznewurl, ppunk), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateRow,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateRow: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICreateRow as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDBAsynchNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnLowResource(this: *mut core::ffi::c_void, dwreserved: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDBAsynchNotify", "OnLowResource", 3usize, |this| ((**(this as *const *const IDBAsynchNotify_Vtbl)).OnLowResource)(this, dwreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnProgress(this: *mut core::ffi::c_void, hchapter: usize, eoperation: u32, ulprogress: usize, ulprogressmax: usize, easynchphase: u32, pwszstatustext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDBAsynchNotify", "OnProgress", 4usize, |this| ((**(this as *const *const IDBAsynchNotify_Vtbl)).OnProgress)(this, hchapter, eoperation, ulprogress, ulprogressmax, easynchphase, pwszstatustext), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnStop(this: *mut core::ffi::c_void, hchapter: usize, eoperation: u32, hrstatus: windows_core::HRESULT, pwszstatustext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDBAsynchNotify", "OnStop", 5usize, |this| ((**(this as *const *const IDBAsynchNotify_Vtbl)).OnStop)(this, hchapter, eoperation, hrstatus, pwszstatustext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnLowResource, OnProgress, OnStop }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDBAsynchNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDBAsynchStatus_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Abort(this: *mut core::ffi::c_void, hchapter: usize, eoperation: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDBAsynchStatus", "Abort", 3usize, |this| ((**(this as *const *const IDBAsynchStatus_Vtbl)).Abort)(this, hchapter, eoperation), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, hchapter: usize, eoperation: u32, pulprogress: *mut usize, pulprogressmax: *mut usize, peasynchphase: *mut u32, ppwszstatustext: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDBAsynchStatus", "GetStatus", 4usize, |this| ((**(this as *const *const IDBAsynchStatus_Vtbl)).GetStatus)(this, hchapter, eoperation, pulprogress, pulprogressmax, peasynchphase, ppwszstatustext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Abort, GetStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDBAsynchStatus as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Variant"))]
+impl windows_core::imp::ProxyVtable for IDBBinderProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDBBinderProperties", "Reset", 6usize, |this| ((**(this as *const *const IDBBinderProperties_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        Self { base__: <IDBProperties_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reset }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDBBinderProperties as windows_core::Interface>::IID || <IDBProperties_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDBCreateCommand_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateCommand(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppcommand: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDBCreateCommand", "CreateCommand", 3usize, |this| ((**(this as *const *const IDBCreateCommand_Vtbl)).CreateCommand)(this, punkouter, riid, ppcommand), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateCommand }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDBCreateCommand as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDBCreateSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateSession(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppdbsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDBCreateSession", "CreateSession", 3usize, |this| ((**(this as *const *const IDBCreateSession_Vtbl)).CreateSession)(this, punkouter, riid, ppdbsession), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateSession }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDBCreateSession as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Variant"))]
+impl windows_core::imp::ProxyVtable for IDBDataSourceAdmin_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn CreateDataSource(this: *mut core::ffi::c_void, cpropertysets: u32, rgpropertysets: *mut DBPROPSET, punkouter: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppdbsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDBDataSourceAdmin", "CreateDataSource", 3usize, |this| ((**(this as *const *const IDBDataSourceAdmin_Vtbl)).CreateDataSource)(this, cpropertysets, rgpropertysets, punkouter, riid, ppdbsession), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestroyDataSource(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDBDataSourceAdmin", "DestroyDataSource", 4usize, |this| ((**(this as *const *const IDBDataSourceAdmin_Vtbl)).DestroyDataSource)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Variant")]
+        unsafe extern "system" fn GetCreationProperties(this: *mut core::ffi::c_void, cpropertyidsets: u32, rgpropertyidsets: *const DBPROPIDSET, pcpropertyinfosets: *mut u32, prgpropertyinfosets: *mut *mut DBPROPINFOSET, ppdescbuffer: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDBDataSourceAdmin", "GetCreationProperties", 5usize, |this| ((**(this as *const *const IDBDataSourceAdmin_Vtbl)).GetCreationProperties)(this, cpropertyidsets, rgpropertyidsets, pcpropertyinfosets, prgpropertyinfosets, ppdescbuffer), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn ModifyDataSource(this: *mut core::ffi::c_void, cpropertysets: u32, rgpropertysets: *mut DBPROPSET) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDBDataSourceAdmin", "ModifyDataSource", 6usize, |this| ((**(this as *const *const IDBDataSourceAdmin_Vtbl)).ModifyDataSource)(this, cpropertysets, rgpropertysets), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            CreateDataSource,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            CreateDataSource: 0,
+            DestroyDataSource,
+            #[cfg(feature = "Win32_System_Variant")]
+            GetCreationProperties,
+            #[cfg(not(feature = "Win32_System_Variant"))]
+            GetCreationProperties: 0,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            ModifyDataSource,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            ModifyDataSource: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDBDataSourceAdmin as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDBInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetKeywords(this: *mut core::ffi::c_void, ppwszkeywords: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDBInfo", "GetKeywords", 3usize, |this| ((**(this as *const *const IDBInfo_Vtbl)).GetKeywords)(this, ppwszkeywords), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLiteralInfo(this: *mut core::ffi::c_void, cliterals: u32, rgliterals: *const u32, pcliteralinfo: *mut u32, prgliteralinfo: *mut *mut DBLITERALINFO, ppcharbuffer: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDBInfo", "GetLiteralInfo", 4usize, |this| ((**(this as *const *const IDBInfo_Vtbl)).GetLiteralInfo)(this, cliterals, rgliterals, pcliteralinfo, prgliteralinfo, ppcharbuffer), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetKeywords, GetLiteralInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDBInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDBInitialize_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDBInitialize", "Initialize", 3usize, |this| ((**(this as *const *const IDBInitialize_Vtbl)).Initialize)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Uninitialize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDBInitialize", "Uninitialize", 4usize, |this| ((**(this as *const *const IDBInitialize_Vtbl)).Uninitialize)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, Uninitialize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDBInitialize as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDBPromptInitialize_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PromptDataSource(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, dwpromptoptions: u32, csourcetypefilter: u32, rgsourcetypefilter: *const u32, pwszszzproviderfilter: windows_core::PCWSTR, riid: *const windows_core::GUID, ppdatasource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDBPromptInitialize", "PromptDataSource", 3usize, |this| ((**(this as *const *const IDBPromptInitialize_Vtbl)).PromptDataSource)(this, punkouter, hwndparent, dwpromptoptions, csourcetypefilter, rgsourcetypefilter, pwszszzproviderfilter, riid, ppdatasource), |result| Some(*result))
+        }
+        unsafe extern "system" fn PromptFileName(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, dwpromptoptions: u32, pwszinitialdirectory: windows_core::PCWSTR, pwszinitialfile: windows_core::PCWSTR, ppwszselectedfile: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDBPromptInitialize", "PromptFileName", 4usize, |this| ((**(this as *const *const IDBPromptInitialize_Vtbl)).PromptFileName)(this, hwndparent, dwpromptoptions, pwszinitialdirectory, pwszinitialfile, ppwszselectedfile), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PromptDataSource, PromptFileName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDBPromptInitialize as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Variant"))]
+impl windows_core::imp::ProxyVtable for IDBProperties_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, cpropertyidsets: u32, rgpropertyidsets: *const DBPROPIDSET, pcpropertysets: *mut u32, prgpropertysets: *mut *mut DBPROPSET) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDBProperties", "GetProperties", 3usize, |this| ((**(this as *const *const IDBProperties_Vtbl)).GetProperties)(this, cpropertyidsets, rgpropertyidsets, pcpropertysets, prgpropertysets), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Variant")]
+        unsafe extern "system" fn GetPropertyInfo(this: *mut core::ffi::c_void, cpropertyidsets: u32, rgpropertyidsets: *const DBPROPIDSET, pcpropertyinfosets: *mut u32, prgpropertyinfosets: *mut *mut DBPROPINFOSET, ppdescbuffer: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDBProperties", "GetPropertyInfo", 4usize, |this| ((**(this as *const *const IDBProperties_Vtbl)).GetPropertyInfo)(this, cpropertyidsets, rgpropertyidsets, pcpropertyinfosets, prgpropertyinfosets, ppdescbuffer), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn SetProperties(this: *mut core::ffi::c_void, cpropertysets: u32, rgpropertysets: *mut DBPROPSET) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDBProperties", "SetProperties", 5usize, |this| ((**(this as *const *const IDBProperties_Vtbl)).SetProperties)(this, cpropertysets, rgpropertysets), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            GetProperties,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            GetProperties: 0,
+            #[cfg(feature = "Win32_System_Variant")]
+            GetPropertyInfo,
+            #[cfg(not(feature = "Win32_System_Variant"))]
+            GetPropertyInfo: 0,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            SetProperties,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            SetProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDBProperties as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDBSchemaCommand_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCommand(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, rguidschema: *const windows_core::GUID, ppcommand: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDBSchemaCommand", "GetCommand", 3usize, |this| ((**(this as *const *const IDBSchemaCommand_Vtbl)).GetCommand)(this, punkouter, rguidschema, ppcommand), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSchemas(this: *mut core::ffi::c_void, pcschemas: *mut u32, prgschemas: *mut *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDBSchemaCommand", "GetSchemas", 4usize, |this| ((**(this as *const *const IDBSchemaCommand_Vtbl)).GetSchemas)(this, pcschemas, prgschemas), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCommand, GetSchemas }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDBSchemaCommand as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_IndexServer")]
+impl windows_core::imp::ProxyVtable for IDBSchemaRowset_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn GetRowset(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, rguidschema: *const windows_core::GUID, crestrictions: u32, rgrestrictions: *const core::mem::MaybeUninit<windows_core::VARIANT>, riid: *const windows_core::GUID, cpropertysets: u32, rgpropertysets: *mut DBPROPSET, pprowset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDBSchemaRowset", "GetRowset", 3usize, |this| ((**(this as *const *const IDBSchemaRowset_Vtbl)).GetRowset)(this, punkouter, rguidschema, crestrictions, rgrestrictions, riid, cpropertysets, rgpropertysets, pprowset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSchemas(this: *mut core::ffi::c_void, pcschemas: *mut u32, prgschemas: *mut *mut windows_core::GUID, prgrestrictionsupport: *mut *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDBSchemaRowset", "GetSchemas", 4usize, |this| ((**(this as *const *const IDBSchemaRowset_Vtbl)).GetSchemas)(this, pcschemas, prgschemas, prgrestrictionsupport), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            GetRowset,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            GetRowset: 0,
+            GetSchemas,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDBSchemaRowset as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDCInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInfo(this: *mut core::ffi::c_void, cinfo: u32, rgeinfotype: *const u32, prginfo: *mut *mut DCINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCInfo", "GetInfo", 3usize, |this| ((**(this as *const *const IDCInfo_Vtbl)).GetInfo)(this, cinfo, rgeinfotype, prginfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInfo(this: *mut core::ffi::c_void, cinfo: u32, rginfo: *const DCINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDCInfo", "SetInfo", 4usize, |this| ((**(this as *const *const IDCInfo_Vtbl)).SetInfo)(this, cinfo, rginfo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetInfo, SetInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDCInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataConvert_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DataConvert(this: *mut core::ffi::c_void, wsrctype: u16, wdsttype: u16, cbsrclength: usize, pcbdstlength: *mut usize, psrc: *const core::ffi::c_void, pdst: *mut core::ffi::c_void, cbdstmaxlength: usize, dbssrcstatus: u32, pdbsstatus: *mut u32, bprecision: u8, bscale: u8, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataConvert", "DataConvert", 3usize, |this| ((**(this as *const *const IDataConvert_Vtbl)).DataConvert)(this, wsrctype, wdsttype, cbsrclength, pcbdstlength, psrc, pdst, cbdstmaxlength, dbssrcstatus, pdbsstatus, bprecision, bscale, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanConvert(this: *mut core::ffi::c_void, wsrctype: u16, wdsttype: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataConvert", "CanConvert", 4usize, |this| ((**(this as *const *const IDataConvert_Vtbl)).CanConvert)(this, wsrctype, wdsttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConversionSize(this: *mut core::ffi::c_void, wsrctype: u16, wdsttype: u16, pcbsrclength: *const usize, pcbdstlength: *mut usize, psrc: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataConvert", "GetConversionSize", 5usize, |this| ((**(this as *const *const IDataConvert_Vtbl)).GetConversionSize)(this, wsrctype, wdsttype, pcbsrclength, pcbdstlength, psrc), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DataConvert, CanConvert, GetConversionSize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataConvert as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDataInitialize_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDataSource(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, dwclsctx: u32, pwszinitializationstring: windows_core::PCWSTR, riid: *const windows_core::GUID, ppdatasource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataInitialize", "GetDataSource", 3usize, |this| ((**(this as *const *const IDataInitialize_Vtbl)).GetDataSource)(this, punkouter, dwclsctx, pwszinitializationstring, riid, ppdatasource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInitializationString(this: *mut core::ffi::c_void, pdatasource: *mut core::ffi::c_void, fincludepassword: u8, ppwszinitstring: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataInitialize", "GetInitializationString", 4usize, |this| ((**(this as *const *const IDataInitialize_Vtbl)).GetInitializationString)(this, pdatasource, fincludepassword, ppwszinitstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDBInstance(this: *mut core::ffi::c_void, clsidprovider: *const windows_core::GUID, punkouter: *mut core::ffi::c_void, dwclsctx: u32, pwszreserved: windows_core::PCWSTR, riid: *const windows_core::GUID, ppdatasource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataInitialize", "CreateDBInstance", 5usize, |this| ((**(this as *const *const IDataInitialize_Vtbl)).CreateDBInstance)(this, clsidprovider, punkouter, dwclsctx, pwszreserved, riid, ppdatasource), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateDBInstanceEx(this: *mut core::ffi::c_void, clsidprovider: *const windows_core::GUID, punkouter: *mut core::ffi::c_void, dwclsctx: u32, pwszreserved: windows_core::PCWSTR, pserverinfo: *const super::Com::COSERVERINFO, cmq: u32, rgmqresults: *mut super::Com::MULTI_QI) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataInitialize", "CreateDBInstanceEx", 6usize, |this| ((**(this as *const *const IDataInitialize_Vtbl)).CreateDBInstanceEx)(this, clsidprovider, punkouter, dwclsctx, pwszreserved, pserverinfo, cmq, rgmqresults), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadStringFromStorage(this: *mut core::ffi::c_void, pwszfilename: windows_core::PCWSTR, ppwszinitializationstring: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataInitialize", "LoadStringFromStorage", 7usize, |this| ((**(this as *const *const IDataInitialize_Vtbl)).LoadStringFromStorage)(this, pwszfilename, ppwszinitializationstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteStringToStorage(this: *mut core::ffi::c_void, pwszfilename: windows_core::PCWSTR, pwszinitializationstring: windows_core::PCWSTR, dwcreationdisposition: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataInitialize", "WriteStringToStorage", 8usize, |this| ((**(this as *const *const IDataInitialize_Vtbl)).WriteStringToStorage)(this, pwszfilename, pwszinitializationstring, dwcreationdisposition), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDataSource,
+            GetInitializationString,
+            CreateDBInstance,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateDBInstanceEx,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateDBInstanceEx: 0,
+            LoadStringFromStorage,
+            WriteStringToStorage,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataInitialize as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDataSourceLocator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn hWnd(this: *mut core::ffi::c_void, phwndparent: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataSourceLocator", "hWnd", 7usize, |this| ((**(this as *const *const IDataSourceLocator_Vtbl)).hWnd)(this, phwndparent), |result| Some(*result))
+        }
+        unsafe extern "system" fn SethWnd(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataSourceLocator", "SethWnd", 8usize, |this| ((**(this as *const *const IDataSourceLocator_Vtbl)).SethWnd)(this, hwndparent), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PromptNew(this: *mut core::ffi::c_void, ppadoconnection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataSourceLocator", "PromptNew", 9usize, |this| ((**(this as *const *const IDataSourceLocator_Vtbl)).PromptNew)(this, ppadoconnection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PromptEdit(this: *mut core::ffi::c_void, ppadoconnection: *mut *mut core::ffi::c_void, pbsuccess: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataSourceLocator", "PromptEdit", 10usize, |this| ((**(this as *const *const IDataSourceLocator_Vtbl)).PromptEdit)(this, ppadoconnection, pbsuccess), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            hWnd,
+            SethWnd,
+            #[cfg(feature = "Win32_System_Com")]
+            PromptNew,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PromptNew: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PromptEdit,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PromptEdit: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataSourceLocator as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEntity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, ppszname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEntity", "Name", 3usize, |this| ((**(this as *const *const IEntity_Vtbl)).Name)(this, ppszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Base(this: *mut core::ffi::c_void, pbaseentity: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEntity", "Base", 4usize, |this| ((**(this as *const *const IEntity_Vtbl)).Base)(this, pbaseentity), |result| Some(*result))
+        }
+        unsafe extern "system" fn Relationships(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, prelationships: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEntity", "Relationships", 5usize, |this| ((**(this as *const *const IEntity_Vtbl)).Relationships)(this, riid, prelationships), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRelationship(this: *mut core::ffi::c_void, pszrelationname: windows_core::PCWSTR, prelationship: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEntity", "GetRelationship", 6usize, |this| ((**(this as *const *const IEntity_Vtbl)).GetRelationship)(this, pszrelationname, prelationship), |result| Some(*result))
+        }
+        unsafe extern "system" fn MetaData(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, pmetadata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEntity", "MetaData", 7usize, |this| ((**(this as *const *const IEntity_Vtbl)).MetaData)(this, riid, pmetadata), |result| Some(*result))
+        }
+        unsafe extern "system" fn NamedEntities(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, pnamedentities: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEntity", "NamedEntities", 8usize, |this| ((**(this as *const *const IEntity_Vtbl)).NamedEntities)(this, riid, pnamedentities), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNamedEntity(this: *mut core::ffi::c_void, pszvalue: windows_core::PCWSTR, ppnamedentity: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEntity", "GetNamedEntity", 9usize, |this| ((**(this as *const *const IEntity_Vtbl)).GetNamedEntity)(this, pszvalue, ppnamedentity), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultPhrase(this: *mut core::ffi::c_void, ppszphrase: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEntity", "DefaultPhrase", 10usize, |this| ((**(this as *const *const IEntity_Vtbl)).DefaultPhrase)(this, ppszphrase), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            Base,
+            Relationships,
+            GetRelationship,
+            MetaData,
+            NamedEntities,
+            GetNamedEntity,
+            DefaultPhrase,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEntity as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumItemProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut ITEMPROP, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumItemProperties", "Next", 3usize, |this| ((**(this as *const *const IEnumItemProperties_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumItemProperties", "Skip", 4usize, |this| ((**(this as *const *const IEnumItemProperties_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumItemProperties", "Reset", 5usize, |this| ((**(this as *const *const IEnumItemProperties_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumItemProperties", "Clone", 6usize, |this| ((**(this as *const *const IEnumItemProperties_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pncount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumItemProperties", "GetCount", 7usize, |this| ((**(this as *const *const IEnumItemProperties_Vtbl)).GetCount)(this, pncount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone, GetCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumItemProperties as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumSearchRoots_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSearchRoots", "Next", 3usize, |this| ((**(this as *const *const IEnumSearchRoots_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSearchRoots", "Skip", 4usize, |this| ((**(this as *const *const IEnumSearchRoots_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSearchRoots", "Reset", 5usize, |this| ((**(this as *const *const IEnumSearchRoots_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSearchRoots", "Clone", 6usize, |this| ((**(this as *const *const IEnumSearchRoots_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumSearchRoots as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumSearchScopeRules_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, pprgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSearchScopeRules", "Next", 3usize, |this| ((**(this as *const *const IEnumSearchScopeRules_Vtbl)).Next)(this, celt, pprgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSearchScopeRules", "Skip", 4usize, |this| ((**(this as *const *const IEnumSearchScopeRules_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSearchScopeRules", "Reset", 5usize, |this| ((**(this as *const *const IEnumSearchScopeRules_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSearchScopeRules", "Clone", 6usize, |this| ((**(this as *const *const IEnumSearchScopeRules_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumSearchScopeRules as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumSubscription_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut windows_core::GUID, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSubscription", "Next", 3usize, |this| ((**(this as *const *const IEnumSubscription_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSubscription", "Skip", 4usize, |this| ((**(this as *const *const IEnumSubscription_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSubscription", "Reset", 5usize, |this| ((**(this as *const *const IEnumSubscription_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSubscription", "Clone", 6usize, |this| ((**(this as *const *const IEnumSubscription_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pncount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSubscription", "GetCount", 7usize, |this| ((**(this as *const *const IEnumSubscription_Vtbl)).GetCount)(this, pncount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone, GetCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumSubscription as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IErrorLookup_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetErrorDescription(this: *mut core::ffi::c_void, hrerror: windows_core::HRESULT, dwlookupid: u32, pdispparams: *const super::Com::DISPPARAMS, lcid: u32, pbstrsource: *mut core::mem::MaybeUninit<windows_core::BSTR>, pbstrdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IErrorLookup", "GetErrorDescription", 3usize, |this| ((**(this as *const *const IErrorLookup_Vtbl)).GetErrorDescription)(this, hrerror, dwlookupid, pdispparams, lcid, pbstrsource, pbstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHelpInfo(this: *mut core::ffi::c_void, hrerror: windows_core::HRESULT, dwlookupid: u32, lcid: u32, pbstrhelpfile: *mut core::mem::MaybeUninit<windows_core::BSTR>, pdwhelpcontext: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IErrorLookup", "GetHelpInfo", 4usize, |this| ((**(this as *const *const IErrorLookup_Vtbl)).GetHelpInfo)(this, hrerror, dwlookupid, lcid, pbstrhelpfile, pdwhelpcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseErrors(this: *mut core::ffi::c_void, dwdynamicerrorid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IErrorLookup", "ReleaseErrors", 5usize, |this| ((**(this as *const *const IErrorLookup_Vtbl)).ReleaseErrors)(this, dwdynamicerrorid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetErrorDescription,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetErrorDescription: 0,
+            GetHelpInfo,
+            ReleaseErrors,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IErrorLookup as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IErrorRecords_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddErrorRecord(this: *mut core::ffi::c_void, perrorinfo: *const ERRORINFO, dwlookupid: u32, pdispparams: *const super::Com::DISPPARAMS, punkcustomerror: *mut core::ffi::c_void, dwdynamicerrorid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IErrorRecords", "AddErrorRecord", 3usize, |this| ((**(this as *const *const IErrorRecords_Vtbl)).AddErrorRecord)(this, perrorinfo, dwlookupid, pdispparams, punkcustomerror, dwdynamicerrorid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBasicErrorInfo(this: *mut core::ffi::c_void, ulrecordnum: u32, perrorinfo: *mut ERRORINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IErrorRecords", "GetBasicErrorInfo", 4usize, |this| ((**(this as *const *const IErrorRecords_Vtbl)).GetBasicErrorInfo)(this, ulrecordnum, perrorinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCustomErrorObject(this: *mut core::ffi::c_void, ulrecordnum: u32, riid: *const windows_core::GUID, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IErrorRecords", "GetCustomErrorObject", 5usize, |this| ((**(this as *const *const IErrorRecords_Vtbl)).GetCustomErrorObject)(this, ulrecordnum, riid, ppobject), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetErrorInfo(this: *mut core::ffi::c_void, ulrecordnum: u32, lcid: u32, pperrorinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IErrorRecords", "GetErrorInfo", 6usize, |this| ((**(this as *const *const IErrorRecords_Vtbl)).GetErrorInfo)(this, ulrecordnum, lcid, pperrorinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetErrorParameters(this: *mut core::ffi::c_void, ulrecordnum: u32, pdispparams: *mut super::Com::DISPPARAMS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IErrorRecords", "GetErrorParameters", 7usize, |this| ((**(this as *const *const IErrorRecords_Vtbl)).GetErrorParameters)(this, ulrecordnum, pdispparams), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecordCount(this: *mut core::ffi::c_void, pcrecords: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IErrorRecords", "GetRecordCount", 8usize, |this| ((**(this as *const *const IErrorRecords_Vtbl)).GetRecordCount)(this, pcrecords), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            AddErrorRecord,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddErrorRecord: 0,
+            GetBasicErrorInfo,
+            GetCustomErrorObject,
+            #[cfg(feature = "Win32_System_Com")]
+            GetErrorInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetErrorInfo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetErrorParameters,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetErrorParameters: 0,
+            GetRecordCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IErrorRecords as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGetDataSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDataSource(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppdatasource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetDataSource", "GetDataSource", 3usize, |this| ((**(this as *const *const IGetDataSource_Vtbl)).GetDataSource)(this, riid, ppdatasource), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDataSource }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGetDataSource as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGetRow_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRowFromHROW(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, hrow: usize, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetRow", "GetRowFromHROW", 3usize, |this| ((**(this as *const *const IGetRow_Vtbl)).GetRowFromHROW)(this, punkouter, hrow, riid, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetURLFromHROW(this: *mut core::ffi::c_void, hrow: usize, ppwszurl: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetRow", "GetURLFromHROW", 4usize, |this| ((**(this as *const *const IGetRow_Vtbl)).GetURLFromHROW)(this, hrow, ppwszurl), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRowFromHROW, GetURLFromHROW }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGetRow as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGetSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSession(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppsession: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetSession", "GetSession", 3usize, |this| ((**(this as *const *const IGetSession_Vtbl)).GetSession)(this, riid, ppsession), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSession }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGetSession as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGetSourceRow_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSourceRow(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, pprow: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGetSourceRow", "GetSourceRow", 3usize, |this| ((**(this as *const *const IGetSourceRow_Vtbl)).GetSourceRow)(this, riid, pprow), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSourceRow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGetSourceRow as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_IndexServer")]
+impl windows_core::imp::ProxyVtable for IIndexDefinition_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn CreateIndex(this: *mut core::ffi::c_void, ptableid: *const super::super::Storage::IndexServer::DBID, pindexid: *const super::super::Storage::IndexServer::DBID, cindexcolumndescs: usize, rgindexcolumndescs: *const DBINDEXCOLUMNDESC, cpropertysets: u32, rgpropertysets: *mut DBPROPSET, ppindexid: *mut *mut super::super::Storage::IndexServer::DBID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIndexDefinition", "CreateIndex", 3usize, |this| ((**(this as *const *const IIndexDefinition_Vtbl)).CreateIndex)(this, ptableid, pindexid, cindexcolumndescs, rgindexcolumndescs, cpropertysets, rgpropertysets, ppindexid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn DropIndex(this: *mut core::ffi::c_void, ptableid: *const super::super::Storage::IndexServer::DBID, pindexid: *const super::super::Storage::IndexServer::DBID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIndexDefinition", "DropIndex", 4usize, |this| ((**(this as *const *const IIndexDefinition_Vtbl)).DropIndex)(this, ptableid, pindexid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            CreateIndex,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            CreateIndex: 0,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            DropIndex,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            DropIndex: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIndexDefinition as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInterval_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLimits(this: *mut core::ffi::c_void, pilklower: *mut INTERVAL_LIMIT_KIND, ppropvarlower: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>, pilkupper: *mut INTERVAL_LIMIT_KIND, ppropvarupper: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInterval", "GetLimits", 3usize, |this| ((**(this as *const *const IInterval_Vtbl)).GetLimits)(this, pilklower, ppropvarlower, pilkupper, ppropvarupper), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetLimits }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInterval as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com_StructuredStorage"))]
+impl windows_core::imp::ProxyVtable for ILoadFilter_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn LoadIFilter(this: *mut core::ffi::c_void, pwcspath: windows_core::PCWSTR, pfilteredsources: *const FILTERED_DATA_SOURCES, punkouter: *mut core::ffi::c_void, fusedefault: super::super::Foundation::BOOL, pfilterclsid: *mut windows_core::GUID, searchdecsize: *mut i32, pwcssearchdesc: *mut *mut u16, ppifilt: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoadFilter", "LoadIFilter", 3usize, |this| ((**(this as *const *const ILoadFilter_Vtbl)).LoadIFilter)(this, pwcspath, pfilteredsources, punkouter, fusedefault, pfilterclsid, searchdecsize, pwcssearchdesc, ppifilt), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com_StructuredStorage"))]
+        unsafe extern "system" fn LoadIFilterFromStorage(this: *mut core::ffi::c_void, pstg: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, pwcsoverride: windows_core::PCWSTR, fusedefault: super::super::Foundation::BOOL, pfilterclsid: *mut windows_core::GUID, searchdecsize: *mut i32, pwcssearchdesc: *mut *mut u16, ppifilt: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoadFilter", "LoadIFilterFromStorage", 4usize, |this| ((**(this as *const *const ILoadFilter_Vtbl)).LoadIFilterFromStorage)(this, pstg, punkouter, pwcsoverride, fusedefault, pfilterclsid, searchdecsize, pwcssearchdesc, ppifilt), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn LoadIFilterFromStream(this: *mut core::ffi::c_void, pstm: *mut core::ffi::c_void, pfilteredsources: *const FILTERED_DATA_SOURCES, punkouter: *mut core::ffi::c_void, fusedefault: super::super::Foundation::BOOL, pfilterclsid: *mut windows_core::GUID, searchdecsize: *mut i32, pwcssearchdesc: *mut *mut u16, ppifilt: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoadFilter", "LoadIFilterFromStream", 5usize, |this| ((**(this as *const *const ILoadFilter_Vtbl)).LoadIFilterFromStream)(this, pstm, pfilteredsources, punkouter, fusedefault, pfilterclsid, searchdecsize, pwcssearchdesc, ppifilt), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            LoadIFilter,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            LoadIFilter: 0,
+            #[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com_StructuredStorage"))]
+            LoadIFilterFromStorage,
+            #[cfg(not(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com_StructuredStorage")))]
+            LoadIFilterFromStorage: 0,
+            #[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com"))]
+            LoadIFilterFromStream,
+            #[cfg(not(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com")))]
+            LoadIFilterFromStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILoadFilter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com_StructuredStorage"))]
+impl windows_core::imp::ProxyVtable for ILoadFilterWithPrivateComActivation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn LoadIFilterWithPrivateComActivation(this: *mut core::ffi::c_void, filteredsources: *const FILTERED_DATA_SOURCES, usedefault: super::super::Foundation::BOOL, filterclsid: *mut windows_core::GUID, isfilterprivatecomactivated: *mut super::super::Foundation::BOOL, filterobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoadFilterWithPrivateComActivation", "LoadIFilterWithPrivateComActivation", 6usize, |this| ((**(this as *const *const ILoadFilterWithPrivateComActivation_Vtbl)).LoadIFilterWithPrivateComActivation)(this, filteredsources, usedefault, filterclsid, isfilterprivatecomactivated, filterobj), |result| Some(*result))
+        }
+        Self {
+            base__: <ILoadFilter_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            LoadIFilterWithPrivateComActivation,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            LoadIFilterWithPrivateComActivation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILoadFilterWithPrivateComActivation as windows_core::Interface>::IID || <ILoadFilter_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_IndexServer")]
+impl windows_core::imp::ProxyVtable for IMDDataset_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FreeAxisInfo(this: *mut core::ffi::c_void, caxes: usize, rgaxisinfo: *const MDAXISINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDDataset", "FreeAxisInfo", 3usize, |this| ((**(this as *const *const IMDDataset_Vtbl)).FreeAxisInfo)(this, caxes, rgaxisinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAxisInfo(this: *mut core::ffi::c_void, pcaxes: *mut usize, prgaxisinfo: *mut *mut MDAXISINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDDataset", "GetAxisInfo", 4usize, |this| ((**(this as *const *const IMDDataset_Vtbl)).GetAxisInfo)(this, pcaxes, prgaxisinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn GetAxisRowset(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, iaxis: usize, riid: *const windows_core::GUID, cpropertysets: u32, rgpropertysets: *mut DBPROPSET, pprowset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDDataset", "GetAxisRowset", 5usize, |this| ((**(this as *const *const IMDDataset_Vtbl)).GetAxisRowset)(this, punkouter, iaxis, riid, cpropertysets, rgpropertysets, pprowset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCellData(this: *mut core::ffi::c_void, haccessor: HACCESSOR, ulstartcell: usize, ulendcell: usize, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDDataset", "GetCellData", 6usize, |this| ((**(this as *const *const IMDDataset_Vtbl)).GetCellData)(this, haccessor, ulstartcell, ulendcell, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSpecification(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppspecification: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDDataset", "GetSpecification", 7usize, |this| ((**(this as *const *const IMDDataset_Vtbl)).GetSpecification)(this, riid, ppspecification), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FreeAxisInfo,
+            GetAxisInfo,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            GetAxisRowset,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            GetAxisRowset: 0,
+            GetCellData,
+            GetSpecification,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMDDataset as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMDFind_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FindCell(this: *mut core::ffi::c_void, ulstartingordinal: usize, cmembers: usize, rgpwszmember: *const windows_core::PCWSTR, pulcellordinal: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDFind", "FindCell", 3usize, |this| ((**(this as *const *const IMDFind_Vtbl)).FindCell)(this, ulstartingordinal, cmembers, rgpwszmember, pulcellordinal), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindTuple(this: *mut core::ffi::c_void, ulaxisidentifier: u32, ulstartingordinal: usize, cmembers: usize, rgpwszmember: *const windows_core::PCWSTR, pultupleordinal: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDFind", "FindTuple", 4usize, |this| ((**(this as *const *const IMDFind_Vtbl)).FindTuple)(this, ulaxisidentifier, ulstartingordinal, cmembers, rgpwszmember, pultupleordinal), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FindCell, FindTuple }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMDFind as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_IndexServer")]
+impl windows_core::imp::ProxyVtable for IMDRangeRowset_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn GetRangeRowset(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, ulstartcell: usize, ulendcell: usize, riid: *const windows_core::GUID, cpropertysets: u32, rgpropertysets: *mut DBPROPSET, pprowset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMDRangeRowset", "GetRangeRowset", 3usize, |this| ((**(this as *const *const IMDRangeRowset_Vtbl)).GetRangeRowset)(this, punkouter, ulstartcell, ulendcell, riid, cpropertysets, rgpropertysets, pprowset), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            GetRangeRowset,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            GetRangeRowset: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMDRangeRowset as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMetaData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetData(this: *mut core::ffi::c_void, ppszkey: *mut windows_core::PWSTR, ppszvalue: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaData", "GetData", 3usize, |this| ((**(this as *const *const IMetaData_Vtbl)).GetData)(this, ppszkey, ppszvalue), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMetaData as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMultipleResults_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetResult(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, lresultflag: isize, riid: *const windows_core::GUID, pcrowsaffected: *mut isize, pprowset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultipleResults", "GetResult", 3usize, |this| ((**(this as *const *const IMultipleResults_Vtbl)).GetResult)(this, punkouter, lresultflag, riid, pcrowsaffected, pprowset), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetResult }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMultipleResults as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INamedEntity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, ppszvalue: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INamedEntity", "GetValue", 3usize, |this| ((**(this as *const *const INamedEntity_Vtbl)).GetValue)(this, ppszvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultPhrase(this: *mut core::ffi::c_void, ppszphrase: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INamedEntity", "DefaultPhrase", 4usize, |this| ((**(this as *const *const INamedEntity_Vtbl)).DefaultPhrase)(this, ppszphrase), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetValue, DefaultPhrase }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INamedEntity as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INamedEntityCollector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, beginspan: u32, endspan: u32, beginactual: u32, endactual: u32, ptype: *mut core::ffi::c_void, pszvalue: windows_core::PCWSTR, certainty: NAMED_ENTITY_CERTAINTY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INamedEntityCollector", "Add", 3usize, |this| ((**(this as *const *const INamedEntityCollector_Vtbl)).Add)(this, beginspan, endspan, beginactual, endactual, ptype, pszvalue, certainty), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Add }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INamedEntityCollector as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Security_Authorization", feature = "Win32_Storage_IndexServer"))]
+impl windows_core::imp::ProxyVtable for IObjectAccessControl_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Security_Authorization", feature = "Win32_Storage_IndexServer"))]
+        unsafe extern "system" fn GetObjectAccessRights(this: *mut core::ffi::c_void, pobject: *const SEC_OBJECT, pcaccessentries: *mut u32, prgaccessentries: *mut *mut super::super::Security::Authorization::EXPLICIT_ACCESS_W) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectAccessControl", "GetObjectAccessRights", 3usize, |this| ((**(this as *const *const IObjectAccessControl_Vtbl)).GetObjectAccessRights)(this, pobject, pcaccessentries, prgaccessentries), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Security_Authorization", feature = "Win32_Storage_IndexServer"))]
+        unsafe extern "system" fn GetObjectOwner(this: *mut core::ffi::c_void, pobject: *const SEC_OBJECT, ppowner: *mut *mut super::super::Security::Authorization::TRUSTEE_W) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectAccessControl", "GetObjectOwner", 4usize, |this| ((**(this as *const *const IObjectAccessControl_Vtbl)).GetObjectOwner)(this, pobject, ppowner), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Security_Authorization", feature = "Win32_Storage_IndexServer"))]
+        unsafe extern "system" fn IsObjectAccessAllowed(this: *mut core::ffi::c_void, pobject: *const SEC_OBJECT, paccessentry: *const super::super::Security::Authorization::EXPLICIT_ACCESS_W, pfresult: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectAccessControl", "IsObjectAccessAllowed", 5usize, |this| ((**(this as *const *const IObjectAccessControl_Vtbl)).IsObjectAccessAllowed)(this, pobject, paccessentry, pfresult), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Security_Authorization", feature = "Win32_Storage_IndexServer"))]
+        unsafe extern "system" fn SetObjectAccessRights(this: *mut core::ffi::c_void, pobject: *const SEC_OBJECT, caccessentries: u32, prgaccessentries: *mut super::super::Security::Authorization::EXPLICIT_ACCESS_W) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectAccessControl", "SetObjectAccessRights", 6usize, |this| ((**(this as *const *const IObjectAccessControl_Vtbl)).SetObjectAccessRights)(this, pobject, caccessentries, prgaccessentries), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Security_Authorization", feature = "Win32_Storage_IndexServer"))]
+        unsafe extern "system" fn SetObjectOwner(this: *mut core::ffi::c_void, pobject: *const SEC_OBJECT, powner: *const super::super::Security::Authorization::TRUSTEE_W) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectAccessControl", "SetObjectOwner", 7usize, |this| ((**(this as *const *const IObjectAccessControl_Vtbl)).SetObjectOwner)(this, pobject, powner), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Security_Authorization", feature = "Win32_Storage_IndexServer"))]
+            GetObjectAccessRights,
+            #[cfg(not(all(feature = "Win32_Security_Authorization", feature = "Win32_Storage_IndexServer")))]
+            GetObjectAccessRights: 0,
+            #[cfg(all(feature = "Win32_Security_Authorization", feature = "Win32_Storage_IndexServer"))]
+            GetObjectOwner,
+            #[cfg(not(all(feature = "Win32_Security_Authorization", feature = "Win32_Storage_IndexServer")))]
+            GetObjectOwner: 0,
+            #[cfg(all(feature = "Win32_Security_Authorization", feature = "Win32_Storage_IndexServer"))]
+            IsObjectAccessAllowed,
+            #[cfg(not(all(feature = "Win32_Security_Authorization", feature = "Win32_Storage_IndexServer")))]
+            IsObjectAccessAllowed: 0,
+            #[cfg(all(feature = "Win32_Security_Authorization", feature = "Win32_Storage_IndexServer"))]
+            SetObjectAccessRights,
+            #[cfg(not(all(feature = "Win32_Security_Authorization", feature = "Win32_Storage_IndexServer")))]
+            SetObjectAccessRights: 0,
+            #[cfg(all(feature = "Win32_Security_Authorization", feature = "Win32_Storage_IndexServer"))]
+            SetObjectOwner,
+            #[cfg(not(all(feature = "Win32_Security_Authorization", feature = "Win32_Storage_IndexServer")))]
+            SetObjectOwner: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IObjectAccessControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOpLockStatus_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsOplockValid(this: *mut core::ffi::c_void, pfisoplockvalid: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpLockStatus", "IsOplockValid", 3usize, |this| ((**(this as *const *const IOpLockStatus_Vtbl)).IsOplockValid)(this, pfisoplockvalid), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOplockBroken(this: *mut core::ffi::c_void, pfisoplockbroken: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpLockStatus", "IsOplockBroken", 4usize, |this| ((**(this as *const *const IOpLockStatus_Vtbl)).IsOplockBroken)(this, pfisoplockbroken), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOplockEventHandle(this: *mut core::ffi::c_void, phoplockev: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpLockStatus", "GetOplockEventHandle", 5usize, |this| ((**(this as *const *const IOpLockStatus_Vtbl)).GetOplockEventHandle)(this, phoplockev), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsOplockValid, IsOplockBroken, GetOplockEventHandle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpLockStatus as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_IndexServer")]
+impl windows_core::imp::ProxyVtable for IOpenRowset_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn OpenRowset(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, ptableid: *const super::super::Storage::IndexServer::DBID, pindexid: *const super::super::Storage::IndexServer::DBID, riid: *const windows_core::GUID, cpropertysets: u32, rgpropertysets: *mut DBPROPSET, pprowset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenRowset", "OpenRowset", 3usize, |this| ((**(this as *const *const IOpenRowset_Vtbl)).OpenRowset)(this, punkouter, ptableid, pindexid, riid, cpropertysets, rgpropertysets, pprowset), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            OpenRowset,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            OpenRowset: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpenRowset as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IParentRowset_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetChildRowset(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, iordinal: usize, riid: *const windows_core::GUID, pprowset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IParentRowset", "GetChildRowset", 3usize, |this| ((**(this as *const *const IParentRowset_Vtbl)).GetChildRowset)(this, punkouter, iordinal, riid, pprowset), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetChildRowset }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IParentRowset as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_IndexServer")]
+impl windows_core::imp::ProxyVtable for IProtocolHandlerSite_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn GetFilter(this: *mut core::ffi::c_void, pclsidobj: *const windows_core::GUID, pcwszcontenttype: windows_core::PCWSTR, pcwszextension: windows_core::PCWSTR, ppfilter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProtocolHandlerSite", "GetFilter", 3usize, |this| ((**(this as *const *const IProtocolHandlerSite_Vtbl)).GetFilter)(this, pclsidobj, pcwszcontenttype, pcwszextension, ppfilter), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            GetFilter,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            GetFilter: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProtocolHandlerSite as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IProvideMoniker_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetMoniker(this: *mut core::ffi::c_void, ppimoniker: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProvideMoniker", "GetMoniker", 3usize, |this| ((**(this as *const *const IProvideMoniker_Vtbl)).GetMoniker)(this, ppimoniker), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetMoniker,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetMoniker: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProvideMoniker as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IQueryParser_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Parse(this: *mut core::ffi::c_void, pszinputstring: windows_core::PCWSTR, pcustomproperties: *mut core::ffi::c_void, ppsolution: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryParser", "Parse", 3usize, |this| ((**(this as *const *const IQueryParser_Vtbl)).Parse)(this, pszinputstring, pcustomproperties, ppsolution), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOption(this: *mut core::ffi::c_void, option: STRUCTURED_QUERY_SINGLE_OPTION, poptionvalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryParser", "SetOption", 4usize, |this| ((**(this as *const *const IQueryParser_Vtbl)).SetOption)(this, option, poptionvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOption(this: *mut core::ffi::c_void, option: STRUCTURED_QUERY_SINGLE_OPTION, poptionvalue: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryParser", "GetOption", 5usize, |this| ((**(this as *const *const IQueryParser_Vtbl)).GetOption)(this, option, poptionvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMultiOption(this: *mut core::ffi::c_void, option: STRUCTURED_QUERY_MULTIOPTION, pszoptionkey: windows_core::PCWSTR, poptionvalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryParser", "SetMultiOption", 6usize, |this| ((**(this as *const *const IQueryParser_Vtbl)).SetMultiOption)(this, option, pszoptionkey, poptionvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSchemaProvider(this: *mut core::ffi::c_void, ppschemaprovider: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryParser", "GetSchemaProvider", 7usize, |this| ((**(this as *const *const IQueryParser_Vtbl)).GetSchemaProvider)(this, ppschemaprovider), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RestateToString(this: *mut core::ffi::c_void, pcondition: *mut core::ffi::c_void, fuseenglish: super::super::Foundation::BOOL, ppszquerystring: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryParser", "RestateToString", 8usize, |this| ((**(this as *const *const IQueryParser_Vtbl)).RestateToString)(this, pcondition, fuseenglish, ppszquerystring), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParsePropertyValue(this: *mut core::ffi::c_void, pszpropertyname: windows_core::PCWSTR, pszinputstring: windows_core::PCWSTR, ppsolution: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryParser", "ParsePropertyValue", 9usize, |this| ((**(this as *const *const IQueryParser_Vtbl)).ParsePropertyValue)(this, pszpropertyname, pszinputstring, ppsolution), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RestatePropertyValueToString(this: *mut core::ffi::c_void, pcondition: *mut core::ffi::c_void, fuseenglish: super::super::Foundation::BOOL, ppszpropertyname: *mut windows_core::PWSTR, ppszquerystring: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryParser", "RestatePropertyValueToString", 10usize, |this| ((**(this as *const *const IQueryParser_Vtbl)).RestatePropertyValueToString)(this, pcondition, fuseenglish, ppszpropertyname, ppszquerystring), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Parse,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Parse: 0,
+            SetOption,
+            GetOption,
+            SetMultiOption,
+            GetSchemaProvider,
+            #[cfg(feature = "Win32_System_Com")]
+            RestateToString,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RestateToString: 0,
+            ParsePropertyValue,
+            #[cfg(feature = "Win32_System_Com")]
+            RestatePropertyValueToString,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RestatePropertyValueToString: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IQueryParser as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IQueryParserManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateLoadedParser(this: *mut core::ffi::c_void, pszcatalog: windows_core::PCWSTR, langidforkeywords: u16, riid: *const windows_core::GUID, ppqueryparser: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryParserManager", "CreateLoadedParser", 3usize, |this| ((**(this as *const *const IQueryParserManager_Vtbl)).CreateLoadedParser)(this, pszcatalog, langidforkeywords, riid, ppqueryparser), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitializeOptions(this: *mut core::ffi::c_void, funderstandnqs: super::super::Foundation::BOOL, fautowildcard: super::super::Foundation::BOOL, pqueryparser: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryParserManager", "InitializeOptions", 4usize, |this| ((**(this as *const *const IQueryParserManager_Vtbl)).InitializeOptions)(this, funderstandnqs, fautowildcard, pqueryparser), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOption(this: *mut core::ffi::c_void, option: QUERY_PARSER_MANAGER_OPTION, poptionvalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQueryParserManager", "SetOption", 5usize, |this| ((**(this as *const *const IQueryParserManager_Vtbl)).SetOption)(this, option, poptionvalue), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateLoadedParser, InitializeOptions, SetOption }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IQueryParserManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Search_Common"))]
+impl windows_core::imp::ProxyVtable for IQuerySolution_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetQuery(this: *mut core::ffi::c_void, ppquerynode: *mut *mut core::ffi::c_void, ppmaintype: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQuerySolution", "GetQuery", 7usize, |this| ((**(this as *const *const IQuerySolution_Vtbl)).GetQuery)(this, ppquerynode, ppmaintype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetErrors(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppparseerrors: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQuerySolution", "GetErrors", 8usize, |this| ((**(this as *const *const IQuerySolution_Vtbl)).GetErrors)(this, riid, ppparseerrors), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLexicalData(this: *mut core::ffi::c_void, ppszinputstring: *mut windows_core::PWSTR, pptokens: *mut *mut core::ffi::c_void, plcid: *mut u32, ppwordbreaker: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IQuerySolution", "GetLexicalData", 9usize, |this| ((**(this as *const *const IQuerySolution_Vtbl)).GetLexicalData)(this, ppszinputstring, pptokens, plcid, ppwordbreaker), |result| Some(*result))
+        }
+        Self {
+            base__: <IConditionFactory_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetQuery,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetQuery: 0,
+            GetErrors,
+            GetLexicalData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IQuerySolution as windows_core::Interface>::IID || <IConditionFactory_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IReadData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReadData(this: *mut core::ffi::c_void, hchapter: usize, cbbookmark: usize, pbookmark: *const u8, lrowsoffset: isize, haccessor: HACCESSOR, crows: isize, pcrowsobtained: *mut usize, ppfixeddata: *mut *mut u8, pcbvariabletotal: *mut usize, ppvariabledata: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReadData", "ReadData", 3usize, |this| ((**(this as *const *const IReadData_Vtbl)).ReadData)(this, hchapter, cbbookmark, pbookmark, lrowsoffset, haccessor, crows, pcrowsobtained, ppfixeddata, pcbvariabletotal, ppvariabledata), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseChapter(this: *mut core::ffi::c_void, hchapter: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReadData", "ReleaseChapter", 4usize, |this| ((**(this as *const *const IReadData_Vtbl)).ReleaseChapter)(this, hchapter), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ReadData, ReleaseChapter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IReadData as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRegisterProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetURLMapping(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, dwreserved: usize, pclsidprovider: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterProvider", "GetURLMapping", 3usize, |this| ((**(this as *const *const IRegisterProvider_Vtbl)).GetURLMapping)(this, pwszurl, dwreserved, pclsidprovider), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetURLMapping(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, dwreserved: usize, rclsidprovider: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterProvider", "SetURLMapping", 4usize, |this| ((**(this as *const *const IRegisterProvider_Vtbl)).SetURLMapping)(this, pwszurl, dwreserved, rclsidprovider), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterProvider(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, dwreserved: usize, rclsidprovider: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisterProvider", "UnregisterProvider", 5usize, |this| ((**(this as *const *const IRegisterProvider_Vtbl)).UnregisterProvider)(this, pwszurl, dwreserved, rclsidprovider), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetURLMapping, SetURLMapping, UnregisterProvider }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRegisterProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRelationship_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, ppszname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRelationship", "Name", 3usize, |this| ((**(this as *const *const IRelationship_Vtbl)).Name)(this, ppszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsReal(this: *mut core::ffi::c_void, pisreal: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRelationship", "IsReal", 4usize, |this| ((**(this as *const *const IRelationship_Vtbl)).IsReal)(this, pisreal), |result| Some(*result))
+        }
+        unsafe extern "system" fn Destination(this: *mut core::ffi::c_void, pdestinationentity: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRelationship", "Destination", 5usize, |this| ((**(this as *const *const IRelationship_Vtbl)).Destination)(this, pdestinationentity), |result| Some(*result))
+        }
+        unsafe extern "system" fn MetaData(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, pmetadata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRelationship", "MetaData", 6usize, |this| ((**(this as *const *const IRelationship_Vtbl)).MetaData)(this, riid, pmetadata), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultPhrase(this: *mut core::ffi::c_void, ppszphrase: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRelationship", "DefaultPhrase", 7usize, |this| ((**(this as *const *const IRelationship_Vtbl)).DefaultPhrase)(this, ppszphrase), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, IsReal, Destination, MetaData, DefaultPhrase }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRelationship as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRichChunk_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetData(this: *mut core::ffi::c_void, pfirstpos: *mut u32, plength: *mut u32, ppsz: *mut windows_core::PWSTR, pvalue: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichChunk", "GetData", 3usize, |this| ((**(this as *const *const IRichChunk_Vtbl)).GetData)(this, pfirstpos, plength, ppsz, pvalue), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRichChunk as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_IndexServer")]
+impl windows_core::imp::ProxyVtable for IRow_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn GetColumns(this: *mut core::ffi::c_void, ccolumns: usize, rgcolumns: *mut DBCOLUMNACCESS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRow", "GetColumns", 3usize, |this| ((**(this as *const *const IRow_Vtbl)).GetColumns)(this, ccolumns, rgcolumns), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourceRowset(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, pprowset: *mut *mut core::ffi::c_void, phrow: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRow", "GetSourceRowset", 4usize, |this| ((**(this as *const *const IRow_Vtbl)).GetSourceRowset)(this, riid, pprowset, phrow), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, pcolumnid: *const super::super::Storage::IndexServer::DBID, rguidcolumntype: *const windows_core::GUID, dwbindflags: u32, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRow", "Open", 5usize, |this| ((**(this as *const *const IRow_Vtbl)).Open)(this, punkouter, pcolumnid, rguidcolumntype, dwbindflags, riid, ppunk), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            GetColumns,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            GetColumns: 0,
+            GetSourceRowset,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            Open,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            Open: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRow as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_IndexServer")]
+impl windows_core::imp::ProxyVtable for IRowChange_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn SetColumns(this: *mut core::ffi::c_void, ccolumns: usize, rgcolumns: *const DBCOLUMNACCESS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowChange", "SetColumns", 3usize, |this| ((**(this as *const *const IRowChange_Vtbl)).SetColumns)(this, ccolumns, rgcolumns), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            SetColumns,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            SetColumns: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowChange as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowPosition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ClearRowPosition(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowPosition", "ClearRowPosition", 3usize, |this| ((**(this as *const *const IRowPosition_Vtbl)).ClearRowPosition)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRowPosition(this: *mut core::ffi::c_void, phchapter: *mut usize, phrow: *mut usize, pdwpositionflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowPosition", "GetRowPosition", 4usize, |this| ((**(this as *const *const IRowPosition_Vtbl)).GetRowPosition)(this, phchapter, phrow, pdwpositionflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRowset(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, pprowset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowPosition", "GetRowset", 5usize, |this| ((**(this as *const *const IRowPosition_Vtbl)).GetRowset)(this, riid, pprowset), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, prowset: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowPosition", "Initialize", 6usize, |this| ((**(this as *const *const IRowPosition_Vtbl)).Initialize)(this, prowset), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRowPosition(this: *mut core::ffi::c_void, hchapter: usize, hrow: usize, dwpositionflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowPosition", "SetRowPosition", 7usize, |this| ((**(this as *const *const IRowPosition_Vtbl)).SetRowPosition)(this, hchapter, hrow, dwpositionflags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ClearRowPosition,
+            GetRowPosition,
+            GetRowset,
+            Initialize,
+            SetRowPosition,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowPosition as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowPositionChange_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnRowPositionChange(this: *mut core::ffi::c_void, ereason: u32, ephase: u32, fcantdeny: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowPositionChange", "OnRowPositionChange", 3usize, |this| ((**(this as *const *const IRowPositionChange_Vtbl)).OnRowPositionChange)(this, ereason, ephase, fcantdeny), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnRowPositionChange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowPositionChange as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IRowSchemaChange_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn DeleteColumns(this: *mut core::ffi::c_void, ccolumns: usize, rgcolumnids: *const super::super::Storage::IndexServer::DBID, rgdwstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowSchemaChange", "DeleteColumns", 4usize, |this| ((**(this as *const *const IRowSchemaChange_Vtbl)).DeleteColumns)(this, ccolumns, rgcolumnids, rgdwstatus), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn AddColumns(this: *mut core::ffi::c_void, ccolumns: usize, rgnewcolumninfo: *const DBCOLUMNINFO, rgcolumns: *mut DBCOLUMNACCESS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowSchemaChange", "AddColumns", 5usize, |this| ((**(this as *const *const IRowSchemaChange_Vtbl)).AddColumns)(this, ccolumns, rgnewcolumninfo, rgcolumns), |result| Some(*result))
+        }
+        Self {
+            base__: <IRowChange_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            DeleteColumns,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            DeleteColumns: 0,
+            #[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com"))]
+            AddColumns,
+            #[cfg(not(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com")))]
+            AddColumns: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowSchemaChange as windows_core::Interface>::IID || <IRowChange_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowset_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddRefRows(this: *mut core::ffi::c_void, crows: usize, rghrows: *const usize, rgrefcounts: *mut u32, rgrowstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowset", "AddRefRows", 3usize, |this| ((**(this as *const *const IRowset_Vtbl)).AddRefRows)(this, crows, rghrows, rgrefcounts, rgrowstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetData(this: *mut core::ffi::c_void, hrow: usize, haccessor: HACCESSOR, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowset", "GetData", 4usize, |this| ((**(this as *const *const IRowset_Vtbl)).GetData)(this, hrow, haccessor, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextRows(this: *mut core::ffi::c_void, hreserved: usize, lrowsoffset: isize, crows: isize, pcrowsobtained: *mut usize, prghrows: *mut *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowset", "GetNextRows", 5usize, |this| ((**(this as *const *const IRowset_Vtbl)).GetNextRows)(this, hreserved, lrowsoffset, crows, pcrowsobtained, prghrows), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseRows(this: *mut core::ffi::c_void, crows: usize, rghrows: *const usize, rgrowoptions: *const u32, rgrefcounts: *mut u32, rgrowstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowset", "ReleaseRows", 6usize, |this| ((**(this as *const *const IRowset_Vtbl)).ReleaseRows)(this, crows, rghrows, rgrowoptions, rgrefcounts, rgrowstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestartPosition(this: *mut core::ffi::c_void, hreserved: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowset", "RestartPosition", 7usize, |this| ((**(this as *const *const IRowset_Vtbl)).RestartPosition)(this, hreserved), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddRefRows, GetData, GetNextRows, ReleaseRows, RestartPosition }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowset as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowsetAsynch_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RatioFinished(this: *mut core::ffi::c_void, puldenominator: *mut usize, pulnumerator: *mut usize, pcrows: *mut usize, pfnewrows: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetAsynch", "RatioFinished", 3usize, |this| ((**(this as *const *const IRowsetAsynch_Vtbl)).RatioFinished)(this, puldenominator, pulnumerator, pcrows, pfnewrows), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetAsynch", "Stop", 4usize, |this| ((**(this as *const *const IRowsetAsynch_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RatioFinished, Stop }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetAsynch as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowsetBookmark_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PositionOnBookmark(this: *mut core::ffi::c_void, hchapter: usize, cbbookmark: usize, pbookmark: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetBookmark", "PositionOnBookmark", 3usize, |this| ((**(this as *const *const IRowsetBookmark_Vtbl)).PositionOnBookmark)(this, hchapter, cbbookmark, pbookmark), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PositionOnBookmark }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetBookmark as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowsetChange_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeleteRows(this: *mut core::ffi::c_void, hreserved: usize, crows: usize, rghrows: *const usize, rgrowstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetChange", "DeleteRows", 3usize, |this| ((**(this as *const *const IRowsetChange_Vtbl)).DeleteRows)(this, hreserved, crows, rghrows, rgrowstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, hrow: usize, haccessor: HACCESSOR, pdata: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetChange", "SetData", 4usize, |this| ((**(this as *const *const IRowsetChange_Vtbl)).SetData)(this, hrow, haccessor, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertRow(this: *mut core::ffi::c_void, hreserved: usize, haccessor: HACCESSOR, pdata: *const core::ffi::c_void, phrow: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetChange", "InsertRow", 5usize, |this| ((**(this as *const *const IRowsetChange_Vtbl)).InsertRow)(this, hreserved, haccessor, pdata, phrow), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeleteRows, SetData, InsertRow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetChange as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowsetChangeExtInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOriginalRow(this: *mut core::ffi::c_void, hreserved: usize, hrow: usize, phroworiginal: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetChangeExtInfo", "GetOriginalRow", 3usize, |this| ((**(this as *const *const IRowsetChangeExtInfo_Vtbl)).GetOriginalRow)(this, hreserved, hrow, phroworiginal), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPendingColumns(this: *mut core::ffi::c_void, hreserved: usize, hrow: usize, ccolumnordinals: u32, rgiordinals: *const u32, rgcolumnstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetChangeExtInfo", "GetPendingColumns", 4usize, |this| ((**(this as *const *const IRowsetChangeExtInfo_Vtbl)).GetPendingColumns)(this, hreserved, hrow, ccolumnordinals, rgiordinals, rgcolumnstatus), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetOriginalRow, GetPendingColumns }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetChangeExtInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowsetChapterMember_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsRowInChapter(this: *mut core::ffi::c_void, hchapter: usize, hrow: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetChapterMember", "IsRowInChapter", 3usize, |this| ((**(this as *const *const IRowsetChapterMember_Vtbl)).IsRowInChapter)(this, hchapter, hrow), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsRowInChapter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetChapterMember as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowsetCopyRows_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CloseSource(this: *mut core::ffi::c_void, hsourceid: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetCopyRows", "CloseSource", 3usize, |this| ((**(this as *const *const IRowsetCopyRows_Vtbl)).CloseSource)(this, hsourceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyByHROWS(this: *mut core::ffi::c_void, hsourceid: u16, hreserved: usize, crows: isize, rghrows: *const usize, bflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetCopyRows", "CopyByHROWS", 4usize, |this| ((**(this as *const *const IRowsetCopyRows_Vtbl)).CopyByHROWS)(this, hsourceid, hreserved, crows, rghrows, bflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyRows(this: *mut core::ffi::c_void, hsourceid: u16, hreserved: usize, crows: isize, bflags: u32, pcrowscopied: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetCopyRows", "CopyRows", 5usize, |this| ((**(this as *const *const IRowsetCopyRows_Vtbl)).CopyRows)(this, hsourceid, hreserved, crows, bflags, pcrowscopied), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefineSource(this: *mut core::ffi::c_void, prowsetsource: *mut core::ffi::c_void, ccolids: usize, rgsourcecolumns: *const isize, rgtargetcolumns: *const isize, phsourceid: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetCopyRows", "DefineSource", 6usize, |this| ((**(this as *const *const IRowsetCopyRows_Vtbl)).DefineSource)(this, prowsetsource, ccolids, rgsourcecolumns, rgtargetcolumns, phsourceid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CloseSource, CopyByHROWS, CopyRows, DefineSource }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetCopyRows as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_IndexServer")]
+impl windows_core::imp::ProxyVtable for IRowsetCurrentIndex_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn GetIndex(this: *mut core::ffi::c_void, ppindexid: *mut *mut super::super::Storage::IndexServer::DBID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetCurrentIndex", "GetIndex", 6usize, |this| ((**(this as *const *const IRowsetCurrentIndex_Vtbl)).GetIndex)(this, ppindexid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn SetIndex(this: *mut core::ffi::c_void, pindexid: *const super::super::Storage::IndexServer::DBID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetCurrentIndex", "SetIndex", 7usize, |this| ((**(this as *const *const IRowsetCurrentIndex_Vtbl)).SetIndex)(this, pindexid), |result| Some(*result))
+        }
+        Self {
+            base__: <IRowsetIndex_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            GetIndex,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            GetIndex: 0,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            SetIndex,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            SetIndex: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetCurrentIndex as windows_core::Interface>::IID || <IRowsetIndex_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowsetEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnNewItem(this: *mut core::ffi::c_void, itemid: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, newitemstate: ROWSETEVENT_ITEMSTATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetEvents", "OnNewItem", 3usize, |this| ((**(this as *const *const IRowsetEvents_Vtbl)).OnNewItem)(this, itemid, newitemstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnChangedItem(this: *mut core::ffi::c_void, itemid: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, rowsetitemstate: ROWSETEVENT_ITEMSTATE, changeditemstate: ROWSETEVENT_ITEMSTATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetEvents", "OnChangedItem", 4usize, |this| ((**(this as *const *const IRowsetEvents_Vtbl)).OnChangedItem)(this, itemid, rowsetitemstate, changeditemstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDeletedItem(this: *mut core::ffi::c_void, itemid: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, deleteditemstate: ROWSETEVENT_ITEMSTATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetEvents", "OnDeletedItem", 5usize, |this| ((**(this as *const *const IRowsetEvents_Vtbl)).OnDeletedItem)(this, itemid, deleteditemstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnRowsetEvent(this: *mut core::ffi::c_void, eventtype: ROWSETEVENT_TYPE, eventdata: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetEvents", "OnRowsetEvent", 6usize, |this| ((**(this as *const *const IRowsetEvents_Vtbl)).OnRowsetEvent)(this, eventtype, eventdata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnNewItem, OnChangedItem, OnDeletedItem, OnRowsetEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowsetExactScroll_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetExactPosition(this: *mut core::ffi::c_void, hchapter: usize, cbbookmark: usize, pbookmark: *const u8, pulposition: *mut usize, pcrows: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetExactScroll", "GetExactPosition", 14usize, |this| ((**(this as *const *const IRowsetExactScroll_Vtbl)).GetExactPosition)(this, hchapter, cbbookmark, pbookmark, pulposition, pcrows), |result| Some(*result))
+        }
+        Self { base__: <IRowsetScroll_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetExactPosition }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetExactScroll as windows_core::Interface>::IID || <IRowsetScroll_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowsetFastLoad_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InsertRow(this: *mut core::ffi::c_void, haccessor: HACCESSOR, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetFastLoad", "InsertRow", 3usize, |this| ((**(this as *const *const IRowsetFastLoad_Vtbl)).InsertRow)(this, haccessor, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void, fdone: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetFastLoad", "Commit", 4usize, |this| ((**(this as *const *const IRowsetFastLoad_Vtbl)).Commit)(this, fdone), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InsertRow, Commit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetFastLoad as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowsetFind_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FindNextRow(this: *mut core::ffi::c_void, hchapter: usize, haccessor: HACCESSOR, pfindvalue: *const core::ffi::c_void, compareop: u32, cbbookmark: usize, pbookmark: *const u8, lrowsoffset: isize, crows: isize, pcrowsobtained: *mut usize, prghrows: *mut *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetFind", "FindNextRow", 3usize, |this| ((**(this as *const *const IRowsetFind_Vtbl)).FindNextRow)(this, hchapter, haccessor, pfindvalue, compareop, cbbookmark, pbookmark, lrowsoffset, crows, pcrowsobtained, prghrows), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FindNextRow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetFind as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowsetIdentity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsSameRow(this: *mut core::ffi::c_void, hthisrow: usize, hthatrow: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetIdentity", "IsSameRow", 3usize, |this| ((**(this as *const *const IRowsetIdentity_Vtbl)).IsSameRow)(this, hthisrow, hthatrow), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsSameRow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetIdentity as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_IndexServer")]
+impl windows_core::imp::ProxyVtable for IRowsetIndex_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn GetIndexInfo(this: *mut core::ffi::c_void, pckeycolumns: *mut usize, prgindexcolumndesc: *mut *mut DBINDEXCOLUMNDESC, pcindexpropertysets: *mut u32, prgindexpropertysets: *mut *mut DBPROPSET) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetIndex", "GetIndexInfo", 3usize, |this| ((**(this as *const *const IRowsetIndex_Vtbl)).GetIndexInfo)(this, pckeycolumns, prgindexcolumndesc, pcindexpropertysets, prgindexpropertysets), |result| Some(*result))
+        }
+        unsafe extern "system" fn Seek(this: *mut core::ffi::c_void, haccessor: HACCESSOR, ckeyvalues: usize, pdata: *const core::ffi::c_void, dwseekoptions: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetIndex", "Seek", 4usize, |this| ((**(this as *const *const IRowsetIndex_Vtbl)).Seek)(this, haccessor, ckeyvalues, pdata, dwseekoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRange(this: *mut core::ffi::c_void, haccessor: HACCESSOR, cstartkeycolumns: usize, pstartdata: *const core::ffi::c_void, cendkeycolumns: usize, penddata: *const core::ffi::c_void, dwrangeoptions: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetIndex", "SetRange", 5usize, |this| ((**(this as *const *const IRowsetIndex_Vtbl)).SetRange)(this, haccessor, cstartkeycolumns, pstartdata, cendkeycolumns, penddata, dwrangeoptions), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            GetIndexInfo,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            GetIndexInfo: 0,
+            Seek,
+            SetRange,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetIndex as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_IndexServer")]
+impl windows_core::imp::ProxyVtable for IRowsetInfo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, cpropertyidsets: u32, rgpropertyidsets: *const DBPROPIDSET, pcpropertysets: *mut u32, prgpropertysets: *mut *mut DBPROPSET) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetInfo", "GetProperties", 3usize, |this| ((**(this as *const *const IRowsetInfo_Vtbl)).GetProperties)(this, cpropertyidsets, rgpropertyidsets, pcpropertysets, prgpropertysets), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReferencedRowset(this: *mut core::ffi::c_void, iordinal: usize, riid: *const windows_core::GUID, ppreferencedrowset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetInfo", "GetReferencedRowset", 4usize, |this| ((**(this as *const *const IRowsetInfo_Vtbl)).GetReferencedRowset)(this, iordinal, riid, ppreferencedrowset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSpecification(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppspecification: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetInfo", "GetSpecification", 5usize, |this| ((**(this as *const *const IRowsetInfo_Vtbl)).GetSpecification)(this, riid, ppspecification), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            GetProperties,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            GetProperties: 0,
+            GetReferencedRowset,
+            GetSpecification,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowsetKeys_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ListKeys(this: *mut core::ffi::c_void, pccolumns: *mut usize, prgcolumns: *mut *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetKeys", "ListKeys", 3usize, |this| ((**(this as *const *const IRowsetKeys_Vtbl)).ListKeys)(this, pccolumns, prgcolumns), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ListKeys }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetKeys as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowsetLocate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Compare(this: *mut core::ffi::c_void, hreserved: usize, cbbookmark1: usize, pbookmark1: *const u8, cbbookmark2: usize, pbookmark2: *const u8, pcomparison: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetLocate", "Compare", 8usize, |this| ((**(this as *const *const IRowsetLocate_Vtbl)).Compare)(this, hreserved, cbbookmark1, pbookmark1, cbbookmark2, pbookmark2, pcomparison), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRowsAt(this: *mut core::ffi::c_void, hreserved1: usize, hreserved2: usize, cbbookmark: usize, pbookmark: *const u8, lrowsoffset: isize, crows: isize, pcrowsobtained: *mut usize, prghrows: *mut *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetLocate", "GetRowsAt", 9usize, |this| ((**(this as *const *const IRowsetLocate_Vtbl)).GetRowsAt)(this, hreserved1, hreserved2, cbbookmark, pbookmark, lrowsoffset, crows, pcrowsobtained, prghrows), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRowsByBookmark(this: *mut core::ffi::c_void, hreserved: usize, crows: usize, rgcbbookmarks: *const usize, rgpbookmarks: *const *const u8, rghrows: *mut usize, rgrowstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetLocate", "GetRowsByBookmark", 10usize, |this| ((**(this as *const *const IRowsetLocate_Vtbl)).GetRowsByBookmark)(this, hreserved, crows, rgcbbookmarks, rgpbookmarks, rghrows, rgrowstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn Hash(this: *mut core::ffi::c_void, hreserved: usize, cbookmarks: usize, rgcbbookmarks: *const usize, rgpbookmarks: *const *const u8, rghashedvalues: *mut usize, rgbookmarkstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetLocate", "Hash", 11usize, |this| ((**(this as *const *const IRowsetLocate_Vtbl)).Hash)(this, hreserved, cbookmarks, rgcbbookmarks, rgpbookmarks, rghashedvalues, rgbookmarkstatus), |result| Some(*result))
+        }
+        Self { base__: <IRowset_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Compare, GetRowsAt, GetRowsByBookmark, Hash }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetLocate as windows_core::Interface>::IID || <IRowset_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowsetNewRowAfter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetNewDataAfter(this: *mut core::ffi::c_void, hchapter: usize, cbbmprevious: u32, pbmprevious: *const u8, haccessor: HACCESSOR, pdata: *const u8, phrow: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetNewRowAfter", "SetNewDataAfter", 3usize, |this| ((**(this as *const *const IRowsetNewRowAfter_Vtbl)).SetNewDataAfter)(this, hchapter, cbbmprevious, pbmprevious, haccessor, pdata, phrow), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetNewDataAfter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetNewRowAfter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowsetNextRowset_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNextRowset(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppnextrowset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetNextRowset", "GetNextRowset", 3usize, |this| ((**(this as *const *const IRowsetNextRowset_Vtbl)).GetNextRowset)(this, punkouter, riid, ppnextrowset), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetNextRowset }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetNextRowset as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowsetNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnFieldChange(this: *mut core::ffi::c_void, prowset: *mut core::ffi::c_void, hrow: usize, ccolumns: usize, rgcolumns: *const usize, ereason: u32, ephase: u32, fcantdeny: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetNotify", "OnFieldChange", 3usize, |this| ((**(this as *const *const IRowsetNotify_Vtbl)).OnFieldChange)(this, prowset, hrow, ccolumns, rgcolumns, ereason, ephase, fcantdeny), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnRowChange(this: *mut core::ffi::c_void, prowset: *mut core::ffi::c_void, crows: usize, rghrows: *const usize, ereason: u32, ephase: u32, fcantdeny: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetNotify", "OnRowChange", 4usize, |this| ((**(this as *const *const IRowsetNotify_Vtbl)).OnRowChange)(this, prowset, crows, rghrows, ereason, ephase, fcantdeny), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnRowsetChange(this: *mut core::ffi::c_void, prowset: *mut core::ffi::c_void, ereason: u32, ephase: u32, fcantdeny: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetNotify", "OnRowsetChange", 5usize, |this| ((**(this as *const *const IRowsetNotify_Vtbl)).OnRowsetChange)(this, prowset, ereason, ephase, fcantdeny), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnFieldChange, OnRowChange, OnRowsetChange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowsetPrioritization_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetScopePriority(this: *mut core::ffi::c_void, priority: PRIORITY_LEVEL, scopestatisticseventfrequency: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetPrioritization", "SetScopePriority", 3usize, |this| ((**(this as *const *const IRowsetPrioritization_Vtbl)).SetScopePriority)(this, priority, scopestatisticseventfrequency), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScopePriority(this: *mut core::ffi::c_void, priority: *mut PRIORITY_LEVEL, scopestatisticseventfrequency: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetPrioritization", "GetScopePriority", 4usize, |this| ((**(this as *const *const IRowsetPrioritization_Vtbl)).GetScopePriority)(this, priority, scopestatisticseventfrequency), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScopeStatistics(this: *mut core::ffi::c_void, indexeddocumentcount: *mut u32, oustandingaddcount: *mut u32, oustandingmodifycount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetPrioritization", "GetScopeStatistics", 5usize, |this| ((**(this as *const *const IRowsetPrioritization_Vtbl)).GetScopeStatistics)(this, indexeddocumentcount, oustandingaddcount, oustandingmodifycount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetScopePriority, GetScopePriority, GetScopeStatistics }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetPrioritization as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowsetQueryStatus_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetQueryStatus", "GetStatus", 3usize, |this| ((**(this as *const *const IRowsetQueryStatus_Vtbl)).GetStatus)(this, pdwstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatusEx(this: *mut core::ffi::c_void, pdwstatus: *mut u32, pcfiltereddocuments: *mut u32, pcdocumentstofilter: *mut u32, pdwratiofinisheddenominator: *mut usize, pdwratiofinishednumerator: *mut usize, cbbmk: usize, pbmk: *const u8, pirowbmk: *mut usize, pcrowstotal: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetQueryStatus", "GetStatusEx", 4usize, |this| ((**(this as *const *const IRowsetQueryStatus_Vtbl)).GetStatusEx)(this, pdwstatus, pcfiltereddocuments, pcdocumentstofilter, pdwratiofinisheddenominator, pdwratiofinishednumerator, cbbmk, pbmk, pirowbmk, pcrowstotal), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStatus, GetStatusEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetQueryStatus as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowsetRefresh_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RefreshVisibleData(this: *mut core::ffi::c_void, hchapter: usize, crows: usize, rghrows: *const usize, foverwrite: super::super::Foundation::BOOL, pcrowsrefreshed: *mut usize, prghrowsrefreshed: *mut *mut usize, prgrowstatus: *mut *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetRefresh", "RefreshVisibleData", 3usize, |this| ((**(this as *const *const IRowsetRefresh_Vtbl)).RefreshVisibleData)(this, hchapter, crows, rghrows, foverwrite, pcrowsrefreshed, prghrowsrefreshed, prgrowstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastVisibleData(this: *mut core::ffi::c_void, hrow: usize, haccessor: HACCESSOR, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetRefresh", "GetLastVisibleData", 4usize, |this| ((**(this as *const *const IRowsetRefresh_Vtbl)).GetLastVisibleData)(this, hrow, haccessor, pdata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RefreshVisibleData, GetLastVisibleData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetRefresh as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowsetResynch_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetVisibleData(this: *mut core::ffi::c_void, hrow: usize, haccessor: HACCESSOR, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetResynch", "GetVisibleData", 3usize, |this| ((**(this as *const *const IRowsetResynch_Vtbl)).GetVisibleData)(this, hrow, haccessor, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResynchRows(this: *mut core::ffi::c_void, crows: usize, rghrows: *const usize, pcrowsresynched: *mut usize, prghrowsresynched: *mut *mut usize, prgrowstatus: *mut *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetResynch", "ResynchRows", 4usize, |this| ((**(this as *const *const IRowsetResynch_Vtbl)).ResynchRows)(this, crows, rghrows, pcrowsresynched, prghrowsresynched, prgrowstatus), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetVisibleData, ResynchRows }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetResynch as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowsetScroll_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetApproximatePosition(this: *mut core::ffi::c_void, hreserved: usize, cbbookmark: usize, pbookmark: *const u8, pulposition: *mut usize, pcrows: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetScroll", "GetApproximatePosition", 12usize, |this| ((**(this as *const *const IRowsetScroll_Vtbl)).GetApproximatePosition)(this, hreserved, cbbookmark, pbookmark, pulposition, pcrows), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRowsAtRatio(this: *mut core::ffi::c_void, hreserved1: usize, hreserved2: usize, ulnumerator: usize, uldenominator: usize, crows: isize, pcrowsobtained: *mut usize, prghrows: *mut *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetScroll", "GetRowsAtRatio", 13usize, |this| ((**(this as *const *const IRowsetScroll_Vtbl)).GetRowsAtRatio)(this, hreserved1, hreserved2, ulnumerator, uldenominator, crows, pcrowsobtained, prghrows), |result| Some(*result))
+        }
+        Self { base__: <IRowsetLocate_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetApproximatePosition, GetRowsAtRatio }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetScroll as windows_core::Interface>::IID || <IRowsetLocate_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowsetUpdate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOriginalData(this: *mut core::ffi::c_void, hrow: usize, haccessor: HACCESSOR, pdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetUpdate", "GetOriginalData", 6usize, |this| ((**(this as *const *const IRowsetUpdate_Vtbl)).GetOriginalData)(this, hrow, haccessor, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPendingRows(this: *mut core::ffi::c_void, hreserved: usize, dwrowstatus: u32, pcpendingrows: *mut usize, prgpendingrows: *mut *mut usize, prgpendingstatus: *mut *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetUpdate", "GetPendingRows", 7usize, |this| ((**(this as *const *const IRowsetUpdate_Vtbl)).GetPendingRows)(this, hreserved, dwrowstatus, pcpendingrows, prgpendingrows, prgpendingstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRowStatus(this: *mut core::ffi::c_void, hreserved: usize, crows: usize, rghrows: *const usize, rgpendingstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetUpdate", "GetRowStatus", 8usize, |this| ((**(this as *const *const IRowsetUpdate_Vtbl)).GetRowStatus)(this, hreserved, crows, rghrows, rgpendingstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn Undo(this: *mut core::ffi::c_void, hreserved: usize, crows: usize, rghrows: *const usize, pcrowsundone: *mut usize, prgrowsundone: *mut *mut usize, prgrowstatus: *mut *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetUpdate", "Undo", 9usize, |this| ((**(this as *const *const IRowsetUpdate_Vtbl)).Undo)(this, hreserved, crows, rghrows, pcrowsundone, prgrowsundone, prgrowstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void, hreserved: usize, crows: usize, rghrows: *const usize, pcrows: *mut usize, prgrows: *mut *mut usize, prgrowstatus: *mut *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetUpdate", "Update", 10usize, |this| ((**(this as *const *const IRowsetUpdate_Vtbl)).Update)(this, hreserved, crows, rghrows, pcrows, prgrows, prgrowstatus), |result| Some(*result))
+        }
+        Self { base__: <IRowsetChange_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetOriginalData, GetPendingRows, GetRowStatus, Undo, Update }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetUpdate as windows_core::Interface>::IID || <IRowsetChange_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowsetView_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateView(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetView", "CreateView", 3usize, |this| ((**(this as *const *const IRowsetView_Vtbl)).CreateView)(this, punkouter, riid, ppview), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetView(this: *mut core::ffi::c_void, hchapter: usize, riid: *const windows_core::GUID, phchaptersource: *mut usize, ppview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetView", "GetView", 4usize, |this| ((**(this as *const *const IRowsetView_Vtbl)).GetView)(this, hchapter, riid, phchaptersource, ppview), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateView, GetView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetView as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowsetWatchAll_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Acknowledge(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetWatchAll", "Acknowledge", 3usize, |this| ((**(this as *const *const IRowsetWatchAll_Vtbl)).Acknowledge)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetWatchAll", "Start", 4usize, |this| ((**(this as *const *const IRowsetWatchAll_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopWatching(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetWatchAll", "StopWatching", 5usize, |this| ((**(this as *const *const IRowsetWatchAll_Vtbl)).StopWatching)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Acknowledge, Start, StopWatching }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetWatchAll as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowsetWatchNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnChange(this: *mut core::ffi::c_void, prowset: *mut core::ffi::c_void, echangereason: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetWatchNotify", "OnChange", 3usize, |this| ((**(this as *const *const IRowsetWatchNotify_Vtbl)).OnChange)(this, prowset, echangereason), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnChange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetWatchNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRowsetWatchRegion_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateWatchRegion(this: *mut core::ffi::c_void, dwwatchmode: u32, phregion: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetWatchRegion", "CreateWatchRegion", 6usize, |this| ((**(this as *const *const IRowsetWatchRegion_Vtbl)).CreateWatchRegion)(this, dwwatchmode, phregion), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeWatchMode(this: *mut core::ffi::c_void, hregion: usize, dwwatchmode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetWatchRegion", "ChangeWatchMode", 7usize, |this| ((**(this as *const *const IRowsetWatchRegion_Vtbl)).ChangeWatchMode)(this, hregion, dwwatchmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteWatchRegion(this: *mut core::ffi::c_void, hregion: usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetWatchRegion", "DeleteWatchRegion", 8usize, |this| ((**(this as *const *const IRowsetWatchRegion_Vtbl)).DeleteWatchRegion)(this, hregion), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWatchRegionInfo(this: *mut core::ffi::c_void, hregion: usize, pdwwatchmode: *mut u32, phchapter: *mut usize, pcbbookmark: *mut usize, ppbookmark: *mut *mut u8, pcrows: *mut isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetWatchRegion", "GetWatchRegionInfo", 9usize, |this| ((**(this as *const *const IRowsetWatchRegion_Vtbl)).GetWatchRegionInfo)(this, hregion, pdwwatchmode, phchapter, pcbbookmark, ppbookmark, pcrows), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void, pcchangesobtained: *mut usize, prgchanges: *mut *mut DBROWWATCHCHANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetWatchRegion", "Refresh", 10usize, |this| ((**(this as *const *const IRowsetWatchRegion_Vtbl)).Refresh)(this, pcchangesobtained, prgchanges), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShrinkWatchRegion(this: *mut core::ffi::c_void, hregion: usize, hchapter: usize, cbbookmark: usize, pbookmark: *const u8, crows: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetWatchRegion", "ShrinkWatchRegion", 11usize, |this| ((**(this as *const *const IRowsetWatchRegion_Vtbl)).ShrinkWatchRegion)(this, hregion, hchapter, cbbookmark, pbookmark, crows), |result| Some(*result))
+        }
+        Self {
+            base__: <IRowsetWatchAll_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateWatchRegion,
+            ChangeWatchMode,
+            DeleteWatchRegion,
+            GetWatchRegionInfo,
+            Refresh,
+            ShrinkWatchRegion,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetWatchRegion as windows_core::Interface>::IID || <IRowsetWatchAll_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRowsetWithParameters_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetParameterInfo(this: *mut core::ffi::c_void, pcparams: *mut usize, prgparaminfo: *mut *mut DBPARAMINFO, ppnamesbuffer: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetWithParameters", "GetParameterInfo", 3usize, |this| ((**(this as *const *const IRowsetWithParameters_Vtbl)).GetParameterInfo)(this, pcparams, prgparaminfo, ppnamesbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn Requery(this: *mut core::ffi::c_void, pparams: *const DBPARAMS, pulerrorparam: *mut u32, phreserved: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRowsetWithParameters", "Requery", 4usize, |this| ((**(this as *const *const IRowsetWithParameters_Vtbl)).Requery)(this, pparams, pulerrorparam, phreserved), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetParameterInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetParameterInfo: 0,
+            Requery,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRowsetWithParameters as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISQLErrorInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSQLInfo(this: *mut core::ffi::c_void, pbstrsqlstate: *mut core::mem::MaybeUninit<windows_core::BSTR>, plnativeerror: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISQLErrorInfo", "GetSQLInfo", 3usize, |this| ((**(this as *const *const ISQLErrorInfo_Vtbl)).GetSQLInfo)(this, pbstrsqlstate, plnativeerror), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSQLInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISQLErrorInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISQLGetDiagField_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDiagField(this: *mut core::ffi::c_void, pdiaginfo: *mut KAGGETDIAG) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISQLGetDiagField", "GetDiagField", 3usize, |this| ((**(this as *const *const ISQLGetDiagField_Vtbl)).GetDiagField)(this, pdiaginfo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDiagField }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISQLGetDiagField as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Variant")]
+impl windows_core::imp::ProxyVtable for ISQLRequestDiagFields_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Variant")]
+        unsafe extern "system" fn RequestDiagFields(this: *mut core::ffi::c_void, cdiagfields: u32, rgdiagfields: *const KAGREQDIAG) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISQLRequestDiagFields", "RequestDiagFields", 3usize, |this| ((**(this as *const *const ISQLRequestDiagFields_Vtbl)).RequestDiagFields)(this, cdiagfields, rgdiagfields), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Variant")]
+            RequestDiagFields,
+            #[cfg(not(feature = "Win32_System_Variant"))]
+            RequestDiagFields: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISQLRequestDiagFields as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISQLServerErrorInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetErrorInfo(this: *mut core::ffi::c_void, pperrorinfo: *mut *mut SSERRORINFO, ppstringsbuffer: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISQLServerErrorInfo", "GetErrorInfo", 3usize, |this| ((**(this as *const *const ISQLServerErrorInfo_Vtbl)).GetErrorInfo)(this, pperrorinfo, ppstringsbuffer), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetErrorInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISQLServerErrorInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISchemaLocalizerSupport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Localize(this: *mut core::ffi::c_void, pszglobalstring: windows_core::PCWSTR, ppszlocalstring: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaLocalizerSupport", "Localize", 3usize, |this| ((**(this as *const *const ISchemaLocalizerSupport_Vtbl)).Localize)(this, pszglobalstring, ppszlocalstring), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Localize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISchemaLocalizerSupport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_IndexServer")]
+impl windows_core::imp::ProxyVtable for ISchemaLock_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn GetSchemaLock(this: *mut core::ffi::c_void, ptableid: *mut super::super::Storage::IndexServer::DBID, lmmode: u32, phlockhandle: *mut super::super::Foundation::HANDLE, ptableversion: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaLock", "GetSchemaLock", 3usize, |this| ((**(this as *const *const ISchemaLock_Vtbl)).GetSchemaLock)(this, ptableid, lmmode, phlockhandle, ptableversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseSchemaLock(this: *mut core::ffi::c_void, hlockhandle: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaLock", "ReleaseSchemaLock", 4usize, |this| ((**(this as *const *const ISchemaLock_Vtbl)).ReleaseSchemaLock)(this, hlockhandle), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            GetSchemaLock,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            GetSchemaLock: 0,
+            ReleaseSchemaLock,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISchemaLock as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISchemaProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Entities(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, pentities: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaProvider", "Entities", 3usize, |this| ((**(this as *const *const ISchemaProvider_Vtbl)).Entities)(this, riid, pentities), |result| Some(*result))
+        }
+        unsafe extern "system" fn RootEntity(this: *mut core::ffi::c_void, prootentity: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaProvider", "RootEntity", 4usize, |this| ((**(this as *const *const ISchemaProvider_Vtbl)).RootEntity)(this, prootentity), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEntity(this: *mut core::ffi::c_void, pszentityname: windows_core::PCWSTR, pentity: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaProvider", "GetEntity", 5usize, |this| ((**(this as *const *const ISchemaProvider_Vtbl)).GetEntity)(this, pszentityname, pentity), |result| Some(*result))
+        }
+        unsafe extern "system" fn MetaData(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, pmetadata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaProvider", "MetaData", 6usize, |this| ((**(this as *const *const ISchemaProvider_Vtbl)).MetaData)(this, riid, pmetadata), |result| Some(*result))
+        }
+        unsafe extern "system" fn Localize(this: *mut core::ffi::c_void, lcid: u32, pschemalocalizersupport: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaProvider", "Localize", 7usize, |this| ((**(this as *const *const ISchemaProvider_Vtbl)).Localize)(this, lcid, pschemalocalizersupport), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveBinary(this: *mut core::ffi::c_void, pszschemabinarypath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaProvider", "SaveBinary", 8usize, |this| ((**(this as *const *const ISchemaProvider_Vtbl)).SaveBinary)(this, pszschemabinarypath), |result| Some(*result))
+        }
+        unsafe extern "system" fn LookupAuthoredNamedEntity(this: *mut core::ffi::c_void, pentity: *mut core::ffi::c_void, pszinputstring: windows_core::PCWSTR, ptokencollection: *mut core::ffi::c_void, ctokensbegin: u32, pctokenslength: *mut u32, ppszvalue: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISchemaProvider", "LookupAuthoredNamedEntity", 9usize, |this| ((**(this as *const *const ISchemaProvider_Vtbl)).LookupAuthoredNamedEntity)(this, pentity, pszinputstring, ptokencollection, ctokensbegin, pctokenslength, ppszvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Entities,
+            RootEntity,
+            GetEntity,
+            MetaData,
+            Localize,
+            SaveBinary,
+            LookupAuthoredNamedEntity,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISchemaProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IScopedOperations_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Copy(this: *mut core::ffi::c_void, crows: usize, rgpwszsourceurls: *const windows_core::PCWSTR, rgpwszdesturls: *const windows_core::PCWSTR, dwcopyflags: u32, pauthenticate: *mut core::ffi::c_void, rgdwstatus: *mut u32, rgpwsznewurls: *mut windows_core::PWSTR, ppstringsbuffer: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScopedOperations", "Copy", 4usize, |this| ((**(this as *const *const IScopedOperations_Vtbl)).Copy)(this, crows, rgpwszsourceurls, rgpwszdesturls, dwcopyflags, pauthenticate, rgdwstatus, rgpwsznewurls, ppstringsbuffer), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Move(this: *mut core::ffi::c_void, crows: usize, rgpwszsourceurls: *const windows_core::PCWSTR, rgpwszdesturls: *const windows_core::PCWSTR, dwmoveflags: u32, pauthenticate: *mut core::ffi::c_void, rgdwstatus: *mut u32, rgpwsznewurls: *mut windows_core::PWSTR, ppstringsbuffer: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScopedOperations", "Move", 5usize, |this| ((**(this as *const *const IScopedOperations_Vtbl)).Move)(this, crows, rgpwszsourceurls, rgpwszdesturls, dwmoveflags, pauthenticate, rgdwstatus, rgpwsznewurls, ppstringsbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, crows: usize, rgpwszurls: *const windows_core::PCWSTR, dwdeleteflags: u32, rgdwstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScopedOperations", "Delete", 6usize, |this| ((**(this as *const *const IScopedOperations_Vtbl)).Delete)(this, crows, rgpwszurls, dwdeleteflags, rgdwstatus), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn OpenRowset(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, ptableid: *const super::super::Storage::IndexServer::DBID, pindexid: *const super::super::Storage::IndexServer::DBID, riid: *const windows_core::GUID, cpropertysets: u32, rgpropertysets: *mut DBPROPSET, pprowset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScopedOperations", "OpenRowset", 7usize, |this| ((**(this as *const *const IScopedOperations_Vtbl)).OpenRowset)(this, punkouter, ptableid, pindexid, riid, cpropertysets, rgpropertysets, pprowset), |result| Some(*result))
+        }
+        Self {
+            base__: <IBindResource_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Copy,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Copy: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Move,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Move: 0,
+            Delete,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            OpenRowset,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            OpenRowset: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScopedOperations as windows_core::Interface>::IID || <IBindResource_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISearchCatalogManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pszname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCatalogManager", "Name", 3usize, |this| ((**(this as *const *const ISearchCatalogManager_Vtbl)).Name)(this, pszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParameter(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, ppvalue: *mut *mut windows_core::PROPVARIANT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCatalogManager", "GetParameter", 4usize, |this| ((**(this as *const *const ISearchCatalogManager_Vtbl)).GetParameter)(this, pszname, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParameter(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, pvalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCatalogManager", "SetParameter", 5usize, |this| ((**(this as *const *const ISearchCatalogManager_Vtbl)).SetParameter)(this, pszname, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCatalogStatus(this: *mut core::ffi::c_void, pstatus: *mut CatalogStatus, ppausedreason: *mut CatalogPausedReason) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCatalogManager", "GetCatalogStatus", 6usize, |this| ((**(this as *const *const ISearchCatalogManager_Vtbl)).GetCatalogStatus)(this, pstatus, ppausedreason), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCatalogManager", "Reset", 7usize, |this| ((**(this as *const *const ISearchCatalogManager_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reindex(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCatalogManager", "Reindex", 8usize, |this| ((**(this as *const *const ISearchCatalogManager_Vtbl)).Reindex)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReindexMatchingURLs(this: *mut core::ffi::c_void, pszpattern: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCatalogManager", "ReindexMatchingURLs", 9usize, |this| ((**(this as *const *const ISearchCatalogManager_Vtbl)).ReindexMatchingURLs)(this, pszpattern), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReindexSearchRoot(this: *mut core::ffi::c_void, pszrooturl: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCatalogManager", "ReindexSearchRoot", 10usize, |this| ((**(this as *const *const ISearchCatalogManager_Vtbl)).ReindexSearchRoot)(this, pszrooturl), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConnectTimeout(this: *mut core::ffi::c_void, dwconnecttimeout: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCatalogManager", "SetConnectTimeout", 11usize, |this| ((**(this as *const *const ISearchCatalogManager_Vtbl)).SetConnectTimeout)(this, dwconnecttimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectTimeout(this: *mut core::ffi::c_void, pdwconnecttimeout: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCatalogManager", "ConnectTimeout", 12usize, |this| ((**(this as *const *const ISearchCatalogManager_Vtbl)).ConnectTimeout)(this, pdwconnecttimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDataTimeout(this: *mut core::ffi::c_void, dwdatatimeout: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCatalogManager", "SetDataTimeout", 13usize, |this| ((**(this as *const *const ISearchCatalogManager_Vtbl)).SetDataTimeout)(this, dwdatatimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataTimeout(this: *mut core::ffi::c_void, pdwdatatimeout: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCatalogManager", "DataTimeout", 14usize, |this| ((**(this as *const *const ISearchCatalogManager_Vtbl)).DataTimeout)(this, pdwdatatimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfItems(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCatalogManager", "NumberOfItems", 15usize, |this| ((**(this as *const *const ISearchCatalogManager_Vtbl)).NumberOfItems)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfItemsToIndex(this: *mut core::ffi::c_void, plincrementalcount: *mut i32, plnotificationqueue: *mut i32, plhighpriorityqueue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCatalogManager", "NumberOfItemsToIndex", 16usize, |this| ((**(this as *const *const ISearchCatalogManager_Vtbl)).NumberOfItemsToIndex)(this, plincrementalcount, plnotificationqueue, plhighpriorityqueue), |result| Some(*result))
+        }
+        unsafe extern "system" fn URLBeingIndexed(this: *mut core::ffi::c_void, pszurl: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCatalogManager", "URLBeingIndexed", 17usize, |this| ((**(this as *const *const ISearchCatalogManager_Vtbl)).URLBeingIndexed)(this, pszurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetURLIndexingState(this: *mut core::ffi::c_void, pszurl: windows_core::PCWSTR, pdwstate: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCatalogManager", "GetURLIndexingState", 18usize, |this| ((**(this as *const *const ISearchCatalogManager_Vtbl)).GetURLIndexingState)(this, pszurl, pdwstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPersistentItemsChangedSink(this: *mut core::ffi::c_void, ppisearchpersistentitemschangedsink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCatalogManager", "GetPersistentItemsChangedSink", 19usize, |this| ((**(this as *const *const ISearchCatalogManager_Vtbl)).GetPersistentItemsChangedSink)(this, ppisearchpersistentitemschangedsink), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterViewForNotification(this: *mut core::ffi::c_void, pszview: windows_core::PCWSTR, pviewchangedsink: *mut core::ffi::c_void, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCatalogManager", "RegisterViewForNotification", 20usize, |this| ((**(this as *const *const ISearchCatalogManager_Vtbl)).RegisterViewForNotification)(this, pszview, pviewchangedsink, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItemsChangedSink(this: *mut core::ffi::c_void, pisearchnotifyinlinesite: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void, pguidcatalogresetsignature: *mut windows_core::GUID, pguidcheckpointsignature: *mut windows_core::GUID, pdwlastcheckpointnumber: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCatalogManager", "GetItemsChangedSink", 21usize, |this| ((**(this as *const *const ISearchCatalogManager_Vtbl)).GetItemsChangedSink)(this, pisearchnotifyinlinesite, riid, ppv, pguidcatalogresetsignature, pguidcheckpointsignature, pdwlastcheckpointnumber), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterViewForNotification(this: *mut core::ffi::c_void, dwcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCatalogManager", "UnregisterViewForNotification", 22usize, |this| ((**(this as *const *const ISearchCatalogManager_Vtbl)).UnregisterViewForNotification)(this, dwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExtensionClusion(this: *mut core::ffi::c_void, pszextension: windows_core::PCWSTR, fexclude: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCatalogManager", "SetExtensionClusion", 23usize, |this| ((**(this as *const *const ISearchCatalogManager_Vtbl)).SetExtensionClusion)(this, pszextension, fexclude), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumerateExcludedExtensions(this: *mut core::ffi::c_void, ppextensions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCatalogManager", "EnumerateExcludedExtensions", 24usize, |this| ((**(this as *const *const ISearchCatalogManager_Vtbl)).EnumerateExcludedExtensions)(this, ppextensions), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetQueryHelper(this: *mut core::ffi::c_void, ppsearchqueryhelper: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCatalogManager", "GetQueryHelper", 25usize, |this| ((**(this as *const *const ISearchCatalogManager_Vtbl)).GetQueryHelper)(this, ppsearchqueryhelper), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDiacriticSensitivity(this: *mut core::ffi::c_void, fdiacriticsensitive: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCatalogManager", "SetDiacriticSensitivity", 26usize, |this| ((**(this as *const *const ISearchCatalogManager_Vtbl)).SetDiacriticSensitivity)(this, fdiacriticsensitive), |result| Some(*result))
+        }
+        unsafe extern "system" fn DiacriticSensitivity(this: *mut core::ffi::c_void, pfdiacriticsensitive: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCatalogManager", "DiacriticSensitivity", 27usize, |this| ((**(this as *const *const ISearchCatalogManager_Vtbl)).DiacriticSensitivity)(this, pfdiacriticsensitive), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCrawlScopeManager(this: *mut core::ffi::c_void, ppcrawlscopemanager: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCatalogManager", "GetCrawlScopeManager", 28usize, |this| ((**(this as *const *const ISearchCatalogManager_Vtbl)).GetCrawlScopeManager)(this, ppcrawlscopemanager), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            GetParameter,
+            SetParameter,
+            GetCatalogStatus,
+            Reset,
+            Reindex,
+            ReindexMatchingURLs,
+            ReindexSearchRoot,
+            SetConnectTimeout,
+            ConnectTimeout,
+            SetDataTimeout,
+            DataTimeout,
+            NumberOfItems,
+            NumberOfItemsToIndex,
+            URLBeingIndexed,
+            GetURLIndexingState,
+            GetPersistentItemsChangedSink,
+            RegisterViewForNotification,
+            GetItemsChangedSink,
+            UnregisterViewForNotification,
+            SetExtensionClusion,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumerateExcludedExtensions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumerateExcludedExtensions: 0,
+            GetQueryHelper,
+            SetDiacriticSensitivity,
+            DiacriticSensitivity,
+            GetCrawlScopeManager,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchCatalogManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISearchCatalogManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PrioritizeMatchingURLs(this: *mut core::ffi::c_void, pszpattern: windows_core::PCWSTR, dwprioritizeflags: PRIORITIZE_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCatalogManager2", "PrioritizeMatchingURLs", 29usize, |this| ((**(this as *const *const ISearchCatalogManager2_Vtbl)).PrioritizeMatchingURLs)(this, pszpattern, dwprioritizeflags), |result| Some(*result))
+        }
+        Self { base__: <ISearchCatalogManager_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PrioritizeMatchingURLs }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchCatalogManager2 as windows_core::Interface>::IID || <ISearchCatalogManager_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISearchCrawlScopeManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddDefaultScopeRule(this: *mut core::ffi::c_void, pszurl: windows_core::PCWSTR, finclude: super::super::Foundation::BOOL, ffollowflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCrawlScopeManager", "AddDefaultScopeRule", 3usize, |this| ((**(this as *const *const ISearchCrawlScopeManager_Vtbl)).AddDefaultScopeRule)(this, pszurl, finclude, ffollowflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddRoot(this: *mut core::ffi::c_void, psearchroot: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCrawlScopeManager", "AddRoot", 4usize, |this| ((**(this as *const *const ISearchCrawlScopeManager_Vtbl)).AddRoot)(this, psearchroot), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRoot(this: *mut core::ffi::c_void, pszurl: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCrawlScopeManager", "RemoveRoot", 5usize, |this| ((**(this as *const *const ISearchCrawlScopeManager_Vtbl)).RemoveRoot)(this, pszurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateRoots(this: *mut core::ffi::c_void, ppsearchroots: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCrawlScopeManager", "EnumerateRoots", 6usize, |this| ((**(this as *const *const ISearchCrawlScopeManager_Vtbl)).EnumerateRoots)(this, ppsearchroots), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddHierarchicalScope(this: *mut core::ffi::c_void, pszurl: windows_core::PCWSTR, finclude: super::super::Foundation::BOOL, fdefault: super::super::Foundation::BOOL, foverridechildren: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCrawlScopeManager", "AddHierarchicalScope", 7usize, |this| ((**(this as *const *const ISearchCrawlScopeManager_Vtbl)).AddHierarchicalScope)(this, pszurl, finclude, fdefault, foverridechildren), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddUserScopeRule(this: *mut core::ffi::c_void, pszurl: windows_core::PCWSTR, finclude: super::super::Foundation::BOOL, foverridechildren: super::super::Foundation::BOOL, ffollowflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCrawlScopeManager", "AddUserScopeRule", 8usize, |this| ((**(this as *const *const ISearchCrawlScopeManager_Vtbl)).AddUserScopeRule)(this, pszurl, finclude, foverridechildren, ffollowflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveScopeRule(this: *mut core::ffi::c_void, pszrule: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCrawlScopeManager", "RemoveScopeRule", 9usize, |this| ((**(this as *const *const ISearchCrawlScopeManager_Vtbl)).RemoveScopeRule)(this, pszrule), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateScopeRules(this: *mut core::ffi::c_void, ppsearchscoperules: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCrawlScopeManager", "EnumerateScopeRules", 10usize, |this| ((**(this as *const *const ISearchCrawlScopeManager_Vtbl)).EnumerateScopeRules)(this, ppsearchscoperules), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasParentScopeRule(this: *mut core::ffi::c_void, pszurl: windows_core::PCWSTR, pfhasparentrule: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCrawlScopeManager", "HasParentScopeRule", 11usize, |this| ((**(this as *const *const ISearchCrawlScopeManager_Vtbl)).HasParentScopeRule)(this, pszurl, pfhasparentrule), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasChildScopeRule(this: *mut core::ffi::c_void, pszurl: windows_core::PCWSTR, pfhaschildrule: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCrawlScopeManager", "HasChildScopeRule", 12usize, |this| ((**(this as *const *const ISearchCrawlScopeManager_Vtbl)).HasChildScopeRule)(this, pszurl, pfhaschildrule), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncludedInCrawlScope(this: *mut core::ffi::c_void, pszurl: windows_core::PCWSTR, pfisincluded: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCrawlScopeManager", "IncludedInCrawlScope", 13usize, |this| ((**(this as *const *const ISearchCrawlScopeManager_Vtbl)).IncludedInCrawlScope)(this, pszurl, pfisincluded), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncludedInCrawlScopeEx(this: *mut core::ffi::c_void, pszurl: windows_core::PCWSTR, pfisincluded: *mut super::super::Foundation::BOOL, preason: *mut CLUSION_REASON) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCrawlScopeManager", "IncludedInCrawlScopeEx", 14usize, |this| ((**(this as *const *const ISearchCrawlScopeManager_Vtbl)).IncludedInCrawlScopeEx)(this, pszurl, pfisincluded, preason), |result| Some(*result))
+        }
+        unsafe extern "system" fn RevertToDefaultScopes(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCrawlScopeManager", "RevertToDefaultScopes", 15usize, |this| ((**(this as *const *const ISearchCrawlScopeManager_Vtbl)).RevertToDefaultScopes)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCrawlScopeManager", "SaveAll", 16usize, |this| ((**(this as *const *const ISearchCrawlScopeManager_Vtbl)).SaveAll)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParentScopeVersionId(this: *mut core::ffi::c_void, pszurl: windows_core::PCWSTR, plscopeid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCrawlScopeManager", "GetParentScopeVersionId", 17usize, |this| ((**(this as *const *const ISearchCrawlScopeManager_Vtbl)).GetParentScopeVersionId)(this, pszurl, plscopeid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveDefaultScopeRule(this: *mut core::ffi::c_void, pszurl: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCrawlScopeManager", "RemoveDefaultScopeRule", 18usize, |this| ((**(this as *const *const ISearchCrawlScopeManager_Vtbl)).RemoveDefaultScopeRule)(this, pszurl), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddDefaultScopeRule,
+            AddRoot,
+            RemoveRoot,
+            EnumerateRoots,
+            AddHierarchicalScope,
+            AddUserScopeRule,
+            RemoveScopeRule,
+            EnumerateScopeRules,
+            HasParentScopeRule,
+            HasChildScopeRule,
+            IncludedInCrawlScope,
+            IncludedInCrawlScopeEx,
+            RevertToDefaultScopes,
+            SaveAll,
+            GetParentScopeVersionId,
+            RemoveDefaultScopeRule,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchCrawlScopeManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISearchCrawlScopeManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetVersion(this: *mut core::ffi::c_void, plversion: *mut *mut i32, phfilemapping: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCrawlScopeManager2", "GetVersion", 19usize, |this| ((**(this as *const *const ISearchCrawlScopeManager2_Vtbl)).GetVersion)(this, plversion, phfilemapping), |result| Some(*result))
+        }
+        Self { base__: <ISearchCrawlScopeManager_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetVersion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchCrawlScopeManager2 as windows_core::Interface>::IID || <ISearchCrawlScopeManager_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISearchItemsChangedSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartedMonitoringScope(this: *mut core::ffi::c_void, pszurl: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchItemsChangedSink", "StartedMonitoringScope", 3usize, |this| ((**(this as *const *const ISearchItemsChangedSink_Vtbl)).StartedMonitoringScope)(this, pszurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn StoppedMonitoringScope(this: *mut core::ffi::c_void, pszurl: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchItemsChangedSink", "StoppedMonitoringScope", 4usize, |this| ((**(this as *const *const ISearchItemsChangedSink_Vtbl)).StoppedMonitoringScope)(this, pszurl), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnItemsChanged(this: *mut core::ffi::c_void, dwnumberofchanges: u32, rgdatachangeentries: *const SEARCH_ITEM_CHANGE, rgdwdocids: *mut u32, rghrcompletioncodes: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchItemsChangedSink", "OnItemsChanged", 5usize, |this| ((**(this as *const *const ISearchItemsChangedSink_Vtbl)).OnItemsChanged)(this, dwnumberofchanges, rgdatachangeentries, rgdwdocids, rghrcompletioncodes), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartedMonitoringScope,
+            StoppedMonitoringScope,
+            #[cfg(feature = "Win32_System_Com")]
+            OnItemsChanged,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnItemsChanged: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchItemsChangedSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISearchLanguageSupport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDiacriticSensitivity(this: *mut core::ffi::c_void, fdiacriticsensitive: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchLanguageSupport", "SetDiacriticSensitivity", 3usize, |this| ((**(this as *const *const ISearchLanguageSupport_Vtbl)).SetDiacriticSensitivity)(this, fdiacriticsensitive), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDiacriticSensitivity(this: *mut core::ffi::c_void, pfdiacriticsensitive: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchLanguageSupport", "GetDiacriticSensitivity", 4usize, |this| ((**(this as *const *const ISearchLanguageSupport_Vtbl)).GetDiacriticSensitivity)(this, pfdiacriticsensitive), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadWordBreaker(this: *mut core::ffi::c_void, lcid: u32, riid: *const windows_core::GUID, ppwordbreaker: *mut *mut core::ffi::c_void, plcidused: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchLanguageSupport", "LoadWordBreaker", 5usize, |this| ((**(this as *const *const ISearchLanguageSupport_Vtbl)).LoadWordBreaker)(this, lcid, riid, ppwordbreaker, plcidused), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadStemmer(this: *mut core::ffi::c_void, lcid: u32, riid: *const windows_core::GUID, ppstemmer: *mut *mut core::ffi::c_void, plcidused: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchLanguageSupport", "LoadStemmer", 6usize, |this| ((**(this as *const *const ISearchLanguageSupport_Vtbl)).LoadStemmer)(this, lcid, riid, ppstemmer, plcidused), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPrefixNormalized(this: *mut core::ffi::c_void, pwcsquerytoken: windows_core::PCWSTR, cwcquerytoken: u32, pwcsdocumenttoken: windows_core::PCWSTR, cwcdocumenttoken: u32, pulprefixlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchLanguageSupport", "IsPrefixNormalized", 7usize, |this| ((**(this as *const *const ISearchLanguageSupport_Vtbl)).IsPrefixNormalized)(this, pwcsquerytoken, cwcquerytoken, pwcsdocumenttoken, cwcdocumenttoken, pulprefixlength), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetDiacriticSensitivity,
+            GetDiacriticSensitivity,
+            LoadWordBreaker,
+            LoadStemmer,
+            IsPrefixNormalized,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchLanguageSupport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISearchManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetIndexerVersionStr(this: *mut core::ffi::c_void, ppszversionstring: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchManager", "GetIndexerVersionStr", 3usize, |this| ((**(this as *const *const ISearchManager_Vtbl)).GetIndexerVersionStr)(this, ppszversionstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIndexerVersion(this: *mut core::ffi::c_void, pdwmajor: *mut u32, pdwminor: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchManager", "GetIndexerVersion", 4usize, |this| ((**(this as *const *const ISearchManager_Vtbl)).GetIndexerVersion)(this, pdwmajor, pdwminor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParameter(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, ppvalue: *mut *mut windows_core::PROPVARIANT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchManager", "GetParameter", 5usize, |this| ((**(this as *const *const ISearchManager_Vtbl)).GetParameter)(this, pszname, ppvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParameter(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, pvalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchManager", "SetParameter", 6usize, |this| ((**(this as *const *const ISearchManager_Vtbl)).SetParameter)(this, pszname, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProxyName(this: *mut core::ffi::c_void, ppszproxyname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchManager", "ProxyName", 7usize, |this| ((**(this as *const *const ISearchManager_Vtbl)).ProxyName)(this, ppszproxyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn BypassList(this: *mut core::ffi::c_void, ppszbypasslist: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchManager", "BypassList", 8usize, |this| ((**(this as *const *const ISearchManager_Vtbl)).BypassList)(this, ppszbypasslist), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProxy(this: *mut core::ffi::c_void, suseproxy: PROXY_ACCESS, flocalbypassproxy: super::super::Foundation::BOOL, dwportnumber: u32, pszproxyname: windows_core::PCWSTR, pszbypasslist: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchManager", "SetProxy", 9usize, |this| ((**(this as *const *const ISearchManager_Vtbl)).SetProxy)(this, suseproxy, flocalbypassproxy, dwportnumber, pszproxyname, pszbypasslist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCatalog(this: *mut core::ffi::c_void, pszcatalog: windows_core::PCWSTR, ppcatalogmanager: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchManager", "GetCatalog", 10usize, |this| ((**(this as *const *const ISearchManager_Vtbl)).GetCatalog)(this, pszcatalog, ppcatalogmanager), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserAgent(this: *mut core::ffi::c_void, ppszuseragent: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchManager", "UserAgent", 11usize, |this| ((**(this as *const *const ISearchManager_Vtbl)).UserAgent)(this, ppszuseragent), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUserAgent(this: *mut core::ffi::c_void, pszuseragent: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchManager", "SetUserAgent", 12usize, |this| ((**(this as *const *const ISearchManager_Vtbl)).SetUserAgent)(this, pszuseragent), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseProxy(this: *mut core::ffi::c_void, puseproxy: *mut PROXY_ACCESS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchManager", "UseProxy", 13usize, |this| ((**(this as *const *const ISearchManager_Vtbl)).UseProxy)(this, puseproxy), |result| Some(*result))
+        }
+        unsafe extern "system" fn LocalBypass(this: *mut core::ffi::c_void, pflocalbypass: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchManager", "LocalBypass", 14usize, |this| ((**(this as *const *const ISearchManager_Vtbl)).LocalBypass)(this, pflocalbypass), |result| Some(*result))
+        }
+        unsafe extern "system" fn PortNumber(this: *mut core::ffi::c_void, pdwportnumber: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchManager", "PortNumber", 15usize, |this| ((**(this as *const *const ISearchManager_Vtbl)).PortNumber)(this, pdwportnumber), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetIndexerVersionStr,
+            GetIndexerVersion,
+            GetParameter,
+            SetParameter,
+            ProxyName,
+            BypassList,
+            SetProxy,
+            GetCatalog,
+            UserAgent,
+            SetUserAgent,
+            UseProxy,
+            LocalBypass,
+            PortNumber,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISearchManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateCatalog(this: *mut core::ffi::c_void, pszcatalog: windows_core::PCWSTR, ppcatalogmanager: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchManager2", "CreateCatalog", 16usize, |this| ((**(this as *const *const ISearchManager2_Vtbl)).CreateCatalog)(this, pszcatalog, ppcatalogmanager), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteCatalog(this: *mut core::ffi::c_void, pszcatalog: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchManager2", "DeleteCatalog", 17usize, |this| ((**(this as *const *const ISearchManager2_Vtbl)).DeleteCatalog)(this, pszcatalog), |result| Some(*result))
+        }
+        Self { base__: <ISearchManager_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateCatalog, DeleteCatalog }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchManager2 as windows_core::Interface>::IID || <ISearchManager_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISearchNotifyInlineSite_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnItemIndexedStatusChange(this: *mut core::ffi::c_void, sipstatus: SEARCH_INDEXING_PHASE, dwnumentries: u32, rgitemstatusentries: *const SEARCH_ITEM_INDEXING_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchNotifyInlineSite", "OnItemIndexedStatusChange", 3usize, |this| ((**(this as *const *const ISearchNotifyInlineSite_Vtbl)).OnItemIndexedStatusChange)(this, sipstatus, dwnumentries, rgitemstatusentries), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnCatalogStatusChange(this: *mut core::ffi::c_void, guidcatalogresetsignature: *const windows_core::GUID, guidcheckpointsignature: *const windows_core::GUID, dwlastcheckpointnumber: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchNotifyInlineSite", "OnCatalogStatusChange", 4usize, |this| ((**(this as *const *const ISearchNotifyInlineSite_Vtbl)).OnCatalogStatusChange)(this, guidcatalogresetsignature, guidcheckpointsignature, dwlastcheckpointnumber), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnItemIndexedStatusChange, OnCatalogStatusChange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchNotifyInlineSite as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISearchPersistentItemsChangedSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartedMonitoringScope(this: *mut core::ffi::c_void, pszurl: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPersistentItemsChangedSink", "StartedMonitoringScope", 3usize, |this| ((**(this as *const *const ISearchPersistentItemsChangedSink_Vtbl)).StartedMonitoringScope)(this, pszurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn StoppedMonitoringScope(this: *mut core::ffi::c_void, pszurl: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPersistentItemsChangedSink", "StoppedMonitoringScope", 4usize, |this| ((**(this as *const *const ISearchPersistentItemsChangedSink_Vtbl)).StoppedMonitoringScope)(this, pszurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnItemsChanged(this: *mut core::ffi::c_void, dwnumberofchanges: u32, datachangeentries: *const SEARCH_ITEM_PERSISTENT_CHANGE, hrcompletioncodes: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchPersistentItemsChangedSink", "OnItemsChanged", 5usize, |this| ((**(this as *const *const ISearchPersistentItemsChangedSink_Vtbl)).OnItemsChanged)(this, dwnumberofchanges, datachangeentries, hrcompletioncodes), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartedMonitoringScope, StoppedMonitoringScope, OnItemsChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchPersistentItemsChangedSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISearchProtocol_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Init(this: *mut core::ffi::c_void, ptimeoutinfo: *const TIMEOUT_INFO, pprotocolhandlersite: *mut core::ffi::c_void, pproxyinfo: *const PROXY_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchProtocol", "Init", 3usize, |this| ((**(this as *const *const ISearchProtocol_Vtbl)).Init)(this, ptimeoutinfo, pprotocolhandlersite, pproxyinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAccessor(this: *mut core::ffi::c_void, pcwszurl: windows_core::PCWSTR, pauthenticationinfo: *const AUTHENTICATION_INFO, pincrementalaccessinfo: *const INCREMENTAL_ACCESS_INFO, piteminfo: *const ITEM_INFO, ppaccessor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchProtocol", "CreateAccessor", 4usize, |this| ((**(this as *const *const ISearchProtocol_Vtbl)).CreateAccessor)(this, pcwszurl, pauthenticationinfo, pincrementalaccessinfo, piteminfo, ppaccessor), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseAccessor(this: *mut core::ffi::c_void, paccessor: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchProtocol", "CloseAccessor", 5usize, |this| ((**(this as *const *const ISearchProtocol_Vtbl)).CloseAccessor)(this, paccessor), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShutDown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchProtocol", "ShutDown", 6usize, |this| ((**(this as *const *const ISearchProtocol_Vtbl)).ShutDown)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Init, CreateAccessor, CloseAccessor, ShutDown }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchProtocol as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISearchProtocol2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateAccessorEx(this: *mut core::ffi::c_void, pcwszurl: windows_core::PCWSTR, pauthenticationinfo: *const AUTHENTICATION_INFO, pincrementalaccessinfo: *const INCREMENTAL_ACCESS_INFO, piteminfo: *const ITEM_INFO, puserdata: *const super::Com::BLOB, ppaccessor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchProtocol2", "CreateAccessorEx", 7usize, |this| ((**(this as *const *const ISearchProtocol2_Vtbl)).CreateAccessorEx)(this, pcwszurl, pauthenticationinfo, pincrementalaccessinfo, piteminfo, puserdata, ppaccessor), |result| Some(*result))
+        }
+        Self {
+            base__: <ISearchProtocol_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateAccessorEx,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateAccessorEx: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchProtocol2 as windows_core::Interface>::IID || <ISearchProtocol_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISearchProtocolThreadContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ThreadInit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchProtocolThreadContext", "ThreadInit", 3usize, |this| ((**(this as *const *const ISearchProtocolThreadContext_Vtbl)).ThreadInit)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ThreadShutdown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchProtocolThreadContext", "ThreadShutdown", 4usize, |this| ((**(this as *const *const ISearchProtocolThreadContext_Vtbl)).ThreadShutdown)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ThreadIdle(this: *mut core::ffi::c_void, dwtimeelaspedsincelastcallinms: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchProtocolThreadContext", "ThreadIdle", 5usize, |this| ((**(this as *const *const ISearchProtocolThreadContext_Vtbl)).ThreadIdle)(this, dwtimeelaspedsincelastcallinms), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ThreadInit, ThreadShutdown, ThreadIdle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchProtocolThreadContext as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for ISearchQueryHelper_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConnectionString(this: *mut core::ffi::c_void, pszconnectionstring: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchQueryHelper", "ConnectionString", 3usize, |this| ((**(this as *const *const ISearchQueryHelper_Vtbl)).ConnectionString)(this, pszconnectionstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQueryContentLocale(this: *mut core::ffi::c_void, lcid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchQueryHelper", "SetQueryContentLocale", 4usize, |this| ((**(this as *const *const ISearchQueryHelper_Vtbl)).SetQueryContentLocale)(this, lcid), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryContentLocale(this: *mut core::ffi::c_void, plcid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchQueryHelper", "QueryContentLocale", 5usize, |this| ((**(this as *const *const ISearchQueryHelper_Vtbl)).QueryContentLocale)(this, plcid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQueryKeywordLocale(this: *mut core::ffi::c_void, lcid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchQueryHelper", "SetQueryKeywordLocale", 6usize, |this| ((**(this as *const *const ISearchQueryHelper_Vtbl)).SetQueryKeywordLocale)(this, lcid), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryKeywordLocale(this: *mut core::ffi::c_void, plcid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchQueryHelper", "QueryKeywordLocale", 7usize, |this| ((**(this as *const *const ISearchQueryHelper_Vtbl)).QueryKeywordLocale)(this, plcid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQueryTermExpansion(this: *mut core::ffi::c_void, expandterms: SEARCH_TERM_EXPANSION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchQueryHelper", "SetQueryTermExpansion", 8usize, |this| ((**(this as *const *const ISearchQueryHelper_Vtbl)).SetQueryTermExpansion)(this, expandterms), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryTermExpansion(this: *mut core::ffi::c_void, pexpandterms: *mut SEARCH_TERM_EXPANSION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchQueryHelper", "QueryTermExpansion", 9usize, |this| ((**(this as *const *const ISearchQueryHelper_Vtbl)).QueryTermExpansion)(this, pexpandterms), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQuerySyntax(this: *mut core::ffi::c_void, querysyntax: SEARCH_QUERY_SYNTAX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchQueryHelper", "SetQuerySyntax", 10usize, |this| ((**(this as *const *const ISearchQueryHelper_Vtbl)).SetQuerySyntax)(this, querysyntax), |result| Some(*result))
+        }
+        unsafe extern "system" fn QuerySyntax(this: *mut core::ffi::c_void, pquerysyntax: *mut SEARCH_QUERY_SYNTAX) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchQueryHelper", "QuerySyntax", 11usize, |this| ((**(this as *const *const ISearchQueryHelper_Vtbl)).QuerySyntax)(this, pquerysyntax), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQueryContentProperties(this: *mut core::ffi::c_void, pszcontentproperties: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchQueryHelper", "SetQueryContentProperties", 12usize, |this| ((**(this as *const *const ISearchQueryHelper_Vtbl)).SetQueryContentProperties)(this, pszcontentproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryContentProperties(this: *mut core::ffi::c_void, ppszcontentproperties: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchQueryHelper", "QueryContentProperties", 13usize, |this| ((**(this as *const *const ISearchQueryHelper_Vtbl)).QueryContentProperties)(this, ppszcontentproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQuerySelectColumns(this: *mut core::ffi::c_void, pszselectcolumns: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchQueryHelper", "SetQuerySelectColumns", 14usize, |this| ((**(this as *const *const ISearchQueryHelper_Vtbl)).SetQuerySelectColumns)(this, pszselectcolumns), |result| Some(*result))
+        }
+        unsafe extern "system" fn QuerySelectColumns(this: *mut core::ffi::c_void, ppszselectcolumns: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchQueryHelper", "QuerySelectColumns", 15usize, |this| ((**(this as *const *const ISearchQueryHelper_Vtbl)).QuerySelectColumns)(this, ppszselectcolumns), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQueryWhereRestrictions(this: *mut core::ffi::c_void, pszrestrictions: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchQueryHelper", "SetQueryWhereRestrictions", 16usize, |this| ((**(this as *const *const ISearchQueryHelper_Vtbl)).SetQueryWhereRestrictions)(this, pszrestrictions), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryWhereRestrictions(this: *mut core::ffi::c_void, ppszrestrictions: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchQueryHelper", "QueryWhereRestrictions", 17usize, |this| ((**(this as *const *const ISearchQueryHelper_Vtbl)).QueryWhereRestrictions)(this, ppszrestrictions), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQuerySorting(this: *mut core::ffi::c_void, pszsorting: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchQueryHelper", "SetQuerySorting", 18usize, |this| ((**(this as *const *const ISearchQueryHelper_Vtbl)).SetQuerySorting)(this, pszsorting), |result| Some(*result))
+        }
+        unsafe extern "system" fn QuerySorting(this: *mut core::ffi::c_void, ppszsorting: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchQueryHelper", "QuerySorting", 19usize, |this| ((**(this as *const *const ISearchQueryHelper_Vtbl)).QuerySorting)(this, ppszsorting), |result| Some(*result))
+        }
+        unsafe extern "system" fn GenerateSQLFromUserQuery(this: *mut core::ffi::c_void, pszquery: windows_core::PCWSTR, ppszsql: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchQueryHelper", "GenerateSQLFromUserQuery", 20usize, |this| ((**(this as *const *const ISearchQueryHelper_Vtbl)).GenerateSQLFromUserQuery)(this, pszquery, ppszsql), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn WriteProperties(this: *mut core::ffi::c_void, itemid: i32, dwnumberofcolumns: u32, pcolumns: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pvalues: *const SEARCH_COLUMN_PROPERTIES, pftgathermodifiedtime: *const super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchQueryHelper", "WriteProperties", 21usize, |this| ((**(this as *const *const ISearchQueryHelper_Vtbl)).WriteProperties)(this, itemid, dwnumberofcolumns, pcolumns, pvalues, pftgathermodifiedtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetQueryMaxResults(this: *mut core::ffi::c_void, cmaxresults: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchQueryHelper", "SetQueryMaxResults", 22usize, |this| ((**(this as *const *const ISearchQueryHelper_Vtbl)).SetQueryMaxResults)(this, cmaxresults), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryMaxResults(this: *mut core::ffi::c_void, pcmaxresults: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchQueryHelper", "QueryMaxResults", 23usize, |this| ((**(this as *const *const ISearchQueryHelper_Vtbl)).QueryMaxResults)(this, pcmaxresults), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ConnectionString,
+            SetQueryContentLocale,
+            QueryContentLocale,
+            SetQueryKeywordLocale,
+            QueryKeywordLocale,
+            SetQueryTermExpansion,
+            QueryTermExpansion,
+            SetQuerySyntax,
+            QuerySyntax,
+            SetQueryContentProperties,
+            QueryContentProperties,
+            SetQuerySelectColumns,
+            QuerySelectColumns,
+            SetQueryWhereRestrictions,
+            QueryWhereRestrictions,
+            SetQuerySorting,
+            QuerySorting,
+            GenerateSQLFromUserQuery,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            WriteProperties,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            WriteProperties: 0,
+            SetQueryMaxResults,
+            QueryMaxResults,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchQueryHelper as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ISearchQueryHits_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn Init(this: *mut core::ffi::c_void, pflt: *mut core::ffi::c_void, ulflags: u32) -> i32 {
+            windows_core::imp::proxy_call(this, "ISearchQueryHits", "Init", 3usize, |this| ((**(this as *const *const ISearchQueryHits_Vtbl)).Init)(this, pflt, ulflags), |_| None)
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn NextHitMoniker(this: *mut core::ffi::c_void, pcmnk: *mut u32, papmnk: *mut *mut Option<super::Com::IMoniker>) -> i32 {
+            windows_core::imp::proxy_call(this, "ISearchQueryHits", "NextHitMoniker", 4usize, |this| ((**(this as *const *const ISearchQueryHits_Vtbl)).NextHitMoniker)(this, pcmnk, papmnk), |_| None)
+        }
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn NextHitOffset(this: *mut core::ffi::c_void, pcregion: *mut u32, paregion: *mut *mut super::super::Storage::IndexServer::FILTERREGION) -> i32 {
+            windows_core::imp::proxy_call(this, "ISearchQueryHits", "NextHitOffset", 5usize, |this| ((**(this as *const *const ISearchQueryHits_Vtbl)).NextHitOffset)(this, pcregion, paregion), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            Init,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            Init: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            NextHitMoniker,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            NextHitMoniker: 0,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            NextHitOffset,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            NextHitOffset: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchQueryHits as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISearchRoot_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSchedule(this: *mut core::ffi::c_void, psztaskarg: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchRoot", "SetSchedule", 3usize, |this| ((**(this as *const *const ISearchRoot_Vtbl)).SetSchedule)(this, psztaskarg), |result| Some(*result))
+        }
+        unsafe extern "system" fn Schedule(this: *mut core::ffi::c_void, ppsztaskarg: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchRoot", "Schedule", 4usize, |this| ((**(this as *const *const ISearchRoot_Vtbl)).Schedule)(this, ppsztaskarg), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRootURL(this: *mut core::ffi::c_void, pszurl: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchRoot", "SetRootURL", 5usize, |this| ((**(this as *const *const ISearchRoot_Vtbl)).SetRootURL)(this, pszurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn RootURL(this: *mut core::ffi::c_void, ppszurl: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchRoot", "RootURL", 6usize, |this| ((**(this as *const *const ISearchRoot_Vtbl)).RootURL)(this, ppszurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsHierarchical(this: *mut core::ffi::c_void, fishierarchical: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchRoot", "SetIsHierarchical", 7usize, |this| ((**(this as *const *const ISearchRoot_Vtbl)).SetIsHierarchical)(this, fishierarchical), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsHierarchical(this: *mut core::ffi::c_void, pfishierarchical: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchRoot", "IsHierarchical", 8usize, |this| ((**(this as *const *const ISearchRoot_Vtbl)).IsHierarchical)(this, pfishierarchical), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProvidesNotifications(this: *mut core::ffi::c_void, fprovidesnotifications: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchRoot", "SetProvidesNotifications", 9usize, |this| ((**(this as *const *const ISearchRoot_Vtbl)).SetProvidesNotifications)(this, fprovidesnotifications), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProvidesNotifications(this: *mut core::ffi::c_void, pfprovidesnotifications: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchRoot", "ProvidesNotifications", 10usize, |this| ((**(this as *const *const ISearchRoot_Vtbl)).ProvidesNotifications)(this, pfprovidesnotifications), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUseNotificationsOnly(this: *mut core::ffi::c_void, fusenotificationsonly: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchRoot", "SetUseNotificationsOnly", 11usize, |this| ((**(this as *const *const ISearchRoot_Vtbl)).SetUseNotificationsOnly)(this, fusenotificationsonly), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseNotificationsOnly(this: *mut core::ffi::c_void, pfusenotificationsonly: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchRoot", "UseNotificationsOnly", 12usize, |this| ((**(this as *const *const ISearchRoot_Vtbl)).UseNotificationsOnly)(this, pfusenotificationsonly), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnumerationDepth(this: *mut core::ffi::c_void, dwdepth: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchRoot", "SetEnumerationDepth", 13usize, |this| ((**(this as *const *const ISearchRoot_Vtbl)).SetEnumerationDepth)(this, dwdepth), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerationDepth(this: *mut core::ffi::c_void, pdwdepth: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchRoot", "EnumerationDepth", 14usize, |this| ((**(this as *const *const ISearchRoot_Vtbl)).EnumerationDepth)(this, pdwdepth), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHostDepth(this: *mut core::ffi::c_void, dwdepth: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchRoot", "SetHostDepth", 15usize, |this| ((**(this as *const *const ISearchRoot_Vtbl)).SetHostDepth)(this, dwdepth), |result| Some(*result))
+        }
+        unsafe extern "system" fn HostDepth(this: *mut core::ffi::c_void, pdwdepth: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchRoot", "HostDepth", 16usize, |this| ((**(this as *const *const ISearchRoot_Vtbl)).HostDepth)(this, pdwdepth), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFollowDirectories(this: *mut core::ffi::c_void, ffollowdirectories: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchRoot", "SetFollowDirectories", 17usize, |this| ((**(this as *const *const ISearchRoot_Vtbl)).SetFollowDirectories)(this, ffollowdirectories), |result| Some(*result))
+        }
+        unsafe extern "system" fn FollowDirectories(this: *mut core::ffi::c_void, pffollowdirectories: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchRoot", "FollowDirectories", 18usize, |this| ((**(this as *const *const ISearchRoot_Vtbl)).FollowDirectories)(this, pffollowdirectories), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthenticationType(this: *mut core::ffi::c_void, authtype: AUTH_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchRoot", "SetAuthenticationType", 19usize, |this| ((**(this as *const *const ISearchRoot_Vtbl)).SetAuthenticationType)(this, authtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthenticationType(this: *mut core::ffi::c_void, pauthtype: *mut AUTH_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchRoot", "AuthenticationType", 20usize, |this| ((**(this as *const *const ISearchRoot_Vtbl)).AuthenticationType)(this, pauthtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUser(this: *mut core::ffi::c_void, pszuser: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchRoot", "SetUser", 21usize, |this| ((**(this as *const *const ISearchRoot_Vtbl)).SetUser)(this, pszuser), |result| Some(*result))
+        }
+        unsafe extern "system" fn User(this: *mut core::ffi::c_void, ppszuser: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchRoot", "User", 22usize, |this| ((**(this as *const *const ISearchRoot_Vtbl)).User)(this, ppszuser), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPassword(this: *mut core::ffi::c_void, pszpassword: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchRoot", "SetPassword", 23usize, |this| ((**(this as *const *const ISearchRoot_Vtbl)).SetPassword)(this, pszpassword), |result| Some(*result))
+        }
+        unsafe extern "system" fn Password(this: *mut core::ffi::c_void, ppszpassword: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchRoot", "Password", 24usize, |this| ((**(this as *const *const ISearchRoot_Vtbl)).Password)(this, ppszpassword), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetSchedule,
+            Schedule,
+            SetRootURL,
+            RootURL,
+            SetIsHierarchical,
+            IsHierarchical,
+            SetProvidesNotifications,
+            ProvidesNotifications,
+            SetUseNotificationsOnly,
+            UseNotificationsOnly,
+            SetEnumerationDepth,
+            EnumerationDepth,
+            SetHostDepth,
+            HostDepth,
+            SetFollowDirectories,
+            FollowDirectories,
+            SetAuthenticationType,
+            AuthenticationType,
+            SetUser,
+            User,
+            SetPassword,
+            Password,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchRoot as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISearchScopeRule_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PatternOrURL(this: *mut core::ffi::c_void, ppszpatternorurl: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchScopeRule", "PatternOrURL", 3usize, |this| ((**(this as *const *const ISearchScopeRule_Vtbl)).PatternOrURL)(this, ppszpatternorurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsIncluded(this: *mut core::ffi::c_void, pfisincluded: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchScopeRule", "IsIncluded", 4usize, |this| ((**(this as *const *const ISearchScopeRule_Vtbl)).IsIncluded)(this, pfisincluded), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDefault(this: *mut core::ffi::c_void, pfisdefault: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchScopeRule", "IsDefault", 5usize, |this| ((**(this as *const *const ISearchScopeRule_Vtbl)).IsDefault)(this, pfisdefault), |result| Some(*result))
+        }
+        unsafe extern "system" fn FollowFlags(this: *mut core::ffi::c_void, pfollowflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchScopeRule", "FollowFlags", 6usize, |this| ((**(this as *const *const ISearchScopeRule_Vtbl)).FollowFlags)(this, pfollowflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PatternOrURL, IsIncluded, IsDefault, FollowFlags }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchScopeRule as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISearchViewChangedSink_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OnChange(this: *mut core::ffi::c_void, pdwdocid: *const i32, pchange: *const SEARCH_ITEM_CHANGE, pfinview: *const super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchViewChangedSink", "OnChange", 3usize, |this| ((**(this as *const *const ISearchViewChangedSink_Vtbl)).OnChange)(this, pdwdocid, pchange, pfinview), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            OnChange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OnChange: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchViewChangedSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Security_Authorization")]
+impl windows_core::imp::ProxyVtable for ISecurityInfo_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Security_Authorization")]
+        unsafe extern "system" fn GetCurrentTrustee(this: *mut core::ffi::c_void, pptrustee: *mut *mut super::super::Security::Authorization::TRUSTEE_W) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityInfo", "GetCurrentTrustee", 3usize, |this| ((**(this as *const *const ISecurityInfo_Vtbl)).GetCurrentTrustee)(this, pptrustee), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObjectTypes(this: *mut core::ffi::c_void, cobjecttypes: *mut u32, rgobjecttypes: *mut *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityInfo", "GetObjectTypes", 4usize, |this| ((**(this as *const *const ISecurityInfo_Vtbl)).GetObjectTypes)(this, cobjecttypes, rgobjecttypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPermissions(this: *mut core::ffi::c_void, objecttype: windows_core::GUID, ppermissions: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISecurityInfo", "GetPermissions", 5usize, |this| ((**(this as *const *const ISecurityInfo_Vtbl)).GetPermissions)(this, objecttype, ppermissions), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Security_Authorization")]
+            GetCurrentTrustee,
+            #[cfg(not(feature = "Win32_Security_Authorization"))]
+            GetCurrentTrustee: 0,
+            GetObjectTypes,
+            GetPermissions,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISecurityInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InvokeService(this: *mut core::ffi::c_void, punkinner: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IService", "InvokeService", 3usize, |this| ((**(this as *const *const IService_Vtbl)).InvokeService)(this, punkinner), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InvokeService }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IService as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_IndexServer")]
+impl windows_core::imp::ProxyVtable for ISessionProperties_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, cpropertyidsets: u32, rgpropertyidsets: *const DBPROPIDSET, pcpropertysets: *mut u32, prgpropertysets: *mut *mut DBPROPSET) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISessionProperties", "GetProperties", 3usize, |this| ((**(this as *const *const ISessionProperties_Vtbl)).GetProperties)(this, cpropertyidsets, rgpropertyidsets, pcpropertysets, prgpropertysets), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn SetProperties(this: *mut core::ffi::c_void, cpropertysets: u32, rgpropertysets: *mut DBPROPSET) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISessionProperties", "SetProperties", 4usize, |this| ((**(this as *const *const ISessionProperties_Vtbl)).SetProperties)(this, cpropertysets, rgpropertysets), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            GetProperties,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            GetProperties: 0,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            SetProperties,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            SetProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISessionProperties as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISimpleCommandCreator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateICommand(this: *mut core::ffi::c_void, ppiunknown: *mut *mut core::ffi::c_void, pouterunk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleCommandCreator", "CreateICommand", 3usize, |this| ((**(this as *const *const ISimpleCommandCreator_Vtbl)).CreateICommand)(this, ppiunknown, pouterunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn VerifyCatalog(this: *mut core::ffi::c_void, pwszmachine: windows_core::PCWSTR, pwszcatalogname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleCommandCreator", "VerifyCatalog", 4usize, |this| ((**(this as *const *const ISimpleCommandCreator_Vtbl)).VerifyCatalog)(this, pwszmachine, pwszcatalogname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultCatalog(this: *mut core::ffi::c_void, pwszcatalogname: windows_core::PCWSTR, cwcin: u32, pcwcout: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISimpleCommandCreator", "GetDefaultCatalog", 5usize, |this| ((**(this as *const *const ISimpleCommandCreator_Vtbl)).GetDefaultCatalog)(this, pwszcatalogname, cwcin, pcwcout), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateICommand, VerifyCatalog, GetDefaultCatalog }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISimpleCommandCreator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_IndexServer")]
+impl windows_core::imp::ProxyVtable for ISourcesRowset_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn GetSourcesRowset(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, riid: *const windows_core::GUID, cpropertysets: u32, rgproperties: *mut DBPROPSET, ppsourcesrowset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISourcesRowset", "GetSourcesRowset", 3usize, |this| ((**(this as *const *const ISourcesRowset_Vtbl)).GetSourcesRowset)(this, punkouter, riid, cpropertysets, rgproperties, ppsourcesrowset), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            GetSourcesRowset,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            GetSourcesRowset: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISourcesRowset as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStemmer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Init(this: *mut core::ffi::c_void, ulmaxtokensize: u32, pflicense: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStemmer", "Init", 3usize, |this| ((**(this as *const *const IStemmer_Vtbl)).Init)(this, ulmaxtokensize, pflicense), |result| Some(*result))
+        }
+        unsafe extern "system" fn GenerateWordForms(this: *mut core::ffi::c_void, pwcinbuf: windows_core::PCWSTR, cwc: u32, pstemsink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStemmer", "GenerateWordForms", 4usize, |this| ((**(this as *const *const IStemmer_Vtbl)).GenerateWordForms)(this, pwcinbuf, cwc, pstemsink), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLicenseToUse(this: *mut core::ffi::c_void, ppwcslicense: *const *const u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStemmer", "GetLicenseToUse", 5usize, |this| ((**(this as *const *const IStemmer_Vtbl)).GetLicenseToUse)(this, ppwcslicense), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Init, GenerateWordForms, GetLicenseToUse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStemmer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISubscriptionItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCookie(this: *mut core::ffi::c_void, pcookie: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubscriptionItem", "GetCookie", 3usize, |this| ((**(this as *const *const ISubscriptionItem_Vtbl)).GetCookie)(this, pcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubscriptionItemInfo(this: *mut core::ffi::c_void, psubscriptioniteminfo: *mut SUBSCRIPTIONITEMINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubscriptionItem", "GetSubscriptionItemInfo", 4usize, |this| ((**(this as *const *const ISubscriptionItem_Vtbl)).GetSubscriptionItemInfo)(this, psubscriptioniteminfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubscriptionItemInfo(this: *mut core::ffi::c_void, psubscriptioniteminfo: *const SUBSCRIPTIONITEMINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubscriptionItem", "SetSubscriptionItemInfo", 5usize, |this| ((**(this as *const *const ISubscriptionItem_Vtbl)).SetSubscriptionItemInfo)(this, psubscriptioniteminfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadProperties(this: *mut core::ffi::c_void, ncount: u32, rgwszname: *const windows_core::PCWSTR, rgvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubscriptionItem", "ReadProperties", 6usize, |this| ((**(this as *const *const ISubscriptionItem_Vtbl)).ReadProperties)(this, ncount, rgwszname, rgvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteProperties(this: *mut core::ffi::c_void, ncount: u32, rgwszname: *const windows_core::PCWSTR, rgvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubscriptionItem", "WriteProperties", 7usize, |this| ((**(this as *const *const ISubscriptionItem_Vtbl)).WriteProperties)(this, ncount, rgwszname, rgvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumProperties(this: *mut core::ffi::c_void, ppenumitemproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubscriptionItem", "EnumProperties", 8usize, |this| ((**(this as *const *const ISubscriptionItem_Vtbl)).EnumProperties)(this, ppenumitemproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyChanged(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubscriptionItem", "NotifyChanged", 9usize, |this| ((**(this as *const *const ISubscriptionItem_Vtbl)).NotifyChanged)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCookie,
+            GetSubscriptionItemInfo,
+            SetSubscriptionItemInfo,
+            ReadProperties,
+            WriteProperties,
+            EnumProperties,
+            NotifyChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISubscriptionItem as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISubscriptionMgr_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeleteSubscription(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, hwnd: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubscriptionMgr", "DeleteSubscription", 3usize, |this| ((**(this as *const *const ISubscriptionMgr_Vtbl)).DeleteSubscription)(this, pwszurl, hwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateSubscription(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubscriptionMgr", "UpdateSubscription", 4usize, |this| ((**(this as *const *const ISubscriptionMgr_Vtbl)).UpdateSubscription)(this, pwszurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubscriptionMgr", "UpdateAll", 5usize, |this| ((**(this as *const *const ISubscriptionMgr_Vtbl)).UpdateAll)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSubscribed(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, pfsubscribed: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubscriptionMgr", "IsSubscribed", 6usize, |this| ((**(this as *const *const ISubscriptionMgr_Vtbl)).IsSubscribed)(this, pwszurl, pfsubscribed), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubscriptionInfo(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, pinfo: *mut SUBSCRIPTIONINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubscriptionMgr", "GetSubscriptionInfo", 7usize, |this| ((**(this as *const *const ISubscriptionMgr_Vtbl)).GetSubscriptionInfo)(this, pwszurl, pinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultInfo(this: *mut core::ffi::c_void, subtype: SUBSCRIPTIONTYPE, pinfo: *mut SUBSCRIPTIONINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubscriptionMgr", "GetDefaultInfo", 8usize, |this| ((**(this as *const *const ISubscriptionMgr_Vtbl)).GetDefaultInfo)(this, subtype, pinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowSubscriptionProperties(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, hwnd: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubscriptionMgr", "ShowSubscriptionProperties", 9usize, |this| ((**(this as *const *const ISubscriptionMgr_Vtbl)).ShowSubscriptionProperties)(this, pwszurl, hwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSubscription(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, pwszurl: windows_core::PCWSTR, pwszfriendlyname: windows_core::PCWSTR, dwflags: u32, substype: SUBSCRIPTIONTYPE, pinfo: *mut SUBSCRIPTIONINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubscriptionMgr", "CreateSubscription", 10usize, |this| ((**(this as *const *const ISubscriptionMgr_Vtbl)).CreateSubscription)(this, hwnd, pwszurl, pwszfriendlyname, dwflags, substype, pinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DeleteSubscription,
+            UpdateSubscription,
+            UpdateAll,
+            IsSubscribed,
+            GetSubscriptionInfo,
+            GetDefaultInfo,
+            ShowSubscriptionProperties,
+            CreateSubscription,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISubscriptionMgr as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISubscriptionMgr2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetItemFromURL(this: *mut core::ffi::c_void, pwszurl: windows_core::PCWSTR, ppsubscriptionitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubscriptionMgr2", "GetItemFromURL", 11usize, |this| ((**(this as *const *const ISubscriptionMgr2_Vtbl)).GetItemFromURL)(this, pwszurl, ppsubscriptionitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItemFromCookie(this: *mut core::ffi::c_void, psubscriptioncookie: *const windows_core::GUID, ppsubscriptionitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubscriptionMgr2", "GetItemFromCookie", 12usize, |this| ((**(this as *const *const ISubscriptionMgr2_Vtbl)).GetItemFromCookie)(this, psubscriptioncookie, ppsubscriptionitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubscriptionRunState(this: *mut core::ffi::c_void, dwnumcookies: u32, pcookies: *const windows_core::GUID, pdwrunstate: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubscriptionMgr2", "GetSubscriptionRunState", 13usize, |this| ((**(this as *const *const ISubscriptionMgr2_Vtbl)).GetSubscriptionRunState)(this, dwnumcookies, pcookies, pdwrunstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumSubscriptions(this: *mut core::ffi::c_void, dwflags: u32, ppenumsubscriptions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubscriptionMgr2", "EnumSubscriptions", 14usize, |this| ((**(this as *const *const ISubscriptionMgr2_Vtbl)).EnumSubscriptions)(this, dwflags, ppenumsubscriptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateItems(this: *mut core::ffi::c_void, dwflags: u32, dwnumcookies: u32, pcookies: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubscriptionMgr2", "UpdateItems", 15usize, |this| ((**(this as *const *const ISubscriptionMgr2_Vtbl)).UpdateItems)(this, dwflags, dwnumcookies, pcookies), |result| Some(*result))
+        }
+        unsafe extern "system" fn AbortItems(this: *mut core::ffi::c_void, dwnumcookies: u32, pcookies: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubscriptionMgr2", "AbortItems", 16usize, |this| ((**(this as *const *const ISubscriptionMgr2_Vtbl)).AbortItems)(this, dwnumcookies, pcookies), |result| Some(*result))
+        }
+        unsafe extern "system" fn AbortAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISubscriptionMgr2", "AbortAll", 17usize, |this| ((**(this as *const *const ISubscriptionMgr2_Vtbl)).AbortAll)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <ISubscriptionMgr_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetItemFromURL,
+            GetItemFromCookie,
+            GetSubscriptionRunState,
+            EnumSubscriptions,
+            UpdateItems,
+            AbortItems,
+            AbortAll,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISubscriptionMgr2 as windows_core::Interface>::IID || <ISubscriptionMgr_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ITableCreation_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn GetTableDefinition(this: *mut core::ffi::c_void, ptableid: *const super::super::Storage::IndexServer::DBID, pccolumndescs: *mut usize, prgcolumndescs: *mut *mut DBCOLUMNDESC, pcpropertysets: *mut u32, prgpropertysets: *mut *mut DBPROPSET, pcconstraintdescs: *mut u32, prgconstraintdescs: *mut *mut DBCONSTRAINTDESC, ppwszstringbuffer: *mut *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITableCreation", "GetTableDefinition", 7usize, |this| ((**(this as *const *const ITableCreation_Vtbl)).GetTableDefinition)(this, ptableid, pccolumndescs, prgcolumndescs, pcpropertysets, prgpropertysets, pcconstraintdescs, prgconstraintdescs, ppwszstringbuffer), |result| Some(*result))
+        }
+        Self {
+            base__: <ITableDefinition_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com"))]
+            GetTableDefinition,
+            #[cfg(not(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com")))]
+            GetTableDefinition: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITableCreation as windows_core::Interface>::IID || <ITableDefinition_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ITableDefinition_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn CreateTable(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, ptableid: *const super::super::Storage::IndexServer::DBID, ccolumndescs: usize, rgcolumndescs: *const DBCOLUMNDESC, riid: *const windows_core::GUID, cpropertysets: u32, rgpropertysets: *mut DBPROPSET, pptableid: *mut *mut super::super::Storage::IndexServer::DBID, pprowset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITableDefinition", "CreateTable", 3usize, |this| ((**(this as *const *const ITableDefinition_Vtbl)).CreateTable)(this, punkouter, ptableid, ccolumndescs, rgcolumndescs, riid, cpropertysets, rgpropertysets, pptableid, pprowset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn DropTable(this: *mut core::ffi::c_void, ptableid: *const super::super::Storage::IndexServer::DBID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITableDefinition", "DropTable", 4usize, |this| ((**(this as *const *const ITableDefinition_Vtbl)).DropTable)(this, ptableid), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn AddColumn(this: *mut core::ffi::c_void, ptableid: *const super::super::Storage::IndexServer::DBID, pcolumndesc: *const DBCOLUMNDESC, ppcolumnid: *mut *mut super::super::Storage::IndexServer::DBID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITableDefinition", "AddColumn", 5usize, |this| ((**(this as *const *const ITableDefinition_Vtbl)).AddColumn)(this, ptableid, pcolumndesc, ppcolumnid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn DropColumn(this: *mut core::ffi::c_void, ptableid: *const super::super::Storage::IndexServer::DBID, pcolumnid: *const super::super::Storage::IndexServer::DBID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITableDefinition", "DropColumn", 6usize, |this| ((**(this as *const *const ITableDefinition_Vtbl)).DropColumn)(this, ptableid, pcolumnid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com"))]
+            CreateTable,
+            #[cfg(not(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com")))]
+            CreateTable: 0,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            DropTable,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            DropTable: 0,
+            #[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com"))]
+            AddColumn,
+            #[cfg(not(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com")))]
+            AddColumn: 0,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            DropColumn,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            DropColumn: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITableDefinition as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for ITableDefinitionWithConstraints_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn AddConstraint(this: *mut core::ffi::c_void, ptableid: *const super::super::Storage::IndexServer::DBID, pconstraintdesc: *const DBCONSTRAINTDESC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITableDefinitionWithConstraints", "AddConstraint", 8usize, |this| ((**(this as *const *const ITableDefinitionWithConstraints_Vtbl)).AddConstraint)(this, ptableid, pconstraintdesc), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn CreateTableWithConstraints(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, ptableid: *const super::super::Storage::IndexServer::DBID, ccolumndescs: usize, rgcolumndescs: *mut DBCOLUMNDESC, cconstraintdescs: u32, rgconstraintdescs: *const DBCONSTRAINTDESC, riid: *const windows_core::GUID, cpropertysets: u32, rgpropertysets: *mut DBPROPSET, pptableid: *mut *mut super::super::Storage::IndexServer::DBID, pprowset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITableDefinitionWithConstraints", "CreateTableWithConstraints", 9usize, |this| ((**(this as *const *const ITableDefinitionWithConstraints_Vtbl)).CreateTableWithConstraints)(this, punkouter, ptableid, ccolumndescs, rgcolumndescs, cconstraintdescs, rgconstraintdescs, riid, cpropertysets, rgpropertysets, pptableid, pprowset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn DropConstraint(this: *mut core::ffi::c_void, ptableid: *const super::super::Storage::IndexServer::DBID, pconstraintid: *const super::super::Storage::IndexServer::DBID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITableDefinitionWithConstraints", "DropConstraint", 10usize, |this| ((**(this as *const *const ITableDefinitionWithConstraints_Vtbl)).DropConstraint)(this, ptableid, pconstraintid), |result| Some(*result))
+        }
+        Self {
+            base__: <ITableCreation_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            AddConstraint,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            AddConstraint: 0,
+            #[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com"))]
+            CreateTableWithConstraints,
+            #[cfg(not(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com")))]
+            CreateTableWithConstraints: 0,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            DropConstraint,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            DropConstraint: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITableDefinitionWithConstraints as windows_core::Interface>::IID || <ITableCreation_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_IndexServer")]
+impl windows_core::imp::ProxyVtable for ITableRename_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn RenameColumn(this: *mut core::ffi::c_void, ptableid: *const super::super::Storage::IndexServer::DBID, poldcolumnid: *const super::super::Storage::IndexServer::DBID, pnewcolumnid: *const super::super::Storage::IndexServer::DBID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITableRename", "RenameColumn", 3usize, |this| ((**(this as *const *const ITableRename_Vtbl)).RenameColumn)(this, ptableid, poldcolumnid, pnewcolumnid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn RenameTable(this: *mut core::ffi::c_void, poldtableid: *const super::super::Storage::IndexServer::DBID, poldindexid: *const super::super::Storage::IndexServer::DBID, pnewtableid: *const super::super::Storage::IndexServer::DBID, pnewindexid: *const super::super::Storage::IndexServer::DBID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITableRename", "RenameTable", 4usize, |this| ((**(this as *const *const ITableRename_Vtbl)).RenameTable)(this, poldtableid, poldindexid, pnewtableid, pnewindexid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            RenameColumn,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            RenameColumn: 0,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            RenameTable,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            RenameTable: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITableRename as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITokenCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NumberOfTokens(this: *mut core::ffi::c_void, pcount: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITokenCollection", "NumberOfTokens", 3usize, |this| ((**(this as *const *const ITokenCollection_Vtbl)).NumberOfTokens)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetToken(this: *mut core::ffi::c_void, i: u32, pbegin: *mut u32, plength: *mut u32, ppsz: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITokenCollection", "GetToken", 4usize, |this| ((**(this as *const *const ITokenCollection_Vtbl)).GetToken)(this, i, pbegin, plength, ppsz), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NumberOfTokens, GetToken }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITokenCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_DistributedTransactionCoordinator")]
+impl windows_core::imp::ProxyVtable for ITransactionJoin_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_DistributedTransactionCoordinator")]
+        unsafe extern "system" fn GetOptionsObject(this: *mut core::ffi::c_void, ppoptions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionJoin", "GetOptionsObject", 3usize, |this| ((**(this as *const *const ITransactionJoin_Vtbl)).GetOptionsObject)(this, ppoptions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_DistributedTransactionCoordinator")]
+        unsafe extern "system" fn JoinTransaction(this: *mut core::ffi::c_void, punktransactioncoord: *mut core::ffi::c_void, isolevel: i32, isoflags: u32, potheroptions: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionJoin", "JoinTransaction", 4usize, |this| ((**(this as *const *const ITransactionJoin_Vtbl)).JoinTransaction)(this, punktransactioncoord, isolevel, isoflags, potheroptions), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_DistributedTransactionCoordinator")]
+            GetOptionsObject,
+            #[cfg(not(feature = "Win32_System_DistributedTransactionCoordinator"))]
+            GetOptionsObject: 0,
+            #[cfg(feature = "Win32_System_DistributedTransactionCoordinator")]
+            JoinTransaction,
+            #[cfg(not(feature = "Win32_System_DistributedTransactionCoordinator"))]
+            JoinTransaction: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionJoin as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_DistributedTransactionCoordinator")]
+impl windows_core::imp::ProxyVtable for ITransactionLocal_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_DistributedTransactionCoordinator")]
+        unsafe extern "system" fn GetOptionsObject(this: *mut core::ffi::c_void, ppoptions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionLocal", "GetOptionsObject", 6usize, |this| ((**(this as *const *const ITransactionLocal_Vtbl)).GetOptionsObject)(this, ppoptions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_DistributedTransactionCoordinator")]
+        unsafe extern "system" fn StartTransaction(this: *mut core::ffi::c_void, isolevel: i32, isoflags: u32, potheroptions: *mut core::ffi::c_void, pultransactionlevel: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionLocal", "StartTransaction", 7usize, |this| ((**(this as *const *const ITransactionLocal_Vtbl)).StartTransaction)(this, isolevel, isoflags, potheroptions, pultransactionlevel), |result| Some(*result))
+        }
+        Self {
+            base__: <super::DistributedTransactionCoordinator::ITransaction_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_DistributedTransactionCoordinator")]
+            GetOptionsObject,
+            #[cfg(not(feature = "Win32_System_DistributedTransactionCoordinator"))]
+            GetOptionsObject: 0,
+            #[cfg(feature = "Win32_System_DistributedTransactionCoordinator")]
+            StartTransaction,
+            #[cfg(not(feature = "Win32_System_DistributedTransactionCoordinator"))]
+            StartTransaction: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionLocal as windows_core::Interface>::IID || <super::DistributedTransactionCoordinator::ITransaction_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_DistributedTransactionCoordinator")]
+impl windows_core::imp::ProxyVtable for ITransactionObject_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_DistributedTransactionCoordinator")]
+        unsafe extern "system" fn GetTransactionObject(this: *mut core::ffi::c_void, ultransactionlevel: u32, pptransactionobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransactionObject", "GetTransactionObject", 3usize, |this| ((**(this as *const *const ITransactionObject_Vtbl)).GetTransactionObject)(this, ultransactionlevel, pptransactionobject), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_DistributedTransactionCoordinator")]
+            GetTransactionObject,
+            #[cfg(not(feature = "Win32_System_DistributedTransactionCoordinator"))]
+            GetTransactionObject: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransactionObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Security_Authorization", feature = "Win32_Storage_IndexServer"))]
+impl windows_core::imp::ProxyVtable for ITrusteeAdmin_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Security_Authorization")]
+        unsafe extern "system" fn CompareTrustees(this: *mut core::ffi::c_void, ptrustee1: *const super::super::Security::Authorization::TRUSTEE_W, ptrustee2: *const super::super::Security::Authorization::TRUSTEE_W) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITrusteeAdmin", "CompareTrustees", 3usize, |this| ((**(this as *const *const ITrusteeAdmin_Vtbl)).CompareTrustees)(this, ptrustee1, ptrustee2), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Security_Authorization", feature = "Win32_Storage_IndexServer"))]
+        unsafe extern "system" fn CreateTrustee(this: *mut core::ffi::c_void, ptrustee: *const super::super::Security::Authorization::TRUSTEE_W, cpropertysets: u32, rgpropertysets: *mut DBPROPSET) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITrusteeAdmin", "CreateTrustee", 4usize, |this| ((**(this as *const *const ITrusteeAdmin_Vtbl)).CreateTrustee)(this, ptrustee, cpropertysets, rgpropertysets), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Security_Authorization")]
+        unsafe extern "system" fn DeleteTrustee(this: *mut core::ffi::c_void, ptrustee: *const super::super::Security::Authorization::TRUSTEE_W) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITrusteeAdmin", "DeleteTrustee", 5usize, |this| ((**(this as *const *const ITrusteeAdmin_Vtbl)).DeleteTrustee)(this, ptrustee), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Security_Authorization", feature = "Win32_Storage_IndexServer"))]
+        unsafe extern "system" fn SetTrusteeProperties(this: *mut core::ffi::c_void, ptrustee: *const super::super::Security::Authorization::TRUSTEE_W, cpropertysets: u32, rgpropertysets: *mut DBPROPSET) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITrusteeAdmin", "SetTrusteeProperties", 6usize, |this| ((**(this as *const *const ITrusteeAdmin_Vtbl)).SetTrusteeProperties)(this, ptrustee, cpropertysets, rgpropertysets), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Security_Authorization", feature = "Win32_Storage_IndexServer"))]
+        unsafe extern "system" fn GetTrusteeProperties(this: *mut core::ffi::c_void, ptrustee: *const super::super::Security::Authorization::TRUSTEE_W, cpropertyidsets: u32, rgpropertyidsets: *const DBPROPIDSET, pcpropertysets: *mut u32, prgpropertysets: *mut *mut DBPROPSET) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITrusteeAdmin", "GetTrusteeProperties", 7usize, |this| ((**(this as *const *const ITrusteeAdmin_Vtbl)).GetTrusteeProperties)(this, ptrustee, cpropertyidsets, rgpropertyidsets, pcpropertysets, prgpropertysets), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Security_Authorization")]
+            CompareTrustees,
+            #[cfg(not(feature = "Win32_Security_Authorization"))]
+            CompareTrustees: 0,
+            #[cfg(all(feature = "Win32_Security_Authorization", feature = "Win32_Storage_IndexServer"))]
+            CreateTrustee,
+            #[cfg(not(all(feature = "Win32_Security_Authorization", feature = "Win32_Storage_IndexServer")))]
+            CreateTrustee: 0,
+            #[cfg(feature = "Win32_Security_Authorization")]
+            DeleteTrustee,
+            #[cfg(not(feature = "Win32_Security_Authorization"))]
+            DeleteTrustee: 0,
+            #[cfg(all(feature = "Win32_Security_Authorization", feature = "Win32_Storage_IndexServer"))]
+            SetTrusteeProperties,
+            #[cfg(not(all(feature = "Win32_Security_Authorization", feature = "Win32_Storage_IndexServer")))]
+            SetTrusteeProperties: 0,
+            #[cfg(all(feature = "Win32_Security_Authorization", feature = "Win32_Storage_IndexServer"))]
+            GetTrusteeProperties,
+            #[cfg(not(all(feature = "Win32_Security_Authorization", feature = "Win32_Storage_IndexServer")))]
+            GetTrusteeProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITrusteeAdmin as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Security_Authorization")]
+impl windows_core::imp::ProxyVtable for ITrusteeGroupAdmin_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Security_Authorization")]
+        unsafe extern "system" fn AddMember(this: *mut core::ffi::c_void, pmembershiptrustee: *const super::super::Security::Authorization::TRUSTEE_W, pmembertrustee: *const super::super::Security::Authorization::TRUSTEE_W) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITrusteeGroupAdmin", "AddMember", 3usize, |this| ((**(this as *const *const ITrusteeGroupAdmin_Vtbl)).AddMember)(this, pmembershiptrustee, pmembertrustee), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Security_Authorization")]
+        unsafe extern "system" fn DeleteMember(this: *mut core::ffi::c_void, pmembershiptrustee: *const super::super::Security::Authorization::TRUSTEE_W, pmembertrustee: *const super::super::Security::Authorization::TRUSTEE_W) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITrusteeGroupAdmin", "DeleteMember", 4usize, |this| ((**(this as *const *const ITrusteeGroupAdmin_Vtbl)).DeleteMember)(this, pmembershiptrustee, pmembertrustee), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Security_Authorization")]
+        unsafe extern "system" fn IsMember(this: *mut core::ffi::c_void, pmembershiptrustee: *const super::super::Security::Authorization::TRUSTEE_W, pmembertrustee: *const super::super::Security::Authorization::TRUSTEE_W, pfstatus: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITrusteeGroupAdmin", "IsMember", 5usize, |this| ((**(this as *const *const ITrusteeGroupAdmin_Vtbl)).IsMember)(this, pmembershiptrustee, pmembertrustee, pfstatus), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Security_Authorization")]
+        unsafe extern "system" fn GetMembers(this: *mut core::ffi::c_void, pmembershiptrustee: *const super::super::Security::Authorization::TRUSTEE_W, pcmembers: *mut u32, prgmembers: *mut *mut super::super::Security::Authorization::TRUSTEE_W) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITrusteeGroupAdmin", "GetMembers", 6usize, |this| ((**(this as *const *const ITrusteeGroupAdmin_Vtbl)).GetMembers)(this, pmembershiptrustee, pcmembers, prgmembers), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Security_Authorization")]
+        unsafe extern "system" fn GetMemberships(this: *mut core::ffi::c_void, ptrustee: *const super::super::Security::Authorization::TRUSTEE_W, pcmemberships: *mut u32, prgmemberships: *mut *mut super::super::Security::Authorization::TRUSTEE_W) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITrusteeGroupAdmin", "GetMemberships", 7usize, |this| ((**(this as *const *const ITrusteeGroupAdmin_Vtbl)).GetMemberships)(this, ptrustee, pcmemberships, prgmemberships), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Security_Authorization")]
+            AddMember,
+            #[cfg(not(feature = "Win32_Security_Authorization"))]
+            AddMember: 0,
+            #[cfg(feature = "Win32_Security_Authorization")]
+            DeleteMember,
+            #[cfg(not(feature = "Win32_Security_Authorization"))]
+            DeleteMember: 0,
+            #[cfg(feature = "Win32_Security_Authorization")]
+            IsMember,
+            #[cfg(not(feature = "Win32_Security_Authorization"))]
+            IsMember: 0,
+            #[cfg(feature = "Win32_Security_Authorization")]
+            GetMembers,
+            #[cfg(not(feature = "Win32_Security_Authorization"))]
+            GetMembers: 0,
+            #[cfg(feature = "Win32_Security_Authorization")]
+            GetMemberships,
+            #[cfg(not(feature = "Win32_Security_Authorization"))]
+            GetMemberships: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITrusteeGroupAdmin as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUMSInitialize_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pums: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUMSInitialize", "Initialize", 3usize, |this| ((**(this as *const *const IUMSInitialize_Vtbl)).Initialize)(this, pums), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUMSInitialize as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com_StructuredStorage"))]
+impl windows_core::imp::ProxyVtable for IUrlAccessor_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn AddRequestParameter(this: *mut core::ffi::c_void, pspec: *const super::Com::StructuredStorage::PROPSPEC, pvar: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUrlAccessor", "AddRequestParameter", 3usize, |this| ((**(this as *const *const IUrlAccessor_Vtbl)).AddRequestParameter)(this, pspec, pvar), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDocFormat(this: *mut core::ffi::c_void, wszdocformat: windows_core::PWSTR, dwsize: u32, pdwlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUrlAccessor", "GetDocFormat", 4usize, |this| ((**(this as *const *const IUrlAccessor_Vtbl)).GetDocFormat)(this, wszdocformat, dwsize, pdwlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCLSID(this: *mut core::ffi::c_void, pclsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUrlAccessor", "GetCLSID", 5usize, |this| ((**(this as *const *const IUrlAccessor_Vtbl)).GetCLSID)(this, pclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHost(this: *mut core::ffi::c_void, wszhost: windows_core::PWSTR, dwsize: u32, pdwlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUrlAccessor", "GetHost", 6usize, |this| ((**(this as *const *const IUrlAccessor_Vtbl)).GetHost)(this, wszhost, dwsize, pdwlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDirectory(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUrlAccessor", "IsDirectory", 7usize, |this| ((**(this as *const *const IUrlAccessor_Vtbl)).IsDirectory)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSize(this: *mut core::ffi::c_void, pllsize: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUrlAccessor", "GetSize", 8usize, |this| ((**(this as *const *const IUrlAccessor_Vtbl)).GetSize)(this, pllsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastModified(this: *mut core::ffi::c_void, pftlastmodified: *mut super::super::Foundation::FILETIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUrlAccessor", "GetLastModified", 9usize, |this| ((**(this as *const *const IUrlAccessor_Vtbl)).GetLastModified)(this, pftlastmodified), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileName(this: *mut core::ffi::c_void, wszfilename: windows_core::PWSTR, dwsize: u32, pdwlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUrlAccessor", "GetFileName", 10usize, |this| ((**(this as *const *const IUrlAccessor_Vtbl)).GetFileName)(this, wszfilename, dwsize, pdwlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSecurityDescriptor(this: *mut core::ffi::c_void, psd: *mut u8, dwsize: u32, pdwlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUrlAccessor", "GetSecurityDescriptor", 11usize, |this| ((**(this as *const *const IUrlAccessor_Vtbl)).GetSecurityDescriptor)(this, psd, dwsize, pdwlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRedirectedURL(this: *mut core::ffi::c_void, wszredirectedurl: windows_core::PWSTR, dwsize: u32, pdwlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUrlAccessor", "GetRedirectedURL", 12usize, |this| ((**(this as *const *const IUrlAccessor_Vtbl)).GetRedirectedURL)(this, wszredirectedurl, dwsize, pdwlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSecurityProvider(this: *mut core::ffi::c_void, pspclsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUrlAccessor", "GetSecurityProvider", 13usize, |this| ((**(this as *const *const IUrlAccessor_Vtbl)).GetSecurityProvider)(this, pspclsid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn BindToStream(this: *mut core::ffi::c_void, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUrlAccessor", "BindToStream", 14usize, |this| ((**(this as *const *const IUrlAccessor_Vtbl)).BindToStream)(this, ppstream), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn BindToFilter(this: *mut core::ffi::c_void, ppfilter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUrlAccessor", "BindToFilter", 15usize, |this| ((**(this as *const *const IUrlAccessor_Vtbl)).BindToFilter)(this, ppfilter), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            AddRequestParameter,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            AddRequestParameter: 0,
+            GetDocFormat,
+            GetCLSID,
+            GetHost,
+            IsDirectory,
+            GetSize,
+            GetLastModified,
+            GetFileName,
+            GetSecurityDescriptor,
+            GetRedirectedURL,
+            GetSecurityProvider,
+            #[cfg(feature = "Win32_System_Com")]
+            BindToStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            BindToStream: 0,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            BindToFilter,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            BindToFilter: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUrlAccessor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com_StructuredStorage"))]
+impl windows_core::imp::ProxyVtable for IUrlAccessor2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDisplayUrl(this: *mut core::ffi::c_void, wszdocurl: windows_core::PWSTR, dwsize: u32, pdwlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUrlAccessor2", "GetDisplayUrl", 16usize, |this| ((**(this as *const *const IUrlAccessor2_Vtbl)).GetDisplayUrl)(this, wszdocurl, dwsize, pdwlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDocument(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUrlAccessor2", "IsDocument", 17usize, |this| ((**(this as *const *const IUrlAccessor2_Vtbl)).IsDocument)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCodePage(this: *mut core::ffi::c_void, wszcodepage: windows_core::PWSTR, dwsize: u32, pdwlength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUrlAccessor2", "GetCodePage", 18usize, |this| ((**(this as *const *const IUrlAccessor2_Vtbl)).GetCodePage)(this, wszcodepage, dwsize, pdwlength), |result| Some(*result))
+        }
+        Self { base__: <IUrlAccessor_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDisplayUrl, IsDocument, GetCodePage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUrlAccessor2 as windows_core::Interface>::IID || <IUrlAccessor_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com_StructuredStorage"))]
+impl windows_core::imp::ProxyVtable for IUrlAccessor3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetImpersonationSidBlobs(this: *mut core::ffi::c_void, pcwszurl: windows_core::PCWSTR, pcsidcount: *mut u32, ppsidblobs: *mut *mut super::Com::BLOB) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUrlAccessor3", "GetImpersonationSidBlobs", 19usize, |this| ((**(this as *const *const IUrlAccessor3_Vtbl)).GetImpersonationSidBlobs)(this, pcwszurl, pcsidcount, ppsidblobs), |result| Some(*result))
+        }
+        Self {
+            base__: <IUrlAccessor2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetImpersonationSidBlobs,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetImpersonationSidBlobs: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUrlAccessor3 as windows_core::Interface>::IID || <IUrlAccessor2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_IndexServer", feature = "Win32_System_Com_StructuredStorage", feature = "Win32_UI_Shell_PropertiesSystem"))]
+impl windows_core::imp::ProxyVtable for IUrlAccessor4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShouldIndexItemContent(this: *mut core::ffi::c_void, pfindexcontent: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUrlAccessor4", "ShouldIndexItemContent", 20usize, |this| ((**(this as *const *const IUrlAccessor4_Vtbl)).ShouldIndexItemContent)(this, pfindexcontent), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn ShouldIndexProperty(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, pfindexproperty: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUrlAccessor4", "ShouldIndexProperty", 21usize, |this| ((**(this as *const *const IUrlAccessor4_Vtbl)).ShouldIndexProperty)(this, key, pfindexproperty), |result| Some(*result))
+        }
+        Self {
+            base__: <IUrlAccessor3_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ShouldIndexItemContent,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            ShouldIndexProperty,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            ShouldIndexProperty: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUrlAccessor4 as windows_core::Interface>::IID || <IUrlAccessor3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IViewChapter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSpecification(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, pprowset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewChapter", "GetSpecification", 3usize, |this| ((**(this as *const *const IViewChapter_Vtbl)).GetSpecification)(this, riid, pprowset), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenViewChapter(this: *mut core::ffi::c_void, hsource: usize, phviewchapter: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewChapter", "OpenViewChapter", 4usize, |this| ((**(this as *const *const IViewChapter_Vtbl)).OpenViewChapter)(this, hsource, phviewchapter), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSpecification, OpenViewChapter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IViewChapter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IViewFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFilter(this: *mut core::ffi::c_void, haccessor: HACCESSOR, pcrows: *mut usize, pcompareops: *mut *mut u32, pcriteriadata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewFilter", "GetFilter", 3usize, |this| ((**(this as *const *const IViewFilter_Vtbl)).GetFilter)(this, haccessor, pcrows, pcompareops, pcriteriadata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFilterBindings(this: *mut core::ffi::c_void, pcbindings: *mut usize, prgbindings: *mut *mut DBBINDING) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewFilter", "GetFilterBindings", 4usize, |this| ((**(this as *const *const IViewFilter_Vtbl)).GetFilterBindings)(this, pcbindings, prgbindings), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFilter(this: *mut core::ffi::c_void, haccessor: HACCESSOR, crows: usize, compareops: *const u32, pcriteriadata: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewFilter", "SetFilter", 5usize, |this| ((**(this as *const *const IViewFilter_Vtbl)).SetFilter)(this, haccessor, crows, compareops, pcriteriadata), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetFilter,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFilterBindings,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFilterBindings: 0,
+            SetFilter,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IViewFilter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IViewRowset_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSpecification(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewRowset", "GetSpecification", 3usize, |this| ((**(this as *const *const IViewRowset_Vtbl)).GetSpecification)(this, riid, ppobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenViewRowset(this: *mut core::ffi::c_void, punkouter: *mut core::ffi::c_void, riid: *const windows_core::GUID, pprowset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewRowset", "OpenViewRowset", 4usize, |this| ((**(this as *const *const IViewRowset_Vtbl)).OpenViewRowset)(this, punkouter, riid, pprowset), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSpecification, OpenViewRowset }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IViewRowset as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IViewSort_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSortOrder(this: *mut core::ffi::c_void, pcvalues: *mut usize, prgcolumns: *mut *mut usize, prgorders: *mut *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewSort", "GetSortOrder", 3usize, |this| ((**(this as *const *const IViewSort_Vtbl)).GetSortOrder)(this, pcvalues, prgcolumns, prgorders), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSortOrder(this: *mut core::ffi::c_void, cvalues: usize, rgcolumns: *const usize, rgorders: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewSort", "SetSortOrder", 4usize, |this| ((**(this as *const *const IViewSort_Vtbl)).SetSortOrder)(this, cvalues, rgcolumns, rgorders), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSortOrder, SetSortOrder }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IViewSort as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_IndexServer")]
+impl windows_core::imp::ProxyVtable for IWordBreaker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Init(this: *mut core::ffi::c_void, fquery: super::super::Foundation::BOOL, ulmaxtokensize: u32, pflicense: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWordBreaker", "Init", 3usize, |this| ((**(this as *const *const IWordBreaker_Vtbl)).Init)(this, fquery, ulmaxtokensize, pflicense), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn BreakText(this: *mut core::ffi::c_void, ptextsource: *mut TEXT_SOURCE, pwordsink: *mut core::ffi::c_void, pphrasesink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWordBreaker", "BreakText", 4usize, |this| ((**(this as *const *const IWordBreaker_Vtbl)).BreakText)(this, ptextsource, pwordsink, pphrasesink), |result| Some(*result))
+        }
+        unsafe extern "system" fn ComposePhrase(this: *mut core::ffi::c_void, pwcnoun: windows_core::PCWSTR, cwcnoun: u32, pwcmodifier: windows_core::PCWSTR, cwcmodifier: u32, ulattachmenttype: u32, pwcphrase: windows_core::PCWSTR, pcwcphrase: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWordBreaker", "ComposePhrase", 5usize, |this| ((**(this as *const *const IWordBreaker_Vtbl)).ComposePhrase)(this, pwcnoun, cwcnoun, pwcmodifier, cwcmodifier, ulattachmenttype, pwcphrase, pcwcphrase), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLicenseToUse(this: *mut core::ffi::c_void, ppwcslicense: *const *const u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWordBreaker", "GetLicenseToUse", 6usize, |this| ((**(this as *const *const IWordBreaker_Vtbl)).GetLicenseToUse)(this, ppwcslicense), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Init,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            BreakText,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            BreakText: 0,
+            ComposePhrase,
+            GetLicenseToUse,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWordBreaker as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWordFormSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PutAltWord(this: *mut core::ffi::c_void, pwcinbuf: windows_core::PCWSTR, cwc: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWordFormSink", "PutAltWord", 3usize, |this| ((**(this as *const *const IWordFormSink_Vtbl)).PutAltWord)(this, pwcinbuf, cwc), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutWord(this: *mut core::ffi::c_void, pwcinbuf: windows_core::PCWSTR, cwc: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWordFormSink", "PutWord", 4usize, |this| ((**(this as *const *const IWordFormSink_Vtbl)).PutWord)(this, pwcinbuf, cwc), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PutAltWord, PutWord }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWordFormSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_IndexServer")]
+impl windows_core::imp::ProxyVtable for IWordSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PutWord(this: *mut core::ffi::c_void, cwc: u32, pwcinbuf: windows_core::PCWSTR, cwcsrclen: u32, cwcsrcpos: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWordSink", "PutWord", 3usize, |this| ((**(this as *const *const IWordSink_Vtbl)).PutWord)(this, cwc, pwcinbuf, cwcsrclen, cwcsrcpos), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutAltWord(this: *mut core::ffi::c_void, cwc: u32, pwcinbuf: windows_core::PCWSTR, cwcsrclen: u32, cwcsrcpos: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWordSink", "PutAltWord", 4usize, |this| ((**(this as *const *const IWordSink_Vtbl)).PutAltWord)(this, cwc, pwcinbuf, cwcsrclen, cwcsrcpos), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartAltPhrase(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWordSink", "StartAltPhrase", 5usize, |this| ((**(this as *const *const IWordSink_Vtbl)).StartAltPhrase)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndAltPhrase(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWordSink", "EndAltPhrase", 6usize, |this| ((**(this as *const *const IWordSink_Vtbl)).EndAltPhrase)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_IndexServer")]
+        unsafe extern "system" fn PutBreak(this: *mut core::ffi::c_void, breaktype: super::super::Storage::IndexServer::WORDREP_BREAK_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWordSink", "PutBreak", 7usize, |this| ((**(this as *const *const IWordSink_Vtbl)).PutBreak)(this, breaktype), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            PutWord,
+            PutAltWord,
+            StartAltPhrase,
+            EndAltPhrase,
+            #[cfg(feature = "Win32_Storage_IndexServer")]
+            PutBreak,
+            #[cfg(not(feature = "Win32_Storage_IndexServer"))]
+            PutBreak: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWordSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for OLEDBSimpleProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn getRowCount(this: *mut core::ffi::c_void, pcrows: *mut isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "OLEDBSimpleProvider", "getRowCount", 3usize, |this| ((**(this as *const *const OLEDBSimpleProvider_Vtbl)).getRowCount)(this, pcrows), |result| Some(*result))
+        }
+        unsafe extern "system" fn getColumnCount(this: *mut core::ffi::c_void, pccolumns: *mut isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "OLEDBSimpleProvider", "getColumnCount", 4usize, |this| ((**(this as *const *const OLEDBSimpleProvider_Vtbl)).getColumnCount)(this, pccolumns), |result| Some(*result))
+        }
+        unsafe extern "system" fn getRWStatus(this: *mut core::ffi::c_void, irow: isize, icolumn: isize, prwstatus: *mut OSPRW) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "OLEDBSimpleProvider", "getRWStatus", 5usize, |this| ((**(this as *const *const OLEDBSimpleProvider_Vtbl)).getRWStatus)(this, irow, icolumn, prwstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn getVariant(this: *mut core::ffi::c_void, irow: isize, icolumn: isize, format: OSPFORMAT, pvar: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "OLEDBSimpleProvider", "getVariant", 6usize, |this| ((**(this as *const *const OLEDBSimpleProvider_Vtbl)).getVariant)(this, irow, icolumn, format, pvar), |result| Some(*result))
+        }
+        unsafe extern "system" fn setVariant(this: *mut core::ffi::c_void, irow: isize, icolumn: isize, format: OSPFORMAT, var: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "OLEDBSimpleProvider", "setVariant", 7usize, |this| ((**(this as *const *const OLEDBSimpleProvider_Vtbl)).setVariant)(this, irow, icolumn, format, var), |result| Some(*result))
+        }
+        unsafe extern "system" fn getLocale(this: *mut core::ffi::c_void, pbstrlocale: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "OLEDBSimpleProvider", "getLocale", 8usize, |this| ((**(this as *const *const OLEDBSimpleProvider_Vtbl)).getLocale)(this, pbstrlocale), |result| Some(*result))
+        }
+        unsafe extern "system" fn deleteRows(this: *mut core::ffi::c_void, irow: isize, crows: isize, pcrowsdeleted: *mut isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "OLEDBSimpleProvider", "deleteRows", 9usize, |this| ((**(this as *const *const OLEDBSimpleProvider_Vtbl)).deleteRows)(this, irow, crows, pcrowsdeleted), |result| Some(*result))
+        }
+        unsafe extern "system" fn insertRows(this: *mut core::ffi::c_void, irow: isize, crows: isize, pcrowsinserted: *mut isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "OLEDBSimpleProvider", "insertRows", 10usize, |this| ((**(this as *const *const OLEDBSimpleProvider_Vtbl)).insertRows)(this, irow, crows, pcrowsinserted), |result| Some(*result))
+        }
+        unsafe extern "system" fn find(this: *mut core::ffi::c_void, irowstart: isize, icolumn: isize, val: core::mem::MaybeUninit<windows_core::VARIANT>, findflags: OSPFIND, comptype: OSPCOMP, pirowfound: *mut isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "OLEDBSimpleProvider", "find", 11usize, |this| ((**(this as *const *const OLEDBSimpleProvider_Vtbl)).find)(this, irowstart, icolumn, val, findflags, comptype, pirowfound), |result| Some(*result))
+        }
+        unsafe extern "system" fn addOLEDBSimpleProviderListener(this: *mut core::ffi::c_void, pospilistener: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "OLEDBSimpleProvider", "addOLEDBSimpleProviderListener", 12usize, |this| ((**(this as *const *const OLEDBSimpleProvider_Vtbl)).addOLEDBSimpleProviderListener)(this, pospilistener), |result| Some(*result))
+        }
+        unsafe extern "system" fn removeOLEDBSimpleProviderListener(this: *mut core::ffi::c_void, pospilistener: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "OLEDBSimpleProvider", "removeOLEDBSimpleProviderListener", 13usize, |this| ((**(this as *const *const OLEDBSimpleProvider_Vtbl)).removeOLEDBSimpleProviderListener)(this, pospilistener), |result| Some(*result))
+        }
+        unsafe extern "system" fn isAsync(this: *mut core::ffi::c_void, pbasynch: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "OLEDBSimpleProvider", "isAsync", 14usize, |this| ((**(this as *const *const OLEDBSimpleProvider_Vtbl)).isAsync)(this, pbasynch), |result| Some(*result))
+        }
+        unsafe extern "system" fn getEstimatedRows(this: *mut core::ffi::c_void, pirows: *mut isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "OLEDBSimpleProvider", "getEstimatedRows", 15usize, |this| ((**(this as *const *const OLEDBSimpleProvider_Vtbl)).getEstimatedRows)(this, pirows), |result| Some(*result))
+        }
+        unsafe extern "system" fn stopTransfer(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "OLEDBSimpleProvider", "stopTransfer", 16usize, |this| ((**(this as *const *const OLEDBSimpleProvider_Vtbl)).stopTransfer)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            getRowCount,
+            getColumnCount,
+            getRWStatus,
+            getVariant,
+            setVariant,
+            getLocale,
+            deleteRows,
+            insertRows,
+            find,
+            addOLEDBSimpleProviderListener,
+            removeOLEDBSimpleProviderListener,
+            isAsync,
+            getEstimatedRows,
+            stopTransfer,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<OLEDBSimpleProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for OLEDBSimpleProviderListener_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn aboutToChangeCell(this: *mut core::ffi::c_void, irow: isize, icolumn: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "OLEDBSimpleProviderListener", "aboutToChangeCell", 3usize, |this| ((**(this as *const *const OLEDBSimpleProviderListener_Vtbl)).aboutToChangeCell)(this, irow, icolumn), |result| Some(*result))
+        }
+        unsafe extern "system" fn cellChanged(this: *mut core::ffi::c_void, irow: isize, icolumn: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "OLEDBSimpleProviderListener", "cellChanged", 4usize, |this| ((**(this as *const *const OLEDBSimpleProviderListener_Vtbl)).cellChanged)(this, irow, icolumn), |result| Some(*result))
+        }
+        unsafe extern "system" fn aboutToDeleteRows(this: *mut core::ffi::c_void, irow: isize, crows: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "OLEDBSimpleProviderListener", "aboutToDeleteRows", 5usize, |this| ((**(this as *const *const OLEDBSimpleProviderListener_Vtbl)).aboutToDeleteRows)(this, irow, crows), |result| Some(*result))
+        }
+        unsafe extern "system" fn deletedRows(this: *mut core::ffi::c_void, irow: isize, crows: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "OLEDBSimpleProviderListener", "deletedRows", 6usize, |this| ((**(this as *const *const OLEDBSimpleProviderListener_Vtbl)).deletedRows)(this, irow, crows), |result| Some(*result))
+        }
+        unsafe extern "system" fn aboutToInsertRows(this: *mut core::ffi::c_void, irow: isize, crows: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "OLEDBSimpleProviderListener", "aboutToInsertRows", 7usize, |this| ((**(this as *const *const OLEDBSimpleProviderListener_Vtbl)).aboutToInsertRows)(this, irow, crows), |result| Some(*result))
+        }
+        unsafe extern "system" fn insertedRows(this: *mut core::ffi::c_void, irow: isize, crows: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "OLEDBSimpleProviderListener", "insertedRows", 8usize, |this| ((**(this as *const *const OLEDBSimpleProviderListener_Vtbl)).insertedRows)(this, irow, crows), |result| Some(*result))
+        }
+        unsafe extern "system" fn rowsAvailable(this: *mut core::ffi::c_void, irow: isize, crows: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "OLEDBSimpleProviderListener", "rowsAvailable", 9usize, |this| ((**(this as *const *const OLEDBSimpleProviderListener_Vtbl)).rowsAvailable)(this, irow, crows), |result| Some(*result))
+        }
+        unsafe extern "system" fn transferComplete(this: *mut core::ffi::c_void, xfer: OSPXFER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "OLEDBSimpleProviderListener", "transferComplete", 10usize, |this| ((**(this as *const *const OLEDBSimpleProviderListener_Vtbl)).transferComplete)(this, xfer), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            aboutToChangeCell,
+            cellChanged,
+            aboutToDeleteRows,
+            deletedRows,
+            aboutToInsertRows,
+            insertedRows,
+            rowsAvailable,
+            transferComplete,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<OLEDBSimpleProviderListener as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
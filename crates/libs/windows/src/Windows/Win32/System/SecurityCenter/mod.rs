@@ -255,6 +255,7 @@ pub struct SECURITY_PRODUCT_TYPE(pub i32);
 impl windows_core::TypeKind for SECURITY_PRODUCT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SECURITY_PRODUCT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SECURITY_PRODUCT_TYPE").field(&self.0).finish()
@@ -266,6 +267,7 @@ pub struct WSC_SECURITY_PRODUCT_STATE(pub i32);
 impl windows_core::TypeKind for WSC_SECURITY_PRODUCT_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WSC_SECURITY_PRODUCT_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WSC_SECURITY_PRODUCT_STATE").field(&self.0).finish()
@@ -277,6 +279,7 @@ pub struct WSC_SECURITY_PRODUCT_SUBSTATUS(pub i32);
 impl windows_core::TypeKind for WSC_SECURITY_PRODUCT_SUBSTATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WSC_SECURITY_PRODUCT_SUBSTATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WSC_SECURITY_PRODUCT_SUBSTATUS").field(&self.0).finish()
@@ -288,6 +291,7 @@ pub struct WSC_SECURITY_PROVIDER(pub i32);
 impl windows_core::TypeKind for WSC_SECURITY_PROVIDER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WSC_SECURITY_PROVIDER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WSC_SECURITY_PROVIDER").field(&self.0).finish()
@@ -299,6 +303,7 @@ pub struct WSC_SECURITY_PROVIDER_HEALTH(pub i32);
 impl windows_core::TypeKind for WSC_SECURITY_PROVIDER_HEALTH {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WSC_SECURITY_PROVIDER_HEALTH {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WSC_SECURITY_PROVIDER_HEALTH").field(&self.0).finish()
@@ -310,6 +315,7 @@ pub struct WSC_SECURITY_SIGNATURE_STATUS(pub i32);
 impl windows_core::TypeKind for WSC_SECURITY_SIGNATURE_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WSC_SECURITY_SIGNATURE_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WSC_SECURITY_SIGNATURE_STATUS").field(&self.0).finish()
@@ -319,3 +325,5 @@ pub const WSCDefaultProduct: windows_core::GUID = windows_core::GUID::from_u128(
 pub const WSCProductList: windows_core::GUID = windows_core::GUID::from_u128(0x17072f7b_9abe_4a74_a261_1eb76b55107a);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
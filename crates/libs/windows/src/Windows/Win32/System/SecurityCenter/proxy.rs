@@ -0,0 +1,130 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWSCDefaultProduct_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDefaultProduct(this: *mut core::ffi::c_void, etype: SECURITY_PRODUCT_TYPE, pguid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSCDefaultProduct", "SetDefaultProduct", 7usize, |this| ((**(this as *const *const IWSCDefaultProduct_Vtbl)).SetDefaultProduct)(this, etype, pguid), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetDefaultProduct }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSCDefaultProduct as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWSCProductList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, provider: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSCProductList", "Initialize", 7usize, |this| ((**(this as *const *const IWSCProductList_Vtbl)).Initialize)(this, provider), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSCProductList", "Count", 8usize, |this| ((**(this as *const *const IWSCProductList_Vtbl)).Count)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: u32, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWSCProductList", "get_Item", 9usize, |this| ((**(this as *const *const IWSCProductList_Vtbl)).get_Item)(this, index, pval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWSCProductList as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWscProduct_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProductName(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWscProduct", "ProductName", 7usize, |this| ((**(this as *const *const IWscProduct_Vtbl)).ProductName)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProductState(this: *mut core::ffi::c_void, pval: *mut WSC_SECURITY_PRODUCT_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWscProduct", "ProductState", 8usize, |this| ((**(this as *const *const IWscProduct_Vtbl)).ProductState)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SignatureStatus(this: *mut core::ffi::c_void, pval: *mut WSC_SECURITY_SIGNATURE_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWscProduct", "SignatureStatus", 9usize, |this| ((**(this as *const *const IWscProduct_Vtbl)).SignatureStatus)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemediationPath(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWscProduct", "RemediationPath", 10usize, |this| ((**(this as *const *const IWscProduct_Vtbl)).RemediationPath)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProductStateTimestamp(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWscProduct", "ProductStateTimestamp", 11usize, |this| ((**(this as *const *const IWscProduct_Vtbl)).ProductStateTimestamp)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProductGuid(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWscProduct", "ProductGuid", 12usize, |this| ((**(this as *const *const IWscProduct_Vtbl)).ProductGuid)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProductIsDefault(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWscProduct", "ProductIsDefault", 13usize, |this| ((**(this as *const *const IWscProduct_Vtbl)).ProductIsDefault)(this, pval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProductName,
+            ProductState,
+            SignatureStatus,
+            RemediationPath,
+            ProductStateTimestamp,
+            ProductGuid,
+            ProductIsDefault,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWscProduct as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWscProduct2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AntivirusScanSubstatus(this: *mut core::ffi::c_void, pestatus: *mut WSC_SECURITY_PRODUCT_SUBSTATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWscProduct2", "AntivirusScanSubstatus", 14usize, |this| ((**(this as *const *const IWscProduct2_Vtbl)).AntivirusScanSubstatus)(this, pestatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn AntivirusSettingsSubstatus(this: *mut core::ffi::c_void, pestatus: *mut WSC_SECURITY_PRODUCT_SUBSTATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWscProduct2", "AntivirusSettingsSubstatus", 15usize, |this| ((**(this as *const *const IWscProduct2_Vtbl)).AntivirusSettingsSubstatus)(this, pestatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn AntivirusProtectionUpdateSubstatus(this: *mut core::ffi::c_void, pestatus: *mut WSC_SECURITY_PRODUCT_SUBSTATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWscProduct2", "AntivirusProtectionUpdateSubstatus", 16usize, |this| ((**(this as *const *const IWscProduct2_Vtbl)).AntivirusProtectionUpdateSubstatus)(this, pestatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn FirewallDomainProfileSubstatus(this: *mut core::ffi::c_void, pestatus: *mut WSC_SECURITY_PRODUCT_SUBSTATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWscProduct2", "FirewallDomainProfileSubstatus", 17usize, |this| ((**(this as *const *const IWscProduct2_Vtbl)).FirewallDomainProfileSubstatus)(this, pestatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn FirewallPrivateProfileSubstatus(this: *mut core::ffi::c_void, pestatus: *mut WSC_SECURITY_PRODUCT_SUBSTATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWscProduct2", "FirewallPrivateProfileSubstatus", 18usize, |this| ((**(this as *const *const IWscProduct2_Vtbl)).FirewallPrivateProfileSubstatus)(this, pestatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn FirewallPublicProfileSubstatus(this: *mut core::ffi::c_void, pestatus: *mut WSC_SECURITY_PRODUCT_SUBSTATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWscProduct2", "FirewallPublicProfileSubstatus", 19usize, |this| ((**(this as *const *const IWscProduct2_Vtbl)).FirewallPublicProfileSubstatus)(this, pestatus), |result| Some(*result))
+        }
+        Self {
+            base__: <IWscProduct_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AntivirusScanSubstatus,
+            AntivirusSettingsSubstatus,
+            AntivirusProtectionUpdateSubstatus,
+            FirewallDomainProfileSubstatus,
+            FirewallPrivateProfileSubstatus,
+            FirewallPublicProfileSubstatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWscProduct2 as windows_core::Interface>::IID || <IWscProduct_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWscProduct3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AntivirusDaysUntilExpired(this: *mut core::ffi::c_void, pdwdays: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWscProduct3", "AntivirusDaysUntilExpired", 20usize, |this| ((**(this as *const *const IWscProduct3_Vtbl)).AntivirusDaysUntilExpired)(this, pdwdays), |result| Some(*result))
+        }
+        Self { base__: <IWscProduct2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AntivirusDaysUntilExpired }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWscProduct3 as windows_core::Interface>::IID || <IWscProduct2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
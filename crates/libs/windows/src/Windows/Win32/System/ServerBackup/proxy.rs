@@ -0,0 +1,48 @@
+impl windows_core::imp::ProxyVtable for IWsbApplicationAsync_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryStatus(this: *mut core::ffi::c_void, phrresult: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWsbApplicationAsync", "QueryStatus", 3usize, |this| ((**(this as *const *const IWsbApplicationAsync_Vtbl)).QueryStatus)(this, phrresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn Abort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWsbApplicationAsync", "Abort", 4usize, |this| ((**(this as *const *const IWsbApplicationAsync_Vtbl)).Abort)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryStatus, Abort }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWsbApplicationAsync as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWsbApplicationBackupSupport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CheckConsistency(this: *mut core::ffi::c_void, wszwritermetadata: windows_core::PCWSTR, wszcomponentname: windows_core::PCWSTR, wszcomponentlogicalpath: windows_core::PCWSTR, cvolumes: u32, rgwszsourcevolumepath: *const windows_core::PCWSTR, rgwszsnapshotvolumepath: *const windows_core::PCWSTR, ppasync: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWsbApplicationBackupSupport", "CheckConsistency", 3usize, |this| ((**(this as *const *const IWsbApplicationBackupSupport_Vtbl)).CheckConsistency)(this, wszwritermetadata, wszcomponentname, wszcomponentlogicalpath, cvolumes, rgwszsourcevolumepath, rgwszsnapshotvolumepath, ppasync), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CheckConsistency }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWsbApplicationBackupSupport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWsbApplicationRestoreSupport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PreRestore(this: *mut core::ffi::c_void, wszwritermetadata: windows_core::PCWSTR, wszcomponentname: windows_core::PCWSTR, wszcomponentlogicalpath: windows_core::PCWSTR, bnorollforward: super::super::Foundation::BOOLEAN) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWsbApplicationRestoreSupport", "PreRestore", 3usize, |this| ((**(this as *const *const IWsbApplicationRestoreSupport_Vtbl)).PreRestore)(this, wszwritermetadata, wszcomponentname, wszcomponentlogicalpath, bnorollforward), |result| Some(*result))
+        }
+        unsafe extern "system" fn PostRestore(this: *mut core::ffi::c_void, wszwritermetadata: windows_core::PCWSTR, wszcomponentname: windows_core::PCWSTR, wszcomponentlogicalpath: windows_core::PCWSTR, bnorollforward: super::super::Foundation::BOOLEAN) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWsbApplicationRestoreSupport", "PostRestore", 4usize, |this| ((**(this as *const *const IWsbApplicationRestoreSupport_Vtbl)).PostRestore)(this, wszwritermetadata, wszcomponentname, wszcomponentlogicalpath, bnorollforward), |result| Some(*result))
+        }
+        unsafe extern "system" fn OrderComponents(this: *mut core::ffi::c_void, ccomponents: u32, rgcomponentname: *const windows_core::PCWSTR, rgcomponentlogicalpaths: *const windows_core::PCWSTR, prgcomponentname: *mut *mut windows_core::PWSTR, prgcomponentlogicalpath: *mut *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWsbApplicationRestoreSupport", "OrderComponents", 5usize, |this| ((**(this as *const *const IWsbApplicationRestoreSupport_Vtbl)).OrderComponents)(this, ccomponents, rgcomponentname, rgcomponentlogicalpaths, prgcomponentname, prgcomponentlogicalpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRollForwardSupported(this: *mut core::ffi::c_void, pbrollforwardsupported: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWsbApplicationRestoreSupport", "IsRollForwardSupported", 6usize, |this| ((**(this as *const *const IWsbApplicationRestoreSupport_Vtbl)).IsRollForwardSupported)(this, pbrollforwardsupported), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PreRestore, PostRestore, OrderComponents, IsRollForwardSupported }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWsbApplicationRestoreSupport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -743,6 +743,7 @@ pub struct WcmDataType(pub i32);
 impl windows_core::TypeKind for WcmDataType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WcmDataType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WcmDataType").field(&self.0).finish()
@@ -754,6 +755,7 @@ pub struct WcmNamespaceAccess(pub i32);
 impl windows_core::TypeKind for WcmNamespaceAccess {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WcmNamespaceAccess {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WcmNamespaceAccess").field(&self.0).finish()
@@ -765,6 +767,7 @@ pub struct WcmNamespaceEnumerationFlags(pub i32);
 impl windows_core::TypeKind for WcmNamespaceEnumerationFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WcmNamespaceEnumerationFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WcmNamespaceEnumerationFlags").field(&self.0).finish()
@@ -776,6 +779,7 @@ pub struct WcmRestrictionFacets(pub i32);
 impl windows_core::TypeKind for WcmRestrictionFacets {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WcmRestrictionFacets {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WcmRestrictionFacets").field(&self.0).finish()
@@ -787,6 +791,7 @@ pub struct WcmSettingType(pub i32);
 impl windows_core::TypeKind for WcmSettingType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WcmSettingType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WcmSettingType").field(&self.0).finish()
@@ -798,6 +803,7 @@ pub struct WcmTargetMode(pub i32);
 impl windows_core::TypeKind for WcmTargetMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WcmTargetMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WcmTargetMode").field(&self.0).finish()
@@ -809,6 +815,7 @@ pub struct WcmUserStatus(pub i32);
 impl windows_core::TypeKind for WcmUserStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WcmUserStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WcmUserStatus").field(&self.0).finish()
@@ -817,3 +824,5 @@ impl core::fmt::Debug for WcmUserStatus {
 pub const SettingsEngine: windows_core::GUID = windows_core::GUID::from_u128(0x9f7d7bb5_20b3_11da_81a5_0030f1642e3c);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
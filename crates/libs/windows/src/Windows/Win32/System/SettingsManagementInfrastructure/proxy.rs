@@ -0,0 +1,435 @@
+impl windows_core::imp::ProxyVtable for IItemEnumerator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Current(this: *mut core::ffi::c_void, item: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IItemEnumerator", "Current", 3usize, |this| ((**(this as *const *const IItemEnumerator_Vtbl)).Current)(this, item), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveNext(this: *mut core::ffi::c_void, itemvalid: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IItemEnumerator", "MoveNext", 4usize, |this| ((**(this as *const *const IItemEnumerator_Vtbl)).MoveNext)(this, itemvalid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IItemEnumerator", "Reset", 5usize, |this| ((**(this as *const *const IItemEnumerator_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Current, MoveNext, Reset }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IItemEnumerator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISettingsContext_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Serialize(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, ptarget: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsContext", "Serialize", 3usize, |this| ((**(this as *const *const ISettingsContext_Vtbl)).Serialize)(this, pstream, ptarget), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Deserialize(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, ptarget: *mut core::ffi::c_void, pppresults: *mut *mut Option<ISettingsResult>, pcresultcount: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsContext", "Deserialize", 4usize, |this| ((**(this as *const *const ISettingsContext_Vtbl)).Deserialize)(this, pstream, ptarget, pppresults, pcresultcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUserData(this: *mut core::ffi::c_void, puserdata: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsContext", "SetUserData", 5usize, |this| ((**(this as *const *const ISettingsContext_Vtbl)).SetUserData)(this, puserdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUserData(this: *mut core::ffi::c_void, puserdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsContext", "GetUserData", 6usize, |this| ((**(this as *const *const ISettingsContext_Vtbl)).GetUserData)(this, puserdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNamespaces(this: *mut core::ffi::c_void, ppnamespaceids: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsContext", "GetNamespaces", 7usize, |this| ((**(this as *const *const ISettingsContext_Vtbl)).GetNamespaces)(this, ppnamespaceids), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStoredSettings(this: *mut core::ffi::c_void, pidentity: *mut core::ffi::c_void, ppaddedsettings: *mut *mut core::ffi::c_void, ppmodifiedsettings: *mut *mut core::ffi::c_void, ppdeletedsettings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsContext", "GetStoredSettings", 8usize, |this| ((**(this as *const *const ISettingsContext_Vtbl)).GetStoredSettings)(this, pidentity, ppaddedsettings, ppmodifiedsettings, ppdeletedsettings), |result| Some(*result))
+        }
+        unsafe extern "system" fn RevertSetting(this: *mut core::ffi::c_void, pidentity: *mut core::ffi::c_void, pwzsetting: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsContext", "RevertSetting", 9usize, |this| ((**(this as *const *const ISettingsContext_Vtbl)).RevertSetting)(this, pidentity, pwzsetting), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Serialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Serialize: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Deserialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Deserialize: 0,
+            SetUserData,
+            GetUserData,
+            GetNamespaces,
+            GetStoredSettings,
+            RevertSetting,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISettingsContext as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISettingsEngine_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNamespaces(this: *mut core::ffi::c_void, flags: WcmNamespaceEnumerationFlags, reserved: *const core::ffi::c_void, namespaces: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsEngine", "GetNamespaces", 3usize, |this| ((**(this as *const *const ISettingsEngine_Vtbl)).GetNamespaces)(this, flags, reserved, namespaces), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNamespace(this: *mut core::ffi::c_void, settingsid: *mut core::ffi::c_void, access: WcmNamespaceAccess, reserved: *const core::ffi::c_void, namespaceitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsEngine", "GetNamespace", 4usize, |this| ((**(this as *const *const ISettingsEngine_Vtbl)).GetNamespace)(this, settingsid, access, reserved, namespaceitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetErrorDescription(this: *mut core::ffi::c_void, hresult: i32, message: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsEngine", "GetErrorDescription", 5usize, |this| ((**(this as *const *const ISettingsEngine_Vtbl)).GetErrorDescription)(this, hresult, message), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSettingsIdentity(this: *mut core::ffi::c_void, settingsid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsEngine", "CreateSettingsIdentity", 6usize, |this| ((**(this as *const *const ISettingsEngine_Vtbl)).CreateSettingsIdentity)(this, settingsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStoreStatus(this: *mut core::ffi::c_void, reserved: *const core::ffi::c_void, status: *mut WcmUserStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsEngine", "GetStoreStatus", 7usize, |this| ((**(this as *const *const ISettingsEngine_Vtbl)).GetStoreStatus)(this, reserved, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadStore(this: *mut core::ffi::c_void, flags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsEngine", "LoadStore", 8usize, |this| ((**(this as *const *const ISettingsEngine_Vtbl)).LoadStore)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnloadStore(this: *mut core::ffi::c_void, reserved: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsEngine", "UnloadStore", 9usize, |this| ((**(this as *const *const ISettingsEngine_Vtbl)).UnloadStore)(this, reserved), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RegisterNamespace(this: *mut core::ffi::c_void, settingsid: *mut core::ffi::c_void, stream: *mut core::ffi::c_void, pushsettings: super::super::Foundation::BOOL, results: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsEngine", "RegisterNamespace", 10usize, |this| ((**(this as *const *const ISettingsEngine_Vtbl)).RegisterNamespace)(this, settingsid, stream, pushsettings, results), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterNamespace(this: *mut core::ffi::c_void, settingsid: *mut core::ffi::c_void, removesettings: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsEngine", "UnregisterNamespace", 11usize, |this| ((**(this as *const *const ISettingsEngine_Vtbl)).UnregisterNamespace)(this, settingsid, removesettings), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTargetInfo(this: *mut core::ffi::c_void, target: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsEngine", "CreateTargetInfo", 12usize, |this| ((**(this as *const *const ISettingsEngine_Vtbl)).CreateTargetInfo)(this, target), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTargetInfo(this: *mut core::ffi::c_void, target: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsEngine", "GetTargetInfo", 13usize, |this| ((**(this as *const *const ISettingsEngine_Vtbl)).GetTargetInfo)(this, target), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTargetInfo(this: *mut core::ffi::c_void, target: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsEngine", "SetTargetInfo", 14usize, |this| ((**(this as *const *const ISettingsEngine_Vtbl)).SetTargetInfo)(this, target), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSettingsContext(this: *mut core::ffi::c_void, flags: u32, reserved: *const core::ffi::c_void, settingscontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsEngine", "CreateSettingsContext", 15usize, |this| ((**(this as *const *const ISettingsEngine_Vtbl)).CreateSettingsContext)(this, flags, reserved, settingscontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSettingsContext(this: *mut core::ffi::c_void, settingscontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsEngine", "SetSettingsContext", 16usize, |this| ((**(this as *const *const ISettingsEngine_Vtbl)).SetSettingsContext)(this, settingscontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplySettingsContext(this: *mut core::ffi::c_void, settingscontext: *mut core::ffi::c_void, pppwzidentities: *mut *mut windows_core::PWSTR, pcidentities: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsEngine", "ApplySettingsContext", 17usize, |this| ((**(this as *const *const ISettingsEngine_Vtbl)).ApplySettingsContext)(this, settingscontext, pppwzidentities, pcidentities), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSettingsContext(this: *mut core::ffi::c_void, settingscontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsEngine", "GetSettingsContext", 18usize, |this| ((**(this as *const *const ISettingsEngine_Vtbl)).GetSettingsContext)(this, settingscontext), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetNamespaces,
+            GetNamespace,
+            GetErrorDescription,
+            CreateSettingsIdentity,
+            GetStoreStatus,
+            LoadStore,
+            UnloadStore,
+            #[cfg(feature = "Win32_System_Com")]
+            RegisterNamespace,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RegisterNamespace: 0,
+            UnregisterNamespace,
+            CreateTargetInfo,
+            GetTargetInfo,
+            SetTargetInfo,
+            CreateSettingsContext,
+            SetSettingsContext,
+            ApplySettingsContext,
+            GetSettingsContext,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISettingsEngine as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISettingsIdentity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAttribute(this: *mut core::ffi::c_void, reserved: *const core::ffi::c_void, name: windows_core::PCWSTR, value: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsIdentity", "GetAttribute", 3usize, |this| ((**(this as *const *const ISettingsIdentity_Vtbl)).GetAttribute)(this, reserved, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAttribute(this: *mut core::ffi::c_void, reserved: *const core::ffi::c_void, name: windows_core::PCWSTR, value: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsIdentity", "SetAttribute", 4usize, |this| ((**(this as *const *const ISettingsIdentity_Vtbl)).SetAttribute)(this, reserved, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlags(this: *mut core::ffi::c_void, flags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsIdentity", "GetFlags", 5usize, |this| ((**(this as *const *const ISettingsIdentity_Vtbl)).GetFlags)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFlags(this: *mut core::ffi::c_void, flags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsIdentity", "SetFlags", 6usize, |this| ((**(this as *const *const ISettingsIdentity_Vtbl)).SetFlags)(this, flags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAttribute, SetAttribute, GetFlags, SetFlags }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISettingsIdentity as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISettingsItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsItem", "GetName", 3usize, |this| ((**(this as *const *const ISettingsItem_Vtbl)).GetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsItem", "GetValue", 4usize, |this| ((**(this as *const *const ISettingsItem_Vtbl)).GetValue)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsItem", "SetValue", 5usize, |this| ((**(this as *const *const ISettingsItem_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSettingType(this: *mut core::ffi::c_void, r#type: *mut WcmSettingType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsItem", "GetSettingType", 6usize, |this| ((**(this as *const *const ISettingsItem_Vtbl)).GetSettingType)(this, r#type), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataType(this: *mut core::ffi::c_void, r#type: *mut WcmDataType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsItem", "GetDataType", 7usize, |this| ((**(this as *const *const ISettingsItem_Vtbl)).GetDataType)(this, r#type), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValueRaw(this: *mut core::ffi::c_void, data: *mut *mut u8, datasize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsItem", "GetValueRaw", 8usize, |this| ((**(this as *const *const ISettingsItem_Vtbl)).GetValueRaw)(this, data, datasize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValueRaw(this: *mut core::ffi::c_void, datatype: i32, data: *const u8, datasize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsItem", "SetValueRaw", 9usize, |this| ((**(this as *const *const ISettingsItem_Vtbl)).SetValueRaw)(this, datatype, data, datasize), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasChild(this: *mut core::ffi::c_void, itemhaschild: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsItem", "HasChild", 10usize, |this| ((**(this as *const *const ISettingsItem_Vtbl)).HasChild)(this, itemhaschild), |result| Some(*result))
+        }
+        unsafe extern "system" fn Children(this: *mut core::ffi::c_void, children: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsItem", "Children", 11usize, |this| ((**(this as *const *const ISettingsItem_Vtbl)).Children)(this, children), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChild(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, child: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsItem", "GetChild", 12usize, |this| ((**(this as *const *const ISettingsItem_Vtbl)).GetChild)(this, name, child), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSettingByPath(this: *mut core::ffi::c_void, path: windows_core::PCWSTR, setting: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsItem", "GetSettingByPath", 13usize, |this| ((**(this as *const *const ISettingsItem_Vtbl)).GetSettingByPath)(this, path, setting), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSettingByPath(this: *mut core::ffi::c_void, path: windows_core::PCWSTR, setting: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsItem", "CreateSettingByPath", 14usize, |this| ((**(this as *const *const ISettingsItem_Vtbl)).CreateSettingByPath)(this, path, setting), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSettingByPath(this: *mut core::ffi::c_void, path: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsItem", "RemoveSettingByPath", 15usize, |this| ((**(this as *const *const ISettingsItem_Vtbl)).RemoveSettingByPath)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetListKeyInformation(this: *mut core::ffi::c_void, keyname: *mut core::mem::MaybeUninit<windows_core::BSTR>, datatype: *mut WcmDataType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsItem", "GetListKeyInformation", 16usize, |this| ((**(this as *const *const ISettingsItem_Vtbl)).GetListKeyInformation)(this, keyname, datatype), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateListElement(this: *mut core::ffi::c_void, keydata: *const core::mem::MaybeUninit<windows_core::VARIANT>, child: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsItem", "CreateListElement", 17usize, |this| ((**(this as *const *const ISettingsItem_Vtbl)).CreateListElement)(this, keydata, child), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveListElement(this: *mut core::ffi::c_void, elementname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsItem", "RemoveListElement", 18usize, |this| ((**(this as *const *const ISettingsItem_Vtbl)).RemoveListElement)(this, elementname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Attributes(this: *mut core::ffi::c_void, attributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsItem", "Attributes", 19usize, |this| ((**(this as *const *const ISettingsItem_Vtbl)).Attributes)(this, attributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttribute(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, value: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsItem", "GetAttribute", 20usize, |this| ((**(this as *const *const ISettingsItem_Vtbl)).GetAttribute)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPath(this: *mut core::ffi::c_void, path: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsItem", "GetPath", 21usize, |this| ((**(this as *const *const ISettingsItem_Vtbl)).GetPath)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRestrictionFacets(this: *mut core::ffi::c_void, restrictionfacets: *mut WcmRestrictionFacets) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsItem", "GetRestrictionFacets", 22usize, |this| ((**(this as *const *const ISettingsItem_Vtbl)).GetRestrictionFacets)(this, restrictionfacets), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRestriction(this: *mut core::ffi::c_void, restrictionfacet: WcmRestrictionFacets, facetdata: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsItem", "GetRestriction", 23usize, |this| ((**(this as *const *const ISettingsItem_Vtbl)).GetRestriction)(this, restrictionfacet, facetdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKeyValue(this: *mut core::ffi::c_void, value: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsItem", "GetKeyValue", 24usize, |this| ((**(this as *const *const ISettingsItem_Vtbl)).GetKeyValue)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetName,
+            GetValue,
+            SetValue,
+            GetSettingType,
+            GetDataType,
+            GetValueRaw,
+            SetValueRaw,
+            HasChild,
+            Children,
+            GetChild,
+            GetSettingByPath,
+            CreateSettingByPath,
+            RemoveSettingByPath,
+            GetListKeyInformation,
+            CreateListElement,
+            RemoveListElement,
+            Attributes,
+            GetAttribute,
+            GetPath,
+            GetRestrictionFacets,
+            GetRestriction,
+            GetKeyValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISettingsItem as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISettingsNamespace_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetIdentity(this: *mut core::ffi::c_void, settingsid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsNamespace", "GetIdentity", 3usize, |this| ((**(this as *const *const ISettingsNamespace_Vtbl)).GetIdentity)(this, settingsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Settings(this: *mut core::ffi::c_void, settings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsNamespace", "Settings", 4usize, |this| ((**(this as *const *const ISettingsNamespace_Vtbl)).Settings)(this, settings), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void, pushsettings: super::super::Foundation::BOOL, result: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsNamespace", "Save", 5usize, |this| ((**(this as *const *const ISettingsNamespace_Vtbl)).Save)(this, pushsettings, result), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSettingByPath(this: *mut core::ffi::c_void, path: windows_core::PCWSTR, setting: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsNamespace", "GetSettingByPath", 6usize, |this| ((**(this as *const *const ISettingsNamespace_Vtbl)).GetSettingByPath)(this, path, setting), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSettingByPath(this: *mut core::ffi::c_void, path: windows_core::PCWSTR, setting: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsNamespace", "CreateSettingByPath", 7usize, |this| ((**(this as *const *const ISettingsNamespace_Vtbl)).CreateSettingByPath)(this, path, setting), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSettingByPath(this: *mut core::ffi::c_void, path: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsNamespace", "RemoveSettingByPath", 8usize, |this| ((**(this as *const *const ISettingsNamespace_Vtbl)).RemoveSettingByPath)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttribute(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, value: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsNamespace", "GetAttribute", 9usize, |this| ((**(this as *const *const ISettingsNamespace_Vtbl)).GetAttribute)(this, name, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetIdentity,
+            Settings,
+            Save,
+            GetSettingByPath,
+            CreateSettingByPath,
+            RemoveSettingByPath,
+            GetAttribute,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISettingsNamespace as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISettingsResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDescription(this: *mut core::ffi::c_void, description: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsResult", "GetDescription", 3usize, |this| ((**(this as *const *const ISettingsResult_Vtbl)).GetDescription)(this, description), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetErrorCode(this: *mut core::ffi::c_void, hrout: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsResult", "GetErrorCode", 4usize, |this| ((**(this as *const *const ISettingsResult_Vtbl)).GetErrorCode)(this, hrout), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContextDescription(this: *mut core::ffi::c_void, description: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsResult", "GetContextDescription", 5usize, |this| ((**(this as *const *const ISettingsResult_Vtbl)).GetContextDescription)(this, description), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLine(this: *mut core::ffi::c_void, dwline: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsResult", "GetLine", 6usize, |this| ((**(this as *const *const ISettingsResult_Vtbl)).GetLine)(this, dwline), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColumn(this: *mut core::ffi::c_void, dwcolumn: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsResult", "GetColumn", 7usize, |this| ((**(this as *const *const ISettingsResult_Vtbl)).GetColumn)(this, dwcolumn), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSource(this: *mut core::ffi::c_void, file: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISettingsResult", "GetSource", 8usize, |this| ((**(this as *const *const ISettingsResult_Vtbl)).GetSource)(this, file), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDescription,
+            GetErrorCode,
+            GetContextDescription,
+            GetLine,
+            GetColumn,
+            GetSource,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISettingsResult as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITargetInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTargetMode(this: *mut core::ffi::c_void, targetmode: *mut WcmTargetMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetInfo", "GetTargetMode", 3usize, |this| ((**(this as *const *const ITargetInfo_Vtbl)).GetTargetMode)(this, targetmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTargetMode(this: *mut core::ffi::c_void, targetmode: WcmTargetMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetInfo", "SetTargetMode", 4usize, |this| ((**(this as *const *const ITargetInfo_Vtbl)).SetTargetMode)(this, targetmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTemporaryStoreLocation(this: *mut core::ffi::c_void, temporarystorelocation: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetInfo", "GetTemporaryStoreLocation", 5usize, |this| ((**(this as *const *const ITargetInfo_Vtbl)).GetTemporaryStoreLocation)(this, temporarystorelocation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTemporaryStoreLocation(this: *mut core::ffi::c_void, temporarystorelocation: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetInfo", "SetTemporaryStoreLocation", 6usize, |this| ((**(this as *const *const ITargetInfo_Vtbl)).SetTemporaryStoreLocation)(this, temporarystorelocation), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTargetID(this: *mut core::ffi::c_void, targetid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetInfo", "GetTargetID", 7usize, |this| ((**(this as *const *const ITargetInfo_Vtbl)).GetTargetID)(this, targetid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTargetID(this: *mut core::ffi::c_void, targetid: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetInfo", "SetTargetID", 8usize, |this| ((**(this as *const *const ITargetInfo_Vtbl)).SetTargetID)(this, targetid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTargetProcessorArchitecture(this: *mut core::ffi::c_void, processorarchitecture: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetInfo", "GetTargetProcessorArchitecture", 9usize, |this| ((**(this as *const *const ITargetInfo_Vtbl)).GetTargetProcessorArchitecture)(this, processorarchitecture), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTargetProcessorArchitecture(this: *mut core::ffi::c_void, processorarchitecture: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetInfo", "SetTargetProcessorArchitecture", 10usize, |this| ((**(this as *const *const ITargetInfo_Vtbl)).SetTargetProcessorArchitecture)(this, processorarchitecture), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, offline: super::super::Foundation::BOOL, property: windows_core::PCWSTR, value: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetInfo", "GetProperty", 11usize, |this| ((**(this as *const *const ITargetInfo_Vtbl)).GetProperty)(this, offline, property, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, offline: super::super::Foundation::BOOL, property: windows_core::PCWSTR, value: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetInfo", "SetProperty", 12usize, |this| ((**(this as *const *const ITargetInfo_Vtbl)).SetProperty)(this, offline, property, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnumerator(this: *mut core::ffi::c_void, enumerator: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetInfo", "GetEnumerator", 13usize, |this| ((**(this as *const *const ITargetInfo_Vtbl)).GetEnumerator)(this, enumerator), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExpandTarget(this: *mut core::ffi::c_void, offline: super::super::Foundation::BOOL, location: windows_core::PCWSTR, expandedlocation: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetInfo", "ExpandTarget", 14usize, |this| ((**(this as *const *const ITargetInfo_Vtbl)).ExpandTarget)(this, offline, location, expandedlocation), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExpandTargetPath(this: *mut core::ffi::c_void, offline: super::super::Foundation::BOOL, location: windows_core::PCWSTR, expandedlocation: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetInfo", "ExpandTargetPath", 15usize, |this| ((**(this as *const *const ITargetInfo_Vtbl)).ExpandTargetPath)(this, offline, location, expandedlocation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetModulePath(this: *mut core::ffi::c_void, module: windows_core::PCWSTR, path: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetInfo", "SetModulePath", 16usize, |this| ((**(this as *const *const ITargetInfo_Vtbl)).SetModulePath)(this, module, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadModule(this: *mut core::ffi::c_void, module: windows_core::PCWSTR, modulehandle: *mut super::super::Foundation::HMODULE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetInfo", "LoadModule", 17usize, |this| ((**(this as *const *const ITargetInfo_Vtbl)).LoadModule)(this, module, modulehandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWow64Context(this: *mut core::ffi::c_void, installermodule: windows_core::PCWSTR, wow64context: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetInfo", "SetWow64Context", 18usize, |this| ((**(this as *const *const ITargetInfo_Vtbl)).SetWow64Context)(this, installermodule, wow64context), |result| Some(*result))
+        }
+        unsafe extern "system" fn TranslateWow64(this: *mut core::ffi::c_void, clientarchitecture: windows_core::PCWSTR, value: windows_core::PCWSTR, translatedvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetInfo", "TranslateWow64", 19usize, |this| ((**(this as *const *const ITargetInfo_Vtbl)).TranslateWow64)(this, clientarchitecture, value, translatedvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSchemaHiveLocation(this: *mut core::ffi::c_void, pwzhivedir: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetInfo", "SetSchemaHiveLocation", 20usize, |this| ((**(this as *const *const ITargetInfo_Vtbl)).SetSchemaHiveLocation)(this, pwzhivedir), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSchemaHiveLocation(this: *mut core::ffi::c_void, phivelocation: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetInfo", "GetSchemaHiveLocation", 21usize, |this| ((**(this as *const *const ITargetInfo_Vtbl)).GetSchemaHiveLocation)(this, phivelocation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSchemaHiveMountName(this: *mut core::ffi::c_void, pwzmountname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetInfo", "SetSchemaHiveMountName", 22usize, |this| ((**(this as *const *const ITargetInfo_Vtbl)).SetSchemaHiveMountName)(this, pwzmountname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSchemaHiveMountName(this: *mut core::ffi::c_void, pmountname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetInfo", "GetSchemaHiveMountName", 23usize, |this| ((**(this as *const *const ITargetInfo_Vtbl)).GetSchemaHiveMountName)(this, pmountname), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetTargetMode,
+            SetTargetMode,
+            GetTemporaryStoreLocation,
+            SetTemporaryStoreLocation,
+            GetTargetID,
+            SetTargetID,
+            GetTargetProcessorArchitecture,
+            SetTargetProcessorArchitecture,
+            GetProperty,
+            SetProperty,
+            GetEnumerator,
+            ExpandTarget,
+            ExpandTargetPath,
+            SetModulePath,
+            LoadModule,
+            SetWow64Context,
+            TranslateWow64,
+            SetSchemaHiveLocation,
+            GetSchemaHiveLocation,
+            SetSchemaHiveMountName,
+            GetSchemaHiveMountName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITargetInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -219,6 +219,7 @@ pub struct EXIT_WINDOWS_FLAGS(pub u32);
 impl windows_core::TypeKind for EXIT_WINDOWS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EXIT_WINDOWS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EXIT_WINDOWS_FLAGS").field(&self.0).finish()
@@ -263,6 +264,7 @@ pub struct SHUTDOWN_FLAGS(pub u32);
 impl windows_core::TypeKind for SHUTDOWN_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHUTDOWN_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHUTDOWN_FLAGS").field(&self.0).finish()
@@ -307,6 +309,7 @@ pub struct SHUTDOWN_REASON(pub u32);
 impl windows_core::TypeKind for SHUTDOWN_REASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHUTDOWN_REASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHUTDOWN_REASON").field(&self.0).finish()
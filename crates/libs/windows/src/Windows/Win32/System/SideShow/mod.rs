@@ -447,6 +447,7 @@ pub struct SCF_BUTTON_IDS(pub i32);
 impl windows_core::TypeKind for SCF_BUTTON_IDS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCF_BUTTON_IDS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCF_BUTTON_IDS").field(&self.0).finish()
@@ -458,6 +459,7 @@ pub struct SCF_EVENT_IDS(pub i32);
 impl windows_core::TypeKind for SCF_EVENT_IDS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCF_EVENT_IDS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCF_EVENT_IDS").field(&self.0).finish()
@@ -469,6 +471,7 @@ pub struct SIDESHOW_COLOR_TYPE(pub i32);
 impl windows_core::TypeKind for SIDESHOW_COLOR_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SIDESHOW_COLOR_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SIDESHOW_COLOR_TYPE").field(&self.0).finish()
@@ -480,6 +483,7 @@ pub struct SIDESHOW_SCREEN_TYPE(pub i32);
 impl windows_core::TypeKind for SIDESHOW_SCREEN_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SIDESHOW_SCREEN_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SIDESHOW_SCREEN_TYPE").field(&self.0).finish()
@@ -564,7 +568,8 @@ impl Default for NEW_EVENT_DATA_AVAILABLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCF_CONTEXTMENU_EVENT {
     pub PreviousPage: u32,
     pub TargetPage: u32,
@@ -581,7 +586,8 @@ impl Default for SCF_CONTEXTMENU_EVENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCF_EVENT_HEADER {
     pub PreviousPage: u32,
     pub TargetPage: u32,
@@ -595,7 +601,8 @@ impl Default for SCF_EVENT_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCF_MENUACTION_EVENT {
     pub PreviousPage: u32,
     pub TargetPage: u32,
@@ -611,7 +618,8 @@ impl Default for SCF_MENUACTION_EVENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCF_NAVIGATION_EVENT {
     pub PreviousPage: u32,
     pub TargetPage: u32,
@@ -631,3 +639,5 @@ pub const SideShowPropVariantCollection: windows_core::GUID = windows_core::GUID
 pub const SideShowSession: windows_core::GUID = windows_core::GUID::from_u128(0xe20543b9_f785_4ea2_981e_c4ffa76bbc7c);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
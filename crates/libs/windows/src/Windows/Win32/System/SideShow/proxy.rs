@@ -0,0 +1,268 @@
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for ISideShowBulkCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCapabilities(this: *mut core::ffi::c_void, in_keycollection: *mut core::ffi::c_void, inout_pvalues: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowBulkCapabilities", "GetCapabilities", 4usize, |this| ((**(this as *const *const ISideShowBulkCapabilities_Vtbl)).GetCapabilities)(this, in_keycollection, inout_pvalues), |result| Some(*result))
+        }
+        Self { base__: <ISideShowCapabilities_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCapabilities }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISideShowBulkCapabilities as windows_core::Interface>::IID || <ISideShowCapabilities_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for ISideShowCapabilities_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetCapability(this: *mut core::ffi::c_void, in_keycapability: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY, inout_pvalue: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowCapabilities", "GetCapability", 3usize, |this| ((**(this as *const *const ISideShowCapabilities_Vtbl)).GetCapability)(this, in_keycapability, inout_pvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetCapability,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetCapability: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISideShowCapabilities as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISideShowCapabilitiesCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, out_pdwcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowCapabilitiesCollection", "GetCount", 3usize, |this| ((**(this as *const *const ISideShowCapabilitiesCollection_Vtbl)).GetCount)(this, out_pdwcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, in_dwindex: u32, out_ppcapabilities: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowCapabilitiesCollection", "GetAt", 4usize, |this| ((**(this as *const *const ISideShowCapabilitiesCollection_Vtbl)).GetAt)(this, in_dwindex, out_ppcapabilities), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCount, GetAt }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISideShowCapabilitiesCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISideShowContent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetContent(this: *mut core::ffi::c_void, in_picapabilities: *mut core::ffi::c_void, out_pdwsize: *mut u32, out_ppbdata: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowContent", "GetContent", 3usize, |this| ((**(this as *const *const ISideShowContent_Vtbl)).GetContent)(this, in_picapabilities, out_pdwsize, out_ppbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentId(this: *mut core::ffi::c_void, out_pcontentid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowContent", "ContentId", 4usize, |this| ((**(this as *const *const ISideShowContent_Vtbl)).ContentId)(this, out_pcontentid), |result| Some(*result))
+        }
+        unsafe extern "system" fn DifferentiateContent(this: *mut core::ffi::c_void, out_pfdifferentiatecontent: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowContent", "DifferentiateContent", 5usize, |this| ((**(this as *const *const ISideShowContent_Vtbl)).DifferentiateContent)(this, out_pfdifferentiatecontent), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetContent, ContentId, DifferentiateContent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISideShowContent as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISideShowContentManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, in_picontent: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowContentManager", "Add", 3usize, |this| ((**(this as *const *const ISideShowContentManager_Vtbl)).Add)(this, in_picontent), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, in_contentid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowContentManager", "Remove", 4usize, |this| ((**(this as *const *const ISideShowContentManager_Vtbl)).Remove)(this, in_contentid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowContentManager", "RemoveAll", 5usize, |this| ((**(this as *const *const ISideShowContentManager_Vtbl)).RemoveAll)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventSink(this: *mut core::ffi::c_void, in_pievents: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowContentManager", "SetEventSink", 6usize, |this| ((**(this as *const *const ISideShowContentManager_Vtbl)).SetEventSink)(this, in_pievents), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeviceCapabilities(this: *mut core::ffi::c_void, out_ppcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowContentManager", "GetDeviceCapabilities", 7usize, |this| ((**(this as *const *const ISideShowContentManager_Vtbl)).GetDeviceCapabilities)(this, out_ppcollection), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Add, Remove, RemoveAll, SetEventSink, GetDeviceCapabilities }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISideShowContentManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISideShowEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ContentMissing(this: *mut core::ffi::c_void, in_contentid: u32, out_ppicontent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowEvents", "ContentMissing", 3usize, |this| ((**(this as *const *const ISideShowEvents_Vtbl)).ContentMissing)(this, in_contentid, out_ppicontent), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplicationEvent(this: *mut core::ffi::c_void, in_picapabilities: *mut core::ffi::c_void, in_dweventid: u32, in_dweventsize: u32, in_pbeventdata: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowEvents", "ApplicationEvent", 4usize, |this| ((**(this as *const *const ISideShowEvents_Vtbl)).ApplicationEvent)(this, in_picapabilities, in_dweventid, in_dweventsize, in_pbeventdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceAdded(this: *mut core::ffi::c_void, in_pidevice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowEvents", "DeviceAdded", 5usize, |this| ((**(this as *const *const ISideShowEvents_Vtbl)).DeviceAdded)(this, in_pidevice), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceRemoved(this: *mut core::ffi::c_void, in_pidevice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowEvents", "DeviceRemoved", 6usize, |this| ((**(this as *const *const ISideShowEvents_Vtbl)).DeviceRemoved)(this, in_pidevice), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ContentMissing, ApplicationEvent, DeviceAdded, DeviceRemoved }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISideShowEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for ISideShowKeyCollection_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, key: *const super::super::UI::Shell::PropertiesSystem::PROPERTYKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowKeyCollection", "Add", 3usize, |this| ((**(this as *const *const ISideShowKeyCollection_Vtbl)).Add)(this, key), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowKeyCollection", "Clear", 4usize, |this| ((**(this as *const *const ISideShowKeyCollection_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, dwindex: u32, pkey: *mut super::super::UI::Shell::PropertiesSystem::PROPERTYKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowKeyCollection", "GetAt", 5usize, |this| ((**(this as *const *const ISideShowKeyCollection_Vtbl)).GetAt)(this, dwindex, pkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcelems: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowKeyCollection", "GetCount", 6usize, |this| ((**(this as *const *const ISideShowKeyCollection_Vtbl)).GetCount)(this, pcelems), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAt(this: *mut core::ffi::c_void, dwindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowKeyCollection", "RemoveAt", 7usize, |this| ((**(this as *const *const ISideShowKeyCollection_Vtbl)).RemoveAt)(this, dwindex), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            Add,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            Add: 0,
+            Clear,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetAt,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetAt: 0,
+            GetCount,
+            RemoveAt,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISideShowKeyCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl windows_core::imp::ProxyVtable for ISideShowNotification_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NotificationId(this: *mut core::ffi::c_void, out_pnotificationid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowNotification", "NotificationId", 3usize, |this| ((**(this as *const *const ISideShowNotification_Vtbl)).NotificationId)(this, out_pnotificationid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNotificationId(this: *mut core::ffi::c_void, in_notificationid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowNotification", "SetNotificationId", 4usize, |this| ((**(this as *const *const ISideShowNotification_Vtbl)).SetNotificationId)(this, in_notificationid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, out_ppwsztitle: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowNotification", "Title", 5usize, |this| ((**(this as *const *const ISideShowNotification_Vtbl)).Title)(this, out_ppwsztitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, in_pwsztitle: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowNotification", "SetTitle", 6usize, |this| ((**(this as *const *const ISideShowNotification_Vtbl)).SetTitle)(this, in_pwsztitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, out_ppwszmessage: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowNotification", "Message", 7usize, |this| ((**(this as *const *const ISideShowNotification_Vtbl)).Message)(this, out_ppwszmessage), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMessage(this: *mut core::ffi::c_void, in_pwszmessage: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowNotification", "SetMessage", 8usize, |this| ((**(this as *const *const ISideShowNotification_Vtbl)).SetMessage)(this, in_pwszmessage), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn Image(this: *mut core::ffi::c_void, out_phicon: *mut super::super::UI::WindowsAndMessaging::HICON) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowNotification", "Image", 9usize, |this| ((**(this as *const *const ISideShowNotification_Vtbl)).Image)(this, out_phicon), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn SetImage(this: *mut core::ffi::c_void, in_hicon: super::super::UI::WindowsAndMessaging::HICON) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowNotification", "SetImage", 10usize, |this| ((**(this as *const *const ISideShowNotification_Vtbl)).SetImage)(this, in_hicon), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExpirationTime(this: *mut core::ffi::c_void, out_ptime: *mut super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowNotification", "ExpirationTime", 11usize, |this| ((**(this as *const *const ISideShowNotification_Vtbl)).ExpirationTime)(this, out_ptime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExpirationTime(this: *mut core::ffi::c_void, in_ptime: *const super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowNotification", "SetExpirationTime", 12usize, |this| ((**(this as *const *const ISideShowNotification_Vtbl)).SetExpirationTime)(this, in_ptime), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            NotificationId,
+            SetNotificationId,
+            Title,
+            SetTitle,
+            Message,
+            SetMessage,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            Image,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            Image: 0,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            SetImage,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            SetImage: 0,
+            ExpirationTime,
+            SetExpirationTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISideShowNotification as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISideShowNotificationManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Show(this: *mut core::ffi::c_void, in_pinotification: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowNotificationManager", "Show", 3usize, |this| ((**(this as *const *const ISideShowNotificationManager_Vtbl)).Show)(this, in_pinotification), |result| Some(*result))
+        }
+        unsafe extern "system" fn Revoke(this: *mut core::ffi::c_void, in_notificationid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowNotificationManager", "Revoke", 4usize, |this| ((**(this as *const *const ISideShowNotificationManager_Vtbl)).Revoke)(this, in_notificationid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RevokeAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowNotificationManager", "RevokeAll", 5usize, |this| ((**(this as *const *const ISideShowNotificationManager_Vtbl)).RevokeAll)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Show, Revoke, RevokeAll }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISideShowNotificationManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISideShowPropVariantCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, pvalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowPropVariantCollection", "Add", 3usize, |this| ((**(this as *const *const ISideShowPropVariantCollection_Vtbl)).Add)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowPropVariantCollection", "Clear", 4usize, |this| ((**(this as *const *const ISideShowPropVariantCollection_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, dwindex: u32, pvalue: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowPropVariantCollection", "GetAt", 5usize, |this| ((**(this as *const *const ISideShowPropVariantCollection_Vtbl)).GetAt)(this, dwindex, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcelems: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowPropVariantCollection", "GetCount", 6usize, |this| ((**(this as *const *const ISideShowPropVariantCollection_Vtbl)).GetCount)(this, pcelems), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAt(this: *mut core::ffi::c_void, dwindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowPropVariantCollection", "RemoveAt", 7usize, |this| ((**(this as *const *const ISideShowPropVariantCollection_Vtbl)).RemoveAt)(this, dwindex), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Add, Clear, GetAt, GetCount, RemoveAt }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISideShowPropVariantCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISideShowSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterContent(this: *mut core::ffi::c_void, in_applicationid: *const windows_core::GUID, in_endpointid: *const windows_core::GUID, out_ppicontent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowSession", "RegisterContent", 3usize, |this| ((**(this as *const *const ISideShowSession_Vtbl)).RegisterContent)(this, in_applicationid, in_endpointid, out_ppicontent), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterNotifications(this: *mut core::ffi::c_void, in_applicationid: *const windows_core::GUID, out_ppinotification: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISideShowSession", "RegisterNotifications", 4usize, |this| ((**(this as *const *const ISideShowSession_Vtbl)).RegisterNotifications)(this, in_applicationid, out_ppinotification), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterContent, RegisterNotifications }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISideShowSession as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -316,6 +316,7 @@ pub struct BROADCAST_SYSTEM_MESSAGE_FLAGS(pub u32);
 impl windows_core::TypeKind for BROADCAST_SYSTEM_MESSAGE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BROADCAST_SYSTEM_MESSAGE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BROADCAST_SYSTEM_MESSAGE_FLAGS").field(&self.0).finish()
@@ -360,6 +361,7 @@ pub struct BROADCAST_SYSTEM_MESSAGE_INFO(pub u32);
 impl windows_core::TypeKind for BROADCAST_SYSTEM_MESSAGE_INFO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BROADCAST_SYSTEM_MESSAGE_INFO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BROADCAST_SYSTEM_MESSAGE_INFO").field(&self.0).finish()
@@ -404,6 +406,7 @@ pub struct DESKTOP_ACCESS_FLAGS(pub u32);
 impl windows_core::TypeKind for DESKTOP_ACCESS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DESKTOP_ACCESS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DESKTOP_ACCESS_FLAGS").field(&self.0).finish()
@@ -415,6 +418,7 @@ pub struct DESKTOP_CONTROL_FLAGS(pub u32);
 impl windows_core::TypeKind for DESKTOP_CONTROL_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DESKTOP_CONTROL_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DESKTOP_CONTROL_FLAGS").field(&self.0).finish()
@@ -426,13 +430,15 @@ pub struct USER_OBJECT_INFORMATION_INDEX(pub i32);
 impl windows_core::TypeKind for USER_OBJECT_INFORMATION_INDEX {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USER_OBJECT_INFORMATION_INDEX {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USER_OBJECT_INFORMATION_INDEX").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BSMINFO {
     pub cbSize: u32,
     pub hdesk: HDESK,
@@ -448,7 +454,8 @@ impl Default for BSMINFO {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HDESK(pub *mut core::ffi::c_void);
 impl HDESK {
     pub fn is_invalid(&self) -> bool {
@@ -472,7 +479,8 @@ impl windows_core::TypeKind for HDESK {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HWINSTA(pub *mut core::ffi::c_void);
 impl HWINSTA {
     pub fn is_invalid(&self) -> bool {
@@ -496,7 +504,8 @@ impl windows_core::TypeKind for HWINSTA {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USEROBJECTFLAGS {
     pub fInherit: super::super::Foundation::BOOL,
     pub fReserved: super::super::Foundation::BOOL,
@@ -74,6 +74,7 @@ pub struct WSL_DISTRIBUTION_FLAGS(pub i32);
 impl windows_core::TypeKind for WSL_DISTRIBUTION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WSL_DISTRIBUTION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WSL_DISTRIBUTION_FLAGS").field(&self.0).finish()
@@ -753,6 +753,7 @@ pub struct COMPUTER_NAME_FORMAT(pub i32);
 impl windows_core::TypeKind for COMPUTER_NAME_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMPUTER_NAME_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMPUTER_NAME_FORMAT").field(&self.0).finish()
@@ -764,6 +765,7 @@ pub struct CPU_SET_INFORMATION_TYPE(pub i32);
 impl windows_core::TypeKind for CPU_SET_INFORMATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CPU_SET_INFORMATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CPU_SET_INFORMATION_TYPE").field(&self.0).finish()
@@ -775,6 +777,7 @@ pub struct DEP_SYSTEM_POLICY_TYPE(pub i32);
 impl windows_core::TypeKind for DEP_SYSTEM_POLICY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEP_SYSTEM_POLICY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEP_SYSTEM_POLICY_TYPE").field(&self.0).finish()
@@ -786,6 +789,7 @@ pub struct DEVELOPER_DRIVE_ENABLEMENT_STATE(pub i32);
 impl windows_core::TypeKind for DEVELOPER_DRIVE_ENABLEMENT_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEVELOPER_DRIVE_ENABLEMENT_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEVELOPER_DRIVE_ENABLEMENT_STATE").field(&self.0).finish()
@@ -797,6 +801,7 @@ pub struct DEVICEFAMILYDEVICEFORM(pub u32);
 impl windows_core::TypeKind for DEVICEFAMILYDEVICEFORM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEVICEFAMILYDEVICEFORM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEVICEFAMILYDEVICEFORM").field(&self.0).finish()
@@ -808,6 +813,7 @@ pub struct DEVICEFAMILYINFOENUM(pub u32);
 impl windows_core::TypeKind for DEVICEFAMILYINFOENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEVICEFAMILYINFOENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEVICEFAMILYINFOENUM").field(&self.0).finish()
@@ -819,6 +825,7 @@ pub struct FIRMWARE_TABLE_PROVIDER(pub u32);
 impl windows_core::TypeKind for FIRMWARE_TABLE_PROVIDER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FIRMWARE_TABLE_PROVIDER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FIRMWARE_TABLE_PROVIDER").field(&self.0).finish()
@@ -830,6 +837,7 @@ pub struct FIRMWARE_TYPE(pub i32);
 impl windows_core::TypeKind for FIRMWARE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FIRMWARE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FIRMWARE_TYPE").field(&self.0).finish()
@@ -841,6 +849,7 @@ pub struct IMAGE_FILE_MACHINE(pub u16);
 impl windows_core::TypeKind for IMAGE_FILE_MACHINE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAGE_FILE_MACHINE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAGE_FILE_MACHINE").field(&self.0).finish()
@@ -852,6 +861,7 @@ pub struct LOGICAL_PROCESSOR_RELATIONSHIP(pub i32);
 impl windows_core::TypeKind for LOGICAL_PROCESSOR_RELATIONSHIP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LOGICAL_PROCESSOR_RELATIONSHIP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LOGICAL_PROCESSOR_RELATIONSHIP").field(&self.0).finish()
@@ -863,6 +873,7 @@ pub struct OS_DEPLOYEMENT_STATE_VALUES(pub i32);
 impl windows_core::TypeKind for OS_DEPLOYEMENT_STATE_VALUES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OS_DEPLOYEMENT_STATE_VALUES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OS_DEPLOYEMENT_STATE_VALUES").field(&self.0).finish()
@@ -874,6 +885,7 @@ pub struct OS_PRODUCT_TYPE(pub u32);
 impl windows_core::TypeKind for OS_PRODUCT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OS_PRODUCT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OS_PRODUCT_TYPE").field(&self.0).finish()
@@ -885,6 +897,7 @@ pub struct PROCESSOR_ARCHITECTURE(pub u16);
 impl windows_core::TypeKind for PROCESSOR_ARCHITECTURE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROCESSOR_ARCHITECTURE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROCESSOR_ARCHITECTURE").field(&self.0).finish()
@@ -896,6 +909,7 @@ pub struct PROCESSOR_CACHE_TYPE(pub i32);
 impl windows_core::TypeKind for PROCESSOR_CACHE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROCESSOR_CACHE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROCESSOR_CACHE_TYPE").field(&self.0).finish()
@@ -907,6 +921,7 @@ pub struct RTL_SYSTEM_GLOBAL_DATA_ID(pub i32);
 impl windows_core::TypeKind for RTL_SYSTEM_GLOBAL_DATA_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTL_SYSTEM_GLOBAL_DATA_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTL_SYSTEM_GLOBAL_DATA_ID").field(&self.0).finish()
@@ -918,6 +933,7 @@ pub struct USER_CET_ENVIRONMENT(pub u32);
 impl windows_core::TypeKind for USER_CET_ENVIRONMENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USER_CET_ENVIRONMENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USER_CET_ENVIRONMENT").field(&self.0).finish()
@@ -929,6 +945,7 @@ pub struct VER_FLAGS(pub u32);
 impl windows_core::TypeKind for VER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VER_FLAGS").field(&self.0).finish()
@@ -968,7 +985,8 @@ impl core::ops::Not for VER_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CACHE_DESCRIPTOR {
     pub Level: u8,
     pub Associativity: u8,
@@ -1019,7 +1037,8 @@ impl Default for CACHE_RELATIONSHIP_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GROUP_AFFINITY {
     pub Mask: usize,
     pub Group: u16,
@@ -1034,7 +1053,8 @@ impl Default for GROUP_AFFINITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GROUP_RELATIONSHIP {
     pub MaximumGroupCount: u16,
     pub ActiveGroupCount: u16,
@@ -1050,7 +1070,8 @@ impl Default for GROUP_RELATIONSHIP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MEMORYSTATUS {
     pub dwLength: u32,
     pub dwMemoryLoad: u32,
@@ -1070,7 +1091,8 @@ impl Default for MEMORYSTATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MEMORYSTATUSEX {
     pub dwLength: u32,
     pub dwMemoryLoad: u32,
@@ -1121,7 +1143,8 @@ impl Default for NUMA_NODE_RELATIONSHIP_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OSVERSIONINFOA {
     pub dwOSVersionInfoSize: u32,
     pub dwMajorVersion: u32,
@@ -1139,7 +1162,8 @@ impl Default for OSVERSIONINFOA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OSVERSIONINFOEXA {
     pub dwOSVersionInfoSize: u32,
     pub dwMajorVersion: u32,
@@ -1162,7 +1186,8 @@ impl Default for OSVERSIONINFOEXA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OSVERSIONINFOEXW {
     pub dwOSVersionInfoSize: u32,
     pub dwMajorVersion: u32,
@@ -1185,7 +1210,8 @@ impl Default for OSVERSIONINFOEXW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OSVERSIONINFOW {
     pub dwOSVersionInfoSize: u32,
     pub dwMajorVersion: u32,
@@ -1203,7 +1229,8 @@ impl Default for OSVERSIONINFOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESSOR_GROUP_INFO {
     pub MaximumProcessorCount: u8,
     pub ActiveProcessorCount: u8,
@@ -1219,7 +1246,8 @@ impl Default for PROCESSOR_GROUP_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESSOR_RELATIONSHIP {
     pub Flags: u8,
     pub EfficiencyClass: u8,
@@ -1300,10 +1328,43 @@ impl Default for SYSTEM_CPU_SET_INFORMATION_0_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYSTEM_CPU_SET_INFORMATION_0_0_0_0 {
     pub _bitfield: u8,
 }
+impl SYSTEM_CPU_SET_INFORMATION_0_0_0_0 {
+    pub const fn Parked(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_Parked(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Allocated(&self) -> u8 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Allocated(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn AllocatedToTargetProcess(&self) -> u8 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_AllocatedToTargetProcess(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn RealTime(&self) -> u8 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_RealTime(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn ReservedFlags(&self) -> u8 {
+        (self._bitfield >> 4) & 15
+    }
+    pub fn set_ReservedFlags(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(15 << 4)) | ((value & 15) << 4);
+    }
+}
 impl windows_core::TypeKind for SYSTEM_CPU_SET_INFORMATION_0_0_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -1363,7 +1424,8 @@ impl Default for SYSTEM_INFO_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYSTEM_INFO_0_0 {
     pub wProcessorArchitecture: PROCESSOR_ARCHITECTURE,
     pub wReserved: u16,
@@ -1408,7 +1470,8 @@ impl Default for SYSTEM_LOGICAL_PROCESSOR_INFORMATION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYSTEM_LOGICAL_PROCESSOR_INFORMATION_0_0 {
     pub NodeNumber: u32,
 }
@@ -1421,7 +1484,8 @@ impl Default for SYSTEM_LOGICAL_PROCESSOR_INFORMATION_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYSTEM_LOGICAL_PROCESSOR_INFORMATION_0_1 {
     pub Flags: u8,
 }
@@ -1465,7 +1529,8 @@ impl Default for SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYSTEM_POOL_ZEROING_INFORMATION {
     pub PoolZeroingSupportPresent: super::super::Foundation::BOOLEAN,
 }
@@ -1478,7 +1543,8 @@ impl Default for SYSTEM_POOL_ZEROING_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYSTEM_PROCESSOR_CYCLE_TIME_INFORMATION {
     pub CycleTime: u64,
 }
@@ -1491,10 +1557,55 @@ impl Default for SYSTEM_PROCESSOR_CYCLE_TIME_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYSTEM_SUPPORTED_PROCESSOR_ARCHITECTURES_INFORMATION {
     pub _bitfield: u32,
 }
+impl SYSTEM_SUPPORTED_PROCESSOR_ARCHITECTURES_INFORMATION {
+    pub const fn Machine(&self) -> u32 {
+        self._bitfield & 65535
+    }
+    pub fn set_Machine(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn KernelMode(&self) -> u32 {
+        (self._bitfield >> 16) & 1
+    }
+    pub fn set_KernelMode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn UserMode(&self) -> u32 {
+        (self._bitfield >> 17) & 1
+    }
+    pub fn set_UserMode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 17)) | ((value & 1) << 17);
+    }
+    pub const fn Native(&self) -> u32 {
+        (self._bitfield >> 18) & 1
+    }
+    pub fn set_Native(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 18)) | ((value & 1) << 18);
+    }
+    pub const fn Process(&self) -> u32 {
+        (self._bitfield >> 19) & 1
+    }
+    pub fn set_Process(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 19)) | ((value & 1) << 19);
+    }
+    pub const fn WoW64Container(&self) -> u32 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_WoW64Container(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn ReservedZero0(&self) -> u32 {
+        (self._bitfield >> 21) & 2047
+    }
+    pub fn set_ReservedZero0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2047 << 21)) | ((value & 2047) << 21);
+    }
+}
 impl windows_core::TypeKind for SYSTEM_SUPPORTED_PROCESSOR_ARCHITECTURES_INFORMATION {
     type TypeKind = windows_core::CopyType;
 }
@@ -2613,6 +2613,7 @@ pub struct ACCESS_REASON_TYPE(pub i32);
 impl windows_core::TypeKind for ACCESS_REASON_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ACCESS_REASON_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ACCESS_REASON_TYPE").field(&self.0).finish()
@@ -2624,6 +2625,7 @@ pub struct ACTIVATION_CONTEXT_INFO_CLASS(pub i32);
 impl windows_core::TypeKind for ACTIVATION_CONTEXT_INFO_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ACTIVATION_CONTEXT_INFO_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ACTIVATION_CONTEXT_INFO_CLASS").field(&self.0).finish()
@@ -2635,6 +2637,7 @@ pub struct ALERT_SYSTEM_SEV(pub u32);
 impl windows_core::TypeKind for ALERT_SYSTEM_SEV {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ALERT_SYSTEM_SEV {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ALERT_SYSTEM_SEV").field(&self.0).finish()
@@ -2646,6 +2649,7 @@ pub struct APPCOMMAND_ID(pub u32);
 impl windows_core::TypeKind for APPCOMMAND_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for APPCOMMAND_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("APPCOMMAND_ID").field(&self.0).finish()
@@ -2657,6 +2661,7 @@ pub struct ARM64_FNPDATA_CR(pub i32);
 impl windows_core::TypeKind for ARM64_FNPDATA_CR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ARM64_FNPDATA_CR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ARM64_FNPDATA_CR").field(&self.0).finish()
@@ -2668,6 +2673,7 @@ pub struct ARM64_FNPDATA_FLAGS(pub i32);
 impl windows_core::TypeKind for ARM64_FNPDATA_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ARM64_FNPDATA_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ARM64_FNPDATA_FLAGS").field(&self.0).finish()
@@ -2679,6 +2685,7 @@ pub struct ATF_FLAGS(pub u32);
 impl windows_core::TypeKind for ATF_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ATF_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ATF_FLAGS").field(&self.0).finish()
@@ -2723,6 +2730,7 @@ pub struct CFE_UNDERLINE(pub u32);
 impl windows_core::TypeKind for CFE_UNDERLINE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CFE_UNDERLINE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CFE_UNDERLINE").field(&self.0).finish()
@@ -2767,6 +2775,7 @@ pub struct ENLISTMENT_INFORMATION_CLASS(pub i32);
 impl windows_core::TypeKind for ENLISTMENT_INFORMATION_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ENLISTMENT_INFORMATION_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ENLISTMENT_INFORMATION_CLASS").field(&self.0).finish()
@@ -2778,6 +2787,7 @@ pub struct GESTURECONFIG_FLAGS(pub u32);
 impl windows_core::TypeKind for GESTURECONFIG_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GESTURECONFIG_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GESTURECONFIG_FLAGS").field(&self.0).finish()
@@ -2822,6 +2832,7 @@ pub struct HIBERFILE_BUCKET_SIZE(pub i32);
 impl windows_core::TypeKind for HIBERFILE_BUCKET_SIZE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HIBERFILE_BUCKET_SIZE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HIBERFILE_BUCKET_SIZE").field(&self.0).finish()
@@ -2833,6 +2844,7 @@ pub struct IGP_ID(pub u32);
 impl windows_core::TypeKind for IGP_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IGP_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IGP_ID").field(&self.0).finish()
@@ -2844,6 +2856,7 @@ pub struct IMAGE_AUX_SYMBOL_TYPE(pub i32);
 impl windows_core::TypeKind for IMAGE_AUX_SYMBOL_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAGE_AUX_SYMBOL_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAGE_AUX_SYMBOL_TYPE").field(&self.0).finish()
@@ -2855,6 +2868,7 @@ pub struct IMAGE_POLICY_ENTRY_TYPE(pub i32);
 impl windows_core::TypeKind for IMAGE_POLICY_ENTRY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAGE_POLICY_ENTRY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAGE_POLICY_ENTRY_TYPE").field(&self.0).finish()
@@ -2866,6 +2880,7 @@ pub struct IMAGE_POLICY_ID(pub i32);
 impl windows_core::TypeKind for IMAGE_POLICY_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAGE_POLICY_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAGE_POLICY_ID").field(&self.0).finish()
@@ -2877,6 +2892,7 @@ pub struct IMPORT_OBJECT_NAME_TYPE(pub i32);
 impl windows_core::TypeKind for IMPORT_OBJECT_NAME_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMPORT_OBJECT_NAME_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMPORT_OBJECT_NAME_TYPE").field(&self.0).finish()
@@ -2888,6 +2904,7 @@ pub struct IMPORT_OBJECT_TYPE(pub i32);
 impl windows_core::TypeKind for IMPORT_OBJECT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMPORT_OBJECT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMPORT_OBJECT_TYPE").field(&self.0).finish()
@@ -2899,6 +2916,7 @@ pub struct KTMOBJECT_TYPE(pub i32);
 impl windows_core::TypeKind for KTMOBJECT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KTMOBJECT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KTMOBJECT_TYPE").field(&self.0).finish()
@@ -2910,6 +2928,7 @@ pub struct MODIFIERKEYS_FLAGS(pub u32);
 impl windows_core::TypeKind for MODIFIERKEYS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MODIFIERKEYS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MODIFIERKEYS_FLAGS").field(&self.0).finish()
@@ -2954,6 +2973,7 @@ pub struct MONITOR_DISPLAY_STATE(pub i32);
 impl windows_core::TypeKind for MONITOR_DISPLAY_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MONITOR_DISPLAY_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MONITOR_DISPLAY_STATE").field(&self.0).finish()
@@ -2965,6 +2985,7 @@ pub struct RECO_FLAGS(pub u32);
 impl windows_core::TypeKind for RECO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RECO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RECO_FLAGS").field(&self.0).finish()
@@ -3009,6 +3030,7 @@ pub struct RESOURCEMANAGER_INFORMATION_CLASS(pub i32);
 impl windows_core::TypeKind for RESOURCEMANAGER_INFORMATION_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RESOURCEMANAGER_INFORMATION_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RESOURCEMANAGER_INFORMATION_CLASS").field(&self.0).finish()
@@ -3020,6 +3042,7 @@ pub struct RTL_UMS_SCHEDULER_REASON(pub i32);
 impl windows_core::TypeKind for RTL_UMS_SCHEDULER_REASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTL_UMS_SCHEDULER_REASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTL_UMS_SCHEDULER_REASON").field(&self.0).finish()
@@ -3031,6 +3054,7 @@ pub struct ReplacesCorHdrNumericDefines(pub i32);
 impl windows_core::TypeKind for ReplacesCorHdrNumericDefines {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ReplacesCorHdrNumericDefines {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ReplacesCorHdrNumericDefines").field(&self.0).finish()
@@ -3042,6 +3066,7 @@ pub struct SERVERSILO_STATE(pub i32);
 impl windows_core::TypeKind for SERVERSILO_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SERVERSILO_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SERVERSILO_STATE").field(&self.0).finish()
@@ -3053,6 +3078,7 @@ pub struct SERVICE_ERROR_TYPE(pub i32);
 impl windows_core::TypeKind for SERVICE_ERROR_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SERVICE_ERROR_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SERVICE_ERROR_TYPE").field(&self.0).finish()
@@ -3064,6 +3090,7 @@ pub struct SERVICE_LOAD_TYPE(pub i32);
 impl windows_core::TypeKind for SERVICE_LOAD_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SERVICE_LOAD_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SERVICE_LOAD_TYPE").field(&self.0).finish()
@@ -3075,6 +3102,7 @@ pub struct SERVICE_NODE_TYPE(pub i32);
 impl windows_core::TypeKind for SERVICE_NODE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SERVICE_NODE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SERVICE_NODE_TYPE").field(&self.0).finish()
@@ -3086,6 +3114,7 @@ pub struct SE_IMAGE_SIGNATURE_TYPE(pub i32);
 impl windows_core::TypeKind for SE_IMAGE_SIGNATURE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SE_IMAGE_SIGNATURE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SE_IMAGE_SIGNATURE_TYPE").field(&self.0).finish()
@@ -3097,6 +3126,7 @@ pub struct SFGAO_FLAGS(pub u32);
 impl windows_core::TypeKind for SFGAO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SFGAO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SFGAO_FLAGS").field(&self.0).finish()
@@ -3141,6 +3171,7 @@ pub struct STATIC_STYLES(pub u32);
 impl windows_core::TypeKind for STATIC_STYLES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STATIC_STYLES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STATIC_STYLES").field(&self.0).finish()
@@ -3152,6 +3183,7 @@ pub struct SharedVirtualDiskHandleState(pub i32);
 impl windows_core::TypeKind for SharedVirtualDiskHandleState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SharedVirtualDiskHandleState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SharedVirtualDiskHandleState").field(&self.0).finish()
@@ -3163,6 +3195,7 @@ pub struct SharedVirtualDiskSupportType(pub i32);
 impl windows_core::TypeKind for SharedVirtualDiskSupportType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SharedVirtualDiskSupportType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SharedVirtualDiskSupportType").field(&self.0).finish()
@@ -3174,6 +3207,7 @@ pub struct TAPE_DRIVE_PROBLEM_TYPE(pub i32);
 impl windows_core::TypeKind for TAPE_DRIVE_PROBLEM_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TAPE_DRIVE_PROBLEM_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TAPE_DRIVE_PROBLEM_TYPE").field(&self.0).finish()
@@ -3185,6 +3219,7 @@ pub struct TAPE_GET_DRIVE_PARAMETERS_FEATURES_HIGH(pub u32);
 impl windows_core::TypeKind for TAPE_GET_DRIVE_PARAMETERS_FEATURES_HIGH {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TAPE_GET_DRIVE_PARAMETERS_FEATURES_HIGH {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TAPE_GET_DRIVE_PARAMETERS_FEATURES_HIGH").field(&self.0).finish()
@@ -3229,6 +3264,7 @@ pub struct TRANSACTIONMANAGER_INFORMATION_CLASS(pub i32);
 impl windows_core::TypeKind for TRANSACTIONMANAGER_INFORMATION_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TRANSACTIONMANAGER_INFORMATION_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TRANSACTIONMANAGER_INFORMATION_CLASS").field(&self.0).finish()
@@ -3240,6 +3276,7 @@ pub struct TRANSACTION_INFORMATION_CLASS(pub i32);
 impl windows_core::TypeKind for TRANSACTION_INFORMATION_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TRANSACTION_INFORMATION_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TRANSACTION_INFORMATION_CLASS").field(&self.0).finish()
@@ -3251,6 +3288,7 @@ pub struct TRANSACTION_STATE(pub i32);
 impl windows_core::TypeKind for TRANSACTION_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TRANSACTION_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TRANSACTION_STATE").field(&self.0).finish()
@@ -3262,6 +3300,7 @@ pub struct WORD_WHEEL_OPEN_FLAGS(pub u32);
 impl windows_core::TypeKind for WORD_WHEEL_OPEN_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WORD_WHEEL_OPEN_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WORD_WHEEL_OPEN_FLAGS").field(&self.0).finish()
@@ -3301,7 +3340,8 @@ impl core::ops::Not for WORD_WHEEL_OPEN_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ANON_OBJECT_HEADER {
     pub Sig1: u16,
     pub Sig2: u16,
@@ -3320,7 +3360,8 @@ impl Default for ANON_OBJECT_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ANON_OBJECT_HEADER_BIGOBJ {
     pub Sig1: u16,
     pub Sig2: u16,
@@ -3345,7 +3386,8 @@ impl Default for ANON_OBJECT_HEADER_BIGOBJ {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ANON_OBJECT_HEADER_V2 {
     pub Sig1: u16,
     pub Sig2: u16,
@@ -3367,7 +3409,8 @@ impl Default for ANON_OBJECT_HEADER_V2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct APPLICATIONLAUNCH_SETTING_VALUE {
     pub ActivationTime: i64,
     pub Flags: u32,
@@ -3382,7 +3425,8 @@ impl Default for APPLICATIONLAUNCH_SETTING_VALUE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COMPONENT_FILTER {
     pub ComponentFlags: u32,
 }
@@ -3409,7 +3453,8 @@ impl Default for DISPATCHER_CONTEXT_NONVOLREG_ARM64 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DISPATCHER_CONTEXT_NONVOLREG_ARM64_0 {
     pub GpNvRegs: [u64; 11],
     pub FpNvRegs: [f64; 8],
@@ -3423,7 +3468,8 @@ impl Default for DISPATCHER_CONTEXT_NONVOLREG_ARM64_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ENLISTMENT_BASIC_INFORMATION {
     pub EnlistmentId: windows_core::GUID,
     pub TransactionId: windows_core::GUID,
@@ -3438,7 +3484,8 @@ impl Default for ENLISTMENT_BASIC_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ENLISTMENT_CRM_INFORMATION {
     pub CrmTransactionManagerId: windows_core::GUID,
     pub CrmResourceManagerId: windows_core::GUID,
@@ -3529,7 +3576,8 @@ impl Default for GDI_NONREMOTE_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HEAP_OPTIMIZE_RESOURCES_INFORMATION {
     pub Version: u32,
     pub Flags: u32,
@@ -3543,7 +3591,8 @@ impl Default for HEAP_OPTIMIZE_RESOURCES_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HIBERFILE_BUCKET {
     pub MaxPhysicalMemory: u64,
     pub PhysicalMemoryPercent: [u32; 3],
@@ -3574,7 +3623,8 @@ impl Default for IMAGE_ALPHA64_RUNTIME_FUNCTION_ENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_ALPHA_RUNTIME_FUNCTION_ENTRY {
     pub BeginAddress: u32,
     pub EndAddress: u32,
@@ -3591,7 +3641,8 @@ impl Default for IMAGE_ALPHA_RUNTIME_FUNCTION_ENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_ARCHITECTURE_ENTRY {
     pub FixupInstRVA: u32,
     pub NewInst: u32,
@@ -3605,11 +3656,38 @@ impl Default for IMAGE_ARCHITECTURE_ENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_ARCHITECTURE_HEADER {
     pub _bitfield: u32,
     pub FirstEntryRVA: u32,
 }
+impl IMAGE_ARCHITECTURE_HEADER {
+    pub const fn AmaskValue(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_AmaskValue(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Anonymous1(&self) -> u32 {
+        (self._bitfield >> 1) & 127
+    }
+    pub fn set_Anonymous1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(127 << 1)) | ((value & 127) << 1);
+    }
+    pub const fn AmaskShift(&self) -> u32 {
+        (self._bitfield >> 8) & 255
+    }
+    pub fn set_AmaskShift(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 8)) | ((value & 255) << 8);
+    }
+    pub const fn Anonymous2(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_Anonymous2(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for IMAGE_ARCHITECTURE_HEADER {
     type TypeKind = windows_core::CopyType;
 }
@@ -3619,7 +3697,8 @@ impl Default for IMAGE_ARCHITECTURE_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_ARCHIVE_MEMBER_HEADER {
     pub Name: [u8; 16],
     pub Date: [u8; 12],
@@ -3652,10 +3731,49 @@ impl Default for IMAGE_ARM64_RUNTIME_FUNCTION_ENTRY_XDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_ARM64_RUNTIME_FUNCTION_ENTRY_XDATA_0 {
     pub _bitfield: u32,
 }
+impl IMAGE_ARM64_RUNTIME_FUNCTION_ENTRY_XDATA_0 {
+    pub const fn FunctionLength(&self) -> u32 {
+        self._bitfield & 262143
+    }
+    pub fn set_FunctionLength(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !262143) | (value & 262143);
+    }
+    pub const fn Version(&self) -> u32 {
+        (self._bitfield >> 18) & 3
+    }
+    pub fn set_Version(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 18)) | ((value & 3) << 18);
+    }
+    pub const fn ExceptionDataPresent(&self) -> u32 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_ExceptionDataPresent(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn EpilogInHeader(&self) -> u32 {
+        (self._bitfield >> 21) & 1
+    }
+    pub fn set_EpilogInHeader(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 21)) | ((value & 1) << 21);
+    }
+    pub const fn EpilogCount(&self) -> u32 {
+        (self._bitfield >> 22) & 31
+    }
+    pub fn set_EpilogCount(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 22)) | ((value & 31) << 22);
+    }
+    pub const fn CodeWords(&self) -> u32 {
+        (self._bitfield >> 27) & 31
+    }
+    pub fn set_CodeWords(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(31 << 27)) | ((value & 31) << 27);
+    }
+}
 impl windows_core::TypeKind for IMAGE_ARM64_RUNTIME_FUNCTION_ENTRY_XDATA_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3693,10 +3811,67 @@ impl Default for IMAGE_ARM_RUNTIME_FUNCTION_ENTRY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_ARM_RUNTIME_FUNCTION_ENTRY_0_0 {
     pub _bitfield: u32,
 }
+impl IMAGE_ARM_RUNTIME_FUNCTION_ENTRY_0_0 {
+    pub const fn Flag(&self) -> u32 {
+        self._bitfield & 3
+    }
+    pub fn set_Flag(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn FunctionLength(&self) -> u32 {
+        (self._bitfield >> 2) & 2047
+    }
+    pub fn set_FunctionLength(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2047 << 2)) | ((value & 2047) << 2);
+    }
+    pub const fn Ret(&self) -> u32 {
+        (self._bitfield >> 13) & 3
+    }
+    pub fn set_Ret(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(3 << 13)) | ((value & 3) << 13);
+    }
+    pub const fn H(&self) -> u32 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_H(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+    pub const fn Reg(&self) -> u32 {
+        (self._bitfield >> 16) & 7
+    }
+    pub fn set_Reg(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(7 << 16)) | ((value & 7) << 16);
+    }
+    pub const fn R(&self) -> u32 {
+        (self._bitfield >> 19) & 1
+    }
+    pub fn set_R(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 19)) | ((value & 1) << 19);
+    }
+    pub const fn L(&self) -> u32 {
+        (self._bitfield >> 20) & 1
+    }
+    pub fn set_L(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 20)) | ((value & 1) << 20);
+    }
+    pub const fn C(&self) -> u32 {
+        (self._bitfield >> 21) & 1
+    }
+    pub fn set_C(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 21)) | ((value & 1) << 21);
+    }
+    pub const fn StackAdjust(&self) -> u32 {
+        (self._bitfield >> 22) & 1023
+    }
+    pub fn set_StackAdjust(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1023 << 22)) | ((value & 1023) << 22);
+    }
+}
 impl windows_core::TypeKind for IMAGE_ARM_RUNTIME_FUNCTION_ENTRY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -3737,7 +3912,8 @@ impl Default for IMAGE_AUX_SYMBOL_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_AUX_SYMBOL_1 {
     pub Name: [u8; 18],
 }
@@ -3800,7 +3976,8 @@ impl Default for IMAGE_AUX_SYMBOL_3_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_AUX_SYMBOL_3_0_0 {
     pub Dimension: [u16; 4],
 }
@@ -3841,7 +4018,8 @@ impl Default for IMAGE_AUX_SYMBOL_3_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_AUX_SYMBOL_3_1_0 {
     pub Linenumber: u16,
     pub Size: u16,
@@ -3900,7 +4078,8 @@ impl Default for IMAGE_AUX_SYMBOL_EX_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_AUX_SYMBOL_EX_2 {
     pub Name: [u8; 20],
 }
@@ -3965,7 +4144,8 @@ impl Default for IMAGE_AUX_SYMBOL_TOKEN_DEF {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_BASE_RELOCATION {
     pub VirtualAddress: u32,
     pub SizeOfBlock: u32,
@@ -4008,7 +4188,8 @@ impl Default for IMAGE_BDD_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_BOUND_FORWARDER_REF {
     pub TimeDateStamp: u32,
     pub OffsetModuleName: u16,
@@ -4023,7 +4204,8 @@ impl Default for IMAGE_BOUND_FORWARDER_REF {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_BOUND_IMPORT_DESCRIPTOR {
     pub TimeDateStamp: u32,
     pub OffsetModuleName: u16,
@@ -4038,11 +4220,38 @@ impl Default for IMAGE_BOUND_IMPORT_DESCRIPTOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_CE_RUNTIME_FUNCTION_ENTRY {
     pub FuncStart: u32,
     pub _bitfield: u32,
 }
+impl IMAGE_CE_RUNTIME_FUNCTION_ENTRY {
+    pub const fn PrologLen(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_PrologLen(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn FuncLen(&self) -> u32 {
+        (self._bitfield >> 8) & 4194303
+    }
+    pub fn set_FuncLen(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(4194303 << 8)) | ((value & 4194303) << 8);
+    }
+    pub const fn ThirtyTwoBit(&self) -> u32 {
+        (self._bitfield >> 30) & 1
+    }
+    pub fn set_ThirtyTwoBit(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 30)) | ((value & 1) << 30);
+    }
+    pub const fn ExceptionFlag(&self) -> u32 {
+        (self._bitfield >> 31) & 1
+    }
+    pub fn set_ExceptionFlag(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 31)) | ((value & 1) << 31);
+    }
+}
 impl windows_core::TypeKind for IMAGE_CE_RUNTIME_FUNCTION_ENTRY {
     type TypeKind = windows_core::CopyType;
 }
@@ -4052,7 +4261,8 @@ impl Default for IMAGE_CE_RUNTIME_FUNCTION_ENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_DEBUG_MISC {
     pub DataType: u32,
     pub Length: u32,
@@ -4162,7 +4372,8 @@ impl Default for IMAGE_DYNAMIC_RELOCATION64_V2 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_DYNAMIC_RELOCATION_TABLE {
     pub Version: u32,
     pub Size: u32,
@@ -4192,7 +4403,8 @@ impl Default for IMAGE_EPILOGUE_DYNAMIC_RELOCATION_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_EXPORT_DIRECTORY {
     pub Characteristics: u32,
     pub TimeDateStamp: u32,
@@ -4244,7 +4456,8 @@ impl Default for IMAGE_FUNCTION_OVERRIDE_HEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_HOT_PATCH_BASE {
     pub SequenceNumber: u32,
     pub Flags: u32,
@@ -4264,7 +4477,8 @@ impl Default for IMAGE_HOT_PATCH_BASE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_HOT_PATCH_HASHES {
     pub SHA256: [u8; 32],
     pub SHA1: [u8; 20],
@@ -4278,7 +4492,8 @@ impl Default for IMAGE_HOT_PATCH_HASHES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_HOT_PATCH_INFO {
     pub Version: u32,
     pub Size: u32,
@@ -4297,7 +4512,8 @@ impl Default for IMAGE_HOT_PATCH_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_IMPORT_BY_NAME {
     pub Hint: u16,
     pub Name: [i8; 1],
@@ -4315,6 +4531,26 @@ impl Default for IMAGE_IMPORT_BY_NAME {
 pub struct IMAGE_IMPORT_CONTROL_TRANSFER_DYNAMIC_RELOCATION {
     pub _bitfield: u32,
 }
+impl IMAGE_IMPORT_CONTROL_TRANSFER_DYNAMIC_RELOCATION {
+    pub const fn PageRelativeOffset(&self) -> u32 {
+        self._bitfield & 4095
+    }
+    pub fn set_PageRelativeOffset(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !4095) | (value & 4095);
+    }
+    pub const fn IndirectCall(&self) -> u32 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_IndirectCall(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn IATIndex(&self) -> u32 {
+        (self._bitfield >> 13) & 524287
+    }
+    pub fn set_IATIndex(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(524287 << 13)) | ((value & 524287) << 13);
+    }
+}
 impl windows_core::TypeKind for IMAGE_IMPORT_CONTROL_TRANSFER_DYNAMIC_RELOCATION {
     type TypeKind = windows_core::CopyType;
 }
@@ -4359,6 +4595,38 @@ impl Default for IMAGE_IMPORT_DESCRIPTOR_0 {
 pub struct IMAGE_INDIR_CONTROL_TRANSFER_DYNAMIC_RELOCATION {
     pub _bitfield: u16,
 }
+impl IMAGE_INDIR_CONTROL_TRANSFER_DYNAMIC_RELOCATION {
+    pub const fn PageRelativeOffset(&self) -> u16 {
+        self._bitfield & 4095
+    }
+    pub fn set_PageRelativeOffset(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !4095) | (value & 4095);
+    }
+    pub const fn IndirectCall(&self) -> u16 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_IndirectCall(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn RexWPrefix(&self) -> u16 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_RexWPrefix(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn CfgCheck(&self) -> u16 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_CfgCheck(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for IMAGE_INDIR_CONTROL_TRANSFER_DYNAMIC_RELOCATION {
     type TypeKind = windows_core::CopyType;
 }
@@ -4493,7 +4761,8 @@ impl Default for IMAGE_POLICY_METADATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_PROLOGUE_DYNAMIC_RELOCATION_HEADER {
     pub PrologueByteCount: u8,
 }
@@ -4535,7 +4804,8 @@ impl Default for IMAGE_RELOCATION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_RESOURCE_DATA_ENTRY {
     pub OffsetToData: u32,
     pub Size: u32,
@@ -4551,7 +4821,8 @@ impl Default for IMAGE_RESOURCE_DATA_ENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_RESOURCE_DIRECTORY {
     pub Characteristics: u32,
     pub TimeDateStamp: u32,
@@ -4598,10 +4869,25 @@ impl Default for IMAGE_RESOURCE_DIRECTORY_ENTRY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_RESOURCE_DIRECTORY_ENTRY_0_0 {
     pub _bitfield: u32,
 }
+impl IMAGE_RESOURCE_DIRECTORY_ENTRY_0_0 {
+    pub const fn NameOffset(&self) -> u32 {
+        self._bitfield & 2147483647
+    }
+    pub fn set_NameOffset(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !2147483647) | (value & 2147483647);
+    }
+    pub const fn NameIsString(&self) -> u32 {
+        (self._bitfield >> 31) & 1
+    }
+    pub fn set_NameIsString(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 31)) | ((value & 1) << 31);
+    }
+}
 impl windows_core::TypeKind for IMAGE_RESOURCE_DIRECTORY_ENTRY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4625,10 +4911,25 @@ impl Default for IMAGE_RESOURCE_DIRECTORY_ENTRY_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_RESOURCE_DIRECTORY_ENTRY_1_0 {
     pub _bitfield: u32,
 }
+impl IMAGE_RESOURCE_DIRECTORY_ENTRY_1_0 {
+    pub const fn OffsetToDirectory(&self) -> u32 {
+        self._bitfield & 2147483647
+    }
+    pub fn set_OffsetToDirectory(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !2147483647) | (value & 2147483647);
+    }
+    pub const fn DataIsDirectory(&self) -> u32 {
+        (self._bitfield >> 31) & 1
+    }
+    pub fn set_DataIsDirectory(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 31)) | ((value & 1) << 31);
+    }
+}
 impl windows_core::TypeKind for IMAGE_RESOURCE_DIRECTORY_ENTRY_1_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4638,7 +4939,8 @@ impl Default for IMAGE_RESOURCE_DIRECTORY_ENTRY_1_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_RESOURCE_DIRECTORY_STRING {
     pub Length: u16,
     pub NameString: [i8; 1],
@@ -4652,7 +4954,8 @@ impl Default for IMAGE_RESOURCE_DIRECTORY_STRING {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_RESOURCE_DIR_STRING_U {
     pub Length: u16,
     pub NameString: [u16; 1],
@@ -4666,7 +4969,8 @@ impl Default for IMAGE_RESOURCE_DIR_STRING_U {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_SEPARATE_DEBUG_HEADER {
     pub Signature: u16,
     pub Flags: u16,
@@ -4695,6 +4999,20 @@ impl Default for IMAGE_SEPARATE_DEBUG_HEADER {
 pub struct IMAGE_SWITCHTABLE_BRANCH_DYNAMIC_RELOCATION {
     pub _bitfield: u16,
 }
+impl IMAGE_SWITCHTABLE_BRANCH_DYNAMIC_RELOCATION {
+    pub const fn PageRelativeOffset(&self) -> u16 {
+        self._bitfield & 4095
+    }
+    pub fn set_PageRelativeOffset(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !4095) | (value & 4095);
+    }
+    pub const fn RegisterNumber(&self) -> u16 {
+        (self._bitfield >> 12) & 15
+    }
+    pub fn set_RegisterNumber(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(15 << 12)) | ((value & 15) << 12);
+    }
+}
 impl windows_core::TypeKind for IMAGE_SWITCHTABLE_BRANCH_DYNAMIC_RELOCATION {
     type TypeKind = windows_core::CopyType;
 }
@@ -4830,10 +5148,31 @@ impl Default for IMAGE_TLS_DIRECTORY32_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_TLS_DIRECTORY32_0_0 {
     pub _bitfield: u32,
 }
+impl IMAGE_TLS_DIRECTORY32_0_0 {
+    pub const fn Reserved0(&self) -> u32 {
+        self._bitfield & 1048575
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1048575) | (value & 1048575);
+    }
+    pub const fn Alignment(&self) -> u32 {
+        (self._bitfield >> 20) & 15
+    }
+    pub fn set_Alignment(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 20)) | ((value & 15) << 20);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for IMAGE_TLS_DIRECTORY32_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4875,10 +5214,31 @@ impl Default for IMAGE_TLS_DIRECTORY64_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_TLS_DIRECTORY64_0_0 {
     pub _bitfield: u32,
 }
+impl IMAGE_TLS_DIRECTORY64_0_0 {
+    pub const fn Reserved0(&self) -> u32 {
+        self._bitfield & 1048575
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1048575) | (value & 1048575);
+    }
+    pub const fn Alignment(&self) -> u32 {
+        (self._bitfield >> 20) & 15
+    }
+    pub fn set_Alignment(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 20)) | ((value & 15) << 20);
+    }
+    pub const fn Reserved1(&self) -> u32 {
+        (self._bitfield >> 24) & 255
+    }
+    pub fn set_Reserved1(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
 impl windows_core::TypeKind for IMAGE_TLS_DIRECTORY64_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4962,6 +5322,26 @@ pub struct IMPORT_OBJECT_HEADER {
     pub Anonymous: IMPORT_OBJECT_HEADER_0,
     pub _bitfield: u16,
 }
+impl IMPORT_OBJECT_HEADER {
+    pub const fn Type(&self) -> u16 {
+        self._bitfield & 3
+    }
+    pub fn set_Type(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn NameType(&self) -> u16 {
+        (self._bitfield >> 2) & 7
+    }
+    pub fn set_NameType(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(7 << 2)) | ((value & 7) << 2);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 5) & 2047
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(2047 << 5)) | ((value & 2047) << 5);
+    }
+}
 impl windows_core::TypeKind for IMPORT_OBJECT_HEADER {
     type TypeKind = windows_core::CopyType;
 }
@@ -5016,10 +5396,31 @@ impl Default for KERNEL_CET_CONTEXT_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KERNEL_CET_CONTEXT_0_0 {
     pub _bitfield: u16,
 }
+impl KERNEL_CET_CONTEXT_0_0 {
+    pub const fn UseWrss(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_UseWrss(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn PopShadowStackOne(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_PopShadowStackOne(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Unused(&self) -> u16 {
+        (self._bitfield >> 2) & 16383
+    }
+    pub fn set_Unused(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(16383 << 2)) | ((value & 16383) << 2);
+    }
+}
 impl windows_core::TypeKind for KERNEL_CET_CONTEXT_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5029,7 +5430,8 @@ impl Default for KERNEL_CET_CONTEXT_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KTMOBJECT_CURSOR {
     pub LastQuery: windows_core::GUID,
     pub ObjectIdCount: u32,
@@ -5044,7 +5446,8 @@ impl Default for KTMOBJECT_CURSOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MAXVERSIONTESTED_INFO {
     pub MaxVersionTested: u64,
 }
@@ -5057,7 +5460,8 @@ impl Default for MAXVERSIONTESTED_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NETWORK_APP_INSTANCE_EA {
     pub AppInstanceID: windows_core::GUID,
     pub CsvFlags: u32,
@@ -5092,7 +5496,8 @@ impl Default for NON_PAGED_DEBUG_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NOTIFY_USER_POWER_SETTING {
     pub Guid: windows_core::GUID,
 }
@@ -5171,7 +5576,8 @@ impl Default for NT_TIB64_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PACKEDEVENTINFO {
     pub ulSize: u32,
     pub ulNumEventsForLogFile: u32,
@@ -5186,7 +5592,8 @@ impl Default for PACKEDEVENTINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESSOR_IDLESTATE_INFO {
     pub TimeCheck: u32,
     pub DemotePercent: u8,
@@ -5232,10 +5639,31 @@ impl Default for PROCESSOR_IDLESTATE_POLICY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESSOR_IDLESTATE_POLICY_0_0 {
     pub _bitfield: u16,
 }
+impl PROCESSOR_IDLESTATE_POLICY_0_0 {
+    pub const fn AllowScaling(&self) -> u16 {
+        self._bitfield & 1
+    }
+    pub fn set_AllowScaling(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Disabled(&self) -> u16 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Disabled(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Reserved(&self) -> u16 {
+        (self._bitfield >> 2) & 16383
+    }
+    pub fn set_Reserved(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(16383 << 2)) | ((value & 16383) << 2);
+    }
+}
 impl windows_core::TypeKind for PROCESSOR_IDLESTATE_POLICY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5295,10 +5723,37 @@ impl Default for PROCESSOR_PERFSTATE_POLICY_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESSOR_PERFSTATE_POLICY_0_0_0 {
     pub _bitfield: u8,
 }
+impl PROCESSOR_PERFSTATE_POLICY_0_0_0 {
+    pub const fn NoDomainAccounting(&self) -> u8 {
+        self._bitfield & 1
+    }
+    pub fn set_NoDomainAccounting(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn IncreasePolicy(&self) -> u8 {
+        (self._bitfield >> 1) & 3
+    }
+    pub fn set_IncreasePolicy(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(3 << 1)) | ((value & 3) << 1);
+    }
+    pub const fn DecreasePolicy(&self) -> u8 {
+        (self._bitfield >> 3) & 3
+    }
+    pub fn set_DecreasePolicy(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(3 << 3)) | ((value & 3) << 3);
+    }
+    pub const fn Reserved(&self) -> u8 {
+        (self._bitfield >> 5) & 7
+    }
+    pub fn set_Reserved(&mut self, value: u8) {
+        self._bitfield = (self._bitfield & !(7 << 5)) | ((value & 7) << 5);
+    }
+}
 impl windows_core::TypeKind for PROCESSOR_PERFSTATE_POLICY_0_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5335,10 +5790,25 @@ impl Default for PROCESS_MITIGATION_ACTIVATION_CONTEXT_TRUST_POLICY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_MITIGATION_ACTIVATION_CONTEXT_TRUST_POLICY_0_0 {
     pub _bitfield: u32,
 }
+impl PROCESS_MITIGATION_ACTIVATION_CONTEXT_TRUST_POLICY_0_0 {
+    pub const fn AssemblyManifestRedirectionTrust(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_AssemblyManifestRedirectionTrust(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ReservedFlags(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_ReservedFlags(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for PROCESS_MITIGATION_ACTIVATION_CONTEXT_TRUST_POLICY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5375,10 +5845,43 @@ impl Default for PROCESS_MITIGATION_ASLR_POLICY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_MITIGATION_ASLR_POLICY_0_0 {
     pub _bitfield: u32,
 }
+impl PROCESS_MITIGATION_ASLR_POLICY_0_0 {
+    pub const fn EnableBottomUpRandomization(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_EnableBottomUpRandomization(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn EnableForceRelocateImages(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_EnableForceRelocateImages(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn EnableHighEntropy(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_EnableHighEntropy(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn DisallowStrippedImages(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_DisallowStrippedImages(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn ReservedFlags(&self) -> u32 {
+        (self._bitfield >> 4) & 268435455
+    }
+    pub fn set_ReservedFlags(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(268435455 << 4)) | ((value & 268435455) << 4);
+    }
+}
 impl windows_core::TypeKind for PROCESS_MITIGATION_ASLR_POLICY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5415,10 +5918,49 @@ impl Default for PROCESS_MITIGATION_BINARY_SIGNATURE_POLICY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_MITIGATION_BINARY_SIGNATURE_POLICY_0_0 {
     pub _bitfield: u32,
 }
+impl PROCESS_MITIGATION_BINARY_SIGNATURE_POLICY_0_0 {
+    pub const fn MicrosoftSignedOnly(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_MicrosoftSignedOnly(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn StoreSignedOnly(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_StoreSignedOnly(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn MitigationOptIn(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_MitigationOptIn(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn AuditMicrosoftSignedOnly(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_AuditMicrosoftSignedOnly(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn AuditStoreSignedOnly(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_AuditStoreSignedOnly(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn ReservedFlags(&self) -> u32 {
+        (self._bitfield >> 5) & 134217727
+    }
+    pub fn set_ReservedFlags(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(134217727 << 5)) | ((value & 134217727) << 5);
+    }
+}
 impl windows_core::TypeKind for PROCESS_MITIGATION_BINARY_SIGNATURE_POLICY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5455,10 +5997,37 @@ impl Default for PROCESS_MITIGATION_CHILD_PROCESS_POLICY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_MITIGATION_CHILD_PROCESS_POLICY_0_0 {
     pub _bitfield: u32,
 }
+impl PROCESS_MITIGATION_CHILD_PROCESS_POLICY_0_0 {
+    pub const fn NoChildProcessCreation(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_NoChildProcessCreation(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn AuditNoChildProcessCreation(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_AuditNoChildProcessCreation(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn AllowSecureProcessCreation(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_AllowSecureProcessCreation(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn ReservedFlags(&self) -> u32 {
+        (self._bitfield >> 3) & 536870911
+    }
+    pub fn set_ReservedFlags(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(536870911 << 3)) | ((value & 536870911) << 3);
+    }
+}
 impl windows_core::TypeKind for PROCESS_MITIGATION_CHILD_PROCESS_POLICY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5495,10 +6064,49 @@ impl Default for PROCESS_MITIGATION_CONTROL_FLOW_GUARD_POLICY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_MITIGATION_CONTROL_FLOW_GUARD_POLICY_0_0 {
     pub _bitfield: u32,
 }
+impl PROCESS_MITIGATION_CONTROL_FLOW_GUARD_POLICY_0_0 {
+    pub const fn EnableControlFlowGuard(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_EnableControlFlowGuard(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn EnableExportSuppression(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_EnableExportSuppression(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn StrictMode(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_StrictMode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn EnableXfg(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_EnableXfg(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn EnableXfgAuditMode(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_EnableXfgAuditMode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn ReservedFlags(&self) -> u32 {
+        (self._bitfield >> 5) & 134217727
+    }
+    pub fn set_ReservedFlags(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(134217727 << 5)) | ((value & 134217727) << 5);
+    }
+}
 impl windows_core::TypeKind for PROCESS_MITIGATION_CONTROL_FLOW_GUARD_POLICY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5536,10 +6144,31 @@ impl Default for PROCESS_MITIGATION_DEP_POLICY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_MITIGATION_DEP_POLICY_0_0 {
     pub _bitfield: u32,
 }
+impl PROCESS_MITIGATION_DEP_POLICY_0_0 {
+    pub const fn Enable(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_Enable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn DisableAtlThunkEmulation(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_DisableAtlThunkEmulation(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ReservedFlags(&self) -> u32 {
+        (self._bitfield >> 2) & 1073741823
+    }
+    pub fn set_ReservedFlags(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1073741823 << 2)) | ((value & 1073741823) << 2);
+    }
+}
 impl windows_core::TypeKind for PROCESS_MITIGATION_DEP_POLICY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5576,10 +6205,43 @@ impl Default for PROCESS_MITIGATION_DYNAMIC_CODE_POLICY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_MITIGATION_DYNAMIC_CODE_POLICY_0_0 {
     pub _bitfield: u32,
 }
+impl PROCESS_MITIGATION_DYNAMIC_CODE_POLICY_0_0 {
+    pub const fn ProhibitDynamicCode(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_ProhibitDynamicCode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn AllowThreadOptOut(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_AllowThreadOptOut(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn AllowRemoteDowngrade(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_AllowRemoteDowngrade(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn AuditProhibitDynamicCode(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_AuditProhibitDynamicCode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn ReservedFlags(&self) -> u32 {
+        (self._bitfield >> 4) & 268435455
+    }
+    pub fn set_ReservedFlags(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(268435455 << 4)) | ((value & 268435455) << 4);
+    }
+}
 impl windows_core::TypeKind for PROCESS_MITIGATION_DYNAMIC_CODE_POLICY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5616,10 +6278,25 @@ impl Default for PROCESS_MITIGATION_EXTENSION_POINT_DISABLE_POLICY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_MITIGATION_EXTENSION_POINT_DISABLE_POLICY_0_0 {
     pub _bitfield: u32,
 }
+impl PROCESS_MITIGATION_EXTENSION_POINT_DISABLE_POLICY_0_0 {
+    pub const fn DisableExtensionPoints(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_DisableExtensionPoints(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ReservedFlags(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_ReservedFlags(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for PROCESS_MITIGATION_EXTENSION_POINT_DISABLE_POLICY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5656,10 +6333,31 @@ impl Default for PROCESS_MITIGATION_FONT_DISABLE_POLICY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_MITIGATION_FONT_DISABLE_POLICY_0_0 {
     pub _bitfield: u32,
 }
+impl PROCESS_MITIGATION_FONT_DISABLE_POLICY_0_0 {
+    pub const fn DisableNonSystemFonts(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_DisableNonSystemFonts(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn AuditNonSystemFontLoading(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_AuditNonSystemFontLoading(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ReservedFlags(&self) -> u32 {
+        (self._bitfield >> 2) & 1073741823
+    }
+    pub fn set_ReservedFlags(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1073741823 << 2)) | ((value & 1073741823) << 2);
+    }
+}
 impl windows_core::TypeKind for PROCESS_MITIGATION_FONT_DISABLE_POLICY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5696,10 +6394,49 @@ impl Default for PROCESS_MITIGATION_IMAGE_LOAD_POLICY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_MITIGATION_IMAGE_LOAD_POLICY_0_0 {
     pub _bitfield: u32,
 }
+impl PROCESS_MITIGATION_IMAGE_LOAD_POLICY_0_0 {
+    pub const fn NoRemoteImages(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_NoRemoteImages(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn NoLowMandatoryLabelImages(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_NoLowMandatoryLabelImages(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn PreferSystem32Images(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_PreferSystem32Images(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn AuditNoRemoteImages(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_AuditNoRemoteImages(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn AuditNoLowMandatoryLabelImages(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_AuditNoLowMandatoryLabelImages(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn ReservedFlags(&self) -> u32 {
+        (self._bitfield >> 5) & 134217727
+    }
+    pub fn set_ReservedFlags(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(134217727 << 5)) | ((value & 134217727) << 5);
+    }
+}
 impl windows_core::TypeKind for PROCESS_MITIGATION_IMAGE_LOAD_POLICY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5736,10 +6473,91 @@ impl Default for PROCESS_MITIGATION_PAYLOAD_RESTRICTION_POLICY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_MITIGATION_PAYLOAD_RESTRICTION_POLICY_0_0 {
     pub _bitfield: u32,
 }
+impl PROCESS_MITIGATION_PAYLOAD_RESTRICTION_POLICY_0_0 {
+    pub const fn EnableExportAddressFilter(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_EnableExportAddressFilter(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn AuditExportAddressFilter(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_AuditExportAddressFilter(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn EnableExportAddressFilterPlus(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_EnableExportAddressFilterPlus(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn AuditExportAddressFilterPlus(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_AuditExportAddressFilterPlus(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn EnableImportAddressFilter(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_EnableImportAddressFilter(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn AuditImportAddressFilter(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_AuditImportAddressFilter(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn EnableRopStackPivot(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_EnableRopStackPivot(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn AuditRopStackPivot(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_AuditRopStackPivot(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn EnableRopCallerCheck(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_EnableRopCallerCheck(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn AuditRopCallerCheck(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_AuditRopCallerCheck(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn EnableRopSimExec(&self) -> u32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_EnableRopSimExec(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn AuditRopSimExec(&self) -> u32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_AuditRopSimExec(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn ReservedFlags(&self) -> u32 {
+        (self._bitfield >> 12) & 1048575
+    }
+    pub fn set_ReservedFlags(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1048575 << 12)) | ((value & 1048575) << 12);
+    }
+}
 impl windows_core::TypeKind for PROCESS_MITIGATION_PAYLOAD_RESTRICTION_POLICY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5776,10 +6594,31 @@ impl Default for PROCESS_MITIGATION_REDIRECTION_TRUST_POLICY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_MITIGATION_REDIRECTION_TRUST_POLICY_0_0 {
     pub _bitfield: u32,
 }
+impl PROCESS_MITIGATION_REDIRECTION_TRUST_POLICY_0_0 {
+    pub const fn EnforceRedirectionTrust(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_EnforceRedirectionTrust(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn AuditRedirectionTrust(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_AuditRedirectionTrust(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ReservedFlags(&self) -> u32 {
+        (self._bitfield >> 2) & 1073741823
+    }
+    pub fn set_ReservedFlags(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1073741823 << 2)) | ((value & 1073741823) << 2);
+    }
+}
 impl windows_core::TypeKind for PROCESS_MITIGATION_REDIRECTION_TRUST_POLICY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5816,10 +6655,25 @@ impl Default for PROCESS_MITIGATION_SEHOP_POLICY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_MITIGATION_SEHOP_POLICY_0_0 {
     pub _bitfield: u32,
 }
+impl PROCESS_MITIGATION_SEHOP_POLICY_0_0 {
+    pub const fn EnableSehop(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_EnableSehop(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ReservedFlags(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_ReservedFlags(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for PROCESS_MITIGATION_SEHOP_POLICY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5856,10 +6710,49 @@ impl Default for PROCESS_MITIGATION_SIDE_CHANNEL_ISOLATION_POLICY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_MITIGATION_SIDE_CHANNEL_ISOLATION_POLICY_0_0 {
     pub _bitfield: u32,
 }
+impl PROCESS_MITIGATION_SIDE_CHANNEL_ISOLATION_POLICY_0_0 {
+    pub const fn SmtBranchTargetIsolation(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_SmtBranchTargetIsolation(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn IsolateSecurityDomain(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_IsolateSecurityDomain(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn DisablePageCombine(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_DisablePageCombine(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn SpeculativeStoreBypassDisable(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_SpeculativeStoreBypassDisable(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn RestrictCoreSharing(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_RestrictCoreSharing(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn ReservedFlags(&self) -> u32 {
+        (self._bitfield >> 5) & 134217727
+    }
+    pub fn set_ReservedFlags(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(134217727 << 5)) | ((value & 134217727) << 5);
+    }
+}
 impl windows_core::TypeKind for PROCESS_MITIGATION_SIDE_CHANNEL_ISOLATION_POLICY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5896,10 +6789,31 @@ impl Default for PROCESS_MITIGATION_STRICT_HANDLE_CHECK_POLICY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_MITIGATION_STRICT_HANDLE_CHECK_POLICY_0_0 {
     pub _bitfield: u32,
 }
+impl PROCESS_MITIGATION_STRICT_HANDLE_CHECK_POLICY_0_0 {
+    pub const fn RaiseExceptionOnInvalidHandleReference(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_RaiseExceptionOnInvalidHandleReference(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn HandleExceptionsPermanentlyEnabled(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_HandleExceptionsPermanentlyEnabled(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn ReservedFlags(&self) -> u32 {
+        (self._bitfield >> 2) & 1073741823
+    }
+    pub fn set_ReservedFlags(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1073741823 << 2)) | ((value & 1073741823) << 2);
+    }
+}
 impl windows_core::TypeKind for PROCESS_MITIGATION_STRICT_HANDLE_CHECK_POLICY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5936,10 +6850,43 @@ impl Default for PROCESS_MITIGATION_SYSTEM_CALL_DISABLE_POLICY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_MITIGATION_SYSTEM_CALL_DISABLE_POLICY_0_0 {
     pub _bitfield: u32,
 }
+impl PROCESS_MITIGATION_SYSTEM_CALL_DISABLE_POLICY_0_0 {
+    pub const fn DisallowWin32kSystemCalls(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_DisallowWin32kSystemCalls(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn AuditDisallowWin32kSystemCalls(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_AuditDisallowWin32kSystemCalls(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn DisallowFsctlSystemCalls(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_DisallowFsctlSystemCalls(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn AuditDisallowFsctlSystemCalls(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_AuditDisallowFsctlSystemCalls(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn ReservedFlags(&self) -> u32 {
+        (self._bitfield >> 4) & 268435455
+    }
+    pub fn set_ReservedFlags(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(268435455 << 4)) | ((value & 268435455) << 4);
+    }
+}
 impl windows_core::TypeKind for PROCESS_MITIGATION_SYSTEM_CALL_DISABLE_POLICY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -5976,10 +6923,25 @@ impl Default for PROCESS_MITIGATION_SYSTEM_CALL_FILTER_POLICY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_MITIGATION_SYSTEM_CALL_FILTER_POLICY_0_0 {
     pub _bitfield: u32,
 }
+impl PROCESS_MITIGATION_SYSTEM_CALL_FILTER_POLICY_0_0 {
+    pub const fn FilterId(&self) -> u32 {
+        self._bitfield & 15
+    }
+    pub fn set_FilterId(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !15) | (value & 15);
+    }
+    pub const fn ReservedFlags(&self) -> u32 {
+        (self._bitfield >> 4) & 268435455
+    }
+    pub fn set_ReservedFlags(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(268435455 << 4)) | ((value & 268435455) << 4);
+    }
+}
 impl windows_core::TypeKind for PROCESS_MITIGATION_SYSTEM_CALL_FILTER_POLICY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -6016,10 +6978,25 @@ impl Default for PROCESS_MITIGATION_USER_POINTER_AUTH_POLICY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_MITIGATION_USER_POINTER_AUTH_POLICY_0_0 {
     pub _bitfield: u32,
 }
+impl PROCESS_MITIGATION_USER_POINTER_AUTH_POLICY_0_0 {
+    pub const fn EnablePointerAuthUserIp(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_EnablePointerAuthUserIp(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn ReservedFlags(&self) -> u32 {
+        (self._bitfield >> 1) & 2147483647
+    }
+    pub fn set_ReservedFlags(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(2147483647 << 1)) | ((value & 2147483647) << 1);
+    }
+}
 impl windows_core::TypeKind for PROCESS_MITIGATION_USER_POINTER_AUTH_POLICY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -6056,10 +7033,79 @@ impl Default for PROCESS_MITIGATION_USER_SHADOW_STACK_POLICY_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_MITIGATION_USER_SHADOW_STACK_POLICY_0_0 {
     pub _bitfield: u32,
 }
+impl PROCESS_MITIGATION_USER_SHADOW_STACK_POLICY_0_0 {
+    pub const fn EnableUserShadowStack(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_EnableUserShadowStack(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn AuditUserShadowStack(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_AuditUserShadowStack(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn SetContextIpValidation(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_SetContextIpValidation(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn AuditSetContextIpValidation(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_AuditSetContextIpValidation(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn EnableUserShadowStackStrictMode(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_EnableUserShadowStackStrictMode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn BlockNonCetBinaries(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_BlockNonCetBinaries(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn BlockNonCetBinariesNonEhcont(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_BlockNonCetBinariesNonEhcont(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn AuditBlockNonCetBinaries(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_AuditBlockNonCetBinaries(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn CetDynamicApisOutOfProcOnly(&self) -> u32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_CetDynamicApisOutOfProcOnly(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn SetContextIpValidationRelaxedMode(&self) -> u32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_SetContextIpValidationRelaxedMode(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn ReservedFlags(&self) -> u32 {
+        (self._bitfield >> 10) & 4194303
+    }
+    pub fn set_ReservedFlags(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(4194303 << 10)) | ((value & 4194303) << 10);
+    }
+}
 impl windows_core::TypeKind for PROCESS_MITIGATION_USER_SHADOW_STACK_POLICY_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -6107,10 +7153,25 @@ impl Default for RATE_QUOTA_LIMIT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RATE_QUOTA_LIMIT_0 {
     pub _bitfield: u32,
 }
+impl RATE_QUOTA_LIMIT_0 {
+    pub const fn RatePercent(&self) -> u32 {
+        self._bitfield & 127
+    }
+    pub fn set_RatePercent(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !127) | (value & 127);
+    }
+    pub const fn Reserved0(&self) -> u32 {
+        (self._bitfield >> 7) & 33554431
+    }
+    pub fn set_Reserved0(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(33554431 << 7)) | ((value & 33554431) << 7);
+    }
+}
 impl windows_core::TypeKind for RATE_QUOTA_LIMIT_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -6120,7 +7181,8 @@ impl Default for RATE_QUOTA_LIMIT_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REARRANGE_FILE_DATA {
     pub SourceStartingOffset: u64,
     pub TargetOffset: u64,
@@ -6138,7 +7200,8 @@ impl Default for REARRANGE_FILE_DATA {
 }
 #[repr(C)]
 #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec", target_arch = "x86_64"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REARRANGE_FILE_DATA32 {
     pub SourceStartingOffset: u64,
     pub TargetOffset: u64,
@@ -6157,7 +7220,8 @@ impl Default for REARRANGE_FILE_DATA32 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REDBOOK_DIGITAL_AUDIO_EXTRACTION_INFO {
     pub Version: u32,
     pub Accurate: u32,
@@ -6173,7 +7237,8 @@ impl Default for REDBOOK_DIGITAL_AUDIO_EXTRACTION_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RESOURCEMANAGER_BASIC_INFORMATION {
     pub ResourceManagerId: windows_core::GUID,
     pub DescriptionLength: u32,
@@ -6188,7 +7253,8 @@ impl Default for RESOURCEMANAGER_BASIC_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RESOURCEMANAGER_COMPLETION_INFORMATION {
     pub IoCompletionPortHandle: super::super::Foundation::HANDLE,
     pub CompletionKey: usize,
@@ -6202,7 +7268,8 @@ impl Default for RESOURCEMANAGER_COMPLETION_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RemHBITMAP {
     pub cbData: u32,
     pub data: [u8; 1],
@@ -6216,7 +7283,8 @@ impl Default for RemHBITMAP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RemHBRUSH {
     pub cbData: u32,
     pub data: [u8; 1],
@@ -6230,7 +7298,8 @@ impl Default for RemHBRUSH {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RemHENHMETAFILE {
     pub cbData: u32,
     pub data: [u8; 1],
@@ -6244,7 +7313,8 @@ impl Default for RemHENHMETAFILE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RemHGLOBAL {
     pub fNullHGlobal: i32,
     pub cbData: u32,
@@ -6259,7 +7329,8 @@ impl Default for RemHGLOBAL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RemHMETAFILEPICT {
     pub mm: i32,
     pub xExt: i32,
@@ -6276,7 +7347,8 @@ impl Default for RemHMETAFILEPICT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RemHPALETTE {
     pub cbData: u32,
     pub data: [u8; 1],
@@ -6318,7 +7390,8 @@ impl Default for RemotableHandle_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCOPE_TABLE_AMD64 {
     pub Count: u32,
     pub ScopeRecord: [SCOPE_TABLE_AMD64_0; 1],
@@ -6332,7 +7405,8 @@ impl Default for SCOPE_TABLE_AMD64 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCOPE_TABLE_AMD64_0 {
     pub BeginAddress: u32,
     pub EndAddress: u32,
@@ -6348,7 +7422,8 @@ impl Default for SCOPE_TABLE_AMD64_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCOPE_TABLE_ARM {
     pub Count: u32,
     pub ScopeRecord: [SCOPE_TABLE_ARM_0; 1],
@@ -6362,7 +7437,8 @@ impl Default for SCOPE_TABLE_ARM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCOPE_TABLE_ARM_0 {
     pub BeginAddress: u32,
     pub EndAddress: u32,
@@ -6378,7 +7454,8 @@ impl Default for SCOPE_TABLE_ARM_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCOPE_TABLE_ARM64 {
     pub Count: u32,
     pub ScopeRecord: [SCOPE_TABLE_ARM64_0; 1],
@@ -6392,7 +7469,8 @@ impl Default for SCOPE_TABLE_ARM64 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCOPE_TABLE_ARM64_0 {
     pub BeginAddress: u32,
     pub EndAddress: u32,
@@ -6408,7 +7486,8 @@ impl Default for SCOPE_TABLE_ARM64_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCRUB_DATA_INPUT {
     pub Size: u32,
     pub Flags: u32,
@@ -6426,7 +7505,8 @@ impl Default for SCRUB_DATA_INPUT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCRUB_DATA_OUTPUT {
     pub Size: u32,
     pub Flags: u32,
@@ -6462,7 +7542,8 @@ impl Default for SCRUB_DATA_OUTPUT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCRUB_PARITY_EXTENT {
     pub Offset: i64,
     pub Length: u64,
@@ -6476,7 +7557,8 @@ impl Default for SCRUB_PARITY_EXTENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SCRUB_PARITY_EXTENT_DATA {
     pub Size: u16,
     pub Flags: u16,
@@ -6493,7 +7575,8 @@ impl Default for SCRUB_PARITY_EXTENT_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SECURITY_OBJECT_AI_PARAMS {
     pub Size: u32,
     pub ConstraintMask: u32,
@@ -6507,7 +7590,8 @@ impl Default for SECURITY_OBJECT_AI_PARAMS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SERVERSILO_BASIC_INFORMATION {
     pub ServiceSessionId: u32,
     pub State: SERVERSILO_STATE,
@@ -6576,7 +7660,8 @@ impl Default for SE_TOKEN_USER_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SHARED_VIRTUAL_DISK_SUPPORT {
     pub SharedVirtualDiskSupport: SharedVirtualDiskSupportType,
     pub HandleState: SharedVirtualDiskHandleState,
@@ -6590,7 +7675,8 @@ impl Default for SHARED_VIRTUAL_DISK_SUPPORT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SHUFFLE_FILE_DATA {
     pub StartingOffset: i64,
     pub Length: i64,
@@ -6605,7 +7691,8 @@ impl Default for SHUFFLE_FILE_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SILOOBJECT_BASIC_INFORMATION {
     pub SiloId: u32,
     pub SiloParentId: u32,
@@ -6622,7 +7709,8 @@ impl Default for SILOOBJECT_BASIC_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SUPPORTED_OS_INFO {
     pub MajorVersion: u16,
     pub MinorVersion: u16,
@@ -6636,7 +7724,8 @@ impl Default for SUPPORTED_OS_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TAPE_CREATE_PARTITION {
     pub Method: u32,
     pub Count: u32,
@@ -6651,7 +7740,8 @@ impl Default for TAPE_CREATE_PARTITION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TAPE_GET_DRIVE_PARAMETERS {
     pub ECC: super::super::Foundation::BOOLEAN,
     pub Compression: super::super::Foundation::BOOLEAN,
@@ -6674,7 +7764,8 @@ impl Default for TAPE_GET_DRIVE_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TAPE_GET_MEDIA_PARAMETERS {
     pub Capacity: i64,
     pub Remaining: i64,
@@ -6691,7 +7782,8 @@ impl Default for TAPE_GET_MEDIA_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TAPE_SET_DRIVE_PARAMETERS {
     pub ECC: super::super::Foundation::BOOLEAN,
     pub Compression: super::super::Foundation::BOOLEAN,
@@ -6708,7 +7800,8 @@ impl Default for TAPE_SET_DRIVE_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TAPE_SET_MEDIA_PARAMETERS {
     pub BlockSize: u32,
 }
@@ -6721,7 +7814,8 @@ impl Default for TAPE_SET_MEDIA_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TAPE_WMI_OPERATIONS {
     pub Method: u32,
     pub DataBufferSize: u32,
@@ -6736,7 +7830,8 @@ impl Default for TAPE_WMI_OPERATIONS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TOKEN_BNO_ISOLATION_INFORMATION {
     pub IsolationPrefix: windows_core::PWSTR,
     pub IsolationEnabled: super::super::Foundation::BOOLEAN,
@@ -6751,7 +7846,8 @@ impl Default for TOKEN_BNO_ISOLATION_INFORMATION {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Security")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TOKEN_SID_INFORMATION {
     pub Sid: super::super::Security::PSID,
 }
@@ -6766,7 +7862,8 @@ impl Default for TOKEN_SID_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TRANSACTIONMANAGER_BASIC_INFORMATION {
     pub TmIdentity: windows_core::GUID,
     pub VirtualClock: i64,
@@ -6780,7 +7877,8 @@ impl Default for TRANSACTIONMANAGER_BASIC_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TRANSACTIONMANAGER_LOGPATH_INFORMATION {
     pub LogPathLength: u32,
     pub LogPath: [u16; 1],
@@ -6794,7 +7892,8 @@ impl Default for TRANSACTIONMANAGER_LOGPATH_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TRANSACTIONMANAGER_LOG_INFORMATION {
     pub LogIdentity: windows_core::GUID,
 }
@@ -6807,7 +7906,8 @@ impl Default for TRANSACTIONMANAGER_LOG_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TRANSACTIONMANAGER_OLDEST_INFORMATION {
     pub OldestTransactionGuid: windows_core::GUID,
 }
@@ -6820,7 +7920,8 @@ impl Default for TRANSACTIONMANAGER_OLDEST_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TRANSACTIONMANAGER_RECOVERY_INFORMATION {
     pub LastRecoveredLsn: u64,
 }
@@ -6833,7 +7934,8 @@ impl Default for TRANSACTIONMANAGER_RECOVERY_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TRANSACTION_BASIC_INFORMATION {
     pub TransactionId: windows_core::GUID,
     pub State: u32,
@@ -6848,7 +7950,8 @@ impl Default for TRANSACTION_BASIC_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TRANSACTION_BIND_INFORMATION {
     pub TmHandle: super::super::Foundation::HANDLE,
 }
@@ -6861,7 +7964,8 @@ impl Default for TRANSACTION_BIND_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TRANSACTION_ENLISTMENTS_INFORMATION {
     pub NumberOfEnlistments: u32,
     pub EnlistmentPair: [TRANSACTION_ENLISTMENT_PAIR; 1],
@@ -6875,7 +7979,8 @@ impl Default for TRANSACTION_ENLISTMENTS_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TRANSACTION_ENLISTMENT_PAIR {
     pub EnlistmentId: windows_core::GUID,
     pub ResourceManagerId: windows_core::GUID,
@@ -6889,7 +7994,8 @@ impl Default for TRANSACTION_ENLISTMENT_PAIR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TRANSACTION_LIST_ENTRY {
     pub UOW: windows_core::GUID,
 }
@@ -6902,7 +8008,8 @@ impl Default for TRANSACTION_LIST_ENTRY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TRANSACTION_LIST_INFORMATION {
     pub NumberOfTransactions: u32,
     pub TransactionInformation: [TRANSACTION_LIST_ENTRY; 1],
@@ -6916,7 +8023,8 @@ impl Default for TRANSACTION_LIST_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TRANSACTION_PROPERTIES_INFORMATION {
     pub IsolationLevel: u32,
     pub IsolationFlags: u32,
@@ -6934,7 +8042,8 @@ impl Default for TRANSACTION_PROPERTIES_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TRANSACTION_SUPERIOR_ENLISTMENT_INFORMATION {
     pub SuperiorEnlistmentPair: TRANSACTION_ENLISTMENT_PAIR,
 }
@@ -6947,7 +8056,8 @@ impl Default for TRANSACTION_SUPERIOR_ENLISTMENT_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UMS_CREATE_THREAD_ATTRIBUTES {
     pub UmsVersion: u32,
     pub UmsContext: *mut core::ffi::c_void,
@@ -6962,7 +8072,8 @@ impl Default for UMS_CREATE_THREAD_ATTRIBUTES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XSAVE_CET_U_FORMAT {
     pub Ia32CetUMsr: u64,
     pub Ia32Pl3SspMsr: u64,
@@ -6977,7 +8088,8 @@ impl Default for XSAVE_CET_U_FORMAT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct remoteMETAFILEPICT {
     pub mm: i32,
     pub xExt: i32,
@@ -6995,7 +8107,8 @@ impl Default for remoteMETAFILEPICT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct userBITMAP {
     pub bmType: i32,
     pub bmWidth: i32,
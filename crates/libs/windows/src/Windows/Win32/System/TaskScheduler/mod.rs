@@ -3231,6 +3231,7 @@ pub struct TASKPAGE(pub i32);
 impl windows_core::TypeKind for TASKPAGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TASKPAGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TASKPAGE").field(&self.0).finish()
@@ -3242,6 +3243,7 @@ pub struct TASK_ACTION_TYPE(pub i32);
 impl windows_core::TypeKind for TASK_ACTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TASK_ACTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TASK_ACTION_TYPE").field(&self.0).finish()
@@ -3253,6 +3255,7 @@ pub struct TASK_COMPATIBILITY(pub i32);
 impl windows_core::TypeKind for TASK_COMPATIBILITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TASK_COMPATIBILITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TASK_COMPATIBILITY").field(&self.0).finish()
@@ -3264,6 +3267,7 @@ pub struct TASK_CREATION(pub i32);
 impl windows_core::TypeKind for TASK_CREATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TASK_CREATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TASK_CREATION").field(&self.0).finish()
@@ -3275,6 +3279,7 @@ pub struct TASK_ENUM_FLAGS(pub i32);
 impl windows_core::TypeKind for TASK_ENUM_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TASK_ENUM_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TASK_ENUM_FLAGS").field(&self.0).finish()
@@ -3286,6 +3291,7 @@ pub struct TASK_INSTANCES_POLICY(pub i32);
 impl windows_core::TypeKind for TASK_INSTANCES_POLICY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TASK_INSTANCES_POLICY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TASK_INSTANCES_POLICY").field(&self.0).finish()
@@ -3297,6 +3303,7 @@ pub struct TASK_LOGON_TYPE(pub i32);
 impl windows_core::TypeKind for TASK_LOGON_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TASK_LOGON_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TASK_LOGON_TYPE").field(&self.0).finish()
@@ -3308,6 +3315,7 @@ pub struct TASK_PROCESSTOKENSID_TYPE(pub i32);
 impl windows_core::TypeKind for TASK_PROCESSTOKENSID_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TASK_PROCESSTOKENSID_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TASK_PROCESSTOKENSID_TYPE").field(&self.0).finish()
@@ -3319,6 +3327,7 @@ pub struct TASK_RUNLEVEL_TYPE(pub i32);
 impl windows_core::TypeKind for TASK_RUNLEVEL_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TASK_RUNLEVEL_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TASK_RUNLEVEL_TYPE").field(&self.0).finish()
@@ -3330,6 +3339,7 @@ pub struct TASK_RUN_FLAGS(pub i32);
 impl windows_core::TypeKind for TASK_RUN_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TASK_RUN_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TASK_RUN_FLAGS").field(&self.0).finish()
@@ -3341,6 +3351,7 @@ pub struct TASK_SESSION_STATE_CHANGE_TYPE(pub i32);
 impl windows_core::TypeKind for TASK_SESSION_STATE_CHANGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TASK_SESSION_STATE_CHANGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TASK_SESSION_STATE_CHANGE_TYPE").field(&self.0).finish()
@@ -3352,6 +3363,7 @@ pub struct TASK_STATE(pub i32);
 impl windows_core::TypeKind for TASK_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TASK_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TASK_STATE").field(&self.0).finish()
@@ -3363,6 +3375,7 @@ pub struct TASK_TRIGGER_TYPE(pub i32);
 impl windows_core::TypeKind for TASK_TRIGGER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TASK_TRIGGER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TASK_TRIGGER_TYPE").field(&self.0).finish()
@@ -3374,13 +3387,15 @@ pub struct TASK_TRIGGER_TYPE2(pub i32);
 impl windows_core::TypeKind for TASK_TRIGGER_TYPE2 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TASK_TRIGGER_TYPE2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TASK_TRIGGER_TYPE2").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DAILY {
     pub DaysInterval: u16,
 }
@@ -3393,7 +3408,8 @@ impl Default for DAILY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MONTHLYDATE {
     pub rgfDays: u32,
     pub rgfMonths: u16,
@@ -3407,7 +3423,8 @@ impl Default for MONTHLYDATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MONTHLYDOW {
     pub wWhichWeek: u16,
     pub rgfDaysOfTheWeek: u16,
@@ -3470,7 +3487,8 @@ pub const TaskHandlerPS: windows_core::GUID = windows_core::GUID::from_u128(0xf2
 pub const TaskHandlerStatusPS: windows_core::GUID = windows_core::GUID::from_u128(0x9f15266d_d7ba_48f0_93c1_e6895f6fe5ac);
 pub const TaskScheduler: windows_core::GUID = windows_core::GUID::from_u128(0x0f87369f_a4e5_4cfc_bd3e_73e6154572dd);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WEEKLY {
     pub WeeksInterval: u16,
     pub rgfDaysOfTheWeek: u16,
@@ -3485,3 +3503,5 @@ impl Default for WEEKLY {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
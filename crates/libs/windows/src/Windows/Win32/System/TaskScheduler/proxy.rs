@@ -0,0 +1,2173 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, pid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAction", "Id", 7usize, |this| ((**(this as *const *const IAction_Vtbl)).Id)(this, pid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetId(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAction", "SetId", 8usize, |this| ((**(this as *const *const IAction_Vtbl)).SetId)(this, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, ptype: *mut TASK_ACTION_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAction", "Type", 9usize, |this| ((**(this as *const *const IAction_Vtbl)).Type)(this, ptype), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, SetId, Type }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAction as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IActionCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActionCollection", "Count", 7usize, |this| ((**(this as *const *const IActionCollection_Vtbl)).Count)(this, pcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, ppaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActionCollection", "get_Item", 8usize, |this| ((**(this as *const *const IActionCollection_Vtbl)).get_Item)(this, index, ppaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActionCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const IActionCollection_Vtbl))._NewEnum)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn XmlText(this: *mut core::ffi::c_void, ptext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActionCollection", "XmlText", 10usize, |this| ((**(this as *const *const IActionCollection_Vtbl)).XmlText)(this, ptext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetXmlText(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActionCollection", "SetXmlText", 11usize, |this| ((**(this as *const *const IActionCollection_Vtbl)).SetXmlText)(this, text), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, r#type: TASK_ACTION_TYPE, ppaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActionCollection", "Create", 12usize, |this| ((**(this as *const *const IActionCollection_Vtbl)).Create)(this, r#type, ppaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActionCollection", "Remove", 13usize, |this| ((**(this as *const *const IActionCollection_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActionCollection", "Clear", 14usize, |this| ((**(this as *const *const IActionCollection_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Context(this: *mut core::ffi::c_void, pcontext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActionCollection", "Context", 15usize, |this| ((**(this as *const *const IActionCollection_Vtbl)).Context)(this, pcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetContext(this: *mut core::ffi::c_void, context: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActionCollection", "SetContext", 16usize, |this| ((**(this as *const *const IActionCollection_Vtbl)).SetContext)(this, context), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            _NewEnum,
+            XmlText,
+            SetXmlText,
+            #[cfg(feature = "Win32_System_Com")]
+            Create,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Create: 0,
+            Remove,
+            Clear,
+            Context,
+            SetContext,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActionCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IBootTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Delay(this: *mut core::ffi::c_void, pdelay: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBootTrigger", "Delay", 20usize, |this| ((**(this as *const *const IBootTrigger_Vtbl)).Delay)(this, pdelay), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDelay(this: *mut core::ffi::c_void, delay: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBootTrigger", "SetDelay", 21usize, |this| ((**(this as *const *const IBootTrigger_Vtbl)).SetDelay)(this, delay), |result| Some(*result))
+        }
+        Self { base__: <ITrigger_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Delay, SetDelay }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBootTrigger as windows_core::Interface>::IID || <ITrigger_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IComHandlerAction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ClassId(this: *mut core::ffi::c_void, pclsid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComHandlerAction", "ClassId", 10usize, |this| ((**(this as *const *const IComHandlerAction_Vtbl)).ClassId)(this, pclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClassId(this: *mut core::ffi::c_void, clsid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComHandlerAction", "SetClassId", 11usize, |this| ((**(this as *const *const IComHandlerAction_Vtbl)).SetClassId)(this, clsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, pdata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComHandlerAction", "Data", 12usize, |this| ((**(this as *const *const IComHandlerAction_Vtbl)).Data)(this, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, data: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComHandlerAction", "SetData", 13usize, |this| ((**(this as *const *const IComHandlerAction_Vtbl)).SetData)(this, data), |result| Some(*result))
+        }
+        Self { base__: <IAction_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ClassId, SetClassId, Data, SetData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComHandlerAction as windows_core::Interface>::IID || <IAction_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDailyTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DaysInterval(this: *mut core::ffi::c_void, pdays: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDailyTrigger", "DaysInterval", 20usize, |this| ((**(this as *const *const IDailyTrigger_Vtbl)).DaysInterval)(this, pdays), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDaysInterval(this: *mut core::ffi::c_void, days: i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDailyTrigger", "SetDaysInterval", 21usize, |this| ((**(this as *const *const IDailyTrigger_Vtbl)).SetDaysInterval)(this, days), |result| Some(*result))
+        }
+        unsafe extern "system" fn RandomDelay(this: *mut core::ffi::c_void, prandomdelay: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDailyTrigger", "RandomDelay", 22usize, |this| ((**(this as *const *const IDailyTrigger_Vtbl)).RandomDelay)(this, prandomdelay), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRandomDelay(this: *mut core::ffi::c_void, randomdelay: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDailyTrigger", "SetRandomDelay", 23usize, |this| ((**(this as *const *const IDailyTrigger_Vtbl)).SetRandomDelay)(this, randomdelay), |result| Some(*result))
+        }
+        Self { base__: <ITrigger_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DaysInterval, SetDaysInterval, RandomDelay, SetRandomDelay }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDailyTrigger as windows_core::Interface>::IID || <ITrigger_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEmailAction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Server(this: *mut core::ffi::c_void, pserver: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAction", "Server", 10usize, |this| ((**(this as *const *const IEmailAction_Vtbl)).Server)(this, pserver), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServer(this: *mut core::ffi::c_void, server: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAction", "SetServer", 11usize, |this| ((**(this as *const *const IEmailAction_Vtbl)).SetServer)(this, server), |result| Some(*result))
+        }
+        unsafe extern "system" fn Subject(this: *mut core::ffi::c_void, psubject: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAction", "Subject", 12usize, |this| ((**(this as *const *const IEmailAction_Vtbl)).Subject)(this, psubject), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubject(this: *mut core::ffi::c_void, subject: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAction", "SetSubject", 13usize, |this| ((**(this as *const *const IEmailAction_Vtbl)).SetSubject)(this, subject), |result| Some(*result))
+        }
+        unsafe extern "system" fn To(this: *mut core::ffi::c_void, pto: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAction", "To", 14usize, |this| ((**(this as *const *const IEmailAction_Vtbl)).To)(this, pto), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTo(this: *mut core::ffi::c_void, to: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAction", "SetTo", 15usize, |this| ((**(this as *const *const IEmailAction_Vtbl)).SetTo)(this, to), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cc(this: *mut core::ffi::c_void, pcc: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAction", "Cc", 16usize, |this| ((**(this as *const *const IEmailAction_Vtbl)).Cc)(this, pcc), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCc(this: *mut core::ffi::c_void, cc: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAction", "SetCc", 17usize, |this| ((**(this as *const *const IEmailAction_Vtbl)).SetCc)(this, cc), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bcc(this: *mut core::ffi::c_void, pbcc: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAction", "Bcc", 18usize, |this| ((**(this as *const *const IEmailAction_Vtbl)).Bcc)(this, pbcc), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBcc(this: *mut core::ffi::c_void, bcc: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAction", "SetBcc", 19usize, |this| ((**(this as *const *const IEmailAction_Vtbl)).SetBcc)(this, bcc), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReplyTo(this: *mut core::ffi::c_void, preplyto: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAction", "ReplyTo", 20usize, |this| ((**(this as *const *const IEmailAction_Vtbl)).ReplyTo)(this, preplyto), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetReplyTo(this: *mut core::ffi::c_void, replyto: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAction", "SetReplyTo", 21usize, |this| ((**(this as *const *const IEmailAction_Vtbl)).SetReplyTo)(this, replyto), |result| Some(*result))
+        }
+        unsafe extern "system" fn From(this: *mut core::ffi::c_void, pfrom: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAction", "From", 22usize, |this| ((**(this as *const *const IEmailAction_Vtbl)).From)(this, pfrom), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFrom(this: *mut core::ffi::c_void, from: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAction", "SetFrom", 23usize, |this| ((**(this as *const *const IEmailAction_Vtbl)).SetFrom)(this, from), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn HeaderFields(this: *mut core::ffi::c_void, ppheaderfields: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAction", "HeaderFields", 24usize, |this| ((**(this as *const *const IEmailAction_Vtbl)).HeaderFields)(this, ppheaderfields), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetHeaderFields(this: *mut core::ffi::c_void, pheaderfields: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAction", "SetHeaderFields", 25usize, |this| ((**(this as *const *const IEmailAction_Vtbl)).SetHeaderFields)(this, pheaderfields), |result| Some(*result))
+        }
+        unsafe extern "system" fn Body(this: *mut core::ffi::c_void, pbody: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAction", "Body", 26usize, |this| ((**(this as *const *const IEmailAction_Vtbl)).Body)(this, pbody), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBody(this: *mut core::ffi::c_void, body: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAction", "SetBody", 27usize, |this| ((**(this as *const *const IEmailAction_Vtbl)).SetBody)(this, body), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Attachments(this: *mut core::ffi::c_void, pattachements: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAction", "Attachments", 28usize, |this| ((**(this as *const *const IEmailAction_Vtbl)).Attachments)(this, pattachements), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetAttachments(this: *mut core::ffi::c_void, pattachements: *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmailAction", "SetAttachments", 29usize, |this| ((**(this as *const *const IEmailAction_Vtbl)).SetAttachments)(this, pattachements), |result| Some(*result))
+        }
+        Self {
+            base__: <IAction_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Server,
+            SetServer,
+            Subject,
+            SetSubject,
+            To,
+            SetTo,
+            Cc,
+            SetCc,
+            Bcc,
+            SetBcc,
+            ReplyTo,
+            SetReplyTo,
+            From,
+            SetFrom,
+            #[cfg(feature = "Win32_System_Com")]
+            HeaderFields,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            HeaderFields: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetHeaderFields,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetHeaderFields: 0,
+            Body,
+            SetBody,
+            #[cfg(feature = "Win32_System_Com")]
+            Attachments,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Attachments: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetAttachments,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetAttachments: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmailAction as windows_core::Interface>::IID || <IAction_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumWorkItems_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgpwsznames: *mut *mut windows_core::PWSTR, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWorkItems", "Next", 3usize, |this| ((**(this as *const *const IEnumWorkItems_Vtbl)).Next)(this, celt, rgpwsznames, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWorkItems", "Skip", 4usize, |this| ((**(this as *const *const IEnumWorkItems_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWorkItems", "Reset", 5usize, |this| ((**(this as *const *const IEnumWorkItems_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenumworkitems: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWorkItems", "Clone", 6usize, |this| ((**(this as *const *const IEnumWorkItems_Vtbl)).Clone)(this, ppenumworkitems), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumWorkItems as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IEventTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Subscription(this: *mut core::ffi::c_void, pquery: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventTrigger", "Subscription", 20usize, |this| ((**(this as *const *const IEventTrigger_Vtbl)).Subscription)(this, pquery), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubscription(this: *mut core::ffi::c_void, query: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventTrigger", "SetSubscription", 21usize, |this| ((**(this as *const *const IEventTrigger_Vtbl)).SetSubscription)(this, query), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delay(this: *mut core::ffi::c_void, pdelay: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventTrigger", "Delay", 22usize, |this| ((**(this as *const *const IEventTrigger_Vtbl)).Delay)(this, pdelay), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDelay(this: *mut core::ffi::c_void, delay: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventTrigger", "SetDelay", 23usize, |this| ((**(this as *const *const IEventTrigger_Vtbl)).SetDelay)(this, delay), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ValueQueries(this: *mut core::ffi::c_void, ppnamedxpaths: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventTrigger", "ValueQueries", 24usize, |this| ((**(this as *const *const IEventTrigger_Vtbl)).ValueQueries)(this, ppnamedxpaths), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetValueQueries(this: *mut core::ffi::c_void, pnamedxpaths: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEventTrigger", "SetValueQueries", 25usize, |this| ((**(this as *const *const IEventTrigger_Vtbl)).SetValueQueries)(this, pnamedxpaths), |result| Some(*result))
+        }
+        Self {
+            base__: <ITrigger_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Subscription,
+            SetSubscription,
+            Delay,
+            SetDelay,
+            #[cfg(feature = "Win32_System_Com")]
+            ValueQueries,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ValueQueries: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetValueQueries,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetValueQueries: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEventTrigger as windows_core::Interface>::IID || <ITrigger_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IExecAction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, ppath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExecAction", "Path", 10usize, |this| ((**(this as *const *const IExecAction_Vtbl)).Path)(this, ppath), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPath(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExecAction", "SetPath", 11usize, |this| ((**(this as *const *const IExecAction_Vtbl)).SetPath)(this, path), |result| Some(*result))
+        }
+        unsafe extern "system" fn Arguments(this: *mut core::ffi::c_void, pargument: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExecAction", "Arguments", 12usize, |this| ((**(this as *const *const IExecAction_Vtbl)).Arguments)(this, pargument), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetArguments(this: *mut core::ffi::c_void, argument: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExecAction", "SetArguments", 13usize, |this| ((**(this as *const *const IExecAction_Vtbl)).SetArguments)(this, argument), |result| Some(*result))
+        }
+        unsafe extern "system" fn WorkingDirectory(this: *mut core::ffi::c_void, pworkingdirectory: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExecAction", "WorkingDirectory", 14usize, |this| ((**(this as *const *const IExecAction_Vtbl)).WorkingDirectory)(this, pworkingdirectory), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWorkingDirectory(this: *mut core::ffi::c_void, workingdirectory: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExecAction", "SetWorkingDirectory", 15usize, |this| ((**(this as *const *const IExecAction_Vtbl)).SetWorkingDirectory)(this, workingdirectory), |result| Some(*result))
+        }
+        Self { base__: <IAction_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Path, SetPath, Arguments, SetArguments, WorkingDirectory, SetWorkingDirectory }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IExecAction as windows_core::Interface>::IID || <IAction_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IExecAction2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HideAppWindow(this: *mut core::ffi::c_void, phideappwindow: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExecAction2", "HideAppWindow", 16usize, |this| ((**(this as *const *const IExecAction2_Vtbl)).HideAppWindow)(this, phideappwindow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHideAppWindow(this: *mut core::ffi::c_void, hideappwindow: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExecAction2", "SetHideAppWindow", 17usize, |this| ((**(this as *const *const IExecAction2_Vtbl)).SetHideAppWindow)(this, hideappwindow), |result| Some(*result))
+        }
+        Self { base__: <IExecAction_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HideAppWindow, SetHideAppWindow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IExecAction2 as windows_core::Interface>::IID || <IExecAction_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IIdleSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IdleDuration(this: *mut core::ffi::c_void, pdelay: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIdleSettings", "IdleDuration", 7usize, |this| ((**(this as *const *const IIdleSettings_Vtbl)).IdleDuration)(this, pdelay), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIdleDuration(this: *mut core::ffi::c_void, delay: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIdleSettings", "SetIdleDuration", 8usize, |this| ((**(this as *const *const IIdleSettings_Vtbl)).SetIdleDuration)(this, delay), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitTimeout(this: *mut core::ffi::c_void, ptimeout: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIdleSettings", "WaitTimeout", 9usize, |this| ((**(this as *const *const IIdleSettings_Vtbl)).WaitTimeout)(this, ptimeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWaitTimeout(this: *mut core::ffi::c_void, timeout: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIdleSettings", "SetWaitTimeout", 10usize, |this| ((**(this as *const *const IIdleSettings_Vtbl)).SetWaitTimeout)(this, timeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopOnIdleEnd(this: *mut core::ffi::c_void, pstop: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIdleSettings", "StopOnIdleEnd", 11usize, |this| ((**(this as *const *const IIdleSettings_Vtbl)).StopOnIdleEnd)(this, pstop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStopOnIdleEnd(this: *mut core::ffi::c_void, stop: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIdleSettings", "SetStopOnIdleEnd", 12usize, |this| ((**(this as *const *const IIdleSettings_Vtbl)).SetStopOnIdleEnd)(this, stop), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestartOnIdle(this: *mut core::ffi::c_void, prestart: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIdleSettings", "RestartOnIdle", 13usize, |this| ((**(this as *const *const IIdleSettings_Vtbl)).RestartOnIdle)(this, prestart), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRestartOnIdle(this: *mut core::ffi::c_void, restart: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIdleSettings", "SetRestartOnIdle", 14usize, |this| ((**(this as *const *const IIdleSettings_Vtbl)).SetRestartOnIdle)(this, restart), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IdleDuration,
+            SetIdleDuration,
+            WaitTimeout,
+            SetWaitTimeout,
+            StopOnIdleEnd,
+            SetStopOnIdleEnd,
+            RestartOnIdle,
+            SetRestartOnIdle,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIdleSettings as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IIdleTrigger_Vtbl {
+    const PROXY: Self = { Self { base__: <ITrigger_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIdleTrigger as windows_core::Interface>::IID || <ITrigger_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ILogonTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Delay(this: *mut core::ffi::c_void, pdelay: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILogonTrigger", "Delay", 20usize, |this| ((**(this as *const *const ILogonTrigger_Vtbl)).Delay)(this, pdelay), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDelay(this: *mut core::ffi::c_void, delay: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILogonTrigger", "SetDelay", 21usize, |this| ((**(this as *const *const ILogonTrigger_Vtbl)).SetDelay)(this, delay), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserId(this: *mut core::ffi::c_void, puser: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILogonTrigger", "UserId", 22usize, |this| ((**(this as *const *const ILogonTrigger_Vtbl)).UserId)(this, puser), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUserId(this: *mut core::ffi::c_void, user: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILogonTrigger", "SetUserId", 23usize, |this| ((**(this as *const *const ILogonTrigger_Vtbl)).SetUserId)(this, user), |result| Some(*result))
+        }
+        Self { base__: <ITrigger_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Delay, SetDelay, UserId, SetUserId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILogonTrigger as windows_core::Interface>::IID || <ITrigger_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMaintenanceSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPeriod(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMaintenanceSettings", "SetPeriod", 7usize, |this| ((**(this as *const *const IMaintenanceSettings_Vtbl)).SetPeriod)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Period(this: *mut core::ffi::c_void, target: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMaintenanceSettings", "Period", 8usize, |this| ((**(this as *const *const IMaintenanceSettings_Vtbl)).Period)(this, target), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeadline(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMaintenanceSettings", "SetDeadline", 9usize, |this| ((**(this as *const *const IMaintenanceSettings_Vtbl)).SetDeadline)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deadline(this: *mut core::ffi::c_void, target: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMaintenanceSettings", "Deadline", 10usize, |this| ((**(this as *const *const IMaintenanceSettings_Vtbl)).Deadline)(this, target), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExclusive(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMaintenanceSettings", "SetExclusive", 11usize, |this| ((**(this as *const *const IMaintenanceSettings_Vtbl)).SetExclusive)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Exclusive(this: *mut core::ffi::c_void, target: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMaintenanceSettings", "Exclusive", 12usize, |this| ((**(this as *const *const IMaintenanceSettings_Vtbl)).Exclusive)(this, target), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetPeriod, Period, SetDeadline, Deadline, SetExclusive, Exclusive }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMaintenanceSettings as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMonthlyDOWTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DaysOfWeek(this: *mut core::ffi::c_void, pdays: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMonthlyDOWTrigger", "DaysOfWeek", 20usize, |this| ((**(this as *const *const IMonthlyDOWTrigger_Vtbl)).DaysOfWeek)(this, pdays), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDaysOfWeek(this: *mut core::ffi::c_void, days: i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMonthlyDOWTrigger", "SetDaysOfWeek", 21usize, |this| ((**(this as *const *const IMonthlyDOWTrigger_Vtbl)).SetDaysOfWeek)(this, days), |result| Some(*result))
+        }
+        unsafe extern "system" fn WeeksOfMonth(this: *mut core::ffi::c_void, pweeks: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMonthlyDOWTrigger", "WeeksOfMonth", 22usize, |this| ((**(this as *const *const IMonthlyDOWTrigger_Vtbl)).WeeksOfMonth)(this, pweeks), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWeeksOfMonth(this: *mut core::ffi::c_void, weeks: i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMonthlyDOWTrigger", "SetWeeksOfMonth", 23usize, |this| ((**(this as *const *const IMonthlyDOWTrigger_Vtbl)).SetWeeksOfMonth)(this, weeks), |result| Some(*result))
+        }
+        unsafe extern "system" fn MonthsOfYear(this: *mut core::ffi::c_void, pmonths: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMonthlyDOWTrigger", "MonthsOfYear", 24usize, |this| ((**(this as *const *const IMonthlyDOWTrigger_Vtbl)).MonthsOfYear)(this, pmonths), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMonthsOfYear(this: *mut core::ffi::c_void, months: i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMonthlyDOWTrigger", "SetMonthsOfYear", 25usize, |this| ((**(this as *const *const IMonthlyDOWTrigger_Vtbl)).SetMonthsOfYear)(this, months), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunOnLastWeekOfMonth(this: *mut core::ffi::c_void, plastweek: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMonthlyDOWTrigger", "RunOnLastWeekOfMonth", 26usize, |this| ((**(this as *const *const IMonthlyDOWTrigger_Vtbl)).RunOnLastWeekOfMonth)(this, plastweek), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRunOnLastWeekOfMonth(this: *mut core::ffi::c_void, lastweek: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMonthlyDOWTrigger", "SetRunOnLastWeekOfMonth", 27usize, |this| ((**(this as *const *const IMonthlyDOWTrigger_Vtbl)).SetRunOnLastWeekOfMonth)(this, lastweek), |result| Some(*result))
+        }
+        unsafe extern "system" fn RandomDelay(this: *mut core::ffi::c_void, prandomdelay: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMonthlyDOWTrigger", "RandomDelay", 28usize, |this| ((**(this as *const *const IMonthlyDOWTrigger_Vtbl)).RandomDelay)(this, prandomdelay), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRandomDelay(this: *mut core::ffi::c_void, randomdelay: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMonthlyDOWTrigger", "SetRandomDelay", 29usize, |this| ((**(this as *const *const IMonthlyDOWTrigger_Vtbl)).SetRandomDelay)(this, randomdelay), |result| Some(*result))
+        }
+        Self {
+            base__: <ITrigger_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DaysOfWeek,
+            SetDaysOfWeek,
+            WeeksOfMonth,
+            SetWeeksOfMonth,
+            MonthsOfYear,
+            SetMonthsOfYear,
+            RunOnLastWeekOfMonth,
+            SetRunOnLastWeekOfMonth,
+            RandomDelay,
+            SetRandomDelay,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMonthlyDOWTrigger as windows_core::Interface>::IID || <ITrigger_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMonthlyTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DaysOfMonth(this: *mut core::ffi::c_void, pdays: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMonthlyTrigger", "DaysOfMonth", 20usize, |this| ((**(this as *const *const IMonthlyTrigger_Vtbl)).DaysOfMonth)(this, pdays), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDaysOfMonth(this: *mut core::ffi::c_void, days: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMonthlyTrigger", "SetDaysOfMonth", 21usize, |this| ((**(this as *const *const IMonthlyTrigger_Vtbl)).SetDaysOfMonth)(this, days), |result| Some(*result))
+        }
+        unsafe extern "system" fn MonthsOfYear(this: *mut core::ffi::c_void, pmonths: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMonthlyTrigger", "MonthsOfYear", 22usize, |this| ((**(this as *const *const IMonthlyTrigger_Vtbl)).MonthsOfYear)(this, pmonths), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMonthsOfYear(this: *mut core::ffi::c_void, months: i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMonthlyTrigger", "SetMonthsOfYear", 23usize, |this| ((**(this as *const *const IMonthlyTrigger_Vtbl)).SetMonthsOfYear)(this, months), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunOnLastDayOfMonth(this: *mut core::ffi::c_void, plastday: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMonthlyTrigger", "RunOnLastDayOfMonth", 24usize, |this| ((**(this as *const *const IMonthlyTrigger_Vtbl)).RunOnLastDayOfMonth)(this, plastday), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRunOnLastDayOfMonth(this: *mut core::ffi::c_void, lastday: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMonthlyTrigger", "SetRunOnLastDayOfMonth", 25usize, |this| ((**(this as *const *const IMonthlyTrigger_Vtbl)).SetRunOnLastDayOfMonth)(this, lastday), |result| Some(*result))
+        }
+        unsafe extern "system" fn RandomDelay(this: *mut core::ffi::c_void, prandomdelay: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMonthlyTrigger", "RandomDelay", 26usize, |this| ((**(this as *const *const IMonthlyTrigger_Vtbl)).RandomDelay)(this, prandomdelay), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRandomDelay(this: *mut core::ffi::c_void, randomdelay: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMonthlyTrigger", "SetRandomDelay", 27usize, |this| ((**(this as *const *const IMonthlyTrigger_Vtbl)).SetRandomDelay)(this, randomdelay), |result| Some(*result))
+        }
+        Self {
+            base__: <ITrigger_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DaysOfMonth,
+            SetDaysOfMonth,
+            MonthsOfYear,
+            SetMonthsOfYear,
+            RunOnLastDayOfMonth,
+            SetRunOnLastDayOfMonth,
+            RandomDelay,
+            SetRandomDelay,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMonthlyTrigger as windows_core::Interface>::IID || <ITrigger_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for INetworkSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkSettings", "Name", 7usize, |this| ((**(this as *const *const INetworkSettings_Vtbl)).Name)(this, pname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkSettings", "SetName", 8usize, |this| ((**(this as *const *const INetworkSettings_Vtbl)).SetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, pid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkSettings", "Id", 9usize, |this| ((**(this as *const *const INetworkSettings_Vtbl)).Id)(this, pid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetId(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INetworkSettings", "SetId", 10usize, |this| ((**(this as *const *const INetworkSettings_Vtbl)).SetId)(this, id), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, SetName, Id, SetId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INetworkSettings as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrincipal_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, pid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrincipal", "Id", 7usize, |this| ((**(this as *const *const IPrincipal_Vtbl)).Id)(this, pid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetId(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrincipal", "SetId", 8usize, |this| ((**(this as *const *const IPrincipal_Vtbl)).SetId)(this, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, pname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrincipal", "DisplayName", 9usize, |this| ((**(this as *const *const IPrincipal_Vtbl)).DisplayName)(this, pname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrincipal", "SetDisplayName", 10usize, |this| ((**(this as *const *const IPrincipal_Vtbl)).SetDisplayName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserId(this: *mut core::ffi::c_void, puser: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrincipal", "UserId", 11usize, |this| ((**(this as *const *const IPrincipal_Vtbl)).UserId)(this, puser), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUserId(this: *mut core::ffi::c_void, user: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrincipal", "SetUserId", 12usize, |this| ((**(this as *const *const IPrincipal_Vtbl)).SetUserId)(this, user), |result| Some(*result))
+        }
+        unsafe extern "system" fn LogonType(this: *mut core::ffi::c_void, plogon: *mut TASK_LOGON_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrincipal", "LogonType", 13usize, |this| ((**(this as *const *const IPrincipal_Vtbl)).LogonType)(this, plogon), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLogonType(this: *mut core::ffi::c_void, logon: TASK_LOGON_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrincipal", "SetLogonType", 14usize, |this| ((**(this as *const *const IPrincipal_Vtbl)).SetLogonType)(this, logon), |result| Some(*result))
+        }
+        unsafe extern "system" fn GroupId(this: *mut core::ffi::c_void, pgroup: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrincipal", "GroupId", 15usize, |this| ((**(this as *const *const IPrincipal_Vtbl)).GroupId)(this, pgroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGroupId(this: *mut core::ffi::c_void, group: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrincipal", "SetGroupId", 16usize, |this| ((**(this as *const *const IPrincipal_Vtbl)).SetGroupId)(this, group), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunLevel(this: *mut core::ffi::c_void, prunlevel: *mut TASK_RUNLEVEL_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrincipal", "RunLevel", 17usize, |this| ((**(this as *const *const IPrincipal_Vtbl)).RunLevel)(this, prunlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRunLevel(this: *mut core::ffi::c_void, runlevel: TASK_RUNLEVEL_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrincipal", "SetRunLevel", 18usize, |this| ((**(this as *const *const IPrincipal_Vtbl)).SetRunLevel)(this, runlevel), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Id,
+            SetId,
+            DisplayName,
+            SetDisplayName,
+            UserId,
+            SetUserId,
+            LogonType,
+            SetLogonType,
+            GroupId,
+            SetGroupId,
+            RunLevel,
+            SetRunLevel,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrincipal as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPrincipal2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProcessTokenSidType(this: *mut core::ffi::c_void, pprocesstokensidtype: *mut TASK_PROCESSTOKENSID_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrincipal2", "ProcessTokenSidType", 7usize, |this| ((**(this as *const *const IPrincipal2_Vtbl)).ProcessTokenSidType)(this, pprocesstokensidtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProcessTokenSidType(this: *mut core::ffi::c_void, processtokensidtype: TASK_PROCESSTOKENSID_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrincipal2", "SetProcessTokenSidType", 8usize, |this| ((**(this as *const *const IPrincipal2_Vtbl)).SetProcessTokenSidType)(this, processtokensidtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequiredPrivilegeCount(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrincipal2", "RequiredPrivilegeCount", 9usize, |this| ((**(this as *const *const IPrincipal2_Vtbl)).RequiredPrivilegeCount)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_RequiredPrivilege(this: *mut core::ffi::c_void, index: i32, pprivilege: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrincipal2", "get_RequiredPrivilege", 10usize, |this| ((**(this as *const *const IPrincipal2_Vtbl)).get_RequiredPrivilege)(this, index, pprivilege), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddRequiredPrivilege(this: *mut core::ffi::c_void, privilege: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrincipal2", "AddRequiredPrivilege", 11usize, |this| ((**(this as *const *const IPrincipal2_Vtbl)).AddRequiredPrivilege)(this, privilege), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProcessTokenSidType,
+            SetProcessTokenSidType,
+            RequiredPrivilegeCount,
+            get_RequiredPrivilege,
+            AddRequiredPrivilege,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrincipal2 as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Controls")]
+impl windows_core::imp::ProxyVtable for IProvideTaskPage_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_Controls")]
+        unsafe extern "system" fn GetPage(this: *mut core::ffi::c_void, tptype: TASKPAGE, fpersistchanges: super::super::Foundation::BOOL, phpage: *mut super::super::UI::Controls::HPROPSHEETPAGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProvideTaskPage", "GetPage", 3usize, |this| ((**(this as *const *const IProvideTaskPage_Vtbl)).GetPage)(this, tptype, fpersistchanges, phpage), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_Controls")]
+            GetPage,
+            #[cfg(not(feature = "Win32_UI_Controls"))]
+            GetPage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProvideTaskPage as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRegisteredTask_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisteredTask", "Name", 7usize, |this| ((**(this as *const *const IRegisteredTask_Vtbl)).Name)(this, pname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, ppath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisteredTask", "Path", 8usize, |this| ((**(this as *const *const IRegisteredTask_Vtbl)).Path)(this, ppath), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, pstate: *mut TASK_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisteredTask", "State", 9usize, |this| ((**(this as *const *const IRegisteredTask_Vtbl)).State)(this, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, penabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisteredTask", "Enabled", 10usize, |this| ((**(this as *const *const IRegisteredTask_Vtbl)).Enabled)(this, penabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, enabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisteredTask", "SetEnabled", 11usize, |this| ((**(this as *const *const IRegisteredTask_Vtbl)).SetEnabled)(this, enabled), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Run(this: *mut core::ffi::c_void, params: core::mem::MaybeUninit<windows_core::VARIANT>, pprunningtask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisteredTask", "Run", 12usize, |this| ((**(this as *const *const IRegisteredTask_Vtbl)).Run)(this, params, pprunningtask), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RunEx(this: *mut core::ffi::c_void, params: core::mem::MaybeUninit<windows_core::VARIANT>, flags: i32, sessionid: i32, user: core::mem::MaybeUninit<windows_core::BSTR>, pprunningtask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisteredTask", "RunEx", 13usize, |this| ((**(this as *const *const IRegisteredTask_Vtbl)).RunEx)(this, params, flags, sessionid, user, pprunningtask), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetInstances(this: *mut core::ffi::c_void, flags: i32, pprunningtasks: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisteredTask", "GetInstances", 14usize, |this| ((**(this as *const *const IRegisteredTask_Vtbl)).GetInstances)(this, flags, pprunningtasks), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastRunTime(this: *mut core::ffi::c_void, plastruntime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisteredTask", "LastRunTime", 15usize, |this| ((**(this as *const *const IRegisteredTask_Vtbl)).LastRunTime)(this, plastruntime), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastTaskResult(this: *mut core::ffi::c_void, plasttaskresult: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisteredTask", "LastTaskResult", 16usize, |this| ((**(this as *const *const IRegisteredTask_Vtbl)).LastTaskResult)(this, plasttaskresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn NumberOfMissedRuns(this: *mut core::ffi::c_void, pnumberofmissedruns: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisteredTask", "NumberOfMissedRuns", 17usize, |this| ((**(this as *const *const IRegisteredTask_Vtbl)).NumberOfMissedRuns)(this, pnumberofmissedruns), |result| Some(*result))
+        }
+        unsafe extern "system" fn NextRunTime(this: *mut core::ffi::c_void, pnextruntime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisteredTask", "NextRunTime", 18usize, |this| ((**(this as *const *const IRegisteredTask_Vtbl)).NextRunTime)(this, pnextruntime), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Definition(this: *mut core::ffi::c_void, ppdefinition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisteredTask", "Definition", 19usize, |this| ((**(this as *const *const IRegisteredTask_Vtbl)).Definition)(this, ppdefinition), |result| Some(*result))
+        }
+        unsafe extern "system" fn Xml(this: *mut core::ffi::c_void, pxml: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisteredTask", "Xml", 20usize, |this| ((**(this as *const *const IRegisteredTask_Vtbl)).Xml)(this, pxml), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSecurityDescriptor(this: *mut core::ffi::c_void, securityinformation: i32, psddl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisteredTask", "GetSecurityDescriptor", 21usize, |this| ((**(this as *const *const IRegisteredTask_Vtbl)).GetSecurityDescriptor)(this, securityinformation, psddl), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSecurityDescriptor(this: *mut core::ffi::c_void, sddl: core::mem::MaybeUninit<windows_core::BSTR>, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisteredTask", "SetSecurityDescriptor", 22usize, |this| ((**(this as *const *const IRegisteredTask_Vtbl)).SetSecurityDescriptor)(this, sddl, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisteredTask", "Stop", 23usize, |this| ((**(this as *const *const IRegisteredTask_Vtbl)).Stop)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRunTimes(this: *mut core::ffi::c_void, pststart: *const super::super::Foundation::SYSTEMTIME, pstend: *const super::super::Foundation::SYSTEMTIME, pcount: *mut u32, pruntimes: *mut *mut super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisteredTask", "GetRunTimes", 24usize, |this| ((**(this as *const *const IRegisteredTask_Vtbl)).GetRunTimes)(this, pststart, pstend, pcount, pruntimes), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            Path,
+            State,
+            Enabled,
+            SetEnabled,
+            #[cfg(feature = "Win32_System_Com")]
+            Run,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Run: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            RunEx,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RunEx: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetInstances,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetInstances: 0,
+            LastRunTime,
+            LastTaskResult,
+            NumberOfMissedRuns,
+            NextRunTime,
+            #[cfg(feature = "Win32_System_Com")]
+            Definition,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Definition: 0,
+            Xml,
+            GetSecurityDescriptor,
+            SetSecurityDescriptor,
+            Stop,
+            GetRunTimes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRegisteredTask as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRegisteredTaskCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisteredTaskCollection", "Count", 7usize, |this| ((**(this as *const *const IRegisteredTaskCollection_Vtbl)).Count)(this, pcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>, ppregisteredtask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisteredTaskCollection", "get_Item", 8usize, |this| ((**(this as *const *const IRegisteredTaskCollection_Vtbl)).get_Item)(this, index, ppregisteredtask), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisteredTaskCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const IRegisteredTaskCollection_Vtbl))._NewEnum)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRegisteredTaskCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRegistrationInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, pdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegistrationInfo", "Description", 7usize, |this| ((**(this as *const *const IRegistrationInfo_Vtbl)).Description)(this, pdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDescription(this: *mut core::ffi::c_void, description: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegistrationInfo", "SetDescription", 8usize, |this| ((**(this as *const *const IRegistrationInfo_Vtbl)).SetDescription)(this, description), |result| Some(*result))
+        }
+        unsafe extern "system" fn Author(this: *mut core::ffi::c_void, pauthor: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegistrationInfo", "Author", 9usize, |this| ((**(this as *const *const IRegistrationInfo_Vtbl)).Author)(this, pauthor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthor(this: *mut core::ffi::c_void, author: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegistrationInfo", "SetAuthor", 10usize, |this| ((**(this as *const *const IRegistrationInfo_Vtbl)).SetAuthor)(this, author), |result| Some(*result))
+        }
+        unsafe extern "system" fn Version(this: *mut core::ffi::c_void, pversion: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegistrationInfo", "Version", 11usize, |this| ((**(this as *const *const IRegistrationInfo_Vtbl)).Version)(this, pversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVersion(this: *mut core::ffi::c_void, version: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegistrationInfo", "SetVersion", 12usize, |this| ((**(this as *const *const IRegistrationInfo_Vtbl)).SetVersion)(this, version), |result| Some(*result))
+        }
+        unsafe extern "system" fn Date(this: *mut core::ffi::c_void, pdate: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegistrationInfo", "Date", 13usize, |this| ((**(this as *const *const IRegistrationInfo_Vtbl)).Date)(this, pdate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDate(this: *mut core::ffi::c_void, date: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegistrationInfo", "SetDate", 14usize, |this| ((**(this as *const *const IRegistrationInfo_Vtbl)).SetDate)(this, date), |result| Some(*result))
+        }
+        unsafe extern "system" fn Documentation(this: *mut core::ffi::c_void, pdocumentation: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegistrationInfo", "Documentation", 15usize, |this| ((**(this as *const *const IRegistrationInfo_Vtbl)).Documentation)(this, pdocumentation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDocumentation(this: *mut core::ffi::c_void, documentation: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegistrationInfo", "SetDocumentation", 16usize, |this| ((**(this as *const *const IRegistrationInfo_Vtbl)).SetDocumentation)(this, documentation), |result| Some(*result))
+        }
+        unsafe extern "system" fn XmlText(this: *mut core::ffi::c_void, ptext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegistrationInfo", "XmlText", 17usize, |this| ((**(this as *const *const IRegistrationInfo_Vtbl)).XmlText)(this, ptext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetXmlText(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegistrationInfo", "SetXmlText", 18usize, |this| ((**(this as *const *const IRegistrationInfo_Vtbl)).SetXmlText)(this, text), |result| Some(*result))
+        }
+        unsafe extern "system" fn URI(this: *mut core::ffi::c_void, puri: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegistrationInfo", "URI", 19usize, |this| ((**(this as *const *const IRegistrationInfo_Vtbl)).URI)(this, puri), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetURI(this: *mut core::ffi::c_void, uri: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegistrationInfo", "SetURI", 20usize, |this| ((**(this as *const *const IRegistrationInfo_Vtbl)).SetURI)(this, uri), |result| Some(*result))
+        }
+        unsafe extern "system" fn SecurityDescriptor(this: *mut core::ffi::c_void, psddl: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegistrationInfo", "SecurityDescriptor", 21usize, |this| ((**(this as *const *const IRegistrationInfo_Vtbl)).SecurityDescriptor)(this, psddl), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSecurityDescriptor(this: *mut core::ffi::c_void, sddl: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegistrationInfo", "SetSecurityDescriptor", 22usize, |this| ((**(this as *const *const IRegistrationInfo_Vtbl)).SetSecurityDescriptor)(this, sddl), |result| Some(*result))
+        }
+        unsafe extern "system" fn Source(this: *mut core::ffi::c_void, psource: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegistrationInfo", "Source", 23usize, |this| ((**(this as *const *const IRegistrationInfo_Vtbl)).Source)(this, psource), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSource(this: *mut core::ffi::c_void, source: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegistrationInfo", "SetSource", 24usize, |this| ((**(this as *const *const IRegistrationInfo_Vtbl)).SetSource)(this, source), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Description,
+            SetDescription,
+            Author,
+            SetAuthor,
+            Version,
+            SetVersion,
+            Date,
+            SetDate,
+            Documentation,
+            SetDocumentation,
+            XmlText,
+            SetXmlText,
+            URI,
+            SetURI,
+            SecurityDescriptor,
+            SetSecurityDescriptor,
+            Source,
+            SetSource,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRegistrationInfo as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRegistrationTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Delay(this: *mut core::ffi::c_void, pdelay: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegistrationTrigger", "Delay", 20usize, |this| ((**(this as *const *const IRegistrationTrigger_Vtbl)).Delay)(this, pdelay), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDelay(this: *mut core::ffi::c_void, delay: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegistrationTrigger", "SetDelay", 21usize, |this| ((**(this as *const *const IRegistrationTrigger_Vtbl)).SetDelay)(this, delay), |result| Some(*result))
+        }
+        Self { base__: <ITrigger_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Delay, SetDelay }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRegistrationTrigger as windows_core::Interface>::IID || <ITrigger_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRepetitionPattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Interval(this: *mut core::ffi::c_void, pinterval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRepetitionPattern", "Interval", 7usize, |this| ((**(this as *const *const IRepetitionPattern_Vtbl)).Interval)(this, pinterval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInterval(this: *mut core::ffi::c_void, interval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRepetitionPattern", "SetInterval", 8usize, |this| ((**(this as *const *const IRepetitionPattern_Vtbl)).SetInterval)(this, interval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Duration(this: *mut core::ffi::c_void, pduration: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRepetitionPattern", "Duration", 9usize, |this| ((**(this as *const *const IRepetitionPattern_Vtbl)).Duration)(this, pduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDuration(this: *mut core::ffi::c_void, duration: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRepetitionPattern", "SetDuration", 10usize, |this| ((**(this as *const *const IRepetitionPattern_Vtbl)).SetDuration)(this, duration), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopAtDurationEnd(this: *mut core::ffi::c_void, pstop: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRepetitionPattern", "StopAtDurationEnd", 11usize, |this| ((**(this as *const *const IRepetitionPattern_Vtbl)).StopAtDurationEnd)(this, pstop), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStopAtDurationEnd(this: *mut core::ffi::c_void, stop: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRepetitionPattern", "SetStopAtDurationEnd", 12usize, |this| ((**(this as *const *const IRepetitionPattern_Vtbl)).SetStopAtDurationEnd)(this, stop), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Interval,
+            SetInterval,
+            Duration,
+            SetDuration,
+            StopAtDurationEnd,
+            SetStopAtDurationEnd,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRepetitionPattern as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRunningTask_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRunningTask", "Name", 7usize, |this| ((**(this as *const *const IRunningTask_Vtbl)).Name)(this, pname), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstanceGuid(this: *mut core::ffi::c_void, pguid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRunningTask", "InstanceGuid", 8usize, |this| ((**(this as *const *const IRunningTask_Vtbl)).InstanceGuid)(this, pguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, ppath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRunningTask", "Path", 9usize, |this| ((**(this as *const *const IRunningTask_Vtbl)).Path)(this, ppath), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, pstate: *mut TASK_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRunningTask", "State", 10usize, |this| ((**(this as *const *const IRunningTask_Vtbl)).State)(this, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentAction(this: *mut core::ffi::c_void, pname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRunningTask", "CurrentAction", 11usize, |this| ((**(this as *const *const IRunningTask_Vtbl)).CurrentAction)(this, pname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRunningTask", "Stop", 12usize, |this| ((**(this as *const *const IRunningTask_Vtbl)).Stop)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRunningTask", "Refresh", 13usize, |this| ((**(this as *const *const IRunningTask_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnginePID(this: *mut core::ffi::c_void, ppid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRunningTask", "EnginePID", 14usize, |this| ((**(this as *const *const IRunningTask_Vtbl)).EnginePID)(this, ppid), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            InstanceGuid,
+            Path,
+            State,
+            CurrentAction,
+            Stop,
+            Refresh,
+            EnginePID,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRunningTask as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRunningTaskCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRunningTaskCollection", "Count", 7usize, |this| ((**(this as *const *const IRunningTaskCollection_Vtbl)).Count)(this, pcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>, pprunningtask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRunningTaskCollection", "get_Item", 8usize, |this| ((**(this as *const *const IRunningTaskCollection_Vtbl)).get_Item)(this, index, pprunningtask), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRunningTaskCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const IRunningTaskCollection_Vtbl))._NewEnum)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRunningTaskCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IScheduledWorkItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateTrigger(this: *mut core::ffi::c_void, pinewtrigger: *mut u16, pptrigger: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "CreateTrigger", 3usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).CreateTrigger)(this, pinewtrigger, pptrigger), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteTrigger(this: *mut core::ffi::c_void, itrigger: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "DeleteTrigger", 4usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).DeleteTrigger)(this, itrigger), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTriggerCount(this: *mut core::ffi::c_void, pwcount: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "GetTriggerCount", 5usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).GetTriggerCount)(this, pwcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTrigger(this: *mut core::ffi::c_void, itrigger: u16, pptrigger: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "GetTrigger", 6usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).GetTrigger)(this, itrigger, pptrigger), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTriggerString(this: *mut core::ffi::c_void, itrigger: u16, ppwsztrigger: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "GetTriggerString", 7usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).GetTriggerString)(this, itrigger, ppwsztrigger), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRunTimes(this: *mut core::ffi::c_void, pstbegin: *const super::super::Foundation::SYSTEMTIME, pstend: *const super::super::Foundation::SYSTEMTIME, pcount: *mut u16, rgsttasktimes: *mut *mut super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "GetRunTimes", 8usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).GetRunTimes)(this, pstbegin, pstend, pcount, rgsttasktimes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextRunTime(this: *mut core::ffi::c_void, pstnextrun: *mut super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "GetNextRunTime", 9usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).GetNextRunTime)(this, pstnextrun), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIdleWait(this: *mut core::ffi::c_void, widleminutes: u16, wdeadlineminutes: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "SetIdleWait", 10usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).SetIdleWait)(this, widleminutes, wdeadlineminutes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIdleWait(this: *mut core::ffi::c_void, pwidleminutes: *mut u16, pwdeadlineminutes: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "GetIdleWait", 11usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).GetIdleWait)(this, pwidleminutes, pwdeadlineminutes), |result| Some(*result))
+        }
+        unsafe extern "system" fn Run(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "Run", 12usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).Run)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Terminate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "Terminate", 13usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).Terminate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EditWorkItem(this: *mut core::ffi::c_void, hparent: super::super::Foundation::HWND, dwreserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "EditWorkItem", 14usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).EditWorkItem)(this, hparent, dwreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMostRecentRunTime(this: *mut core::ffi::c_void, pstlastrun: *mut super::super::Foundation::SYSTEMTIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "GetMostRecentRunTime", 15usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).GetMostRecentRunTime)(this, pstlastrun), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, phrstatus: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "GetStatus", 16usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).GetStatus)(this, phrstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExitCode(this: *mut core::ffi::c_void, pdwexitcode: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "GetExitCode", 17usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).GetExitCode)(this, pdwexitcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetComment(this: *mut core::ffi::c_void, pwszcomment: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "SetComment", 18usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).SetComment)(this, pwszcomment), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetComment(this: *mut core::ffi::c_void, ppwszcomment: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "GetComment", 19usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).GetComment)(this, ppwszcomment), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCreator(this: *mut core::ffi::c_void, pwszcreator: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "SetCreator", 20usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).SetCreator)(this, pwszcreator), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCreator(this: *mut core::ffi::c_void, ppwszcreator: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "GetCreator", 21usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).GetCreator)(this, ppwszcreator), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWorkItemData(this: *mut core::ffi::c_void, cbdata: u16, rgbdata: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "SetWorkItemData", 22usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).SetWorkItemData)(this, cbdata, rgbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWorkItemData(this: *mut core::ffi::c_void, pcbdata: *mut u16, prgbdata: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "GetWorkItemData", 23usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).GetWorkItemData)(this, pcbdata, prgbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetErrorRetryCount(this: *mut core::ffi::c_void, wretrycount: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "SetErrorRetryCount", 24usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).SetErrorRetryCount)(this, wretrycount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetErrorRetryCount(this: *mut core::ffi::c_void, pwretrycount: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "GetErrorRetryCount", 25usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).GetErrorRetryCount)(this, pwretrycount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetErrorRetryInterval(this: *mut core::ffi::c_void, wretryinterval: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "SetErrorRetryInterval", 26usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).SetErrorRetryInterval)(this, wretryinterval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetErrorRetryInterval(this: *mut core::ffi::c_void, pwretryinterval: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "GetErrorRetryInterval", 27usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).GetErrorRetryInterval)(this, pwretryinterval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFlags(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "SetFlags", 28usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).SetFlags)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlags(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "GetFlags", 29usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).GetFlags)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAccountInformation(this: *mut core::ffi::c_void, pwszaccountname: windows_core::PCWSTR, pwszpassword: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "SetAccountInformation", 30usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).SetAccountInformation)(this, pwszaccountname, pwszpassword), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAccountInformation(this: *mut core::ffi::c_void, ppwszaccountname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScheduledWorkItem", "GetAccountInformation", 31usize, |this| ((**(this as *const *const IScheduledWorkItem_Vtbl)).GetAccountInformation)(this, ppwszaccountname), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateTrigger,
+            DeleteTrigger,
+            GetTriggerCount,
+            GetTrigger,
+            GetTriggerString,
+            GetRunTimes,
+            GetNextRunTime,
+            SetIdleWait,
+            GetIdleWait,
+            Run,
+            Terminate,
+            EditWorkItem,
+            GetMostRecentRunTime,
+            GetStatus,
+            GetExitCode,
+            SetComment,
+            GetComment,
+            SetCreator,
+            GetCreator,
+            SetWorkItemData,
+            GetWorkItemData,
+            SetErrorRetryCount,
+            GetErrorRetryCount,
+            SetErrorRetryInterval,
+            GetErrorRetryInterval,
+            SetFlags,
+            GetFlags,
+            SetAccountInformation,
+            GetAccountInformation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScheduledWorkItem as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISessionStateChangeTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Delay(this: *mut core::ffi::c_void, pdelay: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISessionStateChangeTrigger", "Delay", 20usize, |this| ((**(this as *const *const ISessionStateChangeTrigger_Vtbl)).Delay)(this, pdelay), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDelay(this: *mut core::ffi::c_void, delay: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISessionStateChangeTrigger", "SetDelay", 21usize, |this| ((**(this as *const *const ISessionStateChangeTrigger_Vtbl)).SetDelay)(this, delay), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserId(this: *mut core::ffi::c_void, puser: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISessionStateChangeTrigger", "UserId", 22usize, |this| ((**(this as *const *const ISessionStateChangeTrigger_Vtbl)).UserId)(this, puser), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUserId(this: *mut core::ffi::c_void, user: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISessionStateChangeTrigger", "SetUserId", 23usize, |this| ((**(this as *const *const ISessionStateChangeTrigger_Vtbl)).SetUserId)(this, user), |result| Some(*result))
+        }
+        unsafe extern "system" fn StateChange(this: *mut core::ffi::c_void, ptype: *mut TASK_SESSION_STATE_CHANGE_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISessionStateChangeTrigger", "StateChange", 24usize, |this| ((**(this as *const *const ISessionStateChangeTrigger_Vtbl)).StateChange)(this, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStateChange(this: *mut core::ffi::c_void, r#type: TASK_SESSION_STATE_CHANGE_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISessionStateChangeTrigger", "SetStateChange", 25usize, |this| ((**(this as *const *const ISessionStateChangeTrigger_Vtbl)).SetStateChange)(this, r#type), |result| Some(*result))
+        }
+        Self { base__: <ITrigger_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Delay, SetDelay, UserId, SetUserId, StateChange, SetStateChange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISessionStateChangeTrigger as windows_core::Interface>::IID || <ITrigger_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IShowMessageAction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, ptitle: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShowMessageAction", "Title", 10usize, |this| ((**(this as *const *const IShowMessageAction_Vtbl)).Title)(this, ptitle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTitle(this: *mut core::ffi::c_void, title: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShowMessageAction", "SetTitle", 11usize, |this| ((**(this as *const *const IShowMessageAction_Vtbl)).SetTitle)(this, title), |result| Some(*result))
+        }
+        unsafe extern "system" fn MessageBody(this: *mut core::ffi::c_void, pmessagebody: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShowMessageAction", "MessageBody", 12usize, |this| ((**(this as *const *const IShowMessageAction_Vtbl)).MessageBody)(this, pmessagebody), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMessageBody(this: *mut core::ffi::c_void, messagebody: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShowMessageAction", "SetMessageBody", 13usize, |this| ((**(this as *const *const IShowMessageAction_Vtbl)).SetMessageBody)(this, messagebody), |result| Some(*result))
+        }
+        Self { base__: <IAction_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Title, SetTitle, MessageBody, SetMessageBody }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IShowMessageAction as windows_core::Interface>::IID || <IAction_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITask_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetApplicationName(this: *mut core::ffi::c_void, pwszapplicationname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITask", "SetApplicationName", 32usize, |this| ((**(this as *const *const ITask_Vtbl)).SetApplicationName)(this, pwszapplicationname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetApplicationName(this: *mut core::ffi::c_void, ppwszapplicationname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITask", "GetApplicationName", 33usize, |this| ((**(this as *const *const ITask_Vtbl)).GetApplicationName)(this, ppwszapplicationname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParameters(this: *mut core::ffi::c_void, pwszparameters: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITask", "SetParameters", 34usize, |this| ((**(this as *const *const ITask_Vtbl)).SetParameters)(this, pwszparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParameters(this: *mut core::ffi::c_void, ppwszparameters: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITask", "GetParameters", 35usize, |this| ((**(this as *const *const ITask_Vtbl)).GetParameters)(this, ppwszparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWorkingDirectory(this: *mut core::ffi::c_void, pwszworkingdirectory: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITask", "SetWorkingDirectory", 36usize, |this| ((**(this as *const *const ITask_Vtbl)).SetWorkingDirectory)(this, pwszworkingdirectory), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWorkingDirectory(this: *mut core::ffi::c_void, ppwszworkingdirectory: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITask", "GetWorkingDirectory", 37usize, |this| ((**(this as *const *const ITask_Vtbl)).GetWorkingDirectory)(this, ppwszworkingdirectory), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPriority(this: *mut core::ffi::c_void, dwpriority: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITask", "SetPriority", 38usize, |this| ((**(this as *const *const ITask_Vtbl)).SetPriority)(this, dwpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPriority(this: *mut core::ffi::c_void, pdwpriority: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITask", "GetPriority", 39usize, |this| ((**(this as *const *const ITask_Vtbl)).GetPriority)(this, pdwpriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTaskFlags(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITask", "SetTaskFlags", 40usize, |this| ((**(this as *const *const ITask_Vtbl)).SetTaskFlags)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTaskFlags(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITask", "GetTaskFlags", 41usize, |this| ((**(this as *const *const ITask_Vtbl)).GetTaskFlags)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxRunTime(this: *mut core::ffi::c_void, dwmaxruntimems: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITask", "SetMaxRunTime", 42usize, |this| ((**(this as *const *const ITask_Vtbl)).SetMaxRunTime)(this, dwmaxruntimems), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxRunTime(this: *mut core::ffi::c_void, pdwmaxruntimems: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITask", "GetMaxRunTime", 43usize, |this| ((**(this as *const *const ITask_Vtbl)).GetMaxRunTime)(this, pdwmaxruntimems), |result| Some(*result))
+        }
+        Self {
+            base__: <IScheduledWorkItem_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetApplicationName,
+            GetApplicationName,
+            SetParameters,
+            GetParameters,
+            SetWorkingDirectory,
+            GetWorkingDirectory,
+            SetPriority,
+            GetPriority,
+            SetTaskFlags,
+            GetTaskFlags,
+            SetMaxRunTime,
+            GetMaxRunTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITask as windows_core::Interface>::IID || <IScheduledWorkItem_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITaskDefinition_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RegistrationInfo(this: *mut core::ffi::c_void, ppregistrationinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskDefinition", "RegistrationInfo", 7usize, |this| ((**(this as *const *const ITaskDefinition_Vtbl)).RegistrationInfo)(this, ppregistrationinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetRegistrationInfo(this: *mut core::ffi::c_void, pregistrationinfo: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskDefinition", "SetRegistrationInfo", 8usize, |this| ((**(this as *const *const ITaskDefinition_Vtbl)).SetRegistrationInfo)(this, pregistrationinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Triggers(this: *mut core::ffi::c_void, pptriggers: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskDefinition", "Triggers", 9usize, |this| ((**(this as *const *const ITaskDefinition_Vtbl)).Triggers)(this, pptriggers), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetTriggers(this: *mut core::ffi::c_void, ptriggers: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskDefinition", "SetTriggers", 10usize, |this| ((**(this as *const *const ITaskDefinition_Vtbl)).SetTriggers)(this, ptriggers), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Settings(this: *mut core::ffi::c_void, ppsettings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskDefinition", "Settings", 11usize, |this| ((**(this as *const *const ITaskDefinition_Vtbl)).Settings)(this, ppsettings), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSettings(this: *mut core::ffi::c_void, psettings: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskDefinition", "SetSettings", 12usize, |this| ((**(this as *const *const ITaskDefinition_Vtbl)).SetSettings)(this, psettings), |result| Some(*result))
+        }
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, pdata: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskDefinition", "Data", 13usize, |this| ((**(this as *const *const ITaskDefinition_Vtbl)).Data)(this, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, data: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskDefinition", "SetData", 14usize, |this| ((**(this as *const *const ITaskDefinition_Vtbl)).SetData)(this, data), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Principal(this: *mut core::ffi::c_void, ppprincipal: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskDefinition", "Principal", 15usize, |this| ((**(this as *const *const ITaskDefinition_Vtbl)).Principal)(this, ppprincipal), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetPrincipal(this: *mut core::ffi::c_void, pprincipal: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskDefinition", "SetPrincipal", 16usize, |this| ((**(this as *const *const ITaskDefinition_Vtbl)).SetPrincipal)(this, pprincipal), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Actions(this: *mut core::ffi::c_void, ppactions: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskDefinition", "Actions", 17usize, |this| ((**(this as *const *const ITaskDefinition_Vtbl)).Actions)(this, ppactions), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetActions(this: *mut core::ffi::c_void, pactions: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskDefinition", "SetActions", 18usize, |this| ((**(this as *const *const ITaskDefinition_Vtbl)).SetActions)(this, pactions), |result| Some(*result))
+        }
+        unsafe extern "system" fn XmlText(this: *mut core::ffi::c_void, pxml: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskDefinition", "XmlText", 19usize, |this| ((**(this as *const *const ITaskDefinition_Vtbl)).XmlText)(this, pxml), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetXmlText(this: *mut core::ffi::c_void, xml: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskDefinition", "SetXmlText", 20usize, |this| ((**(this as *const *const ITaskDefinition_Vtbl)).SetXmlText)(this, xml), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            RegistrationInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RegistrationInfo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetRegistrationInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetRegistrationInfo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Triggers,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Triggers: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetTriggers,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetTriggers: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Settings,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Settings: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSettings,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSettings: 0,
+            Data,
+            SetData,
+            #[cfg(feature = "Win32_System_Com")]
+            Principal,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Principal: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetPrincipal,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetPrincipal: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Actions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Actions: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetActions,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetActions: 0,
+            XmlText,
+            SetXmlText,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITaskDefinition as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITaskFolder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskFolder", "Name", 7usize, |this| ((**(this as *const *const ITaskFolder_Vtbl)).Name)(this, pname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, ppath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskFolder", "Path", 8usize, |this| ((**(this as *const *const ITaskFolder_Vtbl)).Path)(this, ppath), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFolder(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, ppfolder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskFolder", "GetFolder", 9usize, |this| ((**(this as *const *const ITaskFolder_Vtbl)).GetFolder)(this, path, ppfolder), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFolders(this: *mut core::ffi::c_void, flags: i32, ppfolders: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskFolder", "GetFolders", 10usize, |this| ((**(this as *const *const ITaskFolder_Vtbl)).GetFolders)(this, flags, ppfolders), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateFolder(this: *mut core::ffi::c_void, subfoldername: core::mem::MaybeUninit<windows_core::BSTR>, sddl: core::mem::MaybeUninit<windows_core::VARIANT>, ppfolder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskFolder", "CreateFolder", 11usize, |this| ((**(this as *const *const ITaskFolder_Vtbl)).CreateFolder)(this, subfoldername, sddl, ppfolder), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteFolder(this: *mut core::ffi::c_void, subfoldername: core::mem::MaybeUninit<windows_core::BSTR>, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskFolder", "DeleteFolder", 12usize, |this| ((**(this as *const *const ITaskFolder_Vtbl)).DeleteFolder)(this, subfoldername, flags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetTask(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, pptask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskFolder", "GetTask", 13usize, |this| ((**(this as *const *const ITaskFolder_Vtbl)).GetTask)(this, path, pptask), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetTasks(this: *mut core::ffi::c_void, flags: i32, pptasks: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskFolder", "GetTasks", 14usize, |this| ((**(this as *const *const ITaskFolder_Vtbl)).GetTasks)(this, flags, pptasks), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteTask(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskFolder", "DeleteTask", 15usize, |this| ((**(this as *const *const ITaskFolder_Vtbl)).DeleteTask)(this, name, flags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RegisterTask(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, xmltext: core::mem::MaybeUninit<windows_core::BSTR>, flags: i32, userid: core::mem::MaybeUninit<windows_core::VARIANT>, password: core::mem::MaybeUninit<windows_core::VARIANT>, logontype: TASK_LOGON_TYPE, sddl: core::mem::MaybeUninit<windows_core::VARIANT>, pptask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskFolder", "RegisterTask", 16usize, |this| ((**(this as *const *const ITaskFolder_Vtbl)).RegisterTask)(this, path, xmltext, flags, userid, password, logontype, sddl, pptask), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RegisterTaskDefinition(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, pdefinition: *mut core::ffi::c_void, flags: i32, userid: core::mem::MaybeUninit<windows_core::VARIANT>, password: core::mem::MaybeUninit<windows_core::VARIANT>, logontype: TASK_LOGON_TYPE, sddl: core::mem::MaybeUninit<windows_core::VARIANT>, pptask: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskFolder", "RegisterTaskDefinition", 17usize, |this| ((**(this as *const *const ITaskFolder_Vtbl)).RegisterTaskDefinition)(this, path, pdefinition, flags, userid, password, logontype, sddl, pptask), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSecurityDescriptor(this: *mut core::ffi::c_void, securityinformation: i32, psddl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskFolder", "GetSecurityDescriptor", 18usize, |this| ((**(this as *const *const ITaskFolder_Vtbl)).GetSecurityDescriptor)(this, securityinformation, psddl), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSecurityDescriptor(this: *mut core::ffi::c_void, sddl: core::mem::MaybeUninit<windows_core::BSTR>, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskFolder", "SetSecurityDescriptor", 19usize, |this| ((**(this as *const *const ITaskFolder_Vtbl)).SetSecurityDescriptor)(this, sddl, flags), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            Path,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFolder,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFolder: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFolders,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFolders: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateFolder,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateFolder: 0,
+            DeleteFolder,
+            #[cfg(feature = "Win32_System_Com")]
+            GetTask,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetTask: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetTasks,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetTasks: 0,
+            DeleteTask,
+            #[cfg(feature = "Win32_System_Com")]
+            RegisterTask,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RegisterTask: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            RegisterTaskDefinition,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RegisterTaskDefinition: 0,
+            GetSecurityDescriptor,
+            SetSecurityDescriptor,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITaskFolder as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITaskFolderCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskFolderCollection", "Count", 7usize, |this| ((**(this as *const *const ITaskFolderCollection_Vtbl)).Count)(this, pcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>, ppfolder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskFolderCollection", "get_Item", 8usize, |this| ((**(this as *const *const ITaskFolderCollection_Vtbl)).get_Item)(this, index, ppfolder), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskFolderCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const ITaskFolderCollection_Vtbl))._NewEnum)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            _NewEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITaskFolderCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITaskHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void, phandlerservices: *mut core::ffi::c_void, data: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskHandler", "Start", 3usize, |this| ((**(this as *const *const ITaskHandler_Vtbl)).Start)(this, phandlerservices, data), |result| Some(*result))
+        }
+        unsafe extern "system" fn Stop(this: *mut core::ffi::c_void, pretcode: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskHandler", "Stop", 4usize, |this| ((**(this as *const *const ITaskHandler_Vtbl)).Stop)(this, pretcode), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskHandler", "Pause", 5usize, |this| ((**(this as *const *const ITaskHandler_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskHandler", "Resume", 6usize, |this| ((**(this as *const *const ITaskHandler_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Start, Stop, Pause, Resume }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITaskHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITaskHandlerStatus_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UpdateStatus(this: *mut core::ffi::c_void, percentcomplete: i16, statusmessage: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskHandlerStatus", "UpdateStatus", 3usize, |this| ((**(this as *const *const ITaskHandlerStatus_Vtbl)).UpdateStatus)(this, percentcomplete, statusmessage), |result| Some(*result))
+        }
+        unsafe extern "system" fn TaskCompleted(this: *mut core::ffi::c_void, taskerrcode: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskHandlerStatus", "TaskCompleted", 4usize, |this| ((**(this as *const *const ITaskHandlerStatus_Vtbl)).TaskCompleted)(this, taskerrcode), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UpdateStatus, TaskCompleted }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITaskHandlerStatus as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITaskNamedValueCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskNamedValueCollection", "Count", 7usize, |this| ((**(this as *const *const ITaskNamedValueCollection_Vtbl)).Count)(this, pcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, pppair: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskNamedValueCollection", "get_Item", 8usize, |this| ((**(this as *const *const ITaskNamedValueCollection_Vtbl)).get_Item)(this, index, pppair), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskNamedValueCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const ITaskNamedValueCollection_Vtbl))._NewEnum)(this, ppenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, value: core::mem::MaybeUninit<windows_core::BSTR>, pppair: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskNamedValueCollection", "Create", 10usize, |this| ((**(this as *const *const ITaskNamedValueCollection_Vtbl)).Create)(this, name, value, pppair), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskNamedValueCollection", "Remove", 11usize, |this| ((**(this as *const *const ITaskNamedValueCollection_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskNamedValueCollection", "Clear", 12usize, |this| ((**(this as *const *const ITaskNamedValueCollection_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Create,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Create: 0,
+            Remove,
+            Clear,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITaskNamedValueCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITaskNamedValuePair_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskNamedValuePair", "Name", 7usize, |this| ((**(this as *const *const ITaskNamedValuePair_Vtbl)).Name)(this, pname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskNamedValuePair", "SetName", 8usize, |this| ((**(this as *const *const ITaskNamedValuePair_Vtbl)).SetName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, pvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskNamedValuePair", "Value", 9usize, |this| ((**(this as *const *const ITaskNamedValuePair_Vtbl)).Value)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskNamedValuePair", "SetValue", 10usize, |this| ((**(this as *const *const ITaskNamedValuePair_Vtbl)).SetValue)(this, value), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, SetName, Value, SetValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITaskNamedValuePair as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITaskScheduler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetTargetComputer(this: *mut core::ffi::c_void, pwszcomputer: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskScheduler", "SetTargetComputer", 3usize, |this| ((**(this as *const *const ITaskScheduler_Vtbl)).SetTargetComputer)(this, pwszcomputer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTargetComputer(this: *mut core::ffi::c_void, ppwszcomputer: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskScheduler", "GetTargetComputer", 4usize, |this| ((**(this as *const *const ITaskScheduler_Vtbl)).GetTargetComputer)(this, ppwszcomputer), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enum(this: *mut core::ffi::c_void, ppenumworkitems: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskScheduler", "Enum", 5usize, |this| ((**(this as *const *const ITaskScheduler_Vtbl)).Enum)(this, ppenumworkitems), |result| Some(*result))
+        }
+        unsafe extern "system" fn Activate(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskScheduler", "Activate", 6usize, |this| ((**(this as *const *const ITaskScheduler_Vtbl)).Activate)(this, pwszname, riid, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskScheduler", "Delete", 7usize, |this| ((**(this as *const *const ITaskScheduler_Vtbl)).Delete)(this, pwszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn NewWorkItem(this: *mut core::ffi::c_void, pwsztaskname: windows_core::PCWSTR, rclsid: *const windows_core::GUID, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskScheduler", "NewWorkItem", 8usize, |this| ((**(this as *const *const ITaskScheduler_Vtbl)).NewWorkItem)(this, pwsztaskname, rclsid, riid, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddWorkItem(this: *mut core::ffi::c_void, pwsztaskname: windows_core::PCWSTR, pworkitem: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskScheduler", "AddWorkItem", 9usize, |this| ((**(this as *const *const ITaskScheduler_Vtbl)).AddWorkItem)(this, pwsztaskname, pworkitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOfType(this: *mut core::ffi::c_void, pwszname: windows_core::PCWSTR, riid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskScheduler", "IsOfType", 10usize, |this| ((**(this as *const *const ITaskScheduler_Vtbl)).IsOfType)(this, pwszname, riid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetTargetComputer,
+            GetTargetComputer,
+            Enum,
+            Activate,
+            Delete,
+            NewWorkItem,
+            AddWorkItem,
+            IsOfType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITaskScheduler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITaskService_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFolder(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, ppfolder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskService", "GetFolder", 7usize, |this| ((**(this as *const *const ITaskService_Vtbl)).GetFolder)(this, path, ppfolder), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetRunningTasks(this: *mut core::ffi::c_void, flags: i32, pprunningtasks: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskService", "GetRunningTasks", 8usize, |this| ((**(this as *const *const ITaskService_Vtbl)).GetRunningTasks)(this, flags, pprunningtasks), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn NewTask(this: *mut core::ffi::c_void, flags: u32, ppdefinition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskService", "NewTask", 9usize, |this| ((**(this as *const *const ITaskService_Vtbl)).NewTask)(this, flags, ppdefinition), |result| Some(*result))
+        }
+        unsafe extern "system" fn Connect(this: *mut core::ffi::c_void, servername: core::mem::MaybeUninit<windows_core::VARIANT>, user: core::mem::MaybeUninit<windows_core::VARIANT>, domain: core::mem::MaybeUninit<windows_core::VARIANT>, password: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskService", "Connect", 10usize, |this| ((**(this as *const *const ITaskService_Vtbl)).Connect)(this, servername, user, domain, password), |result| Some(*result))
+        }
+        unsafe extern "system" fn Connected(this: *mut core::ffi::c_void, pconnected: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskService", "Connected", 11usize, |this| ((**(this as *const *const ITaskService_Vtbl)).Connected)(this, pconnected), |result| Some(*result))
+        }
+        unsafe extern "system" fn TargetServer(this: *mut core::ffi::c_void, pserver: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskService", "TargetServer", 12usize, |this| ((**(this as *const *const ITaskService_Vtbl)).TargetServer)(this, pserver), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectedUser(this: *mut core::ffi::c_void, puser: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskService", "ConnectedUser", 13usize, |this| ((**(this as *const *const ITaskService_Vtbl)).ConnectedUser)(this, puser), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectedDomain(this: *mut core::ffi::c_void, pdomain: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskService", "ConnectedDomain", 14usize, |this| ((**(this as *const *const ITaskService_Vtbl)).ConnectedDomain)(this, pdomain), |result| Some(*result))
+        }
+        unsafe extern "system" fn HighestVersion(this: *mut core::ffi::c_void, pversion: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskService", "HighestVersion", 15usize, |this| ((**(this as *const *const ITaskService_Vtbl)).HighestVersion)(this, pversion), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFolder,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFolder: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetRunningTasks,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetRunningTasks: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            NewTask,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            NewTask: 0,
+            Connect,
+            Connected,
+            TargetServer,
+            ConnectedUser,
+            ConnectedDomain,
+            HighestVersion,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITaskService as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITaskSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AllowDemandStart(this: *mut core::ffi::c_void, pallowdemandstart: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "AllowDemandStart", 7usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).AllowDemandStart)(this, pallowdemandstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowDemandStart(this: *mut core::ffi::c_void, allowdemandstart: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "SetAllowDemandStart", 8usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).SetAllowDemandStart)(this, allowdemandstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestartInterval(this: *mut core::ffi::c_void, prestartinterval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "RestartInterval", 9usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).RestartInterval)(this, prestartinterval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRestartInterval(this: *mut core::ffi::c_void, restartinterval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "SetRestartInterval", 10usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).SetRestartInterval)(this, restartinterval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestartCount(this: *mut core::ffi::c_void, prestartcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "RestartCount", 11usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).RestartCount)(this, prestartcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRestartCount(this: *mut core::ffi::c_void, restartcount: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "SetRestartCount", 12usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).SetRestartCount)(this, restartcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn MultipleInstances(this: *mut core::ffi::c_void, ppolicy: *mut TASK_INSTANCES_POLICY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "MultipleInstances", 13usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).MultipleInstances)(this, ppolicy), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMultipleInstances(this: *mut core::ffi::c_void, policy: TASK_INSTANCES_POLICY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "SetMultipleInstances", 14usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).SetMultipleInstances)(this, policy), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopIfGoingOnBatteries(this: *mut core::ffi::c_void, pstopifonbatteries: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "StopIfGoingOnBatteries", 15usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).StopIfGoingOnBatteries)(this, pstopifonbatteries), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStopIfGoingOnBatteries(this: *mut core::ffi::c_void, stopifonbatteries: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "SetStopIfGoingOnBatteries", 16usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).SetStopIfGoingOnBatteries)(this, stopifonbatteries), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisallowStartIfOnBatteries(this: *mut core::ffi::c_void, pdisallowstart: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "DisallowStartIfOnBatteries", 17usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).DisallowStartIfOnBatteries)(this, pdisallowstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisallowStartIfOnBatteries(this: *mut core::ffi::c_void, disallowstart: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "SetDisallowStartIfOnBatteries", 18usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).SetDisallowStartIfOnBatteries)(this, disallowstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowHardTerminate(this: *mut core::ffi::c_void, pallowhardterminate: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "AllowHardTerminate", 19usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).AllowHardTerminate)(this, pallowhardterminate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowHardTerminate(this: *mut core::ffi::c_void, allowhardterminate: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "SetAllowHardTerminate", 20usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).SetAllowHardTerminate)(this, allowhardterminate), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartWhenAvailable(this: *mut core::ffi::c_void, pstartwhenavailable: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "StartWhenAvailable", 21usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).StartWhenAvailable)(this, pstartwhenavailable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartWhenAvailable(this: *mut core::ffi::c_void, startwhenavailable: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "SetStartWhenAvailable", 22usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).SetStartWhenAvailable)(this, startwhenavailable), |result| Some(*result))
+        }
+        unsafe extern "system" fn XmlText(this: *mut core::ffi::c_void, ptext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "XmlText", 23usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).XmlText)(this, ptext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetXmlText(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "SetXmlText", 24usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).SetXmlText)(this, text), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunOnlyIfNetworkAvailable(this: *mut core::ffi::c_void, prunonlyifnetworkavailable: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "RunOnlyIfNetworkAvailable", 25usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).RunOnlyIfNetworkAvailable)(this, prunonlyifnetworkavailable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRunOnlyIfNetworkAvailable(this: *mut core::ffi::c_void, runonlyifnetworkavailable: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "SetRunOnlyIfNetworkAvailable", 26usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).SetRunOnlyIfNetworkAvailable)(this, runonlyifnetworkavailable), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExecutionTimeLimit(this: *mut core::ffi::c_void, pexecutiontimelimit: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "ExecutionTimeLimit", 27usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).ExecutionTimeLimit)(this, pexecutiontimelimit), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExecutionTimeLimit(this: *mut core::ffi::c_void, executiontimelimit: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "SetExecutionTimeLimit", 28usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).SetExecutionTimeLimit)(this, executiontimelimit), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, penabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "Enabled", 29usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).Enabled)(this, penabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, enabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "SetEnabled", 30usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).SetEnabled)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteExpiredTaskAfter(this: *mut core::ffi::c_void, pexpirationdelay: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "DeleteExpiredTaskAfter", 31usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).DeleteExpiredTaskAfter)(this, pexpirationdelay), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDeleteExpiredTaskAfter(this: *mut core::ffi::c_void, expirationdelay: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "SetDeleteExpiredTaskAfter", 32usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).SetDeleteExpiredTaskAfter)(this, expirationdelay), |result| Some(*result))
+        }
+        unsafe extern "system" fn Priority(this: *mut core::ffi::c_void, ppriority: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "Priority", 33usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).Priority)(this, ppriority), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPriority(this: *mut core::ffi::c_void, priority: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "SetPriority", 34usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).SetPriority)(this, priority), |result| Some(*result))
+        }
+        unsafe extern "system" fn Compatibility(this: *mut core::ffi::c_void, pcompatlevel: *mut TASK_COMPATIBILITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "Compatibility", 35usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).Compatibility)(this, pcompatlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompatibility(this: *mut core::ffi::c_void, compatlevel: TASK_COMPATIBILITY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "SetCompatibility", 36usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).SetCompatibility)(this, compatlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn Hidden(this: *mut core::ffi::c_void, phidden: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "Hidden", 37usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).Hidden)(this, phidden), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHidden(this: *mut core::ffi::c_void, hidden: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "SetHidden", 38usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).SetHidden)(this, hidden), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn IdleSettings(this: *mut core::ffi::c_void, ppidlesettings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "IdleSettings", 39usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).IdleSettings)(this, ppidlesettings), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetIdleSettings(this: *mut core::ffi::c_void, pidlesettings: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "SetIdleSettings", 40usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).SetIdleSettings)(this, pidlesettings), |result| Some(*result))
+        }
+        unsafe extern "system" fn RunOnlyIfIdle(this: *mut core::ffi::c_void, prunonlyifidle: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "RunOnlyIfIdle", 41usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).RunOnlyIfIdle)(this, prunonlyifidle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRunOnlyIfIdle(this: *mut core::ffi::c_void, runonlyifidle: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "SetRunOnlyIfIdle", 42usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).SetRunOnlyIfIdle)(this, runonlyifidle), |result| Some(*result))
+        }
+        unsafe extern "system" fn WakeToRun(this: *mut core::ffi::c_void, pwake: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "WakeToRun", 43usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).WakeToRun)(this, pwake), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWakeToRun(this: *mut core::ffi::c_void, wake: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "SetWakeToRun", 44usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).SetWakeToRun)(this, wake), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn NetworkSettings(this: *mut core::ffi::c_void, ppnetworksettings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "NetworkSettings", 45usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).NetworkSettings)(this, ppnetworksettings), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetNetworkSettings(this: *mut core::ffi::c_void, pnetworksettings: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings", "SetNetworkSettings", 46usize, |this| ((**(this as *const *const ITaskSettings_Vtbl)).SetNetworkSettings)(this, pnetworksettings), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AllowDemandStart,
+            SetAllowDemandStart,
+            RestartInterval,
+            SetRestartInterval,
+            RestartCount,
+            SetRestartCount,
+            MultipleInstances,
+            SetMultipleInstances,
+            StopIfGoingOnBatteries,
+            SetStopIfGoingOnBatteries,
+            DisallowStartIfOnBatteries,
+            SetDisallowStartIfOnBatteries,
+            AllowHardTerminate,
+            SetAllowHardTerminate,
+            StartWhenAvailable,
+            SetStartWhenAvailable,
+            XmlText,
+            SetXmlText,
+            RunOnlyIfNetworkAvailable,
+            SetRunOnlyIfNetworkAvailable,
+            ExecutionTimeLimit,
+            SetExecutionTimeLimit,
+            Enabled,
+            SetEnabled,
+            DeleteExpiredTaskAfter,
+            SetDeleteExpiredTaskAfter,
+            Priority,
+            SetPriority,
+            Compatibility,
+            SetCompatibility,
+            Hidden,
+            SetHidden,
+            #[cfg(feature = "Win32_System_Com")]
+            IdleSettings,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            IdleSettings: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetIdleSettings,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetIdleSettings: 0,
+            RunOnlyIfIdle,
+            SetRunOnlyIfIdle,
+            WakeToRun,
+            SetWakeToRun,
+            #[cfg(feature = "Win32_System_Com")]
+            NetworkSettings,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            NetworkSettings: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetNetworkSettings,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetNetworkSettings: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITaskSettings as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITaskSettings2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisallowStartOnRemoteAppSession(this: *mut core::ffi::c_void, pdisallowstart: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings2", "DisallowStartOnRemoteAppSession", 7usize, |this| ((**(this as *const *const ITaskSettings2_Vtbl)).DisallowStartOnRemoteAppSession)(this, pdisallowstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisallowStartOnRemoteAppSession(this: *mut core::ffi::c_void, disallowstart: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings2", "SetDisallowStartOnRemoteAppSession", 8usize, |this| ((**(this as *const *const ITaskSettings2_Vtbl)).SetDisallowStartOnRemoteAppSession)(this, disallowstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseUnifiedSchedulingEngine(this: *mut core::ffi::c_void, puseunifiedengine: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings2", "UseUnifiedSchedulingEngine", 9usize, |this| ((**(this as *const *const ITaskSettings2_Vtbl)).UseUnifiedSchedulingEngine)(this, puseunifiedengine), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUseUnifiedSchedulingEngine(this: *mut core::ffi::c_void, useunifiedengine: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings2", "SetUseUnifiedSchedulingEngine", 10usize, |this| ((**(this as *const *const ITaskSettings2_Vtbl)).SetUseUnifiedSchedulingEngine)(this, useunifiedengine), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DisallowStartOnRemoteAppSession,
+            SetDisallowStartOnRemoteAppSession,
+            UseUnifiedSchedulingEngine,
+            SetUseUnifiedSchedulingEngine,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITaskSettings2 as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITaskSettings3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisallowStartOnRemoteAppSession(this: *mut core::ffi::c_void, pdisallowstart: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings3", "DisallowStartOnRemoteAppSession", 47usize, |this| ((**(this as *const *const ITaskSettings3_Vtbl)).DisallowStartOnRemoteAppSession)(this, pdisallowstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisallowStartOnRemoteAppSession(this: *mut core::ffi::c_void, disallowstart: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings3", "SetDisallowStartOnRemoteAppSession", 48usize, |this| ((**(this as *const *const ITaskSettings3_Vtbl)).SetDisallowStartOnRemoteAppSession)(this, disallowstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseUnifiedSchedulingEngine(this: *mut core::ffi::c_void, puseunifiedengine: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings3", "UseUnifiedSchedulingEngine", 49usize, |this| ((**(this as *const *const ITaskSettings3_Vtbl)).UseUnifiedSchedulingEngine)(this, puseunifiedengine), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUseUnifiedSchedulingEngine(this: *mut core::ffi::c_void, useunifiedengine: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings3", "SetUseUnifiedSchedulingEngine", 50usize, |this| ((**(this as *const *const ITaskSettings3_Vtbl)).SetUseUnifiedSchedulingEngine)(this, useunifiedengine), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn MaintenanceSettings(this: *mut core::ffi::c_void, ppmaintenancesettings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings3", "MaintenanceSettings", 51usize, |this| ((**(this as *const *const ITaskSettings3_Vtbl)).MaintenanceSettings)(this, ppmaintenancesettings), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetMaintenanceSettings(this: *mut core::ffi::c_void, pmaintenancesettings: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings3", "SetMaintenanceSettings", 52usize, |this| ((**(this as *const *const ITaskSettings3_Vtbl)).SetMaintenanceSettings)(this, pmaintenancesettings), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateMaintenanceSettings(this: *mut core::ffi::c_void, ppmaintenancesettings: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings3", "CreateMaintenanceSettings", 53usize, |this| ((**(this as *const *const ITaskSettings3_Vtbl)).CreateMaintenanceSettings)(this, ppmaintenancesettings), |result| Some(*result))
+        }
+        unsafe extern "system" fn Volatile(this: *mut core::ffi::c_void, pvolatile: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings3", "Volatile", 54usize, |this| ((**(this as *const *const ITaskSettings3_Vtbl)).Volatile)(this, pvolatile), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVolatile(this: *mut core::ffi::c_void, volatile: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskSettings3", "SetVolatile", 55usize, |this| ((**(this as *const *const ITaskSettings3_Vtbl)).SetVolatile)(this, volatile), |result| Some(*result))
+        }
+        Self {
+            base__: <ITaskSettings_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DisallowStartOnRemoteAppSession,
+            SetDisallowStartOnRemoteAppSession,
+            UseUnifiedSchedulingEngine,
+            SetUseUnifiedSchedulingEngine,
+            #[cfg(feature = "Win32_System_Com")]
+            MaintenanceSettings,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            MaintenanceSettings: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetMaintenanceSettings,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetMaintenanceSettings: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateMaintenanceSettings,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateMaintenanceSettings: 0,
+            Volatile,
+            SetVolatile,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITaskSettings3 as windows_core::Interface>::IID || <ITaskSettings_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITaskTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetTrigger(this: *mut core::ffi::c_void, ptrigger: *const TASK_TRIGGER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskTrigger", "SetTrigger", 3usize, |this| ((**(this as *const *const ITaskTrigger_Vtbl)).SetTrigger)(this, ptrigger), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTrigger(this: *mut core::ffi::c_void, ptrigger: *mut TASK_TRIGGER) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskTrigger", "GetTrigger", 4usize, |this| ((**(this as *const *const ITaskTrigger_Vtbl)).GetTrigger)(this, ptrigger), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTriggerString(this: *mut core::ffi::c_void, ppwsztrigger: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskTrigger", "GetTriggerString", 5usize, |this| ((**(this as *const *const ITaskTrigger_Vtbl)).GetTriggerString)(this, ppwsztrigger), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetTrigger, GetTrigger, GetTriggerString }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITaskTrigger as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITaskVariables_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInput(this: *mut core::ffi::c_void, pinput: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskVariables", "GetInput", 3usize, |this| ((**(this as *const *const ITaskVariables_Vtbl)).GetInput)(this, pinput), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutput(this: *mut core::ffi::c_void, input: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskVariables", "SetOutput", 4usize, |this| ((**(this as *const *const ITaskVariables_Vtbl)).SetOutput)(this, input), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContext(this: *mut core::ffi::c_void, pcontext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITaskVariables", "GetContext", 5usize, |this| ((**(this as *const *const ITaskVariables_Vtbl)).GetContext)(this, pcontext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetInput, SetOutput, GetContext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITaskVariables as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITimeTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RandomDelay(this: *mut core::ffi::c_void, prandomdelay: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimeTrigger", "RandomDelay", 20usize, |this| ((**(this as *const *const ITimeTrigger_Vtbl)).RandomDelay)(this, prandomdelay), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRandomDelay(this: *mut core::ffi::c_void, randomdelay: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimeTrigger", "SetRandomDelay", 21usize, |this| ((**(this as *const *const ITimeTrigger_Vtbl)).SetRandomDelay)(this, randomdelay), |result| Some(*result))
+        }
+        Self { base__: <ITrigger_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RandomDelay, SetRandomDelay }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimeTrigger as windows_core::Interface>::IID || <ITrigger_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, ptype: *mut TASK_TRIGGER_TYPE2) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITrigger", "Type", 7usize, |this| ((**(this as *const *const ITrigger_Vtbl)).Type)(this, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, pid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITrigger", "Id", 8usize, |this| ((**(this as *const *const ITrigger_Vtbl)).Id)(this, pid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetId(this: *mut core::ffi::c_void, id: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITrigger", "SetId", 9usize, |this| ((**(this as *const *const ITrigger_Vtbl)).SetId)(this, id), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Repetition(this: *mut core::ffi::c_void, pprepeat: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITrigger", "Repetition", 10usize, |this| ((**(this as *const *const ITrigger_Vtbl)).Repetition)(this, pprepeat), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetRepetition(this: *mut core::ffi::c_void, prepeat: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITrigger", "SetRepetition", 11usize, |this| ((**(this as *const *const ITrigger_Vtbl)).SetRepetition)(this, prepeat), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExecutionTimeLimit(this: *mut core::ffi::c_void, ptimelimit: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITrigger", "ExecutionTimeLimit", 12usize, |this| ((**(this as *const *const ITrigger_Vtbl)).ExecutionTimeLimit)(this, ptimelimit), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExecutionTimeLimit(this: *mut core::ffi::c_void, timelimit: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITrigger", "SetExecutionTimeLimit", 13usize, |this| ((**(this as *const *const ITrigger_Vtbl)).SetExecutionTimeLimit)(this, timelimit), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartBoundary(this: *mut core::ffi::c_void, pstart: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITrigger", "StartBoundary", 14usize, |this| ((**(this as *const *const ITrigger_Vtbl)).StartBoundary)(this, pstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStartBoundary(this: *mut core::ffi::c_void, start: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITrigger", "SetStartBoundary", 15usize, |this| ((**(this as *const *const ITrigger_Vtbl)).SetStartBoundary)(this, start), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndBoundary(this: *mut core::ffi::c_void, pend: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITrigger", "EndBoundary", 16usize, |this| ((**(this as *const *const ITrigger_Vtbl)).EndBoundary)(this, pend), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEndBoundary(this: *mut core::ffi::c_void, end: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITrigger", "SetEndBoundary", 17usize, |this| ((**(this as *const *const ITrigger_Vtbl)).SetEndBoundary)(this, end), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, penabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITrigger", "Enabled", 18usize, |this| ((**(this as *const *const ITrigger_Vtbl)).Enabled)(this, penabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, enabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITrigger", "SetEnabled", 19usize, |this| ((**(this as *const *const ITrigger_Vtbl)).SetEnabled)(this, enabled), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Type,
+            Id,
+            SetId,
+            #[cfg(feature = "Win32_System_Com")]
+            Repetition,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Repetition: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetRepetition,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetRepetition: 0,
+            ExecutionTimeLimit,
+            SetExecutionTimeLimit,
+            StartBoundary,
+            SetStartBoundary,
+            EndBoundary,
+            SetEndBoundary,
+            Enabled,
+            SetEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITrigger as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITriggerCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITriggerCollection", "Count", 7usize, |this| ((**(this as *const *const ITriggerCollection_Vtbl)).Count)(this, pcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, pptrigger: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITriggerCollection", "get_Item", 8usize, |this| ((**(this as *const *const ITriggerCollection_Vtbl)).get_Item)(this, index, pptrigger), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITriggerCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const ITriggerCollection_Vtbl))._NewEnum)(this, ppenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, r#type: TASK_TRIGGER_TYPE2, pptrigger: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITriggerCollection", "Create", 10usize, |this| ((**(this as *const *const ITriggerCollection_Vtbl)).Create)(this, r#type, pptrigger), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, index: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITriggerCollection", "Remove", 11usize, |this| ((**(this as *const *const ITriggerCollection_Vtbl)).Remove)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITriggerCollection", "Clear", 12usize, |this| ((**(this as *const *const ITriggerCollection_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Create,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Create: 0,
+            Remove,
+            Clear,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITriggerCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWeeklyTrigger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DaysOfWeek(this: *mut core::ffi::c_void, pdays: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWeeklyTrigger", "DaysOfWeek", 20usize, |this| ((**(this as *const *const IWeeklyTrigger_Vtbl)).DaysOfWeek)(this, pdays), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDaysOfWeek(this: *mut core::ffi::c_void, days: i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWeeklyTrigger", "SetDaysOfWeek", 21usize, |this| ((**(this as *const *const IWeeklyTrigger_Vtbl)).SetDaysOfWeek)(this, days), |result| Some(*result))
+        }
+        unsafe extern "system" fn WeeksInterval(this: *mut core::ffi::c_void, pweeks: *mut i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWeeklyTrigger", "WeeksInterval", 22usize, |this| ((**(this as *const *const IWeeklyTrigger_Vtbl)).WeeksInterval)(this, pweeks), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWeeksInterval(this: *mut core::ffi::c_void, weeks: i16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWeeklyTrigger", "SetWeeksInterval", 23usize, |this| ((**(this as *const *const IWeeklyTrigger_Vtbl)).SetWeeksInterval)(this, weeks), |result| Some(*result))
+        }
+        unsafe extern "system" fn RandomDelay(this: *mut core::ffi::c_void, prandomdelay: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWeeklyTrigger", "RandomDelay", 24usize, |this| ((**(this as *const *const IWeeklyTrigger_Vtbl)).RandomDelay)(this, prandomdelay), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRandomDelay(this: *mut core::ffi::c_void, randomdelay: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWeeklyTrigger", "SetRandomDelay", 25usize, |this| ((**(this as *const *const IWeeklyTrigger_Vtbl)).SetRandomDelay)(this, randomdelay), |result| Some(*result))
+        }
+        Self {
+            base__: <ITrigger_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DaysOfWeek,
+            SetDaysOfWeek,
+            WeeksInterval,
+            SetWeeksInterval,
+            RandomDelay,
+            SetRandomDelay,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWeeklyTrigger as windows_core::Interface>::IID || <ITrigger_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
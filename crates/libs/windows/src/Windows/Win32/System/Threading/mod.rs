@@ -2901,6 +2901,7 @@ pub struct AVRT_PRIORITY(pub i32);
 impl windows_core::TypeKind for AVRT_PRIORITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AVRT_PRIORITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AVRT_PRIORITY").field(&self.0).finish()
@@ -2912,6 +2913,7 @@ pub struct CREATE_EVENT(pub u32);
 impl windows_core::TypeKind for CREATE_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CREATE_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CREATE_EVENT").field(&self.0).finish()
@@ -2956,6 +2958,7 @@ pub struct CREATE_PROCESS_LOGON_FLAGS(pub u32);
 impl windows_core::TypeKind for CREATE_PROCESS_LOGON_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CREATE_PROCESS_LOGON_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CREATE_PROCESS_LOGON_FLAGS").field(&self.0).finish()
@@ -2967,6 +2970,7 @@ pub struct GET_GUI_RESOURCES_FLAGS(pub u32);
 impl windows_core::TypeKind for GET_GUI_RESOURCES_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GET_GUI_RESOURCES_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GET_GUI_RESOURCES_FLAGS").field(&self.0).finish()
@@ -2978,6 +2982,7 @@ pub struct MACHINE_ATTRIBUTES(pub i32);
 impl windows_core::TypeKind for MACHINE_ATTRIBUTES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MACHINE_ATTRIBUTES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MACHINE_ATTRIBUTES").field(&self.0).finish()
@@ -3022,6 +3027,7 @@ pub struct MEMORY_PRIORITY(pub u32);
 impl windows_core::TypeKind for MEMORY_PRIORITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MEMORY_PRIORITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MEMORY_PRIORITY").field(&self.0).finish()
@@ -3033,6 +3039,7 @@ pub struct POWER_REQUEST_CONTEXT_FLAGS(pub u32);
 impl windows_core::TypeKind for POWER_REQUEST_CONTEXT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for POWER_REQUEST_CONTEXT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("POWER_REQUEST_CONTEXT_FLAGS").field(&self.0).finish()
@@ -3044,6 +3051,7 @@ pub struct PROCESSOR_FEATURE_ID(pub u32);
 impl windows_core::TypeKind for PROCESSOR_FEATURE_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROCESSOR_FEATURE_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROCESSOR_FEATURE_ID").field(&self.0).finish()
@@ -3055,6 +3063,7 @@ pub struct PROCESS_ACCESS_RIGHTS(pub u32);
 impl windows_core::TypeKind for PROCESS_ACCESS_RIGHTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROCESS_ACCESS_RIGHTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROCESS_ACCESS_RIGHTS").field(&self.0).finish()
@@ -3099,6 +3108,7 @@ pub struct PROCESS_AFFINITY_AUTO_UPDATE_FLAGS(pub u32);
 impl windows_core::TypeKind for PROCESS_AFFINITY_AUTO_UPDATE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROCESS_AFFINITY_AUTO_UPDATE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROCESS_AFFINITY_AUTO_UPDATE_FLAGS").field(&self.0).finish()
@@ -3110,6 +3120,7 @@ pub struct PROCESS_CREATION_FLAGS(pub u32);
 impl windows_core::TypeKind for PROCESS_CREATION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROCESS_CREATION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROCESS_CREATION_FLAGS").field(&self.0).finish()
@@ -3154,6 +3165,7 @@ pub struct PROCESS_DEP_FLAGS(pub u32);
 impl windows_core::TypeKind for PROCESS_DEP_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROCESS_DEP_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROCESS_DEP_FLAGS").field(&self.0).finish()
@@ -3198,6 +3210,7 @@ pub struct PROCESS_INFORMATION_CLASS(pub i32);
 impl windows_core::TypeKind for PROCESS_INFORMATION_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROCESS_INFORMATION_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROCESS_INFORMATION_CLASS").field(&self.0).finish()
@@ -3209,6 +3222,7 @@ pub struct PROCESS_MEMORY_EXHAUSTION_TYPE(pub i32);
 impl windows_core::TypeKind for PROCESS_MEMORY_EXHAUSTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROCESS_MEMORY_EXHAUSTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROCESS_MEMORY_EXHAUSTION_TYPE").field(&self.0).finish()
@@ -3220,6 +3234,7 @@ pub struct PROCESS_MITIGATION_POLICY(pub i32);
 impl windows_core::TypeKind for PROCESS_MITIGATION_POLICY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROCESS_MITIGATION_POLICY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROCESS_MITIGATION_POLICY").field(&self.0).finish()
@@ -3231,6 +3246,7 @@ pub struct PROCESS_NAME_FORMAT(pub u32);
 impl windows_core::TypeKind for PROCESS_NAME_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROCESS_NAME_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROCESS_NAME_FORMAT").field(&self.0).finish()
@@ -3242,6 +3258,7 @@ pub struct PROCESS_PROTECTION_LEVEL(pub u32);
 impl windows_core::TypeKind for PROCESS_PROTECTION_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROCESS_PROTECTION_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROCESS_PROTECTION_LEVEL").field(&self.0).finish()
@@ -3253,6 +3270,7 @@ pub struct PROC_THREAD_ATTRIBUTE_NUM(pub u32);
 impl windows_core::TypeKind for PROC_THREAD_ATTRIBUTE_NUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROC_THREAD_ATTRIBUTE_NUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROC_THREAD_ATTRIBUTE_NUM").field(&self.0).finish()
@@ -3264,6 +3282,7 @@ pub struct QUEUE_USER_APC_FLAGS(pub i32);
 impl windows_core::TypeKind for QUEUE_USER_APC_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for QUEUE_USER_APC_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("QUEUE_USER_APC_FLAGS").field(&self.0).finish()
@@ -3275,6 +3294,7 @@ pub struct RTWQ_WORKQUEUE_TYPE(pub i32);
 impl windows_core::TypeKind for RTWQ_WORKQUEUE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RTWQ_WORKQUEUE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RTWQ_WORKQUEUE_TYPE").field(&self.0).finish()
@@ -3286,6 +3306,7 @@ pub struct STARTUPINFOW_FLAGS(pub u32);
 impl windows_core::TypeKind for STARTUPINFOW_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STARTUPINFOW_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STARTUPINFOW_FLAGS").field(&self.0).finish()
@@ -3330,6 +3351,7 @@ pub struct SYNCHRONIZATION_ACCESS_RIGHTS(pub u32);
 impl windows_core::TypeKind for SYNCHRONIZATION_ACCESS_RIGHTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNCHRONIZATION_ACCESS_RIGHTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNCHRONIZATION_ACCESS_RIGHTS").field(&self.0).finish()
@@ -3374,6 +3396,7 @@ pub struct THREAD_ACCESS_RIGHTS(pub u32);
 impl windows_core::TypeKind for THREAD_ACCESS_RIGHTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for THREAD_ACCESS_RIGHTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("THREAD_ACCESS_RIGHTS").field(&self.0).finish()
@@ -3418,6 +3441,7 @@ pub struct THREAD_CREATION_FLAGS(pub u32);
 impl windows_core::TypeKind for THREAD_CREATION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for THREAD_CREATION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("THREAD_CREATION_FLAGS").field(&self.0).finish()
@@ -3462,6 +3486,7 @@ pub struct THREAD_INFORMATION_CLASS(pub i32);
 impl windows_core::TypeKind for THREAD_INFORMATION_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for THREAD_INFORMATION_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("THREAD_INFORMATION_CLASS").field(&self.0).finish()
@@ -3473,6 +3498,7 @@ pub struct THREAD_PRIORITY(pub i32);
 impl windows_core::TypeKind for THREAD_PRIORITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for THREAD_PRIORITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("THREAD_PRIORITY").field(&self.0).finish()
@@ -3484,6 +3510,7 @@ pub struct TP_CALLBACK_PRIORITY(pub i32);
 impl windows_core::TypeKind for TP_CALLBACK_PRIORITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TP_CALLBACK_PRIORITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TP_CALLBACK_PRIORITY").field(&self.0).finish()
@@ -3495,6 +3522,7 @@ pub struct UMS_THREAD_INFO_CLASS(pub i32);
 impl windows_core::TypeKind for UMS_THREAD_INFO_CLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UMS_THREAD_INFO_CLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UMS_THREAD_INFO_CLASS").field(&self.0).finish()
@@ -3506,6 +3534,7 @@ pub struct WORKER_THREAD_FLAGS(pub u32);
 impl windows_core::TypeKind for WORKER_THREAD_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WORKER_THREAD_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WORKER_THREAD_FLAGS").field(&self.0).finish()
@@ -3545,7 +3574,8 @@ impl core::ops::Not for WORKER_THREAD_FLAGS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct APP_MEMORY_INFORMATION {
     pub AvailableCommit: u64,
     pub PrivateCommitUsage: u64,
@@ -3561,7 +3591,8 @@ impl Default for APP_MEMORY_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CONDITION_VARIABLE {
     pub Ptr: *mut core::ffi::c_void,
 }
@@ -3575,7 +3606,8 @@ impl Default for CONDITION_VARIABLE {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CRITICAL_SECTION {
     pub DebugInfo: *mut CRITICAL_SECTION_DEBUG,
     pub LockCount: i32,
@@ -3596,7 +3628,8 @@ impl Default for CRITICAL_SECTION {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CRITICAL_SECTION_DEBUG {
     pub Type: u16,
     pub CreatorBackTraceIndex: u16,
@@ -3632,7 +3665,8 @@ impl Default for INIT_ONCE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IO_COUNTERS {
     pub ReadOperationCount: u64,
     pub WriteOperationCount: u64,
@@ -3650,7 +3684,8 @@ impl Default for IO_COUNTERS {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LPPROC_THREAD_ATTRIBUTE_LIST(pub *mut core::ffi::c_void);
 impl LPPROC_THREAD_ATTRIBUTE_LIST {
     pub fn is_invalid(&self) -> bool {
@@ -3674,7 +3709,8 @@ impl windows_core::TypeKind for LPPROC_THREAD_ATTRIBUTE_LIST {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MEMORY_PRIORITY_INFORMATION {
     pub MemoryPriority: MEMORY_PRIORITY,
 }
@@ -3687,7 +3723,8 @@ impl Default for MEMORY_PRIORITY_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OVERRIDE_PREFETCH_PARAMETER {
     pub Value: u32,
 }
@@ -3701,7 +3738,8 @@ impl Default for OVERRIDE_PREFETCH_PARAMETER {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEB {
     pub Reserved1: [u8; 2],
     pub BeingDebugged: u8,
@@ -3735,7 +3773,8 @@ impl Default for PEB {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PEB_LDR_DATA {
     pub Reserved1: [u8; 8],
     pub Reserved2: [*mut core::ffi::c_void; 3],
@@ -3753,7 +3792,8 @@ impl Default for PEB_LDR_DATA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_BASIC_INFORMATION {
     pub ExitStatus: super::super::Foundation::NTSTATUS,
     pub PebBaseAddress: *mut PEB,
@@ -3773,7 +3813,8 @@ impl Default for PROCESS_BASIC_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_DYNAMIC_EH_CONTINUATION_TARGET {
     pub TargetAddress: usize,
     pub Flags: usize,
@@ -3787,7 +3828,8 @@ impl Default for PROCESS_DYNAMIC_EH_CONTINUATION_TARGET {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_DYNAMIC_EH_CONTINUATION_TARGETS_INFORMATION {
     pub NumberOfTargets: u16,
     pub Reserved: u16,
@@ -3803,7 +3845,8 @@ impl Default for PROCESS_DYNAMIC_EH_CONTINUATION_TARGETS_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_DYNAMIC_ENFORCED_ADDRESS_RANGE {
     pub BaseAddress: usize,
     pub Size: usize,
@@ -3818,7 +3861,8 @@ impl Default for PROCESS_DYNAMIC_ENFORCED_ADDRESS_RANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_DYNAMIC_ENFORCED_ADDRESS_RANGES_INFORMATION {
     pub NumberOfRanges: u16,
     pub Reserved: u16,
@@ -3834,7 +3878,8 @@ impl Default for PROCESS_DYNAMIC_ENFORCED_ADDRESS_RANGES_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_INFORMATION {
     pub hProcess: super::super::Foundation::HANDLE,
     pub hThread: super::super::Foundation::HANDLE,
@@ -3850,7 +3895,8 @@ impl Default for PROCESS_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_LEAP_SECOND_INFO {
     pub Flags: u32,
     pub Reserved: u32,
@@ -3865,7 +3911,8 @@ impl Default for PROCESS_LEAP_SECOND_INFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_SystemInformation")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_MACHINE_INFORMATION {
     pub ProcessMachine: super::SystemInformation::IMAGE_FILE_MACHINE,
     pub Res0: u16,
@@ -3882,7 +3929,8 @@ impl Default for PROCESS_MACHINE_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_MEMORY_EXHAUSTION_INFO {
     pub Version: u16,
     pub Reserved: u16,
@@ -3898,7 +3946,8 @@ impl Default for PROCESS_MEMORY_EXHAUSTION_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_POWER_THROTTLING_STATE {
     pub Version: u32,
     pub ControlMask: u32,
@@ -3913,7 +3962,8 @@ impl Default for PROCESS_POWER_THROTTLING_STATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROCESS_PROTECTION_LEVEL_INFORMATION {
     pub ProtectionLevel: PROCESS_PROTECTION_LEVEL,
 }
@@ -3926,7 +3976,8 @@ impl Default for PROCESS_PROTECTION_LEVEL_INFORMATION {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PTP_CALLBACK_INSTANCE(pub isize);
 impl PTP_CALLBACK_INSTANCE {
     pub fn is_invalid(&self) -> bool {
@@ -3942,7 +3993,8 @@ impl windows_core::TypeKind for PTP_CALLBACK_INSTANCE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PTP_CLEANUP_GROUP(pub isize);
 impl PTP_CLEANUP_GROUP {
     pub fn is_invalid(&self) -> bool {
@@ -3966,7 +4018,8 @@ impl windows_core::TypeKind for PTP_CLEANUP_GROUP {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PTP_IO(pub isize);
 impl PTP_IO {
     pub fn is_invalid(&self) -> bool {
@@ -3990,7 +4043,8 @@ impl windows_core::TypeKind for PTP_IO {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PTP_POOL(pub isize);
 impl PTP_POOL {
     pub fn is_invalid(&self) -> bool {
@@ -4014,7 +4068,8 @@ impl windows_core::TypeKind for PTP_POOL {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PTP_TIMER(pub isize);
 impl PTP_TIMER {
     pub fn is_invalid(&self) -> bool {
@@ -4038,7 +4093,8 @@ impl windows_core::TypeKind for PTP_TIMER {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PTP_WAIT(pub isize);
 impl PTP_WAIT {
     pub fn is_invalid(&self) -> bool {
@@ -4062,7 +4118,8 @@ impl windows_core::TypeKind for PTP_WAIT {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PTP_WORK(pub isize);
 impl PTP_WORK {
     pub fn is_invalid(&self) -> bool {
@@ -4115,7 +4172,8 @@ impl Default for REASON_CONTEXT_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REASON_CONTEXT_0_0 {
     pub LocalizedReasonModule: super::super::Foundation::HMODULE,
     pub LocalizedReasonId: u32,
@@ -4131,7 +4189,8 @@ impl Default for REASON_CONTEXT_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RTL_USER_PROCESS_PARAMETERS {
     pub Reserved1: [u8; 16],
     pub Reserved2: [*mut core::ffi::c_void; 10],
@@ -4147,7 +4206,8 @@ impl Default for RTL_USER_PROCESS_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SRWLOCK {
     pub Ptr: *mut core::ffi::c_void,
 }
@@ -4160,7 +4220,8 @@ impl Default for SRWLOCK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STARTUPINFOA {
     pub cb: u32,
     pub lpReserved: windows_core::PSTR,
@@ -4190,7 +4251,8 @@ impl Default for STARTUPINFOA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STARTUPINFOEXA {
     pub StartupInfo: STARTUPINFOA,
     pub lpAttributeList: LPPROC_THREAD_ATTRIBUTE_LIST,
@@ -4204,7 +4266,8 @@ impl Default for STARTUPINFOEXA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STARTUPINFOEXW {
     pub StartupInfo: STARTUPINFOW,
     pub lpAttributeList: LPPROC_THREAD_ATTRIBUTE_LIST,
@@ -4218,7 +4281,8 @@ impl Default for STARTUPINFOEXW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STARTUPINFOW {
     pub cb: u32,
     pub lpReserved: windows_core::PWSTR,
@@ -4248,7 +4312,8 @@ impl Default for STARTUPINFOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYNCHRONIZATION_BARRIER {
     pub Reserved1: u32,
     pub Reserved2: u32,
@@ -4266,7 +4331,8 @@ impl Default for SYNCHRONIZATION_BARRIER {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Kernel")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TEB {
     pub Reserved1: [*mut core::ffi::c_void; 12],
     pub ProcessEnvironmentBlock: *mut PEB,
@@ -4290,7 +4356,8 @@ impl Default for TEB {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct THREAD_POWER_THROTTLING_STATE {
     pub Version: u32,
     pub ControlMask: u32,
@@ -4341,10 +4408,31 @@ impl Default for TP_CALLBACK_ENVIRON_V3_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TP_CALLBACK_ENVIRON_V3_0_0 {
     pub _bitfield: u32,
 }
+impl TP_CALLBACK_ENVIRON_V3_0_0 {
+    pub const fn LongFunction(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_LongFunction(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn Persistent(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_Persistent(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn Private(&self) -> u32 {
+        (self._bitfield >> 2) & 1073741823
+    }
+    pub fn set_Private(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1073741823 << 2)) | ((value & 1073741823) << 2);
+    }
+}
 impl windows_core::TypeKind for TP_CALLBACK_ENVIRON_V3_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4354,7 +4442,8 @@ impl Default for TP_CALLBACK_ENVIRON_V3_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TP_POOL_STACK_INFORMATION {
     pub StackReserve: usize,
     pub StackCommit: usize,
@@ -4369,7 +4458,8 @@ impl Default for TP_POOL_STACK_INFORMATION {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_SystemServices")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UMS_SCHEDULER_STARTUP_INFO {
     pub UmsVersion: u32,
     pub CompletionList: *mut core::ffi::c_void,
@@ -4415,10 +4505,25 @@ impl Default for UMS_SYSTEM_THREAD_INFORMATION_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UMS_SYSTEM_THREAD_INFORMATION_0_0 {
     pub _bitfield: u32,
 }
+impl UMS_SYSTEM_THREAD_INFORMATION_0_0 {
+    pub const fn IsUmsSchedulerThread(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_IsUmsSchedulerThread(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn IsUmsWorkerThread(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_IsUmsWorkerThread(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+}
 impl windows_core::TypeKind for UMS_SYSTEM_THREAD_INFORMATION_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -4445,5 +4550,9 @@ pub type PTP_WORK_CALLBACK = Option<unsafe extern "system" fn(instance: PTP_CALL
 pub type RTWQPERIODICCALLBACK = Option<unsafe extern "system" fn(context: Option<windows_core::IUnknown>)>;
 pub type WAITORTIMERCALLBACK = Option<unsafe extern "system" fn(param0: *mut core::ffi::c_void, param1: super::super::Foundation::BOOLEAN)>;
 pub type WORKERCALLBACKFUNC = Option<unsafe extern "system" fn(param0: *mut core::ffi::c_void)>;
+core::include!(core::concat!(core::env!("CARGO_MANIFEST_DIR"), "/src/includes/", "Win32/System/Threading/Process.rs"));
+core::include!(core::concat!(core::env!("CARGO_MANIFEST_DIR"), "/src/includes/", "Win32/System/Threading/WaitAsync.rs"));
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
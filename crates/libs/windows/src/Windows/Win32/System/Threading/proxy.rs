@@ -0,0 +1,64 @@
+impl windows_core::imp::ProxyVtable for IRtwqAsyncCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetParameters(this: *mut core::ffi::c_void, pdwflags: *mut u32, pdwqueue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRtwqAsyncCallback", "GetParameters", 3usize, |this| ((**(this as *const *const IRtwqAsyncCallback_Vtbl)).GetParameters)(this, pdwflags, pdwqueue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Invoke(this: *mut core::ffi::c_void, pasyncresult: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRtwqAsyncCallback", "Invoke", 4usize, |this| ((**(this as *const *const IRtwqAsyncCallback_Vtbl)).Invoke)(this, pasyncresult), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetParameters, Invoke }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRtwqAsyncCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRtwqAsyncResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetState(this: *mut core::ffi::c_void, ppunkstate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRtwqAsyncResult", "GetState", 3usize, |this| ((**(this as *const *const IRtwqAsyncResult_Vtbl)).GetState)(this, ppunkstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRtwqAsyncResult", "GetStatus", 4usize, |this| ((**(this as *const *const IRtwqAsyncResult_Vtbl)).GetStatus)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatus(this: *mut core::ffi::c_void, hrstatus: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRtwqAsyncResult", "SetStatus", 5usize, |this| ((**(this as *const *const IRtwqAsyncResult_Vtbl)).SetStatus)(this, hrstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObject(this: *mut core::ffi::c_void, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRtwqAsyncResult", "GetObject", 6usize, |this| ((**(this as *const *const IRtwqAsyncResult_Vtbl)).GetObject)(this, ppobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStateNoAddRef(this: *mut core::ffi::c_void) -> Option<windows_core::IUnknown> {
+            windows_core::imp::proxy_call(this, "IRtwqAsyncResult", "GetStateNoAddRef", 7usize, |this| ((**(this as *const *const IRtwqAsyncResult_Vtbl)).GetStateNoAddRef)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetState, GetStatus, SetStatus, GetObject, GetStateNoAddRef }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRtwqAsyncResult as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRtwqPlatformEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitializationComplete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRtwqPlatformEvents", "InitializationComplete", 3usize, |this| ((**(this as *const *const IRtwqPlatformEvents_Vtbl)).InitializationComplete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShutdownStart(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRtwqPlatformEvents", "ShutdownStart", 4usize, |this| ((**(this as *const *const IRtwqPlatformEvents_Vtbl)).ShutdownStart)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShutdownComplete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRtwqPlatformEvents", "ShutdownComplete", 5usize, |this| ((**(this as *const *const IRtwqPlatformEvents_Vtbl)).ShutdownComplete)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InitializationComplete, ShutdownStart, ShutdownComplete }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRtwqPlatformEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for RTWQASYNCRESULT_Vtbl {
+    const PROXY: Self = { Self { base__: <IRtwqAsyncResult_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<RTWQASYNCRESULT as windows_core::Interface>::IID || <IRtwqAsyncResult_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
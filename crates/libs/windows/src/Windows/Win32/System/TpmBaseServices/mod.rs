@@ -111,6 +111,7 @@ pub struct TBS_COMMAND_LOCALITY(pub u32);
 impl windows_core::TypeKind for TBS_COMMAND_LOCALITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TBS_COMMAND_LOCALITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TBS_COMMAND_LOCALITY").field(&self.0).finish()
@@ -122,13 +123,15 @@ pub struct TBS_COMMAND_PRIORITY(pub u32);
 impl windows_core::TypeKind for TBS_COMMAND_PRIORITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TBS_COMMAND_PRIORITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TBS_COMMAND_PRIORITY").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TBS_CONTEXT_PARAMS {
     pub version: u32,
 }
@@ -169,10 +172,31 @@ impl Default for TBS_CONTEXT_PARAMS2_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TBS_CONTEXT_PARAMS2_0_0 {
     pub _bitfield: u32,
 }
+impl TBS_CONTEXT_PARAMS2_0_0 {
+    pub const fn requestRaw(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set_requestRaw(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn includeTpm12(&self) -> u32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_includeTpm12(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn includeTpm20(&self) -> u32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_includeTpm20(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+}
 impl windows_core::TypeKind for TBS_CONTEXT_PARAMS2_0_0 {
     type TypeKind = windows_core::CopyType;
 }
@@ -182,7 +206,8 @@ impl Default for TBS_CONTEXT_PARAMS2_0_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TPM_DEVICE_INFO {
     pub structVersion: u32,
     pub tpmVersion: u32,
@@ -198,7 +223,8 @@ impl Default for TPM_DEVICE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TPM_WNF_PROVISIONING {
     pub status: u32,
     pub message: [u8; 28],
@@ -268,6 +268,7 @@ pub struct MTSAdminErrorCodes(pub i32);
 impl windows_core::TypeKind for MTSAdminErrorCodes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MTSAdminErrorCodes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MTSAdminErrorCodes").field(&self.0).finish()
@@ -279,6 +280,7 @@ pub struct MTSPackageExportOptions(pub i32);
 impl windows_core::TypeKind for MTSPackageExportOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MTSPackageExportOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MTSPackageExportOptions").field(&self.0).finish()
@@ -290,6 +292,7 @@ pub struct MTSPackageInstallOptions(pub i32);
 impl windows_core::TypeKind for MTSPackageInstallOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MTSPackageInstallOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MTSPackageInstallOptions").field(&self.0).finish()
@@ -304,3 +307,5 @@ pub const RemoteComponentUtil: windows_core::GUID = windows_core::GUID::from_u12
 pub const RoleAssociationUtil: windows_core::GUID = windows_core::GUID::from_u128(0x6eb22887_8a19_11d0_81b6_00a0c9231c29);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
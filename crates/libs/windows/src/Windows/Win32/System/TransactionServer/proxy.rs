@@ -0,0 +1,119 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICatalog_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetCollection(this: *mut core::ffi::c_void, bstrcollname: core::mem::MaybeUninit<windows_core::BSTR>, ppcatalogcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalog", "GetCollection", 7usize, |this| ((**(this as *const *const ICatalog_Vtbl)).GetCollection)(this, bstrcollname, ppcatalogcollection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Connect(this: *mut core::ffi::c_void, bstrconnectstring: core::mem::MaybeUninit<windows_core::BSTR>, ppcatalogcollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalog", "Connect", 8usize, |this| ((**(this as *const *const ICatalog_Vtbl)).Connect)(this, bstrconnectstring, ppcatalogcollection), |result| Some(*result))
+        }
+        unsafe extern "system" fn MajorVersion(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalog", "MajorVersion", 9usize, |this| ((**(this as *const *const ICatalog_Vtbl)).MajorVersion)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinorVersion(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICatalog", "MinorVersion", 10usize, |this| ((**(this as *const *const ICatalog_Vtbl)).MinorVersion)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetCollection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetCollection: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Connect,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Connect: 0,
+            MajorVersion,
+            MinorVersion,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICatalog as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IComponentUtil_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InstallComponent(this: *mut core::ffi::c_void, bstrdllfile: core::mem::MaybeUninit<windows_core::BSTR>, bstrtypelibfile: core::mem::MaybeUninit<windows_core::BSTR>, bstrproxystubdllfile: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentUtil", "InstallComponent", 7usize, |this| ((**(this as *const *const IComponentUtil_Vtbl)).InstallComponent)(this, bstrdllfile, bstrtypelibfile, bstrproxystubdllfile), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImportComponent(this: *mut core::ffi::c_void, bstrclsid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentUtil", "ImportComponent", 8usize, |this| ((**(this as *const *const IComponentUtil_Vtbl)).ImportComponent)(this, bstrclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImportComponentByName(this: *mut core::ffi::c_void, bstrprogid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentUtil", "ImportComponentByName", 9usize, |this| ((**(this as *const *const IComponentUtil_Vtbl)).ImportComponentByName)(this, bstrprogid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetCLSIDs(this: *mut core::ffi::c_void, bstrdllfile: core::mem::MaybeUninit<windows_core::BSTR>, bstrtypelibfile: core::mem::MaybeUninit<windows_core::BSTR>, aclsids: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IComponentUtil", "GetCLSIDs", 10usize, |this| ((**(this as *const *const IComponentUtil_Vtbl)).GetCLSIDs)(this, bstrdllfile, bstrtypelibfile, aclsids), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InstallComponent,
+            ImportComponent,
+            ImportComponentByName,
+            #[cfg(feature = "Win32_System_Com")]
+            GetCLSIDs,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetCLSIDs: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IComponentUtil as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPackageUtil_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InstallPackage(this: *mut core::ffi::c_void, bstrpackagefile: core::mem::MaybeUninit<windows_core::BSTR>, bstrinstallpath: core::mem::MaybeUninit<windows_core::BSTR>, loptions: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageUtil", "InstallPackage", 7usize, |this| ((**(this as *const *const IPackageUtil_Vtbl)).InstallPackage)(this, bstrpackagefile, bstrinstallpath, loptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExportPackage(this: *mut core::ffi::c_void, bstrpackageid: core::mem::MaybeUninit<windows_core::BSTR>, bstrpackagefile: core::mem::MaybeUninit<windows_core::BSTR>, loptions: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageUtil", "ExportPackage", 8usize, |this| ((**(this as *const *const IPackageUtil_Vtbl)).ExportPackage)(this, bstrpackageid, bstrpackagefile, loptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShutdownPackage(this: *mut core::ffi::c_void, bstrpackageid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPackageUtil", "ShutdownPackage", 9usize, |this| ((**(this as *const *const IPackageUtil_Vtbl)).ShutdownPackage)(this, bstrpackageid), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InstallPackage, ExportPackage, ShutdownPackage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPackageUtil as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRemoteComponentUtil_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InstallRemoteComponent(this: *mut core::ffi::c_void, bstrserver: core::mem::MaybeUninit<windows_core::BSTR>, bstrpackageid: core::mem::MaybeUninit<windows_core::BSTR>, bstrclsid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteComponentUtil", "InstallRemoteComponent", 7usize, |this| ((**(this as *const *const IRemoteComponentUtil_Vtbl)).InstallRemoteComponent)(this, bstrserver, bstrpackageid, bstrclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn InstallRemoteComponentByName(this: *mut core::ffi::c_void, bstrserver: core::mem::MaybeUninit<windows_core::BSTR>, bstrpackagename: core::mem::MaybeUninit<windows_core::BSTR>, bstrprogid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRemoteComponentUtil", "InstallRemoteComponentByName", 8usize, |this| ((**(this as *const *const IRemoteComponentUtil_Vtbl)).InstallRemoteComponentByName)(this, bstrserver, bstrpackagename, bstrprogid), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InstallRemoteComponent, InstallRemoteComponentByName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRemoteComponentUtil as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRoleAssociationUtil_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AssociateRole(this: *mut core::ffi::c_void, bstrroleid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRoleAssociationUtil", "AssociateRole", 7usize, |this| ((**(this as *const *const IRoleAssociationUtil_Vtbl)).AssociateRole)(this, bstrroleid), |result| Some(*result))
+        }
+        unsafe extern "system" fn AssociateRoleByName(this: *mut core::ffi::c_void, bstrrolename: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRoleAssociationUtil", "AssociateRoleByName", 8usize, |this| ((**(this as *const *const IRoleAssociationUtil_Vtbl)).AssociateRoleByName)(this, bstrrolename), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AssociateRole, AssociateRoleByName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRoleAssociationUtil as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
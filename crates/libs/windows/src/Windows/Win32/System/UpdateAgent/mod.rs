@@ -4249,6 +4249,7 @@ pub struct AddServiceFlag(pub i32);
 impl windows_core::TypeKind for AddServiceFlag {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AddServiceFlag {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AddServiceFlag").field(&self.0).finish()
@@ -4260,6 +4261,7 @@ pub struct AutoDownloadMode(pub i32);
 impl windows_core::TypeKind for AutoDownloadMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AutoDownloadMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AutoDownloadMode").field(&self.0).finish()
@@ -4271,6 +4273,7 @@ pub struct AutoSelectionMode(pub i32);
 impl windows_core::TypeKind for AutoSelectionMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AutoSelectionMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AutoSelectionMode").field(&self.0).finish()
@@ -4282,6 +4285,7 @@ pub struct AutomaticUpdatesNotificationLevel(pub i32);
 impl windows_core::TypeKind for AutomaticUpdatesNotificationLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AutomaticUpdatesNotificationLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AutomaticUpdatesNotificationLevel").field(&self.0).finish()
@@ -4293,6 +4297,7 @@ pub struct AutomaticUpdatesPermissionType(pub i32);
 impl windows_core::TypeKind for AutomaticUpdatesPermissionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AutomaticUpdatesPermissionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AutomaticUpdatesPermissionType").field(&self.0).finish()
@@ -4304,6 +4309,7 @@ pub struct AutomaticUpdatesScheduledInstallationDay(pub i32);
 impl windows_core::TypeKind for AutomaticUpdatesScheduledInstallationDay {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AutomaticUpdatesScheduledInstallationDay {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AutomaticUpdatesScheduledInstallationDay").field(&self.0).finish()
@@ -4315,6 +4321,7 @@ pub struct AutomaticUpdatesUserType(pub i32);
 impl windows_core::TypeKind for AutomaticUpdatesUserType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AutomaticUpdatesUserType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AutomaticUpdatesUserType").field(&self.0).finish()
@@ -4326,6 +4333,7 @@ pub struct DeploymentAction(pub i32);
 impl windows_core::TypeKind for DeploymentAction {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DeploymentAction {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DeploymentAction").field(&self.0).finish()
@@ -4337,6 +4345,7 @@ pub struct DownloadPhase(pub i32);
 impl windows_core::TypeKind for DownloadPhase {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DownloadPhase {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DownloadPhase").field(&self.0).finish()
@@ -4348,6 +4357,7 @@ pub struct DownloadPriority(pub i32);
 impl windows_core::TypeKind for DownloadPriority {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DownloadPriority {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DownloadPriority").field(&self.0).finish()
@@ -4359,6 +4369,7 @@ pub struct InstallationImpact(pub i32);
 impl windows_core::TypeKind for InstallationImpact {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InstallationImpact {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InstallationImpact").field(&self.0).finish()
@@ -4370,6 +4381,7 @@ pub struct InstallationRebootBehavior(pub i32);
 impl windows_core::TypeKind for InstallationRebootBehavior {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InstallationRebootBehavior {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InstallationRebootBehavior").field(&self.0).finish()
@@ -4381,6 +4393,7 @@ pub struct OperationResultCode(pub i32);
 impl windows_core::TypeKind for OperationResultCode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OperationResultCode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OperationResultCode").field(&self.0).finish()
@@ -4392,6 +4405,7 @@ pub struct SearchScope(pub i32);
 impl windows_core::TypeKind for SearchScope {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SearchScope {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SearchScope").field(&self.0).finish()
@@ -4403,6 +4417,7 @@ pub struct ServerSelection(pub i32);
 impl windows_core::TypeKind for ServerSelection {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ServerSelection {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ServerSelection").field(&self.0).finish()
@@ -4414,6 +4429,7 @@ pub struct UpdateExceptionContext(pub i32);
 impl windows_core::TypeKind for UpdateExceptionContext {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UpdateExceptionContext {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UpdateExceptionContext").field(&self.0).finish()
@@ -4425,6 +4441,7 @@ pub struct UpdateLockdownOption(pub i32);
 impl windows_core::TypeKind for UpdateLockdownOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UpdateLockdownOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UpdateLockdownOption").field(&self.0).finish()
@@ -4436,6 +4453,7 @@ pub struct UpdateOperation(pub i32);
 impl windows_core::TypeKind for UpdateOperation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UpdateOperation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UpdateOperation").field(&self.0).finish()
@@ -4447,6 +4465,7 @@ pub struct UpdateServiceOption(pub i32);
 impl windows_core::TypeKind for UpdateServiceOption {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UpdateServiceOption {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UpdateServiceOption").field(&self.0).finish()
@@ -4458,6 +4477,7 @@ pub struct UpdateServiceRegistrationState(pub i32);
 impl windows_core::TypeKind for UpdateServiceRegistrationState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UpdateServiceRegistrationState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UpdateServiceRegistrationState").field(&self.0).finish()
@@ -4469,6 +4489,7 @@ pub struct UpdateType(pub i32);
 impl windows_core::TypeKind for UpdateType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UpdateType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UpdateType").field(&self.0).finish()
@@ -4488,3 +4509,5 @@ pub const WebProxy: windows_core::GUID = windows_core::GUID::from_u128(0x650503c
 pub const WindowsUpdateAgentInfo: windows_core::GUID = windows_core::GUID::from_u128(0xc2e88c2f_6f5b_4aaa_894b_55c847ad3a2d);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
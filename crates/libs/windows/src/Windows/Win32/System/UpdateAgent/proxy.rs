@@ -0,0 +1,2587 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAutomaticUpdates_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DetectNow(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomaticUpdates", "DetectNow", 7usize, |this| ((**(this as *const *const IAutomaticUpdates_Vtbl)).DetectNow)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomaticUpdates", "Pause", 8usize, |this| ((**(this as *const *const IAutomaticUpdates_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomaticUpdates", "Resume", 9usize, |this| ((**(this as *const *const IAutomaticUpdates_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowSettingsDialog(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomaticUpdates", "ShowSettingsDialog", 10usize, |this| ((**(this as *const *const IAutomaticUpdates_Vtbl)).ShowSettingsDialog)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Settings(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomaticUpdates", "Settings", 11usize, |this| ((**(this as *const *const IAutomaticUpdates_Vtbl)).Settings)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceEnabled(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomaticUpdates", "ServiceEnabled", 12usize, |this| ((**(this as *const *const IAutomaticUpdates_Vtbl)).ServiceEnabled)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableService(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomaticUpdates", "EnableService", 13usize, |this| ((**(this as *const *const IAutomaticUpdates_Vtbl)).EnableService)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DetectNow,
+            Pause,
+            Resume,
+            ShowSettingsDialog,
+            #[cfg(feature = "Win32_System_Com")]
+            Settings,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Settings: 0,
+            ServiceEnabled,
+            EnableService,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAutomaticUpdates as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAutomaticUpdates2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Results(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomaticUpdates2", "Results", 14usize, |this| ((**(this as *const *const IAutomaticUpdates2_Vtbl)).Results)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <IAutomaticUpdates_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Results,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Results: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAutomaticUpdates2 as windows_core::Interface>::IID || <IAutomaticUpdates_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAutomaticUpdatesResults_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LastSearchSuccessDate(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomaticUpdatesResults", "LastSearchSuccessDate", 7usize, |this| ((**(this as *const *const IAutomaticUpdatesResults_Vtbl)).LastSearchSuccessDate)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastInstallationSuccessDate(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomaticUpdatesResults", "LastInstallationSuccessDate", 8usize, |this| ((**(this as *const *const IAutomaticUpdatesResults_Vtbl)).LastInstallationSuccessDate)(this, retval), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LastSearchSuccessDate, LastInstallationSuccessDate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAutomaticUpdatesResults as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAutomaticUpdatesSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NotificationLevel(this: *mut core::ffi::c_void, retval: *mut AutomaticUpdatesNotificationLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomaticUpdatesSettings", "NotificationLevel", 7usize, |this| ((**(this as *const *const IAutomaticUpdatesSettings_Vtbl)).NotificationLevel)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNotificationLevel(this: *mut core::ffi::c_void, value: AutomaticUpdatesNotificationLevel) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomaticUpdatesSettings", "SetNotificationLevel", 8usize, |this| ((**(this as *const *const IAutomaticUpdatesSettings_Vtbl)).SetNotificationLevel)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadOnly(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomaticUpdatesSettings", "ReadOnly", 9usize, |this| ((**(this as *const *const IAutomaticUpdatesSettings_Vtbl)).ReadOnly)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Required(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomaticUpdatesSettings", "Required", 10usize, |this| ((**(this as *const *const IAutomaticUpdatesSettings_Vtbl)).Required)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScheduledInstallationDay(this: *mut core::ffi::c_void, retval: *mut AutomaticUpdatesScheduledInstallationDay) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomaticUpdatesSettings", "ScheduledInstallationDay", 11usize, |this| ((**(this as *const *const IAutomaticUpdatesSettings_Vtbl)).ScheduledInstallationDay)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScheduledInstallationDay(this: *mut core::ffi::c_void, value: AutomaticUpdatesScheduledInstallationDay) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomaticUpdatesSettings", "SetScheduledInstallationDay", 12usize, |this| ((**(this as *const *const IAutomaticUpdatesSettings_Vtbl)).SetScheduledInstallationDay)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScheduledInstallationTime(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomaticUpdatesSettings", "ScheduledInstallationTime", 13usize, |this| ((**(this as *const *const IAutomaticUpdatesSettings_Vtbl)).ScheduledInstallationTime)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScheduledInstallationTime(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomaticUpdatesSettings", "SetScheduledInstallationTime", 14usize, |this| ((**(this as *const *const IAutomaticUpdatesSettings_Vtbl)).SetScheduledInstallationTime)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomaticUpdatesSettings", "Refresh", 15usize, |this| ((**(this as *const *const IAutomaticUpdatesSettings_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomaticUpdatesSettings", "Save", 16usize, |this| ((**(this as *const *const IAutomaticUpdatesSettings_Vtbl)).Save)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            NotificationLevel,
+            SetNotificationLevel,
+            ReadOnly,
+            Required,
+            ScheduledInstallationDay,
+            SetScheduledInstallationDay,
+            ScheduledInstallationTime,
+            SetScheduledInstallationTime,
+            Refresh,
+            Save,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAutomaticUpdatesSettings as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAutomaticUpdatesSettings2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IncludeRecommendedUpdates(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomaticUpdatesSettings2", "IncludeRecommendedUpdates", 17usize, |this| ((**(this as *const *const IAutomaticUpdatesSettings2_Vtbl)).IncludeRecommendedUpdates)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIncludeRecommendedUpdates(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomaticUpdatesSettings2", "SetIncludeRecommendedUpdates", 18usize, |this| ((**(this as *const *const IAutomaticUpdatesSettings2_Vtbl)).SetIncludeRecommendedUpdates)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckPermission(this: *mut core::ffi::c_void, usertype: AutomaticUpdatesUserType, permissiontype: AutomaticUpdatesPermissionType, userhaspermission: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomaticUpdatesSettings2", "CheckPermission", 19usize, |this| ((**(this as *const *const IAutomaticUpdatesSettings2_Vtbl)).CheckPermission)(this, usertype, permissiontype, userhaspermission), |result| Some(*result))
+        }
+        Self {
+            base__: <IAutomaticUpdatesSettings_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IncludeRecommendedUpdates,
+            SetIncludeRecommendedUpdates,
+            CheckPermission,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAutomaticUpdatesSettings2 as windows_core::Interface>::IID || <IAutomaticUpdatesSettings_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAutomaticUpdatesSettings3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NonAdministratorsElevated(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomaticUpdatesSettings3", "NonAdministratorsElevated", 20usize, |this| ((**(this as *const *const IAutomaticUpdatesSettings3_Vtbl)).NonAdministratorsElevated)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNonAdministratorsElevated(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomaticUpdatesSettings3", "SetNonAdministratorsElevated", 21usize, |this| ((**(this as *const *const IAutomaticUpdatesSettings3_Vtbl)).SetNonAdministratorsElevated)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn FeaturedUpdatesEnabled(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomaticUpdatesSettings3", "FeaturedUpdatesEnabled", 22usize, |this| ((**(this as *const *const IAutomaticUpdatesSettings3_Vtbl)).FeaturedUpdatesEnabled)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFeaturedUpdatesEnabled(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAutomaticUpdatesSettings3", "SetFeaturedUpdatesEnabled", 23usize, |this| ((**(this as *const *const IAutomaticUpdatesSettings3_Vtbl)).SetFeaturedUpdatesEnabled)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <IAutomaticUpdatesSettings2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            NonAdministratorsElevated,
+            SetNonAdministratorsElevated,
+            FeaturedUpdatesEnabled,
+            SetFeaturedUpdatesEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAutomaticUpdatesSettings3 as windows_core::Interface>::IID || <IAutomaticUpdatesSettings2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICategory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICategory", "Name", 7usize, |this| ((**(this as *const *const ICategory_Vtbl)).Name)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CategoryID(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICategory", "CategoryID", 8usize, |this| ((**(this as *const *const ICategory_Vtbl)).CategoryID)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Children(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICategory", "Children", 9usize, |this| ((**(this as *const *const ICategory_Vtbl)).Children)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICategory", "Description", 10usize, |this| ((**(this as *const *const ICategory_Vtbl)).Description)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Image(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICategory", "Image", 11usize, |this| ((**(this as *const *const ICategory_Vtbl)).Image)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Order(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICategory", "Order", 12usize, |this| ((**(this as *const *const ICategory_Vtbl)).Order)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Parent(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICategory", "Parent", 13usize, |this| ((**(this as *const *const ICategory_Vtbl)).Parent)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICategory", "Type", 14usize, |this| ((**(this as *const *const ICategory_Vtbl)).Type)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Updates(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICategory", "Updates", 15usize, |this| ((**(this as *const *const ICategory_Vtbl)).Updates)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            CategoryID,
+            #[cfg(feature = "Win32_System_Com")]
+            Children,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Children: 0,
+            Description,
+            #[cfg(feature = "Win32_System_Com")]
+            Image,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Image: 0,
+            Order,
+            #[cfg(feature = "Win32_System_Com")]
+            Parent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Parent: 0,
+            Type,
+            #[cfg(feature = "Win32_System_Com")]
+            Updates,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Updates: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICategory as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICategoryCollection_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICategoryCollection", "get_Item", 7usize, |this| ((**(this as *const *const ICategoryCollection_Vtbl)).get_Item)(this, index, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICategoryCollection", "_NewEnum", 8usize, |this| ((**(this as *const *const ICategoryCollection_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICategoryCollection", "Count", 9usize, |this| ((**(this as *const *const ICategoryCollection_Vtbl)).Count)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            _NewEnum,
+            Count,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICategoryCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDownloadCompletedCallback_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Invoke(this: *mut core::ffi::c_void, downloadjob: *mut core::ffi::c_void, callbackargs: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadCompletedCallback", "Invoke", 3usize, |this| ((**(this as *const *const IDownloadCompletedCallback_Vtbl)).Invoke)(this, downloadjob, callbackargs), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Invoke,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Invoke: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDownloadCompletedCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDownloadCompletedCallbackArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDownloadCompletedCallbackArgs as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDownloadJob_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AsyncState(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadJob", "AsyncState", 7usize, |this| ((**(this as *const *const IDownloadJob_Vtbl)).AsyncState)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCompleted(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadJob", "IsCompleted", 8usize, |this| ((**(this as *const *const IDownloadJob_Vtbl)).IsCompleted)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Updates(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadJob", "Updates", 9usize, |this| ((**(this as *const *const IDownloadJob_Vtbl)).Updates)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CleanUp(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadJob", "CleanUp", 10usize, |this| ((**(this as *const *const IDownloadJob_Vtbl)).CleanUp)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetProgress(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadJob", "GetProgress", 11usize, |this| ((**(this as *const *const IDownloadJob_Vtbl)).GetProgress)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAbort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadJob", "RequestAbort", 12usize, |this| ((**(this as *const *const IDownloadJob_Vtbl)).RequestAbort)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AsyncState,
+            IsCompleted,
+            #[cfg(feature = "Win32_System_Com")]
+            Updates,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Updates: 0,
+            CleanUp,
+            #[cfg(feature = "Win32_System_Com")]
+            GetProgress,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetProgress: 0,
+            RequestAbort,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDownloadJob as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDownloadProgress_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentUpdateBytesDownloaded(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::DECIMAL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadProgress", "CurrentUpdateBytesDownloaded", 7usize, |this| ((**(this as *const *const IDownloadProgress_Vtbl)).CurrentUpdateBytesDownloaded)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentUpdateBytesToDownload(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::DECIMAL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadProgress", "CurrentUpdateBytesToDownload", 8usize, |this| ((**(this as *const *const IDownloadProgress_Vtbl)).CurrentUpdateBytesToDownload)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentUpdateIndex(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadProgress", "CurrentUpdateIndex", 9usize, |this| ((**(this as *const *const IDownloadProgress_Vtbl)).CurrentUpdateIndex)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PercentComplete(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadProgress", "PercentComplete", 10usize, |this| ((**(this as *const *const IDownloadProgress_Vtbl)).PercentComplete)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalBytesDownloaded(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::DECIMAL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadProgress", "TotalBytesDownloaded", 11usize, |this| ((**(this as *const *const IDownloadProgress_Vtbl)).TotalBytesDownloaded)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn TotalBytesToDownload(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::DECIMAL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadProgress", "TotalBytesToDownload", 12usize, |this| ((**(this as *const *const IDownloadProgress_Vtbl)).TotalBytesToDownload)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetUpdateResult(this: *mut core::ffi::c_void, updateindex: i32, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadProgress", "GetUpdateResult", 13usize, |this| ((**(this as *const *const IDownloadProgress_Vtbl)).GetUpdateResult)(this, updateindex, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentUpdateDownloadPhase(this: *mut core::ffi::c_void, retval: *mut DownloadPhase) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadProgress", "CurrentUpdateDownloadPhase", 14usize, |this| ((**(this as *const *const IDownloadProgress_Vtbl)).CurrentUpdateDownloadPhase)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentUpdatePercentComplete(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadProgress", "CurrentUpdatePercentComplete", 15usize, |this| ((**(this as *const *const IDownloadProgress_Vtbl)).CurrentUpdatePercentComplete)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CurrentUpdateBytesDownloaded,
+            CurrentUpdateBytesToDownload,
+            CurrentUpdateIndex,
+            PercentComplete,
+            TotalBytesDownloaded,
+            TotalBytesToDownload,
+            #[cfg(feature = "Win32_System_Com")]
+            GetUpdateResult,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetUpdateResult: 0,
+            CurrentUpdateDownloadPhase,
+            CurrentUpdatePercentComplete,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDownloadProgress as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDownloadProgressChangedCallback_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Invoke(this: *mut core::ffi::c_void, downloadjob: *mut core::ffi::c_void, callbackargs: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadProgressChangedCallback", "Invoke", 3usize, |this| ((**(this as *const *const IDownloadProgressChangedCallback_Vtbl)).Invoke)(this, downloadjob, callbackargs), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Invoke,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Invoke: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDownloadProgressChangedCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDownloadProgressChangedCallbackArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Progress(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadProgressChangedCallbackArgs", "Progress", 7usize, |this| ((**(this as *const *const IDownloadProgressChangedCallbackArgs_Vtbl)).Progress)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Progress,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Progress: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDownloadProgressChangedCallbackArgs as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDownloadResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HResult(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadResult", "HResult", 7usize, |this| ((**(this as *const *const IDownloadResult_Vtbl)).HResult)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResultCode(this: *mut core::ffi::c_void, retval: *mut OperationResultCode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadResult", "ResultCode", 8usize, |this| ((**(this as *const *const IDownloadResult_Vtbl)).ResultCode)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetUpdateResult(this: *mut core::ffi::c_void, updateindex: i32, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadResult", "GetUpdateResult", 9usize, |this| ((**(this as *const *const IDownloadResult_Vtbl)).GetUpdateResult)(this, updateindex, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            HResult,
+            ResultCode,
+            #[cfg(feature = "Win32_System_Com")]
+            GetUpdateResult,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetUpdateResult: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDownloadResult as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IImageInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AltText(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageInformation", "AltText", 7usize, |this| ((**(this as *const *const IImageInformation_Vtbl)).AltText)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Height(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageInformation", "Height", 8usize, |this| ((**(this as *const *const IImageInformation_Vtbl)).Height)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Source(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageInformation", "Source", 9usize, |this| ((**(this as *const *const IImageInformation_Vtbl)).Source)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Width(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageInformation", "Width", 10usize, |this| ((**(this as *const *const IImageInformation_Vtbl)).Width)(this, retval), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AltText, Height, Source, Width }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImageInformation as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInstallationAgent_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RecordInstallationResult(this: *mut core::ffi::c_void, installationresultcookie: core::mem::MaybeUninit<windows_core::BSTR>, hresult: i32, extendedreportingdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationAgent", "RecordInstallationResult", 7usize, |this| ((**(this as *const *const IInstallationAgent_Vtbl)).RecordInstallationResult)(this, installationresultcookie, hresult, extendedreportingdata), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            RecordInstallationResult,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RecordInstallationResult: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInstallationAgent as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInstallationBehavior_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CanRequestUserInput(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationBehavior", "CanRequestUserInput", 7usize, |this| ((**(this as *const *const IInstallationBehavior_Vtbl)).CanRequestUserInput)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Impact(this: *mut core::ffi::c_void, retval: *mut InstallationImpact) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationBehavior", "Impact", 8usize, |this| ((**(this as *const *const IInstallationBehavior_Vtbl)).Impact)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RebootBehavior(this: *mut core::ffi::c_void, retval: *mut InstallationRebootBehavior) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationBehavior", "RebootBehavior", 9usize, |this| ((**(this as *const *const IInstallationBehavior_Vtbl)).RebootBehavior)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequiresNetworkConnectivity(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationBehavior", "RequiresNetworkConnectivity", 10usize, |this| ((**(this as *const *const IInstallationBehavior_Vtbl)).RequiresNetworkConnectivity)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CanRequestUserInput,
+            Impact,
+            RebootBehavior,
+            RequiresNetworkConnectivity,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInstallationBehavior as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInstallationCompletedCallback_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Invoke(this: *mut core::ffi::c_void, installationjob: *mut core::ffi::c_void, callbackargs: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationCompletedCallback", "Invoke", 3usize, |this| ((**(this as *const *const IInstallationCompletedCallback_Vtbl)).Invoke)(this, installationjob, callbackargs), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Invoke,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Invoke: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInstallationCompletedCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInstallationCompletedCallbackArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInstallationCompletedCallbackArgs as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInstallationJob_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AsyncState(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationJob", "AsyncState", 7usize, |this| ((**(this as *const *const IInstallationJob_Vtbl)).AsyncState)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCompleted(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationJob", "IsCompleted", 8usize, |this| ((**(this as *const *const IInstallationJob_Vtbl)).IsCompleted)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Updates(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationJob", "Updates", 9usize, |this| ((**(this as *const *const IInstallationJob_Vtbl)).Updates)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CleanUp(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationJob", "CleanUp", 10usize, |this| ((**(this as *const *const IInstallationJob_Vtbl)).CleanUp)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetProgress(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationJob", "GetProgress", 11usize, |this| ((**(this as *const *const IInstallationJob_Vtbl)).GetProgress)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAbort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationJob", "RequestAbort", 12usize, |this| ((**(this as *const *const IInstallationJob_Vtbl)).RequestAbort)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AsyncState,
+            IsCompleted,
+            #[cfg(feature = "Win32_System_Com")]
+            Updates,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Updates: 0,
+            CleanUp,
+            #[cfg(feature = "Win32_System_Com")]
+            GetProgress,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetProgress: 0,
+            RequestAbort,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInstallationJob as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInstallationProgress_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentUpdateIndex(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationProgress", "CurrentUpdateIndex", 7usize, |this| ((**(this as *const *const IInstallationProgress_Vtbl)).CurrentUpdateIndex)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentUpdatePercentComplete(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationProgress", "CurrentUpdatePercentComplete", 8usize, |this| ((**(this as *const *const IInstallationProgress_Vtbl)).CurrentUpdatePercentComplete)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PercentComplete(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationProgress", "PercentComplete", 9usize, |this| ((**(this as *const *const IInstallationProgress_Vtbl)).PercentComplete)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetUpdateResult(this: *mut core::ffi::c_void, updateindex: i32, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationProgress", "GetUpdateResult", 10usize, |this| ((**(this as *const *const IInstallationProgress_Vtbl)).GetUpdateResult)(this, updateindex, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CurrentUpdateIndex,
+            CurrentUpdatePercentComplete,
+            PercentComplete,
+            #[cfg(feature = "Win32_System_Com")]
+            GetUpdateResult,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetUpdateResult: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInstallationProgress as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInstallationProgressChangedCallback_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Invoke(this: *mut core::ffi::c_void, installationjob: *mut core::ffi::c_void, callbackargs: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationProgressChangedCallback", "Invoke", 3usize, |this| ((**(this as *const *const IInstallationProgressChangedCallback_Vtbl)).Invoke)(this, installationjob, callbackargs), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Invoke,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Invoke: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInstallationProgressChangedCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInstallationProgressChangedCallbackArgs_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Progress(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationProgressChangedCallbackArgs", "Progress", 7usize, |this| ((**(this as *const *const IInstallationProgressChangedCallbackArgs_Vtbl)).Progress)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Progress,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Progress: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInstallationProgressChangedCallbackArgs as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInstallationResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HResult(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationResult", "HResult", 7usize, |this| ((**(this as *const *const IInstallationResult_Vtbl)).HResult)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RebootRequired(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationResult", "RebootRequired", 8usize, |this| ((**(this as *const *const IInstallationResult_Vtbl)).RebootRequired)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResultCode(this: *mut core::ffi::c_void, retval: *mut OperationResultCode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationResult", "ResultCode", 9usize, |this| ((**(this as *const *const IInstallationResult_Vtbl)).ResultCode)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetUpdateResult(this: *mut core::ffi::c_void, updateindex: i32, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInstallationResult", "GetUpdateResult", 10usize, |this| ((**(this as *const *const IInstallationResult_Vtbl)).GetUpdateResult)(this, updateindex, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            HResult,
+            RebootRequired,
+            ResultCode,
+            #[cfg(feature = "Win32_System_Com")]
+            GetUpdateResult,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetUpdateResult: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInstallationResult as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInvalidProductLicenseException_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Product(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInvalidProductLicenseException", "Product", 10usize, |this| ((**(this as *const *const IInvalidProductLicenseException_Vtbl)).Product)(this, retval), |result| Some(*result))
+        }
+        Self { base__: <IUpdateException_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Product }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInvalidProductLicenseException as windows_core::Interface>::IID || <IUpdateException_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISearchCompletedCallback_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Invoke(this: *mut core::ffi::c_void, searchjob: *mut core::ffi::c_void, callbackargs: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchCompletedCallback", "Invoke", 3usize, |this| ((**(this as *const *const ISearchCompletedCallback_Vtbl)).Invoke)(this, searchjob, callbackargs), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Invoke,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Invoke: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchCompletedCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISearchCompletedCallbackArgs_Vtbl {
+    const PROXY: Self = { Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchCompletedCallbackArgs as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISearchJob_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AsyncState(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchJob", "AsyncState", 7usize, |this| ((**(this as *const *const ISearchJob_Vtbl)).AsyncState)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsCompleted(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchJob", "IsCompleted", 8usize, |this| ((**(this as *const *const ISearchJob_Vtbl)).IsCompleted)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CleanUp(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchJob", "CleanUp", 9usize, |this| ((**(this as *const *const ISearchJob_Vtbl)).CleanUp)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAbort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchJob", "RequestAbort", 10usize, |this| ((**(this as *const *const ISearchJob_Vtbl)).RequestAbort)(this), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AsyncState, IsCompleted, CleanUp, RequestAbort }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchJob as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISearchResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ResultCode(this: *mut core::ffi::c_void, retval: *mut OperationResultCode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchResult", "ResultCode", 7usize, |this| ((**(this as *const *const ISearchResult_Vtbl)).ResultCode)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RootCategories(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchResult", "RootCategories", 8usize, |this| ((**(this as *const *const ISearchResult_Vtbl)).RootCategories)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Updates(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchResult", "Updates", 9usize, |this| ((**(this as *const *const ISearchResult_Vtbl)).Updates)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Warnings(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISearchResult", "Warnings", 10usize, |this| ((**(this as *const *const ISearchResult_Vtbl)).Warnings)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ResultCode,
+            #[cfg(feature = "Win32_System_Com")]
+            RootCategories,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RootCategories: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Updates,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Updates: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Warnings,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Warnings: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISearchResult as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IStringCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStringCollection", "get_Item", 7usize, |this| ((**(this as *const *const IStringCollection_Vtbl)).get_Item)(this, index, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_Item(this: *mut core::ffi::c_void, index: i32, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStringCollection", "put_Item", 8usize, |this| ((**(this as *const *const IStringCollection_Vtbl)).put_Item)(this, index, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStringCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const IStringCollection_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStringCollection", "Count", 10usize, |this| ((**(this as *const *const IStringCollection_Vtbl)).Count)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadOnly(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStringCollection", "ReadOnly", 11usize, |this| ((**(this as *const *const IStringCollection_Vtbl)).ReadOnly)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStringCollection", "Add", 12usize, |this| ((**(this as *const *const IStringCollection_Vtbl)).Add)(this, value, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStringCollection", "Clear", 13usize, |this| ((**(this as *const *const IStringCollection_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Copy(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStringCollection", "Copy", 14usize, |this| ((**(this as *const *const IStringCollection_Vtbl)).Copy)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Insert(this: *mut core::ffi::c_void, index: i32, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStringCollection", "Insert", 15usize, |this| ((**(this as *const *const IStringCollection_Vtbl)).Insert)(this, index, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAt(this: *mut core::ffi::c_void, index: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStringCollection", "RemoveAt", 16usize, |this| ((**(this as *const *const IStringCollection_Vtbl)).RemoveAt)(this, index), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            get_Item,
+            put_Item,
+            _NewEnum,
+            Count,
+            ReadOnly,
+            Add,
+            Clear,
+            #[cfg(feature = "Win32_System_Com")]
+            Copy,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Copy: 0,
+            Insert,
+            RemoveAt,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStringCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISystemInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OemHardwareSupportLink(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemInformation", "OemHardwareSupportLink", 7usize, |this| ((**(this as *const *const ISystemInformation_Vtbl)).OemHardwareSupportLink)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RebootRequired(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemInformation", "RebootRequired", 8usize, |this| ((**(this as *const *const ISystemInformation_Vtbl)).RebootRequired)(this, retval), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OemHardwareSupportLink, RebootRequired }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemInformation as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "Title", 7usize, |this| ((**(this as *const *const IUpdate_Vtbl)).Title)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoSelectOnWebSites(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "AutoSelectOnWebSites", 8usize, |this| ((**(this as *const *const IUpdate_Vtbl)).AutoSelectOnWebSites)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn BundledUpdates(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "BundledUpdates", 9usize, |this| ((**(this as *const *const IUpdate_Vtbl)).BundledUpdates)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanRequireSource(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "CanRequireSource", 10usize, |this| ((**(this as *const *const IUpdate_Vtbl)).CanRequireSource)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Categories(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "Categories", 11usize, |this| ((**(this as *const *const IUpdate_Vtbl)).Categories)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deadline(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "Deadline", 12usize, |this| ((**(this as *const *const IUpdate_Vtbl)).Deadline)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeltaCompressedContentAvailable(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "DeltaCompressedContentAvailable", 13usize, |this| ((**(this as *const *const IUpdate_Vtbl)).DeltaCompressedContentAvailable)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeltaCompressedContentPreferred(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "DeltaCompressedContentPreferred", 14usize, |this| ((**(this as *const *const IUpdate_Vtbl)).DeltaCompressedContentPreferred)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "Description", 15usize, |this| ((**(this as *const *const IUpdate_Vtbl)).Description)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn EulaAccepted(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "EulaAccepted", 16usize, |this| ((**(this as *const *const IUpdate_Vtbl)).EulaAccepted)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn EulaText(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "EulaText", 17usize, |this| ((**(this as *const *const IUpdate_Vtbl)).EulaText)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn HandlerID(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "HandlerID", 18usize, |this| ((**(this as *const *const IUpdate_Vtbl)).HandlerID)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Identity(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "Identity", 19usize, |this| ((**(this as *const *const IUpdate_Vtbl)).Identity)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Image(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "Image", 20usize, |this| ((**(this as *const *const IUpdate_Vtbl)).Image)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InstallationBehavior(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "InstallationBehavior", 21usize, |this| ((**(this as *const *const IUpdate_Vtbl)).InstallationBehavior)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsBeta(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "IsBeta", 22usize, |this| ((**(this as *const *const IUpdate_Vtbl)).IsBeta)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDownloaded(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "IsDownloaded", 23usize, |this| ((**(this as *const *const IUpdate_Vtbl)).IsDownloaded)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsHidden(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "IsHidden", 24usize, |this| ((**(this as *const *const IUpdate_Vtbl)).IsHidden)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsHidden(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "SetIsHidden", 25usize, |this| ((**(this as *const *const IUpdate_Vtbl)).SetIsHidden)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInstalled(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "IsInstalled", 26usize, |this| ((**(this as *const *const IUpdate_Vtbl)).IsInstalled)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsMandatory(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "IsMandatory", 27usize, |this| ((**(this as *const *const IUpdate_Vtbl)).IsMandatory)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUninstallable(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "IsUninstallable", 28usize, |this| ((**(this as *const *const IUpdate_Vtbl)).IsUninstallable)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Languages(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "Languages", 29usize, |this| ((**(this as *const *const IUpdate_Vtbl)).Languages)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastDeploymentChangeTime(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "LastDeploymentChangeTime", 30usize, |this| ((**(this as *const *const IUpdate_Vtbl)).LastDeploymentChangeTime)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxDownloadSize(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::DECIMAL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "MaxDownloadSize", 31usize, |this| ((**(this as *const *const IUpdate_Vtbl)).MaxDownloadSize)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinDownloadSize(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::DECIMAL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "MinDownloadSize", 32usize, |this| ((**(this as *const *const IUpdate_Vtbl)).MinDownloadSize)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn MoreInfoUrls(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "MoreInfoUrls", 33usize, |this| ((**(this as *const *const IUpdate_Vtbl)).MoreInfoUrls)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn MsrcSeverity(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "MsrcSeverity", 34usize, |this| ((**(this as *const *const IUpdate_Vtbl)).MsrcSeverity)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecommendedCpuSpeed(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "RecommendedCpuSpeed", 35usize, |this| ((**(this as *const *const IUpdate_Vtbl)).RecommendedCpuSpeed)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecommendedHardDiskSpace(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "RecommendedHardDiskSpace", 36usize, |this| ((**(this as *const *const IUpdate_Vtbl)).RecommendedHardDiskSpace)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecommendedMemory(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "RecommendedMemory", 37usize, |this| ((**(this as *const *const IUpdate_Vtbl)).RecommendedMemory)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseNotes(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "ReleaseNotes", 38usize, |this| ((**(this as *const *const IUpdate_Vtbl)).ReleaseNotes)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SecurityBulletinIDs(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "SecurityBulletinIDs", 39usize, |this| ((**(this as *const *const IUpdate_Vtbl)).SecurityBulletinIDs)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SupersededUpdateIDs(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "SupersededUpdateIDs", 40usize, |this| ((**(this as *const *const IUpdate_Vtbl)).SupersededUpdateIDs)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportUrl(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "SupportUrl", 41usize, |this| ((**(this as *const *const IUpdate_Vtbl)).SupportUrl)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Type(this: *mut core::ffi::c_void, retval: *mut UpdateType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "Type", 42usize, |this| ((**(this as *const *const IUpdate_Vtbl)).Type)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn UninstallationNotes(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "UninstallationNotes", 43usize, |this| ((**(this as *const *const IUpdate_Vtbl)).UninstallationNotes)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn UninstallationBehavior(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "UninstallationBehavior", 44usize, |this| ((**(this as *const *const IUpdate_Vtbl)).UninstallationBehavior)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn UninstallationSteps(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "UninstallationSteps", 45usize, |this| ((**(this as *const *const IUpdate_Vtbl)).UninstallationSteps)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn KBArticleIDs(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "KBArticleIDs", 46usize, |this| ((**(this as *const *const IUpdate_Vtbl)).KBArticleIDs)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn AcceptEula(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "AcceptEula", 47usize, |this| ((**(this as *const *const IUpdate_Vtbl)).AcceptEula)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeploymentAction(this: *mut core::ffi::c_void, retval: *mut DeploymentAction) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "DeploymentAction", 48usize, |this| ((**(this as *const *const IUpdate_Vtbl)).DeploymentAction)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CopyFromCache(this: *mut core::ffi::c_void, path: core::mem::MaybeUninit<windows_core::BSTR>, toextractcabfiles: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "CopyFromCache", 49usize, |this| ((**(this as *const *const IUpdate_Vtbl)).CopyFromCache)(this, path, toextractcabfiles), |result| Some(*result))
+        }
+        unsafe extern "system" fn DownloadPriority(this: *mut core::ffi::c_void, retval: *mut DownloadPriority) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "DownloadPriority", 50usize, |this| ((**(this as *const *const IUpdate_Vtbl)).DownloadPriority)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DownloadContents(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate", "DownloadContents", 51usize, |this| ((**(this as *const *const IUpdate_Vtbl)).DownloadContents)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Title,
+            AutoSelectOnWebSites,
+            #[cfg(feature = "Win32_System_Com")]
+            BundledUpdates,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            BundledUpdates: 0,
+            CanRequireSource,
+            #[cfg(feature = "Win32_System_Com")]
+            Categories,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Categories: 0,
+            Deadline,
+            DeltaCompressedContentAvailable,
+            DeltaCompressedContentPreferred,
+            Description,
+            EulaAccepted,
+            EulaText,
+            HandlerID,
+            #[cfg(feature = "Win32_System_Com")]
+            Identity,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Identity: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Image,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Image: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            InstallationBehavior,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InstallationBehavior: 0,
+            IsBeta,
+            IsDownloaded,
+            IsHidden,
+            SetIsHidden,
+            IsInstalled,
+            IsMandatory,
+            IsUninstallable,
+            #[cfg(feature = "Win32_System_Com")]
+            Languages,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Languages: 0,
+            LastDeploymentChangeTime,
+            MaxDownloadSize,
+            MinDownloadSize,
+            #[cfg(feature = "Win32_System_Com")]
+            MoreInfoUrls,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            MoreInfoUrls: 0,
+            MsrcSeverity,
+            RecommendedCpuSpeed,
+            RecommendedHardDiskSpace,
+            RecommendedMemory,
+            ReleaseNotes,
+            #[cfg(feature = "Win32_System_Com")]
+            SecurityBulletinIDs,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SecurityBulletinIDs: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SupersededUpdateIDs,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SupersededUpdateIDs: 0,
+            SupportUrl,
+            Type,
+            UninstallationNotes,
+            #[cfg(feature = "Win32_System_Com")]
+            UninstallationBehavior,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            UninstallationBehavior: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            UninstallationSteps,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            UninstallationSteps: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            KBArticleIDs,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            KBArticleIDs: 0,
+            AcceptEula,
+            DeploymentAction,
+            CopyFromCache,
+            DownloadPriority,
+            #[cfg(feature = "Win32_System_Com")]
+            DownloadContents,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DownloadContents: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdate as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdate2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RebootRequired(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate2", "RebootRequired", 52usize, |this| ((**(this as *const *const IUpdate2_Vtbl)).RebootRequired)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPresent(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate2", "IsPresent", 53usize, |this| ((**(this as *const *const IUpdate2_Vtbl)).IsPresent)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CveIDs(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate2", "CveIDs", 54usize, |this| ((**(this as *const *const IUpdate2_Vtbl)).CveIDs)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CopyToCache(this: *mut core::ffi::c_void, pfiles: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate2", "CopyToCache", 55usize, |this| ((**(this as *const *const IUpdate2_Vtbl)).CopyToCache)(this, pfiles), |result| Some(*result))
+        }
+        Self {
+            base__: <IUpdate_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RebootRequired,
+            IsPresent,
+            #[cfg(feature = "Win32_System_Com")]
+            CveIDs,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CveIDs: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CopyToCache,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CopyToCache: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdate2 as windows_core::Interface>::IID || <IUpdate_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdate3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BrowseOnly(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate3", "BrowseOnly", 56usize, |this| ((**(this as *const *const IUpdate3_Vtbl)).BrowseOnly)(this, retval), |result| Some(*result))
+        }
+        Self { base__: <IUpdate2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BrowseOnly }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdate3 as windows_core::Interface>::IID || <IUpdate2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdate4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PerUser(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate4", "PerUser", 57usize, |this| ((**(this as *const *const IUpdate4_Vtbl)).PerUser)(this, retval), |result| Some(*result))
+        }
+        Self { base__: <IUpdate3_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PerUser }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdate4 as windows_core::Interface>::IID || <IUpdate3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdate5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AutoSelection(this: *mut core::ffi::c_void, retval: *mut AutoSelectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate5", "AutoSelection", 58usize, |this| ((**(this as *const *const IUpdate5_Vtbl)).AutoSelection)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoDownload(this: *mut core::ffi::c_void, retval: *mut AutoDownloadMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdate5", "AutoDownload", 59usize, |this| ((**(this as *const *const IUpdate5_Vtbl)).AutoDownload)(this, retval), |result| Some(*result))
+        }
+        Self { base__: <IUpdate4_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AutoSelection, AutoDownload }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdate5 as windows_core::Interface>::IID || <IUpdate4_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateCollection_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateCollection", "get_Item", 7usize, |this| ((**(this as *const *const IUpdateCollection_Vtbl)).get_Item)(this, index, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn put_Item(this: *mut core::ffi::c_void, index: i32, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateCollection", "put_Item", 8usize, |this| ((**(this as *const *const IUpdateCollection_Vtbl)).put_Item)(this, index, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateCollection", "_NewEnum", 9usize, |this| ((**(this as *const *const IUpdateCollection_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateCollection", "Count", 10usize, |this| ((**(this as *const *const IUpdateCollection_Vtbl)).Count)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadOnly(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateCollection", "ReadOnly", 11usize, |this| ((**(this as *const *const IUpdateCollection_Vtbl)).ReadOnly)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateCollection", "Add", 12usize, |this| ((**(this as *const *const IUpdateCollection_Vtbl)).Add)(this, value, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateCollection", "Clear", 13usize, |this| ((**(this as *const *const IUpdateCollection_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Copy(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateCollection", "Copy", 14usize, |this| ((**(this as *const *const IUpdateCollection_Vtbl)).Copy)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Insert(this: *mut core::ffi::c_void, index: i32, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateCollection", "Insert", 15usize, |this| ((**(this as *const *const IUpdateCollection_Vtbl)).Insert)(this, index, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAt(this: *mut core::ffi::c_void, index: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateCollection", "RemoveAt", 16usize, |this| ((**(this as *const *const IUpdateCollection_Vtbl)).RemoveAt)(this, index), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            put_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            put_Item: 0,
+            _NewEnum,
+            Count,
+            ReadOnly,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Clear,
+            #[cfg(feature = "Win32_System_Com")]
+            Copy,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Copy: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Insert,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Insert: 0,
+            RemoveAt,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateDownloadContent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DownloadUrl(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateDownloadContent", "DownloadUrl", 7usize, |this| ((**(this as *const *const IUpdateDownloadContent_Vtbl)).DownloadUrl)(this, retval), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DownloadUrl }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateDownloadContent as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateDownloadContent2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsDeltaCompressedContent(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateDownloadContent2", "IsDeltaCompressedContent", 8usize, |this| ((**(this as *const *const IUpdateDownloadContent2_Vtbl)).IsDeltaCompressedContent)(this, retval), |result| Some(*result))
+        }
+        Self { base__: <IUpdateDownloadContent_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsDeltaCompressedContent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateDownloadContent2 as windows_core::Interface>::IID || <IUpdateDownloadContent_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateDownloadContentCollection_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateDownloadContentCollection", "get_Item", 7usize, |this| ((**(this as *const *const IUpdateDownloadContentCollection_Vtbl)).get_Item)(this, index, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateDownloadContentCollection", "_NewEnum", 8usize, |this| ((**(this as *const *const IUpdateDownloadContentCollection_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateDownloadContentCollection", "Count", 9usize, |this| ((**(this as *const *const IUpdateDownloadContentCollection_Vtbl)).Count)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            _NewEnum,
+            Count,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateDownloadContentCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateDownloadResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HResult(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateDownloadResult", "HResult", 7usize, |this| ((**(this as *const *const IUpdateDownloadResult_Vtbl)).HResult)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResultCode(this: *mut core::ffi::c_void, retval: *mut OperationResultCode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateDownloadResult", "ResultCode", 8usize, |this| ((**(this as *const *const IUpdateDownloadResult_Vtbl)).ResultCode)(this, retval), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HResult, ResultCode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateDownloadResult as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateDownloader_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ClientApplicationID(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateDownloader", "ClientApplicationID", 7usize, |this| ((**(this as *const *const IUpdateDownloader_Vtbl)).ClientApplicationID)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClientApplicationID(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateDownloader", "SetClientApplicationID", 8usize, |this| ((**(this as *const *const IUpdateDownloader_Vtbl)).SetClientApplicationID)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsForced(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateDownloader", "IsForced", 9usize, |this| ((**(this as *const *const IUpdateDownloader_Vtbl)).IsForced)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsForced(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateDownloader", "SetIsForced", 10usize, |this| ((**(this as *const *const IUpdateDownloader_Vtbl)).SetIsForced)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn Priority(this: *mut core::ffi::c_void, retval: *mut DownloadPriority) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateDownloader", "Priority", 11usize, |this| ((**(this as *const *const IUpdateDownloader_Vtbl)).Priority)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPriority(this: *mut core::ffi::c_void, value: DownloadPriority) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateDownloader", "SetPriority", 12usize, |this| ((**(this as *const *const IUpdateDownloader_Vtbl)).SetPriority)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Updates(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateDownloader", "Updates", 13usize, |this| ((**(this as *const *const IUpdateDownloader_Vtbl)).Updates)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetUpdates(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateDownloader", "SetUpdates", 14usize, |this| ((**(this as *const *const IUpdateDownloader_Vtbl)).SetUpdates)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn BeginDownload(this: *mut core::ffi::c_void, onprogresschanged: *mut core::ffi::c_void, oncompleted: *mut core::ffi::c_void, state: core::mem::MaybeUninit<windows_core::VARIANT>, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateDownloader", "BeginDownload", 15usize, |this| ((**(this as *const *const IUpdateDownloader_Vtbl)).BeginDownload)(this, onprogresschanged, oncompleted, state, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Download(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateDownloader", "Download", 16usize, |this| ((**(this as *const *const IUpdateDownloader_Vtbl)).Download)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EndDownload(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateDownloader", "EndDownload", 17usize, |this| ((**(this as *const *const IUpdateDownloader_Vtbl)).EndDownload)(this, value, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ClientApplicationID,
+            SetClientApplicationID,
+            IsForced,
+            SetIsForced,
+            Priority,
+            SetPriority,
+            #[cfg(feature = "Win32_System_Com")]
+            Updates,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Updates: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetUpdates,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetUpdates: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            BeginDownload,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            BeginDownload: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Download,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Download: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EndDownload,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EndDownload: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateDownloader as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateException_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Message(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateException", "Message", 7usize, |this| ((**(this as *const *const IUpdateException_Vtbl)).Message)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn HResult(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateException", "HResult", 8usize, |this| ((**(this as *const *const IUpdateException_Vtbl)).HResult)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Context(this: *mut core::ffi::c_void, retval: *mut UpdateExceptionContext) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateException", "Context", 9usize, |this| ((**(this as *const *const IUpdateException_Vtbl)).Context)(this, retval), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Message, HResult, Context }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateException as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateExceptionCollection_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateExceptionCollection", "get_Item", 7usize, |this| ((**(this as *const *const IUpdateExceptionCollection_Vtbl)).get_Item)(this, index, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateExceptionCollection", "_NewEnum", 8usize, |this| ((**(this as *const *const IUpdateExceptionCollection_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateExceptionCollection", "Count", 9usize, |this| ((**(this as *const *const IUpdateExceptionCollection_Vtbl)).Count)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            _NewEnum,
+            Count,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateExceptionCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateHistoryEntry_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Operation(this: *mut core::ffi::c_void, retval: *mut UpdateOperation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateHistoryEntry", "Operation", 7usize, |this| ((**(this as *const *const IUpdateHistoryEntry_Vtbl)).Operation)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResultCode(this: *mut core::ffi::c_void, retval: *mut OperationResultCode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateHistoryEntry", "ResultCode", 8usize, |this| ((**(this as *const *const IUpdateHistoryEntry_Vtbl)).ResultCode)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn HResult(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateHistoryEntry", "HResult", 9usize, |this| ((**(this as *const *const IUpdateHistoryEntry_Vtbl)).HResult)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Date(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateHistoryEntry", "Date", 10usize, |this| ((**(this as *const *const IUpdateHistoryEntry_Vtbl)).Date)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn UpdateIdentity(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateHistoryEntry", "UpdateIdentity", 11usize, |this| ((**(this as *const *const IUpdateHistoryEntry_Vtbl)).UpdateIdentity)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Title(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateHistoryEntry", "Title", 12usize, |this| ((**(this as *const *const IUpdateHistoryEntry_Vtbl)).Title)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateHistoryEntry", "Description", 13usize, |this| ((**(this as *const *const IUpdateHistoryEntry_Vtbl)).Description)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnmappedResultCode(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateHistoryEntry", "UnmappedResultCode", 14usize, |this| ((**(this as *const *const IUpdateHistoryEntry_Vtbl)).UnmappedResultCode)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClientApplicationID(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateHistoryEntry", "ClientApplicationID", 15usize, |this| ((**(this as *const *const IUpdateHistoryEntry_Vtbl)).ClientApplicationID)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServerSelection(this: *mut core::ffi::c_void, retval: *mut ServerSelection) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateHistoryEntry", "ServerSelection", 16usize, |this| ((**(this as *const *const IUpdateHistoryEntry_Vtbl)).ServerSelection)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceID(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateHistoryEntry", "ServiceID", 17usize, |this| ((**(this as *const *const IUpdateHistoryEntry_Vtbl)).ServiceID)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn UninstallationSteps(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateHistoryEntry", "UninstallationSteps", 18usize, |this| ((**(this as *const *const IUpdateHistoryEntry_Vtbl)).UninstallationSteps)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn UninstallationNotes(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateHistoryEntry", "UninstallationNotes", 19usize, |this| ((**(this as *const *const IUpdateHistoryEntry_Vtbl)).UninstallationNotes)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportUrl(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateHistoryEntry", "SupportUrl", 20usize, |this| ((**(this as *const *const IUpdateHistoryEntry_Vtbl)).SupportUrl)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Operation,
+            ResultCode,
+            HResult,
+            Date,
+            #[cfg(feature = "Win32_System_Com")]
+            UpdateIdentity,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            UpdateIdentity: 0,
+            Title,
+            Description,
+            UnmappedResultCode,
+            ClientApplicationID,
+            ServerSelection,
+            ServiceID,
+            #[cfg(feature = "Win32_System_Com")]
+            UninstallationSteps,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            UninstallationSteps: 0,
+            UninstallationNotes,
+            SupportUrl,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateHistoryEntry as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateHistoryEntry2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Categories(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateHistoryEntry2", "Categories", 21usize, |this| ((**(this as *const *const IUpdateHistoryEntry2_Vtbl)).Categories)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <IUpdateHistoryEntry_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Categories,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Categories: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateHistoryEntry2 as windows_core::Interface>::IID || <IUpdateHistoryEntry_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateHistoryEntryCollection_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateHistoryEntryCollection", "get_Item", 7usize, |this| ((**(this as *const *const IUpdateHistoryEntryCollection_Vtbl)).get_Item)(this, index, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateHistoryEntryCollection", "_NewEnum", 8usize, |this| ((**(this as *const *const IUpdateHistoryEntryCollection_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateHistoryEntryCollection", "Count", 9usize, |this| ((**(this as *const *const IUpdateHistoryEntryCollection_Vtbl)).Count)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            _NewEnum,
+            Count,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateHistoryEntryCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateIdentity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RevisionNumber(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateIdentity", "RevisionNumber", 7usize, |this| ((**(this as *const *const IUpdateIdentity_Vtbl)).RevisionNumber)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateID(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateIdentity", "UpdateID", 8usize, |this| ((**(this as *const *const IUpdateIdentity_Vtbl)).UpdateID)(this, retval), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RevisionNumber, UpdateID }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateIdentity as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateInstallationResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HResult(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstallationResult", "HResult", 7usize, |this| ((**(this as *const *const IUpdateInstallationResult_Vtbl)).HResult)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RebootRequired(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstallationResult", "RebootRequired", 8usize, |this| ((**(this as *const *const IUpdateInstallationResult_Vtbl)).RebootRequired)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResultCode(this: *mut core::ffi::c_void, retval: *mut OperationResultCode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstallationResult", "ResultCode", 9usize, |this| ((**(this as *const *const IUpdateInstallationResult_Vtbl)).ResultCode)(this, retval), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HResult, RebootRequired, ResultCode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateInstallationResult as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateInstaller_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ClientApplicationID(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstaller", "ClientApplicationID", 7usize, |this| ((**(this as *const *const IUpdateInstaller_Vtbl)).ClientApplicationID)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClientApplicationID(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstaller", "SetClientApplicationID", 8usize, |this| ((**(this as *const *const IUpdateInstaller_Vtbl)).SetClientApplicationID)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsForced(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstaller", "IsForced", 9usize, |this| ((**(this as *const *const IUpdateInstaller_Vtbl)).IsForced)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsForced(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstaller", "SetIsForced", 10usize, |this| ((**(this as *const *const IUpdateInstaller_Vtbl)).SetIsForced)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParentHwnd(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstaller", "ParentHwnd", 11usize, |this| ((**(this as *const *const IUpdateInstaller_Vtbl)).ParentHwnd)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParentHwnd(this: *mut core::ffi::c_void, value: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstaller", "SetParentHwnd", 12usize, |this| ((**(this as *const *const IUpdateInstaller_Vtbl)).SetParentHwnd)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParentWindow(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstaller", "SetParentWindow", 13usize, |this| ((**(this as *const *const IUpdateInstaller_Vtbl)).SetParentWindow)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParentWindow(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstaller", "ParentWindow", 14usize, |this| ((**(this as *const *const IUpdateInstaller_Vtbl)).ParentWindow)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Updates(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstaller", "Updates", 15usize, |this| ((**(this as *const *const IUpdateInstaller_Vtbl)).Updates)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetUpdates(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstaller", "SetUpdates", 16usize, |this| ((**(this as *const *const IUpdateInstaller_Vtbl)).SetUpdates)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn BeginInstall(this: *mut core::ffi::c_void, onprogresschanged: *mut core::ffi::c_void, oncompleted: *mut core::ffi::c_void, state: core::mem::MaybeUninit<windows_core::VARIANT>, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstaller", "BeginInstall", 17usize, |this| ((**(this as *const *const IUpdateInstaller_Vtbl)).BeginInstall)(this, onprogresschanged, oncompleted, state, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn BeginUninstall(this: *mut core::ffi::c_void, onprogresschanged: *mut core::ffi::c_void, oncompleted: *mut core::ffi::c_void, state: core::mem::MaybeUninit<windows_core::VARIANT>, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstaller", "BeginUninstall", 18usize, |this| ((**(this as *const *const IUpdateInstaller_Vtbl)).BeginUninstall)(this, onprogresschanged, oncompleted, state, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EndInstall(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstaller", "EndInstall", 19usize, |this| ((**(this as *const *const IUpdateInstaller_Vtbl)).EndInstall)(this, value, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EndUninstall(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstaller", "EndUninstall", 20usize, |this| ((**(this as *const *const IUpdateInstaller_Vtbl)).EndUninstall)(this, value, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Install(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstaller", "Install", 21usize, |this| ((**(this as *const *const IUpdateInstaller_Vtbl)).Install)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RunWizard(this: *mut core::ffi::c_void, dialogtitle: core::mem::MaybeUninit<windows_core::BSTR>, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstaller", "RunWizard", 22usize, |this| ((**(this as *const *const IUpdateInstaller_Vtbl)).RunWizard)(this, dialogtitle, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsBusy(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstaller", "IsBusy", 23usize, |this| ((**(this as *const *const IUpdateInstaller_Vtbl)).IsBusy)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Uninstall(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstaller", "Uninstall", 24usize, |this| ((**(this as *const *const IUpdateInstaller_Vtbl)).Uninstall)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowSourcePrompts(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstaller", "AllowSourcePrompts", 25usize, |this| ((**(this as *const *const IUpdateInstaller_Vtbl)).AllowSourcePrompts)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowSourcePrompts(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstaller", "SetAllowSourcePrompts", 26usize, |this| ((**(this as *const *const IUpdateInstaller_Vtbl)).SetAllowSourcePrompts)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn RebootRequiredBeforeInstallation(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstaller", "RebootRequiredBeforeInstallation", 27usize, |this| ((**(this as *const *const IUpdateInstaller_Vtbl)).RebootRequiredBeforeInstallation)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ClientApplicationID,
+            SetClientApplicationID,
+            IsForced,
+            SetIsForced,
+            ParentHwnd,
+            SetParentHwnd,
+            SetParentWindow,
+            ParentWindow,
+            #[cfg(feature = "Win32_System_Com")]
+            Updates,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Updates: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetUpdates,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetUpdates: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            BeginInstall,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            BeginInstall: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            BeginUninstall,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            BeginUninstall: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EndInstall,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EndInstall: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EndUninstall,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EndUninstall: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Install,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Install: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            RunWizard,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RunWizard: 0,
+            IsBusy,
+            #[cfg(feature = "Win32_System_Com")]
+            Uninstall,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Uninstall: 0,
+            AllowSourcePrompts,
+            SetAllowSourcePrompts,
+            RebootRequiredBeforeInstallation,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateInstaller as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateInstaller2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ForceQuiet(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstaller2", "ForceQuiet", 28usize, |this| ((**(this as *const *const IUpdateInstaller2_Vtbl)).ForceQuiet)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetForceQuiet(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstaller2", "SetForceQuiet", 29usize, |this| ((**(this as *const *const IUpdateInstaller2_Vtbl)).SetForceQuiet)(this, value), |result| Some(*result))
+        }
+        Self { base__: <IUpdateInstaller_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ForceQuiet, SetForceQuiet }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateInstaller2 as windows_core::Interface>::IID || <IUpdateInstaller_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateInstaller3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AttemptCloseAppsIfNecessary(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstaller3", "AttemptCloseAppsIfNecessary", 30usize, |this| ((**(this as *const *const IUpdateInstaller3_Vtbl)).AttemptCloseAppsIfNecessary)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAttemptCloseAppsIfNecessary(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstaller3", "SetAttemptCloseAppsIfNecessary", 31usize, |this| ((**(this as *const *const IUpdateInstaller3_Vtbl)).SetAttemptCloseAppsIfNecessary)(this, value), |result| Some(*result))
+        }
+        Self { base__: <IUpdateInstaller2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AttemptCloseAppsIfNecessary, SetAttemptCloseAppsIfNecessary }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateInstaller3 as windows_core::Interface>::IID || <IUpdateInstaller2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateInstaller4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateInstaller4", "Commit", 32usize, |this| ((**(this as *const *const IUpdateInstaller4_Vtbl)).Commit)(this, dwflags), |result| Some(*result))
+        }
+        Self { base__: <IUpdateInstaller3_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Commit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateInstaller4 as windows_core::Interface>::IID || <IUpdateInstaller3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUpdateLockdown_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LockDown(this: *mut core::ffi::c_void, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateLockdown", "LockDown", 3usize, |this| ((**(this as *const *const IUpdateLockdown_Vtbl)).LockDown)(this, flags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LockDown }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateLockdown as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateSearcher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CanAutomaticallyUpgradeService(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSearcher", "CanAutomaticallyUpgradeService", 7usize, |this| ((**(this as *const *const IUpdateSearcher_Vtbl)).CanAutomaticallyUpgradeService)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanAutomaticallyUpgradeService(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSearcher", "SetCanAutomaticallyUpgradeService", 8usize, |this| ((**(this as *const *const IUpdateSearcher_Vtbl)).SetCanAutomaticallyUpgradeService)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClientApplicationID(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSearcher", "ClientApplicationID", 9usize, |this| ((**(this as *const *const IUpdateSearcher_Vtbl)).ClientApplicationID)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClientApplicationID(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSearcher", "SetClientApplicationID", 10usize, |this| ((**(this as *const *const IUpdateSearcher_Vtbl)).SetClientApplicationID)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn IncludePotentiallySupersededUpdates(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSearcher", "IncludePotentiallySupersededUpdates", 11usize, |this| ((**(this as *const *const IUpdateSearcher_Vtbl)).IncludePotentiallySupersededUpdates)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIncludePotentiallySupersededUpdates(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSearcher", "SetIncludePotentiallySupersededUpdates", 12usize, |this| ((**(this as *const *const IUpdateSearcher_Vtbl)).SetIncludePotentiallySupersededUpdates)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServerSelection(this: *mut core::ffi::c_void, retval: *mut ServerSelection) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSearcher", "ServerSelection", 13usize, |this| ((**(this as *const *const IUpdateSearcher_Vtbl)).ServerSelection)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServerSelection(this: *mut core::ffi::c_void, value: ServerSelection) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSearcher", "SetServerSelection", 14usize, |this| ((**(this as *const *const IUpdateSearcher_Vtbl)).SetServerSelection)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn BeginSearch(this: *mut core::ffi::c_void, criteria: core::mem::MaybeUninit<windows_core::BSTR>, oncompleted: *mut core::ffi::c_void, state: core::mem::MaybeUninit<windows_core::VARIANT>, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSearcher", "BeginSearch", 15usize, |this| ((**(this as *const *const IUpdateSearcher_Vtbl)).BeginSearch)(this, criteria, oncompleted, state, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EndSearch(this: *mut core::ffi::c_void, searchjob: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSearcher", "EndSearch", 16usize, |this| ((**(this as *const *const IUpdateSearcher_Vtbl)).EndSearch)(this, searchjob, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn EscapeString(this: *mut core::ffi::c_void, unescaped: core::mem::MaybeUninit<windows_core::BSTR>, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSearcher", "EscapeString", 17usize, |this| ((**(this as *const *const IUpdateSearcher_Vtbl)).EscapeString)(this, unescaped, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn QueryHistory(this: *mut core::ffi::c_void, startindex: i32, count: i32, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSearcher", "QueryHistory", 18usize, |this| ((**(this as *const *const IUpdateSearcher_Vtbl)).QueryHistory)(this, startindex, count, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Search(this: *mut core::ffi::c_void, criteria: core::mem::MaybeUninit<windows_core::BSTR>, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSearcher", "Search", 19usize, |this| ((**(this as *const *const IUpdateSearcher_Vtbl)).Search)(this, criteria, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Online(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSearcher", "Online", 20usize, |this| ((**(this as *const *const IUpdateSearcher_Vtbl)).Online)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOnline(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSearcher", "SetOnline", 21usize, |this| ((**(this as *const *const IUpdateSearcher_Vtbl)).SetOnline)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTotalHistoryCount(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSearcher", "GetTotalHistoryCount", 22usize, |this| ((**(this as *const *const IUpdateSearcher_Vtbl)).GetTotalHistoryCount)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceID(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSearcher", "ServiceID", 23usize, |this| ((**(this as *const *const IUpdateSearcher_Vtbl)).ServiceID)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServiceID(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSearcher", "SetServiceID", 24usize, |this| ((**(this as *const *const IUpdateSearcher_Vtbl)).SetServiceID)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CanAutomaticallyUpgradeService,
+            SetCanAutomaticallyUpgradeService,
+            ClientApplicationID,
+            SetClientApplicationID,
+            IncludePotentiallySupersededUpdates,
+            SetIncludePotentiallySupersededUpdates,
+            ServerSelection,
+            SetServerSelection,
+            #[cfg(feature = "Win32_System_Com")]
+            BeginSearch,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            BeginSearch: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EndSearch,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EndSearch: 0,
+            EscapeString,
+            #[cfg(feature = "Win32_System_Com")]
+            QueryHistory,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            QueryHistory: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Search,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Search: 0,
+            Online,
+            SetOnline,
+            GetTotalHistoryCount,
+            ServiceID,
+            SetServiceID,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateSearcher as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateSearcher2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IgnoreDownloadPriority(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSearcher2", "IgnoreDownloadPriority", 25usize, |this| ((**(this as *const *const IUpdateSearcher2_Vtbl)).IgnoreDownloadPriority)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIgnoreDownloadPriority(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSearcher2", "SetIgnoreDownloadPriority", 26usize, |this| ((**(this as *const *const IUpdateSearcher2_Vtbl)).SetIgnoreDownloadPriority)(this, value), |result| Some(*result))
+        }
+        Self { base__: <IUpdateSearcher_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IgnoreDownloadPriority, SetIgnoreDownloadPriority }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateSearcher2 as windows_core::Interface>::IID || <IUpdateSearcher_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateSearcher3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SearchScope(this: *mut core::ffi::c_void, retval: *mut SearchScope) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSearcher3", "SearchScope", 27usize, |this| ((**(this as *const *const IUpdateSearcher3_Vtbl)).SearchScope)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSearchScope(this: *mut core::ffi::c_void, value: SearchScope) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSearcher3", "SetSearchScope", 28usize, |this| ((**(this as *const *const IUpdateSearcher3_Vtbl)).SetSearchScope)(this, value), |result| Some(*result))
+        }
+        Self { base__: <IUpdateSearcher2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SearchScope, SetSearchScope }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateSearcher3 as windows_core::Interface>::IID || <IUpdateSearcher2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateService", "Name", 7usize, |this| ((**(this as *const *const IUpdateService_Vtbl)).Name)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentValidationCert(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateService", "ContentValidationCert", 8usize, |this| ((**(this as *const *const IUpdateService_Vtbl)).ContentValidationCert)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExpirationDate(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateService", "ExpirationDate", 9usize, |this| ((**(this as *const *const IUpdateService_Vtbl)).ExpirationDate)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsManaged(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateService", "IsManaged", 10usize, |this| ((**(this as *const *const IUpdateService_Vtbl)).IsManaged)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRegisteredWithAU(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateService", "IsRegisteredWithAU", 11usize, |this| ((**(this as *const *const IUpdateService_Vtbl)).IsRegisteredWithAU)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IssueDate(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateService", "IssueDate", 12usize, |this| ((**(this as *const *const IUpdateService_Vtbl)).IssueDate)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn OffersWindowsUpdates(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateService", "OffersWindowsUpdates", 13usize, |this| ((**(this as *const *const IUpdateService_Vtbl)).OffersWindowsUpdates)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RedirectUrls(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateService", "RedirectUrls", 14usize, |this| ((**(this as *const *const IUpdateService_Vtbl)).RedirectUrls)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceID(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateService", "ServiceID", 15usize, |this| ((**(this as *const *const IUpdateService_Vtbl)).ServiceID)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsScanPackageService(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateService", "IsScanPackageService", 16usize, |this| ((**(this as *const *const IUpdateService_Vtbl)).IsScanPackageService)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanRegisterWithAU(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateService", "CanRegisterWithAU", 17usize, |this| ((**(this as *const *const IUpdateService_Vtbl)).CanRegisterWithAU)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceUrl(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateService", "ServiceUrl", 18usize, |this| ((**(this as *const *const IUpdateService_Vtbl)).ServiceUrl)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetupPrefix(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateService", "SetupPrefix", 19usize, |this| ((**(this as *const *const IUpdateService_Vtbl)).SetupPrefix)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            ContentValidationCert,
+            ExpirationDate,
+            IsManaged,
+            IsRegisteredWithAU,
+            IssueDate,
+            OffersWindowsUpdates,
+            #[cfg(feature = "Win32_System_Com")]
+            RedirectUrls,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RedirectUrls: 0,
+            ServiceID,
+            IsScanPackageService,
+            CanRegisterWithAU,
+            ServiceUrl,
+            SetupPrefix,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateService as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateService2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsDefaultAUService(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateService2", "IsDefaultAUService", 20usize, |this| ((**(this as *const *const IUpdateService2_Vtbl)).IsDefaultAUService)(this, retval), |result| Some(*result))
+        }
+        Self { base__: <IUpdateService_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsDefaultAUService }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateService2 as windows_core::Interface>::IID || <IUpdateService_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateServiceCollection_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateServiceCollection", "get_Item", 7usize, |this| ((**(this as *const *const IUpdateServiceCollection_Vtbl)).get_Item)(this, index, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateServiceCollection", "_NewEnum", 8usize, |this| ((**(this as *const *const IUpdateServiceCollection_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateServiceCollection", "Count", 9usize, |this| ((**(this as *const *const IUpdateServiceCollection_Vtbl)).Count)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            _NewEnum,
+            Count,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateServiceCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateServiceManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Services(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateServiceManager", "Services", 7usize, |this| ((**(this as *const *const IUpdateServiceManager_Vtbl)).Services)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddService(this: *mut core::ffi::c_void, serviceid: core::mem::MaybeUninit<windows_core::BSTR>, authorizationcabpath: core::mem::MaybeUninit<windows_core::BSTR>, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateServiceManager", "AddService", 8usize, |this| ((**(this as *const *const IUpdateServiceManager_Vtbl)).AddService)(this, serviceid, authorizationcabpath, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterServiceWithAU(this: *mut core::ffi::c_void, serviceid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateServiceManager", "RegisterServiceWithAU", 9usize, |this| ((**(this as *const *const IUpdateServiceManager_Vtbl)).RegisterServiceWithAU)(this, serviceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveService(this: *mut core::ffi::c_void, serviceid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateServiceManager", "RemoveService", 10usize, |this| ((**(this as *const *const IUpdateServiceManager_Vtbl)).RemoveService)(this, serviceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterServiceWithAU(this: *mut core::ffi::c_void, serviceid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateServiceManager", "UnregisterServiceWithAU", 11usize, |this| ((**(this as *const *const IUpdateServiceManager_Vtbl)).UnregisterServiceWithAU)(this, serviceid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddScanPackageService(this: *mut core::ffi::c_void, servicename: core::mem::MaybeUninit<windows_core::BSTR>, scanfilelocation: core::mem::MaybeUninit<windows_core::BSTR>, flags: i32, ppservice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateServiceManager", "AddScanPackageService", 12usize, |this| ((**(this as *const *const IUpdateServiceManager_Vtbl)).AddScanPackageService)(this, servicename, scanfilelocation, flags, ppservice), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOption(this: *mut core::ffi::c_void, optionname: core::mem::MaybeUninit<windows_core::BSTR>, optionvalue: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateServiceManager", "SetOption", 13usize, |this| ((**(this as *const *const IUpdateServiceManager_Vtbl)).SetOption)(this, optionname, optionvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Services,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Services: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AddService,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddService: 0,
+            RegisterServiceWithAU,
+            RemoveService,
+            UnregisterServiceWithAU,
+            #[cfg(feature = "Win32_System_Com")]
+            AddScanPackageService,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddScanPackageService: 0,
+            SetOption,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateServiceManager as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateServiceManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ClientApplicationID(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateServiceManager2", "ClientApplicationID", 14usize, |this| ((**(this as *const *const IUpdateServiceManager2_Vtbl)).ClientApplicationID)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClientApplicationID(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateServiceManager2", "SetClientApplicationID", 15usize, |this| ((**(this as *const *const IUpdateServiceManager2_Vtbl)).SetClientApplicationID)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn QueryServiceRegistration(this: *mut core::ffi::c_void, serviceid: core::mem::MaybeUninit<windows_core::BSTR>, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateServiceManager2", "QueryServiceRegistration", 16usize, |this| ((**(this as *const *const IUpdateServiceManager2_Vtbl)).QueryServiceRegistration)(this, serviceid, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddService2(this: *mut core::ffi::c_void, serviceid: core::mem::MaybeUninit<windows_core::BSTR>, flags: i32, authorizationcabpath: core::mem::MaybeUninit<windows_core::BSTR>, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateServiceManager2", "AddService2", 17usize, |this| ((**(this as *const *const IUpdateServiceManager2_Vtbl)).AddService2)(this, serviceid, flags, authorizationcabpath, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <IUpdateServiceManager_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ClientApplicationID,
+            SetClientApplicationID,
+            #[cfg(feature = "Win32_System_Com")]
+            QueryServiceRegistration,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            QueryServiceRegistration: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AddService2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddService2: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateServiceManager2 as windows_core::Interface>::IID || <IUpdateServiceManager_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateServiceRegistration_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegistrationState(this: *mut core::ffi::c_void, retval: *mut UpdateServiceRegistrationState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateServiceRegistration", "RegistrationState", 7usize, |this| ((**(this as *const *const IUpdateServiceRegistration_Vtbl)).RegistrationState)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ServiceID(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateServiceRegistration", "ServiceID", 8usize, |this| ((**(this as *const *const IUpdateServiceRegistration_Vtbl)).ServiceID)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPendingRegistrationWithAU(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateServiceRegistration", "IsPendingRegistrationWithAU", 9usize, |this| ((**(this as *const *const IUpdateServiceRegistration_Vtbl)).IsPendingRegistrationWithAU)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Service(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateServiceRegistration", "Service", 10usize, |this| ((**(this as *const *const IUpdateServiceRegistration_Vtbl)).Service)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RegistrationState,
+            ServiceID,
+            IsPendingRegistrationWithAU,
+            #[cfg(feature = "Win32_System_Com")]
+            Service,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Service: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateServiceRegistration as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ClientApplicationID(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSession", "ClientApplicationID", 7usize, |this| ((**(this as *const *const IUpdateSession_Vtbl)).ClientApplicationID)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClientApplicationID(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSession", "SetClientApplicationID", 8usize, |this| ((**(this as *const *const IUpdateSession_Vtbl)).SetClientApplicationID)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadOnly(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSession", "ReadOnly", 9usize, |this| ((**(this as *const *const IUpdateSession_Vtbl)).ReadOnly)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn WebProxy(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSession", "WebProxy", 10usize, |this| ((**(this as *const *const IUpdateSession_Vtbl)).WebProxy)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetWebProxy(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSession", "SetWebProxy", 11usize, |this| ((**(this as *const *const IUpdateSession_Vtbl)).SetWebProxy)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateUpdateSearcher(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSession", "CreateUpdateSearcher", 12usize, |this| ((**(this as *const *const IUpdateSession_Vtbl)).CreateUpdateSearcher)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateUpdateDownloader(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSession", "CreateUpdateDownloader", 13usize, |this| ((**(this as *const *const IUpdateSession_Vtbl)).CreateUpdateDownloader)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateUpdateInstaller(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSession", "CreateUpdateInstaller", 14usize, |this| ((**(this as *const *const IUpdateSession_Vtbl)).CreateUpdateInstaller)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ClientApplicationID,
+            SetClientApplicationID,
+            ReadOnly,
+            #[cfg(feature = "Win32_System_Com")]
+            WebProxy,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            WebProxy: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetWebProxy,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetWebProxy: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateUpdateSearcher,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateUpdateSearcher: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateUpdateDownloader,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateUpdateDownloader: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateUpdateInstaller,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateUpdateInstaller: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateSession as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateSession2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UserLocale(this: *mut core::ffi::c_void, retval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSession2", "UserLocale", 15usize, |this| ((**(this as *const *const IUpdateSession2_Vtbl)).UserLocale)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUserLocale(this: *mut core::ffi::c_void, lcid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSession2", "SetUserLocale", 16usize, |this| ((**(this as *const *const IUpdateSession2_Vtbl)).SetUserLocale)(this, lcid), |result| Some(*result))
+        }
+        Self { base__: <IUpdateSession_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UserLocale, SetUserLocale }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateSession2 as windows_core::Interface>::IID || <IUpdateSession_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUpdateSession3_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateUpdateServiceManager(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSession3", "CreateUpdateServiceManager", 17usize, |this| ((**(this as *const *const IUpdateSession3_Vtbl)).CreateUpdateServiceManager)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn QueryHistory(this: *mut core::ffi::c_void, criteria: core::mem::MaybeUninit<windows_core::BSTR>, startindex: i32, count: i32, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUpdateSession3", "QueryHistory", 18usize, |this| ((**(this as *const *const IUpdateSession3_Vtbl)).QueryHistory)(this, criteria, startindex, count, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <IUpdateSession2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateUpdateServiceManager,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateUpdateServiceManager: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            QueryHistory,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            QueryHistory: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUpdateSession3 as windows_core::Interface>::IID || <IUpdateSession2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWebProxy_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Address(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProxy", "Address", 7usize, |this| ((**(this as *const *const IWebProxy_Vtbl)).Address)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAddress(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProxy", "SetAddress", 8usize, |this| ((**(this as *const *const IWebProxy_Vtbl)).SetAddress)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn BypassList(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProxy", "BypassList", 9usize, |this| ((**(this as *const *const IWebProxy_Vtbl)).BypassList)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetBypassList(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProxy", "SetBypassList", 10usize, |this| ((**(this as *const *const IWebProxy_Vtbl)).SetBypassList)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn BypassProxyOnLocal(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProxy", "BypassProxyOnLocal", 11usize, |this| ((**(this as *const *const IWebProxy_Vtbl)).BypassProxyOnLocal)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBypassProxyOnLocal(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProxy", "SetBypassProxyOnLocal", 12usize, |this| ((**(this as *const *const IWebProxy_Vtbl)).SetBypassProxyOnLocal)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadOnly(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProxy", "ReadOnly", 13usize, |this| ((**(this as *const *const IWebProxy_Vtbl)).ReadOnly)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProxy", "UserName", 14usize, |this| ((**(this as *const *const IWebProxy_Vtbl)).UserName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUserName(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProxy", "SetUserName", 15usize, |this| ((**(this as *const *const IWebProxy_Vtbl)).SetUserName)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPassword(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProxy", "SetPassword", 16usize, |this| ((**(this as *const *const IWebProxy_Vtbl)).SetPassword)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PromptForCredentials(this: *mut core::ffi::c_void, parentwindow: *mut core::ffi::c_void, title: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProxy", "PromptForCredentials", 17usize, |this| ((**(this as *const *const IWebProxy_Vtbl)).PromptForCredentials)(this, parentwindow, title), |result| Some(*result))
+        }
+        unsafe extern "system" fn PromptForCredentialsFromHwnd(this: *mut core::ffi::c_void, parentwindow: super::super::Foundation::HWND, title: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProxy", "PromptForCredentialsFromHwnd", 18usize, |this| ((**(this as *const *const IWebProxy_Vtbl)).PromptForCredentialsFromHwnd)(this, parentwindow, title), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoDetect(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProxy", "AutoDetect", 19usize, |this| ((**(this as *const *const IWebProxy_Vtbl)).AutoDetect)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoDetect(this: *mut core::ffi::c_void, value: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebProxy", "SetAutoDetect", 20usize, |this| ((**(this as *const *const IWebProxy_Vtbl)).SetAutoDetect)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Address,
+            SetAddress,
+            #[cfg(feature = "Win32_System_Com")]
+            BypassList,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            BypassList: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetBypassList,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetBypassList: 0,
+            BypassProxyOnLocal,
+            SetBypassProxyOnLocal,
+            ReadOnly,
+            UserName,
+            SetUserName,
+            SetPassword,
+            PromptForCredentials,
+            PromptForCredentialsFromHwnd,
+            AutoDetect,
+            SetAutoDetect,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebProxy as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWindowsDriverUpdate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DriverClass(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDriverUpdate", "DriverClass", 52usize, |this| ((**(this as *const *const IWindowsDriverUpdate_Vtbl)).DriverClass)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DriverHardwareID(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDriverUpdate", "DriverHardwareID", 53usize, |this| ((**(this as *const *const IWindowsDriverUpdate_Vtbl)).DriverHardwareID)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DriverManufacturer(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDriverUpdate", "DriverManufacturer", 54usize, |this| ((**(this as *const *const IWindowsDriverUpdate_Vtbl)).DriverManufacturer)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DriverModel(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDriverUpdate", "DriverModel", 55usize, |this| ((**(this as *const *const IWindowsDriverUpdate_Vtbl)).DriverModel)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DriverProvider(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDriverUpdate", "DriverProvider", 56usize, |this| ((**(this as *const *const IWindowsDriverUpdate_Vtbl)).DriverProvider)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DriverVerDate(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDriverUpdate", "DriverVerDate", 57usize, |this| ((**(this as *const *const IWindowsDriverUpdate_Vtbl)).DriverVerDate)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceProblemNumber(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDriverUpdate", "DeviceProblemNumber", 58usize, |this| ((**(this as *const *const IWindowsDriverUpdate_Vtbl)).DeviceProblemNumber)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceStatus(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDriverUpdate", "DeviceStatus", 59usize, |this| ((**(this as *const *const IWindowsDriverUpdate_Vtbl)).DeviceStatus)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <IUpdate_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DriverClass,
+            DriverHardwareID,
+            DriverManufacturer,
+            DriverModel,
+            DriverProvider,
+            DriverVerDate,
+            DeviceProblemNumber,
+            DeviceStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsDriverUpdate as windows_core::Interface>::IID || <IUpdate_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWindowsDriverUpdate2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RebootRequired(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDriverUpdate2", "RebootRequired", 60usize, |this| ((**(this as *const *const IWindowsDriverUpdate2_Vtbl)).RebootRequired)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPresent(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDriverUpdate2", "IsPresent", 61usize, |this| ((**(this as *const *const IWindowsDriverUpdate2_Vtbl)).IsPresent)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CveIDs(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDriverUpdate2", "CveIDs", 62usize, |this| ((**(this as *const *const IWindowsDriverUpdate2_Vtbl)).CveIDs)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CopyToCache(this: *mut core::ffi::c_void, pfiles: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDriverUpdate2", "CopyToCache", 63usize, |this| ((**(this as *const *const IWindowsDriverUpdate2_Vtbl)).CopyToCache)(this, pfiles), |result| Some(*result))
+        }
+        Self {
+            base__: <IWindowsDriverUpdate_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RebootRequired,
+            IsPresent,
+            #[cfg(feature = "Win32_System_Com")]
+            CveIDs,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CveIDs: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CopyToCache,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CopyToCache: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsDriverUpdate2 as windows_core::Interface>::IID || <IWindowsDriverUpdate_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWindowsDriverUpdate3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BrowseOnly(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDriverUpdate3", "BrowseOnly", 64usize, |this| ((**(this as *const *const IWindowsDriverUpdate3_Vtbl)).BrowseOnly)(this, retval), |result| Some(*result))
+        }
+        Self { base__: <IWindowsDriverUpdate2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BrowseOnly }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsDriverUpdate3 as windows_core::Interface>::IID || <IWindowsDriverUpdate2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWindowsDriverUpdate4_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn WindowsDriverUpdateEntries(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDriverUpdate4", "WindowsDriverUpdateEntries", 65usize, |this| ((**(this as *const *const IWindowsDriverUpdate4_Vtbl)).WindowsDriverUpdateEntries)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn PerUser(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDriverUpdate4", "PerUser", 66usize, |this| ((**(this as *const *const IWindowsDriverUpdate4_Vtbl)).PerUser)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <IWindowsDriverUpdate3_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            WindowsDriverUpdateEntries,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            WindowsDriverUpdateEntries: 0,
+            PerUser,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsDriverUpdate4 as windows_core::Interface>::IID || <IWindowsDriverUpdate3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWindowsDriverUpdate5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AutoSelection(this: *mut core::ffi::c_void, retval: *mut AutoSelectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDriverUpdate5", "AutoSelection", 67usize, |this| ((**(this as *const *const IWindowsDriverUpdate5_Vtbl)).AutoSelection)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoDownload(this: *mut core::ffi::c_void, retval: *mut AutoDownloadMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDriverUpdate5", "AutoDownload", 68usize, |this| ((**(this as *const *const IWindowsDriverUpdate5_Vtbl)).AutoDownload)(this, retval), |result| Some(*result))
+        }
+        Self { base__: <IWindowsDriverUpdate4_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AutoSelection, AutoDownload }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsDriverUpdate5 as windows_core::Interface>::IID || <IWindowsDriverUpdate4_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWindowsDriverUpdateEntry_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DriverClass(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDriverUpdateEntry", "DriverClass", 7usize, |this| ((**(this as *const *const IWindowsDriverUpdateEntry_Vtbl)).DriverClass)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DriverHardwareID(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDriverUpdateEntry", "DriverHardwareID", 8usize, |this| ((**(this as *const *const IWindowsDriverUpdateEntry_Vtbl)).DriverHardwareID)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DriverManufacturer(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDriverUpdateEntry", "DriverManufacturer", 9usize, |this| ((**(this as *const *const IWindowsDriverUpdateEntry_Vtbl)).DriverManufacturer)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DriverModel(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDriverUpdateEntry", "DriverModel", 10usize, |this| ((**(this as *const *const IWindowsDriverUpdateEntry_Vtbl)).DriverModel)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DriverProvider(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDriverUpdateEntry", "DriverProvider", 11usize, |this| ((**(this as *const *const IWindowsDriverUpdateEntry_Vtbl)).DriverProvider)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DriverVerDate(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDriverUpdateEntry", "DriverVerDate", 12usize, |this| ((**(this as *const *const IWindowsDriverUpdateEntry_Vtbl)).DriverVerDate)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceProblemNumber(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDriverUpdateEntry", "DeviceProblemNumber", 13usize, |this| ((**(this as *const *const IWindowsDriverUpdateEntry_Vtbl)).DeviceProblemNumber)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceStatus(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDriverUpdateEntry", "DeviceStatus", 14usize, |this| ((**(this as *const *const IWindowsDriverUpdateEntry_Vtbl)).DeviceStatus)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DriverClass,
+            DriverHardwareID,
+            DriverManufacturer,
+            DriverModel,
+            DriverProvider,
+            DriverVerDate,
+            DeviceProblemNumber,
+            DeviceStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsDriverUpdateEntry as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWindowsDriverUpdateEntryCollection_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_Item(this: *mut core::ffi::c_void, index: i32, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDriverUpdateEntryCollection", "get_Item", 7usize, |this| ((**(this as *const *const IWindowsDriverUpdateEntryCollection_Vtbl)).get_Item)(this, index, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDriverUpdateEntryCollection", "_NewEnum", 8usize, |this| ((**(this as *const *const IWindowsDriverUpdateEntryCollection_Vtbl))._NewEnum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDriverUpdateEntryCollection", "Count", 9usize, |this| ((**(this as *const *const IWindowsDriverUpdateEntryCollection_Vtbl)).Count)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            get_Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_Item: 0,
+            _NewEnum,
+            Count,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsDriverUpdateEntryCollection as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWindowsUpdateAgentInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInfo(this: *mut core::ffi::c_void, varinfoidentifier: core::mem::MaybeUninit<windows_core::VARIANT>, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsUpdateAgentInfo", "GetInfo", 7usize, |this| ((**(this as *const *const IWindowsUpdateAgentInfo_Vtbl)).GetInfo)(this, varinfoidentifier, retval), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsUpdateAgentInfo as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
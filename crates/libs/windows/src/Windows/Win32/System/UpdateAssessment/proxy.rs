@@ -0,0 +1,12 @@
+impl windows_core::imp::ProxyVtable for IWaaSAssessor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOSUpdateAssessment(this: *mut core::ffi::c_void, result: *mut OSUpdateAssessment) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWaaSAssessor", "GetOSUpdateAssessment", 3usize, |this| ((**(this as *const *const IWaaSAssessor_Vtbl)).GetOSUpdateAssessment)(this, result), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetOSUpdateAssessment }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWaaSAssessor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -28,7 +28,8 @@ pub unsafe fn UalStop(data: *const UAL_DATA_BLOB) -> windows_core::Result<()> {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Networking_WinSock")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UAL_DATA_BLOB {
     pub Size: u32,
     pub RoleGuid: windows_core::GUID,
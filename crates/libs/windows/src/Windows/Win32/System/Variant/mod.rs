@@ -548,6 +548,7 @@ pub struct DRAWPROGRESSFLAGS(pub i32);
 impl windows_core::TypeKind for DRAWPROGRESSFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DRAWPROGRESSFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DRAWPROGRESSFLAGS").field(&self.0).finish()
@@ -592,6 +593,7 @@ pub struct PSTIME_FLAGS(pub i32);
 impl windows_core::TypeKind for PSTIME_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PSTIME_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PSTIME_FLAGS").field(&self.0).finish()
@@ -636,6 +638,7 @@ pub struct VARENUM(pub u16);
 impl windows_core::TypeKind for VARENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VARENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VARENUM").field(&self.0).finish()
@@ -680,6 +683,7 @@ pub struct VAR_CHANGE_FLAGS(pub u16);
 impl windows_core::TypeKind for VAR_CHANGE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VAR_CHANGE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VAR_CHANGE_FLAGS").field(&self.0).finish()
@@ -718,3 +722,4 @@ impl core::ops::Not for VAR_CHANGE_FLAGS {
         Self(self.0.not())
     }
 }
+core::include!(core::concat!(core::env!("CARGO_MANIFEST_DIR"), "/src/includes/", "Win32/System/Variant/OleDate.rs"));
@@ -90,3 +90,5 @@ pub struct IWindowsDevicesAllJoynBusObjectInterop_Vtbl {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,54 @@
+impl windows_core::imp::ProxyVtable for IWindowsDevicesAllJoynBusAttachmentFactoryInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromWin32Handle(this: *mut core::ffi::c_void, win32handle: u64, enableaboutdata: u8, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDevicesAllJoynBusAttachmentFactoryInterop", "CreateFromWin32Handle", 6usize, |this| ((**(this as *const *const IWindowsDevicesAllJoynBusAttachmentFactoryInterop_Vtbl)).CreateFromWin32Handle)(this, win32handle, enableaboutdata, riid, ppv), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromWin32Handle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsDevicesAllJoynBusAttachmentFactoryInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowsDevicesAllJoynBusAttachmentInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Win32Handle(this: *mut core::ffi::c_void, value: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDevicesAllJoynBusAttachmentInterop", "Win32Handle", 6usize, |this| ((**(this as *const *const IWindowsDevicesAllJoynBusAttachmentInterop_Vtbl)).Win32Handle)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Win32Handle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsDevicesAllJoynBusAttachmentInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowsDevicesAllJoynBusObjectFactoryInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateFromWin32Handle(this: *mut core::ffi::c_void, win32handle: u64, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDevicesAllJoynBusObjectFactoryInterop", "CreateFromWin32Handle", 6usize, |this| ((**(this as *const *const IWindowsDevicesAllJoynBusObjectFactoryInterop_Vtbl)).CreateFromWin32Handle)(this, win32handle, riid, ppv), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateFromWin32Handle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsDevicesAllJoynBusObjectFactoryInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowsDevicesAllJoynBusObjectInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddPropertyGetHandler(this: *mut core::ffi::c_void, context: *const core::ffi::c_void, interfacename: core::mem::MaybeUninit<windows_core::HSTRING>, callback: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDevicesAllJoynBusObjectInterop", "AddPropertyGetHandler", 6usize, |this| ((**(this as *const *const IWindowsDevicesAllJoynBusObjectInterop_Vtbl)).AddPropertyGetHandler)(this, context, interfacename, callback), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPropertySetHandler(this: *mut core::ffi::c_void, context: *const core::ffi::c_void, interfacename: core::mem::MaybeUninit<windows_core::HSTRING>, callback: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDevicesAllJoynBusObjectInterop", "AddPropertySetHandler", 7usize, |this| ((**(this as *const *const IWindowsDevicesAllJoynBusObjectInterop_Vtbl)).AddPropertySetHandler)(this, context, interfacename, callback), |result| Some(*result))
+        }
+        unsafe extern "system" fn Win32Handle(this: *mut core::ffi::c_void, value: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsDevicesAllJoynBusObjectInterop", "Win32Handle", 8usize, |this| ((**(this as *const *const IWindowsDevicesAllJoynBusObjectInterop_Vtbl)).Win32Handle)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddPropertyGetHandler, AddPropertySetHandler, Win32Handle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsDevicesAllJoynBusObjectInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -289,3 +289,5 @@ pub struct IVisualInteractionSourceInterop_Vtbl {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,202 @@
+#[cfg(feature = "UI_Composition")]
+impl windows_core::imp::ProxyVtable for ICompositionCapabilitiesInteropFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_Composition")]
+        unsafe extern "system" fn GetForWindow(this: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND, result: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionCapabilitiesInteropFactory", "GetForWindow", 6usize, |this| ((**(this as *const *const ICompositionCapabilitiesInteropFactory_Vtbl)).GetForWindow)(this, hwnd, result), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_Composition")]
+            GetForWindow,
+            #[cfg(not(feature = "UI_Composition"))]
+            GetForWindow: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionCapabilitiesInteropFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionDrawingSurfaceInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BeginDraw(this: *mut core::ffi::c_void, updaterect: *const super::super::super::Foundation::RECT, iid: *const windows_core::GUID, updateobject: *mut *mut core::ffi::c_void, updateoffset: *mut super::super::super::Foundation::POINT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionDrawingSurfaceInterop", "BeginDraw", 3usize, |this| ((**(this as *const *const ICompositionDrawingSurfaceInterop_Vtbl)).BeginDraw)(this, updaterect, iid, updateobject, updateoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndDraw(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionDrawingSurfaceInterop", "EndDraw", 4usize, |this| ((**(this as *const *const ICompositionDrawingSurfaceInterop_Vtbl)).EndDraw)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resize(this: *mut core::ffi::c_void, sizepixels: super::super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionDrawingSurfaceInterop", "Resize", 5usize, |this| ((**(this as *const *const ICompositionDrawingSurfaceInterop_Vtbl)).Resize)(this, sizepixels), |result| Some(*result))
+        }
+        unsafe extern "system" fn Scroll(this: *mut core::ffi::c_void, scrollrect: *const super::super::super::Foundation::RECT, cliprect: *const super::super::super::Foundation::RECT, offsetx: i32, offsety: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionDrawingSurfaceInterop", "Scroll", 6usize, |this| ((**(this as *const *const ICompositionDrawingSurfaceInterop_Vtbl)).Scroll)(this, scrollrect, cliprect, offsetx, offsety), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResumeDraw(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionDrawingSurfaceInterop", "ResumeDraw", 7usize, |this| ((**(this as *const *const ICompositionDrawingSurfaceInterop_Vtbl)).ResumeDraw)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuspendDraw(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionDrawingSurfaceInterop", "SuspendDraw", 8usize, |this| ((**(this as *const *const ICompositionDrawingSurfaceInterop_Vtbl)).SuspendDraw)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BeginDraw, EndDraw, Resize, Scroll, ResumeDraw, SuspendDraw }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionDrawingSurfaceInterop as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionDrawingSurfaceInterop2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CopySurface(this: *mut core::ffi::c_void, destinationresource: *mut core::ffi::c_void, destinationoffsetx: i32, destinationoffsety: i32, sourcerectangle: *const super::super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionDrawingSurfaceInterop2", "CopySurface", 9usize, |this| ((**(this as *const *const ICompositionDrawingSurfaceInterop2_Vtbl)).CopySurface)(this, destinationresource, destinationoffsetx, destinationoffsety, sourcerectangle), |result| Some(*result))
+        }
+        Self { base__: <ICompositionDrawingSurfaceInterop_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CopySurface }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionDrawingSurfaceInterop2 as windows_core::Interface>::IID || <ICompositionDrawingSurfaceInterop_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionGraphicsDeviceInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRenderingDevice(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGraphicsDeviceInterop", "GetRenderingDevice", 3usize, |this| ((**(this as *const *const ICompositionGraphicsDeviceInterop_Vtbl)).GetRenderingDevice)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRenderingDevice(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionGraphicsDeviceInterop", "SetRenderingDevice", 4usize, |this| ((**(this as *const *const ICompositionGraphicsDeviceInterop_Vtbl)).SetRenderingDevice)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRenderingDevice, SetRenderingDevice }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionGraphicsDeviceInterop as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICompositionTextureInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAvailableFence(this: *mut core::ffi::c_void, fencevalue: *mut u64, iid: *const windows_core::GUID, availablefence: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositionTextureInterop", "GetAvailableFence", 3usize, |this| ((**(this as *const *const ICompositionTextureInterop_Vtbl)).GetAvailableFence)(this, fencevalue, iid, availablefence), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAvailableFence }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositionTextureInterop as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Composition_Desktop")]
+impl windows_core::imp::ProxyVtable for ICompositorDesktopInterop_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_Composition_Desktop")]
+        unsafe extern "system" fn CreateDesktopWindowTarget(this: *mut core::ffi::c_void, hwndtarget: super::super::super::Foundation::HWND, istopmost: super::super::super::Foundation::BOOL, result: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositorDesktopInterop", "CreateDesktopWindowTarget", 3usize, |this| ((**(this as *const *const ICompositorDesktopInterop_Vtbl)).CreateDesktopWindowTarget)(this, hwndtarget, istopmost, result), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnsureOnThread(this: *mut core::ffi::c_void, threadid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositorDesktopInterop", "EnsureOnThread", 4usize, |this| ((**(this as *const *const ICompositorDesktopInterop_Vtbl)).EnsureOnThread)(this, threadid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_Composition_Desktop")]
+            CreateDesktopWindowTarget,
+            #[cfg(not(feature = "UI_Composition_Desktop"))]
+            CreateDesktopWindowTarget: 0,
+            EnsureOnThread,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositorDesktopInterop as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Composition")]
+impl windows_core::imp::ProxyVtable for ICompositorInterop_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "UI_Composition")]
+        unsafe extern "system" fn CreateCompositionSurfaceForHandle(this: *mut core::ffi::c_void, swapchain: super::super::super::Foundation::HANDLE, result: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositorInterop", "CreateCompositionSurfaceForHandle", 3usize, |this| ((**(this as *const *const ICompositorInterop_Vtbl)).CreateCompositionSurfaceForHandle)(this, swapchain, result), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Composition")]
+        unsafe extern "system" fn CreateCompositionSurfaceForSwapChain(this: *mut core::ffi::c_void, swapchain: *mut core::ffi::c_void, result: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositorInterop", "CreateCompositionSurfaceForSwapChain", 4usize, |this| ((**(this as *const *const ICompositorInterop_Vtbl)).CreateCompositionSurfaceForSwapChain)(this, swapchain, result), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Composition")]
+        unsafe extern "system" fn CreateGraphicsDevice(this: *mut core::ffi::c_void, renderingdevice: *mut core::ffi::c_void, result: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositorInterop", "CreateGraphicsDevice", 5usize, |this| ((**(this as *const *const ICompositorInterop_Vtbl)).CreateGraphicsDevice)(this, renderingdevice, result), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "UI_Composition")]
+            CreateCompositionSurfaceForHandle,
+            #[cfg(not(feature = "UI_Composition"))]
+            CreateCompositionSurfaceForHandle: 0,
+            #[cfg(feature = "UI_Composition")]
+            CreateCompositionSurfaceForSwapChain,
+            #[cfg(not(feature = "UI_Composition"))]
+            CreateCompositionSurfaceForSwapChain: 0,
+            #[cfg(feature = "UI_Composition")]
+            CreateGraphicsDevice,
+            #[cfg(not(feature = "UI_Composition"))]
+            CreateGraphicsDevice: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositorInterop as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "UI_Composition")]
+impl windows_core::imp::ProxyVtable for ICompositorInterop2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CheckCompositionTextureSupport(this: *mut core::ffi::c_void, renderingdevice: *mut core::ffi::c_void, supportscompositiontextures: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositorInterop2", "CheckCompositionTextureSupport", 3usize, |this| ((**(this as *const *const ICompositorInterop2_Vtbl)).CheckCompositionTextureSupport)(this, renderingdevice, supportscompositiontextures), |result| Some(*result))
+        }
+        #[cfg(feature = "UI_Composition")]
+        unsafe extern "system" fn CreateCompositionTexture(this: *mut core::ffi::c_void, d3dtexture: *mut core::ffi::c_void, compositiontexture: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICompositorInterop2", "CreateCompositionTexture", 4usize, |this| ((**(this as *const *const ICompositorInterop2_Vtbl)).CreateCompositionTexture)(this, d3dtexture, compositiontexture), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CheckCompositionTextureSupport,
+            #[cfg(feature = "UI_Composition")]
+            CreateCompositionTexture,
+            #[cfg(not(feature = "UI_Composition"))]
+            CreateCompositionTexture: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICompositorInterop2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDesktopWindowTargetInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Hwnd(this: *mut core::ffi::c_void, value: *mut super::super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDesktopWindowTargetInterop", "Hwnd", 3usize, |this| ((**(this as *const *const IDesktopWindowTargetInterop_Vtbl)).Hwnd)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Hwnd }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDesktopWindowTargetInterop as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_UI_Input_Pointer", feature = "Win32_UI_WindowsAndMessaging"))]
+impl windows_core::imp::ProxyVtable for IVisualInteractionSourceInterop_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_UI_Input_Pointer", feature = "Win32_UI_WindowsAndMessaging"))]
+        unsafe extern "system" fn TryRedirectForManipulation(this: *mut core::ffi::c_void, pointerinfo: *const super::super::super::UI::Input::Pointer::POINTER_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVisualInteractionSourceInterop", "TryRedirectForManipulation", 3usize, |this| ((**(this as *const *const IVisualInteractionSourceInterop_Vtbl)).TryRedirectForManipulation)(this, pointerinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_UI_Input_Pointer", feature = "Win32_UI_WindowsAndMessaging"))]
+            TryRedirectForManipulation,
+            #[cfg(not(all(feature = "Win32_UI_Input_Pointer", feature = "Win32_UI_WindowsAndMessaging")))]
+            TryRedirectForManipulation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVisualInteractionSourceInterop as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
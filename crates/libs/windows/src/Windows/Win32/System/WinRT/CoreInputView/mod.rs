@@ -23,3 +23,5 @@ pub struct ICoreFrameworkInputViewInterop_Vtbl {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
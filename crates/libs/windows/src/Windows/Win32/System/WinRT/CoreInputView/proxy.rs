@@ -0,0 +1,12 @@
+impl windows_core::imp::ProxyVtable for ICoreFrameworkInputViewInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForWindow(this: *mut core::ffi::c_void, appwindow: super::super::super::Foundation::HWND, riid: *const windows_core::GUID, coreframeworkinputview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreFrameworkInputViewInterop", "GetForWindow", 6usize, |this| ((**(this as *const *const ICoreFrameworkInputViewInterop_Vtbl)).GetForWindow)(this, appwindow, riid, coreframeworkinputview), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForWindow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreFrameworkInputViewInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -42,3 +42,5 @@ pub struct IDirect3DDxgiInterfaceAccess_Vtbl {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,12 @@
+impl windows_core::imp::ProxyVtable for IDirect3DDxgiInterfaceAccess_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInterface(this: *mut core::ffi::c_void, iid: *const windows_core::GUID, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDirect3DDxgiInterfaceAccess", "GetInterface", 3usize, |this| ((**(this as *const *const IDirect3DDxgiInterfaceAccess_Vtbl)).GetInterface)(this, iid, p), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetInterface }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDirect3DDxgiInterfaceAccess as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -58,3 +58,5 @@ pub struct IDisplayPathInterop_Vtbl {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,39 @@
+#[cfg(feature = "Win32_Security")]
+impl windows_core::imp::ProxyVtable for IDisplayDeviceInterop_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Security")]
+        unsafe extern "system" fn CreateSharedHandle(this: *mut core::ffi::c_void, pobject: *mut core::ffi::c_void, psecurityattributes: *const super::super::super::Security::SECURITY_ATTRIBUTES, access: u32, name: core::mem::MaybeUninit<windows_core::HSTRING>, phandle: *mut super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayDeviceInterop", "CreateSharedHandle", 3usize, |this| ((**(this as *const *const IDisplayDeviceInterop_Vtbl)).CreateSharedHandle)(this, pobject, psecurityattributes, access, name, phandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenSharedHandle(this: *mut core::ffi::c_void, nthandle: super::super::super::Foundation::HANDLE, riid: windows_core::GUID, ppvobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayDeviceInterop", "OpenSharedHandle", 4usize, |this| ((**(this as *const *const IDisplayDeviceInterop_Vtbl)).OpenSharedHandle)(this, nthandle, riid, ppvobj), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Security")]
+            CreateSharedHandle,
+            #[cfg(not(feature = "Win32_Security"))]
+            CreateSharedHandle: 0,
+            OpenSharedHandle,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayDeviceInterop as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDisplayPathInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateSourcePresentationHandle(this: *mut core::ffi::c_void, pvalue: *mut super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPathInterop", "CreateSourcePresentationHandle", 3usize, |this| ((**(this as *const *const IDisplayPathInterop_Vtbl)).CreateSourcePresentationHandle)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourceId(this: *mut core::ffi::c_void, psourceid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDisplayPathInterop", "GetSourceId", 4usize, |this| ((**(this as *const *const IDisplayPathInterop_Vtbl)).GetSourceId)(this, psourceid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateSourcePresentationHandle, GetSourceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDisplayPathInterop as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -36,3 +36,5 @@ pub struct IGraphicsCaptureItemInterop_Vtbl {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
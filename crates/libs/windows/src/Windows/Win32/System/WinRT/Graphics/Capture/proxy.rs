@@ -0,0 +1,24 @@
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IGraphicsCaptureItemInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateForWindow(this: *mut core::ffi::c_void, window: super::super::super::super::Foundation::HWND, riid: *const windows_core::GUID, result: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsCaptureItemInterop", "CreateForWindow", 3usize, |this| ((**(this as *const *const IGraphicsCaptureItemInterop_Vtbl)).CreateForWindow)(this, window, riid, result), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn CreateForMonitor(this: *mut core::ffi::c_void, monitor: super::super::super::super::Graphics::Gdi::HMONITOR, riid: *const windows_core::GUID, result: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsCaptureItemInterop", "CreateForMonitor", 4usize, |this| ((**(this as *const *const IGraphicsCaptureItemInterop_Vtbl)).CreateForMonitor)(this, monitor, riid, result), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateForWindow,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            CreateForMonitor,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            CreateForMonitor: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGraphicsCaptureItemInterop as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
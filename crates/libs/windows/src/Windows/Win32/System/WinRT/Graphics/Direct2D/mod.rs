@@ -104,6 +104,7 @@ pub struct GRAPHICS_EFFECT_PROPERTY_MAPPING(pub i32);
 impl windows_core::TypeKind for GRAPHICS_EFFECT_PROPERTY_MAPPING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GRAPHICS_EFFECT_PROPERTY_MAPPING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GRAPHICS_EFFECT_PROPERTY_MAPPING").field(&self.0).finish()
@@ -111,3 +112,5 @@ impl core::fmt::Debug for GRAPHICS_EFFECT_PROPERTY_MAPPING {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,72 @@
+#[cfg(feature = "Win32_Graphics_Direct2D")]
+impl windows_core::imp::ProxyVtable for IGeometrySource2DInterop_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct2D")]
+        unsafe extern "system" fn GetGeometry(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeometrySource2DInterop", "GetGeometry", 3usize, |this| ((**(this as *const *const IGeometrySource2DInterop_Vtbl)).GetGeometry)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D")]
+        unsafe extern "system" fn TryGetGeometryUsingFactory(this: *mut core::ffi::c_void, factory: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGeometrySource2DInterop", "TryGetGeometryUsingFactory", 4usize, |this| ((**(this as *const *const IGeometrySource2DInterop_Vtbl)).TryGetGeometryUsingFactory)(this, factory, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct2D")]
+            GetGeometry,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D"))]
+            GetGeometry: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D")]
+            TryGetGeometryUsingFactory,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D"))]
+            TryGetGeometryUsingFactory: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGeometrySource2DInterop as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Foundation", feature = "Graphics_Effects"))]
+impl windows_core::imp::ProxyVtable for IGraphicsEffectD2D1Interop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEffectId(this: *mut core::ffi::c_void, id: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsEffectD2D1Interop", "GetEffectId", 3usize, |this| ((**(this as *const *const IGraphicsEffectD2D1Interop_Vtbl)).GetEffectId)(this, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNamedPropertyMapping(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, index: *mut u32, mapping: *mut GRAPHICS_EFFECT_PROPERTY_MAPPING) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsEffectD2D1Interop", "GetNamedPropertyMapping", 4usize, |this| ((**(this as *const *const IGraphicsEffectD2D1Interop_Vtbl)).GetNamedPropertyMapping)(this, name, index, mapping), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyCount(this: *mut core::ffi::c_void, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsEffectD2D1Interop", "GetPropertyCount", 5usize, |this| ((**(this as *const *const IGraphicsEffectD2D1Interop_Vtbl)).GetPropertyCount)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Foundation")]
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, index: u32, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsEffectD2D1Interop", "GetProperty", 6usize, |this| ((**(this as *const *const IGraphicsEffectD2D1Interop_Vtbl)).GetProperty)(this, index, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Graphics_Effects")]
+        unsafe extern "system" fn GetSource(this: *mut core::ffi::c_void, index: u32, source: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsEffectD2D1Interop", "GetSource", 7usize, |this| ((**(this as *const *const IGraphicsEffectD2D1Interop_Vtbl)).GetSource)(this, index, source), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourceCount(this: *mut core::ffi::c_void, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGraphicsEffectD2D1Interop", "GetSourceCount", 8usize, |this| ((**(this as *const *const IGraphicsEffectD2D1Interop_Vtbl)).GetSourceCount)(this, count), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetEffectId,
+            GetNamedPropertyMapping,
+            GetPropertyCount,
+            #[cfg(feature = "Foundation")]
+            GetProperty,
+            #[cfg(not(feature = "Foundation"))]
+            GetProperty: 0,
+            #[cfg(feature = "Graphics_Effects")]
+            GetSource,
+            #[cfg(not(feature = "Graphics_Effects"))]
+            GetSource: 0,
+            GetSourceCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGraphicsEffectD2D1Interop as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
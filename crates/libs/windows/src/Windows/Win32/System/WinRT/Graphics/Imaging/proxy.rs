@@ -0,0 +1,40 @@
+impl windows_core::imp::ProxyVtable for ISoftwareBitmapNative_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetData(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftwareBitmapNative", "GetData", 6usize, |this| ((**(this as *const *const ISoftwareBitmapNative_Vtbl)).GetData)(this, riid, ppv), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISoftwareBitmapNative as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Imaging", feature = "Win32_Media_MediaFoundation"))]
+impl windows_core::imp::ProxyVtable for ISoftwareBitmapNativeFactory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Imaging")]
+        unsafe extern "system" fn CreateFromWICBitmap(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void, forcereadonly: super::super::super::super::Foundation::BOOL, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftwareBitmapNativeFactory", "CreateFromWICBitmap", 6usize, |this| ((**(this as *const *const ISoftwareBitmapNativeFactory_Vtbl)).CreateFromWICBitmap)(this, data, forcereadonly, riid, ppv), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Media_MediaFoundation")]
+        unsafe extern "system" fn CreateFromMF2DBuffer2(this: *mut core::ffi::c_void, data: *mut core::ffi::c_void, subtype: *const windows_core::GUID, width: u32, height: u32, forcereadonly: super::super::super::super::Foundation::BOOL, mindisplayaperture: *const super::super::super::super::Media::MediaFoundation::MFVideoArea, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISoftwareBitmapNativeFactory", "CreateFromMF2DBuffer2", 7usize, |this| ((**(this as *const *const ISoftwareBitmapNativeFactory_Vtbl)).CreateFromMF2DBuffer2)(this, data, subtype, width, height, forcereadonly, mindisplayaperture, riid, ppv), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Imaging")]
+            CreateFromWICBitmap,
+            #[cfg(not(feature = "Win32_Graphics_Imaging"))]
+            CreateFromWICBitmap: 0,
+            #[cfg(feature = "Win32_Media_MediaFoundation")]
+            CreateFromMF2DBuffer2,
+            #[cfg(not(feature = "Win32_Media_MediaFoundation"))]
+            CreateFromMF2DBuffer2: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISoftwareBitmapNativeFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -0,0 +1,152 @@
+#[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for IHolographicCameraInterop_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn CreateDirect3D12BackBufferResource(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, ptexture2ddesc: *const super::super::super::Graphics::Direct3D12::D3D12_RESOURCE_DESC, ppcreatedtexture2dresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraInterop", "CreateDirect3D12BackBufferResource", 6usize, |this| ((**(this as *const *const IHolographicCameraInterop_Vtbl)).CreateDirect3D12BackBufferResource)(this, pdevice, ptexture2ddesc, ppcreatedtexture2dresource), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn CreateDirect3D12HardwareProtectedBackBufferResource(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, ptexture2ddesc: *const super::super::super::Graphics::Direct3D12::D3D12_RESOURCE_DESC, pprotectedresourcesession: *mut core::ffi::c_void, ppcreatedtexture2dresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraInterop", "CreateDirect3D12HardwareProtectedBackBufferResource", 7usize, |this| ((**(this as *const *const IHolographicCameraInterop_Vtbl)).CreateDirect3D12HardwareProtectedBackBufferResource)(this, pdevice, ptexture2ddesc, pprotectedresourcesession, ppcreatedtexture2dresource), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn AcquireDirect3D12BufferResource(this: *mut core::ffi::c_void, presourcetoacquire: *mut core::ffi::c_void, pcommandqueue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraInterop", "AcquireDirect3D12BufferResource", 8usize, |this| ((**(this as *const *const IHolographicCameraInterop_Vtbl)).AcquireDirect3D12BufferResource)(this, presourcetoacquire, pcommandqueue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn AcquireDirect3D12BufferResourceWithTimeout(this: *mut core::ffi::c_void, presourcetoacquire: *mut core::ffi::c_void, pcommandqueue: *mut core::ffi::c_void, duration: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraInterop", "AcquireDirect3D12BufferResourceWithTimeout", 9usize, |this| ((**(this as *const *const IHolographicCameraInterop_Vtbl)).AcquireDirect3D12BufferResourceWithTimeout)(this, presourcetoacquire, pcommandqueue, duration), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn UnacquireDirect3D12BufferResource(this: *mut core::ffi::c_void, presourcetounacquire: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraInterop", "UnacquireDirect3D12BufferResource", 10usize, |this| ((**(this as *const *const IHolographicCameraInterop_Vtbl)).UnacquireDirect3D12BufferResource)(this, presourcetounacquire), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateDirect3D12BackBufferResource,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common")))]
+            CreateDirect3D12BackBufferResource: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateDirect3D12HardwareProtectedBackBufferResource,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common")))]
+            CreateDirect3D12HardwareProtectedBackBufferResource: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            AcquireDirect3D12BufferResource,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            AcquireDirect3D12BufferResource: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            AcquireDirect3D12BufferResourceWithTimeout,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            AcquireDirect3D12BufferResourceWithTimeout: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            UnacquireDirect3D12BufferResource,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            UnacquireDirect3D12BufferResource: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicCameraInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D12")]
+impl windows_core::imp::ProxyVtable for IHolographicCameraRenderingParametersInterop_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn CommitDirect3D12Resource(this: *mut core::ffi::c_void, pcolorresourcetocommit: *mut core::ffi::c_void, pcolorresourcefence: *mut core::ffi::c_void, colorresourcefencesignalvalue: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraRenderingParametersInterop", "CommitDirect3D12Resource", 6usize, |this| ((**(this as *const *const IHolographicCameraRenderingParametersInterop_Vtbl)).CommitDirect3D12Resource)(this, pcolorresourcetocommit, pcolorresourcefence, colorresourcefencesignalvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn CommitDirect3D12ResourceWithDepthData(this: *mut core::ffi::c_void, pcolorresourcetocommit: *mut core::ffi::c_void, pcolorresourcefence: *mut core::ffi::c_void, colorresourcefencesignalvalue: u64, pdepthresourcetocommit: *mut core::ffi::c_void, pdepthresourcefence: *mut core::ffi::c_void, depthresourcefencesignalvalue: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicCameraRenderingParametersInterop", "CommitDirect3D12ResourceWithDepthData", 7usize, |this| ((**(this as *const *const IHolographicCameraRenderingParametersInterop_Vtbl)).CommitDirect3D12ResourceWithDepthData)(this, pcolorresourcetocommit, pcolorresourcefence, colorresourcefencesignalvalue, pdepthresourcetocommit, pdepthresourcefence, depthresourcefencesignalvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            CommitDirect3D12Resource,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            CommitDirect3D12Resource: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            CommitDirect3D12ResourceWithDepthData,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            CommitDirect3D12ResourceWithDepthData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicCameraRenderingParametersInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+impl windows_core::imp::ProxyVtable for IHolographicQuadLayerInterop_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn CreateDirect3D12ContentBufferResource(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, ptexture2ddesc: *const super::super::super::Graphics::Direct3D12::D3D12_RESOURCE_DESC, pptexture2dresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicQuadLayerInterop", "CreateDirect3D12ContentBufferResource", 6usize, |this| ((**(this as *const *const IHolographicQuadLayerInterop_Vtbl)).CreateDirect3D12ContentBufferResource)(this, pdevice, ptexture2ddesc, pptexture2dresource), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+        unsafe extern "system" fn CreateDirect3D12HardwareProtectedContentBufferResource(this: *mut core::ffi::c_void, pdevice: *mut core::ffi::c_void, ptexture2ddesc: *const super::super::super::Graphics::Direct3D12::D3D12_RESOURCE_DESC, pprotectedresourcesession: *mut core::ffi::c_void, ppcreatedtexture2dresource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicQuadLayerInterop", "CreateDirect3D12HardwareProtectedContentBufferResource", 7usize, |this| ((**(this as *const *const IHolographicQuadLayerInterop_Vtbl)).CreateDirect3D12HardwareProtectedContentBufferResource)(this, pdevice, ptexture2ddesc, pprotectedresourcesession, ppcreatedtexture2dresource), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn AcquireDirect3D12BufferResource(this: *mut core::ffi::c_void, presourcetoacquire: *mut core::ffi::c_void, pcommandqueue: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicQuadLayerInterop", "AcquireDirect3D12BufferResource", 8usize, |this| ((**(this as *const *const IHolographicQuadLayerInterop_Vtbl)).AcquireDirect3D12BufferResource)(this, presourcetoacquire, pcommandqueue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn AcquireDirect3D12BufferResourceWithTimeout(this: *mut core::ffi::c_void, presourcetoacquire: *mut core::ffi::c_void, pcommandqueue: *mut core::ffi::c_void, duration: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicQuadLayerInterop", "AcquireDirect3D12BufferResourceWithTimeout", 9usize, |this| ((**(this as *const *const IHolographicQuadLayerInterop_Vtbl)).AcquireDirect3D12BufferResourceWithTimeout)(this, presourcetoacquire, pcommandqueue, duration), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn UnacquireDirect3D12BufferResource(this: *mut core::ffi::c_void, presourcetounacquire: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicQuadLayerInterop", "UnacquireDirect3D12BufferResource", 10usize, |this| ((**(this as *const *const IHolographicQuadLayerInterop_Vtbl)).UnacquireDirect3D12BufferResource)(this, presourcetounacquire), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateDirect3D12ContentBufferResource,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common")))]
+            CreateDirect3D12ContentBufferResource: 0,
+            #[cfg(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common"))]
+            CreateDirect3D12HardwareProtectedContentBufferResource,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct3D12", feature = "Win32_Graphics_Dxgi_Common")))]
+            CreateDirect3D12HardwareProtectedContentBufferResource: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            AcquireDirect3D12BufferResource,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            AcquireDirect3D12BufferResource: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            AcquireDirect3D12BufferResourceWithTimeout,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            AcquireDirect3D12BufferResourceWithTimeout: 0,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            UnacquireDirect3D12BufferResource,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            UnacquireDirect3D12BufferResource: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicQuadLayerInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D12")]
+impl windows_core::imp::ProxyVtable for IHolographicQuadLayerUpdateParametersInterop_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn CommitDirect3D12Resource(this: *mut core::ffi::c_void, pcolorresourcetocommit: *mut core::ffi::c_void, pcolorresourcefence: *mut core::ffi::c_void, colorresourcefencesignalvalue: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicQuadLayerUpdateParametersInterop", "CommitDirect3D12Resource", 6usize, |this| ((**(this as *const *const IHolographicQuadLayerUpdateParametersInterop_Vtbl)).CommitDirect3D12Resource)(this, pcolorresourcetocommit, pcolorresourcefence, colorresourcefencesignalvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            CommitDirect3D12Resource,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            CommitDirect3D12Resource: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicQuadLayerUpdateParametersInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -22,3 +22,5 @@ pub struct IIsolatedEnvironmentInterop_Vtbl {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
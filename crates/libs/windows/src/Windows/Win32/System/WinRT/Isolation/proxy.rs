@@ -0,0 +1,12 @@
+impl windows_core::imp::ProxyVtable for IIsolatedEnvironmentInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetHostHwndInterop(this: *mut core::ffi::c_void, containerhwnd: super::super::super::Foundation::HWND, hosthwnd: *mut super::super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIsolatedEnvironmentInterop", "GetHostHwndInterop", 3usize, |this| ((**(this as *const *const IIsolatedEnvironmentInterop_Vtbl)).GetHostHwndInterop)(this, containerhwnd, hosthwnd), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetHostHwndInterop }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIsolatedEnvironmentInterop as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -137,3 +137,5 @@ pub struct ITensorStaticsNative_Vtbl {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
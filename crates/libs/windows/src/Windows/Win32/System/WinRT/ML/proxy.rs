@@ -0,0 +1,108 @@
+#[cfg(feature = "Win32_Graphics_Direct3D12")]
+impl windows_core::imp::ProxyVtable for ILearningModelDeviceFactoryNative_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn CreateFromD3D12CommandQueue(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, result: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelDeviceFactoryNative", "CreateFromD3D12CommandQueue", 3usize, |this| ((**(this as *const *const ILearningModelDeviceFactoryNative_Vtbl)).CreateFromD3D12CommandQueue)(this, value, result), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            CreateFromD3D12CommandQueue,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            CreateFromD3D12CommandQueue: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILearningModelDeviceFactoryNative as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_AI_MachineLearning_WinML")]
+impl windows_core::imp::ProxyVtable for ILearningModelOperatorProviderNative_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_AI_MachineLearning_WinML")]
+        unsafe extern "system" fn GetRegistry(this: *mut core::ffi::c_void, ppoperatorregistry: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelOperatorProviderNative", "GetRegistry", 3usize, |this| ((**(this as *const *const ILearningModelOperatorProviderNative_Vtbl)).GetRegistry)(this, ppoperatorregistry), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_AI_MachineLearning_WinML")]
+            GetRegistry,
+            #[cfg(not(feature = "Win32_AI_MachineLearning_WinML"))]
+            GetRegistry: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILearningModelOperatorProviderNative as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILearningModelSessionOptionsNative_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetIntraOpNumThreadsOverride(this: *mut core::ffi::c_void, intraopnumthreads: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelSessionOptionsNative", "SetIntraOpNumThreadsOverride", 3usize, |this| ((**(this as *const *const ILearningModelSessionOptionsNative_Vtbl)).SetIntraOpNumThreadsOverride)(this, intraopnumthreads), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetIntraOpNumThreadsOverride }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILearningModelSessionOptionsNative as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILearningModelSessionOptionsNative1_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetIntraOpThreadSpinning(this: *mut core::ffi::c_void, allowspinning: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILearningModelSessionOptionsNative1", "SetIntraOpThreadSpinning", 3usize, |this| ((**(this as *const *const ILearningModelSessionOptionsNative1_Vtbl)).SetIntraOpThreadSpinning)(this, allowspinning), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetIntraOpThreadSpinning }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILearningModelSessionOptionsNative1 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D12")]
+impl windows_core::imp::ProxyVtable for ITensorNative_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBuffer(this: *mut core::ffi::c_void, value: *mut *mut u8, capacity: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorNative", "GetBuffer", 3usize, |this| ((**(this as *const *const ITensorNative_Vtbl)).GetBuffer)(this, value, capacity), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn GetD3D12Resource(this: *mut core::ffi::c_void, result: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorNative", "GetD3D12Resource", 4usize, |this| ((**(this as *const *const ITensorNative_Vtbl)).GetD3D12Resource)(this, result), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetBuffer,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            GetD3D12Resource,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            GetD3D12Resource: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorNative as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Direct3D12")]
+impl windows_core::imp::ProxyVtable for ITensorStaticsNative_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Direct3D12")]
+        unsafe extern "system" fn CreateFromD3D12Resource(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void, shape: *mut i64, shapecount: i32, result: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITensorStaticsNative", "CreateFromD3D12Resource", 3usize, |this| ((**(this as *const *const ITensorStaticsNative_Vtbl)).CreateFromD3D12Resource)(this, value, shape, shapecount, result), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Direct3D12")]
+            CreateFromD3D12Resource,
+            #[cfg(not(feature = "Win32_Graphics_Direct3D12"))]
+            CreateFromD3D12Resource: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITensorStaticsNative as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
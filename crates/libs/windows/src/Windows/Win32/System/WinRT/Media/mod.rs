@@ -111,3 +111,5 @@ pub const CLSID_AudioFrameNativeFactory: windows_core::GUID = windows_core::GUID
 pub const CLSID_VideoFrameNativeFactory: windows_core::GUID = windows_core::GUID::from_u128(0xd194386a_04e3_4814_8100_b2b0ae6d78c7);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
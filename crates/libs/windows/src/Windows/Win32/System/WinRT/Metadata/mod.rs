@@ -2213,6 +2213,7 @@ pub struct COINITICOR(pub i32);
 impl windows_core::TypeKind for COINITICOR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COINITICOR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COINITICOR").field(&self.0).finish()
@@ -2224,6 +2225,7 @@ pub struct COINITIEE(pub i32);
 impl windows_core::TypeKind for COINITIEE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COINITIEE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COINITIEE").field(&self.0).finish()
@@ -2235,6 +2237,7 @@ pub struct COUNINITIEE(pub i32);
 impl windows_core::TypeKind for COUNINITIEE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COUNINITIEE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COUNINITIEE").field(&self.0).finish()
@@ -2246,6 +2249,7 @@ pub struct CeeSectionAttr(pub i64);
 impl windows_core::TypeKind for CeeSectionAttr {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CeeSectionAttr {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CeeSectionAttr").field(&self.0).finish()
@@ -2257,6 +2261,7 @@ pub struct CeeSectionRelocType(pub i32);
 impl windows_core::TypeKind for CeeSectionRelocType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CeeSectionRelocType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CeeSectionRelocType").field(&self.0).finish()
@@ -2268,6 +2273,7 @@ pub struct CompilationRelaxationsEnum(pub i32);
 impl windows_core::TypeKind for CompilationRelaxationsEnum {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CompilationRelaxationsEnum {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CompilationRelaxationsEnum").field(&self.0).finish()
@@ -2279,6 +2285,7 @@ pub struct CorArgType(pub i32);
 impl windows_core::TypeKind for CorArgType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorArgType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorArgType").field(&self.0).finish()
@@ -2290,6 +2297,7 @@ pub struct CorAssemblyFlags(pub i32);
 impl windows_core::TypeKind for CorAssemblyFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorAssemblyFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorAssemblyFlags").field(&self.0).finish()
@@ -2301,6 +2309,7 @@ pub struct CorAttributeTargets(pub i32);
 impl windows_core::TypeKind for CorAttributeTargets {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorAttributeTargets {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorAttributeTargets").field(&self.0).finish()
@@ -2312,6 +2321,7 @@ pub struct CorCallingConvention(pub i32);
 impl windows_core::TypeKind for CorCallingConvention {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorCallingConvention {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorCallingConvention").field(&self.0).finish()
@@ -2323,6 +2333,7 @@ pub struct CorCheckDuplicatesFor(pub i32);
 impl windows_core::TypeKind for CorCheckDuplicatesFor {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorCheckDuplicatesFor {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorCheckDuplicatesFor").field(&self.0).finish()
@@ -2334,6 +2345,7 @@ pub struct CorDeclSecurity(pub i32);
 impl windows_core::TypeKind for CorDeclSecurity {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorDeclSecurity {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorDeclSecurity").field(&self.0).finish()
@@ -2345,6 +2357,7 @@ pub struct CorElementType(pub u8);
 impl windows_core::TypeKind for CorElementType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorElementType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorElementType").field(&self.0).finish()
@@ -2356,6 +2369,7 @@ pub struct CorErrorIfEmitOutOfOrder(pub i32);
 impl windows_core::TypeKind for CorErrorIfEmitOutOfOrder {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorErrorIfEmitOutOfOrder {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorErrorIfEmitOutOfOrder").field(&self.0).finish()
@@ -2367,6 +2381,7 @@ pub struct CorEventAttr(pub i32);
 impl windows_core::TypeKind for CorEventAttr {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorEventAttr {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorEventAttr").field(&self.0).finish()
@@ -2378,6 +2393,7 @@ pub struct CorExceptionFlag(pub i32);
 impl windows_core::TypeKind for CorExceptionFlag {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorExceptionFlag {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorExceptionFlag").field(&self.0).finish()
@@ -2389,6 +2405,7 @@ pub struct CorFieldAttr(pub i32);
 impl windows_core::TypeKind for CorFieldAttr {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorFieldAttr {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorFieldAttr").field(&self.0).finish()
@@ -2400,6 +2417,7 @@ pub struct CorFileFlags(pub i32);
 impl windows_core::TypeKind for CorFileFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorFileFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorFileFlags").field(&self.0).finish()
@@ -2411,6 +2429,7 @@ pub struct CorFileMapping(pub i32);
 impl windows_core::TypeKind for CorFileMapping {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorFileMapping {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorFileMapping").field(&self.0).finish()
@@ -2422,6 +2441,7 @@ pub struct CorGenericParamAttr(pub i32);
 impl windows_core::TypeKind for CorGenericParamAttr {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorGenericParamAttr {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorGenericParamAttr").field(&self.0).finish()
@@ -2433,6 +2453,7 @@ pub struct CorILMethodFlags(pub i32);
 impl windows_core::TypeKind for CorILMethodFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorILMethodFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorILMethodFlags").field(&self.0).finish()
@@ -2444,6 +2465,7 @@ pub struct CorILMethodSect(pub i32);
 impl windows_core::TypeKind for CorILMethodSect {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorILMethodSect {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorILMethodSect").field(&self.0).finish()
@@ -2455,6 +2477,7 @@ pub struct CorImportOptions(pub i32);
 impl windows_core::TypeKind for CorImportOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorImportOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorImportOptions").field(&self.0).finish()
@@ -2466,6 +2489,7 @@ pub struct CorLinkerOptions(pub i32);
 impl windows_core::TypeKind for CorLinkerOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorLinkerOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorLinkerOptions").field(&self.0).finish()
@@ -2477,6 +2501,7 @@ pub struct CorLocalRefPreservation(pub i32);
 impl windows_core::TypeKind for CorLocalRefPreservation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorLocalRefPreservation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorLocalRefPreservation").field(&self.0).finish()
@@ -2488,6 +2513,7 @@ pub struct CorManifestResourceFlags(pub i32);
 impl windows_core::TypeKind for CorManifestResourceFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorManifestResourceFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorManifestResourceFlags").field(&self.0).finish()
@@ -2499,6 +2525,7 @@ pub struct CorMethodAttr(pub i32);
 impl windows_core::TypeKind for CorMethodAttr {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorMethodAttr {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorMethodAttr").field(&self.0).finish()
@@ -2510,6 +2537,7 @@ pub struct CorMethodImpl(pub i32);
 impl windows_core::TypeKind for CorMethodImpl {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorMethodImpl {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorMethodImpl").field(&self.0).finish()
@@ -2521,6 +2549,7 @@ pub struct CorMethodSemanticsAttr(pub i32);
 impl windows_core::TypeKind for CorMethodSemanticsAttr {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorMethodSemanticsAttr {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorMethodSemanticsAttr").field(&self.0).finish()
@@ -2532,6 +2561,7 @@ pub struct CorNativeLinkFlags(pub i32);
 impl windows_core::TypeKind for CorNativeLinkFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorNativeLinkFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorNativeLinkFlags").field(&self.0).finish()
@@ -2543,6 +2573,7 @@ pub struct CorNativeLinkType(pub i32);
 impl windows_core::TypeKind for CorNativeLinkType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorNativeLinkType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorNativeLinkType").field(&self.0).finish()
@@ -2554,6 +2585,7 @@ pub struct CorNativeType(pub i32);
 impl windows_core::TypeKind for CorNativeType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorNativeType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorNativeType").field(&self.0).finish()
@@ -2565,6 +2597,7 @@ pub struct CorNotificationForTokenMovement(pub i32);
 impl windows_core::TypeKind for CorNotificationForTokenMovement {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorNotificationForTokenMovement {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorNotificationForTokenMovement").field(&self.0).finish()
@@ -2576,6 +2609,7 @@ pub struct CorOpenFlags(pub i32);
 impl windows_core::TypeKind for CorOpenFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorOpenFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorOpenFlags").field(&self.0).finish()
@@ -2587,6 +2621,7 @@ pub struct CorPEKind(pub i32);
 impl windows_core::TypeKind for CorPEKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorPEKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorPEKind").field(&self.0).finish()
@@ -2598,6 +2633,7 @@ pub struct CorParamAttr(pub i32);
 impl windows_core::TypeKind for CorParamAttr {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorParamAttr {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorParamAttr").field(&self.0).finish()
@@ -2609,6 +2645,7 @@ pub struct CorPinvokeMap(pub i32);
 impl windows_core::TypeKind for CorPinvokeMap {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorPinvokeMap {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorPinvokeMap").field(&self.0).finish()
@@ -2620,6 +2657,7 @@ pub struct CorPropertyAttr(pub i32);
 impl windows_core::TypeKind for CorPropertyAttr {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorPropertyAttr {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorPropertyAttr").field(&self.0).finish()
@@ -2631,6 +2669,7 @@ pub struct CorRefToDefCheck(pub i32);
 impl windows_core::TypeKind for CorRefToDefCheck {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorRefToDefCheck {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorRefToDefCheck").field(&self.0).finish()
@@ -2642,6 +2681,7 @@ pub struct CorRegFlags(pub i32);
 impl windows_core::TypeKind for CorRegFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorRegFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorRegFlags").field(&self.0).finish()
@@ -2653,6 +2693,7 @@ pub struct CorSaveSize(pub i32);
 impl windows_core::TypeKind for CorSaveSize {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorSaveSize {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorSaveSize").field(&self.0).finish()
@@ -2664,6 +2705,7 @@ pub struct CorSerializationType(pub i32);
 impl windows_core::TypeKind for CorSerializationType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorSerializationType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorSerializationType").field(&self.0).finish()
@@ -2675,6 +2717,7 @@ pub struct CorSetENC(pub i32);
 impl windows_core::TypeKind for CorSetENC {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorSetENC {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorSetENC").field(&self.0).finish()
@@ -2686,6 +2729,7 @@ pub struct CorThreadSafetyOptions(pub i32);
 impl windows_core::TypeKind for CorThreadSafetyOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorThreadSafetyOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorThreadSafetyOptions").field(&self.0).finish()
@@ -2697,6 +2741,7 @@ pub struct CorTokenType(pub i32);
 impl windows_core::TypeKind for CorTokenType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorTokenType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorTokenType").field(&self.0).finish()
@@ -2708,6 +2753,7 @@ pub struct CorTypeAttr(pub i32);
 impl windows_core::TypeKind for CorTypeAttr {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorTypeAttr {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorTypeAttr").field(&self.0).finish()
@@ -2719,6 +2765,7 @@ pub struct CorUnmanagedCallingConvention(pub i32);
 impl windows_core::TypeKind for CorUnmanagedCallingConvention {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorUnmanagedCallingConvention {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorUnmanagedCallingConvention").field(&self.0).finish()
@@ -2730,6 +2777,7 @@ pub struct CorValidatorModuleType(pub i32);
 impl windows_core::TypeKind for CorValidatorModuleType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorValidatorModuleType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorValidatorModuleType").field(&self.0).finish()
@@ -2741,6 +2789,7 @@ pub struct LoadHintEnum(pub i32);
 impl windows_core::TypeKind for LoadHintEnum {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LoadHintEnum {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LoadHintEnum").field(&self.0).finish()
@@ -2752,6 +2801,7 @@ pub struct MergeFlags(pub i32);
 impl windows_core::TypeKind for MergeFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MergeFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MergeFlags").field(&self.0).finish()
@@ -2763,6 +2813,7 @@ pub struct NGenHintEnum(pub i32);
 impl windows_core::TypeKind for NGenHintEnum {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NGenHintEnum {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NGenHintEnum").field(&self.0).finish()
@@ -2774,6 +2825,7 @@ pub struct NativeTypeArrayFlags(pub i32);
 impl windows_core::TypeKind for NativeTypeArrayFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NativeTypeArrayFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NativeTypeArrayFlags").field(&self.0).finish()
@@ -2785,13 +2837,15 @@ pub struct ReplacesGeneralNumericDefines(pub i32);
 impl windows_core::TypeKind for ReplacesGeneralNumericDefines {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ReplacesGeneralNumericDefines {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ReplacesGeneralNumericDefines").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ASSEMBLYMETADATA {
     pub usMajorVersion: u16,
     pub usMinorVersion: u16,
@@ -2813,7 +2867,8 @@ impl Default for ASSEMBLYMETADATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COR_FIELD_OFFSET {
     pub ridOfField: u32,
     pub ulOffset: u32,
@@ -2842,7 +2897,8 @@ impl Default for COR_NATIVE_LINK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COR_SECATTR {
     pub tkCtor: u32,
     pub pCustomAttribute: *const core::ffi::c_void,
@@ -2857,7 +2913,8 @@ impl Default for COR_SECATTR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CVStruct {
     pub Major: i16,
     pub Minor: i16,
@@ -2900,12 +2957,33 @@ impl Default for IMAGE_COR_ILMETHOD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_COR_ILMETHOD_FAT {
     pub _bitfield: u32,
     pub CodeSize: u32,
     pub LocalVarSigTok: u32,
 }
+impl IMAGE_COR_ILMETHOD_FAT {
+    pub const fn Flags(&self) -> u32 {
+        self._bitfield & 4095
+    }
+    pub fn set_Flags(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !4095) | (value & 4095);
+    }
+    pub const fn Size(&self) -> u32 {
+        (self._bitfield >> 12) & 15
+    }
+    pub fn set_Size(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(15 << 12)) | ((value & 15) << 12);
+    }
+    pub const fn MaxStack(&self) -> u32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_MaxStack(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for IMAGE_COR_ILMETHOD_FAT {
     type TypeKind = windows_core::CopyType;
 }
@@ -2969,6 +3047,39 @@ pub struct IMAGE_COR_ILMETHOD_SECT_EH_CLAUSE_SMALL {
     pub Anonymous: IMAGE_COR_ILMETHOD_SECT_EH_CLAUSE_SMALL_0,
 }
 #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec", target_arch = "x86_64"))]
+impl IMAGE_COR_ILMETHOD_SECT_EH_CLAUSE_SMALL {
+    pub const fn Flags(&self) -> u32 {
+        self._bitfield1 & 65535
+    }
+    pub fn set_Flags(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !65535) | (value & 65535);
+    }
+    pub const fn TryOffset(&self) -> u32 {
+        (self._bitfield1 >> 16) & 65535
+    }
+    pub fn set_TryOffset(&mut self, value: u32) {
+        self._bitfield1 = (self._bitfield1 & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+    pub const fn TryLength(&self) -> u32 {
+        self._bitfield2 & 255
+    }
+    pub fn set_TryLength(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !255) | (value & 255);
+    }
+    pub const fn HandlerOffset(&self) -> u32 {
+        (self._bitfield2 >> 8) & 65535
+    }
+    pub fn set_HandlerOffset(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !(65535 << 8)) | ((value & 65535) << 8);
+    }
+    pub const fn HandlerLength(&self) -> u32 {
+        (self._bitfield2 >> 24) & 255
+    }
+    pub fn set_HandlerLength(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
+#[cfg(any(target_arch = "aarch64", target_arch = "arm64ec", target_arch = "x86_64"))]
 impl windows_core::TypeKind for IMAGE_COR_ILMETHOD_SECT_EH_CLAUSE_SMALL {
     type TypeKind = windows_core::CopyType;
 }
@@ -3004,6 +3115,39 @@ pub struct IMAGE_COR_ILMETHOD_SECT_EH_CLAUSE_SMALL {
     pub Anonymous: IMAGE_COR_ILMETHOD_SECT_EH_CLAUSE_SMALL_0,
 }
 #[cfg(target_arch = "x86")]
+impl IMAGE_COR_ILMETHOD_SECT_EH_CLAUSE_SMALL {
+    pub const fn Flags(&self) -> i32 {
+        self._bitfield1 & 65535
+    }
+    pub fn set_Flags(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !65535) | (value & 65535);
+    }
+    pub const fn TryOffset(&self) -> i32 {
+        (self._bitfield1 >> 16) & 65535
+    }
+    pub fn set_TryOffset(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+    pub const fn TryLength(&self) -> u32 {
+        self._bitfield2 & 255
+    }
+    pub fn set_TryLength(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !255) | (value & 255);
+    }
+    pub const fn HandlerOffset(&self) -> u32 {
+        (self._bitfield2 >> 8) & 65535
+    }
+    pub fn set_HandlerOffset(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !(65535 << 8)) | ((value & 65535) << 8);
+    }
+    pub const fn HandlerLength(&self) -> u32 {
+        (self._bitfield2 >> 24) & 255
+    }
+    pub fn set_HandlerLength(&mut self, value: u32) {
+        self._bitfield2 = (self._bitfield2 & !(255 << 24)) | ((value & 255) << 24);
+    }
+}
+#[cfg(target_arch = "x86")]
 impl windows_core::TypeKind for IMAGE_COR_ILMETHOD_SECT_EH_CLAUSE_SMALL {
     type TypeKind = windows_core::CopyType;
 }
@@ -3060,10 +3204,25 @@ impl Default for IMAGE_COR_ILMETHOD_SECT_EH_SMALL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_COR_ILMETHOD_SECT_FAT {
     pub _bitfield: u32,
 }
+impl IMAGE_COR_ILMETHOD_SECT_FAT {
+    pub const fn Kind(&self) -> u32 {
+        self._bitfield & 255
+    }
+    pub fn set_Kind(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !255) | (value & 255);
+    }
+    pub const fn DataSize(&self) -> u32 {
+        (self._bitfield >> 8) & 16777215
+    }
+    pub fn set_DataSize(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(16777215 << 8)) | ((value & 16777215) << 8);
+    }
+}
 impl windows_core::TypeKind for IMAGE_COR_ILMETHOD_SECT_FAT {
     type TypeKind = windows_core::CopyType;
 }
@@ -3073,7 +3232,8 @@ impl Default for IMAGE_COR_ILMETHOD_SECT_FAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_COR_ILMETHOD_SECT_SMALL {
     pub Kind: u8,
     pub DataSize: u8,
@@ -3087,7 +3247,8 @@ impl Default for IMAGE_COR_ILMETHOD_SECT_SMALL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_COR_ILMETHOD_TINY {
     pub Flags_CodeSize: u8,
 }
@@ -3100,7 +3261,8 @@ impl Default for IMAGE_COR_ILMETHOD_TINY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGE_COR_VTABLEFIXUP {
     pub RVA: u32,
     pub Count: u16,
@@ -3115,7 +3277,8 @@ impl Default for IMAGE_COR_VTABLEFIXUP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OSINFO {
     pub dwOSPlatformId: u32,
     pub dwOSMajorVersion: u32,
@@ -3130,7 +3293,8 @@ impl Default for OSINFO {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ROPARAMIIDHANDLE(pub *mut core::ffi::c_void);
 impl ROPARAMIIDHANDLE {
     pub fn is_invalid(&self) -> bool {
@@ -3155,3 +3319,5 @@ impl windows_core::TypeKind for ROPARAMIIDHANDLE {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1004 @@
+impl windows_core::imp::ProxyVtable for ICeeGen_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EmitString(this: *mut core::ffi::c_void, lpstring: windows_core::PCWSTR, rva: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICeeGen", "EmitString", 3usize, |this| ((**(this as *const *const ICeeGen_Vtbl)).EmitString)(this, lpstring, rva), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetString(this: *mut core::ffi::c_void, rva: u32, lpstring: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICeeGen", "GetString", 4usize, |this| ((**(this as *const *const ICeeGen_Vtbl)).GetString)(this, rva, lpstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllocateMethodBuffer(this: *mut core::ffi::c_void, cchbuffer: u32, lpbuffer: *mut *mut u8, rva: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICeeGen", "AllocateMethodBuffer", 5usize, |this| ((**(this as *const *const ICeeGen_Vtbl)).AllocateMethodBuffer)(this, cchbuffer, lpbuffer, rva), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMethodBuffer(this: *mut core::ffi::c_void, rva: u32, lpbuffer: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICeeGen", "GetMethodBuffer", 6usize, |this| ((**(this as *const *const ICeeGen_Vtbl)).GetMethodBuffer)(this, rva, lpbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIMapTokenIface(this: *mut core::ffi::c_void, pimaptoken: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICeeGen", "GetIMapTokenIface", 7usize, |this| ((**(this as *const *const ICeeGen_Vtbl)).GetIMapTokenIface)(this, pimaptoken), |result| Some(*result))
+        }
+        unsafe extern "system" fn GenerateCeeFile(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICeeGen", "GenerateCeeFile", 8usize, |this| ((**(this as *const *const ICeeGen_Vtbl)).GenerateCeeFile)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIlSection(this: *mut core::ffi::c_void, section: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICeeGen", "GetIlSection", 9usize, |this| ((**(this as *const *const ICeeGen_Vtbl)).GetIlSection)(this, section), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStringSection(this: *mut core::ffi::c_void, section: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICeeGen", "GetStringSection", 10usize, |this| ((**(this as *const *const ICeeGen_Vtbl)).GetStringSection)(this, section), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddSectionReloc(this: *mut core::ffi::c_void, section: *mut core::ffi::c_void, offset: u32, relativeto: *mut core::ffi::c_void, reloctype: CeeSectionRelocType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICeeGen", "AddSectionReloc", 11usize, |this| ((**(this as *const *const ICeeGen_Vtbl)).AddSectionReloc)(this, section, offset, relativeto, reloctype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSectionCreate(this: *mut core::ffi::c_void, name: windows_core::PCSTR, flags: u32, section: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICeeGen", "GetSectionCreate", 12usize, |this| ((**(this as *const *const ICeeGen_Vtbl)).GetSectionCreate)(this, name, flags, section), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSectionDataLen(this: *mut core::ffi::c_void, section: *mut core::ffi::c_void, datalen: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICeeGen", "GetSectionDataLen", 13usize, |this| ((**(this as *const *const ICeeGen_Vtbl)).GetSectionDataLen)(this, section, datalen), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSectionBlock(this: *mut core::ffi::c_void, section: *mut core::ffi::c_void, len: u32, align: u32, ppbytes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICeeGen", "GetSectionBlock", 14usize, |this| ((**(this as *const *const ICeeGen_Vtbl)).GetSectionBlock)(this, section, len, align, ppbytes), |result| Some(*result))
+        }
+        unsafe extern "system" fn TruncateSection(this: *mut core::ffi::c_void, section: *mut core::ffi::c_void, len: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICeeGen", "TruncateSection", 15usize, |this| ((**(this as *const *const ICeeGen_Vtbl)).TruncateSection)(this, section, len), |result| Some(*result))
+        }
+        unsafe extern "system" fn GenerateCeeMemoryImage(this: *mut core::ffi::c_void, ppimage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICeeGen", "GenerateCeeMemoryImage", 16usize, |this| ((**(this as *const *const ICeeGen_Vtbl)).GenerateCeeMemoryImage)(this, ppimage), |result| Some(*result))
+        }
+        unsafe extern "system" fn ComputePointer(this: *mut core::ffi::c_void, section: *mut core::ffi::c_void, rva: u32, lpbuffer: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICeeGen", "ComputePointer", 17usize, |this| ((**(this as *const *const ICeeGen_Vtbl)).ComputePointer)(this, section, rva, lpbuffer), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EmitString,
+            GetString,
+            AllocateMethodBuffer,
+            GetMethodBuffer,
+            GetIMapTokenIface,
+            GenerateCeeFile,
+            GetIlSection,
+            GetStringSection,
+            AddSectionReloc,
+            GetSectionCreate,
+            GetSectionDataLen,
+            GetSectionBlock,
+            TruncateSection,
+            GenerateCeeMemoryImage,
+            ComputePointer,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICeeGen as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHostFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MarkToken(this: *mut core::ffi::c_void, tk: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHostFilter", "MarkToken", 3usize, |this| ((**(this as *const *const IHostFilter_Vtbl)).MarkToken)(this, tk), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MarkToken }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHostFilter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMapToken_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Map(this: *mut core::ffi::c_void, tkimp: u32, tkemit: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapToken", "Map", 3usize, |this| ((**(this as *const *const IMapToken_Vtbl)).Map)(this, tkimp, tkemit), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Map }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapToken as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMetaDataAssemblyEmit_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DefineAssembly(this: *mut core::ffi::c_void, pbpublickey: *const core::ffi::c_void, cbpublickey: u32, ulhashalgid: u32, szname: windows_core::PCWSTR, pmetadata: *const ASSEMBLYMETADATA, dwassemblyflags: u32, pma: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataAssemblyEmit", "DefineAssembly", 3usize, |this| ((**(this as *const *const IMetaDataAssemblyEmit_Vtbl)).DefineAssembly)(this, pbpublickey, cbpublickey, ulhashalgid, szname, pmetadata, dwassemblyflags, pma), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefineAssemblyRef(this: *mut core::ffi::c_void, pbpublickeyortoken: *const core::ffi::c_void, cbpublickeyortoken: u32, szname: windows_core::PCWSTR, pmetadata: *const ASSEMBLYMETADATA, pbhashvalue: *const core::ffi::c_void, cbhashvalue: u32, dwassemblyrefflags: u32, pmdar: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataAssemblyEmit", "DefineAssemblyRef", 4usize, |this| ((**(this as *const *const IMetaDataAssemblyEmit_Vtbl)).DefineAssemblyRef)(this, pbpublickeyortoken, cbpublickeyortoken, szname, pmetadata, pbhashvalue, cbhashvalue, dwassemblyrefflags, pmdar), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefineFile(this: *mut core::ffi::c_void, szname: windows_core::PCWSTR, pbhashvalue: *const core::ffi::c_void, cbhashvalue: u32, dwfileflags: u32, pmdf: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataAssemblyEmit", "DefineFile", 5usize, |this| ((**(this as *const *const IMetaDataAssemblyEmit_Vtbl)).DefineFile)(this, szname, pbhashvalue, cbhashvalue, dwfileflags, pmdf), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefineExportedType(this: *mut core::ffi::c_void, szname: windows_core::PCWSTR, tkimplementation: u32, tktypedef: u32, dwexportedtypeflags: u32, pmdct: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataAssemblyEmit", "DefineExportedType", 6usize, |this| ((**(this as *const *const IMetaDataAssemblyEmit_Vtbl)).DefineExportedType)(this, szname, tkimplementation, tktypedef, dwexportedtypeflags, pmdct), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefineManifestResource(this: *mut core::ffi::c_void, szname: windows_core::PCWSTR, tkimplementation: u32, dwoffset: u32, dwresourceflags: u32, pmdmr: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataAssemblyEmit", "DefineManifestResource", 7usize, |this| ((**(this as *const *const IMetaDataAssemblyEmit_Vtbl)).DefineManifestResource)(this, szname, tkimplementation, dwoffset, dwresourceflags, pmdmr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAssemblyProps(this: *mut core::ffi::c_void, pma: u32, pbpublickey: *const core::ffi::c_void, cbpublickey: u32, ulhashalgid: u32, szname: windows_core::PCWSTR, pmetadata: *const ASSEMBLYMETADATA, dwassemblyflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataAssemblyEmit", "SetAssemblyProps", 8usize, |this| ((**(this as *const *const IMetaDataAssemblyEmit_Vtbl)).SetAssemblyProps)(this, pma, pbpublickey, cbpublickey, ulhashalgid, szname, pmetadata, dwassemblyflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAssemblyRefProps(this: *mut core::ffi::c_void, ar: u32, pbpublickeyortoken: *const core::ffi::c_void, cbpublickeyortoken: u32, szname: windows_core::PCWSTR, pmetadata: *const ASSEMBLYMETADATA, pbhashvalue: *const core::ffi::c_void, cbhashvalue: u32, dwassemblyrefflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataAssemblyEmit", "SetAssemblyRefProps", 9usize, |this| ((**(this as *const *const IMetaDataAssemblyEmit_Vtbl)).SetAssemblyRefProps)(this, ar, pbpublickeyortoken, cbpublickeyortoken, szname, pmetadata, pbhashvalue, cbhashvalue, dwassemblyrefflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFileProps(this: *mut core::ffi::c_void, file: u32, pbhashvalue: *const core::ffi::c_void, cbhashvalue: u32, dwfileflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataAssemblyEmit", "SetFileProps", 10usize, |this| ((**(this as *const *const IMetaDataAssemblyEmit_Vtbl)).SetFileProps)(this, file, pbhashvalue, cbhashvalue, dwfileflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExportedTypeProps(this: *mut core::ffi::c_void, ct: u32, tkimplementation: u32, tktypedef: u32, dwexportedtypeflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataAssemblyEmit", "SetExportedTypeProps", 11usize, |this| ((**(this as *const *const IMetaDataAssemblyEmit_Vtbl)).SetExportedTypeProps)(this, ct, tkimplementation, tktypedef, dwexportedtypeflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetManifestResourceProps(this: *mut core::ffi::c_void, mr: u32, tkimplementation: u32, dwoffset: u32, dwresourceflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataAssemblyEmit", "SetManifestResourceProps", 12usize, |this| ((**(this as *const *const IMetaDataAssemblyEmit_Vtbl)).SetManifestResourceProps)(this, mr, tkimplementation, dwoffset, dwresourceflags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DefineAssembly,
+            DefineAssemblyRef,
+            DefineFile,
+            DefineExportedType,
+            DefineManifestResource,
+            SetAssemblyProps,
+            SetAssemblyRefProps,
+            SetFileProps,
+            SetExportedTypeProps,
+            SetManifestResourceProps,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMetaDataAssemblyEmit as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMetaDataAssemblyImport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAssemblyProps(this: *mut core::ffi::c_void, mda: u32, ppbpublickey: *const *const core::ffi::c_void, pcbpublickey: *mut u32, pulhashalgid: *mut u32, szname: windows_core::PWSTR, cchname: u32, pchname: *mut u32, pmetadata: *mut ASSEMBLYMETADATA, pdwassemblyflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataAssemblyImport", "GetAssemblyProps", 3usize, |this| ((**(this as *const *const IMetaDataAssemblyImport_Vtbl)).GetAssemblyProps)(this, mda, ppbpublickey, pcbpublickey, pulhashalgid, szname, cchname, pchname, pmetadata, pdwassemblyflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAssemblyRefProps(this: *mut core::ffi::c_void, mdar: u32, ppbpublickeyortoken: *const *const core::ffi::c_void, pcbpublickeyortoken: *mut u32, szname: windows_core::PWSTR, cchname: u32, pchname: *mut u32, pmetadata: *mut ASSEMBLYMETADATA, ppbhashvalue: *const *const core::ffi::c_void, pcbhashvalue: *mut u32, pdwassemblyrefflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataAssemblyImport", "GetAssemblyRefProps", 4usize, |this| ((**(this as *const *const IMetaDataAssemblyImport_Vtbl)).GetAssemblyRefProps)(this, mdar, ppbpublickeyortoken, pcbpublickeyortoken, szname, cchname, pchname, pmetadata, ppbhashvalue, pcbhashvalue, pdwassemblyrefflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileProps(this: *mut core::ffi::c_void, mdf: u32, szname: windows_core::PWSTR, cchname: u32, pchname: *mut u32, ppbhashvalue: *const *const core::ffi::c_void, pcbhashvalue: *mut u32, pdwfileflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataAssemblyImport", "GetFileProps", 5usize, |this| ((**(this as *const *const IMetaDataAssemblyImport_Vtbl)).GetFileProps)(this, mdf, szname, cchname, pchname, ppbhashvalue, pcbhashvalue, pdwfileflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExportedTypeProps(this: *mut core::ffi::c_void, mdct: u32, szname: windows_core::PWSTR, cchname: u32, pchname: *mut u32, ptkimplementation: *mut u32, ptktypedef: *mut u32, pdwexportedtypeflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataAssemblyImport", "GetExportedTypeProps", 6usize, |this| ((**(this as *const *const IMetaDataAssemblyImport_Vtbl)).GetExportedTypeProps)(this, mdct, szname, cchname, pchname, ptkimplementation, ptktypedef, pdwexportedtypeflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetManifestResourceProps(this: *mut core::ffi::c_void, mdmr: u32, szname: windows_core::PWSTR, cchname: u32, pchname: *mut u32, ptkimplementation: *mut u32, pdwoffset: *mut u32, pdwresourceflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataAssemblyImport", "GetManifestResourceProps", 7usize, |this| ((**(this as *const *const IMetaDataAssemblyImport_Vtbl)).GetManifestResourceProps)(this, mdmr, szname, cchname, pchname, ptkimplementation, pdwoffset, pdwresourceflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumAssemblyRefs(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, rassemblyrefs: *mut u32, cmax: u32, pctokens: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataAssemblyImport", "EnumAssemblyRefs", 8usize, |this| ((**(this as *const *const IMetaDataAssemblyImport_Vtbl)).EnumAssemblyRefs)(this, phenum, rassemblyrefs, cmax, pctokens), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumFiles(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, rfiles: *mut u32, cmax: u32, pctokens: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataAssemblyImport", "EnumFiles", 9usize, |this| ((**(this as *const *const IMetaDataAssemblyImport_Vtbl)).EnumFiles)(this, phenum, rfiles, cmax, pctokens), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumExportedTypes(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, rexportedtypes: *mut u32, cmax: u32, pctokens: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataAssemblyImport", "EnumExportedTypes", 10usize, |this| ((**(this as *const *const IMetaDataAssemblyImport_Vtbl)).EnumExportedTypes)(this, phenum, rexportedtypes, cmax, pctokens), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumManifestResources(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, rmanifestresources: *mut u32, cmax: u32, pctokens: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataAssemblyImport", "EnumManifestResources", 11usize, |this| ((**(this as *const *const IMetaDataAssemblyImport_Vtbl)).EnumManifestResources)(this, phenum, rmanifestresources, cmax, pctokens), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAssemblyFromScope(this: *mut core::ffi::c_void, ptkassembly: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataAssemblyImport", "GetAssemblyFromScope", 12usize, |this| ((**(this as *const *const IMetaDataAssemblyImport_Vtbl)).GetAssemblyFromScope)(this, ptkassembly), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindExportedTypeByName(this: *mut core::ffi::c_void, szname: windows_core::PCWSTR, mdtexportedtype: u32, ptkexportedtype: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataAssemblyImport", "FindExportedTypeByName", 13usize, |this| ((**(this as *const *const IMetaDataAssemblyImport_Vtbl)).FindExportedTypeByName)(this, szname, mdtexportedtype, ptkexportedtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindManifestResourceByName(this: *mut core::ffi::c_void, szname: windows_core::PCWSTR, ptkmanifestresource: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataAssemblyImport", "FindManifestResourceByName", 14usize, |this| ((**(this as *const *const IMetaDataAssemblyImport_Vtbl)).FindManifestResourceByName)(this, szname, ptkmanifestresource), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseEnum(this: *mut core::ffi::c_void, henum: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IMetaDataAssemblyImport", "CloseEnum", 15usize, |this| ((**(this as *const *const IMetaDataAssemblyImport_Vtbl)).CloseEnum)(this, henum), |_| None)
+        }
+        unsafe extern "system" fn FindAssembliesByName(this: *mut core::ffi::c_void, szappbase: windows_core::PCWSTR, szprivatebin: windows_core::PCWSTR, szassemblyname: windows_core::PCWSTR, ppiunk: *mut *mut core::ffi::c_void, cmax: u32, pcassemblies: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataAssemblyImport", "FindAssembliesByName", 16usize, |this| ((**(this as *const *const IMetaDataAssemblyImport_Vtbl)).FindAssembliesByName)(this, szappbase, szprivatebin, szassemblyname, ppiunk, cmax, pcassemblies), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetAssemblyProps,
+            GetAssemblyRefProps,
+            GetFileProps,
+            GetExportedTypeProps,
+            GetManifestResourceProps,
+            EnumAssemblyRefs,
+            EnumFiles,
+            EnumExportedTypes,
+            EnumManifestResources,
+            GetAssemblyFromScope,
+            FindExportedTypeByName,
+            FindManifestResourceByName,
+            CloseEnum,
+            FindAssembliesByName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMetaDataAssemblyImport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMetaDataDispenser_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DefineScope(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, dwcreateflags: u32, riid: *const windows_core::GUID, ppiunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataDispenser", "DefineScope", 3usize, |this| ((**(this as *const *const IMetaDataDispenser_Vtbl)).DefineScope)(this, rclsid, dwcreateflags, riid, ppiunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenScope(this: *mut core::ffi::c_void, szscope: windows_core::PCWSTR, dwopenflags: u32, riid: *const windows_core::GUID, ppiunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataDispenser", "OpenScope", 4usize, |this| ((**(this as *const *const IMetaDataDispenser_Vtbl)).OpenScope)(this, szscope, dwopenflags, riid, ppiunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenScopeOnMemory(this: *mut core::ffi::c_void, pdata: *const core::ffi::c_void, cbdata: u32, dwopenflags: u32, riid: *const windows_core::GUID, ppiunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataDispenser", "OpenScopeOnMemory", 5usize, |this| ((**(this as *const *const IMetaDataDispenser_Vtbl)).OpenScopeOnMemory)(this, pdata, cbdata, dwopenflags, riid, ppiunk), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DefineScope, OpenScope, OpenScopeOnMemory }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMetaDataDispenser as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMetaDataDispenserEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetOption(this: *mut core::ffi::c_void, optionid: *const windows_core::GUID, value: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataDispenserEx", "SetOption", 6usize, |this| ((**(this as *const *const IMetaDataDispenserEx_Vtbl)).SetOption)(this, optionid, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOption(this: *mut core::ffi::c_void, optionid: *const windows_core::GUID, pvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataDispenserEx", "GetOption", 7usize, |this| ((**(this as *const *const IMetaDataDispenserEx_Vtbl)).GetOption)(this, optionid, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OpenScopeOnITypeInfo(this: *mut core::ffi::c_void, piti: *mut core::ffi::c_void, dwopenflags: u32, riid: *const windows_core::GUID, ppiunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataDispenserEx", "OpenScopeOnITypeInfo", 8usize, |this| ((**(this as *const *const IMetaDataDispenserEx_Vtbl)).OpenScopeOnITypeInfo)(this, piti, dwopenflags, riid, ppiunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCORSystemDirectory(this: *mut core::ffi::c_void, szbuffer: windows_core::PWSTR, cchbuffer: u32, pchbuffer: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataDispenserEx", "GetCORSystemDirectory", 9usize, |this| ((**(this as *const *const IMetaDataDispenserEx_Vtbl)).GetCORSystemDirectory)(this, szbuffer, cchbuffer, pchbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindAssembly(this: *mut core::ffi::c_void, szappbase: windows_core::PCWSTR, szprivatebin: windows_core::PCWSTR, szglobalbin: windows_core::PCWSTR, szassemblyname: windows_core::PCWSTR, szname: windows_core::PCWSTR, cchname: u32, pcname: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataDispenserEx", "FindAssembly", 10usize, |this| ((**(this as *const *const IMetaDataDispenserEx_Vtbl)).FindAssembly)(this, szappbase, szprivatebin, szglobalbin, szassemblyname, szname, cchname, pcname), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindAssemblyModule(this: *mut core::ffi::c_void, szappbase: windows_core::PCWSTR, szprivatebin: windows_core::PCWSTR, szglobalbin: windows_core::PCWSTR, szassemblyname: windows_core::PCWSTR, szmodulename: windows_core::PCWSTR, szname: windows_core::PWSTR, cchname: u32, pcname: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataDispenserEx", "FindAssemblyModule", 11usize, |this| ((**(this as *const *const IMetaDataDispenserEx_Vtbl)).FindAssemblyModule)(this, szappbase, szprivatebin, szglobalbin, szassemblyname, szmodulename, szname, cchname, pcname), |result| Some(*result))
+        }
+        Self {
+            base__: <IMetaDataDispenser_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetOption,
+            GetOption,
+            #[cfg(feature = "Win32_System_Com")]
+            OpenScopeOnITypeInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OpenScopeOnITypeInfo: 0,
+            GetCORSystemDirectory,
+            FindAssembly,
+            FindAssemblyModule,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMetaDataDispenserEx as windows_core::Interface>::IID || <IMetaDataDispenser_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMetaDataEmit_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetModuleProps(this: *mut core::ffi::c_void, szname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "SetModuleProps", 3usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).SetModuleProps)(this, szname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void, szfile: windows_core::PCWSTR, dwsaveflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "Save", 4usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).Save)(this, szfile, dwsaveflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SaveToStream(this: *mut core::ffi::c_void, pistream: *mut core::ffi::c_void, dwsaveflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "SaveToStream", 5usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).SaveToStream)(this, pistream, dwsaveflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSaveSize(this: *mut core::ffi::c_void, fsave: CorSaveSize, pdwsavesize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "GetSaveSize", 6usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).GetSaveSize)(this, fsave, pdwsavesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefineTypeDef(this: *mut core::ffi::c_void, sztypedef: windows_core::PCWSTR, dwtypedefflags: u32, tkextends: u32, rtkimplements: *mut u32, ptd: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "DefineTypeDef", 7usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).DefineTypeDef)(this, sztypedef, dwtypedefflags, tkextends, rtkimplements, ptd), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefineNestedType(this: *mut core::ffi::c_void, sztypedef: windows_core::PCWSTR, dwtypedefflags: u32, tkextends: u32, rtkimplements: *mut u32, tdencloser: u32, ptd: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "DefineNestedType", 8usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).DefineNestedType)(this, sztypedef, dwtypedefflags, tkextends, rtkimplements, tdencloser, ptd), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHandler(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "SetHandler", 9usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).SetHandler)(this, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefineMethod(this: *mut core::ffi::c_void, td: u32, szname: windows_core::PCWSTR, dwmethodflags: u32, pvsigblob: *mut u8, cbsigblob: u32, ulcoderva: u32, dwimplflags: u32, pmd: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "DefineMethod", 10usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).DefineMethod)(this, td, szname, dwmethodflags, pvsigblob, cbsigblob, ulcoderva, dwimplflags, pmd), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefineMethodImpl(this: *mut core::ffi::c_void, td: u32, tkbody: u32, tkdecl: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "DefineMethodImpl", 11usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).DefineMethodImpl)(this, td, tkbody, tkdecl), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefineTypeRefByName(this: *mut core::ffi::c_void, tkresolutionscope: u32, szname: windows_core::PCWSTR, ptr: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "DefineTypeRefByName", 12usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).DefineTypeRefByName)(this, tkresolutionscope, szname, ptr), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefineImportType(this: *mut core::ffi::c_void, passemimport: *mut core::ffi::c_void, pbhashvalue: *const core::ffi::c_void, cbhashvalue: u32, pimport: *mut core::ffi::c_void, tdimport: u32, passememit: *mut core::ffi::c_void, ptr: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "DefineImportType", 13usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).DefineImportType)(this, passemimport, pbhashvalue, cbhashvalue, pimport, tdimport, passememit, ptr), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefineMemberRef(this: *mut core::ffi::c_void, tkimport: u32, szname: windows_core::PCWSTR, pvsigblob: *mut u8, cbsigblob: u32, pmr: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "DefineMemberRef", 14usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).DefineMemberRef)(this, tkimport, szname, pvsigblob, cbsigblob, pmr), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefineImportMember(this: *mut core::ffi::c_void, passemimport: *mut core::ffi::c_void, pbhashvalue: *const core::ffi::c_void, cbhashvalue: u32, pimport: *mut core::ffi::c_void, mbmember: u32, passememit: *mut core::ffi::c_void, tkparent: u32, pmr: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "DefineImportMember", 15usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).DefineImportMember)(this, passemimport, pbhashvalue, cbhashvalue, pimport, mbmember, passememit, tkparent, pmr), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefineEvent(this: *mut core::ffi::c_void, td: u32, szevent: windows_core::PCWSTR, dweventflags: u32, tkeventtype: u32, mdaddon: u32, mdremoveon: u32, mdfire: u32, rmdothermethods: *mut u32, pmdevent: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "DefineEvent", 16usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).DefineEvent)(this, td, szevent, dweventflags, tkeventtype, mdaddon, mdremoveon, mdfire, rmdothermethods, pmdevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClassLayout(this: *mut core::ffi::c_void, td: u32, dwpacksize: u32, rfieldoffsets: *mut COR_FIELD_OFFSET, ulclasssize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "SetClassLayout", 17usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).SetClassLayout)(this, td, dwpacksize, rfieldoffsets, ulclasssize), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteClassLayout(this: *mut core::ffi::c_void, td: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "DeleteClassLayout", 18usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).DeleteClassLayout)(this, td), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFieldMarshal(this: *mut core::ffi::c_void, tk: u32, pvnativetype: *mut u8, cbnativetype: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "SetFieldMarshal", 19usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).SetFieldMarshal)(this, tk, pvnativetype, cbnativetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteFieldMarshal(this: *mut core::ffi::c_void, tk: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "DeleteFieldMarshal", 20usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).DeleteFieldMarshal)(this, tk), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefinePermissionSet(this: *mut core::ffi::c_void, tk: u32, dwaction: u32, pvpermission: *const core::ffi::c_void, cbpermission: u32, ppm: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "DefinePermissionSet", 21usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).DefinePermissionSet)(this, tk, dwaction, pvpermission, cbpermission, ppm), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRVA(this: *mut core::ffi::c_void, md: u32, ulrva: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "SetRVA", 22usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).SetRVA)(this, md, ulrva), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTokenFromSig(this: *mut core::ffi::c_void, pvsig: *mut u8, cbsig: u32, pmsig: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "GetTokenFromSig", 23usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).GetTokenFromSig)(this, pvsig, cbsig, pmsig), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefineModuleRef(this: *mut core::ffi::c_void, szname: windows_core::PCWSTR, pmur: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "DefineModuleRef", 24usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).DefineModuleRef)(this, szname, pmur), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParent(this: *mut core::ffi::c_void, mr: u32, tk: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "SetParent", 25usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).SetParent)(this, mr, tk), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTokenFromTypeSpec(this: *mut core::ffi::c_void, pvsig: *mut u8, cbsig: u32, ptypespec: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "GetTokenFromTypeSpec", 26usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).GetTokenFromTypeSpec)(this, pvsig, cbsig, ptypespec), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveToMemory(this: *mut core::ffi::c_void, pbdata: *mut core::ffi::c_void, cbdata: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "SaveToMemory", 27usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).SaveToMemory)(this, pbdata, cbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefineUserString(this: *mut core::ffi::c_void, szstring: windows_core::PCWSTR, cchstring: u32, pstk: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "DefineUserString", 28usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).DefineUserString)(this, szstring, cchstring, pstk), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteToken(this: *mut core::ffi::c_void, tkobj: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "DeleteToken", 29usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).DeleteToken)(this, tkobj), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMethodProps(this: *mut core::ffi::c_void, md: u32, dwmethodflags: u32, ulcoderva: u32, dwimplflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "SetMethodProps", 30usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).SetMethodProps)(this, md, dwmethodflags, ulcoderva, dwimplflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTypeDefProps(this: *mut core::ffi::c_void, td: u32, dwtypedefflags: u32, tkextends: u32, rtkimplements: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "SetTypeDefProps", 31usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).SetTypeDefProps)(this, td, dwtypedefflags, tkextends, rtkimplements), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventProps(this: *mut core::ffi::c_void, ev: u32, dweventflags: u32, tkeventtype: u32, mdaddon: u32, mdremoveon: u32, mdfire: u32, rmdothermethods: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "SetEventProps", 32usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).SetEventProps)(this, ev, dweventflags, tkeventtype, mdaddon, mdremoveon, mdfire, rmdothermethods), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPermissionSetProps(this: *mut core::ffi::c_void, tk: u32, dwaction: u32, pvpermission: *const core::ffi::c_void, cbpermission: u32, ppm: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "SetPermissionSetProps", 33usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).SetPermissionSetProps)(this, tk, dwaction, pvpermission, cbpermission, ppm), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefinePinvokeMap(this: *mut core::ffi::c_void, tk: u32, dwmappingflags: u32, szimportname: windows_core::PCWSTR, mrimportdll: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "DefinePinvokeMap", 34usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).DefinePinvokeMap)(this, tk, dwmappingflags, szimportname, mrimportdll), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPinvokeMap(this: *mut core::ffi::c_void, tk: u32, dwmappingflags: u32, szimportname: windows_core::PCWSTR, mrimportdll: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "SetPinvokeMap", 35usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).SetPinvokeMap)(this, tk, dwmappingflags, szimportname, mrimportdll), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeletePinvokeMap(this: *mut core::ffi::c_void, tk: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "DeletePinvokeMap", 36usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).DeletePinvokeMap)(this, tk), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefineCustomAttribute(this: *mut core::ffi::c_void, tkowner: u32, tkctor: u32, pcustomattribute: *const core::ffi::c_void, cbcustomattribute: u32, pcv: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "DefineCustomAttribute", 37usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).DefineCustomAttribute)(this, tkowner, tkctor, pcustomattribute, cbcustomattribute, pcv), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCustomAttributeValue(this: *mut core::ffi::c_void, pcv: u32, pcustomattribute: *const core::ffi::c_void, cbcustomattribute: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "SetCustomAttributeValue", 38usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).SetCustomAttributeValue)(this, pcv, pcustomattribute, cbcustomattribute), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefineField(this: *mut core::ffi::c_void, td: u32, szname: windows_core::PCWSTR, dwfieldflags: u32, pvsigblob: *mut u8, cbsigblob: u32, dwcplustypeflag: u32, pvalue: *const core::ffi::c_void, cchvalue: u32, pmd: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "DefineField", 39usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).DefineField)(this, td, szname, dwfieldflags, pvsigblob, cbsigblob, dwcplustypeflag, pvalue, cchvalue, pmd), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefineProperty(this: *mut core::ffi::c_void, td: u32, szproperty: windows_core::PCWSTR, dwpropflags: u32, pvsig: *mut u8, cbsig: u32, dwcplustypeflag: u32, pvalue: *const core::ffi::c_void, cchvalue: u32, mdsetter: u32, mdgetter: u32, rmdothermethods: *mut u32, pmdprop: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "DefineProperty", 40usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).DefineProperty)(this, td, szproperty, dwpropflags, pvsig, cbsig, dwcplustypeflag, pvalue, cchvalue, mdsetter, mdgetter, rmdothermethods, pmdprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefineParam(this: *mut core::ffi::c_void, md: u32, ulparamseq: u32, szname: windows_core::PCWSTR, dwparamflags: u32, dwcplustypeflag: u32, pvalue: *const core::ffi::c_void, cchvalue: u32, ppd: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "DefineParam", 41usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).DefineParam)(this, md, ulparamseq, szname, dwparamflags, dwcplustypeflag, pvalue, cchvalue, ppd), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFieldProps(this: *mut core::ffi::c_void, fd: u32, dwfieldflags: u32, dwcplustypeflag: u32, pvalue: *const core::ffi::c_void, cchvalue: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "SetFieldProps", 42usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).SetFieldProps)(this, fd, dwfieldflags, dwcplustypeflag, pvalue, cchvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPropertyProps(this: *mut core::ffi::c_void, pr: u32, dwpropflags: u32, dwcplustypeflag: u32, pvalue: *const core::ffi::c_void, cchvalue: u32, mdsetter: u32, mdgetter: u32, rmdothermethods: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "SetPropertyProps", 43usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).SetPropertyProps)(this, pr, dwpropflags, dwcplustypeflag, pvalue, cchvalue, mdsetter, mdgetter, rmdothermethods), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParamProps(this: *mut core::ffi::c_void, pd: u32, szname: windows_core::PCWSTR, dwparamflags: u32, dwcplustypeflag: u32, pvalue: *const core::ffi::c_void, cchvalue: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "SetParamProps", 44usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).SetParamProps)(this, pd, szname, dwparamflags, dwcplustypeflag, pvalue, cchvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefineSecurityAttributeSet(this: *mut core::ffi::c_void, tkobj: u32, rsecattrs: *mut COR_SECATTR, csecattrs: u32, pulerrorattr: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "DefineSecurityAttributeSet", 45usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).DefineSecurityAttributeSet)(this, tkobj, rsecattrs, csecattrs, pulerrorattr), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplyEditAndContinue(this: *mut core::ffi::c_void, pimport: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "ApplyEditAndContinue", 46usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).ApplyEditAndContinue)(this, pimport), |result| Some(*result))
+        }
+        unsafe extern "system" fn TranslateSigWithScope(this: *mut core::ffi::c_void, passemimport: *mut core::ffi::c_void, pbhashvalue: *const core::ffi::c_void, cbhashvalue: u32, import: *mut core::ffi::c_void, pbsigblob: *mut u8, cbsigblob: u32, passememit: *mut core::ffi::c_void, emit: *mut core::ffi::c_void, pvtranslatedsig: *mut u8, cbtranslatedsigmax: u32, pcbtranslatedsig: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "TranslateSigWithScope", 47usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).TranslateSigWithScope)(this, passemimport, pbhashvalue, cbhashvalue, import, pbsigblob, cbsigblob, passememit, emit, pvtranslatedsig, cbtranslatedsigmax, pcbtranslatedsig), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMethodImplFlags(this: *mut core::ffi::c_void, md: u32, dwimplflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "SetMethodImplFlags", 48usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).SetMethodImplFlags)(this, md, dwimplflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFieldRVA(this: *mut core::ffi::c_void, fd: u32, ulrva: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "SetFieldRVA", 49usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).SetFieldRVA)(this, fd, ulrva), |result| Some(*result))
+        }
+        unsafe extern "system" fn Merge(this: *mut core::ffi::c_void, pimport: *mut core::ffi::c_void, phostmaptoken: *mut core::ffi::c_void, phandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "Merge", 50usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).Merge)(this, pimport, phostmaptoken, phandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn MergeEnd(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit", "MergeEnd", 51usize, |this| ((**(this as *const *const IMetaDataEmit_Vtbl)).MergeEnd)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetModuleProps,
+            Save,
+            #[cfg(feature = "Win32_System_Com")]
+            SaveToStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SaveToStream: 0,
+            GetSaveSize,
+            DefineTypeDef,
+            DefineNestedType,
+            SetHandler,
+            DefineMethod,
+            DefineMethodImpl,
+            DefineTypeRefByName,
+            DefineImportType,
+            DefineMemberRef,
+            DefineImportMember,
+            DefineEvent,
+            SetClassLayout,
+            DeleteClassLayout,
+            SetFieldMarshal,
+            DeleteFieldMarshal,
+            DefinePermissionSet,
+            SetRVA,
+            GetTokenFromSig,
+            DefineModuleRef,
+            SetParent,
+            GetTokenFromTypeSpec,
+            SaveToMemory,
+            DefineUserString,
+            DeleteToken,
+            SetMethodProps,
+            SetTypeDefProps,
+            SetEventProps,
+            SetPermissionSetProps,
+            DefinePinvokeMap,
+            SetPinvokeMap,
+            DeletePinvokeMap,
+            DefineCustomAttribute,
+            SetCustomAttributeValue,
+            DefineField,
+            DefineProperty,
+            DefineParam,
+            SetFieldProps,
+            SetPropertyProps,
+            SetParamProps,
+            DefineSecurityAttributeSet,
+            ApplyEditAndContinue,
+            TranslateSigWithScope,
+            SetMethodImplFlags,
+            SetFieldRVA,
+            Merge,
+            MergeEnd,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMetaDataEmit as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMetaDataEmit2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DefineMethodSpec(this: *mut core::ffi::c_void, tkparent: u32, pvsigblob: *mut u8, cbsigblob: u32, pmi: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit2", "DefineMethodSpec", 52usize, |this| ((**(this as *const *const IMetaDataEmit2_Vtbl)).DefineMethodSpec)(this, tkparent, pvsigblob, cbsigblob, pmi), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDeltaSaveSize(this: *mut core::ffi::c_void, fsave: CorSaveSize, pdwsavesize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit2", "GetDeltaSaveSize", 53usize, |this| ((**(this as *const *const IMetaDataEmit2_Vtbl)).GetDeltaSaveSize)(this, fsave, pdwsavesize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveDelta(this: *mut core::ffi::c_void, szfile: windows_core::PCWSTR, dwsaveflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit2", "SaveDelta", 54usize, |this| ((**(this as *const *const IMetaDataEmit2_Vtbl)).SaveDelta)(this, szfile, dwsaveflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SaveDeltaToStream(this: *mut core::ffi::c_void, pistream: *mut core::ffi::c_void, dwsaveflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit2", "SaveDeltaToStream", 55usize, |this| ((**(this as *const *const IMetaDataEmit2_Vtbl)).SaveDeltaToStream)(this, pistream, dwsaveflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SaveDeltaToMemory(this: *mut core::ffi::c_void, pbdata: *mut core::ffi::c_void, cbdata: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit2", "SaveDeltaToMemory", 56usize, |this| ((**(this as *const *const IMetaDataEmit2_Vtbl)).SaveDeltaToMemory)(this, pbdata, cbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefineGenericParam(this: *mut core::ffi::c_void, tk: u32, ulparamseq: u32, dwparamflags: u32, szname: windows_core::PCWSTR, reserved: u32, rtkconstraints: *mut u32, pgp: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit2", "DefineGenericParam", 57usize, |this| ((**(this as *const *const IMetaDataEmit2_Vtbl)).DefineGenericParam)(this, tk, ulparamseq, dwparamflags, szname, reserved, rtkconstraints, pgp), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGenericParamProps(this: *mut core::ffi::c_void, gp: u32, dwparamflags: u32, szname: windows_core::PCWSTR, reserved: u32, rtkconstraints: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit2", "SetGenericParamProps", 58usize, |this| ((**(this as *const *const IMetaDataEmit2_Vtbl)).SetGenericParamProps)(this, gp, dwparamflags, szname, reserved, rtkconstraints), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetENCLog(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataEmit2", "ResetENCLog", 59usize, |this| ((**(this as *const *const IMetaDataEmit2_Vtbl)).ResetENCLog)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IMetaDataEmit_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DefineMethodSpec,
+            GetDeltaSaveSize,
+            SaveDelta,
+            #[cfg(feature = "Win32_System_Com")]
+            SaveDeltaToStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SaveDeltaToStream: 0,
+            SaveDeltaToMemory,
+            DefineGenericParam,
+            SetGenericParamProps,
+            ResetENCLog,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMetaDataEmit2 as windows_core::Interface>::IID || <IMetaDataEmit_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMetaDataError_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnError(this: *mut core::ffi::c_void, hrerror: windows_core::HRESULT, token: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataError", "OnError", 3usize, |this| ((**(this as *const *const IMetaDataError_Vtbl)).OnError)(this, hrerror, token), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMetaDataError as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMetaDataFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UnmarkAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataFilter", "UnmarkAll", 3usize, |this| ((**(this as *const *const IMetaDataFilter_Vtbl)).UnmarkAll)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn MarkToken(this: *mut core::ffi::c_void, tk: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataFilter", "MarkToken", 4usize, |this| ((**(this as *const *const IMetaDataFilter_Vtbl)).MarkToken)(this, tk), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTokenMarked(this: *mut core::ffi::c_void, tk: u32, pismarked: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataFilter", "IsTokenMarked", 5usize, |this| ((**(this as *const *const IMetaDataFilter_Vtbl)).IsTokenMarked)(this, tk, pismarked), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UnmarkAll, MarkToken, IsTokenMarked }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMetaDataFilter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMetaDataImport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CloseEnum(this: *mut core::ffi::c_void, henum: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "CloseEnum", 3usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).CloseEnum)(this, henum), |_| None)
+        }
+        unsafe extern "system" fn CountEnum(this: *mut core::ffi::c_void, henum: *mut core::ffi::c_void, pulcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "CountEnum", 4usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).CountEnum)(this, henum, pulcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResetEnum(this: *mut core::ffi::c_void, henum: *mut core::ffi::c_void, ulpos: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "ResetEnum", 5usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).ResetEnum)(this, henum, ulpos), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumTypeDefs(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, rtypedefs: *mut u32, cmax: u32, pctypedefs: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "EnumTypeDefs", 6usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).EnumTypeDefs)(this, phenum, rtypedefs, cmax, pctypedefs), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumInterfaceImpls(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, td: u32, rimpls: *mut u32, cmax: u32, pcimpls: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "EnumInterfaceImpls", 7usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).EnumInterfaceImpls)(this, phenum, td, rimpls, cmax, pcimpls), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumTypeRefs(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, rtyperefs: *mut u32, cmax: u32, pctyperefs: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "EnumTypeRefs", 8usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).EnumTypeRefs)(this, phenum, rtyperefs, cmax, pctyperefs), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindTypeDefByName(this: *mut core::ffi::c_void, sztypedef: windows_core::PCWSTR, tkenclosingclass: u32, ptd: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "FindTypeDefByName", 9usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).FindTypeDefByName)(this, sztypedef, tkenclosingclass, ptd), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScopeProps(this: *mut core::ffi::c_void, szname: windows_core::PWSTR, cchname: u32, pchname: *mut u32, pmvid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "GetScopeProps", 10usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).GetScopeProps)(this, szname, cchname, pchname, pmvid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetModuleFromScope(this: *mut core::ffi::c_void, pmd: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "GetModuleFromScope", 11usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).GetModuleFromScope)(this, pmd), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTypeDefProps(this: *mut core::ffi::c_void, td: u32, sztypedef: windows_core::PWSTR, cchtypedef: u32, pchtypedef: *mut u32, pdwtypedefflags: *mut u32, ptkextends: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "GetTypeDefProps", 12usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).GetTypeDefProps)(this, td, sztypedef, cchtypedef, pchtypedef, pdwtypedefflags, ptkextends), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInterfaceImplProps(this: *mut core::ffi::c_void, iiimpl: u32, pclass: *mut u32, ptkiface: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "GetInterfaceImplProps", 13usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).GetInterfaceImplProps)(this, iiimpl, pclass, ptkiface), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTypeRefProps(this: *mut core::ffi::c_void, tr: u32, ptkresolutionscope: *mut u32, szname: windows_core::PWSTR, cchname: u32, pchname: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "GetTypeRefProps", 14usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).GetTypeRefProps)(this, tr, ptkresolutionscope, szname, cchname, pchname), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResolveTypeRef(this: *mut core::ffi::c_void, tr: u32, riid: *const windows_core::GUID, ppiscope: *mut *mut core::ffi::c_void, ptd: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "ResolveTypeRef", 15usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).ResolveTypeRef)(this, tr, riid, ppiscope, ptd), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumMembers(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, cl: u32, rmembers: *mut u32, cmax: u32, pctokens: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "EnumMembers", 16usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).EnumMembers)(this, phenum, cl, rmembers, cmax, pctokens), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumMembersWithName(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, cl: u32, szname: windows_core::PCWSTR, rmembers: *mut u32, cmax: u32, pctokens: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "EnumMembersWithName", 17usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).EnumMembersWithName)(this, phenum, cl, szname, rmembers, cmax, pctokens), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumMethods(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, cl: u32, rmethods: *mut u32, cmax: u32, pctokens: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "EnumMethods", 18usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).EnumMethods)(this, phenum, cl, rmethods, cmax, pctokens), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumMethodsWithName(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, cl: u32, szname: windows_core::PCWSTR, rmethods: *mut u32, cmax: u32, pctokens: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "EnumMethodsWithName", 19usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).EnumMethodsWithName)(this, phenum, cl, szname, rmethods, cmax, pctokens), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumFields(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, cl: u32, rfields: *mut u32, cmax: u32, pctokens: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "EnumFields", 20usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).EnumFields)(this, phenum, cl, rfields, cmax, pctokens), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumFieldsWithName(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, cl: u32, szname: windows_core::PCWSTR, rfields: *mut u32, cmax: u32, pctokens: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "EnumFieldsWithName", 21usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).EnumFieldsWithName)(this, phenum, cl, szname, rfields, cmax, pctokens), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumParams(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, mb: u32, rparams: *mut u32, cmax: u32, pctokens: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "EnumParams", 22usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).EnumParams)(this, phenum, mb, rparams, cmax, pctokens), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumMemberRefs(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, tkparent: u32, rmemberrefs: *mut u32, cmax: u32, pctokens: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "EnumMemberRefs", 23usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).EnumMemberRefs)(this, phenum, tkparent, rmemberrefs, cmax, pctokens), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumMethodImpls(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, td: u32, rmethodbody: *mut u32, rmethoddecl: *mut u32, cmax: u32, pctokens: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "EnumMethodImpls", 24usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).EnumMethodImpls)(this, phenum, td, rmethodbody, rmethoddecl, cmax, pctokens), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumPermissionSets(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, tk: u32, dwactions: u32, rpermission: *mut u32, cmax: u32, pctokens: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "EnumPermissionSets", 25usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).EnumPermissionSets)(this, phenum, tk, dwactions, rpermission, cmax, pctokens), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindMember(this: *mut core::ffi::c_void, td: u32, szname: windows_core::PCWSTR, pvsigblob: *mut u8, cbsigblob: u32, pmb: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "FindMember", 26usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).FindMember)(this, td, szname, pvsigblob, cbsigblob, pmb), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindMethod(this: *mut core::ffi::c_void, td: u32, szname: windows_core::PCWSTR, pvsigblob: *mut u8, cbsigblob: u32, pmb: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "FindMethod", 27usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).FindMethod)(this, td, szname, pvsigblob, cbsigblob, pmb), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindField(this: *mut core::ffi::c_void, td: u32, szname: windows_core::PCWSTR, pvsigblob: *mut u8, cbsigblob: u32, pmb: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "FindField", 28usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).FindField)(this, td, szname, pvsigblob, cbsigblob, pmb), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindMemberRef(this: *mut core::ffi::c_void, td: u32, szname: windows_core::PCWSTR, pvsigblob: *mut u8, cbsigblob: u32, pmr: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "FindMemberRef", 29usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).FindMemberRef)(this, td, szname, pvsigblob, cbsigblob, pmr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMethodProps(this: *mut core::ffi::c_void, mb: u32, pclass: *mut u32, szmethod: windows_core::PWSTR, cchmethod: u32, pchmethod: *mut u32, pdwattr: *mut u32, ppvsigblob: *mut *mut u8, pcbsigblob: *mut u32, pulcoderva: *mut u32, pdwimplflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "GetMethodProps", 30usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).GetMethodProps)(this, mb, pclass, szmethod, cchmethod, pchmethod, pdwattr, ppvsigblob, pcbsigblob, pulcoderva, pdwimplflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMemberRefProps(this: *mut core::ffi::c_void, mr: u32, ptk: *mut u32, szmember: windows_core::PWSTR, cchmember: u32, pchmember: *mut u32, ppvsigblob: *mut *mut u8, pbsig: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "GetMemberRefProps", 31usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).GetMemberRefProps)(this, mr, ptk, szmember, cchmember, pchmember, ppvsigblob, pbsig), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumProperties(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, td: u32, rproperties: *mut u32, cmax: u32, pcproperties: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "EnumProperties", 32usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).EnumProperties)(this, phenum, td, rproperties, cmax, pcproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumEvents(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, td: u32, revents: *mut u32, cmax: u32, pcevents: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "EnumEvents", 33usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).EnumEvents)(this, phenum, td, revents, cmax, pcevents), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEventProps(this: *mut core::ffi::c_void, ev: u32, pclass: *mut u32, szevent: windows_core::PCWSTR, cchevent: u32, pchevent: *mut u32, pdweventflags: *mut u32, ptkeventtype: *mut u32, pmdaddon: *mut u32, pmdremoveon: *mut u32, pmdfire: *mut u32, rmdothermethod: *mut u32, cmax: u32, pcothermethod: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "GetEventProps", 34usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).GetEventProps)(this, ev, pclass, szevent, cchevent, pchevent, pdweventflags, ptkeventtype, pmdaddon, pmdremoveon, pmdfire, rmdothermethod, cmax, pcothermethod), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumMethodSemantics(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, mb: u32, reventprop: *mut u32, cmax: u32, pceventprop: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "EnumMethodSemantics", 35usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).EnumMethodSemantics)(this, phenum, mb, reventprop, cmax, pceventprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMethodSemantics(this: *mut core::ffi::c_void, mb: u32, tkeventprop: u32, pdwsemanticsflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "GetMethodSemantics", 36usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).GetMethodSemantics)(this, mb, tkeventprop, pdwsemanticsflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClassLayout(this: *mut core::ffi::c_void, td: u32, pdwpacksize: *mut u32, rfieldoffset: *mut COR_FIELD_OFFSET, cmax: u32, pcfieldoffset: *mut u32, pulclasssize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "GetClassLayout", 37usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).GetClassLayout)(this, td, pdwpacksize, rfieldoffset, cmax, pcfieldoffset, pulclasssize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFieldMarshal(this: *mut core::ffi::c_void, tk: u32, ppvnativetype: *mut *mut u8, pcbnativetype: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "GetFieldMarshal", 38usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).GetFieldMarshal)(this, tk, ppvnativetype, pcbnativetype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRVA(this: *mut core::ffi::c_void, tk: u32, pulcoderva: *mut u32, pdwimplflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "GetRVA", 39usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).GetRVA)(this, tk, pulcoderva, pdwimplflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPermissionSetProps(this: *mut core::ffi::c_void, pm: u32, pdwaction: *mut u32, ppvpermission: *const *const core::ffi::c_void, pcbpermission: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "GetPermissionSetProps", 40usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).GetPermissionSetProps)(this, pm, pdwaction, ppvpermission, pcbpermission), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSigFromToken(this: *mut core::ffi::c_void, mdsig: u32, ppvsig: *mut *mut u8, pcbsig: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "GetSigFromToken", 41usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).GetSigFromToken)(this, mdsig, ppvsig, pcbsig), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetModuleRefProps(this: *mut core::ffi::c_void, mur: u32, szname: windows_core::PWSTR, cchname: u32, pchname: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "GetModuleRefProps", 42usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).GetModuleRefProps)(this, mur, szname, cchname, pchname), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumModuleRefs(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, rmodulerefs: *mut u32, cmax: u32, pcmodulerefs: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "EnumModuleRefs", 43usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).EnumModuleRefs)(this, phenum, rmodulerefs, cmax, pcmodulerefs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTypeSpecFromToken(this: *mut core::ffi::c_void, typespec: u32, ppvsig: *mut *mut u8, pcbsig: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "GetTypeSpecFromToken", 44usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).GetTypeSpecFromToken)(this, typespec, ppvsig, pcbsig), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNameFromToken(this: *mut core::ffi::c_void, tk: u32, pszutf8nameptr: *mut *mut i8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "GetNameFromToken", 45usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).GetNameFromToken)(this, tk, pszutf8nameptr), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumUnresolvedMethods(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, rmethods: *mut u32, cmax: u32, pctokens: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "EnumUnresolvedMethods", 46usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).EnumUnresolvedMethods)(this, phenum, rmethods, cmax, pctokens), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUserString(this: *mut core::ffi::c_void, stk: u32, szstring: windows_core::PWSTR, cchstring: u32, pchstring: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "GetUserString", 47usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).GetUserString)(this, stk, szstring, cchstring, pchstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPinvokeMap(this: *mut core::ffi::c_void, tk: u32, pdwmappingflags: *mut u32, szimportname: windows_core::PWSTR, cchimportname: u32, pchimportname: *mut u32, pmrimportdll: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "GetPinvokeMap", 48usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).GetPinvokeMap)(this, tk, pdwmappingflags, szimportname, cchimportname, pchimportname, pmrimportdll), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumSignatures(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, rsignatures: *mut u32, cmax: u32, pcsignatures: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "EnumSignatures", 49usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).EnumSignatures)(this, phenum, rsignatures, cmax, pcsignatures), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumTypeSpecs(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, rtypespecs: *mut u32, cmax: u32, pctypespecs: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "EnumTypeSpecs", 50usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).EnumTypeSpecs)(this, phenum, rtypespecs, cmax, pctypespecs), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumUserStrings(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, rstrings: *mut u32, cmax: u32, pcstrings: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "EnumUserStrings", 51usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).EnumUserStrings)(this, phenum, rstrings, cmax, pcstrings), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParamForMethodIndex(this: *mut core::ffi::c_void, md: u32, ulparamseq: u32, ppd: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "GetParamForMethodIndex", 52usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).GetParamForMethodIndex)(this, md, ulparamseq, ppd), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumCustomAttributes(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, tk: u32, tktype: u32, rcustomattributes: *mut u32, cmax: u32, pccustomattributes: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "EnumCustomAttributes", 53usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).EnumCustomAttributes)(this, phenum, tk, tktype, rcustomattributes, cmax, pccustomattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCustomAttributeProps(this: *mut core::ffi::c_void, cv: u32, ptkobj: *mut u32, ptktype: *mut u32, ppblob: *const *const core::ffi::c_void, pcbsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "GetCustomAttributeProps", 54usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).GetCustomAttributeProps)(this, cv, ptkobj, ptktype, ppblob, pcbsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindTypeRef(this: *mut core::ffi::c_void, tkresolutionscope: u32, szname: windows_core::PCWSTR, ptr: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "FindTypeRef", 55usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).FindTypeRef)(this, tkresolutionscope, szname, ptr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMemberProps(this: *mut core::ffi::c_void, mb: u32, pclass: *mut u32, szmember: windows_core::PWSTR, cchmember: u32, pchmember: *mut u32, pdwattr: *mut u32, ppvsigblob: *mut *mut u8, pcbsigblob: *mut u32, pulcoderva: *mut u32, pdwimplflags: *mut u32, pdwcplustypeflag: *mut u32, ppvalue: *mut *mut core::ffi::c_void, pcchvalue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "GetMemberProps", 56usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).GetMemberProps)(this, mb, pclass, szmember, cchmember, pchmember, pdwattr, ppvsigblob, pcbsigblob, pulcoderva, pdwimplflags, pdwcplustypeflag, ppvalue, pcchvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFieldProps(this: *mut core::ffi::c_void, mb: u32, pclass: *mut u32, szfield: windows_core::PWSTR, cchfield: u32, pchfield: *mut u32, pdwattr: *mut u32, ppvsigblob: *mut *mut u8, pcbsigblob: *mut u32, pdwcplustypeflag: *mut u32, ppvalue: *mut *mut core::ffi::c_void, pcchvalue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "GetFieldProps", 57usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).GetFieldProps)(this, mb, pclass, szfield, cchfield, pchfield, pdwattr, ppvsigblob, pcbsigblob, pdwcplustypeflag, ppvalue, pcchvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyProps(this: *mut core::ffi::c_void, prop: u32, pclass: *mut u32, szproperty: windows_core::PCWSTR, cchproperty: u32, pchproperty: *mut u32, pdwpropflags: *mut u32, ppvsig: *mut *mut u8, pbsig: *mut u32, pdwcplustypeflag: *mut u32, ppdefaultvalue: *mut *mut core::ffi::c_void, pcchdefaultvalue: *mut u32, pmdsetter: *mut u32, pmdgetter: *mut u32, rmdothermethod: *mut u32, cmax: u32, pcothermethod: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "GetPropertyProps", 58usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).GetPropertyProps)(this, prop, pclass, szproperty, cchproperty, pchproperty, pdwpropflags, ppvsig, pbsig, pdwcplustypeflag, ppdefaultvalue, pcchdefaultvalue, pmdsetter, pmdgetter, rmdothermethod, cmax, pcothermethod), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParamProps(this: *mut core::ffi::c_void, tk: u32, pmd: *mut u32, pulsequence: *mut u32, szname: windows_core::PWSTR, cchname: u32, pchname: *mut u32, pdwattr: *mut u32, pdwcplustypeflag: *mut u32, ppvalue: *mut *mut core::ffi::c_void, pcchvalue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "GetParamProps", 59usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).GetParamProps)(this, tk, pmd, pulsequence, szname, cchname, pchname, pdwattr, pdwcplustypeflag, ppvalue, pcchvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCustomAttributeByName(this: *mut core::ffi::c_void, tkobj: u32, szname: windows_core::PCWSTR, ppdata: *const *const core::ffi::c_void, pcbdata: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "GetCustomAttributeByName", 60usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).GetCustomAttributeByName)(this, tkobj, szname, ppdata, pcbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsValidToken(this: *mut core::ffi::c_void, tk: u32) -> super::super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "IsValidToken", 61usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).IsValidToken)(this, tk), |_| None)
+        }
+        unsafe extern "system" fn GetNestedClassProps(this: *mut core::ffi::c_void, tdnestedclass: u32, ptdenclosingclass: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "GetNestedClassProps", 62usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).GetNestedClassProps)(this, tdnestedclass, ptdenclosingclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNativeCallConvFromSig(this: *mut core::ffi::c_void, pvsig: *const core::ffi::c_void, cbsig: u32, pcallconv: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "GetNativeCallConvFromSig", 63usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).GetNativeCallConvFromSig)(this, pvsig, cbsig, pcallconv), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsGlobal(this: *mut core::ffi::c_void, pd: u32, pbglobal: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport", "IsGlobal", 64usize, |this| ((**(this as *const *const IMetaDataImport_Vtbl)).IsGlobal)(this, pd, pbglobal), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CloseEnum,
+            CountEnum,
+            ResetEnum,
+            EnumTypeDefs,
+            EnumInterfaceImpls,
+            EnumTypeRefs,
+            FindTypeDefByName,
+            GetScopeProps,
+            GetModuleFromScope,
+            GetTypeDefProps,
+            GetInterfaceImplProps,
+            GetTypeRefProps,
+            ResolveTypeRef,
+            EnumMembers,
+            EnumMembersWithName,
+            EnumMethods,
+            EnumMethodsWithName,
+            EnumFields,
+            EnumFieldsWithName,
+            EnumParams,
+            EnumMemberRefs,
+            EnumMethodImpls,
+            EnumPermissionSets,
+            FindMember,
+            FindMethod,
+            FindField,
+            FindMemberRef,
+            GetMethodProps,
+            GetMemberRefProps,
+            EnumProperties,
+            EnumEvents,
+            GetEventProps,
+            EnumMethodSemantics,
+            GetMethodSemantics,
+            GetClassLayout,
+            GetFieldMarshal,
+            GetRVA,
+            GetPermissionSetProps,
+            GetSigFromToken,
+            GetModuleRefProps,
+            EnumModuleRefs,
+            GetTypeSpecFromToken,
+            GetNameFromToken,
+            EnumUnresolvedMethods,
+            GetUserString,
+            GetPinvokeMap,
+            EnumSignatures,
+            EnumTypeSpecs,
+            EnumUserStrings,
+            GetParamForMethodIndex,
+            EnumCustomAttributes,
+            GetCustomAttributeProps,
+            FindTypeRef,
+            GetMemberProps,
+            GetFieldProps,
+            GetPropertyProps,
+            GetParamProps,
+            GetCustomAttributeByName,
+            IsValidToken,
+            GetNestedClassProps,
+            GetNativeCallConvFromSig,
+            IsGlobal,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMetaDataImport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMetaDataImport2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumGenericParams(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, tk: u32, rgenericparams: *mut u32, cmax: u32, pcgenericparams: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport2", "EnumGenericParams", 65usize, |this| ((**(this as *const *const IMetaDataImport2_Vtbl)).EnumGenericParams)(this, phenum, tk, rgenericparams, cmax, pcgenericparams), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGenericParamProps(this: *mut core::ffi::c_void, gp: u32, pulparamseq: *mut u32, pdwparamflags: *mut u32, ptowner: *mut u32, reserved: *mut u32, wzname: windows_core::PWSTR, cchname: u32, pchname: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport2", "GetGenericParamProps", 66usize, |this| ((**(this as *const *const IMetaDataImport2_Vtbl)).GetGenericParamProps)(this, gp, pulparamseq, pdwparamflags, ptowner, reserved, wzname, cchname, pchname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMethodSpecProps(this: *mut core::ffi::c_void, mi: u32, tkparent: *mut u32, ppvsigblob: *mut *mut u8, pcbsigblob: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport2", "GetMethodSpecProps", 67usize, |this| ((**(this as *const *const IMetaDataImport2_Vtbl)).GetMethodSpecProps)(this, mi, tkparent, ppvsigblob, pcbsigblob), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumGenericParamConstraints(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, tk: u32, rgenericparamconstraints: *mut u32, cmax: u32, pcgenericparamconstraints: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport2", "EnumGenericParamConstraints", 68usize, |this| ((**(this as *const *const IMetaDataImport2_Vtbl)).EnumGenericParamConstraints)(this, phenum, tk, rgenericparamconstraints, cmax, pcgenericparamconstraints), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGenericParamConstraintProps(this: *mut core::ffi::c_void, gpc: u32, ptgenericparam: *mut u32, ptkconstrainttype: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport2", "GetGenericParamConstraintProps", 69usize, |this| ((**(this as *const *const IMetaDataImport2_Vtbl)).GetGenericParamConstraintProps)(this, gpc, ptgenericparam, ptkconstrainttype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPEKind(this: *mut core::ffi::c_void, pdwpekind: *mut u32, pdwmachine: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport2", "GetPEKind", 70usize, |this| ((**(this as *const *const IMetaDataImport2_Vtbl)).GetPEKind)(this, pdwpekind, pdwmachine), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionString(this: *mut core::ffi::c_void, pwzbuf: windows_core::PWSTR, ccbufsize: u32, pccbufsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport2", "GetVersionString", 71usize, |this| ((**(this as *const *const IMetaDataImport2_Vtbl)).GetVersionString)(this, pwzbuf, ccbufsize, pccbufsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumMethodSpecs(this: *mut core::ffi::c_void, phenum: *mut *mut core::ffi::c_void, tk: u32, rmethodspecs: *mut u32, cmax: u32, pcmethodspecs: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataImport2", "EnumMethodSpecs", 72usize, |this| ((**(this as *const *const IMetaDataImport2_Vtbl)).EnumMethodSpecs)(this, phenum, tk, rmethodspecs, cmax, pcmethodspecs), |result| Some(*result))
+        }
+        Self {
+            base__: <IMetaDataImport_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EnumGenericParams,
+            GetGenericParamProps,
+            GetMethodSpecProps,
+            EnumGenericParamConstraints,
+            GetGenericParamConstraintProps,
+            GetPEKind,
+            GetVersionString,
+            EnumMethodSpecs,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMetaDataImport2 as windows_core::Interface>::IID || <IMetaDataImport_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMetaDataInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFileMapping(this: *mut core::ffi::c_void, ppvdata: *const *const core::ffi::c_void, pcbdata: *mut u64, pdwmappingtype: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataInfo", "GetFileMapping", 3usize, |this| ((**(this as *const *const IMetaDataInfo_Vtbl)).GetFileMapping)(this, ppvdata, pcbdata, pdwmappingtype), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFileMapping }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMetaDataInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMetaDataTables_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStringHeapSize(this: *mut core::ffi::c_void, pcbstrings: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataTables", "GetStringHeapSize", 3usize, |this| ((**(this as *const *const IMetaDataTables_Vtbl)).GetStringHeapSize)(this, pcbstrings), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBlobHeapSize(this: *mut core::ffi::c_void, pcbblobs: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataTables", "GetBlobHeapSize", 4usize, |this| ((**(this as *const *const IMetaDataTables_Vtbl)).GetBlobHeapSize)(this, pcbblobs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGuidHeapSize(this: *mut core::ffi::c_void, pcbguids: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataTables", "GetGuidHeapSize", 5usize, |this| ((**(this as *const *const IMetaDataTables_Vtbl)).GetGuidHeapSize)(this, pcbguids), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUserStringHeapSize(this: *mut core::ffi::c_void, pcbblobs: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataTables", "GetUserStringHeapSize", 6usize, |this| ((**(this as *const *const IMetaDataTables_Vtbl)).GetUserStringHeapSize)(this, pcbblobs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumTables(this: *mut core::ffi::c_void, pctables: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataTables", "GetNumTables", 7usize, |this| ((**(this as *const *const IMetaDataTables_Vtbl)).GetNumTables)(this, pctables), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableIndex(this: *mut core::ffi::c_void, token: u32, pixtbl: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataTables", "GetTableIndex", 8usize, |this| ((**(this as *const *const IMetaDataTables_Vtbl)).GetTableIndex)(this, token, pixtbl), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTableInfo(this: *mut core::ffi::c_void, ixtbl: u32, pcbrow: *mut u32, pcrows: *mut u32, pccols: *mut u32, pikey: *mut u32, ppname: *const *const i8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataTables", "GetTableInfo", 9usize, |this| ((**(this as *const *const IMetaDataTables_Vtbl)).GetTableInfo)(this, ixtbl, pcbrow, pcrows, pccols, pikey, ppname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColumnInfo(this: *mut core::ffi::c_void, ixtbl: u32, ixcol: u32, pocol: *mut u32, pcbcol: *mut u32, ptype: *mut u32, ppname: *const *const i8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataTables", "GetColumnInfo", 10usize, |this| ((**(this as *const *const IMetaDataTables_Vtbl)).GetColumnInfo)(this, ixtbl, ixcol, pocol, pcbcol, ptype, ppname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCodedTokenInfo(this: *mut core::ffi::c_void, ixcdtkn: u32, pctokens: *mut u32, pptokens: *mut *mut u32, ppname: *const *const i8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataTables", "GetCodedTokenInfo", 11usize, |this| ((**(this as *const *const IMetaDataTables_Vtbl)).GetCodedTokenInfo)(this, ixcdtkn, pctokens, pptokens, ppname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRow(this: *mut core::ffi::c_void, ixtbl: u32, rid: u32, pprow: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataTables", "GetRow", 12usize, |this| ((**(this as *const *const IMetaDataTables_Vtbl)).GetRow)(this, ixtbl, rid, pprow), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColumn(this: *mut core::ffi::c_void, ixtbl: u32, ixcol: u32, rid: u32, pval: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataTables", "GetColumn", 13usize, |this| ((**(this as *const *const IMetaDataTables_Vtbl)).GetColumn)(this, ixtbl, ixcol, rid, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetString(this: *mut core::ffi::c_void, ixstring: u32, ppstring: *const *const i8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataTables", "GetString", 14usize, |this| ((**(this as *const *const IMetaDataTables_Vtbl)).GetString)(this, ixstring, ppstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBlob(this: *mut core::ffi::c_void, ixblob: u32, pcbdata: *mut u32, ppdata: *const *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataTables", "GetBlob", 15usize, |this| ((**(this as *const *const IMetaDataTables_Vtbl)).GetBlob)(this, ixblob, pcbdata, ppdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGuid(this: *mut core::ffi::c_void, ixguid: u32, ppguid: *const *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataTables", "GetGuid", 16usize, |this| ((**(this as *const *const IMetaDataTables_Vtbl)).GetGuid)(this, ixguid, ppguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUserString(this: *mut core::ffi::c_void, ixuserstring: u32, pcbdata: *mut u32, ppdata: *const *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataTables", "GetUserString", 17usize, |this| ((**(this as *const *const IMetaDataTables_Vtbl)).GetUserString)(this, ixuserstring, pcbdata, ppdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextString(this: *mut core::ffi::c_void, ixstring: u32, pnext: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataTables", "GetNextString", 18usize, |this| ((**(this as *const *const IMetaDataTables_Vtbl)).GetNextString)(this, ixstring, pnext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextBlob(this: *mut core::ffi::c_void, ixblob: u32, pnext: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataTables", "GetNextBlob", 19usize, |this| ((**(this as *const *const IMetaDataTables_Vtbl)).GetNextBlob)(this, ixblob, pnext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextGuid(this: *mut core::ffi::c_void, ixguid: u32, pnext: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataTables", "GetNextGuid", 20usize, |this| ((**(this as *const *const IMetaDataTables_Vtbl)).GetNextGuid)(this, ixguid, pnext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextUserString(this: *mut core::ffi::c_void, ixuserstring: u32, pnext: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataTables", "GetNextUserString", 21usize, |this| ((**(this as *const *const IMetaDataTables_Vtbl)).GetNextUserString)(this, ixuserstring, pnext), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetStringHeapSize,
+            GetBlobHeapSize,
+            GetGuidHeapSize,
+            GetUserStringHeapSize,
+            GetNumTables,
+            GetTableIndex,
+            GetTableInfo,
+            GetColumnInfo,
+            GetCodedTokenInfo,
+            GetRow,
+            GetColumn,
+            GetString,
+            GetBlob,
+            GetGuid,
+            GetUserString,
+            GetNextString,
+            GetNextBlob,
+            GetNextGuid,
+            GetNextUserString,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMetaDataTables as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMetaDataTables2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMetaDataStorage(this: *mut core::ffi::c_void, ppvmd: *const *const core::ffi::c_void, pcbmd: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataTables2", "GetMetaDataStorage", 22usize, |this| ((**(this as *const *const IMetaDataTables2_Vtbl)).GetMetaDataStorage)(this, ppvmd, pcbmd), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMetaDataStreamInfo(this: *mut core::ffi::c_void, ix: u32, ppchname: *const *const i8, ppv: *const *const core::ffi::c_void, pcb: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataTables2", "GetMetaDataStreamInfo", 23usize, |this| ((**(this as *const *const IMetaDataTables2_Vtbl)).GetMetaDataStreamInfo)(this, ix, ppchname, ppv, pcb), |result| Some(*result))
+        }
+        Self { base__: <IMetaDataTables_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMetaDataStorage, GetMetaDataStreamInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMetaDataTables2 as windows_core::Interface>::IID || <IMetaDataTables_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMetaDataValidate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ValidatorInit(this: *mut core::ffi::c_void, dwmoduletype: u32, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataValidate", "ValidatorInit", 3usize, |this| ((**(this as *const *const IMetaDataValidate_Vtbl)).ValidatorInit)(this, dwmoduletype, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn ValidateMetaData(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataValidate", "ValidateMetaData", 4usize, |this| ((**(this as *const *const IMetaDataValidate_Vtbl)).ValidateMetaData)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ValidatorInit, ValidateMetaData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMetaDataValidate as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMetaDataWinMDImport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetUntransformedTypeRefProps(this: *mut core::ffi::c_void, tr: u32, ptkresolutionscope: *mut u32, szname: windows_core::PWSTR, cchname: u32, pchname: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMetaDataWinMDImport", "GetUntransformedTypeRefProps", 3usize, |this| ((**(this as *const *const IMetaDataWinMDImport_Vtbl)).GetUntransformedTypeRefProps)(this, tr, ptkresolutionscope, szname, cchname, pchname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetUntransformedTypeRefProps }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMetaDataWinMDImport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
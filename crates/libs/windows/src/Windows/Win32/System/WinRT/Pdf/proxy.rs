@@ -0,0 +1,28 @@
+#[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi"))]
+impl windows_core::imp::ProxyVtable for IPdfRendererNative_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi"))]
+        unsafe extern "system" fn RenderPageToSurface(this: *mut core::ffi::c_void, pdfpage: *mut core::ffi::c_void, psurface: *mut core::ffi::c_void, offset: super::super::super::Foundation::POINT, prenderparams: *const PDF_RENDER_PARAMS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfRendererNative", "RenderPageToSurface", 3usize, |this| ((**(this as *const *const IPdfRendererNative_Vtbl)).RenderPageToSurface)(this, pdfpage, psurface, offset, prenderparams), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+        unsafe extern "system" fn RenderPageToDeviceContext(this: *mut core::ffi::c_void, pdfpage: *mut core::ffi::c_void, pd2ddevicecontext: *mut core::ffi::c_void, prenderparams: *const PDF_RENDER_PARAMS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPdfRendererNative", "RenderPageToDeviceContext", 4usize, |this| ((**(this as *const *const IPdfRendererNative_Vtbl)).RenderPageToDeviceContext)(this, pdfpage, pd2ddevicecontext, prenderparams), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi"))]
+            RenderPageToSurface,
+            #[cfg(not(all(feature = "Win32_Graphics_Direct2D_Common", feature = "Win32_Graphics_Dxgi")))]
+            RenderPageToSurface: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D_Common")]
+            RenderPageToDeviceContext,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D_Common"))]
+            RenderPageToDeviceContext: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPdfRendererNative as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -0,0 +1,210 @@
+#[cfg(feature = "Win32_Storage_Xps_Printing")]
+impl windows_core::imp::ProxyVtable for IPrintDocumentPageSource_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_Xps_Printing")]
+        unsafe extern "system" fn GetPreviewPageCollection(this: *mut core::ffi::c_void, docpackagetarget: *mut core::ffi::c_void, docpagecollection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintDocumentPageSource", "GetPreviewPageCollection", 3usize, |this| ((**(this as *const *const IPrintDocumentPageSource_Vtbl)).GetPreviewPageCollection)(this, docpackagetarget, docpagecollection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_Xps_Printing")]
+        unsafe extern "system" fn MakeDocument(this: *mut core::ffi::c_void, printtaskoptions: *mut core::ffi::c_void, docpackagetarget: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintDocumentPageSource", "MakeDocument", 4usize, |this| ((**(this as *const *const IPrintDocumentPageSource_Vtbl)).MakeDocument)(this, printtaskoptions, docpackagetarget), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_Xps_Printing")]
+            GetPreviewPageCollection,
+            #[cfg(not(feature = "Win32_Storage_Xps_Printing"))]
+            GetPreviewPageCollection: 0,
+            #[cfg(feature = "Win32_Storage_Xps_Printing")]
+            MakeDocument,
+            #[cfg(not(feature = "Win32_Storage_Xps_Printing"))]
+            MakeDocument: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintDocumentPageSource as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintManagerInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForWindow(this: *mut core::ffi::c_void, appwindow: super::super::super::Foundation::HWND, riid: *const windows_core::GUID, printmanager: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintManagerInterop", "GetForWindow", 6usize, |this| ((**(this as *const *const IPrintManagerInterop_Vtbl)).GetForWindow)(this, appwindow, riid, printmanager), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowPrintUIForWindowAsync(this: *mut core::ffi::c_void, appwindow: super::super::super::Foundation::HWND, riid: *const windows_core::GUID, asyncoperation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintManagerInterop", "ShowPrintUIForWindowAsync", 7usize, |this| ((**(this as *const *const IPrintManagerInterop_Vtbl)).ShowPrintUIForWindowAsync)(this, appwindow, riid, asyncoperation), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForWindow, ShowPrintUIForWindowAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintManagerInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintPreviewPageCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Paginate(this: *mut core::ffi::c_void, currentjobpage: u32, printtaskoptions: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPreviewPageCollection", "Paginate", 3usize, |this| ((**(this as *const *const IPrintPreviewPageCollection_Vtbl)).Paginate)(this, currentjobpage, printtaskoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn MakePage(this: *mut core::ffi::c_void, desiredjobpage: u32, width: f32, height: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintPreviewPageCollection", "MakePage", 4usize, |this| ((**(this as *const *const IPrintPreviewPageCollection_Vtbl)).MakePage)(this, desiredjobpage, width, height), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Paginate, MakePage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintPreviewPageCollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Printing", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IPrintWorkflowConfigurationNative_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Printing", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn PrinterQueue(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowConfigurationNative", "PrinterQueue", 3usize, |this| ((**(this as *const *const IPrintWorkflowConfigurationNative_Vtbl)).PrinterQueue)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Printing", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn DriverProperties(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowConfigurationNative", "DriverProperties", 4usize, |this| ((**(this as *const *const IPrintWorkflowConfigurationNative_Vtbl)).DriverProperties)(this, value), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Printing", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn UserProperties(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowConfigurationNative", "UserProperties", 5usize, |this| ((**(this as *const *const IPrintWorkflowConfigurationNative_Vtbl)).UserProperties)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Printing", feature = "Win32_System_Com"))]
+            PrinterQueue,
+            #[cfg(not(all(feature = "Win32_Graphics_Printing", feature = "Win32_System_Com")))]
+            PrinterQueue: 0,
+            #[cfg(all(feature = "Win32_Graphics_Printing", feature = "Win32_System_Com"))]
+            DriverProperties,
+            #[cfg(not(all(feature = "Win32_Graphics_Printing", feature = "Win32_System_Com")))]
+            DriverProperties: 0,
+            #[cfg(all(feature = "Win32_Graphics_Printing", feature = "Win32_System_Com"))]
+            UserProperties,
+            #[cfg(not(all(feature = "Win32_Graphics_Printing", feature = "Win32_System_Com")))]
+            UserProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowConfigurationNative as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_Xps")]
+impl windows_core::imp::ProxyVtable for IPrintWorkflowObjectModelSourceFileContentNative_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartXpsOMGeneration(this: *mut core::ffi::c_void, receiver: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowObjectModelSourceFileContentNative", "StartXpsOMGeneration", 3usize, |this| ((**(this as *const *const IPrintWorkflowObjectModelSourceFileContentNative_Vtbl)).StartXpsOMGeneration)(this, receiver), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_Xps")]
+        unsafe extern "system" fn ObjectFactory(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowObjectModelSourceFileContentNative", "ObjectFactory", 4usize, |this| ((**(this as *const *const IPrintWorkflowObjectModelSourceFileContentNative_Vtbl)).ObjectFactory)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartXpsOMGeneration,
+            #[cfg(feature = "Win32_Storage_Xps")]
+            ObjectFactory,
+            #[cfg(not(feature = "Win32_Storage_Xps"))]
+            ObjectFactory: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowObjectModelSourceFileContentNative as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Storage_Xps")]
+impl windows_core::imp::ProxyVtable for IPrintWorkflowXpsObjectModelTargetPackageNative_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Storage_Xps")]
+        unsafe extern "system" fn DocumentPackageTarget(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowXpsObjectModelTargetPackageNative", "DocumentPackageTarget", 3usize, |this| ((**(this as *const *const IPrintWorkflowXpsObjectModelTargetPackageNative_Vtbl)).DocumentPackageTarget)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Storage_Xps")]
+            DocumentPackageTarget,
+            #[cfg(not(feature = "Win32_Storage_Xps"))]
+            DocumentPackageTarget: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowXpsObjectModelTargetPackageNative as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Xps", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IPrintWorkflowXpsReceiver_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetDocumentSequencePrintTicket(this: *mut core::ffi::c_void, documentsequenceprintticket: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowXpsReceiver", "SetDocumentSequencePrintTicket", 3usize, |this| ((**(this as *const *const IPrintWorkflowXpsReceiver_Vtbl)).SetDocumentSequencePrintTicket)(this, documentsequenceprintticket), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDocumentSequenceUri(this: *mut core::ffi::c_void, documentsequenceuri: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowXpsReceiver", "SetDocumentSequenceUri", 4usize, |this| ((**(this as *const *const IPrintWorkflowXpsReceiver_Vtbl)).SetDocumentSequenceUri)(this, documentsequenceuri), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddDocumentData(this: *mut core::ffi::c_void, documentid: u32, documentprintticket: *mut core::ffi::c_void, documenturi: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowXpsReceiver", "AddDocumentData", 5usize, |this| ((**(this as *const *const IPrintWorkflowXpsReceiver_Vtbl)).AddDocumentData)(this, documentid, documentprintticket, documenturi), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Storage_Xps")]
+        unsafe extern "system" fn AddPage(this: *mut core::ffi::c_void, documentid: u32, pageid: u32, pagereference: *mut core::ffi::c_void, pageuri: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowXpsReceiver", "AddPage", 6usize, |this| ((**(this as *const *const IPrintWorkflowXpsReceiver_Vtbl)).AddPage)(this, documentid, pageid, pagereference, pageuri), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowXpsReceiver", "Close", 7usize, |this| ((**(this as *const *const IPrintWorkflowXpsReceiver_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            SetDocumentSequencePrintTicket,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetDocumentSequencePrintTicket: 0,
+            SetDocumentSequenceUri,
+            #[cfg(feature = "Win32_System_Com")]
+            AddDocumentData,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddDocumentData: 0,
+            #[cfg(feature = "Win32_Storage_Xps")]
+            AddPage,
+            #[cfg(not(feature = "Win32_Storage_Xps"))]
+            AddPage: 0,
+            Close,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowXpsReceiver as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Storage_Xps", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IPrintWorkflowXpsReceiver2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Failed(this: *mut core::ffi::c_void, xpserror: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintWorkflowXpsReceiver2", "Failed", 8usize, |this| ((**(this as *const *const IPrintWorkflowXpsReceiver2_Vtbl)).Failed)(this, xpserror), |result| Some(*result))
+        }
+        Self { base__: <IPrintWorkflowXpsReceiver_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Failed }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintWorkflowXpsReceiver2 as windows_core::Interface>::IID || <IPrintWorkflowXpsReceiver_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrinting3DManagerInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForWindow(this: *mut core::ffi::c_void, appwindow: super::super::super::Foundation::HWND, riid: *const windows_core::GUID, printmanager: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DManagerInterop", "GetForWindow", 6usize, |this| ((**(this as *const *const IPrinting3DManagerInterop_Vtbl)).GetForWindow)(this, appwindow, riid, printmanager), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowPrintUIForWindowAsync(this: *mut core::ffi::c_void, appwindow: super::super::super::Foundation::HWND, riid: *const windows_core::GUID, asyncoperation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrinting3DManagerInterop", "ShowPrintUIForWindowAsync", 7usize, |this| ((**(this as *const *const IPrinting3DManagerInterop_Vtbl)).ShowPrintUIForWindowAsync)(this, appwindow, riid, asyncoperation), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForWindow, ShowPrintUIForWindowAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrinting3DManagerInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -39,6 +39,7 @@ pub struct CreateProcessMethod(pub i32);
 impl windows_core::TypeKind for CreateProcessMethod {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CreateProcessMethod {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CreateProcessMethod").field(&self.0).finish()
@@ -46,3 +47,5 @@ impl core::fmt::Debug for CreateProcessMethod {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
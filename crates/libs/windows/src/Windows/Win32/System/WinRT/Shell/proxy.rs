@@ -0,0 +1,20 @@
+#[cfg(feature = "Win32_UI_Shell")]
+impl windows_core::imp::ProxyVtable for IDDEInitializer_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_Shell")]
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, fileextensionorprotocol: windows_core::PCWSTR, method: CreateProcessMethod, currentdirectory: windows_core::PCWSTR, exectarget: *mut core::ffi::c_void, site: *mut core::ffi::c_void, application: windows_core::PCWSTR, targetfile: windows_core::PCWSTR, arguments: windows_core::PCWSTR, verb: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDDEInitializer", "Initialize", 3usize, |this| ((**(this as *const *const IDDEInitializer_Vtbl)).Initialize)(this, fileextensionorprotocol, method, currentdirectory, exectarget, site, application, targetfile, arguments, verb), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_Shell")]
+            Initialize,
+            #[cfg(not(feature = "Win32_UI_Shell"))]
+            Initialize: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDDEInitializer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
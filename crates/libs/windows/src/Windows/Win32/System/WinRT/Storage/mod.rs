@@ -152,6 +152,7 @@ pub struct HANDLE_ACCESS_OPTIONS(pub i32);
 impl windows_core::TypeKind for HANDLE_ACCESS_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HANDLE_ACCESS_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HANDLE_ACCESS_OPTIONS").field(&self.0).finish()
@@ -196,6 +197,7 @@ pub struct HANDLE_CREATION_OPTIONS(pub i32);
 impl windows_core::TypeKind for HANDLE_CREATION_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HANDLE_CREATION_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HANDLE_CREATION_OPTIONS").field(&self.0).finish()
@@ -207,6 +209,7 @@ pub struct HANDLE_OPTIONS(pub u32);
 impl windows_core::TypeKind for HANDLE_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HANDLE_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HANDLE_OPTIONS").field(&self.0).finish()
@@ -251,6 +254,7 @@ pub struct HANDLE_SHARING_OPTIONS(pub i32);
 impl windows_core::TypeKind for HANDLE_SHARING_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HANDLE_SHARING_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HANDLE_SHARING_OPTIONS").field(&self.0).finish()
@@ -291,3 +295,5 @@ impl core::ops::Not for HANDLE_SHARING_OPTIONS {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,75 @@
+impl windows_core::imp::ProxyVtable for IOplockBreakingHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OplockBreaking(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOplockBreakingHandler", "OplockBreaking", 3usize, |this| ((**(this as *const *const IOplockBreakingHandler_Vtbl)).OplockBreaking)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OplockBreaking }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOplockBreakingHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRandomAccessStreamFileAccessMode_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMode(this: *mut core::ffi::c_void, fileaccessmode: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRandomAccessStreamFileAccessMode", "GetMode", 3usize, |this| ((**(this as *const *const IRandomAccessStreamFileAccessMode_Vtbl)).GetMode)(this, fileaccessmode), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRandomAccessStreamFileAccessMode as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageFolderHandleAccess_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, creationoptions: HANDLE_CREATION_OPTIONS, accessoptions: HANDLE_ACCESS_OPTIONS, sharingoptions: HANDLE_SHARING_OPTIONS, options: HANDLE_OPTIONS, oplockbreakinghandler: *mut core::ffi::c_void, interophandle: *mut super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageFolderHandleAccess", "Create", 3usize, |this| ((**(this as *const *const IStorageFolderHandleAccess_Vtbl)).Create)(this, filename, creationoptions, accessoptions, sharingoptions, options, oplockbreakinghandler, interophandle), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageFolderHandleAccess as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStorageItemHandleAccess_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, accessoptions: HANDLE_ACCESS_OPTIONS, sharingoptions: HANDLE_SHARING_OPTIONS, options: HANDLE_OPTIONS, oplockbreakinghandler: *mut core::ffi::c_void, interophandle: *mut super::super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStorageItemHandleAccess", "Create", 3usize, |this| ((**(this as *const *const IStorageItemHandleAccess_Vtbl)).Create)(this, accessoptions, sharingoptions, options, oplockbreakinghandler, interophandle), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Create }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStorageItemHandleAccess as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUnbufferedFileHandleOplockCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnBrokenCallback(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnbufferedFileHandleOplockCallback", "OnBrokenCallback", 3usize, |this| ((**(this as *const *const IUnbufferedFileHandleOplockCallback_Vtbl)).OnBrokenCallback)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnBrokenCallback }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUnbufferedFileHandleOplockCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUnbufferedFileHandleProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenUnbufferedFileHandle(this: *mut core::ffi::c_void, oplockbreakcallback: *mut core::ffi::c_void, filehandle: *mut usize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnbufferedFileHandleProvider", "OpenUnbufferedFileHandle", 3usize, |this| ((**(this as *const *const IUnbufferedFileHandleProvider_Vtbl)).OpenUnbufferedFileHandle)(this, oplockbreakcallback, filehandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn CloseUnbufferedFileHandle(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnbufferedFileHandleProvider", "CloseUnbufferedFileHandle", 4usize, |this| ((**(this as *const *const IUnbufferedFileHandleProvider_Vtbl)).CloseUnbufferedFileHandle)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OpenUnbufferedFileHandle, CloseUnbufferedFileHandle }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUnbufferedFileHandleProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
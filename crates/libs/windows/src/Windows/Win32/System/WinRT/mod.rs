@@ -1457,6 +1457,7 @@ pub struct ACTIVATIONTYPE(pub i32);
 impl windows_core::TypeKind for ACTIVATIONTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ACTIVATIONTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ACTIVATIONTYPE").field(&self.0).finish()
@@ -1468,6 +1469,7 @@ pub struct AgileReferenceOptions(pub i32);
 impl windows_core::TypeKind for AgileReferenceOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AgileReferenceOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AgileReferenceOptions").field(&self.0).finish()
@@ -1479,6 +1481,7 @@ pub struct BSOS_OPTIONS(pub i32);
 impl windows_core::TypeKind for BSOS_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BSOS_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BSOS_OPTIONS").field(&self.0).finish()
@@ -1490,6 +1493,7 @@ pub struct CASTING_CONNECTION_ERROR_STATUS(pub i32);
 impl windows_core::TypeKind for CASTING_CONNECTION_ERROR_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CASTING_CONNECTION_ERROR_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CASTING_CONNECTION_ERROR_STATUS").field(&self.0).finish()
@@ -1501,6 +1505,7 @@ pub struct CASTING_CONNECTION_STATE(pub i32);
 impl windows_core::TypeKind for CASTING_CONNECTION_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CASTING_CONNECTION_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CASTING_CONNECTION_STATE").field(&self.0).finish()
@@ -1512,6 +1517,7 @@ pub struct DISPATCHERQUEUE_THREAD_APARTMENTTYPE(pub i32);
 impl windows_core::TypeKind for DISPATCHERQUEUE_THREAD_APARTMENTTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPATCHERQUEUE_THREAD_APARTMENTTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPATCHERQUEUE_THREAD_APARTMENTTYPE").field(&self.0).finish()
@@ -1523,6 +1529,7 @@ pub struct DISPATCHERQUEUE_THREAD_TYPE(pub i32);
 impl windows_core::TypeKind for DISPATCHERQUEUE_THREAD_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPATCHERQUEUE_THREAD_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPATCHERQUEUE_THREAD_TYPE").field(&self.0).finish()
@@ -1534,6 +1541,7 @@ pub struct RO_ERROR_REPORTING_FLAGS(pub i32);
 impl windows_core::TypeKind for RO_ERROR_REPORTING_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RO_ERROR_REPORTING_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RO_ERROR_REPORTING_FLAGS").field(&self.0).finish()
@@ -1578,6 +1586,7 @@ pub struct RO_INIT_TYPE(pub i32);
 impl windows_core::TypeKind for RO_INIT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RO_INIT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RO_INIT_TYPE").field(&self.0).finish()
@@ -1589,13 +1598,15 @@ pub struct TrustLevel(pub i32);
 impl windows_core::TypeKind for TrustLevel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TrustLevel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TrustLevel").field(&self.0).finish()
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct APARTMENT_SHUTDOWN_REGISTRATION_COOKIE(pub *mut core::ffi::c_void);
 impl APARTMENT_SHUTDOWN_REGISTRATION_COOKIE {
     pub fn is_invalid(&self) -> bool {
@@ -1611,7 +1622,8 @@ impl windows_core::TypeKind for APARTMENT_SHUTDOWN_REGISTRATION_COOKIE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DispatcherQueueOptions {
     pub dwSize: u32,
     pub threadType: DISPATCHERQUEUE_THREAD_TYPE,
@@ -1626,7 +1638,8 @@ impl Default for DispatcherQueueOptions {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EventRegistrationToken {
     pub value: i64,
 }
@@ -1639,7 +1652,8 @@ impl Default for EventRegistrationToken {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HSTRING_BUFFER(pub *mut core::ffi::c_void);
 impl HSTRING_BUFFER {
     pub fn is_invalid(&self) -> bool {
@@ -1663,7 +1677,8 @@ impl windows_core::TypeKind for HSTRING_BUFFER {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HSTRING_HEADER {
     pub flags: u32,
     pub length: u32,
@@ -1680,7 +1695,8 @@ impl Default for HSTRING_HEADER {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RO_REGISTRATION_COOKIE(pub isize);
 impl Default for RO_REGISTRATION_COOKIE {
     fn default() -> Self {
@@ -1691,7 +1707,8 @@ impl windows_core::TypeKind for RO_REGISTRATION_COOKIE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ServerInformation {
     pub dwServerPid: u32,
     pub dwServerTid: u32,
@@ -1711,3 +1728,5 @@ pub type PINSPECT_HSTRING_CALLBACK2 = Option<unsafe extern "system" fn(context:
 pub type PINSPECT_MEMORY_CALLBACK = Option<unsafe extern "system" fn(context: *const core::ffi::c_void, readaddress: usize, length: u32, buffer: *mut u8) -> windows_core::HRESULT>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
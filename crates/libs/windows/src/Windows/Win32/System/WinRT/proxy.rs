@@ -0,0 +1,584 @@
+impl windows_core::imp::ProxyVtable for IAccountsSettingsPaneInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForWindow(this: *mut core::ffi::c_void, appwindow: super::super::Foundation::HWND, riid: *const windows_core::GUID, accountssettingspane: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccountsSettingsPaneInterop", "GetForWindow", 6usize, |this| ((**(this as *const *const IAccountsSettingsPaneInterop_Vtbl)).GetForWindow)(this, appwindow, riid, accountssettingspane), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowManageAccountsForWindowAsync(this: *mut core::ffi::c_void, appwindow: super::super::Foundation::HWND, riid: *const windows_core::GUID, asyncaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccountsSettingsPaneInterop", "ShowManageAccountsForWindowAsync", 7usize, |this| ((**(this as *const *const IAccountsSettingsPaneInterop_Vtbl)).ShowManageAccountsForWindowAsync)(this, appwindow, riid, asyncaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowAddAccountForWindowAsync(this: *mut core::ffi::c_void, appwindow: super::super::Foundation::HWND, riid: *const windows_core::GUID, asyncaction: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccountsSettingsPaneInterop", "ShowAddAccountForWindowAsync", 8usize, |this| ((**(this as *const *const IAccountsSettingsPaneInterop_Vtbl)).ShowAddAccountForWindowAsync)(this, appwindow, riid, asyncaction), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetForWindow,
+            ShowManageAccountsForWindowAsync,
+            ShowAddAccountForWindowAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccountsSettingsPaneInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActivationFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ActivateInstance(this: *mut core::ffi::c_void, instance: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActivationFactory", "ActivateInstance", 6usize, |this| ((**(this as *const *const IActivationFactory_Vtbl)).ActivateInstance)(this, instance), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ActivateInstance }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActivationFactory as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAgileReference_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Resolve(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppvobjectreference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAgileReference", "Resolve", 3usize, |this| ((**(this as *const *const IAgileReference_Vtbl)).Resolve)(this, riid, ppvobjectreference), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Resolve }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAgileReference as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IApartmentShutdown_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnUninitialize(this: *mut core::ffi::c_void, ui64apartmentidentifier: u64) {
+            windows_core::imp::proxy_call(this, "IApartmentShutdown", "OnUninitialize", 3usize, |this| ((**(this as *const *const IApartmentShutdown_Vtbl)).OnUninitialize)(this, ui64apartmentidentifier), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnUninitialize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IApartmentShutdown as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAppServiceConnectionExtendedExecution_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenForExtendedExecutionAsync(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, operation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAppServiceConnectionExtendedExecution", "OpenForExtendedExecutionAsync", 3usize, |this| ((**(this as *const *const IAppServiceConnectionExtendedExecution_Vtbl)).OpenForExtendedExecutionAsync)(this, riid, operation), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OpenForExtendedExecutionAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAppServiceConnectionExtendedExecution as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IBufferByteAccess_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Buffer(this: *mut core::ffi::c_void, value: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBufferByteAccess", "Buffer", 3usize, |this| ((**(this as *const *const IBufferByteAccess_Vtbl)).Buffer)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Buffer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBufferByteAccess as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICastingController_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, castingengine: *mut core::ffi::c_void, castingsource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingController", "Initialize", 3usize, |this| ((**(this as *const *const ICastingController_Vtbl)).Initialize)(this, castingengine, castingsource), |result| Some(*result))
+        }
+        unsafe extern "system" fn Connect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingController", "Connect", 4usize, |this| ((**(this as *const *const ICastingController_Vtbl)).Connect)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disconnect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingController", "Disconnect", 5usize, |this| ((**(this as *const *const ICastingController_Vtbl)).Disconnect)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Advise(this: *mut core::ffi::c_void, eventhandler: *mut core::ffi::c_void, cookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingController", "Advise", 6usize, |this| ((**(this as *const *const ICastingController_Vtbl)).Advise)(this, eventhandler, cookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnAdvise(this: *mut core::ffi::c_void, cookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingController", "UnAdvise", 7usize, |this| ((**(this as *const *const ICastingController_Vtbl)).UnAdvise)(this, cookie), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, Connect, Disconnect, Advise, UnAdvise }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICastingController as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICastingEventHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnStateChanged(this: *mut core::ffi::c_void, newstate: CASTING_CONNECTION_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingEventHandler", "OnStateChanged", 3usize, |this| ((**(this as *const *const ICastingEventHandler_Vtbl)).OnStateChanged)(this, newstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnError(this: *mut core::ffi::c_void, errorstatus: CASTING_CONNECTION_ERROR_STATUS, errormessage: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingEventHandler", "OnError", 4usize, |this| ((**(this as *const *const ICastingEventHandler_Vtbl)).OnError)(this, errorstatus, errormessage), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnStateChanged, OnError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICastingEventHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for ICastingSourceInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetController(this: *mut core::ffi::c_void, controller: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingSourceInfo", "GetController", 3usize, |this| ((**(this as *const *const ICastingSourceInfo_Vtbl)).GetController)(this, controller), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetProperties(this: *mut core::ffi::c_void, props: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICastingSourceInfo", "GetProperties", 4usize, |this| ((**(this as *const *const ICastingSourceInfo_Vtbl)).GetProperties)(this, props), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetController,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetProperties,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetProperties: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICastingSourceInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreInputInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetInputSource(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputInterop", "SetInputSource", 3usize, |this| ((**(this as *const *const ICoreInputInterop_Vtbl)).SetInputSource)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMessageHandled(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputInterop", "SetMessageHandled", 4usize, |this| ((**(this as *const *const ICoreInputInterop_Vtbl)).SetMessageHandled)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetInputSource, SetMessageHandled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreInputInterop as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreInputInterop2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WindowHandle(this: *mut core::ffi::c_void, window: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputInterop2", "WindowHandle", 6usize, |this| ((**(this as *const *const ICoreInputInterop2_Vtbl)).WindowHandle)(this, window), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeHostingContext(this: *mut core::ffi::c_void, newparentwindow: super::super::Foundation::HWND, newviewinstanceid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreInputInterop2", "ChangeHostingContext", 7usize, |this| ((**(this as *const *const ICoreInputInterop2_Vtbl)).ChangeHostingContext)(this, newparentwindow, newviewinstanceid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WindowHandle, ChangeHostingContext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreInputInterop2 as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreWindowAdapterInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AppActivationClientAdapter(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowAdapterInterop", "AppActivationClientAdapter", 6usize, |this| ((**(this as *const *const ICoreWindowAdapterInterop_Vtbl)).AppActivationClientAdapter)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn ApplicationViewClientAdapter(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowAdapterInterop", "ApplicationViewClientAdapter", 7usize, |this| ((**(this as *const *const ICoreWindowAdapterInterop_Vtbl)).ApplicationViewClientAdapter)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn CoreApplicationViewClientAdapter(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowAdapterInterop", "CoreApplicationViewClientAdapter", 8usize, |this| ((**(this as *const *const ICoreWindowAdapterInterop_Vtbl)).CoreApplicationViewClientAdapter)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn HoloViewClientAdapter(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowAdapterInterop", "HoloViewClientAdapter", 9usize, |this| ((**(this as *const *const ICoreWindowAdapterInterop_Vtbl)).HoloViewClientAdapter)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn PositionerClientAdapter(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowAdapterInterop", "PositionerClientAdapter", 10usize, |this| ((**(this as *const *const ICoreWindowAdapterInterop_Vtbl)).PositionerClientAdapter)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemNavigationClientAdapter(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowAdapterInterop", "SystemNavigationClientAdapter", 11usize, |this| ((**(this as *const *const ICoreWindowAdapterInterop_Vtbl)).SystemNavigationClientAdapter)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn TitleBarClientAdapter(this: *mut core::ffi::c_void, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowAdapterInterop", "TitleBarClientAdapter", 12usize, |this| ((**(this as *const *const ICoreWindowAdapterInterop_Vtbl)).TitleBarClientAdapter)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWindowClientAdapter(this: *mut core::ffi::c_void, value: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowAdapterInterop", "SetWindowClientAdapter", 13usize, |this| ((**(this as *const *const ICoreWindowAdapterInterop_Vtbl)).SetWindowClientAdapter)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AppActivationClientAdapter,
+            ApplicationViewClientAdapter,
+            CoreApplicationViewClientAdapter,
+            HoloViewClientAdapter,
+            PositionerClientAdapter,
+            SystemNavigationClientAdapter,
+            TitleBarClientAdapter,
+            SetWindowClientAdapter,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreWindowAdapterInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreWindowComponentInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConfigureComponentInput(this: *mut core::ffi::c_void, hostviewinstanceid: u32, hwndhost: super::super::Foundation::HWND, inputsourcevisual: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowComponentInterop", "ConfigureComponentInput", 3usize, |this| ((**(this as *const *const ICoreWindowComponentInterop_Vtbl)).ConfigureComponentInput)(this, hostviewinstanceid, hwndhost, inputsourcevisual), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetViewInstanceId(this: *mut core::ffi::c_void, componentviewinstanceid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowComponentInterop", "GetViewInstanceId", 4usize, |this| ((**(this as *const *const ICoreWindowComponentInterop_Vtbl)).GetViewInstanceId)(this, componentviewinstanceid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ConfigureComponentInput, GetViewInstanceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreWindowComponentInterop as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreWindowInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WindowHandle(this: *mut core::ffi::c_void, hwnd: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowInterop", "WindowHandle", 3usize, |this| ((**(this as *const *const ICoreWindowInterop_Vtbl)).WindowHandle)(this, hwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMessageHandled(this: *mut core::ffi::c_void, value: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreWindowInterop", "SetMessageHandled", 4usize, |this| ((**(this as *const *const ICoreWindowInterop_Vtbl)).SetMessageHandled)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, WindowHandle, SetMessageHandled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreWindowInterop as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICorrelationVectorInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LastCorrelationVectorForThread(this: *mut core::ffi::c_void, cv: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorrelationVectorInformation", "LastCorrelationVectorForThread", 6usize, |this| ((**(this as *const *const ICorrelationVectorInformation_Vtbl)).LastCorrelationVectorForThread)(this, cv), |result| Some(*result))
+        }
+        unsafe extern "system" fn NextCorrelationVectorForThread(this: *mut core::ffi::c_void, cv: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorrelationVectorInformation", "NextCorrelationVectorForThread", 7usize, |this| ((**(this as *const *const ICorrelationVectorInformation_Vtbl)).NextCorrelationVectorForThread)(this, cv), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNextCorrelationVectorForThread(this: *mut core::ffi::c_void, cv: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorrelationVectorInformation", "SetNextCorrelationVectorForThread", 8usize, |this| ((**(this as *const *const ICorrelationVectorInformation_Vtbl)).SetNextCorrelationVectorForThread)(this, cv), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            LastCorrelationVectorForThread,
+            NextCorrelationVectorForThread,
+            SetNextCorrelationVectorForThread,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorrelationVectorInformation as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICorrelationVectorSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CorrelationVector(this: *mut core::ffi::c_void, cv: *mut core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICorrelationVectorSource", "CorrelationVector", 3usize, |this| ((**(this as *const *const ICorrelationVectorSource_Vtbl)).CorrelationVector)(this, cv), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CorrelationVector }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICorrelationVectorSource as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDragDropManagerInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForWindow(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDragDropManagerInterop", "GetForWindow", 6usize, |this| ((**(this as *const *const IDragDropManagerInterop_Vtbl)).GetForWindow)(this, hwnd, riid, ppv), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForWindow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDragDropManagerInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHolographicSpaceInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateForWindow(this: *mut core::ffi::c_void, window: super::super::Foundation::HWND, riid: *const windows_core::GUID, holographicspace: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHolographicSpaceInterop", "CreateForWindow", 6usize, |this| ((**(this as *const *const IHolographicSpaceInterop_Vtbl)).CreateForWindow)(this, window, riid, holographicspace), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateForWindow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHolographicSpaceInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInputPaneInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForWindow(this: *mut core::ffi::c_void, appwindow: super::super::Foundation::HWND, riid: *const windows_core::GUID, inputpane: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputPaneInterop", "GetForWindow", 6usize, |this| ((**(this as *const *const IInputPaneInterop_Vtbl)).GetForWindow)(this, appwindow, riid, inputpane), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForWindow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInputPaneInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILanguageExceptionErrorInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLanguageException(this: *mut core::ffi::c_void, languageexception: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageExceptionErrorInfo", "GetLanguageException", 3usize, |this| ((**(this as *const *const ILanguageExceptionErrorInfo_Vtbl)).GetLanguageException)(this, languageexception), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetLanguageException }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILanguageExceptionErrorInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILanguageExceptionErrorInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPreviousLanguageExceptionErrorInfo(this: *mut core::ffi::c_void, previouslanguageexceptionerrorinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageExceptionErrorInfo2", "GetPreviousLanguageExceptionErrorInfo", 4usize, |this| ((**(this as *const *const ILanguageExceptionErrorInfo2_Vtbl)).GetPreviousLanguageExceptionErrorInfo)(this, previouslanguageexceptionerrorinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn CapturePropagationContext(this: *mut core::ffi::c_void, languageexception: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageExceptionErrorInfo2", "CapturePropagationContext", 5usize, |this| ((**(this as *const *const ILanguageExceptionErrorInfo2_Vtbl)).CapturePropagationContext)(this, languageexception), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropagationContextHead(this: *mut core::ffi::c_void, propagatedlanguageexceptionerrorinfohead: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageExceptionErrorInfo2", "GetPropagationContextHead", 6usize, |this| ((**(this as *const *const ILanguageExceptionErrorInfo2_Vtbl)).GetPropagationContextHead)(this, propagatedlanguageexceptionerrorinfohead), |result| Some(*result))
+        }
+        Self {
+            base__: <ILanguageExceptionErrorInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPreviousLanguageExceptionErrorInfo,
+            CapturePropagationContext,
+            GetPropagationContextHead,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILanguageExceptionErrorInfo2 as windows_core::Interface>::IID || <ILanguageExceptionErrorInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILanguageExceptionStackBackTrace_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStackBackTrace(this: *mut core::ffi::c_void, maxframestocapture: u32, stackbacktrace: *mut usize, framescaptured: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageExceptionStackBackTrace", "GetStackBackTrace", 3usize, |this| ((**(this as *const *const ILanguageExceptionStackBackTrace_Vtbl)).GetStackBackTrace)(this, maxframestocapture, stackbacktrace, framescaptured), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStackBackTrace }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILanguageExceptionStackBackTrace as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILanguageExceptionTransform_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTransformedRestrictedErrorInfo(this: *mut core::ffi::c_void, restrictederrorinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILanguageExceptionTransform", "GetTransformedRestrictedErrorInfo", 3usize, |this| ((**(this as *const *const ILanguageExceptionTransform_Vtbl)).GetTransformedRestrictedErrorInfo)(this, restrictederrorinfo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetTransformedRestrictedErrorInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILanguageExceptionTransform as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMemoryBufferByteAccess_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBuffer(this: *mut core::ffi::c_void, value: *mut *mut u8, capacity: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMemoryBufferByteAccess", "GetBuffer", 3usize, |this| ((**(this as *const *const IMemoryBufferByteAccess_Vtbl)).GetBuffer)(this, value, capacity), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetBuffer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMemoryBufferByteAccess as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMessageDispatcher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PumpMessages(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMessageDispatcher", "PumpMessages", 6usize, |this| ((**(this as *const *const IMessageDispatcher_Vtbl)).PumpMessages)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PumpMessages }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMessageDispatcher as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPlayToManagerInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForWindow(this: *mut core::ffi::c_void, appwindow: super::super::Foundation::HWND, riid: *const windows_core::GUID, playtomanager: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToManagerInterop", "GetForWindow", 6usize, |this| ((**(this as *const *const IPlayToManagerInterop_Vtbl)).GetForWindow)(this, appwindow, riid, playtomanager), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowPlayToUIForWindow(this: *mut core::ffi::c_void, appwindow: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPlayToManagerInterop", "ShowPlayToUIForWindow", 7usize, |this| ((**(this as *const *const IPlayToManagerInterop_Vtbl)).ShowPlayToUIForWindow)(this, appwindow), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForWindow, ShowPlayToUIForWindow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPlayToManagerInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRestrictedErrorInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetErrorDetails(this: *mut core::ffi::c_void, description: *mut core::mem::MaybeUninit<windows_core::BSTR>, error: *mut windows_core::HRESULT, restricteddescription: *mut core::mem::MaybeUninit<windows_core::BSTR>, capabilitysid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRestrictedErrorInfo", "GetErrorDetails", 3usize, |this| ((**(this as *const *const IRestrictedErrorInfo_Vtbl)).GetErrorDetails)(this, description, error, restricteddescription, capabilitysid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReference(this: *mut core::ffi::c_void, reference: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRestrictedErrorInfo", "GetReference", 4usize, |this| ((**(this as *const *const IRestrictedErrorInfo_Vtbl)).GetReference)(this, reference), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetErrorDetails, GetReference }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRestrictedErrorInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IShareWindowCommandEventArgsInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetWindow(this: *mut core::ffi::c_void, value: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareWindowCommandEventArgsInterop", "GetWindow", 3usize, |this| ((**(this as *const *const IShareWindowCommandEventArgsInterop_Vtbl)).GetWindow)(this, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetWindow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IShareWindowCommandEventArgsInterop as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IShareWindowCommandSourceInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForWindow(this: *mut core::ffi::c_void, appwindow: super::super::Foundation::HWND, riid: *const windows_core::GUID, sharewindowcommandsource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IShareWindowCommandSourceInterop", "GetForWindow", 3usize, |this| ((**(this as *const *const IShareWindowCommandSourceInterop_Vtbl)).GetForWindow)(this, appwindow, riid, sharewindowcommandsource), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForWindow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IShareWindowCommandSourceInterop as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpatialInteractionManagerInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForWindow(this: *mut core::ffi::c_void, window: super::super::Foundation::HWND, riid: *const windows_core::GUID, spatialinteractionmanager: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpatialInteractionManagerInterop", "GetForWindow", 6usize, |this| ((**(this as *const *const ISpatialInteractionManagerInterop_Vtbl)).GetForWindow)(this, window, riid, spatialinteractionmanager), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForWindow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpatialInteractionManagerInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISystemMediaTransportControlsInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForWindow(this: *mut core::ffi::c_void, appwindow: super::super::Foundation::HWND, riid: *const windows_core::GUID, mediatransportcontrol: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISystemMediaTransportControlsInterop", "GetForWindow", 6usize, |this| ((**(this as *const *const ISystemMediaTransportControlsInterop_Vtbl)).GetForWindow)(this, appwindow, riid, mediatransportcontrol), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForWindow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISystemMediaTransportControlsInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIViewSettingsInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForWindow(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIViewSettingsInterop", "GetForWindow", 6usize, |this| ((**(this as *const *const IUIViewSettingsInterop_Vtbl)).GetForWindow)(this, hwnd, riid, ppv), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForWindow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIViewSettingsInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserActivityInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateSessionForWindow(this: *mut core::ffi::c_void, window: super::super::Foundation::HWND, iid: *const windows_core::GUID, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityInterop", "CreateSessionForWindow", 6usize, |this| ((**(this as *const *const IUserActivityInterop_Vtbl)).CreateSessionForWindow)(this, window, iid, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateSessionForWindow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserActivityInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserActivityRequestManagerInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForWindow(this: *mut core::ffi::c_void, window: super::super::Foundation::HWND, iid: *const windows_core::GUID, value: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivityRequestManagerInterop", "GetForWindow", 6usize, |this| ((**(this as *const *const IUserActivityRequestManagerInterop_Vtbl)).GetForWindow)(this, window, iid, value), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForWindow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserActivityRequestManagerInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserActivitySourceHostInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetActivitySourceHost(this: *mut core::ffi::c_void, activitysourcehost: core::mem::MaybeUninit<windows_core::HSTRING>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserActivitySourceHostInterop", "SetActivitySourceHost", 6usize, |this| ((**(this as *const *const IUserActivitySourceHostInterop_Vtbl)).SetActivitySourceHost)(this, activitysourcehost), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetActivitySourceHost }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserActivitySourceHostInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUserConsentVerifierInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestVerificationForWindowAsync(this: *mut core::ffi::c_void, appwindow: super::super::Foundation::HWND, message: core::mem::MaybeUninit<windows_core::HSTRING>, riid: *const windows_core::GUID, asyncoperation: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUserConsentVerifierInterop", "RequestVerificationForWindowAsync", 6usize, |this| ((**(this as *const *const IUserConsentVerifierInterop_Vtbl)).RequestVerificationForWindowAsync)(this, appwindow, message, riid, asyncoperation), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestVerificationForWindowAsync }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUserConsentVerifierInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWeakReference_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Resolve(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, objectreference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWeakReference", "Resolve", 3usize, |this| ((**(this as *const *const IWeakReference_Vtbl)).Resolve)(this, riid, objectreference), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Resolve }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWeakReference as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWeakReferenceSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetWeakReference(this: *mut core::ffi::c_void, weakreference: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWeakReferenceSource", "GetWeakReference", 3usize, |this| ((**(this as *const *const IWeakReferenceSource_Vtbl)).GetWeakReference)(this, weakreference), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetWeakReference }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWeakReferenceSource as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebAuthenticationCoreManagerInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestTokenForWindowAsync(this: *mut core::ffi::c_void, appwindow: super::super::Foundation::HWND, request: *mut core::ffi::c_void, riid: *const windows_core::GUID, asyncinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAuthenticationCoreManagerInterop", "RequestTokenForWindowAsync", 6usize, |this| ((**(this as *const *const IWebAuthenticationCoreManagerInterop_Vtbl)).RequestTokenForWindowAsync)(this, appwindow, request, riid, asyncinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestTokenWithWebAccountForWindowAsync(this: *mut core::ffi::c_void, appwindow: super::super::Foundation::HWND, request: *mut core::ffi::c_void, webaccount: *mut core::ffi::c_void, riid: *const windows_core::GUID, asyncinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebAuthenticationCoreManagerInterop", "RequestTokenWithWebAccountForWindowAsync", 7usize, |this| ((**(this as *const *const IWebAuthenticationCoreManagerInterop_Vtbl)).RequestTokenWithWebAccountForWindowAsync)(this, appwindow, request, webaccount, riid, asyncinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestTokenForWindowAsync,
+            RequestTokenWithWebAccountForWindowAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebAuthenticationCoreManagerInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
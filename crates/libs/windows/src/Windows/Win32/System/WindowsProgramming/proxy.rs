@@ -0,0 +1,214 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ICameraUIControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Show(this: *mut core::ffi::c_void, pwindow: *mut core::ffi::c_void, mode: CameraUIControlMode, selectionmode: CameraUIControlLinearSelectionMode, capturemode: CameraUIControlCaptureMode, photoformat: CameraUIControlPhotoFormat, videoformat: CameraUIControlVideoFormat, bhasclosebutton: super::super::Foundation::BOOL, peventcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraUIControl", "Show", 3usize, |this| ((**(this as *const *const ICameraUIControl_Vtbl)).Show)(this, pwindow, mode, selectionmode, capturemode, photoformat, videoformat, bhasclosebutton, peventcallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraUIControl", "Close", 4usize, |this| ((**(this as *const *const ICameraUIControl_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Suspend(this: *mut core::ffi::c_void, pbdeferralrequired: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraUIControl", "Suspend", 5usize, |this| ((**(this as *const *const ICameraUIControl_Vtbl)).Suspend)(this, pbdeferralrequired), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraUIControl", "Resume", 6usize, |this| ((**(this as *const *const ICameraUIControl_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentViewType(this: *mut core::ffi::c_void, pviewtype: *mut CameraUIControlViewType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraUIControl", "GetCurrentViewType", 7usize, |this| ((**(this as *const *const ICameraUIControl_Vtbl)).GetCurrentViewType)(this, pviewtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActiveItem(this: *mut core::ffi::c_void, pbstractiveitempath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraUIControl", "GetActiveItem", 8usize, |this| ((**(this as *const *const ICameraUIControl_Vtbl)).GetActiveItem)(this, pbstractiveitempath), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSelectedItems(this: *mut core::ffi::c_void, ppselecteditempaths: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraUIControl", "GetSelectedItems", 9usize, |this| ((**(this as *const *const ICameraUIControl_Vtbl)).GetSelectedItems)(this, ppselecteditempaths), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveCapturedItem(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICameraUIControl", "RemoveCapturedItem", 10usize, |this| ((**(this as *const *const ICameraUIControl_Vtbl)).RemoveCapturedItem)(this, pszpath), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Show,
+            Close,
+            Suspend,
+            Resume,
+            GetCurrentViewType,
+            GetActiveItem,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSelectedItems,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSelectedItems: 0,
+            RemoveCapturedItem,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICameraUIControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICameraUIControlEventCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnStartupComplete(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ICameraUIControlEventCallback", "OnStartupComplete", 3usize, |this| ((**(this as *const *const ICameraUIControlEventCallback_Vtbl)).OnStartupComplete)(this), |_| None)
+        }
+        unsafe extern "system" fn OnSuspendComplete(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ICameraUIControlEventCallback", "OnSuspendComplete", 4usize, |this| ((**(this as *const *const ICameraUIControlEventCallback_Vtbl)).OnSuspendComplete)(this), |_| None)
+        }
+        unsafe extern "system" fn OnItemCaptured(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR) {
+            windows_core::imp::proxy_call(this, "ICameraUIControlEventCallback", "OnItemCaptured", 5usize, |this| ((**(this as *const *const ICameraUIControlEventCallback_Vtbl)).OnItemCaptured)(this, pszpath), |_| None)
+        }
+        unsafe extern "system" fn OnItemDeleted(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR) {
+            windows_core::imp::proxy_call(this, "ICameraUIControlEventCallback", "OnItemDeleted", 6usize, |this| ((**(this as *const *const ICameraUIControlEventCallback_Vtbl)).OnItemDeleted)(this, pszpath), |_| None)
+        }
+        unsafe extern "system" fn OnClosed(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ICameraUIControlEventCallback", "OnClosed", 7usize, |this| ((**(this as *const *const ICameraUIControlEventCallback_Vtbl)).OnClosed)(this), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnStartupComplete,
+            OnSuspendComplete,
+            OnItemCaptured,
+            OnItemDeleted,
+            OnClosed,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICameraUIControlEventCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClipServiceNotificationHelper_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowToast(this: *mut core::ffi::c_void, titletext: core::mem::MaybeUninit<windows_core::BSTR>, bodytext: core::mem::MaybeUninit<windows_core::BSTR>, packagename: core::mem::MaybeUninit<windows_core::BSTR>, appid: core::mem::MaybeUninit<windows_core::BSTR>, launchcommand: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClipServiceNotificationHelper", "ShowToast", 3usize, |this| ((**(this as *const *const IClipServiceNotificationHelper_Vtbl)).ShowToast)(this, titletext, bodytext, packagename, appid, launchcommand), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShowToast }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClipServiceNotificationHelper as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IContainerActivationHelper_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CanActivateClientVM(this: *mut core::ffi::c_void, isallowed: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IContainerActivationHelper", "CanActivateClientVM", 3usize, |this| ((**(this as *const *const IContainerActivationHelper_Vtbl)).CanActivateClientVM)(this, isallowed), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CanActivateClientVM }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IContainerActivationHelper as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDefaultBrowserSyncSettings_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IDefaultBrowserSyncSettings", "IsEnabled", 3usize, |this| ((**(this as *const *const IDefaultBrowserSyncSettings_Vtbl)).IsEnabled)(this), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsEnabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDefaultBrowserSyncSettings as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDeleteBrowsingHistory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeleteBrowsingHistory(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeleteBrowsingHistory", "DeleteBrowsingHistory", 3usize, |this| ((**(this as *const *const IDeleteBrowsingHistory_Vtbl)).DeleteBrowsingHistory)(this, dwflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeleteBrowsingHistory }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeleteBrowsingHistory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Ole")]
+impl windows_core::imp::ProxyVtable for IEditionUpgradeBroker_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn InitializeParentWindow(this: *mut core::ffi::c_void, parenthandle: super::Ole::OLE_HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEditionUpgradeBroker", "InitializeParentWindow", 3usize, |this| ((**(this as *const *const IEditionUpgradeBroker_Vtbl)).InitializeParentWindow)(this, parenthandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateOperatingSystem(this: *mut core::ffi::c_void, parameter: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEditionUpgradeBroker", "UpdateOperatingSystem", 4usize, |this| ((**(this as *const *const IEditionUpgradeBroker_Vtbl)).UpdateOperatingSystem)(this, parameter), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowProductKeyUI(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEditionUpgradeBroker", "ShowProductKeyUI", 5usize, |this| ((**(this as *const *const IEditionUpgradeBroker_Vtbl)).ShowProductKeyUI)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanUpgrade(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEditionUpgradeBroker", "CanUpgrade", 6usize, |this| ((**(this as *const *const IEditionUpgradeBroker_Vtbl)).CanUpgrade)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Ole")]
+            InitializeParentWindow,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            InitializeParentWindow: 0,
+            UpdateOperatingSystem,
+            ShowProductKeyUI,
+            CanUpgrade,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEditionUpgradeBroker as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEditionUpgradeHelper_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CanUpgrade(this: *mut core::ffi::c_void, isallowed: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEditionUpgradeHelper", "CanUpgrade", 3usize, |this| ((**(this as *const *const IEditionUpgradeHelper_Vtbl)).CanUpgrade)(this, isallowed), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateOperatingSystem(this: *mut core::ffi::c_void, contentid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEditionUpgradeHelper", "UpdateOperatingSystem", 4usize, |this| ((**(this as *const *const IEditionUpgradeHelper_Vtbl)).UpdateOperatingSystem)(this, contentid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowProductKeyUI(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEditionUpgradeHelper", "ShowProductKeyUI", 5usize, |this| ((**(this as *const *const IEditionUpgradeHelper_Vtbl)).ShowProductKeyUI)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOsProductContentId(this: *mut core::ffi::c_void, contentid: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEditionUpgradeHelper", "GetOsProductContentId", 6usize, |this| ((**(this as *const *const IEditionUpgradeHelper_Vtbl)).GetOsProductContentId)(this, contentid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGenuineLocalStatus(this: *mut core::ffi::c_void, isgenuine: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEditionUpgradeHelper", "GetGenuineLocalStatus", 7usize, |this| ((**(this as *const *const IEditionUpgradeHelper_Vtbl)).GetGenuineLocalStatus)(this, isgenuine), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CanUpgrade,
+            UpdateOperatingSystem,
+            ShowProductKeyUI,
+            GetOsProductContentId,
+            GetGenuineLocalStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEditionUpgradeHelper as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFClipNotificationHelper_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowSystemDialog(this: *mut core::ffi::c_void, titletext: core::mem::MaybeUninit<windows_core::BSTR>, bodytext: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFClipNotificationHelper", "ShowSystemDialog", 3usize, |this| ((**(this as *const *const IFClipNotificationHelper_Vtbl)).ShowSystemDialog)(this, titletext, bodytext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShowSystemDialog }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFClipNotificationHelper as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowsLockModeHelper_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSMode(this: *mut core::ffi::c_void, issmode: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowsLockModeHelper", "GetSMode", 3usize, |this| ((**(this as *const *const IWindowsLockModeHelper_Vtbl)).GetSMode)(this, issmode), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSMode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowsLockModeHelper as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -2802,6 +2802,7 @@ pub struct CONFLICT_RESOLUTION_POLICY(pub i32);
 impl windows_core::TypeKind for CONFLICT_RESOLUTION_POLICY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CONFLICT_RESOLUTION_POLICY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CONFLICT_RESOLUTION_POLICY").field(&self.0).finish()
@@ -2813,6 +2814,7 @@ pub struct CONSTRAINT_CONFLICT_REASON(pub i32);
 impl windows_core::TypeKind for CONSTRAINT_CONFLICT_REASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CONSTRAINT_CONFLICT_REASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CONSTRAINT_CONFLICT_REASON").field(&self.0).finish()
@@ -2824,6 +2826,7 @@ pub struct FILTERING_TYPE(pub i32);
 impl windows_core::TypeKind for FILTERING_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FILTERING_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FILTERING_TYPE").field(&self.0).finish()
@@ -2835,6 +2838,7 @@ pub struct FILTER_COMBINATION_TYPE(pub i32);
 impl windows_core::TypeKind for FILTER_COMBINATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FILTER_COMBINATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FILTER_COMBINATION_TYPE").field(&self.0).finish()
@@ -2846,6 +2850,7 @@ pub struct KNOWLEDGE_COOKIE_COMPARISON_RESULT(pub i32);
 impl windows_core::TypeKind for KNOWLEDGE_COOKIE_COMPARISON_RESULT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KNOWLEDGE_COOKIE_COMPARISON_RESULT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KNOWLEDGE_COOKIE_COMPARISON_RESULT").field(&self.0).finish()
@@ -2857,6 +2862,7 @@ pub struct SYNC_CONSTRAINT_RESOLVE_ACTION(pub i32);
 impl windows_core::TypeKind for SYNC_CONSTRAINT_RESOLVE_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNC_CONSTRAINT_RESOLVE_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNC_CONSTRAINT_RESOLVE_ACTION").field(&self.0).finish()
@@ -2868,6 +2874,7 @@ pub struct SYNC_FULL_ENUMERATION_ACTION(pub i32);
 impl windows_core::TypeKind for SYNC_FULL_ENUMERATION_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNC_FULL_ENUMERATION_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNC_FULL_ENUMERATION_ACTION").field(&self.0).finish()
@@ -2879,6 +2886,7 @@ pub struct SYNC_PROGRESS_STAGE(pub i32);
 impl windows_core::TypeKind for SYNC_PROGRESS_STAGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNC_PROGRESS_STAGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNC_PROGRESS_STAGE").field(&self.0).finish()
@@ -2890,6 +2898,7 @@ pub struct SYNC_PROVIDER_ROLE(pub i32);
 impl windows_core::TypeKind for SYNC_PROVIDER_ROLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNC_PROVIDER_ROLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNC_PROVIDER_ROLE").field(&self.0).finish()
@@ -2901,6 +2910,7 @@ pub struct SYNC_REGISTRATION_EVENT(pub i32);
 impl windows_core::TypeKind for SYNC_REGISTRATION_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNC_REGISTRATION_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNC_REGISTRATION_EVENT").field(&self.0).finish()
@@ -2912,6 +2922,7 @@ pub struct SYNC_RESOLVE_ACTION(pub i32);
 impl windows_core::TypeKind for SYNC_RESOLVE_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNC_RESOLVE_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNC_RESOLVE_ACTION").field(&self.0).finish()
@@ -2923,6 +2934,7 @@ pub struct SYNC_SERIALIZATION_VERSION(pub i32);
 impl windows_core::TypeKind for SYNC_SERIALIZATION_VERSION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNC_SERIALIZATION_VERSION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNC_SERIALIZATION_VERSION").field(&self.0).finish()
@@ -2934,13 +2946,15 @@ pub struct SYNC_STATISTICS(pub i32);
 impl windows_core::TypeKind for SYNC_STATISTICS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNC_STATISTICS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNC_STATISTICS").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ID_PARAMETERS {
     pub dwSize: u32,
     pub replicaId: ID_PARAMETER_PAIR,
@@ -2956,7 +2970,8 @@ impl Default for ID_PARAMETERS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ID_PARAMETER_PAIR {
     pub fIsVariable: super::super::Foundation::BOOL,
     pub cbIdSize: u16,
@@ -2970,7 +2985,8 @@ impl Default for ID_PARAMETER_PAIR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYNC_FILTER_CHANGE {
     pub fMoveIn: super::super::Foundation::BOOL,
     pub moveVersion: SYNC_VERSION,
@@ -2984,7 +3000,8 @@ impl Default for SYNC_FILTER_CHANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYNC_RANGE {
     pub pbClosedLowerBound: *mut u8,
     pub pbClosedUpperBound: *mut u8,
@@ -2998,7 +3015,8 @@ impl Default for SYNC_RANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYNC_SESSION_STATISTICS {
     pub dwChangesApplied: u32,
     pub dwChangesFailed: u32,
@@ -3012,7 +3030,8 @@ impl Default for SYNC_SESSION_STATISTICS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYNC_TIME {
     pub dwDate: u32,
     pub dwTime: u32,
@@ -3026,7 +3045,8 @@ impl Default for SYNC_TIME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYNC_VERSION {
     pub dwLastUpdatingReplicaKey: u32,
     pub ullTickCount: u64,
@@ -3040,7 +3060,8 @@ impl Default for SYNC_VERSION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SyncProviderConfigUIConfiguration {
     pub dwVersion: u32,
     pub guidInstanceId: windows_core::GUID,
@@ -3059,7 +3080,8 @@ impl Default for SyncProviderConfigUIConfiguration {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SyncProviderConfiguration {
     pub dwVersion: u32,
     pub guidInstanceId: windows_core::GUID,
@@ -3080,3 +3102,5 @@ impl Default for SyncProviderConfiguration {
 pub const SyncProviderRegistration: windows_core::GUID = windows_core::GUID::from_u128(0xf82b4ef1_93a9_4dde_8015_f7950a1a6e31);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
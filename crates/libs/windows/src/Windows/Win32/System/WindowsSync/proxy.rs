@@ -0,0 +1,1870 @@
+impl windows_core::imp::ProxyVtable for IAsynchronousDataRetriever_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetIdParameters(this: *mut core::ffi::c_void, pidparameters: *mut ID_PARAMETERS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsynchronousDataRetriever", "GetIdParameters", 3usize, |this| ((**(this as *const *const IAsynchronousDataRetriever_Vtbl)).GetIdParameters)(this, pidparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterCallback(this: *mut core::ffi::c_void, pdataretrievercallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsynchronousDataRetriever", "RegisterCallback", 4usize, |this| ((**(this as *const *const IAsynchronousDataRetriever_Vtbl)).RegisterCallback)(this, pdataretrievercallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn RevokeCallback(this: *mut core::ffi::c_void, pdataretrievercallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsynchronousDataRetriever", "RevokeCallback", 5usize, |this| ((**(this as *const *const IAsynchronousDataRetriever_Vtbl)).RevokeCallback)(this, pdataretrievercallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadChangeData(this: *mut core::ffi::c_void, ploadchangecontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAsynchronousDataRetriever", "LoadChangeData", 6usize, |this| ((**(this as *const *const IAsynchronousDataRetriever_Vtbl)).LoadChangeData)(this, ploadchangecontext), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetIdParameters,
+            RegisterCallback,
+            RevokeCallback,
+            LoadChangeData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAsynchronousDataRetriever as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChangeConflict_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDestinationProviderConflictingChange(this: *mut core::ffi::c_void, ppconflictingchange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChangeConflict", "GetDestinationProviderConflictingChange", 3usize, |this| ((**(this as *const *const IChangeConflict_Vtbl)).GetDestinationProviderConflictingChange)(this, ppconflictingchange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourceProviderConflictingChange(this: *mut core::ffi::c_void, ppconflictingchange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChangeConflict", "GetSourceProviderConflictingChange", 4usize, |this| ((**(this as *const *const IChangeConflict_Vtbl)).GetSourceProviderConflictingChange)(this, ppconflictingchange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDestinationProviderConflictingData(this: *mut core::ffi::c_void, ppconflictingdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChangeConflict", "GetDestinationProviderConflictingData", 5usize, |this| ((**(this as *const *const IChangeConflict_Vtbl)).GetDestinationProviderConflictingData)(this, ppconflictingdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourceProviderConflictingData(this: *mut core::ffi::c_void, ppconflictingdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChangeConflict", "GetSourceProviderConflictingData", 6usize, |this| ((**(this as *const *const IChangeConflict_Vtbl)).GetSourceProviderConflictingData)(this, ppconflictingdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResolveActionForChange(this: *mut core::ffi::c_void, presolveaction: *mut SYNC_RESOLVE_ACTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChangeConflict", "GetResolveActionForChange", 7usize, |this| ((**(this as *const *const IChangeConflict_Vtbl)).GetResolveActionForChange)(this, presolveaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetResolveActionForChange(this: *mut core::ffi::c_void, resolveaction: SYNC_RESOLVE_ACTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChangeConflict", "SetResolveActionForChange", 8usize, |this| ((**(this as *const *const IChangeConflict_Vtbl)).SetResolveActionForChange)(this, resolveaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResolveActionForChangeUnit(this: *mut core::ffi::c_void, pchangeunit: *mut core::ffi::c_void, presolveaction: *mut SYNC_RESOLVE_ACTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChangeConflict", "GetResolveActionForChangeUnit", 9usize, |this| ((**(this as *const *const IChangeConflict_Vtbl)).GetResolveActionForChangeUnit)(this, pchangeunit, presolveaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetResolveActionForChangeUnit(this: *mut core::ffi::c_void, pchangeunit: *mut core::ffi::c_void, resolveaction: SYNC_RESOLVE_ACTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChangeConflict", "SetResolveActionForChangeUnit", 10usize, |this| ((**(this as *const *const IChangeConflict_Vtbl)).SetResolveActionForChangeUnit)(this, pchangeunit, resolveaction), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDestinationProviderConflictingChange,
+            GetSourceProviderConflictingChange,
+            GetDestinationProviderConflictingData,
+            GetSourceProviderConflictingData,
+            GetResolveActionForChange,
+            SetResolveActionForChange,
+            GetResolveActionForChangeUnit,
+            SetResolveActionForChangeUnit,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChangeConflict as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChangeUnitException_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetItemId(this: *mut core::ffi::c_void, pbitemid: *mut u8, pcbidsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChangeUnitException", "GetItemId", 3usize, |this| ((**(this as *const *const IChangeUnitException_Vtbl)).GetItemId)(this, pbitemid, pcbidsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChangeUnitId(this: *mut core::ffi::c_void, pbchangeunitid: *mut u8, pcbidsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChangeUnitException", "GetChangeUnitId", 4usize, |this| ((**(this as *const *const IChangeUnitException_Vtbl)).GetChangeUnitId)(this, pbchangeunitid, pcbidsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClockVector(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChangeUnitException", "GetClockVector", 5usize, |this| ((**(this as *const *const IChangeUnitException_Vtbl)).GetClockVector)(this, riid, ppunk), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetItemId, GetChangeUnitId, GetClockVector }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChangeUnitException as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IChangeUnitListFilterInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, ppbchangeunitids: *const *const u8, dwchangeunitcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChangeUnitListFilterInfo", "Initialize", 4usize, |this| ((**(this as *const *const IChangeUnitListFilterInfo_Vtbl)).Initialize)(this, ppbchangeunitids, dwchangeunitcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChangeUnitIdCount(this: *mut core::ffi::c_void, pdwchangeunitidcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChangeUnitListFilterInfo", "GetChangeUnitIdCount", 5usize, |this| ((**(this as *const *const IChangeUnitListFilterInfo_Vtbl)).GetChangeUnitIdCount)(this, pdwchangeunitidcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChangeUnitId(this: *mut core::ffi::c_void, dwchangeunitidindex: u32, pbchangeunitid: *mut u8, pcbidsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IChangeUnitListFilterInfo", "GetChangeUnitId", 6usize, |this| ((**(this as *const *const IChangeUnitListFilterInfo_Vtbl)).GetChangeUnitId)(this, dwchangeunitidindex, pbchangeunitid, pcbidsize), |result| Some(*result))
+        }
+        Self { base__: <ISyncFilterInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, GetChangeUnitIdCount, GetChangeUnitId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IChangeUnitListFilterInfo as windows_core::Interface>::IID || <ISyncFilterInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClockVector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetClockVectorElements(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppienumclockvector: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClockVector", "GetClockVectorElements", 3usize, |this| ((**(this as *const *const IClockVector_Vtbl)).GetClockVectorElements)(this, riid, ppienumclockvector), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClockVectorElementCount(this: *mut core::ffi::c_void, pdwcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClockVector", "GetClockVectorElementCount", 4usize, |this| ((**(this as *const *const IClockVector_Vtbl)).GetClockVectorElementCount)(this, pdwcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetClockVectorElements, GetClockVectorElementCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClockVector as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClockVectorElement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetReplicaKey(this: *mut core::ffi::c_void, pdwreplicakey: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClockVectorElement", "GetReplicaKey", 3usize, |this| ((**(this as *const *const IClockVectorElement_Vtbl)).GetReplicaKey)(this, pdwreplicakey), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTickCount(this: *mut core::ffi::c_void, pulltickcount: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClockVectorElement", "GetTickCount", 4usize, |this| ((**(this as *const *const IClockVectorElement_Vtbl)).GetTickCount)(this, pulltickcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetReplicaKey, GetTickCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClockVectorElement as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICombinedFilterInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFilterCount(this: *mut core::ffi::c_void, pdwfiltercount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICombinedFilterInfo", "GetFilterCount", 4usize, |this| ((**(this as *const *const ICombinedFilterInfo_Vtbl)).GetFilterCount)(this, pdwfiltercount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFilterInfo(this: *mut core::ffi::c_void, dwfilterindex: u32, ppifilterinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICombinedFilterInfo", "GetFilterInfo", 5usize, |this| ((**(this as *const *const ICombinedFilterInfo_Vtbl)).GetFilterInfo)(this, dwfilterindex, ppifilterinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFilterCombinationType(this: *mut core::ffi::c_void, pfiltercombinationtype: *mut FILTER_COMBINATION_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICombinedFilterInfo", "GetFilterCombinationType", 6usize, |this| ((**(this as *const *const ICombinedFilterInfo_Vtbl)).GetFilterCombinationType)(this, pfiltercombinationtype), |result| Some(*result))
+        }
+        Self { base__: <ISyncFilterInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFilterCount, GetFilterInfo, GetFilterCombinationType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICombinedFilterInfo as windows_core::Interface>::IID || <ISyncFilterInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConstraintConflict_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDestinationProviderConflictingChange(this: *mut core::ffi::c_void, ppconflictingchange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConstraintConflict", "GetDestinationProviderConflictingChange", 3usize, |this| ((**(this as *const *const IConstraintConflict_Vtbl)).GetDestinationProviderConflictingChange)(this, ppconflictingchange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourceProviderConflictingChange(this: *mut core::ffi::c_void, ppconflictingchange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConstraintConflict", "GetSourceProviderConflictingChange", 4usize, |this| ((**(this as *const *const IConstraintConflict_Vtbl)).GetSourceProviderConflictingChange)(this, ppconflictingchange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDestinationProviderOriginalChange(this: *mut core::ffi::c_void, pporiginalchange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConstraintConflict", "GetDestinationProviderOriginalChange", 5usize, |this| ((**(this as *const *const IConstraintConflict_Vtbl)).GetDestinationProviderOriginalChange)(this, pporiginalchange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDestinationProviderConflictingData(this: *mut core::ffi::c_void, ppconflictingdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConstraintConflict", "GetDestinationProviderConflictingData", 6usize, |this| ((**(this as *const *const IConstraintConflict_Vtbl)).GetDestinationProviderConflictingData)(this, ppconflictingdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourceProviderConflictingData(this: *mut core::ffi::c_void, ppconflictingdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConstraintConflict", "GetSourceProviderConflictingData", 7usize, |this| ((**(this as *const *const IConstraintConflict_Vtbl)).GetSourceProviderConflictingData)(this, ppconflictingdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDestinationProviderOriginalData(this: *mut core::ffi::c_void, pporiginaldata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConstraintConflict", "GetDestinationProviderOriginalData", 8usize, |this| ((**(this as *const *const IConstraintConflict_Vtbl)).GetDestinationProviderOriginalData)(this, pporiginaldata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConstraintResolveActionForChange(this: *mut core::ffi::c_void, pconstraintresolveaction: *mut SYNC_CONSTRAINT_RESOLVE_ACTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConstraintConflict", "GetConstraintResolveActionForChange", 9usize, |this| ((**(this as *const *const IConstraintConflict_Vtbl)).GetConstraintResolveActionForChange)(this, pconstraintresolveaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConstraintResolveActionForChange(this: *mut core::ffi::c_void, constraintresolveaction: SYNC_CONSTRAINT_RESOLVE_ACTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConstraintConflict", "SetConstraintResolveActionForChange", 10usize, |this| ((**(this as *const *const IConstraintConflict_Vtbl)).SetConstraintResolveActionForChange)(this, constraintresolveaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConstraintResolveActionForChangeUnit(this: *mut core::ffi::c_void, pchangeunit: *mut core::ffi::c_void, pconstraintresolveaction: *mut SYNC_CONSTRAINT_RESOLVE_ACTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConstraintConflict", "GetConstraintResolveActionForChangeUnit", 11usize, |this| ((**(this as *const *const IConstraintConflict_Vtbl)).GetConstraintResolveActionForChangeUnit)(this, pchangeunit, pconstraintresolveaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConstraintResolveActionForChangeUnit(this: *mut core::ffi::c_void, pchangeunit: *mut core::ffi::c_void, constraintresolveaction: SYNC_CONSTRAINT_RESOLVE_ACTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConstraintConflict", "SetConstraintResolveActionForChangeUnit", 12usize, |this| ((**(this as *const *const IConstraintConflict_Vtbl)).SetConstraintResolveActionForChangeUnit)(this, pchangeunit, constraintresolveaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConstraintConflictReason(this: *mut core::ffi::c_void, pconstraintconflictreason: *mut CONSTRAINT_CONFLICT_REASON) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConstraintConflict", "GetConstraintConflictReason", 13usize, |this| ((**(this as *const *const IConstraintConflict_Vtbl)).GetConstraintConflictReason)(this, pconstraintconflictreason), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTemporary(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConstraintConflict", "IsTemporary", 14usize, |this| ((**(this as *const *const IConstraintConflict_Vtbl)).IsTemporary)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDestinationProviderConflictingChange,
+            GetSourceProviderConflictingChange,
+            GetDestinationProviderOriginalChange,
+            GetDestinationProviderConflictingData,
+            GetSourceProviderConflictingData,
+            GetDestinationProviderOriginalData,
+            GetConstraintResolveActionForChange,
+            SetConstraintResolveActionForChange,
+            GetConstraintResolveActionForChangeUnit,
+            SetConstraintResolveActionForChangeUnit,
+            GetConstraintConflictReason,
+            IsTemporary,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConstraintConflict as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IConstructReplicaKeyMap_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FindOrAddReplica(this: *mut core::ffi::c_void, pbreplicaid: *const u8, pdwreplicakey: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IConstructReplicaKeyMap", "FindOrAddReplica", 3usize, |this| ((**(this as *const *const IConstructReplicaKeyMap_Vtbl)).FindOrAddReplica)(this, pbreplicaid, pdwreplicakey), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FindOrAddReplica }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IConstructReplicaKeyMap as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreFragment_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NextColumn(this: *mut core::ffi::c_void, pchangeunitid: *mut u8, pchangeunitidsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreFragment", "NextColumn", 3usize, |this| ((**(this as *const *const ICoreFragment_Vtbl)).NextColumn)(this, pchangeunitid, pchangeunitidsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn NextRange(this: *mut core::ffi::c_void, pitemid: *mut u8, pitemidsize: *mut u32, piclockvector: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreFragment", "NextRange", 4usize, |this| ((**(this as *const *const ICoreFragment_Vtbl)).NextRange)(this, pitemid, pitemidsize, piclockvector), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreFragment", "Reset", 5usize, |this| ((**(this as *const *const ICoreFragment_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColumnCount(this: *mut core::ffi::c_void, pcolumncount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreFragment", "GetColumnCount", 6usize, |this| ((**(this as *const *const ICoreFragment_Vtbl)).GetColumnCount)(this, pcolumncount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRangeCount(this: *mut core::ffi::c_void, prangecount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreFragment", "GetRangeCount", 7usize, |this| ((**(this as *const *const ICoreFragment_Vtbl)).GetRangeCount)(this, prangecount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NextColumn, NextRange, Reset, GetColumnCount, GetRangeCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreFragment as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoreFragmentInspector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NextCoreFragments(this: *mut core::ffi::c_void, requestedcount: u32, ppicorefragments: *mut *mut core::ffi::c_void, pfetchedcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreFragmentInspector", "NextCoreFragments", 3usize, |this| ((**(this as *const *const ICoreFragmentInspector_Vtbl)).NextCoreFragments)(this, requestedcount, ppicorefragments, pfetchedcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoreFragmentInspector", "Reset", 4usize, |this| ((**(this as *const *const ICoreFragmentInspector_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NextCoreFragments, Reset }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoreFragmentInspector as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICustomFilterInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSyncFilter(this: *mut core::ffi::c_void, pisyncfilter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomFilterInfo", "GetSyncFilter", 4usize, |this| ((**(this as *const *const ICustomFilterInfo_Vtbl)).GetSyncFilter)(this, pisyncfilter), |result| Some(*result))
+        }
+        Self { base__: <ISyncFilterInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSyncFilter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICustomFilterInfo as windows_core::Interface>::IID || <ISyncFilterInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDataRetrieverCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LoadChangeDataComplete(this: *mut core::ffi::c_void, punkdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataRetrieverCallback", "LoadChangeDataComplete", 3usize, |this| ((**(this as *const *const IDataRetrieverCallback_Vtbl)).LoadChangeDataComplete)(this, punkdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadChangeDataError(this: *mut core::ffi::c_void, hrerror: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDataRetrieverCallback", "LoadChangeDataError", 4usize, |this| ((**(this as *const *const IDataRetrieverCallback_Vtbl)).LoadChangeDataError)(this, hrerror), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LoadChangeDataComplete, LoadChangeDataError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDataRetrieverCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumChangeUnitExceptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, cexceptions: u32, ppchangeunitexception: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumChangeUnitExceptions", "Next", 3usize, |this| ((**(this as *const *const IEnumChangeUnitExceptions_Vtbl)).Next)(this, cexceptions, ppchangeunitexception, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, cexceptions: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumChangeUnitExceptions", "Skip", 4usize, |this| ((**(this as *const *const IEnumChangeUnitExceptions_Vtbl)).Skip)(this, cexceptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumChangeUnitExceptions", "Reset", 5usize, |this| ((**(this as *const *const IEnumChangeUnitExceptions_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumChangeUnitExceptions", "Clone", 6usize, |this| ((**(this as *const *const IEnumChangeUnitExceptions_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumChangeUnitExceptions as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumClockVector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, cclockvectorelements: u32, ppiclockvectorelements: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumClockVector", "Next", 3usize, |this| ((**(this as *const *const IEnumClockVector_Vtbl)).Next)(this, cclockvectorelements, ppiclockvectorelements, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, csyncversions: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumClockVector", "Skip", 4usize, |this| ((**(this as *const *const IEnumClockVector_Vtbl)).Skip)(this, csyncversions), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumClockVector", "Reset", 5usize, |this| ((**(this as *const *const IEnumClockVector_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppienum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumClockVector", "Clone", 6usize, |this| ((**(this as *const *const IEnumClockVector_Vtbl)).Clone)(this, ppienum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumClockVector as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumFeedClockVector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, cclockvectorelements: u32, ppiclockvectorelements: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumFeedClockVector", "Next", 3usize, |this| ((**(this as *const *const IEnumFeedClockVector_Vtbl)).Next)(this, cclockvectorelements, ppiclockvectorelements, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, csyncversions: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumFeedClockVector", "Skip", 4usize, |this| ((**(this as *const *const IEnumFeedClockVector_Vtbl)).Skip)(this, csyncversions), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumFeedClockVector", "Reset", 5usize, |this| ((**(this as *const *const IEnumFeedClockVector_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppienum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumFeedClockVector", "Clone", 6usize, |this| ((**(this as *const *const IEnumFeedClockVector_Vtbl)).Clone)(this, ppienum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumFeedClockVector as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumItemIds_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, pbitemid: *mut u8, pcbitemidsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumItemIds", "Next", 3usize, |this| ((**(this as *const *const IEnumItemIds_Vtbl)).Next)(this, pbitemid, pcbitemidsize), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumItemIds as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumRangeExceptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, cexceptions: u32, pprangeexception: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumRangeExceptions", "Next", 3usize, |this| ((**(this as *const *const IEnumRangeExceptions_Vtbl)).Next)(this, cexceptions, pprangeexception, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, cexceptions: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumRangeExceptions", "Skip", 4usize, |this| ((**(this as *const *const IEnumRangeExceptions_Vtbl)).Skip)(this, cexceptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumRangeExceptions", "Reset", 5usize, |this| ((**(this as *const *const IEnumRangeExceptions_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumRangeExceptions", "Clone", 6usize, |this| ((**(this as *const *const IEnumRangeExceptions_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumRangeExceptions as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumSingleItemExceptions_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, cexceptions: u32, ppsingleitemexception: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSingleItemExceptions", "Next", 3usize, |this| ((**(this as *const *const IEnumSingleItemExceptions_Vtbl)).Next)(this, cexceptions, ppsingleitemexception, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, cexceptions: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSingleItemExceptions", "Skip", 4usize, |this| ((**(this as *const *const IEnumSingleItemExceptions_Vtbl)).Skip)(this, cexceptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSingleItemExceptions", "Reset", 5usize, |this| ((**(this as *const *const IEnumSingleItemExceptions_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSingleItemExceptions", "Clone", 6usize, |this| ((**(this as *const *const IEnumSingleItemExceptions_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumSingleItemExceptions as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumSyncChangeUnits_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, cchanges: u32, ppchangeunit: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSyncChangeUnits", "Next", 3usize, |this| ((**(this as *const *const IEnumSyncChangeUnits_Vtbl)).Next)(this, cchanges, ppchangeunit, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, cchanges: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSyncChangeUnits", "Skip", 4usize, |this| ((**(this as *const *const IEnumSyncChangeUnits_Vtbl)).Skip)(this, cchanges), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSyncChangeUnits", "Reset", 5usize, |this| ((**(this as *const *const IEnumSyncChangeUnits_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSyncChangeUnits", "Clone", 6usize, |this| ((**(this as *const *const IEnumSyncChangeUnits_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumSyncChangeUnits as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumSyncChanges_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, cchanges: u32, ppchange: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSyncChanges", "Next", 3usize, |this| ((**(this as *const *const IEnumSyncChanges_Vtbl)).Next)(this, cchanges, ppchange, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, cchanges: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSyncChanges", "Skip", 4usize, |this| ((**(this as *const *const IEnumSyncChanges_Vtbl)).Skip)(this, cchanges), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSyncChanges", "Reset", 5usize, |this| ((**(this as *const *const IEnumSyncChanges_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSyncChanges", "Clone", 6usize, |this| ((**(this as *const *const IEnumSyncChanges_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumSyncChanges as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IEnumSyncProviderConfigUIInfos_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, cfactories: u32, ppsyncproviderconfiguiinfo: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSyncProviderConfigUIInfos", "Next", 3usize, |this| ((**(this as *const *const IEnumSyncProviderConfigUIInfos_Vtbl)).Next)(this, cfactories, ppsyncproviderconfiguiinfo, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, cfactories: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSyncProviderConfigUIInfos", "Skip", 4usize, |this| ((**(this as *const *const IEnumSyncProviderConfigUIInfos_Vtbl)).Skip)(this, cfactories), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSyncProviderConfigUIInfos", "Reset", 5usize, |this| ((**(this as *const *const IEnumSyncProviderConfigUIInfos_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSyncProviderConfigUIInfos", "Clone", 6usize, |this| ((**(this as *const *const IEnumSyncProviderConfigUIInfos_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            Next,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            Next: 0,
+            Skip,
+            Reset,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumSyncProviderConfigUIInfos as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IEnumSyncProviderInfos_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, cinstances: u32, ppsyncproviderinfo: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSyncProviderInfos", "Next", 3usize, |this| ((**(this as *const *const IEnumSyncProviderInfos_Vtbl)).Next)(this, cinstances, ppsyncproviderinfo, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, cinstances: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSyncProviderInfos", "Skip", 4usize, |this| ((**(this as *const *const IEnumSyncProviderInfos_Vtbl)).Skip)(this, cinstances), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSyncProviderInfos", "Reset", 5usize, |this| ((**(this as *const *const IEnumSyncProviderInfos_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSyncProviderInfos", "Clone", 6usize, |this| ((**(this as *const *const IEnumSyncProviderInfos_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            Next,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            Next: 0,
+            Skip,
+            Reset,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumSyncProviderInfos as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFeedClockVector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetUpdateCount(this: *mut core::ffi::c_void, pdwupdatecount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedClockVector", "GetUpdateCount", 5usize, |this| ((**(this as *const *const IFeedClockVector_Vtbl)).GetUpdateCount)(this, pdwupdatecount), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsNoConflictsSpecified(this: *mut core::ffi::c_void, pfisnoconflictsspecified: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedClockVector", "IsNoConflictsSpecified", 6usize, |this| ((**(this as *const *const IFeedClockVector_Vtbl)).IsNoConflictsSpecified)(this, pfisnoconflictsspecified), |result| Some(*result))
+        }
+        Self { base__: <IClockVector_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetUpdateCount, IsNoConflictsSpecified }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFeedClockVector as windows_core::Interface>::IID || <IClockVector_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFeedClockVectorElement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSyncTime(this: *mut core::ffi::c_void, psynctime: *mut SYNC_TIME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedClockVectorElement", "GetSyncTime", 5usize, |this| ((**(this as *const *const IFeedClockVectorElement_Vtbl)).GetSyncTime)(this, psynctime), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlags(this: *mut core::ffi::c_void, pbflags: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFeedClockVectorElement", "GetFlags", 6usize, |this| ((**(this as *const *const IFeedClockVectorElement_Vtbl)).GetFlags)(this, pbflags), |result| Some(*result))
+        }
+        Self { base__: <IClockVectorElement_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSyncTime, GetFlags }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFeedClockVectorElement as windows_core::Interface>::IID || <IClockVectorElement_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFilterKeyMap_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pdwcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterKeyMap", "GetCount", 3usize, |this| ((**(this as *const *const IFilterKeyMap_Vtbl)).GetCount)(this, pdwcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddFilter(this: *mut core::ffi::c_void, pisyncfilter: *mut core::ffi::c_void, pdwfilterkey: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterKeyMap", "AddFilter", 4usize, |this| ((**(this as *const *const IFilterKeyMap_Vtbl)).AddFilter)(this, pisyncfilter, pdwfilterkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFilter(this: *mut core::ffi::c_void, dwfilterkey: u32, ppisyncfilter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterKeyMap", "GetFilter", 5usize, |this| ((**(this as *const *const IFilterKeyMap_Vtbl)).GetFilter)(this, dwfilterkey, ppisyncfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn Serialize(this: *mut core::ffi::c_void, pbfilterkeymap: *mut u8, pcbfilterkeymap: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterKeyMap", "Serialize", 6usize, |this| ((**(this as *const *const IFilterKeyMap_Vtbl)).Serialize)(this, pbfilterkeymap, pcbfilterkeymap), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCount, AddFilter, GetFilter, Serialize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFilterKeyMap as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFilterRequestCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestFilter(this: *mut core::ffi::c_void, pfilter: *mut core::ffi::c_void, filteringtype: FILTERING_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterRequestCallback", "RequestFilter", 3usize, |this| ((**(this as *const *const IFilterRequestCallback_Vtbl)).RequestFilter)(this, pfilter, filteringtype), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestFilter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFilterRequestCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFilterTrackingProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SpecifyTrackedFilters(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterTrackingProvider", "SpecifyTrackedFilters", 3usize, |this| ((**(this as *const *const IFilterTrackingProvider_Vtbl)).SpecifyTrackedFilters)(this, pcallback), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTrackedFilter(this: *mut core::ffi::c_void, pfilter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterTrackingProvider", "AddTrackedFilter", 4usize, |this| ((**(this as *const *const IFilterTrackingProvider_Vtbl)).AddTrackedFilter)(this, pfilter), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SpecifyTrackedFilters, AddTrackedFilter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFilterTrackingProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFilterTrackingRequestCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestTrackedFilter(this: *mut core::ffi::c_void, pfilter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterTrackingRequestCallback", "RequestTrackedFilter", 3usize, |this| ((**(this as *const *const IFilterTrackingRequestCallback_Vtbl)).RequestTrackedFilter)(this, pfilter), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RequestTrackedFilter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFilterTrackingRequestCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFilterTrackingSyncChangeBuilder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddFilterChange(this: *mut core::ffi::c_void, dwfilterkey: u32, pfilterchange: *const SYNC_FILTER_CHANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterTrackingSyncChangeBuilder", "AddFilterChange", 3usize, |this| ((**(this as *const *const IFilterTrackingSyncChangeBuilder_Vtbl)).AddFilterChange)(this, dwfilterkey, pfilterchange), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllChangeUnitsPresentFlag(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFilterTrackingSyncChangeBuilder", "SetAllChangeUnitsPresentFlag", 4usize, |this| ((**(this as *const *const IFilterTrackingSyncChangeBuilder_Vtbl)).SetAllChangeUnitsPresentFlag)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddFilterChange, SetAllChangeUnitsPresentFlag }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFilterTrackingSyncChangeBuilder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IForgottenKnowledge_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ForgetToVersion(this: *mut core::ffi::c_void, pknowledge: *mut core::ffi::c_void, pversion: *const SYNC_VERSION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IForgottenKnowledge", "ForgetToVersion", 27usize, |this| ((**(this as *const *const IForgottenKnowledge_Vtbl)).ForgetToVersion)(this, pknowledge, pversion), |result| Some(*result))
+        }
+        Self { base__: <ISyncKnowledge_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ForgetToVersion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IForgottenKnowledge as windows_core::Interface>::IID || <ISyncKnowledge_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IKnowledgeSyncProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BeginSession(this: *mut core::ffi::c_void, role: SYNC_PROVIDER_ROLE, psessionstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnowledgeSyncProvider", "BeginSession", 4usize, |this| ((**(this as *const *const IKnowledgeSyncProvider_Vtbl)).BeginSession)(this, role, psessionstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSyncBatchParameters(this: *mut core::ffi::c_void, ppsyncknowledge: *mut *mut core::ffi::c_void, pdwrequestedbatchsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnowledgeSyncProvider", "GetSyncBatchParameters", 5usize, |this| ((**(this as *const *const IKnowledgeSyncProvider_Vtbl)).GetSyncBatchParameters)(this, ppsyncknowledge, pdwrequestedbatchsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChangeBatch(this: *mut core::ffi::c_void, dwbatchsize: u32, psyncknowledge: *mut core::ffi::c_void, ppsyncchangebatch: *mut *mut core::ffi::c_void, ppunkdataretriever: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnowledgeSyncProvider", "GetChangeBatch", 6usize, |this| ((**(this as *const *const IKnowledgeSyncProvider_Vtbl)).GetChangeBatch)(this, dwbatchsize, psyncknowledge, ppsyncchangebatch, ppunkdataretriever), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFullEnumerationChangeBatch(this: *mut core::ffi::c_void, dwbatchsize: u32, pblowerenumerationbound: *const u8, psyncknowledge: *mut core::ffi::c_void, ppsyncchangebatch: *mut *mut core::ffi::c_void, ppunkdataretriever: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnowledgeSyncProvider", "GetFullEnumerationChangeBatch", 7usize, |this| ((**(this as *const *const IKnowledgeSyncProvider_Vtbl)).GetFullEnumerationChangeBatch)(this, dwbatchsize, pblowerenumerationbound, psyncknowledge, ppsyncchangebatch, ppunkdataretriever), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessChangeBatch(this: *mut core::ffi::c_void, resolutionpolicy: CONFLICT_RESOLUTION_POLICY, psourcechangebatch: *mut core::ffi::c_void, punkdataretriever: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, psyncsessionstatistics: *mut SYNC_SESSION_STATISTICS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnowledgeSyncProvider", "ProcessChangeBatch", 8usize, |this| ((**(this as *const *const IKnowledgeSyncProvider_Vtbl)).ProcessChangeBatch)(this, resolutionpolicy, psourcechangebatch, punkdataretriever, pcallback, psyncsessionstatistics), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessFullEnumerationChangeBatch(this: *mut core::ffi::c_void, resolutionpolicy: CONFLICT_RESOLUTION_POLICY, psourcechangebatch: *mut core::ffi::c_void, punkdataretriever: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void, psyncsessionstatistics: *mut SYNC_SESSION_STATISTICS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnowledgeSyncProvider", "ProcessFullEnumerationChangeBatch", 9usize, |this| ((**(this as *const *const IKnowledgeSyncProvider_Vtbl)).ProcessFullEnumerationChangeBatch)(this, resolutionpolicy, psourcechangebatch, punkdataretriever, pcallback, psyncsessionstatistics), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndSession(this: *mut core::ffi::c_void, psessionstate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IKnowledgeSyncProvider", "EndSession", 10usize, |this| ((**(this as *const *const IKnowledgeSyncProvider_Vtbl)).EndSession)(this, psessionstate), |result| Some(*result))
+        }
+        Self {
+            base__: <ISyncProvider_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BeginSession,
+            GetSyncBatchParameters,
+            GetChangeBatch,
+            GetFullEnumerationChangeBatch,
+            ProcessChangeBatch,
+            ProcessFullEnumerationChangeBatch,
+            EndSession,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IKnowledgeSyncProvider as windows_core::Interface>::IID || <ISyncProvider_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ILoadChangeContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSyncChange(this: *mut core::ffi::c_void, ppsyncchange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoadChangeContext", "GetSyncChange", 3usize, |this| ((**(this as *const *const ILoadChangeContext_Vtbl)).GetSyncChange)(this, ppsyncchange), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRecoverableErrorOnChange(this: *mut core::ffi::c_void, hrerror: windows_core::HRESULT, perrordata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoadChangeContext", "SetRecoverableErrorOnChange", 4usize, |this| ((**(this as *const *const ILoadChangeContext_Vtbl)).SetRecoverableErrorOnChange)(this, hrerror, perrordata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRecoverableErrorOnChangeUnit(this: *mut core::ffi::c_void, hrerror: windows_core::HRESULT, pchangeunit: *mut core::ffi::c_void, perrordata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILoadChangeContext", "SetRecoverableErrorOnChangeUnit", 5usize, |this| ((**(this as *const *const ILoadChangeContext_Vtbl)).SetRecoverableErrorOnChangeUnit)(this, hrerror, pchangeunit, perrordata), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSyncChange,
+            SetRecoverableErrorOnChange,
+            SetRecoverableErrorOnChangeUnit,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILoadChangeContext as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProviderConverter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pisyncprovider: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProviderConverter", "Initialize", 3usize, |this| ((**(this as *const *const IProviderConverter_Vtbl)).Initialize)(this, pisyncprovider), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProviderConverter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRangeException_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetClosedRangeStart(this: *mut core::ffi::c_void, pbclosedrangestart: *mut u8, pcbidsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRangeException", "GetClosedRangeStart", 3usize, |this| ((**(this as *const *const IRangeException_Vtbl)).GetClosedRangeStart)(this, pbclosedrangestart, pcbidsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClosedRangeEnd(this: *mut core::ffi::c_void, pbclosedrangeend: *mut u8, pcbidsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRangeException", "GetClosedRangeEnd", 4usize, |this| ((**(this as *const *const IRangeException_Vtbl)).GetClosedRangeEnd)(this, pbclosedrangeend, pcbidsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClockVector(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRangeException", "GetClockVector", 5usize, |this| ((**(this as *const *const IRangeException_Vtbl)).GetClockVector)(this, riid, ppunk), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetClosedRangeStart, GetClosedRangeEnd, GetClockVector }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRangeException as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRecoverableError_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStage(this: *mut core::ffi::c_void, pstage: *mut SYNC_PROGRESS_STAGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRecoverableError", "GetStage", 3usize, |this| ((**(this as *const *const IRecoverableError_Vtbl)).GetStage)(this, pstage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProvider(this: *mut core::ffi::c_void, pproviderrole: *mut SYNC_PROVIDER_ROLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRecoverableError", "GetProvider", 4usize, |this| ((**(this as *const *const IRecoverableError_Vtbl)).GetProvider)(this, pproviderrole), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChangeWithRecoverableError(this: *mut core::ffi::c_void, ppchangewithrecoverableerror: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRecoverableError", "GetChangeWithRecoverableError", 5usize, |this| ((**(this as *const *const IRecoverableError_Vtbl)).GetChangeWithRecoverableError)(this, ppchangewithrecoverableerror), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecoverableErrorDataForChange(this: *mut core::ffi::c_void, phrerror: *mut windows_core::HRESULT, pperrordata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRecoverableError", "GetRecoverableErrorDataForChange", 6usize, |this| ((**(this as *const *const IRecoverableError_Vtbl)).GetRecoverableErrorDataForChange)(this, phrerror, pperrordata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRecoverableErrorDataForChangeUnit(this: *mut core::ffi::c_void, pchangeunit: *mut core::ffi::c_void, phrerror: *mut windows_core::HRESULT, pperrordata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRecoverableError", "GetRecoverableErrorDataForChangeUnit", 7usize, |this| ((**(this as *const *const IRecoverableError_Vtbl)).GetRecoverableErrorDataForChangeUnit)(this, pchangeunit, phrerror, pperrordata), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetStage,
+            GetProvider,
+            GetChangeWithRecoverableError,
+            GetRecoverableErrorDataForChange,
+            GetRecoverableErrorDataForChangeUnit,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRecoverableError as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRecoverableErrorData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pcszitemdisplayname: windows_core::PCWSTR, pcszerrordescription: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRecoverableErrorData", "Initialize", 3usize, |this| ((**(this as *const *const IRecoverableErrorData_Vtbl)).Initialize)(this, pcszitemdisplayname, pcszerrordescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItemDisplayName(this: *mut core::ffi::c_void, pszitemdisplayname: windows_core::PCWSTR, pcchitemdisplayname: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRecoverableErrorData", "GetItemDisplayName", 4usize, |this| ((**(this as *const *const IRecoverableErrorData_Vtbl)).GetItemDisplayName)(this, pszitemdisplayname, pcchitemdisplayname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetErrorDescription(this: *mut core::ffi::c_void, pszerrordescription: windows_core::PCWSTR, pccherrordescription: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRecoverableErrorData", "GetErrorDescription", 5usize, |this| ((**(this as *const *const IRecoverableErrorData_Vtbl)).GetErrorDescription)(this, pszerrordescription, pccherrordescription), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, GetItemDisplayName, GetErrorDescription }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRecoverableErrorData as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IRegisteredSyncProvider_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn Init(this: *mut core::ffi::c_void, pguidinstanceid: *const windows_core::GUID, pguidcontenttype: *const windows_core::GUID, pcontextpropertystore: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisteredSyncProvider", "Init", 3usize, |this| ((**(this as *const *const IRegisteredSyncProvider_Vtbl)).Init)(this, pguidinstanceid, pguidcontenttype, pcontextpropertystore), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInstanceId(this: *mut core::ffi::c_void, pguidinstanceid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisteredSyncProvider", "GetInstanceId", 4usize, |this| ((**(this as *const *const IRegisteredSyncProvider_Vtbl)).GetInstanceId)(this, pguidinstanceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRegisteredSyncProvider", "Reset", 5usize, |this| ((**(this as *const *const IRegisteredSyncProvider_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            Init,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            Init: 0,
+            GetInstanceId,
+            Reset,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRegisteredSyncProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IReplicaKeyMap_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LookupReplicaKey(this: *mut core::ffi::c_void, pbreplicaid: *const u8, pdwreplicakey: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReplicaKeyMap", "LookupReplicaKey", 3usize, |this| ((**(this as *const *const IReplicaKeyMap_Vtbl)).LookupReplicaKey)(this, pbreplicaid, pdwreplicakey), |result| Some(*result))
+        }
+        unsafe extern "system" fn LookupReplicaId(this: *mut core::ffi::c_void, dwreplicakey: u32, pbreplicaid: *mut u8, pcbidsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReplicaKeyMap", "LookupReplicaId", 4usize, |this| ((**(this as *const *const IReplicaKeyMap_Vtbl)).LookupReplicaId)(this, dwreplicakey, pbreplicaid, pcbidsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn Serialize(this: *mut core::ffi::c_void, pbreplicakeymap: *mut u8, pcbreplicakeymap: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReplicaKeyMap", "Serialize", 5usize, |this| ((**(this as *const *const IReplicaKeyMap_Vtbl)).Serialize)(this, pbreplicakeymap, pcbreplicakeymap), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LookupReplicaKey, LookupReplicaId, Serialize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IReplicaKeyMap as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRequestFilteredSync_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SpecifyFilter(this: *mut core::ffi::c_void, pcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRequestFilteredSync", "SpecifyFilter", 3usize, |this| ((**(this as *const *const IRequestFilteredSync_Vtbl)).SpecifyFilter)(this, pcallback), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SpecifyFilter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRequestFilteredSync as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISingleItemException_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetItemId(this: *mut core::ffi::c_void, pbitemid: *mut u8, pcbidsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISingleItemException", "GetItemId", 3usize, |this| ((**(this as *const *const ISingleItemException_Vtbl)).GetItemId)(this, pbitemid, pcbidsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClockVector(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISingleItemException", "GetClockVector", 4usize, |this| ((**(this as *const *const ISingleItemException_Vtbl)).GetClockVector)(this, riid, ppunk), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetItemId, GetClockVector }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISingleItemException as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISupportFilteredSync_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddFilter(this: *mut core::ffi::c_void, pfilter: *mut core::ffi::c_void, filteringtype: FILTERING_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISupportFilteredSync", "AddFilter", 3usize, |this| ((**(this as *const *const ISupportFilteredSync_Vtbl)).AddFilter)(this, pfilter, filteringtype), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddFilter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISupportFilteredSync as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISupportLastWriteTime_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetItemChangeTime(this: *mut core::ffi::c_void, pbitemid: *const u8, pulltimestamp: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISupportLastWriteTime", "GetItemChangeTime", 3usize, |this| ((**(this as *const *const ISupportLastWriteTime_Vtbl)).GetItemChangeTime)(this, pbitemid, pulltimestamp), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChangeUnitChangeTime(this: *mut core::ffi::c_void, pbitemid: *const u8, pbchangeunitid: *const u8, pulltimestamp: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISupportLastWriteTime", "GetChangeUnitChangeTime", 4usize, |this| ((**(this as *const *const ISupportLastWriteTime_Vtbl)).GetChangeUnitChangeTime)(this, pbitemid, pbchangeunitid, pulltimestamp), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetItemChangeTime, GetChangeUnitChangeTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISupportLastWriteTime as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnProgress(this: *mut core::ffi::c_void, provider: SYNC_PROVIDER_ROLE, syncstage: SYNC_PROGRESS_STAGE, dwcompletedwork: u32, dwtotalwork: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncCallback", "OnProgress", 3usize, |this| ((**(this as *const *const ISyncCallback_Vtbl)).OnProgress)(this, provider, syncstage, dwcompletedwork, dwtotalwork), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnChange(this: *mut core::ffi::c_void, psyncchange: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncCallback", "OnChange", 4usize, |this| ((**(this as *const *const ISyncCallback_Vtbl)).OnChange)(this, psyncchange), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnConflict(this: *mut core::ffi::c_void, pconflict: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncCallback", "OnConflict", 5usize, |this| ((**(this as *const *const ISyncCallback_Vtbl)).OnConflict)(this, pconflict), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnFullEnumerationNeeded(this: *mut core::ffi::c_void, pfullenumerationaction: *mut SYNC_FULL_ENUMERATION_ACTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncCallback", "OnFullEnumerationNeeded", 6usize, |this| ((**(this as *const *const ISyncCallback_Vtbl)).OnFullEnumerationNeeded)(this, pfullenumerationaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnRecoverableError(this: *mut core::ffi::c_void, precoverableerror: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncCallback", "OnRecoverableError", 7usize, |this| ((**(this as *const *const ISyncCallback_Vtbl)).OnRecoverableError)(this, precoverableerror), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnProgress,
+            OnChange,
+            OnConflict,
+            OnFullEnumerationNeeded,
+            OnRecoverableError,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncCallback2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnChangeApplied(this: *mut core::ffi::c_void, dwchangesapplied: u32, dwchangesfailed: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncCallback2", "OnChangeApplied", 8usize, |this| ((**(this as *const *const ISyncCallback2_Vtbl)).OnChangeApplied)(this, dwchangesapplied, dwchangesfailed), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnChangeFailed(this: *mut core::ffi::c_void, dwchangesapplied: u32, dwchangesfailed: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncCallback2", "OnChangeFailed", 9usize, |this| ((**(this as *const *const ISyncCallback2_Vtbl)).OnChangeFailed)(this, dwchangesapplied, dwchangesfailed), |result| Some(*result))
+        }
+        Self { base__: <ISyncCallback_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnChangeApplied, OnChangeFailed }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncCallback2 as windows_core::Interface>::IID || <ISyncCallback_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncChange_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOwnerReplicaId(this: *mut core::ffi::c_void, pbreplicaid: *mut u8, pcbidsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChange", "GetOwnerReplicaId", 3usize, |this| ((**(this as *const *const ISyncChange_Vtbl)).GetOwnerReplicaId)(this, pbreplicaid, pcbidsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRootItemId(this: *mut core::ffi::c_void, pbrootitemid: *mut u8, pcbidsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChange", "GetRootItemId", 4usize, |this| ((**(this as *const *const ISyncChange_Vtbl)).GetRootItemId)(this, pbrootitemid, pcbidsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChangeVersion(this: *mut core::ffi::c_void, pbcurrentreplicaid: *const u8, pversion: *mut SYNC_VERSION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChange", "GetChangeVersion", 5usize, |this| ((**(this as *const *const ISyncChange_Vtbl)).GetChangeVersion)(this, pbcurrentreplicaid, pversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCreationVersion(this: *mut core::ffi::c_void, pbcurrentreplicaid: *const u8, pversion: *mut SYNC_VERSION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChange", "GetCreationVersion", 6usize, |this| ((**(this as *const *const ISyncChange_Vtbl)).GetCreationVersion)(this, pbcurrentreplicaid, pversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlags(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChange", "GetFlags", 7usize, |this| ((**(this as *const *const ISyncChange_Vtbl)).GetFlags)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWorkEstimate(this: *mut core::ffi::c_void, pdwwork: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChange", "GetWorkEstimate", 8usize, |this| ((**(this as *const *const ISyncChange_Vtbl)).GetWorkEstimate)(this, pdwwork), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChangeUnits(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChange", "GetChangeUnits", 9usize, |this| ((**(this as *const *const ISyncChange_Vtbl)).GetChangeUnits)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMadeWithKnowledge(this: *mut core::ffi::c_void, ppmadewithknowledge: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChange", "GetMadeWithKnowledge", 10usize, |this| ((**(this as *const *const ISyncChange_Vtbl)).GetMadeWithKnowledge)(this, ppmadewithknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLearnedKnowledge(this: *mut core::ffi::c_void, pplearnedknowledge: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChange", "GetLearnedKnowledge", 11usize, |this| ((**(this as *const *const ISyncChange_Vtbl)).GetLearnedKnowledge)(this, pplearnedknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWorkEstimate(this: *mut core::ffi::c_void, dwwork: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChange", "SetWorkEstimate", 12usize, |this| ((**(this as *const *const ISyncChange_Vtbl)).SetWorkEstimate)(this, dwwork), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetOwnerReplicaId,
+            GetRootItemId,
+            GetChangeVersion,
+            GetCreationVersion,
+            GetFlags,
+            GetWorkEstimate,
+            GetChangeUnits,
+            GetMadeWithKnowledge,
+            GetLearnedKnowledge,
+            SetWorkEstimate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncChange as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncChangeBatch_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BeginUnorderedGroup(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatch", "BeginUnorderedGroup", 17usize, |this| ((**(this as *const *const ISyncChangeBatch_Vtbl)).BeginUnorderedGroup)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndUnorderedGroup(this: *mut core::ffi::c_void, pmadewithknowledge: *mut core::ffi::c_void, fallchangesforknowledge: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatch", "EndUnorderedGroup", 18usize, |this| ((**(this as *const *const ISyncChangeBatch_Vtbl)).EndUnorderedGroup)(this, pmadewithknowledge, fallchangesforknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddLoggedConflict(this: *mut core::ffi::c_void, pbownerreplicaid: *const u8, pbitemid: *const u8, pchangeversion: *const SYNC_VERSION, pcreationversion: *const SYNC_VERSION, dwflags: u32, dwworkforchange: u32, pconflictknowledge: *mut core::ffi::c_void, ppchangebuilder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatch", "AddLoggedConflict", 19usize, |this| ((**(this as *const *const ISyncChangeBatch_Vtbl)).AddLoggedConflict)(this, pbownerreplicaid, pbitemid, pchangeversion, pcreationversion, dwflags, dwworkforchange, pconflictknowledge, ppchangebuilder), |result| Some(*result))
+        }
+        Self { base__: <ISyncChangeBatchBase_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BeginUnorderedGroup, EndUnorderedGroup, AddLoggedConflict }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncChangeBatch as windows_core::Interface>::IID || <ISyncChangeBatchBase_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncChangeBatch2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddMergeTombstoneMetadataToGroup(this: *mut core::ffi::c_void, pbownerreplicaid: *const u8, pbwinneritemid: *const u8, pbitemid: *const u8, pchangeversion: *const SYNC_VERSION, pcreationversion: *const SYNC_VERSION, dwworkforchange: u32, ppchangebuilder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatch2", "AddMergeTombstoneMetadataToGroup", 20usize, |this| ((**(this as *const *const ISyncChangeBatch2_Vtbl)).AddMergeTombstoneMetadataToGroup)(this, pbownerreplicaid, pbwinneritemid, pbitemid, pchangeversion, pcreationversion, dwworkforchange, ppchangebuilder), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddMergeTombstoneLoggedConflict(this: *mut core::ffi::c_void, pbownerreplicaid: *const u8, pbwinneritemid: *const u8, pbitemid: *const u8, pchangeversion: *const SYNC_VERSION, pcreationversion: *const SYNC_VERSION, dwworkforchange: u32, pconflictknowledge: *mut core::ffi::c_void, ppchangebuilder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatch2", "AddMergeTombstoneLoggedConflict", 21usize, |this| ((**(this as *const *const ISyncChangeBatch2_Vtbl)).AddMergeTombstoneLoggedConflict)(this, pbownerreplicaid, pbwinneritemid, pbitemid, pchangeversion, pcreationversion, dwworkforchange, pconflictknowledge, ppchangebuilder), |result| Some(*result))
+        }
+        Self { base__: <ISyncChangeBatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddMergeTombstoneMetadataToGroup, AddMergeTombstoneLoggedConflict }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncChangeBatch2 as windows_core::Interface>::IID || <ISyncChangeBatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncChangeBatchAdvanced_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFilterInfo(this: *mut core::ffi::c_void, ppfilterinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchAdvanced", "GetFilterInfo", 3usize, |this| ((**(this as *const *const ISyncChangeBatchAdvanced_Vtbl)).GetFilterInfo)(this, ppfilterinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertFullEnumerationChangeBatchToRegularChangeBatch(this: *mut core::ffi::c_void, ppchangebatch: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchAdvanced", "ConvertFullEnumerationChangeBatchToRegularChangeBatch", 4usize, |this| ((**(this as *const *const ISyncChangeBatchAdvanced_Vtbl)).ConvertFullEnumerationChangeBatchToRegularChangeBatch)(this, ppchangebatch), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUpperBoundItemId(this: *mut core::ffi::c_void, pbitemid: *mut u8, pcbidsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchAdvanced", "GetUpperBoundItemId", 5usize, |this| ((**(this as *const *const ISyncChangeBatchAdvanced_Vtbl)).GetUpperBoundItemId)(this, pbitemid, pcbidsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBatchLevelKnowledgeShouldBeApplied(this: *mut core::ffi::c_void, pfbatchknowledgeshouldbeapplied: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchAdvanced", "GetBatchLevelKnowledgeShouldBeApplied", 6usize, |this| ((**(this as *const *const ISyncChangeBatchAdvanced_Vtbl)).GetBatchLevelKnowledgeShouldBeApplied)(this, pfbatchknowledgeshouldbeapplied), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetFilterInfo,
+            ConvertFullEnumerationChangeBatchToRegularChangeBatch,
+            GetUpperBoundItemId,
+            GetBatchLevelKnowledgeShouldBeApplied,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncChangeBatchAdvanced as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncChangeBatchBase_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetChangeEnumerator(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchBase", "GetChangeEnumerator", 3usize, |this| ((**(this as *const *const ISyncChangeBatchBase_Vtbl)).GetChangeEnumerator)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIsLastBatch(this: *mut core::ffi::c_void, pflastbatch: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchBase", "GetIsLastBatch", 4usize, |this| ((**(this as *const *const ISyncChangeBatchBase_Vtbl)).GetIsLastBatch)(this, pflastbatch), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWorkEstimateForBatch(this: *mut core::ffi::c_void, pdwworkforbatch: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchBase", "GetWorkEstimateForBatch", 5usize, |this| ((**(this as *const *const ISyncChangeBatchBase_Vtbl)).GetWorkEstimateForBatch)(this, pdwworkforbatch), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRemainingWorkEstimateForSession(this: *mut core::ffi::c_void, pdwremainingworkforsession: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchBase", "GetRemainingWorkEstimateForSession", 6usize, |this| ((**(this as *const *const ISyncChangeBatchBase_Vtbl)).GetRemainingWorkEstimateForSession)(this, pdwremainingworkforsession), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginOrderedGroup(this: *mut core::ffi::c_void, pblowerbound: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchBase", "BeginOrderedGroup", 7usize, |this| ((**(this as *const *const ISyncChangeBatchBase_Vtbl)).BeginOrderedGroup)(this, pblowerbound), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndOrderedGroup(this: *mut core::ffi::c_void, pbupperbound: *const u8, pmadewithknowledge: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchBase", "EndOrderedGroup", 8usize, |this| ((**(this as *const *const ISyncChangeBatchBase_Vtbl)).EndOrderedGroup)(this, pbupperbound, pmadewithknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddItemMetadataToGroup(this: *mut core::ffi::c_void, pbownerreplicaid: *const u8, pbitemid: *const u8, pchangeversion: *const SYNC_VERSION, pcreationversion: *const SYNC_VERSION, dwflags: u32, dwworkforchange: u32, ppchangebuilder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchBase", "AddItemMetadataToGroup", 9usize, |this| ((**(this as *const *const ISyncChangeBatchBase_Vtbl)).AddItemMetadataToGroup)(this, pbownerreplicaid, pbitemid, pchangeversion, pcreationversion, dwflags, dwworkforchange, ppchangebuilder), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLearnedKnowledge(this: *mut core::ffi::c_void, pplearnedknowledge: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchBase", "GetLearnedKnowledge", 10usize, |this| ((**(this as *const *const ISyncChangeBatchBase_Vtbl)).GetLearnedKnowledge)(this, pplearnedknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrerequisiteKnowledge(this: *mut core::ffi::c_void, ppprerequisteknowledge: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchBase", "GetPrerequisiteKnowledge", 11usize, |this| ((**(this as *const *const ISyncChangeBatchBase_Vtbl)).GetPrerequisiteKnowledge)(this, ppprerequisteknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSourceForgottenKnowledge(this: *mut core::ffi::c_void, ppsourceforgottenknowledge: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchBase", "GetSourceForgottenKnowledge", 12usize, |this| ((**(this as *const *const ISyncChangeBatchBase_Vtbl)).GetSourceForgottenKnowledge)(this, ppsourceforgottenknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLastBatch(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchBase", "SetLastBatch", 13usize, |this| ((**(this as *const *const ISyncChangeBatchBase_Vtbl)).SetLastBatch)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWorkEstimateForBatch(this: *mut core::ffi::c_void, dwworkforbatch: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchBase", "SetWorkEstimateForBatch", 14usize, |this| ((**(this as *const *const ISyncChangeBatchBase_Vtbl)).SetWorkEstimateForBatch)(this, dwworkforbatch), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRemainingWorkEstimateForSession(this: *mut core::ffi::c_void, dwremainingworkforsession: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchBase", "SetRemainingWorkEstimateForSession", 15usize, |this| ((**(this as *const *const ISyncChangeBatchBase_Vtbl)).SetRemainingWorkEstimateForSession)(this, dwremainingworkforsession), |result| Some(*result))
+        }
+        unsafe extern "system" fn Serialize(this: *mut core::ffi::c_void, pbchangebatch: *mut u8, pcbchangebatch: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchBase", "Serialize", 16usize, |this| ((**(this as *const *const ISyncChangeBatchBase_Vtbl)).Serialize)(this, pbchangebatch, pcbchangebatch), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetChangeEnumerator,
+            GetIsLastBatch,
+            GetWorkEstimateForBatch,
+            GetRemainingWorkEstimateForSession,
+            BeginOrderedGroup,
+            EndOrderedGroup,
+            AddItemMetadataToGroup,
+            GetLearnedKnowledge,
+            GetPrerequisiteKnowledge,
+            GetSourceForgottenKnowledge,
+            SetLastBatch,
+            SetWorkEstimateForBatch,
+            SetRemainingWorkEstimateForSession,
+            Serialize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncChangeBatchBase as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncChangeBatchBase2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SerializeWithOptions(this: *mut core::ffi::c_void, targetformatversion: SYNC_SERIALIZATION_VERSION, dwflags: u32, pbbuffer: *mut u8, pdwserializedsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchBase2", "SerializeWithOptions", 17usize, |this| ((**(this as *const *const ISyncChangeBatchBase2_Vtbl)).SerializeWithOptions)(this, targetformatversion, dwflags, pbbuffer, pdwserializedsize), |result| Some(*result))
+        }
+        Self { base__: <ISyncChangeBatchBase_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SerializeWithOptions }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncChangeBatchBase2 as windows_core::Interface>::IID || <ISyncChangeBatchBase_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncChangeBatchWithFilterKeyMap_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFilterKeyMap(this: *mut core::ffi::c_void, ppifilterkeymap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchWithFilterKeyMap", "GetFilterKeyMap", 3usize, |this| ((**(this as *const *const ISyncChangeBatchWithFilterKeyMap_Vtbl)).GetFilterKeyMap)(this, ppifilterkeymap), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFilterKeyMap(this: *mut core::ffi::c_void, pifilterkeymap: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchWithFilterKeyMap", "SetFilterKeyMap", 4usize, |this| ((**(this as *const *const ISyncChangeBatchWithFilterKeyMap_Vtbl)).SetFilterKeyMap)(this, pifilterkeymap), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFilterForgottenKnowledge(this: *mut core::ffi::c_void, dwfilterkey: u32, pfilterforgottenknowledge: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchWithFilterKeyMap", "SetFilterForgottenKnowledge", 5usize, |this| ((**(this as *const *const ISyncChangeBatchWithFilterKeyMap_Vtbl)).SetFilterForgottenKnowledge)(this, dwfilterkey, pfilterforgottenknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFilteredReplicaLearnedKnowledge(this: *mut core::ffi::c_void, pdestinationknowledge: *mut core::ffi::c_void, pnewmoveins: *mut core::ffi::c_void, pplearnedforgottenknowledge: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchWithFilterKeyMap", "GetFilteredReplicaLearnedKnowledge", 6usize, |this| ((**(this as *const *const ISyncChangeBatchWithFilterKeyMap_Vtbl)).GetFilteredReplicaLearnedKnowledge)(this, pdestinationknowledge, pnewmoveins, pplearnedforgottenknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLearnedFilterForgottenKnowledge(this: *mut core::ffi::c_void, pdestinationknowledge: *mut core::ffi::c_void, pnewmoveins: *mut core::ffi::c_void, dwfilterkey: u32, pplearnedfilterforgottenknowledge: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchWithFilterKeyMap", "GetLearnedFilterForgottenKnowledge", 7usize, |this| ((**(this as *const *const ISyncChangeBatchWithFilterKeyMap_Vtbl)).GetLearnedFilterForgottenKnowledge)(this, pdestinationknowledge, pnewmoveins, dwfilterkey, pplearnedfilterforgottenknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFilteredReplicaLearnedForgottenKnowledge(this: *mut core::ffi::c_void, pdestinationknowledge: *mut core::ffi::c_void, pnewmoveins: *mut core::ffi::c_void, pplearnedforgottenknowledge: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchWithFilterKeyMap", "GetFilteredReplicaLearnedForgottenKnowledge", 8usize, |this| ((**(this as *const *const ISyncChangeBatchWithFilterKeyMap_Vtbl)).GetFilteredReplicaLearnedForgottenKnowledge)(this, pdestinationknowledge, pnewmoveins, pplearnedforgottenknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFilteredReplicaLearnedForgottenKnowledgeAfterRecoveryComplete(this: *mut core::ffi::c_void, pdestinationknowledge: *mut core::ffi::c_void, pnewmoveins: *mut core::ffi::c_void, pplearnedforgottenknowledge: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchWithFilterKeyMap", "GetFilteredReplicaLearnedForgottenKnowledgeAfterRecoveryComplete", 9usize, |this| ((**(this as *const *const ISyncChangeBatchWithFilterKeyMap_Vtbl)).GetFilteredReplicaLearnedForgottenKnowledgeAfterRecoveryComplete)(this, pdestinationknowledge, pnewmoveins, pplearnedforgottenknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLearnedFilterForgottenKnowledgeAfterRecoveryComplete(this: *mut core::ffi::c_void, pdestinationknowledge: *mut core::ffi::c_void, pnewmoveins: *mut core::ffi::c_void, dwfilterkey: u32, pplearnedfilterforgottenknowledge: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchWithFilterKeyMap", "GetLearnedFilterForgottenKnowledgeAfterRecoveryComplete", 10usize, |this| ((**(this as *const *const ISyncChangeBatchWithFilterKeyMap_Vtbl)).GetLearnedFilterForgottenKnowledgeAfterRecoveryComplete)(this, pdestinationknowledge, pnewmoveins, dwfilterkey, pplearnedfilterforgottenknowledge), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetFilterKeyMap,
+            SetFilterKeyMap,
+            SetFilterForgottenKnowledge,
+            GetFilteredReplicaLearnedKnowledge,
+            GetLearnedFilterForgottenKnowledge,
+            GetFilteredReplicaLearnedForgottenKnowledge,
+            GetFilteredReplicaLearnedForgottenKnowledgeAfterRecoveryComplete,
+            GetLearnedFilterForgottenKnowledgeAfterRecoveryComplete,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncChangeBatchWithFilterKeyMap as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncChangeBatchWithPrerequisite_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPrerequisiteKnowledge(this: *mut core::ffi::c_void, pprerequisiteknowledge: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchWithPrerequisite", "SetPrerequisiteKnowledge", 17usize, |this| ((**(this as *const *const ISyncChangeBatchWithPrerequisite_Vtbl)).SetPrerequisiteKnowledge)(this, pprerequisiteknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLearnedKnowledgeWithPrerequisite(this: *mut core::ffi::c_void, pdestinationknowledge: *mut core::ffi::c_void, pplearnedwithprerequisiteknowledge: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchWithPrerequisite", "GetLearnedKnowledgeWithPrerequisite", 18usize, |this| ((**(this as *const *const ISyncChangeBatchWithPrerequisite_Vtbl)).GetLearnedKnowledgeWithPrerequisite)(this, pdestinationknowledge, pplearnedwithprerequisiteknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLearnedForgottenKnowledge(this: *mut core::ffi::c_void, pplearnedforgottenknowledge: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBatchWithPrerequisite", "GetLearnedForgottenKnowledge", 19usize, |this| ((**(this as *const *const ISyncChangeBatchWithPrerequisite_Vtbl)).GetLearnedForgottenKnowledge)(this, pplearnedforgottenknowledge), |result| Some(*result))
+        }
+        Self {
+            base__: <ISyncChangeBatchBase_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetPrerequisiteKnowledge,
+            GetLearnedKnowledgeWithPrerequisite,
+            GetLearnedForgottenKnowledge,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncChangeBatchWithPrerequisite as windows_core::Interface>::IID || <ISyncChangeBatchBase_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncChangeBuilder_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddChangeUnitMetadata(this: *mut core::ffi::c_void, pbchangeunitid: *const u8, pchangeunitversion: *const SYNC_VERSION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeBuilder", "AddChangeUnitMetadata", 3usize, |this| ((**(this as *const *const ISyncChangeBuilder_Vtbl)).AddChangeUnitMetadata)(this, pbchangeunitid, pchangeunitversion), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddChangeUnitMetadata }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncChangeBuilder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncChangeUnit_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetItemChange(this: *mut core::ffi::c_void, ppsyncchange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeUnit", "GetItemChange", 3usize, |this| ((**(this as *const *const ISyncChangeUnit_Vtbl)).GetItemChange)(this, ppsyncchange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChangeUnitId(this: *mut core::ffi::c_void, pbchangeunitid: *mut u8, pcbidsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeUnit", "GetChangeUnitId", 4usize, |this| ((**(this as *const *const ISyncChangeUnit_Vtbl)).GetChangeUnitId)(this, pbchangeunitid, pcbidsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChangeUnitVersion(this: *mut core::ffi::c_void, pbcurrentreplicaid: *const u8, pversion: *mut SYNC_VERSION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeUnit", "GetChangeUnitVersion", 5usize, |this| ((**(this as *const *const ISyncChangeUnit_Vtbl)).GetChangeUnitVersion)(this, pbcurrentreplicaid, pversion), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetItemChange, GetChangeUnitId, GetChangeUnitVersion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncChangeUnit as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncChangeWithFilterKeyMap_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFilterCount(this: *mut core::ffi::c_void, pdwfiltercount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeWithFilterKeyMap", "GetFilterCount", 3usize, |this| ((**(this as *const *const ISyncChangeWithFilterKeyMap_Vtbl)).GetFilterCount)(this, pdwfiltercount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFilterChange(this: *mut core::ffi::c_void, dwfilterkey: u32, pfilterchange: *mut SYNC_FILTER_CHANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeWithFilterKeyMap", "GetFilterChange", 4usize, |this| ((**(this as *const *const ISyncChangeWithFilterKeyMap_Vtbl)).GetFilterChange)(this, dwfilterkey, pfilterchange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllChangeUnitsPresentFlag(this: *mut core::ffi::c_void, pfallchangeunitspresent: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeWithFilterKeyMap", "GetAllChangeUnitsPresentFlag", 5usize, |this| ((**(this as *const *const ISyncChangeWithFilterKeyMap_Vtbl)).GetAllChangeUnitsPresentFlag)(this, pfallchangeunitspresent), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFilterForgottenKnowledge(this: *mut core::ffi::c_void, dwfilterkey: u32, ppifilterforgottenknowledge: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeWithFilterKeyMap", "GetFilterForgottenKnowledge", 6usize, |this| ((**(this as *const *const ISyncChangeWithFilterKeyMap_Vtbl)).GetFilterForgottenKnowledge)(this, dwfilterkey, ppifilterforgottenknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFilteredReplicaLearnedKnowledge(this: *mut core::ffi::c_void, pdestinationknowledge: *mut core::ffi::c_void, pnewmoveins: *mut core::ffi::c_void, pplearnedknowledge: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeWithFilterKeyMap", "GetFilteredReplicaLearnedKnowledge", 7usize, |this| ((**(this as *const *const ISyncChangeWithFilterKeyMap_Vtbl)).GetFilteredReplicaLearnedKnowledge)(this, pdestinationknowledge, pnewmoveins, pplearnedknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLearnedFilterForgottenKnowledge(this: *mut core::ffi::c_void, pdestinationknowledge: *mut core::ffi::c_void, pnewmoveins: *mut core::ffi::c_void, dwfilterkey: u32, pplearnedfilterforgottenknowledge: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeWithFilterKeyMap", "GetLearnedFilterForgottenKnowledge", 8usize, |this| ((**(this as *const *const ISyncChangeWithFilterKeyMap_Vtbl)).GetLearnedFilterForgottenKnowledge)(this, pdestinationknowledge, pnewmoveins, dwfilterkey, pplearnedfilterforgottenknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFilteredReplicaLearnedForgottenKnowledge(this: *mut core::ffi::c_void, pdestinationknowledge: *mut core::ffi::c_void, pnewmoveins: *mut core::ffi::c_void, pplearnedforgottenknowledge: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeWithFilterKeyMap", "GetFilteredReplicaLearnedForgottenKnowledge", 9usize, |this| ((**(this as *const *const ISyncChangeWithFilterKeyMap_Vtbl)).GetFilteredReplicaLearnedForgottenKnowledge)(this, pdestinationknowledge, pnewmoveins, pplearnedforgottenknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFilteredReplicaLearnedForgottenKnowledgeAfterRecoveryComplete(this: *mut core::ffi::c_void, pdestinationknowledge: *mut core::ffi::c_void, pnewmoveins: *mut core::ffi::c_void, pplearnedforgottenknowledge: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeWithFilterKeyMap", "GetFilteredReplicaLearnedForgottenKnowledgeAfterRecoveryComplete", 10usize, |this| ((**(this as *const *const ISyncChangeWithFilterKeyMap_Vtbl)).GetFilteredReplicaLearnedForgottenKnowledgeAfterRecoveryComplete)(this, pdestinationknowledge, pnewmoveins, pplearnedforgottenknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLearnedFilterForgottenKnowledgeAfterRecoveryComplete(this: *mut core::ffi::c_void, pdestinationknowledge: *mut core::ffi::c_void, pnewmoveins: *mut core::ffi::c_void, dwfilterkey: u32, pplearnedfilterforgottenknowledge: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeWithFilterKeyMap", "GetLearnedFilterForgottenKnowledgeAfterRecoveryComplete", 11usize, |this| ((**(this as *const *const ISyncChangeWithFilterKeyMap_Vtbl)).GetLearnedFilterForgottenKnowledgeAfterRecoveryComplete)(this, pdestinationknowledge, pnewmoveins, dwfilterkey, pplearnedfilterforgottenknowledge), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetFilterCount,
+            GetFilterChange,
+            GetAllChangeUnitsPresentFlag,
+            GetFilterForgottenKnowledge,
+            GetFilteredReplicaLearnedKnowledge,
+            GetLearnedFilterForgottenKnowledge,
+            GetFilteredReplicaLearnedForgottenKnowledge,
+            GetFilteredReplicaLearnedForgottenKnowledgeAfterRecoveryComplete,
+            GetLearnedFilterForgottenKnowledgeAfterRecoveryComplete,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncChangeWithFilterKeyMap as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncChangeWithPrerequisite_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPrerequisiteKnowledge(this: *mut core::ffi::c_void, ppprerequisiteknowledge: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeWithPrerequisite", "GetPrerequisiteKnowledge", 3usize, |this| ((**(this as *const *const ISyncChangeWithPrerequisite_Vtbl)).GetPrerequisiteKnowledge)(this, ppprerequisiteknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLearnedKnowledgeWithPrerequisite(this: *mut core::ffi::c_void, pdestinationknowledge: *mut core::ffi::c_void, pplearnedknowledgewithprerequisite: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncChangeWithPrerequisite", "GetLearnedKnowledgeWithPrerequisite", 4usize, |this| ((**(this as *const *const ISyncChangeWithPrerequisite_Vtbl)).GetLearnedKnowledgeWithPrerequisite)(this, pdestinationknowledge, pplearnedknowledgewithprerequisite), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPrerequisiteKnowledge, GetLearnedKnowledgeWithPrerequisite }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncChangeWithPrerequisite as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncConstraintCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnConstraintConflict(this: *mut core::ffi::c_void, pconflict: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncConstraintCallback", "OnConstraintConflict", 3usize, |this| ((**(this as *const *const ISyncConstraintCallback_Vtbl)).OnConstraintConflict)(this, pconflict), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnConstraintConflict }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncConstraintCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncDataConverter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConvertDataRetrieverFromProviderFormat(this: *mut core::ffi::c_void, punkdataretrieverin: *mut core::ffi::c_void, penumsyncchanges: *mut core::ffi::c_void, ppunkdataout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncDataConverter", "ConvertDataRetrieverFromProviderFormat", 3usize, |this| ((**(this as *const *const ISyncDataConverter_Vtbl)).ConvertDataRetrieverFromProviderFormat)(this, punkdataretrieverin, penumsyncchanges, ppunkdataout), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertDataRetrieverToProviderFormat(this: *mut core::ffi::c_void, punkdataretrieverin: *mut core::ffi::c_void, penumsyncchanges: *mut core::ffi::c_void, ppunkdataout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncDataConverter", "ConvertDataRetrieverToProviderFormat", 4usize, |this| ((**(this as *const *const ISyncDataConverter_Vtbl)).ConvertDataRetrieverToProviderFormat)(this, punkdataretrieverin, penumsyncchanges, ppunkdataout), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertDataFromProviderFormat(this: *mut core::ffi::c_void, pdatacontext: *mut core::ffi::c_void, punkdatain: *mut core::ffi::c_void, ppunkdataout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncDataConverter", "ConvertDataFromProviderFormat", 5usize, |this| ((**(this as *const *const ISyncDataConverter_Vtbl)).ConvertDataFromProviderFormat)(this, pdatacontext, punkdatain, ppunkdataout), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertDataToProviderFormat(this: *mut core::ffi::c_void, pdatacontext: *mut core::ffi::c_void, punkdataout: *mut core::ffi::c_void, ppunkdataout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncDataConverter", "ConvertDataToProviderFormat", 6usize, |this| ((**(this as *const *const ISyncDataConverter_Vtbl)).ConvertDataToProviderFormat)(this, pdatacontext, punkdataout, ppunkdataout), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ConvertDataRetrieverFromProviderFormat,
+            ConvertDataRetrieverToProviderFormat,
+            ConvertDataFromProviderFormat,
+            ConvertDataToProviderFormat,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncDataConverter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsIdentical(this: *mut core::ffi::c_void, psyncfilter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncFilter", "IsIdentical", 3usize, |this| ((**(this as *const *const ISyncFilter_Vtbl)).IsIdentical)(this, psyncfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn Serialize(this: *mut core::ffi::c_void, pbsyncfilter: *mut u8, pcbsyncfilter: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncFilter", "Serialize", 4usize, |this| ((**(this as *const *const ISyncFilter_Vtbl)).Serialize)(this, pbsyncfilter, pcbsyncfilter), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsIdentical, Serialize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncFilter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncFilterDeserializer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeserializeSyncFilter(this: *mut core::ffi::c_void, pbsyncfilter: *const u8, dwcbsyncfilter: u32, ppisyncfilter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncFilterDeserializer", "DeserializeSyncFilter", 3usize, |this| ((**(this as *const *const ISyncFilterDeserializer_Vtbl)).DeserializeSyncFilter)(this, pbsyncfilter, dwcbsyncfilter, ppisyncfilter), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeserializeSyncFilter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncFilterDeserializer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncFilterInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Serialize(this: *mut core::ffi::c_void, pbbuffer: *mut u8, pcbbuffer: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncFilterInfo", "Serialize", 3usize, |this| ((**(this as *const *const ISyncFilterInfo_Vtbl)).Serialize)(this, pbbuffer, pcbbuffer), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Serialize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncFilterInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncFilterInfo2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFlags(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncFilterInfo2", "GetFlags", 4usize, |this| ((**(this as *const *const ISyncFilterInfo2_Vtbl)).GetFlags)(this, pdwflags), |result| Some(*result))
+        }
+        Self { base__: <ISyncFilterInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetFlags }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncFilterInfo2 as windows_core::Interface>::IID || <ISyncFilterInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncFullEnumerationChange_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLearnedKnowledgeAfterRecoveryComplete(this: *mut core::ffi::c_void, pplearnedknowledge: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncFullEnumerationChange", "GetLearnedKnowledgeAfterRecoveryComplete", 3usize, |this| ((**(this as *const *const ISyncFullEnumerationChange_Vtbl)).GetLearnedKnowledgeAfterRecoveryComplete)(this, pplearnedknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLearnedForgottenKnowledge(this: *mut core::ffi::c_void, pplearnedforgottenknowledge: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncFullEnumerationChange", "GetLearnedForgottenKnowledge", 4usize, |this| ((**(this as *const *const ISyncFullEnumerationChange_Vtbl)).GetLearnedForgottenKnowledge)(this, pplearnedforgottenknowledge), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetLearnedKnowledgeAfterRecoveryComplete,
+            GetLearnedForgottenKnowledge,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncFullEnumerationChange as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncFullEnumerationChangeBatch_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLearnedKnowledgeAfterRecoveryComplete(this: *mut core::ffi::c_void, pplearnedknowledgeafterrecoverycomplete: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncFullEnumerationChangeBatch", "GetLearnedKnowledgeAfterRecoveryComplete", 17usize, |this| ((**(this as *const *const ISyncFullEnumerationChangeBatch_Vtbl)).GetLearnedKnowledgeAfterRecoveryComplete)(this, pplearnedknowledgeafterrecoverycomplete), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClosedLowerBoundItemId(this: *mut core::ffi::c_void, pbclosedlowerbounditemid: *mut u8, pcbidsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncFullEnumerationChangeBatch", "GetClosedLowerBoundItemId", 18usize, |this| ((**(this as *const *const ISyncFullEnumerationChangeBatch_Vtbl)).GetClosedLowerBoundItemId)(this, pbclosedlowerbounditemid, pcbidsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClosedUpperBoundItemId(this: *mut core::ffi::c_void, pbclosedupperbounditemid: *mut u8, pcbidsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncFullEnumerationChangeBatch", "GetClosedUpperBoundItemId", 19usize, |this| ((**(this as *const *const ISyncFullEnumerationChangeBatch_Vtbl)).GetClosedUpperBoundItemId)(this, pbclosedupperbounditemid, pcbidsize), |result| Some(*result))
+        }
+        Self {
+            base__: <ISyncChangeBatchBase_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetLearnedKnowledgeAfterRecoveryComplete,
+            GetClosedLowerBoundItemId,
+            GetClosedUpperBoundItemId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncFullEnumerationChangeBatch as windows_core::Interface>::IID || <ISyncChangeBatchBase_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncFullEnumerationChangeBatch2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddMergeTombstoneMetadataToGroup(this: *mut core::ffi::c_void, pbownerreplicaid: *const u8, pbwinneritemid: *const u8, pbitemid: *const u8, pchangeversion: *const SYNC_VERSION, pcreationversion: *const SYNC_VERSION, dwworkforchange: u32, ppchangebuilder: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncFullEnumerationChangeBatch2", "AddMergeTombstoneMetadataToGroup", 20usize, |this| ((**(this as *const *const ISyncFullEnumerationChangeBatch2_Vtbl)).AddMergeTombstoneMetadataToGroup)(this, pbownerreplicaid, pbwinneritemid, pbitemid, pchangeversion, pcreationversion, dwworkforchange, ppchangebuilder), |result| Some(*result))
+        }
+        Self { base__: <ISyncFullEnumerationChangeBatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddMergeTombstoneMetadataToGroup }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncFullEnumerationChangeBatch2 as windows_core::Interface>::IID || <ISyncFullEnumerationChangeBatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncKnowledge_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOwnerReplicaId(this: *mut core::ffi::c_void, pbreplicaid: *mut u8, pcbidsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge", "GetOwnerReplicaId", 3usize, |this| ((**(this as *const *const ISyncKnowledge_Vtbl)).GetOwnerReplicaId)(this, pbreplicaid, pcbidsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn Serialize(this: *mut core::ffi::c_void, fserializereplicakeymap: super::super::Foundation::BOOL, pbknowledge: *mut u8, pcbknowledge: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge", "Serialize", 4usize, |this| ((**(this as *const *const ISyncKnowledge_Vtbl)).Serialize)(this, fserializereplicakeymap, pbknowledge, pcbknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocalTickCount(this: *mut core::ffi::c_void, ulltickcount: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge", "SetLocalTickCount", 5usize, |this| ((**(this as *const *const ISyncKnowledge_Vtbl)).SetLocalTickCount)(this, ulltickcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContainsChange(this: *mut core::ffi::c_void, pbversionownerreplicaid: *const u8, pgiditemid: *const u8, psyncversion: *const SYNC_VERSION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge", "ContainsChange", 6usize, |this| ((**(this as *const *const ISyncKnowledge_Vtbl)).ContainsChange)(this, pbversionownerreplicaid, pgiditemid, psyncversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContainsChangeUnit(this: *mut core::ffi::c_void, pbversionownerreplicaid: *const u8, pbitemid: *const u8, pbchangeunitid: *const u8, psyncversion: *const SYNC_VERSION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge", "ContainsChangeUnit", 7usize, |this| ((**(this as *const *const ISyncKnowledge_Vtbl)).ContainsChangeUnit)(this, pbversionownerreplicaid, pbitemid, pbchangeunitid, psyncversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScopeVector(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge", "GetScopeVector", 8usize, |this| ((**(this as *const *const ISyncKnowledge_Vtbl)).GetScopeVector)(this, riid, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReplicaKeyMap(this: *mut core::ffi::c_void, ppreplicakeymap: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge", "GetReplicaKeyMap", 9usize, |this| ((**(this as *const *const ISyncKnowledge_Vtbl)).GetReplicaKeyMap)(this, ppreplicakeymap), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppclonedknowledge: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge", "Clone", 10usize, |this| ((**(this as *const *const ISyncKnowledge_Vtbl)).Clone)(this, ppclonedknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertVersion(this: *mut core::ffi::c_void, pknowledgein: *mut core::ffi::c_void, pbcurrentownerid: *const u8, pversionin: *const SYNC_VERSION, pbnewownerid: *mut u8, pcbidsize: *mut u32, pversionout: *mut SYNC_VERSION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge", "ConvertVersion", 11usize, |this| ((**(this as *const *const ISyncKnowledge_Vtbl)).ConvertVersion)(this, pknowledgein, pbcurrentownerid, pversionin, pbnewownerid, pcbidsize, pversionout), |result| Some(*result))
+        }
+        unsafe extern "system" fn MapRemoteToLocal(this: *mut core::ffi::c_void, premoteknowledge: *mut core::ffi::c_void, ppmappedknowledge: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge", "MapRemoteToLocal", 12usize, |this| ((**(this as *const *const ISyncKnowledge_Vtbl)).MapRemoteToLocal)(this, premoteknowledge, ppmappedknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn Union(this: *mut core::ffi::c_void, pknowledge: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge", "Union", 13usize, |this| ((**(this as *const *const ISyncKnowledge_Vtbl)).Union)(this, pknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProjectOntoItem(this: *mut core::ffi::c_void, pbitemid: *const u8, ppknowledgeout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge", "ProjectOntoItem", 14usize, |this| ((**(this as *const *const ISyncKnowledge_Vtbl)).ProjectOntoItem)(this, pbitemid, ppknowledgeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProjectOntoChangeUnit(this: *mut core::ffi::c_void, pbitemid: *const u8, pbchangeunitid: *const u8, ppknowledgeout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge", "ProjectOntoChangeUnit", 15usize, |this| ((**(this as *const *const ISyncKnowledge_Vtbl)).ProjectOntoChangeUnit)(this, pbitemid, pbchangeunitid, ppknowledgeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProjectOntoRange(this: *mut core::ffi::c_void, psrngsyncrange: *const SYNC_RANGE, ppknowledgeout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge", "ProjectOntoRange", 16usize, |this| ((**(this as *const *const ISyncKnowledge_Vtbl)).ProjectOntoRange)(this, psrngsyncrange, ppknowledgeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExcludeItem(this: *mut core::ffi::c_void, pbitemid: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge", "ExcludeItem", 17usize, |this| ((**(this as *const *const ISyncKnowledge_Vtbl)).ExcludeItem)(this, pbitemid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExcludeChangeUnit(this: *mut core::ffi::c_void, pbitemid: *const u8, pbchangeunitid: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge", "ExcludeChangeUnit", 18usize, |this| ((**(this as *const *const ISyncKnowledge_Vtbl)).ExcludeChangeUnit)(this, pbitemid, pbchangeunitid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContainsKnowledge(this: *mut core::ffi::c_void, pknowledge: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge", "ContainsKnowledge", 19usize, |this| ((**(this as *const *const ISyncKnowledge_Vtbl)).ContainsKnowledge)(this, pknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindMinTickCountForReplica(this: *mut core::ffi::c_void, pbreplicaid: *const u8, pullreplicatickcount: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge", "FindMinTickCountForReplica", 20usize, |this| ((**(this as *const *const ISyncKnowledge_Vtbl)).FindMinTickCountForReplica)(this, pbreplicaid, pullreplicatickcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRangeExceptions(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge", "GetRangeExceptions", 21usize, |this| ((**(this as *const *const ISyncKnowledge_Vtbl)).GetRangeExceptions)(this, riid, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSingleItemExceptions(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge", "GetSingleItemExceptions", 22usize, |this| ((**(this as *const *const ISyncKnowledge_Vtbl)).GetSingleItemExceptions)(this, riid, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChangeUnitExceptions(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge", "GetChangeUnitExceptions", 23usize, |this| ((**(this as *const *const ISyncKnowledge_Vtbl)).GetChangeUnitExceptions)(this, riid, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindClockVectorForItem(this: *mut core::ffi::c_void, pbitemid: *const u8, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge", "FindClockVectorForItem", 24usize, |this| ((**(this as *const *const ISyncKnowledge_Vtbl)).FindClockVectorForItem)(this, pbitemid, riid, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindClockVectorForChangeUnit(this: *mut core::ffi::c_void, pbitemid: *const u8, pbchangeunitid: *const u8, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge", "FindClockVectorForChangeUnit", 25usize, |this| ((**(this as *const *const ISyncKnowledge_Vtbl)).FindClockVectorForChangeUnit)(this, pbitemid, pbchangeunitid, riid, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersion(this: *mut core::ffi::c_void, pdwversion: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge", "GetVersion", 26usize, |this| ((**(this as *const *const ISyncKnowledge_Vtbl)).GetVersion)(this, pdwversion), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetOwnerReplicaId,
+            Serialize,
+            SetLocalTickCount,
+            ContainsChange,
+            ContainsChangeUnit,
+            GetScopeVector,
+            GetReplicaKeyMap,
+            Clone,
+            ConvertVersion,
+            MapRemoteToLocal,
+            Union,
+            ProjectOntoItem,
+            ProjectOntoChangeUnit,
+            ProjectOntoRange,
+            ExcludeItem,
+            ExcludeChangeUnit,
+            ContainsKnowledge,
+            FindMinTickCountForReplica,
+            GetRangeExceptions,
+            GetSingleItemExceptions,
+            GetChangeUnitExceptions,
+            FindClockVectorForItem,
+            FindClockVectorForChangeUnit,
+            GetVersion,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncKnowledge as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncKnowledge2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetIdParameters(this: *mut core::ffi::c_void, pidparameters: *mut ID_PARAMETERS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge2", "GetIdParameters", 27usize, |this| ((**(this as *const *const ISyncKnowledge2_Vtbl)).GetIdParameters)(this, pidparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProjectOntoColumnSet(this: *mut core::ffi::c_void, ppcolumns: *const *const u8, count: u32, ppiknowledgeout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge2", "ProjectOntoColumnSet", 28usize, |this| ((**(this as *const *const ISyncKnowledge2_Vtbl)).ProjectOntoColumnSet)(this, ppcolumns, count, ppiknowledgeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn SerializeWithOptions(this: *mut core::ffi::c_void, targetformatversion: SYNC_SERIALIZATION_VERSION, dwflags: u32, pbbuffer: *mut u8, pdwserializedsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge2", "SerializeWithOptions", 29usize, |this| ((**(this as *const *const ISyncKnowledge2_Vtbl)).SerializeWithOptions)(this, targetformatversion, dwflags, pbbuffer, pdwserializedsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLowestUncontainedId(this: *mut core::ffi::c_void, pisyncknowledge: *mut core::ffi::c_void, pbitemid: *mut u8, pcbitemidsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge2", "GetLowestUncontainedId", 30usize, |this| ((**(this as *const *const ISyncKnowledge2_Vtbl)).GetLowestUncontainedId)(this, pisyncknowledge, pbitemid, pcbitemidsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInspector(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppiinspector: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge2", "GetInspector", 31usize, |this| ((**(this as *const *const ISyncKnowledge2_Vtbl)).GetInspector)(this, riid, ppiinspector), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMinimumSupportedVersion(this: *mut core::ffi::c_void, pversion: *mut SYNC_SERIALIZATION_VERSION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge2", "GetMinimumSupportedVersion", 32usize, |this| ((**(this as *const *const ISyncKnowledge2_Vtbl)).GetMinimumSupportedVersion)(this, pversion), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatistics(this: *mut core::ffi::c_void, which: SYNC_STATISTICS, pvalue: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge2", "GetStatistics", 33usize, |this| ((**(this as *const *const ISyncKnowledge2_Vtbl)).GetStatistics)(this, which, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContainsKnowledgeForItem(this: *mut core::ffi::c_void, pknowledge: *mut core::ffi::c_void, pbitemid: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge2", "ContainsKnowledgeForItem", 34usize, |this| ((**(this as *const *const ISyncKnowledge2_Vtbl)).ContainsKnowledgeForItem)(this, pknowledge, pbitemid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContainsKnowledgeForChangeUnit(this: *mut core::ffi::c_void, pknowledge: *mut core::ffi::c_void, pbitemid: *const u8, pbchangeunitid: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge2", "ContainsKnowledgeForChangeUnit", 35usize, |this| ((**(this as *const *const ISyncKnowledge2_Vtbl)).ContainsKnowledgeForChangeUnit)(this, pknowledge, pbitemid, pbchangeunitid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProjectOntoKnowledgeWithPrerequisite(this: *mut core::ffi::c_void, pprerequisiteknowledge: *mut core::ffi::c_void, ptemplateknowledge: *mut core::ffi::c_void, ppprojectedknowledge: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge2", "ProjectOntoKnowledgeWithPrerequisite", 36usize, |this| ((**(this as *const *const ISyncKnowledge2_Vtbl)).ProjectOntoKnowledgeWithPrerequisite)(this, pprerequisiteknowledge, ptemplateknowledge, ppprojectedknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn Complement(this: *mut core::ffi::c_void, psyncknowledge: *mut core::ffi::c_void, ppcomplementedknowledge: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge2", "Complement", 37usize, |this| ((**(this as *const *const ISyncKnowledge2_Vtbl)).Complement)(this, psyncknowledge, ppcomplementedknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn IntersectsWithKnowledge(this: *mut core::ffi::c_void, psyncknowledge: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge2", "IntersectsWithKnowledge", 38usize, |this| ((**(this as *const *const ISyncKnowledge2_Vtbl)).IntersectsWithKnowledge)(this, psyncknowledge), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKnowledgeCookie(this: *mut core::ffi::c_void, ppknowledgecookie: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge2", "GetKnowledgeCookie", 39usize, |this| ((**(this as *const *const ISyncKnowledge2_Vtbl)).GetKnowledgeCookie)(this, ppknowledgecookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompareToKnowledgeCookie(this: *mut core::ffi::c_void, pknowledgecookie: *mut core::ffi::c_void, presult: *mut KNOWLEDGE_COOKIE_COMPARISON_RESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncKnowledge2", "CompareToKnowledgeCookie", 40usize, |this| ((**(this as *const *const ISyncKnowledge2_Vtbl)).CompareToKnowledgeCookie)(this, pknowledgecookie, presult), |result| Some(*result))
+        }
+        Self {
+            base__: <ISyncKnowledge_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetIdParameters,
+            ProjectOntoColumnSet,
+            SerializeWithOptions,
+            GetLowestUncontainedId,
+            GetInspector,
+            GetMinimumSupportedVersion,
+            GetStatistics,
+            ContainsKnowledgeForItem,
+            ContainsKnowledgeForChangeUnit,
+            ProjectOntoKnowledgeWithPrerequisite,
+            Complement,
+            IntersectsWithKnowledge,
+            GetKnowledgeCookie,
+            CompareToKnowledgeCookie,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncKnowledge2 as windows_core::Interface>::IID || <ISyncKnowledge_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncMergeTombstoneChange_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetWinnerItemId(this: *mut core::ffi::c_void, pbwinneritemid: *mut u8, pcbidsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncMergeTombstoneChange", "GetWinnerItemId", 3usize, |this| ((**(this as *const *const ISyncMergeTombstoneChange_Vtbl)).GetWinnerItemId)(this, pbwinneritemid, pcbidsize), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetWinnerItemId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncMergeTombstoneChange as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetIdParameters(this: *mut core::ffi::c_void, pidparameters: *mut ID_PARAMETERS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncProvider", "GetIdParameters", 3usize, |this| ((**(this as *const *const ISyncProvider_Vtbl)).GetIdParameters)(this, pidparameters), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetIdParameters }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for ISyncProviderConfigUI_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn Init(this: *mut core::ffi::c_void, pguidinstanceid: *const windows_core::GUID, pguidcontenttype: *const windows_core::GUID, pconfigurationproperties: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncProviderConfigUI", "Init", 3usize, |this| ((**(this as *const *const ISyncProviderConfigUI_Vtbl)).Init)(this, pguidinstanceid, pguidcontenttype, pconfigurationproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetRegisteredProperties(this: *mut core::ffi::c_void, ppconfiguiproperties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncProviderConfigUI", "GetRegisteredProperties", 4usize, |this| ((**(this as *const *const ISyncProviderConfigUI_Vtbl)).GetRegisteredProperties)(this, ppconfiguiproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn CreateAndRegisterNewSyncProvider(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, punkcontext: *mut core::ffi::c_void, ppproviderinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncProviderConfigUI", "CreateAndRegisterNewSyncProvider", 5usize, |this| ((**(this as *const *const ISyncProviderConfigUI_Vtbl)).CreateAndRegisterNewSyncProvider)(this, hwndparent, punkcontext, ppproviderinfo), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn ModifySyncProvider(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, punkcontext: *mut core::ffi::c_void, pproviderinfo: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncProviderConfigUI", "ModifySyncProvider", 6usize, |this| ((**(this as *const *const ISyncProviderConfigUI_Vtbl)).ModifySyncProvider)(this, hwndparent, punkcontext, pproviderinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            Init,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            Init: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetRegisteredProperties,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetRegisteredProperties: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            CreateAndRegisterNewSyncProvider,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            CreateAndRegisterNewSyncProvider: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            ModifySyncProvider,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            ModifySyncProvider: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncProviderConfigUI as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for ISyncProviderConfigUIInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSyncProviderConfigUI(this: *mut core::ffi::c_void, dwclscontext: u32, ppsyncproviderconfigui: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncProviderConfigUIInfo", "GetSyncProviderConfigUI", 8usize, |this| ((**(this as *const *const ISyncProviderConfigUIInfo_Vtbl)).GetSyncProviderConfigUI)(this, dwclscontext, ppsyncproviderconfigui), |result| Some(*result))
+        }
+        Self { base__: <super::super::UI::Shell::PropertiesSystem::IPropertyStore_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSyncProviderConfigUI }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncProviderConfigUIInfo as windows_core::Interface>::IID || <super::super::UI::Shell::PropertiesSystem::IPropertyStore_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for ISyncProviderInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSyncProvider(this: *mut core::ffi::c_void, dwclscontext: u32, ppsyncprovider: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncProviderInfo", "GetSyncProvider", 8usize, |this| ((**(this as *const *const ISyncProviderInfo_Vtbl)).GetSyncProvider)(this, dwclscontext, ppsyncprovider), |result| Some(*result))
+        }
+        Self { base__: <super::super::UI::Shell::PropertiesSystem::IPropertyStore_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSyncProvider }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncProviderInfo as windows_core::Interface>::IID || <super::super::UI::Shell::PropertiesSystem::IPropertyStore_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for ISyncProviderRegistration_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn CreateSyncProviderConfigUIRegistrationInstance(this: *mut core::ffi::c_void, pconfiguiconfig: *const SyncProviderConfigUIConfiguration, ppconfiguiinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncProviderRegistration", "CreateSyncProviderConfigUIRegistrationInstance", 3usize, |this| ((**(this as *const *const ISyncProviderRegistration_Vtbl)).CreateSyncProviderConfigUIRegistrationInstance)(this, pconfiguiconfig, ppconfiguiinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterSyncProviderConfigUI(this: *mut core::ffi::c_void, pguidinstanceid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncProviderRegistration", "UnregisterSyncProviderConfigUI", 4usize, |this| ((**(this as *const *const ISyncProviderRegistration_Vtbl)).UnregisterSyncProviderConfigUI)(this, pguidinstanceid), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateSyncProviderConfigUIs(this: *mut core::ffi::c_void, pguidcontenttype: *const windows_core::GUID, dwsupportedarchitecture: u32, ppenumsyncproviderconfiguiinfos: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncProviderRegistration", "EnumerateSyncProviderConfigUIs", 5usize, |this| ((**(this as *const *const ISyncProviderRegistration_Vtbl)).EnumerateSyncProviderConfigUIs)(this, pguidcontenttype, dwsupportedarchitecture, ppenumsyncproviderconfiguiinfos), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn CreateSyncProviderRegistrationInstance(this: *mut core::ffi::c_void, pproviderconfiguration: *const SyncProviderConfiguration, ppproviderinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncProviderRegistration", "CreateSyncProviderRegistrationInstance", 6usize, |this| ((**(this as *const *const ISyncProviderRegistration_Vtbl)).CreateSyncProviderRegistrationInstance)(this, pproviderconfiguration, ppproviderinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterSyncProvider(this: *mut core::ffi::c_void, pguidinstanceid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncProviderRegistration", "UnregisterSyncProvider", 7usize, |this| ((**(this as *const *const ISyncProviderRegistration_Vtbl)).UnregisterSyncProvider)(this, pguidinstanceid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetSyncProviderConfigUIInfoforProvider(this: *mut core::ffi::c_void, pguidproviderinstanceid: *const windows_core::GUID, ppproviderconfiguiinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncProviderRegistration", "GetSyncProviderConfigUIInfoforProvider", 8usize, |this| ((**(this as *const *const ISyncProviderRegistration_Vtbl)).GetSyncProviderConfigUIInfoforProvider)(this, pguidproviderinstanceid, ppproviderconfiguiinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumerateSyncProviders(this: *mut core::ffi::c_void, pguidcontenttype: *const windows_core::GUID, dwstateflagstofiltermask: u32, dwstateflagstofilter: u32, refproviderclsid: *const windows_core::GUID, dwsupportedarchitecture: u32, ppenumsyncproviderinfos: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncProviderRegistration", "EnumerateSyncProviders", 9usize, |this| ((**(this as *const *const ISyncProviderRegistration_Vtbl)).EnumerateSyncProviders)(this, pguidcontenttype, dwstateflagstofiltermask, dwstateflagstofilter, refproviderclsid, dwsupportedarchitecture, ppenumsyncproviderinfos), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetSyncProviderInfo(this: *mut core::ffi::c_void, pguidinstanceid: *const windows_core::GUID, ppproviderinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncProviderRegistration", "GetSyncProviderInfo", 10usize, |this| ((**(this as *const *const ISyncProviderRegistration_Vtbl)).GetSyncProviderInfo)(this, pguidinstanceid, ppproviderinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSyncProviderFromInstanceId(this: *mut core::ffi::c_void, pguidinstanceid: *const windows_core::GUID, dwclscontext: u32, ppsyncprovider: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncProviderRegistration", "GetSyncProviderFromInstanceId", 11usize, |this| ((**(this as *const *const ISyncProviderRegistration_Vtbl)).GetSyncProviderFromInstanceId)(this, pguidinstanceid, dwclscontext, ppsyncprovider), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetSyncProviderConfigUIInfo(this: *mut core::ffi::c_void, pguidinstanceid: *const windows_core::GUID, ppconfiguiinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncProviderRegistration", "GetSyncProviderConfigUIInfo", 12usize, |this| ((**(this as *const *const ISyncProviderRegistration_Vtbl)).GetSyncProviderConfigUIInfo)(this, pguidinstanceid, ppconfiguiinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSyncProviderConfigUIFromInstanceId(this: *mut core::ffi::c_void, pguidinstanceid: *const windows_core::GUID, dwclscontext: u32, ppconfigui: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncProviderRegistration", "GetSyncProviderConfigUIFromInstanceId", 13usize, |this| ((**(this as *const *const ISyncProviderRegistration_Vtbl)).GetSyncProviderConfigUIFromInstanceId)(this, pguidinstanceid, dwclscontext, ppconfigui), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSyncProviderState(this: *mut core::ffi::c_void, pguidinstanceid: *const windows_core::GUID, pdwstateflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncProviderRegistration", "GetSyncProviderState", 14usize, |this| ((**(this as *const *const ISyncProviderRegistration_Vtbl)).GetSyncProviderState)(this, pguidinstanceid, pdwstateflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSyncProviderState(this: *mut core::ffi::c_void, pguidinstanceid: *const windows_core::GUID, dwstateflagsmask: u32, dwstateflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncProviderRegistration", "SetSyncProviderState", 15usize, |this| ((**(this as *const *const ISyncProviderRegistration_Vtbl)).SetSyncProviderState)(this, pguidinstanceid, dwstateflagsmask, dwstateflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterForEvent(this: *mut core::ffi::c_void, phevent: *mut super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncProviderRegistration", "RegisterForEvent", 16usize, |this| ((**(this as *const *const ISyncProviderRegistration_Vtbl)).RegisterForEvent)(this, phevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn RevokeEvent(this: *mut core::ffi::c_void, hevent: super::super::Foundation::HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncProviderRegistration", "RevokeEvent", 17usize, |this| ((**(this as *const *const ISyncProviderRegistration_Vtbl)).RevokeEvent)(this, hevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChange(this: *mut core::ffi::c_void, hevent: super::super::Foundation::HANDLE, ppchange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncProviderRegistration", "GetChange", 18usize, |this| ((**(this as *const *const ISyncProviderRegistration_Vtbl)).GetChange)(this, hevent, ppchange), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            CreateSyncProviderConfigUIRegistrationInstance,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            CreateSyncProviderConfigUIRegistrationInstance: 0,
+            UnregisterSyncProviderConfigUI,
+            EnumerateSyncProviderConfigUIs,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            CreateSyncProviderRegistrationInstance,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            CreateSyncProviderRegistrationInstance: 0,
+            UnregisterSyncProvider,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetSyncProviderConfigUIInfoforProvider,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetSyncProviderConfigUIInfoforProvider: 0,
+            EnumerateSyncProviders,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetSyncProviderInfo,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetSyncProviderInfo: 0,
+            GetSyncProviderFromInstanceId,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetSyncProviderConfigUIInfo,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetSyncProviderConfigUIInfo: 0,
+            GetSyncProviderConfigUIFromInstanceId,
+            GetSyncProviderState,
+            SetSyncProviderState,
+            RegisterForEvent,
+            RevokeEvent,
+            GetChange,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncProviderRegistration as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncRegistrationChange_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEvent(this: *mut core::ffi::c_void, psreevent: *mut SYNC_REGISTRATION_EVENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncRegistrationChange", "GetEvent", 3usize, |this| ((**(this as *const *const ISyncRegistrationChange_Vtbl)).GetEvent)(this, psreevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInstanceId(this: *mut core::ffi::c_void, pguidinstanceid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncRegistrationChange", "GetInstanceId", 4usize, |this| ((**(this as *const *const ISyncRegistrationChange_Vtbl)).GetInstanceId)(this, pguidinstanceid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetEvent, GetInstanceId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncRegistrationChange as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncSessionExtendedErrorInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSyncProviderWithError(this: *mut core::ffi::c_void, ppproviderwitherror: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncSessionExtendedErrorInfo", "GetSyncProviderWithError", 3usize, |this| ((**(this as *const *const ISyncSessionExtendedErrorInfo_Vtbl)).GetSyncProviderWithError)(this, ppproviderwitherror), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSyncProviderWithError }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncSessionExtendedErrorInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncSessionState_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsCanceled(this: *mut core::ffi::c_void, pfiscanceled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncSessionState", "IsCanceled", 3usize, |this| ((**(this as *const *const ISyncSessionState_Vtbl)).IsCanceled)(this, pfiscanceled), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInfoForChangeApplication(this: *mut core::ffi::c_void, pbchangeapplierinfo: *mut u8, pcbchangeapplierinfo: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncSessionState", "GetInfoForChangeApplication", 4usize, |this| ((**(this as *const *const ISyncSessionState_Vtbl)).GetInfoForChangeApplication)(this, pbchangeapplierinfo, pcbchangeapplierinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadInfoFromChangeApplication(this: *mut core::ffi::c_void, pbchangeapplierinfo: *const u8, cbchangeapplierinfo: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncSessionState", "LoadInfoFromChangeApplication", 5usize, |this| ((**(this as *const *const ISyncSessionState_Vtbl)).LoadInfoFromChangeApplication)(this, pbchangeapplierinfo, cbchangeapplierinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForgottenKnowledgeRecoveryRangeStart(this: *mut core::ffi::c_void, pbrangestart: *mut u8, pcbrangestart: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncSessionState", "GetForgottenKnowledgeRecoveryRangeStart", 6usize, |this| ((**(this as *const *const ISyncSessionState_Vtbl)).GetForgottenKnowledgeRecoveryRangeStart)(this, pbrangestart, pcbrangestart), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForgottenKnowledgeRecoveryRangeEnd(this: *mut core::ffi::c_void, pbrangeend: *mut u8, pcbrangeend: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncSessionState", "GetForgottenKnowledgeRecoveryRangeEnd", 7usize, |this| ((**(this as *const *const ISyncSessionState_Vtbl)).GetForgottenKnowledgeRecoveryRangeEnd)(this, pbrangeend, pcbrangeend), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetForgottenKnowledgeRecoveryRange(this: *mut core::ffi::c_void, prange: *const SYNC_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncSessionState", "SetForgottenKnowledgeRecoveryRange", 8usize, |this| ((**(this as *const *const ISyncSessionState_Vtbl)).SetForgottenKnowledgeRecoveryRange)(this, prange), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnProgress(this: *mut core::ffi::c_void, provider: SYNC_PROVIDER_ROLE, syncstage: SYNC_PROGRESS_STAGE, dwcompletedwork: u32, dwtotalwork: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncSessionState", "OnProgress", 9usize, |this| ((**(this as *const *const ISyncSessionState_Vtbl)).OnProgress)(this, provider, syncstage, dwcompletedwork, dwtotalwork), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsCanceled,
+            GetInfoForChangeApplication,
+            LoadInfoFromChangeApplication,
+            GetForgottenKnowledgeRecoveryRangeStart,
+            GetForgottenKnowledgeRecoveryRangeEnd,
+            SetForgottenKnowledgeRecoveryRange,
+            OnProgress,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncSessionState as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISyncSessionState2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetProviderWithError(this: *mut core::ffi::c_void, fself: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncSessionState2", "SetProviderWithError", 10usize, |this| ((**(this as *const *const ISyncSessionState2_Vtbl)).SetProviderWithError)(this, fself), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSessionErrorStatus(this: *mut core::ffi::c_void, phrsessionerror: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISyncSessionState2", "GetSessionErrorStatus", 11usize, |this| ((**(this as *const *const ISyncSessionState2_Vtbl)).GetSessionErrorStatus)(this, phrsessionerror), |result| Some(*result))
+        }
+        Self { base__: <ISyncSessionState_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetProviderWithError, GetSessionErrorStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISyncSessionState2 as windows_core::Interface>::IID || <ISyncSessionState_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISynchronousDataRetriever_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetIdParameters(this: *mut core::ffi::c_void, pidparameters: *mut ID_PARAMETERS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISynchronousDataRetriever", "GetIdParameters", 3usize, |this| ((**(this as *const *const ISynchronousDataRetriever_Vtbl)).GetIdParameters)(this, pidparameters), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadChangeData(this: *mut core::ffi::c_void, ploadchangecontext: *mut core::ffi::c_void, ppunkdata: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISynchronousDataRetriever", "LoadChangeData", 4usize, |this| ((**(this as *const *const ISynchronousDataRetriever_Vtbl)).LoadChangeData)(this, ploadchangecontext, ppunkdata), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetIdParameters, LoadChangeData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISynchronousDataRetriever as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -4978,6 +4978,7 @@ pub struct CIMTYPE_ENUMERATION(pub i32);
 impl windows_core::TypeKind for CIMTYPE_ENUMERATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CIMTYPE_ENUMERATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CIMTYPE_ENUMERATION").field(&self.0).finish()
@@ -4989,6 +4990,7 @@ pub struct MI_CallbackMode(pub i32);
 impl windows_core::TypeKind for MI_CallbackMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MI_CallbackMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MI_CallbackMode").field(&self.0).finish()
@@ -5000,6 +5002,7 @@ pub struct MI_CancellationReason(pub i32);
 impl windows_core::TypeKind for MI_CancellationReason {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MI_CancellationReason {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MI_CancellationReason").field(&self.0).finish()
@@ -5011,6 +5014,7 @@ pub struct MI_DestinationOptions_ImpersonationType(pub i32);
 impl windows_core::TypeKind for MI_DestinationOptions_ImpersonationType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MI_DestinationOptions_ImpersonationType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MI_DestinationOptions_ImpersonationType").field(&self.0).finish()
@@ -5022,6 +5026,7 @@ pub struct MI_ErrorCategory(pub i32);
 impl windows_core::TypeKind for MI_ErrorCategory {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MI_ErrorCategory {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MI_ErrorCategory").field(&self.0).finish()
@@ -5033,6 +5038,7 @@ pub struct MI_LocaleType(pub i32);
 impl windows_core::TypeKind for MI_LocaleType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MI_LocaleType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MI_LocaleType").field(&self.0).finish()
@@ -5044,6 +5050,7 @@ pub struct MI_OperationCallback_ResponseType(pub i32);
 impl windows_core::TypeKind for MI_OperationCallback_ResponseType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MI_OperationCallback_ResponseType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MI_OperationCallback_ResponseType").field(&self.0).finish()
@@ -5055,6 +5062,7 @@ pub struct MI_PromptType(pub i32);
 impl windows_core::TypeKind for MI_PromptType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MI_PromptType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MI_PromptType").field(&self.0).finish()
@@ -5066,6 +5074,7 @@ pub struct MI_ProviderArchitecture(pub i32);
 impl windows_core::TypeKind for MI_ProviderArchitecture {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MI_ProviderArchitecture {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MI_ProviderArchitecture").field(&self.0).finish()
@@ -5077,6 +5086,7 @@ pub struct MI_Result(pub i32);
 impl windows_core::TypeKind for MI_Result {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MI_Result {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MI_Result").field(&self.0).finish()
@@ -5088,6 +5098,7 @@ pub struct MI_SubscriptionDeliveryType(pub i32);
 impl windows_core::TypeKind for MI_SubscriptionDeliveryType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MI_SubscriptionDeliveryType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MI_SubscriptionDeliveryType").field(&self.0).finish()
@@ -5099,6 +5110,7 @@ pub struct MI_Type(pub i32);
 impl windows_core::TypeKind for MI_Type {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MI_Type {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MI_Type").field(&self.0).finish()
@@ -5110,6 +5122,7 @@ pub struct WBEMSTATUS(pub i32);
 impl windows_core::TypeKind for WBEMSTATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEMSTATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEMSTATUS").field(&self.0).finish()
@@ -5121,6 +5134,7 @@ pub struct WBEMSTATUS_FORMAT(pub i32);
 impl windows_core::TypeKind for WBEMSTATUS_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEMSTATUS_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEMSTATUS_FORMAT").field(&self.0).finish()
@@ -5132,6 +5146,7 @@ pub struct WBEM_BACKUP_RESTORE_FLAGS(pub i32);
 impl windows_core::TypeKind for WBEM_BACKUP_RESTORE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_BACKUP_RESTORE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_BACKUP_RESTORE_FLAGS").field(&self.0).finish()
@@ -5143,6 +5158,7 @@ pub struct WBEM_BATCH_TYPE(pub i32);
 impl windows_core::TypeKind for WBEM_BATCH_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_BATCH_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_BATCH_TYPE").field(&self.0).finish()
@@ -5154,6 +5170,7 @@ pub struct WBEM_CHANGE_FLAG_TYPE(pub i32);
 impl windows_core::TypeKind for WBEM_CHANGE_FLAG_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_CHANGE_FLAG_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_CHANGE_FLAG_TYPE").field(&self.0).finish()
@@ -5165,6 +5182,7 @@ pub struct WBEM_COMPARISON_FLAG(pub i32);
 impl windows_core::TypeKind for WBEM_COMPARISON_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_COMPARISON_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_COMPARISON_FLAG").field(&self.0).finish()
@@ -5176,6 +5194,7 @@ pub struct WBEM_COMPILER_OPTIONS(pub i32);
 impl windows_core::TypeKind for WBEM_COMPILER_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_COMPILER_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_COMPILER_OPTIONS").field(&self.0).finish()
@@ -5187,6 +5206,7 @@ pub struct WBEM_CONDITION_FLAG_TYPE(pub i32);
 impl windows_core::TypeKind for WBEM_CONDITION_FLAG_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_CONDITION_FLAG_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_CONDITION_FLAG_TYPE").field(&self.0).finish()
@@ -5198,6 +5218,7 @@ pub struct WBEM_CONNECT_OPTIONS(pub i32);
 impl windows_core::TypeKind for WBEM_CONNECT_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_CONNECT_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_CONNECT_OPTIONS").field(&self.0).finish()
@@ -5209,6 +5230,7 @@ pub struct WBEM_EXTRA_RETURN_CODES(pub i32);
 impl windows_core::TypeKind for WBEM_EXTRA_RETURN_CODES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_EXTRA_RETURN_CODES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_EXTRA_RETURN_CODES").field(&self.0).finish()
@@ -5220,6 +5242,7 @@ pub struct WBEM_FLAVOR_TYPE(pub i32);
 impl windows_core::TypeKind for WBEM_FLAVOR_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_FLAVOR_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_FLAVOR_TYPE").field(&self.0).finish()
@@ -5231,6 +5254,7 @@ pub struct WBEM_GENERIC_FLAG_TYPE(pub i32);
 impl windows_core::TypeKind for WBEM_GENERIC_FLAG_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_GENERIC_FLAG_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_GENERIC_FLAG_TYPE").field(&self.0).finish()
@@ -5275,6 +5299,7 @@ pub struct WBEM_GENUS_TYPE(pub i32);
 impl windows_core::TypeKind for WBEM_GENUS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_GENUS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_GENUS_TYPE").field(&self.0).finish()
@@ -5286,6 +5311,7 @@ pub struct WBEM_GET_KEY_FLAGS(pub i32);
 impl windows_core::TypeKind for WBEM_GET_KEY_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_GET_KEY_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_GET_KEY_FLAGS").field(&self.0).finish()
@@ -5297,6 +5323,7 @@ pub struct WBEM_GET_TEXT_FLAGS(pub i32);
 impl windows_core::TypeKind for WBEM_GET_TEXT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_GET_TEXT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_GET_TEXT_FLAGS").field(&self.0).finish()
@@ -5308,6 +5335,7 @@ pub struct WBEM_INFORMATION_FLAG_TYPE(pub i32);
 impl windows_core::TypeKind for WBEM_INFORMATION_FLAG_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_INFORMATION_FLAG_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_INFORMATION_FLAG_TYPE").field(&self.0).finish()
@@ -5319,6 +5347,7 @@ pub struct WBEM_LIMITATION_FLAG_TYPE(pub i32);
 impl windows_core::TypeKind for WBEM_LIMITATION_FLAG_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_LIMITATION_FLAG_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_LIMITATION_FLAG_TYPE").field(&self.0).finish()
@@ -5330,6 +5359,7 @@ pub struct WBEM_LIMITS(pub i32);
 impl windows_core::TypeKind for WBEM_LIMITS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_LIMITS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_LIMITS").field(&self.0).finish()
@@ -5341,6 +5371,7 @@ pub struct WBEM_LOCKING_FLAG_TYPE(pub i32);
 impl windows_core::TypeKind for WBEM_LOCKING_FLAG_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_LOCKING_FLAG_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_LOCKING_FLAG_TYPE").field(&self.0).finish()
@@ -5352,6 +5383,7 @@ pub struct WBEM_LOGIN_TYPE(pub i32);
 impl windows_core::TypeKind for WBEM_LOGIN_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_LOGIN_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_LOGIN_TYPE").field(&self.0).finish()
@@ -5363,6 +5395,7 @@ pub struct WBEM_PATH_CREATE_FLAG(pub i32);
 impl windows_core::TypeKind for WBEM_PATH_CREATE_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_PATH_CREATE_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_PATH_CREATE_FLAG").field(&self.0).finish()
@@ -5374,6 +5407,7 @@ pub struct WBEM_PATH_STATUS_FLAG(pub i32);
 impl windows_core::TypeKind for WBEM_PATH_STATUS_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_PATH_STATUS_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_PATH_STATUS_FLAG").field(&self.0).finish()
@@ -5385,6 +5419,7 @@ pub struct WBEM_PROVIDER_FLAGS(pub i32);
 impl windows_core::TypeKind for WBEM_PROVIDER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_PROVIDER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_PROVIDER_FLAGS").field(&self.0).finish()
@@ -5396,6 +5431,7 @@ pub struct WBEM_PROVIDER_REQUIREMENTS_TYPE(pub i32);
 impl windows_core::TypeKind for WBEM_PROVIDER_REQUIREMENTS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_PROVIDER_REQUIREMENTS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_PROVIDER_REQUIREMENTS_TYPE").field(&self.0).finish()
@@ -5407,6 +5443,7 @@ pub struct WBEM_QUERY_FLAG_TYPE(pub i32);
 impl windows_core::TypeKind for WBEM_QUERY_FLAG_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_QUERY_FLAG_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_QUERY_FLAG_TYPE").field(&self.0).finish()
@@ -5418,6 +5455,7 @@ pub struct WBEM_REFRESHER_FLAGS(pub i32);
 impl windows_core::TypeKind for WBEM_REFRESHER_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_REFRESHER_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_REFRESHER_FLAGS").field(&self.0).finish()
@@ -5429,6 +5467,7 @@ pub struct WBEM_SECURITY_FLAGS(pub i32);
 impl windows_core::TypeKind for WBEM_SECURITY_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_SECURITY_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_SECURITY_FLAGS").field(&self.0).finish()
@@ -5440,6 +5479,7 @@ pub struct WBEM_SHUTDOWN_FLAGS(pub i32);
 impl windows_core::TypeKind for WBEM_SHUTDOWN_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_SHUTDOWN_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_SHUTDOWN_FLAGS").field(&self.0).finish()
@@ -5451,6 +5491,7 @@ pub struct WBEM_STATUS_TYPE(pub i32);
 impl windows_core::TypeKind for WBEM_STATUS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_STATUS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_STATUS_TYPE").field(&self.0).finish()
@@ -5462,6 +5503,7 @@ pub struct WBEM_TEXT_FLAG_TYPE(pub i32);
 impl windows_core::TypeKind for WBEM_TEXT_FLAG_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_TEXT_FLAG_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_TEXT_FLAG_TYPE").field(&self.0).finish()
@@ -5473,6 +5515,7 @@ pub struct WBEM_UNSECAPP_FLAG_TYPE(pub i32);
 impl windows_core::TypeKind for WBEM_UNSECAPP_FLAG_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WBEM_UNSECAPP_FLAG_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WBEM_UNSECAPP_FLAG_TYPE").field(&self.0).finish()
@@ -5484,6 +5527,7 @@ pub struct WMIQ_ANALYSIS_TYPE(pub i32);
 impl windows_core::TypeKind for WMIQ_ANALYSIS_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMIQ_ANALYSIS_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMIQ_ANALYSIS_TYPE").field(&self.0).finish()
@@ -5495,6 +5539,7 @@ pub struct WMIQ_ASSOCQ_FLAGS(pub i32);
 impl windows_core::TypeKind for WMIQ_ASSOCQ_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMIQ_ASSOCQ_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMIQ_ASSOCQ_FLAGS").field(&self.0).finish()
@@ -5506,6 +5551,7 @@ pub struct WMIQ_LANGUAGE_FEATURES(pub i32);
 impl windows_core::TypeKind for WMIQ_LANGUAGE_FEATURES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMIQ_LANGUAGE_FEATURES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMIQ_LANGUAGE_FEATURES").field(&self.0).finish()
@@ -5517,6 +5563,7 @@ pub struct WMIQ_RPNF_FEATURE(pub i32);
 impl windows_core::TypeKind for WMIQ_RPNF_FEATURE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMIQ_RPNF_FEATURE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMIQ_RPNF_FEATURE").field(&self.0).finish()
@@ -5528,6 +5575,7 @@ pub struct WMIQ_RPN_TOKEN_FLAGS(pub i32);
 impl windows_core::TypeKind for WMIQ_RPN_TOKEN_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMIQ_RPN_TOKEN_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMIQ_RPN_TOKEN_FLAGS").field(&self.0).finish()
@@ -5539,6 +5587,7 @@ pub struct WMI_OBJ_TEXT(pub i32);
 impl windows_core::TypeKind for WMI_OBJ_TEXT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WMI_OBJ_TEXT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WMI_OBJ_TEXT").field(&self.0).finish()
@@ -5550,6 +5599,7 @@ pub struct WbemAuthenticationLevelEnum(pub i32);
 impl windows_core::TypeKind for WbemAuthenticationLevelEnum {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WbemAuthenticationLevelEnum {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WbemAuthenticationLevelEnum").field(&self.0).finish()
@@ -5561,6 +5611,7 @@ pub struct WbemChangeFlagEnum(pub i32);
 impl windows_core::TypeKind for WbemChangeFlagEnum {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WbemChangeFlagEnum {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WbemChangeFlagEnum").field(&self.0).finish()
@@ -5572,6 +5623,7 @@ pub struct WbemCimtypeEnum(pub i32);
 impl windows_core::TypeKind for WbemCimtypeEnum {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WbemCimtypeEnum {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WbemCimtypeEnum").field(&self.0).finish()
@@ -5583,6 +5635,7 @@ pub struct WbemComparisonFlagEnum(pub i32);
 impl windows_core::TypeKind for WbemComparisonFlagEnum {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WbemComparisonFlagEnum {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WbemComparisonFlagEnum").field(&self.0).finish()
@@ -5594,6 +5647,7 @@ pub struct WbemConnectOptionsEnum(pub i32);
 impl windows_core::TypeKind for WbemConnectOptionsEnum {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WbemConnectOptionsEnum {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WbemConnectOptionsEnum").field(&self.0).finish()
@@ -5605,6 +5659,7 @@ pub struct WbemErrorEnum(pub i32);
 impl windows_core::TypeKind for WbemErrorEnum {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WbemErrorEnum {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WbemErrorEnum").field(&self.0).finish()
@@ -5616,6 +5671,7 @@ pub struct WbemFlagEnum(pub i32);
 impl windows_core::TypeKind for WbemFlagEnum {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WbemFlagEnum {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WbemFlagEnum").field(&self.0).finish()
@@ -5627,6 +5683,7 @@ pub struct WbemImpersonationLevelEnum(pub i32);
 impl windows_core::TypeKind for WbemImpersonationLevelEnum {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WbemImpersonationLevelEnum {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WbemImpersonationLevelEnum").field(&self.0).finish()
@@ -5638,6 +5695,7 @@ pub struct WbemObjectTextFormatEnum(pub i32);
 impl windows_core::TypeKind for WbemObjectTextFormatEnum {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WbemObjectTextFormatEnum {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WbemObjectTextFormatEnum").field(&self.0).finish()
@@ -5649,6 +5707,7 @@ pub struct WbemPrivilegeEnum(pub i32);
 impl windows_core::TypeKind for WbemPrivilegeEnum {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WbemPrivilegeEnum {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WbemPrivilegeEnum").field(&self.0).finish()
@@ -5660,6 +5719,7 @@ pub struct WbemQueryFlagEnum(pub i32);
 impl windows_core::TypeKind for WbemQueryFlagEnum {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WbemQueryFlagEnum {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WbemQueryFlagEnum").field(&self.0).finish()
@@ -5671,6 +5731,7 @@ pub struct WbemTextFlagEnum(pub i32);
 impl windows_core::TypeKind for WbemTextFlagEnum {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WbemTextFlagEnum {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WbemTextFlagEnum").field(&self.0).finish()
@@ -5682,13 +5743,15 @@ pub struct WbemTimeout(pub i32);
 impl windows_core::TypeKind for WbemTimeout {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WbemTimeout {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WbemTimeout").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Application {
     pub reserved1: u64,
     pub reserved2: isize,
@@ -5703,7 +5766,8 @@ impl Default for MI_Application {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ApplicationFT {
     pub Close: isize,
     pub NewSession: isize,
@@ -5726,7 +5790,8 @@ impl Default for MI_ApplicationFT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Array {
     pub data: *mut core::ffi::c_void,
     pub size: u32,
@@ -5740,7 +5805,8 @@ impl Default for MI_Array {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ArrayField {
     pub value: MI_Array,
     pub exists: u8,
@@ -5755,7 +5821,8 @@ impl Default for MI_ArrayField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_BooleanA {
     pub data: *mut u8,
     pub size: u32,
@@ -5769,7 +5836,8 @@ impl Default for MI_BooleanA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_BooleanAField {
     pub value: MI_BooleanA,
     pub exists: u8,
@@ -5784,7 +5852,8 @@ impl Default for MI_BooleanAField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_BooleanField {
     pub value: u8,
     pub exists: u8,
@@ -5799,7 +5868,8 @@ impl Default for MI_BooleanField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Char16A {
     pub data: *mut u16,
     pub size: u32,
@@ -5813,7 +5883,8 @@ impl Default for MI_Char16A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Char16AField {
     pub value: MI_Char16A,
     pub exists: u8,
@@ -5828,7 +5899,8 @@ impl Default for MI_Char16AField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Char16Field {
     pub value: u16,
     pub exists: u8,
@@ -5843,7 +5915,8 @@ impl Default for MI_Char16Field {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Class {
     pub ft: *const MI_ClassFT,
     pub classDecl: *const MI_ClassDecl,
@@ -5860,7 +5933,8 @@ impl Default for MI_Class {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ClassDecl {
     pub flags: u32,
     pub code: u32,
@@ -5887,7 +5961,8 @@ impl Default for MI_ClassDecl {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ClassFT {
     pub GetClassNameA: isize,
     pub GetNameSpace: isize,
@@ -5913,7 +5988,8 @@ impl Default for MI_ClassFT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ClientFT_V1 {
     pub applicationFT: *const MI_ApplicationFT,
     pub sessionFT: *const MI_SessionFT,
@@ -5935,7 +6011,8 @@ impl Default for MI_ClientFT_V1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstBooleanA {
     pub data: *const u8,
     pub size: u32,
@@ -5949,7 +6026,8 @@ impl Default for MI_ConstBooleanA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstBooleanAField {
     pub value: MI_ConstBooleanA,
     pub exists: u8,
@@ -5964,7 +6042,8 @@ impl Default for MI_ConstBooleanAField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstBooleanField {
     pub value: u8,
     pub exists: u8,
@@ -5979,7 +6058,8 @@ impl Default for MI_ConstBooleanField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstChar16A {
     pub data: *const u16,
     pub size: u32,
@@ -5993,7 +6073,8 @@ impl Default for MI_ConstChar16A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstChar16AField {
     pub value: MI_ConstChar16A,
     pub exists: u8,
@@ -6008,7 +6089,8 @@ impl Default for MI_ConstChar16AField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstChar16Field {
     pub value: u16,
     pub exists: u8,
@@ -6023,7 +6105,8 @@ impl Default for MI_ConstChar16Field {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstDatetimeA {
     pub data: *const MI_Datetime,
     pub size: u32,
@@ -6037,7 +6120,8 @@ impl Default for MI_ConstDatetimeA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstDatetimeAField {
     pub value: MI_ConstDatetimeA,
     pub exists: u8,
@@ -6067,7 +6151,8 @@ impl Default for MI_ConstDatetimeField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstInstanceA {
     pub data: *const *const MI_Instance,
     pub size: u32,
@@ -6081,7 +6166,8 @@ impl Default for MI_ConstInstanceA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstInstanceAField {
     pub value: MI_ConstInstanceA,
     pub exists: u8,
@@ -6096,7 +6182,8 @@ impl Default for MI_ConstInstanceAField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstInstanceField {
     pub value: *const MI_Instance,
     pub exists: u8,
@@ -6111,7 +6198,8 @@ impl Default for MI_ConstInstanceField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstReal32A {
     pub data: *const f32,
     pub size: u32,
@@ -6125,7 +6213,8 @@ impl Default for MI_ConstReal32A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstReal32AField {
     pub value: MI_ConstReal32A,
     pub exists: u8,
@@ -6140,7 +6229,8 @@ impl Default for MI_ConstReal32AField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstReal32Field {
     pub value: f32,
     pub exists: u8,
@@ -6155,7 +6245,8 @@ impl Default for MI_ConstReal32Field {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstReal64A {
     pub data: *const f64,
     pub size: u32,
@@ -6169,7 +6260,8 @@ impl Default for MI_ConstReal64A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstReal64AField {
     pub value: MI_ConstReal64A,
     pub exists: u8,
@@ -6184,7 +6276,8 @@ impl Default for MI_ConstReal64AField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstReal64Field {
     pub value: f64,
     pub exists: u8,
@@ -6199,7 +6292,8 @@ impl Default for MI_ConstReal64Field {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstReferenceA {
     pub data: *const *const MI_Instance,
     pub size: u32,
@@ -6213,7 +6307,8 @@ impl Default for MI_ConstReferenceA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstReferenceAField {
     pub value: MI_ConstReferenceA,
     pub exists: u8,
@@ -6228,7 +6323,8 @@ impl Default for MI_ConstReferenceAField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstReferenceField {
     pub value: *const MI_Instance,
     pub exists: u8,
@@ -6243,7 +6339,8 @@ impl Default for MI_ConstReferenceField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstSint16A {
     pub data: *const i16,
     pub size: u32,
@@ -6257,7 +6354,8 @@ impl Default for MI_ConstSint16A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstSint16AField {
     pub value: MI_ConstSint16A,
     pub exists: u8,
@@ -6272,7 +6370,8 @@ impl Default for MI_ConstSint16AField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstSint16Field {
     pub value: i16,
     pub exists: u8,
@@ -6287,7 +6386,8 @@ impl Default for MI_ConstSint16Field {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstSint32A {
     pub data: *const i32,
     pub size: u32,
@@ -6301,7 +6401,8 @@ impl Default for MI_ConstSint32A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstSint32AField {
     pub value: MI_ConstSint32A,
     pub exists: u8,
@@ -6316,7 +6417,8 @@ impl Default for MI_ConstSint32AField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstSint32Field {
     pub value: i32,
     pub exists: u8,
@@ -6331,7 +6433,8 @@ impl Default for MI_ConstSint32Field {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstSint64A {
     pub data: *const i64,
     pub size: u32,
@@ -6345,7 +6448,8 @@ impl Default for MI_ConstSint64A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstSint64AField {
     pub value: MI_ConstSint64A,
     pub exists: u8,
@@ -6360,7 +6464,8 @@ impl Default for MI_ConstSint64AField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstSint64Field {
     pub value: i64,
     pub exists: u8,
@@ -6375,7 +6480,8 @@ impl Default for MI_ConstSint64Field {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstSint8A {
     pub data: *const i8,
     pub size: u32,
@@ -6389,7 +6495,8 @@ impl Default for MI_ConstSint8A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstSint8AField {
     pub value: MI_ConstSint8A,
     pub exists: u8,
@@ -6404,7 +6511,8 @@ impl Default for MI_ConstSint8AField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstSint8Field {
     pub value: i8,
     pub exists: u8,
@@ -6419,7 +6527,8 @@ impl Default for MI_ConstSint8Field {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstStringA {
     pub data: *const *const u16,
     pub size: u32,
@@ -6433,7 +6542,8 @@ impl Default for MI_ConstStringA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstStringAField {
     pub value: MI_ConstStringA,
     pub exists: u8,
@@ -6448,7 +6558,8 @@ impl Default for MI_ConstStringAField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstStringField {
     pub value: *const u16,
     pub exists: u8,
@@ -6463,7 +6574,8 @@ impl Default for MI_ConstStringField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstUint16A {
     pub data: *const u16,
     pub size: u32,
@@ -6477,7 +6589,8 @@ impl Default for MI_ConstUint16A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstUint16AField {
     pub value: MI_ConstUint16A,
     pub exists: u8,
@@ -6492,7 +6605,8 @@ impl Default for MI_ConstUint16AField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstUint16Field {
     pub value: u16,
     pub exists: u8,
@@ -6507,7 +6621,8 @@ impl Default for MI_ConstUint16Field {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstUint32A {
     pub data: *const u32,
     pub size: u32,
@@ -6521,7 +6636,8 @@ impl Default for MI_ConstUint32A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstUint32AField {
     pub value: MI_ConstUint32A,
     pub exists: u8,
@@ -6536,7 +6652,8 @@ impl Default for MI_ConstUint32AField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstUint32Field {
     pub value: u32,
     pub exists: u8,
@@ -6551,7 +6668,8 @@ impl Default for MI_ConstUint32Field {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstUint64A {
     pub data: *const u64,
     pub size: u32,
@@ -6565,7 +6683,8 @@ impl Default for MI_ConstUint64A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstUint64AField {
     pub value: MI_ConstUint64A,
     pub exists: u8,
@@ -6580,7 +6699,8 @@ impl Default for MI_ConstUint64AField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstUint64Field {
     pub value: u64,
     pub exists: u8,
@@ -6595,7 +6715,8 @@ impl Default for MI_ConstUint64Field {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstUint8A {
     pub data: *const u8,
     pub size: u32,
@@ -6609,7 +6730,8 @@ impl Default for MI_ConstUint8A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstUint8AField {
     pub value: MI_ConstUint8A,
     pub exists: u8,
@@ -6624,7 +6746,8 @@ impl Default for MI_ConstUint8AField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ConstUint8Field {
     pub value: u8,
     pub exists: u8,
@@ -6639,7 +6762,8 @@ impl Default for MI_ConstUint8Field {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Context {
     pub ft: *const MI_ContextFT,
     pub reserved: [isize; 3],
@@ -6653,7 +6777,8 @@ impl Default for MI_Context {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ContextFT {
     pub PostResult: isize,
     pub PostInstance: isize,
@@ -6723,7 +6848,8 @@ impl Default for MI_Datetime_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_DatetimeA {
     pub data: *mut MI_Datetime,
     pub size: u32,
@@ -6737,7 +6863,8 @@ impl Default for MI_DatetimeA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_DatetimeAField {
     pub value: MI_DatetimeA,
     pub exists: u8,
@@ -6767,7 +6894,8 @@ impl Default for MI_DatetimeField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Deserializer {
     pub reserved1: u64,
     pub reserved2: isize,
@@ -6781,7 +6909,8 @@ impl Default for MI_Deserializer {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_DeserializerFT {
     pub Close: isize,
     pub DeserializeClass: isize,
@@ -6799,7 +6928,8 @@ impl Default for MI_DeserializerFT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_DestinationOptions {
     pub reserved1: u64,
     pub reserved2: isize,
@@ -6814,7 +6944,8 @@ impl Default for MI_DestinationOptions {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_DestinationOptionsFT {
     pub Delete: isize,
     pub SetString: isize,
@@ -6841,7 +6972,8 @@ impl Default for MI_DestinationOptionsFT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_FeatureDecl {
     pub flags: u32,
     pub code: u32,
@@ -6858,7 +6990,8 @@ impl Default for MI_FeatureDecl {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Filter {
     pub ft: *const MI_FilterFT,
     pub reserved: [isize; 3],
@@ -6872,7 +7005,8 @@ impl Default for MI_Filter {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_FilterFT {
     pub Evaluate: isize,
     pub GetExpression: isize,
@@ -6886,7 +7020,8 @@ impl Default for MI_FilterFT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_HostedProvider {
     pub reserved1: u64,
     pub reserved2: isize,
@@ -6901,7 +7036,8 @@ impl Default for MI_HostedProvider {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_HostedProviderFT {
     pub Close: isize,
     pub GetApplication: isize,
@@ -6915,7 +7051,8 @@ impl Default for MI_HostedProviderFT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Instance {
     pub ft: *const MI_InstanceFT,
     pub classDecl: *const MI_ClassDecl,
@@ -6932,7 +7069,8 @@ impl Default for MI_Instance {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_InstanceA {
     pub data: *mut *mut MI_Instance,
     pub size: u32,
@@ -6946,7 +7084,8 @@ impl Default for MI_InstanceA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_InstanceAField {
     pub value: MI_InstanceA,
     pub exists: u8,
@@ -6961,7 +7100,8 @@ impl Default for MI_InstanceAField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_InstanceExFT {
     pub parent: MI_InstanceFT,
     pub Normalize: isize,
@@ -6975,7 +7115,8 @@ impl Default for MI_InstanceExFT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_InstanceFT {
     pub Clone: isize,
     pub Destruct: isize,
@@ -7005,7 +7146,8 @@ impl Default for MI_InstanceFT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_InstanceField {
     pub value: *mut MI_Instance,
     pub exists: u8,
@@ -7020,7 +7162,8 @@ impl Default for MI_InstanceField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Interval {
     pub days: u32,
     pub hours: u32,
@@ -7040,7 +7183,8 @@ impl Default for MI_Interval {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_MethodDecl {
     pub flags: u32,
     pub code: u32,
@@ -7065,7 +7209,8 @@ impl Default for MI_MethodDecl {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Module {
     pub version: u32,
     pub generatorVersion: u32,
@@ -7085,7 +7230,8 @@ impl Default for MI_Module {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Module_Self(pub isize);
 impl Default for MI_Module_Self {
     fn default() -> Self {
@@ -7096,7 +7242,8 @@ impl windows_core::TypeKind for MI_Module_Self {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ObjectDecl {
     pub flags: u32,
     pub code: u32,
@@ -7116,7 +7263,8 @@ impl Default for MI_ObjectDecl {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Operation {
     pub reserved1: u64,
     pub reserved2: isize,
@@ -7131,7 +7279,8 @@ impl Default for MI_Operation {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_OperationCallbacks {
     pub callbackContext: *mut core::ffi::c_void,
     pub promptUser: MI_OperationCallback_PromptUser,
@@ -7152,7 +7301,8 @@ impl Default for MI_OperationCallbacks {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_OperationFT {
     pub Close: isize,
     pub Cancel: isize,
@@ -7170,7 +7320,8 @@ impl Default for MI_OperationFT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_OperationOptions {
     pub reserved1: u64,
     pub reserved2: isize,
@@ -7185,7 +7336,8 @@ impl Default for MI_OperationOptions {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_OperationOptionsFT {
     pub Delete: isize,
     pub SetString: isize,
@@ -7210,7 +7362,8 @@ impl Default for MI_OperationOptionsFT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ParameterDecl {
     pub flags: u32,
     pub code: u32,
@@ -7231,7 +7384,8 @@ impl Default for MI_ParameterDecl {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ParameterSet {
     pub reserved1: u64,
     pub reserved2: isize,
@@ -7246,7 +7400,8 @@ impl Default for MI_ParameterSet {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ParameterSetFT {
     pub GetMethodReturnType: isize,
     pub GetParameterCount: isize,
@@ -7262,7 +7417,8 @@ impl Default for MI_ParameterSetFT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_PropertyDecl {
     pub flags: u32,
     pub code: u32,
@@ -7286,7 +7442,8 @@ impl Default for MI_PropertyDecl {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_PropertySet {
     pub ft: *const MI_PropertySetFT,
     pub reserved: [isize; 3],
@@ -7300,7 +7457,8 @@ impl Default for MI_PropertySet {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_PropertySetFT {
     pub GetElementCount: isize,
     pub ContainsElement: isize,
@@ -7320,7 +7478,8 @@ impl Default for MI_PropertySetFT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ProviderFT {
     pub Load: MI_ProviderFT_Load,
     pub Unload: MI_ProviderFT_Unload,
@@ -7346,7 +7505,8 @@ impl Default for MI_ProviderFT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Qualifier {
     pub name: *const u16,
     pub r#type: u32,
@@ -7362,7 +7522,8 @@ impl Default for MI_Qualifier {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_QualifierDecl {
     pub name: *const u16,
     pub r#type: u32,
@@ -7380,7 +7541,8 @@ impl Default for MI_QualifierDecl {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_QualifierSet {
     pub reserved1: u64,
     pub reserved2: isize,
@@ -7395,7 +7557,8 @@ impl Default for MI_QualifierSet {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_QualifierSetFT {
     pub GetQualifierCount: isize,
     pub GetQualifierAt: isize,
@@ -7410,7 +7573,8 @@ impl Default for MI_QualifierSetFT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Real32A {
     pub data: *mut f32,
     pub size: u32,
@@ -7424,7 +7588,8 @@ impl Default for MI_Real32A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Real32AField {
     pub value: MI_Real32A,
     pub exists: u8,
@@ -7439,7 +7604,8 @@ impl Default for MI_Real32AField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Real32Field {
     pub value: f32,
     pub exists: u8,
@@ -7454,7 +7620,8 @@ impl Default for MI_Real32Field {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Real64A {
     pub data: *mut f64,
     pub size: u32,
@@ -7468,7 +7635,8 @@ impl Default for MI_Real64A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Real64AField {
     pub value: MI_Real64A,
     pub exists: u8,
@@ -7483,7 +7651,8 @@ impl Default for MI_Real64AField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Real64Field {
     pub value: f64,
     pub exists: u8,
@@ -7498,7 +7667,8 @@ impl Default for MI_Real64Field {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ReferenceA {
     pub data: *mut *mut MI_Instance,
     pub size: u32,
@@ -7512,7 +7682,8 @@ impl Default for MI_ReferenceA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ReferenceAField {
     pub value: MI_ReferenceA,
     pub exists: u8,
@@ -7527,7 +7698,8 @@ impl Default for MI_ReferenceAField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ReferenceField {
     pub value: *mut MI_Instance,
     pub exists: u8,
@@ -7542,7 +7714,8 @@ impl Default for MI_ReferenceField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_SchemaDecl {
     pub qualifierDecls: *const *const MI_QualifierDecl,
     pub numQualifierDecls: u32,
@@ -7558,7 +7731,8 @@ impl Default for MI_SchemaDecl {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Serializer {
     pub reserved1: u64,
     pub reserved2: isize,
@@ -7572,7 +7746,8 @@ impl Default for MI_Serializer {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_SerializerFT {
     pub Close: isize,
     pub SerializeClass: isize,
@@ -7587,7 +7762,8 @@ impl Default for MI_SerializerFT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Server {
     pub serverFT: *const MI_ServerFT,
     pub contextFT: *const MI_ContextFT,
@@ -7604,7 +7780,8 @@ impl Default for MI_Server {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_ServerFT {
     pub GetVersion: isize,
     pub GetSystemName: isize,
@@ -7618,7 +7795,8 @@ impl Default for MI_ServerFT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Session {
     pub reserved1: u64,
     pub reserved2: isize,
@@ -7633,7 +7811,8 @@ impl Default for MI_Session {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_SessionCallbacks {
     pub callbackContext: *mut core::ffi::c_void,
     pub writeMessage: isize,
@@ -7648,7 +7827,8 @@ impl Default for MI_SessionCallbacks {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_SessionFT {
     pub Close: isize,
     pub GetApplication: isize,
@@ -7675,7 +7855,8 @@ impl Default for MI_SessionFT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Sint16A {
     pub data: *mut i16,
     pub size: u32,
@@ -7689,7 +7870,8 @@ impl Default for MI_Sint16A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Sint16AField {
     pub value: MI_Sint16A,
     pub exists: u8,
@@ -7704,7 +7886,8 @@ impl Default for MI_Sint16AField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Sint16Field {
     pub value: i16,
     pub exists: u8,
@@ -7719,7 +7902,8 @@ impl Default for MI_Sint16Field {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Sint32A {
     pub data: *mut i32,
     pub size: u32,
@@ -7733,7 +7917,8 @@ impl Default for MI_Sint32A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Sint32AField {
     pub value: MI_Sint32A,
     pub exists: u8,
@@ -7748,7 +7933,8 @@ impl Default for MI_Sint32AField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Sint32Field {
     pub value: i32,
     pub exists: u8,
@@ -7763,7 +7949,8 @@ impl Default for MI_Sint32Field {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Sint64A {
     pub data: *mut i64,
     pub size: u32,
@@ -7777,7 +7964,8 @@ impl Default for MI_Sint64A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Sint64AField {
     pub value: MI_Sint64A,
     pub exists: u8,
@@ -7792,7 +7980,8 @@ impl Default for MI_Sint64AField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Sint64Field {
     pub value: i64,
     pub exists: u8,
@@ -7807,7 +7996,8 @@ impl Default for MI_Sint64Field {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Sint8A {
     pub data: *mut i8,
     pub size: u32,
@@ -7821,7 +8011,8 @@ impl Default for MI_Sint8A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Sint8AField {
     pub value: MI_Sint8A,
     pub exists: u8,
@@ -7836,7 +8027,8 @@ impl Default for MI_Sint8AField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Sint8Field {
     pub value: i8,
     pub exists: u8,
@@ -7851,7 +8043,8 @@ impl Default for MI_Sint8Field {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_StringA {
     pub data: *mut *mut u16,
     pub size: u32,
@@ -7865,7 +8058,8 @@ impl Default for MI_StringA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_StringAField {
     pub value: MI_StringA,
     pub exists: u8,
@@ -7880,7 +8074,8 @@ impl Default for MI_StringAField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_StringField {
     pub value: *mut u16,
     pub exists: u8,
@@ -7895,7 +8090,8 @@ impl Default for MI_StringField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_SubscriptionDeliveryOptions {
     pub reserved1: u64,
     pub reserved2: isize,
@@ -7910,7 +8106,8 @@ impl Default for MI_SubscriptionDeliveryOptions {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_SubscriptionDeliveryOptionsFT {
     pub SetString: isize,
     pub SetNumber: isize,
@@ -7939,7 +8136,8 @@ impl Default for MI_SubscriptionDeliveryOptionsFT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Timestamp {
     pub year: u32,
     pub month: u32,
@@ -7959,7 +8157,8 @@ impl Default for MI_Timestamp {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Uint16A {
     pub data: *mut u16,
     pub size: u32,
@@ -7973,7 +8172,8 @@ impl Default for MI_Uint16A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Uint16AField {
     pub value: MI_Uint16A,
     pub exists: u8,
@@ -7988,7 +8188,8 @@ impl Default for MI_Uint16AField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Uint16Field {
     pub value: u16,
     pub exists: u8,
@@ -8003,7 +8204,8 @@ impl Default for MI_Uint16Field {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Uint32A {
     pub data: *mut u32,
     pub size: u32,
@@ -8017,7 +8219,8 @@ impl Default for MI_Uint32A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Uint32AField {
     pub value: MI_Uint32A,
     pub exists: u8,
@@ -8032,7 +8235,8 @@ impl Default for MI_Uint32AField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Uint32Field {
     pub value: u32,
     pub exists: u8,
@@ -8047,7 +8251,8 @@ impl Default for MI_Uint32Field {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Uint64A {
     pub data: *mut u64,
     pub size: u32,
@@ -8061,7 +8266,8 @@ impl Default for MI_Uint64A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Uint64AField {
     pub value: MI_Uint64A,
     pub exists: u8,
@@ -8076,7 +8282,8 @@ impl Default for MI_Uint64AField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Uint64Field {
     pub value: u64,
     pub exists: u8,
@@ -8091,7 +8298,8 @@ impl Default for MI_Uint64Field {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Uint8A {
     pub data: *mut u8,
     pub size: u32,
@@ -8105,7 +8313,8 @@ impl Default for MI_Uint8A {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Uint8AField {
     pub value: MI_Uint8A,
     pub exists: u8,
@@ -8120,7 +8329,8 @@ impl Default for MI_Uint8AField {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_Uint8Field {
     pub value: u8,
     pub exists: u8,
@@ -8163,7 +8373,8 @@ impl Default for MI_UserCredentials_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_UsernamePasswordCreds {
     pub domain: *const u16,
     pub username: *const u16,
@@ -8178,7 +8389,8 @@ impl Default for MI_UsernamePasswordCreds {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MI_UtilitiesFT {
     pub MapErrorToMiErrorCategory: isize,
     pub CimErrorFromErrorCode: isize,
@@ -8238,7 +8450,8 @@ impl Default for MI_Value {
 }
 pub const MofCompiler: windows_core::GUID = windows_core::GUID::from_u128(0x6daf9757_2e37_11d2_aec9_00c04fb68820);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SWbemAnalysisMatrix {
     pub m_uVersion: u32,
     pub m_uMatrixType: u32,
@@ -8257,7 +8470,8 @@ impl Default for SWbemAnalysisMatrix {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SWbemAnalysisMatrixList {
     pub m_uVersion: u32,
     pub m_uMatrixType: u32,
@@ -8273,7 +8487,8 @@ impl Default for SWbemAnalysisMatrixList {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SWbemAssocQueryInf {
     pub m_uVersion: u32,
     pub m_uAnalysisType: u32,
@@ -8320,7 +8535,8 @@ pub const SWbemPropertySet: windows_core::GUID = windows_core::GUID::from_u128(0
 pub const SWbemQualifier: windows_core::GUID = windows_core::GUID::from_u128(0x04b83d5f_21ae_11d2_8b33_00600806d9b6);
 pub const SWbemQualifierSet: windows_core::GUID = windows_core::GUID::from_u128(0x04b83d5e_21ae_11d2_8b33_00600806d9b6);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SWbemQueryQualifiedName {
     pub m_uVersion: u32,
     pub m_uTokenType: u32,
@@ -8360,7 +8576,8 @@ impl Default for SWbemRpnConst {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SWbemRpnEncodedQuery {
     pub m_uVersion: u32,
     pub m_uTokenType: u32,
@@ -8414,7 +8631,8 @@ impl Default for SWbemRpnQueryToken {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SWbemRpnTokenList {
     pub m_uVersion: u32,
     pub m_uTokenType: u32,
@@ -8434,7 +8652,8 @@ pub const SWbemServicesEx: windows_core::GUID = windows_core::GUID::from_u128(0x
 pub const SWbemSink: windows_core::GUID = windows_core::GUID::from_u128(0x75718c9a_f029_11d1_a1ac_00c04fb6c223);
 pub const UnsecuredApartment: windows_core::GUID = windows_core::GUID::from_u128(0x49bd2028_1523_11d1_ad79_00c04fd8fdff);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WBEM_COMPILE_STATUS_INFO {
     pub lPhaseError: i32,
     pub hRes: windows_core::HRESULT,
@@ -8498,5 +8717,8 @@ pub type MI_ProviderFT_ReferenceInstances = Option<unsafe extern "system" fn(sel
 pub type MI_ProviderFT_Subscribe = Option<unsafe extern "system" fn(self_: *const core::ffi::c_void, context: *const MI_Context, namespace: *const u16, classname: *const u16, filter: *const MI_Filter, bookmark: *const u16, subscriptionid: u64, subscriptionself: *mut *mut core::ffi::c_void)>;
 pub type MI_ProviderFT_Unload = Option<unsafe extern "system" fn(self_: *const core::ffi::c_void, context: *const MI_Context)>;
 pub type MI_ProviderFT_Unsubscribe = Option<unsafe extern "system" fn(self_: *const core::ffi::c_void, context: *const MI_Context, namespace: *const u16, classname: *const u16, subscriptionid: u64, subscriptionself: *const core::ffi::c_void)>;
+core::include!(core::concat!(core::env!("CARGO_MANIFEST_DIR"), "/src/includes/", "Win32/System/Wmi/WmiConnection.rs"));
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
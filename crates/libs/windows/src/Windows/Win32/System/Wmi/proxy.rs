@@ -0,0 +1,2606 @@
+impl windows_core::imp::ProxyVtable for IEnumWbemClassObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWbemClassObject", "Reset", 3usize, |this| ((**(this as *const *const IEnumWbemClassObject_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ltimeout: i32, ucount: u32, apobjects: *mut *mut core::ffi::c_void, pureturned: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWbemClassObject", "Next", 4usize, |this| ((**(this as *const *const IEnumWbemClassObject_Vtbl)).Next)(this, ltimeout, ucount, apobjects, pureturned), |result| Some(*result))
+        }
+        unsafe extern "system" fn NextAsync(this: *mut core::ffi::c_void, ucount: u32, psink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWbemClassObject", "NextAsync", 5usize, |this| ((**(this as *const *const IEnumWbemClassObject_Vtbl)).NextAsync)(this, ucount, psink), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWbemClassObject", "Clone", 6usize, |this| ((**(this as *const *const IEnumWbemClassObject_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, ltimeout: i32, ncount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumWbemClassObject", "Skip", 7usize, |this| ((**(this as *const *const IEnumWbemClassObject_Vtbl)).Skip)(this, ltimeout, ncount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Reset, Next, NextAsync, Clone, Skip }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumWbemClassObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMofCompiler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CompileFile(this: *mut core::ffi::c_void, filename: windows_core::PCWSTR, serverandnamespace: windows_core::PCWSTR, user: windows_core::PCWSTR, authority: windows_core::PCWSTR, password: windows_core::PCWSTR, loptionflags: i32, lclassflags: i32, linstanceflags: i32, pinfo: *mut WBEM_COMPILE_STATUS_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMofCompiler", "CompileFile", 3usize, |this| ((**(this as *const *const IMofCompiler_Vtbl)).CompileFile)(this, filename, serverandnamespace, user, authority, password, loptionflags, lclassflags, linstanceflags, pinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompileBuffer(this: *mut core::ffi::c_void, buffsize: i32, pbuffer: *const u8, serverandnamespace: windows_core::PCWSTR, user: windows_core::PCWSTR, authority: windows_core::PCWSTR, password: windows_core::PCWSTR, loptionflags: i32, lclassflags: i32, linstanceflags: i32, pinfo: *mut WBEM_COMPILE_STATUS_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMofCompiler", "CompileBuffer", 4usize, |this| ((**(this as *const *const IMofCompiler_Vtbl)).CompileBuffer)(this, buffsize, pbuffer, serverandnamespace, user, authority, password, loptionflags, lclassflags, linstanceflags, pinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateBMOF(this: *mut core::ffi::c_void, textfilename: windows_core::PCWSTR, bmoffilename: windows_core::PCWSTR, serverandnamespace: windows_core::PCWSTR, loptionflags: i32, lclassflags: i32, linstanceflags: i32, pinfo: *mut WBEM_COMPILE_STATUS_INFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMofCompiler", "CreateBMOF", 5usize, |this| ((**(this as *const *const IMofCompiler_Vtbl)).CreateBMOF)(this, textfilename, bmoffilename, serverandnamespace, loptionflags, lclassflags, linstanceflags, pinfo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CompileFile, CompileBuffer, CreateBMOF }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMofCompiler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISWbemDateTime_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, strvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "Value", 7usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).Value)(this, strvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, strvalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "SetValue", 8usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).SetValue)(this, strvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Year(this: *mut core::ffi::c_void, iyear: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "Year", 9usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).Year)(this, iyear), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetYear(this: *mut core::ffi::c_void, iyear: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "SetYear", 10usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).SetYear)(this, iyear), |result| Some(*result))
+        }
+        unsafe extern "system" fn YearSpecified(this: *mut core::ffi::c_void, byearspecified: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "YearSpecified", 11usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).YearSpecified)(this, byearspecified), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetYearSpecified(this: *mut core::ffi::c_void, byearspecified: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "SetYearSpecified", 12usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).SetYearSpecified)(this, byearspecified), |result| Some(*result))
+        }
+        unsafe extern "system" fn Month(this: *mut core::ffi::c_void, imonth: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "Month", 13usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).Month)(this, imonth), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMonth(this: *mut core::ffi::c_void, imonth: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "SetMonth", 14usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).SetMonth)(this, imonth), |result| Some(*result))
+        }
+        unsafe extern "system" fn MonthSpecified(this: *mut core::ffi::c_void, bmonthspecified: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "MonthSpecified", 15usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).MonthSpecified)(this, bmonthspecified), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMonthSpecified(this: *mut core::ffi::c_void, bmonthspecified: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "SetMonthSpecified", 16usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).SetMonthSpecified)(this, bmonthspecified), |result| Some(*result))
+        }
+        unsafe extern "system" fn Day(this: *mut core::ffi::c_void, iday: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "Day", 17usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).Day)(this, iday), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDay(this: *mut core::ffi::c_void, iday: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "SetDay", 18usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).SetDay)(this, iday), |result| Some(*result))
+        }
+        unsafe extern "system" fn DaySpecified(this: *mut core::ffi::c_void, bdayspecified: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "DaySpecified", 19usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).DaySpecified)(this, bdayspecified), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDaySpecified(this: *mut core::ffi::c_void, bdayspecified: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "SetDaySpecified", 20usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).SetDaySpecified)(this, bdayspecified), |result| Some(*result))
+        }
+        unsafe extern "system" fn Hours(this: *mut core::ffi::c_void, ihours: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "Hours", 21usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).Hours)(this, ihours), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHours(this: *mut core::ffi::c_void, ihours: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "SetHours", 22usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).SetHours)(this, ihours), |result| Some(*result))
+        }
+        unsafe extern "system" fn HoursSpecified(this: *mut core::ffi::c_void, bhoursspecified: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "HoursSpecified", 23usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).HoursSpecified)(this, bhoursspecified), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHoursSpecified(this: *mut core::ffi::c_void, bhoursspecified: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "SetHoursSpecified", 24usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).SetHoursSpecified)(this, bhoursspecified), |result| Some(*result))
+        }
+        unsafe extern "system" fn Minutes(this: *mut core::ffi::c_void, iminutes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "Minutes", 25usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).Minutes)(this, iminutes), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinutes(this: *mut core::ffi::c_void, iminutes: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "SetMinutes", 26usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).SetMinutes)(this, iminutes), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinutesSpecified(this: *mut core::ffi::c_void, bminutesspecified: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "MinutesSpecified", 27usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).MinutesSpecified)(this, bminutesspecified), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinutesSpecified(this: *mut core::ffi::c_void, bminutesspecified: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "SetMinutesSpecified", 28usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).SetMinutesSpecified)(this, bminutesspecified), |result| Some(*result))
+        }
+        unsafe extern "system" fn Seconds(this: *mut core::ffi::c_void, iseconds: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "Seconds", 29usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).Seconds)(this, iseconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSeconds(this: *mut core::ffi::c_void, iseconds: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "SetSeconds", 30usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).SetSeconds)(this, iseconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn SecondsSpecified(this: *mut core::ffi::c_void, bsecondsspecified: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "SecondsSpecified", 31usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).SecondsSpecified)(this, bsecondsspecified), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSecondsSpecified(this: *mut core::ffi::c_void, bsecondsspecified: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "SetSecondsSpecified", 32usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).SetSecondsSpecified)(this, bsecondsspecified), |result| Some(*result))
+        }
+        unsafe extern "system" fn Microseconds(this: *mut core::ffi::c_void, imicroseconds: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "Microseconds", 33usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).Microseconds)(this, imicroseconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMicroseconds(this: *mut core::ffi::c_void, imicroseconds: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "SetMicroseconds", 34usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).SetMicroseconds)(this, imicroseconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn MicrosecondsSpecified(this: *mut core::ffi::c_void, bmicrosecondsspecified: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "MicrosecondsSpecified", 35usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).MicrosecondsSpecified)(this, bmicrosecondsspecified), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMicrosecondsSpecified(this: *mut core::ffi::c_void, bmicrosecondsspecified: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "SetMicrosecondsSpecified", 36usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).SetMicrosecondsSpecified)(this, bmicrosecondsspecified), |result| Some(*result))
+        }
+        unsafe extern "system" fn UTC(this: *mut core::ffi::c_void, iutc: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "UTC", 37usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).UTC)(this, iutc), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUTC(this: *mut core::ffi::c_void, iutc: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "SetUTC", 38usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).SetUTC)(this, iutc), |result| Some(*result))
+        }
+        unsafe extern "system" fn UTCSpecified(this: *mut core::ffi::c_void, butcspecified: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "UTCSpecified", 39usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).UTCSpecified)(this, butcspecified), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUTCSpecified(this: *mut core::ffi::c_void, butcspecified: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "SetUTCSpecified", 40usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).SetUTCSpecified)(this, butcspecified), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsInterval(this: *mut core::ffi::c_void, bisinterval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "IsInterval", 41usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).IsInterval)(this, bisinterval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsInterval(this: *mut core::ffi::c_void, bisinterval: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "SetIsInterval", 42usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).SetIsInterval)(this, bisinterval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVarDate(this: *mut core::ffi::c_void, bislocal: super::super::Foundation::VARIANT_BOOL, dvardate: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "GetVarDate", 43usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).GetVarDate)(this, bislocal, dvardate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVarDate(this: *mut core::ffi::c_void, dvardate: f64, bislocal: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "SetVarDate", 44usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).SetVarDate)(this, dvardate, bislocal), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFileTime(this: *mut core::ffi::c_void, bislocal: super::super::Foundation::VARIANT_BOOL, strfiletime: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "GetFileTime", 45usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).GetFileTime)(this, bislocal, strfiletime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFileTime(this: *mut core::ffi::c_void, strfiletime: core::mem::MaybeUninit<windows_core::BSTR>, bislocal: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemDateTime", "SetFileTime", 46usize, |this| ((**(this as *const *const ISWbemDateTime_Vtbl)).SetFileTime)(this, strfiletime, bislocal), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Value,
+            SetValue,
+            Year,
+            SetYear,
+            YearSpecified,
+            SetYearSpecified,
+            Month,
+            SetMonth,
+            MonthSpecified,
+            SetMonthSpecified,
+            Day,
+            SetDay,
+            DaySpecified,
+            SetDaySpecified,
+            Hours,
+            SetHours,
+            HoursSpecified,
+            SetHoursSpecified,
+            Minutes,
+            SetMinutes,
+            MinutesSpecified,
+            SetMinutesSpecified,
+            Seconds,
+            SetSeconds,
+            SecondsSpecified,
+            SetSecondsSpecified,
+            Microseconds,
+            SetMicroseconds,
+            MicrosecondsSpecified,
+            SetMicrosecondsSpecified,
+            UTC,
+            SetUTC,
+            UTCSpecified,
+            SetUTCSpecified,
+            IsInterval,
+            SetIsInterval,
+            GetVarDate,
+            SetVarDate,
+            GetFileTime,
+            SetFileTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISWbemDateTime as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISWbemEventSource_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn NextEvent(this: *mut core::ffi::c_void, itimeoutms: i32, objwbemobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemEventSource", "NextEvent", 7usize, |this| ((**(this as *const *const ISWbemEventSource_Vtbl)).NextEvent)(this, itimeoutms, objwbemobject), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Security_(this: *mut core::ffi::c_void, objwbemsecurity: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemEventSource", "Security_", 8usize, |this| ((**(this as *const *const ISWbemEventSource_Vtbl)).Security_)(this, objwbemsecurity), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            NextEvent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            NextEvent: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Security_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Security_: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISWbemEventSource as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISWbemLastError_Vtbl {
+    const PROXY: Self = { Self { base__: <ISWbemObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISWbemLastError as windows_core::Interface>::IID || <ISWbemObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISWbemLocator_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ConnectServer(this: *mut core::ffi::c_void, strserver: core::mem::MaybeUninit<windows_core::BSTR>, strnamespace: core::mem::MaybeUninit<windows_core::BSTR>, struser: core::mem::MaybeUninit<windows_core::BSTR>, strpassword: core::mem::MaybeUninit<windows_core::BSTR>, strlocale: core::mem::MaybeUninit<windows_core::BSTR>, strauthority: core::mem::MaybeUninit<windows_core::BSTR>, isecurityflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemservices: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemLocator", "ConnectServer", 7usize, |this| ((**(this as *const *const ISWbemLocator_Vtbl)).ConnectServer)(this, strserver, strnamespace, struser, strpassword, strlocale, strauthority, isecurityflags, objwbemnamedvalueset, objwbemservices), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Security_(this: *mut core::ffi::c_void, objwbemsecurity: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemLocator", "Security_", 8usize, |this| ((**(this as *const *const ISWbemLocator_Vtbl)).Security_)(this, objwbemsecurity), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            ConnectServer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ConnectServer: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Security_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Security_: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISWbemLocator as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISWbemMethod_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, strname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemMethod", "Name", 7usize, |this| ((**(this as *const *const ISWbemMethod_Vtbl)).Name)(this, strname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Origin(this: *mut core::ffi::c_void, strorigin: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemMethod", "Origin", 8usize, |this| ((**(this as *const *const ISWbemMethod_Vtbl)).Origin)(this, strorigin), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InParameters(this: *mut core::ffi::c_void, objwbeminparameters: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemMethod", "InParameters", 9usize, |this| ((**(this as *const *const ISWbemMethod_Vtbl)).InParameters)(this, objwbeminparameters), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn OutParameters(this: *mut core::ffi::c_void, objwbemoutparameters: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemMethod", "OutParameters", 10usize, |this| ((**(this as *const *const ISWbemMethod_Vtbl)).OutParameters)(this, objwbemoutparameters), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Qualifiers_(this: *mut core::ffi::c_void, objwbemqualifierset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemMethod", "Qualifiers_", 11usize, |this| ((**(this as *const *const ISWbemMethod_Vtbl)).Qualifiers_)(this, objwbemqualifierset), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            Origin,
+            #[cfg(feature = "Win32_System_Com")]
+            InParameters,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InParameters: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            OutParameters,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            OutParameters: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Qualifiers_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Qualifiers_: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISWbemMethod as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISWbemMethodSet_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, punk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemMethodSet", "_NewEnum", 7usize, |this| ((**(this as *const *const ISWbemMethodSet_Vtbl))._NewEnum)(this, punk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, strname: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32, objwbemmethod: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemMethodSet", "Item", 8usize, |this| ((**(this as *const *const ISWbemMethodSet_Vtbl)).Item)(this, strname, iflags, objwbemmethod), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, icount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemMethodSet", "Count", 9usize, |this| ((**(this as *const *const ISWbemMethodSet_Vtbl)).Count)(this, icount), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            Count,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISWbemMethodSet as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISWbemNamedValue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, varvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemNamedValue", "Value", 7usize, |this| ((**(this as *const *const ISWbemNamedValue_Vtbl)).Value)(this, varvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, varvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemNamedValue", "SetValue", 8usize, |this| ((**(this as *const *const ISWbemNamedValue_Vtbl)).SetValue)(this, varvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, strname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemNamedValue", "Name", 9usize, |this| ((**(this as *const *const ISWbemNamedValue_Vtbl)).Name)(this, strname), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Value, SetValue, Name }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISWbemNamedValue as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISWbemNamedValueSet_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, punk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemNamedValueSet", "_NewEnum", 7usize, |this| ((**(this as *const *const ISWbemNamedValueSet_Vtbl))._NewEnum)(this, punk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, strname: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32, objwbemnamedvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemNamedValueSet", "Item", 8usize, |this| ((**(this as *const *const ISWbemNamedValueSet_Vtbl)).Item)(this, strname, iflags, objwbemnamedvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, icount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemNamedValueSet", "Count", 9usize, |this| ((**(this as *const *const ISWbemNamedValueSet_Vtbl)).Count)(this, icount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, strname: core::mem::MaybeUninit<windows_core::BSTR>, varvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>, iflags: i32, objwbemnamedvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemNamedValueSet", "Add", 10usize, |this| ((**(this as *const *const ISWbemNamedValueSet_Vtbl)).Add)(this, strname, varvalue, iflags, objwbemnamedvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, strname: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemNamedValueSet", "Remove", 11usize, |this| ((**(this as *const *const ISWbemNamedValueSet_Vtbl)).Remove)(this, strname, iflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, objwbemnamedvalueset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemNamedValueSet", "Clone", 12usize, |this| ((**(this as *const *const ISWbemNamedValueSet_Vtbl)).Clone)(this, objwbemnamedvalueset), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemNamedValueSet", "DeleteAll", 13usize, |this| ((**(this as *const *const ISWbemNamedValueSet_Vtbl)).DeleteAll)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            #[cfg(feature = "Win32_System_Com")]
+            Clone,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Clone: 0,
+            DeleteAll,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISWbemNamedValueSet as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISWbemObject_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Put_(this: *mut core::ffi::c_void, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemobjectpath: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObject", "Put_", 7usize, |this| ((**(this as *const *const ISWbemObject_Vtbl)).Put_)(this, iflags, objwbemnamedvalueset, objwbemobjectpath), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PutAsync_(this: *mut core::ffi::c_void, objwbemsink: *mut core::ffi::c_void, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemasynccontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObject", "PutAsync_", 8usize, |this| ((**(this as *const *const ISWbemObject_Vtbl)).PutAsync_)(this, objwbemsink, iflags, objwbemnamedvalueset, objwbemasynccontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Delete_(this: *mut core::ffi::c_void, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObject", "Delete_", 9usize, |this| ((**(this as *const *const ISWbemObject_Vtbl)).Delete_)(this, iflags, objwbemnamedvalueset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DeleteAsync_(this: *mut core::ffi::c_void, objwbemsink: *mut core::ffi::c_void, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemasynccontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObject", "DeleteAsync_", 10usize, |this| ((**(this as *const *const ISWbemObject_Vtbl)).DeleteAsync_)(this, objwbemsink, iflags, objwbemnamedvalueset, objwbemasynccontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Instances_(this: *mut core::ffi::c_void, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemobjectset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObject", "Instances_", 11usize, |this| ((**(this as *const *const ISWbemObject_Vtbl)).Instances_)(this, iflags, objwbemnamedvalueset, objwbemobjectset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InstancesAsync_(this: *mut core::ffi::c_void, objwbemsink: *mut core::ffi::c_void, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemasynccontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObject", "InstancesAsync_", 12usize, |this| ((**(this as *const *const ISWbemObject_Vtbl)).InstancesAsync_)(this, objwbemsink, iflags, objwbemnamedvalueset, objwbemasynccontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Subclasses_(this: *mut core::ffi::c_void, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemobjectset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObject", "Subclasses_", 13usize, |this| ((**(this as *const *const ISWbemObject_Vtbl)).Subclasses_)(this, iflags, objwbemnamedvalueset, objwbemobjectset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SubclassesAsync_(this: *mut core::ffi::c_void, objwbemsink: *mut core::ffi::c_void, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemasynccontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObject", "SubclassesAsync_", 14usize, |this| ((**(this as *const *const ISWbemObject_Vtbl)).SubclassesAsync_)(this, objwbemsink, iflags, objwbemnamedvalueset, objwbemasynccontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Associators_(this: *mut core::ffi::c_void, strassocclass: core::mem::MaybeUninit<windows_core::BSTR>, strresultclass: core::mem::MaybeUninit<windows_core::BSTR>, strresultrole: core::mem::MaybeUninit<windows_core::BSTR>, strrole: core::mem::MaybeUninit<windows_core::BSTR>, bclassesonly: super::super::Foundation::VARIANT_BOOL, bschemaonly: super::super::Foundation::VARIANT_BOOL, strrequiredassocqualifier: core::mem::MaybeUninit<windows_core::BSTR>, strrequiredqualifier: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemobjectset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObject", "Associators_", 15usize, |this| ((**(this as *const *const ISWbemObject_Vtbl)).Associators_)(this, strassocclass, strresultclass, strresultrole, strrole, bclassesonly, bschemaonly, strrequiredassocqualifier, strrequiredqualifier, iflags, objwbemnamedvalueset, objwbemobjectset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AssociatorsAsync_(this: *mut core::ffi::c_void, objwbemsink: *mut core::ffi::c_void, strassocclass: core::mem::MaybeUninit<windows_core::BSTR>, strresultclass: core::mem::MaybeUninit<windows_core::BSTR>, strresultrole: core::mem::MaybeUninit<windows_core::BSTR>, strrole: core::mem::MaybeUninit<windows_core::BSTR>, bclassesonly: super::super::Foundation::VARIANT_BOOL, bschemaonly: super::super::Foundation::VARIANT_BOOL, strrequiredassocqualifier: core::mem::MaybeUninit<windows_core::BSTR>, strrequiredqualifier: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemasynccontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObject", "AssociatorsAsync_", 16usize, |this| ((**(this as *const *const ISWbemObject_Vtbl)).AssociatorsAsync_)(this, objwbemsink, strassocclass, strresultclass, strresultrole, strrole, bclassesonly, bschemaonly, strrequiredassocqualifier, strrequiredqualifier, iflags, objwbemnamedvalueset, objwbemasynccontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn References_(this: *mut core::ffi::c_void, strresultclass: core::mem::MaybeUninit<windows_core::BSTR>, strrole: core::mem::MaybeUninit<windows_core::BSTR>, bclassesonly: super::super::Foundation::VARIANT_BOOL, bschemaonly: super::super::Foundation::VARIANT_BOOL, strrequiredqualifier: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemobjectset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObject", "References_", 17usize, |this| ((**(this as *const *const ISWbemObject_Vtbl)).References_)(this, strresultclass, strrole, bclassesonly, bschemaonly, strrequiredqualifier, iflags, objwbemnamedvalueset, objwbemobjectset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ReferencesAsync_(this: *mut core::ffi::c_void, objwbemsink: *mut core::ffi::c_void, strresultclass: core::mem::MaybeUninit<windows_core::BSTR>, strrole: core::mem::MaybeUninit<windows_core::BSTR>, bclassesonly: super::super::Foundation::VARIANT_BOOL, bschemaonly: super::super::Foundation::VARIANT_BOOL, strrequiredqualifier: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemasynccontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObject", "ReferencesAsync_", 18usize, |this| ((**(this as *const *const ISWbemObject_Vtbl)).ReferencesAsync_)(this, objwbemsink, strresultclass, strrole, bclassesonly, bschemaonly, strrequiredqualifier, iflags, objwbemnamedvalueset, objwbemasynccontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ExecMethod_(this: *mut core::ffi::c_void, strmethodname: core::mem::MaybeUninit<windows_core::BSTR>, objwbeminparameters: *mut core::ffi::c_void, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemoutparameters: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObject", "ExecMethod_", 19usize, |this| ((**(this as *const *const ISWbemObject_Vtbl)).ExecMethod_)(this, strmethodname, objwbeminparameters, iflags, objwbemnamedvalueset, objwbemoutparameters), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ExecMethodAsync_(this: *mut core::ffi::c_void, objwbemsink: *mut core::ffi::c_void, strmethodname: core::mem::MaybeUninit<windows_core::BSTR>, objwbeminparameters: *mut core::ffi::c_void, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemasynccontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObject", "ExecMethodAsync_", 20usize, |this| ((**(this as *const *const ISWbemObject_Vtbl)).ExecMethodAsync_)(this, objwbemsink, strmethodname, objwbeminparameters, iflags, objwbemnamedvalueset, objwbemasynccontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Clone_(this: *mut core::ffi::c_void, objwbemobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObject", "Clone_", 21usize, |this| ((**(this as *const *const ISWbemObject_Vtbl)).Clone_)(this, objwbemobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObjectText_(this: *mut core::ffi::c_void, iflags: i32, strobjecttext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObject", "GetObjectText_", 22usize, |this| ((**(this as *const *const ISWbemObject_Vtbl)).GetObjectText_)(this, iflags, strobjecttext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SpawnDerivedClass_(this: *mut core::ffi::c_void, iflags: i32, objwbemobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObject", "SpawnDerivedClass_", 23usize, |this| ((**(this as *const *const ISWbemObject_Vtbl)).SpawnDerivedClass_)(this, iflags, objwbemobject), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SpawnInstance_(this: *mut core::ffi::c_void, iflags: i32, objwbemobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObject", "SpawnInstance_", 24usize, |this| ((**(this as *const *const ISWbemObject_Vtbl)).SpawnInstance_)(this, iflags, objwbemobject), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CompareTo_(this: *mut core::ffi::c_void, objwbemobject: *mut core::ffi::c_void, iflags: i32, bresult: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObject", "CompareTo_", 25usize, |this| ((**(this as *const *const ISWbemObject_Vtbl)).CompareTo_)(this, objwbemobject, iflags, bresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Qualifiers_(this: *mut core::ffi::c_void, objwbemqualifierset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObject", "Qualifiers_", 26usize, |this| ((**(this as *const *const ISWbemObject_Vtbl)).Qualifiers_)(this, objwbemqualifierset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Properties_(this: *mut core::ffi::c_void, objwbempropertyset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObject", "Properties_", 27usize, |this| ((**(this as *const *const ISWbemObject_Vtbl)).Properties_)(this, objwbempropertyset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Methods_(this: *mut core::ffi::c_void, objwbemmethodset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObject", "Methods_", 28usize, |this| ((**(this as *const *const ISWbemObject_Vtbl)).Methods_)(this, objwbemmethodset), |result| Some(*result))
+        }
+        unsafe extern "system" fn Derivation_(this: *mut core::ffi::c_void, strclassnamearray: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObject", "Derivation_", 29usize, |this| ((**(this as *const *const ISWbemObject_Vtbl)).Derivation_)(this, strclassnamearray), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Path_(this: *mut core::ffi::c_void, objwbemobjectpath: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObject", "Path_", 30usize, |this| ((**(this as *const *const ISWbemObject_Vtbl)).Path_)(this, objwbemobjectpath), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Security_(this: *mut core::ffi::c_void, objwbemsecurity: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObject", "Security_", 31usize, |this| ((**(this as *const *const ISWbemObject_Vtbl)).Security_)(this, objwbemsecurity), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Put_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Put_: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PutAsync_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PutAsync_: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Delete_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Delete_: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            DeleteAsync_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DeleteAsync_: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Instances_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Instances_: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            InstancesAsync_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InstancesAsync_: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Subclasses_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Subclasses_: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SubclassesAsync_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SubclassesAsync_: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Associators_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Associators_: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AssociatorsAsync_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AssociatorsAsync_: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            References_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            References_: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ReferencesAsync_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ReferencesAsync_: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ExecMethod_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ExecMethod_: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ExecMethodAsync_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ExecMethodAsync_: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Clone_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Clone_: 0,
+            GetObjectText_,
+            #[cfg(feature = "Win32_System_Com")]
+            SpawnDerivedClass_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SpawnDerivedClass_: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SpawnInstance_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SpawnInstance_: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CompareTo_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CompareTo_: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Qualifiers_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Qualifiers_: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Properties_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Properties_: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Methods_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Methods_: 0,
+            Derivation_,
+            #[cfg(feature = "Win32_System_Com")]
+            Path_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Path_: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Security_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Security_: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISWbemObject as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISWbemObjectEx_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Refresh_(this: *mut core::ffi::c_void, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectEx", "Refresh_", 32usize, |this| ((**(this as *const *const ISWbemObjectEx_Vtbl)).Refresh_)(this, iflags, objwbemnamedvalueset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SystemProperties_(this: *mut core::ffi::c_void, objwbempropertyset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectEx", "SystemProperties_", 33usize, |this| ((**(this as *const *const ISWbemObjectEx_Vtbl)).SystemProperties_)(this, objwbempropertyset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetText_(this: *mut core::ffi::c_void, iobjecttextformat: WbemObjectTextFormatEnum, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, bstext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectEx", "GetText_", 34usize, |this| ((**(this as *const *const ISWbemObjectEx_Vtbl)).GetText_)(this, iobjecttextformat, iflags, objwbemnamedvalueset, bstext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetFromText_(this: *mut core::ffi::c_void, bstext: core::mem::MaybeUninit<windows_core::BSTR>, iobjecttextformat: WbemObjectTextFormatEnum, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectEx", "SetFromText_", 35usize, |this| ((**(this as *const *const ISWbemObjectEx_Vtbl)).SetFromText_)(this, bstext, iobjecttextformat, iflags, objwbemnamedvalueset), |result| Some(*result))
+        }
+        Self {
+            base__: <ISWbemObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Refresh_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Refresh_: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SystemProperties_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SystemProperties_: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetText_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetText_: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetFromText_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetFromText_: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISWbemObjectEx as windows_core::Interface>::IID || <ISWbemObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISWbemObjectPath_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Path(this: *mut core::ffi::c_void, strpath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectPath", "Path", 7usize, |this| ((**(this as *const *const ISWbemObjectPath_Vtbl)).Path)(this, strpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPath(this: *mut core::ffi::c_void, strpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectPath", "SetPath", 8usize, |this| ((**(this as *const *const ISWbemObjectPath_Vtbl)).SetPath)(this, strpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn RelPath(this: *mut core::ffi::c_void, strrelpath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectPath", "RelPath", 9usize, |this| ((**(this as *const *const ISWbemObjectPath_Vtbl)).RelPath)(this, strrelpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRelPath(this: *mut core::ffi::c_void, strrelpath: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectPath", "SetRelPath", 10usize, |this| ((**(this as *const *const ISWbemObjectPath_Vtbl)).SetRelPath)(this, strrelpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn Server(this: *mut core::ffi::c_void, strserver: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectPath", "Server", 11usize, |this| ((**(this as *const *const ISWbemObjectPath_Vtbl)).Server)(this, strserver), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServer(this: *mut core::ffi::c_void, strserver: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectPath", "SetServer", 12usize, |this| ((**(this as *const *const ISWbemObjectPath_Vtbl)).SetServer)(this, strserver), |result| Some(*result))
+        }
+        unsafe extern "system" fn Namespace(this: *mut core::ffi::c_void, strnamespace: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectPath", "Namespace", 13usize, |this| ((**(this as *const *const ISWbemObjectPath_Vtbl)).Namespace)(this, strnamespace), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNamespace(this: *mut core::ffi::c_void, strnamespace: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectPath", "SetNamespace", 14usize, |this| ((**(this as *const *const ISWbemObjectPath_Vtbl)).SetNamespace)(this, strnamespace), |result| Some(*result))
+        }
+        unsafe extern "system" fn ParentNamespace(this: *mut core::ffi::c_void, strparentnamespace: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectPath", "ParentNamespace", 15usize, |this| ((**(this as *const *const ISWbemObjectPath_Vtbl)).ParentNamespace)(this, strparentnamespace), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, strdisplayname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectPath", "DisplayName", 16usize, |this| ((**(this as *const *const ISWbemObjectPath_Vtbl)).DisplayName)(this, strdisplayname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisplayName(this: *mut core::ffi::c_void, strdisplayname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectPath", "SetDisplayName", 17usize, |this| ((**(this as *const *const ISWbemObjectPath_Vtbl)).SetDisplayName)(this, strdisplayname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Class(this: *mut core::ffi::c_void, strclass: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectPath", "Class", 18usize, |this| ((**(this as *const *const ISWbemObjectPath_Vtbl)).Class)(this, strclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClass(this: *mut core::ffi::c_void, strclass: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectPath", "SetClass", 19usize, |this| ((**(this as *const *const ISWbemObjectPath_Vtbl)).SetClass)(this, strclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsClass(this: *mut core::ffi::c_void, bisclass: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectPath", "IsClass", 20usize, |this| ((**(this as *const *const ISWbemObjectPath_Vtbl)).IsClass)(this, bisclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAsClass(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectPath", "SetAsClass", 21usize, |this| ((**(this as *const *const ISWbemObjectPath_Vtbl)).SetAsClass)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSingleton(this: *mut core::ffi::c_void, bissingleton: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectPath", "IsSingleton", 22usize, |this| ((**(this as *const *const ISWbemObjectPath_Vtbl)).IsSingleton)(this, bissingleton), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAsSingleton(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectPath", "SetAsSingleton", 23usize, |this| ((**(this as *const *const ISWbemObjectPath_Vtbl)).SetAsSingleton)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Keys(this: *mut core::ffi::c_void, objwbemnamedvalueset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectPath", "Keys", 24usize, |this| ((**(this as *const *const ISWbemObjectPath_Vtbl)).Keys)(this, objwbemnamedvalueset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Security_(this: *mut core::ffi::c_void, objwbemsecurity: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectPath", "Security_", 25usize, |this| ((**(this as *const *const ISWbemObjectPath_Vtbl)).Security_)(this, objwbemsecurity), |result| Some(*result))
+        }
+        unsafe extern "system" fn Locale(this: *mut core::ffi::c_void, strlocale: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectPath", "Locale", 26usize, |this| ((**(this as *const *const ISWbemObjectPath_Vtbl)).Locale)(this, strlocale), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocale(this: *mut core::ffi::c_void, strlocale: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectPath", "SetLocale", 27usize, |this| ((**(this as *const *const ISWbemObjectPath_Vtbl)).SetLocale)(this, strlocale), |result| Some(*result))
+        }
+        unsafe extern "system" fn Authority(this: *mut core::ffi::c_void, strauthority: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectPath", "Authority", 28usize, |this| ((**(this as *const *const ISWbemObjectPath_Vtbl)).Authority)(this, strauthority), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthority(this: *mut core::ffi::c_void, strauthority: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectPath", "SetAuthority", 29usize, |this| ((**(this as *const *const ISWbemObjectPath_Vtbl)).SetAuthority)(this, strauthority), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Path,
+            SetPath,
+            RelPath,
+            SetRelPath,
+            Server,
+            SetServer,
+            Namespace,
+            SetNamespace,
+            ParentNamespace,
+            DisplayName,
+            SetDisplayName,
+            Class,
+            SetClass,
+            IsClass,
+            SetAsClass,
+            IsSingleton,
+            SetAsSingleton,
+            #[cfg(feature = "Win32_System_Com")]
+            Keys,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Keys: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Security_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Security_: 0,
+            Locale,
+            SetLocale,
+            Authority,
+            SetAuthority,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISWbemObjectPath as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISWbemObjectSet_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, punk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectSet", "_NewEnum", 7usize, |this| ((**(this as *const *const ISWbemObjectSet_Vtbl))._NewEnum)(this, punk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, strobjectpath: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32, objwbemobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectSet", "Item", 8usize, |this| ((**(this as *const *const ISWbemObjectSet_Vtbl)).Item)(this, strobjectpath, iflags, objwbemobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, icount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectSet", "Count", 9usize, |this| ((**(this as *const *const ISWbemObjectSet_Vtbl)).Count)(this, icount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Security_(this: *mut core::ffi::c_void, objwbemsecurity: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectSet", "Security_", 10usize, |this| ((**(this as *const *const ISWbemObjectSet_Vtbl)).Security_)(this, objwbemsecurity), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ItemIndex(this: *mut core::ffi::c_void, lindex: i32, objwbemobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemObjectSet", "ItemIndex", 11usize, |this| ((**(this as *const *const ISWbemObjectSet_Vtbl)).ItemIndex)(this, lindex, objwbemobject), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            Security_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Security_: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ItemIndex,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ItemIndex: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISWbemObjectSet as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISWbemPrivilege_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, bisenabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemPrivilege", "IsEnabled", 7usize, |this| ((**(this as *const *const ISWbemPrivilege_Vtbl)).IsEnabled)(this, bisenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsEnabled(this: *mut core::ffi::c_void, bisenabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemPrivilege", "SetIsEnabled", 8usize, |this| ((**(this as *const *const ISWbemPrivilege_Vtbl)).SetIsEnabled)(this, bisenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, strdisplayname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemPrivilege", "Name", 9usize, |this| ((**(this as *const *const ISWbemPrivilege_Vtbl)).Name)(this, strdisplayname), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayName(this: *mut core::ffi::c_void, strdisplayname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemPrivilege", "DisplayName", 10usize, |this| ((**(this as *const *const ISWbemPrivilege_Vtbl)).DisplayName)(this, strdisplayname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Identifier(this: *mut core::ffi::c_void, iprivilege: *mut WbemPrivilegeEnum) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemPrivilege", "Identifier", 11usize, |this| ((**(this as *const *const ISWbemPrivilege_Vtbl)).Identifier)(this, iprivilege), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsEnabled, SetIsEnabled, Name, DisplayName, Identifier }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISWbemPrivilege as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISWbemPrivilegeSet_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, punk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemPrivilegeSet", "_NewEnum", 7usize, |this| ((**(this as *const *const ISWbemPrivilegeSet_Vtbl))._NewEnum)(this, punk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, iprivilege: WbemPrivilegeEnum, objwbemprivilege: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemPrivilegeSet", "Item", 8usize, |this| ((**(this as *const *const ISWbemPrivilegeSet_Vtbl)).Item)(this, iprivilege, objwbemprivilege), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, icount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemPrivilegeSet", "Count", 9usize, |this| ((**(this as *const *const ISWbemPrivilegeSet_Vtbl)).Count)(this, icount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, iprivilege: WbemPrivilegeEnum, bisenabled: super::super::Foundation::VARIANT_BOOL, objwbemprivilege: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemPrivilegeSet", "Add", 10usize, |this| ((**(this as *const *const ISWbemPrivilegeSet_Vtbl)).Add)(this, iprivilege, bisenabled, objwbemprivilege), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, iprivilege: WbemPrivilegeEnum) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemPrivilegeSet", "Remove", 11usize, |this| ((**(this as *const *const ISWbemPrivilegeSet_Vtbl)).Remove)(this, iprivilege), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemPrivilegeSet", "DeleteAll", 12usize, |this| ((**(this as *const *const ISWbemPrivilegeSet_Vtbl)).DeleteAll)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddAsString(this: *mut core::ffi::c_void, strprivilege: core::mem::MaybeUninit<windows_core::BSTR>, bisenabled: super::super::Foundation::VARIANT_BOOL, objwbemprivilege: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemPrivilegeSet", "AddAsString", 13usize, |this| ((**(this as *const *const ISWbemPrivilegeSet_Vtbl)).AddAsString)(this, strprivilege, bisenabled, objwbemprivilege), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            DeleteAll,
+            #[cfg(feature = "Win32_System_Com")]
+            AddAsString,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddAsString: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISWbemPrivilegeSet as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISWbemProperty_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, varvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemProperty", "Value", 7usize, |this| ((**(this as *const *const ISWbemProperty_Vtbl)).Value)(this, varvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, varvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemProperty", "SetValue", 8usize, |this| ((**(this as *const *const ISWbemProperty_Vtbl)).SetValue)(this, varvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, strname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemProperty", "Name", 9usize, |this| ((**(this as *const *const ISWbemProperty_Vtbl)).Name)(this, strname), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLocal(this: *mut core::ffi::c_void, bislocal: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemProperty", "IsLocal", 10usize, |this| ((**(this as *const *const ISWbemProperty_Vtbl)).IsLocal)(this, bislocal), |result| Some(*result))
+        }
+        unsafe extern "system" fn Origin(this: *mut core::ffi::c_void, strorigin: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemProperty", "Origin", 11usize, |this| ((**(this as *const *const ISWbemProperty_Vtbl)).Origin)(this, strorigin), |result| Some(*result))
+        }
+        unsafe extern "system" fn CIMType(this: *mut core::ffi::c_void, icimtype: *mut WbemCimtypeEnum) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemProperty", "CIMType", 12usize, |this| ((**(this as *const *const ISWbemProperty_Vtbl)).CIMType)(this, icimtype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Qualifiers_(this: *mut core::ffi::c_void, objwbemqualifierset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemProperty", "Qualifiers_", 13usize, |this| ((**(this as *const *const ISWbemProperty_Vtbl)).Qualifiers_)(this, objwbemqualifierset), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsArray(this: *mut core::ffi::c_void, bisarray: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemProperty", "IsArray", 14usize, |this| ((**(this as *const *const ISWbemProperty_Vtbl)).IsArray)(this, bisarray), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Value,
+            SetValue,
+            Name,
+            IsLocal,
+            Origin,
+            CIMType,
+            #[cfg(feature = "Win32_System_Com")]
+            Qualifiers_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Qualifiers_: 0,
+            IsArray,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISWbemProperty as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISWbemPropertySet_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, punk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemPropertySet", "_NewEnum", 7usize, |this| ((**(this as *const *const ISWbemPropertySet_Vtbl))._NewEnum)(this, punk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, strname: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32, objwbemproperty: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemPropertySet", "Item", 8usize, |this| ((**(this as *const *const ISWbemPropertySet_Vtbl)).Item)(this, strname, iflags, objwbemproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, icount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemPropertySet", "Count", 9usize, |this| ((**(this as *const *const ISWbemPropertySet_Vtbl)).Count)(this, icount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, strname: core::mem::MaybeUninit<windows_core::BSTR>, icimtype: WbemCimtypeEnum, bisarray: super::super::Foundation::VARIANT_BOOL, iflags: i32, objwbemproperty: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemPropertySet", "Add", 10usize, |this| ((**(this as *const *const ISWbemPropertySet_Vtbl)).Add)(this, strname, icimtype, bisarray, iflags, objwbemproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, strname: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemPropertySet", "Remove", 11usize, |this| ((**(this as *const *const ISWbemPropertySet_Vtbl)).Remove)(this, strname, iflags), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISWbemPropertySet as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISWbemQualifier_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, varvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemQualifier", "Value", 7usize, |this| ((**(this as *const *const ISWbemQualifier_Vtbl)).Value)(this, varvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, varvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemQualifier", "SetValue", 8usize, |this| ((**(this as *const *const ISWbemQualifier_Vtbl)).SetValue)(this, varvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, strname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemQualifier", "Name", 9usize, |this| ((**(this as *const *const ISWbemQualifier_Vtbl)).Name)(this, strname), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsLocal(this: *mut core::ffi::c_void, bislocal: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemQualifier", "IsLocal", 10usize, |this| ((**(this as *const *const ISWbemQualifier_Vtbl)).IsLocal)(this, bislocal), |result| Some(*result))
+        }
+        unsafe extern "system" fn PropagatesToSubclass(this: *mut core::ffi::c_void, bpropagatestosubclass: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemQualifier", "PropagatesToSubclass", 11usize, |this| ((**(this as *const *const ISWbemQualifier_Vtbl)).PropagatesToSubclass)(this, bpropagatestosubclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPropagatesToSubclass(this: *mut core::ffi::c_void, bpropagatestosubclass: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemQualifier", "SetPropagatesToSubclass", 12usize, |this| ((**(this as *const *const ISWbemQualifier_Vtbl)).SetPropagatesToSubclass)(this, bpropagatestosubclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn PropagatesToInstance(this: *mut core::ffi::c_void, bpropagatestoinstance: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemQualifier", "PropagatesToInstance", 13usize, |this| ((**(this as *const *const ISWbemQualifier_Vtbl)).PropagatesToInstance)(this, bpropagatestoinstance), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPropagatesToInstance(this: *mut core::ffi::c_void, bpropagatestoinstance: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemQualifier", "SetPropagatesToInstance", 14usize, |this| ((**(this as *const *const ISWbemQualifier_Vtbl)).SetPropagatesToInstance)(this, bpropagatestoinstance), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsOverridable(this: *mut core::ffi::c_void, bisoverridable: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemQualifier", "IsOverridable", 15usize, |this| ((**(this as *const *const ISWbemQualifier_Vtbl)).IsOverridable)(this, bisoverridable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIsOverridable(this: *mut core::ffi::c_void, bisoverridable: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemQualifier", "SetIsOverridable", 16usize, |this| ((**(this as *const *const ISWbemQualifier_Vtbl)).SetIsOverridable)(this, bisoverridable), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsAmended(this: *mut core::ffi::c_void, bisamended: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemQualifier", "IsAmended", 17usize, |this| ((**(this as *const *const ISWbemQualifier_Vtbl)).IsAmended)(this, bisamended), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Value,
+            SetValue,
+            Name,
+            IsLocal,
+            PropagatesToSubclass,
+            SetPropagatesToSubclass,
+            PropagatesToInstance,
+            SetPropagatesToInstance,
+            IsOverridable,
+            SetIsOverridable,
+            IsAmended,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISWbemQualifier as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISWbemQualifierSet_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, punk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemQualifierSet", "_NewEnum", 7usize, |this| ((**(this as *const *const ISWbemQualifierSet_Vtbl))._NewEnum)(this, punk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32, objwbemqualifier: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemQualifierSet", "Item", 8usize, |this| ((**(this as *const *const ISWbemQualifierSet_Vtbl)).Item)(this, name, iflags, objwbemqualifier), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, icount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemQualifierSet", "Count", 9usize, |this| ((**(this as *const *const ISWbemQualifierSet_Vtbl)).Count)(this, icount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, strname: core::mem::MaybeUninit<windows_core::BSTR>, varval: *const core::mem::MaybeUninit<windows_core::VARIANT>, bpropagatestosubclass: super::super::Foundation::VARIANT_BOOL, bpropagatestoinstance: super::super::Foundation::VARIANT_BOOL, bisoverridable: super::super::Foundation::VARIANT_BOOL, iflags: i32, objwbemqualifier: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemQualifierSet", "Add", 10usize, |this| ((**(this as *const *const ISWbemQualifierSet_Vtbl)).Add)(this, strname, varval, bpropagatestosubclass, bpropagatestoinstance, bisoverridable, iflags, objwbemqualifier), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, strname: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemQualifierSet", "Remove", 11usize, |this| ((**(this as *const *const ISWbemQualifierSet_Vtbl)).Remove)(this, strname, iflags), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISWbemQualifierSet as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISWbemRefreshableItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Index(this: *mut core::ffi::c_void, iindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemRefreshableItem", "Index", 7usize, |this| ((**(this as *const *const ISWbemRefreshableItem_Vtbl)).Index)(this, iindex), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Refresher(this: *mut core::ffi::c_void, objwbemrefresher: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemRefreshableItem", "Refresher", 8usize, |this| ((**(this as *const *const ISWbemRefreshableItem_Vtbl)).Refresher)(this, objwbemrefresher), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSet(this: *mut core::ffi::c_void, bisset: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemRefreshableItem", "IsSet", 9usize, |this| ((**(this as *const *const ISWbemRefreshableItem_Vtbl)).IsSet)(this, bisset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Object(this: *mut core::ffi::c_void, objwbemobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemRefreshableItem", "Object", 10usize, |this| ((**(this as *const *const ISWbemRefreshableItem_Vtbl)).Object)(this, objwbemobject), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ObjectSet(this: *mut core::ffi::c_void, objwbemobjectset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemRefreshableItem", "ObjectSet", 11usize, |this| ((**(this as *const *const ISWbemRefreshableItem_Vtbl)).ObjectSet)(this, objwbemobjectset), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, iflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemRefreshableItem", "Remove", 12usize, |this| ((**(this as *const *const ISWbemRefreshableItem_Vtbl)).Remove)(this, iflags), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Index,
+            #[cfg(feature = "Win32_System_Com")]
+            Refresher,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Refresher: 0,
+            IsSet,
+            #[cfg(feature = "Win32_System_Com")]
+            Object,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Object: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ObjectSet,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ObjectSet: 0,
+            Remove,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISWbemRefreshableItem as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISWbemRefresher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, punk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemRefresher", "_NewEnum", 7usize, |this| ((**(this as *const *const ISWbemRefresher_Vtbl))._NewEnum)(this, punk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, iindex: i32, objwbemrefreshableitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemRefresher", "Item", 8usize, |this| ((**(this as *const *const ISWbemRefresher_Vtbl)).Item)(this, iindex, objwbemrefreshableitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, icount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemRefresher", "Count", 9usize, |this| ((**(this as *const *const ISWbemRefresher_Vtbl)).Count)(this, icount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, objwbemservices: *mut core::ffi::c_void, bsinstancepath: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemrefreshableitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemRefresher", "Add", 10usize, |this| ((**(this as *const *const ISWbemRefresher_Vtbl)).Add)(this, objwbemservices, bsinstancepath, iflags, objwbemnamedvalueset, objwbemrefreshableitem), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddEnum(this: *mut core::ffi::c_void, objwbemservices: *mut core::ffi::c_void, bsclassname: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemrefreshableitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemRefresher", "AddEnum", 11usize, |this| ((**(this as *const *const ISWbemRefresher_Vtbl)).AddEnum)(this, objwbemservices, bsclassname, iflags, objwbemnamedvalueset, objwbemrefreshableitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, iindex: i32, iflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemRefresher", "Remove", 12usize, |this| ((**(this as *const *const ISWbemRefresher_Vtbl)).Remove)(this, iindex, iflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void, iflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemRefresher", "Refresh", 13usize, |this| ((**(this as *const *const ISWbemRefresher_Vtbl)).Refresh)(this, iflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoReconnect(this: *mut core::ffi::c_void, bcount: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemRefresher", "AutoReconnect", 14usize, |this| ((**(this as *const *const ISWbemRefresher_Vtbl)).AutoReconnect)(this, bcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoReconnect(this: *mut core::ffi::c_void, bcount: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemRefresher", "SetAutoReconnect", 15usize, |this| ((**(this as *const *const ISWbemRefresher_Vtbl)).SetAutoReconnect)(this, bcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemRefresher", "DeleteAll", 16usize, |this| ((**(this as *const *const ISWbemRefresher_Vtbl)).DeleteAll)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AddEnum,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddEnum: 0,
+            Remove,
+            Refresh,
+            AutoReconnect,
+            SetAutoReconnect,
+            DeleteAll,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISWbemRefresher as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISWbemSecurity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ImpersonationLevel(this: *mut core::ffi::c_void, iimpersonationlevel: *mut WbemImpersonationLevelEnum) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemSecurity", "ImpersonationLevel", 7usize, |this| ((**(this as *const *const ISWbemSecurity_Vtbl)).ImpersonationLevel)(this, iimpersonationlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetImpersonationLevel(this: *mut core::ffi::c_void, iimpersonationlevel: WbemImpersonationLevelEnum) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemSecurity", "SetImpersonationLevel", 8usize, |this| ((**(this as *const *const ISWbemSecurity_Vtbl)).SetImpersonationLevel)(this, iimpersonationlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn AuthenticationLevel(this: *mut core::ffi::c_void, iauthenticationlevel: *mut WbemAuthenticationLevelEnum) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemSecurity", "AuthenticationLevel", 9usize, |this| ((**(this as *const *const ISWbemSecurity_Vtbl)).AuthenticationLevel)(this, iauthenticationlevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAuthenticationLevel(this: *mut core::ffi::c_void, iauthenticationlevel: WbemAuthenticationLevelEnum) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemSecurity", "SetAuthenticationLevel", 10usize, |this| ((**(this as *const *const ISWbemSecurity_Vtbl)).SetAuthenticationLevel)(this, iauthenticationlevel), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Privileges(this: *mut core::ffi::c_void, objwbemprivilegeset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemSecurity", "Privileges", 11usize, |this| ((**(this as *const *const ISWbemSecurity_Vtbl)).Privileges)(this, objwbemprivilegeset), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ImpersonationLevel,
+            SetImpersonationLevel,
+            AuthenticationLevel,
+            SetAuthenticationLevel,
+            #[cfg(feature = "Win32_System_Com")]
+            Privileges,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Privileges: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISWbemSecurity as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISWbemServices_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Get(this: *mut core::ffi::c_void, strobjectpath: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemServices", "Get", 7usize, |this| ((**(this as *const *const ISWbemServices_Vtbl)).Get)(this, strobjectpath, iflags, objwbemnamedvalueset, objwbemobject), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetAsync(this: *mut core::ffi::c_void, objwbemsink: *mut core::ffi::c_void, strobjectpath: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemasynccontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemServices", "GetAsync", 8usize, |this| ((**(this as *const *const ISWbemServices_Vtbl)).GetAsync)(this, objwbemsink, strobjectpath, iflags, objwbemnamedvalueset, objwbemasynccontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, strobjectpath: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemServices", "Delete", 9usize, |this| ((**(this as *const *const ISWbemServices_Vtbl)).Delete)(this, strobjectpath, iflags, objwbemnamedvalueset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DeleteAsync(this: *mut core::ffi::c_void, objwbemsink: *mut core::ffi::c_void, strobjectpath: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemasynccontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemServices", "DeleteAsync", 10usize, |this| ((**(this as *const *const ISWbemServices_Vtbl)).DeleteAsync)(this, objwbemsink, strobjectpath, iflags, objwbemnamedvalueset, objwbemasynccontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InstancesOf(this: *mut core::ffi::c_void, strclass: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemobjectset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemServices", "InstancesOf", 11usize, |this| ((**(this as *const *const ISWbemServices_Vtbl)).InstancesOf)(this, strclass, iflags, objwbemnamedvalueset, objwbemobjectset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InstancesOfAsync(this: *mut core::ffi::c_void, objwbemsink: *mut core::ffi::c_void, strclass: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemasynccontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemServices", "InstancesOfAsync", 12usize, |this| ((**(this as *const *const ISWbemServices_Vtbl)).InstancesOfAsync)(this, objwbemsink, strclass, iflags, objwbemnamedvalueset, objwbemasynccontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SubclassesOf(this: *mut core::ffi::c_void, strsuperclass: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemobjectset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemServices", "SubclassesOf", 13usize, |this| ((**(this as *const *const ISWbemServices_Vtbl)).SubclassesOf)(this, strsuperclass, iflags, objwbemnamedvalueset, objwbemobjectset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SubclassesOfAsync(this: *mut core::ffi::c_void, objwbemsink: *mut core::ffi::c_void, strsuperclass: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemasynccontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemServices", "SubclassesOfAsync", 14usize, |this| ((**(this as *const *const ISWbemServices_Vtbl)).SubclassesOfAsync)(this, objwbemsink, strsuperclass, iflags, objwbemnamedvalueset, objwbemasynccontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ExecQuery(this: *mut core::ffi::c_void, strquery: core::mem::MaybeUninit<windows_core::BSTR>, strquerylanguage: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemobjectset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemServices", "ExecQuery", 15usize, |this| ((**(this as *const *const ISWbemServices_Vtbl)).ExecQuery)(this, strquery, strquerylanguage, iflags, objwbemnamedvalueset, objwbemobjectset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ExecQueryAsync(this: *mut core::ffi::c_void, objwbemsink: *mut core::ffi::c_void, strquery: core::mem::MaybeUninit<windows_core::BSTR>, strquerylanguage: core::mem::MaybeUninit<windows_core::BSTR>, lflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemasynccontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemServices", "ExecQueryAsync", 16usize, |this| ((**(this as *const *const ISWbemServices_Vtbl)).ExecQueryAsync)(this, objwbemsink, strquery, strquerylanguage, lflags, objwbemnamedvalueset, objwbemasynccontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AssociatorsOf(this: *mut core::ffi::c_void, strobjectpath: core::mem::MaybeUninit<windows_core::BSTR>, strassocclass: core::mem::MaybeUninit<windows_core::BSTR>, strresultclass: core::mem::MaybeUninit<windows_core::BSTR>, strresultrole: core::mem::MaybeUninit<windows_core::BSTR>, strrole: core::mem::MaybeUninit<windows_core::BSTR>, bclassesonly: super::super::Foundation::VARIANT_BOOL, bschemaonly: super::super::Foundation::VARIANT_BOOL, strrequiredassocqualifier: core::mem::MaybeUninit<windows_core::BSTR>, strrequiredqualifier: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemobjectset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemServices", "AssociatorsOf", 17usize, |this| ((**(this as *const *const ISWbemServices_Vtbl)).AssociatorsOf)(this, strobjectpath, strassocclass, strresultclass, strresultrole, strrole, bclassesonly, bschemaonly, strrequiredassocqualifier, strrequiredqualifier, iflags, objwbemnamedvalueset, objwbemobjectset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AssociatorsOfAsync(this: *mut core::ffi::c_void, objwbemsink: *mut core::ffi::c_void, strobjectpath: core::mem::MaybeUninit<windows_core::BSTR>, strassocclass: core::mem::MaybeUninit<windows_core::BSTR>, strresultclass: core::mem::MaybeUninit<windows_core::BSTR>, strresultrole: core::mem::MaybeUninit<windows_core::BSTR>, strrole: core::mem::MaybeUninit<windows_core::BSTR>, bclassesonly: super::super::Foundation::VARIANT_BOOL, bschemaonly: super::super::Foundation::VARIANT_BOOL, strrequiredassocqualifier: core::mem::MaybeUninit<windows_core::BSTR>, strrequiredqualifier: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemasynccontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemServices", "AssociatorsOfAsync", 18usize, |this| ((**(this as *const *const ISWbemServices_Vtbl)).AssociatorsOfAsync)(this, objwbemsink, strobjectpath, strassocclass, strresultclass, strresultrole, strrole, bclassesonly, bschemaonly, strrequiredassocqualifier, strrequiredqualifier, iflags, objwbemnamedvalueset, objwbemasynccontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ReferencesTo(this: *mut core::ffi::c_void, strobjectpath: core::mem::MaybeUninit<windows_core::BSTR>, strresultclass: core::mem::MaybeUninit<windows_core::BSTR>, strrole: core::mem::MaybeUninit<windows_core::BSTR>, bclassesonly: super::super::Foundation::VARIANT_BOOL, bschemaonly: super::super::Foundation::VARIANT_BOOL, strrequiredqualifier: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemobjectset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemServices", "ReferencesTo", 19usize, |this| ((**(this as *const *const ISWbemServices_Vtbl)).ReferencesTo)(this, strobjectpath, strresultclass, strrole, bclassesonly, bschemaonly, strrequiredqualifier, iflags, objwbemnamedvalueset, objwbemobjectset), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ReferencesToAsync(this: *mut core::ffi::c_void, objwbemsink: *mut core::ffi::c_void, strobjectpath: core::mem::MaybeUninit<windows_core::BSTR>, strresultclass: core::mem::MaybeUninit<windows_core::BSTR>, strrole: core::mem::MaybeUninit<windows_core::BSTR>, bclassesonly: super::super::Foundation::VARIANT_BOOL, bschemaonly: super::super::Foundation::VARIANT_BOOL, strrequiredqualifier: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemasynccontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemServices", "ReferencesToAsync", 20usize, |this| ((**(this as *const *const ISWbemServices_Vtbl)).ReferencesToAsync)(this, objwbemsink, strobjectpath, strresultclass, strrole, bclassesonly, bschemaonly, strrequiredqualifier, iflags, objwbemnamedvalueset, objwbemasynccontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ExecNotificationQuery(this: *mut core::ffi::c_void, strquery: core::mem::MaybeUninit<windows_core::BSTR>, strquerylanguage: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemeventsource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemServices", "ExecNotificationQuery", 21usize, |this| ((**(this as *const *const ISWbemServices_Vtbl)).ExecNotificationQuery)(this, strquery, strquerylanguage, iflags, objwbemnamedvalueset, objwbemeventsource), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ExecNotificationQueryAsync(this: *mut core::ffi::c_void, objwbemsink: *mut core::ffi::c_void, strquery: core::mem::MaybeUninit<windows_core::BSTR>, strquerylanguage: core::mem::MaybeUninit<windows_core::BSTR>, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemasynccontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemServices", "ExecNotificationQueryAsync", 22usize, |this| ((**(this as *const *const ISWbemServices_Vtbl)).ExecNotificationQueryAsync)(this, objwbemsink, strquery, strquerylanguage, iflags, objwbemnamedvalueset, objwbemasynccontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ExecMethod(this: *mut core::ffi::c_void, strobjectpath: core::mem::MaybeUninit<windows_core::BSTR>, strmethodname: core::mem::MaybeUninit<windows_core::BSTR>, objwbeminparameters: *mut core::ffi::c_void, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemoutparameters: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemServices", "ExecMethod", 23usize, |this| ((**(this as *const *const ISWbemServices_Vtbl)).ExecMethod)(this, strobjectpath, strmethodname, objwbeminparameters, iflags, objwbemnamedvalueset, objwbemoutparameters), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ExecMethodAsync(this: *mut core::ffi::c_void, objwbemsink: *mut core::ffi::c_void, strobjectpath: core::mem::MaybeUninit<windows_core::BSTR>, strmethodname: core::mem::MaybeUninit<windows_core::BSTR>, objwbeminparameters: *mut core::ffi::c_void, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemasynccontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemServices", "ExecMethodAsync", 24usize, |this| ((**(this as *const *const ISWbemServices_Vtbl)).ExecMethodAsync)(this, objwbemsink, strobjectpath, strmethodname, objwbeminparameters, iflags, objwbemnamedvalueset, objwbemasynccontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Security_(this: *mut core::ffi::c_void, objwbemsecurity: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemServices", "Security_", 25usize, |this| ((**(this as *const *const ISWbemServices_Vtbl)).Security_)(this, objwbemsecurity), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Get,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Get: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetAsync,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetAsync: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Delete,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Delete: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            DeleteAsync,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DeleteAsync: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            InstancesOf,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InstancesOf: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            InstancesOfAsync,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InstancesOfAsync: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SubclassesOf,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SubclassesOf: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SubclassesOfAsync,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SubclassesOfAsync: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ExecQuery,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ExecQuery: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ExecQueryAsync,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ExecQueryAsync: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AssociatorsOf,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AssociatorsOf: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AssociatorsOfAsync,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AssociatorsOfAsync: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ReferencesTo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ReferencesTo: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ReferencesToAsync,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ReferencesToAsync: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ExecNotificationQuery,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ExecNotificationQuery: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ExecNotificationQueryAsync,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ExecNotificationQueryAsync: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ExecMethod,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ExecMethod: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ExecMethodAsync,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ExecMethodAsync: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Security_,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Security_: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISWbemServices as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISWbemServicesEx_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Put(this: *mut core::ffi::c_void, objwbemobject: *mut core::ffi::c_void, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemobjectpath: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemServicesEx", "Put", 26usize, |this| ((**(this as *const *const ISWbemServicesEx_Vtbl)).Put)(this, objwbemobject, iflags, objwbemnamedvalueset, objwbemobjectpath), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PutAsync(this: *mut core::ffi::c_void, objwbemsink: *mut core::ffi::c_void, objwbemobject: *mut core::ffi::c_void, iflags: i32, objwbemnamedvalueset: *mut core::ffi::c_void, objwbemasynccontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemServicesEx", "PutAsync", 27usize, |this| ((**(this as *const *const ISWbemServicesEx_Vtbl)).PutAsync)(this, objwbemsink, objwbemobject, iflags, objwbemnamedvalueset, objwbemasynccontext), |result| Some(*result))
+        }
+        Self {
+            base__: <ISWbemServices_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Put,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Put: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PutAsync,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PutAsync: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISWbemServicesEx as windows_core::Interface>::IID || <ISWbemServices_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISWbemSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISWbemSink", "Cancel", 7usize, |this| ((**(this as *const *const ISWbemSink_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Cancel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISWbemSink as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISWbemSinkEvents_Vtbl {
+    const PROXY: Self = { Self { base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISWbemSinkEvents as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUnsecuredApartment_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateObjectStub(this: *mut core::ffi::c_void, pobject: *mut core::ffi::c_void, ppstub: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUnsecuredApartment", "CreateObjectStub", 3usize, |this| ((**(this as *const *const IUnsecuredApartment_Vtbl)).CreateObjectStub)(this, pobject, ppstub), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateObjectStub }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUnsecuredApartment as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWMIExtension_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WMIObjectPath(this: *mut core::ffi::c_void, strwmiobjectpath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMIExtension", "WMIObjectPath", 7usize, |this| ((**(this as *const *const IWMIExtension_Vtbl)).WMIObjectPath)(this, strwmiobjectpath), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetWMIObject(this: *mut core::ffi::c_void, objwmiobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMIExtension", "GetWMIObject", 8usize, |this| ((**(this as *const *const IWMIExtension_Vtbl)).GetWMIObject)(this, objwmiobject), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetWMIServices(this: *mut core::ffi::c_void, objwmiservices: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWMIExtension", "GetWMIServices", 9usize, |this| ((**(this as *const *const IWMIExtension_Vtbl)).GetWMIServices)(this, objwmiservices), |result| Some(*result))
+        }
+        Self {
+            base__: <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            WMIObjectPath,
+            #[cfg(feature = "Win32_System_Com")]
+            GetWMIObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetWMIObject: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetWMIServices,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetWMIServices: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWMIExtension as windows_core::Interface>::IID || <super::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemAddressResolution_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Resolve(this: *mut core::ffi::c_void, wsznamespacepath: windows_core::PCWSTR, wszaddresstype: windows_core::PWSTR, pdwaddresslength: *mut u32, pabbinaryaddress: *mut *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemAddressResolution", "Resolve", 3usize, |this| ((**(this as *const *const IWbemAddressResolution_Vtbl)).Resolve)(this, wsznamespacepath, wszaddresstype, pdwaddresslength, pabbinaryaddress), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Resolve }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemAddressResolution as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemBackupRestore_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Backup(this: *mut core::ffi::c_void, strbackuptofile: windows_core::PCWSTR, lflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemBackupRestore", "Backup", 3usize, |this| ((**(this as *const *const IWbemBackupRestore_Vtbl)).Backup)(this, strbackuptofile, lflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Restore(this: *mut core::ffi::c_void, strrestorefromfile: windows_core::PCWSTR, lflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemBackupRestore", "Restore", 4usize, |this| ((**(this as *const *const IWbemBackupRestore_Vtbl)).Restore)(this, strrestorefromfile, lflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Backup, Restore }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemBackupRestore as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemBackupRestoreEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemBackupRestoreEx", "Pause", 5usize, |this| ((**(this as *const *const IWbemBackupRestoreEx_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemBackupRestoreEx", "Resume", 6usize, |this| ((**(this as *const *const IWbemBackupRestoreEx_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        Self { base__: <IWbemBackupRestore_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Pause, Resume }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemBackupRestoreEx as windows_core::Interface>::IID || <IWbemBackupRestore_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemCallResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetResultObject(this: *mut core::ffi::c_void, ltimeout: i32, ppresultobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemCallResult", "GetResultObject", 3usize, |this| ((**(this as *const *const IWbemCallResult_Vtbl)).GetResultObject)(this, ltimeout, ppresultobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResultString(this: *mut core::ffi::c_void, ltimeout: i32, pstrresultstring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemCallResult", "GetResultString", 4usize, |this| ((**(this as *const *const IWbemCallResult_Vtbl)).GetResultString)(this, ltimeout, pstrresultstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetResultServices(this: *mut core::ffi::c_void, ltimeout: i32, ppservices: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemCallResult", "GetResultServices", 5usize, |this| ((**(this as *const *const IWbemCallResult_Vtbl)).GetResultServices)(this, ltimeout, ppservices), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCallStatus(this: *mut core::ffi::c_void, ltimeout: i32, plstatus: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemCallResult", "GetCallStatus", 6usize, |this| ((**(this as *const *const IWbemCallResult_Vtbl)).GetCallStatus)(this, ltimeout, plstatus), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetResultObject,
+            GetResultString,
+            GetResultServices,
+            GetCallStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemCallResult as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWbemClassObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetQualifierSet(this: *mut core::ffi::c_void, ppqualset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemClassObject", "GetQualifierSet", 3usize, |this| ((**(this as *const *const IWbemClassObject_Vtbl)).GetQualifierSet)(this, ppqualset), |result| Some(*result))
+        }
+        unsafe extern "system" fn Get(this: *mut core::ffi::c_void, wszname: windows_core::PCWSTR, lflags: i32, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>, ptype: *mut i32, plflavor: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemClassObject", "Get", 4usize, |this| ((**(this as *const *const IWbemClassObject_Vtbl)).Get)(this, wszname, lflags, pval, ptype, plflavor), |result| Some(*result))
+        }
+        unsafe extern "system" fn Put(this: *mut core::ffi::c_void, wszname: windows_core::PCWSTR, lflags: i32, pval: *const core::mem::MaybeUninit<windows_core::VARIANT>, r#type: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemClassObject", "Put", 5usize, |this| ((**(this as *const *const IWbemClassObject_Vtbl)).Put)(this, wszname, lflags, pval, r#type), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, wszname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemClassObject", "Delete", 6usize, |this| ((**(this as *const *const IWbemClassObject_Vtbl)).Delete)(this, wszname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetNames(this: *mut core::ffi::c_void, wszqualifiername: windows_core::PCWSTR, lflags: WBEM_CONDITION_FLAG_TYPE, pqualifierval: *const core::mem::MaybeUninit<windows_core::VARIANT>, pnames: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemClassObject", "GetNames", 7usize, |this| ((**(this as *const *const IWbemClassObject_Vtbl)).GetNames)(this, wszqualifiername, lflags, pqualifierval, pnames), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginEnumeration(this: *mut core::ffi::c_void, lenumflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemClassObject", "BeginEnumeration", 8usize, |this| ((**(this as *const *const IWbemClassObject_Vtbl)).BeginEnumeration)(this, lenumflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, lflags: i32, strname: *mut core::mem::MaybeUninit<windows_core::BSTR>, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>, ptype: *mut i32, plflavor: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemClassObject", "Next", 9usize, |this| ((**(this as *const *const IWbemClassObject_Vtbl)).Next)(this, lflags, strname, pval, ptype, plflavor), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndEnumeration(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemClassObject", "EndEnumeration", 10usize, |this| ((**(this as *const *const IWbemClassObject_Vtbl)).EndEnumeration)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyQualifierSet(this: *mut core::ffi::c_void, wszproperty: windows_core::PCWSTR, ppqualset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemClassObject", "GetPropertyQualifierSet", 11usize, |this| ((**(this as *const *const IWbemClassObject_Vtbl)).GetPropertyQualifierSet)(this, wszproperty, ppqualset), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppcopy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemClassObject", "Clone", 12usize, |this| ((**(this as *const *const IWbemClassObject_Vtbl)).Clone)(this, ppcopy), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObjectText(this: *mut core::ffi::c_void, lflags: i32, pstrobjecttext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemClassObject", "GetObjectText", 13usize, |this| ((**(this as *const *const IWbemClassObject_Vtbl)).GetObjectText)(this, lflags, pstrobjecttext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SpawnDerivedClass(this: *mut core::ffi::c_void, lflags: i32, ppnewclass: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemClassObject", "SpawnDerivedClass", 14usize, |this| ((**(this as *const *const IWbemClassObject_Vtbl)).SpawnDerivedClass)(this, lflags, ppnewclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn SpawnInstance(this: *mut core::ffi::c_void, lflags: i32, ppnewinstance: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemClassObject", "SpawnInstance", 15usize, |this| ((**(this as *const *const IWbemClassObject_Vtbl)).SpawnInstance)(this, lflags, ppnewinstance), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompareTo(this: *mut core::ffi::c_void, lflags: WBEM_COMPARISON_FLAG, pcompareto: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemClassObject", "CompareTo", 16usize, |this| ((**(this as *const *const IWbemClassObject_Vtbl)).CompareTo)(this, lflags, pcompareto), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyOrigin(this: *mut core::ffi::c_void, wszname: windows_core::PCWSTR, pstrclassname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemClassObject", "GetPropertyOrigin", 17usize, |this| ((**(this as *const *const IWbemClassObject_Vtbl)).GetPropertyOrigin)(this, wszname, pstrclassname), |result| Some(*result))
+        }
+        unsafe extern "system" fn InheritsFrom(this: *mut core::ffi::c_void, strancestor: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemClassObject", "InheritsFrom", 18usize, |this| ((**(this as *const *const IWbemClassObject_Vtbl)).InheritsFrom)(this, strancestor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMethod(this: *mut core::ffi::c_void, wszname: windows_core::PCWSTR, lflags: i32, ppinsignature: *mut *mut core::ffi::c_void, ppoutsignature: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemClassObject", "GetMethod", 19usize, |this| ((**(this as *const *const IWbemClassObject_Vtbl)).GetMethod)(this, wszname, lflags, ppinsignature, ppoutsignature), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutMethod(this: *mut core::ffi::c_void, wszname: windows_core::PCWSTR, lflags: i32, pinsignature: *mut core::ffi::c_void, poutsignature: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemClassObject", "PutMethod", 20usize, |this| ((**(this as *const *const IWbemClassObject_Vtbl)).PutMethod)(this, wszname, lflags, pinsignature, poutsignature), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteMethod(this: *mut core::ffi::c_void, wszname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemClassObject", "DeleteMethod", 21usize, |this| ((**(this as *const *const IWbemClassObject_Vtbl)).DeleteMethod)(this, wszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginMethodEnumeration(this: *mut core::ffi::c_void, lenumflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemClassObject", "BeginMethodEnumeration", 22usize, |this| ((**(this as *const *const IWbemClassObject_Vtbl)).BeginMethodEnumeration)(this, lenumflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn NextMethod(this: *mut core::ffi::c_void, lflags: i32, pstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>, ppinsignature: *mut *mut core::ffi::c_void, ppoutsignature: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemClassObject", "NextMethod", 23usize, |this| ((**(this as *const *const IWbemClassObject_Vtbl)).NextMethod)(this, lflags, pstrname, ppinsignature, ppoutsignature), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndMethodEnumeration(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemClassObject", "EndMethodEnumeration", 24usize, |this| ((**(this as *const *const IWbemClassObject_Vtbl)).EndMethodEnumeration)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMethodQualifierSet(this: *mut core::ffi::c_void, wszmethod: windows_core::PCWSTR, ppqualset: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemClassObject", "GetMethodQualifierSet", 25usize, |this| ((**(this as *const *const IWbemClassObject_Vtbl)).GetMethodQualifierSet)(this, wszmethod, ppqualset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMethodOrigin(this: *mut core::ffi::c_void, wszmethodname: windows_core::PCWSTR, pstrclassname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemClassObject", "GetMethodOrigin", 26usize, |this| ((**(this as *const *const IWbemClassObject_Vtbl)).GetMethodOrigin)(this, wszmethodname, pstrclassname), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetQualifierSet,
+            Get,
+            Put,
+            Delete,
+            #[cfg(feature = "Win32_System_Com")]
+            GetNames,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetNames: 0,
+            BeginEnumeration,
+            Next,
+            EndEnumeration,
+            GetPropertyQualifierSet,
+            Clone,
+            GetObjectText,
+            SpawnDerivedClass,
+            SpawnInstance,
+            CompareTo,
+            GetPropertyOrigin,
+            InheritsFrom,
+            GetMethod,
+            PutMethod,
+            DeleteMethod,
+            BeginMethodEnumeration,
+            NextMethod,
+            EndMethodEnumeration,
+            GetMethodQualifierSet,
+            GetMethodOrigin,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemClassObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemClientConnectionTransport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, straddresstype: core::mem::MaybeUninit<windows_core::BSTR>, dwbinaryaddresslength: u32, abbinaryaddress: *const u8, strobject: core::mem::MaybeUninit<windows_core::BSTR>, struser: core::mem::MaybeUninit<windows_core::BSTR>, strpassword: core::mem::MaybeUninit<windows_core::BSTR>, strlocale: core::mem::MaybeUninit<windows_core::BSTR>, lflags: i32, pctx: *mut core::ffi::c_void, riid: *const windows_core::GUID, pinterface: *mut *mut core::ffi::c_void, pcallres: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemClientConnectionTransport", "Open", 3usize, |this| ((**(this as *const *const IWbemClientConnectionTransport_Vtbl)).Open)(this, straddresstype, dwbinaryaddresslength, abbinaryaddress, strobject, struser, strpassword, strlocale, lflags, pctx, riid, pinterface, pcallres), |result| Some(*result))
+        }
+        unsafe extern "system" fn OpenAsync(this: *mut core::ffi::c_void, straddresstype: core::mem::MaybeUninit<windows_core::BSTR>, dwbinaryaddresslength: u32, abbinaryaddress: *const u8, strobject: core::mem::MaybeUninit<windows_core::BSTR>, struser: core::mem::MaybeUninit<windows_core::BSTR>, strpassword: core::mem::MaybeUninit<windows_core::BSTR>, strlocale: core::mem::MaybeUninit<windows_core::BSTR>, lflags: i32, pctx: *mut core::ffi::c_void, riid: *const windows_core::GUID, presponsehandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemClientConnectionTransport", "OpenAsync", 4usize, |this| ((**(this as *const *const IWbemClientConnectionTransport_Vtbl)).OpenAsync)(this, straddresstype, dwbinaryaddresslength, abbinaryaddress, strobject, struser, strpassword, strlocale, lflags, pctx, riid, presponsehandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void, lflags: i32, phandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemClientConnectionTransport", "Cancel", 5usize, |this| ((**(this as *const *const IWbemClientConnectionTransport_Vtbl)).Cancel)(this, lflags, phandler), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Open, OpenAsync, Cancel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemClientConnectionTransport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemClientTransport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConnectServer(this: *mut core::ffi::c_void, straddresstype: core::mem::MaybeUninit<windows_core::BSTR>, dwbinaryaddresslength: u32, abbinaryaddress: *const u8, strnetworkresource: core::mem::MaybeUninit<windows_core::BSTR>, struser: core::mem::MaybeUninit<windows_core::BSTR>, strpassword: core::mem::MaybeUninit<windows_core::BSTR>, strlocale: core::mem::MaybeUninit<windows_core::BSTR>, lsecurityflags: i32, strauthority: core::mem::MaybeUninit<windows_core::BSTR>, pctx: *mut core::ffi::c_void, ppnamespace: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemClientTransport", "ConnectServer", 3usize, |this| ((**(this as *const *const IWbemClientTransport_Vtbl)).ConnectServer)(this, straddresstype, dwbinaryaddresslength, abbinaryaddress, strnetworkresource, struser, strpassword, strlocale, lsecurityflags, strauthority, pctx, ppnamespace), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ConnectServer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemClientTransport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemConfigureRefresher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddObjectByPath(this: *mut core::ffi::c_void, pnamespace: *mut core::ffi::c_void, wszpath: windows_core::PCWSTR, lflags: i32, pcontext: *mut core::ffi::c_void, pprefreshable: *mut *mut core::ffi::c_void, plid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemConfigureRefresher", "AddObjectByPath", 3usize, |this| ((**(this as *const *const IWbemConfigureRefresher_Vtbl)).AddObjectByPath)(this, pnamespace, wszpath, lflags, pcontext, pprefreshable, plid), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddObjectByTemplate(this: *mut core::ffi::c_void, pnamespace: *mut core::ffi::c_void, ptemplate: *mut core::ffi::c_void, lflags: i32, pcontext: *mut core::ffi::c_void, pprefreshable: *mut *mut core::ffi::c_void, plid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemConfigureRefresher", "AddObjectByTemplate", 4usize, |this| ((**(this as *const *const IWbemConfigureRefresher_Vtbl)).AddObjectByTemplate)(this, pnamespace, ptemplate, lflags, pcontext, pprefreshable, plid), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddRefresher(this: *mut core::ffi::c_void, prefresher: *mut core::ffi::c_void, lflags: i32, plid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemConfigureRefresher", "AddRefresher", 5usize, |this| ((**(this as *const *const IWbemConfigureRefresher_Vtbl)).AddRefresher)(this, prefresher, lflags, plid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, lid: i32, lflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemConfigureRefresher", "Remove", 6usize, |this| ((**(this as *const *const IWbemConfigureRefresher_Vtbl)).Remove)(this, lid, lflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddEnum(this: *mut core::ffi::c_void, pnamespace: *mut core::ffi::c_void, wszclassname: windows_core::PCWSTR, lflags: i32, pcontext: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void, plid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemConfigureRefresher", "AddEnum", 7usize, |this| ((**(this as *const *const IWbemConfigureRefresher_Vtbl)).AddEnum)(this, pnamespace, wszclassname, lflags, pcontext, ppenum, plid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddObjectByPath,
+            AddObjectByTemplate,
+            AddRefresher,
+            Remove,
+            AddEnum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemConfigureRefresher as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemConnectorLogin_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConnectorLogin(this: *mut core::ffi::c_void, wsznetworkresource: windows_core::PCWSTR, wszpreferredlocale: windows_core::PCWSTR, lflags: i32, pctx: *mut core::ffi::c_void, riid: *const windows_core::GUID, pinterface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemConnectorLogin", "ConnectorLogin", 3usize, |this| ((**(this as *const *const IWbemConnectorLogin_Vtbl)).ConnectorLogin)(this, wsznetworkresource, wszpreferredlocale, lflags, pctx, riid, pinterface), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ConnectorLogin }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemConnectorLogin as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemConstructClassObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetInheritanceChain(this: *mut core::ffi::c_void, lnumantecedents: i32, awszantecedents: *const windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemConstructClassObject", "SetInheritanceChain", 3usize, |this| ((**(this as *const *const IWbemConstructClassObject_Vtbl)).SetInheritanceChain)(this, lnumantecedents, awszantecedents), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPropertyOrigin(this: *mut core::ffi::c_void, wszpropertyname: windows_core::PCWSTR, loriginindex: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemConstructClassObject", "SetPropertyOrigin", 4usize, |this| ((**(this as *const *const IWbemConstructClassObject_Vtbl)).SetPropertyOrigin)(this, wszpropertyname, loriginindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMethodOrigin(this: *mut core::ffi::c_void, wszmethodname: windows_core::PCWSTR, loriginindex: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemConstructClassObject", "SetMethodOrigin", 5usize, |this| ((**(this as *const *const IWbemConstructClassObject_Vtbl)).SetMethodOrigin)(this, wszmethodname, loriginindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServerNamespace(this: *mut core::ffi::c_void, wszserver: windows_core::PCWSTR, wsznamespace: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemConstructClassObject", "SetServerNamespace", 6usize, |this| ((**(this as *const *const IWbemConstructClassObject_Vtbl)).SetServerNamespace)(this, wszserver, wsznamespace), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetInheritanceChain,
+            SetPropertyOrigin,
+            SetMethodOrigin,
+            SetServerNamespace,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemConstructClassObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWbemContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppnewcopy: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemContext", "Clone", 3usize, |this| ((**(this as *const *const IWbemContext_Vtbl)).Clone)(this, ppnewcopy), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetNames(this: *mut core::ffi::c_void, lflags: i32, pnames: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemContext", "GetNames", 4usize, |this| ((**(this as *const *const IWbemContext_Vtbl)).GetNames)(this, lflags, pnames), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginEnumeration(this: *mut core::ffi::c_void, lflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemContext", "BeginEnumeration", 5usize, |this| ((**(this as *const *const IWbemContext_Vtbl)).BeginEnumeration)(this, lflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, lflags: i32, pstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>, pvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemContext", "Next", 6usize, |this| ((**(this as *const *const IWbemContext_Vtbl)).Next)(this, lflags, pstrname, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndEnumeration(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemContext", "EndEnumeration", 7usize, |this| ((**(this as *const *const IWbemContext_Vtbl)).EndEnumeration)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, wszname: windows_core::PCWSTR, lflags: i32, pvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemContext", "SetValue", 8usize, |this| ((**(this as *const *const IWbemContext_Vtbl)).SetValue)(this, wszname, lflags, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, wszname: windows_core::PCWSTR, lflags: i32, pvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemContext", "GetValue", 9usize, |this| ((**(this as *const *const IWbemContext_Vtbl)).GetValue)(this, wszname, lflags, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteValue(this: *mut core::ffi::c_void, wszname: windows_core::PCWSTR, lflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemContext", "DeleteValue", 10usize, |this| ((**(this as *const *const IWbemContext_Vtbl)).DeleteValue)(this, wszname, lflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteAll(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemContext", "DeleteAll", 11usize, |this| ((**(this as *const *const IWbemContext_Vtbl)).DeleteAll)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Clone,
+            #[cfg(feature = "Win32_System_Com")]
+            GetNames,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetNames: 0,
+            BeginEnumeration,
+            Next,
+            EndEnumeration,
+            SetValue,
+            GetValue,
+            DeleteValue,
+            DeleteAll,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemContext as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemDecoupledBasicEventProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSink(this: *mut core::ffi::c_void, a_flags: i32, a_context: *mut core::ffi::c_void, a_sink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemDecoupledBasicEventProvider", "GetSink", 5usize, |this| ((**(this as *const *const IWbemDecoupledBasicEventProvider_Vtbl)).GetSink)(this, a_flags, a_context, a_sink), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetService(this: *mut core::ffi::c_void, a_flags: i32, a_context: *mut core::ffi::c_void, a_service: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemDecoupledBasicEventProvider", "GetService", 6usize, |this| ((**(this as *const *const IWbemDecoupledBasicEventProvider_Vtbl)).GetService)(this, a_flags, a_context, a_service), |result| Some(*result))
+        }
+        Self { base__: <IWbemDecoupledRegistrar_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSink, GetService }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemDecoupledBasicEventProvider as windows_core::Interface>::IID || <IWbemDecoupledRegistrar_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemDecoupledRegistrar_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Register(this: *mut core::ffi::c_void, a_flags: i32, a_context: *mut core::ffi::c_void, a_user: windows_core::PCWSTR, a_locale: windows_core::PCWSTR, a_scope: windows_core::PCWSTR, a_registration: windows_core::PCWSTR, piunknown: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemDecoupledRegistrar", "Register", 3usize, |this| ((**(this as *const *const IWbemDecoupledRegistrar_Vtbl)).Register)(this, a_flags, a_context, a_user, a_locale, a_scope, a_registration, piunknown), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnRegister(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemDecoupledRegistrar", "UnRegister", 4usize, |this| ((**(this as *const *const IWbemDecoupledRegistrar_Vtbl)).UnRegister)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Register, UnRegister }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemDecoupledRegistrar as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemEventConsumerProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FindConsumer(this: *mut core::ffi::c_void, plogicalconsumer: *mut core::ffi::c_void, ppconsumer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemEventConsumerProvider", "FindConsumer", 3usize, |this| ((**(this as *const *const IWbemEventConsumerProvider_Vtbl)).FindConsumer)(this, plogicalconsumer, ppconsumer), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FindConsumer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemEventConsumerProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemEventProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProvideEvents(this: *mut core::ffi::c_void, psink: *mut core::ffi::c_void, lflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemEventProvider", "ProvideEvents", 3usize, |this| ((**(this as *const *const IWbemEventProvider_Vtbl)).ProvideEvents)(this, psink, lflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ProvideEvents }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemEventProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemEventProviderQuerySink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NewQuery(this: *mut core::ffi::c_void, dwid: u32, wszquerylanguage: *const u16, wszquery: *const u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemEventProviderQuerySink", "NewQuery", 3usize, |this| ((**(this as *const *const IWbemEventProviderQuerySink_Vtbl)).NewQuery)(this, dwid, wszquerylanguage, wszquery), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelQuery(this: *mut core::ffi::c_void, dwid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemEventProviderQuerySink", "CancelQuery", 4usize, |this| ((**(this as *const *const IWbemEventProviderQuerySink_Vtbl)).CancelQuery)(this, dwid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NewQuery, CancelQuery }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemEventProviderQuerySink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemEventProviderSecurity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AccessCheck(this: *mut core::ffi::c_void, wszquerylanguage: *const u16, wszquery: *const u16, lsidlength: i32, psid: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemEventProviderSecurity", "AccessCheck", 3usize, |this| ((**(this as *const *const IWbemEventProviderSecurity_Vtbl)).AccessCheck)(this, wszquerylanguage, wszquery, lsidlength, psid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AccessCheck }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemEventProviderSecurity as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemEventSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSinkSecurity(this: *mut core::ffi::c_void, lsdlength: i32, psd: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemEventSink", "SetSinkSecurity", 5usize, |this| ((**(this as *const *const IWbemEventSink_Vtbl)).SetSinkSecurity)(this, lsdlength, psd), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsActive(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemEventSink", "IsActive", 6usize, |this| ((**(this as *const *const IWbemEventSink_Vtbl)).IsActive)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRestrictedSink(this: *mut core::ffi::c_void, lnumqueries: i32, awszqueries: *const windows_core::PCWSTR, pcallback: *mut core::ffi::c_void, ppsink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemEventSink", "GetRestrictedSink", 7usize, |this| ((**(this as *const *const IWbemEventSink_Vtbl)).GetRestrictedSink)(this, lnumqueries, awszqueries, pcallback, ppsink), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBatchingParameters(this: *mut core::ffi::c_void, lflags: i32, dwmaxbuffersize: u32, dwmaxsendlatency: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemEventSink", "SetBatchingParameters", 8usize, |this| ((**(this as *const *const IWbemEventSink_Vtbl)).SetBatchingParameters)(this, lflags, dwmaxbuffersize, dwmaxsendlatency), |result| Some(*result))
+        }
+        Self { base__: <IWbemObjectSink_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetSinkSecurity, IsActive, GetRestrictedSink, SetBatchingParameters }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemEventSink as windows_core::Interface>::IID || <IWbemObjectSink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemHiPerfEnum_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddObjects(this: *mut core::ffi::c_void, lflags: i32, unumobjects: u32, apids: *const i32, apobj: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemHiPerfEnum", "AddObjects", 3usize, |this| ((**(this as *const *const IWbemHiPerfEnum_Vtbl)).AddObjects)(this, lflags, unumobjects, apids, apobj), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveObjects(this: *mut core::ffi::c_void, lflags: i32, unumobjects: u32, apids: *const i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemHiPerfEnum", "RemoveObjects", 4usize, |this| ((**(this as *const *const IWbemHiPerfEnum_Vtbl)).RemoveObjects)(this, lflags, unumobjects, apids), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObjects(this: *mut core::ffi::c_void, lflags: i32, unumobjects: u32, apobj: *mut *mut core::ffi::c_void, pureturned: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemHiPerfEnum", "GetObjects", 5usize, |this| ((**(this as *const *const IWbemHiPerfEnum_Vtbl)).GetObjects)(this, lflags, unumobjects, apobj, pureturned), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAll(this: *mut core::ffi::c_void, lflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemHiPerfEnum", "RemoveAll", 6usize, |this| ((**(this as *const *const IWbemHiPerfEnum_Vtbl)).RemoveAll)(this, lflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddObjects, RemoveObjects, GetObjects, RemoveAll }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemHiPerfEnum as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemHiPerfProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryInstances(this: *mut core::ffi::c_void, pnamespace: *mut core::ffi::c_void, wszclass: windows_core::PCWSTR, lflags: i32, pctx: *mut core::ffi::c_void, psink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemHiPerfProvider", "QueryInstances", 3usize, |this| ((**(this as *const *const IWbemHiPerfProvider_Vtbl)).QueryInstances)(this, pnamespace, wszclass, lflags, pctx, psink), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRefresher(this: *mut core::ffi::c_void, pnamespace: *mut core::ffi::c_void, lflags: i32, pprefresher: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemHiPerfProvider", "CreateRefresher", 4usize, |this| ((**(this as *const *const IWbemHiPerfProvider_Vtbl)).CreateRefresher)(this, pnamespace, lflags, pprefresher), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRefreshableObject(this: *mut core::ffi::c_void, pnamespace: *mut core::ffi::c_void, ptemplate: *mut core::ffi::c_void, prefresher: *mut core::ffi::c_void, lflags: i32, pcontext: *mut core::ffi::c_void, pprefreshable: *mut *mut core::ffi::c_void, plid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemHiPerfProvider", "CreateRefreshableObject", 5usize, |this| ((**(this as *const *const IWbemHiPerfProvider_Vtbl)).CreateRefreshableObject)(this, pnamespace, ptemplate, prefresher, lflags, pcontext, pprefreshable, plid), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopRefreshing(this: *mut core::ffi::c_void, prefresher: *mut core::ffi::c_void, lid: i32, lflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemHiPerfProvider", "StopRefreshing", 6usize, |this| ((**(this as *const *const IWbemHiPerfProvider_Vtbl)).StopRefreshing)(this, prefresher, lid, lflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRefreshableEnum(this: *mut core::ffi::c_void, pnamespace: *mut core::ffi::c_void, wszclass: windows_core::PCWSTR, prefresher: *mut core::ffi::c_void, lflags: i32, pcontext: *mut core::ffi::c_void, phiperfenum: *mut core::ffi::c_void, plid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemHiPerfProvider", "CreateRefreshableEnum", 7usize, |this| ((**(this as *const *const IWbemHiPerfProvider_Vtbl)).CreateRefreshableEnum)(this, pnamespace, wszclass, prefresher, lflags, pcontext, phiperfenum, plid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObjects(this: *mut core::ffi::c_void, pnamespace: *mut core::ffi::c_void, lnumobjects: i32, apobj: *mut *mut core::ffi::c_void, lflags: i32, pcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemHiPerfProvider", "GetObjects", 8usize, |this| ((**(this as *const *const IWbemHiPerfProvider_Vtbl)).GetObjects)(this, pnamespace, lnumobjects, apobj, lflags, pcontext), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            QueryInstances,
+            CreateRefresher,
+            CreateRefreshableObject,
+            StopRefreshing,
+            CreateRefreshableEnum,
+            GetObjects,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemHiPerfProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemLevel1Login_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EstablishPosition(this: *mut core::ffi::c_void, wszlocalelist: windows_core::PCWSTR, dwnumlocales: u32, reserved: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemLevel1Login", "EstablishPosition", 3usize, |this| ((**(this as *const *const IWbemLevel1Login_Vtbl)).EstablishPosition)(this, wszlocalelist, dwnumlocales, reserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestChallenge(this: *mut core::ffi::c_void, wsznetworkresource: windows_core::PCWSTR, wszuser: windows_core::PCWSTR, nonce: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemLevel1Login", "RequestChallenge", 4usize, |this| ((**(this as *const *const IWbemLevel1Login_Vtbl)).RequestChallenge)(this, wsznetworkresource, wszuser, nonce), |result| Some(*result))
+        }
+        unsafe extern "system" fn WBEMLogin(this: *mut core::ffi::c_void, wszpreferredlocale: windows_core::PCWSTR, accesstoken: *const u8, lflags: i32, pctx: *mut core::ffi::c_void, ppnamespace: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemLevel1Login", "WBEMLogin", 5usize, |this| ((**(this as *const *const IWbemLevel1Login_Vtbl)).WBEMLogin)(this, wszpreferredlocale, accesstoken, lflags, pctx, ppnamespace), |result| Some(*result))
+        }
+        unsafe extern "system" fn NTLMLogin(this: *mut core::ffi::c_void, wsznetworkresource: windows_core::PCWSTR, wszpreferredlocale: windows_core::PCWSTR, lflags: i32, pctx: *mut core::ffi::c_void, ppnamespace: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemLevel1Login", "NTLMLogin", 6usize, |this| ((**(this as *const *const IWbemLevel1Login_Vtbl)).NTLMLogin)(this, wsznetworkresource, wszpreferredlocale, lflags, pctx, ppnamespace), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EstablishPosition, RequestChallenge, WBEMLogin, NTLMLogin }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemLevel1Login as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemLocator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConnectServer(this: *mut core::ffi::c_void, strnetworkresource: core::mem::MaybeUninit<windows_core::BSTR>, struser: core::mem::MaybeUninit<windows_core::BSTR>, strpassword: core::mem::MaybeUninit<windows_core::BSTR>, strlocale: core::mem::MaybeUninit<windows_core::BSTR>, lsecurityflags: i32, strauthority: core::mem::MaybeUninit<windows_core::BSTR>, pctx: *mut core::ffi::c_void, ppnamespace: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemLocator", "ConnectServer", 3usize, |this| ((**(this as *const *const IWbemLocator_Vtbl)).ConnectServer)(this, strnetworkresource, struser, strpassword, strlocale, lsecurityflags, strauthority, pctx, ppnamespace), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ConnectServer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemLocator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWbemObjectAccess_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPropertyHandle(this: *mut core::ffi::c_void, wszpropertyname: windows_core::PCWSTR, ptype: *mut i32, plhandle: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemObjectAccess", "GetPropertyHandle", 27usize, |this| ((**(this as *const *const IWbemObjectAccess_Vtbl)).GetPropertyHandle)(this, wszpropertyname, ptype, plhandle), |result| Some(*result))
+        }
+        unsafe extern "system" fn WritePropertyValue(this: *mut core::ffi::c_void, lhandle: i32, lnumbytes: i32, adata: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemObjectAccess", "WritePropertyValue", 28usize, |this| ((**(this as *const *const IWbemObjectAccess_Vtbl)).WritePropertyValue)(this, lhandle, lnumbytes, adata), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadPropertyValue(this: *mut core::ffi::c_void, lhandle: i32, lbuffersize: i32, plnumbytes: *mut i32, adata: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemObjectAccess", "ReadPropertyValue", 29usize, |this| ((**(this as *const *const IWbemObjectAccess_Vtbl)).ReadPropertyValue)(this, lhandle, lbuffersize, plnumbytes, adata), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadDWORD(this: *mut core::ffi::c_void, lhandle: i32, pdw: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemObjectAccess", "ReadDWORD", 30usize, |this| ((**(this as *const *const IWbemObjectAccess_Vtbl)).ReadDWORD)(this, lhandle, pdw), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteDWORD(this: *mut core::ffi::c_void, lhandle: i32, dw: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemObjectAccess", "WriteDWORD", 31usize, |this| ((**(this as *const *const IWbemObjectAccess_Vtbl)).WriteDWORD)(this, lhandle, dw), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReadQWORD(this: *mut core::ffi::c_void, lhandle: i32, pqw: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemObjectAccess", "ReadQWORD", 32usize, |this| ((**(this as *const *const IWbemObjectAccess_Vtbl)).ReadQWORD)(this, lhandle, pqw), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteQWORD(this: *mut core::ffi::c_void, lhandle: i32, pw: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemObjectAccess", "WriteQWORD", 33usize, |this| ((**(this as *const *const IWbemObjectAccess_Vtbl)).WriteQWORD)(this, lhandle, pw), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyInfoByHandle(this: *mut core::ffi::c_void, lhandle: i32, pstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>, ptype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemObjectAccess", "GetPropertyInfoByHandle", 34usize, |this| ((**(this as *const *const IWbemObjectAccess_Vtbl)).GetPropertyInfoByHandle)(this, lhandle, pstrname, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Lock(this: *mut core::ffi::c_void, lflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemObjectAccess", "Lock", 35usize, |this| ((**(this as *const *const IWbemObjectAccess_Vtbl)).Lock)(this, lflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unlock(this: *mut core::ffi::c_void, lflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemObjectAccess", "Unlock", 36usize, |this| ((**(this as *const *const IWbemObjectAccess_Vtbl)).Unlock)(this, lflags), |result| Some(*result))
+        }
+        Self {
+            base__: <IWbemClassObject_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPropertyHandle,
+            WritePropertyValue,
+            ReadPropertyValue,
+            ReadDWORD,
+            WriteDWORD,
+            ReadQWORD,
+            WriteQWORD,
+            GetPropertyInfoByHandle,
+            Lock,
+            Unlock,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemObjectAccess as windows_core::Interface>::IID || <IWbemClassObject_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemObjectSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Indicate(this: *mut core::ffi::c_void, lobjectcount: i32, apobjarray: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemObjectSink", "Indicate", 3usize, |this| ((**(this as *const *const IWbemObjectSink_Vtbl)).Indicate)(this, lobjectcount, apobjarray), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatus(this: *mut core::ffi::c_void, lflags: i32, hresult: windows_core::HRESULT, strparam: core::mem::MaybeUninit<windows_core::BSTR>, pobjparam: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemObjectSink", "SetStatus", 4usize, |this| ((**(this as *const *const IWbemObjectSink_Vtbl)).SetStatus)(this, lflags, hresult, strparam, pobjparam), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Indicate, SetStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemObjectSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemObjectSinkEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WriteMessage(this: *mut core::ffi::c_void, uchannel: u32, strmessage: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemObjectSinkEx", "WriteMessage", 5usize, |this| ((**(this as *const *const IWbemObjectSinkEx_Vtbl)).WriteMessage)(this, uchannel, strmessage), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteError(this: *mut core::ffi::c_void, pobjerror: *mut core::ffi::c_void, pureturned: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemObjectSinkEx", "WriteError", 6usize, |this| ((**(this as *const *const IWbemObjectSinkEx_Vtbl)).WriteError)(this, pobjerror, pureturned), |result| Some(*result))
+        }
+        unsafe extern "system" fn PromptUser(this: *mut core::ffi::c_void, strmessage: core::mem::MaybeUninit<windows_core::BSTR>, uprompttype: u8, pureturned: *mut u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemObjectSinkEx", "PromptUser", 7usize, |this| ((**(this as *const *const IWbemObjectSinkEx_Vtbl)).PromptUser)(this, strmessage, uprompttype, pureturned), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteProgress(this: *mut core::ffi::c_void, stractivity: core::mem::MaybeUninit<windows_core::BSTR>, strcurrentoperation: core::mem::MaybeUninit<windows_core::BSTR>, strstatusdescription: core::mem::MaybeUninit<windows_core::BSTR>, upercentcomplete: u32, usecondsremaining: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemObjectSinkEx", "WriteProgress", 8usize, |this| ((**(this as *const *const IWbemObjectSinkEx_Vtbl)).WriteProgress)(this, stractivity, strcurrentoperation, strstatusdescription, upercentcomplete, usecondsremaining), |result| Some(*result))
+        }
+        unsafe extern "system" fn WriteStreamParameter(this: *mut core::ffi::c_void, strname: core::mem::MaybeUninit<windows_core::BSTR>, vtvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>, ultype: u32, ulflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemObjectSinkEx", "WriteStreamParameter", 9usize, |this| ((**(this as *const *const IWbemObjectSinkEx_Vtbl)).WriteStreamParameter)(this, strname, vtvalue, ultype, ulflags), |result| Some(*result))
+        }
+        Self {
+            base__: <IWbemObjectSink_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            WriteMessage,
+            WriteError,
+            PromptUser,
+            WriteProgress,
+            WriteStreamParameter,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemObjectSinkEx as windows_core::Interface>::IID || <IWbemObjectSink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemObjectTextSrc_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetText(this: *mut core::ffi::c_void, lflags: i32, pobj: *mut core::ffi::c_void, uobjtextformat: u32, pctx: *mut core::ffi::c_void, strtext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemObjectTextSrc", "GetText", 3usize, |this| ((**(this as *const *const IWbemObjectTextSrc_Vtbl)).GetText)(this, lflags, pobj, uobjtextformat, pctx, strtext), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFromText(this: *mut core::ffi::c_void, lflags: i32, strtext: core::mem::MaybeUninit<windows_core::BSTR>, uobjtextformat: u32, pctx: *mut core::ffi::c_void, pnewobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemObjectTextSrc", "CreateFromText", 4usize, |this| ((**(this as *const *const IWbemObjectTextSrc_Vtbl)).CreateFromText)(this, lflags, strtext, uobjtextformat, pctx, pnewobj), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetText, CreateFromText }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemObjectTextSrc as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemPath_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetText(this: *mut core::ffi::c_void, umode: u32, pszpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPath", "SetText", 3usize, |this| ((**(this as *const *const IWbemPath_Vtbl)).SetText)(this, umode, pszpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetText(this: *mut core::ffi::c_void, lflags: i32, pubufflength: *mut u32, psztext: windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPath", "GetText", 4usize, |this| ((**(this as *const *const IWbemPath_Vtbl)).GetText)(this, lflags, pubufflength, psztext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInfo(this: *mut core::ffi::c_void, urequestedinfo: u32, puresponse: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPath", "GetInfo", 5usize, |this| ((**(this as *const *const IWbemPath_Vtbl)).GetInfo)(this, urequestedinfo, puresponse), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetServer(this: *mut core::ffi::c_void, name: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPath", "SetServer", 6usize, |this| ((**(this as *const *const IWbemPath_Vtbl)).SetServer)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServer(this: *mut core::ffi::c_void, punamebuflength: *mut u32, pname: windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPath", "GetServer", 7usize, |this| ((**(this as *const *const IWbemPath_Vtbl)).GetServer)(this, punamebuflength, pname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNamespaceCount(this: *mut core::ffi::c_void, pucount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPath", "GetNamespaceCount", 8usize, |this| ((**(this as *const *const IWbemPath_Vtbl)).GetNamespaceCount)(this, pucount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNamespaceAt(this: *mut core::ffi::c_void, uindex: u32, pszname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPath", "SetNamespaceAt", 9usize, |this| ((**(this as *const *const IWbemPath_Vtbl)).SetNamespaceAt)(this, uindex, pszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNamespaceAt(this: *mut core::ffi::c_void, uindex: u32, punamebuflength: *mut u32, pname: windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPath", "GetNamespaceAt", 10usize, |this| ((**(this as *const *const IWbemPath_Vtbl)).GetNamespaceAt)(this, uindex, punamebuflength, pname), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveNamespaceAt(this: *mut core::ffi::c_void, uindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPath", "RemoveNamespaceAt", 11usize, |this| ((**(this as *const *const IWbemPath_Vtbl)).RemoveNamespaceAt)(this, uindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAllNamespaces(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPath", "RemoveAllNamespaces", 12usize, |this| ((**(this as *const *const IWbemPath_Vtbl)).RemoveAllNamespaces)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScopeCount(this: *mut core::ffi::c_void, pucount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPath", "GetScopeCount", 13usize, |this| ((**(this as *const *const IWbemPath_Vtbl)).GetScopeCount)(this, pucount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScope(this: *mut core::ffi::c_void, uindex: u32, pszclass: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPath", "SetScope", 14usize, |this| ((**(this as *const *const IWbemPath_Vtbl)).SetScope)(this, uindex, pszclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScopeFromText(this: *mut core::ffi::c_void, uindex: u32, psztext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPath", "SetScopeFromText", 15usize, |this| ((**(this as *const *const IWbemPath_Vtbl)).SetScopeFromText)(this, uindex, psztext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScope(this: *mut core::ffi::c_void, uindex: u32, puclassnamebufsize: *mut u32, pszclass: windows_core::PWSTR, pkeylist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPath", "GetScope", 16usize, |this| ((**(this as *const *const IWbemPath_Vtbl)).GetScope)(this, uindex, puclassnamebufsize, pszclass, pkeylist), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScopeAsText(this: *mut core::ffi::c_void, uindex: u32, putextbufsize: *mut u32, psztext: windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPath", "GetScopeAsText", 17usize, |this| ((**(this as *const *const IWbemPath_Vtbl)).GetScopeAsText)(this, uindex, putextbufsize, psztext), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveScope(this: *mut core::ffi::c_void, uindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPath", "RemoveScope", 18usize, |this| ((**(this as *const *const IWbemPath_Vtbl)).RemoveScope)(this, uindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAllScopes(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPath", "RemoveAllScopes", 19usize, |this| ((**(this as *const *const IWbemPath_Vtbl)).RemoveAllScopes)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClassName(this: *mut core::ffi::c_void, name: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPath", "SetClassName", 20usize, |this| ((**(this as *const *const IWbemPath_Vtbl)).SetClassName)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClassName(this: *mut core::ffi::c_void, pubufflength: *mut u32, pszname: windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPath", "GetClassName", 21usize, |this| ((**(this as *const *const IWbemPath_Vtbl)).GetClassName)(this, pubufflength, pszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKeyList(this: *mut core::ffi::c_void, pout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPath", "GetKeyList", 22usize, |this| ((**(this as *const *const IWbemPath_Vtbl)).GetKeyList)(this, pout), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateClassPart(this: *mut core::ffi::c_void, lflags: i32, name: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPath", "CreateClassPart", 23usize, |this| ((**(this as *const *const IWbemPath_Vtbl)).CreateClassPart)(this, lflags, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteClassPart(this: *mut core::ffi::c_void, lflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPath", "DeleteClassPart", 24usize, |this| ((**(this as *const *const IWbemPath_Vtbl)).DeleteClassPart)(this, lflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsRelative(this: *mut core::ffi::c_void, wszmachine: windows_core::PCWSTR, wsznamespace: windows_core::PCWSTR) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IWbemPath", "IsRelative", 25usize, |this| ((**(this as *const *const IWbemPath_Vtbl)).IsRelative)(this, wszmachine, wsznamespace), |_| None)
+        }
+        unsafe extern "system" fn IsRelativeOrChild(this: *mut core::ffi::c_void, wszmachine: windows_core::PCWSTR, wsznamespace: windows_core::PCWSTR, lflags: i32) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IWbemPath", "IsRelativeOrChild", 26usize, |this| ((**(this as *const *const IWbemPath_Vtbl)).IsRelativeOrChild)(this, wszmachine, wsznamespace, lflags), |_| None)
+        }
+        unsafe extern "system" fn IsLocal(this: *mut core::ffi::c_void, wszmachine: windows_core::PCWSTR) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IWbemPath", "IsLocal", 27usize, |this| ((**(this as *const *const IWbemPath_Vtbl)).IsLocal)(this, wszmachine), |_| None)
+        }
+        unsafe extern "system" fn IsSameClassName(this: *mut core::ffi::c_void, wszclass: windows_core::PCWSTR) -> super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "IWbemPath", "IsSameClassName", 28usize, |this| ((**(this as *const *const IWbemPath_Vtbl)).IsSameClassName)(this, wszclass), |_| None)
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetText,
+            GetText,
+            GetInfo,
+            SetServer,
+            GetServer,
+            GetNamespaceCount,
+            SetNamespaceAt,
+            GetNamespaceAt,
+            RemoveNamespaceAt,
+            RemoveAllNamespaces,
+            GetScopeCount,
+            SetScope,
+            SetScopeFromText,
+            GetScope,
+            GetScopeAsText,
+            RemoveScope,
+            RemoveAllScopes,
+            SetClassName,
+            GetClassName,
+            GetKeyList,
+            CreateClassPart,
+            DeleteClassPart,
+            IsRelative,
+            IsRelativeOrChild,
+            IsLocal,
+            IsSameClassName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemPath as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemPathKeyList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pukeycount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPathKeyList", "GetCount", 3usize, |this| ((**(this as *const *const IWbemPathKeyList_Vtbl)).GetCount)(this, pukeycount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKey(this: *mut core::ffi::c_void, wszname: windows_core::PCWSTR, uflags: u32, ucimtype: u32, pkeyval: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPathKeyList", "SetKey", 4usize, |this| ((**(this as *const *const IWbemPathKeyList_Vtbl)).SetKey)(this, wszname, uflags, ucimtype, pkeyval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKey2(this: *mut core::ffi::c_void, wszname: windows_core::PCWSTR, uflags: u32, ucimtype: u32, pkeyval: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPathKeyList", "SetKey2", 5usize, |this| ((**(this as *const *const IWbemPathKeyList_Vtbl)).SetKey2)(this, wszname, uflags, ucimtype, pkeyval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKey(this: *mut core::ffi::c_void, ukeyix: u32, uflags: u32, punamebufsize: *mut u32, pszkeyname: windows_core::PWSTR, pukeyvalbufsize: *mut u32, pkeyval: *mut core::ffi::c_void, puapparentcimtype: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPathKeyList", "GetKey", 6usize, |this| ((**(this as *const *const IWbemPathKeyList_Vtbl)).GetKey)(this, ukeyix, uflags, punamebufsize, pszkeyname, pukeyvalbufsize, pkeyval, puapparentcimtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKey2(this: *mut core::ffi::c_void, ukeyix: u32, uflags: u32, punamebufsize: *mut u32, pszkeyname: windows_core::PWSTR, pkeyvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>, puapparentcimtype: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPathKeyList", "GetKey2", 7usize, |this| ((**(this as *const *const IWbemPathKeyList_Vtbl)).GetKey2)(this, ukeyix, uflags, punamebufsize, pszkeyname, pkeyvalue, puapparentcimtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveKey(this: *mut core::ffi::c_void, wszname: windows_core::PCWSTR, uflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPathKeyList", "RemoveKey", 8usize, |this| ((**(this as *const *const IWbemPathKeyList_Vtbl)).RemoveKey)(this, wszname, uflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAllKeys(this: *mut core::ffi::c_void, uflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPathKeyList", "RemoveAllKeys", 9usize, |this| ((**(this as *const *const IWbemPathKeyList_Vtbl)).RemoveAllKeys)(this, uflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn MakeSingleton(this: *mut core::ffi::c_void, bset: u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPathKeyList", "MakeSingleton", 10usize, |this| ((**(this as *const *const IWbemPathKeyList_Vtbl)).MakeSingleton)(this, bset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInfo(this: *mut core::ffi::c_void, urequestedinfo: u32, puresponse: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPathKeyList", "GetInfo", 11usize, |this| ((**(this as *const *const IWbemPathKeyList_Vtbl)).GetInfo)(this, urequestedinfo, puresponse), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetText(this: *mut core::ffi::c_void, lflags: i32, pubufflength: *mut u32, psztext: windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPathKeyList", "GetText", 12usize, |this| ((**(this as *const *const IWbemPathKeyList_Vtbl)).GetText)(this, lflags, pubufflength, psztext), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCount,
+            SetKey,
+            SetKey2,
+            GetKey,
+            GetKey2,
+            RemoveKey,
+            RemoveAllKeys,
+            MakeSingleton,
+            GetInfo,
+            GetText,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemPathKeyList as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemPropertyProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, lflags: i32, strlocale: core::mem::MaybeUninit<windows_core::BSTR>, strclassmapping: core::mem::MaybeUninit<windows_core::BSTR>, strinstmapping: core::mem::MaybeUninit<windows_core::BSTR>, strpropmapping: core::mem::MaybeUninit<windows_core::BSTR>, pvvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPropertyProvider", "GetProperty", 3usize, |this| ((**(this as *const *const IWbemPropertyProvider_Vtbl)).GetProperty)(this, lflags, strlocale, strclassmapping, strinstmapping, strpropmapping, pvvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutProperty(this: *mut core::ffi::c_void, lflags: i32, strlocale: core::mem::MaybeUninit<windows_core::BSTR>, strclassmapping: core::mem::MaybeUninit<windows_core::BSTR>, strinstmapping: core::mem::MaybeUninit<windows_core::BSTR>, strpropmapping: core::mem::MaybeUninit<windows_core::BSTR>, pvvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemPropertyProvider", "PutProperty", 4usize, |this| ((**(this as *const *const IWbemPropertyProvider_Vtbl)).PutProperty)(this, lflags, strlocale, strclassmapping, strinstmapping, strpropmapping, pvvalue), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProperty, PutProperty }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemPropertyProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemProviderIdentity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetRegistrationObject(this: *mut core::ffi::c_void, lflags: i32, pprovreg: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemProviderIdentity", "SetRegistrationObject", 3usize, |this| ((**(this as *const *const IWbemProviderIdentity_Vtbl)).SetRegistrationObject)(this, lflags, pprovreg), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetRegistrationObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemProviderIdentity as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemProviderInit_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, wszuser: windows_core::PCWSTR, lflags: i32, wsznamespace: windows_core::PCWSTR, wszlocale: windows_core::PCWSTR, pnamespace: *mut core::ffi::c_void, pctx: *mut core::ffi::c_void, pinitsink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemProviderInit", "Initialize", 3usize, |this| ((**(this as *const *const IWbemProviderInit_Vtbl)).Initialize)(this, wszuser, lflags, wsznamespace, wszlocale, pnamespace, pctx, pinitsink), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemProviderInit as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemProviderInitSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetStatus(this: *mut core::ffi::c_void, lstatus: i32, lflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemProviderInitSink", "SetStatus", 3usize, |this| ((**(this as *const *const IWbemProviderInitSink_Vtbl)).SetStatus)(this, lstatus, lflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemProviderInitSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IWbemQualifierSet_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Get(this: *mut core::ffi::c_void, wszname: windows_core::PCWSTR, lflags: i32, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>, plflavor: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemQualifierSet", "Get", 3usize, |this| ((**(this as *const *const IWbemQualifierSet_Vtbl)).Get)(this, wszname, lflags, pval, plflavor), |result| Some(*result))
+        }
+        unsafe extern "system" fn Put(this: *mut core::ffi::c_void, wszname: windows_core::PCWSTR, pval: *const core::mem::MaybeUninit<windows_core::VARIANT>, lflavor: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemQualifierSet", "Put", 4usize, |this| ((**(this as *const *const IWbemQualifierSet_Vtbl)).Put)(this, wszname, pval, lflavor), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, wszname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemQualifierSet", "Delete", 5usize, |this| ((**(this as *const *const IWbemQualifierSet_Vtbl)).Delete)(this, wszname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetNames(this: *mut core::ffi::c_void, lflags: i32, pnames: *mut *mut super::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemQualifierSet", "GetNames", 6usize, |this| ((**(this as *const *const IWbemQualifierSet_Vtbl)).GetNames)(this, lflags, pnames), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginEnumeration(this: *mut core::ffi::c_void, lflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemQualifierSet", "BeginEnumeration", 7usize, |this| ((**(this as *const *const IWbemQualifierSet_Vtbl)).BeginEnumeration)(this, lflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, lflags: i32, pstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>, pval: *mut core::mem::MaybeUninit<windows_core::VARIANT>, plflavor: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemQualifierSet", "Next", 8usize, |this| ((**(this as *const *const IWbemQualifierSet_Vtbl)).Next)(this, lflags, pstrname, pval, plflavor), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndEnumeration(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemQualifierSet", "EndEnumeration", 9usize, |this| ((**(this as *const *const IWbemQualifierSet_Vtbl)).EndEnumeration)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Get,
+            Put,
+            Delete,
+            #[cfg(feature = "Win32_System_Com")]
+            GetNames,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetNames: 0,
+            BeginEnumeration,
+            Next,
+            EndEnumeration,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemQualifierSet as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemQuery_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Empty(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemQuery", "Empty", 3usize, |this| ((**(this as *const *const IWbemQuery_Vtbl)).Empty)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLanguageFeatures(this: *mut core::ffi::c_void, uflags: u32, uarraysize: u32, pufeatures: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemQuery", "SetLanguageFeatures", 4usize, |this| ((**(this as *const *const IWbemQuery_Vtbl)).SetLanguageFeatures)(this, uflags, uarraysize, pufeatures), |result| Some(*result))
+        }
+        unsafe extern "system" fn TestLanguageFeatures(this: *mut core::ffi::c_void, uflags: u32, uarraysize: *mut u32, pufeatures: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemQuery", "TestLanguageFeatures", 5usize, |this| ((**(this as *const *const IWbemQuery_Vtbl)).TestLanguageFeatures)(this, uflags, uarraysize, pufeatures), |result| Some(*result))
+        }
+        unsafe extern "system" fn Parse(this: *mut core::ffi::c_void, pszlang: windows_core::PCWSTR, pszquery: windows_core::PCWSTR, uflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemQuery", "Parse", 6usize, |this| ((**(this as *const *const IWbemQuery_Vtbl)).Parse)(this, pszlang, pszquery, uflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAnalysis(this: *mut core::ffi::c_void, uanalysistype: u32, uflags: u32, panalysis: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemQuery", "GetAnalysis", 7usize, |this| ((**(this as *const *const IWbemQuery_Vtbl)).GetAnalysis)(this, uanalysistype, uflags, panalysis), |result| Some(*result))
+        }
+        unsafe extern "system" fn FreeMemory(this: *mut core::ffi::c_void, pmem: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemQuery", "FreeMemory", 8usize, |this| ((**(this as *const *const IWbemQuery_Vtbl)).FreeMemory)(this, pmem), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetQueryInfo(this: *mut core::ffi::c_void, uanalysistype: u32, uinfoid: u32, ubufsize: u32, pdestbuf: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemQuery", "GetQueryInfo", 9usize, |this| ((**(this as *const *const IWbemQuery_Vtbl)).GetQueryInfo)(this, uanalysistype, uinfoid, ubufsize, pdestbuf), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Empty,
+            SetLanguageFeatures,
+            TestLanguageFeatures,
+            Parse,
+            GetAnalysis,
+            FreeMemory,
+            GetQueryInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemQuery as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemRefresher_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void, lflags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemRefresher", "Refresh", 3usize, |this| ((**(this as *const *const IWbemRefresher_Vtbl)).Refresh)(this, lflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Refresh }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemRefresher as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemServices_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OpenNamespace(this: *mut core::ffi::c_void, strnamespace: core::mem::MaybeUninit<windows_core::BSTR>, lflags: WBEM_GENERIC_FLAG_TYPE, pctx: *mut core::ffi::c_void, ppworkingnamespace: *mut *mut core::ffi::c_void, ppresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemServices", "OpenNamespace", 3usize, |this| ((**(this as *const *const IWbemServices_Vtbl)).OpenNamespace)(this, strnamespace, lflags, pctx, ppworkingnamespace, ppresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn CancelAsyncCall(this: *mut core::ffi::c_void, psink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemServices", "CancelAsyncCall", 4usize, |this| ((**(this as *const *const IWbemServices_Vtbl)).CancelAsyncCall)(this, psink), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryObjectSink(this: *mut core::ffi::c_void, lflags: WBEM_GENERIC_FLAG_TYPE, ppresponsehandler: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemServices", "QueryObjectSink", 5usize, |this| ((**(this as *const *const IWbemServices_Vtbl)).QueryObjectSink)(this, lflags, ppresponsehandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObject(this: *mut core::ffi::c_void, strobjectpath: core::mem::MaybeUninit<windows_core::BSTR>, lflags: WBEM_GENERIC_FLAG_TYPE, pctx: *mut core::ffi::c_void, ppobject: *mut *mut core::ffi::c_void, ppcallresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemServices", "GetObject", 6usize, |this| ((**(this as *const *const IWbemServices_Vtbl)).GetObject)(this, strobjectpath, lflags, pctx, ppobject, ppcallresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObjectAsync(this: *mut core::ffi::c_void, strobjectpath: core::mem::MaybeUninit<windows_core::BSTR>, lflags: WBEM_GENERIC_FLAG_TYPE, pctx: *mut core::ffi::c_void, presponsehandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemServices", "GetObjectAsync", 7usize, |this| ((**(this as *const *const IWbemServices_Vtbl)).GetObjectAsync)(this, strobjectpath, lflags, pctx, presponsehandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutClass(this: *mut core::ffi::c_void, pobject: *mut core::ffi::c_void, lflags: WBEM_GENERIC_FLAG_TYPE, pctx: *mut core::ffi::c_void, ppcallresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemServices", "PutClass", 8usize, |this| ((**(this as *const *const IWbemServices_Vtbl)).PutClass)(this, pobject, lflags, pctx, ppcallresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutClassAsync(this: *mut core::ffi::c_void, pobject: *mut core::ffi::c_void, lflags: WBEM_GENERIC_FLAG_TYPE, pctx: *mut core::ffi::c_void, presponsehandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemServices", "PutClassAsync", 9usize, |this| ((**(this as *const *const IWbemServices_Vtbl)).PutClassAsync)(this, pobject, lflags, pctx, presponsehandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteClass(this: *mut core::ffi::c_void, strclass: core::mem::MaybeUninit<windows_core::BSTR>, lflags: WBEM_GENERIC_FLAG_TYPE, pctx: *mut core::ffi::c_void, ppcallresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemServices", "DeleteClass", 10usize, |this| ((**(this as *const *const IWbemServices_Vtbl)).DeleteClass)(this, strclass, lflags, pctx, ppcallresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteClassAsync(this: *mut core::ffi::c_void, strclass: core::mem::MaybeUninit<windows_core::BSTR>, lflags: WBEM_GENERIC_FLAG_TYPE, pctx: *mut core::ffi::c_void, presponsehandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemServices", "DeleteClassAsync", 11usize, |this| ((**(this as *const *const IWbemServices_Vtbl)).DeleteClassAsync)(this, strclass, lflags, pctx, presponsehandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateClassEnum(this: *mut core::ffi::c_void, strsuperclass: core::mem::MaybeUninit<windows_core::BSTR>, lflags: WBEM_GENERIC_FLAG_TYPE, pctx: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemServices", "CreateClassEnum", 12usize, |this| ((**(this as *const *const IWbemServices_Vtbl)).CreateClassEnum)(this, strsuperclass, lflags, pctx, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateClassEnumAsync(this: *mut core::ffi::c_void, strsuperclass: core::mem::MaybeUninit<windows_core::BSTR>, lflags: WBEM_GENERIC_FLAG_TYPE, pctx: *mut core::ffi::c_void, presponsehandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemServices", "CreateClassEnumAsync", 13usize, |this| ((**(this as *const *const IWbemServices_Vtbl)).CreateClassEnumAsync)(this, strsuperclass, lflags, pctx, presponsehandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutInstance(this: *mut core::ffi::c_void, pinst: *mut core::ffi::c_void, lflags: WBEM_GENERIC_FLAG_TYPE, pctx: *mut core::ffi::c_void, ppcallresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemServices", "PutInstance", 14usize, |this| ((**(this as *const *const IWbemServices_Vtbl)).PutInstance)(this, pinst, lflags, pctx, ppcallresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn PutInstanceAsync(this: *mut core::ffi::c_void, pinst: *mut core::ffi::c_void, lflags: WBEM_GENERIC_FLAG_TYPE, pctx: *mut core::ffi::c_void, presponsehandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemServices", "PutInstanceAsync", 15usize, |this| ((**(this as *const *const IWbemServices_Vtbl)).PutInstanceAsync)(this, pinst, lflags, pctx, presponsehandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteInstance(this: *mut core::ffi::c_void, strobjectpath: core::mem::MaybeUninit<windows_core::BSTR>, lflags: WBEM_GENERIC_FLAG_TYPE, pctx: *mut core::ffi::c_void, ppcallresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemServices", "DeleteInstance", 16usize, |this| ((**(this as *const *const IWbemServices_Vtbl)).DeleteInstance)(this, strobjectpath, lflags, pctx, ppcallresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteInstanceAsync(this: *mut core::ffi::c_void, strobjectpath: core::mem::MaybeUninit<windows_core::BSTR>, lflags: WBEM_GENERIC_FLAG_TYPE, pctx: *mut core::ffi::c_void, presponsehandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemServices", "DeleteInstanceAsync", 17usize, |this| ((**(this as *const *const IWbemServices_Vtbl)).DeleteInstanceAsync)(this, strobjectpath, lflags, pctx, presponsehandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInstanceEnum(this: *mut core::ffi::c_void, strfilter: core::mem::MaybeUninit<windows_core::BSTR>, lflags: WBEM_GENERIC_FLAG_TYPE, pctx: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemServices", "CreateInstanceEnum", 18usize, |this| ((**(this as *const *const IWbemServices_Vtbl)).CreateInstanceEnum)(this, strfilter, lflags, pctx, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInstanceEnumAsync(this: *mut core::ffi::c_void, strfilter: core::mem::MaybeUninit<windows_core::BSTR>, lflags: WBEM_GENERIC_FLAG_TYPE, pctx: *mut core::ffi::c_void, presponsehandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemServices", "CreateInstanceEnumAsync", 19usize, |this| ((**(this as *const *const IWbemServices_Vtbl)).CreateInstanceEnumAsync)(this, strfilter, lflags, pctx, presponsehandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExecQuery(this: *mut core::ffi::c_void, strquerylanguage: core::mem::MaybeUninit<windows_core::BSTR>, strquery: core::mem::MaybeUninit<windows_core::BSTR>, lflags: WBEM_GENERIC_FLAG_TYPE, pctx: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemServices", "ExecQuery", 20usize, |this| ((**(this as *const *const IWbemServices_Vtbl)).ExecQuery)(this, strquerylanguage, strquery, lflags, pctx, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExecQueryAsync(this: *mut core::ffi::c_void, strquerylanguage: core::mem::MaybeUninit<windows_core::BSTR>, strquery: core::mem::MaybeUninit<windows_core::BSTR>, lflags: WBEM_GENERIC_FLAG_TYPE, pctx: *mut core::ffi::c_void, presponsehandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemServices", "ExecQueryAsync", 21usize, |this| ((**(this as *const *const IWbemServices_Vtbl)).ExecQueryAsync)(this, strquerylanguage, strquery, lflags, pctx, presponsehandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExecNotificationQuery(this: *mut core::ffi::c_void, strquerylanguage: core::mem::MaybeUninit<windows_core::BSTR>, strquery: core::mem::MaybeUninit<windows_core::BSTR>, lflags: WBEM_GENERIC_FLAG_TYPE, pctx: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemServices", "ExecNotificationQuery", 22usize, |this| ((**(this as *const *const IWbemServices_Vtbl)).ExecNotificationQuery)(this, strquerylanguage, strquery, lflags, pctx, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExecNotificationQueryAsync(this: *mut core::ffi::c_void, strquerylanguage: core::mem::MaybeUninit<windows_core::BSTR>, strquery: core::mem::MaybeUninit<windows_core::BSTR>, lflags: WBEM_GENERIC_FLAG_TYPE, pctx: *mut core::ffi::c_void, presponsehandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemServices", "ExecNotificationQueryAsync", 23usize, |this| ((**(this as *const *const IWbemServices_Vtbl)).ExecNotificationQueryAsync)(this, strquerylanguage, strquery, lflags, pctx, presponsehandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExecMethod(this: *mut core::ffi::c_void, strobjectpath: core::mem::MaybeUninit<windows_core::BSTR>, strmethodname: core::mem::MaybeUninit<windows_core::BSTR>, lflags: WBEM_GENERIC_FLAG_TYPE, pctx: *mut core::ffi::c_void, pinparams: *mut core::ffi::c_void, ppoutparams: *mut *mut core::ffi::c_void, ppcallresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemServices", "ExecMethod", 24usize, |this| ((**(this as *const *const IWbemServices_Vtbl)).ExecMethod)(this, strobjectpath, strmethodname, lflags, pctx, pinparams, ppoutparams, ppcallresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExecMethodAsync(this: *mut core::ffi::c_void, strobjectpath: core::mem::MaybeUninit<windows_core::BSTR>, strmethodname: core::mem::MaybeUninit<windows_core::BSTR>, lflags: WBEM_GENERIC_FLAG_TYPE, pctx: *mut core::ffi::c_void, pinparams: *mut core::ffi::c_void, presponsehandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemServices", "ExecMethodAsync", 25usize, |this| ((**(this as *const *const IWbemServices_Vtbl)).ExecMethodAsync)(this, strobjectpath, strmethodname, lflags, pctx, pinparams, presponsehandler), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OpenNamespace,
+            CancelAsyncCall,
+            QueryObjectSink,
+            GetObject,
+            GetObjectAsync,
+            PutClass,
+            PutClassAsync,
+            DeleteClass,
+            DeleteClassAsync,
+            CreateClassEnum,
+            CreateClassEnumAsync,
+            PutInstance,
+            PutInstanceAsync,
+            DeleteInstance,
+            DeleteInstanceAsync,
+            CreateInstanceEnum,
+            CreateInstanceEnumAsync,
+            ExecQuery,
+            ExecQueryAsync,
+            ExecNotificationQuery,
+            ExecNotificationQueryAsync,
+            ExecMethod,
+            ExecMethodAsync,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemServices as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemShutdown_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void, ureason: i32, umaxmilliseconds: u32, pctx: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemShutdown", "Shutdown", 3usize, |this| ((**(this as *const *const IWbemShutdown_Vtbl)).Shutdown)(this, ureason, umaxmilliseconds, pctx), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Shutdown }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemShutdown as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemStatusCodeText_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetErrorCodeText(this: *mut core::ffi::c_void, hres: windows_core::HRESULT, localeid: u32, lflags: i32, messagetext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemStatusCodeText", "GetErrorCodeText", 3usize, |this| ((**(this as *const *const IWbemStatusCodeText_Vtbl)).GetErrorCodeText)(this, hres, localeid, lflags, messagetext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFacilityCodeText(this: *mut core::ffi::c_void, hres: windows_core::HRESULT, localeid: u32, lflags: i32, messagetext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemStatusCodeText", "GetFacilityCodeText", 4usize, |this| ((**(this as *const *const IWbemStatusCodeText_Vtbl)).GetFacilityCodeText)(this, hres, localeid, lflags, messagetext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetErrorCodeText, GetFacilityCodeText }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemStatusCodeText as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemTransport_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemTransport", "Initialize", 3usize, |this| ((**(this as *const *const IWbemTransport_Vtbl)).Initialize)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemTransport as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemUnboundObjectSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IndicateToConsumer(this: *mut core::ffi::c_void, plogicalconsumer: *mut core::ffi::c_void, lnumobjects: i32, apobjects: *const *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemUnboundObjectSink", "IndicateToConsumer", 3usize, |this| ((**(this as *const *const IWbemUnboundObjectSink_Vtbl)).IndicateToConsumer)(this, plogicalconsumer, lnumobjects, apobjects), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IndicateToConsumer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemUnboundObjectSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWbemUnsecuredApartment_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateSinkStub(this: *mut core::ffi::c_void, psink: *mut core::ffi::c_void, dwflags: u32, wszreserved: windows_core::PCWSTR, ppstub: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWbemUnsecuredApartment", "CreateSinkStub", 4usize, |this| ((**(this as *const *const IWbemUnsecuredApartment_Vtbl)).CreateSinkStub)(this, psink, dwflags, wszreserved, ppstub), |result| Some(*result))
+        }
+        Self { base__: <IUnsecuredApartment_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateSinkStub }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWbemUnsecuredApartment as windows_core::Interface>::IID || <IUnsecuredApartment_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
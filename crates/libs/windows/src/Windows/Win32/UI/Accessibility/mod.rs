@@ -8507,6 +8507,7 @@ pub struct ACC_UTILITY_STATE_FLAGS(pub u32);
 impl windows_core::TypeKind for ACC_UTILITY_STATE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ACC_UTILITY_STATE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ACC_UTILITY_STATE_FLAGS").field(&self.0).finish()
@@ -8551,6 +8552,7 @@ pub struct ActiveEnd(pub i32);
 impl windows_core::TypeKind for ActiveEnd {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ActiveEnd {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ActiveEnd").field(&self.0).finish()
@@ -8562,6 +8564,7 @@ pub struct AnimationStyle(pub i32);
 impl windows_core::TypeKind for AnimationStyle {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AnimationStyle {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AnimationStyle").field(&self.0).finish()
@@ -8573,6 +8576,7 @@ pub struct AnnoScope(pub i32);
 impl windows_core::TypeKind for AnnoScope {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AnnoScope {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AnnoScope").field(&self.0).finish()
@@ -8584,6 +8588,7 @@ pub struct AsyncContentLoadedState(pub i32);
 impl windows_core::TypeKind for AsyncContentLoadedState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AsyncContentLoadedState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AsyncContentLoadedState").field(&self.0).finish()
@@ -8595,6 +8600,7 @@ pub struct AutomationElementMode(pub i32);
 impl windows_core::TypeKind for AutomationElementMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AutomationElementMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AutomationElementMode").field(&self.0).finish()
@@ -8606,6 +8612,7 @@ pub struct AutomationIdentifierType(pub i32);
 impl windows_core::TypeKind for AutomationIdentifierType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AutomationIdentifierType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AutomationIdentifierType").field(&self.0).finish()
@@ -8617,6 +8624,7 @@ pub struct BulletStyle(pub i32);
 impl windows_core::TypeKind for BulletStyle {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BulletStyle {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BulletStyle").field(&self.0).finish()
@@ -8628,6 +8636,7 @@ pub struct CapStyle(pub i32);
 impl windows_core::TypeKind for CapStyle {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CapStyle {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CapStyle").field(&self.0).finish()
@@ -8639,6 +8648,7 @@ pub struct CaretBidiMode(pub i32);
 impl windows_core::TypeKind for CaretBidiMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CaretBidiMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CaretBidiMode").field(&self.0).finish()
@@ -8650,6 +8660,7 @@ pub struct CaretPosition(pub i32);
 impl windows_core::TypeKind for CaretPosition {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CaretPosition {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CaretPosition").field(&self.0).finish()
@@ -8661,6 +8672,7 @@ pub struct CoalesceEventsOptions(pub i32);
 impl windows_core::TypeKind for CoalesceEventsOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CoalesceEventsOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CoalesceEventsOptions").field(&self.0).finish()
@@ -8672,6 +8684,7 @@ pub struct ConditionType(pub i32);
 impl windows_core::TypeKind for ConditionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ConditionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ConditionType").field(&self.0).finish()
@@ -8683,6 +8696,7 @@ pub struct ConnectionRecoveryBehaviorOptions(pub i32);
 impl windows_core::TypeKind for ConnectionRecoveryBehaviorOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ConnectionRecoveryBehaviorOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ConnectionRecoveryBehaviorOptions").field(&self.0).finish()
@@ -8694,6 +8708,7 @@ pub struct DockPosition(pub i32);
 impl windows_core::TypeKind for DockPosition {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DockPosition {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DockPosition").field(&self.0).finish()
@@ -8705,6 +8720,7 @@ pub struct EventArgsType(pub i32);
 impl windows_core::TypeKind for EventArgsType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EventArgsType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EventArgsType").field(&self.0).finish()
@@ -8716,6 +8732,7 @@ pub struct ExpandCollapseState(pub i32);
 impl windows_core::TypeKind for ExpandCollapseState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ExpandCollapseState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ExpandCollapseState").field(&self.0).finish()
@@ -8727,6 +8744,7 @@ pub struct FillType(pub i32);
 impl windows_core::TypeKind for FillType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FillType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FillType").field(&self.0).finish()
@@ -8738,6 +8756,7 @@ pub struct FlowDirections(pub i32);
 impl windows_core::TypeKind for FlowDirections {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FlowDirections {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FlowDirections").field(&self.0).finish()
@@ -8749,6 +8768,7 @@ pub struct HIGHCONTRASTW_FLAGS(pub u32);
 impl windows_core::TypeKind for HIGHCONTRASTW_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HIGHCONTRASTW_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HIGHCONTRASTW_FLAGS").field(&self.0).finish()
@@ -8793,6 +8813,7 @@ pub struct HorizontalTextAlignment(pub i32);
 impl windows_core::TypeKind for HorizontalTextAlignment {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HorizontalTextAlignment {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HorizontalTextAlignment").field(&self.0).finish()
@@ -8804,6 +8825,7 @@ pub struct LiveSetting(pub i32);
 impl windows_core::TypeKind for LiveSetting {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LiveSetting {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LiveSetting").field(&self.0).finish()
@@ -8815,6 +8837,7 @@ pub struct NavigateDirection(pub i32);
 impl windows_core::TypeKind for NavigateDirection {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NavigateDirection {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NavigateDirection").field(&self.0).finish()
@@ -8826,6 +8849,7 @@ pub struct NormalizeState(pub i32);
 impl windows_core::TypeKind for NormalizeState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NormalizeState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NormalizeState").field(&self.0).finish()
@@ -8837,6 +8861,7 @@ pub struct NotificationKind(pub i32);
 impl windows_core::TypeKind for NotificationKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NotificationKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NotificationKind").field(&self.0).finish()
@@ -8848,6 +8873,7 @@ pub struct NotificationProcessing(pub i32);
 impl windows_core::TypeKind for NotificationProcessing {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NotificationProcessing {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NotificationProcessing").field(&self.0).finish()
@@ -8859,6 +8885,7 @@ pub struct OrientationType(pub i32);
 impl windows_core::TypeKind for OrientationType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OrientationType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OrientationType").field(&self.0).finish()
@@ -8870,6 +8897,7 @@ pub struct OutlineStyles(pub i32);
 impl windows_core::TypeKind for OutlineStyles {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OutlineStyles {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OutlineStyles").field(&self.0).finish()
@@ -8881,6 +8909,7 @@ pub struct PropertyConditionFlags(pub i32);
 impl windows_core::TypeKind for PropertyConditionFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PropertyConditionFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PropertyConditionFlags").field(&self.0).finish()
@@ -8892,6 +8921,7 @@ pub struct ProviderOptions(pub i32);
 impl windows_core::TypeKind for ProviderOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ProviderOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ProviderOptions").field(&self.0).finish()
@@ -8936,6 +8966,7 @@ pub struct ProviderType(pub i32);
 impl windows_core::TypeKind for ProviderType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ProviderType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ProviderType").field(&self.0).finish()
@@ -8947,6 +8978,7 @@ pub struct RowOrColumnMajor(pub i32);
 impl windows_core::TypeKind for RowOrColumnMajor {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RowOrColumnMajor {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RowOrColumnMajor").field(&self.0).finish()
@@ -8958,6 +8990,7 @@ pub struct SERIALKEYS_FLAGS(pub u32);
 impl windows_core::TypeKind for SERIALKEYS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SERIALKEYS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SERIALKEYS_FLAGS").field(&self.0).finish()
@@ -9002,6 +9035,7 @@ pub struct SOUNDSENTRY_FLAGS(pub u32);
 impl windows_core::TypeKind for SOUNDSENTRY_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SOUNDSENTRY_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SOUNDSENTRY_FLAGS").field(&self.0).finish()
@@ -9046,6 +9080,7 @@ pub struct SOUNDSENTRY_TEXT_EFFECT(pub u32);
 impl windows_core::TypeKind for SOUNDSENTRY_TEXT_EFFECT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SOUNDSENTRY_TEXT_EFFECT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SOUNDSENTRY_TEXT_EFFECT").field(&self.0).finish()
@@ -9057,6 +9092,7 @@ pub struct SOUNDSENTRY_WINDOWS_EFFECT(pub u32);
 impl windows_core::TypeKind for SOUNDSENTRY_WINDOWS_EFFECT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SOUNDSENTRY_WINDOWS_EFFECT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SOUNDSENTRY_WINDOWS_EFFECT").field(&self.0).finish()
@@ -9068,6 +9104,7 @@ pub struct SOUND_SENTRY_GRAPHICS_EFFECT(pub u32);
 impl windows_core::TypeKind for SOUND_SENTRY_GRAPHICS_EFFECT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SOUND_SENTRY_GRAPHICS_EFFECT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SOUND_SENTRY_GRAPHICS_EFFECT").field(&self.0).finish()
@@ -9079,6 +9116,7 @@ pub struct STICKYKEYS_FLAGS(pub u32);
 impl windows_core::TypeKind for STICKYKEYS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STICKYKEYS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STICKYKEYS_FLAGS").field(&self.0).finish()
@@ -9123,6 +9161,7 @@ pub struct SayAsInterpretAs(pub i32);
 impl windows_core::TypeKind for SayAsInterpretAs {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SayAsInterpretAs {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SayAsInterpretAs").field(&self.0).finish()
@@ -9134,6 +9173,7 @@ pub struct ScrollAmount(pub i32);
 impl windows_core::TypeKind for ScrollAmount {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ScrollAmount {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ScrollAmount").field(&self.0).finish()
@@ -9145,6 +9185,7 @@ pub struct StructureChangeType(pub i32);
 impl windows_core::TypeKind for StructureChangeType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StructureChangeType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StructureChangeType").field(&self.0).finish()
@@ -9156,6 +9197,7 @@ pub struct SupportedTextSelection(pub i32);
 impl windows_core::TypeKind for SupportedTextSelection {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SupportedTextSelection {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SupportedTextSelection").field(&self.0).finish()
@@ -9167,6 +9209,7 @@ pub struct SynchronizedInputType(pub i32);
 impl windows_core::TypeKind for SynchronizedInputType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SynchronizedInputType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SynchronizedInputType").field(&self.0).finish()
@@ -9211,6 +9254,7 @@ pub struct TextDecorationLineStyle(pub i32);
 impl windows_core::TypeKind for TextDecorationLineStyle {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TextDecorationLineStyle {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TextDecorationLineStyle").field(&self.0).finish()
@@ -9222,6 +9266,7 @@ pub struct TextEditChangeType(pub i32);
 impl windows_core::TypeKind for TextEditChangeType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TextEditChangeType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TextEditChangeType").field(&self.0).finish()
@@ -9233,6 +9278,7 @@ pub struct TextPatternRangeEndpoint(pub i32);
 impl windows_core::TypeKind for TextPatternRangeEndpoint {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TextPatternRangeEndpoint {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TextPatternRangeEndpoint").field(&self.0).finish()
@@ -9244,6 +9290,7 @@ pub struct TextUnit(pub i32);
 impl windows_core::TypeKind for TextUnit {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TextUnit {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TextUnit").field(&self.0).finish()
@@ -9255,6 +9302,7 @@ pub struct ToggleState(pub i32);
 impl windows_core::TypeKind for ToggleState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ToggleState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ToggleState").field(&self.0).finish()
@@ -9266,6 +9314,7 @@ pub struct TreeScope(pub i32);
 impl windows_core::TypeKind for TreeScope {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TreeScope {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TreeScope").field(&self.0).finish()
@@ -9277,6 +9326,7 @@ pub struct TreeTraversalOptions(pub i32);
 impl windows_core::TypeKind for TreeTraversalOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TreeTraversalOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TreeTraversalOptions").field(&self.0).finish()
@@ -9288,6 +9338,7 @@ pub struct UIA_ANNOTATIONTYPE(pub i32);
 impl windows_core::TypeKind for UIA_ANNOTATIONTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UIA_ANNOTATIONTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UIA_ANNOTATIONTYPE").field(&self.0).finish()
@@ -9299,6 +9350,7 @@ pub struct UIA_CHANGE_ID(pub i32);
 impl windows_core::TypeKind for UIA_CHANGE_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UIA_CHANGE_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UIA_CHANGE_ID").field(&self.0).finish()
@@ -9310,6 +9362,7 @@ pub struct UIA_CONTROLTYPE_ID(pub i32);
 impl windows_core::TypeKind for UIA_CONTROLTYPE_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UIA_CONTROLTYPE_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UIA_CONTROLTYPE_ID").field(&self.0).finish()
@@ -9321,6 +9374,7 @@ pub struct UIA_EVENT_ID(pub i32);
 impl windows_core::TypeKind for UIA_EVENT_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UIA_EVENT_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UIA_EVENT_ID").field(&self.0).finish()
@@ -9332,6 +9386,7 @@ pub struct UIA_HEADINGLEVEL_ID(pub i32);
 impl windows_core::TypeKind for UIA_HEADINGLEVEL_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UIA_HEADINGLEVEL_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UIA_HEADINGLEVEL_ID").field(&self.0).finish()
@@ -9343,6 +9398,7 @@ pub struct UIA_LANDMARKTYPE_ID(pub i32);
 impl windows_core::TypeKind for UIA_LANDMARKTYPE_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UIA_LANDMARKTYPE_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UIA_LANDMARKTYPE_ID").field(&self.0).finish()
@@ -9354,6 +9410,7 @@ pub struct UIA_METADATA_ID(pub i32);
 impl windows_core::TypeKind for UIA_METADATA_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UIA_METADATA_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UIA_METADATA_ID").field(&self.0).finish()
@@ -9365,6 +9422,7 @@ pub struct UIA_PATTERN_ID(pub i32);
 impl windows_core::TypeKind for UIA_PATTERN_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UIA_PATTERN_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UIA_PATTERN_ID").field(&self.0).finish()
@@ -9376,6 +9434,7 @@ pub struct UIA_PROPERTY_ID(pub i32);
 impl windows_core::TypeKind for UIA_PROPERTY_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UIA_PROPERTY_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UIA_PROPERTY_ID").field(&self.0).finish()
@@ -9387,6 +9446,7 @@ pub struct UIA_STYLE_ID(pub i32);
 impl windows_core::TypeKind for UIA_STYLE_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UIA_STYLE_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UIA_STYLE_ID").field(&self.0).finish()
@@ -9398,6 +9458,7 @@ pub struct UIA_TEXTATTRIBUTE_ID(pub i32);
 impl windows_core::TypeKind for UIA_TEXTATTRIBUTE_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UIA_TEXTATTRIBUTE_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UIA_TEXTATTRIBUTE_ID").field(&self.0).finish()
@@ -9409,6 +9470,7 @@ pub struct UIAutomationType(pub i32);
 impl windows_core::TypeKind for UIAutomationType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UIAutomationType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UIAutomationType").field(&self.0).finish()
@@ -9453,6 +9515,7 @@ pub struct VisualEffects(pub i32);
 impl windows_core::TypeKind for VisualEffects {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VisualEffects {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VisualEffects").field(&self.0).finish()
@@ -9464,6 +9527,7 @@ pub struct WindowInteractionState(pub i32);
 impl windows_core::TypeKind for WindowInteractionState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WindowInteractionState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WindowInteractionState").field(&self.0).finish()
@@ -9475,6 +9539,7 @@ pub struct WindowVisualState(pub i32);
 impl windows_core::TypeKind for WindowVisualState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WindowVisualState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WindowVisualState").field(&self.0).finish()
@@ -9486,13 +9551,15 @@ pub struct ZoomUnit(pub i32);
 impl windows_core::TypeKind for ZoomUnit {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ZoomUnit {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ZoomUnit").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ACCESSTIMEOUT {
     pub cbSize: u32,
     pub dwFlags: u32,
@@ -9511,7 +9578,8 @@ pub const CUIAutomation: windows_core::GUID = windows_core::GUID::from_u128(0xff
 pub const CUIAutomation8: windows_core::GUID = windows_core::GUID::from_u128(0xe22ad333_b25f_460c_83d0_0581107395c9);
 pub const CUIAutomationRegistrar: windows_core::GUID = windows_core::GUID::from_u128(0x6e29fabf_9977_42d1_8d0e_ca7e61ad87e6);
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ExtendedProperty {
     pub PropertyName: core::mem::ManuallyDrop<windows_core::BSTR>,
     pub PropertyValue: core::mem::ManuallyDrop<windows_core::BSTR>,
@@ -9530,7 +9598,8 @@ impl Default for ExtendedProperty {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FILTERKEYS {
     pub cbSize: u32,
     pub dwFlags: u32,
@@ -9548,7 +9617,8 @@ impl Default for FILTERKEYS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HIGHCONTRASTA {
     pub cbSize: u32,
     pub dwFlags: HIGHCONTRASTW_FLAGS,
@@ -9563,7 +9633,8 @@ impl Default for HIGHCONTRASTA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HIGHCONTRASTW {
     pub cbSize: u32,
     pub dwFlags: HIGHCONTRASTW_FLAGS,
@@ -9578,7 +9649,8 @@ impl Default for HIGHCONTRASTW {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HUIAEVENT(pub *mut core::ffi::c_void);
 impl HUIAEVENT {
     pub fn is_invalid(&self) -> bool {
@@ -9602,7 +9674,8 @@ impl windows_core::TypeKind for HUIAEVENT {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HUIANODE(pub *mut core::ffi::c_void);
 impl HUIANODE {
     pub fn is_invalid(&self) -> bool {
@@ -9626,7 +9699,8 @@ impl windows_core::TypeKind for HUIANODE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HUIAPATTERNOBJECT(pub *mut core::ffi::c_void);
 impl HUIAPATTERNOBJECT {
     pub fn is_invalid(&self) -> bool {
@@ -9650,7 +9724,8 @@ impl windows_core::TypeKind for HUIAPATTERNOBJECT {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HUIATEXTRANGE(pub *mut core::ffi::c_void);
 impl HUIATEXTRANGE {
     pub fn is_invalid(&self) -> bool {
@@ -9674,7 +9749,8 @@ impl windows_core::TypeKind for HUIATEXTRANGE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HWINEVENTHOOK(pub *mut core::ffi::c_void);
 impl HWINEVENTHOOK {
     pub fn is_invalid(&self) -> bool {
@@ -9698,7 +9774,8 @@ impl windows_core::TypeKind for HWINEVENTHOOK {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MOUSEKEYS {
     pub cbSize: u32,
     pub dwFlags: u32,
@@ -9717,7 +9794,8 @@ impl Default for MOUSEKEYS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MSAAMENUINFO {
     pub dwMSAASignature: u32,
     pub cchWText: u32,
@@ -9732,7 +9810,8 @@ impl Default for MSAAMENUINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SERIALKEYSA {
     pub cbSize: u32,
     pub dwFlags: SERIALKEYS_FLAGS,
@@ -9751,7 +9830,8 @@ impl Default for SERIALKEYSA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SERIALKEYSW {
     pub cbSize: u32,
     pub dwFlags: SERIALKEYS_FLAGS,
@@ -9770,7 +9850,8 @@ impl Default for SERIALKEYSW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOUNDSENTRYA {
     pub cbSize: u32,
     pub dwFlags: SOUNDSENTRY_FLAGS,
@@ -9794,7 +9875,8 @@ impl Default for SOUNDSENTRYA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SOUNDSENTRYW {
     pub cbSize: u32,
     pub dwFlags: SOUNDSENTRY_FLAGS,
@@ -9818,7 +9900,8 @@ impl Default for SOUNDSENTRYW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STICKYKEYS {
     pub cbSize: u32,
     pub dwFlags: STICKYKEYS_FLAGS,
@@ -9832,7 +9915,8 @@ impl Default for STICKYKEYS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TOGGLEKEYS {
     pub cbSize: u32,
     pub dwFlags: u32,
@@ -9846,7 +9930,8 @@ impl Default for TOGGLEKEYS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UIAutomationEventInfo {
     pub guid: windows_core::GUID,
     pub pProgrammaticName: windows_core::PCWSTR,
@@ -9860,7 +9945,8 @@ impl Default for UIAutomationEventInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UIAutomationMethodInfo {
     pub pProgrammaticName: windows_core::PCWSTR,
     pub doSetFocus: super::super::Foundation::BOOL,
@@ -9878,7 +9964,8 @@ impl Default for UIAutomationMethodInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UIAutomationParameter {
     pub r#type: UIAutomationType,
     pub pData: *mut core::ffi::c_void,
@@ -9892,7 +9979,8 @@ impl Default for UIAutomationParameter {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UIAutomationPatternInfo {
     pub guid: windows_core::GUID,
     pub pProgrammaticName: windows_core::PCWSTR,
@@ -9920,7 +10008,8 @@ impl Default for UIAutomationPatternInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UIAutomationPropertyInfo {
     pub guid: windows_core::GUID,
     pub pProgrammaticName: windows_core::PCWSTR,
@@ -9935,7 +10024,8 @@ impl Default for UIAutomationPropertyInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UiaAndOrCondition {
     pub ConditionType: ConditionType,
     pub ppConditions: *mut *mut UiaCondition,
@@ -9950,7 +10040,8 @@ impl Default for UiaAndOrCondition {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UiaAsyncContentLoadedEventArgs {
     pub Type: EventArgsType,
     pub EventId: i32,
@@ -9966,7 +10057,8 @@ impl Default for UiaAsyncContentLoadedEventArgs {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UiaCacheRequest {
     pub pViewCondition: *mut UiaCondition,
     pub Scope: TreeScope,
@@ -9985,7 +10077,8 @@ impl Default for UiaCacheRequest {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UiaChangeInfo {
     pub uiaId: i32,
     pub payload: core::mem::ManuallyDrop<windows_core::VARIANT>,
@@ -10005,7 +10098,8 @@ impl Default for UiaChangeInfo {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UiaChangesEventArgs {
     pub Type: EventArgsType,
     pub EventId: i32,
@@ -10021,7 +10115,8 @@ impl Default for UiaChangesEventArgs {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UiaCondition {
     pub ConditionType: ConditionType,
 }
@@ -10034,7 +10129,8 @@ impl Default for UiaCondition {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UiaEventArgs {
     pub Type: EventArgsType,
     pub EventId: i32,
@@ -10048,7 +10144,8 @@ impl Default for UiaEventArgs {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UiaFindParams {
     pub MaxDepth: i32,
     pub FindFirst: super::super::Foundation::BOOL,
@@ -10064,7 +10161,8 @@ impl Default for UiaFindParams {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UiaNotCondition {
     pub ConditionType: ConditionType,
     pub pCondition: *mut UiaCondition,
@@ -10078,7 +10176,8 @@ impl Default for UiaNotCondition {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UiaPoint {
     pub x: f64,
     pub y: f64,
@@ -10092,7 +10191,8 @@ impl Default for UiaPoint {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UiaPropertyChangedEventArgs {
     pub Type: EventArgsType,
     pub EventId: UIA_EVENT_ID,
@@ -10114,7 +10214,8 @@ impl Default for UiaPropertyChangedEventArgs {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UiaPropertyCondition {
     pub ConditionType: ConditionType,
     pub PropertyId: UIA_PROPERTY_ID,
@@ -10135,7 +10236,8 @@ impl Default for UiaPropertyCondition {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UiaRect {
     pub left: f64,
     pub top: f64,
@@ -10151,7 +10253,8 @@ impl Default for UiaRect {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UiaStructureChangedEventArgs {
     pub Type: EventArgsType,
     pub EventId: i32,
@@ -10169,7 +10272,8 @@ impl Default for UiaStructureChangedEventArgs {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UiaTextEditTextChangedEventArgs {
     pub Type: EventArgsType,
     pub EventId: i32,
@@ -10187,7 +10291,8 @@ impl Default for UiaTextEditTextChangedEventArgs {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UiaWindowClosedEventArgs {
     pub Type: EventArgsType,
     pub EventId: i32,
@@ -10215,5 +10320,8 @@ pub type UiaEventCallback = Option<unsafe extern "system" fn(pargs: *mut UiaEven
 #[cfg(feature = "Win32_System_Com")]
 pub type UiaProviderCallback = Option<unsafe extern "system" fn(hwnd: super::super::Foundation::HWND, providertype: ProviderType) -> *mut super::super::System::Com::SAFEARRAY>;
 pub type WINEVENTPROC = Option<unsafe extern "system" fn(hwineventhook: HWINEVENTHOOK, event: u32, hwnd: super::super::Foundation::HWND, idobject: i32, idchild: i32, ideventthread: u32, dwmseventtime: u32)>;
+core::include!(core::concat!(core::env!("CARGO_MANIFEST_DIR"), "/src/includes/", "Win32/UI/Accessibility/Automation.rs"));
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,4537 @@
+impl windows_core::imp::ProxyVtable for IAccIdentity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetIdentityString(this: *mut core::ffi::c_void, dwidchild: u32, ppidstring: *mut *mut u8, pdwidstringlen: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccIdentity", "GetIdentityString", 3usize, |this| ((**(this as *const *const IAccIdentity_Vtbl)).GetIdentityString)(this, dwidchild, ppidstring, pdwidstringlen), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetIdentityString }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccIdentity as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAccPropServer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPropValue(this: *mut core::ffi::c_void, pidstring: *const u8, dwidstringlen: u32, idprop: windows_core::GUID, pvarvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>, pfhasprop: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccPropServer", "GetPropValue", 3usize, |this| ((**(this as *const *const IAccPropServer_Vtbl)).GetPropValue)(this, pidstring, dwidstringlen, idprop, pvarvalue, pfhasprop), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPropValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccPropServer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl windows_core::imp::ProxyVtable for IAccPropServices_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPropValue(this: *mut core::ffi::c_void, pidstring: *const u8, dwidstringlen: u32, idprop: windows_core::GUID, var: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccPropServices", "SetPropValue", 3usize, |this| ((**(this as *const *const IAccPropServices_Vtbl)).SetPropValue)(this, pidstring, dwidstringlen, idprop, var), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPropServer(this: *mut core::ffi::c_void, pidstring: *const u8, dwidstringlen: u32, paprops: *const windows_core::GUID, cprops: i32, pserver: *mut core::ffi::c_void, annoscope: AnnoScope) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccPropServices", "SetPropServer", 4usize, |this| ((**(this as *const *const IAccPropServices_Vtbl)).SetPropServer)(this, pidstring, dwidstringlen, paprops, cprops, pserver, annoscope), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearProps(this: *mut core::ffi::c_void, pidstring: *const u8, dwidstringlen: u32, paprops: *const windows_core::GUID, cprops: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccPropServices", "ClearProps", 5usize, |this| ((**(this as *const *const IAccPropServices_Vtbl)).ClearProps)(this, pidstring, dwidstringlen, paprops, cprops), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHwndProp(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, idobject: u32, idchild: u32, idprop: windows_core::GUID, var: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccPropServices", "SetHwndProp", 6usize, |this| ((**(this as *const *const IAccPropServices_Vtbl)).SetHwndProp)(this, hwnd, idobject, idchild, idprop, var), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHwndPropStr(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, idobject: u32, idchild: u32, idprop: windows_core::GUID, str: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccPropServices", "SetHwndPropStr", 7usize, |this| ((**(this as *const *const IAccPropServices_Vtbl)).SetHwndPropStr)(this, hwnd, idobject, idchild, idprop, str), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHwndPropServer(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, idobject: u32, idchild: u32, paprops: *const windows_core::GUID, cprops: i32, pserver: *mut core::ffi::c_void, annoscope: AnnoScope) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccPropServices", "SetHwndPropServer", 8usize, |this| ((**(this as *const *const IAccPropServices_Vtbl)).SetHwndPropServer)(this, hwnd, idobject, idchild, paprops, cprops, pserver, annoscope), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearHwndProps(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, idobject: u32, idchild: u32, paprops: *const windows_core::GUID, cprops: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccPropServices", "ClearHwndProps", 9usize, |this| ((**(this as *const *const IAccPropServices_Vtbl)).ClearHwndProps)(this, hwnd, idobject, idchild, paprops, cprops), |result| Some(*result))
+        }
+        unsafe extern "system" fn ComposeHwndIdentityString(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, idobject: u32, idchild: u32, ppidstring: *mut *mut u8, pdwidstringlen: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccPropServices", "ComposeHwndIdentityString", 10usize, |this| ((**(this as *const *const IAccPropServices_Vtbl)).ComposeHwndIdentityString)(this, hwnd, idobject, idchild, ppidstring, pdwidstringlen), |result| Some(*result))
+        }
+        unsafe extern "system" fn DecomposeHwndIdentityString(this: *mut core::ffi::c_void, pidstring: *const u8, dwidstringlen: u32, phwnd: *mut super::super::Foundation::HWND, pidobject: *mut u32, pidchild: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccPropServices", "DecomposeHwndIdentityString", 11usize, |this| ((**(this as *const *const IAccPropServices_Vtbl)).DecomposeHwndIdentityString)(this, pidstring, dwidstringlen, phwnd, pidobject, pidchild), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn SetHmenuProp(this: *mut core::ffi::c_void, hmenu: super::WindowsAndMessaging::HMENU, idchild: u32, idprop: windows_core::GUID, var: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccPropServices", "SetHmenuProp", 12usize, |this| ((**(this as *const *const IAccPropServices_Vtbl)).SetHmenuProp)(this, hmenu, idchild, idprop, var), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn SetHmenuPropStr(this: *mut core::ffi::c_void, hmenu: super::WindowsAndMessaging::HMENU, idchild: u32, idprop: windows_core::GUID, str: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccPropServices", "SetHmenuPropStr", 13usize, |this| ((**(this as *const *const IAccPropServices_Vtbl)).SetHmenuPropStr)(this, hmenu, idchild, idprop, str), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn SetHmenuPropServer(this: *mut core::ffi::c_void, hmenu: super::WindowsAndMessaging::HMENU, idchild: u32, paprops: *const windows_core::GUID, cprops: i32, pserver: *mut core::ffi::c_void, annoscope: AnnoScope) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccPropServices", "SetHmenuPropServer", 14usize, |this| ((**(this as *const *const IAccPropServices_Vtbl)).SetHmenuPropServer)(this, hmenu, idchild, paprops, cprops, pserver, annoscope), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn ClearHmenuProps(this: *mut core::ffi::c_void, hmenu: super::WindowsAndMessaging::HMENU, idchild: u32, paprops: *const windows_core::GUID, cprops: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccPropServices", "ClearHmenuProps", 15usize, |this| ((**(this as *const *const IAccPropServices_Vtbl)).ClearHmenuProps)(this, hmenu, idchild, paprops, cprops), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn ComposeHmenuIdentityString(this: *mut core::ffi::c_void, hmenu: super::WindowsAndMessaging::HMENU, idchild: u32, ppidstring: *mut *mut u8, pdwidstringlen: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccPropServices", "ComposeHmenuIdentityString", 16usize, |this| ((**(this as *const *const IAccPropServices_Vtbl)).ComposeHmenuIdentityString)(this, hmenu, idchild, ppidstring, pdwidstringlen), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn DecomposeHmenuIdentityString(this: *mut core::ffi::c_void, pidstring: *const u8, dwidstringlen: u32, phmenu: *mut super::WindowsAndMessaging::HMENU, pidchild: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccPropServices", "DecomposeHmenuIdentityString", 17usize, |this| ((**(this as *const *const IAccPropServices_Vtbl)).DecomposeHmenuIdentityString)(this, pidstring, dwidstringlen, phmenu, pidchild), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetPropValue,
+            SetPropServer,
+            ClearProps,
+            SetHwndProp,
+            SetHwndPropStr,
+            SetHwndPropServer,
+            ClearHwndProps,
+            ComposeHwndIdentityString,
+            DecomposeHwndIdentityString,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            SetHmenuProp,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            SetHmenuProp: 0,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            SetHmenuPropStr,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            SetHmenuPropStr: 0,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            SetHmenuPropServer,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            SetHmenuPropServer: 0,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            ClearHmenuProps,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            ClearHmenuProps: 0,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            ComposeHmenuIdentityString,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            ComposeHmenuIdentityString: 0,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            DecomposeHmenuIdentityString,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            DecomposeHmenuIdentityString: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccPropServices as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAccessible_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn accParent(this: *mut core::ffi::c_void, ppdispparent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessible", "accParent", 7usize, |this| ((**(this as *const *const IAccessible_Vtbl)).accParent)(this, ppdispparent), |result| Some(*result))
+        }
+        unsafe extern "system" fn accChildCount(this: *mut core::ffi::c_void, pcountchildren: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessible", "accChildCount", 8usize, |this| ((**(this as *const *const IAccessible_Vtbl)).accChildCount)(this, pcountchildren), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn get_accChild(this: *mut core::ffi::c_void, varchild: core::mem::MaybeUninit<windows_core::VARIANT>, ppdispchild: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessible", "get_accChild", 9usize, |this| ((**(this as *const *const IAccessible_Vtbl)).get_accChild)(this, varchild, ppdispchild), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_accName(this: *mut core::ffi::c_void, varchild: core::mem::MaybeUninit<windows_core::VARIANT>, pszname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessible", "get_accName", 10usize, |this| ((**(this as *const *const IAccessible_Vtbl)).get_accName)(this, varchild, pszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_accValue(this: *mut core::ffi::c_void, varchild: core::mem::MaybeUninit<windows_core::VARIANT>, pszvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessible", "get_accValue", 11usize, |this| ((**(this as *const *const IAccessible_Vtbl)).get_accValue)(this, varchild, pszvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_accDescription(this: *mut core::ffi::c_void, varchild: core::mem::MaybeUninit<windows_core::VARIANT>, pszdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessible", "get_accDescription", 12usize, |this| ((**(this as *const *const IAccessible_Vtbl)).get_accDescription)(this, varchild, pszdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_accRole(this: *mut core::ffi::c_void, varchild: core::mem::MaybeUninit<windows_core::VARIANT>, pvarrole: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessible", "get_accRole", 13usize, |this| ((**(this as *const *const IAccessible_Vtbl)).get_accRole)(this, varchild, pvarrole), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_accState(this: *mut core::ffi::c_void, varchild: core::mem::MaybeUninit<windows_core::VARIANT>, pvarstate: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessible", "get_accState", 14usize, |this| ((**(this as *const *const IAccessible_Vtbl)).get_accState)(this, varchild, pvarstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_accHelp(this: *mut core::ffi::c_void, varchild: core::mem::MaybeUninit<windows_core::VARIANT>, pszhelp: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessible", "get_accHelp", 15usize, |this| ((**(this as *const *const IAccessible_Vtbl)).get_accHelp)(this, varchild, pszhelp), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_accHelpTopic(this: *mut core::ffi::c_void, pszhelpfile: *mut core::mem::MaybeUninit<windows_core::BSTR>, varchild: core::mem::MaybeUninit<windows_core::VARIANT>, pidtopic: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessible", "get_accHelpTopic", 16usize, |this| ((**(this as *const *const IAccessible_Vtbl)).get_accHelpTopic)(this, pszhelpfile, varchild, pidtopic), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_accKeyboardShortcut(this: *mut core::ffi::c_void, varchild: core::mem::MaybeUninit<windows_core::VARIANT>, pszkeyboardshortcut: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessible", "get_accKeyboardShortcut", 17usize, |this| ((**(this as *const *const IAccessible_Vtbl)).get_accKeyboardShortcut)(this, varchild, pszkeyboardshortcut), |result| Some(*result))
+        }
+        unsafe extern "system" fn accFocus(this: *mut core::ffi::c_void, pvarchild: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessible", "accFocus", 18usize, |this| ((**(this as *const *const IAccessible_Vtbl)).accFocus)(this, pvarchild), |result| Some(*result))
+        }
+        unsafe extern "system" fn accSelection(this: *mut core::ffi::c_void, pvarchildren: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessible", "accSelection", 19usize, |this| ((**(this as *const *const IAccessible_Vtbl)).accSelection)(this, pvarchildren), |result| Some(*result))
+        }
+        unsafe extern "system" fn get_accDefaultAction(this: *mut core::ffi::c_void, varchild: core::mem::MaybeUninit<windows_core::VARIANT>, pszdefaultaction: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessible", "get_accDefaultAction", 20usize, |this| ((**(this as *const *const IAccessible_Vtbl)).get_accDefaultAction)(this, varchild, pszdefaultaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn accSelect(this: *mut core::ffi::c_void, flagsselect: i32, varchild: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessible", "accSelect", 21usize, |this| ((**(this as *const *const IAccessible_Vtbl)).accSelect)(this, flagsselect, varchild), |result| Some(*result))
+        }
+        unsafe extern "system" fn accLocation(this: *mut core::ffi::c_void, pxleft: *mut i32, pytop: *mut i32, pcxwidth: *mut i32, pcyheight: *mut i32, varchild: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessible", "accLocation", 22usize, |this| ((**(this as *const *const IAccessible_Vtbl)).accLocation)(this, pxleft, pytop, pcxwidth, pcyheight, varchild), |result| Some(*result))
+        }
+        unsafe extern "system" fn accNavigate(this: *mut core::ffi::c_void, navdir: i32, varstart: core::mem::MaybeUninit<windows_core::VARIANT>, pvarendupat: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessible", "accNavigate", 23usize, |this| ((**(this as *const *const IAccessible_Vtbl)).accNavigate)(this, navdir, varstart, pvarendupat), |result| Some(*result))
+        }
+        unsafe extern "system" fn accHitTest(this: *mut core::ffi::c_void, xleft: i32, ytop: i32, pvarchild: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessible", "accHitTest", 24usize, |this| ((**(this as *const *const IAccessible_Vtbl)).accHitTest)(this, xleft, ytop, pvarchild), |result| Some(*result))
+        }
+        unsafe extern "system" fn accDoDefaultAction(this: *mut core::ffi::c_void, varchild: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessible", "accDoDefaultAction", 25usize, |this| ((**(this as *const *const IAccessible_Vtbl)).accDoDefaultAction)(this, varchild), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_accName(this: *mut core::ffi::c_void, varchild: core::mem::MaybeUninit<windows_core::VARIANT>, szname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessible", "put_accName", 26usize, |this| ((**(this as *const *const IAccessible_Vtbl)).put_accName)(this, varchild, szname), |result| Some(*result))
+        }
+        unsafe extern "system" fn put_accValue(this: *mut core::ffi::c_void, varchild: core::mem::MaybeUninit<windows_core::VARIANT>, szvalue: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessible", "put_accValue", 27usize, |this| ((**(this as *const *const IAccessible_Vtbl)).put_accValue)(this, varchild, szvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            accParent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            accParent: 0,
+            accChildCount,
+            #[cfg(feature = "Win32_System_Com")]
+            get_accChild,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            get_accChild: 0,
+            get_accName,
+            get_accValue,
+            get_accDescription,
+            get_accRole,
+            get_accState,
+            get_accHelp,
+            get_accHelpTopic,
+            get_accKeyboardShortcut,
+            accFocus,
+            accSelection,
+            get_accDefaultAction,
+            accSelect,
+            accLocation,
+            accNavigate,
+            accHitTest,
+            accDoDefaultAction,
+            put_accName,
+            put_accValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccessible as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAccessibleEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetObjectForChild(this: *mut core::ffi::c_void, idchild: i32, pretval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessibleEx", "GetObjectForChild", 3usize, |this| ((**(this as *const *const IAccessibleEx_Vtbl)).GetObjectForChild)(this, idchild, pretval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetIAccessiblePair(this: *mut core::ffi::c_void, ppacc: *mut *mut core::ffi::c_void, pidchild: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessibleEx", "GetIAccessiblePair", 4usize, |this| ((**(this as *const *const IAccessibleEx_Vtbl)).GetIAccessiblePair)(this, ppacc, pidchild), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetRuntimeId(this: *mut core::ffi::c_void, pretval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessibleEx", "GetRuntimeId", 5usize, |this| ((**(this as *const *const IAccessibleEx_Vtbl)).GetRuntimeId)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertReturnedElement(this: *mut core::ffi::c_void, pin: *mut core::ffi::c_void, ppretvalout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessibleEx", "ConvertReturnedElement", 6usize, |this| ((**(this as *const *const IAccessibleEx_Vtbl)).ConvertReturnedElement)(this, pin, ppretvalout), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetObjectForChild,
+            #[cfg(feature = "Win32_System_Com")]
+            GetIAccessiblePair,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetIAccessiblePair: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetRuntimeId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetRuntimeId: 0,
+            ConvertReturnedElement,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccessibleEx as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAccessibleHandler_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AccessibleObjectFromID(this: *mut core::ffi::c_void, hwnd: i32, lobjectid: i32, piaccessible: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessibleHandler", "AccessibleObjectFromID", 3usize, |this| ((**(this as *const *const IAccessibleHandler_Vtbl)).AccessibleObjectFromID)(this, hwnd, lobjectid, piaccessible), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            AccessibleObjectFromID,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AccessibleObjectFromID: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccessibleHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAccessibleHostingElementProviders_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetEmbeddedFragmentRoots(this: *mut core::ffi::c_void, pretval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessibleHostingElementProviders", "GetEmbeddedFragmentRoots", 3usize, |this| ((**(this as *const *const IAccessibleHostingElementProviders_Vtbl)).GetEmbeddedFragmentRoots)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObjectIdForProvider(this: *mut core::ffi::c_void, pprovider: *mut core::ffi::c_void, pidobject: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessibleHostingElementProviders", "GetObjectIdForProvider", 4usize, |this| ((**(this as *const *const IAccessibleHostingElementProviders_Vtbl)).GetObjectIdForProvider)(this, pprovider, pidobject), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetEmbeddedFragmentRoots,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetEmbeddedFragmentRoots: 0,
+            GetObjectIdForProvider,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccessibleHostingElementProviders as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAccessibleWindowlessSite_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AcquireObjectIdRange(this: *mut core::ffi::c_void, rangesize: i32, prangeowner: *mut core::ffi::c_void, prangebase: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessibleWindowlessSite", "AcquireObjectIdRange", 3usize, |this| ((**(this as *const *const IAccessibleWindowlessSite_Vtbl)).AcquireObjectIdRange)(this, rangesize, prangeowner, prangebase), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseObjectIdRange(this: *mut core::ffi::c_void, rangebase: i32, prangeowner: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessibleWindowlessSite", "ReleaseObjectIdRange", 4usize, |this| ((**(this as *const *const IAccessibleWindowlessSite_Vtbl)).ReleaseObjectIdRange)(this, rangebase, prangeowner), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn QueryObjectIdRanges(this: *mut core::ffi::c_void, prangesowner: *mut core::ffi::c_void, psaranges: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessibleWindowlessSite", "QueryObjectIdRanges", 5usize, |this| ((**(this as *const *const IAccessibleWindowlessSite_Vtbl)).QueryObjectIdRanges)(this, prangesowner, psaranges), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetParentAccessible(this: *mut core::ffi::c_void, ppparent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccessibleWindowlessSite", "GetParentAccessible", 6usize, |this| ((**(this as *const *const IAccessibleWindowlessSite_Vtbl)).GetParentAccessible)(this, ppparent), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AcquireObjectIdRange,
+            ReleaseObjectIdRange,
+            #[cfg(feature = "Win32_System_Com")]
+            QueryObjectIdRanges,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            QueryObjectIdRanges: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetParentAccessible,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetParentAccessible: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccessibleWindowlessSite as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAnnotationProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AnnotationTypeId(this: *mut core::ffi::c_void, retval: *mut UIA_ANNOTATIONTYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnnotationProvider", "AnnotationTypeId", 3usize, |this| ((**(this as *const *const IAnnotationProvider_Vtbl)).AnnotationTypeId)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn AnnotationTypeName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnnotationProvider", "AnnotationTypeName", 4usize, |this| ((**(this as *const *const IAnnotationProvider_Vtbl)).AnnotationTypeName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Author(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnnotationProvider", "Author", 5usize, |this| ((**(this as *const *const IAnnotationProvider_Vtbl)).Author)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DateTime(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnnotationProvider", "DateTime", 6usize, |this| ((**(this as *const *const IAnnotationProvider_Vtbl)).DateTime)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Target(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnnotationProvider", "Target", 7usize, |this| ((**(this as *const *const IAnnotationProvider_Vtbl)).Target)(this, retval), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AnnotationTypeId, AnnotationTypeName, Author, DateTime, Target }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAnnotationProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICustomNavigationProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Navigate(this: *mut core::ffi::c_void, direction: NavigateDirection, pretval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICustomNavigationProvider", "Navigate", 3usize, |this| ((**(this as *const *const ICustomNavigationProvider_Vtbl)).Navigate)(this, direction, pretval), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Navigate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICustomNavigationProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDockProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDockPosition(this: *mut core::ffi::c_void, dockposition: DockPosition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDockProvider", "SetDockPosition", 3usize, |this| ((**(this as *const *const IDockProvider_Vtbl)).SetDockPosition)(this, dockposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn DockPosition(this: *mut core::ffi::c_void, pretval: *mut DockPosition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDockProvider", "DockPosition", 4usize, |this| ((**(this as *const *const IDockProvider_Vtbl)).DockPosition)(this, pretval), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetDockPosition, DockPosition }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDockProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDragProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsGrabbed(this: *mut core::ffi::c_void, pretval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDragProvider", "IsGrabbed", 3usize, |this| ((**(this as *const *const IDragProvider_Vtbl)).IsGrabbed)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DropEffect(this: *mut core::ffi::c_void, pretval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDragProvider", "DropEffect", 4usize, |this| ((**(this as *const *const IDragProvider_Vtbl)).DropEffect)(this, pretval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DropEffects(this: *mut core::ffi::c_void, pretval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDragProvider", "DropEffects", 5usize, |this| ((**(this as *const *const IDragProvider_Vtbl)).DropEffects)(this, pretval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetGrabbedItems(this: *mut core::ffi::c_void, pretval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDragProvider", "GetGrabbedItems", 6usize, |this| ((**(this as *const *const IDragProvider_Vtbl)).GetGrabbedItems)(this, pretval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsGrabbed,
+            DropEffect,
+            #[cfg(feature = "Win32_System_Com")]
+            DropEffects,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DropEffects: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetGrabbedItems,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetGrabbedItems: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDragProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDropTargetProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DropTargetEffect(this: *mut core::ffi::c_void, pretval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDropTargetProvider", "DropTargetEffect", 3usize, |this| ((**(this as *const *const IDropTargetProvider_Vtbl)).DropTargetEffect)(this, pretval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DropTargetEffects(this: *mut core::ffi::c_void, pretval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDropTargetProvider", "DropTargetEffects", 4usize, |this| ((**(this as *const *const IDropTargetProvider_Vtbl)).DropTargetEffects)(this, pretval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            DropTargetEffect,
+            #[cfg(feature = "Win32_System_Com")]
+            DropTargetEffects,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DropTargetEffects: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDropTargetProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IExpandCollapseProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Expand(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExpandCollapseProvider", "Expand", 3usize, |this| ((**(this as *const *const IExpandCollapseProvider_Vtbl)).Expand)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Collapse(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExpandCollapseProvider", "Collapse", 4usize, |this| ((**(this as *const *const IExpandCollapseProvider_Vtbl)).Collapse)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExpandCollapseState(this: *mut core::ffi::c_void, pretval: *mut ExpandCollapseState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IExpandCollapseProvider", "ExpandCollapseState", 5usize, |this| ((**(this as *const *const IExpandCollapseProvider_Vtbl)).ExpandCollapseState)(this, pretval), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Expand, Collapse, ExpandCollapseState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IExpandCollapseProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGridItemProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Row(this: *mut core::ffi::c_void, pretval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGridItemProvider", "Row", 3usize, |this| ((**(this as *const *const IGridItemProvider_Vtbl)).Row)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Column(this: *mut core::ffi::c_void, pretval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGridItemProvider", "Column", 4usize, |this| ((**(this as *const *const IGridItemProvider_Vtbl)).Column)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RowSpan(this: *mut core::ffi::c_void, pretval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGridItemProvider", "RowSpan", 5usize, |this| ((**(this as *const *const IGridItemProvider_Vtbl)).RowSpan)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ColumnSpan(this: *mut core::ffi::c_void, pretval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGridItemProvider", "ColumnSpan", 6usize, |this| ((**(this as *const *const IGridItemProvider_Vtbl)).ColumnSpan)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContainingGrid(this: *mut core::ffi::c_void, pretval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGridItemProvider", "ContainingGrid", 7usize, |this| ((**(this as *const *const IGridItemProvider_Vtbl)).ContainingGrid)(this, pretval), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Row, Column, RowSpan, ColumnSpan, ContainingGrid }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGridItemProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGridProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetItem(this: *mut core::ffi::c_void, row: i32, column: i32, pretval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGridProvider", "GetItem", 3usize, |this| ((**(this as *const *const IGridProvider_Vtbl)).GetItem)(this, row, column, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RowCount(this: *mut core::ffi::c_void, pretval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGridProvider", "RowCount", 4usize, |this| ((**(this as *const *const IGridProvider_Vtbl)).RowCount)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ColumnCount(this: *mut core::ffi::c_void, pretval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGridProvider", "ColumnCount", 5usize, |this| ((**(this as *const *const IGridProvider_Vtbl)).ColumnCount)(this, pretval), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetItem, RowCount, ColumnCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGridProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInvokeProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Invoke(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInvokeProvider", "Invoke", 3usize, |this| ((**(this as *const *const IInvokeProvider_Vtbl)).Invoke)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Invoke }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInvokeProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IItemContainerProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FindItemByProperty(this: *mut core::ffi::c_void, pstartafter: *mut core::ffi::c_void, propertyid: UIA_PROPERTY_ID, value: core::mem::MaybeUninit<windows_core::VARIANT>, pfound: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IItemContainerProvider", "FindItemByProperty", 3usize, |this| ((**(this as *const *const IItemContainerProvider_Vtbl)).FindItemByProperty)(this, pstartafter, propertyid, value, pfound), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FindItemByProperty }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IItemContainerProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ILegacyIAccessibleProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Select(this: *mut core::ffi::c_void, flagsselect: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyIAccessibleProvider", "Select", 3usize, |this| ((**(this as *const *const ILegacyIAccessibleProvider_Vtbl)).Select)(this, flagsselect), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoDefaultAction(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyIAccessibleProvider", "DoDefaultAction", 4usize, |this| ((**(this as *const *const ILegacyIAccessibleProvider_Vtbl)).DoDefaultAction)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, szvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyIAccessibleProvider", "SetValue", 5usize, |this| ((**(this as *const *const ILegacyIAccessibleProvider_Vtbl)).SetValue)(this, szvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetIAccessible(this: *mut core::ffi::c_void, ppaccessible: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyIAccessibleProvider", "GetIAccessible", 6usize, |this| ((**(this as *const *const ILegacyIAccessibleProvider_Vtbl)).GetIAccessible)(this, ppaccessible), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChildId(this: *mut core::ffi::c_void, pretval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyIAccessibleProvider", "ChildId", 7usize, |this| ((**(this as *const *const ILegacyIAccessibleProvider_Vtbl)).ChildId)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, pszname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyIAccessibleProvider", "Name", 8usize, |this| ((**(this as *const *const ILegacyIAccessibleProvider_Vtbl)).Name)(this, pszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, pszvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyIAccessibleProvider", "Value", 9usize, |this| ((**(this as *const *const ILegacyIAccessibleProvider_Vtbl)).Value)(this, pszvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Description(this: *mut core::ffi::c_void, pszdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyIAccessibleProvider", "Description", 10usize, |this| ((**(this as *const *const ILegacyIAccessibleProvider_Vtbl)).Description)(this, pszdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn Role(this: *mut core::ffi::c_void, pdwrole: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyIAccessibleProvider", "Role", 11usize, |this| ((**(this as *const *const ILegacyIAccessibleProvider_Vtbl)).Role)(this, pdwrole), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, pdwstate: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyIAccessibleProvider", "State", 12usize, |this| ((**(this as *const *const ILegacyIAccessibleProvider_Vtbl)).State)(this, pdwstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Help(this: *mut core::ffi::c_void, pszhelp: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyIAccessibleProvider", "Help", 13usize, |this| ((**(this as *const *const ILegacyIAccessibleProvider_Vtbl)).Help)(this, pszhelp), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyboardShortcut(this: *mut core::ffi::c_void, pszkeyboardshortcut: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyIAccessibleProvider", "KeyboardShortcut", 14usize, |this| ((**(this as *const *const ILegacyIAccessibleProvider_Vtbl)).KeyboardShortcut)(this, pszkeyboardshortcut), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSelection(this: *mut core::ffi::c_void, pvarselectedchildren: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyIAccessibleProvider", "GetSelection", 15usize, |this| ((**(this as *const *const ILegacyIAccessibleProvider_Vtbl)).GetSelection)(this, pvarselectedchildren), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultAction(this: *mut core::ffi::c_void, pszdefaultaction: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILegacyIAccessibleProvider", "DefaultAction", 16usize, |this| ((**(this as *const *const ILegacyIAccessibleProvider_Vtbl)).DefaultAction)(this, pszdefaultaction), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Select,
+            DoDefaultAction,
+            SetValue,
+            #[cfg(feature = "Win32_System_Com")]
+            GetIAccessible,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetIAccessible: 0,
+            ChildId,
+            Name,
+            Value,
+            Description,
+            Role,
+            State,
+            Help,
+            KeyboardShortcut,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSelection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSelection: 0,
+            DefaultAction,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILegacyIAccessibleProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IMultipleViewProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetViewName(this: *mut core::ffi::c_void, viewid: i32, pretval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultipleViewProvider", "GetViewName", 3usize, |this| ((**(this as *const *const IMultipleViewProvider_Vtbl)).GetViewName)(this, viewid, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentView(this: *mut core::ffi::c_void, viewid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultipleViewProvider", "SetCurrentView", 4usize, |this| ((**(this as *const *const IMultipleViewProvider_Vtbl)).SetCurrentView)(this, viewid), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentView(this: *mut core::ffi::c_void, pretval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultipleViewProvider", "CurrentView", 5usize, |this| ((**(this as *const *const IMultipleViewProvider_Vtbl)).CurrentView)(this, pretval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSupportedViews(this: *mut core::ffi::c_void, pretval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMultipleViewProvider", "GetSupportedViews", 6usize, |this| ((**(this as *const *const IMultipleViewProvider_Vtbl)).GetSupportedViews)(this, pretval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetViewName,
+            SetCurrentView,
+            CurrentView,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSupportedViews,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSupportedViews: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMultipleViewProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IObjectModelProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetUnderlyingObjectModel(this: *mut core::ffi::c_void, ppunknown: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectModelProvider", "GetUnderlyingObjectModel", 3usize, |this| ((**(this as *const *const IObjectModelProvider_Vtbl)).GetUnderlyingObjectModel)(this, ppunknown), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetUnderlyingObjectModel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IObjectModelProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IProxyProviderWinEventHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RespondToWinEvent(this: *mut core::ffi::c_void, idwinevent: u32, hwnd: super::super::Foundation::HWND, idobject: i32, idchild: i32, psink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProxyProviderWinEventHandler", "RespondToWinEvent", 3usize, |this| ((**(this as *const *const IProxyProviderWinEventHandler_Vtbl)).RespondToWinEvent)(this, idwinevent, hwnd, idobject, idchild, psink), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RespondToWinEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProxyProviderWinEventHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IProxyProviderWinEventSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddAutomationPropertyChangedEvent(this: *mut core::ffi::c_void, pprovider: *mut core::ffi::c_void, id: UIA_PROPERTY_ID, newvalue: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProxyProviderWinEventSink", "AddAutomationPropertyChangedEvent", 3usize, |this| ((**(this as *const *const IProxyProviderWinEventSink_Vtbl)).AddAutomationPropertyChangedEvent)(this, pprovider, id, newvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddAutomationEvent(this: *mut core::ffi::c_void, pprovider: *mut core::ffi::c_void, id: UIA_EVENT_ID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProxyProviderWinEventSink", "AddAutomationEvent", 4usize, |this| ((**(this as *const *const IProxyProviderWinEventSink_Vtbl)).AddAutomationEvent)(this, pprovider, id), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddStructureChangedEvent(this: *mut core::ffi::c_void, pprovider: *mut core::ffi::c_void, structurechangetype: StructureChangeType, runtimeid: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IProxyProviderWinEventSink", "AddStructureChangedEvent", 5usize, |this| ((**(this as *const *const IProxyProviderWinEventSink_Vtbl)).AddStructureChangedEvent)(this, pprovider, structurechangetype, runtimeid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddAutomationPropertyChangedEvent,
+            AddAutomationEvent,
+            #[cfg(feature = "Win32_System_Com")]
+            AddStructureChangedEvent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddStructureChangedEvent: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IProxyProviderWinEventSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRangeValueProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, val: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRangeValueProvider", "SetValue", 3usize, |this| ((**(this as *const *const IRangeValueProvider_Vtbl)).SetValue)(this, val), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, pretval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRangeValueProvider", "Value", 4usize, |this| ((**(this as *const *const IRangeValueProvider_Vtbl)).Value)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsReadOnly(this: *mut core::ffi::c_void, pretval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRangeValueProvider", "IsReadOnly", 5usize, |this| ((**(this as *const *const IRangeValueProvider_Vtbl)).IsReadOnly)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Maximum(this: *mut core::ffi::c_void, pretval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRangeValueProvider", "Maximum", 6usize, |this| ((**(this as *const *const IRangeValueProvider_Vtbl)).Maximum)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Minimum(this: *mut core::ffi::c_void, pretval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRangeValueProvider", "Minimum", 7usize, |this| ((**(this as *const *const IRangeValueProvider_Vtbl)).Minimum)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn LargeChange(this: *mut core::ffi::c_void, pretval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRangeValueProvider", "LargeChange", 8usize, |this| ((**(this as *const *const IRangeValueProvider_Vtbl)).LargeChange)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SmallChange(this: *mut core::ffi::c_void, pretval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRangeValueProvider", "SmallChange", 9usize, |this| ((**(this as *const *const IRangeValueProvider_Vtbl)).SmallChange)(this, pretval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetValue,
+            Value,
+            IsReadOnly,
+            Maximum,
+            Minimum,
+            LargeChange,
+            SmallChange,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRangeValueProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRawElementProviderAdviseEvents_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AdviseEventAdded(this: *mut core::ffi::c_void, eventid: UIA_EVENT_ID, propertyids: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawElementProviderAdviseEvents", "AdviseEventAdded", 3usize, |this| ((**(this as *const *const IRawElementProviderAdviseEvents_Vtbl)).AdviseEventAdded)(this, eventid, propertyids), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AdviseEventRemoved(this: *mut core::ffi::c_void, eventid: UIA_EVENT_ID, propertyids: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawElementProviderAdviseEvents", "AdviseEventRemoved", 4usize, |this| ((**(this as *const *const IRawElementProviderAdviseEvents_Vtbl)).AdviseEventRemoved)(this, eventid, propertyids), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            AdviseEventAdded,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AdviseEventAdded: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AdviseEventRemoved,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AdviseEventRemoved: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRawElementProviderAdviseEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRawElementProviderFragment_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Navigate(this: *mut core::ffi::c_void, direction: NavigateDirection, pretval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawElementProviderFragment", "Navigate", 3usize, |this| ((**(this as *const *const IRawElementProviderFragment_Vtbl)).Navigate)(this, direction, pretval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetRuntimeId(this: *mut core::ffi::c_void, pretval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawElementProviderFragment", "GetRuntimeId", 4usize, |this| ((**(this as *const *const IRawElementProviderFragment_Vtbl)).GetRuntimeId)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn BoundingRectangle(this: *mut core::ffi::c_void, pretval: *mut UiaRect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawElementProviderFragment", "BoundingRectangle", 5usize, |this| ((**(this as *const *const IRawElementProviderFragment_Vtbl)).BoundingRectangle)(this, pretval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetEmbeddedFragmentRoots(this: *mut core::ffi::c_void, pretval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawElementProviderFragment", "GetEmbeddedFragmentRoots", 6usize, |this| ((**(this as *const *const IRawElementProviderFragment_Vtbl)).GetEmbeddedFragmentRoots)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFocus(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawElementProviderFragment", "SetFocus", 7usize, |this| ((**(this as *const *const IRawElementProviderFragment_Vtbl)).SetFocus)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn FragmentRoot(this: *mut core::ffi::c_void, pretval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawElementProviderFragment", "FragmentRoot", 8usize, |this| ((**(this as *const *const IRawElementProviderFragment_Vtbl)).FragmentRoot)(this, pretval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Navigate,
+            #[cfg(feature = "Win32_System_Com")]
+            GetRuntimeId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetRuntimeId: 0,
+            BoundingRectangle,
+            #[cfg(feature = "Win32_System_Com")]
+            GetEmbeddedFragmentRoots,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetEmbeddedFragmentRoots: 0,
+            SetFocus,
+            FragmentRoot,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRawElementProviderFragment as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRawElementProviderFragmentRoot_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ElementProviderFromPoint(this: *mut core::ffi::c_void, x: f64, y: f64, pretval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawElementProviderFragmentRoot", "ElementProviderFromPoint", 3usize, |this| ((**(this as *const *const IRawElementProviderFragmentRoot_Vtbl)).ElementProviderFromPoint)(this, x, y, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFocus(this: *mut core::ffi::c_void, pretval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawElementProviderFragmentRoot", "GetFocus", 4usize, |this| ((**(this as *const *const IRawElementProviderFragmentRoot_Vtbl)).GetFocus)(this, pretval), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ElementProviderFromPoint, GetFocus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRawElementProviderFragmentRoot as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRawElementProviderHostingAccessibles_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetEmbeddedAccessibles(this: *mut core::ffi::c_void, pretval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawElementProviderHostingAccessibles", "GetEmbeddedAccessibles", 3usize, |this| ((**(this as *const *const IRawElementProviderHostingAccessibles_Vtbl)).GetEmbeddedAccessibles)(this, pretval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetEmbeddedAccessibles,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetEmbeddedAccessibles: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRawElementProviderHostingAccessibles as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRawElementProviderHwndOverride_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOverrideProviderForHwnd(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, pretval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawElementProviderHwndOverride", "GetOverrideProviderForHwnd", 3usize, |this| ((**(this as *const *const IRawElementProviderHwndOverride_Vtbl)).GetOverrideProviderForHwnd)(this, hwnd, pretval), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetOverrideProviderForHwnd }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRawElementProviderHwndOverride as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRawElementProviderSimple_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProviderOptions(this: *mut core::ffi::c_void, pretval: *mut ProviderOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawElementProviderSimple", "ProviderOptions", 3usize, |this| ((**(this as *const *const IRawElementProviderSimple_Vtbl)).ProviderOptions)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPatternProvider(this: *mut core::ffi::c_void, patternid: UIA_PATTERN_ID, pretval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawElementProviderSimple", "GetPatternProvider", 4usize, |this| ((**(this as *const *const IRawElementProviderSimple_Vtbl)).GetPatternProvider)(this, patternid, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyValue(this: *mut core::ffi::c_void, propertyid: UIA_PROPERTY_ID, pretval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawElementProviderSimple", "GetPropertyValue", 5usize, |this| ((**(this as *const *const IRawElementProviderSimple_Vtbl)).GetPropertyValue)(this, propertyid, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn HostRawElementProvider(this: *mut core::ffi::c_void, pretval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawElementProviderSimple", "HostRawElementProvider", 6usize, |this| ((**(this as *const *const IRawElementProviderSimple_Vtbl)).HostRawElementProvider)(this, pretval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProviderOptions,
+            GetPatternProvider,
+            GetPropertyValue,
+            HostRawElementProvider,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRawElementProviderSimple as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRawElementProviderSimple2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowContextMenu(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawElementProviderSimple2", "ShowContextMenu", 7usize, |this| ((**(this as *const *const IRawElementProviderSimple2_Vtbl)).ShowContextMenu)(this), |result| Some(*result))
+        }
+        Self { base__: <IRawElementProviderSimple_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShowContextMenu }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRawElementProviderSimple2 as windows_core::Interface>::IID || <IRawElementProviderSimple_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRawElementProviderSimple3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetMetadataValue(this: *mut core::ffi::c_void, targetid: i32, metadataid: UIA_METADATA_ID, returnval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawElementProviderSimple3", "GetMetadataValue", 8usize, |this| ((**(this as *const *const IRawElementProviderSimple3_Vtbl)).GetMetadataValue)(this, targetid, metadataid, returnval), |result| Some(*result))
+        }
+        Self { base__: <IRawElementProviderSimple2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetMetadataValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRawElementProviderSimple3 as windows_core::Interface>::IID || <IRawElementProviderSimple2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRawElementProviderWindowlessSite_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAdjacentFragment(this: *mut core::ffi::c_void, direction: NavigateDirection, ppparent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawElementProviderWindowlessSite", "GetAdjacentFragment", 3usize, |this| ((**(this as *const *const IRawElementProviderWindowlessSite_Vtbl)).GetAdjacentFragment)(this, direction, ppparent), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetRuntimeIdPrefix(this: *mut core::ffi::c_void, pretval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRawElementProviderWindowlessSite", "GetRuntimeIdPrefix", 4usize, |this| ((**(this as *const *const IRawElementProviderWindowlessSite_Vtbl)).GetRuntimeIdPrefix)(this, pretval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetAdjacentFragment,
+            #[cfg(feature = "Win32_System_Com")]
+            GetRuntimeIdPrefix,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetRuntimeIdPrefix: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRawElementProviderWindowlessSite as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRichEditUiaInformation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBoundaryRectangle(this: *mut core::ffi::c_void, puiarect: *mut UiaRect) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichEditUiaInformation", "GetBoundaryRectangle", 3usize, |this| ((**(this as *const *const IRichEditUiaInformation_Vtbl)).GetBoundaryRectangle)(this, puiarect), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsVisible(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichEditUiaInformation", "IsVisible", 4usize, |this| ((**(this as *const *const IRichEditUiaInformation_Vtbl)).IsVisible)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetBoundaryRectangle, IsVisible }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRichEditUiaInformation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRicheditWindowlessAccessibility_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateProvider(this: *mut core::ffi::c_void, psite: *mut core::ffi::c_void, ppprovider: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRicheditWindowlessAccessibility", "CreateProvider", 3usize, |this| ((**(this as *const *const IRicheditWindowlessAccessibility_Vtbl)).CreateProvider)(this, psite, ppprovider), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateProvider }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRicheditWindowlessAccessibility as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IScrollItemProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ScrollIntoView(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScrollItemProvider", "ScrollIntoView", 3usize, |this| ((**(this as *const *const IScrollItemProvider_Vtbl)).ScrollIntoView)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ScrollIntoView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScrollItemProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IScrollProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Scroll(this: *mut core::ffi::c_void, horizontalamount: ScrollAmount, verticalamount: ScrollAmount) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScrollProvider", "Scroll", 3usize, |this| ((**(this as *const *const IScrollProvider_Vtbl)).Scroll)(this, horizontalamount, verticalamount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScrollPercent(this: *mut core::ffi::c_void, horizontalpercent: f64, verticalpercent: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScrollProvider", "SetScrollPercent", 4usize, |this| ((**(this as *const *const IScrollProvider_Vtbl)).SetScrollPercent)(this, horizontalpercent, verticalpercent), |result| Some(*result))
+        }
+        unsafe extern "system" fn HorizontalScrollPercent(this: *mut core::ffi::c_void, pretval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScrollProvider", "HorizontalScrollPercent", 5usize, |this| ((**(this as *const *const IScrollProvider_Vtbl)).HorizontalScrollPercent)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn VerticalScrollPercent(this: *mut core::ffi::c_void, pretval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScrollProvider", "VerticalScrollPercent", 6usize, |this| ((**(this as *const *const IScrollProvider_Vtbl)).VerticalScrollPercent)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn HorizontalViewSize(this: *mut core::ffi::c_void, pretval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScrollProvider", "HorizontalViewSize", 7usize, |this| ((**(this as *const *const IScrollProvider_Vtbl)).HorizontalViewSize)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn VerticalViewSize(this: *mut core::ffi::c_void, pretval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScrollProvider", "VerticalViewSize", 8usize, |this| ((**(this as *const *const IScrollProvider_Vtbl)).VerticalViewSize)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn HorizontallyScrollable(this: *mut core::ffi::c_void, pretval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScrollProvider", "HorizontallyScrollable", 9usize, |this| ((**(this as *const *const IScrollProvider_Vtbl)).HorizontallyScrollable)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn VerticallyScrollable(this: *mut core::ffi::c_void, pretval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScrollProvider", "VerticallyScrollable", 10usize, |this| ((**(this as *const *const IScrollProvider_Vtbl)).VerticallyScrollable)(this, pretval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Scroll,
+            SetScrollPercent,
+            HorizontalScrollPercent,
+            VerticalScrollPercent,
+            HorizontalViewSize,
+            VerticalViewSize,
+            HorizontallyScrollable,
+            VerticallyScrollable,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScrollProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISelectionItemProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Select(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISelectionItemProvider", "Select", 3usize, |this| ((**(this as *const *const ISelectionItemProvider_Vtbl)).Select)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddToSelection(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISelectionItemProvider", "AddToSelection", 4usize, |this| ((**(this as *const *const ISelectionItemProvider_Vtbl)).AddToSelection)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFromSelection(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISelectionItemProvider", "RemoveFromSelection", 5usize, |this| ((**(this as *const *const ISelectionItemProvider_Vtbl)).RemoveFromSelection)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSelected(this: *mut core::ffi::c_void, pretval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISelectionItemProvider", "IsSelected", 6usize, |this| ((**(this as *const *const ISelectionItemProvider_Vtbl)).IsSelected)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectionContainer(this: *mut core::ffi::c_void, pretval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISelectionItemProvider", "SelectionContainer", 7usize, |this| ((**(this as *const *const ISelectionItemProvider_Vtbl)).SelectionContainer)(this, pretval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Select,
+            AddToSelection,
+            RemoveFromSelection,
+            IsSelected,
+            SelectionContainer,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISelectionItemProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISelectionProvider_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSelection(this: *mut core::ffi::c_void, pretval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISelectionProvider", "GetSelection", 3usize, |this| ((**(this as *const *const ISelectionProvider_Vtbl)).GetSelection)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanSelectMultiple(this: *mut core::ffi::c_void, pretval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISelectionProvider", "CanSelectMultiple", 4usize, |this| ((**(this as *const *const ISelectionProvider_Vtbl)).CanSelectMultiple)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSelectionRequired(this: *mut core::ffi::c_void, pretval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISelectionProvider", "IsSelectionRequired", 5usize, |this| ((**(this as *const *const ISelectionProvider_Vtbl)).IsSelectionRequired)(this, pretval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSelection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSelection: 0,
+            CanSelectMultiple,
+            IsSelectionRequired,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISelectionProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISelectionProvider2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FirstSelectedItem(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISelectionProvider2", "FirstSelectedItem", 6usize, |this| ((**(this as *const *const ISelectionProvider2_Vtbl)).FirstSelectedItem)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn LastSelectedItem(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISelectionProvider2", "LastSelectedItem", 7usize, |this| ((**(this as *const *const ISelectionProvider2_Vtbl)).LastSelectedItem)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentSelectedItem(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISelectionProvider2", "CurrentSelectedItem", 8usize, |this| ((**(this as *const *const ISelectionProvider2_Vtbl)).CurrentSelectedItem)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ItemCount(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISelectionProvider2", "ItemCount", 9usize, |this| ((**(this as *const *const ISelectionProvider2_Vtbl)).ItemCount)(this, retval), |result| Some(*result))
+        }
+        Self { base__: <ISelectionProvider_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FirstSelectedItem, LastSelectedItem, CurrentSelectedItem, ItemCount }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISelectionProvider2 as windows_core::Interface>::IID || <ISelectionProvider_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISpreadsheetItemProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Formula(this: *mut core::ffi::c_void, pretval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpreadsheetItemProvider", "Formula", 3usize, |this| ((**(this as *const *const ISpreadsheetItemProvider_Vtbl)).Formula)(this, pretval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetAnnotationObjects(this: *mut core::ffi::c_void, pretval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpreadsheetItemProvider", "GetAnnotationObjects", 4usize, |this| ((**(this as *const *const ISpreadsheetItemProvider_Vtbl)).GetAnnotationObjects)(this, pretval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetAnnotationTypes(this: *mut core::ffi::c_void, pretval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpreadsheetItemProvider", "GetAnnotationTypes", 5usize, |this| ((**(this as *const *const ISpreadsheetItemProvider_Vtbl)).GetAnnotationTypes)(this, pretval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Formula,
+            #[cfg(feature = "Win32_System_Com")]
+            GetAnnotationObjects,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetAnnotationObjects: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetAnnotationTypes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetAnnotationTypes: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpreadsheetItemProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpreadsheetProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetItemByName(this: *mut core::ffi::c_void, name: windows_core::PCWSTR, pretval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpreadsheetProvider", "GetItemByName", 3usize, |this| ((**(this as *const *const ISpreadsheetProvider_Vtbl)).GetItemByName)(this, name, pretval), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetItemByName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpreadsheetProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IStylesProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StyleId(this: *mut core::ffi::c_void, retval: *mut UIA_STYLE_ID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStylesProvider", "StyleId", 3usize, |this| ((**(this as *const *const IStylesProvider_Vtbl)).StyleId)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn StyleName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStylesProvider", "StyleName", 4usize, |this| ((**(this as *const *const IStylesProvider_Vtbl)).StyleName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn FillColor(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStylesProvider", "FillColor", 5usize, |this| ((**(this as *const *const IStylesProvider_Vtbl)).FillColor)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn FillPatternStyle(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStylesProvider", "FillPatternStyle", 6usize, |this| ((**(this as *const *const IStylesProvider_Vtbl)).FillPatternStyle)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shape(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStylesProvider", "Shape", 7usize, |this| ((**(this as *const *const IStylesProvider_Vtbl)).Shape)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn FillPatternColor(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStylesProvider", "FillPatternColor", 8usize, |this| ((**(this as *const *const IStylesProvider_Vtbl)).FillPatternColor)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExtendedProperties(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStylesProvider", "ExtendedProperties", 9usize, |this| ((**(this as *const *const IStylesProvider_Vtbl)).ExtendedProperties)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StyleId,
+            StyleName,
+            FillColor,
+            FillPatternStyle,
+            Shape,
+            FillPatternColor,
+            ExtendedProperties,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStylesProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISynchronizedInputProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartListening(this: *mut core::ffi::c_void, inputtype: SynchronizedInputType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISynchronizedInputProvider", "StartListening", 3usize, |this| ((**(this as *const *const ISynchronizedInputProvider_Vtbl)).StartListening)(this, inputtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISynchronizedInputProvider", "Cancel", 4usize, |this| ((**(this as *const *const ISynchronizedInputProvider_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartListening, Cancel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISynchronizedInputProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITableItemProvider_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetRowHeaderItems(this: *mut core::ffi::c_void, pretval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITableItemProvider", "GetRowHeaderItems", 3usize, |this| ((**(this as *const *const ITableItemProvider_Vtbl)).GetRowHeaderItems)(this, pretval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetColumnHeaderItems(this: *mut core::ffi::c_void, pretval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITableItemProvider", "GetColumnHeaderItems", 4usize, |this| ((**(this as *const *const ITableItemProvider_Vtbl)).GetColumnHeaderItems)(this, pretval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetRowHeaderItems,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetRowHeaderItems: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetColumnHeaderItems,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetColumnHeaderItems: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITableItemProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITableProvider_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetRowHeaders(this: *mut core::ffi::c_void, pretval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITableProvider", "GetRowHeaders", 3usize, |this| ((**(this as *const *const ITableProvider_Vtbl)).GetRowHeaders)(this, pretval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetColumnHeaders(this: *mut core::ffi::c_void, pretval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITableProvider", "GetColumnHeaders", 4usize, |this| ((**(this as *const *const ITableProvider_Vtbl)).GetColumnHeaders)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RowOrColumnMajor(this: *mut core::ffi::c_void, pretval: *mut RowOrColumnMajor) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITableProvider", "RowOrColumnMajor", 5usize, |this| ((**(this as *const *const ITableProvider_Vtbl)).RowOrColumnMajor)(this, pretval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetRowHeaders,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetRowHeaders: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetColumnHeaders,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetColumnHeaders: 0,
+            RowOrColumnMajor,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITableProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITextChildProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TextContainer(this: *mut core::ffi::c_void, pretval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextChildProvider", "TextContainer", 3usize, |this| ((**(this as *const *const ITextChildProvider_Vtbl)).TextContainer)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn TextRange(this: *mut core::ffi::c_void, pretval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextChildProvider", "TextRange", 4usize, |this| ((**(this as *const *const ITextChildProvider_Vtbl)).TextRange)(this, pretval), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TextContainer, TextRange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextChildProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITextEditProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetActiveComposition(this: *mut core::ffi::c_void, pretval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextEditProvider", "GetActiveComposition", 9usize, |this| ((**(this as *const *const ITextEditProvider_Vtbl)).GetActiveComposition)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConversionTarget(this: *mut core::ffi::c_void, pretval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextEditProvider", "GetConversionTarget", 10usize, |this| ((**(this as *const *const ITextEditProvider_Vtbl)).GetConversionTarget)(this, pretval), |result| Some(*result))
+        }
+        Self { base__: <ITextProvider_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetActiveComposition, GetConversionTarget }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextEditProvider as windows_core::Interface>::IID || <ITextProvider_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITextProvider_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSelection(this: *mut core::ffi::c_void, pretval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextProvider", "GetSelection", 3usize, |this| ((**(this as *const *const ITextProvider_Vtbl)).GetSelection)(this, pretval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetVisibleRanges(this: *mut core::ffi::c_void, pretval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextProvider", "GetVisibleRanges", 4usize, |this| ((**(this as *const *const ITextProvider_Vtbl)).GetVisibleRanges)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RangeFromChild(this: *mut core::ffi::c_void, childelement: *mut core::ffi::c_void, pretval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextProvider", "RangeFromChild", 5usize, |this| ((**(this as *const *const ITextProvider_Vtbl)).RangeFromChild)(this, childelement, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RangeFromPoint(this: *mut core::ffi::c_void, point: UiaPoint, pretval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextProvider", "RangeFromPoint", 6usize, |this| ((**(this as *const *const ITextProvider_Vtbl)).RangeFromPoint)(this, point, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DocumentRange(this: *mut core::ffi::c_void, pretval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextProvider", "DocumentRange", 7usize, |this| ((**(this as *const *const ITextProvider_Vtbl)).DocumentRange)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportedTextSelection(this: *mut core::ffi::c_void, pretval: *mut SupportedTextSelection) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextProvider", "SupportedTextSelection", 8usize, |this| ((**(this as *const *const ITextProvider_Vtbl)).SupportedTextSelection)(this, pretval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSelection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSelection: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetVisibleRanges,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetVisibleRanges: 0,
+            RangeFromChild,
+            RangeFromPoint,
+            DocumentRange,
+            SupportedTextSelection,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITextProvider2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RangeFromAnnotation(this: *mut core::ffi::c_void, annotationelement: *mut core::ffi::c_void, pretval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextProvider2", "RangeFromAnnotation", 9usize, |this| ((**(this as *const *const ITextProvider2_Vtbl)).RangeFromAnnotation)(this, annotationelement, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCaretRange(this: *mut core::ffi::c_void, isactive: *mut super::super::Foundation::BOOL, pretval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextProvider2", "GetCaretRange", 10usize, |this| ((**(this as *const *const ITextProvider2_Vtbl)).GetCaretRange)(this, isactive, pretval), |result| Some(*result))
+        }
+        Self { base__: <ITextProvider_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RangeFromAnnotation, GetCaretRange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextProvider2 as windows_core::Interface>::IID || <ITextProvider_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITextRangeProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, pretval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRangeProvider", "Clone", 3usize, |this| ((**(this as *const *const ITextRangeProvider_Vtbl)).Clone)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Compare(this: *mut core::ffi::c_void, range: *mut core::ffi::c_void, pretval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRangeProvider", "Compare", 4usize, |this| ((**(this as *const *const ITextRangeProvider_Vtbl)).Compare)(this, range, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompareEndpoints(this: *mut core::ffi::c_void, endpoint: TextPatternRangeEndpoint, targetrange: *mut core::ffi::c_void, targetendpoint: TextPatternRangeEndpoint, pretval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRangeProvider", "CompareEndpoints", 5usize, |this| ((**(this as *const *const ITextRangeProvider_Vtbl)).CompareEndpoints)(this, endpoint, targetrange, targetendpoint, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExpandToEnclosingUnit(this: *mut core::ffi::c_void, unit: TextUnit) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRangeProvider", "ExpandToEnclosingUnit", 6usize, |this| ((**(this as *const *const ITextRangeProvider_Vtbl)).ExpandToEnclosingUnit)(this, unit), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindAttribute(this: *mut core::ffi::c_void, attributeid: UIA_TEXTATTRIBUTE_ID, val: core::mem::MaybeUninit<windows_core::VARIANT>, backward: super::super::Foundation::BOOL, pretval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRangeProvider", "FindAttribute", 7usize, |this| ((**(this as *const *const ITextRangeProvider_Vtbl)).FindAttribute)(this, attributeid, val, backward, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindText(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::BSTR>, backward: super::super::Foundation::BOOL, ignorecase: super::super::Foundation::BOOL, pretval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRangeProvider", "FindText", 8usize, |this| ((**(this as *const *const ITextRangeProvider_Vtbl)).FindText)(this, text, backward, ignorecase, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributeValue(this: *mut core::ffi::c_void, attributeid: UIA_TEXTATTRIBUTE_ID, pretval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRangeProvider", "GetAttributeValue", 9usize, |this| ((**(this as *const *const ITextRangeProvider_Vtbl)).GetAttributeValue)(this, attributeid, pretval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetBoundingRectangles(this: *mut core::ffi::c_void, pretval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRangeProvider", "GetBoundingRectangles", 10usize, |this| ((**(this as *const *const ITextRangeProvider_Vtbl)).GetBoundingRectangles)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnclosingElement(this: *mut core::ffi::c_void, pretval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRangeProvider", "GetEnclosingElement", 11usize, |this| ((**(this as *const *const ITextRangeProvider_Vtbl)).GetEnclosingElement)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetText(this: *mut core::ffi::c_void, maxlength: i32, pretval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRangeProvider", "GetText", 12usize, |this| ((**(this as *const *const ITextRangeProvider_Vtbl)).GetText)(this, maxlength, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Move(this: *mut core::ffi::c_void, unit: TextUnit, count: i32, pretval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRangeProvider", "Move", 13usize, |this| ((**(this as *const *const ITextRangeProvider_Vtbl)).Move)(this, unit, count, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveEndpointByUnit(this: *mut core::ffi::c_void, endpoint: TextPatternRangeEndpoint, unit: TextUnit, count: i32, pretval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRangeProvider", "MoveEndpointByUnit", 14usize, |this| ((**(this as *const *const ITextRangeProvider_Vtbl)).MoveEndpointByUnit)(this, endpoint, unit, count, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveEndpointByRange(this: *mut core::ffi::c_void, endpoint: TextPatternRangeEndpoint, targetrange: *mut core::ffi::c_void, targetendpoint: TextPatternRangeEndpoint) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRangeProvider", "MoveEndpointByRange", 15usize, |this| ((**(this as *const *const ITextRangeProvider_Vtbl)).MoveEndpointByRange)(this, endpoint, targetrange, targetendpoint), |result| Some(*result))
+        }
+        unsafe extern "system" fn Select(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRangeProvider", "Select", 16usize, |this| ((**(this as *const *const ITextRangeProvider_Vtbl)).Select)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddToSelection(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRangeProvider", "AddToSelection", 17usize, |this| ((**(this as *const *const ITextRangeProvider_Vtbl)).AddToSelection)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFromSelection(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRangeProvider", "RemoveFromSelection", 18usize, |this| ((**(this as *const *const ITextRangeProvider_Vtbl)).RemoveFromSelection)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScrollIntoView(this: *mut core::ffi::c_void, aligntotop: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRangeProvider", "ScrollIntoView", 19usize, |this| ((**(this as *const *const ITextRangeProvider_Vtbl)).ScrollIntoView)(this, aligntotop), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetChildren(this: *mut core::ffi::c_void, pretval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRangeProvider", "GetChildren", 20usize, |this| ((**(this as *const *const ITextRangeProvider_Vtbl)).GetChildren)(this, pretval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Clone,
+            Compare,
+            CompareEndpoints,
+            ExpandToEnclosingUnit,
+            FindAttribute,
+            FindText,
+            GetAttributeValue,
+            #[cfg(feature = "Win32_System_Com")]
+            GetBoundingRectangles,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetBoundingRectangles: 0,
+            GetEnclosingElement,
+            GetText,
+            Move,
+            MoveEndpointByUnit,
+            MoveEndpointByRange,
+            Select,
+            AddToSelection,
+            RemoveFromSelection,
+            ScrollIntoView,
+            #[cfg(feature = "Win32_System_Com")]
+            GetChildren,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetChildren: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextRangeProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITextRangeProvider2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowContextMenu(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRangeProvider2", "ShowContextMenu", 21usize, |this| ((**(this as *const *const ITextRangeProvider2_Vtbl)).ShowContextMenu)(this), |result| Some(*result))
+        }
+        Self { base__: <ITextRangeProvider_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShowContextMenu }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextRangeProvider2 as windows_core::Interface>::IID || <ITextRangeProvider_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IToggleProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Toggle(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToggleProvider", "Toggle", 3usize, |this| ((**(this as *const *const IToggleProvider_Vtbl)).Toggle)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ToggleState(this: *mut core::ffi::c_void, pretval: *mut ToggleState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IToggleProvider", "ToggleState", 4usize, |this| ((**(this as *const *const IToggleProvider_Vtbl)).ToggleState)(this, pretval), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Toggle, ToggleState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IToggleProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransformProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Move(this: *mut core::ffi::c_void, x: f64, y: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransformProvider", "Move", 3usize, |this| ((**(this as *const *const ITransformProvider_Vtbl)).Move)(this, x, y), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resize(this: *mut core::ffi::c_void, width: f64, height: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransformProvider", "Resize", 4usize, |this| ((**(this as *const *const ITransformProvider_Vtbl)).Resize)(this, width, height), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rotate(this: *mut core::ffi::c_void, degrees: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransformProvider", "Rotate", 5usize, |this| ((**(this as *const *const ITransformProvider_Vtbl)).Rotate)(this, degrees), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanMove(this: *mut core::ffi::c_void, pretval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransformProvider", "CanMove", 6usize, |this| ((**(this as *const *const ITransformProvider_Vtbl)).CanMove)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanResize(this: *mut core::ffi::c_void, pretval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransformProvider", "CanResize", 7usize, |this| ((**(this as *const *const ITransformProvider_Vtbl)).CanResize)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanRotate(this: *mut core::ffi::c_void, pretval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransformProvider", "CanRotate", 8usize, |this| ((**(this as *const *const ITransformProvider_Vtbl)).CanRotate)(this, pretval), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Move, Resize, Rotate, CanMove, CanResize, CanRotate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransformProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITransformProvider2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Zoom(this: *mut core::ffi::c_void, zoom: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransformProvider2", "Zoom", 9usize, |this| ((**(this as *const *const ITransformProvider2_Vtbl)).Zoom)(this, zoom), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanZoom(this: *mut core::ffi::c_void, pretval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransformProvider2", "CanZoom", 10usize, |this| ((**(this as *const *const ITransformProvider2_Vtbl)).CanZoom)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ZoomLevel(this: *mut core::ffi::c_void, pretval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransformProvider2", "ZoomLevel", 11usize, |this| ((**(this as *const *const ITransformProvider2_Vtbl)).ZoomLevel)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ZoomMinimum(this: *mut core::ffi::c_void, pretval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransformProvider2", "ZoomMinimum", 12usize, |this| ((**(this as *const *const ITransformProvider2_Vtbl)).ZoomMinimum)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ZoomMaximum(this: *mut core::ffi::c_void, pretval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransformProvider2", "ZoomMaximum", 13usize, |this| ((**(this as *const *const ITransformProvider2_Vtbl)).ZoomMaximum)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ZoomByUnit(this: *mut core::ffi::c_void, zoomunit: ZoomUnit) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITransformProvider2", "ZoomByUnit", 14usize, |this| ((**(this as *const *const ITransformProvider2_Vtbl)).ZoomByUnit)(this, zoomunit), |result| Some(*result))
+        }
+        Self { base__: <ITransformProvider_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Zoom, CanZoom, ZoomLevel, ZoomMinimum, ZoomMaximum, ZoomByUnit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITransformProvider2 as windows_core::Interface>::IID || <ITransformProvider_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CompareElements(this: *mut core::ffi::c_void, el1: *mut core::ffi::c_void, el2: *mut core::ffi::c_void, aresame: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "CompareElements", 3usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).CompareElements)(this, el1, el2, aresame), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CompareRuntimeIds(this: *mut core::ffi::c_void, runtimeid1: *const super::super::System::Com::SAFEARRAY, runtimeid2: *const super::super::System::Com::SAFEARRAY, aresame: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "CompareRuntimeIds", 4usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).CompareRuntimeIds)(this, runtimeid1, runtimeid2, aresame), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRootElement(this: *mut core::ffi::c_void, root: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "GetRootElement", 5usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).GetRootElement)(this, root), |result| Some(*result))
+        }
+        unsafe extern "system" fn ElementFromHandle(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, element: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "ElementFromHandle", 6usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).ElementFromHandle)(this, hwnd, element), |result| Some(*result))
+        }
+        unsafe extern "system" fn ElementFromPoint(this: *mut core::ffi::c_void, pt: super::super::Foundation::POINT, element: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "ElementFromPoint", 7usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).ElementFromPoint)(this, pt, element), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFocusedElement(this: *mut core::ffi::c_void, element: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "GetFocusedElement", 8usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).GetFocusedElement)(this, element), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRootElementBuildCache(this: *mut core::ffi::c_void, cacherequest: *mut core::ffi::c_void, root: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "GetRootElementBuildCache", 9usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).GetRootElementBuildCache)(this, cacherequest, root), |result| Some(*result))
+        }
+        unsafe extern "system" fn ElementFromHandleBuildCache(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, cacherequest: *mut core::ffi::c_void, element: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "ElementFromHandleBuildCache", 10usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).ElementFromHandleBuildCache)(this, hwnd, cacherequest, element), |result| Some(*result))
+        }
+        unsafe extern "system" fn ElementFromPointBuildCache(this: *mut core::ffi::c_void, pt: super::super::Foundation::POINT, cacherequest: *mut core::ffi::c_void, element: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "ElementFromPointBuildCache", 11usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).ElementFromPointBuildCache)(this, pt, cacherequest, element), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFocusedElementBuildCache(this: *mut core::ffi::c_void, cacherequest: *mut core::ffi::c_void, element: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "GetFocusedElementBuildCache", 12usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).GetFocusedElementBuildCache)(this, cacherequest, element), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTreeWalker(this: *mut core::ffi::c_void, pcondition: *mut core::ffi::c_void, walker: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "CreateTreeWalker", 13usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).CreateTreeWalker)(this, pcondition, walker), |result| Some(*result))
+        }
+        unsafe extern "system" fn ControlViewWalker(this: *mut core::ffi::c_void, walker: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "ControlViewWalker", 14usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).ControlViewWalker)(this, walker), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentViewWalker(this: *mut core::ffi::c_void, walker: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "ContentViewWalker", 15usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).ContentViewWalker)(this, walker), |result| Some(*result))
+        }
+        unsafe extern "system" fn RawViewWalker(this: *mut core::ffi::c_void, walker: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "RawViewWalker", 16usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).RawViewWalker)(this, walker), |result| Some(*result))
+        }
+        unsafe extern "system" fn RawViewCondition(this: *mut core::ffi::c_void, condition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "RawViewCondition", 17usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).RawViewCondition)(this, condition), |result| Some(*result))
+        }
+        unsafe extern "system" fn ControlViewCondition(this: *mut core::ffi::c_void, condition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "ControlViewCondition", 18usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).ControlViewCondition)(this, condition), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContentViewCondition(this: *mut core::ffi::c_void, condition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "ContentViewCondition", 19usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).ContentViewCondition)(this, condition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCacheRequest(this: *mut core::ffi::c_void, cacherequest: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "CreateCacheRequest", 20usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).CreateCacheRequest)(this, cacherequest), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateTrueCondition(this: *mut core::ffi::c_void, newcondition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "CreateTrueCondition", 21usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).CreateTrueCondition)(this, newcondition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateFalseCondition(this: *mut core::ffi::c_void, newcondition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "CreateFalseCondition", 22usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).CreateFalseCondition)(this, newcondition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePropertyCondition(this: *mut core::ffi::c_void, propertyid: UIA_PROPERTY_ID, value: core::mem::MaybeUninit<windows_core::VARIANT>, newcondition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "CreatePropertyCondition", 23usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).CreatePropertyCondition)(this, propertyid, value, newcondition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreatePropertyConditionEx(this: *mut core::ffi::c_void, propertyid: UIA_PROPERTY_ID, value: core::mem::MaybeUninit<windows_core::VARIANT>, flags: PropertyConditionFlags, newcondition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "CreatePropertyConditionEx", 24usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).CreatePropertyConditionEx)(this, propertyid, value, flags, newcondition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAndCondition(this: *mut core::ffi::c_void, condition1: *mut core::ffi::c_void, condition2: *mut core::ffi::c_void, newcondition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "CreateAndCondition", 25usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).CreateAndCondition)(this, condition1, condition2, newcondition), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateAndConditionFromArray(this: *mut core::ffi::c_void, conditions: *const super::super::System::Com::SAFEARRAY, newcondition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "CreateAndConditionFromArray", 26usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).CreateAndConditionFromArray)(this, conditions, newcondition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAndConditionFromNativeArray(this: *mut core::ffi::c_void, conditions: *const *mut core::ffi::c_void, conditioncount: i32, newcondition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "CreateAndConditionFromNativeArray", 27usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).CreateAndConditionFromNativeArray)(this, conditions, conditioncount, newcondition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateOrCondition(this: *mut core::ffi::c_void, condition1: *mut core::ffi::c_void, condition2: *mut core::ffi::c_void, newcondition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "CreateOrCondition", 28usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).CreateOrCondition)(this, condition1, condition2, newcondition), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateOrConditionFromArray(this: *mut core::ffi::c_void, conditions: *const super::super::System::Com::SAFEARRAY, newcondition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "CreateOrConditionFromArray", 29usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).CreateOrConditionFromArray)(this, conditions, newcondition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateOrConditionFromNativeArray(this: *mut core::ffi::c_void, conditions: *const *mut core::ffi::c_void, conditioncount: i32, newcondition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "CreateOrConditionFromNativeArray", 30usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).CreateOrConditionFromNativeArray)(this, conditions, conditioncount, newcondition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateNotCondition(this: *mut core::ffi::c_void, condition: *mut core::ffi::c_void, newcondition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "CreateNotCondition", 31usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).CreateNotCondition)(this, condition, newcondition), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddAutomationEventHandler(this: *mut core::ffi::c_void, eventid: UIA_EVENT_ID, element: *mut core::ffi::c_void, scope: TreeScope, cacherequest: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "AddAutomationEventHandler", 32usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).AddAutomationEventHandler)(this, eventid, element, scope, cacherequest, handler), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAutomationEventHandler(this: *mut core::ffi::c_void, eventid: UIA_EVENT_ID, element: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "RemoveAutomationEventHandler", 33usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).RemoveAutomationEventHandler)(this, eventid, element, handler), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPropertyChangedEventHandlerNativeArray(this: *mut core::ffi::c_void, element: *mut core::ffi::c_void, scope: TreeScope, cacherequest: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, propertyarray: *const UIA_PROPERTY_ID, propertycount: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "AddPropertyChangedEventHandlerNativeArray", 34usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).AddPropertyChangedEventHandlerNativeArray)(this, element, scope, cacherequest, handler, propertyarray, propertycount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddPropertyChangedEventHandler(this: *mut core::ffi::c_void, element: *mut core::ffi::c_void, scope: TreeScope, cacherequest: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, propertyarray: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "AddPropertyChangedEventHandler", 35usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).AddPropertyChangedEventHandler)(this, element, scope, cacherequest, handler, propertyarray), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemovePropertyChangedEventHandler(this: *mut core::ffi::c_void, element: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "RemovePropertyChangedEventHandler", 36usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).RemovePropertyChangedEventHandler)(this, element, handler), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddStructureChangedEventHandler(this: *mut core::ffi::c_void, element: *mut core::ffi::c_void, scope: TreeScope, cacherequest: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "AddStructureChangedEventHandler", 37usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).AddStructureChangedEventHandler)(this, element, scope, cacherequest, handler), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStructureChangedEventHandler(this: *mut core::ffi::c_void, element: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "RemoveStructureChangedEventHandler", 38usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).RemoveStructureChangedEventHandler)(this, element, handler), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddFocusChangedEventHandler(this: *mut core::ffi::c_void, cacherequest: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "AddFocusChangedEventHandler", 39usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).AddFocusChangedEventHandler)(this, cacherequest, handler), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFocusChangedEventHandler(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "RemoveFocusChangedEventHandler", 40usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).RemoveFocusChangedEventHandler)(this, handler), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAllEventHandlers(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "RemoveAllEventHandlers", 41usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).RemoveAllEventHandlers)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn IntNativeArrayToSafeArray(this: *mut core::ffi::c_void, array: *const i32, arraycount: i32, safearray: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "IntNativeArrayToSafeArray", 42usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).IntNativeArrayToSafeArray)(this, array, arraycount, safearray), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn IntSafeArrayToNativeArray(this: *mut core::ffi::c_void, intarray: *const super::super::System::Com::SAFEARRAY, array: *mut *mut i32, arraycount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "IntSafeArrayToNativeArray", 43usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).IntSafeArrayToNativeArray)(this, intarray, array, arraycount), |result| Some(*result))
+        }
+        unsafe extern "system" fn RectToVariant(this: *mut core::ffi::c_void, rc: super::super::Foundation::RECT, var: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "RectToVariant", 44usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).RectToVariant)(this, rc, var), |result| Some(*result))
+        }
+        unsafe extern "system" fn VariantToRect(this: *mut core::ffi::c_void, var: core::mem::MaybeUninit<windows_core::VARIANT>, rc: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "VariantToRect", 45usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).VariantToRect)(this, var, rc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SafeArrayToRectNativeArray(this: *mut core::ffi::c_void, rects: *const super::super::System::Com::SAFEARRAY, rectarray: *mut *mut super::super::Foundation::RECT, rectarraycount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "SafeArrayToRectNativeArray", 46usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).SafeArrayToRectNativeArray)(this, rects, rectarray, rectarraycount), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateProxyFactoryEntry(this: *mut core::ffi::c_void, factory: *mut core::ffi::c_void, factoryentry: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "CreateProxyFactoryEntry", 47usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).CreateProxyFactoryEntry)(this, factory, factoryentry), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProxyFactoryMapping(this: *mut core::ffi::c_void, factorymapping: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "ProxyFactoryMapping", 48usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).ProxyFactoryMapping)(this, factorymapping), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyProgrammaticName(this: *mut core::ffi::c_void, property: UIA_PROPERTY_ID, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "GetPropertyProgrammaticName", 49usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).GetPropertyProgrammaticName)(this, property, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPatternProgrammaticName(this: *mut core::ffi::c_void, pattern: UIA_PATTERN_ID, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "GetPatternProgrammaticName", 50usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).GetPatternProgrammaticName)(this, pattern, name), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PollForPotentialSupportedPatterns(this: *mut core::ffi::c_void, pelement: *mut core::ffi::c_void, patternids: *mut *mut super::super::System::Com::SAFEARRAY, patternnames: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "PollForPotentialSupportedPatterns", 51usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).PollForPotentialSupportedPatterns)(this, pelement, patternids, patternnames), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn PollForPotentialSupportedProperties(this: *mut core::ffi::c_void, pelement: *mut core::ffi::c_void, propertyids: *mut *mut super::super::System::Com::SAFEARRAY, propertynames: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "PollForPotentialSupportedProperties", 52usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).PollForPotentialSupportedProperties)(this, pelement, propertyids, propertynames), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckNotSupported(this: *mut core::ffi::c_void, value: core::mem::MaybeUninit<windows_core::VARIANT>, isnotsupported: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "CheckNotSupported", 53usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).CheckNotSupported)(this, value, isnotsupported), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReservedNotSupportedValue(this: *mut core::ffi::c_void, notsupportedvalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "ReservedNotSupportedValue", 54usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).ReservedNotSupportedValue)(this, notsupportedvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReservedMixedAttributeValue(this: *mut core::ffi::c_void, mixedattributevalue: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "ReservedMixedAttributeValue", 55usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).ReservedMixedAttributeValue)(this, mixedattributevalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ElementFromIAccessible(this: *mut core::ffi::c_void, accessible: *mut core::ffi::c_void, childid: i32, element: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "ElementFromIAccessible", 56usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).ElementFromIAccessible)(this, accessible, childid, element), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ElementFromIAccessibleBuildCache(this: *mut core::ffi::c_void, accessible: *mut core::ffi::c_void, childid: i32, cacherequest: *mut core::ffi::c_void, element: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation", "ElementFromIAccessibleBuildCache", 57usize, |this| ((**(this as *const *const IUIAutomation_Vtbl)).ElementFromIAccessibleBuildCache)(this, accessible, childid, cacherequest, element), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CompareElements,
+            #[cfg(feature = "Win32_System_Com")]
+            CompareRuntimeIds,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CompareRuntimeIds: 0,
+            GetRootElement,
+            ElementFromHandle,
+            ElementFromPoint,
+            GetFocusedElement,
+            GetRootElementBuildCache,
+            ElementFromHandleBuildCache,
+            ElementFromPointBuildCache,
+            GetFocusedElementBuildCache,
+            CreateTreeWalker,
+            ControlViewWalker,
+            ContentViewWalker,
+            RawViewWalker,
+            RawViewCondition,
+            ControlViewCondition,
+            ContentViewCondition,
+            CreateCacheRequest,
+            CreateTrueCondition,
+            CreateFalseCondition,
+            CreatePropertyCondition,
+            CreatePropertyConditionEx,
+            CreateAndCondition,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateAndConditionFromArray,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateAndConditionFromArray: 0,
+            CreateAndConditionFromNativeArray,
+            CreateOrCondition,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateOrConditionFromArray,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateOrConditionFromArray: 0,
+            CreateOrConditionFromNativeArray,
+            CreateNotCondition,
+            AddAutomationEventHandler,
+            RemoveAutomationEventHandler,
+            AddPropertyChangedEventHandlerNativeArray,
+            #[cfg(feature = "Win32_System_Com")]
+            AddPropertyChangedEventHandler,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddPropertyChangedEventHandler: 0,
+            RemovePropertyChangedEventHandler,
+            AddStructureChangedEventHandler,
+            RemoveStructureChangedEventHandler,
+            AddFocusChangedEventHandler,
+            RemoveFocusChangedEventHandler,
+            RemoveAllEventHandlers,
+            #[cfg(feature = "Win32_System_Com")]
+            IntNativeArrayToSafeArray,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            IntNativeArrayToSafeArray: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            IntSafeArrayToNativeArray,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            IntSafeArrayToNativeArray: 0,
+            RectToVariant,
+            VariantToRect,
+            #[cfg(feature = "Win32_System_Com")]
+            SafeArrayToRectNativeArray,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SafeArrayToRectNativeArray: 0,
+            CreateProxyFactoryEntry,
+            ProxyFactoryMapping,
+            GetPropertyProgrammaticName,
+            GetPatternProgrammaticName,
+            #[cfg(feature = "Win32_System_Com")]
+            PollForPotentialSupportedPatterns,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PollForPotentialSupportedPatterns: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            PollForPotentialSupportedProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            PollForPotentialSupportedProperties: 0,
+            CheckNotSupported,
+            ReservedNotSupportedValue,
+            ReservedMixedAttributeValue,
+            #[cfg(feature = "Win32_System_Com")]
+            ElementFromIAccessible,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ElementFromIAccessible: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ElementFromIAccessibleBuildCache,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ElementFromIAccessibleBuildCache: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomation2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AutoSetFocus(this: *mut core::ffi::c_void, autosetfocus: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation2", "AutoSetFocus", 58usize, |this| ((**(this as *const *const IUIAutomation2_Vtbl)).AutoSetFocus)(this, autosetfocus), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoSetFocus(this: *mut core::ffi::c_void, autosetfocus: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation2", "SetAutoSetFocus", 59usize, |this| ((**(this as *const *const IUIAutomation2_Vtbl)).SetAutoSetFocus)(this, autosetfocus), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionTimeout(this: *mut core::ffi::c_void, timeout: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation2", "ConnectionTimeout", 60usize, |this| ((**(this as *const *const IUIAutomation2_Vtbl)).ConnectionTimeout)(this, timeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConnectionTimeout(this: *mut core::ffi::c_void, timeout: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation2", "SetConnectionTimeout", 61usize, |this| ((**(this as *const *const IUIAutomation2_Vtbl)).SetConnectionTimeout)(this, timeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransactionTimeout(this: *mut core::ffi::c_void, timeout: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation2", "TransactionTimeout", 62usize, |this| ((**(this as *const *const IUIAutomation2_Vtbl)).TransactionTimeout)(this, timeout), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransactionTimeout(this: *mut core::ffi::c_void, timeout: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation2", "SetTransactionTimeout", 63usize, |this| ((**(this as *const *const IUIAutomation2_Vtbl)).SetTransactionTimeout)(this, timeout), |result| Some(*result))
+        }
+        Self {
+            base__: <IUIAutomation_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AutoSetFocus,
+            SetAutoSetFocus,
+            ConnectionTimeout,
+            SetConnectionTimeout,
+            TransactionTimeout,
+            SetTransactionTimeout,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomation2 as windows_core::Interface>::IID || <IUIAutomation_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomation3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddTextEditTextChangedEventHandler(this: *mut core::ffi::c_void, element: *mut core::ffi::c_void, scope: TreeScope, texteditchangetype: TextEditChangeType, cacherequest: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation3", "AddTextEditTextChangedEventHandler", 64usize, |this| ((**(this as *const *const IUIAutomation3_Vtbl)).AddTextEditTextChangedEventHandler)(this, element, scope, texteditchangetype, cacherequest, handler), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveTextEditTextChangedEventHandler(this: *mut core::ffi::c_void, element: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation3", "RemoveTextEditTextChangedEventHandler", 65usize, |this| ((**(this as *const *const IUIAutomation3_Vtbl)).RemoveTextEditTextChangedEventHandler)(this, element, handler), |result| Some(*result))
+        }
+        Self {
+            base__: <IUIAutomation2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddTextEditTextChangedEventHandler,
+            RemoveTextEditTextChangedEventHandler,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomation3 as windows_core::Interface>::IID || <IUIAutomation2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomation4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddChangesEventHandler(this: *mut core::ffi::c_void, element: *mut core::ffi::c_void, scope: TreeScope, changetypes: *const i32, changescount: i32, pcacherequest: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation4", "AddChangesEventHandler", 66usize, |this| ((**(this as *const *const IUIAutomation4_Vtbl)).AddChangesEventHandler)(this, element, scope, changetypes, changescount, pcacherequest, handler), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveChangesEventHandler(this: *mut core::ffi::c_void, element: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation4", "RemoveChangesEventHandler", 67usize, |this| ((**(this as *const *const IUIAutomation4_Vtbl)).RemoveChangesEventHandler)(this, element, handler), |result| Some(*result))
+        }
+        Self { base__: <IUIAutomation3_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddChangesEventHandler, RemoveChangesEventHandler }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomation4 as windows_core::Interface>::IID || <IUIAutomation3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomation5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddNotificationEventHandler(this: *mut core::ffi::c_void, element: *mut core::ffi::c_void, scope: TreeScope, cacherequest: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation5", "AddNotificationEventHandler", 68usize, |this| ((**(this as *const *const IUIAutomation5_Vtbl)).AddNotificationEventHandler)(this, element, scope, cacherequest, handler), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveNotificationEventHandler(this: *mut core::ffi::c_void, element: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation5", "RemoveNotificationEventHandler", 69usize, |this| ((**(this as *const *const IUIAutomation5_Vtbl)).RemoveNotificationEventHandler)(this, element, handler), |result| Some(*result))
+        }
+        Self { base__: <IUIAutomation4_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddNotificationEventHandler, RemoveNotificationEventHandler }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomation5 as windows_core::Interface>::IID || <IUIAutomation4_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomation6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateEventHandlerGroup(this: *mut core::ffi::c_void, handlergroup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation6", "CreateEventHandlerGroup", 70usize, |this| ((**(this as *const *const IUIAutomation6_Vtbl)).CreateEventHandlerGroup)(this, handlergroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddEventHandlerGroup(this: *mut core::ffi::c_void, element: *mut core::ffi::c_void, handlergroup: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation6", "AddEventHandlerGroup", 71usize, |this| ((**(this as *const *const IUIAutomation6_Vtbl)).AddEventHandlerGroup)(this, element, handlergroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEventHandlerGroup(this: *mut core::ffi::c_void, element: *mut core::ffi::c_void, handlergroup: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation6", "RemoveEventHandlerGroup", 72usize, |this| ((**(this as *const *const IUIAutomation6_Vtbl)).RemoveEventHandlerGroup)(this, element, handlergroup), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConnectionRecoveryBehavior(this: *mut core::ffi::c_void, connectionrecoverybehavioroptions: *mut ConnectionRecoveryBehaviorOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation6", "ConnectionRecoveryBehavior", 73usize, |this| ((**(this as *const *const IUIAutomation6_Vtbl)).ConnectionRecoveryBehavior)(this, connectionrecoverybehavioroptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConnectionRecoveryBehavior(this: *mut core::ffi::c_void, connectionrecoverybehavioroptions: ConnectionRecoveryBehaviorOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation6", "SetConnectionRecoveryBehavior", 74usize, |this| ((**(this as *const *const IUIAutomation6_Vtbl)).SetConnectionRecoveryBehavior)(this, connectionrecoverybehavioroptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn CoalesceEvents(this: *mut core::ffi::c_void, coalesceeventsoptions: *mut CoalesceEventsOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation6", "CoalesceEvents", 75usize, |this| ((**(this as *const *const IUIAutomation6_Vtbl)).CoalesceEvents)(this, coalesceeventsoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCoalesceEvents(this: *mut core::ffi::c_void, coalesceeventsoptions: CoalesceEventsOptions) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation6", "SetCoalesceEvents", 76usize, |this| ((**(this as *const *const IUIAutomation6_Vtbl)).SetCoalesceEvents)(this, coalesceeventsoptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddActiveTextPositionChangedEventHandler(this: *mut core::ffi::c_void, element: *mut core::ffi::c_void, scope: TreeScope, cacherequest: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation6", "AddActiveTextPositionChangedEventHandler", 77usize, |this| ((**(this as *const *const IUIAutomation6_Vtbl)).AddActiveTextPositionChangedEventHandler)(this, element, scope, cacherequest, handler), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveActiveTextPositionChangedEventHandler(this: *mut core::ffi::c_void, element: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomation6", "RemoveActiveTextPositionChangedEventHandler", 78usize, |this| ((**(this as *const *const IUIAutomation6_Vtbl)).RemoveActiveTextPositionChangedEventHandler)(this, element, handler), |result| Some(*result))
+        }
+        Self {
+            base__: <IUIAutomation5_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateEventHandlerGroup,
+            AddEventHandlerGroup,
+            RemoveEventHandlerGroup,
+            ConnectionRecoveryBehavior,
+            SetConnectionRecoveryBehavior,
+            CoalesceEvents,
+            SetCoalesceEvents,
+            AddActiveTextPositionChangedEventHandler,
+            RemoveActiveTextPositionChangedEventHandler,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomation6 as windows_core::Interface>::IID || <IUIAutomation5_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationActiveTextPositionChangedEventHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HandleActiveTextPositionChangedEvent(this: *mut core::ffi::c_void, sender: *mut core::ffi::c_void, range: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationActiveTextPositionChangedEventHandler", "HandleActiveTextPositionChangedEvent", 3usize, |this| ((**(this as *const *const IUIAutomationActiveTextPositionChangedEventHandler_Vtbl)).HandleActiveTextPositionChangedEvent)(this, sender, range), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HandleActiveTextPositionChangedEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationActiveTextPositionChangedEventHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomationAndCondition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChildCount(this: *mut core::ffi::c_void, childcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationAndCondition", "ChildCount", 3usize, |this| ((**(this as *const *const IUIAutomationAndCondition_Vtbl)).ChildCount)(this, childcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChildrenAsNativeArray(this: *mut core::ffi::c_void, childarray: *mut *mut Option<IUIAutomationCondition>, childarraycount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationAndCondition", "GetChildrenAsNativeArray", 4usize, |this| ((**(this as *const *const IUIAutomationAndCondition_Vtbl)).GetChildrenAsNativeArray)(this, childarray, childarraycount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetChildren(this: *mut core::ffi::c_void, childarray: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationAndCondition", "GetChildren", 5usize, |this| ((**(this as *const *const IUIAutomationAndCondition_Vtbl)).GetChildren)(this, childarray), |result| Some(*result))
+        }
+        Self {
+            base__: <IUIAutomationCondition_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ChildCount,
+            GetChildrenAsNativeArray,
+            #[cfg(feature = "Win32_System_Com")]
+            GetChildren,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetChildren: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationAndCondition as windows_core::Interface>::IID || <IUIAutomationCondition_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationAnnotationPattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentAnnotationTypeId(this: *mut core::ffi::c_void, retval: *mut UIA_ANNOTATIONTYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationAnnotationPattern", "CurrentAnnotationTypeId", 3usize, |this| ((**(this as *const *const IUIAutomationAnnotationPattern_Vtbl)).CurrentAnnotationTypeId)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentAnnotationTypeName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationAnnotationPattern", "CurrentAnnotationTypeName", 4usize, |this| ((**(this as *const *const IUIAutomationAnnotationPattern_Vtbl)).CurrentAnnotationTypeName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentAuthor(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationAnnotationPattern", "CurrentAuthor", 5usize, |this| ((**(this as *const *const IUIAutomationAnnotationPattern_Vtbl)).CurrentAuthor)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentDateTime(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationAnnotationPattern", "CurrentDateTime", 6usize, |this| ((**(this as *const *const IUIAutomationAnnotationPattern_Vtbl)).CurrentDateTime)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentTarget(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationAnnotationPattern", "CurrentTarget", 7usize, |this| ((**(this as *const *const IUIAutomationAnnotationPattern_Vtbl)).CurrentTarget)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedAnnotationTypeId(this: *mut core::ffi::c_void, retval: *mut UIA_ANNOTATIONTYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationAnnotationPattern", "CachedAnnotationTypeId", 8usize, |this| ((**(this as *const *const IUIAutomationAnnotationPattern_Vtbl)).CachedAnnotationTypeId)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedAnnotationTypeName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationAnnotationPattern", "CachedAnnotationTypeName", 9usize, |this| ((**(this as *const *const IUIAutomationAnnotationPattern_Vtbl)).CachedAnnotationTypeName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedAuthor(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationAnnotationPattern", "CachedAuthor", 10usize, |this| ((**(this as *const *const IUIAutomationAnnotationPattern_Vtbl)).CachedAuthor)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedDateTime(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationAnnotationPattern", "CachedDateTime", 11usize, |this| ((**(this as *const *const IUIAutomationAnnotationPattern_Vtbl)).CachedDateTime)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedTarget(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationAnnotationPattern", "CachedTarget", 12usize, |this| ((**(this as *const *const IUIAutomationAnnotationPattern_Vtbl)).CachedTarget)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CurrentAnnotationTypeId,
+            CurrentAnnotationTypeName,
+            CurrentAuthor,
+            CurrentDateTime,
+            CurrentTarget,
+            CachedAnnotationTypeId,
+            CachedAnnotationTypeName,
+            CachedAuthor,
+            CachedDateTime,
+            CachedTarget,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationAnnotationPattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationBoolCondition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BooleanValue(this: *mut core::ffi::c_void, boolval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationBoolCondition", "BooleanValue", 3usize, |this| ((**(this as *const *const IUIAutomationBoolCondition_Vtbl)).BooleanValue)(this, boolval), |result| Some(*result))
+        }
+        Self { base__: <IUIAutomationCondition_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BooleanValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationBoolCondition as windows_core::Interface>::IID || <IUIAutomationCondition_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationCacheRequest_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddProperty(this: *mut core::ffi::c_void, propertyid: UIA_PROPERTY_ID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationCacheRequest", "AddProperty", 3usize, |this| ((**(this as *const *const IUIAutomationCacheRequest_Vtbl)).AddProperty)(this, propertyid), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPattern(this: *mut core::ffi::c_void, patternid: UIA_PATTERN_ID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationCacheRequest", "AddPattern", 4usize, |this| ((**(this as *const *const IUIAutomationCacheRequest_Vtbl)).AddPattern)(this, patternid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, clonedrequest: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationCacheRequest", "Clone", 5usize, |this| ((**(this as *const *const IUIAutomationCacheRequest_Vtbl)).Clone)(this, clonedrequest), |result| Some(*result))
+        }
+        unsafe extern "system" fn TreeScope(this: *mut core::ffi::c_void, scope: *mut TreeScope) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationCacheRequest", "TreeScope", 6usize, |this| ((**(this as *const *const IUIAutomationCacheRequest_Vtbl)).TreeScope)(this, scope), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTreeScope(this: *mut core::ffi::c_void, scope: TreeScope) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationCacheRequest", "SetTreeScope", 7usize, |this| ((**(this as *const *const IUIAutomationCacheRequest_Vtbl)).SetTreeScope)(this, scope), |result| Some(*result))
+        }
+        unsafe extern "system" fn TreeFilter(this: *mut core::ffi::c_void, filter: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationCacheRequest", "TreeFilter", 8usize, |this| ((**(this as *const *const IUIAutomationCacheRequest_Vtbl)).TreeFilter)(this, filter), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTreeFilter(this: *mut core::ffi::c_void, filter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationCacheRequest", "SetTreeFilter", 9usize, |this| ((**(this as *const *const IUIAutomationCacheRequest_Vtbl)).SetTreeFilter)(this, filter), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutomationElementMode(this: *mut core::ffi::c_void, mode: *mut AutomationElementMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationCacheRequest", "AutomationElementMode", 10usize, |this| ((**(this as *const *const IUIAutomationCacheRequest_Vtbl)).AutomationElementMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutomationElementMode(this: *mut core::ffi::c_void, mode: AutomationElementMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationCacheRequest", "SetAutomationElementMode", 11usize, |this| ((**(this as *const *const IUIAutomationCacheRequest_Vtbl)).SetAutomationElementMode)(this, mode), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddProperty,
+            AddPattern,
+            Clone,
+            TreeScope,
+            SetTreeScope,
+            TreeFilter,
+            SetTreeFilter,
+            AutomationElementMode,
+            SetAutomationElementMode,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationCacheRequest as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationChangesEventHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HandleChangesEvent(this: *mut core::ffi::c_void, sender: *mut core::ffi::c_void, uiachanges: *const UiaChangeInfo, changescount: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationChangesEventHandler", "HandleChangesEvent", 3usize, |this| ((**(this as *const *const IUIAutomationChangesEventHandler_Vtbl)).HandleChangesEvent)(this, sender, uiachanges, changescount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HandleChangesEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationChangesEventHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationCondition_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationCondition as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationCustomNavigationPattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Navigate(this: *mut core::ffi::c_void, direction: NavigateDirection, pretval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationCustomNavigationPattern", "Navigate", 3usize, |this| ((**(this as *const *const IUIAutomationCustomNavigationPattern_Vtbl)).Navigate)(this, direction, pretval), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Navigate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationCustomNavigationPattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationDockPattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDockPosition(this: *mut core::ffi::c_void, dockpos: DockPosition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationDockPattern", "SetDockPosition", 3usize, |this| ((**(this as *const *const IUIAutomationDockPattern_Vtbl)).SetDockPosition)(this, dockpos), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentDockPosition(this: *mut core::ffi::c_void, retval: *mut DockPosition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationDockPattern", "CurrentDockPosition", 4usize, |this| ((**(this as *const *const IUIAutomationDockPattern_Vtbl)).CurrentDockPosition)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedDockPosition(this: *mut core::ffi::c_void, retval: *mut DockPosition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationDockPattern", "CachedDockPosition", 5usize, |this| ((**(this as *const *const IUIAutomationDockPattern_Vtbl)).CachedDockPosition)(this, retval), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetDockPosition, CurrentDockPosition, CachedDockPosition }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationDockPattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomationDragPattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentIsGrabbed(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationDragPattern", "CurrentIsGrabbed", 3usize, |this| ((**(this as *const *const IUIAutomationDragPattern_Vtbl)).CurrentIsGrabbed)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedIsGrabbed(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationDragPattern", "CachedIsGrabbed", 4usize, |this| ((**(this as *const *const IUIAutomationDragPattern_Vtbl)).CachedIsGrabbed)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentDropEffect(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationDragPattern", "CurrentDropEffect", 5usize, |this| ((**(this as *const *const IUIAutomationDragPattern_Vtbl)).CurrentDropEffect)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedDropEffect(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationDragPattern", "CachedDropEffect", 6usize, |this| ((**(this as *const *const IUIAutomationDragPattern_Vtbl)).CachedDropEffect)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CurrentDropEffects(this: *mut core::ffi::c_void, retval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationDragPattern", "CurrentDropEffects", 7usize, |this| ((**(this as *const *const IUIAutomationDragPattern_Vtbl)).CurrentDropEffects)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CachedDropEffects(this: *mut core::ffi::c_void, retval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationDragPattern", "CachedDropEffects", 8usize, |this| ((**(this as *const *const IUIAutomationDragPattern_Vtbl)).CachedDropEffects)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentGrabbedItems(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationDragPattern", "GetCurrentGrabbedItems", 9usize, |this| ((**(this as *const *const IUIAutomationDragPattern_Vtbl)).GetCurrentGrabbedItems)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCachedGrabbedItems(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationDragPattern", "GetCachedGrabbedItems", 10usize, |this| ((**(this as *const *const IUIAutomationDragPattern_Vtbl)).GetCachedGrabbedItems)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CurrentIsGrabbed,
+            CachedIsGrabbed,
+            CurrentDropEffect,
+            CachedDropEffect,
+            #[cfg(feature = "Win32_System_Com")]
+            CurrentDropEffects,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CurrentDropEffects: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CachedDropEffects,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CachedDropEffects: 0,
+            GetCurrentGrabbedItems,
+            GetCachedGrabbedItems,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationDragPattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomationDropTargetPattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentDropTargetEffect(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationDropTargetPattern", "CurrentDropTargetEffect", 3usize, |this| ((**(this as *const *const IUIAutomationDropTargetPattern_Vtbl)).CurrentDropTargetEffect)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedDropTargetEffect(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationDropTargetPattern", "CachedDropTargetEffect", 4usize, |this| ((**(this as *const *const IUIAutomationDropTargetPattern_Vtbl)).CachedDropTargetEffect)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CurrentDropTargetEffects(this: *mut core::ffi::c_void, retval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationDropTargetPattern", "CurrentDropTargetEffects", 5usize, |this| ((**(this as *const *const IUIAutomationDropTargetPattern_Vtbl)).CurrentDropTargetEffects)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CachedDropTargetEffects(this: *mut core::ffi::c_void, retval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationDropTargetPattern", "CachedDropTargetEffects", 6usize, |this| ((**(this as *const *const IUIAutomationDropTargetPattern_Vtbl)).CachedDropTargetEffects)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CurrentDropTargetEffect,
+            CachedDropTargetEffect,
+            #[cfg(feature = "Win32_System_Com")]
+            CurrentDropTargetEffects,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CurrentDropTargetEffects: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CachedDropTargetEffects,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CachedDropTargetEffects: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationDropTargetPattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomationElement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetFocus(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "SetFocus", 3usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).SetFocus)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetRuntimeId(this: *mut core::ffi::c_void, runtimeid: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "GetRuntimeId", 4usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).GetRuntimeId)(this, runtimeid), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindFirst(this: *mut core::ffi::c_void, scope: TreeScope, condition: *mut core::ffi::c_void, found: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "FindFirst", 5usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).FindFirst)(this, scope, condition, found), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindAll(this: *mut core::ffi::c_void, scope: TreeScope, condition: *mut core::ffi::c_void, found: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "FindAll", 6usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).FindAll)(this, scope, condition, found), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindFirstBuildCache(this: *mut core::ffi::c_void, scope: TreeScope, condition: *mut core::ffi::c_void, cacherequest: *mut core::ffi::c_void, found: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "FindFirstBuildCache", 7usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).FindFirstBuildCache)(this, scope, condition, cacherequest, found), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindAllBuildCache(this: *mut core::ffi::c_void, scope: TreeScope, condition: *mut core::ffi::c_void, cacherequest: *mut core::ffi::c_void, found: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "FindAllBuildCache", 8usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).FindAllBuildCache)(this, scope, condition, cacherequest, found), |result| Some(*result))
+        }
+        unsafe extern "system" fn BuildUpdatedCache(this: *mut core::ffi::c_void, cacherequest: *mut core::ffi::c_void, updatedelement: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "BuildUpdatedCache", 9usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).BuildUpdatedCache)(this, cacherequest, updatedelement), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentPropertyValue(this: *mut core::ffi::c_void, propertyid: UIA_PROPERTY_ID, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "GetCurrentPropertyValue", 10usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).GetCurrentPropertyValue)(this, propertyid, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentPropertyValueEx(this: *mut core::ffi::c_void, propertyid: UIA_PROPERTY_ID, ignoredefaultvalue: super::super::Foundation::BOOL, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "GetCurrentPropertyValueEx", 11usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).GetCurrentPropertyValueEx)(this, propertyid, ignoredefaultvalue, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCachedPropertyValue(this: *mut core::ffi::c_void, propertyid: UIA_PROPERTY_ID, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "GetCachedPropertyValue", 12usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).GetCachedPropertyValue)(this, propertyid, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCachedPropertyValueEx(this: *mut core::ffi::c_void, propertyid: UIA_PROPERTY_ID, ignoredefaultvalue: super::super::Foundation::BOOL, retval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "GetCachedPropertyValueEx", 13usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).GetCachedPropertyValueEx)(this, propertyid, ignoredefaultvalue, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentPatternAs(this: *mut core::ffi::c_void, patternid: UIA_PATTERN_ID, riid: *const windows_core::GUID, patternobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "GetCurrentPatternAs", 14usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).GetCurrentPatternAs)(this, patternid, riid, patternobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCachedPatternAs(this: *mut core::ffi::c_void, patternid: UIA_PATTERN_ID, riid: *const windows_core::GUID, patternobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "GetCachedPatternAs", 15usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).GetCachedPatternAs)(this, patternid, riid, patternobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentPattern(this: *mut core::ffi::c_void, patternid: UIA_PATTERN_ID, patternobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "GetCurrentPattern", 16usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).GetCurrentPattern)(this, patternid, patternobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCachedPattern(this: *mut core::ffi::c_void, patternid: UIA_PATTERN_ID, patternobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "GetCachedPattern", 17usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).GetCachedPattern)(this, patternid, patternobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCachedParent(this: *mut core::ffi::c_void, parent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "GetCachedParent", 18usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).GetCachedParent)(this, parent), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCachedChildren(this: *mut core::ffi::c_void, children: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "GetCachedChildren", 19usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).GetCachedChildren)(this, children), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentProcessId(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentProcessId", 20usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentProcessId)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentControlType(this: *mut core::ffi::c_void, retval: *mut UIA_CONTROLTYPE_ID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentControlType", 21usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentControlType)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentLocalizedControlType(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentLocalizedControlType", 22usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentLocalizedControlType)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentName", 23usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentAcceleratorKey(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentAcceleratorKey", 24usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentAcceleratorKey)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentAccessKey(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentAccessKey", 25usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentAccessKey)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentHasKeyboardFocus(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentHasKeyboardFocus", 26usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentHasKeyboardFocus)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentIsKeyboardFocusable(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentIsKeyboardFocusable", 27usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentIsKeyboardFocusable)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentIsEnabled(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentIsEnabled", 28usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentIsEnabled)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentAutomationId(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentAutomationId", 29usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentAutomationId)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentClassName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentClassName", 30usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentClassName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentHelpText(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentHelpText", 31usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentHelpText)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentCulture(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentCulture", 32usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentCulture)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentIsControlElement(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentIsControlElement", 33usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentIsControlElement)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentIsContentElement(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentIsContentElement", 34usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentIsContentElement)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentIsPassword(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentIsPassword", 35usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentIsPassword)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentNativeWindowHandle(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentNativeWindowHandle", 36usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentNativeWindowHandle)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentItemType(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentItemType", 37usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentItemType)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentIsOffscreen(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentIsOffscreen", 38usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentIsOffscreen)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentOrientation(this: *mut core::ffi::c_void, retval: *mut OrientationType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentOrientation", 39usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentOrientation)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentFrameworkId(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentFrameworkId", 40usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentFrameworkId)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentIsRequiredForForm(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentIsRequiredForForm", 41usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentIsRequiredForForm)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentItemStatus(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentItemStatus", 42usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentItemStatus)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentBoundingRectangle(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentBoundingRectangle", 43usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentBoundingRectangle)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentLabeledBy(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentLabeledBy", 44usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentLabeledBy)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentAriaRole(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentAriaRole", 45usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentAriaRole)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentAriaProperties(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentAriaProperties", 46usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentAriaProperties)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentIsDataValidForForm(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentIsDataValidForForm", 47usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentIsDataValidForForm)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentControllerFor(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentControllerFor", 48usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentControllerFor)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentDescribedBy(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentDescribedBy", 49usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentDescribedBy)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentFlowsTo(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentFlowsTo", 50usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentFlowsTo)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentProviderDescription(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CurrentProviderDescription", 51usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CurrentProviderDescription)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedProcessId(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedProcessId", 52usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedProcessId)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedControlType(this: *mut core::ffi::c_void, retval: *mut UIA_CONTROLTYPE_ID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedControlType", 53usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedControlType)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedLocalizedControlType(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedLocalizedControlType", 54usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedLocalizedControlType)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedName", 55usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedAcceleratorKey(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedAcceleratorKey", 56usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedAcceleratorKey)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedAccessKey(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedAccessKey", 57usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedAccessKey)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedHasKeyboardFocus(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedHasKeyboardFocus", 58usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedHasKeyboardFocus)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedIsKeyboardFocusable(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedIsKeyboardFocusable", 59usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedIsKeyboardFocusable)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedIsEnabled(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedIsEnabled", 60usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedIsEnabled)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedAutomationId(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedAutomationId", 61usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedAutomationId)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedClassName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedClassName", 62usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedClassName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedHelpText(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedHelpText", 63usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedHelpText)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedCulture(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedCulture", 64usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedCulture)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedIsControlElement(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedIsControlElement", 65usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedIsControlElement)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedIsContentElement(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedIsContentElement", 66usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedIsContentElement)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedIsPassword(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedIsPassword", 67usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedIsPassword)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedNativeWindowHandle(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedNativeWindowHandle", 68usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedNativeWindowHandle)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedItemType(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedItemType", 69usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedItemType)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedIsOffscreen(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedIsOffscreen", 70usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedIsOffscreen)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedOrientation(this: *mut core::ffi::c_void, retval: *mut OrientationType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedOrientation", 71usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedOrientation)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedFrameworkId(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedFrameworkId", 72usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedFrameworkId)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedIsRequiredForForm(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedIsRequiredForForm", 73usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedIsRequiredForForm)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedItemStatus(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedItemStatus", 74usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedItemStatus)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedBoundingRectangle(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedBoundingRectangle", 75usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedBoundingRectangle)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedLabeledBy(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedLabeledBy", 76usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedLabeledBy)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedAriaRole(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedAriaRole", 77usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedAriaRole)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedAriaProperties(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedAriaProperties", 78usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedAriaProperties)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedIsDataValidForForm(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedIsDataValidForForm", 79usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedIsDataValidForForm)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedControllerFor(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedControllerFor", 80usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedControllerFor)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedDescribedBy(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedDescribedBy", 81usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedDescribedBy)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedFlowsTo(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedFlowsTo", 82usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedFlowsTo)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedProviderDescription(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "CachedProviderDescription", 83usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).CachedProviderDescription)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClickablePoint(this: *mut core::ffi::c_void, clickable: *mut super::super::Foundation::POINT, gotclickable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement", "GetClickablePoint", 84usize, |this| ((**(this as *const *const IUIAutomationElement_Vtbl)).GetClickablePoint)(this, clickable, gotclickable), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetFocus,
+            #[cfg(feature = "Win32_System_Com")]
+            GetRuntimeId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetRuntimeId: 0,
+            FindFirst,
+            FindAll,
+            FindFirstBuildCache,
+            FindAllBuildCache,
+            BuildUpdatedCache,
+            GetCurrentPropertyValue,
+            GetCurrentPropertyValueEx,
+            GetCachedPropertyValue,
+            GetCachedPropertyValueEx,
+            GetCurrentPatternAs,
+            GetCachedPatternAs,
+            GetCurrentPattern,
+            GetCachedPattern,
+            GetCachedParent,
+            GetCachedChildren,
+            CurrentProcessId,
+            CurrentControlType,
+            CurrentLocalizedControlType,
+            CurrentName,
+            CurrentAcceleratorKey,
+            CurrentAccessKey,
+            CurrentHasKeyboardFocus,
+            CurrentIsKeyboardFocusable,
+            CurrentIsEnabled,
+            CurrentAutomationId,
+            CurrentClassName,
+            CurrentHelpText,
+            CurrentCulture,
+            CurrentIsControlElement,
+            CurrentIsContentElement,
+            CurrentIsPassword,
+            CurrentNativeWindowHandle,
+            CurrentItemType,
+            CurrentIsOffscreen,
+            CurrentOrientation,
+            CurrentFrameworkId,
+            CurrentIsRequiredForForm,
+            CurrentItemStatus,
+            CurrentBoundingRectangle,
+            CurrentLabeledBy,
+            CurrentAriaRole,
+            CurrentAriaProperties,
+            CurrentIsDataValidForForm,
+            CurrentControllerFor,
+            CurrentDescribedBy,
+            CurrentFlowsTo,
+            CurrentProviderDescription,
+            CachedProcessId,
+            CachedControlType,
+            CachedLocalizedControlType,
+            CachedName,
+            CachedAcceleratorKey,
+            CachedAccessKey,
+            CachedHasKeyboardFocus,
+            CachedIsKeyboardFocusable,
+            CachedIsEnabled,
+            CachedAutomationId,
+            CachedClassName,
+            CachedHelpText,
+            CachedCulture,
+            CachedIsControlElement,
+            CachedIsContentElement,
+            CachedIsPassword,
+            CachedNativeWindowHandle,
+            CachedItemType,
+            CachedIsOffscreen,
+            CachedOrientation,
+            CachedFrameworkId,
+            CachedIsRequiredForForm,
+            CachedItemStatus,
+            CachedBoundingRectangle,
+            CachedLabeledBy,
+            CachedAriaRole,
+            CachedAriaProperties,
+            CachedIsDataValidForForm,
+            CachedControllerFor,
+            CachedDescribedBy,
+            CachedFlowsTo,
+            CachedProviderDescription,
+            GetClickablePoint,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationElement as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomationElement2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentOptimizeForVisualContent(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement2", "CurrentOptimizeForVisualContent", 85usize, |this| ((**(this as *const *const IUIAutomationElement2_Vtbl)).CurrentOptimizeForVisualContent)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedOptimizeForVisualContent(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement2", "CachedOptimizeForVisualContent", 86usize, |this| ((**(this as *const *const IUIAutomationElement2_Vtbl)).CachedOptimizeForVisualContent)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentLiveSetting(this: *mut core::ffi::c_void, retval: *mut LiveSetting) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement2", "CurrentLiveSetting", 87usize, |this| ((**(this as *const *const IUIAutomationElement2_Vtbl)).CurrentLiveSetting)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedLiveSetting(this: *mut core::ffi::c_void, retval: *mut LiveSetting) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement2", "CachedLiveSetting", 88usize, |this| ((**(this as *const *const IUIAutomationElement2_Vtbl)).CachedLiveSetting)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentFlowsFrom(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement2", "CurrentFlowsFrom", 89usize, |this| ((**(this as *const *const IUIAutomationElement2_Vtbl)).CurrentFlowsFrom)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedFlowsFrom(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement2", "CachedFlowsFrom", 90usize, |this| ((**(this as *const *const IUIAutomationElement2_Vtbl)).CachedFlowsFrom)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <IUIAutomationElement_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CurrentOptimizeForVisualContent,
+            CachedOptimizeForVisualContent,
+            CurrentLiveSetting,
+            CachedLiveSetting,
+            CurrentFlowsFrom,
+            CachedFlowsFrom,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationElement2 as windows_core::Interface>::IID || <IUIAutomationElement_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomationElement3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowContextMenu(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement3", "ShowContextMenu", 91usize, |this| ((**(this as *const *const IUIAutomationElement3_Vtbl)).ShowContextMenu)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentIsPeripheral(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement3", "CurrentIsPeripheral", 92usize, |this| ((**(this as *const *const IUIAutomationElement3_Vtbl)).CurrentIsPeripheral)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedIsPeripheral(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement3", "CachedIsPeripheral", 93usize, |this| ((**(this as *const *const IUIAutomationElement3_Vtbl)).CachedIsPeripheral)(this, retval), |result| Some(*result))
+        }
+        Self { base__: <IUIAutomationElement2_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShowContextMenu, CurrentIsPeripheral, CachedIsPeripheral }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationElement3 as windows_core::Interface>::IID || <IUIAutomationElement2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomationElement4_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentPositionInSet(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement4", "CurrentPositionInSet", 94usize, |this| ((**(this as *const *const IUIAutomationElement4_Vtbl)).CurrentPositionInSet)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentSizeOfSet(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement4", "CurrentSizeOfSet", 95usize, |this| ((**(this as *const *const IUIAutomationElement4_Vtbl)).CurrentSizeOfSet)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentLevel(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement4", "CurrentLevel", 96usize, |this| ((**(this as *const *const IUIAutomationElement4_Vtbl)).CurrentLevel)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CurrentAnnotationTypes(this: *mut core::ffi::c_void, retval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement4", "CurrentAnnotationTypes", 97usize, |this| ((**(this as *const *const IUIAutomationElement4_Vtbl)).CurrentAnnotationTypes)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentAnnotationObjects(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement4", "CurrentAnnotationObjects", 98usize, |this| ((**(this as *const *const IUIAutomationElement4_Vtbl)).CurrentAnnotationObjects)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedPositionInSet(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement4", "CachedPositionInSet", 99usize, |this| ((**(this as *const *const IUIAutomationElement4_Vtbl)).CachedPositionInSet)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedSizeOfSet(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement4", "CachedSizeOfSet", 100usize, |this| ((**(this as *const *const IUIAutomationElement4_Vtbl)).CachedSizeOfSet)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedLevel(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement4", "CachedLevel", 101usize, |this| ((**(this as *const *const IUIAutomationElement4_Vtbl)).CachedLevel)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CachedAnnotationTypes(this: *mut core::ffi::c_void, retval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement4", "CachedAnnotationTypes", 102usize, |this| ((**(this as *const *const IUIAutomationElement4_Vtbl)).CachedAnnotationTypes)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedAnnotationObjects(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement4", "CachedAnnotationObjects", 103usize, |this| ((**(this as *const *const IUIAutomationElement4_Vtbl)).CachedAnnotationObjects)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <IUIAutomationElement3_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CurrentPositionInSet,
+            CurrentSizeOfSet,
+            CurrentLevel,
+            #[cfg(feature = "Win32_System_Com")]
+            CurrentAnnotationTypes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CurrentAnnotationTypes: 0,
+            CurrentAnnotationObjects,
+            CachedPositionInSet,
+            CachedSizeOfSet,
+            CachedLevel,
+            #[cfg(feature = "Win32_System_Com")]
+            CachedAnnotationTypes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CachedAnnotationTypes: 0,
+            CachedAnnotationObjects,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationElement4 as windows_core::Interface>::IID || <IUIAutomationElement3_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomationElement5_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentLandmarkType(this: *mut core::ffi::c_void, retval: *mut UIA_LANDMARKTYPE_ID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement5", "CurrentLandmarkType", 104usize, |this| ((**(this as *const *const IUIAutomationElement5_Vtbl)).CurrentLandmarkType)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentLocalizedLandmarkType(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement5", "CurrentLocalizedLandmarkType", 105usize, |this| ((**(this as *const *const IUIAutomationElement5_Vtbl)).CurrentLocalizedLandmarkType)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedLandmarkType(this: *mut core::ffi::c_void, retval: *mut UIA_LANDMARKTYPE_ID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement5", "CachedLandmarkType", 106usize, |this| ((**(this as *const *const IUIAutomationElement5_Vtbl)).CachedLandmarkType)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedLocalizedLandmarkType(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement5", "CachedLocalizedLandmarkType", 107usize, |this| ((**(this as *const *const IUIAutomationElement5_Vtbl)).CachedLocalizedLandmarkType)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <IUIAutomationElement4_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CurrentLandmarkType,
+            CurrentLocalizedLandmarkType,
+            CachedLandmarkType,
+            CachedLocalizedLandmarkType,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationElement5 as windows_core::Interface>::IID || <IUIAutomationElement4_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomationElement6_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentFullDescription(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement6", "CurrentFullDescription", 108usize, |this| ((**(this as *const *const IUIAutomationElement6_Vtbl)).CurrentFullDescription)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedFullDescription(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement6", "CachedFullDescription", 109usize, |this| ((**(this as *const *const IUIAutomationElement6_Vtbl)).CachedFullDescription)(this, retval), |result| Some(*result))
+        }
+        Self { base__: <IUIAutomationElement5_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CurrentFullDescription, CachedFullDescription }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationElement6 as windows_core::Interface>::IID || <IUIAutomationElement5_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomationElement7_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FindFirstWithOptions(this: *mut core::ffi::c_void, scope: TreeScope, condition: *mut core::ffi::c_void, traversaloptions: TreeTraversalOptions, root: *mut core::ffi::c_void, found: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement7", "FindFirstWithOptions", 110usize, |this| ((**(this as *const *const IUIAutomationElement7_Vtbl)).FindFirstWithOptions)(this, scope, condition, traversaloptions, root, found), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindAllWithOptions(this: *mut core::ffi::c_void, scope: TreeScope, condition: *mut core::ffi::c_void, traversaloptions: TreeTraversalOptions, root: *mut core::ffi::c_void, found: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement7", "FindAllWithOptions", 111usize, |this| ((**(this as *const *const IUIAutomationElement7_Vtbl)).FindAllWithOptions)(this, scope, condition, traversaloptions, root, found), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindFirstWithOptionsBuildCache(this: *mut core::ffi::c_void, scope: TreeScope, condition: *mut core::ffi::c_void, cacherequest: *mut core::ffi::c_void, traversaloptions: TreeTraversalOptions, root: *mut core::ffi::c_void, found: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement7", "FindFirstWithOptionsBuildCache", 112usize, |this| ((**(this as *const *const IUIAutomationElement7_Vtbl)).FindFirstWithOptionsBuildCache)(this, scope, condition, cacherequest, traversaloptions, root, found), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindAllWithOptionsBuildCache(this: *mut core::ffi::c_void, scope: TreeScope, condition: *mut core::ffi::c_void, cacherequest: *mut core::ffi::c_void, traversaloptions: TreeTraversalOptions, root: *mut core::ffi::c_void, found: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement7", "FindAllWithOptionsBuildCache", 113usize, |this| ((**(this as *const *const IUIAutomationElement7_Vtbl)).FindAllWithOptionsBuildCache)(this, scope, condition, cacherequest, traversaloptions, root, found), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentMetadataValue(this: *mut core::ffi::c_void, targetid: i32, metadataid: UIA_METADATA_ID, returnval: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement7", "GetCurrentMetadataValue", 114usize, |this| ((**(this as *const *const IUIAutomationElement7_Vtbl)).GetCurrentMetadataValue)(this, targetid, metadataid, returnval), |result| Some(*result))
+        }
+        Self {
+            base__: <IUIAutomationElement6_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FindFirstWithOptions,
+            FindAllWithOptions,
+            FindFirstWithOptionsBuildCache,
+            FindAllWithOptionsBuildCache,
+            GetCurrentMetadataValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationElement7 as windows_core::Interface>::IID || <IUIAutomationElement6_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomationElement8_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentHeadingLevel(this: *mut core::ffi::c_void, retval: *mut UIA_HEADINGLEVEL_ID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement8", "CurrentHeadingLevel", 115usize, |this| ((**(this as *const *const IUIAutomationElement8_Vtbl)).CurrentHeadingLevel)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedHeadingLevel(this: *mut core::ffi::c_void, retval: *mut UIA_HEADINGLEVEL_ID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement8", "CachedHeadingLevel", 116usize, |this| ((**(this as *const *const IUIAutomationElement8_Vtbl)).CachedHeadingLevel)(this, retval), |result| Some(*result))
+        }
+        Self { base__: <IUIAutomationElement7_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CurrentHeadingLevel, CachedHeadingLevel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationElement8 as windows_core::Interface>::IID || <IUIAutomationElement7_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomationElement9_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentIsDialog(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement9", "CurrentIsDialog", 117usize, |this| ((**(this as *const *const IUIAutomationElement9_Vtbl)).CurrentIsDialog)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedIsDialog(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElement9", "CachedIsDialog", 118usize, |this| ((**(this as *const *const IUIAutomationElement9_Vtbl)).CachedIsDialog)(this, retval), |result| Some(*result))
+        }
+        Self { base__: <IUIAutomationElement8_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CurrentIsDialog, CachedIsDialog }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationElement9 as windows_core::Interface>::IID || <IUIAutomationElement8_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationElementArray_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, length: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElementArray", "Length", 3usize, |this| ((**(this as *const *const IUIAutomationElementArray_Vtbl)).Length)(this, length), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetElement(this: *mut core::ffi::c_void, index: i32, element: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationElementArray", "GetElement", 4usize, |this| ((**(this as *const *const IUIAutomationElementArray_Vtbl)).GetElement)(this, index, element), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Length, GetElement }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationElementArray as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationEventHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HandleAutomationEvent(this: *mut core::ffi::c_void, sender: *mut core::ffi::c_void, eventid: UIA_EVENT_ID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationEventHandler", "HandleAutomationEvent", 3usize, |this| ((**(this as *const *const IUIAutomationEventHandler_Vtbl)).HandleAutomationEvent)(this, sender, eventid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HandleAutomationEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationEventHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationEventHandlerGroup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddActiveTextPositionChangedEventHandler(this: *mut core::ffi::c_void, scope: TreeScope, cacherequest: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationEventHandlerGroup", "AddActiveTextPositionChangedEventHandler", 3usize, |this| ((**(this as *const *const IUIAutomationEventHandlerGroup_Vtbl)).AddActiveTextPositionChangedEventHandler)(this, scope, cacherequest, handler), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddAutomationEventHandler(this: *mut core::ffi::c_void, eventid: UIA_EVENT_ID, scope: TreeScope, cacherequest: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationEventHandlerGroup", "AddAutomationEventHandler", 4usize, |this| ((**(this as *const *const IUIAutomationEventHandlerGroup_Vtbl)).AddAutomationEventHandler)(this, eventid, scope, cacherequest, handler), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddChangesEventHandler(this: *mut core::ffi::c_void, scope: TreeScope, changetypes: *const i32, changescount: i32, cacherequest: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationEventHandlerGroup", "AddChangesEventHandler", 5usize, |this| ((**(this as *const *const IUIAutomationEventHandlerGroup_Vtbl)).AddChangesEventHandler)(this, scope, changetypes, changescount, cacherequest, handler), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddNotificationEventHandler(this: *mut core::ffi::c_void, scope: TreeScope, cacherequest: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationEventHandlerGroup", "AddNotificationEventHandler", 6usize, |this| ((**(this as *const *const IUIAutomationEventHandlerGroup_Vtbl)).AddNotificationEventHandler)(this, scope, cacherequest, handler), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddPropertyChangedEventHandler(this: *mut core::ffi::c_void, scope: TreeScope, cacherequest: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, propertyarray: *const UIA_PROPERTY_ID, propertycount: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationEventHandlerGroup", "AddPropertyChangedEventHandler", 7usize, |this| ((**(this as *const *const IUIAutomationEventHandlerGroup_Vtbl)).AddPropertyChangedEventHandler)(this, scope, cacherequest, handler, propertyarray, propertycount), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddStructureChangedEventHandler(this: *mut core::ffi::c_void, scope: TreeScope, cacherequest: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationEventHandlerGroup", "AddStructureChangedEventHandler", 8usize, |this| ((**(this as *const *const IUIAutomationEventHandlerGroup_Vtbl)).AddStructureChangedEventHandler)(this, scope, cacherequest, handler), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTextEditTextChangedEventHandler(this: *mut core::ffi::c_void, scope: TreeScope, texteditchangetype: TextEditChangeType, cacherequest: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationEventHandlerGroup", "AddTextEditTextChangedEventHandler", 9usize, |this| ((**(this as *const *const IUIAutomationEventHandlerGroup_Vtbl)).AddTextEditTextChangedEventHandler)(this, scope, texteditchangetype, cacherequest, handler), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddActiveTextPositionChangedEventHandler,
+            AddAutomationEventHandler,
+            AddChangesEventHandler,
+            AddNotificationEventHandler,
+            AddPropertyChangedEventHandler,
+            AddStructureChangedEventHandler,
+            AddTextEditTextChangedEventHandler,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationEventHandlerGroup as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationExpandCollapsePattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Expand(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationExpandCollapsePattern", "Expand", 3usize, |this| ((**(this as *const *const IUIAutomationExpandCollapsePattern_Vtbl)).Expand)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Collapse(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationExpandCollapsePattern", "Collapse", 4usize, |this| ((**(this as *const *const IUIAutomationExpandCollapsePattern_Vtbl)).Collapse)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentExpandCollapseState(this: *mut core::ffi::c_void, retval: *mut ExpandCollapseState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationExpandCollapsePattern", "CurrentExpandCollapseState", 5usize, |this| ((**(this as *const *const IUIAutomationExpandCollapsePattern_Vtbl)).CurrentExpandCollapseState)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedExpandCollapseState(this: *mut core::ffi::c_void, retval: *mut ExpandCollapseState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationExpandCollapsePattern", "CachedExpandCollapseState", 6usize, |this| ((**(this as *const *const IUIAutomationExpandCollapsePattern_Vtbl)).CachedExpandCollapseState)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Expand,
+            Collapse,
+            CurrentExpandCollapseState,
+            CachedExpandCollapseState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationExpandCollapsePattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationFocusChangedEventHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HandleFocusChangedEvent(this: *mut core::ffi::c_void, sender: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationFocusChangedEventHandler", "HandleFocusChangedEvent", 3usize, |this| ((**(this as *const *const IUIAutomationFocusChangedEventHandler_Vtbl)).HandleFocusChangedEvent)(this, sender), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HandleFocusChangedEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationFocusChangedEventHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationGridItemPattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentContainingGrid(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationGridItemPattern", "CurrentContainingGrid", 3usize, |this| ((**(this as *const *const IUIAutomationGridItemPattern_Vtbl)).CurrentContainingGrid)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentRow(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationGridItemPattern", "CurrentRow", 4usize, |this| ((**(this as *const *const IUIAutomationGridItemPattern_Vtbl)).CurrentRow)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentColumn(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationGridItemPattern", "CurrentColumn", 5usize, |this| ((**(this as *const *const IUIAutomationGridItemPattern_Vtbl)).CurrentColumn)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentRowSpan(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationGridItemPattern", "CurrentRowSpan", 6usize, |this| ((**(this as *const *const IUIAutomationGridItemPattern_Vtbl)).CurrentRowSpan)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentColumnSpan(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationGridItemPattern", "CurrentColumnSpan", 7usize, |this| ((**(this as *const *const IUIAutomationGridItemPattern_Vtbl)).CurrentColumnSpan)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedContainingGrid(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationGridItemPattern", "CachedContainingGrid", 8usize, |this| ((**(this as *const *const IUIAutomationGridItemPattern_Vtbl)).CachedContainingGrid)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedRow(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationGridItemPattern", "CachedRow", 9usize, |this| ((**(this as *const *const IUIAutomationGridItemPattern_Vtbl)).CachedRow)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedColumn(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationGridItemPattern", "CachedColumn", 10usize, |this| ((**(this as *const *const IUIAutomationGridItemPattern_Vtbl)).CachedColumn)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedRowSpan(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationGridItemPattern", "CachedRowSpan", 11usize, |this| ((**(this as *const *const IUIAutomationGridItemPattern_Vtbl)).CachedRowSpan)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedColumnSpan(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationGridItemPattern", "CachedColumnSpan", 12usize, |this| ((**(this as *const *const IUIAutomationGridItemPattern_Vtbl)).CachedColumnSpan)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CurrentContainingGrid,
+            CurrentRow,
+            CurrentColumn,
+            CurrentRowSpan,
+            CurrentColumnSpan,
+            CachedContainingGrid,
+            CachedRow,
+            CachedColumn,
+            CachedRowSpan,
+            CachedColumnSpan,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationGridItemPattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationGridPattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetItem(this: *mut core::ffi::c_void, row: i32, column: i32, element: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationGridPattern", "GetItem", 3usize, |this| ((**(this as *const *const IUIAutomationGridPattern_Vtbl)).GetItem)(this, row, column, element), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentRowCount(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationGridPattern", "CurrentRowCount", 4usize, |this| ((**(this as *const *const IUIAutomationGridPattern_Vtbl)).CurrentRowCount)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentColumnCount(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationGridPattern", "CurrentColumnCount", 5usize, |this| ((**(this as *const *const IUIAutomationGridPattern_Vtbl)).CurrentColumnCount)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedRowCount(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationGridPattern", "CachedRowCount", 6usize, |this| ((**(this as *const *const IUIAutomationGridPattern_Vtbl)).CachedRowCount)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedColumnCount(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationGridPattern", "CachedColumnCount", 7usize, |this| ((**(this as *const *const IUIAutomationGridPattern_Vtbl)).CachedColumnCount)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetItem,
+            CurrentRowCount,
+            CurrentColumnCount,
+            CachedRowCount,
+            CachedColumnCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationGridPattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationInvokePattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Invoke(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationInvokePattern", "Invoke", 3usize, |this| ((**(this as *const *const IUIAutomationInvokePattern_Vtbl)).Invoke)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Invoke }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationInvokePattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationItemContainerPattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FindItemByProperty(this: *mut core::ffi::c_void, pstartafter: *mut core::ffi::c_void, propertyid: UIA_PROPERTY_ID, value: core::mem::MaybeUninit<windows_core::VARIANT>, pfound: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationItemContainerPattern", "FindItemByProperty", 3usize, |this| ((**(this as *const *const IUIAutomationItemContainerPattern_Vtbl)).FindItemByProperty)(this, pstartafter, propertyid, value, pfound), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FindItemByProperty }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationItemContainerPattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomationLegacyIAccessiblePattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Select(this: *mut core::ffi::c_void, flagsselect: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationLegacyIAccessiblePattern", "Select", 3usize, |this| ((**(this as *const *const IUIAutomationLegacyIAccessiblePattern_Vtbl)).Select)(this, flagsselect), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoDefaultAction(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationLegacyIAccessiblePattern", "DoDefaultAction", 4usize, |this| ((**(this as *const *const IUIAutomationLegacyIAccessiblePattern_Vtbl)).DoDefaultAction)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, szvalue: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationLegacyIAccessiblePattern", "SetValue", 5usize, |this| ((**(this as *const *const IUIAutomationLegacyIAccessiblePattern_Vtbl)).SetValue)(this, szvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentChildId(this: *mut core::ffi::c_void, pretval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationLegacyIAccessiblePattern", "CurrentChildId", 6usize, |this| ((**(this as *const *const IUIAutomationLegacyIAccessiblePattern_Vtbl)).CurrentChildId)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentName(this: *mut core::ffi::c_void, pszname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationLegacyIAccessiblePattern", "CurrentName", 7usize, |this| ((**(this as *const *const IUIAutomationLegacyIAccessiblePattern_Vtbl)).CurrentName)(this, pszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentValue(this: *mut core::ffi::c_void, pszvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationLegacyIAccessiblePattern", "CurrentValue", 8usize, |this| ((**(this as *const *const IUIAutomationLegacyIAccessiblePattern_Vtbl)).CurrentValue)(this, pszvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentDescription(this: *mut core::ffi::c_void, pszdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationLegacyIAccessiblePattern", "CurrentDescription", 9usize, |this| ((**(this as *const *const IUIAutomationLegacyIAccessiblePattern_Vtbl)).CurrentDescription)(this, pszdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentRole(this: *mut core::ffi::c_void, pdwrole: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationLegacyIAccessiblePattern", "CurrentRole", 10usize, |this| ((**(this as *const *const IUIAutomationLegacyIAccessiblePattern_Vtbl)).CurrentRole)(this, pdwrole), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentState(this: *mut core::ffi::c_void, pdwstate: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationLegacyIAccessiblePattern", "CurrentState", 11usize, |this| ((**(this as *const *const IUIAutomationLegacyIAccessiblePattern_Vtbl)).CurrentState)(this, pdwstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentHelp(this: *mut core::ffi::c_void, pszhelp: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationLegacyIAccessiblePattern", "CurrentHelp", 12usize, |this| ((**(this as *const *const IUIAutomationLegacyIAccessiblePattern_Vtbl)).CurrentHelp)(this, pszhelp), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentKeyboardShortcut(this: *mut core::ffi::c_void, pszkeyboardshortcut: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationLegacyIAccessiblePattern", "CurrentKeyboardShortcut", 13usize, |this| ((**(this as *const *const IUIAutomationLegacyIAccessiblePattern_Vtbl)).CurrentKeyboardShortcut)(this, pszkeyboardshortcut), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentSelection(this: *mut core::ffi::c_void, pvarselectedchildren: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationLegacyIAccessiblePattern", "GetCurrentSelection", 14usize, |this| ((**(this as *const *const IUIAutomationLegacyIAccessiblePattern_Vtbl)).GetCurrentSelection)(this, pvarselectedchildren), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentDefaultAction(this: *mut core::ffi::c_void, pszdefaultaction: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationLegacyIAccessiblePattern", "CurrentDefaultAction", 15usize, |this| ((**(this as *const *const IUIAutomationLegacyIAccessiblePattern_Vtbl)).CurrentDefaultAction)(this, pszdefaultaction), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedChildId(this: *mut core::ffi::c_void, pretval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationLegacyIAccessiblePattern", "CachedChildId", 16usize, |this| ((**(this as *const *const IUIAutomationLegacyIAccessiblePattern_Vtbl)).CachedChildId)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedName(this: *mut core::ffi::c_void, pszname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationLegacyIAccessiblePattern", "CachedName", 17usize, |this| ((**(this as *const *const IUIAutomationLegacyIAccessiblePattern_Vtbl)).CachedName)(this, pszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedValue(this: *mut core::ffi::c_void, pszvalue: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationLegacyIAccessiblePattern", "CachedValue", 18usize, |this| ((**(this as *const *const IUIAutomationLegacyIAccessiblePattern_Vtbl)).CachedValue)(this, pszvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedDescription(this: *mut core::ffi::c_void, pszdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationLegacyIAccessiblePattern", "CachedDescription", 19usize, |this| ((**(this as *const *const IUIAutomationLegacyIAccessiblePattern_Vtbl)).CachedDescription)(this, pszdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedRole(this: *mut core::ffi::c_void, pdwrole: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationLegacyIAccessiblePattern", "CachedRole", 20usize, |this| ((**(this as *const *const IUIAutomationLegacyIAccessiblePattern_Vtbl)).CachedRole)(this, pdwrole), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedState(this: *mut core::ffi::c_void, pdwstate: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationLegacyIAccessiblePattern", "CachedState", 21usize, |this| ((**(this as *const *const IUIAutomationLegacyIAccessiblePattern_Vtbl)).CachedState)(this, pdwstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedHelp(this: *mut core::ffi::c_void, pszhelp: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationLegacyIAccessiblePattern", "CachedHelp", 22usize, |this| ((**(this as *const *const IUIAutomationLegacyIAccessiblePattern_Vtbl)).CachedHelp)(this, pszhelp), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedKeyboardShortcut(this: *mut core::ffi::c_void, pszkeyboardshortcut: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationLegacyIAccessiblePattern", "CachedKeyboardShortcut", 23usize, |this| ((**(this as *const *const IUIAutomationLegacyIAccessiblePattern_Vtbl)).CachedKeyboardShortcut)(this, pszkeyboardshortcut), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCachedSelection(this: *mut core::ffi::c_void, pvarselectedchildren: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationLegacyIAccessiblePattern", "GetCachedSelection", 24usize, |this| ((**(this as *const *const IUIAutomationLegacyIAccessiblePattern_Vtbl)).GetCachedSelection)(this, pvarselectedchildren), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedDefaultAction(this: *mut core::ffi::c_void, pszdefaultaction: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationLegacyIAccessiblePattern", "CachedDefaultAction", 25usize, |this| ((**(this as *const *const IUIAutomationLegacyIAccessiblePattern_Vtbl)).CachedDefaultAction)(this, pszdefaultaction), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetIAccessible(this: *mut core::ffi::c_void, ppaccessible: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationLegacyIAccessiblePattern", "GetIAccessible", 26usize, |this| ((**(this as *const *const IUIAutomationLegacyIAccessiblePattern_Vtbl)).GetIAccessible)(this, ppaccessible), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Select,
+            DoDefaultAction,
+            SetValue,
+            CurrentChildId,
+            CurrentName,
+            CurrentValue,
+            CurrentDescription,
+            CurrentRole,
+            CurrentState,
+            CurrentHelp,
+            CurrentKeyboardShortcut,
+            GetCurrentSelection,
+            CurrentDefaultAction,
+            CachedChildId,
+            CachedName,
+            CachedValue,
+            CachedDescription,
+            CachedRole,
+            CachedState,
+            CachedHelp,
+            CachedKeyboardShortcut,
+            GetCachedSelection,
+            CachedDefaultAction,
+            #[cfg(feature = "Win32_System_Com")]
+            GetIAccessible,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetIAccessible: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationLegacyIAccessiblePattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomationMultipleViewPattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetViewName(this: *mut core::ffi::c_void, view: i32, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationMultipleViewPattern", "GetViewName", 3usize, |this| ((**(this as *const *const IUIAutomationMultipleViewPattern_Vtbl)).GetViewName)(this, view, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentView(this: *mut core::ffi::c_void, view: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationMultipleViewPattern", "SetCurrentView", 4usize, |this| ((**(this as *const *const IUIAutomationMultipleViewPattern_Vtbl)).SetCurrentView)(this, view), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentCurrentView(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationMultipleViewPattern", "CurrentCurrentView", 5usize, |this| ((**(this as *const *const IUIAutomationMultipleViewPattern_Vtbl)).CurrentCurrentView)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetCurrentSupportedViews(this: *mut core::ffi::c_void, retval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationMultipleViewPattern", "GetCurrentSupportedViews", 6usize, |this| ((**(this as *const *const IUIAutomationMultipleViewPattern_Vtbl)).GetCurrentSupportedViews)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedCurrentView(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationMultipleViewPattern", "CachedCurrentView", 7usize, |this| ((**(this as *const *const IUIAutomationMultipleViewPattern_Vtbl)).CachedCurrentView)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetCachedSupportedViews(this: *mut core::ffi::c_void, retval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationMultipleViewPattern", "GetCachedSupportedViews", 8usize, |this| ((**(this as *const *const IUIAutomationMultipleViewPattern_Vtbl)).GetCachedSupportedViews)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetViewName,
+            SetCurrentView,
+            CurrentCurrentView,
+            #[cfg(feature = "Win32_System_Com")]
+            GetCurrentSupportedViews,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetCurrentSupportedViews: 0,
+            CachedCurrentView,
+            #[cfg(feature = "Win32_System_Com")]
+            GetCachedSupportedViews,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetCachedSupportedViews: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationMultipleViewPattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationNotCondition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetChild(this: *mut core::ffi::c_void, condition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationNotCondition", "GetChild", 3usize, |this| ((**(this as *const *const IUIAutomationNotCondition_Vtbl)).GetChild)(this, condition), |result| Some(*result))
+        }
+        Self { base__: <IUIAutomationCondition_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetChild }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationNotCondition as windows_core::Interface>::IID || <IUIAutomationCondition_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationNotificationEventHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HandleNotificationEvent(this: *mut core::ffi::c_void, sender: *mut core::ffi::c_void, notificationkind: NotificationKind, notificationprocessing: NotificationProcessing, displaystring: core::mem::MaybeUninit<windows_core::BSTR>, activityid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationNotificationEventHandler", "HandleNotificationEvent", 3usize, |this| ((**(this as *const *const IUIAutomationNotificationEventHandler_Vtbl)).HandleNotificationEvent)(this, sender, notificationkind, notificationprocessing, displaystring, activityid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HandleNotificationEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationNotificationEventHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationObjectModelPattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetUnderlyingObjectModel(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationObjectModelPattern", "GetUnderlyingObjectModel", 3usize, |this| ((**(this as *const *const IUIAutomationObjectModelPattern_Vtbl)).GetUnderlyingObjectModel)(this, retval), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetUnderlyingObjectModel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationObjectModelPattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomationOrCondition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ChildCount(this: *mut core::ffi::c_void, childcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationOrCondition", "ChildCount", 3usize, |this| ((**(this as *const *const IUIAutomationOrCondition_Vtbl)).ChildCount)(this, childcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChildrenAsNativeArray(this: *mut core::ffi::c_void, childarray: *mut *mut Option<IUIAutomationCondition>, childarraycount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationOrCondition", "GetChildrenAsNativeArray", 4usize, |this| ((**(this as *const *const IUIAutomationOrCondition_Vtbl)).GetChildrenAsNativeArray)(this, childarray, childarraycount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetChildren(this: *mut core::ffi::c_void, childarray: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationOrCondition", "GetChildren", 5usize, |this| ((**(this as *const *const IUIAutomationOrCondition_Vtbl)).GetChildren)(this, childarray), |result| Some(*result))
+        }
+        Self {
+            base__: <IUIAutomationCondition_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ChildCount,
+            GetChildrenAsNativeArray,
+            #[cfg(feature = "Win32_System_Com")]
+            GetChildren,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetChildren: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationOrCondition as windows_core::Interface>::IID || <IUIAutomationCondition_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationPatternHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateClientWrapper(this: *mut core::ffi::c_void, ppatterninstance: *mut core::ffi::c_void, pclientwrapper: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationPatternHandler", "CreateClientWrapper", 3usize, |this| ((**(this as *const *const IUIAutomationPatternHandler_Vtbl)).CreateClientWrapper)(this, ppatterninstance, pclientwrapper), |result| Some(*result))
+        }
+        unsafe extern "system" fn Dispatch(this: *mut core::ffi::c_void, ptarget: *mut core::ffi::c_void, index: u32, pparams: *const UIAutomationParameter, cparams: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationPatternHandler", "Dispatch", 4usize, |this| ((**(this as *const *const IUIAutomationPatternHandler_Vtbl)).Dispatch)(this, ptarget, index, pparams, cparams), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateClientWrapper, Dispatch }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationPatternHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationPatternInstance_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, index: u32, cached: super::super::Foundation::BOOL, r#type: UIAutomationType, pptr: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationPatternInstance", "GetProperty", 3usize, |this| ((**(this as *const *const IUIAutomationPatternInstance_Vtbl)).GetProperty)(this, index, cached, r#type, pptr), |result| Some(*result))
+        }
+        unsafe extern "system" fn CallMethod(this: *mut core::ffi::c_void, index: u32, pparams: *const UIAutomationParameter, cparams: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationPatternInstance", "CallMethod", 4usize, |this| ((**(this as *const *const IUIAutomationPatternInstance_Vtbl)).CallMethod)(this, index, pparams, cparams), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetProperty, CallMethod }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationPatternInstance as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationPropertyChangedEventHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HandlePropertyChangedEvent(this: *mut core::ffi::c_void, sender: *mut core::ffi::c_void, propertyid: UIA_PROPERTY_ID, newvalue: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationPropertyChangedEventHandler", "HandlePropertyChangedEvent", 3usize, |this| ((**(this as *const *const IUIAutomationPropertyChangedEventHandler_Vtbl)).HandlePropertyChangedEvent)(this, sender, propertyid, newvalue), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HandlePropertyChangedEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationPropertyChangedEventHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationPropertyCondition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PropertyId(this: *mut core::ffi::c_void, propertyid: *mut UIA_PROPERTY_ID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationPropertyCondition", "PropertyId", 3usize, |this| ((**(this as *const *const IUIAutomationPropertyCondition_Vtbl)).PropertyId)(this, propertyid), |result| Some(*result))
+        }
+        unsafe extern "system" fn PropertyValue(this: *mut core::ffi::c_void, propertyvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationPropertyCondition", "PropertyValue", 4usize, |this| ((**(this as *const *const IUIAutomationPropertyCondition_Vtbl)).PropertyValue)(this, propertyvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn PropertyConditionFlags(this: *mut core::ffi::c_void, flags: *mut PropertyConditionFlags) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationPropertyCondition", "PropertyConditionFlags", 5usize, |this| ((**(this as *const *const IUIAutomationPropertyCondition_Vtbl)).PropertyConditionFlags)(this, flags), |result| Some(*result))
+        }
+        Self { base__: <IUIAutomationCondition_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PropertyId, PropertyValue, PropertyConditionFlags }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationPropertyCondition as windows_core::Interface>::IID || <IUIAutomationCondition_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationProxyFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateProvider(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, idobject: i32, idchild: i32, provider: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationProxyFactory", "CreateProvider", 3usize, |this| ((**(this as *const *const IUIAutomationProxyFactory_Vtbl)).CreateProvider)(this, hwnd, idobject, idchild, provider), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProxyFactoryId(this: *mut core::ffi::c_void, factoryid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationProxyFactory", "ProxyFactoryId", 4usize, |this| ((**(this as *const *const IUIAutomationProxyFactory_Vtbl)).ProxyFactoryId)(this, factoryid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateProvider, ProxyFactoryId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationProxyFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomationProxyFactoryEntry_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProxyFactory(this: *mut core::ffi::c_void, factory: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationProxyFactoryEntry", "ProxyFactory", 3usize, |this| ((**(this as *const *const IUIAutomationProxyFactoryEntry_Vtbl)).ProxyFactory)(this, factory), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClassName(this: *mut core::ffi::c_void, classname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationProxyFactoryEntry", "ClassName", 4usize, |this| ((**(this as *const *const IUIAutomationProxyFactoryEntry_Vtbl)).ClassName)(this, classname), |result| Some(*result))
+        }
+        unsafe extern "system" fn ImageName(this: *mut core::ffi::c_void, imagename: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationProxyFactoryEntry", "ImageName", 5usize, |this| ((**(this as *const *const IUIAutomationProxyFactoryEntry_Vtbl)).ImageName)(this, imagename), |result| Some(*result))
+        }
+        unsafe extern "system" fn AllowSubstringMatch(this: *mut core::ffi::c_void, allowsubstringmatch: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationProxyFactoryEntry", "AllowSubstringMatch", 6usize, |this| ((**(this as *const *const IUIAutomationProxyFactoryEntry_Vtbl)).AllowSubstringMatch)(this, allowsubstringmatch), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanCheckBaseClass(this: *mut core::ffi::c_void, cancheckbaseclass: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationProxyFactoryEntry", "CanCheckBaseClass", 7usize, |this| ((**(this as *const *const IUIAutomationProxyFactoryEntry_Vtbl)).CanCheckBaseClass)(this, cancheckbaseclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn NeedsAdviseEvents(this: *mut core::ffi::c_void, adviseevents: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationProxyFactoryEntry", "NeedsAdviseEvents", 8usize, |this| ((**(this as *const *const IUIAutomationProxyFactoryEntry_Vtbl)).NeedsAdviseEvents)(this, adviseevents), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClassName(this: *mut core::ffi::c_void, classname: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationProxyFactoryEntry", "SetClassName", 9usize, |this| ((**(this as *const *const IUIAutomationProxyFactoryEntry_Vtbl)).SetClassName)(this, classname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetImageName(this: *mut core::ffi::c_void, imagename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationProxyFactoryEntry", "SetImageName", 10usize, |this| ((**(this as *const *const IUIAutomationProxyFactoryEntry_Vtbl)).SetImageName)(this, imagename), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllowSubstringMatch(this: *mut core::ffi::c_void, allowsubstringmatch: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationProxyFactoryEntry", "SetAllowSubstringMatch", 11usize, |this| ((**(this as *const *const IUIAutomationProxyFactoryEntry_Vtbl)).SetAllowSubstringMatch)(this, allowsubstringmatch), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCanCheckBaseClass(this: *mut core::ffi::c_void, cancheckbaseclass: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationProxyFactoryEntry", "SetCanCheckBaseClass", 12usize, |this| ((**(this as *const *const IUIAutomationProxyFactoryEntry_Vtbl)).SetCanCheckBaseClass)(this, cancheckbaseclass), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNeedsAdviseEvents(this: *mut core::ffi::c_void, adviseevents: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationProxyFactoryEntry", "SetNeedsAdviseEvents", 13usize, |this| ((**(this as *const *const IUIAutomationProxyFactoryEntry_Vtbl)).SetNeedsAdviseEvents)(this, adviseevents), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetWinEventsForAutomationEvent(this: *mut core::ffi::c_void, eventid: UIA_EVENT_ID, propertyid: UIA_PROPERTY_ID, winevents: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationProxyFactoryEntry", "SetWinEventsForAutomationEvent", 14usize, |this| ((**(this as *const *const IUIAutomationProxyFactoryEntry_Vtbl)).SetWinEventsForAutomationEvent)(this, eventid, propertyid, winevents), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetWinEventsForAutomationEvent(this: *mut core::ffi::c_void, eventid: UIA_EVENT_ID, propertyid: UIA_PROPERTY_ID, winevents: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationProxyFactoryEntry", "GetWinEventsForAutomationEvent", 15usize, |this| ((**(this as *const *const IUIAutomationProxyFactoryEntry_Vtbl)).GetWinEventsForAutomationEvent)(this, eventid, propertyid, winevents), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ProxyFactory,
+            ClassName,
+            ImageName,
+            AllowSubstringMatch,
+            CanCheckBaseClass,
+            NeedsAdviseEvents,
+            SetClassName,
+            SetImageName,
+            SetAllowSubstringMatch,
+            SetCanCheckBaseClass,
+            SetNeedsAdviseEvents,
+            #[cfg(feature = "Win32_System_Com")]
+            SetWinEventsForAutomationEvent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetWinEventsForAutomationEvent: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetWinEventsForAutomationEvent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetWinEventsForAutomationEvent: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationProxyFactoryEntry as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomationProxyFactoryMapping_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationProxyFactoryMapping", "Count", 3usize, |this| ((**(this as *const *const IUIAutomationProxyFactoryMapping_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetTable(this: *mut core::ffi::c_void, table: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationProxyFactoryMapping", "GetTable", 4usize, |this| ((**(this as *const *const IUIAutomationProxyFactoryMapping_Vtbl)).GetTable)(this, table), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEntry(this: *mut core::ffi::c_void, index: u32, entry: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationProxyFactoryMapping", "GetEntry", 5usize, |this| ((**(this as *const *const IUIAutomationProxyFactoryMapping_Vtbl)).GetEntry)(this, index, entry), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetTable(this: *mut core::ffi::c_void, factorylist: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationProxyFactoryMapping", "SetTable", 6usize, |this| ((**(this as *const *const IUIAutomationProxyFactoryMapping_Vtbl)).SetTable)(this, factorylist), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InsertEntries(this: *mut core::ffi::c_void, before: u32, factorylist: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationProxyFactoryMapping", "InsertEntries", 7usize, |this| ((**(this as *const *const IUIAutomationProxyFactoryMapping_Vtbl)).InsertEntries)(this, before, factorylist), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertEntry(this: *mut core::ffi::c_void, before: u32, factory: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationProxyFactoryMapping", "InsertEntry", 8usize, |this| ((**(this as *const *const IUIAutomationProxyFactoryMapping_Vtbl)).InsertEntry)(this, before, factory), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveEntry(this: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationProxyFactoryMapping", "RemoveEntry", 9usize, |this| ((**(this as *const *const IUIAutomationProxyFactoryMapping_Vtbl)).RemoveEntry)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearTable(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationProxyFactoryMapping", "ClearTable", 10usize, |this| ((**(this as *const *const IUIAutomationProxyFactoryMapping_Vtbl)).ClearTable)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestoreDefaultTable(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationProxyFactoryMapping", "RestoreDefaultTable", 11usize, |this| ((**(this as *const *const IUIAutomationProxyFactoryMapping_Vtbl)).RestoreDefaultTable)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            #[cfg(feature = "Win32_System_Com")]
+            GetTable,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetTable: 0,
+            GetEntry,
+            #[cfg(feature = "Win32_System_Com")]
+            SetTable,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetTable: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            InsertEntries,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InsertEntries: 0,
+            InsertEntry,
+            RemoveEntry,
+            ClearTable,
+            RestoreDefaultTable,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationProxyFactoryMapping as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationRangeValuePattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, val: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationRangeValuePattern", "SetValue", 3usize, |this| ((**(this as *const *const IUIAutomationRangeValuePattern_Vtbl)).SetValue)(this, val), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentValue(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationRangeValuePattern", "CurrentValue", 4usize, |this| ((**(this as *const *const IUIAutomationRangeValuePattern_Vtbl)).CurrentValue)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentIsReadOnly(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationRangeValuePattern", "CurrentIsReadOnly", 5usize, |this| ((**(this as *const *const IUIAutomationRangeValuePattern_Vtbl)).CurrentIsReadOnly)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentMaximum(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationRangeValuePattern", "CurrentMaximum", 6usize, |this| ((**(this as *const *const IUIAutomationRangeValuePattern_Vtbl)).CurrentMaximum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentMinimum(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationRangeValuePattern", "CurrentMinimum", 7usize, |this| ((**(this as *const *const IUIAutomationRangeValuePattern_Vtbl)).CurrentMinimum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentLargeChange(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationRangeValuePattern", "CurrentLargeChange", 8usize, |this| ((**(this as *const *const IUIAutomationRangeValuePattern_Vtbl)).CurrentLargeChange)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentSmallChange(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationRangeValuePattern", "CurrentSmallChange", 9usize, |this| ((**(this as *const *const IUIAutomationRangeValuePattern_Vtbl)).CurrentSmallChange)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedValue(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationRangeValuePattern", "CachedValue", 10usize, |this| ((**(this as *const *const IUIAutomationRangeValuePattern_Vtbl)).CachedValue)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedIsReadOnly(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationRangeValuePattern", "CachedIsReadOnly", 11usize, |this| ((**(this as *const *const IUIAutomationRangeValuePattern_Vtbl)).CachedIsReadOnly)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedMaximum(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationRangeValuePattern", "CachedMaximum", 12usize, |this| ((**(this as *const *const IUIAutomationRangeValuePattern_Vtbl)).CachedMaximum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedMinimum(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationRangeValuePattern", "CachedMinimum", 13usize, |this| ((**(this as *const *const IUIAutomationRangeValuePattern_Vtbl)).CachedMinimum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedLargeChange(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationRangeValuePattern", "CachedLargeChange", 14usize, |this| ((**(this as *const *const IUIAutomationRangeValuePattern_Vtbl)).CachedLargeChange)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedSmallChange(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationRangeValuePattern", "CachedSmallChange", 15usize, |this| ((**(this as *const *const IUIAutomationRangeValuePattern_Vtbl)).CachedSmallChange)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetValue,
+            CurrentValue,
+            CurrentIsReadOnly,
+            CurrentMaximum,
+            CurrentMinimum,
+            CurrentLargeChange,
+            CurrentSmallChange,
+            CachedValue,
+            CachedIsReadOnly,
+            CachedMaximum,
+            CachedMinimum,
+            CachedLargeChange,
+            CachedSmallChange,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationRangeValuePattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationRegistrar_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterProperty(this: *mut core::ffi::c_void, property: *const UIAutomationPropertyInfo, propertyid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationRegistrar", "RegisterProperty", 3usize, |this| ((**(this as *const *const IUIAutomationRegistrar_Vtbl)).RegisterProperty)(this, property, propertyid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterEvent(this: *mut core::ffi::c_void, event: *const UIAutomationEventInfo, eventid: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationRegistrar", "RegisterEvent", 4usize, |this| ((**(this as *const *const IUIAutomationRegistrar_Vtbl)).RegisterEvent)(this, event, eventid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterPattern(this: *mut core::ffi::c_void, pattern: *const UIAutomationPatternInfo, ppatternid: *mut i32, ppatternavailablepropertyid: *mut i32, propertyidcount: u32, ppropertyids: *mut i32, eventidcount: u32, peventids: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationRegistrar", "RegisterPattern", 5usize, |this| ((**(this as *const *const IUIAutomationRegistrar_Vtbl)).RegisterPattern)(this, pattern, ppatternid, ppatternavailablepropertyid, propertyidcount, ppropertyids, eventidcount, peventids), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterProperty, RegisterEvent, RegisterPattern }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationRegistrar as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationScrollItemPattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ScrollIntoView(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationScrollItemPattern", "ScrollIntoView", 3usize, |this| ((**(this as *const *const IUIAutomationScrollItemPattern_Vtbl)).ScrollIntoView)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ScrollIntoView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationScrollItemPattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationScrollPattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Scroll(this: *mut core::ffi::c_void, horizontalamount: ScrollAmount, verticalamount: ScrollAmount) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationScrollPattern", "Scroll", 3usize, |this| ((**(this as *const *const IUIAutomationScrollPattern_Vtbl)).Scroll)(this, horizontalamount, verticalamount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScrollPercent(this: *mut core::ffi::c_void, horizontalpercent: f64, verticalpercent: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationScrollPattern", "SetScrollPercent", 4usize, |this| ((**(this as *const *const IUIAutomationScrollPattern_Vtbl)).SetScrollPercent)(this, horizontalpercent, verticalpercent), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentHorizontalScrollPercent(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationScrollPattern", "CurrentHorizontalScrollPercent", 5usize, |this| ((**(this as *const *const IUIAutomationScrollPattern_Vtbl)).CurrentHorizontalScrollPercent)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentVerticalScrollPercent(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationScrollPattern", "CurrentVerticalScrollPercent", 6usize, |this| ((**(this as *const *const IUIAutomationScrollPattern_Vtbl)).CurrentVerticalScrollPercent)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentHorizontalViewSize(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationScrollPattern", "CurrentHorizontalViewSize", 7usize, |this| ((**(this as *const *const IUIAutomationScrollPattern_Vtbl)).CurrentHorizontalViewSize)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentVerticalViewSize(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationScrollPattern", "CurrentVerticalViewSize", 8usize, |this| ((**(this as *const *const IUIAutomationScrollPattern_Vtbl)).CurrentVerticalViewSize)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentHorizontallyScrollable(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationScrollPattern", "CurrentHorizontallyScrollable", 9usize, |this| ((**(this as *const *const IUIAutomationScrollPattern_Vtbl)).CurrentHorizontallyScrollable)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentVerticallyScrollable(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationScrollPattern", "CurrentVerticallyScrollable", 10usize, |this| ((**(this as *const *const IUIAutomationScrollPattern_Vtbl)).CurrentVerticallyScrollable)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedHorizontalScrollPercent(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationScrollPattern", "CachedHorizontalScrollPercent", 11usize, |this| ((**(this as *const *const IUIAutomationScrollPattern_Vtbl)).CachedHorizontalScrollPercent)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedVerticalScrollPercent(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationScrollPattern", "CachedVerticalScrollPercent", 12usize, |this| ((**(this as *const *const IUIAutomationScrollPattern_Vtbl)).CachedVerticalScrollPercent)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedHorizontalViewSize(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationScrollPattern", "CachedHorizontalViewSize", 13usize, |this| ((**(this as *const *const IUIAutomationScrollPattern_Vtbl)).CachedHorizontalViewSize)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedVerticalViewSize(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationScrollPattern", "CachedVerticalViewSize", 14usize, |this| ((**(this as *const *const IUIAutomationScrollPattern_Vtbl)).CachedVerticalViewSize)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedHorizontallyScrollable(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationScrollPattern", "CachedHorizontallyScrollable", 15usize, |this| ((**(this as *const *const IUIAutomationScrollPattern_Vtbl)).CachedHorizontallyScrollable)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedVerticallyScrollable(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationScrollPattern", "CachedVerticallyScrollable", 16usize, |this| ((**(this as *const *const IUIAutomationScrollPattern_Vtbl)).CachedVerticallyScrollable)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Scroll,
+            SetScrollPercent,
+            CurrentHorizontalScrollPercent,
+            CurrentVerticalScrollPercent,
+            CurrentHorizontalViewSize,
+            CurrentVerticalViewSize,
+            CurrentHorizontallyScrollable,
+            CurrentVerticallyScrollable,
+            CachedHorizontalScrollPercent,
+            CachedVerticalScrollPercent,
+            CachedHorizontalViewSize,
+            CachedVerticalViewSize,
+            CachedHorizontallyScrollable,
+            CachedVerticallyScrollable,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationScrollPattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationSelectionItemPattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Select(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSelectionItemPattern", "Select", 3usize, |this| ((**(this as *const *const IUIAutomationSelectionItemPattern_Vtbl)).Select)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddToSelection(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSelectionItemPattern", "AddToSelection", 4usize, |this| ((**(this as *const *const IUIAutomationSelectionItemPattern_Vtbl)).AddToSelection)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFromSelection(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSelectionItemPattern", "RemoveFromSelection", 5usize, |this| ((**(this as *const *const IUIAutomationSelectionItemPattern_Vtbl)).RemoveFromSelection)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentIsSelected(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSelectionItemPattern", "CurrentIsSelected", 6usize, |this| ((**(this as *const *const IUIAutomationSelectionItemPattern_Vtbl)).CurrentIsSelected)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentSelectionContainer(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSelectionItemPattern", "CurrentSelectionContainer", 7usize, |this| ((**(this as *const *const IUIAutomationSelectionItemPattern_Vtbl)).CurrentSelectionContainer)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedIsSelected(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSelectionItemPattern", "CachedIsSelected", 8usize, |this| ((**(this as *const *const IUIAutomationSelectionItemPattern_Vtbl)).CachedIsSelected)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedSelectionContainer(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSelectionItemPattern", "CachedSelectionContainer", 9usize, |this| ((**(this as *const *const IUIAutomationSelectionItemPattern_Vtbl)).CachedSelectionContainer)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Select,
+            AddToSelection,
+            RemoveFromSelection,
+            CurrentIsSelected,
+            CurrentSelectionContainer,
+            CachedIsSelected,
+            CachedSelectionContainer,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationSelectionItemPattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationSelectionPattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentSelection(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSelectionPattern", "GetCurrentSelection", 3usize, |this| ((**(this as *const *const IUIAutomationSelectionPattern_Vtbl)).GetCurrentSelection)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentCanSelectMultiple(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSelectionPattern", "CurrentCanSelectMultiple", 4usize, |this| ((**(this as *const *const IUIAutomationSelectionPattern_Vtbl)).CurrentCanSelectMultiple)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentIsSelectionRequired(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSelectionPattern", "CurrentIsSelectionRequired", 5usize, |this| ((**(this as *const *const IUIAutomationSelectionPattern_Vtbl)).CurrentIsSelectionRequired)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCachedSelection(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSelectionPattern", "GetCachedSelection", 6usize, |this| ((**(this as *const *const IUIAutomationSelectionPattern_Vtbl)).GetCachedSelection)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedCanSelectMultiple(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSelectionPattern", "CachedCanSelectMultiple", 7usize, |this| ((**(this as *const *const IUIAutomationSelectionPattern_Vtbl)).CachedCanSelectMultiple)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedIsSelectionRequired(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSelectionPattern", "CachedIsSelectionRequired", 8usize, |this| ((**(this as *const *const IUIAutomationSelectionPattern_Vtbl)).CachedIsSelectionRequired)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCurrentSelection,
+            CurrentCanSelectMultiple,
+            CurrentIsSelectionRequired,
+            GetCachedSelection,
+            CachedCanSelectMultiple,
+            CachedIsSelectionRequired,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationSelectionPattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationSelectionPattern2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentFirstSelectedItem(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSelectionPattern2", "CurrentFirstSelectedItem", 9usize, |this| ((**(this as *const *const IUIAutomationSelectionPattern2_Vtbl)).CurrentFirstSelectedItem)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentLastSelectedItem(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSelectionPattern2", "CurrentLastSelectedItem", 10usize, |this| ((**(this as *const *const IUIAutomationSelectionPattern2_Vtbl)).CurrentLastSelectedItem)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentCurrentSelectedItem(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSelectionPattern2", "CurrentCurrentSelectedItem", 11usize, |this| ((**(this as *const *const IUIAutomationSelectionPattern2_Vtbl)).CurrentCurrentSelectedItem)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentItemCount(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSelectionPattern2", "CurrentItemCount", 12usize, |this| ((**(this as *const *const IUIAutomationSelectionPattern2_Vtbl)).CurrentItemCount)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedFirstSelectedItem(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSelectionPattern2", "CachedFirstSelectedItem", 13usize, |this| ((**(this as *const *const IUIAutomationSelectionPattern2_Vtbl)).CachedFirstSelectedItem)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedLastSelectedItem(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSelectionPattern2", "CachedLastSelectedItem", 14usize, |this| ((**(this as *const *const IUIAutomationSelectionPattern2_Vtbl)).CachedLastSelectedItem)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedCurrentSelectedItem(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSelectionPattern2", "CachedCurrentSelectedItem", 15usize, |this| ((**(this as *const *const IUIAutomationSelectionPattern2_Vtbl)).CachedCurrentSelectedItem)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedItemCount(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSelectionPattern2", "CachedItemCount", 16usize, |this| ((**(this as *const *const IUIAutomationSelectionPattern2_Vtbl)).CachedItemCount)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <IUIAutomationSelectionPattern_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CurrentFirstSelectedItem,
+            CurrentLastSelectedItem,
+            CurrentCurrentSelectedItem,
+            CurrentItemCount,
+            CachedFirstSelectedItem,
+            CachedLastSelectedItem,
+            CachedCurrentSelectedItem,
+            CachedItemCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationSelectionPattern2 as windows_core::Interface>::IID || <IUIAutomationSelectionPattern_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomationSpreadsheetItemPattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentFormula(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSpreadsheetItemPattern", "CurrentFormula", 3usize, |this| ((**(this as *const *const IUIAutomationSpreadsheetItemPattern_Vtbl)).CurrentFormula)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentAnnotationObjects(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSpreadsheetItemPattern", "GetCurrentAnnotationObjects", 4usize, |this| ((**(this as *const *const IUIAutomationSpreadsheetItemPattern_Vtbl)).GetCurrentAnnotationObjects)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetCurrentAnnotationTypes(this: *mut core::ffi::c_void, retval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSpreadsheetItemPattern", "GetCurrentAnnotationTypes", 5usize, |this| ((**(this as *const *const IUIAutomationSpreadsheetItemPattern_Vtbl)).GetCurrentAnnotationTypes)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedFormula(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSpreadsheetItemPattern", "CachedFormula", 6usize, |this| ((**(this as *const *const IUIAutomationSpreadsheetItemPattern_Vtbl)).CachedFormula)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCachedAnnotationObjects(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSpreadsheetItemPattern", "GetCachedAnnotationObjects", 7usize, |this| ((**(this as *const *const IUIAutomationSpreadsheetItemPattern_Vtbl)).GetCachedAnnotationObjects)(this, retval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetCachedAnnotationTypes(this: *mut core::ffi::c_void, retval: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSpreadsheetItemPattern", "GetCachedAnnotationTypes", 8usize, |this| ((**(this as *const *const IUIAutomationSpreadsheetItemPattern_Vtbl)).GetCachedAnnotationTypes)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CurrentFormula,
+            GetCurrentAnnotationObjects,
+            #[cfg(feature = "Win32_System_Com")]
+            GetCurrentAnnotationTypes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetCurrentAnnotationTypes: 0,
+            CachedFormula,
+            GetCachedAnnotationObjects,
+            #[cfg(feature = "Win32_System_Com")]
+            GetCachedAnnotationTypes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetCachedAnnotationTypes: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationSpreadsheetItemPattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationSpreadsheetPattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetItemByName(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, element: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSpreadsheetPattern", "GetItemByName", 3usize, |this| ((**(this as *const *const IUIAutomationSpreadsheetPattern_Vtbl)).GetItemByName)(this, name, element), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetItemByName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationSpreadsheetPattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomationStructureChangedEventHandler_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn HandleStructureChangedEvent(this: *mut core::ffi::c_void, sender: *mut core::ffi::c_void, changetype: StructureChangeType, runtimeid: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationStructureChangedEventHandler", "HandleStructureChangedEvent", 3usize, |this| ((**(this as *const *const IUIAutomationStructureChangedEventHandler_Vtbl)).HandleStructureChangedEvent)(this, sender, changetype, runtimeid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            HandleStructureChangedEvent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            HandleStructureChangedEvent: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationStructureChangedEventHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationStylesPattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CurrentStyleId(this: *mut core::ffi::c_void, retval: *mut UIA_STYLE_ID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationStylesPattern", "CurrentStyleId", 3usize, |this| ((**(this as *const *const IUIAutomationStylesPattern_Vtbl)).CurrentStyleId)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentStyleName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationStylesPattern", "CurrentStyleName", 4usize, |this| ((**(this as *const *const IUIAutomationStylesPattern_Vtbl)).CurrentStyleName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentFillColor(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationStylesPattern", "CurrentFillColor", 5usize, |this| ((**(this as *const *const IUIAutomationStylesPattern_Vtbl)).CurrentFillColor)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentFillPatternStyle(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationStylesPattern", "CurrentFillPatternStyle", 6usize, |this| ((**(this as *const *const IUIAutomationStylesPattern_Vtbl)).CurrentFillPatternStyle)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentShape(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationStylesPattern", "CurrentShape", 7usize, |this| ((**(this as *const *const IUIAutomationStylesPattern_Vtbl)).CurrentShape)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentFillPatternColor(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationStylesPattern", "CurrentFillPatternColor", 8usize, |this| ((**(this as *const *const IUIAutomationStylesPattern_Vtbl)).CurrentFillPatternColor)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentExtendedProperties(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationStylesPattern", "CurrentExtendedProperties", 9usize, |this| ((**(this as *const *const IUIAutomationStylesPattern_Vtbl)).CurrentExtendedProperties)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentExtendedPropertiesAsArray(this: *mut core::ffi::c_void, propertyarray: *mut *mut ExtendedProperty, propertycount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationStylesPattern", "GetCurrentExtendedPropertiesAsArray", 10usize, |this| ((**(this as *const *const IUIAutomationStylesPattern_Vtbl)).GetCurrentExtendedPropertiesAsArray)(this, propertyarray, propertycount), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedStyleId(this: *mut core::ffi::c_void, retval: *mut UIA_STYLE_ID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationStylesPattern", "CachedStyleId", 11usize, |this| ((**(this as *const *const IUIAutomationStylesPattern_Vtbl)).CachedStyleId)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedStyleName(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationStylesPattern", "CachedStyleName", 12usize, |this| ((**(this as *const *const IUIAutomationStylesPattern_Vtbl)).CachedStyleName)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedFillColor(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationStylesPattern", "CachedFillColor", 13usize, |this| ((**(this as *const *const IUIAutomationStylesPattern_Vtbl)).CachedFillColor)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedFillPatternStyle(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationStylesPattern", "CachedFillPatternStyle", 14usize, |this| ((**(this as *const *const IUIAutomationStylesPattern_Vtbl)).CachedFillPatternStyle)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedShape(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationStylesPattern", "CachedShape", 15usize, |this| ((**(this as *const *const IUIAutomationStylesPattern_Vtbl)).CachedShape)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedFillPatternColor(this: *mut core::ffi::c_void, retval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationStylesPattern", "CachedFillPatternColor", 16usize, |this| ((**(this as *const *const IUIAutomationStylesPattern_Vtbl)).CachedFillPatternColor)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedExtendedProperties(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationStylesPattern", "CachedExtendedProperties", 17usize, |this| ((**(this as *const *const IUIAutomationStylesPattern_Vtbl)).CachedExtendedProperties)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCachedExtendedPropertiesAsArray(this: *mut core::ffi::c_void, propertyarray: *mut *mut ExtendedProperty, propertycount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationStylesPattern", "GetCachedExtendedPropertiesAsArray", 18usize, |this| ((**(this as *const *const IUIAutomationStylesPattern_Vtbl)).GetCachedExtendedPropertiesAsArray)(this, propertyarray, propertycount), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CurrentStyleId,
+            CurrentStyleName,
+            CurrentFillColor,
+            CurrentFillPatternStyle,
+            CurrentShape,
+            CurrentFillPatternColor,
+            CurrentExtendedProperties,
+            GetCurrentExtendedPropertiesAsArray,
+            CachedStyleId,
+            CachedStyleName,
+            CachedFillColor,
+            CachedFillPatternStyle,
+            CachedShape,
+            CachedFillPatternColor,
+            CachedExtendedProperties,
+            GetCachedExtendedPropertiesAsArray,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationStylesPattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationSynchronizedInputPattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartListening(this: *mut core::ffi::c_void, inputtype: SynchronizedInputType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSynchronizedInputPattern", "StartListening", 3usize, |this| ((**(this as *const *const IUIAutomationSynchronizedInputPattern_Vtbl)).StartListening)(this, inputtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cancel(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationSynchronizedInputPattern", "Cancel", 4usize, |this| ((**(this as *const *const IUIAutomationSynchronizedInputPattern_Vtbl)).Cancel)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, StartListening, Cancel }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationSynchronizedInputPattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationTableItemPattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentRowHeaderItems(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTableItemPattern", "GetCurrentRowHeaderItems", 3usize, |this| ((**(this as *const *const IUIAutomationTableItemPattern_Vtbl)).GetCurrentRowHeaderItems)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentColumnHeaderItems(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTableItemPattern", "GetCurrentColumnHeaderItems", 4usize, |this| ((**(this as *const *const IUIAutomationTableItemPattern_Vtbl)).GetCurrentColumnHeaderItems)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCachedRowHeaderItems(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTableItemPattern", "GetCachedRowHeaderItems", 5usize, |this| ((**(this as *const *const IUIAutomationTableItemPattern_Vtbl)).GetCachedRowHeaderItems)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCachedColumnHeaderItems(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTableItemPattern", "GetCachedColumnHeaderItems", 6usize, |this| ((**(this as *const *const IUIAutomationTableItemPattern_Vtbl)).GetCachedColumnHeaderItems)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCurrentRowHeaderItems,
+            GetCurrentColumnHeaderItems,
+            GetCachedRowHeaderItems,
+            GetCachedColumnHeaderItems,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationTableItemPattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationTablePattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCurrentRowHeaders(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTablePattern", "GetCurrentRowHeaders", 3usize, |this| ((**(this as *const *const IUIAutomationTablePattern_Vtbl)).GetCurrentRowHeaders)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentColumnHeaders(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTablePattern", "GetCurrentColumnHeaders", 4usize, |this| ((**(this as *const *const IUIAutomationTablePattern_Vtbl)).GetCurrentColumnHeaders)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentRowOrColumnMajor(this: *mut core::ffi::c_void, retval: *mut RowOrColumnMajor) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTablePattern", "CurrentRowOrColumnMajor", 5usize, |this| ((**(this as *const *const IUIAutomationTablePattern_Vtbl)).CurrentRowOrColumnMajor)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCachedRowHeaders(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTablePattern", "GetCachedRowHeaders", 6usize, |this| ((**(this as *const *const IUIAutomationTablePattern_Vtbl)).GetCachedRowHeaders)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCachedColumnHeaders(this: *mut core::ffi::c_void, retval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTablePattern", "GetCachedColumnHeaders", 7usize, |this| ((**(this as *const *const IUIAutomationTablePattern_Vtbl)).GetCachedColumnHeaders)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedRowOrColumnMajor(this: *mut core::ffi::c_void, retval: *mut RowOrColumnMajor) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTablePattern", "CachedRowOrColumnMajor", 8usize, |this| ((**(this as *const *const IUIAutomationTablePattern_Vtbl)).CachedRowOrColumnMajor)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCurrentRowHeaders,
+            GetCurrentColumnHeaders,
+            CurrentRowOrColumnMajor,
+            GetCachedRowHeaders,
+            GetCachedColumnHeaders,
+            CachedRowOrColumnMajor,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationTablePattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationTextChildPattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TextContainer(this: *mut core::ffi::c_void, container: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextChildPattern", "TextContainer", 3usize, |this| ((**(this as *const *const IUIAutomationTextChildPattern_Vtbl)).TextContainer)(this, container), |result| Some(*result))
+        }
+        unsafe extern "system" fn TextRange(this: *mut core::ffi::c_void, range: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextChildPattern", "TextRange", 4usize, |this| ((**(this as *const *const IUIAutomationTextChildPattern_Vtbl)).TextRange)(this, range), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TextContainer, TextRange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationTextChildPattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationTextEditPattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetActiveComposition(this: *mut core::ffi::c_void, range: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextEditPattern", "GetActiveComposition", 9usize, |this| ((**(this as *const *const IUIAutomationTextEditPattern_Vtbl)).GetActiveComposition)(this, range), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConversionTarget(this: *mut core::ffi::c_void, range: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextEditPattern", "GetConversionTarget", 10usize, |this| ((**(this as *const *const IUIAutomationTextEditPattern_Vtbl)).GetConversionTarget)(this, range), |result| Some(*result))
+        }
+        Self { base__: <IUIAutomationTextPattern_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetActiveComposition, GetConversionTarget }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationTextEditPattern as windows_core::Interface>::IID || <IUIAutomationTextPattern_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomationTextEditTextChangedEventHandler_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn HandleTextEditTextChangedEvent(this: *mut core::ffi::c_void, sender: *mut core::ffi::c_void, texteditchangetype: TextEditChangeType, eventstrings: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextEditTextChangedEventHandler", "HandleTextEditTextChangedEvent", 3usize, |this| ((**(this as *const *const IUIAutomationTextEditTextChangedEventHandler_Vtbl)).HandleTextEditTextChangedEvent)(this, sender, texteditchangetype, eventstrings), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            HandleTextEditTextChangedEvent,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            HandleTextEditTextChangedEvent: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationTextEditTextChangedEventHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationTextPattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RangeFromPoint(this: *mut core::ffi::c_void, pt: super::super::Foundation::POINT, range: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextPattern", "RangeFromPoint", 3usize, |this| ((**(this as *const *const IUIAutomationTextPattern_Vtbl)).RangeFromPoint)(this, pt, range), |result| Some(*result))
+        }
+        unsafe extern "system" fn RangeFromChild(this: *mut core::ffi::c_void, child: *mut core::ffi::c_void, range: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextPattern", "RangeFromChild", 4usize, |this| ((**(this as *const *const IUIAutomationTextPattern_Vtbl)).RangeFromChild)(this, child, range), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSelection(this: *mut core::ffi::c_void, ranges: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextPattern", "GetSelection", 5usize, |this| ((**(this as *const *const IUIAutomationTextPattern_Vtbl)).GetSelection)(this, ranges), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVisibleRanges(this: *mut core::ffi::c_void, ranges: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextPattern", "GetVisibleRanges", 6usize, |this| ((**(this as *const *const IUIAutomationTextPattern_Vtbl)).GetVisibleRanges)(this, ranges), |result| Some(*result))
+        }
+        unsafe extern "system" fn DocumentRange(this: *mut core::ffi::c_void, range: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextPattern", "DocumentRange", 7usize, |this| ((**(this as *const *const IUIAutomationTextPattern_Vtbl)).DocumentRange)(this, range), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportedTextSelection(this: *mut core::ffi::c_void, supportedtextselection: *mut SupportedTextSelection) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextPattern", "SupportedTextSelection", 8usize, |this| ((**(this as *const *const IUIAutomationTextPattern_Vtbl)).SupportedTextSelection)(this, supportedtextselection), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RangeFromPoint,
+            RangeFromChild,
+            GetSelection,
+            GetVisibleRanges,
+            DocumentRange,
+            SupportedTextSelection,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationTextPattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationTextPattern2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RangeFromAnnotation(this: *mut core::ffi::c_void, annotation: *mut core::ffi::c_void, range: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextPattern2", "RangeFromAnnotation", 9usize, |this| ((**(this as *const *const IUIAutomationTextPattern2_Vtbl)).RangeFromAnnotation)(this, annotation, range), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCaretRange(this: *mut core::ffi::c_void, isactive: *mut super::super::Foundation::BOOL, range: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextPattern2", "GetCaretRange", 10usize, |this| ((**(this as *const *const IUIAutomationTextPattern2_Vtbl)).GetCaretRange)(this, isactive, range), |result| Some(*result))
+        }
+        Self { base__: <IUIAutomationTextPattern_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RangeFromAnnotation, GetCaretRange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationTextPattern2 as windows_core::Interface>::IID || <IUIAutomationTextPattern_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomationTextRange_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, clonedrange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextRange", "Clone", 3usize, |this| ((**(this as *const *const IUIAutomationTextRange_Vtbl)).Clone)(this, clonedrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn Compare(this: *mut core::ffi::c_void, range: *mut core::ffi::c_void, aresame: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextRange", "Compare", 4usize, |this| ((**(this as *const *const IUIAutomationTextRange_Vtbl)).Compare)(this, range, aresame), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompareEndpoints(this: *mut core::ffi::c_void, srcendpoint: TextPatternRangeEndpoint, range: *mut core::ffi::c_void, targetendpoint: TextPatternRangeEndpoint, compvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextRange", "CompareEndpoints", 5usize, |this| ((**(this as *const *const IUIAutomationTextRange_Vtbl)).CompareEndpoints)(this, srcendpoint, range, targetendpoint, compvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExpandToEnclosingUnit(this: *mut core::ffi::c_void, textunit: TextUnit) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextRange", "ExpandToEnclosingUnit", 6usize, |this| ((**(this as *const *const IUIAutomationTextRange_Vtbl)).ExpandToEnclosingUnit)(this, textunit), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindAttribute(this: *mut core::ffi::c_void, attr: UIA_TEXTATTRIBUTE_ID, val: core::mem::MaybeUninit<windows_core::VARIANT>, backward: super::super::Foundation::BOOL, found: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextRange", "FindAttribute", 7usize, |this| ((**(this as *const *const IUIAutomationTextRange_Vtbl)).FindAttribute)(this, attr, val, backward, found), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindText(this: *mut core::ffi::c_void, text: core::mem::MaybeUninit<windows_core::BSTR>, backward: super::super::Foundation::BOOL, ignorecase: super::super::Foundation::BOOL, found: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextRange", "FindText", 8usize, |this| ((**(this as *const *const IUIAutomationTextRange_Vtbl)).FindText)(this, text, backward, ignorecase, found), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributeValue(this: *mut core::ffi::c_void, attr: UIA_TEXTATTRIBUTE_ID, value: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextRange", "GetAttributeValue", 9usize, |this| ((**(this as *const *const IUIAutomationTextRange_Vtbl)).GetAttributeValue)(this, attr, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetBoundingRectangles(this: *mut core::ffi::c_void, boundingrects: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextRange", "GetBoundingRectangles", 10usize, |this| ((**(this as *const *const IUIAutomationTextRange_Vtbl)).GetBoundingRectangles)(this, boundingrects), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnclosingElement(this: *mut core::ffi::c_void, enclosingelement: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextRange", "GetEnclosingElement", 11usize, |this| ((**(this as *const *const IUIAutomationTextRange_Vtbl)).GetEnclosingElement)(this, enclosingelement), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetText(this: *mut core::ffi::c_void, maxlength: i32, text: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextRange", "GetText", 12usize, |this| ((**(this as *const *const IUIAutomationTextRange_Vtbl)).GetText)(this, maxlength, text), |result| Some(*result))
+        }
+        unsafe extern "system" fn Move(this: *mut core::ffi::c_void, unit: TextUnit, count: i32, moved: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextRange", "Move", 13usize, |this| ((**(this as *const *const IUIAutomationTextRange_Vtbl)).Move)(this, unit, count, moved), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveEndpointByUnit(this: *mut core::ffi::c_void, endpoint: TextPatternRangeEndpoint, unit: TextUnit, count: i32, moved: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextRange", "MoveEndpointByUnit", 14usize, |this| ((**(this as *const *const IUIAutomationTextRange_Vtbl)).MoveEndpointByUnit)(this, endpoint, unit, count, moved), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveEndpointByRange(this: *mut core::ffi::c_void, srcendpoint: TextPatternRangeEndpoint, range: *mut core::ffi::c_void, targetendpoint: TextPatternRangeEndpoint) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextRange", "MoveEndpointByRange", 15usize, |this| ((**(this as *const *const IUIAutomationTextRange_Vtbl)).MoveEndpointByRange)(this, srcendpoint, range, targetendpoint), |result| Some(*result))
+        }
+        unsafe extern "system" fn Select(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextRange", "Select", 16usize, |this| ((**(this as *const *const IUIAutomationTextRange_Vtbl)).Select)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddToSelection(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextRange", "AddToSelection", 17usize, |this| ((**(this as *const *const IUIAutomationTextRange_Vtbl)).AddToSelection)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFromSelection(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextRange", "RemoveFromSelection", 18usize, |this| ((**(this as *const *const IUIAutomationTextRange_Vtbl)).RemoveFromSelection)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScrollIntoView(this: *mut core::ffi::c_void, aligntotop: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextRange", "ScrollIntoView", 19usize, |this| ((**(this as *const *const IUIAutomationTextRange_Vtbl)).ScrollIntoView)(this, aligntotop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChildren(this: *mut core::ffi::c_void, children: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextRange", "GetChildren", 20usize, |this| ((**(this as *const *const IUIAutomationTextRange_Vtbl)).GetChildren)(this, children), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Clone,
+            Compare,
+            CompareEndpoints,
+            ExpandToEnclosingUnit,
+            FindAttribute,
+            FindText,
+            GetAttributeValue,
+            #[cfg(feature = "Win32_System_Com")]
+            GetBoundingRectangles,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetBoundingRectangles: 0,
+            GetEnclosingElement,
+            GetText,
+            Move,
+            MoveEndpointByUnit,
+            MoveEndpointByRange,
+            Select,
+            AddToSelection,
+            RemoveFromSelection,
+            ScrollIntoView,
+            GetChildren,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationTextRange as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomationTextRange2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowContextMenu(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextRange2", "ShowContextMenu", 21usize, |this| ((**(this as *const *const IUIAutomationTextRange2_Vtbl)).ShowContextMenu)(this), |result| Some(*result))
+        }
+        Self { base__: <IUIAutomationTextRange_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShowContextMenu }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationTextRange2 as windows_core::Interface>::IID || <IUIAutomationTextRange_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIAutomationTextRange3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEnclosingElementBuildCache(this: *mut core::ffi::c_void, cacherequest: *mut core::ffi::c_void, enclosingelement: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextRange3", "GetEnclosingElementBuildCache", 22usize, |this| ((**(this as *const *const IUIAutomationTextRange3_Vtbl)).GetEnclosingElementBuildCache)(this, cacherequest, enclosingelement), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChildrenBuildCache(this: *mut core::ffi::c_void, cacherequest: *mut core::ffi::c_void, children: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextRange3", "GetChildrenBuildCache", 23usize, |this| ((**(this as *const *const IUIAutomationTextRange3_Vtbl)).GetChildrenBuildCache)(this, cacherequest, children), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetAttributeValues(this: *mut core::ffi::c_void, attributeids: *const UIA_TEXTATTRIBUTE_ID, attributeidcount: i32, attributevalues: *mut *mut super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextRange3", "GetAttributeValues", 24usize, |this| ((**(this as *const *const IUIAutomationTextRange3_Vtbl)).GetAttributeValues)(this, attributeids, attributeidcount, attributevalues), |result| Some(*result))
+        }
+        Self {
+            base__: <IUIAutomationTextRange2_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetEnclosingElementBuildCache,
+            GetChildrenBuildCache,
+            #[cfg(feature = "Win32_System_Com")]
+            GetAttributeValues,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetAttributeValues: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationTextRange3 as windows_core::Interface>::IID || <IUIAutomationTextRange2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationTextRangeArray_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Length(this: *mut core::ffi::c_void, length: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextRangeArray", "Length", 3usize, |this| ((**(this as *const *const IUIAutomationTextRangeArray_Vtbl)).Length)(this, length), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetElement(this: *mut core::ffi::c_void, index: i32, element: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTextRangeArray", "GetElement", 4usize, |this| ((**(this as *const *const IUIAutomationTextRangeArray_Vtbl)).GetElement)(this, index, element), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Length, GetElement }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationTextRangeArray as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationTogglePattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Toggle(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTogglePattern", "Toggle", 3usize, |this| ((**(this as *const *const IUIAutomationTogglePattern_Vtbl)).Toggle)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentToggleState(this: *mut core::ffi::c_void, retval: *mut ToggleState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTogglePattern", "CurrentToggleState", 4usize, |this| ((**(this as *const *const IUIAutomationTogglePattern_Vtbl)).CurrentToggleState)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedToggleState(this: *mut core::ffi::c_void, retval: *mut ToggleState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTogglePattern", "CachedToggleState", 5usize, |this| ((**(this as *const *const IUIAutomationTogglePattern_Vtbl)).CachedToggleState)(this, retval), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Toggle, CurrentToggleState, CachedToggleState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationTogglePattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationTransformPattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Move(this: *mut core::ffi::c_void, x: f64, y: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTransformPattern", "Move", 3usize, |this| ((**(this as *const *const IUIAutomationTransformPattern_Vtbl)).Move)(this, x, y), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resize(this: *mut core::ffi::c_void, width: f64, height: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTransformPattern", "Resize", 4usize, |this| ((**(this as *const *const IUIAutomationTransformPattern_Vtbl)).Resize)(this, width, height), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rotate(this: *mut core::ffi::c_void, degrees: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTransformPattern", "Rotate", 5usize, |this| ((**(this as *const *const IUIAutomationTransformPattern_Vtbl)).Rotate)(this, degrees), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentCanMove(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTransformPattern", "CurrentCanMove", 6usize, |this| ((**(this as *const *const IUIAutomationTransformPattern_Vtbl)).CurrentCanMove)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentCanResize(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTransformPattern", "CurrentCanResize", 7usize, |this| ((**(this as *const *const IUIAutomationTransformPattern_Vtbl)).CurrentCanResize)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentCanRotate(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTransformPattern", "CurrentCanRotate", 8usize, |this| ((**(this as *const *const IUIAutomationTransformPattern_Vtbl)).CurrentCanRotate)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedCanMove(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTransformPattern", "CachedCanMove", 9usize, |this| ((**(this as *const *const IUIAutomationTransformPattern_Vtbl)).CachedCanMove)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedCanResize(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTransformPattern", "CachedCanResize", 10usize, |this| ((**(this as *const *const IUIAutomationTransformPattern_Vtbl)).CachedCanResize)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedCanRotate(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTransformPattern", "CachedCanRotate", 11usize, |this| ((**(this as *const *const IUIAutomationTransformPattern_Vtbl)).CachedCanRotate)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Move,
+            Resize,
+            Rotate,
+            CurrentCanMove,
+            CurrentCanResize,
+            CurrentCanRotate,
+            CachedCanMove,
+            CachedCanResize,
+            CachedCanRotate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationTransformPattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationTransformPattern2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Zoom(this: *mut core::ffi::c_void, zoomvalue: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTransformPattern2", "Zoom", 12usize, |this| ((**(this as *const *const IUIAutomationTransformPattern2_Vtbl)).Zoom)(this, zoomvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ZoomByUnit(this: *mut core::ffi::c_void, zoomunit: ZoomUnit) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTransformPattern2", "ZoomByUnit", 13usize, |this| ((**(this as *const *const IUIAutomationTransformPattern2_Vtbl)).ZoomByUnit)(this, zoomunit), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentCanZoom(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTransformPattern2", "CurrentCanZoom", 14usize, |this| ((**(this as *const *const IUIAutomationTransformPattern2_Vtbl)).CurrentCanZoom)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedCanZoom(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTransformPattern2", "CachedCanZoom", 15usize, |this| ((**(this as *const *const IUIAutomationTransformPattern2_Vtbl)).CachedCanZoom)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentZoomLevel(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTransformPattern2", "CurrentZoomLevel", 16usize, |this| ((**(this as *const *const IUIAutomationTransformPattern2_Vtbl)).CurrentZoomLevel)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedZoomLevel(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTransformPattern2", "CachedZoomLevel", 17usize, |this| ((**(this as *const *const IUIAutomationTransformPattern2_Vtbl)).CachedZoomLevel)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentZoomMinimum(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTransformPattern2", "CurrentZoomMinimum", 18usize, |this| ((**(this as *const *const IUIAutomationTransformPattern2_Vtbl)).CurrentZoomMinimum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedZoomMinimum(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTransformPattern2", "CachedZoomMinimum", 19usize, |this| ((**(this as *const *const IUIAutomationTransformPattern2_Vtbl)).CachedZoomMinimum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentZoomMaximum(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTransformPattern2", "CurrentZoomMaximum", 20usize, |this| ((**(this as *const *const IUIAutomationTransformPattern2_Vtbl)).CurrentZoomMaximum)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedZoomMaximum(this: *mut core::ffi::c_void, retval: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTransformPattern2", "CachedZoomMaximum", 21usize, |this| ((**(this as *const *const IUIAutomationTransformPattern2_Vtbl)).CachedZoomMaximum)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <IUIAutomationTransformPattern_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Zoom,
+            ZoomByUnit,
+            CurrentCanZoom,
+            CachedCanZoom,
+            CurrentZoomLevel,
+            CachedZoomLevel,
+            CurrentZoomMinimum,
+            CachedZoomMinimum,
+            CurrentZoomMaximum,
+            CachedZoomMaximum,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationTransformPattern2 as windows_core::Interface>::IID || <IUIAutomationTransformPattern_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationTreeWalker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetParentElement(this: *mut core::ffi::c_void, element: *mut core::ffi::c_void, parent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTreeWalker", "GetParentElement", 3usize, |this| ((**(this as *const *const IUIAutomationTreeWalker_Vtbl)).GetParentElement)(this, element, parent), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFirstChildElement(this: *mut core::ffi::c_void, element: *mut core::ffi::c_void, first: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTreeWalker", "GetFirstChildElement", 4usize, |this| ((**(this as *const *const IUIAutomationTreeWalker_Vtbl)).GetFirstChildElement)(this, element, first), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastChildElement(this: *mut core::ffi::c_void, element: *mut core::ffi::c_void, last: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTreeWalker", "GetLastChildElement", 5usize, |this| ((**(this as *const *const IUIAutomationTreeWalker_Vtbl)).GetLastChildElement)(this, element, last), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextSiblingElement(this: *mut core::ffi::c_void, element: *mut core::ffi::c_void, next: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTreeWalker", "GetNextSiblingElement", 6usize, |this| ((**(this as *const *const IUIAutomationTreeWalker_Vtbl)).GetNextSiblingElement)(this, element, next), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreviousSiblingElement(this: *mut core::ffi::c_void, element: *mut core::ffi::c_void, previous: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTreeWalker", "GetPreviousSiblingElement", 7usize, |this| ((**(this as *const *const IUIAutomationTreeWalker_Vtbl)).GetPreviousSiblingElement)(this, element, previous), |result| Some(*result))
+        }
+        unsafe extern "system" fn NormalizeElement(this: *mut core::ffi::c_void, element: *mut core::ffi::c_void, normalized: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTreeWalker", "NormalizeElement", 8usize, |this| ((**(this as *const *const IUIAutomationTreeWalker_Vtbl)).NormalizeElement)(this, element, normalized), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParentElementBuildCache(this: *mut core::ffi::c_void, element: *mut core::ffi::c_void, cacherequest: *mut core::ffi::c_void, parent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTreeWalker", "GetParentElementBuildCache", 9usize, |this| ((**(this as *const *const IUIAutomationTreeWalker_Vtbl)).GetParentElementBuildCache)(this, element, cacherequest, parent), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFirstChildElementBuildCache(this: *mut core::ffi::c_void, element: *mut core::ffi::c_void, cacherequest: *mut core::ffi::c_void, first: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTreeWalker", "GetFirstChildElementBuildCache", 10usize, |this| ((**(this as *const *const IUIAutomationTreeWalker_Vtbl)).GetFirstChildElementBuildCache)(this, element, cacherequest, first), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLastChildElementBuildCache(this: *mut core::ffi::c_void, element: *mut core::ffi::c_void, cacherequest: *mut core::ffi::c_void, last: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTreeWalker", "GetLastChildElementBuildCache", 11usize, |this| ((**(this as *const *const IUIAutomationTreeWalker_Vtbl)).GetLastChildElementBuildCache)(this, element, cacherequest, last), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNextSiblingElementBuildCache(this: *mut core::ffi::c_void, element: *mut core::ffi::c_void, cacherequest: *mut core::ffi::c_void, next: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTreeWalker", "GetNextSiblingElementBuildCache", 12usize, |this| ((**(this as *const *const IUIAutomationTreeWalker_Vtbl)).GetNextSiblingElementBuildCache)(this, element, cacherequest, next), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreviousSiblingElementBuildCache(this: *mut core::ffi::c_void, element: *mut core::ffi::c_void, cacherequest: *mut core::ffi::c_void, previous: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTreeWalker", "GetPreviousSiblingElementBuildCache", 13usize, |this| ((**(this as *const *const IUIAutomationTreeWalker_Vtbl)).GetPreviousSiblingElementBuildCache)(this, element, cacherequest, previous), |result| Some(*result))
+        }
+        unsafe extern "system" fn NormalizeElementBuildCache(this: *mut core::ffi::c_void, element: *mut core::ffi::c_void, cacherequest: *mut core::ffi::c_void, normalized: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTreeWalker", "NormalizeElementBuildCache", 14usize, |this| ((**(this as *const *const IUIAutomationTreeWalker_Vtbl)).NormalizeElementBuildCache)(this, element, cacherequest, normalized), |result| Some(*result))
+        }
+        unsafe extern "system" fn Condition(this: *mut core::ffi::c_void, condition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationTreeWalker", "Condition", 15usize, |this| ((**(this as *const *const IUIAutomationTreeWalker_Vtbl)).Condition)(this, condition), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetParentElement,
+            GetFirstChildElement,
+            GetLastChildElement,
+            GetNextSiblingElement,
+            GetPreviousSiblingElement,
+            NormalizeElement,
+            GetParentElementBuildCache,
+            GetFirstChildElementBuildCache,
+            GetLastChildElementBuildCache,
+            GetNextSiblingElementBuildCache,
+            GetPreviousSiblingElementBuildCache,
+            NormalizeElementBuildCache,
+            Condition,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationTreeWalker as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationValuePattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, val: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationValuePattern", "SetValue", 3usize, |this| ((**(this as *const *const IUIAutomationValuePattern_Vtbl)).SetValue)(this, val), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentValue(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationValuePattern", "CurrentValue", 4usize, |this| ((**(this as *const *const IUIAutomationValuePattern_Vtbl)).CurrentValue)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentIsReadOnly(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationValuePattern", "CurrentIsReadOnly", 5usize, |this| ((**(this as *const *const IUIAutomationValuePattern_Vtbl)).CurrentIsReadOnly)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedValue(this: *mut core::ffi::c_void, retval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationValuePattern", "CachedValue", 6usize, |this| ((**(this as *const *const IUIAutomationValuePattern_Vtbl)).CachedValue)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedIsReadOnly(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationValuePattern", "CachedIsReadOnly", 7usize, |this| ((**(this as *const *const IUIAutomationValuePattern_Vtbl)).CachedIsReadOnly)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetValue,
+            CurrentValue,
+            CurrentIsReadOnly,
+            CachedValue,
+            CachedIsReadOnly,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationValuePattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationVirtualizedItemPattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Realize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationVirtualizedItemPattern", "Realize", 3usize, |this| ((**(this as *const *const IUIAutomationVirtualizedItemPattern_Vtbl)).Realize)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Realize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationVirtualizedItemPattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAutomationWindowPattern_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationWindowPattern", "Close", 3usize, |this| ((**(this as *const *const IUIAutomationWindowPattern_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForInputIdle(this: *mut core::ffi::c_void, milliseconds: i32, success: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationWindowPattern", "WaitForInputIdle", 4usize, |this| ((**(this as *const *const IUIAutomationWindowPattern_Vtbl)).WaitForInputIdle)(this, milliseconds, success), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWindowVisualState(this: *mut core::ffi::c_void, state: WindowVisualState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationWindowPattern", "SetWindowVisualState", 5usize, |this| ((**(this as *const *const IUIAutomationWindowPattern_Vtbl)).SetWindowVisualState)(this, state), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentCanMaximize(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationWindowPattern", "CurrentCanMaximize", 6usize, |this| ((**(this as *const *const IUIAutomationWindowPattern_Vtbl)).CurrentCanMaximize)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentCanMinimize(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationWindowPattern", "CurrentCanMinimize", 7usize, |this| ((**(this as *const *const IUIAutomationWindowPattern_Vtbl)).CurrentCanMinimize)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentIsModal(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationWindowPattern", "CurrentIsModal", 8usize, |this| ((**(this as *const *const IUIAutomationWindowPattern_Vtbl)).CurrentIsModal)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentIsTopmost(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationWindowPattern", "CurrentIsTopmost", 9usize, |this| ((**(this as *const *const IUIAutomationWindowPattern_Vtbl)).CurrentIsTopmost)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentWindowVisualState(this: *mut core::ffi::c_void, retval: *mut WindowVisualState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationWindowPattern", "CurrentWindowVisualState", 10usize, |this| ((**(this as *const *const IUIAutomationWindowPattern_Vtbl)).CurrentWindowVisualState)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentWindowInteractionState(this: *mut core::ffi::c_void, retval: *mut WindowInteractionState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationWindowPattern", "CurrentWindowInteractionState", 11usize, |this| ((**(this as *const *const IUIAutomationWindowPattern_Vtbl)).CurrentWindowInteractionState)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedCanMaximize(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationWindowPattern", "CachedCanMaximize", 12usize, |this| ((**(this as *const *const IUIAutomationWindowPattern_Vtbl)).CachedCanMaximize)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedCanMinimize(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationWindowPattern", "CachedCanMinimize", 13usize, |this| ((**(this as *const *const IUIAutomationWindowPattern_Vtbl)).CachedCanMinimize)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedIsModal(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationWindowPattern", "CachedIsModal", 14usize, |this| ((**(this as *const *const IUIAutomationWindowPattern_Vtbl)).CachedIsModal)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedIsTopmost(this: *mut core::ffi::c_void, retval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationWindowPattern", "CachedIsTopmost", 15usize, |this| ((**(this as *const *const IUIAutomationWindowPattern_Vtbl)).CachedIsTopmost)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedWindowVisualState(this: *mut core::ffi::c_void, retval: *mut WindowVisualState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationWindowPattern", "CachedWindowVisualState", 16usize, |this| ((**(this as *const *const IUIAutomationWindowPattern_Vtbl)).CachedWindowVisualState)(this, retval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CachedWindowInteractionState(this: *mut core::ffi::c_void, retval: *mut WindowInteractionState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAutomationWindowPattern", "CachedWindowInteractionState", 17usize, |this| ((**(this as *const *const IUIAutomationWindowPattern_Vtbl)).CachedWindowInteractionState)(this, retval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Close,
+            WaitForInputIdle,
+            SetWindowVisualState,
+            CurrentCanMaximize,
+            CurrentCanMinimize,
+            CurrentIsModal,
+            CurrentIsTopmost,
+            CurrentWindowVisualState,
+            CurrentWindowInteractionState,
+            CachedCanMaximize,
+            CachedCanMinimize,
+            CachedIsModal,
+            CachedIsTopmost,
+            CachedWindowVisualState,
+            CachedWindowInteractionState,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAutomationWindowPattern as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IValueProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, val: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueProvider", "SetValue", 3usize, |this| ((**(this as *const *const IValueProvider_Vtbl)).SetValue)(this, val), |result| Some(*result))
+        }
+        unsafe extern "system" fn Value(this: *mut core::ffi::c_void, pretval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueProvider", "Value", 4usize, |this| ((**(this as *const *const IValueProvider_Vtbl)).Value)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsReadOnly(this: *mut core::ffi::c_void, pretval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IValueProvider", "IsReadOnly", 5usize, |this| ((**(this as *const *const IValueProvider_Vtbl)).IsReadOnly)(this, pretval), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetValue, Value, IsReadOnly }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IValueProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVirtualizedItemProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Realize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVirtualizedItemProvider", "Realize", 3usize, |this| ((**(this as *const *const IVirtualizedItemProvider_Vtbl)).Realize)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Realize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVirtualizedItemProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWindowProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetVisualState(this: *mut core::ffi::c_void, state: WindowVisualState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowProvider", "SetVisualState", 3usize, |this| ((**(this as *const *const IWindowProvider_Vtbl)).SetVisualState)(this, state), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowProvider", "Close", 4usize, |this| ((**(this as *const *const IWindowProvider_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn WaitForInputIdle(this: *mut core::ffi::c_void, milliseconds: i32, pretval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowProvider", "WaitForInputIdle", 5usize, |this| ((**(this as *const *const IWindowProvider_Vtbl)).WaitForInputIdle)(this, milliseconds, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanMaximize(this: *mut core::ffi::c_void, pretval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowProvider", "CanMaximize", 6usize, |this| ((**(this as *const *const IWindowProvider_Vtbl)).CanMaximize)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanMinimize(this: *mut core::ffi::c_void, pretval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowProvider", "CanMinimize", 7usize, |this| ((**(this as *const *const IWindowProvider_Vtbl)).CanMinimize)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsModal(this: *mut core::ffi::c_void, pretval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowProvider", "IsModal", 8usize, |this| ((**(this as *const *const IWindowProvider_Vtbl)).IsModal)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn WindowVisualState(this: *mut core::ffi::c_void, pretval: *mut WindowVisualState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowProvider", "WindowVisualState", 9usize, |this| ((**(this as *const *const IWindowProvider_Vtbl)).WindowVisualState)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn WindowInteractionState(this: *mut core::ffi::c_void, pretval: *mut WindowInteractionState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowProvider", "WindowInteractionState", 10usize, |this| ((**(this as *const *const IWindowProvider_Vtbl)).WindowInteractionState)(this, pretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsTopmost(this: *mut core::ffi::c_void, pretval: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWindowProvider", "IsTopmost", 11usize, |this| ((**(this as *const *const IWindowProvider_Vtbl)).IsTopmost)(this, pretval), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetVisualState,
+            Close,
+            WaitForInputIdle,
+            CanMaximize,
+            CanMinimize,
+            IsModal,
+            WindowVisualState,
+            WindowInteractionState,
+            IsTopmost,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWindowProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
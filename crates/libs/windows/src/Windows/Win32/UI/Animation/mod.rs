@@ -1597,6 +1597,7 @@ pub struct UI_ANIMATION_DEPENDENCIES(pub i32);
 impl windows_core::TypeKind for UI_ANIMATION_DEPENDENCIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_ANIMATION_DEPENDENCIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_ANIMATION_DEPENDENCIES").field(&self.0).finish()
@@ -1641,6 +1642,7 @@ pub struct UI_ANIMATION_IDLE_BEHAVIOR(pub i32);
 impl windows_core::TypeKind for UI_ANIMATION_IDLE_BEHAVIOR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_ANIMATION_IDLE_BEHAVIOR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_ANIMATION_IDLE_BEHAVIOR").field(&self.0).finish()
@@ -1652,6 +1654,7 @@ pub struct UI_ANIMATION_MANAGER_STATUS(pub i32);
 impl windows_core::TypeKind for UI_ANIMATION_MANAGER_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_ANIMATION_MANAGER_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_ANIMATION_MANAGER_STATUS").field(&self.0).finish()
@@ -1663,6 +1666,7 @@ pub struct UI_ANIMATION_MODE(pub i32);
 impl windows_core::TypeKind for UI_ANIMATION_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_ANIMATION_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_ANIMATION_MODE").field(&self.0).finish()
@@ -1674,6 +1678,7 @@ pub struct UI_ANIMATION_PRIORITY_EFFECT(pub i32);
 impl windows_core::TypeKind for UI_ANIMATION_PRIORITY_EFFECT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_ANIMATION_PRIORITY_EFFECT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_ANIMATION_PRIORITY_EFFECT").field(&self.0).finish()
@@ -1685,6 +1690,7 @@ pub struct UI_ANIMATION_REPEAT_MODE(pub i32);
 impl windows_core::TypeKind for UI_ANIMATION_REPEAT_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_ANIMATION_REPEAT_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_ANIMATION_REPEAT_MODE").field(&self.0).finish()
@@ -1696,6 +1702,7 @@ pub struct UI_ANIMATION_ROUNDING_MODE(pub i32);
 impl windows_core::TypeKind for UI_ANIMATION_ROUNDING_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_ANIMATION_ROUNDING_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_ANIMATION_ROUNDING_MODE").field(&self.0).finish()
@@ -1707,6 +1714,7 @@ pub struct UI_ANIMATION_SCHEDULING_RESULT(pub i32);
 impl windows_core::TypeKind for UI_ANIMATION_SCHEDULING_RESULT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_ANIMATION_SCHEDULING_RESULT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_ANIMATION_SCHEDULING_RESULT").field(&self.0).finish()
@@ -1718,6 +1726,7 @@ pub struct UI_ANIMATION_SLOPE(pub i32);
 impl windows_core::TypeKind for UI_ANIMATION_SLOPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_ANIMATION_SLOPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_ANIMATION_SLOPE").field(&self.0).finish()
@@ -1729,6 +1738,7 @@ pub struct UI_ANIMATION_STORYBOARD_STATUS(pub i32);
 impl windows_core::TypeKind for UI_ANIMATION_STORYBOARD_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_ANIMATION_STORYBOARD_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_ANIMATION_STORYBOARD_STATUS").field(&self.0).finish()
@@ -1740,6 +1750,7 @@ pub struct UI_ANIMATION_TIMER_CLIENT_STATUS(pub i32);
 impl windows_core::TypeKind for UI_ANIMATION_TIMER_CLIENT_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_ANIMATION_TIMER_CLIENT_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_ANIMATION_TIMER_CLIENT_STATUS").field(&self.0).finish()
@@ -1751,6 +1762,7 @@ pub struct UI_ANIMATION_UPDATE_RESULT(pub i32);
 impl windows_core::TypeKind for UI_ANIMATION_UPDATE_RESULT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_ANIMATION_UPDATE_RESULT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_ANIMATION_UPDATE_RESULT").field(&self.0).finish()
@@ -1764,7 +1776,8 @@ pub const UIAnimationTransitionFactory2: windows_core::GUID = windows_core::GUID
 pub const UIAnimationTransitionLibrary: windows_core::GUID = windows_core::GUID::from_u128(0x1d6322ad_aa85_4ef5_a828_86d71067d145);
 pub const UIAnimationTransitionLibrary2: windows_core::GUID = windows_core::GUID::from_u128(0x812f944a_c5c8_4cd9_b0a6_b3da802f228d);
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UI_ANIMATION_KEYFRAME(pub isize);
 impl Default for UI_ANIMATION_KEYFRAME {
     fn default() -> Self {
@@ -1776,3 +1789,5 @@ impl windows_core::TypeKind for UI_ANIMATION_KEYFRAME {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
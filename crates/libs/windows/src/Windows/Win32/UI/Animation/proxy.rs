@@ -0,0 +1,1114 @@
+impl windows_core::imp::ProxyVtable for IUIAnimationInterpolator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetInitialValueAndVelocity(this: *mut core::ffi::c_void, initialvalue: f64, initialvelocity: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationInterpolator", "SetInitialValueAndVelocity", 3usize, |this| ((**(this as *const *const IUIAnimationInterpolator_Vtbl)).SetInitialValueAndVelocity)(this, initialvalue, initialvelocity), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDuration(this: *mut core::ffi::c_void, duration: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationInterpolator", "SetDuration", 4usize, |this| ((**(this as *const *const IUIAnimationInterpolator_Vtbl)).SetDuration)(this, duration), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDuration(this: *mut core::ffi::c_void, duration: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationInterpolator", "GetDuration", 5usize, |this| ((**(this as *const *const IUIAnimationInterpolator_Vtbl)).GetDuration)(this, duration), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFinalValue(this: *mut core::ffi::c_void, value: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationInterpolator", "GetFinalValue", 6usize, |this| ((**(this as *const *const IUIAnimationInterpolator_Vtbl)).GetFinalValue)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InterpolateValue(this: *mut core::ffi::c_void, offset: f64, value: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationInterpolator", "InterpolateValue", 7usize, |this| ((**(this as *const *const IUIAnimationInterpolator_Vtbl)).InterpolateValue)(this, offset, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn InterpolateVelocity(this: *mut core::ffi::c_void, offset: f64, velocity: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationInterpolator", "InterpolateVelocity", 8usize, |this| ((**(this as *const *const IUIAnimationInterpolator_Vtbl)).InterpolateVelocity)(this, offset, velocity), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDependencies(this: *mut core::ffi::c_void, initialvaluedependencies: *mut UI_ANIMATION_DEPENDENCIES, initialvelocitydependencies: *mut UI_ANIMATION_DEPENDENCIES, durationdependencies: *mut UI_ANIMATION_DEPENDENCIES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationInterpolator", "GetDependencies", 9usize, |this| ((**(this as *const *const IUIAnimationInterpolator_Vtbl)).GetDependencies)(this, initialvaluedependencies, initialvelocitydependencies, durationdependencies), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetInitialValueAndVelocity,
+            SetDuration,
+            GetDuration,
+            GetFinalValue,
+            InterpolateValue,
+            InterpolateVelocity,
+            GetDependencies,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationInterpolator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationInterpolator2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDimension(this: *mut core::ffi::c_void, dimension: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationInterpolator2", "GetDimension", 3usize, |this| ((**(this as *const *const IUIAnimationInterpolator2_Vtbl)).GetDimension)(this, dimension), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInitialValueAndVelocity(this: *mut core::ffi::c_void, initialvalue: *const f64, initialvelocity: *const f64, cdimension: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationInterpolator2", "SetInitialValueAndVelocity", 4usize, |this| ((**(this as *const *const IUIAnimationInterpolator2_Vtbl)).SetInitialValueAndVelocity)(this, initialvalue, initialvelocity, cdimension), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDuration(this: *mut core::ffi::c_void, duration: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationInterpolator2", "SetDuration", 5usize, |this| ((**(this as *const *const IUIAnimationInterpolator2_Vtbl)).SetDuration)(this, duration), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDuration(this: *mut core::ffi::c_void, duration: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationInterpolator2", "GetDuration", 6usize, |this| ((**(this as *const *const IUIAnimationInterpolator2_Vtbl)).GetDuration)(this, duration), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFinalValue(this: *mut core::ffi::c_void, value: *mut f64, cdimension: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationInterpolator2", "GetFinalValue", 7usize, |this| ((**(this as *const *const IUIAnimationInterpolator2_Vtbl)).GetFinalValue)(this, value, cdimension), |result| Some(*result))
+        }
+        unsafe extern "system" fn InterpolateValue(this: *mut core::ffi::c_void, offset: f64, value: *mut f64, cdimension: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationInterpolator2", "InterpolateValue", 8usize, |this| ((**(this as *const *const IUIAnimationInterpolator2_Vtbl)).InterpolateValue)(this, offset, value, cdimension), |result| Some(*result))
+        }
+        unsafe extern "system" fn InterpolateVelocity(this: *mut core::ffi::c_void, offset: f64, velocity: *mut f64, cdimension: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationInterpolator2", "InterpolateVelocity", 9usize, |this| ((**(this as *const *const IUIAnimationInterpolator2_Vtbl)).InterpolateVelocity)(this, offset, velocity, cdimension), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrimitiveInterpolation(this: *mut core::ffi::c_void, interpolation: *mut core::ffi::c_void, cdimension: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationInterpolator2", "GetPrimitiveInterpolation", 10usize, |this| ((**(this as *const *const IUIAnimationInterpolator2_Vtbl)).GetPrimitiveInterpolation)(this, interpolation, cdimension), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDependencies(this: *mut core::ffi::c_void, initialvaluedependencies: *mut UI_ANIMATION_DEPENDENCIES, initialvelocitydependencies: *mut UI_ANIMATION_DEPENDENCIES, durationdependencies: *mut UI_ANIMATION_DEPENDENCIES) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationInterpolator2", "GetDependencies", 11usize, |this| ((**(this as *const *const IUIAnimationInterpolator2_Vtbl)).GetDependencies)(this, initialvaluedependencies, initialvelocitydependencies, durationdependencies), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDimension,
+            SetInitialValueAndVelocity,
+            SetDuration,
+            GetDuration,
+            GetFinalValue,
+            InterpolateValue,
+            InterpolateVelocity,
+            GetPrimitiveInterpolation,
+            GetDependencies,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationInterpolator2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationLoopIterationChangeHandler2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnLoopIterationChanged(this: *mut core::ffi::c_void, storyboard: *mut core::ffi::c_void, id: usize, newiterationcount: u32, olditerationcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationLoopIterationChangeHandler2", "OnLoopIterationChanged", 3usize, |this| ((**(this as *const *const IUIAnimationLoopIterationChangeHandler2_Vtbl)).OnLoopIterationChanged)(this, storyboard, id, newiterationcount, olditerationcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnLoopIterationChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationLoopIterationChangeHandler2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateAnimationVariable(this: *mut core::ffi::c_void, initialvalue: f64, variable: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager", "CreateAnimationVariable", 3usize, |this| ((**(this as *const *const IUIAnimationManager_Vtbl)).CreateAnimationVariable)(this, initialvalue, variable), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScheduleTransition(this: *mut core::ffi::c_void, variable: *mut core::ffi::c_void, transition: *mut core::ffi::c_void, timenow: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager", "ScheduleTransition", 4usize, |this| ((**(this as *const *const IUIAnimationManager_Vtbl)).ScheduleTransition)(this, variable, transition, timenow), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateStoryboard(this: *mut core::ffi::c_void, storyboard: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager", "CreateStoryboard", 5usize, |this| ((**(this as *const *const IUIAnimationManager_Vtbl)).CreateStoryboard)(this, storyboard), |result| Some(*result))
+        }
+        unsafe extern "system" fn FinishAllStoryboards(this: *mut core::ffi::c_void, completiondeadline: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager", "FinishAllStoryboards", 6usize, |this| ((**(this as *const *const IUIAnimationManager_Vtbl)).FinishAllStoryboards)(this, completiondeadline), |result| Some(*result))
+        }
+        unsafe extern "system" fn AbandonAllStoryboards(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager", "AbandonAllStoryboards", 7usize, |this| ((**(this as *const *const IUIAnimationManager_Vtbl)).AbandonAllStoryboards)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void, timenow: f64, updateresult: *mut UI_ANIMATION_UPDATE_RESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager", "Update", 8usize, |this| ((**(this as *const *const IUIAnimationManager_Vtbl)).Update)(this, timenow, updateresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVariableFromTag(this: *mut core::ffi::c_void, object: *mut core::ffi::c_void, id: u32, variable: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager", "GetVariableFromTag", 9usize, |this| ((**(this as *const *const IUIAnimationManager_Vtbl)).GetVariableFromTag)(this, object, id, variable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStoryboardFromTag(this: *mut core::ffi::c_void, object: *mut core::ffi::c_void, id: u32, storyboard: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager", "GetStoryboardFromTag", 10usize, |this| ((**(this as *const *const IUIAnimationManager_Vtbl)).GetStoryboardFromTag)(this, object, id, storyboard), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, status: *mut UI_ANIMATION_MANAGER_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager", "GetStatus", 11usize, |this| ((**(this as *const *const IUIAnimationManager_Vtbl)).GetStatus)(this, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAnimationMode(this: *mut core::ffi::c_void, mode: UI_ANIMATION_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager", "SetAnimationMode", 12usize, |this| ((**(this as *const *const IUIAnimationManager_Vtbl)).SetAnimationMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager", "Pause", 13usize, |this| ((**(this as *const *const IUIAnimationManager_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager", "Resume", 14usize, |this| ((**(this as *const *const IUIAnimationManager_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetManagerEventHandler(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager", "SetManagerEventHandler", 15usize, |this| ((**(this as *const *const IUIAnimationManager_Vtbl)).SetManagerEventHandler)(this, handler), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCancelPriorityComparison(this: *mut core::ffi::c_void, comparison: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager", "SetCancelPriorityComparison", 16usize, |this| ((**(this as *const *const IUIAnimationManager_Vtbl)).SetCancelPriorityComparison)(this, comparison), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTrimPriorityComparison(this: *mut core::ffi::c_void, comparison: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager", "SetTrimPriorityComparison", 17usize, |this| ((**(this as *const *const IUIAnimationManager_Vtbl)).SetTrimPriorityComparison)(this, comparison), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompressPriorityComparison(this: *mut core::ffi::c_void, comparison: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager", "SetCompressPriorityComparison", 18usize, |this| ((**(this as *const *const IUIAnimationManager_Vtbl)).SetCompressPriorityComparison)(this, comparison), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConcludePriorityComparison(this: *mut core::ffi::c_void, comparison: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager", "SetConcludePriorityComparison", 19usize, |this| ((**(this as *const *const IUIAnimationManager_Vtbl)).SetConcludePriorityComparison)(this, comparison), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultLongestAcceptableDelay(this: *mut core::ffi::c_void, delay: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager", "SetDefaultLongestAcceptableDelay", 20usize, |this| ((**(this as *const *const IUIAnimationManager_Vtbl)).SetDefaultLongestAcceptableDelay)(this, delay), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager", "Shutdown", 21usize, |this| ((**(this as *const *const IUIAnimationManager_Vtbl)).Shutdown)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateAnimationVariable,
+            ScheduleTransition,
+            CreateStoryboard,
+            FinishAllStoryboards,
+            AbandonAllStoryboards,
+            Update,
+            GetVariableFromTag,
+            GetStoryboardFromTag,
+            GetStatus,
+            SetAnimationMode,
+            Pause,
+            Resume,
+            SetManagerEventHandler,
+            SetCancelPriorityComparison,
+            SetTrimPriorityComparison,
+            SetCompressPriorityComparison,
+            SetConcludePriorityComparison,
+            SetDefaultLongestAcceptableDelay,
+            Shutdown,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateAnimationVectorVariable(this: *mut core::ffi::c_void, initialvalue: *const f64, cdimension: u32, variable: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager2", "CreateAnimationVectorVariable", 3usize, |this| ((**(this as *const *const IUIAnimationManager2_Vtbl)).CreateAnimationVectorVariable)(this, initialvalue, cdimension, variable), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAnimationVariable(this: *mut core::ffi::c_void, initialvalue: f64, variable: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager2", "CreateAnimationVariable", 4usize, |this| ((**(this as *const *const IUIAnimationManager2_Vtbl)).CreateAnimationVariable)(this, initialvalue, variable), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScheduleTransition(this: *mut core::ffi::c_void, variable: *mut core::ffi::c_void, transition: *mut core::ffi::c_void, timenow: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager2", "ScheduleTransition", 5usize, |this| ((**(this as *const *const IUIAnimationManager2_Vtbl)).ScheduleTransition)(this, variable, transition, timenow), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateStoryboard(this: *mut core::ffi::c_void, storyboard: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager2", "CreateStoryboard", 6usize, |this| ((**(this as *const *const IUIAnimationManager2_Vtbl)).CreateStoryboard)(this, storyboard), |result| Some(*result))
+        }
+        unsafe extern "system" fn FinishAllStoryboards(this: *mut core::ffi::c_void, completiondeadline: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager2", "FinishAllStoryboards", 7usize, |this| ((**(this as *const *const IUIAnimationManager2_Vtbl)).FinishAllStoryboards)(this, completiondeadline), |result| Some(*result))
+        }
+        unsafe extern "system" fn AbandonAllStoryboards(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager2", "AbandonAllStoryboards", 8usize, |this| ((**(this as *const *const IUIAnimationManager2_Vtbl)).AbandonAllStoryboards)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void, timenow: f64, updateresult: *mut UI_ANIMATION_UPDATE_RESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager2", "Update", 9usize, |this| ((**(this as *const *const IUIAnimationManager2_Vtbl)).Update)(this, timenow, updateresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVariableFromTag(this: *mut core::ffi::c_void, object: *mut core::ffi::c_void, id: u32, variable: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager2", "GetVariableFromTag", 10usize, |this| ((**(this as *const *const IUIAnimationManager2_Vtbl)).GetVariableFromTag)(this, object, id, variable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStoryboardFromTag(this: *mut core::ffi::c_void, object: *mut core::ffi::c_void, id: u32, storyboard: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager2", "GetStoryboardFromTag", 11usize, |this| ((**(this as *const *const IUIAnimationManager2_Vtbl)).GetStoryboardFromTag)(this, object, id, storyboard), |result| Some(*result))
+        }
+        unsafe extern "system" fn EstimateNextEventTime(this: *mut core::ffi::c_void, seconds: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager2", "EstimateNextEventTime", 12usize, |this| ((**(this as *const *const IUIAnimationManager2_Vtbl)).EstimateNextEventTime)(this, seconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, status: *mut UI_ANIMATION_MANAGER_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager2", "GetStatus", 13usize, |this| ((**(this as *const *const IUIAnimationManager2_Vtbl)).GetStatus)(this, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAnimationMode(this: *mut core::ffi::c_void, mode: UI_ANIMATION_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager2", "SetAnimationMode", 14usize, |this| ((**(this as *const *const IUIAnimationManager2_Vtbl)).SetAnimationMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager2", "Pause", 15usize, |this| ((**(this as *const *const IUIAnimationManager2_Vtbl)).Pause)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager2", "Resume", 16usize, |this| ((**(this as *const *const IUIAnimationManager2_Vtbl)).Resume)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetManagerEventHandler(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, fregisterfornextanimationevent: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager2", "SetManagerEventHandler", 17usize, |this| ((**(this as *const *const IUIAnimationManager2_Vtbl)).SetManagerEventHandler)(this, handler, fregisterfornextanimationevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCancelPriorityComparison(this: *mut core::ffi::c_void, comparison: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager2", "SetCancelPriorityComparison", 18usize, |this| ((**(this as *const *const IUIAnimationManager2_Vtbl)).SetCancelPriorityComparison)(this, comparison), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTrimPriorityComparison(this: *mut core::ffi::c_void, comparison: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager2", "SetTrimPriorityComparison", 19usize, |this| ((**(this as *const *const IUIAnimationManager2_Vtbl)).SetTrimPriorityComparison)(this, comparison), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompressPriorityComparison(this: *mut core::ffi::c_void, comparison: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager2", "SetCompressPriorityComparison", 20usize, |this| ((**(this as *const *const IUIAnimationManager2_Vtbl)).SetCompressPriorityComparison)(this, comparison), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConcludePriorityComparison(this: *mut core::ffi::c_void, comparison: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager2", "SetConcludePriorityComparison", 21usize, |this| ((**(this as *const *const IUIAnimationManager2_Vtbl)).SetConcludePriorityComparison)(this, comparison), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultLongestAcceptableDelay(this: *mut core::ffi::c_void, delay: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager2", "SetDefaultLongestAcceptableDelay", 22usize, |this| ((**(this as *const *const IUIAnimationManager2_Vtbl)).SetDefaultLongestAcceptableDelay)(this, delay), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shutdown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManager2", "Shutdown", 23usize, |this| ((**(this as *const *const IUIAnimationManager2_Vtbl)).Shutdown)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateAnimationVectorVariable,
+            CreateAnimationVariable,
+            ScheduleTransition,
+            CreateStoryboard,
+            FinishAllStoryboards,
+            AbandonAllStoryboards,
+            Update,
+            GetVariableFromTag,
+            GetStoryboardFromTag,
+            EstimateNextEventTime,
+            GetStatus,
+            SetAnimationMode,
+            Pause,
+            Resume,
+            SetManagerEventHandler,
+            SetCancelPriorityComparison,
+            SetTrimPriorityComparison,
+            SetCompressPriorityComparison,
+            SetConcludePriorityComparison,
+            SetDefaultLongestAcceptableDelay,
+            Shutdown,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationManager2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationManagerEventHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnManagerStatusChanged(this: *mut core::ffi::c_void, newstatus: UI_ANIMATION_MANAGER_STATUS, previousstatus: UI_ANIMATION_MANAGER_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManagerEventHandler", "OnManagerStatusChanged", 3usize, |this| ((**(this as *const *const IUIAnimationManagerEventHandler_Vtbl)).OnManagerStatusChanged)(this, newstatus, previousstatus), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnManagerStatusChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationManagerEventHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationManagerEventHandler2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnManagerStatusChanged(this: *mut core::ffi::c_void, newstatus: UI_ANIMATION_MANAGER_STATUS, previousstatus: UI_ANIMATION_MANAGER_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationManagerEventHandler2", "OnManagerStatusChanged", 3usize, |this| ((**(this as *const *const IUIAnimationManagerEventHandler2_Vtbl)).OnManagerStatusChanged)(this, newstatus, previousstatus), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnManagerStatusChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationManagerEventHandler2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationPrimitiveInterpolation_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddCubic(this: *mut core::ffi::c_void, dimension: u32, beginoffset: f64, constantcoefficient: f32, linearcoefficient: f32, quadraticcoefficient: f32, cubiccoefficient: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationPrimitiveInterpolation", "AddCubic", 3usize, |this| ((**(this as *const *const IUIAnimationPrimitiveInterpolation_Vtbl)).AddCubic)(this, dimension, beginoffset, constantcoefficient, linearcoefficient, quadraticcoefficient, cubiccoefficient), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddSinusoidal(this: *mut core::ffi::c_void, dimension: u32, beginoffset: f64, bias: f32, amplitude: f32, frequency: f32, phase: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationPrimitiveInterpolation", "AddSinusoidal", 4usize, |this| ((**(this as *const *const IUIAnimationPrimitiveInterpolation_Vtbl)).AddSinusoidal)(this, dimension, beginoffset, bias, amplitude, frequency, phase), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddCubic, AddSinusoidal }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationPrimitiveInterpolation as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationPriorityComparison_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HasPriority(this: *mut core::ffi::c_void, scheduledstoryboard: *mut core::ffi::c_void, newstoryboard: *mut core::ffi::c_void, priorityeffect: UI_ANIMATION_PRIORITY_EFFECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationPriorityComparison", "HasPriority", 3usize, |this| ((**(this as *const *const IUIAnimationPriorityComparison_Vtbl)).HasPriority)(this, scheduledstoryboard, newstoryboard, priorityeffect), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HasPriority }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationPriorityComparison as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationPriorityComparison2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HasPriority(this: *mut core::ffi::c_void, scheduledstoryboard: *mut core::ffi::c_void, newstoryboard: *mut core::ffi::c_void, priorityeffect: UI_ANIMATION_PRIORITY_EFFECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationPriorityComparison2", "HasPriority", 3usize, |this| ((**(this as *const *const IUIAnimationPriorityComparison2_Vtbl)).HasPriority)(this, scheduledstoryboard, newstoryboard, priorityeffect), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HasPriority }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationPriorityComparison2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationStoryboard_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddTransition(this: *mut core::ffi::c_void, variable: *mut core::ffi::c_void, transition: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard", "AddTransition", 3usize, |this| ((**(this as *const *const IUIAnimationStoryboard_Vtbl)).AddTransition)(this, variable, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddKeyframeAtOffset(this: *mut core::ffi::c_void, existingkeyframe: UI_ANIMATION_KEYFRAME, offset: f64, keyframe: *mut UI_ANIMATION_KEYFRAME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard", "AddKeyframeAtOffset", 4usize, |this| ((**(this as *const *const IUIAnimationStoryboard_Vtbl)).AddKeyframeAtOffset)(this, existingkeyframe, offset, keyframe), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddKeyframeAfterTransition(this: *mut core::ffi::c_void, transition: *mut core::ffi::c_void, keyframe: *mut UI_ANIMATION_KEYFRAME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard", "AddKeyframeAfterTransition", 5usize, |this| ((**(this as *const *const IUIAnimationStoryboard_Vtbl)).AddKeyframeAfterTransition)(this, transition, keyframe), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTransitionAtKeyframe(this: *mut core::ffi::c_void, variable: *mut core::ffi::c_void, transition: *mut core::ffi::c_void, startkeyframe: UI_ANIMATION_KEYFRAME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard", "AddTransitionAtKeyframe", 6usize, |this| ((**(this as *const *const IUIAnimationStoryboard_Vtbl)).AddTransitionAtKeyframe)(this, variable, transition, startkeyframe), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTransitionBetweenKeyframes(this: *mut core::ffi::c_void, variable: *mut core::ffi::c_void, transition: *mut core::ffi::c_void, startkeyframe: UI_ANIMATION_KEYFRAME, endkeyframe: UI_ANIMATION_KEYFRAME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard", "AddTransitionBetweenKeyframes", 7usize, |this| ((**(this as *const *const IUIAnimationStoryboard_Vtbl)).AddTransitionBetweenKeyframes)(this, variable, transition, startkeyframe, endkeyframe), |result| Some(*result))
+        }
+        unsafe extern "system" fn RepeatBetweenKeyframes(this: *mut core::ffi::c_void, startkeyframe: UI_ANIMATION_KEYFRAME, endkeyframe: UI_ANIMATION_KEYFRAME, repetitioncount: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard", "RepeatBetweenKeyframes", 8usize, |this| ((**(this as *const *const IUIAnimationStoryboard_Vtbl)).RepeatBetweenKeyframes)(this, startkeyframe, endkeyframe, repetitioncount), |result| Some(*result))
+        }
+        unsafe extern "system" fn HoldVariable(this: *mut core::ffi::c_void, variable: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard", "HoldVariable", 9usize, |this| ((**(this as *const *const IUIAnimationStoryboard_Vtbl)).HoldVariable)(this, variable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLongestAcceptableDelay(this: *mut core::ffi::c_void, delay: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard", "SetLongestAcceptableDelay", 10usize, |this| ((**(this as *const *const IUIAnimationStoryboard_Vtbl)).SetLongestAcceptableDelay)(this, delay), |result| Some(*result))
+        }
+        unsafe extern "system" fn Schedule(this: *mut core::ffi::c_void, timenow: f64, schedulingresult: *mut UI_ANIMATION_SCHEDULING_RESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard", "Schedule", 11usize, |this| ((**(this as *const *const IUIAnimationStoryboard_Vtbl)).Schedule)(this, timenow, schedulingresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn Conclude(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard", "Conclude", 12usize, |this| ((**(this as *const *const IUIAnimationStoryboard_Vtbl)).Conclude)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish(this: *mut core::ffi::c_void, completiondeadline: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard", "Finish", 13usize, |this| ((**(this as *const *const IUIAnimationStoryboard_Vtbl)).Finish)(this, completiondeadline), |result| Some(*result))
+        }
+        unsafe extern "system" fn Abandon(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard", "Abandon", 14usize, |this| ((**(this as *const *const IUIAnimationStoryboard_Vtbl)).Abandon)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTag(this: *mut core::ffi::c_void, object: *mut core::ffi::c_void, id: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard", "SetTag", 15usize, |this| ((**(this as *const *const IUIAnimationStoryboard_Vtbl)).SetTag)(this, object, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, object: *mut *mut core::ffi::c_void, id: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard", "GetTag", 16usize, |this| ((**(this as *const *const IUIAnimationStoryboard_Vtbl)).GetTag)(this, object, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, status: *mut UI_ANIMATION_STORYBOARD_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard", "GetStatus", 17usize, |this| ((**(this as *const *const IUIAnimationStoryboard_Vtbl)).GetStatus)(this, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetElapsedTime(this: *mut core::ffi::c_void, elapsedtime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard", "GetElapsedTime", 18usize, |this| ((**(this as *const *const IUIAnimationStoryboard_Vtbl)).GetElapsedTime)(this, elapsedtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStoryboardEventHandler(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard", "SetStoryboardEventHandler", 19usize, |this| ((**(this as *const *const IUIAnimationStoryboard_Vtbl)).SetStoryboardEventHandler)(this, handler), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddTransition,
+            AddKeyframeAtOffset,
+            AddKeyframeAfterTransition,
+            AddTransitionAtKeyframe,
+            AddTransitionBetweenKeyframes,
+            RepeatBetweenKeyframes,
+            HoldVariable,
+            SetLongestAcceptableDelay,
+            Schedule,
+            Conclude,
+            Finish,
+            Abandon,
+            SetTag,
+            GetTag,
+            GetStatus,
+            GetElapsedTime,
+            SetStoryboardEventHandler,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationStoryboard as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationStoryboard2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddTransition(this: *mut core::ffi::c_void, variable: *mut core::ffi::c_void, transition: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard2", "AddTransition", 3usize, |this| ((**(this as *const *const IUIAnimationStoryboard2_Vtbl)).AddTransition)(this, variable, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddKeyframeAtOffset(this: *mut core::ffi::c_void, existingkeyframe: UI_ANIMATION_KEYFRAME, offset: f64, keyframe: *mut UI_ANIMATION_KEYFRAME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard2", "AddKeyframeAtOffset", 4usize, |this| ((**(this as *const *const IUIAnimationStoryboard2_Vtbl)).AddKeyframeAtOffset)(this, existingkeyframe, offset, keyframe), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddKeyframeAfterTransition(this: *mut core::ffi::c_void, transition: *mut core::ffi::c_void, keyframe: *mut UI_ANIMATION_KEYFRAME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard2", "AddKeyframeAfterTransition", 5usize, |this| ((**(this as *const *const IUIAnimationStoryboard2_Vtbl)).AddKeyframeAfterTransition)(this, transition, keyframe), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTransitionAtKeyframe(this: *mut core::ffi::c_void, variable: *mut core::ffi::c_void, transition: *mut core::ffi::c_void, startkeyframe: UI_ANIMATION_KEYFRAME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard2", "AddTransitionAtKeyframe", 6usize, |this| ((**(this as *const *const IUIAnimationStoryboard2_Vtbl)).AddTransitionAtKeyframe)(this, variable, transition, startkeyframe), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTransitionBetweenKeyframes(this: *mut core::ffi::c_void, variable: *mut core::ffi::c_void, transition: *mut core::ffi::c_void, startkeyframe: UI_ANIMATION_KEYFRAME, endkeyframe: UI_ANIMATION_KEYFRAME) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard2", "AddTransitionBetweenKeyframes", 7usize, |this| ((**(this as *const *const IUIAnimationStoryboard2_Vtbl)).AddTransitionBetweenKeyframes)(this, variable, transition, startkeyframe, endkeyframe), |result| Some(*result))
+        }
+        unsafe extern "system" fn RepeatBetweenKeyframes(this: *mut core::ffi::c_void, startkeyframe: UI_ANIMATION_KEYFRAME, endkeyframe: UI_ANIMATION_KEYFRAME, crepetition: f64, repeatmode: UI_ANIMATION_REPEAT_MODE, piterationchangehandler: *mut core::ffi::c_void, id: usize, fregisterfornextanimationevent: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard2", "RepeatBetweenKeyframes", 8usize, |this| ((**(this as *const *const IUIAnimationStoryboard2_Vtbl)).RepeatBetweenKeyframes)(this, startkeyframe, endkeyframe, crepetition, repeatmode, piterationchangehandler, id, fregisterfornextanimationevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn HoldVariable(this: *mut core::ffi::c_void, variable: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard2", "HoldVariable", 9usize, |this| ((**(this as *const *const IUIAnimationStoryboard2_Vtbl)).HoldVariable)(this, variable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLongestAcceptableDelay(this: *mut core::ffi::c_void, delay: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard2", "SetLongestAcceptableDelay", 10usize, |this| ((**(this as *const *const IUIAnimationStoryboard2_Vtbl)).SetLongestAcceptableDelay)(this, delay), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSkipDuration(this: *mut core::ffi::c_void, secondsduration: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard2", "SetSkipDuration", 11usize, |this| ((**(this as *const *const IUIAnimationStoryboard2_Vtbl)).SetSkipDuration)(this, secondsduration), |result| Some(*result))
+        }
+        unsafe extern "system" fn Schedule(this: *mut core::ffi::c_void, timenow: f64, schedulingresult: *mut UI_ANIMATION_SCHEDULING_RESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard2", "Schedule", 12usize, |this| ((**(this as *const *const IUIAnimationStoryboard2_Vtbl)).Schedule)(this, timenow, schedulingresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn Conclude(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard2", "Conclude", 13usize, |this| ((**(this as *const *const IUIAnimationStoryboard2_Vtbl)).Conclude)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finish(this: *mut core::ffi::c_void, completiondeadline: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard2", "Finish", 14usize, |this| ((**(this as *const *const IUIAnimationStoryboard2_Vtbl)).Finish)(this, completiondeadline), |result| Some(*result))
+        }
+        unsafe extern "system" fn Abandon(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard2", "Abandon", 15usize, |this| ((**(this as *const *const IUIAnimationStoryboard2_Vtbl)).Abandon)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTag(this: *mut core::ffi::c_void, object: *mut core::ffi::c_void, id: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard2", "SetTag", 16usize, |this| ((**(this as *const *const IUIAnimationStoryboard2_Vtbl)).SetTag)(this, object, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, object: *mut *mut core::ffi::c_void, id: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard2", "GetTag", 17usize, |this| ((**(this as *const *const IUIAnimationStoryboard2_Vtbl)).GetTag)(this, object, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, status: *mut UI_ANIMATION_STORYBOARD_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard2", "GetStatus", 18usize, |this| ((**(this as *const *const IUIAnimationStoryboard2_Vtbl)).GetStatus)(this, status), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetElapsedTime(this: *mut core::ffi::c_void, elapsedtime: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard2", "GetElapsedTime", 19usize, |this| ((**(this as *const *const IUIAnimationStoryboard2_Vtbl)).GetElapsedTime)(this, elapsedtime), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStoryboardEventHandler(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, fregisterstatuschangefornextanimationevent: super::super::Foundation::BOOL, fregisterupdatefornextanimationevent: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboard2", "SetStoryboardEventHandler", 20usize, |this| ((**(this as *const *const IUIAnimationStoryboard2_Vtbl)).SetStoryboardEventHandler)(this, handler, fregisterstatuschangefornextanimationevent, fregisterupdatefornextanimationevent), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddTransition,
+            AddKeyframeAtOffset,
+            AddKeyframeAfterTransition,
+            AddTransitionAtKeyframe,
+            AddTransitionBetweenKeyframes,
+            RepeatBetweenKeyframes,
+            HoldVariable,
+            SetLongestAcceptableDelay,
+            SetSkipDuration,
+            Schedule,
+            Conclude,
+            Finish,
+            Abandon,
+            SetTag,
+            GetTag,
+            GetStatus,
+            GetElapsedTime,
+            SetStoryboardEventHandler,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationStoryboard2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationStoryboardEventHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnStoryboardStatusChanged(this: *mut core::ffi::c_void, storyboard: *mut core::ffi::c_void, newstatus: UI_ANIMATION_STORYBOARD_STATUS, previousstatus: UI_ANIMATION_STORYBOARD_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboardEventHandler", "OnStoryboardStatusChanged", 3usize, |this| ((**(this as *const *const IUIAnimationStoryboardEventHandler_Vtbl)).OnStoryboardStatusChanged)(this, storyboard, newstatus, previousstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnStoryboardUpdated(this: *mut core::ffi::c_void, storyboard: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboardEventHandler", "OnStoryboardUpdated", 4usize, |this| ((**(this as *const *const IUIAnimationStoryboardEventHandler_Vtbl)).OnStoryboardUpdated)(this, storyboard), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnStoryboardStatusChanged, OnStoryboardUpdated }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationStoryboardEventHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationStoryboardEventHandler2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnStoryboardStatusChanged(this: *mut core::ffi::c_void, storyboard: *mut core::ffi::c_void, newstatus: UI_ANIMATION_STORYBOARD_STATUS, previousstatus: UI_ANIMATION_STORYBOARD_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboardEventHandler2", "OnStoryboardStatusChanged", 3usize, |this| ((**(this as *const *const IUIAnimationStoryboardEventHandler2_Vtbl)).OnStoryboardStatusChanged)(this, storyboard, newstatus, previousstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnStoryboardUpdated(this: *mut core::ffi::c_void, storyboard: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationStoryboardEventHandler2", "OnStoryboardUpdated", 4usize, |this| ((**(this as *const *const IUIAnimationStoryboardEventHandler2_Vtbl)).OnStoryboardUpdated)(this, storyboard), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnStoryboardStatusChanged, OnStoryboardUpdated }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationStoryboardEventHandler2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationTimer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetTimerUpdateHandler(this: *mut core::ffi::c_void, updatehandler: *mut core::ffi::c_void, idlebehavior: UI_ANIMATION_IDLE_BEHAVIOR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTimer", "SetTimerUpdateHandler", 3usize, |this| ((**(this as *const *const IUIAnimationTimer_Vtbl)).SetTimerUpdateHandler)(this, updatehandler, idlebehavior), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTimerEventHandler(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTimer", "SetTimerEventHandler", 4usize, |this| ((**(this as *const *const IUIAnimationTimer_Vtbl)).SetTimerEventHandler)(this, handler), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enable(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTimer", "Enable", 5usize, |this| ((**(this as *const *const IUIAnimationTimer_Vtbl)).Enable)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Disable(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTimer", "Disable", 6usize, |this| ((**(this as *const *const IUIAnimationTimer_Vtbl)).Disable)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTimer", "IsEnabled", 7usize, |this| ((**(this as *const *const IUIAnimationTimer_Vtbl)).IsEnabled)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTime(this: *mut core::ffi::c_void, seconds: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTimer", "GetTime", 8usize, |this| ((**(this as *const *const IUIAnimationTimer_Vtbl)).GetTime)(this, seconds), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFrameRateThreshold(this: *mut core::ffi::c_void, framespersecond: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTimer", "SetFrameRateThreshold", 9usize, |this| ((**(this as *const *const IUIAnimationTimer_Vtbl)).SetFrameRateThreshold)(this, framespersecond), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetTimerUpdateHandler,
+            SetTimerEventHandler,
+            Enable,
+            Disable,
+            IsEnabled,
+            GetTime,
+            SetFrameRateThreshold,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationTimer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationTimerClientEventHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnTimerClientStatusChanged(this: *mut core::ffi::c_void, newstatus: UI_ANIMATION_TIMER_CLIENT_STATUS, previousstatus: UI_ANIMATION_TIMER_CLIENT_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTimerClientEventHandler", "OnTimerClientStatusChanged", 3usize, |this| ((**(this as *const *const IUIAnimationTimerClientEventHandler_Vtbl)).OnTimerClientStatusChanged)(this, newstatus, previousstatus), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnTimerClientStatusChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationTimerClientEventHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationTimerEventHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnPreUpdate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTimerEventHandler", "OnPreUpdate", 3usize, |this| ((**(this as *const *const IUIAnimationTimerEventHandler_Vtbl)).OnPreUpdate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnPostUpdate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTimerEventHandler", "OnPostUpdate", 4usize, |this| ((**(this as *const *const IUIAnimationTimerEventHandler_Vtbl)).OnPostUpdate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnRenderingTooSlow(this: *mut core::ffi::c_void, framespersecond: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTimerEventHandler", "OnRenderingTooSlow", 5usize, |this| ((**(this as *const *const IUIAnimationTimerEventHandler_Vtbl)).OnRenderingTooSlow)(this, framespersecond), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnPreUpdate, OnPostUpdate, OnRenderingTooSlow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationTimerEventHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationTimerUpdateHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnUpdate(this: *mut core::ffi::c_void, timenow: f64, result: *mut UI_ANIMATION_UPDATE_RESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTimerUpdateHandler", "OnUpdate", 3usize, |this| ((**(this as *const *const IUIAnimationTimerUpdateHandler_Vtbl)).OnUpdate)(this, timenow, result), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTimerClientEventHandler(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTimerUpdateHandler", "SetTimerClientEventHandler", 4usize, |this| ((**(this as *const *const IUIAnimationTimerUpdateHandler_Vtbl)).SetTimerClientEventHandler)(this, handler), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearTimerClientEventHandler(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTimerUpdateHandler", "ClearTimerClientEventHandler", 5usize, |this| ((**(this as *const *const IUIAnimationTimerUpdateHandler_Vtbl)).ClearTimerClientEventHandler)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnUpdate,
+            SetTimerClientEventHandler,
+            ClearTimerClientEventHandler,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationTimerUpdateHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationTransition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetInitialValue(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransition", "SetInitialValue", 3usize, |this| ((**(this as *const *const IUIAnimationTransition_Vtbl)).SetInitialValue)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInitialVelocity(this: *mut core::ffi::c_void, velocity: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransition", "SetInitialVelocity", 4usize, |this| ((**(this as *const *const IUIAnimationTransition_Vtbl)).SetInitialVelocity)(this, velocity), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDurationKnown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransition", "IsDurationKnown", 5usize, |this| ((**(this as *const *const IUIAnimationTransition_Vtbl)).IsDurationKnown)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDuration(this: *mut core::ffi::c_void, duration: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransition", "GetDuration", 6usize, |this| ((**(this as *const *const IUIAnimationTransition_Vtbl)).GetDuration)(this, duration), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetInitialValue,
+            SetInitialVelocity,
+            IsDurationKnown,
+            GetDuration,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationTransition as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationTransition2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDimension(this: *mut core::ffi::c_void, dimension: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransition2", "GetDimension", 3usize, |this| ((**(this as *const *const IUIAnimationTransition2_Vtbl)).GetDimension)(this, dimension), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInitialValue(this: *mut core::ffi::c_void, value: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransition2", "SetInitialValue", 4usize, |this| ((**(this as *const *const IUIAnimationTransition2_Vtbl)).SetInitialValue)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInitialVectorValue(this: *mut core::ffi::c_void, value: *const f64, cdimension: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransition2", "SetInitialVectorValue", 5usize, |this| ((**(this as *const *const IUIAnimationTransition2_Vtbl)).SetInitialVectorValue)(this, value, cdimension), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInitialVelocity(this: *mut core::ffi::c_void, velocity: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransition2", "SetInitialVelocity", 6usize, |this| ((**(this as *const *const IUIAnimationTransition2_Vtbl)).SetInitialVelocity)(this, velocity), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInitialVectorVelocity(this: *mut core::ffi::c_void, velocity: *const f64, cdimension: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransition2", "SetInitialVectorVelocity", 7usize, |this| ((**(this as *const *const IUIAnimationTransition2_Vtbl)).SetInitialVectorVelocity)(this, velocity, cdimension), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsDurationKnown(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransition2", "IsDurationKnown", 8usize, |this| ((**(this as *const *const IUIAnimationTransition2_Vtbl)).IsDurationKnown)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDuration(this: *mut core::ffi::c_void, duration: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransition2", "GetDuration", 9usize, |this| ((**(this as *const *const IUIAnimationTransition2_Vtbl)).GetDuration)(this, duration), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDimension,
+            SetInitialValue,
+            SetInitialVectorValue,
+            SetInitialVelocity,
+            SetInitialVectorVelocity,
+            IsDurationKnown,
+            GetDuration,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationTransition2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationTransitionFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateTransition(this: *mut core::ffi::c_void, interpolator: *mut core::ffi::c_void, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionFactory", "CreateTransition", 3usize, |this| ((**(this as *const *const IUIAnimationTransitionFactory_Vtbl)).CreateTransition)(this, interpolator, transition), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateTransition }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationTransitionFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationTransitionFactory2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateTransition(this: *mut core::ffi::c_void, interpolator: *mut core::ffi::c_void, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionFactory2", "CreateTransition", 3usize, |this| ((**(this as *const *const IUIAnimationTransitionFactory2_Vtbl)).CreateTransition)(this, interpolator, transition), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateTransition }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationTransitionFactory2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationTransitionLibrary_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstantaneousTransition(this: *mut core::ffi::c_void, finalvalue: f64, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary", "CreateInstantaneousTransition", 3usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary_Vtbl)).CreateInstantaneousTransition)(this, finalvalue, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateConstantTransition(this: *mut core::ffi::c_void, duration: f64, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary", "CreateConstantTransition", 4usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary_Vtbl)).CreateConstantTransition)(this, duration, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDiscreteTransition(this: *mut core::ffi::c_void, delay: f64, finalvalue: f64, hold: f64, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary", "CreateDiscreteTransition", 5usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary_Vtbl)).CreateDiscreteTransition)(this, delay, finalvalue, hold, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateLinearTransition(this: *mut core::ffi::c_void, duration: f64, finalvalue: f64, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary", "CreateLinearTransition", 6usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary_Vtbl)).CreateLinearTransition)(this, duration, finalvalue, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateLinearTransitionFromSpeed(this: *mut core::ffi::c_void, speed: f64, finalvalue: f64, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary", "CreateLinearTransitionFromSpeed", 7usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary_Vtbl)).CreateLinearTransitionFromSpeed)(this, speed, finalvalue, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSinusoidalTransitionFromVelocity(this: *mut core::ffi::c_void, duration: f64, period: f64, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary", "CreateSinusoidalTransitionFromVelocity", 8usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary_Vtbl)).CreateSinusoidalTransitionFromVelocity)(this, duration, period, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSinusoidalTransitionFromRange(this: *mut core::ffi::c_void, duration: f64, minimumvalue: f64, maximumvalue: f64, period: f64, slope: UI_ANIMATION_SLOPE, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary", "CreateSinusoidalTransitionFromRange", 9usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary_Vtbl)).CreateSinusoidalTransitionFromRange)(this, duration, minimumvalue, maximumvalue, period, slope, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAccelerateDecelerateTransition(this: *mut core::ffi::c_void, duration: f64, finalvalue: f64, accelerationratio: f64, decelerationratio: f64, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary", "CreateAccelerateDecelerateTransition", 10usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary_Vtbl)).CreateAccelerateDecelerateTransition)(this, duration, finalvalue, accelerationratio, decelerationratio, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateReversalTransition(this: *mut core::ffi::c_void, duration: f64, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary", "CreateReversalTransition", 11usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary_Vtbl)).CreateReversalTransition)(this, duration, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCubicTransition(this: *mut core::ffi::c_void, duration: f64, finalvalue: f64, finalvelocity: f64, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary", "CreateCubicTransition", 12usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary_Vtbl)).CreateCubicTransition)(this, duration, finalvalue, finalvelocity, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSmoothStopTransition(this: *mut core::ffi::c_void, maximumduration: f64, finalvalue: f64, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary", "CreateSmoothStopTransition", 13usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary_Vtbl)).CreateSmoothStopTransition)(this, maximumduration, finalvalue, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateParabolicTransitionFromAcceleration(this: *mut core::ffi::c_void, finalvalue: f64, finalvelocity: f64, acceleration: f64, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary", "CreateParabolicTransitionFromAcceleration", 14usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary_Vtbl)).CreateParabolicTransitionFromAcceleration)(this, finalvalue, finalvelocity, acceleration, transition), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateInstantaneousTransition,
+            CreateConstantTransition,
+            CreateDiscreteTransition,
+            CreateLinearTransition,
+            CreateLinearTransitionFromSpeed,
+            CreateSinusoidalTransitionFromVelocity,
+            CreateSinusoidalTransitionFromRange,
+            CreateAccelerateDecelerateTransition,
+            CreateReversalTransition,
+            CreateCubicTransition,
+            CreateSmoothStopTransition,
+            CreateParabolicTransitionFromAcceleration,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationTransitionLibrary as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationTransitionLibrary2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateInstantaneousTransition(this: *mut core::ffi::c_void, finalvalue: f64, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary2", "CreateInstantaneousTransition", 3usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary2_Vtbl)).CreateInstantaneousTransition)(this, finalvalue, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInstantaneousVectorTransition(this: *mut core::ffi::c_void, finalvalue: *const f64, cdimension: u32, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary2", "CreateInstantaneousVectorTransition", 4usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary2_Vtbl)).CreateInstantaneousVectorTransition)(this, finalvalue, cdimension, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateConstantTransition(this: *mut core::ffi::c_void, duration: f64, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary2", "CreateConstantTransition", 5usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary2_Vtbl)).CreateConstantTransition)(this, duration, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDiscreteTransition(this: *mut core::ffi::c_void, delay: f64, finalvalue: f64, hold: f64, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary2", "CreateDiscreteTransition", 6usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary2_Vtbl)).CreateDiscreteTransition)(this, delay, finalvalue, hold, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDiscreteVectorTransition(this: *mut core::ffi::c_void, delay: f64, finalvalue: *const f64, cdimension: u32, hold: f64, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary2", "CreateDiscreteVectorTransition", 7usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary2_Vtbl)).CreateDiscreteVectorTransition)(this, delay, finalvalue, cdimension, hold, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateLinearTransition(this: *mut core::ffi::c_void, duration: f64, finalvalue: f64, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary2", "CreateLinearTransition", 8usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary2_Vtbl)).CreateLinearTransition)(this, duration, finalvalue, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateLinearVectorTransition(this: *mut core::ffi::c_void, duration: f64, finalvalue: *const f64, cdimension: u32, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary2", "CreateLinearVectorTransition", 9usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary2_Vtbl)).CreateLinearVectorTransition)(this, duration, finalvalue, cdimension, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateLinearTransitionFromSpeed(this: *mut core::ffi::c_void, speed: f64, finalvalue: f64, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary2", "CreateLinearTransitionFromSpeed", 10usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary2_Vtbl)).CreateLinearTransitionFromSpeed)(this, speed, finalvalue, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateLinearVectorTransitionFromSpeed(this: *mut core::ffi::c_void, speed: f64, finalvalue: *const f64, cdimension: u32, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary2", "CreateLinearVectorTransitionFromSpeed", 11usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary2_Vtbl)).CreateLinearVectorTransitionFromSpeed)(this, speed, finalvalue, cdimension, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSinusoidalTransitionFromVelocity(this: *mut core::ffi::c_void, duration: f64, period: f64, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary2", "CreateSinusoidalTransitionFromVelocity", 12usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary2_Vtbl)).CreateSinusoidalTransitionFromVelocity)(this, duration, period, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSinusoidalTransitionFromRange(this: *mut core::ffi::c_void, duration: f64, minimumvalue: f64, maximumvalue: f64, period: f64, slope: UI_ANIMATION_SLOPE, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary2", "CreateSinusoidalTransitionFromRange", 13usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary2_Vtbl)).CreateSinusoidalTransitionFromRange)(this, duration, minimumvalue, maximumvalue, period, slope, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAccelerateDecelerateTransition(this: *mut core::ffi::c_void, duration: f64, finalvalue: f64, accelerationratio: f64, decelerationratio: f64, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary2", "CreateAccelerateDecelerateTransition", 14usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary2_Vtbl)).CreateAccelerateDecelerateTransition)(this, duration, finalvalue, accelerationratio, decelerationratio, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateReversalTransition(this: *mut core::ffi::c_void, duration: f64, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary2", "CreateReversalTransition", 15usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary2_Vtbl)).CreateReversalTransition)(this, duration, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCubicTransition(this: *mut core::ffi::c_void, duration: f64, finalvalue: f64, finalvelocity: f64, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary2", "CreateCubicTransition", 16usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary2_Vtbl)).CreateCubicTransition)(this, duration, finalvalue, finalvelocity, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCubicVectorTransition(this: *mut core::ffi::c_void, duration: f64, finalvalue: *const f64, finalvelocity: *const f64, cdimension: u32, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary2", "CreateCubicVectorTransition", 17usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary2_Vtbl)).CreateCubicVectorTransition)(this, duration, finalvalue, finalvelocity, cdimension, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSmoothStopTransition(this: *mut core::ffi::c_void, maximumduration: f64, finalvalue: f64, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary2", "CreateSmoothStopTransition", 18usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary2_Vtbl)).CreateSmoothStopTransition)(this, maximumduration, finalvalue, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateParabolicTransitionFromAcceleration(this: *mut core::ffi::c_void, finalvalue: f64, finalvelocity: f64, acceleration: f64, transition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary2", "CreateParabolicTransitionFromAcceleration", 19usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary2_Vtbl)).CreateParabolicTransitionFromAcceleration)(this, finalvalue, finalvelocity, acceleration, transition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCubicBezierLinearTransition(this: *mut core::ffi::c_void, duration: f64, finalvalue: f64, x1: f64, y1: f64, x2: f64, y2: f64, pptransition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary2", "CreateCubicBezierLinearTransition", 20usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary2_Vtbl)).CreateCubicBezierLinearTransition)(this, duration, finalvalue, x1, y1, x2, y2, pptransition), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateCubicBezierLinearVectorTransition(this: *mut core::ffi::c_void, duration: f64, finalvalue: *const f64, cdimension: u32, x1: f64, y1: f64, x2: f64, y2: f64, pptransition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationTransitionLibrary2", "CreateCubicBezierLinearVectorTransition", 21usize, |this| ((**(this as *const *const IUIAnimationTransitionLibrary2_Vtbl)).CreateCubicBezierLinearVectorTransition)(this, duration, finalvalue, cdimension, x1, y1, x2, y2, pptransition), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            CreateInstantaneousTransition,
+            CreateInstantaneousVectorTransition,
+            CreateConstantTransition,
+            CreateDiscreteTransition,
+            CreateDiscreteVectorTransition,
+            CreateLinearTransition,
+            CreateLinearVectorTransition,
+            CreateLinearTransitionFromSpeed,
+            CreateLinearVectorTransitionFromSpeed,
+            CreateSinusoidalTransitionFromVelocity,
+            CreateSinusoidalTransitionFromRange,
+            CreateAccelerateDecelerateTransition,
+            CreateReversalTransition,
+            CreateCubicTransition,
+            CreateCubicVectorTransition,
+            CreateSmoothStopTransition,
+            CreateParabolicTransitionFromAcceleration,
+            CreateCubicBezierLinearTransition,
+            CreateCubicBezierLinearVectorTransition,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationTransitionLibrary2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationVariable_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, value: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable", "GetValue", 3usize, |this| ((**(this as *const *const IUIAnimationVariable_Vtbl)).GetValue)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFinalValue(this: *mut core::ffi::c_void, finalvalue: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable", "GetFinalValue", 4usize, |this| ((**(this as *const *const IUIAnimationVariable_Vtbl)).GetFinalValue)(this, finalvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreviousValue(this: *mut core::ffi::c_void, previousvalue: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable", "GetPreviousValue", 5usize, |this| ((**(this as *const *const IUIAnimationVariable_Vtbl)).GetPreviousValue)(this, previousvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIntegerValue(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable", "GetIntegerValue", 6usize, |this| ((**(this as *const *const IUIAnimationVariable_Vtbl)).GetIntegerValue)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFinalIntegerValue(this: *mut core::ffi::c_void, finalvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable", "GetFinalIntegerValue", 7usize, |this| ((**(this as *const *const IUIAnimationVariable_Vtbl)).GetFinalIntegerValue)(this, finalvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreviousIntegerValue(this: *mut core::ffi::c_void, previousvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable", "GetPreviousIntegerValue", 8usize, |this| ((**(this as *const *const IUIAnimationVariable_Vtbl)).GetPreviousIntegerValue)(this, previousvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentStoryboard(this: *mut core::ffi::c_void, storyboard: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable", "GetCurrentStoryboard", 9usize, |this| ((**(this as *const *const IUIAnimationVariable_Vtbl)).GetCurrentStoryboard)(this, storyboard), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLowerBound(this: *mut core::ffi::c_void, bound: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable", "SetLowerBound", 10usize, |this| ((**(this as *const *const IUIAnimationVariable_Vtbl)).SetLowerBound)(this, bound), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUpperBound(this: *mut core::ffi::c_void, bound: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable", "SetUpperBound", 11usize, |this| ((**(this as *const *const IUIAnimationVariable_Vtbl)).SetUpperBound)(this, bound), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRoundingMode(this: *mut core::ffi::c_void, mode: UI_ANIMATION_ROUNDING_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable", "SetRoundingMode", 12usize, |this| ((**(this as *const *const IUIAnimationVariable_Vtbl)).SetRoundingMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTag(this: *mut core::ffi::c_void, object: *mut core::ffi::c_void, id: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable", "SetTag", 13usize, |this| ((**(this as *const *const IUIAnimationVariable_Vtbl)).SetTag)(this, object, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, object: *mut *mut core::ffi::c_void, id: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable", "GetTag", 14usize, |this| ((**(this as *const *const IUIAnimationVariable_Vtbl)).GetTag)(this, object, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVariableChangeHandler(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable", "SetVariableChangeHandler", 15usize, |this| ((**(this as *const *const IUIAnimationVariable_Vtbl)).SetVariableChangeHandler)(this, handler), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVariableIntegerChangeHandler(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable", "SetVariableIntegerChangeHandler", 16usize, |this| ((**(this as *const *const IUIAnimationVariable_Vtbl)).SetVariableIntegerChangeHandler)(this, handler), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetValue,
+            GetFinalValue,
+            GetPreviousValue,
+            GetIntegerValue,
+            GetFinalIntegerValue,
+            GetPreviousIntegerValue,
+            GetCurrentStoryboard,
+            SetLowerBound,
+            SetUpperBound,
+            SetRoundingMode,
+            SetTag,
+            GetTag,
+            SetVariableChangeHandler,
+            SetVariableIntegerChangeHandler,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationVariable as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_DirectComposition")]
+impl windows_core::imp::ProxyVtable for IUIAnimationVariable2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDimension(this: *mut core::ffi::c_void, dimension: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable2", "GetDimension", 3usize, |this| ((**(this as *const *const IUIAnimationVariable2_Vtbl)).GetDimension)(this, dimension), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, value: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable2", "GetValue", 4usize, |this| ((**(this as *const *const IUIAnimationVariable2_Vtbl)).GetValue)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVectorValue(this: *mut core::ffi::c_void, value: *mut f64, cdimension: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable2", "GetVectorValue", 5usize, |this| ((**(this as *const *const IUIAnimationVariable2_Vtbl)).GetVectorValue)(this, value, cdimension), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectComposition")]
+        unsafe extern "system" fn GetCurve(this: *mut core::ffi::c_void, animation: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable2", "GetCurve", 6usize, |this| ((**(this as *const *const IUIAnimationVariable2_Vtbl)).GetCurve)(this, animation), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_DirectComposition")]
+        unsafe extern "system" fn GetVectorCurve(this: *mut core::ffi::c_void, animation: *const *mut core::ffi::c_void, cdimension: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable2", "GetVectorCurve", 7usize, |this| ((**(this as *const *const IUIAnimationVariable2_Vtbl)).GetVectorCurve)(this, animation, cdimension), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFinalValue(this: *mut core::ffi::c_void, finalvalue: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable2", "GetFinalValue", 8usize, |this| ((**(this as *const *const IUIAnimationVariable2_Vtbl)).GetFinalValue)(this, finalvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFinalVectorValue(this: *mut core::ffi::c_void, finalvalue: *mut f64, cdimension: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable2", "GetFinalVectorValue", 9usize, |this| ((**(this as *const *const IUIAnimationVariable2_Vtbl)).GetFinalVectorValue)(this, finalvalue, cdimension), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreviousValue(this: *mut core::ffi::c_void, previousvalue: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable2", "GetPreviousValue", 10usize, |this| ((**(this as *const *const IUIAnimationVariable2_Vtbl)).GetPreviousValue)(this, previousvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreviousVectorValue(this: *mut core::ffi::c_void, previousvalue: *mut f64, cdimension: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable2", "GetPreviousVectorValue", 11usize, |this| ((**(this as *const *const IUIAnimationVariable2_Vtbl)).GetPreviousVectorValue)(this, previousvalue, cdimension), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIntegerValue(this: *mut core::ffi::c_void, value: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable2", "GetIntegerValue", 12usize, |this| ((**(this as *const *const IUIAnimationVariable2_Vtbl)).GetIntegerValue)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIntegerVectorValue(this: *mut core::ffi::c_void, value: *mut i32, cdimension: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable2", "GetIntegerVectorValue", 13usize, |this| ((**(this as *const *const IUIAnimationVariable2_Vtbl)).GetIntegerVectorValue)(this, value, cdimension), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFinalIntegerValue(this: *mut core::ffi::c_void, finalvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable2", "GetFinalIntegerValue", 14usize, |this| ((**(this as *const *const IUIAnimationVariable2_Vtbl)).GetFinalIntegerValue)(this, finalvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFinalIntegerVectorValue(this: *mut core::ffi::c_void, finalvalue: *mut i32, cdimension: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable2", "GetFinalIntegerVectorValue", 15usize, |this| ((**(this as *const *const IUIAnimationVariable2_Vtbl)).GetFinalIntegerVectorValue)(this, finalvalue, cdimension), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreviousIntegerValue(this: *mut core::ffi::c_void, previousvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable2", "GetPreviousIntegerValue", 16usize, |this| ((**(this as *const *const IUIAnimationVariable2_Vtbl)).GetPreviousIntegerValue)(this, previousvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreviousIntegerVectorValue(this: *mut core::ffi::c_void, previousvalue: *mut i32, cdimension: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable2", "GetPreviousIntegerVectorValue", 17usize, |this| ((**(this as *const *const IUIAnimationVariable2_Vtbl)).GetPreviousIntegerVectorValue)(this, previousvalue, cdimension), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentStoryboard(this: *mut core::ffi::c_void, storyboard: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable2", "GetCurrentStoryboard", 18usize, |this| ((**(this as *const *const IUIAnimationVariable2_Vtbl)).GetCurrentStoryboard)(this, storyboard), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLowerBound(this: *mut core::ffi::c_void, bound: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable2", "SetLowerBound", 19usize, |this| ((**(this as *const *const IUIAnimationVariable2_Vtbl)).SetLowerBound)(this, bound), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLowerBoundVector(this: *mut core::ffi::c_void, bound: *const f64, cdimension: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable2", "SetLowerBoundVector", 20usize, |this| ((**(this as *const *const IUIAnimationVariable2_Vtbl)).SetLowerBoundVector)(this, bound, cdimension), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUpperBound(this: *mut core::ffi::c_void, bound: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable2", "SetUpperBound", 21usize, |this| ((**(this as *const *const IUIAnimationVariable2_Vtbl)).SetUpperBound)(this, bound), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUpperBoundVector(this: *mut core::ffi::c_void, bound: *const f64, cdimension: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable2", "SetUpperBoundVector", 22usize, |this| ((**(this as *const *const IUIAnimationVariable2_Vtbl)).SetUpperBoundVector)(this, bound, cdimension), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRoundingMode(this: *mut core::ffi::c_void, mode: UI_ANIMATION_ROUNDING_MODE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable2", "SetRoundingMode", 23usize, |this| ((**(this as *const *const IUIAnimationVariable2_Vtbl)).SetRoundingMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTag(this: *mut core::ffi::c_void, object: *mut core::ffi::c_void, id: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable2", "SetTag", 24usize, |this| ((**(this as *const *const IUIAnimationVariable2_Vtbl)).SetTag)(this, object, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTag(this: *mut core::ffi::c_void, object: *mut *mut core::ffi::c_void, id: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable2", "GetTag", 25usize, |this| ((**(this as *const *const IUIAnimationVariable2_Vtbl)).GetTag)(this, object, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVariableChangeHandler(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, fregisterfornextanimationevent: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable2", "SetVariableChangeHandler", 26usize, |this| ((**(this as *const *const IUIAnimationVariable2_Vtbl)).SetVariableChangeHandler)(this, handler, fregisterfornextanimationevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVariableIntegerChangeHandler(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void, fregisterfornextanimationevent: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable2", "SetVariableIntegerChangeHandler", 27usize, |this| ((**(this as *const *const IUIAnimationVariable2_Vtbl)).SetVariableIntegerChangeHandler)(this, handler, fregisterfornextanimationevent), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVariableCurveChangeHandler(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariable2", "SetVariableCurveChangeHandler", 28usize, |this| ((**(this as *const *const IUIAnimationVariable2_Vtbl)).SetVariableCurveChangeHandler)(this, handler), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetDimension,
+            GetValue,
+            GetVectorValue,
+            #[cfg(feature = "Win32_Graphics_DirectComposition")]
+            GetCurve,
+            #[cfg(not(feature = "Win32_Graphics_DirectComposition"))]
+            GetCurve: 0,
+            #[cfg(feature = "Win32_Graphics_DirectComposition")]
+            GetVectorCurve,
+            #[cfg(not(feature = "Win32_Graphics_DirectComposition"))]
+            GetVectorCurve: 0,
+            GetFinalValue,
+            GetFinalVectorValue,
+            GetPreviousValue,
+            GetPreviousVectorValue,
+            GetIntegerValue,
+            GetIntegerVectorValue,
+            GetFinalIntegerValue,
+            GetFinalIntegerVectorValue,
+            GetPreviousIntegerValue,
+            GetPreviousIntegerVectorValue,
+            GetCurrentStoryboard,
+            SetLowerBound,
+            SetLowerBoundVector,
+            SetUpperBound,
+            SetUpperBoundVector,
+            SetRoundingMode,
+            SetTag,
+            GetTag,
+            SetVariableChangeHandler,
+            SetVariableIntegerChangeHandler,
+            SetVariableCurveChangeHandler,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationVariable2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationVariableChangeHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnValueChanged(this: *mut core::ffi::c_void, storyboard: *mut core::ffi::c_void, variable: *mut core::ffi::c_void, newvalue: f64, previousvalue: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariableChangeHandler", "OnValueChanged", 3usize, |this| ((**(this as *const *const IUIAnimationVariableChangeHandler_Vtbl)).OnValueChanged)(this, storyboard, variable, newvalue, previousvalue), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnValueChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationVariableChangeHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationVariableChangeHandler2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnValueChanged(this: *mut core::ffi::c_void, storyboard: *mut core::ffi::c_void, variable: *mut core::ffi::c_void, newvalue: *const f64, previousvalue: *const f64, cdimension: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariableChangeHandler2", "OnValueChanged", 3usize, |this| ((**(this as *const *const IUIAnimationVariableChangeHandler2_Vtbl)).OnValueChanged)(this, storyboard, variable, newvalue, previousvalue, cdimension), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnValueChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationVariableChangeHandler2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationVariableCurveChangeHandler2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnCurveChanged(this: *mut core::ffi::c_void, variable: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariableCurveChangeHandler2", "OnCurveChanged", 3usize, |this| ((**(this as *const *const IUIAnimationVariableCurveChangeHandler2_Vtbl)).OnCurveChanged)(this, variable), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnCurveChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationVariableCurveChangeHandler2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationVariableIntegerChangeHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnIntegerValueChanged(this: *mut core::ffi::c_void, storyboard: *mut core::ffi::c_void, variable: *mut core::ffi::c_void, newvalue: i32, previousvalue: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariableIntegerChangeHandler", "OnIntegerValueChanged", 3usize, |this| ((**(this as *const *const IUIAnimationVariableIntegerChangeHandler_Vtbl)).OnIntegerValueChanged)(this, storyboard, variable, newvalue, previousvalue), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnIntegerValueChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationVariableIntegerChangeHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIAnimationVariableIntegerChangeHandler2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnIntegerValueChanged(this: *mut core::ffi::c_void, storyboard: *mut core::ffi::c_void, variable: *mut core::ffi::c_void, newvalue: *const i32, previousvalue: *const i32, cdimension: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIAnimationVariableIntegerChangeHandler2", "OnIntegerValueChanged", 3usize, |this| ((**(this as *const *const IUIAnimationVariableIntegerChangeHandler2_Vtbl)).OnIntegerValueChanged)(this, storyboard, variable, newvalue, previousvalue, cdimension), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnIntegerValueChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIAnimationVariableIntegerChangeHandler2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -0,0 +1,70 @@
+impl windows_core::imp::ProxyVtable for IDeviceModelPlugIn_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, bstrxml: core::mem::MaybeUninit<windows_core::BSTR>, cnummodels: u32, imodelposition: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceModelPlugIn", "Initialize", 3usize, |this| ((**(this as *const *const IDeviceModelPlugIn_Vtbl)).Initialize)(this, bstrxml, cnummodels, imodelposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumChannels(this: *mut core::ffi::c_void, pnumchannels: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceModelPlugIn", "GetNumChannels", 4usize, |this| ((**(this as *const *const IDeviceModelPlugIn_Vtbl)).GetNumChannels)(this, pnumchannels), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeviceToColorimetricColors(this: *mut core::ffi::c_void, ccolors: u32, cchannels: u32, pdevicevalues: *const f32, pxyzcolors: *mut XYZColorF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceModelPlugIn", "DeviceToColorimetricColors", 5usize, |this| ((**(this as *const *const IDeviceModelPlugIn_Vtbl)).DeviceToColorimetricColors)(this, ccolors, cchannels, pdevicevalues, pxyzcolors), |result| Some(*result))
+        }
+        unsafe extern "system" fn ColorimetricToDeviceColors(this: *mut core::ffi::c_void, ccolors: u32, cchannels: u32, pxyzcolors: *const XYZColorF, pdevicevalues: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceModelPlugIn", "ColorimetricToDeviceColors", 6usize, |this| ((**(this as *const *const IDeviceModelPlugIn_Vtbl)).ColorimetricToDeviceColors)(this, ccolors, cchannels, pxyzcolors, pdevicevalues), |result| Some(*result))
+        }
+        unsafe extern "system" fn ColorimetricToDeviceColorsWithBlack(this: *mut core::ffi::c_void, ccolors: u32, cchannels: u32, pxyzcolors: *const XYZColorF, pblackinformation: *const BlackInformation, pdevicevalues: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceModelPlugIn", "ColorimetricToDeviceColorsWithBlack", 7usize, |this| ((**(this as *const *const IDeviceModelPlugIn_Vtbl)).ColorimetricToDeviceColorsWithBlack)(this, ccolors, cchannels, pxyzcolors, pblackinformation, pdevicevalues), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransformDeviceModelInfo(this: *mut core::ffi::c_void, imodelposition: u32, pidevicemodelother: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceModelPlugIn", "SetTransformDeviceModelInfo", 8usize, |this| ((**(this as *const *const IDeviceModelPlugIn_Vtbl)).SetTransformDeviceModelInfo)(this, imodelposition, pidevicemodelother), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPrimarySamples(this: *mut core::ffi::c_void, pprimarycolor: *mut PrimaryXYZColors) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceModelPlugIn", "GetPrimarySamples", 9usize, |this| ((**(this as *const *const IDeviceModelPlugIn_Vtbl)).GetPrimarySamples)(this, pprimarycolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGamutBoundaryMeshSize(this: *mut core::ffi::c_void, pnumvertices: *mut u32, pnumtriangles: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceModelPlugIn", "GetGamutBoundaryMeshSize", 10usize, |this| ((**(this as *const *const IDeviceModelPlugIn_Vtbl)).GetGamutBoundaryMeshSize)(this, pnumvertices, pnumtriangles), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGamutBoundaryMesh(this: *mut core::ffi::c_void, cchannels: u32, cvertices: u32, ctriangles: u32, pvertices: *mut f32, ptriangles: *mut GamutShellTriangle) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceModelPlugIn", "GetGamutBoundaryMesh", 11usize, |this| ((**(this as *const *const IDeviceModelPlugIn_Vtbl)).GetGamutBoundaryMesh)(this, cchannels, cvertices, ctriangles, pvertices, ptriangles), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNeutralAxisSize(this: *mut core::ffi::c_void, pccolors: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceModelPlugIn", "GetNeutralAxisSize", 12usize, |this| ((**(this as *const *const IDeviceModelPlugIn_Vtbl)).GetNeutralAxisSize)(this, pccolors), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNeutralAxis(this: *mut core::ffi::c_void, ccolors: u32, pxyzcolors: *mut XYZColorF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDeviceModelPlugIn", "GetNeutralAxis", 13usize, |this| ((**(this as *const *const IDeviceModelPlugIn_Vtbl)).GetNeutralAxis)(this, ccolors, pxyzcolors), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            GetNumChannels,
+            DeviceToColorimetricColors,
+            ColorimetricToDeviceColors,
+            ColorimetricToDeviceColorsWithBlack,
+            SetTransformDeviceModelInfo,
+            GetPrimarySamples,
+            GetGamutBoundaryMeshSize,
+            GetGamutBoundaryMesh,
+            GetNeutralAxisSize,
+            GetNeutralAxis,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceModelPlugIn as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGamutMapModelPlugIn_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, bstrxml: core::mem::MaybeUninit<windows_core::BSTR>, psrcplugin: *mut core::ffi::c_void, pdestplugin: *mut core::ffi::c_void, psrcgbd: *const GamutBoundaryDescription, pdestgbd: *const GamutBoundaryDescription) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGamutMapModelPlugIn", "Initialize", 3usize, |this| ((**(this as *const *const IGamutMapModelPlugIn_Vtbl)).Initialize)(this, bstrxml, psrcplugin, pdestplugin, psrcgbd, pdestgbd), |result| Some(*result))
+        }
+        unsafe extern "system" fn SourceToDestinationAppearanceColors(this: *mut core::ffi::c_void, ccolors: u32, pinputcolors: *const JChColorF, poutputcolors: *mut JChColorF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGamutMapModelPlugIn", "SourceToDestinationAppearanceColors", 4usize, |this| ((**(this as *const *const IGamutMapModelPlugIn_Vtbl)).SourceToDestinationAppearanceColors)(this, ccolors, pinputcolors, poutputcolors), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, SourceToDestinationAppearanceColors }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGamutMapModelPlugIn as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
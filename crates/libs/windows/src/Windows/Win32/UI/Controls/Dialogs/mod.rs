@@ -462,6 +462,7 @@ pub struct CHOOSECOLOR_FLAGS(pub u32);
 impl windows_core::TypeKind for CHOOSECOLOR_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CHOOSECOLOR_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CHOOSECOLOR_FLAGS").field(&self.0).finish()
@@ -506,6 +507,7 @@ pub struct CHOOSEFONT_FLAGS(pub u32);
 impl windows_core::TypeKind for CHOOSEFONT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CHOOSEFONT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CHOOSEFONT_FLAGS").field(&self.0).finish()
@@ -550,6 +552,7 @@ pub struct CHOOSEFONT_FONT_TYPE(pub u16);
 impl windows_core::TypeKind for CHOOSEFONT_FONT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CHOOSEFONT_FONT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CHOOSEFONT_FONT_TYPE").field(&self.0).finish()
@@ -594,6 +597,7 @@ pub struct COMMON_DLG_ERRORS(pub u32);
 impl windows_core::TypeKind for COMMON_DLG_ERRORS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMMON_DLG_ERRORS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMMON_DLG_ERRORS").field(&self.0).finish()
@@ -605,6 +609,7 @@ pub struct FINDREPLACE_FLAGS(pub u32);
 impl windows_core::TypeKind for FINDREPLACE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FINDREPLACE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FINDREPLACE_FLAGS").field(&self.0).finish()
@@ -649,6 +654,7 @@ pub struct OPEN_FILENAME_FLAGS(pub u32);
 impl windows_core::TypeKind for OPEN_FILENAME_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPEN_FILENAME_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPEN_FILENAME_FLAGS").field(&self.0).finish()
@@ -693,6 +699,7 @@ pub struct OPEN_FILENAME_FLAGS_EX(pub u32);
 impl windows_core::TypeKind for OPEN_FILENAME_FLAGS_EX {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPEN_FILENAME_FLAGS_EX {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPEN_FILENAME_FLAGS_EX").field(&self.0).finish()
@@ -737,6 +744,7 @@ pub struct PAGESETUPDLG_FLAGS(pub u32);
 impl windows_core::TypeKind for PAGESETUPDLG_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PAGESETUPDLG_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PAGESETUPDLG_FLAGS").field(&self.0).finish()
@@ -781,6 +789,7 @@ pub struct PRINTDLGEX_FLAGS(pub u32);
 impl windows_core::TypeKind for PRINTDLGEX_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PRINTDLGEX_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PRINTDLGEX_FLAGS").field(&self.0).finish()
@@ -2107,3 +2116,5 @@ pub type LPPRINTHOOKPROC = Option<unsafe extern "system" fn(param0: super::super
 pub type LPSETUPHOOKPROC = Option<unsafe extern "system" fn(param0: super::super::super::Foundation::HWND, param1: u32, param2: super::super::super::Foundation::WPARAM, param3: super::super::super::Foundation::LPARAM) -> usize>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,46 @@
+impl windows_core::imp::ProxyVtable for IPrintDialogCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitDone(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintDialogCallback", "InitDone", 3usize, |this| ((**(this as *const *const IPrintDialogCallback_Vtbl)).InitDone)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelectionChange(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintDialogCallback", "SelectionChange", 4usize, |this| ((**(this as *const *const IPrintDialogCallback_Vtbl)).SelectionChange)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn HandleMessage(this: *mut core::ffi::c_void, hdlg: super::super::super::Foundation::HWND, umsg: u32, wparam: super::super::super::Foundation::WPARAM, lparam: super::super::super::Foundation::LPARAM, presult: *mut super::super::super::Foundation::LRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintDialogCallback", "HandleMessage", 5usize, |this| ((**(this as *const *const IPrintDialogCallback_Vtbl)).HandleMessage)(this, hdlg, umsg, wparam, lparam, presult), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InitDone, SelectionChange, HandleMessage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintDialogCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IPrintDialogServices_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetCurrentDevMode(this: *mut core::ffi::c_void, pdevmode: *mut super::super::super::Graphics::Gdi::DEVMODEA, pcbsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintDialogServices", "GetCurrentDevMode", 3usize, |this| ((**(this as *const *const IPrintDialogServices_Vtbl)).GetCurrentDevMode)(this, pdevmode, pcbsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentPrinterName(this: *mut core::ffi::c_void, pprintername: windows_core::PWSTR, pcchsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintDialogServices", "GetCurrentPrinterName", 4usize, |this| ((**(this as *const *const IPrintDialogServices_Vtbl)).GetCurrentPrinterName)(this, pprintername, pcchsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentPortName(this: *mut core::ffi::c_void, pportname: windows_core::PWSTR, pcchsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintDialogServices", "GetCurrentPortName", 5usize, |this| ((**(this as *const *const IPrintDialogServices_Vtbl)).GetCurrentPortName)(this, pportname, pcchsize), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetCurrentDevMode,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetCurrentDevMode: 0,
+            GetCurrentPrinterName,
+            GetCurrentPortName,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintDialogServices as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -4687,6 +4687,7 @@ pub struct CARET_FLAGS(pub i32);
 impl windows_core::TypeKind for CARET_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CARET_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CARET_FLAGS").field(&self.0).finish()
@@ -4698,6 +4699,7 @@ pub struct CFE_EFFECTS(pub u32);
 impl windows_core::TypeKind for CFE_EFFECTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CFE_EFFECTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CFE_EFFECTS").field(&self.0).finish()
@@ -4742,6 +4744,7 @@ pub struct CFM_MASK(pub u32);
 impl windows_core::TypeKind for CFM_MASK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CFM_MASK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CFM_MASK").field(&self.0).finish()
@@ -4786,6 +4789,7 @@ pub struct CHANGETYPE(pub i32);
 impl windows_core::TypeKind for CHANGETYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CHANGETYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CHANGETYPE").field(&self.0).finish()
@@ -4797,6 +4801,7 @@ pub struct ENDCOMPOSITIONNOTIFY_CODE(pub u32);
 impl windows_core::TypeKind for ENDCOMPOSITIONNOTIFY_CODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ENDCOMPOSITIONNOTIFY_CODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ENDCOMPOSITIONNOTIFY_CODE").field(&self.0).finish()
@@ -4808,6 +4813,7 @@ pub struct GETTEXTEX_FLAGS(pub u32);
 impl windows_core::TypeKind for GETTEXTEX_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GETTEXTEX_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GETTEXTEX_FLAGS").field(&self.0).finish()
@@ -4819,6 +4825,7 @@ pub struct GETTEXTLENGTHEX_FLAGS(pub u32);
 impl windows_core::TypeKind for GETTEXTLENGTHEX_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GETTEXTLENGTHEX_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GETTEXTLENGTHEX_FLAGS").field(&self.0).finish()
@@ -4863,6 +4870,7 @@ pub struct IMECOMPTEXT_FLAGS(pub u32);
 impl windows_core::TypeKind for IMECOMPTEXT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMECOMPTEXT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMECOMPTEXT_FLAGS").field(&self.0).finish()
@@ -4874,6 +4882,7 @@ pub struct KHYPH(pub i32);
 impl windows_core::TypeKind for KHYPH {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KHYPH {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KHYPH").field(&self.0).finish()
@@ -4885,6 +4894,7 @@ pub struct MANCODE(pub i32);
 impl windows_core::TypeKind for MANCODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MANCODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MANCODE").field(&self.0).finish()
@@ -4896,6 +4906,7 @@ pub struct OBJECTTYPE(pub i32);
 impl windows_core::TypeKind for OBJECTTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OBJECTTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OBJECTTYPE").field(&self.0).finish()
@@ -4907,6 +4918,7 @@ pub struct PARAFORMAT_ALIGNMENT(pub u16);
 impl windows_core::TypeKind for PARAFORMAT_ALIGNMENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PARAFORMAT_ALIGNMENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PARAFORMAT_ALIGNMENT").field(&self.0).finish()
@@ -4918,6 +4930,7 @@ pub struct PARAFORMAT_BORDERS(pub u16);
 impl windows_core::TypeKind for PARAFORMAT_BORDERS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PARAFORMAT_BORDERS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PARAFORMAT_BORDERS").field(&self.0).finish()
@@ -4962,6 +4975,7 @@ pub struct PARAFORMAT_MASK(pub u32);
 impl windows_core::TypeKind for PARAFORMAT_MASK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PARAFORMAT_MASK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PARAFORMAT_MASK").field(&self.0).finish()
@@ -5006,6 +5020,7 @@ pub struct PARAFORMAT_NUMBERING(pub u16);
 impl windows_core::TypeKind for PARAFORMAT_NUMBERING {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PARAFORMAT_NUMBERING {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PARAFORMAT_NUMBERING").field(&self.0).finish()
@@ -5050,6 +5065,7 @@ pub struct PARAFORMAT_NUMBERING_STYLE(pub u16);
 impl windows_core::TypeKind for PARAFORMAT_NUMBERING_STYLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PARAFORMAT_NUMBERING_STYLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PARAFORMAT_NUMBERING_STYLE").field(&self.0).finish()
@@ -5061,6 +5077,7 @@ pub struct PARAFORMAT_SHADING_STYLE(pub u16);
 impl windows_core::TypeKind for PARAFORMAT_SHADING_STYLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PARAFORMAT_SHADING_STYLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PARAFORMAT_SHADING_STYLE").field(&self.0).finish()
@@ -5072,6 +5089,7 @@ pub struct REOBJECT_FLAGS(pub u32);
 impl windows_core::TypeKind for REOBJECT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for REOBJECT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("REOBJECT_FLAGS").field(&self.0).finish()
@@ -5116,6 +5134,7 @@ pub struct RICH_EDIT_GET_CONTEXT_MENU_SEL_TYPE(pub u16);
 impl windows_core::TypeKind for RICH_EDIT_GET_CONTEXT_MENU_SEL_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RICH_EDIT_GET_CONTEXT_MENU_SEL_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RICH_EDIT_GET_CONTEXT_MENU_SEL_TYPE").field(&self.0).finish()
@@ -5160,6 +5179,7 @@ pub struct RICH_EDIT_GET_OBJECT_FLAGS(pub u32);
 impl windows_core::TypeKind for RICH_EDIT_GET_OBJECT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RICH_EDIT_GET_OBJECT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RICH_EDIT_GET_OBJECT_FLAGS").field(&self.0).finish()
@@ -5204,6 +5224,7 @@ pub struct TEXTMODE(pub i32);
 impl windows_core::TypeKind for TEXTMODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TEXTMODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TEXTMODE").field(&self.0).finish()
@@ -5215,6 +5236,7 @@ pub struct TXTBACKSTYLE(pub i32);
 impl windows_core::TypeKind for TXTBACKSTYLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TXTBACKSTYLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TXTBACKSTYLE").field(&self.0).finish()
@@ -5226,6 +5248,7 @@ pub struct TXTHITRESULT(pub i32);
 impl windows_core::TypeKind for TXTHITRESULT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TXTHITRESULT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TXTHITRESULT").field(&self.0).finish()
@@ -5237,6 +5260,7 @@ pub struct TXTNATURALSIZE(pub i32);
 impl windows_core::TypeKind for TXTNATURALSIZE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TXTNATURALSIZE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TXTNATURALSIZE").field(&self.0).finish()
@@ -5248,6 +5272,7 @@ pub struct TXTVIEW(pub i32);
 impl windows_core::TypeKind for TXTVIEW {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TXTVIEW {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TXTVIEW").field(&self.0).finish()
@@ -5259,6 +5284,7 @@ pub struct UNDONAMEID(pub i32);
 impl windows_core::TypeKind for UNDONAMEID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UNDONAMEID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UNDONAMEID").field(&self.0).finish()
@@ -5270,13 +5296,15 @@ pub struct tomConstants(pub i32);
 impl windows_core::TypeKind for tomConstants {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for tomConstants {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("tomConstants").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BIDIOPTIONS {
     pub cbSize: u32,
     pub wMask: u16,
@@ -5308,7 +5336,8 @@ impl Default for CARET_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CHANGENOTIFY {
     pub dwChangeType: u32,
     pub pvCookieData: *mut core::ffi::c_void,
@@ -5411,7 +5440,8 @@ impl Default for CHARFORMAT2W_0 {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CHARFORMATA {
     pub cbSize: u32,
     pub dwMask: CFM_MASK,
@@ -5435,7 +5465,8 @@ impl Default for CHARFORMATA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CHARFORMATW {
     pub cbSize: u32,
     pub dwMask: CFM_MASK,
@@ -5458,7 +5489,8 @@ impl Default for CHARFORMATW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CHARRANGE {
     pub cpMin: i32,
     pub cpMax: i32,
@@ -5506,7 +5538,8 @@ impl Default for CLIPBOARDFORMAT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COMPCOLOR {
     pub crText: super::super::super::Foundation::COLORREF,
     pub crBackground: super::super::super::Foundation::COLORREF,
@@ -6117,7 +6150,8 @@ impl Default for GETTEXTEX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GETTEXTLENGTHEX {
     pub flags: GETTEXTLENGTHEX_FLAGS,
     pub codepage: u32,
@@ -6181,7 +6215,8 @@ impl Default for HYPHENATEINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HYPHRESULT {
     pub khyph: KHYPH,
     pub ichHyph: i32,
@@ -6196,7 +6231,8 @@ impl Default for HYPHRESULT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMECOMPTEXT {
     pub cb: i32,
     pub flags: IMECOMPTEXT_FLAGS,
@@ -6382,7 +6418,8 @@ impl Default for PUNCTUATION {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Ole"))]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REOBJECT {
     pub cbStruct: u32,
     pub cp: i32,
@@ -6575,7 +6612,8 @@ impl Default for SELCHANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SETTEXTEX {
     pub flags: u32,
     pub codepage: u32,
@@ -6589,7 +6627,8 @@ impl Default for SETTEXTEX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TABLECELLPARMS {
     pub dxWidth: i32,
     pub _bitfield: u16,
@@ -6605,6 +6644,44 @@ pub struct TABLECELLPARMS {
     pub crBackPat: super::super::super::Foundation::COLORREF,
     pub crForePat: super::super::super::Foundation::COLORREF,
 }
+impl TABLECELLPARMS {
+    pub const fn nVertAlign(&self) -> u16 {
+        self._bitfield & 3
+    }
+    pub fn set_nVertAlign(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !3) | (value & 3);
+    }
+    pub const fn fMergeTop(&self) -> u16 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_fMergeTop(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn fMergePrev(&self) -> u16 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_fMergePrev(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn fVertical(&self) -> u16 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_fVertical(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn fMergeStart(&self) -> u16 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_fMergeStart(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn fMergeCont(&self) -> u16 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_fMergeCont(&mut self, value: u16) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+}
 impl windows_core::TypeKind for TABLECELLPARMS {
     type TypeKind = windows_core::CopyType;
 }
@@ -6614,7 +6691,8 @@ impl Default for TABLECELLPARMS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TABLEROWPARMS {
     pub cbRow: u8,
     pub cbCell: u8,
@@ -6628,6 +6706,44 @@ pub struct TABLEROWPARMS {
     pub bTableLevel: u8,
     pub iCell: u8,
 }
+impl TABLEROWPARMS {
+    pub const fn nAlignment(&self) -> u32 {
+        self._bitfield & 7
+    }
+    pub fn set_nAlignment(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !7) | (value & 7);
+    }
+    pub const fn fRTL(&self) -> u32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_fRTL(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn fKeep(&self) -> u32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_fKeep(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn fKeepFollow(&self) -> u32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_fKeepFollow(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn fWrap(&self) -> u32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_fWrap(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn fIdentCells(&self) -> u32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_fIdentCells(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+}
 impl windows_core::TypeKind for TABLEROWPARMS {
     type TypeKind = windows_core::CopyType;
 }
@@ -6711,3 +6827,5 @@ pub type PCreateTextServices = Option<unsafe extern "system" fn(punkouter: Optio
 pub type PShutdownTextServices = Option<unsafe extern "system" fn(ptextservices: Option<windows_core::IUnknown>) -> windows_core::HRESULT>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,2772 @@
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IRichEditOle_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn GetClientSite(this: *mut core::ffi::c_void, lplpolesite: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichEditOle", "GetClientSite", 3usize, |this| ((**(this as *const *const IRichEditOle_Vtbl)).GetClientSite)(this, lplpolesite), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetObjectCount(this: *mut core::ffi::c_void) -> i32 {
+            windows_core::imp::proxy_call(this, "IRichEditOle", "GetObjectCount", 4usize, |this| ((**(this as *const *const IRichEditOle_Vtbl)).GetObjectCount)(this), |_| None)
+        }
+        unsafe extern "system" fn GetLinkCount(this: *mut core::ffi::c_void) -> i32 {
+            windows_core::imp::proxy_call(this, "IRichEditOle", "GetLinkCount", 5usize, |this| ((**(this as *const *const IRichEditOle_Vtbl)).GetLinkCount)(this), |_| None)
+        }
+        #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Ole"))]
+        unsafe extern "system" fn GetObject(this: *mut core::ffi::c_void, iob: i32, lpreobject: *mut REOBJECT, dwflags: RICH_EDIT_GET_OBJECT_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichEditOle", "GetObject", 6usize, |this| ((**(this as *const *const IRichEditOle_Vtbl)).GetObject)(this, iob, lpreobject, dwflags), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Ole"))]
+        unsafe extern "system" fn InsertObject(this: *mut core::ffi::c_void, lpreobject: *mut REOBJECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichEditOle", "InsertObject", 7usize, |this| ((**(this as *const *const IRichEditOle_Vtbl)).InsertObject)(this, lpreobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertObject(this: *mut core::ffi::c_void, iob: i32, rclsidnew: *const windows_core::GUID, lpstrusertypenew: windows_core::PCSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichEditOle", "ConvertObject", 8usize, |this| ((**(this as *const *const IRichEditOle_Vtbl)).ConvertObject)(this, iob, rclsidnew, lpstrusertypenew), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivateAs(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, rclsidas: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichEditOle", "ActivateAs", 9usize, |this| ((**(this as *const *const IRichEditOle_Vtbl)).ActivateAs)(this, rclsid, rclsidas), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHostNames(this: *mut core::ffi::c_void, lpstrcontainerapp: windows_core::PCSTR, lpstrcontainerobj: windows_core::PCSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichEditOle", "SetHostNames", 10usize, |this| ((**(this as *const *const IRichEditOle_Vtbl)).SetHostNames)(this, lpstrcontainerapp, lpstrcontainerobj), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLinkAvailable(this: *mut core::ffi::c_void, iob: i32, favailable: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichEditOle", "SetLinkAvailable", 11usize, |this| ((**(this as *const *const IRichEditOle_Vtbl)).SetLinkAvailable)(this, iob, favailable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDvaspect(this: *mut core::ffi::c_void, iob: i32, dvaspect: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichEditOle", "SetDvaspect", 12usize, |this| ((**(this as *const *const IRichEditOle_Vtbl)).SetDvaspect)(this, iob, dvaspect), |result| Some(*result))
+        }
+        unsafe extern "system" fn HandsOffStorage(this: *mut core::ffi::c_void, iob: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichEditOle", "HandsOffStorage", 13usize, |this| ((**(this as *const *const IRichEditOle_Vtbl)).HandsOffStorage)(this, iob), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn SaveCompleted(this: *mut core::ffi::c_void, iob: i32, lpstg: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichEditOle", "SaveCompleted", 14usize, |this| ((**(this as *const *const IRichEditOle_Vtbl)).SaveCompleted)(this, iob, lpstg), |result| Some(*result))
+        }
+        unsafe extern "system" fn InPlaceDeactivate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichEditOle", "InPlaceDeactivate", 15usize, |this| ((**(this as *const *const IRichEditOle_Vtbl)).InPlaceDeactivate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContextSensitiveHelp(this: *mut core::ffi::c_void, fentermode: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichEditOle", "ContextSensitiveHelp", 16usize, |this| ((**(this as *const *const IRichEditOle_Vtbl)).ContextSensitiveHelp)(this, fentermode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetClipboardData(this: *mut core::ffi::c_void, lpchrg: *mut CHARRANGE, reco: u32, lplpdataobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichEditOle", "GetClipboardData", 17usize, |this| ((**(this as *const *const IRichEditOle_Vtbl)).GetClipboardData)(this, lpchrg, reco, lplpdataobj), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ImportDataObject(this: *mut core::ffi::c_void, lpdataobj: *mut core::ffi::c_void, cf: u16, hmetapict: super::super::super::Foundation::HGLOBAL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichEditOle", "ImportDataObject", 18usize, |this| ((**(this as *const *const IRichEditOle_Vtbl)).ImportDataObject)(this, lpdataobj, cf, hmetapict), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Ole")]
+            GetClientSite,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            GetClientSite: 0,
+            GetObjectCount,
+            GetLinkCount,
+            #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Ole"))]
+            GetObject,
+            #[cfg(not(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Ole")))]
+            GetObject: 0,
+            #[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Ole"))]
+            InsertObject,
+            #[cfg(not(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Ole")))]
+            InsertObject: 0,
+            ConvertObject,
+            ActivateAs,
+            SetHostNames,
+            SetLinkAvailable,
+            SetDvaspect,
+            HandsOffStorage,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            SaveCompleted,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            SaveCompleted: 0,
+            InPlaceDeactivate,
+            ContextSensitiveHelp,
+            #[cfg(feature = "Win32_System_Com")]
+            GetClipboardData,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetClipboardData: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ImportDataObject,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ImportDataObject: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRichEditOle as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com_StructuredStorage", feature = "Win32_System_Ole", feature = "Win32_System_SystemServices", feature = "Win32_UI_WindowsAndMessaging"))]
+impl windows_core::imp::ProxyVtable for IRichEditOleCallback_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn GetNewStorage(this: *mut core::ffi::c_void, lplpstg: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichEditOleCallback", "GetNewStorage", 3usize, |this| ((**(this as *const *const IRichEditOleCallback_Vtbl)).GetNewStorage)(this, lplpstg), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_UI_WindowsAndMessaging"))]
+        unsafe extern "system" fn GetInPlaceContext(this: *mut core::ffi::c_void, lplpframe: *mut *mut core::ffi::c_void, lplpdoc: *mut *mut core::ffi::c_void, lpframeinfo: *mut super::super::super::System::Ole::OLEINPLACEFRAMEINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichEditOleCallback", "GetInPlaceContext", 4usize, |this| ((**(this as *const *const IRichEditOleCallback_Vtbl)).GetInPlaceContext)(this, lplpframe, lplpdoc, lpframeinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowContainerUI(this: *mut core::ffi::c_void, fshow: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichEditOleCallback", "ShowContainerUI", 5usize, |this| ((**(this as *const *const IRichEditOleCallback_Vtbl)).ShowContainerUI)(this, fshow), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn QueryInsertObject(this: *mut core::ffi::c_void, lpclsid: *mut windows_core::GUID, lpstg: *mut core::ffi::c_void, cp: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichEditOleCallback", "QueryInsertObject", 6usize, |this| ((**(this as *const *const IRichEditOleCallback_Vtbl)).QueryInsertObject)(this, lpclsid, lpstg, cp), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn DeleteObject(this: *mut core::ffi::c_void, lpoleobj: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichEditOleCallback", "DeleteObject", 7usize, |this| ((**(this as *const *const IRichEditOleCallback_Vtbl)).DeleteObject)(this, lpoleobj), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_SystemServices"))]
+        unsafe extern "system" fn QueryAcceptData(this: *mut core::ffi::c_void, lpdataobj: *mut core::ffi::c_void, lpcfformat: *mut u16, reco: super::super::super::System::SystemServices::RECO_FLAGS, freally: super::super::super::Foundation::BOOL, hmetapict: super::super::super::Foundation::HGLOBAL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichEditOleCallback", "QueryAcceptData", 8usize, |this| ((**(this as *const *const IRichEditOleCallback_Vtbl)).QueryAcceptData)(this, lpdataobj, lpcfformat, reco, freally, hmetapict), |result| Some(*result))
+        }
+        unsafe extern "system" fn ContextSensitiveHelp(this: *mut core::ffi::c_void, fentermode: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichEditOleCallback", "ContextSensitiveHelp", 9usize, |this| ((**(this as *const *const IRichEditOleCallback_Vtbl)).ContextSensitiveHelp)(this, fentermode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetClipboardData(this: *mut core::ffi::c_void, lpchrg: *mut CHARRANGE, reco: u32, lplpdataobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichEditOleCallback", "GetClipboardData", 10usize, |this| ((**(this as *const *const IRichEditOleCallback_Vtbl)).GetClipboardData)(this, lpchrg, reco, lplpdataobj), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_SystemServices"))]
+        unsafe extern "system" fn GetDragDropEffect(this: *mut core::ffi::c_void, fdrag: super::super::super::Foundation::BOOL, grfkeystate: super::super::super::System::SystemServices::MODIFIERKEYS_FLAGS, pdweffect: *mut super::super::super::System::Ole::DROPEFFECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichEditOleCallback", "GetDragDropEffect", 11usize, |this| ((**(this as *const *const IRichEditOleCallback_Vtbl)).GetDragDropEffect)(this, fdrag, grfkeystate, pdweffect), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_UI_WindowsAndMessaging"))]
+        unsafe extern "system" fn GetContextMenu(this: *mut core::ffi::c_void, seltype: RICH_EDIT_GET_CONTEXT_MENU_SEL_TYPE, lpoleobj: *mut core::ffi::c_void, lpchrg: *mut CHARRANGE, lphmenu: *mut super::super::WindowsAndMessaging::HMENU) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRichEditOleCallback", "GetContextMenu", 12usize, |this| ((**(this as *const *const IRichEditOleCallback_Vtbl)).GetContextMenu)(this, seltype, lpoleobj, lpchrg, lphmenu), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            GetNewStorage,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            GetNewStorage: 0,
+            #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_UI_WindowsAndMessaging"))]
+            GetInPlaceContext,
+            #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_UI_WindowsAndMessaging")))]
+            GetInPlaceContext: 0,
+            ShowContainerUI,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            QueryInsertObject,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            QueryInsertObject: 0,
+            #[cfg(feature = "Win32_System_Ole")]
+            DeleteObject,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            DeleteObject: 0,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_SystemServices"))]
+            QueryAcceptData,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_SystemServices")))]
+            QueryAcceptData: 0,
+            ContextSensitiveHelp,
+            #[cfg(feature = "Win32_System_Com")]
+            GetClipboardData,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetClipboardData: 0,
+            #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_System_SystemServices"))]
+            GetDragDropEffect,
+            #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_System_SystemServices")))]
+            GetDragDropEffect: 0,
+            #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_UI_WindowsAndMessaging"))]
+            GetContextMenu,
+            #[cfg(not(all(feature = "Win32_System_Ole", feature = "Win32_UI_WindowsAndMessaging")))]
+            GetContextMenu: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRichEditOleCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRicheditUiaOverrides_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPropertyOverrideValue(this: *mut core::ffi::c_void, propertyid: i32, pretvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRicheditUiaOverrides", "GetPropertyOverrideValue", 3usize, |this| ((**(this as *const *const IRicheditUiaOverrides_Vtbl)).GetPropertyOverrideValue)(this, propertyid, pretvalue), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPropertyOverrideValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRicheditUiaOverrides as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITextDisplays_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextDisplays as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITextDocument_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, pname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "GetName", 7usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).GetName)(this, pname), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSelection(this: *mut core::ffi::c_void, ppsel: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "GetSelection", 8usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).GetSelection)(this, ppsel), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStoryCount(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "GetStoryCount", 9usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).GetStoryCount)(this, pcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetStoryRanges(this: *mut core::ffi::c_void, ppstories: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "GetStoryRanges", 10usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).GetStoryRanges)(this, ppstories), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSaved(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "GetSaved", 11usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).GetSaved)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSaved(this: *mut core::ffi::c_void, value: tomConstants) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "SetSaved", 12usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).SetSaved)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultTabStop(this: *mut core::ffi::c_void, pvalue: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "GetDefaultTabStop", 13usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).GetDefaultTabStop)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultTabStop(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "SetDefaultTabStop", 14usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).SetDefaultTabStop)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn New(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "New", 15usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).New)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, pvar: *const core::mem::MaybeUninit<windows_core::VARIANT>, flags: tomConstants, codepage: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "Open", 16usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).Open)(this, pvar, flags, codepage), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void, pvar: *const core::mem::MaybeUninit<windows_core::VARIANT>, flags: tomConstants, codepage: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "Save", 17usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).Save)(this, pvar, flags, codepage), |result| Some(*result))
+        }
+        unsafe extern "system" fn Freeze(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "Freeze", 18usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).Freeze)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unfreeze(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "Unfreeze", 19usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).Unfreeze)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginEditCollection(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "BeginEditCollection", 20usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).BeginEditCollection)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndEditCollection(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "EndEditCollection", 21usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).EndEditCollection)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Undo(this: *mut core::ffi::c_void, count: i32, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "Undo", 22usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).Undo)(this, count, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn Redo(this: *mut core::ffi::c_void, count: i32, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "Redo", 23usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).Redo)(this, count, pcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Range(this: *mut core::ffi::c_void, cpactive: i32, cpanchor: i32, pprange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "Range", 24usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).Range)(this, cpactive, cpanchor, pprange), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RangeFromPoint(this: *mut core::ffi::c_void, x: i32, y: i32, pprange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument", "RangeFromPoint", 25usize, |this| ((**(this as *const *const ITextDocument_Vtbl)).RangeFromPoint)(this, x, y, pprange), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetName,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSelection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSelection: 0,
+            GetStoryCount,
+            #[cfg(feature = "Win32_System_Com")]
+            GetStoryRanges,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetStoryRanges: 0,
+            GetSaved,
+            SetSaved,
+            GetDefaultTabStop,
+            SetDefaultTabStop,
+            New,
+            Open,
+            Save,
+            Freeze,
+            Unfreeze,
+            BeginEditCollection,
+            EndEditCollection,
+            Undo,
+            Redo,
+            #[cfg(feature = "Win32_System_Com")]
+            Range,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Range: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            RangeFromPoint,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RangeFromPoint: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextDocument as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITextDocument2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCaretType(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "GetCaretType", 26usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).GetCaretType)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCaretType(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "SetCaretType", 27usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).SetCaretType)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetDisplays(this: *mut core::ffi::c_void, ppdisplays: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "GetDisplays", 28usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).GetDisplays)(this, ppdisplays), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetDocumentFont(this: *mut core::ffi::c_void, ppfont: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "GetDocumentFont", 29usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).GetDocumentFont)(this, ppfont), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetDocumentFont(this: *mut core::ffi::c_void, pfont: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "SetDocumentFont", 30usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).SetDocumentFont)(this, pfont), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetDocumentPara(this: *mut core::ffi::c_void, pppara: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "GetDocumentPara", 31usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).GetDocumentPara)(this, pppara), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetDocumentPara(this: *mut core::ffi::c_void, ppara: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "SetDocumentPara", 32usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).SetDocumentPara)(this, ppara), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEastAsianFlags(this: *mut core::ffi::c_void, pflags: *mut tomConstants) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "GetEastAsianFlags", 33usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).GetEastAsianFlags)(this, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGenerator(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "GetGenerator", 34usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).GetGenerator)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIMEInProgress(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "SetIMEInProgress", 35usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).SetIMEInProgress)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNotificationMode(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "GetNotificationMode", 36usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).GetNotificationMode)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNotificationMode(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "SetNotificationMode", 37usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).SetNotificationMode)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSelection2(this: *mut core::ffi::c_void, ppsel: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "GetSelection2", 38usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).GetSelection2)(this, ppsel), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetStoryRanges2(this: *mut core::ffi::c_void, ppstories: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "GetStoryRanges2", 39usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).GetStoryRanges2)(this, ppstories), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTypographyOptions(this: *mut core::ffi::c_void, poptions: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "GetTypographyOptions", 40usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).GetTypographyOptions)(this, poptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersion(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "GetVersion", 41usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).GetVersion)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWindow(this: *mut core::ffi::c_void, phwnd: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "GetWindow", 42usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).GetWindow)(this, phwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttachMsgFilter(this: *mut core::ffi::c_void, pfilter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "AttachMsgFilter", 43usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).AttachMsgFilter)(this, pfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckTextLimit(this: *mut core::ffi::c_void, cch: i32, pcch: *const i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "CheckTextLimit", 44usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).CheckTextLimit)(this, cch, pcch), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCallManager(this: *mut core::ffi::c_void, ppvoid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "GetCallManager", 45usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).GetCallManager)(this, ppvoid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClientRect(this: *mut core::ffi::c_void, r#type: tomConstants, pleft: *mut i32, ptop: *mut i32, pright: *mut i32, pbottom: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "GetClientRect", 46usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).GetClientRect)(this, r#type, pleft, ptop, pright, pbottom), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEffectColor(this: *mut core::ffi::c_void, index: i32, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "GetEffectColor", 47usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).GetEffectColor)(this, index, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetImmContext(this: *mut core::ffi::c_void, pcontext: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "GetImmContext", 48usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).GetImmContext)(this, pcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreferredFont(this: *mut core::ffi::c_void, cp: i32, charrep: i32, options: i32, curcharrep: i32, curfontsize: i32, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>, ppitchandfamily: *mut i32, pnewfontsize: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "GetPreferredFont", 49usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).GetPreferredFont)(this, cp, charrep, options, curcharrep, curfontsize, pbstr, ppitchandfamily, pnewfontsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, r#type: i32, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "GetProperty", 50usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).GetProperty)(this, r#type, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetStrings(this: *mut core::ffi::c_void, ppstrs: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "GetStrings", 51usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).GetStrings)(this, ppstrs), |result| Some(*result))
+        }
+        unsafe extern "system" fn Notify(this: *mut core::ffi::c_void, notify: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "Notify", 52usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).Notify)(this, notify), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Range2(this: *mut core::ffi::c_void, cpactive: i32, cpanchor: i32, pprange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "Range2", 53usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).Range2)(this, cpactive, cpanchor, pprange), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RangeFromPoint2(this: *mut core::ffi::c_void, x: i32, y: i32, r#type: i32, pprange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "RangeFromPoint2", 54usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).RangeFromPoint2)(this, x, y, r#type, pprange), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseCallManager(this: *mut core::ffi::c_void, pvoid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "ReleaseCallManager", 55usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).ReleaseCallManager)(this, pvoid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseImmContext(this: *mut core::ffi::c_void, context: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "ReleaseImmContext", 56usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).ReleaseImmContext)(this, context), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEffectColor(this: *mut core::ffi::c_void, index: i32, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "SetEffectColor", 57usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).SetEffectColor)(this, index, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, r#type: i32, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "SetProperty", 58usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).SetProperty)(this, r#type, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTypographyOptions(this: *mut core::ffi::c_void, options: i32, mask: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "SetTypographyOptions", 59usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).SetTypographyOptions)(this, options, mask), |result| Some(*result))
+        }
+        unsafe extern "system" fn SysBeep(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "SysBeep", 60usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).SysBeep)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "Update", 61usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).Update)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateWindow(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "UpdateWindow", 62usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).UpdateWindow)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMathProperties(this: *mut core::ffi::c_void, poptions: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "GetMathProperties", 63usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).GetMathProperties)(this, poptions), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMathProperties(this: *mut core::ffi::c_void, options: i32, mask: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "SetMathProperties", 64usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).SetMathProperties)(this, options, mask), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActiveStory(this: *mut core::ffi::c_void, ppstory: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "GetActiveStory", 65usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).GetActiveStory)(this, ppstory), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActiveStory(this: *mut core::ffi::c_void, pstory: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "SetActiveStory", 66usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).SetActiveStory)(this, pstory), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMainStory(this: *mut core::ffi::c_void, ppstory: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "GetMainStory", 67usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).GetMainStory)(this, ppstory), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNewStory(this: *mut core::ffi::c_void, ppstory: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "GetNewStory", 68usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).GetNewStory)(this, ppstory), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStory(this: *mut core::ffi::c_void, index: i32, ppstory: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2", "GetStory", 69usize, |this| ((**(this as *const *const ITextDocument2_Vtbl)).GetStory)(this, index, ppstory), |result| Some(*result))
+        }
+        Self {
+            base__: <ITextDocument_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCaretType,
+            SetCaretType,
+            #[cfg(feature = "Win32_System_Com")]
+            GetDisplays,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetDisplays: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetDocumentFont,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetDocumentFont: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetDocumentFont,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetDocumentFont: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetDocumentPara,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetDocumentPara: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetDocumentPara,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetDocumentPara: 0,
+            GetEastAsianFlags,
+            GetGenerator,
+            SetIMEInProgress,
+            GetNotificationMode,
+            SetNotificationMode,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSelection2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSelection2: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetStoryRanges2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetStoryRanges2: 0,
+            GetTypographyOptions,
+            GetVersion,
+            GetWindow,
+            AttachMsgFilter,
+            CheckTextLimit,
+            GetCallManager,
+            GetClientRect,
+            GetEffectColor,
+            GetImmContext,
+            GetPreferredFont,
+            GetProperty,
+            #[cfg(feature = "Win32_System_Com")]
+            GetStrings,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetStrings: 0,
+            Notify,
+            #[cfg(feature = "Win32_System_Com")]
+            Range2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Range2: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            RangeFromPoint2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RangeFromPoint2: 0,
+            ReleaseCallManager,
+            ReleaseImmContext,
+            SetEffectColor,
+            SetProperty,
+            SetTypographyOptions,
+            SysBeep,
+            Update,
+            UpdateWindow,
+            GetMathProperties,
+            SetMathProperties,
+            GetActiveStory,
+            SetActiveStory,
+            GetMainStory,
+            GetNewStory,
+            GetStory,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextDocument2 as windows_core::Interface>::IID || <ITextDocument_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITextDocument2Old_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AttachMsgFilter(this: *mut core::ffi::c_void, pfilter: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2Old", "AttachMsgFilter", 26usize, |this| ((**(this as *const *const ITextDocument2Old_Vtbl)).AttachMsgFilter)(this, pfilter), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEffectColor(this: *mut core::ffi::c_void, index: i32, cr: super::super::super::Foundation::COLORREF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2Old", "SetEffectColor", 27usize, |this| ((**(this as *const *const ITextDocument2Old_Vtbl)).SetEffectColor)(this, index, cr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEffectColor(this: *mut core::ffi::c_void, index: i32, pcr: *mut super::super::super::Foundation::COLORREF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2Old", "GetEffectColor", 28usize, |this| ((**(this as *const *const ITextDocument2Old_Vtbl)).GetEffectColor)(this, index, pcr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCaretType(this: *mut core::ffi::c_void, pcarettype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2Old", "GetCaretType", 29usize, |this| ((**(this as *const *const ITextDocument2Old_Vtbl)).GetCaretType)(this, pcarettype), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCaretType(this: *mut core::ffi::c_void, carettype: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2Old", "SetCaretType", 30usize, |this| ((**(this as *const *const ITextDocument2Old_Vtbl)).SetCaretType)(this, carettype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetImmContext(this: *mut core::ffi::c_void, pcontext: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2Old", "GetImmContext", 31usize, |this| ((**(this as *const *const ITextDocument2Old_Vtbl)).GetImmContext)(this, pcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseImmContext(this: *mut core::ffi::c_void, context: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2Old", "ReleaseImmContext", 32usize, |this| ((**(this as *const *const ITextDocument2Old_Vtbl)).ReleaseImmContext)(this, context), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreferredFont(this: *mut core::ffi::c_void, cp: i32, charrep: i32, option: i32, charrepcur: i32, curfontsize: i32, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>, ppitchandfamily: *mut i32, pnewfontsize: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2Old", "GetPreferredFont", 33usize, |this| ((**(this as *const *const ITextDocument2Old_Vtbl)).GetPreferredFont)(this, cp, charrep, option, charrepcur, curfontsize, pbstr, ppitchandfamily, pnewfontsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNotificationMode(this: *mut core::ffi::c_void, pmode: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2Old", "GetNotificationMode", 34usize, |this| ((**(this as *const *const ITextDocument2Old_Vtbl)).GetNotificationMode)(this, pmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNotificationMode(this: *mut core::ffi::c_void, mode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2Old", "SetNotificationMode", 35usize, |this| ((**(this as *const *const ITextDocument2Old_Vtbl)).SetNotificationMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetClientRect(this: *mut core::ffi::c_void, r#type: i32, pleft: *mut i32, ptop: *mut i32, pright: *mut i32, pbottom: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2Old", "GetClientRect", 36usize, |this| ((**(this as *const *const ITextDocument2Old_Vtbl)).GetClientRect)(this, r#type, pleft, ptop, pright, pbottom), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetSelection2(this: *mut core::ffi::c_void, ppsel: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2Old", "GetSelection2", 37usize, |this| ((**(this as *const *const ITextDocument2Old_Vtbl)).GetSelection2)(this, ppsel), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWindow(this: *mut core::ffi::c_void, phwnd: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2Old", "GetWindow", 38usize, |this| ((**(this as *const *const ITextDocument2Old_Vtbl)).GetWindow)(this, phwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFEFlags(this: *mut core::ffi::c_void, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2Old", "GetFEFlags", 39usize, |this| ((**(this as *const *const ITextDocument2Old_Vtbl)).GetFEFlags)(this, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateWindow(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2Old", "UpdateWindow", 40usize, |this| ((**(this as *const *const ITextDocument2Old_Vtbl)).UpdateWindow)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CheckTextLimit(this: *mut core::ffi::c_void, cch: i32, pcch: *const i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2Old", "CheckTextLimit", 41usize, |this| ((**(this as *const *const ITextDocument2Old_Vtbl)).CheckTextLimit)(this, cch, pcch), |result| Some(*result))
+        }
+        unsafe extern "system" fn IMEInProgress(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2Old", "IMEInProgress", 42usize, |this| ((**(this as *const *const ITextDocument2Old_Vtbl)).IMEInProgress)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SysBeep(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2Old", "SysBeep", 43usize, |this| ((**(this as *const *const ITextDocument2Old_Vtbl)).SysBeep)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Update(this: *mut core::ffi::c_void, mode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2Old", "Update", 44usize, |this| ((**(this as *const *const ITextDocument2Old_Vtbl)).Update)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn Notify(this: *mut core::ffi::c_void, notify: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2Old", "Notify", 45usize, |this| ((**(this as *const *const ITextDocument2Old_Vtbl)).Notify)(this, notify), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetDocumentFont(this: *mut core::ffi::c_void, ppitextfont: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2Old", "GetDocumentFont", 46usize, |this| ((**(this as *const *const ITextDocument2Old_Vtbl)).GetDocumentFont)(this, ppitextfont), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetDocumentPara(this: *mut core::ffi::c_void, ppitextpara: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2Old", "GetDocumentPara", 47usize, |this| ((**(this as *const *const ITextDocument2Old_Vtbl)).GetDocumentPara)(this, ppitextpara), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCallManager(this: *mut core::ffi::c_void, ppvoid: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2Old", "GetCallManager", 48usize, |this| ((**(this as *const *const ITextDocument2Old_Vtbl)).GetCallManager)(this, ppvoid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseCallManager(this: *mut core::ffi::c_void, pvoid: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextDocument2Old", "ReleaseCallManager", 49usize, |this| ((**(this as *const *const ITextDocument2Old_Vtbl)).ReleaseCallManager)(this, pvoid), |result| Some(*result))
+        }
+        Self {
+            base__: <ITextDocument_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AttachMsgFilter,
+            SetEffectColor,
+            GetEffectColor,
+            GetCaretType,
+            SetCaretType,
+            GetImmContext,
+            ReleaseImmContext,
+            GetPreferredFont,
+            GetNotificationMode,
+            SetNotificationMode,
+            GetClientRect,
+            #[cfg(feature = "Win32_System_Com")]
+            GetSelection2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetSelection2: 0,
+            GetWindow,
+            GetFEFlags,
+            UpdateWindow,
+            CheckTextLimit,
+            IMEInProgress,
+            SysBeep,
+            Update,
+            Notify,
+            #[cfg(feature = "Win32_System_Com")]
+            GetDocumentFont,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetDocumentFont: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetDocumentPara,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetDocumentPara: 0,
+            GetCallManager,
+            ReleaseCallManager,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextDocument2Old as windows_core::Interface>::IID || <ITextDocument_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITextFont_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetDuplicate(this: *mut core::ffi::c_void, ppfont: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "GetDuplicate", 7usize, |this| ((**(this as *const *const ITextFont_Vtbl)).GetDuplicate)(this, ppfont), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetDuplicate(this: *mut core::ffi::c_void, pfont: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "SetDuplicate", 8usize, |this| ((**(this as *const *const ITextFont_Vtbl)).SetDuplicate)(this, pfont), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanChange(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "CanChange", 9usize, |this| ((**(this as *const *const ITextFont_Vtbl)).CanChange)(this, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn IsEqual(this: *mut core::ffi::c_void, pfont: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "IsEqual", 10usize, |this| ((**(this as *const *const ITextFont_Vtbl)).IsEqual)(this, pfont, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void, value: tomConstants) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "Reset", 11usize, |this| ((**(this as *const *const ITextFont_Vtbl)).Reset)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStyle(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "GetStyle", 12usize, |this| ((**(this as *const *const ITextFont_Vtbl)).GetStyle)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStyle(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "SetStyle", 13usize, |this| ((**(this as *const *const ITextFont_Vtbl)).SetStyle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllCaps(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "GetAllCaps", 14usize, |this| ((**(this as *const *const ITextFont_Vtbl)).GetAllCaps)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllCaps(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "SetAllCaps", 15usize, |this| ((**(this as *const *const ITextFont_Vtbl)).SetAllCaps)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAnimation(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "GetAnimation", 16usize, |this| ((**(this as *const *const ITextFont_Vtbl)).GetAnimation)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAnimation(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "SetAnimation", 17usize, |this| ((**(this as *const *const ITextFont_Vtbl)).SetAnimation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBackColor(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "GetBackColor", 18usize, |this| ((**(this as *const *const ITextFont_Vtbl)).GetBackColor)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackColor(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "SetBackColor", 19usize, |this| ((**(this as *const *const ITextFont_Vtbl)).SetBackColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBold(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "GetBold", 20usize, |this| ((**(this as *const *const ITextFont_Vtbl)).GetBold)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBold(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "SetBold", 21usize, |this| ((**(this as *const *const ITextFont_Vtbl)).SetBold)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEmboss(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "GetEmboss", 22usize, |this| ((**(this as *const *const ITextFont_Vtbl)).GetEmboss)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEmboss(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "SetEmboss", 23usize, |this| ((**(this as *const *const ITextFont_Vtbl)).SetEmboss)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForeColor(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "GetForeColor", 24usize, |this| ((**(this as *const *const ITextFont_Vtbl)).GetForeColor)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetForeColor(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "SetForeColor", 25usize, |this| ((**(this as *const *const ITextFont_Vtbl)).SetForeColor)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHidden(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "GetHidden", 26usize, |this| ((**(this as *const *const ITextFont_Vtbl)).GetHidden)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHidden(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "SetHidden", 27usize, |this| ((**(this as *const *const ITextFont_Vtbl)).SetHidden)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEngrave(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "GetEngrave", 28usize, |this| ((**(this as *const *const ITextFont_Vtbl)).GetEngrave)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEngrave(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "SetEngrave", 29usize, |this| ((**(this as *const *const ITextFont_Vtbl)).SetEngrave)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItalic(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "GetItalic", 30usize, |this| ((**(this as *const *const ITextFont_Vtbl)).GetItalic)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetItalic(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "SetItalic", 31usize, |this| ((**(this as *const *const ITextFont_Vtbl)).SetItalic)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKerning(this: *mut core::ffi::c_void, pvalue: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "GetKerning", 32usize, |this| ((**(this as *const *const ITextFont_Vtbl)).GetKerning)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKerning(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "SetKerning", 33usize, |this| ((**(this as *const *const ITextFont_Vtbl)).SetKerning)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLanguageID(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "GetLanguageID", 34usize, |this| ((**(this as *const *const ITextFont_Vtbl)).GetLanguageID)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLanguageID(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "SetLanguageID", 35usize, |this| ((**(this as *const *const ITextFont_Vtbl)).SetLanguageID)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "GetName", 36usize, |this| ((**(this as *const *const ITextFont_Vtbl)).GetName)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetName(this: *mut core::ffi::c_void, bstr: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "SetName", 37usize, |this| ((**(this as *const *const ITextFont_Vtbl)).SetName)(this, bstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutline(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "GetOutline", 38usize, |this| ((**(this as *const *const ITextFont_Vtbl)).GetOutline)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutline(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "SetOutline", 39usize, |this| ((**(this as *const *const ITextFont_Vtbl)).SetOutline)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPosition(this: *mut core::ffi::c_void, pvalue: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "GetPosition", 40usize, |this| ((**(this as *const *const ITextFont_Vtbl)).GetPosition)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPosition(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "SetPosition", 41usize, |this| ((**(this as *const *const ITextFont_Vtbl)).SetPosition)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProtected(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "GetProtected", 42usize, |this| ((**(this as *const *const ITextFont_Vtbl)).GetProtected)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProtected(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "SetProtected", 43usize, |this| ((**(this as *const *const ITextFont_Vtbl)).SetProtected)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetShadow(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "GetShadow", 44usize, |this| ((**(this as *const *const ITextFont_Vtbl)).GetShadow)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetShadow(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "SetShadow", 45usize, |this| ((**(this as *const *const ITextFont_Vtbl)).SetShadow)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSize(this: *mut core::ffi::c_void, pvalue: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "GetSize", 46usize, |this| ((**(this as *const *const ITextFont_Vtbl)).GetSize)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSize(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "SetSize", 47usize, |this| ((**(this as *const *const ITextFont_Vtbl)).SetSize)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSmallCaps(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "GetSmallCaps", 48usize, |this| ((**(this as *const *const ITextFont_Vtbl)).GetSmallCaps)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSmallCaps(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "SetSmallCaps", 49usize, |this| ((**(this as *const *const ITextFont_Vtbl)).SetSmallCaps)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSpacing(this: *mut core::ffi::c_void, pvalue: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "GetSpacing", 50usize, |this| ((**(this as *const *const ITextFont_Vtbl)).GetSpacing)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSpacing(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "SetSpacing", 51usize, |this| ((**(this as *const *const ITextFont_Vtbl)).SetSpacing)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStrikeThrough(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "GetStrikeThrough", 52usize, |this| ((**(this as *const *const ITextFont_Vtbl)).GetStrikeThrough)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStrikeThrough(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "SetStrikeThrough", 53usize, |this| ((**(this as *const *const ITextFont_Vtbl)).SetStrikeThrough)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubscript(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "GetSubscript", 54usize, |this| ((**(this as *const *const ITextFont_Vtbl)).GetSubscript)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSubscript(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "SetSubscript", 55usize, |this| ((**(this as *const *const ITextFont_Vtbl)).SetSubscript)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSuperscript(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "GetSuperscript", 56usize, |this| ((**(this as *const *const ITextFont_Vtbl)).GetSuperscript)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSuperscript(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "SetSuperscript", 57usize, |this| ((**(this as *const *const ITextFont_Vtbl)).SetSuperscript)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUnderline(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "GetUnderline", 58usize, |this| ((**(this as *const *const ITextFont_Vtbl)).GetUnderline)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUnderline(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "SetUnderline", 59usize, |this| ((**(this as *const *const ITextFont_Vtbl)).SetUnderline)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWeight(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "GetWeight", 60usize, |this| ((**(this as *const *const ITextFont_Vtbl)).GetWeight)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWeight(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont", "SetWeight", 61usize, |this| ((**(this as *const *const ITextFont_Vtbl)).SetWeight)(this, value), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetDuplicate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetDuplicate: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetDuplicate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetDuplicate: 0,
+            CanChange,
+            #[cfg(feature = "Win32_System_Com")]
+            IsEqual,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            IsEqual: 0,
+            Reset,
+            GetStyle,
+            SetStyle,
+            GetAllCaps,
+            SetAllCaps,
+            GetAnimation,
+            SetAnimation,
+            GetBackColor,
+            SetBackColor,
+            GetBold,
+            SetBold,
+            GetEmboss,
+            SetEmboss,
+            GetForeColor,
+            SetForeColor,
+            GetHidden,
+            SetHidden,
+            GetEngrave,
+            SetEngrave,
+            GetItalic,
+            SetItalic,
+            GetKerning,
+            SetKerning,
+            GetLanguageID,
+            SetLanguageID,
+            GetName,
+            SetName,
+            GetOutline,
+            SetOutline,
+            GetPosition,
+            SetPosition,
+            GetProtected,
+            SetProtected,
+            GetShadow,
+            SetShadow,
+            GetSize,
+            SetSize,
+            GetSmallCaps,
+            SetSmallCaps,
+            GetSpacing,
+            SetSpacing,
+            GetStrikeThrough,
+            SetStrikeThrough,
+            GetSubscript,
+            SetSubscript,
+            GetSuperscript,
+            SetSuperscript,
+            GetUnderline,
+            SetUnderline,
+            GetWeight,
+            SetWeight,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextFont as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITextFont2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "GetCount", 62usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).GetCount)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAutoLigatures(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "GetAutoLigatures", 63usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).GetAutoLigatures)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoLigatures(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "SetAutoLigatures", 64usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).SetAutoLigatures)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAutospaceAlpha(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "GetAutospaceAlpha", 65usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).GetAutospaceAlpha)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutospaceAlpha(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "SetAutospaceAlpha", 66usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).SetAutospaceAlpha)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAutospaceNumeric(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "GetAutospaceNumeric", 67usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).GetAutospaceNumeric)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutospaceNumeric(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "SetAutospaceNumeric", 68usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).SetAutospaceNumeric)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAutospaceParens(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "GetAutospaceParens", 69usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).GetAutospaceParens)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutospaceParens(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "SetAutospaceParens", 70usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).SetAutospaceParens)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCharRep(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "GetCharRep", 71usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).GetCharRep)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCharRep(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "SetCharRep", 72usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).SetCharRep)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCompressionMode(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "GetCompressionMode", 73usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).GetCompressionMode)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompressionMode(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "SetCompressionMode", 74usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).SetCompressionMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCookie(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "GetCookie", 75usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).GetCookie)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCookie(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "SetCookie", 76usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).SetCookie)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDoubleStrike(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "GetDoubleStrike", 77usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).GetDoubleStrike)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDoubleStrike(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "SetDoubleStrike", 78usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).SetDoubleStrike)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetDuplicate2(this: *mut core::ffi::c_void, ppfont: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "GetDuplicate2", 79usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).GetDuplicate2)(this, ppfont), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetDuplicate2(this: *mut core::ffi::c_void, pfont: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "SetDuplicate2", 80usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).SetDuplicate2)(this, pfont), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLinkType(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "GetLinkType", 81usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).GetLinkType)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMathZone(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "GetMathZone", 82usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).GetMathZone)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMathZone(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "SetMathZone", 83usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).SetMathZone)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetModWidthPairs(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "GetModWidthPairs", 84usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).GetModWidthPairs)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetModWidthPairs(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "SetModWidthPairs", 85usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).SetModWidthPairs)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetModWidthSpace(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "GetModWidthSpace", 86usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).GetModWidthSpace)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetModWidthSpace(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "SetModWidthSpace", 87usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).SetModWidthSpace)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOldNumbers(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "GetOldNumbers", 88usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).GetOldNumbers)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOldNumbers(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "SetOldNumbers", 89usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).SetOldNumbers)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOverlapping(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "GetOverlapping", 90usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).GetOverlapping)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOverlapping(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "SetOverlapping", 91usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).SetOverlapping)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPositionSubSuper(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "GetPositionSubSuper", 92usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).GetPositionSubSuper)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPositionSubSuper(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "SetPositionSubSuper", 93usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).SetPositionSubSuper)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScaling(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "GetScaling", 94usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).GetScaling)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScaling(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "SetScaling", 95usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).SetScaling)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSpaceExtension(this: *mut core::ffi::c_void, pvalue: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "GetSpaceExtension", 96usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).GetSpaceExtension)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSpaceExtension(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "SetSpaceExtension", 97usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).SetSpaceExtension)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUnderlinePositionMode(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "GetUnderlinePositionMode", 98usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).GetUnderlinePositionMode)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUnderlinePositionMode(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "SetUnderlinePositionMode", 99usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).SetUnderlinePositionMode)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEffects(this: *mut core::ffi::c_void, pvalue: *mut i32, pmask: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "GetEffects", 100usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).GetEffects)(this, pvalue, pmask), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEffects2(this: *mut core::ffi::c_void, pvalue: *mut i32, pmask: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "GetEffects2", 101usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).GetEffects2)(this, pvalue, pmask), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, r#type: i32, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "GetProperty", 102usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).GetProperty)(this, r#type, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyInfo(this: *mut core::ffi::c_void, index: i32, ptype: *mut i32, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "GetPropertyInfo", 103usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).GetPropertyInfo)(this, index, ptype, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn IsEqual2(this: *mut core::ffi::c_void, pfont: *mut core::ffi::c_void, pb: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "IsEqual2", 104usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).IsEqual2)(this, pfont, pb), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEffects(this: *mut core::ffi::c_void, value: i32, mask: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "SetEffects", 105usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).SetEffects)(this, value, mask), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEffects2(this: *mut core::ffi::c_void, value: i32, mask: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "SetEffects2", 106usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).SetEffects2)(this, value, mask), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, r#type: i32, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextFont2", "SetProperty", 107usize, |this| ((**(this as *const *const ITextFont2_Vtbl)).SetProperty)(this, r#type, value), |result| Some(*result))
+        }
+        Self {
+            base__: <ITextFont_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCount,
+            GetAutoLigatures,
+            SetAutoLigatures,
+            GetAutospaceAlpha,
+            SetAutospaceAlpha,
+            GetAutospaceNumeric,
+            SetAutospaceNumeric,
+            GetAutospaceParens,
+            SetAutospaceParens,
+            GetCharRep,
+            SetCharRep,
+            GetCompressionMode,
+            SetCompressionMode,
+            GetCookie,
+            SetCookie,
+            GetDoubleStrike,
+            SetDoubleStrike,
+            #[cfg(feature = "Win32_System_Com")]
+            GetDuplicate2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetDuplicate2: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetDuplicate2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetDuplicate2: 0,
+            GetLinkType,
+            GetMathZone,
+            SetMathZone,
+            GetModWidthPairs,
+            SetModWidthPairs,
+            GetModWidthSpace,
+            SetModWidthSpace,
+            GetOldNumbers,
+            SetOldNumbers,
+            GetOverlapping,
+            SetOverlapping,
+            GetPositionSubSuper,
+            SetPositionSubSuper,
+            GetScaling,
+            SetScaling,
+            GetSpaceExtension,
+            SetSpaceExtension,
+            GetUnderlinePositionMode,
+            SetUnderlinePositionMode,
+            GetEffects,
+            GetEffects2,
+            GetProperty,
+            GetPropertyInfo,
+            #[cfg(feature = "Win32_System_Com")]
+            IsEqual2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            IsEqual2: 0,
+            SetEffects,
+            SetEffects2,
+            SetProperty,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextFont2 as windows_core::Interface>::IID || <ITextFont_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_Input_Ime", feature = "Win32_UI_WindowsAndMessaging"))]
+impl windows_core::imp::ProxyVtable for ITextHost_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn TxGetDC(this: *mut core::ffi::c_void) -> super::super::super::Graphics::Gdi::HDC {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxGetDC", 3usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxGetDC)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn TxReleaseDC(this: *mut core::ffi::c_void, hdc: super::super::super::Graphics::Gdi::HDC) -> i32 {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxReleaseDC", 4usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxReleaseDC)(this, hdc), |_| None)
+        }
+        unsafe extern "system" fn TxShowScrollBar(this: *mut core::ffi::c_void, fnbar: i32, fshow: super::super::super::Foundation::BOOL) -> super::super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxShowScrollBar", 5usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxShowScrollBar)(this, fnbar, fshow), |_| None)
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn TxEnableScrollBar(this: *mut core::ffi::c_void, fusbflags: super::super::WindowsAndMessaging::SCROLLBAR_CONSTANTS, fuarrowflags: i32) -> super::super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxEnableScrollBar", 6usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxEnableScrollBar)(this, fusbflags, fuarrowflags), |_| None)
+        }
+        unsafe extern "system" fn TxSetScrollRange(this: *mut core::ffi::c_void, fnbar: i32, nminpos: i32, nmaxpos: i32, fredraw: super::super::super::Foundation::BOOL) -> super::super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxSetScrollRange", 7usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxSetScrollRange)(this, fnbar, nminpos, nmaxpos, fredraw), |_| None)
+        }
+        unsafe extern "system" fn TxSetScrollPos(this: *mut core::ffi::c_void, fnbar: i32, npos: i32, fredraw: super::super::super::Foundation::BOOL) -> super::super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxSetScrollPos", 8usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxSetScrollPos)(this, fnbar, npos, fredraw), |_| None)
+        }
+        unsafe extern "system" fn TxInvalidateRect(this: *mut core::ffi::c_void, prc: *mut super::super::super::Foundation::RECT, fmode: super::super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxInvalidateRect", 9usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxInvalidateRect)(this, prc, fmode), |_| None)
+        }
+        unsafe extern "system" fn TxViewChange(this: *mut core::ffi::c_void, fupdate: super::super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxViewChange", 10usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxViewChange)(this, fupdate), |_| None)
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn TxCreateCaret(this: *mut core::ffi::c_void, hbmp: super::super::super::Graphics::Gdi::HBITMAP, xwidth: i32, yheight: i32) -> super::super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxCreateCaret", 11usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxCreateCaret)(this, hbmp, xwidth, yheight), |_| None)
+        }
+        unsafe extern "system" fn TxShowCaret(this: *mut core::ffi::c_void, fshow: super::super::super::Foundation::BOOL) -> super::super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxShowCaret", 12usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxShowCaret)(this, fshow), |_| None)
+        }
+        unsafe extern "system" fn TxSetCaretPos(this: *mut core::ffi::c_void, x: i32, y: i32) -> super::super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxSetCaretPos", 13usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxSetCaretPos)(this, x, y), |_| None)
+        }
+        unsafe extern "system" fn TxSetTimer(this: *mut core::ffi::c_void, idtimer: u32, utimeout: u32) -> super::super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxSetTimer", 14usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxSetTimer)(this, idtimer, utimeout), |_| None)
+        }
+        unsafe extern "system" fn TxKillTimer(this: *mut core::ffi::c_void, idtimer: u32) {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxKillTimer", 15usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxKillTimer)(this, idtimer), |_| None)
+        }
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+        unsafe extern "system" fn TxScrollWindowEx(this: *mut core::ffi::c_void, dx: i32, dy: i32, lprcscroll: *mut super::super::super::Foundation::RECT, lprcclip: *mut super::super::super::Foundation::RECT, hrgnupdate: super::super::super::Graphics::Gdi::HRGN, lprcupdate: *mut super::super::super::Foundation::RECT, fuscroll: super::super::WindowsAndMessaging::SCROLL_WINDOW_FLAGS) {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxScrollWindowEx", 16usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxScrollWindowEx)(this, dx, dy, lprcscroll, lprcclip, hrgnupdate, lprcupdate, fuscroll), |_| None)
+        }
+        unsafe extern "system" fn TxSetCapture(this: *mut core::ffi::c_void, fcapture: super::super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxSetCapture", 17usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxSetCapture)(this, fcapture), |_| None)
+        }
+        unsafe extern "system" fn TxSetFocus(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxSetFocus", 18usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxSetFocus)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn TxSetCursor(this: *mut core::ffi::c_void, hcur: super::super::WindowsAndMessaging::HCURSOR, ftext: super::super::super::Foundation::BOOL) {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxSetCursor", 19usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxSetCursor)(this, hcur, ftext), |_| None)
+        }
+        unsafe extern "system" fn TxScreenToClient(this: *mut core::ffi::c_void, lppt: *mut super::super::super::Foundation::POINT) -> super::super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxScreenToClient", 20usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxScreenToClient)(this, lppt), |_| None)
+        }
+        unsafe extern "system" fn TxClientToScreen(this: *mut core::ffi::c_void, lppt: *mut super::super::super::Foundation::POINT) -> super::super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxClientToScreen", 21usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxClientToScreen)(this, lppt), |_| None)
+        }
+        unsafe extern "system" fn TxActivate(this: *mut core::ffi::c_void, ploldstate: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxActivate", 22usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxActivate)(this, ploldstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn TxDeactivate(this: *mut core::ffi::c_void, lnewstate: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxDeactivate", 23usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxDeactivate)(this, lnewstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn TxGetClientRect(this: *mut core::ffi::c_void, prc: *mut super::super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxGetClientRect", 24usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxGetClientRect)(this, prc), |result| Some(*result))
+        }
+        unsafe extern "system" fn TxGetViewInset(this: *mut core::ffi::c_void, prc: *mut super::super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxGetViewInset", 25usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxGetViewInset)(this, prc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn TxGetCharFormat(this: *mut core::ffi::c_void, ppcf: *const *const CHARFORMATW) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxGetCharFormat", 26usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxGetCharFormat)(this, ppcf), |result| Some(*result))
+        }
+        unsafe extern "system" fn TxGetParaFormat(this: *mut core::ffi::c_void, pppf: *const *const PARAFORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxGetParaFormat", 27usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxGetParaFormat)(this, pppf), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn TxGetSysColor(this: *mut core::ffi::c_void, nindex: super::super::super::Graphics::Gdi::SYS_COLOR_INDEX) -> super::super::super::Foundation::COLORREF {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxGetSysColor", 28usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxGetSysColor)(this, nindex), |_| None)
+        }
+        unsafe extern "system" fn TxGetBackStyle(this: *mut core::ffi::c_void, pstyle: *mut TXTBACKSTYLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxGetBackStyle", 29usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxGetBackStyle)(this, pstyle), |result| Some(*result))
+        }
+        unsafe extern "system" fn TxGetMaxLength(this: *mut core::ffi::c_void, plength: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxGetMaxLength", 30usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxGetMaxLength)(this, plength), |result| Some(*result))
+        }
+        unsafe extern "system" fn TxGetScrollBars(this: *mut core::ffi::c_void, pdwscrollbar: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxGetScrollBars", 31usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxGetScrollBars)(this, pdwscrollbar), |result| Some(*result))
+        }
+        unsafe extern "system" fn TxGetPasswordChar(this: *mut core::ffi::c_void, pch: *mut i8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxGetPasswordChar", 32usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxGetPasswordChar)(this, pch), |result| Some(*result))
+        }
+        unsafe extern "system" fn TxGetAcceleratorPos(this: *mut core::ffi::c_void, pcp: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxGetAcceleratorPos", 33usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxGetAcceleratorPos)(this, pcp), |result| Some(*result))
+        }
+        unsafe extern "system" fn TxGetExtent(this: *mut core::ffi::c_void, lpextent: *mut super::super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxGetExtent", 34usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxGetExtent)(this, lpextent), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn OnTxCharFormatChange(this: *mut core::ffi::c_void, pcf: *const CHARFORMATW) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextHost", "OnTxCharFormatChange", 35usize, |this| ((**(this as *const *const ITextHost_Vtbl)).OnTxCharFormatChange)(this, pcf), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnTxParaFormatChange(this: *mut core::ffi::c_void, ppf: *const PARAFORMAT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextHost", "OnTxParaFormatChange", 36usize, |this| ((**(this as *const *const ITextHost_Vtbl)).OnTxParaFormatChange)(this, ppf), |result| Some(*result))
+        }
+        unsafe extern "system" fn TxGetPropertyBits(this: *mut core::ffi::c_void, dwmask: u32, pdwbits: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxGetPropertyBits", 37usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxGetPropertyBits)(this, dwmask, pdwbits), |result| Some(*result))
+        }
+        unsafe extern "system" fn TxNotify(this: *mut core::ffi::c_void, inotify: u32, pv: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxNotify", 38usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxNotify)(this, inotify, pv), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_Ime")]
+        unsafe extern "system" fn TxImmGetContext(this: *mut core::ffi::c_void) -> super::super::Input::Ime::HIMC {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxImmGetContext", 39usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxImmGetContext)(this), |_| None)
+        }
+        #[cfg(feature = "Win32_UI_Input_Ime")]
+        unsafe extern "system" fn TxImmReleaseContext(this: *mut core::ffi::c_void, himc: super::super::Input::Ime::HIMC) {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxImmReleaseContext", 40usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxImmReleaseContext)(this, himc), |_| None)
+        }
+        unsafe extern "system" fn TxGetSelectionBarWidth(this: *mut core::ffi::c_void, lselbarwidth: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextHost", "TxGetSelectionBarWidth", 41usize, |this| ((**(this as *const *const ITextHost_Vtbl)).TxGetSelectionBarWidth)(this, lselbarwidth), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            TxGetDC,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            TxGetDC: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            TxReleaseDC,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            TxReleaseDC: 0,
+            TxShowScrollBar,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            TxEnableScrollBar,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            TxEnableScrollBar: 0,
+            TxSetScrollRange,
+            TxSetScrollPos,
+            TxInvalidateRect,
+            TxViewChange,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            TxCreateCaret,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            TxCreateCaret: 0,
+            TxShowCaret,
+            TxSetCaretPos,
+            TxSetTimer,
+            TxKillTimer,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+            TxScrollWindowEx,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging")))]
+            TxScrollWindowEx: 0,
+            TxSetCapture,
+            TxSetFocus,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            TxSetCursor,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            TxSetCursor: 0,
+            TxScreenToClient,
+            TxClientToScreen,
+            TxActivate,
+            TxDeactivate,
+            TxGetClientRect,
+            TxGetViewInset,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            TxGetCharFormat,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            TxGetCharFormat: 0,
+            TxGetParaFormat,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            TxGetSysColor,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            TxGetSysColor: 0,
+            TxGetBackStyle,
+            TxGetMaxLength,
+            TxGetScrollBars,
+            TxGetPasswordChar,
+            TxGetAcceleratorPos,
+            TxGetExtent,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            OnTxCharFormatChange,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            OnTxCharFormatChange: 0,
+            OnTxParaFormatChange,
+            TxGetPropertyBits,
+            TxNotify,
+            #[cfg(feature = "Win32_UI_Input_Ime")]
+            TxImmGetContext,
+            #[cfg(not(feature = "Win32_UI_Input_Ime"))]
+            TxImmGetContext: 0,
+            #[cfg(feature = "Win32_UI_Input_Ime")]
+            TxImmReleaseContext,
+            #[cfg(not(feature = "Win32_UI_Input_Ime"))]
+            TxImmReleaseContext: 0,
+            TxGetSelectionBarWidth,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextHost as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_Input_Ime", feature = "Win32_UI_WindowsAndMessaging"))]
+impl windows_core::imp::ProxyVtable for ITextHost2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TxIsDoubleClickPending(this: *mut core::ffi::c_void) -> super::super::super::Foundation::BOOL {
+            windows_core::imp::proxy_call(this, "ITextHost2", "TxIsDoubleClickPending", 42usize, |this| ((**(this as *const *const ITextHost2_Vtbl)).TxIsDoubleClickPending)(this), |_| None)
+        }
+        unsafe extern "system" fn TxGetWindow(this: *mut core::ffi::c_void, phwnd: *mut super::super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextHost2", "TxGetWindow", 43usize, |this| ((**(this as *const *const ITextHost2_Vtbl)).TxGetWindow)(this, phwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn TxSetForegroundWindow(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextHost2", "TxSetForegroundWindow", 44usize, |this| ((**(this as *const *const ITextHost2_Vtbl)).TxSetForegroundWindow)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn TxGetPalette(this: *mut core::ffi::c_void) -> super::super::super::Graphics::Gdi::HPALETTE {
+            windows_core::imp::proxy_call(this, "ITextHost2", "TxGetPalette", 45usize, |this| ((**(this as *const *const ITextHost2_Vtbl)).TxGetPalette)(this), |_| None)
+        }
+        unsafe extern "system" fn TxGetEastAsianFlags(this: *mut core::ffi::c_void, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextHost2", "TxGetEastAsianFlags", 46usize, |this| ((**(this as *const *const ITextHost2_Vtbl)).TxGetEastAsianFlags)(this, pflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn TxSetCursor2(this: *mut core::ffi::c_void, hcur: super::super::WindowsAndMessaging::HCURSOR, btext: super::super::super::Foundation::BOOL) -> super::super::WindowsAndMessaging::HCURSOR {
+            windows_core::imp::proxy_call(this, "ITextHost2", "TxSetCursor2", 47usize, |this| ((**(this as *const *const ITextHost2_Vtbl)).TxSetCursor2)(this, hcur, btext), |_| None)
+        }
+        unsafe extern "system" fn TxFreeTextServicesNotification(this: *mut core::ffi::c_void) {
+            windows_core::imp::proxy_call(this, "ITextHost2", "TxFreeTextServicesNotification", 48usize, |this| ((**(this as *const *const ITextHost2_Vtbl)).TxFreeTextServicesNotification)(this), |_| None)
+        }
+        unsafe extern "system" fn TxGetEditStyle(this: *mut core::ffi::c_void, dwitem: u32, pdwdata: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextHost2", "TxGetEditStyle", 49usize, |this| ((**(this as *const *const ITextHost2_Vtbl)).TxGetEditStyle)(this, dwitem, pdwdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn TxGetWindowStyles(this: *mut core::ffi::c_void, pdwstyle: *mut u32, pdwexstyle: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextHost2", "TxGetWindowStyles", 50usize, |this| ((**(this as *const *const ITextHost2_Vtbl)).TxGetWindowStyles)(this, pdwstyle, pdwexstyle), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn TxShowDropCaret(this: *mut core::ffi::c_void, fshow: super::super::super::Foundation::BOOL, hdc: super::super::super::Graphics::Gdi::HDC, prc: *mut super::super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextHost2", "TxShowDropCaret", 51usize, |this| ((**(this as *const *const ITextHost2_Vtbl)).TxShowDropCaret)(this, fshow, hdc, prc), |result| Some(*result))
+        }
+        unsafe extern "system" fn TxDestroyCaret(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextHost2", "TxDestroyCaret", 52usize, |this| ((**(this as *const *const ITextHost2_Vtbl)).TxDestroyCaret)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn TxGetHorzExtent(this: *mut core::ffi::c_void, plhorzextent: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextHost2", "TxGetHorzExtent", 53usize, |this| ((**(this as *const *const ITextHost2_Vtbl)).TxGetHorzExtent)(this, plhorzextent), |result| Some(*result))
+        }
+        Self {
+            base__: <ITextHost_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TxIsDoubleClickPending,
+            TxGetWindow,
+            TxSetForegroundWindow,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            TxGetPalette,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            TxGetPalette: 0,
+            TxGetEastAsianFlags,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            TxSetCursor2,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            TxSetCursor2: 0,
+            TxFreeTextServicesNotification,
+            TxGetEditStyle,
+            TxGetWindowStyles,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            TxShowDropCaret,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            TxShowDropCaret: 0,
+            TxDestroyCaret,
+            TxGetHorzExtent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextHost2 as windows_core::Interface>::IID || <ITextHost_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITextPara_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetDuplicate(this: *mut core::ffi::c_void, pppara: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "GetDuplicate", 7usize, |this| ((**(this as *const *const ITextPara_Vtbl)).GetDuplicate)(this, pppara), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetDuplicate(this: *mut core::ffi::c_void, ppara: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "SetDuplicate", 8usize, |this| ((**(this as *const *const ITextPara_Vtbl)).SetDuplicate)(this, ppara), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanChange(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "CanChange", 9usize, |this| ((**(this as *const *const ITextPara_Vtbl)).CanChange)(this, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn IsEqual(this: *mut core::ffi::c_void, ppara: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "IsEqual", 10usize, |this| ((**(this as *const *const ITextPara_Vtbl)).IsEqual)(this, ppara, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "Reset", 11usize, |this| ((**(this as *const *const ITextPara_Vtbl)).Reset)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStyle(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "GetStyle", 12usize, |this| ((**(this as *const *const ITextPara_Vtbl)).GetStyle)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStyle(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "SetStyle", 13usize, |this| ((**(this as *const *const ITextPara_Vtbl)).SetStyle)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAlignment(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "GetAlignment", 14usize, |this| ((**(this as *const *const ITextPara_Vtbl)).GetAlignment)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlignment(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "SetAlignment", 15usize, |this| ((**(this as *const *const ITextPara_Vtbl)).SetAlignment)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHyphenation(this: *mut core::ffi::c_void, pvalue: *mut tomConstants) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "GetHyphenation", 16usize, |this| ((**(this as *const *const ITextPara_Vtbl)).GetHyphenation)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHyphenation(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "SetHyphenation", 17usize, |this| ((**(this as *const *const ITextPara_Vtbl)).SetHyphenation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFirstLineIndent(this: *mut core::ffi::c_void, pvalue: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "GetFirstLineIndent", 18usize, |this| ((**(this as *const *const ITextPara_Vtbl)).GetFirstLineIndent)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKeepTogether(this: *mut core::ffi::c_void, pvalue: *mut tomConstants) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "GetKeepTogether", 19usize, |this| ((**(this as *const *const ITextPara_Vtbl)).GetKeepTogether)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeepTogether(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "SetKeepTogether", 20usize, |this| ((**(this as *const *const ITextPara_Vtbl)).SetKeepTogether)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKeepWithNext(this: *mut core::ffi::c_void, pvalue: *mut tomConstants) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "GetKeepWithNext", 21usize, |this| ((**(this as *const *const ITextPara_Vtbl)).GetKeepWithNext)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeepWithNext(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "SetKeepWithNext", 22usize, |this| ((**(this as *const *const ITextPara_Vtbl)).SetKeepWithNext)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLeftIndent(this: *mut core::ffi::c_void, pvalue: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "GetLeftIndent", 23usize, |this| ((**(this as *const *const ITextPara_Vtbl)).GetLeftIndent)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLineSpacing(this: *mut core::ffi::c_void, pvalue: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "GetLineSpacing", 24usize, |this| ((**(this as *const *const ITextPara_Vtbl)).GetLineSpacing)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLineSpacingRule(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "GetLineSpacingRule", 25usize, |this| ((**(this as *const *const ITextPara_Vtbl)).GetLineSpacingRule)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetListAlignment(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "GetListAlignment", 26usize, |this| ((**(this as *const *const ITextPara_Vtbl)).GetListAlignment)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetListAlignment(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "SetListAlignment", 27usize, |this| ((**(this as *const *const ITextPara_Vtbl)).SetListAlignment)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetListLevelIndex(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "GetListLevelIndex", 28usize, |this| ((**(this as *const *const ITextPara_Vtbl)).GetListLevelIndex)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetListLevelIndex(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "SetListLevelIndex", 29usize, |this| ((**(this as *const *const ITextPara_Vtbl)).SetListLevelIndex)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetListStart(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "GetListStart", 30usize, |this| ((**(this as *const *const ITextPara_Vtbl)).GetListStart)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetListStart(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "SetListStart", 31usize, |this| ((**(this as *const *const ITextPara_Vtbl)).SetListStart)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetListTab(this: *mut core::ffi::c_void, pvalue: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "GetListTab", 32usize, |this| ((**(this as *const *const ITextPara_Vtbl)).GetListTab)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetListTab(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "SetListTab", 33usize, |this| ((**(this as *const *const ITextPara_Vtbl)).SetListTab)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetListType(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "GetListType", 34usize, |this| ((**(this as *const *const ITextPara_Vtbl)).GetListType)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetListType(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "SetListType", 35usize, |this| ((**(this as *const *const ITextPara_Vtbl)).SetListType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNoLineNumber(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "GetNoLineNumber", 36usize, |this| ((**(this as *const *const ITextPara_Vtbl)).GetNoLineNumber)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNoLineNumber(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "SetNoLineNumber", 37usize, |this| ((**(this as *const *const ITextPara_Vtbl)).SetNoLineNumber)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPageBreakBefore(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "GetPageBreakBefore", 38usize, |this| ((**(this as *const *const ITextPara_Vtbl)).GetPageBreakBefore)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPageBreakBefore(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "SetPageBreakBefore", 39usize, |this| ((**(this as *const *const ITextPara_Vtbl)).SetPageBreakBefore)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRightIndent(this: *mut core::ffi::c_void, pvalue: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "GetRightIndent", 40usize, |this| ((**(this as *const *const ITextPara_Vtbl)).GetRightIndent)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRightIndent(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "SetRightIndent", 41usize, |this| ((**(this as *const *const ITextPara_Vtbl)).SetRightIndent)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIndents(this: *mut core::ffi::c_void, first: f32, left: f32, right: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "SetIndents", 42usize, |this| ((**(this as *const *const ITextPara_Vtbl)).SetIndents)(this, first, left, right), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLineSpacing(this: *mut core::ffi::c_void, rule: i32, spacing: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "SetLineSpacing", 43usize, |this| ((**(this as *const *const ITextPara_Vtbl)).SetLineSpacing)(this, rule, spacing), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSpaceAfter(this: *mut core::ffi::c_void, pvalue: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "GetSpaceAfter", 44usize, |this| ((**(this as *const *const ITextPara_Vtbl)).GetSpaceAfter)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSpaceAfter(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "SetSpaceAfter", 45usize, |this| ((**(this as *const *const ITextPara_Vtbl)).SetSpaceAfter)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSpaceBefore(this: *mut core::ffi::c_void, pvalue: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "GetSpaceBefore", 46usize, |this| ((**(this as *const *const ITextPara_Vtbl)).GetSpaceBefore)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSpaceBefore(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "SetSpaceBefore", 47usize, |this| ((**(this as *const *const ITextPara_Vtbl)).SetSpaceBefore)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWidowControl(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "GetWidowControl", 48usize, |this| ((**(this as *const *const ITextPara_Vtbl)).GetWidowControl)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWidowControl(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "SetWidowControl", 49usize, |this| ((**(this as *const *const ITextPara_Vtbl)).SetWidowControl)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTabCount(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "GetTabCount", 50usize, |this| ((**(this as *const *const ITextPara_Vtbl)).GetTabCount)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddTab(this: *mut core::ffi::c_void, tbpos: f32, tbalign: i32, tbleader: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "AddTab", 51usize, |this| ((**(this as *const *const ITextPara_Vtbl)).AddTab)(this, tbpos, tbalign, tbleader), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearAllTabs(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "ClearAllTabs", 52usize, |this| ((**(this as *const *const ITextPara_Vtbl)).ClearAllTabs)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteTab(this: *mut core::ffi::c_void, tbpos: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "DeleteTab", 53usize, |this| ((**(this as *const *const ITextPara_Vtbl)).DeleteTab)(this, tbpos), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTab(this: *mut core::ffi::c_void, itab: i32, ptbpos: *mut f32, ptbalign: *mut i32, ptbleader: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara", "GetTab", 54usize, |this| ((**(this as *const *const ITextPara_Vtbl)).GetTab)(this, itab, ptbpos, ptbalign, ptbleader), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetDuplicate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetDuplicate: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetDuplicate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetDuplicate: 0,
+            CanChange,
+            #[cfg(feature = "Win32_System_Com")]
+            IsEqual,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            IsEqual: 0,
+            Reset,
+            GetStyle,
+            SetStyle,
+            GetAlignment,
+            SetAlignment,
+            GetHyphenation,
+            SetHyphenation,
+            GetFirstLineIndent,
+            GetKeepTogether,
+            SetKeepTogether,
+            GetKeepWithNext,
+            SetKeepWithNext,
+            GetLeftIndent,
+            GetLineSpacing,
+            GetLineSpacingRule,
+            GetListAlignment,
+            SetListAlignment,
+            GetListLevelIndex,
+            SetListLevelIndex,
+            GetListStart,
+            SetListStart,
+            GetListTab,
+            SetListTab,
+            GetListType,
+            SetListType,
+            GetNoLineNumber,
+            SetNoLineNumber,
+            GetPageBreakBefore,
+            SetPageBreakBefore,
+            GetRightIndent,
+            SetRightIndent,
+            SetIndents,
+            SetLineSpacing,
+            GetSpaceAfter,
+            SetSpaceAfter,
+            GetSpaceBefore,
+            SetSpaceBefore,
+            GetWidowControl,
+            SetWidowControl,
+            GetTabCount,
+            AddTab,
+            ClearAllTabs,
+            DeleteTab,
+            GetTab,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextPara as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITextPara2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetBorders(this: *mut core::ffi::c_void, ppborders: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara2", "GetBorders", 55usize, |this| ((**(this as *const *const ITextPara2_Vtbl)).GetBorders)(this, ppborders), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetDuplicate2(this: *mut core::ffi::c_void, pppara: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara2", "GetDuplicate2", 56usize, |this| ((**(this as *const *const ITextPara2_Vtbl)).GetDuplicate2)(this, pppara), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetDuplicate2(this: *mut core::ffi::c_void, ppara: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara2", "SetDuplicate2", 57usize, |this| ((**(this as *const *const ITextPara2_Vtbl)).SetDuplicate2)(this, ppara), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFontAlignment(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara2", "GetFontAlignment", 58usize, |this| ((**(this as *const *const ITextPara2_Vtbl)).GetFontAlignment)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFontAlignment(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara2", "SetFontAlignment", 59usize, |this| ((**(this as *const *const ITextPara2_Vtbl)).SetFontAlignment)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHangingPunctuation(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara2", "GetHangingPunctuation", 60usize, |this| ((**(this as *const *const ITextPara2_Vtbl)).GetHangingPunctuation)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHangingPunctuation(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara2", "SetHangingPunctuation", 61usize, |this| ((**(this as *const *const ITextPara2_Vtbl)).SetHangingPunctuation)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSnapToGrid(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara2", "GetSnapToGrid", 62usize, |this| ((**(this as *const *const ITextPara2_Vtbl)).GetSnapToGrid)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSnapToGrid(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara2", "SetSnapToGrid", 63usize, |this| ((**(this as *const *const ITextPara2_Vtbl)).SetSnapToGrid)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTrimPunctuationAtStart(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara2", "GetTrimPunctuationAtStart", 64usize, |this| ((**(this as *const *const ITextPara2_Vtbl)).GetTrimPunctuationAtStart)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTrimPunctuationAtStart(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara2", "SetTrimPunctuationAtStart", 65usize, |this| ((**(this as *const *const ITextPara2_Vtbl)).SetTrimPunctuationAtStart)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEffects(this: *mut core::ffi::c_void, pvalue: *mut i32, pmask: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara2", "GetEffects", 66usize, |this| ((**(this as *const *const ITextPara2_Vtbl)).GetEffects)(this, pvalue, pmask), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, r#type: i32, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara2", "GetProperty", 67usize, |this| ((**(this as *const *const ITextPara2_Vtbl)).GetProperty)(this, r#type, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn IsEqual2(this: *mut core::ffi::c_void, ppara: *mut core::ffi::c_void, pb: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara2", "IsEqual2", 68usize, |this| ((**(this as *const *const ITextPara2_Vtbl)).IsEqual2)(this, ppara, pb), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEffects(this: *mut core::ffi::c_void, value: i32, mask: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara2", "SetEffects", 69usize, |this| ((**(this as *const *const ITextPara2_Vtbl)).SetEffects)(this, value, mask), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, r#type: i32, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextPara2", "SetProperty", 70usize, |this| ((**(this as *const *const ITextPara2_Vtbl)).SetProperty)(this, r#type, value), |result| Some(*result))
+        }
+        Self {
+            base__: <ITextPara_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetBorders,
+            #[cfg(feature = "Win32_System_Com")]
+            GetDuplicate2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetDuplicate2: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetDuplicate2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetDuplicate2: 0,
+            GetFontAlignment,
+            SetFontAlignment,
+            GetHangingPunctuation,
+            SetHangingPunctuation,
+            GetSnapToGrid,
+            SetSnapToGrid,
+            GetTrimPunctuationAtStart,
+            SetTrimPunctuationAtStart,
+            GetEffects,
+            GetProperty,
+            #[cfg(feature = "Win32_System_Com")]
+            IsEqual2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            IsEqual2: 0,
+            SetEffects,
+            SetProperty,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextPara2 as windows_core::Interface>::IID || <ITextPara_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITextRange_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetText(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "GetText", 7usize, |this| ((**(this as *const *const ITextRange_Vtbl)).GetText)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetText(this: *mut core::ffi::c_void, bstr: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "SetText", 8usize, |this| ((**(this as *const *const ITextRange_Vtbl)).SetText)(this, bstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChar(this: *mut core::ffi::c_void, pchar: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "GetChar", 9usize, |this| ((**(this as *const *const ITextRange_Vtbl)).GetChar)(this, pchar), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetChar(this: *mut core::ffi::c_void, char: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "SetChar", 10usize, |this| ((**(this as *const *const ITextRange_Vtbl)).SetChar)(this, char), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetDuplicate(this: *mut core::ffi::c_void, pprange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "GetDuplicate", 11usize, |this| ((**(this as *const *const ITextRange_Vtbl)).GetDuplicate)(this, pprange), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFormattedText(this: *mut core::ffi::c_void, pprange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "GetFormattedText", 12usize, |this| ((**(this as *const *const ITextRange_Vtbl)).GetFormattedText)(this, pprange), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetFormattedText(this: *mut core::ffi::c_void, prange: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "SetFormattedText", 13usize, |this| ((**(this as *const *const ITextRange_Vtbl)).SetFormattedText)(this, prange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStart(this: *mut core::ffi::c_void, pcpfirst: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "GetStart", 14usize, |this| ((**(this as *const *const ITextRange_Vtbl)).GetStart)(this, pcpfirst), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStart(this: *mut core::ffi::c_void, cpfirst: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "SetStart", 15usize, |this| ((**(this as *const *const ITextRange_Vtbl)).SetStart)(this, cpfirst), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnd(this: *mut core::ffi::c_void, pcplim: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "GetEnd", 16usize, |this| ((**(this as *const *const ITextRange_Vtbl)).GetEnd)(this, pcplim), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnd(this: *mut core::ffi::c_void, cplim: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "SetEnd", 17usize, |this| ((**(this as *const *const ITextRange_Vtbl)).SetEnd)(this, cplim), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFont(this: *mut core::ffi::c_void, ppfont: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "GetFont", 18usize, |this| ((**(this as *const *const ITextRange_Vtbl)).GetFont)(this, ppfont), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetFont(this: *mut core::ffi::c_void, pfont: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "SetFont", 19usize, |this| ((**(this as *const *const ITextRange_Vtbl)).SetFont)(this, pfont), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetPara(this: *mut core::ffi::c_void, pppara: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "GetPara", 20usize, |this| ((**(this as *const *const ITextRange_Vtbl)).GetPara)(this, pppara), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetPara(this: *mut core::ffi::c_void, ppara: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "SetPara", 21usize, |this| ((**(this as *const *const ITextRange_Vtbl)).SetPara)(this, ppara), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStoryLength(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "GetStoryLength", 22usize, |this| ((**(this as *const *const ITextRange_Vtbl)).GetStoryLength)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStoryType(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "GetStoryType", 23usize, |this| ((**(this as *const *const ITextRange_Vtbl)).GetStoryType)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Collapse(this: *mut core::ffi::c_void, bstart: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "Collapse", 24usize, |this| ((**(this as *const *const ITextRange_Vtbl)).Collapse)(this, bstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn Expand(this: *mut core::ffi::c_void, unit: i32, pdelta: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "Expand", 25usize, |this| ((**(this as *const *const ITextRange_Vtbl)).Expand)(this, unit, pdelta), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIndex(this: *mut core::ffi::c_void, unit: i32, pindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "GetIndex", 26usize, |this| ((**(this as *const *const ITextRange_Vtbl)).GetIndex)(this, unit, pindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIndex(this: *mut core::ffi::c_void, unit: i32, index: i32, extend: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "SetIndex", 27usize, |this| ((**(this as *const *const ITextRange_Vtbl)).SetIndex)(this, unit, index, extend), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRange(this: *mut core::ffi::c_void, cpanchor: i32, cpactive: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "SetRange", 28usize, |this| ((**(this as *const *const ITextRange_Vtbl)).SetRange)(this, cpanchor, cpactive), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InRange(this: *mut core::ffi::c_void, prange: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "InRange", 29usize, |this| ((**(this as *const *const ITextRange_Vtbl)).InRange)(this, prange, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InStory(this: *mut core::ffi::c_void, prange: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "InStory", 30usize, |this| ((**(this as *const *const ITextRange_Vtbl)).InStory)(this, prange, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn IsEqual(this: *mut core::ffi::c_void, prange: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "IsEqual", 31usize, |this| ((**(this as *const *const ITextRange_Vtbl)).IsEqual)(this, prange, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Select(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "Select", 32usize, |this| ((**(this as *const *const ITextRange_Vtbl)).Select)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn StartOf(this: *mut core::ffi::c_void, unit: i32, extend: i32, pdelta: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "StartOf", 33usize, |this| ((**(this as *const *const ITextRange_Vtbl)).StartOf)(this, unit, extend, pdelta), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndOf(this: *mut core::ffi::c_void, unit: i32, extend: i32, pdelta: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "EndOf", 34usize, |this| ((**(this as *const *const ITextRange_Vtbl)).EndOf)(this, unit, extend, pdelta), |result| Some(*result))
+        }
+        unsafe extern "system" fn Move(this: *mut core::ffi::c_void, unit: i32, count: i32, pdelta: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "Move", 35usize, |this| ((**(this as *const *const ITextRange_Vtbl)).Move)(this, unit, count, pdelta), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveStart(this: *mut core::ffi::c_void, unit: i32, count: i32, pdelta: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "MoveStart", 36usize, |this| ((**(this as *const *const ITextRange_Vtbl)).MoveStart)(this, unit, count, pdelta), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveEnd(this: *mut core::ffi::c_void, unit: i32, count: i32, pdelta: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "MoveEnd", 37usize, |this| ((**(this as *const *const ITextRange_Vtbl)).MoveEnd)(this, unit, count, pdelta), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveWhile(this: *mut core::ffi::c_void, cset: *const core::mem::MaybeUninit<windows_core::VARIANT>, count: i32, pdelta: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "MoveWhile", 38usize, |this| ((**(this as *const *const ITextRange_Vtbl)).MoveWhile)(this, cset, count, pdelta), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveStartWhile(this: *mut core::ffi::c_void, cset: *const core::mem::MaybeUninit<windows_core::VARIANT>, count: i32, pdelta: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "MoveStartWhile", 39usize, |this| ((**(this as *const *const ITextRange_Vtbl)).MoveStartWhile)(this, cset, count, pdelta), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveEndWhile(this: *mut core::ffi::c_void, cset: *const core::mem::MaybeUninit<windows_core::VARIANT>, count: i32, pdelta: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "MoveEndWhile", 40usize, |this| ((**(this as *const *const ITextRange_Vtbl)).MoveEndWhile)(this, cset, count, pdelta), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveUntil(this: *mut core::ffi::c_void, cset: *const core::mem::MaybeUninit<windows_core::VARIANT>, count: i32, pdelta: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "MoveUntil", 41usize, |this| ((**(this as *const *const ITextRange_Vtbl)).MoveUntil)(this, cset, count, pdelta), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveStartUntil(this: *mut core::ffi::c_void, cset: *const core::mem::MaybeUninit<windows_core::VARIANT>, count: i32, pdelta: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "MoveStartUntil", 42usize, |this| ((**(this as *const *const ITextRange_Vtbl)).MoveStartUntil)(this, cset, count, pdelta), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveEndUntil(this: *mut core::ffi::c_void, cset: *const core::mem::MaybeUninit<windows_core::VARIANT>, count: i32, pdelta: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "MoveEndUntil", 43usize, |this| ((**(this as *const *const ITextRange_Vtbl)).MoveEndUntil)(this, cset, count, pdelta), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindText(this: *mut core::ffi::c_void, bstr: core::mem::MaybeUninit<windows_core::BSTR>, count: i32, flags: tomConstants, plength: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "FindText", 44usize, |this| ((**(this as *const *const ITextRange_Vtbl)).FindText)(this, bstr, count, flags, plength), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindTextStart(this: *mut core::ffi::c_void, bstr: core::mem::MaybeUninit<windows_core::BSTR>, count: i32, flags: tomConstants, plength: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "FindTextStart", 45usize, |this| ((**(this as *const *const ITextRange_Vtbl)).FindTextStart)(this, bstr, count, flags, plength), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindTextEnd(this: *mut core::ffi::c_void, bstr: core::mem::MaybeUninit<windows_core::BSTR>, count: i32, flags: tomConstants, plength: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "FindTextEnd", 46usize, |this| ((**(this as *const *const ITextRange_Vtbl)).FindTextEnd)(this, bstr, count, flags, plength), |result| Some(*result))
+        }
+        unsafe extern "system" fn Delete(this: *mut core::ffi::c_void, unit: i32, count: i32, pdelta: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "Delete", 47usize, |this| ((**(this as *const *const ITextRange_Vtbl)).Delete)(this, unit, count, pdelta), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cut(this: *mut core::ffi::c_void, pvar: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "Cut", 48usize, |this| ((**(this as *const *const ITextRange_Vtbl)).Cut)(this, pvar), |result| Some(*result))
+        }
+        unsafe extern "system" fn Copy(this: *mut core::ffi::c_void, pvar: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "Copy", 49usize, |this| ((**(this as *const *const ITextRange_Vtbl)).Copy)(this, pvar), |result| Some(*result))
+        }
+        unsafe extern "system" fn Paste(this: *mut core::ffi::c_void, pvar: *const core::mem::MaybeUninit<windows_core::VARIANT>, format: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "Paste", 50usize, |this| ((**(this as *const *const ITextRange_Vtbl)).Paste)(this, pvar, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanPaste(this: *mut core::ffi::c_void, pvar: *const core::mem::MaybeUninit<windows_core::VARIANT>, format: i32, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "CanPaste", 51usize, |this| ((**(this as *const *const ITextRange_Vtbl)).CanPaste)(this, pvar, format, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanEdit(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "CanEdit", 52usize, |this| ((**(this as *const *const ITextRange_Vtbl)).CanEdit)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeCase(this: *mut core::ffi::c_void, r#type: tomConstants) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "ChangeCase", 53usize, |this| ((**(this as *const *const ITextRange_Vtbl)).ChangeCase)(this, r#type), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPoint(this: *mut core::ffi::c_void, r#type: tomConstants, px: *mut i32, py: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "GetPoint", 54usize, |this| ((**(this as *const *const ITextRange_Vtbl)).GetPoint)(this, r#type, px, py), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPoint(this: *mut core::ffi::c_void, x: i32, y: i32, r#type: tomConstants, extend: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "SetPoint", 55usize, |this| ((**(this as *const *const ITextRange_Vtbl)).SetPoint)(this, x, y, r#type, extend), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScrollIntoView(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "ScrollIntoView", 56usize, |this| ((**(this as *const *const ITextRange_Vtbl)).ScrollIntoView)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEmbeddedObject(this: *mut core::ffi::c_void, ppobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange", "GetEmbeddedObject", 57usize, |this| ((**(this as *const *const ITextRange_Vtbl)).GetEmbeddedObject)(this, ppobject), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetText,
+            SetText,
+            GetChar,
+            SetChar,
+            #[cfg(feature = "Win32_System_Com")]
+            GetDuplicate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetDuplicate: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFormattedText,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFormattedText: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetFormattedText,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetFormattedText: 0,
+            GetStart,
+            SetStart,
+            GetEnd,
+            SetEnd,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFont,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFont: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetFont,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetFont: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetPara,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetPara: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetPara,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetPara: 0,
+            GetStoryLength,
+            GetStoryType,
+            Collapse,
+            Expand,
+            GetIndex,
+            SetIndex,
+            SetRange,
+            #[cfg(feature = "Win32_System_Com")]
+            InRange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InRange: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            InStory,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InStory: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            IsEqual,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            IsEqual: 0,
+            Select,
+            StartOf,
+            EndOf,
+            Move,
+            MoveStart,
+            MoveEnd,
+            MoveWhile,
+            MoveStartWhile,
+            MoveEndWhile,
+            MoveUntil,
+            MoveStartUntil,
+            MoveEndUntil,
+            FindText,
+            FindTextStart,
+            FindTextEnd,
+            Delete,
+            Cut,
+            Copy,
+            Paste,
+            CanPaste,
+            CanEdit,
+            ChangeCase,
+            GetPoint,
+            SetPoint,
+            ScrollIntoView,
+            GetEmbeddedObject,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextRange as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITextRange2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCch(this: *mut core::ffi::c_void, pcch: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "GetCch", 68usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).GetCch)(this, pcch), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCells(this: *mut core::ffi::c_void, ppcells: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "GetCells", 69usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).GetCells)(this, ppcells), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColumn(this: *mut core::ffi::c_void, ppcolumn: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "GetColumn", 70usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).GetColumn)(this, ppcolumn), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "GetCount", 71usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).GetCount)(this, pcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetDuplicate2(this: *mut core::ffi::c_void, pprange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "GetDuplicate2", 72usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).GetDuplicate2)(this, pprange), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFont2(this: *mut core::ffi::c_void, ppfont: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "GetFont2", 73usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).GetFont2)(this, ppfont), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetFont2(this: *mut core::ffi::c_void, pfont: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "SetFont2", 74usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).SetFont2)(this, pfont), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFormattedText2(this: *mut core::ffi::c_void, pprange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "GetFormattedText2", 75usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).GetFormattedText2)(this, pprange), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetFormattedText2(this: *mut core::ffi::c_void, prange: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "SetFormattedText2", 76usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).SetFormattedText2)(this, prange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGravity(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "GetGravity", 77usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).GetGravity)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGravity(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "SetGravity", 78usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).SetGravity)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetPara2(this: *mut core::ffi::c_void, pppara: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "GetPara2", 79usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).GetPara2)(this, pppara), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetPara2(this: *mut core::ffi::c_void, ppara: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "SetPara2", 80usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).SetPara2)(this, ppara), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetRow(this: *mut core::ffi::c_void, pprow: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "GetRow", 81usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).GetRow)(this, pprow), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStartPara(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "GetStartPara", 82usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).GetStartPara)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTable(this: *mut core::ffi::c_void, pptable: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "GetTable", 83usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).GetTable)(this, pptable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetURL(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "GetURL", 84usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).GetURL)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetURL(this: *mut core::ffi::c_void, bstr: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "SetURL", 85usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).SetURL)(this, bstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddSubrange(this: *mut core::ffi::c_void, cp1: i32, cp2: i32, activate: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "AddSubrange", 86usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).AddSubrange)(this, cp1, cp2, activate), |result| Some(*result))
+        }
+        unsafe extern "system" fn BuildUpMath(this: *mut core::ffi::c_void, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "BuildUpMath", 87usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).BuildUpMath)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteSubrange(this: *mut core::ffi::c_void, cpfirst: i32, cplim: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "DeleteSubrange", 88usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).DeleteSubrange)(this, cpfirst, cplim), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Find(this: *mut core::ffi::c_void, prange: *mut core::ffi::c_void, count: i32, flags: i32, pdelta: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "Find", 89usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).Find)(this, prange, count, flags, pdelta), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChar2(this: *mut core::ffi::c_void, pchar: *mut i32, offset: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "GetChar2", 90usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).GetChar2)(this, pchar, offset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDropCap(this: *mut core::ffi::c_void, pcline: *mut i32, pposition: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "GetDropCap", 91usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).GetDropCap)(this, pcline, pposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInlineObject(this: *mut core::ffi::c_void, ptype: *mut i32, palign: *mut i32, pchar: *mut i32, pchar1: *mut i32, pchar2: *mut i32, pcount: *mut i32, ptexstyle: *mut i32, pccol: *mut i32, plevel: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "GetInlineObject", 92usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).GetInlineObject)(this, ptype, palign, pchar, pchar1, pchar2, pcount, ptexstyle, pccol, plevel), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, r#type: i32, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "GetProperty", 93usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).GetProperty)(this, r#type, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRect(this: *mut core::ffi::c_void, r#type: i32, pleft: *mut i32, ptop: *mut i32, pright: *mut i32, pbottom: *mut i32, phit: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "GetRect", 94usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).GetRect)(this, r#type, pleft, ptop, pright, pbottom, phit), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSubrange(this: *mut core::ffi::c_void, isubrange: i32, pcpfirst: *mut i32, pcplim: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "GetSubrange", 95usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).GetSubrange)(this, isubrange, pcpfirst, pcplim), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetText2(this: *mut core::ffi::c_void, flags: i32, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "GetText2", 96usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).GetText2)(this, flags, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn HexToUnicode(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "HexToUnicode", 97usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).HexToUnicode)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertTable(this: *mut core::ffi::c_void, ccol: i32, crow: i32, autofit: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "InsertTable", 98usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).InsertTable)(this, ccol, crow, autofit), |result| Some(*result))
+        }
+        unsafe extern "system" fn Linearize(this: *mut core::ffi::c_void, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "Linearize", 99usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).Linearize)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActiveSubrange(this: *mut core::ffi::c_void, cpanchor: i32, cpactive: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "SetActiveSubrange", 100usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).SetActiveSubrange)(this, cpanchor, cpactive), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDropCap(this: *mut core::ffi::c_void, cline: i32, position: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "SetDropCap", 101usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).SetDropCap)(this, cline, position), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, r#type: i32, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "SetProperty", 102usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).SetProperty)(this, r#type, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetText2(this: *mut core::ffi::c_void, flags: i32, bstr: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "SetText2", 103usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).SetText2)(this, flags, bstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnicodeToHex(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "UnicodeToHex", 104usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).UnicodeToHex)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInlineObject(this: *mut core::ffi::c_void, r#type: i32, align: i32, char: i32, char1: i32, char2: i32, count: i32, texstyle: i32, ccol: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "SetInlineObject", 105usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).SetInlineObject)(this, r#type, align, char, char1, char2, count, texstyle, ccol), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMathFunctionType(this: *mut core::ffi::c_void, bstr: core::mem::MaybeUninit<windows_core::BSTR>, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "GetMathFunctionType", 106usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).GetMathFunctionType)(this, bstr, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InsertImage(this: *mut core::ffi::c_void, width: i32, height: i32, ascent: i32, r#type: i32, bstralttext: core::mem::MaybeUninit<windows_core::BSTR>, pstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRange2", "InsertImage", 107usize, |this| ((**(this as *const *const ITextRange2_Vtbl)).InsertImage)(this, width, height, ascent, r#type, bstralttext, pstream), |result| Some(*result))
+        }
+        Self {
+            base__: <ITextSelection_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCch,
+            GetCells,
+            GetColumn,
+            GetCount,
+            #[cfg(feature = "Win32_System_Com")]
+            GetDuplicate2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetDuplicate2: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFont2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFont2: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetFont2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetFont2: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFormattedText2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFormattedText2: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetFormattedText2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetFormattedText2: 0,
+            GetGravity,
+            SetGravity,
+            #[cfg(feature = "Win32_System_Com")]
+            GetPara2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetPara2: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetPara2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetPara2: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetRow,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetRow: 0,
+            GetStartPara,
+            GetTable,
+            GetURL,
+            SetURL,
+            AddSubrange,
+            BuildUpMath,
+            DeleteSubrange,
+            #[cfg(feature = "Win32_System_Com")]
+            Find,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Find: 0,
+            GetChar2,
+            GetDropCap,
+            GetInlineObject,
+            GetProperty,
+            GetRect,
+            GetSubrange,
+            GetText2,
+            HexToUnicode,
+            InsertTable,
+            Linearize,
+            SetActiveSubrange,
+            SetDropCap,
+            SetProperty,
+            SetText2,
+            UnicodeToHex,
+            SetInlineObject,
+            GetMathFunctionType,
+            #[cfg(feature = "Win32_System_Com")]
+            InsertImage,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InsertImage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextRange2 as windows_core::Interface>::IID || <ITextSelection_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITextRow_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAlignment(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "GetAlignment", 7usize, |this| ((**(this as *const *const ITextRow_Vtbl)).GetAlignment)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAlignment(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "SetAlignment", 8usize, |this| ((**(this as *const *const ITextRow_Vtbl)).SetAlignment)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCellCount(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "GetCellCount", 9usize, |this| ((**(this as *const *const ITextRow_Vtbl)).GetCellCount)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCellCount(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "SetCellCount", 10usize, |this| ((**(this as *const *const ITextRow_Vtbl)).SetCellCount)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCellCountCache(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "GetCellCountCache", 11usize, |this| ((**(this as *const *const ITextRow_Vtbl)).GetCellCountCache)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCellCountCache(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "SetCellCountCache", 12usize, |this| ((**(this as *const *const ITextRow_Vtbl)).SetCellCountCache)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCellIndex(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "GetCellIndex", 13usize, |this| ((**(this as *const *const ITextRow_Vtbl)).GetCellIndex)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCellIndex(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "SetCellIndex", 14usize, |this| ((**(this as *const *const ITextRow_Vtbl)).SetCellIndex)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCellMargin(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "GetCellMargin", 15usize, |this| ((**(this as *const *const ITextRow_Vtbl)).GetCellMargin)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCellMargin(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "SetCellMargin", 16usize, |this| ((**(this as *const *const ITextRow_Vtbl)).SetCellMargin)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHeight(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "GetHeight", 17usize, |this| ((**(this as *const *const ITextRow_Vtbl)).GetHeight)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHeight(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "SetHeight", 18usize, |this| ((**(this as *const *const ITextRow_Vtbl)).SetHeight)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIndent(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "GetIndent", 19usize, |this| ((**(this as *const *const ITextRow_Vtbl)).GetIndent)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIndent(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "SetIndent", 20usize, |this| ((**(this as *const *const ITextRow_Vtbl)).SetIndent)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKeepTogether(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "GetKeepTogether", 21usize, |this| ((**(this as *const *const ITextRow_Vtbl)).GetKeepTogether)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeepTogether(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "SetKeepTogether", 22usize, |this| ((**(this as *const *const ITextRow_Vtbl)).SetKeepTogether)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetKeepWithNext(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "GetKeepWithNext", 23usize, |this| ((**(this as *const *const ITextRow_Vtbl)).GetKeepWithNext)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetKeepWithNext(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "SetKeepWithNext", 24usize, |this| ((**(this as *const *const ITextRow_Vtbl)).SetKeepWithNext)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNestLevel(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "GetNestLevel", 25usize, |this| ((**(this as *const *const ITextRow_Vtbl)).GetNestLevel)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRTL(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "GetRTL", 26usize, |this| ((**(this as *const *const ITextRow_Vtbl)).GetRTL)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRTL(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "SetRTL", 27usize, |this| ((**(this as *const *const ITextRow_Vtbl)).SetRTL)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCellAlignment(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "GetCellAlignment", 28usize, |this| ((**(this as *const *const ITextRow_Vtbl)).GetCellAlignment)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCellAlignment(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "SetCellAlignment", 29usize, |this| ((**(this as *const *const ITextRow_Vtbl)).SetCellAlignment)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCellColorBack(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "GetCellColorBack", 30usize, |this| ((**(this as *const *const ITextRow_Vtbl)).GetCellColorBack)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCellColorBack(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "SetCellColorBack", 31usize, |this| ((**(this as *const *const ITextRow_Vtbl)).SetCellColorBack)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCellColorFore(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "GetCellColorFore", 32usize, |this| ((**(this as *const *const ITextRow_Vtbl)).GetCellColorFore)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCellColorFore(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "SetCellColorFore", 33usize, |this| ((**(this as *const *const ITextRow_Vtbl)).SetCellColorFore)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCellMergeFlags(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "GetCellMergeFlags", 34usize, |this| ((**(this as *const *const ITextRow_Vtbl)).GetCellMergeFlags)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCellMergeFlags(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "SetCellMergeFlags", 35usize, |this| ((**(this as *const *const ITextRow_Vtbl)).SetCellMergeFlags)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCellShading(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "GetCellShading", 36usize, |this| ((**(this as *const *const ITextRow_Vtbl)).GetCellShading)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCellShading(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "SetCellShading", 37usize, |this| ((**(this as *const *const ITextRow_Vtbl)).SetCellShading)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCellVerticalText(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "GetCellVerticalText", 38usize, |this| ((**(this as *const *const ITextRow_Vtbl)).GetCellVerticalText)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCellVerticalText(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "SetCellVerticalText", 39usize, |this| ((**(this as *const *const ITextRow_Vtbl)).SetCellVerticalText)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCellWidth(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "GetCellWidth", 40usize, |this| ((**(this as *const *const ITextRow_Vtbl)).GetCellWidth)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCellWidth(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "SetCellWidth", 41usize, |this| ((**(this as *const *const ITextRow_Vtbl)).SetCellWidth)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCellBorderColors(this: *mut core::ffi::c_void, pcrleft: *mut i32, pcrtop: *mut i32, pcrright: *mut i32, pcrbottom: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "GetCellBorderColors", 42usize, |this| ((**(this as *const *const ITextRow_Vtbl)).GetCellBorderColors)(this, pcrleft, pcrtop, pcrright, pcrbottom), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCellBorderWidths(this: *mut core::ffi::c_void, pduleft: *mut i32, pdutop: *mut i32, pduright: *mut i32, pdubottom: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "GetCellBorderWidths", 43usize, |this| ((**(this as *const *const ITextRow_Vtbl)).GetCellBorderWidths)(this, pduleft, pdutop, pduright, pdubottom), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCellBorderColors(this: *mut core::ffi::c_void, crleft: i32, crtop: i32, crright: i32, crbottom: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "SetCellBorderColors", 44usize, |this| ((**(this as *const *const ITextRow_Vtbl)).SetCellBorderColors)(this, crleft, crtop, crright, crbottom), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCellBorderWidths(this: *mut core::ffi::c_void, duleft: i32, dutop: i32, duright: i32, dubottom: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "SetCellBorderWidths", 45usize, |this| ((**(this as *const *const ITextRow_Vtbl)).SetCellBorderWidths)(this, duleft, dutop, duright, dubottom), |result| Some(*result))
+        }
+        unsafe extern "system" fn Apply(this: *mut core::ffi::c_void, crow: i32, flags: tomConstants) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "Apply", 46usize, |this| ((**(this as *const *const ITextRow_Vtbl)).Apply)(this, crow, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanChange(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "CanChange", 47usize, |this| ((**(this as *const *const ITextRow_Vtbl)).CanChange)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, r#type: i32, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "GetProperty", 48usize, |this| ((**(this as *const *const ITextRow_Vtbl)).GetProperty)(this, r#type, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Insert(this: *mut core::ffi::c_void, crow: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "Insert", 49usize, |this| ((**(this as *const *const ITextRow_Vtbl)).Insert)(this, crow), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn IsEqual(this: *mut core::ffi::c_void, prow: *mut core::ffi::c_void, pb: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "IsEqual", 50usize, |this| ((**(this as *const *const ITextRow_Vtbl)).IsEqual)(this, prow, pb), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "Reset", 51usize, |this| ((**(this as *const *const ITextRow_Vtbl)).Reset)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, r#type: i32, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextRow", "SetProperty", 52usize, |this| ((**(this as *const *const ITextRow_Vtbl)).SetProperty)(this, r#type, value), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetAlignment,
+            SetAlignment,
+            GetCellCount,
+            SetCellCount,
+            GetCellCountCache,
+            SetCellCountCache,
+            GetCellIndex,
+            SetCellIndex,
+            GetCellMargin,
+            SetCellMargin,
+            GetHeight,
+            SetHeight,
+            GetIndent,
+            SetIndent,
+            GetKeepTogether,
+            SetKeepTogether,
+            GetKeepWithNext,
+            SetKeepWithNext,
+            GetNestLevel,
+            GetRTL,
+            SetRTL,
+            GetCellAlignment,
+            SetCellAlignment,
+            GetCellColorBack,
+            SetCellColorBack,
+            GetCellColorFore,
+            SetCellColorFore,
+            GetCellMergeFlags,
+            SetCellMergeFlags,
+            GetCellShading,
+            SetCellShading,
+            GetCellVerticalText,
+            SetCellVerticalText,
+            GetCellWidth,
+            SetCellWidth,
+            GetCellBorderColors,
+            GetCellBorderWidths,
+            SetCellBorderColors,
+            SetCellBorderWidths,
+            Apply,
+            CanChange,
+            GetProperty,
+            Insert,
+            #[cfg(feature = "Win32_System_Com")]
+            IsEqual,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            IsEqual: 0,
+            Reset,
+            SetProperty,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextRow as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITextSelection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFlags(this: *mut core::ffi::c_void, pflags: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextSelection", "GetFlags", 58usize, |this| ((**(this as *const *const ITextSelection_Vtbl)).GetFlags)(this, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFlags(this: *mut core::ffi::c_void, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextSelection", "SetFlags", 59usize, |this| ((**(this as *const *const ITextSelection_Vtbl)).SetFlags)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, ptype: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextSelection", "GetType", 60usize, |this| ((**(this as *const *const ITextSelection_Vtbl)).GetType)(this, ptype), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveLeft(this: *mut core::ffi::c_void, unit: i32, count: i32, extend: i32, pdelta: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextSelection", "MoveLeft", 61usize, |this| ((**(this as *const *const ITextSelection_Vtbl)).MoveLeft)(this, unit, count, extend, pdelta), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveRight(this: *mut core::ffi::c_void, unit: i32, count: i32, extend: i32, pdelta: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextSelection", "MoveRight", 62usize, |this| ((**(this as *const *const ITextSelection_Vtbl)).MoveRight)(this, unit, count, extend, pdelta), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveUp(this: *mut core::ffi::c_void, unit: i32, count: i32, extend: i32, pdelta: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextSelection", "MoveUp", 63usize, |this| ((**(this as *const *const ITextSelection_Vtbl)).MoveUp)(this, unit, count, extend, pdelta), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveDown(this: *mut core::ffi::c_void, unit: i32, count: i32, extend: i32, pdelta: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextSelection", "MoveDown", 64usize, |this| ((**(this as *const *const ITextSelection_Vtbl)).MoveDown)(this, unit, count, extend, pdelta), |result| Some(*result))
+        }
+        unsafe extern "system" fn HomeKey(this: *mut core::ffi::c_void, unit: tomConstants, extend: i32, pdelta: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextSelection", "HomeKey", 65usize, |this| ((**(this as *const *const ITextSelection_Vtbl)).HomeKey)(this, unit, extend, pdelta), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndKey(this: *mut core::ffi::c_void, unit: i32, extend: i32, pdelta: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextSelection", "EndKey", 66usize, |this| ((**(this as *const *const ITextSelection_Vtbl)).EndKey)(this, unit, extend, pdelta), |result| Some(*result))
+        }
+        unsafe extern "system" fn TypeText(this: *mut core::ffi::c_void, bstr: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextSelection", "TypeText", 67usize, |this| ((**(this as *const *const ITextSelection_Vtbl)).TypeText)(this, bstr), |result| Some(*result))
+        }
+        Self {
+            base__: <ITextRange_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetFlags,
+            SetFlags,
+            GetType,
+            MoveLeft,
+            MoveRight,
+            MoveUp,
+            MoveDown,
+            HomeKey,
+            EndKey,
+            TypeText,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextSelection as windows_core::Interface>::IID || <ITextRange_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITextSelection2_Vtbl {
+    const PROXY: Self = { Self { base__: <ITextRange2_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextSelection2 as windows_core::Interface>::IID || <ITextRange2_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for ITextServices_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TxSendMessage(this: *mut core::ffi::c_void, msg: u32, wparam: super::super::super::Foundation::WPARAM, lparam: super::super::super::Foundation::LPARAM, plresult: *mut super::super::super::Foundation::LRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextServices", "TxSendMessage", 3usize, |this| ((**(this as *const *const ITextServices_Vtbl)).TxSendMessage)(this, msg, wparam, lparam, plresult), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn TxDraw(this: *mut core::ffi::c_void, dwdrawaspect: super::super::super::System::Com::DVASPECT, lindex: i32, pvaspect: *mut core::ffi::c_void, ptd: *mut super::super::super::System::Com::DVTARGETDEVICE, hdcdraw: super::super::super::Graphics::Gdi::HDC, hictargetdev: super::super::super::Graphics::Gdi::HDC, lprcbounds: *mut super::super::super::Foundation::RECTL, lprcwbounds: *mut super::super::super::Foundation::RECTL, lprcupdate: *mut super::super::super::Foundation::RECT, pfncontinue: isize, dwcontinue: u32, lviewid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextServices", "TxDraw", 4usize, |this| ((**(this as *const *const ITextServices_Vtbl)).TxDraw)(this, dwdrawaspect, lindex, pvaspect, ptd, hdcdraw, hictargetdev, lprcbounds, lprcwbounds, lprcupdate, pfncontinue, dwcontinue, lviewid), |result| Some(*result))
+        }
+        unsafe extern "system" fn TxGetHScroll(this: *mut core::ffi::c_void, plmin: *mut i32, plmax: *mut i32, plpos: *mut i32, plpage: *mut i32, pfenabled: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextServices", "TxGetHScroll", 5usize, |this| ((**(this as *const *const ITextServices_Vtbl)).TxGetHScroll)(this, plmin, plmax, plpos, plpage, pfenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn TxGetVScroll(this: *mut core::ffi::c_void, plmin: *mut i32, plmax: *mut i32, plpos: *mut i32, plpage: *mut i32, pfenabled: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextServices", "TxGetVScroll", 6usize, |this| ((**(this as *const *const ITextServices_Vtbl)).TxGetVScroll)(this, plmin, plmax, plpos, plpage, pfenabled), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn OnTxSetCursor(this: *mut core::ffi::c_void, dwdrawaspect: super::super::super::System::Com::DVASPECT, lindex: i32, pvaspect: *mut core::ffi::c_void, ptd: *mut super::super::super::System::Com::DVTARGETDEVICE, hdcdraw: super::super::super::Graphics::Gdi::HDC, hictargetdev: super::super::super::Graphics::Gdi::HDC, lprcclient: *mut super::super::super::Foundation::RECT, x: i32, y: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextServices", "OnTxSetCursor", 7usize, |this| ((**(this as *const *const ITextServices_Vtbl)).OnTxSetCursor)(this, dwdrawaspect, lindex, pvaspect, ptd, hdcdraw, hictargetdev, lprcclient, x, y), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn TxQueryHitPoint(this: *mut core::ffi::c_void, dwdrawaspect: super::super::super::System::Com::DVASPECT, lindex: i32, pvaspect: *mut core::ffi::c_void, ptd: *mut super::super::super::System::Com::DVTARGETDEVICE, hdcdraw: super::super::super::Graphics::Gdi::HDC, hictargetdev: super::super::super::Graphics::Gdi::HDC, lprcclient: *mut super::super::super::Foundation::RECT, x: i32, y: i32, phitresult: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextServices", "TxQueryHitPoint", 8usize, |this| ((**(this as *const *const ITextServices_Vtbl)).TxQueryHitPoint)(this, dwdrawaspect, lindex, pvaspect, ptd, hdcdraw, hictargetdev, lprcclient, x, y, phitresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnTxInPlaceActivate(this: *mut core::ffi::c_void, prcclient: *mut super::super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextServices", "OnTxInPlaceActivate", 9usize, |this| ((**(this as *const *const ITextServices_Vtbl)).OnTxInPlaceActivate)(this, prcclient), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnTxInPlaceDeactivate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextServices", "OnTxInPlaceDeactivate", 10usize, |this| ((**(this as *const *const ITextServices_Vtbl)).OnTxInPlaceDeactivate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnTxUIActivate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextServices", "OnTxUIActivate", 11usize, |this| ((**(this as *const *const ITextServices_Vtbl)).OnTxUIActivate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnTxUIDeactivate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextServices", "OnTxUIDeactivate", 12usize, |this| ((**(this as *const *const ITextServices_Vtbl)).OnTxUIDeactivate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn TxGetText(this: *mut core::ffi::c_void, pbstrtext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextServices", "TxGetText", 13usize, |this| ((**(this as *const *const ITextServices_Vtbl)).TxGetText)(this, pbstrtext), |result| Some(*result))
+        }
+        unsafe extern "system" fn TxSetText(this: *mut core::ffi::c_void, psztext: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextServices", "TxSetText", 14usize, |this| ((**(this as *const *const ITextServices_Vtbl)).TxSetText)(this, psztext), |result| Some(*result))
+        }
+        unsafe extern "system" fn TxGetCurTargetX(this: *mut core::ffi::c_void, param0: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextServices", "TxGetCurTargetX", 15usize, |this| ((**(this as *const *const ITextServices_Vtbl)).TxGetCurTargetX)(this, param0), |result| Some(*result))
+        }
+        unsafe extern "system" fn TxGetBaseLinePos(this: *mut core::ffi::c_void, param0: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextServices", "TxGetBaseLinePos", 16usize, |this| ((**(this as *const *const ITextServices_Vtbl)).TxGetBaseLinePos)(this, param0), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn TxGetNaturalSize(this: *mut core::ffi::c_void, dwaspect: u32, hdcdraw: super::super::super::Graphics::Gdi::HDC, hictargetdev: super::super::super::Graphics::Gdi::HDC, ptd: *mut super::super::super::System::Com::DVTARGETDEVICE, dwmode: u32, psizelextent: *const super::super::super::Foundation::SIZE, pwidth: *mut i32, pheight: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextServices", "TxGetNaturalSize", 17usize, |this| ((**(this as *const *const ITextServices_Vtbl)).TxGetNaturalSize)(this, dwaspect, hdcdraw, hictargetdev, ptd, dwmode, psizelextent, pwidth, pheight), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn TxGetDropTarget(this: *mut core::ffi::c_void, ppdroptarget: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextServices", "TxGetDropTarget", 18usize, |this| ((**(this as *const *const ITextServices_Vtbl)).TxGetDropTarget)(this, ppdroptarget), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnTxPropertyBitsChange(this: *mut core::ffi::c_void, dwmask: u32, dwbits: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextServices", "OnTxPropertyBitsChange", 19usize, |this| ((**(this as *const *const ITextServices_Vtbl)).OnTxPropertyBitsChange)(this, dwmask, dwbits), |result| Some(*result))
+        }
+        unsafe extern "system" fn TxGetCachedSize(this: *mut core::ffi::c_void, pdwwidth: *mut u32, pdwheight: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextServices", "TxGetCachedSize", 20usize, |this| ((**(this as *const *const ITextServices_Vtbl)).TxGetCachedSize)(this, pdwwidth, pdwheight), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TxSendMessage,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+            TxDraw,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com")))]
+            TxDraw: 0,
+            TxGetHScroll,
+            TxGetVScroll,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+            OnTxSetCursor,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com")))]
+            OnTxSetCursor: 0,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+            TxQueryHitPoint,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com")))]
+            TxQueryHitPoint: 0,
+            OnTxInPlaceActivate,
+            OnTxInPlaceDeactivate,
+            OnTxUIActivate,
+            OnTxUIDeactivate,
+            TxGetText,
+            TxSetText,
+            TxGetCurTargetX,
+            TxGetBaseLinePos,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+            TxGetNaturalSize,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com")))]
+            TxGetNaturalSize: 0,
+            #[cfg(feature = "Win32_System_Ole")]
+            TxGetDropTarget,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            TxGetDropTarget: 0,
+            OnTxPropertyBitsChange,
+            TxGetCachedSize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextServices as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Direct2D", feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for ITextServices2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+        unsafe extern "system" fn TxGetNaturalSize2(this: *mut core::ffi::c_void, dwaspect: u32, hdcdraw: super::super::super::Graphics::Gdi::HDC, hictargetdev: super::super::super::Graphics::Gdi::HDC, ptd: *mut super::super::super::System::Com::DVTARGETDEVICE, dwmode: u32, psizelextent: *const super::super::super::Foundation::SIZE, pwidth: *mut i32, pheight: *mut i32, pascent: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextServices2", "TxGetNaturalSize2", 21usize, |this| ((**(this as *const *const ITextServices2_Vtbl)).TxGetNaturalSize2)(this, dwaspect, hdcdraw, hictargetdev, ptd, dwmode, psizelextent, pwidth, pheight, pascent), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Direct2D")]
+        unsafe extern "system" fn TxDrawD2D(this: *mut core::ffi::c_void, prendertarget: *mut core::ffi::c_void, lprcbounds: *mut super::super::super::Foundation::RECTL, lprcupdate: *mut super::super::super::Foundation::RECT, lviewid: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextServices2", "TxDrawD2D", 22usize, |this| ((**(this as *const *const ITextServices2_Vtbl)).TxDrawD2D)(this, prendertarget, lprcbounds, lprcupdate, lviewid), |result| Some(*result))
+        }
+        Self {
+            base__: <ITextServices_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+            TxGetNaturalSize2,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com")))]
+            TxGetNaturalSize2: 0,
+            #[cfg(feature = "Win32_Graphics_Direct2D")]
+            TxDrawD2D,
+            #[cfg(not(feature = "Win32_Graphics_Direct2D"))]
+            TxDrawD2D: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextServices2 as windows_core::Interface>::IID || <ITextServices_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITextStory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetActive(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStory", "GetActive", 3usize, |this| ((**(this as *const *const ITextStory_Vtbl)).GetActive)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActive(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStory", "SetActive", 4usize, |this| ((**(this as *const *const ITextStory_Vtbl)).SetActive)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplay(this: *mut core::ffi::c_void, ppdisplay: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStory", "GetDisplay", 5usize, |this| ((**(this as *const *const ITextStory_Vtbl)).GetDisplay)(this, ppdisplay), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIndex(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStory", "GetIndex", 6usize, |this| ((**(this as *const *const ITextStory_Vtbl)).GetIndex)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStory", "GetType", 7usize, |this| ((**(this as *const *const ITextStory_Vtbl)).GetType)(this, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetType(this: *mut core::ffi::c_void, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStory", "SetType", 8usize, |this| ((**(this as *const *const ITextStory_Vtbl)).SetType)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, r#type: i32, pvalue: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStory", "GetProperty", 9usize, |this| ((**(this as *const *const ITextStory_Vtbl)).GetProperty)(this, r#type, pvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetRange(this: *mut core::ffi::c_void, cpactive: i32, cpanchor: i32, pprange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStory", "GetRange", 10usize, |this| ((**(this as *const *const ITextStory_Vtbl)).GetRange)(this, cpactive, cpanchor, pprange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetText(this: *mut core::ffi::c_void, flags: i32, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStory", "GetText", 11usize, |this| ((**(this as *const *const ITextStory_Vtbl)).GetText)(this, flags, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFormattedText(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStory", "SetFormattedText", 12usize, |this| ((**(this as *const *const ITextStory_Vtbl)).SetFormattedText)(this, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProperty(this: *mut core::ffi::c_void, r#type: i32, value: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStory", "SetProperty", 13usize, |this| ((**(this as *const *const ITextStory_Vtbl)).SetProperty)(this, r#type, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetText(this: *mut core::ffi::c_void, flags: i32, bstr: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStory", "SetText", 14usize, |this| ((**(this as *const *const ITextStory_Vtbl)).SetText)(this, flags, bstr), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetActive,
+            SetActive,
+            GetDisplay,
+            GetIndex,
+            GetType,
+            SetType,
+            GetProperty,
+            #[cfg(feature = "Win32_System_Com")]
+            GetRange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetRange: 0,
+            GetText,
+            SetFormattedText,
+            SetProperty,
+            SetText,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextStory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITextStoryRanges_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppunkenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoryRanges", "_NewEnum", 7usize, |this| ((**(this as *const *const ITextStoryRanges_Vtbl))._NewEnum)(this, ppunkenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: i32, pprange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoryRanges", "Item", 8usize, |this| ((**(this as *const *const ITextStoryRanges_Vtbl)).Item)(this, index, pprange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoryRanges", "GetCount", 9usize, |this| ((**(this as *const *const ITextStoryRanges_Vtbl)).GetCount)(this, pcount), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            GetCount,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextStoryRanges as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITextStoryRanges2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item2(this: *mut core::ffi::c_void, index: i32, pprange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoryRanges2", "Item2", 10usize, |this| ((**(this as *const *const ITextStoryRanges2_Vtbl)).Item2)(this, index, pprange), |result| Some(*result))
+        }
+        Self {
+            base__: <ITextStoryRanges_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Item2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item2: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextStoryRanges2 as windows_core::Interface>::IID || <ITextStoryRanges_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITextStrings_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: i32, pprange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStrings", "Item", 7usize, |this| ((**(this as *const *const ITextStrings_Vtbl)).Item)(this, index, pprange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStrings", "GetCount", 8usize, |this| ((**(this as *const *const ITextStrings_Vtbl)).GetCount)(this, pcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, bstr: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStrings", "Add", 9usize, |this| ((**(this as *const *const ITextStrings_Vtbl)).Add)(this, bstr), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Append(this: *mut core::ffi::c_void, prange: *mut core::ffi::c_void, istring: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStrings", "Append", 10usize, |this| ((**(this as *const *const ITextStrings_Vtbl)).Append)(this, prange, istring), |result| Some(*result))
+        }
+        unsafe extern "system" fn Cat2(this: *mut core::ffi::c_void, istring: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStrings", "Cat2", 11usize, |this| ((**(this as *const *const ITextStrings_Vtbl)).Cat2)(this, istring), |result| Some(*result))
+        }
+        unsafe extern "system" fn CatTop2(this: *mut core::ffi::c_void, bstr: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStrings", "CatTop2", 12usize, |this| ((**(this as *const *const ITextStrings_Vtbl)).CatTop2)(this, bstr), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DeleteRange(this: *mut core::ffi::c_void, prange: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStrings", "DeleteRange", 13usize, |this| ((**(this as *const *const ITextStrings_Vtbl)).DeleteRange)(this, prange), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EncodeFunction(this: *mut core::ffi::c_void, r#type: i32, align: i32, char: i32, char1: i32, char2: i32, count: i32, texstyle: i32, ccol: i32, prange: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStrings", "EncodeFunction", 14usize, |this| ((**(this as *const *const ITextStrings_Vtbl)).EncodeFunction)(this, r#type, align, char, char1, char2, count, texstyle, ccol, prange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCch(this: *mut core::ffi::c_void, istring: i32, pcch: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStrings", "GetCch", 15usize, |this| ((**(this as *const *const ITextStrings_Vtbl)).GetCch)(this, istring, pcch), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertNullStr(this: *mut core::ffi::c_void, istring: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStrings", "InsertNullStr", 16usize, |this| ((**(this as *const *const ITextStrings_Vtbl)).InsertNullStr)(this, istring), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveBoundary(this: *mut core::ffi::c_void, istring: i32, cch: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStrings", "MoveBoundary", 17usize, |this| ((**(this as *const *const ITextStrings_Vtbl)).MoveBoundary)(this, istring, cch), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrefixTop(this: *mut core::ffi::c_void, bstr: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStrings", "PrefixTop", 18usize, |this| ((**(this as *const *const ITextStrings_Vtbl)).PrefixTop)(this, bstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, istring: i32, cstring: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStrings", "Remove", 19usize, |this| ((**(this as *const *const ITextStrings_Vtbl)).Remove)(this, istring, cstring), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetFormattedText(this: *mut core::ffi::c_void, pranged: *mut core::ffi::c_void, pranges: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStrings", "SetFormattedText", 20usize, |this| ((**(this as *const *const ITextStrings_Vtbl)).SetFormattedText)(this, pranged, pranges), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOpCp(this: *mut core::ffi::c_void, istring: i32, cp: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStrings", "SetOpCp", 21usize, |this| ((**(this as *const *const ITextStrings_Vtbl)).SetOpCp)(this, istring, cp), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SuffixTop(this: *mut core::ffi::c_void, bstr: core::mem::MaybeUninit<windows_core::BSTR>, prange: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStrings", "SuffixTop", 22usize, |this| ((**(this as *const *const ITextStrings_Vtbl)).SuffixTop)(this, bstr, prange), |result| Some(*result))
+        }
+        unsafe extern "system" fn Swap(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStrings", "Swap", 23usize, |this| ((**(this as *const *const ITextStrings_Vtbl)).Swap)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            GetCount,
+            Add,
+            #[cfg(feature = "Win32_System_Com")]
+            Append,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Append: 0,
+            Cat2,
+            CatTop2,
+            #[cfg(feature = "Win32_System_Com")]
+            DeleteRange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DeleteRange: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EncodeFunction,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EncodeFunction: 0,
+            GetCch,
+            InsertNullStr,
+            MoveBoundary,
+            PrefixTop,
+            Remove,
+            #[cfg(feature = "Win32_System_Com")]
+            SetFormattedText,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetFormattedText: 0,
+            SetOpCp,
+            #[cfg(feature = "Win32_System_Com")]
+            SuffixTop,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SuffixTop: 0,
+            Swap,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextStrings as windows_core::Interface>::IID || <super::super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -6259,6 +6259,7 @@ pub struct AEROWIZARDPARTS(pub i32);
 impl windows_core::TypeKind for AEROWIZARDPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AEROWIZARDPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AEROWIZARDPARTS").field(&self.0).finish()
@@ -6270,6 +6271,7 @@ pub struct ARROWBTNSTATES(pub i32);
 impl windows_core::TypeKind for ARROWBTNSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ARROWBTNSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ARROWBTNSTATES").field(&self.0).finish()
@@ -6281,6 +6283,7 @@ pub struct BACKGROUNDSTATES(pub i32);
 impl windows_core::TypeKind for BACKGROUNDSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BACKGROUNDSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BACKGROUNDSTATES").field(&self.0).finish()
@@ -6292,6 +6295,7 @@ pub struct BACKGROUNDWITHBORDERSTATES(pub i32);
 impl windows_core::TypeKind for BACKGROUNDWITHBORDERSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BACKGROUNDWITHBORDERSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BACKGROUNDWITHBORDERSTATES").field(&self.0).finish()
@@ -6303,6 +6307,7 @@ pub struct BALLOONSTATES(pub i32);
 impl windows_core::TypeKind for BALLOONSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BALLOONSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BALLOONSTATES").field(&self.0).finish()
@@ -6314,6 +6319,7 @@ pub struct BALLOONSTEMSTATES(pub i32);
 impl windows_core::TypeKind for BALLOONSTEMSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BALLOONSTEMSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BALLOONSTEMSTATES").field(&self.0).finish()
@@ -6325,6 +6331,7 @@ pub struct BARBACKGROUNDSTATES(pub i32);
 impl windows_core::TypeKind for BARBACKGROUNDSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BARBACKGROUNDSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BARBACKGROUNDSTATES").field(&self.0).finish()
@@ -6336,6 +6343,7 @@ pub struct BARITEMSTATES(pub i32);
 impl windows_core::TypeKind for BARITEMSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BARITEMSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BARITEMSTATES").field(&self.0).finish()
@@ -6347,6 +6355,7 @@ pub struct BGTYPE(pub i32);
 impl windows_core::TypeKind for BGTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BGTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BGTYPE").field(&self.0).finish()
@@ -6358,6 +6367,7 @@ pub struct BODYSTATES(pub i32);
 impl windows_core::TypeKind for BODYSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BODYSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BODYSTATES").field(&self.0).finish()
@@ -6369,6 +6379,7 @@ pub struct BORDERSTATES(pub i32);
 impl windows_core::TypeKind for BORDERSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BORDERSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BORDERSTATES").field(&self.0).finish()
@@ -6380,6 +6391,7 @@ pub struct BORDERTYPE(pub i32);
 impl windows_core::TypeKind for BORDERTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BORDERTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BORDERTYPE").field(&self.0).finish()
@@ -6391,6 +6403,7 @@ pub struct BORDER_HSCROLLSTATES(pub i32);
 impl windows_core::TypeKind for BORDER_HSCROLLSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BORDER_HSCROLLSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BORDER_HSCROLLSTATES").field(&self.0).finish()
@@ -6402,6 +6415,7 @@ pub struct BORDER_HVSCROLLSTATES(pub i32);
 impl windows_core::TypeKind for BORDER_HVSCROLLSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BORDER_HVSCROLLSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BORDER_HVSCROLLSTATES").field(&self.0).finish()
@@ -6413,6 +6427,7 @@ pub struct BORDER_NOSCROLLSTATES(pub i32);
 impl windows_core::TypeKind for BORDER_NOSCROLLSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BORDER_NOSCROLLSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BORDER_NOSCROLLSTATES").field(&self.0).finish()
@@ -6424,6 +6439,7 @@ pub struct BORDER_VSCROLLSTATES(pub i32);
 impl windows_core::TypeKind for BORDER_VSCROLLSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BORDER_VSCROLLSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BORDER_VSCROLLSTATES").field(&self.0).finish()
@@ -6435,6 +6451,7 @@ pub struct BP_ANIMATIONSTYLE(pub i32);
 impl windows_core::TypeKind for BP_ANIMATIONSTYLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BP_ANIMATIONSTYLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BP_ANIMATIONSTYLE").field(&self.0).finish()
@@ -6446,6 +6463,7 @@ pub struct BP_BUFFERFORMAT(pub i32);
 impl windows_core::TypeKind for BP_BUFFERFORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BP_BUFFERFORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BP_BUFFERFORMAT").field(&self.0).finish()
@@ -6457,6 +6475,7 @@ pub struct BP_PAINTPARAMS_FLAGS(pub u32);
 impl windows_core::TypeKind for BP_PAINTPARAMS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BP_PAINTPARAMS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BP_PAINTPARAMS_FLAGS").field(&self.0).finish()
@@ -6501,6 +6520,7 @@ pub struct BUTTONPARTS(pub i32);
 impl windows_core::TypeKind for BUTTONPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BUTTONPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BUTTONPARTS").field(&self.0).finish()
@@ -6512,6 +6532,7 @@ pub struct BUTTON_IMAGELIST_ALIGN(pub u32);
 impl windows_core::TypeKind for BUTTON_IMAGELIST_ALIGN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BUTTON_IMAGELIST_ALIGN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BUTTON_IMAGELIST_ALIGN").field(&self.0).finish()
@@ -6523,6 +6544,7 @@ pub struct CAPTIONSTATES(pub i32);
 impl windows_core::TypeKind for CAPTIONSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CAPTIONSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CAPTIONSTATES").field(&self.0).finish()
@@ -6534,6 +6556,7 @@ pub struct CHECKBOXSTATES(pub i32);
 impl windows_core::TypeKind for CHECKBOXSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CHECKBOXSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CHECKBOXSTATES").field(&self.0).finish()
@@ -6545,6 +6568,7 @@ pub struct CHEVRONSTATES(pub i32);
 impl windows_core::TypeKind for CHEVRONSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CHEVRONSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CHEVRONSTATES").field(&self.0).finish()
@@ -6556,6 +6580,7 @@ pub struct CHEVRONVERTSTATES(pub i32);
 impl windows_core::TypeKind for CHEVRONVERTSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CHEVRONVERTSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CHEVRONVERTSTATES").field(&self.0).finish()
@@ -6567,6 +6592,7 @@ pub struct CLOCKPARTS(pub i32);
 impl windows_core::TypeKind for CLOCKPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLOCKPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLOCKPARTS").field(&self.0).finish()
@@ -6578,6 +6604,7 @@ pub struct CLOCKSTATES(pub i32);
 impl windows_core::TypeKind for CLOCKSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLOCKSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLOCKSTATES").field(&self.0).finish()
@@ -6589,6 +6616,7 @@ pub struct CLOSEBUTTONSTATES(pub i32);
 impl windows_core::TypeKind for CLOSEBUTTONSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLOSEBUTTONSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLOSEBUTTONSTATES").field(&self.0).finish()
@@ -6600,6 +6628,7 @@ pub struct CLOSESTATES(pub i32);
 impl windows_core::TypeKind for CLOSESTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CLOSESTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CLOSESTATES").field(&self.0).finish()
@@ -6611,6 +6640,7 @@ pub struct COLLAPSEBUTTONSTATES(pub i32);
 impl windows_core::TypeKind for COLLAPSEBUTTONSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COLLAPSEBUTTONSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COLLAPSEBUTTONSTATES").field(&self.0).finish()
@@ -6622,6 +6652,7 @@ pub struct COMBOBOXINFO_BUTTON_STATE(pub u32);
 impl windows_core::TypeKind for COMBOBOXINFO_BUTTON_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMBOBOXINFO_BUTTON_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMBOBOXINFO_BUTTON_STATE").field(&self.0).finish()
@@ -6633,6 +6664,7 @@ pub struct COMBOBOXPARTS(pub i32);
 impl windows_core::TypeKind for COMBOBOXPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMBOBOXPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMBOBOXPARTS").field(&self.0).finish()
@@ -6644,6 +6676,7 @@ pub struct COMBOBOXSTYLESTATES(pub i32);
 impl windows_core::TypeKind for COMBOBOXSTYLESTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMBOBOXSTYLESTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMBOBOXSTYLESTATES").field(&self.0).finish()
@@ -6655,6 +6688,7 @@ pub struct COMBOBOX_EX_ITEM_FLAGS(pub u32);
 impl windows_core::TypeKind for COMBOBOX_EX_ITEM_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMBOBOX_EX_ITEM_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMBOBOX_EX_ITEM_FLAGS").field(&self.0).finish()
@@ -6699,6 +6733,7 @@ pub struct COMMANDLINKGLYPHSTATES(pub i32);
 impl windows_core::TypeKind for COMMANDLINKGLYPHSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMMANDLINKGLYPHSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMMANDLINKGLYPHSTATES").field(&self.0).finish()
@@ -6710,6 +6745,7 @@ pub struct COMMANDLINKSTATES(pub i32);
 impl windows_core::TypeKind for COMMANDLINKSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMMANDLINKSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMMANDLINKSTATES").field(&self.0).finish()
@@ -6721,6 +6757,7 @@ pub struct COMMUNICATIONSPARTS(pub i32);
 impl windows_core::TypeKind for COMMUNICATIONSPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COMMUNICATIONSPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COMMUNICATIONSPARTS").field(&self.0).finish()
@@ -6732,6 +6769,7 @@ pub struct CONTENTALIGNMENT(pub i32);
 impl windows_core::TypeKind for CONTENTALIGNMENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CONTENTALIGNMENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CONTENTALIGNMENT").field(&self.0).finish()
@@ -6743,6 +6781,7 @@ pub struct CONTENTAREASTATES(pub i32);
 impl windows_core::TypeKind for CONTENTAREASTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CONTENTAREASTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CONTENTAREASTATES").field(&self.0).finish()
@@ -6754,6 +6793,7 @@ pub struct CONTENTLINKSTATES(pub i32);
 impl windows_core::TypeKind for CONTENTLINKSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CONTENTLINKSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CONTENTLINKSTATES").field(&self.0).finish()
@@ -6765,6 +6805,7 @@ pub struct CONTENTPANESTATES(pub i32);
 impl windows_core::TypeKind for CONTENTPANESTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CONTENTPANESTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CONTENTPANESTATES").field(&self.0).finish()
@@ -6776,6 +6817,7 @@ pub struct CONTROLLABELSTATES(pub i32);
 impl windows_core::TypeKind for CONTROLLABELSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CONTROLLABELSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CONTROLLABELSTATES").field(&self.0).finish()
@@ -6787,6 +6829,7 @@ pub struct CONTROLPANELPARTS(pub i32);
 impl windows_core::TypeKind for CONTROLPANELPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CONTROLPANELPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CONTROLPANELPARTS").field(&self.0).finish()
@@ -6798,6 +6841,7 @@ pub struct COPYSTATES(pub i32);
 impl windows_core::TypeKind for COPYSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for COPYSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("COPYSTATES").field(&self.0).finish()
@@ -6809,6 +6853,7 @@ pub struct CREATELINKSTATES(pub i32);
 impl windows_core::TypeKind for CREATELINKSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CREATELINKSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CREATELINKSTATES").field(&self.0).finish()
@@ -6820,6 +6865,7 @@ pub struct CUEBANNERSTATES(pub i32);
 impl windows_core::TypeKind for CUEBANNERSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CUEBANNERSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CUEBANNERSTATES").field(&self.0).finish()
@@ -6831,6 +6877,7 @@ pub struct DATEBORDERSTATES(pub i32);
 impl windows_core::TypeKind for DATEBORDERSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DATEBORDERSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DATEBORDERSTATES").field(&self.0).finish()
@@ -6842,6 +6889,7 @@ pub struct DATEPICKERPARTS(pub i32);
 impl windows_core::TypeKind for DATEPICKERPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DATEPICKERPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DATEPICKERPARTS").field(&self.0).finish()
@@ -6853,6 +6901,7 @@ pub struct DATETEXTSTATES(pub i32);
 impl windows_core::TypeKind for DATETEXTSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DATETEXTSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DATETEXTSTATES").field(&self.0).finish()
@@ -6864,6 +6913,7 @@ pub struct DLG_BUTTON_CHECK_STATE(pub u32);
 impl windows_core::TypeKind for DLG_BUTTON_CHECK_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DLG_BUTTON_CHECK_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DLG_BUTTON_CHECK_STATE").field(&self.0).finish()
@@ -6875,6 +6925,7 @@ pub struct DLG_DIR_LIST_FILE_TYPE(pub u32);
 impl windows_core::TypeKind for DLG_DIR_LIST_FILE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DLG_DIR_LIST_FILE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DLG_DIR_LIST_FILE_TYPE").field(&self.0).finish()
@@ -6919,6 +6970,7 @@ pub struct DOWNHORZSTATES(pub i32);
 impl windows_core::TypeKind for DOWNHORZSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DOWNHORZSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DOWNHORZSTATES").field(&self.0).finish()
@@ -6930,6 +6982,7 @@ pub struct DOWNSTATES(pub i32);
 impl windows_core::TypeKind for DOWNSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DOWNSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DOWNSTATES").field(&self.0).finish()
@@ -6941,6 +6994,7 @@ pub struct DPAMM_MESSAGE(pub u32);
 impl windows_core::TypeKind for DPAMM_MESSAGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DPAMM_MESSAGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DPAMM_MESSAGE").field(&self.0).finish()
@@ -6952,6 +7006,7 @@ pub struct DRAGDROPPARTS(pub i32);
 impl windows_core::TypeKind for DRAGDROPPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DRAGDROPPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DRAGDROPPARTS").field(&self.0).finish()
@@ -6963,6 +7018,7 @@ pub struct DRAGLISTINFO_NOTIFICATION_FLAGS(pub u32);
 impl windows_core::TypeKind for DRAGLISTINFO_NOTIFICATION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DRAGLISTINFO_NOTIFICATION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DRAGLISTINFO_NOTIFICATION_FLAGS").field(&self.0).finish()
@@ -6974,6 +7030,7 @@ pub struct DRAWITEMSTRUCT_CTL_TYPE(pub u32);
 impl windows_core::TypeKind for DRAWITEMSTRUCT_CTL_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DRAWITEMSTRUCT_CTL_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DRAWITEMSTRUCT_CTL_TYPE").field(&self.0).finish()
@@ -6985,6 +7042,7 @@ pub struct DRAW_THEME_PARENT_BACKGROUND_FLAGS(pub u32);
 impl windows_core::TypeKind for DRAW_THEME_PARENT_BACKGROUND_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DRAW_THEME_PARENT_BACKGROUND_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DRAW_THEME_PARENT_BACKGROUND_FLAGS").field(&self.0).finish()
@@ -7029,6 +7087,7 @@ pub struct DROPDOWNBUTTONLEFTSTATES(pub i32);
 impl windows_core::TypeKind for DROPDOWNBUTTONLEFTSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DROPDOWNBUTTONLEFTSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DROPDOWNBUTTONLEFTSTATES").field(&self.0).finish()
@@ -7040,6 +7099,7 @@ pub struct DROPDOWNBUTTONRIGHTSTATES(pub i32);
 impl windows_core::TypeKind for DROPDOWNBUTTONRIGHTSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DROPDOWNBUTTONRIGHTSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DROPDOWNBUTTONRIGHTSTATES").field(&self.0).finish()
@@ -7051,6 +7111,7 @@ pub struct DROPDOWNITEMSTATES(pub i32);
 impl windows_core::TypeKind for DROPDOWNITEMSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DROPDOWNITEMSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DROPDOWNITEMSTATES").field(&self.0).finish()
@@ -7062,6 +7123,7 @@ pub struct DTTOPTS_FLAGS(pub u32);
 impl windows_core::TypeKind for DTTOPTS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DTTOPTS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DTTOPTS_FLAGS").field(&self.0).finish()
@@ -7106,6 +7168,7 @@ pub struct EC_ENDOFLINE(pub i32);
 impl windows_core::TypeKind for EC_ENDOFLINE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EC_ENDOFLINE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EC_ENDOFLINE").field(&self.0).finish()
@@ -7117,6 +7180,7 @@ pub struct EC_SEARCHWEB_ENTRYPOINT(pub i32);
 impl windows_core::TypeKind for EC_SEARCHWEB_ENTRYPOINT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EC_SEARCHWEB_ENTRYPOINT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EC_SEARCHWEB_ENTRYPOINT").field(&self.0).finish()
@@ -7128,6 +7192,7 @@ pub struct EDITBALLOONTIP_ICON(pub i32);
 impl windows_core::TypeKind for EDITBALLOONTIP_ICON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EDITBALLOONTIP_ICON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EDITBALLOONTIP_ICON").field(&self.0).finish()
@@ -7139,6 +7204,7 @@ pub struct EDITBORDER_HSCROLLSTATES(pub i32);
 impl windows_core::TypeKind for EDITBORDER_HSCROLLSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EDITBORDER_HSCROLLSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EDITBORDER_HSCROLLSTATES").field(&self.0).finish()
@@ -7150,6 +7216,7 @@ pub struct EDITBORDER_HVSCROLLSTATES(pub i32);
 impl windows_core::TypeKind for EDITBORDER_HVSCROLLSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EDITBORDER_HVSCROLLSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EDITBORDER_HVSCROLLSTATES").field(&self.0).finish()
@@ -7161,6 +7228,7 @@ pub struct EDITBORDER_NOSCROLLSTATES(pub i32);
 impl windows_core::TypeKind for EDITBORDER_NOSCROLLSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EDITBORDER_NOSCROLLSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EDITBORDER_NOSCROLLSTATES").field(&self.0).finish()
@@ -7172,6 +7240,7 @@ pub struct EDITBORDER_VSCROLLSTATES(pub i32);
 impl windows_core::TypeKind for EDITBORDER_VSCROLLSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EDITBORDER_VSCROLLSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EDITBORDER_VSCROLLSTATES").field(&self.0).finish()
@@ -7183,6 +7252,7 @@ pub struct EDITPARTS(pub i32);
 impl windows_core::TypeKind for EDITPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EDITPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EDITPARTS").field(&self.0).finish()
@@ -7194,6 +7264,7 @@ pub struct EDITTEXTSTATES(pub i32);
 impl windows_core::TypeKind for EDITTEXTSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EDITTEXTSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EDITTEXTSTATES").field(&self.0).finish()
@@ -7205,6 +7276,7 @@ pub struct EMPTYMARKUPPARTS(pub i32);
 impl windows_core::TypeKind for EMPTYMARKUPPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EMPTYMARKUPPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EMPTYMARKUPPARTS").field(&self.0).finish()
@@ -7216,6 +7288,7 @@ pub struct ENABLE_SCROLL_BAR_ARROWS(pub u32);
 impl windows_core::TypeKind for ENABLE_SCROLL_BAR_ARROWS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ENABLE_SCROLL_BAR_ARROWS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ENABLE_SCROLL_BAR_ARROWS").field(&self.0).finish()
@@ -7227,6 +7300,7 @@ pub struct EXPANDBUTTONSTATES(pub i32);
 impl windows_core::TypeKind for EXPANDBUTTONSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EXPANDBUTTONSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EXPANDBUTTONSTATES").field(&self.0).finish()
@@ -7238,6 +7312,7 @@ pub struct EXPANDOBUTTONSTATES(pub i32);
 impl windows_core::TypeKind for EXPANDOBUTTONSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EXPANDOBUTTONSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EXPANDOBUTTONSTATES").field(&self.0).finish()
@@ -7249,6 +7324,7 @@ pub struct EXPLORERBARPARTS(pub i32);
 impl windows_core::TypeKind for EXPLORERBARPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EXPLORERBARPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EXPLORERBARPARTS").field(&self.0).finish()
@@ -7260,6 +7336,7 @@ pub struct FEEDBACK_TYPE(pub i32);
 impl windows_core::TypeKind for FEEDBACK_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FEEDBACK_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FEEDBACK_TYPE").field(&self.0).finish()
@@ -7271,6 +7348,7 @@ pub struct FILLSTATES(pub i32);
 impl windows_core::TypeKind for FILLSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FILLSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FILLSTATES").field(&self.0).finish()
@@ -7282,6 +7360,7 @@ pub struct FILLTYPE(pub i32);
 impl windows_core::TypeKind for FILLTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FILLTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FILLTYPE").field(&self.0).finish()
@@ -7293,6 +7372,7 @@ pub struct FILLVERTSTATES(pub i32);
 impl windows_core::TypeKind for FILLVERTSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FILLVERTSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FILLVERTSTATES").field(&self.0).finish()
@@ -7304,6 +7384,7 @@ pub struct FLYOUTPARTS(pub i32);
 impl windows_core::TypeKind for FLYOUTPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FLYOUTPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FLYOUTPARTS").field(&self.0).finish()
@@ -7315,6 +7396,7 @@ pub struct FRAMEBOTTOMSTATES(pub i32);
 impl windows_core::TypeKind for FRAMEBOTTOMSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FRAMEBOTTOMSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FRAMEBOTTOMSTATES").field(&self.0).finish()
@@ -7326,6 +7408,7 @@ pub struct FRAMELEFTSTATES(pub i32);
 impl windows_core::TypeKind for FRAMELEFTSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FRAMELEFTSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FRAMELEFTSTATES").field(&self.0).finish()
@@ -7337,6 +7420,7 @@ pub struct FRAMERIGHTSTATES(pub i32);
 impl windows_core::TypeKind for FRAMERIGHTSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FRAMERIGHTSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FRAMERIGHTSTATES").field(&self.0).finish()
@@ -7348,6 +7432,7 @@ pub struct FRAMESTATES(pub i32);
 impl windows_core::TypeKind for FRAMESTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FRAMESTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FRAMESTATES").field(&self.0).finish()
@@ -7359,6 +7444,7 @@ pub struct GET_THEME_BITMAP_FLAGS(pub u32);
 impl windows_core::TypeKind for GET_THEME_BITMAP_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GET_THEME_BITMAP_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GET_THEME_BITMAP_FLAGS").field(&self.0).finish()
@@ -7370,6 +7456,7 @@ pub struct GLYPHFONTSIZINGTYPE(pub i32);
 impl windows_core::TypeKind for GLYPHFONTSIZINGTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GLYPHFONTSIZINGTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GLYPHFONTSIZINGTYPE").field(&self.0).finish()
@@ -7381,6 +7468,7 @@ pub struct GLYPHSTATES(pub i32);
 impl windows_core::TypeKind for GLYPHSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GLYPHSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GLYPHSTATES").field(&self.0).finish()
@@ -7392,6 +7480,7 @@ pub struct GLYPHTYPE(pub i32);
 impl windows_core::TypeKind for GLYPHTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GLYPHTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GLYPHTYPE").field(&self.0).finish()
@@ -7403,6 +7492,7 @@ pub struct GRIDCELLBACKGROUNDSTATES(pub i32);
 impl windows_core::TypeKind for GRIDCELLBACKGROUNDSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GRIDCELLBACKGROUNDSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GRIDCELLBACKGROUNDSTATES").field(&self.0).finish()
@@ -7414,6 +7504,7 @@ pub struct GRIDCELLSTATES(pub i32);
 impl windows_core::TypeKind for GRIDCELLSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GRIDCELLSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GRIDCELLSTATES").field(&self.0).finish()
@@ -7425,6 +7516,7 @@ pub struct GRIDCELLUPPERSTATES(pub i32);
 impl windows_core::TypeKind for GRIDCELLUPPERSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GRIDCELLUPPERSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GRIDCELLUPPERSTATES").field(&self.0).finish()
@@ -7436,6 +7528,7 @@ pub struct GRIPPERSTATES(pub i32);
 impl windows_core::TypeKind for GRIPPERSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GRIPPERSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GRIPPERSTATES").field(&self.0).finish()
@@ -7447,6 +7540,7 @@ pub struct GROUPBOXSTATES(pub i32);
 impl windows_core::TypeKind for GROUPBOXSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GROUPBOXSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GROUPBOXSTATES").field(&self.0).finish()
@@ -7458,6 +7552,7 @@ pub struct GROUPHEADERLINESTATES(pub i32);
 impl windows_core::TypeKind for GROUPHEADERLINESTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GROUPHEADERLINESTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GROUPHEADERLINESTATES").field(&self.0).finish()
@@ -7469,6 +7564,7 @@ pub struct GROUPHEADERSTATES(pub i32);
 impl windows_core::TypeKind for GROUPHEADERSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GROUPHEADERSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GROUPHEADERSTATES").field(&self.0).finish()
@@ -7480,6 +7576,7 @@ pub struct HALIGN(pub i32);
 impl windows_core::TypeKind for HALIGN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HALIGN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HALIGN").field(&self.0).finish()
@@ -7491,6 +7588,7 @@ pub struct HDI_MASK(pub u32);
 impl windows_core::TypeKind for HDI_MASK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HDI_MASK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HDI_MASK").field(&self.0).finish()
@@ -7535,6 +7633,7 @@ pub struct HEADERAREASTATES(pub i32);
 impl windows_core::TypeKind for HEADERAREASTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HEADERAREASTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HEADERAREASTATES").field(&self.0).finish()
@@ -7546,6 +7645,7 @@ pub struct HEADERCLOSESTATES(pub i32);
 impl windows_core::TypeKind for HEADERCLOSESTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HEADERCLOSESTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HEADERCLOSESTATES").field(&self.0).finish()
@@ -7557,6 +7657,7 @@ pub struct HEADERDROPDOWNFILTERSTATES(pub i32);
 impl windows_core::TypeKind for HEADERDROPDOWNFILTERSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HEADERDROPDOWNFILTERSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HEADERDROPDOWNFILTERSTATES").field(&self.0).finish()
@@ -7568,6 +7669,7 @@ pub struct HEADERDROPDOWNSTATES(pub i32);
 impl windows_core::TypeKind for HEADERDROPDOWNSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HEADERDROPDOWNSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HEADERDROPDOWNSTATES").field(&self.0).finish()
@@ -7579,6 +7681,7 @@ pub struct HEADERITEMLEFTSTATES(pub i32);
 impl windows_core::TypeKind for HEADERITEMLEFTSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HEADERITEMLEFTSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HEADERITEMLEFTSTATES").field(&self.0).finish()
@@ -7590,6 +7693,7 @@ pub struct HEADERITEMRIGHTSTATES(pub i32);
 impl windows_core::TypeKind for HEADERITEMRIGHTSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HEADERITEMRIGHTSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HEADERITEMRIGHTSTATES").field(&self.0).finish()
@@ -7601,6 +7705,7 @@ pub struct HEADERITEMSTATES(pub i32);
 impl windows_core::TypeKind for HEADERITEMSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HEADERITEMSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HEADERITEMSTATES").field(&self.0).finish()
@@ -7612,6 +7717,7 @@ pub struct HEADEROVERFLOWSTATES(pub i32);
 impl windows_core::TypeKind for HEADEROVERFLOWSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HEADEROVERFLOWSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HEADEROVERFLOWSTATES").field(&self.0).finish()
@@ -7623,6 +7729,7 @@ pub struct HEADERPARTS(pub i32);
 impl windows_core::TypeKind for HEADERPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HEADERPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HEADERPARTS").field(&self.0).finish()
@@ -7634,6 +7741,7 @@ pub struct HEADERPINSTATES(pub i32);
 impl windows_core::TypeKind for HEADERPINSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HEADERPINSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HEADERPINSTATES").field(&self.0).finish()
@@ -7645,6 +7753,7 @@ pub struct HEADERSORTARROWSTATES(pub i32);
 impl windows_core::TypeKind for HEADERSORTARROWSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HEADERSORTARROWSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HEADERSORTARROWSTATES").field(&self.0).finish()
@@ -7656,6 +7765,7 @@ pub struct HEADERSTYLESTATES(pub i32);
 impl windows_core::TypeKind for HEADERSTYLESTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HEADERSTYLESTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HEADERSTYLESTATES").field(&self.0).finish()
@@ -7667,6 +7777,7 @@ pub struct HEADER_CONTROL_FORMAT_FLAGS(pub i32);
 impl windows_core::TypeKind for HEADER_CONTROL_FORMAT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HEADER_CONTROL_FORMAT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HEADER_CONTROL_FORMAT_FLAGS").field(&self.0).finish()
@@ -7678,6 +7789,7 @@ pub struct HEADER_CONTROL_FORMAT_STATE(pub u32);
 impl windows_core::TypeKind for HEADER_CONTROL_FORMAT_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HEADER_CONTROL_FORMAT_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HEADER_CONTROL_FORMAT_STATE").field(&self.0).finish()
@@ -7689,6 +7801,7 @@ pub struct HEADER_CONTROL_FORMAT_TYPE(pub u32);
 impl windows_core::TypeKind for HEADER_CONTROL_FORMAT_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HEADER_CONTROL_FORMAT_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HEADER_CONTROL_FORMAT_TYPE").field(&self.0).finish()
@@ -7700,6 +7813,7 @@ pub struct HEADER_CONTROL_NOTIFICATION_BUTTON(pub i32);
 impl windows_core::TypeKind for HEADER_CONTROL_NOTIFICATION_BUTTON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HEADER_CONTROL_NOTIFICATION_BUTTON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HEADER_CONTROL_NOTIFICATION_BUTTON").field(&self.0).finish()
@@ -7711,6 +7825,7 @@ pub struct HEADER_HITTEST_INFO_FLAGS(pub u32);
 impl windows_core::TypeKind for HEADER_HITTEST_INFO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HEADER_HITTEST_INFO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HEADER_HITTEST_INFO_FLAGS").field(&self.0).finish()
@@ -7755,6 +7870,7 @@ pub struct HELPBUTTONSTATES(pub i32);
 impl windows_core::TypeKind for HELPBUTTONSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HELPBUTTONSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HELPBUTTONSTATES").field(&self.0).finish()
@@ -7766,6 +7882,7 @@ pub struct HELPLINKSTATES(pub i32);
 impl windows_core::TypeKind for HELPLINKSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HELPLINKSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HELPLINKSTATES").field(&self.0).finish()
@@ -7777,6 +7894,7 @@ pub struct HIT_TEST_BACKGROUND_OPTIONS(pub u32);
 impl windows_core::TypeKind for HIT_TEST_BACKGROUND_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HIT_TEST_BACKGROUND_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HIT_TEST_BACKGROUND_OPTIONS").field(&self.0).finish()
@@ -7788,6 +7906,7 @@ pub struct HORZSCROLLSTATES(pub i32);
 impl windows_core::TypeKind for HORZSCROLLSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HORZSCROLLSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HORZSCROLLSTATES").field(&self.0).finish()
@@ -7799,6 +7918,7 @@ pub struct HORZTHUMBSTATES(pub i32);
 impl windows_core::TypeKind for HORZTHUMBSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HORZTHUMBSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HORZTHUMBSTATES").field(&self.0).finish()
@@ -7810,6 +7930,7 @@ pub struct HOTGLYPHSTATES(pub i32);
 impl windows_core::TypeKind for HOTGLYPHSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HOTGLYPHSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HOTGLYPHSTATES").field(&self.0).finish()
@@ -7821,6 +7942,7 @@ pub struct HOVERBACKGROUNDSTATES(pub i32);
 impl windows_core::TypeKind for HOVERBACKGROUNDSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HOVERBACKGROUNDSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HOVERBACKGROUNDSTATES").field(&self.0).finish()
@@ -7832,6 +7954,7 @@ pub struct HYPERLINKSTATES(pub i32);
 impl windows_core::TypeKind for HYPERLINKSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HYPERLINKSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HYPERLINKSTATES").field(&self.0).finish()
@@ -7843,6 +7966,7 @@ pub struct HYPERLINKTEXTSTATES(pub i32);
 impl windows_core::TypeKind for HYPERLINKTEXTSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HYPERLINKTEXTSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HYPERLINKTEXTSTATES").field(&self.0).finish()
@@ -7854,6 +7978,7 @@ pub struct ICONEFFECT(pub i32);
 impl windows_core::TypeKind for ICONEFFECT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ICONEFFECT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ICONEFFECT").field(&self.0).finish()
@@ -7865,6 +7990,7 @@ pub struct IEBARMENUSTATES(pub i32);
 impl windows_core::TypeKind for IEBARMENUSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IEBARMENUSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IEBARMENUSTATES").field(&self.0).finish()
@@ -7876,6 +8002,7 @@ pub struct IMAGELAYOUT(pub i32);
 impl windows_core::TypeKind for IMAGELAYOUT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAGELAYOUT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAGELAYOUT").field(&self.0).finish()
@@ -7887,6 +8014,7 @@ pub struct IMAGELIST_CREATION_FLAGS(pub u32);
 impl windows_core::TypeKind for IMAGELIST_CREATION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAGELIST_CREATION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAGELIST_CREATION_FLAGS").field(&self.0).finish()
@@ -7931,6 +8059,7 @@ pub struct IMAGESELECTTYPE(pub i32);
 impl windows_core::TypeKind for IMAGESELECTTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAGESELECTTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAGESELECTTYPE").field(&self.0).finish()
@@ -7942,6 +8071,7 @@ pub struct IMAGE_LIST_COPY_FLAGS(pub u32);
 impl windows_core::TypeKind for IMAGE_LIST_COPY_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAGE_LIST_COPY_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAGE_LIST_COPY_FLAGS").field(&self.0).finish()
@@ -7953,6 +8083,7 @@ pub struct IMAGE_LIST_DRAW_STYLE(pub u32);
 impl windows_core::TypeKind for IMAGE_LIST_DRAW_STYLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAGE_LIST_DRAW_STYLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAGE_LIST_DRAW_STYLE").field(&self.0).finish()
@@ -7997,6 +8128,7 @@ pub struct IMAGE_LIST_ITEM_FLAGS(pub u32);
 impl windows_core::TypeKind for IMAGE_LIST_ITEM_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAGE_LIST_ITEM_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAGE_LIST_ITEM_FLAGS").field(&self.0).finish()
@@ -8008,6 +8140,7 @@ pub struct IMAGE_LIST_WRITE_STREAM_FLAGS(pub u32);
 impl windows_core::TypeKind for IMAGE_LIST_WRITE_STREAM_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IMAGE_LIST_WRITE_STREAM_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IMAGE_LIST_WRITE_STREAM_FLAGS").field(&self.0).finish()
@@ -8052,6 +8185,7 @@ pub struct INITCOMMONCONTROLSEX_ICC(pub u32);
 impl windows_core::TypeKind for INITCOMMONCONTROLSEX_ICC {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INITCOMMONCONTROLSEX_ICC {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INITCOMMONCONTROLSEX_ICC").field(&self.0).finish()
@@ -8096,6 +8230,7 @@ pub struct ITEMSTATES(pub i32);
 impl windows_core::TypeKind for ITEMSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ITEMSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ITEMSTATES").field(&self.0).finish()
@@ -8107,6 +8242,7 @@ pub struct LABELSTATES(pub i32);
 impl windows_core::TypeKind for LABELSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LABELSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LABELSTATES").field(&self.0).finish()
@@ -8118,6 +8254,7 @@ pub struct LINKHEADERSTATES(pub i32);
 impl windows_core::TypeKind for LINKHEADERSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LINKHEADERSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LINKHEADERSTATES").field(&self.0).finish()
@@ -8129,6 +8266,7 @@ pub struct LINKPARTS(pub i32);
 impl windows_core::TypeKind for LINKPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LINKPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LINKPARTS").field(&self.0).finish()
@@ -8140,6 +8278,7 @@ pub struct LINKSTATES(pub i32);
 impl windows_core::TypeKind for LINKSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LINKSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LINKSTATES").field(&self.0).finish()
@@ -8151,6 +8290,7 @@ pub struct LISTBOXPARTS(pub i32);
 impl windows_core::TypeKind for LISTBOXPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LISTBOXPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LISTBOXPARTS").field(&self.0).finish()
@@ -8162,6 +8302,7 @@ pub struct LISTITEMSTATES(pub i32);
 impl windows_core::TypeKind for LISTITEMSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LISTITEMSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LISTITEMSTATES").field(&self.0).finish()
@@ -8173,6 +8314,7 @@ pub struct LISTVIEWPARTS(pub i32);
 impl windows_core::TypeKind for LISTVIEWPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LISTVIEWPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LISTVIEWPARTS").field(&self.0).finish()
@@ -8184,6 +8326,7 @@ pub struct LIST_ITEM_FLAGS(pub u32);
 impl windows_core::TypeKind for LIST_ITEM_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LIST_ITEM_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LIST_ITEM_FLAGS").field(&self.0).finish()
@@ -8228,6 +8371,7 @@ pub struct LIST_ITEM_STATE_FLAGS(pub u32);
 impl windows_core::TypeKind for LIST_ITEM_STATE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LIST_ITEM_STATE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LIST_ITEM_STATE_FLAGS").field(&self.0).finish()
@@ -8272,6 +8416,7 @@ pub struct LIST_VIEW_BACKGROUND_IMAGE_FLAGS(pub u32);
 impl windows_core::TypeKind for LIST_VIEW_BACKGROUND_IMAGE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LIST_VIEW_BACKGROUND_IMAGE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LIST_VIEW_BACKGROUND_IMAGE_FLAGS").field(&self.0).finish()
@@ -8316,6 +8461,7 @@ pub struct LIST_VIEW_GROUP_ALIGN_FLAGS(pub u32);
 impl windows_core::TypeKind for LIST_VIEW_GROUP_ALIGN_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LIST_VIEW_GROUP_ALIGN_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LIST_VIEW_GROUP_ALIGN_FLAGS").field(&self.0).finish()
@@ -8360,6 +8506,7 @@ pub struct LIST_VIEW_GROUP_STATE_FLAGS(pub u32);
 impl windows_core::TypeKind for LIST_VIEW_GROUP_STATE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LIST_VIEW_GROUP_STATE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LIST_VIEW_GROUP_STATE_FLAGS").field(&self.0).finish()
@@ -8404,6 +8551,7 @@ pub struct LIST_VIEW_ITEM_COLUMN_FORMAT_FLAGS(pub i32);
 impl windows_core::TypeKind for LIST_VIEW_ITEM_COLUMN_FORMAT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LIST_VIEW_ITEM_COLUMN_FORMAT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LIST_VIEW_ITEM_COLUMN_FORMAT_FLAGS").field(&self.0).finish()
@@ -8448,6 +8596,7 @@ pub struct LIST_VIEW_ITEM_FLAGS(pub u32);
 impl windows_core::TypeKind for LIST_VIEW_ITEM_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LIST_VIEW_ITEM_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LIST_VIEW_ITEM_FLAGS").field(&self.0).finish()
@@ -8492,6 +8641,7 @@ pub struct LIST_VIEW_ITEM_STATE_FLAGS(pub u32);
 impl windows_core::TypeKind for LIST_VIEW_ITEM_STATE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LIST_VIEW_ITEM_STATE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LIST_VIEW_ITEM_STATE_FLAGS").field(&self.0).finish()
@@ -8503,6 +8653,7 @@ pub struct LOGOFFBUTTONSSTATES(pub i32);
 impl windows_core::TypeKind for LOGOFFBUTTONSSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LOGOFFBUTTONSSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LOGOFFBUTTONSSTATES").field(&self.0).finish()
@@ -8514,6 +8665,7 @@ pub struct LVCOLUMNW_FORMAT(pub i32);
 impl windows_core::TypeKind for LVCOLUMNW_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LVCOLUMNW_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LVCOLUMNW_FORMAT").field(&self.0).finish()
@@ -8558,6 +8710,7 @@ pub struct LVCOLUMNW_MASK(pub u32);
 impl windows_core::TypeKind for LVCOLUMNW_MASK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LVCOLUMNW_MASK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LVCOLUMNW_MASK").field(&self.0).finish()
@@ -8602,6 +8755,7 @@ pub struct LVFINDINFOW_FLAGS(pub u32);
 impl windows_core::TypeKind for LVFINDINFOW_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LVFINDINFOW_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LVFINDINFOW_FLAGS").field(&self.0).finish()
@@ -8646,6 +8800,7 @@ pub struct LVFOOTERITEM_MASK(pub u32);
 impl windows_core::TypeKind for LVFOOTERITEM_MASK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LVFOOTERITEM_MASK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LVFOOTERITEM_MASK").field(&self.0).finish()
@@ -8657,6 +8812,7 @@ pub struct LVGROUP_MASK(pub u32);
 impl windows_core::TypeKind for LVGROUP_MASK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LVGROUP_MASK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LVGROUP_MASK").field(&self.0).finish()
@@ -8701,6 +8857,7 @@ pub struct LVHITTESTINFO_FLAGS(pub u32);
 impl windows_core::TypeKind for LVHITTESTINFO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LVHITTESTINFO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LVHITTESTINFO_FLAGS").field(&self.0).finish()
@@ -8745,6 +8902,7 @@ pub struct LVITEMA_GROUP_ID(pub i32);
 impl windows_core::TypeKind for LVITEMA_GROUP_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LVITEMA_GROUP_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LVITEMA_GROUP_ID").field(&self.0).finish()
@@ -8756,6 +8914,7 @@ pub struct LVTILEVIEWINFO_FLAGS(pub u32);
 impl windows_core::TypeKind for LVTILEVIEWINFO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LVTILEVIEWINFO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LVTILEVIEWINFO_FLAGS").field(&self.0).finish()
@@ -8800,6 +8959,7 @@ pub struct LVTILEVIEWINFO_MASK(pub u32);
 impl windows_core::TypeKind for LVTILEVIEWINFO_MASK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LVTILEVIEWINFO_MASK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LVTILEVIEWINFO_MASK").field(&self.0).finish()
@@ -8844,6 +9004,7 @@ pub struct MARKUPTEXTSTATES(pub i32);
 impl windows_core::TypeKind for MARKUPTEXTSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MARKUPTEXTSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MARKUPTEXTSTATES").field(&self.0).finish()
@@ -8855,6 +9016,7 @@ pub struct MAXBUTTONSTATES(pub i32);
 impl windows_core::TypeKind for MAXBUTTONSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MAXBUTTONSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MAXBUTTONSTATES").field(&self.0).finish()
@@ -8866,6 +9028,7 @@ pub struct MAXCAPTIONSTATES(pub i32);
 impl windows_core::TypeKind for MAXCAPTIONSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MAXCAPTIONSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MAXCAPTIONSTATES").field(&self.0).finish()
@@ -8877,6 +9040,7 @@ pub struct MCGRIDINFO_FLAGS(pub u32);
 impl windows_core::TypeKind for MCGRIDINFO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MCGRIDINFO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MCGRIDINFO_FLAGS").field(&self.0).finish()
@@ -8921,6 +9085,7 @@ pub struct MCGRIDINFO_PART(pub u32);
 impl windows_core::TypeKind for MCGRIDINFO_PART {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MCGRIDINFO_PART {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MCGRIDINFO_PART").field(&self.0).finish()
@@ -8932,6 +9097,7 @@ pub struct MCHITTESTINFO_HIT_FLAGS(pub u32);
 impl windows_core::TypeKind for MCHITTESTINFO_HIT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MCHITTESTINFO_HIT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MCHITTESTINFO_HIT_FLAGS").field(&self.0).finish()
@@ -8976,6 +9142,7 @@ pub struct MDICLOSEBUTTONSTATES(pub i32);
 impl windows_core::TypeKind for MDICLOSEBUTTONSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MDICLOSEBUTTONSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MDICLOSEBUTTONSTATES").field(&self.0).finish()
@@ -8987,6 +9154,7 @@ pub struct MDIMINBUTTONSTATES(pub i32);
 impl windows_core::TypeKind for MDIMINBUTTONSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MDIMINBUTTONSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MDIMINBUTTONSTATES").field(&self.0).finish()
@@ -8998,6 +9166,7 @@ pub struct MDIRESTOREBUTTONSTATES(pub i32);
 impl windows_core::TypeKind for MDIRESTOREBUTTONSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MDIRESTOREBUTTONSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MDIRESTOREBUTTONSTATES").field(&self.0).finish()
@@ -9009,6 +9178,7 @@ pub struct MENUBANDPARTS(pub i32);
 impl windows_core::TypeKind for MENUBANDPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MENUBANDPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MENUBANDPARTS").field(&self.0).finish()
@@ -9020,6 +9190,7 @@ pub struct MENUBANDSTATES(pub i32);
 impl windows_core::TypeKind for MENUBANDSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MENUBANDSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MENUBANDSTATES").field(&self.0).finish()
@@ -9031,6 +9202,7 @@ pub struct MENUPARTS(pub i32);
 impl windows_core::TypeKind for MENUPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MENUPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MENUPARTS").field(&self.0).finish()
@@ -9042,6 +9214,7 @@ pub struct MINBUTTONSTATES(pub i32);
 impl windows_core::TypeKind for MINBUTTONSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MINBUTTONSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MINBUTTONSTATES").field(&self.0).finish()
@@ -9053,6 +9226,7 @@ pub struct MINCAPTIONSTATES(pub i32);
 impl windows_core::TypeKind for MINCAPTIONSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MINCAPTIONSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MINCAPTIONSTATES").field(&self.0).finish()
@@ -9064,6 +9238,7 @@ pub struct MONTHCALPARTS(pub i32);
 impl windows_core::TypeKind for MONTHCALPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MONTHCALPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MONTHCALPARTS").field(&self.0).finish()
@@ -9075,6 +9250,7 @@ pub struct MONTH_CALDENDAR_MESSAGES_VIEW(pub u32);
 impl windows_core::TypeKind for MONTH_CALDENDAR_MESSAGES_VIEW {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MONTH_CALDENDAR_MESSAGES_VIEW {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MONTH_CALDENDAR_MESSAGES_VIEW").field(&self.0).finish()
@@ -9086,6 +9262,7 @@ pub struct MOREPROGRAMSARROWBACKSTATES(pub i32);
 impl windows_core::TypeKind for MOREPROGRAMSARROWBACKSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MOREPROGRAMSARROWBACKSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MOREPROGRAMSARROWBACKSTATES").field(&self.0).finish()
@@ -9097,6 +9274,7 @@ pub struct MOREPROGRAMSARROWSTATES(pub i32);
 impl windows_core::TypeKind for MOREPROGRAMSARROWSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MOREPROGRAMSARROWSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MOREPROGRAMSARROWSTATES").field(&self.0).finish()
@@ -9108,6 +9286,7 @@ pub struct MOREPROGRAMSTABSTATES(pub i32);
 impl windows_core::TypeKind for MOREPROGRAMSTABSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MOREPROGRAMSTABSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MOREPROGRAMSTABSTATES").field(&self.0).finish()
@@ -9119,6 +9298,7 @@ pub struct MOVESTATES(pub i32);
 impl windows_core::TypeKind for MOVESTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MOVESTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MOVESTATES").field(&self.0).finish()
@@ -9130,6 +9310,7 @@ pub struct NAVIGATIONPARTS(pub i32);
 impl windows_core::TypeKind for NAVIGATIONPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NAVIGATIONPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NAVIGATIONPARTS").field(&self.0).finish()
@@ -9141,6 +9322,7 @@ pub struct NAVNEXTSTATES(pub i32);
 impl windows_core::TypeKind for NAVNEXTSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NAVNEXTSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NAVNEXTSTATES").field(&self.0).finish()
@@ -9152,6 +9334,7 @@ pub struct NAVPREVSTATES(pub i32);
 impl windows_core::TypeKind for NAVPREVSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NAVPREVSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NAVPREVSTATES").field(&self.0).finish()
@@ -9163,6 +9346,7 @@ pub struct NAV_BACKBUTTONSTATES(pub i32);
 impl windows_core::TypeKind for NAV_BACKBUTTONSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NAV_BACKBUTTONSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NAV_BACKBUTTONSTATES").field(&self.0).finish()
@@ -9174,6 +9358,7 @@ pub struct NAV_FORWARDBUTTONSTATES(pub i32);
 impl windows_core::TypeKind for NAV_FORWARDBUTTONSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NAV_FORWARDBUTTONSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NAV_FORWARDBUTTONSTATES").field(&self.0).finish()
@@ -9185,6 +9370,7 @@ pub struct NAV_MENUBUTTONSTATES(pub i32);
 impl windows_core::TypeKind for NAV_MENUBUTTONSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NAV_MENUBUTTONSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NAV_MENUBUTTONSTATES").field(&self.0).finish()
@@ -9196,6 +9382,7 @@ pub struct NMCUSTOMDRAW_DRAW_STAGE(pub u32);
 impl windows_core::TypeKind for NMCUSTOMDRAW_DRAW_STAGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NMCUSTOMDRAW_DRAW_STAGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NMCUSTOMDRAW_DRAW_STAGE").field(&self.0).finish()
@@ -9207,6 +9394,7 @@ pub struct NMCUSTOMDRAW_DRAW_STATE_FLAGS(pub u32);
 impl windows_core::TypeKind for NMCUSTOMDRAW_DRAW_STATE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NMCUSTOMDRAW_DRAW_STATE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NMCUSTOMDRAW_DRAW_STATE_FLAGS").field(&self.0).finish()
@@ -9251,6 +9439,7 @@ pub struct NMDATETIMECHANGE_FLAGS(pub u32);
 impl windows_core::TypeKind for NMDATETIMECHANGE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NMDATETIMECHANGE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NMDATETIMECHANGE_FLAGS").field(&self.0).finish()
@@ -9262,6 +9451,7 @@ pub struct NMLVCUSTOMDRAW_ITEM_TYPE(pub u32);
 impl windows_core::TypeKind for NMLVCUSTOMDRAW_ITEM_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NMLVCUSTOMDRAW_ITEM_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NMLVCUSTOMDRAW_ITEM_TYPE").field(&self.0).finish()
@@ -9273,6 +9463,7 @@ pub struct NMLVEMPTYMARKUP_FLAGS(pub u32);
 impl windows_core::TypeKind for NMLVEMPTYMARKUP_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NMLVEMPTYMARKUP_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NMLVEMPTYMARKUP_FLAGS").field(&self.0).finish()
@@ -9284,6 +9475,7 @@ pub struct NMLVGETINFOTIP_FLAGS(pub u32);
 impl windows_core::TypeKind for NMLVGETINFOTIP_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NMLVGETINFOTIP_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NMLVGETINFOTIP_FLAGS").field(&self.0).finish()
@@ -9295,6 +9487,7 @@ pub struct NMPGCALCSIZE_FLAGS(pub u32);
 impl windows_core::TypeKind for NMPGCALCSIZE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NMPGCALCSIZE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NMPGCALCSIZE_FLAGS").field(&self.0).finish()
@@ -9306,6 +9499,7 @@ pub struct NMPGSCROLL_DIR(pub i32);
 impl windows_core::TypeKind for NMPGSCROLL_DIR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NMPGSCROLL_DIR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NMPGSCROLL_DIR").field(&self.0).finish()
@@ -9317,6 +9511,7 @@ pub struct NMPGSCROLL_KEYS(pub u16);
 impl windows_core::TypeKind for NMPGSCROLL_KEYS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NMPGSCROLL_KEYS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NMPGSCROLL_KEYS").field(&self.0).finish()
@@ -9361,6 +9556,7 @@ pub struct NMREBAR_MASK_FLAGS(pub u32);
 impl windows_core::TypeKind for NMREBAR_MASK_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NMREBAR_MASK_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NMREBAR_MASK_FLAGS").field(&self.0).finish()
@@ -9405,6 +9601,7 @@ pub struct NMTBDISPINFOW_MASK(pub u32);
 impl windows_core::TypeKind for NMTBDISPINFOW_MASK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NMTBDISPINFOW_MASK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NMTBDISPINFOW_MASK").field(&self.0).finish()
@@ -9449,6 +9646,7 @@ pub struct NMTBHOTITEM_FLAGS(pub u32);
 impl windows_core::TypeKind for NMTBHOTITEM_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NMTBHOTITEM_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NMTBHOTITEM_FLAGS").field(&self.0).finish()
@@ -9493,6 +9691,7 @@ pub struct NM_TREEVIEW_ACTION(pub u32);
 impl windows_core::TypeKind for NM_TREEVIEW_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NM_TREEVIEW_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NM_TREEVIEW_ACTION").field(&self.0).finish()
@@ -9537,6 +9736,7 @@ pub struct NONESTATES(pub i32);
 impl windows_core::TypeKind for NONESTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NONESTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NONESTATES").field(&self.0).finish()
@@ -9548,6 +9748,7 @@ pub struct NORMALGROUPCOLLAPSESTATES(pub i32);
 impl windows_core::TypeKind for NORMALGROUPCOLLAPSESTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NORMALGROUPCOLLAPSESTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NORMALGROUPCOLLAPSESTATES").field(&self.0).finish()
@@ -9559,6 +9760,7 @@ pub struct NORMALGROUPEXPANDSTATES(pub i32);
 impl windows_core::TypeKind for NORMALGROUPEXPANDSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NORMALGROUPEXPANDSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NORMALGROUPEXPANDSTATES").field(&self.0).finish()
@@ -9570,6 +9772,7 @@ pub struct ODA_FLAGS(pub u32);
 impl windows_core::TypeKind for ODA_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ODA_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ODA_FLAGS").field(&self.0).finish()
@@ -9581,6 +9784,7 @@ pub struct ODS_FLAGS(pub u32);
 impl windows_core::TypeKind for ODS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ODS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ODS_FLAGS").field(&self.0).finish()
@@ -9592,6 +9796,7 @@ pub struct OFFSETTYPE(pub i32);
 impl windows_core::TypeKind for OFFSETTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OFFSETTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OFFSETTYPE").field(&self.0).finish()
@@ -9603,6 +9808,7 @@ pub struct OPENBOXSTATES(pub i32);
 impl windows_core::TypeKind for OPENBOXSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPENBOXSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPENBOXSTATES").field(&self.0).finish()
@@ -9614,6 +9820,7 @@ pub struct OPEN_THEME_DATA_FLAGS(pub u32);
 impl windows_core::TypeKind for OPEN_THEME_DATA_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPEN_THEME_DATA_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPEN_THEME_DATA_FLAGS").field(&self.0).finish()
@@ -9658,6 +9865,7 @@ pub struct PAGEPARTS(pub i32);
 impl windows_core::TypeKind for PAGEPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PAGEPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PAGEPARTS").field(&self.0).finish()
@@ -9669,6 +9877,7 @@ pub struct POINTER_DEVICE_CURSOR_TYPE(pub i32);
 impl windows_core::TypeKind for POINTER_DEVICE_CURSOR_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for POINTER_DEVICE_CURSOR_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("POINTER_DEVICE_CURSOR_TYPE").field(&self.0).finish()
@@ -9680,6 +9889,7 @@ pub struct POINTER_DEVICE_TYPE(pub i32);
 impl windows_core::TypeKind for POINTER_DEVICE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for POINTER_DEVICE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("POINTER_DEVICE_TYPE").field(&self.0).finish()
@@ -9691,6 +9901,7 @@ pub struct POINTER_FEEDBACK_MODE(pub i32);
 impl windows_core::TypeKind for POINTER_FEEDBACK_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for POINTER_FEEDBACK_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("POINTER_FEEDBACK_MODE").field(&self.0).finish()
@@ -9702,6 +9913,7 @@ pub struct POPUPCHECKBACKGROUNDSTATES(pub i32);
 impl windows_core::TypeKind for POPUPCHECKBACKGROUNDSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for POPUPCHECKBACKGROUNDSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("POPUPCHECKBACKGROUNDSTATES").field(&self.0).finish()
@@ -9713,6 +9925,7 @@ pub struct POPUPCHECKSTATES(pub i32);
 impl windows_core::TypeKind for POPUPCHECKSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for POPUPCHECKSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("POPUPCHECKSTATES").field(&self.0).finish()
@@ -9724,6 +9937,7 @@ pub struct POPUPITEMFOCUSABLESTATES(pub i32);
 impl windows_core::TypeKind for POPUPITEMFOCUSABLESTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for POPUPITEMFOCUSABLESTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("POPUPITEMFOCUSABLESTATES").field(&self.0).finish()
@@ -9735,6 +9949,7 @@ pub struct POPUPITEMKBFOCUSSTATES(pub i32);
 impl windows_core::TypeKind for POPUPITEMKBFOCUSSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for POPUPITEMKBFOCUSSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("POPUPITEMKBFOCUSSTATES").field(&self.0).finish()
@@ -9746,6 +9961,7 @@ pub struct POPUPITEMSTATES(pub i32);
 impl windows_core::TypeKind for POPUPITEMSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for POPUPITEMSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("POPUPITEMSTATES").field(&self.0).finish()
@@ -9757,6 +9973,7 @@ pub struct POPUPSUBMENUHCHOTSTATES(pub i32);
 impl windows_core::TypeKind for POPUPSUBMENUHCHOTSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for POPUPSUBMENUHCHOTSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("POPUPSUBMENUHCHOTSTATES").field(&self.0).finish()
@@ -9768,6 +9985,7 @@ pub struct POPUPSUBMENUSTATES(pub i32);
 impl windows_core::TypeKind for POPUPSUBMENUSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for POPUPSUBMENUSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("POPUPSUBMENUSTATES").field(&self.0).finish()
@@ -9779,6 +9997,7 @@ pub struct PROGRESSPARTS(pub i32);
 impl windows_core::TypeKind for PROGRESSPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROGRESSPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROGRESSPARTS").field(&self.0).finish()
@@ -9790,6 +10009,7 @@ pub struct PROPERTYORIGIN(pub i32);
 impl windows_core::TypeKind for PROPERTYORIGIN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROPERTYORIGIN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROPERTYORIGIN").field(&self.0).finish()
@@ -9801,6 +10021,7 @@ pub struct PSPCB_MESSAGE(pub u32);
 impl windows_core::TypeKind for PSPCB_MESSAGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PSPCB_MESSAGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PSPCB_MESSAGE").field(&self.0).finish()
@@ -9812,6 +10033,7 @@ pub struct PUSHBUTTONDROPDOWNSTATES(pub i32);
 impl windows_core::TypeKind for PUSHBUTTONDROPDOWNSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PUSHBUTTONDROPDOWNSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PUSHBUTTONDROPDOWNSTATES").field(&self.0).finish()
@@ -9823,6 +10045,7 @@ pub struct PUSHBUTTONSTATES(pub i32);
 impl windows_core::TypeKind for PUSHBUTTONSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PUSHBUTTONSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PUSHBUTTONSTATES").field(&self.0).finish()
@@ -9834,6 +10057,7 @@ pub struct RADIOBUTTONSTATES(pub i32);
 impl windows_core::TypeKind for RADIOBUTTONSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RADIOBUTTONSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RADIOBUTTONSTATES").field(&self.0).finish()
@@ -9845,6 +10069,7 @@ pub struct READONLYSTATES(pub i32);
 impl windows_core::TypeKind for READONLYSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for READONLYSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("READONLYSTATES").field(&self.0).finish()
@@ -9856,6 +10081,7 @@ pub struct REBARPARTS(pub i32);
 impl windows_core::TypeKind for REBARPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for REBARPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("REBARPARTS").field(&self.0).finish()
@@ -9867,6 +10093,7 @@ pub struct RESTOREBUTTONSTATES(pub i32);
 impl windows_core::TypeKind for RESTOREBUTTONSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RESTOREBUTTONSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RESTOREBUTTONSTATES").field(&self.0).finish()
@@ -9878,6 +10105,7 @@ pub struct SCROLLBARPARTS(pub i32);
 impl windows_core::TypeKind for SCROLLBARPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCROLLBARPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCROLLBARPARTS").field(&self.0).finish()
@@ -9889,6 +10117,7 @@ pub struct SCROLLBARSTYLESTATES(pub i32);
 impl windows_core::TypeKind for SCROLLBARSTYLESTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCROLLBARSTYLESTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCROLLBARSTYLESTATES").field(&self.0).finish()
@@ -9900,6 +10129,7 @@ pub struct SECTIONTITLELINKSTATES(pub i32);
 impl windows_core::TypeKind for SECTIONTITLELINKSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SECTIONTITLELINKSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SECTIONTITLELINKSTATES").field(&self.0).finish()
@@ -9911,6 +10141,7 @@ pub struct SET_THEME_APP_PROPERTIES_FLAGS(pub u32);
 impl windows_core::TypeKind for SET_THEME_APP_PROPERTIES_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SET_THEME_APP_PROPERTIES_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SET_THEME_APP_PROPERTIES_FLAGS").field(&self.0).finish()
@@ -9955,6 +10186,7 @@ pub struct SHOWCALENDARBUTTONRIGHTSTATES(pub i32);
 impl windows_core::TypeKind for SHOWCALENDARBUTTONRIGHTSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHOWCALENDARBUTTONRIGHTSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHOWCALENDARBUTTONRIGHTSTATES").field(&self.0).finish()
@@ -9966,6 +10198,7 @@ pub struct SIZEBOXSTATES(pub i32);
 impl windows_core::TypeKind for SIZEBOXSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SIZEBOXSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SIZEBOXSTATES").field(&self.0).finish()
@@ -9977,6 +10210,7 @@ pub struct SIZINGTYPE(pub i32);
 impl windows_core::TypeKind for SIZINGTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SIZINGTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SIZINGTYPE").field(&self.0).finish()
@@ -9988,6 +10222,7 @@ pub struct SMALLCAPTIONSTATES(pub i32);
 impl windows_core::TypeKind for SMALLCAPTIONSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SMALLCAPTIONSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SMALLCAPTIONSTATES").field(&self.0).finish()
@@ -9999,6 +10234,7 @@ pub struct SMALLCLOSEBUTTONSTATES(pub i32);
 impl windows_core::TypeKind for SMALLCLOSEBUTTONSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SMALLCLOSEBUTTONSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SMALLCLOSEBUTTONSTATES").field(&self.0).finish()
@@ -10010,6 +10246,7 @@ pub struct SMALLFRAMEBOTTOMSTATES(pub i32);
 impl windows_core::TypeKind for SMALLFRAMEBOTTOMSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SMALLFRAMEBOTTOMSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SMALLFRAMEBOTTOMSTATES").field(&self.0).finish()
@@ -10021,6 +10258,7 @@ pub struct SMALLFRAMELEFTSTATES(pub i32);
 impl windows_core::TypeKind for SMALLFRAMELEFTSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SMALLFRAMELEFTSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SMALLFRAMELEFTSTATES").field(&self.0).finish()
@@ -10032,6 +10270,7 @@ pub struct SMALLFRAMERIGHTSTATES(pub i32);
 impl windows_core::TypeKind for SMALLFRAMERIGHTSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SMALLFRAMERIGHTSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SMALLFRAMERIGHTSTATES").field(&self.0).finish()
@@ -10043,6 +10282,7 @@ pub struct SOFTWAREEXPLORERSTATES(pub i32);
 impl windows_core::TypeKind for SOFTWAREEXPLORERSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SOFTWAREEXPLORERSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SOFTWAREEXPLORERSTATES").field(&self.0).finish()
@@ -10054,6 +10294,7 @@ pub struct SPECIALGROUPCOLLAPSESTATES(pub i32);
 impl windows_core::TypeKind for SPECIALGROUPCOLLAPSESTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPECIALGROUPCOLLAPSESTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPECIALGROUPCOLLAPSESTATES").field(&self.0).finish()
@@ -10065,6 +10306,7 @@ pub struct SPECIALGROUPEXPANDSTATES(pub i32);
 impl windows_core::TypeKind for SPECIALGROUPEXPANDSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPECIALGROUPEXPANDSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPECIALGROUPEXPANDSTATES").field(&self.0).finish()
@@ -10076,6 +10318,7 @@ pub struct SPINPARTS(pub i32);
 impl windows_core::TypeKind for SPINPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPINPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPINPARTS").field(&self.0).finish()
@@ -10087,6 +10330,7 @@ pub struct SPLITTERSTATES(pub i32);
 impl windows_core::TypeKind for SPLITTERSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPLITTERSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPLITTERSTATES").field(&self.0).finish()
@@ -10098,6 +10342,7 @@ pub struct SPLITTERVERTSTATES(pub i32);
 impl windows_core::TypeKind for SPLITTERVERTSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPLITTERVERTSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPLITTERVERTSTATES").field(&self.0).finish()
@@ -10109,6 +10354,7 @@ pub struct STANDARDSTATES(pub i32);
 impl windows_core::TypeKind for STANDARDSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STANDARDSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STANDARDSTATES").field(&self.0).finish()
@@ -10120,6 +10366,7 @@ pub struct STARTPANELPARTS(pub i32);
 impl windows_core::TypeKind for STARTPANELPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STARTPANELPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STARTPANELPARTS").field(&self.0).finish()
@@ -10131,6 +10378,7 @@ pub struct STATICPARTS(pub i32);
 impl windows_core::TypeKind for STATICPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STATICPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STATICPARTS").field(&self.0).finish()
@@ -10142,6 +10390,7 @@ pub struct STATUSPARTS(pub i32);
 impl windows_core::TypeKind for STATUSPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STATUSPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STATUSPARTS").field(&self.0).finish()
@@ -10153,6 +10402,7 @@ pub struct SYSBUTTONSTATES(pub i32);
 impl windows_core::TypeKind for SYSBUTTONSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYSBUTTONSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYSBUTTONSTATES").field(&self.0).finish()
@@ -10164,6 +10414,7 @@ pub struct SYSTEMCLOSEHCHOTSTATES(pub i32);
 impl windows_core::TypeKind for SYSTEMCLOSEHCHOTSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYSTEMCLOSEHCHOTSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYSTEMCLOSEHCHOTSTATES").field(&self.0).finish()
@@ -10175,6 +10426,7 @@ pub struct SYSTEMCLOSESTATES(pub i32);
 impl windows_core::TypeKind for SYSTEMCLOSESTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYSTEMCLOSESTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYSTEMCLOSESTATES").field(&self.0).finish()
@@ -10186,6 +10438,7 @@ pub struct SYSTEMMAXIMIZEHCHOTSTATES(pub i32);
 impl windows_core::TypeKind for SYSTEMMAXIMIZEHCHOTSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYSTEMMAXIMIZEHCHOTSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYSTEMMAXIMIZEHCHOTSTATES").field(&self.0).finish()
@@ -10197,6 +10450,7 @@ pub struct SYSTEMMAXIMIZESTATES(pub i32);
 impl windows_core::TypeKind for SYSTEMMAXIMIZESTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYSTEMMAXIMIZESTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYSTEMMAXIMIZESTATES").field(&self.0).finish()
@@ -10208,6 +10462,7 @@ pub struct SYSTEMMINIMIZEHCHOTSTATES(pub i32);
 impl windows_core::TypeKind for SYSTEMMINIMIZEHCHOTSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYSTEMMINIMIZEHCHOTSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYSTEMMINIMIZEHCHOTSTATES").field(&self.0).finish()
@@ -10219,6 +10474,7 @@ pub struct SYSTEMMINIMIZESTATES(pub i32);
 impl windows_core::TypeKind for SYSTEMMINIMIZESTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYSTEMMINIMIZESTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYSTEMMINIMIZESTATES").field(&self.0).finish()
@@ -10230,6 +10486,7 @@ pub struct SYSTEMRESTOREHCHOTSTATES(pub i32);
 impl windows_core::TypeKind for SYSTEMRESTOREHCHOTSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYSTEMRESTOREHCHOTSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYSTEMRESTOREHCHOTSTATES").field(&self.0).finish()
@@ -10241,6 +10498,7 @@ pub struct SYSTEMRESTORESTATES(pub i32);
 impl windows_core::TypeKind for SYSTEMRESTORESTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYSTEMRESTORESTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYSTEMRESTORESTATES").field(&self.0).finish()
@@ -10252,6 +10510,7 @@ pub struct TABITEMBOTHEDGESTATES(pub i32);
 impl windows_core::TypeKind for TABITEMBOTHEDGESTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TABITEMBOTHEDGESTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TABITEMBOTHEDGESTATES").field(&self.0).finish()
@@ -10263,6 +10522,7 @@ pub struct TABITEMLEFTEDGESTATES(pub i32);
 impl windows_core::TypeKind for TABITEMLEFTEDGESTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TABITEMLEFTEDGESTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TABITEMLEFTEDGESTATES").field(&self.0).finish()
@@ -10274,6 +10534,7 @@ pub struct TABITEMRIGHTEDGESTATES(pub i32);
 impl windows_core::TypeKind for TABITEMRIGHTEDGESTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TABITEMRIGHTEDGESTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TABITEMRIGHTEDGESTATES").field(&self.0).finish()
@@ -10285,6 +10546,7 @@ pub struct TABITEMSTATES(pub i32);
 impl windows_core::TypeKind for TABITEMSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TABITEMSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TABITEMSTATES").field(&self.0).finish()
@@ -10296,6 +10558,7 @@ pub struct TABPARTS(pub i32);
 impl windows_core::TypeKind for TABPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TABPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TABPARTS").field(&self.0).finish()
@@ -10307,6 +10570,7 @@ pub struct TABSTATES(pub i32);
 impl windows_core::TypeKind for TABSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TABSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TABSTATES").field(&self.0).finish()
@@ -10318,6 +10582,7 @@ pub struct TAB_CONTROL_ITEM_STATE(pub u32);
 impl windows_core::TypeKind for TAB_CONTROL_ITEM_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TAB_CONTROL_ITEM_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TAB_CONTROL_ITEM_STATE").field(&self.0).finish()
@@ -10329,6 +10594,7 @@ pub struct TASKBANDPARTS(pub i32);
 impl windows_core::TypeKind for TASKBANDPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TASKBANDPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TASKBANDPARTS").field(&self.0).finish()
@@ -10340,6 +10606,7 @@ pub struct TASKBARPARTS(pub i32);
 impl windows_core::TypeKind for TASKBARPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TASKBARPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TASKBARPARTS").field(&self.0).finish()
@@ -10351,6 +10618,7 @@ pub struct TASKDIALOGPARTS(pub i32);
 impl windows_core::TypeKind for TASKDIALOGPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TASKDIALOGPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TASKDIALOGPARTS").field(&self.0).finish()
@@ -10362,6 +10630,7 @@ pub struct TASKDIALOG_COMMON_BUTTON_FLAGS(pub i32);
 impl windows_core::TypeKind for TASKDIALOG_COMMON_BUTTON_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TASKDIALOG_COMMON_BUTTON_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TASKDIALOG_COMMON_BUTTON_FLAGS").field(&self.0).finish()
@@ -10406,6 +10675,7 @@ pub struct TASKDIALOG_ELEMENTS(pub i32);
 impl windows_core::TypeKind for TASKDIALOG_ELEMENTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TASKDIALOG_ELEMENTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TASKDIALOG_ELEMENTS").field(&self.0).finish()
@@ -10417,6 +10687,7 @@ pub struct TASKDIALOG_FLAGS(pub i32);
 impl windows_core::TypeKind for TASKDIALOG_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TASKDIALOG_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TASKDIALOG_FLAGS").field(&self.0).finish()
@@ -10461,6 +10732,7 @@ pub struct TASKDIALOG_ICON_ELEMENTS(pub i32);
 impl windows_core::TypeKind for TASKDIALOG_ICON_ELEMENTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TASKDIALOG_ICON_ELEMENTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TASKDIALOG_ICON_ELEMENTS").field(&self.0).finish()
@@ -10472,6 +10744,7 @@ pub struct TASKDIALOG_MESSAGES(pub i32);
 impl windows_core::TypeKind for TASKDIALOG_MESSAGES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TASKDIALOG_MESSAGES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TASKDIALOG_MESSAGES").field(&self.0).finish()
@@ -10483,6 +10756,7 @@ pub struct TASKDIALOG_NOTIFICATIONS(pub i32);
 impl windows_core::TypeKind for TASKDIALOG_NOTIFICATIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TASKDIALOG_NOTIFICATIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TASKDIALOG_NOTIFICATIONS").field(&self.0).finish()
@@ -10494,6 +10768,7 @@ pub struct TASKLINKSTATES(pub i32);
 impl windows_core::TypeKind for TASKLINKSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TASKLINKSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TASKLINKSTATES").field(&self.0).finish()
@@ -10505,6 +10780,7 @@ pub struct TA_PROPERTY(pub i32);
 impl windows_core::TypeKind for TA_PROPERTY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TA_PROPERTY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TA_PROPERTY").field(&self.0).finish()
@@ -10516,6 +10792,7 @@ pub struct TA_PROPERTY_FLAG(pub i32);
 impl windows_core::TypeKind for TA_PROPERTY_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TA_PROPERTY_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TA_PROPERTY_FLAG").field(&self.0).finish()
@@ -10560,6 +10837,7 @@ pub struct TA_TIMINGFUNCTION_TYPE(pub i32);
 impl windows_core::TypeKind for TA_TIMINGFUNCTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TA_TIMINGFUNCTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TA_TIMINGFUNCTION_TYPE").field(&self.0).finish()
@@ -10571,6 +10849,7 @@ pub struct TA_TRANSFORM_FLAG(pub i32);
 impl windows_core::TypeKind for TA_TRANSFORM_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TA_TRANSFORM_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TA_TRANSFORM_FLAG").field(&self.0).finish()
@@ -10615,6 +10894,7 @@ pub struct TA_TRANSFORM_TYPE(pub i32);
 impl windows_core::TypeKind for TA_TRANSFORM_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TA_TRANSFORM_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TA_TRANSFORM_TYPE").field(&self.0).finish()
@@ -10626,6 +10906,7 @@ pub struct TBBUTTONINFOW_MASK(pub u32);
 impl windows_core::TypeKind for TBBUTTONINFOW_MASK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TBBUTTONINFOW_MASK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TBBUTTONINFOW_MASK").field(&self.0).finish()
@@ -10670,6 +10951,7 @@ pub struct TBINSERTMARK_FLAGS(pub u32);
 impl windows_core::TypeKind for TBINSERTMARK_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TBINSERTMARK_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TBINSERTMARK_FLAGS").field(&self.0).finish()
@@ -10681,6 +10963,7 @@ pub struct TCHITTESTINFO_FLAGS(pub u32);
 impl windows_core::TypeKind for TCHITTESTINFO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TCHITTESTINFO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TCHITTESTINFO_FLAGS").field(&self.0).finish()
@@ -10692,6 +10975,7 @@ pub struct TCITEMHEADERA_MASK(pub u32);
 impl windows_core::TypeKind for TCITEMHEADERA_MASK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TCITEMHEADERA_MASK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TCITEMHEADERA_MASK").field(&self.0).finish()
@@ -10736,6 +11020,7 @@ pub struct TEXTSELECTIONGRIPPERPARTS(pub i32);
 impl windows_core::TypeKind for TEXTSELECTIONGRIPPERPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TEXTSELECTIONGRIPPERPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TEXTSELECTIONGRIPPERPARTS").field(&self.0).finish()
@@ -10747,6 +11032,7 @@ pub struct TEXTSHADOWTYPE(pub i32);
 impl windows_core::TypeKind for TEXTSHADOWTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TEXTSHADOWTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TEXTSHADOWTYPE").field(&self.0).finish()
@@ -10758,6 +11044,7 @@ pub struct TEXTSTYLEPARTS(pub i32);
 impl windows_core::TypeKind for TEXTSTYLEPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TEXTSTYLEPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TEXTSTYLEPARTS").field(&self.0).finish()
@@ -10769,6 +11056,7 @@ pub struct THEMESIZE(pub i32);
 impl windows_core::TypeKind for THEMESIZE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for THEMESIZE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("THEMESIZE").field(&self.0).finish()
@@ -10780,6 +11068,7 @@ pub struct THEME_PROPERTY_SYMBOL_ID(pub u32);
 impl windows_core::TypeKind for THEME_PROPERTY_SYMBOL_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for THEME_PROPERTY_SYMBOL_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("THEME_PROPERTY_SYMBOL_ID").field(&self.0).finish()
@@ -10791,6 +11080,7 @@ pub struct THUMBBOTTOMSTATES(pub i32);
 impl windows_core::TypeKind for THUMBBOTTOMSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for THUMBBOTTOMSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("THUMBBOTTOMSTATES").field(&self.0).finish()
@@ -10802,6 +11092,7 @@ pub struct THUMBLEFTSTATES(pub i32);
 impl windows_core::TypeKind for THUMBLEFTSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for THUMBLEFTSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("THUMBLEFTSTATES").field(&self.0).finish()
@@ -10813,6 +11104,7 @@ pub struct THUMBRIGHTSTATES(pub i32);
 impl windows_core::TypeKind for THUMBRIGHTSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for THUMBRIGHTSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("THUMBRIGHTSTATES").field(&self.0).finish()
@@ -10824,6 +11116,7 @@ pub struct THUMBSTATES(pub i32);
 impl windows_core::TypeKind for THUMBSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for THUMBSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("THUMBSTATES").field(&self.0).finish()
@@ -10835,6 +11128,7 @@ pub struct THUMBTOPSTATES(pub i32);
 impl windows_core::TypeKind for THUMBTOPSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for THUMBTOPSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("THUMBTOPSTATES").field(&self.0).finish()
@@ -10846,6 +11140,7 @@ pub struct THUMBVERTSTATES(pub i32);
 impl windows_core::TypeKind for THUMBVERTSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for THUMBVERTSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("THUMBVERTSTATES").field(&self.0).finish()
@@ -10857,6 +11152,7 @@ pub struct TICSSTATES(pub i32);
 impl windows_core::TypeKind for TICSSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TICSSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TICSSTATES").field(&self.0).finish()
@@ -10868,6 +11164,7 @@ pub struct TICSVERTSTATES(pub i32);
 impl windows_core::TypeKind for TICSVERTSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TICSVERTSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TICSVERTSTATES").field(&self.0).finish()
@@ -10879,6 +11176,7 @@ pub struct TITLEBARSTATES(pub i32);
 impl windows_core::TypeKind for TITLEBARSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TITLEBARSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TITLEBARSTATES").field(&self.0).finish()
@@ -10890,6 +11188,7 @@ pub struct TOOLBARPARTS(pub i32);
 impl windows_core::TypeKind for TOOLBARPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TOOLBARPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TOOLBARPARTS").field(&self.0).finish()
@@ -10901,6 +11200,7 @@ pub struct TOOLBARSTYLESTATES(pub i32);
 impl windows_core::TypeKind for TOOLBARSTYLESTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TOOLBARSTYLESTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TOOLBARSTYLESTATES").field(&self.0).finish()
@@ -10912,6 +11212,7 @@ pub struct TOOLTIPPARTS(pub i32);
 impl windows_core::TypeKind for TOOLTIPPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TOOLTIPPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TOOLTIPPARTS").field(&self.0).finish()
@@ -10923,6 +11224,7 @@ pub struct TOOLTIP_FLAGS(pub u32);
 impl windows_core::TypeKind for TOOLTIP_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TOOLTIP_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TOOLTIP_FLAGS").field(&self.0).finish()
@@ -10967,6 +11269,7 @@ pub struct TOPTABITEMBOTHEDGESTATES(pub i32);
 impl windows_core::TypeKind for TOPTABITEMBOTHEDGESTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TOPTABITEMBOTHEDGESTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TOPTABITEMBOTHEDGESTATES").field(&self.0).finish()
@@ -10978,6 +11281,7 @@ pub struct TOPTABITEMLEFTEDGESTATES(pub i32);
 impl windows_core::TypeKind for TOPTABITEMLEFTEDGESTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TOPTABITEMLEFTEDGESTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TOPTABITEMLEFTEDGESTATES").field(&self.0).finish()
@@ -10989,6 +11293,7 @@ pub struct TOPTABITEMRIGHTEDGESTATES(pub i32);
 impl windows_core::TypeKind for TOPTABITEMRIGHTEDGESTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TOPTABITEMRIGHTEDGESTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TOPTABITEMRIGHTEDGESTATES").field(&self.0).finish()
@@ -11000,6 +11305,7 @@ pub struct TOPTABITEMSTATES(pub i32);
 impl windows_core::TypeKind for TOPTABITEMSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TOPTABITEMSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TOPTABITEMSTATES").field(&self.0).finish()
@@ -11011,6 +11317,7 @@ pub struct TRACKBARPARTS(pub i32);
 impl windows_core::TypeKind for TRACKBARPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TRACKBARPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TRACKBARPARTS").field(&self.0).finish()
@@ -11022,6 +11329,7 @@ pub struct TRACKBARSTYLESTATES(pub i32);
 impl windows_core::TypeKind for TRACKBARSTYLESTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TRACKBARSTYLESTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TRACKBARSTYLESTATES").field(&self.0).finish()
@@ -11033,6 +11341,7 @@ pub struct TRACKSTATES(pub i32);
 impl windows_core::TypeKind for TRACKSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TRACKSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TRACKSTATES").field(&self.0).finish()
@@ -11044,6 +11353,7 @@ pub struct TRACKVERTSTATES(pub i32);
 impl windows_core::TypeKind for TRACKVERTSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TRACKVERTSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TRACKVERTSTATES").field(&self.0).finish()
@@ -11055,6 +11365,7 @@ pub struct TRAILINGGRIDCELLSTATES(pub i32);
 impl windows_core::TypeKind for TRAILINGGRIDCELLSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TRAILINGGRIDCELLSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TRAILINGGRIDCELLSTATES").field(&self.0).finish()
@@ -11066,6 +11377,7 @@ pub struct TRAILINGGRIDCELLUPPERSTATES(pub i32);
 impl windows_core::TypeKind for TRAILINGGRIDCELLUPPERSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TRAILINGGRIDCELLUPPERSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TRAILINGGRIDCELLUPPERSTATES").field(&self.0).finish()
@@ -11077,6 +11389,7 @@ pub struct TRANSPARENTBACKGROUNDSTATES(pub i32);
 impl windows_core::TypeKind for TRANSPARENTBACKGROUNDSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TRANSPARENTBACKGROUNDSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TRANSPARENTBACKGROUNDSTATES").field(&self.0).finish()
@@ -11088,6 +11401,7 @@ pub struct TRANSPARENTBARSTATES(pub i32);
 impl windows_core::TypeKind for TRANSPARENTBARSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TRANSPARENTBARSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TRANSPARENTBARSTATES").field(&self.0).finish()
@@ -11099,6 +11413,7 @@ pub struct TRANSPARENTBARVERTSTATES(pub i32);
 impl windows_core::TypeKind for TRANSPARENTBARVERTSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TRANSPARENTBARVERTSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TRANSPARENTBARVERTSTATES").field(&self.0).finish()
@@ -11110,6 +11425,7 @@ pub struct TRAYNOTIFYPARTS(pub i32);
 impl windows_core::TypeKind for TRAYNOTIFYPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TRAYNOTIFYPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TRAYNOTIFYPARTS").field(&self.0).finish()
@@ -11121,6 +11437,7 @@ pub struct TREEITEMSTATES(pub i32);
 impl windows_core::TypeKind for TREEITEMSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TREEITEMSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TREEITEMSTATES").field(&self.0).finish()
@@ -11132,6 +11449,7 @@ pub struct TREEVIEWPARTS(pub i32);
 impl windows_core::TypeKind for TREEVIEWPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TREEVIEWPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TREEVIEWPARTS").field(&self.0).finish()
@@ -11143,6 +11461,7 @@ pub struct TREE_VIEW_ITEM_STATE_FLAGS(pub u32);
 impl windows_core::TypeKind for TREE_VIEW_ITEM_STATE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TREE_VIEW_ITEM_STATE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TREE_VIEW_ITEM_STATE_FLAGS").field(&self.0).finish()
@@ -11154,6 +11473,7 @@ pub struct TRUESIZESCALINGTYPE(pub i32);
 impl windows_core::TypeKind for TRUESIZESCALINGTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TRUESIZESCALINGTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TRUESIZESCALINGTYPE").field(&self.0).finish()
@@ -11165,6 +11485,7 @@ pub struct TVHITTESTINFO_FLAGS(pub u32);
 impl windows_core::TypeKind for TVHITTESTINFO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TVHITTESTINFO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TVHITTESTINFO_FLAGS").field(&self.0).finish()
@@ -11209,6 +11530,7 @@ pub struct TVITEMEXW_CHILDREN(pub i32);
 impl windows_core::TypeKind for TVITEMEXW_CHILDREN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TVITEMEXW_CHILDREN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TVITEMEXW_CHILDREN").field(&self.0).finish()
@@ -11220,6 +11542,7 @@ pub struct TVITEMPART(pub i32);
 impl windows_core::TypeKind for TVITEMPART {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TVITEMPART {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TVITEMPART").field(&self.0).finish()
@@ -11231,6 +11554,7 @@ pub struct TVITEM_MASK(pub u32);
 impl windows_core::TypeKind for TVITEM_MASK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TVITEM_MASK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TVITEM_MASK").field(&self.0).finish()
@@ -11275,6 +11599,7 @@ pub struct UPDATEMETADATASTATES(pub i32);
 impl windows_core::TypeKind for UPDATEMETADATASTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UPDATEMETADATASTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UPDATEMETADATASTATES").field(&self.0).finish()
@@ -11286,6 +11611,7 @@ pub struct UPHORZSTATES(pub i32);
 impl windows_core::TypeKind for UPHORZSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UPHORZSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UPHORZSTATES").field(&self.0).finish()
@@ -11297,6 +11623,7 @@ pub struct UPSTATES(pub i32);
 impl windows_core::TypeKind for UPSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UPSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UPSTATES").field(&self.0).finish()
@@ -11308,6 +11635,7 @@ pub struct USERTILEPARTS(pub i32);
 impl windows_core::TypeKind for USERTILEPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for USERTILEPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("USERTILEPARTS").field(&self.0).finish()
@@ -11319,6 +11647,7 @@ pub struct VALIGN(pub i32);
 impl windows_core::TypeKind for VALIGN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VALIGN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VALIGN").field(&self.0).finish()
@@ -11330,6 +11659,7 @@ pub struct VERTSCROLLSTATES(pub i32);
 impl windows_core::TypeKind for VERTSCROLLSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VERTSCROLLSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VERTSCROLLSTATES").field(&self.0).finish()
@@ -11341,6 +11671,7 @@ pub struct VERTTHUMBSTATES(pub i32);
 impl windows_core::TypeKind for VERTTHUMBSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VERTTHUMBSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VERTTHUMBSTATES").field(&self.0).finish()
@@ -11352,6 +11683,7 @@ pub struct WARNINGSTATES(pub i32);
 impl windows_core::TypeKind for WARNINGSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WARNINGSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WARNINGSTATES").field(&self.0).finish()
@@ -11363,6 +11695,7 @@ pub struct WINDOWPARTS(pub i32);
 impl windows_core::TypeKind for WINDOWPARTS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WINDOWPARTS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WINDOWPARTS").field(&self.0).finish()
@@ -11374,6 +11707,7 @@ pub struct WINDOWTHEMEATTRIBUTETYPE(pub i32);
 impl windows_core::TypeKind for WINDOWTHEMEATTRIBUTETYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WINDOWTHEMEATTRIBUTETYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WINDOWTHEMEATTRIBUTETYPE").field(&self.0).finish()
@@ -11385,6 +11719,7 @@ pub struct WORD_BREAK_ACTION(pub i32);
 impl windows_core::TypeKind for WORD_BREAK_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WORD_BREAK_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WORD_BREAK_ACTION").field(&self.0).finish()
@@ -11396,6 +11731,7 @@ pub struct WRENCHSTATES(pub i32);
 impl windows_core::TypeKind for WRENCHSTATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WRENCHSTATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WRENCHSTATES").field(&self.0).finish()
@@ -11407,6 +11743,7 @@ pub struct WSB_PROP(pub i32);
 impl windows_core::TypeKind for WSB_PROP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WSB_PROP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WSB_PROP").field(&self.0).finish()
@@ -11418,13 +11755,15 @@ pub struct _LI_METRIC(pub i32);
 impl windows_core::TypeKind for _LI_METRIC {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _LI_METRIC {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_LI_METRIC").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BP_ANIMATIONPARAMS {
     pub cbSize: u32,
     pub dwFlags: u32,
@@ -11441,7 +11780,8 @@ impl Default for BP_ANIMATIONPARAMS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BP_PAINTPARAMS {
     pub cbSize: u32,
     pub dwFlags: BP_PAINTPARAMS_FLAGS,
@@ -11459,7 +11799,8 @@ impl Default for BP_PAINTPARAMS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BUTTON_IMAGELIST {
     pub himl: HIMAGELIST,
     pub margin: super::super::Foundation::RECT,
@@ -11474,7 +11815,8 @@ impl Default for BUTTON_IMAGELIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BUTTON_SPLITINFO {
     pub mask: u32,
     pub himlGlyph: HIMAGELIST,
@@ -11491,7 +11833,8 @@ impl Default for BUTTON_SPLITINFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CCINFOA {
     pub szClass: [i8; 32],
     pub flOptions: u32,
@@ -11521,7 +11864,8 @@ impl Default for CCINFOA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CCINFOW {
     pub szClass: [u16; 32],
     pub flOptions: u32,
@@ -11550,7 +11894,8 @@ impl Default for CCINFOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CCSTYLEA {
     pub flStyle: u32,
     pub flExtStyle: u32,
@@ -11567,7 +11912,8 @@ impl Default for CCSTYLEA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CCSTYLEFLAGA {
     pub flStyle: u32,
     pub flStyleMask: u32,
@@ -11582,7 +11928,8 @@ impl Default for CCSTYLEFLAGA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CCSTYLEFLAGW {
     pub flStyle: u32,
     pub flStyleMask: u32,
@@ -11597,7 +11944,8 @@ impl Default for CCSTYLEFLAGW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CCSTYLEW {
     pub flStyle: u32,
     pub flExtStyle: u32,
@@ -11614,7 +11962,8 @@ impl Default for CCSTYLEW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COLORMAP {
     pub from: super::super::Foundation::COLORREF,
     pub to: super::super::Foundation::COLORREF,
@@ -11628,7 +11977,8 @@ impl Default for COLORMAP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COLORSCHEME {
     pub dwSize: u32,
     pub clrBtnHighlight: super::super::Foundation::COLORREF,
@@ -11643,7 +11993,8 @@ impl Default for COLORSCHEME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COMBOBOXEXITEMA {
     pub mask: COMBOBOX_EX_ITEM_FLAGS,
     pub iItem: isize,
@@ -11664,7 +12015,8 @@ impl Default for COMBOBOXEXITEMA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COMBOBOXEXITEMW {
     pub mask: COMBOBOX_EX_ITEM_FLAGS,
     pub iItem: isize,
@@ -11685,7 +12037,8 @@ impl Default for COMBOBOXEXITEMW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COMBOBOXINFO {
     pub cbSize: u32,
     pub rcItem: super::super::Foundation::RECT,
@@ -11704,7 +12057,8 @@ impl Default for COMBOBOXINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COMPAREITEMSTRUCT {
     pub CtlType: DRAWITEMSTRUCT_CTL_TYPE,
     pub CtlID: u32,
@@ -11724,7 +12078,8 @@ impl Default for COMPAREITEMSTRUCT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DATETIMEPICKERINFO {
     pub cbSize: u32,
     pub rcCheck: super::super::Foundation::RECT,
@@ -11744,7 +12099,8 @@ impl Default for DATETIMEPICKERINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DELETEITEMSTRUCT {
     pub CtlType: DRAWITEMSTRUCT_CTL_TYPE,
     pub CtlID: u32,
@@ -11761,7 +12117,8 @@ impl Default for DELETEITEMSTRUCT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DPASTREAMINFO {
     pub iPos: i32,
     pub pvItem: *mut core::ffi::c_void,
@@ -11775,7 +12132,8 @@ impl Default for DPASTREAMINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRAGLISTINFO {
     pub uNotification: DRAGLISTINFO_NOTIFICATION_FLAGS,
     pub hWnd: super::super::Foundation::HWND,
@@ -11791,7 +12149,8 @@ impl Default for DRAGLISTINFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DRAWITEMSTRUCT {
     pub CtlType: DRAWITEMSTRUCT_CTL_TYPE,
     pub CtlID: u32,
@@ -11814,7 +12173,8 @@ impl Default for DRAWITEMSTRUCT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DTBGOPTS {
     pub dwSize: u32,
     pub dwFlags: u32,
@@ -11830,7 +12190,8 @@ impl Default for DTBGOPTS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DTTOPTS {
     pub dwSize: u32,
     pub dwFlags: DTTOPTS_FLAGS,
@@ -11859,7 +12220,8 @@ impl Default for DTTOPTS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EDITBALLOONTIP {
     pub cbStruct: u32,
     pub pszTitle: windows_core::PCWSTR,
@@ -11875,7 +12237,8 @@ impl Default for EDITBALLOONTIP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HDHITTESTINFO {
     pub pt: super::super::Foundation::POINT,
     pub flags: HEADER_HITTEST_INFO_FLAGS,
@@ -11891,7 +12254,8 @@ impl Default for HDHITTESTINFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HDITEMA {
     pub mask: HDI_MASK,
     pub cxy: i32,
@@ -11918,7 +12282,8 @@ impl Default for HDITEMA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HDITEMW {
     pub mask: HDI_MASK,
     pub cxy: i32,
@@ -11945,7 +12310,8 @@ impl Default for HDITEMW {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HDLAYOUT {
     pub prc: *mut super::super::Foundation::RECT,
     pub pwpos: *mut super::WindowsAndMessaging::WINDOWPOS,
@@ -11961,7 +12327,8 @@ impl Default for HDLAYOUT {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HDPA(pub isize);
 impl HDPA {
     pub fn is_invalid(&self) -> bool {
@@ -11985,7 +12352,8 @@ impl windows_core::TypeKind for HDPA {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HDSA(pub isize);
 impl HDSA {
     pub fn is_invalid(&self) -> bool {
@@ -12009,7 +12377,8 @@ impl windows_core::TypeKind for HDSA {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HD_TEXTFILTERA {
     pub pszText: windows_core::PSTR,
     pub cchTextMax: i32,
@@ -12023,7 +12392,8 @@ impl Default for HD_TEXTFILTERA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HD_TEXTFILTERW {
     pub pszText: windows_core::PWSTR,
     pub cchTextMax: i32,
@@ -12037,7 +12407,8 @@ impl Default for HD_TEXTFILTERW {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HIMAGELIST(pub isize);
 impl HIMAGELIST {
     pub fn is_invalid(&self) -> bool {
@@ -12061,7 +12432,8 @@ impl windows_core::TypeKind for HIMAGELIST {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HPROPSHEETPAGE(pub *mut core::ffi::c_void);
 impl HPROPSHEETPAGE {
     pub fn is_invalid(&self) -> bool {
@@ -12085,7 +12457,8 @@ impl windows_core::TypeKind for HPROPSHEETPAGE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HSYNTHETICPOINTERDEVICE(pub *mut core::ffi::c_void);
 impl HSYNTHETICPOINTERDEVICE {
     pub fn is_invalid(&self) -> bool {
@@ -12109,7 +12482,8 @@ impl windows_core::TypeKind for HSYNTHETICPOINTERDEVICE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HTHEME(pub isize);
 impl HTHEME {
     pub fn is_invalid(&self) -> bool {
@@ -12133,7 +12507,8 @@ impl windows_core::TypeKind for HTHEME {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HTREEITEM(pub isize);
 impl Default for HTREEITEM {
     fn default() -> Self {
@@ -12145,7 +12520,8 @@ impl windows_core::TypeKind for HTREEITEM {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGEINFO {
     pub hbmImage: super::super::Graphics::Gdi::HBITMAP,
     pub hbmMask: super::super::Graphics::Gdi::HBITMAP,
@@ -12165,7 +12541,8 @@ impl Default for IMAGEINFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGELISTDRAWPARAMS {
     pub cbSize: u32,
     pub himl: HIMAGELIST,
@@ -12196,7 +12573,8 @@ impl Default for IMAGELISTDRAWPARAMS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IMAGELISTSTATS {
     pub cbSize: u32,
     pub cAlloc: i32,
@@ -12212,7 +12590,8 @@ impl Default for IMAGELISTSTATS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct INITCOMMONCONTROLSEX {
     pub dwSize: u32,
     pub dwICC: INITCOMMONCONTROLSEX_ICC,
@@ -12226,7 +12605,8 @@ impl Default for INITCOMMONCONTROLSEX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct INTLIST {
     pub iValueCount: i32,
     pub iValues: [i32; 402],
@@ -12241,7 +12621,8 @@ impl Default for INTLIST {
 }
 pub const ImageList: windows_core::GUID = windows_core::GUID::from_u128(0x7c476ba2_02b1_48f4_8048_b24619ddc058);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LHITTESTINFO {
     pub pt: super::super::Foundation::POINT,
     pub item: LITEM,
@@ -12255,7 +12636,8 @@ impl Default for LHITTESTINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LITEM {
     pub mask: LIST_ITEM_FLAGS,
     pub iLink: i32,
@@ -12274,7 +12656,8 @@ impl Default for LITEM {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LVBKIMAGEA {
     pub ulFlags: LIST_VIEW_BACKGROUND_IMAGE_FLAGS,
     pub hbm: super::super::Graphics::Gdi::HBITMAP,
@@ -12295,7 +12678,8 @@ impl Default for LVBKIMAGEA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LVBKIMAGEW {
     pub ulFlags: LIST_VIEW_BACKGROUND_IMAGE_FLAGS,
     pub hbm: super::super::Graphics::Gdi::HBITMAP,
@@ -12315,7 +12699,8 @@ impl Default for LVBKIMAGEW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LVCOLUMNA {
     pub mask: LVCOLUMNW_MASK,
     pub fmt: LVCOLUMNW_FORMAT,
@@ -12338,7 +12723,8 @@ impl Default for LVCOLUMNA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LVCOLUMNW {
     pub mask: LVCOLUMNW_MASK,
     pub fmt: LVCOLUMNW_FORMAT,
@@ -12361,7 +12747,8 @@ impl Default for LVCOLUMNW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LVFINDINFOA {
     pub flags: LVFINDINFOW_FLAGS,
     pub psz: windows_core::PCSTR,
@@ -12378,7 +12765,8 @@ impl Default for LVFINDINFOA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LVFINDINFOW {
     pub flags: LVFINDINFOW_FLAGS,
     pub psz: windows_core::PCWSTR,
@@ -12395,7 +12783,8 @@ impl Default for LVFINDINFOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LVFOOTERINFO {
     pub mask: u32,
     pub pszText: windows_core::PWSTR,
@@ -12411,7 +12800,8 @@ impl Default for LVFOOTERINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LVFOOTERITEM {
     pub mask: LVFOOTERITEM_MASK,
     pub iItem: i32,
@@ -12429,7 +12819,8 @@ impl Default for LVFOOTERITEM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LVGROUP {
     pub cbSize: u32,
     pub mask: LVGROUP_MASK,
@@ -12465,7 +12856,8 @@ impl Default for LVGROUP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LVGROUPMETRICS {
     pub cbSize: u32,
     pub mask: u32,
@@ -12489,7 +12881,8 @@ impl Default for LVGROUPMETRICS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LVHITTESTINFO {
     pub pt: super::super::Foundation::POINT,
     pub flags: LVHITTESTINFO_FLAGS,
@@ -12506,7 +12899,8 @@ impl Default for LVHITTESTINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LVINSERTGROUPSORTED {
     pub pfnGroupCompare: PFNLVGROUPCOMPARE,
     pub pvData: *mut core::ffi::c_void,
@@ -12521,7 +12915,8 @@ impl Default for LVINSERTGROUPSORTED {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LVINSERTMARK {
     pub cbSize: u32,
     pub dwFlags: u32,
@@ -12537,7 +12932,8 @@ impl Default for LVINSERTMARK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LVITEMA {
     pub mask: LIST_VIEW_ITEM_FLAGS,
     pub iItem: i32,
@@ -12564,7 +12960,8 @@ impl Default for LVITEMA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LVITEMINDEX {
     pub iItem: i32,
     pub iGroup: i32,
@@ -12578,7 +12975,8 @@ impl Default for LVITEMINDEX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LVITEMW {
     pub mask: LIST_VIEW_ITEM_FLAGS,
     pub iItem: i32,
@@ -12605,7 +13003,8 @@ impl Default for LVITEMW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LVSETINFOTIP {
     pub cbSize: u32,
     pub dwFlags: u32,
@@ -12622,7 +13021,8 @@ impl Default for LVSETINFOTIP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LVTILEINFO {
     pub cbSize: u32,
     pub iItem: i32,
@@ -12639,7 +13039,8 @@ impl Default for LVTILEINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LVTILEVIEWINFO {
     pub cbSize: u32,
     pub dwMask: LVTILEVIEWINFO_MASK,
@@ -12657,7 +13058,8 @@ impl Default for LVTILEVIEWINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MARGINS {
     pub cxLeftWidth: i32,
     pub cxRightWidth: i32,
@@ -12673,7 +13075,8 @@ impl Default for MARGINS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MCGRIDINFO {
     pub cbSize: u32,
     pub dwPart: MCGRIDINFO_PART,
@@ -12697,7 +13100,8 @@ impl Default for MCGRIDINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MCHITTESTINFO {
     pub cbSize: u32,
     pub pt: super::super::Foundation::POINT,
@@ -12717,7 +13121,8 @@ impl Default for MCHITTESTINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MEASUREITEMSTRUCT {
     pub CtlType: DRAWITEMSTRUCT_CTL_TYPE,
     pub CtlID: u32,
@@ -12735,7 +13140,8 @@ impl Default for MEASUREITEMSTRUCT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMBCDROPDOWN {
     pub hdr: NMHDR,
     pub rcButton: super::super::Foundation::RECT,
@@ -12749,7 +13155,8 @@ impl Default for NMBCDROPDOWN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMBCHOTITEM {
     pub hdr: NMHDR,
     pub dwFlags: NMTBHOTITEM_FLAGS,
@@ -12763,7 +13170,8 @@ impl Default for NMBCHOTITEM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMCBEDRAGBEGINA {
     pub hdr: NMHDR,
     pub iItemid: i32,
@@ -12778,7 +13186,8 @@ impl Default for NMCBEDRAGBEGINA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMCBEDRAGBEGINW {
     pub hdr: NMHDR,
     pub iItemid: i32,
@@ -12793,7 +13202,8 @@ impl Default for NMCBEDRAGBEGINW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMCBEENDEDITA {
     pub hdr: NMHDR,
     pub fChanged: super::super::Foundation::BOOL,
@@ -12810,7 +13220,8 @@ impl Default for NMCBEENDEDITA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMCBEENDEDITW {
     pub hdr: NMHDR,
     pub fChanged: super::super::Foundation::BOOL,
@@ -12827,7 +13238,8 @@ impl Default for NMCBEENDEDITW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMCHAR {
     pub hdr: NMHDR,
     pub ch: u32,
@@ -12843,7 +13255,8 @@ impl Default for NMCHAR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMCOMBOBOXEXA {
     pub hdr: NMHDR,
     pub ceItem: COMBOBOXEXITEMA,
@@ -12857,7 +13270,8 @@ impl Default for NMCOMBOBOXEXA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMCOMBOBOXEXW {
     pub hdr: NMHDR,
     pub ceItem: COMBOBOXEXITEMW,
@@ -12872,7 +13286,8 @@ impl Default for NMCOMBOBOXEXW {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMCUSTOMDRAW {
     pub hdr: NMHDR,
     pub dwDrawStage: NMCUSTOMDRAW_DRAW_STAGE,
@@ -12893,7 +13308,8 @@ impl Default for NMCUSTOMDRAW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMCUSTOMSPLITRECTINFO {
     pub hdr: NMHDR,
     pub rcClient: super::super::Foundation::RECT,
@@ -12910,7 +13326,8 @@ impl Default for NMCUSTOMSPLITRECTINFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMCUSTOMTEXT {
     pub hdr: NMHDR,
     pub hDC: super::super::Graphics::Gdi::HDC,
@@ -12931,7 +13348,8 @@ impl Default for NMCUSTOMTEXT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMDATETIMECHANGE {
     pub nmhdr: NMHDR,
     pub dwFlags: NMDATETIMECHANGE_FLAGS,
@@ -12946,7 +13364,8 @@ impl Default for NMDATETIMECHANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMDATETIMEFORMATA {
     pub nmhdr: NMHDR,
     pub pszFormat: windows_core::PCSTR,
@@ -12963,7 +13382,8 @@ impl Default for NMDATETIMEFORMATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMDATETIMEFORMATQUERYA {
     pub nmhdr: NMHDR,
     pub pszFormat: windows_core::PCSTR,
@@ -12978,7 +13398,8 @@ impl Default for NMDATETIMEFORMATQUERYA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMDATETIMEFORMATQUERYW {
     pub nmhdr: NMHDR,
     pub pszFormat: windows_core::PCWSTR,
@@ -12993,7 +13414,8 @@ impl Default for NMDATETIMEFORMATQUERYW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMDATETIMEFORMATW {
     pub nmhdr: NMHDR,
     pub pszFormat: windows_core::PCWSTR,
@@ -13010,7 +13432,8 @@ impl Default for NMDATETIMEFORMATW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMDATETIMESTRINGA {
     pub nmhdr: NMHDR,
     pub pszUserString: windows_core::PCSTR,
@@ -13026,7 +13449,8 @@ impl Default for NMDATETIMESTRINGA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMDATETIMESTRINGW {
     pub nmhdr: NMHDR,
     pub pszUserString: windows_core::PCWSTR,
@@ -13042,7 +13466,8 @@ impl Default for NMDATETIMESTRINGW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMDATETIMEWMKEYDOWNA {
     pub nmhdr: NMHDR,
     pub nVirtKey: i32,
@@ -13058,7 +13483,8 @@ impl Default for NMDATETIMEWMKEYDOWNA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMDATETIMEWMKEYDOWNW {
     pub nmhdr: NMHDR,
     pub nVirtKey: i32,
@@ -13074,7 +13500,8 @@ impl Default for NMDATETIMEWMKEYDOWNW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMDAYSTATE {
     pub nmhdr: NMHDR,
     pub stStart: super::super::Foundation::SYSTEMTIME,
@@ -13090,7 +13517,8 @@ impl Default for NMDAYSTATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMHDDISPINFOA {
     pub hdr: NMHDR,
     pub iItem: i32,
@@ -13109,7 +13537,8 @@ impl Default for NMHDDISPINFOA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMHDDISPINFOW {
     pub hdr: NMHDR,
     pub iItem: i32,
@@ -13128,7 +13557,8 @@ impl Default for NMHDDISPINFOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMHDFILTERBTNCLICK {
     pub hdr: NMHDR,
     pub iItem: i32,
@@ -13143,7 +13573,8 @@ impl Default for NMHDFILTERBTNCLICK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMHDR {
     pub hwndFrom: super::super::Foundation::HWND,
     pub idFrom: usize,
@@ -13159,7 +13590,8 @@ impl Default for NMHDR {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMHEADERA {
     pub hdr: NMHDR,
     pub iItem: i32,
@@ -13178,7 +13610,8 @@ impl Default for NMHEADERA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMHEADERW {
     pub hdr: NMHDR,
     pub iItem: i32,
@@ -13196,7 +13629,8 @@ impl Default for NMHEADERW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMIPADDRESS {
     pub hdr: NMHDR,
     pub iField: i32,
@@ -13211,7 +13645,8 @@ impl Default for NMIPADDRESS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMITEMACTIVATE {
     pub hdr: NMHDR,
     pub iItem: i32,
@@ -13232,7 +13667,8 @@ impl Default for NMITEMACTIVATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMKEY {
     pub hdr: NMHDR,
     pub nVKey: u32,
@@ -13247,7 +13683,8 @@ impl Default for NMKEY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMLINK {
     pub hdr: NMHDR,
     pub item: LITEM,
@@ -13261,7 +13698,8 @@ impl Default for NMLINK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMLISTVIEW {
     pub hdr: NMHDR,
     pub iItem: i32,
@@ -13281,7 +13719,8 @@ impl Default for NMLISTVIEW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMLVCACHEHINT {
     pub hdr: NMHDR,
     pub iFrom: i32,
@@ -13297,7 +13736,8 @@ impl Default for NMLVCACHEHINT {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMLVCUSTOMDRAW {
     pub nmcd: NMCUSTOMDRAW,
     pub clrText: super::super::Foundation::COLORREF,
@@ -13323,7 +13763,8 @@ impl Default for NMLVCUSTOMDRAW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMLVDISPINFOA {
     pub hdr: NMHDR,
     pub item: LVITEMA,
@@ -13337,7 +13778,8 @@ impl Default for NMLVDISPINFOA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMLVDISPINFOW {
     pub hdr: NMHDR,
     pub item: LVITEMW,
@@ -13351,7 +13793,8 @@ impl Default for NMLVDISPINFOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMLVEMPTYMARKUP {
     pub hdr: NMHDR,
     pub dwFlags: NMLVEMPTYMARKUP_FLAGS,
@@ -13366,7 +13809,8 @@ impl Default for NMLVEMPTYMARKUP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMLVFINDITEMA {
     pub hdr: NMHDR,
     pub iStart: i32,
@@ -13381,7 +13825,8 @@ impl Default for NMLVFINDITEMA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMLVFINDITEMW {
     pub hdr: NMHDR,
     pub iStart: i32,
@@ -13396,7 +13841,8 @@ impl Default for NMLVFINDITEMW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMLVGETINFOTIPA {
     pub hdr: NMHDR,
     pub dwFlags: NMLVGETINFOTIP_FLAGS,
@@ -13415,7 +13861,8 @@ impl Default for NMLVGETINFOTIPA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMLVGETINFOTIPW {
     pub hdr: NMHDR,
     pub dwFlags: NMLVGETINFOTIP_FLAGS,
@@ -13449,7 +13896,8 @@ impl Default for NMLVKEYDOWN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMLVLINK {
     pub hdr: NMHDR,
     pub link: LITEM,
@@ -13465,7 +13913,8 @@ impl Default for NMLVLINK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMLVODSTATECHANGE {
     pub hdr: NMHDR,
     pub iFrom: i32,
@@ -13482,7 +13931,8 @@ impl Default for NMLVODSTATECHANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMLVSCROLL {
     pub hdr: NMHDR,
     pub dx: i32,
@@ -13497,7 +13947,8 @@ impl Default for NMLVSCROLL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMMOUSE {
     pub hdr: NMHDR,
     pub dwItemSpec: usize,
@@ -13514,7 +13965,8 @@ impl Default for NMMOUSE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMOBJECTNOTIFY {
     pub hdr: NMHDR,
     pub iItem: i32,
@@ -13532,7 +13984,8 @@ impl Default for NMOBJECTNOTIFY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMPGCALCSIZE {
     pub hdr: NMHDR,
     pub dwFlag: NMPGCALCSIZE_FLAGS,
@@ -13548,7 +14001,8 @@ impl Default for NMPGCALCSIZE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMPGHOTITEM {
     pub hdr: NMHDR,
     pub idOld: i32,
@@ -13583,7 +14037,8 @@ impl Default for NMPGSCROLL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMRBAUTOSIZE {
     pub hdr: NMHDR,
     pub fChanged: super::super::Foundation::BOOL,
@@ -13599,7 +14054,8 @@ impl Default for NMRBAUTOSIZE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMREBAR {
     pub hdr: NMHDR,
     pub dwMask: NMREBAR_MASK_FLAGS,
@@ -13617,7 +14073,8 @@ impl Default for NMREBAR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMREBARAUTOBREAK {
     pub hdr: NMHDR,
     pub uBand: u32,
@@ -13636,7 +14093,8 @@ impl Default for NMREBARAUTOBREAK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMREBARCHEVRON {
     pub hdr: NMHDR,
     pub uBand: u32,
@@ -13654,7 +14112,8 @@ impl Default for NMREBARCHEVRON {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMREBARCHILDSIZE {
     pub hdr: NMHDR,
     pub uBand: u32,
@@ -13671,7 +14130,8 @@ impl Default for NMREBARCHILDSIZE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMREBARSPLITTER {
     pub hdr: NMHDR,
     pub rcSizing: super::super::Foundation::RECT,
@@ -13685,7 +14145,8 @@ impl Default for NMREBARSPLITTER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMSEARCHWEB {
     pub hdr: NMHDR,
     pub entrypoint: EC_SEARCHWEB_ENTRYPOINT,
@@ -13701,7 +14162,8 @@ impl Default for NMSEARCHWEB {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMSELCHANGE {
     pub nmhdr: NMHDR,
     pub stSelStart: super::super::Foundation::SYSTEMTIME,
@@ -13717,7 +14179,8 @@ impl Default for NMSELCHANGE {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMTBCUSTOMDRAW {
     pub nmcd: NMCUSTOMDRAW,
     pub hbrMonoDither: super::super::Graphics::Gdi::HBRUSH,
@@ -13745,7 +14208,8 @@ impl Default for NMTBCUSTOMDRAW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMTBDISPINFOA {
     pub hdr: NMHDR,
     pub dwMask: NMTBDISPINFOW_MASK,
@@ -13764,7 +14228,8 @@ impl Default for NMTBDISPINFOA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMTBDISPINFOW {
     pub hdr: NMHDR,
     pub dwMask: NMTBDISPINFOW_MASK,
@@ -13783,7 +14248,8 @@ impl Default for NMTBDISPINFOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMTBGETINFOTIPA {
     pub hdr: NMHDR,
     pub pszText: windows_core::PSTR,
@@ -13800,7 +14266,8 @@ impl Default for NMTBGETINFOTIPA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMTBGETINFOTIPW {
     pub hdr: NMHDR,
     pub pszText: windows_core::PWSTR,
@@ -13817,7 +14284,8 @@ impl Default for NMTBGETINFOTIPW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMTBHOTITEM {
     pub hdr: NMHDR,
     pub idOld: i32,
@@ -13833,7 +14301,8 @@ impl Default for NMTBHOTITEM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMTBRESTORE {
     pub hdr: NMHDR,
     pub pData: *mut u32,
@@ -13853,7 +14322,8 @@ impl Default for NMTBRESTORE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMTBSAVE {
     pub hdr: NMHDR,
     pub pData: *mut u32,
@@ -13887,7 +14357,8 @@ impl Default for NMTCKEYDOWN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMTOOLBARA {
     pub hdr: NMHDR,
     pub iItem: i32,
@@ -13905,7 +14376,8 @@ impl Default for NMTOOLBARA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMTOOLBARW {
     pub hdr: NMHDR,
     pub iItem: i32,
@@ -13923,7 +14395,8 @@ impl Default for NMTOOLBARW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMTOOLTIPSCREATED {
     pub hdr: NMHDR,
     pub hwndToolTips: super::super::Foundation::HWND,
@@ -13937,7 +14410,8 @@ impl Default for NMTOOLTIPSCREATED {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMTRBTHUMBPOSCHANGING {
     pub hdr: NMHDR,
     pub dwPos: u32,
@@ -13952,7 +14426,8 @@ impl Default for NMTRBTHUMBPOSCHANGING {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMTREEVIEWA {
     pub hdr: NMHDR,
     pub action: NM_TREEVIEW_ACTION,
@@ -13969,7 +14444,8 @@ impl Default for NMTREEVIEWA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMTREEVIEWW {
     pub hdr: NMHDR,
     pub action: NM_TREEVIEW_ACTION,
@@ -13987,7 +14463,8 @@ impl Default for NMTREEVIEWW {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMTTCUSTOMDRAW {
     pub nmcd: NMCUSTOMDRAW,
     pub uDrawFlags: u32,
@@ -14003,7 +14480,8 @@ impl Default for NMTTCUSTOMDRAW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMTTDISPINFOA {
     pub hdr: NMHDR,
     pub lpszText: windows_core::PSTR,
@@ -14021,7 +14499,8 @@ impl Default for NMTTDISPINFOA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMTTDISPINFOW {
     pub hdr: NMHDR,
     pub lpszText: windows_core::PWSTR,
@@ -14040,7 +14519,8 @@ impl Default for NMTTDISPINFOW {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMTVASYNCDRAW {
     pub hdr: NMHDR,
     pub pimldp: *mut IMAGELISTDRAWPARAMS,
@@ -14062,7 +14542,8 @@ impl Default for NMTVASYNCDRAW {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMTVCUSTOMDRAW {
     pub nmcd: NMCUSTOMDRAW,
     pub clrText: super::super::Foundation::COLORREF,
@@ -14080,7 +14561,8 @@ impl Default for NMTVCUSTOMDRAW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMTVDISPINFOA {
     pub hdr: NMHDR,
     pub item: TVITEMA,
@@ -14094,7 +14576,8 @@ impl Default for NMTVDISPINFOA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMTVDISPINFOEXA {
     pub hdr: NMHDR,
     pub item: TVITEMEXA,
@@ -14108,7 +14591,8 @@ impl Default for NMTVDISPINFOEXA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMTVDISPINFOEXW {
     pub hdr: NMHDR,
     pub item: TVITEMEXW,
@@ -14122,7 +14606,8 @@ impl Default for NMTVDISPINFOEXW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMTVDISPINFOW {
     pub hdr: NMHDR,
     pub item: TVITEMW,
@@ -14136,7 +14621,8 @@ impl Default for NMTVDISPINFOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMTVGETINFOTIPA {
     pub hdr: NMHDR,
     pub pszText: windows_core::PSTR,
@@ -14153,7 +14639,8 @@ impl Default for NMTVGETINFOTIPA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMTVGETINFOTIPW {
     pub hdr: NMHDR,
     pub pszText: windows_core::PWSTR,
@@ -14170,7 +14657,8 @@ impl Default for NMTVGETINFOTIPW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMTVITEMCHANGE {
     pub hdr: NMHDR,
     pub uChanged: u32,
@@ -14203,7 +14691,8 @@ impl Default for NMTVKEYDOWN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMTVSTATEIMAGECHANGING {
     pub hdr: NMHDR,
     pub hti: HTREEITEM,
@@ -14219,7 +14708,8 @@ impl Default for NMTVSTATEIMAGECHANGING {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMUPDOWN {
     pub hdr: NMHDR,
     pub iPos: i32,
@@ -14234,7 +14724,8 @@ impl Default for NMUPDOWN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NMVIEWCHANGE {
     pub nmhdr: NMHDR,
     pub dwOldView: MONTH_CALDENDAR_MESSAGES_VIEW,
@@ -14249,7 +14740,8 @@ impl Default for NMVIEWCHANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PBRANGE {
     pub iLow: i32,
     pub iHigh: i32,
@@ -14263,7 +14755,8 @@ impl Default for PBRANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POINTER_DEVICE_CURSOR_INFO {
     pub cursorId: u32,
     pub cursor: POINTER_DEVICE_CURSOR_TYPE,
@@ -14278,7 +14771,8 @@ impl Default for POINTER_DEVICE_CURSOR_INFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POINTER_DEVICE_INFO {
     pub displayOrientation: u32,
     pub device: super::super::Foundation::HANDLE,
@@ -14299,7 +14793,8 @@ impl Default for POINTER_DEVICE_INFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct POINTER_DEVICE_PROPERTY {
     pub logicalMin: i32,
     pub logicalMax: i32,
@@ -15255,7 +15750,8 @@ impl Default for PROPSHEETPAGEW_V3_1 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PSHNOTIFY {
     pub hdr: NMHDR,
     pub lParam: super::super::Foundation::LPARAM,
@@ -15269,7 +15765,8 @@ impl Default for PSHNOTIFY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RBHITTESTINFO {
     pub pt: super::super::Foundation::POINT,
     pub flags: u32,
@@ -15285,7 +15782,8 @@ impl Default for RBHITTESTINFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REBARBANDINFOA {
     pub cbSize: u32,
     pub fMask: u32,
@@ -15322,7 +15820,8 @@ impl Default for REBARBANDINFOA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REBARBANDINFOW {
     pub cbSize: u32,
     pub fMask: u32,
@@ -15358,7 +15857,8 @@ impl Default for REBARBANDINFOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct REBARINFO {
     pub cbSize: u32,
     pub fMask: u32,
@@ -15460,7 +15960,8 @@ impl Default for TASKDIALOG_BUTTON {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TA_CUBIC_BEZIER {
     pub header: TA_TIMINGFUNCTION,
     pub rX0: f32,
@@ -15477,7 +15978,8 @@ impl Default for TA_CUBIC_BEZIER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TA_TIMINGFUNCTION {
     pub eTimingFunctionType: TA_TIMINGFUNCTION_TYPE,
 }
@@ -15490,7 +15992,8 @@ impl Default for TA_TIMINGFUNCTION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TA_TRANSFORM {
     pub eTransformType: TA_TRANSFORM_TYPE,
     pub dwTimingFunctionId: u32,
@@ -15507,7 +16010,8 @@ impl Default for TA_TRANSFORM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TA_TRANSFORM_2D {
     pub header: TA_TRANSFORM,
     pub rX: f32,
@@ -15526,7 +16030,8 @@ impl Default for TA_TRANSFORM_2D {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TA_TRANSFORM_CLIP {
     pub header: TA_TRANSFORM,
     pub rLeft: f32,
@@ -15547,7 +16052,8 @@ impl Default for TA_TRANSFORM_CLIP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TA_TRANSFORM_OPACITY {
     pub header: TA_TRANSFORM,
     pub rOpacity: f32,
@@ -15562,7 +16068,8 @@ impl Default for TA_TRANSFORM_OPACITY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TBADDBITMAP {
     pub hInst: super::super::Foundation::HINSTANCE,
     pub nID: usize,
@@ -15577,7 +16084,8 @@ impl Default for TBADDBITMAP {
 }
 #[repr(C)]
 #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec", target_arch = "x86_64"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TBBUTTON {
     pub iBitmap: i32,
     pub idCommand: i32,
@@ -15599,7 +16107,8 @@ impl Default for TBBUTTON {
 }
 #[repr(C)]
 #[cfg(target_arch = "x86")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TBBUTTON {
     pub iBitmap: i32,
     pub idCommand: i32,
@@ -15620,7 +16129,8 @@ impl Default for TBBUTTON {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TBBUTTONINFOA {
     pub cbSize: u32,
     pub dwMask: TBBUTTONINFOW_MASK,
@@ -15642,7 +16152,8 @@ impl Default for TBBUTTONINFOA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TBBUTTONINFOW {
     pub cbSize: u32,
     pub dwMask: TBBUTTONINFOW_MASK,
@@ -15664,7 +16175,8 @@ impl Default for TBBUTTONINFOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TBINSERTMARK {
     pub iButton: i32,
     pub dwFlags: TBINSERTMARK_FLAGS,
@@ -15678,7 +16190,8 @@ impl Default for TBINSERTMARK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TBMETRICS {
     pub cbSize: u32,
     pub dwMask: u32,
@@ -15698,7 +16211,8 @@ impl Default for TBMETRICS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TBREPLACEBITMAP {
     pub hInstOld: super::super::Foundation::HINSTANCE,
     pub nIDOld: usize,
@@ -15716,7 +16230,8 @@ impl Default for TBREPLACEBITMAP {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Registry")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TBSAVEPARAMSA {
     pub hkr: super::super::System::Registry::HKEY,
     pub pszSubKey: windows_core::PCSTR,
@@ -15734,7 +16249,8 @@ impl Default for TBSAVEPARAMSA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Registry")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TBSAVEPARAMSW {
     pub hkr: super::super::System::Registry::HKEY,
     pub pszSubKey: windows_core::PCWSTR,
@@ -15751,7 +16267,8 @@ impl Default for TBSAVEPARAMSW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TCHITTESTINFO {
     pub pt: super::super::Foundation::POINT,
     pub flags: TCHITTESTINFO_FLAGS,
@@ -15765,7 +16282,8 @@ impl Default for TCHITTESTINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TCITEMA {
     pub mask: TCITEMHEADERA_MASK,
     pub dwState: TAB_CONTROL_ITEM_STATE,
@@ -15784,7 +16302,8 @@ impl Default for TCITEMA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TCITEMHEADERA {
     pub mask: TCITEMHEADERA_MASK,
     pub lpReserved1: u32,
@@ -15802,7 +16321,8 @@ impl Default for TCITEMHEADERA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TCITEMHEADERW {
     pub mask: TCITEMHEADERA_MASK,
     pub lpReserved1: u32,
@@ -15820,7 +16340,8 @@ impl Default for TCITEMHEADERW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TCITEMW {
     pub mask: TCITEMHEADERA_MASK,
     pub dwState: TAB_CONTROL_ITEM_STATE,
@@ -15839,7 +16360,8 @@ impl Default for TCITEMW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TOUCH_HIT_TESTING_INPUT {
     pub pointerId: u32,
     pub point: super::super::Foundation::POINT,
@@ -15856,7 +16378,8 @@ impl Default for TOUCH_HIT_TESTING_INPUT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TOUCH_HIT_TESTING_PROXIMITY_EVALUATION {
     pub score: u16,
     pub adjustedPoint: super::super::Foundation::POINT,
@@ -15870,7 +16393,8 @@ impl Default for TOUCH_HIT_TESTING_PROXIMITY_EVALUATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TTGETTITLE {
     pub dwSize: u32,
     pub uTitleBitmap: u32,
@@ -15886,7 +16410,8 @@ impl Default for TTGETTITLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TTHITTESTINFOA {
     pub hwnd: super::super::Foundation::HWND,
     pub pt: super::super::Foundation::POINT,
@@ -15901,7 +16426,8 @@ impl Default for TTHITTESTINFOA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TTHITTESTINFOW {
     pub hwnd: super::super::Foundation::HWND,
     pub pt: super::super::Foundation::POINT,
@@ -15916,7 +16442,8 @@ impl Default for TTHITTESTINFOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TTTOOLINFOA {
     pub cbSize: u32,
     pub uFlags: TOOLTIP_FLAGS,
@@ -15937,7 +16464,8 @@ impl Default for TTTOOLINFOA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TTTOOLINFOW {
     pub cbSize: u32,
     pub uFlags: TOOLTIP_FLAGS,
@@ -15958,7 +16486,8 @@ impl Default for TTTOOLINFOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TVGETITEMPARTRECTINFO {
     pub hti: HTREEITEM,
     pub prc: *mut super::super::Foundation::RECT,
@@ -15973,7 +16502,8 @@ impl Default for TVGETITEMPARTRECTINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TVHITTESTINFO {
     pub pt: super::super::Foundation::POINT,
     pub flags: TVHITTESTINFO_FLAGS,
@@ -16046,7 +16576,8 @@ impl Default for TVINSERTSTRUCTW_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TVITEMA {
     pub mask: TVITEM_MASK,
     pub hItem: HTREEITEM,
@@ -16068,7 +16599,8 @@ impl Default for TVITEMA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TVITEMEXA {
     pub mask: TVITEM_MASK,
     pub hItem: HTREEITEM,
@@ -16095,7 +16627,8 @@ impl Default for TVITEMEXA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TVITEMEXW {
     pub mask: TVITEM_MASK,
     pub hItem: HTREEITEM,
@@ -16122,7 +16655,8 @@ impl Default for TVITEMEXW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TVITEMW {
     pub mask: TVITEM_MASK,
     pub hItem: HTREEITEM,
@@ -16144,7 +16678,8 @@ impl Default for TVITEMW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TVSORTCB {
     pub hParent: HTREEITEM,
     pub lpfnCompare: PFNTVCOMPARE,
@@ -16159,7 +16694,8 @@ impl Default for TVSORTCB {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UDACCEL {
     pub nSec: u32,
     pub nInc: u32,
@@ -16173,7 +16709,8 @@ impl Default for UDACCEL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct USAGE_PROPERTIES {
     pub level: u16,
     pub page: u16,
@@ -16195,7 +16732,8 @@ impl Default for USAGE_PROPERTIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WTA_OPTIONS {
     pub dwFlags: u32,
     pub dwMask: u32,
@@ -16243,3 +16781,5 @@ pub type PFNTVCOMPARE = Option<unsafe extern "system" fn(lparam1: super::super::
 pub type PFTASKDIALOGCALLBACK = Option<unsafe extern "system" fn(hwnd: super::super::Foundation::HWND, msg: TASKDIALOG_NOTIFICATIONS, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM, lprefdata: isize) -> windows_core::HRESULT>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
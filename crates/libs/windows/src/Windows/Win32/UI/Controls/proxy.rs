@@ -0,0 +1,224 @@
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+impl windows_core::imp::ProxyVtable for IImageList_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, hbmimage: super::super::Graphics::Gdi::HBITMAP, hbmmask: super::super::Graphics::Gdi::HBITMAP, pi: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "Add", 3usize, |this| ((**(this as *const *const IImageList_Vtbl)).Add)(this, hbmimage, hbmmask, pi), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn ReplaceIcon(this: *mut core::ffi::c_void, i: i32, hicon: super::WindowsAndMessaging::HICON, pi: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "ReplaceIcon", 4usize, |this| ((**(this as *const *const IImageList_Vtbl)).ReplaceIcon)(this, i, hicon, pi), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOverlayImage(this: *mut core::ffi::c_void, iimage: i32, ioverlay: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "SetOverlayImage", 5usize, |this| ((**(this as *const *const IImageList_Vtbl)).SetOverlayImage)(this, iimage, ioverlay), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn Replace(this: *mut core::ffi::c_void, i: i32, hbmimage: super::super::Graphics::Gdi::HBITMAP, hbmmask: super::super::Graphics::Gdi::HBITMAP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "Replace", 6usize, |this| ((**(this as *const *const IImageList_Vtbl)).Replace)(this, i, hbmimage, hbmmask), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn AddMasked(this: *mut core::ffi::c_void, hbmimage: super::super::Graphics::Gdi::HBITMAP, crmask: super::super::Foundation::COLORREF, pi: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "AddMasked", 7usize, |this| ((**(this as *const *const IImageList_Vtbl)).AddMasked)(this, hbmimage, crmask, pi), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn Draw(this: *mut core::ffi::c_void, pimldp: *const IMAGELISTDRAWPARAMS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "Draw", 8usize, |this| ((**(this as *const *const IImageList_Vtbl)).Draw)(this, pimldp), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, i: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "Remove", 9usize, |this| ((**(this as *const *const IImageList_Vtbl)).Remove)(this, i), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn GetIcon(this: *mut core::ffi::c_void, i: i32, flags: u32, picon: *mut super::WindowsAndMessaging::HICON) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "GetIcon", 10usize, |this| ((**(this as *const *const IImageList_Vtbl)).GetIcon)(this, i, flags, picon), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetImageInfo(this: *mut core::ffi::c_void, i: i32, pimageinfo: *mut IMAGEINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "GetImageInfo", 11usize, |this| ((**(this as *const *const IImageList_Vtbl)).GetImageInfo)(this, i, pimageinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn Copy(this: *mut core::ffi::c_void, idst: i32, punksrc: *mut core::ffi::c_void, isrc: i32, uflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "Copy", 12usize, |this| ((**(this as *const *const IImageList_Vtbl)).Copy)(this, idst, punksrc, isrc, uflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Merge(this: *mut core::ffi::c_void, i1: i32, punk2: *mut core::ffi::c_void, i2: i32, dx: i32, dy: i32, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "Merge", 13usize, |this| ((**(this as *const *const IImageList_Vtbl)).Merge)(this, i1, punk2, i2, dx, dy, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "Clone", 14usize, |this| ((**(this as *const *const IImageList_Vtbl)).Clone)(this, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetImageRect(this: *mut core::ffi::c_void, i: i32, prc: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "GetImageRect", 15usize, |this| ((**(this as *const *const IImageList_Vtbl)).GetImageRect)(this, i, prc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIconSize(this: *mut core::ffi::c_void, cx: *mut i32, cy: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "GetIconSize", 16usize, |this| ((**(this as *const *const IImageList_Vtbl)).GetIconSize)(this, cx, cy), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIconSize(this: *mut core::ffi::c_void, cx: i32, cy: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "SetIconSize", 17usize, |this| ((**(this as *const *const IImageList_Vtbl)).SetIconSize)(this, cx, cy), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetImageCount(this: *mut core::ffi::c_void, pi: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "GetImageCount", 18usize, |this| ((**(this as *const *const IImageList_Vtbl)).GetImageCount)(this, pi), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetImageCount(this: *mut core::ffi::c_void, unewcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "SetImageCount", 19usize, |this| ((**(this as *const *const IImageList_Vtbl)).SetImageCount)(this, unewcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBkColor(this: *mut core::ffi::c_void, clrbk: super::super::Foundation::COLORREF, pclr: *mut super::super::Foundation::COLORREF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "SetBkColor", 20usize, |this| ((**(this as *const *const IImageList_Vtbl)).SetBkColor)(this, clrbk, pclr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBkColor(this: *mut core::ffi::c_void, pclr: *mut super::super::Foundation::COLORREF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "GetBkColor", 21usize, |this| ((**(this as *const *const IImageList_Vtbl)).GetBkColor)(this, pclr), |result| Some(*result))
+        }
+        unsafe extern "system" fn BeginDrag(this: *mut core::ffi::c_void, itrack: i32, dxhotspot: i32, dyhotspot: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "BeginDrag", 22usize, |this| ((**(this as *const *const IImageList_Vtbl)).BeginDrag)(this, itrack, dxhotspot, dyhotspot), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndDrag(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "EndDrag", 23usize, |this| ((**(this as *const *const IImageList_Vtbl)).EndDrag)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DragEnter(this: *mut core::ffi::c_void, hwndlock: super::super::Foundation::HWND, x: i32, y: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "DragEnter", 24usize, |this| ((**(this as *const *const IImageList_Vtbl)).DragEnter)(this, hwndlock, x, y), |result| Some(*result))
+        }
+        unsafe extern "system" fn DragLeave(this: *mut core::ffi::c_void, hwndlock: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "DragLeave", 25usize, |this| ((**(this as *const *const IImageList_Vtbl)).DragLeave)(this, hwndlock), |result| Some(*result))
+        }
+        unsafe extern "system" fn DragMove(this: *mut core::ffi::c_void, x: i32, y: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "DragMove", 26usize, |this| ((**(this as *const *const IImageList_Vtbl)).DragMove)(this, x, y), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDragCursorImage(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void, idrag: i32, dxhotspot: i32, dyhotspot: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "SetDragCursorImage", 27usize, |this| ((**(this as *const *const IImageList_Vtbl)).SetDragCursorImage)(this, punk, idrag, dxhotspot, dyhotspot), |result| Some(*result))
+        }
+        unsafe extern "system" fn DragShowNolock(this: *mut core::ffi::c_void, fshow: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "DragShowNolock", 28usize, |this| ((**(this as *const *const IImageList_Vtbl)).DragShowNolock)(this, fshow), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDragImage(this: *mut core::ffi::c_void, ppt: *mut super::super::Foundation::POINT, ppthotspot: *mut super::super::Foundation::POINT, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "GetDragImage", 29usize, |this| ((**(this as *const *const IImageList_Vtbl)).GetDragImage)(this, ppt, ppthotspot, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItemFlags(this: *mut core::ffi::c_void, i: i32, dwflags: *mut IMAGE_LIST_ITEM_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "GetItemFlags", 30usize, |this| ((**(this as *const *const IImageList_Vtbl)).GetItemFlags)(this, i, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOverlayImage(this: *mut core::ffi::c_void, ioverlay: i32, piindex: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList", "GetOverlayImage", 31usize, |this| ((**(this as *const *const IImageList_Vtbl)).GetOverlayImage)(this, ioverlay, piindex), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            Add,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            Add: 0,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            ReplaceIcon,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            ReplaceIcon: 0,
+            SetOverlayImage,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            Replace,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            Replace: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            AddMasked,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            AddMasked: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            Draw,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            Draw: 0,
+            Remove,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            GetIcon,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            GetIcon: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetImageInfo,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetImageInfo: 0,
+            Copy,
+            Merge,
+            Clone,
+            GetImageRect,
+            GetIconSize,
+            SetIconSize,
+            GetImageCount,
+            SetImageCount,
+            SetBkColor,
+            GetBkColor,
+            BeginDrag,
+            EndDrag,
+            DragEnter,
+            DragLeave,
+            DragMove,
+            SetDragCursorImage,
+            DragShowNolock,
+            GetDragImage,
+            GetItemFlags,
+            GetOverlayImage,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImageList as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+impl windows_core::imp::ProxyVtable for IImageList2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Resize(this: *mut core::ffi::c_void, cxnewiconsize: i32, cynewiconsize: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList2", "Resize", 32usize, |this| ((**(this as *const *const IImageList2_Vtbl)).Resize)(this, cxnewiconsize, cynewiconsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOriginalSize(this: *mut core::ffi::c_void, iimage: i32, dwflags: u32, pcx: *mut i32, pcy: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList2", "GetOriginalSize", 33usize, |this| ((**(this as *const *const IImageList2_Vtbl)).GetOriginalSize)(this, iimage, dwflags, pcx, pcy), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOriginalSize(this: *mut core::ffi::c_void, iimage: i32, cx: i32, cy: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList2", "SetOriginalSize", 34usize, |this| ((**(this as *const *const IImageList2_Vtbl)).SetOriginalSize)(this, iimage, cx, cy), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCallback(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList2", "SetCallback", 35usize, |this| ((**(this as *const *const IImageList2_Vtbl)).SetCallback)(this, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCallback(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList2", "GetCallback", 36usize, |this| ((**(this as *const *const IImageList2_Vtbl)).GetCallback)(this, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForceImagePresent(this: *mut core::ffi::c_void, iimage: i32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList2", "ForceImagePresent", 37usize, |this| ((**(this as *const *const IImageList2_Vtbl)).ForceImagePresent)(this, iimage, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn DiscardImages(this: *mut core::ffi::c_void, ifirstimage: i32, ilastimage: i32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList2", "DiscardImages", 38usize, |this| ((**(this as *const *const IImageList2_Vtbl)).DiscardImages)(this, ifirstimage, ilastimage, dwflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn PreloadImages(this: *mut core::ffi::c_void, pimldp: *const IMAGELISTDRAWPARAMS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList2", "PreloadImages", 39usize, |this| ((**(this as *const *const IImageList2_Vtbl)).PreloadImages)(this, pimldp), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatistics(this: *mut core::ffi::c_void, pils: *mut IMAGELISTSTATS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList2", "GetStatistics", 40usize, |this| ((**(this as *const *const IImageList2_Vtbl)).GetStatistics)(this, pils), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, cx: i32, cy: i32, flags: IMAGELIST_CREATION_FLAGS, cinitial: i32, cgrow: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList2", "Initialize", 41usize, |this| ((**(this as *const *const IImageList2_Vtbl)).Initialize)(this, cx, cy, flags, cinitial, cgrow), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn Replace2(this: *mut core::ffi::c_void, i: i32, hbmimage: super::super::Graphics::Gdi::HBITMAP, hbmmask: super::super::Graphics::Gdi::HBITMAP, punk: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList2", "Replace2", 42usize, |this| ((**(this as *const *const IImageList2_Vtbl)).Replace2)(this, i, hbmimage, hbmmask, punk, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReplaceFromImageList(this: *mut core::ffi::c_void, i: i32, pil: *mut core::ffi::c_void, isrc: i32, punk: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageList2", "ReplaceFromImageList", 43usize, |this| ((**(this as *const *const IImageList2_Vtbl)).ReplaceFromImageList)(this, i, pil, isrc, punk, dwflags), |result| Some(*result))
+        }
+        Self {
+            base__: <IImageList_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Resize,
+            GetOriginalSize,
+            SetOriginalSize,
+            SetCallback,
+            GetCallback,
+            ForceImagePresent,
+            DiscardImages,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            PreloadImages,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            PreloadImages: 0,
+            GetStatistics,
+            Initialize,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            Replace2,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            Replace2: 0,
+            ReplaceFromImageList,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImageList2 as windows_core::Interface>::IID || <IImageList_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -248,6 +248,7 @@ pub struct DIALOG_CONTROL_DPI_CHANGE_BEHAVIORS(pub i32);
 impl windows_core::TypeKind for DIALOG_CONTROL_DPI_CHANGE_BEHAVIORS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DIALOG_CONTROL_DPI_CHANGE_BEHAVIORS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DIALOG_CONTROL_DPI_CHANGE_BEHAVIORS").field(&self.0).finish()
@@ -292,6 +293,7 @@ pub struct DIALOG_DPI_CHANGE_BEHAVIORS(pub i32);
 impl windows_core::TypeKind for DIALOG_DPI_CHANGE_BEHAVIORS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DIALOG_DPI_CHANGE_BEHAVIORS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DIALOG_DPI_CHANGE_BEHAVIORS").field(&self.0).finish()
@@ -336,6 +338,7 @@ pub struct DPI_AWARENESS(pub i32);
 impl windows_core::TypeKind for DPI_AWARENESS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DPI_AWARENESS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DPI_AWARENESS").field(&self.0).finish()
@@ -347,6 +350,7 @@ pub struct DPI_HOSTING_BEHAVIOR(pub i32);
 impl windows_core::TypeKind for DPI_HOSTING_BEHAVIOR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DPI_HOSTING_BEHAVIOR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DPI_HOSTING_BEHAVIOR").field(&self.0).finish()
@@ -358,6 +362,7 @@ pub struct MONITOR_DPI_TYPE(pub i32);
 impl windows_core::TypeKind for MONITOR_DPI_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MONITOR_DPI_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MONITOR_DPI_TYPE").field(&self.0).finish()
@@ -369,13 +374,15 @@ pub struct PROCESS_DPI_AWARENESS(pub i32);
 impl windows_core::TypeKind for PROCESS_DPI_AWARENESS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROCESS_DPI_AWARENESS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROCESS_DPI_AWARENESS").field(&self.0).finish()
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DPI_AWARENESS_CONTEXT(pub *mut core::ffi::c_void);
 impl DPI_AWARENESS_CONTEXT {
     pub fn is_invalid(&self) -> bool {
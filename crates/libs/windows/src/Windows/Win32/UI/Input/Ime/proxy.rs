@@ -0,0 +1,1347 @@
+#[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+impl windows_core::imp::ProxyVtable for IActiveIME_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Inquire(this: *mut core::ffi::c_void, dwsysteminfoflags: u32, pimeinfo: *mut IMEINFO, szwndclass: windows_core::PWSTR, pdwprivate: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIME", "Inquire", 3usize, |this| ((**(this as *const *const IActiveIME_Vtbl)).Inquire)(this, dwsysteminfoflags, pimeinfo, szwndclass, pdwprivate), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConversionList(this: *mut core::ffi::c_void, himc: HIMC, szsource: windows_core::PCWSTR, uflag: u32, ubuflen: u32, pdest: *mut CANDIDATELIST, pucopied: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIME", "ConversionList", 4usize, |this| ((**(this as *const *const IActiveIME_Vtbl)).ConversionList)(this, himc, szsource, uflag, ubuflen, pdest, pucopied), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn Configure(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, hwnd: super::super::super::Foundation::HWND, dwmode: u32, pregisterword: *const REGISTERWORDW) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIME", "Configure", 5usize, |this| ((**(this as *const *const IActiveIME_Vtbl)).Configure)(this, hkl, hwnd, dwmode, pregisterword), |result| Some(*result))
+        }
+        unsafe extern "system" fn Destroy(this: *mut core::ffi::c_void, ureserved: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIME", "Destroy", 6usize, |this| ((**(this as *const *const IActiveIME_Vtbl)).Destroy)(this, ureserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn Escape(this: *mut core::ffi::c_void, himc: HIMC, uescape: u32, pdata: *mut core::ffi::c_void, plresult: *mut super::super::super::Foundation::LRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIME", "Escape", 7usize, |this| ((**(this as *const *const IActiveIME_Vtbl)).Escape)(this, himc, uescape, pdata, plresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetActiveContext(this: *mut core::ffi::c_void, himc: HIMC, fflag: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIME", "SetActiveContext", 8usize, |this| ((**(this as *const *const IActiveIME_Vtbl)).SetActiveContext)(this, himc, fflag), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessKey(this: *mut core::ffi::c_void, himc: HIMC, uvirkey: u32, lparam: u32, pbkeystate: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIME", "ProcessKey", 9usize, |this| ((**(this as *const *const IActiveIME_Vtbl)).ProcessKey)(this, himc, uvirkey, lparam, pbkeystate), |result| Some(*result))
+        }
+        unsafe extern "system" fn Notify(this: *mut core::ffi::c_void, himc: HIMC, dwaction: u32, dwindex: u32, dwvalue: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIME", "Notify", 10usize, |this| ((**(this as *const *const IActiveIME_Vtbl)).Notify)(this, himc, dwaction, dwindex, dwvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Select(this: *mut core::ffi::c_void, himc: HIMC, fselect: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIME", "Select", 11usize, |this| ((**(this as *const *const IActiveIME_Vtbl)).Select)(this, himc, fselect), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompositionString(this: *mut core::ffi::c_void, himc: HIMC, dwindex: u32, pcomp: *const core::ffi::c_void, dwcomplen: u32, pread: *const core::ffi::c_void, dwreadlen: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIME", "SetCompositionString", 12usize, |this| ((**(this as *const *const IActiveIME_Vtbl)).SetCompositionString)(this, himc, dwindex, pcomp, dwcomplen, pread, dwreadlen), |result| Some(*result))
+        }
+        unsafe extern "system" fn ToAsciiEx(this: *mut core::ffi::c_void, uvirkey: u32, uscancode: u32, pbkeystate: *const u8, fustate: u32, himc: HIMC, pdwtransbuf: *mut u32, pusize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIME", "ToAsciiEx", 13usize, |this| ((**(this as *const *const IActiveIME_Vtbl)).ToAsciiEx)(this, uvirkey, uscancode, pbkeystate, fustate, himc, pdwtransbuf, pusize), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterWord(this: *mut core::ffi::c_void, szreading: windows_core::PCWSTR, dwstyle: u32, szstring: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIME", "RegisterWord", 14usize, |this| ((**(this as *const *const IActiveIME_Vtbl)).RegisterWord)(this, szreading, dwstyle, szstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterWord(this: *mut core::ffi::c_void, szreading: windows_core::PCWSTR, dwstyle: u32, szstring: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIME", "UnregisterWord", 15usize, |this| ((**(this as *const *const IActiveIME_Vtbl)).UnregisterWord)(this, szreading, dwstyle, szstring), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRegisterWordStyle(this: *mut core::ffi::c_void, nitem: u32, pstylebuf: *mut STYLEBUFW, pubufsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIME", "GetRegisterWordStyle", 16usize, |this| ((**(this as *const *const IActiveIME_Vtbl)).GetRegisterWordStyle)(this, nitem, pstylebuf, pubufsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumRegisterWord(this: *mut core::ffi::c_void, szreading: windows_core::PCWSTR, dwstyle: u32, szregister: windows_core::PCWSTR, pdata: *const core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIME", "EnumRegisterWord", 17usize, |this| ((**(this as *const *const IActiveIME_Vtbl)).EnumRegisterWord)(this, szreading, dwstyle, szregister, pdata, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCodePageA(this: *mut core::ffi::c_void, ucodepage: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIME", "GetCodePageA", 18usize, |this| ((**(this as *const *const IActiveIME_Vtbl)).GetCodePageA)(this, ucodepage), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLangId(this: *mut core::ffi::c_void, plid: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIME", "GetLangId", 19usize, |this| ((**(this as *const *const IActiveIME_Vtbl)).GetLangId)(this, plid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Inquire,
+            ConversionList,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            Configure,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            Configure: 0,
+            Destroy,
+            Escape,
+            SetActiveContext,
+            ProcessKey,
+            Notify,
+            Select,
+            SetCompositionString,
+            ToAsciiEx,
+            RegisterWord,
+            UnregisterWord,
+            GetRegisterWordStyle,
+            EnumRegisterWord,
+            GetCodePageA,
+            GetLangId,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveIME as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+impl windows_core::imp::ProxyVtable for IActiveIME2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Sleep(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIME2", "Sleep", 20usize, |this| ((**(this as *const *const IActiveIME2_Vtbl)).Sleep)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unsleep(this: *mut core::ffi::c_void, fdead: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIME2", "Unsleep", 21usize, |this| ((**(this as *const *const IActiveIME2_Vtbl)).Unsleep)(this, fdead), |result| Some(*result))
+        }
+        Self { base__: <IActiveIME_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Sleep, Unsleep }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveIME2 as windows_core::Interface>::IID || <IActiveIME_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_Input_KeyboardAndMouse"))]
+impl windows_core::imp::ProxyVtable for IActiveIMMApp_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AssociateContext(this: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND, hime: HIMC, phprev: *mut HIMC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "AssociateContext", 3usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).AssociateContext)(this, hwnd, hime, phprev), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn ConfigureIMEA(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, hwnd: super::super::super::Foundation::HWND, dwmode: u32, pdata: *const REGISTERWORDA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "ConfigureIMEA", 4usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).ConfigureIMEA)(this, hkl, hwnd, dwmode, pdata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn ConfigureIMEW(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, hwnd: super::super::super::Foundation::HWND, dwmode: u32, pdata: *const REGISTERWORDW) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "ConfigureIMEW", 5usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).ConfigureIMEW)(this, hkl, hwnd, dwmode, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateContext(this: *mut core::ffi::c_void, phimc: *mut HIMC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "CreateContext", 6usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).CreateContext)(this, phimc), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestroyContext(this: *mut core::ffi::c_void, hime: HIMC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "DestroyContext", 7usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).DestroyContext)(this, hime), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn EnumRegisterWordA(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, szreading: windows_core::PCSTR, dwstyle: u32, szregister: windows_core::PCSTR, pdata: *const core::ffi::c_void, penum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "EnumRegisterWordA", 8usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).EnumRegisterWordA)(this, hkl, szreading, dwstyle, szregister, pdata, penum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn EnumRegisterWordW(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, szreading: windows_core::PCWSTR, dwstyle: u32, szregister: windows_core::PCWSTR, pdata: *const core::ffi::c_void, penum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "EnumRegisterWordW", 9usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).EnumRegisterWordW)(this, hkl, szreading, dwstyle, szregister, pdata, penum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn EscapeA(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, himc: HIMC, uescape: u32, pdata: *mut core::ffi::c_void, plresult: *mut super::super::super::Foundation::LRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "EscapeA", 10usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).EscapeA)(this, hkl, himc, uescape, pdata, plresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn EscapeW(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, himc: HIMC, uescape: u32, pdata: *mut core::ffi::c_void, plresult: *mut super::super::super::Foundation::LRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "EscapeW", 11usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).EscapeW)(this, hkl, himc, uescape, pdata, plresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCandidateListA(this: *mut core::ffi::c_void, himc: HIMC, dwindex: u32, ubuflen: u32, pcandlist: *mut CANDIDATELIST, pucopied: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetCandidateListA", 12usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetCandidateListA)(this, himc, dwindex, ubuflen, pcandlist, pucopied), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCandidateListW(this: *mut core::ffi::c_void, himc: HIMC, dwindex: u32, ubuflen: u32, pcandlist: *mut CANDIDATELIST, pucopied: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetCandidateListW", 13usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetCandidateListW)(this, himc, dwindex, ubuflen, pcandlist, pucopied), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCandidateListCountA(this: *mut core::ffi::c_void, himc: HIMC, pdwlistsize: *mut u32, pdwbuflen: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetCandidateListCountA", 14usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetCandidateListCountA)(this, himc, pdwlistsize, pdwbuflen), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCandidateListCountW(this: *mut core::ffi::c_void, himc: HIMC, pdwlistsize: *mut u32, pdwbuflen: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetCandidateListCountW", 15usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetCandidateListCountW)(this, himc, pdwlistsize, pdwbuflen), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCandidateWindow(this: *mut core::ffi::c_void, himc: HIMC, dwindex: u32, pcandidate: *mut CANDIDATEFORM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetCandidateWindow", 16usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetCandidateWindow)(this, himc, dwindex, pcandidate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetCompositionFontA(this: *mut core::ffi::c_void, himc: HIMC, plf: *mut super::super::super::Graphics::Gdi::LOGFONTA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetCompositionFontA", 17usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetCompositionFontA)(this, himc, plf), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetCompositionFontW(this: *mut core::ffi::c_void, himc: HIMC, plf: *mut super::super::super::Graphics::Gdi::LOGFONTW) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetCompositionFontW", 18usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetCompositionFontW)(this, himc, plf), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCompositionStringA(this: *mut core::ffi::c_void, himc: HIMC, dwindex: u32, dwbuflen: u32, plcopied: *mut i32, pbuf: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetCompositionStringA", 19usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetCompositionStringA)(this, himc, dwindex, dwbuflen, plcopied, pbuf), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCompositionStringW(this: *mut core::ffi::c_void, himc: HIMC, dwindex: u32, dwbuflen: u32, plcopied: *mut i32, pbuf: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetCompositionStringW", 20usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetCompositionStringW)(this, himc, dwindex, dwbuflen, plcopied, pbuf), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCompositionWindow(this: *mut core::ffi::c_void, himc: HIMC, pcompform: *mut COMPOSITIONFORM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetCompositionWindow", 21usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetCompositionWindow)(this, himc, pcompform), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContext(this: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND, phimc: *mut HIMC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetContext", 22usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetContext)(this, hwnd, phimc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn GetConversionListA(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, himc: HIMC, psrc: windows_core::PCSTR, ubuflen: u32, uflag: u32, pdst: *mut CANDIDATELIST, pucopied: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetConversionListA", 23usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetConversionListA)(this, hkl, himc, psrc, ubuflen, uflag, pdst, pucopied), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn GetConversionListW(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, himc: HIMC, psrc: windows_core::PCWSTR, ubuflen: u32, uflag: u32, pdst: *mut CANDIDATELIST, pucopied: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetConversionListW", 24usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetConversionListW)(this, hkl, himc, psrc, ubuflen, uflag, pdst, pucopied), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConversionStatus(this: *mut core::ffi::c_void, himc: HIMC, pfdwconversion: *mut u32, pfdwsentence: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetConversionStatus", 25usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetConversionStatus)(this, himc, pfdwconversion, pfdwsentence), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultIMEWnd(this: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND, phdefwnd: *mut super::super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetDefaultIMEWnd", 26usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetDefaultIMEWnd)(this, hwnd, phdefwnd), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn GetDescriptionA(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, ubuflen: u32, szdescription: windows_core::PSTR, pucopied: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetDescriptionA", 27usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetDescriptionA)(this, hkl, ubuflen, szdescription, pucopied), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn GetDescriptionW(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, ubuflen: u32, szdescription: windows_core::PWSTR, pucopied: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetDescriptionW", 28usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetDescriptionW)(this, hkl, ubuflen, szdescription, pucopied), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGuideLineA(this: *mut core::ffi::c_void, himc: HIMC, dwindex: u32, dwbuflen: u32, pbuf: windows_core::PSTR, pdwresult: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetGuideLineA", 29usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetGuideLineA)(this, himc, dwindex, dwbuflen, pbuf, pdwresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGuideLineW(this: *mut core::ffi::c_void, himc: HIMC, dwindex: u32, dwbuflen: u32, pbuf: windows_core::PWSTR, pdwresult: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetGuideLineW", 30usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetGuideLineW)(this, himc, dwindex, dwbuflen, pbuf, pdwresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn GetIMEFileNameA(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, ubuflen: u32, szfilename: windows_core::PSTR, pucopied: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetIMEFileNameA", 31usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetIMEFileNameA)(this, hkl, ubuflen, szfilename, pucopied), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn GetIMEFileNameW(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, ubuflen: u32, szfilename: windows_core::PWSTR, pucopied: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetIMEFileNameW", 32usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetIMEFileNameW)(this, hkl, ubuflen, szfilename, pucopied), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOpenStatus(this: *mut core::ffi::c_void, himc: HIMC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetOpenStatus", 33usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetOpenStatus)(this, himc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, fdwindex: u32, pdwproperty: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetProperty", 34usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetProperty)(this, hkl, fdwindex, pdwproperty), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn GetRegisterWordStyleA(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, nitem: u32, pstylebuf: *mut STYLEBUFA, pucopied: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetRegisterWordStyleA", 35usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetRegisterWordStyleA)(this, hkl, nitem, pstylebuf, pucopied), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn GetRegisterWordStyleW(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, nitem: u32, pstylebuf: *mut STYLEBUFW, pucopied: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetRegisterWordStyleW", 36usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetRegisterWordStyleW)(this, hkl, nitem, pstylebuf, pucopied), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatusWindowPos(this: *mut core::ffi::c_void, himc: HIMC, pptpos: *mut super::super::super::Foundation::POINT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetStatusWindowPos", 37usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetStatusWindowPos)(this, himc, pptpos), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVirtualKey(this: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND, puvirtualkey: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetVirtualKey", 38usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetVirtualKey)(this, hwnd, puvirtualkey), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn InstallIMEA(this: *mut core::ffi::c_void, szimefilename: windows_core::PCSTR, szlayouttext: windows_core::PCSTR, phkl: *mut super::KeyboardAndMouse::HKL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "InstallIMEA", 39usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).InstallIMEA)(this, szimefilename, szlayouttext, phkl), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn InstallIMEW(this: *mut core::ffi::c_void, szimefilename: windows_core::PCWSTR, szlayouttext: windows_core::PCWSTR, phkl: *mut super::KeyboardAndMouse::HKL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "InstallIMEW", 40usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).InstallIMEW)(this, szimefilename, szlayouttext, phkl), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn IsIME(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "IsIME", 41usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).IsIME)(this, hkl), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUIMessageA(this: *mut core::ffi::c_void, hwndime: super::super::super::Foundation::HWND, msg: u32, wparam: super::super::super::Foundation::WPARAM, lparam: super::super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "IsUIMessageA", 42usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).IsUIMessageA)(this, hwndime, msg, wparam, lparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUIMessageW(this: *mut core::ffi::c_void, hwndime: super::super::super::Foundation::HWND, msg: u32, wparam: super::super::super::Foundation::WPARAM, lparam: super::super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "IsUIMessageW", 43usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).IsUIMessageW)(this, hwndime, msg, wparam, lparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyIME(this: *mut core::ffi::c_void, himc: HIMC, dwaction: u32, dwindex: u32, dwvalue: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "NotifyIME", 44usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).NotifyIME)(this, himc, dwaction, dwindex, dwvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn RegisterWordA(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, szreading: windows_core::PCSTR, dwstyle: u32, szregister: windows_core::PCSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "RegisterWordA", 45usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).RegisterWordA)(this, hkl, szreading, dwstyle, szregister), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn RegisterWordW(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, szreading: windows_core::PCWSTR, dwstyle: u32, szregister: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "RegisterWordW", 46usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).RegisterWordW)(this, hkl, szreading, dwstyle, szregister), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseContext(this: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND, himc: HIMC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "ReleaseContext", 47usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).ReleaseContext)(this, hwnd, himc), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCandidateWindow(this: *mut core::ffi::c_void, himc: HIMC, pcandidate: *const CANDIDATEFORM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "SetCandidateWindow", 48usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).SetCandidateWindow)(this, himc, pcandidate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn SetCompositionFontA(this: *mut core::ffi::c_void, himc: HIMC, plf: *const super::super::super::Graphics::Gdi::LOGFONTA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "SetCompositionFontA", 49usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).SetCompositionFontA)(this, himc, plf), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn SetCompositionFontW(this: *mut core::ffi::c_void, himc: HIMC, plf: *const super::super::super::Graphics::Gdi::LOGFONTW) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "SetCompositionFontW", 50usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).SetCompositionFontW)(this, himc, plf), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompositionStringA(this: *mut core::ffi::c_void, himc: HIMC, dwindex: u32, pcomp: *const core::ffi::c_void, dwcomplen: u32, pread: *const core::ffi::c_void, dwreadlen: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "SetCompositionStringA", 51usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).SetCompositionStringA)(this, himc, dwindex, pcomp, dwcomplen, pread, dwreadlen), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompositionStringW(this: *mut core::ffi::c_void, himc: HIMC, dwindex: u32, pcomp: *const core::ffi::c_void, dwcomplen: u32, pread: *const core::ffi::c_void, dwreadlen: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "SetCompositionStringW", 52usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).SetCompositionStringW)(this, himc, dwindex, pcomp, dwcomplen, pread, dwreadlen), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompositionWindow(this: *mut core::ffi::c_void, himc: HIMC, pcompform: *const COMPOSITIONFORM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "SetCompositionWindow", 53usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).SetCompositionWindow)(this, himc, pcompform), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConversionStatus(this: *mut core::ffi::c_void, himc: HIMC, fdwconversion: u32, fdwsentence: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "SetConversionStatus", 54usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).SetConversionStatus)(this, himc, fdwconversion, fdwsentence), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOpenStatus(this: *mut core::ffi::c_void, himc: HIMC, fopen: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "SetOpenStatus", 55usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).SetOpenStatus)(this, himc, fopen), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatusWindowPos(this: *mut core::ffi::c_void, himc: HIMC, pptpos: *const super::super::super::Foundation::POINT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "SetStatusWindowPos", 56usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).SetStatusWindowPos)(this, himc, pptpos), |result| Some(*result))
+        }
+        unsafe extern "system" fn SimulateHotKey(this: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND, dwhotkeyid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "SimulateHotKey", 57usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).SimulateHotKey)(this, hwnd, dwhotkeyid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn UnregisterWordA(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, szreading: windows_core::PCSTR, dwstyle: u32, szunregister: windows_core::PCSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "UnregisterWordA", 58usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).UnregisterWordA)(this, hkl, szreading, dwstyle, szunregister), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn UnregisterWordW(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, szreading: windows_core::PCWSTR, dwstyle: u32, szunregister: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "UnregisterWordW", 59usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).UnregisterWordW)(this, hkl, szreading, dwstyle, szunregister), |result| Some(*result))
+        }
+        unsafe extern "system" fn Activate(this: *mut core::ffi::c_void, frestorelayout: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "Activate", 60usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).Activate)(this, frestorelayout), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deactivate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "Deactivate", 61usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).Deactivate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDefWindowProc(this: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND, msg: u32, wparam: super::super::super::Foundation::WPARAM, lparam: super::super::super::Foundation::LPARAM, plresult: *mut super::super::super::Foundation::LRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "OnDefWindowProc", 62usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).OnDefWindowProc)(this, hwnd, msg, wparam, lparam, plresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn FilterClientWindows(this: *mut core::ffi::c_void, aaclasslist: *const u16, usize: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "FilterClientWindows", 63usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).FilterClientWindows)(this, aaclasslist, usize), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn GetCodePageA(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, ucodepage: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetCodePageA", 64usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetCodePageA)(this, hkl, ucodepage), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn GetLangId(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, plid: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetLangId", 65usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetLangId)(this, hkl, plid), |result| Some(*result))
+        }
+        unsafe extern "system" fn AssociateContextEx(this: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND, himc: HIMC, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "AssociateContextEx", 66usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).AssociateContextEx)(this, hwnd, himc, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableIME(this: *mut core::ffi::c_void, idthread: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "DisableIME", 67usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).DisableIME)(this, idthread), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetImeMenuItemsA(this: *mut core::ffi::c_void, himc: HIMC, dwflags: u32, dwtype: u32, pimeparentmenu: *const IMEMENUITEMINFOA, pimemenu: *mut IMEMENUITEMINFOA, dwsize: u32, pdwresult: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetImeMenuItemsA", 68usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetImeMenuItemsA)(this, himc, dwflags, dwtype, pimeparentmenu, pimemenu, dwsize, pdwresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetImeMenuItemsW(this: *mut core::ffi::c_void, himc: HIMC, dwflags: u32, dwtype: u32, pimeparentmenu: *const IMEMENUITEMINFOW, pimemenu: *mut IMEMENUITEMINFOW, dwsize: u32, pdwresult: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "GetImeMenuItemsW", 69usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).GetImeMenuItemsW)(this, himc, dwflags, dwtype, pimeparentmenu, pimemenu, dwsize, pdwresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumInputContext(this: *mut core::ffi::c_void, idthread: u32, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMApp", "EnumInputContext", 70usize, |this| ((**(this as *const *const IActiveIMMApp_Vtbl)).EnumInputContext)(this, idthread, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AssociateContext,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            ConfigureIMEA,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            ConfigureIMEA: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            ConfigureIMEW,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            ConfigureIMEW: 0,
+            CreateContext,
+            DestroyContext,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            EnumRegisterWordA,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            EnumRegisterWordA: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            EnumRegisterWordW,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            EnumRegisterWordW: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            EscapeA,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            EscapeA: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            EscapeW,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            EscapeW: 0,
+            GetCandidateListA,
+            GetCandidateListW,
+            GetCandidateListCountA,
+            GetCandidateListCountW,
+            GetCandidateWindow,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetCompositionFontA,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetCompositionFontA: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetCompositionFontW,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetCompositionFontW: 0,
+            GetCompositionStringA,
+            GetCompositionStringW,
+            GetCompositionWindow,
+            GetContext,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            GetConversionListA,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            GetConversionListA: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            GetConversionListW,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            GetConversionListW: 0,
+            GetConversionStatus,
+            GetDefaultIMEWnd,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            GetDescriptionA,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            GetDescriptionA: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            GetDescriptionW,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            GetDescriptionW: 0,
+            GetGuideLineA,
+            GetGuideLineW,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            GetIMEFileNameA,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            GetIMEFileNameA: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            GetIMEFileNameW,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            GetIMEFileNameW: 0,
+            GetOpenStatus,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            GetProperty,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            GetProperty: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            GetRegisterWordStyleA,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            GetRegisterWordStyleA: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            GetRegisterWordStyleW,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            GetRegisterWordStyleW: 0,
+            GetStatusWindowPos,
+            GetVirtualKey,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            InstallIMEA,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            InstallIMEA: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            InstallIMEW,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            InstallIMEW: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            IsIME,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            IsIME: 0,
+            IsUIMessageA,
+            IsUIMessageW,
+            NotifyIME,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            RegisterWordA,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            RegisterWordA: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            RegisterWordW,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            RegisterWordW: 0,
+            ReleaseContext,
+            SetCandidateWindow,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            SetCompositionFontA,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            SetCompositionFontA: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            SetCompositionFontW,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            SetCompositionFontW: 0,
+            SetCompositionStringA,
+            SetCompositionStringW,
+            SetCompositionWindow,
+            SetConversionStatus,
+            SetOpenStatus,
+            SetStatusWindowPos,
+            SimulateHotKey,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            UnregisterWordA,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            UnregisterWordA: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            UnregisterWordW,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            UnregisterWordW: 0,
+            Activate,
+            Deactivate,
+            OnDefWindowProc,
+            FilterClientWindows,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            GetCodePageA,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            GetCodePageA: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            GetLangId,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            GetLangId: 0,
+            AssociateContextEx,
+            DisableIME,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetImeMenuItemsA,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetImeMenuItemsA: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetImeMenuItemsW,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetImeMenuItemsW: 0,
+            EnumInputContext,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveIMMApp as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_Input_KeyboardAndMouse"))]
+impl windows_core::imp::ProxyVtable for IActiveIMMIME_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AssociateContext(this: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND, hime: HIMC, phprev: *mut HIMC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "AssociateContext", 3usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).AssociateContext)(this, hwnd, hime, phprev), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn ConfigureIMEA(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, hwnd: super::super::super::Foundation::HWND, dwmode: u32, pdata: *const REGISTERWORDA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "ConfigureIMEA", 4usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).ConfigureIMEA)(this, hkl, hwnd, dwmode, pdata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn ConfigureIMEW(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, hwnd: super::super::super::Foundation::HWND, dwmode: u32, pdata: *const REGISTERWORDW) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "ConfigureIMEW", 5usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).ConfigureIMEW)(this, hkl, hwnd, dwmode, pdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateContext(this: *mut core::ffi::c_void, phimc: *mut HIMC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "CreateContext", 6usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).CreateContext)(this, phimc), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestroyContext(this: *mut core::ffi::c_void, hime: HIMC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "DestroyContext", 7usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).DestroyContext)(this, hime), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn EnumRegisterWordA(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, szreading: windows_core::PCSTR, dwstyle: u32, szregister: windows_core::PCSTR, pdata: *const core::ffi::c_void, penum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "EnumRegisterWordA", 8usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).EnumRegisterWordA)(this, hkl, szreading, dwstyle, szregister, pdata, penum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn EnumRegisterWordW(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, szreading: windows_core::PCWSTR, dwstyle: u32, szregister: windows_core::PCWSTR, pdata: *const core::ffi::c_void, penum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "EnumRegisterWordW", 9usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).EnumRegisterWordW)(this, hkl, szreading, dwstyle, szregister, pdata, penum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn EscapeA(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, himc: HIMC, uescape: u32, pdata: *mut core::ffi::c_void, plresult: *mut super::super::super::Foundation::LRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "EscapeA", 10usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).EscapeA)(this, hkl, himc, uescape, pdata, plresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn EscapeW(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, himc: HIMC, uescape: u32, pdata: *mut core::ffi::c_void, plresult: *mut super::super::super::Foundation::LRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "EscapeW", 11usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).EscapeW)(this, hkl, himc, uescape, pdata, plresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCandidateListA(this: *mut core::ffi::c_void, himc: HIMC, dwindex: u32, ubuflen: u32, pcandlist: *mut CANDIDATELIST, pucopied: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetCandidateListA", 12usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetCandidateListA)(this, himc, dwindex, ubuflen, pcandlist, pucopied), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCandidateListW(this: *mut core::ffi::c_void, himc: HIMC, dwindex: u32, ubuflen: u32, pcandlist: *mut CANDIDATELIST, pucopied: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetCandidateListW", 13usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetCandidateListW)(this, himc, dwindex, ubuflen, pcandlist, pucopied), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCandidateListCountA(this: *mut core::ffi::c_void, himc: HIMC, pdwlistsize: *mut u32, pdwbuflen: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetCandidateListCountA", 14usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetCandidateListCountA)(this, himc, pdwlistsize, pdwbuflen), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCandidateListCountW(this: *mut core::ffi::c_void, himc: HIMC, pdwlistsize: *mut u32, pdwbuflen: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetCandidateListCountW", 15usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetCandidateListCountW)(this, himc, pdwlistsize, pdwbuflen), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCandidateWindow(this: *mut core::ffi::c_void, himc: HIMC, dwindex: u32, pcandidate: *mut CANDIDATEFORM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetCandidateWindow", 16usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetCandidateWindow)(this, himc, dwindex, pcandidate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetCompositionFontA(this: *mut core::ffi::c_void, himc: HIMC, plf: *mut super::super::super::Graphics::Gdi::LOGFONTA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetCompositionFontA", 17usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetCompositionFontA)(this, himc, plf), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetCompositionFontW(this: *mut core::ffi::c_void, himc: HIMC, plf: *mut super::super::super::Graphics::Gdi::LOGFONTW) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetCompositionFontW", 18usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetCompositionFontW)(this, himc, plf), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCompositionStringA(this: *mut core::ffi::c_void, himc: HIMC, dwindex: u32, dwbuflen: u32, plcopied: *mut i32, pbuf: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetCompositionStringA", 19usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetCompositionStringA)(this, himc, dwindex, dwbuflen, plcopied, pbuf), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCompositionStringW(this: *mut core::ffi::c_void, himc: HIMC, dwindex: u32, dwbuflen: u32, plcopied: *mut i32, pbuf: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetCompositionStringW", 20usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetCompositionStringW)(this, himc, dwindex, dwbuflen, plcopied, pbuf), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCompositionWindow(this: *mut core::ffi::c_void, himc: HIMC, pcompform: *mut COMPOSITIONFORM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetCompositionWindow", 21usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetCompositionWindow)(this, himc, pcompform), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContext(this: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND, phimc: *mut HIMC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetContext", 22usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetContext)(this, hwnd, phimc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn GetConversionListA(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, himc: HIMC, psrc: windows_core::PCSTR, ubuflen: u32, uflag: u32, pdst: *mut CANDIDATELIST, pucopied: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetConversionListA", 23usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetConversionListA)(this, hkl, himc, psrc, ubuflen, uflag, pdst, pucopied), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn GetConversionListW(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, himc: HIMC, psrc: windows_core::PCWSTR, ubuflen: u32, uflag: u32, pdst: *mut CANDIDATELIST, pucopied: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetConversionListW", 24usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetConversionListW)(this, hkl, himc, psrc, ubuflen, uflag, pdst, pucopied), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConversionStatus(this: *mut core::ffi::c_void, himc: HIMC, pfdwconversion: *mut u32, pfdwsentence: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetConversionStatus", 25usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetConversionStatus)(this, himc, pfdwconversion, pfdwsentence), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultIMEWnd(this: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND, phdefwnd: *mut super::super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetDefaultIMEWnd", 26usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetDefaultIMEWnd)(this, hwnd, phdefwnd), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn GetDescriptionA(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, ubuflen: u32, szdescription: windows_core::PSTR, pucopied: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetDescriptionA", 27usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetDescriptionA)(this, hkl, ubuflen, szdescription, pucopied), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn GetDescriptionW(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, ubuflen: u32, szdescription: windows_core::PWSTR, pucopied: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetDescriptionW", 28usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetDescriptionW)(this, hkl, ubuflen, szdescription, pucopied), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGuideLineA(this: *mut core::ffi::c_void, himc: HIMC, dwindex: u32, dwbuflen: u32, pbuf: windows_core::PSTR, pdwresult: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetGuideLineA", 29usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetGuideLineA)(this, himc, dwindex, dwbuflen, pbuf, pdwresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGuideLineW(this: *mut core::ffi::c_void, himc: HIMC, dwindex: u32, dwbuflen: u32, pbuf: windows_core::PWSTR, pdwresult: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetGuideLineW", 30usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetGuideLineW)(this, himc, dwindex, dwbuflen, pbuf, pdwresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn GetIMEFileNameA(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, ubuflen: u32, szfilename: windows_core::PSTR, pucopied: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetIMEFileNameA", 31usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetIMEFileNameA)(this, hkl, ubuflen, szfilename, pucopied), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn GetIMEFileNameW(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, ubuflen: u32, szfilename: windows_core::PWSTR, pucopied: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetIMEFileNameW", 32usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetIMEFileNameW)(this, hkl, ubuflen, szfilename, pucopied), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOpenStatus(this: *mut core::ffi::c_void, himc: HIMC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetOpenStatus", 33usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetOpenStatus)(this, himc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, fdwindex: u32, pdwproperty: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetProperty", 34usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetProperty)(this, hkl, fdwindex, pdwproperty), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn GetRegisterWordStyleA(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, nitem: u32, pstylebuf: *mut STYLEBUFA, pucopied: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetRegisterWordStyleA", 35usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetRegisterWordStyleA)(this, hkl, nitem, pstylebuf, pucopied), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn GetRegisterWordStyleW(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, nitem: u32, pstylebuf: *mut STYLEBUFW, pucopied: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetRegisterWordStyleW", 36usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetRegisterWordStyleW)(this, hkl, nitem, pstylebuf, pucopied), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatusWindowPos(this: *mut core::ffi::c_void, himc: HIMC, pptpos: *mut super::super::super::Foundation::POINT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetStatusWindowPos", 37usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetStatusWindowPos)(this, himc, pptpos), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVirtualKey(this: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND, puvirtualkey: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetVirtualKey", 38usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetVirtualKey)(this, hwnd, puvirtualkey), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn InstallIMEA(this: *mut core::ffi::c_void, szimefilename: windows_core::PCSTR, szlayouttext: windows_core::PCSTR, phkl: *mut super::KeyboardAndMouse::HKL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "InstallIMEA", 39usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).InstallIMEA)(this, szimefilename, szlayouttext, phkl), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn InstallIMEW(this: *mut core::ffi::c_void, szimefilename: windows_core::PCWSTR, szlayouttext: windows_core::PCWSTR, phkl: *mut super::KeyboardAndMouse::HKL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "InstallIMEW", 40usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).InstallIMEW)(this, szimefilename, szlayouttext, phkl), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn IsIME(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "IsIME", 41usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).IsIME)(this, hkl), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUIMessageA(this: *mut core::ffi::c_void, hwndime: super::super::super::Foundation::HWND, msg: u32, wparam: super::super::super::Foundation::WPARAM, lparam: super::super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "IsUIMessageA", 42usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).IsUIMessageA)(this, hwndime, msg, wparam, lparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsUIMessageW(this: *mut core::ffi::c_void, hwndime: super::super::super::Foundation::HWND, msg: u32, wparam: super::super::super::Foundation::WPARAM, lparam: super::super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "IsUIMessageW", 43usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).IsUIMessageW)(this, hwndime, msg, wparam, lparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn NotifyIME(this: *mut core::ffi::c_void, himc: HIMC, dwaction: u32, dwindex: u32, dwvalue: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "NotifyIME", 44usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).NotifyIME)(this, himc, dwaction, dwindex, dwvalue), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn RegisterWordA(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, szreading: windows_core::PCSTR, dwstyle: u32, szregister: windows_core::PCSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "RegisterWordA", 45usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).RegisterWordA)(this, hkl, szreading, dwstyle, szregister), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn RegisterWordW(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, szreading: windows_core::PCWSTR, dwstyle: u32, szregister: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "RegisterWordW", 46usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).RegisterWordW)(this, hkl, szreading, dwstyle, szregister), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseContext(this: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND, himc: HIMC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "ReleaseContext", 47usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).ReleaseContext)(this, hwnd, himc), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCandidateWindow(this: *mut core::ffi::c_void, himc: HIMC, pcandidate: *const CANDIDATEFORM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "SetCandidateWindow", 48usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).SetCandidateWindow)(this, himc, pcandidate), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn SetCompositionFontA(this: *mut core::ffi::c_void, himc: HIMC, plf: *const super::super::super::Graphics::Gdi::LOGFONTA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "SetCompositionFontA", 49usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).SetCompositionFontA)(this, himc, plf), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn SetCompositionFontW(this: *mut core::ffi::c_void, himc: HIMC, plf: *const super::super::super::Graphics::Gdi::LOGFONTW) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "SetCompositionFontW", 50usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).SetCompositionFontW)(this, himc, plf), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompositionStringA(this: *mut core::ffi::c_void, himc: HIMC, dwindex: u32, pcomp: *const core::ffi::c_void, dwcomplen: u32, pread: *const core::ffi::c_void, dwreadlen: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "SetCompositionStringA", 51usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).SetCompositionStringA)(this, himc, dwindex, pcomp, dwcomplen, pread, dwreadlen), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompositionStringW(this: *mut core::ffi::c_void, himc: HIMC, dwindex: u32, pcomp: *const core::ffi::c_void, dwcomplen: u32, pread: *const core::ffi::c_void, dwreadlen: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "SetCompositionStringW", 52usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).SetCompositionStringW)(this, himc, dwindex, pcomp, dwcomplen, pread, dwreadlen), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCompositionWindow(this: *mut core::ffi::c_void, himc: HIMC, pcompform: *const COMPOSITIONFORM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "SetCompositionWindow", 53usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).SetCompositionWindow)(this, himc, pcompform), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetConversionStatus(this: *mut core::ffi::c_void, himc: HIMC, fdwconversion: u32, fdwsentence: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "SetConversionStatus", 54usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).SetConversionStatus)(this, himc, fdwconversion, fdwsentence), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOpenStatus(this: *mut core::ffi::c_void, himc: HIMC, fopen: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "SetOpenStatus", 55usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).SetOpenStatus)(this, himc, fopen), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatusWindowPos(this: *mut core::ffi::c_void, himc: HIMC, pptpos: *const super::super::super::Foundation::POINT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "SetStatusWindowPos", 56usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).SetStatusWindowPos)(this, himc, pptpos), |result| Some(*result))
+        }
+        unsafe extern "system" fn SimulateHotKey(this: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND, dwhotkeyid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "SimulateHotKey", 57usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).SimulateHotKey)(this, hwnd, dwhotkeyid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn UnregisterWordA(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, szreading: windows_core::PCSTR, dwstyle: u32, szunregister: windows_core::PCSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "UnregisterWordA", 58usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).UnregisterWordA)(this, hkl, szreading, dwstyle, szunregister), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn UnregisterWordW(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, szreading: windows_core::PCWSTR, dwstyle: u32, szunregister: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "UnregisterWordW", 59usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).UnregisterWordW)(this, hkl, szreading, dwstyle, szunregister), |result| Some(*result))
+        }
+        unsafe extern "system" fn GenerateMessage(this: *mut core::ffi::c_void, himc: HIMC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GenerateMessage", 60usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GenerateMessage)(this, himc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn LockIMC(this: *mut core::ffi::c_void, himc: HIMC, ppimc: *mut *mut INPUTCONTEXT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "LockIMC", 61usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).LockIMC)(this, himc, ppimc), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockIMC(this: *mut core::ffi::c_void, himc: HIMC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "UnlockIMC", 62usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).UnlockIMC)(this, himc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIMCLockCount(this: *mut core::ffi::c_void, himc: HIMC, pdwlockcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetIMCLockCount", 63usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetIMCLockCount)(this, himc, pdwlockcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateIMCC(this: *mut core::ffi::c_void, dwsize: u32, phimcc: *mut HIMCC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "CreateIMCC", 64usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).CreateIMCC)(this, dwsize, phimcc), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestroyIMCC(this: *mut core::ffi::c_void, himcc: HIMCC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "DestroyIMCC", 65usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).DestroyIMCC)(this, himcc), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockIMCC(this: *mut core::ffi::c_void, himcc: HIMCC, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "LockIMCC", 66usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).LockIMCC)(this, himcc, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockIMCC(this: *mut core::ffi::c_void, himcc: HIMCC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "UnlockIMCC", 67usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).UnlockIMCC)(this, himcc), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReSizeIMCC(this: *mut core::ffi::c_void, himcc: HIMCC, dwsize: u32, phimcc: *mut HIMCC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "ReSizeIMCC", 68usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).ReSizeIMCC)(this, himcc, dwsize, phimcc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIMCCSize(this: *mut core::ffi::c_void, himcc: HIMCC, pdwsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetIMCCSize", 69usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetIMCCSize)(this, himcc, pdwsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIMCCLockCount(this: *mut core::ffi::c_void, himcc: HIMCC, pdwlockcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetIMCCLockCount", 70usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetIMCCLockCount)(this, himcc, pdwlockcount), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn GetHotKey(this: *mut core::ffi::c_void, dwhotkeyid: u32, pumodifiers: *mut u32, puvkey: *mut u32, phkl: *mut super::KeyboardAndMouse::HKL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetHotKey", 71usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetHotKey)(this, dwhotkeyid, pumodifiers, puvkey, phkl), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn SetHotKey(this: *mut core::ffi::c_void, dwhotkeyid: u32, umodifiers: u32, uvkey: u32, hkl: super::KeyboardAndMouse::HKL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "SetHotKey", 72usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).SetHotKey)(this, dwhotkeyid, umodifiers, uvkey, hkl), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateSoftKeyboard(this: *mut core::ffi::c_void, utype: u32, howner: super::super::super::Foundation::HWND, x: i32, y: i32, phsoftkbdwnd: *mut super::super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "CreateSoftKeyboard", 73usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).CreateSoftKeyboard)(this, utype, howner, x, y, phsoftkbdwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn DestroySoftKeyboard(this: *mut core::ffi::c_void, hsoftkbdwnd: super::super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "DestroySoftKeyboard", 74usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).DestroySoftKeyboard)(this, hsoftkbdwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowSoftKeyboard(this: *mut core::ffi::c_void, hsoftkbdwnd: super::super::super::Foundation::HWND, ncmdshow: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "ShowSoftKeyboard", 75usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).ShowSoftKeyboard)(this, hsoftkbdwnd, ncmdshow), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn GetCodePageA(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, ucodepage: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetCodePageA", 76usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetCodePageA)(this, hkl, ucodepage), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn GetLangId(this: *mut core::ffi::c_void, hkl: super::KeyboardAndMouse::HKL, plid: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetLangId", 77usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetLangId)(this, hkl, plid), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeybdEvent(this: *mut core::ffi::c_void, lgidime: u16, bvk: u8, bscan: u8, dwflags: u32, dwextrainfo: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "KeybdEvent", 78usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).KeybdEvent)(this, lgidime, bvk, bscan, dwflags, dwextrainfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn LockModal(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "LockModal", 79usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).LockModal)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnlockModal(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "UnlockModal", 80usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).UnlockModal)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn AssociateContextEx(this: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND, himc: HIMC, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "AssociateContextEx", 81usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).AssociateContextEx)(this, hwnd, himc, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableIME(this: *mut core::ffi::c_void, idthread: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "DisableIME", 82usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).DisableIME)(this, idthread), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetImeMenuItemsA(this: *mut core::ffi::c_void, himc: HIMC, dwflags: u32, dwtype: u32, pimeparentmenu: *const IMEMENUITEMINFOA, pimemenu: *mut IMEMENUITEMINFOA, dwsize: u32, pdwresult: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetImeMenuItemsA", 83usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetImeMenuItemsA)(this, himc, dwflags, dwtype, pimeparentmenu, pimemenu, dwsize, pdwresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetImeMenuItemsW(this: *mut core::ffi::c_void, himc: HIMC, dwflags: u32, dwtype: u32, pimeparentmenu: *const IMEMENUITEMINFOW, pimemenu: *mut IMEMENUITEMINFOW, dwsize: u32, pdwresult: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "GetImeMenuItemsW", 84usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).GetImeMenuItemsW)(this, himc, dwflags, dwtype, pimeparentmenu, pimemenu, dwsize, pdwresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumInputContext(this: *mut core::ffi::c_void, idthread: u32, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "EnumInputContext", 85usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).EnumInputContext)(this, idthread, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestMessageA(this: *mut core::ffi::c_void, himc: HIMC, wparam: super::super::super::Foundation::WPARAM, lparam: super::super::super::Foundation::LPARAM, plresult: *mut super::super::super::Foundation::LRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "RequestMessageA", 86usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).RequestMessageA)(this, himc, wparam, lparam, plresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestMessageW(this: *mut core::ffi::c_void, himc: HIMC, wparam: super::super::super::Foundation::WPARAM, lparam: super::super::super::Foundation::LPARAM, plresult: *mut super::super::super::Foundation::LRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "RequestMessageW", 87usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).RequestMessageW)(this, himc, wparam, lparam, plresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendIMCA(this: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND, umsg: u32, wparam: super::super::super::Foundation::WPARAM, lparam: super::super::super::Foundation::LPARAM, plresult: *mut super::super::super::Foundation::LRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "SendIMCA", 88usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).SendIMCA)(this, hwnd, umsg, wparam, lparam, plresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn SendIMCW(this: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND, umsg: u32, wparam: super::super::super::Foundation::WPARAM, lparam: super::super::super::Foundation::LPARAM, plresult: *mut super::super::super::Foundation::LRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "SendIMCW", 89usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).SendIMCW)(this, hwnd, umsg, wparam, lparam, plresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsSleeping(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMIME", "IsSleeping", 90usize, |this| ((**(this as *const *const IActiveIMMIME_Vtbl)).IsSleeping)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AssociateContext,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            ConfigureIMEA,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            ConfigureIMEA: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            ConfigureIMEW,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            ConfigureIMEW: 0,
+            CreateContext,
+            DestroyContext,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            EnumRegisterWordA,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            EnumRegisterWordA: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            EnumRegisterWordW,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            EnumRegisterWordW: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            EscapeA,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            EscapeA: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            EscapeW,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            EscapeW: 0,
+            GetCandidateListA,
+            GetCandidateListW,
+            GetCandidateListCountA,
+            GetCandidateListCountW,
+            GetCandidateWindow,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetCompositionFontA,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetCompositionFontA: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetCompositionFontW,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetCompositionFontW: 0,
+            GetCompositionStringA,
+            GetCompositionStringW,
+            GetCompositionWindow,
+            GetContext,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            GetConversionListA,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            GetConversionListA: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            GetConversionListW,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            GetConversionListW: 0,
+            GetConversionStatus,
+            GetDefaultIMEWnd,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            GetDescriptionA,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            GetDescriptionA: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            GetDescriptionW,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            GetDescriptionW: 0,
+            GetGuideLineA,
+            GetGuideLineW,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            GetIMEFileNameA,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            GetIMEFileNameA: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            GetIMEFileNameW,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            GetIMEFileNameW: 0,
+            GetOpenStatus,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            GetProperty,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            GetProperty: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            GetRegisterWordStyleA,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            GetRegisterWordStyleA: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            GetRegisterWordStyleW,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            GetRegisterWordStyleW: 0,
+            GetStatusWindowPos,
+            GetVirtualKey,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            InstallIMEA,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            InstallIMEA: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            InstallIMEW,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            InstallIMEW: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            IsIME,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            IsIME: 0,
+            IsUIMessageA,
+            IsUIMessageW,
+            NotifyIME,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            RegisterWordA,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            RegisterWordA: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            RegisterWordW,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            RegisterWordW: 0,
+            ReleaseContext,
+            SetCandidateWindow,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            SetCompositionFontA,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            SetCompositionFontA: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            SetCompositionFontW,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            SetCompositionFontW: 0,
+            SetCompositionStringA,
+            SetCompositionStringW,
+            SetCompositionWindow,
+            SetConversionStatus,
+            SetOpenStatus,
+            SetStatusWindowPos,
+            SimulateHotKey,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            UnregisterWordA,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            UnregisterWordA: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            UnregisterWordW,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            UnregisterWordW: 0,
+            GenerateMessage,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            LockIMC,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            LockIMC: 0,
+            UnlockIMC,
+            GetIMCLockCount,
+            CreateIMCC,
+            DestroyIMCC,
+            LockIMCC,
+            UnlockIMCC,
+            ReSizeIMCC,
+            GetIMCCSize,
+            GetIMCCLockCount,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            GetHotKey,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            GetHotKey: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            SetHotKey,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            SetHotKey: 0,
+            CreateSoftKeyboard,
+            DestroySoftKeyboard,
+            ShowSoftKeyboard,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            GetCodePageA,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            GetCodePageA: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            GetLangId,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            GetLangId: 0,
+            KeybdEvent,
+            LockModal,
+            UnlockModal,
+            AssociateContextEx,
+            DisableIME,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetImeMenuItemsA,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetImeMenuItemsA: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetImeMenuItemsW,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetImeMenuItemsW: 0,
+            EnumInputContext,
+            RequestMessageA,
+            RequestMessageW,
+            SendIMCA,
+            SendIMCW,
+            IsSleeping,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveIMMIME as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl windows_core::imp::ProxyVtable for IActiveIMMMessagePumpOwner_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Start(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMMessagePumpOwner", "Start", 3usize, |this| ((**(this as *const *const IActiveIMMMessagePumpOwner_Vtbl)).Start)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn End(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMMessagePumpOwner", "End", 4usize, |this| ((**(this as *const *const IActiveIMMMessagePumpOwner_Vtbl)).End)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn OnTranslateMessage(this: *mut core::ffi::c_void, pmsg: *const super::super::WindowsAndMessaging::MSG) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMMessagePumpOwner", "OnTranslateMessage", 5usize, |this| ((**(this as *const *const IActiveIMMMessagePumpOwner_Vtbl)).OnTranslateMessage)(this, pmsg), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pause(this: *mut core::ffi::c_void, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMMessagePumpOwner", "Pause", 6usize, |this| ((**(this as *const *const IActiveIMMMessagePumpOwner_Vtbl)).Pause)(this, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn Resume(this: *mut core::ffi::c_void, dwcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMMessagePumpOwner", "Resume", 7usize, |this| ((**(this as *const *const IActiveIMMMessagePumpOwner_Vtbl)).Resume)(this, dwcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Start,
+            End,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            OnTranslateMessage,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            OnTranslateMessage: 0,
+            Pause,
+            Resume,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveIMMMessagePumpOwner as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveIMMRegistrar_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterIME(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, lgid: u16, psziconfile: windows_core::PCWSTR, pszdesc: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMRegistrar", "RegisterIME", 3usize, |this| ((**(this as *const *const IActiveIMMRegistrar_Vtbl)).RegisterIME)(this, rclsid, lgid, psziconfile, pszdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterIME(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveIMMRegistrar", "UnregisterIME", 4usize, |this| ((**(this as *const *const IActiveIMMRegistrar_Vtbl)).UnregisterIME)(this, rclsid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterIME, UnregisterIME }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveIMMRegistrar as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumInputContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumInputContext", "Clone", 3usize, |this| ((**(this as *const *const IEnumInputContext_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ulcount: u32, rginputcontext: *mut HIMC, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumInputContext", "Next", 4usize, |this| ((**(this as *const *const IEnumInputContext_Vtbl)).Next)(this, ulcount, rginputcontext, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumInputContext", "Reset", 5usize, |this| ((**(this as *const *const IEnumInputContext_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, ulcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumInputContext", "Skip", 6usize, |this| ((**(this as *const *const IEnumInputContext_Vtbl)).Skip)(this, ulcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Clone, Next, Reset, Skip }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumInputContext as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumRegisterWordA_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumRegisterWordA", "Clone", 3usize, |this| ((**(this as *const *const IEnumRegisterWordA_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ulcount: u32, rgregisterword: *mut REGISTERWORDA, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumRegisterWordA", "Next", 4usize, |this| ((**(this as *const *const IEnumRegisterWordA_Vtbl)).Next)(this, ulcount, rgregisterword, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumRegisterWordA", "Reset", 5usize, |this| ((**(this as *const *const IEnumRegisterWordA_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, ulcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumRegisterWordA", "Skip", 6usize, |this| ((**(this as *const *const IEnumRegisterWordA_Vtbl)).Skip)(this, ulcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Clone, Next, Reset, Skip }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumRegisterWordA as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumRegisterWordW_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumRegisterWordW", "Clone", 3usize, |this| ((**(this as *const *const IEnumRegisterWordW_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ulcount: u32, rgregisterword: *mut REGISTERWORDW, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumRegisterWordW", "Next", 4usize, |this| ((**(this as *const *const IEnumRegisterWordW_Vtbl)).Next)(this, ulcount, rgregisterword, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumRegisterWordW", "Reset", 5usize, |this| ((**(this as *const *const IEnumRegisterWordW_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, ulcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumRegisterWordW", "Skip", 6usize, |this| ((**(this as *const *const IEnumRegisterWordW_Vtbl)).Skip)(this, ulcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Clone, Next, Reset, Skip }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumRegisterWordW as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IFEClassFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::super::System::Com::IClassFactory_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFEClassFactory as windows_core::Interface>::IID || <super::super::super::System::Com::IClassFactory_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFECommon_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsDefaultIME(this: *mut core::ffi::c_void, szname: windows_core::PCSTR, cszname: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFECommon", "IsDefaultIME", 3usize, |this| ((**(this as *const *const IFECommon_Vtbl)).IsDefaultIME)(this, szname, cszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultIME(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFECommon", "SetDefaultIME", 4usize, |this| ((**(this as *const *const IFECommon_Vtbl)).SetDefaultIME)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn InvokeWordRegDialog(this: *mut core::ffi::c_void, pimedlg: *mut IMEDLG) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFECommon", "InvokeWordRegDialog", 5usize, |this| ((**(this as *const *const IFECommon_Vtbl)).InvokeWordRegDialog)(this, pimedlg), |result| Some(*result))
+        }
+        unsafe extern "system" fn InvokeDictToolDialog(this: *mut core::ffi::c_void, pimedlg: *mut IMEDLG) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFECommon", "InvokeDictToolDialog", 6usize, |this| ((**(this as *const *const IFECommon_Vtbl)).InvokeDictToolDialog)(this, pimedlg), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsDefaultIME,
+            SetDefaultIME,
+            InvokeWordRegDialog,
+            InvokeDictToolDialog,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFECommon as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFEDictionary_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void, pchdictpath: windows_core::PSTR, pshf: *mut IMESHF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFEDictionary", "Open", 3usize, |this| ((**(this as *const *const IFEDictionary_Vtbl)).Open)(this, pchdictpath, pshf), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFEDictionary", "Close", 4usize, |this| ((**(this as *const *const IFEDictionary_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHeader(this: *mut core::ffi::c_void, pchdictpath: windows_core::PSTR, pshf: *mut IMESHF, pjfmt: *mut IMEFMT, pultype: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFEDictionary", "GetHeader", 5usize, |this| ((**(this as *const *const IFEDictionary_Vtbl)).GetHeader)(this, pchdictpath, pshf, pjfmt, pultype), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisplayProperty(this: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFEDictionary", "DisplayProperty", 6usize, |this| ((**(this as *const *const IFEDictionary_Vtbl)).DisplayProperty)(this, hwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPosTable(this: *mut core::ffi::c_void, prgpostbl: *mut *mut POSTBL, pcpostbl: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFEDictionary", "GetPosTable", 7usize, |this| ((**(this as *const *const IFEDictionary_Vtbl)).GetPosTable)(this, prgpostbl, pcpostbl), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWords(this: *mut core::ffi::c_void, pwchfirst: windows_core::PCWSTR, pwchlast: windows_core::PCWSTR, pwchdisplay: windows_core::PCWSTR, ulpos: u32, ulselect: u32, ulwordsrc: u32, pchbuffer: *mut u8, cbbuffer: u32, pcwrd: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFEDictionary", "GetWords", 8usize, |this| ((**(this as *const *const IFEDictionary_Vtbl)).GetWords)(this, pwchfirst, pwchlast, pwchdisplay, ulpos, ulselect, ulwordsrc, pchbuffer, cbbuffer, pcwrd), |result| Some(*result))
+        }
+        unsafe extern "system" fn NextWords(this: *mut core::ffi::c_void, pchbuffer: *mut u8, cbbuffer: u32, pcwrd: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFEDictionary", "NextWords", 9usize, |this| ((**(this as *const *const IFEDictionary_Vtbl)).NextWords)(this, pchbuffer, cbbuffer, pcwrd), |result| Some(*result))
+        }
+        unsafe extern "system" fn Create(this: *mut core::ffi::c_void, pchdictpath: windows_core::PCSTR, pshf: *mut IMESHF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFEDictionary", "Create", 10usize, |this| ((**(this as *const *const IFEDictionary_Vtbl)).Create)(this, pchdictpath, pshf), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHeader(this: *mut core::ffi::c_void, pshf: *mut IMESHF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFEDictionary", "SetHeader", 11usize, |this| ((**(this as *const *const IFEDictionary_Vtbl)).SetHeader)(this, pshf), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExistWord(this: *mut core::ffi::c_void, pwrd: *mut IMEWRD) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFEDictionary", "ExistWord", 12usize, |this| ((**(this as *const *const IFEDictionary_Vtbl)).ExistWord)(this, pwrd), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExistDependency(this: *mut core::ffi::c_void, pdp: *mut IMEDP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFEDictionary", "ExistDependency", 13usize, |this| ((**(this as *const *const IFEDictionary_Vtbl)).ExistDependency)(this, pdp), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterWord(this: *mut core::ffi::c_void, reg: IMEREG, pwrd: *mut IMEWRD) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFEDictionary", "RegisterWord", 14usize, |this| ((**(this as *const *const IFEDictionary_Vtbl)).RegisterWord)(this, reg, pwrd), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterDependency(this: *mut core::ffi::c_void, reg: IMEREG, pdp: *mut IMEDP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFEDictionary", "RegisterDependency", 15usize, |this| ((**(this as *const *const IFEDictionary_Vtbl)).RegisterDependency)(this, reg, pdp), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDependencies(this: *mut core::ffi::c_void, pwchkakarireading: windows_core::PCWSTR, pwchkakaridisplay: windows_core::PCWSTR, ulkakaripos: u32, pwchukereading: windows_core::PCWSTR, pwchukedisplay: windows_core::PCWSTR, ulukepos: u32, jrel: IMEREL, ulwordsrc: u32, pchbuffer: *mut u8, cbbuffer: u32, pcdp: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFEDictionary", "GetDependencies", 16usize, |this| ((**(this as *const *const IFEDictionary_Vtbl)).GetDependencies)(this, pwchkakarireading, pwchkakaridisplay, ulkakaripos, pwchukereading, pwchukedisplay, ulukepos, jrel, ulwordsrc, pchbuffer, cbbuffer, pcdp), |result| Some(*result))
+        }
+        unsafe extern "system" fn NextDependencies(this: *mut core::ffi::c_void, pchbuffer: *mut u8, cbbuffer: u32, pcdp: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFEDictionary", "NextDependencies", 17usize, |this| ((**(this as *const *const IFEDictionary_Vtbl)).NextDependencies)(this, pchbuffer, cbbuffer, pcdp), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertFromOldMSIME(this: *mut core::ffi::c_void, pchdic: windows_core::PCSTR, pfnlog: PFNLOG, reg: IMEREG) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFEDictionary", "ConvertFromOldMSIME", 18usize, |this| ((**(this as *const *const IFEDictionary_Vtbl)).ConvertFromOldMSIME)(this, pchdic, pfnlog, reg), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertFromUserToSys(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFEDictionary", "ConvertFromUserToSys", 19usize, |this| ((**(this as *const *const IFEDictionary_Vtbl)).ConvertFromUserToSys)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Open,
+            Close,
+            GetHeader,
+            DisplayProperty,
+            GetPosTable,
+            GetWords,
+            NextWords,
+            Create,
+            SetHeader,
+            ExistWord,
+            ExistDependency,
+            RegisterWord,
+            RegisterDependency,
+            GetDependencies,
+            NextDependencies,
+            ConvertFromOldMSIME,
+            ConvertFromUserToSys,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFEDictionary as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IFELanguage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Open(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFELanguage", "Open", 3usize, |this| ((**(this as *const *const IFELanguage_Vtbl)).Open)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Close(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFELanguage", "Close", 4usize, |this| ((**(this as *const *const IFELanguage_Vtbl)).Close)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetJMorphResult(this: *mut core::ffi::c_void, dwrequest: u32, dwcmode: u32, cwchinput: i32, pwchinput: windows_core::PCWSTR, pfcinfo: *mut u32, ppresult: *mut *mut MORRSLT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFELanguage", "GetJMorphResult", 5usize, |this| ((**(this as *const *const IFELanguage_Vtbl)).GetJMorphResult)(this, dwrequest, dwcmode, cwchinput, pwchinput, pfcinfo, ppresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConversionModeCaps(this: *mut core::ffi::c_void, pdwcaps: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFELanguage", "GetConversionModeCaps", 6usize, |this| ((**(this as *const *const IFELanguage_Vtbl)).GetConversionModeCaps)(this, pdwcaps), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPhonetic(this: *mut core::ffi::c_void, string: core::mem::MaybeUninit<windows_core::BSTR>, start: i32, length: i32, phonetic: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFELanguage", "GetPhonetic", 7usize, |this| ((**(this as *const *const IFELanguage_Vtbl)).GetPhonetic)(this, string, start, length, phonetic), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConversion(this: *mut core::ffi::c_void, string: core::mem::MaybeUninit<windows_core::BSTR>, start: i32, length: i32, result: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IFELanguage", "GetConversion", 8usize, |this| ((**(this as *const *const IFELanguage_Vtbl)).GetConversion)(this, string, start, length, result), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Open,
+            Close,
+            GetJMorphResult,
+            GetConversionModeCaps,
+            GetPhonetic,
+            GetConversion,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IFELanguage as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IImePad_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Request(this: *mut core::ffi::c_void, piimepadapplet: *mut core::ffi::c_void, reqid: i32, wparam: super::super::super::Foundation::WPARAM, lparam: super::super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImePad", "Request", 3usize, |this| ((**(this as *const *const IImePad_Vtbl)).Request)(this, piimepadapplet, reqid, wparam, lparam), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Request }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImePad as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl windows_core::imp::ProxyVtable for IImePadApplet_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, lpiimepad: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImePadApplet", "Initialize", 3usize, |this| ((**(this as *const *const IImePadApplet_Vtbl)).Initialize)(this, lpiimepad), |result| Some(*result))
+        }
+        unsafe extern "system" fn Terminate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImePadApplet", "Terminate", 4usize, |this| ((**(this as *const *const IImePadApplet_Vtbl)).Terminate)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn GetAppletConfig(this: *mut core::ffi::c_void, lpappletcfg: *mut IMEAPPLETCFG) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImePadApplet", "GetAppletConfig", 5usize, |this| ((**(this as *const *const IImePadApplet_Vtbl)).GetAppletConfig)(this, lpappletcfg), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateUI(this: *mut core::ffi::c_void, hwndparent: super::super::super::Foundation::HWND, lpimeappletui: *mut IMEAPPLETUI) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImePadApplet", "CreateUI", 6usize, |this| ((**(this as *const *const IImePadApplet_Vtbl)).CreateUI)(this, hwndparent, lpimeappletui), |result| Some(*result))
+        }
+        unsafe extern "system" fn Notify(this: *mut core::ffi::c_void, lpimepad: *mut core::ffi::c_void, notify: i32, wparam: super::super::super::Foundation::WPARAM, lparam: super::super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImePadApplet", "Notify", 7usize, |this| ((**(this as *const *const IImePadApplet_Vtbl)).Notify)(this, lpimepad, notify, wparam, lparam), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            Terminate,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            GetAppletConfig,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            GetAppletConfig: 0,
+            CreateUI,
+            Notify,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImePadApplet as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IImePlugInDictDictionaryList_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetDictionariesInUse(this: *mut core::ffi::c_void, prgdictionaryguid: *mut *mut super::super::super::System::Com::SAFEARRAY, prgdatecreated: *mut *mut super::super::super::System::Com::SAFEARRAY, prgfencrypted: *mut *mut super::super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImePlugInDictDictionaryList", "GetDictionariesInUse", 3usize, |this| ((**(this as *const *const IImePlugInDictDictionaryList_Vtbl)).GetDictionariesInUse)(this, prgdictionaryguid, prgdatecreated, prgfencrypted), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteDictionary(this: *mut core::ffi::c_void, bstrdictionaryguid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImePlugInDictDictionaryList", "DeleteDictionary", 4usize, |this| ((**(this as *const *const IImePlugInDictDictionaryList_Vtbl)).DeleteDictionary)(this, bstrdictionaryguid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetDictionariesInUse,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetDictionariesInUse: 0,
+            DeleteDictionary,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImePlugInDictDictionaryList as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IImeSpecifyApplets_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAppletIIDList(this: *mut core::ffi::c_void, refiid: *const windows_core::GUID, lpiidlist: *mut APPLETIDLIST) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImeSpecifyApplets", "GetAppletIIDList", 3usize, |this| ((**(this as *const *const IImeSpecifyApplets_Vtbl)).GetAppletIIDList)(this, refiid, lpiidlist), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAppletIIDList }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImeSpecifyApplets as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -167,6 +167,7 @@ pub struct INK_HIGH_CONTRAST_ADJUSTMENT(pub i32);
 impl windows_core::TypeKind for INK_HIGH_CONTRAST_ADJUSTMENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INK_HIGH_CONTRAST_ADJUSTMENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INK_HIGH_CONTRAST_ADJUSTMENT").field(&self.0).finish()
@@ -176,3 +177,5 @@ pub const InkD2DRenderer: windows_core::GUID = windows_core::GUID::from_u128(0x4
 pub const InkDesktopHost: windows_core::GUID = windows_core::GUID::from_u128(0x062584a6_f830_4bdc_a4d2_0a10ab062b1d);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,102 @@
+impl windows_core::imp::ProxyVtable for IInkCommitRequestHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnCommitRequested(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCommitRequestHandler", "OnCommitRequested", 3usize, |this| ((**(this as *const *const IInkCommitRequestHandler_Vtbl)).OnCommitRequested)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnCommitRequested }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkCommitRequestHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkD2DRenderer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Draw(this: *mut core::ffi::c_void, pd2d1devicecontext: *mut core::ffi::c_void, pinkstrokeiterable: *mut core::ffi::c_void, fhighcontrast: super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkD2DRenderer", "Draw", 3usize, |this| ((**(this as *const *const IInkD2DRenderer_Vtbl)).Draw)(this, pd2d1devicecontext, pinkstrokeiterable, fhighcontrast), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Draw }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkD2DRenderer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkD2DRenderer2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Draw(this: *mut core::ffi::c_void, pd2d1devicecontext: *mut core::ffi::c_void, pinkstrokeiterable: *mut core::ffi::c_void, highcontrastadjustment: INK_HIGH_CONTRAST_ADJUSTMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkD2DRenderer2", "Draw", 3usize, |this| ((**(this as *const *const IInkD2DRenderer2_Vtbl)).Draw)(this, pd2d1devicecontext, pinkstrokeiterable, highcontrastadjustment), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Draw }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkD2DRenderer2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkDesktopHost_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueueWorkItem(this: *mut core::ffi::c_void, workitem: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDesktopHost", "QueueWorkItem", 3usize, |this| ((**(this as *const *const IInkDesktopHost_Vtbl)).QueueWorkItem)(this, workitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateInkPresenter(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDesktopHost", "CreateInkPresenter", 4usize, |this| ((**(this as *const *const IInkDesktopHost_Vtbl)).CreateInkPresenter)(this, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateAndInitializeInkPresenter(this: *mut core::ffi::c_void, rootvisual: *mut core::ffi::c_void, width: f32, height: f32, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDesktopHost", "CreateAndInitializeInkPresenter", 5usize, |this| ((**(this as *const *const IInkDesktopHost_Vtbl)).CreateAndInitializeInkPresenter)(this, rootvisual, width, height, riid, ppv), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            QueueWorkItem,
+            CreateInkPresenter,
+            CreateAndInitializeInkPresenter,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkDesktopHost as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkHostWorkItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Invoke(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkHostWorkItem", "Invoke", 3usize, |this| ((**(this as *const *const IInkHostWorkItem_Vtbl)).Invoke)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Invoke }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkHostWorkItem as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkPresenterDesktop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetRootVisual(this: *mut core::ffi::c_void, rootvisual: *mut core::ffi::c_void, device: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterDesktop", "SetRootVisual", 3usize, |this| ((**(this as *const *const IInkPresenterDesktop_Vtbl)).SetRootVisual)(this, rootvisual, device), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCommitRequestHandler(this: *mut core::ffi::c_void, handler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterDesktop", "SetCommitRequestHandler", 4usize, |this| ((**(this as *const *const IInkPresenterDesktop_Vtbl)).SetCommitRequestHandler)(this, handler), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSize(this: *mut core::ffi::c_void, width: *mut f32, height: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterDesktop", "GetSize", 5usize, |this| ((**(this as *const *const IInkPresenterDesktop_Vtbl)).GetSize)(this, width, height), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSize(this: *mut core::ffi::c_void, width: f32, height: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterDesktop", "SetSize", 6usize, |this| ((**(this as *const *const IInkPresenterDesktop_Vtbl)).SetSize)(this, width, height), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnHighContrastChanged(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPresenterDesktop", "OnHighContrastChanged", 7usize, |this| ((**(this as *const *const IInkPresenterDesktop_Vtbl)).OnHighContrastChanged)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetRootVisual,
+            SetCommitRequestHandler,
+            GetSize,
+            SetSize,
+            OnHighContrastChanged,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkPresenterDesktop as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -0,0 +1,36 @@
+impl windows_core::imp::ProxyVtable for IRadialControllerConfigurationInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetForWindow(this: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerConfigurationInterop", "GetForWindow", 6usize, |this| ((**(this as *const *const IRadialControllerConfigurationInterop_Vtbl)).GetForWindow)(this, hwnd, riid, ppv), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetForWindow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerConfigurationInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRadialControllerIndependentInputSourceInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateForWindow(this: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerIndependentInputSourceInterop", "CreateForWindow", 6usize, |this| ((**(this as *const *const IRadialControllerIndependentInputSourceInterop_Vtbl)).CreateForWindow)(this, hwnd, riid, ppv), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateForWindow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerIndependentInputSourceInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRadialControllerInterop_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateForWindow(this: *mut core::ffi::c_void, hwnd: super::super::super::Foundation::HWND, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRadialControllerInterop", "CreateForWindow", 6usize, |this| ((**(this as *const *const IRadialControllerInterop_Vtbl)).CreateForWindow)(this, hwnd, riid, ppv), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateForWindow }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRadialControllerInterop as windows_core::Interface>::IID || <windows_core::IInspectable_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -478,6 +478,7 @@ pub struct GESTURECONFIG_ID(pub u32);
 impl windows_core::TypeKind for GESTURECONFIG_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GESTURECONFIG_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GESTURECONFIG_ID").field(&self.0).finish()
@@ -522,6 +523,7 @@ pub struct MANIPULATION_PROCESSOR_MANIPULATIONS(pub i32);
 impl windows_core::TypeKind for MANIPULATION_PROCESSOR_MANIPULATIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MANIPULATION_PROCESSOR_MANIPULATIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MANIPULATION_PROCESSOR_MANIPULATIONS").field(&self.0).finish()
@@ -566,6 +568,7 @@ pub struct REGISTER_TOUCH_WINDOW_FLAGS(pub u32);
 impl windows_core::TypeKind for REGISTER_TOUCH_WINDOW_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for REGISTER_TOUCH_WINDOW_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("REGISTER_TOUCH_WINDOW_FLAGS").field(&self.0).finish()
@@ -577,6 +580,7 @@ pub struct TOUCHEVENTF_FLAGS(pub u32);
 impl windows_core::TypeKind for TOUCHEVENTF_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TOUCHEVENTF_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TOUCHEVENTF_FLAGS").field(&self.0).finish()
@@ -621,6 +625,7 @@ pub struct TOUCHINPUTMASKF_MASK(pub u32);
 impl windows_core::TypeKind for TOUCHINPUTMASKF_MASK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TOUCHINPUTMASKF_MASK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TOUCHINPUTMASKF_MASK").field(&self.0).finish()
@@ -660,7 +665,8 @@ impl core::ops::Not for TOUCHINPUTMASKF_MASK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GESTURECONFIG {
     pub dwID: GESTURECONFIG_ID,
     pub dwWant: u32,
@@ -675,7 +681,8 @@ impl Default for GESTURECONFIG {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GESTUREINFO {
     pub cbSize: u32,
     pub dwFlags: u32,
@@ -696,7 +703,8 @@ impl Default for GESTUREINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GESTURENOTIFYSTRUCT {
     pub cbSize: u32,
     pub dwFlags: u32,
@@ -713,7 +721,8 @@ impl Default for GESTURENOTIFYSTRUCT {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HGESTUREINFO(pub *mut core::ffi::c_void);
 impl HGESTUREINFO {
     pub fn is_invalid(&self) -> bool {
@@ -737,7 +746,8 @@ impl windows_core::TypeKind for HGESTUREINFO {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HTOUCHINPUT(pub *mut core::ffi::c_void);
 impl HTOUCHINPUT {
     pub fn is_invalid(&self) -> bool {
@@ -763,7 +773,8 @@ impl windows_core::TypeKind for HTOUCHINPUT {
 pub const InertiaProcessor: windows_core::GUID = windows_core::GUID::from_u128(0xabb27087_4ce0_4e58_a0cb_e24df96814be);
 pub const ManipulationProcessor: windows_core::GUID = windows_core::GUID::from_u128(0x597d4fb0_47fd_4aff_89b9_c6cfae8cf08e);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TOUCHINPUT {
     pub x: i32,
     pub y: i32,
@@ -786,3 +797,5 @@ impl Default for TOUCHINPUT {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,320 @@
+impl windows_core::imp::ProxyVtable for IInertiaProcessor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitialOriginX(this: *mut core::ffi::c_void, x: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "InitialOriginX", 3usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).InitialOriginX)(this, x), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInitialOriginX(this: *mut core::ffi::c_void, x: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "SetInitialOriginX", 4usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).SetInitialOriginX)(this, x), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitialOriginY(this: *mut core::ffi::c_void, y: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "InitialOriginY", 5usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).InitialOriginY)(this, y), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInitialOriginY(this: *mut core::ffi::c_void, y: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "SetInitialOriginY", 6usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).SetInitialOriginY)(this, y), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitialVelocityX(this: *mut core::ffi::c_void, x: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "InitialVelocityX", 7usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).InitialVelocityX)(this, x), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInitialVelocityX(this: *mut core::ffi::c_void, x: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "SetInitialVelocityX", 8usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).SetInitialVelocityX)(this, x), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitialVelocityY(this: *mut core::ffi::c_void, y: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "InitialVelocityY", 9usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).InitialVelocityY)(this, y), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInitialVelocityY(this: *mut core::ffi::c_void, y: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "SetInitialVelocityY", 10usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).SetInitialVelocityY)(this, y), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitialAngularVelocity(this: *mut core::ffi::c_void, velocity: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "InitialAngularVelocity", 11usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).InitialAngularVelocity)(this, velocity), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInitialAngularVelocity(this: *mut core::ffi::c_void, velocity: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "SetInitialAngularVelocity", 12usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).SetInitialAngularVelocity)(this, velocity), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitialExpansionVelocity(this: *mut core::ffi::c_void, velocity: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "InitialExpansionVelocity", 13usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).InitialExpansionVelocity)(this, velocity), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInitialExpansionVelocity(this: *mut core::ffi::c_void, velocity: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "SetInitialExpansionVelocity", 14usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).SetInitialExpansionVelocity)(this, velocity), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitialRadius(this: *mut core::ffi::c_void, radius: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "InitialRadius", 15usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).InitialRadius)(this, radius), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInitialRadius(this: *mut core::ffi::c_void, radius: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "SetInitialRadius", 16usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).SetInitialRadius)(this, radius), |result| Some(*result))
+        }
+        unsafe extern "system" fn BoundaryLeft(this: *mut core::ffi::c_void, left: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "BoundaryLeft", 17usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).BoundaryLeft)(this, left), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBoundaryLeft(this: *mut core::ffi::c_void, left: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "SetBoundaryLeft", 18usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).SetBoundaryLeft)(this, left), |result| Some(*result))
+        }
+        unsafe extern "system" fn BoundaryTop(this: *mut core::ffi::c_void, top: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "BoundaryTop", 19usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).BoundaryTop)(this, top), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBoundaryTop(this: *mut core::ffi::c_void, top: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "SetBoundaryTop", 20usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).SetBoundaryTop)(this, top), |result| Some(*result))
+        }
+        unsafe extern "system" fn BoundaryRight(this: *mut core::ffi::c_void, right: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "BoundaryRight", 21usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).BoundaryRight)(this, right), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBoundaryRight(this: *mut core::ffi::c_void, right: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "SetBoundaryRight", 22usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).SetBoundaryRight)(this, right), |result| Some(*result))
+        }
+        unsafe extern "system" fn BoundaryBottom(this: *mut core::ffi::c_void, bottom: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "BoundaryBottom", 23usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).BoundaryBottom)(this, bottom), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBoundaryBottom(this: *mut core::ffi::c_void, bottom: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "SetBoundaryBottom", 24usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).SetBoundaryBottom)(this, bottom), |result| Some(*result))
+        }
+        unsafe extern "system" fn ElasticMarginLeft(this: *mut core::ffi::c_void, left: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "ElasticMarginLeft", 25usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).ElasticMarginLeft)(this, left), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetElasticMarginLeft(this: *mut core::ffi::c_void, left: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "SetElasticMarginLeft", 26usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).SetElasticMarginLeft)(this, left), |result| Some(*result))
+        }
+        unsafe extern "system" fn ElasticMarginTop(this: *mut core::ffi::c_void, top: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "ElasticMarginTop", 27usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).ElasticMarginTop)(this, top), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetElasticMarginTop(this: *mut core::ffi::c_void, top: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "SetElasticMarginTop", 28usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).SetElasticMarginTop)(this, top), |result| Some(*result))
+        }
+        unsafe extern "system" fn ElasticMarginRight(this: *mut core::ffi::c_void, right: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "ElasticMarginRight", 29usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).ElasticMarginRight)(this, right), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetElasticMarginRight(this: *mut core::ffi::c_void, right: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "SetElasticMarginRight", 30usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).SetElasticMarginRight)(this, right), |result| Some(*result))
+        }
+        unsafe extern "system" fn ElasticMarginBottom(this: *mut core::ffi::c_void, bottom: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "ElasticMarginBottom", 31usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).ElasticMarginBottom)(this, bottom), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetElasticMarginBottom(this: *mut core::ffi::c_void, bottom: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "SetElasticMarginBottom", 32usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).SetElasticMarginBottom)(this, bottom), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredDisplacement(this: *mut core::ffi::c_void, displacement: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "DesiredDisplacement", 33usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).DesiredDisplacement)(this, displacement), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredDisplacement(this: *mut core::ffi::c_void, displacement: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "SetDesiredDisplacement", 34usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).SetDesiredDisplacement)(this, displacement), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredRotation(this: *mut core::ffi::c_void, rotation: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "DesiredRotation", 35usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).DesiredRotation)(this, rotation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredRotation(this: *mut core::ffi::c_void, rotation: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "SetDesiredRotation", 36usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).SetDesiredRotation)(this, rotation), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredExpansion(this: *mut core::ffi::c_void, expansion: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "DesiredExpansion", 37usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).DesiredExpansion)(this, expansion), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredExpansion(this: *mut core::ffi::c_void, expansion: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "SetDesiredExpansion", 38usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).SetDesiredExpansion)(this, expansion), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredDeceleration(this: *mut core::ffi::c_void, deceleration: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "DesiredDeceleration", 39usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).DesiredDeceleration)(this, deceleration), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredDeceleration(this: *mut core::ffi::c_void, deceleration: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "SetDesiredDeceleration", 40usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).SetDesiredDeceleration)(this, deceleration), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredAngularDeceleration(this: *mut core::ffi::c_void, deceleration: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "DesiredAngularDeceleration", 41usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).DesiredAngularDeceleration)(this, deceleration), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredAngularDeceleration(this: *mut core::ffi::c_void, deceleration: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "SetDesiredAngularDeceleration", 42usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).SetDesiredAngularDeceleration)(this, deceleration), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredExpansionDeceleration(this: *mut core::ffi::c_void, deceleration: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "DesiredExpansionDeceleration", 43usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).DesiredExpansionDeceleration)(this, deceleration), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredExpansionDeceleration(this: *mut core::ffi::c_void, deceleration: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "SetDesiredExpansionDeceleration", 44usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).SetDesiredExpansionDeceleration)(this, deceleration), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitialTimestamp(this: *mut core::ffi::c_void, timestamp: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "InitialTimestamp", 45usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).InitialTimestamp)(this, timestamp), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInitialTimestamp(this: *mut core::ffi::c_void, timestamp: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "SetInitialTimestamp", 46usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).SetInitialTimestamp)(this, timestamp), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "Reset", 47usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Process(this: *mut core::ffi::c_void, completed: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "Process", 48usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).Process)(this, completed), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessTime(this: *mut core::ffi::c_void, timestamp: u32, completed: *mut super::super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "ProcessTime", 49usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).ProcessTime)(this, timestamp, completed), |result| Some(*result))
+        }
+        unsafe extern "system" fn Complete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "Complete", 50usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).Complete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompleteTime(this: *mut core::ffi::c_void, timestamp: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInertiaProcessor", "CompleteTime", 51usize, |this| ((**(this as *const *const IInertiaProcessor_Vtbl)).CompleteTime)(this, timestamp), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InitialOriginX,
+            SetInitialOriginX,
+            InitialOriginY,
+            SetInitialOriginY,
+            InitialVelocityX,
+            SetInitialVelocityX,
+            InitialVelocityY,
+            SetInitialVelocityY,
+            InitialAngularVelocity,
+            SetInitialAngularVelocity,
+            InitialExpansionVelocity,
+            SetInitialExpansionVelocity,
+            InitialRadius,
+            SetInitialRadius,
+            BoundaryLeft,
+            SetBoundaryLeft,
+            BoundaryTop,
+            SetBoundaryTop,
+            BoundaryRight,
+            SetBoundaryRight,
+            BoundaryBottom,
+            SetBoundaryBottom,
+            ElasticMarginLeft,
+            SetElasticMarginLeft,
+            ElasticMarginTop,
+            SetElasticMarginTop,
+            ElasticMarginRight,
+            SetElasticMarginRight,
+            ElasticMarginBottom,
+            SetElasticMarginBottom,
+            DesiredDisplacement,
+            SetDesiredDisplacement,
+            DesiredRotation,
+            SetDesiredRotation,
+            DesiredExpansion,
+            SetDesiredExpansion,
+            DesiredDeceleration,
+            SetDesiredDeceleration,
+            DesiredAngularDeceleration,
+            SetDesiredAngularDeceleration,
+            DesiredExpansionDeceleration,
+            SetDesiredExpansionDeceleration,
+            InitialTimestamp,
+            SetInitialTimestamp,
+            Reset,
+            Process,
+            ProcessTime,
+            Complete,
+            CompleteTime,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInertiaProcessor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IManipulationProcessor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SupportedManipulations(this: *mut core::ffi::c_void, manipulations: *mut MANIPULATION_PROCESSOR_MANIPULATIONS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationProcessor", "SupportedManipulations", 3usize, |this| ((**(this as *const *const IManipulationProcessor_Vtbl)).SupportedManipulations)(this, manipulations), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSupportedManipulations(this: *mut core::ffi::c_void, manipulations: MANIPULATION_PROCESSOR_MANIPULATIONS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationProcessor", "SetSupportedManipulations", 4usize, |this| ((**(this as *const *const IManipulationProcessor_Vtbl)).SetSupportedManipulations)(this, manipulations), |result| Some(*result))
+        }
+        unsafe extern "system" fn PivotPointX(this: *mut core::ffi::c_void, pivotpointx: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationProcessor", "PivotPointX", 5usize, |this| ((**(this as *const *const IManipulationProcessor_Vtbl)).PivotPointX)(this, pivotpointx), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPivotPointX(this: *mut core::ffi::c_void, pivotpointx: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationProcessor", "SetPivotPointX", 6usize, |this| ((**(this as *const *const IManipulationProcessor_Vtbl)).SetPivotPointX)(this, pivotpointx), |result| Some(*result))
+        }
+        unsafe extern "system" fn PivotPointY(this: *mut core::ffi::c_void, pivotpointy: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationProcessor", "PivotPointY", 7usize, |this| ((**(this as *const *const IManipulationProcessor_Vtbl)).PivotPointY)(this, pivotpointy), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPivotPointY(this: *mut core::ffi::c_void, pivotpointy: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationProcessor", "SetPivotPointY", 8usize, |this| ((**(this as *const *const IManipulationProcessor_Vtbl)).SetPivotPointY)(this, pivotpointy), |result| Some(*result))
+        }
+        unsafe extern "system" fn PivotRadius(this: *mut core::ffi::c_void, pivotradius: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationProcessor", "PivotRadius", 9usize, |this| ((**(this as *const *const IManipulationProcessor_Vtbl)).PivotRadius)(this, pivotradius), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPivotRadius(this: *mut core::ffi::c_void, pivotradius: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationProcessor", "SetPivotRadius", 10usize, |this| ((**(this as *const *const IManipulationProcessor_Vtbl)).SetPivotRadius)(this, pivotradius), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompleteManipulation(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationProcessor", "CompleteManipulation", 11usize, |this| ((**(this as *const *const IManipulationProcessor_Vtbl)).CompleteManipulation)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessDown(this: *mut core::ffi::c_void, manipulatorid: u32, x: f32, y: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationProcessor", "ProcessDown", 12usize, |this| ((**(this as *const *const IManipulationProcessor_Vtbl)).ProcessDown)(this, manipulatorid, x, y), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessMove(this: *mut core::ffi::c_void, manipulatorid: u32, x: f32, y: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationProcessor", "ProcessMove", 13usize, |this| ((**(this as *const *const IManipulationProcessor_Vtbl)).ProcessMove)(this, manipulatorid, x, y), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessUp(this: *mut core::ffi::c_void, manipulatorid: u32, x: f32, y: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationProcessor", "ProcessUp", 14usize, |this| ((**(this as *const *const IManipulationProcessor_Vtbl)).ProcessUp)(this, manipulatorid, x, y), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessDownWithTime(this: *mut core::ffi::c_void, manipulatorid: u32, x: f32, y: f32, timestamp: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationProcessor", "ProcessDownWithTime", 15usize, |this| ((**(this as *const *const IManipulationProcessor_Vtbl)).ProcessDownWithTime)(this, manipulatorid, x, y, timestamp), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessMoveWithTime(this: *mut core::ffi::c_void, manipulatorid: u32, x: f32, y: f32, timestamp: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationProcessor", "ProcessMoveWithTime", 16usize, |this| ((**(this as *const *const IManipulationProcessor_Vtbl)).ProcessMoveWithTime)(this, manipulatorid, x, y, timestamp), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessUpWithTime(this: *mut core::ffi::c_void, manipulatorid: u32, x: f32, y: f32, timestamp: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationProcessor", "ProcessUpWithTime", 17usize, |this| ((**(this as *const *const IManipulationProcessor_Vtbl)).ProcessUpWithTime)(this, manipulatorid, x, y, timestamp), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVelocityX(this: *mut core::ffi::c_void, velocityx: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationProcessor", "GetVelocityX", 18usize, |this| ((**(this as *const *const IManipulationProcessor_Vtbl)).GetVelocityX)(this, velocityx), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVelocityY(this: *mut core::ffi::c_void, velocityy: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationProcessor", "GetVelocityY", 19usize, |this| ((**(this as *const *const IManipulationProcessor_Vtbl)).GetVelocityY)(this, velocityy), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetExpansionVelocity(this: *mut core::ffi::c_void, expansionvelocity: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationProcessor", "GetExpansionVelocity", 20usize, |this| ((**(this as *const *const IManipulationProcessor_Vtbl)).GetExpansionVelocity)(this, expansionvelocity), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAngularVelocity(this: *mut core::ffi::c_void, angularvelocity: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationProcessor", "GetAngularVelocity", 21usize, |this| ((**(this as *const *const IManipulationProcessor_Vtbl)).GetAngularVelocity)(this, angularvelocity), |result| Some(*result))
+        }
+        unsafe extern "system" fn MinimumScaleRotateRadius(this: *mut core::ffi::c_void, minradius: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationProcessor", "MinimumScaleRotateRadius", 22usize, |this| ((**(this as *const *const IManipulationProcessor_Vtbl)).MinimumScaleRotateRadius)(this, minradius), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMinimumScaleRotateRadius(this: *mut core::ffi::c_void, minradius: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IManipulationProcessor", "SetMinimumScaleRotateRadius", 23usize, |this| ((**(this as *const *const IManipulationProcessor_Vtbl)).SetMinimumScaleRotateRadius)(this, minradius), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SupportedManipulations,
+            SetSupportedManipulations,
+            PivotPointX,
+            SetPivotPointX,
+            PivotPointY,
+            SetPivotPointY,
+            PivotRadius,
+            SetPivotRadius,
+            CompleteManipulation,
+            ProcessDown,
+            ProcessMove,
+            ProcessUp,
+            ProcessDownWithTime,
+            ProcessMoveWithTime,
+            ProcessUpWithTime,
+            GetVelocityX,
+            GetVelocityY,
+            GetExpansionVelocity,
+            GetAngularVelocity,
+            MinimumScaleRotateRadius,
+            SetMinimumScaleRotateRadius,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IManipulationProcessor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for _IManipulationEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ManipulationStarted(this: *mut core::ffi::c_void, x: f32, y: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_IManipulationEvents", "ManipulationStarted", 3usize, |this| ((**(this as *const *const _IManipulationEvents_Vtbl)).ManipulationStarted)(this, x, y), |result| Some(*result))
+        }
+        unsafe extern "system" fn ManipulationDelta(this: *mut core::ffi::c_void, x: f32, y: f32, translationdeltax: f32, translationdeltay: f32, scaledelta: f32, expansiondelta: f32, rotationdelta: f32, cumulativetranslationx: f32, cumulativetranslationy: f32, cumulativescale: f32, cumulativeexpansion: f32, cumulativerotation: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_IManipulationEvents", "ManipulationDelta", 4usize, |this| ((**(this as *const *const _IManipulationEvents_Vtbl)).ManipulationDelta)(this, x, y, translationdeltax, translationdeltay, scaledelta, expansiondelta, rotationdelta, cumulativetranslationx, cumulativetranslationy, cumulativescale, cumulativeexpansion, cumulativerotation), |result| Some(*result))
+        }
+        unsafe extern "system" fn ManipulationCompleted(this: *mut core::ffi::c_void, x: f32, y: f32, cumulativetranslationx: f32, cumulativetranslationy: f32, cumulativescale: f32, cumulativeexpansion: f32, cumulativerotation: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "_IManipulationEvents", "ManipulationCompleted", 5usize, |this| ((**(this as *const *const _IManipulationEvents_Vtbl)).ManipulationCompleted)(this, x, y, cumulativetranslationx, cumulativetranslationy, cumulativescale, cumulativeexpansion, cumulativerotation), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ManipulationStarted, ManipulationDelta, ManipulationCompleted }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<_IManipulationEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
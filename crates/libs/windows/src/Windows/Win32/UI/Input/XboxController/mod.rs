@@ -129,6 +129,7 @@ pub struct BATTERY_DEVTYPE(pub u8);
 impl windows_core::TypeKind for BATTERY_DEVTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BATTERY_DEVTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BATTERY_DEVTYPE").field(&self.0).finish()
@@ -140,6 +141,7 @@ pub struct BATTERY_LEVEL(pub u8);
 impl windows_core::TypeKind for BATTERY_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BATTERY_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BATTERY_LEVEL").field(&self.0).finish()
@@ -151,6 +153,7 @@ pub struct BATTERY_TYPE(pub u8);
 impl windows_core::TypeKind for BATTERY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BATTERY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BATTERY_TYPE").field(&self.0).finish()
@@ -162,6 +165,7 @@ pub struct XINPUT_CAPABILITIES_FLAGS(pub u16);
 impl windows_core::TypeKind for XINPUT_CAPABILITIES_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XINPUT_CAPABILITIES_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XINPUT_CAPABILITIES_FLAGS").field(&self.0).finish()
@@ -206,6 +210,7 @@ pub struct XINPUT_DEVSUBTYPE(pub u8);
 impl windows_core::TypeKind for XINPUT_DEVSUBTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XINPUT_DEVSUBTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XINPUT_DEVSUBTYPE").field(&self.0).finish()
@@ -217,6 +222,7 @@ pub struct XINPUT_DEVTYPE(pub u8);
 impl windows_core::TypeKind for XINPUT_DEVTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XINPUT_DEVTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XINPUT_DEVTYPE").field(&self.0).finish()
@@ -228,6 +234,7 @@ pub struct XINPUT_FLAG(pub u32);
 impl windows_core::TypeKind for XINPUT_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XINPUT_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XINPUT_FLAG").field(&self.0).finish()
@@ -272,6 +279,7 @@ pub struct XINPUT_GAMEPAD_BUTTON_FLAGS(pub u16);
 impl windows_core::TypeKind for XINPUT_GAMEPAD_BUTTON_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XINPUT_GAMEPAD_BUTTON_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XINPUT_GAMEPAD_BUTTON_FLAGS").field(&self.0).finish()
@@ -316,6 +324,7 @@ pub struct XINPUT_KEYSTROKE_FLAGS(pub u16);
 impl windows_core::TypeKind for XINPUT_KEYSTROKE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XINPUT_KEYSTROKE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XINPUT_KEYSTROKE_FLAGS").field(&self.0).finish()
@@ -360,13 +369,15 @@ pub struct XINPUT_VIRTUAL_KEY(pub u16);
 impl windows_core::TypeKind for XINPUT_VIRTUAL_KEY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for XINPUT_VIRTUAL_KEY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("XINPUT_VIRTUAL_KEY").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XINPUT_BATTERY_INFORMATION {
     pub BatteryType: BATTERY_TYPE,
     pub BatteryLevel: BATTERY_LEVEL,
@@ -380,7 +391,8 @@ impl Default for XINPUT_BATTERY_INFORMATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XINPUT_CAPABILITIES {
     pub Type: XINPUT_DEVTYPE,
     pub SubType: XINPUT_DEVSUBTYPE,
@@ -397,7 +409,8 @@ impl Default for XINPUT_CAPABILITIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XINPUT_GAMEPAD {
     pub wButtons: XINPUT_GAMEPAD_BUTTON_FLAGS,
     pub bLeftTrigger: u8,
@@ -416,7 +429,8 @@ impl Default for XINPUT_GAMEPAD {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XINPUT_KEYSTROKE {
     pub VirtualKey: XINPUT_VIRTUAL_KEY,
     pub Unicode: u16,
@@ -433,7 +447,8 @@ impl Default for XINPUT_KEYSTROKE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XINPUT_STATE {
     pub dwPacketNumber: u32,
     pub Gamepad: XINPUT_GAMEPAD,
@@ -447,7 +462,8 @@ impl Default for XINPUT_STATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct XINPUT_VIBRATION {
     pub wLeftMotorSpeed: u16,
     pub wRightMotorSpeed: u16,
@@ -345,6 +345,7 @@ pub struct CROSS_SLIDE_FLAGS(pub u32);
 impl windows_core::TypeKind for CROSS_SLIDE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CROSS_SLIDE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CROSS_SLIDE_FLAGS").field(&self.0).finish()
@@ -389,6 +390,7 @@ pub struct CROSS_SLIDE_THRESHOLD(pub i32);
 impl windows_core::TypeKind for CROSS_SLIDE_THRESHOLD {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CROSS_SLIDE_THRESHOLD {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CROSS_SLIDE_THRESHOLD").field(&self.0).finish()
@@ -400,6 +402,7 @@ pub struct HOLD_PARAMETER(pub i32);
 impl windows_core::TypeKind for HOLD_PARAMETER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HOLD_PARAMETER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HOLD_PARAMETER").field(&self.0).finish()
@@ -411,6 +414,7 @@ pub struct INERTIA_PARAMETER(pub i32);
 impl windows_core::TypeKind for INERTIA_PARAMETER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INERTIA_PARAMETER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INERTIA_PARAMETER").field(&self.0).finish()
@@ -422,6 +426,7 @@ pub struct INTERACTION_CONFIGURATION_FLAGS(pub u32);
 impl windows_core::TypeKind for INTERACTION_CONFIGURATION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INTERACTION_CONFIGURATION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INTERACTION_CONFIGURATION_FLAGS").field(&self.0).finish()
@@ -466,6 +471,7 @@ pub struct INTERACTION_CONTEXT_PROPERTY(pub i32);
 impl windows_core::TypeKind for INTERACTION_CONTEXT_PROPERTY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INTERACTION_CONTEXT_PROPERTY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INTERACTION_CONTEXT_PROPERTY").field(&self.0).finish()
@@ -477,6 +483,7 @@ pub struct INTERACTION_FLAGS(pub u32);
 impl windows_core::TypeKind for INTERACTION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INTERACTION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INTERACTION_FLAGS").field(&self.0).finish()
@@ -521,6 +528,7 @@ pub struct INTERACTION_ID(pub i32);
 impl windows_core::TypeKind for INTERACTION_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INTERACTION_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INTERACTION_ID").field(&self.0).finish()
@@ -532,6 +540,7 @@ pub struct INTERACTION_STATE(pub i32);
 impl windows_core::TypeKind for INTERACTION_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INTERACTION_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INTERACTION_STATE").field(&self.0).finish()
@@ -543,6 +552,7 @@ pub struct MANIPULATION_RAILS_STATE(pub i32);
 impl windows_core::TypeKind for MANIPULATION_RAILS_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MANIPULATION_RAILS_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MANIPULATION_RAILS_STATE").field(&self.0).finish()
@@ -554,6 +564,7 @@ pub struct MOUSE_WHEEL_PARAMETER(pub i32);
 impl windows_core::TypeKind for MOUSE_WHEEL_PARAMETER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MOUSE_WHEEL_PARAMETER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MOUSE_WHEEL_PARAMETER").field(&self.0).finish()
@@ -565,6 +576,7 @@ pub struct TAP_PARAMETER(pub i32);
 impl windows_core::TypeKind for TAP_PARAMETER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TAP_PARAMETER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TAP_PARAMETER").field(&self.0).finish()
@@ -576,13 +588,15 @@ pub struct TRANSLATION_PARAMETER(pub i32);
 impl windows_core::TypeKind for TRANSLATION_PARAMETER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TRANSLATION_PARAMETER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TRANSLATION_PARAMETER").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CROSS_SLIDE_PARAMETER {
     pub threshold: CROSS_SLIDE_THRESHOLD,
     pub distance: f32,
@@ -596,7 +610,8 @@ impl Default for CROSS_SLIDE_PARAMETER {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HINTERACTIONCONTEXT(pub *mut core::ffi::c_void);
 impl HINTERACTIONCONTEXT {
     pub fn is_invalid(&self) -> bool {
@@ -620,7 +635,8 @@ impl windows_core::TypeKind for HINTERACTIONCONTEXT {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct INTERACTION_ARGUMENTS_CROSS_SLIDE {
     pub flags: CROSS_SLIDE_FLAGS,
 }
@@ -633,7 +649,8 @@ impl Default for INTERACTION_ARGUMENTS_CROSS_SLIDE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct INTERACTION_ARGUMENTS_MANIPULATION {
     pub delta: MANIPULATION_TRANSFORM,
     pub cumulative: MANIPULATION_TRANSFORM,
@@ -649,7 +666,8 @@ impl Default for INTERACTION_ARGUMENTS_MANIPULATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct INTERACTION_ARGUMENTS_TAP {
     pub count: u32,
 }
@@ -662,7 +680,8 @@ impl Default for INTERACTION_ARGUMENTS_TAP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct INTERACTION_CONTEXT_CONFIGURATION {
     pub interactionId: INTERACTION_ID,
     pub enable: INTERACTION_CONFIGURATION_FLAGS,
@@ -756,7 +775,8 @@ impl Default for INTERACTION_CONTEXT_OUTPUT2_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MANIPULATION_TRANSFORM {
     pub translationX: f32,
     pub translationY: f32,
@@ -773,7 +793,8 @@ impl Default for MANIPULATION_TRANSFORM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MANIPULATION_VELOCITY {
     pub velocityX: f32,
     pub velocityY: f32,
@@ -291,6 +291,7 @@ pub struct EMPTY_VOLUME_CACHE_FLAGS(pub u32);
 impl windows_core::TypeKind for EMPTY_VOLUME_CACHE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EMPTY_VOLUME_CACHE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EMPTY_VOLUME_CACHE_FLAGS").field(&self.0).finish()
@@ -302,6 +303,7 @@ pub struct RECONCILEF(pub i32);
 impl windows_core::TypeKind for RECONCILEF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RECONCILEF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RECONCILEF").field(&self.0).finish()
@@ -309,3 +311,5 @@ impl core::fmt::Debug for RECONCILEF {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
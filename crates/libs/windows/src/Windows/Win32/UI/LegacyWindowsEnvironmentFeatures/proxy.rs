@@ -0,0 +1,183 @@
+#[cfg(feature = "Win32_System_Ole")]
+impl windows_core::imp::ProxyVtable for IADesktopP2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ReReadWallpaper(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADesktopP2", "ReReadWallpaper", 3usize, |this| ((**(this as *const *const IADesktopP2_Vtbl)).ReReadWallpaper)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetADObjectFlags(this: *mut core::ffi::c_void, pdwflags: *mut u32, dwmask: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADesktopP2", "GetADObjectFlags", 4usize, |this| ((**(this as *const *const IADesktopP2_Vtbl)).GetADObjectFlags)(this, pdwflags, dwmask), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateAllDesktopSubscriptions(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADesktopP2", "UpdateAllDesktopSubscriptions", 5usize, |this| ((**(this as *const *const IADesktopP2_Vtbl)).UpdateAllDesktopSubscriptions)(this), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn MakeDynamicChanges(this: *mut core::ffi::c_void, poleobj: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IADesktopP2", "MakeDynamicChanges", 6usize, |this| ((**(this as *const *const IADesktopP2_Vtbl)).MakeDynamicChanges)(this, poleobj), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ReReadWallpaper,
+            GetADObjectFlags,
+            UpdateAllDesktopSubscriptions,
+            #[cfg(feature = "Win32_System_Ole")]
+            MakeDynamicChanges,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            MakeDynamicChanges: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IADesktopP2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveDesktopP_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSafeMode(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveDesktopP", "SetSafeMode", 3usize, |this| ((**(this as *const *const IActiveDesktopP_Vtbl)).SetSafeMode)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnsureUpdateHTML(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveDesktopP", "EnsureUpdateHTML", 4usize, |this| ((**(this as *const *const IActiveDesktopP_Vtbl)).EnsureUpdateHTML)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScheme(this: *mut core::ffi::c_void, pwszschemename: windows_core::PCWSTR, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveDesktopP", "SetScheme", 5usize, |this| ((**(this as *const *const IActiveDesktopP_Vtbl)).SetScheme)(this, pwszschemename, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScheme(this: *mut core::ffi::c_void, pwszschemename: windows_core::PWSTR, pdwcchbuffer: *mut u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveDesktopP", "GetScheme", 6usize, |this| ((**(this as *const *const IActiveDesktopP_Vtbl)).GetScheme)(this, pwszschemename, pdwcchbuffer, dwflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetSafeMode, EnsureUpdateHTML, SetScheme, GetScheme }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveDesktopP as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IBriefcaseInitiator_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn IsMonikerInBriefcase(this: *mut core::ffi::c_void, pmk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IBriefcaseInitiator", "IsMonikerInBriefcase", 3usize, |this| ((**(this as *const *const IBriefcaseInitiator_Vtbl)).IsMonikerInBriefcase)(this, pmk), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            IsMonikerInBriefcase,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            IsMonikerInBriefcase: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IBriefcaseInitiator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Registry")]
+impl windows_core::imp::ProxyVtable for IEmptyVolumeCache_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Registry")]
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, hkregkey: super::super::System::Registry::HKEY, pcwszvolume: windows_core::PCWSTR, ppwszdisplayname: *mut windows_core::PWSTR, ppwszdescription: *mut windows_core::PWSTR, pdwflags: *mut EMPTY_VOLUME_CACHE_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmptyVolumeCache", "Initialize", 3usize, |this| ((**(this as *const *const IEmptyVolumeCache_Vtbl)).Initialize)(this, hkregkey, pcwszvolume, ppwszdisplayname, ppwszdescription, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSpaceUsed(this: *mut core::ffi::c_void, pdwlspaceused: *mut u64, picb: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmptyVolumeCache", "GetSpaceUsed", 4usize, |this| ((**(this as *const *const IEmptyVolumeCache_Vtbl)).GetSpaceUsed)(this, pdwlspaceused, picb), |result| Some(*result))
+        }
+        unsafe extern "system" fn Purge(this: *mut core::ffi::c_void, dwlspacetofree: u64, picb: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmptyVolumeCache", "Purge", 5usize, |this| ((**(this as *const *const IEmptyVolumeCache_Vtbl)).Purge)(this, dwlspacetofree, picb), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowProperties(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmptyVolumeCache", "ShowProperties", 6usize, |this| ((**(this as *const *const IEmptyVolumeCache_Vtbl)).ShowProperties)(this, hwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deactivate(this: *mut core::ffi::c_void, pdwflags: *mut EMPTY_VOLUME_CACHE_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmptyVolumeCache", "Deactivate", 7usize, |this| ((**(this as *const *const IEmptyVolumeCache_Vtbl)).Deactivate)(this, pdwflags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Registry")]
+            Initialize,
+            #[cfg(not(feature = "Win32_System_Registry"))]
+            Initialize: 0,
+            GetSpaceUsed,
+            Purge,
+            ShowProperties,
+            Deactivate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmptyVolumeCache as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Registry")]
+impl windows_core::imp::ProxyVtable for IEmptyVolumeCache2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Registry")]
+        unsafe extern "system" fn InitializeEx(this: *mut core::ffi::c_void, hkregkey: super::super::System::Registry::HKEY, pcwszvolume: windows_core::PCWSTR, pcwszkeyname: windows_core::PCWSTR, ppwszdisplayname: *mut windows_core::PWSTR, ppwszdescription: *mut windows_core::PWSTR, ppwszbtntext: *mut windows_core::PWSTR, pdwflags: *mut EMPTY_VOLUME_CACHE_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmptyVolumeCache2", "InitializeEx", 8usize, |this| ((**(this as *const *const IEmptyVolumeCache2_Vtbl)).InitializeEx)(this, hkregkey, pcwszvolume, pcwszkeyname, ppwszdisplayname, ppwszdescription, ppwszbtntext, pdwflags), |result| Some(*result))
+        }
+        Self {
+            base__: <IEmptyVolumeCache_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Registry")]
+            InitializeEx,
+            #[cfg(not(feature = "Win32_System_Registry"))]
+            InitializeEx: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmptyVolumeCache2 as windows_core::Interface>::IID || <IEmptyVolumeCache_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEmptyVolumeCacheCallBack_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ScanProgress(this: *mut core::ffi::c_void, dwlspaceused: u64, dwflags: u32, pcwszstatus: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmptyVolumeCacheCallBack", "ScanProgress", 3usize, |this| ((**(this as *const *const IEmptyVolumeCacheCallBack_Vtbl)).ScanProgress)(this, dwlspaceused, dwflags, pcwszstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn PurgeProgress(this: *mut core::ffi::c_void, dwlspacefreed: u64, dwlspacetofree: u64, dwflags: u32, pcwszstatus: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEmptyVolumeCacheCallBack", "PurgeProgress", 4usize, |this| ((**(this as *const *const IEmptyVolumeCacheCallBack_Vtbl)).PurgeProgress)(this, dwlspacefreed, dwlspacetofree, dwflags, pcwszstatus), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ScanProgress, PurgeProgress }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEmptyVolumeCacheCallBack as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com_StructuredStorage")]
+impl windows_core::imp::ProxyVtable for IReconcilableObject_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+        unsafe extern "system" fn Reconcile(this: *mut core::ffi::c_void, pinitiator: *mut core::ffi::c_void, dwflags: u32, hwndowner: super::super::Foundation::HWND, hwndprogressfeedback: super::super::Foundation::HWND, ulcinput: u32, rgpmkotherinput: *mut *mut core::ffi::c_void, ploutindex: *mut i32, pstgnewresidues: *mut core::ffi::c_void, pvreserved: *const core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReconcilableObject", "Reconcile", 3usize, |this| ((**(this as *const *const IReconcilableObject_Vtbl)).Reconcile)(this, pinitiator, dwflags, hwndowner, hwndprogressfeedback, ulcinput, rgpmkotherinput, ploutindex, pstgnewresidues, pvreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProgressFeedbackMaxEstimate(this: *mut core::ffi::c_void, pulprogressmax: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReconcilableObject", "GetProgressFeedbackMaxEstimate", 4usize, |this| ((**(this as *const *const IReconcilableObject_Vtbl)).GetProgressFeedbackMaxEstimate)(this, pulprogressmax), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com_StructuredStorage")]
+            Reconcile,
+            #[cfg(not(feature = "Win32_System_Com_StructuredStorage"))]
+            Reconcile: 0,
+            GetProgressFeedbackMaxEstimate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IReconcilableObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IReconcileInitiator_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetAbortCallback(this: *mut core::ffi::c_void, punkforabort: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReconcileInitiator", "SetAbortCallback", 3usize, |this| ((**(this as *const *const IReconcileInitiator_Vtbl)).SetAbortCallback)(this, punkforabort), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetProgressFeedback(this: *mut core::ffi::c_void, ulprogress: u32, ulprogressmax: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IReconcileInitiator", "SetProgressFeedback", 4usize, |this| ((**(this as *const *const IReconcileInitiator_Vtbl)).SetProgressFeedback)(this, ulprogress, ulprogressmax), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetAbortCallback, SetProgressFeedback }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IReconcileInitiator as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -145,13 +145,15 @@ pub struct MW_FILTERMODE(pub u32);
 impl windows_core::TypeKind for MW_FILTERMODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MW_FILTERMODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MW_FILTERMODE").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MAGCOLOREFFECT {
     pub transform: [f32; 25],
 }
@@ -164,7 +166,8 @@ impl Default for MAGCOLOREFFECT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MAGIMAGEHEADER {
     pub width: u32,
     pub height: u32,
@@ -182,7 +185,8 @@ impl Default for MAGIMAGEHEADER {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MAGTRANSFORM {
     pub v: [f32; 9],
 }
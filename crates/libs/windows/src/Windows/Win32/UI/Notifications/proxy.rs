@@ -0,0 +1,12 @@
+impl windows_core::imp::ProxyVtable for INotificationActivationCallback_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Activate(this: *mut core::ffi::c_void, appusermodelid: windows_core::PCWSTR, invokedargs: windows_core::PCWSTR, data: *const NOTIFICATION_USER_INPUT_DATA, count: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INotificationActivationCallback", "Activate", 3usize, |this| ((**(this as *const *const INotificationActivationCallback_Vtbl)).Activate)(this, appusermodelid, invokedargs, data, count), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Activate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INotificationActivationCallback as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
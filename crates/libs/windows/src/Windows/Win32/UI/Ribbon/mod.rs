@@ -455,6 +455,7 @@ pub struct UI_COLLECTIONCHANGE(pub i32);
 impl windows_core::TypeKind for UI_COLLECTIONCHANGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_COLLECTIONCHANGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_COLLECTIONCHANGE").field(&self.0).finish()
@@ -466,6 +467,7 @@ pub struct UI_COMMANDTYPE(pub i32);
 impl windows_core::TypeKind for UI_COMMANDTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_COMMANDTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_COMMANDTYPE").field(&self.0).finish()
@@ -477,6 +479,7 @@ pub struct UI_CONTEXTAVAILABILITY(pub i32);
 impl windows_core::TypeKind for UI_CONTEXTAVAILABILITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_CONTEXTAVAILABILITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_CONTEXTAVAILABILITY").field(&self.0).finish()
@@ -488,6 +491,7 @@ pub struct UI_CONTROLDOCK(pub i32);
 impl windows_core::TypeKind for UI_CONTROLDOCK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_CONTROLDOCK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_CONTROLDOCK").field(&self.0).finish()
@@ -499,6 +503,7 @@ pub struct UI_EVENTLOCATION(pub i32);
 impl windows_core::TypeKind for UI_EVENTLOCATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_EVENTLOCATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_EVENTLOCATION").field(&self.0).finish()
@@ -510,6 +515,7 @@ pub struct UI_EVENTTYPE(pub i32);
 impl windows_core::TypeKind for UI_EVENTTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_EVENTTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_EVENTTYPE").field(&self.0).finish()
@@ -521,6 +527,7 @@ pub struct UI_EXECUTIONVERB(pub i32);
 impl windows_core::TypeKind for UI_EXECUTIONVERB {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_EXECUTIONVERB {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_EXECUTIONVERB").field(&self.0).finish()
@@ -532,6 +539,7 @@ pub struct UI_FONTDELTASIZE(pub i32);
 impl windows_core::TypeKind for UI_FONTDELTASIZE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_FONTDELTASIZE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_FONTDELTASIZE").field(&self.0).finish()
@@ -543,6 +551,7 @@ pub struct UI_FONTPROPERTIES(pub i32);
 impl windows_core::TypeKind for UI_FONTPROPERTIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_FONTPROPERTIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_FONTPROPERTIES").field(&self.0).finish()
@@ -554,6 +563,7 @@ pub struct UI_FONTUNDERLINE(pub i32);
 impl windows_core::TypeKind for UI_FONTUNDERLINE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_FONTUNDERLINE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_FONTUNDERLINE").field(&self.0).finish()
@@ -565,6 +575,7 @@ pub struct UI_FONTVERTICALPOSITION(pub i32);
 impl windows_core::TypeKind for UI_FONTVERTICALPOSITION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_FONTVERTICALPOSITION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_FONTVERTICALPOSITION").field(&self.0).finish()
@@ -576,6 +587,7 @@ pub struct UI_INVALIDATIONS(pub i32);
 impl windows_core::TypeKind for UI_INVALIDATIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_INVALIDATIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_INVALIDATIONS").field(&self.0).finish()
@@ -620,6 +632,7 @@ pub struct UI_OWNERSHIP(pub i32);
 impl windows_core::TypeKind for UI_OWNERSHIP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_OWNERSHIP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_OWNERSHIP").field(&self.0).finish()
@@ -631,6 +644,7 @@ pub struct UI_SWATCHCOLORMODE(pub i32);
 impl windows_core::TypeKind for UI_SWATCHCOLORMODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_SWATCHCOLORMODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_SWATCHCOLORMODE").field(&self.0).finish()
@@ -642,6 +656,7 @@ pub struct UI_SWATCHCOLORTYPE(pub i32);
 impl windows_core::TypeKind for UI_SWATCHCOLORTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_SWATCHCOLORTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_SWATCHCOLORTYPE").field(&self.0).finish()
@@ -653,6 +668,7 @@ pub struct UI_VIEWTYPE(pub i32);
 impl windows_core::TypeKind for UI_VIEWTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_VIEWTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_VIEWTYPE").field(&self.0).finish()
@@ -664,6 +680,7 @@ pub struct UI_VIEWVERB(pub i32);
 impl windows_core::TypeKind for UI_VIEWVERB {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UI_VIEWVERB {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UI_VIEWVERB").field(&self.0).finish()
@@ -700,7 +717,8 @@ impl Default for UI_EVENTPARAMS_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct UI_EVENTPARAMS_COMMAND {
     pub CommandID: u32,
     pub CommandName: windows_core::PCWSTR,
@@ -719,3 +737,5 @@ impl Default for UI_EVENTPARAMS_COMMAND {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,276 @@
+impl windows_core::imp::ProxyVtable for IUIApplication_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnViewChanged(this: *mut core::ffi::c_void, viewid: u32, typeid: UI_VIEWTYPE, view: *mut core::ffi::c_void, verb: UI_VIEWVERB, ureasoncode: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIApplication", "OnViewChanged", 3usize, |this| ((**(this as *const *const IUIApplication_Vtbl)).OnViewChanged)(this, viewid, typeid, view, verb, ureasoncode), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnCreateUICommand(this: *mut core::ffi::c_void, commandid: u32, typeid: UI_COMMANDTYPE, commandhandler: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIApplication", "OnCreateUICommand", 4usize, |this| ((**(this as *const *const IUIApplication_Vtbl)).OnCreateUICommand)(this, commandid, typeid, commandhandler), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDestroyUICommand(this: *mut core::ffi::c_void, commandid: u32, typeid: UI_COMMANDTYPE, commandhandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIApplication", "OnDestroyUICommand", 5usize, |this| ((**(this as *const *const IUIApplication_Vtbl)).OnDestroyUICommand)(this, commandid, typeid, commandhandler), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnViewChanged, OnCreateUICommand, OnDestroyUICommand }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIApplication as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUICollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, count: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUICollection", "GetCount", 3usize, |this| ((**(this as *const *const IUICollection_Vtbl)).GetCount)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItem(this: *mut core::ffi::c_void, index: u32, item: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUICollection", "GetItem", 4usize, |this| ((**(this as *const *const IUICollection_Vtbl)).GetItem)(this, index, item), |result| Some(*result))
+        }
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, item: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUICollection", "Add", 5usize, |this| ((**(this as *const *const IUICollection_Vtbl)).Add)(this, item), |result| Some(*result))
+        }
+        unsafe extern "system" fn Insert(this: *mut core::ffi::c_void, index: u32, item: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUICollection", "Insert", 6usize, |this| ((**(this as *const *const IUICollection_Vtbl)).Insert)(this, index, item), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAt(this: *mut core::ffi::c_void, index: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUICollection", "RemoveAt", 7usize, |this| ((**(this as *const *const IUICollection_Vtbl)).RemoveAt)(this, index), |result| Some(*result))
+        }
+        unsafe extern "system" fn Replace(this: *mut core::ffi::c_void, indexreplaced: u32, itemreplacewith: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUICollection", "Replace", 8usize, |this| ((**(this as *const *const IUICollection_Vtbl)).Replace)(this, indexreplaced, itemreplacewith), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUICollection", "Clear", 9usize, |this| ((**(this as *const *const IUICollection_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCount, GetItem, Add, Insert, RemoveAt, Replace, Clear }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUICollection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUICollectionChangedEvent_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnChanged(this: *mut core::ffi::c_void, action: UI_COLLECTIONCHANGE, oldindex: u32, olditem: *mut core::ffi::c_void, newindex: u32, newitem: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUICollectionChangedEvent", "OnChanged", 3usize, |this| ((**(this as *const *const IUICollectionChangedEvent_Vtbl)).OnChanged)(this, action, oldindex, olditem, newindex, newitem), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUICollectionChangedEvent as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IUICommandHandler_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn Execute(this: *mut core::ffi::c_void, commandid: u32, verb: UI_EXECUTIONVERB, key: *const super::Shell::PropertiesSystem::PROPERTYKEY, currentvalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, commandexecutionproperties: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUICommandHandler", "Execute", 3usize, |this| ((**(this as *const *const IUICommandHandler_Vtbl)).Execute)(this, commandid, verb, key, currentvalue, commandexecutionproperties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn UpdateProperty(this: *mut core::ffi::c_void, commandid: u32, key: *const super::Shell::PropertiesSystem::PROPERTYKEY, currentvalue: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, newvalue: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUICommandHandler", "UpdateProperty", 4usize, |this| ((**(this as *const *const IUICommandHandler_Vtbl)).UpdateProperty)(this, commandid, key, currentvalue, newvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            Execute,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            Execute: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            UpdateProperty,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            UpdateProperty: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUICommandHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIContextualUI_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ShowAtLocation(this: *mut core::ffi::c_void, x: i32, y: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIContextualUI", "ShowAtLocation", 3usize, |this| ((**(this as *const *const IUIContextualUI_Vtbl)).ShowAtLocation)(this, x, y), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ShowAtLocation }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIContextualUI as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIEventLogger_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnUIEvent(this: *mut core::ffi::c_void, peventparams: *const UI_EVENTPARAMS) {
+            windows_core::imp::proxy_call(this, "IUIEventLogger", "OnUIEvent", 3usize, |this| ((**(this as *const *const IUIEventLogger_Vtbl)).OnUIEvent)(this, peventparams), |_| None)
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnUIEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIEventLogger as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIEventingManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetEventLogger(this: *mut core::ffi::c_void, eventlogger: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIEventingManager", "SetEventLogger", 3usize, |this| ((**(this as *const *const IUIEventingManager_Vtbl)).SetEventLogger)(this, eventlogger), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetEventLogger }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIEventingManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IUIFramework_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, framewnd: super::super::Foundation::HWND, application: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIFramework", "Initialize", 3usize, |this| ((**(this as *const *const IUIFramework_Vtbl)).Initialize)(this, framewnd, application), |result| Some(*result))
+        }
+        unsafe extern "system" fn Destroy(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIFramework", "Destroy", 4usize, |this| ((**(this as *const *const IUIFramework_Vtbl)).Destroy)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn LoadUI(this: *mut core::ffi::c_void, instance: super::super::Foundation::HINSTANCE, resourcename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIFramework", "LoadUI", 5usize, |this| ((**(this as *const *const IUIFramework_Vtbl)).LoadUI)(this, instance, resourcename), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetView(this: *mut core::ffi::c_void, viewid: u32, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIFramework", "GetView", 6usize, |this| ((**(this as *const *const IUIFramework_Vtbl)).GetView)(this, viewid, riid, ppv), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetUICommandProperty(this: *mut core::ffi::c_void, commandid: u32, key: *const super::Shell::PropertiesSystem::PROPERTYKEY, value: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIFramework", "GetUICommandProperty", 7usize, |this| ((**(this as *const *const IUIFramework_Vtbl)).GetUICommandProperty)(this, commandid, key, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn SetUICommandProperty(this: *mut core::ffi::c_void, commandid: u32, key: *const super::Shell::PropertiesSystem::PROPERTYKEY, value: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIFramework", "SetUICommandProperty", 8usize, |this| ((**(this as *const *const IUIFramework_Vtbl)).SetUICommandProperty)(this, commandid, key, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn InvalidateUICommand(this: *mut core::ffi::c_void, commandid: u32, flags: UI_INVALIDATIONS, key: *const super::Shell::PropertiesSystem::PROPERTYKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIFramework", "InvalidateUICommand", 9usize, |this| ((**(this as *const *const IUIFramework_Vtbl)).InvalidateUICommand)(this, commandid, flags, key), |result| Some(*result))
+        }
+        unsafe extern "system" fn FlushPendingInvalidations(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIFramework", "FlushPendingInvalidations", 10usize, |this| ((**(this as *const *const IUIFramework_Vtbl)).FlushPendingInvalidations)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetModes(this: *mut core::ffi::c_void, imodes: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIFramework", "SetModes", 11usize, |this| ((**(this as *const *const IUIFramework_Vtbl)).SetModes)(this, imodes), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            Destroy,
+            LoadUI,
+            GetView,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetUICommandProperty,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetUICommandProperty: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            SetUICommandProperty,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            SetUICommandProperty: 0,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            InvalidateUICommand,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            InvalidateUICommand: 0,
+            FlushPendingInvalidations,
+            SetModes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIFramework as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IUIImage_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn GetBitmap(this: *mut core::ffi::c_void, bitmap: *mut super::super::Graphics::Gdi::HBITMAP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIImage", "GetBitmap", 3usize, |this| ((**(this as *const *const IUIImage_Vtbl)).GetBitmap)(this, bitmap), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            GetBitmap,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            GetBitmap: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIImage as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IUIImageFromBitmap_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn CreateImage(this: *mut core::ffi::c_void, bitmap: super::super::Graphics::Gdi::HBITMAP, options: UI_OWNERSHIP, image: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIImageFromBitmap", "CreateImage", 3usize, |this| ((**(this as *const *const IUIImageFromBitmap_Vtbl)).CreateImage)(this, bitmap, options, image), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            CreateImage,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            CreateImage: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIImageFromBitmap as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IUIRibbon_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetHeight(this: *mut core::ffi::c_void, cy: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIRibbon", "GetHeight", 3usize, |this| ((**(this as *const *const IUIRibbon_Vtbl)).GetHeight)(this, cy), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn LoadSettingsFromStream(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIRibbon", "LoadSettingsFromStream", 4usize, |this| ((**(this as *const *const IUIRibbon_Vtbl)).LoadSettingsFromStream)(this, pstream), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SaveSettingsToStream(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIRibbon", "SaveSettingsToStream", 5usize, |this| ((**(this as *const *const IUIRibbon_Vtbl)).SaveSettingsToStream)(this, pstream), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetHeight,
+            #[cfg(feature = "Win32_System_Com")]
+            LoadSettingsFromStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            LoadSettingsFromStream: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SaveSettingsToStream,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SaveSettingsToStream: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIRibbon as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+impl windows_core::imp::ProxyVtable for IUISimplePropertySet_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, key: *const super::Shell::PropertiesSystem::PROPERTYKEY, value: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUISimplePropertySet", "GetValue", 3usize, |this| ((**(this as *const *const IUISimplePropertySet_Vtbl)).GetValue)(this, key, value), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
+            GetValue,
+            #[cfg(not(feature = "Win32_UI_Shell_PropertiesSystem"))]
+            GetValue: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUISimplePropertySet as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -130,6 +130,7 @@ pub struct DEVICE_SCALE_FACTOR(pub i32);
 impl windows_core::TypeKind for DEVICE_SCALE_FACTOR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEVICE_SCALE_FACTOR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEVICE_SCALE_FACTOR").field(&self.0).finish()
@@ -141,6 +142,7 @@ pub struct PERCEIVED(pub i32);
 impl windows_core::TypeKind for PERCEIVED {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PERCEIVED {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PERCEIVED").field(&self.0).finish()
@@ -152,6 +154,7 @@ pub struct SHCOLSTATE(pub i32);
 impl windows_core::TypeKind for SHCOLSTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHCOLSTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHCOLSTATE").field(&self.0).finish()
@@ -163,13 +166,15 @@ pub struct STRRET_TYPE(pub i32);
 impl windows_core::TypeKind for STRRET_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STRRET_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STRRET_TYPE").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct COMDLG_FILTERSPEC {
     pub pszName: windows_core::PCWSTR,
     pub pszSpec: windows_core::PCWSTR,
@@ -255,3 +260,5 @@ impl Default for STRRET_0 {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,36 @@
+impl windows_core::imp::ProxyVtable for IObjectArray_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcobjects: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectArray", "GetCount", 3usize, |this| ((**(this as *const *const IObjectArray_Vtbl)).GetCount)(this, pcobjects), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, uiindex: u32, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectArray", "GetAt", 4usize, |this| ((**(this as *const *const IObjectArray_Vtbl)).GetAt)(this, uiindex, riid, ppv), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCount, GetAt }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IObjectArray as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IObjectCollection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddObject(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectCollection", "AddObject", 5usize, |this| ((**(this as *const *const IObjectCollection_Vtbl)).AddObject)(this, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddFromArray(this: *mut core::ffi::c_void, poasource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectCollection", "AddFromArray", 6usize, |this| ((**(this as *const *const IObjectCollection_Vtbl)).AddFromArray)(this, poasource), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveObjectAt(this: *mut core::ffi::c_void, uiindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectCollection", "RemoveObjectAt", 7usize, |this| ((**(this as *const *const IObjectCollection_Vtbl)).RemoveObjectAt)(this, uiindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectCollection", "Clear", 8usize, |this| ((**(this as *const *const IObjectCollection_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        Self { base__: <IObjectArray_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddObject, AddFromArray, RemoveObjectAt, Clear }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IObjectCollection as windows_core::Interface>::IID || <IObjectArray_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -1756,6 +1756,7 @@ pub struct GETPROPERTYSTOREFLAGS(pub i32);
 impl windows_core::TypeKind for GETPROPERTYSTOREFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GETPROPERTYSTOREFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GETPROPERTYSTOREFLAGS").field(&self.0).finish()
@@ -1800,6 +1801,7 @@ pub struct PDOPSTATUS(pub i32);
 impl windows_core::TypeKind for PDOPSTATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PDOPSTATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PDOPSTATUS").field(&self.0).finish()
@@ -1811,6 +1813,7 @@ pub struct PKA_FLAGS(pub i32);
 impl windows_core::TypeKind for PKA_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PKA_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PKA_FLAGS").field(&self.0).finish()
@@ -1855,6 +1858,7 @@ pub struct PLACEHOLDER_STATES(pub i32);
 impl windows_core::TypeKind for PLACEHOLDER_STATES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PLACEHOLDER_STATES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PLACEHOLDER_STATES").field(&self.0).finish()
@@ -1899,6 +1903,7 @@ pub struct PROPDESC_AGGREGATION_TYPE(pub i32);
 impl windows_core::TypeKind for PROPDESC_AGGREGATION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROPDESC_AGGREGATION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROPDESC_AGGREGATION_TYPE").field(&self.0).finish()
@@ -1910,6 +1915,7 @@ pub struct PROPDESC_COLUMNINDEX_TYPE(pub i32);
 impl windows_core::TypeKind for PROPDESC_COLUMNINDEX_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROPDESC_COLUMNINDEX_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROPDESC_COLUMNINDEX_TYPE").field(&self.0).finish()
@@ -1921,6 +1927,7 @@ pub struct PROPDESC_CONDITION_TYPE(pub i32);
 impl windows_core::TypeKind for PROPDESC_CONDITION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROPDESC_CONDITION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROPDESC_CONDITION_TYPE").field(&self.0).finish()
@@ -1932,6 +1939,7 @@ pub struct PROPDESC_DISPLAYTYPE(pub i32);
 impl windows_core::TypeKind for PROPDESC_DISPLAYTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROPDESC_DISPLAYTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROPDESC_DISPLAYTYPE").field(&self.0).finish()
@@ -1943,6 +1951,7 @@ pub struct PROPDESC_ENUMFILTER(pub i32);
 impl windows_core::TypeKind for PROPDESC_ENUMFILTER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROPDESC_ENUMFILTER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROPDESC_ENUMFILTER").field(&self.0).finish()
@@ -1954,6 +1963,7 @@ pub struct PROPDESC_FORMAT_FLAGS(pub i32);
 impl windows_core::TypeKind for PROPDESC_FORMAT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROPDESC_FORMAT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROPDESC_FORMAT_FLAGS").field(&self.0).finish()
@@ -1998,6 +2008,7 @@ pub struct PROPDESC_GROUPING_RANGE(pub i32);
 impl windows_core::TypeKind for PROPDESC_GROUPING_RANGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROPDESC_GROUPING_RANGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROPDESC_GROUPING_RANGE").field(&self.0).finish()
@@ -2009,6 +2020,7 @@ pub struct PROPDESC_RELATIVEDESCRIPTION_TYPE(pub i32);
 impl windows_core::TypeKind for PROPDESC_RELATIVEDESCRIPTION_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROPDESC_RELATIVEDESCRIPTION_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROPDESC_RELATIVEDESCRIPTION_TYPE").field(&self.0).finish()
@@ -2020,6 +2032,7 @@ pub struct PROPDESC_SEARCHINFO_FLAGS(pub i32);
 impl windows_core::TypeKind for PROPDESC_SEARCHINFO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROPDESC_SEARCHINFO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROPDESC_SEARCHINFO_FLAGS").field(&self.0).finish()
@@ -2064,6 +2077,7 @@ pub struct PROPDESC_SORTDESCRIPTION(pub i32);
 impl windows_core::TypeKind for PROPDESC_SORTDESCRIPTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROPDESC_SORTDESCRIPTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROPDESC_SORTDESCRIPTION").field(&self.0).finish()
@@ -2075,6 +2089,7 @@ pub struct PROPDESC_TYPE_FLAGS(pub u32);
 impl windows_core::TypeKind for PROPDESC_TYPE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROPDESC_TYPE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROPDESC_TYPE_FLAGS").field(&self.0).finish()
@@ -2119,6 +2134,7 @@ pub struct PROPDESC_VIEW_FLAGS(pub i32);
 impl windows_core::TypeKind for PROPDESC_VIEW_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROPDESC_VIEW_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROPDESC_VIEW_FLAGS").field(&self.0).finish()
@@ -2163,6 +2179,7 @@ pub struct PROPENUMTYPE(pub i32);
 impl windows_core::TypeKind for PROPENUMTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROPENUMTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROPENUMTYPE").field(&self.0).finish()
@@ -2174,6 +2191,7 @@ pub struct PROPERTYUI_FLAGS(pub i32);
 impl windows_core::TypeKind for PROPERTYUI_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROPERTYUI_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROPERTYUI_FLAGS").field(&self.0).finish()
@@ -2218,6 +2236,7 @@ pub struct PROPERTYUI_FORMAT_FLAGS(pub i32);
 impl windows_core::TypeKind for PROPERTYUI_FORMAT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROPERTYUI_FORMAT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROPERTYUI_FORMAT_FLAGS").field(&self.0).finish()
@@ -2262,6 +2281,7 @@ pub struct PROPERTYUI_NAME_FLAGS(pub i32);
 impl windows_core::TypeKind for PROPERTYUI_NAME_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROPERTYUI_NAME_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROPERTYUI_NAME_FLAGS").field(&self.0).finish()
@@ -2306,6 +2326,7 @@ pub struct PSC_STATE(pub i32);
 impl windows_core::TypeKind for PSC_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PSC_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PSC_STATE").field(&self.0).finish()
@@ -2317,6 +2338,7 @@ pub struct SYNC_ENGINE_STATE_FLAGS(pub i32);
 impl windows_core::TypeKind for SYNC_ENGINE_STATE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNC_ENGINE_STATE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNC_ENGINE_STATE_FLAGS").field(&self.0).finish()
@@ -2361,6 +2383,7 @@ pub struct SYNC_TRANSFER_STATUS(pub i32);
 impl windows_core::TypeKind for SYNC_TRANSFER_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNC_TRANSFER_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNC_TRANSFER_STATUS").field(&self.0).finish()
@@ -2405,6 +2428,7 @@ pub struct _PERSIST_SPROPSTORE_FLAGS(pub i32);
 impl windows_core::TypeKind for _PERSIST_SPROPSTORE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _PERSIST_SPROPSTORE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_PERSIST_SPROPSTORE_FLAGS").field(&self.0).finish()
@@ -2413,7 +2437,8 @@ impl core::fmt::Debug for _PERSIST_SPROPSTORE_FLAGS {
 pub const InMemoryPropertyStore: windows_core::GUID = windows_core::GUID::from_u128(0x9a02e012_6303_4e1e_b9a1_630f802592c5);
 pub const InMemoryPropertyStoreMarshalByValue: windows_core::GUID = windows_core::GUID::from_u128(0xd4ca0e2d_6da7_4b75_a97c_5f306f0eaedc);
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PCUSERIALIZEDPROPSTORAGE(pub isize);
 impl Default for PCUSERIALIZEDPROPSTORAGE {
     fn default() -> Self {
@@ -2424,7 +2449,8 @@ impl windows_core::TypeKind for PCUSERIALIZEDPROPSTORAGE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROPERTYKEY {
     pub fmtid: windows_core::GUID,
     pub pid: u32,
@@ -2463,7 +2489,8 @@ impl Default for PROPPRG {
 }
 pub const PropertySystem: windows_core::GUID = windows_core::GUID::from_u128(0xb8967f85_58ae_4f46_9fb2_5d7904798f4b);
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SERIALIZEDPROPSTORAGE(pub isize);
 impl Default for SERIALIZEDPROPSTORAGE {
     fn default() -> Self {
@@ -2475,3 +2502,5 @@ impl windows_core::TypeKind for SERIALIZEDPROPSTORAGE {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
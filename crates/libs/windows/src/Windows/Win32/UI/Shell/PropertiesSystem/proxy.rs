@@ -0,0 +1,599 @@
+impl windows_core::imp::ProxyVtable for ICreateObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateObject(this: *mut core::ffi::c_void, clsid: *const windows_core::GUID, punkouter: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICreateObject", "CreateObject", 3usize, |this| ((**(this as *const *const ICreateObject_Vtbl)).CreateObject)(this, clsid, punkouter, riid, ppv), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICreateObject as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDelayedPropertyStoreFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDelayedPropertyStore(this: *mut core::ffi::c_void, flags: GETPROPERTYSTOREFLAGS, dwstoreid: u32, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDelayedPropertyStoreFactory", "GetDelayedPropertyStore", 5usize, |this| ((**(this as *const *const IDelayedPropertyStoreFactory_Vtbl)).GetDelayedPropertyStore)(this, flags, dwstoreid, riid, ppv), |result| Some(*result))
+        }
+        Self { base__: <IPropertyStoreFactory_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDelayedPropertyStore }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDelayedPropertyStoreFactory as windows_core::Interface>::IID || <IPropertyStoreFactory_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInitializeWithFile_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pszfilepath: windows_core::PCWSTR, grfmode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInitializeWithFile", "Initialize", 3usize, |this| ((**(this as *const *const IInitializeWithFile_Vtbl)).Initialize)(this, pszfilepath, grfmode), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInitializeWithFile as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInitializeWithStream_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, grfmode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInitializeWithStream", "Initialize", 3usize, |this| ((**(this as *const *const IInitializeWithStream_Vtbl)).Initialize)(this, pstream, grfmode), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Initialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Initialize: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInitializeWithStream as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for INamedPropertyStore_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNamedValue(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, ppropvar: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INamedPropertyStore", "GetNamedValue", 3usize, |this| ((**(this as *const *const INamedPropertyStore_Vtbl)).GetNamedValue)(this, pszname, ppropvar), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNamedValue(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, propvar: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INamedPropertyStore", "SetNamedValue", 4usize, |this| ((**(this as *const *const INamedPropertyStore_Vtbl)).SetNamedValue)(this, pszname, propvar), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNameCount(this: *mut core::ffi::c_void, pdwcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INamedPropertyStore", "GetNameCount", 5usize, |this| ((**(this as *const *const INamedPropertyStore_Vtbl)).GetNameCount)(this, pdwcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNameAt(this: *mut core::ffi::c_void, iprop: u32, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "INamedPropertyStore", "GetNameAt", 6usize, |this| ((**(this as *const *const INamedPropertyStore_Vtbl)).GetNameAt)(this, iprop, pbstrname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetNamedValue, SetNamedValue, GetNameCount, GetNameAt }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<INamedPropertyStore as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IObjectWithPropertyKey_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetPropertyKey(this: *mut core::ffi::c_void, key: *const PROPERTYKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectWithPropertyKey", "SetPropertyKey", 3usize, |this| ((**(this as *const *const IObjectWithPropertyKey_Vtbl)).SetPropertyKey)(this, key), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyKey(this: *mut core::ffi::c_void, pkey: *mut PROPERTYKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IObjectWithPropertyKey", "GetPropertyKey", 4usize, |this| ((**(this as *const *const IObjectWithPropertyKey_Vtbl)).GetPropertyKey)(this, pkey), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetPropertyKey, GetPropertyKey }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IObjectWithPropertyKey as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPersistSerializedPropStorage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetFlags(this: *mut core::ffi::c_void, flags: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistSerializedPropStorage", "SetFlags", 3usize, |this| ((**(this as *const *const IPersistSerializedPropStorage_Vtbl)).SetFlags)(this, flags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPropertyStorage(this: *mut core::ffi::c_void, psps: PCUSERIALIZEDPROPSTORAGE, cb: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistSerializedPropStorage", "SetPropertyStorage", 4usize, |this| ((**(this as *const *const IPersistSerializedPropStorage_Vtbl)).SetPropertyStorage)(this, psps, cb), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyStorage(this: *mut core::ffi::c_void, ppsps: *mut *mut SERIALIZEDPROPSTORAGE, pcb: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistSerializedPropStorage", "GetPropertyStorage", 5usize, |this| ((**(this as *const *const IPersistSerializedPropStorage_Vtbl)).GetPropertyStorage)(this, ppsps, pcb), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetFlags, SetPropertyStorage, GetPropertyStorage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPersistSerializedPropStorage as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPersistSerializedPropStorage2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPropertyStorageSize(this: *mut core::ffi::c_void, pcb: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistSerializedPropStorage2", "GetPropertyStorageSize", 6usize, |this| ((**(this as *const *const IPersistSerializedPropStorage2_Vtbl)).GetPropertyStorageSize)(this, pcb), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyStorageBuffer(this: *mut core::ffi::c_void, psps: *mut SERIALIZEDPROPSTORAGE, cb: u32, pcbwritten: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistSerializedPropStorage2", "GetPropertyStorageBuffer", 7usize, |this| ((**(this as *const *const IPersistSerializedPropStorage2_Vtbl)).GetPropertyStorageBuffer)(this, psps, cb, pcbwritten), |result| Some(*result))
+        }
+        Self { base__: <IPersistSerializedPropStorage_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPropertyStorageSize, GetPropertyStorageBuffer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPersistSerializedPropStorage2 as windows_core::Interface>::IID || <IPersistSerializedPropStorage_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPropertyChange_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ApplyToPropVariant(this: *mut core::ffi::c_void, propvarin: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, ppropvarout: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyChange", "ApplyToPropVariant", 5usize, |this| ((**(this as *const *const IPropertyChange_Vtbl)).ApplyToPropVariant)(this, propvarin, ppropvarout), |result| Some(*result))
+        }
+        Self { base__: <IObjectWithPropertyKey_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ApplyToPropVariant }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertyChange as windows_core::Interface>::IID || <IObjectWithPropertyKey_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPropertyChangeArray_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcoperations: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyChangeArray", "GetCount", 3usize, |this| ((**(this as *const *const IPropertyChangeArray_Vtbl)).GetCount)(this, pcoperations), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, iindex: u32, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyChangeArray", "GetAt", 4usize, |this| ((**(this as *const *const IPropertyChangeArray_Vtbl)).GetAt)(this, iindex, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertAt(this: *mut core::ffi::c_void, iindex: u32, ppropchange: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyChangeArray", "InsertAt", 5usize, |this| ((**(this as *const *const IPropertyChangeArray_Vtbl)).InsertAt)(this, iindex, ppropchange), |result| Some(*result))
+        }
+        unsafe extern "system" fn Append(this: *mut core::ffi::c_void, ppropchange: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyChangeArray", "Append", 6usize, |this| ((**(this as *const *const IPropertyChangeArray_Vtbl)).Append)(this, ppropchange), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppendOrReplace(this: *mut core::ffi::c_void, ppropchange: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyChangeArray", "AppendOrReplace", 7usize, |this| ((**(this as *const *const IPropertyChangeArray_Vtbl)).AppendOrReplace)(this, ppropchange), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAt(this: *mut core::ffi::c_void, iindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyChangeArray", "RemoveAt", 8usize, |this| ((**(this as *const *const IPropertyChangeArray_Vtbl)).RemoveAt)(this, iindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsKeyInArray(this: *mut core::ffi::c_void, key: *const PROPERTYKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyChangeArray", "IsKeyInArray", 9usize, |this| ((**(this as *const *const IPropertyChangeArray_Vtbl)).IsKeyInArray)(this, key), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCount,
+            GetAt,
+            InsertAt,
+            Append,
+            AppendOrReplace,
+            RemoveAt,
+            IsKeyInArray,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertyChangeArray as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Search_Common")]
+impl windows_core::imp::ProxyVtable for IPropertyDescription_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPropertyKey(this: *mut core::ffi::c_void, pkey: *mut PROPERTYKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescription", "GetPropertyKey", 3usize, |this| ((**(this as *const *const IPropertyDescription_Vtbl)).GetPropertyKey)(this, pkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCanonicalName(this: *mut core::ffi::c_void, ppszname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescription", "GetCanonicalName", 4usize, |this| ((**(this as *const *const IPropertyDescription_Vtbl)).GetCanonicalName)(this, ppszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyType(this: *mut core::ffi::c_void, pvartype: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescription", "GetPropertyType", 5usize, |this| ((**(this as *const *const IPropertyDescription_Vtbl)).GetPropertyType)(this, pvartype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayName(this: *mut core::ffi::c_void, ppszname: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescription", "GetDisplayName", 6usize, |this| ((**(this as *const *const IPropertyDescription_Vtbl)).GetDisplayName)(this, ppszname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEditInvitation(this: *mut core::ffi::c_void, ppszinvite: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescription", "GetEditInvitation", 7usize, |this| ((**(this as *const *const IPropertyDescription_Vtbl)).GetEditInvitation)(this, ppszinvite), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTypeFlags(this: *mut core::ffi::c_void, mask: PROPDESC_TYPE_FLAGS, ppdtflags: *mut PROPDESC_TYPE_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescription", "GetTypeFlags", 8usize, |this| ((**(this as *const *const IPropertyDescription_Vtbl)).GetTypeFlags)(this, mask, ppdtflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetViewFlags(this: *mut core::ffi::c_void, ppdvflags: *mut PROPDESC_VIEW_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescription", "GetViewFlags", 9usize, |this| ((**(this as *const *const IPropertyDescription_Vtbl)).GetViewFlags)(this, ppdvflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultColumnWidth(this: *mut core::ffi::c_void, pcxchars: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescription", "GetDefaultColumnWidth", 10usize, |this| ((**(this as *const *const IPropertyDescription_Vtbl)).GetDefaultColumnWidth)(this, pcxchars), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayType(this: *mut core::ffi::c_void, pdisplaytype: *mut PROPDESC_DISPLAYTYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescription", "GetDisplayType", 11usize, |this| ((**(this as *const *const IPropertyDescription_Vtbl)).GetDisplayType)(this, pdisplaytype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColumnState(this: *mut core::ffi::c_void, pcsflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescription", "GetColumnState", 12usize, |this| ((**(this as *const *const IPropertyDescription_Vtbl)).GetColumnState)(this, pcsflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGroupingRange(this: *mut core::ffi::c_void, pgr: *mut PROPDESC_GROUPING_RANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescription", "GetGroupingRange", 13usize, |this| ((**(this as *const *const IPropertyDescription_Vtbl)).GetGroupingRange)(this, pgr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRelativeDescriptionType(this: *mut core::ffi::c_void, prdt: *mut PROPDESC_RELATIVEDESCRIPTION_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescription", "GetRelativeDescriptionType", 14usize, |this| ((**(this as *const *const IPropertyDescription_Vtbl)).GetRelativeDescriptionType)(this, prdt), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRelativeDescription(this: *mut core::ffi::c_void, propvar1: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, propvar2: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, ppszdesc1: *mut windows_core::PWSTR, ppszdesc2: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescription", "GetRelativeDescription", 15usize, |this| ((**(this as *const *const IPropertyDescription_Vtbl)).GetRelativeDescription)(this, propvar1, propvar2, ppszdesc1, ppszdesc2), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSortDescription(this: *mut core::ffi::c_void, psd: *mut PROPDESC_SORTDESCRIPTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescription", "GetSortDescription", 16usize, |this| ((**(this as *const *const IPropertyDescription_Vtbl)).GetSortDescription)(this, psd), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSortDescriptionLabel(this: *mut core::ffi::c_void, fdescending: super::super::super::Foundation::BOOL, ppszdescription: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescription", "GetSortDescriptionLabel", 17usize, |this| ((**(this as *const *const IPropertyDescription_Vtbl)).GetSortDescriptionLabel)(this, fdescending, ppszdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAggregationType(this: *mut core::ffi::c_void, paggtype: *mut PROPDESC_AGGREGATION_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescription", "GetAggregationType", 18usize, |this| ((**(this as *const *const IPropertyDescription_Vtbl)).GetAggregationType)(this, paggtype), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Search_Common")]
+        unsafe extern "system" fn GetConditionType(this: *mut core::ffi::c_void, pcontype: *mut PROPDESC_CONDITION_TYPE, popdefault: *mut super::super::super::System::Search::Common::CONDITION_OPERATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescription", "GetConditionType", 19usize, |this| ((**(this as *const *const IPropertyDescription_Vtbl)).GetConditionType)(this, pcontype, popdefault), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnumTypeList(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescription", "GetEnumTypeList", 20usize, |this| ((**(this as *const *const IPropertyDescription_Vtbl)).GetEnumTypeList)(this, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn CoerceToCanonicalValue(this: *mut core::ffi::c_void, ppropvar: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescription", "CoerceToCanonicalValue", 21usize, |this| ((**(this as *const *const IPropertyDescription_Vtbl)).CoerceToCanonicalValue)(this, ppropvar), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormatForDisplay(this: *mut core::ffi::c_void, propvar: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, pdfflags: PROPDESC_FORMAT_FLAGS, ppszdisplay: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescription", "FormatForDisplay", 22usize, |this| ((**(this as *const *const IPropertyDescription_Vtbl)).FormatForDisplay)(this, propvar, pdfflags, ppszdisplay), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsValueCanonical(this: *mut core::ffi::c_void, propvar: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescription", "IsValueCanonical", 23usize, |this| ((**(this as *const *const IPropertyDescription_Vtbl)).IsValueCanonical)(this, propvar), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPropertyKey,
+            GetCanonicalName,
+            GetPropertyType,
+            GetDisplayName,
+            GetEditInvitation,
+            GetTypeFlags,
+            GetViewFlags,
+            GetDefaultColumnWidth,
+            GetDisplayType,
+            GetColumnState,
+            GetGroupingRange,
+            GetRelativeDescriptionType,
+            GetRelativeDescription,
+            GetSortDescription,
+            GetSortDescriptionLabel,
+            GetAggregationType,
+            #[cfg(feature = "Win32_System_Search_Common")]
+            GetConditionType,
+            #[cfg(not(feature = "Win32_System_Search_Common"))]
+            GetConditionType: 0,
+            GetEnumTypeList,
+            CoerceToCanonicalValue,
+            FormatForDisplay,
+            IsValueCanonical,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertyDescription as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Search_Common")]
+impl windows_core::imp::ProxyVtable for IPropertyDescription2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetImageReferenceForValue(this: *mut core::ffi::c_void, propvar: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, ppszimageres: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescription2", "GetImageReferenceForValue", 24usize, |this| ((**(this as *const *const IPropertyDescription2_Vtbl)).GetImageReferenceForValue)(this, propvar, ppszimageres), |result| Some(*result))
+        }
+        Self { base__: <IPropertyDescription_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetImageReferenceForValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertyDescription2 as windows_core::Interface>::IID || <IPropertyDescription_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Search_Common")]
+impl windows_core::imp::ProxyVtable for IPropertyDescriptionAliasInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSortByAlias(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescriptionAliasInfo", "GetSortByAlias", 24usize, |this| ((**(this as *const *const IPropertyDescriptionAliasInfo_Vtbl)).GetSortByAlias)(this, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAdditionalSortByAliases(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescriptionAliasInfo", "GetAdditionalSortByAliases", 25usize, |this| ((**(this as *const *const IPropertyDescriptionAliasInfo_Vtbl)).GetAdditionalSortByAliases)(this, riid, ppv), |result| Some(*result))
+        }
+        Self { base__: <IPropertyDescription_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSortByAlias, GetAdditionalSortByAliases }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertyDescriptionAliasInfo as windows_core::Interface>::IID || <IPropertyDescription_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPropertyDescriptionList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pcelem: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescriptionList", "GetCount", 3usize, |this| ((**(this as *const *const IPropertyDescriptionList_Vtbl)).GetCount)(this, pcelem), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, ielem: u32, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescriptionList", "GetAt", 4usize, |this| ((**(this as *const *const IPropertyDescriptionList_Vtbl)).GetAt)(this, ielem, riid, ppv), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCount, GetAt }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertyDescriptionList as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Search_Common")]
+impl windows_core::imp::ProxyVtable for IPropertyDescriptionRelatedPropertyInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRelatedProperty(this: *mut core::ffi::c_void, pszrelationshipname: windows_core::PCWSTR, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescriptionRelatedPropertyInfo", "GetRelatedProperty", 24usize, |this| ((**(this as *const *const IPropertyDescriptionRelatedPropertyInfo_Vtbl)).GetRelatedProperty)(this, pszrelationshipname, riid, ppv), |result| Some(*result))
+        }
+        Self { base__: <IPropertyDescription_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRelatedProperty }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertyDescriptionRelatedPropertyInfo as windows_core::Interface>::IID || <IPropertyDescription_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Search_Common")]
+impl windows_core::imp::ProxyVtable for IPropertyDescriptionSearchInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSearchInfoFlags(this: *mut core::ffi::c_void, ppdsiflags: *mut PROPDESC_SEARCHINFO_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescriptionSearchInfo", "GetSearchInfoFlags", 24usize, |this| ((**(this as *const *const IPropertyDescriptionSearchInfo_Vtbl)).GetSearchInfoFlags)(this, ppdsiflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetColumnIndexType(this: *mut core::ffi::c_void, ppdcitype: *mut PROPDESC_COLUMNINDEX_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescriptionSearchInfo", "GetColumnIndexType", 25usize, |this| ((**(this as *const *const IPropertyDescriptionSearchInfo_Vtbl)).GetColumnIndexType)(this, ppdcitype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProjectionString(this: *mut core::ffi::c_void, ppszprojection: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescriptionSearchInfo", "GetProjectionString", 26usize, |this| ((**(this as *const *const IPropertyDescriptionSearchInfo_Vtbl)).GetProjectionString)(this, ppszprojection), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxSize(this: *mut core::ffi::c_void, pcbmaxsize: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyDescriptionSearchInfo", "GetMaxSize", 27usize, |this| ((**(this as *const *const IPropertyDescriptionSearchInfo_Vtbl)).GetMaxSize)(this, pcbmaxsize), |result| Some(*result))
+        }
+        Self {
+            base__: <IPropertyDescription_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSearchInfoFlags,
+            GetColumnIndexType,
+            GetProjectionString,
+            GetMaxSize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertyDescriptionSearchInfo as windows_core::Interface>::IID || <IPropertyDescription_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPropertyEnumType_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetEnumType(this: *mut core::ffi::c_void, penumtype: *mut PROPENUMTYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyEnumType", "GetEnumType", 3usize, |this| ((**(this as *const *const IPropertyEnumType_Vtbl)).GetEnumType)(this, penumtype), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, ppropvar: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyEnumType", "GetValue", 4usize, |this| ((**(this as *const *const IPropertyEnumType_Vtbl)).GetValue)(this, ppropvar), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRangeMinValue(this: *mut core::ffi::c_void, ppropvarmin: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyEnumType", "GetRangeMinValue", 5usize, |this| ((**(this as *const *const IPropertyEnumType_Vtbl)).GetRangeMinValue)(this, ppropvarmin), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRangeSetValue(this: *mut core::ffi::c_void, ppropvarset: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyEnumType", "GetRangeSetValue", 6usize, |this| ((**(this as *const *const IPropertyEnumType_Vtbl)).GetRangeSetValue)(this, ppropvarset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayText(this: *mut core::ffi::c_void, ppszdisplay: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyEnumType", "GetDisplayText", 7usize, |this| ((**(this as *const *const IPropertyEnumType_Vtbl)).GetDisplayText)(this, ppszdisplay), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetEnumType,
+            GetValue,
+            GetRangeMinValue,
+            GetRangeSetValue,
+            GetDisplayText,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertyEnumType as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPropertyEnumType2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetImageReference(this: *mut core::ffi::c_void, ppszimageres: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyEnumType2", "GetImageReference", 8usize, |this| ((**(this as *const *const IPropertyEnumType2_Vtbl)).GetImageReference)(this, ppszimageres), |result| Some(*result))
+        }
+        Self { base__: <IPropertyEnumType_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetImageReference }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertyEnumType2 as windows_core::Interface>::IID || <IPropertyEnumType_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPropertyEnumTypeList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pctypes: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyEnumTypeList", "GetCount", 3usize, |this| ((**(this as *const *const IPropertyEnumTypeList_Vtbl)).GetCount)(this, pctypes), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, itype: u32, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyEnumTypeList", "GetAt", 4usize, |this| ((**(this as *const *const IPropertyEnumTypeList_Vtbl)).GetAt)(this, itype, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConditionAt(this: *mut core::ffi::c_void, nindex: u32, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyEnumTypeList", "GetConditionAt", 5usize, |this| ((**(this as *const *const IPropertyEnumTypeList_Vtbl)).GetConditionAt)(this, nindex, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindMatchingIndex(this: *mut core::ffi::c_void, propvarcmp: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, pnindex: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyEnumTypeList", "FindMatchingIndex", 6usize, |this| ((**(this as *const *const IPropertyEnumTypeList_Vtbl)).FindMatchingIndex)(this, propvarcmp, pnindex), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCount, GetAt, GetConditionAt, FindMatchingIndex }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertyEnumTypeList as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPropertyStore_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, cprops: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStore", "GetCount", 3usize, |this| ((**(this as *const *const IPropertyStore_Vtbl)).GetCount)(this, cprops), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAt(this: *mut core::ffi::c_void, iprop: u32, pkey: *mut PROPERTYKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStore", "GetAt", 4usize, |this| ((**(this as *const *const IPropertyStore_Vtbl)).GetAt)(this, iprop, pkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, key: *const PROPERTYKEY, pv: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStore", "GetValue", 5usize, |this| ((**(this as *const *const IPropertyStore_Vtbl)).GetValue)(this, key, pv), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, key: *const PROPERTYKEY, propvar: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStore", "SetValue", 6usize, |this| ((**(this as *const *const IPropertyStore_Vtbl)).SetValue)(this, key, propvar), |result| Some(*result))
+        }
+        unsafe extern "system" fn Commit(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStore", "Commit", 7usize, |this| ((**(this as *const *const IPropertyStore_Vtbl)).Commit)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCount, GetAt, GetValue, SetValue, Commit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertyStore as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPropertyStoreCache_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetState(this: *mut core::ffi::c_void, key: *const PROPERTYKEY, pstate: *mut PSC_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStoreCache", "GetState", 8usize, |this| ((**(this as *const *const IPropertyStoreCache_Vtbl)).GetState)(this, key, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValueAndState(this: *mut core::ffi::c_void, key: *const PROPERTYKEY, ppropvar: *mut core::mem::MaybeUninit<windows_core::PROPVARIANT>, pstate: *mut PSC_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStoreCache", "GetValueAndState", 9usize, |this| ((**(this as *const *const IPropertyStoreCache_Vtbl)).GetValueAndState)(this, key, ppropvar, pstate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetState(this: *mut core::ffi::c_void, key: *const PROPERTYKEY, state: PSC_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStoreCache", "SetState", 10usize, |this| ((**(this as *const *const IPropertyStoreCache_Vtbl)).SetState)(this, key, state), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValueAndState(this: *mut core::ffi::c_void, key: *const PROPERTYKEY, ppropvar: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, state: PSC_STATE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStoreCache", "SetValueAndState", 11usize, |this| ((**(this as *const *const IPropertyStoreCache_Vtbl)).SetValueAndState)(this, key, ppropvar, state), |result| Some(*result))
+        }
+        Self { base__: <IPropertyStore_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetState, GetValueAndState, SetState, SetValueAndState }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertyStoreCache as windows_core::Interface>::IID || <IPropertyStore_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPropertyStoreCapabilities_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsPropertyWritable(this: *mut core::ffi::c_void, key: *const PROPERTYKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStoreCapabilities", "IsPropertyWritable", 3usize, |this| ((**(this as *const *const IPropertyStoreCapabilities_Vtbl)).IsPropertyWritable)(this, key), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsPropertyWritable }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertyStoreCapabilities as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPropertyStoreFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPropertyStore(this: *mut core::ffi::c_void, flags: GETPROPERTYSTOREFLAGS, punkfactory: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStoreFactory", "GetPropertyStore", 3usize, |this| ((**(this as *const *const IPropertyStoreFactory_Vtbl)).GetPropertyStore)(this, flags, punkfactory, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyStoreForKeys(this: *mut core::ffi::c_void, rgkeys: *const PROPERTYKEY, ckeys: u32, flags: GETPROPERTYSTOREFLAGS, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyStoreFactory", "GetPropertyStoreForKeys", 4usize, |this| ((**(this as *const *const IPropertyStoreFactory_Vtbl)).GetPropertyStoreForKeys)(this, rgkeys, ckeys, flags, riid, ppv), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetPropertyStore, GetPropertyStoreForKeys }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertyStoreFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPropertySystem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetPropertyDescription(this: *mut core::ffi::c_void, propkey: *const PROPERTYKEY, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertySystem", "GetPropertyDescription", 3usize, |this| ((**(this as *const *const IPropertySystem_Vtbl)).GetPropertyDescription)(this, propkey, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyDescriptionByName(this: *mut core::ffi::c_void, pszcanonicalname: windows_core::PCWSTR, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertySystem", "GetPropertyDescriptionByName", 4usize, |this| ((**(this as *const *const IPropertySystem_Vtbl)).GetPropertyDescriptionByName)(this, pszcanonicalname, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyDescriptionListFromString(this: *mut core::ffi::c_void, pszproplist: windows_core::PCWSTR, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertySystem", "GetPropertyDescriptionListFromString", 5usize, |this| ((**(this as *const *const IPropertySystem_Vtbl)).GetPropertyDescriptionListFromString)(this, pszproplist, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumeratePropertyDescriptions(this: *mut core::ffi::c_void, filteron: PROPDESC_ENUMFILTER, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertySystem", "EnumeratePropertyDescriptions", 6usize, |this| ((**(this as *const *const IPropertySystem_Vtbl)).EnumeratePropertyDescriptions)(this, filteron, riid, ppv), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormatForDisplay(this: *mut core::ffi::c_void, key: *const PROPERTYKEY, propvar: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, pdff: PROPDESC_FORMAT_FLAGS, psztext: windows_core::PWSTR, cchtext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertySystem", "FormatForDisplay", 7usize, |this| ((**(this as *const *const IPropertySystem_Vtbl)).FormatForDisplay)(this, key, propvar, pdff, psztext, cchtext), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormatForDisplayAlloc(this: *mut core::ffi::c_void, key: *const PROPERTYKEY, propvar: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, pdff: PROPDESC_FORMAT_FLAGS, ppszdisplay: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertySystem", "FormatForDisplayAlloc", 8usize, |this| ((**(this as *const *const IPropertySystem_Vtbl)).FormatForDisplayAlloc)(this, key, propvar, pdff, ppszdisplay), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterPropertySchema(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertySystem", "RegisterPropertySchema", 9usize, |this| ((**(this as *const *const IPropertySystem_Vtbl)).RegisterPropertySchema)(this, pszpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterPropertySchema(this: *mut core::ffi::c_void, pszpath: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertySystem", "UnregisterPropertySchema", 10usize, |this| ((**(this as *const *const IPropertySystem_Vtbl)).UnregisterPropertySchema)(this, pszpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn RefreshPropertySchema(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertySystem", "RefreshPropertySchema", 11usize, |this| ((**(this as *const *const IPropertySystem_Vtbl)).RefreshPropertySchema)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetPropertyDescription,
+            GetPropertyDescriptionByName,
+            GetPropertyDescriptionListFromString,
+            EnumeratePropertyDescriptions,
+            FormatForDisplay,
+            FormatForDisplayAlloc,
+            RegisterPropertySchema,
+            UnregisterPropertySchema,
+            RefreshPropertySchema,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertySystem as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPropertySystemChangeNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SchemaRefreshed(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertySystemChangeNotify", "SchemaRefreshed", 3usize, |this| ((**(this as *const *const IPropertySystemChangeNotify_Vtbl)).SchemaRefreshed)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SchemaRefreshed }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertySystemChangeNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPropertyUI_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ParsePropertyName(this: *mut core::ffi::c_void, pszname: windows_core::PCWSTR, pfmtid: *mut windows_core::GUID, ppid: *mut u32, pcheaten: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyUI", "ParsePropertyName", 3usize, |this| ((**(this as *const *const IPropertyUI_Vtbl)).ParsePropertyName)(this, pszname, pfmtid, ppid, pcheaten), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCannonicalName(this: *mut core::ffi::c_void, fmtid: *const windows_core::GUID, pid: u32, pwsztext: windows_core::PWSTR, cchtext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyUI", "GetCannonicalName", 4usize, |this| ((**(this as *const *const IPropertyUI_Vtbl)).GetCannonicalName)(this, fmtid, pid, pwsztext, cchtext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayName(this: *mut core::ffi::c_void, fmtid: *const windows_core::GUID, pid: u32, flags: PROPERTYUI_NAME_FLAGS, pwsztext: windows_core::PWSTR, cchtext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyUI", "GetDisplayName", 5usize, |this| ((**(this as *const *const IPropertyUI_Vtbl)).GetDisplayName)(this, fmtid, pid, flags, pwsztext, cchtext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyDescription(this: *mut core::ffi::c_void, fmtid: *const windows_core::GUID, pid: u32, pwsztext: windows_core::PWSTR, cchtext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyUI", "GetPropertyDescription", 6usize, |this| ((**(this as *const *const IPropertyUI_Vtbl)).GetPropertyDescription)(this, fmtid, pid, pwsztext, cchtext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultWidth(this: *mut core::ffi::c_void, fmtid: *const windows_core::GUID, pid: u32, pcxchars: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyUI", "GetDefaultWidth", 7usize, |this| ((**(this as *const *const IPropertyUI_Vtbl)).GetDefaultWidth)(this, fmtid, pid, pcxchars), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlags(this: *mut core::ffi::c_void, fmtid: *const windows_core::GUID, pid: u32, pflags: *mut PROPERTYUI_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyUI", "GetFlags", 8usize, |this| ((**(this as *const *const IPropertyUI_Vtbl)).GetFlags)(this, fmtid, pid, pflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn FormatForDisplay(this: *mut core::ffi::c_void, fmtid: *const windows_core::GUID, pid: u32, ppropvar: *const core::mem::MaybeUninit<windows_core::PROPVARIANT>, puiff: PROPERTYUI_FORMAT_FLAGS, pwsztext: windows_core::PWSTR, cchtext: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyUI", "FormatForDisplay", 9usize, |this| ((**(this as *const *const IPropertyUI_Vtbl)).FormatForDisplay)(this, fmtid, pid, ppropvar, puiff, pwsztext, cchtext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHelpInfo(this: *mut core::ffi::c_void, fmtid: *const windows_core::GUID, pid: u32, pwszhelpfile: windows_core::PWSTR, cch: u32, puhelpid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPropertyUI", "GetHelpInfo", 10usize, |this| ((**(this as *const *const IPropertyUI_Vtbl)).GetHelpInfo)(this, fmtid, pid, pwszhelpfile, cch, puhelpid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ParsePropertyName,
+            GetCannonicalName,
+            GetDisplayName,
+            GetPropertyDescription,
+            GetDefaultWidth,
+            GetFlags,
+            FormatForDisplay,
+            GetHelpInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPropertyUI as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
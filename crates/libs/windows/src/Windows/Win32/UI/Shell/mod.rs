@@ -28181,6 +28181,7 @@ pub struct ACENUMOPTION(pub i32);
 impl windows_core::TypeKind for ACENUMOPTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ACENUMOPTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ACENUMOPTION").field(&self.0).finish()
@@ -28192,6 +28193,7 @@ pub struct ACTIVATEOPTIONS(pub i32);
 impl windows_core::TypeKind for ACTIVATEOPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ACTIVATEOPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ACTIVATEOPTIONS").field(&self.0).finish()
@@ -28236,6 +28238,7 @@ pub struct ADJACENT_DISPLAY_EDGES(pub i32);
 impl windows_core::TypeKind for ADJACENT_DISPLAY_EDGES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ADJACENT_DISPLAY_EDGES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ADJACENT_DISPLAY_EDGES").field(&self.0).finish()
@@ -28280,6 +28283,7 @@ pub struct AHE_TYPE(pub i32);
 impl windows_core::TypeKind for AHE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AHE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AHE_TYPE").field(&self.0).finish()
@@ -28291,6 +28295,7 @@ pub struct AHTYPE(pub i32);
 impl windows_core::TypeKind for AHTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AHTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AHTYPE").field(&self.0).finish()
@@ -28335,6 +28340,7 @@ pub struct APPACTIONFLAGS(pub i32);
 impl windows_core::TypeKind for APPACTIONFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for APPACTIONFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("APPACTIONFLAGS").field(&self.0).finish()
@@ -28346,6 +28352,7 @@ pub struct APPDOCLISTTYPE(pub i32);
 impl windows_core::TypeKind for APPDOCLISTTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for APPDOCLISTTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("APPDOCLISTTYPE").field(&self.0).finish()
@@ -28357,6 +28364,7 @@ pub struct APPINFODATAFLAGS(pub i32);
 impl windows_core::TypeKind for APPINFODATAFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for APPINFODATAFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("APPINFODATAFLAGS").field(&self.0).finish()
@@ -28368,6 +28376,7 @@ pub struct APPLICATION_VIEW_MIN_WIDTH(pub i32);
 impl windows_core::TypeKind for APPLICATION_VIEW_MIN_WIDTH {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for APPLICATION_VIEW_MIN_WIDTH {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("APPLICATION_VIEW_MIN_WIDTH").field(&self.0).finish()
@@ -28379,6 +28388,7 @@ pub struct APPLICATION_VIEW_ORIENTATION(pub i32);
 impl windows_core::TypeKind for APPLICATION_VIEW_ORIENTATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for APPLICATION_VIEW_ORIENTATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("APPLICATION_VIEW_ORIENTATION").field(&self.0).finish()
@@ -28390,6 +28400,7 @@ pub struct APPLICATION_VIEW_SIZE_PREFERENCE(pub i32);
 impl windows_core::TypeKind for APPLICATION_VIEW_SIZE_PREFERENCE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for APPLICATION_VIEW_SIZE_PREFERENCE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("APPLICATION_VIEW_SIZE_PREFERENCE").field(&self.0).finish()
@@ -28401,6 +28412,7 @@ pub struct APPLICATION_VIEW_STATE(pub i32);
 impl windows_core::TypeKind for APPLICATION_VIEW_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for APPLICATION_VIEW_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("APPLICATION_VIEW_STATE").field(&self.0).finish()
@@ -28412,6 +28424,7 @@ pub struct ASSOCCLASS(pub i32);
 impl windows_core::TypeKind for ASSOCCLASS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ASSOCCLASS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ASSOCCLASS").field(&self.0).finish()
@@ -28423,6 +28436,7 @@ pub struct ASSOCDATA(pub i32);
 impl windows_core::TypeKind for ASSOCDATA {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ASSOCDATA {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ASSOCDATA").field(&self.0).finish()
@@ -28434,6 +28448,7 @@ pub struct ASSOCENUM(pub i32);
 impl windows_core::TypeKind for ASSOCENUM {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ASSOCENUM {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ASSOCENUM").field(&self.0).finish()
@@ -28445,6 +28460,7 @@ pub struct ASSOCF(pub u32);
 impl windows_core::TypeKind for ASSOCF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ASSOCF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ASSOCF").field(&self.0).finish()
@@ -28489,6 +28505,7 @@ pub struct ASSOCIATIONLEVEL(pub i32);
 impl windows_core::TypeKind for ASSOCIATIONLEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ASSOCIATIONLEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ASSOCIATIONLEVEL").field(&self.0).finish()
@@ -28500,6 +28517,7 @@ pub struct ASSOCIATIONTYPE(pub i32);
 impl windows_core::TypeKind for ASSOCIATIONTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ASSOCIATIONTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ASSOCIATIONTYPE").field(&self.0).finish()
@@ -28511,6 +28529,7 @@ pub struct ASSOCKEY(pub i32);
 impl windows_core::TypeKind for ASSOCKEY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ASSOCKEY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ASSOCKEY").field(&self.0).finish()
@@ -28522,6 +28541,7 @@ pub struct ASSOCSTR(pub i32);
 impl windows_core::TypeKind for ASSOCSTR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ASSOCSTR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ASSOCSTR").field(&self.0).finish()
@@ -28533,6 +28553,7 @@ pub struct ASSOC_FILTER(pub i32);
 impl windows_core::TypeKind for ASSOC_FILTER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ASSOC_FILTER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ASSOC_FILTER").field(&self.0).finish()
@@ -28577,6 +28598,7 @@ pub struct ATTACHMENT_ACTION(pub i32);
 impl windows_core::TypeKind for ATTACHMENT_ACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ATTACHMENT_ACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ATTACHMENT_ACTION").field(&self.0).finish()
@@ -28588,6 +28610,7 @@ pub struct ATTACHMENT_PROMPT(pub i32);
 impl windows_core::TypeKind for ATTACHMENT_PROMPT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ATTACHMENT_PROMPT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ATTACHMENT_PROMPT").field(&self.0).finish()
@@ -28599,6 +28622,7 @@ pub struct AUTOCOMPLETELISTOPTIONS(pub i32);
 impl windows_core::TypeKind for AUTOCOMPLETELISTOPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AUTOCOMPLETELISTOPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AUTOCOMPLETELISTOPTIONS").field(&self.0).finish()
@@ -28610,6 +28634,7 @@ pub struct AUTOCOMPLETEOPTIONS(pub i32);
 impl windows_core::TypeKind for AUTOCOMPLETEOPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AUTOCOMPLETEOPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AUTOCOMPLETEOPTIONS").field(&self.0).finish()
@@ -28621,6 +28646,7 @@ pub struct BANDSITECID(pub i32);
 impl windows_core::TypeKind for BANDSITECID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BANDSITECID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BANDSITECID").field(&self.0).finish()
@@ -28632,6 +28658,7 @@ pub struct BANNER_NOTIFICATION_EVENT(pub i32);
 impl windows_core::TypeKind for BANNER_NOTIFICATION_EVENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BANNER_NOTIFICATION_EVENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BANNER_NOTIFICATION_EVENT").field(&self.0).finish()
@@ -28643,6 +28670,7 @@ pub struct BNSTATE(pub i32);
 impl windows_core::TypeKind for BNSTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BNSTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BNSTATE").field(&self.0).finish()
@@ -28654,6 +28682,7 @@ pub struct BrowserNavConstants(pub i32);
 impl windows_core::TypeKind for BrowserNavConstants {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BrowserNavConstants {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BrowserNavConstants").field(&self.0).finish()
@@ -28665,6 +28694,7 @@ pub struct CATEGORYINFO_FLAGS(pub i32);
 impl windows_core::TypeKind for CATEGORYINFO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CATEGORYINFO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CATEGORYINFO_FLAGS").field(&self.0).finish()
@@ -28709,6 +28739,7 @@ pub struct CATSORT_FLAGS(pub i32);
 impl windows_core::TypeKind for CATSORT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CATSORT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CATSORT_FLAGS").field(&self.0).finish()
@@ -28753,6 +28784,7 @@ pub struct CDBURNINGEXTENSIONRET(pub i32);
 impl windows_core::TypeKind for CDBURNINGEXTENSIONRET {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CDBURNINGEXTENSIONRET {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CDBURNINGEXTENSIONRET").field(&self.0).finish()
@@ -28764,6 +28796,7 @@ pub struct CDCONTROLSTATEF(pub i32);
 impl windows_core::TypeKind for CDCONTROLSTATEF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CDCONTROLSTATEF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CDCONTROLSTATEF").field(&self.0).finish()
@@ -28808,6 +28841,7 @@ pub struct CM_ENUM_FLAGS(pub i32);
 impl windows_core::TypeKind for CM_ENUM_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CM_ENUM_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CM_ENUM_FLAGS").field(&self.0).finish()
@@ -28852,6 +28886,7 @@ pub struct CM_MASK(pub i32);
 impl windows_core::TypeKind for CM_MASK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CM_MASK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CM_MASK").field(&self.0).finish()
@@ -28896,6 +28931,7 @@ pub struct CM_SET_WIDTH_VALUE(pub i32);
 impl windows_core::TypeKind for CM_SET_WIDTH_VALUE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CM_SET_WIDTH_VALUE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CM_SET_WIDTH_VALUE").field(&self.0).finish()
@@ -28907,6 +28943,7 @@ pub struct CM_STATE(pub i32);
 impl windows_core::TypeKind for CM_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CM_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CM_STATE").field(&self.0).finish()
@@ -28951,6 +28988,7 @@ pub struct CPVIEW(pub i32);
 impl windows_core::TypeKind for CPVIEW {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CPVIEW {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CPVIEW").field(&self.0).finish()
@@ -28962,6 +29000,7 @@ pub struct CREDENTIAL_PROVIDER_ACCOUNT_OPTIONS(pub i32);
 impl windows_core::TypeKind for CREDENTIAL_PROVIDER_ACCOUNT_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CREDENTIAL_PROVIDER_ACCOUNT_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CREDENTIAL_PROVIDER_ACCOUNT_OPTIONS").field(&self.0).finish()
@@ -29006,6 +29045,7 @@ pub struct CREDENTIAL_PROVIDER_CREDENTIAL_FIELD_OPTIONS(pub i32);
 impl windows_core::TypeKind for CREDENTIAL_PROVIDER_CREDENTIAL_FIELD_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CREDENTIAL_PROVIDER_CREDENTIAL_FIELD_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CREDENTIAL_PROVIDER_CREDENTIAL_FIELD_OPTIONS").field(&self.0).finish()
@@ -29050,6 +29090,7 @@ pub struct CREDENTIAL_PROVIDER_FIELD_INTERACTIVE_STATE(pub i32);
 impl windows_core::TypeKind for CREDENTIAL_PROVIDER_FIELD_INTERACTIVE_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CREDENTIAL_PROVIDER_FIELD_INTERACTIVE_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CREDENTIAL_PROVIDER_FIELD_INTERACTIVE_STATE").field(&self.0).finish()
@@ -29061,6 +29102,7 @@ pub struct CREDENTIAL_PROVIDER_FIELD_STATE(pub i32);
 impl windows_core::TypeKind for CREDENTIAL_PROVIDER_FIELD_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CREDENTIAL_PROVIDER_FIELD_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CREDENTIAL_PROVIDER_FIELD_STATE").field(&self.0).finish()
@@ -29072,6 +29114,7 @@ pub struct CREDENTIAL_PROVIDER_FIELD_TYPE(pub i32);
 impl windows_core::TypeKind for CREDENTIAL_PROVIDER_FIELD_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CREDENTIAL_PROVIDER_FIELD_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CREDENTIAL_PROVIDER_FIELD_TYPE").field(&self.0).finish()
@@ -29083,6 +29126,7 @@ pub struct CREDENTIAL_PROVIDER_GET_SERIALIZATION_RESPONSE(pub i32);
 impl windows_core::TypeKind for CREDENTIAL_PROVIDER_GET_SERIALIZATION_RESPONSE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CREDENTIAL_PROVIDER_GET_SERIALIZATION_RESPONSE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CREDENTIAL_PROVIDER_GET_SERIALIZATION_RESPONSE").field(&self.0).finish()
@@ -29094,6 +29138,7 @@ pub struct CREDENTIAL_PROVIDER_STATUS_ICON(pub i32);
 impl windows_core::TypeKind for CREDENTIAL_PROVIDER_STATUS_ICON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CREDENTIAL_PROVIDER_STATUS_ICON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CREDENTIAL_PROVIDER_STATUS_ICON").field(&self.0).finish()
@@ -29105,6 +29150,7 @@ pub struct CREDENTIAL_PROVIDER_USAGE_SCENARIO(pub i32);
 impl windows_core::TypeKind for CREDENTIAL_PROVIDER_USAGE_SCENARIO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CREDENTIAL_PROVIDER_USAGE_SCENARIO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CREDENTIAL_PROVIDER_USAGE_SCENARIO").field(&self.0).finish()
@@ -29116,6 +29162,7 @@ pub struct CommandStateChangeConstants(pub i32);
 impl windows_core::TypeKind for CommandStateChangeConstants {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CommandStateChangeConstants {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CommandStateChangeConstants").field(&self.0).finish()
@@ -29127,6 +29174,7 @@ pub struct DATAOBJ_GET_ITEM_FLAGS(pub i32);
 impl windows_core::TypeKind for DATAOBJ_GET_ITEM_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DATAOBJ_GET_ITEM_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DATAOBJ_GET_ITEM_FLAGS").field(&self.0).finish()
@@ -29171,6 +29219,7 @@ pub struct DEFAULTSAVEFOLDERTYPE(pub i32);
 impl windows_core::TypeKind for DEFAULTSAVEFOLDERTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEFAULTSAVEFOLDERTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEFAULTSAVEFOLDERTYPE").field(&self.0).finish()
@@ -29182,6 +29231,7 @@ pub struct DEFAULT_FOLDER_MENU_RESTRICTIONS(pub i32);
 impl windows_core::TypeKind for DEFAULT_FOLDER_MENU_RESTRICTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEFAULT_FOLDER_MENU_RESTRICTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEFAULT_FOLDER_MENU_RESTRICTIONS").field(&self.0).finish()
@@ -29226,6 +29276,7 @@ pub struct DEF_SHARE_ID(pub i32);
 impl windows_core::TypeKind for DEF_SHARE_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DEF_SHARE_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DEF_SHARE_ID").field(&self.0).finish()
@@ -29237,6 +29288,7 @@ pub struct DESKBANDCID(pub i32);
 impl windows_core::TypeKind for DESKBANDCID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DESKBANDCID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DESKBANDCID").field(&self.0).finish()
@@ -29248,6 +29300,7 @@ pub struct DESKTOP_SLIDESHOW_DIRECTION(pub i32);
 impl windows_core::TypeKind for DESKTOP_SLIDESHOW_DIRECTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DESKTOP_SLIDESHOW_DIRECTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DESKTOP_SLIDESHOW_DIRECTION").field(&self.0).finish()
@@ -29259,6 +29312,7 @@ pub struct DESKTOP_SLIDESHOW_OPTIONS(pub i32);
 impl windows_core::TypeKind for DESKTOP_SLIDESHOW_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DESKTOP_SLIDESHOW_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DESKTOP_SLIDESHOW_OPTIONS").field(&self.0).finish()
@@ -29303,6 +29357,7 @@ pub struct DESKTOP_SLIDESHOW_STATE(pub i32);
 impl windows_core::TypeKind for DESKTOP_SLIDESHOW_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DESKTOP_SLIDESHOW_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DESKTOP_SLIDESHOW_STATE").field(&self.0).finish()
@@ -29347,6 +29402,7 @@ pub struct DESKTOP_WALLPAPER_POSITION(pub i32);
 impl windows_core::TypeKind for DESKTOP_WALLPAPER_POSITION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DESKTOP_WALLPAPER_POSITION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DESKTOP_WALLPAPER_POSITION").field(&self.0).finish()
@@ -29358,6 +29414,7 @@ pub struct DFM_CMD(pub i32);
 impl windows_core::TypeKind for DFM_CMD {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DFM_CMD {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DFM_CMD").field(&self.0).finish()
@@ -29369,6 +29426,7 @@ pub struct DFM_MESSAGE_ID(pub i32);
 impl windows_core::TypeKind for DFM_MESSAGE_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DFM_MESSAGE_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DFM_MESSAGE_ID").field(&self.0).finish()
@@ -29380,6 +29438,7 @@ pub struct DISPLAY_DEVICE_TYPE(pub i32);
 impl windows_core::TypeKind for DISPLAY_DEVICE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPLAY_DEVICE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPLAY_DEVICE_TYPE").field(&self.0).finish()
@@ -29391,6 +29450,7 @@ pub struct DROPIMAGETYPE(pub i32);
 impl windows_core::TypeKind for DROPIMAGETYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DROPIMAGETYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DROPIMAGETYPE").field(&self.0).finish()
@@ -29402,6 +29462,7 @@ pub struct DSH_FLAGS(pub i32);
 impl windows_core::TypeKind for DSH_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DSH_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DSH_FLAGS").field(&self.0).finish()
@@ -29446,6 +29507,7 @@ pub struct EC_HOST_UI_MODE(pub i32);
 impl windows_core::TypeKind for EC_HOST_UI_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EC_HOST_UI_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EC_HOST_UI_MODE").field(&self.0).finish()
@@ -29457,6 +29519,7 @@ pub struct EDGE_GESTURE_KIND(pub i32);
 impl windows_core::TypeKind for EDGE_GESTURE_KIND {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EDGE_GESTURE_KIND {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EDGE_GESTURE_KIND").field(&self.0).finish()
@@ -29468,6 +29531,7 @@ pub struct EXPLORER_BROWSER_FILL_FLAGS(pub i32);
 impl windows_core::TypeKind for EXPLORER_BROWSER_FILL_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EXPLORER_BROWSER_FILL_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EXPLORER_BROWSER_FILL_FLAGS").field(&self.0).finish()
@@ -29512,6 +29576,7 @@ pub struct EXPLORER_BROWSER_OPTIONS(pub i32);
 impl windows_core::TypeKind for EXPLORER_BROWSER_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EXPLORER_BROWSER_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EXPLORER_BROWSER_OPTIONS").field(&self.0).finish()
@@ -29556,6 +29621,7 @@ pub struct FDAP(pub i32);
 impl windows_core::TypeKind for FDAP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FDAP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FDAP").field(&self.0).finish()
@@ -29567,6 +29633,7 @@ pub struct FDE_OVERWRITE_RESPONSE(pub i32);
 impl windows_core::TypeKind for FDE_OVERWRITE_RESPONSE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FDE_OVERWRITE_RESPONSE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FDE_OVERWRITE_RESPONSE").field(&self.0).finish()
@@ -29578,6 +29645,7 @@ pub struct FDE_SHAREVIOLATION_RESPONSE(pub i32);
 impl windows_core::TypeKind for FDE_SHAREVIOLATION_RESPONSE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FDE_SHAREVIOLATION_RESPONSE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FDE_SHAREVIOLATION_RESPONSE").field(&self.0).finish()
@@ -29589,6 +29657,7 @@ pub struct FD_FLAGS(pub i32);
 impl windows_core::TypeKind for FD_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FD_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FD_FLAGS").field(&self.0).finish()
@@ -29600,6 +29669,7 @@ pub struct FFFP_MODE(pub i32);
 impl windows_core::TypeKind for FFFP_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FFFP_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FFFP_MODE").field(&self.0).finish()
@@ -29611,6 +29681,7 @@ pub struct FILEOPENDIALOGOPTIONS(pub u32);
 impl windows_core::TypeKind for FILEOPENDIALOGOPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FILEOPENDIALOGOPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FILEOPENDIALOGOPTIONS").field(&self.0).finish()
@@ -29655,6 +29726,7 @@ pub struct FILEOPERATION_FLAGS(pub u32);
 impl windows_core::TypeKind for FILEOPERATION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FILEOPERATION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FILEOPERATION_FLAGS").field(&self.0).finish()
@@ -29699,6 +29771,7 @@ pub struct FILETYPEATTRIBUTEFLAGS(pub i32);
 impl windows_core::TypeKind for FILETYPEATTRIBUTEFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FILETYPEATTRIBUTEFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FILETYPEATTRIBUTEFLAGS").field(&self.0).finish()
@@ -29743,6 +29816,7 @@ pub struct FILE_OPERATION_FLAGS2(pub i32);
 impl windows_core::TypeKind for FILE_OPERATION_FLAGS2 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FILE_OPERATION_FLAGS2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FILE_OPERATION_FLAGS2").field(&self.0).finish()
@@ -29787,6 +29861,7 @@ pub struct FILE_USAGE_TYPE(pub i32);
 impl windows_core::TypeKind for FILE_USAGE_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FILE_USAGE_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FILE_USAGE_TYPE").field(&self.0).finish()
@@ -29798,6 +29873,7 @@ pub struct FLYOUT_PLACEMENT(pub i32);
 impl windows_core::TypeKind for FLYOUT_PLACEMENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FLYOUT_PLACEMENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FLYOUT_PLACEMENT").field(&self.0).finish()
@@ -29809,6 +29885,7 @@ pub struct FOLDERFLAGS(pub i32);
 impl windows_core::TypeKind for FOLDERFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FOLDERFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FOLDERFLAGS").field(&self.0).finish()
@@ -29853,6 +29930,7 @@ pub struct FOLDERLOGICALVIEWMODE(pub i32);
 impl windows_core::TypeKind for FOLDERLOGICALVIEWMODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FOLDERLOGICALVIEWMODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FOLDERLOGICALVIEWMODE").field(&self.0).finish()
@@ -29864,6 +29942,7 @@ pub struct FOLDERVIEWMODE(pub i32);
 impl windows_core::TypeKind for FOLDERVIEWMODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FOLDERVIEWMODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FOLDERVIEWMODE").field(&self.0).finish()
@@ -29875,6 +29954,7 @@ pub struct FOLDERVIEWOPTIONS(pub i32);
 impl windows_core::TypeKind for FOLDERVIEWOPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FOLDERVIEWOPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FOLDERVIEWOPTIONS").field(&self.0).finish()
@@ -29919,6 +29999,7 @@ pub struct FOLDER_ENUM_MODE(pub i32);
 impl windows_core::TypeKind for FOLDER_ENUM_MODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FOLDER_ENUM_MODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FOLDER_ENUM_MODE").field(&self.0).finish()
@@ -29930,6 +30011,7 @@ pub struct FVTEXTTYPE(pub i32);
 impl windows_core::TypeKind for FVTEXTTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FVTEXTTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FVTEXTTYPE").field(&self.0).finish()
@@ -29941,6 +30023,7 @@ pub struct GPFIDL_FLAGS(pub u32);
 impl windows_core::TypeKind for GPFIDL_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GPFIDL_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GPFIDL_FLAGS").field(&self.0).finish()
@@ -29952,6 +30035,7 @@ pub struct HELP_INFO_TYPE(pub i32);
 impl windows_core::TypeKind for HELP_INFO_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HELP_INFO_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HELP_INFO_TYPE").field(&self.0).finish()
@@ -29963,6 +30047,7 @@ pub struct HLBWIF_FLAGS(pub i32);
 impl windows_core::TypeKind for HLBWIF_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HLBWIF_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HLBWIF_FLAGS").field(&self.0).finish()
@@ -30007,6 +30092,7 @@ pub struct HLFNAMEF(pub i32);
 impl windows_core::TypeKind for HLFNAMEF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HLFNAMEF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HLFNAMEF").field(&self.0).finish()
@@ -30051,6 +30137,7 @@ pub struct HLID_INFO(pub u32);
 impl windows_core::TypeKind for HLID_INFO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HLID_INFO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HLID_INFO").field(&self.0).finish()
@@ -30062,6 +30149,7 @@ pub struct HLINKGETREF(pub i32);
 impl windows_core::TypeKind for HLINKGETREF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HLINKGETREF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HLINKGETREF").field(&self.0).finish()
@@ -30073,6 +30161,7 @@ pub struct HLINKMISC(pub i32);
 impl windows_core::TypeKind for HLINKMISC {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HLINKMISC {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HLINKMISC").field(&self.0).finish()
@@ -30084,6 +30173,7 @@ pub struct HLINKSETF(pub i32);
 impl windows_core::TypeKind for HLINKSETF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HLINKSETF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HLINKSETF").field(&self.0).finish()
@@ -30095,6 +30185,7 @@ pub struct HLINKWHICHMK(pub i32);
 impl windows_core::TypeKind for HLINKWHICHMK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HLINKWHICHMK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HLINKWHICHMK").field(&self.0).finish()
@@ -30106,6 +30197,7 @@ pub struct HLNF(pub u32);
 impl windows_core::TypeKind for HLNF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HLNF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HLNF").field(&self.0).finish()
@@ -30150,6 +30242,7 @@ pub struct HLQF_INFO(pub i32);
 impl windows_core::TypeKind for HLQF_INFO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HLQF_INFO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HLQF_INFO").field(&self.0).finish()
@@ -30161,6 +30254,7 @@ pub struct HLSHORTCUTF(pub i32);
 impl windows_core::TypeKind for HLSHORTCUTF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HLSHORTCUTF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HLSHORTCUTF").field(&self.0).finish()
@@ -30172,6 +30266,7 @@ pub struct HLSR(pub i32);
 impl windows_core::TypeKind for HLSR {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HLSR {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HLSR").field(&self.0).finish()
@@ -30183,6 +30278,7 @@ pub struct HLTB_INFO(pub i32);
 impl windows_core::TypeKind for HLTB_INFO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HLTB_INFO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HLTB_INFO").field(&self.0).finish()
@@ -30194,6 +30290,7 @@ pub struct HLTRANSLATEF(pub i32);
 impl windows_core::TypeKind for HLTRANSLATEF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HLTRANSLATEF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HLTRANSLATEF").field(&self.0).finish()
@@ -30205,6 +30302,7 @@ pub struct HOMEGROUPSHARINGCHOICES(pub i32);
 impl windows_core::TypeKind for HOMEGROUPSHARINGCHOICES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for HOMEGROUPSHARINGCHOICES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("HOMEGROUPSHARINGCHOICES").field(&self.0).finish()
@@ -30249,6 +30347,7 @@ pub struct IEPDNFLAGS(pub i32);
 impl windows_core::TypeKind for IEPDNFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IEPDNFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IEPDNFLAGS").field(&self.0).finish()
@@ -30260,6 +30359,7 @@ pub struct IESHORTCUTFLAGS(pub i32);
 impl windows_core::TypeKind for IESHORTCUTFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IESHORTCUTFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IESHORTCUTFLAGS").field(&self.0).finish()
@@ -30271,6 +30371,7 @@ pub struct IURL_INVOKECOMMAND_FLAGS(pub i32);
 impl windows_core::TypeKind for IURL_INVOKECOMMAND_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IURL_INVOKECOMMAND_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IURL_INVOKECOMMAND_FLAGS").field(&self.0).finish()
@@ -30282,6 +30383,7 @@ pub struct IURL_SETURL_FLAGS(pub i32);
 impl windows_core::TypeKind for IURL_SETURL_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IURL_SETURL_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IURL_SETURL_FLAGS").field(&self.0).finish()
@@ -30293,6 +30395,7 @@ pub struct KF_CATEGORY(pub i32);
 impl windows_core::TypeKind for KF_CATEGORY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KF_CATEGORY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KF_CATEGORY").field(&self.0).finish()
@@ -30304,6 +30407,7 @@ pub struct KNOWNDESTCATEGORY(pub i32);
 impl windows_core::TypeKind for KNOWNDESTCATEGORY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KNOWNDESTCATEGORY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KNOWNDESTCATEGORY").field(&self.0).finish()
@@ -30315,6 +30419,7 @@ pub struct KNOWN_FOLDER_FLAG(pub i32);
 impl windows_core::TypeKind for KNOWN_FOLDER_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KNOWN_FOLDER_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KNOWN_FOLDER_FLAG").field(&self.0).finish()
@@ -30359,6 +30464,7 @@ pub struct LIBRARYFOLDERFILTER(pub i32);
 impl windows_core::TypeKind for LIBRARYFOLDERFILTER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LIBRARYFOLDERFILTER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LIBRARYFOLDERFILTER").field(&self.0).finish()
@@ -30370,6 +30476,7 @@ pub struct LIBRARYMANAGEDIALOGOPTIONS(pub i32);
 impl windows_core::TypeKind for LIBRARYMANAGEDIALOGOPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LIBRARYMANAGEDIALOGOPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LIBRARYMANAGEDIALOGOPTIONS").field(&self.0).finish()
@@ -30414,6 +30521,7 @@ pub struct LIBRARYOPTIONFLAGS(pub i32);
 impl windows_core::TypeKind for LIBRARYOPTIONFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LIBRARYOPTIONFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LIBRARYOPTIONFLAGS").field(&self.0).finish()
@@ -30458,6 +30566,7 @@ pub struct LIBRARYSAVEFLAGS(pub i32);
 impl windows_core::TypeKind for LIBRARYSAVEFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LIBRARYSAVEFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LIBRARYSAVEFLAGS").field(&self.0).finish()
@@ -30502,6 +30611,7 @@ pub struct MENUBANDHANDLERCID(pub i32);
 impl windows_core::TypeKind for MENUBANDHANDLERCID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MENUBANDHANDLERCID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MENUBANDHANDLERCID").field(&self.0).finish()
@@ -30513,6 +30623,7 @@ pub struct MENUPOPUPPOPUPFLAGS(pub i32);
 impl windows_core::TypeKind for MENUPOPUPPOPUPFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MENUPOPUPPOPUPFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MENUPOPUPPOPUPFLAGS").field(&self.0).finish()
@@ -30524,6 +30635,7 @@ pub struct MENUPOPUPSELECT(pub i32);
 impl windows_core::TypeKind for MENUPOPUPSELECT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MENUPOPUPSELECT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MENUPOPUPSELECT").field(&self.0).finish()
@@ -30535,6 +30647,7 @@ pub struct MERGE_UPDATE_STATUS(pub i32);
 impl windows_core::TypeKind for MERGE_UPDATE_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MERGE_UPDATE_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MERGE_UPDATE_STATUS").field(&self.0).finish()
@@ -30546,6 +30659,7 @@ pub struct MIMEASSOCIATIONDIALOG_IN_FLAGS(pub i32);
 impl windows_core::TypeKind for MIMEASSOCIATIONDIALOG_IN_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MIMEASSOCIATIONDIALOG_IN_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MIMEASSOCIATIONDIALOG_IN_FLAGS").field(&self.0).finish()
@@ -30557,6 +30671,7 @@ pub struct MM_FLAGS(pub u32);
 impl windows_core::TypeKind for MM_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MM_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MM_FLAGS").field(&self.0).finish()
@@ -30601,6 +30716,7 @@ pub struct MONITOR_APP_VISIBILITY(pub i32);
 impl windows_core::TypeKind for MONITOR_APP_VISIBILITY {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MONITOR_APP_VISIBILITY {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MONITOR_APP_VISIBILITY").field(&self.0).finish()
@@ -30612,6 +30728,7 @@ pub struct NAMESPACEWALKFLAG(pub i32);
 impl windows_core::TypeKind for NAMESPACEWALKFLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NAMESPACEWALKFLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NAMESPACEWALKFLAG").field(&self.0).finish()
@@ -30656,6 +30773,7 @@ pub struct NATIVE_DISPLAY_ORIENTATION(pub i32);
 impl windows_core::TypeKind for NATIVE_DISPLAY_ORIENTATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NATIVE_DISPLAY_ORIENTATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NATIVE_DISPLAY_ORIENTATION").field(&self.0).finish()
@@ -30667,6 +30785,7 @@ pub struct NOTIFY_ICON_DATA_FLAGS(pub u32);
 impl windows_core::TypeKind for NOTIFY_ICON_DATA_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NOTIFY_ICON_DATA_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NOTIFY_ICON_DATA_FLAGS").field(&self.0).finish()
@@ -30711,6 +30830,7 @@ pub struct NOTIFY_ICON_INFOTIP_FLAGS(pub u32);
 impl windows_core::TypeKind for NOTIFY_ICON_INFOTIP_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NOTIFY_ICON_INFOTIP_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NOTIFY_ICON_INFOTIP_FLAGS").field(&self.0).finish()
@@ -30755,6 +30875,7 @@ pub struct NOTIFY_ICON_MESSAGE(pub u32);
 impl windows_core::TypeKind for NOTIFY_ICON_MESSAGE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NOTIFY_ICON_MESSAGE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NOTIFY_ICON_MESSAGE").field(&self.0).finish()
@@ -30766,6 +30887,7 @@ pub struct NOTIFY_ICON_STATE(pub u32);
 impl windows_core::TypeKind for NOTIFY_ICON_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NOTIFY_ICON_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NOTIFY_ICON_STATE").field(&self.0).finish()
@@ -30810,6 +30932,7 @@ pub struct NSTCFOLDERCAPABILITIES(pub i32);
 impl windows_core::TypeKind for NSTCFOLDERCAPABILITIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NSTCFOLDERCAPABILITIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NSTCFOLDERCAPABILITIES").field(&self.0).finish()
@@ -30854,6 +30977,7 @@ pub struct NSTCGNI(pub i32);
 impl windows_core::TypeKind for NSTCGNI {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NSTCGNI {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NSTCGNI").field(&self.0).finish()
@@ -30865,6 +30989,7 @@ pub struct NSTCSTYLE2(pub i32);
 impl windows_core::TypeKind for NSTCSTYLE2 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NSTCSTYLE2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NSTCSTYLE2").field(&self.0).finish()
@@ -30909,6 +31034,7 @@ pub struct NWMF(pub i32);
 impl windows_core::TypeKind for NWMF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NWMF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NWMF").field(&self.0).finish()
@@ -30953,6 +31079,7 @@ pub struct NewProcessCauseConstants(pub i32);
 impl windows_core::TypeKind for NewProcessCauseConstants {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NewProcessCauseConstants {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NewProcessCauseConstants").field(&self.0).finish()
@@ -30964,6 +31091,7 @@ pub struct OPEN_AS_INFO_FLAGS(pub i32);
 impl windows_core::TypeKind for OPEN_AS_INFO_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OPEN_AS_INFO_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OPEN_AS_INFO_FLAGS").field(&self.0).finish()
@@ -31008,6 +31136,7 @@ pub struct OS(pub u32);
 impl windows_core::TypeKind for OS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OS").field(&self.0).finish()
@@ -31019,6 +31148,7 @@ pub struct OfflineFolderStatus(pub i32);
 impl windows_core::TypeKind for OfflineFolderStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OfflineFolderStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OfflineFolderStatus").field(&self.0).finish()
@@ -31030,6 +31160,7 @@ pub struct PACKAGE_EXECUTION_STATE(pub i32);
 impl windows_core::TypeKind for PACKAGE_EXECUTION_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PACKAGE_EXECUTION_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PACKAGE_EXECUTION_STATE").field(&self.0).finish()
@@ -31041,6 +31172,7 @@ pub struct PATHCCH_OPTIONS(pub u32);
 impl windows_core::TypeKind for PATHCCH_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PATHCCH_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PATHCCH_OPTIONS").field(&self.0).finish()
@@ -31085,6 +31217,7 @@ pub struct PCS_RET(pub u32);
 impl windows_core::TypeKind for PCS_RET {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PCS_RET {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PCS_RET").field(&self.0).finish()
@@ -31129,6 +31262,7 @@ pub struct PIDISF_FLAGS(pub i32);
 impl windows_core::TypeKind for PIDISF_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PIDISF_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PIDISF_FLAGS").field(&self.0).finish()
@@ -31173,6 +31307,7 @@ pub struct PIDISM_OPTIONS(pub i32);
 impl windows_core::TypeKind for PIDISM_OPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PIDISM_OPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PIDISM_OPTIONS").field(&self.0).finish()
@@ -31184,6 +31319,7 @@ pub struct PIDISR_INFO(pub i32);
 impl windows_core::TypeKind for PIDISR_INFO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PIDISR_INFO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PIDISR_INFO").field(&self.0).finish()
@@ -31195,6 +31331,7 @@ pub struct PID_INTSITE(pub i32);
 impl windows_core::TypeKind for PID_INTSITE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PID_INTSITE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PID_INTSITE").field(&self.0).finish()
@@ -31206,6 +31343,7 @@ pub struct PID_IS(pub i32);
 impl windows_core::TypeKind for PID_IS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PID_IS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PID_IS").field(&self.0).finish()
@@ -31217,6 +31355,7 @@ pub struct PRF_FLAGS(pub i32);
 impl windows_core::TypeKind for PRF_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PRF_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PRF_FLAGS").field(&self.0).finish()
@@ -31261,6 +31400,7 @@ pub struct PUBAPPINFOFLAGS(pub i32);
 impl windows_core::TypeKind for PUBAPPINFOFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PUBAPPINFOFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PUBAPPINFOFLAGS").field(&self.0).finish()
@@ -31272,6 +31412,7 @@ pub struct QITIPF_FLAGS(pub i32);
 impl windows_core::TypeKind for QITIPF_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for QITIPF_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("QITIPF_FLAGS").field(&self.0).finish()
@@ -31316,6 +31457,7 @@ pub struct QUERY_USER_NOTIFICATION_STATE(pub i32);
 impl windows_core::TypeKind for QUERY_USER_NOTIFICATION_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for QUERY_USER_NOTIFICATION_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("QUERY_USER_NOTIFICATION_STATE").field(&self.0).finish()
@@ -31327,6 +31469,7 @@ pub struct RESTRICTIONS(pub i32);
 impl windows_core::TypeKind for RESTRICTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RESTRICTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RESTRICTIONS").field(&self.0).finish()
@@ -31338,6 +31481,7 @@ pub struct RefreshConstants(pub i32);
 impl windows_core::TypeKind for RefreshConstants {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RefreshConstants {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RefreshConstants").field(&self.0).finish()
@@ -31349,6 +31493,7 @@ pub struct SCALE_CHANGE_FLAGS(pub i32);
 impl windows_core::TypeKind for SCALE_CHANGE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCALE_CHANGE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCALE_CHANGE_FLAGS").field(&self.0).finish()
@@ -31393,6 +31538,7 @@ pub struct SCNRT_STATUS(pub i32);
 impl windows_core::TypeKind for SCNRT_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCNRT_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCNRT_STATUS").field(&self.0).finish()
@@ -31404,6 +31550,7 @@ pub struct SECURELOCKCODE(pub i32);
 impl windows_core::TypeKind for SECURELOCKCODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SECURELOCKCODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SECURELOCKCODE").field(&self.0).finish()
@@ -31415,6 +31562,7 @@ pub struct SFBS_FLAGS(pub i32);
 impl windows_core::TypeKind for SFBS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SFBS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SFBS_FLAGS").field(&self.0).finish()
@@ -31426,6 +31574,7 @@ pub struct SFVM_MESSAGE_ID(pub i32);
 impl windows_core::TypeKind for SFVM_MESSAGE_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SFVM_MESSAGE_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SFVM_MESSAGE_ID").field(&self.0).finish()
@@ -31437,6 +31586,7 @@ pub struct SFVS_SELECT(pub i32);
 impl windows_core::TypeKind for SFVS_SELECT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SFVS_SELECT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SFVS_SELECT").field(&self.0).finish()
@@ -31448,6 +31598,7 @@ pub struct SHARD(pub i32);
 impl windows_core::TypeKind for SHARD {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHARD {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHARD").field(&self.0).finish()
@@ -31459,6 +31610,7 @@ pub struct SHARE_ROLE(pub i32);
 impl windows_core::TypeKind for SHARE_ROLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHARE_ROLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHARE_ROLE").field(&self.0).finish()
@@ -31470,6 +31622,7 @@ pub struct SHCNE_ID(pub u32);
 impl windows_core::TypeKind for SHCNE_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHCNE_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHCNE_ID").field(&self.0).finish()
@@ -31514,6 +31667,7 @@ pub struct SHCNF_FLAGS(pub u32);
 impl windows_core::TypeKind for SHCNF_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHCNF_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHCNF_FLAGS").field(&self.0).finish()
@@ -31558,6 +31712,7 @@ pub struct SHCNRF_SOURCE(pub i32);
 impl windows_core::TypeKind for SHCNRF_SOURCE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHCNRF_SOURCE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHCNRF_SOURCE").field(&self.0).finish()
@@ -31602,6 +31757,7 @@ pub struct SHDID_ID(pub i32);
 impl windows_core::TypeKind for SHDID_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHDID_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHDID_ID").field(&self.0).finish()
@@ -31613,6 +31769,7 @@ pub struct SHELLBROWSERSHOWCONTROL(pub i32);
 impl windows_core::TypeKind for SHELLBROWSERSHOWCONTROL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHELLBROWSERSHOWCONTROL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHELLBROWSERSHOWCONTROL").field(&self.0).finish()
@@ -31624,6 +31781,7 @@ pub struct SHELL_AUTOCOMPLETE_FLAGS(pub u32);
 impl windows_core::TypeKind for SHELL_AUTOCOMPLETE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHELL_AUTOCOMPLETE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHELL_AUTOCOMPLETE_FLAGS").field(&self.0).finish()
@@ -31668,6 +31826,7 @@ pub struct SHELL_LINK_DATA_FLAGS(pub i32);
 impl windows_core::TypeKind for SHELL_LINK_DATA_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHELL_LINK_DATA_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHELL_LINK_DATA_FLAGS").field(&self.0).finish()
@@ -31712,6 +31871,7 @@ pub struct SHELL_UI_COMPONENT(pub i32);
 impl windows_core::TypeKind for SHELL_UI_COMPONENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHELL_UI_COMPONENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHELL_UI_COMPONENT").field(&self.0).finish()
@@ -31723,6 +31883,7 @@ pub struct SHFMT_ID(pub u32);
 impl windows_core::TypeKind for SHFMT_ID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHFMT_ID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHFMT_ID").field(&self.0).finish()
@@ -31734,6 +31895,7 @@ pub struct SHFMT_OPT(pub i32);
 impl windows_core::TypeKind for SHFMT_OPT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHFMT_OPT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHFMT_OPT").field(&self.0).finish()
@@ -31778,6 +31940,7 @@ pub struct SHFMT_RET(pub u32);
 impl windows_core::TypeKind for SHFMT_RET {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHFMT_RET {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHFMT_RET").field(&self.0).finish()
@@ -31789,6 +31952,7 @@ pub struct SHGDFIL_FORMAT(pub i32);
 impl windows_core::TypeKind for SHGDFIL_FORMAT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHGDFIL_FORMAT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHGDFIL_FORMAT").field(&self.0).finish()
@@ -31800,6 +31964,7 @@ pub struct SHGDNF(pub u32);
 impl windows_core::TypeKind for SHGDNF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHGDNF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHGDNF").field(&self.0).finish()
@@ -31811,6 +31976,7 @@ pub struct SHGFI_FLAGS(pub u32);
 impl windows_core::TypeKind for SHGFI_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHGFI_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHGFI_FLAGS").field(&self.0).finish()
@@ -31855,6 +32021,7 @@ pub struct SHGFP_TYPE(pub i32);
 impl windows_core::TypeKind for SHGFP_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHGFP_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHGFP_TYPE").field(&self.0).finish()
@@ -31866,6 +32033,7 @@ pub struct SHGLOBALCOUNTER(pub i32);
 impl windows_core::TypeKind for SHGLOBALCOUNTER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHGLOBALCOUNTER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHGLOBALCOUNTER").field(&self.0).finish()
@@ -31877,6 +32045,7 @@ pub struct SHGSI_FLAGS(pub u32);
 impl windows_core::TypeKind for SHGSI_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHGSI_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHGSI_FLAGS").field(&self.0).finish()
@@ -31921,6 +32090,7 @@ pub struct SHOP_TYPE(pub i32);
 impl windows_core::TypeKind for SHOP_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHOP_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHOP_TYPE").field(&self.0).finish()
@@ -31965,6 +32135,7 @@ pub struct SHREGDEL_FLAGS(pub i32);
 impl windows_core::TypeKind for SHREGDEL_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHREGDEL_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHREGDEL_FLAGS").field(&self.0).finish()
@@ -31976,6 +32147,7 @@ pub struct SHREGENUM_FLAGS(pub i32);
 impl windows_core::TypeKind for SHREGENUM_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHREGENUM_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHREGENUM_FLAGS").field(&self.0).finish()
@@ -31987,6 +32159,7 @@ pub struct SHSTOCKICONID(pub i32);
 impl windows_core::TypeKind for SHSTOCKICONID {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SHSTOCKICONID {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SHSTOCKICONID").field(&self.0).finish()
@@ -31998,6 +32171,7 @@ pub struct SIATTRIBFLAGS(pub i32);
 impl windows_core::TypeKind for SIATTRIBFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SIATTRIBFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SIATTRIBFLAGS").field(&self.0).finish()
@@ -32042,6 +32216,7 @@ pub struct SIGDN(pub i32);
 impl windows_core::TypeKind for SIGDN {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SIGDN {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SIGDN").field(&self.0).finish()
@@ -32053,6 +32228,7 @@ pub struct SIIGBF(pub i32);
 impl windows_core::TypeKind for SIIGBF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SIIGBF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SIIGBF").field(&self.0).finish()
@@ -32097,6 +32273,7 @@ pub struct SLGP_FLAGS(pub i32);
 impl windows_core::TypeKind for SLGP_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SLGP_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SLGP_FLAGS").field(&self.0).finish()
@@ -32141,6 +32318,7 @@ pub struct SLR_FLAGS(pub i32);
 impl windows_core::TypeKind for SLR_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SLR_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SLR_FLAGS").field(&self.0).finish()
@@ -32185,6 +32363,7 @@ pub struct SMINFOFLAGS(pub i32);
 impl windows_core::TypeKind for SMINFOFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SMINFOFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SMINFOFLAGS").field(&self.0).finish()
@@ -32196,6 +32375,7 @@ pub struct SMINFOMASK(pub i32);
 impl windows_core::TypeKind for SMINFOMASK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SMINFOMASK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SMINFOMASK").field(&self.0).finish()
@@ -32207,6 +32387,7 @@ pub struct SMINFOTYPE(pub i32);
 impl windows_core::TypeKind for SMINFOTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SMINFOTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SMINFOTYPE").field(&self.0).finish()
@@ -32218,6 +32399,7 @@ pub struct SORTDIRECTION(pub i32);
 impl windows_core::TypeKind for SORTDIRECTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SORTDIRECTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SORTDIRECTION").field(&self.0).finish()
@@ -32229,6 +32411,7 @@ pub struct SORT_ORDER_TYPE(pub i32);
 impl windows_core::TypeKind for SORT_ORDER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SORT_ORDER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SORT_ORDER_TYPE").field(&self.0).finish()
@@ -32240,6 +32423,7 @@ pub struct SPACTION(pub i32);
 impl windows_core::TypeKind for SPACTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPACTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPACTION").field(&self.0).finish()
@@ -32251,6 +32435,7 @@ pub struct SPTEXT(pub i32);
 impl windows_core::TypeKind for SPTEXT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SPTEXT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SPTEXT").field(&self.0).finish()
@@ -32262,6 +32447,7 @@ pub struct SSF_MASK(pub u32);
 impl windows_core::TypeKind for SSF_MASK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SSF_MASK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SSF_MASK").field(&self.0).finish()
@@ -32306,6 +32492,7 @@ pub struct STGOP(pub i32);
 impl windows_core::TypeKind for STGOP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STGOP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STGOP").field(&self.0).finish()
@@ -32317,6 +32504,7 @@ pub struct STORAGE_PROVIDER_FILE_FLAGS(pub i32);
 impl windows_core::TypeKind for STORAGE_PROVIDER_FILE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STORAGE_PROVIDER_FILE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STORAGE_PROVIDER_FILE_FLAGS").field(&self.0).finish()
@@ -32361,6 +32549,7 @@ pub struct STPFLAG(pub i32);
 impl windows_core::TypeKind for STPFLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for STPFLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("STPFLAG").field(&self.0).finish()
@@ -32405,6 +32594,7 @@ pub struct SVUIA_STATUS(pub i32);
 impl windows_core::TypeKind for SVUIA_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SVUIA_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SVUIA_STATUS").field(&self.0).finish()
@@ -32416,6 +32606,7 @@ pub struct SYNCMGRERRORFLAGS(pub i32);
 impl windows_core::TypeKind for SYNCMGRERRORFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNCMGRERRORFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNCMGRERRORFLAGS").field(&self.0).finish()
@@ -32427,6 +32618,7 @@ pub struct SYNCMGRFLAG(pub i32);
 impl windows_core::TypeKind for SYNCMGRFLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNCMGRFLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNCMGRFLAG").field(&self.0).finish()
@@ -32438,6 +32630,7 @@ pub struct SYNCMGRHANDLERFLAGS(pub i32);
 impl windows_core::TypeKind for SYNCMGRHANDLERFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNCMGRHANDLERFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNCMGRHANDLERFLAGS").field(&self.0).finish()
@@ -32449,6 +32642,7 @@ pub struct SYNCMGRINVOKEFLAGS(pub i32);
 impl windows_core::TypeKind for SYNCMGRINVOKEFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNCMGRINVOKEFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNCMGRINVOKEFLAGS").field(&self.0).finish()
@@ -32460,6 +32654,7 @@ pub struct SYNCMGRITEMFLAGS(pub i32);
 impl windows_core::TypeKind for SYNCMGRITEMFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNCMGRITEMFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNCMGRITEMFLAGS").field(&self.0).finish()
@@ -32471,6 +32666,7 @@ pub struct SYNCMGRITEMSTATE(pub i32);
 impl windows_core::TypeKind for SYNCMGRITEMSTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNCMGRITEMSTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNCMGRITEMSTATE").field(&self.0).finish()
@@ -32482,6 +32678,7 @@ pub struct SYNCMGRLOGLEVEL(pub i32);
 impl windows_core::TypeKind for SYNCMGRLOGLEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNCMGRLOGLEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNCMGRLOGLEVEL").field(&self.0).finish()
@@ -32493,6 +32690,7 @@ pub struct SYNCMGRREGISTERFLAGS(pub i32);
 impl windows_core::TypeKind for SYNCMGRREGISTERFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNCMGRREGISTERFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNCMGRREGISTERFLAGS").field(&self.0).finish()
@@ -32504,6 +32702,7 @@ pub struct SYNCMGRSTATUS(pub i32);
 impl windows_core::TypeKind for SYNCMGRSTATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNCMGRSTATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNCMGRSTATUS").field(&self.0).finish()
@@ -32515,6 +32714,7 @@ pub struct SYNCMGR_CANCEL_REQUEST(pub i32);
 impl windows_core::TypeKind for SYNCMGR_CANCEL_REQUEST {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNCMGR_CANCEL_REQUEST {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNCMGR_CANCEL_REQUEST").field(&self.0).finish()
@@ -32526,6 +32726,7 @@ pub struct SYNCMGR_CONFLICT_ITEM_TYPE(pub i32);
 impl windows_core::TypeKind for SYNCMGR_CONFLICT_ITEM_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNCMGR_CONFLICT_ITEM_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNCMGR_CONFLICT_ITEM_TYPE").field(&self.0).finish()
@@ -32537,6 +32738,7 @@ pub struct SYNCMGR_CONTROL_FLAGS(pub i32);
 impl windows_core::TypeKind for SYNCMGR_CONTROL_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNCMGR_CONTROL_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNCMGR_CONTROL_FLAGS").field(&self.0).finish()
@@ -32548,6 +32750,7 @@ pub struct SYNCMGR_EVENT_FLAGS(pub i32);
 impl windows_core::TypeKind for SYNCMGR_EVENT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNCMGR_EVENT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNCMGR_EVENT_FLAGS").field(&self.0).finish()
@@ -32559,6 +32762,7 @@ pub struct SYNCMGR_EVENT_LEVEL(pub i32);
 impl windows_core::TypeKind for SYNCMGR_EVENT_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNCMGR_EVENT_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNCMGR_EVENT_LEVEL").field(&self.0).finish()
@@ -32570,6 +32774,7 @@ pub struct SYNCMGR_HANDLER_CAPABILITIES(pub i32);
 impl windows_core::TypeKind for SYNCMGR_HANDLER_CAPABILITIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNCMGR_HANDLER_CAPABILITIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNCMGR_HANDLER_CAPABILITIES").field(&self.0).finish()
@@ -32581,6 +32786,7 @@ pub struct SYNCMGR_HANDLER_POLICIES(pub i32);
 impl windows_core::TypeKind for SYNCMGR_HANDLER_POLICIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNCMGR_HANDLER_POLICIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNCMGR_HANDLER_POLICIES").field(&self.0).finish()
@@ -32592,6 +32798,7 @@ pub struct SYNCMGR_HANDLER_TYPE(pub i32);
 impl windows_core::TypeKind for SYNCMGR_HANDLER_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNCMGR_HANDLER_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNCMGR_HANDLER_TYPE").field(&self.0).finish()
@@ -32603,6 +32810,7 @@ pub struct SYNCMGR_ITEM_CAPABILITIES(pub i32);
 impl windows_core::TypeKind for SYNCMGR_ITEM_CAPABILITIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNCMGR_ITEM_CAPABILITIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNCMGR_ITEM_CAPABILITIES").field(&self.0).finish()
@@ -32614,6 +32822,7 @@ pub struct SYNCMGR_ITEM_POLICIES(pub i32);
 impl windows_core::TypeKind for SYNCMGR_ITEM_POLICIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNCMGR_ITEM_POLICIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNCMGR_ITEM_POLICIES").field(&self.0).finish()
@@ -32625,6 +32834,7 @@ pub struct SYNCMGR_PRESENTER_CHOICE(pub i32);
 impl windows_core::TypeKind for SYNCMGR_PRESENTER_CHOICE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNCMGR_PRESENTER_CHOICE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNCMGR_PRESENTER_CHOICE").field(&self.0).finish()
@@ -32636,6 +32846,7 @@ pub struct SYNCMGR_PRESENTER_NEXT_STEP(pub i32);
 impl windows_core::TypeKind for SYNCMGR_PRESENTER_NEXT_STEP {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNCMGR_PRESENTER_NEXT_STEP {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNCMGR_PRESENTER_NEXT_STEP").field(&self.0).finish()
@@ -32647,6 +32858,7 @@ pub struct SYNCMGR_PROGRESS_STATUS(pub i32);
 impl windows_core::TypeKind for SYNCMGR_PROGRESS_STATUS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNCMGR_PROGRESS_STATUS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNCMGR_PROGRESS_STATUS").field(&self.0).finish()
@@ -32658,6 +32870,7 @@ pub struct SYNCMGR_RESOLUTION_ABILITIES(pub i32);
 impl windows_core::TypeKind for SYNCMGR_RESOLUTION_ABILITIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNCMGR_RESOLUTION_ABILITIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNCMGR_RESOLUTION_ABILITIES").field(&self.0).finish()
@@ -32669,6 +32882,7 @@ pub struct SYNCMGR_RESOLUTION_FEEDBACK(pub i32);
 impl windows_core::TypeKind for SYNCMGR_RESOLUTION_FEEDBACK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNCMGR_RESOLUTION_FEEDBACK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNCMGR_RESOLUTION_FEEDBACK").field(&self.0).finish()
@@ -32680,6 +32894,7 @@ pub struct SYNCMGR_SYNC_CONTROL_FLAGS(pub i32);
 impl windows_core::TypeKind for SYNCMGR_SYNC_CONTROL_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNCMGR_SYNC_CONTROL_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNCMGR_SYNC_CONTROL_FLAGS").field(&self.0).finish()
@@ -32691,6 +32906,7 @@ pub struct SYNCMGR_UPDATE_REASON(pub i32);
 impl windows_core::TypeKind for SYNCMGR_UPDATE_REASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SYNCMGR_UPDATE_REASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SYNCMGR_UPDATE_REASON").field(&self.0).finish()
@@ -32702,6 +32918,7 @@ pub struct SecureLockIconConstants(pub i32);
 impl windows_core::TypeKind for SecureLockIconConstants {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SecureLockIconConstants {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SecureLockIconConstants").field(&self.0).finish()
@@ -32713,6 +32930,7 @@ pub struct ShellFolderViewOptions(pub i32);
 impl windows_core::TypeKind for ShellFolderViewOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ShellFolderViewOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ShellFolderViewOptions").field(&self.0).finish()
@@ -32724,6 +32942,7 @@ pub struct ShellSpecialFolderConstants(pub i32);
 impl windows_core::TypeKind for ShellSpecialFolderConstants {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ShellSpecialFolderConstants {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ShellSpecialFolderConstants").field(&self.0).finish()
@@ -32735,6 +32954,7 @@ pub struct ShellWindowFindWindowOptions(pub i32);
 impl windows_core::TypeKind for ShellWindowFindWindowOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ShellWindowFindWindowOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ShellWindowFindWindowOptions").field(&self.0).finish()
@@ -32746,6 +32966,7 @@ pub struct ShellWindowTypeConstants(pub i32);
 impl windows_core::TypeKind for ShellWindowTypeConstants {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ShellWindowTypeConstants {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ShellWindowTypeConstants").field(&self.0).finish()
@@ -32757,6 +32978,7 @@ pub struct TBPFLAG(pub i32);
 impl windows_core::TypeKind for TBPFLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TBPFLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TBPFLAG").field(&self.0).finish()
@@ -32801,6 +33023,7 @@ pub struct THUMBBUTTONFLAGS(pub i32);
 impl windows_core::TypeKind for THUMBBUTTONFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for THUMBBUTTONFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("THUMBBUTTONFLAGS").field(&self.0).finish()
@@ -32845,6 +33068,7 @@ pub struct THUMBBUTTONMASK(pub i32);
 impl windows_core::TypeKind for THUMBBUTTONMASK {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for THUMBBUTTONMASK {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("THUMBBUTTONMASK").field(&self.0).finish()
@@ -32889,6 +33113,7 @@ pub struct TI_FLAGS(pub i32);
 impl windows_core::TypeKind for TI_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TI_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TI_FLAGS").field(&self.0).finish()
@@ -32900,6 +33125,7 @@ pub struct TLENUMF(pub i32);
 impl windows_core::TypeKind for TLENUMF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TLENUMF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TLENUMF").field(&self.0).finish()
@@ -32911,6 +33137,7 @@ pub struct TRANSLATEURL_IN_FLAGS(pub i32);
 impl windows_core::TypeKind for TRANSLATEURL_IN_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TRANSLATEURL_IN_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TRANSLATEURL_IN_FLAGS").field(&self.0).finish()
@@ -32922,6 +33149,7 @@ pub struct ThumbnailStreamCacheOptions(pub i32);
 impl windows_core::TypeKind for ThumbnailStreamCacheOptions {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ThumbnailStreamCacheOptions {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ThumbnailStreamCacheOptions").field(&self.0).finish()
@@ -32966,6 +33194,7 @@ pub struct UNDOCK_REASON(pub i32);
 impl windows_core::TypeKind for UNDOCK_REASON {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for UNDOCK_REASON {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("UNDOCK_REASON").field(&self.0).finish()
@@ -32977,6 +33206,7 @@ pub struct URLASSOCIATIONDIALOG_IN_FLAGS(pub i32);
 impl windows_core::TypeKind for URLASSOCIATIONDIALOG_IN_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for URLASSOCIATIONDIALOG_IN_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("URLASSOCIATIONDIALOG_IN_FLAGS").field(&self.0).finish()
@@ -32988,6 +33218,7 @@ pub struct URLIS(pub i32);
 impl windows_core::TypeKind for URLIS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for URLIS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("URLIS").field(&self.0).finish()
@@ -32999,6 +33230,7 @@ pub struct URL_PART(pub i32);
 impl windows_core::TypeKind for URL_PART {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for URL_PART {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("URL_PART").field(&self.0).finish()
@@ -33010,6 +33242,7 @@ pub struct URL_SCHEME(pub i32);
 impl windows_core::TypeKind for URL_SCHEME {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for URL_SCHEME {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("URL_SCHEME").field(&self.0).finish()
@@ -33021,6 +33254,7 @@ pub struct VALIDATEUNC_OPTION(pub i32);
 impl windows_core::TypeKind for VALIDATEUNC_OPTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VALIDATEUNC_OPTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VALIDATEUNC_OPTION").field(&self.0).finish()
@@ -33065,6 +33299,7 @@ pub struct VPCOLORFLAGS(pub i32);
 impl windows_core::TypeKind for VPCOLORFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VPCOLORFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VPCOLORFLAGS").field(&self.0).finish()
@@ -33076,6 +33311,7 @@ pub struct VPWATERMARKFLAGS(pub i32);
 impl windows_core::TypeKind for VPWATERMARKFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VPWATERMARKFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VPWATERMARKFLAGS").field(&self.0).finish()
@@ -33120,6 +33356,7 @@ pub struct WTS_ALPHATYPE(pub i32);
 impl windows_core::TypeKind for WTS_ALPHATYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WTS_ALPHATYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WTS_ALPHATYPE").field(&self.0).finish()
@@ -33131,6 +33368,7 @@ pub struct WTS_CACHEFLAGS(pub i32);
 impl windows_core::TypeKind for WTS_CACHEFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WTS_CACHEFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WTS_CACHEFLAGS").field(&self.0).finish()
@@ -33175,6 +33413,7 @@ pub struct WTS_CONTEXTFLAGS(pub i32);
 impl windows_core::TypeKind for WTS_CONTEXTFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WTS_CONTEXTFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WTS_CONTEXTFLAGS").field(&self.0).finish()
@@ -33219,6 +33458,7 @@ pub struct WTS_FLAGS(pub i32);
 impl windows_core::TypeKind for WTS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for WTS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("WTS_FLAGS").field(&self.0).finish()
@@ -33263,6 +33503,7 @@ pub struct _BROWSERFRAMEOPTIONS(pub i32);
 impl windows_core::TypeKind for _BROWSERFRAMEOPTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _BROWSERFRAMEOPTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_BROWSERFRAMEOPTIONS").field(&self.0).finish()
@@ -33274,6 +33515,7 @@ pub struct _CDBE_ACTIONS(pub i32);
 impl windows_core::TypeKind for _CDBE_ACTIONS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _CDBE_ACTIONS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_CDBE_ACTIONS").field(&self.0).finish()
@@ -33285,6 +33527,7 @@ pub struct _EXPCMDFLAGS(pub i32);
 impl windows_core::TypeKind for _EXPCMDFLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _EXPCMDFLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_EXPCMDFLAGS").field(&self.0).finish()
@@ -33296,6 +33539,7 @@ pub struct _EXPCMDSTATE(pub i32);
 impl windows_core::TypeKind for _EXPCMDSTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _EXPCMDSTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_EXPCMDSTATE").field(&self.0).finish()
@@ -33307,6 +33551,7 @@ pub struct _EXPLORERPANESTATE(pub i32);
 impl windows_core::TypeKind for _EXPLORERPANESTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _EXPLORERPANESTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_EXPLORERPANESTATE").field(&self.0).finish()
@@ -33318,6 +33563,7 @@ pub struct _EXPPS(pub i32);
 impl windows_core::TypeKind for _EXPPS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _EXPPS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_EXPPS").field(&self.0).finish()
@@ -33329,6 +33575,7 @@ pub struct _KF_DEFINITION_FLAGS(pub i32);
 impl windows_core::TypeKind for _KF_DEFINITION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _KF_DEFINITION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_KF_DEFINITION_FLAGS").field(&self.0).finish()
@@ -33340,6 +33587,7 @@ pub struct _KF_REDIRECTION_CAPABILITIES(pub i32);
 impl windows_core::TypeKind for _KF_REDIRECTION_CAPABILITIES {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _KF_REDIRECTION_CAPABILITIES {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_KF_REDIRECTION_CAPABILITIES").field(&self.0).finish()
@@ -33351,6 +33599,7 @@ pub struct _KF_REDIRECT_FLAGS(pub i32);
 impl windows_core::TypeKind for _KF_REDIRECT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _KF_REDIRECT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_KF_REDIRECT_FLAGS").field(&self.0).finish()
@@ -33362,6 +33611,7 @@ pub struct _NMCII_FLAGS(pub i32);
 impl windows_core::TypeKind for _NMCII_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _NMCII_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_NMCII_FLAGS").field(&self.0).finish()
@@ -33373,6 +33623,7 @@ pub struct _NMCSAEI_FLAGS(pub i32);
 impl windows_core::TypeKind for _NMCSAEI_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _NMCSAEI_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_NMCSAEI_FLAGS").field(&self.0).finish()
@@ -33384,6 +33635,7 @@ pub struct _NSTCECLICKTYPE(pub i32);
 impl windows_core::TypeKind for _NSTCECLICKTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _NSTCECLICKTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_NSTCECLICKTYPE").field(&self.0).finish()
@@ -33395,6 +33647,7 @@ pub struct _NSTCEHITTEST(pub i32);
 impl windows_core::TypeKind for _NSTCEHITTEST {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _NSTCEHITTEST {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_NSTCEHITTEST").field(&self.0).finish()
@@ -33406,6 +33659,7 @@ pub struct _NSTCITEMSTATE(pub i32);
 impl windows_core::TypeKind for _NSTCITEMSTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _NSTCITEMSTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_NSTCITEMSTATE").field(&self.0).finish()
@@ -33417,6 +33671,7 @@ pub struct _NSTCROOTSTYLE(pub i32);
 impl windows_core::TypeKind for _NSTCROOTSTYLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _NSTCROOTSTYLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_NSTCROOTSTYLE").field(&self.0).finish()
@@ -33428,6 +33683,7 @@ pub struct _NSTCSTYLE(pub i32);
 impl windows_core::TypeKind for _NSTCSTYLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _NSTCSTYLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_NSTCSTYLE").field(&self.0).finish()
@@ -33439,6 +33695,7 @@ pub struct _OPPROGDLGF(pub i32);
 impl windows_core::TypeKind for _OPPROGDLGF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _OPPROGDLGF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_OPPROGDLGF").field(&self.0).finish()
@@ -33450,6 +33707,7 @@ pub struct _PDMODE(pub i32);
 impl windows_core::TypeKind for _PDMODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _PDMODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_PDMODE").field(&self.0).finish()
@@ -33461,6 +33719,7 @@ pub struct _SHCONTF(pub i32);
 impl windows_core::TypeKind for _SHCONTF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _SHCONTF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_SHCONTF").field(&self.0).finish()
@@ -33472,6 +33731,7 @@ pub struct _SICHINTF(pub i32);
 impl windows_core::TypeKind for _SICHINTF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _SICHINTF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_SICHINTF").field(&self.0).finish()
@@ -33483,6 +33743,7 @@ pub struct _SPBEGINF(pub i32);
 impl windows_core::TypeKind for _SPBEGINF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _SPBEGINF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_SPBEGINF").field(&self.0).finish()
@@ -33494,6 +33755,7 @@ pub struct _SPINITF(pub i32);
 impl windows_core::TypeKind for _SPINITF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _SPINITF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_SPINITF").field(&self.0).finish()
@@ -33505,6 +33767,7 @@ pub struct _SV3CVW3_FLAGS(pub i32);
 impl windows_core::TypeKind for _SV3CVW3_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _SV3CVW3_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_SV3CVW3_FLAGS").field(&self.0).finish()
@@ -33516,6 +33779,7 @@ pub struct _SVGIO(pub i32);
 impl windows_core::TypeKind for _SVGIO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _SVGIO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_SVGIO").field(&self.0).finish()
@@ -33560,6 +33824,7 @@ pub struct _SVSIF(pub i32);
 impl windows_core::TypeKind for _SVSIF {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _SVSIF {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_SVSIF").field(&self.0).finish()
@@ -33571,6 +33836,7 @@ pub struct _TRANSFER_ADVISE_STATE(pub i32);
 impl windows_core::TypeKind for _TRANSFER_ADVISE_STATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _TRANSFER_ADVISE_STATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_TRANSFER_ADVISE_STATE").field(&self.0).finish()
@@ -33582,13 +33848,15 @@ pub struct _TRANSFER_SOURCE_FLAGS(pub i32);
 impl windows_core::TypeKind for _TRANSFER_SOURCE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for _TRANSFER_SOURCE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("_TRANSFER_SOURCE_FLAGS").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AASHELLMENUFILENAME {
     pub cbTotal: i16,
     pub rgbReserved: [u8; 12],
@@ -33603,7 +33871,8 @@ impl Default for AASHELLMENUFILENAME {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct AASHELLMENUITEM {
     pub lpReserved1: *mut core::ffi::c_void,
     pub iReserved: i32,
@@ -33662,7 +33931,8 @@ impl Default for APPBARDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct APPCATEGORYINFO {
     pub Locale: u32,
     pub pszDescription: windows_core::PWSTR,
@@ -33677,7 +33947,8 @@ impl Default for APPCATEGORYINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct APPCATEGORYINFOLIST {
     pub cCategory: u32,
     pub pCategoryInfo: *mut APPCATEGORYINFO,
@@ -33691,7 +33962,8 @@ impl Default for APPCATEGORYINFOLIST {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct APPINFODATA {
     pub cbSize: u32,
     pub dwMask: u32,
@@ -33795,7 +34067,8 @@ pub const ApplicationDocumentLists: windows_core::GUID = windows_core::GUID::fro
 pub const AttachmentServices: windows_core::GUID = windows_core::GUID::from_u128(0x4125dd96_e03a_4103_8f70_e0597d803b9c);
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Shell_Common")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BANDINFOSFB {
     pub dwMask: u32,
     pub dwStateMask: u32,
@@ -33825,7 +34098,8 @@ impl Default for BANDINFOSFB {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BANDSITEINFO {
     pub dwMask: u32,
     pub dwState: u32,
@@ -33840,7 +34114,8 @@ impl Default for BANDSITEINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BANNER_NOTIFICATION {
     pub event: BANNER_NOTIFICATION_EVENT,
     pub providerIdentity: windows_core::PCWSTR,
@@ -33856,7 +34131,8 @@ impl Default for BANNER_NOTIFICATION {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_UI_Shell_Common"))]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BASEBROWSERDATALH {
     pub _hwnd: super::super::Foundation::HWND,
     pub _ptl: core::mem::ManuallyDrop<Option<ITravelLog>>,
@@ -33887,6 +34163,15 @@ pub struct BASEBROWSERDATALH {
     pub _lPhishingFilterStatus: i32,
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_UI_Shell_Common"))]
+impl BASEBROWSERDATALH {
+    pub const fn _fCreatingViewWindow(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set__fCreatingViewWindow(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_UI_Shell_Common"))]
 impl Clone for BASEBROWSERDATALH {
     fn clone(&self) -> Self {
         unsafe { core::mem::transmute_copy(self) }
@@ -33904,7 +34189,8 @@ impl Default for BASEBROWSERDATALH {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_UI_Shell_Common"))]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BASEBROWSERDATAXP {
     pub _hwnd: super::super::Foundation::HWND,
     pub _ptl: core::mem::ManuallyDrop<Option<ITravelLog>>,
@@ -33934,6 +34220,15 @@ pub struct BASEBROWSERDATAXP {
     pub _hwndFrame: super::super::Foundation::HWND,
 }
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_UI_Shell_Common"))]
+impl BASEBROWSERDATAXP {
+    pub const fn _fCreatingViewWindow(&self) -> u32 {
+        self._bitfield & 1
+    }
+    pub fn set__fCreatingViewWindow(&mut self, value: u32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_UI_Shell_Common"))]
 impl Clone for BASEBROWSERDATAXP {
     fn clone(&self) -> Self {
         unsafe { core::mem::transmute_copy(self) }
@@ -33951,7 +34246,8 @@ impl Default for BASEBROWSERDATAXP {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Shell_Common")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BROWSEINFOA {
     pub hwndOwner: super::super::Foundation::HWND,
     pub pidlRoot: *mut Common::ITEMIDLIST,
@@ -33974,7 +34270,8 @@ impl Default for BROWSEINFOA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Shell_Common")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct BROWSEINFOW {
     pub hwndOwner: super::super::Foundation::HWND,
     pub pidlRoot: *mut Common::ITEMIDLIST,
@@ -34003,6 +34300,68 @@ pub struct CABINETSTATE {
     pub _bitfield: i32,
     pub fMenuEnumFilter: u32,
 }
+impl CABINETSTATE {
+    pub const fn fFullPathTitle(&self) -> i32 {
+        self._bitfield & 1
+    }
+    pub fn set_fFullPathTitle(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn fSaveLocalView(&self) -> i32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_fSaveLocalView(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn fNotShell(&self) -> i32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_fNotShell(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn fSimpleDefault(&self) -> i32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_fSimpleDefault(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn fDontShowDescBar(&self) -> i32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_fDontShowDescBar(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn fNewWindowMode(&self) -> i32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_fNewWindowMode(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn fShowCompColor(&self) -> i32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_fShowCompColor(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn fDontPrettyNames(&self) -> i32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_fDontPrettyNames(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn fAdminsCreateCommonGroups(&self) -> i32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_fAdminsCreateCommonGroups(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn fUnusedFlags(&self) -> i32 {
+        (self._bitfield >> 9) & 127
+    }
+    pub fn set_fUnusedFlags(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(127 << 9)) | ((value & 127) << 9);
+    }
+}
 impl windows_core::TypeKind for CABINETSTATE {
     type TypeKind = windows_core::CopyType;
 }
@@ -34012,7 +34371,8 @@ impl Default for CABINETSTATE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CATEGORY_INFO {
     pub cif: CATEGORYINFO_FLAGS,
     pub wszName: [u16; 260],
@@ -34041,7 +34401,8 @@ impl Default for CIDA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CMINVOKECOMMANDINFO {
     pub cbSize: u32,
     pub fMask: u32,
@@ -34062,7 +34423,8 @@ impl Default for CMINVOKECOMMANDINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CMINVOKECOMMANDINFOEX {
     pub cbSize: u32,
     pub fMask: u32,
@@ -34089,7 +34451,8 @@ impl Default for CMINVOKECOMMANDINFOEX {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CMINVOKECOMMANDINFOEX_REMOTE {
     pub cbSize: u32,
     pub fMask: u32,
@@ -34117,7 +34480,8 @@ impl Default for CMINVOKECOMMANDINFOEX_REMOTE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CM_COLUMNINFO {
     pub cbSize: u32,
     pub dwMask: u32,
@@ -34136,7 +34500,8 @@ impl Default for CM_COLUMNINFO {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CONFIRM_CONFLICT_ITEM {
     pub pShellItem: core::mem::ManuallyDrop<Option<IShellItem2>>,
     pub pszOriginalName: windows_core::PWSTR,
@@ -34159,7 +34524,8 @@ impl Default for CONFIRM_CONFLICT_ITEM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CONFIRM_CONFLICT_RESULT_INFO {
     pub pszNewName: windows_core::PWSTR,
     pub iItemIndex: u32,
@@ -34189,7 +34555,8 @@ impl Default for CPLINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CREDENTIAL_PROVIDER_CREDENTIAL_SERIALIZATION {
     pub ulAuthenticationPackage: u32,
     pub clsidCredentialProvider: windows_core::GUID,
@@ -34205,7 +34572,8 @@ impl Default for CREDENTIAL_PROVIDER_CREDENTIAL_SERIALIZATION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CREDENTIAL_PROVIDER_FIELD_DESCRIPTOR {
     pub dwFieldID: u32,
     pub cpft: CREDENTIAL_PROVIDER_FIELD_TYPE,
@@ -34222,7 +34590,7 @@ impl Default for CREDENTIAL_PROVIDER_FIELD_DESCRIPTOR {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_System_Ole", feature = "Win32_UI_Shell_Common"))]
-#[derive(Debug)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CSFV {
     pub cbSize: u32,
     pub pshf: core::mem::ManuallyDrop<Option<IShellFolder>>,
@@ -34266,7 +34634,8 @@ impl Default for DATABLOCK_HEADER {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_System_Registry", feature = "Win32_UI_Shell_Common"))]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DEFCONTEXTMENU {
     pub hwnd: super::super::Foundation::HWND,
     pub pcmcb: core::mem::ManuallyDrop<Option<IContextMenuCB>>,
@@ -34311,7 +34680,8 @@ impl Default for DELEGATEITEMID {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DESKBANDINFO {
     pub dwMask: u32,
     pub ptMinSize: super::super::Foundation::POINTL,
@@ -34351,7 +34721,8 @@ impl Default for DETAILSINFO {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DFMICS {
     pub cbSize: u32,
     pub fMask: u32,
@@ -34375,7 +34746,8 @@ impl Default for DFMICS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DLLVERSIONINFO {
     pub cbSize: u32,
     pub dwMajorVersion: u32,
@@ -34392,7 +34764,8 @@ impl Default for DLLVERSIONINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DLLVERSIONINFO2 {
     pub info1: DLLVERSIONINFO,
     pub dwFlags: u32,
@@ -34587,7 +34960,8 @@ impl Default for EXP_SZ_LINK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct EXTRASEARCH {
     pub guidSearch: windows_core::GUID,
     pub wszFriendlyName: [u16; 80],
@@ -34696,7 +35070,8 @@ impl Default for FILE_ATTRIBUTES_ARRAY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FOLDERSETDATA {
     pub _fs: FOLDERSETTINGS,
     pub _vidRestore: windows_core::GUID,
@@ -34711,7 +35086,8 @@ impl Default for FOLDERSETDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FOLDERSETTINGS {
     pub ViewMode: u32,
     pub fFlags: u32,
@@ -34734,7 +35110,8 @@ pub const FrameworkInputPane: windows_core::GUID = windows_core::GUID::from_u128
 pub const FreeSpaceCategorizer: windows_core::GUID = windows_core::GUID::from_u128(0xb5607793_24ac_44c7_82e2_831726aa6cb7);
 pub const GenericCredentialProvider: windows_core::GUID = windows_core::GUID::from_u128(0x25cbb996_92ed_457e_b28c_4774084bd562);
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HDROP(pub *mut core::ffi::c_void);
 impl HDROP {
     pub fn is_invalid(&self) -> bool {
@@ -34750,7 +35127,8 @@ impl windows_core::TypeKind for HDROP {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HELPINFO {
     pub cbSize: u32,
     pub iContextType: HELP_INFO_TYPE,
@@ -34768,7 +35146,8 @@ impl Default for HELPINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HELPWININFOA {
     pub wStructSize: i32,
     pub x: i32,
@@ -34787,7 +35166,8 @@ impl Default for HELPWININFOA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HELPWININFOW {
     pub wStructSize: i32,
     pub x: i32,
@@ -34806,7 +35186,8 @@ impl Default for HELPWININFOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HLBWINFO {
     pub cbSize: u32,
     pub grfHLBWIF: u32,
@@ -34823,7 +35204,8 @@ impl Default for HLBWINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HLITEM {
     pub uHLID: u32,
     pub pwzFriendlyName: windows_core::PWSTR,
@@ -34837,7 +35219,8 @@ impl Default for HLITEM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HLTBINFO {
     pub uDockType: u32,
     pub rcTbPos: super::super::Foundation::RECT,
@@ -34851,7 +35234,8 @@ impl Default for HLTBINFO {
     }
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HPSXA(pub *mut core::ffi::c_void);
 impl HPSXA {
     pub fn is_invalid(&self) -> bool {
@@ -34878,7 +35262,8 @@ pub const HideInputPaneAnimationCoordinator: windows_core::GUID = windows_core::
 pub const HomeGroup: windows_core::GUID = windows_core::GUID::from_u128(0xde77ba04_3c92_4d11_a1a5_42352a53e0e3);
 pub const IENamespaceTreeControl: windows_core::GUID = windows_core::GUID::from_u128(0xace52d03_e5cd_4b20_82ff_e71b11beae1d);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct ITEMSPACING {
     pub cxSmall: i32,
     pub cySmall: i32,
@@ -34901,7 +35286,8 @@ pub const InternetExplorer: windows_core::GUID = windows_core::GUID::from_u128(0
 pub const InternetExplorerMedium: windows_core::GUID = windows_core::GUID::from_u128(0xd5e8041d_920f_45e9_b8fb_b1deb82c6e5e);
 pub const InternetPrintOrdering: windows_core::GUID = windows_core::GUID::from_u128(0xadd36aa8_751a_4579_a266_d66f5202ccbb);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct KNOWNFOLDER_DEFINITION {
     pub category: KF_CATEGORY,
     pub pszName: windows_core::PWSTR,
@@ -34928,7 +35314,8 @@ impl Default for KNOWNFOLDER_DEFINITION {
 pub const KnownFolderManager: windows_core::GUID = windows_core::GUID::from_u128(0x4df0c730_df9d_4ae3_9153_aa6b82e9795a);
 pub const LocalThumbnailCache: windows_core::GUID = windows_core::GUID::from_u128(0x50ef4544_ac9f_4a8e_b21b_8a26180db13f);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MULTIKEYHELPA {
     pub mkSize: u32,
     pub mkKeylist: i8,
@@ -34943,7 +35330,8 @@ impl Default for MULTIKEYHELPA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MULTIKEYHELPW {
     pub mkSize: u32,
     pub mkKeylist: u16,
@@ -34961,7 +35349,8 @@ pub const MailRecipient: windows_core::GUID = windows_core::GUID::from_u128(0x9e
 pub const MergedCategorizer: windows_core::GUID = windows_core::GUID::from_u128(0x8e827c11_33e7_4bc1_b242_8cd9a1c2b304);
 #[repr(C)]
 #[cfg(all(feature = "Win32_NetworkManagement_IpHelper", feature = "Win32_Networking_WinSock"))]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NC_ADDRESS {
     pub pAddrInfo: *mut super::super::NetworkManagement::IpHelper::NET_ADDRESS_INFO,
     pub PortNumber: u16,
@@ -35276,7 +35665,8 @@ impl Default for NOTIFYICONIDENTIFIER {
 pub const NPCredentialProvider: windows_core::GUID = windows_core::GUID::from_u128(0x3dd6bec0_8193_4ffe_ae25_e08e39ea4063);
 #[repr(C)]
 #[cfg(feature = "Win32_NetworkManagement_WNet")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NRESARRAY {
     pub cItems: u32,
     pub nr: [super::super::NetworkManagement::WNet::NETRESOURCEA; 1],
@@ -35293,7 +35683,8 @@ impl Default for NRESARRAY {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Controls")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NSTCCUSTOMDRAW {
     pub psi: core::mem::ManuallyDrop<Option<IShellItem>>,
     pub uItemState: u32,
@@ -35376,7 +35767,8 @@ pub const NetworkConnections: windows_core::GUID = windows_core::GUID::from_u128
 pub const NetworkExplorerFolder: windows_core::GUID = windows_core::GUID::from_u128(0xf02c1a0d_be21_4350_88b0_7367fc96ef3c);
 pub const NetworkPlaces: windows_core::GUID = windows_core::GUID::from_u128(0x208d2c60_3aea_1069_a2d7_08002b30309d);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct OPENASINFO {
     pub pcszFile: windows_core::PCWSTR,
     pub pcszClass: windows_core::PCWSTR,
@@ -35474,7 +35866,8 @@ pub const OnexCredentialProvider: windows_core::GUID = windows_core::GUID::from_
 pub const OnexPlapSmartcardCredentialProvider: windows_core::GUID = windows_core::GUID::from_u128(0x33c86cd6_705f_4ba1_9adb_67070b837775);
 pub const OpenControlPanel: windows_core::GUID = windows_core::GUID::from_u128(0x06622d85_6856_4460_8de1_a81921b41c4b);
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PAPPCONSTRAIN_REGISTRATION(pub isize);
 impl Default for PAPPCONSTRAIN_REGISTRATION {
     fn default() -> Self {
@@ -35485,7 +35878,8 @@ impl windows_core::TypeKind for PAPPCONSTRAIN_REGISTRATION {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PAPPSTATE_REGISTRATION(pub isize);
 impl Default for PAPPSTATE_REGISTRATION {
     fn default() -> Self {
@@ -35496,7 +35890,8 @@ impl windows_core::TypeKind for PAPPSTATE_REGISTRATION {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PARSEDURLA {
     pub cbSize: u32,
     pub pszProtocol: windows_core::PCSTR,
@@ -35514,7 +35909,8 @@ impl Default for PARSEDURLA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PARSEDURLW {
     pub cbSize: u32,
     pub pszProtocol: windows_core::PCWSTR,
@@ -35533,7 +35929,8 @@ impl Default for PARSEDURLW {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Shell_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PERSIST_FOLDER_TARGET_INFO {
     pub pidlTargetFolder: *mut Common::ITEMIDLIST,
     pub szTargetParsingName: [u16; 260],
@@ -35554,7 +35951,8 @@ impl Default for PERSIST_FOLDER_TARGET_INFO {
 pub const PINLogonCredentialProvider: windows_core::GUID = windows_core::GUID::from_u128(0xcb82ea12_9f71_446d_89e1_8d0924e1256e);
 #[repr(C)]
 #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PREVIEWHANDLERFRAMEINFO {
     pub haccel: super::WindowsAndMessaging::HACCEL,
     pub cAccelEntries: u32,
@@ -35570,7 +35968,8 @@ impl Default for PREVIEWHANDLERFRAMEINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROFILEINFOA {
     pub dwSize: u32,
     pub dwFlags: u32,
@@ -35590,7 +35989,8 @@ impl Default for PROFILEINFOA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROFILEINFOW {
     pub dwSize: u32,
     pub dwFlags: u32,
@@ -35610,7 +36010,8 @@ impl Default for PROFILEINFOW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PUBAPPINFO {
     pub cbSize: u32,
     pub dwMask: u32,
@@ -35636,7 +36037,8 @@ pub const PublishDropTarget: windows_core::GUID = windows_core::GUID::from_u128(
 pub const PublishingWizard: windows_core::GUID = windows_core::GUID::from_u128(0x6b33163c_76a5_4b6c_bf21_45de9cd503a1);
 #[repr(C)]
 #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct QCMINFO {
     pub hmenu: super::WindowsAndMessaging::HMENU,
     pub indexMenu: u32,
@@ -35655,7 +36057,8 @@ impl Default for QCMINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct QCMINFO_IDMAP {
     pub nMaxIds: u32,
     pub pIdList: [QCMINFO_IDMAP_PLACEMENT; 1],
@@ -35669,7 +36072,8 @@ impl Default for QCMINFO_IDMAP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct QCMINFO_IDMAP_PLACEMENT {
     pub id: u32,
     pub fFlags: u32,
@@ -35683,7 +36087,8 @@ impl Default for QCMINFO_IDMAP_PLACEMENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct QITAB {
     pub piid: *const windows_core::GUID,
     pub dwOffset: u32,
@@ -35699,7 +36104,8 @@ impl Default for QITAB {
 pub const QueryCancelAutoPlay: windows_core::GUID = windows_core::GUID::from_u128(0x331f1768_05a9_4ddd_b86e_dae34ddc998a);
 pub const RASProvider: windows_core::GUID = windows_core::GUID::from_u128(0x5537e283_b1e7_4ef8_9c6e_7ab0afe5056d);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SFVM_HELPTOPIC_DATA {
     pub wszHelpFile: [u16; 260],
     pub wszHelpTopic: [u16; 260],
@@ -35714,7 +36120,8 @@ impl Default for SFVM_HELPTOPIC_DATA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Controls")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SFVM_PROPPAGE_DATA {
     pub dwReserved: u32,
     pub pfn: super::Controls::LPFNSVADDPROPSHEETPAGE,
@@ -35732,7 +36139,8 @@ impl Default for SFVM_PROPPAGE_DATA {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Ole")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SFV_CREATE {
     pub cbSize: u32,
     pub pshf: core::mem::ManuallyDrop<Option<IShellFolder>>,
@@ -35757,7 +36165,8 @@ impl Default for SFV_CREATE {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Shell_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SFV_SETITEMPOS {
     pub pidl: *mut Common::ITEMIDLIST,
     pub pt: super::super::Foundation::POINT,
@@ -35816,7 +36225,8 @@ impl Default for SHARDAPPIDINFOLINK {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SHCOLUMNDATA {
     pub dwFlags: u32,
     pub dwFileAttributes: u32,
@@ -35855,7 +36265,8 @@ impl Default for SHCOLUMNINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SHCOLUMNINIT {
     pub dwFlags: u32,
     pub dwReserved: u32,
@@ -35999,7 +36410,8 @@ impl Default for SHChangeUpdateImageIDList {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SHDESCRIPTIONID {
     pub dwDescriptionId: u32,
     pub clsid: windows_core::GUID,
@@ -36014,7 +36426,8 @@ impl Default for SHDESCRIPTIONID {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_Graphics_Gdi")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SHDRAGIMAGE {
     pub sizeDragImage: super::super::Foundation::SIZE,
     pub ptOffset: super::super::Foundation::POINT,
@@ -36248,6 +36661,104 @@ impl Default for SHELLEXECUTEINFOW_0 {
 pub struct SHELLFLAGSTATE {
     pub _bitfield: i32,
 }
+impl SHELLFLAGSTATE {
+    pub const fn fShowAllObjects(&self) -> i32 {
+        self._bitfield & 1
+    }
+    pub fn set_fShowAllObjects(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !1) | (value & 1);
+    }
+    pub const fn fShowExtensions(&self) -> i32 {
+        (self._bitfield >> 1) & 1
+    }
+    pub fn set_fShowExtensions(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn fNoConfirmRecycle(&self) -> i32 {
+        (self._bitfield >> 2) & 1
+    }
+    pub fn set_fNoConfirmRecycle(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn fShowSysFiles(&self) -> i32 {
+        (self._bitfield >> 3) & 1
+    }
+    pub fn set_fShowSysFiles(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn fShowCompColor(&self) -> i32 {
+        (self._bitfield >> 4) & 1
+    }
+    pub fn set_fShowCompColor(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn fDoubleClickInWebView(&self) -> i32 {
+        (self._bitfield >> 5) & 1
+    }
+    pub fn set_fDoubleClickInWebView(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn fDesktopHTML(&self) -> i32 {
+        (self._bitfield >> 6) & 1
+    }
+    pub fn set_fDesktopHTML(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn fWin95Classic(&self) -> i32 {
+        (self._bitfield >> 7) & 1
+    }
+    pub fn set_fWin95Classic(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn fDontPrettyPath(&self) -> i32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_fDontPrettyPath(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn fShowAttribCol(&self) -> i32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_fShowAttribCol(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn fMapNetDrvBtn(&self) -> i32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_fMapNetDrvBtn(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn fShowInfoTip(&self) -> i32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_fShowInfoTip(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn fHideIcons(&self) -> i32 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_fHideIcons(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn fAutoCheckSelect(&self) -> i32 {
+        (self._bitfield >> 13) & 1
+    }
+    pub fn set_fAutoCheckSelect(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn fIconsOnly(&self) -> i32 {
+        (self._bitfield >> 14) & 1
+    }
+    pub fn set_fIconsOnly(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn fRestFlags(&self) -> i32 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_fRestFlags(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+}
 impl windows_core::TypeKind for SHELLFLAGSTATE {
     type TypeKind = windows_core::CopyType;
 }
@@ -36268,6 +36779,158 @@ pub struct SHELLSTATEA {
     pub uNotUsed: u32,
     pub _bitfield2: i32,
 }
+impl SHELLSTATEA {
+    pub const fn fShowAllObjects(&self) -> i32 {
+        self._bitfield1 & 1
+    }
+    pub fn set_fShowAllObjects(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !1) | (value & 1);
+    }
+    pub const fn fShowExtensions(&self) -> i32 {
+        (self._bitfield1 >> 1) & 1
+    }
+    pub fn set_fShowExtensions(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn fNoConfirmRecycle(&self) -> i32 {
+        (self._bitfield1 >> 2) & 1
+    }
+    pub fn set_fNoConfirmRecycle(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn fShowSysFiles(&self) -> i32 {
+        (self._bitfield1 >> 3) & 1
+    }
+    pub fn set_fShowSysFiles(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn fShowCompColor(&self) -> i32 {
+        (self._bitfield1 >> 4) & 1
+    }
+    pub fn set_fShowCompColor(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn fDoubleClickInWebView(&self) -> i32 {
+        (self._bitfield1 >> 5) & 1
+    }
+    pub fn set_fDoubleClickInWebView(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn fDesktopHTML(&self) -> i32 {
+        (self._bitfield1 >> 6) & 1
+    }
+    pub fn set_fDesktopHTML(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn fWin95Classic(&self) -> i32 {
+        (self._bitfield1 >> 7) & 1
+    }
+    pub fn set_fWin95Classic(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn fDontPrettyPath(&self) -> i32 {
+        (self._bitfield1 >> 8) & 1
+    }
+    pub fn set_fDontPrettyPath(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn fShowAttribCol(&self) -> i32 {
+        (self._bitfield1 >> 9) & 1
+    }
+    pub fn set_fShowAttribCol(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn fMapNetDrvBtn(&self) -> i32 {
+        (self._bitfield1 >> 10) & 1
+    }
+    pub fn set_fMapNetDrvBtn(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn fShowInfoTip(&self) -> i32 {
+        (self._bitfield1 >> 11) & 1
+    }
+    pub fn set_fShowInfoTip(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn fHideIcons(&self) -> i32 {
+        (self._bitfield1 >> 12) & 1
+    }
+    pub fn set_fHideIcons(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn fWebView(&self) -> i32 {
+        (self._bitfield1 >> 13) & 1
+    }
+    pub fn set_fWebView(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn fFilter(&self) -> i32 {
+        (self._bitfield1 >> 14) & 1
+    }
+    pub fn set_fFilter(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn fShowSuperHidden(&self) -> i32 {
+        (self._bitfield1 >> 15) & 1
+    }
+    pub fn set_fShowSuperHidden(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 15)) | ((value & 1) << 15);
+    }
+    pub const fn fNoNetCrawling(&self) -> i32 {
+        (self._bitfield1 >> 16) & 1
+    }
+    pub fn set_fNoNetCrawling(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn fSepProcess(&self) -> i32 {
+        self._bitfield2 & 1
+    }
+    pub fn set_fSepProcess(&mut self, value: i32) {
+        self._bitfield2 = (self._bitfield2 & !1) | (value & 1);
+    }
+    pub const fn fStartPanelOn(&self) -> i32 {
+        (self._bitfield2 >> 1) & 1
+    }
+    pub fn set_fStartPanelOn(&mut self, value: i32) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn fShowStartPage(&self) -> i32 {
+        (self._bitfield2 >> 2) & 1
+    }
+    pub fn set_fShowStartPage(&mut self, value: i32) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn fAutoCheckSelect(&self) -> i32 {
+        (self._bitfield2 >> 3) & 1
+    }
+    pub fn set_fAutoCheckSelect(&mut self, value: i32) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn fIconsOnly(&self) -> i32 {
+        (self._bitfield2 >> 4) & 1
+    }
+    pub fn set_fIconsOnly(&mut self, value: i32) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn fShowTypeOverlay(&self) -> i32 {
+        (self._bitfield2 >> 5) & 1
+    }
+    pub fn set_fShowTypeOverlay(&mut self, value: i32) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn fShowStatusBar(&self) -> i32 {
+        (self._bitfield2 >> 6) & 1
+    }
+    pub fn set_fShowStatusBar(&mut self, value: i32) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn fSpareFlags(&self) -> i32 {
+        (self._bitfield2 >> 7) & 511
+    }
+    pub fn set_fSpareFlags(&mut self, value: i32) {
+        self._bitfield2 = (self._bitfield2 & !(511 << 7)) | ((value & 511) << 7);
+    }
+}
 impl windows_core::TypeKind for SHELLSTATEA {
     type TypeKind = windows_core::CopyType;
 }
@@ -36288,6 +36951,158 @@ pub struct SHELLSTATEW {
     pub uNotUsed: u32,
     pub _bitfield2: i32,
 }
+impl SHELLSTATEW {
+    pub const fn fShowAllObjects(&self) -> i32 {
+        self._bitfield1 & 1
+    }
+    pub fn set_fShowAllObjects(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !1) | (value & 1);
+    }
+    pub const fn fShowExtensions(&self) -> i32 {
+        (self._bitfield1 >> 1) & 1
+    }
+    pub fn set_fShowExtensions(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn fNoConfirmRecycle(&self) -> i32 {
+        (self._bitfield1 >> 2) & 1
+    }
+    pub fn set_fNoConfirmRecycle(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn fShowSysFiles(&self) -> i32 {
+        (self._bitfield1 >> 3) & 1
+    }
+    pub fn set_fShowSysFiles(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn fShowCompColor(&self) -> i32 {
+        (self._bitfield1 >> 4) & 1
+    }
+    pub fn set_fShowCompColor(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn fDoubleClickInWebView(&self) -> i32 {
+        (self._bitfield1 >> 5) & 1
+    }
+    pub fn set_fDoubleClickInWebView(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn fDesktopHTML(&self) -> i32 {
+        (self._bitfield1 >> 6) & 1
+    }
+    pub fn set_fDesktopHTML(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn fWin95Classic(&self) -> i32 {
+        (self._bitfield1 >> 7) & 1
+    }
+    pub fn set_fWin95Classic(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 7)) | ((value & 1) << 7);
+    }
+    pub const fn fDontPrettyPath(&self) -> i32 {
+        (self._bitfield1 >> 8) & 1
+    }
+    pub fn set_fDontPrettyPath(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn fShowAttribCol(&self) -> i32 {
+        (self._bitfield1 >> 9) & 1
+    }
+    pub fn set_fShowAttribCol(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn fMapNetDrvBtn(&self) -> i32 {
+        (self._bitfield1 >> 10) & 1
+    }
+    pub fn set_fMapNetDrvBtn(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn fShowInfoTip(&self) -> i32 {
+        (self._bitfield1 >> 11) & 1
+    }
+    pub fn set_fShowInfoTip(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn fHideIcons(&self) -> i32 {
+        (self._bitfield1 >> 12) & 1
+    }
+    pub fn set_fHideIcons(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn fWebView(&self) -> i32 {
+        (self._bitfield1 >> 13) & 1
+    }
+    pub fn set_fWebView(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 13)) | ((value & 1) << 13);
+    }
+    pub const fn fFilter(&self) -> i32 {
+        (self._bitfield1 >> 14) & 1
+    }
+    pub fn set_fFilter(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 14)) | ((value & 1) << 14);
+    }
+    pub const fn fShowSuperHidden(&self) -> i32 {
+        (self._bitfield1 >> 15) & 1
+    }
+    pub fn set_fShowSuperHidden(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 15)) | ((value & 1) << 15);
+    }
+    pub const fn fNoNetCrawling(&self) -> i32 {
+        (self._bitfield1 >> 16) & 1
+    }
+    pub fn set_fNoNetCrawling(&mut self, value: i32) {
+        self._bitfield1 = (self._bitfield1 & !(1 << 16)) | ((value & 1) << 16);
+    }
+    pub const fn fSepProcess(&self) -> i32 {
+        self._bitfield2 & 1
+    }
+    pub fn set_fSepProcess(&mut self, value: i32) {
+        self._bitfield2 = (self._bitfield2 & !1) | (value & 1);
+    }
+    pub const fn fStartPanelOn(&self) -> i32 {
+        (self._bitfield2 >> 1) & 1
+    }
+    pub fn set_fStartPanelOn(&mut self, value: i32) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 1)) | ((value & 1) << 1);
+    }
+    pub const fn fShowStartPage(&self) -> i32 {
+        (self._bitfield2 >> 2) & 1
+    }
+    pub fn set_fShowStartPage(&mut self, value: i32) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 2)) | ((value & 1) << 2);
+    }
+    pub const fn fAutoCheckSelect(&self) -> i32 {
+        (self._bitfield2 >> 3) & 1
+    }
+    pub fn set_fAutoCheckSelect(&mut self, value: i32) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 3)) | ((value & 1) << 3);
+    }
+    pub const fn fIconsOnly(&self) -> i32 {
+        (self._bitfield2 >> 4) & 1
+    }
+    pub fn set_fIconsOnly(&mut self, value: i32) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 4)) | ((value & 1) << 4);
+    }
+    pub const fn fShowTypeOverlay(&self) -> i32 {
+        (self._bitfield2 >> 5) & 1
+    }
+    pub fn set_fShowTypeOverlay(&mut self, value: i32) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 5)) | ((value & 1) << 5);
+    }
+    pub const fn fShowStatusBar(&self) -> i32 {
+        (self._bitfield2 >> 6) & 1
+    }
+    pub fn set_fShowStatusBar(&mut self, value: i32) {
+        self._bitfield2 = (self._bitfield2 & !(1 << 6)) | ((value & 1) << 6);
+    }
+    pub const fn fSpareFlags(&self) -> i32 {
+        (self._bitfield2 >> 7) & 511
+    }
+    pub fn set_fSpareFlags(&mut self, value: i32) {
+        self._bitfield2 = (self._bitfield2 & !(511 << 7)) | ((value & 511) << 7);
+    }
+}
 impl windows_core::TypeKind for SHELLSTATEW {
     type TypeKind = windows_core::CopyType;
 }
@@ -36297,7 +37112,8 @@ impl Default for SHELLSTATEW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SHELL_ITEM_RESOURCE {
     pub guidType: windows_core::GUID,
     pub szName: [u16; 260],
@@ -36495,7 +37311,8 @@ impl Default for SHFILEOPSTRUCTW {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SHFOLDERCUSTOMSETTINGS {
     pub dwSize: u32,
     pub dwMask: u32,
@@ -36680,7 +37497,8 @@ impl Default for SHSTOCKICONINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SLOWAPPINFO {
     pub ullSize: u64,
     pub ftLastUsed: super::super::Foundation::FILETIME,
@@ -36697,7 +37515,8 @@ impl Default for SLOWAPPINFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Shell_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SMCSHCHANGENOTIFYSTRUCT {
     pub lEvent: i32,
     pub pidl1: *mut Common::ITEMIDLIST,
@@ -36715,7 +37534,8 @@ impl Default for SMCSHCHANGENOTIFYSTRUCT {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_UI_Shell_Common", feature = "Win32_UI_WindowsAndMessaging"))]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SMDATA {
     pub dwMask: u32,
     pub dwFlags: u32,
@@ -36747,7 +37567,8 @@ impl Default for SMDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SMINFO {
     pub dwMask: u32,
     pub dwType: u32,
@@ -36764,7 +37585,8 @@ impl Default for SMINFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Shell_PropertiesSystem")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SORTCOLUMN {
     pub propkey: PropertiesSystem::PROPERTYKEY,
     pub direction: SORTDIRECTION,
@@ -36781,7 +37603,8 @@ impl Default for SORTCOLUMN {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Ole")]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SV2CVW2_PARAMS {
     pub cbSize: u32,
     pub psvPrev: core::mem::ManuallyDrop<Option<IShellView>>,
@@ -36809,7 +37632,8 @@ impl Default for SV2CVW2_PARAMS {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYNCMGRHANDLERINFO {
     pub cbSize: u32,
     pub hIcon: super::WindowsAndMessaging::HICON,
@@ -36828,7 +37652,8 @@ impl Default for SYNCMGRHANDLERINFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYNCMGRITEM {
     pub cbSize: u32,
     pub dwFlags: u32,
@@ -36849,7 +37674,8 @@ impl Default for SYNCMGRITEM {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYNCMGRLOGERRORINFO {
     pub cbSize: u32,
     pub mask: u32,
@@ -36866,7 +37692,8 @@ impl Default for SYNCMGRLOGERRORINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYNCMGRPROGRESSITEM {
     pub cbSize: u32,
     pub mask: u32,
@@ -36885,7 +37712,8 @@ impl Default for SYNCMGRPROGRESSITEM {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_System_Com")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYNCMGR_CONFLICT_ID_INFO {
     pub pblobID: *mut super::super::System::Com::BYTE_BLOB,
     pub pblobExtra: *mut super::super::System::Com::BYTE_BLOB,
@@ -36938,7 +37766,8 @@ pub const SyncMgrScheduleWizard: windows_core::GUID = windows_core::GUID::from_u
 pub const SyncResultsFolder: windows_core::GUID = windows_core::GUID::from_u128(0x71d99464_3b6b_475c_b241_e15883207529);
 pub const SyncSetupFolder: windows_core::GUID = windows_core::GUID::from_u128(0x2e9e59c0_b437_4981_a647_9c34b9b90891);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TBINFO {
     pub cbuttons: u32,
     pub uFlags: u32,
@@ -36953,7 +37782,8 @@ impl Default for TBINFO {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct THUMBBUTTON {
     pub dwMask: THUMBBUTTONMASK,
     pub iId: u32,
@@ -36974,7 +37804,8 @@ impl Default for THUMBBUTTON {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Ole"))]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TOOLBARITEM {
     pub ptbar: core::mem::ManuallyDrop<Option<IDockingWindow>>,
     pub rcBorderTool: super::super::Foundation::RECT,
@@ -37005,7 +37836,8 @@ pub const TrackShellMenu: windows_core::GUID = windows_core::GUID::from_u128(0x8
 pub const TrayBandSiteService: windows_core::GUID = windows_core::GUID::from_u128(0xf60ad0a0_e5e1_45cb_b51a_e15b9f8b2934);
 pub const TrayDeskBand: windows_core::GUID = windows_core::GUID::from_u128(0xe6442437_6c68_4f52_94dd_2cfed267efb9);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct URLINVOKECOMMANDINFOA {
     pub dwcbSize: u32,
     pub dwFlags: u32,
@@ -37021,7 +37853,8 @@ impl Default for URLINVOKECOMMANDINFOA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct URLINVOKECOMMANDINFOW {
     pub dwcbSize: u32,
     pub dwFlags: u32,
@@ -37044,7 +37877,8 @@ pub const VaultProvider: windows_core::GUID = windows_core::GUID::from_u128(0x50
 pub const VirtualDesktopManager: windows_core::GUID = windows_core::GUID::from_u128(0xaa509086_5ca9_4c25_8f95_589d3c07b48a);
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Shell_Common")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WINDOWDATA {
     pub dwWindowID: u32,
     pub uiCP: u32,
@@ -37064,7 +37898,8 @@ impl Default for WINDOWDATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct WTS_THUMBNAILID {
     pub rgbKey: [u8; 16],
 }
@@ -37094,3 +37929,5 @@ pub type PFNSHOWSHAREFOLDERUIW = Option<unsafe extern "system" fn(hwndparent: su
 pub type SUBCLASSPROC = Option<unsafe extern "system" fn(hwnd: super::super::Foundation::HWND, umsg: u32, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM, uidsubclass: usize, dwrefdata: usize) -> super::super::Foundation::LRESULT>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
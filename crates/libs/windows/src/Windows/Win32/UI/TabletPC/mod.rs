@@ -7190,6 +7190,7 @@ pub struct ALT_BREAKS(pub i32);
 impl windows_core::TypeKind for ALT_BREAKS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ALT_BREAKS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ALT_BREAKS").field(&self.0).finish()
@@ -7201,6 +7202,7 @@ pub struct AppearanceConstants(pub i32);
 impl windows_core::TypeKind for AppearanceConstants {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for AppearanceConstants {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("AppearanceConstants").field(&self.0).finish()
@@ -7212,6 +7214,7 @@ pub struct BorderStyleConstants(pub i32);
 impl windows_core::TypeKind for BorderStyleConstants {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for BorderStyleConstants {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("BorderStyleConstants").field(&self.0).finish()
@@ -7223,6 +7226,7 @@ pub struct CONFIDENCE_LEVEL(pub i32);
 impl windows_core::TypeKind for CONFIDENCE_LEVEL {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CONFIDENCE_LEVEL {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CONFIDENCE_LEVEL").field(&self.0).finish()
@@ -7234,6 +7238,7 @@ pub struct CorrectionMode(pub i32);
 impl windows_core::TypeKind for CorrectionMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorrectionMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorrectionMode").field(&self.0).finish()
@@ -7245,6 +7250,7 @@ pub struct CorrectionPosition(pub i32);
 impl windows_core::TypeKind for CorrectionPosition {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for CorrectionPosition {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("CorrectionPosition").field(&self.0).finish()
@@ -7256,6 +7262,7 @@ pub struct DISPID_Ink(pub i32);
 impl windows_core::TypeKind for DISPID_Ink {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_Ink {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_Ink").field(&self.0).finish()
@@ -7267,6 +7274,7 @@ pub struct DISPID_InkCollector(pub i32);
 impl windows_core::TypeKind for DISPID_InkCollector {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkCollector {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkCollector").field(&self.0).finish()
@@ -7278,6 +7286,7 @@ pub struct DISPID_InkCollectorEvent(pub i32);
 impl windows_core::TypeKind for DISPID_InkCollectorEvent {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkCollectorEvent {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkCollectorEvent").field(&self.0).finish()
@@ -7289,6 +7298,7 @@ pub struct DISPID_InkCursor(pub i32);
 impl windows_core::TypeKind for DISPID_InkCursor {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkCursor {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkCursor").field(&self.0).finish()
@@ -7300,6 +7310,7 @@ pub struct DISPID_InkCursorButton(pub i32);
 impl windows_core::TypeKind for DISPID_InkCursorButton {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkCursorButton {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkCursorButton").field(&self.0).finish()
@@ -7311,6 +7322,7 @@ pub struct DISPID_InkCursorButtons(pub i32);
 impl windows_core::TypeKind for DISPID_InkCursorButtons {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkCursorButtons {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkCursorButtons").field(&self.0).finish()
@@ -7322,6 +7334,7 @@ pub struct DISPID_InkCursors(pub i32);
 impl windows_core::TypeKind for DISPID_InkCursors {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkCursors {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkCursors").field(&self.0).finish()
@@ -7333,6 +7346,7 @@ pub struct DISPID_InkCustomStrokes(pub i32);
 impl windows_core::TypeKind for DISPID_InkCustomStrokes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkCustomStrokes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkCustomStrokes").field(&self.0).finish()
@@ -7344,6 +7358,7 @@ pub struct DISPID_InkDivider(pub i32);
 impl windows_core::TypeKind for DISPID_InkDivider {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkDivider {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkDivider").field(&self.0).finish()
@@ -7355,6 +7370,7 @@ pub struct DISPID_InkDivisionResult(pub i32);
 impl windows_core::TypeKind for DISPID_InkDivisionResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkDivisionResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkDivisionResult").field(&self.0).finish()
@@ -7366,6 +7382,7 @@ pub struct DISPID_InkDivisionUnit(pub i32);
 impl windows_core::TypeKind for DISPID_InkDivisionUnit {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkDivisionUnit {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkDivisionUnit").field(&self.0).finish()
@@ -7377,6 +7394,7 @@ pub struct DISPID_InkDivisionUnits(pub i32);
 impl windows_core::TypeKind for DISPID_InkDivisionUnits {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkDivisionUnits {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkDivisionUnits").field(&self.0).finish()
@@ -7388,6 +7406,7 @@ pub struct DISPID_InkDrawingAttributes(pub i32);
 impl windows_core::TypeKind for DISPID_InkDrawingAttributes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkDrawingAttributes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkDrawingAttributes").field(&self.0).finish()
@@ -7399,6 +7418,7 @@ pub struct DISPID_InkEdit(pub i32);
 impl windows_core::TypeKind for DISPID_InkEdit {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkEdit {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkEdit").field(&self.0).finish()
@@ -7410,6 +7430,7 @@ pub struct DISPID_InkEditEvents(pub i32);
 impl windows_core::TypeKind for DISPID_InkEditEvents {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkEditEvents {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkEditEvents").field(&self.0).finish()
@@ -7421,6 +7442,7 @@ pub struct DISPID_InkEvent(pub i32);
 impl windows_core::TypeKind for DISPID_InkEvent {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkEvent {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkEvent").field(&self.0).finish()
@@ -7432,6 +7454,7 @@ pub struct DISPID_InkExtendedProperties(pub i32);
 impl windows_core::TypeKind for DISPID_InkExtendedProperties {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkExtendedProperties {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkExtendedProperties").field(&self.0).finish()
@@ -7443,6 +7466,7 @@ pub struct DISPID_InkExtendedProperty(pub i32);
 impl windows_core::TypeKind for DISPID_InkExtendedProperty {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkExtendedProperty {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkExtendedProperty").field(&self.0).finish()
@@ -7454,6 +7478,7 @@ pub struct DISPID_InkGesture(pub i32);
 impl windows_core::TypeKind for DISPID_InkGesture {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkGesture {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkGesture").field(&self.0).finish()
@@ -7465,6 +7490,7 @@ pub struct DISPID_InkRecoAlternate(pub i32);
 impl windows_core::TypeKind for DISPID_InkRecoAlternate {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkRecoAlternate {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkRecoAlternate").field(&self.0).finish()
@@ -7476,6 +7502,7 @@ pub struct DISPID_InkRecoContext(pub i32);
 impl windows_core::TypeKind for DISPID_InkRecoContext {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkRecoContext {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkRecoContext").field(&self.0).finish()
@@ -7487,6 +7514,7 @@ pub struct DISPID_InkRecoContext2(pub i32);
 impl windows_core::TypeKind for DISPID_InkRecoContext2 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkRecoContext2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkRecoContext2").field(&self.0).finish()
@@ -7498,6 +7526,7 @@ pub struct DISPID_InkRecognitionAlternates(pub i32);
 impl windows_core::TypeKind for DISPID_InkRecognitionAlternates {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkRecognitionAlternates {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkRecognitionAlternates").field(&self.0).finish()
@@ -7509,6 +7538,7 @@ pub struct DISPID_InkRecognitionEvent(pub i32);
 impl windows_core::TypeKind for DISPID_InkRecognitionEvent {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkRecognitionEvent {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkRecognitionEvent").field(&self.0).finish()
@@ -7520,6 +7550,7 @@ pub struct DISPID_InkRecognitionResult(pub i32);
 impl windows_core::TypeKind for DISPID_InkRecognitionResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkRecognitionResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkRecognitionResult").field(&self.0).finish()
@@ -7531,6 +7562,7 @@ pub struct DISPID_InkRecognizer(pub i32);
 impl windows_core::TypeKind for DISPID_InkRecognizer {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkRecognizer {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkRecognizer").field(&self.0).finish()
@@ -7542,6 +7574,7 @@ pub struct DISPID_InkRecognizer2(pub i32);
 impl windows_core::TypeKind for DISPID_InkRecognizer2 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkRecognizer2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkRecognizer2").field(&self.0).finish()
@@ -7553,6 +7586,7 @@ pub struct DISPID_InkRecognizerGuide(pub i32);
 impl windows_core::TypeKind for DISPID_InkRecognizerGuide {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkRecognizerGuide {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkRecognizerGuide").field(&self.0).finish()
@@ -7564,6 +7598,7 @@ pub struct DISPID_InkRecognizers(pub i32);
 impl windows_core::TypeKind for DISPID_InkRecognizers {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkRecognizers {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkRecognizers").field(&self.0).finish()
@@ -7575,6 +7610,7 @@ pub struct DISPID_InkRectangle(pub i32);
 impl windows_core::TypeKind for DISPID_InkRectangle {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkRectangle {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkRectangle").field(&self.0).finish()
@@ -7586,6 +7622,7 @@ pub struct DISPID_InkRenderer(pub i32);
 impl windows_core::TypeKind for DISPID_InkRenderer {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkRenderer {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkRenderer").field(&self.0).finish()
@@ -7597,6 +7634,7 @@ pub struct DISPID_InkStrokeDisp(pub i32);
 impl windows_core::TypeKind for DISPID_InkStrokeDisp {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkStrokeDisp {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkStrokeDisp").field(&self.0).finish()
@@ -7608,6 +7646,7 @@ pub struct DISPID_InkStrokes(pub i32);
 impl windows_core::TypeKind for DISPID_InkStrokes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkStrokes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkStrokes").field(&self.0).finish()
@@ -7619,6 +7658,7 @@ pub struct DISPID_InkTablet(pub i32);
 impl windows_core::TypeKind for DISPID_InkTablet {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkTablet {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkTablet").field(&self.0).finish()
@@ -7630,6 +7670,7 @@ pub struct DISPID_InkTablet2(pub i32);
 impl windows_core::TypeKind for DISPID_InkTablet2 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkTablet2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkTablet2").field(&self.0).finish()
@@ -7641,6 +7682,7 @@ pub struct DISPID_InkTablet3(pub i32);
 impl windows_core::TypeKind for DISPID_InkTablet3 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkTablet3 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkTablet3").field(&self.0).finish()
@@ -7652,6 +7694,7 @@ pub struct DISPID_InkTablets(pub i32);
 impl windows_core::TypeKind for DISPID_InkTablets {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkTablets {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkTablets").field(&self.0).finish()
@@ -7663,6 +7706,7 @@ pub struct DISPID_InkTransform(pub i32);
 impl windows_core::TypeKind for DISPID_InkTransform {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkTransform {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkTransform").field(&self.0).finish()
@@ -7674,6 +7718,7 @@ pub struct DISPID_InkWordList(pub i32);
 impl windows_core::TypeKind for DISPID_InkWordList {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkWordList {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkWordList").field(&self.0).finish()
@@ -7685,6 +7730,7 @@ pub struct DISPID_InkWordList2(pub i32);
 impl windows_core::TypeKind for DISPID_InkWordList2 {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_InkWordList2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_InkWordList2").field(&self.0).finish()
@@ -7696,6 +7742,7 @@ pub struct DISPID_MathInputControlEvents(pub i32);
 impl windows_core::TypeKind for DISPID_MathInputControlEvents {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_MathInputControlEvents {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_MathInputControlEvents").field(&self.0).finish()
@@ -7707,6 +7754,7 @@ pub struct DISPID_PenInputPanel(pub i32);
 impl windows_core::TypeKind for DISPID_PenInputPanel {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_PenInputPanel {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_PenInputPanel").field(&self.0).finish()
@@ -7718,6 +7766,7 @@ pub struct DISPID_PenInputPanelEvents(pub i32);
 impl windows_core::TypeKind for DISPID_PenInputPanelEvents {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_PenInputPanelEvents {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_PenInputPanelEvents").field(&self.0).finish()
@@ -7729,6 +7778,7 @@ pub struct DISPID_StrokeEvent(pub i32);
 impl windows_core::TypeKind for DISPID_StrokeEvent {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for DISPID_StrokeEvent {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("DISPID_StrokeEvent").field(&self.0).finish()
@@ -7740,6 +7790,7 @@ pub struct EventMask(pub i32);
 impl windows_core::TypeKind for EventMask {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for EventMask {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("EventMask").field(&self.0).finish()
@@ -7751,6 +7802,7 @@ pub struct FLICKACTION_COMMANDCODE(pub i32);
 impl windows_core::TypeKind for FLICKACTION_COMMANDCODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FLICKACTION_COMMANDCODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FLICKACTION_COMMANDCODE").field(&self.0).finish()
@@ -7762,6 +7814,7 @@ pub struct FLICKDIRECTION(pub i32);
 impl windows_core::TypeKind for FLICKDIRECTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FLICKDIRECTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FLICKDIRECTION").field(&self.0).finish()
@@ -7773,6 +7826,7 @@ pub struct FLICKMODE(pub i32);
 impl windows_core::TypeKind for FLICKMODE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FLICKMODE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FLICKMODE").field(&self.0).finish()
@@ -7784,6 +7838,7 @@ pub struct GET_DANDIDATE_FLAGS(pub i32);
 impl windows_core::TypeKind for GET_DANDIDATE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GET_DANDIDATE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GET_DANDIDATE_FLAGS").field(&self.0).finish()
@@ -7795,6 +7850,7 @@ pub struct INK_METRIC_FLAGS(pub i32);
 impl windows_core::TypeKind for INK_METRIC_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INK_METRIC_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INK_METRIC_FLAGS").field(&self.0).finish()
@@ -7806,6 +7862,7 @@ pub struct InPlaceDirection(pub i32);
 impl windows_core::TypeKind for InPlaceDirection {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InPlaceDirection {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InPlaceDirection").field(&self.0).finish()
@@ -7817,6 +7874,7 @@ pub struct InPlaceState(pub i32);
 impl windows_core::TypeKind for InPlaceState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InPlaceState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InPlaceState").field(&self.0).finish()
@@ -7828,6 +7886,7 @@ pub struct InkApplicationGesture(pub i32);
 impl windows_core::TypeKind for InkApplicationGesture {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkApplicationGesture {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkApplicationGesture").field(&self.0).finish()
@@ -7839,6 +7898,7 @@ pub struct InkBoundingBoxMode(pub i32);
 impl windows_core::TypeKind for InkBoundingBoxMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkBoundingBoxMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkBoundingBoxMode").field(&self.0).finish()
@@ -7850,6 +7910,7 @@ pub struct InkClipboardFormats(pub i32);
 impl windows_core::TypeKind for InkClipboardFormats {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkClipboardFormats {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkClipboardFormats").field(&self.0).finish()
@@ -7861,6 +7922,7 @@ pub struct InkClipboardModes(pub i32);
 impl windows_core::TypeKind for InkClipboardModes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkClipboardModes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkClipboardModes").field(&self.0).finish()
@@ -7872,6 +7934,7 @@ pub struct InkCollectionMode(pub i32);
 impl windows_core::TypeKind for InkCollectionMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkCollectionMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkCollectionMode").field(&self.0).finish()
@@ -7883,6 +7946,7 @@ pub struct InkCollectorEventInterest(pub i32);
 impl windows_core::TypeKind for InkCollectorEventInterest {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkCollectorEventInterest {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkCollectorEventInterest").field(&self.0).finish()
@@ -7894,6 +7958,7 @@ pub struct InkCursorButtonState(pub i32);
 impl windows_core::TypeKind for InkCursorButtonState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkCursorButtonState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkCursorButtonState").field(&self.0).finish()
@@ -7905,6 +7970,7 @@ pub struct InkDisplayMode(pub i32);
 impl windows_core::TypeKind for InkDisplayMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkDisplayMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkDisplayMode").field(&self.0).finish()
@@ -7916,6 +7982,7 @@ pub struct InkDivisionType(pub i32);
 impl windows_core::TypeKind for InkDivisionType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkDivisionType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkDivisionType").field(&self.0).finish()
@@ -7927,6 +7994,7 @@ pub struct InkEditStatus(pub i32);
 impl windows_core::TypeKind for InkEditStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkEditStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkEditStatus").field(&self.0).finish()
@@ -7938,6 +8006,7 @@ pub struct InkExtractFlags(pub i32);
 impl windows_core::TypeKind for InkExtractFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkExtractFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkExtractFlags").field(&self.0).finish()
@@ -7949,6 +8018,7 @@ pub struct InkInsertMode(pub i32);
 impl windows_core::TypeKind for InkInsertMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkInsertMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkInsertMode").field(&self.0).finish()
@@ -7960,6 +8030,7 @@ pub struct InkMode(pub i32);
 impl windows_core::TypeKind for InkMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkMode").field(&self.0).finish()
@@ -7971,6 +8042,7 @@ pub struct InkMouseButton(pub i32);
 impl windows_core::TypeKind for InkMouseButton {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkMouseButton {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkMouseButton").field(&self.0).finish()
@@ -7982,6 +8054,7 @@ pub struct InkMousePointer(pub i32);
 impl windows_core::TypeKind for InkMousePointer {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkMousePointer {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkMousePointer").field(&self.0).finish()
@@ -7993,6 +8066,7 @@ pub struct InkOverlayAttachMode(pub i32);
 impl windows_core::TypeKind for InkOverlayAttachMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkOverlayAttachMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkOverlayAttachMode").field(&self.0).finish()
@@ -8004,6 +8078,7 @@ pub struct InkOverlayEditingMode(pub i32);
 impl windows_core::TypeKind for InkOverlayEditingMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkOverlayEditingMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkOverlayEditingMode").field(&self.0).finish()
@@ -8015,6 +8090,7 @@ pub struct InkOverlayEraserMode(pub i32);
 impl windows_core::TypeKind for InkOverlayEraserMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkOverlayEraserMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkOverlayEraserMode").field(&self.0).finish()
@@ -8026,6 +8102,7 @@ pub struct InkPenTip(pub i32);
 impl windows_core::TypeKind for InkPenTip {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkPenTip {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkPenTip").field(&self.0).finish()
@@ -8037,6 +8114,7 @@ pub struct InkPersistenceCompressionMode(pub i32);
 impl windows_core::TypeKind for InkPersistenceCompressionMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkPersistenceCompressionMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkPersistenceCompressionMode").field(&self.0).finish()
@@ -8048,6 +8126,7 @@ pub struct InkPersistenceFormat(pub i32);
 impl windows_core::TypeKind for InkPersistenceFormat {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkPersistenceFormat {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkPersistenceFormat").field(&self.0).finish()
@@ -8059,6 +8138,7 @@ pub struct InkPictureSizeMode(pub i32);
 impl windows_core::TypeKind for InkPictureSizeMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkPictureSizeMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkPictureSizeMode").field(&self.0).finish()
@@ -8070,6 +8150,7 @@ pub struct InkRasterOperation(pub i32);
 impl windows_core::TypeKind for InkRasterOperation {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkRasterOperation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkRasterOperation").field(&self.0).finish()
@@ -8081,6 +8162,7 @@ pub struct InkRecognitionAlternatesSelection(pub i32);
 impl windows_core::TypeKind for InkRecognitionAlternatesSelection {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkRecognitionAlternatesSelection {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkRecognitionAlternatesSelection").field(&self.0).finish()
@@ -8092,6 +8174,7 @@ pub struct InkRecognitionConfidence(pub i32);
 impl windows_core::TypeKind for InkRecognitionConfidence {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkRecognitionConfidence {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkRecognitionConfidence").field(&self.0).finish()
@@ -8103,6 +8186,7 @@ pub struct InkRecognitionModes(pub i32);
 impl windows_core::TypeKind for InkRecognitionModes {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkRecognitionModes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkRecognitionModes").field(&self.0).finish()
@@ -8114,6 +8198,7 @@ pub struct InkRecognitionStatus(pub i32);
 impl windows_core::TypeKind for InkRecognitionStatus {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkRecognitionStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkRecognitionStatus").field(&self.0).finish()
@@ -8125,6 +8210,7 @@ pub struct InkRecognizerCapabilities(pub i32);
 impl windows_core::TypeKind for InkRecognizerCapabilities {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkRecognizerCapabilities {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkRecognizerCapabilities").field(&self.0).finish()
@@ -8136,6 +8222,7 @@ pub struct InkRecognizerCharacterAutoCompletionMode(pub i32);
 impl windows_core::TypeKind for InkRecognizerCharacterAutoCompletionMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkRecognizerCharacterAutoCompletionMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkRecognizerCharacterAutoCompletionMode").field(&self.0).finish()
@@ -8147,6 +8234,7 @@ pub struct InkSelectionConstants(pub i32);
 impl windows_core::TypeKind for InkSelectionConstants {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkSelectionConstants {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkSelectionConstants").field(&self.0).finish()
@@ -8158,6 +8246,7 @@ pub struct InkShiftKeyModifierFlags(pub i32);
 impl windows_core::TypeKind for InkShiftKeyModifierFlags {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkShiftKeyModifierFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkShiftKeyModifierFlags").field(&self.0).finish()
@@ -8169,6 +8258,7 @@ pub struct InkSystemGesture(pub i32);
 impl windows_core::TypeKind for InkSystemGesture {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InkSystemGesture {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InkSystemGesture").field(&self.0).finish()
@@ -8180,6 +8270,7 @@ pub struct InteractionMode(pub i32);
 impl windows_core::TypeKind for InteractionMode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InteractionMode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InteractionMode").field(&self.0).finish()
@@ -8191,6 +8282,7 @@ pub struct KEYMODIFIER(pub i32);
 impl windows_core::TypeKind for KEYMODIFIER {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for KEYMODIFIER {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("KEYMODIFIER").field(&self.0).finish()
@@ -8202,6 +8294,7 @@ pub struct LINE_METRICS(pub i32);
 impl windows_core::TypeKind for LINE_METRICS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LINE_METRICS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LINE_METRICS").field(&self.0).finish()
@@ -8213,6 +8306,7 @@ pub struct MICUIELEMENT(pub i32);
 impl windows_core::TypeKind for MICUIELEMENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MICUIELEMENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MICUIELEMENT").field(&self.0).finish()
@@ -8224,6 +8318,7 @@ pub struct MICUIELEMENTSTATE(pub i32);
 impl windows_core::TypeKind for MICUIELEMENTSTATE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MICUIELEMENTSTATE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MICUIELEMENTSTATE").field(&self.0).finish()
@@ -8235,6 +8330,7 @@ pub struct MouseButton(pub i32);
 impl windows_core::TypeKind for MouseButton {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MouseButton {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MouseButton").field(&self.0).finish()
@@ -8246,6 +8342,7 @@ pub struct PROPERTY_UNITS(pub i32);
 impl windows_core::TypeKind for PROPERTY_UNITS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PROPERTY_UNITS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PROPERTY_UNITS").field(&self.0).finish()
@@ -8257,6 +8354,7 @@ pub struct PanelInputArea(pub i32);
 impl windows_core::TypeKind for PanelInputArea {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PanelInputArea {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PanelInputArea").field(&self.0).finish()
@@ -8268,6 +8366,7 @@ pub struct PanelType(pub i32);
 impl windows_core::TypeKind for PanelType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for PanelType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("PanelType").field(&self.0).finish()
@@ -8279,6 +8378,7 @@ pub struct RECO_TYPE(pub i32);
 impl windows_core::TypeKind for RECO_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RECO_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RECO_TYPE").field(&self.0).finish()
@@ -8290,6 +8390,7 @@ pub struct RealTimeStylusDataInterest(pub i32);
 impl windows_core::TypeKind for RealTimeStylusDataInterest {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RealTimeStylusDataInterest {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RealTimeStylusDataInterest").field(&self.0).finish()
@@ -8301,6 +8402,7 @@ pub struct RealTimeStylusLockType(pub i32);
 impl windows_core::TypeKind for RealTimeStylusLockType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for RealTimeStylusLockType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("RealTimeStylusLockType").field(&self.0).finish()
@@ -8312,6 +8414,7 @@ pub struct SCROLLDIRECTION(pub i32);
 impl windows_core::TypeKind for SCROLLDIRECTION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCROLLDIRECTION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCROLLDIRECTION").field(&self.0).finish()
@@ -8323,6 +8426,7 @@ pub struct ScrollBarsConstants(pub i32);
 impl windows_core::TypeKind for ScrollBarsConstants {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ScrollBarsConstants {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ScrollBarsConstants").field(&self.0).finish()
@@ -8334,6 +8438,7 @@ pub struct SelAlignmentConstants(pub i32);
 impl windows_core::TypeKind for SelAlignmentConstants {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SelAlignmentConstants {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SelAlignmentConstants").field(&self.0).finish()
@@ -8345,6 +8450,7 @@ pub struct SelectionHitResult(pub i32);
 impl windows_core::TypeKind for SelectionHitResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SelectionHitResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SelectionHitResult").field(&self.0).finish()
@@ -8356,6 +8462,7 @@ pub struct StylusQueue(pub i32);
 impl windows_core::TypeKind for StylusQueue {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for StylusQueue {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("StylusQueue").field(&self.0).finish()
@@ -8367,6 +8474,7 @@ pub struct TabletDeviceKind(pub i32);
 impl windows_core::TypeKind for TabletDeviceKind {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TabletDeviceKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TabletDeviceKind").field(&self.0).finish()
@@ -8378,6 +8486,7 @@ pub struct TabletHardwareCapabilities(pub i32);
 impl windows_core::TypeKind for TabletHardwareCapabilities {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TabletHardwareCapabilities {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TabletHardwareCapabilities").field(&self.0).finish()
@@ -8389,6 +8498,7 @@ pub struct TabletPropertyMetricUnit(pub i32);
 impl windows_core::TypeKind for TabletPropertyMetricUnit {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TabletPropertyMetricUnit {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TabletPropertyMetricUnit").field(&self.0).finish()
@@ -8400,13 +8510,15 @@ pub struct VisualState(pub i32);
 impl windows_core::TypeKind for VisualState {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for VisualState {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("VisualState").field(&self.0).finish()
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct CHARACTER_RANGE {
     pub wcLow: u16,
     pub cChars: u16,
@@ -8420,7 +8532,8 @@ impl Default for CHARACTER_RANGE {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct DYNAMIC_RENDERER_CACHED_DATA {
     pub strokeId: i32,
     pub dynamicRenderer: core::mem::ManuallyDrop<Option<IDynamicRenderer>>,
@@ -8440,10 +8553,73 @@ impl Default for DYNAMIC_RENDERER_CACHED_DATA {
 }
 pub const DynamicRenderer: windows_core::GUID = windows_core::GUID::from_u128(0xecd32aea_746f_4dcb_bf68_082757faff18);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FLICK_DATA {
     pub _bitfield: i32,
 }
+impl FLICK_DATA {
+    pub const fn iFlickActionCommandCode(&self) -> i32 {
+        self._bitfield & 31
+    }
+    pub fn set_iFlickActionCommandCode(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !31) | (value & 31);
+    }
+    pub const fn iFlickDirection(&self) -> i32 {
+        (self._bitfield >> 5) & 7
+    }
+    pub fn set_iFlickDirection(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(7 << 5)) | ((value & 7) << 5);
+    }
+    pub const fn fControlModifier(&self) -> i32 {
+        (self._bitfield >> 8) & 1
+    }
+    pub fn set_fControlModifier(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 8)) | ((value & 1) << 8);
+    }
+    pub const fn fMenuModifier(&self) -> i32 {
+        (self._bitfield >> 9) & 1
+    }
+    pub fn set_fMenuModifier(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 9)) | ((value & 1) << 9);
+    }
+    pub const fn fAltGRModifier(&self) -> i32 {
+        (self._bitfield >> 10) & 1
+    }
+    pub fn set_fAltGRModifier(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 10)) | ((value & 1) << 10);
+    }
+    pub const fn fWinModifier(&self) -> i32 {
+        (self._bitfield >> 11) & 1
+    }
+    pub fn set_fWinModifier(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 11)) | ((value & 1) << 11);
+    }
+    pub const fn fShiftModifier(&self) -> i32 {
+        (self._bitfield >> 12) & 1
+    }
+    pub fn set_fShiftModifier(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 12)) | ((value & 1) << 12);
+    }
+    pub const fn iReserved(&self) -> i32 {
+        (self._bitfield >> 13) & 3
+    }
+    pub fn set_iReserved(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(3 << 13)) | ((value & 3) << 13);
+    }
+    pub const fn fOnInkingSurface(&self) -> i32 {
+        (self._bitfield >> 15) & 1
+    }
+    pub fn set_fOnInkingSurface(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(1 << 15)) | ((value & 1) << 15);
+    }
+    pub const fn iActionArgument(&self) -> i32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_iActionArgument(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for FLICK_DATA {
     type TypeKind = windows_core::CopyType;
 }
@@ -8453,10 +8629,25 @@ impl Default for FLICK_DATA {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct FLICK_POINT {
     pub _bitfield: i32,
 }
+impl FLICK_POINT {
+    pub const fn x(&self) -> i32 {
+        self._bitfield & 65535
+    }
+    pub fn set_x(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !65535) | (value & 65535);
+    }
+    pub const fn y(&self) -> i32 {
+        (self._bitfield >> 16) & 65535
+    }
+    pub fn set_y(&mut self, value: i32) {
+        self._bitfield = (self._bitfield & !(65535 << 16)) | ((value & 65535) << 16);
+    }
+}
 impl windows_core::TypeKind for FLICK_POINT {
     type TypeKind = windows_core::CopyType;
 }
@@ -8466,7 +8657,8 @@ impl Default for FLICK_POINT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct GESTURE_DATA {
     pub gestureId: i32,
     pub recoConfidence: i32,
@@ -8482,7 +8674,8 @@ impl Default for GESTURE_DATA {
 }
 pub const GestureRecognizer: windows_core::GUID = windows_core::GUID::from_u128(0xea30c654_c62c_441f_ac00_95f9a196782c);
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HRECOALT(pub *mut core::ffi::c_void);
 impl HRECOALT {
     pub fn is_invalid(&self) -> bool {
@@ -8498,7 +8691,8 @@ impl windows_core::TypeKind for HRECOALT {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HRECOCONTEXT(pub *mut core::ffi::c_void);
 impl HRECOCONTEXT {
     pub fn is_invalid(&self) -> bool {
@@ -8522,7 +8716,8 @@ impl windows_core::TypeKind for HRECOCONTEXT {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HRECOGNIZER(pub *mut core::ffi::c_void);
 impl HRECOGNIZER {
     pub fn is_invalid(&self) -> bool {
@@ -8546,7 +8741,8 @@ impl windows_core::TypeKind for HRECOGNIZER {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HRECOLATTICE(pub *mut core::ffi::c_void);
 impl HRECOLATTICE {
     pub fn is_invalid(&self) -> bool {
@@ -8562,7 +8758,8 @@ impl windows_core::TypeKind for HRECOLATTICE {
     type TypeKind = windows_core::CopyType;
 }
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct HRECOWORDLIST(pub *mut core::ffi::c_void);
 impl HRECOWORDLIST {
     pub fn is_invalid(&self) -> bool {
@@ -8588,7 +8785,8 @@ impl windows_core::TypeKind for HRECOWORDLIST {
 pub const HandwrittenTextInsertion: windows_core::GUID = windows_core::GUID::from_u128(0x9f074ee2_e6e9_4d8a_a047_eb5b5c3c55da);
 #[repr(C)]
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Controls"))]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IEC_GESTUREINFO {
     pub nmhdr: super::Controls::NMHDR,
     pub Cursor: core::mem::ManuallyDrop<Option<IInkCursor>>,
@@ -8613,7 +8811,8 @@ impl Default for IEC_GESTUREINFO {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Controls"))]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IEC_RECOGNITIONRESULTINFO {
     pub nmhdr: super::Controls::NMHDR,
     pub RecognitionResult: core::mem::ManuallyDrop<Option<IInkRecognitionResult>>,
@@ -8636,7 +8835,8 @@ impl Default for IEC_RECOGNITIONRESULTINFO {
 }
 #[repr(C)]
 #[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Controls"))]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IEC_STROKEINFO {
     pub nmhdr: super::Controls::NMHDR,
     pub Cursor: core::mem::ManuallyDrop<Option<IInkCursor>>,
@@ -8659,7 +8859,8 @@ impl Default for IEC_STROKEINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct INKMETRIC {
     pub iHeight: i32,
     pub iFontAscent: i32,
@@ -8684,7 +8885,8 @@ pub const InkEdit: windows_core::GUID = windows_core::GUID::from_u128(0xe5ca59f5
 pub const InkOverlay: windows_core::GUID = windows_core::GUID::from_u128(0x65d00646_cde3_4a88_9163_6769f0f1a97d);
 pub const InkPicture: windows_core::GUID = windows_core::GUID::from_u128(0x04a1e553_fe36_4fde_865e_344194e69424);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct InkRecoGuide {
     pub rectWritingBox: super::super::Foundation::RECT,
     pub rectDrawnBox: super::super::Foundation::RECT,
@@ -8710,7 +8912,8 @@ pub const InkTablets: windows_core::GUID = windows_core::GUID::from_u128(0x6e4fc
 pub const InkTransform: windows_core::GUID = windows_core::GUID::from_u128(0xe3d5d93c_1663_4a78_a1a7_22375dfebaee);
 pub const InkWordList: windows_core::GUID = windows_core::GUID::from_u128(0x9de85094_f71f_44f1_8471_15a2fa76fcf3);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LATTICE_METRICS {
     pub lsBaseline: LINE_SEGMENT,
     pub iMidlineOffset: i16,
@@ -8724,7 +8927,8 @@ impl Default for LATTICE_METRICS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct LINE_SEGMENT {
     pub PtA: super::super::Foundation::POINT,
     pub PtB: super::super::Foundation::POINT,
@@ -8739,7 +8943,8 @@ impl Default for LINE_SEGMENT {
 }
 pub const MathInputControl: windows_core::GUID = windows_core::GUID::from_u128(0xc561816c_14d8_4090_830c_98d994b21c7b);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PACKET_DESCRIPTION {
     pub cbPacketSize: u32,
     pub cPacketProperties: u32,
@@ -8756,7 +8961,8 @@ impl Default for PACKET_DESCRIPTION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PACKET_PROPERTY {
     pub guid: windows_core::GUID,
     pub PropertyMetrics: PROPERTY_METRICS,
@@ -8770,7 +8976,8 @@ impl Default for PACKET_PROPERTY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct PROPERTY_METRICS {
     pub nLogicalMin: i32,
     pub nLogicalMax: i32,
@@ -8788,7 +8995,8 @@ impl Default for PROPERTY_METRICS {
 pub const PenInputPanel: windows_core::GUID = windows_core::GUID::from_u128(0xf744e496_1b5a_489e_81dc_fbd7ac6298a8);
 pub const PenInputPanel_Internal: windows_core::GUID = windows_core::GUID::from_u128(0x802b1fb9_056b_4720_b0cc_80d23b71171e);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RECO_ATTRS {
     pub dwRecoCapabilityFlags: u32,
     pub awcVendorName: [u16; 32],
@@ -8804,7 +9012,8 @@ impl Default for RECO_ATTRS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RECO_GUIDE {
     pub xOrigin: i32,
     pub yOrigin: i32,
@@ -8825,7 +9034,8 @@ impl Default for RECO_GUIDE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RECO_LATTICE {
     pub ulColumnCount: u32,
     pub pLatticeColumns: *mut RECO_LATTICE_COLUMN,
@@ -8844,7 +9054,8 @@ impl Default for RECO_LATTICE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RECO_LATTICE_COLUMN {
     pub key: u32,
     pub cpProp: RECO_LATTICE_PROPERTIES,
@@ -8862,7 +9073,8 @@ impl Default for RECO_LATTICE_COLUMN {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RECO_LATTICE_ELEMENT {
     pub score: i32,
     pub r#type: u16,
@@ -8880,7 +9092,8 @@ impl Default for RECO_LATTICE_ELEMENT {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RECO_LATTICE_PROPERTIES {
     pub cProperties: u32,
     pub apProps: *mut *mut RECO_LATTICE_PROPERTY,
@@ -8894,7 +9107,8 @@ impl Default for RECO_LATTICE_PROPERTIES {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RECO_LATTICE_PROPERTY {
     pub guidProperty: windows_core::GUID,
     pub cbPropertyValue: u16,
@@ -8909,7 +9123,8 @@ impl Default for RECO_LATTICE_PROPERTY {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct RECO_RANGE {
     pub iwcBegin: u32,
     pub cCount: u32,
@@ -8924,7 +9139,8 @@ impl Default for RECO_RANGE {
 }
 pub const RealTimeStylus: windows_core::GUID = windows_core::GUID::from_u128(0xe26b366d_f998_43ce_836f_cb6d904432b0);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STROKE_RANGE {
     pub iStrokeBegin: u32,
     pub iStrokeEnd: u32,
@@ -8938,7 +9154,8 @@ impl Default for STROKE_RANGE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct SYSTEM_EVENT_DATA {
     pub bModifier: u8,
     pub wKey: u16,
@@ -8958,7 +9175,8 @@ impl Default for SYSTEM_EVENT_DATA {
 pub const SketchInk: windows_core::GUID = windows_core::GUID::from_u128(0xf0291081_e87c_4e07_97da_a0a03761e586);
 pub const StrokeBuilder: windows_core::GUID = windows_core::GUID::from_u128(0xe810cee7_6e51_4cb0_aa3a_0b985b70daf7);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct StylusInfo {
     pub tcid: u32,
     pub cid: u32,
@@ -8977,3 +9195,5 @@ pub const TipAutoCompleteClient: windows_core::GUID = windows_core::GUID::from_u
 pub type PfnRecoCallback = Option<unsafe extern "system" fn(param0: u32, param1: *mut u8, param2: HRECOCONTEXT) -> windows_core::HRESULT>;
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,4282 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDynamicRenderer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, benabled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicRenderer", "Enabled", 3usize, |this| ((**(this as *const *const IDynamicRenderer_Vtbl)).Enabled)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, benabled: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicRenderer", "SetEnabled", 4usize, |this| ((**(this as *const *const IDynamicRenderer_Vtbl)).SetEnabled)(this, benabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn HWND(this: *mut core::ffi::c_void, hwnd: *mut super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicRenderer", "HWND", 5usize, |this| ((**(this as *const *const IDynamicRenderer_Vtbl)).HWND)(this, hwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHWND(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicRenderer", "SetHWND", 6usize, |this| ((**(this as *const *const IDynamicRenderer_Vtbl)).SetHWND)(this, hwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClipRectangle(this: *mut core::ffi::c_void, prccliprect: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicRenderer", "ClipRectangle", 7usize, |this| ((**(this as *const *const IDynamicRenderer_Vtbl)).ClipRectangle)(this, prccliprect), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClipRectangle(this: *mut core::ffi::c_void, prccliprect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicRenderer", "SetClipRectangle", 8usize, |this| ((**(this as *const *const IDynamicRenderer_Vtbl)).SetClipRectangle)(this, prccliprect), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClipRegion(this: *mut core::ffi::c_void, phcliprgn: *mut super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicRenderer", "ClipRegion", 9usize, |this| ((**(this as *const *const IDynamicRenderer_Vtbl)).ClipRegion)(this, phcliprgn), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetClipRegion(this: *mut core::ffi::c_void, hcliprgn: super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicRenderer", "SetClipRegion", 10usize, |this| ((**(this as *const *const IDynamicRenderer_Vtbl)).SetClipRegion)(this, hcliprgn), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DrawingAttributes(this: *mut core::ffi::c_void, ppida: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicRenderer", "DrawingAttributes", 11usize, |this| ((**(this as *const *const IDynamicRenderer_Vtbl)).DrawingAttributes)(this, ppida), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_DrawingAttributes(this: *mut core::ffi::c_void, pida: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicRenderer", "putref_DrawingAttributes", 12usize, |this| ((**(this as *const *const IDynamicRenderer_Vtbl)).putref_DrawingAttributes)(this, pida), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataCacheEnabled(this: *mut core::ffi::c_void, pfcachedata: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicRenderer", "DataCacheEnabled", 13usize, |this| ((**(this as *const *const IDynamicRenderer_Vtbl)).DataCacheEnabled)(this, pfcachedata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDataCacheEnabled(this: *mut core::ffi::c_void, fcachedata: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicRenderer", "SetDataCacheEnabled", 14usize, |this| ((**(this as *const *const IDynamicRenderer_Vtbl)).SetDataCacheEnabled)(this, fcachedata), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseCachedData(this: *mut core::ffi::c_void, strokeid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicRenderer", "ReleaseCachedData", 15usize, |this| ((**(this as *const *const IDynamicRenderer_Vtbl)).ReleaseCachedData)(this, strokeid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicRenderer", "Refresh", 16usize, |this| ((**(this as *const *const IDynamicRenderer_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Draw(this: *mut core::ffi::c_void, hdc: super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDynamicRenderer", "Draw", 17usize, |this| ((**(this as *const *const IDynamicRenderer_Vtbl)).Draw)(this, hdc), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Enabled,
+            SetEnabled,
+            HWND,
+            SetHWND,
+            ClipRectangle,
+            SetClipRectangle,
+            ClipRegion,
+            SetClipRegion,
+            #[cfg(feature = "Win32_System_Com")]
+            DrawingAttributes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DrawingAttributes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_DrawingAttributes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_DrawingAttributes: 0,
+            DataCacheEnabled,
+            SetDataCacheEnabled,
+            ReleaseCachedData,
+            Refresh,
+            Draw,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDynamicRenderer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IGestureRecognizer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, pfenabled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "Enabled", 3usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).Enabled)(this, pfenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, fenabled: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "SetEnabled", 4usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).SetEnabled)(this, fenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxStrokeCount(this: *mut core::ffi::c_void, pcstrokes: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "MaxStrokeCount", 5usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).MaxStrokeCount)(this, pcstrokes), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxStrokeCount(this: *mut core::ffi::c_void, cstrokes: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "SetMaxStrokeCount", 6usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).SetMaxStrokeCount)(this, cstrokes), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableGestures(this: *mut core::ffi::c_void, cgestures: u32, pgestures: *const i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "EnableGestures", 7usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).EnableGestures)(this, cgestures, pgestures), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IGestureRecognizer", "Reset", 8usize, |this| ((**(this as *const *const IGestureRecognizer_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Enabled,
+            SetEnabled,
+            MaxStrokeCount,
+            SetMaxStrokeCount,
+            EnableGestures,
+            Reset,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IGestureRecognizer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IHandwrittenTextInsertion_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InsertRecognitionResultsArray(this: *mut core::ffi::c_void, psaalternates: *const super::super::System::Com::SAFEARRAY, locale: u32, falternatecontainsautospacinginformation: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHandwrittenTextInsertion", "InsertRecognitionResultsArray", 3usize, |this| ((**(this as *const *const IHandwrittenTextInsertion_Vtbl)).InsertRecognitionResultsArray)(this, psaalternates, locale, falternatecontainsautospacinginformation), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InsertInkRecognitionResult(this: *mut core::ffi::c_void, piinkrecoresult: *mut core::ffi::c_void, locale: u32, falternatecontainsautospacinginformation: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHandwrittenTextInsertion", "InsertInkRecognitionResult", 4usize, |this| ((**(this as *const *const IHandwrittenTextInsertion_Vtbl)).InsertInkRecognitionResult)(this, piinkrecoresult, locale, falternatecontainsautospacinginformation), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            InsertRecognitionResultsArray,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InsertRecognitionResultsArray: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            InsertInkRecognitionResult,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InsertInkRecognitionResult: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHandwrittenTextInsertion as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInk_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInk as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IInkCollector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn hWnd(this: *mut core::ffi::c_void, currentwindow: *mut isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "hWnd", 7usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).hWnd)(this, currentwindow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SethWnd(this: *mut core::ffi::c_void, newwindow: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "SethWnd", 8usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).SethWnd)(this, newwindow), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, collecting: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "Enabled", 9usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).Enabled)(this, collecting), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, collecting: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "SetEnabled", 10usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).SetEnabled)(this, collecting), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DefaultDrawingAttributes(this: *mut core::ffi::c_void, currentattributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "DefaultDrawingAttributes", 11usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).DefaultDrawingAttributes)(this, currentattributes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_DefaultDrawingAttributes(this: *mut core::ffi::c_void, newattributes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "putref_DefaultDrawingAttributes", 12usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).putref_DefaultDrawingAttributes)(this, newattributes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Renderer(this: *mut core::ffi::c_void, currentinkrenderer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "Renderer", 13usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).Renderer)(this, currentinkrenderer), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_Renderer(this: *mut core::ffi::c_void, newinkrenderer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "putref_Renderer", 14usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).putref_Renderer)(this, newinkrenderer), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Ink(this: *mut core::ffi::c_void, ink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "Ink", 15usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).Ink)(this, ink), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_Ink(this: *mut core::ffi::c_void, newink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "putref_Ink", 16usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).putref_Ink)(this, newink), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoRedraw(this: *mut core::ffi::c_void, autoredraw: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "AutoRedraw", 17usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).AutoRedraw)(this, autoredraw), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoRedraw(this: *mut core::ffi::c_void, autoredraw: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "SetAutoRedraw", 18usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).SetAutoRedraw)(this, autoredraw), |result| Some(*result))
+        }
+        unsafe extern "system" fn CollectingInk(this: *mut core::ffi::c_void, collecting: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "CollectingInk", 19usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).CollectingInk)(this, collecting), |result| Some(*result))
+        }
+        unsafe extern "system" fn CollectionMode(this: *mut core::ffi::c_void, mode: *mut InkCollectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "CollectionMode", 20usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).CollectionMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCollectionMode(this: *mut core::ffi::c_void, mode: InkCollectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "SetCollectionMode", 21usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).SetCollectionMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn DynamicRendering(this: *mut core::ffi::c_void, enabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "DynamicRendering", 22usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).DynamicRendering)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDynamicRendering(this: *mut core::ffi::c_void, enabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "SetDynamicRendering", 23usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).SetDynamicRendering)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredPacketDescription(this: *mut core::ffi::c_void, packetguids: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "DesiredPacketDescription", 24usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).DesiredPacketDescription)(this, packetguids), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredPacketDescription(this: *mut core::ffi::c_void, packetguids: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "SetDesiredPacketDescription", 25usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).SetDesiredPacketDescription)(this, packetguids), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+        unsafe extern "system" fn MouseIcon(this: *mut core::ffi::c_void, mouseicon: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "MouseIcon", 26usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).MouseIcon)(this, mouseicon), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+        unsafe extern "system" fn SetMouseIcon(this: *mut core::ffi::c_void, mouseicon: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "SetMouseIcon", 27usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).SetMouseIcon)(this, mouseicon), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+        unsafe extern "system" fn putref_MouseIcon(this: *mut core::ffi::c_void, mouseicon: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "putref_MouseIcon", 28usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).putref_MouseIcon)(this, mouseicon), |result| Some(*result))
+        }
+        unsafe extern "system" fn MousePointer(this: *mut core::ffi::c_void, mousepointer: *mut InkMousePointer) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "MousePointer", 29usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).MousePointer)(this, mousepointer), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMousePointer(this: *mut core::ffi::c_void, mousepointer: InkMousePointer) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "SetMousePointer", 30usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).SetMousePointer)(this, mousepointer), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Cursors(this: *mut core::ffi::c_void, cursors: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "Cursors", 31usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).Cursors)(this, cursors), |result| Some(*result))
+        }
+        unsafe extern "system" fn MarginX(this: *mut core::ffi::c_void, marginx: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "MarginX", 32usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).MarginX)(this, marginx), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMarginX(this: *mut core::ffi::c_void, marginx: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "SetMarginX", 33usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).SetMarginX)(this, marginx), |result| Some(*result))
+        }
+        unsafe extern "system" fn MarginY(this: *mut core::ffi::c_void, marginy: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "MarginY", 34usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).MarginY)(this, marginy), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMarginY(this: *mut core::ffi::c_void, marginy: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "SetMarginY", 35usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).SetMarginY)(this, marginy), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Tablet(this: *mut core::ffi::c_void, singletablet: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "Tablet", 36usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).Tablet)(this, singletablet), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportHighContrastInk(this: *mut core::ffi::c_void, support: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "SupportHighContrastInk", 37usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).SupportHighContrastInk)(this, support), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSupportHighContrastInk(this: *mut core::ffi::c_void, support: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "SetSupportHighContrastInk", 38usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).SetSupportHighContrastInk)(this, support), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGestureStatus(this: *mut core::ffi::c_void, gesture: InkApplicationGesture, listen: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "SetGestureStatus", 39usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).SetGestureStatus)(this, gesture, listen), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGestureStatus(this: *mut core::ffi::c_void, gesture: InkApplicationGesture, listening: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "GetGestureStatus", 40usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).GetGestureStatus)(this, gesture, listening), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetWindowInputRectangle(this: *mut core::ffi::c_void, windowinputrectangle: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "GetWindowInputRectangle", 41usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).GetWindowInputRectangle)(this, windowinputrectangle), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetWindowInputRectangle(this: *mut core::ffi::c_void, windowinputrectangle: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "SetWindowInputRectangle", 42usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).SetWindowInputRectangle)(this, windowinputrectangle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllTabletsMode(this: *mut core::ffi::c_void, usemouseforinput: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "SetAllTabletsMode", 43usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).SetAllTabletsMode)(this, usemouseforinput), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSingleTabletIntegratedMode(this: *mut core::ffi::c_void, tablet: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "SetSingleTabletIntegratedMode", 44usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).SetSingleTabletIntegratedMode)(this, tablet), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEventInterest(this: *mut core::ffi::c_void, eventid: InkCollectorEventInterest, listen: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "GetEventInterest", 45usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).GetEventInterest)(this, eventid, listen), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventInterest(this: *mut core::ffi::c_void, eventid: InkCollectorEventInterest, listen: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCollector", "SetEventInterest", 46usize, |this| ((**(this as *const *const IInkCollector_Vtbl)).SetEventInterest)(this, eventid, listen), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            hWnd,
+            SethWnd,
+            Enabled,
+            SetEnabled,
+            #[cfg(feature = "Win32_System_Com")]
+            DefaultDrawingAttributes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DefaultDrawingAttributes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_DefaultDrawingAttributes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_DefaultDrawingAttributes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Renderer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Renderer: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_Renderer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_Renderer: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Ink,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Ink: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_Ink,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_Ink: 0,
+            AutoRedraw,
+            SetAutoRedraw,
+            CollectingInk,
+            CollectionMode,
+            SetCollectionMode,
+            DynamicRendering,
+            SetDynamicRendering,
+            DesiredPacketDescription,
+            SetDesiredPacketDescription,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+            MouseIcon,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Ole")))]
+            MouseIcon: 0,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+            SetMouseIcon,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Ole")))]
+            SetMouseIcon: 0,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+            putref_MouseIcon,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Ole")))]
+            putref_MouseIcon: 0,
+            MousePointer,
+            SetMousePointer,
+            #[cfg(feature = "Win32_System_Com")]
+            Cursors,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Cursors: 0,
+            MarginX,
+            SetMarginX,
+            MarginY,
+            SetMarginY,
+            #[cfg(feature = "Win32_System_Com")]
+            Tablet,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Tablet: 0,
+            SupportHighContrastInk,
+            SetSupportHighContrastInk,
+            SetGestureStatus,
+            GetGestureStatus,
+            #[cfg(feature = "Win32_System_Com")]
+            GetWindowInputRectangle,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetWindowInputRectangle: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetWindowInputRectangle,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetWindowInputRectangle: 0,
+            SetAllTabletsMode,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSingleTabletIntegratedMode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSingleTabletIntegratedMode: 0,
+            GetEventInterest,
+            SetEventInterest,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkCollector as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkCursor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCursor", "Name", 7usize, |this| ((**(this as *const *const IInkCursor_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, id: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCursor", "Id", 8usize, |this| ((**(this as *const *const IInkCursor_Vtbl)).Id)(this, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn Inverted(this: *mut core::ffi::c_void, status: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCursor", "Inverted", 9usize, |this| ((**(this as *const *const IInkCursor_Vtbl)).Inverted)(this, status), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DrawingAttributes(this: *mut core::ffi::c_void, attributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCursor", "DrawingAttributes", 10usize, |this| ((**(this as *const *const IInkCursor_Vtbl)).DrawingAttributes)(this, attributes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_DrawingAttributes(this: *mut core::ffi::c_void, attributes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCursor", "putref_DrawingAttributes", 11usize, |this| ((**(this as *const *const IInkCursor_Vtbl)).putref_DrawingAttributes)(this, attributes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Tablet(this: *mut core::ffi::c_void, tablet: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCursor", "Tablet", 12usize, |this| ((**(this as *const *const IInkCursor_Vtbl)).Tablet)(this, tablet), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Buttons(this: *mut core::ffi::c_void, buttons: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCursor", "Buttons", 13usize, |this| ((**(this as *const *const IInkCursor_Vtbl)).Buttons)(this, buttons), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            Id,
+            Inverted,
+            #[cfg(feature = "Win32_System_Com")]
+            DrawingAttributes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DrawingAttributes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_DrawingAttributes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_DrawingAttributes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Tablet,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Tablet: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Buttons,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Buttons: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkCursor as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkCursorButton_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCursorButton", "Name", 7usize, |this| ((**(this as *const *const IInkCursorButton_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, id: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCursorButton", "Id", 8usize, |this| ((**(this as *const *const IInkCursorButton_Vtbl)).Id)(this, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn State(this: *mut core::ffi::c_void, currentstate: *mut InkCursorButtonState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCursorButton", "State", 9usize, |this| ((**(this as *const *const IInkCursorButton_Vtbl)).State)(this, currentstate), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Name, Id, State }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkCursorButton as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkCursorButtons_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCursorButtons", "Count", 7usize, |this| ((**(this as *const *const IInkCursorButtons_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, _newenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCursorButtons", "_NewEnum", 8usize, |this| ((**(this as *const *const IInkCursorButtons_Vtbl))._NewEnum)(this, _newenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, identifier: core::mem::MaybeUninit<windows_core::VARIANT>, button: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCursorButtons", "Item", 9usize, |this| ((**(this as *const *const IInkCursorButtons_Vtbl)).Item)(this, identifier, button), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkCursorButtons as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkCursors_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCursors", "Count", 7usize, |this| ((**(this as *const *const IInkCursors_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, _newenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCursors", "_NewEnum", 8usize, |this| ((**(this as *const *const IInkCursors_Vtbl))._NewEnum)(this, _newenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: i32, cursor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCursors", "Item", 9usize, |this| ((**(this as *const *const IInkCursors_Vtbl)).Item)(this, index, cursor), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkCursors as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkCustomStrokes_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCustomStrokes", "Count", 7usize, |this| ((**(this as *const *const IInkCustomStrokes_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, _newenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCustomStrokes", "_NewEnum", 8usize, |this| ((**(this as *const *const IInkCustomStrokes_Vtbl))._NewEnum)(this, _newenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, identifier: core::mem::MaybeUninit<windows_core::VARIANT>, strokes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCustomStrokes", "Item", 9usize, |this| ((**(this as *const *const IInkCustomStrokes_Vtbl)).Item)(this, identifier, strokes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, strokes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCustomStrokes", "Add", 10usize, |this| ((**(this as *const *const IInkCustomStrokes_Vtbl)).Add)(this, name, strokes), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, identifier: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCustomStrokes", "Remove", 11usize, |this| ((**(this as *const *const IInkCustomStrokes_Vtbl)).Remove)(this, identifier), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkCustomStrokes", "Clear", 12usize, |this| ((**(this as *const *const IInkCustomStrokes_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            Clear,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkCustomStrokes as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkDisp_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Strokes(this: *mut core::ffi::c_void, strokes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDisp", "Strokes", 7usize, |this| ((**(this as *const *const IInkDisp_Vtbl)).Strokes)(this, strokes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ExtendedProperties(this: *mut core::ffi::c_void, properties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDisp", "ExtendedProperties", 8usize, |this| ((**(this as *const *const IInkDisp_Vtbl)).ExtendedProperties)(this, properties), |result| Some(*result))
+        }
+        unsafe extern "system" fn Dirty(this: *mut core::ffi::c_void, dirty: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDisp", "Dirty", 9usize, |this| ((**(this as *const *const IInkDisp_Vtbl)).Dirty)(this, dirty), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDirty(this: *mut core::ffi::c_void, dirty: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDisp", "SetDirty", 10usize, |this| ((**(this as *const *const IInkDisp_Vtbl)).SetDirty)(this, dirty), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CustomStrokes(this: *mut core::ffi::c_void, ppunkinkcustomstrokes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDisp", "CustomStrokes", 11usize, |this| ((**(this as *const *const IInkDisp_Vtbl)).CustomStrokes)(this, ppunkinkcustomstrokes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetBoundingBox(this: *mut core::ffi::c_void, boundingboxmode: InkBoundingBoxMode, rectangle: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDisp", "GetBoundingBox", 12usize, |this| ((**(this as *const *const IInkDisp_Vtbl)).GetBoundingBox)(this, boundingboxmode, rectangle), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DeleteStrokes(this: *mut core::ffi::c_void, strokes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDisp", "DeleteStrokes", 13usize, |this| ((**(this as *const *const IInkDisp_Vtbl)).DeleteStrokes)(this, strokes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DeleteStroke(this: *mut core::ffi::c_void, stroke: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDisp", "DeleteStroke", 14usize, |this| ((**(this as *const *const IInkDisp_Vtbl)).DeleteStroke)(this, stroke), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ExtractStrokes(this: *mut core::ffi::c_void, strokes: *mut core::ffi::c_void, extractflags: InkExtractFlags, extractedink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDisp", "ExtractStrokes", 15usize, |this| ((**(this as *const *const IInkDisp_Vtbl)).ExtractStrokes)(this, strokes, extractflags, extractedink), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ExtractWithRectangle(this: *mut core::ffi::c_void, rectangle: *mut core::ffi::c_void, extractflags: InkExtractFlags, extractedink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDisp", "ExtractWithRectangle", 16usize, |this| ((**(this as *const *const IInkDisp_Vtbl)).ExtractWithRectangle)(this, rectangle, extractflags, extractedink), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Clip(this: *mut core::ffi::c_void, rectangle: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDisp", "Clip", 17usize, |this| ((**(this as *const *const IInkDisp_Vtbl)).Clip)(this, rectangle), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, newink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDisp", "Clone", 18usize, |this| ((**(this as *const *const IInkDisp_Vtbl)).Clone)(this, newink), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn HitTestCircle(this: *mut core::ffi::c_void, x: i32, y: i32, radius: f32, strokes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDisp", "HitTestCircle", 19usize, |this| ((**(this as *const *const IInkDisp_Vtbl)).HitTestCircle)(this, x, y, radius, strokes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn HitTestWithRectangle(this: *mut core::ffi::c_void, selectionrectangle: *mut core::ffi::c_void, intersectpercent: f32, strokes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDisp", "HitTestWithRectangle", 20usize, |this| ((**(this as *const *const IInkDisp_Vtbl)).HitTestWithRectangle)(this, selectionrectangle, intersectpercent, strokes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn HitTestWithLasso(this: *mut core::ffi::c_void, points: core::mem::MaybeUninit<windows_core::VARIANT>, intersectpercent: f32, lassopoints: *mut core::mem::MaybeUninit<windows_core::VARIANT>, strokes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDisp", "HitTestWithLasso", 21usize, |this| ((**(this as *const *const IInkDisp_Vtbl)).HitTestWithLasso)(this, points, intersectpercent, lassopoints, strokes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn NearestPoint(this: *mut core::ffi::c_void, x: i32, y: i32, pointonstroke: *mut f32, distancefrompacket: *mut f32, stroke: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDisp", "NearestPoint", 22usize, |this| ((**(this as *const *const IInkDisp_Vtbl)).NearestPoint)(this, x, y, pointonstroke, distancefrompacket, stroke), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateStrokes(this: *mut core::ffi::c_void, strokeids: core::mem::MaybeUninit<windows_core::VARIANT>, strokes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDisp", "CreateStrokes", 23usize, |this| ((**(this as *const *const IInkDisp_Vtbl)).CreateStrokes)(this, strokeids, strokes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddStrokesAtRectangle(this: *mut core::ffi::c_void, sourcestrokes: *mut core::ffi::c_void, targetrectangle: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDisp", "AddStrokesAtRectangle", 24usize, |this| ((**(this as *const *const IInkDisp_Vtbl)).AddStrokesAtRectangle)(this, sourcestrokes, targetrectangle), |result| Some(*result))
+        }
+        unsafe extern "system" fn Save(this: *mut core::ffi::c_void, persistenceformat: InkPersistenceFormat, compressionmode: InkPersistenceCompressionMode, data: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDisp", "Save", 25usize, |this| ((**(this as *const *const IInkDisp_Vtbl)).Save)(this, persistenceformat, compressionmode, data), |result| Some(*result))
+        }
+        unsafe extern "system" fn Load(this: *mut core::ffi::c_void, data: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDisp", "Load", 26usize, |this| ((**(this as *const *const IInkDisp_Vtbl)).Load)(this, data), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateStroke(this: *mut core::ffi::c_void, packetdata: core::mem::MaybeUninit<windows_core::VARIANT>, packetdescription: core::mem::MaybeUninit<windows_core::VARIANT>, stroke: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDisp", "CreateStroke", 27usize, |this| ((**(this as *const *const IInkDisp_Vtbl)).CreateStroke)(this, packetdata, packetdescription, stroke), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ClipboardCopyWithRectangle(this: *mut core::ffi::c_void, rectangle: *mut core::ffi::c_void, clipboardformats: InkClipboardFormats, clipboardmodes: InkClipboardModes, dataobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDisp", "ClipboardCopyWithRectangle", 28usize, |this| ((**(this as *const *const IInkDisp_Vtbl)).ClipboardCopyWithRectangle)(this, rectangle, clipboardformats, clipboardmodes, dataobject), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ClipboardCopy(this: *mut core::ffi::c_void, strokes: *mut core::ffi::c_void, clipboardformats: InkClipboardFormats, clipboardmodes: InkClipboardModes, dataobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDisp", "ClipboardCopy", 29usize, |this| ((**(this as *const *const IInkDisp_Vtbl)).ClipboardCopy)(this, strokes, clipboardformats, clipboardmodes, dataobject), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CanPaste(this: *mut core::ffi::c_void, dataobject: *mut core::ffi::c_void, canpaste: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDisp", "CanPaste", 30usize, |this| ((**(this as *const *const IInkDisp_Vtbl)).CanPaste)(this, dataobject, canpaste), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ClipboardPaste(this: *mut core::ffi::c_void, x: i32, y: i32, dataobject: *mut core::ffi::c_void, strokes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDisp", "ClipboardPaste", 31usize, |this| ((**(this as *const *const IInkDisp_Vtbl)).ClipboardPaste)(this, x, y, dataobject, strokes), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Strokes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Strokes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ExtendedProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ExtendedProperties: 0,
+            Dirty,
+            SetDirty,
+            #[cfg(feature = "Win32_System_Com")]
+            CustomStrokes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CustomStrokes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetBoundingBox,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetBoundingBox: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            DeleteStrokes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DeleteStrokes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            DeleteStroke,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DeleteStroke: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ExtractStrokes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ExtractStrokes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ExtractWithRectangle,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ExtractWithRectangle: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Clip,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Clip: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Clone,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Clone: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            HitTestCircle,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            HitTestCircle: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            HitTestWithRectangle,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            HitTestWithRectangle: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            HitTestWithLasso,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            HitTestWithLasso: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            NearestPoint,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            NearestPoint: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateStrokes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateStrokes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AddStrokesAtRectangle,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddStrokesAtRectangle: 0,
+            Save,
+            Load,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateStroke,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateStroke: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ClipboardCopyWithRectangle,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ClipboardCopyWithRectangle: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ClipboardCopy,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ClipboardCopy: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CanPaste,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CanPaste: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ClipboardPaste,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ClipboardPaste: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkDisp as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkDivider_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Strokes(this: *mut core::ffi::c_void, strokes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDivider", "Strokes", 7usize, |this| ((**(this as *const *const IInkDivider_Vtbl)).Strokes)(this, strokes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_Strokes(this: *mut core::ffi::c_void, strokes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDivider", "putref_Strokes", 8usize, |this| ((**(this as *const *const IInkDivider_Vtbl)).putref_Strokes)(this, strokes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RecognizerContext(this: *mut core::ffi::c_void, recognizercontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDivider", "RecognizerContext", 9usize, |this| ((**(this as *const *const IInkDivider_Vtbl)).RecognizerContext)(this, recognizercontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_RecognizerContext(this: *mut core::ffi::c_void, recognizercontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDivider", "putref_RecognizerContext", 10usize, |this| ((**(this as *const *const IInkDivider_Vtbl)).putref_RecognizerContext)(this, recognizercontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn LineHeight(this: *mut core::ffi::c_void, lineheight: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDivider", "LineHeight", 11usize, |this| ((**(this as *const *const IInkDivider_Vtbl)).LineHeight)(this, lineheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLineHeight(this: *mut core::ffi::c_void, lineheight: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDivider", "SetLineHeight", 12usize, |this| ((**(this as *const *const IInkDivider_Vtbl)).SetLineHeight)(this, lineheight), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Divide(this: *mut core::ffi::c_void, inkdivisionresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDivider", "Divide", 13usize, |this| ((**(this as *const *const IInkDivider_Vtbl)).Divide)(this, inkdivisionresult), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Strokes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Strokes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_Strokes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_Strokes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            RecognizerContext,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RecognizerContext: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_RecognizerContext,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_RecognizerContext: 0,
+            LineHeight,
+            SetLineHeight,
+            #[cfg(feature = "Win32_System_Com")]
+            Divide,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Divide: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkDivider as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkDivisionResult_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Strokes(this: *mut core::ffi::c_void, strokes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDivisionResult", "Strokes", 7usize, |this| ((**(this as *const *const IInkDivisionResult_Vtbl)).Strokes)(this, strokes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ResultByType(this: *mut core::ffi::c_void, divisiontype: InkDivisionType, inkdivisionunits: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDivisionResult", "ResultByType", 8usize, |this| ((**(this as *const *const IInkDivisionResult_Vtbl)).ResultByType)(this, divisiontype, inkdivisionunits), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Strokes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Strokes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ResultByType,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ResultByType: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkDivisionResult as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkDivisionUnit_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Strokes(this: *mut core::ffi::c_void, strokes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDivisionUnit", "Strokes", 7usize, |this| ((**(this as *const *const IInkDivisionUnit_Vtbl)).Strokes)(this, strokes), |result| Some(*result))
+        }
+        unsafe extern "system" fn DivisionType(this: *mut core::ffi::c_void, divisiontype: *mut InkDivisionType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDivisionUnit", "DivisionType", 8usize, |this| ((**(this as *const *const IInkDivisionUnit_Vtbl)).DivisionType)(this, divisiontype), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecognizedString(this: *mut core::ffi::c_void, recostring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDivisionUnit", "RecognizedString", 9usize, |this| ((**(this as *const *const IInkDivisionUnit_Vtbl)).RecognizedString)(this, recostring), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RotationTransform(this: *mut core::ffi::c_void, rotationtransform: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDivisionUnit", "RotationTransform", 10usize, |this| ((**(this as *const *const IInkDivisionUnit_Vtbl)).RotationTransform)(this, rotationtransform), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Strokes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Strokes: 0,
+            DivisionType,
+            RecognizedString,
+            #[cfg(feature = "Win32_System_Com")]
+            RotationTransform,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RotationTransform: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkDivisionUnit as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkDivisionUnits_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDivisionUnits", "Count", 7usize, |this| ((**(this as *const *const IInkDivisionUnits_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, _newenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDivisionUnits", "_NewEnum", 8usize, |this| ((**(this as *const *const IInkDivisionUnits_Vtbl))._NewEnum)(this, _newenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: i32, inkdivisionunit: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDivisionUnits", "Item", 9usize, |this| ((**(this as *const *const IInkDivisionUnits_Vtbl)).Item)(this, index, inkdivisionunit), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkDivisionUnits as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkDrawingAttributes_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Color(this: *mut core::ffi::c_void, currentcolor: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "Color", 7usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).Color)(this, currentcolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColor(this: *mut core::ffi::c_void, newcolor: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "SetColor", 8usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).SetColor)(this, newcolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn Width(this: *mut core::ffi::c_void, currentwidth: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "Width", 9usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).Width)(this, currentwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWidth(this: *mut core::ffi::c_void, newwidth: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "SetWidth", 10usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).SetWidth)(this, newwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn Height(this: *mut core::ffi::c_void, currentheight: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "Height", 11usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).Height)(this, currentheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHeight(this: *mut core::ffi::c_void, newheight: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "SetHeight", 12usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).SetHeight)(this, newheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn FitToCurve(this: *mut core::ffi::c_void, flag: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "FitToCurve", 13usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).FitToCurve)(this, flag), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFitToCurve(this: *mut core::ffi::c_void, flag: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "SetFitToCurve", 14usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).SetFitToCurve)(this, flag), |result| Some(*result))
+        }
+        unsafe extern "system" fn IgnorePressure(this: *mut core::ffi::c_void, flag: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "IgnorePressure", 15usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).IgnorePressure)(this, flag), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetIgnorePressure(this: *mut core::ffi::c_void, flag: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "SetIgnorePressure", 16usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).SetIgnorePressure)(this, flag), |result| Some(*result))
+        }
+        unsafe extern "system" fn AntiAliased(this: *mut core::ffi::c_void, flag: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "AntiAliased", 17usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).AntiAliased)(this, flag), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAntiAliased(this: *mut core::ffi::c_void, flag: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "SetAntiAliased", 18usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).SetAntiAliased)(this, flag), |result| Some(*result))
+        }
+        unsafe extern "system" fn Transparency(this: *mut core::ffi::c_void, currenttransparency: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "Transparency", 19usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).Transparency)(this, currenttransparency), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransparency(this: *mut core::ffi::c_void, newtransparency: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "SetTransparency", 20usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).SetTransparency)(this, newtransparency), |result| Some(*result))
+        }
+        unsafe extern "system" fn RasterOperation(this: *mut core::ffi::c_void, currentrasteroperation: *mut InkRasterOperation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "RasterOperation", 21usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).RasterOperation)(this, currentrasteroperation), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRasterOperation(this: *mut core::ffi::c_void, newrasteroperation: InkRasterOperation) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "SetRasterOperation", 22usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).SetRasterOperation)(this, newrasteroperation), |result| Some(*result))
+        }
+        unsafe extern "system" fn PenTip(this: *mut core::ffi::c_void, currentpentip: *mut InkPenTip) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "PenTip", 23usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).PenTip)(this, currentpentip), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPenTip(this: *mut core::ffi::c_void, newpentip: InkPenTip) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "SetPenTip", 24usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).SetPenTip)(this, newpentip), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ExtendedProperties(this: *mut core::ffi::c_void, properties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "ExtendedProperties", 25usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).ExtendedProperties)(this, properties), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, drawingattributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkDrawingAttributes", "Clone", 26usize, |this| ((**(this as *const *const IInkDrawingAttributes_Vtbl)).Clone)(this, drawingattributes), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Color,
+            SetColor,
+            Width,
+            SetWidth,
+            Height,
+            SetHeight,
+            FitToCurve,
+            SetFitToCurve,
+            IgnorePressure,
+            SetIgnorePressure,
+            AntiAliased,
+            SetAntiAliased,
+            Transparency,
+            SetTransparency,
+            RasterOperation,
+            SetRasterOperation,
+            PenTip,
+            SetPenTip,
+            #[cfg(feature = "Win32_System_Com")]
+            ExtendedProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ExtendedProperties: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Clone,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Clone: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkDrawingAttributes as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IInkEdit_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Status(this: *mut core::ffi::c_void, pstatus: *mut InkEditStatus) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "Status", 7usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).Status)(this, pstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn UseMouseForInput(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "UseMouseForInput", 8usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).UseMouseForInput)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUseMouseForInput(this: *mut core::ffi::c_void, newval: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetUseMouseForInput", 9usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetUseMouseForInput)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn InkMode(this: *mut core::ffi::c_void, pval: *mut InkMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "InkMode", 10usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).InkMode)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInkMode(this: *mut core::ffi::c_void, newval: InkMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetInkMode", 11usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetInkMode)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn InkInsertMode(this: *mut core::ffi::c_void, pval: *mut InkInsertMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "InkInsertMode", 12usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).InkInsertMode)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInkInsertMode(this: *mut core::ffi::c_void, newval: InkInsertMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetInkInsertMode", 13usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetInkInsertMode)(this, newval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DrawingAttributes(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "DrawingAttributes", 14usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).DrawingAttributes)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_DrawingAttributes(this: *mut core::ffi::c_void, newval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "putref_DrawingAttributes", 15usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).putref_DrawingAttributes)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecognitionTimeout(this: *mut core::ffi::c_void, pval: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "RecognitionTimeout", 16usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).RecognitionTimeout)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRecognitionTimeout(this: *mut core::ffi::c_void, newval: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetRecognitionTimeout", 17usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetRecognitionTimeout)(this, newval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Recognizer(this: *mut core::ffi::c_void, pval: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "Recognizer", 18usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).Recognizer)(this, pval), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_Recognizer(this: *mut core::ffi::c_void, newval: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "putref_Recognizer", 19usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).putref_Recognizer)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Factoid(this: *mut core::ffi::c_void, pval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "Factoid", 20usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).Factoid)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFactoid(this: *mut core::ffi::c_void, newval: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetFactoid", 21usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetFactoid)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelInks(this: *mut core::ffi::c_void, pselink: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SelInks", 22usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SelInks)(this, pselink), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelInks(this: *mut core::ffi::c_void, selink: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetSelInks", 23usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetSelInks)(this, selink), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelInksDisplayMode(this: *mut core::ffi::c_void, pinkdisplaymode: *mut InkDisplayMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SelInksDisplayMode", 24usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SelInksDisplayMode)(this, pinkdisplaymode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelInksDisplayMode(this: *mut core::ffi::c_void, inkdisplaymode: InkDisplayMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetSelInksDisplayMode", 25usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetSelInksDisplayMode)(this, inkdisplaymode), |result| Some(*result))
+        }
+        unsafe extern "system" fn Recognize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "Recognize", 26usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).Recognize)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGestureStatus(this: *mut core::ffi::c_void, gesture: InkApplicationGesture, plisten: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "GetGestureStatus", 27usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).GetGestureStatus)(this, gesture, plisten), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGestureStatus(this: *mut core::ffi::c_void, gesture: InkApplicationGesture, listen: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetGestureStatus", 28usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetGestureStatus)(this, gesture, listen), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackColor(this: *mut core::ffi::c_void, clr: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetBackColor", 29usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetBackColor)(this, clr), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackColor(this: *mut core::ffi::c_void, pclr: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "BackColor", 30usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).BackColor)(this, pclr), |result| Some(*result))
+        }
+        unsafe extern "system" fn Appearance(this: *mut core::ffi::c_void, pappearance: *mut AppearanceConstants) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "Appearance", 31usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).Appearance)(this, pappearance), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAppearance(this: *mut core::ffi::c_void, pappearance: AppearanceConstants) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetAppearance", 32usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetAppearance)(this, pappearance), |result| Some(*result))
+        }
+        unsafe extern "system" fn BorderStyle(this: *mut core::ffi::c_void, pborderstyle: *mut BorderStyleConstants) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "BorderStyle", 33usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).BorderStyle)(this, pborderstyle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBorderStyle(this: *mut core::ffi::c_void, pborderstyle: BorderStyleConstants) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetBorderStyle", 34usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetBorderStyle)(this, pborderstyle), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn Hwnd(this: *mut core::ffi::c_void, pohhwnd: *mut super::super::System::Ole::OLE_HANDLE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "Hwnd", 35usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).Hwnd)(this, pohhwnd), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+        unsafe extern "system" fn Font(this: *mut core::ffi::c_void, ppfont: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "Font", 36usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).Font)(this, ppfont), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+        unsafe extern "system" fn putref_Font(this: *mut core::ffi::c_void, ppfont: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "putref_Font", 37usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).putref_Font)(this, ppfont), |result| Some(*result))
+        }
+        unsafe extern "system" fn Text(this: *mut core::ffi::c_void, pbstrtext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "Text", 38usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).Text)(this, pbstrtext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetText(this: *mut core::ffi::c_void, pbstrtext: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetText", 39usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetText)(this, pbstrtext), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+        unsafe extern "system" fn MouseIcon(this: *mut core::ffi::c_void, mouseicon: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "MouseIcon", 40usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).MouseIcon)(this, mouseicon), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+        unsafe extern "system" fn SetMouseIcon(this: *mut core::ffi::c_void, mouseicon: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetMouseIcon", 41usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetMouseIcon)(this, mouseicon), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+        unsafe extern "system" fn putref_MouseIcon(this: *mut core::ffi::c_void, mouseicon: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "putref_MouseIcon", 42usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).putref_MouseIcon)(this, mouseicon), |result| Some(*result))
+        }
+        unsafe extern "system" fn MousePointer(this: *mut core::ffi::c_void, mousepointer: *mut InkMousePointer) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "MousePointer", 43usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).MousePointer)(this, mousepointer), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMousePointer(this: *mut core::ffi::c_void, mousepointer: InkMousePointer) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetMousePointer", 44usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetMousePointer)(this, mousepointer), |result| Some(*result))
+        }
+        unsafe extern "system" fn Locked(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "Locked", 45usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).Locked)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLocked(this: *mut core::ffi::c_void, newval: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetLocked", 46usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetLocked)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "Enabled", 47usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).Enabled)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, newval: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetEnabled", 48usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetEnabled)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaxLength(this: *mut core::ffi::c_void, plmaxlength: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "MaxLength", 49usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).MaxLength)(this, plmaxlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMaxLength(this: *mut core::ffi::c_void, lmaxlength: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetMaxLength", 50usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetMaxLength)(this, lmaxlength), |result| Some(*result))
+        }
+        unsafe extern "system" fn MultiLine(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "MultiLine", 51usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).MultiLine)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMultiLine(this: *mut core::ffi::c_void, newval: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetMultiLine", 52usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetMultiLine)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScrollBars(this: *mut core::ffi::c_void, pval: *mut ScrollBarsConstants) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "ScrollBars", 53usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).ScrollBars)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetScrollBars(this: *mut core::ffi::c_void, newval: ScrollBarsConstants) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetScrollBars", 54usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetScrollBars)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn DisableNoScroll(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "DisableNoScroll", 55usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).DisableNoScroll)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDisableNoScroll(this: *mut core::ffi::c_void, newval: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetDisableNoScroll", 56usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetDisableNoScroll)(this, newval), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelAlignment(this: *mut core::ffi::c_void, pvarselalignment: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SelAlignment", 57usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SelAlignment)(this, pvarselalignment), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelAlignment(this: *mut core::ffi::c_void, pvarselalignment: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetSelAlignment", 58usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetSelAlignment)(this, pvarselalignment), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelBold(this: *mut core::ffi::c_void, pvarselbold: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SelBold", 59usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SelBold)(this, pvarselbold), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelBold(this: *mut core::ffi::c_void, pvarselbold: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetSelBold", 60usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetSelBold)(this, pvarselbold), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelItalic(this: *mut core::ffi::c_void, pvarselitalic: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SelItalic", 61usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SelItalic)(this, pvarselitalic), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelItalic(this: *mut core::ffi::c_void, pvarselitalic: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetSelItalic", 62usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetSelItalic)(this, pvarselitalic), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelUnderline(this: *mut core::ffi::c_void, pvarselunderline: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SelUnderline", 63usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SelUnderline)(this, pvarselunderline), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelUnderline(this: *mut core::ffi::c_void, pvarselunderline: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetSelUnderline", 64usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetSelUnderline)(this, pvarselunderline), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelColor(this: *mut core::ffi::c_void, pvarselcolor: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SelColor", 65usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SelColor)(this, pvarselcolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelColor(this: *mut core::ffi::c_void, pvarselcolor: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetSelColor", 66usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetSelColor)(this, pvarselcolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelFontName(this: *mut core::ffi::c_void, pvarselfontname: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SelFontName", 67usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SelFontName)(this, pvarselfontname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelFontName(this: *mut core::ffi::c_void, pvarselfontname: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetSelFontName", 68usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetSelFontName)(this, pvarselfontname), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelFontSize(this: *mut core::ffi::c_void, pvarselfontsize: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SelFontSize", 69usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SelFontSize)(this, pvarselfontsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelFontSize(this: *mut core::ffi::c_void, pvarselfontsize: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetSelFontSize", 70usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetSelFontSize)(this, pvarselfontsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelCharOffset(this: *mut core::ffi::c_void, pvarselcharoffset: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SelCharOffset", 71usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SelCharOffset)(this, pvarselcharoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelCharOffset(this: *mut core::ffi::c_void, pvarselcharoffset: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetSelCharOffset", 72usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetSelCharOffset)(this, pvarselcharoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn TextRTF(this: *mut core::ffi::c_void, pbstrtextrtf: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "TextRTF", 73usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).TextRTF)(this, pbstrtextrtf), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTextRTF(this: *mut core::ffi::c_void, pbstrtextrtf: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetTextRTF", 74usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetTextRTF)(this, pbstrtextrtf), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelStart(this: *mut core::ffi::c_void, plselstart: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SelStart", 75usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SelStart)(this, plselstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelStart(this: *mut core::ffi::c_void, plselstart: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetSelStart", 76usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetSelStart)(this, plselstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelLength(this: *mut core::ffi::c_void, plsellength: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SelLength", 77usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SelLength)(this, plsellength), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelLength(this: *mut core::ffi::c_void, plsellength: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetSelLength", 78usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetSelLength)(this, plsellength), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelText(this: *mut core::ffi::c_void, pbstrseltext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SelText", 79usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SelText)(this, pbstrseltext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelText(this: *mut core::ffi::c_void, pbstrseltext: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetSelText", 80usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetSelText)(this, pbstrseltext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelRTF(this: *mut core::ffi::c_void, pbstrselrtf: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SelRTF", 81usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SelRTF)(this, pbstrselrtf), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelRTF(this: *mut core::ffi::c_void, pbstrselrtf: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "SetSelRTF", 82usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).SetSelRTF)(this, pbstrselrtf), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkEdit", "Refresh", 83usize, |this| ((**(this as *const *const IInkEdit_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Status,
+            UseMouseForInput,
+            SetUseMouseForInput,
+            InkMode,
+            SetInkMode,
+            InkInsertMode,
+            SetInkInsertMode,
+            #[cfg(feature = "Win32_System_Com")]
+            DrawingAttributes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DrawingAttributes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_DrawingAttributes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_DrawingAttributes: 0,
+            RecognitionTimeout,
+            SetRecognitionTimeout,
+            #[cfg(feature = "Win32_System_Com")]
+            Recognizer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Recognizer: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_Recognizer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_Recognizer: 0,
+            Factoid,
+            SetFactoid,
+            SelInks,
+            SetSelInks,
+            SelInksDisplayMode,
+            SetSelInksDisplayMode,
+            Recognize,
+            GetGestureStatus,
+            SetGestureStatus,
+            SetBackColor,
+            BackColor,
+            Appearance,
+            SetAppearance,
+            BorderStyle,
+            SetBorderStyle,
+            #[cfg(feature = "Win32_System_Ole")]
+            Hwnd,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            Hwnd: 0,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+            Font,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Ole")))]
+            Font: 0,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+            putref_Font,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Ole")))]
+            putref_Font: 0,
+            Text,
+            SetText,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+            MouseIcon,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Ole")))]
+            MouseIcon: 0,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+            SetMouseIcon,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Ole")))]
+            SetMouseIcon: 0,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+            putref_MouseIcon,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Ole")))]
+            putref_MouseIcon: 0,
+            MousePointer,
+            SetMousePointer,
+            Locked,
+            SetLocked,
+            Enabled,
+            SetEnabled,
+            MaxLength,
+            SetMaxLength,
+            MultiLine,
+            SetMultiLine,
+            ScrollBars,
+            SetScrollBars,
+            DisableNoScroll,
+            SetDisableNoScroll,
+            SelAlignment,
+            SetSelAlignment,
+            SelBold,
+            SetSelBold,
+            SelItalic,
+            SetSelItalic,
+            SelUnderline,
+            SetSelUnderline,
+            SelColor,
+            SetSelColor,
+            SelFontName,
+            SetSelFontName,
+            SelFontSize,
+            SetSelFontSize,
+            SelCharOffset,
+            SetSelCharOffset,
+            TextRTF,
+            SetTextRTF,
+            SelStart,
+            SetSelStart,
+            SelLength,
+            SetSelLength,
+            SelText,
+            SetSelText,
+            SelRTF,
+            SetSelRTF,
+            Refresh,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkEdit as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkExtendedProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkExtendedProperties", "Count", 7usize, |this| ((**(this as *const *const IInkExtendedProperties_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, _newenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkExtendedProperties", "_NewEnum", 8usize, |this| ((**(this as *const *const IInkExtendedProperties_Vtbl))._NewEnum)(this, _newenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, identifier: core::mem::MaybeUninit<windows_core::VARIANT>, item: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkExtendedProperties", "Item", 9usize, |this| ((**(this as *const *const IInkExtendedProperties_Vtbl)).Item)(this, identifier, item), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, guid: core::mem::MaybeUninit<windows_core::BSTR>, data: core::mem::MaybeUninit<windows_core::VARIANT>, inkextendedproperty: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkExtendedProperties", "Add", 10usize, |this| ((**(this as *const *const IInkExtendedProperties_Vtbl)).Add)(this, guid, data, inkextendedproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, identifier: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkExtendedProperties", "Remove", 11usize, |this| ((**(this as *const *const IInkExtendedProperties_Vtbl)).Remove)(this, identifier), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkExtendedProperties", "Clear", 12usize, |this| ((**(this as *const *const IInkExtendedProperties_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn DoesPropertyExist(this: *mut core::ffi::c_void, guid: core::mem::MaybeUninit<windows_core::BSTR>, doespropertyexist: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkExtendedProperties", "DoesPropertyExist", 13usize, |this| ((**(this as *const *const IInkExtendedProperties_Vtbl)).DoesPropertyExist)(this, guid, doespropertyexist), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            Remove,
+            Clear,
+            DoesPropertyExist,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkExtendedProperties as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkExtendedProperty_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Guid(this: *mut core::ffi::c_void, guid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkExtendedProperty", "Guid", 7usize, |this| ((**(this as *const *const IInkExtendedProperty_Vtbl)).Guid)(this, guid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, data: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkExtendedProperty", "Data", 8usize, |this| ((**(this as *const *const IInkExtendedProperty_Vtbl)).Data)(this, data), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, data: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkExtendedProperty", "SetData", 9usize, |this| ((**(this as *const *const IInkExtendedProperty_Vtbl)).SetData)(this, data), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Guid, Data, SetData }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkExtendedProperty as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkGesture_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Confidence(this: *mut core::ffi::c_void, confidence: *mut InkRecognitionConfidence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkGesture", "Confidence", 7usize, |this| ((**(this as *const *const IInkGesture_Vtbl)).Confidence)(this, confidence), |result| Some(*result))
+        }
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, id: *mut InkApplicationGesture) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkGesture", "Id", 8usize, |this| ((**(this as *const *const IInkGesture_Vtbl)).Id)(this, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHotPoint(this: *mut core::ffi::c_void, x: *mut i32, y: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkGesture", "GetHotPoint", 9usize, |this| ((**(this as *const *const IInkGesture_Vtbl)).GetHotPoint)(this, x, y), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Confidence, Id, GetHotPoint }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkGesture as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInkLineInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetFormat(this: *mut core::ffi::c_void, pim: *const INKMETRIC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkLineInfo", "SetFormat", 3usize, |this| ((**(this as *const *const IInkLineInfo_Vtbl)).SetFormat)(this, pim), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFormat(this: *mut core::ffi::c_void, pim: *const INKMETRIC) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkLineInfo", "GetFormat", 4usize, |this| ((**(this as *const *const IInkLineInfo_Vtbl)).GetFormat)(this, pim), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInkExtent(this: *mut core::ffi::c_void, pim: *const INKMETRIC, pnwidth: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkLineInfo", "GetInkExtent", 5usize, |this| ((**(this as *const *const IInkLineInfo_Vtbl)).GetInkExtent)(this, pim, pnwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCandidate(this: *mut core::ffi::c_void, ncandidatenum: u32, pwcrecogword: windows_core::PCWSTR, pcwcrecogword: *const u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkLineInfo", "GetCandidate", 6usize, |this| ((**(this as *const *const IInkLineInfo_Vtbl)).GetCandidate)(this, ncandidatenum, pwcrecogword, pcwcrecogword, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCandidate(this: *mut core::ffi::c_void, ncandidatenum: u32, strrecogword: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkLineInfo", "SetCandidate", 7usize, |this| ((**(this as *const *const IInkLineInfo_Vtbl)).SetCandidate)(this, ncandidatenum, strrecogword), |result| Some(*result))
+        }
+        unsafe extern "system" fn Recognize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkLineInfo", "Recognize", 8usize, |this| ((**(this as *const *const IInkLineInfo_Vtbl)).Recognize)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetFormat,
+            GetFormat,
+            GetInkExtent,
+            GetCandidate,
+            SetCandidate,
+            Recognize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkLineInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IInkOverlay_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn hWnd(this: *mut core::ffi::c_void, currentwindow: *mut isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "hWnd", 7usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).hWnd)(this, currentwindow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SethWnd(this: *mut core::ffi::c_void, newwindow: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "SethWnd", 8usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).SethWnd)(this, newwindow), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, collecting: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "Enabled", 9usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).Enabled)(this, collecting), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, collecting: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "SetEnabled", 10usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).SetEnabled)(this, collecting), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DefaultDrawingAttributes(this: *mut core::ffi::c_void, currentattributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "DefaultDrawingAttributes", 11usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).DefaultDrawingAttributes)(this, currentattributes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_DefaultDrawingAttributes(this: *mut core::ffi::c_void, newattributes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "putref_DefaultDrawingAttributes", 12usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).putref_DefaultDrawingAttributes)(this, newattributes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Renderer(this: *mut core::ffi::c_void, currentinkrenderer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "Renderer", 13usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).Renderer)(this, currentinkrenderer), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_Renderer(this: *mut core::ffi::c_void, newinkrenderer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "putref_Renderer", 14usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).putref_Renderer)(this, newinkrenderer), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Ink(this: *mut core::ffi::c_void, ink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "Ink", 15usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).Ink)(this, ink), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_Ink(this: *mut core::ffi::c_void, newink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "putref_Ink", 16usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).putref_Ink)(this, newink), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoRedraw(this: *mut core::ffi::c_void, autoredraw: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "AutoRedraw", 17usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).AutoRedraw)(this, autoredraw), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoRedraw(this: *mut core::ffi::c_void, autoredraw: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "SetAutoRedraw", 18usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).SetAutoRedraw)(this, autoredraw), |result| Some(*result))
+        }
+        unsafe extern "system" fn CollectingInk(this: *mut core::ffi::c_void, collecting: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "CollectingInk", 19usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).CollectingInk)(this, collecting), |result| Some(*result))
+        }
+        unsafe extern "system" fn CollectionMode(this: *mut core::ffi::c_void, mode: *mut InkCollectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "CollectionMode", 20usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).CollectionMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCollectionMode(this: *mut core::ffi::c_void, mode: InkCollectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "SetCollectionMode", 21usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).SetCollectionMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn DynamicRendering(this: *mut core::ffi::c_void, enabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "DynamicRendering", 22usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).DynamicRendering)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDynamicRendering(this: *mut core::ffi::c_void, enabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "SetDynamicRendering", 23usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).SetDynamicRendering)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredPacketDescription(this: *mut core::ffi::c_void, packetguids: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "DesiredPacketDescription", 24usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).DesiredPacketDescription)(this, packetguids), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredPacketDescription(this: *mut core::ffi::c_void, packetguids: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "SetDesiredPacketDescription", 25usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).SetDesiredPacketDescription)(this, packetguids), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+        unsafe extern "system" fn MouseIcon(this: *mut core::ffi::c_void, mouseicon: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "MouseIcon", 26usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).MouseIcon)(this, mouseicon), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+        unsafe extern "system" fn SetMouseIcon(this: *mut core::ffi::c_void, mouseicon: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "SetMouseIcon", 27usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).SetMouseIcon)(this, mouseicon), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+        unsafe extern "system" fn putref_MouseIcon(this: *mut core::ffi::c_void, mouseicon: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "putref_MouseIcon", 28usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).putref_MouseIcon)(this, mouseicon), |result| Some(*result))
+        }
+        unsafe extern "system" fn MousePointer(this: *mut core::ffi::c_void, mousepointer: *mut InkMousePointer) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "MousePointer", 29usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).MousePointer)(this, mousepointer), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMousePointer(this: *mut core::ffi::c_void, mousepointer: InkMousePointer) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "SetMousePointer", 30usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).SetMousePointer)(this, mousepointer), |result| Some(*result))
+        }
+        unsafe extern "system" fn EditingMode(this: *mut core::ffi::c_void, editingmode: *mut InkOverlayEditingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "EditingMode", 31usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).EditingMode)(this, editingmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEditingMode(this: *mut core::ffi::c_void, editingmode: InkOverlayEditingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "SetEditingMode", 32usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).SetEditingMode)(this, editingmode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Selection(this: *mut core::ffi::c_void, selection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "Selection", 33usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).Selection)(this, selection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSelection(this: *mut core::ffi::c_void, selection: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "SetSelection", 34usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).SetSelection)(this, selection), |result| Some(*result))
+        }
+        unsafe extern "system" fn EraserMode(this: *mut core::ffi::c_void, erasermode: *mut InkOverlayEraserMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "EraserMode", 35usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).EraserMode)(this, erasermode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEraserMode(this: *mut core::ffi::c_void, erasermode: InkOverlayEraserMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "SetEraserMode", 36usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).SetEraserMode)(this, erasermode), |result| Some(*result))
+        }
+        unsafe extern "system" fn EraserWidth(this: *mut core::ffi::c_void, eraserwidth: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "EraserWidth", 37usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).EraserWidth)(this, eraserwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEraserWidth(this: *mut core::ffi::c_void, neweraserwidth: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "SetEraserWidth", 38usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).SetEraserWidth)(this, neweraserwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttachMode(this: *mut core::ffi::c_void, attachmode: *mut InkOverlayAttachMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "AttachMode", 39usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).AttachMode)(this, attachmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAttachMode(this: *mut core::ffi::c_void, attachmode: InkOverlayAttachMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "SetAttachMode", 40usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).SetAttachMode)(this, attachmode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Cursors(this: *mut core::ffi::c_void, cursors: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "Cursors", 41usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).Cursors)(this, cursors), |result| Some(*result))
+        }
+        unsafe extern "system" fn MarginX(this: *mut core::ffi::c_void, marginx: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "MarginX", 42usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).MarginX)(this, marginx), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMarginX(this: *mut core::ffi::c_void, marginx: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "SetMarginX", 43usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).SetMarginX)(this, marginx), |result| Some(*result))
+        }
+        unsafe extern "system" fn MarginY(this: *mut core::ffi::c_void, marginy: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "MarginY", 44usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).MarginY)(this, marginy), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMarginY(this: *mut core::ffi::c_void, marginy: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "SetMarginY", 45usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).SetMarginY)(this, marginy), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Tablet(this: *mut core::ffi::c_void, singletablet: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "Tablet", 46usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).Tablet)(this, singletablet), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportHighContrastInk(this: *mut core::ffi::c_void, support: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "SupportHighContrastInk", 47usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).SupportHighContrastInk)(this, support), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSupportHighContrastInk(this: *mut core::ffi::c_void, support: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "SetSupportHighContrastInk", 48usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).SetSupportHighContrastInk)(this, support), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportHighContrastSelectionUI(this: *mut core::ffi::c_void, support: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "SupportHighContrastSelectionUI", 49usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).SupportHighContrastSelectionUI)(this, support), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSupportHighContrastSelectionUI(this: *mut core::ffi::c_void, support: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "SetSupportHighContrastSelectionUI", 50usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).SetSupportHighContrastSelectionUI)(this, support), |result| Some(*result))
+        }
+        unsafe extern "system" fn HitTestSelection(this: *mut core::ffi::c_void, x: i32, y: i32, selarea: *mut SelectionHitResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "HitTestSelection", 51usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).HitTestSelection)(this, x, y, selarea), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Draw(this: *mut core::ffi::c_void, rect: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "Draw", 52usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).Draw)(this, rect), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGestureStatus(this: *mut core::ffi::c_void, gesture: InkApplicationGesture, listen: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "SetGestureStatus", 53usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).SetGestureStatus)(this, gesture, listen), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGestureStatus(this: *mut core::ffi::c_void, gesture: InkApplicationGesture, listening: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "GetGestureStatus", 54usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).GetGestureStatus)(this, gesture, listening), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetWindowInputRectangle(this: *mut core::ffi::c_void, windowinputrectangle: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "GetWindowInputRectangle", 55usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).GetWindowInputRectangle)(this, windowinputrectangle), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetWindowInputRectangle(this: *mut core::ffi::c_void, windowinputrectangle: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "SetWindowInputRectangle", 56usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).SetWindowInputRectangle)(this, windowinputrectangle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllTabletsMode(this: *mut core::ffi::c_void, usemouseforinput: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "SetAllTabletsMode", 57usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).SetAllTabletsMode)(this, usemouseforinput), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSingleTabletIntegratedMode(this: *mut core::ffi::c_void, tablet: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "SetSingleTabletIntegratedMode", 58usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).SetSingleTabletIntegratedMode)(this, tablet), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEventInterest(this: *mut core::ffi::c_void, eventid: InkCollectorEventInterest, listen: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "GetEventInterest", 59usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).GetEventInterest)(this, eventid, listen), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventInterest(this: *mut core::ffi::c_void, eventid: InkCollectorEventInterest, listen: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkOverlay", "SetEventInterest", 60usize, |this| ((**(this as *const *const IInkOverlay_Vtbl)).SetEventInterest)(this, eventid, listen), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            hWnd,
+            SethWnd,
+            Enabled,
+            SetEnabled,
+            #[cfg(feature = "Win32_System_Com")]
+            DefaultDrawingAttributes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DefaultDrawingAttributes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_DefaultDrawingAttributes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_DefaultDrawingAttributes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Renderer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Renderer: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_Renderer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_Renderer: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Ink,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Ink: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_Ink,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_Ink: 0,
+            AutoRedraw,
+            SetAutoRedraw,
+            CollectingInk,
+            CollectionMode,
+            SetCollectionMode,
+            DynamicRendering,
+            SetDynamicRendering,
+            DesiredPacketDescription,
+            SetDesiredPacketDescription,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+            MouseIcon,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Ole")))]
+            MouseIcon: 0,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+            SetMouseIcon,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Ole")))]
+            SetMouseIcon: 0,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+            putref_MouseIcon,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Ole")))]
+            putref_MouseIcon: 0,
+            MousePointer,
+            SetMousePointer,
+            EditingMode,
+            SetEditingMode,
+            #[cfg(feature = "Win32_System_Com")]
+            Selection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Selection: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSelection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSelection: 0,
+            EraserMode,
+            SetEraserMode,
+            EraserWidth,
+            SetEraserWidth,
+            AttachMode,
+            SetAttachMode,
+            #[cfg(feature = "Win32_System_Com")]
+            Cursors,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Cursors: 0,
+            MarginX,
+            SetMarginX,
+            MarginY,
+            SetMarginY,
+            #[cfg(feature = "Win32_System_Com")]
+            Tablet,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Tablet: 0,
+            SupportHighContrastInk,
+            SetSupportHighContrastInk,
+            SupportHighContrastSelectionUI,
+            SetSupportHighContrastSelectionUI,
+            HitTestSelection,
+            #[cfg(feature = "Win32_System_Com")]
+            Draw,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Draw: 0,
+            SetGestureStatus,
+            GetGestureStatus,
+            #[cfg(feature = "Win32_System_Com")]
+            GetWindowInputRectangle,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetWindowInputRectangle: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetWindowInputRectangle,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetWindowInputRectangle: 0,
+            SetAllTabletsMode,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSingleTabletIntegratedMode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSingleTabletIntegratedMode: 0,
+            GetEventInterest,
+            SetEventInterest,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkOverlay as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IInkPicture_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn hWnd(this: *mut core::ffi::c_void, currentwindow: *mut isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "hWnd", 7usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).hWnd)(this, currentwindow), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DefaultDrawingAttributes(this: *mut core::ffi::c_void, currentattributes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "DefaultDrawingAttributes", 8usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).DefaultDrawingAttributes)(this, currentattributes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_DefaultDrawingAttributes(this: *mut core::ffi::c_void, newattributes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "putref_DefaultDrawingAttributes", 9usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).putref_DefaultDrawingAttributes)(this, newattributes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Renderer(this: *mut core::ffi::c_void, currentinkrenderer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "Renderer", 10usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).Renderer)(this, currentinkrenderer), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_Renderer(this: *mut core::ffi::c_void, newinkrenderer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "putref_Renderer", 11usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).putref_Renderer)(this, newinkrenderer), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Ink(this: *mut core::ffi::c_void, ink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "Ink", 12usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).Ink)(this, ink), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_Ink(this: *mut core::ffi::c_void, newink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "putref_Ink", 13usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).putref_Ink)(this, newink), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoRedraw(this: *mut core::ffi::c_void, autoredraw: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "AutoRedraw", 14usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).AutoRedraw)(this, autoredraw), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoRedraw(this: *mut core::ffi::c_void, autoredraw: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "SetAutoRedraw", 15usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).SetAutoRedraw)(this, autoredraw), |result| Some(*result))
+        }
+        unsafe extern "system" fn CollectingInk(this: *mut core::ffi::c_void, collecting: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "CollectingInk", 16usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).CollectingInk)(this, collecting), |result| Some(*result))
+        }
+        unsafe extern "system" fn CollectionMode(this: *mut core::ffi::c_void, mode: *mut InkCollectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "CollectionMode", 17usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).CollectionMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCollectionMode(this: *mut core::ffi::c_void, mode: InkCollectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "SetCollectionMode", 18usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).SetCollectionMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn DynamicRendering(this: *mut core::ffi::c_void, enabled: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "DynamicRendering", 19usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).DynamicRendering)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDynamicRendering(this: *mut core::ffi::c_void, enabled: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "SetDynamicRendering", 20usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).SetDynamicRendering)(this, enabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn DesiredPacketDescription(this: *mut core::ffi::c_void, packetguids: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "DesiredPacketDescription", 21usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).DesiredPacketDescription)(this, packetguids), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredPacketDescription(this: *mut core::ffi::c_void, packetguids: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "SetDesiredPacketDescription", 22usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).SetDesiredPacketDescription)(this, packetguids), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+        unsafe extern "system" fn MouseIcon(this: *mut core::ffi::c_void, mouseicon: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "MouseIcon", 23usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).MouseIcon)(this, mouseicon), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+        unsafe extern "system" fn SetMouseIcon(this: *mut core::ffi::c_void, mouseicon: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "SetMouseIcon", 24usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).SetMouseIcon)(this, mouseicon), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+        unsafe extern "system" fn putref_MouseIcon(this: *mut core::ffi::c_void, mouseicon: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "putref_MouseIcon", 25usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).putref_MouseIcon)(this, mouseicon), |result| Some(*result))
+        }
+        unsafe extern "system" fn MousePointer(this: *mut core::ffi::c_void, mousepointer: *mut InkMousePointer) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "MousePointer", 26usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).MousePointer)(this, mousepointer), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMousePointer(this: *mut core::ffi::c_void, mousepointer: InkMousePointer) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "SetMousePointer", 27usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).SetMousePointer)(this, mousepointer), |result| Some(*result))
+        }
+        unsafe extern "system" fn EditingMode(this: *mut core::ffi::c_void, editingmode: *mut InkOverlayEditingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "EditingMode", 28usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).EditingMode)(this, editingmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEditingMode(this: *mut core::ffi::c_void, editingmode: InkOverlayEditingMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "SetEditingMode", 29usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).SetEditingMode)(this, editingmode), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Selection(this: *mut core::ffi::c_void, selection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "Selection", 30usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).Selection)(this, selection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSelection(this: *mut core::ffi::c_void, selection: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "SetSelection", 31usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).SetSelection)(this, selection), |result| Some(*result))
+        }
+        unsafe extern "system" fn EraserMode(this: *mut core::ffi::c_void, erasermode: *mut InkOverlayEraserMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "EraserMode", 32usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).EraserMode)(this, erasermode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEraserMode(this: *mut core::ffi::c_void, erasermode: InkOverlayEraserMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "SetEraserMode", 33usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).SetEraserMode)(this, erasermode), |result| Some(*result))
+        }
+        unsafe extern "system" fn EraserWidth(this: *mut core::ffi::c_void, eraserwidth: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "EraserWidth", 34usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).EraserWidth)(this, eraserwidth), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEraserWidth(this: *mut core::ffi::c_void, neweraserwidth: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "SetEraserWidth", 35usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).SetEraserWidth)(this, neweraserwidth), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+        unsafe extern "system" fn putref_Picture(this: *mut core::ffi::c_void, ppicture: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "putref_Picture", 36usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).putref_Picture)(this, ppicture), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+        unsafe extern "system" fn SetPicture(this: *mut core::ffi::c_void, ppicture: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "SetPicture", 37usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).SetPicture)(this, ppicture), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+        unsafe extern "system" fn Picture(this: *mut core::ffi::c_void, pppicture: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "Picture", 38usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).Picture)(this, pppicture), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSizeMode(this: *mut core::ffi::c_void, smnewsizemode: InkPictureSizeMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "SetSizeMode", 39usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).SetSizeMode)(this, smnewsizemode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SizeMode(this: *mut core::ffi::c_void, smsizemode: *mut InkPictureSizeMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "SizeMode", 40usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).SizeMode)(this, smsizemode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBackColor(this: *mut core::ffi::c_void, newcolor: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "SetBackColor", 41usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).SetBackColor)(this, newcolor), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackColor(this: *mut core::ffi::c_void, pcolor: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "BackColor", 42usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).BackColor)(this, pcolor), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Cursors(this: *mut core::ffi::c_void, cursors: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "Cursors", 43usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).Cursors)(this, cursors), |result| Some(*result))
+        }
+        unsafe extern "system" fn MarginX(this: *mut core::ffi::c_void, marginx: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "MarginX", 44usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).MarginX)(this, marginx), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMarginX(this: *mut core::ffi::c_void, marginx: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "SetMarginX", 45usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).SetMarginX)(this, marginx), |result| Some(*result))
+        }
+        unsafe extern "system" fn MarginY(this: *mut core::ffi::c_void, marginy: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "MarginY", 46usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).MarginY)(this, marginy), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMarginY(this: *mut core::ffi::c_void, marginy: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "SetMarginY", 47usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).SetMarginY)(this, marginy), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Tablet(this: *mut core::ffi::c_void, singletablet: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "Tablet", 48usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).Tablet)(this, singletablet), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportHighContrastInk(this: *mut core::ffi::c_void, support: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "SupportHighContrastInk", 49usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).SupportHighContrastInk)(this, support), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSupportHighContrastInk(this: *mut core::ffi::c_void, support: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "SetSupportHighContrastInk", 50usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).SetSupportHighContrastInk)(this, support), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportHighContrastSelectionUI(this: *mut core::ffi::c_void, support: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "SupportHighContrastSelectionUI", 51usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).SupportHighContrastSelectionUI)(this, support), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSupportHighContrastSelectionUI(this: *mut core::ffi::c_void, support: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "SetSupportHighContrastSelectionUI", 52usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).SetSupportHighContrastSelectionUI)(this, support), |result| Some(*result))
+        }
+        unsafe extern "system" fn HitTestSelection(this: *mut core::ffi::c_void, x: i32, y: i32, selarea: *mut SelectionHitResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "HitTestSelection", 53usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).HitTestSelection)(this, x, y, selarea), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGestureStatus(this: *mut core::ffi::c_void, gesture: InkApplicationGesture, listen: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "SetGestureStatus", 54usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).SetGestureStatus)(this, gesture, listen), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGestureStatus(this: *mut core::ffi::c_void, gesture: InkApplicationGesture, listening: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "GetGestureStatus", 55usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).GetGestureStatus)(this, gesture, listening), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetWindowInputRectangle(this: *mut core::ffi::c_void, windowinputrectangle: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "GetWindowInputRectangle", 56usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).GetWindowInputRectangle)(this, windowinputrectangle), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetWindowInputRectangle(this: *mut core::ffi::c_void, windowinputrectangle: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "SetWindowInputRectangle", 57usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).SetWindowInputRectangle)(this, windowinputrectangle), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllTabletsMode(this: *mut core::ffi::c_void, usemouseforinput: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "SetAllTabletsMode", 58usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).SetAllTabletsMode)(this, usemouseforinput), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSingleTabletIntegratedMode(this: *mut core::ffi::c_void, tablet: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "SetSingleTabletIntegratedMode", 59usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).SetSingleTabletIntegratedMode)(this, tablet), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEventInterest(this: *mut core::ffi::c_void, eventid: InkCollectorEventInterest, listen: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "GetEventInterest", 60usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).GetEventInterest)(this, eventid, listen), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventInterest(this: *mut core::ffi::c_void, eventid: InkCollectorEventInterest, listen: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "SetEventInterest", 61usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).SetEventInterest)(this, eventid, listen), |result| Some(*result))
+        }
+        unsafe extern "system" fn InkEnabled(this: *mut core::ffi::c_void, collecting: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "InkEnabled", 62usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).InkEnabled)(this, collecting), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInkEnabled(this: *mut core::ffi::c_void, collecting: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "SetInkEnabled", 63usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).SetInkEnabled)(this, collecting), |result| Some(*result))
+        }
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, pbool: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "Enabled", 64usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).Enabled)(this, pbool), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, vbool: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkPicture", "SetEnabled", 65usize, |this| ((**(this as *const *const IInkPicture_Vtbl)).SetEnabled)(this, vbool), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            hWnd,
+            #[cfg(feature = "Win32_System_Com")]
+            DefaultDrawingAttributes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DefaultDrawingAttributes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_DefaultDrawingAttributes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_DefaultDrawingAttributes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Renderer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Renderer: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_Renderer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_Renderer: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Ink,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Ink: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_Ink,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_Ink: 0,
+            AutoRedraw,
+            SetAutoRedraw,
+            CollectingInk,
+            CollectionMode,
+            SetCollectionMode,
+            DynamicRendering,
+            SetDynamicRendering,
+            DesiredPacketDescription,
+            SetDesiredPacketDescription,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+            MouseIcon,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Ole")))]
+            MouseIcon: 0,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+            SetMouseIcon,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Ole")))]
+            SetMouseIcon: 0,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+            putref_MouseIcon,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Ole")))]
+            putref_MouseIcon: 0,
+            MousePointer,
+            SetMousePointer,
+            EditingMode,
+            SetEditingMode,
+            #[cfg(feature = "Win32_System_Com")]
+            Selection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Selection: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSelection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSelection: 0,
+            EraserMode,
+            SetEraserMode,
+            EraserWidth,
+            SetEraserWidth,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+            putref_Picture,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Ole")))]
+            putref_Picture: 0,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+            SetPicture,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Ole")))]
+            SetPicture: 0,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+            Picture,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Ole")))]
+            Picture: 0,
+            SetSizeMode,
+            SizeMode,
+            SetBackColor,
+            BackColor,
+            #[cfg(feature = "Win32_System_Com")]
+            Cursors,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Cursors: 0,
+            MarginX,
+            SetMarginX,
+            MarginY,
+            SetMarginY,
+            #[cfg(feature = "Win32_System_Com")]
+            Tablet,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Tablet: 0,
+            SupportHighContrastInk,
+            SetSupportHighContrastInk,
+            SupportHighContrastSelectionUI,
+            SetSupportHighContrastSelectionUI,
+            HitTestSelection,
+            SetGestureStatus,
+            GetGestureStatus,
+            #[cfg(feature = "Win32_System_Com")]
+            GetWindowInputRectangle,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetWindowInputRectangle: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetWindowInputRectangle,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetWindowInputRectangle: 0,
+            SetAllTabletsMode,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSingleTabletIntegratedMode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSingleTabletIntegratedMode: 0,
+            GetEventInterest,
+            SetEventInterest,
+            InkEnabled,
+            SetInkEnabled,
+            Enabled,
+            SetEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkPicture as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkRecognitionAlternate_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn String(this: *mut core::ffi::c_void, recostring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionAlternate", "String", 7usize, |this| ((**(this as *const *const IInkRecognitionAlternate_Vtbl)).String)(this, recostring), |result| Some(*result))
+        }
+        unsafe extern "system" fn Confidence(this: *mut core::ffi::c_void, confidence: *mut InkRecognitionConfidence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionAlternate", "Confidence", 8usize, |this| ((**(this as *const *const IInkRecognitionAlternate_Vtbl)).Confidence)(this, confidence), |result| Some(*result))
+        }
+        unsafe extern "system" fn Baseline(this: *mut core::ffi::c_void, baseline: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionAlternate", "Baseline", 9usize, |this| ((**(this as *const *const IInkRecognitionAlternate_Vtbl)).Baseline)(this, baseline), |result| Some(*result))
+        }
+        unsafe extern "system" fn Midline(this: *mut core::ffi::c_void, midline: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionAlternate", "Midline", 10usize, |this| ((**(this as *const *const IInkRecognitionAlternate_Vtbl)).Midline)(this, midline), |result| Some(*result))
+        }
+        unsafe extern "system" fn Ascender(this: *mut core::ffi::c_void, ascender: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionAlternate", "Ascender", 11usize, |this| ((**(this as *const *const IInkRecognitionAlternate_Vtbl)).Ascender)(this, ascender), |result| Some(*result))
+        }
+        unsafe extern "system" fn Descender(this: *mut core::ffi::c_void, descender: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionAlternate", "Descender", 12usize, |this| ((**(this as *const *const IInkRecognitionAlternate_Vtbl)).Descender)(this, descender), |result| Some(*result))
+        }
+        unsafe extern "system" fn LineNumber(this: *mut core::ffi::c_void, linenumber: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionAlternate", "LineNumber", 13usize, |this| ((**(this as *const *const IInkRecognitionAlternate_Vtbl)).LineNumber)(this, linenumber), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Strokes(this: *mut core::ffi::c_void, strokes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionAlternate", "Strokes", 14usize, |this| ((**(this as *const *const IInkRecognitionAlternate_Vtbl)).Strokes)(this, strokes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn LineAlternates(this: *mut core::ffi::c_void, linealternates: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionAlternate", "LineAlternates", 15usize, |this| ((**(this as *const *const IInkRecognitionAlternate_Vtbl)).LineAlternates)(this, linealternates), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ConfidenceAlternates(this: *mut core::ffi::c_void, confidencealternates: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionAlternate", "ConfidenceAlternates", 16usize, |this| ((**(this as *const *const IInkRecognitionAlternate_Vtbl)).ConfidenceAlternates)(this, confidencealternates), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetStrokesFromStrokeRanges(this: *mut core::ffi::c_void, strokes: *mut core::ffi::c_void, getstrokesfromstrokeranges: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionAlternate", "GetStrokesFromStrokeRanges", 17usize, |this| ((**(this as *const *const IInkRecognitionAlternate_Vtbl)).GetStrokesFromStrokeRanges)(this, strokes, getstrokesfromstrokeranges), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetStrokesFromTextRange(this: *mut core::ffi::c_void, selectionstart: *mut i32, selectionlength: *mut i32, getstrokesfromtextrange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionAlternate", "GetStrokesFromTextRange", 18usize, |this| ((**(this as *const *const IInkRecognitionAlternate_Vtbl)).GetStrokesFromTextRange)(this, selectionstart, selectionlength, getstrokesfromtextrange), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetTextRangeFromStrokes(this: *mut core::ffi::c_void, strokes: *mut core::ffi::c_void, selectionstart: *mut i32, selectionlength: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionAlternate", "GetTextRangeFromStrokes", 19usize, |this| ((**(this as *const *const IInkRecognitionAlternate_Vtbl)).GetTextRangeFromStrokes)(this, strokes, selectionstart, selectionlength), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AlternatesWithConstantPropertyValues(this: *mut core::ffi::c_void, propertytype: core::mem::MaybeUninit<windows_core::BSTR>, alternateswithconstantpropertyvalues: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionAlternate", "AlternatesWithConstantPropertyValues", 20usize, |this| ((**(this as *const *const IInkRecognitionAlternate_Vtbl)).AlternatesWithConstantPropertyValues)(this, propertytype, alternateswithconstantpropertyvalues), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyValue(this: *mut core::ffi::c_void, propertytype: core::mem::MaybeUninit<windows_core::BSTR>, propertyvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionAlternate", "GetPropertyValue", 21usize, |this| ((**(this as *const *const IInkRecognitionAlternate_Vtbl)).GetPropertyValue)(this, propertytype, propertyvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            String,
+            Confidence,
+            Baseline,
+            Midline,
+            Ascender,
+            Descender,
+            LineNumber,
+            #[cfg(feature = "Win32_System_Com")]
+            Strokes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Strokes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            LineAlternates,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            LineAlternates: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ConfidenceAlternates,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ConfidenceAlternates: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetStrokesFromStrokeRanges,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetStrokesFromStrokeRanges: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetStrokesFromTextRange,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetStrokesFromTextRange: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetTextRangeFromStrokes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetTextRangeFromStrokes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AlternatesWithConstantPropertyValues,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AlternatesWithConstantPropertyValues: 0,
+            GetPropertyValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkRecognitionAlternate as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkRecognitionAlternates_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionAlternates", "Count", 7usize, |this| ((**(this as *const *const IInkRecognitionAlternates_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, _newenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionAlternates", "_NewEnum", 8usize, |this| ((**(this as *const *const IInkRecognitionAlternates_Vtbl))._NewEnum)(this, _newenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Strokes(this: *mut core::ffi::c_void, strokes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionAlternates", "Strokes", 9usize, |this| ((**(this as *const *const IInkRecognitionAlternates_Vtbl)).Strokes)(this, strokes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: i32, inkrecoalternate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionAlternates", "Item", 10usize, |this| ((**(this as *const *const IInkRecognitionAlternates_Vtbl)).Item)(this, index, inkrecoalternate), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Strokes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Strokes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkRecognitionAlternates as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkRecognitionResult_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TopString(this: *mut core::ffi::c_void, topstring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionResult", "TopString", 7usize, |this| ((**(this as *const *const IInkRecognitionResult_Vtbl)).TopString)(this, topstring), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn TopAlternate(this: *mut core::ffi::c_void, topalternate: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionResult", "TopAlternate", 8usize, |this| ((**(this as *const *const IInkRecognitionResult_Vtbl)).TopAlternate)(this, topalternate), |result| Some(*result))
+        }
+        unsafe extern "system" fn TopConfidence(this: *mut core::ffi::c_void, topconfidence: *mut InkRecognitionConfidence) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionResult", "TopConfidence", 9usize, |this| ((**(this as *const *const IInkRecognitionResult_Vtbl)).TopConfidence)(this, topconfidence), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Strokes(this: *mut core::ffi::c_void, strokes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionResult", "Strokes", 10usize, |this| ((**(this as *const *const IInkRecognitionResult_Vtbl)).Strokes)(this, strokes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AlternatesFromSelection(this: *mut core::ffi::c_void, selectionstart: i32, selectionlength: i32, maximumalternates: i32, alternatesfromselection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionResult", "AlternatesFromSelection", 11usize, |this| ((**(this as *const *const IInkRecognitionResult_Vtbl)).AlternatesFromSelection)(this, selectionstart, selectionlength, maximumalternates, alternatesfromselection), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ModifyTopAlternate(this: *mut core::ffi::c_void, alternate: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionResult", "ModifyTopAlternate", 12usize, |this| ((**(this as *const *const IInkRecognitionResult_Vtbl)).ModifyTopAlternate)(this, alternate), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetResultOnStrokes(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognitionResult", "SetResultOnStrokes", 13usize, |this| ((**(this as *const *const IInkRecognitionResult_Vtbl)).SetResultOnStrokes)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            TopString,
+            #[cfg(feature = "Win32_System_Com")]
+            TopAlternate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            TopAlternate: 0,
+            TopConfidence,
+            #[cfg(feature = "Win32_System_Com")]
+            Strokes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Strokes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AlternatesFromSelection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AlternatesFromSelection: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ModifyTopAlternate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ModifyTopAlternate: 0,
+            SetResultOnStrokes,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkRecognitionResult as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkRecognizer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizer", "Name", 7usize, |this| ((**(this as *const *const IInkRecognizer_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn Vendor(this: *mut core::ffi::c_void, vendor: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizer", "Vendor", 8usize, |this| ((**(this as *const *const IInkRecognizer_Vtbl)).Vendor)(this, vendor), |result| Some(*result))
+        }
+        unsafe extern "system" fn Capabilities(this: *mut core::ffi::c_void, capabilitiesflags: *mut InkRecognizerCapabilities) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizer", "Capabilities", 9usize, |this| ((**(this as *const *const IInkRecognizer_Vtbl)).Capabilities)(this, capabilitiesflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn Languages(this: *mut core::ffi::c_void, languages: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizer", "Languages", 10usize, |this| ((**(this as *const *const IInkRecognizer_Vtbl)).Languages)(this, languages), |result| Some(*result))
+        }
+        unsafe extern "system" fn SupportedProperties(this: *mut core::ffi::c_void, supportedproperties: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizer", "SupportedProperties", 11usize, |this| ((**(this as *const *const IInkRecognizer_Vtbl)).SupportedProperties)(this, supportedproperties), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreferredPacketDescription(this: *mut core::ffi::c_void, preferredpacketdescription: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizer", "PreferredPacketDescription", 12usize, |this| ((**(this as *const *const IInkRecognizer_Vtbl)).PreferredPacketDescription)(this, preferredpacketdescription), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateRecognizerContext(this: *mut core::ffi::c_void, context: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizer", "CreateRecognizerContext", 13usize, |this| ((**(this as *const *const IInkRecognizer_Vtbl)).CreateRecognizerContext)(this, context), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            Vendor,
+            Capabilities,
+            Languages,
+            SupportedProperties,
+            PreferredPacketDescription,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateRecognizerContext,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateRecognizerContext: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkRecognizer as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkRecognizer2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Id(this: *mut core::ffi::c_void, pbstrid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizer2", "Id", 7usize, |this| ((**(this as *const *const IInkRecognizer2_Vtbl)).Id)(this, pbstrid), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnicodeRanges(this: *mut core::ffi::c_void, unicoderanges: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizer2", "UnicodeRanges", 8usize, |this| ((**(this as *const *const IInkRecognizer2_Vtbl)).UnicodeRanges)(this, unicoderanges), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Id, UnicodeRanges }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkRecognizer2 as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkRecognizerContext_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Strokes(this: *mut core::ffi::c_void, strokes: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContext", "Strokes", 7usize, |this| ((**(this as *const *const IInkRecognizerContext_Vtbl)).Strokes)(this, strokes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_Strokes(this: *mut core::ffi::c_void, strokes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContext", "putref_Strokes", 8usize, |this| ((**(this as *const *const IInkRecognizerContext_Vtbl)).putref_Strokes)(this, strokes), |result| Some(*result))
+        }
+        unsafe extern "system" fn CharacterAutoCompletionMode(this: *mut core::ffi::c_void, mode: *mut InkRecognizerCharacterAutoCompletionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContext", "CharacterAutoCompletionMode", 9usize, |this| ((**(this as *const *const IInkRecognizerContext_Vtbl)).CharacterAutoCompletionMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCharacterAutoCompletionMode(this: *mut core::ffi::c_void, mode: InkRecognizerCharacterAutoCompletionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContext", "SetCharacterAutoCompletionMode", 10usize, |this| ((**(this as *const *const IInkRecognizerContext_Vtbl)).SetCharacterAutoCompletionMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn Factoid(this: *mut core::ffi::c_void, factoid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContext", "Factoid", 11usize, |this| ((**(this as *const *const IInkRecognizerContext_Vtbl)).Factoid)(this, factoid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFactoid(this: *mut core::ffi::c_void, factoid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContext", "SetFactoid", 12usize, |this| ((**(this as *const *const IInkRecognizerContext_Vtbl)).SetFactoid)(this, factoid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Guide(this: *mut core::ffi::c_void, recognizerguide: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContext", "Guide", 13usize, |this| ((**(this as *const *const IInkRecognizerContext_Vtbl)).Guide)(this, recognizerguide), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_Guide(this: *mut core::ffi::c_void, recognizerguide: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContext", "putref_Guide", 14usize, |this| ((**(this as *const *const IInkRecognizerContext_Vtbl)).putref_Guide)(this, recognizerguide), |result| Some(*result))
+        }
+        unsafe extern "system" fn PrefixText(this: *mut core::ffi::c_void, prefix: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContext", "PrefixText", 15usize, |this| ((**(this as *const *const IInkRecognizerContext_Vtbl)).PrefixText)(this, prefix), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPrefixText(this: *mut core::ffi::c_void, prefix: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContext", "SetPrefixText", 16usize, |this| ((**(this as *const *const IInkRecognizerContext_Vtbl)).SetPrefixText)(this, prefix), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuffixText(this: *mut core::ffi::c_void, suffix: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContext", "SuffixText", 17usize, |this| ((**(this as *const *const IInkRecognizerContext_Vtbl)).SuffixText)(this, suffix), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSuffixText(this: *mut core::ffi::c_void, suffix: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContext", "SetSuffixText", 18usize, |this| ((**(this as *const *const IInkRecognizerContext_Vtbl)).SetSuffixText)(this, suffix), |result| Some(*result))
+        }
+        unsafe extern "system" fn RecognitionFlags(this: *mut core::ffi::c_void, modes: *mut InkRecognitionModes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContext", "RecognitionFlags", 19usize, |this| ((**(this as *const *const IInkRecognizerContext_Vtbl)).RecognitionFlags)(this, modes), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRecognitionFlags(this: *mut core::ffi::c_void, modes: InkRecognitionModes) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContext", "SetRecognitionFlags", 20usize, |this| ((**(this as *const *const IInkRecognizerContext_Vtbl)).SetRecognitionFlags)(this, modes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn WordList(this: *mut core::ffi::c_void, wordlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContext", "WordList", 21usize, |this| ((**(this as *const *const IInkRecognizerContext_Vtbl)).WordList)(this, wordlist), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_WordList(this: *mut core::ffi::c_void, wordlist: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContext", "putref_WordList", 22usize, |this| ((**(this as *const *const IInkRecognizerContext_Vtbl)).putref_WordList)(this, wordlist), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Recognizer(this: *mut core::ffi::c_void, recognizer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContext", "Recognizer", 23usize, |this| ((**(this as *const *const IInkRecognizerContext_Vtbl)).Recognizer)(this, recognizer), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Recognize(this: *mut core::ffi::c_void, recognitionstatus: *mut InkRecognitionStatus, recognitionresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContext", "Recognize", 24usize, |this| ((**(this as *const *const IInkRecognizerContext_Vtbl)).Recognize)(this, recognitionstatus, recognitionresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn StopBackgroundRecognition(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContext", "StopBackgroundRecognition", 25usize, |this| ((**(this as *const *const IInkRecognizerContext_Vtbl)).StopBackgroundRecognition)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndInkInput(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContext", "EndInkInput", 26usize, |this| ((**(this as *const *const IInkRecognizerContext_Vtbl)).EndInkInput)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackgroundRecognize(this: *mut core::ffi::c_void, customdata: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContext", "BackgroundRecognize", 27usize, |this| ((**(this as *const *const IInkRecognizerContext_Vtbl)).BackgroundRecognize)(this, customdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn BackgroundRecognizeWithAlternates(this: *mut core::ffi::c_void, customdata: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContext", "BackgroundRecognizeWithAlternates", 28usize, |this| ((**(this as *const *const IInkRecognizerContext_Vtbl)).BackgroundRecognizeWithAlternates)(this, customdata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, recocontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContext", "Clone", 29usize, |this| ((**(this as *const *const IInkRecognizerContext_Vtbl)).Clone)(this, recocontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsStringSupported(this: *mut core::ffi::c_void, string: core::mem::MaybeUninit<windows_core::BSTR>, supported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContext", "IsStringSupported", 30usize, |this| ((**(this as *const *const IInkRecognizerContext_Vtbl)).IsStringSupported)(this, string, supported), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Strokes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Strokes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_Strokes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_Strokes: 0,
+            CharacterAutoCompletionMode,
+            SetCharacterAutoCompletionMode,
+            Factoid,
+            SetFactoid,
+            #[cfg(feature = "Win32_System_Com")]
+            Guide,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Guide: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_Guide,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_Guide: 0,
+            PrefixText,
+            SetPrefixText,
+            SuffixText,
+            SetSuffixText,
+            RecognitionFlags,
+            SetRecognitionFlags,
+            #[cfg(feature = "Win32_System_Com")]
+            WordList,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            WordList: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_WordList,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_WordList: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Recognizer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Recognizer: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Recognize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Recognize: 0,
+            StopBackgroundRecognition,
+            EndInkInput,
+            BackgroundRecognize,
+            BackgroundRecognizeWithAlternates,
+            #[cfg(feature = "Win32_System_Com")]
+            Clone,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Clone: 0,
+            IsStringSupported,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkRecognizerContext as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkRecognizerContext2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnabledUnicodeRanges(this: *mut core::ffi::c_void, unicoderanges: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContext2", "EnabledUnicodeRanges", 7usize, |this| ((**(this as *const *const IInkRecognizerContext2_Vtbl)).EnabledUnicodeRanges)(this, unicoderanges), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabledUnicodeRanges(this: *mut core::ffi::c_void, unicoderanges: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerContext2", "SetEnabledUnicodeRanges", 8usize, |this| ((**(this as *const *const IInkRecognizerContext2_Vtbl)).SetEnabledUnicodeRanges)(this, unicoderanges), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnabledUnicodeRanges, SetEnabledUnicodeRanges }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkRecognizerContext2 as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkRecognizerGuide_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn WritingBox(this: *mut core::ffi::c_void, rectangle: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerGuide", "WritingBox", 7usize, |this| ((**(this as *const *const IInkRecognizerGuide_Vtbl)).WritingBox)(this, rectangle), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetWritingBox(this: *mut core::ffi::c_void, rectangle: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerGuide", "SetWritingBox", 8usize, |this| ((**(this as *const *const IInkRecognizerGuide_Vtbl)).SetWritingBox)(this, rectangle), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DrawnBox(this: *mut core::ffi::c_void, rectangle: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerGuide", "DrawnBox", 9usize, |this| ((**(this as *const *const IInkRecognizerGuide_Vtbl)).DrawnBox)(this, rectangle), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetDrawnBox(this: *mut core::ffi::c_void, rectangle: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerGuide", "SetDrawnBox", 10usize, |this| ((**(this as *const *const IInkRecognizerGuide_Vtbl)).SetDrawnBox)(this, rectangle), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rows(this: *mut core::ffi::c_void, units: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerGuide", "Rows", 11usize, |this| ((**(this as *const *const IInkRecognizerGuide_Vtbl)).Rows)(this, units), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRows(this: *mut core::ffi::c_void, units: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerGuide", "SetRows", 12usize, |this| ((**(this as *const *const IInkRecognizerGuide_Vtbl)).SetRows)(this, units), |result| Some(*result))
+        }
+        unsafe extern "system" fn Columns(this: *mut core::ffi::c_void, units: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerGuide", "Columns", 13usize, |this| ((**(this as *const *const IInkRecognizerGuide_Vtbl)).Columns)(this, units), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetColumns(this: *mut core::ffi::c_void, units: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerGuide", "SetColumns", 14usize, |this| ((**(this as *const *const IInkRecognizerGuide_Vtbl)).SetColumns)(this, units), |result| Some(*result))
+        }
+        unsafe extern "system" fn Midline(this: *mut core::ffi::c_void, units: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerGuide", "Midline", 15usize, |this| ((**(this as *const *const IInkRecognizerGuide_Vtbl)).Midline)(this, units), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMidline(this: *mut core::ffi::c_void, units: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerGuide", "SetMidline", 16usize, |this| ((**(this as *const *const IInkRecognizerGuide_Vtbl)).SetMidline)(this, units), |result| Some(*result))
+        }
+        unsafe extern "system" fn GuideData(this: *mut core::ffi::c_void, precoguide: *mut InkRecoGuide) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerGuide", "GuideData", 17usize, |this| ((**(this as *const *const IInkRecognizerGuide_Vtbl)).GuideData)(this, precoguide), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGuideData(this: *mut core::ffi::c_void, recoguide: InkRecoGuide) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizerGuide", "SetGuideData", 18usize, |this| ((**(this as *const *const IInkRecognizerGuide_Vtbl)).SetGuideData)(this, recoguide), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            WritingBox,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            WritingBox: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetWritingBox,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetWritingBox: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            DrawnBox,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DrawnBox: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetDrawnBox,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetDrawnBox: 0,
+            Rows,
+            SetRows,
+            Columns,
+            SetColumns,
+            Midline,
+            SetMidline,
+            GuideData,
+            SetGuideData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkRecognizerGuide as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkRecognizers_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizers", "Count", 7usize, |this| ((**(this as *const *const IInkRecognizers_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, _newenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizers", "_NewEnum", 8usize, |this| ((**(this as *const *const IInkRecognizers_Vtbl))._NewEnum)(this, _newenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetDefaultRecognizer(this: *mut core::ffi::c_void, lcid: i32, defaultrecognizer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizers", "GetDefaultRecognizer", 9usize, |this| ((**(this as *const *const IInkRecognizers_Vtbl)).GetDefaultRecognizer)(this, lcid, defaultrecognizer), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: i32, inkrecognizer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRecognizers", "Item", 10usize, |this| ((**(this as *const *const IInkRecognizers_Vtbl)).Item)(this, index, inkrecognizer), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            GetDefaultRecognizer,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetDefaultRecognizer: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkRecognizers as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkRectangle_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Top(this: *mut core::ffi::c_void, units: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRectangle", "Top", 7usize, |this| ((**(this as *const *const IInkRectangle_Vtbl)).Top)(this, units), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTop(this: *mut core::ffi::c_void, units: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRectangle", "SetTop", 8usize, |this| ((**(this as *const *const IInkRectangle_Vtbl)).SetTop)(this, units), |result| Some(*result))
+        }
+        unsafe extern "system" fn Left(this: *mut core::ffi::c_void, units: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRectangle", "Left", 9usize, |this| ((**(this as *const *const IInkRectangle_Vtbl)).Left)(this, units), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetLeft(this: *mut core::ffi::c_void, units: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRectangle", "SetLeft", 10usize, |this| ((**(this as *const *const IInkRectangle_Vtbl)).SetLeft)(this, units), |result| Some(*result))
+        }
+        unsafe extern "system" fn Bottom(this: *mut core::ffi::c_void, units: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRectangle", "Bottom", 11usize, |this| ((**(this as *const *const IInkRectangle_Vtbl)).Bottom)(this, units), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetBottom(this: *mut core::ffi::c_void, units: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRectangle", "SetBottom", 12usize, |this| ((**(this as *const *const IInkRectangle_Vtbl)).SetBottom)(this, units), |result| Some(*result))
+        }
+        unsafe extern "system" fn Right(this: *mut core::ffi::c_void, units: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRectangle", "Right", 13usize, |this| ((**(this as *const *const IInkRectangle_Vtbl)).Right)(this, units), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRight(this: *mut core::ffi::c_void, units: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRectangle", "SetRight", 14usize, |this| ((**(this as *const *const IInkRectangle_Vtbl)).SetRight)(this, units), |result| Some(*result))
+        }
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, rect: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRectangle", "Data", 15usize, |this| ((**(this as *const *const IInkRectangle_Vtbl)).Data)(this, rect), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, rect: super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRectangle", "SetData", 16usize, |this| ((**(this as *const *const IInkRectangle_Vtbl)).SetData)(this, rect), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRectangle(this: *mut core::ffi::c_void, top: *mut i32, left: *mut i32, bottom: *mut i32, right: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRectangle", "GetRectangle", 17usize, |this| ((**(this as *const *const IInkRectangle_Vtbl)).GetRectangle)(this, top, left, bottom, right), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRectangle(this: *mut core::ffi::c_void, top: i32, left: i32, bottom: i32, right: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRectangle", "SetRectangle", 18usize, |this| ((**(this as *const *const IInkRectangle_Vtbl)).SetRectangle)(this, top, left, bottom, right), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Top,
+            SetTop,
+            Left,
+            SetLeft,
+            Bottom,
+            SetBottom,
+            Right,
+            SetRight,
+            Data,
+            SetData,
+            GetRectangle,
+            SetRectangle,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkRectangle as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkRenderer_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetViewTransform(this: *mut core::ffi::c_void, viewtransform: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRenderer", "GetViewTransform", 7usize, |this| ((**(this as *const *const IInkRenderer_Vtbl)).GetViewTransform)(this, viewtransform), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetViewTransform(this: *mut core::ffi::c_void, viewtransform: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRenderer", "SetViewTransform", 8usize, |this| ((**(this as *const *const IInkRenderer_Vtbl)).SetViewTransform)(this, viewtransform), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetObjectTransform(this: *mut core::ffi::c_void, objecttransform: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRenderer", "GetObjectTransform", 9usize, |this| ((**(this as *const *const IInkRenderer_Vtbl)).GetObjectTransform)(this, objecttransform), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetObjectTransform(this: *mut core::ffi::c_void, objecttransform: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRenderer", "SetObjectTransform", 10usize, |this| ((**(this as *const *const IInkRenderer_Vtbl)).SetObjectTransform)(this, objecttransform), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Draw(this: *mut core::ffi::c_void, hdc: isize, strokes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRenderer", "Draw", 11usize, |this| ((**(this as *const *const IInkRenderer_Vtbl)).Draw)(this, hdc, strokes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DrawStroke(this: *mut core::ffi::c_void, hdc: isize, stroke: *mut core::ffi::c_void, drawingattributes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRenderer", "DrawStroke", 12usize, |this| ((**(this as *const *const IInkRenderer_Vtbl)).DrawStroke)(this, hdc, stroke, drawingattributes), |result| Some(*result))
+        }
+        unsafe extern "system" fn PixelToInkSpace(this: *mut core::ffi::c_void, hdc: isize, x: *mut i32, y: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRenderer", "PixelToInkSpace", 13usize, |this| ((**(this as *const *const IInkRenderer_Vtbl)).PixelToInkSpace)(this, hdc, x, y), |result| Some(*result))
+        }
+        unsafe extern "system" fn InkSpaceToPixel(this: *mut core::ffi::c_void, hdcdisplay: isize, x: *mut i32, y: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRenderer", "InkSpaceToPixel", 14usize, |this| ((**(this as *const *const IInkRenderer_Vtbl)).InkSpaceToPixel)(this, hdcdisplay, x, y), |result| Some(*result))
+        }
+        unsafe extern "system" fn PixelToInkSpaceFromPoints(this: *mut core::ffi::c_void, hdc: isize, points: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRenderer", "PixelToInkSpaceFromPoints", 15usize, |this| ((**(this as *const *const IInkRenderer_Vtbl)).PixelToInkSpaceFromPoints)(this, hdc, points), |result| Some(*result))
+        }
+        unsafe extern "system" fn InkSpaceToPixelFromPoints(this: *mut core::ffi::c_void, hdc: isize, points: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRenderer", "InkSpaceToPixelFromPoints", 16usize, |this| ((**(this as *const *const IInkRenderer_Vtbl)).InkSpaceToPixelFromPoints)(this, hdc, points), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Measure(this: *mut core::ffi::c_void, strokes: *mut core::ffi::c_void, rectangle: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRenderer", "Measure", 17usize, |this| ((**(this as *const *const IInkRenderer_Vtbl)).Measure)(this, strokes, rectangle), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn MeasureStroke(this: *mut core::ffi::c_void, stroke: *mut core::ffi::c_void, drawingattributes: *mut core::ffi::c_void, rectangle: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRenderer", "MeasureStroke", 18usize, |this| ((**(this as *const *const IInkRenderer_Vtbl)).MeasureStroke)(this, stroke, drawingattributes, rectangle), |result| Some(*result))
+        }
+        unsafe extern "system" fn Move(this: *mut core::ffi::c_void, horizontalcomponent: f32, verticalcomponent: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRenderer", "Move", 19usize, |this| ((**(this as *const *const IInkRenderer_Vtbl)).Move)(this, horizontalcomponent, verticalcomponent), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rotate(this: *mut core::ffi::c_void, degrees: f32, x: f32, y: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRenderer", "Rotate", 20usize, |this| ((**(this as *const *const IInkRenderer_Vtbl)).Rotate)(this, degrees, x, y), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScaleTransform(this: *mut core::ffi::c_void, horizontalmultiplier: f32, verticalmultiplier: f32, applyonpenwidth: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkRenderer", "ScaleTransform", 21usize, |this| ((**(this as *const *const IInkRenderer_Vtbl)).ScaleTransform)(this, horizontalmultiplier, verticalmultiplier, applyonpenwidth), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetViewTransform,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetViewTransform: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetViewTransform,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetViewTransform: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetObjectTransform,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetObjectTransform: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SetObjectTransform,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetObjectTransform: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Draw,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Draw: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            DrawStroke,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DrawStroke: 0,
+            PixelToInkSpace,
+            InkSpaceToPixel,
+            PixelToInkSpaceFromPoints,
+            InkSpaceToPixelFromPoints,
+            #[cfg(feature = "Win32_System_Com")]
+            Measure,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Measure: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            MeasureStroke,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            MeasureStroke: 0,
+            Move,
+            Rotate,
+            ScaleTransform,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkRenderer as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkStrokeDisp_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ID(this: *mut core::ffi::c_void, id: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "ID", 7usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).ID)(this, id), |result| Some(*result))
+        }
+        unsafe extern "system" fn BezierPoints(this: *mut core::ffi::c_void, points: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "BezierPoints", 8usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).BezierPoints)(this, points), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DrawingAttributes(this: *mut core::ffi::c_void, drawattrs: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "DrawingAttributes", 9usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).DrawingAttributes)(this, drawattrs), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_DrawingAttributes(this: *mut core::ffi::c_void, drawattrs: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "putref_DrawingAttributes", 10usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).putref_DrawingAttributes)(this, drawattrs), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Ink(this: *mut core::ffi::c_void, ink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "Ink", 11usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).Ink)(this, ink), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ExtendedProperties(this: *mut core::ffi::c_void, properties: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "ExtendedProperties", 12usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).ExtendedProperties)(this, properties), |result| Some(*result))
+        }
+        unsafe extern "system" fn PolylineCusps(this: *mut core::ffi::c_void, cusps: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "PolylineCusps", 13usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).PolylineCusps)(this, cusps), |result| Some(*result))
+        }
+        unsafe extern "system" fn BezierCusps(this: *mut core::ffi::c_void, cusps: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "BezierCusps", 14usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).BezierCusps)(this, cusps), |result| Some(*result))
+        }
+        unsafe extern "system" fn SelfIntersections(this: *mut core::ffi::c_void, intersections: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "SelfIntersections", 15usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).SelfIntersections)(this, intersections), |result| Some(*result))
+        }
+        unsafe extern "system" fn PacketCount(this: *mut core::ffi::c_void, plcount: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "PacketCount", 16usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).PacketCount)(this, plcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn PacketSize(this: *mut core::ffi::c_void, plsize: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "PacketSize", 17usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).PacketSize)(this, plsize), |result| Some(*result))
+        }
+        unsafe extern "system" fn PacketDescription(this: *mut core::ffi::c_void, packetdescription: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "PacketDescription", 18usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).PacketDescription)(this, packetdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deleted(this: *mut core::ffi::c_void, deleted: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "Deleted", 19usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).Deleted)(this, deleted), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetBoundingBox(this: *mut core::ffi::c_void, boundingboxmode: InkBoundingBoxMode, rectangle: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "GetBoundingBox", 20usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).GetBoundingBox)(this, boundingboxmode, rectangle), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn FindIntersections(this: *mut core::ffi::c_void, strokes: *mut core::ffi::c_void, intersections: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "FindIntersections", 21usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).FindIntersections)(this, strokes, intersections), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetRectangleIntersections(this: *mut core::ffi::c_void, rectangle: *mut core::ffi::c_void, intersections: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "GetRectangleIntersections", 22usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).GetRectangleIntersections)(this, rectangle, intersections), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Clip(this: *mut core::ffi::c_void, rectangle: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "Clip", 23usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).Clip)(this, rectangle), |result| Some(*result))
+        }
+        unsafe extern "system" fn HitTestCircle(this: *mut core::ffi::c_void, x: i32, y: i32, radius: f32, intersects: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "HitTestCircle", 24usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).HitTestCircle)(this, x, y, radius, intersects), |result| Some(*result))
+        }
+        unsafe extern "system" fn NearestPoint(this: *mut core::ffi::c_void, x: i32, y: i32, distance: *mut f32, point: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "NearestPoint", 25usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).NearestPoint)(this, x, y, distance, point), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Split(this: *mut core::ffi::c_void, splitat: f32, newstroke: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "Split", 26usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).Split)(this, splitat, newstroke), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPacketDescriptionPropertyMetrics(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::BSTR>, minimum: *mut i32, maximum: *mut i32, units: *mut TabletPropertyMetricUnit, resolution: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "GetPacketDescriptionPropertyMetrics", 27usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).GetPacketDescriptionPropertyMetrics)(this, propertyname, minimum, maximum, units, resolution), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPoints(this: *mut core::ffi::c_void, index: i32, count: i32, points: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "GetPoints", 28usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).GetPoints)(this, index, count, points), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPoints(this: *mut core::ffi::c_void, points: core::mem::MaybeUninit<windows_core::VARIANT>, index: i32, count: i32, numberofpointsset: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "SetPoints", 29usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).SetPoints)(this, points, index, count, numberofpointsset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPacketData(this: *mut core::ffi::c_void, index: i32, count: i32, packetdata: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "GetPacketData", 30usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).GetPacketData)(this, index, count, packetdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPacketValuesByProperty(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::BSTR>, index: i32, count: i32, packetvalues: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "GetPacketValuesByProperty", 31usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).GetPacketValuesByProperty)(this, propertyname, index, count, packetvalues), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPacketValuesByProperty(this: *mut core::ffi::c_void, bstrpropertyname: core::mem::MaybeUninit<windows_core::BSTR>, packetvalues: core::mem::MaybeUninit<windows_core::VARIANT>, index: i32, count: i32, numberofpacketsset: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "SetPacketValuesByProperty", 32usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).SetPacketValuesByProperty)(this, bstrpropertyname, packetvalues, index, count, numberofpacketsset), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFlattenedBezierPoints(this: *mut core::ffi::c_void, fittingerror: i32, flattenedbezierpoints: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "GetFlattenedBezierPoints", 33usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).GetFlattenedBezierPoints)(this, fittingerror, flattenedbezierpoints), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Transform(this: *mut core::ffi::c_void, transform: *mut core::ffi::c_void, applyonpenwidth: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "Transform", 34usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).Transform)(this, transform, applyonpenwidth), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ScaleToRectangle(this: *mut core::ffi::c_void, rectangle: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "ScaleToRectangle", 35usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).ScaleToRectangle)(this, rectangle), |result| Some(*result))
+        }
+        unsafe extern "system" fn Move(this: *mut core::ffi::c_void, horizontalcomponent: f32, verticalcomponent: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "Move", 36usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).Move)(this, horizontalcomponent, verticalcomponent), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rotate(this: *mut core::ffi::c_void, degrees: f32, x: f32, y: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "Rotate", 37usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).Rotate)(this, degrees, x, y), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shear(this: *mut core::ffi::c_void, horizontalmultiplier: f32, verticalmultiplier: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "Shear", 38usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).Shear)(this, horizontalmultiplier, verticalmultiplier), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScaleTransform(this: *mut core::ffi::c_void, horizontalmultiplier: f32, verticalmultiplier: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokeDisp", "ScaleTransform", 39usize, |this| ((**(this as *const *const IInkStrokeDisp_Vtbl)).ScaleTransform)(this, horizontalmultiplier, verticalmultiplier), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            ID,
+            BezierPoints,
+            #[cfg(feature = "Win32_System_Com")]
+            DrawingAttributes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DrawingAttributes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_DrawingAttributes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_DrawingAttributes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Ink,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Ink: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ExtendedProperties,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ExtendedProperties: 0,
+            PolylineCusps,
+            BezierCusps,
+            SelfIntersections,
+            PacketCount,
+            PacketSize,
+            PacketDescription,
+            Deleted,
+            #[cfg(feature = "Win32_System_Com")]
+            GetBoundingBox,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetBoundingBox: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            FindIntersections,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            FindIntersections: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetRectangleIntersections,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetRectangleIntersections: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Clip,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Clip: 0,
+            HitTestCircle,
+            NearestPoint,
+            #[cfg(feature = "Win32_System_Com")]
+            Split,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Split: 0,
+            GetPacketDescriptionPropertyMetrics,
+            GetPoints,
+            SetPoints,
+            GetPacketData,
+            GetPacketValuesByProperty,
+            SetPacketValuesByProperty,
+            GetFlattenedBezierPoints,
+            #[cfg(feature = "Win32_System_Com")]
+            Transform,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Transform: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ScaleToRectangle,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ScaleToRectangle: 0,
+            Move,
+            Rotate,
+            Shear,
+            ScaleTransform,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkStrokeDisp as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkStrokes_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokes", "Count", 7usize, |this| ((**(this as *const *const IInkStrokes_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, _newenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokes", "_NewEnum", 8usize, |this| ((**(this as *const *const IInkStrokes_Vtbl))._NewEnum)(this, _newenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Ink(this: *mut core::ffi::c_void, ink: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokes", "Ink", 9usize, |this| ((**(this as *const *const IInkStrokes_Vtbl)).Ink)(this, ink), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RecognitionResult(this: *mut core::ffi::c_void, recognitionresult: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokes", "RecognitionResult", 10usize, |this| ((**(this as *const *const IInkStrokes_Vtbl)).RecognitionResult)(this, recognitionresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn ToString(this: *mut core::ffi::c_void, tostring: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokes", "ToString", 11usize, |this| ((**(this as *const *const IInkStrokes_Vtbl)).ToString)(this, tostring), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: i32, stroke: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokes", "Item", 12usize, |this| ((**(this as *const *const IInkStrokes_Vtbl)).Item)(this, index, stroke), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, inkstroke: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokes", "Add", 13usize, |this| ((**(this as *const *const IInkStrokes_Vtbl)).Add)(this, inkstroke), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AddStrokes(this: *mut core::ffi::c_void, inkstrokes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokes", "AddStrokes", 14usize, |this| ((**(this as *const *const IInkStrokes_Vtbl)).AddStrokes)(this, inkstrokes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Remove(this: *mut core::ffi::c_void, inkstroke: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokes", "Remove", 15usize, |this| ((**(this as *const *const IInkStrokes_Vtbl)).Remove)(this, inkstroke), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn RemoveStrokes(this: *mut core::ffi::c_void, inkstrokes: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokes", "RemoveStrokes", 16usize, |this| ((**(this as *const *const IInkStrokes_Vtbl)).RemoveStrokes)(this, inkstrokes), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ModifyDrawingAttributes(this: *mut core::ffi::c_void, drawattrs: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokes", "ModifyDrawingAttributes", 17usize, |this| ((**(this as *const *const IInkStrokes_Vtbl)).ModifyDrawingAttributes)(this, drawattrs), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetBoundingBox(this: *mut core::ffi::c_void, boundingboxmode: InkBoundingBoxMode, boundingbox: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokes", "GetBoundingBox", 18usize, |this| ((**(this as *const *const IInkStrokes_Vtbl)).GetBoundingBox)(this, boundingboxmode, boundingbox), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Transform(this: *mut core::ffi::c_void, transform: *mut core::ffi::c_void, applyonpenwidth: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokes", "Transform", 19usize, |this| ((**(this as *const *const IInkStrokes_Vtbl)).Transform)(this, transform, applyonpenwidth), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn ScaleToRectangle(this: *mut core::ffi::c_void, rectangle: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokes", "ScaleToRectangle", 20usize, |this| ((**(this as *const *const IInkStrokes_Vtbl)).ScaleToRectangle)(this, rectangle), |result| Some(*result))
+        }
+        unsafe extern "system" fn Move(this: *mut core::ffi::c_void, horizontalcomponent: f32, verticalcomponent: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokes", "Move", 21usize, |this| ((**(this as *const *const IInkStrokes_Vtbl)).Move)(this, horizontalcomponent, verticalcomponent), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rotate(this: *mut core::ffi::c_void, degrees: f32, x: f32, y: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokes", "Rotate", 22usize, |this| ((**(this as *const *const IInkStrokes_Vtbl)).Rotate)(this, degrees, x, y), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shear(this: *mut core::ffi::c_void, horizontalmultiplier: f32, verticalmultiplier: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokes", "Shear", 23usize, |this| ((**(this as *const *const IInkStrokes_Vtbl)).Shear)(this, horizontalmultiplier, verticalmultiplier), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScaleTransform(this: *mut core::ffi::c_void, horizontalmultiplier: f32, verticalmultiplier: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokes", "ScaleTransform", 24usize, |this| ((**(this as *const *const IInkStrokes_Vtbl)).ScaleTransform)(this, horizontalmultiplier, verticalmultiplier), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Clip(this: *mut core::ffi::c_void, rectangle: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokes", "Clip", 25usize, |this| ((**(this as *const *const IInkStrokes_Vtbl)).Clip)(this, rectangle), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveRecognitionResult(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkStrokes", "RemoveRecognitionResult", 26usize, |this| ((**(this as *const *const IInkStrokes_Vtbl)).RemoveRecognitionResult)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            Ink,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Ink: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            RecognitionResult,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RecognitionResult: 0,
+            ToString,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Add,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Add: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            AddStrokes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AddStrokes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Remove,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Remove: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            RemoveStrokes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            RemoveStrokes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ModifyDrawingAttributes,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ModifyDrawingAttributes: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetBoundingBox,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetBoundingBox: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Transform,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Transform: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            ScaleToRectangle,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            ScaleToRectangle: 0,
+            Move,
+            Rotate,
+            Shear,
+            ScaleTransform,
+            #[cfg(feature = "Win32_System_Com")]
+            Clip,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Clip: 0,
+            RemoveRecognitionResult,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkStrokes as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkTablet_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Name(this: *mut core::ffi::c_void, name: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTablet", "Name", 7usize, |this| ((**(this as *const *const IInkTablet_Vtbl)).Name)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn PlugAndPlayId(this: *mut core::ffi::c_void, id: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTablet", "PlugAndPlayId", 8usize, |this| ((**(this as *const *const IInkTablet_Vtbl)).PlugAndPlayId)(this, id), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn MaximumInputRectangle(this: *mut core::ffi::c_void, rectangle: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTablet", "MaximumInputRectangle", 9usize, |this| ((**(this as *const *const IInkTablet_Vtbl)).MaximumInputRectangle)(this, rectangle), |result| Some(*result))
+        }
+        unsafe extern "system" fn HardwareCapabilities(this: *mut core::ffi::c_void, capabilities: *mut TabletHardwareCapabilities) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTablet", "HardwareCapabilities", 10usize, |this| ((**(this as *const *const IInkTablet_Vtbl)).HardwareCapabilities)(this, capabilities), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPacketPropertySupported(this: *mut core::ffi::c_void, packetpropertyname: core::mem::MaybeUninit<windows_core::BSTR>, supported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTablet", "IsPacketPropertySupported", 11usize, |this| ((**(this as *const *const IInkTablet_Vtbl)).IsPacketPropertySupported)(this, packetpropertyname, supported), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyMetrics(this: *mut core::ffi::c_void, propertyname: core::mem::MaybeUninit<windows_core::BSTR>, minimum: *mut i32, maximum: *mut i32, units: *mut TabletPropertyMetricUnit, resolution: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTablet", "GetPropertyMetrics", 12usize, |this| ((**(this as *const *const IInkTablet_Vtbl)).GetPropertyMetrics)(this, propertyname, minimum, maximum, units, resolution), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Name,
+            PlugAndPlayId,
+            #[cfg(feature = "Win32_System_Com")]
+            MaximumInputRectangle,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            MaximumInputRectangle: 0,
+            HardwareCapabilities,
+            IsPacketPropertySupported,
+            GetPropertyMetrics,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkTablet as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkTablet2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DeviceKind(this: *mut core::ffi::c_void, kind: *mut TabletDeviceKind) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTablet2", "DeviceKind", 7usize, |this| ((**(this as *const *const IInkTablet2_Vtbl)).DeviceKind)(this, kind), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DeviceKind }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkTablet2 as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkTablet3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsMultiTouch(this: *mut core::ffi::c_void, pismultitouch: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTablet3", "IsMultiTouch", 7usize, |this| ((**(this as *const *const IInkTablet3_Vtbl)).IsMultiTouch)(this, pismultitouch), |result| Some(*result))
+        }
+        unsafe extern "system" fn MaximumCursors(this: *mut core::ffi::c_void, pmaximumcursors: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTablet3", "MaximumCursors", 8usize, |this| ((**(this as *const *const IInkTablet3_Vtbl)).MaximumCursors)(this, pmaximumcursors), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsMultiTouch, MaximumCursors }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkTablet3 as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkTablets_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, count: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTablets", "Count", 7usize, |this| ((**(this as *const *const IInkTablets_Vtbl)).Count)(this, count), |result| Some(*result))
+        }
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, _newenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTablets", "_NewEnum", 8usize, |this| ((**(this as *const *const IInkTablets_Vtbl))._NewEnum)(this, _newenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn DefaultTablet(this: *mut core::ffi::c_void, defaulttablet: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTablets", "DefaultTablet", 9usize, |this| ((**(this as *const *const IInkTablets_Vtbl)).DefaultTablet)(this, defaulttablet), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, index: i32, tablet: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTablets", "Item", 10usize, |this| ((**(this as *const *const IInkTablets_Vtbl)).Item)(this, index, tablet), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPacketPropertySupported(this: *mut core::ffi::c_void, packetpropertyname: core::mem::MaybeUninit<windows_core::BSTR>, supported: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTablets", "IsPacketPropertySupported", 11usize, |this| ((**(this as *const *const IInkTablets_Vtbl)).IsPacketPropertySupported)(this, packetpropertyname, supported), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Count,
+            _NewEnum,
+            #[cfg(feature = "Win32_System_Com")]
+            DefaultTablet,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            DefaultTablet: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Item,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Item: 0,
+            IsPacketPropertySupported,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkTablets as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_System_Com"))]
+impl windows_core::imp::ProxyVtable for IInkTransform_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTransform", "Reset", 7usize, |this| ((**(this as *const *const IInkTransform_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Translate(this: *mut core::ffi::c_void, horizontalcomponent: f32, verticalcomponent: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTransform", "Translate", 8usize, |this| ((**(this as *const *const IInkTransform_Vtbl)).Translate)(this, horizontalcomponent, verticalcomponent), |result| Some(*result))
+        }
+        unsafe extern "system" fn Rotate(this: *mut core::ffi::c_void, degrees: f32, x: f32, y: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTransform", "Rotate", 9usize, |this| ((**(this as *const *const IInkTransform_Vtbl)).Rotate)(this, degrees, x, y), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reflect(this: *mut core::ffi::c_void, horizontally: super::super::Foundation::VARIANT_BOOL, vertically: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTransform", "Reflect", 10usize, |this| ((**(this as *const *const IInkTransform_Vtbl)).Reflect)(this, horizontally, vertically), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shear(this: *mut core::ffi::c_void, horizontalcomponent: f32, verticalcomponent: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTransform", "Shear", 11usize, |this| ((**(this as *const *const IInkTransform_Vtbl)).Shear)(this, horizontalcomponent, verticalcomponent), |result| Some(*result))
+        }
+        unsafe extern "system" fn ScaleTransform(this: *mut core::ffi::c_void, horizontalmultiplier: f32, verticalmultiplier: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTransform", "ScaleTransform", 12usize, |this| ((**(this as *const *const IInkTransform_Vtbl)).ScaleTransform)(this, horizontalmultiplier, verticalmultiplier), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransform(this: *mut core::ffi::c_void, em11: *mut f32, em12: *mut f32, em21: *mut f32, em22: *mut f32, edx: *mut f32, edy: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTransform", "GetTransform", 13usize, |this| ((**(this as *const *const IInkTransform_Vtbl)).GetTransform)(this, em11, em12, em21, em22, edx, edy), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTransform(this: *mut core::ffi::c_void, em11: f32, em12: f32, em21: f32, em22: f32, edx: f32, edy: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTransform", "SetTransform", 14usize, |this| ((**(this as *const *const IInkTransform_Vtbl)).SetTransform)(this, em11, em12, em21, em22, edx, edy), |result| Some(*result))
+        }
+        unsafe extern "system" fn eM11(this: *mut core::ffi::c_void, value: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTransform", "eM11", 15usize, |this| ((**(this as *const *const IInkTransform_Vtbl)).eM11)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SeteM11(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTransform", "SeteM11", 16usize, |this| ((**(this as *const *const IInkTransform_Vtbl)).SeteM11)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn eM12(this: *mut core::ffi::c_void, value: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTransform", "eM12", 17usize, |this| ((**(this as *const *const IInkTransform_Vtbl)).eM12)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SeteM12(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTransform", "SeteM12", 18usize, |this| ((**(this as *const *const IInkTransform_Vtbl)).SeteM12)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn eM21(this: *mut core::ffi::c_void, value: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTransform", "eM21", 19usize, |this| ((**(this as *const *const IInkTransform_Vtbl)).eM21)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SeteM21(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTransform", "SeteM21", 20usize, |this| ((**(this as *const *const IInkTransform_Vtbl)).SeteM21)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn eM22(this: *mut core::ffi::c_void, value: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTransform", "eM22", 21usize, |this| ((**(this as *const *const IInkTransform_Vtbl)).eM22)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SeteM22(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTransform", "SeteM22", 22usize, |this| ((**(this as *const *const IInkTransform_Vtbl)).SeteM22)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn eDx(this: *mut core::ffi::c_void, value: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTransform", "eDx", 23usize, |this| ((**(this as *const *const IInkTransform_Vtbl)).eDx)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SeteDx(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTransform", "SeteDx", 24usize, |this| ((**(this as *const *const IInkTransform_Vtbl)).SeteDx)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn eDy(this: *mut core::ffi::c_void, value: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTransform", "eDy", 25usize, |this| ((**(this as *const *const IInkTransform_Vtbl)).eDy)(this, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn SeteDy(this: *mut core::ffi::c_void, value: f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTransform", "SeteDy", 26usize, |this| ((**(this as *const *const IInkTransform_Vtbl)).SeteDy)(this, value), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn Data(this: *mut core::ffi::c_void, xform: *mut super::super::Graphics::Gdi::XFORM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTransform", "Data", 27usize, |this| ((**(this as *const *const IInkTransform_Vtbl)).Data)(this, xform), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn SetData(this: *mut core::ffi::c_void, xform: super::super::Graphics::Gdi::XFORM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkTransform", "SetData", 28usize, |this| ((**(this as *const *const IInkTransform_Vtbl)).SetData)(this, xform), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Reset,
+            Translate,
+            Rotate,
+            Reflect,
+            Shear,
+            ScaleTransform,
+            GetTransform,
+            SetTransform,
+            eM11,
+            SeteM11,
+            eM12,
+            SeteM12,
+            eM21,
+            SeteM21,
+            eM22,
+            SeteM22,
+            eDx,
+            SeteDx,
+            eDy,
+            SeteDy,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            Data,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            Data: 0,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            SetData,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            SetData: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkTransform as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkWordList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddWord(this: *mut core::ffi::c_void, newword: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkWordList", "AddWord", 7usize, |this| ((**(this as *const *const IInkWordList_Vtbl)).AddWord)(this, newword), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveWord(this: *mut core::ffi::c_void, removeword: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkWordList", "RemoveWord", 8usize, |this| ((**(this as *const *const IInkWordList_Vtbl)).RemoveWord)(this, removeword), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Merge(this: *mut core::ffi::c_void, mergewordlist: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkWordList", "Merge", 9usize, |this| ((**(this as *const *const IInkWordList_Vtbl)).Merge)(this, mergewordlist), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AddWord,
+            RemoveWord,
+            #[cfg(feature = "Win32_System_Com")]
+            Merge,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Merge: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkWordList as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IInkWordList2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddWords(this: *mut core::ffi::c_void, newwords: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInkWordList2", "AddWords", 7usize, |this| ((**(this as *const *const IInkWordList2_Vtbl)).AddWords)(this, newwords), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddWords }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInkWordList2 as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInputPanelWindowHandle_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AttachedEditWindow32(this: *mut core::ffi::c_void, attachededitwindow: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputPanelWindowHandle", "AttachedEditWindow32", 3usize, |this| ((**(this as *const *const IInputPanelWindowHandle_Vtbl)).AttachedEditWindow32)(this, attachededitwindow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAttachedEditWindow32(this: *mut core::ffi::c_void, attachededitwindow: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputPanelWindowHandle", "SetAttachedEditWindow32", 4usize, |this| ((**(this as *const *const IInputPanelWindowHandle_Vtbl)).SetAttachedEditWindow32)(this, attachededitwindow), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttachedEditWindow64(this: *mut core::ffi::c_void, attachededitwindow: *mut i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputPanelWindowHandle", "AttachedEditWindow64", 5usize, |this| ((**(this as *const *const IInputPanelWindowHandle_Vtbl)).AttachedEditWindow64)(this, attachededitwindow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAttachedEditWindow64(this: *mut core::ffi::c_void, attachededitwindow: i64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInputPanelWindowHandle", "SetAttachedEditWindow64", 6usize, |this| ((**(this as *const *const IInputPanelWindowHandle_Vtbl)).SetAttachedEditWindow64)(this, attachededitwindow), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AttachedEditWindow32,
+            SetAttachedEditWindow32,
+            AttachedEditWindow64,
+            SetAttachedEditWindow64,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInputPanelWindowHandle as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+impl windows_core::imp::ProxyVtable for IMathInputControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Show(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMathInputControl", "Show", 7usize, |this| ((**(this as *const *const IMathInputControl_Vtbl)).Show)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Hide(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMathInputControl", "Hide", 8usize, |this| ((**(this as *const *const IMathInputControl_Vtbl)).Hide)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsVisible(this: *mut core::ffi::c_void, pvbshown: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMathInputControl", "IsVisible", 9usize, |this| ((**(this as *const *const IMathInputControl_Vtbl)).IsVisible)(this, pvbshown), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPosition(this: *mut core::ffi::c_void, left: *mut i32, top: *mut i32, right: *mut i32, bottom: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMathInputControl", "GetPosition", 10usize, |this| ((**(this as *const *const IMathInputControl_Vtbl)).GetPosition)(this, left, top, right, bottom), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPosition(this: *mut core::ffi::c_void, left: i32, top: i32, right: i32, bottom: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMathInputControl", "SetPosition", 11usize, |this| ((**(this as *const *const IMathInputControl_Vtbl)).SetPosition)(this, left, top, right, bottom), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMathInputControl", "Clear", 12usize, |this| ((**(this as *const *const IMathInputControl_Vtbl)).Clear)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCustomPaint(this: *mut core::ffi::c_void, element: i32, paint: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMathInputControl", "SetCustomPaint", 13usize, |this| ((**(this as *const *const IMathInputControl_Vtbl)).SetCustomPaint)(this, element, paint), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCaptionText(this: *mut core::ffi::c_void, captiontext: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMathInputControl", "SetCaptionText", 14usize, |this| ((**(this as *const *const IMathInputControl_Vtbl)).SetCaptionText)(this, captiontext), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn LoadInk(this: *mut core::ffi::c_void, ink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMathInputControl", "LoadInk", 15usize, |this| ((**(this as *const *const IMathInputControl_Vtbl)).LoadInk)(this, ink), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOwnerWindow(this: *mut core::ffi::c_void, ownerwindow: isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMathInputControl", "SetOwnerWindow", 16usize, |this| ((**(this as *const *const IMathInputControl_Vtbl)).SetOwnerWindow)(this, ownerwindow), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableExtendedButtons(this: *mut core::ffi::c_void, extended: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMathInputControl", "EnableExtendedButtons", 17usize, |this| ((**(this as *const *const IMathInputControl_Vtbl)).EnableExtendedButtons)(this, extended), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreviewHeight(this: *mut core::ffi::c_void, height: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMathInputControl", "GetPreviewHeight", 18usize, |this| ((**(this as *const *const IMathInputControl_Vtbl)).GetPreviewHeight)(this, height), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreviewHeight(this: *mut core::ffi::c_void, height: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMathInputControl", "SetPreviewHeight", 19usize, |this| ((**(this as *const *const IMathInputControl_Vtbl)).SetPreviewHeight)(this, height), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableAutoGrow(this: *mut core::ffi::c_void, autogrow: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMathInputControl", "EnableAutoGrow", 20usize, |this| ((**(this as *const *const IMathInputControl_Vtbl)).EnableAutoGrow)(this, autogrow), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddFunctionName(this: *mut core::ffi::c_void, functionname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMathInputControl", "AddFunctionName", 21usize, |this| ((**(this as *const *const IMathInputControl_Vtbl)).AddFunctionName)(this, functionname), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveFunctionName(this: *mut core::ffi::c_void, functionname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMathInputControl", "RemoveFunctionName", 22usize, |this| ((**(this as *const *const IMathInputControl_Vtbl)).RemoveFunctionName)(this, functionname), |result| Some(*result))
+        }
+        #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+        unsafe extern "system" fn GetHoverIcon(this: *mut core::ffi::c_void, hoverimage: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMathInputControl", "GetHoverIcon", 23usize, |this| ((**(this as *const *const IMathInputControl_Vtbl)).GetHoverIcon)(this, hoverimage), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Show,
+            Hide,
+            IsVisible,
+            GetPosition,
+            SetPosition,
+            Clear,
+            SetCustomPaint,
+            SetCaptionText,
+            #[cfg(feature = "Win32_System_Com")]
+            LoadInk,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            LoadInk: 0,
+            SetOwnerWindow,
+            EnableExtendedButtons,
+            GetPreviewHeight,
+            SetPreviewHeight,
+            EnableAutoGrow,
+            AddFunctionName,
+            RemoveFunctionName,
+            #[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Ole"))]
+            GetHoverIcon,
+            #[cfg(not(all(feature = "Win32_System_Com", feature = "Win32_System_Ole")))]
+            GetHoverIcon: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMathInputControl as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPenInputPanel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Busy(this: *mut core::ffi::c_void, busy: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenInputPanel", "Busy", 7usize, |this| ((**(this as *const *const IPenInputPanel_Vtbl)).Busy)(this, busy), |result| Some(*result))
+        }
+        unsafe extern "system" fn Factoid(this: *mut core::ffi::c_void, factoid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenInputPanel", "Factoid", 8usize, |this| ((**(this as *const *const IPenInputPanel_Vtbl)).Factoid)(this, factoid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFactoid(this: *mut core::ffi::c_void, factoid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenInputPanel", "SetFactoid", 9usize, |this| ((**(this as *const *const IPenInputPanel_Vtbl)).SetFactoid)(this, factoid), |result| Some(*result))
+        }
+        unsafe extern "system" fn AttachedEditWindow(this: *mut core::ffi::c_void, attachededitwindow: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenInputPanel", "AttachedEditWindow", 10usize, |this| ((**(this as *const *const IPenInputPanel_Vtbl)).AttachedEditWindow)(this, attachededitwindow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAttachedEditWindow(this: *mut core::ffi::c_void, attachededitwindow: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenInputPanel", "SetAttachedEditWindow", 11usize, |this| ((**(this as *const *const IPenInputPanel_Vtbl)).SetAttachedEditWindow)(this, attachededitwindow), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentPanel(this: *mut core::ffi::c_void, currentpanel: *mut PanelType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenInputPanel", "CurrentPanel", 12usize, |this| ((**(this as *const *const IPenInputPanel_Vtbl)).CurrentPanel)(this, currentpanel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetCurrentPanel(this: *mut core::ffi::c_void, currentpanel: PanelType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenInputPanel", "SetCurrentPanel", 13usize, |this| ((**(this as *const *const IPenInputPanel_Vtbl)).SetCurrentPanel)(this, currentpanel), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultPanel(this: *mut core::ffi::c_void, pdefaultpanel: *mut PanelType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenInputPanel", "DefaultPanel", 14usize, |this| ((**(this as *const *const IPenInputPanel_Vtbl)).DefaultPanel)(this, pdefaultpanel), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultPanel(this: *mut core::ffi::c_void, defaultpanel: PanelType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenInputPanel", "SetDefaultPanel", 15usize, |this| ((**(this as *const *const IPenInputPanel_Vtbl)).SetDefaultPanel)(this, defaultpanel), |result| Some(*result))
+        }
+        unsafe extern "system" fn Visible(this: *mut core::ffi::c_void, visible: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenInputPanel", "Visible", 16usize, |this| ((**(this as *const *const IPenInputPanel_Vtbl)).Visible)(this, visible), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVisible(this: *mut core::ffi::c_void, visible: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenInputPanel", "SetVisible", 17usize, |this| ((**(this as *const *const IPenInputPanel_Vtbl)).SetVisible)(this, visible), |result| Some(*result))
+        }
+        unsafe extern "system" fn Top(this: *mut core::ffi::c_void, top: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenInputPanel", "Top", 18usize, |this| ((**(this as *const *const IPenInputPanel_Vtbl)).Top)(this, top), |result| Some(*result))
+        }
+        unsafe extern "system" fn Left(this: *mut core::ffi::c_void, left: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenInputPanel", "Left", 19usize, |this| ((**(this as *const *const IPenInputPanel_Vtbl)).Left)(this, left), |result| Some(*result))
+        }
+        unsafe extern "system" fn Width(this: *mut core::ffi::c_void, width: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenInputPanel", "Width", 20usize, |this| ((**(this as *const *const IPenInputPanel_Vtbl)).Width)(this, width), |result| Some(*result))
+        }
+        unsafe extern "system" fn Height(this: *mut core::ffi::c_void, height: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenInputPanel", "Height", 21usize, |this| ((**(this as *const *const IPenInputPanel_Vtbl)).Height)(this, height), |result| Some(*result))
+        }
+        unsafe extern "system" fn VerticalOffset(this: *mut core::ffi::c_void, verticaloffset: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenInputPanel", "VerticalOffset", 22usize, |this| ((**(this as *const *const IPenInputPanel_Vtbl)).VerticalOffset)(this, verticaloffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetVerticalOffset(this: *mut core::ffi::c_void, verticaloffset: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenInputPanel", "SetVerticalOffset", 23usize, |this| ((**(this as *const *const IPenInputPanel_Vtbl)).SetVerticalOffset)(this, verticaloffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn HorizontalOffset(this: *mut core::ffi::c_void, horizontaloffset: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenInputPanel", "HorizontalOffset", 24usize, |this| ((**(this as *const *const IPenInputPanel_Vtbl)).HorizontalOffset)(this, horizontaloffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHorizontalOffset(this: *mut core::ffi::c_void, horizontaloffset: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenInputPanel", "SetHorizontalOffset", 25usize, |this| ((**(this as *const *const IPenInputPanel_Vtbl)).SetHorizontalOffset)(this, horizontaloffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn AutoShow(this: *mut core::ffi::c_void, pautoshow: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenInputPanel", "AutoShow", 26usize, |this| ((**(this as *const *const IPenInputPanel_Vtbl)).AutoShow)(this, pautoshow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAutoShow(this: *mut core::ffi::c_void, autoshow: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenInputPanel", "SetAutoShow", 27usize, |this| ((**(this as *const *const IPenInputPanel_Vtbl)).SetAutoShow)(this, autoshow), |result| Some(*result))
+        }
+        unsafe extern "system" fn MoveTo(this: *mut core::ffi::c_void, left: i32, top: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenInputPanel", "MoveTo", 28usize, |this| ((**(this as *const *const IPenInputPanel_Vtbl)).MoveTo)(this, left, top), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommitPendingInput(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenInputPanel", "CommitPendingInput", 29usize, |this| ((**(this as *const *const IPenInputPanel_Vtbl)).CommitPendingInput)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Refresh(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenInputPanel", "Refresh", 30usize, |this| ((**(this as *const *const IPenInputPanel_Vtbl)).Refresh)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableTsf(this: *mut core::ffi::c_void, enable: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPenInputPanel", "EnableTsf", 31usize, |this| ((**(this as *const *const IPenInputPanel_Vtbl)).EnableTsf)(this, enable), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Busy,
+            Factoid,
+            SetFactoid,
+            AttachedEditWindow,
+            SetAttachedEditWindow,
+            CurrentPanel,
+            SetCurrentPanel,
+            DefaultPanel,
+            SetDefaultPanel,
+            Visible,
+            SetVisible,
+            Top,
+            Left,
+            Width,
+            Height,
+            VerticalOffset,
+            SetVerticalOffset,
+            HorizontalOffset,
+            SetHorizontalOffset,
+            AutoShow,
+            SetAutoShow,
+            MoveTo,
+            CommitPendingInput,
+            Refresh,
+            EnableTsf,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPenInputPanel as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IRealTimeStylus_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Enabled(this: *mut core::ffi::c_void, pfenable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "Enabled", 3usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).Enabled)(this, pfenable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, fenable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "SetEnabled", 4usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).SetEnabled)(this, fenable), |result| Some(*result))
+        }
+        unsafe extern "system" fn HWND(this: *mut core::ffi::c_void, phwnd: *mut super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "HWND", 5usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).HWND)(this, phwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHWND(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HANDLE_PTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "SetHWND", 6usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).SetHWND)(this, hwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn WindowInputRectangle(this: *mut core::ffi::c_void, prcwndinputrect: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "WindowInputRectangle", 7usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).WindowInputRectangle)(this, prcwndinputrect), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetWindowInputRectangle(this: *mut core::ffi::c_void, prcwndinputrect: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "SetWindowInputRectangle", 8usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).SetWindowInputRectangle)(this, prcwndinputrect), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddStylusSyncPlugin(this: *mut core::ffi::c_void, iindex: u32, piplugin: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "AddStylusSyncPlugin", 9usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).AddStylusSyncPlugin)(this, iindex, piplugin), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStylusSyncPlugin(this: *mut core::ffi::c_void, iindex: u32, ppiplugin: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "RemoveStylusSyncPlugin", 10usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).RemoveStylusSyncPlugin)(this, iindex, ppiplugin), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAllStylusSyncPlugins(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "RemoveAllStylusSyncPlugins", 11usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).RemoveAllStylusSyncPlugins)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStylusSyncPlugin(this: *mut core::ffi::c_void, iindex: u32, ppiplugin: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "GetStylusSyncPlugin", 12usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).GetStylusSyncPlugin)(this, iindex, ppiplugin), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStylusSyncPluginCount(this: *mut core::ffi::c_void, pcplugins: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "GetStylusSyncPluginCount", 13usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).GetStylusSyncPluginCount)(this, pcplugins), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddStylusAsyncPlugin(this: *mut core::ffi::c_void, iindex: u32, piplugin: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "AddStylusAsyncPlugin", 14usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).AddStylusAsyncPlugin)(this, iindex, piplugin), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveStylusAsyncPlugin(this: *mut core::ffi::c_void, iindex: u32, ppiplugin: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "RemoveStylusAsyncPlugin", 15usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).RemoveStylusAsyncPlugin)(this, iindex, ppiplugin), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveAllStylusAsyncPlugins(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "RemoveAllStylusAsyncPlugins", 16usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).RemoveAllStylusAsyncPlugins)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStylusAsyncPlugin(this: *mut core::ffi::c_void, iindex: u32, ppiplugin: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "GetStylusAsyncPlugin", 17usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).GetStylusAsyncPlugin)(this, iindex, ppiplugin), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStylusAsyncPluginCount(this: *mut core::ffi::c_void, pcplugins: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "GetStylusAsyncPluginCount", 18usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).GetStylusAsyncPluginCount)(this, pcplugins), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChildRealTimeStylusPlugin(this: *mut core::ffi::c_void, ppirts: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "ChildRealTimeStylusPlugin", 19usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).ChildRealTimeStylusPlugin)(this, ppirts), |result| Some(*result))
+        }
+        unsafe extern "system" fn putref_ChildRealTimeStylusPlugin(this: *mut core::ffi::c_void, pirts: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "putref_ChildRealTimeStylusPlugin", 20usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).putref_ChildRealTimeStylusPlugin)(this, pirts), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddCustomStylusDataToQueue(this: *mut core::ffi::c_void, sq: StylusQueue, pguidid: *const windows_core::GUID, cbdata: u32, pbdata: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "AddCustomStylusDataToQueue", 21usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).AddCustomStylusDataToQueue)(this, sq, pguidid, cbdata, pbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearStylusQueues(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "ClearStylusQueues", 22usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).ClearStylusQueues)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAllTabletsMode(this: *mut core::ffi::c_void, fusemouseforinput: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "SetAllTabletsMode", 23usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).SetAllTabletsMode)(this, fusemouseforinput), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetSingleTabletMode(this: *mut core::ffi::c_void, pitablet: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "SetSingleTabletMode", 24usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).SetSingleTabletMode)(this, pitablet), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetTablet(this: *mut core::ffi::c_void, ppisingletablet: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "GetTablet", 25usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).GetTablet)(this, ppisingletablet), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetTabletContextIdFromTablet(this: *mut core::ffi::c_void, pitablet: *mut core::ffi::c_void, ptcid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "GetTabletContextIdFromTablet", 26usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).GetTabletContextIdFromTablet)(this, pitablet, ptcid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetTabletFromTabletContextId(this: *mut core::ffi::c_void, tcid: u32, ppitablet: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "GetTabletFromTabletContextId", 27usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).GetTabletFromTabletContextId)(this, tcid, ppitablet), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAllTabletContextIds(this: *mut core::ffi::c_void, pctcidcount: *mut u32, pptcids: *mut *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "GetAllTabletContextIds", 28usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).GetAllTabletContextIds)(this, pctcidcount, pptcids), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetStyluses(this: *mut core::ffi::c_void, ppiinkcursors: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "GetStyluses", 29usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).GetStyluses)(this, ppiinkcursors), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetStylusForId(this: *mut core::ffi::c_void, sid: u32, ppiinkcursor: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "GetStylusForId", 30usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).GetStylusForId)(this, sid, ppiinkcursor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDesiredPacketDescription(this: *mut core::ffi::c_void, cproperties: u32, ppropertyguids: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "SetDesiredPacketDescription", 31usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).SetDesiredPacketDescription)(this, cproperties, ppropertyguids), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDesiredPacketDescription(this: *mut core::ffi::c_void, pcproperties: *mut u32, pppropertyguids: *mut *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "GetDesiredPacketDescription", 32usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).GetDesiredPacketDescription)(this, pcproperties, pppropertyguids), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPacketDescriptionData(this: *mut core::ffi::c_void, tcid: u32, pfinktodevicescalex: *mut f32, pfinktodevicescaley: *mut f32, pcpacketproperties: *mut u32, pppacketproperties: *mut *mut PACKET_PROPERTY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus", "GetPacketDescriptionData", 33usize, |this| ((**(this as *const *const IRealTimeStylus_Vtbl)).GetPacketDescriptionData)(this, tcid, pfinktodevicescalex, pfinktodevicescaley, pcpacketproperties, pppacketproperties), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Enabled,
+            SetEnabled,
+            HWND,
+            SetHWND,
+            WindowInputRectangle,
+            SetWindowInputRectangle,
+            AddStylusSyncPlugin,
+            RemoveStylusSyncPlugin,
+            RemoveAllStylusSyncPlugins,
+            GetStylusSyncPlugin,
+            GetStylusSyncPluginCount,
+            AddStylusAsyncPlugin,
+            RemoveStylusAsyncPlugin,
+            RemoveAllStylusAsyncPlugins,
+            GetStylusAsyncPlugin,
+            GetStylusAsyncPluginCount,
+            ChildRealTimeStylusPlugin,
+            putref_ChildRealTimeStylusPlugin,
+            AddCustomStylusDataToQueue,
+            ClearStylusQueues,
+            SetAllTabletsMode,
+            #[cfg(feature = "Win32_System_Com")]
+            SetSingleTabletMode,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetSingleTabletMode: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetTablet,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetTablet: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetTabletContextIdFromTablet,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetTabletContextIdFromTablet: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetTabletFromTabletContextId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetTabletFromTabletContextId: 0,
+            GetAllTabletContextIds,
+            #[cfg(feature = "Win32_System_Com")]
+            GetStyluses,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetStyluses: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            GetStylusForId,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetStylusForId: 0,
+            SetDesiredPacketDescription,
+            GetDesiredPacketDescription,
+            GetPacketDescriptionData,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRealTimeStylus as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRealTimeStylus2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FlicksEnabled(this: *mut core::ffi::c_void, pfenable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus2", "FlicksEnabled", 3usize, |this| ((**(this as *const *const IRealTimeStylus2_Vtbl)).FlicksEnabled)(this, pfenable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFlicksEnabled(this: *mut core::ffi::c_void, fenable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus2", "SetFlicksEnabled", 4usize, |this| ((**(this as *const *const IRealTimeStylus2_Vtbl)).SetFlicksEnabled)(this, fenable), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FlicksEnabled, SetFlicksEnabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRealTimeStylus2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRealTimeStylus3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MultiTouchEnabled(this: *mut core::ffi::c_void, pfenable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus3", "MultiTouchEnabled", 3usize, |this| ((**(this as *const *const IRealTimeStylus3_Vtbl)).MultiTouchEnabled)(this, pfenable), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMultiTouchEnabled(this: *mut core::ffi::c_void, fenable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylus3", "SetMultiTouchEnabled", 4usize, |this| ((**(this as *const *const IRealTimeStylus3_Vtbl)).SetMultiTouchEnabled)(this, fenable), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MultiTouchEnabled, SetMultiTouchEnabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRealTimeStylus3 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IRealTimeStylusSynchronization_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AcquireLock(this: *mut core::ffi::c_void, lock: RealTimeStylusLockType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylusSynchronization", "AcquireLock", 3usize, |this| ((**(this as *const *const IRealTimeStylusSynchronization_Vtbl)).AcquireLock)(this, lock), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseLock(this: *mut core::ffi::c_void, lock: RealTimeStylusLockType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IRealTimeStylusSynchronization", "ReleaseLock", 4usize, |this| ((**(this as *const *const IRealTimeStylusSynchronization_Vtbl)).ReleaseLock)(this, lock), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AcquireLock, ReleaseLock }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IRealTimeStylusSynchronization as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISketchInk_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISketchInk as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IStrokeBuilder_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreateStroke(this: *mut core::ffi::c_void, cpktbufflength: u32, ppackets: *const i32, cpacketproperties: u32, ppacketproperties: *const PACKET_PROPERTY, finktodevicescalex: f32, finktodevicescaley: f32, ppiinkstroke: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStrokeBuilder", "CreateStroke", 3usize, |this| ((**(this as *const *const IStrokeBuilder_Vtbl)).CreateStroke)(this, cpktbufflength, ppackets, cpacketproperties, ppacketproperties, finktodevicescalex, finktodevicescaley, ppiinkstroke), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn BeginStroke(this: *mut core::ffi::c_void, tcid: u32, sid: u32, ppacket: *const i32, cpacketproperties: u32, ppacketproperties: *const PACKET_PROPERTY, finktodevicescalex: f32, finktodevicescaley: f32, ppiinkstroke: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStrokeBuilder", "BeginStroke", 4usize, |this| ((**(this as *const *const IStrokeBuilder_Vtbl)).BeginStroke)(this, tcid, sid, ppacket, cpacketproperties, ppacketproperties, finktodevicescalex, finktodevicescaley, ppiinkstroke), |result| Some(*result))
+        }
+        unsafe extern "system" fn AppendPackets(this: *mut core::ffi::c_void, tcid: u32, sid: u32, cpktbufflength: u32, ppackets: *const i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStrokeBuilder", "AppendPackets", 5usize, |this| ((**(this as *const *const IStrokeBuilder_Vtbl)).AppendPackets)(this, tcid, sid, cpktbufflength, ppackets), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EndStroke(this: *mut core::ffi::c_void, tcid: u32, sid: u32, ppiinkstroke: *mut *mut core::ffi::c_void, pdirtyrect: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStrokeBuilder", "EndStroke", 6usize, |this| ((**(this as *const *const IStrokeBuilder_Vtbl)).EndStroke)(this, tcid, sid, ppiinkstroke, pdirtyrect), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Ink(this: *mut core::ffi::c_void, ppiinkobj: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStrokeBuilder", "Ink", 7usize, |this| ((**(this as *const *const IStrokeBuilder_Vtbl)).Ink)(this, ppiinkobj), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn putref_Ink(this: *mut core::ffi::c_void, piinkobj: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStrokeBuilder", "putref_Ink", 8usize, |this| ((**(this as *const *const IStrokeBuilder_Vtbl)).putref_Ink)(this, piinkobj), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            CreateStroke,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreateStroke: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            BeginStroke,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            BeginStroke: 0,
+            AppendPackets,
+            #[cfg(feature = "Win32_System_Com")]
+            EndStroke,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EndStroke: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Ink,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Ink: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            putref_Ink,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            putref_Ink: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStrokeBuilder as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IStylusAsyncPlugin_Vtbl {
+    const PROXY: Self = { Self { base__: <IStylusPlugin_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStylusAsyncPlugin as windows_core::Interface>::IID || <IStylusPlugin_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IStylusPlugin_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RealTimeStylusEnabled(this: *mut core::ffi::c_void, pirtssrc: *mut core::ffi::c_void, ctcidcount: u32, ptcids: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStylusPlugin", "RealTimeStylusEnabled", 3usize, |this| ((**(this as *const *const IStylusPlugin_Vtbl)).RealTimeStylusEnabled)(this, pirtssrc, ctcidcount, ptcids), |result| Some(*result))
+        }
+        unsafe extern "system" fn RealTimeStylusDisabled(this: *mut core::ffi::c_void, pirtssrc: *mut core::ffi::c_void, ctcidcount: u32, ptcids: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStylusPlugin", "RealTimeStylusDisabled", 4usize, |this| ((**(this as *const *const IStylusPlugin_Vtbl)).RealTimeStylusDisabled)(this, pirtssrc, ctcidcount, ptcids), |result| Some(*result))
+        }
+        unsafe extern "system" fn StylusInRange(this: *mut core::ffi::c_void, pirtssrc: *mut core::ffi::c_void, tcid: u32, sid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStylusPlugin", "StylusInRange", 5usize, |this| ((**(this as *const *const IStylusPlugin_Vtbl)).StylusInRange)(this, pirtssrc, tcid, sid), |result| Some(*result))
+        }
+        unsafe extern "system" fn StylusOutOfRange(this: *mut core::ffi::c_void, pirtssrc: *mut core::ffi::c_void, tcid: u32, sid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStylusPlugin", "StylusOutOfRange", 6usize, |this| ((**(this as *const *const IStylusPlugin_Vtbl)).StylusOutOfRange)(this, pirtssrc, tcid, sid), |result| Some(*result))
+        }
+        unsafe extern "system" fn StylusDown(this: *mut core::ffi::c_void, pirtssrc: *mut core::ffi::c_void, pstylusinfo: *const StylusInfo, cpropcountperpkt: u32, ppacket: *const i32, ppinoutpkt: *mut *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStylusPlugin", "StylusDown", 7usize, |this| ((**(this as *const *const IStylusPlugin_Vtbl)).StylusDown)(this, pirtssrc, pstylusinfo, cpropcountperpkt, ppacket, ppinoutpkt), |result| Some(*result))
+        }
+        unsafe extern "system" fn StylusUp(this: *mut core::ffi::c_void, pirtssrc: *mut core::ffi::c_void, pstylusinfo: *const StylusInfo, cpropcountperpkt: u32, ppacket: *const i32, ppinoutpkt: *mut *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStylusPlugin", "StylusUp", 8usize, |this| ((**(this as *const *const IStylusPlugin_Vtbl)).StylusUp)(this, pirtssrc, pstylusinfo, cpropcountperpkt, ppacket, ppinoutpkt), |result| Some(*result))
+        }
+        unsafe extern "system" fn StylusButtonDown(this: *mut core::ffi::c_void, pirtssrc: *mut core::ffi::c_void, sid: u32, pguidstylusbutton: *const windows_core::GUID, pstyluspos: *mut super::super::Foundation::POINT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStylusPlugin", "StylusButtonDown", 9usize, |this| ((**(this as *const *const IStylusPlugin_Vtbl)).StylusButtonDown)(this, pirtssrc, sid, pguidstylusbutton, pstyluspos), |result| Some(*result))
+        }
+        unsafe extern "system" fn StylusButtonUp(this: *mut core::ffi::c_void, pirtssrc: *mut core::ffi::c_void, sid: u32, pguidstylusbutton: *const windows_core::GUID, pstyluspos: *mut super::super::Foundation::POINT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStylusPlugin", "StylusButtonUp", 10usize, |this| ((**(this as *const *const IStylusPlugin_Vtbl)).StylusButtonUp)(this, pirtssrc, sid, pguidstylusbutton, pstyluspos), |result| Some(*result))
+        }
+        unsafe extern "system" fn InAirPackets(this: *mut core::ffi::c_void, pirtssrc: *mut core::ffi::c_void, pstylusinfo: *const StylusInfo, cpktcount: u32, cpktbufflength: u32, ppackets: *const i32, pcinoutpkts: *mut u32, ppinoutpkts: *mut *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStylusPlugin", "InAirPackets", 11usize, |this| ((**(this as *const *const IStylusPlugin_Vtbl)).InAirPackets)(this, pirtssrc, pstylusinfo, cpktcount, cpktbufflength, ppackets, pcinoutpkts, ppinoutpkts), |result| Some(*result))
+        }
+        unsafe extern "system" fn Packets(this: *mut core::ffi::c_void, pirtssrc: *mut core::ffi::c_void, pstylusinfo: *const StylusInfo, cpktcount: u32, cpktbufflength: u32, ppackets: *const i32, pcinoutpkts: *mut u32, ppinoutpkts: *mut *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStylusPlugin", "Packets", 12usize, |this| ((**(this as *const *const IStylusPlugin_Vtbl)).Packets)(this, pirtssrc, pstylusinfo, cpktcount, cpktbufflength, ppackets, pcinoutpkts, ppinoutpkts), |result| Some(*result))
+        }
+        unsafe extern "system" fn CustomStylusDataAdded(this: *mut core::ffi::c_void, pirtssrc: *mut core::ffi::c_void, pguidid: *const windows_core::GUID, cbdata: u32, pbdata: *const u8) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStylusPlugin", "CustomStylusDataAdded", 13usize, |this| ((**(this as *const *const IStylusPlugin_Vtbl)).CustomStylusDataAdded)(this, pirtssrc, pguidid, cbdata, pbdata), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemEvent(this: *mut core::ffi::c_void, pirtssrc: *mut core::ffi::c_void, tcid: u32, sid: u32, event: u16, eventdata: SYSTEM_EVENT_DATA) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStylusPlugin", "SystemEvent", 14usize, |this| ((**(this as *const *const IStylusPlugin_Vtbl)).SystemEvent)(this, pirtssrc, tcid, sid, event, eventdata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn TabletAdded(this: *mut core::ffi::c_void, pirtssrc: *mut core::ffi::c_void, pitablet: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStylusPlugin", "TabletAdded", 15usize, |this| ((**(this as *const *const IStylusPlugin_Vtbl)).TabletAdded)(this, pirtssrc, pitablet), |result| Some(*result))
+        }
+        unsafe extern "system" fn TabletRemoved(this: *mut core::ffi::c_void, pirtssrc: *mut core::ffi::c_void, itabletindex: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStylusPlugin", "TabletRemoved", 16usize, |this| ((**(this as *const *const IStylusPlugin_Vtbl)).TabletRemoved)(this, pirtssrc, itabletindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn Error(this: *mut core::ffi::c_void, pirtssrc: *mut core::ffi::c_void, piplugin: *mut core::ffi::c_void, datainterest: RealTimeStylusDataInterest, hrerrorcode: windows_core::HRESULT, lptrkey: *mut isize) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStylusPlugin", "Error", 17usize, |this| ((**(this as *const *const IStylusPlugin_Vtbl)).Error)(this, pirtssrc, piplugin, datainterest, hrerrorcode, lptrkey), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateMapping(this: *mut core::ffi::c_void, pirtssrc: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStylusPlugin", "UpdateMapping", 18usize, |this| ((**(this as *const *const IStylusPlugin_Vtbl)).UpdateMapping)(this, pirtssrc), |result| Some(*result))
+        }
+        unsafe extern "system" fn DataInterest(this: *mut core::ffi::c_void, pdatainterest: *mut RealTimeStylusDataInterest) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IStylusPlugin", "DataInterest", 19usize, |this| ((**(this as *const *const IStylusPlugin_Vtbl)).DataInterest)(this, pdatainterest), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RealTimeStylusEnabled,
+            RealTimeStylusDisabled,
+            StylusInRange,
+            StylusOutOfRange,
+            StylusDown,
+            StylusUp,
+            StylusButtonDown,
+            StylusButtonUp,
+            InAirPackets,
+            Packets,
+            CustomStylusDataAdded,
+            SystemEvent,
+            #[cfg(feature = "Win32_System_Com")]
+            TabletAdded,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            TabletAdded: 0,
+            TabletRemoved,
+            Error,
+            UpdateMapping,
+            DataInterest,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStylusPlugin as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IStylusSyncPlugin_Vtbl {
+    const PROXY: Self = { Self { base__: <IStylusPlugin_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IStylusSyncPlugin as windows_core::Interface>::IID || <IStylusPlugin_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITextInputPanel_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AttachedEditWindow(this: *mut core::ffi::c_void, attachededitwindow: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanel", "AttachedEditWindow", 3usize, |this| ((**(this as *const *const ITextInputPanel_Vtbl)).AttachedEditWindow)(this, attachededitwindow), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAttachedEditWindow(this: *mut core::ffi::c_void, attachededitwindow: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanel", "SetAttachedEditWindow", 4usize, |this| ((**(this as *const *const ITextInputPanel_Vtbl)).SetAttachedEditWindow)(this, attachededitwindow), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentInteractionMode(this: *mut core::ffi::c_void, currentinteractionmode: *mut InteractionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanel", "CurrentInteractionMode", 5usize, |this| ((**(this as *const *const ITextInputPanel_Vtbl)).CurrentInteractionMode)(this, currentinteractionmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultInPlaceState(this: *mut core::ffi::c_void, state: *mut InPlaceState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanel", "DefaultInPlaceState", 6usize, |this| ((**(this as *const *const ITextInputPanel_Vtbl)).DefaultInPlaceState)(this, state), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultInPlaceState(this: *mut core::ffi::c_void, state: InPlaceState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanel", "SetDefaultInPlaceState", 7usize, |this| ((**(this as *const *const ITextInputPanel_Vtbl)).SetDefaultInPlaceState)(this, state), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentInPlaceState(this: *mut core::ffi::c_void, state: *mut InPlaceState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanel", "CurrentInPlaceState", 8usize, |this| ((**(this as *const *const ITextInputPanel_Vtbl)).CurrentInPlaceState)(this, state), |result| Some(*result))
+        }
+        unsafe extern "system" fn DefaultInputArea(this: *mut core::ffi::c_void, area: *mut PanelInputArea) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanel", "DefaultInputArea", 9usize, |this| ((**(this as *const *const ITextInputPanel_Vtbl)).DefaultInputArea)(this, area), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultInputArea(this: *mut core::ffi::c_void, area: PanelInputArea) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanel", "SetDefaultInputArea", 10usize, |this| ((**(this as *const *const ITextInputPanel_Vtbl)).SetDefaultInputArea)(this, area), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentInputArea(this: *mut core::ffi::c_void, area: *mut PanelInputArea) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanel", "CurrentInputArea", 11usize, |this| ((**(this as *const *const ITextInputPanel_Vtbl)).CurrentInputArea)(this, area), |result| Some(*result))
+        }
+        unsafe extern "system" fn CurrentCorrectionMode(this: *mut core::ffi::c_void, mode: *mut CorrectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanel", "CurrentCorrectionMode", 12usize, |this| ((**(this as *const *const ITextInputPanel_Vtbl)).CurrentCorrectionMode)(this, mode), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreferredInPlaceDirection(this: *mut core::ffi::c_void, direction: *mut InPlaceDirection) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanel", "PreferredInPlaceDirection", 13usize, |this| ((**(this as *const *const ITextInputPanel_Vtbl)).PreferredInPlaceDirection)(this, direction), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreferredInPlaceDirection(this: *mut core::ffi::c_void, direction: InPlaceDirection) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanel", "SetPreferredInPlaceDirection", 14usize, |this| ((**(this as *const *const ITextInputPanel_Vtbl)).SetPreferredInPlaceDirection)(this, direction), |result| Some(*result))
+        }
+        unsafe extern "system" fn ExpandPostInsertionCorrection(this: *mut core::ffi::c_void, expand: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanel", "ExpandPostInsertionCorrection", 15usize, |this| ((**(this as *const *const ITextInputPanel_Vtbl)).ExpandPostInsertionCorrection)(this, expand), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExpandPostInsertionCorrection(this: *mut core::ffi::c_void, expand: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanel", "SetExpandPostInsertionCorrection", 16usize, |this| ((**(this as *const *const ITextInputPanel_Vtbl)).SetExpandPostInsertionCorrection)(this, expand), |result| Some(*result))
+        }
+        unsafe extern "system" fn InPlaceVisibleOnFocus(this: *mut core::ffi::c_void, visible: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanel", "InPlaceVisibleOnFocus", 17usize, |this| ((**(this as *const *const ITextInputPanel_Vtbl)).InPlaceVisibleOnFocus)(this, visible), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInPlaceVisibleOnFocus(this: *mut core::ffi::c_void, visible: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanel", "SetInPlaceVisibleOnFocus", 18usize, |this| ((**(this as *const *const ITextInputPanel_Vtbl)).SetInPlaceVisibleOnFocus)(this, visible), |result| Some(*result))
+        }
+        unsafe extern "system" fn InPlaceBoundingRectangle(this: *mut core::ffi::c_void, boundingrectangle: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanel", "InPlaceBoundingRectangle", 19usize, |this| ((**(this as *const *const ITextInputPanel_Vtbl)).InPlaceBoundingRectangle)(this, boundingrectangle), |result| Some(*result))
+        }
+        unsafe extern "system" fn PopUpCorrectionHeight(this: *mut core::ffi::c_void, height: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanel", "PopUpCorrectionHeight", 20usize, |this| ((**(this as *const *const ITextInputPanel_Vtbl)).PopUpCorrectionHeight)(this, height), |result| Some(*result))
+        }
+        unsafe extern "system" fn PopDownCorrectionHeight(this: *mut core::ffi::c_void, height: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanel", "PopDownCorrectionHeight", 21usize, |this| ((**(this as *const *const ITextInputPanel_Vtbl)).PopDownCorrectionHeight)(this, height), |result| Some(*result))
+        }
+        unsafe extern "system" fn CommitPendingInput(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanel", "CommitPendingInput", 22usize, |this| ((**(this as *const *const ITextInputPanel_Vtbl)).CommitPendingInput)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInPlaceVisibility(this: *mut core::ffi::c_void, visible: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanel", "SetInPlaceVisibility", 23usize, |this| ((**(this as *const *const ITextInputPanel_Vtbl)).SetInPlaceVisibility)(this, visible), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInPlacePosition(this: *mut core::ffi::c_void, xposition: i32, yposition: i32, position: CorrectionPosition) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanel", "SetInPlacePosition", 24usize, |this| ((**(this as *const *const ITextInputPanel_Vtbl)).SetInPlacePosition)(this, xposition, yposition, position), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInPlaceHoverTargetPosition(this: *mut core::ffi::c_void, xposition: i32, yposition: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanel", "SetInPlaceHoverTargetPosition", 25usize, |this| ((**(this as *const *const ITextInputPanel_Vtbl)).SetInPlaceHoverTargetPosition)(this, xposition, yposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn Advise(this: *mut core::ffi::c_void, eventsink: *mut core::ffi::c_void, eventmask: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanel", "Advise", 26usize, |this| ((**(this as *const *const ITextInputPanel_Vtbl)).Advise)(this, eventsink, eventmask), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unadvise(this: *mut core::ffi::c_void, eventsink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanel", "Unadvise", 27usize, |this| ((**(this as *const *const ITextInputPanel_Vtbl)).Unadvise)(this, eventsink), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AttachedEditWindow,
+            SetAttachedEditWindow,
+            CurrentInteractionMode,
+            DefaultInPlaceState,
+            SetDefaultInPlaceState,
+            CurrentInPlaceState,
+            DefaultInputArea,
+            SetDefaultInputArea,
+            CurrentInputArea,
+            CurrentCorrectionMode,
+            PreferredInPlaceDirection,
+            SetPreferredInPlaceDirection,
+            ExpandPostInsertionCorrection,
+            SetExpandPostInsertionCorrection,
+            InPlaceVisibleOnFocus,
+            SetInPlaceVisibleOnFocus,
+            InPlaceBoundingRectangle,
+            PopUpCorrectionHeight,
+            PopDownCorrectionHeight,
+            CommitPendingInput,
+            SetInPlaceVisibility,
+            SetInPlacePosition,
+            SetInPlaceHoverTargetPosition,
+            Advise,
+            Unadvise,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextInputPanel as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITextInputPanelEventSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InPlaceStateChanging(this: *mut core::ffi::c_void, oldinplacestate: InPlaceState, newinplacestate: InPlaceState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanelEventSink", "InPlaceStateChanging", 3usize, |this| ((**(this as *const *const ITextInputPanelEventSink_Vtbl)).InPlaceStateChanging)(this, oldinplacestate, newinplacestate), |result| Some(*result))
+        }
+        unsafe extern "system" fn InPlaceStateChanged(this: *mut core::ffi::c_void, oldinplacestate: InPlaceState, newinplacestate: InPlaceState) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanelEventSink", "InPlaceStateChanged", 4usize, |this| ((**(this as *const *const ITextInputPanelEventSink_Vtbl)).InPlaceStateChanged)(this, oldinplacestate, newinplacestate), |result| Some(*result))
+        }
+        unsafe extern "system" fn InPlaceSizeChanging(this: *mut core::ffi::c_void, oldboundingrectangle: super::super::Foundation::RECT, newboundingrectangle: super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanelEventSink", "InPlaceSizeChanging", 5usize, |this| ((**(this as *const *const ITextInputPanelEventSink_Vtbl)).InPlaceSizeChanging)(this, oldboundingrectangle, newboundingrectangle), |result| Some(*result))
+        }
+        unsafe extern "system" fn InPlaceSizeChanged(this: *mut core::ffi::c_void, oldboundingrectangle: super::super::Foundation::RECT, newboundingrectangle: super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanelEventSink", "InPlaceSizeChanged", 6usize, |this| ((**(this as *const *const ITextInputPanelEventSink_Vtbl)).InPlaceSizeChanged)(this, oldboundingrectangle, newboundingrectangle), |result| Some(*result))
+        }
+        unsafe extern "system" fn InputAreaChanging(this: *mut core::ffi::c_void, oldinputarea: PanelInputArea, newinputarea: PanelInputArea) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanelEventSink", "InputAreaChanging", 7usize, |this| ((**(this as *const *const ITextInputPanelEventSink_Vtbl)).InputAreaChanging)(this, oldinputarea, newinputarea), |result| Some(*result))
+        }
+        unsafe extern "system" fn InputAreaChanged(this: *mut core::ffi::c_void, oldinputarea: PanelInputArea, newinputarea: PanelInputArea) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanelEventSink", "InputAreaChanged", 8usize, |this| ((**(this as *const *const ITextInputPanelEventSink_Vtbl)).InputAreaChanged)(this, oldinputarea, newinputarea), |result| Some(*result))
+        }
+        unsafe extern "system" fn CorrectionModeChanging(this: *mut core::ffi::c_void, oldcorrectionmode: CorrectionMode, newcorrectionmode: CorrectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanelEventSink", "CorrectionModeChanging", 9usize, |this| ((**(this as *const *const ITextInputPanelEventSink_Vtbl)).CorrectionModeChanging)(this, oldcorrectionmode, newcorrectionmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn CorrectionModeChanged(this: *mut core::ffi::c_void, oldcorrectionmode: CorrectionMode, newcorrectionmode: CorrectionMode) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanelEventSink", "CorrectionModeChanged", 10usize, |this| ((**(this as *const *const ITextInputPanelEventSink_Vtbl)).CorrectionModeChanged)(this, oldcorrectionmode, newcorrectionmode), |result| Some(*result))
+        }
+        unsafe extern "system" fn InPlaceVisibilityChanging(this: *mut core::ffi::c_void, oldvisible: super::super::Foundation::BOOL, newvisible: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanelEventSink", "InPlaceVisibilityChanging", 11usize, |this| ((**(this as *const *const ITextInputPanelEventSink_Vtbl)).InPlaceVisibilityChanging)(this, oldvisible, newvisible), |result| Some(*result))
+        }
+        unsafe extern "system" fn InPlaceVisibilityChanged(this: *mut core::ffi::c_void, oldvisible: super::super::Foundation::BOOL, newvisible: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanelEventSink", "InPlaceVisibilityChanged", 12usize, |this| ((**(this as *const *const ITextInputPanelEventSink_Vtbl)).InPlaceVisibilityChanged)(this, oldvisible, newvisible), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn TextInserting(this: *mut core::ffi::c_void, ink: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanelEventSink", "TextInserting", 13usize, |this| ((**(this as *const *const ITextInputPanelEventSink_Vtbl)).TextInserting)(this, ink), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn TextInserted(this: *mut core::ffi::c_void, ink: *const super::super::System::Com::SAFEARRAY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanelEventSink", "TextInserted", 14usize, |this| ((**(this as *const *const ITextInputPanelEventSink_Vtbl)).TextInserted)(this, ink), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InPlaceStateChanging,
+            InPlaceStateChanged,
+            InPlaceSizeChanging,
+            InPlaceSizeChanged,
+            InputAreaChanging,
+            InputAreaChanged,
+            CorrectionModeChanging,
+            CorrectionModeChanged,
+            InPlaceVisibilityChanging,
+            InPlaceVisibilityChanged,
+            #[cfg(feature = "Win32_System_Com")]
+            TextInserting,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            TextInserting: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            TextInserted,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            TextInserted: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextInputPanelEventSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITextInputPanelRunInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsTipRunning(this: *mut core::ffi::c_void, pfrunning: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextInputPanelRunInfo", "IsTipRunning", 3usize, |this| ((**(this as *const *const ITextInputPanelRunInfo_Vtbl)).IsTipRunning)(this, pfrunning), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsTipRunning }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextInputPanelRunInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITipAutoCompleteClient_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AdviseProvider(this: *mut core::ffi::c_void, hwndfield: super::super::Foundation::HWND, piprovider: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITipAutoCompleteClient", "AdviseProvider", 3usize, |this| ((**(this as *const *const ITipAutoCompleteClient_Vtbl)).AdviseProvider)(this, hwndfield, piprovider), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnadviseProvider(this: *mut core::ffi::c_void, hwndfield: super::super::Foundation::HWND, piprovider: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITipAutoCompleteClient", "UnadviseProvider", 4usize, |this| ((**(this as *const *const ITipAutoCompleteClient_Vtbl)).UnadviseProvider)(this, hwndfield, piprovider), |result| Some(*result))
+        }
+        unsafe extern "system" fn UserSelection(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITipAutoCompleteClient", "UserSelection", 5usize, |this| ((**(this as *const *const ITipAutoCompleteClient_Vtbl)).UserSelection)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreferredRects(this: *mut core::ffi::c_void, prcaclist: *const super::super::Foundation::RECT, prcfield: *const super::super::Foundation::RECT, prcmodifiedaclist: *mut super::super::Foundation::RECT, pfshownabovetip: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITipAutoCompleteClient", "PreferredRects", 6usize, |this| ((**(this as *const *const ITipAutoCompleteClient_Vtbl)).PreferredRects)(this, prcaclist, prcfield, prcmodifiedaclist, pfshownabovetip), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestShowUI(this: *mut core::ffi::c_void, hwndlist: super::super::Foundation::HWND, pfallowshowing: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITipAutoCompleteClient", "RequestShowUI", 7usize, |this| ((**(this as *const *const ITipAutoCompleteClient_Vtbl)).RequestShowUI)(this, hwndlist, pfallowshowing), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AdviseProvider,
+            UnadviseProvider,
+            UserSelection,
+            PreferredRects,
+            RequestShowUI,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITipAutoCompleteClient as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITipAutoCompleteProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UpdatePendingText(this: *mut core::ffi::c_void, bstrpendingtext: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITipAutoCompleteProvider", "UpdatePendingText", 3usize, |this| ((**(this as *const *const ITipAutoCompleteProvider_Vtbl)).UpdatePendingText)(this, bstrpendingtext), |result| Some(*result))
+        }
+        unsafe extern "system" fn Show(this: *mut core::ffi::c_void, fshow: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITipAutoCompleteProvider", "Show", 4usize, |this| ((**(this as *const *const ITipAutoCompleteProvider_Vtbl)).Show)(this, fshow), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UpdatePendingText, Show }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITipAutoCompleteProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for _IInkCollectorEvents_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<_IInkCollectorEvents as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for _IInkEditEvents_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<_IInkEditEvents as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for _IInkEvents_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<_IInkEvents as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for _IInkOverlayEvents_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<_IInkOverlayEvents as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for _IInkPictureEvents_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<_IInkPictureEvents as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for _IInkRecognitionEvents_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<_IInkRecognitionEvents as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for _IInkStrokesEvents_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<_IInkStrokesEvents as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for _IMathInputControlEvents_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<_IMathInputControlEvents as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for _IPenInputPanelEvents_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<_IPenInputPanelEvents as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
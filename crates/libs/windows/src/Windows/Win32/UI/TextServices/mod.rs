@@ -6357,6 +6357,7 @@ pub struct ANCHOR_CHANGE_HISTORY_FLAGS(pub u32);
 impl windows_core::TypeKind for ANCHOR_CHANGE_HISTORY_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ANCHOR_CHANGE_HISTORY_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ANCHOR_CHANGE_HISTORY_FLAGS").field(&self.0).finish()
@@ -6401,6 +6402,7 @@ pub struct GET_TEXT_AND_PROPERTY_UPDATES_FLAGS(pub u32);
 impl windows_core::TypeKind for GET_TEXT_AND_PROPERTY_UPDATES_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for GET_TEXT_AND_PROPERTY_UPDATES_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("GET_TEXT_AND_PROPERTY_UPDATES_FLAGS").field(&self.0).finish()
@@ -6445,6 +6447,7 @@ pub struct INSERT_TEXT_AT_SELECTION_FLAGS(pub u32);
 impl windows_core::TypeKind for INSERT_TEXT_AT_SELECTION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INSERT_TEXT_AT_SELECTION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INSERT_TEXT_AT_SELECTION_FLAGS").field(&self.0).finish()
@@ -6456,6 +6459,7 @@ pub struct InputScope(pub i32);
 impl windows_core::TypeKind for InputScope {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for InputScope {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("InputScope").field(&self.0).finish()
@@ -6467,6 +6471,7 @@ pub struct LANG_BAR_ITEM_ICON_MODE_FLAGS(pub u32);
 impl windows_core::TypeKind for LANG_BAR_ITEM_ICON_MODE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for LANG_BAR_ITEM_ICON_MODE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("LANG_BAR_ITEM_ICON_MODE_FLAGS").field(&self.0).finish()
@@ -6478,6 +6483,7 @@ pub struct TEXT_STORE_CHANGE_FLAGS(pub u32);
 impl windows_core::TypeKind for TEXT_STORE_CHANGE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TEXT_STORE_CHANGE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TEXT_STORE_CHANGE_FLAGS").field(&self.0).finish()
@@ -6522,6 +6528,7 @@ pub struct TEXT_STORE_LOCK_FLAGS(pub u32);
 impl windows_core::TypeKind for TEXT_STORE_LOCK_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TEXT_STORE_LOCK_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TEXT_STORE_LOCK_FLAGS").field(&self.0).finish()
@@ -6533,6 +6540,7 @@ pub struct TEXT_STORE_TEXT_CHANGE_FLAGS(pub u32);
 impl windows_core::TypeKind for TEXT_STORE_TEXT_CHANGE_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TEXT_STORE_TEXT_CHANGE_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TEXT_STORE_TEXT_CHANGE_FLAGS").field(&self.0).finish()
@@ -6577,6 +6585,7 @@ pub struct TF_CONTEXT_EDIT_CONTEXT_FLAGS(pub u32);
 impl windows_core::TypeKind for TF_CONTEXT_EDIT_CONTEXT_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TF_CONTEXT_EDIT_CONTEXT_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TF_CONTEXT_EDIT_CONTEXT_FLAGS").field(&self.0).finish()
@@ -6621,6 +6630,7 @@ pub struct TF_DA_ATTR_INFO(pub i32);
 impl windows_core::TypeKind for TF_DA_ATTR_INFO {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TF_DA_ATTR_INFO {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TF_DA_ATTR_INFO").field(&self.0).finish()
@@ -6632,6 +6642,7 @@ pub struct TF_DA_COLORTYPE(pub i32);
 impl windows_core::TypeKind for TF_DA_COLORTYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TF_DA_COLORTYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TF_DA_COLORTYPE").field(&self.0).finish()
@@ -6643,6 +6654,7 @@ pub struct TF_DA_LINESTYLE(pub i32);
 impl windows_core::TypeKind for TF_DA_LINESTYLE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TF_DA_LINESTYLE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TF_DA_LINESTYLE").field(&self.0).finish()
@@ -6654,6 +6666,7 @@ pub struct TKBLayoutType(pub i32);
 impl windows_core::TypeKind for TKBLayoutType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TKBLayoutType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TKBLayoutType").field(&self.0).finish()
@@ -6665,6 +6678,7 @@ pub struct TfActiveSelEnd(pub i32);
 impl windows_core::TypeKind for TfActiveSelEnd {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TfActiveSelEnd {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TfActiveSelEnd").field(&self.0).finish()
@@ -6676,6 +6690,7 @@ pub struct TfAnchor(pub i32);
 impl windows_core::TypeKind for TfAnchor {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TfAnchor {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TfAnchor").field(&self.0).finish()
@@ -6687,6 +6702,7 @@ pub struct TfCandidateResult(pub i32);
 impl windows_core::TypeKind for TfCandidateResult {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TfCandidateResult {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TfCandidateResult").field(&self.0).finish()
@@ -6698,6 +6714,7 @@ pub struct TfGravity(pub i32);
 impl windows_core::TypeKind for TfGravity {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TfGravity {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TfGravity").field(&self.0).finish()
@@ -6709,6 +6726,7 @@ pub struct TfIntegratableCandidateListSelectionStyle(pub i32);
 impl windows_core::TypeKind for TfIntegratableCandidateListSelectionStyle {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TfIntegratableCandidateListSelectionStyle {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TfIntegratableCandidateListSelectionStyle").field(&self.0).finish()
@@ -6720,6 +6738,7 @@ pub struct TfLBBalloonStyle(pub i32);
 impl windows_core::TypeKind for TfLBBalloonStyle {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TfLBBalloonStyle {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TfLBBalloonStyle").field(&self.0).finish()
@@ -6731,6 +6750,7 @@ pub struct TfLBIClick(pub i32);
 impl windows_core::TypeKind for TfLBIClick {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TfLBIClick {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TfLBIClick").field(&self.0).finish()
@@ -6742,6 +6762,7 @@ pub struct TfLayoutCode(pub i32);
 impl windows_core::TypeKind for TfLayoutCode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TfLayoutCode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TfLayoutCode").field(&self.0).finish()
@@ -6753,6 +6774,7 @@ pub struct TfSapiObject(pub i32);
 impl windows_core::TypeKind for TfSapiObject {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TfSapiObject {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TfSapiObject").field(&self.0).finish()
@@ -6764,6 +6786,7 @@ pub struct TfShiftDir(pub i32);
 impl windows_core::TypeKind for TfShiftDir {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TfShiftDir {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TfShiftDir").field(&self.0).finish()
@@ -6775,6 +6798,7 @@ pub struct TsActiveSelEnd(pub i32);
 impl windows_core::TypeKind for TsActiveSelEnd {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TsActiveSelEnd {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TsActiveSelEnd").field(&self.0).finish()
@@ -6786,6 +6810,7 @@ pub struct TsGravity(pub i32);
 impl windows_core::TypeKind for TsGravity {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TsGravity {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TsGravity").field(&self.0).finish()
@@ -6797,6 +6822,7 @@ pub struct TsLayoutCode(pub i32);
 impl windows_core::TypeKind for TsLayoutCode {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TsLayoutCode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TsLayoutCode").field(&self.0).finish()
@@ -6808,6 +6834,7 @@ pub struct TsRunType(pub i32);
 impl windows_core::TypeKind for TsRunType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TsRunType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TsRunType").field(&self.0).finish()
@@ -6819,6 +6846,7 @@ pub struct TsShiftDir(pub i32);
 impl windows_core::TypeKind for TsShiftDir {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for TsShiftDir {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("TsShiftDir").field(&self.0).finish()
@@ -6877,7 +6905,8 @@ impl Default for TF_DISPLAYATTRIBUTE {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TF_HALTCOND {
     pub pHaltRange: core::mem::ManuallyDrop<Option<ITfRange>>,
     pub aHaltPos: TfAnchor,
@@ -6898,7 +6927,8 @@ impl Default for TF_HALTCOND {
 }
 #[repr(C)]
 #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TF_INPUTPROCESSORPROFILE {
     pub dwProfileType: u32,
     pub langid: u16,
@@ -6921,7 +6951,8 @@ impl Default for TF_INPUTPROCESSORPROFILE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TF_LANGBARITEMINFO {
     pub clsidService: windows_core::GUID,
     pub guidItem: windows_core::GUID,
@@ -6938,7 +6969,8 @@ impl Default for TF_LANGBARITEMINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TF_LANGUAGEPROFILE {
     pub clsid: windows_core::GUID,
     pub langid: u16,
@@ -6955,7 +6987,8 @@ impl Default for TF_LANGUAGEPROFILE {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TF_LBBALLOONINFO {
     pub style: TfLBBalloonStyle,
     pub bstrText: core::mem::ManuallyDrop<windows_core::BSTR>,
@@ -7008,7 +7041,8 @@ impl Default for TF_LMLATTELEMENT_0 {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TF_PERSISTENT_PROPERTY_HEADER_ACP {
     pub guidType: windows_core::GUID,
     pub ichStart: i32,
@@ -7026,7 +7060,8 @@ impl Default for TF_PERSISTENT_PROPERTY_HEADER_ACP {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TF_PRESERVEDKEY {
     pub uVKey: u32,
     pub uModifiers: u32,
@@ -7040,7 +7075,8 @@ impl Default for TF_PRESERVEDKEY {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TF_PROPERTYVAL {
     pub guidId: windows_core::GUID,
     pub varValue: core::mem::ManuallyDrop<windows_core::VARIANT>,
@@ -7059,7 +7095,8 @@ impl Default for TF_PROPERTYVAL {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TF_SELECTION {
     pub range: core::mem::ManuallyDrop<Option<ITfRange>>,
     pub style: TF_SELECTIONSTYLE,
@@ -7078,7 +7115,8 @@ impl Default for TF_SELECTION {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TF_SELECTIONSTYLE {
     pub ase: TfActiveSelEnd,
     pub fInterimChar: super::super::Foundation::BOOL,
@@ -7092,7 +7130,8 @@ impl Default for TF_SELECTIONSTYLE {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TS_ATTRVAL {
     pub idAttr: windows_core::GUID,
     pub dwOverlapId: u32,
@@ -7112,7 +7151,8 @@ impl Default for TS_ATTRVAL {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TS_RUNINFO {
     pub uCount: u32,
     pub r#type: TsRunType,
@@ -7126,7 +7166,8 @@ impl Default for TS_RUNINFO {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TS_SELECTIONSTYLE {
     pub ase: TsActiveSelEnd,
     pub fInterimChar: super::super::Foundation::BOOL,
@@ -7140,7 +7181,8 @@ impl Default for TS_SELECTIONSTYLE {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TS_SELECTION_ACP {
     pub acpStart: i32,
     pub acpEnd: i32,
@@ -7155,7 +7197,8 @@ impl Default for TS_SELECTION_ACP {
     }
 }
 #[repr(C)]
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TS_SELECTION_ANCHOR {
     pub paStart: core::mem::ManuallyDrop<Option<IAnchor>>,
     pub paEnd: core::mem::ManuallyDrop<Option<IAnchor>>,
@@ -7175,7 +7218,8 @@ impl Default for TS_SELECTION_ANCHOR {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TS_STATUS {
     pub dwDynamicFlags: u32,
     pub dwStaticFlags: u32,
@@ -7189,7 +7233,8 @@ impl Default for TS_STATUS {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct TS_TEXTCHANGE {
     pub acpStart: i32,
     pub acpOldEnd: i32,
@@ -7205,3 +7250,5 @@ impl Default for TS_TEXTCHANGE {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,3980 @@
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAccClientDocMgr_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetDocuments(this: *mut core::ffi::c_void, enumunknown: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccClientDocMgr", "GetDocuments", 3usize, |this| ((**(this as *const *const IAccClientDocMgr_Vtbl)).GetDocuments)(this, enumunknown), |result| Some(*result))
+        }
+        unsafe extern "system" fn LookupByHWND(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccClientDocMgr", "LookupByHWND", 4usize, |this| ((**(this as *const *const IAccClientDocMgr_Vtbl)).LookupByHWND)(this, hwnd, riid, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn LookupByPoint(this: *mut core::ffi::c_void, pt: super::super::Foundation::POINT, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccClientDocMgr", "LookupByPoint", 5usize, |this| ((**(this as *const *const IAccClientDocMgr_Vtbl)).LookupByPoint)(this, pt, riid, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFocused(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccClientDocMgr", "GetFocused", 6usize, |this| ((**(this as *const *const IAccClientDocMgr_Vtbl)).GetFocused)(this, riid, ppunk), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            GetDocuments,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetDocuments: 0,
+            LookupByHWND,
+            LookupByPoint,
+            GetFocused,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccClientDocMgr as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAccDictionary_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLocalizedString(this: *mut core::ffi::c_void, term: *const windows_core::GUID, lcid: u32, presult: *mut core::mem::MaybeUninit<windows_core::BSTR>, plcid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccDictionary", "GetLocalizedString", 3usize, |this| ((**(this as *const *const IAccDictionary_Vtbl)).GetLocalizedString)(this, term, lcid, presult, plcid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParentTerm(this: *mut core::ffi::c_void, term: *const windows_core::GUID, pparentterm: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccDictionary", "GetParentTerm", 4usize, |this| ((**(this as *const *const IAccDictionary_Vtbl)).GetParentTerm)(this, term, pparentterm), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMnemonicString(this: *mut core::ffi::c_void, term: *const windows_core::GUID, presult: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccDictionary", "GetMnemonicString", 5usize, |this| ((**(this as *const *const IAccDictionary_Vtbl)).GetMnemonicString)(this, term, presult), |result| Some(*result))
+        }
+        unsafe extern "system" fn LookupMnemonicTerm(this: *mut core::ffi::c_void, bstrmnemonic: core::mem::MaybeUninit<windows_core::BSTR>, pterm: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccDictionary", "LookupMnemonicTerm", 6usize, |this| ((**(this as *const *const IAccDictionary_Vtbl)).LookupMnemonicTerm)(this, bstrmnemonic, pterm), |result| Some(*result))
+        }
+        unsafe extern "system" fn ConvertValueToString(this: *mut core::ffi::c_void, term: *const windows_core::GUID, lcid: u32, varvalue: core::mem::MaybeUninit<windows_core::VARIANT>, pbstrresult: *mut core::mem::MaybeUninit<windows_core::BSTR>, plcid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccDictionary", "ConvertValueToString", 7usize, |this| ((**(this as *const *const IAccDictionary_Vtbl)).ConvertValueToString)(this, term, lcid, varvalue, pbstrresult, plcid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetLocalizedString,
+            GetParentTerm,
+            GetMnemonicString,
+            LookupMnemonicTerm,
+            ConvertValueToString,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccDictionary as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAccServerDocMgr_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NewDocument(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccServerDocMgr", "NewDocument", 3usize, |this| ((**(this as *const *const IAccServerDocMgr_Vtbl)).NewDocument)(this, riid, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn RevokeDocument(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccServerDocMgr", "RevokeDocument", 4usize, |this| ((**(this as *const *const IAccServerDocMgr_Vtbl)).RevokeDocument)(this, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDocumentFocus(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccServerDocMgr", "OnDocumentFocus", 5usize, |this| ((**(this as *const *const IAccServerDocMgr_Vtbl)).OnDocumentFocus)(this, punk), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NewDocument, RevokeDocument, OnDocumentFocus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccServerDocMgr as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAccStore_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Register(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccStore", "Register", 3usize, |this| ((**(this as *const *const IAccStore_Vtbl)).Register)(this, riid, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unregister(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccStore", "Unregister", 4usize, |this| ((**(this as *const *const IAccStore_Vtbl)).Unregister)(this, punk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetDocuments(this: *mut core::ffi::c_void, enumunknown: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccStore", "GetDocuments", 5usize, |this| ((**(this as *const *const IAccStore_Vtbl)).GetDocuments)(this, enumunknown), |result| Some(*result))
+        }
+        unsafe extern "system" fn LookupByHWND(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccStore", "LookupByHWND", 6usize, |this| ((**(this as *const *const IAccStore_Vtbl)).LookupByHWND)(this, hwnd, riid, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn LookupByPoint(this: *mut core::ffi::c_void, pt: super::super::Foundation::POINT, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccStore", "LookupByPoint", 7usize, |this| ((**(this as *const *const IAccStore_Vtbl)).LookupByPoint)(this, pt, riid, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDocumentFocus(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccStore", "OnDocumentFocus", 8usize, |this| ((**(this as *const *const IAccStore_Vtbl)).OnDocumentFocus)(this, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFocused(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAccStore", "GetFocused", 9usize, |this| ((**(this as *const *const IAccStore_Vtbl)).GetFocused)(this, riid, ppunk), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Register,
+            Unregister,
+            #[cfg(feature = "Win32_System_Com")]
+            GetDocuments,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetDocuments: 0,
+            LookupByHWND,
+            LookupByPoint,
+            OnDocumentFocus,
+            GetFocused,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAccStore as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAnchor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetGravity(this: *mut core::ffi::c_void, gravity: TsGravity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnchor", "SetGravity", 3usize, |this| ((**(this as *const *const IAnchor_Vtbl)).SetGravity)(this, gravity), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGravity(this: *mut core::ffi::c_void, pgravity: *mut TsGravity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnchor", "GetGravity", 4usize, |this| ((**(this as *const *const IAnchor_Vtbl)).GetGravity)(this, pgravity), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEqual(this: *mut core::ffi::c_void, pawith: *mut core::ffi::c_void, pfequal: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnchor", "IsEqual", 5usize, |this| ((**(this as *const *const IAnchor_Vtbl)).IsEqual)(this, pawith, pfequal), |result| Some(*result))
+        }
+        unsafe extern "system" fn Compare(this: *mut core::ffi::c_void, pawith: *mut core::ffi::c_void, plresult: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnchor", "Compare", 6usize, |this| ((**(this as *const *const IAnchor_Vtbl)).Compare)(this, pawith, plresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shift(this: *mut core::ffi::c_void, dwflags: u32, cchreq: i32, pcch: *mut i32, pahaltanchor: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnchor", "Shift", 7usize, |this| ((**(this as *const *const IAnchor_Vtbl)).Shift)(this, dwflags, cchreq, pcch, pahaltanchor), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShiftTo(this: *mut core::ffi::c_void, pasite: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnchor", "ShiftTo", 8usize, |this| ((**(this as *const *const IAnchor_Vtbl)).ShiftTo)(this, pasite), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShiftRegion(this: *mut core::ffi::c_void, dwflags: u32, dir: TsShiftDir, pfnoregion: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnchor", "ShiftRegion", 9usize, |this| ((**(this as *const *const IAnchor_Vtbl)).ShiftRegion)(this, dwflags, dir, pfnoregion), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetChangeHistoryMask(this: *mut core::ffi::c_void, dwmask: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnchor", "SetChangeHistoryMask", 10usize, |this| ((**(this as *const *const IAnchor_Vtbl)).SetChangeHistoryMask)(this, dwmask), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChangeHistory(this: *mut core::ffi::c_void, pdwhistory: *mut ANCHOR_CHANGE_HISTORY_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnchor", "GetChangeHistory", 11usize, |this| ((**(this as *const *const IAnchor_Vtbl)).GetChangeHistory)(this, pdwhistory), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearChangeHistory(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnchor", "ClearChangeHistory", 12usize, |this| ((**(this as *const *const IAnchor_Vtbl)).ClearChangeHistory)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppaclone: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnchor", "Clone", 13usize, |this| ((**(this as *const *const IAnchor_Vtbl)).Clone)(this, ppaclone), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetGravity,
+            GetGravity,
+            IsEqual,
+            Compare,
+            Shift,
+            ShiftTo,
+            ShiftRegion,
+            SetChangeHistoryMask,
+            GetChangeHistory,
+            ClearChangeHistory,
+            Clone,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAnchor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IClonableWrapper_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CloneNewWrapper(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IClonableWrapper", "CloneNewWrapper", 3usize, |this| ((**(this as *const *const IClonableWrapper_Vtbl)).CloneNewWrapper)(this, riid, ppv), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CloneNewWrapper }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IClonableWrapper as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoCreateLocally_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CoCreateLocally(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, dwclscontext: u32, riid: *const windows_core::GUID, punk: *mut *mut core::ffi::c_void, riidparam: *const windows_core::GUID, punkparam: *mut core::ffi::c_void, varparam: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoCreateLocally", "CoCreateLocally", 3usize, |this| ((**(this as *const *const ICoCreateLocally_Vtbl)).CoCreateLocally)(this, rclsid, dwclscontext, riid, punk, riidparam, punkparam, varparam), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CoCreateLocally }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoCreateLocally as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICoCreatedLocally_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn LocalInit(this: *mut core::ffi::c_void, punklocalobject: *mut core::ffi::c_void, riidparam: *const windows_core::GUID, punkparam: *mut core::ffi::c_void, varparam: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICoCreatedLocally", "LocalInit", 3usize, |this| ((**(this as *const *const ICoCreatedLocally_Vtbl)).LocalInit)(this, punklocalobject, riidparam, punkparam, varparam), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, LocalInit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICoCreatedLocally as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IDocWrap_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetDoc(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDocWrap", "SetDoc", 3usize, |this| ((**(this as *const *const IDocWrap_Vtbl)).SetDoc)(this, riid, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWrappedDoc(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDocWrap", "GetWrappedDoc", 4usize, |this| ((**(this as *const *const IDocWrap_Vtbl)).GetWrappedDoc)(this, riid, ppunk), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetDoc, GetWrappedDoc }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDocWrap as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumITfCompositionView_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumITfCompositionView", "Clone", 3usize, |this| ((**(this as *const *const IEnumITfCompositionView_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ulcount: u32, rgcompositionview: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumITfCompositionView", "Next", 4usize, |this| ((**(this as *const *const IEnumITfCompositionView_Vtbl)).Next)(this, ulcount, rgcompositionview, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumITfCompositionView", "Reset", 5usize, |this| ((**(this as *const *const IEnumITfCompositionView_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, ulcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumITfCompositionView", "Skip", 6usize, |this| ((**(this as *const *const IEnumITfCompositionView_Vtbl)).Skip)(this, ulcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Clone, Next, Reset, Skip }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumITfCompositionView as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumSpeechCommands_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSpeechCommands", "Clone", 3usize, |this| ((**(this as *const *const IEnumSpeechCommands_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ulcount: u32, pspcmds: *mut *mut u16, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSpeechCommands", "Next", 4usize, |this| ((**(this as *const *const IEnumSpeechCommands_Vtbl)).Next)(this, ulcount, pspcmds, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSpeechCommands", "Reset", 5usize, |this| ((**(this as *const *const IEnumSpeechCommands_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, ulcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSpeechCommands", "Skip", 6usize, |this| ((**(this as *const *const IEnumSpeechCommands_Vtbl)).Skip)(this, ulcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Clone, Next, Reset, Skip }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumSpeechCommands as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumTfCandidates_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfCandidates", "Clone", 3usize, |this| ((**(this as *const *const IEnumTfCandidates_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ulcount: u32, ppcand: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfCandidates", "Next", 4usize, |this| ((**(this as *const *const IEnumTfCandidates_Vtbl)).Next)(this, ulcount, ppcand, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfCandidates", "Reset", 5usize, |this| ((**(this as *const *const IEnumTfCandidates_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, ulcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfCandidates", "Skip", 6usize, |this| ((**(this as *const *const IEnumTfCandidates_Vtbl)).Skip)(this, ulcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Clone, Next, Reset, Skip }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumTfCandidates as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumTfContextViews_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfContextViews", "Clone", 3usize, |this| ((**(this as *const *const IEnumTfContextViews_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ulcount: u32, rgviews: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfContextViews", "Next", 4usize, |this| ((**(this as *const *const IEnumTfContextViews_Vtbl)).Next)(this, ulcount, rgviews, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfContextViews", "Reset", 5usize, |this| ((**(this as *const *const IEnumTfContextViews_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, ulcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfContextViews", "Skip", 6usize, |this| ((**(this as *const *const IEnumTfContextViews_Vtbl)).Skip)(this, ulcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Clone, Next, Reset, Skip }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumTfContextViews as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumTfContexts_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfContexts", "Clone", 3usize, |this| ((**(this as *const *const IEnumTfContexts_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ulcount: u32, rgcontext: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfContexts", "Next", 4usize, |this| ((**(this as *const *const IEnumTfContexts_Vtbl)).Next)(this, ulcount, rgcontext, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfContexts", "Reset", 5usize, |this| ((**(this as *const *const IEnumTfContexts_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, ulcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfContexts", "Skip", 6usize, |this| ((**(this as *const *const IEnumTfContexts_Vtbl)).Skip)(this, ulcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Clone, Next, Reset, Skip }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumTfContexts as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumTfDisplayAttributeInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfDisplayAttributeInfo", "Clone", 3usize, |this| ((**(this as *const *const IEnumTfDisplayAttributeInfo_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ulcount: u32, rginfo: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfDisplayAttributeInfo", "Next", 4usize, |this| ((**(this as *const *const IEnumTfDisplayAttributeInfo_Vtbl)).Next)(this, ulcount, rginfo, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfDisplayAttributeInfo", "Reset", 5usize, |this| ((**(this as *const *const IEnumTfDisplayAttributeInfo_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, ulcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfDisplayAttributeInfo", "Skip", 6usize, |this| ((**(this as *const *const IEnumTfDisplayAttributeInfo_Vtbl)).Skip)(this, ulcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Clone, Next, Reset, Skip }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumTfDisplayAttributeInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumTfDocumentMgrs_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfDocumentMgrs", "Clone", 3usize, |this| ((**(this as *const *const IEnumTfDocumentMgrs_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ulcount: u32, rgdocumentmgr: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfDocumentMgrs", "Next", 4usize, |this| ((**(this as *const *const IEnumTfDocumentMgrs_Vtbl)).Next)(this, ulcount, rgdocumentmgr, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfDocumentMgrs", "Reset", 5usize, |this| ((**(this as *const *const IEnumTfDocumentMgrs_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, ulcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfDocumentMgrs", "Skip", 6usize, |this| ((**(this as *const *const IEnumTfDocumentMgrs_Vtbl)).Skip)(this, ulcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Clone, Next, Reset, Skip }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumTfDocumentMgrs as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumTfFunctionProviders_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfFunctionProviders", "Clone", 3usize, |this| ((**(this as *const *const IEnumTfFunctionProviders_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ulcount: u32, ppcmdobj: *mut *mut core::ffi::c_void, pcfetch: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfFunctionProviders", "Next", 4usize, |this| ((**(this as *const *const IEnumTfFunctionProviders_Vtbl)).Next)(this, ulcount, ppcmdobj, pcfetch), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfFunctionProviders", "Reset", 5usize, |this| ((**(this as *const *const IEnumTfFunctionProviders_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, ulcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfFunctionProviders", "Skip", 6usize, |this| ((**(this as *const *const IEnumTfFunctionProviders_Vtbl)).Skip)(this, ulcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Clone, Next, Reset, Skip }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumTfFunctionProviders as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+impl windows_core::imp::ProxyVtable for IEnumTfInputProcessorProfiles_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfInputProcessorProfiles", "Clone", 3usize, |this| ((**(this as *const *const IEnumTfInputProcessorProfiles_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ulcount: u32, pprofile: *mut TF_INPUTPROCESSORPROFILE, pcfetch: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfInputProcessorProfiles", "Next", 4usize, |this| ((**(this as *const *const IEnumTfInputProcessorProfiles_Vtbl)).Next)(this, ulcount, pprofile, pcfetch), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfInputProcessorProfiles", "Reset", 5usize, |this| ((**(this as *const *const IEnumTfInputProcessorProfiles_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, ulcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfInputProcessorProfiles", "Skip", 6usize, |this| ((**(this as *const *const IEnumTfInputProcessorProfiles_Vtbl)).Skip)(this, ulcount), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Clone,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            Next,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            Next: 0,
+            Reset,
+            Skip,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumTfInputProcessorProfiles as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumTfLangBarItems_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfLangBarItems", "Clone", 3usize, |this| ((**(this as *const *const IEnumTfLangBarItems_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ulcount: u32, ppitem: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfLangBarItems", "Next", 4usize, |this| ((**(this as *const *const IEnumTfLangBarItems_Vtbl)).Next)(this, ulcount, ppitem, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfLangBarItems", "Reset", 5usize, |this| ((**(this as *const *const IEnumTfLangBarItems_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, ulcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfLangBarItems", "Skip", 6usize, |this| ((**(this as *const *const IEnumTfLangBarItems_Vtbl)).Skip)(this, ulcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Clone, Next, Reset, Skip }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumTfLangBarItems as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumTfLanguageProfiles_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfLanguageProfiles", "Clone", 3usize, |this| ((**(this as *const *const IEnumTfLanguageProfiles_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ulcount: u32, pprofile: *mut TF_LANGUAGEPROFILE, pcfetch: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfLanguageProfiles", "Next", 4usize, |this| ((**(this as *const *const IEnumTfLanguageProfiles_Vtbl)).Next)(this, ulcount, pprofile, pcfetch), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfLanguageProfiles", "Reset", 5usize, |this| ((**(this as *const *const IEnumTfLanguageProfiles_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, ulcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfLanguageProfiles", "Skip", 6usize, |this| ((**(this as *const *const IEnumTfLanguageProfiles_Vtbl)).Skip)(this, ulcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Clone, Next, Reset, Skip }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumTfLanguageProfiles as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumTfLatticeElements_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfLatticeElements", "Clone", 3usize, |this| ((**(this as *const *const IEnumTfLatticeElements_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ulcount: u32, rgselements: *mut TF_LMLATTELEMENT, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfLatticeElements", "Next", 4usize, |this| ((**(this as *const *const IEnumTfLatticeElements_Vtbl)).Next)(this, ulcount, rgselements, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfLatticeElements", "Reset", 5usize, |this| ((**(this as *const *const IEnumTfLatticeElements_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, ulcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfLatticeElements", "Skip", 6usize, |this| ((**(this as *const *const IEnumTfLatticeElements_Vtbl)).Skip)(this, ulcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Clone, Next, Reset, Skip }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumTfLatticeElements as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumTfProperties_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfProperties", "Clone", 3usize, |this| ((**(this as *const *const IEnumTfProperties_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ulcount: u32, ppprop: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfProperties", "Next", 4usize, |this| ((**(this as *const *const IEnumTfProperties_Vtbl)).Next)(this, ulcount, ppprop, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfProperties", "Reset", 5usize, |this| ((**(this as *const *const IEnumTfProperties_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, ulcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfProperties", "Skip", 6usize, |this| ((**(this as *const *const IEnumTfProperties_Vtbl)).Skip)(this, ulcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Clone, Next, Reset, Skip }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumTfProperties as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumTfPropertyValue_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfPropertyValue", "Clone", 3usize, |this| ((**(this as *const *const IEnumTfPropertyValue_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ulcount: u32, rgvalues: *mut TF_PROPERTYVAL, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfPropertyValue", "Next", 4usize, |this| ((**(this as *const *const IEnumTfPropertyValue_Vtbl)).Next)(this, ulcount, rgvalues, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfPropertyValue", "Reset", 5usize, |this| ((**(this as *const *const IEnumTfPropertyValue_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, ulcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfPropertyValue", "Skip", 6usize, |this| ((**(this as *const *const IEnumTfPropertyValue_Vtbl)).Skip)(this, ulcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Clone, Next, Reset, Skip }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumTfPropertyValue as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumTfRanges_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfRanges", "Clone", 3usize, |this| ((**(this as *const *const IEnumTfRanges_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ulcount: u32, pprange: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfRanges", "Next", 4usize, |this| ((**(this as *const *const IEnumTfRanges_Vtbl)).Next)(this, ulcount, pprange, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfRanges", "Reset", 5usize, |this| ((**(this as *const *const IEnumTfRanges_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, ulcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfRanges", "Skip", 6usize, |this| ((**(this as *const *const IEnumTfRanges_Vtbl)).Skip)(this, ulcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Clone, Next, Reset, Skip }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumTfRanges as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumTfUIElements_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfUIElements", "Clone", 3usize, |this| ((**(this as *const *const IEnumTfUIElements_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, ulcount: u32, ppelement: *mut *mut core::ffi::c_void, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfUIElements", "Next", 4usize, |this| ((**(this as *const *const IEnumTfUIElements_Vtbl)).Next)(this, ulcount, ppelement, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfUIElements", "Reset", 5usize, |this| ((**(this as *const *const IEnumTfUIElements_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, ulcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumTfUIElements", "Skip", 6usize, |this| ((**(this as *const *const IEnumTfUIElements_Vtbl)).Skip)(this, ulcount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Clone, Next, Reset, Skip }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumTfUIElements as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInternalDocWrap_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NotifyRevoke(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternalDocWrap", "NotifyRevoke", 3usize, |this| ((**(this as *const *const IInternalDocWrap_Vtbl)).NotifyRevoke)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NotifyRevoke }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInternalDocWrap as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISpeechCommandProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumSpeechCommands(this: *mut core::ffi::c_void, langid: u16, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechCommandProvider", "EnumSpeechCommands", 3usize, |this| ((**(this as *const *const ISpeechCommandProvider_Vtbl)).EnumSpeechCommands)(this, langid, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn ProcessCommand(this: *mut core::ffi::c_void, pszcommand: windows_core::PCWSTR, cch: u32, langid: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISpeechCommandProvider", "ProcessCommand", 4usize, |this| ((**(this as *const *const ISpeechCommandProvider_Vtbl)).ProcessCommand)(this, pszcommand, cch, langid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnumSpeechCommands, ProcessCommand }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISpeechCommandProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITextStoreACP_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AdviseSink(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, punk: *mut core::ffi::c_void, dwmask: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP", "AdviseSink", 3usize, |this| ((**(this as *const *const ITextStoreACP_Vtbl)).AdviseSink)(this, riid, punk, dwmask), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnadviseSink(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP", "UnadviseSink", 4usize, |this| ((**(this as *const *const ITextStoreACP_Vtbl)).UnadviseSink)(this, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestLock(this: *mut core::ffi::c_void, dwlockflags: u32, phrsession: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP", "RequestLock", 5usize, |this| ((**(this as *const *const ITextStoreACP_Vtbl)).RequestLock)(this, dwlockflags, phrsession), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pdcs: *mut TS_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP", "GetStatus", 6usize, |this| ((**(this as *const *const ITextStoreACP_Vtbl)).GetStatus)(this, pdcs), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryInsert(this: *mut core::ffi::c_void, acpteststart: i32, acptestend: i32, cch: u32, pacpresultstart: *mut i32, pacpresultend: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP", "QueryInsert", 7usize, |this| ((**(this as *const *const ITextStoreACP_Vtbl)).QueryInsert)(this, acpteststart, acptestend, cch, pacpresultstart, pacpresultend), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSelection(this: *mut core::ffi::c_void, ulindex: u32, ulcount: u32, pselection: *mut TS_SELECTION_ACP, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP", "GetSelection", 8usize, |this| ((**(this as *const *const ITextStoreACP_Vtbl)).GetSelection)(this, ulindex, ulcount, pselection, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelection(this: *mut core::ffi::c_void, ulcount: u32, pselection: *const TS_SELECTION_ACP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP", "SetSelection", 9usize, |this| ((**(this as *const *const ITextStoreACP_Vtbl)).SetSelection)(this, ulcount, pselection), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetText(this: *mut core::ffi::c_void, acpstart: i32, acpend: i32, pchplain: windows_core::PWSTR, cchplainreq: u32, pcchplainret: *mut u32, prgruninfo: *mut TS_RUNINFO, cruninforeq: u32, pcruninforet: *mut u32, pacpnext: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP", "GetText", 10usize, |this| ((**(this as *const *const ITextStoreACP_Vtbl)).GetText)(this, acpstart, acpend, pchplain, cchplainreq, pcchplainret, prgruninfo, cruninforeq, pcruninforet, pacpnext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetText(this: *mut core::ffi::c_void, dwflags: u32, acpstart: i32, acpend: i32, pchtext: windows_core::PCWSTR, cch: u32, pchange: *mut TS_TEXTCHANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP", "SetText", 11usize, |this| ((**(this as *const *const ITextStoreACP_Vtbl)).SetText)(this, dwflags, acpstart, acpend, pchtext, cch, pchange), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFormattedText(this: *mut core::ffi::c_void, acpstart: i32, acpend: i32, ppdataobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP", "GetFormattedText", 12usize, |this| ((**(this as *const *const ITextStoreACP_Vtbl)).GetFormattedText)(this, acpstart, acpend, ppdataobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEmbedded(this: *mut core::ffi::c_void, acppos: i32, rguidservice: *const windows_core::GUID, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP", "GetEmbedded", 13usize, |this| ((**(this as *const *const ITextStoreACP_Vtbl)).GetEmbedded)(this, acppos, rguidservice, riid, ppunk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn QueryInsertEmbedded(this: *mut core::ffi::c_void, pguidservice: *const windows_core::GUID, pformatetc: *const super::super::System::Com::FORMATETC, pfinsertable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP", "QueryInsertEmbedded", 14usize, |this| ((**(this as *const *const ITextStoreACP_Vtbl)).QueryInsertEmbedded)(this, pguidservice, pformatetc, pfinsertable), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InsertEmbedded(this: *mut core::ffi::c_void, dwflags: u32, acpstart: i32, acpend: i32, pdataobject: *mut core::ffi::c_void, pchange: *mut TS_TEXTCHANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP", "InsertEmbedded", 15usize, |this| ((**(this as *const *const ITextStoreACP_Vtbl)).InsertEmbedded)(this, dwflags, acpstart, acpend, pdataobject, pchange), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertTextAtSelection(this: *mut core::ffi::c_void, dwflags: u32, pchtext: windows_core::PCWSTR, cch: u32, pacpstart: *mut i32, pacpend: *mut i32, pchange: *mut TS_TEXTCHANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP", "InsertTextAtSelection", 16usize, |this| ((**(this as *const *const ITextStoreACP_Vtbl)).InsertTextAtSelection)(this, dwflags, pchtext, cch, pacpstart, pacpend, pchange), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InsertEmbeddedAtSelection(this: *mut core::ffi::c_void, dwflags: u32, pdataobject: *mut core::ffi::c_void, pacpstart: *mut i32, pacpend: *mut i32, pchange: *mut TS_TEXTCHANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP", "InsertEmbeddedAtSelection", 17usize, |this| ((**(this as *const *const ITextStoreACP_Vtbl)).InsertEmbeddedAtSelection)(this, dwflags, pdataobject, pacpstart, pacpend, pchange), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestSupportedAttrs(this: *mut core::ffi::c_void, dwflags: u32, cfilterattrs: u32, pafilterattrs: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP", "RequestSupportedAttrs", 18usize, |this| ((**(this as *const *const ITextStoreACP_Vtbl)).RequestSupportedAttrs)(this, dwflags, cfilterattrs, pafilterattrs), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAttrsAtPosition(this: *mut core::ffi::c_void, acppos: i32, cfilterattrs: u32, pafilterattrs: *const windows_core::GUID, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP", "RequestAttrsAtPosition", 19usize, |this| ((**(this as *const *const ITextStoreACP_Vtbl)).RequestAttrsAtPosition)(this, acppos, cfilterattrs, pafilterattrs, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAttrsTransitioningAtPosition(this: *mut core::ffi::c_void, acppos: i32, cfilterattrs: u32, pafilterattrs: *const windows_core::GUID, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP", "RequestAttrsTransitioningAtPosition", 20usize, |this| ((**(this as *const *const ITextStoreACP_Vtbl)).RequestAttrsTransitioningAtPosition)(this, acppos, cfilterattrs, pafilterattrs, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindNextAttrTransition(this: *mut core::ffi::c_void, acpstart: i32, acphalt: i32, cfilterattrs: u32, pafilterattrs: *const windows_core::GUID, dwflags: u32, pacpnext: *mut i32, pffound: *mut super::super::Foundation::BOOL, plfoundoffset: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP", "FindNextAttrTransition", 21usize, |this| ((**(this as *const *const ITextStoreACP_Vtbl)).FindNextAttrTransition)(this, acpstart, acphalt, cfilterattrs, pafilterattrs, dwflags, pacpnext, pffound, plfoundoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn RetrieveRequestedAttrs(this: *mut core::ffi::c_void, ulcount: u32, paattrvals: *mut TS_ATTRVAL, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP", "RetrieveRequestedAttrs", 22usize, |this| ((**(this as *const *const ITextStoreACP_Vtbl)).RetrieveRequestedAttrs)(this, ulcount, paattrvals, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEndACP(this: *mut core::ffi::c_void, pacp: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP", "GetEndACP", 23usize, |this| ((**(this as *const *const ITextStoreACP_Vtbl)).GetEndACP)(this, pacp), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActiveView(this: *mut core::ffi::c_void, pvcview: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP", "GetActiveView", 24usize, |this| ((**(this as *const *const ITextStoreACP_Vtbl)).GetActiveView)(this, pvcview), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetACPFromPoint(this: *mut core::ffi::c_void, vcview: u32, ptscreen: *const super::super::Foundation::POINT, dwflags: u32, pacp: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP", "GetACPFromPoint", 25usize, |this| ((**(this as *const *const ITextStoreACP_Vtbl)).GetACPFromPoint)(this, vcview, ptscreen, dwflags, pacp), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTextExt(this: *mut core::ffi::c_void, vcview: u32, acpstart: i32, acpend: i32, prc: *mut super::super::Foundation::RECT, pfclipped: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP", "GetTextExt", 26usize, |this| ((**(this as *const *const ITextStoreACP_Vtbl)).GetTextExt)(this, vcview, acpstart, acpend, prc, pfclipped), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScreenExt(this: *mut core::ffi::c_void, vcview: u32, prc: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP", "GetScreenExt", 27usize, |this| ((**(this as *const *const ITextStoreACP_Vtbl)).GetScreenExt)(this, vcview, prc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWnd(this: *mut core::ffi::c_void, vcview: u32, phwnd: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP", "GetWnd", 28usize, |this| ((**(this as *const *const ITextStoreACP_Vtbl)).GetWnd)(this, vcview, phwnd), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AdviseSink,
+            UnadviseSink,
+            RequestLock,
+            GetStatus,
+            QueryInsert,
+            GetSelection,
+            SetSelection,
+            GetText,
+            SetText,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFormattedText,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFormattedText: 0,
+            GetEmbedded,
+            #[cfg(feature = "Win32_System_Com")]
+            QueryInsertEmbedded,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            QueryInsertEmbedded: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            InsertEmbedded,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InsertEmbedded: 0,
+            InsertTextAtSelection,
+            #[cfg(feature = "Win32_System_Com")]
+            InsertEmbeddedAtSelection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InsertEmbeddedAtSelection: 0,
+            RequestSupportedAttrs,
+            RequestAttrsAtPosition,
+            RequestAttrsTransitioningAtPosition,
+            FindNextAttrTransition,
+            RetrieveRequestedAttrs,
+            GetEndACP,
+            GetActiveView,
+            GetACPFromPoint,
+            GetTextExt,
+            GetScreenExt,
+            GetWnd,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextStoreACP as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITextStoreACP2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AdviseSink(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, punk: *mut core::ffi::c_void, dwmask: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP2", "AdviseSink", 3usize, |this| ((**(this as *const *const ITextStoreACP2_Vtbl)).AdviseSink)(this, riid, punk, dwmask), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnadviseSink(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP2", "UnadviseSink", 4usize, |this| ((**(this as *const *const ITextStoreACP2_Vtbl)).UnadviseSink)(this, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestLock(this: *mut core::ffi::c_void, dwlockflags: u32, phrsession: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP2", "RequestLock", 5usize, |this| ((**(this as *const *const ITextStoreACP2_Vtbl)).RequestLock)(this, dwlockflags, phrsession), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pdcs: *mut TS_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP2", "GetStatus", 6usize, |this| ((**(this as *const *const ITextStoreACP2_Vtbl)).GetStatus)(this, pdcs), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryInsert(this: *mut core::ffi::c_void, acpteststart: i32, acptestend: i32, cch: u32, pacpresultstart: *mut i32, pacpresultend: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP2", "QueryInsert", 7usize, |this| ((**(this as *const *const ITextStoreACP2_Vtbl)).QueryInsert)(this, acpteststart, acptestend, cch, pacpresultstart, pacpresultend), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSelection(this: *mut core::ffi::c_void, ulindex: u32, ulcount: u32, pselection: *mut TS_SELECTION_ACP, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP2", "GetSelection", 8usize, |this| ((**(this as *const *const ITextStoreACP2_Vtbl)).GetSelection)(this, ulindex, ulcount, pselection, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelection(this: *mut core::ffi::c_void, ulcount: u32, pselection: *const TS_SELECTION_ACP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP2", "SetSelection", 9usize, |this| ((**(this as *const *const ITextStoreACP2_Vtbl)).SetSelection)(this, ulcount, pselection), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetText(this: *mut core::ffi::c_void, acpstart: i32, acpend: i32, pchplain: windows_core::PWSTR, cchplainreq: u32, pcchplainret: *mut u32, prgruninfo: *mut TS_RUNINFO, cruninforeq: u32, pcruninforet: *mut u32, pacpnext: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP2", "GetText", 10usize, |this| ((**(this as *const *const ITextStoreACP2_Vtbl)).GetText)(this, acpstart, acpend, pchplain, cchplainreq, pcchplainret, prgruninfo, cruninforeq, pcruninforet, pacpnext), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetText(this: *mut core::ffi::c_void, dwflags: u32, acpstart: i32, acpend: i32, pchtext: windows_core::PCWSTR, cch: u32, pchange: *mut TS_TEXTCHANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP2", "SetText", 11usize, |this| ((**(this as *const *const ITextStoreACP2_Vtbl)).SetText)(this, dwflags, acpstart, acpend, pchtext, cch, pchange), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFormattedText(this: *mut core::ffi::c_void, acpstart: i32, acpend: i32, ppdataobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP2", "GetFormattedText", 12usize, |this| ((**(this as *const *const ITextStoreACP2_Vtbl)).GetFormattedText)(this, acpstart, acpend, ppdataobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEmbedded(this: *mut core::ffi::c_void, acppos: i32, rguidservice: *const windows_core::GUID, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP2", "GetEmbedded", 13usize, |this| ((**(this as *const *const ITextStoreACP2_Vtbl)).GetEmbedded)(this, acppos, rguidservice, riid, ppunk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn QueryInsertEmbedded(this: *mut core::ffi::c_void, pguidservice: *const windows_core::GUID, pformatetc: *const super::super::System::Com::FORMATETC, pfinsertable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP2", "QueryInsertEmbedded", 14usize, |this| ((**(this as *const *const ITextStoreACP2_Vtbl)).QueryInsertEmbedded)(this, pguidservice, pformatetc, pfinsertable), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InsertEmbedded(this: *mut core::ffi::c_void, dwflags: u32, acpstart: i32, acpend: i32, pdataobject: *mut core::ffi::c_void, pchange: *mut TS_TEXTCHANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP2", "InsertEmbedded", 15usize, |this| ((**(this as *const *const ITextStoreACP2_Vtbl)).InsertEmbedded)(this, dwflags, acpstart, acpend, pdataobject, pchange), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertTextAtSelection(this: *mut core::ffi::c_void, dwflags: u32, pchtext: windows_core::PCWSTR, cch: u32, pacpstart: *mut i32, pacpend: *mut i32, pchange: *mut TS_TEXTCHANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP2", "InsertTextAtSelection", 16usize, |this| ((**(this as *const *const ITextStoreACP2_Vtbl)).InsertTextAtSelection)(this, dwflags, pchtext, cch, pacpstart, pacpend, pchange), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InsertEmbeddedAtSelection(this: *mut core::ffi::c_void, dwflags: u32, pdataobject: *mut core::ffi::c_void, pacpstart: *mut i32, pacpend: *mut i32, pchange: *mut TS_TEXTCHANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP2", "InsertEmbeddedAtSelection", 17usize, |this| ((**(this as *const *const ITextStoreACP2_Vtbl)).InsertEmbeddedAtSelection)(this, dwflags, pdataobject, pacpstart, pacpend, pchange), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestSupportedAttrs(this: *mut core::ffi::c_void, dwflags: u32, cfilterattrs: u32, pafilterattrs: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP2", "RequestSupportedAttrs", 18usize, |this| ((**(this as *const *const ITextStoreACP2_Vtbl)).RequestSupportedAttrs)(this, dwflags, cfilterattrs, pafilterattrs), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAttrsAtPosition(this: *mut core::ffi::c_void, acppos: i32, cfilterattrs: u32, pafilterattrs: *const windows_core::GUID, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP2", "RequestAttrsAtPosition", 19usize, |this| ((**(this as *const *const ITextStoreACP2_Vtbl)).RequestAttrsAtPosition)(this, acppos, cfilterattrs, pafilterattrs, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAttrsTransitioningAtPosition(this: *mut core::ffi::c_void, acppos: i32, cfilterattrs: u32, pafilterattrs: *const windows_core::GUID, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP2", "RequestAttrsTransitioningAtPosition", 20usize, |this| ((**(this as *const *const ITextStoreACP2_Vtbl)).RequestAttrsTransitioningAtPosition)(this, acppos, cfilterattrs, pafilterattrs, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindNextAttrTransition(this: *mut core::ffi::c_void, acpstart: i32, acphalt: i32, cfilterattrs: u32, pafilterattrs: *const windows_core::GUID, dwflags: u32, pacpnext: *mut i32, pffound: *mut super::super::Foundation::BOOL, plfoundoffset: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP2", "FindNextAttrTransition", 21usize, |this| ((**(this as *const *const ITextStoreACP2_Vtbl)).FindNextAttrTransition)(this, acpstart, acphalt, cfilterattrs, pafilterattrs, dwflags, pacpnext, pffound, plfoundoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn RetrieveRequestedAttrs(this: *mut core::ffi::c_void, ulcount: u32, paattrvals: *mut TS_ATTRVAL, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP2", "RetrieveRequestedAttrs", 22usize, |this| ((**(this as *const *const ITextStoreACP2_Vtbl)).RetrieveRequestedAttrs)(this, ulcount, paattrvals, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEndACP(this: *mut core::ffi::c_void, pacp: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP2", "GetEndACP", 23usize, |this| ((**(this as *const *const ITextStoreACP2_Vtbl)).GetEndACP)(this, pacp), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActiveView(this: *mut core::ffi::c_void, pvcview: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP2", "GetActiveView", 24usize, |this| ((**(this as *const *const ITextStoreACP2_Vtbl)).GetActiveView)(this, pvcview), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetACPFromPoint(this: *mut core::ffi::c_void, vcview: u32, ptscreen: *const super::super::Foundation::POINT, dwflags: u32, pacp: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP2", "GetACPFromPoint", 25usize, |this| ((**(this as *const *const ITextStoreACP2_Vtbl)).GetACPFromPoint)(this, vcview, ptscreen, dwflags, pacp), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTextExt(this: *mut core::ffi::c_void, vcview: u32, acpstart: i32, acpend: i32, prc: *mut super::super::Foundation::RECT, pfclipped: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP2", "GetTextExt", 26usize, |this| ((**(this as *const *const ITextStoreACP2_Vtbl)).GetTextExt)(this, vcview, acpstart, acpend, prc, pfclipped), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScreenExt(this: *mut core::ffi::c_void, vcview: u32, prc: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACP2", "GetScreenExt", 27usize, |this| ((**(this as *const *const ITextStoreACP2_Vtbl)).GetScreenExt)(this, vcview, prc), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AdviseSink,
+            UnadviseSink,
+            RequestLock,
+            GetStatus,
+            QueryInsert,
+            GetSelection,
+            SetSelection,
+            GetText,
+            SetText,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFormattedText,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFormattedText: 0,
+            GetEmbedded,
+            #[cfg(feature = "Win32_System_Com")]
+            QueryInsertEmbedded,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            QueryInsertEmbedded: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            InsertEmbedded,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InsertEmbedded: 0,
+            InsertTextAtSelection,
+            #[cfg(feature = "Win32_System_Com")]
+            InsertEmbeddedAtSelection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InsertEmbeddedAtSelection: 0,
+            RequestSupportedAttrs,
+            RequestAttrsAtPosition,
+            RequestAttrsTransitioningAtPosition,
+            FindNextAttrTransition,
+            RetrieveRequestedAttrs,
+            GetEndACP,
+            GetActiveView,
+            GetACPFromPoint,
+            GetTextExt,
+            GetScreenExt,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextStoreACP2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITextStoreACPEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ScrollToRect(this: *mut core::ffi::c_void, acpstart: i32, acpend: i32, rc: super::super::Foundation::RECT, dwposition: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACPEx", "ScrollToRect", 3usize, |this| ((**(this as *const *const ITextStoreACPEx_Vtbl)).ScrollToRect)(this, acpstart, acpend, rc, dwposition), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ScrollToRect }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextStoreACPEx as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITextStoreACPServices_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Serialize(this: *mut core::ffi::c_void, pprop: *mut core::ffi::c_void, prange: *mut core::ffi::c_void, phdr: *mut TF_PERSISTENT_PROPERTY_HEADER_ACP, pstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACPServices", "Serialize", 3usize, |this| ((**(this as *const *const ITextStoreACPServices_Vtbl)).Serialize)(this, pprop, prange, phdr, pstream), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Unserialize(this: *mut core::ffi::c_void, pprop: *mut core::ffi::c_void, phdr: *const TF_PERSISTENT_PROPERTY_HEADER_ACP, pstream: *mut core::ffi::c_void, ploader: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACPServices", "Unserialize", 4usize, |this| ((**(this as *const *const ITextStoreACPServices_Vtbl)).Unserialize)(this, pprop, phdr, pstream, ploader), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForceLoadProperty(this: *mut core::ffi::c_void, pprop: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACPServices", "ForceLoadProperty", 5usize, |this| ((**(this as *const *const ITextStoreACPServices_Vtbl)).ForceLoadProperty)(this, pprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRange(this: *mut core::ffi::c_void, acpstart: i32, acpend: i32, pprange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACPServices", "CreateRange", 6usize, |this| ((**(this as *const *const ITextStoreACPServices_Vtbl)).CreateRange)(this, acpstart, acpend, pprange), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Serialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Serialize: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Unserialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Unserialize: 0,
+            ForceLoadProperty,
+            CreateRange,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextStoreACPServices as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITextStoreACPSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnTextChange(this: *mut core::ffi::c_void, dwflags: TEXT_STORE_TEXT_CHANGE_FLAGS, pchange: *const TS_TEXTCHANGE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACPSink", "OnTextChange", 3usize, |this| ((**(this as *const *const ITextStoreACPSink_Vtbl)).OnTextChange)(this, dwflags, pchange), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnSelectionChange(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACPSink", "OnSelectionChange", 4usize, |this| ((**(this as *const *const ITextStoreACPSink_Vtbl)).OnSelectionChange)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnLayoutChange(this: *mut core::ffi::c_void, lcode: TsLayoutCode, vcview: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACPSink", "OnLayoutChange", 5usize, |this| ((**(this as *const *const ITextStoreACPSink_Vtbl)).OnLayoutChange)(this, lcode, vcview), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnStatusChange(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACPSink", "OnStatusChange", 6usize, |this| ((**(this as *const *const ITextStoreACPSink_Vtbl)).OnStatusChange)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnAttrsChange(this: *mut core::ffi::c_void, acpstart: i32, acpend: i32, cattrs: u32, paattrs: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACPSink", "OnAttrsChange", 7usize, |this| ((**(this as *const *const ITextStoreACPSink_Vtbl)).OnAttrsChange)(this, acpstart, acpend, cattrs, paattrs), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnLockGranted(this: *mut core::ffi::c_void, dwlockflags: TEXT_STORE_LOCK_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACPSink", "OnLockGranted", 8usize, |this| ((**(this as *const *const ITextStoreACPSink_Vtbl)).OnLockGranted)(this, dwlockflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnStartEditTransaction(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACPSink", "OnStartEditTransaction", 9usize, |this| ((**(this as *const *const ITextStoreACPSink_Vtbl)).OnStartEditTransaction)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnEndEditTransaction(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACPSink", "OnEndEditTransaction", 10usize, |this| ((**(this as *const *const ITextStoreACPSink_Vtbl)).OnEndEditTransaction)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnTextChange,
+            OnSelectionChange,
+            OnLayoutChange,
+            OnStatusChange,
+            OnAttrsChange,
+            OnLockGranted,
+            OnStartEditTransaction,
+            OnEndEditTransaction,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextStoreACPSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITextStoreACPSinkEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnDisconnect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreACPSinkEx", "OnDisconnect", 11usize, |this| ((**(this as *const *const ITextStoreACPSinkEx_Vtbl)).OnDisconnect)(this), |result| Some(*result))
+        }
+        Self { base__: <ITextStoreACPSink_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnDisconnect }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextStoreACPSinkEx as windows_core::Interface>::IID || <ITextStoreACPSink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITextStoreAnchor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AdviseSink(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, punk: *mut core::ffi::c_void, dwmask: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchor", "AdviseSink", 3usize, |this| ((**(this as *const *const ITextStoreAnchor_Vtbl)).AdviseSink)(this, riid, punk, dwmask), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnadviseSink(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchor", "UnadviseSink", 4usize, |this| ((**(this as *const *const ITextStoreAnchor_Vtbl)).UnadviseSink)(this, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestLock(this: *mut core::ffi::c_void, dwlockflags: u32, phrsession: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchor", "RequestLock", 5usize, |this| ((**(this as *const *const ITextStoreAnchor_Vtbl)).RequestLock)(this, dwlockflags, phrsession), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pdcs: *mut TS_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchor", "GetStatus", 6usize, |this| ((**(this as *const *const ITextStoreAnchor_Vtbl)).GetStatus)(this, pdcs), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryInsert(this: *mut core::ffi::c_void, pateststart: *mut core::ffi::c_void, patestend: *mut core::ffi::c_void, cch: u32, pparesultstart: *mut *mut core::ffi::c_void, pparesultend: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchor", "QueryInsert", 7usize, |this| ((**(this as *const *const ITextStoreAnchor_Vtbl)).QueryInsert)(this, pateststart, patestend, cch, pparesultstart, pparesultend), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSelection(this: *mut core::ffi::c_void, ulindex: u32, ulcount: u32, pselection: *mut TS_SELECTION_ANCHOR, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchor", "GetSelection", 8usize, |this| ((**(this as *const *const ITextStoreAnchor_Vtbl)).GetSelection)(this, ulindex, ulcount, pselection, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelection(this: *mut core::ffi::c_void, ulcount: u32, pselection: *const TS_SELECTION_ANCHOR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchor", "SetSelection", 9usize, |this| ((**(this as *const *const ITextStoreAnchor_Vtbl)).SetSelection)(this, ulcount, pselection), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetText(this: *mut core::ffi::c_void, dwflags: u32, pastart: *mut core::ffi::c_void, paend: *mut core::ffi::c_void, pchtext: windows_core::PWSTR, cchreq: u32, pcch: *mut u32, fupdateanchor: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchor", "GetText", 10usize, |this| ((**(this as *const *const ITextStoreAnchor_Vtbl)).GetText)(this, dwflags, pastart, paend, pchtext, cchreq, pcch, fupdateanchor), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetText(this: *mut core::ffi::c_void, dwflags: u32, pastart: *mut core::ffi::c_void, paend: *mut core::ffi::c_void, pchtext: windows_core::PCWSTR, cch: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchor", "SetText", 11usize, |this| ((**(this as *const *const ITextStoreAnchor_Vtbl)).SetText)(this, dwflags, pastart, paend, pchtext, cch), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFormattedText(this: *mut core::ffi::c_void, pastart: *mut core::ffi::c_void, paend: *mut core::ffi::c_void, ppdataobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchor", "GetFormattedText", 12usize, |this| ((**(this as *const *const ITextStoreAnchor_Vtbl)).GetFormattedText)(this, pastart, paend, ppdataobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEmbedded(this: *mut core::ffi::c_void, dwflags: u32, papos: *mut core::ffi::c_void, rguidservice: *const windows_core::GUID, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchor", "GetEmbedded", 13usize, |this| ((**(this as *const *const ITextStoreAnchor_Vtbl)).GetEmbedded)(this, dwflags, papos, rguidservice, riid, ppunk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InsertEmbedded(this: *mut core::ffi::c_void, dwflags: u32, pastart: *mut core::ffi::c_void, paend: *mut core::ffi::c_void, pdataobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchor", "InsertEmbedded", 14usize, |this| ((**(this as *const *const ITextStoreAnchor_Vtbl)).InsertEmbedded)(this, dwflags, pastart, paend, pdataobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestSupportedAttrs(this: *mut core::ffi::c_void, dwflags: u32, cfilterattrs: u32, pafilterattrs: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchor", "RequestSupportedAttrs", 15usize, |this| ((**(this as *const *const ITextStoreAnchor_Vtbl)).RequestSupportedAttrs)(this, dwflags, cfilterattrs, pafilterattrs), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAttrsAtPosition(this: *mut core::ffi::c_void, papos: *mut core::ffi::c_void, cfilterattrs: u32, pafilterattrs: *const windows_core::GUID, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchor", "RequestAttrsAtPosition", 16usize, |this| ((**(this as *const *const ITextStoreAnchor_Vtbl)).RequestAttrsAtPosition)(this, papos, cfilterattrs, pafilterattrs, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn RequestAttrsTransitioningAtPosition(this: *mut core::ffi::c_void, papos: *mut core::ffi::c_void, cfilterattrs: u32, pafilterattrs: *const windows_core::GUID, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchor", "RequestAttrsTransitioningAtPosition", 17usize, |this| ((**(this as *const *const ITextStoreAnchor_Vtbl)).RequestAttrsTransitioningAtPosition)(this, papos, cfilterattrs, pafilterattrs, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindNextAttrTransition(this: *mut core::ffi::c_void, pastart: *mut core::ffi::c_void, pahalt: *mut core::ffi::c_void, cfilterattrs: u32, pafilterattrs: *const windows_core::GUID, dwflags: u32, pffound: *mut super::super::Foundation::BOOL, plfoundoffset: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchor", "FindNextAttrTransition", 18usize, |this| ((**(this as *const *const ITextStoreAnchor_Vtbl)).FindNextAttrTransition)(this, pastart, pahalt, cfilterattrs, pafilterattrs, dwflags, pffound, plfoundoffset), |result| Some(*result))
+        }
+        unsafe extern "system" fn RetrieveRequestedAttrs(this: *mut core::ffi::c_void, ulcount: u32, paattrvals: *mut TS_ATTRVAL, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchor", "RetrieveRequestedAttrs", 19usize, |this| ((**(this as *const *const ITextStoreAnchor_Vtbl)).RetrieveRequestedAttrs)(this, ulcount, paattrvals, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStart(this: *mut core::ffi::c_void, ppastart: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchor", "GetStart", 20usize, |this| ((**(this as *const *const ITextStoreAnchor_Vtbl)).GetStart)(this, ppastart), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnd(this: *mut core::ffi::c_void, ppaend: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchor", "GetEnd", 21usize, |this| ((**(this as *const *const ITextStoreAnchor_Vtbl)).GetEnd)(this, ppaend), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActiveView(this: *mut core::ffi::c_void, pvcview: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchor", "GetActiveView", 22usize, |this| ((**(this as *const *const ITextStoreAnchor_Vtbl)).GetActiveView)(this, pvcview), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAnchorFromPoint(this: *mut core::ffi::c_void, vcview: u32, ptscreen: *const super::super::Foundation::POINT, dwflags: u32, ppasite: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchor", "GetAnchorFromPoint", 23usize, |this| ((**(this as *const *const ITextStoreAnchor_Vtbl)).GetAnchorFromPoint)(this, vcview, ptscreen, dwflags, ppasite), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTextExt(this: *mut core::ffi::c_void, vcview: u32, pastart: *mut core::ffi::c_void, paend: *mut core::ffi::c_void, prc: *mut super::super::Foundation::RECT, pfclipped: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchor", "GetTextExt", 24usize, |this| ((**(this as *const *const ITextStoreAnchor_Vtbl)).GetTextExt)(this, vcview, pastart, paend, prc, pfclipped), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScreenExt(this: *mut core::ffi::c_void, vcview: u32, prc: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchor", "GetScreenExt", 25usize, |this| ((**(this as *const *const ITextStoreAnchor_Vtbl)).GetScreenExt)(this, vcview, prc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWnd(this: *mut core::ffi::c_void, vcview: u32, phwnd: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchor", "GetWnd", 26usize, |this| ((**(this as *const *const ITextStoreAnchor_Vtbl)).GetWnd)(this, vcview, phwnd), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn QueryInsertEmbedded(this: *mut core::ffi::c_void, pguidservice: *const windows_core::GUID, pformatetc: *const super::super::System::Com::FORMATETC, pfinsertable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchor", "QueryInsertEmbedded", 27usize, |this| ((**(this as *const *const ITextStoreAnchor_Vtbl)).QueryInsertEmbedded)(this, pguidservice, pformatetc, pfinsertable), |result| Some(*result))
+        }
+        unsafe extern "system" fn InsertTextAtSelection(this: *mut core::ffi::c_void, dwflags: u32, pchtext: windows_core::PCWSTR, cch: u32, ppastart: *mut *mut core::ffi::c_void, ppaend: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchor", "InsertTextAtSelection", 28usize, |this| ((**(this as *const *const ITextStoreAnchor_Vtbl)).InsertTextAtSelection)(this, dwflags, pchtext, cch, ppastart, ppaend), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InsertEmbeddedAtSelection(this: *mut core::ffi::c_void, dwflags: u32, pdataobject: *mut core::ffi::c_void, ppastart: *mut *mut core::ffi::c_void, ppaend: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchor", "InsertEmbeddedAtSelection", 29usize, |this| ((**(this as *const *const ITextStoreAnchor_Vtbl)).InsertEmbeddedAtSelection)(this, dwflags, pdataobject, ppastart, ppaend), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AdviseSink,
+            UnadviseSink,
+            RequestLock,
+            GetStatus,
+            QueryInsert,
+            GetSelection,
+            SetSelection,
+            GetText,
+            SetText,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFormattedText,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFormattedText: 0,
+            GetEmbedded,
+            #[cfg(feature = "Win32_System_Com")]
+            InsertEmbedded,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InsertEmbedded: 0,
+            RequestSupportedAttrs,
+            RequestAttrsAtPosition,
+            RequestAttrsTransitioningAtPosition,
+            FindNextAttrTransition,
+            RetrieveRequestedAttrs,
+            GetStart,
+            GetEnd,
+            GetActiveView,
+            GetAnchorFromPoint,
+            GetTextExt,
+            GetScreenExt,
+            GetWnd,
+            #[cfg(feature = "Win32_System_Com")]
+            QueryInsertEmbedded,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            QueryInsertEmbedded: 0,
+            InsertTextAtSelection,
+            #[cfg(feature = "Win32_System_Com")]
+            InsertEmbeddedAtSelection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InsertEmbeddedAtSelection: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextStoreAnchor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITextStoreAnchorEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ScrollToRect(this: *mut core::ffi::c_void, pstart: *mut core::ffi::c_void, pend: *mut core::ffi::c_void, rc: super::super::Foundation::RECT, dwposition: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchorEx", "ScrollToRect", 3usize, |this| ((**(this as *const *const ITextStoreAnchorEx_Vtbl)).ScrollToRect)(this, pstart, pend, rc, dwposition), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ScrollToRect }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextStoreAnchorEx as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITextStoreAnchorSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnTextChange(this: *mut core::ffi::c_void, dwflags: TEXT_STORE_CHANGE_FLAGS, pastart: *mut core::ffi::c_void, paend: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchorSink", "OnTextChange", 3usize, |this| ((**(this as *const *const ITextStoreAnchorSink_Vtbl)).OnTextChange)(this, dwflags, pastart, paend), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnSelectionChange(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchorSink", "OnSelectionChange", 4usize, |this| ((**(this as *const *const ITextStoreAnchorSink_Vtbl)).OnSelectionChange)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnLayoutChange(this: *mut core::ffi::c_void, lcode: TsLayoutCode, vcview: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchorSink", "OnLayoutChange", 5usize, |this| ((**(this as *const *const ITextStoreAnchorSink_Vtbl)).OnLayoutChange)(this, lcode, vcview), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnStatusChange(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchorSink", "OnStatusChange", 6usize, |this| ((**(this as *const *const ITextStoreAnchorSink_Vtbl)).OnStatusChange)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnAttrsChange(this: *mut core::ffi::c_void, pastart: *mut core::ffi::c_void, paend: *mut core::ffi::c_void, cattrs: u32, paattrs: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchorSink", "OnAttrsChange", 7usize, |this| ((**(this as *const *const ITextStoreAnchorSink_Vtbl)).OnAttrsChange)(this, pastart, paend, cattrs, paattrs), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnLockGranted(this: *mut core::ffi::c_void, dwlockflags: TEXT_STORE_LOCK_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchorSink", "OnLockGranted", 8usize, |this| ((**(this as *const *const ITextStoreAnchorSink_Vtbl)).OnLockGranted)(this, dwlockflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnStartEditTransaction(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchorSink", "OnStartEditTransaction", 9usize, |this| ((**(this as *const *const ITextStoreAnchorSink_Vtbl)).OnStartEditTransaction)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnEndEditTransaction(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreAnchorSink", "OnEndEditTransaction", 10usize, |this| ((**(this as *const *const ITextStoreAnchorSink_Vtbl)).OnEndEditTransaction)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnTextChange,
+            OnSelectionChange,
+            OnLayoutChange,
+            OnStatusChange,
+            OnAttrsChange,
+            OnLockGranted,
+            OnStartEditTransaction,
+            OnEndEditTransaction,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextStoreAnchorSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITextStoreSinkAnchorEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnDisconnect(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITextStoreSinkAnchorEx", "OnDisconnect", 11usize, |this| ((**(this as *const *const ITextStoreSinkAnchorEx_Vtbl)).OnDisconnect)(this), |result| Some(*result))
+        }
+        Self { base__: <ITextStoreAnchorSink_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnDisconnect }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITextStoreSinkAnchorEx as windows_core::Interface>::IID || <ITextStoreAnchorSink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfActiveLanguageProfileNotifySink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnActivated(this: *mut core::ffi::c_void, clsid: *const windows_core::GUID, guidprofile: *const windows_core::GUID, factivated: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfActiveLanguageProfileNotifySink", "OnActivated", 3usize, |this| ((**(this as *const *const ITfActiveLanguageProfileNotifySink_Vtbl)).OnActivated)(this, clsid, guidprofile, factivated), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnActivated }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfActiveLanguageProfileNotifySink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfCandidateList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumCandidates(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCandidateList", "EnumCandidates", 3usize, |this| ((**(this as *const *const ITfCandidateList_Vtbl)).EnumCandidates)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCandidate(this: *mut core::ffi::c_void, nindex: u32, ppcand: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCandidateList", "GetCandidate", 4usize, |this| ((**(this as *const *const ITfCandidateList_Vtbl)).GetCandidate)(this, nindex, ppcand), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCandidateNum(this: *mut core::ffi::c_void, pncnt: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCandidateList", "GetCandidateNum", 5usize, |this| ((**(this as *const *const ITfCandidateList_Vtbl)).GetCandidateNum)(this, pncnt), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetResult(this: *mut core::ffi::c_void, nindex: u32, imcr: TfCandidateResult) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCandidateList", "SetResult", 6usize, |this| ((**(this as *const *const ITfCandidateList_Vtbl)).SetResult)(this, nindex, imcr), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnumCandidates, GetCandidate, GetCandidateNum, SetResult }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfCandidateList as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfCandidateListUIElement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetUpdatedFlags(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCandidateListUIElement", "GetUpdatedFlags", 7usize, |this| ((**(this as *const *const ITfCandidateListUIElement_Vtbl)).GetUpdatedFlags)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDocumentMgr(this: *mut core::ffi::c_void, ppdim: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCandidateListUIElement", "GetDocumentMgr", 8usize, |this| ((**(this as *const *const ITfCandidateListUIElement_Vtbl)).GetDocumentMgr)(this, ppdim), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCount(this: *mut core::ffi::c_void, pucount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCandidateListUIElement", "GetCount", 9usize, |this| ((**(this as *const *const ITfCandidateListUIElement_Vtbl)).GetCount)(this, pucount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSelection(this: *mut core::ffi::c_void, puindex: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCandidateListUIElement", "GetSelection", 10usize, |this| ((**(this as *const *const ITfCandidateListUIElement_Vtbl)).GetSelection)(this, puindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetString(this: *mut core::ffi::c_void, uindex: u32, pstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCandidateListUIElement", "GetString", 11usize, |this| ((**(this as *const *const ITfCandidateListUIElement_Vtbl)).GetString)(this, uindex, pstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPageIndex(this: *mut core::ffi::c_void, pindex: *mut u32, usize: u32, pupagecnt: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCandidateListUIElement", "GetPageIndex", 12usize, |this| ((**(this as *const *const ITfCandidateListUIElement_Vtbl)).GetPageIndex)(this, pindex, usize, pupagecnt), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPageIndex(this: *mut core::ffi::c_void, pindex: *const u32, upagecnt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCandidateListUIElement", "SetPageIndex", 13usize, |this| ((**(this as *const *const ITfCandidateListUIElement_Vtbl)).SetPageIndex)(this, pindex, upagecnt), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentPage(this: *mut core::ffi::c_void, pupage: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCandidateListUIElement", "GetCurrentPage", 14usize, |this| ((**(this as *const *const ITfCandidateListUIElement_Vtbl)).GetCurrentPage)(this, pupage), |result| Some(*result))
+        }
+        Self {
+            base__: <ITfUIElement_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetUpdatedFlags,
+            GetDocumentMgr,
+            GetCount,
+            GetSelection,
+            GetString,
+            GetPageIndex,
+            SetPageIndex,
+            GetCurrentPage,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfCandidateListUIElement as windows_core::Interface>::IID || <ITfUIElement_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfCandidateListUIElementBehavior_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSelection(this: *mut core::ffi::c_void, nindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCandidateListUIElementBehavior", "SetSelection", 15usize, |this| ((**(this as *const *const ITfCandidateListUIElementBehavior_Vtbl)).SetSelection)(this, nindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn Finalize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCandidateListUIElementBehavior", "Finalize", 16usize, |this| ((**(this as *const *const ITfCandidateListUIElementBehavior_Vtbl)).Finalize)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Abort(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCandidateListUIElementBehavior", "Abort", 17usize, |this| ((**(this as *const *const ITfCandidateListUIElementBehavior_Vtbl)).Abort)(this), |result| Some(*result))
+        }
+        Self { base__: <ITfCandidateListUIElement_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetSelection, Finalize, Abort }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfCandidateListUIElementBehavior as windows_core::Interface>::IID || <ITfCandidateListUIElement_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfCandidateString_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetString(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCandidateString", "GetString", 3usize, |this| ((**(this as *const *const ITfCandidateString_Vtbl)).GetString)(this, pbstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIndex(this: *mut core::ffi::c_void, pnindex: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCandidateString", "GetIndex", 4usize, |this| ((**(this as *const *const ITfCandidateString_Vtbl)).GetIndex)(this, pnindex), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetString, GetIndex }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfCandidateString as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITfCategoryMgr_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterCategory(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, rcatid: *const windows_core::GUID, rguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCategoryMgr", "RegisterCategory", 3usize, |this| ((**(this as *const *const ITfCategoryMgr_Vtbl)).RegisterCategory)(this, rclsid, rcatid, rguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterCategory(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, rcatid: *const windows_core::GUID, rguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCategoryMgr", "UnregisterCategory", 4usize, |this| ((**(this as *const *const ITfCategoryMgr_Vtbl)).UnregisterCategory)(this, rclsid, rcatid, rguid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumCategoriesInItem(this: *mut core::ffi::c_void, rguid: *const windows_core::GUID, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCategoryMgr", "EnumCategoriesInItem", 5usize, |this| ((**(this as *const *const ITfCategoryMgr_Vtbl)).EnumCategoriesInItem)(this, rguid, ppenum), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumItemsInCategory(this: *mut core::ffi::c_void, rcatid: *const windows_core::GUID, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCategoryMgr", "EnumItemsInCategory", 6usize, |this| ((**(this as *const *const ITfCategoryMgr_Vtbl)).EnumItemsInCategory)(this, rcatid, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindClosestCategory(this: *mut core::ffi::c_void, rguid: *const windows_core::GUID, pcatid: *mut windows_core::GUID, ppcatidlist: *const *const windows_core::GUID, ulcount: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCategoryMgr", "FindClosestCategory", 7usize, |this| ((**(this as *const *const ITfCategoryMgr_Vtbl)).FindClosestCategory)(this, rguid, pcatid, ppcatidlist, ulcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterGUIDDescription(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, rguid: *const windows_core::GUID, pchdesc: windows_core::PCWSTR, cch: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCategoryMgr", "RegisterGUIDDescription", 8usize, |this| ((**(this as *const *const ITfCategoryMgr_Vtbl)).RegisterGUIDDescription)(this, rclsid, rguid, pchdesc, cch), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterGUIDDescription(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, rguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCategoryMgr", "UnregisterGUIDDescription", 9usize, |this| ((**(this as *const *const ITfCategoryMgr_Vtbl)).UnregisterGUIDDescription)(this, rclsid, rguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGUIDDescription(this: *mut core::ffi::c_void, rguid: *const windows_core::GUID, pbstrdesc: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCategoryMgr", "GetGUIDDescription", 10usize, |this| ((**(this as *const *const ITfCategoryMgr_Vtbl)).GetGUIDDescription)(this, rguid, pbstrdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterGUIDDWORD(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, rguid: *const windows_core::GUID, dw: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCategoryMgr", "RegisterGUIDDWORD", 11usize, |this| ((**(this as *const *const ITfCategoryMgr_Vtbl)).RegisterGUIDDWORD)(this, rclsid, rguid, dw), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterGUIDDWORD(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, rguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCategoryMgr", "UnregisterGUIDDWORD", 12usize, |this| ((**(this as *const *const ITfCategoryMgr_Vtbl)).UnregisterGUIDDWORD)(this, rclsid, rguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGUIDDWORD(this: *mut core::ffi::c_void, rguid: *const windows_core::GUID, pdw: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCategoryMgr", "GetGUIDDWORD", 13usize, |this| ((**(this as *const *const ITfCategoryMgr_Vtbl)).GetGUIDDWORD)(this, rguid, pdw), |result| Some(*result))
+        }
+        unsafe extern "system" fn RegisterGUID(this: *mut core::ffi::c_void, rguid: *const windows_core::GUID, pguidatom: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCategoryMgr", "RegisterGUID", 14usize, |this| ((**(this as *const *const ITfCategoryMgr_Vtbl)).RegisterGUID)(this, rguid, pguidatom), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGUID(this: *mut core::ffi::c_void, guidatom: u32, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCategoryMgr", "GetGUID", 15usize, |this| ((**(this as *const *const ITfCategoryMgr_Vtbl)).GetGUID)(this, guidatom, pguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEqualTfGuidAtom(this: *mut core::ffi::c_void, guidatom: u32, rguid: *const windows_core::GUID, pfequal: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCategoryMgr", "IsEqualTfGuidAtom", 16usize, |this| ((**(this as *const *const ITfCategoryMgr_Vtbl)).IsEqualTfGuidAtom)(this, guidatom, rguid, pfequal), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RegisterCategory,
+            UnregisterCategory,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumCategoriesInItem,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumCategoriesInItem: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumItemsInCategory,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumItemsInCategory: 0,
+            FindClosestCategory,
+            RegisterGUIDDescription,
+            UnregisterGUIDDescription,
+            GetGUIDDescription,
+            RegisterGUIDDWORD,
+            UnregisterGUIDDWORD,
+            GetGUIDDWORD,
+            RegisterGUID,
+            GetGUID,
+            IsEqualTfGuidAtom,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfCategoryMgr as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfCleanupContextDurationSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnStartCleanupContext(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCleanupContextDurationSink", "OnStartCleanupContext", 3usize, |this| ((**(this as *const *const ITfCleanupContextDurationSink_Vtbl)).OnStartCleanupContext)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnEndCleanupContext(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCleanupContextDurationSink", "OnEndCleanupContext", 4usize, |this| ((**(this as *const *const ITfCleanupContextDurationSink_Vtbl)).OnEndCleanupContext)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnStartCleanupContext, OnEndCleanupContext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfCleanupContextDurationSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfCleanupContextSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnCleanupContext(this: *mut core::ffi::c_void, ecwrite: u32, pic: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCleanupContextSink", "OnCleanupContext", 3usize, |this| ((**(this as *const *const ITfCleanupContextSink_Vtbl)).OnCleanupContext)(this, ecwrite, pic), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnCleanupContext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfCleanupContextSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfClientId_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetClientId(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, ptid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfClientId", "GetClientId", 3usize, |this| ((**(this as *const *const ITfClientId_Vtbl)).GetClientId)(this, rclsid, ptid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetClientId }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfClientId as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfCompartment_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, tid: u32, pvarvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCompartment", "SetValue", 3usize, |this| ((**(this as *const *const ITfCompartment_Vtbl)).SetValue)(this, tid, pvarvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, pvarvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCompartment", "GetValue", 4usize, |this| ((**(this as *const *const ITfCompartment_Vtbl)).GetValue)(this, pvarvalue), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetValue, GetValue }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfCompartment as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfCompartmentEventSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnChange(this: *mut core::ffi::c_void, rguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCompartmentEventSink", "OnChange", 3usize, |this| ((**(this as *const *const ITfCompartmentEventSink_Vtbl)).OnChange)(this, rguid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnChange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfCompartmentEventSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITfCompartmentMgr_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCompartment(this: *mut core::ffi::c_void, rguid: *const windows_core::GUID, ppcomp: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCompartmentMgr", "GetCompartment", 3usize, |this| ((**(this as *const *const ITfCompartmentMgr_Vtbl)).GetCompartment)(this, rguid, ppcomp), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearCompartment(this: *mut core::ffi::c_void, tid: u32, rguid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCompartmentMgr", "ClearCompartment", 4usize, |this| ((**(this as *const *const ITfCompartmentMgr_Vtbl)).ClearCompartment)(this, tid, rguid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumCompartments(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCompartmentMgr", "EnumCompartments", 5usize, |this| ((**(this as *const *const ITfCompartmentMgr_Vtbl)).EnumCompartments)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCompartment,
+            ClearCompartment,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumCompartments,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumCompartments: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfCompartmentMgr as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfComposition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRange(this: *mut core::ffi::c_void, pprange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfComposition", "GetRange", 3usize, |this| ((**(this as *const *const ITfComposition_Vtbl)).GetRange)(this, pprange), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShiftStart(this: *mut core::ffi::c_void, ecwrite: u32, pnewstart: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfComposition", "ShiftStart", 4usize, |this| ((**(this as *const *const ITfComposition_Vtbl)).ShiftStart)(this, ecwrite, pnewstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShiftEnd(this: *mut core::ffi::c_void, ecwrite: u32, pnewend: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfComposition", "ShiftEnd", 5usize, |this| ((**(this as *const *const ITfComposition_Vtbl)).ShiftEnd)(this, ecwrite, pnewend), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndComposition(this: *mut core::ffi::c_void, ecwrite: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfComposition", "EndComposition", 6usize, |this| ((**(this as *const *const ITfComposition_Vtbl)).EndComposition)(this, ecwrite), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRange, ShiftStart, ShiftEnd, EndComposition }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfComposition as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfCompositionSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnCompositionTerminated(this: *mut core::ffi::c_void, ecwrite: u32, pcomposition: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCompositionSink", "OnCompositionTerminated", 3usize, |this| ((**(this as *const *const ITfCompositionSink_Vtbl)).OnCompositionTerminated)(this, ecwrite, pcomposition), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnCompositionTerminated }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfCompositionSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfCompositionView_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOwnerClsid(this: *mut core::ffi::c_void, pclsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCompositionView", "GetOwnerClsid", 3usize, |this| ((**(this as *const *const ITfCompositionView_Vtbl)).GetOwnerClsid)(this, pclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRange(this: *mut core::ffi::c_void, pprange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCompositionView", "GetRange", 4usize, |this| ((**(this as *const *const ITfCompositionView_Vtbl)).GetRange)(this, pprange), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetOwnerClsid, GetRange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfCompositionView as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfConfigureSystemKeystrokeFeed_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DisableSystemKeystrokeFeed(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfConfigureSystemKeystrokeFeed", "DisableSystemKeystrokeFeed", 3usize, |this| ((**(this as *const *const ITfConfigureSystemKeystrokeFeed_Vtbl)).DisableSystemKeystrokeFeed)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableSystemKeystrokeFeed(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfConfigureSystemKeystrokeFeed", "EnableSystemKeystrokeFeed", 4usize, |this| ((**(this as *const *const ITfConfigureSystemKeystrokeFeed_Vtbl)).EnableSystemKeystrokeFeed)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DisableSystemKeystrokeFeed, EnableSystemKeystrokeFeed }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfConfigureSystemKeystrokeFeed as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RequestEditSession(this: *mut core::ffi::c_void, tid: u32, pes: *mut core::ffi::c_void, dwflags: TF_CONTEXT_EDIT_CONTEXT_FLAGS, phrsession: *mut windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContext", "RequestEditSession", 3usize, |this| ((**(this as *const *const ITfContext_Vtbl)).RequestEditSession)(this, tid, pes, dwflags, phrsession), |result| Some(*result))
+        }
+        unsafe extern "system" fn InWriteSession(this: *mut core::ffi::c_void, tid: u32, pfwritesession: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContext", "InWriteSession", 4usize, |this| ((**(this as *const *const ITfContext_Vtbl)).InWriteSession)(this, tid, pfwritesession), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSelection(this: *mut core::ffi::c_void, ec: u32, ulindex: u32, ulcount: u32, pselection: *mut TF_SELECTION, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContext", "GetSelection", 5usize, |this| ((**(this as *const *const ITfContext_Vtbl)).GetSelection)(this, ec, ulindex, ulcount, pselection, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetSelection(this: *mut core::ffi::c_void, ec: u32, ulcount: u32, pselection: *const TF_SELECTION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContext", "SetSelection", 6usize, |this| ((**(this as *const *const ITfContext_Vtbl)).SetSelection)(this, ec, ulcount, pselection), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStart(this: *mut core::ffi::c_void, ec: u32, ppstart: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContext", "GetStart", 7usize, |this| ((**(this as *const *const ITfContext_Vtbl)).GetStart)(this, ec, ppstart), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEnd(this: *mut core::ffi::c_void, ec: u32, ppend: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContext", "GetEnd", 8usize, |this| ((**(this as *const *const ITfContext_Vtbl)).GetEnd)(this, ec, ppend), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActiveView(this: *mut core::ffi::c_void, ppview: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContext", "GetActiveView", 9usize, |this| ((**(this as *const *const ITfContext_Vtbl)).GetActiveView)(this, ppview), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumViews(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContext", "EnumViews", 10usize, |this| ((**(this as *const *const ITfContext_Vtbl)).EnumViews)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pdcs: *mut TS_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContext", "GetStatus", 11usize, |this| ((**(this as *const *const ITfContext_Vtbl)).GetStatus)(this, pdcs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetProperty(this: *mut core::ffi::c_void, guidprop: *const windows_core::GUID, ppprop: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContext", "GetProperty", 12usize, |this| ((**(this as *const *const ITfContext_Vtbl)).GetProperty)(this, guidprop, ppprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAppProperty(this: *mut core::ffi::c_void, guidprop: *const windows_core::GUID, ppprop: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContext", "GetAppProperty", 13usize, |this| ((**(this as *const *const ITfContext_Vtbl)).GetAppProperty)(this, guidprop, ppprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn TrackProperties(this: *mut core::ffi::c_void, prgprop: *const *const windows_core::GUID, cprop: u32, prgappprop: *const *const windows_core::GUID, cappprop: u32, ppproperty: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContext", "TrackProperties", 14usize, |this| ((**(this as *const *const ITfContext_Vtbl)).TrackProperties)(this, prgprop, cprop, prgappprop, cappprop, ppproperty), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumProperties(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContext", "EnumProperties", 15usize, |this| ((**(this as *const *const ITfContext_Vtbl)).EnumProperties)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDocumentMgr(this: *mut core::ffi::c_void, ppdm: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContext", "GetDocumentMgr", 16usize, |this| ((**(this as *const *const ITfContext_Vtbl)).GetDocumentMgr)(this, ppdm), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRangeBackup(this: *mut core::ffi::c_void, ec: u32, prange: *mut core::ffi::c_void, ppbackup: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContext", "CreateRangeBackup", 17usize, |this| ((**(this as *const *const ITfContext_Vtbl)).CreateRangeBackup)(this, ec, prange, ppbackup), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            RequestEditSession,
+            InWriteSession,
+            GetSelection,
+            SetSelection,
+            GetStart,
+            GetEnd,
+            GetActiveView,
+            EnumViews,
+            GetStatus,
+            GetProperty,
+            GetAppProperty,
+            TrackProperties,
+            EnumProperties,
+            GetDocumentMgr,
+            CreateRangeBackup,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfContext as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfContextComposition_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn StartComposition(this: *mut core::ffi::c_void, ecwrite: u32, pcompositionrange: *mut core::ffi::c_void, psink: *mut core::ffi::c_void, ppcomposition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextComposition", "StartComposition", 3usize, |this| ((**(this as *const *const ITfContextComposition_Vtbl)).StartComposition)(this, ecwrite, pcompositionrange, psink, ppcomposition), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumCompositions(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextComposition", "EnumCompositions", 4usize, |this| ((**(this as *const *const ITfContextComposition_Vtbl)).EnumCompositions)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindComposition(this: *mut core::ffi::c_void, ecread: u32, ptestrange: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextComposition", "FindComposition", 5usize, |this| ((**(this as *const *const ITfContextComposition_Vtbl)).FindComposition)(this, ecread, ptestrange, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn TakeOwnership(this: *mut core::ffi::c_void, ecwrite: u32, pcomposition: *mut core::ffi::c_void, psink: *mut core::ffi::c_void, ppcomposition: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextComposition", "TakeOwnership", 6usize, |this| ((**(this as *const *const ITfContextComposition_Vtbl)).TakeOwnership)(this, ecwrite, pcomposition, psink, ppcomposition), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            StartComposition,
+            EnumCompositions,
+            FindComposition,
+            TakeOwnership,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfContextComposition as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfContextKeyEventSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnKeyDown(this: *mut core::ffi::c_void, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM, pfeaten: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextKeyEventSink", "OnKeyDown", 3usize, |this| ((**(this as *const *const ITfContextKeyEventSink_Vtbl)).OnKeyDown)(this, wparam, lparam, pfeaten), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnKeyUp(this: *mut core::ffi::c_void, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM, pfeaten: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextKeyEventSink", "OnKeyUp", 4usize, |this| ((**(this as *const *const ITfContextKeyEventSink_Vtbl)).OnKeyUp)(this, wparam, lparam, pfeaten), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnTestKeyDown(this: *mut core::ffi::c_void, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM, pfeaten: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextKeyEventSink", "OnTestKeyDown", 5usize, |this| ((**(this as *const *const ITfContextKeyEventSink_Vtbl)).OnTestKeyDown)(this, wparam, lparam, pfeaten), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnTestKeyUp(this: *mut core::ffi::c_void, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM, pfeaten: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextKeyEventSink", "OnTestKeyUp", 6usize, |this| ((**(this as *const *const ITfContextKeyEventSink_Vtbl)).OnTestKeyUp)(this, wparam, lparam, pfeaten), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnKeyDown, OnKeyUp, OnTestKeyDown, OnTestKeyUp }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfContextKeyEventSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfContextOwner_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetACPFromPoint(this: *mut core::ffi::c_void, ptscreen: *const super::super::Foundation::POINT, dwflags: u32, pacp: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextOwner", "GetACPFromPoint", 3usize, |this| ((**(this as *const *const ITfContextOwner_Vtbl)).GetACPFromPoint)(this, ptscreen, dwflags, pacp), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTextExt(this: *mut core::ffi::c_void, acpstart: i32, acpend: i32, prc: *mut super::super::Foundation::RECT, pfclipped: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextOwner", "GetTextExt", 4usize, |this| ((**(this as *const *const ITfContextOwner_Vtbl)).GetTextExt)(this, acpstart, acpend, prc, pfclipped), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScreenExt(this: *mut core::ffi::c_void, prc: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextOwner", "GetScreenExt", 5usize, |this| ((**(this as *const *const ITfContextOwner_Vtbl)).GetScreenExt)(this, prc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pdcs: *mut TS_STATUS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextOwner", "GetStatus", 6usize, |this| ((**(this as *const *const ITfContextOwner_Vtbl)).GetStatus)(this, pdcs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWnd(this: *mut core::ffi::c_void, phwnd: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextOwner", "GetWnd", 7usize, |this| ((**(this as *const *const ITfContextOwner_Vtbl)).GetWnd)(this, phwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttribute(this: *mut core::ffi::c_void, rguidattribute: *const windows_core::GUID, pvarvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextOwner", "GetAttribute", 8usize, |this| ((**(this as *const *const ITfContextOwner_Vtbl)).GetAttribute)(this, rguidattribute, pvarvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetACPFromPoint,
+            GetTextExt,
+            GetScreenExt,
+            GetStatus,
+            GetWnd,
+            GetAttribute,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfContextOwner as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfContextOwnerCompositionServices_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn TerminateComposition(this: *mut core::ffi::c_void, pcomposition: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextOwnerCompositionServices", "TerminateComposition", 7usize, |this| ((**(this as *const *const ITfContextOwnerCompositionServices_Vtbl)).TerminateComposition)(this, pcomposition), |result| Some(*result))
+        }
+        Self { base__: <ITfContextComposition_Vtbl as windows_core::imp::ProxyVtable>::PROXY, TerminateComposition }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfContextOwnerCompositionServices as windows_core::Interface>::IID || <ITfContextComposition_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfContextOwnerCompositionSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnStartComposition(this: *mut core::ffi::c_void, pcomposition: *mut core::ffi::c_void, pfok: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextOwnerCompositionSink", "OnStartComposition", 3usize, |this| ((**(this as *const *const ITfContextOwnerCompositionSink_Vtbl)).OnStartComposition)(this, pcomposition, pfok), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnUpdateComposition(this: *mut core::ffi::c_void, pcomposition: *mut core::ffi::c_void, prangenew: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextOwnerCompositionSink", "OnUpdateComposition", 4usize, |this| ((**(this as *const *const ITfContextOwnerCompositionSink_Vtbl)).OnUpdateComposition)(this, pcomposition, prangenew), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnEndComposition(this: *mut core::ffi::c_void, pcomposition: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextOwnerCompositionSink", "OnEndComposition", 5usize, |this| ((**(this as *const *const ITfContextOwnerCompositionSink_Vtbl)).OnEndComposition)(this, pcomposition), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnStartComposition, OnUpdateComposition, OnEndComposition }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfContextOwnerCompositionSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITfContextOwnerServices_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnLayoutChange(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextOwnerServices", "OnLayoutChange", 3usize, |this| ((**(this as *const *const ITfContextOwnerServices_Vtbl)).OnLayoutChange)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnStatusChange(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextOwnerServices", "OnStatusChange", 4usize, |this| ((**(this as *const *const ITfContextOwnerServices_Vtbl)).OnStatusChange)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnAttributeChange(this: *mut core::ffi::c_void, rguidattribute: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextOwnerServices", "OnAttributeChange", 5usize, |this| ((**(this as *const *const ITfContextOwnerServices_Vtbl)).OnAttributeChange)(this, rguidattribute), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Serialize(this: *mut core::ffi::c_void, pprop: *mut core::ffi::c_void, prange: *mut core::ffi::c_void, phdr: *mut TF_PERSISTENT_PROPERTY_HEADER_ACP, pstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextOwnerServices", "Serialize", 6usize, |this| ((**(this as *const *const ITfContextOwnerServices_Vtbl)).Serialize)(this, pprop, prange, phdr, pstream), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Unserialize(this: *mut core::ffi::c_void, pprop: *mut core::ffi::c_void, phdr: *const TF_PERSISTENT_PROPERTY_HEADER_ACP, pstream: *mut core::ffi::c_void, ploader: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextOwnerServices", "Unserialize", 7usize, |this| ((**(this as *const *const ITfContextOwnerServices_Vtbl)).Unserialize)(this, pprop, phdr, pstream, ploader), |result| Some(*result))
+        }
+        unsafe extern "system" fn ForceLoadProperty(this: *mut core::ffi::c_void, pprop: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextOwnerServices", "ForceLoadProperty", 8usize, |this| ((**(this as *const *const ITfContextOwnerServices_Vtbl)).ForceLoadProperty)(this, pprop), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateRange(this: *mut core::ffi::c_void, acpstart: i32, acpend: i32, pprange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextOwnerServices", "CreateRange", 9usize, |this| ((**(this as *const *const ITfContextOwnerServices_Vtbl)).CreateRange)(this, acpstart, acpend, pprange), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnLayoutChange,
+            OnStatusChange,
+            OnAttributeChange,
+            #[cfg(feature = "Win32_System_Com")]
+            Serialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Serialize: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            Unserialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Unserialize: 0,
+            ForceLoadProperty,
+            CreateRange,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfContextOwnerServices as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfContextView_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetRangeFromPoint(this: *mut core::ffi::c_void, ec: u32, ppt: *const super::super::Foundation::POINT, dwflags: u32, pprange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextView", "GetRangeFromPoint", 3usize, |this| ((**(this as *const *const ITfContextView_Vtbl)).GetRangeFromPoint)(this, ec, ppt, dwflags, pprange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTextExt(this: *mut core::ffi::c_void, ec: u32, prange: *mut core::ffi::c_void, prc: *mut super::super::Foundation::RECT, pfclipped: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextView", "GetTextExt", 4usize, |this| ((**(this as *const *const ITfContextView_Vtbl)).GetTextExt)(this, ec, prange, prc, pfclipped), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetScreenExt(this: *mut core::ffi::c_void, prc: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextView", "GetScreenExt", 5usize, |this| ((**(this as *const *const ITfContextView_Vtbl)).GetScreenExt)(this, prc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetWnd(this: *mut core::ffi::c_void, phwnd: *mut super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfContextView", "GetWnd", 6usize, |this| ((**(this as *const *const ITfContextView_Vtbl)).GetWnd)(this, phwnd), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetRangeFromPoint, GetTextExt, GetScreenExt, GetWnd }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfContextView as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITfCreatePropertyStore_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsStoreSerializable(this: *mut core::ffi::c_void, guidprop: *const windows_core::GUID, prange: *mut core::ffi::c_void, ppropstore: *mut core::ffi::c_void, pfserializable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCreatePropertyStore", "IsStoreSerializable", 3usize, |this| ((**(this as *const *const ITfCreatePropertyStore_Vtbl)).IsStoreSerializable)(this, guidprop, prange, ppropstore, pfserializable), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CreatePropertyStore(this: *mut core::ffi::c_void, guidprop: *const windows_core::GUID, prange: *mut core::ffi::c_void, cb: u32, pstream: *mut core::ffi::c_void, ppstore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfCreatePropertyStore", "CreatePropertyStore", 4usize, |this| ((**(this as *const *const ITfCreatePropertyStore_Vtbl)).CreatePropertyStore)(this, guidprop, prange, cb, pstream, ppstore), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsStoreSerializable,
+            #[cfg(feature = "Win32_System_Com")]
+            CreatePropertyStore,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CreatePropertyStore: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfCreatePropertyStore as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfDisplayAttributeInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetGUID(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfDisplayAttributeInfo", "GetGUID", 3usize, |this| ((**(this as *const *const ITfDisplayAttributeInfo_Vtbl)).GetGUID)(this, pguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescription(this: *mut core::ffi::c_void, pbstrdesc: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfDisplayAttributeInfo", "GetDescription", 4usize, |this| ((**(this as *const *const ITfDisplayAttributeInfo_Vtbl)).GetDescription)(this, pbstrdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetAttributeInfo(this: *mut core::ffi::c_void, pda: *mut TF_DISPLAYATTRIBUTE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfDisplayAttributeInfo", "GetAttributeInfo", 5usize, |this| ((**(this as *const *const ITfDisplayAttributeInfo_Vtbl)).GetAttributeInfo)(this, pda), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetAttributeInfo(this: *mut core::ffi::c_void, pda: *const TF_DISPLAYATTRIBUTE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfDisplayAttributeInfo", "SetAttributeInfo", 6usize, |this| ((**(this as *const *const ITfDisplayAttributeInfo_Vtbl)).SetAttributeInfo)(this, pda), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfDisplayAttributeInfo", "Reset", 7usize, |this| ((**(this as *const *const ITfDisplayAttributeInfo_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetGUID,
+            GetDescription,
+            GetAttributeInfo,
+            SetAttributeInfo,
+            Reset,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfDisplayAttributeInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfDisplayAttributeMgr_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnUpdateInfo(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfDisplayAttributeMgr", "OnUpdateInfo", 3usize, |this| ((**(this as *const *const ITfDisplayAttributeMgr_Vtbl)).OnUpdateInfo)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumDisplayAttributeInfo(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfDisplayAttributeMgr", "EnumDisplayAttributeInfo", 4usize, |this| ((**(this as *const *const ITfDisplayAttributeMgr_Vtbl)).EnumDisplayAttributeInfo)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayAttributeInfo(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, ppinfo: *mut *mut core::ffi::c_void, pclsidowner: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfDisplayAttributeMgr", "GetDisplayAttributeInfo", 5usize, |this| ((**(this as *const *const ITfDisplayAttributeMgr_Vtbl)).GetDisplayAttributeInfo)(this, guid, ppinfo, pclsidowner), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnUpdateInfo, EnumDisplayAttributeInfo, GetDisplayAttributeInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfDisplayAttributeMgr as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfDisplayAttributeNotifySink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnUpdateInfo(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfDisplayAttributeNotifySink", "OnUpdateInfo", 3usize, |this| ((**(this as *const *const ITfDisplayAttributeNotifySink_Vtbl)).OnUpdateInfo)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnUpdateInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfDisplayAttributeNotifySink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfDisplayAttributeProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumDisplayAttributeInfo(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfDisplayAttributeProvider", "EnumDisplayAttributeInfo", 3usize, |this| ((**(this as *const *const ITfDisplayAttributeProvider_Vtbl)).EnumDisplayAttributeInfo)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayAttributeInfo(this: *mut core::ffi::c_void, guid: *const windows_core::GUID, ppinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfDisplayAttributeProvider", "GetDisplayAttributeInfo", 4usize, |this| ((**(this as *const *const ITfDisplayAttributeProvider_Vtbl)).GetDisplayAttributeInfo)(this, guid, ppinfo), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnumDisplayAttributeInfo, GetDisplayAttributeInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfDisplayAttributeProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfDocumentMgr_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateContext(this: *mut core::ffi::c_void, tidowner: u32, dwflags: u32, punk: *mut core::ffi::c_void, ppic: *mut *mut core::ffi::c_void, pectextstore: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfDocumentMgr", "CreateContext", 3usize, |this| ((**(this as *const *const ITfDocumentMgr_Vtbl)).CreateContext)(this, tidowner, dwflags, punk, ppic, pectextstore), |result| Some(*result))
+        }
+        unsafe extern "system" fn Push(this: *mut core::ffi::c_void, pic: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfDocumentMgr", "Push", 4usize, |this| ((**(this as *const *const ITfDocumentMgr_Vtbl)).Push)(this, pic), |result| Some(*result))
+        }
+        unsafe extern "system" fn Pop(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfDocumentMgr", "Pop", 5usize, |this| ((**(this as *const *const ITfDocumentMgr_Vtbl)).Pop)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTop(this: *mut core::ffi::c_void, ppic: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfDocumentMgr", "GetTop", 6usize, |this| ((**(this as *const *const ITfDocumentMgr_Vtbl)).GetTop)(this, ppic), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBase(this: *mut core::ffi::c_void, ppic: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfDocumentMgr", "GetBase", 7usize, |this| ((**(this as *const *const ITfDocumentMgr_Vtbl)).GetBase)(this, ppic), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumContexts(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfDocumentMgr", "EnumContexts", 8usize, |this| ((**(this as *const *const ITfDocumentMgr_Vtbl)).EnumContexts)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateContext, Push, Pop, GetTop, GetBase, EnumContexts }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfDocumentMgr as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfEditRecord_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSelectionStatus(this: *mut core::ffi::c_void, pfchanged: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfEditRecord", "GetSelectionStatus", 3usize, |this| ((**(this as *const *const ITfEditRecord_Vtbl)).GetSelectionStatus)(this, pfchanged), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTextAndPropertyUpdates(this: *mut core::ffi::c_void, dwflags: GET_TEXT_AND_PROPERTY_UPDATES_FLAGS, prgproperties: *const *const windows_core::GUID, cproperties: u32, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfEditRecord", "GetTextAndPropertyUpdates", 4usize, |this| ((**(this as *const *const ITfEditRecord_Vtbl)).GetTextAndPropertyUpdates)(this, dwflags, prgproperties, cproperties, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSelectionStatus, GetTextAndPropertyUpdates }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfEditRecord as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfEditSession_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DoEditSession(this: *mut core::ffi::c_void, ec: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfEditSession", "DoEditSession", 3usize, |this| ((**(this as *const *const ITfEditSession_Vtbl)).DoEditSession)(this, ec), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DoEditSession }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfEditSession as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfEditTransactionSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnStartEditTransaction(this: *mut core::ffi::c_void, pic: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfEditTransactionSink", "OnStartEditTransaction", 3usize, |this| ((**(this as *const *const ITfEditTransactionSink_Vtbl)).OnStartEditTransaction)(this, pic), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnEndEditTransaction(this: *mut core::ffi::c_void, pic: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfEditTransactionSink", "OnEndEditTransaction", 4usize, |this| ((**(this as *const *const ITfEditTransactionSink_Vtbl)).OnEndEditTransaction)(this, pic), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnStartEditTransaction, OnEndEditTransaction }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfEditTransactionSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfFnAdviseText_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnTextUpdate(this: *mut core::ffi::c_void, prange: *mut core::ffi::c_void, pchtext: windows_core::PCWSTR, cch: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnAdviseText", "OnTextUpdate", 4usize, |this| ((**(this as *const *const ITfFnAdviseText_Vtbl)).OnTextUpdate)(this, prange, pchtext, cch), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnLatticeUpdate(this: *mut core::ffi::c_void, prange: *mut core::ffi::c_void, plattice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnAdviseText", "OnLatticeUpdate", 5usize, |this| ((**(this as *const *const ITfFnAdviseText_Vtbl)).OnLatticeUpdate)(this, prange, plattice), |result| Some(*result))
+        }
+        Self { base__: <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnTextUpdate, OnLatticeUpdate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfFnAdviseText as windows_core::Interface>::IID || <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfFnBalloon_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn UpdateBalloon(this: *mut core::ffi::c_void, style: TfLBBalloonStyle, pch: windows_core::PCWSTR, cch: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnBalloon", "UpdateBalloon", 3usize, |this| ((**(this as *const *const ITfFnBalloon_Vtbl)).UpdateBalloon)(this, style, pch, cch), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, UpdateBalloon }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfFnBalloon as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfFnConfigure_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Show(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, langid: u16, rguidprofile: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnConfigure", "Show", 4usize, |this| ((**(this as *const *const ITfFnConfigure_Vtbl)).Show)(this, hwndparent, langid, rguidprofile), |result| Some(*result))
+        }
+        Self { base__: <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Show }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfFnConfigure as windows_core::Interface>::IID || <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfFnConfigureRegisterEudc_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Show(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, langid: u16, rguidprofile: *const windows_core::GUID, bstrregistered: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnConfigureRegisterEudc", "Show", 4usize, |this| ((**(this as *const *const ITfFnConfigureRegisterEudc_Vtbl)).Show)(this, hwndparent, langid, rguidprofile, bstrregistered), |result| Some(*result))
+        }
+        Self { base__: <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Show }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfFnConfigureRegisterEudc as windows_core::Interface>::IID || <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfFnConfigureRegisterWord_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Show(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND, langid: u16, rguidprofile: *const windows_core::GUID, bstrregistered: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnConfigureRegisterWord", "Show", 4usize, |this| ((**(this as *const *const ITfFnConfigureRegisterWord_Vtbl)).Show)(this, hwndparent, langid, rguidprofile, bstrregistered), |result| Some(*result))
+        }
+        Self { base__: <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Show }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfFnConfigureRegisterWord as windows_core::Interface>::IID || <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfFnCustomSpeechCommand_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetSpeechCommandProvider(this: *mut core::ffi::c_void, pspcmdprovider: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnCustomSpeechCommand", "SetSpeechCommandProvider", 4usize, |this| ((**(this as *const *const ITfFnCustomSpeechCommand_Vtbl)).SetSpeechCommandProvider)(this, pspcmdprovider), |result| Some(*result))
+        }
+        Self { base__: <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetSpeechCommandProvider }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfFnCustomSpeechCommand as windows_core::Interface>::IID || <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfFnGetLinguisticAlternates_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAlternates(this: *mut core::ffi::c_void, prange: *mut core::ffi::c_void, ppcandidatelist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnGetLinguisticAlternates", "GetAlternates", 4usize, |this| ((**(this as *const *const ITfFnGetLinguisticAlternates_Vtbl)).GetAlternates)(this, prange, ppcandidatelist), |result| Some(*result))
+        }
+        Self { base__: <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetAlternates }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfFnGetLinguisticAlternates as windows_core::Interface>::IID || <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfFnGetPreferredTouchKeyboardLayout_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLayout(this: *mut core::ffi::c_void, ptkblayouttype: *mut TKBLayoutType, pwpreferredlayoutid: *const u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnGetPreferredTouchKeyboardLayout", "GetLayout", 4usize, |this| ((**(this as *const *const ITfFnGetPreferredTouchKeyboardLayout_Vtbl)).GetLayout)(this, ptkblayouttype, pwpreferredlayoutid), |result| Some(*result))
+        }
+        Self { base__: <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetLayout }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfFnGetPreferredTouchKeyboardLayout as windows_core::Interface>::IID || <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfFnGetSAPIObject_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Get(this: *mut core::ffi::c_void, sobj: TfSapiObject, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnGetSAPIObject", "Get", 4usize, |this| ((**(this as *const *const ITfFnGetSAPIObject_Vtbl)).Get)(this, sobj, ppunk), |result| Some(*result))
+        }
+        Self { base__: <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Get }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfFnGetSAPIObject as windows_core::Interface>::IID || <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfFnLMInternal_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProcessLattice(this: *mut core::ffi::c_void, prange: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnLMInternal", "ProcessLattice", 11usize, |this| ((**(this as *const *const ITfFnLMInternal_Vtbl)).ProcessLattice)(this, prange), |result| Some(*result))
+        }
+        Self { base__: <ITfFnLMProcessor_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ProcessLattice }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfFnLMInternal as windows_core::Interface>::IID || <ITfFnLMProcessor_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfFnLMProcessor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryRange(this: *mut core::ffi::c_void, prange: *mut core::ffi::c_void, ppnewrange: *mut *mut core::ffi::c_void, pfaccepted: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnLMProcessor", "QueryRange", 4usize, |this| ((**(this as *const *const ITfFnLMProcessor_Vtbl)).QueryRange)(this, prange, ppnewrange, pfaccepted), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryLangID(this: *mut core::ffi::c_void, langid: u16, pfaccepted: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnLMProcessor", "QueryLangID", 5usize, |this| ((**(this as *const *const ITfFnLMProcessor_Vtbl)).QueryLangID)(this, langid, pfaccepted), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReconversion(this: *mut core::ffi::c_void, prange: *mut core::ffi::c_void, ppcandlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnLMProcessor", "GetReconversion", 6usize, |this| ((**(this as *const *const ITfFnLMProcessor_Vtbl)).GetReconversion)(this, prange, ppcandlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reconvert(this: *mut core::ffi::c_void, prange: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnLMProcessor", "Reconvert", 7usize, |this| ((**(this as *const *const ITfFnLMProcessor_Vtbl)).Reconvert)(this, prange), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryKey(this: *mut core::ffi::c_void, fup: super::super::Foundation::BOOL, vkey: super::super::Foundation::WPARAM, lparamkeydata: super::super::Foundation::LPARAM, pfinterested: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnLMProcessor", "QueryKey", 8usize, |this| ((**(this as *const *const ITfFnLMProcessor_Vtbl)).QueryKey)(this, fup, vkey, lparamkeydata, pfinterested), |result| Some(*result))
+        }
+        unsafe extern "system" fn InvokeKey(this: *mut core::ffi::c_void, fup: super::super::Foundation::BOOL, vkey: super::super::Foundation::WPARAM, lparamkeydata: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnLMProcessor", "InvokeKey", 9usize, |this| ((**(this as *const *const ITfFnLMProcessor_Vtbl)).InvokeKey)(this, fup, vkey, lparamkeydata), |result| Some(*result))
+        }
+        unsafe extern "system" fn InvokeFunc(this: *mut core::ffi::c_void, pic: *mut core::ffi::c_void, refguidfunc: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnLMProcessor", "InvokeFunc", 10usize, |this| ((**(this as *const *const ITfFnLMProcessor_Vtbl)).InvokeFunc)(this, pic, refguidfunc), |result| Some(*result))
+        }
+        Self {
+            base__: <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            QueryRange,
+            QueryLangID,
+            GetReconversion,
+            Reconvert,
+            QueryKey,
+            InvokeKey,
+            InvokeFunc,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfFnLMProcessor as windows_core::Interface>::IID || <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfFnLangProfileUtil_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn RegisterActiveProfiles(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnLangProfileUtil", "RegisterActiveProfiles", 4usize, |this| ((**(this as *const *const ITfFnLangProfileUtil_Vtbl)).RegisterActiveProfiles)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsProfileAvailableForLang(this: *mut core::ffi::c_void, langid: u16, pfavailable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnLangProfileUtil", "IsProfileAvailableForLang", 5usize, |this| ((**(this as *const *const ITfFnLangProfileUtil_Vtbl)).IsProfileAvailableForLang)(this, langid, pfavailable), |result| Some(*result))
+        }
+        Self { base__: <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::PROXY, RegisterActiveProfiles, IsProfileAvailableForLang }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfFnLangProfileUtil as windows_core::Interface>::IID || <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfFnPlayBack_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryRange(this: *mut core::ffi::c_void, prange: *mut core::ffi::c_void, ppnewrange: *mut *mut core::ffi::c_void, pfplayable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnPlayBack", "QueryRange", 4usize, |this| ((**(this as *const *const ITfFnPlayBack_Vtbl)).QueryRange)(this, prange, ppnewrange, pfplayable), |result| Some(*result))
+        }
+        unsafe extern "system" fn Play(this: *mut core::ffi::c_void, prange: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnPlayBack", "Play", 5usize, |this| ((**(this as *const *const ITfFnPlayBack_Vtbl)).Play)(this, prange), |result| Some(*result))
+        }
+        Self { base__: <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryRange, Play }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfFnPlayBack as windows_core::Interface>::IID || <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfFnPropertyUIStatus_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, refguidprop: *const windows_core::GUID, pdw: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnPropertyUIStatus", "GetStatus", 4usize, |this| ((**(this as *const *const ITfFnPropertyUIStatus_Vtbl)).GetStatus)(this, refguidprop, pdw), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetStatus(this: *mut core::ffi::c_void, refguidprop: *const windows_core::GUID, dw: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnPropertyUIStatus", "SetStatus", 5usize, |this| ((**(this as *const *const ITfFnPropertyUIStatus_Vtbl)).SetStatus)(this, refguidprop, dw), |result| Some(*result))
+        }
+        Self { base__: <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetStatus, SetStatus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfFnPropertyUIStatus as windows_core::Interface>::IID || <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfFnReconversion_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryRange(this: *mut core::ffi::c_void, prange: *mut core::ffi::c_void, ppnewrange: *mut *mut core::ffi::c_void, pfconvertable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnReconversion", "QueryRange", 4usize, |this| ((**(this as *const *const ITfFnReconversion_Vtbl)).QueryRange)(this, prange, ppnewrange, pfconvertable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetReconversion(this: *mut core::ffi::c_void, prange: *mut core::ffi::c_void, ppcandlist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnReconversion", "GetReconversion", 5usize, |this| ((**(this as *const *const ITfFnReconversion_Vtbl)).GetReconversion)(this, prange, ppcandlist), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reconvert(this: *mut core::ffi::c_void, prange: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnReconversion", "Reconvert", 6usize, |this| ((**(this as *const *const ITfFnReconversion_Vtbl)).Reconvert)(this, prange), |result| Some(*result))
+        }
+        Self { base__: <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryRange, GetReconversion, Reconvert }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfFnReconversion as windows_core::Interface>::IID || <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfFnSearchCandidateProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSearchCandidates(this: *mut core::ffi::c_void, bstrquery: core::mem::MaybeUninit<windows_core::BSTR>, bstrapplicationid: core::mem::MaybeUninit<windows_core::BSTR>, pplist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnSearchCandidateProvider", "GetSearchCandidates", 4usize, |this| ((**(this as *const *const ITfFnSearchCandidateProvider_Vtbl)).GetSearchCandidates)(this, bstrquery, bstrapplicationid, pplist), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetResult(this: *mut core::ffi::c_void, bstrquery: core::mem::MaybeUninit<windows_core::BSTR>, bstrapplicationid: core::mem::MaybeUninit<windows_core::BSTR>, bstrresult: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnSearchCandidateProvider", "SetResult", 5usize, |this| ((**(this as *const *const ITfFnSearchCandidateProvider_Vtbl)).SetResult)(this, bstrquery, bstrapplicationid, bstrresult), |result| Some(*result))
+        }
+        Self { base__: <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetSearchCandidates, SetResult }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfFnSearchCandidateProvider as windows_core::Interface>::IID || <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfFnShowHelp_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Show(this: *mut core::ffi::c_void, hwndparent: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFnShowHelp", "Show", 4usize, |this| ((**(this as *const *const ITfFnShowHelp_Vtbl)).Show)(this, hwndparent), |result| Some(*result))
+        }
+        Self { base__: <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Show }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfFnShowHelp as windows_core::Interface>::IID || <ITfFunction_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfFunction_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDisplayName(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFunction", "GetDisplayName", 3usize, |this| ((**(this as *const *const ITfFunction_Vtbl)).GetDisplayName)(this, pbstrname), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDisplayName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfFunction as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfFunctionProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFunctionProvider", "GetType", 3usize, |this| ((**(this as *const *const ITfFunctionProvider_Vtbl)).GetType)(this, pguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescription(this: *mut core::ffi::c_void, pbstrdesc: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFunctionProvider", "GetDescription", 4usize, |this| ((**(this as *const *const ITfFunctionProvider_Vtbl)).GetDescription)(this, pbstrdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFunction(this: *mut core::ffi::c_void, rguid: *const windows_core::GUID, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfFunctionProvider", "GetFunction", 5usize, |this| ((**(this as *const *const ITfFunctionProvider_Vtbl)).GetFunction)(this, rguid, riid, ppunk), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetType, GetDescription, GetFunction }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfFunctionProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+impl windows_core::imp::ProxyVtable for ITfInputProcessorProfileActivationSink_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn OnActivated(this: *mut core::ffi::c_void, dwprofiletype: u32, langid: u16, clsid: *const windows_core::GUID, catid: *const windows_core::GUID, guidprofile: *const windows_core::GUID, hkl: super::Input::KeyboardAndMouse::HKL, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfileActivationSink", "OnActivated", 3usize, |this| ((**(this as *const *const ITfInputProcessorProfileActivationSink_Vtbl)).OnActivated)(this, dwprofiletype, langid, clsid, catid, guidprofile, hkl, dwflags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            OnActivated,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            OnActivated: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfInputProcessorProfileActivationSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+impl windows_core::imp::ProxyVtable for ITfInputProcessorProfileMgr_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn ActivateProfile(this: *mut core::ffi::c_void, dwprofiletype: u32, langid: u16, clsid: *const windows_core::GUID, guidprofile: *const windows_core::GUID, hkl: super::Input::KeyboardAndMouse::HKL, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfileMgr", "ActivateProfile", 3usize, |this| ((**(this as *const *const ITfInputProcessorProfileMgr_Vtbl)).ActivateProfile)(this, dwprofiletype, langid, clsid, guidprofile, hkl, dwflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn DeactivateProfile(this: *mut core::ffi::c_void, dwprofiletype: u32, langid: u16, clsid: *const windows_core::GUID, guidprofile: *const windows_core::GUID, hkl: super::Input::KeyboardAndMouse::HKL, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfileMgr", "DeactivateProfile", 4usize, |this| ((**(this as *const *const ITfInputProcessorProfileMgr_Vtbl)).DeactivateProfile)(this, dwprofiletype, langid, clsid, guidprofile, hkl, dwflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn GetProfile(this: *mut core::ffi::c_void, dwprofiletype: u32, langid: u16, clsid: *const windows_core::GUID, guidprofile: *const windows_core::GUID, hkl: super::Input::KeyboardAndMouse::HKL, pprofile: *mut TF_INPUTPROCESSORPROFILE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfileMgr", "GetProfile", 5usize, |this| ((**(this as *const *const ITfInputProcessorProfileMgr_Vtbl)).GetProfile)(this, dwprofiletype, langid, clsid, guidprofile, hkl, pprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumProfiles(this: *mut core::ffi::c_void, langid: u16, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfileMgr", "EnumProfiles", 6usize, |this| ((**(this as *const *const ITfInputProcessorProfileMgr_Vtbl)).EnumProfiles)(this, langid, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn ReleaseInputProcessor(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfileMgr", "ReleaseInputProcessor", 7usize, |this| ((**(this as *const *const ITfInputProcessorProfileMgr_Vtbl)).ReleaseInputProcessor)(this, rclsid, dwflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn RegisterProfile(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, langid: u16, guidprofile: *const windows_core::GUID, pchdesc: windows_core::PCWSTR, cchdesc: u32, pchiconfile: windows_core::PCWSTR, cchfile: u32, uiconindex: u32, hklsubstitute: super::Input::KeyboardAndMouse::HKL, dwpreferredlayout: u32, benabledbydefault: super::super::Foundation::BOOL, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfileMgr", "RegisterProfile", 8usize, |this| ((**(this as *const *const ITfInputProcessorProfileMgr_Vtbl)).RegisterProfile)(this, rclsid, langid, guidprofile, pchdesc, cchdesc, pchiconfile, cchfile, uiconindex, hklsubstitute, dwpreferredlayout, benabledbydefault, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnregisterProfile(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, langid: u16, guidprofile: *const windows_core::GUID, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfileMgr", "UnregisterProfile", 9usize, |this| ((**(this as *const *const ITfInputProcessorProfileMgr_Vtbl)).UnregisterProfile)(this, rclsid, langid, guidprofile, dwflags), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn GetActiveProfile(this: *mut core::ffi::c_void, catid: *const windows_core::GUID, pprofile: *mut TF_INPUTPROCESSORPROFILE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfileMgr", "GetActiveProfile", 10usize, |this| ((**(this as *const *const ITfInputProcessorProfileMgr_Vtbl)).GetActiveProfile)(this, catid, pprofile), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            ActivateProfile,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            ActivateProfile: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            DeactivateProfile,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            DeactivateProfile: 0,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            GetProfile,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            GetProfile: 0,
+            EnumProfiles,
+            ReleaseInputProcessor,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            RegisterProfile,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            RegisterProfile: 0,
+            UnregisterProfile,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            GetActiveProfile,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            GetActiveProfile: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfInputProcessorProfileMgr as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+impl windows_core::imp::ProxyVtable for ITfInputProcessorProfileSubstituteLayout_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn GetSubstituteKeyboardLayout(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, langid: u16, guidprofile: *const windows_core::GUID, phkl: *mut super::Input::KeyboardAndMouse::HKL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfileSubstituteLayout", "GetSubstituteKeyboardLayout", 3usize, |this| ((**(this as *const *const ITfInputProcessorProfileSubstituteLayout_Vtbl)).GetSubstituteKeyboardLayout)(this, rclsid, langid, guidprofile, phkl), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            GetSubstituteKeyboardLayout,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            GetSubstituteKeyboardLayout: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfInputProcessorProfileSubstituteLayout as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Input_KeyboardAndMouse"))]
+impl windows_core::imp::ProxyVtable for ITfInputProcessorProfiles_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Register(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfiles", "Register", 3usize, |this| ((**(this as *const *const ITfInputProcessorProfiles_Vtbl)).Register)(this, rclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unregister(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfiles", "Unregister", 4usize, |this| ((**(this as *const *const ITfInputProcessorProfiles_Vtbl)).Unregister)(this, rclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddLanguageProfile(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, langid: u16, guidprofile: *const windows_core::GUID, pchdesc: windows_core::PCWSTR, cchdesc: u32, pchiconfile: windows_core::PCWSTR, cchfile: u32, uiconindex: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfiles", "AddLanguageProfile", 5usize, |this| ((**(this as *const *const ITfInputProcessorProfiles_Vtbl)).AddLanguageProfile)(this, rclsid, langid, guidprofile, pchdesc, cchdesc, pchiconfile, cchfile, uiconindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveLanguageProfile(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, langid: u16, guidprofile: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfiles", "RemoveLanguageProfile", 6usize, |this| ((**(this as *const *const ITfInputProcessorProfiles_Vtbl)).RemoveLanguageProfile)(this, rclsid, langid, guidprofile), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumInputProcessorInfo(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfiles", "EnumInputProcessorInfo", 7usize, |this| ((**(this as *const *const ITfInputProcessorProfiles_Vtbl)).EnumInputProcessorInfo)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultLanguageProfile(this: *mut core::ffi::c_void, langid: u16, catid: *const windows_core::GUID, pclsid: *mut windows_core::GUID, pguidprofile: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfiles", "GetDefaultLanguageProfile", 8usize, |this| ((**(this as *const *const ITfInputProcessorProfiles_Vtbl)).GetDefaultLanguageProfile)(this, langid, catid, pclsid, pguidprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultLanguageProfile(this: *mut core::ffi::c_void, langid: u16, rclsid: *const windows_core::GUID, guidprofiles: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfiles", "SetDefaultLanguageProfile", 9usize, |this| ((**(this as *const *const ITfInputProcessorProfiles_Vtbl)).SetDefaultLanguageProfile)(this, langid, rclsid, guidprofiles), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivateLanguageProfile(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, langid: u16, guidprofiles: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfiles", "ActivateLanguageProfile", 10usize, |this| ((**(this as *const *const ITfInputProcessorProfiles_Vtbl)).ActivateLanguageProfile)(this, rclsid, langid, guidprofiles), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActiveLanguageProfile(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, plangid: *mut u16, pguidprofile: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfiles", "GetActiveLanguageProfile", 11usize, |this| ((**(this as *const *const ITfInputProcessorProfiles_Vtbl)).GetActiveLanguageProfile)(this, rclsid, plangid, pguidprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLanguageProfileDescription(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, langid: u16, guidprofile: *const windows_core::GUID, pbstrprofile: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfiles", "GetLanguageProfileDescription", 12usize, |this| ((**(this as *const *const ITfInputProcessorProfiles_Vtbl)).GetLanguageProfileDescription)(this, rclsid, langid, guidprofile, pbstrprofile), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCurrentLanguage(this: *mut core::ffi::c_void, plangid: *mut u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfiles", "GetCurrentLanguage", 13usize, |this| ((**(this as *const *const ITfInputProcessorProfiles_Vtbl)).GetCurrentLanguage)(this, plangid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ChangeCurrentLanguage(this: *mut core::ffi::c_void, langid: u16) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfiles", "ChangeCurrentLanguage", 14usize, |this| ((**(this as *const *const ITfInputProcessorProfiles_Vtbl)).ChangeCurrentLanguage)(this, langid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetLanguageList(this: *mut core::ffi::c_void, pplangid: *mut *mut u16, pulcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfiles", "GetLanguageList", 15usize, |this| ((**(this as *const *const ITfInputProcessorProfiles_Vtbl)).GetLanguageList)(this, pplangid, pulcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumLanguageProfiles(this: *mut core::ffi::c_void, langid: u16, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfiles", "EnumLanguageProfiles", 16usize, |this| ((**(this as *const *const ITfInputProcessorProfiles_Vtbl)).EnumLanguageProfiles)(this, langid, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableLanguageProfile(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, langid: u16, guidprofile: *const windows_core::GUID, fenable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfiles", "EnableLanguageProfile", 17usize, |this| ((**(this as *const *const ITfInputProcessorProfiles_Vtbl)).EnableLanguageProfile)(this, rclsid, langid, guidprofile, fenable), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEnabledLanguageProfile(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, langid: u16, guidprofile: *const windows_core::GUID, pfenable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfiles", "IsEnabledLanguageProfile", 18usize, |this| ((**(this as *const *const ITfInputProcessorProfiles_Vtbl)).IsEnabledLanguageProfile)(this, rclsid, langid, guidprofile, pfenable), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnableLanguageProfileByDefault(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, langid: u16, guidprofile: *const windows_core::GUID, fenable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfiles", "EnableLanguageProfileByDefault", 19usize, |this| ((**(this as *const *const ITfInputProcessorProfiles_Vtbl)).EnableLanguageProfileByDefault)(this, rclsid, langid, guidprofile, fenable), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+        unsafe extern "system" fn SubstituteKeyboardLayout(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, langid: u16, guidprofile: *const windows_core::GUID, hkl: super::Input::KeyboardAndMouse::HKL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfiles", "SubstituteKeyboardLayout", 20usize, |this| ((**(this as *const *const ITfInputProcessorProfiles_Vtbl)).SubstituteKeyboardLayout)(this, rclsid, langid, guidprofile, hkl), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Register,
+            Unregister,
+            AddLanguageProfile,
+            RemoveLanguageProfile,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumInputProcessorInfo,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumInputProcessorInfo: 0,
+            GetDefaultLanguageProfile,
+            SetDefaultLanguageProfile,
+            ActivateLanguageProfile,
+            GetActiveLanguageProfile,
+            GetLanguageProfileDescription,
+            GetCurrentLanguage,
+            ChangeCurrentLanguage,
+            GetLanguageList,
+            EnumLanguageProfiles,
+            EnableLanguageProfile,
+            IsEnabledLanguageProfile,
+            EnableLanguageProfileByDefault,
+            #[cfg(feature = "Win32_UI_Input_KeyboardAndMouse")]
+            SubstituteKeyboardLayout,
+            #[cfg(not(feature = "Win32_UI_Input_KeyboardAndMouse"))]
+            SubstituteKeyboardLayout: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfInputProcessorProfiles as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_UI_Input_KeyboardAndMouse"))]
+impl windows_core::imp::ProxyVtable for ITfInputProcessorProfilesEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetLanguageProfileDisplayName(this: *mut core::ffi::c_void, rclsid: *const windows_core::GUID, langid: u16, guidprofile: *const windows_core::GUID, pchfile: windows_core::PCWSTR, cchfile: u32, uresid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputProcessorProfilesEx", "SetLanguageProfileDisplayName", 21usize, |this| ((**(this as *const *const ITfInputProcessorProfilesEx_Vtbl)).SetLanguageProfileDisplayName)(this, rclsid, langid, guidprofile, pchfile, cchfile, uresid), |result| Some(*result))
+        }
+        Self { base__: <ITfInputProcessorProfiles_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetLanguageProfileDisplayName }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfInputProcessorProfilesEx as windows_core::Interface>::IID || <ITfInputProcessorProfiles_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfInputScope_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInputScopes(this: *mut core::ffi::c_void, pprginputscopes: *mut *mut InputScope, pccount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputScope", "GetInputScopes", 3usize, |this| ((**(this as *const *const ITfInputScope_Vtbl)).GetInputScopes)(this, pprginputscopes, pccount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPhrase(this: *mut core::ffi::c_void, ppbstrphrases: *mut *mut windows_core::BSTR, pccount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputScope", "GetPhrase", 4usize, |this| ((**(this as *const *const ITfInputScope_Vtbl)).GetPhrase)(this, ppbstrphrases, pccount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetRegularExpression(this: *mut core::ffi::c_void, pbstrregexp: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputScope", "GetRegularExpression", 5usize, |this| ((**(this as *const *const ITfInputScope_Vtbl)).GetRegularExpression)(this, pbstrregexp), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSRGS(this: *mut core::ffi::c_void, pbstrsrgs: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputScope", "GetSRGS", 6usize, |this| ((**(this as *const *const ITfInputScope_Vtbl)).GetSRGS)(this, pbstrsrgs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetXML(this: *mut core::ffi::c_void, pbstrxml: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputScope", "GetXML", 7usize, |this| ((**(this as *const *const ITfInputScope_Vtbl)).GetXML)(this, pbstrxml), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetInputScopes, GetPhrase, GetRegularExpression, GetSRGS, GetXML }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfInputScope as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITfInputScope2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn EnumWordList(this: *mut core::ffi::c_void, ppenumstring: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInputScope2", "EnumWordList", 8usize, |this| ((**(this as *const *const ITfInputScope2_Vtbl)).EnumWordList)(this, ppenumstring), |result| Some(*result))
+        }
+        Self {
+            base__: <ITfInputScope_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            EnumWordList,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            EnumWordList: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfInputScope2 as windows_core::Interface>::IID || <ITfInputScope_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITfInsertAtSelection_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InsertTextAtSelection(this: *mut core::ffi::c_void, ec: u32, dwflags: INSERT_TEXT_AT_SELECTION_FLAGS, pchtext: windows_core::PCWSTR, cch: i32, pprange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInsertAtSelection", "InsertTextAtSelection", 3usize, |this| ((**(this as *const *const ITfInsertAtSelection_Vtbl)).InsertTextAtSelection)(this, ec, dwflags, pchtext, cch, pprange), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InsertEmbeddedAtSelection(this: *mut core::ffi::c_void, ec: u32, dwflags: u32, pdataobject: *mut core::ffi::c_void, pprange: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfInsertAtSelection", "InsertEmbeddedAtSelection", 4usize, |this| ((**(this as *const *const ITfInsertAtSelection_Vtbl)).InsertEmbeddedAtSelection)(this, ec, dwflags, pdataobject, pprange), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            InsertTextAtSelection,
+            #[cfg(feature = "Win32_System_Com")]
+            InsertEmbeddedAtSelection,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InsertEmbeddedAtSelection: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfInsertAtSelection as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfIntegratableCandidateListUIElement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetIntegrationStyle(this: *mut core::ffi::c_void, guidintegrationstyle: windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfIntegratableCandidateListUIElement", "SetIntegrationStyle", 3usize, |this| ((**(this as *const *const ITfIntegratableCandidateListUIElement_Vtbl)).SetIntegrationStyle)(this, guidintegrationstyle), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetSelectionStyle(this: *mut core::ffi::c_void, ptfselectionstyle: *mut TfIntegratableCandidateListSelectionStyle) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfIntegratableCandidateListUIElement", "GetSelectionStyle", 4usize, |this| ((**(this as *const *const ITfIntegratableCandidateListUIElement_Vtbl)).GetSelectionStyle)(this, ptfselectionstyle), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnKeyDown(this: *mut core::ffi::c_void, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM, pfeaten: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfIntegratableCandidateListUIElement", "OnKeyDown", 5usize, |this| ((**(this as *const *const ITfIntegratableCandidateListUIElement_Vtbl)).OnKeyDown)(this, wparam, lparam, pfeaten), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowCandidateNumbers(this: *mut core::ffi::c_void, pfshow: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfIntegratableCandidateListUIElement", "ShowCandidateNumbers", 6usize, |this| ((**(this as *const *const ITfIntegratableCandidateListUIElement_Vtbl)).ShowCandidateNumbers)(this, pfshow), |result| Some(*result))
+        }
+        unsafe extern "system" fn FinalizeExactCompositionString(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfIntegratableCandidateListUIElement", "FinalizeExactCompositionString", 7usize, |this| ((**(this as *const *const ITfIntegratableCandidateListUIElement_Vtbl)).FinalizeExactCompositionString)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetIntegrationStyle,
+            GetSelectionStyle,
+            OnKeyDown,
+            ShowCandidateNumbers,
+            FinalizeExactCompositionString,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfIntegratableCandidateListUIElement as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfKeyEventSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnSetFocus(this: *mut core::ffi::c_void, fforeground: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfKeyEventSink", "OnSetFocus", 3usize, |this| ((**(this as *const *const ITfKeyEventSink_Vtbl)).OnSetFocus)(this, fforeground), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnTestKeyDown(this: *mut core::ffi::c_void, pic: *mut core::ffi::c_void, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM, pfeaten: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfKeyEventSink", "OnTestKeyDown", 4usize, |this| ((**(this as *const *const ITfKeyEventSink_Vtbl)).OnTestKeyDown)(this, pic, wparam, lparam, pfeaten), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnTestKeyUp(this: *mut core::ffi::c_void, pic: *mut core::ffi::c_void, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM, pfeaten: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfKeyEventSink", "OnTestKeyUp", 5usize, |this| ((**(this as *const *const ITfKeyEventSink_Vtbl)).OnTestKeyUp)(this, pic, wparam, lparam, pfeaten), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnKeyDown(this: *mut core::ffi::c_void, pic: *mut core::ffi::c_void, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM, pfeaten: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfKeyEventSink", "OnKeyDown", 6usize, |this| ((**(this as *const *const ITfKeyEventSink_Vtbl)).OnKeyDown)(this, pic, wparam, lparam, pfeaten), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnKeyUp(this: *mut core::ffi::c_void, pic: *mut core::ffi::c_void, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM, pfeaten: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfKeyEventSink", "OnKeyUp", 7usize, |this| ((**(this as *const *const ITfKeyEventSink_Vtbl)).OnKeyUp)(this, pic, wparam, lparam, pfeaten), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnPreservedKey(this: *mut core::ffi::c_void, pic: *mut core::ffi::c_void, rguid: *const windows_core::GUID, pfeaten: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfKeyEventSink", "OnPreservedKey", 8usize, |this| ((**(this as *const *const ITfKeyEventSink_Vtbl)).OnPreservedKey)(this, pic, rguid, pfeaten), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnSetFocus,
+            OnTestKeyDown,
+            OnTestKeyUp,
+            OnKeyDown,
+            OnKeyUp,
+            OnPreservedKey,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfKeyEventSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfKeyTraceEventSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnKeyTraceDown(this: *mut core::ffi::c_void, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfKeyTraceEventSink", "OnKeyTraceDown", 3usize, |this| ((**(this as *const *const ITfKeyTraceEventSink_Vtbl)).OnKeyTraceDown)(this, wparam, lparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnKeyTraceUp(this: *mut core::ffi::c_void, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfKeyTraceEventSink", "OnKeyTraceUp", 4usize, |this| ((**(this as *const *const ITfKeyTraceEventSink_Vtbl)).OnKeyTraceUp)(this, wparam, lparam), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnKeyTraceDown, OnKeyTraceUp }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfKeyTraceEventSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfKeystrokeMgr_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AdviseKeyEventSink(this: *mut core::ffi::c_void, tid: u32, psink: *mut core::ffi::c_void, fforeground: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfKeystrokeMgr", "AdviseKeyEventSink", 3usize, |this| ((**(this as *const *const ITfKeystrokeMgr_Vtbl)).AdviseKeyEventSink)(this, tid, psink, fforeground), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnadviseKeyEventSink(this: *mut core::ffi::c_void, tid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfKeystrokeMgr", "UnadviseKeyEventSink", 4usize, |this| ((**(this as *const *const ITfKeystrokeMgr_Vtbl)).UnadviseKeyEventSink)(this, tid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetForeground(this: *mut core::ffi::c_void, pclsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfKeystrokeMgr", "GetForeground", 5usize, |this| ((**(this as *const *const ITfKeystrokeMgr_Vtbl)).GetForeground)(this, pclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn TestKeyDown(this: *mut core::ffi::c_void, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM, pfeaten: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfKeystrokeMgr", "TestKeyDown", 6usize, |this| ((**(this as *const *const ITfKeystrokeMgr_Vtbl)).TestKeyDown)(this, wparam, lparam, pfeaten), |result| Some(*result))
+        }
+        unsafe extern "system" fn TestKeyUp(this: *mut core::ffi::c_void, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM, pfeaten: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfKeystrokeMgr", "TestKeyUp", 7usize, |this| ((**(this as *const *const ITfKeystrokeMgr_Vtbl)).TestKeyUp)(this, wparam, lparam, pfeaten), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyDown(this: *mut core::ffi::c_void, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM, pfeaten: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfKeystrokeMgr", "KeyDown", 8usize, |this| ((**(this as *const *const ITfKeystrokeMgr_Vtbl)).KeyDown)(this, wparam, lparam, pfeaten), |result| Some(*result))
+        }
+        unsafe extern "system" fn KeyUp(this: *mut core::ffi::c_void, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM, pfeaten: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfKeystrokeMgr", "KeyUp", 9usize, |this| ((**(this as *const *const ITfKeystrokeMgr_Vtbl)).KeyUp)(this, wparam, lparam, pfeaten), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreservedKey(this: *mut core::ffi::c_void, pic: *mut core::ffi::c_void, pprekey: *const TF_PRESERVEDKEY, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfKeystrokeMgr", "GetPreservedKey", 10usize, |this| ((**(this as *const *const ITfKeystrokeMgr_Vtbl)).GetPreservedKey)(this, pic, pprekey, pguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsPreservedKey(this: *mut core::ffi::c_void, rguid: *const windows_core::GUID, pprekey: *const TF_PRESERVEDKEY, pfregistered: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfKeystrokeMgr", "IsPreservedKey", 11usize, |this| ((**(this as *const *const ITfKeystrokeMgr_Vtbl)).IsPreservedKey)(this, rguid, pprekey, pfregistered), |result| Some(*result))
+        }
+        unsafe extern "system" fn PreserveKey(this: *mut core::ffi::c_void, tid: u32, rguid: *const windows_core::GUID, prekey: *const TF_PRESERVEDKEY, pchdesc: windows_core::PCWSTR, cchdesc: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfKeystrokeMgr", "PreserveKey", 12usize, |this| ((**(this as *const *const ITfKeystrokeMgr_Vtbl)).PreserveKey)(this, tid, rguid, prekey, pchdesc, cchdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnpreserveKey(this: *mut core::ffi::c_void, rguid: *const windows_core::GUID, pprekey: *const TF_PRESERVEDKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfKeystrokeMgr", "UnpreserveKey", 13usize, |this| ((**(this as *const *const ITfKeystrokeMgr_Vtbl)).UnpreserveKey)(this, rguid, pprekey), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPreservedKeyDescription(this: *mut core::ffi::c_void, rguid: *const windows_core::GUID, pchdesc: windows_core::PCWSTR, cchdesc: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfKeystrokeMgr", "SetPreservedKeyDescription", 14usize, |this| ((**(this as *const *const ITfKeystrokeMgr_Vtbl)).SetPreservedKeyDescription)(this, rguid, pchdesc, cchdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreservedKeyDescription(this: *mut core::ffi::c_void, rguid: *const windows_core::GUID, pbstrdesc: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfKeystrokeMgr", "GetPreservedKeyDescription", 15usize, |this| ((**(this as *const *const ITfKeystrokeMgr_Vtbl)).GetPreservedKeyDescription)(this, rguid, pbstrdesc), |result| Some(*result))
+        }
+        unsafe extern "system" fn SimulatePreservedKey(this: *mut core::ffi::c_void, pic: *mut core::ffi::c_void, rguid: *const windows_core::GUID, pfeaten: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfKeystrokeMgr", "SimulatePreservedKey", 16usize, |this| ((**(this as *const *const ITfKeystrokeMgr_Vtbl)).SimulatePreservedKey)(this, pic, rguid, pfeaten), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AdviseKeyEventSink,
+            UnadviseKeyEventSink,
+            GetForeground,
+            TestKeyDown,
+            TestKeyUp,
+            KeyDown,
+            KeyUp,
+            GetPreservedKey,
+            IsPreservedKey,
+            PreserveKey,
+            UnpreserveKey,
+            SetPreservedKeyDescription,
+            GetPreservedKeyDescription,
+            SimulatePreservedKey,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfKeystrokeMgr as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfLMLattice_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn QueryType(this: *mut core::ffi::c_void, rguidtype: *const windows_core::GUID, pfsupported: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLMLattice", "QueryType", 3usize, |this| ((**(this as *const *const ITfLMLattice_Vtbl)).QueryType)(this, rguidtype, pfsupported), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumLatticeElements(this: *mut core::ffi::c_void, dwframestart: u32, rguidtype: *const windows_core::GUID, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLMLattice", "EnumLatticeElements", 4usize, |this| ((**(this as *const *const ITfLMLattice_Vtbl)).EnumLatticeElements)(this, dwframestart, rguidtype, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, QueryType, EnumLatticeElements }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfLMLattice as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfLangBarEventSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnSetFocus(this: *mut core::ffi::c_void, dwthreadid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarEventSink", "OnSetFocus", 3usize, |this| ((**(this as *const *const ITfLangBarEventSink_Vtbl)).OnSetFocus)(this, dwthreadid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnThreadTerminate(this: *mut core::ffi::c_void, dwthreadid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarEventSink", "OnThreadTerminate", 4usize, |this| ((**(this as *const *const ITfLangBarEventSink_Vtbl)).OnThreadTerminate)(this, dwthreadid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnThreadItemChange(this: *mut core::ffi::c_void, dwthreadid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarEventSink", "OnThreadItemChange", 5usize, |this| ((**(this as *const *const ITfLangBarEventSink_Vtbl)).OnThreadItemChange)(this, dwthreadid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnModalInput(this: *mut core::ffi::c_void, dwthreadid: u32, umsg: u32, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarEventSink", "OnModalInput", 6usize, |this| ((**(this as *const *const ITfLangBarEventSink_Vtbl)).OnModalInput)(this, dwthreadid, umsg, wparam, lparam), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowFloating(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarEventSink", "ShowFloating", 7usize, |this| ((**(this as *const *const ITfLangBarEventSink_Vtbl)).ShowFloating)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItemFloatingRect(this: *mut core::ffi::c_void, dwthreadid: u32, rguid: *const windows_core::GUID, prc: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarEventSink", "GetItemFloatingRect", 8usize, |this| ((**(this as *const *const ITfLangBarEventSink_Vtbl)).GetItemFloatingRect)(this, dwthreadid, rguid, prc), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnSetFocus,
+            OnThreadTerminate,
+            OnThreadItemChange,
+            OnModalInput,
+            ShowFloating,
+            GetItemFloatingRect,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfLangBarEventSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfLangBarItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInfo(this: *mut core::ffi::c_void, pinfo: *mut TF_LANGBARITEMINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItem", "GetInfo", 3usize, |this| ((**(this as *const *const ITfLangBarItem_Vtbl)).GetInfo)(this, pinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatus(this: *mut core::ffi::c_void, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItem", "GetStatus", 4usize, |this| ((**(this as *const *const ITfLangBarItem_Vtbl)).GetStatus)(this, pdwstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn Show(this: *mut core::ffi::c_void, fshow: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItem", "Show", 5usize, |this| ((**(this as *const *const ITfLangBarItem_Vtbl)).Show)(this, fshow), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTooltipString(this: *mut core::ffi::c_void, pbstrtooltip: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItem", "GetTooltipString", 6usize, |this| ((**(this as *const *const ITfLangBarItem_Vtbl)).GetTooltipString)(this, pbstrtooltip), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetInfo, GetStatus, Show, GetTooltipString }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfLangBarItem as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfLangBarItemBalloon_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnClick(this: *mut core::ffi::c_void, click: TfLBIClick, pt: super::super::Foundation::POINT, prcarea: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemBalloon", "OnClick", 7usize, |this| ((**(this as *const *const ITfLangBarItemBalloon_Vtbl)).OnClick)(this, click, pt, prcarea), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreferredSize(this: *mut core::ffi::c_void, pszdefault: *const super::super::Foundation::SIZE, psz: *mut super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemBalloon", "GetPreferredSize", 8usize, |this| ((**(this as *const *const ITfLangBarItemBalloon_Vtbl)).GetPreferredSize)(this, pszdefault, psz), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBalloonInfo(this: *mut core::ffi::c_void, pinfo: *mut TF_LBBALLOONINFO) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemBalloon", "GetBalloonInfo", 9usize, |this| ((**(this as *const *const ITfLangBarItemBalloon_Vtbl)).GetBalloonInfo)(this, pinfo), |result| Some(*result))
+        }
+        Self { base__: <ITfLangBarItem_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnClick, GetPreferredSize, GetBalloonInfo }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfLangBarItemBalloon as windows_core::Interface>::IID || <ITfLangBarItem_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for ITfLangBarItemBitmap_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnClick(this: *mut core::ffi::c_void, click: TfLBIClick, pt: super::super::Foundation::POINT, prcarea: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemBitmap", "OnClick", 7usize, |this| ((**(this as *const *const ITfLangBarItemBitmap_Vtbl)).OnClick)(this, click, pt, prcarea), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreferredSize(this: *mut core::ffi::c_void, pszdefault: *const super::super::Foundation::SIZE, psz: *mut super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemBitmap", "GetPreferredSize", 8usize, |this| ((**(this as *const *const ITfLangBarItemBitmap_Vtbl)).GetPreferredSize)(this, pszdefault, psz), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn DrawBitmap(this: *mut core::ffi::c_void, bmwidth: i32, bmheight: i32, dwflags: u32, phbmp: *mut super::super::Graphics::Gdi::HBITMAP, phbmpmask: *mut super::super::Graphics::Gdi::HBITMAP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemBitmap", "DrawBitmap", 9usize, |this| ((**(this as *const *const ITfLangBarItemBitmap_Vtbl)).DrawBitmap)(this, bmwidth, bmheight, dwflags, phbmp, phbmpmask), |result| Some(*result))
+        }
+        Self {
+            base__: <ITfLangBarItem_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnClick,
+            GetPreferredSize,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            DrawBitmap,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            DrawBitmap: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfLangBarItemBitmap as windows_core::Interface>::IID || <ITfLangBarItem_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for ITfLangBarItemBitmapButton_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnClick(this: *mut core::ffi::c_void, click: TfLBIClick, pt: super::super::Foundation::POINT, prcarea: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemBitmapButton", "OnClick", 7usize, |this| ((**(this as *const *const ITfLangBarItemBitmapButton_Vtbl)).OnClick)(this, click, pt, prcarea), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitMenu(this: *mut core::ffi::c_void, pmenu: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemBitmapButton", "InitMenu", 8usize, |this| ((**(this as *const *const ITfLangBarItemBitmapButton_Vtbl)).InitMenu)(this, pmenu), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnMenuSelect(this: *mut core::ffi::c_void, wid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemBitmapButton", "OnMenuSelect", 9usize, |this| ((**(this as *const *const ITfLangBarItemBitmapButton_Vtbl)).OnMenuSelect)(this, wid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPreferredSize(this: *mut core::ffi::c_void, pszdefault: *const super::super::Foundation::SIZE, psz: *mut super::super::Foundation::SIZE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemBitmapButton", "GetPreferredSize", 10usize, |this| ((**(this as *const *const ITfLangBarItemBitmapButton_Vtbl)).GetPreferredSize)(this, pszdefault, psz), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn DrawBitmap(this: *mut core::ffi::c_void, bmwidth: i32, bmheight: i32, dwflags: u32, phbmp: *mut super::super::Graphics::Gdi::HBITMAP, phbmpmask: *mut super::super::Graphics::Gdi::HBITMAP) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemBitmapButton", "DrawBitmap", 11usize, |this| ((**(this as *const *const ITfLangBarItemBitmapButton_Vtbl)).DrawBitmap)(this, bmwidth, bmheight, dwflags, phbmp, phbmpmask), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetText(this: *mut core::ffi::c_void, pbstrtext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemBitmapButton", "GetText", 12usize, |this| ((**(this as *const *const ITfLangBarItemBitmapButton_Vtbl)).GetText)(this, pbstrtext), |result| Some(*result))
+        }
+        Self {
+            base__: <ITfLangBarItem_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnClick,
+            InitMenu,
+            OnMenuSelect,
+            GetPreferredSize,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            DrawBitmap,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            DrawBitmap: 0,
+            GetText,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfLangBarItemBitmapButton as windows_core::Interface>::IID || <ITfLangBarItem_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl windows_core::imp::ProxyVtable for ITfLangBarItemButton_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnClick(this: *mut core::ffi::c_void, click: TfLBIClick, pt: super::super::Foundation::POINT, prcarea: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemButton", "OnClick", 7usize, |this| ((**(this as *const *const ITfLangBarItemButton_Vtbl)).OnClick)(this, click, pt, prcarea), |result| Some(*result))
+        }
+        unsafe extern "system" fn InitMenu(this: *mut core::ffi::c_void, pmenu: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemButton", "InitMenu", 8usize, |this| ((**(this as *const *const ITfLangBarItemButton_Vtbl)).InitMenu)(this, pmenu), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnMenuSelect(this: *mut core::ffi::c_void, wid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemButton", "OnMenuSelect", 9usize, |this| ((**(this as *const *const ITfLangBarItemButton_Vtbl)).OnMenuSelect)(this, wid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn GetIcon(this: *mut core::ffi::c_void, phicon: *mut super::WindowsAndMessaging::HICON) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemButton", "GetIcon", 10usize, |this| ((**(this as *const *const ITfLangBarItemButton_Vtbl)).GetIcon)(this, phicon), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetText(this: *mut core::ffi::c_void, pbstrtext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemButton", "GetText", 11usize, |this| ((**(this as *const *const ITfLangBarItemButton_Vtbl)).GetText)(this, pbstrtext), |result| Some(*result))
+        }
+        Self {
+            base__: <ITfLangBarItem_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnClick,
+            InitMenu,
+            OnMenuSelect,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            GetIcon,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            GetIcon: 0,
+            GetText,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfLangBarItemButton as windows_core::Interface>::IID || <ITfLangBarItem_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfLangBarItemMgr_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumItems(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemMgr", "EnumItems", 3usize, |this| ((**(this as *const *const ITfLangBarItemMgr_Vtbl)).EnumItems)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItem(this: *mut core::ffi::c_void, rguid: *const windows_core::GUID, ppitem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemMgr", "GetItem", 4usize, |this| ((**(this as *const *const ITfLangBarItemMgr_Vtbl)).GetItem)(this, rguid, ppitem), |result| Some(*result))
+        }
+        unsafe extern "system" fn AddItem(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemMgr", "AddItem", 5usize, |this| ((**(this as *const *const ITfLangBarItemMgr_Vtbl)).AddItem)(this, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveItem(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemMgr", "RemoveItem", 6usize, |this| ((**(this as *const *const ITfLangBarItemMgr_Vtbl)).RemoveItem)(this, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdviseItemSink(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void, pdwcookie: *mut u32, rguiditem: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemMgr", "AdviseItemSink", 7usize, |this| ((**(this as *const *const ITfLangBarItemMgr_Vtbl)).AdviseItemSink)(this, punk, pdwcookie, rguiditem), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnadviseItemSink(this: *mut core::ffi::c_void, dwcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemMgr", "UnadviseItemSink", 8usize, |this| ((**(this as *const *const ITfLangBarItemMgr_Vtbl)).UnadviseItemSink)(this, dwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItemFloatingRect(this: *mut core::ffi::c_void, dwthreadid: u32, rguid: *const windows_core::GUID, prc: *mut super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemMgr", "GetItemFloatingRect", 9usize, |this| ((**(this as *const *const ITfLangBarItemMgr_Vtbl)).GetItemFloatingRect)(this, dwthreadid, rguid, prc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItemsStatus(this: *mut core::ffi::c_void, ulcount: u32, prgguid: *const windows_core::GUID, pdwstatus: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemMgr", "GetItemsStatus", 10usize, |this| ((**(this as *const *const ITfLangBarItemMgr_Vtbl)).GetItemsStatus)(this, ulcount, prgguid, pdwstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItemNum(this: *mut core::ffi::c_void, pulcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemMgr", "GetItemNum", 11usize, |this| ((**(this as *const *const ITfLangBarItemMgr_Vtbl)).GetItemNum)(this, pulcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItems(this: *mut core::ffi::c_void, ulcount: u32, ppitem: *mut *mut core::ffi::c_void, pinfo: *mut TF_LANGBARITEMINFO, pdwstatus: *mut u32, pcfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemMgr", "GetItems", 12usize, |this| ((**(this as *const *const ITfLangBarItemMgr_Vtbl)).GetItems)(this, ulcount, ppitem, pinfo, pdwstatus, pcfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdviseItemsSink(this: *mut core::ffi::c_void, ulcount: u32, ppunk: *const *mut core::ffi::c_void, pguiditem: *const windows_core::GUID, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemMgr", "AdviseItemsSink", 13usize, |this| ((**(this as *const *const ITfLangBarItemMgr_Vtbl)).AdviseItemsSink)(this, ulcount, ppunk, pguiditem, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnadviseItemsSink(this: *mut core::ffi::c_void, ulcount: u32, pdwcookie: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemMgr", "UnadviseItemsSink", 14usize, |this| ((**(this as *const *const ITfLangBarItemMgr_Vtbl)).UnadviseItemsSink)(this, ulcount, pdwcookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            EnumItems,
+            GetItem,
+            AddItem,
+            RemoveItem,
+            AdviseItemSink,
+            UnadviseItemSink,
+            GetItemFloatingRect,
+            GetItemsStatus,
+            GetItemNum,
+            GetItems,
+            AdviseItemsSink,
+            UnadviseItemsSink,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfLangBarItemMgr as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfLangBarItemSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnUpdate(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarItemSink", "OnUpdate", 3usize, |this| ((**(this as *const *const ITfLangBarItemSink_Vtbl)).OnUpdate)(this, dwflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnUpdate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfLangBarItemSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfLangBarMgr_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AdviseEventSink(this: *mut core::ffi::c_void, psink: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, dwflags: u32, pdwcookie: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarMgr", "AdviseEventSink", 3usize, |this| ((**(this as *const *const ITfLangBarMgr_Vtbl)).AdviseEventSink)(this, psink, hwnd, dwflags, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnadviseEventSink(this: *mut core::ffi::c_void, dwcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarMgr", "UnadviseEventSink", 4usize, |this| ((**(this as *const *const ITfLangBarMgr_Vtbl)).UnadviseEventSink)(this, dwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetThreadMarshalInterface(this: *mut core::ffi::c_void, dwthreadid: u32, dwtype: u32, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarMgr", "GetThreadMarshalInterface", 5usize, |this| ((**(this as *const *const ITfLangBarMgr_Vtbl)).GetThreadMarshalInterface)(this, dwthreadid, dwtype, riid, ppunk), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetThreadLangBarItemMgr(this: *mut core::ffi::c_void, dwthreadid: u32, pplbi: *mut *mut core::ffi::c_void, pdwthreadid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarMgr", "GetThreadLangBarItemMgr", 6usize, |this| ((**(this as *const *const ITfLangBarMgr_Vtbl)).GetThreadLangBarItemMgr)(this, dwthreadid, pplbi, pdwthreadid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputProcessorProfiles(this: *mut core::ffi::c_void, dwthreadid: u32, ppaip: *mut *mut core::ffi::c_void, pdwthreadid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarMgr", "GetInputProcessorProfiles", 7usize, |this| ((**(this as *const *const ITfLangBarMgr_Vtbl)).GetInputProcessorProfiles)(this, dwthreadid, ppaip, pdwthreadid), |result| Some(*result))
+        }
+        unsafe extern "system" fn RestoreLastFocus(this: *mut core::ffi::c_void, pdwthreadid: *mut u32, fprev: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarMgr", "RestoreLastFocus", 8usize, |this| ((**(this as *const *const ITfLangBarMgr_Vtbl)).RestoreLastFocus)(this, pdwthreadid, fprev), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetModalInput(this: *mut core::ffi::c_void, psink: *mut core::ffi::c_void, dwthreadid: u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarMgr", "SetModalInput", 9usize, |this| ((**(this as *const *const ITfLangBarMgr_Vtbl)).SetModalInput)(this, psink, dwthreadid, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowFloating(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarMgr", "ShowFloating", 10usize, |this| ((**(this as *const *const ITfLangBarMgr_Vtbl)).ShowFloating)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetShowFloatingStatus(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLangBarMgr", "GetShowFloatingStatus", 11usize, |this| ((**(this as *const *const ITfLangBarMgr_Vtbl)).GetShowFloatingStatus)(this, pdwflags), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            AdviseEventSink,
+            UnadviseEventSink,
+            GetThreadMarshalInterface,
+            GetThreadLangBarItemMgr,
+            GetInputProcessorProfiles,
+            RestoreLastFocus,
+            SetModalInput,
+            ShowFloating,
+            GetShowFloatingStatus,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfLangBarMgr as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfLanguageProfileNotifySink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnLanguageChange(this: *mut core::ffi::c_void, langid: u16, pfaccept: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLanguageProfileNotifySink", "OnLanguageChange", 3usize, |this| ((**(this as *const *const ITfLanguageProfileNotifySink_Vtbl)).OnLanguageChange)(this, langid, pfaccept), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnLanguageChanged(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfLanguageProfileNotifySink", "OnLanguageChanged", 4usize, |this| ((**(this as *const *const ITfLanguageProfileNotifySink_Vtbl)).OnLanguageChanged)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnLanguageChange, OnLanguageChanged }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfLanguageProfileNotifySink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfMSAAControl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SystemEnableMSAA(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfMSAAControl", "SystemEnableMSAA", 3usize, |this| ((**(this as *const *const ITfMSAAControl_Vtbl)).SystemEnableMSAA)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SystemDisableMSAA(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfMSAAControl", "SystemDisableMSAA", 4usize, |this| ((**(this as *const *const ITfMSAAControl_Vtbl)).SystemDisableMSAA)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SystemEnableMSAA, SystemDisableMSAA }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfMSAAControl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for ITfMenu_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn AddMenuItem(this: *mut core::ffi::c_void, uid: u32, dwflags: u32, hbmp: super::super::Graphics::Gdi::HBITMAP, hbmpmask: super::super::Graphics::Gdi::HBITMAP, pch: windows_core::PCWSTR, cch: u32, ppmenu: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfMenu", "AddMenuItem", 3usize, |this| ((**(this as *const *const ITfMenu_Vtbl)).AddMenuItem)(this, uid, dwflags, hbmp, hbmpmask, pch, cch, ppmenu), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            AddMenuItem,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            AddMenuItem: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfMenu as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl windows_core::imp::ProxyVtable for ITfMessagePump_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn PeekMessageA(this: *mut core::ffi::c_void, pmsg: *mut super::WindowsAndMessaging::MSG, hwnd: super::super::Foundation::HWND, wmsgfiltermin: u32, wmsgfiltermax: u32, wremovemsg: u32, pfresult: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfMessagePump", "PeekMessageA", 3usize, |this| ((**(this as *const *const ITfMessagePump_Vtbl)).PeekMessageA)(this, pmsg, hwnd, wmsgfiltermin, wmsgfiltermax, wremovemsg, pfresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn GetMessageA(this: *mut core::ffi::c_void, pmsg: *mut super::WindowsAndMessaging::MSG, hwnd: super::super::Foundation::HWND, wmsgfiltermin: u32, wmsgfiltermax: u32, pfresult: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfMessagePump", "GetMessageA", 4usize, |this| ((**(this as *const *const ITfMessagePump_Vtbl)).GetMessageA)(this, pmsg, hwnd, wmsgfiltermin, wmsgfiltermax, pfresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn PeekMessageW(this: *mut core::ffi::c_void, pmsg: *mut super::WindowsAndMessaging::MSG, hwnd: super::super::Foundation::HWND, wmsgfiltermin: u32, wmsgfiltermax: u32, wremovemsg: u32, pfresult: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfMessagePump", "PeekMessageW", 5usize, |this| ((**(this as *const *const ITfMessagePump_Vtbl)).PeekMessageW)(this, pmsg, hwnd, wmsgfiltermin, wmsgfiltermax, wremovemsg, pfresult), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn GetMessageW(this: *mut core::ffi::c_void, pmsg: *mut super::WindowsAndMessaging::MSG, hwnd: super::super::Foundation::HWND, wmsgfiltermin: u32, wmsgfiltermax: u32, pfresult: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfMessagePump", "GetMessageW", 6usize, |this| ((**(this as *const *const ITfMessagePump_Vtbl)).GetMessageW)(this, pmsg, hwnd, wmsgfiltermin, wmsgfiltermax, pfresult), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            PeekMessageA,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            PeekMessageA: 0,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            GetMessageA,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            GetMessageA: 0,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            PeekMessageW,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            PeekMessageW: 0,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            GetMessageW,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            GetMessageW: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfMessagePump as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfMouseSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnMouseEvent(this: *mut core::ffi::c_void, uedge: u32, uquadrant: u32, dwbtnstatus: u32, pfeaten: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfMouseSink", "OnMouseEvent", 3usize, |this| ((**(this as *const *const ITfMouseSink_Vtbl)).OnMouseEvent)(this, uedge, uquadrant, dwbtnstatus, pfeaten), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnMouseEvent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfMouseSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfMouseTracker_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AdviseMouseSink(this: *mut core::ffi::c_void, range: *mut core::ffi::c_void, psink: *mut core::ffi::c_void, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfMouseTracker", "AdviseMouseSink", 3usize, |this| ((**(this as *const *const ITfMouseTracker_Vtbl)).AdviseMouseSink)(this, range, psink, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnadviseMouseSink(this: *mut core::ffi::c_void, dwcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfMouseTracker", "UnadviseMouseSink", 4usize, |this| ((**(this as *const *const ITfMouseTracker_Vtbl)).UnadviseMouseSink)(this, dwcookie), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AdviseMouseSink, UnadviseMouseSink }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfMouseTracker as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfMouseTrackerACP_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AdviseMouseSink(this: *mut core::ffi::c_void, range: *mut core::ffi::c_void, psink: *mut core::ffi::c_void, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfMouseTrackerACP", "AdviseMouseSink", 3usize, |this| ((**(this as *const *const ITfMouseTrackerACP_Vtbl)).AdviseMouseSink)(this, range, psink, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnadviseMouseSink(this: *mut core::ffi::c_void, dwcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfMouseTrackerACP", "UnadviseMouseSink", 4usize, |this| ((**(this as *const *const ITfMouseTrackerACP_Vtbl)).UnadviseMouseSink)(this, dwcookie), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AdviseMouseSink, UnadviseMouseSink }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfMouseTrackerACP as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITfPersistentPropertyLoaderACP_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn LoadProperty(this: *mut core::ffi::c_void, phdr: *const TF_PERSISTENT_PROPERTY_HEADER_ACP, ppstream: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfPersistentPropertyLoaderACP", "LoadProperty", 3usize, |this| ((**(this as *const *const ITfPersistentPropertyLoaderACP_Vtbl)).LoadProperty)(this, phdr, ppstream), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            LoadProperty,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            LoadProperty: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfPersistentPropertyLoaderACP as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfPreservedKeyNotifySink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnUpdated(this: *mut core::ffi::c_void, pprekey: *const TF_PRESERVEDKEY) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfPreservedKeyNotifySink", "OnUpdated", 3usize, |this| ((**(this as *const *const ITfPreservedKeyNotifySink_Vtbl)).OnUpdated)(this, pprekey), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnUpdated }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfPreservedKeyNotifySink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfProperty_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FindRange(this: *mut core::ffi::c_void, ec: u32, prange: *mut core::ffi::c_void, pprange: *mut *mut core::ffi::c_void, apos: TfAnchor) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfProperty", "FindRange", 7usize, |this| ((**(this as *const *const ITfProperty_Vtbl)).FindRange)(this, ec, prange, pprange, apos), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValueStore(this: *mut core::ffi::c_void, ec: u32, prange: *mut core::ffi::c_void, ppropstore: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfProperty", "SetValueStore", 8usize, |this| ((**(this as *const *const ITfProperty_Vtbl)).SetValueStore)(this, ec, prange, ppropstore), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetValue(this: *mut core::ffi::c_void, ec: u32, prange: *mut core::ffi::c_void, pvarvalue: *const core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfProperty", "SetValue", 9usize, |this| ((**(this as *const *const ITfProperty_Vtbl)).SetValue)(this, ec, prange, pvarvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clear(this: *mut core::ffi::c_void, ec: u32, prange: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfProperty", "Clear", 10usize, |this| ((**(this as *const *const ITfProperty_Vtbl)).Clear)(this, ec, prange), |result| Some(*result))
+        }
+        Self { base__: <ITfReadOnlyProperty_Vtbl as windows_core::imp::ProxyVtable>::PROXY, FindRange, SetValueStore, SetValue, Clear }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfProperty as windows_core::Interface>::IID || <ITfReadOnlyProperty_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITfPropertyStore_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfPropertyStore", "GetType", 3usize, |this| ((**(this as *const *const ITfPropertyStore_Vtbl)).GetType)(this, pguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDataType(this: *mut core::ffi::c_void, pdwreserved: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfPropertyStore", "GetDataType", 4usize, |this| ((**(this as *const *const ITfPropertyStore_Vtbl)).GetDataType)(this, pdwreserved), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetData(this: *mut core::ffi::c_void, pvarvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfPropertyStore", "GetData", 5usize, |this| ((**(this as *const *const ITfPropertyStore_Vtbl)).GetData)(this, pvarvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnTextUpdated(this: *mut core::ffi::c_void, dwflags: u32, prangenew: *mut core::ffi::c_void, pfaccept: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfPropertyStore", "OnTextUpdated", 6usize, |this| ((**(this as *const *const ITfPropertyStore_Vtbl)).OnTextUpdated)(this, dwflags, prangenew, pfaccept), |result| Some(*result))
+        }
+        unsafe extern "system" fn Shrink(this: *mut core::ffi::c_void, prangenew: *mut core::ffi::c_void, pffree: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfPropertyStore", "Shrink", 7usize, |this| ((**(this as *const *const ITfPropertyStore_Vtbl)).Shrink)(this, prangenew, pffree), |result| Some(*result))
+        }
+        unsafe extern "system" fn Divide(this: *mut core::ffi::c_void, prangethis: *mut core::ffi::c_void, prangenew: *mut core::ffi::c_void, pppropstore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfPropertyStore", "Divide", 8usize, |this| ((**(this as *const *const ITfPropertyStore_Vtbl)).Divide)(this, prangethis, prangenew, pppropstore), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppropstore: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfPropertyStore", "Clone", 9usize, |this| ((**(this as *const *const ITfPropertyStore_Vtbl)).Clone)(this, ppropstore), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPropertyRangeCreator(this: *mut core::ffi::c_void, pclsid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfPropertyStore", "GetPropertyRangeCreator", 10usize, |this| ((**(this as *const *const ITfPropertyStore_Vtbl)).GetPropertyRangeCreator)(this, pclsid), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Serialize(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, pcb: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfPropertyStore", "Serialize", 11usize, |this| ((**(this as *const *const ITfPropertyStore_Vtbl)).Serialize)(this, pstream, pcb), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetType,
+            GetDataType,
+            GetData,
+            OnTextUpdated,
+            Shrink,
+            Divide,
+            Clone,
+            GetPropertyRangeCreator,
+            #[cfg(feature = "Win32_System_Com")]
+            Serialize,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Serialize: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfPropertyStore as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITfQueryEmbedded_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn QueryInsertEmbedded(this: *mut core::ffi::c_void, pguidservice: *const windows_core::GUID, pformatetc: *const super::super::System::Com::FORMATETC, pfinsertable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfQueryEmbedded", "QueryInsertEmbedded", 3usize, |this| ((**(this as *const *const ITfQueryEmbedded_Vtbl)).QueryInsertEmbedded)(this, pguidservice, pformatetc, pfinsertable), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            QueryInsertEmbedded,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            QueryInsertEmbedded: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfQueryEmbedded as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITfRange_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetText(this: *mut core::ffi::c_void, ec: u32, dwflags: u32, pchtext: windows_core::PWSTR, cchmax: u32, pcch: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfRange", "GetText", 3usize, |this| ((**(this as *const *const ITfRange_Vtbl)).GetText)(this, ec, dwflags, pchtext, cchmax, pcch), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetText(this: *mut core::ffi::c_void, ec: u32, dwflags: u32, pchtext: windows_core::PCWSTR, cch: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfRange", "SetText", 4usize, |this| ((**(this as *const *const ITfRange_Vtbl)).SetText)(this, ec, dwflags, pchtext, cch), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn GetFormattedText(this: *mut core::ffi::c_void, ec: u32, ppdataobject: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfRange", "GetFormattedText", 5usize, |this| ((**(this as *const *const ITfRange_Vtbl)).GetFormattedText)(this, ec, ppdataobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetEmbedded(this: *mut core::ffi::c_void, ec: u32, rguidservice: *const windows_core::GUID, riid: *const windows_core::GUID, ppunk: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfRange", "GetEmbedded", 6usize, |this| ((**(this as *const *const ITfRange_Vtbl)).GetEmbedded)(this, ec, rguidservice, riid, ppunk), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn InsertEmbedded(this: *mut core::ffi::c_void, ec: u32, dwflags: u32, pdataobject: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfRange", "InsertEmbedded", 7usize, |this| ((**(this as *const *const ITfRange_Vtbl)).InsertEmbedded)(this, ec, dwflags, pdataobject), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShiftStart(this: *mut core::ffi::c_void, ec: u32, cchreq: i32, pcch: *mut i32, phalt: *const TF_HALTCOND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfRange", "ShiftStart", 8usize, |this| ((**(this as *const *const ITfRange_Vtbl)).ShiftStart)(this, ec, cchreq, pcch, phalt), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShiftEnd(this: *mut core::ffi::c_void, ec: u32, cchreq: i32, pcch: *mut i32, phalt: *const TF_HALTCOND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfRange", "ShiftEnd", 9usize, |this| ((**(this as *const *const ITfRange_Vtbl)).ShiftEnd)(this, ec, cchreq, pcch, phalt), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShiftStartToRange(this: *mut core::ffi::c_void, ec: u32, prange: *mut core::ffi::c_void, apos: TfAnchor) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfRange", "ShiftStartToRange", 10usize, |this| ((**(this as *const *const ITfRange_Vtbl)).ShiftStartToRange)(this, ec, prange, apos), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShiftEndToRange(this: *mut core::ffi::c_void, ec: u32, prange: *mut core::ffi::c_void, apos: TfAnchor) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfRange", "ShiftEndToRange", 11usize, |this| ((**(this as *const *const ITfRange_Vtbl)).ShiftEndToRange)(this, ec, prange, apos), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShiftStartRegion(this: *mut core::ffi::c_void, ec: u32, dir: TfShiftDir, pfnoregion: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfRange", "ShiftStartRegion", 12usize, |this| ((**(this as *const *const ITfRange_Vtbl)).ShiftStartRegion)(this, ec, dir, pfnoregion), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShiftEndRegion(this: *mut core::ffi::c_void, ec: u32, dir: TfShiftDir, pfnoregion: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfRange", "ShiftEndRegion", 13usize, |this| ((**(this as *const *const ITfRange_Vtbl)).ShiftEndRegion)(this, ec, dir, pfnoregion), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEmpty(this: *mut core::ffi::c_void, ec: u32, pfempty: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfRange", "IsEmpty", 14usize, |this| ((**(this as *const *const ITfRange_Vtbl)).IsEmpty)(this, ec, pfempty), |result| Some(*result))
+        }
+        unsafe extern "system" fn Collapse(this: *mut core::ffi::c_void, ec: u32, apos: TfAnchor) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfRange", "Collapse", 15usize, |this| ((**(this as *const *const ITfRange_Vtbl)).Collapse)(this, ec, apos), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEqualStart(this: *mut core::ffi::c_void, ec: u32, pwith: *mut core::ffi::c_void, apos: TfAnchor, pfequal: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfRange", "IsEqualStart", 16usize, |this| ((**(this as *const *const ITfRange_Vtbl)).IsEqualStart)(this, ec, pwith, apos, pfequal), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEqualEnd(this: *mut core::ffi::c_void, ec: u32, pwith: *mut core::ffi::c_void, apos: TfAnchor, pfequal: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfRange", "IsEqualEnd", 17usize, |this| ((**(this as *const *const ITfRange_Vtbl)).IsEqualEnd)(this, ec, pwith, apos, pfequal), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompareStart(this: *mut core::ffi::c_void, ec: u32, pwith: *mut core::ffi::c_void, apos: TfAnchor, plresult: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfRange", "CompareStart", 18usize, |this| ((**(this as *const *const ITfRange_Vtbl)).CompareStart)(this, ec, pwith, apos, plresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn CompareEnd(this: *mut core::ffi::c_void, ec: u32, pwith: *mut core::ffi::c_void, apos: TfAnchor, plresult: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfRange", "CompareEnd", 19usize, |this| ((**(this as *const *const ITfRange_Vtbl)).CompareEnd)(this, ec, pwith, apos, plresult), |result| Some(*result))
+        }
+        unsafe extern "system" fn AdjustForInsert(this: *mut core::ffi::c_void, ec: u32, cchinsert: u32, pfinsertok: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfRange", "AdjustForInsert", 20usize, |this| ((**(this as *const *const ITfRange_Vtbl)).AdjustForInsert)(this, ec, cchinsert, pfinsertok), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGravity(this: *mut core::ffi::c_void, pgstart: *mut TfGravity, pgend: *mut TfGravity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfRange", "GetGravity", 21usize, |this| ((**(this as *const *const ITfRange_Vtbl)).GetGravity)(this, pgstart, pgend), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetGravity(this: *mut core::ffi::c_void, ec: u32, gstart: TfGravity, gend: TfGravity) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfRange", "SetGravity", 22usize, |this| ((**(this as *const *const ITfRange_Vtbl)).SetGravity)(this, ec, gstart, gend), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppclone: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfRange", "Clone", 23usize, |this| ((**(this as *const *const ITfRange_Vtbl)).Clone)(this, ppclone), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContext(this: *mut core::ffi::c_void, ppcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfRange", "GetContext", 24usize, |this| ((**(this as *const *const ITfRange_Vtbl)).GetContext)(this, ppcontext), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetText,
+            SetText,
+            #[cfg(feature = "Win32_System_Com")]
+            GetFormattedText,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            GetFormattedText: 0,
+            GetEmbedded,
+            #[cfg(feature = "Win32_System_Com")]
+            InsertEmbedded,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            InsertEmbedded: 0,
+            ShiftStart,
+            ShiftEnd,
+            ShiftStartToRange,
+            ShiftEndToRange,
+            ShiftStartRegion,
+            ShiftEndRegion,
+            IsEmpty,
+            Collapse,
+            IsEqualStart,
+            IsEqualEnd,
+            CompareStart,
+            CompareEnd,
+            AdjustForInsert,
+            GetGravity,
+            SetGravity,
+            Clone,
+            GetContext,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfRange as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITfRangeACP_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetExtent(this: *mut core::ffi::c_void, pacpanchor: *mut i32, pcch: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfRangeACP", "GetExtent", 25usize, |this| ((**(this as *const *const ITfRangeACP_Vtbl)).GetExtent)(this, pacpanchor, pcch), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetExtent(this: *mut core::ffi::c_void, acpanchor: i32, cch: i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfRangeACP", "SetExtent", 26usize, |this| ((**(this as *const *const ITfRangeACP_Vtbl)).SetExtent)(this, acpanchor, cch), |result| Some(*result))
+        }
+        Self { base__: <ITfRange_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetExtent, SetExtent }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfRangeACP as windows_core::Interface>::IID || <ITfRange_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfRangeBackup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Restore(this: *mut core::ffi::c_void, ec: u32, prange: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfRangeBackup", "Restore", 3usize, |this| ((**(this as *const *const ITfRangeBackup_Vtbl)).Restore)(this, ec, prange), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Restore }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfRangeBackup as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfReadOnlyProperty_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfReadOnlyProperty", "GetType", 3usize, |this| ((**(this as *const *const ITfReadOnlyProperty_Vtbl)).GetType)(this, pguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumRanges(this: *mut core::ffi::c_void, ec: u32, ppenum: *mut *mut core::ffi::c_void, ptargetrange: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfReadOnlyProperty", "EnumRanges", 4usize, |this| ((**(this as *const *const ITfReadOnlyProperty_Vtbl)).EnumRanges)(this, ec, ppenum, ptargetrange), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetValue(this: *mut core::ffi::c_void, ec: u32, prange: *mut core::ffi::c_void, pvarvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfReadOnlyProperty", "GetValue", 5usize, |this| ((**(this as *const *const ITfReadOnlyProperty_Vtbl)).GetValue)(this, ec, prange, pvarvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContext(this: *mut core::ffi::c_void, ppcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfReadOnlyProperty", "GetContext", 6usize, |this| ((**(this as *const *const ITfReadOnlyProperty_Vtbl)).GetContext)(this, ppcontext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetType, EnumRanges, GetValue, GetContext }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfReadOnlyProperty as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfReadingInformationUIElement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetUpdatedFlags(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfReadingInformationUIElement", "GetUpdatedFlags", 7usize, |this| ((**(this as *const *const ITfReadingInformationUIElement_Vtbl)).GetUpdatedFlags)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetContext(this: *mut core::ffi::c_void, ppic: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfReadingInformationUIElement", "GetContext", 8usize, |this| ((**(this as *const *const ITfReadingInformationUIElement_Vtbl)).GetContext)(this, ppic), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetString(this: *mut core::ffi::c_void, pstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfReadingInformationUIElement", "GetString", 9usize, |this| ((**(this as *const *const ITfReadingInformationUIElement_Vtbl)).GetString)(this, pstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetMaxReadingStringLength(this: *mut core::ffi::c_void, pcchmax: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfReadingInformationUIElement", "GetMaxReadingStringLength", 10usize, |this| ((**(this as *const *const ITfReadingInformationUIElement_Vtbl)).GetMaxReadingStringLength)(this, pcchmax), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetErrorIndex(this: *mut core::ffi::c_void, perrorindex: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfReadingInformationUIElement", "GetErrorIndex", 11usize, |this| ((**(this as *const *const ITfReadingInformationUIElement_Vtbl)).GetErrorIndex)(this, perrorindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsVerticalOrderPreferred(this: *mut core::ffi::c_void, pfvertical: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfReadingInformationUIElement", "IsVerticalOrderPreferred", 12usize, |this| ((**(this as *const *const ITfReadingInformationUIElement_Vtbl)).IsVerticalOrderPreferred)(this, pfvertical), |result| Some(*result))
+        }
+        Self {
+            base__: <ITfUIElement_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetUpdatedFlags,
+            GetContext,
+            GetString,
+            GetMaxReadingStringLength,
+            GetErrorIndex,
+            IsVerticalOrderPreferred,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfReadingInformationUIElement as windows_core::Interface>::IID || <ITfUIElement_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfReverseConversion_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn DoReverseConversion(this: *mut core::ffi::c_void, lpstr: windows_core::PCWSTR, pplist: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfReverseConversion", "DoReverseConversion", 3usize, |this| ((**(this as *const *const ITfReverseConversion_Vtbl)).DoReverseConversion)(this, lpstr, pplist), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, DoReverseConversion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfReverseConversion as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfReverseConversionList_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetLength(this: *mut core::ffi::c_void, puindex: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfReverseConversionList", "GetLength", 3usize, |this| ((**(this as *const *const ITfReverseConversionList_Vtbl)).GetLength)(this, puindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetString(this: *mut core::ffi::c_void, uindex: u32, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfReverseConversionList", "GetString", 4usize, |this| ((**(this as *const *const ITfReverseConversionList_Vtbl)).GetString)(this, uindex, pbstr), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetLength, GetString }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfReverseConversionList as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfReverseConversionMgr_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetReverseConversion(this: *mut core::ffi::c_void, langid: u16, guidprofile: *const windows_core::GUID, dwflag: u32, ppreverseconversion: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfReverseConversionMgr", "GetReverseConversion", 3usize, |this| ((**(this as *const *const ITfReverseConversionMgr_Vtbl)).GetReverseConversion)(this, langid, guidprofile, dwflag, ppreverseconversion), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetReverseConversion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfReverseConversionMgr as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfSource_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AdviseSink(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, punk: *mut core::ffi::c_void, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfSource", "AdviseSink", 3usize, |this| ((**(this as *const *const ITfSource_Vtbl)).AdviseSink)(this, riid, punk, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnadviseSink(this: *mut core::ffi::c_void, dwcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfSource", "UnadviseSink", 4usize, |this| ((**(this as *const *const ITfSource_Vtbl)).UnadviseSink)(this, dwcookie), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AdviseSink, UnadviseSink }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfSource as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfSourceSingle_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AdviseSingleSink(this: *mut core::ffi::c_void, tid: u32, riid: *const windows_core::GUID, punk: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfSourceSingle", "AdviseSingleSink", 3usize, |this| ((**(this as *const *const ITfSourceSingle_Vtbl)).AdviseSingleSink)(this, tid, riid, punk), |result| Some(*result))
+        }
+        unsafe extern "system" fn UnadviseSingleSink(this: *mut core::ffi::c_void, tid: u32, riid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfSourceSingle", "UnadviseSingleSink", 4usize, |this| ((**(this as *const *const ITfSourceSingle_Vtbl)).UnadviseSingleSink)(this, tid, riid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AdviseSingleSink, UnadviseSingleSink }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfSourceSingle as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfSpeechUIServer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfSpeechUIServer", "Initialize", 3usize, |this| ((**(this as *const *const ITfSpeechUIServer_Vtbl)).Initialize)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowUI(this: *mut core::ffi::c_void, fshow: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfSpeechUIServer", "ShowUI", 4usize, |this| ((**(this as *const *const ITfSpeechUIServer_Vtbl)).ShowUI)(this, fshow), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateBalloon(this: *mut core::ffi::c_void, style: TfLBBalloonStyle, pch: windows_core::PCWSTR, cch: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfSpeechUIServer", "UpdateBalloon", 5usize, |this| ((**(this as *const *const ITfSpeechUIServer_Vtbl)).UpdateBalloon)(this, style, pch, cch), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Initialize, ShowUI, UpdateBalloon }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfSpeechUIServer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfStatusSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnStatusChange(this: *mut core::ffi::c_void, pic: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfStatusSink", "OnStatusChange", 3usize, |this| ((**(this as *const *const ITfStatusSink_Vtbl)).OnStatusChange)(this, pic, dwflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnStatusChange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfStatusSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfSystemDeviceTypeLangBarItem_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetIconMode(this: *mut core::ffi::c_void, dwflags: LANG_BAR_ITEM_ICON_MODE_FLAGS) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfSystemDeviceTypeLangBarItem", "SetIconMode", 3usize, |this| ((**(this as *const *const ITfSystemDeviceTypeLangBarItem_Vtbl)).SetIconMode)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIconMode(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfSystemDeviceTypeLangBarItem", "GetIconMode", 4usize, |this| ((**(this as *const *const ITfSystemDeviceTypeLangBarItem_Vtbl)).GetIconMode)(this, pdwflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetIconMode, GetIconMode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfSystemDeviceTypeLangBarItem as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl windows_core::imp::ProxyVtable for ITfSystemLangBarItem_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn SetIcon(this: *mut core::ffi::c_void, hicon: super::WindowsAndMessaging::HICON) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfSystemLangBarItem", "SetIcon", 3usize, |this| ((**(this as *const *const ITfSystemLangBarItem_Vtbl)).SetIcon)(this, hicon), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetTooltipString(this: *mut core::ffi::c_void, pchtooltip: windows_core::PCWSTR, cch: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfSystemLangBarItem", "SetTooltipString", 4usize, |this| ((**(this as *const *const ITfSystemLangBarItem_Vtbl)).SetTooltipString)(this, pchtooltip, cch), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            SetIcon,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            SetIcon: 0,
+            SetTooltipString,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfSystemLangBarItem as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfSystemLangBarItemSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InitMenu(this: *mut core::ffi::c_void, pmenu: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfSystemLangBarItemSink", "InitMenu", 3usize, |this| ((**(this as *const *const ITfSystemLangBarItemSink_Vtbl)).InitMenu)(this, pmenu), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnMenuSelect(this: *mut core::ffi::c_void, wid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfSystemLangBarItemSink", "OnMenuSelect", 4usize, |this| ((**(this as *const *const ITfSystemLangBarItemSink_Vtbl)).OnMenuSelect)(this, wid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InitMenu, OnMenuSelect }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfSystemLangBarItemSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfSystemLangBarItemText_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetItemText(this: *mut core::ffi::c_void, pch: windows_core::PCWSTR, cch: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfSystemLangBarItemText", "SetItemText", 3usize, |this| ((**(this as *const *const ITfSystemLangBarItemText_Vtbl)).SetItemText)(this, pch, cch), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetItemText(this: *mut core::ffi::c_void, pbstrtext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfSystemLangBarItemText", "GetItemText", 4usize, |this| ((**(this as *const *const ITfSystemLangBarItemText_Vtbl)).GetItemText)(this, pbstrtext), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetItemText, GetItemText }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfSystemLangBarItemText as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfTextEditSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnEndEdit(this: *mut core::ffi::c_void, pic: *mut core::ffi::c_void, ecreadonly: u32, peditrecord: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfTextEditSink", "OnEndEdit", 3usize, |this| ((**(this as *const *const ITfTextEditSink_Vtbl)).OnEndEdit)(this, pic, ecreadonly, peditrecord), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnEndEdit }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfTextEditSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfTextInputProcessor_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Activate(this: *mut core::ffi::c_void, ptim: *mut core::ffi::c_void, tid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfTextInputProcessor", "Activate", 3usize, |this| ((**(this as *const *const ITfTextInputProcessor_Vtbl)).Activate)(this, ptim, tid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deactivate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfTextInputProcessor", "Deactivate", 4usize, |this| ((**(this as *const *const ITfTextInputProcessor_Vtbl)).Deactivate)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Activate, Deactivate }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfTextInputProcessor as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfTextInputProcessorEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ActivateEx(this: *mut core::ffi::c_void, ptim: *mut core::ffi::c_void, tid: u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfTextInputProcessorEx", "ActivateEx", 5usize, |this| ((**(this as *const *const ITfTextInputProcessorEx_Vtbl)).ActivateEx)(this, ptim, tid, dwflags), |result| Some(*result))
+        }
+        Self { base__: <ITfTextInputProcessor_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ActivateEx }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfTextInputProcessorEx as windows_core::Interface>::IID || <ITfTextInputProcessor_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfTextLayoutSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnLayoutChange(this: *mut core::ffi::c_void, pic: *mut core::ffi::c_void, lcode: TfLayoutCode, pview: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfTextLayoutSink", "OnLayoutChange", 3usize, |this| ((**(this as *const *const ITfTextLayoutSink_Vtbl)).OnLayoutChange)(this, pic, lcode, pview), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnLayoutChange }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfTextLayoutSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfThreadFocusSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnSetThreadFocus(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadFocusSink", "OnSetThreadFocus", 3usize, |this| ((**(this as *const *const ITfThreadFocusSink_Vtbl)).OnSetThreadFocus)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnKillThreadFocus(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadFocusSink", "OnKillThreadFocus", 4usize, |this| ((**(this as *const *const ITfThreadFocusSink_Vtbl)).OnKillThreadFocus)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnSetThreadFocus, OnKillThreadFocus }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfThreadFocusSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfThreadMgr_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Activate(this: *mut core::ffi::c_void, ptid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgr", "Activate", 3usize, |this| ((**(this as *const *const ITfThreadMgr_Vtbl)).Activate)(this, ptid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deactivate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgr", "Deactivate", 4usize, |this| ((**(this as *const *const ITfThreadMgr_Vtbl)).Deactivate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDocumentMgr(this: *mut core::ffi::c_void, ppdim: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgr", "CreateDocumentMgr", 5usize, |this| ((**(this as *const *const ITfThreadMgr_Vtbl)).CreateDocumentMgr)(this, ppdim), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumDocumentMgrs(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgr", "EnumDocumentMgrs", 6usize, |this| ((**(this as *const *const ITfThreadMgr_Vtbl)).EnumDocumentMgrs)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFocus(this: *mut core::ffi::c_void, ppdimfocus: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgr", "GetFocus", 7usize, |this| ((**(this as *const *const ITfThreadMgr_Vtbl)).GetFocus)(this, ppdimfocus), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFocus(this: *mut core::ffi::c_void, pdimfocus: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgr", "SetFocus", 8usize, |this| ((**(this as *const *const ITfThreadMgr_Vtbl)).SetFocus)(this, pdimfocus), |result| Some(*result))
+        }
+        unsafe extern "system" fn AssociateFocus(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, pdimnew: *mut core::ffi::c_void, ppdimprev: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgr", "AssociateFocus", 9usize, |this| ((**(this as *const *const ITfThreadMgr_Vtbl)).AssociateFocus)(this, hwnd, pdimnew, ppdimprev), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsThreadFocus(this: *mut core::ffi::c_void, pfthreadfocus: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgr", "IsThreadFocus", 10usize, |this| ((**(this as *const *const ITfThreadMgr_Vtbl)).IsThreadFocus)(this, pfthreadfocus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFunctionProvider(this: *mut core::ffi::c_void, clsid: *const windows_core::GUID, ppfuncprov: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgr", "GetFunctionProvider", 11usize, |this| ((**(this as *const *const ITfThreadMgr_Vtbl)).GetFunctionProvider)(this, clsid, ppfuncprov), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumFunctionProviders(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgr", "EnumFunctionProviders", 12usize, |this| ((**(this as *const *const ITfThreadMgr_Vtbl)).EnumFunctionProviders)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGlobalCompartment(this: *mut core::ffi::c_void, ppcompmgr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgr", "GetGlobalCompartment", 13usize, |this| ((**(this as *const *const ITfThreadMgr_Vtbl)).GetGlobalCompartment)(this, ppcompmgr), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Activate,
+            Deactivate,
+            CreateDocumentMgr,
+            EnumDocumentMgrs,
+            GetFocus,
+            SetFocus,
+            AssociateFocus,
+            IsThreadFocus,
+            GetFunctionProvider,
+            EnumFunctionProviders,
+            GetGlobalCompartment,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfThreadMgr as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfThreadMgr2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Activate(this: *mut core::ffi::c_void, ptid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgr2", "Activate", 3usize, |this| ((**(this as *const *const ITfThreadMgr2_Vtbl)).Activate)(this, ptid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Deactivate(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgr2", "Deactivate", 4usize, |this| ((**(this as *const *const ITfThreadMgr2_Vtbl)).Deactivate)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateDocumentMgr(this: *mut core::ffi::c_void, ppdim: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgr2", "CreateDocumentMgr", 5usize, |this| ((**(this as *const *const ITfThreadMgr2_Vtbl)).CreateDocumentMgr)(this, ppdim), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumDocumentMgrs(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgr2", "EnumDocumentMgrs", 6usize, |this| ((**(this as *const *const ITfThreadMgr2_Vtbl)).EnumDocumentMgrs)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFocus(this: *mut core::ffi::c_void, ppdimfocus: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgr2", "GetFocus", 7usize, |this| ((**(this as *const *const ITfThreadMgr2_Vtbl)).GetFocus)(this, ppdimfocus), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFocus(this: *mut core::ffi::c_void, pdimfocus: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgr2", "SetFocus", 8usize, |this| ((**(this as *const *const ITfThreadMgr2_Vtbl)).SetFocus)(this, pdimfocus), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsThreadFocus(this: *mut core::ffi::c_void, pfthreadfocus: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgr2", "IsThreadFocus", 9usize, |this| ((**(this as *const *const ITfThreadMgr2_Vtbl)).IsThreadFocus)(this, pfthreadfocus), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFunctionProvider(this: *mut core::ffi::c_void, clsid: *const windows_core::GUID, ppfuncprov: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgr2", "GetFunctionProvider", 10usize, |this| ((**(this as *const *const ITfThreadMgr2_Vtbl)).GetFunctionProvider)(this, clsid, ppfuncprov), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumFunctionProviders(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgr2", "EnumFunctionProviders", 11usize, |this| ((**(this as *const *const ITfThreadMgr2_Vtbl)).EnumFunctionProviders)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGlobalCompartment(this: *mut core::ffi::c_void, ppcompmgr: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgr2", "GetGlobalCompartment", 12usize, |this| ((**(this as *const *const ITfThreadMgr2_Vtbl)).GetGlobalCompartment)(this, ppcompmgr), |result| Some(*result))
+        }
+        unsafe extern "system" fn ActivateEx(this: *mut core::ffi::c_void, ptid: *mut u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgr2", "ActivateEx", 13usize, |this| ((**(this as *const *const ITfThreadMgr2_Vtbl)).ActivateEx)(this, ptid, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActiveFlags(this: *mut core::ffi::c_void, lpdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgr2", "GetActiveFlags", 14usize, |this| ((**(this as *const *const ITfThreadMgr2_Vtbl)).GetActiveFlags)(this, lpdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SuspendKeystrokeHandling(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgr2", "SuspendKeystrokeHandling", 15usize, |this| ((**(this as *const *const ITfThreadMgr2_Vtbl)).SuspendKeystrokeHandling)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn ResumeKeystrokeHandling(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgr2", "ResumeKeystrokeHandling", 16usize, |this| ((**(this as *const *const ITfThreadMgr2_Vtbl)).ResumeKeystrokeHandling)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Activate,
+            Deactivate,
+            CreateDocumentMgr,
+            EnumDocumentMgrs,
+            GetFocus,
+            SetFocus,
+            IsThreadFocus,
+            GetFunctionProvider,
+            EnumFunctionProviders,
+            GetGlobalCompartment,
+            ActivateEx,
+            GetActiveFlags,
+            SuspendKeystrokeHandling,
+            ResumeKeystrokeHandling,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfThreadMgr2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfThreadMgrEventSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnInitDocumentMgr(this: *mut core::ffi::c_void, pdim: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgrEventSink", "OnInitDocumentMgr", 3usize, |this| ((**(this as *const *const ITfThreadMgrEventSink_Vtbl)).OnInitDocumentMgr)(this, pdim), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnUninitDocumentMgr(this: *mut core::ffi::c_void, pdim: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgrEventSink", "OnUninitDocumentMgr", 4usize, |this| ((**(this as *const *const ITfThreadMgrEventSink_Vtbl)).OnUninitDocumentMgr)(this, pdim), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnSetFocus(this: *mut core::ffi::c_void, pdimfocus: *mut core::ffi::c_void, pdimprevfocus: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgrEventSink", "OnSetFocus", 5usize, |this| ((**(this as *const *const ITfThreadMgrEventSink_Vtbl)).OnSetFocus)(this, pdimfocus, pdimprevfocus), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnPushContext(this: *mut core::ffi::c_void, pic: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgrEventSink", "OnPushContext", 6usize, |this| ((**(this as *const *const ITfThreadMgrEventSink_Vtbl)).OnPushContext)(this, pic), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnPopContext(this: *mut core::ffi::c_void, pic: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgrEventSink", "OnPopContext", 7usize, |this| ((**(this as *const *const ITfThreadMgrEventSink_Vtbl)).OnPopContext)(this, pic), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnInitDocumentMgr,
+            OnUninitDocumentMgr,
+            OnSetFocus,
+            OnPushContext,
+            OnPopContext,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfThreadMgrEventSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfThreadMgrEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ActivateEx(this: *mut core::ffi::c_void, ptid: *mut u32, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgrEx", "ActivateEx", 14usize, |this| ((**(this as *const *const ITfThreadMgrEx_Vtbl)).ActivateEx)(this, ptid, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActiveFlags(this: *mut core::ffi::c_void, lpdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfThreadMgrEx", "GetActiveFlags", 15usize, |this| ((**(this as *const *const ITfThreadMgrEx_Vtbl)).GetActiveFlags)(this, lpdwflags), |result| Some(*result))
+        }
+        Self { base__: <ITfThreadMgr_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ActivateEx, GetActiveFlags }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfThreadMgrEx as windows_core::Interface>::IID || <ITfThreadMgr_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfToolTipUIElement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetString(this: *mut core::ffi::c_void, pstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfToolTipUIElement", "GetString", 7usize, |this| ((**(this as *const *const ITfToolTipUIElement_Vtbl)).GetString)(this, pstr), |result| Some(*result))
+        }
+        Self { base__: <ITfUIElement_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetString }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfToolTipUIElement as windows_core::Interface>::IID || <ITfUIElement_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfTransitoryExtensionSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnTransitoryExtensionUpdated(this: *mut core::ffi::c_void, pic: *mut core::ffi::c_void, ecreadonly: u32, presultrange: *mut core::ffi::c_void, pcompositionrange: *mut core::ffi::c_void, pfdeleteresultrange: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfTransitoryExtensionSink", "OnTransitoryExtensionUpdated", 3usize, |this| ((**(this as *const *const ITfTransitoryExtensionSink_Vtbl)).OnTransitoryExtensionUpdated)(this, pic, ecreadonly, presultrange, pcompositionrange, pfdeleteresultrange), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnTransitoryExtensionUpdated }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfTransitoryExtensionSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfTransitoryExtensionUIElement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDocumentMgr(this: *mut core::ffi::c_void, ppdim: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfTransitoryExtensionUIElement", "GetDocumentMgr", 7usize, |this| ((**(this as *const *const ITfTransitoryExtensionUIElement_Vtbl)).GetDocumentMgr)(this, ppdim), |result| Some(*result))
+        }
+        Self { base__: <ITfUIElement_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDocumentMgr }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfTransitoryExtensionUIElement as windows_core::Interface>::IID || <ITfUIElement_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfUIElement_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetDescription(this: *mut core::ffi::c_void, pbstrdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfUIElement", "GetDescription", 3usize, |this| ((**(this as *const *const ITfUIElement_Vtbl)).GetDescription)(this, pbstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetGUID(this: *mut core::ffi::c_void, pguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfUIElement", "GetGUID", 4usize, |this| ((**(this as *const *const ITfUIElement_Vtbl)).GetGUID)(this, pguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn Show(this: *mut core::ffi::c_void, bshow: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfUIElement", "Show", 5usize, |this| ((**(this as *const *const ITfUIElement_Vtbl)).Show)(this, bshow), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsShown(this: *mut core::ffi::c_void, pbshow: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfUIElement", "IsShown", 6usize, |this| ((**(this as *const *const ITfUIElement_Vtbl)).IsShown)(this, pbshow), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetDescription, GetGUID, Show, IsShown }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfUIElement as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfUIElementMgr_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BeginUIElement(this: *mut core::ffi::c_void, pelement: *mut core::ffi::c_void, pbshow: *mut super::super::Foundation::BOOL, pdwuielementid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfUIElementMgr", "BeginUIElement", 3usize, |this| ((**(this as *const *const ITfUIElementMgr_Vtbl)).BeginUIElement)(this, pelement, pbshow, pdwuielementid), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateUIElement(this: *mut core::ffi::c_void, dwuielementid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfUIElementMgr", "UpdateUIElement", 4usize, |this| ((**(this as *const *const ITfUIElementMgr_Vtbl)).UpdateUIElement)(this, dwuielementid), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndUIElement(this: *mut core::ffi::c_void, dwuielementid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfUIElementMgr", "EndUIElement", 5usize, |this| ((**(this as *const *const ITfUIElementMgr_Vtbl)).EndUIElement)(this, dwuielementid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetUIElement(this: *mut core::ffi::c_void, dwuielementid: u32, ppelement: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfUIElementMgr", "GetUIElement", 6usize, |this| ((**(this as *const *const ITfUIElementMgr_Vtbl)).GetUIElement)(this, dwuielementid, ppelement), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumUIElements(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfUIElementMgr", "EnumUIElements", 7usize, |this| ((**(this as *const *const ITfUIElementMgr_Vtbl)).EnumUIElements)(this, ppenum), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            BeginUIElement,
+            UpdateUIElement,
+            EndUIElement,
+            GetUIElement,
+            EnumUIElements,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfUIElementMgr as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITfUIElementSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BeginUIElement(this: *mut core::ffi::c_void, dwuielementid: u32, pbshow: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfUIElementSink", "BeginUIElement", 3usize, |this| ((**(this as *const *const ITfUIElementSink_Vtbl)).BeginUIElement)(this, dwuielementid, pbshow), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateUIElement(this: *mut core::ffi::c_void, dwuielementid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfUIElementSink", "UpdateUIElement", 4usize, |this| ((**(this as *const *const ITfUIElementSink_Vtbl)).UpdateUIElement)(this, dwuielementid), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndUIElement(this: *mut core::ffi::c_void, dwuielementid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITfUIElementSink", "EndUIElement", 5usize, |this| ((**(this as *const *const ITfUIElementSink_Vtbl)).EndUIElement)(this, dwuielementid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BeginUIElement, UpdateUIElement, EndUIElement }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITfUIElementSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUIManagerEventSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnWindowOpening(this: *mut core::ffi::c_void, prcbounds: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIManagerEventSink", "OnWindowOpening", 3usize, |this| ((**(this as *const *const IUIManagerEventSink_Vtbl)).OnWindowOpening)(this, prcbounds), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnWindowOpened(this: *mut core::ffi::c_void, prcbounds: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIManagerEventSink", "OnWindowOpened", 4usize, |this| ((**(this as *const *const IUIManagerEventSink_Vtbl)).OnWindowOpened)(this, prcbounds), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnWindowUpdating(this: *mut core::ffi::c_void, prcupdatedbounds: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIManagerEventSink", "OnWindowUpdating", 5usize, |this| ((**(this as *const *const IUIManagerEventSink_Vtbl)).OnWindowUpdating)(this, prcupdatedbounds), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnWindowUpdated(this: *mut core::ffi::c_void, prcupdatedbounds: *const super::super::Foundation::RECT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIManagerEventSink", "OnWindowUpdated", 6usize, |this| ((**(this as *const *const IUIManagerEventSink_Vtbl)).OnWindowUpdated)(this, prcupdatedbounds), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnWindowClosing(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIManagerEventSink", "OnWindowClosing", 7usize, |this| ((**(this as *const *const IUIManagerEventSink_Vtbl)).OnWindowClosing)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnWindowClosed(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUIManagerEventSink", "OnWindowClosed", 8usize, |this| ((**(this as *const *const IUIManagerEventSink_Vtbl)).OnWindowClosed)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            OnWindowOpening,
+            OnWindowOpened,
+            OnWindowUpdating,
+            OnWindowUpdated,
+            OnWindowClosing,
+            OnWindowClosed,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUIManagerEventSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IVersionInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSubcomponentCount(this: *mut core::ffi::c_void, ulsub: u32, ulcount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVersionInfo", "GetSubcomponentCount", 3usize, |this| ((**(this as *const *const IVersionInfo_Vtbl)).GetSubcomponentCount)(this, ulsub, ulcount), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetImplementationID(this: *mut core::ffi::c_void, ulsub: u32, implid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVersionInfo", "GetImplementationID", 4usize, |this| ((**(this as *const *const IVersionInfo_Vtbl)).GetImplementationID)(this, ulsub, implid), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBuildVersion(this: *mut core::ffi::c_void, ulsub: u32, pdwmajor: *mut u32, pdwminor: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVersionInfo", "GetBuildVersion", 5usize, |this| ((**(this as *const *const IVersionInfo_Vtbl)).GetBuildVersion)(this, ulsub, pdwmajor, pdwminor), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetComponentDescription(this: *mut core::ffi::c_void, ulsub: u32, pimplstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVersionInfo", "GetComponentDescription", 6usize, |this| ((**(this as *const *const IVersionInfo_Vtbl)).GetComponentDescription)(this, ulsub, pimplstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInstanceDescription(this: *mut core::ffi::c_void, ulsub: u32, pimplstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IVersionInfo", "GetInstanceDescription", 7usize, |this| ((**(this as *const *const IVersionInfo_Vtbl)).GetInstanceDescription)(this, ulsub, pimplstr), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSubcomponentCount,
+            GetImplementationID,
+            GetBuildVersion,
+            GetComponentDescription,
+            GetInstanceDescription,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IVersionInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
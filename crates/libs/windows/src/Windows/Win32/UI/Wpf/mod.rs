@@ -675,7 +675,8 @@ pub const CLSID_MILBitmapEffectGroup: windows_core::GUID = windows_core::GUID::f
 pub const CLSID_MILBitmapEffectOuterGlow: windows_core::GUID = windows_core::GUID::from_u128(0xe2161bdd_7eb6_4725_9c0b_8a2a1b4f0667);
 pub const MILBITMAPEFFECT_SDK_VERSION: u32 = 16777216u32;
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MILMatrixF {
     pub _11: f64,
     pub _12: f64,
@@ -703,7 +704,8 @@ impl Default for MILMatrixF {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MilPoint2D {
     pub X: f64,
     pub Y: f64,
@@ -717,7 +719,8 @@ impl Default for MilPoint2D {
     }
 }
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct MilRectD {
     pub left: f64,
     pub top: f64,
@@ -734,3 +737,5 @@ impl Default for MilRectD {
 }
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,454 @@
+#[cfg(feature = "Win32_Graphics_Imaging")]
+impl windows_core::imp::ProxyVtable for IMILBitmapEffect_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Imaging")]
+        unsafe extern "system" fn GetOutput(this: *mut core::ffi::c_void, uiindex: u32, pcontext: *mut core::ffi::c_void, ppbitmapsource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffect", "GetOutput", 3usize, |this| ((**(this as *const *const IMILBitmapEffect_Vtbl)).GetOutput)(this, uiindex, pcontext, ppbitmapsource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParentEffect(this: *mut core::ffi::c_void, ppparenteffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffect", "GetParentEffect", 4usize, |this| ((**(this as *const *const IMILBitmapEffect_Vtbl)).GetParentEffect)(this, ppparenteffect), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Imaging")]
+        unsafe extern "system" fn SetInputSource(this: *mut core::ffi::c_void, uiindex: u32, pbitmapsource: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffect", "SetInputSource", 5usize, |this| ((**(this as *const *const IMILBitmapEffect_Vtbl)).SetInputSource)(this, uiindex, pbitmapsource), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Imaging")]
+            GetOutput,
+            #[cfg(not(feature = "Win32_Graphics_Imaging"))]
+            GetOutput: 0,
+            GetParentEffect,
+            #[cfg(feature = "Win32_Graphics_Imaging")]
+            SetInputSource,
+            #[cfg(not(feature = "Win32_Graphics_Imaging"))]
+            SetInputSource: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMILBitmapEffect as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMILBitmapEffectConnections_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInputConnector(this: *mut core::ffi::c_void, uiindex: u32, ppconnector: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectConnections", "GetInputConnector", 3usize, |this| ((**(this as *const *const IMILBitmapEffectConnections_Vtbl)).GetInputConnector)(this, uiindex, ppconnector), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputConnector(this: *mut core::ffi::c_void, uiindex: u32, ppconnector: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectConnections", "GetOutputConnector", 4usize, |this| ((**(this as *const *const IMILBitmapEffectConnections_Vtbl)).GetOutputConnector)(this, uiindex, ppconnector), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetInputConnector, GetOutputConnector }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMILBitmapEffectConnections as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMILBitmapEffectConnectionsInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNumberInputs(this: *mut core::ffi::c_void, puinuminputs: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectConnectionsInfo", "GetNumberInputs", 3usize, |this| ((**(this as *const *const IMILBitmapEffectConnectionsInfo_Vtbl)).GetNumberInputs)(this, puinuminputs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumberOutputs(this: *mut core::ffi::c_void, puinumoutputs: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectConnectionsInfo", "GetNumberOutputs", 4usize, |this| ((**(this as *const *const IMILBitmapEffectConnectionsInfo_Vtbl)).GetNumberOutputs)(this, puinumoutputs), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputConnectorInfo(this: *mut core::ffi::c_void, uiindex: u32, ppconnectorinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectConnectionsInfo", "GetInputConnectorInfo", 5usize, |this| ((**(this as *const *const IMILBitmapEffectConnectionsInfo_Vtbl)).GetInputConnectorInfo)(this, uiindex, ppconnectorinfo), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputConnectorInfo(this: *mut core::ffi::c_void, uiindex: u32, ppconnectorinfo: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectConnectionsInfo", "GetOutputConnectorInfo", 6usize, |this| ((**(this as *const *const IMILBitmapEffectConnectionsInfo_Vtbl)).GetOutputConnectorInfo)(this, uiindex, ppconnectorinfo), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetNumberInputs,
+            GetNumberOutputs,
+            GetInputConnectorInfo,
+            GetOutputConnectorInfo,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMILBitmapEffectConnectionsInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMILBitmapEffectConnector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsConnected(this: *mut core::ffi::c_void, pfconnected: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectConnector", "IsConnected", 7usize, |this| ((**(this as *const *const IMILBitmapEffectConnector_Vtbl)).IsConnected)(this, pfconnected), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBitmapEffect(this: *mut core::ffi::c_void, ppeffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectConnector", "GetBitmapEffect", 8usize, |this| ((**(this as *const *const IMILBitmapEffectConnector_Vtbl)).GetBitmapEffect)(this, ppeffect), |result| Some(*result))
+        }
+        Self { base__: <IMILBitmapEffectConnectorInfo_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsConnected, GetBitmapEffect }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMILBitmapEffectConnector as windows_core::Interface>::IID || <IMILBitmapEffectConnectorInfo_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMILBitmapEffectConnectorInfo_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetIndex(this: *mut core::ffi::c_void, puiindex: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectConnectorInfo", "GetIndex", 3usize, |this| ((**(this as *const *const IMILBitmapEffectConnectorInfo_Vtbl)).GetIndex)(this, puiindex), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOptimalFormat(this: *mut core::ffi::c_void, pformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectConnectorInfo", "GetOptimalFormat", 4usize, |this| ((**(this as *const *const IMILBitmapEffectConnectorInfo_Vtbl)).GetOptimalFormat)(this, pformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumberFormats(this: *mut core::ffi::c_void, pulnumberformats: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectConnectorInfo", "GetNumberFormats", 5usize, |this| ((**(this as *const *const IMILBitmapEffectConnectorInfo_Vtbl)).GetNumberFormats)(this, pulnumberformats), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFormat(this: *mut core::ffi::c_void, ulindex: u32, pformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectConnectorInfo", "GetFormat", 6usize, |this| ((**(this as *const *const IMILBitmapEffectConnectorInfo_Vtbl)).GetFormat)(this, ulindex, pformat), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetIndex, GetOptimalFormat, GetNumberFormats, GetFormat }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMILBitmapEffectConnectorInfo as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMILBitmapEffectEvents_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn PropertyChange(this: *mut core::ffi::c_void, peffect: *mut core::ffi::c_void, bstrpropertyname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectEvents", "PropertyChange", 3usize, |this| ((**(this as *const *const IMILBitmapEffectEvents_Vtbl)).PropertyChange)(this, peffect, bstrpropertyname), |result| Some(*result))
+        }
+        unsafe extern "system" fn DirtyRegion(this: *mut core::ffi::c_void, peffect: *mut core::ffi::c_void, prect: *const MilRectD) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectEvents", "DirtyRegion", 4usize, |this| ((**(this as *const *const IMILBitmapEffectEvents_Vtbl)).DirtyRegion)(this, peffect, prect), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, PropertyChange, DirtyRegion }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMILBitmapEffectEvents as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMILBitmapEffectFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateEffect(this: *mut core::ffi::c_void, pguideffect: *const windows_core::GUID, ppeffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectFactory", "CreateEffect", 3usize, |this| ((**(this as *const *const IMILBitmapEffectFactory_Vtbl)).CreateEffect)(this, pguideffect, ppeffect), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateContext(this: *mut core::ffi::c_void, ppcontext: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectFactory", "CreateContext", 4usize, |this| ((**(this as *const *const IMILBitmapEffectFactory_Vtbl)).CreateContext)(this, ppcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn CreateEffectOuter(this: *mut core::ffi::c_void, ppeffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectFactory", "CreateEffectOuter", 5usize, |this| ((**(this as *const *const IMILBitmapEffectFactory_Vtbl)).CreateEffectOuter)(this, ppeffect), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateEffect, CreateContext, CreateEffectOuter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMILBitmapEffectFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMILBitmapEffectGroup_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInteriorInputConnector(this: *mut core::ffi::c_void, uiindex: u32, ppconnector: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectGroup", "GetInteriorInputConnector", 3usize, |this| ((**(this as *const *const IMILBitmapEffectGroup_Vtbl)).GetInteriorInputConnector)(this, uiindex, ppconnector), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInteriorOutputConnector(this: *mut core::ffi::c_void, uiindex: u32, ppconnector: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectGroup", "GetInteriorOutputConnector", 4usize, |this| ((**(this as *const *const IMILBitmapEffectGroup_Vtbl)).GetInteriorOutputConnector)(this, uiindex, ppconnector), |result| Some(*result))
+        }
+        unsafe extern "system" fn Add(this: *mut core::ffi::c_void, peffect: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectGroup", "Add", 5usize, |this| ((**(this as *const *const IMILBitmapEffectGroup_Vtbl)).Add)(this, peffect), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetInteriorInputConnector, GetInteriorOutputConnector, Add }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMILBitmapEffectGroup as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMILBitmapEffectGroupImpl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Preprocess(this: *mut core::ffi::c_void, pcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectGroupImpl", "Preprocess", 3usize, |this| ((**(this as *const *const IMILBitmapEffectGroupImpl_Vtbl)).Preprocess)(this, pcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNumberChildren(this: *mut core::ffi::c_void, puinumberchildren: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectGroupImpl", "GetNumberChildren", 4usize, |this| ((**(this as *const *const IMILBitmapEffectGroupImpl_Vtbl)).GetNumberChildren)(this, puinumberchildren), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetChildren(this: *mut core::ffi::c_void, pchildren: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectGroupImpl", "GetChildren", 5usize, |this| ((**(this as *const *const IMILBitmapEffectGroupImpl_Vtbl)).GetChildren)(this, pchildren), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Preprocess, GetNumberChildren, GetChildren }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMILBitmapEffectGroupImpl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Imaging")]
+impl windows_core::imp::ProxyVtable for IMILBitmapEffectImpl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsInPlaceModificationAllowed(this: *mut core::ffi::c_void, poutputconnector: *mut core::ffi::c_void, pfmodifyinplace: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectImpl", "IsInPlaceModificationAllowed", 3usize, |this| ((**(this as *const *const IMILBitmapEffectImpl_Vtbl)).IsInPlaceModificationAllowed)(this, poutputconnector, pfmodifyinplace), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetParentEffect(this: *mut core::ffi::c_void, pparenteffect: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectImpl", "SetParentEffect", 4usize, |this| ((**(this as *const *const IMILBitmapEffectImpl_Vtbl)).SetParentEffect)(this, pparenteffect), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Imaging")]
+        unsafe extern "system" fn GetInputSource(this: *mut core::ffi::c_void, uiindex: u32, ppbitmapsource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectImpl", "GetInputSource", 5usize, |this| ((**(this as *const *const IMILBitmapEffectImpl_Vtbl)).GetInputSource)(this, uiindex, ppbitmapsource), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInputSourceBounds(this: *mut core::ffi::c_void, uiindex: u32, prect: *mut MilRectD) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectImpl", "GetInputSourceBounds", 6usize, |this| ((**(this as *const *const IMILBitmapEffectImpl_Vtbl)).GetInputSourceBounds)(this, uiindex, prect), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Imaging")]
+        unsafe extern "system" fn GetInputBitmapSource(this: *mut core::ffi::c_void, uiindex: u32, prendercontext: *mut core::ffi::c_void, pfmodifyinplace: *mut super::super::Foundation::VARIANT_BOOL, ppbitmapsource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectImpl", "GetInputBitmapSource", 7usize, |this| ((**(this as *const *const IMILBitmapEffectImpl_Vtbl)).GetInputBitmapSource)(this, uiindex, prendercontext, pfmodifyinplace, ppbitmapsource), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Imaging")]
+        unsafe extern "system" fn GetOutputBitmapSource(this: *mut core::ffi::c_void, uiindex: u32, prendercontext: *mut core::ffi::c_void, pfmodifyinplace: *mut super::super::Foundation::VARIANT_BOOL, ppbitmapsource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectImpl", "GetOutputBitmapSource", 8usize, |this| ((**(this as *const *const IMILBitmapEffectImpl_Vtbl)).GetOutputBitmapSource)(this, uiindex, prendercontext, pfmodifyinplace, ppbitmapsource), |result| Some(*result))
+        }
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, pinner: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectImpl", "Initialize", 9usize, |this| ((**(this as *const *const IMILBitmapEffectImpl_Vtbl)).Initialize)(this, pinner), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            IsInPlaceModificationAllowed,
+            SetParentEffect,
+            #[cfg(feature = "Win32_Graphics_Imaging")]
+            GetInputSource,
+            #[cfg(not(feature = "Win32_Graphics_Imaging"))]
+            GetInputSource: 0,
+            GetInputSourceBounds,
+            #[cfg(feature = "Win32_Graphics_Imaging")]
+            GetInputBitmapSource,
+            #[cfg(not(feature = "Win32_Graphics_Imaging"))]
+            GetInputBitmapSource: 0,
+            #[cfg(feature = "Win32_Graphics_Imaging")]
+            GetOutputBitmapSource,
+            #[cfg(not(feature = "Win32_Graphics_Imaging"))]
+            GetOutputBitmapSource: 0,
+            Initialize,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMILBitmapEffectImpl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMILBitmapEffectInputConnector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ConnectTo(this: *mut core::ffi::c_void, pconnector: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectInputConnector", "ConnectTo", 9usize, |this| ((**(this as *const *const IMILBitmapEffectInputConnector_Vtbl)).ConnectTo)(this, pconnector), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConnection(this: *mut core::ffi::c_void, ppconnector: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectInputConnector", "GetConnection", 10usize, |this| ((**(this as *const *const IMILBitmapEffectInputConnector_Vtbl)).GetConnection)(this, ppconnector), |result| Some(*result))
+        }
+        Self { base__: <IMILBitmapEffectConnector_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ConnectTo, GetConnection }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMILBitmapEffectInputConnector as windows_core::Interface>::IID || <IMILBitmapEffectConnector_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMILBitmapEffectInteriorInputConnector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetInputConnector(this: *mut core::ffi::c_void, pinputconnector: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectInteriorInputConnector", "GetInputConnector", 3usize, |this| ((**(this as *const *const IMILBitmapEffectInteriorInputConnector_Vtbl)).GetInputConnector)(this, pinputconnector), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetInputConnector }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMILBitmapEffectInteriorInputConnector as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMILBitmapEffectInteriorOutputConnector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOutputConnector(this: *mut core::ffi::c_void, poutputconnector: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectInteriorOutputConnector", "GetOutputConnector", 3usize, |this| ((**(this as *const *const IMILBitmapEffectInteriorOutputConnector_Vtbl)).GetOutputConnector)(this, poutputconnector), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetOutputConnector }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMILBitmapEffectInteriorOutputConnector as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMILBitmapEffectOutputConnector_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetNumberConnections(this: *mut core::ffi::c_void, puinumberconnections: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectOutputConnector", "GetNumberConnections", 9usize, |this| ((**(this as *const *const IMILBitmapEffectOutputConnector_Vtbl)).GetNumberConnections)(this, puinumberconnections), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetConnection(this: *mut core::ffi::c_void, uiindex: u32, ppconnection: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectOutputConnector", "GetConnection", 10usize, |this| ((**(this as *const *const IMILBitmapEffectOutputConnector_Vtbl)).GetConnection)(this, uiindex, ppconnection), |result| Some(*result))
+        }
+        Self { base__: <IMILBitmapEffectConnector_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetNumberConnections, GetConnection }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMILBitmapEffectOutputConnector as windows_core::Interface>::IID || <IMILBitmapEffectConnector_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMILBitmapEffectOutputConnectorImpl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddBackLink(this: *mut core::ffi::c_void, pconnection: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectOutputConnectorImpl", "AddBackLink", 3usize, |this| ((**(this as *const *const IMILBitmapEffectOutputConnectorImpl_Vtbl)).AddBackLink)(this, pconnection), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveBackLink(this: *mut core::ffi::c_void, pconnection: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectOutputConnectorImpl", "RemoveBackLink", 4usize, |this| ((**(this as *const *const IMILBitmapEffectOutputConnectorImpl_Vtbl)).RemoveBackLink)(this, pconnection), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddBackLink, RemoveBackLink }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMILBitmapEffectOutputConnectorImpl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Dwm", feature = "Win32_Graphics_Imaging"))]
+impl windows_core::imp::ProxyVtable for IMILBitmapEffectPrimitive_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Imaging")]
+        unsafe extern "system" fn GetOutput(this: *mut core::ffi::c_void, uiindex: u32, pcontext: *mut core::ffi::c_void, pfmodifyinplace: *mut super::super::Foundation::VARIANT_BOOL, ppbitmapsource: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectPrimitive", "GetOutput", 3usize, |this| ((**(this as *const *const IMILBitmapEffectPrimitive_Vtbl)).GetOutput)(this, uiindex, pcontext, pfmodifyinplace, ppbitmapsource), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransformPoint(this: *mut core::ffi::c_void, uiindex: u32, p: *mut MilPoint2D, fforwardtransform: super::super::Foundation::VARIANT_BOOL, pcontext: *mut core::ffi::c_void, pfpointtransformed: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectPrimitive", "TransformPoint", 4usize, |this| ((**(this as *const *const IMILBitmapEffectPrimitive_Vtbl)).TransformPoint)(this, uiindex, p, fforwardtransform, pcontext, pfpointtransformed), |result| Some(*result))
+        }
+        unsafe extern "system" fn TransformRect(this: *mut core::ffi::c_void, uiindex: u32, p: *mut MilRectD, fforwardtransform: super::super::Foundation::VARIANT_BOOL, pcontext: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectPrimitive", "TransformRect", 5usize, |this| ((**(this as *const *const IMILBitmapEffectPrimitive_Vtbl)).TransformRect)(this, uiindex, p, fforwardtransform, pcontext), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasAffineTransform(this: *mut core::ffi::c_void, uiindex: u32, pfaffine: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectPrimitive", "HasAffineTransform", 6usize, |this| ((**(this as *const *const IMILBitmapEffectPrimitive_Vtbl)).HasAffineTransform)(this, uiindex, pfaffine), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasInverseTransform(this: *mut core::ffi::c_void, uiindex: u32, pfhasinverse: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectPrimitive", "HasInverseTransform", 7usize, |this| ((**(this as *const *const IMILBitmapEffectPrimitive_Vtbl)).HasInverseTransform)(this, uiindex, pfhasinverse), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_Graphics_Dwm")]
+        unsafe extern "system" fn GetAffineMatrix(this: *mut core::ffi::c_void, uiindex: u32, pmatrix: *mut super::super::Graphics::Dwm::MilMatrix3x2D) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectPrimitive", "GetAffineMatrix", 8usize, |this| ((**(this as *const *const IMILBitmapEffectPrimitive_Vtbl)).GetAffineMatrix)(this, uiindex, pmatrix), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Imaging")]
+            GetOutput,
+            #[cfg(not(feature = "Win32_Graphics_Imaging"))]
+            GetOutput: 0,
+            TransformPoint,
+            TransformRect,
+            HasAffineTransform,
+            HasInverseTransform,
+            #[cfg(feature = "Win32_Graphics_Dwm")]
+            GetAffineMatrix,
+            #[cfg(not(feature = "Win32_Graphics_Dwm"))]
+            GetAffineMatrix: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMILBitmapEffectPrimitive as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMILBitmapEffectPrimitiveImpl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsDirty(this: *mut core::ffi::c_void, uioutputindex: u32, pfdirty: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectPrimitiveImpl", "IsDirty", 3usize, |this| ((**(this as *const *const IMILBitmapEffectPrimitiveImpl_Vtbl)).IsDirty)(this, uioutputindex, pfdirty), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsVolatile(this: *mut core::ffi::c_void, uioutputindex: u32, pfvolatile: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectPrimitiveImpl", "IsVolatile", 4usize, |this| ((**(this as *const *const IMILBitmapEffectPrimitiveImpl_Vtbl)).IsVolatile)(this, uioutputindex, pfvolatile), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsDirty, IsVolatile }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMILBitmapEffectPrimitiveImpl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMILBitmapEffectRenderContext_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetOutputPixelFormat(this: *mut core::ffi::c_void, format: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectRenderContext", "SetOutputPixelFormat", 3usize, |this| ((**(this as *const *const IMILBitmapEffectRenderContext_Vtbl)).SetOutputPixelFormat)(this, format), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputPixelFormat(this: *mut core::ffi::c_void, pformat: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectRenderContext", "GetOutputPixelFormat", 4usize, |this| ((**(this as *const *const IMILBitmapEffectRenderContext_Vtbl)).GetOutputPixelFormat)(this, pformat), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetUseSoftwareRenderer(this: *mut core::ffi::c_void, fsoftware: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectRenderContext", "SetUseSoftwareRenderer", 5usize, |this| ((**(this as *const *const IMILBitmapEffectRenderContext_Vtbl)).SetUseSoftwareRenderer)(this, fsoftware), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetInitialTransform(this: *mut core::ffi::c_void, pmatrix: *const MILMatrixF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectRenderContext", "SetInitialTransform", 6usize, |this| ((**(this as *const *const IMILBitmapEffectRenderContext_Vtbl)).SetInitialTransform)(this, pmatrix), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFinalTransform(this: *mut core::ffi::c_void, pmatrix: *mut MILMatrixF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectRenderContext", "GetFinalTransform", 7usize, |this| ((**(this as *const *const IMILBitmapEffectRenderContext_Vtbl)).GetFinalTransform)(this, pmatrix), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetOutputDPI(this: *mut core::ffi::c_void, dbldpix: f64, dbldpiy: f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectRenderContext", "SetOutputDPI", 8usize, |this| ((**(this as *const *const IMILBitmapEffectRenderContext_Vtbl)).SetOutputDPI)(this, dbldpix, dbldpiy), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputDPI(this: *mut core::ffi::c_void, pdbldpix: *mut f64, pdbldpiy: *mut f64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectRenderContext", "GetOutputDPI", 9usize, |this| ((**(this as *const *const IMILBitmapEffectRenderContext_Vtbl)).GetOutputDPI)(this, pdbldpix, pdbldpiy), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetRegionOfInterest(this: *mut core::ffi::c_void, prect: *const MilRectD) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectRenderContext", "SetRegionOfInterest", 10usize, |this| ((**(this as *const *const IMILBitmapEffectRenderContext_Vtbl)).SetRegionOfInterest)(this, prect), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetOutputPixelFormat,
+            GetOutputPixelFormat,
+            SetUseSoftwareRenderer,
+            SetInitialTransform,
+            GetFinalTransform,
+            SetOutputDPI,
+            GetOutputDPI,
+            SetRegionOfInterest,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMILBitmapEffectRenderContext as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMILBitmapEffectRenderContextImpl_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetUseSoftwareRenderer(this: *mut core::ffi::c_void, pfsoftware: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectRenderContextImpl", "GetUseSoftwareRenderer", 3usize, |this| ((**(this as *const *const IMILBitmapEffectRenderContextImpl_Vtbl)).GetUseSoftwareRenderer)(this, pfsoftware), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTransform(this: *mut core::ffi::c_void, pmatrix: *mut MILMatrixF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectRenderContextImpl", "GetTransform", 4usize, |this| ((**(this as *const *const IMILBitmapEffectRenderContextImpl_Vtbl)).GetTransform)(this, pmatrix), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateTransform(this: *mut core::ffi::c_void, pmatrix: *const MILMatrixF) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectRenderContextImpl", "UpdateTransform", 5usize, |this| ((**(this as *const *const IMILBitmapEffectRenderContextImpl_Vtbl)).UpdateTransform)(this, pmatrix), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetOutputBounds(this: *mut core::ffi::c_void, prect: *mut MilRectD) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectRenderContextImpl", "GetOutputBounds", 6usize, |this| ((**(this as *const *const IMILBitmapEffectRenderContextImpl_Vtbl)).GetOutputBounds)(this, prect), |result| Some(*result))
+        }
+        unsafe extern "system" fn UpdateOutputBounds(this: *mut core::ffi::c_void, prect: *const MilRectD) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffectRenderContextImpl", "UpdateOutputBounds", 7usize, |this| ((**(this as *const *const IMILBitmapEffectRenderContextImpl_Vtbl)).UpdateOutputBounds)(this, prect), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetUseSoftwareRenderer,
+            GetTransform,
+            UpdateTransform,
+            GetOutputBounds,
+            UpdateOutputBounds,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMILBitmapEffectRenderContextImpl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMILBitmapEffects_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn _NewEnum(this: *mut core::ffi::c_void, ppiureturn: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffects", "_NewEnum", 3usize, |this| ((**(this as *const *const IMILBitmapEffects_Vtbl))._NewEnum)(this, ppiureturn), |result| Some(*result))
+        }
+        unsafe extern "system" fn Parent(this: *mut core::ffi::c_void, ppeffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffects", "Parent", 4usize, |this| ((**(this as *const *const IMILBitmapEffects_Vtbl)).Parent)(this, ppeffect), |result| Some(*result))
+        }
+        unsafe extern "system" fn Item(this: *mut core::ffi::c_void, uindex: u32, ppeffect: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffects", "Item", 5usize, |this| ((**(this as *const *const IMILBitmapEffects_Vtbl)).Item)(this, uindex, ppeffect), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, puicount: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMILBitmapEffects", "Count", 6usize, |this| ((**(this as *const *const IMILBitmapEffects_Vtbl)).Count)(this, puicount), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, _NewEnum, Parent, Item, Count }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMILBitmapEffects as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -3702,6 +3702,7 @@ pub struct ADDURL_FLAG(pub i32);
 impl windows_core::TypeKind for ADDURL_FLAG {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ADDURL_FLAG {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ADDURL_FLAG").field(&self.0).finish()
@@ -3713,6 +3714,7 @@ pub struct ExtensionValidationContexts(pub i32);
 impl windows_core::TypeKind for ExtensionValidationContexts {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ExtensionValidationContexts {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ExtensionValidationContexts").field(&self.0).finish()
@@ -3724,6 +3726,7 @@ pub struct ExtensionValidationResults(pub i32);
 impl windows_core::TypeKind for ExtensionValidationResults {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for ExtensionValidationResults {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("ExtensionValidationResults").field(&self.0).finish()
@@ -3735,6 +3738,7 @@ pub struct FINDFRAME_FLAGS(pub i32);
 impl windows_core::TypeKind for FINDFRAME_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FINDFRAME_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FINDFRAME_FLAGS").field(&self.0).finish()
@@ -3746,6 +3750,7 @@ pub struct FRAMEOPTIONS_FLAGS(pub i32);
 impl windows_core::TypeKind for FRAMEOPTIONS_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for FRAMEOPTIONS_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("FRAMEOPTIONS_FLAGS").field(&self.0).finish()
@@ -3757,6 +3762,7 @@ pub struct IELAUNCHOPTION_FLAGS(pub i32);
 impl windows_core::TypeKind for IELAUNCHOPTION_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for IELAUNCHOPTION_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("IELAUNCHOPTION_FLAGS").field(&self.0).finish()
@@ -3768,6 +3774,7 @@ pub struct INTERNETEXPLORERCONFIGURATION(pub i32);
 impl windows_core::TypeKind for INTERNETEXPLORERCONFIGURATION {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for INTERNETEXPLORERCONFIGURATION {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("INTERNETEXPLORERCONFIGURATION").field(&self.0).finish()
@@ -3779,6 +3786,7 @@ pub struct MEDIA_ACTIVITY_NOTIFY_TYPE(pub i32);
 impl windows_core::TypeKind for MEDIA_ACTIVITY_NOTIFY_TYPE {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for MEDIA_ACTIVITY_NOTIFY_TYPE {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("MEDIA_ACTIVITY_NOTIFY_TYPE").field(&self.0).finish()
@@ -3790,6 +3798,7 @@ pub struct NAVIGATEFRAME_FLAGS(pub i32);
 impl windows_core::TypeKind for NAVIGATEFRAME_FLAGS {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for NAVIGATEFRAME_FLAGS {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("NAVIGATEFRAME_FLAGS").field(&self.0).finish()
@@ -3801,6 +3810,7 @@ pub struct OpenServiceActivityContentType(pub i32);
 impl windows_core::TypeKind for OpenServiceActivityContentType {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OpenServiceActivityContentType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OpenServiceActivityContentType").field(&self.0).finish()
@@ -3812,6 +3822,7 @@ pub struct OpenServiceErrors(pub i32);
 impl windows_core::TypeKind for OpenServiceErrors {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for OpenServiceErrors {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("OpenServiceErrors").field(&self.0).finish()
@@ -3823,6 +3834,7 @@ pub struct SCROLLABLECONTEXTMENU_PLACEMENT(pub i32);
 impl windows_core::TypeKind for SCROLLABLECONTEXTMENU_PLACEMENT {
     type TypeKind = windows_core::CopyType;
 }
+#[cfg(not(windows_no_debug))]
 impl core::fmt::Debug for SCROLLABLECONTEXTMENU_PLACEMENT {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_tuple("SCROLLABLECONTEXTMENU_PLACEMENT").field(&self.0).finish()
@@ -3844,7 +3856,8 @@ pub const CoSniffStream: windows_core::GUID = windows_core::GUID::from_u128(0x6a
 pub const HomePage: windows_core::GUID = windows_core::GUID::from_u128(0x766bf2ae_d650_11d1_9811_00c04fc31d2e);
 pub const HomePageSetting: windows_core::GUID = windows_core::GUID::from_u128(0x374cede0_873a_4c4f_bc86_bcc8cf5116a3);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct IELAUNCHURLINFO {
     pub cbSize: u32,
     pub dwCreationFlags: u32,
@@ -3862,7 +3875,8 @@ pub const IEWebDriverManager: windows_core::GUID = windows_core::GUID::from_u128
 pub const IntelliForms: windows_core::GUID = windows_core::GUID::from_u128(0x613ab92e_16bf_11d2_bca5_00c04fd929db);
 pub const InternetExplorerManager: windows_core::GUID = windows_core::GUID::from_u128(0xdf4fcc34_067a_4e0a_8352_4a1a5095346e);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct NAVIGATEDATA {
     pub ulTarget: u32,
     pub ulURL: u32,
@@ -3882,7 +3896,8 @@ pub const OpenServiceActivityManager: windows_core::GUID = windows_core::GUID::f
 pub const OpenServiceManager: windows_core::GUID = windows_core::GUID::from_u128(0x098870b6_39ea_480b_b8b5_dd0167c4db59);
 pub const PeerFactory: windows_core::GUID = windows_core::GUID::from_u128(0x3050f4cf_98b5_11cf_bb82_00aa00bdce0b);
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
 pub struct STATURL {
     pub cbSize: u32,
     pub pwcsUrl: windows_core::PWSTR,
@@ -3903,3 +3918,5 @@ impl Default for STATURL {
 pub const wfolders: windows_core::GUID = windows_core::GUID::from_u128(0xbae31f9a_1b81_11d2_a97a_00c04f8ecb02);
 #[cfg(feature = "implement")]
 core::include!("impl.rs");
+#[cfg(feature = "proxy")]
+core::include!("proxy.rs");
@@ -0,0 +1,1657 @@
+impl windows_core::imp::ProxyVtable for IActiveXUIHandlerSite_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateScrollableContextMenu(this: *mut core::ffi::c_void, scrollablecontextmenu: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveXUIHandlerSite", "CreateScrollableContextMenu", 3usize, |this| ((**(this as *const *const IActiveXUIHandlerSite_Vtbl)).CreateScrollableContextMenu)(this, scrollablecontextmenu), |result| Some(*result))
+        }
+        unsafe extern "system" fn PickFileAndGetResult(this: *mut core::ffi::c_void, filepicker: *mut core::ffi::c_void, allowmultipleselections: super::super::Foundation::BOOL, result: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveXUIHandlerSite", "PickFileAndGetResult", 4usize, |this| ((**(this as *const *const IActiveXUIHandlerSite_Vtbl)).PickFileAndGetResult)(this, filepicker, allowmultipleselections, result), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateScrollableContextMenu, PickFileAndGetResult }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveXUIHandlerSite as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveXUIHandlerSite2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddSuspensionExemption(this: *mut core::ffi::c_void, pullcookie: *mut u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveXUIHandlerSite2", "AddSuspensionExemption", 3usize, |this| ((**(this as *const *const IActiveXUIHandlerSite2_Vtbl)).AddSuspensionExemption)(this, pullcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoveSuspensionExemption(this: *mut core::ffi::c_void, ullcookie: u64) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveXUIHandlerSite2", "RemoveSuspensionExemption", 4usize, |this| ((**(this as *const *const IActiveXUIHandlerSite2_Vtbl)).RemoveSuspensionExemption)(this, ullcookie), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddSuspensionExemption, RemoveSuspensionExemption }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveXUIHandlerSite2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IActiveXUIHandlerSite3_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn MessageBoxW(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, text: windows_core::PCWSTR, caption: windows_core::PCWSTR, r#type: u32, result: *mut i32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IActiveXUIHandlerSite3", "MessageBoxW", 3usize, |this| ((**(this as *const *const IActiveXUIHandlerSite3_Vtbl)).MessageBoxW)(this, hwnd, text, caption, r#type, result), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, MessageBoxW }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IActiveXUIHandlerSite3 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IAnchorClick_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ProcOnClick(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAnchorClick", "ProcOnClick", 7usize, |this| ((**(this as *const *const IAnchorClick_Vtbl)).ProcOnClick)(this), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ProcOnClick }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAnchorClick as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IAudioSessionSite_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetAudioSessionGuid(this: *mut core::ffi::c_void, audiosessionguid: *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionSite", "GetAudioSessionGuid", 3usize, |this| ((**(this as *const *const IAudioSessionSite_Vtbl)).GetAudioSessionGuid)(this, audiosessionguid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnAudioStreamCreated(this: *mut core::ffi::c_void, endpointid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionSite", "OnAudioStreamCreated", 4usize, |this| ((**(this as *const *const IAudioSessionSite_Vtbl)).OnAudioStreamCreated)(this, endpointid), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnAudioStreamDestroyed(this: *mut core::ffi::c_void, endpointid: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IAudioSessionSite", "OnAudioStreamDestroyed", 5usize, |this| ((**(this as *const *const IAudioSessionSite_Vtbl)).OnAudioStreamDestroyed)(this, endpointid), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetAudioSessionGuid,
+            OnAudioStreamCreated,
+            OnAudioStreamDestroyed,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IAudioSessionSite as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ICaretPositionProvider_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCaretPosition(this: *mut core::ffi::c_void, pptcaret: *mut super::super::Foundation::POINT, pflheight: *mut f32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ICaretPositionProvider", "GetCaretPosition", 3usize, |this| ((**(this as *const *const ICaretPositionProvider_Vtbl)).GetCaretPosition)(this, pptcaret, pflheight), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetCaretPosition }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ICaretPositionProvider as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDeviceRect_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDeviceRect as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl windows_core::imp::ProxyVtable for IDithererImpl_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Gdi")]
+        unsafe extern "system" fn SetDestColorTable(this: *mut core::ffi::c_void, ncolors: u32, prgbcolors: *const super::super::Graphics::Gdi::RGBQUAD) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDithererImpl", "SetDestColorTable", 3usize, |this| ((**(this as *const *const IDithererImpl_Vtbl)).SetDestColorTable)(this, ncolors, prgbcolors), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEventSink(this: *mut core::ffi::c_void, peventsink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDithererImpl", "SetEventSink", 4usize, |this| ((**(this as *const *const IDithererImpl_Vtbl)).SetEventSink)(this, peventsink), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Gdi")]
+            SetDestColorTable,
+            #[cfg(not(feature = "Win32_Graphics_Gdi"))]
+            SetDestColorTable: 0,
+            SetEventSink,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDithererImpl as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IDownloadBehavior_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn startDownload(this: *mut core::ffi::c_void, bstrurl: core::mem::MaybeUninit<windows_core::BSTR>, pdispcallback: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadBehavior", "startDownload", 7usize, |this| ((**(this as *const *const IDownloadBehavior_Vtbl)).startDownload)(this, bstrurl, pdispcallback), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            startDownload,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            startDownload: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDownloadBehavior as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Security", feature = "Win32_System_Com_StructuredStorage"))]
+impl windows_core::imp::ProxyVtable for IDownloadManager_Vtbl {
+    const PROXY: Self = {
+        #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Security", feature = "Win32_System_Com_StructuredStorage"))]
+        unsafe extern "system" fn Download(this: *mut core::ffi::c_void, pmk: *mut core::ffi::c_void, pbc: *mut core::ffi::c_void, dwbindverb: u32, grfbindf: i32, pbindinfo: *const super::super::System::Com::BINDINFO, pszheaders: windows_core::PCWSTR, pszredir: windows_core::PCWSTR, uicp: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IDownloadManager", "Download", 3usize, |this| ((**(this as *const *const IDownloadManager_Vtbl)).Download)(this, pmk, pbc, dwbindverb, grfbindf, pbindinfo, pszheaders, pszredir, uicp), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Security", feature = "Win32_System_Com_StructuredStorage"))]
+            Download,
+            #[cfg(not(all(feature = "Win32_Graphics_Gdi", feature = "Win32_Security", feature = "Win32_System_Com_StructuredStorage")))]
+            Download: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IDownloadManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumManagerFrames_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, ppwindows: *mut *mut super::super::Foundation::HWND, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumManagerFrames", "Next", 3usize, |this| ((**(this as *const *const IEnumManagerFrames_Vtbl)).Next)(this, celt, ppwindows, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Count(this: *mut core::ffi::c_void, pcelt: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumManagerFrames", "Count", 4usize, |this| ((**(this as *const *const IEnumManagerFrames_Vtbl)).Count)(this, pcelt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumManagerFrames", "Skip", 5usize, |this| ((**(this as *const *const IEnumManagerFrames_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumManagerFrames", "Reset", 6usize, |this| ((**(this as *const *const IEnumManagerFrames_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumManagerFrames", "Clone", 7usize, |this| ((**(this as *const *const IEnumManagerFrames_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Count, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumManagerFrames as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumOpenServiceActivity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumOpenServiceActivity", "Next", 3usize, |this| ((**(this as *const *const IEnumOpenServiceActivity_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumOpenServiceActivity", "Skip", 4usize, |this| ((**(this as *const *const IEnumOpenServiceActivity_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumOpenServiceActivity", "Reset", 5usize, |this| ((**(this as *const *const IEnumOpenServiceActivity_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumOpenServiceActivity", "Clone", 6usize, |this| ((**(this as *const *const IEnumOpenServiceActivity_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumOpenServiceActivity as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumOpenServiceActivityCategory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut *mut core::ffi::c_void, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumOpenServiceActivityCategory", "Next", 3usize, |this| ((**(this as *const *const IEnumOpenServiceActivityCategory_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumOpenServiceActivityCategory", "Skip", 4usize, |this| ((**(this as *const *const IEnumOpenServiceActivityCategory_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumOpenServiceActivityCategory", "Reset", 5usize, |this| ((**(this as *const *const IEnumOpenServiceActivityCategory_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumOpenServiceActivityCategory", "Clone", 6usize, |this| ((**(this as *const *const IEnumOpenServiceActivityCategory_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumOpenServiceActivityCategory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IEnumSTATURL_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Next(this: *mut core::ffi::c_void, celt: u32, rgelt: *mut STATURL, pceltfetched: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSTATURL", "Next", 3usize, |this| ((**(this as *const *const IEnumSTATURL_Vtbl)).Next)(this, celt, rgelt, pceltfetched), |result| Some(*result))
+        }
+        unsafe extern "system" fn Skip(this: *mut core::ffi::c_void, celt: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSTATURL", "Skip", 4usize, |this| ((**(this as *const *const IEnumSTATURL_Vtbl)).Skip)(this, celt), |result| Some(*result))
+        }
+        unsafe extern "system" fn Reset(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSTATURL", "Reset", 5usize, |this| ((**(this as *const *const IEnumSTATURL_Vtbl)).Reset)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn Clone(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSTATURL", "Clone", 6usize, |this| ((**(this as *const *const IEnumSTATURL_Vtbl)).Clone)(this, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFilter(this: *mut core::ffi::c_void, poszfilter: windows_core::PCWSTR, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IEnumSTATURL", "SetFilter", 7usize, |this| ((**(this as *const *const IEnumSTATURL_Vtbl)).SetFilter)(this, poszfilter, dwflags), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Next, Skip, Reset, Clone, SetFilter }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IEnumSTATURL as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHTMLPersistData_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn save(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void, ltype: i32, fcontinuebroacast: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHTMLPersistData", "save", 3usize, |this| ((**(this as *const *const IHTMLPersistData_Vtbl)).save)(this, punk, ltype, fcontinuebroacast), |result| Some(*result))
+        }
+        unsafe extern "system" fn load(this: *mut core::ffi::c_void, punk: *mut core::ffi::c_void, ltype: i32, fdodefault: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHTMLPersistData", "load", 4usize, |this| ((**(this as *const *const IHTMLPersistData_Vtbl)).load)(this, punk, ltype, fdodefault), |result| Some(*result))
+        }
+        unsafe extern "system" fn queryType(this: *mut core::ffi::c_void, ltype: i32, pfsupportstype: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHTMLPersistData", "queryType", 5usize, |this| ((**(this as *const *const IHTMLPersistData_Vtbl)).queryType)(this, ltype, pfsupportstype), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, save, load, queryType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHTMLPersistData as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IHTMLPersistDataOM_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn XMLDocument(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHTMLPersistDataOM", "XMLDocument", 7usize, |this| ((**(this as *const *const IHTMLPersistDataOM_Vtbl)).XMLDocument)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn getAttribute(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, pvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHTMLPersistDataOM", "getAttribute", 8usize, |this| ((**(this as *const *const IHTMLPersistDataOM_Vtbl)).getAttribute)(this, name, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn setAttribute(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, value: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHTMLPersistDataOM", "setAttribute", 9usize, |this| ((**(this as *const *const IHTMLPersistDataOM_Vtbl)).setAttribute)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn removeAttribute(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHTMLPersistDataOM", "removeAttribute", 10usize, |this| ((**(this as *const *const IHTMLPersistDataOM_Vtbl)).removeAttribute)(this, name), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            XMLDocument,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            XMLDocument: 0,
+            getAttribute,
+            setAttribute,
+            removeAttribute,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHTMLPersistDataOM as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IHTMLUserDataOM_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn XMLDocument(this: *mut core::ffi::c_void, p: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHTMLUserDataOM", "XMLDocument", 7usize, |this| ((**(this as *const *const IHTMLUserDataOM_Vtbl)).XMLDocument)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn save(this: *mut core::ffi::c_void, strname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHTMLUserDataOM", "save", 8usize, |this| ((**(this as *const *const IHTMLUserDataOM_Vtbl)).save)(this, strname), |result| Some(*result))
+        }
+        unsafe extern "system" fn load(this: *mut core::ffi::c_void, strname: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHTMLUserDataOM", "load", 9usize, |this| ((**(this as *const *const IHTMLUserDataOM_Vtbl)).load)(this, strname), |result| Some(*result))
+        }
+        unsafe extern "system" fn getAttribute(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, pvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHTMLUserDataOM", "getAttribute", 10usize, |this| ((**(this as *const *const IHTMLUserDataOM_Vtbl)).getAttribute)(this, name, pvalue), |result| Some(*result))
+        }
+        unsafe extern "system" fn setAttribute(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>, value: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHTMLUserDataOM", "setAttribute", 11usize, |this| ((**(this as *const *const IHTMLUserDataOM_Vtbl)).setAttribute)(this, name, value), |result| Some(*result))
+        }
+        unsafe extern "system" fn removeAttribute(this: *mut core::ffi::c_void, name: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHTMLUserDataOM", "removeAttribute", 12usize, |this| ((**(this as *const *const IHTMLUserDataOM_Vtbl)).removeAttribute)(this, name), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setexpires(this: *mut core::ffi::c_void, bstr: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHTMLUserDataOM", "Setexpires", 13usize, |this| ((**(this as *const *const IHTMLUserDataOM_Vtbl)).Setexpires)(this, bstr), |result| Some(*result))
+        }
+        unsafe extern "system" fn expires(this: *mut core::ffi::c_void, pbstr: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHTMLUserDataOM", "expires", 14usize, |this| ((**(this as *const *const IHTMLUserDataOM_Vtbl)).expires)(this, pbstr), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            XMLDocument,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            XMLDocument: 0,
+            save,
+            load,
+            getAttribute,
+            setAttribute,
+            removeAttribute,
+            Setexpires,
+            expires,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHTMLUserDataOM as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IHeaderFooter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn htmlHead(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderFooter", "htmlHead", 7usize, |this| ((**(this as *const *const IHeaderFooter_Vtbl)).htmlHead)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn htmlFoot(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderFooter", "htmlFoot", 8usize, |this| ((**(this as *const *const IHeaderFooter_Vtbl)).htmlFoot)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn SettextHead(this: *mut core::ffi::c_void, v: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderFooter", "SettextHead", 9usize, |this| ((**(this as *const *const IHeaderFooter_Vtbl)).SettextHead)(this, v), |result| Some(*result))
+        }
+        unsafe extern "system" fn textHead(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderFooter", "textHead", 10usize, |this| ((**(this as *const *const IHeaderFooter_Vtbl)).textHead)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn SettextFoot(this: *mut core::ffi::c_void, v: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderFooter", "SettextFoot", 11usize, |this| ((**(this as *const *const IHeaderFooter_Vtbl)).SettextFoot)(this, v), |result| Some(*result))
+        }
+        unsafe extern "system" fn textFoot(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderFooter", "textFoot", 12usize, |this| ((**(this as *const *const IHeaderFooter_Vtbl)).textFoot)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setpage(this: *mut core::ffi::c_void, v: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderFooter", "Setpage", 13usize, |this| ((**(this as *const *const IHeaderFooter_Vtbl)).Setpage)(this, v), |result| Some(*result))
+        }
+        unsafe extern "system" fn page(this: *mut core::ffi::c_void, p: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderFooter", "page", 14usize, |this| ((**(this as *const *const IHeaderFooter_Vtbl)).page)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetpageTotal(this: *mut core::ffi::c_void, v: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderFooter", "SetpageTotal", 15usize, |this| ((**(this as *const *const IHeaderFooter_Vtbl)).SetpageTotal)(this, v), |result| Some(*result))
+        }
+        unsafe extern "system" fn pageTotal(this: *mut core::ffi::c_void, p: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderFooter", "pageTotal", 16usize, |this| ((**(this as *const *const IHeaderFooter_Vtbl)).pageTotal)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetURL(this: *mut core::ffi::c_void, v: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderFooter", "SetURL", 17usize, |this| ((**(this as *const *const IHeaderFooter_Vtbl)).SetURL)(this, v), |result| Some(*result))
+        }
+        unsafe extern "system" fn URL(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderFooter", "URL", 18usize, |this| ((**(this as *const *const IHeaderFooter_Vtbl)).URL)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn Settitle(this: *mut core::ffi::c_void, v: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderFooter", "Settitle", 19usize, |this| ((**(this as *const *const IHeaderFooter_Vtbl)).Settitle)(this, v), |result| Some(*result))
+        }
+        unsafe extern "system" fn title(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderFooter", "title", 20usize, |this| ((**(this as *const *const IHeaderFooter_Vtbl)).title)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetdateShort(this: *mut core::ffi::c_void, v: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderFooter", "SetdateShort", 21usize, |this| ((**(this as *const *const IHeaderFooter_Vtbl)).SetdateShort)(this, v), |result| Some(*result))
+        }
+        unsafe extern "system" fn dateShort(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderFooter", "dateShort", 22usize, |this| ((**(this as *const *const IHeaderFooter_Vtbl)).dateShort)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetdateLong(this: *mut core::ffi::c_void, v: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderFooter", "SetdateLong", 23usize, |this| ((**(this as *const *const IHeaderFooter_Vtbl)).SetdateLong)(this, v), |result| Some(*result))
+        }
+        unsafe extern "system" fn dateLong(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderFooter", "dateLong", 24usize, |this| ((**(this as *const *const IHeaderFooter_Vtbl)).dateLong)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn SettimeShort(this: *mut core::ffi::c_void, v: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderFooter", "SettimeShort", 25usize, |this| ((**(this as *const *const IHeaderFooter_Vtbl)).SettimeShort)(this, v), |result| Some(*result))
+        }
+        unsafe extern "system" fn timeShort(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderFooter", "timeShort", 26usize, |this| ((**(this as *const *const IHeaderFooter_Vtbl)).timeShort)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn SettimeLong(this: *mut core::ffi::c_void, v: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderFooter", "SettimeLong", 27usize, |this| ((**(this as *const *const IHeaderFooter_Vtbl)).SettimeLong)(this, v), |result| Some(*result))
+        }
+        unsafe extern "system" fn timeLong(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderFooter", "timeLong", 28usize, |this| ((**(this as *const *const IHeaderFooter_Vtbl)).timeLong)(this, p), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            htmlHead,
+            htmlFoot,
+            SettextHead,
+            textHead,
+            SettextFoot,
+            textFoot,
+            Setpage,
+            page,
+            SetpageTotal,
+            pageTotal,
+            SetURL,
+            URL,
+            Settitle,
+            title,
+            SetdateShort,
+            dateShort,
+            SetdateLong,
+            dateLong,
+            SettimeShort,
+            timeShort,
+            SettimeLong,
+            timeLong,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHeaderFooter as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IHeaderFooter2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Setfont(this: *mut core::ffi::c_void, v: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderFooter2", "Setfont", 29usize, |this| ((**(this as *const *const IHeaderFooter2_Vtbl)).Setfont)(this, v), |result| Some(*result))
+        }
+        unsafe extern "system" fn font(this: *mut core::ffi::c_void, p: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHeaderFooter2", "font", 30usize, |this| ((**(this as *const *const IHeaderFooter2_Vtbl)).font)(this, p), |result| Some(*result))
+        }
+        Self { base__: <IHeaderFooter_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Setfont, font }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHeaderFooter2 as windows_core::Interface>::IID || <IHeaderFooter_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IHomePage_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn navigateHomePage(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHomePage", "navigateHomePage", 7usize, |this| ((**(this as *const *const IHomePage_Vtbl)).navigateHomePage)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn setHomePage(this: *mut core::ffi::c_void, bstrurl: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHomePage", "setHomePage", 8usize, |this| ((**(this as *const *const IHomePage_Vtbl)).setHomePage)(this, bstrurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn isHomePage(this: *mut core::ffi::c_void, bstrurl: core::mem::MaybeUninit<windows_core::BSTR>, p: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHomePage", "isHomePage", 9usize, |this| ((**(this as *const *const IHomePage_Vtbl)).isHomePage)(this, bstrurl, p), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, navigateHomePage, setHomePage, isHomePage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHomePage as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IHomePageSetting_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetHomePage(this: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND, homepageuri: windows_core::PCWSTR, brandingmessage: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHomePageSetting", "SetHomePage", 3usize, |this| ((**(this as *const *const IHomePageSetting_Vtbl)).SetHomePage)(this, hwnd, homepageuri, brandingmessage), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsHomePage(this: *mut core::ffi::c_void, uri: windows_core::PCWSTR, isdefault: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHomePageSetting", "IsHomePage", 4usize, |this| ((**(this as *const *const IHomePageSetting_Vtbl)).IsHomePage)(this, uri, isdefault), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetHomePageToBrowserDefault(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IHomePageSetting", "SetHomePageToBrowserDefault", 5usize, |this| ((**(this as *const *const IHomePageSetting_Vtbl)).SetHomePageToBrowserDefault)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetHomePage, IsHomePage, SetHomePageToBrowserDefault }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IHomePageSetting as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IIEWebDriverManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn ExecuteCommand(this: *mut core::ffi::c_void, command: windows_core::PCWSTR, response: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIEWebDriverManager", "ExecuteCommand", 7usize, |this| ((**(this as *const *const IIEWebDriverManager_Vtbl)).ExecuteCommand)(this, command, response), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, ExecuteCommand }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIEWebDriverManager as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IIEWebDriverSite_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn WindowOperation(this: *mut core::ffi::c_void, operationcode: u32, hwnd: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIEWebDriverSite", "WindowOperation", 7usize, |this| ((**(this as *const *const IIEWebDriverSite_Vtbl)).WindowOperation)(this, operationcode, hwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn DetachWebdriver(this: *mut core::ffi::c_void, punkwd: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIEWebDriverSite", "DetachWebdriver", 8usize, |this| ((**(this as *const *const IIEWebDriverSite_Vtbl)).DetachWebdriver)(this, punkwd), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCapabilityValue(this: *mut core::ffi::c_void, punkwd: *mut core::ffi::c_void, capname: windows_core::PCWSTR, capvalue: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIEWebDriverSite", "GetCapabilityValue", 9usize, |this| ((**(this as *const *const IIEWebDriverSite_Vtbl)).GetCapabilityValue)(this, punkwd, capname, capvalue), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            WindowOperation,
+            DetachWebdriver,
+            GetCapabilityValue,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIEWebDriverSite as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IImageDecodeEventSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetSurface(this: *mut core::ffi::c_void, nwidth: i32, nheight: i32, bfid: *const windows_core::GUID, npasses: u32, dwhints: u32, ppsurface: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageDecodeEventSink", "GetSurface", 3usize, |this| ((**(this as *const *const IImageDecodeEventSink_Vtbl)).GetSurface)(this, nwidth, nheight, bfid, npasses, dwhints, ppsurface), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnBeginDecode(this: *mut core::ffi::c_void, pdwevents: *mut u32, pnformats: *mut u32, ppformats: *mut *mut windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageDecodeEventSink", "OnBeginDecode", 4usize, |this| ((**(this as *const *const IImageDecodeEventSink_Vtbl)).OnBeginDecode)(this, pdwevents, pnformats, ppformats), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnBitsComplete(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageDecodeEventSink", "OnBitsComplete", 5usize, |this| ((**(this as *const *const IImageDecodeEventSink_Vtbl)).OnBitsComplete)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnDecodeComplete(this: *mut core::ffi::c_void, hrstatus: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageDecodeEventSink", "OnDecodeComplete", 6usize, |this| ((**(this as *const *const IImageDecodeEventSink_Vtbl)).OnDecodeComplete)(this, hrstatus), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnPalette(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageDecodeEventSink", "OnPalette", 7usize, |this| ((**(this as *const *const IImageDecodeEventSink_Vtbl)).OnPalette)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnProgress(this: *mut core::ffi::c_void, pbounds: *const super::super::Foundation::RECT, bcomplete: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageDecodeEventSink", "OnProgress", 8usize, |this| ((**(this as *const *const IImageDecodeEventSink_Vtbl)).OnProgress)(this, pbounds, bcomplete), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetSurface,
+            OnBeginDecode,
+            OnBitsComplete,
+            OnDecodeComplete,
+            OnPalette,
+            OnProgress,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImageDecodeEventSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IImageDecodeEventSink2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsAlphaPremultRequired(this: *mut core::ffi::c_void, pfpremultalpha: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageDecodeEventSink2", "IsAlphaPremultRequired", 9usize, |this| ((**(this as *const *const IImageDecodeEventSink2_Vtbl)).IsAlphaPremultRequired)(this, pfpremultalpha), |result| Some(*result))
+        }
+        Self { base__: <IImageDecodeEventSink_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsAlphaPremultRequired }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImageDecodeEventSink2 as windows_core::Interface>::IID || <IImageDecodeEventSink_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IImageDecodeFilter_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Initialize(this: *mut core::ffi::c_void, peventsink: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageDecodeFilter", "Initialize", 3usize, |this| ((**(this as *const *const IImageDecodeFilter_Vtbl)).Initialize)(this, peventsink), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Process(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageDecodeFilter", "Process", 4usize, |this| ((**(this as *const *const IImageDecodeFilter_Vtbl)).Process)(this, pstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn Terminate(this: *mut core::ffi::c_void, hrstatus: windows_core::HRESULT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IImageDecodeFilter", "Terminate", 5usize, |this| ((**(this as *const *const IImageDecodeFilter_Vtbl)).Terminate)(this, hrstatus), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Initialize,
+            #[cfg(feature = "Win32_System_Com")]
+            Process,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Process: 0,
+            Terminate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IImageDecodeFilter as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IIntelliForms_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn enabled(this: *mut core::ffi::c_void, pval: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIntelliForms", "enabled", 7usize, |this| ((**(this as *const *const IIntelliForms_Vtbl)).enabled)(this, pval), |result| Some(*result))
+        }
+        unsafe extern "system" fn Setenabled(this: *mut core::ffi::c_void, bval: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IIntelliForms", "Setenabled", 8usize, |this| ((**(this as *const *const IIntelliForms_Vtbl)).Setenabled)(this, bval), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, enabled, Setenabled }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IIntelliForms as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInternetExplorerManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateObject(this: *mut core::ffi::c_void, dwconfig: u32, pszurl: windows_core::PCWSTR, riid: *const windows_core::GUID, ppv: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetExplorerManager", "CreateObject", 3usize, |this| ((**(this as *const *const IInternetExplorerManager_Vtbl)).CreateObject)(this, dwconfig, pszurl, riid, ppv), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateObject }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInternetExplorerManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IInternetExplorerManager2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnumFrameWindows(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IInternetExplorerManager2", "EnumFrameWindows", 3usize, |this| ((**(this as *const *const IInternetExplorerManager2_Vtbl)).EnumFrameWindows)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnumFrameWindows }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IInternetExplorerManager2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ILayoutRect_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetnextRect(this: *mut core::ffi::c_void, bstrelementid: core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILayoutRect", "SetnextRect", 7usize, |this| ((**(this as *const *const ILayoutRect_Vtbl)).SetnextRect)(this, bstrelementid), |result| Some(*result))
+        }
+        unsafe extern "system" fn nextRect(this: *mut core::ffi::c_void, pbstrelementid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILayoutRect", "nextRect", 8usize, |this| ((**(this as *const *const ILayoutRect_Vtbl)).nextRect)(this, pbstrelementid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetcontentSrc(this: *mut core::ffi::c_void, varcontentsrc: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILayoutRect", "SetcontentSrc", 9usize, |this| ((**(this as *const *const ILayoutRect_Vtbl)).SetcontentSrc)(this, varcontentsrc), |result| Some(*result))
+        }
+        unsafe extern "system" fn contentSrc(this: *mut core::ffi::c_void, pvarcontentsrc: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILayoutRect", "contentSrc", 10usize, |this| ((**(this as *const *const ILayoutRect_Vtbl)).contentSrc)(this, pvarcontentsrc), |result| Some(*result))
+        }
+        unsafe extern "system" fn SethonorPageBreaks(this: *mut core::ffi::c_void, v: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILayoutRect", "SethonorPageBreaks", 11usize, |this| ((**(this as *const *const ILayoutRect_Vtbl)).SethonorPageBreaks)(this, v), |result| Some(*result))
+        }
+        unsafe extern "system" fn honorPageBreaks(this: *mut core::ffi::c_void, p: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILayoutRect", "honorPageBreaks", 12usize, |this| ((**(this as *const *const ILayoutRect_Vtbl)).honorPageBreaks)(this, p), |result| Some(*result))
+        }
+        unsafe extern "system" fn SethonorPageRules(this: *mut core::ffi::c_void, v: super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILayoutRect", "SethonorPageRules", 13usize, |this| ((**(this as *const *const ILayoutRect_Vtbl)).SethonorPageRules)(this, v), |result| Some(*result))
+        }
+        unsafe extern "system" fn honorPageRules(this: *mut core::ffi::c_void, p: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILayoutRect", "honorPageRules", 14usize, |this| ((**(this as *const *const ILayoutRect_Vtbl)).honorPageRules)(this, p), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SetnextRectElement(this: *mut core::ffi::c_void, pelem: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILayoutRect", "SetnextRectElement", 15usize, |this| ((**(this as *const *const ILayoutRect_Vtbl)).SetnextRectElement)(this, pelem), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn nextRectElement(this: *mut core::ffi::c_void, ppelem: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILayoutRect", "nextRectElement", 16usize, |this| ((**(this as *const *const ILayoutRect_Vtbl)).nextRectElement)(this, ppelem), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn contentDocument(this: *mut core::ffi::c_void, pdoc: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ILayoutRect", "contentDocument", 17usize, |this| ((**(this as *const *const ILayoutRect_Vtbl)).contentDocument)(this, pdoc), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetnextRect,
+            nextRect,
+            SetcontentSrc,
+            contentSrc,
+            SethonorPageBreaks,
+            honorPageBreaks,
+            SethonorPageRules,
+            honorPageRules,
+            #[cfg(feature = "Win32_System_Com")]
+            SetnextRectElement,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SetnextRectElement: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            nextRectElement,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            nextRectElement: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            contentDocument,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            contentDocument: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ILayoutRect as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMapMIMEToCLSID_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn EnableDefaultMappings(this: *mut core::ffi::c_void, benable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapMIMEToCLSID", "EnableDefaultMappings", 3usize, |this| ((**(this as *const *const IMapMIMEToCLSID_Vtbl)).EnableDefaultMappings)(this, benable), |result| Some(*result))
+        }
+        unsafe extern "system" fn MapMIMEToCLSID(this: *mut core::ffi::c_void, pszmimetype: windows_core::PCWSTR, pclsid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapMIMEToCLSID", "MapMIMEToCLSID", 4usize, |this| ((**(this as *const *const IMapMIMEToCLSID_Vtbl)).MapMIMEToCLSID)(this, pszmimetype, pclsid), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetMapping(this: *mut core::ffi::c_void, pszmimetype: windows_core::PCWSTR, dwmapmode: u32, clsid: *const windows_core::GUID) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMapMIMEToCLSID", "SetMapping", 5usize, |this| ((**(this as *const *const IMapMIMEToCLSID_Vtbl)).SetMapping)(this, pszmimetype, dwmapmode, clsid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, EnableDefaultMappings, MapMIMEToCLSID, SetMapping }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMapMIMEToCLSID as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IMediaActivityNotifySite_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnMediaActivityStarted(this: *mut core::ffi::c_void, mediaactivitytype: MEDIA_ACTIVITY_NOTIFY_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaActivityNotifySite", "OnMediaActivityStarted", 3usize, |this| ((**(this as *const *const IMediaActivityNotifySite_Vtbl)).OnMediaActivityStarted)(this, mediaactivitytype), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnMediaActivityStopped(this: *mut core::ffi::c_void, mediaactivitytype: MEDIA_ACTIVITY_NOTIFY_TYPE) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IMediaActivityNotifySite", "OnMediaActivityStopped", 4usize, |this| ((**(this as *const *const IMediaActivityNotifySite_Vtbl)).OnMediaActivityStopped)(this, mediaactivitytype), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnMediaActivityStarted, OnMediaActivityStopped }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IMediaActivityNotifySite as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOpenService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn IsDefault(this: *mut core::ffi::c_void, pfisdefault: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenService", "IsDefault", 3usize, |this| ((**(this as *const *const IOpenService_Vtbl)).IsDefault)(this, pfisdefault), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefault(this: *mut core::ffi::c_void, fdefault: super::super::Foundation::BOOL, hwnd: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenService", "SetDefault", 4usize, |this| ((**(this as *const *const IOpenService_Vtbl)).SetDefault)(this, fdefault, hwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetID(this: *mut core::ffi::c_void, pbstrid: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenService", "GetID", 5usize, |this| ((**(this as *const *const IOpenService_Vtbl)).GetID)(this, pbstrid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, IsDefault, SetDefault, GetID }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpenService as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+impl windows_core::imp::ProxyVtable for IOpenServiceActivity_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Execute(this: *mut core::ffi::c_void, pinput: *mut core::ffi::c_void, poutput: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivity", "Execute", 6usize, |this| ((**(this as *const *const IOpenServiceActivity_Vtbl)).Execute)(this, pinput, poutput), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanExecute(this: *mut core::ffi::c_void, pinput: *mut core::ffi::c_void, poutput: *mut core::ffi::c_void, pfcanexecute: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivity", "CanExecute", 7usize, |this| ((**(this as *const *const IOpenServiceActivity_Vtbl)).CanExecute)(this, pinput, poutput, pfcanexecute), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanExecuteType(this: *mut core::ffi::c_void, r#type: OpenServiceActivityContentType, pfcanexecute: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivity", "CanExecuteType", 8usize, |this| ((**(this as *const *const IOpenServiceActivity_Vtbl)).CanExecuteType)(this, r#type, pfcanexecute), |result| Some(*result))
+        }
+        unsafe extern "system" fn Preview(this: *mut core::ffi::c_void, pinput: *mut core::ffi::c_void, poutput: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivity", "Preview", 9usize, |this| ((**(this as *const *const IOpenServiceActivity_Vtbl)).Preview)(this, pinput, poutput), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanPreview(this: *mut core::ffi::c_void, pinput: *mut core::ffi::c_void, poutput: *mut core::ffi::c_void, pfcanpreview: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivity", "CanPreview", 10usize, |this| ((**(this as *const *const IOpenServiceActivity_Vtbl)).CanPreview)(this, pinput, poutput, pfcanpreview), |result| Some(*result))
+        }
+        unsafe extern "system" fn CanPreviewType(this: *mut core::ffi::c_void, r#type: OpenServiceActivityContentType, pfcanpreview: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivity", "CanPreviewType", 11usize, |this| ((**(this as *const *const IOpenServiceActivity_Vtbl)).CanPreviewType)(this, r#type, pfcanpreview), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetStatusText(this: *mut core::ffi::c_void, pinput: *mut core::ffi::c_void, pbstrstatustext: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivity", "GetStatusText", 12usize, |this| ((**(this as *const *const IOpenServiceActivity_Vtbl)).GetStatusText)(this, pinput, pbstrstatustext), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetHomepageUrl(this: *mut core::ffi::c_void, pbstrhomepageurl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivity", "GetHomepageUrl", 13usize, |this| ((**(this as *const *const IOpenServiceActivity_Vtbl)).GetHomepageUrl)(this, pbstrhomepageurl), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDisplayName(this: *mut core::ffi::c_void, pbstrdisplayname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivity", "GetDisplayName", 14usize, |this| ((**(this as *const *const IOpenServiceActivity_Vtbl)).GetDisplayName)(this, pbstrdisplayname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescription(this: *mut core::ffi::c_void, pbstrdescription: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivity", "GetDescription", 15usize, |this| ((**(this as *const *const IOpenServiceActivity_Vtbl)).GetDescription)(this, pbstrdescription), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetCategoryName(this: *mut core::ffi::c_void, pbstrcategoryname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivity", "GetCategoryName", 16usize, |this| ((**(this as *const *const IOpenServiceActivity_Vtbl)).GetCategoryName)(this, pbstrcategoryname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetIconPath(this: *mut core::ffi::c_void, pbstriconpath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivity", "GetIconPath", 17usize, |this| ((**(this as *const *const IOpenServiceActivity_Vtbl)).GetIconPath)(this, pbstriconpath), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        unsafe extern "system" fn GetIcon(this: *mut core::ffi::c_void, fsmallicon: super::super::Foundation::BOOL, phicon: *mut super::super::UI::WindowsAndMessaging::HICON) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivity", "GetIcon", 18usize, |this| ((**(this as *const *const IOpenServiceActivity_Vtbl)).GetIcon)(this, fsmallicon, phicon), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDescriptionFilePath(this: *mut core::ffi::c_void, pbstrxmlpath: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivity", "GetDescriptionFilePath", 19usize, |this| ((**(this as *const *const IOpenServiceActivity_Vtbl)).GetDescriptionFilePath)(this, pbstrxmlpath), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDownloadUrl(this: *mut core::ffi::c_void, pbstrxmluri: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivity", "GetDownloadUrl", 20usize, |this| ((**(this as *const *const IOpenServiceActivity_Vtbl)).GetDownloadUrl)(this, pbstrxmluri), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetInstallUrl(this: *mut core::ffi::c_void, pbstrinstalluri: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivity", "GetInstallUrl", 21usize, |this| ((**(this as *const *const IOpenServiceActivity_Vtbl)).GetInstallUrl)(this, pbstrinstalluri), |result| Some(*result))
+        }
+        unsafe extern "system" fn IsEnabled(this: *mut core::ffi::c_void, pfisenabled: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivity", "IsEnabled", 22usize, |this| ((**(this as *const *const IOpenServiceActivity_Vtbl)).IsEnabled)(this, pfisenabled), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetEnabled(this: *mut core::ffi::c_void, fenable: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivity", "SetEnabled", 23usize, |this| ((**(this as *const *const IOpenServiceActivity_Vtbl)).SetEnabled)(this, fenable), |result| Some(*result))
+        }
+        Self {
+            base__: <IOpenService_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            Execute,
+            CanExecute,
+            CanExecuteType,
+            Preview,
+            CanPreview,
+            CanPreviewType,
+            GetStatusText,
+            GetHomepageUrl,
+            GetDisplayName,
+            GetDescription,
+            GetCategoryName,
+            GetIconPath,
+            #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+            GetIcon,
+            #[cfg(not(feature = "Win32_UI_WindowsAndMessaging"))]
+            GetIcon: 0,
+            GetDescriptionFilePath,
+            GetDownloadUrl,
+            GetInstallUrl,
+            IsEnabled,
+            SetEnabled,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpenServiceActivity as windows_core::Interface>::IID || <IOpenService_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOpenServiceActivityCategory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HasDefaultActivity(this: *mut core::ffi::c_void, pfhasdefaultactivity: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivityCategory", "HasDefaultActivity", 3usize, |this| ((**(this as *const *const IOpenServiceActivityCategory_Vtbl)).HasDefaultActivity)(this, pfhasdefaultactivity), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetDefaultActivity(this: *mut core::ffi::c_void, ppdefaultactivity: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivityCategory", "GetDefaultActivity", 4usize, |this| ((**(this as *const *const IOpenServiceActivityCategory_Vtbl)).GetDefaultActivity)(this, ppdefaultactivity), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetDefaultActivity(this: *mut core::ffi::c_void, pactivity: *mut core::ffi::c_void, hwnd: super::super::Foundation::HWND) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivityCategory", "SetDefaultActivity", 5usize, |this| ((**(this as *const *const IOpenServiceActivityCategory_Vtbl)).SetDefaultActivity)(this, pactivity, hwnd), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetName(this: *mut core::ffi::c_void, pbstrname: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivityCategory", "GetName", 6usize, |this| ((**(this as *const *const IOpenServiceActivityCategory_Vtbl)).GetName)(this, pbstrname), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActivityEnumerator(this: *mut core::ffi::c_void, pinput: *mut core::ffi::c_void, poutput: *mut core::ffi::c_void, ppenumactivity: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivityCategory", "GetActivityEnumerator", 7usize, |this| ((**(this as *const *const IOpenServiceActivityCategory_Vtbl)).GetActivityEnumerator)(this, pinput, poutput, ppenumactivity), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            HasDefaultActivity,
+            GetDefaultActivity,
+            SetDefaultActivity,
+            GetName,
+            GetActivityEnumerator,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpenServiceActivityCategory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOpenServiceActivityInput_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetVariable(this: *mut core::ffi::c_void, pwzvariablename: windows_core::PCWSTR, pwzvariabletype: windows_core::PCWSTR, pbstrvariablecontent: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivityInput", "GetVariable", 3usize, |this| ((**(this as *const *const IOpenServiceActivityInput_Vtbl)).GetVariable)(this, pwzvariablename, pwzvariabletype, pbstrvariablecontent), |result| Some(*result))
+        }
+        unsafe extern "system" fn HasVariable(this: *mut core::ffi::c_void, pwzvariablename: windows_core::PCWSTR, pwzvariabletype: windows_core::PCWSTR, pfhasvariable: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivityInput", "HasVariable", 4usize, |this| ((**(this as *const *const IOpenServiceActivityInput_Vtbl)).HasVariable)(this, pwzvariablename, pwzvariabletype, pfhasvariable), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetType(this: *mut core::ffi::c_void, ptype: *mut OpenServiceActivityContentType) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivityInput", "GetType", 5usize, |this| ((**(this as *const *const IOpenServiceActivityInput_Vtbl)).GetType)(this, ptype), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetVariable, HasVariable, GetType }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpenServiceActivityInput as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOpenServiceActivityManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetCategoryEnumerator(this: *mut core::ffi::c_void, etype: OpenServiceActivityContentType, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivityManager", "GetCategoryEnumerator", 3usize, |this| ((**(this as *const *const IOpenServiceActivityManager_Vtbl)).GetCategoryEnumerator)(this, etype, ppenum), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActivityByID(this: *mut core::ffi::c_void, pwzactivityid: windows_core::PCWSTR, ppactivity: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivityManager", "GetActivityByID", 4usize, |this| ((**(this as *const *const IOpenServiceActivityManager_Vtbl)).GetActivityByID)(this, pwzactivityid, ppactivity), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetActivityByHomepageAndCategory(this: *mut core::ffi::c_void, pwzhomepage: windows_core::PCWSTR, pwzcategory: windows_core::PCWSTR, ppactivity: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivityManager", "GetActivityByHomepageAndCategory", 5usize, |this| ((**(this as *const *const IOpenServiceActivityManager_Vtbl)).GetActivityByHomepageAndCategory)(this, pwzhomepage, pwzcategory, ppactivity), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetVersionCookie(this: *mut core::ffi::c_void, pdwversioncookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivityManager", "GetVersionCookie", 6usize, |this| ((**(this as *const *const IOpenServiceActivityManager_Vtbl)).GetVersionCookie)(this, pdwversioncookie), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetCategoryEnumerator,
+            GetActivityByID,
+            GetActivityByHomepageAndCategory,
+            GetVersionCookie,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpenServiceActivityManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IOpenServiceActivityOutputContext_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Navigate(this: *mut core::ffi::c_void, pwzuri: windows_core::PCWSTR, pwzmethod: windows_core::PCWSTR, pwzheaders: windows_core::PCWSTR, ppostdata: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivityOutputContext", "Navigate", 3usize, |this| ((**(this as *const *const IOpenServiceActivityOutputContext_Vtbl)).Navigate)(this, pwzuri, pwzmethod, pwzheaders, ppostdata), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn CanNavigate(this: *mut core::ffi::c_void, pwzuri: windows_core::PCWSTR, pwzmethod: windows_core::PCWSTR, pwzheaders: windows_core::PCWSTR, ppostdata: *mut core::ffi::c_void, pfcannavigate: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceActivityOutputContext", "CanNavigate", 4usize, |this| ((**(this as *const *const IOpenServiceActivityOutputContext_Vtbl)).CanNavigate)(this, pwzuri, pwzmethod, pwzheaders, ppostdata, pfcannavigate), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Navigate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Navigate: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            CanNavigate,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            CanNavigate: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpenServiceActivityOutputContext as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IOpenServiceManager_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn InstallService(this: *mut core::ffi::c_void, pwzserviceurl: windows_core::PCWSTR, ppservice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceManager", "InstallService", 3usize, |this| ((**(this as *const *const IOpenServiceManager_Vtbl)).InstallService)(this, pwzserviceurl, ppservice), |result| Some(*result))
+        }
+        unsafe extern "system" fn UninstallService(this: *mut core::ffi::c_void, pservice: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceManager", "UninstallService", 4usize, |this| ((**(this as *const *const IOpenServiceManager_Vtbl)).UninstallService)(this, pservice), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetServiceByID(this: *mut core::ffi::c_void, pwzid: windows_core::PCWSTR, ppservice: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IOpenServiceManager", "GetServiceByID", 5usize, |this| ((**(this as *const *const IOpenServiceManager_Vtbl)).GetServiceByID)(this, pwzid, ppservice), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, InstallService, UninstallService, GetServiceByID }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IOpenServiceManager as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPeerFactory_Vtbl {
+    const PROXY: Self = { Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPeerFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for IPersistHistory_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn LoadHistory(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void, pbc: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistHistory", "LoadHistory", 4usize, |this| ((**(this as *const *const IPersistHistory_Vtbl)).LoadHistory)(this, pstream, pbc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn SaveHistory(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistHistory", "SaveHistory", 5usize, |this| ((**(this as *const *const IPersistHistory_Vtbl)).SaveHistory)(this, pstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPositionCookie(this: *mut core::ffi::c_void, dwpositioncookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistHistory", "SetPositionCookie", 6usize, |this| ((**(this as *const *const IPersistHistory_Vtbl)).SetPositionCookie)(this, dwpositioncookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetPositionCookie(this: *mut core::ffi::c_void, pdwpositioncookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPersistHistory", "GetPositionCookie", 7usize, |this| ((**(this as *const *const IPersistHistory_Vtbl)).GetPositionCookie)(this, pdwpositioncookie), |result| Some(*result))
+        }
+        Self {
+            base__: <super::super::System::Com::IPersist_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            LoadHistory,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            LoadHistory: 0,
+            #[cfg(feature = "Win32_System_Com")]
+            SaveHistory,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            SaveHistory: 0,
+            SetPositionCookie,
+            GetPositionCookie,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPersistHistory as windows_core::Interface>::IID || <super::super::System::Com::IPersist_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintTaskRequestFactory_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreatePrintTaskRequest(this: *mut core::ffi::c_void, pprinttaskrequesthandler: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskRequestFactory", "CreatePrintTaskRequest", 3usize, |this| ((**(this as *const *const IPrintTaskRequestFactory_Vtbl)).CreatePrintTaskRequest)(this, pprinttaskrequesthandler), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreatePrintTaskRequest }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTaskRequestFactory as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IPrintTaskRequestHandler_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn HandlePrintTaskRequest(this: *mut core::ffi::c_void, pprinttaskrequest: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IPrintTaskRequestHandler", "HandlePrintTaskRequest", 3usize, |this| ((**(this as *const *const IPrintTaskRequestHandler_Vtbl)).HandlePrintTaskRequest)(this, pprinttaskrequest), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, HandlePrintTaskRequest }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IPrintTaskRequestHandler as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IScrollableContextMenu_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddItem(this: *mut core::ffi::c_void, itemtext: windows_core::PCWSTR, cmdid: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScrollableContextMenu", "AddItem", 3usize, |this| ((**(this as *const *const IScrollableContextMenu_Vtbl)).AddItem)(this, itemtext, cmdid), |result| Some(*result))
+        }
+        unsafe extern "system" fn ShowModal(this: *mut core::ffi::c_void, x: i32, y: i32, cmdid: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScrollableContextMenu", "ShowModal", 4usize, |this| ((**(this as *const *const IScrollableContextMenu_Vtbl)).ShowModal)(this, x, y, cmdid), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddItem, ShowModal }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScrollableContextMenu as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IScrollableContextMenu2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddSeparator(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScrollableContextMenu2", "AddSeparator", 5usize, |this| ((**(this as *const *const IScrollableContextMenu2_Vtbl)).AddSeparator)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetPlacement(this: *mut core::ffi::c_void, scmp: SCROLLABLECONTEXTMENU_PLACEMENT) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IScrollableContextMenu2", "SetPlacement", 6usize, |this| ((**(this as *const *const IScrollableContextMenu2_Vtbl)).SetPlacement)(this, scmp), |result| Some(*result))
+        }
+        Self { base__: <IScrollableContextMenu_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddSeparator, SetPlacement }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IScrollableContextMenu2 as windows_core::Interface>::IID || <IScrollableContextMenu_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ISniffStream_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn Init(this: *mut core::ffi::c_void, pstream: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISniffStream", "Init", 3usize, |this| ((**(this as *const *const ISniffStream_Vtbl)).Init)(this, pstream), |result| Some(*result))
+        }
+        unsafe extern "system" fn Peek(this: *mut core::ffi::c_void, pbuffer: *mut core::ffi::c_void, nbytes: u32, pnbytesread: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISniffStream", "Peek", 4usize, |this| ((**(this as *const *const ISniffStream_Vtbl)).Peek)(this, pbuffer, nbytes, pnbytesread), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            Init,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            Init: 0,
+            Peek,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISniffStream as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISurfacePresenterFlip_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Present(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISurfacePresenterFlip", "Present", 3usize, |this| ((**(this as *const *const ISurfacePresenterFlip_Vtbl)).Present)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetBuffer(this: *mut core::ffi::c_void, backbufferindex: u32, riid: *const windows_core::GUID, ppbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISurfacePresenterFlip", "GetBuffer", 4usize, |this| ((**(this as *const *const ISurfacePresenterFlip_Vtbl)).GetBuffer)(this, backbufferindex, riid, ppbuffer), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Present, GetBuffer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISurfacePresenterFlip as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for ISurfacePresenterFlip2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn SetRotation(this: *mut core::ffi::c_void, dxgirotation: super::super::Graphics::Dxgi::Common::DXGI_MODE_ROTATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISurfacePresenterFlip2", "SetRotation", 3usize, |this| ((**(this as *const *const ISurfacePresenterFlip2_Vtbl)).SetRotation)(this, dxgirotation), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            SetRotation,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            SetRotation: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISurfacePresenterFlip2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ISurfacePresenterFlipBuffer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn BeginDraw(this: *mut core::ffi::c_void, riid: *const windows_core::GUID, ppbuffer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISurfacePresenterFlipBuffer", "BeginDraw", 3usize, |this| ((**(this as *const *const ISurfacePresenterFlipBuffer_Vtbl)).BeginDraw)(this, riid, ppbuffer), |result| Some(*result))
+        }
+        unsafe extern "system" fn EndDraw(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ISurfacePresenterFlipBuffer", "EndDraw", 4usize, |this| ((**(this as *const *const ISurfacePresenterFlipBuffer_Vtbl)).EndDraw)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, BeginDraw, EndDraw }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ISurfacePresenterFlipBuffer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Ole")]
+impl windows_core::imp::ProxyVtable for ITargetContainer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetFrameUrl(this: *mut core::ffi::c_void, ppszframesrc: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetContainer", "GetFrameUrl", 3usize, |this| ((**(this as *const *const ITargetContainer_Vtbl)).GetFrameUrl)(this, ppszframesrc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn GetFramesContainer(this: *mut core::ffi::c_void, ppcontainer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetContainer", "GetFramesContainer", 4usize, |this| ((**(this as *const *const ITargetContainer_Vtbl)).GetFramesContainer)(this, ppcontainer), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            GetFrameUrl,
+            #[cfg(feature = "Win32_System_Ole")]
+            GetFramesContainer,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            GetFramesContainer: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITargetContainer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITargetEmbedding_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetTargetFrame(this: *mut core::ffi::c_void, pptargetframe: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetEmbedding", "GetTargetFrame", 3usize, |this| ((**(this as *const *const ITargetEmbedding_Vtbl)).GetTargetFrame)(this, pptargetframe), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetTargetFrame }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITargetEmbedding as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Ole")]
+impl windows_core::imp::ProxyVtable for ITargetFrame_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetFrameName(this: *mut core::ffi::c_void, pszframename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFrame", "SetFrameName", 3usize, |this| ((**(this as *const *const ITargetFrame_Vtbl)).SetFrameName)(this, pszframename), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFrameName(this: *mut core::ffi::c_void, ppszframename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFrame", "GetFrameName", 4usize, |this| ((**(this as *const *const ITargetFrame_Vtbl)).GetFrameName)(this, ppszframename), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParentFrame(this: *mut core::ffi::c_void, ppunkparent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFrame", "GetParentFrame", 5usize, |this| ((**(this as *const *const ITargetFrame_Vtbl)).GetParentFrame)(this, ppunkparent), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindFrame(this: *mut core::ffi::c_void, psztargetname: windows_core::PCWSTR, ppunkcontextframe: *mut core::ffi::c_void, dwflags: u32, ppunktargetframe: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFrame", "FindFrame", 6usize, |this| ((**(this as *const *const ITargetFrame_Vtbl)).FindFrame)(this, psztargetname, ppunkcontextframe, dwflags, ppunktargetframe), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFrameSrc(this: *mut core::ffi::c_void, pszframesrc: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFrame", "SetFrameSrc", 7usize, |this| ((**(this as *const *const ITargetFrame_Vtbl)).SetFrameSrc)(this, pszframesrc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFrameSrc(this: *mut core::ffi::c_void, ppszframesrc: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFrame", "GetFrameSrc", 8usize, |this| ((**(this as *const *const ITargetFrame_Vtbl)).GetFrameSrc)(this, ppszframesrc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn GetFramesContainer(this: *mut core::ffi::c_void, ppcontainer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFrame", "GetFramesContainer", 9usize, |this| ((**(this as *const *const ITargetFrame_Vtbl)).GetFramesContainer)(this, ppcontainer), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFrameOptions(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFrame", "SetFrameOptions", 10usize, |this| ((**(this as *const *const ITargetFrame_Vtbl)).SetFrameOptions)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFrameOptions(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFrame", "GetFrameOptions", 11usize, |this| ((**(this as *const *const ITargetFrame_Vtbl)).GetFrameOptions)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFrameMargins(this: *mut core::ffi::c_void, dwwidth: u32, dwheight: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFrame", "SetFrameMargins", 12usize, |this| ((**(this as *const *const ITargetFrame_Vtbl)).SetFrameMargins)(this, dwwidth, dwheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFrameMargins(this: *mut core::ffi::c_void, pdwwidth: *mut u32, pdwheight: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFrame", "GetFrameMargins", 13usize, |this| ((**(this as *const *const ITargetFrame_Vtbl)).GetFrameMargins)(this, pdwwidth, pdwheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn RemoteNavigate(this: *mut core::ffi::c_void, clength: u32, puldata: *const u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFrame", "RemoteNavigate", 14usize, |this| ((**(this as *const *const ITargetFrame_Vtbl)).RemoteNavigate)(this, clength, puldata), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnChildFrameActivate(this: *mut core::ffi::c_void, punkchildframe: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFrame", "OnChildFrameActivate", 15usize, |this| ((**(this as *const *const ITargetFrame_Vtbl)).OnChildFrameActivate)(this, punkchildframe), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnChildFrameDeactivate(this: *mut core::ffi::c_void, punkchildframe: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFrame", "OnChildFrameDeactivate", 16usize, |this| ((**(this as *const *const ITargetFrame_Vtbl)).OnChildFrameDeactivate)(this, punkchildframe), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetFrameName,
+            GetFrameName,
+            GetParentFrame,
+            FindFrame,
+            SetFrameSrc,
+            GetFrameSrc,
+            #[cfg(feature = "Win32_System_Ole")]
+            GetFramesContainer,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            GetFramesContainer: 0,
+            SetFrameOptions,
+            GetFrameOptions,
+            SetFrameMargins,
+            GetFrameMargins,
+            RemoteNavigate,
+            OnChildFrameActivate,
+            OnChildFrameDeactivate,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITargetFrame as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Ole")]
+impl windows_core::imp::ProxyVtable for ITargetFrame2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetFrameName(this: *mut core::ffi::c_void, pszframename: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFrame2", "SetFrameName", 3usize, |this| ((**(this as *const *const ITargetFrame2_Vtbl)).SetFrameName)(this, pszframename), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFrameName(this: *mut core::ffi::c_void, ppszframename: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFrame2", "GetFrameName", 4usize, |this| ((**(this as *const *const ITargetFrame2_Vtbl)).GetFrameName)(this, ppszframename), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetParentFrame(this: *mut core::ffi::c_void, ppunkparent: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFrame2", "GetParentFrame", 5usize, |this| ((**(this as *const *const ITargetFrame2_Vtbl)).GetParentFrame)(this, ppunkparent), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFrameSrc(this: *mut core::ffi::c_void, pszframesrc: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFrame2", "SetFrameSrc", 6usize, |this| ((**(this as *const *const ITargetFrame2_Vtbl)).SetFrameSrc)(this, pszframesrc), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFrameSrc(this: *mut core::ffi::c_void, ppszframesrc: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFrame2", "GetFrameSrc", 7usize, |this| ((**(this as *const *const ITargetFrame2_Vtbl)).GetFrameSrc)(this, ppszframesrc), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn GetFramesContainer(this: *mut core::ffi::c_void, ppcontainer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFrame2", "GetFramesContainer", 8usize, |this| ((**(this as *const *const ITargetFrame2_Vtbl)).GetFramesContainer)(this, ppcontainer), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFrameOptions(this: *mut core::ffi::c_void, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFrame2", "SetFrameOptions", 9usize, |this| ((**(this as *const *const ITargetFrame2_Vtbl)).SetFrameOptions)(this, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFrameOptions(this: *mut core::ffi::c_void, pdwflags: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFrame2", "GetFrameOptions", 10usize, |this| ((**(this as *const *const ITargetFrame2_Vtbl)).GetFrameOptions)(this, pdwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetFrameMargins(this: *mut core::ffi::c_void, dwwidth: u32, dwheight: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFrame2", "SetFrameMargins", 11usize, |this| ((**(this as *const *const ITargetFrame2_Vtbl)).SetFrameMargins)(this, dwwidth, dwheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetFrameMargins(this: *mut core::ffi::c_void, pdwwidth: *mut u32, pdwheight: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFrame2", "GetFrameMargins", 12usize, |this| ((**(this as *const *const ITargetFrame2_Vtbl)).GetFrameMargins)(this, pdwwidth, pdwheight), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindFrame(this: *mut core::ffi::c_void, psztargetname: windows_core::PCWSTR, dwflags: u32, ppunktargetframe: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFrame2", "FindFrame", 13usize, |this| ((**(this as *const *const ITargetFrame2_Vtbl)).FindFrame)(this, psztargetname, dwflags, ppunktargetframe), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTargetAlias(this: *mut core::ffi::c_void, psztargetname: windows_core::PCWSTR, ppsztargetalias: *mut windows_core::PWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFrame2", "GetTargetAlias", 14usize, |this| ((**(this as *const *const ITargetFrame2_Vtbl)).GetTargetAlias)(this, psztargetname, ppsztargetalias), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            SetFrameName,
+            GetFrameName,
+            GetParentFrame,
+            SetFrameSrc,
+            GetFrameSrc,
+            #[cfg(feature = "Win32_System_Ole")]
+            GetFramesContainer,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            GetFramesContainer: 0,
+            SetFrameOptions,
+            GetFrameOptions,
+            SetFrameMargins,
+            GetFrameMargins,
+            FindFrame,
+            GetTargetAlias,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITargetFrame2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITargetFramePriv_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FindFrameDownwards(this: *mut core::ffi::c_void, psztargetname: windows_core::PCWSTR, dwflags: u32, ppunktargetframe: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFramePriv", "FindFrameDownwards", 3usize, |this| ((**(this as *const *const ITargetFramePriv_Vtbl)).FindFrameDownwards)(this, psztargetname, dwflags, ppunktargetframe), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindFrameInContext(this: *mut core::ffi::c_void, psztargetname: windows_core::PCWSTR, punkcontextframe: *mut core::ffi::c_void, dwflags: u32, ppunktargetframe: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFramePriv", "FindFrameInContext", 4usize, |this| ((**(this as *const *const ITargetFramePriv_Vtbl)).FindFrameInContext)(this, psztargetname, punkcontextframe, dwflags, ppunktargetframe), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnChildFrameActivate(this: *mut core::ffi::c_void, punkchildframe: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFramePriv", "OnChildFrameActivate", 5usize, |this| ((**(this as *const *const ITargetFramePriv_Vtbl)).OnChildFrameActivate)(this, punkchildframe), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnChildFrameDeactivate(this: *mut core::ffi::c_void, punkchildframe: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFramePriv", "OnChildFrameDeactivate", 6usize, |this| ((**(this as *const *const ITargetFramePriv_Vtbl)).OnChildFrameDeactivate)(this, punkchildframe), |result| Some(*result))
+        }
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn NavigateHack(this: *mut core::ffi::c_void, grfhlnf: u32, pbc: *mut core::ffi::c_void, pibsc: *mut core::ffi::c_void, psztargetname: windows_core::PCWSTR, pszurl: windows_core::PCWSTR, pszlocation: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFramePriv", "NavigateHack", 7usize, |this| ((**(this as *const *const ITargetFramePriv_Vtbl)).NavigateHack)(this, grfhlnf, pbc, pibsc, psztargetname, pszurl, pszlocation), |result| Some(*result))
+        }
+        unsafe extern "system" fn FindBrowserByIndex(this: *mut core::ffi::c_void, dwid: u32, ppunkbrowser: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFramePriv", "FindBrowserByIndex", 8usize, |this| ((**(this as *const *const ITargetFramePriv_Vtbl)).FindBrowserByIndex)(this, dwid, ppunkbrowser), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FindFrameDownwards,
+            FindFrameInContext,
+            OnChildFrameActivate,
+            OnChildFrameDeactivate,
+            #[cfg(feature = "Win32_System_Com")]
+            NavigateHack,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            NavigateHack: 0,
+            FindBrowserByIndex,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITargetFramePriv as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for ITargetFramePriv2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Com")]
+        unsafe extern "system" fn AggregatedNavigation2(this: *mut core::ffi::c_void, grfhlnf: u32, pbc: *mut core::ffi::c_void, pibsc: *mut core::ffi::c_void, psztargetname: windows_core::PCWSTR, puri: *mut core::ffi::c_void, pszlocation: windows_core::PCWSTR) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetFramePriv2", "AggregatedNavigation2", 9usize, |this| ((**(this as *const *const ITargetFramePriv2_Vtbl)).AggregatedNavigation2)(this, grfhlnf, pbc, pibsc, psztargetname, puri, pszlocation), |result| Some(*result))
+        }
+        Self {
+            base__: <ITargetFramePriv_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Com")]
+            AggregatedNavigation2,
+            #[cfg(not(feature = "Win32_System_Com"))]
+            AggregatedNavigation2: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITargetFramePriv2 as windows_core::Interface>::IID || <ITargetFramePriv_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITargetNotify_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnCreate(this: *mut core::ffi::c_void, punkdestination: *mut core::ffi::c_void, cbcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetNotify", "OnCreate", 3usize, |this| ((**(this as *const *const ITargetNotify_Vtbl)).OnCreate)(this, punkdestination, cbcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn OnReuse(this: *mut core::ffi::c_void, punkdestination: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetNotify", "OnReuse", 4usize, |this| ((**(this as *const *const ITargetNotify_Vtbl)).OnReuse)(this, punkdestination), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnCreate, OnReuse }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITargetNotify as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITargetNotify2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetOptionString(this: *mut core::ffi::c_void, pbstroptions: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITargetNotify2", "GetOptionString", 5usize, |this| ((**(this as *const *const ITargetNotify2_Vtbl)).GetOptionString)(this, pbstroptions), |result| Some(*result))
+        }
+        Self { base__: <ITargetNotify_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetOptionString }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITargetNotify2 as windows_core::Interface>::IID || <ITargetNotify_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITimer_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn Advise(this: *mut core::ffi::c_void, vtimemin: core::mem::MaybeUninit<windows_core::VARIANT>, vtimemax: core::mem::MaybeUninit<windows_core::VARIANT>, vtimeinterval: core::mem::MaybeUninit<windows_core::VARIANT>, dwflags: u32, ptimersink: *mut core::ffi::c_void, pdwcookie: *mut u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimer", "Advise", 3usize, |this| ((**(this as *const *const ITimer_Vtbl)).Advise)(this, vtimemin, vtimemax, vtimeinterval, dwflags, ptimersink, pdwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn Unadvise(this: *mut core::ffi::c_void, dwcookie: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimer", "Unadvise", 4usize, |this| ((**(this as *const *const ITimer_Vtbl)).Unadvise)(this, dwcookie), |result| Some(*result))
+        }
+        unsafe extern "system" fn Freeze(this: *mut core::ffi::c_void, ffreeze: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimer", "Freeze", 5usize, |this| ((**(this as *const *const ITimer_Vtbl)).Freeze)(this, ffreeze), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetTime(this: *mut core::ffi::c_void, pvtime: *mut core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimer", "GetTime", 6usize, |this| ((**(this as *const *const ITimer_Vtbl)).GetTime)(this, pvtime), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, Advise, Unadvise, Freeze, GetTime }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimer as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITimerEx_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn SetMode(this: *mut core::ffi::c_void, dwmode: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimerEx", "SetMode", 7usize, |this| ((**(this as *const *const ITimerEx_Vtbl)).SetMode)(this, dwmode), |result| Some(*result))
+        }
+        Self { base__: <ITimer_Vtbl as windows_core::imp::ProxyVtable>::PROXY, SetMode }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimerEx as windows_core::Interface>::IID || <ITimer_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITimerService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn CreateTimer(this: *mut core::ffi::c_void, preferencetimer: *mut core::ffi::c_void, ppnewtimer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimerService", "CreateTimer", 3usize, |this| ((**(this as *const *const ITimerService_Vtbl)).CreateTimer)(this, preferencetimer, ppnewtimer), |result| Some(*result))
+        }
+        unsafe extern "system" fn GetNamedTimer(this: *mut core::ffi::c_void, rguidname: *const windows_core::GUID, pptimer: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimerService", "GetNamedTimer", 4usize, |this| ((**(this as *const *const ITimerService_Vtbl)).GetNamedTimer)(this, rguidname, pptimer), |result| Some(*result))
+        }
+        unsafe extern "system" fn SetNamedTimerReference(this: *mut core::ffi::c_void, rguidname: *const windows_core::GUID, preferencetimer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimerService", "SetNamedTimerReference", 5usize, |this| ((**(this as *const *const ITimerService_Vtbl)).SetNamedTimerReference)(this, rguidname, preferencetimer), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, CreateTimer, GetNamedTimer, SetNamedTimerReference }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimerService as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITimerSink_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnTimer(this: *mut core::ffi::c_void, vtimeadvise: core::mem::MaybeUninit<windows_core::VARIANT>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITimerSink", "OnTimer", 3usize, |this| ((**(this as *const *const ITimerSink_Vtbl)).OnTimer)(this, vtimeadvise), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnTimer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITimerSink as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for ITridentTouchInput_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn OnPointerMessage(this: *mut core::ffi::c_void, msg: u32, wparam: super::super::Foundation::WPARAM, lparam: super::super::Foundation::LPARAM, pfallowmanipulations: *mut super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "ITridentTouchInput", "OnPointerMessage", 3usize, |this| ((**(this as *const *const ITridentTouchInput_Vtbl)).OnPointerMessage)(this, msg, wparam, lparam, pfallowmanipulations), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, OnPointerMessage }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<ITridentTouchInput as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Ole")]
+impl windows_core::imp::ProxyVtable for IUrlHistoryNotify_Vtbl {
+    const PROXY: Self = { Self { base__: <super::super::System::Ole::IOleCommandTarget_Vtbl as windows_core::imp::ProxyVtable>::PROXY } };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUrlHistoryNotify as windows_core::Interface>::IID || <super::super::System::Ole::IOleCommandTarget_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IUrlHistoryStg_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn AddUrl(this: *mut core::ffi::c_void, pocsurl: windows_core::PCWSTR, pocstitle: windows_core::PCWSTR, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUrlHistoryStg", "AddUrl", 3usize, |this| ((**(this as *const *const IUrlHistoryStg_Vtbl)).AddUrl)(this, pocsurl, pocstitle, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn DeleteUrl(this: *mut core::ffi::c_void, pocsurl: windows_core::PCWSTR, dwflags: u32) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUrlHistoryStg", "DeleteUrl", 4usize, |this| ((**(this as *const *const IUrlHistoryStg_Vtbl)).DeleteUrl)(this, pocsurl, dwflags), |result| Some(*result))
+        }
+        unsafe extern "system" fn QueryUrl(this: *mut core::ffi::c_void, pocsurl: windows_core::PCWSTR, dwflags: u32, lpstaturl: *mut STATURL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUrlHistoryStg", "QueryUrl", 5usize, |this| ((**(this as *const *const IUrlHistoryStg_Vtbl)).QueryUrl)(this, pocsurl, dwflags, lpstaturl), |result| Some(*result))
+        }
+        unsafe extern "system" fn BindToObject(this: *mut core::ffi::c_void, pocsurl: windows_core::PCWSTR, riid: *const windows_core::GUID, ppvout: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUrlHistoryStg", "BindToObject", 6usize, |this| ((**(this as *const *const IUrlHistoryStg_Vtbl)).BindToObject)(this, pocsurl, riid, ppvout), |result| Some(*result))
+        }
+        unsafe extern "system" fn EnumUrls(this: *mut core::ffi::c_void, ppenum: *mut *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUrlHistoryStg", "EnumUrls", 7usize, |this| ((**(this as *const *const IUrlHistoryStg_Vtbl)).EnumUrls)(this, ppenum), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, AddUrl, DeleteUrl, QueryUrl, BindToObject, EnumUrls }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUrlHistoryStg as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Ole")]
+impl windows_core::imp::ProxyVtable for IUrlHistoryStg2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_System_Ole")]
+        unsafe extern "system" fn AddUrlAndNotify(this: *mut core::ffi::c_void, pocsurl: windows_core::PCWSTR, pocstitle: windows_core::PCWSTR, dwflags: u32, fwritehistory: super::super::Foundation::BOOL, poctnotify: *mut core::ffi::c_void, punkisfolder: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUrlHistoryStg2", "AddUrlAndNotify", 8usize, |this| ((**(this as *const *const IUrlHistoryStg2_Vtbl)).AddUrlAndNotify)(this, pocsurl, pocstitle, dwflags, fwritehistory, poctnotify, punkisfolder), |result| Some(*result))
+        }
+        unsafe extern "system" fn ClearHistory(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IUrlHistoryStg2", "ClearHistory", 9usize, |this| ((**(this as *const *const IUrlHistoryStg2_Vtbl)).ClearHistory)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <IUrlHistoryStg_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_System_Ole")]
+            AddUrlAndNotify,
+            #[cfg(not(feature = "Win32_System_Ole"))]
+            AddUrlAndNotify: 0,
+            ClearHistory,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IUrlHistoryStg2 as windows_core::Interface>::IID || <IUrlHistoryStg_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IViewObjectPresentFlip_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NotifyRender(this: *mut core::ffi::c_void, frecreatepresenter: super::super::Foundation::BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewObjectPresentFlip", "NotifyRender", 3usize, |this| ((**(this as *const *const IViewObjectPresentFlip_Vtbl)).NotifyRender)(this, frecreatepresenter), |result| Some(*result))
+        }
+        unsafe extern "system" fn RenderObjectToBitmap(this: *mut core::ffi::c_void, pbitmap: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewObjectPresentFlip", "RenderObjectToBitmap", 4usize, |this| ((**(this as *const *const IViewObjectPresentFlip_Vtbl)).RenderObjectToBitmap)(this, pbitmap), |result| Some(*result))
+        }
+        unsafe extern "system" fn RenderObjectToSharedBuffer(this: *mut core::ffi::c_void, pbuffer: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewObjectPresentFlip", "RenderObjectToSharedBuffer", 5usize, |this| ((**(this as *const *const IViewObjectPresentFlip_Vtbl)).RenderObjectToSharedBuffer)(this, pbuffer), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NotifyRender, RenderObjectToBitmap, RenderObjectToSharedBuffer }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IViewObjectPresentFlip as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IViewObjectPresentFlip2_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn NotifyLeavingView(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewObjectPresentFlip2", "NotifyLeavingView", 3usize, |this| ((**(this as *const *const IViewObjectPresentFlip2_Vtbl)).NotifyLeavingView)(this), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, NotifyLeavingView }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IViewObjectPresentFlip2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+impl windows_core::imp::ProxyVtable for IViewObjectPresentFlipSite2_Vtbl {
+    const PROXY: Self = {
+        #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+        unsafe extern "system" fn GetRotationForCurrentOutput(this: *mut core::ffi::c_void, pdxgirotation: *mut super::super::Graphics::Dxgi::Common::DXGI_MODE_ROTATION) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IViewObjectPresentFlipSite2", "GetRotationForCurrentOutput", 3usize, |this| ((**(this as *const *const IViewObjectPresentFlipSite2_Vtbl)).GetRotationForCurrentOutput)(this, pdxgirotation), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            #[cfg(feature = "Win32_Graphics_Dxgi_Common")]
+            GetRotationForCurrentOutput,
+            #[cfg(not(feature = "Win32_Graphics_Dxgi_Common"))]
+            GetRotationForCurrentOutput: 0,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IViewObjectPresentFlipSite2 as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebBrowserEventsService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn FireBeforeNavigate2Event(this: *mut core::ffi::c_void, pfcancel: *mut super::super::Foundation::VARIANT_BOOL) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebBrowserEventsService", "FireBeforeNavigate2Event", 3usize, |this| ((**(this as *const *const IWebBrowserEventsService_Vtbl)).FireBeforeNavigate2Event)(this, pfcancel), |result| Some(*result))
+        }
+        unsafe extern "system" fn FireNavigateComplete2Event(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebBrowserEventsService", "FireNavigateComplete2Event", 4usize, |this| ((**(this as *const *const IWebBrowserEventsService_Vtbl)).FireNavigateComplete2Event)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn FireDownloadBeginEvent(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebBrowserEventsService", "FireDownloadBeginEvent", 5usize, |this| ((**(this as *const *const IWebBrowserEventsService_Vtbl)).FireDownloadBeginEvent)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn FireDownloadCompleteEvent(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebBrowserEventsService", "FireDownloadCompleteEvent", 6usize, |this| ((**(this as *const *const IWebBrowserEventsService_Vtbl)).FireDownloadCompleteEvent)(this), |result| Some(*result))
+        }
+        unsafe extern "system" fn FireDocumentCompleteEvent(this: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebBrowserEventsService", "FireDocumentCompleteEvent", 7usize, |this| ((**(this as *const *const IWebBrowserEventsService_Vtbl)).FireDocumentCompleteEvent)(this), |result| Some(*result))
+        }
+        Self {
+            base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY,
+            FireBeforeNavigate2Event,
+            FireNavigateComplete2Event,
+            FireDownloadBeginEvent,
+            FireDownloadCompleteEvent,
+            FireDocumentCompleteEvent,
+        }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebBrowserEventsService as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+impl windows_core::imp::ProxyVtable for IWebBrowserEventsUrlService_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn GetUrlForEvents(this: *mut core::ffi::c_void, purl: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "IWebBrowserEventsUrlService", "GetUrlForEvents", 3usize, |this| ((**(this as *const *const IWebBrowserEventsUrlService_Vtbl)).GetUrlForEvents)(this, purl), |result| Some(*result))
+        }
+        Self { base__: <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::PROXY, GetUrlForEvents }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<IWebBrowserEventsUrlService as windows_core::Interface>::IID || <windows_core::IUnknown_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
+#[cfg(feature = "Win32_System_Com")]
+impl windows_core::imp::ProxyVtable for Iwfolders_Vtbl {
+    const PROXY: Self = {
+        unsafe extern "system" fn navigate(this: *mut core::ffi::c_void, bstrurl: core::mem::MaybeUninit<windows_core::BSTR>, pbstrretval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Iwfolders", "navigate", 7usize, |this| ((**(this as *const *const Iwfolders_Vtbl)).navigate)(this, bstrurl, pbstrretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn navigateFrame(this: *mut core::ffi::c_void, bstrurl: core::mem::MaybeUninit<windows_core::BSTR>, bstrtargetframe: core::mem::MaybeUninit<windows_core::BSTR>, pbstrretval: *mut core::mem::MaybeUninit<windows_core::BSTR>) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Iwfolders", "navigateFrame", 8usize, |this| ((**(this as *const *const Iwfolders_Vtbl)).navigateFrame)(this, bstrurl, bstrtargetframe, pbstrretval), |result| Some(*result))
+        }
+        unsafe extern "system" fn navigateNoSite(this: *mut core::ffi::c_void, bstrurl: core::mem::MaybeUninit<windows_core::BSTR>, bstrtargetframe: core::mem::MaybeUninit<windows_core::BSTR>, dwhwnd: u32, pwb: *mut core::ffi::c_void) -> windows_core::HRESULT {
+            windows_core::imp::proxy_call(this, "Iwfolders", "navigateNoSite", 9usize, |this| ((**(this as *const *const Iwfolders_Vtbl)).navigateNoSite)(this, bstrurl, bstrtargetframe, dwhwnd, pwb), |result| Some(*result))
+        }
+        Self { base__: <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::PROXY, navigate, navigateFrame, navigateNoSite }
+    };
+    const VTABLE: &'static Self = &<Self as windows_core::imp::ProxyVtable>::PROXY;
+    fn matches(iid: &windows_core::GUID) -> bool {
+        iid == &<Iwfolders as windows_core::Interface>::IID || <super::super::System::Com::IDispatch_Vtbl as windows_core::imp::ProxyVtable>::matches(iid)
+    }
+}
@@ -15,6 +15,12 @@
 //! and then to use `pub` for the specific items that will be re-exported.
 
 #[cfg(feature = "Foundation")]
-mod Foundation;
+pub(crate) mod Foundation;
+#[cfg(feature = "Storage")]
+pub(crate) mod Storage;
+#[cfg(feature = "System")]
+pub(crate) mod System;
+#[cfg(feature = "UI")]
+pub(crate) mod UI;
 #[cfg(feature = "Win32")]
-mod Win32;
+pub(crate) mod Win32;
@@ -1,5 +1,18 @@
+#[cfg(feature = "std")]
+pub(crate) mod Async;
 #[cfg(feature = "Foundation_Collections")]
 pub mod Collections;
+pub mod DateTime;
+pub(crate) mod Deferral;
+#[cfg(feature = "std")]
+pub(crate) mod Deferrals;
+#[cfg(feature = "std")]
+pub mod EventDelegate;
+#[cfg(feature = "std")]
+pub(crate) mod EventStream;
 #[cfg(feature = "Foundation_Numerics")]
 pub mod Numerics;
+pub(crate) mod PropertyValue;
+#[cfg(feature = "implement")]
+pub mod Reference;
 pub mod TimeSpan;
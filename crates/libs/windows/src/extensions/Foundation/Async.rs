@@ -0,0 +1,481 @@
+use crate::Foundation::*;
+use core::future::{Future, IntoFuture};
+use core::pin::Pin;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::{Context, Poll, Waker};
+use core::time::Duration;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use windows_core::{Interface, Result, RuntimeType};
+
+/// Provides a common abstraction over the WinRT async interfaces.
+///
+/// There are precisely four such interfaces: `IAsyncAction`, `IAsyncActionWithProgress`, `IAsyncOperation`,
+/// and `IAsyncOperationWithProgress`. This trait is implemented for them, and for any class whose default
+/// interface is one of them, by the generated bindings and there is thus no need to implement it. It exists
+/// so that the async adapters can be shared by all of them.
+pub trait Async: Interface + Clone {
+    /// The type of value produced on completion.
+    type Output;
+
+    /// Sets the handler to invoke when execution completes. This handler can only be set once.
+    fn set_completed<F: Fn() + Send + 'static>(&self, handler: F) -> Result<()>;
+
+    /// Returns the value produced on completion. This should only be called when execution completes.
+    fn get_results(&self) -> Result<Self::Output>;
+
+    /// Waits for this async object to complete and returns its results, dispatching COM calls and window
+    /// messages while waiting.
+    ///
+    /// Unlike `get`, this may be called from a single-threaded apartment (STA) such as a UI thread
+    /// without deadlocking when completion depends on that thread, for example when the async object
+    /// marshals its completion back to the calling apartment.
+    fn get_pumping(&self) -> Result<Self::Output> {
+        let status: IAsyncInfo = self.cast()?;
+
+        if status.Status()? == AsyncStatus::Started {
+            let (waiter, signaler) = windows_core::imp::Waiter::new()?;
+
+            self.set_completed(move || {
+                // Safe because the waiter will only be dropped after being signaled.
+                unsafe { signaler.signal() }
+            })?;
+
+            waiter.wait_pumping()?;
+        }
+
+        results(self, &status)
+    }
+
+    /// Returns a future that cancels this async object when the given token is canceled.
+    ///
+    /// Cancellation is reported by the future as an error with the `E_ABORT` code.
+    fn with_cancellation(self, token: &CancellationToken) -> AsyncFuture<Self> {
+        let mut future = AsyncFuture::new(self);
+        future.cancellation = token.register(&future.status).map(|id| (token.clone(), id));
+        future
+    }
+
+    /// Returns a future that cancels this async object if it does not complete within the given duration.
+    ///
+    /// The duration is measured from the time the future is first polled. Expiry is reported by the future
+    /// as an error with the `ERROR_TIMEOUT` code, without waiting for the async object to acknowledge the
+    /// cancellation.
+    fn timeout(self, duration: Duration) -> AsyncTimeout<Self> {
+        AsyncTimeout { future: AsyncFuture::new(self), duration, timer: None }
+    }
+}
+
+/// Provides a common abstraction over the two WinRT async interfaces that report progress.
+pub trait AsyncWithProgress: Async {
+    /// The type of value reported while execution is in flight.
+    type Progress: RuntimeType + 'static;
+
+    /// Sets the handler to invoke when progress is reported. This handler can only be set once.
+    fn set_progress<F: Fn(Self::Progress) + Send + 'static>(&self, handler: F) -> Result<()>;
+
+    /// Returns a stream of the progress values reported by this async object. The stream may be awaited
+    /// to retrieve the final result once it ends.
+    fn progress_stream(&self) -> Result<AsyncProgressStream<Self>>
+    where
+        Self::Progress: Send,
+    {
+        AsyncProgressStream::new(self)
+    }
+}
+
+/// A future that completes when the underlying WinRT async execution completes.
+///
+/// This is produced by the `IntoFuture` implementations of the WinRT async interfaces, so they can be
+/// awaited directly. Dropping the future before execution completes cancels the async object, and an
+/// async object that was canceled completes the future with an error with the `E_ABORT` code.
+///
+/// Awaiting never blocks the calling thread. Completion is signaled by the async object's `Completed`
+/// handler, which wakes whichever task most recently polled the future, so the future may be awaited from
+/// any executor, including the worker threads of runtimes like tokio and async-std, and may move between
+/// worker threads while pending.
+///
+/// The future is `Send` and `Sync` because WinRT async objects are required to be agile. Whether the
+/// output may be sent to another thread depends on the result type: runtime classes are only `Send` and
+/// `Sync` if their metadata marks them as agile, while interfaces and non-agile classes must be used on
+/// the thread that awaited them.
+pub struct AsyncFuture<A: Async> {
+    // Represents the async execution and provides `SetCompleted` and `GetResults`.
+    inner: A,
+
+    // Provides the `Status` method and saves repeated calls to `QueryInterface` during polling.
+    status: IAsyncInfo,
+
+    // The `Completed` handler can only be set once, so the waker is shared with it and replaced
+    // whenever the future is polled from a different execution context.
+    waker: Option<Arc<Mutex<Waker>>>,
+
+    // The token, and the registration with it, used to cancel the async object on request.
+    cancellation: Option<(CancellationToken, u64)>,
+}
+
+impl<A: Async> AsyncFuture<A> {
+    /// Creates a future for the given async object.
+    pub fn new(inner: A) -> Self {
+        Self {
+            // All four async interfaces require `IAsyncInfo` so this `cast` will always succeed.
+            status: inner.cast().unwrap(),
+            inner,
+            waker: None,
+            cancellation: None,
+        }
+    }
+
+    fn results(&self) -> Result<A::Output> {
+        results(&self.inner, &self.status)
+    }
+}
+
+impl<A: Async> Drop for AsyncFuture<A> {
+    fn drop(&mut self) {
+        if let Some((token, id)) = self.cancellation.take() {
+            token.unregister(id);
+        }
+
+        cancel_started(&self.status);
+    }
+}
+
+// Cancels async execution that is still in flight.
+fn cancel_started(status: &IAsyncInfo) {
+    if status.Status() == Ok(AsyncStatus::Started) {
+        _ = status.Cancel();
+    }
+}
+
+// Returns the results of completed async execution, reporting cancellation distinctly rather than
+// relying on the particular error that `GetResults` returns for a canceled async object.
+fn results<A: Async>(inner: &A, status: &IAsyncInfo) -> Result<A::Output> {
+    if status.Status()? == AsyncStatus::Canceled {
+        Err(windows_core::imp::E_ABORT.into())
+    } else {
+        inner.get_results()
+    }
+}
+
+// WinRT async objects are required to be agile.
+unsafe impl<A: Async> Send for AsyncFuture<A> {}
+unsafe impl<A: Async> Sync for AsyncFuture<A> {}
+impl<A: Async> Unpin for AsyncFuture<A> {}
+
+impl<A: Async> Future for AsyncFuture<A> {
+    type Output = Result<A::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // WinRT async is always "hot start" so anything other than `Started` means `GetResults` may be called.
+        if self.status.Status()? != AsyncStatus::Started {
+            return Poll::Ready(self.results());
+        }
+
+        if let Some(shared_waker) = &self.waker {
+            let mut guard = shared_waker.lock().unwrap();
+            guard.clone_from(cx.waker());
+
+            // The `Completed` handler may have signaled the old waker before the lock was acquired.
+            if self.status.Status()? != AsyncStatus::Started {
+                return Poll::Ready(self.results());
+            }
+        } else {
+            let shared_waker = Arc::new(Mutex::new(cx.waker().clone()));
+            self.waker = Some(shared_waker.clone());
+
+            // The handler is called even if execution has already completed, so it is safe to
+            // return `Pending` after setting it.
+            self.inner.set_completed(move || {
+                shared_waker.lock().unwrap().wake_by_ref();
+            })?;
+        }
+
+        Poll::Pending
+    }
+}
+
+/// A stream of the progress values reported by a WinRT async execution.
+///
+/// The stream ends once execution completes. The final result may then be retrieved by awaiting the
+/// stream itself, which is possible because the `Completed` handler of the async object is claimed by the
+/// stream and can no longer be used by an `AsyncFuture`. Like `AsyncFuture`, dropping the stream before
+/// execution completes cancels the async object.
+pub struct AsyncProgressStream<A: AsyncWithProgress> {
+    inner: A,
+    status: IAsyncInfo,
+    shared: Arc<Mutex<ProgressState<A::Progress>>>,
+}
+
+struct ProgressState<P> {
+    values: VecDeque<P>,
+    completed: bool,
+    waker: Option<Waker>,
+}
+
+impl<P> ProgressState<P> {
+    fn wake(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl<A: AsyncWithProgress> AsyncProgressStream<A>
+where
+    A::Progress: Send,
+{
+    fn new(inner: &A) -> Result<Self> {
+        let shared = Arc::new(Mutex::new(ProgressState { values: VecDeque::new(), completed: false, waker: None }));
+
+        let progress = shared.clone();
+        inner.set_progress(move |value| {
+            let mut state = progress.lock().unwrap();
+            state.values.push_back(value);
+            state.wake();
+        })?;
+
+        let completed = shared.clone();
+        inner.set_completed(move || {
+            let mut state = completed.lock().unwrap();
+            state.completed = true;
+            state.wake();
+        })?;
+
+        Ok(Self { inner: inner.clone(), status: inner.cast()?, shared })
+    }
+}
+
+impl<A: AsyncWithProgress> Drop for AsyncProgressStream<A> {
+    fn drop(&mut self) {
+        cancel_started(&self.status);
+    }
+}
+
+impl<A: AsyncWithProgress> AsyncProgressStream<A> {
+    /// Attempts to pull out the next progress value, returning `None` once execution has completed and
+    /// all reported values have been observed.
+    pub fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<A::Progress>> {
+        let mut state = self.shared.lock().unwrap();
+
+        if let Some(value) = state.values.pop_front() {
+            Poll::Ready(Some(value))
+        } else if state.completed {
+            Poll::Ready(None)
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+// WinRT async objects are required to be agile.
+unsafe impl<A: AsyncWithProgress> Send for AsyncProgressStream<A> where A::Progress: Send {}
+unsafe impl<A: AsyncWithProgress> Sync for AsyncProgressStream<A> where A::Progress: Send {}
+impl<A: AsyncWithProgress> Unpin for AsyncProgressStream<A> {}
+
+#[cfg(feature = "futures")]
+impl<A: AsyncWithProgress> futures_core::Stream for AsyncProgressStream<A> {
+    type Item = A::Progress;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().poll_next(cx)
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<A: AsyncWithProgress> futures_core::FusedStream for AsyncProgressStream<A> {
+    fn is_terminated(&self) -> bool {
+        let state = self.shared.lock().unwrap();
+        state.completed && state.values.is_empty()
+    }
+}
+
+impl<A: AsyncWithProgress> IntoFuture for AsyncProgressStream<A> {
+    type Output = Result<A::Output>;
+    type IntoFuture = AsyncProgressResult<A>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        AsyncProgressResult { stream: self }
+    }
+}
+
+/// A future that completes with the final result of an async execution whose progress is being streamed.
+///
+/// Any progress values that have not been observed by the time execution completes are discarded.
+pub struct AsyncProgressResult<A: AsyncWithProgress> {
+    stream: AsyncProgressStream<A>,
+}
+
+unsafe impl<A: AsyncWithProgress> Send for AsyncProgressResult<A> where A::Progress: Send {}
+unsafe impl<A: AsyncWithProgress> Sync for AsyncProgressResult<A> where A::Progress: Send {}
+impl<A: AsyncWithProgress> Unpin for AsyncProgressResult<A> {}
+
+impl<A: AsyncWithProgress> Future for AsyncProgressResult<A> {
+    type Output = Result<A::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.stream.shared.lock().unwrap();
+
+        if state.completed {
+            state.values.clear();
+            drop(state);
+            Poll::Ready(results(&self.stream.inner, &self.stream.status))
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+/// A token that may be used to cancel async execution on request.
+///
+/// Clones of a token share the same state, so the token may be handed to any number of async objects
+/// by way of `Async::with_cancellation` and canceled from anywhere.
+#[derive(Clone, Default)]
+pub struct CancellationToken(Arc<Mutex<CancellationState>>);
+
+#[derive(Default)]
+struct CancellationState {
+    canceled: bool,
+    next: u64,
+    registered: Vec<(u64, AgileAsyncInfo)>,
+}
+
+// WinRT async objects are required to be agile.
+struct AgileAsyncInfo(IAsyncInfo);
+unsafe impl Send for AgileAsyncInfo {}
+
+impl CancellationToken {
+    /// Creates a new token that has not been canceled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels any async execution registered with the token, as well as any registered in future.
+    pub fn cancel(&self) {
+        let registered = {
+            let mut state = self.0.lock().unwrap();
+            state.canceled = true;
+            core::mem::take(&mut state.registered)
+        };
+
+        // The async objects are canceled after releasing the lock as cancellation may reenter the token.
+        for (_, info) in registered {
+            cancel_started(&info.0);
+        }
+    }
+
+    /// Returns `true` if the token has been canceled.
+    pub fn is_canceled(&self) -> bool {
+        self.0.lock().unwrap().canceled
+    }
+
+    fn register(&self, info: &IAsyncInfo) -> Option<u64> {
+        let mut state = self.0.lock().unwrap();
+
+        if state.canceled {
+            drop(state);
+            cancel_started(info);
+            None
+        } else {
+            let id = state.next;
+            state.next += 1;
+            state.registered.push((id, AgileAsyncInfo(info.clone())));
+            Some(id)
+        }
+    }
+
+    fn unregister(&self, id: u64) {
+        let mut state = self.0.lock().unwrap();
+        state.registered.retain(|(registered, _)| *registered != id);
+    }
+}
+
+/// A future that races async execution against a thread pool timer.
+///
+/// This is produced by `Async::timeout`.
+pub struct AsyncTimeout<A: Async> {
+    future: AsyncFuture<A>,
+    duration: Duration,
+    timer: Option<TimeoutTimer>,
+}
+
+struct TimeoutTimer {
+    handle: windows_core::imp::PTP_TIMER,
+    state: Arc<TimeoutState>,
+}
+
+struct TimeoutState {
+    expired: AtomicBool,
+    waker: Mutex<Option<Waker>>,
+    info: AgileAsyncInfo,
+}
+
+impl TimeoutTimer {
+    fn new(duration: Duration, info: &IAsyncInfo) -> Result<Self> {
+        let state = Arc::new(TimeoutState { expired: AtomicBool::new(false), waker: Mutex::new(None), info: AgileAsyncInfo(info.clone()) });
+
+        unsafe {
+            let handle = windows_core::imp::CreateThreadpoolTimer(Some(Self::callback), Arc::as_ptr(&state) as *mut _, core::ptr::null());
+
+            if handle == 0 {
+                return Err(windows_core::Error::from_win32());
+            }
+
+            // A negative due time is relative to the current time, in 100-nanosecond intervals.
+            let due = -((duration.as_nanos() / 100).min(i64::MAX as u128) as i64);
+            let due = windows_core::imp::FILETIME { dwLowDateTime: due as u32, dwHighDateTime: (due >> 32) as u32 };
+            windows_core::imp::SetThreadpoolTimer(handle, &due, 0, 0);
+
+            Ok(Self { handle, state })
+        }
+    }
+
+    unsafe extern "system" fn callback(_instance: windows_core::imp::PTP_CALLBACK_INSTANCE, context: *mut core::ffi::c_void, _timer: windows_core::imp::PTP_TIMER) {
+        // Safe because the timer is closed, waiting for callbacks, before the state is released.
+        let state = &*(context as *const TimeoutState);
+        state.expired.store(true, Ordering::Release);
+        cancel_started(&state.info.0);
+
+        if let Some(waker) = state.waker.lock().unwrap().take() {
+            waker.wake();
+        }
+    }
+}
+
+impl Drop for TimeoutTimer {
+    fn drop(&mut self) {
+        unsafe {
+            windows_core::imp::SetThreadpoolTimer(self.handle, core::ptr::null(), 0, 0);
+            windows_core::imp::WaitForThreadpoolTimerCallbacks(self.handle, 1);
+            windows_core::imp::CloseThreadpoolTimer(self.handle);
+        }
+    }
+}
+
+unsafe impl<A: Async> Send for AsyncTimeout<A> {}
+unsafe impl<A: Async> Sync for AsyncTimeout<A> {}
+impl<A: Async> Unpin for AsyncTimeout<A> {}
+
+impl<A: Async> Future for AsyncTimeout<A> {
+    type Output = Result<A::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+
+        let state = match &this.timer {
+            Some(timer) => &timer.state,
+            None => &this.timer.insert(TimeoutTimer::new(this.duration, &this.future.status)?).state,
+        };
+
+        *state.waker.lock().unwrap() = Some(cx.waker().clone());
+        let expired = || state.expired.load(Ordering::Acquire);
+
+        match Pin::new(&mut this.future).poll(cx) {
+            Poll::Ready(Ok(value)) => Poll::Ready(Ok(value)),
+            Poll::Ready(Err(_)) | Poll::Pending if expired() => Poll::Ready(Err(windows_core::HRESULT::from_win32(windows_core::imp::ERROR_TIMEOUT).into())),
+            result => result,
+        }
+    }
+}
@@ -1,6 +1,15 @@
+pub mod Batch;
+pub mod Change;
 #[cfg(feature = "implement")]
 pub mod Iterable;
+pub mod KeyValuePair;
+pub mod Map;
 #[cfg(feature = "implement")]
 pub mod MapView;
+#[cfg(all(feature = "implement", feature = "std"))]
+pub mod ObservableVector;
+pub mod PropertySet;
+#[cfg(feature = "implement")]
+pub mod Vector;
 #[cfg(feature = "implement")]
 pub mod VectorView;
@@ -0,0 +1,64 @@
+use crate::Foundation::Collections::IVector;
+use core::cmp::Ordering;
+use windows_core::{Array, Result, RuntimeType};
+
+// These helpers read the vector with a single `GetMany` call and write it back with a single `ReplaceAll`
+// call rather than calling across the ABI for each element.
+impl<T: RuntimeType + 'static> IVector<T> {
+    /// Sorts the vector with a comparator function.
+    ///
+    /// The sort is stable and the vector is only replaced if sorting changed the order of its elements.
+    pub fn sort_by<F: FnMut(&T::Default, &T::Default) -> Ordering>(&self, mut compare: F) -> Result<()> {
+        let mut values = self.values()?;
+
+        if !values.windows(2).all(|pair| compare(&pair[0], &pair[1]) != Ordering::Greater) {
+            values.sort_by(compare);
+            self.ReplaceAll(&values)?;
+        }
+
+        Ok(())
+    }
+
+    /// Binary searches the sorted vector with a comparator function.
+    ///
+    /// Like `slice::binary_search_by`, this returns `Ok` with the index of a matching element or `Err` with the
+    /// index where a matching element could be inserted. Each probe reads a single element, so only a
+    /// logarithmic number of elements are retrieved.
+    pub fn binary_search_by<F: FnMut(&T::Default) -> Ordering>(&self, mut compare: F) -> Result<core::result::Result<usize, usize>> {
+        let mut low = 0;
+        let mut high = self.Size()?;
+
+        while low < high {
+            let middle = low + (high - low) / 2;
+            let value = Array::<T>::fill(1, |values| self.GetMany(middle, values))?;
+            let value = value.first().ok_or_else(|| windows_core::Error::from(windows_core::imp::E_BOUNDS))?;
+
+            match compare(value) {
+                Ordering::Less => low = middle + 1,
+                Ordering::Greater => high = middle,
+                Ordering::Equal => return Ok(Ok(middle as usize)),
+            }
+        }
+
+        Ok(Err(low as usize))
+    }
+
+    /// Retains only the elements for which the predicate returns `true`.
+    ///
+    /// The vector is only replaced if any elements were removed.
+    pub fn retain<F: FnMut(&T::Default) -> bool>(&self, f: F) -> Result<()> {
+        let mut values = self.values()?.into_vec();
+        let len = values.len();
+        values.retain(f);
+
+        if values.len() != len {
+            self.ReplaceAll(&values)?;
+        }
+
+        Ok(())
+    }
+
+    fn values(&self) -> Result<Array<T>> {
+        Array::fill(self.Size()? as usize, |values| self.GetMany(0, values))
+    }
+}
@@ -0,0 +1,54 @@
+use crate::Foundation::Collections::{CollectionChange, IMapChangedEventArgs, IVectorChangedEventArgs};
+use windows_core::{Result, RuntimeType};
+
+/// A change to an observable vector, as described by `IVectorChangedEventArgs`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VectorChange {
+    /// The vector changed in a way that requires reading it again.
+    Reset,
+    /// An element was inserted at the index.
+    Inserted(u32),
+    /// The element at the index was removed.
+    Removed(u32),
+    /// The element at the index was replaced.
+    Changed(u32),
+}
+
+/// A change to an observable map, as described by `IMapChangedEventArgs<K>`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MapChange<K> {
+    /// The map changed in a way that requires reading it again.
+    Reset,
+    /// The key was inserted.
+    Inserted(K),
+    /// The key was removed.
+    Removed(K),
+    /// The value for the key was replaced.
+    Changed(K),
+}
+
+impl IVectorChangedEventArgs {
+    /// Returns the change described by the event arguments.
+    pub fn change(&self) -> Result<VectorChange> {
+        Ok(match self.CollectionChange()? {
+            CollectionChange::ItemInserted => VectorChange::Inserted(self.Index()?),
+            CollectionChange::ItemRemoved => VectorChange::Removed(self.Index()?),
+            CollectionChange::ItemChanged => VectorChange::Changed(self.Index()?),
+            // Any other change is treated as a reset, which is always a correct if conservative response.
+            _ => VectorChange::Reset,
+        })
+    }
+}
+
+impl<K: RuntimeType + 'static> IMapChangedEventArgs<K> {
+    /// Returns the change described by the event arguments.
+    pub fn change(&self) -> Result<MapChange<K>> {
+        Ok(match self.CollectionChange()? {
+            CollectionChange::ItemInserted => MapChange::Inserted(self.Key()?),
+            CollectionChange::ItemRemoved => MapChange::Removed(self.Key()?),
+            CollectionChange::ItemChanged => MapChange::Changed(self.Key()?),
+            // Any other change is treated as a reset, which is always a correct if conservative response.
+            _ => MapChange::Reset,
+        })
+    }
+}
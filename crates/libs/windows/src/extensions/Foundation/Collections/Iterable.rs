@@ -87,3 +87,140 @@ where
         Ok(windows_core::ComObject::new(StockIterable { values }).into_interface())
     }
 }
+
+impl<T> FromIterator<T::Default> for IIterable<T>
+where
+    T: windows_core::RuntimeType,
+    T::Default: Clone,
+{
+    fn from_iter<I: IntoIterator<Item = T::Default>>(iter: I) -> Self {
+        // Creating a stock collection from a `Vec` never fails.
+        Self::try_from(iter.into_iter().collect::<Vec<_>>()).unwrap()
+    }
+}
+
+// The adapters require `T::Default: Clone`, like the other stock implementations, even though they don't clone
+// any values. Otherwise their `AsImpl` implementations would make `as_impl` ambiguous for user implementations
+// of `IIterable<T>` and `IIterator<T>` in generic code.
+#[windows_core::implement(IIterable<T>)]
+struct StockIterableAdapter<T>
+where
+    T: windows_core::RuntimeType + 'static,
+    T::Default: Clone,
+{
+    factory: Box<dyn Fn() -> IIterator<T> + Send + Sync>,
+}
+
+impl<T> IIterable_Impl<T> for StockIterableAdapter_Impl<T>
+where
+    T: windows_core::RuntimeType,
+    T::Default: Clone,
+{
+    fn First(&self) -> windows_core::Result<IIterator<T>> {
+        Ok((self.factory)())
+    }
+}
+
+#[windows_core::implement(IIterator<T>)]
+struct StockIteratorAdapter<T, R>
+where
+    T: windows_core::RuntimeType + 'static,
+    T::Default: Clone,
+    R: Iterator<Item = T::Default> + Send + 'static,
+{
+    state: std::sync::Mutex<AdapterState<T::Default, R>>,
+}
+
+// The adapter reads one element ahead so that it can answer `HasCurrent` and `Current` repeatedly.
+struct AdapterState<D, R> {
+    iter: R,
+    current: Option<D>,
+}
+
+impl<T, R> IIterator_Impl<T> for StockIteratorAdapter_Impl<T, R>
+where
+    T: windows_core::RuntimeType,
+    T::Default: Clone,
+    R: Iterator<Item = T::Default> + Send,
+{
+    fn Current(&self) -> windows_core::Result<T> {
+        let state = self.state.lock().unwrap();
+        let current = state.current.as_ref().ok_or_else(|| windows_core::Error::from(windows_core::imp::E_BOUNDS))?;
+        T::from_default(current)
+    }
+
+    fn HasCurrent(&self) -> windows_core::Result<bool> {
+        Ok(self.state.lock().unwrap().current.is_some())
+    }
+
+    fn MoveNext(&self) -> windows_core::Result<bool> {
+        let mut state = self.state.lock().unwrap();
+
+        if state.current.is_some() {
+            state.current = state.iter.next();
+        }
+
+        Ok(state.current.is_some())
+    }
+
+    fn GetMany(&self, values: &mut [T::Default]) -> windows_core::Result<u32> {
+        let mut state = self.state.lock().unwrap();
+        let mut actual = 0;
+
+        while actual < values.len() {
+            let Some(value) = state.current.take() else {
+                break;
+            };
+
+            values[actual] = value;
+            actual += 1;
+            state.current = state.iter.next();
+        }
+
+        Ok(actual as u32)
+    }
+}
+
+impl<T> IIterable<T>
+where
+    T: windows_core::RuntimeType,
+    T::Default: Clone,
+{
+    /// Creates an iterable that calls `f` for a new Rust iterator each time iteration starts.
+    ///
+    /// Elements are produced lazily as the WinRT iterators are advanced, so sequences can be passed to WinRT
+    /// APIs without first collecting them into a vector.
+    pub fn from_fn<I, F>(f: F) -> Self
+    where
+        I: Iterator<Item = T::Default> + Send + 'static,
+        F: Fn() -> I + Send + Sync + 'static,
+    {
+        windows_core::ComObject::new(StockIterableAdapter { factory: Box::new(move || IIterator::from_iterator(f())) }).into_interface()
+    }
+
+    /// Creates an iterable that clones the Rust iterator each time iteration starts.
+    pub fn from_cloneable<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T::Default> + Clone + Send + 'static,
+    {
+        let source = std::sync::Mutex::new(iter);
+        Self::from_fn(move || source.lock().unwrap().clone())
+    }
+}
+
+impl<T> IIterator<T>
+where
+    T: windows_core::RuntimeType,
+    T::Default: Clone,
+{
+    /// Creates a WinRT iterator over the elements of a Rust iterator.
+    ///
+    /// The first element is retrieved immediately and the rest are retrieved as the iterator is advanced.
+    pub fn from_iterator<I>(mut iter: I) -> Self
+    where
+        I: Iterator<Item = T::Default> + Send + 'static,
+    {
+        let current = iter.next();
+        windows_core::ComObject::new(StockIteratorAdapter { state: std::sync::Mutex::new(AdapterState { iter, current }) }).into_interface()
+    }
+}
@@ -0,0 +1,29 @@
+use crate::Foundation::Collections::{IIterable, IKeyValuePair};
+use windows_core::{Result, RuntimeType};
+
+impl<K: RuntimeType + 'static, V: RuntimeType + 'static> TryFrom<&IKeyValuePair<K, V>> for (K, V) {
+    type Error = windows_core::Error;
+    fn try_from(pair: &IKeyValuePair<K, V>) -> Result<Self> {
+        Ok((pair.Key()?, pair.Value()?))
+    }
+}
+
+impl<K: RuntimeType + 'static, V: RuntimeType + 'static> TryFrom<IKeyValuePair<K, V>> for (K, V) {
+    type Error = windows_core::Error;
+    fn try_from(pair: IKeyValuePair<K, V>) -> Result<Self> {
+        Self::try_from(&pair)
+    }
+}
+
+impl<K: RuntimeType + 'static, V: RuntimeType + 'static> IIterable<IKeyValuePair<K, V>> {
+    /// Returns an iterator over the pairs as `(key, value)` tuples.
+    ///
+    /// Like the `IntoIterator` implementation, iteration stops at the first pair that cannot be retrieved.
+    pub fn pairs(&self) -> impl Iterator<Item = (K, V)> {
+        pairs(self.into_iter())
+    }
+}
+
+pub(super) fn pairs<K: RuntimeType + 'static, V: RuntimeType + 'static>(iter: impl Iterator<Item = IKeyValuePair<K, V>>) -> impl Iterator<Item = (K, V)> {
+    iter.map_while(|pair| pair.try_into().ok())
+}
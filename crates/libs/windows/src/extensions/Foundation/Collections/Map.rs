@@ -0,0 +1,253 @@
+use crate::Foundation::Collections::{IMap, IMapView};
+use windows_core::{Param, Result, RuntimeType};
+
+// Maps report a missing key with `E_BOUNDS`, which the following methods translate into `None` or `false`.
+pub(super) fn missing<T>(result: Result<T>) -> Result<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(error) if error.code() == windows_core::imp::E_BOUNDS => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+impl<K: RuntimeType + 'static, V: RuntimeType + 'static> IMapView<K, V> {
+    /// Returns the value for the key, or `None` if the map doesn't contain the key.
+    pub fn get<P0: Param<K>>(&self, key: P0) -> Result<Option<V>> {
+        missing(self.Lookup(key))
+    }
+
+    /// Returns `true` if the map contains the key.
+    pub fn contains_key<P0: Param<K>>(&self, key: P0) -> Result<bool> {
+        self.HasKey(key)
+    }
+
+    /// Returns the number of entries in the map.
+    pub fn len(&self) -> Result<usize> {
+        Ok(self.Size()? as usize)
+    }
+
+    /// Returns `true` if the map contains no entries.
+    pub fn is_empty(&self) -> Result<bool> {
+        Ok(self.Size()? == 0)
+    }
+
+    /// Returns an iterator over the entries of the map as `(key, value)` tuples.
+    ///
+    /// Like the `IntoIterator` implementation, iteration stops at the first entry that cannot be retrieved.
+    pub fn iter(&self) -> impl Iterator<Item = (K, V)> {
+        super::KeyValuePair::pairs(self.into_iter())
+    }
+}
+
+impl<K: RuntimeType + 'static, V: RuntimeType + 'static> IMap<K, V> {
+    /// Returns the value for the key, or `None` if the map doesn't contain the key.
+    pub fn get<P0: Param<K>>(&self, key: P0) -> Result<Option<V>> {
+        missing(self.Lookup(key))
+    }
+
+    /// Returns `true` if the map contains the key.
+    pub fn contains_key<P0: Param<K>>(&self, key: P0) -> Result<bool> {
+        self.HasKey(key)
+    }
+
+    /// Returns the number of entries in the map.
+    pub fn len(&self) -> Result<usize> {
+        Ok(self.Size()? as usize)
+    }
+
+    /// Returns `true` if the map contains no entries.
+    pub fn is_empty(&self) -> Result<bool> {
+        Ok(self.Size()? == 0)
+    }
+
+    /// Inserts the value for the key, returning `true` if it replaced an existing value.
+    pub fn insert<P0: Param<K>, P1: Param<V>>(&self, key: P0, value: P1) -> Result<bool> {
+        self.Insert(key, value)
+    }
+
+    /// Removes the key from the map, returning `true` if the map contained the key.
+    pub fn remove<P0: Param<K>>(&self, key: P0) -> Result<bool> {
+        Ok(missing(self.Remove(key))?.is_some())
+    }
+
+    /// Returns the value for the key, first inserting the value returned by `default` if the map doesn't
+    /// contain the key.
+    pub fn get_or_insert_with<P0: Param<K> + Copy, P1: Param<V>, F: FnOnce() -> P1>(&self, key: P0, default: F) -> Result<V> {
+        if let Some(value) = self.get(key)? {
+            return Ok(value);
+        }
+
+        self.Insert(key, default())?;
+        self.Lookup(key)
+    }
+
+    /// Returns an iterator over the entries of the map as `(key, value)` tuples.
+    ///
+    /// Like the `IntoIterator` implementation, iteration stops at the first entry that cannot be retrieved.
+    pub fn iter(&self) -> impl Iterator<Item = (K, V)> {
+        super::KeyValuePair::pairs(self.into_iter())
+    }
+}
+
+#[cfg(feature = "implement")]
+mod stock {
+    use super::super::MapView::StockKeyValuePair;
+    use crate::Foundation::Collections::{IIterable, IIterable_Impl, IIterator, IKeyValuePair, IMap, IMapView, IMapView_Impl, IMap_Impl};
+    use std::collections::HashMap;
+    use std::hash::Hash;
+
+    #[windows_core::implement(IMap<K, V>, IIterable<IKeyValuePair<K, V>>)]
+    struct StockMap<K, V>
+    where
+        K: windows_core::RuntimeType + 'static,
+        V: windows_core::RuntimeType + 'static,
+        K::Default: Clone + Eq + Hash,
+        V::Default: Clone,
+    {
+        map: std::sync::RwLock<HashMap<K::Default, V::Default>>,
+    }
+
+    impl<K, V> StockMap<K, V>
+    where
+        K: windows_core::RuntimeType,
+        V: windows_core::RuntimeType,
+        K::Default: Clone + Eq + Hash,
+        V::Default: Clone,
+    {
+        fn read(&self) -> std::sync::RwLockReadGuard<'_, HashMap<K::Default, V::Default>> {
+            self.map.read().unwrap()
+        }
+
+        fn write(&self) -> std::sync::RwLockWriteGuard<'_, HashMap<K::Default, V::Default>> {
+            self.map.write().unwrap()
+        }
+
+        fn lookup(&self, key: &K::Default) -> windows_core::Result<V> {
+            let map = self.read();
+            let value = map.get(key).ok_or_else(|| windows_core::Error::from(windows_core::imp::E_BOUNDS))?;
+            V::from_default(value)
+        }
+
+        // Iterators walk a snapshot of the entries, so changes to the map don't invalidate them.
+        fn first(&self) -> windows_core::Result<IIterator<IKeyValuePair<K, V>>> {
+            let pairs: Vec<Option<IKeyValuePair<K, V>>> = self.read().iter().map(|(key, value)| Some(windows_core::ComObject::new(StockKeyValuePair { key: key.clone(), value: value.clone() }).into_interface())).collect();
+            IIterable::<IKeyValuePair<K, V>>::try_from(pairs)?.First()
+        }
+    }
+
+    impl<K, V> IIterable_Impl<IKeyValuePair<K, V>> for StockMap_Impl<K, V>
+    where
+        K: windows_core::RuntimeType,
+        V: windows_core::RuntimeType,
+        K::Default: Clone + Eq + Hash,
+        V::Default: Clone,
+    {
+        fn First(&self) -> windows_core::Result<IIterator<IKeyValuePair<K, V>>> {
+            self.first()
+        }
+    }
+
+    impl<K, V> IMap_Impl<K, V> for StockMap_Impl<K, V>
+    where
+        K: windows_core::RuntimeType,
+        V: windows_core::RuntimeType,
+        K::Default: Clone + Eq + Hash,
+        V::Default: Clone,
+    {
+        fn Lookup(&self, key: &K::Default) -> windows_core::Result<V> {
+            self.lookup(key)
+        }
+        fn Size(&self) -> windows_core::Result<u32> {
+            Ok(self.read().len().try_into()?)
+        }
+        fn HasKey(&self, key: &K::Default) -> windows_core::Result<bool> {
+            Ok(self.read().contains_key(key))
+        }
+        fn GetView(&self) -> windows_core::Result<IMapView<K, V>> {
+            use windows_core::IUnknownImpl;
+
+            Ok(windows_core::ComObject::new(StockMapViewOf { owner: self.to_object() }).into_interface())
+        }
+        fn Insert(&self, key: &K::Default, value: &V::Default) -> windows_core::Result<bool> {
+            Ok(self.write().insert(key.clone(), value.clone()).is_some())
+        }
+        fn Remove(&self, key: &K::Default) -> windows_core::Result<()> {
+            self.write().remove(key).map(|_| ()).ok_or_else(|| windows_core::Error::from(windows_core::imp::E_BOUNDS))
+        }
+        fn Clear(&self) -> windows_core::Result<()> {
+            self.write().clear();
+            Ok(())
+        }
+    }
+
+    // A view of a `StockMap` that reflects subsequent changes to the map.
+    #[windows_core::implement(IMapView<K, V>, IIterable<IKeyValuePair<K, V>>)]
+    struct StockMapViewOf<K, V>
+    where
+        K: windows_core::RuntimeType + 'static,
+        V: windows_core::RuntimeType + 'static,
+        K::Default: Clone + Eq + Hash,
+        V::Default: Clone,
+    {
+        owner: windows_core::ComObject<StockMap<K, V>>,
+    }
+
+    impl<K, V> IIterable_Impl<IKeyValuePair<K, V>> for StockMapViewOf_Impl<K, V>
+    where
+        K: windows_core::RuntimeType,
+        V: windows_core::RuntimeType,
+        K::Default: Clone + Eq + Hash,
+        V::Default: Clone,
+    {
+        fn First(&self) -> windows_core::Result<IIterator<IKeyValuePair<K, V>>> {
+            self.owner.first()
+        }
+    }
+
+    impl<K, V> IMapView_Impl<K, V> for StockMapViewOf_Impl<K, V>
+    where
+        K: windows_core::RuntimeType,
+        V: windows_core::RuntimeType,
+        K::Default: Clone + Eq + Hash,
+        V::Default: Clone,
+    {
+        fn Lookup(&self, key: &K::Default) -> windows_core::Result<V> {
+            self.owner.lookup(key)
+        }
+        fn Size(&self) -> windows_core::Result<u32> {
+            Ok(self.owner.read().len().try_into()?)
+        }
+        fn HasKey(&self, key: &K::Default) -> windows_core::Result<bool> {
+            Ok(self.owner.read().contains_key(key))
+        }
+        fn Split(&self, first: &mut Option<IMapView<K, V>>, second: &mut Option<IMapView<K, V>>) -> windows_core::Result<()> {
+            *first = None;
+            *second = None;
+            Ok(())
+        }
+    }
+
+    impl<K, V> From<HashMap<K::Default, V::Default>> for IMap<K, V>
+    where
+        K: windows_core::RuntimeType,
+        V: windows_core::RuntimeType,
+        K::Default: Clone + Eq + Hash,
+        V::Default: Clone,
+    {
+        fn from(map: HashMap<K::Default, V::Default>) -> Self {
+            windows_core::ComObject::new(StockMap { map: map.into() }).into_interface()
+        }
+    }
+
+    impl<K, V> FromIterator<(K::Default, V::Default)> for IMap<K, V>
+    where
+        K: windows_core::RuntimeType,
+        V: windows_core::RuntimeType,
+        K::Default: Clone + Eq + Hash,
+        V::Default: Clone,
+    {
+        fn from_iter<I: IntoIterator<Item = (K::Default, V::Default)>>(iter: I) -> Self {
+            iter.into_iter().collect::<HashMap<_, _>>().into()
+        }
+    }
+}
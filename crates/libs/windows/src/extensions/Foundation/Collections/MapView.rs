@@ -109,15 +109,15 @@ where
 }
 
 #[windows_core::implement(IKeyValuePair<K, V>)]
-struct StockKeyValuePair<K, V>
+pub(super) struct StockKeyValuePair<K, V>
 where
     K: windows_core::RuntimeType + 'static,
     V: windows_core::RuntimeType + 'static,
     K::Default: Clone,
     V::Default: Clone,
 {
-    key: K::Default,
-    value: V::Default,
+    pub(super) key: K::Default,
+    pub(super) value: V::Default,
 }
 
 impl<K, V> IKeyValuePair_Impl<K, V> for StockKeyValuePair_Impl<K, V>
@@ -148,3 +148,15 @@ where
         Ok(StockMapView { map }.into())
     }
 }
+
+impl<K, V> FromIterator<(K::Default, V::Default)> for IMapView<K, V>
+where
+    K: windows_core::RuntimeType,
+    V: windows_core::RuntimeType,
+    K::Default: Clone + Ord,
+    V::Default: Clone,
+{
+    fn from_iter<I: IntoIterator<Item = (K::Default, V::Default)>>(iter: I) -> Self {
+        StockMapView { map: iter.into_iter().collect() }.into()
+    }
+}
@@ -0,0 +1,160 @@
+use super::Vector::StockVector;
+use crate::Foundation::Collections::{CollectionChange, IIterable, IIterable_Impl, IIterator, IObservableVector, IObservableVector_Impl, IVector, IVectorChangedEventArgs, IVectorChangedEventArgs_Impl, IVectorView, IVector_Impl, VectorChangedEventHandler};
+use crate::Foundation::EventRegistrationToken;
+
+#[windows_core::implement(IObservableVector<T>, IVector<T>, IIterable<T>)]
+struct StockObservableVector<T>
+where
+    T: windows_core::RuntimeType + 'static,
+    T::Default: Clone + PartialEq,
+{
+    vector: windows_core::ComObject<StockVector<T>>,
+    changed: windows_core::Event<VectorChangedEventHandler<T>>,
+}
+
+impl<T> StockObservableVector_Impl<T>
+where
+    T: windows_core::RuntimeType,
+    T::Default: Clone + PartialEq,
+{
+    // Handlers are called after the vector's lock is released so that they may freely read or change the vector.
+    fn raise(&self, change: CollectionChange, index: u32) {
+        use windows_core::IUnknownImpl;
+
+        let sender: IObservableVector<T> = self.to_interface();
+        let args: IVectorChangedEventArgs = windows_core::ComObject::new(StockVectorChangedEventArgs { change, index }).into_interface();
+        self.changed.call(|handler| handler.Invoke(&sender, &args));
+    }
+}
+
+impl<T> IIterable_Impl<T> for StockObservableVector_Impl<T>
+where
+    T: windows_core::RuntimeType,
+    T::Default: Clone + PartialEq,
+{
+    fn First(&self) -> windows_core::Result<IIterator<T>> {
+        IIterable_Impl::First(&*self.vector)
+    }
+}
+
+impl<T> IVector_Impl<T> for StockObservableVector_Impl<T>
+where
+    T: windows_core::RuntimeType,
+    T::Default: Clone + PartialEq,
+{
+    fn GetAt(&self, index: u32) -> windows_core::Result<T> {
+        self.vector.GetAt(index)
+    }
+    fn Size(&self) -> windows_core::Result<u32> {
+        self.vector.Size()
+    }
+    fn GetView(&self) -> windows_core::Result<IVectorView<T>> {
+        self.vector.GetView()
+    }
+    fn IndexOf(&self, value: &T::Default, result: &mut u32) -> windows_core::Result<bool> {
+        self.vector.IndexOf(value, result)
+    }
+    fn SetAt(&self, index: u32, value: &T::Default) -> windows_core::Result<()> {
+        self.vector.SetAt(index, value)?;
+        self.raise(CollectionChange::ItemChanged, index);
+        Ok(())
+    }
+    fn InsertAt(&self, index: u32, value: &T::Default) -> windows_core::Result<()> {
+        self.vector.InsertAt(index, value)?;
+        self.raise(CollectionChange::ItemInserted, index);
+        Ok(())
+    }
+    fn RemoveAt(&self, index: u32) -> windows_core::Result<()> {
+        self.vector.RemoveAt(index)?;
+        self.raise(CollectionChange::ItemRemoved, index);
+        Ok(())
+    }
+    fn Append(&self, value: &T::Default) -> windows_core::Result<()> {
+        let index = {
+            let mut values = self.vector.write();
+            values.push(value.clone());
+            values.len() - 1
+        };
+
+        self.raise(CollectionChange::ItemInserted, index as u32);
+        Ok(())
+    }
+    fn RemoveAtEnd(&self) -> windows_core::Result<()> {
+        let index = {
+            let mut values = self.vector.write();
+            values.pop().ok_or_else(|| windows_core::Error::from(windows_core::imp::E_BOUNDS))?;
+            values.len()
+        };
+
+        self.raise(CollectionChange::ItemRemoved, index as u32);
+        Ok(())
+    }
+    fn Clear(&self) -> windows_core::Result<()> {
+        self.vector.Clear()?;
+        self.raise(CollectionChange::Reset, 0);
+        Ok(())
+    }
+    fn GetMany(&self, current: u32, values: &mut [T::Default]) -> windows_core::Result<u32> {
+        self.vector.GetMany(current, values)
+    }
+    fn ReplaceAll(&self, values: &[T::Default]) -> windows_core::Result<()> {
+        self.vector.ReplaceAll(values)?;
+        self.raise(CollectionChange::Reset, 0);
+        Ok(())
+    }
+}
+
+impl<T> IObservableVector_Impl<T> for StockObservableVector_Impl<T>
+where
+    T: windows_core::RuntimeType,
+    T::Default: Clone + PartialEq,
+{
+    fn VectorChanged(&self, handler: Option<&VectorChangedEventHandler<T>>) -> windows_core::Result<EventRegistrationToken> {
+        let handler = handler.ok_or_else(|| windows_core::Error::from(windows_core::imp::E_POINTER))?;
+        Ok(EventRegistrationToken { Value: self.changed.add(handler)? })
+    }
+    fn RemoveVectorChanged(&self, token: &EventRegistrationToken) -> windows_core::Result<()> {
+        self.changed.remove(token.Value);
+        Ok(())
+    }
+}
+
+#[windows_core::implement(IVectorChangedEventArgs)]
+struct StockVectorChangedEventArgs {
+    change: CollectionChange,
+    index: u32,
+}
+
+impl IVectorChangedEventArgs_Impl for StockVectorChangedEventArgs_Impl {
+    fn CollectionChange(&self) -> windows_core::Result<CollectionChange> {
+        Ok(self.change)
+    }
+    fn Index(&self) -> windows_core::Result<u32> {
+        Ok(self.index)
+    }
+}
+
+/// Creates an observable vector that raises the `VectorChanged` event whenever it is changed.
+///
+/// The event is raised on the thread making the change, after the change is made. Inserting, removing, or
+/// replacing a single element reports the element's index, while `Clear` and `ReplaceAll` report a `Reset`.
+/// An `IObservableVector<IInspectable>` may be used as the `ItemsSource` of a XAML items control.
+impl<T> From<Vec<T::Default>> for IObservableVector<T>
+where
+    T: windows_core::RuntimeType,
+    T::Default: Clone + PartialEq,
+{
+    fn from(values: Vec<T::Default>) -> Self {
+        windows_core::ComObject::new(StockObservableVector { vector: windows_core::ComObject::new(StockVector::new(values)), changed: windows_core::Event::new() }).into_interface()
+    }
+}
+
+impl<T> FromIterator<T::Default> for IObservableVector<T>
+where
+    T: windows_core::RuntimeType,
+    T::Default: Clone + PartialEq,
+{
+    fn from_iter<I: IntoIterator<Item = T::Default>>(iter: I) -> Self {
+        iter.into_iter().collect::<Vec<_>>().into()
+    }
+}
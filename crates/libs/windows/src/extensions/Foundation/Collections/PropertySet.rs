@@ -0,0 +1,144 @@
+use crate::Foundation::Collections::{PropertySet, ValueSet};
+use crate::Foundation::*;
+use windows_core::{IInspectable, Interface, Result, GUID, HSTRING};
+
+/// A value stored in a `PropertySet` or `ValueSet`.
+///
+/// Scalar property values are unboxed into the matching variant, while arrays and any other objects are kept
+/// as `Inspectable`.
+#[derive(Clone, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub enum Value {
+    UInt8(u8),
+    Int16(i16),
+    UInt16(u16),
+    Int32(i32),
+    UInt32(u32),
+    Int64(i64),
+    UInt64(u64),
+    Single(f32),
+    Double(f64),
+    Char16(u16),
+    Boolean(bool),
+    String(HSTRING),
+    Guid(GUID),
+    DateTime(DateTime),
+    TimeSpan(TimeSpan),
+    Point(Point),
+    Size(Size),
+    Rect(Rect),
+    Inspectable(IInspectable),
+}
+
+impl Boxable for Value {
+    fn box_value(&self) -> Result<IInspectable> {
+        match self {
+            Self::UInt8(value) => PropertyValue::CreateUInt8(*value),
+            Self::Int16(value) => PropertyValue::CreateInt16(*value),
+            Self::UInt16(value) => PropertyValue::CreateUInt16(*value),
+            Self::Int32(value) => PropertyValue::CreateInt32(*value),
+            Self::UInt32(value) => PropertyValue::CreateUInt32(*value),
+            Self::Int64(value) => PropertyValue::CreateInt64(*value),
+            Self::UInt64(value) => PropertyValue::CreateUInt64(*value),
+            Self::Single(value) => PropertyValue::CreateSingle(*value),
+            Self::Double(value) => PropertyValue::CreateDouble(*value),
+            Self::Char16(value) => PropertyValue::CreateChar16(*value),
+            Self::Boolean(value) => PropertyValue::CreateBoolean(*value),
+            Self::String(value) => PropertyValue::CreateString(value),
+            Self::Guid(value) => PropertyValue::CreateGuid(*value),
+            Self::DateTime(value) => PropertyValue::CreateDateTime(*value),
+            Self::TimeSpan(value) => PropertyValue::CreateTimeSpan(*value),
+            Self::Point(value) => PropertyValue::CreatePoint(*value),
+            Self::Size(value) => PropertyValue::CreateSize(*value),
+            Self::Rect(value) => PropertyValue::CreateRect(*value),
+            Self::Inspectable(value) => Ok(value.clone()),
+        }
+    }
+
+    fn unbox_value(value: &IInspectable) -> Result<Self> {
+        let Ok(property) = value.cast::<IPropertyValue>() else {
+            return Ok(Self::Inspectable(value.clone()));
+        };
+
+        Ok(match property.Type()? {
+            PropertyType::UInt8 => Self::UInt8(property.GetUInt8()?),
+            PropertyType::Int16 => Self::Int16(property.GetInt16()?),
+            PropertyType::UInt16 => Self::UInt16(property.GetUInt16()?),
+            PropertyType::Int32 => Self::Int32(property.GetInt32()?),
+            PropertyType::UInt32 => Self::UInt32(property.GetUInt32()?),
+            PropertyType::Int64 => Self::Int64(property.GetInt64()?),
+            PropertyType::UInt64 => Self::UInt64(property.GetUInt64()?),
+            PropertyType::Single => Self::Single(property.GetSingle()?),
+            PropertyType::Double => Self::Double(property.GetDouble()?),
+            PropertyType::Char16 => Self::Char16(property.GetChar16()?),
+            PropertyType::Boolean => Self::Boolean(property.GetBoolean()?),
+            PropertyType::String => Self::String(property.GetString()?),
+            PropertyType::Guid => Self::Guid(property.GetGuid()?),
+            PropertyType::DateTime => Self::DateTime(property.GetDateTime()?),
+            PropertyType::TimeSpan => Self::TimeSpan(property.GetTimeSpan()?),
+            PropertyType::Point => Self::Point(property.GetPoint()?),
+            PropertyType::Size => Self::Size(property.GetSize()?),
+            PropertyType::Rect => Self::Rect(property.GetRect()?),
+            _ => Self::Inspectable(value.clone()),
+        })
+    }
+}
+
+macro_rules! property_set {
+    ($($set:ident)*) => {
+        $(
+            impl $set {
+                /// Returns the value for the key unboxed as `T`, or `None` if the set doesn't contain the key.
+                pub fn get_t<T: Boxable>(&self, key: &HSTRING) -> Result<Option<T>> {
+                    match super::Map::missing(self.Lookup(key))? {
+                        Some(value) => Ok(Some(T::unbox_value(&value)?)),
+                        None => Ok(None),
+                    }
+                }
+
+                /// Boxes the value and inserts it for the key, returning `true` if it replaced an existing value.
+                pub fn insert_t<T: Boxable>(&self, key: &HSTRING, value: T) -> Result<bool> {
+                    self.Insert(key, &value.box_value()?)
+                }
+
+                /// Returns an iterator over the entries of the set as `(key, value)` tuples.
+                ///
+                /// Fails if the iterator cannot be created. Iteration then stops at the first entry that cannot be retrieved.
+                pub fn iter(&self) -> Result<impl Iterator<Item = (HSTRING, IInspectable)>> {
+                    Ok(super::KeyValuePair::pairs(self.First()?))
+                }
+            }
+
+            #[cfg(feature = "std")]
+            impl TryFrom<&$set> for std::collections::HashMap<String, Value> {
+                type Error = windows_core::Error;
+                fn try_from(set: &$set) -> Result<Self> {
+                    let mut map = Self::new();
+
+                    for pair in set.First()? {
+                        let (key, value) = pair.try_into()?;
+                        map.insert(String::try_from(key)?, Value::unbox_value(&value)?);
+                    }
+
+                    Ok(map)
+                }
+            }
+
+            #[cfg(feature = "std")]
+            impl TryFrom<std::collections::HashMap<String, Value>> for $set {
+                type Error = windows_core::Error;
+                fn try_from(map: std::collections::HashMap<String, Value>) -> Result<Self> {
+                    let set = Self::new()?;
+
+                    for (key, value) in map {
+                        set.Insert(&HSTRING::from(key), &value.box_value()?)?;
+                    }
+
+                    Ok(set)
+                }
+            }
+        )*
+    };
+}
+
+property_set!(PropertySet ValueSet);
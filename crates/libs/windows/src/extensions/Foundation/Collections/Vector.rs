@@ -0,0 +1,269 @@
+use crate::Foundation::Collections::{IIterable, IIterable_Impl, IIterator, IIterator_Impl, IVector, IVectorView, IVectorView_Impl, IVector_Impl};
+
+#[windows_core::implement(IVector<T>, IIterable<T>)]
+pub(super) struct StockVector<T>
+where
+    T: windows_core::RuntimeType + 'static,
+    T::Default: Clone + PartialEq,
+{
+    values: std::sync::RwLock<Vec<T::Default>>,
+}
+
+impl<T> StockVector<T>
+where
+    T: windows_core::RuntimeType,
+    T::Default: Clone + PartialEq,
+{
+    pub(super) fn new(values: Vec<T::Default>) -> Self {
+        Self { values: values.into() }
+    }
+
+    pub(super) fn read(&self) -> std::sync::RwLockReadGuard<'_, Vec<T::Default>> {
+        self.values.read().unwrap()
+    }
+
+    pub(super) fn write(&self) -> std::sync::RwLockWriteGuard<'_, Vec<T::Default>> {
+        self.values.write().unwrap()
+    }
+
+    fn get_at(&self, index: u32) -> windows_core::Result<T> {
+        let values = self.read();
+        let item = values.get(index as usize).ok_or_else(|| windows_core::Error::from(windows_core::imp::E_BOUNDS))?;
+        T::from_default(item)
+    }
+
+    fn size(&self) -> windows_core::Result<u32> {
+        Ok(self.read().len().try_into()?)
+    }
+
+    fn index_of(&self, value: &T::Default, result: &mut u32) -> windows_core::Result<bool> {
+        match self.read().iter().position(|element| element == value) {
+            Some(index) => {
+                *result = index as u32;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    fn get_many(&self, current: u32, values: &mut [T::Default]) -> windows_core::Result<u32> {
+        let source = self.read();
+        let current = current as usize;
+        if current >= source.len() {
+            return Ok(0);
+        }
+        let actual = std::cmp::min(source.len() - current, values.len());
+        let (values, _) = values.split_at_mut(actual);
+        values.clone_from_slice(&source[current..current + actual]);
+        Ok(actual as u32)
+    }
+}
+
+impl<T> IIterable_Impl<T> for StockVector_Impl<T>
+where
+    T: windows_core::RuntimeType,
+    T::Default: Clone + PartialEq,
+{
+    fn First(&self) -> windows_core::Result<IIterator<T>> {
+        use windows_core::IUnknownImpl;
+
+        Ok(windows_core::ComObject::new(StockVectorIterator { owner: self.to_object(), current: 0.into() }).into_interface())
+    }
+}
+
+impl<T> IVector_Impl<T> for StockVector_Impl<T>
+where
+    T: windows_core::RuntimeType,
+    T::Default: Clone + PartialEq,
+{
+    fn GetAt(&self, index: u32) -> windows_core::Result<T> {
+        self.get_at(index)
+    }
+    fn Size(&self) -> windows_core::Result<u32> {
+        self.size()
+    }
+    fn GetView(&self) -> windows_core::Result<IVectorView<T>> {
+        use windows_core::IUnknownImpl;
+
+        Ok(windows_core::ComObject::new(StockVectorViewOf { owner: self.to_object() }).into_interface())
+    }
+    fn IndexOf(&self, value: &T::Default, result: &mut u32) -> windows_core::Result<bool> {
+        self.index_of(value, result)
+    }
+    fn SetAt(&self, index: u32, value: &T::Default) -> windows_core::Result<()> {
+        let mut values = self.write();
+        let item = values.get_mut(index as usize).ok_or_else(|| windows_core::Error::from(windows_core::imp::E_BOUNDS))?;
+        *item = value.clone();
+        Ok(())
+    }
+    fn InsertAt(&self, index: u32, value: &T::Default) -> windows_core::Result<()> {
+        let mut values = self.write();
+        if index as usize > values.len() {
+            return Err(windows_core::Error::from(windows_core::imp::E_BOUNDS));
+        }
+        values.insert(index as usize, value.clone());
+        Ok(())
+    }
+    fn RemoveAt(&self, index: u32) -> windows_core::Result<()> {
+        let mut values = self.write();
+        if index as usize >= values.len() {
+            return Err(windows_core::Error::from(windows_core::imp::E_BOUNDS));
+        }
+        values.remove(index as usize);
+        Ok(())
+    }
+    fn Append(&self, value: &T::Default) -> windows_core::Result<()> {
+        self.write().push(value.clone());
+        Ok(())
+    }
+    fn RemoveAtEnd(&self) -> windows_core::Result<()> {
+        self.write().pop().map(|_| ()).ok_or_else(|| windows_core::Error::from(windows_core::imp::E_BOUNDS))
+    }
+    fn Clear(&self) -> windows_core::Result<()> {
+        self.write().clear();
+        Ok(())
+    }
+    fn GetMany(&self, current: u32, values: &mut [T::Default]) -> windows_core::Result<u32> {
+        self.get_many(current, values)
+    }
+    fn ReplaceAll(&self, values: &[T::Default]) -> windows_core::Result<()> {
+        *self.write() = values.to_vec();
+        Ok(())
+    }
+}
+
+// A view of a `StockVector` that reflects subsequent changes to the vector.
+#[windows_core::implement(IVectorView<T>, IIterable<T>)]
+struct StockVectorViewOf<T>
+where
+    T: windows_core::RuntimeType + 'static,
+    T::Default: Clone + PartialEq,
+{
+    owner: windows_core::ComObject<StockVector<T>>,
+}
+
+impl<T> IIterable_Impl<T> for StockVectorViewOf_Impl<T>
+where
+    T: windows_core::RuntimeType,
+    T::Default: Clone + PartialEq,
+{
+    fn First(&self) -> windows_core::Result<IIterator<T>> {
+        Ok(windows_core::ComObject::new(StockVectorIterator { owner: self.owner.clone(), current: 0.into() }).into_interface())
+    }
+}
+
+impl<T> IVectorView_Impl<T> for StockVectorViewOf_Impl<T>
+where
+    T: windows_core::RuntimeType,
+    T::Default: Clone + PartialEq,
+{
+    fn GetAt(&self, index: u32) -> windows_core::Result<T> {
+        self.owner.get_at(index)
+    }
+    fn Size(&self) -> windows_core::Result<u32> {
+        self.owner.size()
+    }
+    fn IndexOf(&self, value: &T::Default, result: &mut u32) -> windows_core::Result<bool> {
+        self.owner.index_of(value, result)
+    }
+    fn GetMany(&self, current: u32, values: &mut [T::Default]) -> windows_core::Result<u32> {
+        self.owner.get_many(current, values)
+    }
+}
+
+#[windows_core::implement(IIterator<T>)]
+struct StockVectorIterator<T>
+where
+    T: windows_core::RuntimeType + 'static,
+    T::Default: Clone + PartialEq,
+{
+    owner: windows_core::ComObject<StockVector<T>>,
+    current: std::sync::atomic::AtomicUsize,
+}
+
+impl<T> IIterator_Impl<T> for StockVectorIterator_Impl<T>
+where
+    T: windows_core::RuntimeType,
+    T::Default: Clone + PartialEq,
+{
+    fn Current(&self) -> windows_core::Result<T> {
+        let current = self.current.load(std::sync::atomic::Ordering::Relaxed);
+        self.owner.get_at(current as u32)
+    }
+
+    fn HasCurrent(&self) -> windows_core::Result<bool> {
+        let current = self.current.load(std::sync::atomic::Ordering::Relaxed);
+        Ok(self.owner.read().len() > current)
+    }
+
+    fn MoveNext(&self) -> windows_core::Result<bool> {
+        let len = self.owner.read().len();
+        let current = self.current.load(std::sync::atomic::Ordering::Relaxed);
+
+        if current < len {
+            self.current.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        }
+
+        Ok(len > current + 1)
+    }
+
+    fn GetMany(&self, values: &mut [T::Default]) -> windows_core::Result<u32> {
+        let current = self.current.load(std::sync::atomic::Ordering::Relaxed);
+        let actual = self.owner.get_many(current as u32, values)?;
+        self.current.fetch_add(actual as usize, std::sync::atomic::Ordering::Relaxed);
+        Ok(actual)
+    }
+}
+
+impl<T> From<Vec<T::Default>> for IVector<T>
+where
+    T: windows_core::RuntimeType,
+    T::Default: Clone + PartialEq,
+{
+    fn from(values: Vec<T::Default>) -> Self {
+        windows_core::ComObject::new(StockVector::new(values)).into_interface()
+    }
+}
+
+impl<T> FromIterator<T::Default> for IVector<T>
+where
+    T: windows_core::RuntimeType,
+    T::Default: Clone + PartialEq,
+{
+    fn from_iter<I: IntoIterator<Item = T::Default>>(iter: I) -> Self {
+        iter.into_iter().collect::<Vec<_>>().into()
+    }
+}
+
+impl<T> IVector<T>
+where
+    T: windows_core::RuntimeType,
+{
+    /// Appends the values to the vector, stopping at the first value that the vector fails to append.
+    pub fn try_extend<I: IntoIterator<Item = T::Default>>(&self, iter: I) -> windows_core::Result<()> {
+        for value in iter {
+            // Only a `None` interface fails to convert, and is appended as a null value.
+            match T::from_default(&value) {
+                Ok(value) => self.Append(Some(&value))?,
+                Err(_) => self.Append(None)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Appends the values to the vector. Use `IVector::try_extend` to handle failures instead.
+///
+/// # Panics
+///
+/// Panics if the vector fails to append a value, such as when it is read-only. Vectors created from a `Vec`
+/// never fail.
+impl<T> Extend<T::Default> for IVector<T>
+where
+    T: windows_core::RuntimeType,
+{
+    fn extend<I: IntoIterator<Item = T::Default>>(&mut self, iter: I) {
+        self.try_extend(iter).unwrap();
+    }
+}
@@ -116,3 +116,14 @@ where
         Ok(windows_core::ComObject::new(StockVectorView { values }).into_interface())
     }
 }
+
+impl<T> FromIterator<T::Default> for IVectorView<T>
+where
+    T: windows_core::RuntimeType,
+    T::Default: Clone + PartialEq,
+{
+    fn from_iter<I: IntoIterator<Item = T::Default>>(iter: I) -> Self {
+        // Creating a stock collection from a `Vec` never fails.
+        Self::try_from(iter.into_iter().collect::<Vec<_>>()).unwrap()
+    }
+}
@@ -0,0 +1,100 @@
+use crate::Foundation::DateTime;
+
+// `UniversalTime` counts 100-nanosecond intervals since January 1, 1601, just like `FILETIME`.
+const UNIX_EPOCH_TICKS: i128 = 116_444_736_000_000_000;
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+impl DateTime {
+    // Returns the nanoseconds since the Unix epoch, which may be negative.
+    #[cfg(any(feature = "std", feature = "chrono", feature = "time"))]
+    fn unix_nanos(&self) -> i128 {
+        (self.UniversalTime as i128 - UNIX_EPOCH_TICKS) * 100
+    }
+
+    // Creates a `DateTime` from nanoseconds since the Unix epoch, rounded down to a multiple of 100 nanoseconds.
+    #[cfg(any(feature = "std", feature = "chrono", feature = "time"))]
+    fn from_unix_nanos(nanos: i128) -> windows_core::Result<Self> {
+        let ticks = nanos.div_euclid(100) + UNIX_EPOCH_TICKS;
+        i64::try_from(ticks).map(|UniversalTime| Self { UniversalTime }).map_err(|_| windows_core::Error::from(windows_core::imp::E_INVALIDARG))
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<DateTime> for std::time::SystemTime {
+    type Error = windows_core::Error;
+    fn try_from(value: DateTime) -> windows_core::Result<Self> {
+        let nanos = value.unix_nanos();
+        let magnitude = nanos.unsigned_abs();
+        let duration = core::time::Duration::new((magnitude / NANOS_PER_SECOND as u128) as u64, (magnitude % NANOS_PER_SECOND as u128) as u32);
+
+        let time = if nanos < 0 { Self::UNIX_EPOCH.checked_sub(duration) } else { Self::UNIX_EPOCH.checked_add(duration) };
+        time.ok_or_else(|| windows_core::Error::from(windows_core::imp::E_INVALIDARG))
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<std::time::SystemTime> for DateTime {
+    type Error = windows_core::Error;
+    /// Converts a time, rounded down to a multiple of 100 nanoseconds.
+    fn try_from(value: std::time::SystemTime) -> windows_core::Result<Self> {
+        let nanos = match value.duration_since(std::time::SystemTime::UNIX_EPOCH) {
+            Ok(after) => after.as_nanos() as i128,
+            Err(before) => -(before.duration().as_nanos() as i128),
+        };
+
+        Self::from_unix_nanos(nanos)
+    }
+}
+
+#[cfg(feature = "Win32_Foundation")]
+impl TryFrom<crate::Win32::Foundation::FILETIME> for DateTime {
+    type Error = windows_core::Error;
+    fn try_from(value: crate::Win32::Foundation::FILETIME) -> windows_core::Result<Self> {
+        i64::try_from(value.ticks()).map(|UniversalTime| Self { UniversalTime }).map_err(|_| windows_core::Error::from(windows_core::imp::E_INVALIDARG))
+    }
+}
+
+#[cfg(feature = "Win32_Foundation")]
+impl TryFrom<DateTime> for crate::Win32::Foundation::FILETIME {
+    type Error = windows_core::Error;
+    fn try_from(value: DateTime) -> windows_core::Result<Self> {
+        u64::try_from(value.UniversalTime).map(Self::from_ticks).map_err(|_| windows_core::Error::from(windows_core::imp::E_INVALIDARG))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<DateTime> for chrono::DateTime<chrono::Utc> {
+    fn from(value: DateTime) -> Self {
+        let nanos = value.unix_nanos();
+        // Every `DateTime` falls well within the range of `chrono::DateTime`.
+        Self::from_timestamp(nanos.div_euclid(NANOS_PER_SECOND) as i64, nanos.rem_euclid(NANOS_PER_SECOND) as u32).unwrap()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> TryFrom<chrono::DateTime<Tz>> for DateTime {
+    type Error = windows_core::Error;
+    /// Converts the time to UTC, rounded down to a multiple of 100 nanoseconds.
+    fn try_from(value: chrono::DateTime<Tz>) -> windows_core::Result<Self> {
+        Self::from_unix_nanos(value.timestamp() as i128 * NANOS_PER_SECOND + value.timestamp_subsec_nanos() as i128)
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<DateTime> for time::OffsetDateTime {
+    type Error = windows_core::Error;
+    /// Converts the time, which fails for years outside 1..=9999 unless the `time` crate's `large-dates` feature is
+    /// enabled.
+    fn try_from(value: DateTime) -> windows_core::Result<Self> {
+        Self::from_unix_timestamp_nanos(value.unix_nanos()).map_err(|_| windows_core::Error::from(windows_core::imp::E_INVALIDARG))
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<time::OffsetDateTime> for DateTime {
+    type Error = windows_core::Error;
+    /// Converts the time to UTC, rounded down to a multiple of 100 nanoseconds.
+    fn try_from(value: time::OffsetDateTime) -> windows_core::Result<Self> {
+        Self::from_unix_nanos(value.unix_timestamp_nanos())
+    }
+}
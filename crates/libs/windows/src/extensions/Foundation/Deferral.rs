@@ -0,0 +1,57 @@
+use core::future::Future;
+use windows_core::Result;
+
+/// Completes a WinRT deferral when dropped.
+///
+/// Event handlers for events such as `Suspending`, `BackgroundActivated`, or `DataRequested` take a
+/// deferral to keep the operation alive until some asynchronous work is done. The guard pairs the
+/// deferral with its completion method so that the deferral is completed exactly once, including when
+/// the handler returns early or the work is abandoned:
+///
+/// ```rust,ignore
+/// let deferral = DeferralGuard::new(args.SuspendingOperation()?.GetDeferral()?, SuspendingDeferral::Complete);
+/// ```
+pub struct DeferralGuard<D> {
+    deferral: Option<D>,
+    complete: fn(&D) -> Result<()>,
+}
+
+impl<D> DeferralGuard<D> {
+    /// Creates a guard that calls `complete` on the deferral when dropped.
+    pub fn new(deferral: D, complete: fn(&D) -> Result<()>) -> Self {
+        Self { deferral: Some(deferral), complete }
+    }
+
+    /// Returns the deferral.
+    pub fn deferral(&self) -> &D {
+        // The deferral is only taken by `complete` or `drop`, both of which consume the guard.
+        self.deferral.as_ref().unwrap()
+    }
+
+    /// Completes the deferral now, returning any error from the completion method.
+    pub fn complete(mut self) -> Result<()> {
+        self.take()
+    }
+
+    /// Completes the deferral once the given future has finished.
+    ///
+    /// The deferral is also completed if the returned future is dropped before finishing.
+    pub async fn complete_after<F: Future>(self, future: F) -> F::Output {
+        let output = future.await;
+        drop(self);
+        output
+    }
+
+    fn take(&mut self) -> Result<()> {
+        match self.deferral.take() {
+            Some(deferral) => (self.complete)(&deferral),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<D> Drop for DeferralGuard<D> {
+    fn drop(&mut self) {
+        _ = self.take();
+    }
+}
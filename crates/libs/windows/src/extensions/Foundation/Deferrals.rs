@@ -0,0 +1,121 @@
+use crate::Foundation::*;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use std::sync::{Arc, Mutex};
+use windows_core::{Event, Interface, Result};
+
+/// Tracks the deferrals taken by the handlers of an event whose arguments are implemented in Rust, so that
+/// the event source can wait for handlers that complete asynchronously, much like `deferrable_event_args`
+/// in C++/WinRT.
+///
+/// The event arguments hand out deferrals with `get_deferral` and the event source raises the event with
+/// `call`, which returns a future that completes once every handler has returned and every deferral has
+/// been completed:
+///
+/// ```rust,ignore
+/// #[implement(ClosingEventArgs)]
+/// struct Closing {
+///     deferrals: Deferrals,
+/// }
+///
+/// impl IClosingEventArgs_Impl for Closing_Impl {
+///     fn GetDeferral(&self) -> Result<Deferral> {
+///         self.deferrals.get_deferral()
+///     }
+/// }
+///
+/// let deferrals = Deferrals::new();
+/// let args: ClosingEventArgs = Closing { deferrals: deferrals.clone() }.into();
+/// deferrals.call(&self.closing, |handler| handler.Invoke(&sender, &args)).await?;
+/// ```
+#[derive(Clone, Default)]
+pub struct Deferrals {
+    shared: Arc<Mutex<DeferralState>>,
+}
+
+#[derive(Default)]
+struct DeferralState {
+    pending: usize,
+    waker: Option<Waker>,
+}
+
+impl Deferrals {
+    /// Creates a tracker without any pending deferrals.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new deferral that must be completed before the future returned by `call` or `wait`
+    /// completes. Completing the same deferral more than once has no further effect.
+    pub fn get_deferral(&self) -> Result<Deferral> {
+        self.shared.lock().unwrap().pending += 1;
+        let shared = self.shared.clone();
+        let mut completed = false;
+
+        let deferral = Deferral::Create(&DeferralCompletedHandler::new(move || {
+            if !core::mem::replace(&mut completed, true) {
+                Self::complete(&shared);
+            }
+            Ok(())
+        }));
+
+        if deferral.is_err() {
+            Self::complete(&self.shared);
+        }
+
+        deferral
+    }
+
+    /// Invokes the event's delegates with the provided callback, as `Event::try_call` does, and returns a
+    /// future that completes once all of the deferrals taken by the delegates have been completed.
+    ///
+    /// The delegates are invoked before `call` returns. The future's output is the first error returned by
+    /// a delegate, if any.
+    pub fn call<T: Interface, F: FnMut(&T) -> Result<()>>(&self, event: &Event<T>, callback: F) -> DeferralFuture {
+        let result = event.try_call(callback);
+        DeferralFuture { shared: self.shared.clone(), result: Some(result) }
+    }
+
+    /// Returns a future that completes once all of the deferrals taken so far, and any taken before it
+    /// completes, have been completed.
+    pub fn wait(&self) -> DeferralFuture {
+        DeferralFuture { shared: self.shared.clone(), result: Some(Ok(())) }
+    }
+
+    fn complete(shared: &Mutex<DeferralState>) {
+        let mut state = shared.lock().unwrap();
+        state.pending -= 1;
+
+        if state.pending == 0 {
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+/// A future that completes once the deferrals tracked by `Deferrals` have been completed.
+///
+/// This is produced by `Deferrals::call` and `Deferrals::wait`.
+pub struct DeferralFuture {
+    shared: Arc<Mutex<DeferralState>>,
+    result: Option<Result<()>>,
+}
+
+impl Future for DeferralFuture {
+    type Output = Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.shared.lock().unwrap();
+
+        if state.pending != 0 {
+            state.waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+
+        drop(state);
+        // A future must not be polled again after it has completed.
+        Poll::Ready(self.result.take().expect("`DeferralFuture` polled after completion"))
+    }
+}
@@ -0,0 +1,26 @@
+use crate::Foundation::*;
+use windows_core::{EventDelegate, IInspectable, Result, RuntimeType, Type};
+
+impl<TSender: RuntimeType + 'static, TResult: RuntimeType + 'static> EventDelegate for TypedEventHandler<TSender, TResult> {
+    type Sender = TSender;
+    type Args = TResult;
+
+    fn from_fn<F>(callback: F) -> Self
+    where
+        F: FnMut(&<TSender as Type<TSender>>::Default, &<TResult as Type<TResult>>::Default) -> Result<()> + Send + 'static,
+    {
+        Self::new(callback)
+    }
+}
+
+impl<T: RuntimeType + 'static> EventDelegate for EventHandler<T> {
+    type Sender = IInspectable;
+    type Args = T;
+
+    fn from_fn<F>(mut callback: F) -> Self
+    where
+        F: FnMut(&Option<IInspectable>, &<T as Type<T>>::Default) -> Result<()> + Send + 'static,
+    {
+        Self::new(move |sender, args| callback(&sender.cloned(), args))
+    }
+}
@@ -0,0 +1,134 @@
+use crate::Foundation::*;
+use core::task::{Context, Poll, Waker};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use windows_core::{Result, RuntimeType, Type};
+
+/// A stream of the arguments passed to a WinRT event.
+///
+/// The stream registers a handler for the event when created and removes it when dropped, so push-based
+/// APIs such as device watchers can be consumed by polling:
+///
+/// ```rust,ignore
+/// let watcher = DeviceInformation::CreateWatcher()?;
+/// let mut added = EventStream::new(|handler| watcher.Added(handler), |token| watcher.RemoveAdded(token))?;
+/// watcher.Start()?;
+///
+/// while let Some(info) = added.next().await {
+///     println!("{}", info.unwrap().Name()?);
+/// }
+/// ```
+///
+/// Event arguments are queued until they are polled, and the stream never ends on its own.
+pub struct EventStream<T, R: FnOnce(EventRegistrationToken) -> Result<()>> {
+    shared: Arc<Mutex<EventState<T>>>,
+    registration: Option<(EventRegistrationToken, R)>,
+}
+
+struct EventState<T> {
+    values: VecDeque<T>,
+    waker: Option<Waker>,
+}
+
+impl<T: Send + 'static, R: FnOnce(EventRegistrationToken) -> Result<()>> EventStream<T, R> {
+    /// Creates a stream for an event whose handler is a `TypedEventHandler`.
+    ///
+    /// The `add` closure registers the given handler and the `remove` closure unregisters it when the stream
+    /// is dropped.
+    pub fn new<S, A, F>(add: F, remove: R) -> Result<Self>
+    where
+        S: RuntimeType + 'static,
+        A: RuntimeType + Type<A, Default = T> + 'static,
+        T: Clone,
+        F: FnOnce(&TypedEventHandler<S, A>) -> Result<EventRegistrationToken>,
+    {
+        let shared = Self::shared();
+        let state = shared.clone();
+
+        let token = add(&TypedEventHandler::<S, A>::new(move |_, args: &T| {
+            Self::push(&state, args.clone());
+            Ok(())
+        }))?;
+
+        Ok(Self { shared, registration: Some((token, remove)) })
+    }
+
+    /// Creates a stream for an event whose handler is an `EventHandler`.
+    ///
+    /// The `add` closure registers the given handler and the `remove` closure unregisters it when the stream
+    /// is dropped.
+    pub fn from_event_handler<A, F>(add: F, remove: R) -> Result<Self>
+    where
+        A: RuntimeType + Type<A, Default = T> + 'static,
+        T: Clone,
+        F: FnOnce(&EventHandler<A>) -> Result<EventRegistrationToken>,
+    {
+        let shared = Self::shared();
+        let state = shared.clone();
+
+        let token = add(&EventHandler::<A>::new(move |_, args: &T| {
+            Self::push(&state, args.clone());
+            Ok(())
+        }))?;
+
+        Ok(Self { shared, registration: Some((token, remove)) })
+    }
+
+    fn shared() -> Arc<Mutex<EventState<T>>> {
+        Arc::new(Mutex::new(EventState { values: VecDeque::new(), waker: None }))
+    }
+
+    fn push(state: &Mutex<EventState<T>>, value: T) {
+        let mut state = state.lock().unwrap();
+        state.values.push_back(value);
+
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl<T, R: FnOnce(EventRegistrationToken) -> Result<()>> EventStream<T, R> {
+    /// Attempts to pull out the arguments of the next event that was raised.
+    pub fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let mut state = self.shared.lock().unwrap();
+
+        if let Some(value) = state.values.pop_front() {
+            Poll::Ready(Some(value))
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+
+    /// Removes the event handler, returning any error reported by the `remove` closure.
+    ///
+    /// This happens automatically when the stream is dropped, in which case the error is ignored.
+    pub fn close(mut self) -> Result<()> {
+        self.remove()
+    }
+
+    fn remove(&mut self) -> Result<()> {
+        match self.registration.take() {
+            Some((token, remove)) => remove(token),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T, R: FnOnce(EventRegistrationToken) -> Result<()>> Drop for EventStream<T, R> {
+    fn drop(&mut self) {
+        _ = self.remove();
+    }
+}
+
+impl<T, R: FnOnce(EventRegistrationToken) -> Result<()>> Unpin for EventStream<T, R> {}
+
+#[cfg(feature = "futures")]
+impl<T, R: FnOnce(EventRegistrationToken) -> Result<()>> futures_core::Stream for EventStream<T, R> {
+    type Item = T;
+
+    fn poll_next(self: core::pin::Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().poll_next(cx)
+    }
+}
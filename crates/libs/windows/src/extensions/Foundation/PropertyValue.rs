@@ -0,0 +1,319 @@
+use crate::Foundation::*;
+use windows_core::{IInspectable, IUnknown, Interface, Result, BSTR, GUID, HSTRING, PROPVARIANT, VARIANT};
+
+/// A value that can be boxed into an `IInspectable` as a WinRT property value.
+///
+/// Boxed values are created with `PropertyValue` and unboxed through `IReference<T>`, so unboxing requires the
+/// boxed value to have exactly the same type. This is the representation expected by property sets, value
+/// sets, and any API that takes an `IInspectable` value.
+pub trait Boxable: Sized {
+    /// Boxes the value into an `IInspectable`.
+    fn box_value(&self) -> Result<IInspectable>;
+
+    /// Unboxes a value of this type from an `IInspectable`.
+    fn unbox_value(value: &IInspectable) -> Result<Self>;
+}
+
+macro_rules! boxable {
+    ($($ty:ty => $create:ident,)*) => {
+        $(
+            impl Boxable for $ty {
+                fn box_value(&self) -> Result<IInspectable> {
+                    PropertyValue::$create(*self)
+                }
+
+                fn unbox_value(value: &IInspectable) -> Result<Self> {
+                    value.cast::<IReference<Self>>()?.Value()
+                }
+            }
+        )*
+    };
+}
+
+boxable! {
+    u8 => CreateUInt8,
+    i16 => CreateInt16,
+    u16 => CreateUInt16,
+    i32 => CreateInt32,
+    u32 => CreateUInt32,
+    i64 => CreateInt64,
+    u64 => CreateUInt64,
+    f32 => CreateSingle,
+    f64 => CreateDouble,
+    bool => CreateBoolean,
+    GUID => CreateGuid,
+    DateTime => CreateDateTime,
+    TimeSpan => CreateTimeSpan,
+    Point => CreatePoint,
+    Size => CreateSize,
+    Rect => CreateRect,
+}
+
+impl Boxable for HSTRING {
+    fn box_value(&self) -> Result<IInspectable> {
+        PropertyValue::CreateString(self)
+    }
+
+    fn unbox_value(value: &IInspectable) -> Result<Self> {
+        value.cast::<IReference<Self>>()?.Value()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Boxable for String {
+    fn box_value(&self) -> Result<IInspectable> {
+        PropertyValue::CreateString(&HSTRING::from(self))
+    }
+
+    fn unbox_value(value: &IInspectable) -> Result<Self> {
+        Ok(String::try_from(HSTRING::unbox_value(value)?)?)
+    }
+}
+
+macro_rules! boxable_array {
+    ($($ty:ty => $create:ident,)*) => {
+        $(
+            #[cfg(feature = "std")]
+            impl Boxable for Vec<$ty> {
+                fn box_value(&self) -> Result<IInspectable> {
+                    PropertyValue::$create(self)
+                }
+
+                fn unbox_value(value: &IInspectable) -> Result<Self> {
+                    Ok(value.cast::<IReferenceArray<$ty>>()?.Value()?.into_vec())
+                }
+            }
+        )*
+    };
+}
+
+boxable_array! {
+    u8 => CreateUInt8Array,
+    i16 => CreateInt16Array,
+    u16 => CreateUInt16Array,
+    i32 => CreateInt32Array,
+    u32 => CreateUInt32Array,
+    i64 => CreateInt64Array,
+    u64 => CreateUInt64Array,
+    f32 => CreateSingleArray,
+    f64 => CreateDoubleArray,
+    bool => CreateBooleanArray,
+    HSTRING => CreateStringArray,
+    GUID => CreateGuidArray,
+    DateTime => CreateDateTimeArray,
+    TimeSpan => CreateTimeSpanArray,
+    Point => CreatePointArray,
+    Size => CreateSizeArray,
+    Rect => CreateRectArray,
+}
+
+// Variant types that `windows-core` doesn't define.
+const VT_CY: u16 = 6;
+const VT_DATE: u16 = 7;
+const VT_DISPATCH: u16 = 9;
+const VT_INT: u16 = 22;
+const VT_UINT: u16 = 23;
+const VT_LPSTR: u16 = 30;
+const VT_LPWSTR: u16 = 31;
+const VT_FILETIME: u16 = 64;
+const VT_CLSID: u16 = 72;
+const VT_VECTOR: u16 = 0x1000;
+
+// OLE Automation dates count days from December 30, 1899, while `DateTime` counts 100-nanosecond intervals from
+// January 1, 1601. The fractional part of a date before 1899 is still added to the day rather than subtracted, and
+// dates are rounded to the nearest millisecond since that is all the precision a `VT_DATE` reliably holds.
+const TICKS_PER_DAY: i64 = 864_000_000_000;
+const OLE_EPOCH: i64 = 109_205 * TICKS_PER_DAY;
+const MILLISECONDS_PER_DAY: f64 = 86_400_000.0;
+
+fn date_to_ticks(date: f64) -> i64 {
+    let days = date as i64;
+    let fraction = date - days as f64;
+    let fraction = if fraction < 0.0 { -fraction } else { fraction };
+    let milliseconds = (fraction * MILLISECONDS_PER_DAY + 0.5) as i64;
+    OLE_EPOCH + days * TICKS_PER_DAY + milliseconds * 10_000
+}
+
+fn ticks_to_date(ticks: i64) -> f64 {
+    let ticks = ticks - OLE_EPOCH;
+    let days = ticks.div_euclid(TICKS_PER_DAY) as f64;
+    let fraction = ticks.rem_euclid(TICKS_PER_DAY) as f64 / TICKS_PER_DAY as f64;
+
+    if days < 0.0 {
+        days - fraction
+    } else {
+        days + fraction
+    }
+}
+
+fn mismatch() -> windows_core::Error {
+    windows_core::Error::from(windows_core::imp::TYPE_E_TYPEMISMATCH)
+}
+
+/// Boxes the value held by a `VARIANT` as the closest WinRT property value, such as `VT_I4` as `Int32`, `VT_BSTR` as
+/// `String`, and `VT_DATE` as `DateTime`, and unboxes a property value into a `VARIANT` in the same way.
+///
+/// `VT_I1` is widened to `Int16` and `VT_CY` is converted to `Double`, since WinRT has no equivalent types. Objects
+/// are passed through as `VT_UNKNOWN`. Empty variants can't be boxed, since WinRT represents an empty value with a
+/// null `IInspectable`.
+///
+/// ```rust,ignore
+/// let variant = VARIANT::from(123i32);
+/// let boxed = variant.box_value()?;
+/// assert_eq!(i32::unbox_value(&boxed)?, 123);
+/// ```
+#[cfg(feature = "std")]
+impl Boxable for VARIANT {
+    fn box_value(&self) -> Result<IInspectable> {
+        let raw = unsafe { &self.as_raw().Anonymous.Anonymous };
+
+        match self.vt() {
+            windows_core::imp::VT_UI1 => u8::try_from(self)?.box_value(),
+            windows_core::imp::VT_I1 => (i8::try_from(self)? as i16).box_value(),
+            windows_core::imp::VT_I2 => i16::try_from(self)?.box_value(),
+            windows_core::imp::VT_UI2 => u16::try_from(self)?.box_value(),
+            windows_core::imp::VT_I4 | VT_INT => i32::try_from(self)?.box_value(),
+            windows_core::imp::VT_UI4 | VT_UINT => u32::try_from(self)?.box_value(),
+            windows_core::imp::VT_I8 => i64::try_from(self)?.box_value(),
+            windows_core::imp::VT_UI8 => u64::try_from(self)?.box_value(),
+            windows_core::imp::VT_R4 => f32::try_from(self)?.box_value(),
+            windows_core::imp::VT_R8 => f64::try_from(self)?.box_value(),
+            windows_core::imp::VT_BOOL => bool::try_from(self)?.box_value(),
+            windows_core::imp::VT_BSTR => HSTRING::from_wide(BSTR::try_from(self)?.as_wide())?.box_value(),
+            VT_CY => (unsafe { raw.Anonymous.cyVal.int64 } as f64 / 10_000.0).box_value(),
+            VT_DATE => DateTime { UniversalTime: date_to_ticks(unsafe { raw.Anonymous.date }) }.box_value(),
+            windows_core::imp::VT_UNKNOWN | VT_DISPATCH => IUnknown::try_from(&self.change_type(windows_core::imp::VT_UNKNOWN)?)?.cast(),
+            _ => Err(mismatch()),
+        }
+    }
+
+    fn unbox_value(value: &IInspectable) -> Result<Self> {
+        let Ok(property) = value.cast::<IPropertyValue>() else {
+            return Ok(value.cast::<IUnknown>()?.into());
+        };
+
+        match property.Type()? {
+            PropertyType::UInt8 => Ok(property.GetUInt8()?.into()),
+            PropertyType::Int16 => Ok(property.GetInt16()?.into()),
+            PropertyType::UInt16 => Ok(property.GetUInt16()?.into()),
+            PropertyType::Int32 => Ok(property.GetInt32()?.into()),
+            PropertyType::UInt32 => Ok(property.GetUInt32()?.into()),
+            PropertyType::Int64 => Ok(property.GetInt64()?.into()),
+            PropertyType::UInt64 => Ok(property.GetUInt64()?.into()),
+            PropertyType::Single => Ok(property.GetSingle()?.into()),
+            PropertyType::Double => Ok(property.GetDouble()?.into()),
+            PropertyType::Boolean => Ok(property.GetBoolean()?.into()),
+            PropertyType::Char16 => Ok(BSTR::from_wide(&[property.GetChar16()?])?.into()),
+            PropertyType::String => Ok(BSTR::from_wide(property.GetString()?.as_wide())?.into()),
+            PropertyType::DateTime => Ok(date(ticks_to_date(property.GetDateTime()?.UniversalTime))),
+            PropertyType::Inspectable => Ok(value.cast::<IUnknown>()?.into()),
+            _ => Err(mismatch()),
+        }
+    }
+}
+
+fn date(date: f64) -> VARIANT {
+    unsafe {
+        VARIANT::from_raw(windows_core::imp::VARIANT {
+            Anonymous: windows_core::imp::VARIANT_0 {
+                Anonymous: windows_core::imp::VARIANT_0_0 { vt: VT_DATE, wReserved1: 0, wReserved2: 0, wReserved3: 0, Anonymous: windows_core::imp::VARIANT_0_0_0 { date } },
+            },
+        })
+    }
+}
+
+/// Boxes the value held by a `PROPVARIANT` as the closest WinRT property value, and unboxes a property value into a
+/// `PROPVARIANT` in the same way.
+///
+/// This supports the same types as the `VARIANT` conversion, as well as `VT_FILETIME` as `DateTime`, `VT_CLSID` as
+/// `Guid`, `VT_LPWSTR` as `String`, and vectors of integers, doubles, and strings as arrays.
+#[cfg(feature = "std")]
+impl Boxable for PROPVARIANT {
+    fn box_value(&self) -> Result<IInspectable> {
+        let raw = unsafe { &self.as_raw().Anonymous.Anonymous };
+
+        match self.vt() {
+            windows_core::imp::VT_UI1 => u8::try_from(self)?.box_value(),
+            windows_core::imp::VT_I1 => (unsafe { raw.Anonymous.cVal } as i16).box_value(),
+            windows_core::imp::VT_I2 => i16::try_from(self)?.box_value(),
+            windows_core::imp::VT_UI2 => u16::try_from(self)?.box_value(),
+            windows_core::imp::VT_I4 | VT_INT => i32::try_from(self)?.box_value(),
+            windows_core::imp::VT_UI4 | VT_UINT => u32::try_from(self)?.box_value(),
+            windows_core::imp::VT_I8 => i64::try_from(self)?.box_value(),
+            windows_core::imp::VT_UI8 => u64::try_from(self)?.box_value(),
+            windows_core::imp::VT_R4 => unsafe { raw.Anonymous.fltVal }.box_value(),
+            windows_core::imp::VT_R8 => f64::try_from(self)?.box_value(),
+            windows_core::imp::VT_BOOL => bool::try_from(self)?.box_value(),
+            windows_core::imp::VT_BSTR | VT_LPWSTR | VT_LPSTR => HSTRING::from_wide(BSTR::try_from(self)?.as_wide())?.box_value(),
+            VT_CY => (unsafe { raw.Anonymous.cyVal.int64 } as f64 / 10_000.0).box_value(),
+            VT_DATE => DateTime { UniversalTime: date_to_ticks(unsafe { raw.Anonymous.date }) }.box_value(),
+            VT_FILETIME => {
+                let filetime = unsafe { raw.Anonymous.filetime };
+                DateTime { UniversalTime: ((filetime.dwHighDateTime as i64) << 32) | filetime.dwLowDateTime as i64 }.box_value()
+            }
+            VT_CLSID => GUID::try_from(self)?.box_value(),
+            windows_core::imp::VT_UNKNOWN | VT_DISPATCH => IUnknown::try_from(&self.change_type(windows_core::imp::VT_UNKNOWN)?)?.cast(),
+            vt if vt == VT_VECTOR | windows_core::imp::VT_I2 => Vec::<i16>::try_from(self)?.box_value(),
+            vt if vt == VT_VECTOR | windows_core::imp::VT_UI2 => Vec::<u16>::try_from(self)?.box_value(),
+            vt if vt == VT_VECTOR | windows_core::imp::VT_I4 => Vec::<i32>::try_from(self)?.box_value(),
+            vt if vt == VT_VECTOR | windows_core::imp::VT_UI4 => Vec::<u32>::try_from(self)?.box_value(),
+            vt if vt == VT_VECTOR | windows_core::imp::VT_I8 => Vec::<i64>::try_from(self)?.box_value(),
+            vt if vt == VT_VECTOR | windows_core::imp::VT_UI8 => Vec::<u64>::try_from(self)?.box_value(),
+            vt if vt == VT_VECTOR | windows_core::imp::VT_R8 => Vec::<f64>::try_from(self)?.box_value(),
+            vt if vt == VT_VECTOR | VT_LPWSTR || vt == VT_VECTOR | windows_core::imp::VT_BSTR => Vec::<String>::try_from(self)?.iter().map(HSTRING::from).collect::<Vec<_>>().box_value(),
+            _ => Err(mismatch()),
+        }
+    }
+
+    fn unbox_value(value: &IInspectable) -> Result<Self> {
+        let Ok(property) = value.cast::<IPropertyValue>() else {
+            return Ok(value.cast::<IUnknown>()?.into());
+        };
+
+        match property.Type()? {
+            PropertyType::UInt8 => Ok(property.GetUInt8()?.into()),
+            PropertyType::Int16 => Ok(property.GetInt16()?.into()),
+            PropertyType::UInt16 => Ok(property.GetUInt16()?.into()),
+            PropertyType::Int32 => Ok(property.GetInt32()?.into()),
+            PropertyType::UInt32 => Ok(property.GetUInt32()?.into()),
+            PropertyType::Int64 => Ok(property.GetInt64()?.into()),
+            PropertyType::UInt64 => Ok(property.GetUInt64()?.into()),
+            PropertyType::Single => Ok(property.GetSingle()?.into()),
+            PropertyType::Double => Ok(property.GetDouble()?.into()),
+            PropertyType::Boolean => Ok(property.GetBoolean()?.into()),
+            PropertyType::Char16 => Ok(BSTR::from_wide(&[property.GetChar16()?])?.into()),
+            PropertyType::String => Ok(BSTR::from_wide(property.GetString()?.as_wide())?.into()),
+            PropertyType::DateTime => Ok(filetime(property.GetDateTime()?.UniversalTime)),
+            PropertyType::Guid => Ok(property.GetGuid()?.into()),
+            PropertyType::Inspectable => Ok(value.cast::<IUnknown>()?.into()),
+            PropertyType::Int16Array => PROPVARIANT::try_from(&*Vec::<i16>::unbox_value(value)?),
+            PropertyType::UInt16Array => PROPVARIANT::try_from(&*Vec::<u16>::unbox_value(value)?),
+            PropertyType::Int32Array => PROPVARIANT::try_from(&*Vec::<i32>::unbox_value(value)?),
+            PropertyType::UInt32Array => PROPVARIANT::try_from(&*Vec::<u32>::unbox_value(value)?),
+            PropertyType::Int64Array => PROPVARIANT::try_from(&*Vec::<i64>::unbox_value(value)?),
+            PropertyType::UInt64Array => PROPVARIANT::try_from(&*Vec::<u64>::unbox_value(value)?),
+            PropertyType::DoubleArray => PROPVARIANT::try_from(&*Vec::<f64>::unbox_value(value)?),
+            PropertyType::StringArray => {
+                let strings: Vec<String> = Vec::<HSTRING>::unbox_value(value)?.iter().map(|value| value.to_string_lossy()).collect();
+                PROPVARIANT::try_from(&*strings.iter().map(String::as_str).collect::<Vec<_>>())
+            }
+            _ => Err(mismatch()),
+        }
+    }
+}
+
+fn filetime(ticks: i64) -> PROPVARIANT {
+    unsafe {
+        PROPVARIANT::from_raw(windows_core::imp::PROPVARIANT {
+            Anonymous: windows_core::imp::PROPVARIANT_0 {
+                Anonymous: windows_core::imp::PROPVARIANT_0_0 {
+                    vt: VT_FILETIME,
+                    wReserved1: 0,
+                    wReserved2: 0,
+                    wReserved3: 0,
+                    Anonymous: windows_core::imp::PROPVARIANT_0_0_0 { filetime: windows_core::imp::FILETIME { dwLowDateTime: ticks as u32, dwHighDateTime: (ticks >> 32) as u32 } },
+                },
+            },
+        })
+    }
+}
@@ -0,0 +1,123 @@
+use crate::Foundation::*;
+use windows_core::{Array, Result, RuntimeType, GUID, HSTRING};
+
+#[windows_core::implement(IReference<T>, IPropertyValue)]
+struct StockReference<T>
+where
+    T: RuntimeType + 'static,
+{
+    value: T,
+}
+
+impl<T: RuntimeType> IReference_Impl<T> for StockReference_Impl<T> {
+    fn Value(&self) -> Result<T> {
+        Ok(self.value.clone())
+    }
+}
+
+macro_rules! mismatch {
+    ($($get:ident -> $ty:ty,)* ; $($get_array:ident($array:ty),)*) => {
+        $(
+            fn $get(&self) -> Result<$ty> {
+                Err(windows_core::imp::TYPE_E_TYPEMISMATCH.into())
+            }
+        )*
+        $(
+            fn $get_array(&self, _value: &mut Array<$array>) -> Result<()> {
+                Err(windows_core::imp::TYPE_E_TYPEMISMATCH.into())
+            }
+        )*
+    };
+}
+
+macro_rules! scalars {
+    ($($get:ident -> $ty:ty = $kind:ident,)*) => {
+        fn Type(&self) -> Result<PropertyType> {
+            $(
+                if (&self.value as &dyn core::any::Any).is::<$ty>() {
+                    return Ok(PropertyType::$kind);
+                }
+            )*
+            Ok(PropertyType::OtherType)
+        }
+        $(
+            fn $get(&self) -> Result<$ty> {
+                (&self.value as &dyn core::any::Any).downcast_ref::<$ty>().cloned().ok_or_else(|| windows_core::imp::TYPE_E_TYPEMISMATCH.into())
+            }
+        )*
+    };
+}
+
+// Scalars report their matching property type and can be retrieved with the corresponding typed getter, as with the
+// system's own boxed values. Values of other types can only be retrieved through `IReference<T>`.
+impl<T: RuntimeType + 'static> IPropertyValue_Impl for StockReference_Impl<T> {
+    fn IsNumericScalar(&self) -> Result<bool> {
+        Ok(matches!(self.Type()?, PropertyType::UInt8 | PropertyType::Int16 | PropertyType::UInt16 | PropertyType::Int32 | PropertyType::UInt32 | PropertyType::Int64 | PropertyType::UInt64 | PropertyType::Single | PropertyType::Double))
+    }
+    scalars! {
+        GetUInt8 -> u8 = UInt8,
+        GetInt16 -> i16 = Int16,
+        GetUInt16 -> u16 = UInt16,
+        GetInt32 -> i32 = Int32,
+        GetUInt32 -> u32 = UInt32,
+        GetInt64 -> i64 = Int64,
+        GetUInt64 -> u64 = UInt64,
+        GetSingle -> f32 = Single,
+        GetDouble -> f64 = Double,
+        GetBoolean -> bool = Boolean,
+        GetString -> HSTRING = String,
+        GetGuid -> GUID = Guid,
+        GetDateTime -> DateTime = DateTime,
+        GetTimeSpan -> TimeSpan = TimeSpan,
+        GetPoint -> Point = Point,
+        GetSize -> Size = Size,
+        GetRect -> Rect = Rect,
+    }
+    mismatch! {
+        // A `u16` is boxed as a `UInt16` rather than a `Char16`.
+        GetChar16 -> u16,
+        ;
+        GetUInt8Array(u8),
+        GetInt16Array(i16),
+        GetUInt16Array(u16),
+        GetInt32Array(i32),
+        GetUInt32Array(u32),
+        GetInt64Array(i64),
+        GetUInt64Array(u64),
+        GetSingleArray(f32),
+        GetDoubleArray(f64),
+        GetChar16Array(u16),
+        GetBooleanArray(bool),
+        GetStringArray(HSTRING),
+        GetInspectableArray(windows_core::IInspectable),
+        GetGuidArray(GUID),
+        GetDateTimeArray(DateTime),
+        GetTimeSpanArray(TimeSpan),
+        GetPointArray(Point),
+        GetSizeArray(Size),
+        GetRectArray(Rect),
+    }
+}
+
+/// Boxes any WinRT type, such as a struct defined by a component, as an `IReference<T>`.
+///
+/// Scalars such as `i32` or `HSTRING` report their matching `PropertyType`, while any other type reports
+/// `PropertyType::OtherType` and is unboxed by casting to `IReference<T>`. Types boxed this way may implement
+/// `Boxable` in terms of this conversion:
+///
+/// ```rust,ignore
+/// impl Boxable for Contact {
+///     fn box_value(&self) -> Result<IInspectable> {
+///         IReference::from(self.clone()).cast()
+///     }
+///
+///     fn unbox_value(value: &IInspectable) -> Result<Self> {
+///         value.cast::<IReference<Self>>()?.Value()
+///     }
+/// }
+/// ```
+impl<T: RuntimeType> From<T> for IReference<T> {
+    fn from(value: T) -> Self {
+        windows_core::ComObject::new(StockReference { value }).into_interface()
+    }
+}
@@ -10,3 +10,35 @@ impl From<TimeSpan> for core::time::Duration {
         core::time::Duration::from_nanos((value.Duration * 100) as u64)
     }
 }
+
+#[cfg(feature = "chrono")]
+impl From<TimeSpan> for chrono::TimeDelta {
+    fn from(value: TimeSpan) -> Self {
+        Self::seconds(value.Duration / 10_000_000) + Self::nanoseconds(value.Duration % 10_000_000 * 100)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::TimeDelta> for TimeSpan {
+    type Error = windows_core::Error;
+    /// Converts the duration, truncated toward zero to a multiple of 100 nanoseconds.
+    fn try_from(value: chrono::TimeDelta) -> windows_core::Result<Self> {
+        value.num_seconds().checked_mul(10_000_000).and_then(|ticks| ticks.checked_add(value.subsec_nanos() as i64 / 100)).map(|Duration| Self { Duration }).ok_or_else(|| windows_core::Error::from(windows_core::imp::E_INVALIDARG))
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<TimeSpan> for time::Duration {
+    fn from(value: TimeSpan) -> Self {
+        Self::new(value.Duration / 10_000_000, (value.Duration % 10_000_000 * 100) as i32)
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<time::Duration> for TimeSpan {
+    type Error = windows_core::Error;
+    /// Converts the duration, truncated toward zero to a multiple of 100 nanoseconds.
+    fn try_from(value: time::Duration) -> windows_core::Result<Self> {
+        value.whole_seconds().checked_mul(10_000_000).and_then(|ticks| ticks.checked_add(value.subsec_nanoseconds() as i64 / 100)).map(|Duration| Self { Duration }).ok_or_else(|| windows_core::Error::from(windows_core::imp::E_INVALIDARG))
+    }
+}
@@ -0,0 +1,2 @@
+#[cfg(feature = "Storage_Streams")]
+pub mod Streams;
@@ -0,0 +1,108 @@
+use crate::Storage::Streams::{Buffer, IBuffer};
+use windows_core::{Interface, Result};
+
+// Declared here rather than taken from `Win32::System::WinRT` so that byte access only requires the
+// `Storage_Streams` feature.
+#[windows_core::interface("905a0fef-bc53-11df-8c49-001e4fc686da")]
+unsafe trait IBufferByteAccess: windows_core::IUnknown {
+    unsafe fn Buffer(&self, value: *mut *mut u8) -> windows_core::HRESULT;
+}
+
+impl IBuffer {
+    /// Creates a buffer containing a copy of the bytes.
+    pub fn from_slice(bytes: &[u8]) -> Result<Self> {
+        let length = bytes.len().try_into()?;
+        let buffer: Self = Buffer::Create(length)?.cast()?;
+
+        if length > 0 {
+            unsafe { core::ptr::copy_nonoverlapping(bytes.as_ptr(), buffer.data()?, bytes.len()) };
+        }
+
+        buffer.SetLength(length)?;
+        Ok(buffer)
+    }
+
+    /// Returns the bytes of the buffer up to its current length.
+    ///
+    /// # Safety
+    ///
+    /// The buffer's memory is owned by the buffer object and may be written through any other reference to the
+    /// same object. The caller must ensure that nothing writes to the buffer or changes its length while the
+    /// slice is borrowed.
+    pub unsafe fn as_slice(&self) -> Result<&[u8]> {
+        let length = self.Length()? as usize;
+
+        if length == 0 {
+            Ok(&[])
+        } else {
+            Ok(core::slice::from_raw_parts(self.data()?, length))
+        }
+    }
+
+    /// Returns a copy of the bytes of the buffer up to its current length.
+    pub fn to_vec(&self) -> Result<Vec<u8>> {
+        // The slice is only borrowed for as long as it takes to copy it.
+        unsafe { Ok(self.as_slice()?.to_vec()) }
+    }
+
+    fn data(&self) -> Result<*mut u8> {
+        let access = self.cast::<IBufferByteAccess>()?;
+        let mut data = core::ptr::null_mut();
+        unsafe { access.Buffer(&mut data).ok()? };
+        Ok(data)
+    }
+}
+
+#[cfg(feature = "implement")]
+mod stock {
+    use super::{IBufferByteAccess, IBufferByteAccess_Impl};
+    use crate::Storage::Streams::{IBuffer, IBuffer_Impl};
+    use core::cell::UnsafeCell;
+    use core::sync::atomic::{AtomicU32, Ordering};
+
+    #[windows_core::implement(IBuffer, IBufferByteAccess)]
+    struct StockBuffer {
+        data: UnsafeCell<Box<[u8]>>,
+        capacity: u32,
+        length: AtomicU32,
+    }
+
+    impl IBuffer_Impl for StockBuffer_Impl {
+        fn Capacity(&self) -> windows_core::Result<u32> {
+            Ok(self.capacity)
+        }
+        fn Length(&self) -> windows_core::Result<u32> {
+            Ok(self.length.load(Ordering::Relaxed))
+        }
+        fn SetLength(&self, value: u32) -> windows_core::Result<()> {
+            if value > self.Capacity()? {
+                return Err(windows_core::Error::from(windows_core::imp::E_INVALIDARG));
+            }
+
+            self.length.store(value, Ordering::Relaxed);
+            Ok(())
+        }
+    }
+
+    impl IBufferByteAccess_Impl for StockBuffer_Impl {
+        unsafe fn Buffer(&self, value: *mut *mut u8) -> windows_core::HRESULT {
+            if value.is_null() {
+                return windows_core::imp::E_POINTER;
+            }
+
+            // The pointer is taken without creating a reference, since other callers may be using the bytes.
+            *value = core::ptr::addr_of_mut!(**self.data.get()).cast();
+            windows_core::HRESULT(0)
+        }
+    }
+
+    /// Creates a buffer that takes ownership of the bytes, with a capacity and length equal to the
+    /// length of the `Vec`.
+    impl TryFrom<Vec<u8>> for IBuffer {
+        type Error = windows_core::Error;
+        fn try_from(bytes: Vec<u8>) -> windows_core::Result<Self> {
+            let length = bytes.len().try_into()?;
+            Ok(windows_core::ComObject::new(StockBuffer { data: UnsafeCell::new(bytes.into_boxed_slice()), capacity: length, length: AtomicU32::new(length) }).into_interface())
+        }
+    }
+}
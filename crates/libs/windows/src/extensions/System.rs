@@ -0,0 +1,2 @@
+#[cfg(feature = "std")]
+pub(crate) mod DispatcherQueue;
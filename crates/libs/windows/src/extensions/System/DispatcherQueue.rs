@@ -0,0 +1,268 @@
+use crate::System::*;
+use core::future::Future;
+use core::mem::ManuallyDrop;
+use core::pin::Pin;
+use core::sync::atomic::{AtomicU8, Ordering};
+use core::task::{Context, Poll, Waker};
+use std::cell::RefCell;
+use std::sync::{Arc, Mutex};
+use std::task::Wake;
+use windows_core::Result;
+
+/// Runs Rust futures on a `DispatcherQueue`.
+///
+/// Every poll of a spawned future is dispatched to the queue, so futures spawned on the
+/// dispatcher queue of a UI thread may freely use UI-affine objects between suspension points.
+/// Futures spawned with `spawn` may also move themselves to another queue or to the thread pool
+/// by awaiting `resume_on` or `resume_background`.
+pub struct DispatcherQueueExecutor {
+    queue: DispatcherQueue,
+    controller: Option<DispatcherQueueController>,
+}
+
+impl DispatcherQueueExecutor {
+    /// Creates an executor that dispatches to the given queue.
+    pub fn new(queue: DispatcherQueue) -> Self {
+        Self { queue, controller: None }
+    }
+
+    /// Creates an executor that dispatches to the queue of the calling thread.
+    pub fn current() -> Result<Self> {
+        Ok(Self::new(DispatcherQueue::GetForCurrentThread()?))
+    }
+
+    /// Creates an executor that dispatches to a new queue running on a dedicated thread.
+    ///
+    /// The queue is shut down when the executor is dropped.
+    pub fn dedicated() -> Result<Self> {
+        let controller = DispatcherQueueController::CreateOnDedicatedThread()?;
+        Ok(Self { queue: controller.DispatcherQueue()?, controller: Some(controller) })
+    }
+
+    /// Returns the queue that this executor dispatches to.
+    pub fn queue(&self) -> &DispatcherQueue {
+        &self.queue
+    }
+
+    /// Spawns a future that starts running on the queue.
+    pub fn spawn<F: Future<Output = ()> + Send + 'static>(&self, future: F) -> Result<()> {
+        Task::spawn(Box::pin(future), Scheduler::Queue(self.queue.clone()))
+    }
+
+    /// Spawns a future that is not `Send` and must therefore always run on the queue.
+    ///
+    /// This must be called on the queue's thread so that the future never leaves it. Such futures
+    /// cannot use `resume_on` or `resume_background` to move to another thread.
+    pub fn spawn_local<F: Future<Output = ()> + 'static>(&self, future: F) -> Result<()> {
+        if !self.queue.HasThreadAccess()? {
+            return Err(windows_core::imp::RPC_E_WRONG_THREAD.into());
+        }
+
+        Task::spawn(Box::pin(future), Scheduler::Local(self.queue.clone()))
+    }
+}
+
+impl Drop for DispatcherQueueExecutor {
+    fn drop(&mut self) {
+        if let Some(controller) = &self.controller {
+            _ = controller.ShutdownQueueAsync();
+        }
+    }
+}
+
+/// Moves the current task to the given queue.
+///
+/// This is the equivalent of C++/WinRT's `resume_foreground` and must be awaited from within
+/// a future spawned with `DispatcherQueueExecutor::spawn`; otherwise it completes with
+/// `E_ILLEGAL_METHOD_CALL`. Code following the `await` runs on the queue's thread.
+pub fn resume_on(queue: &DispatcherQueue) -> Resume {
+    Resume { target: Some(Scheduler::Queue(queue.clone())) }
+}
+
+/// Moves the current task to the thread pool.
+///
+/// This is the equivalent of C++/WinRT's `resume_background` and must be awaited from within
+/// a future spawned with `DispatcherQueueExecutor::spawn`; otherwise it completes with
+/// `E_ILLEGAL_METHOD_CALL`. Code following the `await` runs on a thread pool thread.
+pub fn resume_background() -> Resume {
+    Resume { target: Some(Scheduler::ThreadPool) }
+}
+
+/// A future that moves the current task to another thread.
+///
+/// This is produced by `resume_on` and `resume_background`.
+pub struct Resume {
+    target: Option<Scheduler>,
+}
+
+impl Future for Resume {
+    type Output = Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let Some(target) = self.target.take() else {
+            return Poll::Ready(Ok(()));
+        };
+
+        CURRENT.with(|current| match &*current.borrow() {
+            Some(task) if !matches!(*task.scheduler.lock().unwrap(), Scheduler::Local(_)) => {
+                *task.scheduler.lock().unwrap() = target;
+                // The task is polled again once it has been dispatched to the new scheduler.
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+            _ => Poll::Ready(Err(windows_core::imp::E_ILLEGAL_METHOD_CALL.into())),
+        })
+    }
+}
+
+std::thread_local! {
+    static CURRENT: RefCell<Option<Arc<Task>>> = const { RefCell::new(None) };
+}
+
+#[derive(Clone)]
+enum Scheduler {
+    Queue(DispatcherQueue),
+    Local(DispatcherQueue),
+    ThreadPool,
+}
+
+const IDLE: u8 = 0;
+const SCHEDULED: u8 = 1;
+const RUNNING: u8 = 2;
+const NOTIFIED: u8 = 3;
+
+struct Task {
+    future: Mutex<Option<LocalFuture>>,
+    scheduler: Mutex<Scheduler>,
+    state: AtomicU8,
+}
+
+// Only futures that are `Send` may be scheduled on more than one thread. Local futures are confined
+// to their queue's thread by `spawn_local`, by `Resume`, and by `Task`'s drop implementation.
+struct LocalFuture(Pin<Box<dyn Future<Output = ()>>>);
+unsafe impl Send for LocalFuture {}
+
+impl Task {
+    fn spawn(future: Pin<Box<dyn Future<Output = ()>>>, scheduler: Scheduler) -> Result<()> {
+        let task = Arc::new(Self { future: Mutex::new(Some(LocalFuture(future))), scheduler: Mutex::new(scheduler), state: AtomicU8::new(SCHEDULED) });
+        task.dispatch()
+    }
+
+    fn dispatch(self: Arc<Self>) -> Result<()> {
+        let scheduler = self.scheduler.lock().unwrap().clone();
+
+        match scheduler {
+            Scheduler::Queue(queue) | Scheduler::Local(queue) => {
+                let mut task = Some(self);
+
+                let handler = DispatcherQueueHandler::new(move || {
+                    if let Some(task) = task.take() {
+                        task.run();
+                    }
+                    Ok(())
+                });
+
+                if queue.TryEnqueue(&handler)? {
+                    Ok(())
+                } else {
+                    Err(windows_core::imp::RO_E_CLOSED.into())
+                }
+            }
+            Scheduler::ThreadPool => unsafe {
+                let context = Arc::into_raw(self);
+
+                if windows_core::imp::TrySubmitThreadpoolCallback(Some(Self::callback), context as *mut _, core::ptr::null()) != 0 {
+                    Ok(())
+                } else {
+                    let error = windows_core::Error::from_win32();
+                    drop(Arc::from_raw(context));
+                    Err(error)
+                }
+            },
+        }
+    }
+
+    unsafe extern "system" fn callback(_instance: windows_core::imp::PTP_CALLBACK_INSTANCE, context: *mut core::ffi::c_void) {
+        Arc::from_raw(context as *const Self).run();
+    }
+
+    fn run(self: Arc<Self>) {
+        self.state.store(RUNNING, Ordering::Release);
+        let mut slot = self.future.lock().unwrap();
+
+        let Some(future) = slot.as_mut() else {
+            return;
+        };
+
+        let waker = Waker::from(self.clone());
+        let previous = CURRENT.with(|current| current.replace(Some(self.clone())));
+        let poll = future.0.as_mut().poll(&mut Context::from_waker(&waker));
+        CURRENT.with(|current| *current.borrow_mut() = previous);
+
+        if poll.is_ready() {
+            *slot = None;
+            return;
+        }
+
+        drop(slot);
+
+        if self.state.compare_exchange(RUNNING, IDLE, Ordering::AcqRel, Ordering::Acquire).is_err() {
+            self.state.store(SCHEDULED, Ordering::Release);
+            // If the queue has been shut down the task is simply dropped.
+            _ = self.dispatch();
+        }
+    }
+}
+
+impl Wake for Task {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        let mut state = self.state.load(Ordering::Acquire);
+
+        loop {
+            let next = match state {
+                IDLE => SCHEDULED,
+                RUNNING => NOTIFIED,
+                _ => return,
+            };
+
+            match self.state.compare_exchange(state, next, Ordering::AcqRel, Ordering::Acquire) {
+                Ok(_) if next == SCHEDULED => {
+                    _ = self.clone().dispatch();
+                    return;
+                }
+                Ok(_) => return,
+                Err(actual) => state = actual,
+            }
+        }
+    }
+}
+
+impl Drop for Task {
+    fn drop(&mut self) {
+        let Some(future) = self.future.get_mut().unwrap().take() else {
+            return;
+        };
+
+        if let Scheduler::Local(queue) = self.scheduler.get_mut().unwrap() {
+            if !queue.HasThreadAccess().unwrap_or(false) {
+                // A local future must be dropped on its own thread. If the queue has been shut down, it is leaked.
+                let mut future = Some(ManuallyDrop::new(future));
+
+                _ = queue.TryEnqueue(&DispatcherQueueHandler::new(move || {
+                    if let Some(mut future) = future.take() {
+                        unsafe { ManuallyDrop::drop(&mut future) };
+                    }
+                    Ok(())
+                }));
+
+                return;
+            }
+        }
+
+        drop(future);
+    }
+}
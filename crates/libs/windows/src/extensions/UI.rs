@@ -0,0 +1,2 @@
+#[cfg(all(feature = "Data_Xml_Dom", feature = "Foundation_Collections", feature = "UI_Notifications", feature = "std"))]
+pub(crate) mod Notifications;
@@ -0,0 +1,236 @@
+use crate::Data::Xml::Dom::*;
+use crate::Foundation::*;
+use crate::UI::Notifications::*;
+use std::sync::Arc;
+use windows_core::{IInspectable, Interface, Result, HSTRING};
+
+/// A toast notification, built without assembling the XML by hand.
+///
+/// Unpackaged apps must first register the application user model ID that the toast is shown under:
+///
+/// ```rust,ignore
+/// register_app_id("Contoso.Backup", "Contoso Backup", None)?;
+///
+/// Toast::new()
+///     .title("Backup complete")
+///     .body("12 files were copied.")
+///     .button("Open folder", "open")
+///     .on_activated(|activation| println!("{}", activation.arguments))
+///     .show("Contoso.Backup")?;
+/// ```
+#[derive(Clone, Default)]
+pub struct Toast {
+    launch: Option<String>,
+    title: Option<String>,
+    body: Vec<String>,
+    logo: Option<String>,
+    image: Option<String>,
+    inputs: Vec<(String, String)>,
+    buttons: Vec<(String, String)>,
+    silent: bool,
+    activated: Option<Arc<dyn Fn(ToastActivation) + Send + Sync>>,
+}
+
+impl Toast {
+    /// Creates an empty toast.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the title, which is shown in bold above the body.
+    pub fn title<T: Into<String>>(mut self, title: T) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Adds a line of body text. Toasts show at most two lines below the title.
+    pub fn body<T: Into<String>>(mut self, body: T) -> Self {
+        self.body.push(body.into());
+        self
+    }
+
+    /// Sets the arguments passed to the activation callback when the toast itself is clicked.
+    pub fn launch<T: Into<String>>(mut self, arguments: T) -> Self {
+        self.launch = Some(arguments.into());
+        self
+    }
+
+    /// Replaces the app's logo with an image, given as an `http`, `ms-appx`, or `file` URI.
+    pub fn logo<T: Into<String>>(mut self, uri: T) -> Self {
+        self.logo = Some(uri.into());
+        self
+    }
+
+    /// Adds an image below the text, given as an `http`, `ms-appx`, or `file` URI.
+    pub fn image<T: Into<String>>(mut self, uri: T) -> Self {
+        self.image = Some(uri.into());
+        self
+    }
+
+    /// Adds a text box, whose contents are passed to the activation callback under the given identifier.
+    pub fn input<T: Into<String>, P: Into<String>>(mut self, id: T, placeholder: P) -> Self {
+        self.inputs.push((id.into(), placeholder.into()));
+        self
+    }
+
+    /// Adds a button that passes `arguments` to the activation callback when clicked.
+    pub fn button<T: Into<String>, A: Into<String>>(mut self, content: T, arguments: A) -> Self {
+        self.buttons.push((content.into(), arguments.into()));
+        self
+    }
+
+    /// Shows the toast without playing a sound.
+    pub fn silent(mut self) -> Self {
+        self.silent = true;
+        self
+    }
+
+    /// Sets a callback that is called on a background thread when the toast or one of its buttons is clicked.
+    ///
+    /// The callback is only called while the process that showed the toast is running.
+    pub fn on_activated<F: Fn(ToastActivation) + Send + Sync + 'static>(mut self, callback: F) -> Self {
+        self.activated = Some(Arc::new(callback));
+        self
+    }
+
+    /// Returns the toast's XML content.
+    pub fn xml(&self) -> String {
+        let mut xml = String::from("<toast");
+
+        if let Some(launch) = &self.launch {
+            xml.push_str(&format!(r#" launch="{}""#, escape(launch)));
+        }
+
+        xml.push_str(r#"><visual><binding template="ToastGeneric">"#);
+
+        for text in self.title.iter().chain(&self.body) {
+            xml.push_str(&format!("<text>{}</text>", escape(text)));
+        }
+
+        if let Some(logo) = &self.logo {
+            xml.push_str(&format!(r#"<image placement="appLogoOverride" src="{}"/>"#, escape(logo)));
+        }
+
+        if let Some(image) = &self.image {
+            xml.push_str(&format!(r#"<image src="{}"/>"#, escape(image)));
+        }
+
+        xml.push_str("</binding></visual>");
+
+        if !self.inputs.is_empty() || !self.buttons.is_empty() {
+            xml.push_str("<actions>");
+
+            for (id, placeholder) in &self.inputs {
+                xml.push_str(&format!(r#"<input id="{}" type="text" placeHolderContent="{}"/>"#, escape(id), escape(placeholder)));
+            }
+
+            for (content, arguments) in &self.buttons {
+                xml.push_str(&format!(r#"<action content="{}" arguments="{}" activationType="foreground"/>"#, escape(content), escape(arguments)));
+            }
+
+            xml.push_str("</actions>");
+        }
+
+        if self.silent {
+            xml.push_str(r#"<audio silent="true"/>"#);
+        }
+
+        xml.push_str("</toast>");
+        xml
+    }
+
+    /// Creates a `ToastNotification` with the toast's content and activation callback.
+    pub fn notification(&self) -> Result<ToastNotification> {
+        let document = XmlDocument::new()?;
+        document.LoadXml(&HSTRING::from(self.xml()))?;
+        let notification = ToastNotification::CreateToastNotification(&document)?;
+
+        if let Some(callback) = self.activated.clone() {
+            notification.Activated(&TypedEventHandler::new(move |_, args: &Option<IInspectable>| {
+                if let Some(args) = args {
+                    callback(ToastActivation::new(&args.cast()?)?);
+                }
+
+                Ok(())
+            }))?;
+        }
+
+        Ok(notification)
+    }
+
+    /// Shows the toast under a registered application user model ID, returning the notification that was shown.
+    pub fn show(&self, app_id: &str) -> Result<ToastNotification> {
+        let notification = self.notification()?;
+        ToastNotificationManager::CreateToastNotifierWithId(&HSTRING::from(app_id))?.Show(&notification)?;
+        Ok(notification)
+    }
+}
+
+impl core::fmt::Debug for Toast {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Toast").field(&self.xml()).finish()
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;").replace('\'', "&apos;")
+}
+
+/// The arguments passed to a toast's activation callback.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ToastActivation {
+    /// The launch arguments of the toast, or the arguments of the button that was clicked.
+    pub arguments: String,
+    /// The contents of the toast's text boxes, keyed by identifier.
+    pub inputs: Vec<(String, String)>,
+}
+
+impl ToastActivation {
+    fn new(args: &ToastActivatedEventArgs) -> Result<Self> {
+        let mut inputs = Vec::new();
+
+        for pair in args.UserInput()?.First()? {
+            inputs.push((pair.Key()?.to_string(), HSTRING::unbox_value(&pair.Value()?)?.to_string()));
+        }
+
+        Ok(Self { arguments: args.Arguments()?.to_string(), inputs })
+    }
+}
+
+/// Registers an application user model ID for the current user, so that an unpackaged app can show toasts
+/// under that identifier with the given display name and optional icon URI.
+///
+/// Packaged apps and apps with a Start menu shortcut that specifies the identifier don't need to register it.
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_System_Registry"))]
+pub fn register_app_id(app_id: &str, display_name: &str, icon_uri: Option<&str>) -> Result<()> {
+    use crate::Win32::System::Registry::*;
+
+    unsafe {
+        let mut key = windows_core::Owned::default();
+        RegCreateKeyW(HKEY_CURRENT_USER, &app_id_key(app_id), &mut *key).ok()?;
+
+        for (name, value) in [("DisplayName", Some(display_name)), ("IconUri", icon_uri)] {
+            match value {
+                Some(value) => {
+                    let value: Vec<u8> = value.encode_utf16().chain([0]).flat_map(u16::to_le_bytes).collect();
+                    RegSetValueExW(*key, &HSTRING::from(name), 0, REG_SZ, Some(&value)).ok()?;
+                }
+                None => _ = RegDeleteValueW(*key, &HSTRING::from(name)),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Removes an application user model ID registered with `register_app_id`.
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_System_Registry"))]
+pub fn unregister_app_id(app_id: &str) -> Result<()> {
+    use crate::Win32::System::Registry::*;
+    unsafe { RegDeleteTreeW(HKEY_CURRENT_USER, &app_id_key(app_id)).ok() }
+}
+
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_System_Registry"))]
+fn app_id_key(app_id: &str) -> HSTRING {
+    HSTRING::from(format!(r"Software\Classes\AppUserModelId\{app_id}"))
+}
@@ -1,6 +1,12 @@
 #[cfg(feature = "Win32_Foundation")]
-mod Foundation;
+pub(crate) mod Foundation;
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_Graphics_Gdi"))]
+pub(crate) mod Graphics;
 #[cfg(feature = "Win32_Networking")]
-mod Networking;
+pub(crate) mod Networking;
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_Security"))]
+pub(crate) mod Security;
 #[cfg(feature = "Win32_System")]
-mod System;
+pub(crate) mod System;
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_UI"))]
+pub(crate) mod UI;
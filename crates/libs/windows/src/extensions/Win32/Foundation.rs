@@ -1,5 +1,14 @@
 pub mod BOOL;
 pub mod BOOLEAN;
+pub mod DECIMAL;
+pub mod FILETIME;
+#[cfg(feature = "std")]
+pub mod HANDLE;
+#[cfg(feature = "raw-window-handle")]
+pub mod HWND;
 pub mod NTSTATUS;
+pub mod SYSTEMTIME;
 pub mod VARIANT_BOOL;
 pub mod WIN32_ERROR;
+#[cfg(feature = "windows-sys")]
+mod sys;
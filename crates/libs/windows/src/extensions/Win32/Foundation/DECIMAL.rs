@@ -0,0 +1,174 @@
+use crate::Win32::Foundation::{DECIMAL, DECIMAL_0, DECIMAL_0_0, DECIMAL_1, DISP_E_OVERFLOW, E_INVALIDARG};
+
+const MAX_SCALE: u8 = 28;
+const MAX_MANTISSA: u128 = (1 << 96) - 1;
+const NEGATIVE: u8 = 0x80;
+
+impl DECIMAL {
+    /// Creates a `DECIMAL` with the value `mantissa / 10^scale`.
+    ///
+    /// The mantissa must fit in 96 bits and the scale must not exceed 28.
+    pub fn new(mantissa: i128, scale: u8) -> windows_core::Result<Self> {
+        if scale > MAX_SCALE {
+            return Err(E_INVALIDARG.into());
+        }
+
+        let magnitude = mantissa.unsigned_abs();
+
+        if magnitude > MAX_MANTISSA {
+            return Err(DISP_E_OVERFLOW.into());
+        }
+
+        Ok(Self {
+            wReserved: 0,
+            Anonymous1: DECIMAL_0 { Anonymous: DECIMAL_0_0 { scale, sign: if mantissa < 0 { NEGATIVE } else { 0 } } },
+            Hi32: (magnitude >> 64) as u32,
+            Anonymous2: DECIMAL_1 { Lo64: magnitude as u64 },
+        })
+    }
+
+    /// Returns the signed 96-bit mantissa.
+    pub fn mantissa(&self) -> i128 {
+        let magnitude = ((self.Hi32 as i128) << 64) | unsafe { self.Anonymous2.Lo64 } as i128;
+
+        if self.is_negative() {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    /// Returns the number of digits to the right of the decimal point.
+    pub fn scale(&self) -> u8 {
+        unsafe { self.Anonymous1.Anonymous.scale }
+    }
+
+    /// Returns true if the sign bit is set, which is the case for negative values and negative zero.
+    pub fn is_negative(&self) -> bool {
+        unsafe { self.Anonymous1.Anonymous.sign & NEGATIVE != 0 }
+    }
+
+    /// Returns the value rounded to the given number of decimal places, using banker's rounding as OLE Automation does.
+    pub fn round(&self, scale: u8) -> windows_core::Result<Self> {
+        Self::new(rescale(self.mantissa(), self.scale(), scale)?, scale)
+    }
+}
+
+impl core::fmt::Debug for DECIMAL {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let scale = self.scale();
+
+        if scale > MAX_SCALE {
+            return f.debug_struct("DECIMAL").field("mantissa", &self.mantissa()).field("scale", &scale).finish();
+        }
+
+        let sign = if self.is_negative() { "-" } else { "" };
+        let magnitude = self.mantissa().unsigned_abs();
+        let divisor = 10u128.pow(scale as u32);
+
+        if scale == 0 {
+            write!(f, "DECIMAL({sign}{magnitude})")
+        } else {
+            write!(f, "DECIMAL({sign}{}.{:0width$})", magnitude / divisor, magnitude % divisor, width = scale as usize)
+        }
+    }
+}
+
+// Adjusts the mantissa to the new scale, rounding half to even when digits are removed.
+pub(crate) fn rescale(mantissa: i128, from: u8, to: u8) -> windows_core::Result<i128> {
+    if from > MAX_SCALE || to > MAX_SCALE {
+        return Err(E_INVALIDARG.into());
+    }
+
+    if from > to {
+        Ok(round_half_even(mantissa, 10i128.pow((from - to) as u32)))
+    } else {
+        mantissa.checked_mul(10i128.pow((to - from) as u32)).ok_or_else(|| DISP_E_OVERFLOW.into())
+    }
+}
+
+// Divides by the divisor, rounding half to even.
+pub(crate) fn round_half_even(value: i128, divisor: i128) -> i128 {
+    let quotient = value / divisor;
+    let remainder = (value % divisor).abs() * 2;
+
+    if remainder > divisor || (remainder == divisor && quotient % 2 != 0) {
+        quotient + value.signum()
+    } else {
+        quotient
+    }
+}
+
+macro_rules! decimal_from_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for DECIMAL {
+                fn from(value: $ty) -> Self {
+                    Self::new(value as i128, 0).unwrap()
+                }
+            }
+
+            impl TryFrom<DECIMAL> for $ty {
+                type Error = windows_core::Error;
+                /// Rounds to the nearest integer, using banker's rounding as OLE Automation does.
+                fn try_from(value: DECIMAL) -> windows_core::Result<Self> {
+                    let integer = rescale(value.mantissa(), value.scale(), 0)?;
+                    Self::try_from(integer).map_err(|_| DISP_E_OVERFLOW.into())
+                }
+            }
+        )*
+    };
+}
+
+decimal_from_integer!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl TryFrom<i128> for DECIMAL {
+    type Error = windows_core::Error;
+    fn try_from(value: i128) -> windows_core::Result<Self> {
+        Self::new(value, 0)
+    }
+}
+
+impl TryFrom<DECIMAL> for i128 {
+    type Error = windows_core::Error;
+    fn try_from(value: DECIMAL) -> windows_core::Result<Self> {
+        rescale(value.mantissa(), value.scale(), 0)
+    }
+}
+
+#[cfg(feature = "Win32_System_Ole")]
+impl TryFrom<f64> for DECIMAL {
+    type Error = windows_core::Error;
+    /// Converts the value, keeping at most 15 significant digits as OLE Automation does.
+    fn try_from(value: f64) -> windows_core::Result<Self> {
+        unsafe { crate::Win32::System::Ole::VarDecFromR8(value) }
+    }
+}
+
+#[cfg(feature = "Win32_System_Ole")]
+impl TryFrom<DECIMAL> for f64 {
+    type Error = windows_core::Error;
+    fn try_from(value: DECIMAL) -> windows_core::Result<Self> {
+        unsafe { crate::Win32::System::Ole::VarR8FromDec(&value) }
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl From<rust_decimal::Decimal> for DECIMAL {
+    fn from(value: rust_decimal::Decimal) -> Self {
+        // `Decimal` has the same range and precision as `DECIMAL`.
+        Self::new(value.mantissa(), value.scale() as u8).unwrap()
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl TryFrom<DECIMAL> for rust_decimal::Decimal {
+    type Error = windows_core::Error;
+    fn try_from(value: DECIMAL) -> windows_core::Result<Self> {
+        if value.scale() > MAX_SCALE {
+            return Err(E_INVALIDARG.into());
+        }
+
+        Ok(Self::from_i128_with_scale(value.mantissa(), value.scale() as u32))
+    }
+}
@@ -0,0 +1,142 @@
+use crate::Win32::Foundation::FILETIME;
+
+// `FILETIME` counts 100-nanosecond intervals since January 1, 1601, which is 11,644,473,600 seconds before the Unix
+// epoch.
+pub(crate) const TICKS_PER_SECOND: u64 = 10_000_000;
+pub(crate) const UNIX_EPOCH_TICKS: u64 = 11_644_473_600 * TICKS_PER_SECOND;
+
+impl FILETIME {
+    /// Creates a `FILETIME` from a count of 100-nanosecond intervals.
+    pub const fn from_ticks(ticks: u64) -> Self {
+        Self { dwLowDateTime: ticks as u32, dwHighDateTime: (ticks >> 32) as u32 }
+    }
+
+    /// Returns the count of 100-nanosecond intervals, which is either the time since January 1, 1601 (UTC) or a
+    /// duration, depending on the API that returned it.
+    pub const fn ticks(&self) -> u64 {
+        ((self.dwHighDateTime as u64) << 32) | self.dwLowDateTime as u64
+    }
+}
+
+impl PartialOrd for FILETIME {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FILETIME {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.ticks().cmp(&other.ticks())
+    }
+}
+
+impl From<FILETIME> for core::time::Duration {
+    /// Converts a `FILETIME` that holds a duration, such as a process's kernel time.
+    fn from(value: FILETIME) -> Self {
+        let ticks = value.ticks();
+        Self::new(ticks / TICKS_PER_SECOND, (ticks % TICKS_PER_SECOND) as u32 * 100)
+    }
+}
+
+impl TryFrom<core::time::Duration> for FILETIME {
+    type Error = windows_core::Error;
+    /// Converts a duration, truncated to a multiple of 100 nanoseconds.
+    fn try_from(value: core::time::Duration) -> windows_core::Result<Self> {
+        let ticks = u64::try_from(value.as_nanos() / 100).map_err(|_| windows_core::Error::from(windows_core::imp::E_INVALIDARG))?;
+        Ok(Self::from_ticks(ticks))
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<FILETIME> for std::time::SystemTime {
+    fn from(value: FILETIME) -> Self {
+        let ticks = value.ticks();
+
+        if ticks >= UNIX_EPOCH_TICKS {
+            Self::UNIX_EPOCH + core::time::Duration::from(FILETIME::from_ticks(ticks - UNIX_EPOCH_TICKS))
+        } else {
+            Self::UNIX_EPOCH - core::time::Duration::from(FILETIME::from_ticks(UNIX_EPOCH_TICKS - ticks))
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<std::time::SystemTime> for FILETIME {
+    type Error = windows_core::Error;
+    /// Converts a time, rounded down to a multiple of 100 nanoseconds. Times before 1601 can't be converted.
+    fn try_from(value: std::time::SystemTime) -> windows_core::Result<Self> {
+        let invalid = || windows_core::Error::from(windows_core::imp::E_INVALIDARG);
+
+        let ticks = match value.duration_since(std::time::SystemTime::UNIX_EPOCH) {
+            Ok(after) => u64::try_from(after.as_nanos() / 100).ok().and_then(|ticks| ticks.checked_add(UNIX_EPOCH_TICKS)),
+            Err(before) => u64::try_from((before.duration().as_nanos() + 99) / 100).ok().and_then(|ticks| UNIX_EPOCH_TICKS.checked_sub(ticks)),
+        };
+
+        ticks.map(Self::from_ticks).ok_or_else(invalid)
+    }
+}
+
+// Returns the nanoseconds since the Unix epoch, which may be negative.
+#[cfg(any(feature = "chrono", feature = "time"))]
+fn unix_nanos(value: &FILETIME) -> i128 {
+    (value.ticks() as i128 - UNIX_EPOCH_TICKS as i128) * 100
+}
+
+// Creates a `FILETIME` from nanoseconds since the Unix epoch, rounded down to a multiple of 100 nanoseconds.
+#[cfg(any(feature = "chrono", feature = "time"))]
+fn from_unix_nanos(nanos: i128) -> windows_core::Result<FILETIME> {
+    let ticks = nanos.div_euclid(100) + UNIX_EPOCH_TICKS as i128;
+    u64::try_from(ticks).map(FILETIME::from_ticks).map_err(|_| windows_core::Error::from(windows_core::imp::E_INVALIDARG))
+}
+
+#[cfg(feature = "chrono")]
+impl From<FILETIME> for chrono::DateTime<chrono::Utc> {
+    fn from(value: FILETIME) -> Self {
+        let nanos = unix_nanos(&value);
+        // Every `FILETIME` falls well within the range of `DateTime`.
+        Self::from_timestamp(nanos.div_euclid(1_000_000_000) as i64, nanos.rem_euclid(1_000_000_000) as u32).unwrap()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> TryFrom<chrono::DateTime<Tz>> for FILETIME {
+    type Error = windows_core::Error;
+    /// Converts the time to UTC, rounded down to a multiple of 100 nanoseconds.
+    fn try_from(value: chrono::DateTime<Tz>) -> windows_core::Result<Self> {
+        from_unix_nanos(value.timestamp() as i128 * 1_000_000_000 + value.timestamp_subsec_nanos() as i128)
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<FILETIME> for time::OffsetDateTime {
+    type Error = windows_core::Error;
+    /// Converts the time, which fails for times after the year 9999 unless the `time` crate's `large-dates` feature
+    /// is enabled.
+    fn try_from(value: FILETIME) -> windows_core::Result<Self> {
+        Self::from_unix_timestamp_nanos(unix_nanos(&value)).map_err(|_| windows_core::Error::from(windows_core::imp::E_INVALIDARG))
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<time::OffsetDateTime> for FILETIME {
+    type Error = windows_core::Error;
+    /// Converts the time to UTC, rounded down to a multiple of 100 nanoseconds.
+    fn try_from(value: time::OffsetDateTime) -> windows_core::Result<Self> {
+        from_unix_nanos(value.unix_timestamp_nanos())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for FILETIME {
+    /// Serializes the `FILETIME` as its count of 100-nanosecond intervals.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.ticks())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FILETIME {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u64::deserialize(deserializer).map(Self::from_ticks)
+    }
+}
@@ -0,0 +1,12 @@
+use crate::Win32::Foundation::HANDLE;
+use std::os::windows::io::RawHandle;
+
+unsafe impl windows_core::KernelHandle for HANDLE {
+    fn from_raw_handle(handle: RawHandle) -> Self {
+        Self(handle)
+    }
+
+    fn as_raw_handle(&self) -> RawHandle {
+        self.0
+    }
+}
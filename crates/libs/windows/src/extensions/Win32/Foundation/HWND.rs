@@ -0,0 +1,84 @@
+use crate::Win32::Foundation::HWND;
+use core::num::NonZeroIsize;
+use raw_window_handle::{DisplayHandle, HandleError, HasDisplayHandle, HasWindowHandle, RawWindowHandle, Win32WindowHandle, WindowHandle};
+
+impl TryFrom<HWND> for Win32WindowHandle {
+    type Error = windows_core::Error;
+    /// Converts a window handle, which must not be null, including the window's `HINSTANCE` when available.
+    fn try_from(value: HWND) -> windows_core::Result<Self> {
+        let hwnd = NonZeroIsize::new(value.0 as isize).ok_or_else(|| windows_core::Error::from(windows_core::imp::E_INVALIDARG))?;
+        let mut handle = Self::new(hwnd);
+
+        #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+        {
+            use crate::Win32::UI::WindowsAndMessaging::*;
+            handle.hinstance = NonZeroIsize::new(unsafe { GetWindowLongPtrW(value, GWLP_HINSTANCE) });
+        }
+
+        Ok(handle)
+    }
+}
+
+impl From<Win32WindowHandle> for HWND {
+    fn from(value: Win32WindowHandle) -> Self {
+        Self(value.hwnd.get() as *mut _)
+    }
+}
+
+impl TryFrom<HWND> for RawWindowHandle {
+    type Error = windows_core::Error;
+    fn try_from(value: HWND) -> windows_core::Result<Self> {
+        Ok(Self::Win32(value.try_into()?))
+    }
+}
+
+impl TryFrom<RawWindowHandle> for HWND {
+    type Error = windows_core::Error;
+    /// Converts a `RawWindowHandle::Win32` handle, failing for any other platform's handle.
+    fn try_from(value: RawWindowHandle) -> windows_core::Result<Self> {
+        match value {
+            RawWindowHandle::Win32(handle) => Ok(handle.into()),
+            _ => Err(windows_core::Error::from(windows_core::imp::E_INVALIDARG)),
+        }
+    }
+}
+
+/// A borrowed window handle that implements `HasWindowHandle` and `HasDisplayHandle`, so that an `HWND` can be
+/// passed to libraries such as `wgpu`.
+///
+/// ```rust,ignore
+/// let window = unsafe { RawWindow::new(hwnd) };
+/// let surface = instance.create_surface(&window)?;
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct RawWindow(HWND);
+
+impl RawWindow {
+    /// Wraps a window handle.
+    ///
+    /// # Safety
+    ///
+    /// The window must remain valid for as long as the `RawWindow` or any `WindowHandle` borrowed from it is used.
+    pub unsafe fn new(hwnd: HWND) -> Self {
+        Self(hwnd)
+    }
+
+    /// Returns the window handle.
+    pub fn handle(&self) -> HWND {
+        self.0
+    }
+}
+
+impl HasWindowHandle for RawWindow {
+    fn window_handle(&self) -> Result<WindowHandle<'_>, HandleError> {
+        let raw = RawWindowHandle::try_from(self.0).map_err(|_| HandleError::Unavailable)?;
+        Ok(unsafe { WindowHandle::borrow_raw(raw) })
+    }
+}
+
+impl HasDisplayHandle for RawWindow {
+    fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+        Ok(DisplayHandle::windows())
+    }
+}
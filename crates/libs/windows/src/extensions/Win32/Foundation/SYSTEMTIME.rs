@@ -0,0 +1,238 @@
+use super::FILETIME::TICKS_PER_SECOND;
+use crate::Win32::Foundation::{FILETIME, SYSTEMTIME};
+
+const TICKS_PER_MILLISECOND: u64 = 10_000;
+const TICKS_PER_DAY: u64 = 86_400 * TICKS_PER_SECOND;
+
+// The largest value that `FileTimeToSystemTime` accepts, which is in the year 30828.
+const MAX_TICKS: u64 = i64::MAX as u64;
+
+impl TryFrom<SYSTEMTIME> for FILETIME {
+    type Error = windows_core::Error;
+    /// Converts a time in the same way as `SystemTimeToFileTime`, ignoring `wDayOfWeek`.
+    fn try_from(value: SYSTEMTIME) -> windows_core::Result<Self> {
+        let month_days = match value.wMonth {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if is_leap_year(value.wYear as i64) => 29,
+            2 => 28,
+            _ => 0,
+        };
+
+        if value.wYear < 1601 || value.wDay < 1 || value.wDay > month_days || value.wHour > 23 || value.wMinute > 59 || value.wSecond > 59 || value.wMilliseconds > 999 {
+            return Err(windows_core::Error::from(windows_core::imp::E_INVALIDARG));
+        }
+
+        let days = days_from_civil(value.wYear as i64, value.wMonth as i64, value.wDay as i64) - days_from_civil(1601, 1, 1);
+        let seconds = value.wHour as u64 * 3600 + value.wMinute as u64 * 60 + value.wSecond as u64;
+        let ticks = days as u64 * TICKS_PER_DAY + seconds * TICKS_PER_SECOND + value.wMilliseconds as u64 * TICKS_PER_MILLISECOND;
+
+        if ticks > MAX_TICKS {
+            return Err(windows_core::Error::from(windows_core::imp::E_INVALIDARG));
+        }
+
+        Ok(FILETIME::from_ticks(ticks))
+    }
+}
+
+impl TryFrom<FILETIME> for SYSTEMTIME {
+    type Error = windows_core::Error;
+    /// Converts a time in the same way as `FileTimeToSystemTime`, truncated to the millisecond.
+    fn try_from(value: FILETIME) -> windows_core::Result<Self> {
+        let ticks = value.ticks();
+
+        if ticks > MAX_TICKS {
+            return Err(windows_core::Error::from(windows_core::imp::E_INVALIDARG));
+        }
+
+        let days = (ticks / TICKS_PER_DAY) as i64;
+        let time = ticks % TICKS_PER_DAY;
+        let (year, month, day) = civil_from_days(days + days_from_civil(1601, 1, 1));
+
+        Ok(Self {
+            wYear: year as u16,
+            wMonth: month as u16,
+            // January 1, 1601 was a Monday.
+            wDayOfWeek: ((days + 1) % 7) as u16,
+            wDay: day as u16,
+            wHour: (time / (3600 * TICKS_PER_SECOND)) as u16,
+            wMinute: (time / (60 * TICKS_PER_SECOND) % 60) as u16,
+            wSecond: (time / TICKS_PER_SECOND % 60) as u16,
+            wMilliseconds: (time / TICKS_PER_MILLISECOND % 1000) as u16,
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<SYSTEMTIME> for std::time::SystemTime {
+    type Error = windows_core::Error;
+    /// Converts a time, which is interpreted as UTC.
+    fn try_from(value: SYSTEMTIME) -> windows_core::Result<Self> {
+        Ok(FILETIME::try_from(value)?.into())
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<std::time::SystemTime> for SYSTEMTIME {
+    type Error = windows_core::Error;
+    /// Converts a time to UTC, truncated to the millisecond.
+    fn try_from(value: std::time::SystemTime) -> windows_core::Result<Self> {
+        FILETIME::try_from(value)?.try_into()
+    }
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+// Returns the number of days since 1970-01-01 in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+// Returns the year, month, and day for a number of days since 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<SYSTEMTIME> for chrono::DateTime<chrono::Utc> {
+    type Error = windows_core::Error;
+    /// Converts a time, which is interpreted as UTC.
+    fn try_from(value: SYSTEMTIME) -> windows_core::Result<Self> {
+        Ok(FILETIME::try_from(value)?.into())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> TryFrom<chrono::DateTime<Tz>> for SYSTEMTIME {
+    type Error = windows_core::Error;
+    /// Converts a time to UTC, truncated to the millisecond.
+    fn try_from(value: chrono::DateTime<Tz>) -> windows_core::Result<Self> {
+        FILETIME::try_from(value)?.try_into()
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<SYSTEMTIME> for time::OffsetDateTime {
+    type Error = windows_core::Error;
+    /// Converts a time, which is interpreted as UTC.
+    fn try_from(value: SYSTEMTIME) -> windows_core::Result<Self> {
+        FILETIME::try_from(value)?.try_into()
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<time::OffsetDateTime> for SYSTEMTIME {
+    type Error = windows_core::Error;
+    /// Converts a time to UTC, truncated to the millisecond.
+    fn try_from(value: time::OffsetDateTime) -> windows_core::Result<Self> {
+        FILETIME::try_from(value)?.try_into()
+    }
+}
+
+#[cfg(feature = "serde")]
+const FIELDS: &[&str] = &["wYear", "wMonth", "wDayOfWeek", "wDay", "wHour", "wMinute", "wSecond", "wMilliseconds"];
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SYSTEMTIME {
+    /// Serializes the `SYSTEMTIME` as a struct with the same field names.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let values = [self.wYear, self.wMonth, self.wDayOfWeek, self.wDay, self.wHour, self.wMinute, self.wSecond, self.wMilliseconds];
+        let mut state = serializer.serialize_struct("SYSTEMTIME", FIELDS.len())?;
+
+        for (field, value) in FIELDS.iter().zip(values) {
+            state.serialize_field(field, &value)?;
+        }
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SYSTEMTIME {
+    /// Deserializes the `SYSTEMTIME` from a struct with the same field names, or a sequence of the fields in order.
+    /// The fields aren't validated, just as the struct itself doesn't validate them.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = [u16; 8];
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("struct SYSTEMTIME")
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<[u16; 8], A::Error> {
+                let mut values = [0; 8];
+
+                for (index, value) in values.iter_mut().enumerate() {
+                    *value = seq.next_element()?.ok_or_else(|| serde::de::Error::invalid_length(index, &self))?;
+                }
+
+                Ok(values)
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<[u16; 8], A::Error> {
+                let mut values = [None; 8];
+
+                while let Some(Field(index)) = map.next_key()? {
+                    if values[index].is_some() {
+                        return Err(serde::de::Error::duplicate_field(FIELDS[index]));
+                    }
+
+                    values[index] = Some(map.next_value()?);
+                }
+
+                let mut result = [0; 8];
+
+                for (index, value) in values.into_iter().enumerate() {
+                    result[index] = value.ok_or_else(|| serde::de::Error::missing_field(FIELDS[index]))?;
+                }
+
+                Ok(result)
+            }
+        }
+
+        // The index of a field in `FIELDS`.
+        struct Field(usize);
+
+        impl<'de> serde::Deserialize<'de> for Field {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct Visitor;
+
+                impl serde::de::Visitor<'_> for Visitor {
+                    type Value = Field;
+
+                    fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                        f.write_str("a SYSTEMTIME field name")
+                    }
+
+                    fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Field, E> {
+                        FIELDS.iter().position(|field| *field == value).map(Field).ok_or_else(|| E::unknown_field(value, FIELDS))
+                    }
+                }
+
+                deserializer.deserialize_identifier(Visitor)
+            }
+        }
+
+        let [wYear, wMonth, wDayOfWeek, wDay, wHour, wMinute, wSecond, wMilliseconds] = deserializer.deserialize_struct("SYSTEMTIME", FIELDS, Visitor)?;
+        Ok(Self { wYear, wMonth, wDayOfWeek, wDay, wHour, wMinute, wSecond, wMilliseconds })
+    }
+}
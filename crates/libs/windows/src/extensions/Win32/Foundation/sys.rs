@@ -0,0 +1,46 @@
+// Conversions between the types in `Win32::Foundation` and their equivalents in the `windows-sys` crate.
+//
+// The `windows-sys` crate represents handles and other newtypes as type aliases for their underlying primitive
+// types, so those conversions are simply the wrapped value. Both crates generate structs from the same metadata with
+// the same `#[repr(C)]` layout, so those conversions copy the value as is.
+
+macro_rules! bridge_newtype {
+    ($($ty:ident),*) => {
+        $(
+            impl From<windows_sys::Win32::Foundation::$ty> for crate::Win32::Foundation::$ty {
+                fn from(value: windows_sys::Win32::Foundation::$ty) -> Self {
+                    Self(value)
+                }
+            }
+
+            impl From<crate::Win32::Foundation::$ty> for windows_sys::Win32::Foundation::$ty {
+                fn from(value: crate::Win32::Foundation::$ty) -> Self {
+                    value.0
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! bridge_struct {
+    ($($ty:ident),*) => {
+        $(
+            const _: () = assert!(core::mem::size_of::<crate::Win32::Foundation::$ty>() == core::mem::size_of::<windows_sys::Win32::Foundation::$ty>());
+
+            impl From<windows_sys::Win32::Foundation::$ty> for crate::Win32::Foundation::$ty {
+                fn from(value: windows_sys::Win32::Foundation::$ty) -> Self {
+                    unsafe { core::mem::transmute(value) }
+                }
+            }
+
+            impl From<crate::Win32::Foundation::$ty> for windows_sys::Win32::Foundation::$ty {
+                fn from(value: crate::Win32::Foundation::$ty) -> Self {
+                    unsafe { core::mem::transmute(value) }
+                }
+            }
+        )*
+    };
+}
+
+bridge_newtype!(BOOL, BOOLEAN, HANDLE, HGLOBAL, HINSTANCE, HLOCAL, HMODULE, HRSRC, HWND, LPARAM, LRESULT, NTSTATUS, VARIANT_BOOL, WIN32_ERROR, WPARAM);
+bridge_struct!(FILETIME, LUID, POINT, POINTL, POINTS, RECT, RECTL, SIZE, SYSTEMTIME);
@@ -0,0 +1,73 @@
+use crate::Win32::Foundation::HWND;
+use crate::Win32::Graphics::Gdi::*;
+
+/// A device context created by `CreateDCW` or `CreateCompatibleDC` that is freed with `DeleteDC`.
+///
+/// Device contexts retrieved by `GetDC` or `GetWindowDC` must be released with `ReleaseDC` instead, so
+/// `HDC` itself cannot be freed by [`Owned`](windows_core::Owned).
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct CreatedHDC(pub HDC);
+
+impl CreatedHDC {
+    pub fn is_invalid(&self) -> bool {
+        self.0.is_invalid()
+    }
+}
+
+impl windows_core::Free for CreatedHDC {
+    #[inline]
+    unsafe fn free(&mut self) {
+        if !self.is_invalid() {
+            _ = DeleteDC(self.0);
+        }
+    }
+}
+
+impl windows_core::TypeKind for CreatedHDC {
+    type TypeKind = windows_core::CopyType;
+}
+
+impl windows_core::imp::CanInto<HDC> for CreatedHDC {}
+
+impl From<CreatedHDC> for HDC {
+    fn from(value: CreatedHDC) -> Self {
+        value.0
+    }
+}
+
+/// A device context retrieved for a window by `GetDC` or `GetWindowDC` that is freed with `ReleaseDC`.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct WindowHDC {
+    pub hwnd: HWND,
+    pub hdc: HDC,
+}
+
+impl WindowHDC {
+    pub fn is_invalid(&self) -> bool {
+        self.hdc.is_invalid()
+    }
+}
+
+impl windows_core::Free for WindowHDC {
+    #[inline]
+    unsafe fn free(&mut self) {
+        if !self.is_invalid() {
+            _ = ReleaseDC(self.hwnd, self.hdc);
+        }
+    }
+}
+
+impl windows_core::TypeKind for WindowHDC {
+    type TypeKind = windows_core::CopyType;
+}
+
+impl windows_core::imp::CanInto<HDC> for WindowHDC {}
+
+impl From<WindowHDC> for HDC {
+    fn from(value: WindowHDC) -> Self {
+        value.hdc
+    }
+}
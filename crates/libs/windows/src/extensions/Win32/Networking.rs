@@ -1,2 +1,4 @@
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_Networking_WinHttp", feature = "std"))]
+pub(crate) mod WinHttp;
 #[cfg(feature = "Win32_Networking_WinSock")]
 mod WinSock;
@@ -0,0 +1,699 @@
+use crate::Win32::Foundation::*;
+use crate::Win32::Networking::WinHttp::*;
+use core::ffi::c_void;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use core::time::Duration;
+use std::sync::{Arc, Mutex};
+use windows_core::{Error, Result, HRESULT, HSTRING, PCWSTR};
+
+// Closes a session, connection, request, or WebSocket handle.
+struct Handle(*mut c_void);
+
+unsafe impl Send for Handle {}
+unsafe impl Sync for Handle {}
+
+impl Handle {
+    fn new(handle: *mut c_void) -> Result<Self> {
+        if handle.is_null() {
+            Err(Error::from_win32())
+        } else {
+            Ok(Self(handle))
+        }
+    }
+}
+
+impl Drop for Handle {
+    fn drop(&mut self) {
+        unsafe {
+            _ = WinHttpCloseHandle(self.0);
+        }
+    }
+}
+
+/// How a session reaches the servers it connects to.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum HttpProxy {
+    /// Uses the system's proxy settings, including automatic proxy configuration scripts.
+    #[default]
+    Automatic,
+    /// Connects to servers directly.
+    None,
+    /// Connects through the given proxy server, except for hosts that match the bypass list.
+    Named {
+        /// The proxy server, such as `http=proxy:8080`.
+        server: String,
+        /// A semicolon-separated list of hosts that are reached directly, such as `<local>`.
+        bypass: Option<String>,
+    },
+}
+
+/// Opens an `HttpSession` with non-default options.
+///
+/// ```rust,ignore
+/// let session = HttpSessionBuilder::new("Contoso/1.0")
+///     .proxy(HttpProxy::None)
+///     .secure_protocols(WINHTTP_FLAG_SECURE_PROTOCOL_TLS1_2 | WINHTTP_FLAG_SECURE_PROTOCOL_TLS1_3)
+///     .asynchronous()
+///     .open()?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct HttpSessionBuilder {
+    user_agent: String,
+    proxy: HttpProxy,
+    secure_protocols: Option<u32>,
+    timeouts: Option<[Duration; 4]>,
+    asynchronous: bool,
+}
+
+impl HttpSessionBuilder {
+    /// Creates a builder for a session that identifies itself with the given user agent.
+    pub fn new(user_agent: &str) -> Self {
+        Self { user_agent: user_agent.to_string(), proxy: HttpProxy::Automatic, secure_protocols: None, timeouts: None, asynchronous: false }
+    }
+
+    /// Sets how the session reaches servers. The default is `HttpProxy::Automatic`.
+    pub fn proxy(mut self, proxy: HttpProxy) -> Self {
+        self.proxy = proxy;
+        self
+    }
+
+    /// Restricts the TLS versions the session may negotiate to a combination of `WINHTTP_FLAG_SECURE_PROTOCOL_*` flags.
+    pub fn secure_protocols(mut self, protocols: u32) -> Self {
+        self.secure_protocols = Some(protocols);
+        self
+    }
+
+    /// Sets the name resolution, connect, send, and receive timeouts.
+    pub fn timeouts(mut self, resolve: Duration, connect: Duration, send: Duration, receive: Duration) -> Self {
+        self.timeouts = Some([resolve, connect, send, receive]);
+        self
+    }
+
+    /// Opens the session in asynchronous mode, where requests are sent and read with the `_async` methods.
+    pub fn asynchronous(mut self) -> Self {
+        self.asynchronous = true;
+        self
+    }
+
+    /// Opens the session.
+    pub fn open(&self) -> Result<HttpSession> {
+        let (access, server, bypass) = match &self.proxy {
+            HttpProxy::Automatic => (WINHTTP_ACCESS_TYPE_AUTOMATIC_PROXY, None, None),
+            HttpProxy::None => (WINHTTP_ACCESS_TYPE_NO_PROXY, None, None),
+            HttpProxy::Named { server, bypass } => (WINHTTP_ACCESS_TYPE_NAMED_PROXY, Some(HSTRING::from(server)), bypass.as_deref().map(HSTRING::from)),
+        };
+
+        unsafe {
+            let handle = Handle::new(WinHttpOpen(&HSTRING::from(&self.user_agent), access, pcwstr(&server), pcwstr(&bypass), if self.asynchronous { WINHTTP_FLAG_ASYNC } else { 0 }))?;
+
+            if let Some(protocols) = self.secure_protocols {
+                WinHttpSetOption(Some(handle.0), WINHTTP_OPTION_SECURE_PROTOCOLS, Some(&protocols.to_ne_bytes()))?;
+            }
+
+            if let Some(timeouts) = self.timeouts {
+                let [resolve, connect, send, receive] = timeouts.map(|timeout| timeout.as_millis().min(i32::MAX as u128) as i32);
+                WinHttpSetTimeouts(handle.0, resolve, connect, send, receive)?;
+            }
+
+            if self.asynchronous {
+                let flags = WINHTTP_CALLBACK_FLAG_SENDREQUEST_COMPLETE | WINHTTP_CALLBACK_FLAG_HEADERS_AVAILABLE | WINHTTP_CALLBACK_FLAG_READ_COMPLETE | WINHTTP_CALLBACK_FLAG_WRITE_COMPLETE | WINHTTP_CALLBACK_FLAG_REQUEST_ERROR | WINHTTP_CALLBACK_FLAG_HANDLES;
+
+                // WINHTTP_INVALID_STATUS_CALLBACK is returned on failure.
+                if WinHttpSetStatusCallback(handle.0, Some(callback), flags, 0).map_or(0, |previous| previous as usize) == usize::MAX {
+                    return Err(Error::from_win32());
+                }
+            }
+
+            Ok(HttpSession(Arc::new(Session { handle, asynchronous: self.asynchronous })))
+        }
+    }
+}
+
+// winhttp.h defines this as a combination of the handle status values, so the metadata omits it.
+const WINHTTP_CALLBACK_FLAG_HANDLES: u32 = WINHTTP_CALLBACK_STATUS_HANDLE_CREATED | WINHTTP_CALLBACK_STATUS_HANDLE_CLOSING;
+
+fn pcwstr(value: &Option<HSTRING>) -> PCWSTR {
+    value.as_ref().map_or(PCWSTR::null(), |value| PCWSTR(value.as_ptr()))
+}
+
+/// A WinHTTP session, which uses the system's proxy and TLS configuration.
+///
+/// Sessions are cheap to clone and share a single session handle. Connections made by the session
+/// keep it open until they are dropped.
+///
+/// ```rust,ignore
+/// let session = HttpSession::new("Contoso/1.0")?;
+/// let response = session.request("GET", "https://example.com/")?.send(&[])?;
+/// assert_eq!(response.status(), 200);
+/// println!("{}", response.text()?);
+/// ```
+#[derive(Clone)]
+pub struct HttpSession(Arc<Session>);
+
+struct Session {
+    handle: Handle,
+    asynchronous: bool,
+}
+
+impl HttpSession {
+    /// Opens a synchronous session with the system's proxy settings.
+    pub fn new(user_agent: &str) -> Result<Self> {
+        HttpSessionBuilder::new(user_agent).open()
+    }
+
+    /// Returns the raw session handle.
+    pub fn handle(&self) -> *mut c_void {
+        self.0.handle.0
+    }
+
+    /// Returns `true` if the session was opened in asynchronous mode.
+    pub fn is_async(&self) -> bool {
+        self.0.asynchronous
+    }
+
+    /// Creates a connection to a server. Secure connections use TLS for all of their requests.
+    ///
+    /// No network traffic occurs until a request is sent.
+    pub fn connect(&self, host: &str, port: u16, secure: bool) -> Result<HttpConnection> {
+        let handle = Handle::new(unsafe { WinHttpConnect(self.handle(), &HSTRING::from(host), port, 0) })?;
+        Ok(HttpConnection(Arc::new(Connection { handle, secure, session: self.clone() })))
+    }
+
+    /// Creates a request for an `http` or `https` URL, connecting to the URL's server.
+    pub fn request(&self, method: &str, url: &str) -> Result<HttpRequest> {
+        let url: Vec<u16> = url.encode_utf16().collect();
+
+        let mut components = URL_COMPONENTS {
+            dwStructSize: core::mem::size_of::<URL_COMPONENTS>() as u32,
+            dwHostNameLength: u32::MAX,
+            dwUrlPathLength: u32::MAX,
+            dwExtraInfoLength: u32::MAX,
+            ..Default::default()
+        };
+
+        unsafe {
+            WinHttpCrackUrl(&url, 0, &mut components)?;
+
+            let host = String::from_utf16_lossy(core::slice::from_raw_parts(components.lpszHostName.0, components.dwHostNameLength as usize));
+            let mut path = String::from_utf16_lossy(core::slice::from_raw_parts(components.lpszUrlPath.0, components.dwUrlPathLength as usize));
+            path.push_str(&String::from_utf16_lossy(core::slice::from_raw_parts(components.lpszExtraInfo.0, components.dwExtraInfoLength as usize)));
+
+            if path.is_empty() {
+                path.push('/');
+            }
+
+            self.connect(&host, components.nPort, components.nScheme == WINHTTP_INTERNET_SCHEME_HTTPS)?.request(method, &path)
+        }
+    }
+}
+
+/// A connection to a server, from which requests are made.
+///
+/// WinHTTP pools the underlying network connections, so a connection may be shared by many requests,
+/// each of which keeps the connection open until it is dropped.
+#[derive(Clone)]
+pub struct HttpConnection(Arc<Connection>);
+
+struct Connection {
+    handle: Handle,
+    secure: bool,
+    session: HttpSession,
+}
+
+impl HttpConnection {
+    /// Returns the raw connection handle.
+    pub fn handle(&self) -> *mut c_void {
+        self.0.handle.0
+    }
+
+    /// Returns the session that the connection was made from.
+    pub fn session(&self) -> &HttpSession {
+        &self.0.session
+    }
+
+    /// Creates a request for a path on the server, such as `/index.html?page=2`.
+    pub fn request(&self, method: &str, path: &str) -> Result<HttpRequest> {
+        let flags = if self.0.secure { WINHTTP_FLAG_SECURE } else { WINHTTP_OPEN_REQUEST_FLAGS(0) };
+
+        unsafe {
+            let handle = Handle::new(WinHttpOpenRequest(self.handle(), &HSTRING::from(method), &HSTRING::from(path), PCWSTR::null(), PCWSTR::null(), core::ptr::null(), flags))?;
+
+            let operation = if self.0.session.is_async() {
+                let operation = Arc::new(Operation::default());
+
+                // WinHTTP holds a reference to the operation until it reports that the handle is closing.
+                let raw = Arc::into_raw(operation.clone());
+
+                if let Err(error) = WinHttpSetOption(Some(handle.0), WINHTTP_OPTION_CONTEXT_VALUE, Some(&(raw as usize).to_ne_bytes())) {
+                    drop(Arc::from_raw(raw));
+                    return Err(error);
+                }
+
+                Some(operation)
+            } else {
+                None
+            };
+
+            Ok(HttpRequest(Request { handle, operation, connection: self.clone() }))
+        }
+    }
+}
+
+// The state shared by the request, body, and response types.
+struct Request {
+    handle: Handle,
+    // Set if the request belongs to an asynchronous session.
+    operation: Option<Arc<Operation>>,
+    connection: HttpConnection,
+}
+
+impl Request {
+    fn sync(&self) -> Result<()> {
+        match self.operation {
+            None => Ok(()),
+            Some(_) => Err(HRESULT::from_win32(ERROR_WINHTTP_INCORRECT_HANDLE_TYPE).into()),
+        }
+    }
+
+    fn operation(&self) -> Result<&Arc<Operation>> {
+        self.operation.as_ref().ok_or_else(|| HRESULT::from_win32(ERROR_WINHTTP_INCORRECT_HANDLE_TYPE).into())
+    }
+
+    fn read(&self, buffer: &mut [u8]) -> Result<usize> {
+        self.sync()?;
+        let mut read = 0;
+        unsafe { WinHttpReadData(self.handle.0, buffer.as_mut_ptr() as _, buffer.len().try_into()?, &mut read)? };
+        Ok(read as usize)
+    }
+
+    fn write(&self, buffer: &[u8]) -> Result<usize> {
+        self.sync()?;
+        let mut written = 0;
+        unsafe { WinHttpWriteData(self.handle.0, Some(buffer.as_ptr() as _), buffer.len().try_into()?, &mut written)? };
+        Ok(written as usize)
+    }
+
+    fn receive(self) -> Result<HttpResponse> {
+        self.sync()?;
+        unsafe { WinHttpReceiveResponse(self.handle.0, core::ptr::null_mut())? };
+        HttpResponse::new(self)
+    }
+
+    async fn receive_async(self) -> Result<HttpResponse> {
+        let handle = self.handle.0;
+        self.operation()?.start(Vec::new(), |_| unsafe { WinHttpReceiveResponse(handle, core::ptr::null_mut()) })?.await?;
+        HttpResponse::new(self)
+    }
+}
+
+/// An HTTP request that has not yet been sent.
+pub struct HttpRequest(Request);
+
+impl HttpRequest {
+    /// Returns the raw request handle.
+    pub fn handle(&self) -> *mut c_void {
+        self.0.handle.0
+    }
+
+    /// Adds a header to the request, replacing any existing header with the same name.
+    pub fn header(&self, name: &str, value: &str) -> Result<()> {
+        let header: Vec<u16> = format!("{name}: {value}").encode_utf16().collect();
+        unsafe { WinHttpAddRequestHeaders(self.handle(), &header, WINHTTP_ADDREQ_FLAG_ADD | WINHTTP_ADDREQ_FLAG_REPLACE) }
+    }
+
+    /// Accepts the server's certificate even if it is expired, untrusted, or issued for another host.
+    ///
+    /// This should only be used when testing against servers with self-signed certificates.
+    pub fn ignore_certificate_errors(&self) -> Result<()> {
+        let flags = SECURITY_FLAG_IGNORE_UNKNOWN_CA | SECURITY_FLAG_IGNORE_CERT_DATE_INVALID | SECURITY_FLAG_IGNORE_CERT_CN_INVALID | SECURITY_FLAG_IGNORE_CERT_WRONG_USAGE;
+        unsafe { WinHttpSetOption(Some(self.handle()), WINHTTP_OPTION_SECURITY_FLAGS, Some(&flags.to_ne_bytes())) }
+    }
+
+    /// Sends the request with the given body and waits for the response headers.
+    pub fn send(self, body: &[u8]) -> Result<HttpResponse> {
+        self.0.sync()?;
+        let len = body.len().try_into()?;
+        unsafe { WinHttpSendRequest(self.handle(), None, (!body.is_empty()).then_some(body.as_ptr() as _), len, len, 0)? };
+        self.0.receive()
+    }
+
+    /// Sends the request headers, returning a writer for a body of exactly `len` bytes.
+    pub fn stream(self, len: u32) -> Result<HttpRequestBody> {
+        self.0.sync()?;
+        unsafe { WinHttpSendRequest(self.handle(), None, None, 0, len, 0)? };
+        Ok(HttpRequestBody(self.0))
+    }
+
+    /// Upgrades the request to a WebSocket connection, using the URL the request was created with.
+    ///
+    /// WebSockets are only supported by synchronous sessions.
+    pub fn upgrade(self) -> Result<WebSocket> {
+        self.0.sync()?;
+
+        unsafe {
+            WinHttpSetOption(Some(self.handle()), WINHTTP_OPTION_UPGRADE_TO_WEB_SOCKET, None)?;
+            WinHttpSendRequest(self.handle(), None, None, 0, 0, 0)?;
+        }
+
+        let response = self.0.receive()?;
+
+        if response.status != 101 {
+            return Err(HRESULT::from_win32(ERROR_WINHTTP_INVALID_SERVER_RESPONSE).into());
+        }
+
+        let handle = Handle::new(unsafe { WinHttpWebSocketCompleteUpgrade(response.handle(), 0) })?;
+        Ok(WebSocket { handle, _connection: response.request.connection.clone() })
+    }
+
+    /// Sends the request from an asynchronous session with the given body and waits for the response headers.
+    pub async fn send_async(self, body: Vec<u8>) -> Result<HttpResponse> {
+        let handle = self.handle();
+        let len = body.len().try_into()?;
+        self.0.operation()?.start(body, |body| unsafe { WinHttpSendRequest(handle, None, (!body.is_empty()).then_some(body.as_ptr() as _), len, len, 0) })?.await?;
+        self.0.receive_async().await
+    }
+
+    /// Sends the request headers from an asynchronous session, returning a writer for a body of exactly `len` bytes.
+    pub async fn stream_async(self, len: u32) -> Result<HttpRequestBody> {
+        let handle = self.handle();
+        self.0.operation()?.start(Vec::new(), |_| unsafe { WinHttpSendRequest(handle, None, None, 0, len, 0) })?.await?;
+        Ok(HttpRequestBody(self.0))
+    }
+}
+
+/// The body of a request that is being streamed to the server.
+///
+/// This is produced by `HttpRequest::stream`. Once the whole body has been written, call `finish` to
+/// receive the response.
+pub struct HttpRequestBody(Request);
+
+impl HttpRequestBody {
+    /// Returns the raw request handle.
+    pub fn handle(&self) -> *mut c_void {
+        self.0.handle.0
+    }
+
+    /// Waits for the response headers once the whole body has been written.
+    pub fn finish(self) -> Result<HttpResponse> {
+        self.0.receive()
+    }
+
+    /// Writes part of the body from an asynchronous session, returning the number of bytes written.
+    pub async fn write_async(&mut self, buffer: Vec<u8>) -> Result<usize> {
+        let handle = self.handle();
+        let len = buffer.len().try_into()?;
+        let (written, _) = self.0.operation()?.start(buffer, |buffer| unsafe { WinHttpWriteData(handle, Some(buffer.as_ptr() as _), len, core::ptr::null_mut()) })?.await?;
+        Ok(written as usize)
+    }
+
+    /// Waits for the response headers from an asynchronous session once the whole body has been written.
+    pub async fn finish_async(self) -> Result<HttpResponse> {
+        self.0.receive_async().await
+    }
+}
+
+impl std::io::Write for HttpRequestBody {
+    fn write(&mut self, buffer: &[u8]) -> std::io::Result<usize> {
+        Ok(self.0.write(buffer)?)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The response to a request, whose body is read as a stream.
+pub struct HttpResponse {
+    request: Request,
+    status: u16,
+}
+
+impl HttpResponse {
+    fn new(request: Request) -> Result<Self> {
+        let mut status = 0u32;
+        let mut len = core::mem::size_of::<u32>() as u32;
+        unsafe { WinHttpQueryHeaders(request.handle.0, WINHTTP_QUERY_STATUS_CODE | WINHTTP_QUERY_FLAG_NUMBER, PCWSTR::null(), Some(&mut status as *mut u32 as _), &mut len, core::ptr::null_mut())? };
+        Ok(Self { request, status: status as u16 })
+    }
+
+    /// Returns the raw request handle.
+    pub fn handle(&self) -> *mut c_void {
+        self.request.handle.0
+    }
+
+    /// Returns the HTTP status code.
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// Returns the status line and headers, separated by CRLF.
+    pub fn headers(&self) -> Result<String> {
+        self.query(WINHTTP_QUERY_RAW_HEADERS_CRLF, PCWSTR::null()).map(Option::unwrap_or_default)
+    }
+
+    /// Returns the value of a header, or `None` if the response doesn't include it.
+    pub fn header(&self, name: &str) -> Result<Option<String>> {
+        let name = HSTRING::from(name);
+        self.query(WINHTTP_QUERY_CUSTOM, PCWSTR(name.as_ptr()))
+    }
+
+    fn query(&self, level: u32, name: PCWSTR) -> Result<Option<String>> {
+        let mut len = 0;
+
+        unsafe {
+            match WinHttpQueryHeaders(self.handle(), level, name, None, &mut len, core::ptr::null_mut()) {
+                Err(error) if error.code() == HRESULT::from_win32(ERROR_WINHTTP_HEADER_NOT_FOUND) => return Ok(None),
+                Err(error) if error.code() != ERROR_INSUFFICIENT_BUFFER.to_hresult() => return Err(error),
+                _ => {}
+            }
+
+            let mut buffer = vec![0u16; len as usize / 2];
+            WinHttpQueryHeaders(self.handle(), level, name, Some(buffer.as_mut_ptr() as _), &mut len, core::ptr::null_mut())?;
+            buffer.truncate(len as usize / 2);
+            Ok(Some(String::from_utf16_lossy(&buffer)))
+        }
+    }
+
+    /// Reads the rest of the body.
+    pub fn bytes(mut self) -> Result<Vec<u8>> {
+        let mut body = Vec::new();
+        std::io::Read::read_to_end(&mut self, &mut body)?;
+        Ok(body)
+    }
+
+    /// Reads the rest of the body as UTF-8 text, replacing invalid sequences.
+    pub fn text(self) -> Result<String> {
+        Ok(String::from_utf8_lossy(&self.bytes()?).into_owned())
+    }
+
+    /// Reads up to `len` bytes of the body from an asynchronous session. An empty buffer marks the end of the body.
+    pub async fn read_async(&mut self, len: usize) -> Result<Vec<u8>> {
+        let handle = self.handle();
+        let (read, mut buffer) = self.request.operation()?.start(vec![0; len], |buffer| unsafe { WinHttpReadData(handle, buffer.as_mut_ptr() as _, buffer.len() as u32, core::ptr::null_mut()) })?.await?;
+        buffer.truncate(read as usize);
+        Ok(buffer)
+    }
+
+    /// Reads the rest of the body from an asynchronous session.
+    pub async fn bytes_async(mut self) -> Result<Vec<u8>> {
+        let mut body = Vec::new();
+
+        loop {
+            let buffer = self.read_async(16 * 1024).await?;
+
+            if buffer.is_empty() {
+                return Ok(body);
+            }
+
+            body.extend_from_slice(&buffer);
+        }
+    }
+}
+
+impl std::io::Read for HttpResponse {
+    fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+        Ok(self.request.read(buffer)?)
+    }
+}
+
+// Tracks the single outstanding operation of an asynchronous request.
+#[derive(Default)]
+struct Operation {
+    state: Mutex<OperationState>,
+}
+
+#[derive(Default)]
+struct OperationState {
+    // Set while WinHTTP may be using the buffer.
+    busy: bool,
+    buffer: Vec<u8>,
+    result: Option<Result<u32>>,
+    waker: Option<Waker>,
+}
+
+impl Operation {
+    fn start<F: FnOnce(&mut [u8]) -> Result<()>>(self: &Arc<Self>, buffer: Vec<u8>, start: F) -> Result<OperationFuture> {
+        let mut state = self.state.lock().unwrap();
+
+        if state.busy {
+            return Err(HRESULT::from_win32(ERROR_WINHTTP_INCORRECT_HANDLE_STATE).into());
+        }
+
+        state.busy = true;
+        state.buffer = buffer;
+        state.result = None;
+
+        // The buffer's allocation doesn't move while it is owned by the operation.
+        let buffer = unsafe { core::slice::from_raw_parts_mut(state.buffer.as_mut_ptr(), state.buffer.len()) };
+
+        // The completion callback may run on this thread before the call returns.
+        drop(state);
+
+        if let Err(error) = start(buffer) {
+            // No completion is reported for operations that fail immediately.
+            let mut state = self.state.lock().unwrap();
+            state.busy = false;
+            state.buffer = Vec::new();
+            return Err(error);
+        }
+
+        Ok(OperationFuture(self.clone()))
+    }
+
+    fn complete(&self, result: Result<u32>) {
+        let mut state = self.state.lock().unwrap();
+        state.busy = false;
+        state.result = Some(result);
+
+        if let Some(waker) = state.waker.take() {
+            drop(state);
+            waker.wake();
+        }
+    }
+}
+
+// Completes with the operation's result and its buffer.
+struct OperationFuture(Arc<Operation>);
+
+impl Future for OperationFuture {
+    type Output = Result<(u32, Vec<u8>)>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.0.state.lock().unwrap();
+
+        match state.result.take() {
+            Some(result) => Poll::Ready(result.map(|value| (value, core::mem::take(&mut state.buffer)))),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+unsafe extern "system" fn callback(_handle: *mut c_void, context: usize, status: u32, info: *mut c_void, len: u32) {
+    if context == 0 {
+        return;
+    }
+
+    // Reclaims the reference that was handed to WinHTTP when the request was created.
+    if status == WINHTTP_CALLBACK_STATUS_HANDLE_CLOSING {
+        drop(Arc::from_raw(context as *const Operation));
+        return;
+    }
+
+    let operation = &*(context as *const Operation);
+
+    match status {
+        WINHTTP_CALLBACK_STATUS_SENDREQUEST_COMPLETE | WINHTTP_CALLBACK_STATUS_HEADERS_AVAILABLE => operation.complete(Ok(0)),
+        WINHTTP_CALLBACK_STATUS_READ_COMPLETE => operation.complete(Ok(len)),
+        WINHTTP_CALLBACK_STATUS_WRITE_COMPLETE => operation.complete(Ok(*(info as *const u32))),
+        WINHTTP_CALLBACK_STATUS_REQUEST_ERROR => operation.complete(Err(HRESULT::from_win32((*(info as *const WINHTTP_ASYNC_RESULT)).dwError).into())),
+        _ => {}
+    }
+}
+
+/// A message received from a `WebSocket`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WebSocketMessage {
+    /// A UTF-8 text message.
+    Text(String),
+    /// A binary message.
+    Binary(Vec<u8>),
+    /// The server closed the connection with a status code and reason.
+    Close(u16, String),
+}
+
+/// A WebSocket connection, created by upgrading an `HttpRequest`.
+///
+/// ```rust,ignore
+/// let session = HttpSession::new("Contoso/1.0")?;
+/// let socket = session.request("GET", "wss://example.com/chat")?.upgrade()?;
+/// socket.send_text("hello")?;
+/// println!("{:?}", socket.receive()?);
+/// socket.close(1000, "")?;
+/// ```
+pub struct WebSocket {
+    handle: Handle,
+    _connection: HttpConnection,
+}
+
+impl WebSocket {
+    /// Returns the raw WebSocket handle.
+    pub fn handle(&self) -> *mut c_void {
+        self.handle.0
+    }
+
+    /// Sends a text message.
+    pub fn send_text(&self, text: &str) -> Result<()> {
+        check(unsafe { WinHttpWebSocketSend(self.handle(), WINHTTP_WEB_SOCKET_UTF8_MESSAGE_BUFFER_TYPE, Some(text.as_bytes())) })
+    }
+
+    /// Sends a binary message.
+    pub fn send_binary(&self, data: &[u8]) -> Result<()> {
+        check(unsafe { WinHttpWebSocketSend(self.handle(), WINHTTP_WEB_SOCKET_BINARY_MESSAGE_BUFFER_TYPE, Some(data)) })
+    }
+
+    /// Waits for the next message, joining fragmented messages together.
+    pub fn receive(&self) -> Result<WebSocketMessage> {
+        let mut message = Vec::new();
+        let mut buffer = [0u8; 4096];
+
+        loop {
+            let mut read = 0;
+            let mut kind = WINHTTP_WEB_SOCKET_BUFFER_TYPE::default();
+            check(unsafe { WinHttpWebSocketReceive(self.handle(), buffer.as_mut_ptr() as _, buffer.len() as u32, &mut read, &mut kind) })?;
+            message.extend_from_slice(&buffer[..read as usize]);
+
+            match kind {
+                WINHTTP_WEB_SOCKET_UTF8_MESSAGE_BUFFER_TYPE => return Ok(WebSocketMessage::Text(String::from_utf8_lossy(&message).into_owned())),
+                WINHTTP_WEB_SOCKET_BINARY_MESSAGE_BUFFER_TYPE => return Ok(WebSocketMessage::Binary(message)),
+                WINHTTP_WEB_SOCKET_CLOSE_BUFFER_TYPE => return self.close_status(),
+                _ => {}
+            }
+        }
+    }
+
+    fn close_status(&self) -> Result<WebSocketMessage> {
+        let mut status = 0;
+        let mut reason = [0u8; 123];
+        let mut len = 0;
+        check(unsafe { WinHttpWebSocketQueryCloseStatus(self.handle(), &mut status, Some(reason.as_mut_ptr() as _), reason.len() as u32, &mut len) })?;
+        Ok(WebSocketMessage::Close(status, String::from_utf8_lossy(&reason[..len as usize]).into_owned()))
+    }
+
+    /// Closes the connection with a status code and reason, waiting for the server to acknowledge it.
+    pub fn close(&self, status: u16, reason: &str) -> Result<()> {
+        check(unsafe { WinHttpWebSocketClose(self.handle(), status, (!reason.is_empty()).then_some(reason.as_ptr() as _), reason.len() as u32) })
+    }
+}
+
+fn check(error: u32) -> Result<()> {
+    if error == 0 {
+        Ok(())
+    } else {
+        Err(HRESULT::from_win32(error).into())
+    }
+}
@@ -3,3 +3,7 @@ mod IN_ADDR;
 mod SOCKADDR_IN;
 mod SOCKADDR_IN6;
 mod SOCKADDR_INET;
+#[cfg(feature = "std")]
+mod SOCKET;
+#[cfg(feature = "std")]
+mod WSAPROTOCOL_INFOW;
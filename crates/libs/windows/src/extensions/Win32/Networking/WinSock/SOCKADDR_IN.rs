@@ -7,3 +7,9 @@ impl From<std::net::SocketAddrV4> for SOCKADDR_IN {
         SOCKADDR_IN { sin_family: AF_INET, sin_port: addr.port().to_be(), sin_addr: (*addr.ip()).into(), ..Default::default() }
     }
 }
+impl From<SOCKADDR_IN> for std::net::SocketAddrV4 {
+    fn from(addr: SOCKADDR_IN) -> Self {
+        // sin_port is big-endian, network byte order
+        Self::new(addr.sin_addr.into(), u16::from_be(addr.sin_port))
+    }
+}
@@ -15,3 +15,10 @@ impl From<std::net::SocketAddrV6> for SOCKADDR_IN6 {
         }
     }
 }
+impl From<SOCKADDR_IN6> for std::net::SocketAddrV6 {
+    fn from(addr: SOCKADDR_IN6) -> Self {
+        // sin6_port and sin6_flowinfo are big-endian, network byte order
+        // SAFETY: sin6_scope_id and sin6_scope_struct are views of the same data
+        Self::new(addr.sin6_addr.into(), u16::from_be(addr.sin6_port), u32::from_be(addr.sin6_flowinfo), unsafe { addr.Anonymous.sin6_scope_id })
+    }
+}
@@ -1,4 +1,4 @@
-use crate::Win32::Networking::WinSock::SOCKADDR_INET;
+use crate::Win32::Networking::WinSock::{AF_INET, AF_INET6, SOCKADDR_INET, WSAEAFNOSUPPORT};
 
 impl From<std::net::SocketAddrV4> for SOCKADDR_INET {
     fn from(addr: std::net::SocketAddrV4) -> Self {
@@ -18,3 +18,16 @@ impl From<std::net::SocketAddr> for SOCKADDR_INET {
         }
     }
 }
+impl TryFrom<SOCKADDR_INET> for std::net::SocketAddr {
+    type Error = windows_core::Error;
+    fn try_from(addr: SOCKADDR_INET) -> windows_core::Result<Self> {
+        // SAFETY: the family is shared by both variants and identifies the one in use
+        unsafe {
+            match addr.si_family {
+                AF_INET => Ok(Self::V4(addr.Ipv4.into())),
+                AF_INET6 => Ok(Self::V6(addr.Ipv6.into())),
+                _ => Err(windows_core::Error::from_hresult(windows_core::HRESULT::from_win32(WSAEAFNOSUPPORT.0 as u32))),
+            }
+        }
+    }
+}
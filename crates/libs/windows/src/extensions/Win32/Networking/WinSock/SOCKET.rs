@@ -0,0 +1,12 @@
+use crate::Win32::Networking::WinSock::SOCKET;
+use std::os::windows::io::RawSocket;
+
+unsafe impl windows_core::SocketHandle for SOCKET {
+    fn from_raw_socket(socket: RawSocket) -> Self {
+        Self(socket as usize)
+    }
+
+    fn as_raw_socket(&self) -> RawSocket {
+        self.0 as RawSocket
+    }
+}
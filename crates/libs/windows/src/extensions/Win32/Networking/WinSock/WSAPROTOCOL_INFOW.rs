@@ -0,0 +1,64 @@
+use crate::Win32::Networking::WinSock::*;
+use std::os::windows::io::{AsRawSocket, AsSocket, OwnedSocket};
+use windows_core::{Owned, Result};
+
+impl WSAPROTOCOL_INFOW {
+    /// Returns the protocol information for a socket, such as a `TcpStream`.
+    pub fn from_socket<S: AsSocket>(socket: &S) -> Result<Self> {
+        let mut info = Self::default();
+        let mut len = core::mem::size_of::<Self>() as i32;
+
+        if unsafe { getsockopt(raw(socket), SOL_SOCKET, SO_PROTOCOL_INFOW, windows_core::PSTR(&mut info as *mut _ as _), &mut len) } != 0 {
+            return Err(windows_core::Error::from_win32());
+        }
+
+        Ok(info)
+    }
+
+    /// Duplicates a socket for use by another process, which can then open it with `create_socket`.
+    pub fn duplicate<S: AsSocket>(socket: &S, process_id: u32) -> Result<Self> {
+        let mut info = Self::default();
+
+        if unsafe { WSADuplicateSocketW(raw(socket), process_id, &mut info) } != 0 {
+            return Err(windows_core::Error::from_win32());
+        }
+
+        Ok(info)
+    }
+
+    /// Creates a socket from the protocol information, such as one duplicated by another process.
+    pub fn create_socket(&self) -> Result<Owned<SOCKET>> {
+        startup();
+        unsafe { WSASocketW(FROM_PROTOCOL_INFO, FROM_PROTOCOL_INFO, FROM_PROTOCOL_INFO, Some(self), 0, WSA_FLAG_OVERLAPPED).map(|socket| Owned::new(socket)) }
+    }
+}
+
+fn raw<S: AsSocket>(socket: &S) -> SOCKET {
+    SOCKET(socket.as_socket().as_raw_socket() as usize)
+}
+
+// The standard library only initializes Windows Sockets once a socket is created, which may not have happened in a
+// process that receives a duplicated socket.
+fn startup() {
+    static STARTUP: std::sync::Once = std::sync::Once::new();
+
+    STARTUP.call_once(|| unsafe {
+        let mut data = WSADATA::default();
+        WSAStartup(0x202, &mut data);
+    });
+}
+
+macro_rules! socket_from_protocol_info {
+    ($($ty:ty),*) => {
+        $(
+            impl TryFrom<&WSAPROTOCOL_INFOW> for $ty {
+                type Error = windows_core::Error;
+                fn try_from(info: &WSAPROTOCOL_INFOW) -> Result<Self> {
+                    Ok(OwnedSocket::from(info.create_socket()?).into())
+                }
+            }
+        )*
+    };
+}
+
+socket_from_protocol_info!(std::net::TcpStream, std::net::TcpListener, std::net::UdpSocket);
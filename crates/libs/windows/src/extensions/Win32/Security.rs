@@ -0,0 +1,8 @@
+#[cfg(all(feature = "Win32_Security_Cryptography", feature = "std"))]
+pub mod Cryptography;
+#[cfg(all(feature = "Win32_Security_Authorization", feature = "Win32_System_SystemServices", feature = "std"))]
+pub mod Descriptor;
+#[cfg(feature = "std")]
+pub mod Sid;
+#[cfg(all(feature = "Win32_System_SystemServices", feature = "Win32_System_Threading", feature = "std"))]
+pub mod Token;
@@ -0,0 +1,3 @@
+#[cfg(feature = "Win32_Foundation")]
+pub mod Bcrypt;
+pub mod DataProtection;
@@ -0,0 +1,174 @@
+use crate::Win32::Foundation::*;
+use crate::Win32::Security::Cryptography::*;
+use core::marker::PhantomData;
+use windows_core::{Owned, Result, PCWSTR};
+
+/// An open CNG algorithm provider, such as `BCRYPT_SHA256_ALGORITHM`.
+pub struct Algorithm(Owned<BCRYPT_ALG_HANDLE>);
+
+impl Algorithm {
+    /// Opens the algorithm provider with the given identifier, such as `BCRYPT_SHA256_ALGORITHM`.
+    pub fn open(id: PCWSTR) -> Result<Self> {
+        Self::open_with_flags(id, BCRYPT_OPEN_ALGORITHM_PROVIDER_FLAGS(0))
+    }
+
+    /// Opens a hash algorithm provider for computing HMACs, such as `BCRYPT_SHA256_ALGORITHM`.
+    pub fn open_hmac(id: PCWSTR) -> Result<Self> {
+        Self::open_with_flags(id, BCRYPT_ALG_HANDLE_HMAC_FLAG)
+    }
+
+    fn open_with_flags(id: PCWSTR, flags: BCRYPT_OPEN_ALGORITHM_PROVIDER_FLAGS) -> Result<Self> {
+        unsafe {
+            let mut handle = Owned::default();
+            BCryptOpenAlgorithmProvider(&mut *handle, id, None, flags).ok()?;
+            Ok(Self(handle))
+        }
+    }
+
+    /// Returns the provider handle, which is valid for the lifetime of the `Algorithm`.
+    pub fn handle(&self) -> BCRYPT_ALG_HANDLE {
+        *self.0
+    }
+
+    /// Returns the length in bytes of the digests produced by a hash algorithm.
+    pub fn hash_len(&self) -> Result<usize> {
+        let mut len = [0; 4];
+        let mut copied = 0;
+        unsafe { BCryptGetProperty(self.handle(), BCRYPT_HASH_LENGTH, Some(&mut len), &mut copied, 0).ok()? };
+        Ok(u32::from_le_bytes(len) as usize)
+    }
+
+    /// Starts a streaming hash.
+    pub fn hash(&self) -> Result<Hash<'_>> {
+        Hash::new(self, None)
+    }
+
+    /// Starts a streaming HMAC with the given key. The algorithm must be opened with `open_hmac`.
+    pub fn hmac(&self, key: &[u8]) -> Result<Hash<'_>> {
+        Hash::new(self, Some(key))
+    }
+}
+
+/// A streaming hash or HMAC computation, created by `Algorithm::hash` or `Algorithm::hmac`.
+pub struct Hash<'a> {
+    handle: Owned<BCRYPT_HASH_HANDLE>,
+    len: usize,
+    algorithm: PhantomData<&'a Algorithm>,
+}
+
+impl<'a> Hash<'a> {
+    fn new(algorithm: &'a Algorithm, key: Option<&[u8]>) -> Result<Self> {
+        unsafe {
+            let mut handle = Owned::default();
+            BCryptCreateHash(algorithm.handle(), &mut *handle, None, key, 0).ok()?;
+            Ok(Self { handle, len: algorithm.hash_len()?, algorithm: PhantomData })
+        }
+    }
+
+    /// Adds data to the hash.
+    pub fn update(&mut self, data: &[u8]) -> Result<()> {
+        unsafe { BCryptHashData(*self.handle, data, 0).ok() }
+    }
+
+    /// Completes the hash and returns the digest.
+    pub fn finish(self) -> Result<Vec<u8>> {
+        let mut digest = vec![0; self.len];
+        unsafe { BCryptFinishHash(*self.handle, &mut digest, 0).ok()? };
+        Ok(digest)
+    }
+}
+
+/// Computes the SHA-256 digest of the data.
+pub fn sha256(data: &[u8]) -> Result<[u8; 32]> {
+    let mut digest = [0; 32];
+    unsafe { BCryptHash(BCRYPT_SHA256_ALG_HANDLE, None, data, &mut digest).ok()? };
+    Ok(digest)
+}
+
+/// Computes the HMAC-SHA-256 of the data with the given key.
+pub fn hmac_sha256(key: &[u8], data: &[u8]) -> Result<[u8; 32]> {
+    let mut digest = [0; 32];
+    unsafe { BCryptHash(BCRYPT_HMAC_SHA256_ALG_HANDLE, Some(key), data, &mut digest).ok()? };
+    Ok(digest)
+}
+
+/// Fills the buffer with cryptographically secure random bytes from the system-preferred generator.
+pub fn random(buffer: &mut [u8]) -> Result<()> {
+    unsafe { BCryptGenRandom(BCRYPT_ALG_HANDLE::default(), buffer, BCRYPT_USE_SYSTEM_PREFERRED_RNG).ok() }
+}
+
+/// An AES key for authenticated encryption in Galois/Counter Mode.
+///
+/// Encrypted data is returned with the 16 byte authentication tag appended, and `decrypt` verifies
+/// the tag before returning any data. A nonce must never be reused with the same key.
+///
+/// ```rust,ignore
+/// let mut key = [0; 32];
+/// let mut nonce = [0; AesGcm::NONCE_LEN];
+/// random(&mut key)?;
+/// random(&mut nonce)?;
+///
+/// let aes = AesGcm::new(&key)?;
+/// let sealed = aes.encrypt(&nonce, b"secret", b"header")?;
+/// assert_eq!(aes.decrypt(&nonce, &sealed, b"header")?, b"secret");
+/// ```
+pub struct AesGcm(Owned<BCRYPT_KEY_HANDLE>);
+
+impl AesGcm {
+    /// The length in bytes of the nonce.
+    pub const NONCE_LEN: usize = 12;
+
+    /// The length in bytes of the authentication tag appended to encrypted data.
+    pub const TAG_LEN: usize = 16;
+
+    /// Creates a key from 16, 24, or 32 bytes of secret key material.
+    pub fn new(key: &[u8]) -> Result<Self> {
+        unsafe {
+            let mut handle = Owned::default();
+            BCryptGenerateSymmetricKey(BCRYPT_AES_GCM_ALG_HANDLE, &mut *handle, None, key, 0).ok()?;
+            Ok(Self(handle))
+        }
+    }
+
+    /// Encrypts the data and authenticates it along with the additional data, returning the
+    /// ciphertext followed by the authentication tag.
+    pub fn encrypt(&self, nonce: &[u8; Self::NONCE_LEN], data: &[u8], additional: &[u8]) -> Result<Vec<u8>> {
+        let mut output = vec![0; data.len() + Self::TAG_LEN];
+        let (ciphertext, tag) = output.split_at_mut(data.len());
+        let info = mode_info(nonce, additional, tag.as_mut_ptr())?;
+        let mut len = 0;
+
+        unsafe { BCryptEncrypt(*self.0, Some(data), Some(&info as *const _ as _), None, Some(ciphertext), &mut len, BCRYPT_FLAGS(0)).ok()? };
+        Ok(output)
+    }
+
+    /// Verifies and decrypts data returned by `encrypt`. Fails with `STATUS_AUTH_TAG_MISMATCH` if the data,
+    /// nonce, or additional data don't match.
+    pub fn decrypt(&self, nonce: &[u8; Self::NONCE_LEN], data: &[u8], additional: &[u8]) -> Result<Vec<u8>> {
+        let Some(split) = data.len().checked_sub(Self::TAG_LEN) else {
+            return Err(STATUS_AUTH_TAG_MISMATCH.into());
+        };
+
+        let (ciphertext, tag) = data.split_at(split);
+        let info = mode_info(nonce, additional, tag.as_ptr() as _)?;
+        let mut output = vec![0; ciphertext.len()];
+        let mut len = 0;
+
+        unsafe { BCryptDecrypt(*self.0, Some(ciphertext), Some(&info as *const _ as _), None, Some(&mut output), &mut len, BCRYPT_FLAGS(0)).ok()? };
+        Ok(output)
+    }
+}
+
+fn mode_info(nonce: &[u8], additional: &[u8], tag: *mut u8) -> Result<BCRYPT_AUTHENTICATED_CIPHER_MODE_INFO> {
+    Ok(BCRYPT_AUTHENTICATED_CIPHER_MODE_INFO {
+        cbSize: core::mem::size_of::<BCRYPT_AUTHENTICATED_CIPHER_MODE_INFO>() as u32,
+        dwInfoVersion: BCRYPT_AUTHENTICATED_CIPHER_MODE_INFO_VERSION,
+        pbNonce: nonce.as_ptr() as _,
+        cbNonce: nonce.len() as u32,
+        pbAuthData: if additional.is_empty() { core::ptr::null_mut() } else { additional.as_ptr() as _ },
+        cbAuthData: additional.len().try_into()?,
+        pbTag: tag,
+        cbTag: AesGcm::TAG_LEN as u32,
+        ..Default::default()
+    })
+}
@@ -0,0 +1,156 @@
+use crate::Win32::Foundation::*;
+use crate::Win32::Security::Cryptography::*;
+use crate::Win32::Security::NCRYPT_DESCRIPTOR_HANDLE;
+use windows_core::{Result, HSTRING, PWSTR};
+
+/// The scope within which data protected with DPAPI may be unprotected.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ProtectionScope {
+    /// Only the current user, on the current machine, can unprotect the data.
+    #[default]
+    User,
+    /// Any user on the current machine can unprotect the data.
+    Machine,
+}
+
+/// Options for protecting data with `CryptProtectData`, for use when secrets such as configuration
+/// passwords must be stored at rest.
+///
+/// ```rust,ignore
+/// let options = DataProtection::new().entropy(b"my app").description("database password");
+/// let blob = options.protect(b"hunter2")?;
+/// assert_eq!(options.unprotect(&blob)?, b"hunter2");
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DataProtection<'a> {
+    scope: ProtectionScope,
+    entropy: &'a [u8],
+    description: &'a str,
+}
+
+impl<'a> DataProtection<'a> {
+    /// Creates options that protect data for the current user, without entropy or description.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the scope within which the data may be unprotected.
+    pub fn scope(mut self, scope: ProtectionScope) -> Self {
+        self.scope = scope;
+        self
+    }
+
+    /// Sets additional secret bytes that must also be provided to unprotect the data.
+    pub fn entropy(mut self, entropy: &'a [u8]) -> Self {
+        self.entropy = entropy;
+        self
+    }
+
+    /// Sets a readable description that is stored, unencrypted, along with the protected data.
+    pub fn description(mut self, description: &'a str) -> Self {
+        self.description = description;
+        self
+    }
+
+    /// Encrypts the data, returning an opaque blob.
+    pub fn protect(&self, data: &[u8]) -> Result<Vec<u8>> {
+        let mut flags = CRYPTPROTECT_UI_FORBIDDEN;
+
+        if self.scope == ProtectionScope::Machine {
+            flags |= CRYPTPROTECT_LOCAL_MACHINE;
+        }
+
+        let mut output = CRYPT_INTEGER_BLOB::default();
+
+        unsafe {
+            CryptProtectData(&blob(data)?, &HSTRING::from(self.description), self.entropy_blob()?.as_ref().map(|entropy| entropy as *const _), None, None, flags, &mut output)?;
+            Ok(take_blob(output))
+        }
+    }
+
+    /// Decrypts a blob returned by `protect`. The entropy must match the entropy used to protect it.
+    pub fn unprotect(&self, data: &[u8]) -> Result<Vec<u8>> {
+        self.unprotect_with_description(data).map(|(data, _)| data)
+    }
+
+    /// Decrypts a blob returned by `protect`, also returning the description stored with it.
+    pub fn unprotect_with_description(&self, data: &[u8]) -> Result<(Vec<u8>, String)> {
+        let mut output = CRYPT_INTEGER_BLOB::default();
+        let mut description = PWSTR::null();
+
+        unsafe {
+            CryptUnprotectData(&blob(data)?, Some(&mut description), self.entropy_blob()?.as_ref().map(|entropy| entropy as *const _), None, None, CRYPTPROTECT_UI_FORBIDDEN, &mut output)?;
+            let text = if description.is_null() { String::new() } else { description.to_string().unwrap_or_default() };
+            LocalFree(HLOCAL(description.0 as _));
+            Ok((take_blob(output), text))
+        }
+    }
+
+    fn entropy_blob(&self) -> Result<Option<CRYPT_INTEGER_BLOB>> {
+        if self.entropy.is_empty() {
+            Ok(None)
+        } else {
+            blob(self.entropy).map(Some)
+        }
+    }
+}
+
+/// Encrypts data for the current user with `CryptProtectData`.
+pub fn protect(data: &[u8]) -> Result<Vec<u8>> {
+    DataProtection::new().protect(data)
+}
+
+/// Decrypts data returned by `protect`.
+pub fn unprotect(data: &[u8]) -> Result<Vec<u8>> {
+    DataProtection::new().unprotect(data)
+}
+
+/// Encrypts data with DPAPI-NG (`NCryptProtectSecret`) for the principals named by a protection
+/// descriptor, such as `LOCAL=user`, `LOCAL=machine`, or `SID=S-1-5-32-544`.
+///
+/// Unlike `protect`, data protected for a domain principal may be unprotected on other machines.
+pub fn protect_secret(descriptor: &str, data: &[u8]) -> Result<Vec<u8>> {
+    unsafe {
+        let descriptor = Descriptor(NCryptCreateProtectionDescriptor(&HSTRING::from(descriptor), 0)?);
+        let mut output = core::ptr::null_mut();
+        let mut len = 0;
+        NCryptProtectSecret(descriptor.0, NCRYPT_SILENT_FLAG.0, data, None, HWND::default(), &mut output, &mut len)?;
+        Ok(take_local(output, len))
+    }
+}
+
+/// Decrypts data returned by `protect_secret`. The protection descriptor is stored in the data.
+pub fn unprotect_secret(data: &[u8]) -> Result<Vec<u8>> {
+    unsafe {
+        let mut output = core::ptr::null_mut();
+        let mut len = 0;
+        NCryptUnprotectSecret(None, NCRYPT_SILENT_FLAG, data, None, HWND::default(), &mut output, &mut len)?;
+        Ok(take_local(output, len))
+    }
+}
+
+struct Descriptor(NCRYPT_DESCRIPTOR_HANDLE);
+
+impl Drop for Descriptor {
+    fn drop(&mut self) {
+        unsafe { _ = NCryptCloseProtectionDescriptor(self.0) };
+    }
+}
+
+fn blob(data: &[u8]) -> Result<CRYPT_INTEGER_BLOB> {
+    Ok(CRYPT_INTEGER_BLOB { cbData: data.len().try_into()?, pbData: data.as_ptr() as _ })
+}
+
+unsafe fn take_blob(blob: CRYPT_INTEGER_BLOB) -> Vec<u8> {
+    take_local(blob.pbData, blob.cbData)
+}
+
+unsafe fn take_local(data: *mut u8, len: u32) -> Vec<u8> {
+    if data.is_null() {
+        return Vec::new();
+    }
+
+    let bytes = core::slice::from_raw_parts(data, len as usize).to_vec();
+    LocalFree(HLOCAL(data as _));
+    bytes
+}
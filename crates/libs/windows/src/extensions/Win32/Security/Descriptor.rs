@@ -0,0 +1,425 @@
+use super::Sid::Sid;
+use crate::Win32::Foundation::*;
+use crate::Win32::Security::Authorization::*;
+use crate::Win32::Security::*;
+use crate::Win32::System::SystemServices::{ACCESS_ALLOWED_ACE_TYPE, ACCESS_DENIED_ACE_TYPE, SYSTEM_AUDIT_ACE_TYPE, SYSTEM_MANDATORY_LABEL_ACE_TYPE};
+use core::fmt::Write;
+use windows_core::{Result, HSTRING, PWSTR};
+
+// The ACE flags along with their SDDL abbreviations.
+const ACE_FLAG_NAMES: [(ACE_FLAGS, &str); 7] = [(OBJECT_INHERIT_ACE, "OI"), (CONTAINER_INHERIT_ACE, "CI"), (NO_PROPAGATE_INHERIT_ACE, "NP"), (INHERIT_ONLY_ACE, "IO"), (INHERITED_ACE, "ID"), (SUCCESSFUL_ACCESS_ACE_FLAG, "SA"), (FAILED_ACCESS_ACE_FLAG, "FA")];
+
+/// The kind of an access control entry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AceKind {
+    /// Allows the access rights, and belongs in the DACL.
+    Allow,
+    /// Denies the access rights, and belongs in the DACL.
+    Deny,
+    /// Audits attempts to use the access rights, and belongs in the SACL.
+    Audit,
+    /// Sets the integrity level of the object, and belongs in the SACL. The access mask holds the
+    /// `SYSTEM_MANDATORY_LABEL_NO_*_UP` policy.
+    MandatoryLabel,
+}
+
+impl AceKind {
+    fn sddl(self) -> &'static str {
+        match self {
+            Self::Allow => "A",
+            Self::Deny => "D",
+            Self::Audit => "AU",
+            Self::MandatoryLabel => "ML",
+        }
+    }
+
+    fn in_sacl(self) -> bool {
+        matches!(self, Self::Audit | Self::MandatoryLabel)
+    }
+}
+
+/// An access control entry, which allows, denies, or audits access rights for a security identifier.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct Ace {
+    /// The kind of entry.
+    pub kind: AceKind,
+    /// The inheritance and auditing flags, such as `OBJECT_INHERIT_ACE` and `CONTAINER_INHERIT_ACE`.
+    pub flags: ACE_FLAGS,
+    /// The access rights, such as `FILE_GENERIC_READ.0` or `GENERIC_ALL.0`.
+    pub mask: u32,
+    /// The security identifier that the entry applies to.
+    pub sid: Sid,
+}
+
+impl Ace {
+    /// Creates an entry that allows the access rights.
+    pub fn allow(sid: Sid, mask: u32) -> Self {
+        Self { kind: AceKind::Allow, flags: ACE_FLAGS(0), mask, sid }
+    }
+
+    /// Creates an entry that denies the access rights.
+    pub fn deny(sid: Sid, mask: u32) -> Self {
+        Self { kind: AceKind::Deny, flags: ACE_FLAGS(0), mask, sid }
+    }
+
+    /// Creates an entry that audits successful or failed attempts to use the access rights.
+    pub fn audit(sid: Sid, mask: u32, success: bool, failure: bool) -> Self {
+        let mut flags = ACE_FLAGS(0);
+
+        if success {
+            flags.0 |= SUCCESSFUL_ACCESS_ACE_FLAG.0;
+        }
+
+        if failure {
+            flags.0 |= FAILED_ACCESS_ACE_FLAG.0;
+        }
+
+        Self { kind: AceKind::Audit, flags, mask, sid }
+    }
+
+    /// Creates an entry that sets the integrity level, such as `S-1-16-4096` for low integrity, along with
+    /// the `SYSTEM_MANDATORY_LABEL_NO_*_UP` policy.
+    pub fn mandatory_label(level: Sid, policy: u32) -> Self {
+        Self { kind: AceKind::MandatoryLabel, flags: ACE_FLAGS(0), mask: policy, sid: level }
+    }
+
+    /// Adds inheritance flags, such as `OBJECT_INHERIT_ACE | CONTAINER_INHERIT_ACE` so that files and
+    /// directories created within a directory inherit the entry.
+    pub fn inherit(mut self, flags: ACE_FLAGS) -> Self {
+        self.flags.0 |= flags.0;
+        self
+    }
+
+    fn write_sddl(&self, sddl: &mut String) -> Result<()> {
+        sddl.push('(');
+        sddl.push_str(self.kind.sddl());
+        sddl.push(';');
+
+        let mut remaining = self.flags.0;
+
+        for (flag, name) in ACE_FLAG_NAMES {
+            if remaining & flag.0 != 0 {
+                sddl.push_str(name);
+                remaining &= !flag.0;
+            }
+        }
+
+        if remaining != 0 {
+            return Err(windows_core::Error::from(windows_core::imp::E_INVALIDARG));
+        }
+
+        _ = write!(sddl, ";0x{:x};;;{})", self.mask, self.sid);
+        Ok(())
+    }
+
+    // Copies an entry of one of the kinds that share the layout of `ACCESS_ALLOWED_ACE`.
+    unsafe fn from_raw(ace: *const ACCESS_ALLOWED_ACE) -> Result<Option<Self>> {
+        let kind = match (*ace).Header.AceType as u32 {
+            ACCESS_ALLOWED_ACE_TYPE => AceKind::Allow,
+            ACCESS_DENIED_ACE_TYPE => AceKind::Deny,
+            SYSTEM_AUDIT_ACE_TYPE => AceKind::Audit,
+            SYSTEM_MANDATORY_LABEL_ACE_TYPE => AceKind::MandatoryLabel,
+            _ => return Ok(None),
+        };
+
+        let sid = Sid::from_psid(PSID(core::ptr::addr_of!((*ace).SidStart) as _))?;
+        Ok(Some(Self { kind, flags: ACE_FLAGS((*ace).Header.AceFlags as u32), mask: (*ace).Mask, sid }))
+    }
+}
+
+/// Builds a security descriptor from an owner, group, and typed access control entries.
+///
+/// ```rust,ignore
+/// let descriptor = SecurityDescriptorBuilder::new()
+///     .owner("BA".parse()?)
+///     .ace(Ace::allow("SY".parse()?, GENERIC_ALL.0).inherit(OBJECT_INHERIT_ACE | CONTAINER_INHERIT_ACE))
+///     .ace(Ace::allow(Token::current_process(TOKEN_QUERY)?.user()?, FILE_GENERIC_READ.0))
+///     .protected()
+///     .build()?;
+///
+/// descriptor.apply_to_named(r"C:\ProgramData\app", SE_FILE_OBJECT, OWNER_SECURITY_INFORMATION | DACL_SECURITY_INFORMATION)?;
+/// ```
+#[derive(Clone, Default)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct SecurityDescriptorBuilder {
+    owner: Option<Sid>,
+    group: Option<Sid>,
+    dacl: Option<Vec<Ace>>,
+    sacl: Option<Vec<Ace>>,
+    protected: bool,
+}
+
+impl SecurityDescriptorBuilder {
+    /// Creates a builder for a security descriptor without an owner, group, DACL, or SACL.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the owner.
+    pub fn owner(mut self, owner: Sid) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+
+    /// Sets the primary group.
+    pub fn group(mut self, group: Sid) -> Self {
+        self.group = Some(group);
+        self
+    }
+
+    /// Adds an entry to the DACL, or to the SACL for audit and mandatory label entries.
+    ///
+    /// Entries are kept in the order they are added, although Windows expects deny entries to come before
+    /// allow entries.
+    pub fn ace(mut self, ace: Ace) -> Self {
+        let acl = if ace.kind.in_sacl() { &mut self.sacl } else { &mut self.dacl };
+        acl.get_or_insert_with(Vec::new).push(ace);
+        self
+    }
+
+    /// Includes an empty DACL, which denies all access, if no entries are added to it.
+    ///
+    /// Without a DACL, the security descriptor allows all access.
+    pub fn empty_dacl(mut self) -> Self {
+        self.dacl.get_or_insert_with(Vec::new);
+        self
+    }
+
+    /// Prevents the DACL and SACL from inheriting entries from the parent object.
+    pub fn protected(mut self) -> Self {
+        self.protected = true;
+        self
+    }
+
+    /// Returns the SDDL string form of the security descriptor.
+    pub fn sddl(&self) -> Result<String> {
+        let mut sddl = String::new();
+
+        if let Some(owner) = &self.owner {
+            _ = write!(sddl, "O:{owner}");
+        }
+
+        if let Some(group) = &self.group {
+            _ = write!(sddl, "G:{group}");
+        }
+
+        for (prefix, acl) in [("D:", &self.dacl), ("S:", &self.sacl)] {
+            if let Some(acl) = acl {
+                sddl.push_str(prefix);
+
+                if self.protected {
+                    sddl.push('P');
+                }
+
+                for ace in acl {
+                    ace.write_sddl(&mut sddl)?;
+                }
+            }
+        }
+
+        Ok(sddl)
+    }
+
+    /// Builds the security descriptor.
+    pub fn build(&self) -> Result<SecurityDescriptor> {
+        SecurityDescriptor::from_sddl(&self.sddl()?)
+    }
+}
+
+/// A self-relative security descriptor, which holds the owner, group, DACL, and SACL of an object.
+///
+/// ```rust,ignore
+/// let descriptor = SecurityDescriptor::from_named(r"C:\Windows", SE_FILE_OBJECT, DACL_SECURITY_INFORMATION)?;
+///
+/// for ace in descriptor.dacl()?.unwrap_or_default() {
+///     println!("{:?} {} {:#x}", ace.kind, ace.sid, ace.mask);
+/// }
+/// ```
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct SecurityDescriptor(PSECURITY_DESCRIPTOR);
+
+unsafe impl Send for SecurityDescriptor {}
+unsafe impl Sync for SecurityDescriptor {}
+
+impl SecurityDescriptor {
+    /// Parses a security descriptor in SDDL string form, such as `O:BAD:P(A;;GA;;;SY)`.
+    pub fn from_sddl(sddl: &str) -> Result<Self> {
+        let mut descriptor = PSECURITY_DESCRIPTOR::default();
+        unsafe { ConvertStringSecurityDescriptorToSecurityDescriptorW(&HSTRING::from(sddl), SDDL_REVISION_1, &mut descriptor, None)? };
+        Ok(Self(descriptor))
+    }
+
+    /// Reads the security descriptor of a named object, such as a file path or a registry key like `MACHINE\SOFTWARE`.
+    ///
+    /// The information selects the parts to read, such as `OWNER_SECURITY_INFORMATION | DACL_SECURITY_INFORMATION`.
+    pub fn from_named(name: &str, object_type: SE_OBJECT_TYPE, info: OBJECT_SECURITY_INFORMATION) -> Result<Self> {
+        let mut descriptor = PSECURITY_DESCRIPTOR::default();
+        unsafe { GetNamedSecurityInfoW(&HSTRING::from(name), object_type, info, None, None, None, None, &mut descriptor).ok()? };
+        Ok(Self(descriptor))
+    }
+
+    /// Reads the security descriptor of the object that a handle refers to, such as a file, registry key, or kernel object.
+    pub fn from_handle(handle: HANDLE, object_type: SE_OBJECT_TYPE, info: OBJECT_SECURITY_INFORMATION) -> Result<Self> {
+        let mut descriptor = PSECURITY_DESCRIPTOR::default();
+        unsafe { GetSecurityInfo(handle, object_type, info, None, None, None, None, Some(&mut descriptor)).ok()? };
+        Ok(Self(descriptor))
+    }
+
+    /// Returns a pointer to the security descriptor, which is valid for the lifetime of the `SecurityDescriptor`.
+    pub fn as_psd(&self) -> PSECURITY_DESCRIPTOR {
+        self.0
+    }
+
+    /// Returns `SECURITY_ATTRIBUTES` that refer to the security descriptor, for functions that create objects.
+    ///
+    /// The attributes are only valid for the lifetime of the `SecurityDescriptor`.
+    pub fn security_attributes(&self, inherit_handle: bool) -> SECURITY_ATTRIBUTES {
+        SECURITY_ATTRIBUTES { nLength: core::mem::size_of::<SECURITY_ATTRIBUTES>() as u32, lpSecurityDescriptor: self.0 .0, bInheritHandle: inherit_handle.into() }
+    }
+
+    /// Returns the SDDL string form of the parts of the security descriptor that the information selects.
+    pub fn to_sddl(&self, info: OBJECT_SECURITY_INFORMATION) -> Result<String> {
+        let mut sddl = PWSTR::null();
+
+        unsafe {
+            ConvertSecurityDescriptorToStringSecurityDescriptorW(self.0, SDDL_REVISION_1, info, &mut sddl, None)?;
+            let result = sddl.to_string().map_err(|_| windows_core::Error::from(windows_core::imp::E_INVALIDARG));
+            LocalFree(HLOCAL(sddl.0 as _));
+            result
+        }
+    }
+
+    /// Returns the owner, or `None` if the security descriptor doesn't include one.
+    pub fn owner(&self) -> Result<Option<Sid>> {
+        let mut owner = PSID::default();
+        let mut defaulted = BOOL::default();
+        unsafe { GetSecurityDescriptorOwner(self.0, &mut owner, &mut defaulted)? };
+        Self::sid(owner)
+    }
+
+    /// Returns the primary group, or `None` if the security descriptor doesn't include one.
+    pub fn group(&self) -> Result<Option<Sid>> {
+        let mut group = PSID::default();
+        let mut defaulted = BOOL::default();
+        unsafe { GetSecurityDescriptorGroup(self.0, &mut group, &mut defaulted)? };
+        Self::sid(group)
+    }
+
+    /// Returns the entries of the DACL, or `None` if the security descriptor doesn't include one.
+    ///
+    /// Entries of other kinds, such as object entries, are skipped.
+    pub fn dacl(&self) -> Result<Option<Vec<Ace>>> {
+        Self::aces(self.raw_dacl()?)
+    }
+
+    /// Returns the entries of the SACL, or `None` if the security descriptor doesn't include one.
+    pub fn sacl(&self) -> Result<Option<Vec<Ace>>> {
+        Self::aces(self.raw_sacl()?)
+    }
+
+    /// Returns whether the DACL is protected from inheriting entries from the parent object.
+    pub fn is_dacl_protected(&self) -> Result<bool> {
+        let mut control = 0;
+        let mut revision = 0;
+        unsafe { GetSecurityDescriptorControl(self.0, &mut control, &mut revision)? };
+        Ok(control & SE_DACL_PROTECTED.0 != 0)
+    }
+
+    /// Applies the parts of the security descriptor that the information selects to a named object, such as a
+    /// file path or a registry key like `CURRENT_USER\Software\app`.
+    ///
+    /// Whether the DACL is protected is applied along with the DACL.
+    pub fn apply_to_named(&self, name: &str, object_type: SE_OBJECT_TYPE, info: OBJECT_SECURITY_INFORMATION) -> Result<()> {
+        let (info, owner, group, dacl, sacl) = self.parts(info)?;
+        unsafe { SetNamedSecurityInfoW(&HSTRING::from(name), object_type, info, owner, group, dacl, sacl).ok() }
+    }
+
+    /// Applies the parts of the security descriptor that the information selects to the object that a handle refers to.
+    pub fn apply_to_handle(&self, handle: HANDLE, object_type: SE_OBJECT_TYPE, info: OBJECT_SECURITY_INFORMATION) -> Result<()> {
+        let (info, owner, group, dacl, sacl) = self.parts(info)?;
+        unsafe { SetSecurityInfo(handle, object_type, info, owner, group, dacl, sacl).ok() }
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn parts(&self, mut info: OBJECT_SECURITY_INFORMATION) -> Result<(OBJECT_SECURITY_INFORMATION, PSID, PSID, Option<*const ACL>, Option<*const ACL>)> {
+        let mut owner = PSID::default();
+        let mut group = PSID::default();
+        let mut defaulted = BOOL::default();
+
+        unsafe {
+            GetSecurityDescriptorOwner(self.0, &mut owner, &mut defaulted)?;
+            GetSecurityDescriptorGroup(self.0, &mut group, &mut defaulted)?;
+        }
+
+        if info.0 & DACL_SECURITY_INFORMATION.0 != 0 {
+            info.0 |= if self.is_dacl_protected()? { PROTECTED_DACL_SECURITY_INFORMATION.0 } else { UNPROTECTED_DACL_SECURITY_INFORMATION.0 };
+        }
+
+        let dacl = self.raw_dacl()?.map(|acl| acl as *const ACL);
+        let sacl = self.raw_sacl()?.map(|acl| acl as *const ACL);
+        Ok((info, owner, group, dacl, sacl))
+    }
+
+    fn raw_dacl(&self) -> Result<Option<*mut ACL>> {
+        let mut present = BOOL::default();
+        let mut acl = core::ptr::null_mut();
+        let mut defaulted = BOOL::default();
+        unsafe { GetSecurityDescriptorDacl(self.0, &mut present, &mut acl, &mut defaulted)? };
+        Ok((present.as_bool() && !acl.is_null()).then_some(acl))
+    }
+
+    fn raw_sacl(&self) -> Result<Option<*mut ACL>> {
+        let mut present = BOOL::default();
+        let mut acl = core::ptr::null_mut();
+        let mut defaulted = BOOL::default();
+        unsafe { GetSecurityDescriptorSacl(self.0, &mut present, &mut acl, &mut defaulted)? };
+        Ok((present.as_bool() && !acl.is_null()).then_some(acl))
+    }
+
+    fn sid(sid: PSID) -> Result<Option<Sid>> {
+        if sid.is_invalid() {
+            Ok(None)
+        } else {
+            unsafe { Sid::from_psid(sid).map(Some) }
+        }
+    }
+
+    fn aces(acl: Option<*mut ACL>) -> Result<Option<Vec<Ace>>> {
+        let Some(acl) = acl else {
+            return Ok(None);
+        };
+
+        unsafe {
+            let mut size = ACL_SIZE_INFORMATION::default();
+            GetAclInformation(acl, &mut size as *mut _ as _, core::mem::size_of::<ACL_SIZE_INFORMATION>() as u32, AclSizeInformation)?;
+
+            let mut aces = Vec::with_capacity(size.AceCount as usize);
+
+            for index in 0..size.AceCount {
+                let mut ace = core::ptr::null_mut();
+                GetAce(acl, index, &mut ace)?;
+
+                if let Some(ace) = Ace::from_raw(ace as *const ACCESS_ALLOWED_ACE)? {
+                    aces.push(ace);
+                }
+            }
+
+            Ok(Some(aces))
+        }
+    }
+}
+
+impl Drop for SecurityDescriptor {
+    fn drop(&mut self) {
+        unsafe { LocalFree(HLOCAL(self.0 .0)) };
+    }
+}
+
+impl core::str::FromStr for SecurityDescriptor {
+    type Err = windows_core::Error;
+
+    fn from_str(sddl: &str) -> Result<Self> {
+        Self::from_sddl(sddl)
+    }
+}
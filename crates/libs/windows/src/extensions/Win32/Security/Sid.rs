@@ -0,0 +1,87 @@
+use crate::Win32::Security::*;
+use windows_core::Result;
+
+/// A security identifier, such as the identifier of a user or group.
+///
+/// This owns a copy of the identifier and formats it in the `S-1-5-...` string form.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Sid(Vec<usize>);
+
+impl Sid {
+    /// Copies a security identifier.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must refer to a valid security identifier.
+    pub unsafe fn from_psid(sid: PSID) -> Result<Self> {
+        if !IsValidSid(sid).as_bool() {
+            return Err(windows_core::Error::from(windows_core::imp::E_INVALIDARG));
+        }
+
+        let len = GetLengthSid(sid);
+        let mut buffer = vec![0usize; (len as usize).div_ceil(core::mem::size_of::<usize>())];
+        CopySid(len, PSID(buffer.as_mut_ptr() as _), sid)?;
+        Ok(Self(buffer))
+    }
+
+    /// Returns a pointer to the security identifier, which is valid for the lifetime of the `Sid`.
+    pub fn as_psid(&self) -> PSID {
+        PSID(self.0.as_ptr() as _)
+    }
+
+    /// Returns the identifier authority, such as 5 for the NT authority.
+    pub fn authority(&self) -> u64 {
+        let authority = unsafe { *GetSidIdentifierAuthority(self.as_psid()) };
+        authority.Value.iter().fold(0, |value, byte| (value << 8) | *byte as u64)
+    }
+
+    /// Returns the subauthorities of the security identifier, ending with the relative identifier.
+    pub fn sub_authorities(&self) -> Vec<u32> {
+        unsafe {
+            let count = *GetSidSubAuthorityCount(self.as_psid());
+            (0..count as u32).map(|index| *GetSidSubAuthority(self.as_psid(), index)).collect()
+        }
+    }
+}
+
+#[cfg(feature = "Win32_Security_Authorization")]
+impl core::str::FromStr for Sid {
+    type Err = windows_core::Error;
+
+    /// Parses a security identifier in the `S-1-5-...` string form, or an SDDL alias such as `BA` or `WD`.
+    fn from_str(value: &str) -> Result<Self> {
+        let mut sid = PSID::default();
+
+        unsafe {
+            crate::Win32::Security::Authorization::ConvertStringSidToSidW(&windows_core::HSTRING::from(value), &mut sid)?;
+            let result = Self::from_psid(sid);
+            crate::Win32::Foundation::LocalFree(crate::Win32::Foundation::HLOCAL(sid.0));
+            result
+        }
+    }
+}
+
+impl core::fmt::Display for Sid {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let authority = self.authority();
+
+        // Authorities that don't fit in 32 bits are formatted in hexadecimal, as by `ConvertSidToStringSidW`.
+        if authority >> 32 == 0 {
+            write!(f, "S-1-{authority}")?;
+        } else {
+            write!(f, "S-1-0x{authority:012X}")?;
+        }
+
+        for sub_authority in self.sub_authorities() {
+            write!(f, "-{sub_authority}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for Sid {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Sid({self})")
+    }
+}
@@ -0,0 +1,186 @@
+use super::Sid::Sid;
+use crate::Win32::Foundation::*;
+use crate::Win32::Security::*;
+use crate::Win32::System::SystemServices::PRIVILEGE_SET_ALL_NECESSARY;
+use crate::Win32::System::Threading::{GetCurrentProcess, GetCurrentThread, OpenProcessToken, OpenThreadToken};
+use core::marker::PhantomData;
+use windows_core::{Owned, Param, Result, PCWSTR};
+
+/// An access token, which describes the security context of a process or thread.
+///
+/// ```rust,ignore
+/// let token = Token::current_process(TOKEN_QUERY | TOKEN_ADJUST_PRIVILEGES)?;
+/// token.enable_privilege(SE_SHUTDOWN_NAME)?;
+///
+/// if !token.is_elevated()? {
+///     println!("running as {} without elevation", token.user()?);
+/// }
+/// ```
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct Token(Owned<HANDLE>);
+
+unsafe impl Send for Token {}
+unsafe impl Sync for Token {}
+
+impl Token {
+    /// Opens the primary token of the current process with the given access rights.
+    pub fn current_process(access: TOKEN_ACCESS_MASK) -> Result<Self> {
+        unsafe { Self::open_process(GetCurrentProcess(), access) }
+    }
+
+    /// Opens the primary token of a process, which requires the `PROCESS_QUERY_LIMITED_INFORMATION` access right.
+    ///
+    /// # Safety
+    ///
+    /// The process handle must be valid.
+    pub unsafe fn open_process(process: HANDLE, access: TOKEN_ACCESS_MASK) -> Result<Self> {
+        let mut token = Owned::default();
+        OpenProcessToken(process, access, &mut *token)?;
+        Ok(Self(token))
+    }
+
+    /// Opens the impersonation token of the current thread, returning `None` if the thread is not impersonating.
+    ///
+    /// The token is opened with the security context of the process rather than that of the thread.
+    pub fn current_thread(access: TOKEN_ACCESS_MASK) -> Result<Option<Self>> {
+        let mut token = Owned::default();
+
+        match unsafe { OpenThreadToken(GetCurrentThread(), access, true, &mut *token) } {
+            Ok(()) => Ok(Some(Self(token))),
+            Err(error) if error.code() == ERROR_NO_TOKEN.to_hresult() => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Takes ownership of a token handle.
+    pub fn from_handle(handle: Owned<HANDLE>) -> Self {
+        Self(handle)
+    }
+
+    /// Returns the underlying handle.
+    pub fn handle(&self) -> HANDLE {
+        *self.0
+    }
+
+    /// Enables a privilege such as `SE_SHUTDOWN_NAME`, which requires the `TOKEN_ADJUST_PRIVILEGES` access right.
+    ///
+    /// Unlike `AdjustTokenPrivileges`, this fails with `ERROR_NOT_ALL_ASSIGNED` if the token doesn't hold the
+    /// privilege rather than reporting success.
+    pub fn enable_privilege<P0: Param<PCWSTR>>(&self, name: P0) -> Result<()> {
+        self.adjust_privilege(name, SE_PRIVILEGE_ENABLED)
+    }
+
+    /// Disables a privilege, which requires the `TOKEN_ADJUST_PRIVILEGES` access right.
+    ///
+    /// As with `enable_privilege`, this fails with `ERROR_NOT_ALL_ASSIGNED` if the token doesn't hold the privilege.
+    pub fn disable_privilege<P0: Param<PCWSTR>>(&self, name: P0) -> Result<()> {
+        self.adjust_privilege(name, TOKEN_PRIVILEGES_ATTRIBUTES(0))
+    }
+
+    fn adjust_privilege<P0: Param<PCWSTR>>(&self, name: P0, attributes: TOKEN_PRIVILEGES_ATTRIBUTES) -> Result<()> {
+        unsafe {
+            let privileges = TOKEN_PRIVILEGES { PrivilegeCount: 1, Privileges: [LUID_AND_ATTRIBUTES { Luid: lookup_privilege(name)?, Attributes: attributes }] };
+            AdjustTokenPrivileges(*self.0, false, Some(&privileges), 0, None, None)?;
+
+            // The function succeeds even if the privilege could not be adjusted, reporting this only as the last error.
+            match GetLastError() {
+                ERROR_SUCCESS => Ok(()),
+                error => Err(error.into()),
+            }
+        }
+    }
+
+    /// Returns `true` if the token holds the privilege and it is enabled, which requires the `TOKEN_QUERY` access right.
+    pub fn privilege_enabled<P0: Param<PCWSTR>>(&self, name: P0) -> Result<bool> {
+        unsafe {
+            let mut set = PRIVILEGE_SET {
+                PrivilegeCount: 1,
+                Control: PRIVILEGE_SET_ALL_NECESSARY,
+                Privilege: [LUID_AND_ATTRIBUTES { Luid: lookup_privilege(name)?, Attributes: TOKEN_PRIVILEGES_ATTRIBUTES(0) }],
+            };
+            let mut result = BOOL::default();
+            PrivilegeCheck(*self.0, &mut set, &mut result)?;
+            Ok(result.as_bool())
+        }
+    }
+
+    /// Returns `true` if the token is elevated, which requires the `TOKEN_QUERY` access right.
+    pub fn is_elevated(&self) -> Result<bool> {
+        let elevation: TOKEN_ELEVATION = self.fixed(TokenElevation)?;
+        Ok(elevation.TokenIsElevated != 0)
+    }
+
+    /// Returns the elevation type of the token, which requires the `TOKEN_QUERY` access right.
+    ///
+    /// This is `TokenElevationTypeLimited` for a filtered token when User Account Control is enabled, or
+    /// `TokenElevationTypeDefault` if the user does not have a split token.
+    pub fn elevation_type(&self) -> Result<TOKEN_ELEVATION_TYPE> {
+        self.fixed(TokenElevationType)
+    }
+
+    /// Returns the user that the token represents, which requires the `TOKEN_QUERY` access right.
+    pub fn user(&self) -> Result<Sid> {
+        let buffer = self.variable(TokenUser)?;
+        unsafe { Sid::from_psid((*(buffer.as_ptr() as *const TOKEN_USER)).User.Sid) }
+    }
+
+    /// Returns the integrity level of the token, which requires the `TOKEN_QUERY` access right.
+    ///
+    /// This is the relative identifier of the token's mandatory label, which may be compared with the
+    /// `SECURITY_MANDATORY_*_RID` constants such as `SECURITY_MANDATORY_HIGH_RID`.
+    pub fn integrity_level(&self) -> Result<u32> {
+        let buffer = self.variable(TokenIntegrityLevel)?;
+        let sid = unsafe { Sid::from_psid((*(buffer.as_ptr() as *const TOKEN_MANDATORY_LABEL)).Label.Sid)? };
+        Ok(sid.sub_authorities().last().copied().unwrap_or_default())
+    }
+
+    /// Impersonates the token on the current thread until the returned guard is dropped.
+    ///
+    /// A primary token requires the `TOKEN_QUERY` and `TOKEN_DUPLICATE` access rights, while an impersonation
+    /// token requires the `TOKEN_QUERY` and `TOKEN_IMPERSONATE` access rights.
+    pub fn impersonate(&self) -> Result<Impersonation> {
+        unsafe { ImpersonateLoggedOnUser(*self.0)? };
+        Ok(Impersonation(PhantomData))
+    }
+
+    fn fixed<T: Default>(&self, class: TOKEN_INFORMATION_CLASS) -> Result<T> {
+        let mut value = T::default();
+        let mut len = 0;
+        unsafe { GetTokenInformation(*self.0, class, Some(&mut value as *mut T as _), core::mem::size_of::<T>() as u32, &mut len)? };
+        Ok(value)
+    }
+
+    // Returns a buffer aligned for the pointers found in variable-length token information.
+    fn variable(&self, class: TOKEN_INFORMATION_CLASS) -> Result<Vec<usize>> {
+        let mut len = 0;
+        _ = unsafe { GetTokenInformation(*self.0, class, None, 0, &mut len) };
+
+        if len == 0 {
+            return Err(windows_core::Error::from_win32());
+        }
+
+        let mut buffer = vec![0usize; (len as usize).div_ceil(core::mem::size_of::<usize>())];
+        unsafe { GetTokenInformation(*self.0, class, Some(buffer.as_mut_ptr() as _), len, &mut len)? };
+        Ok(buffer)
+    }
+}
+
+fn lookup_privilege<P0: Param<PCWSTR>>(name: P0) -> Result<LUID> {
+    let mut luid = LUID::default();
+    unsafe { LookupPrivilegeValueW(PCWSTR::null(), name, &mut luid)? };
+    Ok(luid)
+}
+
+/// A guard that reverts the current thread to the security context of its process when dropped.
+///
+/// This is returned by `Token::impersonate` and must be dropped on the thread that created it.
+#[derive(Debug)]
+pub struct Impersonation(PhantomData<*const ()>);
+
+impl Drop for Impersonation {
+    fn drop(&mut self) {
+        unsafe {
+            _ = RevertToSelf();
+        }
+    }
+}
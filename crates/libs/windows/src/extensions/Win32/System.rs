@@ -1,4 +1,36 @@
 #[cfg(feature = "Win32_System_Com")]
-mod Com;
+pub(crate) mod Com;
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_System_Console", feature = "std"))]
+pub(crate) mod Console;
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_System_DataExchange", feature = "Win32_System_Memory", feature = "Win32_System_Ole", feature = "std"))]
+pub(crate) mod DataExchange;
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_System_Diagnostics", feature = "std"))]
+pub(crate) mod Diagnostics;
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_Security", feature = "Win32_System_EventLog", feature = "std"))]
+pub(crate) mod EventLog;
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_System_IO", feature = "std"))]
+pub(crate) mod IO;
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_Security", feature = "Win32_System_JobObjects", feature = "Win32_System_Threading", feature = "std"))]
+pub(crate) mod JobObjects;
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_System_Memory"))]
+pub(crate) mod Memory;
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_System_Com", feature = "Win32_System_Ole", feature = "Win32_System_Variant", feature = "std"))]
+pub(crate) mod Ole;
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_System_Performance", feature = "std"))]
+pub(crate) mod Performance;
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_Security", feature = "Win32_Storage_FileSystem", feature = "Win32_System_IO", feature = "Win32_System_Pipes", feature = "std"))]
+pub(crate) mod Pipes;
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_Graphics_Gdi", feature = "Win32_System_Power", feature = "Win32_UI_WindowsAndMessaging", feature = "std"))]
+pub(crate) mod Power;
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_System_RemoteDesktop", feature = "std"))]
+pub(crate) mod RemoteDesktop;
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_System_RestartManager", feature = "std"))]
+pub(crate) mod RestartManager;
 #[cfg(feature = "Win32_System_Rpc")]
 mod Rpc;
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_System_Threading", feature = "std"))]
+pub(crate) mod Threading;
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_System_Variant"))]
+pub(crate) mod Variant;
+#[cfg(all(feature = "Win32_System_Com", feature = "Win32_System_Wmi", feature = "std"))]
+pub(crate) mod Wmi;
@@ -1 +1,9 @@
+#[cfg(feature = "Win32_Foundation")]
+pub mod CY;
 pub mod IDispatch;
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_System_Com_StructuredStorage", feature = "std"))]
+pub(crate) mod Persist;
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_Security"))]
+pub mod Security;
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_System_Com_StructuredStorage"))]
+pub mod StructuredStorage;
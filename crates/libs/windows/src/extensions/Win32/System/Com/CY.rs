@@ -0,0 +1,93 @@
+use crate::extensions::Win32::Foundation::DECIMAL::{rescale, round_half_even};
+use crate::Win32::Foundation::{DECIMAL, DISP_E_OVERFLOW};
+use crate::Win32::System::Com::CY;
+
+// A currency value is stored as an integer scaled by this factor, giving four digits after the decimal point.
+const SCALE: i64 = 10_000;
+
+impl CY {
+    /// Creates a `CY` from a value in units of 1/10,000, so that `CY::from_scaled(12_345)` is 1.2345.
+    pub fn from_scaled(value: i64) -> Self {
+        Self { int64: value }
+    }
+
+    /// Returns the value in units of 1/10,000.
+    pub fn scaled(&self) -> i64 {
+        unsafe { self.int64 }
+    }
+}
+
+impl PartialEq for CY {
+    fn eq(&self, other: &Self) -> bool {
+        self.scaled() == other.scaled()
+    }
+}
+
+impl Eq for CY {}
+
+impl core::fmt::Debug for CY {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let value = self.scaled();
+        let sign = if value < 0 { "-" } else { "" };
+        let value = value.unsigned_abs();
+        write!(f, "CY({sign}{}.{:04})", value / SCALE as u64, value % SCALE as u64)
+    }
+}
+
+macro_rules! cy_from_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for CY {
+                fn from(value: $ty) -> Self {
+                    Self::from_scaled(value as i64 * SCALE)
+                }
+            }
+        )*
+    };
+}
+
+cy_from_integer!(i8, i16, i32, u8, u16, u32);
+
+impl TryFrom<i64> for CY {
+    type Error = windows_core::Error;
+    fn try_from(value: i64) -> windows_core::Result<Self> {
+        value.checked_mul(SCALE).map(Self::from_scaled).ok_or_else(|| DISP_E_OVERFLOW.into())
+    }
+}
+
+impl From<CY> for i64 {
+    /// Rounds to the nearest integer, using banker's rounding as OLE Automation does.
+    fn from(value: CY) -> Self {
+        round_half_even(value.scaled() as i128, SCALE as i128) as i64
+    }
+}
+
+impl From<CY> for f64 {
+    fn from(value: CY) -> Self {
+        value.scaled() as f64 / SCALE as f64
+    }
+}
+
+#[cfg(feature = "Win32_System_Ole")]
+impl TryFrom<f64> for CY {
+    type Error = windows_core::Error;
+    /// Rounds to four decimal places, using banker's rounding as OLE Automation does.
+    fn try_from(value: f64) -> windows_core::Result<Self> {
+        unsafe { crate::Win32::System::Ole::VarCyFromR8(value) }
+    }
+}
+
+impl From<CY> for DECIMAL {
+    fn from(value: CY) -> Self {
+        Self::new(value.scaled() as i128, 4).unwrap()
+    }
+}
+
+impl TryFrom<DECIMAL> for CY {
+    type Error = windows_core::Error;
+    /// Rounds to four decimal places, using banker's rounding as OLE Automation does.
+    fn try_from(value: DECIMAL) -> windows_core::Result<Self> {
+        let scaled = rescale(value.mantissa(), value.scale(), 4)?;
+        i64::try_from(scaled).map(Self::from_scaled).map_err(|_| DISP_E_OVERFLOW.into())
+    }
+}
@@ -0,0 +1,301 @@
+use crate::Win32::Foundation::*;
+use crate::Win32::System::Com::StructuredStorage::{CreateStreamOnHGlobal, ReadClassStm, WriteClassStm};
+use crate::Win32::System::Com::*;
+use std::io::{Read, Seek, SeekFrom, Write};
+use windows_core::{IUnknown, Interface, Result, HSTRING};
+
+/// Adapts an `IStream` to the standard `Read`, `Write`, and `Seek` traits.
+pub struct StreamIo<'a>(&'a IStream);
+
+impl<'a> StreamIo<'a> {
+    /// Wraps the stream without changing its current position.
+    pub fn new(stream: &'a IStream) -> Self {
+        Self(stream)
+    }
+}
+
+impl Read for StreamIo<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let mut read = 0;
+        unsafe { self.0.Read(buf.as_mut_ptr() as _, buf.len().try_into().unwrap_or(u32::MAX), Some(&mut read)).ok()? };
+        Ok(read as usize)
+    }
+}
+
+impl Write for StreamIo<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let mut written = 0;
+        unsafe { self.0.Write(buf.as_ptr() as _, buf.len().try_into().unwrap_or(u32::MAX), Some(&mut written)).ok()? };
+        Ok(written as usize)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        unsafe { self.0.Commit(STGC_DEFAULT)? };
+        Ok(())
+    }
+}
+
+impl Seek for StreamIo<'_> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let (offset, origin) = match pos {
+            SeekFrom::Start(offset) => (offset as i64, STREAM_SEEK_SET),
+            SeekFrom::Current(offset) => (offset, STREAM_SEEK_CUR),
+            SeekFrom::End(offset) => (offset, STREAM_SEEK_END),
+        };
+
+        let mut position = 0;
+        unsafe { self.0.Seek(offset, origin, Some(&mut position))? };
+        Ok(position)
+    }
+}
+
+fn memory_stream(bytes: &[u8]) -> Result<IStream> {
+    let stream = unsafe { CreateStreamOnHGlobal(HGLOBAL::default(), true)? };
+    let mut io = StreamIo::new(&stream);
+    io.write_all(bytes)?;
+    io.rewind()?;
+    Ok(stream)
+}
+
+fn stream_bytes(stream: &IStream) -> Result<Vec<u8>> {
+    let mut io = StreamIo::new(stream);
+    let mut bytes = Vec::new();
+    io.rewind()?;
+    io.read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn save_stream(object: &IUnknown, stream: &IStream) -> Result<()> {
+    unsafe {
+        if let Ok(persist) = object.cast::<IPersistStream>() {
+            persist.Save(stream, true)
+        } else {
+            object.cast::<IPersistStreamInit>()?.Save(stream, true)
+        }
+    }
+}
+
+fn load_stream(object: &IUnknown, stream: &IStream) -> Result<()> {
+    unsafe {
+        if let Ok(persist) = object.cast::<IPersistStream>() {
+            persist.Load(stream)
+        } else {
+            object.cast::<IPersistStreamInit>()?.Load(stream)
+        }
+    }
+}
+
+/// Saves the state of an object that implements `IPersistStream` or `IPersistStreamInit` to bytes.
+///
+/// The object's dirty flag is cleared on success.
+pub fn save_to_bytes<T: Interface>(object: &T) -> Result<Vec<u8>> {
+    let stream = memory_stream(&[])?;
+    save_stream(&object.cast()?, &stream)?;
+    stream_bytes(&stream)
+}
+
+/// Loads bytes produced by [`save_to_bytes`] into an object that implements `IPersistStream` or `IPersistStreamInit`.
+pub fn load_from_bytes<T: Interface>(object: &T, bytes: &[u8]) -> Result<()> {
+    load_stream(&object.cast()?, &memory_stream(bytes)?)
+}
+
+/// Saves an object together with its class identifier, as OLE does for embedded objects.
+///
+/// The object must implement `IPersist` and either `IPersistStream` or `IPersistStreamInit`.
+pub fn save_object_to_bytes<T: Interface>(object: &T) -> Result<Vec<u8>> {
+    let class = unsafe { object.cast::<IPersist>()?.GetClassID()? };
+    let stream = memory_stream(&[])?;
+    unsafe { WriteClassStm(&stream, &class)? };
+    save_stream(&object.cast()?, &stream)?;
+    stream_bytes(&stream)
+}
+
+/// Creates an object from bytes produced by [`save_object_to_bytes`].
+///
+/// The class identifier stored in the bytes is used to create the object, which then loads the remaining state.
+pub fn create_object_from_bytes<T: Interface>(bytes: &[u8], context: CLSCTX) -> Result<T> {
+    let stream = memory_stream(bytes)?;
+    let class = unsafe { ReadClassStm(&stream)? };
+    let object: IUnknown = unsafe { CoCreateInstance(&class, None, context)? };
+    load_stream(&object, &stream)?;
+    object.cast()
+}
+
+/// Saves an object that implements `IPersistFile`, such as a shell link, to the given file.
+///
+/// The file becomes the object's current file.
+pub fn save_to_file<T: Interface>(object: &T, path: &HSTRING) -> Result<()> {
+    unsafe { object.cast::<IPersistFile>()?.Save(path, true) }
+}
+
+/// Loads an object that implements `IPersistFile`, such as a shell link, from the given file.
+pub fn load_from_file<T: Interface>(object: &T, path: &HSTRING, mode: STGM) -> Result<()> {
+    unsafe { object.cast::<IPersistFile>()?.Load(path, mode) }
+}
+
+#[cfg(all(feature = "implement", feature = "serde_json"))]
+pub use serde_persist::*;
+
+#[cfg(all(feature = "implement", feature = "serde_json"))]
+mod serde_persist {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+    use windows_core::{Error, GUID, HRESULT};
+
+    /// The state of an object that is persisted with serde, along with its dirty flag.
+    pub struct PersistState<T> {
+        value: RwLock<T>,
+        dirty: AtomicBool,
+    }
+
+    impl<T> PersistState<T> {
+        /// Creates a clean state with the given value.
+        pub const fn new(value: T) -> Self {
+            Self { value: RwLock::new(value), dirty: AtomicBool::new(false) }
+        }
+
+        /// Locks the state for reading.
+        pub fn read(&self) -> RwLockReadGuard<'_, T> {
+            self.value.read().unwrap_or_else(PoisonError::into_inner)
+        }
+
+        /// Locks the state for writing and marks it as dirty.
+        pub fn write(&self) -> RwLockWriteGuard<'_, T> {
+            let value = self.value.write().unwrap_or_else(PoisonError::into_inner);
+            // Marked while the lock is held, so that a concurrent save either includes the change or leaves the state dirty.
+            self.dirty.store(true, Ordering::Release);
+            value
+        }
+
+        /// Returns `true` if the state has changed since it was last loaded or saved.
+        pub fn is_dirty(&self) -> bool {
+            self.dirty.load(Ordering::Acquire)
+        }
+
+        fn replace(&self, value: T) {
+            let mut current = self.value.write().unwrap_or_else(PoisonError::into_inner);
+            *current = value;
+            self.dirty.store(false, Ordering::Release);
+        }
+    }
+
+    impl<T: Default> Default for PersistState<T> {
+        fn default() -> Self {
+            Self::new(T::default())
+        }
+    }
+
+    /// Implements `IPersist`, `IPersistStream`, and `IPersistStreamInit` for an `#[implement]` object
+    /// whose state can be serialized with serde.
+    ///
+    /// Implement this trait for the generated `_Impl` type and list the persistence interfaces in the
+    /// `#[implement]` attribute. The state is written as a little-endian `u32` byte count followed by JSON.
+    pub trait SerdePersist {
+        /// The serialized state of the object.
+        type State: serde::Serialize + serde::de::DeserializeOwned + Default;
+
+        /// The class identifier returned by `IPersist::GetClassID`.
+        const CLASS_ID: GUID;
+
+        /// Returns the object's state.
+        fn persist_state(&self) -> &PersistState<Self::State>;
+    }
+
+    fn encode<T: serde::Serialize>(value: &T) -> Result<Vec<u8>> {
+        let json = serde_json::to_vec(value).map_err(invalid_data)?;
+        let mut bytes = Vec::with_capacity(json.len() + 4);
+        bytes.extend_from_slice(&u32::try_from(json.len())?.to_le_bytes());
+        bytes.extend_from_slice(&json);
+        Ok(bytes)
+    }
+
+    fn invalid_data(error: serde_json::Error) -> Error {
+        Error::new(ERROR_INVALID_DATA.to_hresult(), error.to_string())
+    }
+
+    fn is_dirty<T: SerdePersist>(this: &T) -> HRESULT {
+        if this.persist_state().is_dirty() {
+            S_OK
+        } else {
+            S_FALSE
+        }
+    }
+
+    fn load<T: SerdePersist>(this: &T, stream: Option<&IStream>) -> Result<()> {
+        let mut io = StreamIo::new(stream.ok_or_else(|| Error::from(E_POINTER))?);
+        let mut len = [0; 4];
+        io.read_exact(&mut len)?;
+        let mut json = vec![0; u32::from_le_bytes(len) as usize];
+        io.read_exact(&mut json)?;
+        this.persist_state().replace(serde_json::from_slice(&json).map_err(invalid_data)?);
+        Ok(())
+    }
+
+    fn save<T: SerdePersist>(this: &T, stream: Option<&IStream>, clear_dirty: BOOL) -> Result<()> {
+        let mut io = StreamIo::new(stream.ok_or_else(|| Error::from(E_POINTER))?);
+        let state = this.persist_state();
+
+        // The flag is cleared while the snapshot is taken and writers are excluded, so that changes made after the
+        // snapshot leave the state dirty.
+        let (bytes, cleared) = {
+            let value = state.read();
+            (encode(&*value), clear_dirty.as_bool() && state.dirty.swap(false, Ordering::AcqRel))
+        };
+
+        let result = bytes.and_then(|bytes| Ok(io.write_all(&bytes)?));
+
+        if result.is_err() && cleared {
+            state.dirty.store(true, Ordering::Release);
+        }
+
+        result
+    }
+
+    impl<T: SerdePersist> IPersist_Impl for T {
+        fn GetClassID(&self) -> Result<GUID> {
+            Ok(T::CLASS_ID)
+        }
+    }
+
+    impl<T: SerdePersist> IPersistStream_Impl for T {
+        fn IsDirty(&self) -> HRESULT {
+            is_dirty(self)
+        }
+
+        fn Load(&self, stream: Option<&IStream>) -> Result<()> {
+            load(self, stream)
+        }
+
+        fn Save(&self, stream: Option<&IStream>, clear_dirty: BOOL) -> Result<()> {
+            save(self, stream, clear_dirty)
+        }
+
+        fn GetSizeMax(&self) -> Result<u64> {
+            Ok(encode(&*self.persist_state().read())?.len() as u64)
+        }
+    }
+
+    impl<T: SerdePersist> IPersistStreamInit_Impl for T {
+        fn IsDirty(&self) -> HRESULT {
+            is_dirty(self)
+        }
+
+        fn Load(&self, stream: Option<&IStream>) -> Result<()> {
+            load(self, stream)
+        }
+
+        fn Save(&self, stream: Option<&IStream>, clear_dirty: BOOL) -> Result<()> {
+            save(self, stream, clear_dirty)
+        }
+
+        fn GetSizeMax(&self) -> Result<u64> {
+            Ok(encode(&*self.persist_state().read())?.len() as u64)
+        }
+
+        fn InitNew(&self) -> Result<()> {
+            self.persist_state().replace(Default::default());
+            Ok(())
+        }
+    }
+}
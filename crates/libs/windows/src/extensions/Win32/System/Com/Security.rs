@@ -0,0 +1,130 @@
+use crate::Win32::Foundation::*;
+use crate::Win32::Security::PSECURITY_DESCRIPTOR;
+use crate::Win32::System::Com::*;
+use core::marker::PhantomData;
+use windows_core::{Error, Result, GUID};
+
+/// Initializes the process-wide security of COM, such as the access permissions that clients of an
+/// out-of-process server need and the impersonation level that servers called by the process are given.
+///
+/// COM security can only be initialized once per process, after COM has been initialized on the calling
+/// thread and before any interfaces are marshaled. Otherwise COM initializes it implicitly from the
+/// registry the first time an interface is marshaled, and `initialize` fails with `RPC_E_TOO_LATE`.
+///
+/// ```rust,ignore
+/// unsafe { CoInitializeEx(None, COINIT_MULTITHREADED).ok()? };
+///
+/// // Allow callers running as the same user or as SYSTEM.
+/// let permissions = SecurityDescriptor::from_sddl("O:BAG:BAD:(A;;0x3;;;PS)(A;;0x3;;;SY)")?;
+///
+/// ComSecurity::new()
+///     .authentication_level(RPC_C_AUTHN_LEVEL_PKT_PRIVACY)
+///     .impersonation_level(RPC_C_IMP_LEVEL_IDENTIFY)
+///     .access_permissions(&permissions)
+///     .capabilities(EOAC_DISABLE_AAA)
+///     .capabilities(EOAC_NO_CUSTOM_MARSHAL)
+///     .initialize()?;
+/// ```
+#[derive(Clone)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct ComSecurity<'a> {
+    access: Access<'a>,
+    authentication_level: RPC_C_AUTHN_LEVEL,
+    impersonation_level: RPC_C_IMP_LEVEL,
+    capabilities: EOLE_AUTHENTICATION_CAPABILITIES,
+}
+
+#[derive(Clone)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+enum Access<'a> {
+    Default,
+    Permissions(PSECURITY_DESCRIPTOR, PhantomData<&'a ()>),
+    AppId(GUID),
+}
+
+impl<'a> ComSecurity<'a> {
+    /// Creates a builder with the default authentication level, the `RPC_C_IMP_LEVEL_IDENTIFY` impersonation
+    /// level, and the access permissions that COM uses when none are specified.
+    pub fn new() -> Self {
+        Self { access: Access::Default, authentication_level: RPC_C_AUTHN_LEVEL_DEFAULT, impersonation_level: RPC_C_IMP_LEVEL_IDENTIFY, capabilities: EOAC_NONE }
+    }
+
+    /// Sets the minimum authentication level of calls that the process receives and the default
+    /// authentication level of calls that it makes, such as `RPC_C_AUTHN_LEVEL_PKT_PRIVACY`.
+    pub fn authentication_level(mut self, level: RPC_C_AUTHN_LEVEL) -> Self {
+        self.authentication_level = level;
+        self
+    }
+
+    /// Sets the default impersonation level that servers are given when the process calls them, such as
+    /// `RPC_C_IMP_LEVEL_IMPERSONATE`.
+    pub fn impersonation_level(mut self, level: RPC_C_IMP_LEVEL) -> Self {
+        self.impersonation_level = level;
+        self
+    }
+
+    /// Sets the security descriptor that determines which clients may call the process, using the
+    /// `COM_RIGHTS_EXECUTE` right and the other `COM_RIGHTS_*` rights. This replaces any app ID.
+    ///
+    /// The security descriptor must have an owner and a group.
+    #[cfg(all(feature = "Win32_Security_Authorization", feature = "Win32_System_SystemServices"))]
+    pub fn access_permissions(mut self, descriptor: &'a crate::Win32::Security::SecurityDescriptor) -> Self {
+        self.access = Access::Permissions(descriptor.as_psd(), PhantomData);
+        self
+    }
+
+    /// Reads the access permissions, authentication level and other settings from the registry values of
+    /// the app ID, as `dcomcnfg` does for servers that don't initialize security themselves. This replaces
+    /// any access permissions, and COM then ignores the other settings of the builder.
+    pub fn app_id(mut self, app_id: GUID) -> Self {
+        self.access = Access::AppId(app_id);
+        self
+    }
+
+    /// Adds authentication capabilities, such as `EOAC_STATIC_CLOAKING`, `EOAC_DISABLE_AAA`, or
+    /// `EOAC_NO_CUSTOM_MARSHAL`.
+    pub fn capabilities(mut self, capabilities: EOLE_AUTHENTICATION_CAPABILITIES) -> Self {
+        self.capabilities.0 |= capabilities.0;
+        self
+    }
+
+    /// Initializes COM security for the process.
+    ///
+    /// Fails with `RPC_E_TOO_LATE` if COM security has already been initialized, whether explicitly or
+    /// implicitly, and with `CO_E_NOTINITIALIZED` if COM has not been initialized on the calling thread.
+    pub fn initialize(&self) -> Result<()> {
+        let mut capabilities = self.capabilities;
+
+        let descriptor = match &self.access {
+            Access::Default => PSECURITY_DESCRIPTOR::default(),
+            Access::Permissions(descriptor, _) => *descriptor,
+            Access::AppId(app_id) => {
+                capabilities.0 |= EOAC_APPID.0;
+                PSECURITY_DESCRIPTOR(app_id as *const GUID as _)
+            }
+        };
+
+        // These capabilities change what the descriptor pointer refers to, so only the builder sets them.
+        if capabilities.0 & EOAC_APPID.0 != 0 && !matches!(self.access, Access::AppId(_)) {
+            return Err(Error::new(E_INVALIDARG, "EOAC_APPID requires an app ID"));
+        }
+
+        if capabilities.0 & EOAC_ACCESS_CONTROL.0 != 0 {
+            return Err(Error::new(E_INVALIDARG, "EOAC_ACCESS_CONTROL is not supported"));
+        }
+
+        let result = unsafe { CoInitializeSecurity(descriptor, -1, None, None, self.authentication_level, self.impersonation_level, None, capabilities, None) };
+
+        result.map_err(|error| match error.code() {
+            RPC_E_TOO_LATE => Error::new(RPC_E_TOO_LATE, "COM security has already been initialized for this process"),
+            CO_E_NOTINITIALIZED => Error::new(CO_E_NOTINITIALIZED, "COM must be initialized on the calling thread before COM security"),
+            _ => error,
+        })
+    }
+}
+
+impl Default for ComSecurity<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
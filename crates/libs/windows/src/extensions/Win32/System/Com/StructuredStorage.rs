@@ -0,0 +1,62 @@
+use crate::Win32::Foundation::FILETIME;
+use crate::Win32::System::Com::IStream;
+
+impl From<FILETIME> for windows_core::PROPVARIANT {
+    fn from(value: FILETIME) -> Self {
+        unsafe {
+            Self::from_raw(windows_core::imp::PROPVARIANT {
+                Anonymous: windows_core::imp::PROPVARIANT_0 {
+                    Anonymous: windows_core::imp::PROPVARIANT_0_0 {
+                        vt: 64,
+                        wReserved1: 0,
+                        wReserved2: 0,
+                        wReserved3: 0,
+                        Anonymous: windows_core::imp::PROPVARIANT_0_0_0 { filetime: windows_core::imp::FILETIME { dwLowDateTime: value.dwLowDateTime, dwHighDateTime: value.dwHighDateTime } },
+                    },
+                },
+            })
+        }
+    }
+}
+
+impl TryFrom<&windows_core::PROPVARIANT> for FILETIME {
+    type Error = windows_core::Error;
+    fn try_from(from: &windows_core::PROPVARIANT) -> windows_core::Result<Self> {
+        let from = from.as_raw();
+        unsafe {
+            if from.Anonymous.Anonymous.vt == 64 {
+                let value = from.Anonymous.Anonymous.Anonymous.filetime;
+                Ok(Self { dwLowDateTime: value.dwLowDateTime, dwHighDateTime: value.dwHighDateTime })
+            } else {
+                Err(windows_core::Error::from_hresult(windows_core::imp::TYPE_E_TYPEMISMATCH))
+            }
+        }
+    }
+}
+
+impl From<IStream> for windows_core::PROPVARIANT {
+    fn from(value: IStream) -> Self {
+        unsafe {
+            Self::from_raw(windows_core::imp::PROPVARIANT {
+                Anonymous: windows_core::imp::PROPVARIANT_0 {
+                    Anonymous: windows_core::imp::PROPVARIANT_0_0 { vt: 66, wReserved1: 0, wReserved2: 0, wReserved3: 0, Anonymous: windows_core::imp::PROPVARIANT_0_0_0 { pStream: core::mem::transmute(value) } },
+                },
+            })
+        }
+    }
+}
+
+impl TryFrom<&windows_core::PROPVARIANT> for IStream {
+    type Error = windows_core::Error;
+    fn try_from(from: &windows_core::PROPVARIANT) -> windows_core::Result<Self> {
+        let from = from.as_raw();
+        unsafe {
+            if from.Anonymous.Anonymous.vt == 66 && !from.Anonymous.Anonymous.Anonymous.pStream.is_null() {
+                let stream: &IStream = core::mem::transmute(&from.Anonymous.Anonymous.Anonymous.pStream);
+                Ok(stream.clone())
+            } else {
+                Err(windows_core::Error::from_hresult(windows_core::imp::TYPE_E_TYPEMISMATCH))
+            }
+        }
+    }
+}
@@ -0,0 +1,428 @@
+use crate::Win32::Foundation::*;
+use crate::Win32::System::Console::*;
+use std::collections::VecDeque;
+use windows_core::Result;
+
+/// The input buffer of a console, from which keyboard, mouse, and resize events are read.
+///
+/// ```rust,ignore
+/// let input = ConsoleInput::stdin()?;
+/// let _raw = input.set_mode_scoped(ENABLE_WINDOW_INPUT | ENABLE_MOUSE_INPUT | ENABLE_EXTENDED_FLAGS)?;
+///
+/// for event in input.events() {
+///     match event? {
+///         ConsoleEvent::Key(key) if key.down && key.char() == Some('q') => break,
+///         ConsoleEvent::Resize(size) => println!("{} x {}", size.X, size.Y),
+///         _ => {}
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct ConsoleInput(HANDLE);
+
+unsafe impl Send for ConsoleInput {}
+unsafe impl Sync for ConsoleInput {}
+
+impl ConsoleInput {
+    /// Returns the standard input handle, which must refer to a console.
+    pub fn stdin() -> Result<Self> {
+        unsafe { GetStdHandle(STD_INPUT_HANDLE).map(Self) }
+    }
+
+    /// Wraps a console input handle, such as one opened from `CONIN$`.
+    ///
+    /// # Safety
+    ///
+    /// The handle must remain valid while the `ConsoleInput` is used.
+    pub unsafe fn from_handle(handle: HANDLE) -> Self {
+        Self(handle)
+    }
+
+    /// Returns the underlying handle.
+    pub fn handle(&self) -> HANDLE {
+        self.0
+    }
+
+    /// Returns the input mode.
+    pub fn mode(&self) -> Result<CONSOLE_MODE> {
+        mode(self.0)
+    }
+
+    /// Sets the input mode.
+    pub fn set_mode(&self, mode: CONSOLE_MODE) -> Result<()> {
+        unsafe { SetConsoleMode(self.0, mode) }
+    }
+
+    /// Sets the input mode until the returned guard is dropped, which restores the previous mode.
+    pub fn set_mode_scoped(&self, mode: CONSOLE_MODE) -> Result<ConsoleModeGuard> {
+        ConsoleModeGuard::new(self.0, mode)
+    }
+
+    /// Returns the number of events that can be read without blocking.
+    pub fn pending(&self) -> Result<u32> {
+        let mut count = 0;
+        unsafe { GetNumberOfConsoleInputEvents(self.0, &mut count)? };
+        Ok(count)
+    }
+
+    /// Waits for at least one event and returns the events that are available.
+    pub fn read(&self) -> Result<Vec<ConsoleEvent>> {
+        let mut records = [INPUT_RECORD::default(); 64];
+        let mut read = 0;
+        unsafe { ReadConsoleInputW(self.0, &mut records, &mut read)? };
+        Ok(records[..read as usize].iter().filter_map(ConsoleEvent::from_record).collect())
+    }
+
+    /// Returns an iterator that waits for each event in turn. The iterator never ends.
+    pub fn events(&self) -> ConsoleEvents {
+        ConsoleEvents { input: *self, buffer: VecDeque::new() }
+    }
+
+    /// Returns a stream of events that waits on the thread pool rather than blocking a thread.
+    #[cfg(feature = "Win32_System_Threading")]
+    pub fn stream(&self) -> ConsoleEventStream {
+        ConsoleEventStream { input: *self, buffer: VecDeque::new(), wait: None }
+    }
+}
+
+/// The screen buffer of a console, to which output is written.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct ConsoleOutput(HANDLE);
+
+unsafe impl Send for ConsoleOutput {}
+unsafe impl Sync for ConsoleOutput {}
+
+impl ConsoleOutput {
+    /// Returns the standard output handle, which must refer to a console.
+    pub fn stdout() -> Result<Self> {
+        unsafe { GetStdHandle(STD_OUTPUT_HANDLE).map(Self) }
+    }
+
+    /// Returns the standard error handle, which must refer to a console.
+    pub fn stderr() -> Result<Self> {
+        unsafe { GetStdHandle(STD_ERROR_HANDLE).map(Self) }
+    }
+
+    /// Wraps a console screen buffer handle, such as one opened from `CONOUT$`.
+    ///
+    /// # Safety
+    ///
+    /// The handle must remain valid while the `ConsoleOutput` is used.
+    pub unsafe fn from_handle(handle: HANDLE) -> Self {
+        Self(handle)
+    }
+
+    /// Returns the underlying handle.
+    pub fn handle(&self) -> HANDLE {
+        self.0
+    }
+
+    /// Returns the output mode.
+    pub fn mode(&self) -> Result<CONSOLE_MODE> {
+        mode(self.0)
+    }
+
+    /// Sets the output mode.
+    pub fn set_mode(&self, mode: CONSOLE_MODE) -> Result<()> {
+        unsafe { SetConsoleMode(self.0, mode) }
+    }
+
+    /// Sets the output mode until the returned guard is dropped, which restores the previous mode.
+    pub fn set_mode_scoped(&self, mode: CONSOLE_MODE) -> Result<ConsoleModeGuard> {
+        ConsoleModeGuard::new(self.0, mode)
+    }
+
+    /// Enables processing of virtual terminal sequences, so that ANSI escape sequences control
+    /// colors, cursor movement, and other formatting.
+    pub fn enable_virtual_terminal(&self) -> Result<()> {
+        self.set_mode(self.mode()? | ENABLE_VIRTUAL_TERMINAL_PROCESSING)
+    }
+
+    /// Returns the size, cursor position, attributes, and visible window of the screen buffer.
+    pub fn buffer_info(&self) -> Result<CONSOLE_SCREEN_BUFFER_INFO> {
+        let mut info = CONSOLE_SCREEN_BUFFER_INFO::default();
+        unsafe { GetConsoleScreenBufferInfo(self.0, &mut info)? };
+        Ok(info)
+    }
+
+    /// Returns the number of columns and rows in the visible window.
+    pub fn window_size(&self) -> Result<COORD> {
+        let window = self.buffer_info()?.srWindow;
+        Ok(COORD { X: window.Right - window.Left + 1, Y: window.Bottom - window.Top + 1 })
+    }
+
+    /// Moves the cursor to a position in the screen buffer.
+    pub fn set_cursor_position(&self, position: COORD) -> Result<()> {
+        unsafe { SetConsoleCursorPosition(self.0, position) }
+    }
+
+    /// Sets the attributes of characters written from now on, such as `FOREGROUND_RED | FOREGROUND_INTENSITY`.
+    pub fn set_text_attribute(&self, attributes: CONSOLE_CHARACTER_ATTRIBUTES) -> Result<()> {
+        unsafe { SetConsoleTextAttribute(self.0, attributes) }
+    }
+}
+
+fn mode(handle: HANDLE) -> Result<CONSOLE_MODE> {
+    let mut mode = CONSOLE_MODE::default();
+    unsafe { GetConsoleMode(handle, &mut mode)? };
+    Ok(mode)
+}
+
+/// Restores a console's previous mode when dropped.
+///
+/// This is produced by `ConsoleInput::set_mode_scoped` and `ConsoleOutput::set_mode_scoped`.
+pub struct ConsoleModeGuard {
+    handle: HANDLE,
+    previous: CONSOLE_MODE,
+}
+
+impl ConsoleModeGuard {
+    fn new(handle: HANDLE, mode: CONSOLE_MODE) -> Result<Self> {
+        let previous = self::mode(handle)?;
+        unsafe { SetConsoleMode(handle, mode)? };
+        Ok(Self { handle, previous })
+    }
+
+    /// Returns the mode that is restored when the guard is dropped.
+    pub fn previous(&self) -> CONSOLE_MODE {
+        self.previous
+    }
+}
+
+impl Drop for ConsoleModeGuard {
+    fn drop(&mut self) {
+        unsafe {
+            _ = SetConsoleMode(self.handle, self.previous);
+        }
+    }
+}
+
+/// An event read from a console's input buffer.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub enum ConsoleEvent {
+    /// A key was pressed or released.
+    Key(KeyEvent),
+    /// The mouse moved or a button was pressed, if `ENABLE_MOUSE_INPUT` is set.
+    Mouse(MOUSE_EVENT_RECORD),
+    /// The screen buffer was resized to the given number of columns and rows, if `ENABLE_WINDOW_INPUT` is set.
+    Resize(COORD),
+    /// The console gained or lost focus.
+    Focus(bool),
+    /// A menu command was used. These events are used internally by the console.
+    Menu(u32),
+}
+
+impl ConsoleEvent {
+    fn from_record(record: &INPUT_RECORD) -> Option<Self> {
+        unsafe {
+            match record.EventType as u32 {
+                KEY_EVENT => {
+                    let key = &record.Event.KeyEvent;
+                    Some(Self::Key(KeyEvent {
+                        down: key.bKeyDown.as_bool(),
+                        repeat_count: key.wRepeatCount,
+                        virtual_key: key.wVirtualKeyCode,
+                        scan_code: key.wVirtualScanCode,
+                        unicode_char: key.uChar.UnicodeChar,
+                        control_key_state: key.dwControlKeyState,
+                    }))
+                }
+                MOUSE_EVENT => Some(Self::Mouse(record.Event.MouseEvent)),
+                WINDOW_BUFFER_SIZE_EVENT => Some(Self::Resize(record.Event.WindowBufferSizeEvent.dwSize)),
+                FOCUS_EVENT => Some(Self::Focus(record.Event.FocusEvent.bSetFocus.as_bool())),
+                MENU_EVENT => Some(Self::Menu(record.Event.MenuEvent.dwCommandId)),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// A keyboard event read from a console's input buffer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct KeyEvent {
+    /// `true` if the key was pressed rather than released.
+    pub down: bool,
+    /// The number of times the keystroke is repeated because the key is held down.
+    pub repeat_count: u16,
+    /// The virtual key code, such as `VK_RETURN`.
+    pub virtual_key: u16,
+    /// The hardware scan code.
+    pub scan_code: u16,
+    /// The UTF-16 code unit produced by the key, or zero if it doesn't produce a character.
+    pub unicode_char: u16,
+    /// The state of the modifier and lock keys, such as `SHIFT_PRESSED`.
+    pub control_key_state: u32,
+}
+
+impl KeyEvent {
+    /// Returns the character produced by the key, if any.
+    ///
+    /// Characters outside the Basic Multilingual Plane are reported as two events, one for each half
+    /// of the surrogate pair, and this returns `None` for both.
+    pub fn char(&self) -> Option<char> {
+        match self.unicode_char {
+            0 => None,
+            unit => char::from_u32(unit as u32),
+        }
+    }
+}
+
+/// An iterator over a console's input events, produced by `ConsoleInput::events`.
+pub struct ConsoleEvents {
+    input: ConsoleInput,
+    buffer: VecDeque<ConsoleEvent>,
+}
+
+impl Iterator for ConsoleEvents {
+    type Item = Result<ConsoleEvent>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(event) = self.buffer.pop_front() {
+                return Some(Ok(event));
+            }
+
+            match self.input.read() {
+                Ok(events) => self.buffer.extend(events),
+                Err(error) => return Some(Err(error)),
+            }
+        }
+    }
+}
+
+/// A stream of a console's input events, produced by `ConsoleInput::stream`.
+///
+/// The stream waits for input on the thread pool and never ends.
+#[cfg(feature = "Win32_System_Threading")]
+pub struct ConsoleEventStream {
+    input: ConsoleInput,
+    buffer: VecDeque<ConsoleEvent>,
+    wait: Option<crate::Win32::System::Threading::WaitFuture>,
+}
+
+#[cfg(feature = "Win32_System_Threading")]
+impl ConsoleEventStream {
+    /// Attempts to pull out the next event, registering the current task for wakeup if none is available.
+    pub fn poll_next(&mut self, cx: &mut core::task::Context<'_>) -> core::task::Poll<Option<Result<ConsoleEvent>>> {
+        use core::future::Future;
+        use core::task::Poll;
+
+        loop {
+            if let Some(event) = self.buffer.pop_front() {
+                return Poll::Ready(Some(Ok(event)));
+            }
+
+            match self.input.pending() {
+                Ok(0) => {}
+                Ok(_) => {
+                    match self.input.read() {
+                        Ok(events) => self.buffer.extend(events),
+                        Err(error) => return Poll::Ready(Some(Err(error))),
+                    }
+
+                    continue;
+                }
+                Err(error) => return Poll::Ready(Some(Err(error))),
+            }
+
+            // The input handle is signaled while the input buffer isn't empty.
+            let input = self.input.handle();
+            let wait = self.wait.get_or_insert_with(|| unsafe { crate::Win32::System::Threading::wait_async(input) });
+
+            match core::pin::Pin::new(wait).poll(cx) {
+                Poll::Ready(result) => {
+                    self.wait = None;
+
+                    if let Err(error) = result {
+                        return Poll::Ready(Some(Err(error)));
+                    }
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "Win32_System_Threading")]
+impl Unpin for ConsoleEventStream {}
+
+#[cfg(all(feature = "Win32_System_Threading", feature = "futures"))]
+impl futures_core::Stream for ConsoleEventStream {
+    type Item = Result<ConsoleEvent>;
+
+    fn poll_next(self: core::pin::Pin<&mut Self>, cx: &mut core::task::Context<'_>) -> core::task::Poll<Option<Self::Item>> {
+        self.get_mut().poll_next(cx)
+    }
+}
+
+/// A pseudo console (ConPTY), which hosts console apps and translates their output to virtual terminal sequences.
+///
+/// Input written to `input` is received by the hosted app as if it were typed, and everything the app
+/// draws is read from `output`. The output should be read continuously, for example on another
+/// thread, since the hosted app blocks once the pipe is full.
+///
+/// ```rust,ignore
+/// let console = PseudoConsole::new(COORD { X: 80, Y: 25 })?;
+/// let process = ProcessBuilder::new("cmd.exe").pseudo_console(console.handle()).spawn()?;
+///
+/// let mut output = console.output().try_clone()?;
+/// std::thread::spawn(move || std::io::copy(&mut output, &mut std::io::stdout()));
+///
+/// console.input().write_all(b"dir\r\nexit\r\n")?;
+/// process.wait(None)?;
+/// ```
+#[cfg(all(feature = "Win32_Security", feature = "Win32_System_Pipes"))]
+pub struct PseudoConsole {
+    // The output is closed first, so that closing the pseudo console doesn't wait for it to be drained.
+    output: std::fs::File,
+    input: std::fs::File,
+    handle: windows_core::Owned<HPCON>,
+}
+
+#[cfg(all(feature = "Win32_Security", feature = "Win32_System_Pipes"))]
+impl PseudoConsole {
+    /// Creates a pseudo console with the given number of columns and rows.
+    pub fn new(size: COORD) -> Result<Self> {
+        use crate::Win32::System::Pipes::CreatePipe;
+        use std::os::windows::io::FromRawHandle;
+        use windows_core::Owned;
+
+        unsafe {
+            let (mut console_input, mut input) = (HANDLE::default(), HANDLE::default());
+            CreatePipe(&mut console_input, &mut input, None, 0)?;
+            let (console_input, input) = (Owned::new(console_input), std::fs::File::from_raw_handle(input.0));
+
+            let (mut output, mut console_output) = (HANDLE::default(), HANDLE::default());
+            CreatePipe(&mut output, &mut console_output, None, 0)?;
+            let (output, console_output) = (std::fs::File::from_raw_handle(output.0), Owned::new(console_output));
+
+            // The pseudo console duplicates its ends of the pipes, which are closed here.
+            let handle = Owned::new(CreatePseudoConsole(size, *console_input, *console_output, 0)?);
+
+            Ok(Self { output, input, handle })
+        }
+    }
+
+    /// Returns the pseudo console handle, which is passed to `ProcessBuilder::pseudo_console`.
+    pub fn handle(&self) -> HPCON {
+        *self.handle
+    }
+
+    /// Returns the pipe that the hosted app's input is written to.
+    pub fn input(&self) -> &std::fs::File {
+        &self.input
+    }
+
+    /// Returns the pipe that the hosted app's output is read from.
+    pub fn output(&self) -> &std::fs::File {
+        &self.output
+    }
+
+    /// Resizes the pseudo console to the given number of columns and rows.
+    pub fn resize(&self, size: COORD) -> Result<()> {
+        unsafe { ResizePseudoConsole(*self.handle, size) }
+    }
+}
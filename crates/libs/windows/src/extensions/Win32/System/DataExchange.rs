@@ -0,0 +1,371 @@
+use crate::Win32::Foundation::*;
+use crate::Win32::System::DataExchange::*;
+use crate::Win32::System::Memory::*;
+use crate::Win32::System::Ole::*;
+#[cfg(feature = "Win32_UI_Shell")]
+use crate::Win32::UI::Shell::DROPFILES;
+use core::marker::PhantomData;
+use core::time::Duration;
+#[cfg(feature = "Win32_UI_Shell")]
+use std::path::{Path, PathBuf};
+use windows_core::{Result, HSTRING};
+
+// The number of attempts made to open the clipboard while another window has it open.
+const OPEN_ATTEMPTS: u32 = 10;
+
+/// The clipboard, which stays open until this is dropped.
+///
+/// Only one window can have the clipboard open at a time, so it should be dropped as soon as possible. To
+/// replace the clipboard contents, clear it first and then set each format:
+///
+/// ```rust,ignore
+/// let clipboard = Clipboard::open()?;
+/// clipboard.clear()?;
+/// clipboard.set_text("hello")?;
+/// clipboard.set_html("<b>hello</b>")?;
+/// ```
+pub struct Clipboard {
+    // The clipboard is opened by the calling thread and must be closed by the same thread.
+    _thread: PhantomData<*const ()>,
+}
+
+impl Clipboard {
+    /// Opens the clipboard without an owner window, retrying briefly while another window has it open.
+    pub fn open() -> Result<Self> {
+        Self::open_with_owner(HWND::default())
+    }
+
+    /// Opens the clipboard, retrying briefly while another window has it open.
+    ///
+    /// The owner window becomes the clipboard owner when the clipboard is cleared.
+    pub fn open_with_owner(owner: HWND) -> Result<Self> {
+        let mut attempt = 1;
+
+        loop {
+            match unsafe { OpenClipboard(owner) } {
+                Ok(()) => return Ok(Self { _thread: PhantomData }),
+                Err(error) if error.code() == ERROR_ACCESS_DENIED.to_hresult() && attempt < OPEN_ATTEMPTS => {
+                    std::thread::sleep(Duration::from_millis(10 * attempt as u64));
+                    attempt += 1;
+                }
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
+    /// Returns the clipboard sequence number, which changes whenever the clipboard contents change.
+    pub fn sequence_number() -> u32 {
+        unsafe { GetClipboardSequenceNumber() }
+    }
+
+    /// Registers a clipboard format by name, returning the same identifier to every caller.
+    pub fn register_format(name: &str) -> Result<u32> {
+        match unsafe { RegisterClipboardFormatW(&HSTRING::from(name)) } {
+            0 => Err(windows_core::Error::from_win32()),
+            format => Ok(format),
+        }
+    }
+
+    /// Empties the clipboard and makes the owner window, if any, the clipboard owner.
+    pub fn clear(&self) -> Result<()> {
+        unsafe { EmptyClipboard() }
+    }
+
+    /// Returns `true` if the clipboard contains data in the given format.
+    pub fn contains(&self, format: u32) -> bool {
+        unsafe { IsClipboardFormatAvailable(format).is_ok() }
+    }
+
+    /// Returns the data in the given format, or `None` if the clipboard doesn't contain that format.
+    pub fn get(&self, format: u32) -> Result<Option<Vec<u8>>> {
+        if !self.contains(format) {
+            return Ok(None);
+        }
+
+        unsafe {
+            let memory = HGLOBAL(GetClipboardData(format)?.0);
+            let data = GlobalLock(memory);
+
+            if data.is_null() {
+                return Err(windows_core::Error::from_win32());
+            }
+
+            let bytes = core::slice::from_raw_parts(data as *const u8, GlobalSize(memory)).to_vec();
+            _ = GlobalUnlock(memory);
+            Ok(Some(bytes))
+        }
+    }
+
+    /// Places data on the clipboard in the given format, replacing any data in that format.
+    pub fn set(&self, format: u32, data: &[u8]) -> Result<()> {
+        unsafe {
+            let memory = GlobalAlloc(GMEM_MOVEABLE, data.len())?;
+            let buffer = GlobalLock(memory);
+
+            if buffer.is_null() {
+                let error = windows_core::Error::from_win32();
+                _ = GlobalFree(memory);
+                return Err(error);
+            }
+
+            core::ptr::copy_nonoverlapping(data.as_ptr(), buffer as *mut u8, data.len());
+            _ = GlobalUnlock(memory);
+
+            // The clipboard owns the memory once it has been set successfully.
+            if let Err(error) = SetClipboardData(format, HANDLE(memory.0)) {
+                _ = GlobalFree(memory);
+                return Err(error);
+            }
+
+            Ok(())
+        }
+    }
+
+    /// Returns the clipboard text, or `None` if the clipboard doesn't contain text.
+    ///
+    /// The system converts ANSI and OEM text to Unicode, so this returns text placed on the clipboard in any of
+    /// the text formats.
+    pub fn text(&self) -> Result<Option<String>> {
+        Ok(self.get(CF_UNICODETEXT.0.into())?.map(|bytes| from_wide(&bytes)))
+    }
+
+    /// Places text on the clipboard.
+    pub fn set_text(&self, text: &str) -> Result<()> {
+        self.set(CF_UNICODETEXT.0.into(), &to_wide(text))
+    }
+
+    /// Returns the HTML fragment on the clipboard, or `None` if the clipboard doesn't contain HTML.
+    pub fn html(&self) -> Result<Option<String>> {
+        let Some(bytes) = self.get(Self::register_format("HTML Format")?)? else {
+            return Ok(None);
+        };
+
+        let text = String::from_utf8_lossy(&bytes);
+        let offset = |name: &str| text.lines().find_map(|line| line.strip_prefix(name)).and_then(|value| value.trim().parse::<usize>().ok());
+
+        match (offset("StartFragment:"), offset("EndFragment:")) {
+            (Some(start), Some(end)) if start <= end && end <= bytes.len() => Ok(Some(String::from_utf8_lossy(&bytes[start..end]).into_owned())),
+            _ => Err(windows_core::imp::E_INVALIDARG.into()),
+        }
+    }
+
+    /// Places an HTML fragment on the clipboard in the `HTML Format` format that browsers and Office use.
+    pub fn set_html(&self, fragment: &str) -> Result<()> {
+        // The offsets are written with a fixed width so that the header's length doesn't depend on them.
+        const HEADER: usize = "Version:0.9\r\nStartHTML:0000000000\r\nEndHTML:0000000000\r\nStartFragment:0000000000\r\nEndFragment:0000000000\r\n".len();
+        const PREFIX: &str = "<html><body><!--StartFragment-->";
+        const SUFFIX: &str = "<!--EndFragment--></body></html>";
+
+        let start = HEADER + PREFIX.len();
+        let end = start + fragment.len();
+        let html = format!("Version:0.9\r\nStartHTML:{HEADER:010}\r\nEndHTML:{:010}\r\nStartFragment:{start:010}\r\nEndFragment:{end:010}\r\n{PREFIX}{fragment}{SUFFIX}", end + SUFFIX.len());
+
+        let mut bytes = html.into_bytes();
+        bytes.push(0);
+        self.set(Self::register_format("HTML Format")?, &bytes)
+    }
+
+    /// Returns the paths of the files on the clipboard, such as those copied in File Explorer, or `None` if the
+    /// clipboard doesn't contain a file list.
+    #[cfg(feature = "Win32_UI_Shell")]
+    pub fn files(&self) -> Result<Option<Vec<PathBuf>>> {
+        let Some(bytes) = self.get(CF_HDROP.0.into())? else {
+            return Ok(None);
+        };
+
+        if bytes.len() < core::mem::size_of::<DROPFILES>() {
+            return Err(windows_core::imp::E_INVALIDARG.into());
+        }
+
+        // The file names follow the `DROPFILES` header at the offset it specifies.
+        let header = unsafe { (bytes.as_ptr() as *const DROPFILES).read_unaligned() };
+        let wide = header.fWide.as_bool();
+        let names = bytes.get(header.pFiles as usize..).unwrap_or_default();
+
+        // The names are separated by null terminators, and the list ends with an empty name.
+        let names: Vec<String> = if wide {
+            let names: Vec<u16> = names.chunks_exact(2).map(|c| u16::from_le_bytes([c[0], c[1]])).collect();
+            names.split(|c| *c == 0).take_while(|name| !name.is_empty()).map(String::from_utf16_lossy).collect()
+        } else {
+            names.split(|c| *c == 0).take_while(|name| !name.is_empty()).map(|name| String::from_utf8_lossy(name).into_owned()).collect()
+        };
+
+        Ok(Some(names.into_iter().map(PathBuf::from).collect()))
+    }
+
+    /// Places a list of files on the clipboard, which File Explorer can paste.
+    #[cfg(feature = "Win32_UI_Shell")]
+    pub fn set_files<P: AsRef<Path>>(&self, files: &[P]) -> Result<()> {
+        let header = DROPFILES { pFiles: core::mem::size_of::<DROPFILES>() as u32, fWide: TRUE, ..Default::default() };
+        let mut bytes = unsafe { core::slice::from_raw_parts(&header as *const DROPFILES as *const u8, core::mem::size_of::<DROPFILES>()) }.to_vec();
+
+        for file in files {
+            bytes.extend_from_slice(&to_wide(&file.as_ref().to_string_lossy()));
+        }
+
+        bytes.extend_from_slice(&[0, 0]);
+        self.set(CF_HDROP.0.into(), &bytes)
+    }
+
+    /// Returns the device-independent bitmap on the clipboard, consisting of a `BITMAPINFO` header followed by
+    /// the pixels, or `None` if the clipboard doesn't contain an image.
+    ///
+    /// The system converts bitmaps placed on the clipboard in other bitmap formats to this format.
+    pub fn dib(&self) -> Result<Option<Vec<u8>>> {
+        self.get(CF_DIB.0.into())
+    }
+
+    /// Places a device-independent bitmap, consisting of a `BITMAPINFO` header followed by the pixels, on the
+    /// clipboard.
+    pub fn set_dib(&self, dib: &[u8]) -> Result<()> {
+        self.set(CF_DIB.0.into(), dib)
+    }
+
+    /// Returns the PNG image on the clipboard, or `None` if the clipboard doesn't contain one.
+    ///
+    /// Browsers and Office place images on the clipboard in the registered `PNG` format, which preserves
+    /// transparency.
+    pub fn png(&self) -> Result<Option<Vec<u8>>> {
+        self.get(Self::register_format("PNG")?)
+    }
+
+    /// Places a PNG image on the clipboard in the registered `PNG` format.
+    pub fn set_png(&self, png: &[u8]) -> Result<()> {
+        self.set(Self::register_format("PNG")?, png)
+    }
+}
+
+impl Drop for Clipboard {
+    fn drop(&mut self) {
+        unsafe {
+            _ = CloseClipboard();
+        }
+    }
+}
+
+fn to_wide(text: &str) -> Vec<u8> {
+    text.encode_utf16().chain([0]).flat_map(u16::to_le_bytes).collect()
+}
+
+fn from_wide(bytes: &[u8]) -> String {
+    let wide: Vec<u16> = bytes.chunks_exact(2).map(|c| u16::from_le_bytes([c[0], c[1]])).take_while(|c| *c != 0).collect();
+    String::from_utf16_lossy(&wide)
+}
+
+/// Calls a callback whenever the clipboard contents change, until it is dropped.
+///
+/// The listener owns a hidden message-only window on a dedicated thread, which is where the callback is called.
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+pub struct ClipboardListener {
+    // The window handle, stored as an integer since `HWND` can't be sent between threads.
+    window: isize,
+    thread: Option<std::thread::JoinHandle<()>>,
+}
+
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+impl ClipboardListener {
+    /// Starts listening for clipboard changes.
+    pub fn new<F: FnMut() + Send + 'static>(callback: F) -> Result<Self> {
+        let (sender, receiver) = std::sync::mpsc::channel();
+
+        let thread = std::thread::Builder::new().name("ClipboardListener".to_string()).spawn(move || {
+            let window = match listener::create(Box::new(callback)) {
+                Ok(window) => window,
+                Err(error) => {
+                    _ = sender.send(Err(error));
+                    return;
+                }
+            };
+
+            _ = sender.send(Ok(window.0 as isize));
+            listener::run();
+        })?;
+
+        match receiver.recv() {
+            Ok(Ok(window)) => Ok(Self { window, thread: Some(thread) }),
+            Ok(Err(error)) => {
+                _ = thread.join();
+                Err(error)
+            }
+            Err(_) => Err(E_UNEXPECTED.into()),
+        }
+    }
+}
+
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+impl Drop for ClipboardListener {
+    fn drop(&mut self) {
+        unsafe {
+            _ = crate::Win32::UI::WindowsAndMessaging::PostMessageW(HWND(self.window as _), crate::Win32::UI::WindowsAndMessaging::WM_CLOSE, WPARAM(0), LPARAM(0));
+        }
+
+        if let Some(thread) = self.thread.take() {
+            _ = thread.join();
+        }
+    }
+}
+
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging"))]
+mod listener {
+    use super::*;
+    use crate::Win32::UI::WindowsAndMessaging::*;
+    use std::cell::RefCell;
+
+    std::thread_local! {
+        // The callback of the listener whose window belongs to this thread.
+        static CALLBACK: RefCell<Option<Box<dyn FnMut()>>> = RefCell::new(None);
+    }
+
+    pub fn create(callback: Box<dyn FnMut()>) -> Result<HWND> {
+        let class = windows_core::w!("windows-rs-clipboard-listener");
+
+        unsafe {
+            let wc = WNDCLASSW { lpfnWndProc: Some(window_proc), lpszClassName: class, ..Default::default() };
+
+            // The class is shared by every listener in the process, so it may already be registered.
+            if RegisterClassW(&wc) == 0 && GetLastError() != ERROR_CLASS_ALREADY_EXISTS {
+                return Err(windows_core::Error::from_win32());
+            }
+
+            let window = CreateWindowExW(WINDOW_EX_STYLE(0), class, None, WINDOW_STYLE(0), 0, 0, 0, 0, HWND_MESSAGE, None, None, None)?;
+
+            if let Err(error) = AddClipboardFormatListener(window) {
+                _ = DestroyWindow(window);
+                return Err(error);
+            }
+
+            CALLBACK.with(|cell| *cell.borrow_mut() = Some(callback));
+            Ok(window)
+        }
+    }
+
+    // Runs until the window is closed, which also removes it from the clipboard's listeners.
+    pub fn run() {
+        unsafe {
+            let mut message = MSG::default();
+
+            while GetMessageW(&mut message, None, 0, 0).as_bool() {
+                DispatchMessageW(&message);
+            }
+        }
+    }
+
+    extern "system" fn window_proc(window: HWND, message: u32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+        if message == WM_CLIPBOARDUPDATE {
+            CALLBACK.with(|cell| {
+                if let Some(callback) = cell.borrow_mut().as_mut() {
+                    callback();
+                }
+            });
+
+            return LRESULT(0);
+        }
+
+        if message == WM_DESTROY {
+            unsafe { PostQuitMessage(0) };
+            return LRESULT(0);
+        }
+
+        unsafe { DefWindowProcW(window, message, wparam, lparam) }
+    }
+}
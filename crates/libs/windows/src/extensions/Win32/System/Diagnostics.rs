@@ -0,0 +1,6 @@
+#[cfg(all(feature = "Win32_Security", feature = "Win32_System_Diagnostics_Debug", feature = "Win32_System_Kernel", feature = "Win32_System_Threading"))]
+pub mod Debug;
+#[cfg(feature = "Win32_System_Diagnostics_Etw")]
+pub mod Etw;
+#[cfg(feature = "Win32_System_Diagnostics_ToolHelp")]
+pub mod ToolHelp;
@@ -0,0 +1,2 @@
+/// Helpers for surviving exceptions raised by native code.
+pub mod seh;
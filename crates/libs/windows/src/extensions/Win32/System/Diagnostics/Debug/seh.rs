@@ -0,0 +1,152 @@
+use crate::Win32::Foundation::*;
+use crate::Win32::System::Diagnostics::Debug::*;
+use crate::Win32::System::Threading::*;
+use core::cell::Cell;
+use core::ffi::c_void;
+use std::panic::AssertUnwindSafe;
+use std::sync::Mutex;
+use windows_core::{Error, Owned, Result};
+
+/// Runs `f` on a dedicated thread, converting an access violation or other exception that is not handled
+/// within `f` into an [`Error`] rather than terminating the process.
+///
+/// This lets plugin hosts survive misbehaving native components, such as a third-party COM server that
+/// dereferences an invalid pointer. The error's code is derived from the exception code, such as
+/// `STATUS_ACCESS_VIOLATION`, and its message includes the address at which the exception was raised.
+/// Panics within `f` are propagated to the caller as usual.
+///
+/// `f` runs on a new thread rather than the calling thread, which is why it must be `Send`. Most interfaces are
+/// not agile and cannot be captured by `f`, so the component should be initialized and created within `f`:
+///
+/// ```rust,ignore
+/// let result = seh::guard(|| unsafe {
+///     CoInitializeEx(None, COINIT_MULTITHREADED).ok()?;
+///     let plugin: IPlugin = CoCreateInstance(&CLSID_PLUGIN, None, CLSCTX_INPROC_SERVER)?;
+///     plugin.Run()
+/// })?;
+/// ```
+///
+/// Exceptions are only converted once no exception handler within `f` has handled them, so components that
+/// use structured exception handling internally behave as usual. The guarded thread is then terminated
+/// without unwinding, so any values owned by `f` are leaked and any locks it holds are never released; the
+/// process should treat the component as unusable from that point on. Exceptions are not converted while a
+/// debugger is attached, as the debugger is notified of unhandled exceptions instead.
+///
+/// The conversion relies on the process-wide unhandled exception filter, which is installed while any guard is
+/// running and restored once none are. Exceptions are not converted if another component replaces the filter
+/// while `f` is running.
+pub fn guard<F, R>(f: F) -> Result<R>
+where
+    F: FnOnce() -> R + Send,
+    R: Send,
+{
+    let _filter = FilterGuard::install();
+    let mut state = GuardState { f: Some(f), result: None, exception: Cell::new(None) };
+
+    unsafe {
+        let thread = Owned::new(CreateThread(None, 0, Some(guard_thread::<F, R>), Some(&mut state as *mut _ as *const c_void), THREAD_CREATION_FLAGS(0), None)?);
+
+        // Waiting for the thread to exit also ensures that its writes to `state` are visible here.
+        WaitForSingleObject(*thread, INFINITE);
+    }
+
+    if let Some((code, address)) = state.exception.get() {
+        return Err(Error::new(code.to_hresult(), format!("Exception 0x{:08X} at address {address:#x}", code.0 as u32)));
+    }
+
+    match state.result {
+        Some(Ok(result)) => Ok(result),
+        Some(Err(panic)) => std::panic::resume_unwind(panic),
+        None => unreachable!(),
+    }
+}
+
+struct GuardState<F, R> {
+    f: Option<F>,
+    result: Option<std::thread::Result<R>>,
+    exception: Cell<Option<(NTSTATUS, usize)>>,
+}
+
+thread_local! {
+    // Points to the `exception` field of the state of the guard running on this thread, if any.
+    static GUARDED: Cell<*const Cell<Option<(NTSTATUS, usize)>>> = const { Cell::new(core::ptr::null()) };
+}
+
+// The number of running guards and the filter that was installed before ours, which handles exceptions on threads
+// that are not guarded.
+static FILTER: Mutex<(usize, LPTOP_LEVEL_EXCEPTION_FILTER)> = Mutex::new((0, None));
+
+// Installs our filter for the lifetime of a guard. The filter is installed by every guard, rather than once, in case
+// another component replaced it since, and the previous filter is restored once no guards are running.
+struct FilterGuard;
+
+impl FilterGuard {
+    fn install() -> Self {
+        let mut state = FILTER.lock().unwrap();
+        let previous = unsafe { SetUnhandledExceptionFilter(Some(filter)) };
+
+        if !is_filter(previous) {
+            state.1 = previous;
+        }
+
+        state.0 += 1;
+        Self
+    }
+}
+
+impl Drop for FilterGuard {
+    fn drop(&mut self) {
+        let mut state = FILTER.lock().unwrap();
+        state.0 -= 1;
+
+        if state.0 == 0 {
+            let current = unsafe { SetUnhandledExceptionFilter(state.1.take()) };
+
+            // Leaves a filter installed by another component in place.
+            if !is_filter(current) {
+                unsafe { SetUnhandledExceptionFilter(current) };
+            }
+        }
+    }
+}
+
+fn is_filter(value: LPTOP_LEVEL_EXCEPTION_FILTER) -> bool {
+    value.is_some_and(|value| value as usize == filter as *const () as usize)
+}
+
+unsafe extern "system" fn guard_thread<F, R>(param: *mut c_void) -> u32
+where
+    F: FnOnce() -> R + Send,
+    R: Send,
+{
+    let state = &mut *(param as *mut GuardState<F, R>);
+    GUARDED.with(|guarded| guarded.set(&state.exception));
+
+    if let Some(f) = state.f.take() {
+        state.result = Some(std::panic::catch_unwind(AssertUnwindSafe(f)));
+    }
+
+    GUARDED.with(|guarded| guarded.set(core::ptr::null()));
+    0
+}
+
+unsafe extern "system" fn filter(info: *const EXCEPTION_POINTERS) -> i32 {
+    let exception = GUARDED.with(|guarded| guarded.get());
+
+    if !exception.is_null() {
+        if let Some(record) = (*info).ExceptionRecord.as_ref() {
+            (*exception).set(Some((record.ExceptionCode, record.ExceptionAddress as usize)));
+        }
+
+        // The guarded thread cannot safely continue, so it exits without unwinding and the guard reports the
+        // exception once the thread has exited.
+        ExitThread(1);
+    }
+
+    let previous = FILTER.lock().map_or(None, |state| state.1);
+
+    match previous {
+        Some(previous) => previous(info),
+        None => EXCEPTION_CONTINUE_SEARCH,
+    }
+}